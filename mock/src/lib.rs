@@ -34,6 +34,7 @@
 #![deny(missing_docs)]
 use std::fs::File;
 use std::io::Read;
+use std::sync::Mutex;
 use std::time::Duration;
 
 use async_trait::async_trait;
@@ -110,6 +111,13 @@ impl MockRequestDispatcher {
         self
     }
 
+    /// Mocks a binary service response body, such as an event stream,
+    /// that would be returned from AWS
+    pub fn with_binary_body(mut self, body: &[u8]) -> MockRequestDispatcher {
+        self.body = body.to_vec();
+        self
+    }
+
     /// Mocks the json serialized response body what would be
     /// returned from AWS
     pub fn with_json_body<B>(mut self, body: B) -> MockRequestDispatcher
@@ -159,6 +167,51 @@ impl DispatchSignedRequest for MockRequestDispatcher {
     }
 }
 
+/// Mocks a sequence of service responses, dispatching each request
+/// to the next of the given `MockRequestDispatcher`s
+///
+/// Panics when a request is dispatched after the last response.
+pub struct MultipleMockRequestDispatcher<I>
+where
+    I: Iterator<Item = MockRequestDispatcher>,
+{
+    dispatchers: Mutex<I>,
+}
+
+impl<I> MultipleMockRequestDispatcher<I>
+where
+    I: Iterator<Item = MockRequestDispatcher>,
+{
+    /// Returns an instance answering requests with `dispatchers` in order
+    pub fn new<C>(dispatchers: C) -> MultipleMockRequestDispatcher<I>
+    where
+        C: IntoIterator<Item = MockRequestDispatcher, IntoIter = I>,
+    {
+        MultipleMockRequestDispatcher {
+            dispatchers: Mutex::new(dispatchers.into_iter()),
+        }
+    }
+}
+
+impl<I> DispatchSignedRequest for MultipleMockRequestDispatcher<I>
+where
+    I: Iterator<Item = MockRequestDispatcher>,
+{
+    fn dispatch(
+        &self,
+        request: SignedRequest,
+        timeout: Option<Duration>,
+    ) -> rusoto_core::request::DispatchSignedRequestFuture {
+        let dispatcher = self
+            .dispatchers
+            .lock()
+            .unwrap()
+            .next()
+            .expect("no mock response left for the request");
+        dispatcher.dispatch(request, timeout)
+    }
+}
+
 /// An interface for producing response body content
 pub trait ReadMockResponse {
     /// Return a response body string for a given directory and file name
@@ -0,0 +1,56 @@
+use std::time::Duration;
+
+use rusoto_core::signature::SignedRequest;
+use rusoto_core::{AdaptiveRateLimiter, Client, Region, RetryPolicy};
+use rusoto_mock::{MockCredentialsProvider, MockRequestDispatcher};
+use tokio::time::{self, Instant};
+
+fn request() -> SignedRequest {
+    SignedRequest::new("POST", "dynamodb", &Region::UsEast1, "/")
+}
+
+#[tokio::test]
+async fn throttling_slows_down_every_client_sharing_the_limiter() {
+    time::pause();
+    let rate_limiter = AdaptiveRateLimiter::new()
+        .with_min_rate(1.0)
+        .with_rate_increase(0.0);
+    let policy = RetryPolicy::disabled().with_rate_limiter(rate_limiter.clone());
+
+    let throttled = Client::new_with_retry_policy(
+        MockCredentialsProvider,
+        MockRequestDispatcher::with_status(400)
+            .with_body(r#"{"__type":"ProvisionedThroughputExceededException"}"#),
+        policy.clone(),
+    );
+    let healthy = Client::new_with_retry_policy(
+        MockCredentialsProvider,
+        MockRequestDispatcher::with_status(200).with_body("{}"),
+        policy,
+    );
+
+    let start = Instant::now();
+    for _ in 0..3 {
+        healthy.sign_and_dispatch(request()).await.unwrap();
+    }
+    assert_eq!(Instant::now(), start);
+    assert!(!rate_limiter.is_enabled());
+
+    // four requests sent within the first measurement window of half a second
+    throttled.sign_and_dispatch(request()).await.unwrap();
+    let fill_rate = rate_limiter.fill_rate().unwrap();
+    assert!(
+        (fill_rate - 8.0 * 0.7).abs() < 1e-9,
+        "fill rate {}",
+        fill_rate
+    );
+    assert_eq!(rate_limiter.tokens(), 0.0);
+
+    let start = Instant::now();
+    for _ in 0..3 {
+        healthy.sign_and_dispatch(request()).await.unwrap();
+    }
+    // the timer has millisecond resolution
+    let expected = Duration::from_secs_f64(3.0 / fill_rate) - Duration::from_millis(1);
+    assert!(Instant::now() - start >= expected);
+}
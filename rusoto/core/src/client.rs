@@ -1,20 +1,30 @@
+use std::error::Error;
+use std::fmt;
 use std::sync::{Arc, Mutex, Weak};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use crate::credential::{
     Anonymous, CredentialsError, DefaultCredentialsProvider, ProvideAwsCredentials, StaticProvider,
 };
 use crate::encoding::ContentEncoding;
+use crate::endpoint::{DefaultEndpointResolver, EndpointResolver, ResolveEndpointError};
+use crate::interceptor::Interceptor;
+use crate::metrics::{self, CallMetrics, MetricsSink};
+use crate::region::Region;
 use crate::request::{DispatchSignedRequest, HttpClient, HttpDispatchError, HttpResponse};
+use crate::retry::{RetryKind, RetryPolicy};
 use crate::signature::SignedRequest;
 
 use async_trait::async_trait;
 use lazy_static::lazy_static;
+use log::debug;
 use tokio::time;
 
+type SharedClient = Mutex<Weak<ClientInner<DefaultCredentialsProvider, HttpClient>>>;
+
 lazy_static! {
-    static ref SHARED_CLIENT: Mutex<Weak<ClientInner<DefaultCredentialsProvider, HttpClient>>> =
-        Mutex::new(Weak::new());
+    static ref SHARED_CLIENT: SharedClient = Mutex::new(Weak::new());
+    static ref SHARED_CLIENT_WITH_RETRY: SharedClient = Mutex::new(Weak::new());
 }
 
 /// Re-usable logic for all clients.
@@ -25,8 +35,24 @@ pub struct Client {
 
 impl Client {
     /// Return the shared default client.
+    ///
+    /// It attempts requests once, see `shared_with_retry_from_env` for a shared client
+    /// retrying them.
     pub fn shared() -> Self {
-        let mut lock = SHARED_CLIENT.lock().unwrap();
+        Client::shared_from(&SHARED_CLIENT, RetryPolicy::disabled)
+    }
+
+    /// Return the shared client retrying requests as the `AWS_RETRY_MODE` and
+    /// `AWS_MAX_ATTEMPTS` environment variables say, see `RetryPolicy::from_env`.
+    ///
+    /// With the `adaptive` retry mode, all users of this client share a single rate limiter.
+    /// Pass it to the `new_with_client` constructors of service clients.
+    pub fn shared_with_retry_from_env() -> Self {
+        Client::shared_from(&SHARED_CLIENT_WITH_RETRY, RetryPolicy::from_env)
+    }
+
+    fn shared_from(shared: &SharedClient, retry_policy: fn() -> RetryPolicy) -> Self {
+        let mut lock = shared.lock().unwrap();
         if let Some(inner) = lock.upgrade() {
             return Client { inner };
         }
@@ -37,6 +63,10 @@ impl Client {
             credentials_provider: Some(Arc::new(credentials_provider)),
             dispatcher: Arc::new(dispatcher),
             content_encoding: Default::default(),
+            retry_policy: retry_policy(),
+            endpoint_resolver: None,
+            interceptors: Vec::new(),
+            metrics_sink: None,
         });
         *lock = Arc::downgrade(&inner);
         Client { inner }
@@ -52,6 +82,38 @@ impl Client {
             credentials_provider: Some(Arc::new(credentials_provider)),
             dispatcher: Arc::new(dispatcher),
             content_encoding: Default::default(),
+            retry_policy: RetryPolicy::disabled(),
+            endpoint_resolver: None,
+            interceptors: Vec::new(),
+            metrics_sink: None,
+        };
+        Client {
+            inner: Arc::new(inner),
+        }
+    }
+
+    /// Create a client from a credentials provider, request dispatcher and retry policy.
+    ///
+    /// Failed attempts are retried according to `retry_policy`, and each attempt is signed
+    /// anew. Requests with a streaming payload are only ever attempted once, since the
+    /// stream cannot be replayed.
+    pub fn new_with_retry_policy<P, D>(
+        credentials_provider: P,
+        dispatcher: D,
+        retry_policy: RetryPolicy,
+    ) -> Self
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        let inner = ClientInner {
+            credentials_provider: Some(Arc::new(credentials_provider)),
+            dispatcher: Arc::new(dispatcher),
+            content_encoding: Default::default(),
+            retry_policy,
+            endpoint_resolver: None,
+            interceptors: Vec::new(),
+            metrics_sink: None,
         };
         Client {
             inner: Arc::new(inner),
@@ -71,6 +133,10 @@ impl Client {
             credentials_provider: None,
             dispatcher: Arc::new(dispatcher),
             content_encoding: Default::default(),
+            retry_policy: RetryPolicy::disabled(),
+            endpoint_resolver: None,
+            interceptors: Vec::new(),
+            metrics_sink: None,
         };
         Client {
             inner: Arc::new(inner),
@@ -92,18 +158,74 @@ impl Client {
             credentials_provider: Some(Arc::new(credentials_provider)),
             dispatcher: Arc::new(dispatcher),
             content_encoding,
+            retry_policy: RetryPolicy::disabled(),
+            endpoint_resolver: None,
+            interceptors: Vec::new(),
+            metrics_sink: None,
         };
         Client {
             inner: Arc::new(inner),
         }
     }
 
+    /// Resolve the endpoints of requests with `endpoint_resolver`, such as the
+    /// `DefaultEndpointResolver`.
+    ///
+    /// Requests whose hostname is set, or whose region is custom, are sent as they are. Without
+    /// a resolver, requests are sent to the endpoint of their region and service, and only those
+    /// to regions known by their name, `Region::Named`, are resolved with the
+    /// `DefaultEndpointResolver`.
+    pub fn with_endpoint_resolver<R>(self, endpoint_resolver: R) -> Self
+    where
+        R: EndpointResolver + Send + Sync + 'static,
+    {
+        Client {
+            inner: self
+                .inner
+                .with_endpoint_resolver(Arc::new(endpoint_resolver)),
+        }
+    }
+
+    /// Call `interceptor` at every attempt of the requests the client sends, after the
+    /// interceptors already added, see the `interceptor` module.
+    pub fn with_interceptor<I>(self, interceptor: I) -> Self
+    where
+        I: Interceptor + 'static,
+    {
+        Client {
+            inner: self.inner.with_interceptor(Arc::new(interceptor)),
+        }
+    }
+
+    /// Record the metrics of every call the client makes with `metrics_sink`, see the `metrics`
+    /// module.
+    pub fn with_metrics_sink<M>(self, metrics_sink: M) -> Self
+    where
+        M: MetricsSink + 'static,
+    {
+        Client {
+            inner: self.inner.with_metrics_sink(Arc::new(metrics_sink)),
+        }
+    }
+
     /// Fetch credentials, sign the request and dispatch it.
     pub async fn sign_and_dispatch(
         &self,
         request: SignedRequest,
     ) -> Result<HttpResponse, SignAndDispatchError> {
-        self.inner.sign_and_dispatch(request, None).await
+        self.inner.sign_and_dispatch(request, None, false).await
+    }
+
+    /// Fetch credentials, sign the request and dispatch it, like `sign_and_dispatch`, buffering
+    /// successful responses to check their body.
+    ///
+    /// Successful responses whose body is an XML `Error` document, as S3 sends for some failed
+    /// requests, are retried as failed attempts.
+    pub async fn sign_and_dispatch_checking_body(
+        &self,
+        request: SignedRequest,
+    ) -> Result<HttpResponse, SignAndDispatchError> {
+        self.inner.sign_and_dispatch(request, None, true).await
     }
 }
 
@@ -114,6 +236,28 @@ pub enum SignAndDispatchError {
     Credentials(CredentialsError),
     /// Error was due to http dispatch
     Dispatch(HttpDispatchError),
+    /// Error was due to the endpoint of the request not being resolved
+    Endpoint(ResolveEndpointError),
+}
+
+impl fmt::Display for SignAndDispatchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            SignAndDispatchError::Credentials(ref err) => write!(f, "{}", err),
+            SignAndDispatchError::Dispatch(ref err) => write!(f, "{}", err),
+            SignAndDispatchError::Endpoint(ref err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl Error for SignAndDispatchError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            SignAndDispatchError::Credentials(ref err) => Some(err),
+            SignAndDispatchError::Dispatch(ref err) => Some(err),
+            SignAndDispatchError::Endpoint(ref err) => Some(err),
+        }
+    }
 }
 
 #[async_trait]
@@ -122,13 +266,33 @@ trait SignAndDispatch {
         &self,
         request: SignedRequest,
         timeout: Option<Duration>,
+        check_body: bool,
     ) -> Result<HttpResponse, SignAndDispatchError>;
+
+    fn with_endpoint_resolver(
+        &self,
+        endpoint_resolver: Arc<dyn EndpointResolver + Send + Sync>,
+    ) -> Arc<dyn SignAndDispatch + Send + Sync>;
+
+    fn with_interceptor(
+        &self,
+        interceptor: Arc<dyn Interceptor>,
+    ) -> Arc<dyn SignAndDispatch + Send + Sync>;
+
+    fn with_metrics_sink(
+        &self,
+        metrics_sink: Arc<dyn MetricsSink>,
+    ) -> Arc<dyn SignAndDispatch + Send + Sync>;
 }
 
 struct ClientInner<P, D> {
     credentials_provider: Option<Arc<P>>,
     dispatcher: Arc<D>,
     content_encoding: ContentEncoding,
+    retry_policy: RetryPolicy,
+    endpoint_resolver: Option<Arc<dyn EndpointResolver + Send + Sync>>,
+    interceptors: Vec<Arc<dyn Interceptor>>,
+    metrics_sink: Option<Arc<dyn MetricsSink>>,
 }
 
 impl<P, D> Clone for ClientInner<P, D> {
@@ -137,12 +301,206 @@ impl<P, D> Clone for ClientInner<P, D> {
             credentials_provider: self.credentials_provider.clone(),
             dispatcher: self.dispatcher.clone(),
             content_encoding: self.content_encoding.clone(),
+            retry_policy: self.retry_policy.clone(),
+            endpoint_resolver: self.endpoint_resolver.clone(),
+            interceptors: self.interceptors.clone(),
+            metrics_sink: self.metrics_sink.clone(),
         }
     }
 }
 
 async fn sign_and_dispatch<P, D>(
     client: ClientInner<P, D>,
+    request: SignedRequest,
+    timeout: Option<Duration>,
+    check_body: bool,
+) -> Result<HttpResponse, SignAndDispatchError>
+where
+    P: ProvideAwsCredentials + Send + Sync + 'static,
+    D: DispatchSignedRequest + Send + Sync + 'static,
+{
+    let service = request.service.clone();
+    let operation = request.operation().map(ToOwned::to_owned);
+    let region = request.region.name().to_owned();
+    let bytes_sent = request.payload_len().map(|len| len as u64);
+    #[cfg(feature = "tracing")]
+    let span = tracing::info_span!(
+        "aws_call",
+        service = service.as_str(),
+        operation = operation.as_deref().unwrap_or_default(),
+        region = region.as_str(),
+        attempts = tracing::field::Empty,
+        status = tracing::field::Empty,
+        request_id = tracing::field::Empty,
+        latency_ms = tracing::field::Empty,
+    );
+
+    let start = Instant::now();
+    let mut attempts = 0;
+    let call = sign_and_dispatch_attempts(&client, request, timeout, check_body, &mut attempts);
+    #[cfg(feature = "tracing")]
+    let call = tracing::Instrument::instrument(call, span.clone());
+    let result = call.await;
+
+    let response = result.as_ref().ok();
+    let call_metrics = CallMetrics {
+        service,
+        operation,
+        region,
+        attempts,
+        status: response.map(|response| response.status.as_u16()),
+        request_id: response.and_then(|response| metrics::request_id(&response.headers)),
+        latency: start.elapsed(),
+        bytes_sent,
+        bytes_received: response
+            .and_then(|response| response.headers.get("content-length"))
+            .and_then(|len| len.parse().ok()),
+    };
+    #[cfg(feature = "tracing")]
+    {
+        span.record("attempts", call_metrics.attempts);
+        if let Some(status) = call_metrics.status {
+            span.record("status", status);
+        }
+        if let Some(ref request_id) = call_metrics.request_id {
+            span.record("request_id", request_id.as_str());
+        }
+        span.record("latency_ms", call_metrics.latency.as_millis() as u64);
+    }
+    if let Some(ref metrics_sink) = client.metrics_sink {
+        metrics_sink.record(&call_metrics);
+    }
+    result
+}
+
+/// Sends `request`, retrying it according to the retry policy of `client`, counting the
+/// attempts in `attempts`.
+async fn sign_and_dispatch_attempts<P, D>(
+    client: &ClientInner<P, D>,
+    mut request: SignedRequest,
+    timeout: Option<Duration>,
+    check_body: bool,
+    attempts: &mut u32,
+) -> Result<HttpResponse, SignAndDispatchError>
+where
+    P: ProvideAwsCredentials + Send + Sync + 'static,
+    D: DispatchSignedRequest + Send + Sync + 'static,
+{
+    resolve_endpoint(client.endpoint_resolver.as_deref(), &mut request)
+        .map_err(SignAndDispatchError::Endpoint)?;
+
+    let method = request.method().to_owned();
+    let rate_limiter = client.retry_policy.rate_limiter();
+    let mut attempt = 1;
+    loop {
+        *attempts = attempt;
+        if let Some(rate_limiter) = rate_limiter {
+            rate_limiter.acquire().await;
+        }
+
+        // keep an unsigned copy around in case this attempt has to be retried
+        let retry_request = if attempt < client.retry_policy.max_attempts() {
+            request.try_clone()
+        } else {
+            None
+        };
+
+        let result = sign_and_dispatch_once(client, request, timeout).await;
+
+        if retry_request.is_none() && rate_limiter.is_none() {
+            return result;
+        }
+        let (result, retry_kind) = match result {
+            Ok(response) if response.status.is_success() && !check_body => {
+                if let Some(rate_limiter) = rate_limiter {
+                    rate_limiter.on_success();
+                }
+                return Ok(response);
+            }
+            Ok(mut response) => {
+                let buffered = response
+                    .buffer()
+                    .await
+                    .map_err(SignAndDispatchError::Dispatch)?;
+                if buffered.status.is_success() && !buffered.is_xml_error() {
+                    if let Some(rate_limiter) = rate_limiter {
+                        rate_limiter.on_success();
+                    }
+                    return Ok(HttpResponse::from(buffered));
+                }
+                let retry_kind = client
+                    .retry_policy
+                    .classifier()
+                    .classify_response(&buffered);
+                (Ok(HttpResponse::from(buffered)), retry_kind)
+            }
+            Err(SignAndDispatchError::Dispatch(err)) => {
+                let retry_kind = client
+                    .retry_policy
+                    .classifier()
+                    .classify_dispatch_error(&method, &err);
+                (Err(SignAndDispatchError::Dispatch(err)), retry_kind)
+            }
+            Err(err) => return Err(err),
+        };
+        if retry_kind == RetryKind::Throttling {
+            if let Some(rate_limiter) = rate_limiter {
+                rate_limiter.on_throttle();
+            }
+        }
+        let next_request = match retry_request {
+            Some(next_request) if retry_kind.is_retryable() => next_request,
+            _ => return result,
+        };
+
+        let delay = client.retry_policy.backoff(attempt);
+        debug!(
+            "Attempt {} failed ({:?}), retrying in {:?}",
+            attempt, retry_kind, delay
+        );
+        #[cfg(feature = "tracing")]
+        tracing::debug!(attempt, ?retry_kind, ?delay, "retrying failed attempt");
+        time::delay_for(delay).await;
+        request = next_request;
+        attempt += 1;
+    }
+}
+
+/// Points `request` to the endpoint `resolver` resolves, signed for the region and service it's
+/// scoped to, unless its hostname is set or its region is custom.
+///
+/// Without a resolver, only requests to `Region::Named` regions are resolved, with the
+/// `DefaultEndpointResolver`.
+fn resolve_endpoint(
+    resolver: Option<&(dyn EndpointResolver + Send + Sync)>,
+    request: &mut SignedRequest,
+) -> Result<(), ResolveEndpointError> {
+    if request.hostname.is_some() {
+        return Ok(());
+    }
+    let default_resolver = DefaultEndpointResolver::new();
+    let resolver: &dyn EndpointResolver = match (resolver, &request.region) {
+        (_, Region::Custom { .. }) => return Ok(()),
+        (Some(resolver), _) => resolver,
+        (None, Region::Named(_)) => &default_resolver,
+        (None, _) => return Ok(()),
+    };
+    let endpoint = resolver.resolve(request.endpoint_prefix(), request.region.name())?;
+    if let Some(signing_region) = endpoint.signing_region.clone() {
+        request.region = signing_region.parse().unwrap_or_else(|_| Region::Custom {
+            endpoint: format!("https://{}", endpoint.hostname),
+            name: signing_region,
+        });
+    }
+    if let Some(signing_service) = endpoint.signing_service {
+        request.service = signing_service;
+    }
+    request.set_hostname(Some(endpoint.hostname));
+    Ok(())
+}
+
+async fn sign_and_dispatch_once<P, D>(
+    client: &ClientInner<P, D>,
     mut request: SignedRequest,
     timeout: Option<Duration>,
 ) -> Result<HttpResponse, SignAndDispatchError>
@@ -150,7 +508,10 @@ where
     P: ProvideAwsCredentials + Send + Sync + 'static,
     D: DispatchSignedRequest + Send + Sync + 'static,
 {
-    if let Some(provider) = client.credentials_provider {
+    for interceptor in &client.interceptors {
+        interceptor.before_sign(&mut request);
+    }
+    if let Some(ref provider) = client.credentials_provider {
         let credentials = if let Some(to) = timeout {
             time::timeout(to, provider.credentials())
                 .await
@@ -170,11 +531,21 @@ where
     } else {
         request.complement_with_plus(true);
     }
-    client
+    for interceptor in &client.interceptors {
+        interceptor.after_sign(&request);
+    }
+    for interceptor in &client.interceptors {
+        interceptor.before_dispatch(&mut request);
+    }
+    let mut response = client
         .dispatcher
         .dispatch(request, timeout)
         .await
-        .map_err(SignAndDispatchError::Dispatch)
+        .map_err(SignAndDispatchError::Dispatch)?;
+    for interceptor in &client.interceptors {
+        interceptor.after_response(&mut response);
+    }
+    Ok(response)
 }
 
 #[async_trait]
@@ -187,8 +558,36 @@ where
         &self,
         request: SignedRequest,
         timeout: Option<Duration>,
+        check_body: bool,
     ) -> Result<HttpResponse, SignAndDispatchError> {
-        sign_and_dispatch(self.clone(), request, timeout).await
+        sign_and_dispatch(self.clone(), request, timeout, check_body).await
+    }
+
+    fn with_endpoint_resolver(
+        &self,
+        endpoint_resolver: Arc<dyn EndpointResolver + Send + Sync>,
+    ) -> Arc<dyn SignAndDispatch + Send + Sync> {
+        let mut inner = self.clone();
+        inner.endpoint_resolver = Some(endpoint_resolver);
+        Arc::new(inner)
+    }
+
+    fn with_interceptor(
+        &self,
+        interceptor: Arc<dyn Interceptor>,
+    ) -> Arc<dyn SignAndDispatch + Send + Sync> {
+        let mut inner = self.clone();
+        inner.interceptors.push(interceptor);
+        Arc::new(inner)
+    }
+
+    fn with_metrics_sink(
+        &self,
+        metrics_sink: Arc<dyn MetricsSink>,
+    ) -> Arc<dyn SignAndDispatch + Send + Sync> {
+        let mut inner = self.clone();
+        inner.metrics_sink = Some(metrics_sink);
+        Arc::new(inner)
     }
 }
 
@@ -198,3 +597,516 @@ fn client_is_send_and_sync() {
 
     is_send_and_sync::<Client>();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::request::{DispatchErrorKind, DispatchSignedRequestFuture};
+    use crate::stream::ByteStream;
+    use crate::{AdaptiveRateLimiter, Region};
+    use futures::FutureExt;
+    use http::{HeaderMap, StatusCode};
+
+    /// Replays the given outcomes in order and records the requests it receives.
+    struct SequenceDispatcher {
+        outcomes: Mutex<Vec<Result<(u16, &'static str), HttpDispatchError>>>,
+        requests: Arc<Mutex<Vec<SignedRequest>>>,
+    }
+
+    impl SequenceDispatcher {
+        fn new(
+            outcomes: Vec<Result<(u16, &'static str), HttpDispatchError>>,
+        ) -> (Self, Arc<Mutex<Vec<SignedRequest>>>) {
+            let requests = Arc::new(Mutex::new(Vec::new()));
+            let dispatcher = SequenceDispatcher {
+                outcomes: Mutex::new(outcomes.into_iter().rev().collect()),
+                requests: requests.clone(),
+            };
+            (dispatcher, requests)
+        }
+    }
+
+    impl DispatchSignedRequest for SequenceDispatcher {
+        fn dispatch(
+            &self,
+            request: SignedRequest,
+            _timeout: Option<Duration>,
+        ) -> DispatchSignedRequestFuture {
+            self.requests.lock().unwrap().push(request);
+            let outcome = self
+                .outcomes
+                .lock()
+                .unwrap()
+                .pop()
+                .expect("unexpected request");
+            let result = outcome.map(|(status, body)| HttpResponse {
+                status: StatusCode::from_u16(status).unwrap(),
+                body: ByteStream::from(body.as_bytes().to_vec()),
+                headers: HeaderMap::default(),
+            });
+            futures::future::ready(result).boxed()
+        }
+    }
+
+    fn credentials() -> StaticProvider {
+        StaticProvider::new_minimal("key".to_owned(), "secret".to_owned())
+    }
+
+    fn fast_retries() -> RetryPolicy {
+        RetryPolicy::new()
+            .with_max_attempts(3)
+            .with_base_delay(Duration::from_millis(1))
+            .with_max_delay(Duration::from_millis(1))
+    }
+
+    #[tokio::test]
+    async fn retries_throttled_requests() {
+        let (dispatcher, requests) = SequenceDispatcher::new(vec![
+            Ok((400, r#"{"__type":"ThrottlingException"}"#)),
+            Err(HttpDispatchError::new_with_kind(
+                "connection refused".to_owned(),
+                DispatchErrorKind::Connect,
+            )),
+            Ok((200, "{}")),
+        ]);
+        let client = Client::new_with_retry_policy(credentials(), dispatcher, fast_retries());
+        let mut request = SignedRequest::new("POST", "dynamodb", &Region::UsEast1, "/");
+        request.set_payload(Some(b"{}".to_vec()));
+
+        let response = client.sign_and_dispatch(request).await.unwrap();
+
+        assert_eq!(response.status, StatusCode::OK);
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 3);
+        for request in requests.iter() {
+            assert!(request.headers().contains_key("authorization"));
+            assert_eq!(request.headers().get("authorization").unwrap().len(), 1);
+        }
+    }
+
+    #[tokio::test]
+    async fn retries_errors_in_successful_responses() {
+        let error = "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+                     <Error><Code>InternalError</Code><Message>Try again</Message></Error>";
+        let result = "<CompleteMultipartUploadResult></CompleteMultipartUploadResult>";
+        let (dispatcher, requests) =
+            SequenceDispatcher::new(vec![Ok((200, error)), Ok((200, result))]);
+        let client = Client::new_with_retry_policy(credentials(), dispatcher, fast_retries());
+        let mut request = SignedRequest::new("POST", "s3", &Region::UsEast1, "/bucket/key");
+        request.set_payload(Some(b"<CompleteMultipartUpload/>".to_vec()));
+
+        let mut response = client
+            .sign_and_dispatch_checking_body(request)
+            .await
+            .unwrap();
+
+        assert_eq!(response.buffer().await.unwrap().body_as_str(), result);
+        assert_eq!(requests.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn returns_errors_in_successful_responses_once_out_of_attempts() {
+        let error = "<Error><Code>AccessDenied</Code></Error>";
+        let (dispatcher, requests) = SequenceDispatcher::new(vec![Ok((200, error))]);
+        let client = Client::new_with_retry_policy(credentials(), dispatcher, fast_retries());
+        let request = SignedRequest::new("PUT", "s3", &Region::UsEast1, "/bucket/key");
+
+        let mut response = client
+            .sign_and_dispatch_checking_body(request)
+            .await
+            .unwrap();
+
+        assert!(response.buffer().await.unwrap().is_xml_error());
+        assert_eq!(requests.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn gives_up_after_max_attempts() {
+        let (dispatcher, requests) = SequenceDispatcher::new(vec![
+            Ok((503, "<Error><Code>SlowDown</Code></Error>")),
+            Ok((503, "<Error><Code>SlowDown</Code></Error>")),
+            Ok((503, "<Error><Code>SlowDown</Code></Error>")),
+        ]);
+        let client = Client::new_with_retry_policy(credentials(), dispatcher, fast_retries());
+        let request = SignedRequest::new("GET", "s3", &Region::UsEast1, "/bucket");
+
+        let mut response = client.sign_and_dispatch(request).await.unwrap();
+
+        assert_eq!(response.status, StatusCode::SERVICE_UNAVAILABLE);
+        let buffered = response.buffer().await.unwrap();
+        assert_eq!(
+            buffered.body_as_str(),
+            "<Error><Code>SlowDown</Code></Error>"
+        );
+        assert_eq!(requests.lock().unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn does_not_retry_client_errors() {
+        let (dispatcher, requests) =
+            SequenceDispatcher::new(vec![Ok((400, r#"{"__type":"ValidationException"}"#))]);
+        let client = Client::new_with_retry_policy(credentials(), dispatcher, fast_retries());
+        let request = SignedRequest::new("POST", "dynamodb", &Region::UsEast1, "/");
+
+        let response = client.sign_and_dispatch(request).await.unwrap();
+
+        assert_eq!(response.status, StatusCode::BAD_REQUEST);
+        assert_eq!(requests.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn retries_only_idempotent_requests_failing_once_sent() {
+        let reset = || Err(HttpDispatchError::new("connection reset".to_owned()));
+        let (dispatcher, requests) = SequenceDispatcher::new(vec![reset(), reset(), Ok((200, ""))]);
+        let client = Client::new_with_retry_policy(credentials(), dispatcher, fast_retries());
+
+        let mut request = SignedRequest::new("POST", "dynamodb", &Region::UsEast1, "/");
+        request.set_payload(Some(b"{}".to_vec()));
+        assert!(client.sign_and_dispatch(request).await.is_err());
+        assert_eq!(requests.lock().unwrap().len(), 1);
+
+        let request = SignedRequest::new("GET", "s3", &Region::UsEast1, "/bucket");
+        let response = client.sign_and_dispatch(request).await.unwrap();
+        assert_eq!(response.status, StatusCode::OK);
+        assert_eq!(requests.lock().unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn does_not_retry_streaming_payloads() {
+        let (dispatcher, requests) = SequenceDispatcher::new(vec![Ok((500, ""))]);
+        let client = Client::new_with_retry_policy(credentials(), dispatcher, fast_retries());
+        let mut request = SignedRequest::new("PUT", "s3", &Region::UsEast1, "/bucket/key");
+        request.set_payload_stream(ByteStream::from(b"data".to_vec()));
+
+        let response = client.sign_and_dispatch(request).await.unwrap();
+
+        assert_eq!(response.status, StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(requests.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn rate_limiter_is_shared_between_clients() {
+        time::pause();
+        let rate_limiter = AdaptiveRateLimiter::new()
+            .with_min_rate(2.0)
+            .with_rate_increase(0.0);
+        let policy = RetryPolicy::disabled().with_rate_limiter(rate_limiter.clone());
+        let (throttled, _) =
+            SequenceDispatcher::new(vec![Ok((400, r#"{"__type":"ThrottlingException"}"#))]);
+        let (healthy, requests) = SequenceDispatcher::new(vec![Ok((200, "{}")), Ok((200, "{}"))]);
+        let throttled = Client::new_with_retry_policy(credentials(), throttled, policy.clone());
+        let healthy = Client::new_with_retry_policy(credentials(), healthy, policy);
+
+        let request = SignedRequest::new("POST", "dynamodb", &Region::UsEast1, "/");
+        throttled.sign_and_dispatch(request).await.unwrap();
+        assert_eq!(rate_limiter.fill_rate(), Some(2.0));
+
+        let start = time::Instant::now();
+        for _ in 0..2 {
+            let request = SignedRequest::new("POST", "dynamodb", &Region::UsEast1, "/");
+            healthy.sign_and_dispatch(request).await.unwrap();
+        }
+        // the timer has millisecond resolution
+        let elapsed = time::Instant::now() - start;
+        assert!(elapsed >= Duration::from_secs(1), "elapsed {:?}", elapsed);
+        assert!(
+            elapsed < Duration::from_millis(1010),
+            "elapsed {:?}",
+            elapsed
+        );
+        assert_eq!(requests.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn resolves_endpoints() {
+        let (dispatcher, requests) = SequenceDispatcher::new(vec![
+            Ok((200, "")),
+            Ok((200, "")),
+            Ok((200, "")),
+            Ok((200, "")),
+        ]);
+        let client = Client::new_with(credentials(), dispatcher)
+            .with_endpoint_resolver(DefaultEndpointResolver::new());
+        client
+            .sign_and_dispatch(SignedRequest::new("POST", "iam", &Region::EuWest1, "/"))
+            .await
+            .unwrap();
+        let mut request = SignedRequest::new("POST", "iotdata", &Region::EuWest1, "/");
+        request.set_endpoint_prefix("data.iot".to_owned());
+        client.sign_and_dispatch(request).await.unwrap();
+        let custom = Region::Custom {
+            name: "local".to_owned(),
+            endpoint: "http://localhost:9324".to_owned(),
+        };
+        client
+            .sign_and_dispatch(SignedRequest::new("POST", "sqs", &custom, "/"))
+            .await
+            .unwrap();
+        let named = Region::Named("ap-southeast-3".to_owned());
+        client
+            .sign_and_dispatch(SignedRequest::new("POST", "sqs", &named, "/"))
+            .await
+            .unwrap();
+
+        let requests = requests.lock().unwrap();
+        // global services are signed for the region of their endpoint
+        assert_eq!(requests[0].hostname(), "iam.amazonaws.com");
+        assert_eq!(requests[0].region, Region::UsEast1);
+        assert_eq!(requests[1].hostname(), "data.iot.eu-west-1.amazonaws.com");
+        assert_eq!(requests[1].service, "iotdata");
+        assert_eq!(requests[2].hostname(), "localhost:9324");
+        // regions known by their name only are resolved as the others
+        assert_eq!(requests[3].hostname(), "sqs.ap-southeast-3.amazonaws.com");
+        assert_eq!(requests[3].region, named);
+    }
+
+    #[tokio::test]
+    async fn sends_requests_to_their_own_endpoints_without_a_resolver() {
+        let (dispatcher, requests) = SequenceDispatcher::new(vec![Ok((200, "")); 5]);
+        let client = Client::new_with(credentials(), dispatcher);
+        let mut iot = SignedRequest::new("POST", "iotdata", &Region::EuWest1, "/");
+        iot.set_endpoint_prefix("data.iot".to_owned());
+        let sent = vec![
+            SignedRequest::new("POST", "iam", &Region::EuWest1, "/"),
+            SignedRequest::new("GET", "s3", &Region::UsEast1, "/bucket"),
+            SignedRequest::new("GET", "s3", &Region::EuWest1, "/bucket"),
+            SignedRequest::new("POST", "sqs", &Region::CnNorth1, "/"),
+            iot,
+        ];
+        let expected: Vec<_> = sent
+            .iter()
+            .map(|request| {
+                (
+                    request.hostname(),
+                    request.region.clone(),
+                    request.service.clone(),
+                )
+            })
+            .collect();
+        for request in sent {
+            client.sign_and_dispatch(request).await.unwrap();
+        }
+
+        let requests = requests.lock().unwrap();
+        let dispatched: Vec<_> = requests
+            .iter()
+            .map(|request| {
+                (
+                    request.hostname(),
+                    request.region.clone(),
+                    request.service.clone(),
+                )
+            })
+            .collect();
+        assert_eq!(dispatched, expected);
+        assert_eq!(requests[0].hostname(), "iam.amazonaws.com");
+        assert_eq!(requests[0].region, Region::EuWest1);
+        assert_eq!(requests[2].hostname(), "s3-eu-west-1.amazonaws.com");
+    }
+
+    #[tokio::test]
+    async fn resolves_named_regions_without_a_resolver() {
+        let (dispatcher, requests) = SequenceDispatcher::new(vec![Ok((200, "")), Ok((200, ""))]);
+        let client = Client::new_with(credentials(), dispatcher);
+        let named = Region::Named("ap-southeast-3".to_owned());
+        client
+            .sign_and_dispatch(SignedRequest::new("POST", "sqs", &named, "/"))
+            .await
+            .unwrap();
+        client
+            .sign_and_dispatch(SignedRequest::new("POST", "iam", &named, "/"))
+            .await
+            .unwrap();
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests[0].hostname(), "sqs.ap-southeast-3.amazonaws.com");
+        assert_eq!(requests[0].region, named);
+        assert_eq!(requests[1].hostname(), "iam.amazonaws.com");
+        assert_eq!(requests[1].region, Region::UsEast1);
+    }
+
+    #[tokio::test]
+    async fn endpoint_resolvers_can_be_overridden() {
+        struct LocalResolver;
+
+        impl EndpointResolver for LocalResolver {
+            fn resolve(
+                &self,
+                service: &str,
+                region: &str,
+            ) -> Result<crate::endpoint::Endpoint, ResolveEndpointError> {
+                if region == "eu-west-1" {
+                    Ok(crate::endpoint::Endpoint {
+                        hostname: format!("{}.local", service),
+                        signing_region: None,
+                        signing_service: None,
+                    })
+                } else {
+                    Err(ResolveEndpointError::new("only eu-west-1 is local"))
+                }
+            }
+        }
+
+        let (dispatcher, requests) = SequenceDispatcher::new(vec![Ok((200, ""))]);
+        let client =
+            Client::new_with(credentials(), dispatcher).with_endpoint_resolver(LocalResolver);
+        client
+            .sign_and_dispatch(SignedRequest::new("POST", "sqs", &Region::EuWest1, "/"))
+            .await
+            .unwrap();
+        assert_eq!(requests.lock().unwrap()[0].hostname(), "sqs.local");
+
+        let result = client
+            .sign_and_dispatch(SignedRequest::new("POST", "sqs", &Region::UsEast1, "/"))
+            .await;
+        assert_eq!(
+            result.err(),
+            Some(SignAndDispatchError::Endpoint(ResolveEndpointError::new(
+                "only eu-west-1 is local"
+            )))
+        );
+    }
+
+    #[tokio::test]
+    async fn calls_interceptors_in_order() {
+        /// Records the hooks it's called at, and marks requests and responses with its name.
+        struct Recorder {
+            name: &'static str,
+            calls: Arc<Mutex<Vec<String>>>,
+        }
+
+        impl Interceptor for Recorder {
+            fn before_sign(&self, request: &mut SignedRequest) {
+                self.calls
+                    .lock()
+                    .unwrap()
+                    .push(format!("{} before_sign", self.name));
+                request.add_header("x-signed", self.name);
+            }
+
+            fn after_sign(&self, request: &SignedRequest) {
+                assert!(request.headers().contains_key("authorization"));
+                self.calls
+                    .lock()
+                    .unwrap()
+                    .push(format!("{} after_sign", self.name));
+            }
+
+            fn before_dispatch(&self, request: &mut SignedRequest) {
+                self.calls
+                    .lock()
+                    .unwrap()
+                    .push(format!("{} before_dispatch", self.name));
+                request.add_header("x-unsigned", self.name);
+            }
+
+            fn after_response(&self, response: &mut HttpResponse) {
+                self.calls
+                    .lock()
+                    .unwrap()
+                    .push(format!("{} after_response", self.name));
+                response
+                    .headers
+                    .append("x-intercepted", self.name.to_owned());
+            }
+        }
+
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let (dispatcher, requests) = SequenceDispatcher::new(vec![Ok((500, "")), Ok((200, ""))]);
+        let client = Client::new_with_retry_policy(credentials(), dispatcher, fast_retries())
+            .with_interceptor(Recorder {
+                name: "first",
+                calls: calls.clone(),
+            })
+            .with_interceptor(Recorder {
+                name: "second",
+                calls: calls.clone(),
+            });
+        let response = client
+            .sign_and_dispatch(SignedRequest::new("GET", "s3", &Region::UsEast1, "/bucket"))
+            .await
+            .unwrap();
+
+        let hooks = [
+            "first before_sign",
+            "second before_sign",
+            "first after_sign",
+            "second after_sign",
+            "first before_dispatch",
+            "second before_dispatch",
+            "first after_response",
+            "second after_response",
+        ];
+        // interceptors are called at every attempt
+        assert_eq!(*calls.lock().unwrap(), [hooks, hooks].concat());
+        let requests = requests.lock().unwrap();
+        let signed_headers =
+            String::from_utf8(requests[1].headers()["authorization"][0].clone()).unwrap();
+        assert!(signed_headers.contains("x-signed"));
+        assert!(!signed_headers.contains("x-unsigned"));
+        assert_eq!(requests[1].headers()["x-signed"].len(), 2);
+        assert_eq!(requests[1].headers()["x-unsigned"].len(), 2);
+        assert_eq!(
+            response
+                .headers
+                .get_all("x-intercepted")
+                .iter()
+                .collect::<Vec<_>>(),
+            ["first", "second"]
+        );
+    }
+
+    #[tokio::test]
+    async fn records_metrics_of_calls() {
+        struct Recorder(Arc<Mutex<Vec<CallMetrics>>>);
+
+        impl MetricsSink for Recorder {
+            fn record(&self, metrics: &CallMetrics) {
+                self.0.lock().unwrap().push(metrics.clone());
+            }
+        }
+
+        let recorded = Arc::new(Mutex::new(Vec::new()));
+        let (dispatcher, _) = SequenceDispatcher::new(vec![
+            Ok((500, "")),
+            Ok((200, "{}")),
+            Err(HttpDispatchError::new("connection reset".to_owned())),
+        ]);
+        let client = Client::new_with_retry_policy(credentials(), dispatcher, fast_retries())
+            .with_metrics_sink(Recorder(recorded.clone()));
+        let mut request = SignedRequest::new("POST", "dynamodb", &Region::UsEast1, "/");
+        request.set_operation("GetItem");
+        request.set_payload(Some(b"{}".to_vec()));
+        client.sign_and_dispatch(request).await.unwrap();
+        let mut request = SignedRequest::new("PUT", "s3", &Region::EuWest1, "/bucket/key");
+        request.set_payload_stream(ByteStream::from(b"data".to_vec()));
+        assert!(client.sign_and_dispatch(request).await.is_err());
+
+        let recorded = recorded.lock().unwrap();
+        assert_eq!(recorded.len(), 2);
+        assert_eq!(recorded[0].service, "dynamodb");
+        assert_eq!(recorded[0].operation.as_deref(), Some("GetItem"));
+        assert_eq!(recorded[0].region, "us-east-1");
+        assert_eq!(recorded[0].attempts, 2);
+        assert_eq!(recorded[0].retries(), 1);
+        assert_eq!(recorded[0].status, Some(200));
+        assert!(recorded[0].is_success());
+        assert_eq!(recorded[0].bytes_sent, Some(2));
+        // streaming payloads aren't retried
+        assert_eq!(recorded[1].operation, None);
+        assert_eq!(recorded[1].attempts, 1);
+        assert_eq!(recorded[1].status, None);
+        assert!(!recorded[1].is_success());
+        assert_eq!(recorded[1].bytes_sent, Some(4));
+    }
+
+    #[test]
+    fn shared_clients_are_kept_apart() {
+        let shared = Client::shared();
+        assert!(Arc::ptr_eq(&shared.inner, &Client::shared().inner));
+        assert!(!Arc::ptr_eq(
+            &shared.inner,
+            &Client::shared_with_retry_from_env().inner
+        ));
+    }
+}
@@ -0,0 +1,492 @@
+//! Resolution of the endpoints requests are sent to.
+//!
+//! Clients given an `EndpointResolver` with `Client::with_endpoint_resolver` resolve the endpoints
+//! of the requests they send with it, unless their hostname is set or their region is custom.
+//! Without one, they only resolve those of regions known by their name, `Region::Named`, with the
+//! `DefaultEndpointResolver`. `DefaultEndpointResolver` resolves them from the
+//! `endpoints.json` of botocore, which knows of the endpoints of the services in every region of
+//! every partition, including the FIPS and dual-stack ones.
+//!
+//! # Examples
+//!
+//! ```
+//! use rusoto_core::endpoint::{DefaultEndpointResolver, EndpointResolver};
+//!
+//! let resolver = DefaultEndpointResolver::new();
+//! let endpoint = resolver.resolve("iam", "eu-west-1").unwrap();
+//! assert_eq!(endpoint.hostname, "iam.amazonaws.com");
+//! assert_eq!(endpoint.signing_region.as_deref(), Some("us-east-1"));
+//!
+//! let fips = resolver.with_fips(true).resolve("sts", "us-east-1").unwrap();
+//! assert_eq!(fips.hostname, "sts-fips.us-east-1.amazonaws.com");
+//! ```
+//!
+//! Endpoint URLs configured in the environment or in `~/.aws/config`, such as the one of a local
+//! DynamoDB, take precedence over them in the clients created with `new`, which send their
+//! requests to the custom region `with_configured_endpoint` returns.
+
+use std::collections::HashMap;
+use std::env;
+use std::error::Error;
+use std::fmt;
+
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::de::IgnoredAny;
+use serde::{Deserialize, Deserializer};
+
+use crate::credential::ProfileProvider;
+use crate::Region;
+
+const AWS_ENDPOINT_URL: &str = "AWS_ENDPOINT_URL";
+const AWS_IGNORE_CONFIGURED_ENDPOINT_URLS: &str = "AWS_IGNORE_CONFIGURED_ENDPOINT_URLS";
+
+lazy_static! {
+    static ref ENDPOINTS: Endpoints = serde_json::from_str(include_str!("endpoints.json"))
+        .expect("failed to parse the endpoints of services");
+}
+
+/// An endpoint requests are sent to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Endpoint {
+    /// Hostname of the endpoint
+    pub hostname: String,
+    /// Region requests sent to the endpoint are signed for, if it isn't the region they're about
+    pub signing_region: Option<String>,
+    /// Name requests sent to the endpoint are signed with, if it isn't the one of their service
+    pub signing_service: Option<String>,
+}
+
+/// Resolves the endpoints of services in regions.
+pub trait EndpointResolver {
+    /// Resolves the endpoint of the service with the endpoint prefix `service` in `region`.
+    fn resolve(&self, service: &str, region: &str) -> Result<Endpoint, ResolveEndpointError>;
+}
+
+/// An error produced when the endpoint of a service can't be resolved.
+#[derive(Debug, PartialEq)]
+pub struct ResolveEndpointError {
+    message: String,
+}
+
+impl ResolveEndpointError {
+    /// Creates an error with a message explaining why the endpoint can't be resolved.
+    pub fn new(message: &str) -> Self {
+        ResolveEndpointError {
+            message: message.to_owned(),
+        }
+    }
+}
+
+impl Error for ResolveEndpointError {}
+
+impl fmt::Display for ResolveEndpointError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+/// Resolves endpoints from the `endpoints.json` of botocore, embedded in the crate.
+///
+/// Regions are matched against the partitions they're in, by name or regular expression, so that
+/// regions launched after the crate was released resolve to the endpoints of their partition.
+/// Global services resolve to their endpoint in the partition, signed for the region it's in.
+///
+/// As botocore does by default, S3 in `us-east-1` resolves to its legacy global endpoint,
+/// `s3.amazonaws.com`, rather than `s3.us-east-1.amazonaws.com`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DefaultEndpointResolver {
+    fips: bool,
+    dual_stack: bool,
+}
+
+impl DefaultEndpointResolver {
+    /// Creates a resolver of the regular endpoints of services.
+    pub fn new() -> DefaultEndpointResolver {
+        DefaultEndpointResolver::default()
+    }
+
+    /// Resolves the FIPS 140-2 validated endpoints of services, failing for services without.
+    pub fn with_fips(mut self, fips: bool) -> DefaultEndpointResolver {
+        self.fips = fips;
+        self
+    }
+
+    /// Resolves the dual-stack endpoints of services, reachable over both IPv4 and IPv6,
+    /// failing for services without.
+    pub fn with_dual_stack(mut self, dual_stack: bool) -> DefaultEndpointResolver {
+        self.dual_stack = dual_stack;
+        self
+    }
+}
+
+impl EndpointResolver for DefaultEndpointResolver {
+    fn resolve(&self, service: &str, region: &str) -> Result<Endpoint, ResolveEndpointError> {
+        let partition = ENDPOINTS
+            .partitions
+            .iter()
+            .find(|partition| partition.contains(service, region))
+            .ok_or_else(|| {
+                ResolveEndpointError::new(&format!("No partition has the region {}", region))
+            })?;
+        partition
+            .resolve(service, region, self.fips, self.dual_stack)
+            .ok_or_else(|| {
+                ResolveEndpointError::new(&format!(
+                    "No {}endpoint of {} in {}",
+                    match (self.fips, self.dual_stack) {
+                        (true, true) => "FIPS dual-stack ",
+                        (true, false) => "FIPS ",
+                        (false, true) => "dual-stack ",
+                        (false, false) => "",
+                    },
+                    service,
+                    region
+                ))
+            })
+    }
+}
+
+#[derive(Deserialize)]
+struct Endpoints {
+    partitions: Vec<Partition>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Partition {
+    dns_suffix: String,
+    #[serde(deserialize_with = "deserialize_regex")]
+    region_regex: Regex,
+    regions: HashMap<String, IgnoredAny>,
+    #[serde(default)]
+    defaults: EndpointData,
+    services: HashMap<String, ServiceData>,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ServiceData {
+    #[serde(default)]
+    defaults: EndpointData,
+    #[serde(default)]
+    endpoints: HashMap<String, EndpointData>,
+    partition_endpoint: Option<String>,
+    is_regionalized: Option<bool>,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EndpointData {
+    hostname: Option<String>,
+    credential_scope: Option<CredentialScope>,
+    #[serde(default)]
+    variants: Vec<Variant>,
+}
+
+#[derive(Deserialize)]
+struct CredentialScope {
+    region: Option<String>,
+    service: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Variant {
+    hostname: Option<String>,
+    dns_suffix: Option<String>,
+    tags: Vec<String>,
+}
+
+impl Variant {
+    fn is(&self, fips: bool, dual_stack: bool) -> bool {
+        self.tags.len() == fips as usize + dual_stack as usize
+            && self.tags.iter().all(|tag| match tag.as_str() {
+                "fips" => fips,
+                "dualstack" => dual_stack,
+                _ => false,
+            })
+    }
+}
+
+impl EndpointData {
+    fn variant(&self, fips: bool, dual_stack: bool) -> Option<&Variant> {
+        self.variants
+            .iter()
+            .find(|variant| variant.is(fips, dual_stack))
+    }
+}
+
+impl Partition {
+    fn contains(&self, service: &str, region: &str) -> bool {
+        self.regions.contains_key(region)
+            || self.region_regex.is_match(region)
+            || self
+                .services
+                .get(service)
+                .is_some_and(|service| service.endpoints.contains_key(region))
+    }
+
+    // as botocore does, the data of the endpoint taking precedence over the defaults of the
+    // service, themselves taking precedence over the defaults of the partition
+    fn resolve(
+        &self,
+        service: &str,
+        region: &str,
+        fips: bool,
+        dual_stack: bool,
+    ) -> Option<Endpoint> {
+        let default_service = ServiceData::default();
+        let service_data = self.services.get(service).unwrap_or(&default_service);
+        let endpoint_name = match service_data.partition_endpoint {
+            Some(ref partition_endpoint)
+                if !service_data.endpoints.contains_key(region)
+                    && service_data.is_regionalized == Some(false) =>
+            {
+                partition_endpoint.as_str()
+            }
+            Some(ref partition_endpoint)
+                if service == "s3" && region == "us-east-1" && !fips && !dual_stack =>
+            {
+                partition_endpoint.as_str()
+            }
+            _ => region,
+        };
+        let default_endpoint = EndpointData::default();
+        let endpoint = service_data
+            .endpoints
+            .get(endpoint_name)
+            .unwrap_or(&default_endpoint);
+        let layers = [endpoint, &service_data.defaults, &self.defaults];
+
+        let (template, dns_suffix) = if fips || dual_stack {
+            let variants: Vec<&Variant> = layers
+                .iter()
+                .filter_map(|layer| layer.variant(fips, dual_stack))
+                .collect();
+            (
+                variants
+                    .iter()
+                    .find_map(|variant| variant.hostname.as_ref())?,
+                variants
+                    .iter()
+                    .find_map(|variant| variant.dns_suffix.as_ref())
+                    .unwrap_or(&self.dns_suffix),
+            )
+        } else {
+            (
+                layers.iter().find_map(|layer| layer.hostname.as_ref())?,
+                &self.dns_suffix,
+            )
+        };
+        let credential_scope = layers
+            .iter()
+            .find_map(|layer| layer.credential_scope.as_ref());
+
+        Some(Endpoint {
+            hostname: template
+                .replace("{service}", service)
+                .replace("{region}", endpoint_name)
+                .replace("{dnsSuffix}", dns_suffix),
+            signing_region: credential_scope
+                .and_then(|scope| scope.region.clone())
+                .filter(|signing_region| signing_region != region),
+            signing_service: credential_scope
+                .and_then(|scope| scope.service.clone())
+                .filter(|signing_service| signing_service != service),
+        })
+    }
+}
+
+/// Resolves the endpoint URL configured for the service with the ID `service_id`, such as
+/// `DynamoDB`, from, in order of precedence:
+///
+/// * the `AWS_ENDPOINT_URL_<SERVICE>` environment variable, `<SERVICE>` being the ID of the
+///   service in upper case, spaces replaced by underscores, such as `AWS_ENDPOINT_URL_DYNAMODB`
+/// * the `AWS_ENDPOINT_URL` environment variable
+/// * the `endpoint_url` of the service in the `services` section the current profile of
+///   `~/.aws/config` refers to, or the `endpoint_url` of the profile
+///
+/// None is configured if `AWS_IGNORE_CONFIGURED_ENDPOINT_URLS` is set to `true`.
+pub fn configured_endpoint_url(service_id: &str) -> Option<String> {
+    if non_empty_env_var(AWS_IGNORE_CONFIGURED_ENDPOINT_URLS)
+        .is_some_and(|ignore| ignore.eq_ignore_ascii_case("true"))
+    {
+        return None;
+    }
+    let service_var = format!(
+        "{}_{}",
+        AWS_ENDPOINT_URL,
+        service_id.to_ascii_uppercase().replace(' ', "_")
+    );
+    non_empty_env_var(&service_var)
+        .or_else(|| non_empty_env_var(AWS_ENDPOINT_URL))
+        .or_else(|| ProfileProvider::endpoint_url(service_id).ok().flatten())
+}
+
+/// Returns a custom region named after `region`, with the endpoint URL configured for the
+/// service with the ID `service_id`, or `region` if none is configured.
+///
+/// Custom regions are returned as they are, their endpoint taking precedence.
+pub fn with_configured_endpoint(region: Region, service_id: &str) -> Region {
+    if let Region::Custom { .. } = region {
+        return region;
+    }
+    match configured_endpoint_url(service_id) {
+        Some(endpoint) => Region::Custom {
+            name: region.name().to_owned(),
+            endpoint,
+        },
+        None => region,
+    }
+}
+
+fn non_empty_env_var(name: &str) -> Option<String> {
+    env::var(name)
+        .ok()
+        .map(|value| value.trim().to_owned())
+        .filter(|value| !value.is_empty())
+}
+
+fn deserialize_regex<'de, D>(deserializer: D) -> Result<Regex, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let regex = String::deserialize(deserializer)?;
+    Regex::new(&regex).map_err(serde::de::Error::custom)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn endpoint(resolver: DefaultEndpointResolver, service: &str, region: &str) -> Endpoint {
+        resolver.resolve(service, region).unwrap()
+    }
+
+    #[test]
+    fn configured_endpoints() {
+        // a service of its own, unaffected by the environment of other tests
+        env::set_var("AWS_ENDPOINT_URL_RUSOTO_TEST", "http://localhost:8000");
+        assert_eq!(
+            with_configured_endpoint(Region::EuWest1, "Rusoto Test"),
+            Region::Custom {
+                name: "eu-west-1".to_owned(),
+                endpoint: "http://localhost:8000".to_owned(),
+            }
+        );
+        let custom = Region::Custom {
+            name: "eu-west-1".to_owned(),
+            endpoint: "http://localhost:9000".to_owned(),
+        };
+        assert_eq!(
+            with_configured_endpoint(custom.clone(), "Rusoto Test"),
+            custom
+        );
+        env::remove_var("AWS_ENDPOINT_URL_RUSOTO_TEST");
+    }
+
+    #[test]
+    fn regional_endpoints() {
+        let resolver = DefaultEndpointResolver::new();
+        assert_eq!(
+            endpoint(resolver, "sqs", "eu-west-1"),
+            Endpoint {
+                hostname: "sqs.eu-west-1.amazonaws.com".to_owned(),
+                signing_region: None,
+                signing_service: None,
+            }
+        );
+        assert_eq!(
+            endpoint(resolver, "sqs", "cn-north-1").hostname,
+            "sqs.cn-north-1.amazonaws.com.cn"
+        );
+        assert_eq!(
+            endpoint(resolver, "s3", "eu-west-1").hostname,
+            "s3.eu-west-1.amazonaws.com"
+        );
+        assert_eq!(
+            endpoint(resolver, "s3", "us-east-1"),
+            Endpoint {
+                hostname: "s3.amazonaws.com".to_owned(),
+                signing_region: None,
+                signing_service: None,
+            }
+        );
+        // regions unknown to the crate are matched against the partitions
+        assert_eq!(
+            endpoint(resolver, "sqs", "ap-southeast-9").hostname,
+            "sqs.ap-southeast-9.amazonaws.com"
+        );
+        assert!(resolver.resolve("sqs", "moon-base-1").is_err());
+    }
+
+    #[test]
+    fn global_endpoints() {
+        let resolver = DefaultEndpointResolver::new();
+        assert_eq!(
+            endpoint(resolver, "iam", "eu-west-1"),
+            Endpoint {
+                hostname: "iam.amazonaws.com".to_owned(),
+                signing_region: Some("us-east-1".to_owned()),
+                signing_service: None,
+            }
+        );
+        assert_eq!(endpoint(resolver, "iam", "us-east-1").signing_region, None);
+        assert_eq!(
+            endpoint(resolver, "iam", "cn-northwest-1"),
+            Endpoint {
+                hostname: "iam.cn-north-1.amazonaws.com.cn".to_owned(),
+                signing_region: Some("cn-north-1".to_owned()),
+                signing_service: None,
+            }
+        );
+    }
+
+    #[test]
+    fn fips_and_dual_stack_endpoints() {
+        let fips = DefaultEndpointResolver::new().with_fips(true);
+        assert_eq!(
+            endpoint(fips, "sts", "us-east-1").hostname,
+            "sts-fips.us-east-1.amazonaws.com"
+        );
+        assert_eq!(
+            endpoint(fips, "iam", "us-east-1").hostname,
+            "iam-fips.amazonaws.com"
+        );
+        // from the defaults of the service
+        assert_eq!(
+            endpoint(fips, "sqs", "us-gov-west-1").hostname,
+            "sqs.us-gov-west-1.amazonaws.com"
+        );
+        // from the defaults of the partition
+        assert_eq!(
+            endpoint(fips, "sqs", "us-east-1").hostname,
+            "sqs-fips.us-east-1.amazonaws.com"
+        );
+
+        let dual_stack = DefaultEndpointResolver::new().with_dual_stack(true);
+        assert_eq!(
+            endpoint(dual_stack, "s3", "eu-west-1").hostname,
+            "s3.dualstack.eu-west-1.amazonaws.com"
+        );
+        assert_eq!(
+            endpoint(dual_stack, "ec2", "eu-west-1").hostname,
+            "ec2.eu-west-1.api.aws"
+        );
+
+        let both = fips.with_dual_stack(true);
+        assert_eq!(
+            endpoint(both, "s3", "us-east-1").hostname,
+            "s3-fips.dualstack.us-east-1.amazonaws.com"
+        );
+    }
+
+    #[test]
+    fn credential_scopes() {
+        let resolver = DefaultEndpointResolver::new();
+        let iot_data = endpoint(resolver, "data.iot", "eu-west-1");
+        assert_eq!(iot_data.hostname, "data.iot.eu-west-1.amazonaws.com");
+        assert_eq!(iot_data.signing_service, Some("iotdata".to_owned()));
+    }
+}
@@ -4,8 +4,10 @@ use std::io;
 
 use crate::credential::CredentialsError;
 
+use super::event_stream::EventStreamError;
 use super::proto::xml::util::XmlParseError;
 use super::request::{BufferedHttpResponse, HttpDispatchError};
+use super::serialization::ParseTimestampError;
 use crate::client::SignAndDispatchError;
 
 /// Generic error type returned by all rusoto requests.
@@ -37,6 +39,19 @@ impl<E> From<XmlParseError> for RusotoError<E> {
     }
 }
 
+impl<E> From<EventStreamError> for RusotoError<E> {
+    fn from(err: EventStreamError) -> Self {
+        let EventStreamError(message) = err;
+        RusotoError::ParseError(message)
+    }
+}
+
+impl<E> From<ParseTimestampError> for RusotoError<E> {
+    fn from(err: ParseTimestampError) -> Self {
+        RusotoError::ParseError(err.to_string())
+    }
+}
+
 impl<E> From<serde_json::error::Error> for RusotoError<E> {
     fn from(err: serde_json::error::Error) -> Self {
         RusotoError::ParseError(err.to_string())
@@ -60,6 +75,7 @@ impl<E> From<SignAndDispatchError> for RusotoError<E> {
         match err {
             SignAndDispatchError::Credentials(e) => Self::from(e),
             SignAndDispatchError::Dispatch(e) => Self::from(e),
+            SignAndDispatchError::Endpoint(e) => RusotoError::Validation(e.to_string()),
         }
     }
 }
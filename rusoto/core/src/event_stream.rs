@@ -0,0 +1,699 @@
+//! Decoding of `application/vnd.amazon.eventstream` responses.
+//!
+//! Operations such as S3's `SelectObjectContent` or Kinesis' `SubscribeToShard` don't respond
+//! with a single document, but with a stream of binary framed messages. Every message carries
+//! a set of typed headers, saying what kind of message it is, and a payload holding the event
+//! itself, encoded the same way as the service's other responses.
+//!
+//! Service crates implement `DeserializeEvent` for the event types of those operations, and
+//! hand their responses out as an `EventStream` of events.
+
+use std::error::Error;
+use std::fmt;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use chrono::{DateTime, TimeZone, Utc};
+use futures::{ready, Stream};
+use http::{HeaderMap, StatusCode};
+use pin_project::pin_project;
+use xml::escape::escape_str_pcdata;
+use xml::reader::{EventReader, XmlEvent};
+
+use crate::error::RusotoError;
+use crate::proto::xml::util::XmlParseError;
+use crate::request::BufferedHttpResponse;
+use crate::stream::ByteStream;
+
+/// Total length, headers length and prelude checksum.
+const PRELUDE_LENGTH: usize = 12;
+/// Checksum of the whole message, following the payload.
+const MESSAGE_CRC_LENGTH: usize = 4;
+/// AWS limits payloads to 16 MiB and headers to 128 KiB.
+const MAX_MESSAGE_LENGTH: usize = (16 << 20) + (128 << 10) + PRELUDE_LENGTH + MESSAGE_CRC_LENGTH;
+
+/// Errors decoding an event stream.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EventStreamError(pub String);
+
+impl fmt::Display for EventStreamError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Error for EventStreamError {}
+
+impl From<XmlParseError> for EventStreamError {
+    fn from(err: XmlParseError) -> Self {
+        let XmlParseError(message) = err;
+        EventStreamError(message)
+    }
+}
+
+impl From<serde_json::error::Error> for EventStreamError {
+    fn from(err: serde_json::error::Error) -> Self {
+        EventStreamError(err.to_string())
+    }
+}
+
+/// Value of a message header.
+#[derive(Clone, Debug, PartialEq)]
+pub enum HeaderValue {
+    /// A boolean.
+    Bool(bool),
+    /// A signed byte.
+    Byte(i8),
+    /// A 16 bit signed integer.
+    Int16(i16),
+    /// A 32 bit signed integer.
+    Int32(i32),
+    /// A 64 bit signed integer.
+    Int64(i64),
+    /// Raw bytes.
+    ByteArray(Bytes),
+    /// A UTF-8 string.
+    String(String),
+    /// Milliseconds since the Unix epoch.
+    Timestamp(i64),
+    /// A UUID, in its binary form.
+    Uuid([u8; 16]),
+}
+
+impl HeaderValue {
+    /// The value, if it is a string.
+    pub fn as_str(&self) -> Option<&str> {
+        match *self {
+            HeaderValue::String(ref value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// The value, if it is a boolean.
+    pub fn as_bool(&self) -> Option<bool> {
+        match *self {
+            HeaderValue::Bool(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// The value, if it is an integer of any width.
+    pub fn as_i64(&self) -> Option<i64> {
+        match *self {
+            HeaderValue::Byte(value) => Some(i64::from(value)),
+            HeaderValue::Int16(value) => Some(i64::from(value)),
+            HeaderValue::Int32(value) => Some(i64::from(value)),
+            HeaderValue::Int64(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// The value, if it is a byte array.
+    pub fn as_bytes(&self) -> Option<&Bytes> {
+        match *self {
+            HeaderValue::ByteArray(ref value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// The value, if it is a timestamp.
+    pub fn as_timestamp(&self) -> Option<DateTime<Utc>> {
+        match *self {
+            HeaderValue::Timestamp(millis) => Utc.timestamp_millis_opt(millis).single(),
+            _ => None,
+        }
+    }
+}
+
+/// A single message of an event stream.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Message {
+    /// Headers of the message, in the order they were sent.
+    pub headers: Vec<(String, HeaderValue)>,
+    /// Payload of the message.
+    pub payload: Bytes,
+}
+
+impl Message {
+    /// The value of the first header named `name`.
+    pub fn header(&self, name: &str) -> Option<&HeaderValue> {
+        self.headers
+            .iter()
+            .find(|(header, _)| header == name)
+            .map(|(_, value)| value)
+    }
+
+    fn string_header(&self, name: &str) -> Option<&str> {
+        self.header(name).and_then(HeaderValue::as_str)
+    }
+
+    /// Decode the first message in `buf`, removing it from the buffer.
+    ///
+    /// Returns `None` while `buf` doesn't hold a complete message yet.
+    pub fn decode(buf: &mut BytesMut) -> Result<Option<Message>, EventStreamError> {
+        if buf.len() < PRELUDE_LENGTH {
+            return Ok(None);
+        }
+
+        let mut prelude = &buf[..PRELUDE_LENGTH];
+        let total_length = prelude.get_u32() as usize;
+        let headers_length = prelude.get_u32() as usize;
+        let prelude_crc = prelude.get_u32();
+        if crc32fast::hash(&buf[..8]) != prelude_crc {
+            return Err(EventStreamError(
+                "Event stream message prelude checksum mismatch".to_owned(),
+            ));
+        }
+        if total_length > MAX_MESSAGE_LENGTH
+            || total_length < PRELUDE_LENGTH + headers_length + MESSAGE_CRC_LENGTH
+        {
+            return Err(EventStreamError(format!(
+                "Invalid event stream message length {} with headers of length {}",
+                total_length, headers_length
+            )));
+        }
+        if buf.len() < total_length {
+            return Ok(None);
+        }
+
+        let mut message = buf.split_to(total_length).freeze();
+        let mut message_crc = message.split_off(total_length - MESSAGE_CRC_LENGTH);
+        if crc32fast::hash(&message) != message_crc.get_u32() {
+            return Err(EventStreamError(
+                "Event stream message checksum mismatch".to_owned(),
+            ));
+        }
+        message.advance(PRELUDE_LENGTH);
+        let headers = message.split_to(headers_length);
+
+        Ok(Some(Message {
+            headers: decode_headers(headers)?,
+            payload: message,
+        }))
+    }
+
+    /// Encode the message the way services send it.
+    pub fn encode(&self) -> Bytes {
+        let mut headers = BytesMut::new();
+        for (name, value) in &self.headers {
+            headers.put_u8(name.len() as u8);
+            headers.put_slice(name.as_bytes());
+            match *value {
+                HeaderValue::Bool(true) => headers.put_u8(0),
+                HeaderValue::Bool(false) => headers.put_u8(1),
+                HeaderValue::Byte(value) => {
+                    headers.put_u8(2);
+                    headers.put_i8(value);
+                }
+                HeaderValue::Int16(value) => {
+                    headers.put_u8(3);
+                    headers.put_i16(value);
+                }
+                HeaderValue::Int32(value) => {
+                    headers.put_u8(4);
+                    headers.put_i32(value);
+                }
+                HeaderValue::Int64(value) => {
+                    headers.put_u8(5);
+                    headers.put_i64(value);
+                }
+                HeaderValue::ByteArray(ref value) => {
+                    headers.put_u8(6);
+                    headers.put_u16(value.len() as u16);
+                    headers.put_slice(value);
+                }
+                HeaderValue::String(ref value) => {
+                    headers.put_u8(7);
+                    headers.put_u16(value.len() as u16);
+                    headers.put_slice(value.as_bytes());
+                }
+                HeaderValue::Timestamp(value) => {
+                    headers.put_u8(8);
+                    headers.put_i64(value);
+                }
+                HeaderValue::Uuid(ref value) => {
+                    headers.put_u8(9);
+                    headers.put_slice(value);
+                }
+            }
+        }
+
+        let total_length = PRELUDE_LENGTH + headers.len() + self.payload.len() + MESSAGE_CRC_LENGTH;
+        let mut buf = BytesMut::with_capacity(total_length);
+        buf.put_u32(total_length as u32);
+        buf.put_u32(headers.len() as u32);
+        let prelude_crc = crc32fast::hash(&buf);
+        buf.put_u32(prelude_crc);
+        buf.put_slice(&headers);
+        buf.put_slice(&self.payload);
+        let message_crc = crc32fast::hash(&buf);
+        buf.put_u32(message_crc);
+        buf.freeze()
+    }
+}
+
+fn take(bytes: &mut Bytes, len: usize) -> Result<Bytes, EventStreamError> {
+    if bytes.len() < len {
+        return Err(EventStreamError(
+            "Event stream message headers are truncated".to_owned(),
+        ));
+    }
+    Ok(bytes.split_to(len))
+}
+
+fn take_string(bytes: &mut Bytes, len: usize) -> Result<String, EventStreamError> {
+    String::from_utf8(take(bytes, len)?.to_vec())
+        .map_err(|_| EventStreamError("Event stream message header is not UTF-8".to_owned()))
+}
+
+fn decode_headers(mut bytes: Bytes) -> Result<Vec<(String, HeaderValue)>, EventStreamError> {
+    let mut headers = Vec::new();
+    while !bytes.is_empty() {
+        let name_length = take(&mut bytes, 1)?.get_u8() as usize;
+        let name = take_string(&mut bytes, name_length)?;
+        let value = match take(&mut bytes, 1)?.get_u8() {
+            0 => HeaderValue::Bool(true),
+            1 => HeaderValue::Bool(false),
+            2 => HeaderValue::Byte(take(&mut bytes, 1)?.get_i8()),
+            3 => HeaderValue::Int16(take(&mut bytes, 2)?.get_i16()),
+            4 => HeaderValue::Int32(take(&mut bytes, 4)?.get_i32()),
+            5 => HeaderValue::Int64(take(&mut bytes, 8)?.get_i64()),
+            6 => {
+                let length = take(&mut bytes, 2)?.get_u16() as usize;
+                HeaderValue::ByteArray(take(&mut bytes, length)?)
+            }
+            7 => {
+                let length = take(&mut bytes, 2)?.get_u16() as usize;
+                HeaderValue::String(take_string(&mut bytes, length)?)
+            }
+            8 => HeaderValue::Timestamp(take(&mut bytes, 8)?.get_i64()),
+            9 => {
+                let mut uuid = [0; 16];
+                uuid.copy_from_slice(&take(&mut bytes, 16)?);
+                HeaderValue::Uuid(uuid)
+            }
+            value_type => {
+                return Err(EventStreamError(format!(
+                    "Unknown event stream header type {} for header {}",
+                    value_type, name
+                )))
+            }
+        };
+        headers.push((name, value));
+    }
+    Ok(headers)
+}
+
+/// An exception or error sent in place of an event, when an operation fails after it has
+/// started responding.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ErrorMessage {
+    /// The error code, such as `InternalError` or `ResourceNotFoundException`.
+    pub code: String,
+    /// The error message.
+    pub message: String,
+}
+
+impl ErrorMessage {
+    fn from_message(message: &Message) -> Option<ErrorMessage> {
+        match message.string_header(":message-type") {
+            Some("exception") => Some(ErrorMessage {
+                code: message
+                    .string_header(":exception-type")
+                    .unwrap_or("Unknown")
+                    .to_owned(),
+                message: payload_error_message(&message.payload),
+            }),
+            Some("error") => Some(ErrorMessage {
+                code: message
+                    .string_header(":error-code")
+                    .unwrap_or("Unknown")
+                    .to_owned(),
+                message: message
+                    .string_header(":error-message")
+                    .unwrap_or_default()
+                    .to_owned(),
+            }),
+            _ => None,
+        }
+    }
+
+    /// The error as a response of a JSON protocol, for the operation's error type to parse.
+    pub fn into_json_response(self) -> BufferedHttpResponse {
+        let body = serde_json::json!({ "__type": self.code, "message": self.message });
+        self.into_response(body.to_string())
+    }
+
+    /// The error as a response of an XML protocol, for the operation's error type to parse.
+    pub fn into_xml_response(self) -> BufferedHttpResponse {
+        let body = format!(
+            "<Error><Code>{}</Code><Message>{}</Message></Error>",
+            escape_str_pcdata(&self.code),
+            escape_str_pcdata(&self.message)
+        );
+        self.into_response(body)
+    }
+
+    fn into_response(self, body: String) -> BufferedHttpResponse {
+        let mut headers = HeaderMap::<String>::default();
+        headers.insert("x-amzn-errortype", self.code);
+        BufferedHttpResponse {
+            status: StatusCode::BAD_REQUEST,
+            body: Bytes::from(body),
+            headers,
+        }
+    }
+}
+
+/// Find the message of an exception, which is serialized like the exception shape.
+fn payload_error_message(payload: &[u8]) -> String {
+    if let Ok(json) = serde_json::from_slice::<serde_json::Value>(payload) {
+        return json
+            .get("message")
+            .or_else(|| json.get("Message"))
+            .and_then(serde_json::Value::as_str)
+            .unwrap_or_default()
+            .to_owned();
+    }
+
+    let mut in_message = false;
+    for event in EventReader::new(payload) {
+        match event {
+            Ok(XmlEvent::StartElement { ref name, .. }) => {
+                in_message = name.local_name == "Message"
+            }
+            Ok(XmlEvent::Characters(message)) if in_message => return message,
+            Ok(XmlEvent::EndElement { .. }) => in_message = false,
+            Err(_) => break,
+            _ => {}
+        }
+    }
+    String::new()
+}
+
+/// Event types sent through an event stream.
+///
+/// Implemented by service crates for the event stream shapes of their operations.
+pub trait DeserializeEvent: Sized {
+    /// Error type of the operation responding with the events.
+    type Error;
+
+    /// Deserialize an event message with the given `:event-type`.
+    fn deserialize_event(event_type: &str, message: &Message) -> Result<Self, EventStreamError>;
+
+    /// Convert an exception or error sent in place of an event.
+    fn deserialize_error(error: ErrorMessage) -> RusotoError<Self::Error>;
+}
+
+/// Stream of the events sent in an `application/vnd.amazon.eventstream` response body.
+///
+/// Exceptions and errors sent by the service are returned as errors, in the same way they
+/// would be had the operation failed right away.
+#[pin_project]
+pub struct EventStream<T> {
+    #[pin]
+    body: ByteStream,
+    buffer: BytesMut,
+    finished: bool,
+    event_type: PhantomData<fn() -> T>,
+}
+
+impl<T> EventStream<T> {
+    /// Create a new `EventStream` decoding the messages of a response body.
+    pub fn new(body: ByteStream) -> EventStream<T> {
+        EventStream {
+            body,
+            buffer: BytesMut::new(),
+            finished: false,
+            event_type: PhantomData,
+        }
+    }
+}
+
+impl<T> fmt::Debug for EventStream<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "<EventStream buffered={}>", self.buffer.len())
+    }
+}
+
+fn deserialize_message<T: DeserializeEvent>(
+    message: &Message,
+) -> Option<Result<T, RusotoError<T::Error>>> {
+    if let Some(error) = ErrorMessage::from_message(message) {
+        return Some(Err(T::deserialize_error(error)));
+    }
+    match message.string_header(":message-type") {
+        Some("event") => match message.string_header(":event-type") {
+            // the response of JSON protocols leads with the non-event members of the output,
+            // which those operations don't have
+            Some("initial-response") => None,
+            Some(event_type) => Some(T::deserialize_event(event_type, message).map_err(Into::into)),
+            None => Some(Err(RusotoError::ParseError(
+                "Event stream message without an event type".to_owned(),
+            ))),
+        },
+        message_type => Some(Err(RusotoError::ParseError(format!(
+            "Unexpected event stream message type {:?}",
+            message_type
+        )))),
+    }
+}
+
+impl<T: DeserializeEvent> Stream for EventStream<T> {
+    type Item = Result<T, RusotoError<T::Error>>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        loop {
+            if *this.finished {
+                return Poll::Ready(None);
+            }
+
+            match Message::decode(this.buffer) {
+                Ok(Some(message)) => match deserialize_message(&message) {
+                    Some(event) => return Poll::Ready(Some(event)),
+                    None => continue,
+                },
+                Ok(None) => {}
+                Err(err) => {
+                    *this.finished = true;
+                    return Poll::Ready(Some(Err(err.into())));
+                }
+            }
+
+            match ready!(this.body.as_mut().poll_next(cx)) {
+                Some(Ok(bytes)) => this.buffer.extend_from_slice(&bytes),
+                Some(Err(err)) => {
+                    *this.finished = true;
+                    return Poll::Ready(Some(Err(err.into())));
+                }
+                None => {
+                    *this.finished = true;
+                    if !this.buffer.is_empty() {
+                        return Poll::Ready(Some(Err(RusotoError::ParseError(
+                            "Event stream ended in the middle of a message".to_owned(),
+                        ))));
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::{stream, StreamExt};
+
+    #[derive(Debug, PartialEq)]
+    struct Record(String);
+
+    #[derive(Debug, PartialEq)]
+    struct RecordError(String);
+
+    impl DeserializeEvent for Record {
+        type Error = RecordError;
+
+        fn deserialize_event(
+            event_type: &str,
+            message: &Message,
+        ) -> Result<Record, EventStreamError> {
+            match event_type {
+                "Records" => Ok(Record(
+                    String::from_utf8_lossy(&message.payload).into_owned(),
+                )),
+                _ => Err(EventStreamError(event_type.to_owned())),
+            }
+        }
+
+        fn deserialize_error(error: ErrorMessage) -> RusotoError<RecordError> {
+            RusotoError::Service(RecordError(error.code))
+        }
+    }
+
+    fn event(event_type: &str, payload: &'static [u8]) -> Message {
+        Message {
+            headers: vec![
+                (
+                    ":message-type".to_owned(),
+                    HeaderValue::String("event".to_owned()),
+                ),
+                (
+                    ":event-type".to_owned(),
+                    HeaderValue::String(event_type.to_owned()),
+                ),
+            ],
+            payload: Bytes::from_static(payload),
+        }
+    }
+
+    fn body(chunks: Vec<Bytes>) -> ByteStream {
+        ByteStream::new(stream::iter(chunks.into_iter().map(Ok)))
+    }
+
+    #[test]
+    fn round_trips_every_header_type() {
+        let message = Message {
+            headers: vec![
+                ("true".to_owned(), HeaderValue::Bool(true)),
+                ("false".to_owned(), HeaderValue::Bool(false)),
+                ("byte".to_owned(), HeaderValue::Byte(-8)),
+                ("short".to_owned(), HeaderValue::Int16(-16)),
+                ("int".to_owned(), HeaderValue::Int32(32)),
+                ("long".to_owned(), HeaderValue::Int64(1 << 40)),
+                (
+                    "bytes".to_owned(),
+                    HeaderValue::ByteArray(Bytes::from_static(b"\x00\xff")),
+                ),
+                ("string".to_owned(), HeaderValue::String("☃".to_owned())),
+                ("time".to_owned(), HeaderValue::Timestamp(1_582_000_000_000)),
+                ("uuid".to_owned(), HeaderValue::Uuid([7; 16])),
+            ],
+            payload: Bytes::from_static(b"{\"foo\":\"bar\"}"),
+        };
+        let mut buf = BytesMut::from(&message.encode()[..]);
+        assert_eq!(Message::decode(&mut buf), Ok(Some(message)));
+        assert!(buf.is_empty());
+    }
+
+    #[test]
+    fn waits_for_complete_messages() {
+        let encoded = event("Records", b"1,2,3\n").encode();
+        let mut buf = BytesMut::from(&encoded[..20]);
+        assert_eq!(Message::decode(&mut buf), Ok(None));
+        buf.extend_from_slice(&encoded[20..]);
+        assert!(Message::decode(&mut buf).unwrap().is_some());
+    }
+
+    #[test]
+    fn rejects_corrupted_messages() {
+        let mut encoded = event("Records", b"1,2,3\n").encode().to_vec();
+        let last = encoded.len() - 5;
+        encoded[last] ^= 1;
+        let mut buf = BytesMut::from(&encoded[..]);
+        assert!(Message::decode(&mut buf).is_err());
+
+        let mut encoded = event("Records", b"1,2,3\n").encode().to_vec();
+        encoded[2] ^= 1;
+        let mut buf = BytesMut::from(&encoded[..]);
+        assert!(Message::decode(&mut buf).is_err());
+    }
+
+    #[tokio::test]
+    async fn streams_events_split_across_chunks() {
+        let mut encoded = BytesMut::new();
+        encoded.extend_from_slice(&event("initial-response", b"{}").encode());
+        encoded.extend_from_slice(&event("Records", b"1,2,3\n").encode());
+        encoded.extend_from_slice(&event("Records", b"4,5,6\n").encode());
+        let encoded = encoded.freeze();
+        let chunks = vec![encoded.slice(..30), encoded.slice(30..)];
+
+        let events: Vec<_> = EventStream::<Record>::new(body(chunks)).collect().await;
+        assert_eq!(
+            events,
+            vec![
+                Ok(Record("1,2,3\n".to_owned())),
+                Ok(Record("4,5,6\n".to_owned()))
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn returns_exceptions_and_errors() {
+        let exception = Message {
+            headers: vec![
+                (
+                    ":message-type".to_owned(),
+                    HeaderValue::String("exception".to_owned()),
+                ),
+                (
+                    ":exception-type".to_owned(),
+                    HeaderValue::String("ResourceNotFoundException".to_owned()),
+                ),
+            ],
+            payload: Bytes::from_static(b"{\"message\":\"Shard not found\"}"),
+        };
+        let error = Message {
+            headers: vec![
+                (
+                    ":message-type".to_owned(),
+                    HeaderValue::String("error".to_owned()),
+                ),
+                (
+                    ":error-code".to_owned(),
+                    HeaderValue::String("InternalError".to_owned()),
+                ),
+            ],
+            payload: Bytes::new(),
+        };
+
+        let events: Vec<_> =
+            EventStream::<Record>::new(body(vec![exception.encode(), error.encode()]))
+                .collect()
+                .await;
+        assert_eq!(
+            events,
+            vec![
+                Err(RusotoError::Service(RecordError(
+                    "ResourceNotFoundException".to_owned()
+                ))),
+                Err(RusotoError::Service(RecordError(
+                    "InternalError".to_owned()
+                )))
+            ]
+        );
+        assert_eq!(
+            ErrorMessage::from_message(&exception).unwrap().message,
+            "Shard not found"
+        );
+    }
+
+    #[tokio::test]
+    async fn fails_on_truncated_bodies() {
+        let encoded = event("Records", b"1,2,3\n").encode();
+        let events: Vec<_> = EventStream::<Record>::new(body(vec![encoded.slice(..20)]))
+            .collect()
+            .await;
+        assert_eq!(events.len(), 1);
+        assert!(events[0].is_err());
+    }
+
+    #[test]
+    fn renders_errors_as_responses() {
+        let error = ErrorMessage {
+            code: "InternalError".to_owned(),
+            message: "<oops>".to_owned(),
+        };
+        let response = error.clone().into_xml_response();
+        assert_eq!(
+            response.body_as_str(),
+            "<Error><Code>InternalError</Code><Message>&lt;oops&gt;</Message></Error>"
+        );
+        assert_eq!(
+            crate::retry::error_code(&error.into_json_response()).as_deref(),
+            Some("InternalError")
+        );
+    }
+}
@@ -0,0 +1,58 @@
+//! Hooks into the requests clients send and the responses they receive.
+//!
+//! Interceptors registered with `Client::with_interceptor` are called in the order they were
+//! registered, at every attempt of every request the client sends, so that all the generated
+//! clients sharing it can be extended without forking them:
+//!
+//! 1. `before_sign`, before the request is signed, to change what's signed
+//! 2. `after_sign`, once the request is signed, to inspect it
+//! 3. `before_dispatch`, before the request is dispatched, to add headers left out of the
+//!    signature
+//! 4. `after_response`, once the response is received, to inspect or change it before it's
+//!    parsed
+//!
+//! # Examples
+//!
+//! ```
+//! use rusoto_core::interceptor::Interceptor;
+//! use rusoto_core::request::HttpClient;
+//! use rusoto_core::signature::SignedRequest;
+//! use rusoto_core::Client;
+//! use rusoto_core::credential::DefaultCredentialsProvider;
+//!
+//! struct TraceId(String);
+//!
+//! impl Interceptor for TraceId {
+//!     fn before_dispatch(&self, request: &mut SignedRequest) {
+//!         request.remove_header("x-amzn-trace-id");
+//!         request.add_header("x-amzn-trace-id", &self.0);
+//!     }
+//! }
+//!
+//! let client = Client::new_with(
+//!     DefaultCredentialsProvider::new().unwrap(),
+//!     HttpClient::new().unwrap(),
+//! )
+//! .with_interceptor(TraceId("Root=1-5759e988-bd862e3fe1be46a994272793".to_owned()));
+//! ```
+
+use crate::request::HttpResponse;
+use crate::signature::SignedRequest;
+
+/// Hooks called by a `Client` at every attempt of the requests it sends.
+///
+/// Every hook does nothing by default.
+pub trait Interceptor: Send + Sync {
+    /// Called before `request` is signed, once its endpoint is resolved.
+    fn before_sign(&self, _request: &mut SignedRequest) {}
+
+    /// Called once `request` is signed, or completed if the client doesn't sign requests.
+    fn after_sign(&self, _request: &SignedRequest) {}
+
+    /// Called before `request` is dispatched. Changes to the signed parts of the request
+    /// invalidate its signature.
+    fn before_dispatch(&self, _request: &mut SignedRequest) {}
+
+    /// Called once `response` is received, before it's checked for errors and parsed.
+    fn after_response(&self, _response: &mut HttpResponse) {}
+}
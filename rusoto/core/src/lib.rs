@@ -29,15 +29,25 @@ mod error;
 mod future;
 mod stream;
 
+pub mod endpoint;
+pub mod event_stream;
+pub mod interceptor;
+pub mod metrics;
+pub mod pagination;
 pub mod param;
+pub mod rate_limit;
 #[doc(hidden)]
 pub mod region;
 pub mod request;
+pub mod retry;
 #[doc(hidden)]
 pub mod signature;
+#[cfg(feature = "tower")]
+pub mod tower;
+pub mod waiter;
 
 #[doc(hidden)]
-pub use crate::client::Client;
+pub use crate::client::{Client, SignAndDispatchError};
 #[doc(hidden)]
 pub mod encoding;
 #[doc(hidden)]
@@ -45,9 +55,13 @@ pub mod proto;
 #[doc(hidden)]
 pub mod serialization;
 
+pub use crate::endpoint::{DefaultEndpointResolver, EndpointResolver};
 pub use crate::error::{RusotoError, RusotoResult};
 pub use crate::future::RusotoFuture;
+pub use crate::pagination::RusotoStream;
+pub use crate::rate_limit::AdaptiveRateLimiter;
 pub use crate::region::Region;
 pub use crate::request::{DispatchSignedRequest, HttpClient, HttpConfig, HttpDispatchError};
+pub use crate::retry::RetryPolicy;
 pub use crate::stream::ByteStream;
 pub use rusoto_credential as credential;
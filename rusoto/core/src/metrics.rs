@@ -0,0 +1,89 @@
+//! Metrics of the calls clients make to AWS services.
+//!
+//! A `MetricsSink` registered with `Client::with_metrics_sink` records the metrics of every call
+//! the client makes, so that call counts, latencies, retries and bytes transferred can be
+//! reported to any metrics system.
+//!
+//! With the `tracing` feature, every call is also traced in an `aws_call` span with the same
+//! fields.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::sync::atomic::{AtomicU64, Ordering};
+//!
+//! use rusoto_core::metrics::{CallMetrics, MetricsSink};
+//! use rusoto_core::request::HttpClient;
+//! use rusoto_core::Client;
+//! use rusoto_core::credential::DefaultCredentialsProvider;
+//!
+//! #[derive(Default)]
+//! struct RetryCounter(AtomicU64);
+//!
+//! impl MetricsSink for RetryCounter {
+//!     fn record(&self, metrics: &CallMetrics) {
+//!         self.0.fetch_add(u64::from(metrics.retries()), Ordering::Relaxed);
+//!     }
+//! }
+//!
+//! let client = Client::new_with(
+//!     DefaultCredentialsProvider::new().unwrap(),
+//!     HttpClient::new().unwrap(),
+//! )
+//! .with_metrics_sink(RetryCounter::default());
+//! ```
+
+use std::time::Duration;
+
+use http::HeaderMap;
+
+/// Records the metrics of the calls a `Client` makes.
+pub trait MetricsSink: Send + Sync {
+    /// Records the metrics of a call, once its response is received or it failed.
+    fn record(&self, metrics: &CallMetrics);
+}
+
+/// Metrics of a call to an AWS service, from its first attempt to its response.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CallMetrics {
+    /// Name of the service the request is signed for, such as `s3`
+    pub service: String,
+    /// Name of the operation called, such as `GetObject`, if the request knows it
+    pub operation: Option<String>,
+    /// Name of the region of the client
+    pub region: String,
+    /// Number of attempts, the retries included
+    pub attempts: u32,
+    /// Status of the response, if one was received
+    pub status: Option<u16>,
+    /// ID AWS assigned to the request, from the `x-amzn-RequestId` or `x-amz-request-id` header
+    /// of the response
+    pub request_id: Option<String>,
+    /// Time from the first attempt to the headers of the response
+    pub latency: Duration,
+    /// Length of the payload sent at every attempt, unless it's a stream of unknown size
+    pub bytes_sent: Option<u64>,
+    /// Length of the body of the response, as its `Content-Length` header reports it
+    pub bytes_received: Option<u64>,
+}
+
+impl CallMetrics {
+    /// Returns the number of attempts retrying the first one.
+    pub fn retries(&self) -> u32 {
+        self.attempts.saturating_sub(1)
+    }
+
+    /// Returns whether a successful response was received.
+    pub fn is_success(&self) -> bool {
+        self.status
+            .is_some_and(|status| (200..300).contains(&status))
+    }
+}
+
+/// Returns the ID AWS assigned to the request answered with `headers`.
+pub(crate) fn request_id(headers: &HeaderMap<String>) -> Option<String> {
+    headers
+        .get("x-amzn-requestid")
+        .or_else(|| headers.get("x-amz-request-id"))
+        .cloned()
+}
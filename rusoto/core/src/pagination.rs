@@ -0,0 +1,160 @@
+//! Streams over the results of paginated operations.
+//!
+//! Service crates generate `<operation>_pages` and `<operation>_items` methods on their
+//! clients for every operation that returns its results one page at a time. Those methods
+//! are built on the functions in this module, which keep requesting pages, copying the
+//! continuation tokens of each response into the next request, until the service reports
+//! there are no more results.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use futures::stream::{self, Stream, StreamExt};
+
+use crate::error::RusotoError;
+
+/// Stream of pages, or of the items in them, returned by paginated operations.
+pub type RusotoStream<T, E> = Pin<Box<dyn Stream<Item = Result<T, RusotoError<E>>> + Send>>;
+
+/// Stream every page of a paginated operation.
+///
+/// `fetch` sends a single request with `client`. `next` builds the request for the
+/// following page out of the previous request and its response, returning `None` once
+/// the last page has been received. The stream also ends after the first error, and when
+/// `next` returns the same request again, which would otherwise loop forever.
+pub fn paginate<C, I, O, E, F, Fut, N>(client: C, input: I, fetch: F, next: N) -> RusotoStream<O, E>
+where
+    C: Clone + Send + 'static,
+    I: Clone + PartialEq + Send + 'static,
+    O: Send + 'static,
+    E: Send + 'static,
+    F: Fn(C, I) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<O, RusotoError<E>>> + Send + 'static,
+    N: Fn(&I, &O) -> Option<I> + Send + Sync + 'static,
+{
+    let fetch = Arc::new(fetch);
+    let next = Arc::new(next);
+    Box::pin(stream::unfold(Some(input), move |input| {
+        let client = client.clone();
+        let fetch = fetch.clone();
+        let next = next.clone();
+        async move {
+            let input = input?;
+            match fetch(client, input.clone()).await {
+                Ok(output) => {
+                    let next_input =
+                        next(&input, &output).filter(|next_input| *next_input != input);
+                    Some((Ok(output), next_input))
+                }
+                Err(err) => Some((Err(err), None)),
+            }
+        }
+    }))
+}
+
+/// Stream the items of every page of a paginated operation, as extracted by `items`.
+pub fn paginate_items<O, T, E, F>(pages: RusotoStream<O, E>, items: F) -> RusotoStream<T, E>
+where
+    O: 'static,
+    T: Send + 'static,
+    E: Send + 'static,
+    F: Fn(O) -> Vec<T> + Send + 'static,
+{
+    Box::pin(
+        pages
+            .map(move |page| {
+                let items = match page {
+                    Ok(page) => items(page).into_iter().map(Ok).collect(),
+                    Err(err) => vec![Err(err)],
+                };
+                stream::iter(items)
+            })
+            .flatten(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::TryStreamExt;
+
+    #[derive(Clone, Debug, Default, PartialEq)]
+    struct Input {
+        token: Option<usize>,
+    }
+
+    #[derive(Debug)]
+    struct Output {
+        items: Vec<usize>,
+        next_token: Option<usize>,
+    }
+
+    /// Pages of two items, with the token naming the index of the next page.
+    fn pages(count: usize) -> RusotoStream<Output, ()> {
+        paginate(
+            count,
+            Input::default(),
+            |count, input: Input| async move {
+                let page = input.token.unwrap_or(0);
+                if page >= count {
+                    return Err(RusotoError::Validation("no such page".to_owned()));
+                }
+                Ok(Output {
+                    items: vec![page * 2, page * 2 + 1],
+                    next_token: if page + 1 < count {
+                        Some(page + 1)
+                    } else {
+                        None
+                    },
+                })
+            },
+            |_, output: &Output| {
+                Some(Input {
+                    token: Some(output.next_token?),
+                })
+            },
+        )
+    }
+
+    #[tokio::test]
+    async fn follows_tokens_until_the_last_page() {
+        let pages: Vec<Output> = pages(3).try_collect().await.unwrap();
+        assert_eq!(pages.len(), 3);
+        assert_eq!(pages[2].items, vec![4, 5]);
+    }
+
+    #[tokio::test]
+    async fn streams_items_of_every_page() {
+        let items: Vec<usize> = paginate_items(pages(3), |output| output.items)
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(items, vec![0, 1, 2, 3, 4, 5]);
+    }
+
+    #[tokio::test]
+    async fn stops_when_the_token_does_not_change() {
+        let stream = paginate(
+            (),
+            Input::default(),
+            |_, _| async { Ok::<_, RusotoError<()>>(Some(1)) },
+            |_, token: &Option<usize>| Some(Input { token: *token }),
+        );
+        let pages: Vec<Option<usize>> = stream.try_collect().await.unwrap();
+        assert_eq!(pages.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn ends_after_an_error() {
+        let stream = paginate(
+            (),
+            Input::default(),
+            |_, _| async { Err::<(), _>(RusotoError::<()>::Validation("boom".to_owned())) },
+            |input: &Input, _| Some(input.clone()),
+        );
+        let results: Vec<_> = stream.collect().await;
+        assert_eq!(results.len(), 1);
+        assert!(results[0].is_err());
+    }
+}
@@ -5,6 +5,10 @@
 //! Supports optional parameters for calling SQS and ETS.
 
 use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+
+use crate::serialization::TimestampFormat;
 /// Paramaters for HTTP Request stored as a `BTreeMap`
 pub type Params = BTreeMap<String, Option<String>>;
 
@@ -74,3 +78,11 @@ impl ToParam for String {
         self.clone()
     }
 }
+
+impl ToParam for DateTime<Utc> {
+    /// Converts a timestamp to an ISO 8601 paramater, the format both query services and
+    /// query strings default to
+    fn to_param(&self) -> String {
+        TimestampFormat::Iso8601.format(self)
+    }
+}
@@ -3,12 +3,15 @@
 //! Wraps an XML stack via traits.
 //! Also provides a method of supplying an XML stack from a file for testing purposes.
 
+use chrono::{DateTime, Utc};
 use std::collections::HashMap;
 use std::iter::Peekable;
 use std::num::ParseIntError;
 use xml;
 use xml::reader::{Events, XmlEvent};
 
+use crate::serialization::{parse_timestamp, ParseTimestampError};
+
 /// generic Error for XML parsing
 #[derive(Debug)]
 pub struct XmlParseError(pub String);
@@ -66,6 +69,12 @@ impl<'b> Next for XmlResponse<'b> {
     }
 }
 
+impl From<ParseTimestampError> for XmlParseError {
+    fn from(e: ParseTimestampError) -> XmlParseError {
+        XmlParseError(e.to_string())
+    }
+}
+
 impl From<ParseIntError> for XmlParseError {
     fn from(_e: ParseIntError) -> XmlParseError {
         XmlParseError::new("ParseIntError")
@@ -98,6 +107,11 @@ pub fn characters<T: Peek + Next>(stack: &mut T) -> Result<String, XmlParseError
     }
 }
 
+/// return some XML Characters as a timestamp, in any of the formats AWS services use
+pub fn timestamp_characters<T: Peek + Next>(stack: &mut T) -> Result<DateTime<Utc>, XmlParseError> {
+    Ok(parse_timestamp(&characters(stack)?)?)
+}
+
 /// get the name of the current element in the stack.  throw a parse error if it's not a `StartElement`
 pub fn peek_at_name<T: Peek + Next>(stack: &mut T) -> Result<String, XmlParseError> {
     let current = stack.peek();
@@ -0,0 +1,290 @@
+//! Client side rate limiting for throttled services.
+//!
+//! An `AdaptiveRateLimiter` is a token bucket whose fill rate follows the throttling
+//! responses of the service. Until the first throttling response it lets every request
+//! through. From then on every attempt has to take a token from the bucket: a throttling
+//! response cuts the fill rate to a fraction of the measured sending rate and empties the
+//! bucket, while successful responses raise the fill rate again, linearly over time, until
+//! `max_rate` is reached and limiting switches itself off.
+//!
+//! The limiter is cheap to clone and clones share their state, so every `Client` (and every
+//! service client built on it) configured with the same limiter backs off together.
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use tokio::time::{self, Instant};
+
+/// Fraction of the measured sending rate the fill rate is cut to when throttled.
+const THROTTLE_BETA: f64 = 0.7;
+/// Length of the window the sending rate is measured over.
+const MEASUREMENT_INTERVAL: f64 = 0.5;
+/// Weight of the latest window when smoothing the measured sending rate.
+const MEASUREMENT_SMOOTHING: f64 = 0.8;
+
+/// Token bucket limiting the rate at which requests are sent once a service starts
+/// throttling them.
+#[derive(Clone, Debug)]
+pub struct AdaptiveRateLimiter {
+    min_rate: f64,
+    max_rate: f64,
+    rate_increase: f64,
+    state: Arc<Mutex<State>>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    enabled: bool,
+    fill_rate: f64,
+    tokens: f64,
+    last_refill: Option<Instant>,
+    throttled_rate: f64,
+    last_throttle: Option<Instant>,
+    measured_rate: f64,
+    request_count: u64,
+    last_measurement: Option<Instant>,
+}
+
+impl AdaptiveRateLimiter {
+    /// Create a limiter with a minimum rate of 0.5 and a maximum rate of 1000 requests
+    /// per second, recovering by 2 requests per second every second after being throttled.
+    pub fn new() -> AdaptiveRateLimiter {
+        AdaptiveRateLimiter {
+            min_rate: 0.5,
+            max_rate: 1000.0,
+            rate_increase: 2.0,
+            state: Arc::new(Mutex::new(State::default())),
+        }
+    }
+
+    /// Sets the rate, in requests per second, the limiter never goes below.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min_rate` is not greater than zero, as requests would never be sent again
+    /// once throttled.
+    pub fn with_min_rate(mut self, min_rate: f64) -> AdaptiveRateLimiter {
+        assert!(
+            min_rate > 0.0,
+            "the minimum rate must be greater than zero, got {}",
+            min_rate
+        );
+        self.min_rate = min_rate;
+        self
+    }
+
+    /// Sets the rate, in requests per second, at which limiting switches off again.
+    pub fn with_max_rate(mut self, max_rate: f64) -> AdaptiveRateLimiter {
+        self.max_rate = max_rate;
+        self
+    }
+
+    /// Sets by how many requests per second the rate recovers every second after a
+    /// throttling response.
+    pub fn with_rate_increase(mut self, rate_increase: f64) -> AdaptiveRateLimiter {
+        self.rate_increase = rate_increase;
+        self
+    }
+
+    /// Whether requests are currently being limited.
+    pub fn is_enabled(&self) -> bool {
+        self.state.lock().unwrap().enabled
+    }
+
+    /// The rate, in requests per second, the bucket is currently refilled at.
+    ///
+    /// Returns `None` while requests are not being limited.
+    pub fn fill_rate(&self) -> Option<f64> {
+        let state = self.state.lock().unwrap();
+        if state.enabled {
+            Some(state.fill_rate)
+        } else {
+            None
+        }
+    }
+
+    /// The number of tokens currently available in the bucket.
+    pub fn tokens(&self) -> f64 {
+        let mut state = self.state.lock().unwrap();
+        state.refill(Instant::now());
+        state.tokens
+    }
+
+    /// The smoothed rate, in requests per second, at which requests have been sent.
+    pub fn measured_rate(&self) -> f64 {
+        self.state.lock().unwrap().measured_rate
+    }
+
+    /// Wait until a request may be sent.
+    pub async fn acquire(&self) {
+        self.state.lock().unwrap().record_request(Instant::now());
+        loop {
+            let wait = {
+                let mut state = self.state.lock().unwrap();
+                if !state.enabled {
+                    return;
+                }
+                state.refill(Instant::now());
+                if state.tokens >= 1.0 {
+                    state.tokens -= 1.0;
+                    return;
+                }
+                Duration::from_secs_f64((1.0 - state.tokens) / state.fill_rate)
+            };
+            time::delay_for(wait).await;
+        }
+    }
+
+    /// Record that the service throttled a request.
+    pub fn on_throttle(&self) {
+        let now = Instant::now();
+        let mut state = self.state.lock().unwrap();
+        let sending_rate = state.sending_rate();
+        let base_rate = if state.enabled {
+            sending_rate.min(state.fill_rate)
+        } else {
+            sending_rate
+        };
+        state.fill_rate = (base_rate * THROTTLE_BETA).max(self.min_rate);
+        state.throttled_rate = state.fill_rate;
+        state.last_throttle = Some(now);
+        state.enabled = true;
+        state.tokens = 0.0;
+        state.last_refill = Some(now);
+    }
+
+    /// Record that the service successfully handled a request.
+    pub fn on_success(&self) {
+        let now = Instant::now();
+        let mut state = self.state.lock().unwrap();
+        if !state.enabled {
+            return;
+        }
+        let since_throttle = state
+            .last_throttle
+            .map_or(0.0, |last| (now - last).as_secs_f64());
+        state.refill(now);
+        state.fill_rate = state.throttled_rate + self.rate_increase * since_throttle;
+        if state.fill_rate >= self.max_rate {
+            state.enabled = false;
+            state.fill_rate = self.max_rate;
+        }
+    }
+}
+
+impl Default for AdaptiveRateLimiter {
+    /// Same as `AdaptiveRateLimiter::new()`.
+    fn default() -> AdaptiveRateLimiter {
+        AdaptiveRateLimiter::new()
+    }
+}
+
+impl State {
+    fn refill(&mut self, now: Instant) {
+        if let Some(last_refill) = self.last_refill {
+            let capacity = self.fill_rate.max(1.0);
+            let added = (now - last_refill).as_secs_f64() * self.fill_rate;
+            self.tokens = (self.tokens + added).min(capacity);
+        }
+        self.last_refill = Some(now);
+    }
+
+    fn record_request(&mut self, now: Instant) {
+        self.request_count += 1;
+        let last_measurement = *self.last_measurement.get_or_insert(now);
+        let elapsed = (now - last_measurement).as_secs_f64();
+        if elapsed >= MEASUREMENT_INTERVAL {
+            let current_rate = self.request_count as f64 / elapsed;
+            self.measured_rate = current_rate * MEASUREMENT_SMOOTHING
+                + self.measured_rate * (1.0 - MEASUREMENT_SMOOTHING);
+            self.request_count = 0;
+            self.last_measurement = Some(now);
+        }
+    }
+
+    /// The measured sending rate, falling back to the requests counted so far while the
+    /// first measurement window is still open.
+    fn sending_rate(&self) -> f64 {
+        if self.measured_rate > 0.0 {
+            self.measured_rate
+        } else {
+            self.request_count as f64 / MEASUREMENT_INTERVAL
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn unlimited_until_throttled() {
+        time::pause();
+        let limiter = AdaptiveRateLimiter::new();
+        let start = Instant::now();
+        for _ in 0..100 {
+            limiter.acquire().await;
+        }
+        assert_eq!(Instant::now(), start);
+        assert!(!limiter.is_enabled());
+        assert_eq!(limiter.fill_rate(), None);
+    }
+
+    #[tokio::test]
+    async fn throttling_drains_and_slows_down() {
+        time::pause();
+        let limiter = AdaptiveRateLimiter::new();
+        // 10 requests per second for a couple of seconds
+        for _ in 0..20 {
+            limiter.acquire().await;
+            time::advance(Duration::from_millis(100)).await;
+        }
+        assert!((limiter.measured_rate() - 10.0).abs() < 0.5);
+
+        limiter.on_throttle();
+        assert!(limiter.is_enabled());
+        let rate = limiter.fill_rate().unwrap();
+        assert!((rate - 7.0).abs() < 0.5, "unexpected rate {}", rate);
+        assert_eq!(limiter.tokens(), 0.0);
+
+        let start = Instant::now();
+        for _ in 0..7 {
+            limiter.acquire().await;
+        }
+        let elapsed = (Instant::now() - start).as_secs_f64();
+        assert!((elapsed - 7.0 / rate).abs() < 0.1, "elapsed {}", elapsed);
+    }
+
+    #[tokio::test]
+    async fn recovers_after_throttling() {
+        time::pause();
+        let limiter = AdaptiveRateLimiter::new()
+            .with_min_rate(1.0)
+            .with_max_rate(10.0)
+            .with_rate_increase(2.0);
+        limiter.on_throttle();
+        assert_eq!(limiter.fill_rate(), Some(1.0));
+
+        time::advance(Duration::from_secs(2)).await;
+        limiter.on_success();
+        assert_eq!(limiter.fill_rate(), Some(5.0));
+
+        time::advance(Duration::from_secs(3)).await;
+        limiter.on_success();
+        assert!(!limiter.is_enabled());
+    }
+
+    #[test]
+    #[should_panic(expected = "the minimum rate must be greater than zero")]
+    fn rejects_a_min_rate_of_zero() {
+        AdaptiveRateLimiter::new().with_min_rate(0.0);
+    }
+
+    #[test]
+    fn clones_share_state() {
+        let limiter = AdaptiveRateLimiter::new();
+        let clone = limiter.clone();
+        limiter.on_throttle();
+        assert!(clone.is_enabled());
+    }
+}
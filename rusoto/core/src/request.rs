@@ -24,6 +24,7 @@ use hyper::Error as HyperError;
 use hyper::{Body, Client as HyperClient, Request as HyperRequest, Response as HyperResponse};
 use lazy_static::lazy_static;
 use tokio::time;
+use xml::reader::{EventReader, XmlEvent};
 
 use log::Level::Debug;
 use log::*;
@@ -75,6 +76,31 @@ impl BufferedHttpResponse {
             _ => "unknown error",
         }
     }
+
+    /// Whether the body is an XML document whose root is an `Error` element.
+    ///
+    /// S3 reports some errors, such as those of `CompleteMultipartUpload`, in the body of
+    /// responses whose status is success.
+    pub fn is_xml_error(&self) -> bool {
+        for event in EventReader::new(self.body.as_ref()) {
+            match event {
+                Ok(XmlEvent::StartElement { name, .. }) => return name.local_name == "Error",
+                Err(_) | Ok(XmlEvent::EndDocument) => return false,
+                _ => {}
+            }
+        }
+        false
+    }
+}
+
+impl From<BufferedHttpResponse> for HttpResponse {
+    fn from(response: BufferedHttpResponse) -> HttpResponse {
+        HttpResponse {
+            status: response.status,
+            headers: response.headers,
+            body: ByteStream::from(response.body.to_vec()),
+        }
+    }
 }
 
 /// Best effort based Debug implementation to make generic error's body more readable.
@@ -102,6 +128,7 @@ impl HttpResponse {
         while let Some(try_chunk) = self.body.next().await {
             let chunk = try_chunk.map_err(|e| HttpDispatchError {
                 message: format!("Error obtaining body: {}", e),
+                kind: DispatchErrorKind::Other,
             })?;
             bytes.extend(chunk);
         }
@@ -112,7 +139,7 @@ impl HttpResponse {
         })
     }
 
-    async fn from_hyper(hyper_response: HyperResponse<Body>) -> HttpResponse {
+    pub(crate) async fn from_hyper(hyper_response: HyperResponse<Body>) -> HttpResponse {
         let status = hyper_response.status();
         let headers = hyper_response
             .headers()
@@ -143,12 +170,39 @@ impl HttpResponse {
 /// An error produced when sending the request, such as a timeout error.
 pub struct HttpDispatchError {
     message: String,
+    kind: DispatchErrorKind,
+}
+
+/// The stage at which dispatching a request failed, telling whether it may have reached the
+/// service.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DispatchErrorKind {
+    /// The request could not be built, for instance because of an invalid header, and was not
+    /// sent.
+    InvalidRequest,
+    /// No connection to the service could be established, so the request was not sent.
+    Connect,
+    /// No response arrived before the timeout of the request.
+    Timeout,
+    /// The request failed once it may have been sent, for instance because the connection was
+    /// closed before the response arrived.
+    Other,
 }
 
 impl HttpDispatchError {
     /// Construct a new HttpDispatchError for testing purposes
     pub fn new(message: String) -> HttpDispatchError {
-        HttpDispatchError { message }
+        HttpDispatchError::new_with_kind(message, DispatchErrorKind::Other)
+    }
+
+    /// Construct a new HttpDispatchError of the given kind
+    pub fn new_with_kind(message: String, kind: DispatchErrorKind) -> HttpDispatchError {
+        HttpDispatchError { message, kind }
+    }
+
+    /// The stage at which dispatching the request failed
+    pub fn kind(&self) -> DispatchErrorKind {
+        self.kind
     }
 }
 
@@ -164,6 +218,7 @@ impl From<HyperError> for HttpDispatchError {
     fn from(err: HyperError) -> HttpDispatchError {
         HttpDispatchError {
             message: err.to_string(),
+            kind: dispatch_error_kind(&err),
         }
     }
 }
@@ -172,10 +227,31 @@ impl From<IoError> for HttpDispatchError {
     fn from(err: IoError) -> HttpDispatchError {
         HttpDispatchError {
             message: err.to_string(),
+            kind: dispatch_error_kind(&err),
         }
     }
 }
 
+/// Tells the kind of a dispatch error from the hyper or I/O errors it's made of.
+fn dispatch_error_kind(err: &(dyn Error + 'static)) -> DispatchErrorKind {
+    let mut source = Some(err);
+    while let Some(err) = source {
+        if let Some(err) = err.downcast_ref::<HyperError>() {
+            if err.is_connect() {
+                return DispatchErrorKind::Connect;
+            }
+        } else if let Some(err) = err.downcast_ref::<IoError>() {
+            match err.kind() {
+                io::ErrorKind::ConnectionRefused => return DispatchErrorKind::Connect,
+                io::ErrorKind::TimedOut => return DispatchErrorKind::Timeout,
+                _ => {}
+            }
+        }
+        source = err.source();
+    }
+    DispatchErrorKind::Other
+}
+
 /// Type returned from `dispatch` for a `DispatchSignedRequest` implementor
 pub type DispatchSignedRequestFuture =
     Pin<Box<dyn Future<Output = Result<HttpResponse, HttpDispatchError>> + Send>>;
@@ -295,14 +371,10 @@ impl Default for HttpConfig {
     }
 }
 
-async fn http_client_dispatch<C>(
-    client: HyperClient<C, Body>,
+/// Translates a signed request to the hyper request sending it.
+pub(crate) fn hyper_request(
     request: SignedRequest,
-    timeout: Option<Duration>,
-) -> Result<HttpResponse, HttpDispatchError>
-where
-    C: Connect + Send + Sync + Clone + 'static,
-{
+) -> Result<HyperRequest<Body>, HttpDispatchError> {
     let hyper_method = match request.method().as_ref() {
         "POST" => Method::POST,
         "PUT" => Method::PUT,
@@ -312,6 +384,7 @@ where
         v => {
             return Err(HttpDispatchError {
                 message: format!("Unsupported HTTP verb {}", v),
+                kind: DispatchErrorKind::InvalidRequest,
             });
         }
     };
@@ -324,6 +397,7 @@ where
             Err(err) => {
                 return Err(HttpDispatchError {
                     message: format!("error parsing header name: {}", err),
+                    kind: DispatchErrorKind::InvalidRequest,
                 });
             }
         };
@@ -333,6 +407,7 @@ where
                 Err(err) => {
                     return Err(HttpDispatchError {
                         message: format!("error parsing header value: {}", err),
+                        kind: DispatchErrorKind::InvalidRequest,
                     });
                 }
             };
@@ -375,29 +450,56 @@ where
 
     let mut http_request = try_http_request.map_err(|err| HttpDispatchError {
         message: format!("error building request: {}", err),
+        kind: DispatchErrorKind::InvalidRequest,
     })?;
 
     *http_request.headers_mut() = hyper_headers;
+    Ok(http_request)
+}
 
-    let f = client.request(http_request);
-
+/// Awaits the response to a request sent by `f`, failing if it takes longer than `timeout`.
+pub(crate) async fn send_with_timeout<F, E>(
+    f: F,
+    timeout: Option<Duration>,
+) -> Result<HttpResponse, HttpDispatchError>
+where
+    F: Future<Output = Result<HyperResponse<Body>, E>>,
+    E: Into<Box<dyn Error + Send + Sync>>,
+{
     let try_resp = match timeout {
         None => f.await,
         Some(duration) => match time::timeout(duration, f).await {
             Err(_e) => {
                 return Err(HttpDispatchError {
                     message: "Timeout while dispatching request".to_owned(),
+                    kind: DispatchErrorKind::Timeout,
                 })
             }
             Ok(try_req) => try_req,
         },
     };
-    let resp = try_resp.map_err(|e| HttpDispatchError {
-        message: format!("Error during dispatch: {}", e),
+    let resp = try_resp.map_err(|e| {
+        let err = e.into();
+        HttpDispatchError {
+            message: format!("Error during dispatch: {}", err),
+            kind: dispatch_error_kind(&*err),
+        }
     })?;
     Ok(HttpResponse::from_hyper(resp).await)
 }
 
+async fn http_client_dispatch<C>(
+    client: HyperClient<C, Body>,
+    request: SignedRequest,
+    timeout: Option<Duration>,
+) -> Result<HttpResponse, HttpDispatchError>
+where
+    C: Connect + Send + Sync + Clone + 'static,
+{
+    let http_request = hyper_request(request)?;
+    send_with_timeout(client.request(http_request), timeout).await
+}
+
 impl<C> DispatchSignedRequest for HttpClient<C>
 where
     C: Connect + Clone + Send + Sync + 'static,
@@ -0,0 +1,447 @@
+//! Retry policies for requests dispatched by a `Client`.
+//!
+//! A `RetryPolicy` decides how many times a request is attempted and how long to wait
+//! between attempts. Delays grow exponentially from a base delay up to a maximum and are
+//! randomized with "full jitter", so that many callers throttled at the same time do not
+//! retry in lockstep.
+//!
+//! Whether a failed attempt is worth retrying is decided by a `ClassifyRetry`
+//! implementation. The `DefaultRetryClassifier` understands the throttling and transient
+//! error codes returned by AWS services across all protocols.
+//!
+//! A policy can also carry an `AdaptiveRateLimiter`, which slows down all requests sent
+//! through it once the service starts throttling them.
+
+use std::cmp;
+use std::env;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+use rand::Rng;
+use serde_json::Value;
+use xml::reader::{EventReader, XmlEvent};
+
+use crate::rate_limit::AdaptiveRateLimiter;
+use crate::request::{BufferedHttpResponse, DispatchErrorKind, HttpDispatchError};
+
+/// Error codes returned by AWS services when a request was throttled.
+pub const THROTTLING_ERROR_CODES: &[&str] = &[
+    "Throttling",
+    "ThrottlingException",
+    "ThrottledException",
+    "RequestThrottledException",
+    "RequestThrottled",
+    "TooManyRequestsException",
+    "ProvisionedThroughputExceededException",
+    "TransactionInProgressException",
+    "RequestLimitExceeded",
+    "BandwidthLimitExceeded",
+    "LimitExceededException",
+    "SlowDown",
+    "PriorRequestNotComplete",
+    "EC2ThrottledException",
+];
+
+/// Error codes returned by AWS services for failures that may succeed when retried.
+pub const TRANSIENT_ERROR_CODES: &[&str] = &[
+    "RequestTimeout",
+    "RequestTimeoutException",
+    "InternalError",
+    "InternalFailure",
+    "InternalServerError",
+    "ServiceUnavailable",
+];
+
+/// HTTP status codes that indicate a transient failure.
+const TRANSIENT_STATUS_CODES: &[u16] = &[500, 502, 503, 504];
+
+/// HTTP methods of requests that can be sent again once they may have reached the service.
+const IDEMPOTENT_METHODS: &[&str] = &["GET", "HEAD", "PUT", "DELETE", "OPTIONS"];
+
+/// How a failed attempt should be treated by a `RetryPolicy`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RetryKind {
+    /// The request was throttled by the service and may be retried after backing off.
+    Throttling,
+    /// The request failed for a transient reason and may be retried.
+    Transient,
+    /// The request failed and retrying it will not help.
+    NotRetryable,
+}
+
+impl RetryKind {
+    /// Whether an attempt classified this way should be retried.
+    pub fn is_retryable(self) -> bool {
+        self != RetryKind::NotRetryable
+    }
+}
+
+/// Decides whether a failed attempt should be retried.
+pub trait ClassifyRetry {
+    /// Classify an unsuccessful (non-2xx) response from the service.
+    fn classify_response(&self, response: &BufferedHttpResponse) -> RetryKind;
+
+    /// Classify an error that occurred while dispatching a request with the HTTP method `method`.
+    fn classify_dispatch_error(&self, method: &str, error: &HttpDispatchError) -> RetryKind;
+}
+
+/// Classifies responses based on their HTTP status and AWS error code.
+///
+/// Throttling is detected from the status code `429` and from the error codes in
+/// `THROTTLING_ERROR_CODES`. `5xx` responses, error codes in `TRANSIENT_ERROR_CODES`,
+/// connection failures and timeouts are considered transient, as are other dispatch errors
+/// of idempotent requests. Requests that could not be built, and non-idempotent requests
+/// that failed once they may have been sent, are not retried.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DefaultRetryClassifier;
+
+impl ClassifyRetry for DefaultRetryClassifier {
+    fn classify_response(&self, response: &BufferedHttpResponse) -> RetryKind {
+        if let Some(code) = error_code(response) {
+            if THROTTLING_ERROR_CODES.contains(&code.as_str()) {
+                return RetryKind::Throttling;
+            }
+            if TRANSIENT_ERROR_CODES.contains(&code.as_str()) {
+                return RetryKind::Transient;
+            }
+        }
+        if response.status.as_u16() == 429 {
+            RetryKind::Throttling
+        } else if TRANSIENT_STATUS_CODES.contains(&response.status.as_u16()) {
+            RetryKind::Transient
+        } else {
+            RetryKind::NotRetryable
+        }
+    }
+
+    fn classify_dispatch_error(&self, method: &str, error: &HttpDispatchError) -> RetryKind {
+        match error.kind() {
+            DispatchErrorKind::Connect | DispatchErrorKind::Timeout => RetryKind::Transient,
+            DispatchErrorKind::InvalidRequest => RetryKind::NotRetryable,
+            DispatchErrorKind::Other if IDEMPOTENT_METHODS.contains(&method) => {
+                RetryKind::Transient
+            }
+            DispatchErrorKind::Other => RetryKind::NotRetryable,
+        }
+    }
+}
+
+/// Extract the AWS error code from an error response.
+///
+/// Looks at the `x-amzn-ErrorType` header, then at the `__type` or `code` field of a
+/// JSON body and finally at the first `Code` element of an XML body, which covers the
+/// error formats of all AWS protocols.
+pub fn error_code(response: &BufferedHttpResponse) -> Option<String> {
+    if let Some(error_type) = response.headers.get("x-amzn-errortype") {
+        if let Some(code) = error_type.split(':').next() {
+            if !code.is_empty() {
+                return Some(code.to_owned());
+            }
+        }
+    }
+
+    if let Ok(json) = serde_json::from_slice::<Value>(&response.body) {
+        return json
+            .get("__type")
+            .or_else(|| json.get("code"))
+            .or_else(|| json.get("Code"))
+            .and_then(Value::as_str)
+            .and_then(|typ| typ.rsplit('#').next())
+            .map(ToOwned::to_owned);
+    }
+
+    let mut in_code = false;
+    for event in EventReader::new(response.body.as_ref()) {
+        match event {
+            Ok(XmlEvent::StartElement { ref name, .. }) => in_code = name.local_name == "Code",
+            Ok(XmlEvent::Characters(code)) if in_code => return Some(code.trim().to_owned()),
+            Ok(XmlEvent::EndElement { .. }) => in_code = false,
+            Err(_) => return None,
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Controls how many times a request is attempted and how long to wait between attempts.
+#[derive(Clone)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+    classifier: Arc<dyn ClassifyRetry + Send + Sync>,
+    rate_limiter: Option<AdaptiveRateLimiter>,
+}
+
+impl RetryPolicy {
+    /// Create a retry policy making at most 3 attempts, with delays starting at 100
+    /// milliseconds and capped at 20 seconds.
+    pub fn new() -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(20),
+            classifier: Arc::new(DefaultRetryClassifier),
+            rate_limiter: None,
+        }
+    }
+
+    /// Create a retry policy which never retries.
+    pub fn disabled() -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: 1,
+            ..RetryPolicy::new()
+        }
+    }
+
+    /// Create a retry policy from the `AWS_RETRY_MODE` and `AWS_MAX_ATTEMPTS` environment
+    /// variables.
+    ///
+    /// The `standard` mode retries with exponential backoff, the `adaptive` mode additionally
+    /// uses an `AdaptiveRateLimiter`. Retries are disabled if `AWS_RETRY_MODE` is unset or
+    /// set to `legacy`, unless `AWS_MAX_ATTEMPTS` is set.
+    pub fn from_env() -> RetryPolicy {
+        let max_attempts = env::var("AWS_MAX_ATTEMPTS")
+            .ok()
+            .and_then(|attempts| attempts.trim().parse::<u32>().ok());
+        let mode = env::var("AWS_RETRY_MODE").unwrap_or_default();
+        let policy = match mode.trim() {
+            "standard" => RetryPolicy::new(),
+            "adaptive" => RetryPolicy::new().with_rate_limiter(AdaptiveRateLimiter::new()),
+            _ if max_attempts.is_some() => RetryPolicy::new(),
+            _ => return RetryPolicy::disabled(),
+        };
+        match max_attempts {
+            Some(max_attempts) => policy.with_max_attempts(max_attempts),
+            None => policy,
+        }
+    }
+
+    /// Sets the maximum number of attempts, including the first one.
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> RetryPolicy {
+        self.max_attempts = cmp::max(max_attempts, 1);
+        self
+    }
+
+    /// Sets the delay the exponential backoff starts from.
+    pub fn with_base_delay(mut self, base_delay: Duration) -> RetryPolicy {
+        self.base_delay = base_delay;
+        self
+    }
+
+    /// Sets the upper bound for the delay between two attempts.
+    pub fn with_max_delay(mut self, max_delay: Duration) -> RetryPolicy {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Sets the classifier deciding which failures are retried.
+    pub fn with_classifier<C>(mut self, classifier: C) -> RetryPolicy
+    where
+        C: ClassifyRetry + Send + Sync + 'static,
+    {
+        self.classifier = Arc::new(classifier);
+        self
+    }
+
+    /// Sets the rate limiter every attempt has to acquire a token from.
+    ///
+    /// The limiter may be shared between several policies and clients.
+    pub fn with_rate_limiter(mut self, rate_limiter: AdaptiveRateLimiter) -> RetryPolicy {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// The maximum number of attempts, including the first one.
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// The delay the exponential backoff starts from.
+    pub fn base_delay(&self) -> Duration {
+        self.base_delay
+    }
+
+    /// The upper bound for the delay between two attempts.
+    pub fn max_delay(&self) -> Duration {
+        self.max_delay
+    }
+
+    /// The classifier deciding which failures are retried.
+    pub fn classifier(&self) -> &(dyn ClassifyRetry + Send + Sync) {
+        &*self.classifier
+    }
+
+    /// The rate limiter every attempt has to acquire a token from, if any.
+    pub fn rate_limiter(&self) -> Option<&AdaptiveRateLimiter> {
+        self.rate_limiter.as_ref()
+    }
+
+    /// The upper bound of the delay before the given retry, where `1` is the first retry.
+    ///
+    /// This is `base_delay * 2^(retry - 1)`, capped at `max_delay`.
+    pub fn backoff_ceiling(&self, retry: u32) -> Duration {
+        let exponent = cmp::min(retry.saturating_sub(1), 31);
+        self.base_delay
+            .checked_mul(1 << exponent)
+            .map_or(self.max_delay, |delay| cmp::min(delay, self.max_delay))
+    }
+
+    /// The delay before the given retry, where `1` is the first retry.
+    ///
+    /// The delay is chosen uniformly between zero and `backoff_ceiling(retry)`.
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let ceiling = self.backoff_ceiling(retry);
+        let ceiling_nanos = cmp::min(ceiling.as_nanos(), u128::from(u64::MAX)) as u64;
+        if ceiling_nanos == 0 {
+            return ceiling;
+        }
+        Duration::from_nanos(rand::thread_rng().gen_range(0, ceiling_nanos + 1))
+    }
+}
+
+impl Default for RetryPolicy {
+    /// Same as `RetryPolicy::new()`.
+    fn default() -> RetryPolicy {
+        RetryPolicy::new()
+    }
+}
+
+impl fmt::Debug for RetryPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("RetryPolicy")
+            .field("max_attempts", &self.max_attempts)
+            .field("base_delay", &self.base_delay)
+            .field("max_delay", &self.max_delay)
+            .field("rate_limiter", &self.rate_limiter)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::Bytes;
+    use http::{HeaderMap, StatusCode};
+
+    fn response(status: u16, body: &'static str) -> BufferedHttpResponse {
+        BufferedHttpResponse {
+            status: StatusCode::from_u16(status).unwrap(),
+            body: Bytes::from_static(body.as_bytes()),
+            headers: HeaderMap::default(),
+        }
+    }
+
+    #[test]
+    fn error_code_from_json_type() {
+        let res = response(
+            400,
+            r#"{"__type":"com.amazonaws.dynamodb.v20120810#ProvisionedThroughputExceededException","message":"slow down"}"#,
+        );
+        assert_eq!(
+            error_code(&res),
+            Some("ProvisionedThroughputExceededException".to_owned())
+        );
+    }
+
+    #[test]
+    fn error_code_from_header() {
+        let mut res = response(400, "{}");
+        res.headers.insert(
+            "x-amzn-errortype",
+            "TooManyRequestsException:http://internal.amazon.com/".to_owned(),
+        );
+        assert_eq!(
+            error_code(&res),
+            Some("TooManyRequestsException".to_owned())
+        );
+    }
+
+    #[test]
+    fn error_code_from_xml() {
+        let res = response(
+            503,
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<Error><Code>SlowDown</Code><Message>Please reduce your request rate.</Message></Error>",
+        );
+        assert_eq!(error_code(&res), Some("SlowDown".to_owned()));
+
+        let res = response(
+            400,
+            "<Response><Errors><Error><Code>RequestLimitExceeded</Code><Message>Request limit exceeded.</Message></Error></Errors></Response>",
+        );
+        assert_eq!(error_code(&res), Some("RequestLimitExceeded".to_owned()));
+    }
+
+    #[test]
+    fn default_classifier() {
+        let classifier = DefaultRetryClassifier;
+        assert_eq!(
+            classifier.classify_response(&response(400, r#"{"__type":"ThrottlingException"}"#)),
+            RetryKind::Throttling
+        );
+        assert_eq!(
+            classifier.classify_response(&response(429, "")),
+            RetryKind::Throttling
+        );
+        assert_eq!(
+            classifier.classify_response(&response(500, "")),
+            RetryKind::Transient
+        );
+        assert_eq!(
+            classifier.classify_response(&response(400, r#"{"__type":"ValidationException"}"#)),
+            RetryKind::NotRetryable
+        );
+        assert_eq!(
+            classifier.classify_response(&response(404, "")),
+            RetryKind::NotRetryable
+        );
+    }
+
+    #[test]
+    fn default_classifier_classifies_dispatch_errors_by_kind() {
+        let classifier = DefaultRetryClassifier;
+        let error = |kind| HttpDispatchError::new_with_kind("failed".to_owned(), kind);
+        assert_eq!(
+            classifier.classify_dispatch_error("POST", &error(DispatchErrorKind::Connect)),
+            RetryKind::Transient
+        );
+        assert_eq!(
+            classifier.classify_dispatch_error("POST", &error(DispatchErrorKind::Timeout)),
+            RetryKind::Transient
+        );
+        assert_eq!(
+            classifier.classify_dispatch_error("GET", &error(DispatchErrorKind::InvalidRequest)),
+            RetryKind::NotRetryable
+        );
+        assert_eq!(
+            classifier.classify_dispatch_error("PUT", &error(DispatchErrorKind::Other)),
+            RetryKind::Transient
+        );
+        assert_eq!(
+            classifier.classify_dispatch_error("POST", &error(DispatchErrorKind::Other)),
+            RetryKind::NotRetryable
+        );
+    }
+
+    #[test]
+    fn backoff_is_capped_and_jittered() {
+        let policy = RetryPolicy::new()
+            .with_base_delay(Duration::from_millis(100))
+            .with_max_delay(Duration::from_secs(1));
+        assert_eq!(policy.backoff_ceiling(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff_ceiling(2), Duration::from_millis(200));
+        assert_eq!(policy.backoff_ceiling(4), Duration::from_millis(800));
+        assert_eq!(policy.backoff_ceiling(5), Duration::from_secs(1));
+        assert_eq!(policy.backoff_ceiling(64), Duration::from_secs(1));
+        for retry in 1..10 {
+            assert!(policy.backoff(retry) <= policy.backoff_ceiling(retry));
+        }
+    }
+
+    #[test]
+    fn max_attempts_is_at_least_one() {
+        assert_eq!(RetryPolicy::new().with_max_attempts(0).max_attempts(), 1);
+        assert_eq!(RetryPolicy::disabled().max_attempts(), 1);
+    }
+}
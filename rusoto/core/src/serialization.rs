@@ -1,7 +1,9 @@
-use std::fmt::{Formatter, Result as FmtResult};
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult};
 
 use base64;
 use bytes::Bytes;
+use chrono::{DateTime, NaiveDateTime, SecondsFormat, TimeZone, Utc};
 use serde::de::{Error as SerdeError, SeqAccess, Visitor};
 use serde::ser::SerializeSeq;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
@@ -216,13 +218,373 @@ impl SerdeBlobList for Option<Vec<Bytes>> {
     }
 }
 
+/// The formats AWS services send timestamps in.
+///
+/// Services pick one per protocol and location, which botocore lets members override through
+/// `timestampFormat`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TimestampFormat {
+    /// `2020-01-01T00:00:00Z`, the default in XML and query strings.
+    Iso8601,
+    /// `Wed, 01 Jan 2020 00:00:00 GMT`, the default in headers.
+    Rfc822,
+    /// Seconds since the epoch, `1577836800`, the default in JSON.
+    UnixTimestamp,
+}
+
+impl TimestampFormat {
+    /// Format `timestamp` as a string.
+    pub fn format(self, timestamp: &DateTime<Utc>) -> String {
+        match self {
+            TimestampFormat::Iso8601 => timestamp.to_rfc3339_opts(SecondsFormat::AutoSi, true),
+            TimestampFormat::Rfc822 => timestamp.format("%a, %d %b %Y %H:%M:%S GMT").to_string(),
+            TimestampFormat::UnixTimestamp => {
+                if timestamp.timestamp_subsec_nanos() == 0 {
+                    timestamp.timestamp().to_string()
+                } else {
+                    epoch_seconds(timestamp).to_string()
+                }
+            }
+        }
+    }
+}
+
+fn epoch_seconds(timestamp: &DateTime<Utc>) -> f64 {
+    timestamp.timestamp() as f64 + f64::from(timestamp.timestamp_subsec_nanos()) / 1e9
+}
+
+fn from_epoch_seconds(seconds: f64) -> Option<DateTime<Utc>> {
+    if !seconds.is_finite() {
+        return None;
+    }
+    let whole = seconds.floor();
+    let nanos = ((seconds - whole) * 1e9).round().min(999_999_999.0);
+    Utc.timestamp_opt(whole as i64, nanos as u32).single()
+}
+
+/// A timestamp in none of the formats AWS services use.
+#[derive(Debug, PartialEq)]
+pub struct ParseTimestampError(pub String);
+
+impl Display for ParseTimestampError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "Invalid timestamp: {}", self.0)
+    }
+}
+
+impl Error for ParseTimestampError {}
+
+/// Parse a timestamp in any of the formats of `TimestampFormat`.
+///
+/// Services don't always stick to the format their model documents, so all of them are
+/// accepted wherever a timestamp is expected.
+pub fn parse_timestamp(value: &str) -> Result<DateTime<Utc>, ParseTimestampError> {
+    let value = value.trim();
+    if let Ok(timestamp) = DateTime::parse_from_rfc3339(value) {
+        return Ok(timestamp.with_timezone(&Utc));
+    }
+    if let Ok(timestamp) = DateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f%z") {
+        return Ok(timestamp.with_timezone(&Utc));
+    }
+    if let Ok(timestamp) = NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f") {
+        return Ok(Utc.from_utc_datetime(&timestamp));
+    }
+    if let Ok(timestamp) = DateTime::parse_from_rfc2822(value) {
+        return Ok(timestamp.with_timezone(&Utc));
+    }
+    value
+        .parse::<f64>()
+        .ok()
+        .and_then(from_epoch_seconds)
+        .ok_or_else(|| ParseTimestampError(value.to_owned()))
+}
+
+pub trait SerdeTimestamp: Sized {
+    fn deserialize_timestamp<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>;
+
+    fn serialize_iso8601<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer;
+
+    fn serialize_rfc822<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer;
+
+    fn serialize_unix_timestamp<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer;
+}
+
+struct TimestampVisitor;
+
+impl<'de> Visitor<'de> for TimestampVisitor {
+    type Value = DateTime<Utc>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
+        write!(formatter, "a timestamp")
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+    where
+        E: SerdeError,
+    {
+        Utc.timestamp_opt(v, 0)
+            .single()
+            .ok_or_else(|| SerdeError::custom(ParseTimestampError(v.to_string())))
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: SerdeError,
+    {
+        self.visit_f64(v as f64)
+    }
+
+    fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E>
+    where
+        E: SerdeError,
+    {
+        from_epoch_seconds(v).ok_or_else(|| SerdeError::custom(ParseTimestampError(v.to_string())))
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: SerdeError,
+    {
+        parse_timestamp(v).map_err(SerdeError::custom)
+    }
+}
+
+struct TimestampWrapper(DateTime<Utc>);
+
+impl<'de> Deserialize<'de> for TimestampWrapper {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(TimestampWrapper(
+            deserializer.deserialize_any(TimestampVisitor)?,
+        ))
+    }
+}
+
+struct FormattedTimestamp<'a>(&'a DateTime<Utc>, TimestampFormat);
+
+impl<'a> Serialize for FormattedTimestamp<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self.1 {
+            TimestampFormat::UnixTimestamp if self.0.timestamp_subsec_nanos() == 0 => {
+                serializer.serialize_i64(self.0.timestamp())
+            }
+            TimestampFormat::UnixTimestamp => serializer.serialize_f64(epoch_seconds(self.0)),
+            format => serializer.serialize_str(&format.format(self.0)),
+        }
+    }
+}
+
+impl SerdeTimestamp for DateTime<Utc> {
+    fn deserialize_timestamp<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(TimestampVisitor)
+    }
+
+    fn serialize_iso8601<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        FormattedTimestamp(self, TimestampFormat::Iso8601).serialize(serializer)
+    }
+
+    fn serialize_rfc822<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        FormattedTimestamp(self, TimestampFormat::Rfc822).serialize(serializer)
+    }
+
+    fn serialize_unix_timestamp<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        FormattedTimestamp(self, TimestampFormat::UnixTimestamp).serialize(serializer)
+    }
+}
+
+fn serialize_optional_timestamp<S>(
+    timestamp: &Option<DateTime<Utc>>,
+    format: TimestampFormat,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match *timestamp {
+        Some(ref timestamp) => FormattedTimestamp(timestamp, format).serialize(serializer),
+        None => serializer.serialize_none(),
+    }
+}
+
+impl SerdeTimestamp for Option<DateTime<Utc>> {
+    fn deserialize_timestamp<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let timestamp: Option<TimestampWrapper> = Deserialize::deserialize(deserializer)?;
+        Ok(timestamp.map(|TimestampWrapper(timestamp)| timestamp))
+    }
+
+    fn serialize_iso8601<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_optional_timestamp(self, TimestampFormat::Iso8601, serializer)
+    }
+
+    fn serialize_rfc822<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_optional_timestamp(self, TimestampFormat::Rfc822, serializer)
+    }
+
+    fn serialize_unix_timestamp<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_optional_timestamp(self, TimestampFormat::UnixTimestamp, serializer)
+    }
+}
+
+pub trait SerdeTimestampList: Sized {
+    fn deserialize_timestamp_list<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>;
+
+    fn serialize_iso8601_list<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer;
+
+    fn serialize_rfc822_list<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer;
+
+    fn serialize_unix_timestamp_list<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer;
+}
+
+fn serialize_timestamp_list<S>(
+    timestamps: &[DateTime<Utc>],
+    format: TimestampFormat,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let mut seq = serializer.serialize_seq(Some(timestamps.len()))?;
+    for timestamp in timestamps {
+        seq.serialize_element(&FormattedTimestamp(timestamp, format))?;
+    }
+    seq.end()
+}
+
+impl SerdeTimestampList for Vec<DateTime<Utc>> {
+    fn deserialize_timestamp_list<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let timestamps: Vec<TimestampWrapper> = Deserialize::deserialize(deserializer)?;
+        Ok(timestamps
+            .into_iter()
+            .map(|TimestampWrapper(timestamp)| timestamp)
+            .collect())
+    }
+
+    fn serialize_iso8601_list<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_timestamp_list(self, TimestampFormat::Iso8601, serializer)
+    }
+
+    fn serialize_rfc822_list<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_timestamp_list(self, TimestampFormat::Rfc822, serializer)
+    }
+
+    fn serialize_unix_timestamp_list<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_timestamp_list(self, TimestampFormat::UnixTimestamp, serializer)
+    }
+}
+
+impl SerdeTimestampList for Option<Vec<DateTime<Utc>>> {
+    fn deserialize_timestamp_list<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let timestamps: Option<Vec<TimestampWrapper>> = Deserialize::deserialize(deserializer)?;
+        Ok(timestamps.map(|timestamps| {
+            timestamps
+                .into_iter()
+                .map(|TimestampWrapper(timestamp)| timestamp)
+                .collect()
+        }))
+    }
+
+    fn serialize_iso8601_list<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match *self {
+            Some(ref list) => list.serialize_iso8601_list(serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    fn serialize_rfc822_list<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match *self {
+            Some(ref list) => list.serialize_rfc822_list(serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    fn serialize_unix_timestamp_list<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match *self {
+            Some(ref list) => list.serialize_unix_timestamp_list(serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     extern crate serde;
     extern crate serde_json;
 
-    use super::{SerdeBlob, SerdeBlobList};
+    use super::{
+        parse_timestamp, SerdeBlob, SerdeBlobList, SerdeTimestamp, SerdeTimestampList,
+        TimestampFormat,
+    };
     use bytes::Bytes;
+    use chrono::{DateTime, TimeZone, Utc};
 
     #[test]
     fn serialize_optional_blob_when_none() {
@@ -348,6 +710,87 @@ mod tests {
         );
     }
 
+    #[test]
+    fn formats_timestamps() {
+        let timestamp = Utc.timestamp_opt(1_577_881_805, 250_000_000).unwrap();
+
+        assert_eq!(
+            "2020-01-01T12:30:05.250Z",
+            TimestampFormat::Iso8601.format(&timestamp)
+        );
+        assert_eq!(
+            "Wed, 01 Jan 2020 12:30:05 GMT",
+            TimestampFormat::Rfc822.format(&timestamp)
+        );
+        assert_eq!(
+            "1577881805.25",
+            TimestampFormat::UnixTimestamp.format(&timestamp)
+        );
+    }
+
+    #[test]
+    fn parses_timestamps_in_any_format() {
+        let timestamp = Utc.timestamp_opt(1_577_881_805, 0).unwrap();
+
+        for value in &[
+            "2020-01-01T12:30:05Z",
+            "2020-01-01T12:30:05.000Z",
+            "2020-01-01T14:30:05+02:00",
+            "2020-01-01T12:30:05+0000",
+            "2020-01-01T12:30:05",
+            "Wed, 01 Jan 2020 12:30:05 GMT",
+            "1577881805",
+        ] {
+            assert_eq!(Ok(timestamp), parse_timestamp(value), "{}", value);
+        }
+        assert!(parse_timestamp("yesterday").is_err());
+    }
+
+    #[test]
+    fn serialize_timestamp_in_member_format() {
+        let timestamp = Utc.timestamp_opt(1_577_836_800, 0).unwrap();
+        let mut serialized = Vec::new();
+        timestamp
+            .serialize_unix_timestamp(&mut serde_json::Serializer::new(&mut serialized))
+            .unwrap();
+        assert_eq!(b"1577836800", &serialized[..]);
+
+        let mut serialized = Vec::new();
+        Some(timestamp)
+            .serialize_iso8601(&mut serde_json::Serializer::new(&mut serialized))
+            .unwrap();
+        assert_eq!(b"\"2020-01-01T00:00:00Z\"", &serialized[..]);
+
+        let mut serialized = Vec::new();
+        vec![timestamp]
+            .serialize_rfc822_list(&mut serde_json::Serializer::new(&mut serialized))
+            .unwrap();
+        assert_eq!(b"[\"Wed, 01 Jan 2020 00:00:00 GMT\"]", &serialized[..]);
+    }
+
+    #[test]
+    fn deserialize_timestamps() {
+        let mut deserializer = serde_json::Deserializer::from_str("1577836800.5");
+        let timestamp = DateTime::<Utc>::deserialize_timestamp(&mut deserializer).unwrap();
+        assert_eq!(
+            Utc.timestamp_opt(1_577_836_800, 500_000_000).unwrap(),
+            timestamp
+        );
+
+        let mut deserializer = serde_json::Deserializer::from_str("null");
+        let timestamp = Option::<DateTime<Utc>>::deserialize_timestamp(&mut deserializer).unwrap();
+        assert_eq!(None, timestamp);
+
+        let mut deserializer =
+            serde_json::Deserializer::from_str("[\"2020-01-01T00:00:00Z\", 1577836800]");
+        let timestamps =
+            Vec::<DateTime<Utc>>::deserialize_timestamp_list(&mut deserializer).unwrap();
+        assert_eq!(
+            vec![Utc.timestamp_opt(1_577_836_800, 0).unwrap(); 2],
+            timestamps
+        );
+    }
+
     fn serialize_blob_helper<B: SerdeBlob>(blob: B) -> String {
         let mut serialized_data = Vec::new();
         {
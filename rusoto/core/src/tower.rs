@@ -0,0 +1,177 @@
+//! Integration with [tower](https://github.com/tower-rs/tower), enabled by the `tower` feature.
+//!
+//! `HttpClient` and `Client` are `tower::Service`s of signed requests, so middleware such as
+//! timeouts, concurrency limits or load shedding can wrap the requests clients send. The other
+//! way around, `TowerDispatcher` sends requests through any `tower::Service` of HTTP requests,
+//! such as a hyper client wrapped in middleware, as a `DispatchSignedRequest`.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use rusoto_core::tower::TowerDispatcher;
+//! use rusoto_core::Client;
+//! use rusoto_core::credential::DefaultCredentialsProvider;
+//!
+//! let hyper_client = hyper::Client::builder().build(hyper_tls::HttpsConnector::new());
+//! let client = Client::new_with(
+//!     DefaultCredentialsProvider::new().unwrap(),
+//!     TowerDispatcher::new(hyper_client),
+//! );
+//! ```
+
+use std::error::Error;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Mutex;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use futures::future::{self, FutureExt, TryFutureExt};
+use hyper::client::connect::Connect;
+use hyper::{Body, Request as HyperRequest, Response as HyperResponse};
+use tower_service::Service;
+
+use crate::client::{Client, SignAndDispatchError};
+use crate::request::{
+    hyper_request, send_with_timeout, DispatchSignedRequest, DispatchSignedRequestFuture,
+    HttpClient, HttpDispatchError, HttpResponse,
+};
+use crate::signature::SignedRequest;
+
+type BoxError = Box<dyn Error + Send + Sync>;
+
+impl<C> Service<SignedRequest> for HttpClient<C>
+where
+    C: Connect + Clone + Send + Sync + 'static,
+{
+    type Response = HttpResponse;
+    type Error = HttpDispatchError;
+    type Future = DispatchSignedRequestFuture;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: SignedRequest) -> Self::Future {
+        self.dispatch(request, None)
+    }
+}
+
+/// Signs and dispatches requests, retrying them according to the retry policy of the client.
+impl Service<SignedRequest> for Client {
+    type Response = HttpResponse;
+    type Error = SignAndDispatchError;
+    type Future =
+        Pin<Box<dyn Future<Output = Result<HttpResponse, SignAndDispatchError>> + Send + 'static>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: SignedRequest) -> Self::Future {
+        let client = self.clone();
+        async move { client.sign_and_dispatch(request).await }.boxed()
+    }
+}
+
+/// Dispatches signed requests through a `tower::Service` of HTTP requests.
+///
+/// Every request is sent by a clone of the service, once it's ready, as `tower` services
+/// shared between tasks are.
+pub struct TowerDispatcher<S> {
+    service: Mutex<S>,
+}
+
+impl<S> TowerDispatcher<S> {
+    /// Creates a dispatcher sending requests through `service`.
+    pub fn new(service: S) -> TowerDispatcher<S> {
+        TowerDispatcher {
+            service: Mutex::new(service),
+        }
+    }
+
+    /// Returns the service requests are sent through.
+    pub fn into_inner(self) -> S {
+        self.service
+            .into_inner()
+            .expect("TowerDispatcher service lock poisoned")
+    }
+}
+
+impl<S> DispatchSignedRequest for TowerDispatcher<S>
+where
+    S: Service<HyperRequest<Body>, Response = HyperResponse<Body>> + Clone + Send + 'static,
+    S::Error: Into<BoxError>,
+    S::Future: Send,
+{
+    fn dispatch(
+        &self,
+        request: SignedRequest,
+        timeout: Option<Duration>,
+    ) -> DispatchSignedRequestFuture {
+        let mut service = self
+            .service
+            .lock()
+            .expect("TowerDispatcher service lock poisoned")
+            .clone();
+        async move {
+            let http_request = hyper_request(request)?;
+            future::poll_fn(|cx| service.poll_ready(cx))
+                .await
+                .map_err(|err| {
+                    HttpDispatchError::new(format!("Service not ready: {}", err.into()))
+                })?;
+            send_with_timeout(
+                service.call(http_request).map_err(Into::<BoxError>::into),
+                timeout,
+            )
+            .await
+        }
+        .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Region;
+
+    /// Responds with the URI of the request.
+    #[derive(Clone)]
+    struct EchoUri;
+
+    impl Service<HyperRequest<Body>> for EchoUri {
+        type Response = HyperResponse<Body>;
+        type Error = HttpDispatchError;
+        type Future = future::Ready<Result<HyperResponse<Body>, HttpDispatchError>>;
+
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, request: HyperRequest<Body>) -> Self::Future {
+            future::ok(HyperResponse::new(Body::from(request.uri().to_string())))
+        }
+    }
+
+    #[test]
+    fn clients_are_services() {
+        fn assert_service<S: Service<SignedRequest>>() {}
+        assert_service::<HttpClient>();
+        assert_service::<Client>();
+    }
+
+    #[tokio::test]
+    async fn dispatches_through_services() {
+        let mut client = Client::new_not_signing(TowerDispatcher::new(EchoUri));
+        let request = SignedRequest::new("GET", "sqs", &Region::EuWest1, "/queue");
+
+        future::poll_fn(|cx| client.poll_ready(cx)).await.unwrap();
+        let mut response = client.call(request).await.unwrap();
+        let response = response.buffer().await.unwrap();
+        assert_eq!(
+            response.body_as_str(),
+            "https://sqs.eu-west-1.amazonaws.com/queue"
+        );
+    }
+}
@@ -0,0 +1,254 @@
+//! Polling an operation until a resource reaches a desired state.
+//!
+//! Service crates generate `wait_until_<state>` methods on their clients for every waiter
+//! botocore defines, such as `wait_until_table_exists` or `wait_until_instance_running`.
+//! A waiter repeatedly calls an operation and runs the result through its acceptors, which
+//! decide whether the awaited state has been reached, can no longer be reached, or whether
+//! to try again after a delay.
+
+use std::error::Error;
+use std::fmt;
+use std::future::Future;
+use std::time::Duration;
+
+use log::debug;
+use tokio::time;
+
+use crate::error::RusotoError;
+
+/// How often, and how many times, a waiter polls its operation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WaiterConfig {
+    delay: Duration,
+    max_attempts: u32,
+}
+
+impl WaiterConfig {
+    /// Poll up to `max_attempts` times, waiting `delay` between attempts. A waiter always
+    /// polls at least once.
+    pub fn new(delay: Duration, max_attempts: u32) -> WaiterConfig {
+        WaiterConfig {
+            delay,
+            max_attempts: max_attempts.max(1),
+        }
+    }
+
+    /// Sets the time to wait between attempts.
+    pub fn with_delay(mut self, delay: Duration) -> WaiterConfig {
+        self.delay = delay;
+        self
+    }
+
+    /// Sets the number of attempts after which the waiter gives up.
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> WaiterConfig {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// The time to wait between attempts.
+    pub fn delay(&self) -> Duration {
+        self.delay
+    }
+
+    /// The number of attempts after which the waiter gives up.
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+}
+
+/// The outcome of a single attempt, as decided by a waiter's acceptors.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum WaiterState {
+    /// The awaited state has been reached.
+    Success,
+    /// The awaited state can no longer be reached.
+    Failure,
+    /// The awaited state has not been reached yet.
+    Retry,
+}
+
+/// Errors returned by waiters.
+#[derive(Debug, PartialEq)]
+pub enum WaiterError<E> {
+    /// The resource reached a state from which the awaited state can't be reached.
+    Failure(String),
+    /// The awaited state wasn't reached within the given number of attempts.
+    Timeout(u32),
+    /// Polling failed with an error none of the waiter's acceptors expects.
+    Service(RusotoError<E>),
+}
+
+impl<E> From<RusotoError<E>> for WaiterError<E> {
+    fn from(err: RusotoError<E>) -> WaiterError<E> {
+        WaiterError::Service(err)
+    }
+}
+
+impl<E: Error + 'static> fmt::Display for WaiterError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            WaiterError::Failure(ref waiter) => {
+                write!(f, "Waiter {} encountered a terminal failure state", waiter)
+            }
+            WaiterError::Timeout(attempts) => {
+                write!(f, "Waiter gave up after {} attempts", attempts)
+            }
+            WaiterError::Service(ref err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for WaiterError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            WaiterError::Service(ref err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// Whether there are `values` and all of them match `predicate`, as required by the
+/// `pathAll` acceptors of waiters.
+pub fn all_match<I, F>(values: I, mut predicate: F) -> bool
+where
+    I: IntoIterator,
+    F: FnMut(I::Item) -> bool,
+{
+    let mut any = false;
+    for value in values {
+        if !predicate(value) {
+            return false;
+        }
+        any = true;
+    }
+    any
+}
+
+/// Poll an operation until `acceptor` reports success or failure, or `config` runs out
+/// of attempts.
+///
+/// Results none of the acceptors match are retried when successful, and returned as
+/// `WaiterError::Service` otherwise.
+pub async fn wait<O, E, P, Fut, A>(
+    waiter: &str,
+    config: &WaiterConfig,
+    mut poll: P,
+    acceptor: A,
+) -> Result<(), WaiterError<E>>
+where
+    P: FnMut() -> Fut,
+    Fut: Future<Output = Result<O, RusotoError<E>>>,
+    A: Fn(&Result<O, RusotoError<E>>) -> Option<WaiterState>,
+{
+    for attempt in 1..=config.max_attempts {
+        let result = poll().await;
+        match acceptor(&result) {
+            Some(WaiterState::Success) => return Ok(()),
+            Some(WaiterState::Failure) => return Err(WaiterError::Failure(waiter.to_owned())),
+            Some(WaiterState::Retry) => {}
+            None => {
+                if let Err(err) = result {
+                    return Err(WaiterError::Service(err));
+                }
+            }
+        }
+        if attempt < config.max_attempts {
+            debug!("Waiter {} retrying in {:?}", waiter, config.delay);
+            time::delay_for(config.delay).await;
+        }
+    }
+    Err(WaiterError::Timeout(config.max_attempts))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use tokio::time::Instant;
+
+    fn status_acceptor(result: &Result<&'static str, RusotoError<()>>) -> Option<WaiterState> {
+        match result {
+            Ok("ACTIVE") => Some(WaiterState::Success),
+            Ok("FAILED") => Some(WaiterState::Failure),
+            Err(RusotoError::Validation(_)) => Some(WaiterState::Retry),
+            _ => None,
+        }
+    }
+
+    #[tokio::test]
+    async fn polls_until_success() {
+        time::pause();
+        let statuses = ["CREATING", "CREATING", "ACTIVE"];
+        let attempts = Cell::new(0);
+        let start = Instant::now();
+        let config = WaiterConfig::new(Duration::from_secs(20), 25);
+
+        let result = wait(
+            "TableExists",
+            &config,
+            || {
+                let status = statuses[attempts.get()];
+                attempts.set(attempts.get() + 1);
+                async move { Ok(status) }
+            },
+            status_acceptor,
+        )
+        .await;
+
+        assert_eq!(result, Ok(()));
+        assert_eq!(attempts.get(), 3);
+        assert!(Instant::now() - start >= Duration::from_secs(40));
+    }
+
+    #[tokio::test]
+    async fn stops_on_failure_state() {
+        let config = WaiterConfig::new(Duration::from_millis(1), 25);
+        let result = wait(
+            "TableExists",
+            &config,
+            || async { Ok("FAILED") },
+            status_acceptor,
+        )
+        .await;
+        assert_eq!(result, Err(WaiterError::Failure("TableExists".to_owned())));
+    }
+
+    #[tokio::test]
+    async fn times_out() {
+        time::pause();
+        let config = WaiterConfig::new(Duration::from_secs(5), 3);
+        let result = wait(
+            "TableExists",
+            &config,
+            || async { Err(RusotoError::Validation("not yet".to_owned())) },
+            status_acceptor,
+        )
+        .await;
+        assert_eq!(result, Err(WaiterError::Timeout(3)));
+    }
+
+    #[test]
+    fn all_match_requires_values() {
+        assert!(all_match(vec!["running", "running"], |v| v == "running"));
+        assert!(!all_match(vec!["running", "pending"], |v| v == "running"));
+        assert!(!all_match(Vec::<&str>::new(), |v| v == "running"));
+    }
+
+    #[tokio::test]
+    async fn returns_unexpected_errors() {
+        let config = WaiterConfig::new(Duration::from_millis(1), 3);
+        let result = wait(
+            "TableExists",
+            &config,
+            || async { Err(RusotoError::ParseError("garbage".to_owned())) },
+            status_acceptor,
+        )
+        .await;
+        assert_eq!(
+            result,
+            Err(WaiterError::Service(RusotoError::ParseError(
+                "garbage".to_owned()
+            )))
+        );
+    }
+}
@@ -10,8 +10,9 @@
 pub use crate::container::ContainerProvider;
 pub use crate::environment::EnvironmentProvider;
 pub use crate::instance_metadata::InstanceMetadataProvider;
-pub use crate::profile::ProfileProvider;
+pub use crate::profile::{AssumeRole, AssumeRoleProfile, ProfileProvider};
 pub use crate::secrets::Secret;
+pub use crate::sso::{ExchangeSsoToken, SsoProfile};
 pub use crate::static_provider::StaticProvider;
 pub use crate::variable::Variable;
 
@@ -22,6 +23,7 @@ mod instance_metadata;
 mod profile;
 mod request;
 mod secrets;
+mod sso;
 mod static_provider;
 #[cfg(test)]
 pub(crate) mod test_utils;
@@ -320,6 +322,17 @@ impl DefaultCredentialsProvider {
         let inner = AutoRefreshingProvider::new(ChainProvider::new())?;
         Ok(DefaultCredentialsProvider(inner))
     }
+
+    /// Creates a new thread-safe `DefaultCredentialsProvider` using the provided
+    /// `ProfileProvider`, such as one set to assume roles or to use AWS SSO sessions, whose
+    /// credentials are then reused until they expire.
+    pub fn with_profile_provider(
+        profile_provider: ProfileProvider,
+    ) -> Result<DefaultCredentialsProvider, CredentialsError> {
+        let inner =
+            AutoRefreshingProvider::new(ChainProvider::with_profile_provider(profile_provider))?;
+        Ok(DefaultCredentialsProvider(inner))
+    }
 }
 
 #[async_trait]
@@ -340,6 +353,10 @@ impl ProvideAwsCredentials for DefaultCredentialsProvider {
 ///
 /// If the sources are exhausted without finding credentials, an error is returned.
 ///
+/// The `ProfileProvider` of `ChainProvider::new` can neither assume roles nor use AWS SSO
+/// sessions, so profiles configured to are skipped: use `with_profile_provider` with a
+/// `ProfileProvider` set to, as described in its documentation.
+///
 /// The provider has a default timeout of 30 seconds. While it should work well for most setups,
 /// you can change the timeout using the `set_timeout` method.
 ///
@@ -1,10 +1,12 @@
 //! The Credentials Provider for Credentials stored in a profile inside of a Credentials file.
 
 use std::collections::HashMap;
+use std::fmt;
 use std::fs;
 use std::fs::File;
 use std::io::{BufRead, BufReader};
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 use async_trait::async_trait;
 use dirs::home_dir;
@@ -12,13 +14,74 @@ use regex::Regex;
 use serde::Deserialize;
 use tokio::process::Command;
 
-use crate::{non_empty_env_var, AwsCredentials, CredentialsError, ProvideAwsCredentials};
+use crate::sso::{self, ExchangeSsoToken, SsoProfile};
+use crate::{
+    non_empty_env_var, AwsCredentials, ContainerProvider, CredentialsError, EnvironmentProvider,
+    InstanceMetadataProvider, ProvideAwsCredentials,
+};
 
 const AWS_CONFIG_FILE: &str = "AWS_CONFIG_FILE";
 const AWS_PROFILE: &str = "AWS_PROFILE";
 const AWS_SHARED_CREDENTIALS_FILE: &str = "AWS_SHARED_CREDENTIALS_FILE";
 const DEFAULT: &str = "default";
 const REGION: &str = "region";
+const AWS_ACCESS_KEY_ID: &str = "aws_access_key_id";
+const AWS_SECRET_ACCESS_KEY: &str = "aws_secret_access_key";
+const AWS_SESSION_TOKEN: &str = "aws_session_token";
+const CREDENTIAL_PROCESS: &str = "credential_process";
+const CREDENTIAL_SOURCE: &str = "credential_source";
+const DURATION_SECONDS: &str = "duration_seconds";
+const ENDPOINT_URL: &str = "endpoint_url";
+const EXTERNAL_ID: &str = "external_id";
+const IGNORE_CONFIGURED_ENDPOINT_URLS: &str = "ignore_configured_endpoint_urls";
+const MFA_SERIAL: &str = "mfa_serial";
+const ROLE_ARN: &str = "role_arn";
+const ROLE_SESSION_NAME: &str = "role_session_name";
+const SERVICES: &str = "services";
+const SOURCE_PROFILE: &str = "source_profile";
+const SSO_ACCOUNT_ID: &str = "sso_account_id";
+const SSO_REGION: &str = "sso_region";
+const SSO_ROLE_NAME: &str = "sso_role_name";
+const SSO_START_URL: &str = "sso_start_url";
+
+/// The settings of a profile assuming an IAM role, handed to an [`AssumeRole`](trait.AssumeRole.html)
+/// along with the credentials of the profile's source.
+///
+/// See the [AWS CLI documentation][assume-role] for what they mean.
+///
+/// [assume-role]: https://docs.aws.amazon.com/cli/latest/userguide/cli-configure-role.html
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AssumeRoleProfile {
+    /// Name of the profile.
+    pub profile: String,
+    /// ARN of the role to assume (`role_arn`).
+    pub role_arn: String,
+    /// Name of the role session (`role_session_name`).
+    pub role_session_name: Option<String>,
+    /// External ID the role's trust policy requires (`external_id`).
+    pub external_id: Option<String>,
+    /// Serial number or ARN of the MFA device the role requires (`mfa_serial`).
+    pub mfa_serial: Option<String>,
+    /// Lifetime of the role session, in seconds (`duration_seconds`).
+    pub duration_seconds: Option<i64>,
+    /// Region of the profile (`region`).
+    pub region: Option<String>,
+}
+
+/// Assumes IAM roles for profiles configured with a `role_arn`.
+///
+/// This is how a [`ProfileProvider`](struct.ProfileProvider.html) resolves such profiles without
+/// `rusoto_credential` depending on STS: `rusoto_sts::StsRoleAssumer` implements it by calling
+/// `AssumeRole`.
+#[async_trait]
+pub trait AssumeRole: Send + Sync {
+    /// Assume the role `profile` configures, using `credentials` from the profile's source.
+    async fn assume_role(
+        &self,
+        credentials: AwsCredentials,
+        profile: &AssumeRoleProfile,
+    ) -> Result<AwsCredentials, CredentialsError>;
+}
 
 /// Provides AWS credentials from a profile in a credentials file, or from a credential process.
 ///
@@ -31,12 +94,55 @@ const REGION: &str = "region";
 /// best practices for your operating system.
 ///
 /// [credential_process]: https://docs.aws.amazon.com/cli/latest/topic/config-vars.html#sourcing-credentials-from-external-processes
-#[derive(Clone, Debug)]
+///
+/// # Assuming roles
+///
+/// Profiles may assume an IAM role (`role_arn`) with the credentials of another profile
+/// (`source_profile`), itself possibly assuming a role, or of the environment, the instance
+/// metadata or the ECS container (`credential_source`). The role is assumed with the
+/// [`AssumeRole`](trait.AssumeRole.html) set with `set_role_assumer`, such as
+/// `rusoto_sts::StsRoleAssumer`; without one such profiles fail to resolve.
+///
+/// # AWS SSO
+///
+/// Profiles using AWS SSO (`sso_start_url`, `sso_region`, `sso_account_id` and `sso_role_name`)
+/// get the credentials of their role with the session `aws sso login` cached in
+/// `~/.aws/sso/cache`. The session's token is exchanged for them with the
+/// [`ExchangeSsoToken`](trait.ExchangeSsoToken.html) set with `set_sso_token_exchanger`, such as
+/// `rusoto_sso::SsoTokenExchanger`.
+#[derive(Clone)]
 pub struct ProfileProvider {
     /// The File Path the Credentials File is located at.
     file_path: PathBuf,
     /// The Profile Path to parse out of the Credentials File.
     profile: String,
+    /// Assumes the roles of profiles configured with one.
+    role_assumer: Option<Arc<dyn AssumeRole>>,
+    /// Exchanges SSO session tokens for the credentials of profiles using AWS SSO.
+    sso_token_exchanger: Option<Arc<dyn ExchangeSsoToken>>,
+    /// Where `aws sso login` caches session tokens, `~/.aws/sso/cache` unless set.
+    sso_cache_dir: Option<PathBuf>,
+}
+
+impl fmt::Debug for ProfileProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ProfileProvider")
+            .field("file_path", &self.file_path)
+            .field("profile", &self.profile)
+            .field(
+                "role_assumer",
+                &self.role_assumer.as_ref().map(|_| "AssumeRole"),
+            )
+            .field(
+                "sso_token_exchanger",
+                &self
+                    .sso_token_exchanger
+                    .as_ref()
+                    .map(|_| "ExchangeSsoToken"),
+            )
+            .field("sso_cache_dir", &self.sso_cache_dir)
+            .finish()
+    }
 }
 
 impl ProfileProvider {
@@ -58,6 +164,9 @@ impl ProfileProvider {
         ProfileProvider {
             file_path: file_path.into(),
             profile: profile.into(),
+            role_assumer: None,
+            sso_token_exchanger: None,
+            sso_cache_dir: None,
         }
     }
 
@@ -88,6 +197,47 @@ impl ProfileProvider {
         })
     }
 
+    /// Attempts to resolve the endpoint URL of the service with the ID `service_id`, such as
+    /// `DynamoDB`, configured for the current profile in `~/.aws/config` or the file associated
+    /// with the `AWS_CONFIG_FILE` environment variable.
+    ///
+    /// The `endpoint_url` of the service in the `services` section the profile refers to takes
+    /// precedence over the `endpoint_url` of the profile. None is configured if the profile sets
+    /// `ignore_configured_endpoint_urls`.
+    ///
+    /// ```ini
+    /// [profile local]
+    /// services = local-services
+    ///
+    /// [services local-services]
+    /// dynamodb =
+    ///   endpoint_url = http://localhost:8000
+    /// ```
+    pub fn endpoint_url(service_id: &str) -> Result<Option<String>, CredentialsError> {
+        let location = ProfileProvider::default_config_location()?;
+        Ok(parse_config_file(&location).and_then(|config| {
+            let props = config.get(&ProfileProvider::default_profile_name())?;
+            if props
+                .get(IGNORE_CONFIGURED_ENDPOINT_URLS)
+                .is_some_and(|ignore| ignore.eq_ignore_ascii_case("true"))
+            {
+                return None;
+            }
+            // services are keyed by their ID in lower case, spaces replaced by underscores
+            let service_key = format!(
+                "{}.{}",
+                service_id.to_ascii_lowercase().replace(' ', "_"),
+                ENDPOINT_URL
+            );
+            props
+                .get(SERVICES)
+                .and_then(|services| config.get(&format!("{} {}", SERVICES, services)))
+                .and_then(|services| services.get(&service_key))
+                .or_else(|| props.get(ENDPOINT_URL))
+                .map(std::borrow::ToOwned::to_owned)
+        }))
+    }
+
     /// Default config file location:
     /// 1: if set and not empty, use the value from environment variable ```AWS_CONFIG_FILE```
     /// 2. otherwise return `~/.aws/config` (Linux/Mac) resp. `%USERPROFILE%\.aws\config` (Windows)
@@ -165,48 +315,270 @@ impl ProfileProvider {
     {
         self.profile = profile.into();
     }
+
+    /// Set what assumes the roles of profiles configured with a `role_arn`.
+    pub fn set_role_assumer<A>(&mut self, role_assumer: A)
+    where
+        A: AssumeRole + 'static,
+    {
+        self.role_assumer = Some(Arc::new(role_assumer));
+    }
+
+    /// Set what exchanges SSO session tokens for the credentials of profiles using AWS SSO.
+    pub fn set_sso_token_exchanger<E>(&mut self, sso_token_exchanger: E)
+    where
+        E: ExchangeSsoToken + 'static,
+    {
+        self.sso_token_exchanger = Some(Arc::new(sso_token_exchanger));
+    }
+
+    /// Set the directory `aws sso login` caches session tokens in, `~/.aws/sso/cache` by default.
+    pub fn set_sso_cache_dir<D>(&mut self, sso_cache_dir: D)
+    where
+        D: Into<PathBuf>,
+    {
+        self.sso_cache_dir = Some(sso_cache_dir.into());
+    }
+
+    /// Resolves the credentials of a profile not assuming a role: those of its AWS SSO role, the
+    /// output of its `credential_process`, or its keys.
+    async fn source_credentials(
+        &self,
+        profiles: &Profiles,
+        profile: &str,
+    ) -> Result<AwsCredentials, CredentialsError> {
+        if let Some(sso_profile) = profiles.sso_profile(profile)? {
+            let sso_token_exchanger = self.sso_token_exchanger.as_ref().ok_or_else(|| {
+                CredentialsError::new(format!(
+                    "Profile {} uses AWS SSO, which requires an SSO token exchanger: see ProfileProvider::set_sso_token_exchanger",
+                    profile
+                ))
+            })?;
+            let cache_dir = match self.sso_cache_dir {
+                Some(ref cache_dir) => cache_dir.clone(),
+                None => sso::default_cache_dir()?,
+            };
+            return sso::sso_credentials(&sso_profile, &cache_dir, sso_token_exchanger.as_ref())
+                .await;
+        }
+        match profiles.get(profile, CREDENTIAL_PROCESS) {
+            Some(command) => run_credential_process(command).await,
+            None => profiles.static_credentials(profile),
+        }
+    }
 }
 
 #[async_trait]
 impl ProvideAwsCredentials for ProfileProvider {
     async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
-        match ProfileProvider::default_config_location().map(|location| {
-            parse_config_file(&location).and_then(|config| {
-                config
-                    .get(&ProfileProvider::default_profile_name())
-                    .and_then(|props| props.get("credential_process"))
-                    .map(std::borrow::ToOwned::to_owned)
-            })
-        }) {
-            Ok(Some(command)) => {
-                // credential_process is set, create the future
-                let mut command = parse_command_str(&command)?;
-                let output = command.output().await.map_err(|e| {
-                    CredentialsError::new(format!("Credential process failed: {:?}", e))
-                })?;
-                if output.status.success() {
-                    parse_credential_process_output(&output.stdout)
-                } else {
-                    Err(CredentialsError::new(format!(
-                        "Credential process failed with {}: {}",
-                        output.status,
-                        String::from_utf8_lossy(&output.stderr)
+        let profiles = Profiles::load(
+            &ProfileProvider::default_config_location()?,
+            self.file_path(),
+        );
+
+        // as it always has, the credential_process of the profile named in the environment takes
+        // precedence over the profile of the provider
+        if let Some(command) = profiles
+            .config
+            .get(&ProfileProvider::default_profile_name())
+            .and_then(|props| props.get(CREDENTIAL_PROCESS))
+        {
+            return run_credential_process(command).await;
+        }
+
+        // walk the chain of profiles assuming roles down to one with credentials of its own
+        let mut visited: Vec<&str> = Vec::new();
+        let mut roles = Vec::new();
+        let mut profile = self.profile();
+        let mut credentials = loop {
+            if visited.contains(&profile) {
+                visited.push(profile);
+                return Err(CredentialsError::new(format!(
+                    "Profiles assume roles in a cycle: {}",
+                    visited.join(" -> ")
+                )));
+            }
+            visited.push(profile);
+
+            let role = match profiles.assume_role_profile(profile)? {
+                Some(role) => role,
+                None => break self.source_credentials(&profiles, profile).await?,
+            };
+            roles.push(role);
+            match (
+                profiles.get(profile, SOURCE_PROFILE),
+                profiles.get(profile, CREDENTIAL_SOURCE),
+            ) {
+                // a profile may assume a role with its own keys
+                (Some(source), None) if source == profile => {
+                    break profiles.static_credentials(profile)?
+                }
+                (Some(source), None) => profile = source,
+                (None, Some(source)) => break credential_source_credentials(source).await?,
+                (Some(_), Some(_)) => {
+                    return Err(CredentialsError::new(format!(
+                        "Profile {} sets both {} and {}",
+                        profile, SOURCE_PROFILE, CREDENTIAL_SOURCE
+                    )))
+                }
+                (None, None) => {
+                    return Err(CredentialsError::new(format!(
+                        "Profile {} sets {} without {} or {}",
+                        profile, ROLE_ARN, SOURCE_PROFILE, CREDENTIAL_SOURCE
                     )))
                 }
             }
-            Ok(None) => {
-                // credential_process is not set, parse the credentials file
-                parse_credentials_file(self.file_path()).and_then(|mut profiles| {
-                    profiles
-                        .remove(self.profile())
-                        .ok_or_else(|| CredentialsError::new("profile not found"))
+        };
+
+        if roles.is_empty() {
+            return Ok(credentials);
+        }
+        let role_assumer = self.role_assumer.as_ref().ok_or_else(|| {
+            CredentialsError::new(format!(
+                "Profile {} assumes a role, which requires a role assumer: see ProfileProvider::set_role_assumer",
+                self.profile()
+            ))
+        })?;
+        for role in roles.iter().rev() {
+            credentials = role_assumer.assume_role(credentials, role).await?;
+        }
+        Ok(credentials)
+    }
+}
+
+/// The profiles of the config and credentials files.
+struct Profiles {
+    config: HashMap<String, HashMap<String, String>>,
+    credentials: HashMap<String, HashMap<String, String>>,
+    /// Keys of the credentials file, or why they couldn't be read.
+    keys: Result<HashMap<String, AwsCredentials>, CredentialsError>,
+}
+
+impl Profiles {
+    fn load(config_path: &Path, credentials_path: &Path) -> Profiles {
+        Profiles {
+            config: parse_config_file(config_path).unwrap_or_default(),
+            credentials: parse_config_file(credentials_path).unwrap_or_default(),
+            keys: parse_credentials_file(credentials_path),
+        }
+    }
+
+    /// A setting of `profile`, those of the credentials file taking precedence.
+    fn get(&self, profile: &str, key: &str) -> Option<&str> {
+        self.credentials
+            .get(profile)
+            .and_then(|props| props.get(key))
+            .or_else(|| self.config.get(profile).and_then(|props| props.get(key)))
+            .map(String::as_str)
+    }
+
+    fn assume_role_profile(
+        &self,
+        profile: &str,
+    ) -> Result<Option<AssumeRoleProfile>, CredentialsError> {
+        let role_arn = match self.get(profile, ROLE_ARN) {
+            Some(role_arn) => role_arn,
+            None => return Ok(None),
+        };
+        let duration_seconds = match self.get(profile, DURATION_SECONDS) {
+            Some(duration) => Some(duration.parse().map_err(|_| {
+                CredentialsError::new(format!(
+                    "Invalid {} in profile {}: {}",
+                    DURATION_SECONDS, profile, duration
+                ))
+            })?),
+            None => None,
+        };
+        let setting = |key| self.get(profile, key).map(ToOwned::to_owned);
+        Ok(Some(AssumeRoleProfile {
+            profile: profile.to_owned(),
+            role_arn: role_arn.to_owned(),
+            role_session_name: setting(ROLE_SESSION_NAME),
+            external_id: setting(EXTERNAL_ID),
+            mfa_serial: setting(MFA_SERIAL),
+            duration_seconds,
+            region: setting(REGION),
+        }))
+    }
+
+    fn sso_profile(&self, profile: &str) -> Result<Option<SsoProfile>, CredentialsError> {
+        let start_url = match self.get(profile, SSO_START_URL) {
+            Some(start_url) => start_url,
+            None => return Ok(None),
+        };
+        let setting = |key| {
+            self.get(profile, key)
+                .map(ToOwned::to_owned)
+                .ok_or_else(|| {
+                    CredentialsError::new(format!(
+                        "Profile {} sets {} without {}",
+                        profile, SSO_START_URL, key
+                    ))
                 })
+        };
+        Ok(Some(SsoProfile {
+            profile: profile.to_owned(),
+            start_url: start_url.to_owned(),
+            region: setting(SSO_REGION)?,
+            account_id: setting(SSO_ACCOUNT_ID)?,
+            role_name: setting(SSO_ROLE_NAME)?,
+        }))
+    }
+
+    /// The keys of `profile`, from the credentials file or else the config file.
+    fn static_credentials(&self, profile: &str) -> Result<AwsCredentials, CredentialsError> {
+        if let Ok(ref keys) = self.keys {
+            if let Some(credentials) = keys.get(profile) {
+                return Ok(credentials.clone());
             }
-            Err(err) => Err(err),
+        }
+        let config = self.config.get(profile);
+        let key = |key| config.and_then(|props| props.get(key)).cloned();
+        match (key(AWS_ACCESS_KEY_ID), key(AWS_SECRET_ACCESS_KEY)) {
+            (Some(access_key), Some(secret_key)) => Ok(AwsCredentials::new(
+                access_key,
+                secret_key,
+                key(AWS_SESSION_TOKEN),
+                None,
+            )),
+            _ => match self.keys {
+                Err(ref err) => Err(err.clone()),
+                Ok(_) => Err(CredentialsError::new("profile not found")),
+            },
         }
     }
 }
 
+/// Resolves the credentials of a `credential_source`.
+async fn credential_source_credentials(source: &str) -> Result<AwsCredentials, CredentialsError> {
+    match source {
+        "Environment" => EnvironmentProvider::default().credentials().await,
+        "Ec2InstanceMetadata" => InstanceMetadataProvider::new().credentials().await,
+        "EcsContainer" => ContainerProvider::new().credentials().await,
+        _ => Err(CredentialsError::new(format!(
+            "Unsupported {}: {}",
+            CREDENTIAL_SOURCE, source
+        ))),
+    }
+}
+
+async fn run_credential_process(command: &str) -> Result<AwsCredentials, CredentialsError> {
+    let mut command = parse_command_str(command)?;
+    let output = command
+        .output()
+        .await
+        .map_err(|e| CredentialsError::new(format!("Credential process failed: {:?}", e)))?;
+    if output.status.success() {
+        parse_credential_process_output(&output.stdout)
+    } else {
+        Err(CredentialsError::new(format!(
+            "Credential process failed with {}: {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr)
+        )))
+    }
+}
+
 #[derive(Deserialize)]
 struct CredentialProcessOutput {
     #[serde(flatten)]
@@ -244,36 +616,52 @@ fn parse_config_file(file_path: &Path) -> Option<HashMap<String, HashMap<String,
     let profile_regex = new_profile_regex();
     let file = File::open(file_path).expect("expected file");
     let file_lines = BufReader::new(&file);
-    let result: (HashMap<String, HashMap<String, String>>, Option<String>) = file_lines
+    // sub-properties, such as the settings of a service in a `services` section, are indented
+    // under a property without value, and keyed `property.sub_property`
+    let result = file_lines
         .lines()
         .filter_map(|line| {
             line.ok()
-                .map(|l| l.trim_matches(' ').to_owned())
+                .map(|l| {
+                    let indented = l.starts_with([' ', '\t']);
+                    (l.trim_matches([' ', '\t']).to_owned(), indented)
+                })
                 .into_iter()
-                .find(|l| !l.starts_with('#') && !l.is_empty())
+                .find(|(l, _)| !l.starts_with('#') && !l.is_empty())
         })
-        .fold(Default::default(), |(mut result, profile), line| {
-            if profile_regex.is_match(&line) {
-                let caps = profile_regex.captures(&line).unwrap();
-                let next_profile = caps.get(2).map(|value| value.as_str().to_string());
-                (result, next_profile)
-            } else {
-                match &line
-                    .splitn(2, '=')
-                    .map(|value| value.trim_matches(' '))
-                    .collect::<Vec<&str>>()[..]
-                {
-                    [key, value] if !key.is_empty() && !value.is_empty() => {
-                        if let Some(current) = profile.clone() {
-                            let values = result.entry(current).or_insert_with(HashMap::new);
-                            (*values).insert((*key).to_string(), (*value).to_string());
+        .fold(
+            (HashMap::new(), None::<String>, None::<String>),
+            |(mut result, profile, property), (line, indented)| {
+                if profile_regex.is_match(&line) {
+                    let caps = profile_regex.captures(&line).unwrap();
+                    let next_profile = caps.get(2).map(|value| value.as_str().to_string());
+                    (result, next_profile, None)
+                } else {
+                    let property = property.filter(|_| indented);
+                    match &line
+                        .splitn(2, '=')
+                        .map(|value| value.trim_matches(' '))
+                        .collect::<Vec<&str>>()[..]
+                    {
+                        [key, value] if !key.is_empty() && !value.is_empty() => {
+                            if let Some(current) = profile.clone() {
+                                let key = match property {
+                                    Some(ref property) => format!("{}.{}", property, key),
+                                    None => (*key).to_string(),
+                                };
+                                let values = result.entry(current).or_insert_with(HashMap::new);
+                                (*values).insert(key, (*value).to_string());
+                            }
+                            (result, profile, property)
+                        }
+                        [key, ""] if !key.is_empty() && !indented => {
+                            (result, profile, Some((*key).to_string()))
                         }
-                        (result, profile)
+                        _ => (result, profile, property),
                     }
-                    _ => (result, profile),
                 }
-            }
-        });
+            },
+        );
     Some(result.0)
 }
 
@@ -401,7 +789,7 @@ mod tests {
     use std::path::Path;
 
     use super::*;
-    use crate::test_utils::lock_env;
+    use crate::test_utils::{block_on, lock_env};
     use crate::{CredentialsError, ProvideAwsCredentials};
 
     #[test]
@@ -457,6 +845,48 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_config_file_services() {
+        let result = super::parse_config_file(Path::new("tests/sample-data/services_config"));
+        let profiles = result.unwrap();
+        let services = profiles
+            .get("services local")
+            .expect("No services section in services_config");
+        assert_eq!(
+            services.get("dynamodb.endpoint_url"),
+            Some(&"http://localhost:8000".to_string())
+        );
+        assert_eq!(
+            services.get("s3.endpoint_url"),
+            Some(&"http://localhost:9000".to_string())
+        );
+        assert_eq!(services.get("endpoint_url"), None);
+        // properties following sub-properties aren't part of them
+        let default_profile = profiles.get(DEFAULT).unwrap();
+        assert_eq!(default_profile.get(REGION), Some(&"us-east-2".to_string()));
+    }
+
+    #[test]
+    fn profile_provider_endpoint_url() {
+        let _guard = lock_env();
+        env::set_var(AWS_CONFIG_FILE, "tests/sample-data/services_config");
+        env::set_var(AWS_PROFILE, "local");
+        assert_eq!(
+            ProfileProvider::endpoint_url("DynamoDB").unwrap(),
+            Some("http://localhost:8000".to_owned())
+        );
+        // services without endpoint of their own fall back to the one of the profile
+        assert_eq!(
+            ProfileProvider::endpoint_url("SQS").unwrap(),
+            Some("http://localhost:4566".to_owned())
+        );
+        env::set_var(AWS_PROFILE, "ignored");
+        assert_eq!(ProfileProvider::endpoint_url("DynamoDB").unwrap(), None);
+        env::remove_var(AWS_PROFILE);
+        assert_eq!(ProfileProvider::endpoint_url("DynamoDB").unwrap(), None);
+        env::remove_var(AWS_CONFIG_FILE);
+    }
+
     #[test]
     fn parse_credentials_file_default_profile() {
         let result = super::parse_credentials_file(Path::new(
@@ -513,20 +943,22 @@ mod tests {
         assert_eq!(default_profile.aws_secret_access_key(), "bar");
     }
 
-    #[tokio::test]
-    async fn profile_provider_happy_path() {
+    #[test]
+    fn profile_provider_happy_path() {
         let _guard = lock_env();
-        let provider = ProfileProvider::with_configuration(
-            "tests/sample-data/multiple_profile_credentials",
-            "foo",
-        );
-        let result = provider.credentials().await;
-
-        assert!(result.is_ok());
-
-        let creds = result.ok().unwrap();
-        assert_eq!(creds.aws_access_key_id(), "foo_access_key");
-        assert_eq!(creds.aws_secret_access_key(), "foo_secret_key");
+        block_on(async {
+            let provider = ProfileProvider::with_configuration(
+                "tests/sample-data/multiple_profile_credentials",
+                "foo",
+            );
+            let result = provider.credentials().await;
+
+            assert!(result.is_ok());
+
+            let creds = result.ok().unwrap();
+            assert_eq!(creds.aws_access_key_id(), "foo_access_key");
+            assert_eq!(creds.aws_secret_access_key(), "foo_secret_key");
+        });
     }
 
     #[test]
@@ -541,54 +973,332 @@ mod tests {
         env::remove_var(AWS_SHARED_CREDENTIALS_FILE);
     }
 
-    #[tokio::test]
-    async fn profile_provider_profile_name_via_environment_variable() {
+    #[test]
+    fn profile_provider_profile_name_via_environment_variable() {
         let _guard = lock_env();
-        let credentials_path = "tests/sample-data/multiple_profile_credentials";
-        env::set_var(AWS_SHARED_CREDENTIALS_FILE, credentials_path);
-        env::set_var(AWS_PROFILE, "bar");
-        let result = ProfileProvider::new();
-        assert!(result.is_ok());
-        let provider = result.unwrap();
-        assert_eq!(provider.file_path().to_str().unwrap(), credentials_path);
-        let creds = provider.credentials().await;
-        assert_eq!(creds.unwrap().aws_access_key_id(), "bar_access_key");
-        env::remove_var(AWS_SHARED_CREDENTIALS_FILE);
-        env::remove_var(AWS_PROFILE);
+        block_on(async {
+            let credentials_path = "tests/sample-data/multiple_profile_credentials";
+            env::set_var(AWS_SHARED_CREDENTIALS_FILE, credentials_path);
+            env::set_var(AWS_PROFILE, "bar");
+            let result = ProfileProvider::new();
+            assert!(result.is_ok());
+            let provider = result.unwrap();
+            assert_eq!(provider.file_path().to_str().unwrap(), credentials_path);
+            let creds = provider.credentials().await;
+            assert_eq!(creds.unwrap().aws_access_key_id(), "bar_access_key");
+            env::remove_var(AWS_SHARED_CREDENTIALS_FILE);
+            env::remove_var(AWS_PROFILE);
+        });
     }
 
-    #[tokio::test]
-    async fn profile_provider_bad_profile() {
+    #[test]
+    fn profile_provider_bad_profile() {
         let _guard = lock_env();
-        let provider = ProfileProvider::with_configuration(
-            "tests/sample-data/multiple_profile_credentials",
-            "not_a_profile",
-        );
-        let result = provider.credentials().await;
+        block_on(async {
+            let provider = ProfileProvider::with_configuration(
+                "tests/sample-data/multiple_profile_credentials",
+                "not_a_profile",
+            );
+            let result = provider.credentials().await;
+
+            assert!(result.is_err());
+            assert_eq!(
+                result.err(),
+                Some(CredentialsError::new("profile not found"))
+            );
+        });
+    }
 
-        assert!(result.is_err());
-        assert_eq!(
-            result.err(),
-            Some(CredentialsError::new("profile not found"))
+    #[test]
+    fn profile_provider_credential_process() {
+        let _guard = lock_env();
+        block_on(async {
+            env::set_var(
+                AWS_CONFIG_FILE,
+                "tests/sample-data/credential_process_config",
+            );
+            let provider = ProfileProvider::new().unwrap();
+            let result = provider.credentials().await;
+
+            assert!(result.is_ok());
+
+            let creds = result.ok().unwrap();
+            assert_eq!(creds.aws_access_key_id(), "baz_access_key");
+            assert_eq!(creds.aws_secret_access_key(), "baz_secret_key");
+            env::remove_var(AWS_CONFIG_FILE);
+        });
+    }
+
+    #[test]
+    fn profile_provider_credential_process_of_profile_in_environment() {
+        let _guard = lock_env();
+        block_on(async {
+            env::set_var(
+                AWS_CONFIG_FILE,
+                "tests/sample-data/credential_process_config",
+            );
+            env::set_var(AWS_PROFILE, "foo");
+            let provider = ProfileProvider::with_configuration(
+                "tests/sample-data/multiple_profile_credentials",
+                "bar",
+            );
+            let creds = provider.credentials().await.expect("credentials");
+
+            assert_eq!(creds.aws_access_key_id(), "foo_access_key");
+            env::remove_var(AWS_PROFILE);
+            env::remove_var(AWS_CONFIG_FILE);
+        });
+    }
+
+    /// Assumes roles by handing out credentials named after the profile, recording its calls.
+    #[derive(Clone, Default)]
+    struct RecordingRoleAssumer {
+        calls: Arc<std::sync::Mutex<Vec<(String, AssumeRoleProfile)>>>,
+    }
+
+    #[async_trait]
+    impl AssumeRole for RecordingRoleAssumer {
+        async fn assume_role(
+            &self,
+            credentials: AwsCredentials,
+            profile: &AssumeRoleProfile,
+        ) -> Result<AwsCredentials, CredentialsError> {
+            self.calls
+                .lock()
+                .unwrap()
+                .push((credentials.aws_access_key_id().to_owned(), profile.clone()));
+            Ok(AwsCredentials::new(
+                format!("{}_access_key", profile.profile),
+                format!("{}_secret_key", profile.profile),
+                Some("token".to_owned()),
+                None,
+            ))
+        }
+    }
+
+    fn assume_role_provider(profile: &str, role_assumer: &RecordingRoleAssumer) -> ProfileProvider {
+        env::set_var(AWS_CONFIG_FILE, "tests/sample-data/assume_role_config");
+        let mut provider = ProfileProvider::with_configuration(
+            "tests/sample-data/assume_role_credentials",
+            profile,
         );
+        provider.set_role_assumer(role_assumer.clone());
+        provider
     }
 
-    #[tokio::test]
-    async fn profile_provider_credential_process() {
+    #[test]
+    fn profile_provider_assumes_chained_roles() {
+        let _guard = lock_env();
+        block_on(async {
+            let role_assumer = RecordingRoleAssumer::default();
+            let provider = assume_role_provider("chained", &role_assumer);
+            let creds = provider.credentials().await.expect("credentials");
+            env::remove_var(AWS_CONFIG_FILE);
+
+            assert_eq!(creds.aws_access_key_id(), "chained_access_key");
+            let calls = role_assumer.calls.lock().unwrap();
+            assert_eq!(
+                *calls,
+                vec![
+                    (
+                        "keys_access_key".to_owned(),
+                        AssumeRoleProfile {
+                            profile: "base-role".to_owned(),
+                            role_arn: "arn:aws:iam::123456789012:role/base".to_owned(),
+                            external_id: Some("base_external_id".to_owned()),
+                            ..Default::default()
+                        }
+                    ),
+                    (
+                        "base-role_access_key".to_owned(),
+                        AssumeRoleProfile {
+                            profile: "chained".to_owned(),
+                            role_arn: "arn:aws:iam::123456789012:role/chained".to_owned(),
+                            role_session_name: Some("chained_session".to_owned()),
+                            external_id: None,
+                            mfa_serial: Some("arn:aws:iam::123456789012:mfa/user".to_owned()),
+                            duration_seconds: Some(1800),
+                            region: Some("us-west-2".to_owned()),
+                        }
+                    ),
+                ]
+            );
+        });
+    }
+
+    #[test]
+    fn profile_provider_assumes_role_with_own_keys() {
+        let _guard = lock_env();
+        block_on(async {
+            let role_assumer = RecordingRoleAssumer::default();
+            let provider = assume_role_provider("self", &role_assumer);
+            let creds = provider.credentials().await.expect("credentials");
+            env::remove_var(AWS_CONFIG_FILE);
+
+            assert_eq!(creds.aws_access_key_id(), "self_access_key");
+            let calls = role_assumer.calls.lock().unwrap();
+            assert_eq!(calls.len(), 1);
+            assert_eq!(calls[0].0, "self_access_key");
+            assert_eq!(calls[0].1.role_arn, "arn:aws:iam::123456789012:role/self");
+        });
+    }
+
+    #[test]
+    fn profile_provider_assumes_role_from_credential_source() {
+        let _guard = lock_env();
+        block_on(async {
+            env::set_var("AWS_ACCESS_KEY_ID", "environment_access_key");
+            env::set_var("AWS_SECRET_ACCESS_KEY", "environment_secret_key");
+            let role_assumer = RecordingRoleAssumer::default();
+            let provider = assume_role_provider("environment", &role_assumer);
+            let creds = provider.credentials().await.expect("credentials");
+            env::remove_var(AWS_CONFIG_FILE);
+            env::remove_var("AWS_ACCESS_KEY_ID");
+            env::remove_var("AWS_SECRET_ACCESS_KEY");
+
+            assert_eq!(creds.aws_access_key_id(), "environment_access_key");
+            let calls = role_assumer.calls.lock().unwrap();
+            assert_eq!(calls.len(), 1);
+            assert_eq!(calls[0].0, "environment_access_key");
+        });
+    }
+
+    #[test]
+    fn profile_provider_detects_role_cycles() {
         let _guard = lock_env();
-        env::set_var(
-            AWS_CONFIG_FILE,
-            "tests/sample-data/credential_process_config",
+        block_on(async {
+            let role_assumer = RecordingRoleAssumer::default();
+            let provider = assume_role_provider("loop-a", &role_assumer);
+            let result = provider.credentials().await;
+            env::remove_var(AWS_CONFIG_FILE);
+
+            assert_eq!(
+                result.err(),
+                Some(CredentialsError::new(
+                    "Profiles assume roles in a cycle: loop-a -> loop-b -> loop-a"
+                ))
+            );
+            assert!(role_assumer.calls.lock().unwrap().is_empty());
+        });
+    }
+
+    #[test]
+    fn profile_provider_requires_role_assumer() {
+        let _guard = lock_env();
+        block_on(async {
+            env::set_var(AWS_CONFIG_FILE, "tests/sample-data/assume_role_config");
+            let provider = ProfileProvider::with_configuration(
+                "tests/sample-data/assume_role_credentials",
+                "base-role",
+            );
+            let result = provider.credentials().await;
+            env::remove_var(AWS_CONFIG_FILE);
+
+            assert!(result.is_err());
+        });
+    }
+
+    /// Exchanges SSO tokens for credentials named after the token, recording its calls.
+    #[derive(Clone, Default)]
+    struct RecordingSsoTokenExchanger {
+        calls: Arc<std::sync::Mutex<Vec<(SsoProfile, String)>>>,
+    }
+
+    #[async_trait]
+    impl ExchangeSsoToken for RecordingSsoTokenExchanger {
+        async fn exchange_sso_token(
+            &self,
+            profile: &SsoProfile,
+            access_token: &str,
+        ) -> Result<AwsCredentials, CredentialsError> {
+            self.calls
+                .lock()
+                .unwrap()
+                .push((profile.clone(), access_token.to_owned()));
+            Ok(AwsCredentials::new(
+                format!("{}_access_key", access_token),
+                format!("{}_secret_key", access_token),
+                Some("token".to_owned()),
+                None,
+            ))
+        }
+    }
+
+    fn sso_provider(
+        profile: &str,
+        sso_token_exchanger: &RecordingSsoTokenExchanger,
+    ) -> ProfileProvider {
+        env::set_var(AWS_CONFIG_FILE, "tests/sample-data/sso_config");
+        let mut provider = ProfileProvider::with_configuration(
+            "tests/sample-data/default_profile_credentials",
+            profile,
         );
-        let provider = ProfileProvider::new().unwrap();
-        let result = provider.credentials().await;
+        provider.set_sso_token_exchanger(sso_token_exchanger.clone());
+        provider.set_sso_cache_dir("tests/sample-data/sso_cache");
+        provider
+    }
 
-        assert!(result.is_ok());
+    #[test]
+    fn profile_provider_exchanges_sso_token() {
+        let _guard = lock_env();
+        block_on(async {
+            let sso_token_exchanger = RecordingSsoTokenExchanger::default();
+            let provider = sso_provider("sso", &sso_token_exchanger);
+            let creds = provider.credentials().await.expect("credentials");
+            env::remove_var(AWS_CONFIG_FILE);
+
+            assert_eq!(creds.aws_access_key_id(), "sso_access_token_access_key");
+            assert_eq!(
+                *sso_token_exchanger.calls.lock().unwrap(),
+                vec![(
+                    SsoProfile {
+                        profile: "sso".to_owned(),
+                        start_url: "https://rusoto-test.awsapps.com/start".to_owned(),
+                        region: "us-east-1".to_owned(),
+                        account_id: "123456789012".to_owned(),
+                        role_name: "Developer".to_owned(),
+                    },
+                    "sso_access_token".to_owned()
+                )]
+            );
+        });
+    }
 
-        let creds = result.ok().unwrap();
-        assert_eq!(creds.aws_access_key_id(), "baz_access_key");
-        assert_eq!(creds.aws_secret_access_key(), "baz_secret_key");
-        env::remove_var(AWS_CONFIG_FILE);
+    #[test]
+    fn profile_provider_assumes_role_with_sso_credentials() {
+        let _guard = lock_env();
+        block_on(async {
+            let sso_token_exchanger = RecordingSsoTokenExchanger::default();
+            let role_assumer = RecordingRoleAssumer::default();
+            let mut provider = sso_provider("sso-role", &sso_token_exchanger);
+            provider.set_role_assumer(role_assumer.clone());
+            let creds = provider.credentials().await.expect("credentials");
+            env::remove_var(AWS_CONFIG_FILE);
+
+            assert_eq!(creds.aws_access_key_id(), "sso-role_access_key");
+            let calls = role_assumer.calls.lock().unwrap();
+            assert_eq!(calls.len(), 1);
+            assert_eq!(calls[0].0, "sso_access_token_access_key");
+        });
+    }
+
+    #[test]
+    fn profile_provider_rejects_expired_sso_session() {
+        let _guard = lock_env();
+        block_on(async {
+            let sso_token_exchanger = RecordingSsoTokenExchanger::default();
+            let provider = sso_provider("expired", &sso_token_exchanger);
+            let result = provider.credentials().await;
+            env::remove_var(AWS_CONFIG_FILE);
+
+            assert_eq!(
+                result.err(),
+                Some(CredentialsError::new(
+                    "The SSO session for profile expired expired, log in again with `aws sso login`"
+                ))
+            );
+            assert!(sso_token_exchanger.calls.lock().unwrap().is_empty());
+        });
     }
 
     #[test]
@@ -4,7 +4,7 @@ use std::time::Duration;
 
 use futures::StreamExt;
 use hyper::client::HttpConnector;
-use hyper::{Body, Client as HyperClient, Request, Uri};
+use hyper::{Body, Client as HyperClient, Request, StatusCode};
 use tokio::time;
 
 /// Http client for use in a credentials provider.
@@ -21,23 +21,25 @@ impl HttpClient {
         }
     }
 
-    pub async fn get(&self, uri: Uri, timeout: Duration) -> Result<String, IoError> {
-        match Request::get(uri).body(Body::empty()) {
-            Ok(request) => self.request(request, timeout).await,
-            Err(err) => Err(IoError::new(
-                ErrorKind::Other,
-                format!("Invalid request: {}", err),
-            )),
-        }
+    pub async fn request(&self, req: Request<Body>, timeout: Duration) -> Result<String, IoError> {
+        self.request_with_status(req, timeout)
+            .await
+            .map(|(_status, text)| text)
     }
 
-    pub async fn request(&self, req: Request<Body>, timeout: Duration) -> Result<String, IoError> {
+    /// Send a request, returning the status of the response along with its body.
+    pub async fn request_with_status(
+        &self,
+        req: Request<Body>,
+        timeout: Duration,
+    ) -> Result<(StatusCode, String), IoError> {
         match time::timeout(timeout, self.inner.request(req)).await {
             Err(_elapsed) => Err(IoError::new(ErrorKind::TimedOut, "Request timed out")),
             Ok(try_resp) => {
                 let mut resp = try_resp.map_err(|err| {
                     IoError::new(ErrorKind::Other, format!("Response failed: {}", err))
                 })?;
+                let status = resp.status();
                 let body = resp.body_mut();
                 let mut text = vec![];
                 while let Some(chunk) = body.next().await {
@@ -47,6 +49,7 @@ impl HttpClient {
                     text.extend(chunk.to_vec());
                 }
                 String::from_utf8(text)
+                    .map(|text| (status, text))
                     .map_err(|_| IoError::new(ErrorKind::InvalidData, "Non UTF-8 Data returned"))
             }
         }
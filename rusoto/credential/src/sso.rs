@@ -0,0 +1,149 @@
+//! Credentials of profiles using AWS SSO, exchanged for the tokens `aws sso login` caches.
+
+use std::fs::File;
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
+use dirs::home_dir;
+use serde::Deserialize;
+use sha1::{Digest, Sha1};
+
+use crate::{AwsCredentials, CredentialsError};
+
+/// The settings of a profile using AWS SSO, handed to an [`ExchangeSsoToken`](trait.ExchangeSsoToken.html)
+/// along with the access token of the profile's SSO session.
+///
+/// See the [AWS CLI documentation][sso] for what they mean.
+///
+/// [sso]: https://docs.aws.amazon.com/cli/latest/userguide/cli-configure-sso.html
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SsoProfile {
+    /// Name of the profile.
+    pub profile: String,
+    /// URL of the AWS SSO user portal (`sso_start_url`).
+    pub start_url: String,
+    /// Region of the AWS SSO directory (`sso_region`).
+    pub region: String,
+    /// ID of the account to get credentials for (`sso_account_id`).
+    pub account_id: String,
+    /// Name of the role to get credentials for (`sso_role_name`).
+    pub role_name: String,
+}
+
+/// Exchanges the access tokens of AWS SSO sessions for role credentials, for profiles
+/// configured with an `sso_start_url`.
+///
+/// This is how a [`ProfileProvider`](struct.ProfileProvider.html) resolves such profiles without
+/// `rusoto_credential` depending on the SSO service: `rusoto_sso::SsoTokenExchanger` implements it
+/// by calling `GetRoleCredentials`.
+#[async_trait]
+pub trait ExchangeSsoToken: Send + Sync {
+    /// Get credentials for the role `profile` configures, with the `access_token` of its session.
+    async fn exchange_sso_token(
+        &self,
+        profile: &SsoProfile,
+        access_token: &str,
+    ) -> Result<AwsCredentials, CredentialsError>;
+}
+
+/// A token cached by `aws sso login`.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CachedToken {
+    access_token: String,
+    expires_at: String,
+}
+
+/// Default location of the SSO token cache: `~/.aws/sso/cache`.
+pub(crate) fn default_cache_dir() -> Result<PathBuf, CredentialsError> {
+    match home_dir() {
+        Some(mut home_path) => {
+            home_path.push(".aws");
+            home_path.push("sso");
+            home_path.push("cache");
+            Ok(home_path)
+        }
+        None => Err(CredentialsError::new("Failed to determine home directory.")),
+    }
+}
+
+/// Gets credentials for `profile`, with the token of its session in `cache_dir`.
+pub(crate) async fn sso_credentials(
+    profile: &SsoProfile,
+    cache_dir: &Path,
+    exchanger: &dyn ExchangeSsoToken,
+) -> Result<AwsCredentials, CredentialsError> {
+    let access_token = cached_access_token(profile, cache_dir)?;
+    exchanger.exchange_sso_token(profile, &access_token).await
+}
+
+/// Reads the access token of the session of `profile`, cached in a file named after the SHA-1
+/// digest of its start URL.
+fn cached_access_token(profile: &SsoProfile, cache_dir: &Path) -> Result<String, CredentialsError> {
+    let path = cache_dir.join(cache_file_name(&profile.start_url));
+    let file = File::open(&path).map_err(|err| {
+        CredentialsError::new(format!(
+            "No SSO session for profile {} in {:?}, log in with `aws sso login`: {}",
+            profile.profile, path, err
+        ))
+    })?;
+    let token: CachedToken = serde_json::from_reader(BufReader::new(file))?;
+
+    if parse_expiration(&token.expires_at)? <= Utc::now() {
+        return Err(CredentialsError::new(format!(
+            "The SSO session for profile {} expired, log in again with `aws sso login`",
+            profile.profile
+        )));
+    }
+    Ok(token.access_token)
+}
+
+/// Name of the file the token of the session of `start_url` is cached in.
+fn cache_file_name(start_url: &str) -> String {
+    let digest = Sha1::digest(start_url.as_bytes());
+    let hex = digest
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect::<String>();
+    format!("{}.json", hex)
+}
+
+/// Parses the expiration of a cached token, which older versions of the AWS CLI wrote as
+/// `2020-01-01T00:00:00UTC` rather than RFC 3339.
+fn parse_expiration(expires_at: &str) -> Result<DateTime<Utc>, CredentialsError> {
+    if let Ok(date_time) = DateTime::parse_from_rfc3339(expires_at) {
+        return Ok(date_time.with_timezone(&Utc));
+    }
+    let naive =
+        NaiveDateTime::parse_from_str(expires_at.trim_end_matches("UTC"), "%Y-%m-%dT%H:%M:%S")
+            .map_err(|err| {
+                CredentialsError::new(format!(
+                    "Invalid SSO token expiration {}: {}",
+                    expires_at, err
+                ))
+            })?;
+    Ok(Utc.from_utc_datetime(&naive))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cache_file_names() {
+        assert_eq!(
+            cache_file_name("https://my-sso-portal.awsapps.com/start"),
+            "c7aaaf71fcc8777ae2475525ed049d39fe16c484.json"
+        );
+    }
+
+    #[test]
+    fn parses_expirations() {
+        let expected = "2020-01-01T00:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        assert_eq!(parse_expiration("2020-01-01T00:00:00Z"), Ok(expected));
+        assert_eq!(parse_expiration("2020-01-01T00:00:00UTC"), Ok(expected));
+        assert!(parse_expiration("tomorrow").is_err());
+    }
+}
@@ -4,6 +4,7 @@ use lazy_static::lazy_static;
 use std::collections::HashMap;
 use std::ffi::OsString;
 use std::fmt::Debug;
+use std::future::Future;
 use std::sync::{Mutex, MutexGuard};
 
 pub const SECRET: &str = &"TtnuieannGt2rGuie2t8Tt7urarg5nauedRndrur";
@@ -48,3 +49,15 @@ pub fn lock_env() -> MutexGuard<'static, ()> {
         }
     }
 }
+
+// Tests changing environment variables hold the lock above while they run, and a `MutexGuard`
+// mustn't be held across an `.await`: such tests run their futures to completion with this helper
+// rather than being async themselves.
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    tokio::runtime::Builder::new()
+        .basic_scheduler()
+        .enable_all()
+        .build()
+        .expect("failed to create the runtime")
+        .block_on(future)
+}
@@ -1,8 +1,8 @@
 use rusoto_credential::{InstanceMetadataProvider, ProvideAwsCredentials};
 use std::time::Duration;
 
-// This test is marked ignored because it requires special setup.
-// It's run with the `credential_integration_test` Makefile target.
+// These tests are marked ignored because they require special setup.
+// They're run with the `credential_integration_test` Makefile target.
 #[tokio::test]
 #[ignore]
 async fn it_fetches_basic_role() {
@@ -22,3 +22,37 @@ async fn it_fetches_basic_role() {
     };
     assert_eq!(dt, "2015-08-04 06:32:37 UTC");
 }
+
+#[tokio::test]
+#[ignore]
+async fn it_fetches_basic_role_with_session_token() {
+    // the mock requires session tokens (IMDSv2) on this port
+    let mut provider = InstanceMetadataProvider::new();
+    provider.set_timeout(Duration::from_secs(5));
+    provider.set_ip_addr_with_port("127.0.0.1", "8081");
+
+    let creds = provider.credentials().await.expect("credentials");
+
+    assert_eq!(creds.aws_access_key_id(), "Access_key_id_value");
+    assert_eq!(creds.aws_secret_access_key(), "Secret_access_key_value");
+    assert_eq!(creds.token().as_ref(), Some(&"AAAAA".to_string()));
+
+    // the cached session token is reused
+    let creds = provider.credentials().await.expect("credentials");
+    assert_eq!(creds.aws_access_key_id(), "Access_key_id_value");
+}
+
+#[tokio::test]
+#[ignore]
+async fn it_refreshes_expired_session_tokens() {
+    // tokens this short lived are refreshed on every request
+    let mut provider = InstanceMetadataProvider::new();
+    provider.set_timeout(Duration::from_secs(5));
+    provider.set_ip_addr_with_port("127.0.0.1", "8081");
+    provider.set_token_ttl(Duration::from_secs(1));
+
+    for _ in 0..2 {
+        let creds = provider.credentials().await.expect("credentials");
+        assert_eq!(creds.aws_access_key_id(), "Access_key_id_value");
+    }
+}
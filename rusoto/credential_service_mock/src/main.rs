@@ -1,7 +1,35 @@
-use warp::{self, path, Filter};
+use warp::http::StatusCode;
+use warp::{self, path, Filter, Rejection, Reply};
+
+// Session token handed out by the IMDSv2 flavour of the service.
+const SESSION_TOKEN: &str = "AQAAAMockSessionToken==";
 
 #[tokio::main]
 async fn main() {
+    // IMDSv1: metadata is served to anyone asking, session tokens aren't known of.
+    let imds_v1 = warp::get().and(instance_profile());
+
+    // IMDSv2 with `HttpTokens=required`: metadata is only served along with a session token.
+    let session_token = warp::put()
+        .and(path!("latest" / "api" / "token"))
+        .and(warp::header::<u32>("x-aws-ec2-metadata-token-ttl-seconds"))
+        .map(|_ttl: u32| SESSION_TOKEN);
+    let authenticated = warp::get()
+        .and(warp::header::exact(
+            "x-aws-ec2-metadata-token",
+            SESSION_TOKEN,
+        ))
+        .and(instance_profile());
+    let unauthorized = warp::any().map(|| warp::reply::with_status("", StatusCode::UNAUTHORIZED));
+    let imds_v2 = session_token.or(authenticated).or(unauthorized);
+
+    tokio::join!(
+        warp::serve(imds_v1).run(([127, 0, 0, 1], 8080)),
+        warp::serve(imds_v2).run(([127, 0, 0, 1], 8081)),
+    );
+}
+
+fn instance_profile() -> impl Filter<Extract = (impl Reply,), Error = Rejection> + Clone {
     let instance_profile_role =
         path!("latest" / "meta-data" / "iam" / "security-credentials").map(|| "testrole");
     let instance_profile_creds =
@@ -17,9 +45,5 @@ async fn main() {
 }"#
         });
 
-    let routes = warp::get()
-        .and(instance_profile_creds)
-        .or(instance_profile_role);
-
-    warp::serve(routes).run(([127, 0, 0, 1], 8080)).await;
+    instance_profile_creds.or(instance_profile_role)
 }
@@ -35,10 +35,20 @@ pub struct AnalyzedResource {
     pub actions: Option<Vec<String>>,
     /// <p>The time at which the resource was analyzed.</p>
     #[serde(rename = "analyzedAt")]
-    pub analyzed_at: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
+    pub analyzed_at: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The time at which the finding was created.</p>
     #[serde(rename = "createdAt")]
-    pub created_at: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
+    pub created_at: ::chrono::DateTime<::chrono::Utc>,
     /// <p>An error message.</p>
     #[serde(rename = "error")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -51,7 +61,7 @@ pub struct AnalyzedResource {
     pub resource_arn: String,
     /// <p>The type of the resource that was analyzed.</p>
     #[serde(rename = "resourceType")]
-    pub resource_type: String,
+    pub resource_type: ResourceType,
     /// <p>Indicates how the access that generated the finding is granted.</p>
     #[serde(rename = "sharedVia")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -59,10 +69,15 @@ pub struct AnalyzedResource {
     /// <p>The current status of the finding generated from the analyzed resource.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<FindingStatus>,
     /// <p>The time at which the finding was updated.</p>
     #[serde(rename = "updatedAt")]
-    pub updated_at: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
+    pub updated_at: ::chrono::DateTime<::chrono::Utc>,
 }
 
 /// <p>Contains the ARN of the analyzed resource.</p>
@@ -74,7 +89,7 @@ pub struct AnalyzedResourceSummary {
     pub resource_arn: String,
     /// <p>The type of resource that was analyzed.</p>
     #[serde(rename = "resourceType")]
-    pub resource_type: String,
+    pub resource_type: ResourceType,
 }
 
 /// <p>Contains information about the analyzer.</p>
@@ -86,15 +101,25 @@ pub struct AnalyzerSummary {
     pub arn: String,
     /// <p>A timestamp for the time at which the analyzer was created.</p>
     #[serde(rename = "createdAt")]
-    pub created_at: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
+    pub created_at: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The resource that was most recently analyzed by the analyzer.</p>
     #[serde(rename = "lastResourceAnalyzed")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub last_resource_analyzed: Option<String>,
     /// <p>The time at which the most recently analyzed resource was analyzed.</p>
     #[serde(rename = "lastResourceAnalyzedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_resource_analyzed_at: Option<f64>,
+    pub last_resource_analyzed_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the analyzer.</p>
     #[serde(rename = "name")]
     pub name: String,
@@ -104,7 +129,7 @@ pub struct AnalyzerSummary {
     pub tags: Option<::std::collections::HashMap<String, String>>,
     /// <p>The type of analyzer, which corresponds to the zone of trust chosen for the analyzer.</p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: Type,
 }
 
 /// <p>Contains information about an archive rule.</p>
@@ -113,7 +138,12 @@ pub struct AnalyzerSummary {
 pub struct ArchiveRuleSummary {
     /// <p>The time at which the archive rule was created.</p>
     #[serde(rename = "createdAt")]
-    pub created_at: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
+    pub created_at: ::chrono::DateTime<::chrono::Utc>,
     /// <p>A filter used to define the archive rule.</p>
     #[serde(rename = "filter")]
     pub filter: ::std::collections::HashMap<String, Criterion>,
@@ -122,7 +152,12 @@ pub struct ArchiveRuleSummary {
     pub rule_name: String,
     /// <p>The time at which the archive rule was last updated.</p>
     #[serde(rename = "updatedAt")]
-    pub updated_at: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
+    pub updated_at: ::chrono::DateTime<::chrono::Utc>,
 }
 
 /// <p>Creates an analyzer.</p>
@@ -146,7 +181,7 @@ pub struct CreateAnalyzerRequest {
     pub tags: Option<::std::collections::HashMap<String, String>>,
     /// <p>The type of analyzer to create. Only ACCOUNT analyzers are supported. You can create only one analyzer per account per Region.</p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: Type,
 }
 
 /// <p>The response to the request to create an analyzer.</p>
@@ -238,13 +273,23 @@ pub struct Finding {
     pub action: Option<Vec<String>>,
     /// <p>The time at which the resource was analyzed.</p>
     #[serde(rename = "analyzedAt")]
-    pub analyzed_at: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
+    pub analyzed_at: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The condition in the analyzed policy statement that resulted in a finding.</p>
     #[serde(rename = "condition")]
     pub condition: ::std::collections::HashMap<String, String>,
     /// <p>The time at which the finding was generated.</p>
     #[serde(rename = "createdAt")]
-    pub created_at: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
+    pub created_at: ::chrono::DateTime<::chrono::Utc>,
     /// <p>An error.</p>
     #[serde(rename = "error")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -266,15 +311,211 @@ pub struct Finding {
     pub resource: Option<String>,
     /// <p>The type of the resource reported in the finding.</p>
     #[serde(rename = "resourceType")]
-    pub resource_type: String,
+    pub resource_type: ResourceType,
     /// <p>The current status of the finding.</p>
     #[serde(rename = "status")]
-    pub status: String,
+    pub status: FindingStatus,
     /// <p>The time at which the finding was updated.</p>
     #[serde(rename = "updatedAt")]
-    pub updated_at: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
+    pub updated_at: ::chrono::DateTime<::chrono::Utc>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FindingStatus {
+    /// `ACTIVE`
+    Active,
+    /// `ARCHIVED`
+    Archived,
+    /// `RESOLVED`
+    Resolved,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl FindingStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FindingStatus::Active => "ACTIVE",
+            FindingStatus::Archived => "ARCHIVED",
+            FindingStatus::Resolved => "RESOLVED",
+            FindingStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for FindingStatus {
+    fn default() -> FindingStatus {
+        FindingStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for FindingStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FindingStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FindingStatus {
+    fn from(value: &'a str) -> FindingStatus {
+        match value {
+            "ACTIVE" => FindingStatus::Active,
+            "ARCHIVED" => FindingStatus::Archived,
+            "RESOLVED" => FindingStatus::Resolved,
+            _ => FindingStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FindingStatus {
+    fn from(value: String) -> FindingStatus {
+        match value.as_str() {
+            "ACTIVE" => FindingStatus::Active,
+            "ARCHIVED" => FindingStatus::Archived,
+            "RESOLVED" => FindingStatus::Resolved,
+            _ => FindingStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for FindingStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<FindingStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FindingStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FindingStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FindingStatus {
+    fn deserialize<D>(deserializer: D) -> Result<FindingStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FindingStatusUpdate {
+    /// `ACTIVE`
+    Active,
+    /// `ARCHIVED`
+    Archived,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl FindingStatusUpdate {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FindingStatusUpdate::Active => "ACTIVE",
+            FindingStatusUpdate::Archived => "ARCHIVED",
+            FindingStatusUpdate::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for FindingStatusUpdate {
+    fn default() -> FindingStatusUpdate {
+        FindingStatusUpdate::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for FindingStatusUpdate {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FindingStatusUpdate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FindingStatusUpdate {
+    fn from(value: &'a str) -> FindingStatusUpdate {
+        match value {
+            "ACTIVE" => FindingStatusUpdate::Active,
+            "ARCHIVED" => FindingStatusUpdate::Archived,
+            _ => FindingStatusUpdate::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FindingStatusUpdate {
+    fn from(value: String) -> FindingStatusUpdate {
+        match value.as_str() {
+            "ACTIVE" => FindingStatusUpdate::Active,
+            "ARCHIVED" => FindingStatusUpdate::Archived,
+            _ => FindingStatusUpdate::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for FindingStatusUpdate {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<FindingStatusUpdate, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FindingStatusUpdate {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
 }
 
+impl ::serde::Serialize for FindingStatusUpdate {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FindingStatusUpdate {
+    fn deserialize<D>(deserializer: D) -> Result<FindingStatusUpdate, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Contains information about a finding.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -285,13 +526,23 @@ pub struct FindingSummary {
     pub action: Option<Vec<String>>,
     /// <p>The time at which the resource-based policy that generated the finding was analyzed.</p>
     #[serde(rename = "analyzedAt")]
-    pub analyzed_at: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
+    pub analyzed_at: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The condition in the analyzed policy statement that resulted in a finding.</p>
     #[serde(rename = "condition")]
     pub condition: ::std::collections::HashMap<String, String>,
     /// <p>The time at which the finding was created.</p>
     #[serde(rename = "createdAt")]
-    pub created_at: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
+    pub created_at: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The error that resulted in an Error finding.</p>
     #[serde(rename = "error")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -313,13 +564,18 @@ pub struct FindingSummary {
     pub resource: Option<String>,
     /// <p>The type of the resource that the external principal has access to.</p>
     #[serde(rename = "resourceType")]
-    pub resource_type: String,
+    pub resource_type: ResourceType,
     /// <p>The status of the finding.</p>
     #[serde(rename = "status")]
-    pub status: String,
+    pub status: FindingStatus,
     /// <p>The time at which the finding was most recently updated.</p>
     #[serde(rename = "updatedAt")]
-    pub updated_at: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
+    pub updated_at: ::chrono::DateTime<::chrono::Utc>,
 }
 
 /// <p>Retrieves an analyzed resource.</p>
@@ -434,7 +690,7 @@ pub struct ListAnalyzedResourcesRequest {
     /// <p>The type of resource.</p>
     #[serde(rename = "resourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ResourceType>,
 }
 
 /// <p>The response to the request.</p>
@@ -465,7 +721,7 @@ pub struct ListAnalyzersRequest {
     /// <p>The type of analyzer.</p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<Type>,
 }
 
 /// <p>The response to the request.</p>
@@ -568,6 +824,252 @@ pub struct ListTagsForResourceResponse {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OrderBy {
+    /// `ASC`
+    Asc,
+    /// `DESC`
+    Desc,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl OrderBy {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OrderBy::Asc => "ASC",
+            OrderBy::Desc => "DESC",
+            OrderBy::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for OrderBy {
+    fn default() -> OrderBy {
+        OrderBy::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for OrderBy {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for OrderBy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for OrderBy {
+    fn from(value: &'a str) -> OrderBy {
+        match value {
+            "ASC" => OrderBy::Asc,
+            "DESC" => OrderBy::Desc,
+            _ => OrderBy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OrderBy {
+    fn from(value: String) -> OrderBy {
+        match value.as_str() {
+            "ASC" => OrderBy::Asc,
+            "DESC" => OrderBy::Desc,
+            _ => OrderBy::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for OrderBy {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<OrderBy, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OrderBy {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for OrderBy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OrderBy {
+    fn deserialize<D>(deserializer: D) -> Result<OrderBy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ResourceType {
+    /// `AWS::S3::Bucket`
+    AwsS3Bucket,
+    /// `AWS::IAM::Role`
+    AwsIamRole,
+    /// `AWS::SQS::Queue`
+    AwsSqsQueue,
+    /// `AWS::Lambda::Function`
+    AwsLambdaFunction,
+    /// `AWS::Lambda::LayerVersion`
+    AwsLambdaLayerVersion,
+    /// `AWS::KMS::Key`
+    AwsKmsKey,
+    /// `AWS::SecretsManager::Secret`
+    AwsSecretsManagerSecret,
+    /// `AWS::EFS::FileSystem`
+    AwsEfsFileSystem,
+    /// `AWS::EC2::Snapshot`
+    AwsEc2Snapshot,
+    /// `AWS::ECR::Repository`
+    AwsEcrRepository,
+    /// `AWS::RDS::DBSnapshot`
+    AwsRdsDBSnapshot,
+    /// `AWS::RDS::DBClusterSnapshot`
+    AwsRdsDBClusterSnapshot,
+    /// `AWS::SNS::Topic`
+    AwsSnsTopic,
+    /// `AWS::S3Express::DirectoryBucket`
+    AwsS3ExpressDirectoryBucket,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ResourceType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceType::AwsS3Bucket => "AWS::S3::Bucket",
+            ResourceType::AwsIamRole => "AWS::IAM::Role",
+            ResourceType::AwsSqsQueue => "AWS::SQS::Queue",
+            ResourceType::AwsLambdaFunction => "AWS::Lambda::Function",
+            ResourceType::AwsLambdaLayerVersion => "AWS::Lambda::LayerVersion",
+            ResourceType::AwsKmsKey => "AWS::KMS::Key",
+            ResourceType::AwsSecretsManagerSecret => "AWS::SecretsManager::Secret",
+            ResourceType::AwsEfsFileSystem => "AWS::EFS::FileSystem",
+            ResourceType::AwsEc2Snapshot => "AWS::EC2::Snapshot",
+            ResourceType::AwsEcrRepository => "AWS::ECR::Repository",
+            ResourceType::AwsRdsDBSnapshot => "AWS::RDS::DBSnapshot",
+            ResourceType::AwsRdsDBClusterSnapshot => "AWS::RDS::DBClusterSnapshot",
+            ResourceType::AwsSnsTopic => "AWS::SNS::Topic",
+            ResourceType::AwsS3ExpressDirectoryBucket => "AWS::S3Express::DirectoryBucket",
+            ResourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ResourceType {
+    fn default() -> ResourceType {
+        ResourceType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ResourceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ResourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ResourceType {
+    fn from(value: &'a str) -> ResourceType {
+        match value {
+            "AWS::S3::Bucket" => ResourceType::AwsS3Bucket,
+            "AWS::IAM::Role" => ResourceType::AwsIamRole,
+            "AWS::SQS::Queue" => ResourceType::AwsSqsQueue,
+            "AWS::Lambda::Function" => ResourceType::AwsLambdaFunction,
+            "AWS::Lambda::LayerVersion" => ResourceType::AwsLambdaLayerVersion,
+            "AWS::KMS::Key" => ResourceType::AwsKmsKey,
+            "AWS::SecretsManager::Secret" => ResourceType::AwsSecretsManagerSecret,
+            "AWS::EFS::FileSystem" => ResourceType::AwsEfsFileSystem,
+            "AWS::EC2::Snapshot" => ResourceType::AwsEc2Snapshot,
+            "AWS::ECR::Repository" => ResourceType::AwsEcrRepository,
+            "AWS::RDS::DBSnapshot" => ResourceType::AwsRdsDBSnapshot,
+            "AWS::RDS::DBClusterSnapshot" => ResourceType::AwsRdsDBClusterSnapshot,
+            "AWS::SNS::Topic" => ResourceType::AwsSnsTopic,
+            "AWS::S3Express::DirectoryBucket" => ResourceType::AwsS3ExpressDirectoryBucket,
+            _ => ResourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceType {
+    fn from(value: String) -> ResourceType {
+        match value.as_str() {
+            "AWS::S3::Bucket" => ResourceType::AwsS3Bucket,
+            "AWS::IAM::Role" => ResourceType::AwsIamRole,
+            "AWS::SQS::Queue" => ResourceType::AwsSqsQueue,
+            "AWS::Lambda::Function" => ResourceType::AwsLambdaFunction,
+            "AWS::Lambda::LayerVersion" => ResourceType::AwsLambdaLayerVersion,
+            "AWS::KMS::Key" => ResourceType::AwsKmsKey,
+            "AWS::SecretsManager::Secret" => ResourceType::AwsSecretsManagerSecret,
+            "AWS::EFS::FileSystem" => ResourceType::AwsEfsFileSystem,
+            "AWS::EC2::Snapshot" => ResourceType::AwsEc2Snapshot,
+            "AWS::ECR::Repository" => ResourceType::AwsEcrRepository,
+            "AWS::RDS::DBSnapshot" => ResourceType::AwsRdsDBSnapshot,
+            "AWS::RDS::DBClusterSnapshot" => ResourceType::AwsRdsDBClusterSnapshot,
+            "AWS::SNS::Topic" => ResourceType::AwsSnsTopic,
+            "AWS::S3Express::DirectoryBucket" => ResourceType::AwsS3ExpressDirectoryBucket,
+            _ => ResourceType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ResourceType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ResourceType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ResourceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ResourceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ResourceType {
+    fn deserialize<D>(deserializer: D) -> Result<ResourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The criteria used to sort.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -579,7 +1081,7 @@ pub struct SortCriteria {
     /// <p>The sort order, ascending or descending.</p>
     #[serde(rename = "orderBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub order_by: Option<String>,
+    pub order_by: Option<OrderBy>,
 }
 
 /// <p>Starts a scan of the policies applied to the specified resource.</p>
@@ -611,6 +1113,109 @@ pub struct TagResourceRequest {
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TagResourceResponse {}
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Type {
+    /// `ACCOUNT`
+    Account,
+    /// `ORGANIZATION`
+    Organization,
+    /// `ACCOUNT_UNUSED_ACCESS`
+    AccountUnusedAccess,
+    /// `ORGANIZATION_UNUSED_ACCESS`
+    OrganizationUnusedAccess,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Type {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Type::Account => "ACCOUNT",
+            Type::Organization => "ORGANIZATION",
+            Type::AccountUnusedAccess => "ACCOUNT_UNUSED_ACCESS",
+            Type::OrganizationUnusedAccess => "ORGANIZATION_UNUSED_ACCESS",
+            Type::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Type {
+    fn default() -> Type {
+        Type::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Type {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Type {
+    fn from(value: &'a str) -> Type {
+        match value {
+            "ACCOUNT" => Type::Account,
+            "ORGANIZATION" => Type::Organization,
+            "ACCOUNT_UNUSED_ACCESS" => Type::AccountUnusedAccess,
+            "ORGANIZATION_UNUSED_ACCESS" => Type::OrganizationUnusedAccess,
+            _ => Type::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Type {
+    fn from(value: String) -> Type {
+        match value.as_str() {
+            "ACCOUNT" => Type::Account,
+            "ORGANIZATION" => Type::Organization,
+            "ACCOUNT_UNUSED_ACCESS" => Type::AccountUnusedAccess,
+            "ORGANIZATION_UNUSED_ACCESS" => Type::OrganizationUnusedAccess,
+            _ => Type::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Type {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Type, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Type {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Type {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Type {
+    fn deserialize<D>(deserializer: D) -> Result<Type, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Removes a tag from the specified resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -668,7 +1273,7 @@ pub struct UpdateFindingsRequest {
     pub resource_arn: Option<String>,
     /// <p>The state represents the action to take to update the finding Status. Use <code>ARCHIVE</code> to change an Active finding to an Archived finding. Use <code>ACTIVE</code> to change an Archived finding to an Active finding.</p>
     #[serde(rename = "status")]
-    pub status: String,
+    pub status: FindingStatusUpdate,
 }
 
 /// <p>Contains information about a validation exception.</p>
@@ -680,6 +1285,109 @@ pub struct ValidationExceptionField {
     pub name: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ValidationExceptionReason {
+    /// `unknownOperation`
+    UnknownOperation,
+    /// `cannotParse`
+    CannotParse,
+    /// `fieldValidationFailed`
+    FieldValidationFailed,
+    /// `other`
+    Other,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ValidationExceptionReason {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ValidationExceptionReason::UnknownOperation => "unknownOperation",
+            ValidationExceptionReason::CannotParse => "cannotParse",
+            ValidationExceptionReason::FieldValidationFailed => "fieldValidationFailed",
+            ValidationExceptionReason::Other => "other",
+            ValidationExceptionReason::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ValidationExceptionReason {
+    fn default() -> ValidationExceptionReason {
+        ValidationExceptionReason::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ValidationExceptionReason {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ValidationExceptionReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ValidationExceptionReason {
+    fn from(value: &'a str) -> ValidationExceptionReason {
+        match value {
+            "unknownOperation" => ValidationExceptionReason::UnknownOperation,
+            "cannotParse" => ValidationExceptionReason::CannotParse,
+            "fieldValidationFailed" => ValidationExceptionReason::FieldValidationFailed,
+            "other" => ValidationExceptionReason::Other,
+            _ => ValidationExceptionReason::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ValidationExceptionReason {
+    fn from(value: String) -> ValidationExceptionReason {
+        match value.as_str() {
+            "unknownOperation" => ValidationExceptionReason::UnknownOperation,
+            "cannotParse" => ValidationExceptionReason::CannotParse,
+            "fieldValidationFailed" => ValidationExceptionReason::FieldValidationFailed,
+            "other" => ValidationExceptionReason::Other,
+            _ => ValidationExceptionReason::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ValidationExceptionReason {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ValidationExceptionReason, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ValidationExceptionReason {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ValidationExceptionReason {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ValidationExceptionReason {
+    fn deserialize<D>(deserializer: D) -> Result<ValidationExceptionReason, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// Errors returned by CreateAnalyzer
 #[derive(Debug, PartialEq)]
 pub enum CreateAnalyzerError {
@@ -1681,11 +2389,12 @@ pub struct AccessAnalyzerClient {
 impl AccessAnalyzerClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> AccessAnalyzerClient {
         AccessAnalyzerClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "AccessAnalyzer"),
         }
     }
 
@@ -1719,6 +2428,7 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         let request_uri = "/analyzer";
 
         let mut request = SignedRequest::new("PUT", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("CreateAnalyzer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1752,6 +2462,7 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         );
 
         let mut request = SignedRequest::new("PUT", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("CreateArchiveRule");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1785,6 +2496,7 @@ impl AccessAnalyzer for AccessAnalyzerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("DeleteAnalyzer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1822,6 +2534,7 @@ impl AccessAnalyzer for AccessAnalyzerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("DeleteArchiveRule");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1854,6 +2567,7 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         let request_uri = "/analyzed-resource";
 
         let mut request = SignedRequest::new("GET", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("GetAnalyzedResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1889,6 +2603,7 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         );
 
         let mut request = SignedRequest::new("GET", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("GetAnalyzer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1920,6 +2635,7 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         );
 
         let mut request = SignedRequest::new("GET", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("GetArchiveRule");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1947,6 +2663,7 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         let request_uri = format!("/finding/{id}", id = input.id);
 
         let mut request = SignedRequest::new("GET", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("GetFinding");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1978,6 +2695,7 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         let request_uri = "/analyzed-resource";
 
         let mut request = SignedRequest::new("POST", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("ListAnalyzedResources");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2008,6 +2726,7 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         let request_uri = "/analyzer";
 
         let mut request = SignedRequest::new("GET", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("ListAnalyzers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2050,6 +2769,7 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         );
 
         let mut request = SignedRequest::new("GET", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("ListArchiveRules");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2086,6 +2806,7 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         let request_uri = "/finding";
 
         let mut request = SignedRequest::new("POST", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("ListFindings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2116,6 +2837,7 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2143,6 +2865,7 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         let request_uri = "/resource/scan";
 
         let mut request = SignedRequest::new("POST", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("StartResourceScan");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2172,6 +2895,7 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2203,6 +2927,7 @@ impl AccessAnalyzer for AccessAnalyzerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2240,6 +2965,7 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         );
 
         let mut request = SignedRequest::new("PUT", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("UpdateArchiveRule");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2269,6 +2995,7 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         let request_uri = "/finding";
 
         let mut request = SignedRequest::new("PUT", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("UpdateFindings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2290,3 +3017,133 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         }
     }
 }
+impl AccessAnalyzerClient {
+    /// Auto-paginating version of `list_analyzed_resources`, streaming every page of results.
+    pub fn list_analyzed_resources_pages(
+        &self,
+        input: ListAnalyzedResourcesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListAnalyzedResourcesResponse,
+        ListAnalyzedResourcesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AccessAnalyzerClient, input: ListAnalyzedResourcesRequest| async move {
+                client.list_analyzed_resources(input).await
+            },
+            |input: &ListAnalyzedResourcesRequest, output: &ListAnalyzedResourcesResponse| {
+                Some(ListAnalyzedResourcesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_analyzed_resources`, streaming the `analyzedResources` of every page of results.
+    pub fn list_analyzed_resources_items(
+        &self,
+        input: ListAnalyzedResourcesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<AnalyzedResourceSummary, ListAnalyzedResourcesError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_analyzed_resources_pages(input),
+            |output: ListAnalyzedResourcesResponse| output.analyzed_resources,
+        )
+    }
+
+    /// Auto-paginating version of `list_analyzers`, streaming every page of results.
+    pub fn list_analyzers_pages(
+        &self,
+        input: ListAnalyzersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListAnalyzersResponse, ListAnalyzersError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AccessAnalyzerClient, input: ListAnalyzersRequest| async move {
+                client.list_analyzers(input).await
+            },
+            |input: &ListAnalyzersRequest, output: &ListAnalyzersResponse| {
+                Some(ListAnalyzersRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_analyzers`, streaming the `analyzers` of every page of results.
+    pub fn list_analyzers_items(
+        &self,
+        input: ListAnalyzersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<AnalyzerSummary, ListAnalyzersError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_analyzers_pages(input),
+            |output: ListAnalyzersResponse| output.analyzers,
+        )
+    }
+
+    /// Auto-paginating version of `list_archive_rules`, streaming every page of results.
+    pub fn list_archive_rules_pages(
+        &self,
+        input: ListArchiveRulesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListArchiveRulesResponse, ListArchiveRulesError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AccessAnalyzerClient, input: ListArchiveRulesRequest| async move {
+                client.list_archive_rules(input).await
+            },
+            |input: &ListArchiveRulesRequest, output: &ListArchiveRulesResponse| {
+                Some(ListArchiveRulesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_archive_rules`, streaming the `archiveRules` of every page of results.
+    pub fn list_archive_rules_items(
+        &self,
+        input: ListArchiveRulesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ArchiveRuleSummary, ListArchiveRulesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_archive_rules_pages(input),
+            |output: ListArchiveRulesResponse| output.archive_rules,
+        )
+    }
+
+    /// Auto-paginating version of `list_findings`, streaming every page of results.
+    pub fn list_findings_pages(
+        &self,
+        input: ListFindingsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListFindingsResponse, ListFindingsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AccessAnalyzerClient, input: ListFindingsRequest| async move {
+                client.list_findings(input).await
+            },
+            |input: &ListFindingsRequest, output: &ListFindingsResponse| {
+                Some(ListFindingsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_findings`, streaming the `findings` of every page of results.
+    pub fn list_findings_items(
+        &self,
+        input: ListFindingsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<FindingSummary, ListFindingsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_findings_pages(input),
+            |output: ListFindingsResponse| output.findings,
+        )
+    }
+}
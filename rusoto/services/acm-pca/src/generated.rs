@@ -85,6 +85,295 @@ pub struct ASN1Subject {
     pub title: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ActionType {
+    /// `IssueCertificate`
+    IssueCertificate,
+    /// `GetCertificate`
+    GetCertificate,
+    /// `ListPermissions`
+    ListPermissions,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ActionType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ActionType::IssueCertificate => "IssueCertificate",
+            ActionType::GetCertificate => "GetCertificate",
+            ActionType::ListPermissions => "ListPermissions",
+            ActionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ActionType {
+    fn default() -> ActionType {
+        ActionType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ActionType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ActionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ActionType {
+    fn from(value: &'a str) -> ActionType {
+        match value {
+            "IssueCertificate" => ActionType::IssueCertificate,
+            "GetCertificate" => ActionType::GetCertificate,
+            "ListPermissions" => ActionType::ListPermissions,
+            _ => ActionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ActionType {
+    fn from(value: String) -> ActionType {
+        match value.as_str() {
+            "IssueCertificate" => ActionType::IssueCertificate,
+            "GetCertificate" => ActionType::GetCertificate,
+            "ListPermissions" => ActionType::ListPermissions,
+            _ => ActionType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ActionType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ActionType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ActionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ActionType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ActionType {
+    fn deserialize<D>(deserializer: D) -> Result<ActionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AuditReportResponseFormat {
+    /// `JSON`
+    Json,
+    /// `CSV`
+    Csv,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AuditReportResponseFormat {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AuditReportResponseFormat::Json => "JSON",
+            AuditReportResponseFormat::Csv => "CSV",
+            AuditReportResponseFormat::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AuditReportResponseFormat {
+    fn default() -> AuditReportResponseFormat {
+        AuditReportResponseFormat::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AuditReportResponseFormat {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AuditReportResponseFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AuditReportResponseFormat {
+    fn from(value: &'a str) -> AuditReportResponseFormat {
+        match value {
+            "JSON" => AuditReportResponseFormat::Json,
+            "CSV" => AuditReportResponseFormat::Csv,
+            _ => AuditReportResponseFormat::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AuditReportResponseFormat {
+    fn from(value: String) -> AuditReportResponseFormat {
+        match value.as_str() {
+            "JSON" => AuditReportResponseFormat::Json,
+            "CSV" => AuditReportResponseFormat::Csv,
+            _ => AuditReportResponseFormat::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AuditReportResponseFormat {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AuditReportResponseFormat, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AuditReportResponseFormat {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AuditReportResponseFormat {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AuditReportResponseFormat {
+    fn deserialize<D>(deserializer: D) -> Result<AuditReportResponseFormat, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AuditReportStatus {
+    /// `CREATING`
+    Creating,
+    /// `SUCCESS`
+    Success,
+    /// `FAILED`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AuditReportStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AuditReportStatus::Creating => "CREATING",
+            AuditReportStatus::Success => "SUCCESS",
+            AuditReportStatus::Failed => "FAILED",
+            AuditReportStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AuditReportStatus {
+    fn default() -> AuditReportStatus {
+        AuditReportStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AuditReportStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AuditReportStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AuditReportStatus {
+    fn from(value: &'a str) -> AuditReportStatus {
+        match value {
+            "CREATING" => AuditReportStatus::Creating,
+            "SUCCESS" => AuditReportStatus::Success,
+            "FAILED" => AuditReportStatus::Failed,
+            _ => AuditReportStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AuditReportStatus {
+    fn from(value: String) -> AuditReportStatus {
+        match value.as_str() {
+            "CREATING" => AuditReportStatus::Creating,
+            "SUCCESS" => AuditReportStatus::Success,
+            "FAILED" => AuditReportStatus::Failed,
+            _ => AuditReportStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AuditReportStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AuditReportStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AuditReportStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AuditReportStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AuditReportStatus {
+    fn deserialize<D>(deserializer: D) -> Result<AuditReportStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Contains information about your private certificate authority (CA). Your private CA can issue and revoke X.509 digital certificates. Digital certificates verify that the entity named in the certificate <b>Subject</b> field owns or controls the public key contained in the <b>Subject Public Key Info</b> field. Call the <a>CreateCertificateAuthority</a> action to create your private CA. You must then call the <a>GetCertificateAuthorityCertificate</a> action to retrieve a private CA certificate signing request (CSR). Sign the CSR with your ACM Private CA-hosted or on-premises root or subordinate CA certificate. Call the <a>ImportCertificateAuthorityCertificate</a> action to import the signed certificate into AWS Certificate Manager (ACM). </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -99,28 +388,53 @@ pub struct CertificateAuthority {
     pub certificate_authority_configuration: Option<CertificateAuthorityConfiguration>,
     /// <p>Date and time at which your private CA was created.</p>
     #[serde(rename = "CreatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Reason the request to create your private CA failed.</p>
     #[serde(rename = "FailureReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub failure_reason: Option<String>,
+    pub failure_reason: Option<FailureReason>,
     /// <p>Date and time at which your private CA was last updated.</p>
     #[serde(rename = "LastStateChangeAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_state_change_at: Option<f64>,
+    pub last_state_change_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Date and time after which your private CA certificate is not valid.</p>
     #[serde(rename = "NotAfter")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub not_after: Option<f64>,
+    pub not_after: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Date and time before which your private CA certificate is not valid.</p>
     #[serde(rename = "NotBefore")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub not_before: Option<f64>,
+    pub not_before: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The period during which a deleted CA can be restored. For more information, see the <code>PermanentDeletionTimeInDays</code> parameter of the <a>DeleteCertificateAuthorityRequest</a> action. </p>
     #[serde(rename = "RestorableUntil")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub restorable_until: Option<f64>,
+    pub restorable_until: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Information about the certificate revocation list (CRL) created and maintained by your private CA. </p>
     #[serde(rename = "RevocationConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -132,11 +446,11 @@ pub struct CertificateAuthority {
     /// <p>Status of your private CA.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<CertificateAuthorityStatus>,
     /// <p>Type of your private CA.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<CertificateAuthorityType>,
 }
 
 /// <p>Contains configuration information for your private certificate authority (CA). This includes information about the class of public key algorithm and the key pair that your private CA creates when it issues a certificate. It also includes the signature algorithm that it uses when issuing certificates, and its X.500 distinguished name. You must specify this information when you call the <a>CreateCertificateAuthority</a> action. </p>
@@ -144,21 +458,232 @@ pub struct CertificateAuthority {
 pub struct CertificateAuthorityConfiguration {
     /// <p>Type of the public key algorithm and size, in bits, of the key pair that your CA creates when it issues a certificate. When you create a subordinate CA, you must use a key algorithm supported by the parent CA.</p>
     #[serde(rename = "KeyAlgorithm")]
-    pub key_algorithm: String,
+    pub key_algorithm: KeyAlgorithm,
     /// <p>Name of the algorithm your private CA uses to sign certificate requests.</p>
     #[serde(rename = "SigningAlgorithm")]
-    pub signing_algorithm: String,
+    pub signing_algorithm: SigningAlgorithm,
     /// <p>Structure that contains X.500 distinguished name information for your private CA.</p>
     #[serde(rename = "Subject")]
     pub subject: ASN1Subject,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CertificateAuthorityStatus {
+    /// `CREATING`
+    Creating,
+    /// `PENDING_CERTIFICATE`
+    PendingCertificate,
+    /// `ACTIVE`
+    Active,
+    /// `DELETED`
+    Deleted,
+    /// `DISABLED`
+    Disabled,
+    /// `EXPIRED`
+    Expired,
+    /// `FAILED`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CertificateAuthorityStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CertificateAuthorityStatus::Creating => "CREATING",
+            CertificateAuthorityStatus::PendingCertificate => "PENDING_CERTIFICATE",
+            CertificateAuthorityStatus::Active => "ACTIVE",
+            CertificateAuthorityStatus::Deleted => "DELETED",
+            CertificateAuthorityStatus::Disabled => "DISABLED",
+            CertificateAuthorityStatus::Expired => "EXPIRED",
+            CertificateAuthorityStatus::Failed => "FAILED",
+            CertificateAuthorityStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CertificateAuthorityStatus {
+    fn default() -> CertificateAuthorityStatus {
+        CertificateAuthorityStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CertificateAuthorityStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CertificateAuthorityStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CertificateAuthorityStatus {
+    fn from(value: &'a str) -> CertificateAuthorityStatus {
+        match value {
+            "CREATING" => CertificateAuthorityStatus::Creating,
+            "PENDING_CERTIFICATE" => CertificateAuthorityStatus::PendingCertificate,
+            "ACTIVE" => CertificateAuthorityStatus::Active,
+            "DELETED" => CertificateAuthorityStatus::Deleted,
+            "DISABLED" => CertificateAuthorityStatus::Disabled,
+            "EXPIRED" => CertificateAuthorityStatus::Expired,
+            "FAILED" => CertificateAuthorityStatus::Failed,
+            _ => CertificateAuthorityStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CertificateAuthorityStatus {
+    fn from(value: String) -> CertificateAuthorityStatus {
+        match value.as_str() {
+            "CREATING" => CertificateAuthorityStatus::Creating,
+            "PENDING_CERTIFICATE" => CertificateAuthorityStatus::PendingCertificate,
+            "ACTIVE" => CertificateAuthorityStatus::Active,
+            "DELETED" => CertificateAuthorityStatus::Deleted,
+            "DISABLED" => CertificateAuthorityStatus::Disabled,
+            "EXPIRED" => CertificateAuthorityStatus::Expired,
+            "FAILED" => CertificateAuthorityStatus::Failed,
+            _ => CertificateAuthorityStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CertificateAuthorityStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CertificateAuthorityStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CertificateAuthorityStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CertificateAuthorityStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CertificateAuthorityStatus {
+    fn deserialize<D>(deserializer: D) -> Result<CertificateAuthorityStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CertificateAuthorityType {
+    /// `ROOT`
+    Root,
+    /// `SUBORDINATE`
+    Subordinate,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CertificateAuthorityType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CertificateAuthorityType::Root => "ROOT",
+            CertificateAuthorityType::Subordinate => "SUBORDINATE",
+            CertificateAuthorityType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CertificateAuthorityType {
+    fn default() -> CertificateAuthorityType {
+        CertificateAuthorityType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CertificateAuthorityType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CertificateAuthorityType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CertificateAuthorityType {
+    fn from(value: &'a str) -> CertificateAuthorityType {
+        match value {
+            "ROOT" => CertificateAuthorityType::Root,
+            "SUBORDINATE" => CertificateAuthorityType::Subordinate,
+            _ => CertificateAuthorityType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CertificateAuthorityType {
+    fn from(value: String) -> CertificateAuthorityType {
+        match value.as_str() {
+            "ROOT" => CertificateAuthorityType::Root,
+            "SUBORDINATE" => CertificateAuthorityType::Subordinate,
+            _ => CertificateAuthorityType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CertificateAuthorityType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CertificateAuthorityType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CertificateAuthorityType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CertificateAuthorityType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CertificateAuthorityType {
+    fn deserialize<D>(deserializer: D) -> Result<CertificateAuthorityType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateCertificateAuthorityAuditReportRequest {
     /// <p>The format in which to create the report. This can be either <b>JSON</b> or <b>CSV</b>.</p>
     #[serde(rename = "AuditReportResponseFormat")]
-    pub audit_report_response_format: String,
+    pub audit_report_response_format: AuditReportResponseFormat,
     /// <p>The Amazon Resource Name (ARN) of the CA to be audited. This is of the form:</p> <p> <code>arn:aws:acm-pca:<i>region</i>:<i>account</i>:certificate-authority/<i>12345678-1234-1234-1234-123456789012</i> </code>.</p>
     #[serde(rename = "CertificateAuthorityArn")]
     pub certificate_authority_arn: String,
@@ -188,7 +713,7 @@ pub struct CreateCertificateAuthorityRequest {
     pub certificate_authority_configuration: CertificateAuthorityConfiguration,
     /// <p>The type of the certificate authority.</p>
     #[serde(rename = "CertificateAuthorityType")]
-    pub certificate_authority_type: String,
+    pub certificate_authority_type: CertificateAuthorityType,
     /// <p>Alphanumeric string that can be used to distinguish between calls to <b>CreateCertificateAuthority</b>. Idempotency tokens time out after five minutes. Therefore, if you call <b>CreateCertificateAuthority</b> multiple times with the same idempotency token within a five minute period, ACM Private CA recognizes that you are requesting only one certificate. As a result, ACM Private CA issues only one. If you change the idempotency token for each call, however, ACM Private CA recognizes that you are requesting multiple certificates.</p>
     #[serde(rename = "IdempotencyToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -217,7 +742,7 @@ pub struct CreateCertificateAuthorityResponse {
 pub struct CreatePermissionRequest {
     /// <p>The actions that the specified AWS service principal can use. These include <code>IssueCertificate</code>, <code>GetCertificate</code>, and <code>ListPermissions</code>.</p>
     #[serde(rename = "Actions")]
-    pub actions: Vec<String>,
+    pub actions: Vec<ActionType>,
     /// <p>The Amazon Resource Name (ARN) of the CA that grants the permissions. You can find the ARN by calling the <a>ListCertificateAuthorities</a> action. This must have the following form: </p> <p> <code>arn:aws:acm-pca:<i>region</i>:<i>account</i>:certificate-authority/<i>12345678-1234-1234-1234-123456789012</i> </code>. </p>
     #[serde(rename = "CertificateAuthorityArn")]
     pub certificate_authority_arn: String,
@@ -294,11 +819,16 @@ pub struct DescribeCertificateAuthorityAuditReportResponse {
     /// <p>Specifies whether report creation is in progress, has succeeded, or has failed.</p>
     #[serde(rename = "AuditReportStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub audit_report_status: Option<String>,
+    pub audit_report_status: Option<AuditReportStatus>,
     /// <p>The date and time at which the report was created.</p>
     #[serde(rename = "CreatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Name of the S3 bucket that contains the report.</p>
     #[serde(rename = "S3BucketName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -326,6 +856,104 @@ pub struct DescribeCertificateAuthorityResponse {
     pub certificate_authority: Option<CertificateAuthority>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FailureReason {
+    /// `REQUEST_TIMED_OUT`
+    RequestTimedOut,
+    /// `UNSUPPORTED_ALGORITHM`
+    UnsupportedAlgorithm,
+    /// `OTHER`
+    Other,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl FailureReason {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FailureReason::RequestTimedOut => "REQUEST_TIMED_OUT",
+            FailureReason::UnsupportedAlgorithm => "UNSUPPORTED_ALGORITHM",
+            FailureReason::Other => "OTHER",
+            FailureReason::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for FailureReason {
+    fn default() -> FailureReason {
+        FailureReason::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for FailureReason {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FailureReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FailureReason {
+    fn from(value: &'a str) -> FailureReason {
+        match value {
+            "REQUEST_TIMED_OUT" => FailureReason::RequestTimedOut,
+            "UNSUPPORTED_ALGORITHM" => FailureReason::UnsupportedAlgorithm,
+            "OTHER" => FailureReason::Other,
+            _ => FailureReason::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FailureReason {
+    fn from(value: String) -> FailureReason {
+        match value.as_str() {
+            "REQUEST_TIMED_OUT" => FailureReason::RequestTimedOut,
+            "UNSUPPORTED_ALGORITHM" => FailureReason::UnsupportedAlgorithm,
+            "OTHER" => FailureReason::Other,
+            _ => FailureReason::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for FailureReason {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<FailureReason, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FailureReason {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FailureReason {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FailureReason {
+    fn deserialize<D>(deserializer: D) -> Result<FailureReason, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct GetCertificateAuthorityCertificateRequest {
@@ -433,7 +1061,7 @@ pub struct IssueCertificateRequest {
     pub idempotency_token: Option<String>,
     /// <p>The name of the algorithm that will be used to sign the certificate to be issued.</p>
     #[serde(rename = "SigningAlgorithm")]
-    pub signing_algorithm: String,
+    pub signing_algorithm: SigningAlgorithm,
     /// <p>Specifies a custom configuration template to use when issuing a certificate. If this parameter is not provided, ACM Private CA defaults to the <code>EndEntityCertificate/V1</code> template.</p> <p>The following service-owned <code>TemplateArn</code> values are supported by ACM Private CA: </p> <ul> <li> <p>arn:aws:acm-pca:::template/EndEntityCertificate/V1</p> </li> <li> <p>arn:aws:acm-pca:::template/SubordinateCACertificate_PathLen0/V1</p> </li> <li> <p>arn:aws:acm-pca:::template/SubordinateCACertificate_PathLen1/V1</p> </li> <li> <p>arn:aws:acm-pca:::template/SubordinateCACertificate_PathLen2/V1</p> </li> <li> <p>arn:aws:acm-pca:::template/SubordinateCACertificate_PathLen3/V1</p> </li> <li> <p>arn:aws:acm-pca:::template/RootCACertificate/V1</p> </li> </ul> <p>For more information, see <a href="https://docs.aws.amazon.com/acm-pca/latest/userguide/UsingTemplates.html">Using Templates</a>.</p>
     #[serde(rename = "TemplateArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -452,6 +1080,109 @@ pub struct IssueCertificateResponse {
     pub certificate_arn: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum KeyAlgorithm {
+    /// `RSA_2048`
+    Rsa2048,
+    /// `RSA_4096`
+    Rsa4096,
+    /// `EC_prime256v1`
+    EcPrime256v1,
+    /// `EC_secp384r1`
+    EcSecp384r1,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl KeyAlgorithm {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            KeyAlgorithm::Rsa2048 => "RSA_2048",
+            KeyAlgorithm::Rsa4096 => "RSA_4096",
+            KeyAlgorithm::EcPrime256v1 => "EC_prime256v1",
+            KeyAlgorithm::EcSecp384r1 => "EC_secp384r1",
+            KeyAlgorithm::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for KeyAlgorithm {
+    fn default() -> KeyAlgorithm {
+        KeyAlgorithm::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for KeyAlgorithm {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for KeyAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for KeyAlgorithm {
+    fn from(value: &'a str) -> KeyAlgorithm {
+        match value {
+            "RSA_2048" => KeyAlgorithm::Rsa2048,
+            "RSA_4096" => KeyAlgorithm::Rsa4096,
+            "EC_prime256v1" => KeyAlgorithm::EcPrime256v1,
+            "EC_secp384r1" => KeyAlgorithm::EcSecp384r1,
+            _ => KeyAlgorithm::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for KeyAlgorithm {
+    fn from(value: String) -> KeyAlgorithm {
+        match value.as_str() {
+            "RSA_2048" => KeyAlgorithm::Rsa2048,
+            "RSA_4096" => KeyAlgorithm::Rsa4096,
+            "EC_prime256v1" => KeyAlgorithm::EcPrime256v1,
+            "EC_secp384r1" => KeyAlgorithm::EcSecp384r1,
+            _ => KeyAlgorithm::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for KeyAlgorithm {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<KeyAlgorithm, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for KeyAlgorithm {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for KeyAlgorithm {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for KeyAlgorithm {
+    fn deserialize<D>(deserializer: D) -> Result<KeyAlgorithm, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ListCertificateAuthoritiesRequest {
@@ -543,15 +1274,20 @@ pub struct Permission {
     /// <p>The private CA actions that can be performed by the designated AWS service.</p>
     #[serde(rename = "Actions")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub actions: Option<Vec<String>>,
+    pub actions: Option<Vec<ActionType>>,
     /// <p>The Amazon Resource Number (ARN) of the private CA from which the permission was issued.</p>
     #[serde(rename = "CertificateAuthorityArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub certificate_authority_arn: Option<String>,
     /// <p>The time at which the permission was created.</p>
     #[serde(rename = "CreatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the policy that is associated with the permission.</p>
     #[serde(rename = "Policy")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -583,6 +1319,129 @@ pub struct RevocationConfiguration {
     pub crl_configuration: Option<CrlConfiguration>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RevocationReason {
+    /// `UNSPECIFIED`
+    Unspecified,
+    /// `KEY_COMPROMISE`
+    KeyCompromise,
+    /// `CERTIFICATE_AUTHORITY_COMPROMISE`
+    CertificateAuthorityCompromise,
+    /// `AFFILIATION_CHANGED`
+    AffiliationChanged,
+    /// `SUPERSEDED`
+    Superseded,
+    /// `CESSATION_OF_OPERATION`
+    CessationOfOperation,
+    /// `PRIVILEGE_WITHDRAWN`
+    PrivilegeWithdrawn,
+    /// `A_A_COMPROMISE`
+    AACompromise,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RevocationReason {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RevocationReason::Unspecified => "UNSPECIFIED",
+            RevocationReason::KeyCompromise => "KEY_COMPROMISE",
+            RevocationReason::CertificateAuthorityCompromise => "CERTIFICATE_AUTHORITY_COMPROMISE",
+            RevocationReason::AffiliationChanged => "AFFILIATION_CHANGED",
+            RevocationReason::Superseded => "SUPERSEDED",
+            RevocationReason::CessationOfOperation => "CESSATION_OF_OPERATION",
+            RevocationReason::PrivilegeWithdrawn => "PRIVILEGE_WITHDRAWN",
+            RevocationReason::AACompromise => "A_A_COMPROMISE",
+            RevocationReason::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RevocationReason {
+    fn default() -> RevocationReason {
+        RevocationReason::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RevocationReason {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RevocationReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RevocationReason {
+    fn from(value: &'a str) -> RevocationReason {
+        match value {
+            "UNSPECIFIED" => RevocationReason::Unspecified,
+            "KEY_COMPROMISE" => RevocationReason::KeyCompromise,
+            "CERTIFICATE_AUTHORITY_COMPROMISE" => RevocationReason::CertificateAuthorityCompromise,
+            "AFFILIATION_CHANGED" => RevocationReason::AffiliationChanged,
+            "SUPERSEDED" => RevocationReason::Superseded,
+            "CESSATION_OF_OPERATION" => RevocationReason::CessationOfOperation,
+            "PRIVILEGE_WITHDRAWN" => RevocationReason::PrivilegeWithdrawn,
+            "A_A_COMPROMISE" => RevocationReason::AACompromise,
+            _ => RevocationReason::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RevocationReason {
+    fn from(value: String) -> RevocationReason {
+        match value.as_str() {
+            "UNSPECIFIED" => RevocationReason::Unspecified,
+            "KEY_COMPROMISE" => RevocationReason::KeyCompromise,
+            "CERTIFICATE_AUTHORITY_COMPROMISE" => RevocationReason::CertificateAuthorityCompromise,
+            "AFFILIATION_CHANGED" => RevocationReason::AffiliationChanged,
+            "SUPERSEDED" => RevocationReason::Superseded,
+            "CESSATION_OF_OPERATION" => RevocationReason::CessationOfOperation,
+            "PRIVILEGE_WITHDRAWN" => RevocationReason::PrivilegeWithdrawn,
+            "A_A_COMPROMISE" => RevocationReason::AACompromise,
+            _ => RevocationReason::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RevocationReason {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RevocationReason, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RevocationReason {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RevocationReason {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RevocationReason {
+    fn deserialize<D>(deserializer: D) -> Result<RevocationReason, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct RevokeCertificateRequest {
@@ -594,9 +1453,122 @@ pub struct RevokeCertificateRequest {
     pub certificate_serial: String,
     /// <p>Specifies why you revoked the certificate.</p>
     #[serde(rename = "RevocationReason")]
-    pub revocation_reason: String,
+    pub revocation_reason: RevocationReason,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SigningAlgorithm {
+    /// `SHA256WITHECDSA`
+    Sha256withecdsa,
+    /// `SHA384WITHECDSA`
+    Sha384withecdsa,
+    /// `SHA512WITHECDSA`
+    Sha512withecdsa,
+    /// `SHA256WITHRSA`
+    Sha256withrsa,
+    /// `SHA384WITHRSA`
+    Sha384withrsa,
+    /// `SHA512WITHRSA`
+    Sha512withrsa,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SigningAlgorithm {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SigningAlgorithm::Sha256withecdsa => "SHA256WITHECDSA",
+            SigningAlgorithm::Sha384withecdsa => "SHA384WITHECDSA",
+            SigningAlgorithm::Sha512withecdsa => "SHA512WITHECDSA",
+            SigningAlgorithm::Sha256withrsa => "SHA256WITHRSA",
+            SigningAlgorithm::Sha384withrsa => "SHA384WITHRSA",
+            SigningAlgorithm::Sha512withrsa => "SHA512WITHRSA",
+            SigningAlgorithm::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SigningAlgorithm {
+    fn default() -> SigningAlgorithm {
+        SigningAlgorithm::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SigningAlgorithm {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SigningAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SigningAlgorithm {
+    fn from(value: &'a str) -> SigningAlgorithm {
+        match value {
+            "SHA256WITHECDSA" => SigningAlgorithm::Sha256withecdsa,
+            "SHA384WITHECDSA" => SigningAlgorithm::Sha384withecdsa,
+            "SHA512WITHECDSA" => SigningAlgorithm::Sha512withecdsa,
+            "SHA256WITHRSA" => SigningAlgorithm::Sha256withrsa,
+            "SHA384WITHRSA" => SigningAlgorithm::Sha384withrsa,
+            "SHA512WITHRSA" => SigningAlgorithm::Sha512withrsa,
+            _ => SigningAlgorithm::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SigningAlgorithm {
+    fn from(value: String) -> SigningAlgorithm {
+        match value.as_str() {
+            "SHA256WITHECDSA" => SigningAlgorithm::Sha256withecdsa,
+            "SHA384WITHECDSA" => SigningAlgorithm::Sha384withecdsa,
+            "SHA512WITHECDSA" => SigningAlgorithm::Sha512withecdsa,
+            "SHA256WITHRSA" => SigningAlgorithm::Sha256withrsa,
+            "SHA384WITHRSA" => SigningAlgorithm::Sha384withrsa,
+            "SHA512WITHRSA" => SigningAlgorithm::Sha512withrsa,
+            _ => SigningAlgorithm::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SigningAlgorithm {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SigningAlgorithm, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SigningAlgorithm {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SigningAlgorithm {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for SigningAlgorithm {
+    fn deserialize<D>(deserializer: D) -> Result<SigningAlgorithm, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Tags are labels that you can use to identify and organize your private CAs. Each tag consists of a key and an optional value. You can associate up to 50 tags with a private CA. To add one or more tags to a private CA, call the <a>TagCertificateAuthority</a> action. To remove a tag, call the <a>UntagCertificateAuthority</a> action. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Tag {
@@ -644,7 +1616,7 @@ pub struct UpdateCertificateAuthorityRequest {
     /// <p>Status of your private CA.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<CertificateAuthorityStatus>,
 }
 
 /// <p>Length of time for which the certificate issued by your private certificate authority (CA), or by the private CA itself, is valid in days, months, or years. You can issue a certificate by calling the <a>IssueCertificate</a> action.</p>
@@ -653,12 +1625,120 @@ pub struct UpdateCertificateAuthorityRequest {
 pub struct Validity {
     /// <p>Specifies whether the <code>Value</code> parameter represents days, months, or years.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: ValidityPeriodType,
     /// <p>Time period.</p>
     #[serde(rename = "Value")]
     pub value: i64,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ValidityPeriodType {
+    /// `END_DATE`
+    EndDate,
+    /// `ABSOLUTE`
+    Absolute,
+    /// `DAYS`
+    Days,
+    /// `MONTHS`
+    Months,
+    /// `YEARS`
+    Years,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ValidityPeriodType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ValidityPeriodType::EndDate => "END_DATE",
+            ValidityPeriodType::Absolute => "ABSOLUTE",
+            ValidityPeriodType::Days => "DAYS",
+            ValidityPeriodType::Months => "MONTHS",
+            ValidityPeriodType::Years => "YEARS",
+            ValidityPeriodType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ValidityPeriodType {
+    fn default() -> ValidityPeriodType {
+        ValidityPeriodType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ValidityPeriodType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ValidityPeriodType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ValidityPeriodType {
+    fn from(value: &'a str) -> ValidityPeriodType {
+        match value {
+            "END_DATE" => ValidityPeriodType::EndDate,
+            "ABSOLUTE" => ValidityPeriodType::Absolute,
+            "DAYS" => ValidityPeriodType::Days,
+            "MONTHS" => ValidityPeriodType::Months,
+            "YEARS" => ValidityPeriodType::Years,
+            _ => ValidityPeriodType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ValidityPeriodType {
+    fn from(value: String) -> ValidityPeriodType {
+        match value.as_str() {
+            "END_DATE" => ValidityPeriodType::EndDate,
+            "ABSOLUTE" => ValidityPeriodType::Absolute,
+            "DAYS" => ValidityPeriodType::Days,
+            "MONTHS" => ValidityPeriodType::Months,
+            "YEARS" => ValidityPeriodType::Years,
+            _ => ValidityPeriodType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ValidityPeriodType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ValidityPeriodType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ValidityPeriodType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ValidityPeriodType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ValidityPeriodType {
+    fn deserialize<D>(deserializer: D) -> Result<ValidityPeriodType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// Errors returned by CreateCertificateAuthority
 #[derive(Debug, PartialEq)]
 pub enum CreateCertificateAuthorityError {
@@ -2019,11 +3099,12 @@ pub struct AcmPcaClient {
 impl AcmPcaClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> AcmPcaClient {
         AcmPcaClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "ACM PCA"),
         }
     }
 
@@ -2056,6 +3137,7 @@ impl AcmPca for AcmPcaClient {
     ) -> Result<CreateCertificateAuthorityResponse, RusotoError<CreateCertificateAuthorityError>>
     {
         let mut request = SignedRequest::new("POST", "acm-pca", &self.region, "/");
+        request.set_operation("CreateCertificateAuthority");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ACMPrivateCA.CreateCertificateAuthority");
@@ -2087,6 +3169,7 @@ impl AcmPca for AcmPcaClient {
         RusotoError<CreateCertificateAuthorityAuditReportError>,
     > {
         let mut request = SignedRequest::new("POST", "acm-pca", &self.region, "/");
+        request.set_operation("CreateCertificateAuthorityAuditReport");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2120,6 +3203,7 @@ impl AcmPca for AcmPcaClient {
         input: CreatePermissionRequest,
     ) -> Result<(), RusotoError<CreatePermissionError>> {
         let mut request = SignedRequest::new("POST", "acm-pca", &self.region, "/");
+        request.set_operation("CreatePermission");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ACMPrivateCA.CreatePermission");
@@ -2147,6 +3231,7 @@ impl AcmPca for AcmPcaClient {
         input: DeleteCertificateAuthorityRequest,
     ) -> Result<(), RusotoError<DeleteCertificateAuthorityError>> {
         let mut request = SignedRequest::new("POST", "acm-pca", &self.region, "/");
+        request.set_operation("DeleteCertificateAuthority");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ACMPrivateCA.DeleteCertificateAuthority");
@@ -2174,6 +3259,7 @@ impl AcmPca for AcmPcaClient {
         input: DeletePermissionRequest,
     ) -> Result<(), RusotoError<DeletePermissionError>> {
         let mut request = SignedRequest::new("POST", "acm-pca", &self.region, "/");
+        request.set_operation("DeletePermission");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ACMPrivateCA.DeletePermission");
@@ -2202,6 +3288,7 @@ impl AcmPca for AcmPcaClient {
     ) -> Result<DescribeCertificateAuthorityResponse, RusotoError<DescribeCertificateAuthorityError>>
     {
         let mut request = SignedRequest::new("POST", "acm-pca", &self.region, "/");
+        request.set_operation("DescribeCertificateAuthority");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ACMPrivateCA.DescribeCertificateAuthority");
@@ -2233,6 +3320,7 @@ impl AcmPca for AcmPcaClient {
         RusotoError<DescribeCertificateAuthorityAuditReportError>,
     > {
         let mut request = SignedRequest::new("POST", "acm-pca", &self.region, "/");
+        request.set_operation("DescribeCertificateAuthorityAuditReport");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2266,6 +3354,7 @@ impl AcmPca for AcmPcaClient {
         input: GetCertificateRequest,
     ) -> Result<GetCertificateResponse, RusotoError<GetCertificateError>> {
         let mut request = SignedRequest::new("POST", "acm-pca", &self.region, "/");
+        request.set_operation("GetCertificate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ACMPrivateCA.GetCertificate");
@@ -2296,6 +3385,7 @@ impl AcmPca for AcmPcaClient {
         RusotoError<GetCertificateAuthorityCertificateError>,
     > {
         let mut request = SignedRequest::new("POST", "acm-pca", &self.region, "/");
+        request.set_operation("GetCertificateAuthorityCertificate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2330,6 +3420,7 @@ impl AcmPca for AcmPcaClient {
     ) -> Result<GetCertificateAuthorityCsrResponse, RusotoError<GetCertificateAuthorityCsrError>>
     {
         let mut request = SignedRequest::new("POST", "acm-pca", &self.region, "/");
+        request.set_operation("GetCertificateAuthorityCsr");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ACMPrivateCA.GetCertificateAuthorityCsr");
@@ -2358,6 +3449,7 @@ impl AcmPca for AcmPcaClient {
         input: ImportCertificateAuthorityCertificateRequest,
     ) -> Result<(), RusotoError<ImportCertificateAuthorityCertificateError>> {
         let mut request = SignedRequest::new("POST", "acm-pca", &self.region, "/");
+        request.set_operation("ImportCertificateAuthorityCertificate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2390,6 +3482,7 @@ impl AcmPca for AcmPcaClient {
         input: IssueCertificateRequest,
     ) -> Result<IssueCertificateResponse, RusotoError<IssueCertificateError>> {
         let mut request = SignedRequest::new("POST", "acm-pca", &self.region, "/");
+        request.set_operation("IssueCertificate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ACMPrivateCA.IssueCertificate");
@@ -2419,6 +3512,7 @@ impl AcmPca for AcmPcaClient {
     ) -> Result<ListCertificateAuthoritiesResponse, RusotoError<ListCertificateAuthoritiesError>>
     {
         let mut request = SignedRequest::new("POST", "acm-pca", &self.region, "/");
+        request.set_operation("ListCertificateAuthorities");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ACMPrivateCA.ListCertificateAuthorities");
@@ -2447,6 +3541,7 @@ impl AcmPca for AcmPcaClient {
         input: ListPermissionsRequest,
     ) -> Result<ListPermissionsResponse, RusotoError<ListPermissionsError>> {
         let mut request = SignedRequest::new("POST", "acm-pca", &self.region, "/");
+        request.set_operation("ListPermissions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ACMPrivateCA.ListPermissions");
@@ -2474,6 +3569,7 @@ impl AcmPca for AcmPcaClient {
         input: ListTagsRequest,
     ) -> Result<ListTagsResponse, RusotoError<ListTagsError>> {
         let mut request = SignedRequest::new("POST", "acm-pca", &self.region, "/");
+        request.set_operation("ListTags");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ACMPrivateCA.ListTags");
@@ -2501,6 +3597,7 @@ impl AcmPca for AcmPcaClient {
         input: RestoreCertificateAuthorityRequest,
     ) -> Result<(), RusotoError<RestoreCertificateAuthorityError>> {
         let mut request = SignedRequest::new("POST", "acm-pca", &self.region, "/");
+        request.set_operation("RestoreCertificateAuthority");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ACMPrivateCA.RestoreCertificateAuthority");
@@ -2528,6 +3625,7 @@ impl AcmPca for AcmPcaClient {
         input: RevokeCertificateRequest,
     ) -> Result<(), RusotoError<RevokeCertificateError>> {
         let mut request = SignedRequest::new("POST", "acm-pca", &self.region, "/");
+        request.set_operation("RevokeCertificate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ACMPrivateCA.RevokeCertificate");
@@ -2555,6 +3653,7 @@ impl AcmPca for AcmPcaClient {
         input: TagCertificateAuthorityRequest,
     ) -> Result<(), RusotoError<TagCertificateAuthorityError>> {
         let mut request = SignedRequest::new("POST", "acm-pca", &self.region, "/");
+        request.set_operation("TagCertificateAuthority");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ACMPrivateCA.TagCertificateAuthority");
@@ -2582,6 +3681,7 @@ impl AcmPca for AcmPcaClient {
         input: UntagCertificateAuthorityRequest,
     ) -> Result<(), RusotoError<UntagCertificateAuthorityError>> {
         let mut request = SignedRequest::new("POST", "acm-pca", &self.region, "/");
+        request.set_operation("UntagCertificateAuthority");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ACMPrivateCA.UntagCertificateAuthority");
@@ -2609,6 +3709,7 @@ impl AcmPca for AcmPcaClient {
         input: UpdateCertificateAuthorityRequest,
     ) -> Result<(), RusotoError<UpdateCertificateAuthorityError>> {
         let mut request = SignedRequest::new("POST", "acm-pca", &self.region, "/");
+        request.set_operation("UpdateCertificateAuthority");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ACMPrivateCA.UpdateCertificateAuthority");
@@ -2630,3 +3731,229 @@ impl AcmPca for AcmPcaClient {
         }
     }
 }
+impl AcmPcaClient {
+    /// Auto-paginating version of `list_certificate_authorities`, streaming every page of results.
+    pub fn list_certificate_authorities_pages(
+        &self,
+        input: ListCertificateAuthoritiesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListCertificateAuthoritiesResponse,
+        ListCertificateAuthoritiesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AcmPcaClient, input: ListCertificateAuthoritiesRequest| async move {
+                client.list_certificate_authorities(input).await
+            },
+            |input: &ListCertificateAuthoritiesRequest,
+             output: &ListCertificateAuthoritiesResponse| {
+                Some(ListCertificateAuthoritiesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_certificate_authorities`, streaming the `CertificateAuthorities` of every page of results.
+    pub fn list_certificate_authorities_items(
+        &self,
+        input: ListCertificateAuthoritiesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<CertificateAuthority, ListCertificateAuthoritiesError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_certificate_authorities_pages(input),
+            |output: ListCertificateAuthoritiesResponse| {
+                output.certificate_authorities.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_permissions`, streaming every page of results.
+    pub fn list_permissions_pages(
+        &self,
+        input: ListPermissionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListPermissionsResponse, ListPermissionsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AcmPcaClient, input: ListPermissionsRequest| async move {
+                client.list_permissions(input).await
+            },
+            |input: &ListPermissionsRequest, output: &ListPermissionsResponse| {
+                Some(ListPermissionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_permissions`, streaming the `Permissions` of every page of results.
+    pub fn list_permissions_items(
+        &self,
+        input: ListPermissionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Permission, ListPermissionsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_permissions_pages(input),
+            |output: ListPermissionsResponse| output.permissions.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_tags`, streaming every page of results.
+    pub fn list_tags_pages(
+        &self,
+        input: ListTagsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListTagsResponse, ListTagsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AcmPcaClient, input: ListTagsRequest| async move { client.list_tags(input).await },
+            |input: &ListTagsRequest, output: &ListTagsResponse| {
+                Some(ListTagsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_tags`, streaming the `Tags` of every page of results.
+    pub fn list_tags_items(
+        &self,
+        input: ListTagsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Tag, ListTagsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_tags_pages(input),
+            |output: ListTagsResponse| output.tags.unwrap_or_default(),
+        )
+    }
+}
+impl AcmPcaClient {
+    /// Waits until `describe_certificate_authority_audit_report` reports the `AuditReportCreated` state, polling every 3 seconds up to 60 times.
+    pub async fn wait_until_audit_report_created(
+        &self,
+        input: DescribeCertificateAuthorityAuditReportRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeCertificateAuthorityAuditReportError>>
+    {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(3), 60);
+        self.wait_until_audit_report_created_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_audit_report_created`, polling as often and as many times as `config` says.
+    pub async fn wait_until_audit_report_created_with_config(
+        &self,
+        input: DescribeCertificateAuthorityAuditReportRequest,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeCertificateAuthorityAuditReportError>>
+    {
+        rusoto_core::waiter::wait(
+            "AuditReportCreated",
+            &config,
+            || self.describe_certificate_authority_audit_report(input.clone()),
+            |result: &Result<
+                DescribeCertificateAuthorityAuditReportResponse,
+                RusotoError<DescribeCertificateAuthorityAuditReportError>,
+            >| match result {
+                Ok(output)
+                    if output
+                        .audit_report_status
+                        .iter()
+                        .any(|v| *v == AuditReportStatus::Success) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if output
+                        .audit_report_status
+                        .iter()
+                        .any(|v| *v == AuditReportStatus::Failed) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+
+    /// Waits until `get_certificate_authority_csr` reports the `CertificateAuthorityCSRCreated` state, polling every 3 seconds up to 60 times.
+    pub async fn wait_until_certificate_authority_csr_created(
+        &self,
+        input: GetCertificateAuthorityCsrRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetCertificateAuthorityCsrError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(3), 60);
+        self.wait_until_certificate_authority_csr_created_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_certificate_authority_csr_created`, polling as often and as many times as `config` says.
+    pub async fn wait_until_certificate_authority_csr_created_with_config(
+        &self,
+        input: GetCertificateAuthorityCsrRequest,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetCertificateAuthorityCsrError>> {
+        rusoto_core::waiter::wait(
+            "CertificateAuthorityCSRCreated",
+            &config,
+            || self.get_certificate_authority_csr(input.clone()),
+            |result: &Result<
+                GetCertificateAuthorityCsrResponse,
+                RusotoError<GetCertificateAuthorityCsrError>,
+            >| match result {
+                Ok(_) => Some(rusoto_core::waiter::WaiterState::Success),
+                Err(RusotoError::Service(GetCertificateAuthorityCsrError::RequestInProgress(
+                    _,
+                ))) => Some(rusoto_core::waiter::WaiterState::Retry),
+                Err(RusotoError::Unknown(response))
+                    if rusoto_core::retry::error_code(response).as_deref()
+                        == Some("RequestInProgressException") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Retry)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+
+    /// Waits until `get_certificate` reports the `CertificateIssued` state, polling every 3 seconds up to 60 times.
+    pub async fn wait_until_certificate_issued(
+        &self,
+        input: GetCertificateRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetCertificateError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(3), 60);
+        self.wait_until_certificate_issued_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_certificate_issued`, polling as often and as many times as `config` says.
+    pub async fn wait_until_certificate_issued_with_config(
+        &self,
+        input: GetCertificateRequest,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetCertificateError>> {
+        rusoto_core::waiter::wait(
+            "CertificateIssued",
+            &config,
+            || self.get_certificate(input.clone()),
+            |result: &Result<GetCertificateResponse, RusotoError<GetCertificateError>>| match result
+            {
+                Ok(_) => Some(rusoto_core::waiter::WaiterState::Success),
+                Err(RusotoError::Service(GetCertificateError::RequestInProgress(_))) => {
+                    Some(rusoto_core::waiter::WaiterState::Retry)
+                }
+                Err(RusotoError::Unknown(response))
+                    if rusoto_core::retry::error_code(response).as_deref()
+                        == Some("RequestInProgressException") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Retry)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+}
@@ -49,8 +49,13 @@ pub struct CertificateDetail {
     pub certificate_authority_arn: Option<String>,
     /// <p>The time at which the certificate was requested. This value exists only when the certificate type is <code>AMAZON_ISSUED</code>. </p>
     #[serde(rename = "CreatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The fully qualified domain name for the certificate, such as www.example.com or example.com.</p>
     #[serde(rename = "DomainName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -66,19 +71,29 @@ pub struct CertificateDetail {
     /// <p>The reason the certificate request failed. This value exists only when the certificate status is <code>FAILED</code>. For more information, see <a href="https://docs.aws.amazon.com/acm/latest/userguide/troubleshooting.html#troubleshooting-failed">Certificate Request Failed</a> in the <i>AWS Certificate Manager User Guide</i>. </p>
     #[serde(rename = "FailureReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub failure_reason: Option<String>,
+    pub failure_reason: Option<FailureReason>,
     /// <p>The date and time at which the certificate was imported. This value exists only when the certificate type is <code>IMPORTED</code>. </p>
     #[serde(rename = "ImportedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub imported_at: Option<f64>,
+    pub imported_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A list of ARNs for the AWS resources that are using the certificate. A certificate can be used by multiple AWS resources. </p>
     #[serde(rename = "InUseBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub in_use_by: Option<Vec<String>>,
     /// <p>The time at which the certificate was issued. This value exists only when the certificate type is <code>AMAZON_ISSUED</code>. </p>
     #[serde(rename = "IssuedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub issued_at: Option<f64>,
+    pub issued_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the certificate authority that issued and signed the certificate.</p>
     #[serde(rename = "Issuer")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -86,19 +101,29 @@ pub struct CertificateDetail {
     /// <p>The algorithm that was used to generate the public-private key pair.</p>
     #[serde(rename = "KeyAlgorithm")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub key_algorithm: Option<String>,
+    pub key_algorithm: Option<KeyAlgorithm>,
     /// <p>A list of Key Usage X.509 v3 extension objects. Each object is a string value that identifies the purpose of the public key contained in the certificate. Possible extension values include DIGITAL_SIGNATURE, KEY_ENCHIPHERMENT, NON_REPUDIATION, and more.</p>
     #[serde(rename = "KeyUsages")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub key_usages: Option<Vec<KeyUsage>>,
     /// <p>The time after which the certificate is not valid.</p>
     #[serde(rename = "NotAfter")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub not_after: Option<f64>,
+    pub not_after: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The time before which the certificate is not valid.</p>
     #[serde(rename = "NotBefore")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub not_before: Option<f64>,
+    pub not_before: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Value that specifies whether to add the certificate to a transparency log. Certificate transparency makes it possible to detect SSL certificates that have been mistakenly or maliciously issued. A browser might respond to certificate that has not been logged by showing an error message. The logs are cryptographically secure. </p>
     #[serde(rename = "Options")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -106,7 +131,7 @@ pub struct CertificateDetail {
     /// <p>Specifies whether the certificate is eligible for renewal. At this time, only exported private certificates can be renewed with the <a>RenewCertificate</a> command.</p>
     #[serde(rename = "RenewalEligibility")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub renewal_eligibility: Option<String>,
+    pub renewal_eligibility: Option<RenewalEligibility>,
     /// <p>Contains information about the status of ACM's <a href="https://docs.aws.amazon.com/acm/latest/userguide/acm-renewal.html">managed renewal</a> for the certificate. This field exists only when the certificate type is <code>AMAZON_ISSUED</code>.</p>
     #[serde(rename = "RenewalSummary")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -114,11 +139,16 @@ pub struct CertificateDetail {
     /// <p>The reason the certificate was revoked. This value exists only when the certificate status is <code>REVOKED</code>. </p>
     #[serde(rename = "RevocationReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub revocation_reason: Option<String>,
+    pub revocation_reason: Option<RevocationReason>,
     /// <p>The time at which the certificate was revoked. This value exists only when the certificate status is <code>REVOKED</code>. </p>
     #[serde(rename = "RevokedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub revoked_at: Option<f64>,
+    pub revoked_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The serial number of the certificate.</p>
     #[serde(rename = "Serial")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -130,7 +160,7 @@ pub struct CertificateDetail {
     /// <p>The status of the certificate.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<CertificateStatus>,
     /// <p>The name of the entity that is associated with the public key contained in the certificate.</p>
     #[serde(rename = "Subject")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -142,7 +172,7 @@ pub struct CertificateDetail {
     /// <p>The source of the certificate. For certificates provided by ACM, this value is <code>AMAZON_ISSUED</code>. For certificates that you imported with <a>ImportCertificate</a>, this value is <code>IMPORTED</code>. ACM does not provide <a href="https://docs.aws.amazon.com/acm/latest/userguide/acm-renewal.html">managed renewal</a> for imported certificates. For more information about the differences between certificates that you import and those that ACM provides, see <a href="https://docs.aws.amazon.com/acm/latest/userguide/import-certificate.html">Importing Certificates</a> in the <i>AWS Certificate Manager User Guide</i>. </p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<CertificateType>,
 }
 
 /// <p>Structure that contains options for your certificate. Currently, you can use this only to specify whether to opt in to or out of certificate transparency logging. Some browsers require that public certificates issued for your domain be recorded in a log. Certificates that are not logged typically generate a browser error. Transparency makes it possible for you to detect SSL/TLS certificates that have been mistakenly or maliciously issued for your domain. For general information, see <a href="https://docs.aws.amazon.com/acm/latest/userguide/acm-concepts.html#concept-transparency">Certificate Transparency Logging</a>. </p>
@@ -151,9 +181,128 @@ pub struct CertificateOptions {
     /// <p>You can opt out of certificate transparency logging by specifying the <code>DISABLED</code> option. Opt in by specifying <code>ENABLED</code>. </p>
     #[serde(rename = "CertificateTransparencyLoggingPreference")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub certificate_transparency_logging_preference: Option<String>,
+    pub certificate_transparency_logging_preference:
+        Option<CertificateTransparencyLoggingPreference>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CertificateStatus {
+    /// `PENDING_VALIDATION`
+    PendingValidation,
+    /// `ISSUED`
+    Issued,
+    /// `INACTIVE`
+    Inactive,
+    /// `EXPIRED`
+    Expired,
+    /// `VALIDATION_TIMED_OUT`
+    ValidationTimedOut,
+    /// `REVOKED`
+    Revoked,
+    /// `FAILED`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CertificateStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CertificateStatus::PendingValidation => "PENDING_VALIDATION",
+            CertificateStatus::Issued => "ISSUED",
+            CertificateStatus::Inactive => "INACTIVE",
+            CertificateStatus::Expired => "EXPIRED",
+            CertificateStatus::ValidationTimedOut => "VALIDATION_TIMED_OUT",
+            CertificateStatus::Revoked => "REVOKED",
+            CertificateStatus::Failed => "FAILED",
+            CertificateStatus::Unknown(ref value) => value,
+        }
+    }
 }
 
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CertificateStatus {
+    fn default() -> CertificateStatus {
+        CertificateStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CertificateStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CertificateStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CertificateStatus {
+    fn from(value: &'a str) -> CertificateStatus {
+        match value {
+            "PENDING_VALIDATION" => CertificateStatus::PendingValidation,
+            "ISSUED" => CertificateStatus::Issued,
+            "INACTIVE" => CertificateStatus::Inactive,
+            "EXPIRED" => CertificateStatus::Expired,
+            "VALIDATION_TIMED_OUT" => CertificateStatus::ValidationTimedOut,
+            "REVOKED" => CertificateStatus::Revoked,
+            "FAILED" => CertificateStatus::Failed,
+            _ => CertificateStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CertificateStatus {
+    fn from(value: String) -> CertificateStatus {
+        match value.as_str() {
+            "PENDING_VALIDATION" => CertificateStatus::PendingValidation,
+            "ISSUED" => CertificateStatus::Issued,
+            "INACTIVE" => CertificateStatus::Inactive,
+            "EXPIRED" => CertificateStatus::Expired,
+            "VALIDATION_TIMED_OUT" => CertificateStatus::ValidationTimedOut,
+            "REVOKED" => CertificateStatus::Revoked,
+            "FAILED" => CertificateStatus::Failed,
+            _ => CertificateStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CertificateStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CertificateStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CertificateStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CertificateStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CertificateStatus {
+    fn deserialize<D>(deserializer: D) -> Result<CertificateStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>This structure is returned in the response object of <a>ListCertificates</a> action. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -168,6 +317,197 @@ pub struct CertificateSummary {
     pub domain_name: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CertificateTransparencyLoggingPreference {
+    /// `ENABLED`
+    Enabled,
+    /// `DISABLED`
+    Disabled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CertificateTransparencyLoggingPreference {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CertificateTransparencyLoggingPreference::Enabled => "ENABLED",
+            CertificateTransparencyLoggingPreference::Disabled => "DISABLED",
+            CertificateTransparencyLoggingPreference::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CertificateTransparencyLoggingPreference {
+    fn default() -> CertificateTransparencyLoggingPreference {
+        CertificateTransparencyLoggingPreference::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CertificateTransparencyLoggingPreference {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CertificateTransparencyLoggingPreference {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CertificateTransparencyLoggingPreference {
+    fn from(value: &'a str) -> CertificateTransparencyLoggingPreference {
+        match value {
+            "ENABLED" => CertificateTransparencyLoggingPreference::Enabled,
+            "DISABLED" => CertificateTransparencyLoggingPreference::Disabled,
+            _ => CertificateTransparencyLoggingPreference::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CertificateTransparencyLoggingPreference {
+    fn from(value: String) -> CertificateTransparencyLoggingPreference {
+        match value.as_str() {
+            "ENABLED" => CertificateTransparencyLoggingPreference::Enabled,
+            "DISABLED" => CertificateTransparencyLoggingPreference::Disabled,
+            _ => CertificateTransparencyLoggingPreference::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CertificateTransparencyLoggingPreference {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CertificateTransparencyLoggingPreference, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CertificateTransparencyLoggingPreference {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CertificateTransparencyLoggingPreference {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CertificateTransparencyLoggingPreference {
+    fn deserialize<D>(deserializer: D) -> Result<CertificateTransparencyLoggingPreference, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CertificateType {
+    /// `IMPORTED`
+    Imported,
+    /// `AMAZON_ISSUED`
+    AmazonIssued,
+    /// `PRIVATE`
+    Private,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CertificateType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CertificateType::Imported => "IMPORTED",
+            CertificateType::AmazonIssued => "AMAZON_ISSUED",
+            CertificateType::Private => "PRIVATE",
+            CertificateType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CertificateType {
+    fn default() -> CertificateType {
+        CertificateType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CertificateType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CertificateType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CertificateType {
+    fn from(value: &'a str) -> CertificateType {
+        match value {
+            "IMPORTED" => CertificateType::Imported,
+            "AMAZON_ISSUED" => CertificateType::AmazonIssued,
+            "PRIVATE" => CertificateType::Private,
+            _ => CertificateType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CertificateType {
+    fn from(value: String) -> CertificateType {
+        match value.as_str() {
+            "IMPORTED" => CertificateType::Imported,
+            "AMAZON_ISSUED" => CertificateType::AmazonIssued,
+            "PRIVATE" => CertificateType::Private,
+            _ => CertificateType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CertificateType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CertificateType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CertificateType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CertificateType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CertificateType {
+    fn deserialize<D>(deserializer: D) -> Result<CertificateType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct DeleteCertificateRequest {
@@ -193,6 +533,104 @@ pub struct DescribeCertificateResponse {
     pub certificate: Option<CertificateDetail>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DomainStatus {
+    /// `PENDING_VALIDATION`
+    PendingValidation,
+    /// `SUCCESS`
+    Success,
+    /// `FAILED`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DomainStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DomainStatus::PendingValidation => "PENDING_VALIDATION",
+            DomainStatus::Success => "SUCCESS",
+            DomainStatus::Failed => "FAILED",
+            DomainStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DomainStatus {
+    fn default() -> DomainStatus {
+        DomainStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DomainStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DomainStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DomainStatus {
+    fn from(value: &'a str) -> DomainStatus {
+        match value {
+            "PENDING_VALIDATION" => DomainStatus::PendingValidation,
+            "SUCCESS" => DomainStatus::Success,
+            "FAILED" => DomainStatus::Failed,
+            _ => DomainStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DomainStatus {
+    fn from(value: String) -> DomainStatus {
+        match value.as_str() {
+            "PENDING_VALIDATION" => DomainStatus::PendingValidation,
+            "SUCCESS" => DomainStatus::Success,
+            "FAILED" => DomainStatus::Failed,
+            _ => DomainStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DomainStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DomainStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DomainStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DomainStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DomainStatus {
+    fn deserialize<D>(deserializer: D) -> Result<DomainStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Contains information about the validation of each domain name in the certificate.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -215,11 +653,11 @@ pub struct DomainValidation {
     /// <p>Specifies the domain validation method.</p>
     #[serde(rename = "ValidationMethod")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub validation_method: Option<String>,
+    pub validation_method: Option<ValidationMethod>,
     /// <p><p>The validation status of the domain name. This can be one of the following values:</p> <ul> <li> <p> <code>PENDING_VALIDATION</code> </p> </li> <li> <p> <code/>SUCCESS</p> </li> <li> <p> <code/>FAILED</p> </li> </ul></p>
     #[serde(rename = "ValidationStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub validation_status: Option<String>,
+    pub validation_status: Option<DomainStatus>,
 }
 
 /// <p>Contains information about the domain names that you want ACM to use to send you emails that enable you to validate domain ownership.</p>
@@ -274,13 +712,324 @@ pub struct ExtendedKeyUsage {
     /// <p>The name of an Extended Key Usage value.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
+    pub name: Option<ExtendedKeyUsageName>,
     /// <p><p>An object identifier (OID) for the extension value. OIDs are strings of numbers separated by periods. The following OIDs are defined in RFC 3280 and RFC 5280. </p> <ul> <li> <p> <code>1.3.6.1.5.5.7.3.1 (TLS<em>WEB</em>SERVER<em>AUTHENTICATION)</code> </p> </li> <li> <p> <code>1.3.6.1.5.5.7.3.2 (TLS</em>WEB<em>CLIENT</em>AUTHENTICATION)</code> </p> </li> <li> <p> <code>1.3.6.1.5.5.7.3.3 (CODE<em>SIGNING)</code> </p> </li> <li> <p> <code>1.3.6.1.5.5.7.3.4 (EMAIL</em>PROTECTION)</code> </p> </li> <li> <p> <code>1.3.6.1.5.5.7.3.8 (TIME<em>STAMPING)</code> </p> </li> <li> <p> <code>1.3.6.1.5.5.7.3.9 (OCSP</em>SIGNING)</code> </p> </li> <li> <p> <code>1.3.6.1.5.5.7.3.5 (IPSEC<em>END</em>SYSTEM)</code> </p> </li> <li> <p> <code>1.3.6.1.5.5.7.3.6 (IPSEC<em>TUNNEL)</code> </p> </li> <li> <p> <code>1.3.6.1.5.5.7.3.7 (IPSEC</em>USER)</code> </p> </li> </ul></p>
     #[serde(rename = "OID")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub oid: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ExtendedKeyUsageName {
+    /// `TLS_WEB_SERVER_AUTHENTICATION`
+    TlsWebServerAuthentication,
+    /// `TLS_WEB_CLIENT_AUTHENTICATION`
+    TlsWebClientAuthentication,
+    /// `CODE_SIGNING`
+    CodeSigning,
+    /// `EMAIL_PROTECTION`
+    EmailProtection,
+    /// `TIME_STAMPING`
+    TimeStamping,
+    /// `OCSP_SIGNING`
+    OcspSigning,
+    /// `IPSEC_END_SYSTEM`
+    IpsecEndSystem,
+    /// `IPSEC_TUNNEL`
+    IpsecTunnel,
+    /// `IPSEC_USER`
+    IpsecUser,
+    /// `ANY`
+    Any,
+    /// `NONE`
+    None,
+    /// `CUSTOM`
+    Custom,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ExtendedKeyUsageName {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ExtendedKeyUsageName::TlsWebServerAuthentication => "TLS_WEB_SERVER_AUTHENTICATION",
+            ExtendedKeyUsageName::TlsWebClientAuthentication => "TLS_WEB_CLIENT_AUTHENTICATION",
+            ExtendedKeyUsageName::CodeSigning => "CODE_SIGNING",
+            ExtendedKeyUsageName::EmailProtection => "EMAIL_PROTECTION",
+            ExtendedKeyUsageName::TimeStamping => "TIME_STAMPING",
+            ExtendedKeyUsageName::OcspSigning => "OCSP_SIGNING",
+            ExtendedKeyUsageName::IpsecEndSystem => "IPSEC_END_SYSTEM",
+            ExtendedKeyUsageName::IpsecTunnel => "IPSEC_TUNNEL",
+            ExtendedKeyUsageName::IpsecUser => "IPSEC_USER",
+            ExtendedKeyUsageName::Any => "ANY",
+            ExtendedKeyUsageName::None => "NONE",
+            ExtendedKeyUsageName::Custom => "CUSTOM",
+            ExtendedKeyUsageName::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ExtendedKeyUsageName {
+    fn default() -> ExtendedKeyUsageName {
+        ExtendedKeyUsageName::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ExtendedKeyUsageName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ExtendedKeyUsageName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ExtendedKeyUsageName {
+    fn from(value: &'a str) -> ExtendedKeyUsageName {
+        match value {
+            "TLS_WEB_SERVER_AUTHENTICATION" => ExtendedKeyUsageName::TlsWebServerAuthentication,
+            "TLS_WEB_CLIENT_AUTHENTICATION" => ExtendedKeyUsageName::TlsWebClientAuthentication,
+            "CODE_SIGNING" => ExtendedKeyUsageName::CodeSigning,
+            "EMAIL_PROTECTION" => ExtendedKeyUsageName::EmailProtection,
+            "TIME_STAMPING" => ExtendedKeyUsageName::TimeStamping,
+            "OCSP_SIGNING" => ExtendedKeyUsageName::OcspSigning,
+            "IPSEC_END_SYSTEM" => ExtendedKeyUsageName::IpsecEndSystem,
+            "IPSEC_TUNNEL" => ExtendedKeyUsageName::IpsecTunnel,
+            "IPSEC_USER" => ExtendedKeyUsageName::IpsecUser,
+            "ANY" => ExtendedKeyUsageName::Any,
+            "NONE" => ExtendedKeyUsageName::None,
+            "CUSTOM" => ExtendedKeyUsageName::Custom,
+            _ => ExtendedKeyUsageName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ExtendedKeyUsageName {
+    fn from(value: String) -> ExtendedKeyUsageName {
+        match value.as_str() {
+            "TLS_WEB_SERVER_AUTHENTICATION" => ExtendedKeyUsageName::TlsWebServerAuthentication,
+            "TLS_WEB_CLIENT_AUTHENTICATION" => ExtendedKeyUsageName::TlsWebClientAuthentication,
+            "CODE_SIGNING" => ExtendedKeyUsageName::CodeSigning,
+            "EMAIL_PROTECTION" => ExtendedKeyUsageName::EmailProtection,
+            "TIME_STAMPING" => ExtendedKeyUsageName::TimeStamping,
+            "OCSP_SIGNING" => ExtendedKeyUsageName::OcspSigning,
+            "IPSEC_END_SYSTEM" => ExtendedKeyUsageName::IpsecEndSystem,
+            "IPSEC_TUNNEL" => ExtendedKeyUsageName::IpsecTunnel,
+            "IPSEC_USER" => ExtendedKeyUsageName::IpsecUser,
+            "ANY" => ExtendedKeyUsageName::Any,
+            "NONE" => ExtendedKeyUsageName::None,
+            "CUSTOM" => ExtendedKeyUsageName::Custom,
+            _ => ExtendedKeyUsageName::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ExtendedKeyUsageName {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ExtendedKeyUsageName, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ExtendedKeyUsageName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ExtendedKeyUsageName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ExtendedKeyUsageName {
+    fn deserialize<D>(deserializer: D) -> Result<ExtendedKeyUsageName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FailureReason {
+    /// `NO_AVAILABLE_CONTACTS`
+    NoAvailableContacts,
+    /// `ADDITIONAL_VERIFICATION_REQUIRED`
+    AdditionalVerificationRequired,
+    /// `DOMAIN_NOT_ALLOWED`
+    DomainNotAllowed,
+    /// `INVALID_PUBLIC_DOMAIN`
+    InvalidPublicDomain,
+    /// `DOMAIN_VALIDATION_DENIED`
+    DomainValidationDenied,
+    /// `CAA_ERROR`
+    CaaError,
+    /// `PCA_LIMIT_EXCEEDED`
+    PcaLimitExceeded,
+    /// `PCA_INVALID_ARN`
+    PcaInvalidArn,
+    /// `PCA_INVALID_STATE`
+    PcaInvalidState,
+    /// `PCA_REQUEST_FAILED`
+    PcaRequestFailed,
+    /// `PCA_NAME_CONSTRAINTS_VALIDATION`
+    PcaNameConstraintsValidation,
+    /// `PCA_RESOURCE_NOT_FOUND`
+    PcaResourceNotFound,
+    /// `PCA_INVALID_ARGS`
+    PcaInvalidArgs,
+    /// `PCA_INVALID_DURATION`
+    PcaInvalidDuration,
+    /// `PCA_ACCESS_DENIED`
+    PcaAccessDenied,
+    /// `SLR_NOT_FOUND`
+    SlrNotFound,
+    /// `OTHER`
+    Other,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl FailureReason {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FailureReason::NoAvailableContacts => "NO_AVAILABLE_CONTACTS",
+            FailureReason::AdditionalVerificationRequired => "ADDITIONAL_VERIFICATION_REQUIRED",
+            FailureReason::DomainNotAllowed => "DOMAIN_NOT_ALLOWED",
+            FailureReason::InvalidPublicDomain => "INVALID_PUBLIC_DOMAIN",
+            FailureReason::DomainValidationDenied => "DOMAIN_VALIDATION_DENIED",
+            FailureReason::CaaError => "CAA_ERROR",
+            FailureReason::PcaLimitExceeded => "PCA_LIMIT_EXCEEDED",
+            FailureReason::PcaInvalidArn => "PCA_INVALID_ARN",
+            FailureReason::PcaInvalidState => "PCA_INVALID_STATE",
+            FailureReason::PcaRequestFailed => "PCA_REQUEST_FAILED",
+            FailureReason::PcaNameConstraintsValidation => "PCA_NAME_CONSTRAINTS_VALIDATION",
+            FailureReason::PcaResourceNotFound => "PCA_RESOURCE_NOT_FOUND",
+            FailureReason::PcaInvalidArgs => "PCA_INVALID_ARGS",
+            FailureReason::PcaInvalidDuration => "PCA_INVALID_DURATION",
+            FailureReason::PcaAccessDenied => "PCA_ACCESS_DENIED",
+            FailureReason::SlrNotFound => "SLR_NOT_FOUND",
+            FailureReason::Other => "OTHER",
+            FailureReason::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for FailureReason {
+    fn default() -> FailureReason {
+        FailureReason::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for FailureReason {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FailureReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FailureReason {
+    fn from(value: &'a str) -> FailureReason {
+        match value {
+            "NO_AVAILABLE_CONTACTS" => FailureReason::NoAvailableContacts,
+            "ADDITIONAL_VERIFICATION_REQUIRED" => FailureReason::AdditionalVerificationRequired,
+            "DOMAIN_NOT_ALLOWED" => FailureReason::DomainNotAllowed,
+            "INVALID_PUBLIC_DOMAIN" => FailureReason::InvalidPublicDomain,
+            "DOMAIN_VALIDATION_DENIED" => FailureReason::DomainValidationDenied,
+            "CAA_ERROR" => FailureReason::CaaError,
+            "PCA_LIMIT_EXCEEDED" => FailureReason::PcaLimitExceeded,
+            "PCA_INVALID_ARN" => FailureReason::PcaInvalidArn,
+            "PCA_INVALID_STATE" => FailureReason::PcaInvalidState,
+            "PCA_REQUEST_FAILED" => FailureReason::PcaRequestFailed,
+            "PCA_NAME_CONSTRAINTS_VALIDATION" => FailureReason::PcaNameConstraintsValidation,
+            "PCA_RESOURCE_NOT_FOUND" => FailureReason::PcaResourceNotFound,
+            "PCA_INVALID_ARGS" => FailureReason::PcaInvalidArgs,
+            "PCA_INVALID_DURATION" => FailureReason::PcaInvalidDuration,
+            "PCA_ACCESS_DENIED" => FailureReason::PcaAccessDenied,
+            "SLR_NOT_FOUND" => FailureReason::SlrNotFound,
+            "OTHER" => FailureReason::Other,
+            _ => FailureReason::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FailureReason {
+    fn from(value: String) -> FailureReason {
+        match value.as_str() {
+            "NO_AVAILABLE_CONTACTS" => FailureReason::NoAvailableContacts,
+            "ADDITIONAL_VERIFICATION_REQUIRED" => FailureReason::AdditionalVerificationRequired,
+            "DOMAIN_NOT_ALLOWED" => FailureReason::DomainNotAllowed,
+            "INVALID_PUBLIC_DOMAIN" => FailureReason::InvalidPublicDomain,
+            "DOMAIN_VALIDATION_DENIED" => FailureReason::DomainValidationDenied,
+            "CAA_ERROR" => FailureReason::CaaError,
+            "PCA_LIMIT_EXCEEDED" => FailureReason::PcaLimitExceeded,
+            "PCA_INVALID_ARN" => FailureReason::PcaInvalidArn,
+            "PCA_INVALID_STATE" => FailureReason::PcaInvalidState,
+            "PCA_REQUEST_FAILED" => FailureReason::PcaRequestFailed,
+            "PCA_NAME_CONSTRAINTS_VALIDATION" => FailureReason::PcaNameConstraintsValidation,
+            "PCA_RESOURCE_NOT_FOUND" => FailureReason::PcaResourceNotFound,
+            "PCA_INVALID_ARGS" => FailureReason::PcaInvalidArgs,
+            "PCA_INVALID_DURATION" => FailureReason::PcaInvalidDuration,
+            "PCA_ACCESS_DENIED" => FailureReason::PcaAccessDenied,
+            "SLR_NOT_FOUND" => FailureReason::SlrNotFound,
+            "OTHER" => FailureReason::Other,
+            _ => FailureReason::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for FailureReason {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<FailureReason, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FailureReason {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FailureReason {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FailureReason {
+    fn deserialize<D>(deserializer: D) -> Result<FailureReason, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>This structure can be used in the <a>ListCertificates</a> action to filter the output of the certificate list. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -288,15 +1037,15 @@ pub struct Filters {
     /// <p>Specify one or more <a>ExtendedKeyUsage</a> extension values.</p>
     #[serde(rename = "extendedKeyUsage")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub extended_key_usage: Option<Vec<String>>,
+    pub extended_key_usage: Option<Vec<ExtendedKeyUsageName>>,
     /// <p>Specify one or more algorithms that can be used to generate key pairs.</p> <p>Default filtering returns only <code>RSA_2048</code> certificates. To return other certificate types, provide the desired type signatures in a comma-separated list. For example, <code>"keyTypes": ["RSA_2048,RSA_4096"]</code> returns both <code>RSA_2048</code> and <code>RSA_4096</code> certificates.</p>
     #[serde(rename = "keyTypes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub key_types: Option<Vec<String>>,
+    pub key_types: Option<Vec<KeyAlgorithm>>,
     /// <p>Specify one or more <a>KeyUsage</a> extension values.</p>
     #[serde(rename = "keyUsage")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub key_usage: Option<Vec<String>>,
+    pub key_usage: Option<Vec<KeyUsageName>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -358,32 +1107,288 @@ pub struct ImportCertificateRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct ImportCertificateResponse {
-    /// <p>The <a href="https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Name (ARN)</a> of the imported certificate.</p>
-    #[serde(rename = "CertificateArn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub certificate_arn: Option<String>,
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct ImportCertificateResponse {
+    /// <p>The <a href="https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Name (ARN)</a> of the imported certificate.</p>
+    #[serde(rename = "CertificateArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub certificate_arn: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum KeyAlgorithm {
+    /// `RSA_1024`
+    Rsa1024,
+    /// `RSA_2048`
+    Rsa2048,
+    /// `RSA_3072`
+    Rsa3072,
+    /// `RSA_4096`
+    Rsa4096,
+    /// `EC_prime256v1`
+    EcPrime256v1,
+    /// `EC_secp384r1`
+    EcSecp384r1,
+    /// `EC_secp521r1`
+    EcSecp521r1,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl KeyAlgorithm {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            KeyAlgorithm::Rsa1024 => "RSA_1024",
+            KeyAlgorithm::Rsa2048 => "RSA_2048",
+            KeyAlgorithm::Rsa3072 => "RSA_3072",
+            KeyAlgorithm::Rsa4096 => "RSA_4096",
+            KeyAlgorithm::EcPrime256v1 => "EC_prime256v1",
+            KeyAlgorithm::EcSecp384r1 => "EC_secp384r1",
+            KeyAlgorithm::EcSecp521r1 => "EC_secp521r1",
+            KeyAlgorithm::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for KeyAlgorithm {
+    fn default() -> KeyAlgorithm {
+        KeyAlgorithm::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for KeyAlgorithm {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for KeyAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for KeyAlgorithm {
+    fn from(value: &'a str) -> KeyAlgorithm {
+        match value {
+            "RSA_1024" => KeyAlgorithm::Rsa1024,
+            "RSA_2048" => KeyAlgorithm::Rsa2048,
+            "RSA_3072" => KeyAlgorithm::Rsa3072,
+            "RSA_4096" => KeyAlgorithm::Rsa4096,
+            "EC_prime256v1" => KeyAlgorithm::EcPrime256v1,
+            "EC_secp384r1" => KeyAlgorithm::EcSecp384r1,
+            "EC_secp521r1" => KeyAlgorithm::EcSecp521r1,
+            _ => KeyAlgorithm::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for KeyAlgorithm {
+    fn from(value: String) -> KeyAlgorithm {
+        match value.as_str() {
+            "RSA_1024" => KeyAlgorithm::Rsa1024,
+            "RSA_2048" => KeyAlgorithm::Rsa2048,
+            "RSA_3072" => KeyAlgorithm::Rsa3072,
+            "RSA_4096" => KeyAlgorithm::Rsa4096,
+            "EC_prime256v1" => KeyAlgorithm::EcPrime256v1,
+            "EC_secp384r1" => KeyAlgorithm::EcSecp384r1,
+            "EC_secp521r1" => KeyAlgorithm::EcSecp521r1,
+            _ => KeyAlgorithm::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for KeyAlgorithm {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<KeyAlgorithm, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for KeyAlgorithm {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for KeyAlgorithm {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for KeyAlgorithm {
+    fn deserialize<D>(deserializer: D) -> Result<KeyAlgorithm, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>The Key Usage X.509 v3 extension defines the purpose of the public key contained in the certificate.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct KeyUsage {
+    /// <p>A string value that contains a Key Usage extension name.</p>
+    #[serde(rename = "Name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<KeyUsageName>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum KeyUsageName {
+    /// `DIGITAL_SIGNATURE`
+    DigitalSignature,
+    /// `NON_REPUDIATION`
+    NonRepudiation,
+    /// `KEY_ENCIPHERMENT`
+    KeyEncipherment,
+    /// `DATA_ENCIPHERMENT`
+    DataEncipherment,
+    /// `KEY_AGREEMENT`
+    KeyAgreement,
+    /// `CERTIFICATE_SIGNING`
+    CertificateSigning,
+    /// `CRL_SIGNING`
+    CrlSigning,
+    /// `ENCIPHER_ONLY`
+    EncipherOnly,
+    /// `DECIPHER_ONLY`
+    DecipherOnly,
+    /// `ANY`
+    Any,
+    /// `CUSTOM`
+    Custom,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl KeyUsageName {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            KeyUsageName::DigitalSignature => "DIGITAL_SIGNATURE",
+            KeyUsageName::NonRepudiation => "NON_REPUDIATION",
+            KeyUsageName::KeyEncipherment => "KEY_ENCIPHERMENT",
+            KeyUsageName::DataEncipherment => "DATA_ENCIPHERMENT",
+            KeyUsageName::KeyAgreement => "KEY_AGREEMENT",
+            KeyUsageName::CertificateSigning => "CERTIFICATE_SIGNING",
+            KeyUsageName::CrlSigning => "CRL_SIGNING",
+            KeyUsageName::EncipherOnly => "ENCIPHER_ONLY",
+            KeyUsageName::DecipherOnly => "DECIPHER_ONLY",
+            KeyUsageName::Any => "ANY",
+            KeyUsageName::Custom => "CUSTOM",
+            KeyUsageName::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for KeyUsageName {
+    fn default() -> KeyUsageName {
+        KeyUsageName::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for KeyUsageName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for KeyUsageName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for KeyUsageName {
+    fn from(value: &'a str) -> KeyUsageName {
+        match value {
+            "DIGITAL_SIGNATURE" => KeyUsageName::DigitalSignature,
+            "NON_REPUDIATION" => KeyUsageName::NonRepudiation,
+            "KEY_ENCIPHERMENT" => KeyUsageName::KeyEncipherment,
+            "DATA_ENCIPHERMENT" => KeyUsageName::DataEncipherment,
+            "KEY_AGREEMENT" => KeyUsageName::KeyAgreement,
+            "CERTIFICATE_SIGNING" => KeyUsageName::CertificateSigning,
+            "CRL_SIGNING" => KeyUsageName::CrlSigning,
+            "ENCIPHER_ONLY" => KeyUsageName::EncipherOnly,
+            "DECIPHER_ONLY" => KeyUsageName::DecipherOnly,
+            "ANY" => KeyUsageName::Any,
+            "CUSTOM" => KeyUsageName::Custom,
+            _ => KeyUsageName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for KeyUsageName {
+    fn from(value: String) -> KeyUsageName {
+        match value.as_str() {
+            "DIGITAL_SIGNATURE" => KeyUsageName::DigitalSignature,
+            "NON_REPUDIATION" => KeyUsageName::NonRepudiation,
+            "KEY_ENCIPHERMENT" => KeyUsageName::KeyEncipherment,
+            "DATA_ENCIPHERMENT" => KeyUsageName::DataEncipherment,
+            "KEY_AGREEMENT" => KeyUsageName::KeyAgreement,
+            "CERTIFICATE_SIGNING" => KeyUsageName::CertificateSigning,
+            "CRL_SIGNING" => KeyUsageName::CrlSigning,
+            "ENCIPHER_ONLY" => KeyUsageName::EncipherOnly,
+            "DECIPHER_ONLY" => KeyUsageName::DecipherOnly,
+            "ANY" => KeyUsageName::Any,
+            "CUSTOM" => KeyUsageName::Custom,
+            _ => KeyUsageName::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for KeyUsageName {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<KeyUsageName, Self::Err> {
+        Ok(value.into())
+    }
 }
 
-/// <p>The Key Usage X.509 v3 extension defines the purpose of the public key contained in the certificate.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct KeyUsage {
-    /// <p>A string value that contains a Key Usage extension name.</p>
-    #[serde(rename = "Name")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
+impl ::rusoto_core::param::ToParam for KeyUsageName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for KeyUsageName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for KeyUsageName {
+    fn deserialize<D>(deserializer: D) -> Result<KeyUsageName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ListCertificatesRequest {
     /// <p>Filter the certificate list by status value.</p>
     #[serde(rename = "CertificateStatuses")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub certificate_statuses: Option<Vec<String>>,
+    pub certificate_statuses: Option<Vec<CertificateStatus>>,
     /// <p>Filter the certificate list. For more information, see the <a>Filters</a> structure.</p>
     #[serde(rename = "Includes")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -428,6 +1433,94 @@ pub struct ListTagsForCertificateResponse {
     pub tags: Option<Vec<Tag>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RecordType {
+    /// `CNAME`
+    Cname,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RecordType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RecordType::Cname => "CNAME",
+            RecordType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RecordType {
+    fn default() -> RecordType {
+        RecordType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RecordType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RecordType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RecordType {
+    fn from(value: &'a str) -> RecordType {
+        match value {
+            "CNAME" => RecordType::Cname,
+            _ => RecordType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RecordType {
+    fn from(value: String) -> RecordType {
+        match value.as_str() {
+            "CNAME" => RecordType::Cname,
+            _ => RecordType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RecordType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RecordType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RecordType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RecordType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RecordType {
+    fn deserialize<D>(deserializer: D) -> Result<RecordType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct RemoveTagsFromCertificateRequest {
@@ -447,6 +1540,202 @@ pub struct RenewCertificateRequest {
     pub certificate_arn: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RenewalEligibility {
+    /// `ELIGIBLE`
+    Eligible,
+    /// `INELIGIBLE`
+    Ineligible,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RenewalEligibility {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RenewalEligibility::Eligible => "ELIGIBLE",
+            RenewalEligibility::Ineligible => "INELIGIBLE",
+            RenewalEligibility::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RenewalEligibility {
+    fn default() -> RenewalEligibility {
+        RenewalEligibility::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RenewalEligibility {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RenewalEligibility {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RenewalEligibility {
+    fn from(value: &'a str) -> RenewalEligibility {
+        match value {
+            "ELIGIBLE" => RenewalEligibility::Eligible,
+            "INELIGIBLE" => RenewalEligibility::Ineligible,
+            _ => RenewalEligibility::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RenewalEligibility {
+    fn from(value: String) -> RenewalEligibility {
+        match value.as_str() {
+            "ELIGIBLE" => RenewalEligibility::Eligible,
+            "INELIGIBLE" => RenewalEligibility::Ineligible,
+            _ => RenewalEligibility::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RenewalEligibility {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RenewalEligibility, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RenewalEligibility {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RenewalEligibility {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RenewalEligibility {
+    fn deserialize<D>(deserializer: D) -> Result<RenewalEligibility, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RenewalStatus {
+    /// `PENDING_AUTO_RENEWAL`
+    PendingAutoRenewal,
+    /// `PENDING_VALIDATION`
+    PendingValidation,
+    /// `SUCCESS`
+    Success,
+    /// `FAILED`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RenewalStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RenewalStatus::PendingAutoRenewal => "PENDING_AUTO_RENEWAL",
+            RenewalStatus::PendingValidation => "PENDING_VALIDATION",
+            RenewalStatus::Success => "SUCCESS",
+            RenewalStatus::Failed => "FAILED",
+            RenewalStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RenewalStatus {
+    fn default() -> RenewalStatus {
+        RenewalStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RenewalStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RenewalStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RenewalStatus {
+    fn from(value: &'a str) -> RenewalStatus {
+        match value {
+            "PENDING_AUTO_RENEWAL" => RenewalStatus::PendingAutoRenewal,
+            "PENDING_VALIDATION" => RenewalStatus::PendingValidation,
+            "SUCCESS" => RenewalStatus::Success,
+            "FAILED" => RenewalStatus::Failed,
+            _ => RenewalStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RenewalStatus {
+    fn from(value: String) -> RenewalStatus {
+        match value.as_str() {
+            "PENDING_AUTO_RENEWAL" => RenewalStatus::PendingAutoRenewal,
+            "PENDING_VALIDATION" => RenewalStatus::PendingValidation,
+            "SUCCESS" => RenewalStatus::Success,
+            "FAILED" => RenewalStatus::Failed,
+            _ => RenewalStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RenewalStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RenewalStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RenewalStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RenewalStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RenewalStatus {
+    fn deserialize<D>(deserializer: D) -> Result<RenewalStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Contains information about the status of ACM's <a href="https://docs.aws.amazon.com/acm/latest/userguide/acm-renewal.html">managed renewal</a> for the certificate. This structure exists only when the certificate type is <code>AMAZON_ISSUED</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -456,14 +1745,19 @@ pub struct RenewalSummary {
     pub domain_validation_options: Vec<DomainValidation>,
     /// <p>The status of ACM's <a href="https://docs.aws.amazon.com/acm/latest/userguide/acm-renewal.html">managed renewal</a> of the certificate.</p>
     #[serde(rename = "RenewalStatus")]
-    pub renewal_status: String,
+    pub renewal_status: RenewalStatus,
     /// <p>The reason that a renewal request was unsuccessful.</p>
     #[serde(rename = "RenewalStatusReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub renewal_status_reason: Option<String>,
+    pub renewal_status_reason: Option<FailureReason>,
     /// <p>The time at which the renewal summary was last updated.</p>
     #[serde(rename = "UpdatedAt")]
-    pub updated_at: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub updated_at: ::chrono::DateTime<::chrono::Utc>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -499,7 +1793,7 @@ pub struct RequestCertificateRequest {
     /// <p>The method you want to use if you are requesting a public certificate to validate that you own or control domain. You can <a href="https://docs.aws.amazon.com/acm/latest/userguide/gs-acm-validate-dns.html">validate with DNS</a> or <a href="https://docs.aws.amazon.com/acm/latest/userguide/gs-acm-validate-email.html">validate with email</a>. We recommend that you use DNS validation. </p>
     #[serde(rename = "ValidationMethod")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub validation_method: Option<String>,
+    pub validation_method: Option<ValidationMethod>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -534,12 +1828,145 @@ pub struct ResourceRecord {
     pub name: String,
     /// <p>The type of DNS record. Currently this can be <code>CNAME</code>.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: RecordType,
     /// <p>The value of the CNAME record to add to your DNS database. This is supplied by ACM.</p>
     #[serde(rename = "Value")]
     pub value: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RevocationReason {
+    /// `UNSPECIFIED`
+    Unspecified,
+    /// `KEY_COMPROMISE`
+    KeyCompromise,
+    /// `CA_COMPROMISE`
+    CaCompromise,
+    /// `AFFILIATION_CHANGED`
+    AffiliationChanged,
+    /// `SUPERCEDED`
+    Superceded,
+    /// `CESSATION_OF_OPERATION`
+    CessationOfOperation,
+    /// `CERTIFICATE_HOLD`
+    CertificateHold,
+    /// `REMOVE_FROM_CRL`
+    RemoveFromCrl,
+    /// `PRIVILEGE_WITHDRAWN`
+    PrivilegeWithdrawn,
+    /// `A_A_COMPROMISE`
+    AACompromise,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RevocationReason {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RevocationReason::Unspecified => "UNSPECIFIED",
+            RevocationReason::KeyCompromise => "KEY_COMPROMISE",
+            RevocationReason::CaCompromise => "CA_COMPROMISE",
+            RevocationReason::AffiliationChanged => "AFFILIATION_CHANGED",
+            RevocationReason::Superceded => "SUPERCEDED",
+            RevocationReason::CessationOfOperation => "CESSATION_OF_OPERATION",
+            RevocationReason::CertificateHold => "CERTIFICATE_HOLD",
+            RevocationReason::RemoveFromCrl => "REMOVE_FROM_CRL",
+            RevocationReason::PrivilegeWithdrawn => "PRIVILEGE_WITHDRAWN",
+            RevocationReason::AACompromise => "A_A_COMPROMISE",
+            RevocationReason::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RevocationReason {
+    fn default() -> RevocationReason {
+        RevocationReason::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RevocationReason {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RevocationReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RevocationReason {
+    fn from(value: &'a str) -> RevocationReason {
+        match value {
+            "UNSPECIFIED" => RevocationReason::Unspecified,
+            "KEY_COMPROMISE" => RevocationReason::KeyCompromise,
+            "CA_COMPROMISE" => RevocationReason::CaCompromise,
+            "AFFILIATION_CHANGED" => RevocationReason::AffiliationChanged,
+            "SUPERCEDED" => RevocationReason::Superceded,
+            "CESSATION_OF_OPERATION" => RevocationReason::CessationOfOperation,
+            "CERTIFICATE_HOLD" => RevocationReason::CertificateHold,
+            "REMOVE_FROM_CRL" => RevocationReason::RemoveFromCrl,
+            "PRIVILEGE_WITHDRAWN" => RevocationReason::PrivilegeWithdrawn,
+            "A_A_COMPROMISE" => RevocationReason::AACompromise,
+            _ => RevocationReason::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RevocationReason {
+    fn from(value: String) -> RevocationReason {
+        match value.as_str() {
+            "UNSPECIFIED" => RevocationReason::Unspecified,
+            "KEY_COMPROMISE" => RevocationReason::KeyCompromise,
+            "CA_COMPROMISE" => RevocationReason::CaCompromise,
+            "AFFILIATION_CHANGED" => RevocationReason::AffiliationChanged,
+            "SUPERCEDED" => RevocationReason::Superceded,
+            "CESSATION_OF_OPERATION" => RevocationReason::CessationOfOperation,
+            "CERTIFICATE_HOLD" => RevocationReason::CertificateHold,
+            "REMOVE_FROM_CRL" => RevocationReason::RemoveFromCrl,
+            "PRIVILEGE_WITHDRAWN" => RevocationReason::PrivilegeWithdrawn,
+            "A_A_COMPROMISE" => RevocationReason::AACompromise,
+            _ => RevocationReason::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RevocationReason {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RevocationReason, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RevocationReason {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RevocationReason {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RevocationReason {
+    fn deserialize<D>(deserializer: D) -> Result<RevocationReason, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A key-value pair that identifies or specifies metadata about an ACM resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Tag {
@@ -563,6 +1990,99 @@ pub struct UpdateCertificateOptionsRequest {
     pub options: CertificateOptions,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ValidationMethod {
+    /// `EMAIL`
+    Email,
+    /// `DNS`
+    Dns,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ValidationMethod {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ValidationMethod::Email => "EMAIL",
+            ValidationMethod::Dns => "DNS",
+            ValidationMethod::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ValidationMethod {
+    fn default() -> ValidationMethod {
+        ValidationMethod::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ValidationMethod {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ValidationMethod {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ValidationMethod {
+    fn from(value: &'a str) -> ValidationMethod {
+        match value {
+            "EMAIL" => ValidationMethod::Email,
+            "DNS" => ValidationMethod::Dns,
+            _ => ValidationMethod::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ValidationMethod {
+    fn from(value: String) -> ValidationMethod {
+        match value.as_str() {
+            "EMAIL" => ValidationMethod::Email,
+            "DNS" => ValidationMethod::Dns,
+            _ => ValidationMethod::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ValidationMethod {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ValidationMethod, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ValidationMethod {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ValidationMethod {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ValidationMethod {
+    fn deserialize<D>(deserializer: D) -> Result<ValidationMethod, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// Errors returned by AddTagsToCertificate
 #[derive(Debug, PartialEq)]
 pub enum AddTagsToCertificateError {
@@ -1286,11 +2806,12 @@ pub struct AcmClient {
 impl AcmClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> AcmClient {
         AcmClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "ACM"),
         }
     }
 
@@ -1322,6 +2843,7 @@ impl Acm for AcmClient {
         input: AddTagsToCertificateRequest,
     ) -> Result<(), RusotoError<AddTagsToCertificateError>> {
         let mut request = SignedRequest::new("POST", "acm", &self.region, "/");
+        request.set_operation("AddTagsToCertificate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CertificateManager.AddTagsToCertificate");
@@ -1349,6 +2871,7 @@ impl Acm for AcmClient {
         input: DeleteCertificateRequest,
     ) -> Result<(), RusotoError<DeleteCertificateError>> {
         let mut request = SignedRequest::new("POST", "acm", &self.region, "/");
+        request.set_operation("DeleteCertificate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CertificateManager.DeleteCertificate");
@@ -1376,6 +2899,7 @@ impl Acm for AcmClient {
         input: DescribeCertificateRequest,
     ) -> Result<DescribeCertificateResponse, RusotoError<DescribeCertificateError>> {
         let mut request = SignedRequest::new("POST", "acm", &self.region, "/");
+        request.set_operation("DescribeCertificate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CertificateManager.DescribeCertificate");
@@ -1404,6 +2928,7 @@ impl Acm for AcmClient {
         input: ExportCertificateRequest,
     ) -> Result<ExportCertificateResponse, RusotoError<ExportCertificateError>> {
         let mut request = SignedRequest::new("POST", "acm", &self.region, "/");
+        request.set_operation("ExportCertificate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CertificateManager.ExportCertificate");
@@ -1432,6 +2957,7 @@ impl Acm for AcmClient {
         input: GetCertificateRequest,
     ) -> Result<GetCertificateResponse, RusotoError<GetCertificateError>> {
         let mut request = SignedRequest::new("POST", "acm", &self.region, "/");
+        request.set_operation("GetCertificate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CertificateManager.GetCertificate");
@@ -1459,6 +2985,7 @@ impl Acm for AcmClient {
         input: ImportCertificateRequest,
     ) -> Result<ImportCertificateResponse, RusotoError<ImportCertificateError>> {
         let mut request = SignedRequest::new("POST", "acm", &self.region, "/");
+        request.set_operation("ImportCertificate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CertificateManager.ImportCertificate");
@@ -1487,6 +3014,7 @@ impl Acm for AcmClient {
         input: ListCertificatesRequest,
     ) -> Result<ListCertificatesResponse, RusotoError<ListCertificatesError>> {
         let mut request = SignedRequest::new("POST", "acm", &self.region, "/");
+        request.set_operation("ListCertificates");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CertificateManager.ListCertificates");
@@ -1515,6 +3043,7 @@ impl Acm for AcmClient {
         input: ListTagsForCertificateRequest,
     ) -> Result<ListTagsForCertificateResponse, RusotoError<ListTagsForCertificateError>> {
         let mut request = SignedRequest::new("POST", "acm", &self.region, "/");
+        request.set_operation("ListTagsForCertificate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CertificateManager.ListTagsForCertificate");
@@ -1543,6 +3072,7 @@ impl Acm for AcmClient {
         input: RemoveTagsFromCertificateRequest,
     ) -> Result<(), RusotoError<RemoveTagsFromCertificateError>> {
         let mut request = SignedRequest::new("POST", "acm", &self.region, "/");
+        request.set_operation("RemoveTagsFromCertificate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1573,6 +3103,7 @@ impl Acm for AcmClient {
         input: RenewCertificateRequest,
     ) -> Result<(), RusotoError<RenewCertificateError>> {
         let mut request = SignedRequest::new("POST", "acm", &self.region, "/");
+        request.set_operation("RenewCertificate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CertificateManager.RenewCertificate");
@@ -1600,6 +3131,7 @@ impl Acm for AcmClient {
         input: RequestCertificateRequest,
     ) -> Result<RequestCertificateResponse, RusotoError<RequestCertificateError>> {
         let mut request = SignedRequest::new("POST", "acm", &self.region, "/");
+        request.set_operation("RequestCertificate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CertificateManager.RequestCertificate");
@@ -1628,6 +3160,7 @@ impl Acm for AcmClient {
         input: ResendValidationEmailRequest,
     ) -> Result<(), RusotoError<ResendValidationEmailError>> {
         let mut request = SignedRequest::new("POST", "acm", &self.region, "/");
+        request.set_operation("ResendValidationEmail");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CertificateManager.ResendValidationEmail");
@@ -1655,6 +3188,7 @@ impl Acm for AcmClient {
         input: UpdateCertificateOptionsRequest,
     ) -> Result<(), RusotoError<UpdateCertificateOptionsError>> {
         let mut request = SignedRequest::new("POST", "acm", &self.region, "/");
+        request.set_operation("UpdateCertificateOptions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1679,3 +3213,109 @@ impl Acm for AcmClient {
         }
     }
 }
+impl AcmClient {
+    /// Auto-paginating version of `list_certificates`, streaming every page of results.
+    pub fn list_certificates_pages(
+        &self,
+        input: ListCertificatesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListCertificatesResponse, ListCertificatesError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AcmClient, input: ListCertificatesRequest| async move {
+                client.list_certificates(input).await
+            },
+            |input: &ListCertificatesRequest, output: &ListCertificatesResponse| {
+                Some(ListCertificatesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_certificates`, streaming the `CertificateSummaryList` of every page of results.
+    pub fn list_certificates_items(
+        &self,
+        input: ListCertificatesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<CertificateSummary, ListCertificatesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_certificates_pages(input),
+            |output: ListCertificatesResponse| output.certificate_summary_list.unwrap_or_default(),
+        )
+    }
+}
+impl AcmClient {
+    /// Waits until `describe_certificate` reports the `CertificateValidated` state, polling every 60 seconds up to 40 times.
+    pub async fn wait_until_certificate_validated(
+        &self,
+        input: DescribeCertificateRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeCertificateError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(60), 40);
+        self.wait_until_certificate_validated_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_certificate_validated`, polling as often and as many times as `config` says.
+    pub async fn wait_until_certificate_validated_with_config(
+        &self,
+        input: DescribeCertificateRequest,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeCertificateError>> {
+        rusoto_core::waiter::wait(
+            "CertificateValidated",
+            &config,
+            || self.describe_certificate(input.clone()),
+            |result: &Result<
+                DescribeCertificateResponse,
+                RusotoError<DescribeCertificateError>,
+            >| match result {
+                Ok(output)
+                    if rusoto_core::waiter::all_match(
+                        output
+                            .certificate
+                            .iter()
+                            .flat_map(|v| v.domain_validation_options.iter())
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.validation_status.iter()),
+                        |v| *v == DomainStatus::Success,
+                    ) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if output
+                        .certificate
+                        .iter()
+                        .flat_map(|v| v.domain_validation_options.iter())
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.validation_status.iter())
+                        .any(|v| *v == DomainStatus::PendingValidation) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Retry)
+                }
+                Ok(output)
+                    if output
+                        .certificate
+                        .iter()
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| *v == CertificateStatus::Failed) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Err(RusotoError::Service(DescribeCertificateError::ResourceNotFound(_))) => {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Err(RusotoError::Unknown(response))
+                    if rusoto_core::retry::error_code(response).as_deref()
+                        == Some("ResourceNotFoundException") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+}
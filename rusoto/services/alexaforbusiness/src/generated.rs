@@ -170,7 +170,7 @@ pub struct AssociateSkillWithUsersResponse {}
 pub struct Audio {
     /// <p>The locale of the audio message. Currently, en-US is supported.</p>
     #[serde(rename = "Locale")]
-    pub locale: String,
+    pub locale: Locale,
     /// <p>The location of the audio file. Currently, S3 URLs are supported. Only S3 locations comprised of safe characters are valid. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/UsingMetadata.html#Safe%20Characters">Safe Characters</a>.</p>
     #[serde(rename = "Location")]
     pub location: String,
@@ -182,8 +182,13 @@ pub struct Audio {
 pub struct BusinessReport {
     /// <p>The time of report delivery.</p>
     #[serde(rename = "DeliveryTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub delivery_time: Option<f64>,
+    pub delivery_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The download link where a user can download the report.</p>
     #[serde(rename = "DownloadUrl")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -191,7 +196,7 @@ pub struct BusinessReport {
     /// <p>The failure code.</p>
     #[serde(rename = "FailureCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub failure_code: Option<String>,
+    pub failure_code: Option<BusinessReportFailureCode>,
     /// <p>The S3 location of the output reports.</p>
     #[serde(rename = "S3Location")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -199,7 +204,7 @@ pub struct BusinessReport {
     /// <p>The status of the report generation execution (RUNNING, SUCCEEDED, or FAILED).</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<BusinessReportStatus>,
 }
 
 /// <p>The content range of the report.</p>
@@ -208,9 +213,298 @@ pub struct BusinessReportContentRange {
     /// <p>The interval of the content range.</p>
     #[serde(rename = "Interval")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub interval: Option<String>,
+    pub interval: Option<BusinessReportInterval>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BusinessReportFailureCode {
+    /// `ACCESS_DENIED`
+    AccessDenied,
+    /// `NO_SUCH_BUCKET`
+    NoSuchBucket,
+    /// `INTERNAL_FAILURE`
+    InternalFailure,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl BusinessReportFailureCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BusinessReportFailureCode::AccessDenied => "ACCESS_DENIED",
+            BusinessReportFailureCode::NoSuchBucket => "NO_SUCH_BUCKET",
+            BusinessReportFailureCode::InternalFailure => "INTERNAL_FAILURE",
+            BusinessReportFailureCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for BusinessReportFailureCode {
+    fn default() -> BusinessReportFailureCode {
+        BusinessReportFailureCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for BusinessReportFailureCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for BusinessReportFailureCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for BusinessReportFailureCode {
+    fn from(value: &'a str) -> BusinessReportFailureCode {
+        match value {
+            "ACCESS_DENIED" => BusinessReportFailureCode::AccessDenied,
+            "NO_SUCH_BUCKET" => BusinessReportFailureCode::NoSuchBucket,
+            "INTERNAL_FAILURE" => BusinessReportFailureCode::InternalFailure,
+            _ => BusinessReportFailureCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BusinessReportFailureCode {
+    fn from(value: String) -> BusinessReportFailureCode {
+        match value.as_str() {
+            "ACCESS_DENIED" => BusinessReportFailureCode::AccessDenied,
+            "NO_SUCH_BUCKET" => BusinessReportFailureCode::NoSuchBucket,
+            "INTERNAL_FAILURE" => BusinessReportFailureCode::InternalFailure,
+            _ => BusinessReportFailureCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for BusinessReportFailureCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<BusinessReportFailureCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for BusinessReportFailureCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for BusinessReportFailureCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BusinessReportFailureCode {
+    fn deserialize<D>(deserializer: D) -> Result<BusinessReportFailureCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BusinessReportFormat {
+    /// `CSV`
+    Csv,
+    /// `CSV_ZIP`
+    CsvZip,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl BusinessReportFormat {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BusinessReportFormat::Csv => "CSV",
+            BusinessReportFormat::CsvZip => "CSV_ZIP",
+            BusinessReportFormat::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for BusinessReportFormat {
+    fn default() -> BusinessReportFormat {
+        BusinessReportFormat::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for BusinessReportFormat {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for BusinessReportFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for BusinessReportFormat {
+    fn from(value: &'a str) -> BusinessReportFormat {
+        match value {
+            "CSV" => BusinessReportFormat::Csv,
+            "CSV_ZIP" => BusinessReportFormat::CsvZip,
+            _ => BusinessReportFormat::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BusinessReportFormat {
+    fn from(value: String) -> BusinessReportFormat {
+        match value.as_str() {
+            "CSV" => BusinessReportFormat::Csv,
+            "CSV_ZIP" => BusinessReportFormat::CsvZip,
+            _ => BusinessReportFormat::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for BusinessReportFormat {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<BusinessReportFormat, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for BusinessReportFormat {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for BusinessReportFormat {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BusinessReportFormat {
+    fn deserialize<D>(deserializer: D) -> Result<BusinessReportFormat, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BusinessReportInterval {
+    /// `ONE_DAY`
+    OneDay,
+    /// `ONE_WEEK`
+    OneWeek,
+    /// `THIRTY_DAYS`
+    ThirtyDays,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl BusinessReportInterval {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BusinessReportInterval::OneDay => "ONE_DAY",
+            BusinessReportInterval::OneWeek => "ONE_WEEK",
+            BusinessReportInterval::ThirtyDays => "THIRTY_DAYS",
+            BusinessReportInterval::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for BusinessReportInterval {
+    fn default() -> BusinessReportInterval {
+        BusinessReportInterval::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for BusinessReportInterval {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for BusinessReportInterval {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for BusinessReportInterval {
+    fn from(value: &'a str) -> BusinessReportInterval {
+        match value {
+            "ONE_DAY" => BusinessReportInterval::OneDay,
+            "ONE_WEEK" => BusinessReportInterval::OneWeek,
+            "THIRTY_DAYS" => BusinessReportInterval::ThirtyDays,
+            _ => BusinessReportInterval::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BusinessReportInterval {
+    fn from(value: String) -> BusinessReportInterval {
+        match value.as_str() {
+            "ONE_DAY" => BusinessReportInterval::OneDay,
+            "ONE_WEEK" => BusinessReportInterval::OneWeek,
+            "THIRTY_DAYS" => BusinessReportInterval::ThirtyDays,
+            _ => BusinessReportInterval::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for BusinessReportInterval {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<BusinessReportInterval, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for BusinessReportInterval {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for BusinessReportInterval {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BusinessReportInterval {
+    fn deserialize<D>(deserializer: D) -> Result<BusinessReportInterval, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The recurrence of the reports.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct BusinessReportRecurrence {
@@ -245,7 +539,7 @@ pub struct BusinessReportSchedule {
     /// <p>The format of the generated report (individual CSV files or zipped files of individual files).</p>
     #[serde(rename = "Format")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub format: Option<String>,
+    pub format: Option<BusinessReportFormat>,
     /// <p>The details of the last business report delivery for a specified time interval.</p>
     #[serde(rename = "LastBusinessReport")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -272,6 +566,104 @@ pub struct BusinessReportSchedule {
     pub schedule_name: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BusinessReportStatus {
+    /// `RUNNING`
+    Running,
+    /// `SUCCEEDED`
+    Succeeded,
+    /// `FAILED`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl BusinessReportStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BusinessReportStatus::Running => "RUNNING",
+            BusinessReportStatus::Succeeded => "SUCCEEDED",
+            BusinessReportStatus::Failed => "FAILED",
+            BusinessReportStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for BusinessReportStatus {
+    fn default() -> BusinessReportStatus {
+        BusinessReportStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for BusinessReportStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for BusinessReportStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for BusinessReportStatus {
+    fn from(value: &'a str) -> BusinessReportStatus {
+        match value {
+            "RUNNING" => BusinessReportStatus::Running,
+            "SUCCEEDED" => BusinessReportStatus::Succeeded,
+            "FAILED" => BusinessReportStatus::Failed,
+            _ => BusinessReportStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BusinessReportStatus {
+    fn from(value: String) -> BusinessReportStatus {
+        match value.as_str() {
+            "RUNNING" => BusinessReportStatus::Running,
+            "SUCCEEDED" => BusinessReportStatus::Succeeded,
+            "FAILED" => BusinessReportStatus::Failed,
+            _ => BusinessReportStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for BusinessReportStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<BusinessReportStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for BusinessReportStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for BusinessReportStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BusinessReportStatus {
+    fn deserialize<D>(deserializer: D) -> Result<BusinessReportStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The skill store category that is shown. Alexa skills are assigned a specific skill category during creation, such as News, Social, and Sports.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -286,6 +678,104 @@ pub struct Category {
     pub category_name: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CommsProtocol {
+    /// `SIP`
+    Sip,
+    /// `SIPS`
+    Sips,
+    /// `H323`
+    H323,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CommsProtocol {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CommsProtocol::Sip => "SIP",
+            CommsProtocol::Sips => "SIPS",
+            CommsProtocol::H323 => "H323",
+            CommsProtocol::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CommsProtocol {
+    fn default() -> CommsProtocol {
+        CommsProtocol::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CommsProtocol {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CommsProtocol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CommsProtocol {
+    fn from(value: &'a str) -> CommsProtocol {
+        match value {
+            "SIP" => CommsProtocol::Sip,
+            "SIPS" => CommsProtocol::Sips,
+            "H323" => CommsProtocol::H323,
+            _ => CommsProtocol::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CommsProtocol {
+    fn from(value: String) -> CommsProtocol {
+        match value.as_str() {
+            "SIP" => CommsProtocol::Sip,
+            "SIPS" => CommsProtocol::Sips,
+            "H323" => CommsProtocol::H323,
+            _ => CommsProtocol::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CommsProtocol {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CommsProtocol, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CommsProtocol {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CommsProtocol {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CommsProtocol {
+    fn deserialize<D>(deserializer: D) -> Result<CommsProtocol, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The default conference provider that is used if no other scheduled meetings are detected.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ConferencePreference {
@@ -322,62 +812,288 @@ pub struct ConferenceProvider {
     /// <p>The type of conference providers.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<ConferenceProviderType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ConferenceProviderType {
+    /// `CHIME`
+    Chime,
+    /// `BLUEJEANS`
+    Bluejeans,
+    /// `FUZE`
+    Fuze,
+    /// `GOOGLE_HANGOUTS`
+    GoogleHangouts,
+    /// `POLYCOM`
+    Polycom,
+    /// `RINGCENTRAL`
+    Ringcentral,
+    /// `SKYPE_FOR_BUSINESS`
+    SkypeForBusiness,
+    /// `WEBEX`
+    Webex,
+    /// `ZOOM`
+    Zoom,
+    /// `CUSTOM`
+    Custom,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ConferenceProviderType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ConferenceProviderType::Chime => "CHIME",
+            ConferenceProviderType::Bluejeans => "BLUEJEANS",
+            ConferenceProviderType::Fuze => "FUZE",
+            ConferenceProviderType::GoogleHangouts => "GOOGLE_HANGOUTS",
+            ConferenceProviderType::Polycom => "POLYCOM",
+            ConferenceProviderType::Ringcentral => "RINGCENTRAL",
+            ConferenceProviderType::SkypeForBusiness => "SKYPE_FOR_BUSINESS",
+            ConferenceProviderType::Webex => "WEBEX",
+            ConferenceProviderType::Zoom => "ZOOM",
+            ConferenceProviderType::Custom => "CUSTOM",
+            ConferenceProviderType::Unknown(ref value) => value,
+        }
+    }
 }
 
-/// <p>A contact with attributes.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct Contact {
-    /// <p>The ARN of the contact.</p>
-    #[serde(rename = "ContactArn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub contact_arn: Option<String>,
-    /// <p>The name of the contact to display on the console.</p>
-    #[serde(rename = "DisplayName")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub display_name: Option<String>,
-    /// <p>The first name of the contact, used to call the contact on the device.</p>
-    #[serde(rename = "FirstName")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub first_name: Option<String>,
-    /// <p>The last name of the contact, used to call the contact on the device.</p>
-    #[serde(rename = "LastName")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_name: Option<String>,
-    /// <p>The phone number of the contact. The phone number type defaults to WORK. You can either specify PhoneNumber or PhoneNumbers. We recommend that you use PhoneNumbers, which lets you specify the phone number type and multiple numbers.</p>
-    #[serde(rename = "PhoneNumber")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub phone_number: Option<String>,
-    /// <p>The list of phone numbers for the contact.</p>
-    #[serde(rename = "PhoneNumbers")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub phone_numbers: Option<Vec<PhoneNumber>>,
-    /// <p>The list of SIP addresses for the contact.</p>
-    #[serde(rename = "SipAddresses")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub sip_addresses: Option<Vec<SipAddress>>,
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ConferenceProviderType {
+    fn default() -> ConferenceProviderType {
+        ConferenceProviderType::Unknown(String::new())
+    }
 }
 
-/// <p>Information related to a contact.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct ContactData {
-    /// <p>The ARN of the contact.</p>
-    #[serde(rename = "ContactArn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub contact_arn: Option<String>,
-    /// <p>The name of the contact to display on the console.</p>
-    #[serde(rename = "DisplayName")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub display_name: Option<String>,
-    /// <p>The first name of the contact, used to call the contact on the device.</p>
-    #[serde(rename = "FirstName")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub first_name: Option<String>,
-    /// <p>The last name of the contact, used to call the contact on the device.</p>
-    #[serde(rename = "LastName")]
-    #[serde(skip_serializing_if = "Option::is_none")]
+impl AsRef<str> for ConferenceProviderType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ConferenceProviderType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ConferenceProviderType {
+    fn from(value: &'a str) -> ConferenceProviderType {
+        match value {
+            "CHIME" => ConferenceProviderType::Chime,
+            "BLUEJEANS" => ConferenceProviderType::Bluejeans,
+            "FUZE" => ConferenceProviderType::Fuze,
+            "GOOGLE_HANGOUTS" => ConferenceProviderType::GoogleHangouts,
+            "POLYCOM" => ConferenceProviderType::Polycom,
+            "RINGCENTRAL" => ConferenceProviderType::Ringcentral,
+            "SKYPE_FOR_BUSINESS" => ConferenceProviderType::SkypeForBusiness,
+            "WEBEX" => ConferenceProviderType::Webex,
+            "ZOOM" => ConferenceProviderType::Zoom,
+            "CUSTOM" => ConferenceProviderType::Custom,
+            _ => ConferenceProviderType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ConferenceProviderType {
+    fn from(value: String) -> ConferenceProviderType {
+        match value.as_str() {
+            "CHIME" => ConferenceProviderType::Chime,
+            "BLUEJEANS" => ConferenceProviderType::Bluejeans,
+            "FUZE" => ConferenceProviderType::Fuze,
+            "GOOGLE_HANGOUTS" => ConferenceProviderType::GoogleHangouts,
+            "POLYCOM" => ConferenceProviderType::Polycom,
+            "RINGCENTRAL" => ConferenceProviderType::Ringcentral,
+            "SKYPE_FOR_BUSINESS" => ConferenceProviderType::SkypeForBusiness,
+            "WEBEX" => ConferenceProviderType::Webex,
+            "ZOOM" => ConferenceProviderType::Zoom,
+            "CUSTOM" => ConferenceProviderType::Custom,
+            _ => ConferenceProviderType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ConferenceProviderType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ConferenceProviderType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ConferenceProviderType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ConferenceProviderType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ConferenceProviderType {
+    fn deserialize<D>(deserializer: D) -> Result<ConferenceProviderType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ConnectionStatus {
+    /// `ONLINE`
+    Online,
+    /// `OFFLINE`
+    Offline,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ConnectionStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ConnectionStatus::Online => "ONLINE",
+            ConnectionStatus::Offline => "OFFLINE",
+            ConnectionStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ConnectionStatus {
+    fn default() -> ConnectionStatus {
+        ConnectionStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ConnectionStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ConnectionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ConnectionStatus {
+    fn from(value: &'a str) -> ConnectionStatus {
+        match value {
+            "ONLINE" => ConnectionStatus::Online,
+            "OFFLINE" => ConnectionStatus::Offline,
+            _ => ConnectionStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ConnectionStatus {
+    fn from(value: String) -> ConnectionStatus {
+        match value.as_str() {
+            "ONLINE" => ConnectionStatus::Online,
+            "OFFLINE" => ConnectionStatus::Offline,
+            _ => ConnectionStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ConnectionStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ConnectionStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ConnectionStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ConnectionStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ConnectionStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ConnectionStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>A contact with attributes.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct Contact {
+    /// <p>The ARN of the contact.</p>
+    #[serde(rename = "ContactArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contact_arn: Option<String>,
+    /// <p>The name of the contact to display on the console.</p>
+    #[serde(rename = "DisplayName")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    /// <p>The first name of the contact, used to call the contact on the device.</p>
+    #[serde(rename = "FirstName")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<String>,
+    /// <p>The last name of the contact, used to call the contact on the device.</p>
+    #[serde(rename = "LastName")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+    /// <p>The phone number of the contact. The phone number type defaults to WORK. You can either specify PhoneNumber or PhoneNumbers. We recommend that you use PhoneNumbers, which lets you specify the phone number type and multiple numbers.</p>
+    #[serde(rename = "PhoneNumber")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number: Option<String>,
+    /// <p>The list of phone numbers for the contact.</p>
+    #[serde(rename = "PhoneNumbers")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_numbers: Option<Vec<PhoneNumber>>,
+    /// <p>The list of SIP addresses for the contact.</p>
+    #[serde(rename = "SipAddresses")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sip_addresses: Option<Vec<SipAddress>>,
+}
+
+/// <p>Information related to a contact.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct ContactData {
+    /// <p>The ARN of the contact.</p>
+    #[serde(rename = "ContactArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contact_arn: Option<String>,
+    /// <p>The name of the contact to display on the console.</p>
+    #[serde(rename = "DisplayName")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    /// <p>The first name of the contact, used to call the contact on the device.</p>
+    #[serde(rename = "FirstName")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<String>,
+    /// <p>The last name of the contact, used to call the contact on the device.</p>
+    #[serde(rename = "LastName")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub last_name: Option<String>,
     /// <p>The phone number of the contact. The phone number type defaults to WORK. You can specify PhoneNumber or PhoneNumbers. We recommend that you use PhoneNumbers, which lets you specify the phone number type and multiple numbers.</p>
     #[serde(rename = "PhoneNumber")]
@@ -448,7 +1164,7 @@ pub struct CreateBusinessReportScheduleRequest {
     pub content_range: BusinessReportContentRange,
     /// <p>The format of the generated report (individual CSV files or zipped files of individual files).</p>
     #[serde(rename = "Format")]
-    pub format: String,
+    pub format: BusinessReportFormat,
     /// <p>The recurrence of the reports. If this isn't specified, the report will only be delivered one time when the API is called. </p>
     #[serde(rename = "Recurrence")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -488,7 +1204,7 @@ pub struct CreateConferenceProviderRequest {
     pub conference_provider_name: String,
     /// <p>Represents a type within a list of predefined types.</p>
     #[serde(rename = "ConferenceProviderType")]
-    pub conference_provider_type: String,
+    pub conference_provider_type: ConferenceProviderType,
     /// <p>The IP endpoint and protocol for calling.</p>
     #[serde(rename = "IPDialIn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -564,7 +1280,7 @@ pub struct CreateEndOfMeetingReminder {
     pub reminder_at_minutes: Vec<i64>,
     /// <p>The type of sound that users hear during the end of meeting reminder. </p>
     #[serde(rename = "ReminderType")]
-    pub reminder_type: String,
+    pub reminder_type: EndOfMeetingReminderType,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -644,7 +1360,7 @@ pub struct CreateNetworkProfileRequest {
     /// <p>The authentication standard that is used in the EAP framework. Currently, EAP_TLS is supported.</p>
     #[serde(rename = "EapMethod")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub eap_method: Option<String>,
+    pub eap_method: Option<NetworkEapMethod>,
     /// <p>The name of the network profile associated with a device.</p>
     #[serde(rename = "NetworkProfileName")]
     pub network_profile_name: String,
@@ -654,7 +1370,7 @@ pub struct CreateNetworkProfileRequest {
     pub next_password: Option<String>,
     /// <p>The security type of the Wi-Fi network. This can be WPA2_ENTERPRISE, WPA2_PSK, WPA_PSK, WEP, or OPEN.</p>
     #[serde(rename = "SecurityType")]
-    pub security_type: String,
+    pub security_type: NetworkSecurityType,
     /// <p>The SSID of the Wi-Fi network.</p>
     #[serde(rename = "Ssid")]
     pub ssid: String,
@@ -685,7 +1401,7 @@ pub struct CreateProfileRequest {
     pub client_request_token: Option<String>,
     /// <p>The distance unit to be used by devices in the profile.</p>
     #[serde(rename = "DistanceUnit")]
-    pub distance_unit: String,
+    pub distance_unit: DistanceUnit,
     /// <p>The locale of the room profile. (This is currently only available to a limited preview audience.)</p>
     #[serde(rename = "Locale")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -711,13 +1427,13 @@ pub struct CreateProfileRequest {
     pub setup_mode_disabled: Option<bool>,
     /// <p>The temperature unit to be used by devices in the profile.</p>
     #[serde(rename = "TemperatureUnit")]
-    pub temperature_unit: String,
+    pub temperature_unit: TemperatureUnit,
     /// <p>The time zone used by a room profile.</p>
     #[serde(rename = "Timezone")]
     pub timezone: String,
     /// <p>A wake word for Alexa, Echo, Amazon, or a computer.</p>
     #[serde(rename = "WakeWord")]
-    pub wake_word: String,
+    pub wake_word: WakeWord,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -908,7 +1624,7 @@ pub struct DeleteDeviceUsageDataRequest {
     pub device_arn: String,
     /// <p>The type of usage data to delete.</p>
     #[serde(rename = "DeviceUsageType")]
-    pub device_usage_type: String,
+    pub device_usage_type: DeviceUsageType,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1070,7 +1786,7 @@ pub struct Device {
     /// <p>The status of a device. If the status is not READY, check the DeviceStatusInfo value for details.</p>
     #[serde(rename = "DeviceStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub device_status: Option<String>,
+    pub device_status: Option<DeviceStatus>,
     /// <p>Detailed information about a device's status.</p>
     #[serde(rename = "DeviceStatusInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1103,8 +1819,13 @@ pub struct Device {
 pub struct DeviceData {
     /// <p>The time (in epoch) when the device data was created.</p>
     #[serde(rename = "CreatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time: Option<f64>,
+    pub created_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The ARN of a device.</p>
     #[serde(rename = "DeviceArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1120,7 +1841,7 @@ pub struct DeviceData {
     /// <p>The status of a device.</p>
     #[serde(rename = "DeviceStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub device_status: Option<String>,
+    pub device_status: Option<DeviceStatus>,
     /// <p>Detailed information about a device's status.</p>
     #[serde(rename = "DeviceStatusInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1161,18 +1882,116 @@ pub struct DeviceData {
 pub struct DeviceEvent {
     /// <p>The time (in epoch) when the event occurred. </p>
     #[serde(rename = "Timestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub timestamp: Option<f64>,
+    pub timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The type of device event.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<DeviceEventType>,
     /// <p>The value of the event.</p>
     #[serde(rename = "Value")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DeviceEventType {
+    /// `CONNECTION_STATUS`
+    ConnectionStatus,
+    /// `DEVICE_STATUS`
+    DeviceStatus,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DeviceEventType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeviceEventType::ConnectionStatus => "CONNECTION_STATUS",
+            DeviceEventType::DeviceStatus => "DEVICE_STATUS",
+            DeviceEventType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DeviceEventType {
+    fn default() -> DeviceEventType {
+        DeviceEventType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DeviceEventType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DeviceEventType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DeviceEventType {
+    fn from(value: &'a str) -> DeviceEventType {
+        match value {
+            "CONNECTION_STATUS" => DeviceEventType::ConnectionStatus,
+            "DEVICE_STATUS" => DeviceEventType::DeviceStatus,
+            _ => DeviceEventType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeviceEventType {
+    fn from(value: String) -> DeviceEventType {
+        match value.as_str() {
+            "CONNECTION_STATUS" => DeviceEventType::ConnectionStatus,
+            "DEVICE_STATUS" => DeviceEventType::DeviceStatus,
+            _ => DeviceEventType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DeviceEventType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DeviceEventType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DeviceEventType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DeviceEventType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeviceEventType {
+    fn deserialize<D>(deserializer: D) -> Result<DeviceEventType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Detailed information about a device's network profile.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1183,14 +2002,127 @@ pub struct DeviceNetworkProfileInfo {
     pub certificate_arn: Option<String>,
     /// <p>The time (in epoch) when the certificate expires.</p>
     #[serde(rename = "CertificateExpirationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub certificate_expiration_time: Option<f64>,
+    pub certificate_expiration_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The ARN of the network profile associated with a device.</p>
     #[serde(rename = "NetworkProfileArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub network_profile_arn: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DeviceStatus {
+    /// `READY`
+    Ready,
+    /// `PENDING`
+    Pending,
+    /// `WAS_OFFLINE`
+    WasOffline,
+    /// `DEREGISTERED`
+    Deregistered,
+    /// `FAILED`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DeviceStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeviceStatus::Ready => "READY",
+            DeviceStatus::Pending => "PENDING",
+            DeviceStatus::WasOffline => "WAS_OFFLINE",
+            DeviceStatus::Deregistered => "DEREGISTERED",
+            DeviceStatus::Failed => "FAILED",
+            DeviceStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DeviceStatus {
+    fn default() -> DeviceStatus {
+        DeviceStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DeviceStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DeviceStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DeviceStatus {
+    fn from(value: &'a str) -> DeviceStatus {
+        match value {
+            "READY" => DeviceStatus::Ready,
+            "PENDING" => DeviceStatus::Pending,
+            "WAS_OFFLINE" => DeviceStatus::WasOffline,
+            "DEREGISTERED" => DeviceStatus::Deregistered,
+            "FAILED" => DeviceStatus::Failed,
+            _ => DeviceStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeviceStatus {
+    fn from(value: String) -> DeviceStatus {
+        match value.as_str() {
+            "READY" => DeviceStatus::Ready,
+            "PENDING" => DeviceStatus::Pending,
+            "WAS_OFFLINE" => DeviceStatus::WasOffline,
+            "DEREGISTERED" => DeviceStatus::Deregistered,
+            "FAILED" => DeviceStatus::Failed,
+            _ => DeviceStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DeviceStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DeviceStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DeviceStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DeviceStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeviceStatus {
+    fn deserialize<D>(deserializer: D) -> Result<DeviceStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Details of a device’s status.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1198,13 +2130,193 @@ pub struct DeviceStatusDetail {
     /// <p>The device status detail code.</p>
     #[serde(rename = "Code")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub code: Option<String>,
+    pub code: Option<DeviceStatusDetailCode>,
     /// <p>The list of available features on the device.</p>
     #[serde(rename = "Feature")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub feature: Option<String>,
+    pub feature: Option<Feature>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DeviceStatusDetailCode {
+    /// `DEVICE_SOFTWARE_UPDATE_NEEDED`
+    DeviceSoftwareUpdateNeeded,
+    /// `DEVICE_WAS_OFFLINE`
+    DeviceWasOffline,
+    /// `CREDENTIALS_ACCESS_FAILURE`
+    CredentialsAccessFailure,
+    /// `TLS_VERSION_MISMATCH`
+    TlsVersionMismatch,
+    /// `ASSOCIATION_REJECTION`
+    AssociationRejection,
+    /// `AUTHENTICATION_FAILURE`
+    AuthenticationFailure,
+    /// `DHCP_FAILURE`
+    DhcpFailure,
+    /// `INTERNET_UNAVAILABLE`
+    InternetUnavailable,
+    /// `DNS_FAILURE`
+    DnsFailure,
+    /// `UNKNOWN_FAILURE`
+    UnknownFailure,
+    /// `CERTIFICATE_ISSUING_LIMIT_EXCEEDED`
+    CertificateIssuingLimitExceeded,
+    /// `INVALID_CERTIFICATE_AUTHORITY`
+    InvalidCertificateAuthority,
+    /// `NETWORK_PROFILE_NOT_FOUND`
+    NetworkProfileNotFound,
+    /// `INVALID_PASSWORD_STATE`
+    InvalidPasswordState,
+    /// `PASSWORD_NOT_FOUND`
+    PasswordNotFound,
+    /// `PASSWORD_MANAGER_ACCESS_DENIED`
+    PasswordManagerAccessDenied,
+    /// `CERTIFICATE_AUTHORITY_ACCESS_DENIED`
+    CertificateAuthorityAccessDenied,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DeviceStatusDetailCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeviceStatusDetailCode::DeviceSoftwareUpdateNeeded => "DEVICE_SOFTWARE_UPDATE_NEEDED",
+            DeviceStatusDetailCode::DeviceWasOffline => "DEVICE_WAS_OFFLINE",
+            DeviceStatusDetailCode::CredentialsAccessFailure => "CREDENTIALS_ACCESS_FAILURE",
+            DeviceStatusDetailCode::TlsVersionMismatch => "TLS_VERSION_MISMATCH",
+            DeviceStatusDetailCode::AssociationRejection => "ASSOCIATION_REJECTION",
+            DeviceStatusDetailCode::AuthenticationFailure => "AUTHENTICATION_FAILURE",
+            DeviceStatusDetailCode::DhcpFailure => "DHCP_FAILURE",
+            DeviceStatusDetailCode::InternetUnavailable => "INTERNET_UNAVAILABLE",
+            DeviceStatusDetailCode::DnsFailure => "DNS_FAILURE",
+            DeviceStatusDetailCode::UnknownFailure => "UNKNOWN_FAILURE",
+            DeviceStatusDetailCode::CertificateIssuingLimitExceeded => {
+                "CERTIFICATE_ISSUING_LIMIT_EXCEEDED"
+            }
+            DeviceStatusDetailCode::InvalidCertificateAuthority => "INVALID_CERTIFICATE_AUTHORITY",
+            DeviceStatusDetailCode::NetworkProfileNotFound => "NETWORK_PROFILE_NOT_FOUND",
+            DeviceStatusDetailCode::InvalidPasswordState => "INVALID_PASSWORD_STATE",
+            DeviceStatusDetailCode::PasswordNotFound => "PASSWORD_NOT_FOUND",
+            DeviceStatusDetailCode::PasswordManagerAccessDenied => "PASSWORD_MANAGER_ACCESS_DENIED",
+            DeviceStatusDetailCode::CertificateAuthorityAccessDenied => {
+                "CERTIFICATE_AUTHORITY_ACCESS_DENIED"
+            }
+            DeviceStatusDetailCode::Unknown(ref value) => value,
+        }
+    }
 }
 
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DeviceStatusDetailCode {
+    fn default() -> DeviceStatusDetailCode {
+        DeviceStatusDetailCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DeviceStatusDetailCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DeviceStatusDetailCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DeviceStatusDetailCode {
+    fn from(value: &'a str) -> DeviceStatusDetailCode {
+        match value {
+            "DEVICE_SOFTWARE_UPDATE_NEEDED" => DeviceStatusDetailCode::DeviceSoftwareUpdateNeeded,
+            "DEVICE_WAS_OFFLINE" => DeviceStatusDetailCode::DeviceWasOffline,
+            "CREDENTIALS_ACCESS_FAILURE" => DeviceStatusDetailCode::CredentialsAccessFailure,
+            "TLS_VERSION_MISMATCH" => DeviceStatusDetailCode::TlsVersionMismatch,
+            "ASSOCIATION_REJECTION" => DeviceStatusDetailCode::AssociationRejection,
+            "AUTHENTICATION_FAILURE" => DeviceStatusDetailCode::AuthenticationFailure,
+            "DHCP_FAILURE" => DeviceStatusDetailCode::DhcpFailure,
+            "INTERNET_UNAVAILABLE" => DeviceStatusDetailCode::InternetUnavailable,
+            "DNS_FAILURE" => DeviceStatusDetailCode::DnsFailure,
+            "UNKNOWN_FAILURE" => DeviceStatusDetailCode::UnknownFailure,
+            "CERTIFICATE_ISSUING_LIMIT_EXCEEDED" => {
+                DeviceStatusDetailCode::CertificateIssuingLimitExceeded
+            }
+            "INVALID_CERTIFICATE_AUTHORITY" => DeviceStatusDetailCode::InvalidCertificateAuthority,
+            "NETWORK_PROFILE_NOT_FOUND" => DeviceStatusDetailCode::NetworkProfileNotFound,
+            "INVALID_PASSWORD_STATE" => DeviceStatusDetailCode::InvalidPasswordState,
+            "PASSWORD_NOT_FOUND" => DeviceStatusDetailCode::PasswordNotFound,
+            "PASSWORD_MANAGER_ACCESS_DENIED" => DeviceStatusDetailCode::PasswordManagerAccessDenied,
+            "CERTIFICATE_AUTHORITY_ACCESS_DENIED" => {
+                DeviceStatusDetailCode::CertificateAuthorityAccessDenied
+            }
+            _ => DeviceStatusDetailCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeviceStatusDetailCode {
+    fn from(value: String) -> DeviceStatusDetailCode {
+        match value.as_str() {
+            "DEVICE_SOFTWARE_UPDATE_NEEDED" => DeviceStatusDetailCode::DeviceSoftwareUpdateNeeded,
+            "DEVICE_WAS_OFFLINE" => DeviceStatusDetailCode::DeviceWasOffline,
+            "CREDENTIALS_ACCESS_FAILURE" => DeviceStatusDetailCode::CredentialsAccessFailure,
+            "TLS_VERSION_MISMATCH" => DeviceStatusDetailCode::TlsVersionMismatch,
+            "ASSOCIATION_REJECTION" => DeviceStatusDetailCode::AssociationRejection,
+            "AUTHENTICATION_FAILURE" => DeviceStatusDetailCode::AuthenticationFailure,
+            "DHCP_FAILURE" => DeviceStatusDetailCode::DhcpFailure,
+            "INTERNET_UNAVAILABLE" => DeviceStatusDetailCode::InternetUnavailable,
+            "DNS_FAILURE" => DeviceStatusDetailCode::DnsFailure,
+            "UNKNOWN_FAILURE" => DeviceStatusDetailCode::UnknownFailure,
+            "CERTIFICATE_ISSUING_LIMIT_EXCEEDED" => {
+                DeviceStatusDetailCode::CertificateIssuingLimitExceeded
+            }
+            "INVALID_CERTIFICATE_AUTHORITY" => DeviceStatusDetailCode::InvalidCertificateAuthority,
+            "NETWORK_PROFILE_NOT_FOUND" => DeviceStatusDetailCode::NetworkProfileNotFound,
+            "INVALID_PASSWORD_STATE" => DeviceStatusDetailCode::InvalidPasswordState,
+            "PASSWORD_NOT_FOUND" => DeviceStatusDetailCode::PasswordNotFound,
+            "PASSWORD_MANAGER_ACCESS_DENIED" => DeviceStatusDetailCode::PasswordManagerAccessDenied,
+            "CERTIFICATE_AUTHORITY_ACCESS_DENIED" => {
+                DeviceStatusDetailCode::CertificateAuthorityAccessDenied
+            }
+            _ => DeviceStatusDetailCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DeviceStatusDetailCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DeviceStatusDetailCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DeviceStatusDetailCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DeviceStatusDetailCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeviceStatusDetailCode {
+    fn deserialize<D>(deserializer: D) -> Result<DeviceStatusDetailCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Detailed information about a device's status.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1212,17 +2324,110 @@ pub struct DeviceStatusInfo {
     /// <p>The latest available information about the connection status of a device. </p>
     #[serde(rename = "ConnectionStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub connection_status: Option<String>,
+    pub connection_status: Option<ConnectionStatus>,
     /// <p>The time (in epoch) when the device connection status changed.</p>
     #[serde(rename = "ConnectionStatusUpdatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub connection_status_updated_time: Option<f64>,
+    pub connection_status_updated_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>One or more device status detail descriptions.</p>
     #[serde(rename = "DeviceStatusDetails")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub device_status_details: Option<Vec<DeviceStatusDetail>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DeviceUsageType {
+    /// `VOICE`
+    Voice,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DeviceUsageType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeviceUsageType::Voice => "VOICE",
+            DeviceUsageType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DeviceUsageType {
+    fn default() -> DeviceUsageType {
+        DeviceUsageType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DeviceUsageType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DeviceUsageType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DeviceUsageType {
+    fn from(value: &'a str) -> DeviceUsageType {
+        match value {
+            "VOICE" => DeviceUsageType::Voice,
+            _ => DeviceUsageType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeviceUsageType {
+    fn from(value: String) -> DeviceUsageType {
+        match value.as_str() {
+            "VOICE" => DeviceUsageType::Voice,
+            _ => DeviceUsageType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DeviceUsageType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DeviceUsageType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DeviceUsageType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DeviceUsageType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeviceUsageType {
+    fn deserialize<D>(deserializer: D) -> Result<DeviceUsageType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct DisassociateContactFromAddressBookRequest {
@@ -1247,55 +2452,334 @@ pub struct DisassociateDeviceFromRoomRequest {
     pub device_arn: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct DisassociateDeviceFromRoomResponse {}
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct DisassociateDeviceFromRoomResponse {}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct DisassociateSkillFromSkillGroupRequest {
+    /// <p>The unique identifier of a skill. Required.</p>
+    #[serde(rename = "SkillGroupArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skill_group_arn: Option<String>,
+    /// <p>The ARN of a skill group to associate to a skill.</p>
+    #[serde(rename = "SkillId")]
+    pub skill_id: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct DisassociateSkillFromSkillGroupResponse {}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct DisassociateSkillFromUsersRequest {
+    /// <p> The private skill ID you want to make unavailable for enrolled users.</p>
+    #[serde(rename = "SkillId")]
+    pub skill_id: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct DisassociateSkillFromUsersResponse {}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct DisassociateSkillGroupFromRoomRequest {
+    /// <p>The ARN of the room from which the skill group is to be disassociated. Required.</p>
+    #[serde(rename = "RoomArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub room_arn: Option<String>,
+    /// <p>The ARN of the skill group to disassociate from a room. Required.</p>
+    #[serde(rename = "SkillGroupArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skill_group_arn: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct DisassociateSkillGroupFromRoomResponse {}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DistanceUnit {
+    /// `METRIC`
+    Metric,
+    /// `IMPERIAL`
+    Imperial,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DistanceUnit {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DistanceUnit::Metric => "METRIC",
+            DistanceUnit::Imperial => "IMPERIAL",
+            DistanceUnit::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DistanceUnit {
+    fn default() -> DistanceUnit {
+        DistanceUnit::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DistanceUnit {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DistanceUnit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DistanceUnit {
+    fn from(value: &'a str) -> DistanceUnit {
+        match value {
+            "METRIC" => DistanceUnit::Metric,
+            "IMPERIAL" => DistanceUnit::Imperial,
+            _ => DistanceUnit::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DistanceUnit {
+    fn from(value: String) -> DistanceUnit {
+        match value.as_str() {
+            "METRIC" => DistanceUnit::Metric,
+            "IMPERIAL" => DistanceUnit::Imperial,
+            _ => DistanceUnit::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DistanceUnit {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DistanceUnit, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DistanceUnit {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DistanceUnit {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DistanceUnit {
+    fn deserialize<D>(deserializer: D) -> Result<DistanceUnit, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EnablementType {
+    /// `ENABLED`
+    Enabled,
+    /// `PENDING`
+    Pending,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EnablementType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EnablementType::Enabled => "ENABLED",
+            EnablementType::Pending => "PENDING",
+            EnablementType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EnablementType {
+    fn default() -> EnablementType {
+        EnablementType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EnablementType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EnablementType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EnablementType {
+    fn from(value: &'a str) -> EnablementType {
+        match value {
+            "ENABLED" => EnablementType::Enabled,
+            "PENDING" => EnablementType::Pending,
+            _ => EnablementType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EnablementType {
+    fn from(value: String) -> EnablementType {
+        match value.as_str() {
+            "ENABLED" => EnablementType::Enabled,
+            "PENDING" => EnablementType::Pending,
+            _ => EnablementType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EnablementType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EnablementType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EnablementType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EnablementType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EnablementType {
+    fn deserialize<D>(deserializer: D) -> Result<EnablementType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EnablementTypeFilter {
+    /// `ENABLED`
+    Enabled,
+    /// `PENDING`
+    Pending,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EnablementTypeFilter {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EnablementTypeFilter::Enabled => "ENABLED",
+            EnablementTypeFilter::Pending => "PENDING",
+            EnablementTypeFilter::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EnablementTypeFilter {
+    fn default() -> EnablementTypeFilter {
+        EnablementTypeFilter::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EnablementTypeFilter {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct DisassociateSkillFromSkillGroupRequest {
-    /// <p>The unique identifier of a skill. Required.</p>
-    #[serde(rename = "SkillGroupArn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub skill_group_arn: Option<String>,
-    /// <p>The ARN of a skill group to associate to a skill.</p>
-    #[serde(rename = "SkillId")]
-    pub skill_id: String,
+impl fmt::Display for EnablementTypeFilter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct DisassociateSkillFromSkillGroupResponse {}
+impl<'a> From<&'a str> for EnablementTypeFilter {
+    fn from(value: &'a str) -> EnablementTypeFilter {
+        match value {
+            "ENABLED" => EnablementTypeFilter::Enabled,
+            "PENDING" => EnablementTypeFilter::Pending,
+            _ => EnablementTypeFilter::Unknown(value.to_owned()),
+        }
+    }
+}
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct DisassociateSkillFromUsersRequest {
-    /// <p> The private skill ID you want to make unavailable for enrolled users.</p>
-    #[serde(rename = "SkillId")]
-    pub skill_id: String,
+impl From<String> for EnablementTypeFilter {
+    fn from(value: String) -> EnablementTypeFilter {
+        match value.as_str() {
+            "ENABLED" => EnablementTypeFilter::Enabled,
+            "PENDING" => EnablementTypeFilter::Pending,
+            _ => EnablementTypeFilter::Unknown(value),
+        }
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct DisassociateSkillFromUsersResponse {}
+impl ::std::str::FromStr for EnablementTypeFilter {
+    type Err = ::std::convert::Infallible;
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct DisassociateSkillGroupFromRoomRequest {
-    /// <p>The ARN of the room from which the skill group is to be disassociated. Required.</p>
-    #[serde(rename = "RoomArn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub room_arn: Option<String>,
-    /// <p>The ARN of the skill group to disassociate from a room. Required.</p>
-    #[serde(rename = "SkillGroupArn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub skill_group_arn: Option<String>,
+    fn from_str(value: &str) -> Result<EnablementTypeFilter, Self::Err> {
+        Ok(value.into())
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct DisassociateSkillGroupFromRoomResponse {}
+impl ::rusoto_core::param::ToParam for EnablementTypeFilter {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EnablementTypeFilter {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
 
+impl<'de> ::serde::Deserialize<'de> for EnablementTypeFilter {
+    fn deserialize<D>(deserializer: D) -> Result<EnablementTypeFilter, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Settings for the end of meeting reminder feature that are applied to a room profile. The end of meeting reminder enables Alexa to remind users when a meeting is ending. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1311,9 +2795,349 @@ pub struct EndOfMeetingReminder {
     /// <p>The type of sound that users hear during the end of meeting reminder. </p>
     #[serde(rename = "ReminderType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub reminder_type: Option<String>,
+    pub reminder_type: Option<EndOfMeetingReminderType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EndOfMeetingReminderType {
+    /// `ANNOUNCEMENT_TIME_CHECK`
+    AnnouncementTimeCheck,
+    /// `ANNOUNCEMENT_VARIABLE_TIME_LEFT`
+    AnnouncementVariableTimeLeft,
+    /// `CHIME`
+    Chime,
+    /// `KNOCK`
+    Knock,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EndOfMeetingReminderType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EndOfMeetingReminderType::AnnouncementTimeCheck => "ANNOUNCEMENT_TIME_CHECK",
+            EndOfMeetingReminderType::AnnouncementVariableTimeLeft => {
+                "ANNOUNCEMENT_VARIABLE_TIME_LEFT"
+            }
+            EndOfMeetingReminderType::Chime => "CHIME",
+            EndOfMeetingReminderType::Knock => "KNOCK",
+            EndOfMeetingReminderType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EndOfMeetingReminderType {
+    fn default() -> EndOfMeetingReminderType {
+        EndOfMeetingReminderType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EndOfMeetingReminderType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EndOfMeetingReminderType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EndOfMeetingReminderType {
+    fn from(value: &'a str) -> EndOfMeetingReminderType {
+        match value {
+            "ANNOUNCEMENT_TIME_CHECK" => EndOfMeetingReminderType::AnnouncementTimeCheck,
+            "ANNOUNCEMENT_VARIABLE_TIME_LEFT" => {
+                EndOfMeetingReminderType::AnnouncementVariableTimeLeft
+            }
+            "CHIME" => EndOfMeetingReminderType::Chime,
+            "KNOCK" => EndOfMeetingReminderType::Knock,
+            _ => EndOfMeetingReminderType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EndOfMeetingReminderType {
+    fn from(value: String) -> EndOfMeetingReminderType {
+        match value.as_str() {
+            "ANNOUNCEMENT_TIME_CHECK" => EndOfMeetingReminderType::AnnouncementTimeCheck,
+            "ANNOUNCEMENT_VARIABLE_TIME_LEFT" => {
+                EndOfMeetingReminderType::AnnouncementVariableTimeLeft
+            }
+            "CHIME" => EndOfMeetingReminderType::Chime,
+            "KNOCK" => EndOfMeetingReminderType::Knock,
+            _ => EndOfMeetingReminderType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EndOfMeetingReminderType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EndOfMeetingReminderType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EndOfMeetingReminderType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EndOfMeetingReminderType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EndOfMeetingReminderType {
+    fn deserialize<D>(deserializer: D) -> Result<EndOfMeetingReminderType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EnrollmentStatus {
+    /// `INITIALIZED`
+    Initialized,
+    /// `PENDING`
+    Pending,
+    /// `REGISTERED`
+    Registered,
+    /// `DISASSOCIATING`
+    Disassociating,
+    /// `DEREGISTERING`
+    Deregistering,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EnrollmentStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EnrollmentStatus::Initialized => "INITIALIZED",
+            EnrollmentStatus::Pending => "PENDING",
+            EnrollmentStatus::Registered => "REGISTERED",
+            EnrollmentStatus::Disassociating => "DISASSOCIATING",
+            EnrollmentStatus::Deregistering => "DEREGISTERING",
+            EnrollmentStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EnrollmentStatus {
+    fn default() -> EnrollmentStatus {
+        EnrollmentStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EnrollmentStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EnrollmentStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EnrollmentStatus {
+    fn from(value: &'a str) -> EnrollmentStatus {
+        match value {
+            "INITIALIZED" => EnrollmentStatus::Initialized,
+            "PENDING" => EnrollmentStatus::Pending,
+            "REGISTERED" => EnrollmentStatus::Registered,
+            "DISASSOCIATING" => EnrollmentStatus::Disassociating,
+            "DEREGISTERING" => EnrollmentStatus::Deregistering,
+            _ => EnrollmentStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EnrollmentStatus {
+    fn from(value: String) -> EnrollmentStatus {
+        match value.as_str() {
+            "INITIALIZED" => EnrollmentStatus::Initialized,
+            "PENDING" => EnrollmentStatus::Pending,
+            "REGISTERED" => EnrollmentStatus::Registered,
+            "DISASSOCIATING" => EnrollmentStatus::Disassociating,
+            "DEREGISTERING" => EnrollmentStatus::Deregistering,
+            _ => EnrollmentStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EnrollmentStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EnrollmentStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EnrollmentStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EnrollmentStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EnrollmentStatus {
+    fn deserialize<D>(deserializer: D) -> Result<EnrollmentStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Feature {
+    /// `BLUETOOTH`
+    Bluetooth,
+    /// `VOLUME`
+    Volume,
+    /// `NOTIFICATIONS`
+    Notifications,
+    /// `LISTS`
+    Lists,
+    /// `SKILLS`
+    Skills,
+    /// `NETWORK_PROFILE`
+    NetworkProfile,
+    /// `SETTINGS`
+    Settings,
+    /// `ALL`
+    All,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Feature {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Feature::Bluetooth => "BLUETOOTH",
+            Feature::Volume => "VOLUME",
+            Feature::Notifications => "NOTIFICATIONS",
+            Feature::Lists => "LISTS",
+            Feature::Skills => "SKILLS",
+            Feature::NetworkProfile => "NETWORK_PROFILE",
+            Feature::Settings => "SETTINGS",
+            Feature::All => "ALL",
+            Feature::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Feature {
+    fn default() -> Feature {
+        Feature::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Feature {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Feature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Feature {
+    fn from(value: &'a str) -> Feature {
+        match value {
+            "BLUETOOTH" => Feature::Bluetooth,
+            "VOLUME" => Feature::Volume,
+            "NOTIFICATIONS" => Feature::Notifications,
+            "LISTS" => Feature::Lists,
+            "SKILLS" => Feature::Skills,
+            "NETWORK_PROFILE" => Feature::NetworkProfile,
+            "SETTINGS" => Feature::Settings,
+            "ALL" => Feature::All,
+            _ => Feature::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Feature {
+    fn from(value: String) -> Feature {
+        match value.as_str() {
+            "BLUETOOTH" => Feature::Bluetooth,
+            "VOLUME" => Feature::Volume,
+            "NOTIFICATIONS" => Feature::Notifications,
+            "LISTS" => Feature::Lists,
+            "SKILLS" => Feature::Skills,
+            "NETWORK_PROFILE" => Feature::NetworkProfile,
+            "SETTINGS" => Feature::Settings,
+            "ALL" => Feature::All,
+            _ => Feature::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Feature {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Feature, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Feature {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Feature {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for Feature {
+    fn deserialize<D>(deserializer: D) -> Result<Feature, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A filter name and value pair that is used to return a more specific list of results. Filters can be used to match a set of resources by various criteria.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -1662,7 +3486,7 @@ pub struct GetSkillGroupResponse {
 pub struct IPDialIn {
     /// <p>The protocol, including SIP, SIPS, and H323.</p>
     #[serde(rename = "CommsProtocol")]
-    pub comms_protocol: String,
+    pub comms_protocol: CommsProtocol,
     /// <p>The IP address.</p>
     #[serde(rename = "Endpoint")]
     pub endpoint: String,
@@ -1743,7 +3567,7 @@ pub struct ListDeviceEventsRequest {
     /// <p>The event type to filter device events. If EventType isn't specified, this returns a list of all device events in reverse chronological order. If EventType is specified, this returns a list of device events for that EventType in reverse chronological order. </p>
     #[serde(rename = "EventType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub event_type: Option<String>,
+    pub event_type: Option<DeviceEventType>,
     /// <p>The maximum number of results to include in the response. The default value is 50. If more results exist than the specified MaxResults value, a token is included in the response so that the remaining results can be retrieved. </p>
     #[serde(rename = "MaxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1829,7 +3653,7 @@ pub struct ListSkillsRequest {
     /// <p>Whether the skill is enabled under the user's account.</p>
     #[serde(rename = "EnablementType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub enablement_type: Option<String>,
+    pub enablement_type: Option<EnablementTypeFilter>,
     /// <p>The maximum number of results to include in the response. If more results exist than the specified <code>MaxResults</code> value, a token is included in the response so that the remaining results can be retrieved.</p>
     #[serde(rename = "MaxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1845,7 +3669,7 @@ pub struct ListSkillsRequest {
     /// <p>Whether the skill is publicly available or is a private skill.</p>
     #[serde(rename = "SkillType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub skill_type: Option<String>,
+    pub skill_type: Option<SkillTypeFilter>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1974,6 +3798,94 @@ pub struct ListTagsResponse {
     pub tags: Option<Vec<Tag>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Locale {
+    /// `en-US`
+    EnUs,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Locale {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Locale::EnUs => "en-US",
+            Locale::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Locale {
+    fn default() -> Locale {
+        Locale::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Locale {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Locale {
+    fn from(value: &'a str) -> Locale {
+        match value {
+            "en-US" => Locale::EnUs,
+            _ => Locale::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Locale {
+    fn from(value: String) -> Locale {
+        match value.as_str() {
+            "en-US" => Locale::EnUs,
+            _ => Locale::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Locale {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Locale, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Locale {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Locale {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Locale {
+    fn deserialize<D>(deserializer: D) -> Result<Locale, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Meeting room settings of a room profile.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1996,14 +3908,102 @@ pub struct MeetingRoomConfiguration {
     pub room_utilization_metrics_enabled: Option<bool>,
 }
 
-/// <p><p>The values that indicate whether a pin is always required (YES), never required (NO), or OPTIONAL.</p> <ul> <li> <p>If YES, Alexa will always ask for a meeting pin.</p> </li> <li> <p>If NO, Alexa will never ask for a meeting pin.</p> </li> <li> <p>If OPTIONAL, Alexa will ask if you have a meeting pin and if the customer responds with yes, it will ask for the meeting pin.</p> </li> </ul></p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct MeetingSetting {
-    /// <p>The values that indicate whether the pin is always required.</p>
-    #[serde(rename = "RequirePin")]
-    pub require_pin: String,
+/// <p><p>The values that indicate whether a pin is always required (YES), never required (NO), or OPTIONAL.</p> <ul> <li> <p>If YES, Alexa will always ask for a meeting pin.</p> </li> <li> <p>If NO, Alexa will never ask for a meeting pin.</p> </li> <li> <p>If OPTIONAL, Alexa will ask if you have a meeting pin and if the customer responds with yes, it will ask for the meeting pin.</p> </li> </ul></p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MeetingSetting {
+    /// <p>The values that indicate whether the pin is always required.</p>
+    #[serde(rename = "RequirePin")]
+    pub require_pin: RequirePin,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum NetworkEapMethod {
+    /// `EAP_TLS`
+    EapTls,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl NetworkEapMethod {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            NetworkEapMethod::EapTls => "EAP_TLS",
+            NetworkEapMethod::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for NetworkEapMethod {
+    fn default() -> NetworkEapMethod {
+        NetworkEapMethod::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for NetworkEapMethod {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for NetworkEapMethod {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for NetworkEapMethod {
+    fn from(value: &'a str) -> NetworkEapMethod {
+        match value {
+            "EAP_TLS" => NetworkEapMethod::EapTls,
+            _ => NetworkEapMethod::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for NetworkEapMethod {
+    fn from(value: String) -> NetworkEapMethod {
+        match value.as_str() {
+            "EAP_TLS" => NetworkEapMethod::EapTls,
+            _ => NetworkEapMethod::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for NetworkEapMethod {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<NetworkEapMethod, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for NetworkEapMethod {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for NetworkEapMethod {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for NetworkEapMethod {
+    fn deserialize<D>(deserializer: D) -> Result<NetworkEapMethod, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The network profile associated with a device.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -2023,7 +4023,7 @@ pub struct NetworkProfile {
     /// <p>The authentication standard that is used in the EAP framework. Currently, EAP_TLS is supported. </p>
     #[serde(rename = "EapMethod")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub eap_method: Option<String>,
+    pub eap_method: Option<NetworkEapMethod>,
     /// <p>The ARN of the network profile associated with a device.</p>
     #[serde(rename = "NetworkProfileArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2039,7 +4039,7 @@ pub struct NetworkProfile {
     /// <p>The security type of the Wi-Fi network. This can be WPA2_ENTERPRISE, WPA2_PSK, WPA_PSK, WEP, or OPEN.</p>
     #[serde(rename = "SecurityType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub security_type: Option<String>,
+    pub security_type: Option<NetworkSecurityType>,
     /// <p>The SSID of the Wi-Fi network.</p>
     #[serde(rename = "Ssid")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2065,7 +4065,7 @@ pub struct NetworkProfileData {
     /// <p>The authentication standard that is used in the EAP framework. Currently, EAP_TLS is supported.</p>
     #[serde(rename = "EapMethod")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub eap_method: Option<String>,
+    pub eap_method: Option<NetworkEapMethod>,
     /// <p>The ARN of the network profile associated with a device.</p>
     #[serde(rename = "NetworkProfileArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2077,13 +4077,121 @@ pub struct NetworkProfileData {
     /// <p>The security type of the Wi-Fi network. This can be WPA2_ENTERPRISE, WPA2_PSK, WPA_PSK, WEP, or OPEN.</p>
     #[serde(rename = "SecurityType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub security_type: Option<String>,
+    pub security_type: Option<NetworkSecurityType>,
     /// <p>The SSID of the Wi-Fi network.</p>
     #[serde(rename = "Ssid")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ssid: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum NetworkSecurityType {
+    /// `OPEN`
+    Open,
+    /// `WEP`
+    Wep,
+    /// `WPA_PSK`
+    WpaPsk,
+    /// `WPA2_PSK`
+    Wpa2Psk,
+    /// `WPA2_ENTERPRISE`
+    Wpa2Enterprise,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl NetworkSecurityType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            NetworkSecurityType::Open => "OPEN",
+            NetworkSecurityType::Wep => "WEP",
+            NetworkSecurityType::WpaPsk => "WPA_PSK",
+            NetworkSecurityType::Wpa2Psk => "WPA2_PSK",
+            NetworkSecurityType::Wpa2Enterprise => "WPA2_ENTERPRISE",
+            NetworkSecurityType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for NetworkSecurityType {
+    fn default() -> NetworkSecurityType {
+        NetworkSecurityType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for NetworkSecurityType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for NetworkSecurityType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for NetworkSecurityType {
+    fn from(value: &'a str) -> NetworkSecurityType {
+        match value {
+            "OPEN" => NetworkSecurityType::Open,
+            "WEP" => NetworkSecurityType::Wep,
+            "WPA_PSK" => NetworkSecurityType::WpaPsk,
+            "WPA2_PSK" => NetworkSecurityType::Wpa2Psk,
+            "WPA2_ENTERPRISE" => NetworkSecurityType::Wpa2Enterprise,
+            _ => NetworkSecurityType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for NetworkSecurityType {
+    fn from(value: String) -> NetworkSecurityType {
+        match value.as_str() {
+            "OPEN" => NetworkSecurityType::Open,
+            "WEP" => NetworkSecurityType::Wep,
+            "WPA_PSK" => NetworkSecurityType::WpaPsk,
+            "WPA2_PSK" => NetworkSecurityType::Wpa2Psk,
+            "WPA2_ENTERPRISE" => NetworkSecurityType::Wpa2Enterprise,
+            _ => NetworkSecurityType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for NetworkSecurityType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<NetworkSecurityType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for NetworkSecurityType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for NetworkSecurityType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for NetworkSecurityType {
+    fn deserialize<D>(deserializer: D) -> Result<NetworkSecurityType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The information for public switched telephone network (PSTN) conferencing.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PSTNDialIn {
@@ -2109,9 +4217,107 @@ pub struct PhoneNumber {
     pub number: String,
     /// <p>The type of the phone number.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: PhoneNumberType,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PhoneNumberType {
+    /// `MOBILE`
+    Mobile,
+    /// `WORK`
+    Work,
+    /// `HOME`
+    Home,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PhoneNumberType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PhoneNumberType::Mobile => "MOBILE",
+            PhoneNumberType::Work => "WORK",
+            PhoneNumberType::Home => "HOME",
+            PhoneNumberType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PhoneNumberType {
+    fn default() -> PhoneNumberType {
+        PhoneNumberType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PhoneNumberType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PhoneNumberType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PhoneNumberType {
+    fn from(value: &'a str) -> PhoneNumberType {
+        match value {
+            "MOBILE" => PhoneNumberType::Mobile,
+            "WORK" => PhoneNumberType::Work,
+            "HOME" => PhoneNumberType::Home,
+            _ => PhoneNumberType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PhoneNumberType {
+    fn from(value: String) -> PhoneNumberType {
+        match value.as_str() {
+            "MOBILE" => PhoneNumberType::Mobile,
+            "WORK" => PhoneNumberType::Work,
+            "HOME" => PhoneNumberType::Home,
+            _ => PhoneNumberType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PhoneNumberType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PhoneNumberType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PhoneNumberType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PhoneNumberType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for PhoneNumberType {
+    fn deserialize<D>(deserializer: D) -> Result<PhoneNumberType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A room profile with attributes.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -2127,7 +4333,7 @@ pub struct Profile {
     /// <p>The distance unit of a room profile.</p>
     #[serde(rename = "DistanceUnit")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub distance_unit: Option<String>,
+    pub distance_unit: Option<DistanceUnit>,
     /// <p>Retrieves if the profile is default or not.</p>
     #[serde(rename = "IsDefault")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2163,7 +4369,7 @@ pub struct Profile {
     /// <p>The temperature unit of a room profile.</p>
     #[serde(rename = "TemperatureUnit")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub temperature_unit: Option<String>,
+    pub temperature_unit: Option<TemperatureUnit>,
     /// <p>The time zone of a room profile.</p>
     #[serde(rename = "Timezone")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2171,7 +4377,7 @@ pub struct Profile {
     /// <p>The wake word of a room profile.</p>
     #[serde(rename = "WakeWord")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub wake_word: Option<String>,
+    pub wake_word: Option<WakeWord>,
 }
 
 /// <p>The data of a room profile.</p>
@@ -2185,7 +4391,7 @@ pub struct ProfileData {
     /// <p>The distance unit of a room profile.</p>
     #[serde(rename = "DistanceUnit")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub distance_unit: Option<String>,
+    pub distance_unit: Option<DistanceUnit>,
     /// <p>Retrieves if the profile data is default or not.</p>
     #[serde(rename = "IsDefault")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2205,7 +4411,7 @@ pub struct ProfileData {
     /// <p>The temperature unit of a room profile.</p>
     #[serde(rename = "TemperatureUnit")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub temperature_unit: Option<String>,
+    pub temperature_unit: Option<TemperatureUnit>,
     /// <p>The time zone of a room profile.</p>
     #[serde(rename = "Timezone")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2213,7 +4419,7 @@ pub struct ProfileData {
     /// <p>The wake word of a room profile.</p>
     #[serde(rename = "WakeWord")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub wake_word: Option<String>,
+    pub wake_word: Option<WakeWord>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -2341,6 +4547,104 @@ pub struct RequireCheckIn {
     pub release_after_minutes: Option<i64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RequirePin {
+    /// `YES`
+    Yes,
+    /// `NO`
+    No,
+    /// `OPTIONAL`
+    Optional,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RequirePin {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RequirePin::Yes => "YES",
+            RequirePin::No => "NO",
+            RequirePin::Optional => "OPTIONAL",
+            RequirePin::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RequirePin {
+    fn default() -> RequirePin {
+        RequirePin::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RequirePin {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RequirePin {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RequirePin {
+    fn from(value: &'a str) -> RequirePin {
+        match value {
+            "YES" => RequirePin::Yes,
+            "NO" => RequirePin::No,
+            "OPTIONAL" => RequirePin::Optional,
+            _ => RequirePin::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RequirePin {
+    fn from(value: String) -> RequirePin {
+        match value.as_str() {
+            "YES" => RequirePin::Yes,
+            "NO" => RequirePin::No,
+            "OPTIONAL" => RequirePin::Optional,
+            _ => RequirePin::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RequirePin {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RequirePin, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RequirePin {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RequirePin {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RequirePin {
+    fn deserialize<D>(deserializer: D) -> Result<RequirePin, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ResolveRoomRequest {
@@ -2802,12 +5106,100 @@ pub struct SendInvitationResponse {}
 pub struct SipAddress {
     /// <p>The type of the SIP address.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: SipType,
     /// <p>The URI for the SIP address.</p>
     #[serde(rename = "Uri")]
     pub uri: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SipType {
+    /// `WORK`
+    Work,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SipType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SipType::Work => "WORK",
+            SipType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SipType {
+    fn default() -> SipType {
+        SipType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SipType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SipType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SipType {
+    fn from(value: &'a str) -> SipType {
+        match value {
+            "WORK" => SipType::Work,
+            _ => SipType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SipType {
+    fn from(value: String) -> SipType {
+        match value.as_str() {
+            "WORK" => SipType::Work,
+            _ => SipType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SipType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SipType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SipType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SipType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SipType {
+    fn deserialize<D>(deserializer: D) -> Result<SipType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Granular information about the skill.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -2890,32 +5282,223 @@ pub struct SkillGroupData {
     pub skill_group_name: Option<String>,
 }
 
-/// <p>The summary of skills.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct SkillSummary {
-    /// <p>Whether the skill is enabled under the user's account, or if it requires linking to be used.</p>
-    #[serde(rename = "EnablementType")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub enablement_type: Option<String>,
-    /// <p>The ARN of the skill summary.</p>
-    #[serde(rename = "SkillId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub skill_id: Option<String>,
-    /// <p>The name of the skill.</p>
-    #[serde(rename = "SkillName")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub skill_name: Option<String>,
-    /// <p>Whether the skill is publicly available or is a private skill.</p>
-    #[serde(rename = "SkillType")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub skill_type: Option<String>,
-    /// <p>Linking support for a skill.</p>
-    #[serde(rename = "SupportsLinking")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub supports_linking: Option<bool>,
+/// <p>The summary of skills.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct SkillSummary {
+    /// <p>Whether the skill is enabled under the user's account, or if it requires linking to be used.</p>
+    #[serde(rename = "EnablementType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enablement_type: Option<EnablementType>,
+    /// <p>The ARN of the skill summary.</p>
+    #[serde(rename = "SkillId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skill_id: Option<String>,
+    /// <p>The name of the skill.</p>
+    #[serde(rename = "SkillName")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skill_name: Option<String>,
+    /// <p>Whether the skill is publicly available or is a private skill.</p>
+    #[serde(rename = "SkillType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skill_type: Option<SkillType>,
+    /// <p>Linking support for a skill.</p>
+    #[serde(rename = "SupportsLinking")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supports_linking: Option<bool>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SkillType {
+    /// `PUBLIC`
+    Public,
+    /// `PRIVATE`
+    Private,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SkillType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SkillType::Public => "PUBLIC",
+            SkillType::Private => "PRIVATE",
+            SkillType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SkillType {
+    fn default() -> SkillType {
+        SkillType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SkillType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SkillType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SkillType {
+    fn from(value: &'a str) -> SkillType {
+        match value {
+            "PUBLIC" => SkillType::Public,
+            "PRIVATE" => SkillType::Private,
+            _ => SkillType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SkillType {
+    fn from(value: String) -> SkillType {
+        match value.as_str() {
+            "PUBLIC" => SkillType::Public,
+            "PRIVATE" => SkillType::Private,
+            _ => SkillType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SkillType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SkillType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SkillType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SkillType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SkillType {
+    fn deserialize<D>(deserializer: D) -> Result<SkillType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SkillTypeFilter {
+    /// `PUBLIC`
+    Public,
+    /// `PRIVATE`
+    Private,
+    /// `ALL`
+    All,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SkillTypeFilter {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SkillTypeFilter::Public => "PUBLIC",
+            SkillTypeFilter::Private => "PRIVATE",
+            SkillTypeFilter::All => "ALL",
+            SkillTypeFilter::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SkillTypeFilter {
+    fn default() -> SkillTypeFilter {
+        SkillTypeFilter::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SkillTypeFilter {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SkillTypeFilter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SkillTypeFilter {
+    fn from(value: &'a str) -> SkillTypeFilter {
+        match value {
+            "PUBLIC" => SkillTypeFilter::Public,
+            "PRIVATE" => SkillTypeFilter::Private,
+            "ALL" => SkillTypeFilter::All,
+            _ => SkillTypeFilter::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SkillTypeFilter {
+    fn from(value: String) -> SkillTypeFilter {
+        match value.as_str() {
+            "PUBLIC" => SkillTypeFilter::Public,
+            "PRIVATE" => SkillTypeFilter::Private,
+            "ALL" => SkillTypeFilter::All,
+            _ => SkillTypeFilter::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SkillTypeFilter {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SkillTypeFilter, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SkillTypeFilter {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SkillTypeFilter {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for SkillTypeFilter {
+    fn deserialize<D>(deserializer: D) -> Result<SkillTypeFilter, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The detailed information about an Alexa skill.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -2977,16 +5560,109 @@ pub struct Sort {
     pub key: String,
     /// <p>The sort value of a sort object.</p>
     #[serde(rename = "Value")]
-    pub value: String,
+    pub value: SortValue,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SortValue {
+    /// `ASC`
+    Asc,
+    /// `DESC`
+    Desc,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SortValue {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SortValue::Asc => "ASC",
+            SortValue::Desc => "DESC",
+            SortValue::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SortValue {
+    fn default() -> SortValue {
+        SortValue::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SortValue {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SortValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SortValue {
+    fn from(value: &'a str) -> SortValue {
+        match value {
+            "ASC" => SortValue::Asc,
+            "DESC" => SortValue::Desc,
+            _ => SortValue::Unknown(value.to_owned()),
+        }
+    }
 }
 
+impl From<String> for SortValue {
+    fn from(value: String) -> SortValue {
+        match value.as_str() {
+            "ASC" => SortValue::Asc,
+            "DESC" => SortValue::Desc,
+            _ => SortValue::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SortValue {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SortValue, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SortValue {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SortValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SortValue {
+    fn deserialize<D>(deserializer: D) -> Result<SortValue, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The SSML message. For more information, see <a href="https://developer.amazon.com/docs/custom-skills/speech-synthesis-markup-language-ssml-reference.html">SSML Reference</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct Ssml {
     /// <p>The locale of the SSML message. Currently, en-US is supported.</p>
     #[serde(rename = "Locale")]
-    pub locale: String,
+    pub locale: Locale,
     /// <p>The value of the SSML message in the correct SSML format. The audio tag is not supported.</p>
     #[serde(rename = "Value")]
     pub value: String,
@@ -3001,7 +5677,7 @@ pub struct StartDeviceSyncRequest {
     pub device_arn: Option<String>,
     /// <p>Request structure to start the device sync. Required.</p>
     #[serde(rename = "Features")]
-    pub features: Vec<String>,
+    pub features: Vec<Feature>,
     /// <p>The ARN of the room with which the device to sync is associated. Required.</p>
     #[serde(rename = "RoomArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3050,13 +5726,106 @@ pub struct TagResourceRequest {
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TagResourceResponse {}
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TemperatureUnit {
+    /// `FAHRENHEIT`
+    Fahrenheit,
+    /// `CELSIUS`
+    Celsius,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TemperatureUnit {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TemperatureUnit::Fahrenheit => "FAHRENHEIT",
+            TemperatureUnit::Celsius => "CELSIUS",
+            TemperatureUnit::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TemperatureUnit {
+    fn default() -> TemperatureUnit {
+        TemperatureUnit::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TemperatureUnit {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TemperatureUnit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TemperatureUnit {
+    fn from(value: &'a str) -> TemperatureUnit {
+        match value {
+            "FAHRENHEIT" => TemperatureUnit::Fahrenheit,
+            "CELSIUS" => TemperatureUnit::Celsius,
+            _ => TemperatureUnit::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TemperatureUnit {
+    fn from(value: String) -> TemperatureUnit {
+        match value.as_str() {
+            "FAHRENHEIT" => TemperatureUnit::Fahrenheit,
+            "CELSIUS" => TemperatureUnit::Celsius,
+            _ => TemperatureUnit::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TemperatureUnit {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TemperatureUnit, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TemperatureUnit {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TemperatureUnit {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TemperatureUnit {
+    fn deserialize<D>(deserializer: D) -> Result<TemperatureUnit, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The text message.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct Text {
     /// <p>The locale of the text message. Currently, en-US is supported.</p>
     #[serde(rename = "Locale")]
-    pub locale: String,
+    pub locale: Locale,
     /// <p>The value of the text message.</p>
     #[serde(rename = "Value")]
     pub value: String,
@@ -3103,7 +5872,7 @@ pub struct UpdateBusinessReportScheduleRequest {
     /// <p>The format of the generated report (individual CSV files or zipped files of individual files).</p>
     #[serde(rename = "Format")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub format: Option<String>,
+    pub format: Option<BusinessReportFormat>,
     /// <p>The recurrence of the reports.</p>
     #[serde(rename = "Recurrence")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3137,7 +5906,7 @@ pub struct UpdateConferenceProviderRequest {
     pub conference_provider_arn: String,
     /// <p>The type of the conference provider.</p>
     #[serde(rename = "ConferenceProviderType")]
-    pub conference_provider_type: String,
+    pub conference_provider_type: ConferenceProviderType,
     /// <p>The IP endpoint and protocol for calling.</p>
     #[serde(rename = "IPDialIn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3223,7 +5992,7 @@ pub struct UpdateEndOfMeetingReminder {
     /// <p>The type of sound that users hear during the end of meeting reminder. </p>
     #[serde(rename = "ReminderType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub reminder_type: Option<String>,
+    pub reminder_type: Option<EndOfMeetingReminderType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -3352,7 +6121,7 @@ pub struct UpdateProfileRequest {
     /// <p>The updated distance unit for the room profile.</p>
     #[serde(rename = "DistanceUnit")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub distance_unit: Option<String>,
+    pub distance_unit: Option<DistanceUnit>,
     /// <p>Sets the profile as default if selected. If this is missing, no update is done to the default status.</p>
     #[serde(rename = "IsDefault")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3388,7 +6157,7 @@ pub struct UpdateProfileRequest {
     /// <p>The updated temperature unit for the room profile.</p>
     #[serde(rename = "TemperatureUnit")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub temperature_unit: Option<String>,
+    pub temperature_unit: Option<TemperatureUnit>,
     /// <p>The updated timezone for the room profile.</p>
     #[serde(rename = "Timezone")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3396,7 +6165,7 @@ pub struct UpdateProfileRequest {
     /// <p>The updated wake word for the room profile.</p>
     #[serde(rename = "WakeWord")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub wake_word: Option<String>,
+    pub wake_word: Option<WakeWord>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -3482,7 +6251,7 @@ pub struct UserData {
     /// <p>The enrollment status of a user.</p>
     #[serde(rename = "EnrollmentStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub enrollment_status: Option<String>,
+    pub enrollment_status: Option<EnrollmentStatus>,
     /// <p>The first name of a user.</p>
     #[serde(rename = "FirstName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3497,6 +6266,109 @@ pub struct UserData {
     pub user_arn: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum WakeWord {
+    /// `ALEXA`
+    Alexa,
+    /// `AMAZON`
+    Amazon,
+    /// `ECHO`
+    Echo,
+    /// `COMPUTER`
+    Computer,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl WakeWord {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            WakeWord::Alexa => "ALEXA",
+            WakeWord::Amazon => "AMAZON",
+            WakeWord::Echo => "ECHO",
+            WakeWord::Computer => "COMPUTER",
+            WakeWord::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for WakeWord {
+    fn default() -> WakeWord {
+        WakeWord::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for WakeWord {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for WakeWord {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for WakeWord {
+    fn from(value: &'a str) -> WakeWord {
+        match value {
+            "ALEXA" => WakeWord::Alexa,
+            "AMAZON" => WakeWord::Amazon,
+            "ECHO" => WakeWord::Echo,
+            "COMPUTER" => WakeWord::Computer,
+            _ => WakeWord::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for WakeWord {
+    fn from(value: String) -> WakeWord {
+        match value.as_str() {
+            "ALEXA" => WakeWord::Alexa,
+            "AMAZON" => WakeWord::Amazon,
+            "ECHO" => WakeWord::Echo,
+            "COMPUTER" => WakeWord::Computer,
+            _ => WakeWord::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for WakeWord {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<WakeWord, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for WakeWord {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for WakeWord {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for WakeWord {
+    fn deserialize<D>(deserializer: D) -> Result<WakeWord, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// Errors returned by ApproveSkill
 #[derive(Debug, PartialEq)]
 pub enum ApproveSkillError {
@@ -7337,11 +10209,12 @@ pub struct AlexaForBusinessClient {
 impl AlexaForBusinessClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> AlexaForBusinessClient {
         AlexaForBusinessClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Alexa For Business"),
         }
     }
 
@@ -7373,6 +10246,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: ApproveSkillRequest,
     ) -> Result<ApproveSkillResponse, RusotoError<ApproveSkillError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("ApproveSkill");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.ApproveSkill");
@@ -7403,6 +10277,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         RusotoError<AssociateContactWithAddressBookError>,
     > {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("AssociateContactWithAddressBook");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7439,6 +10314,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         RusotoError<AssociateDeviceWithNetworkProfileError>,
     > {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("AssociateDeviceWithNetworkProfile");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7472,6 +10348,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: AssociateDeviceWithRoomRequest,
     ) -> Result<AssociateDeviceWithRoomResponse, RusotoError<AssociateDeviceWithRoomError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("AssociateDeviceWithRoom");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.AssociateDeviceWithRoom");
@@ -7501,6 +10378,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
     ) -> Result<AssociateSkillGroupWithRoomResponse, RusotoError<AssociateSkillGroupWithRoomError>>
     {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("AssociateSkillGroupWithRoom");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7533,6 +10411,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
     ) -> Result<AssociateSkillWithSkillGroupResponse, RusotoError<AssociateSkillWithSkillGroupError>>
     {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("AssociateSkillWithSkillGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7564,6 +10443,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: AssociateSkillWithUsersRequest,
     ) -> Result<AssociateSkillWithUsersResponse, RusotoError<AssociateSkillWithUsersError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("AssociateSkillWithUsers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.AssociateSkillWithUsers");
@@ -7592,6 +10472,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: CreateAddressBookRequest,
     ) -> Result<CreateAddressBookResponse, RusotoError<CreateAddressBookError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("CreateAddressBook");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.CreateAddressBook");
@@ -7621,6 +10502,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
     ) -> Result<CreateBusinessReportScheduleResponse, RusotoError<CreateBusinessReportScheduleError>>
     {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("CreateBusinessReportSchedule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7652,6 +10534,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: CreateConferenceProviderRequest,
     ) -> Result<CreateConferenceProviderResponse, RusotoError<CreateConferenceProviderError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("CreateConferenceProvider");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.CreateConferenceProvider");
@@ -7680,6 +10563,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: CreateContactRequest,
     ) -> Result<CreateContactResponse, RusotoError<CreateContactError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("CreateContact");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.CreateContact");
@@ -7707,6 +10591,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: CreateGatewayGroupRequest,
     ) -> Result<CreateGatewayGroupResponse, RusotoError<CreateGatewayGroupError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("CreateGatewayGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.CreateGatewayGroup");
@@ -7735,6 +10620,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: CreateNetworkProfileRequest,
     ) -> Result<CreateNetworkProfileResponse, RusotoError<CreateNetworkProfileError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("CreateNetworkProfile");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.CreateNetworkProfile");
@@ -7763,6 +10649,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: CreateProfileRequest,
     ) -> Result<CreateProfileResponse, RusotoError<CreateProfileError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("CreateProfile");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.CreateProfile");
@@ -7790,6 +10677,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: CreateRoomRequest,
     ) -> Result<CreateRoomResponse, RusotoError<CreateRoomError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("CreateRoom");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.CreateRoom");
@@ -7817,6 +10705,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: CreateSkillGroupRequest,
     ) -> Result<CreateSkillGroupResponse, RusotoError<CreateSkillGroupError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("CreateSkillGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.CreateSkillGroup");
@@ -7845,6 +10734,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: CreateUserRequest,
     ) -> Result<CreateUserResponse, RusotoError<CreateUserError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("CreateUser");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.CreateUser");
@@ -7872,6 +10762,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: DeleteAddressBookRequest,
     ) -> Result<DeleteAddressBookResponse, RusotoError<DeleteAddressBookError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("DeleteAddressBook");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.DeleteAddressBook");
@@ -7901,6 +10792,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
     ) -> Result<DeleteBusinessReportScheduleResponse, RusotoError<DeleteBusinessReportScheduleError>>
     {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("DeleteBusinessReportSchedule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7932,6 +10824,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: DeleteConferenceProviderRequest,
     ) -> Result<DeleteConferenceProviderResponse, RusotoError<DeleteConferenceProviderError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("DeleteConferenceProvider");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.DeleteConferenceProvider");
@@ -7960,6 +10853,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: DeleteContactRequest,
     ) -> Result<DeleteContactResponse, RusotoError<DeleteContactError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("DeleteContact");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.DeleteContact");
@@ -7987,6 +10881,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: DeleteDeviceRequest,
     ) -> Result<DeleteDeviceResponse, RusotoError<DeleteDeviceError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("DeleteDevice");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.DeleteDevice");
@@ -8014,6 +10909,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: DeleteDeviceUsageDataRequest,
     ) -> Result<DeleteDeviceUsageDataResponse, RusotoError<DeleteDeviceUsageDataError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("DeleteDeviceUsageData");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.DeleteDeviceUsageData");
@@ -8042,6 +10938,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: DeleteGatewayGroupRequest,
     ) -> Result<DeleteGatewayGroupResponse, RusotoError<DeleteGatewayGroupError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("DeleteGatewayGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.DeleteGatewayGroup");
@@ -8070,6 +10967,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: DeleteNetworkProfileRequest,
     ) -> Result<DeleteNetworkProfileResponse, RusotoError<DeleteNetworkProfileError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("DeleteNetworkProfile");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.DeleteNetworkProfile");
@@ -8098,6 +10996,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: DeleteProfileRequest,
     ) -> Result<DeleteProfileResponse, RusotoError<DeleteProfileError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("DeleteProfile");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.DeleteProfile");
@@ -8125,6 +11024,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: DeleteRoomRequest,
     ) -> Result<DeleteRoomResponse, RusotoError<DeleteRoomError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("DeleteRoom");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.DeleteRoom");
@@ -8152,6 +11052,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: DeleteRoomSkillParameterRequest,
     ) -> Result<DeleteRoomSkillParameterResponse, RusotoError<DeleteRoomSkillParameterError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("DeleteRoomSkillParameter");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.DeleteRoomSkillParameter");
@@ -8180,6 +11081,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: DeleteSkillAuthorizationRequest,
     ) -> Result<DeleteSkillAuthorizationResponse, RusotoError<DeleteSkillAuthorizationError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("DeleteSkillAuthorization");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.DeleteSkillAuthorization");
@@ -8208,6 +11110,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: DeleteSkillGroupRequest,
     ) -> Result<DeleteSkillGroupResponse, RusotoError<DeleteSkillGroupError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("DeleteSkillGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.DeleteSkillGroup");
@@ -8236,6 +11139,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: DeleteUserRequest,
     ) -> Result<DeleteUserResponse, RusotoError<DeleteUserError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("DeleteUser");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.DeleteUser");
@@ -8266,6 +11170,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         RusotoError<DisassociateContactFromAddressBookError>,
     > {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("DisassociateContactFromAddressBook");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8300,6 +11205,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
     ) -> Result<DisassociateDeviceFromRoomResponse, RusotoError<DisassociateDeviceFromRoomError>>
     {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("DisassociateDeviceFromRoom");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8334,6 +11240,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         RusotoError<DisassociateSkillFromSkillGroupError>,
     > {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("DisassociateSkillFromSkillGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8368,6 +11275,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
     ) -> Result<DisassociateSkillFromUsersResponse, RusotoError<DisassociateSkillFromUsersError>>
     {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("DisassociateSkillFromUsers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8402,6 +11310,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         RusotoError<DisassociateSkillGroupFromRoomError>,
     > {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("DisassociateSkillGroupFromRoom");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8434,6 +11343,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
     ) -> Result<ForgetSmartHomeAppliancesResponse, RusotoError<ForgetSmartHomeAppliancesError>>
     {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("ForgetSmartHomeAppliances");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.ForgetSmartHomeAppliances");
@@ -8462,6 +11372,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: GetAddressBookRequest,
     ) -> Result<GetAddressBookResponse, RusotoError<GetAddressBookError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("GetAddressBook");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.GetAddressBook");
@@ -8488,6 +11399,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         &self,
     ) -> Result<GetConferencePreferenceResponse, RusotoError<GetConferencePreferenceError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("GetConferencePreference");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.GetConferencePreference");
@@ -8515,6 +11427,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: GetConferenceProviderRequest,
     ) -> Result<GetConferenceProviderResponse, RusotoError<GetConferenceProviderError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("GetConferenceProvider");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.GetConferenceProvider");
@@ -8543,6 +11456,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: GetContactRequest,
     ) -> Result<GetContactResponse, RusotoError<GetContactError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("GetContact");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.GetContact");
@@ -8570,6 +11484,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: GetDeviceRequest,
     ) -> Result<GetDeviceResponse, RusotoError<GetDeviceError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("GetDevice");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.GetDevice");
@@ -8597,6 +11512,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: GetGatewayRequest,
     ) -> Result<GetGatewayResponse, RusotoError<GetGatewayError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("GetGateway");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.GetGateway");
@@ -8624,6 +11540,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: GetGatewayGroupRequest,
     ) -> Result<GetGatewayGroupResponse, RusotoError<GetGatewayGroupError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("GetGatewayGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.GetGatewayGroup");
@@ -8651,6 +11568,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
     ) -> Result<GetInvitationConfigurationResponse, RusotoError<GetInvitationConfigurationError>>
     {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("GetInvitationConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8681,6 +11599,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: GetNetworkProfileRequest,
     ) -> Result<GetNetworkProfileResponse, RusotoError<GetNetworkProfileError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("GetNetworkProfile");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.GetNetworkProfile");
@@ -8709,6 +11628,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: GetProfileRequest,
     ) -> Result<GetProfileResponse, RusotoError<GetProfileError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("GetProfile");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.GetProfile");
@@ -8736,6 +11656,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: GetRoomRequest,
     ) -> Result<GetRoomResponse, RusotoError<GetRoomError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("GetRoom");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.GetRoom");
@@ -8763,6 +11684,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: GetRoomSkillParameterRequest,
     ) -> Result<GetRoomSkillParameterResponse, RusotoError<GetRoomSkillParameterError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("GetRoomSkillParameter");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.GetRoomSkillParameter");
@@ -8791,6 +11713,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: GetSkillGroupRequest,
     ) -> Result<GetSkillGroupResponse, RusotoError<GetSkillGroupError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("GetSkillGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.GetSkillGroup");
@@ -8819,6 +11742,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
     ) -> Result<ListBusinessReportSchedulesResponse, RusotoError<ListBusinessReportSchedulesError>>
     {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("ListBusinessReportSchedules");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8850,6 +11774,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: ListConferenceProvidersRequest,
     ) -> Result<ListConferenceProvidersResponse, RusotoError<ListConferenceProvidersError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("ListConferenceProviders");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.ListConferenceProviders");
@@ -8878,6 +11803,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: ListDeviceEventsRequest,
     ) -> Result<ListDeviceEventsResponse, RusotoError<ListDeviceEventsError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("ListDeviceEvents");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.ListDeviceEvents");
@@ -8906,6 +11832,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: ListGatewayGroupsRequest,
     ) -> Result<ListGatewayGroupsResponse, RusotoError<ListGatewayGroupsError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("ListGatewayGroups");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.ListGatewayGroups");
@@ -8934,6 +11861,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: ListGatewaysRequest,
     ) -> Result<ListGatewaysResponse, RusotoError<ListGatewaysError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("ListGateways");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.ListGateways");
@@ -8961,6 +11889,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: ListSkillsRequest,
     ) -> Result<ListSkillsResponse, RusotoError<ListSkillsError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("ListSkills");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.ListSkills");
@@ -8989,6 +11918,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
     ) -> Result<ListSkillsStoreCategoriesResponse, RusotoError<ListSkillsStoreCategoriesError>>
     {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("ListSkillsStoreCategories");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.ListSkillsStoreCategories");
@@ -9020,6 +11950,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         RusotoError<ListSkillsStoreSkillsByCategoryError>,
     > {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("ListSkillsStoreSkillsByCategory");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9053,6 +11984,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: ListSmartHomeAppliancesRequest,
     ) -> Result<ListSmartHomeAppliancesResponse, RusotoError<ListSmartHomeAppliancesError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("ListSmartHomeAppliances");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.ListSmartHomeAppliances");
@@ -9081,6 +12013,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: ListTagsRequest,
     ) -> Result<ListTagsResponse, RusotoError<ListTagsError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("ListTags");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.ListTags");
@@ -9108,6 +12041,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: PutConferencePreferenceRequest,
     ) -> Result<PutConferencePreferenceResponse, RusotoError<PutConferencePreferenceError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("PutConferencePreference");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.PutConferencePreference");
@@ -9137,6 +12071,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
     ) -> Result<PutInvitationConfigurationResponse, RusotoError<PutInvitationConfigurationError>>
     {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("PutInvitationConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9168,6 +12103,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: PutRoomSkillParameterRequest,
     ) -> Result<PutRoomSkillParameterResponse, RusotoError<PutRoomSkillParameterError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("PutRoomSkillParameter");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.PutRoomSkillParameter");
@@ -9196,6 +12132,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: PutSkillAuthorizationRequest,
     ) -> Result<PutSkillAuthorizationResponse, RusotoError<PutSkillAuthorizationError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("PutSkillAuthorization");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.PutSkillAuthorization");
@@ -9224,6 +12161,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: RegisterAVSDeviceRequest,
     ) -> Result<RegisterAVSDeviceResponse, RusotoError<RegisterAVSDeviceError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("RegisterAVSDevice");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.RegisterAVSDevice");
@@ -9252,6 +12190,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: RejectSkillRequest,
     ) -> Result<RejectSkillResponse, RusotoError<RejectSkillError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("RejectSkill");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.RejectSkill");
@@ -9279,6 +12218,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: ResolveRoomRequest,
     ) -> Result<ResolveRoomResponse, RusotoError<ResolveRoomError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("ResolveRoom");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.ResolveRoom");
@@ -9306,6 +12246,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: RevokeInvitationRequest,
     ) -> Result<RevokeInvitationResponse, RusotoError<RevokeInvitationError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("RevokeInvitation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.RevokeInvitation");
@@ -9334,6 +12275,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: SearchAddressBooksRequest,
     ) -> Result<SearchAddressBooksResponse, RusotoError<SearchAddressBooksError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("SearchAddressBooks");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.SearchAddressBooks");
@@ -9362,6 +12304,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: SearchContactsRequest,
     ) -> Result<SearchContactsResponse, RusotoError<SearchContactsError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("SearchContacts");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.SearchContacts");
@@ -9389,6 +12332,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: SearchDevicesRequest,
     ) -> Result<SearchDevicesResponse, RusotoError<SearchDevicesError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("SearchDevices");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.SearchDevices");
@@ -9416,6 +12360,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: SearchNetworkProfilesRequest,
     ) -> Result<SearchNetworkProfilesResponse, RusotoError<SearchNetworkProfilesError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("SearchNetworkProfiles");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.SearchNetworkProfiles");
@@ -9444,6 +12389,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: SearchProfilesRequest,
     ) -> Result<SearchProfilesResponse, RusotoError<SearchProfilesError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("SearchProfiles");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.SearchProfiles");
@@ -9471,6 +12417,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: SearchRoomsRequest,
     ) -> Result<SearchRoomsResponse, RusotoError<SearchRoomsError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("SearchRooms");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.SearchRooms");
@@ -9498,6 +12445,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: SearchSkillGroupsRequest,
     ) -> Result<SearchSkillGroupsResponse, RusotoError<SearchSkillGroupsError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("SearchSkillGroups");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.SearchSkillGroups");
@@ -9526,6 +12474,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: SearchUsersRequest,
     ) -> Result<SearchUsersResponse, RusotoError<SearchUsersError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("SearchUsers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.SearchUsers");
@@ -9553,6 +12502,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: SendAnnouncementRequest,
     ) -> Result<SendAnnouncementResponse, RusotoError<SendAnnouncementError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("SendAnnouncement");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.SendAnnouncement");
@@ -9581,6 +12531,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: SendInvitationRequest,
     ) -> Result<SendInvitationResponse, RusotoError<SendInvitationError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("SendInvitation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.SendInvitation");
@@ -9608,6 +12559,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: StartDeviceSyncRequest,
     ) -> Result<StartDeviceSyncResponse, RusotoError<StartDeviceSyncError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("StartDeviceSync");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.StartDeviceSync");
@@ -9638,6 +12590,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         RusotoError<StartSmartHomeApplianceDiscoveryError>,
     > {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("StartSmartHomeApplianceDiscovery");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9671,6 +12624,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.TagResource");
@@ -9698,6 +12652,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.UntagResource");
@@ -9725,6 +12680,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: UpdateAddressBookRequest,
     ) -> Result<UpdateAddressBookResponse, RusotoError<UpdateAddressBookError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("UpdateAddressBook");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.UpdateAddressBook");
@@ -9754,6 +12710,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
     ) -> Result<UpdateBusinessReportScheduleResponse, RusotoError<UpdateBusinessReportScheduleError>>
     {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("UpdateBusinessReportSchedule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9785,6 +12742,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: UpdateConferenceProviderRequest,
     ) -> Result<UpdateConferenceProviderResponse, RusotoError<UpdateConferenceProviderError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("UpdateConferenceProvider");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.UpdateConferenceProvider");
@@ -9813,6 +12771,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: UpdateContactRequest,
     ) -> Result<UpdateContactResponse, RusotoError<UpdateContactError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("UpdateContact");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.UpdateContact");
@@ -9840,6 +12799,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: UpdateDeviceRequest,
     ) -> Result<UpdateDeviceResponse, RusotoError<UpdateDeviceError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("UpdateDevice");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.UpdateDevice");
@@ -9867,6 +12827,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: UpdateGatewayRequest,
     ) -> Result<UpdateGatewayResponse, RusotoError<UpdateGatewayError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("UpdateGateway");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.UpdateGateway");
@@ -9894,6 +12855,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: UpdateGatewayGroupRequest,
     ) -> Result<UpdateGatewayGroupResponse, RusotoError<UpdateGatewayGroupError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("UpdateGatewayGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.UpdateGatewayGroup");
@@ -9922,6 +12884,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: UpdateNetworkProfileRequest,
     ) -> Result<UpdateNetworkProfileResponse, RusotoError<UpdateNetworkProfileError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("UpdateNetworkProfile");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.UpdateNetworkProfile");
@@ -9950,6 +12913,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: UpdateProfileRequest,
     ) -> Result<UpdateProfileResponse, RusotoError<UpdateProfileError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("UpdateProfile");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.UpdateProfile");
@@ -9977,6 +12941,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: UpdateRoomRequest,
     ) -> Result<UpdateRoomResponse, RusotoError<UpdateRoomError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("UpdateRoom");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.UpdateRoom");
@@ -10004,6 +12969,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: UpdateSkillGroupRequest,
     ) -> Result<UpdateSkillGroupResponse, RusotoError<UpdateSkillGroupError>> {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("UpdateSkillGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.UpdateSkillGroup");
@@ -10026,3 +12992,441 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         }
     }
 }
+impl AlexaForBusinessClient {
+    /// Auto-paginating version of `list_business_report_schedules`, streaming every page of results.
+    pub fn list_business_report_schedules_pages(
+        &self,
+        input: ListBusinessReportSchedulesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListBusinessReportSchedulesResponse,
+        ListBusinessReportSchedulesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AlexaForBusinessClient, input: ListBusinessReportSchedulesRequest| async move {
+                client.list_business_report_schedules(input).await
+            },
+            |input: &ListBusinessReportSchedulesRequest,
+             output: &ListBusinessReportSchedulesResponse| {
+                Some(ListBusinessReportSchedulesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_business_report_schedules`, streaming the `BusinessReportSchedules` of every page of results.
+    pub fn list_business_report_schedules_items(
+        &self,
+        input: ListBusinessReportSchedulesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        BusinessReportSchedule,
+        ListBusinessReportSchedulesError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_business_report_schedules_pages(input),
+            |output: ListBusinessReportSchedulesResponse| {
+                output.business_report_schedules.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_conference_providers`, streaming every page of results.
+    pub fn list_conference_providers_pages(
+        &self,
+        input: ListConferenceProvidersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListConferenceProvidersResponse,
+        ListConferenceProvidersError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AlexaForBusinessClient, input: ListConferenceProvidersRequest| async move {
+                client.list_conference_providers(input).await
+            },
+            |input: &ListConferenceProvidersRequest, output: &ListConferenceProvidersResponse| {
+                Some(ListConferenceProvidersRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_conference_providers`, streaming the `ConferenceProviders` of every page of results.
+    pub fn list_conference_providers_items(
+        &self,
+        input: ListConferenceProvidersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ConferenceProvider, ListConferenceProvidersError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_conference_providers_pages(input),
+            |output: ListConferenceProvidersResponse| {
+                output.conference_providers.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_device_events`, streaming every page of results.
+    pub fn list_device_events_pages(
+        &self,
+        input: ListDeviceEventsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListDeviceEventsResponse, ListDeviceEventsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AlexaForBusinessClient, input: ListDeviceEventsRequest| async move {
+                client.list_device_events(input).await
+            },
+            |input: &ListDeviceEventsRequest, output: &ListDeviceEventsResponse| {
+                Some(ListDeviceEventsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_device_events`, streaming the `DeviceEvents` of every page of results.
+    pub fn list_device_events_items(
+        &self,
+        input: ListDeviceEventsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DeviceEvent, ListDeviceEventsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_device_events_pages(input),
+            |output: ListDeviceEventsResponse| output.device_events.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_skills`, streaming every page of results.
+    pub fn list_skills_pages(
+        &self,
+        input: ListSkillsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListSkillsResponse, ListSkillsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AlexaForBusinessClient, input: ListSkillsRequest| async move {
+                client.list_skills(input).await
+            },
+            |input: &ListSkillsRequest, output: &ListSkillsResponse| {
+                Some(ListSkillsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_skills`, streaming the `SkillSummaries` of every page of results.
+    pub fn list_skills_items(
+        &self,
+        input: ListSkillsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<SkillSummary, ListSkillsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_skills_pages(input),
+            |output: ListSkillsResponse| output.skill_summaries.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_skills_store_categories`, streaming every page of results.
+    pub fn list_skills_store_categories_pages(
+        &self,
+        input: ListSkillsStoreCategoriesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListSkillsStoreCategoriesResponse,
+        ListSkillsStoreCategoriesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AlexaForBusinessClient, input: ListSkillsStoreCategoriesRequest| async move {
+                client.list_skills_store_categories(input).await
+            },
+            |input: &ListSkillsStoreCategoriesRequest,
+             output: &ListSkillsStoreCategoriesResponse| {
+                Some(ListSkillsStoreCategoriesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_skills_store_categories`, streaming the `CategoryList` of every page of results.
+    pub fn list_skills_store_categories_items(
+        &self,
+        input: ListSkillsStoreCategoriesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Category, ListSkillsStoreCategoriesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_skills_store_categories_pages(input),
+            |output: ListSkillsStoreCategoriesResponse| output.category_list.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_skills_store_skills_by_category`, streaming every page of results.
+    pub fn list_skills_store_skills_by_category_pages(
+        &self,
+        input: ListSkillsStoreSkillsByCategoryRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListSkillsStoreSkillsByCategoryResponse,
+        ListSkillsStoreSkillsByCategoryError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AlexaForBusinessClient, input: ListSkillsStoreSkillsByCategoryRequest| async move {
+                client.list_skills_store_skills_by_category(input).await
+            },
+            |input: &ListSkillsStoreSkillsByCategoryRequest,
+             output: &ListSkillsStoreSkillsByCategoryResponse| {
+                Some(ListSkillsStoreSkillsByCategoryRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_skills_store_skills_by_category`, streaming the `SkillsStoreSkills` of every page of results.
+    pub fn list_skills_store_skills_by_category_items(
+        &self,
+        input: ListSkillsStoreSkillsByCategoryRequest,
+    ) -> rusoto_core::pagination::RusotoStream<SkillsStoreSkill, ListSkillsStoreSkillsByCategoryError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_skills_store_skills_by_category_pages(input),
+            |output: ListSkillsStoreSkillsByCategoryResponse| {
+                output.skills_store_skills.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_smart_home_appliances`, streaming every page of results.
+    pub fn list_smart_home_appliances_pages(
+        &self,
+        input: ListSmartHomeAppliancesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListSmartHomeAppliancesResponse,
+        ListSmartHomeAppliancesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AlexaForBusinessClient, input: ListSmartHomeAppliancesRequest| async move {
+                client.list_smart_home_appliances(input).await
+            },
+            |input: &ListSmartHomeAppliancesRequest, output: &ListSmartHomeAppliancesResponse| {
+                Some(ListSmartHomeAppliancesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_smart_home_appliances`, streaming the `SmartHomeAppliances` of every page of results.
+    pub fn list_smart_home_appliances_items(
+        &self,
+        input: ListSmartHomeAppliancesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<SmartHomeAppliance, ListSmartHomeAppliancesError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_smart_home_appliances_pages(input),
+            |output: ListSmartHomeAppliancesResponse| {
+                output.smart_home_appliances.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_tags`, streaming every page of results.
+    pub fn list_tags_pages(
+        &self,
+        input: ListTagsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListTagsResponse, ListTagsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AlexaForBusinessClient, input: ListTagsRequest| async move {
+                client.list_tags(input).await
+            },
+            |input: &ListTagsRequest, output: &ListTagsResponse| {
+                Some(ListTagsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_tags`, streaming the `Tags` of every page of results.
+    pub fn list_tags_items(
+        &self,
+        input: ListTagsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Tag, ListTagsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_tags_pages(input),
+            |output: ListTagsResponse| output.tags.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `search_devices`, streaming every page of results.
+    pub fn search_devices_pages(
+        &self,
+        input: SearchDevicesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<SearchDevicesResponse, SearchDevicesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AlexaForBusinessClient, input: SearchDevicesRequest| async move {
+                client.search_devices(input).await
+            },
+            |input: &SearchDevicesRequest, output: &SearchDevicesResponse| {
+                Some(SearchDevicesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `search_devices`, streaming the `Devices` of every page of results.
+    pub fn search_devices_items(
+        &self,
+        input: SearchDevicesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DeviceData, SearchDevicesError> {
+        rusoto_core::pagination::paginate_items(
+            self.search_devices_pages(input),
+            |output: SearchDevicesResponse| output.devices.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `search_profiles`, streaming every page of results.
+    pub fn search_profiles_pages(
+        &self,
+        input: SearchProfilesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<SearchProfilesResponse, SearchProfilesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AlexaForBusinessClient, input: SearchProfilesRequest| async move {
+                client.search_profiles(input).await
+            },
+            |input: &SearchProfilesRequest, output: &SearchProfilesResponse| {
+                Some(SearchProfilesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `search_profiles`, streaming the `Profiles` of every page of results.
+    pub fn search_profiles_items(
+        &self,
+        input: SearchProfilesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ProfileData, SearchProfilesError> {
+        rusoto_core::pagination::paginate_items(
+            self.search_profiles_pages(input),
+            |output: SearchProfilesResponse| output.profiles.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `search_rooms`, streaming every page of results.
+    pub fn search_rooms_pages(
+        &self,
+        input: SearchRoomsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<SearchRoomsResponse, SearchRoomsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AlexaForBusinessClient, input: SearchRoomsRequest| async move {
+                client.search_rooms(input).await
+            },
+            |input: &SearchRoomsRequest, output: &SearchRoomsResponse| {
+                Some(SearchRoomsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `search_rooms`, streaming the `Rooms` of every page of results.
+    pub fn search_rooms_items(
+        &self,
+        input: SearchRoomsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<RoomData, SearchRoomsError> {
+        rusoto_core::pagination::paginate_items(
+            self.search_rooms_pages(input),
+            |output: SearchRoomsResponse| output.rooms.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `search_skill_groups`, streaming every page of results.
+    pub fn search_skill_groups_pages(
+        &self,
+        input: SearchSkillGroupsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<SearchSkillGroupsResponse, SearchSkillGroupsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AlexaForBusinessClient, input: SearchSkillGroupsRequest| async move {
+                client.search_skill_groups(input).await
+            },
+            |input: &SearchSkillGroupsRequest, output: &SearchSkillGroupsResponse| {
+                Some(SearchSkillGroupsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `search_skill_groups`, streaming the `SkillGroups` of every page of results.
+    pub fn search_skill_groups_items(
+        &self,
+        input: SearchSkillGroupsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<SkillGroupData, SearchSkillGroupsError> {
+        rusoto_core::pagination::paginate_items(
+            self.search_skill_groups_pages(input),
+            |output: SearchSkillGroupsResponse| output.skill_groups.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `search_users`, streaming every page of results.
+    pub fn search_users_pages(
+        &self,
+        input: SearchUsersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<SearchUsersResponse, SearchUsersError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AlexaForBusinessClient, input: SearchUsersRequest| async move {
+                client.search_users(input).await
+            },
+            |input: &SearchUsersRequest, output: &SearchUsersResponse| {
+                Some(SearchUsersRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `search_users`, streaming the `Users` of every page of results.
+    pub fn search_users_items(
+        &self,
+        input: SearchUsersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<UserData, SearchUsersError> {
+        rusoto_core::pagination::paginate_items(
+            self.search_users_pages(input),
+            |output: SearchUsersResponse| output.users.unwrap_or_default(),
+        )
+    }
+}
@@ -53,7 +53,12 @@ pub struct App {
     pub build_spec: Option<String>,
     /// <p> Create date / time for the Amplify App. </p>
     #[serde(rename = "createTime")]
-    pub create_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub create_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p> Custom redirect / rewrite rules for the Amplify App. </p>
     #[serde(rename = "customRules")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -86,7 +91,7 @@ pub struct App {
     pub name: String,
     /// <p> Platform for the Amplify App. </p>
     #[serde(rename = "platform")]
-    pub platform: String,
+    pub platform: Platform,
     /// <p> Structure with Production Branch information. </p>
     #[serde(rename = "productionBranch")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -100,7 +105,12 @@ pub struct App {
     pub tags: Option<::std::collections::HashMap<String, String>>,
     /// <p> Update date / time for the Amplify App. </p>
     #[serde(rename = "updateTime")]
-    pub update_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub update_time: ::chrono::DateTime<::chrono::Utc>,
 }
 
 /// <p> Structure for artifact. </p>
@@ -153,7 +163,7 @@ pub struct AutoBranchCreationConfig {
     /// <p> Stage for the auto created branch. </p>
     #[serde(rename = "stage")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stage: Option<String>,
+    pub stage: Option<Stage>,
 }
 
 /// <p> Backend environment for an Amplify App. </p>
@@ -165,7 +175,12 @@ pub struct BackendEnvironment {
     pub backend_environment_arn: String,
     /// <p> Creation date and time for a backend environment, part of an Amplify App. </p>
     #[serde(rename = "createTime")]
-    pub create_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub create_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p> Name of deployment artifacts. </p>
     #[serde(rename = "deploymentArtifacts")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -179,7 +194,12 @@ pub struct BackendEnvironment {
     pub stack_name: Option<String>,
     /// <p> Last updated date and time for a backend environment, part of an Amplify App. </p>
     #[serde(rename = "updateTime")]
-    pub update_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub update_time: ::chrono::DateTime<::chrono::Utc>,
 }
 
 /// <p> Branch for an Amplify App, which maps to a 3rd party repository branch. </p>
@@ -213,7 +233,12 @@ pub struct Branch {
     pub build_spec: Option<String>,
     /// <p> Creation date and time for a branch, part of an Amplify App. </p>
     #[serde(rename = "createTime")]
-    pub create_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub create_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p> Custom domains for a branch, part of an Amplify App. </p>
     #[serde(rename = "customDomains")]
     pub custom_domains: Vec<String>,
@@ -255,7 +280,7 @@ pub struct Branch {
     pub source_branch: Option<String>,
     /// <p> Stage for a branch, part of an Amplify App. </p>
     #[serde(rename = "stage")]
-    pub stage: String,
+    pub stage: Stage,
     /// <p> Tag for branch for Amplify App. </p>
     #[serde(rename = "tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -272,7 +297,12 @@ pub struct Branch {
     pub ttl: String,
     /// <p> Last updated date and time for a branch, part of an Amplify App. </p>
     #[serde(rename = "updateTime")]
-    pub update_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub update_time: ::chrono::DateTime<::chrono::Utc>,
 }
 
 /// <p> Request structure used to create Apps in Amplify. </p>
@@ -337,7 +367,7 @@ pub struct CreateAppRequest {
     /// <p> Platform / framework for an Amplify App </p>
     #[serde(rename = "platform")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub platform: Option<String>,
+    pub platform: Option<Platform>,
     /// <p> Repository for an Amplify App </p>
     #[serde(rename = "repository")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -445,7 +475,7 @@ pub struct CreateBranchRequest {
     /// <p> Stage for the branch. </p>
     #[serde(rename = "stage")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stage: Option<String>,
+    pub stage: Option<Stage>,
     /// <p> Tag for the branch. </p>
     #[serde(rename = "tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -704,7 +734,7 @@ pub struct DomainAssociation {
     pub domain_name: String,
     /// <p> Status fo the Domain Association. </p>
     #[serde(rename = "domainStatus")]
-    pub domain_status: String,
+    pub domain_status: DomainStatus,
     /// <p> Enables automated creation of Subdomains for branches. (Currently not supported) </p>
     #[serde(rename = "enableAutoSubDomain")]
     pub enable_auto_sub_domain: bool,
@@ -716,6 +746,129 @@ pub struct DomainAssociation {
     pub sub_domains: Vec<SubDomain>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DomainStatus {
+    /// `PENDING_VERIFICATION`
+    PendingVerification,
+    /// `IN_PROGRESS`
+    InProgress,
+    /// `AVAILABLE`
+    Available,
+    /// `PENDING_DEPLOYMENT`
+    PendingDeployment,
+    /// `FAILED`
+    Failed,
+    /// `CREATING`
+    Creating,
+    /// `REQUESTING_CERTIFICATE`
+    RequestingCertificate,
+    /// `UPDATING`
+    Updating,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DomainStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DomainStatus::PendingVerification => "PENDING_VERIFICATION",
+            DomainStatus::InProgress => "IN_PROGRESS",
+            DomainStatus::Available => "AVAILABLE",
+            DomainStatus::PendingDeployment => "PENDING_DEPLOYMENT",
+            DomainStatus::Failed => "FAILED",
+            DomainStatus::Creating => "CREATING",
+            DomainStatus::RequestingCertificate => "REQUESTING_CERTIFICATE",
+            DomainStatus::Updating => "UPDATING",
+            DomainStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DomainStatus {
+    fn default() -> DomainStatus {
+        DomainStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DomainStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DomainStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DomainStatus {
+    fn from(value: &'a str) -> DomainStatus {
+        match value {
+            "PENDING_VERIFICATION" => DomainStatus::PendingVerification,
+            "IN_PROGRESS" => DomainStatus::InProgress,
+            "AVAILABLE" => DomainStatus::Available,
+            "PENDING_DEPLOYMENT" => DomainStatus::PendingDeployment,
+            "FAILED" => DomainStatus::Failed,
+            "CREATING" => DomainStatus::Creating,
+            "REQUESTING_CERTIFICATE" => DomainStatus::RequestingCertificate,
+            "UPDATING" => DomainStatus::Updating,
+            _ => DomainStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DomainStatus {
+    fn from(value: String) -> DomainStatus {
+        match value.as_str() {
+            "PENDING_VERIFICATION" => DomainStatus::PendingVerification,
+            "IN_PROGRESS" => DomainStatus::InProgress,
+            "AVAILABLE" => DomainStatus::Available,
+            "PENDING_DEPLOYMENT" => DomainStatus::PendingDeployment,
+            "FAILED" => DomainStatus::Failed,
+            "CREATING" => DomainStatus::Creating,
+            "REQUESTING_CERTIFICATE" => DomainStatus::RequestingCertificate,
+            "UPDATING" => DomainStatus::Updating,
+            _ => DomainStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DomainStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DomainStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DomainStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DomainStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DomainStatus {
+    fn deserialize<D>(deserializer: D) -> Result<DomainStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p> Request structure for the generate access logs request. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -728,12 +881,22 @@ pub struct GenerateAccessLogsRequest {
     pub domain_name: String,
     /// <p> The time at which the logs should end, inclusive. </p>
     #[serde(rename = "endTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p> The time at which the logs should start, inclusive. </p>
     #[serde(rename = "startTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p> Result structure for the generate access logs request. </p>
@@ -896,6 +1059,124 @@ pub struct Job {
     pub summary: JobSummary,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum JobStatus {
+    /// `PENDING`
+    Pending,
+    /// `PROVISIONING`
+    Provisioning,
+    /// `RUNNING`
+    Running,
+    /// `FAILED`
+    Failed,
+    /// `SUCCEED`
+    Succeed,
+    /// `CANCELLING`
+    Cancelling,
+    /// `CANCELLED`
+    Cancelled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl JobStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            JobStatus::Pending => "PENDING",
+            JobStatus::Provisioning => "PROVISIONING",
+            JobStatus::Running => "RUNNING",
+            JobStatus::Failed => "FAILED",
+            JobStatus::Succeed => "SUCCEED",
+            JobStatus::Cancelling => "CANCELLING",
+            JobStatus::Cancelled => "CANCELLED",
+            JobStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for JobStatus {
+    fn default() -> JobStatus {
+        JobStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for JobStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for JobStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for JobStatus {
+    fn from(value: &'a str) -> JobStatus {
+        match value {
+            "PENDING" => JobStatus::Pending,
+            "PROVISIONING" => JobStatus::Provisioning,
+            "RUNNING" => JobStatus::Running,
+            "FAILED" => JobStatus::Failed,
+            "SUCCEED" => JobStatus::Succeed,
+            "CANCELLING" => JobStatus::Cancelling,
+            "CANCELLED" => JobStatus::Cancelled,
+            _ => JobStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for JobStatus {
+    fn from(value: String) -> JobStatus {
+        match value.as_str() {
+            "PENDING" => JobStatus::Pending,
+            "PROVISIONING" => JobStatus::Provisioning,
+            "RUNNING" => JobStatus::Running,
+            "FAILED" => JobStatus::Failed,
+            "SUCCEED" => JobStatus::Succeed,
+            "CANCELLING" => JobStatus::Cancelling,
+            "CANCELLED" => JobStatus::Cancelled,
+            _ => JobStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for JobStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<JobStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for JobStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for JobStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for JobStatus {
+    fn deserialize<D>(deserializer: D) -> Result<JobStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p> Structure for the summary of a Job. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -908,11 +1189,21 @@ pub struct JobSummary {
     pub commit_message: String,
     /// <p> Commit date / time for the Job. </p>
     #[serde(rename = "commitTime")]
-    pub commit_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub commit_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p> End date / time for the Job. </p>
     #[serde(rename = "endTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p> Arn for the Job. </p>
     #[serde(rename = "jobArn")]
     pub job_arn: String,
@@ -921,15 +1212,123 @@ pub struct JobSummary {
     pub job_id: String,
     /// <p> Type for the Job. \n "RELEASE": Manually released from source by using StartJob API. "RETRY": Manually retried by using StartJob API. "WEB_HOOK": Automatically triggered by WebHooks. </p>
     #[serde(rename = "jobType")]
-    pub job_type: String,
+    pub job_type: JobType,
     /// <p> Start date / time for the Job. </p>
     #[serde(rename = "startTime")]
-    pub start_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub start_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p> Status for the Job. </p>
     #[serde(rename = "status")]
-    pub status: String,
+    pub status: JobStatus,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum JobType {
+    /// `RELEASE`
+    Release,
+    /// `RETRY`
+    Retry,
+    /// `MANUAL`
+    Manual,
+    /// `WEB_HOOK`
+    WebHook,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl JobType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            JobType::Release => "RELEASE",
+            JobType::Retry => "RETRY",
+            JobType::Manual => "MANUAL",
+            JobType::WebHook => "WEB_HOOK",
+            JobType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for JobType {
+    fn default() -> JobType {
+        JobType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for JobType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
 }
 
+impl fmt::Display for JobType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for JobType {
+    fn from(value: &'a str) -> JobType {
+        match value {
+            "RELEASE" => JobType::Release,
+            "RETRY" => JobType::Retry,
+            "MANUAL" => JobType::Manual,
+            "WEB_HOOK" => JobType::WebHook,
+            _ => JobType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for JobType {
+    fn from(value: String) -> JobType {
+        match value.as_str() {
+            "RELEASE" => JobType::Release,
+            "RETRY" => JobType::Retry,
+            "MANUAL" => JobType::Manual,
+            "WEB_HOOK" => JobType::WebHook,
+            _ => JobType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for JobType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<JobType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for JobType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for JobType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for JobType {
+    fn deserialize<D>(deserializer: D) -> Result<JobType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p> Request structure for an Amplify App list request. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -1169,6 +1568,104 @@ pub struct ListWebhooksResult {
     pub webhooks: Vec<Webhook>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Platform {
+    /// `WEB`
+    Web,
+    /// `WEB_DYNAMIC`
+    WebDynamic,
+    /// `WEB_COMPUTE`
+    WebCompute,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Platform {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Platform::Web => "WEB",
+            Platform::WebDynamic => "WEB_DYNAMIC",
+            Platform::WebCompute => "WEB_COMPUTE",
+            Platform::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Platform {
+    fn default() -> Platform {
+        Platform::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Platform {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Platform {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Platform {
+    fn from(value: &'a str) -> Platform {
+        match value {
+            "WEB" => Platform::Web,
+            "WEB_DYNAMIC" => Platform::WebDynamic,
+            "WEB_COMPUTE" => Platform::WebCompute,
+            _ => Platform::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Platform {
+    fn from(value: String) -> Platform {
+        match value.as_str() {
+            "WEB" => Platform::Web,
+            "WEB_DYNAMIC" => Platform::WebDynamic,
+            "WEB_COMPUTE" => Platform::WebCompute,
+            _ => Platform::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Platform {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Platform, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Platform {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Platform {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Platform {
+    fn deserialize<D>(deserializer: D) -> Result<Platform, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p> Structure with Production Branch information. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1179,8 +1676,13 @@ pub struct ProductionBranch {
     pub branch_name: Option<String>,
     /// <p> Last Deploy Time of Production Branch. </p>
     #[serde(rename = "lastDeployTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_deploy_time: Option<f64>,
+    pub last_deploy_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p> Status of Production Branch. </p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1191,6 +1693,114 @@ pub struct ProductionBranch {
     pub thumbnail_url: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Stage {
+    /// `PRODUCTION`
+    Production,
+    /// `BETA`
+    Beta,
+    /// `DEVELOPMENT`
+    Development,
+    /// `EXPERIMENTAL`
+    Experimental,
+    /// `PULL_REQUEST`
+    PullRequest,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Stage {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Stage::Production => "PRODUCTION",
+            Stage::Beta => "BETA",
+            Stage::Development => "DEVELOPMENT",
+            Stage::Experimental => "EXPERIMENTAL",
+            Stage::PullRequest => "PULL_REQUEST",
+            Stage::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Stage {
+    fn default() -> Stage {
+        Stage::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Stage {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Stage {
+    fn from(value: &'a str) -> Stage {
+        match value {
+            "PRODUCTION" => Stage::Production,
+            "BETA" => Stage::Beta,
+            "DEVELOPMENT" => Stage::Development,
+            "EXPERIMENTAL" => Stage::Experimental,
+            "PULL_REQUEST" => Stage::PullRequest,
+            _ => Stage::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Stage {
+    fn from(value: String) -> Stage {
+        match value.as_str() {
+            "PRODUCTION" => Stage::Production,
+            "BETA" => Stage::Beta,
+            "DEVELOPMENT" => Stage::Development,
+            "EXPERIMENTAL" => Stage::Experimental,
+            "PULL_REQUEST" => Stage::PullRequest,
+            _ => Stage::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Stage {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Stage, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Stage {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Stage {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Stage {
+    fn deserialize<D>(deserializer: D) -> Result<Stage, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p> Request structure for start a deployment. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -1240,8 +1850,13 @@ pub struct StartJobRequest {
     pub commit_message: Option<String>,
     /// <p> Commit date / time for the Job. </p>
     #[serde(rename = "commitTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub commit_time: Option<f64>,
+    pub commit_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p> Unique Id for an existing job. Required for "RETRY" JobType. </p>
     #[serde(rename = "jobId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1252,7 +1867,7 @@ pub struct StartJobRequest {
     pub job_reason: Option<String>,
     /// <p> Type for the Job. Available JobTypes are: \n "RELEASE": Start a new job with the latest change from the specified branch. Only available for apps that have connected to a repository. "RETRY": Retry an existing job. JobId is required for this type of job. </p>
     #[serde(rename = "jobType")]
-    pub job_type: String,
+    pub job_type: JobType,
 }
 
 /// <p> Result structure for run job request. </p>
@@ -1278,7 +1893,12 @@ pub struct Step {
     pub context: Option<String>,
     /// <p> End date/ time of the execution step. </p>
     #[serde(rename = "endTime")]
-    pub end_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub end_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p> URL to the logs for the execution step. </p>
     #[serde(rename = "logUrl")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1289,10 +1909,15 @@ pub struct Step {
     pub screenshots: Option<::std::collections::HashMap<String, String>>,
     /// <p> Start date/ time of the execution step. </p>
     #[serde(rename = "startTime")]
-    pub start_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub start_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p> Status of the execution step. </p>
     #[serde(rename = "status")]
-    pub status: String,
+    pub status: JobStatus,
     /// <p> The reason for current step status. </p>
     #[serde(rename = "statusReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1460,7 +2085,7 @@ pub struct UpdateAppRequest {
     /// <p> Platform for an Amplify App. </p>
     #[serde(rename = "platform")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub platform: Option<String>,
+    pub platform: Option<Platform>,
     /// <p> Repository for an Amplify App </p>
     #[serde(rename = "repository")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1537,7 +2162,7 @@ pub struct UpdateBranchRequest {
     /// <p> Stage for the branch. </p>
     #[serde(rename = "stage")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stage: Option<String>,
+    pub stage: Option<Stage>,
     /// <p> The content TTL for the website in seconds. </p>
     #[serde(rename = "ttl")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1616,13 +2241,23 @@ pub struct Webhook {
     pub branch_name: String,
     /// <p> Create date / time for a webhook. </p>
     #[serde(rename = "createTime")]
-    pub create_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub create_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p> Description for a webhook. </p>
     #[serde(rename = "description")]
     pub description: String,
     /// <p> Update date / time for a webhook. </p>
     #[serde(rename = "updateTime")]
-    pub update_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub update_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p> ARN for the webhook. </p>
     #[serde(rename = "webhookArn")]
     pub webhook_arn: String,
@@ -3795,11 +4430,12 @@ pub struct AmplifyClient {
 impl AmplifyClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> AmplifyClient {
         AmplifyClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Amplify"),
         }
     }
 
@@ -3833,6 +4469,7 @@ impl Amplify for AmplifyClient {
         let request_uri = "/apps";
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("CreateApp");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3863,6 +4500,7 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/apps/{app_id}/backendenvironments", app_id = input.app_id);
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("CreateBackendEnvironment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3893,6 +4531,7 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/apps/{app_id}/branches", app_id = input.app_id);
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("CreateBranch");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3927,6 +4566,7 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("CreateDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3957,6 +4597,7 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/apps/{app_id}/domains", app_id = input.app_id);
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("CreateDomainAssociation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3987,6 +4628,7 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/apps/{app_id}/webhooks", app_id = input.app_id);
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("CreateWebhook");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4017,6 +4659,7 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/apps/{app_id}", app_id = input.app_id);
 
         let mut request = SignedRequest::new("DELETE", "amplify", &self.region, &request_uri);
+        request.set_operation("DeleteApp");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4048,6 +4691,7 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "amplify", &self.region, &request_uri);
+        request.set_operation("DeleteBackendEnvironment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4079,6 +4723,7 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "amplify", &self.region, &request_uri);
+        request.set_operation("DeleteBranch");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4110,6 +4755,7 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "amplify", &self.region, &request_uri);
+        request.set_operation("DeleteDomainAssociation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4142,6 +4788,7 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "amplify", &self.region, &request_uri);
+        request.set_operation("DeleteJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4169,6 +4816,7 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/webhooks/{webhook_id}", webhook_id = input.webhook_id);
 
         let mut request = SignedRequest::new("DELETE", "amplify", &self.region, &request_uri);
+        request.set_operation("DeleteWebhook");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4196,6 +4844,7 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/apps/{app_id}/accesslogs", app_id = input.app_id);
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("GenerateAccessLogs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4226,6 +4875,7 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/apps/{app_id}", app_id = input.app_id);
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("GetApp");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4253,6 +4903,7 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/artifacts/{artifact_id}", artifact_id = input.artifact_id);
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("GetArtifactUrl");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4284,6 +4935,7 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("GetBackendEnvironment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4315,6 +4967,7 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("GetBranch");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4346,6 +4999,7 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("GetDomainAssociation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4378,6 +5032,7 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("GetJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4405,6 +5060,7 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/webhooks/{webhook_id}", webhook_id = input.webhook_id);
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("GetWebhook");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4432,6 +5088,7 @@ impl Amplify for AmplifyClient {
         let request_uri = "/apps";
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("ListApps");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4473,6 +5130,7 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("ListArtifacts");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4509,6 +5167,7 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/apps/{app_id}/backendenvironments", app_id = input.app_id);
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("ListBackendEnvironments");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4548,6 +5207,7 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/apps/{app_id}/branches", app_id = input.app_id);
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("ListBranches");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4584,6 +5244,7 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/apps/{app_id}/domains", app_id = input.app_id);
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("ListDomainAssociations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4624,6 +5285,7 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("ListJobs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4660,6 +5322,7 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4687,6 +5350,7 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/apps/{app_id}/webhooks", app_id = input.app_id);
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("ListWebhooks");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4727,6 +5391,7 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("StartDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4761,6 +5426,7 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("StartJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4796,6 +5462,7 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "amplify", &self.region, &request_uri);
+        request.set_operation("StopJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4823,6 +5490,7 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4853,6 +5521,7 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "amplify", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4886,6 +5555,7 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/apps/{app_id}", app_id = input.app_id);
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("UpdateApp");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4920,6 +5590,7 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("UpdateBranch");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4954,6 +5625,7 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("UpdateDomainAssociation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4984,6 +5656,7 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/webhooks/{webhook_id}", webhook_id = input.webhook_id);
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("UpdateWebhook");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5006,3 +5679,127 @@ impl Amplify for AmplifyClient {
         }
     }
 }
+impl AmplifyClient {
+    /// Auto-paginating version of `list_apps`, streaming every page of results.
+    pub fn list_apps_pages(
+        &self,
+        input: ListAppsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListAppsResult, ListAppsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AmplifyClient, input: ListAppsRequest| async move { client.list_apps(input).await },
+            |input: &ListAppsRequest, output: &ListAppsResult| {
+                Some(ListAppsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_apps`, streaming the `apps` of every page of results.
+    pub fn list_apps_items(
+        &self,
+        input: ListAppsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<App, ListAppsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_apps_pages(input),
+            |output: ListAppsResult| output.apps,
+        )
+    }
+
+    /// Auto-paginating version of `list_branches`, streaming every page of results.
+    pub fn list_branches_pages(
+        &self,
+        input: ListBranchesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListBranchesResult, ListBranchesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AmplifyClient, input: ListBranchesRequest| async move {
+                client.list_branches(input).await
+            },
+            |input: &ListBranchesRequest, output: &ListBranchesResult| {
+                Some(ListBranchesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_branches`, streaming the `branches` of every page of results.
+    pub fn list_branches_items(
+        &self,
+        input: ListBranchesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Branch, ListBranchesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_branches_pages(input),
+            |output: ListBranchesResult| output.branches,
+        )
+    }
+
+    /// Auto-paginating version of `list_domain_associations`, streaming every page of results.
+    pub fn list_domain_associations_pages(
+        &self,
+        input: ListDomainAssociationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListDomainAssociationsResult,
+        ListDomainAssociationsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AmplifyClient, input: ListDomainAssociationsRequest| async move {
+                client.list_domain_associations(input).await
+            },
+            |input: &ListDomainAssociationsRequest, output: &ListDomainAssociationsResult| {
+                Some(ListDomainAssociationsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_domain_associations`, streaming the `domainAssociations` of every page of results.
+    pub fn list_domain_associations_items(
+        &self,
+        input: ListDomainAssociationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DomainAssociation, ListDomainAssociationsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_domain_associations_pages(input),
+            |output: ListDomainAssociationsResult| output.domain_associations,
+        )
+    }
+
+    /// Auto-paginating version of `list_jobs`, streaming every page of results.
+    pub fn list_jobs_pages(
+        &self,
+        input: ListJobsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListJobsResult, ListJobsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AmplifyClient, input: ListJobsRequest| async move { client.list_jobs(input).await },
+            |input: &ListJobsRequest, output: &ListJobsResult| {
+                Some(ListJobsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_jobs`, streaming the `jobSummaries` of every page of results.
+    pub fn list_jobs_items(
+        &self,
+        input: ListJobsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<JobSummary, ListJobsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_jobs_pages(input),
+            |output: ListJobsResult| output.job_summaries,
+        )
+    }
+}
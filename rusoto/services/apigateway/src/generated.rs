@@ -67,8 +67,13 @@ pub struct Account {
 pub struct ApiKey {
     /// <p>The timestamp when the API Key was created.</p>
     #[serde(rename = "createdDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>An AWS Marketplace customer identifier , when integrating with the AWS SaaS Marketplace.</p>
     #[serde(rename = "customerId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -87,8 +92,13 @@ pub struct ApiKey {
     pub id: Option<String>,
     /// <p>The timestamp when the API Key was last updated.</p>
     #[serde(rename = "lastUpdatedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date: Option<f64>,
+    pub last_updated_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the API Key.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -121,6 +131,99 @@ pub struct ApiKeyIds {
     pub warnings: Option<Vec<String>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ApiKeySourceType {
+    /// `HEADER`
+    Header,
+    /// `AUTHORIZER`
+    Authorizer,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ApiKeySourceType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ApiKeySourceType::Header => "HEADER",
+            ApiKeySourceType::Authorizer => "AUTHORIZER",
+            ApiKeySourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ApiKeySourceType {
+    fn default() -> ApiKeySourceType {
+        ApiKeySourceType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ApiKeySourceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ApiKeySourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ApiKeySourceType {
+    fn from(value: &'a str) -> ApiKeySourceType {
+        match value {
+            "HEADER" => ApiKeySourceType::Header,
+            "AUTHORIZER" => ApiKeySourceType::Authorizer,
+            _ => ApiKeySourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ApiKeySourceType {
+    fn from(value: String) -> ApiKeySourceType {
+        match value.as_str() {
+            "HEADER" => ApiKeySourceType::Header,
+            "AUTHORIZER" => ApiKeySourceType::Authorizer,
+            _ => ApiKeySourceType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ApiKeySourceType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ApiKeySourceType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ApiKeySourceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ApiKeySourceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ApiKeySourceType {
+    fn deserialize<D>(deserializer: D) -> Result<ApiKeySourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p><p>Represents a collection of API keys as represented by an <a>ApiKeys</a> resource.</p> <div class="seeAlso"> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/how-to-api-keys.html">Use API Keys</a> </div></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -138,6 +241,94 @@ pub struct ApiKeys {
     pub warnings: Option<Vec<String>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ApiKeysFormat {
+    /// `csv`
+    Csv,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ApiKeysFormat {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ApiKeysFormat::Csv => "csv",
+            ApiKeysFormat::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ApiKeysFormat {
+    fn default() -> ApiKeysFormat {
+        ApiKeysFormat::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ApiKeysFormat {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ApiKeysFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ApiKeysFormat {
+    fn from(value: &'a str) -> ApiKeysFormat {
+        match value {
+            "csv" => ApiKeysFormat::Csv,
+            _ => ApiKeysFormat::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ApiKeysFormat {
+    fn from(value: String) -> ApiKeysFormat {
+        match value.as_str() {
+            "csv" => ApiKeysFormat::Csv,
+            _ => ApiKeysFormat::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ApiKeysFormat {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ApiKeysFormat, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ApiKeysFormat {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ApiKeysFormat {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ApiKeysFormat {
+    fn deserialize<D>(deserializer: D) -> Result<ApiKeysFormat, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>API stage name of the associated API stage in a usage plan.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ApiStage {
@@ -198,9 +389,108 @@ pub struct Authorizer {
     /// <p>The authorizer type. Valid values are <code>TOKEN</code> for a Lambda function using a single authorization token submitted in a custom header, <code>REQUEST</code> for a Lambda function using incoming request parameters, and <code>COGNITO_USER_POOLS</code> for using an Amazon Cognito user pool.</p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<AuthorizerType>,
+}
+
+/// <p>The authorizer type. Valid values are <code>TOKEN</code> for a Lambda function using a single authorization token submitted in a custom header, <code>REQUEST</code> for a Lambda function using incoming request parameters, and <code>COGNITO_USER_POOLS</code> for using an Amazon Cognito user pool.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AuthorizerType {
+    /// `TOKEN`
+    Token,
+    /// `REQUEST`
+    Request,
+    /// `COGNITO_USER_POOLS`
+    CognitoUserPools,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AuthorizerType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AuthorizerType::Token => "TOKEN",
+            AuthorizerType::Request => "REQUEST",
+            AuthorizerType::CognitoUserPools => "COGNITO_USER_POOLS",
+            AuthorizerType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AuthorizerType {
+    fn default() -> AuthorizerType {
+        AuthorizerType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AuthorizerType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AuthorizerType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AuthorizerType {
+    fn from(value: &'a str) -> AuthorizerType {
+        match value {
+            "TOKEN" => AuthorizerType::Token,
+            "REQUEST" => AuthorizerType::Request,
+            "COGNITO_USER_POOLS" => AuthorizerType::CognitoUserPools,
+            _ => AuthorizerType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AuthorizerType {
+    fn from(value: String) -> AuthorizerType {
+        match value.as_str() {
+            "TOKEN" => AuthorizerType::Token,
+            "REQUEST" => AuthorizerType::Request,
+            "COGNITO_USER_POOLS" => AuthorizerType::CognitoUserPools,
+            _ => AuthorizerType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AuthorizerType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AuthorizerType, Self::Err> {
+        Ok(value.into())
+    }
 }
 
+impl ::rusoto_core::param::ToParam for AuthorizerType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AuthorizerType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AuthorizerType {
+    fn deserialize<D>(deserializer: D) -> Result<AuthorizerType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p><p>Represents a collection of <a>Authorizer</a> resources.</p> <div class="seeAlso"> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/apigateway-use-lambda-authorizer.html">Use Lambda Function as Authorizer</a> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/apigateway-integrate-with-cognito.html">Use Cognito User Pool as Authorizer</a> </div></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -245,6 +535,239 @@ pub struct BasePathMappings {
     pub position: Option<String>,
 }
 
+/// <p>Returns the size of the CacheCluster.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CacheClusterSize {
+    /// `0.5`
+    V0_5,
+    /// `1.6`
+    V1_6,
+    /// `6.1`
+    V6_1,
+    /// `13.5`
+    V13_5,
+    /// `28.4`
+    V28_4,
+    /// `58.2`
+    V58_2,
+    /// `118`
+    V118,
+    /// `237`
+    V237,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CacheClusterSize {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CacheClusterSize::V0_5 => "0.5",
+            CacheClusterSize::V1_6 => "1.6",
+            CacheClusterSize::V6_1 => "6.1",
+            CacheClusterSize::V13_5 => "13.5",
+            CacheClusterSize::V28_4 => "28.4",
+            CacheClusterSize::V58_2 => "58.2",
+            CacheClusterSize::V118 => "118",
+            CacheClusterSize::V237 => "237",
+            CacheClusterSize::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CacheClusterSize {
+    fn default() -> CacheClusterSize {
+        CacheClusterSize::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CacheClusterSize {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CacheClusterSize {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CacheClusterSize {
+    fn from(value: &'a str) -> CacheClusterSize {
+        match value {
+            "0.5" => CacheClusterSize::V0_5,
+            "1.6" => CacheClusterSize::V1_6,
+            "6.1" => CacheClusterSize::V6_1,
+            "13.5" => CacheClusterSize::V13_5,
+            "28.4" => CacheClusterSize::V28_4,
+            "58.2" => CacheClusterSize::V58_2,
+            "118" => CacheClusterSize::V118,
+            "237" => CacheClusterSize::V237,
+            _ => CacheClusterSize::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CacheClusterSize {
+    fn from(value: String) -> CacheClusterSize {
+        match value.as_str() {
+            "0.5" => CacheClusterSize::V0_5,
+            "1.6" => CacheClusterSize::V1_6,
+            "6.1" => CacheClusterSize::V6_1,
+            "13.5" => CacheClusterSize::V13_5,
+            "28.4" => CacheClusterSize::V28_4,
+            "58.2" => CacheClusterSize::V58_2,
+            "118" => CacheClusterSize::V118,
+            "237" => CacheClusterSize::V237,
+            _ => CacheClusterSize::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CacheClusterSize {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CacheClusterSize, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CacheClusterSize {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CacheClusterSize {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CacheClusterSize {
+    fn deserialize<D>(deserializer: D) -> Result<CacheClusterSize, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Returns the status of the CacheCluster.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CacheClusterStatus {
+    /// `CREATE_IN_PROGRESS`
+    CreateInProgress,
+    /// `AVAILABLE`
+    Available,
+    /// `DELETE_IN_PROGRESS`
+    DeleteInProgress,
+    /// `NOT_AVAILABLE`
+    NotAvailable,
+    /// `FLUSH_IN_PROGRESS`
+    FlushInProgress,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CacheClusterStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CacheClusterStatus::CreateInProgress => "CREATE_IN_PROGRESS",
+            CacheClusterStatus::Available => "AVAILABLE",
+            CacheClusterStatus::DeleteInProgress => "DELETE_IN_PROGRESS",
+            CacheClusterStatus::NotAvailable => "NOT_AVAILABLE",
+            CacheClusterStatus::FlushInProgress => "FLUSH_IN_PROGRESS",
+            CacheClusterStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CacheClusterStatus {
+    fn default() -> CacheClusterStatus {
+        CacheClusterStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CacheClusterStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CacheClusterStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CacheClusterStatus {
+    fn from(value: &'a str) -> CacheClusterStatus {
+        match value {
+            "CREATE_IN_PROGRESS" => CacheClusterStatus::CreateInProgress,
+            "AVAILABLE" => CacheClusterStatus::Available,
+            "DELETE_IN_PROGRESS" => CacheClusterStatus::DeleteInProgress,
+            "NOT_AVAILABLE" => CacheClusterStatus::NotAvailable,
+            "FLUSH_IN_PROGRESS" => CacheClusterStatus::FlushInProgress,
+            _ => CacheClusterStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CacheClusterStatus {
+    fn from(value: String) -> CacheClusterStatus {
+        match value.as_str() {
+            "CREATE_IN_PROGRESS" => CacheClusterStatus::CreateInProgress,
+            "AVAILABLE" => CacheClusterStatus::Available,
+            "DELETE_IN_PROGRESS" => CacheClusterStatus::DeleteInProgress,
+            "NOT_AVAILABLE" => CacheClusterStatus::NotAvailable,
+            "FLUSH_IN_PROGRESS" => CacheClusterStatus::FlushInProgress,
+            _ => CacheClusterStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CacheClusterStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CacheClusterStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CacheClusterStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CacheClusterStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CacheClusterStatus {
+    fn deserialize<D>(deserializer: D) -> Result<CacheClusterStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Configuration settings of a canary deployment.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CanarySettings {
@@ -276,16 +799,26 @@ pub struct ClientCertificate {
     pub client_certificate_id: Option<String>,
     /// <p>The timestamp when the client certificate was created.</p>
     #[serde(rename = "createdDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The description of the client certificate.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     /// <p>The timestamp when the client certificate will expire.</p>
     #[serde(rename = "expirationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expiration_date: Option<f64>,
+    pub expiration_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The PEM-encoded public key of the client certificate, which can be used to configure certificate authentication in the integration endpoint .</p>
     #[serde(rename = "pemEncodedCertificate")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -309,6 +842,192 @@ pub struct ClientCertificates {
     pub position: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ConnectionType {
+    /// `INTERNET`
+    Internet,
+    /// `VPC_LINK`
+    VpcLink,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ConnectionType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ConnectionType::Internet => "INTERNET",
+            ConnectionType::VpcLink => "VPC_LINK",
+            ConnectionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ConnectionType {
+    fn default() -> ConnectionType {
+        ConnectionType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ConnectionType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ConnectionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ConnectionType {
+    fn from(value: &'a str) -> ConnectionType {
+        match value {
+            "INTERNET" => ConnectionType::Internet,
+            "VPC_LINK" => ConnectionType::VpcLink,
+            _ => ConnectionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ConnectionType {
+    fn from(value: String) -> ConnectionType {
+        match value.as_str() {
+            "INTERNET" => ConnectionType::Internet,
+            "VPC_LINK" => ConnectionType::VpcLink,
+            _ => ConnectionType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ConnectionType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ConnectionType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ConnectionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ConnectionType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ConnectionType {
+    fn deserialize<D>(deserializer: D) -> Result<ConnectionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ContentHandlingStrategy {
+    /// `CONVERT_TO_BINARY`
+    ConvertToBinary,
+    /// `CONVERT_TO_TEXT`
+    ConvertToText,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ContentHandlingStrategy {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ContentHandlingStrategy::ConvertToBinary => "CONVERT_TO_BINARY",
+            ContentHandlingStrategy::ConvertToText => "CONVERT_TO_TEXT",
+            ContentHandlingStrategy::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ContentHandlingStrategy {
+    fn default() -> ContentHandlingStrategy {
+        ContentHandlingStrategy::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ContentHandlingStrategy {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ContentHandlingStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ContentHandlingStrategy {
+    fn from(value: &'a str) -> ContentHandlingStrategy {
+        match value {
+            "CONVERT_TO_BINARY" => ContentHandlingStrategy::ConvertToBinary,
+            "CONVERT_TO_TEXT" => ContentHandlingStrategy::ConvertToText,
+            _ => ContentHandlingStrategy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ContentHandlingStrategy {
+    fn from(value: String) -> ContentHandlingStrategy {
+        match value.as_str() {
+            "CONVERT_TO_BINARY" => ContentHandlingStrategy::ConvertToBinary,
+            "CONVERT_TO_TEXT" => ContentHandlingStrategy::ConvertToText,
+            _ => ContentHandlingStrategy::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ContentHandlingStrategy {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ContentHandlingStrategy, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ContentHandlingStrategy {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ContentHandlingStrategy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ContentHandlingStrategy {
+    fn deserialize<D>(deserializer: D) -> Result<ContentHandlingStrategy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Request to create an <a>ApiKey</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -387,7 +1106,7 @@ pub struct CreateAuthorizerRequest {
     pub rest_api_id: String,
     /// <p>[Required] The authorizer type. Valid values are <code>TOKEN</code> for a Lambda function using a single authorization token submitted in a custom header, <code>REQUEST</code> for a Lambda function using incoming request parameters, and <code>COGNITO_USER_POOLS</code> for using an Amazon Cognito user pool.</p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: AuthorizerType,
 }
 
 /// <p>Requests API Gateway to create a new <a>BasePathMapping</a> resource.</p>
@@ -421,7 +1140,7 @@ pub struct CreateDeploymentRequest {
     /// <p>Specifies the cache cluster size for the <a>Stage</a> resource specified in the input, if a cache cluster is enabled.</p>
     #[serde(rename = "cacheClusterSize")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub cache_cluster_size: Option<String>,
+    pub cache_cluster_size: Option<CacheClusterSize>,
     /// <p>The input configuration for the canary deployment when the deployment is a canary release deployment. </p>
     #[serde(rename = "canarySettings")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -528,7 +1247,7 @@ pub struct CreateDomainNameRequest {
     /// <p>The Transport Layer Security (TLS) version + cipher suite for this <a>DomainName</a>. The valid values are <code>TLS_1_0</code> and <code>TLS_1_2</code>.</p>
     #[serde(rename = "securityPolicy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub security_policy: Option<String>,
+    pub security_policy: Option<SecurityPolicy>,
     /// <p>The key-value map of strings. The valid character set is [a-zA-Z+-=._:/]. The tag key can be up to 128 characters and must not start with <code>aws:</code>. The tag value can be up to 256 characters.</p>
     #[serde(rename = "tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -601,7 +1320,7 @@ pub struct CreateRestApiRequest {
     /// <p>The source of the API key for metering requests according to a usage plan. Valid values are: <ul><li><code>HEADER</code> to read the API key from the <code>X-API-Key</code> header of a request. </li><li><code>AUTHORIZER</code> to read the API key from the <code>UsageIdentifierKey</code> from a custom authorizer.</li></ul> </p>
     #[serde(rename = "apiKeySource")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub api_key_source: Option<String>,
+    pub api_key_source: Option<ApiKeySourceType>,
     /// <p>The list of binary media types supported by the <a>RestApi</a>. By default, the <a>RestApi</a> supports only UTF-8-encoded text payloads.</p>
     #[serde(rename = "binaryMediaTypes")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -650,7 +1369,7 @@ pub struct CreateStageRequest {
     /// <p>The stage's cache cluster size.</p>
     #[serde(rename = "cacheClusterSize")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub cache_cluster_size: Option<String>,
+    pub cache_cluster_size: Option<CacheClusterSize>,
     /// <p>The canary deployment settings of this stage.</p>
     #[serde(rename = "canarySettings")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -843,7 +1562,7 @@ pub struct DeleteDomainNameRequest {
 pub struct DeleteGatewayResponseRequest {
     /// <p>[Required] <p>The response type of the associated <a>GatewayResponse</a>. Valid values are <ul><li>ACCESS_DENIED</li><li>API_CONFIGURATION_ERROR</li><li>AUTHORIZER_FAILURE</li><li> AUTHORIZER_CONFIGURATION_ERROR</li><li>BAD_REQUEST_PARAMETERS</li><li>BAD_REQUEST_BODY</li><li>DEFAULT_4XX</li><li>DEFAULT_5XX</li><li>EXPIRED_TOKEN</li><li>INVALID_SIGNATURE</li><li>INTEGRATION_FAILURE</li><li>INTEGRATION_TIMEOUT</li><li>INVALID_API_KEY</li><li>MISSING_AUTHENTICATION_TOKEN</li><li> QUOTA_EXCEEDED</li><li>REQUEST_TOO_LARGE</li><li>RESOURCE_NOT_FOUND</li><li>THROTTLED</li><li>UNAUTHORIZED</li><li>UNSUPPORTED_MEDIA_TYPE</li></ul> </p></p>
     #[serde(rename = "responseType")]
-    pub response_type: String,
+    pub response_type: GatewayResponseType,
     /// <p>[Required] The string identifier of the associated <a>RestApi</a>.</p>
     #[serde(rename = "restApiId")]
     pub rest_api_id: String,
@@ -1014,8 +1733,13 @@ pub struct Deployment {
     >,
     /// <p>The date and time that the deployment resource was created.</p>
     #[serde(rename = "createdDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The description for the deployment resource.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1110,9 +1834,152 @@ pub struct DocumentationPartLocation {
     pub status_code: Option<String>,
     /// <p>[Required] The type of API entity to which the documentation content applies. Valid values are <code>API</code>, <code>AUTHORIZER</code>, <code>MODEL</code>, <code>RESOURCE</code>, <code>METHOD</code>, <code>PATH_PARAMETER</code>, <code>QUERY_PARAMETER</code>, <code>REQUEST_HEADER</code>, <code>REQUEST_BODY</code>, <code>RESPONSE</code>, <code>RESPONSE_HEADER</code>, and <code>RESPONSE_BODY</code>. Content inheritance does not apply to any entity of the <code>API</code>, <code>AUTHORIZER</code>, <code>METHOD</code>, <code>MODEL</code>, <code>REQUEST_BODY</code>, or <code>RESOURCE</code> type.</p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: DocumentationPartType,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DocumentationPartType {
+    /// `API`
+    Api,
+    /// `AUTHORIZER`
+    Authorizer,
+    /// `MODEL`
+    Model,
+    /// `RESOURCE`
+    Resource,
+    /// `METHOD`
+    Method,
+    /// `PATH_PARAMETER`
+    PathParameter,
+    /// `QUERY_PARAMETER`
+    QueryParameter,
+    /// `REQUEST_HEADER`
+    RequestHeader,
+    /// `REQUEST_BODY`
+    RequestBody,
+    /// `RESPONSE`
+    Response,
+    /// `RESPONSE_HEADER`
+    ResponseHeader,
+    /// `RESPONSE_BODY`
+    ResponseBody,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DocumentationPartType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DocumentationPartType::Api => "API",
+            DocumentationPartType::Authorizer => "AUTHORIZER",
+            DocumentationPartType::Model => "MODEL",
+            DocumentationPartType::Resource => "RESOURCE",
+            DocumentationPartType::Method => "METHOD",
+            DocumentationPartType::PathParameter => "PATH_PARAMETER",
+            DocumentationPartType::QueryParameter => "QUERY_PARAMETER",
+            DocumentationPartType::RequestHeader => "REQUEST_HEADER",
+            DocumentationPartType::RequestBody => "REQUEST_BODY",
+            DocumentationPartType::Response => "RESPONSE",
+            DocumentationPartType::ResponseHeader => "RESPONSE_HEADER",
+            DocumentationPartType::ResponseBody => "RESPONSE_BODY",
+            DocumentationPartType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DocumentationPartType {
+    fn default() -> DocumentationPartType {
+        DocumentationPartType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DocumentationPartType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
 }
 
+impl fmt::Display for DocumentationPartType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DocumentationPartType {
+    fn from(value: &'a str) -> DocumentationPartType {
+        match value {
+            "API" => DocumentationPartType::Api,
+            "AUTHORIZER" => DocumentationPartType::Authorizer,
+            "MODEL" => DocumentationPartType::Model,
+            "RESOURCE" => DocumentationPartType::Resource,
+            "METHOD" => DocumentationPartType::Method,
+            "PATH_PARAMETER" => DocumentationPartType::PathParameter,
+            "QUERY_PARAMETER" => DocumentationPartType::QueryParameter,
+            "REQUEST_HEADER" => DocumentationPartType::RequestHeader,
+            "REQUEST_BODY" => DocumentationPartType::RequestBody,
+            "RESPONSE" => DocumentationPartType::Response,
+            "RESPONSE_HEADER" => DocumentationPartType::ResponseHeader,
+            "RESPONSE_BODY" => DocumentationPartType::ResponseBody,
+            _ => DocumentationPartType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DocumentationPartType {
+    fn from(value: String) -> DocumentationPartType {
+        match value.as_str() {
+            "API" => DocumentationPartType::Api,
+            "AUTHORIZER" => DocumentationPartType::Authorizer,
+            "MODEL" => DocumentationPartType::Model,
+            "RESOURCE" => DocumentationPartType::Resource,
+            "METHOD" => DocumentationPartType::Method,
+            "PATH_PARAMETER" => DocumentationPartType::PathParameter,
+            "QUERY_PARAMETER" => DocumentationPartType::QueryParameter,
+            "REQUEST_HEADER" => DocumentationPartType::RequestHeader,
+            "REQUEST_BODY" => DocumentationPartType::RequestBody,
+            "RESPONSE" => DocumentationPartType::Response,
+            "RESPONSE_HEADER" => DocumentationPartType::ResponseHeader,
+            "RESPONSE_BODY" => DocumentationPartType::ResponseBody,
+            _ => DocumentationPartType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DocumentationPartType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DocumentationPartType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DocumentationPartType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DocumentationPartType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DocumentationPartType {
+    fn deserialize<D>(deserializer: D) -> Result<DocumentationPartType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p><p>The collection of documentation parts of an API.</p> <div class="remarks"/> <div class="seeAlso"> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/api-gateway-documenting-api.html">Documenting an API</a>, <a>DocumentationPart</a> </div></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1132,8 +1999,13 @@ pub struct DocumentationParts {
 pub struct DocumentationVersion {
     /// <p>The date when the API documentation snapshot is created.</p>
     #[serde(rename = "createdDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The description of the API documentation snapshot.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1171,8 +2043,13 @@ pub struct DomainName {
     pub certificate_name: Option<String>,
     /// <p>The timestamp when the certificate that was used by edge-optimized endpoint for this domain name was uploaded.</p>
     #[serde(rename = "certificateUploadDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub certificate_upload_date: Option<f64>,
+    pub certificate_upload_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The domain name of the Amazon CloudFront distribution associated with this custom domain name for an edge-optimized endpoint. You set up this association when adding a DNS record pointing the custom domain name to this distribution name. For more information about CloudFront distributions, see the <a href="https://aws.amazon.com/documentation/cloudfront/" target="_blank">Amazon CloudFront documentation</a>.</p>
     #[serde(rename = "distributionDomainName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1188,7 +2065,7 @@ pub struct DomainName {
     /// <p>The status of the <a>DomainName</a> migration. The valid values are <code>AVAILABLE</code> and <code>UPDATING</code>. If the status is <code>UPDATING</code>, the domain cannot be modified further until the existing operation is complete. If it is <code>AVAILABLE</code>, the domain can be updated.</p>
     #[serde(rename = "domainNameStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub domain_name_status: Option<String>,
+    pub domain_name_status: Option<DomainNameStatus>,
     /// <p>An optional text message containing detailed information about status of the <a>DomainName</a> migration.</p>
     #[serde(rename = "domainNameStatusMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1216,13 +2093,121 @@ pub struct DomainName {
     /// <p>The Transport Layer Security (TLS) version + cipher suite for this <a>DomainName</a>. The valid values are <code>TLS_1_0</code> and <code>TLS_1_2</code>.</p>
     #[serde(rename = "securityPolicy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub security_policy: Option<String>,
+    pub security_policy: Option<SecurityPolicy>,
     /// <p>The collection of tags. Each tag element is associated with a given resource.</p>
     #[serde(rename = "tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DomainNameStatus {
+    /// `AVAILABLE`
+    Available,
+    /// `UPDATING`
+    Updating,
+    /// `PENDING`
+    Pending,
+    /// `PENDING_CERTIFICATE_REIMPORT`
+    PendingCertificateReimport,
+    /// `PENDING_OWNERSHIP_VERIFICATION`
+    PendingOwnershipVerification,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DomainNameStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DomainNameStatus::Available => "AVAILABLE",
+            DomainNameStatus::Updating => "UPDATING",
+            DomainNameStatus::Pending => "PENDING",
+            DomainNameStatus::PendingCertificateReimport => "PENDING_CERTIFICATE_REIMPORT",
+            DomainNameStatus::PendingOwnershipVerification => "PENDING_OWNERSHIP_VERIFICATION",
+            DomainNameStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DomainNameStatus {
+    fn default() -> DomainNameStatus {
+        DomainNameStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DomainNameStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DomainNameStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DomainNameStatus {
+    fn from(value: &'a str) -> DomainNameStatus {
+        match value {
+            "AVAILABLE" => DomainNameStatus::Available,
+            "UPDATING" => DomainNameStatus::Updating,
+            "PENDING" => DomainNameStatus::Pending,
+            "PENDING_CERTIFICATE_REIMPORT" => DomainNameStatus::PendingCertificateReimport,
+            "PENDING_OWNERSHIP_VERIFICATION" => DomainNameStatus::PendingOwnershipVerification,
+            _ => DomainNameStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DomainNameStatus {
+    fn from(value: String) -> DomainNameStatus {
+        match value.as_str() {
+            "AVAILABLE" => DomainNameStatus::Available,
+            "UPDATING" => DomainNameStatus::Updating,
+            "PENDING" => DomainNameStatus::Pending,
+            "PENDING_CERTIFICATE_REIMPORT" => DomainNameStatus::PendingCertificateReimport,
+            "PENDING_OWNERSHIP_VERIFICATION" => DomainNameStatus::PendingOwnershipVerification,
+            _ => DomainNameStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DomainNameStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DomainNameStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DomainNameStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DomainNameStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DomainNameStatus {
+    fn deserialize<D>(deserializer: D) -> Result<DomainNameStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p><p>Represents a collection of <a>DomainName</a> resources.</p> <div class="seeAlso"> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/how-to-custom-domains.html">Use Client-Side Certificate</a> </div></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1242,13 +2227,112 @@ pub struct EndpointConfiguration {
     /// <p>A list of endpoint types of an API (<a>RestApi</a>) or its custom domain name (<a>DomainName</a>). For an edge-optimized API and its custom domain name, the endpoint type is <code>"EDGE"</code>. For a regional API and its custom domain name, the endpoint type is <code>REGIONAL</code>. For a private API, the endpoint type is <code>PRIVATE</code>.</p>
     #[serde(rename = "types")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub types: Option<Vec<String>>,
+    pub types: Option<Vec<EndpointType>>,
     /// <p>A list of VpcEndpointIds of an API (<a>RestApi</a>) against which to create Route53 ALIASes. It is only supported for <code>PRIVATE</code> endpoint type.</p>
     #[serde(rename = "vpcEndpointIds")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub vpc_endpoint_ids: Option<Vec<String>>,
 }
 
+/// <p>The endpoint type. The valid values are <code>EDGE</code> for edge-optimized API setup, most suitable for mobile applications; <code>REGIONAL</code> for regional API endpoint setup, most suitable for calling from AWS Region; and <code>PRIVATE</code> for private APIs.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EndpointType {
+    /// `REGIONAL`
+    Regional,
+    /// `EDGE`
+    Edge,
+    /// `PRIVATE`
+    Private,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EndpointType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EndpointType::Regional => "REGIONAL",
+            EndpointType::Edge => "EDGE",
+            EndpointType::Private => "PRIVATE",
+            EndpointType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EndpointType {
+    fn default() -> EndpointType {
+        EndpointType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EndpointType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EndpointType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EndpointType {
+    fn from(value: &'a str) -> EndpointType {
+        match value {
+            "REGIONAL" => EndpointType::Regional,
+            "EDGE" => EndpointType::Edge,
+            "PRIVATE" => EndpointType::Private,
+            _ => EndpointType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EndpointType {
+    fn from(value: String) -> EndpointType {
+        match value.as_str() {
+            "REGIONAL" => EndpointType::Regional,
+            "EDGE" => EndpointType::Edge,
+            "PRIVATE" => EndpointType::Private,
+            _ => EndpointType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EndpointType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EndpointType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EndpointType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EndpointType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EndpointType {
+    fn deserialize<D>(deserializer: D) -> Result<EndpointType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The binary blob response to <a>GetExport</a>, which contains the generated SDK.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct ExportResponse {
@@ -1303,13 +2387,201 @@ pub struct GatewayResponse {
     /// <p>The response type of the associated <a>GatewayResponse</a>. Valid values are <ul><li>ACCESS_DENIED</li><li>API_CONFIGURATION_ERROR</li><li>AUTHORIZER_FAILURE</li><li> AUTHORIZER_CONFIGURATION_ERROR</li><li>BAD_REQUEST_PARAMETERS</li><li>BAD_REQUEST_BODY</li><li>DEFAULT_4XX</li><li>DEFAULT_5XX</li><li>EXPIRED_TOKEN</li><li>INVALID_SIGNATURE</li><li>INTEGRATION_FAILURE</li><li>INTEGRATION_TIMEOUT</li><li>INVALID_API_KEY</li><li>MISSING_AUTHENTICATION_TOKEN</li><li> QUOTA_EXCEEDED</li><li>REQUEST_TOO_LARGE</li><li>RESOURCE_NOT_FOUND</li><li>THROTTLED</li><li>UNAUTHORIZED</li><li>UNSUPPORTED_MEDIA_TYPE</li></ul> </p>
     #[serde(rename = "responseType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub response_type: Option<String>,
+    pub response_type: Option<GatewayResponseType>,
     /// <p>The HTTP status code for this <a>GatewayResponse</a>.</p>
     #[serde(rename = "statusCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status_code: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum GatewayResponseType {
+    /// `DEFAULT_4XX`
+    Default4xx,
+    /// `DEFAULT_5XX`
+    Default5xx,
+    /// `RESOURCE_NOT_FOUND`
+    ResourceNotFound,
+    /// `UNAUTHORIZED`
+    Unauthorized,
+    /// `INVALID_API_KEY`
+    InvalidApiKey,
+    /// `ACCESS_DENIED`
+    AccessDenied,
+    /// `AUTHORIZER_FAILURE`
+    AuthorizerFailure,
+    /// `AUTHORIZER_CONFIGURATION_ERROR`
+    AuthorizerConfigurationError,
+    /// `INVALID_SIGNATURE`
+    InvalidSignature,
+    /// `EXPIRED_TOKEN`
+    ExpiredToken,
+    /// `MISSING_AUTHENTICATION_TOKEN`
+    MissingAuthenticationToken,
+    /// `INTEGRATION_FAILURE`
+    IntegrationFailure,
+    /// `INTEGRATION_TIMEOUT`
+    IntegrationTimeout,
+    /// `API_CONFIGURATION_ERROR`
+    ApiConfigurationError,
+    /// `UNSUPPORTED_MEDIA_TYPE`
+    UnsupportedMediaType,
+    /// `BAD_REQUEST_PARAMETERS`
+    BadRequestParameters,
+    /// `BAD_REQUEST_BODY`
+    BadRequestBody,
+    /// `REQUEST_TOO_LARGE`
+    RequestTooLarge,
+    /// `THROTTLED`
+    Throttled,
+    /// `QUOTA_EXCEEDED`
+    QuotaExceeded,
+    /// `WAF_FILTERED`
+    WafFiltered,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl GatewayResponseType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            GatewayResponseType::Default4xx => "DEFAULT_4XX",
+            GatewayResponseType::Default5xx => "DEFAULT_5XX",
+            GatewayResponseType::ResourceNotFound => "RESOURCE_NOT_FOUND",
+            GatewayResponseType::Unauthorized => "UNAUTHORIZED",
+            GatewayResponseType::InvalidApiKey => "INVALID_API_KEY",
+            GatewayResponseType::AccessDenied => "ACCESS_DENIED",
+            GatewayResponseType::AuthorizerFailure => "AUTHORIZER_FAILURE",
+            GatewayResponseType::AuthorizerConfigurationError => "AUTHORIZER_CONFIGURATION_ERROR",
+            GatewayResponseType::InvalidSignature => "INVALID_SIGNATURE",
+            GatewayResponseType::ExpiredToken => "EXPIRED_TOKEN",
+            GatewayResponseType::MissingAuthenticationToken => "MISSING_AUTHENTICATION_TOKEN",
+            GatewayResponseType::IntegrationFailure => "INTEGRATION_FAILURE",
+            GatewayResponseType::IntegrationTimeout => "INTEGRATION_TIMEOUT",
+            GatewayResponseType::ApiConfigurationError => "API_CONFIGURATION_ERROR",
+            GatewayResponseType::UnsupportedMediaType => "UNSUPPORTED_MEDIA_TYPE",
+            GatewayResponseType::BadRequestParameters => "BAD_REQUEST_PARAMETERS",
+            GatewayResponseType::BadRequestBody => "BAD_REQUEST_BODY",
+            GatewayResponseType::RequestTooLarge => "REQUEST_TOO_LARGE",
+            GatewayResponseType::Throttled => "THROTTLED",
+            GatewayResponseType::QuotaExceeded => "QUOTA_EXCEEDED",
+            GatewayResponseType::WafFiltered => "WAF_FILTERED",
+            GatewayResponseType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for GatewayResponseType {
+    fn default() -> GatewayResponseType {
+        GatewayResponseType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for GatewayResponseType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for GatewayResponseType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for GatewayResponseType {
+    fn from(value: &'a str) -> GatewayResponseType {
+        match value {
+            "DEFAULT_4XX" => GatewayResponseType::Default4xx,
+            "DEFAULT_5XX" => GatewayResponseType::Default5xx,
+            "RESOURCE_NOT_FOUND" => GatewayResponseType::ResourceNotFound,
+            "UNAUTHORIZED" => GatewayResponseType::Unauthorized,
+            "INVALID_API_KEY" => GatewayResponseType::InvalidApiKey,
+            "ACCESS_DENIED" => GatewayResponseType::AccessDenied,
+            "AUTHORIZER_FAILURE" => GatewayResponseType::AuthorizerFailure,
+            "AUTHORIZER_CONFIGURATION_ERROR" => GatewayResponseType::AuthorizerConfigurationError,
+            "INVALID_SIGNATURE" => GatewayResponseType::InvalidSignature,
+            "EXPIRED_TOKEN" => GatewayResponseType::ExpiredToken,
+            "MISSING_AUTHENTICATION_TOKEN" => GatewayResponseType::MissingAuthenticationToken,
+            "INTEGRATION_FAILURE" => GatewayResponseType::IntegrationFailure,
+            "INTEGRATION_TIMEOUT" => GatewayResponseType::IntegrationTimeout,
+            "API_CONFIGURATION_ERROR" => GatewayResponseType::ApiConfigurationError,
+            "UNSUPPORTED_MEDIA_TYPE" => GatewayResponseType::UnsupportedMediaType,
+            "BAD_REQUEST_PARAMETERS" => GatewayResponseType::BadRequestParameters,
+            "BAD_REQUEST_BODY" => GatewayResponseType::BadRequestBody,
+            "REQUEST_TOO_LARGE" => GatewayResponseType::RequestTooLarge,
+            "THROTTLED" => GatewayResponseType::Throttled,
+            "QUOTA_EXCEEDED" => GatewayResponseType::QuotaExceeded,
+            "WAF_FILTERED" => GatewayResponseType::WafFiltered,
+            _ => GatewayResponseType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for GatewayResponseType {
+    fn from(value: String) -> GatewayResponseType {
+        match value.as_str() {
+            "DEFAULT_4XX" => GatewayResponseType::Default4xx,
+            "DEFAULT_5XX" => GatewayResponseType::Default5xx,
+            "RESOURCE_NOT_FOUND" => GatewayResponseType::ResourceNotFound,
+            "UNAUTHORIZED" => GatewayResponseType::Unauthorized,
+            "INVALID_API_KEY" => GatewayResponseType::InvalidApiKey,
+            "ACCESS_DENIED" => GatewayResponseType::AccessDenied,
+            "AUTHORIZER_FAILURE" => GatewayResponseType::AuthorizerFailure,
+            "AUTHORIZER_CONFIGURATION_ERROR" => GatewayResponseType::AuthorizerConfigurationError,
+            "INVALID_SIGNATURE" => GatewayResponseType::InvalidSignature,
+            "EXPIRED_TOKEN" => GatewayResponseType::ExpiredToken,
+            "MISSING_AUTHENTICATION_TOKEN" => GatewayResponseType::MissingAuthenticationToken,
+            "INTEGRATION_FAILURE" => GatewayResponseType::IntegrationFailure,
+            "INTEGRATION_TIMEOUT" => GatewayResponseType::IntegrationTimeout,
+            "API_CONFIGURATION_ERROR" => GatewayResponseType::ApiConfigurationError,
+            "UNSUPPORTED_MEDIA_TYPE" => GatewayResponseType::UnsupportedMediaType,
+            "BAD_REQUEST_PARAMETERS" => GatewayResponseType::BadRequestParameters,
+            "BAD_REQUEST_BODY" => GatewayResponseType::BadRequestBody,
+            "REQUEST_TOO_LARGE" => GatewayResponseType::RequestTooLarge,
+            "THROTTLED" => GatewayResponseType::Throttled,
+            "QUOTA_EXCEEDED" => GatewayResponseType::QuotaExceeded,
+            "WAF_FILTERED" => GatewayResponseType::WafFiltered,
+            _ => GatewayResponseType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for GatewayResponseType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<GatewayResponseType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for GatewayResponseType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for GatewayResponseType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for GatewayResponseType {
+    fn deserialize<D>(deserializer: D) -> Result<GatewayResponseType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p><p>The collection of the <a>GatewayResponse</a> instances of a <a>RestApi</a> as a <code>responseType</code>-to-<a>GatewayResponse</a> object map of key-value pairs. As such, pagination is not supported for querying this collection.</p> <div class="remarks"> For more information about valid gateway response types, see <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/supported-gateway-response-types.html">Gateway Response Types Supported by API Gateway</a> <div class="example"> <h4>Example: Get the collection of gateway responses of an API</h4> <h5>Request</h5> <p>This example request shows how to retrieve the <a>GatewayResponses</a> collection from an API.</p> <pre><code>GET /restapis/o81lxisefl/gatewayresponses HTTP/1.1 Host: beta-apigateway.us-east-1.amazonaws.com Content-Type: application/json X-Amz-Date: 20170503T220604Z Authorization: AWS4-HMAC-SHA256 Credential={access-key-id}/20170503/us-east-1/apigateway/aws4<em>request, SignedHeaders=content-type;host;x-amz-date, Signature=59b42fe54a76a5de8adf2c67baa6d39206f8e9ad49a1d77ccc6a5da3103a398a Cache-Control: no-cache Postman-Token: 5637af27-dc29-fc5c-9dfe-0645d52cb515 </code></pre> <p></p> <h5>Response</h5> <p>The successful operation returns the <code>200 OK</code> status code and a payload similar to the following:</p> <pre><code>{ &quot;</em>links&quot;: { &quot;curies&quot;: { &quot;href&quot;: &quot;http://docs.aws.amazon.com/apigateway/latest/developerguide/restapi-gatewayresponse-{rel}.html&quot;, &quot;name&quot;: &quot;gatewayresponse&quot;, &quot;templated&quot;: true }, &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses&quot; }, &quot;first&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses&quot; }, &quot;gatewayresponse:by-type&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response<em>type}&quot;, &quot;templated&quot;: true }, &quot;item&quot;: [ { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/INTEGRATION</em>FAILURE&quot; }, { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/RESOURCE<em>NOT</em>FOUND&quot; }, { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/REQUEST<em>TOO</em>LARGE&quot; }, { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/THROTTLED&quot; }, { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/UNSUPPORTED<em>MEDIA</em>TYPE&quot; }, { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/AUTHORIZER<em>CONFIGURATION</em>ERROR&quot; }, { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/DEFAULT<em>5XX&quot; }, { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/DEFAULT</em>4XX&quot; }, { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/BAD<em>REQUEST</em>PARAMETERS&quot; }, { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/BAD<em>REQUEST</em>BODY&quot; }, { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/EXPIRED<em>TOKEN&quot; }, { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/ACCESS</em>DENIED&quot; }, { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/INVALID<em>API</em>KEY&quot; }, { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/UNAUTHORIZED&quot; }, { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/API<em>CONFIGURATION</em>ERROR&quot; }, { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/QUOTA<em>EXCEEDED&quot; }, { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/INTEGRATION</em>TIMEOUT&quot; }, { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/MISSING<em>AUTHENTICATION</em>TOKEN&quot; }, { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/INVALID<em>SIGNATURE&quot; }, { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/AUTHORIZER</em>FAILURE&quot; } ] }, &quot;<em>embedded&quot;: { &quot;item&quot;: [ { &quot;</em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/INTEGRATION<em>FAILURE&quot; }, &quot;gatewayresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response</em>type}&quot;, &quot;templated&quot;: true }, &quot;gatewayresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/INTEGRATION<em>FAILURE&quot; } }, &quot;defaultResponse&quot;: true, &quot;responseParameters&quot;: {}, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;{&quot;message&quot;:$context.error.messageString}&quot; }, &quot;responseType&quot;: &quot;INTEGRATION</em>FAILURE&quot;, &quot;statusCode&quot;: &quot;504&quot; }, { &quot;<em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/RESOURCE</em>NOT<em>FOUND&quot; }, &quot;gatewayresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response</em>type}&quot;, &quot;templated&quot;: true }, &quot;gatewayresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/RESOURCE<em>NOT</em>FOUND&quot; } }, &quot;defaultResponse&quot;: true, &quot;responseParameters&quot;: {}, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;{&quot;message&quot;:$context.error.messageString}&quot; }, &quot;responseType&quot;: &quot;RESOURCE<em>NOT</em>FOUND&quot;, &quot;statusCode&quot;: &quot;404&quot; }, { &quot;<em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/REQUEST</em>TOO<em>LARGE&quot; }, &quot;gatewayresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response</em>type}&quot;, &quot;templated&quot;: true }, &quot;gatewayresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/REQUEST<em>TOO</em>LARGE&quot; } }, &quot;defaultResponse&quot;: true, &quot;responseParameters&quot;: {}, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;{&quot;message&quot;:$context.error.messageString}&quot; }, &quot;responseType&quot;: &quot;REQUEST<em>TOO</em>LARGE&quot;, &quot;statusCode&quot;: &quot;413&quot; }, { &quot;<em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/THROTTLED&quot; }, &quot;gatewayresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response</em>type}&quot;, &quot;templated&quot;: true }, &quot;gatewayresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/THROTTLED&quot; } }, &quot;defaultResponse&quot;: true, &quot;responseParameters&quot;: {}, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;{&quot;message&quot;:$context.error.messageString}&quot; }, &quot;responseType&quot;: &quot;THROTTLED&quot;, &quot;statusCode&quot;: &quot;429&quot; }, { &quot;<em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/UNSUPPORTED</em>MEDIA<em>TYPE&quot; }, &quot;gatewayresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response</em>type}&quot;, &quot;templated&quot;: true }, &quot;gatewayresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/UNSUPPORTED<em>MEDIA</em>TYPE&quot; } }, &quot;defaultResponse&quot;: true, &quot;responseParameters&quot;: {}, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;{&quot;message&quot;:$context.error.messageString}&quot; }, &quot;responseType&quot;: &quot;UNSUPPORTED<em>MEDIA</em>TYPE&quot;, &quot;statusCode&quot;: &quot;415&quot; }, { &quot;<em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/AUTHORIZER</em>CONFIGURATION<em>ERROR&quot; }, &quot;gatewayresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response</em>type}&quot;, &quot;templated&quot;: true }, &quot;gatewayresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/AUTHORIZER<em>CONFIGURATION</em>ERROR&quot; } }, &quot;defaultResponse&quot;: true, &quot;responseParameters&quot;: {}, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;{&quot;message&quot;:$context.error.messageString}&quot; }, &quot;responseType&quot;: &quot;AUTHORIZER<em>CONFIGURATION</em>ERROR&quot;, &quot;statusCode&quot;: &quot;500&quot; }, { &quot;<em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/DEFAULT</em>5XX&quot; }, &quot;gatewayresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response<em>type}&quot;, &quot;templated&quot;: true }, &quot;gatewayresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/DEFAULT</em>5XX&quot; } }, &quot;defaultResponse&quot;: true, &quot;responseParameters&quot;: {}, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;{&quot;message&quot;:$context.error.messageString}&quot; }, &quot;responseType&quot;: &quot;DEFAULT<em>5XX&quot; }, { &quot;</em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/DEFAULT<em>4XX&quot; }, &quot;gatewayresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response</em>type}&quot;, &quot;templated&quot;: true }, &quot;gatewayresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/DEFAULT<em>4XX&quot; } }, &quot;defaultResponse&quot;: true, &quot;responseParameters&quot;: {}, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;{&quot;message&quot;:$context.error.messageString}&quot; }, &quot;responseType&quot;: &quot;DEFAULT</em>4XX&quot; }, { &quot;<em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/BAD</em>REQUEST<em>PARAMETERS&quot; }, &quot;gatewayresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response</em>type}&quot;, &quot;templated&quot;: true }, &quot;gatewayresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/BAD<em>REQUEST</em>PARAMETERS&quot; } }, &quot;defaultResponse&quot;: true, &quot;responseParameters&quot;: {}, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;{&quot;message&quot;:$context.error.messageString}&quot; }, &quot;responseType&quot;: &quot;BAD<em>REQUEST</em>PARAMETERS&quot;, &quot;statusCode&quot;: &quot;400&quot; }, { &quot;<em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/BAD</em>REQUEST<em>BODY&quot; }, &quot;gatewayresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response</em>type}&quot;, &quot;templated&quot;: true }, &quot;gatewayresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/BAD<em>REQUEST</em>BODY&quot; } }, &quot;defaultResponse&quot;: true, &quot;responseParameters&quot;: {}, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;{&quot;message&quot;:$context.error.messageString}&quot; }, &quot;responseType&quot;: &quot;BAD<em>REQUEST</em>BODY&quot;, &quot;statusCode&quot;: &quot;400&quot; }, { &quot;<em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/EXPIRED</em>TOKEN&quot; }, &quot;gatewayresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response<em>type}&quot;, &quot;templated&quot;: true }, &quot;gatewayresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/EXPIRED</em>TOKEN&quot; } }, &quot;defaultResponse&quot;: true, &quot;responseParameters&quot;: {}, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;{&quot;message&quot;:$context.error.messageString}&quot; }, &quot;responseType&quot;: &quot;EXPIRED<em>TOKEN&quot;, &quot;statusCode&quot;: &quot;403&quot; }, { &quot;</em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/ACCESS<em>DENIED&quot; }, &quot;gatewayresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response</em>type}&quot;, &quot;templated&quot;: true }, &quot;gatewayresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/ACCESS<em>DENIED&quot; } }, &quot;defaultResponse&quot;: true, &quot;responseParameters&quot;: {}, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;{&quot;message&quot;:$context.error.messageString}&quot; }, &quot;responseType&quot;: &quot;ACCESS</em>DENIED&quot;, &quot;statusCode&quot;: &quot;403&quot; }, { &quot;<em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/INVALID</em>API<em>KEY&quot; }, &quot;gatewayresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response</em>type}&quot;, &quot;templated&quot;: true }, &quot;gatewayresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/INVALID<em>API</em>KEY&quot; } }, &quot;defaultResponse&quot;: true, &quot;responseParameters&quot;: {}, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;{&quot;message&quot;:$context.error.messageString}&quot; }, &quot;responseType&quot;: &quot;INVALID<em>API</em>KEY&quot;, &quot;statusCode&quot;: &quot;403&quot; }, { &quot;<em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/UNAUTHORIZED&quot; }, &quot;gatewayresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response</em>type}&quot;, &quot;templated&quot;: true }, &quot;gatewayresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/UNAUTHORIZED&quot; } }, &quot;defaultResponse&quot;: true, &quot;responseParameters&quot;: {}, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;{&quot;message&quot;:$context.error.messageString}&quot; }, &quot;responseType&quot;: &quot;UNAUTHORIZED&quot;, &quot;statusCode&quot;: &quot;401&quot; }, { &quot;<em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/API</em>CONFIGURATION<em>ERROR&quot; }, &quot;gatewayresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response</em>type}&quot;, &quot;templated&quot;: true }, &quot;gatewayresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/API<em>CONFIGURATION</em>ERROR&quot; } }, &quot;defaultResponse&quot;: true, &quot;responseParameters&quot;: {}, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;{&quot;message&quot;:$context.error.messageString}&quot; }, &quot;responseType&quot;: &quot;API<em>CONFIGURATION</em>ERROR&quot;, &quot;statusCode&quot;: &quot;500&quot; }, { &quot;<em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/QUOTA</em>EXCEEDED&quot; }, &quot;gatewayresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response<em>type}&quot;, &quot;templated&quot;: true }, &quot;gatewayresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/QUOTA</em>EXCEEDED&quot; } }, &quot;defaultResponse&quot;: true, &quot;responseParameters&quot;: {}, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;{&quot;message&quot;:$context.error.messageString}&quot; }, &quot;responseType&quot;: &quot;QUOTA<em>EXCEEDED&quot;, &quot;statusCode&quot;: &quot;429&quot; }, { &quot;</em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/INTEGRATION<em>TIMEOUT&quot; }, &quot;gatewayresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response</em>type}&quot;, &quot;templated&quot;: true }, &quot;gatewayresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/INTEGRATION<em>TIMEOUT&quot; } }, &quot;defaultResponse&quot;: true, &quot;responseParameters&quot;: {}, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;{&quot;message&quot;:$context.error.messageString}&quot; }, &quot;responseType&quot;: &quot;INTEGRATION</em>TIMEOUT&quot;, &quot;statusCode&quot;: &quot;504&quot; }, { &quot;<em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/MISSING</em>AUTHENTICATION<em>TOKEN&quot; }, &quot;gatewayresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response</em>type}&quot;, &quot;templated&quot;: true }, &quot;gatewayresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/MISSING<em>AUTHENTICATION</em>TOKEN&quot; } }, &quot;defaultResponse&quot;: true, &quot;responseParameters&quot;: {}, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;{&quot;message&quot;:$context.error.messageString}&quot; }, &quot;responseType&quot;: &quot;MISSING<em>AUTHENTICATION</em>TOKEN&quot;, &quot;statusCode&quot;: &quot;403&quot; }, { &quot;<em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/INVALID</em>SIGNATURE&quot; }, &quot;gatewayresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response<em>type}&quot;, &quot;templated&quot;: true }, &quot;gatewayresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/INVALID</em>SIGNATURE&quot; } }, &quot;defaultResponse&quot;: true, &quot;responseParameters&quot;: {}, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;{&quot;message&quot;:$context.error.messageString}&quot; }, &quot;responseType&quot;: &quot;INVALID<em>SIGNATURE&quot;, &quot;statusCode&quot;: &quot;403&quot; }, { &quot;</em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/AUTHORIZER<em>FAILURE&quot; }, &quot;gatewayresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response</em>type}&quot;, &quot;templated&quot;: true }, &quot;gatewayresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/AUTHORIZER<em>FAILURE&quot; } }, &quot;defaultResponse&quot;: true, &quot;responseParameters&quot;: {}, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;{&quot;message&quot;:$context.error.messageString}&quot; }, &quot;responseType&quot;: &quot;AUTHORIZER</em>FAILURE&quot;, &quot;statusCode&quot;: &quot;500&quot; } ] } }</code></pre> <p></p> </div> </div> <div class="seeAlso"> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/customize-gateway-responses.html">Customize Gateway Responses</a> </div></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1518,7 +2790,7 @@ pub struct GetDocumentationPartsRequest {
     /// <p>The status of the API documentation parts to retrieve. Valid values are <code>DOCUMENTED</code> for retrieving <a>DocumentationPart</a> resources with content and <code>UNDOCUMENTED</code> for <a>DocumentationPart</a> resources without content.</p>
     #[serde(rename = "locationStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub location_status: Option<String>,
+    pub location_status: Option<LocationStatusType>,
     /// <p>The name of API entities of the to-be-retrieved documentation parts.</p>
     #[serde(rename = "nameQuery")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1537,7 +2809,7 @@ pub struct GetDocumentationPartsRequest {
     /// <p>The type of API entities of the to-be-retrieved documentation parts. </p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<DocumentationPartType>,
 }
 
 /// <p>Gets a documentation snapshot of an API.</p>
@@ -1621,7 +2893,7 @@ pub struct GetExportRequest {
 pub struct GetGatewayResponseRequest {
     /// <p>[Required] <p>The response type of the associated <a>GatewayResponse</a>. Valid values are <ul><li>ACCESS_DENIED</li><li>API_CONFIGURATION_ERROR</li><li>AUTHORIZER_FAILURE</li><li> AUTHORIZER_CONFIGURATION_ERROR</li><li>BAD_REQUEST_PARAMETERS</li><li>BAD_REQUEST_BODY</li><li>DEFAULT_4XX</li><li>DEFAULT_5XX</li><li>EXPIRED_TOKEN</li><li>INVALID_SIGNATURE</li><li>INTEGRATION_FAILURE</li><li>INTEGRATION_TIMEOUT</li><li>INVALID_API_KEY</li><li>MISSING_AUTHENTICATION_TOKEN</li><li> QUOTA_EXCEEDED</li><li>REQUEST_TOO_LARGE</li><li>RESOURCE_NOT_FOUND</li><li>THROTTLED</li><li>UNAUTHORIZED</li><li>UNSUPPORTED_MEDIA_TYPE</li></ul> </p></p>
     #[serde(rename = "responseType")]
-    pub response_type: String,
+    pub response_type: GatewayResponseType,
     /// <p>[Required] The string identifier of the associated <a>RestApi</a>.</p>
     #[serde(rename = "restApiId")]
     pub rest_api_id: String,
@@ -2056,7 +3328,7 @@ pub struct ImportApiKeysRequest {
     pub fail_on_warnings: Option<bool>,
     /// <p>A query parameter to specify the input format to imported API keys. Currently, only the <code>csv</code> format is supported.</p>
     #[serde(rename = "format")]
-    pub format: String,
+    pub format: ApiKeysFormat,
 }
 
 /// <p>Import documentation parts from an external (e.g., OpenAPI) definition file. </p>
@@ -2078,7 +3350,7 @@ pub struct ImportDocumentationPartsRequest {
     /// <p>A query parameter to indicate whether to overwrite (<code>OVERWRITE</code>) any existing <a>DocumentationParts</a> definition or to merge (<code>MERGE</code>) the new definition into the existing one. The default value is <code>MERGE</code>.</p>
     #[serde(rename = "mode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub mode: Option<String>,
+    pub mode: Option<PutMode>,
     /// <p>[Required] The string identifier of the associated <a>RestApi</a>.</p>
     #[serde(rename = "restApiId")]
     pub rest_api_id: String,
@@ -2125,11 +3397,11 @@ pub struct Integration {
     /// <p>The type of the network connection to the integration endpoint. The valid value is <code>INTERNET</code> for connections through the public routable internet or <code>VPC_LINK</code> for private connections between API Gateway and a network load balancer in a VPC. The default value is <code>INTERNET</code>.</p>
     #[serde(rename = "connectionType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub connection_type: Option<String>,
+    pub connection_type: Option<ConnectionType>,
     /// <p>Specifies how to handle request payload content type conversions. Supported values are <code>CONVERT_TO_BINARY</code> and <code>CONVERT_TO_TEXT</code>, with the following behaviors:</p> <ul> <li><p><code>CONVERT_TO_BINARY</code>: Converts a request payload from a Base64-encoded string to the corresponding binary blob.</p></li> <li><p><code>CONVERT_TO_TEXT</code>: Converts a request payload from a binary blob to a Base64-encoded string.</p></li> </ul> <p>If this property is not defined, the request payload will be passed through from the method request to integration request without modification, provided that the <code>passthroughBehavior</code> is configured to support payload pass-through.</p>
     #[serde(rename = "contentHandling")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub content_handling: Option<String>,
+    pub content_handling: Option<ContentHandlingStrategy>,
     /// <p>Specifies the credentials required for the integration, if any. For AWS integrations, three options are available. To specify an IAM Role for API Gateway to assume, use the role's Amazon Resource Name (ARN). To require that the caller's identity be passed through from the request, specify the string <code>arn:aws:iam::\*:user/\*</code>. To use resource-based permissions on supported AWS services, specify null.</p>
     #[serde(rename = "credentials")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2161,7 +3433,7 @@ pub struct Integration {
     /// <p>Specifies an API method integration type. The valid value is one of the following:</p> <ul> <li><code>AWS</code>: for integrating the API method request with an AWS service action, including the Lambda function-invoking action. With the Lambda function-invoking action, this is referred to as the Lambda custom integration. With any other AWS service action, this is known as AWS integration.</li> <li><code>AWS_PROXY</code>: for integrating the API method request with the Lambda function-invoking action with the client request passed through as-is. This integration is also referred to as the Lambda proxy integration.</li> <li><code>HTTP</code>: for integrating the API method request with an HTTP endpoint, including a private HTTP endpoint within a VPC. This integration is also referred to as the HTTP custom integration.</li> <li><code>HTTP_PROXY</code>: for integrating the API method request with an HTTP endpoint, including a private HTTP endpoint within a VPC, with the client request passed through as-is. This is also referred to as the HTTP proxy integration.</li> <li><code>MOCK</code>: for integrating the API method request with API Gateway as a "loop-back" endpoint without invoking any backend.</li> </ul> <p>For the HTTP and HTTP proxy integrations, each integration can specify a protocol (<code>http/https</code>), port and path. Standard 80 and 443 ports are supported as well as custom ports above 1024. An HTTP or HTTP proxy integration with a <code>connectionType</code> of <code>VPC_LINK</code> is referred to as a private integration and uses a <a>VpcLink</a> to connect API Gateway to a network load balancer of a VPC.</p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<IntegrationType>,
     /// <p><p>Specifies Uniform Resource Identifier (URI) of the integration endpoint.</p> <ul> <li><p> For <code>HTTP</code> or <code>HTTP<em>PROXY</code> integrations, the URI must be a fully formed, encoded HTTP(S) URL according to the &lt;a target=&quot;</em>blank&quot; href=&quot;https://en.wikipedia.org/wiki/Uniform<em>Resource</em>Identifier&quot;&gt;RFC-3986 specification</a>, for either standard integration, where <code>connectionType</code> is not <code>VPC<em>LINK</code>, or private integration, where <code>connectionType</code> is <code>VPC</em>LINK</code>. For a private HTTP integration, the URI is not used for routing. </p> </li> <li><p> For <code>AWS</code> or <code>AWS<em>PROXY</code> integrations, the URI is of the form <code>arn:aws:apigateway:{region}:{subdomain.service|service}:path|action/{service</em>api}</code>. Here, <code>{Region}</code> is the API Gateway region (e.g., <code>us-east-1</code>); <code>{service}</code> is the name of the integrated AWS service (e.g., <code>s3</code>); and <code>{subdomain}</code> is a designated subdomain supported by certain AWS service for fast host-name lookup. <code>action</code> can be used for an AWS service action-based API, using an <code>Action={name}&amp;{p1}={v1}&amp;p2={v2}...</code> query string. The ensuing <code>{service<em>api}</code> refers to a supported action <code>{name}</code> plus any required input parameters. Alternatively, <code>path</code> can be used for an AWS service path-based API. The ensuing <code>service</em>api</code> refers to the path to an AWS service resource, including the region of the integrated AWS service, if applicable. For example, for integration with the S3 API of <code><a href="https://docs.aws.amazon.com/AmazonS3/latest/API/RESTObjectGET.html">GetObject</a></code>, the <code>uri</code> can be either <code>arn:aws:apigateway:us-west-2:s3:action/GetObject&amp;Bucket={bucket}&amp;Key={key}</code> or <code>arn:aws:apigateway:us-west-2:s3:path/{bucket}/{key}</code></p> </li></ul></p>
     #[serde(rename = "uri")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2175,7 +3447,7 @@ pub struct IntegrationResponse {
     /// <p>Specifies how to handle response payload content type conversions. Supported values are <code>CONVERT_TO_BINARY</code> and <code>CONVERT_TO_TEXT</code>, with the following behaviors:</p> <ul> <li><p><code>CONVERT_TO_BINARY</code>: Converts a response payload from a Base64-encoded string to the corresponding binary blob.</p></li> <li><p><code>CONVERT_TO_TEXT</code>: Converts a response payload from a binary blob to a Base64-encoded string.</p></li> </ul> <p>If this property is not defined, the response payload will be passed through from the integration response to the method response without modification.</p>
     #[serde(rename = "contentHandling")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub content_handling: Option<String>,
+    pub content_handling: Option<ContentHandlingStrategy>,
     /// <p>A key-value map specifying response parameters that are passed to the method response from the back end. The key is a method response header parameter name and the mapped value is an integration response header value, a static value enclosed within a pair of single quotes, or a JSON expression from the integration response body. The mapping key must match the pattern of <code>method.response.header.{name}</code>, where <code>name</code> is a valid and unique header name. The mapped non-static value must match the pattern of <code>integration.response.header.{name}</code> or <code>integration.response.body.{JSON-expression}</code>, where <code>name</code> is a valid and unique response header name and <code>JSON-expression</code> is a valid JSON expression without the <code>$</code> prefix.</p>
     #[serde(rename = "responseParameters")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2194,6 +3466,208 @@ pub struct IntegrationResponse {
     pub status_code: Option<String>,
 }
 
+/// <p>The integration type. The valid value is <code>HTTP</code> for integrating an API method with an HTTP backend; <code>AWS</code> with any Amazon Web Services service endpoints; <code>MOCK</code> for testing without actually invoking the backend; <code>HTTP_PROXY</code> for integrating with the HTTP proxy integration; <code>AWS_PROXY</code> for integrating with the Lambda proxy integration. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum IntegrationType {
+    /// `HTTP`
+    Http,
+    /// `AWS`
+    Aws,
+    /// `MOCK`
+    Mock,
+    /// `HTTP_PROXY`
+    HttpProxy,
+    /// `AWS_PROXY`
+    AwsProxy,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl IntegrationType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            IntegrationType::Http => "HTTP",
+            IntegrationType::Aws => "AWS",
+            IntegrationType::Mock => "MOCK",
+            IntegrationType::HttpProxy => "HTTP_PROXY",
+            IntegrationType::AwsProxy => "AWS_PROXY",
+            IntegrationType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for IntegrationType {
+    fn default() -> IntegrationType {
+        IntegrationType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for IntegrationType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for IntegrationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for IntegrationType {
+    fn from(value: &'a str) -> IntegrationType {
+        match value {
+            "HTTP" => IntegrationType::Http,
+            "AWS" => IntegrationType::Aws,
+            "MOCK" => IntegrationType::Mock,
+            "HTTP_PROXY" => IntegrationType::HttpProxy,
+            "AWS_PROXY" => IntegrationType::AwsProxy,
+            _ => IntegrationType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for IntegrationType {
+    fn from(value: String) -> IntegrationType {
+        match value.as_str() {
+            "HTTP" => IntegrationType::Http,
+            "AWS" => IntegrationType::Aws,
+            "MOCK" => IntegrationType::Mock,
+            "HTTP_PROXY" => IntegrationType::HttpProxy,
+            "AWS_PROXY" => IntegrationType::AwsProxy,
+            _ => IntegrationType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for IntegrationType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<IntegrationType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for IntegrationType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for IntegrationType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for IntegrationType {
+    fn deserialize<D>(deserializer: D) -> Result<IntegrationType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LocationStatusType {
+    /// `DOCUMENTED`
+    Documented,
+    /// `UNDOCUMENTED`
+    Undocumented,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LocationStatusType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LocationStatusType::Documented => "DOCUMENTED",
+            LocationStatusType::Undocumented => "UNDOCUMENTED",
+            LocationStatusType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LocationStatusType {
+    fn default() -> LocationStatusType {
+        LocationStatusType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LocationStatusType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LocationStatusType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LocationStatusType {
+    fn from(value: &'a str) -> LocationStatusType {
+        match value {
+            "DOCUMENTED" => LocationStatusType::Documented,
+            "UNDOCUMENTED" => LocationStatusType::Undocumented,
+            _ => LocationStatusType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LocationStatusType {
+    fn from(value: String) -> LocationStatusType {
+        match value.as_str() {
+            "DOCUMENTED" => LocationStatusType::Documented,
+            "UNDOCUMENTED" => LocationStatusType::Undocumented,
+            _ => LocationStatusType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LocationStatusType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LocationStatusType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LocationStatusType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LocationStatusType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LocationStatusType {
+    fn deserialize<D>(deserializer: D) -> Result<LocationStatusType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p><p> Represents a client-facing interface by which the client calls the API to access back-end resources. A <b>Method</b> resource is integrated with an <a>Integration</a> resource. Both consist of a request and one or more responses. The method request takes the client input that is passed to the back end through the integration request. A method response returns the output from the back end to the client through an integration response. A method request is embodied in a <b>Method</b> resource, whereas an integration request is embodied in an <a>Integration</a> resource. On the other hand, a method response is represented by a <a>MethodResponse</a> resource, whereas an integration response is represented by an <a>IntegrationResponse</a> resource. </p> <div class="remarks"> <p/> <h4>Example: Retrive the GET method on a specified resource</h4> <h5>Request</h5> <p>The following example request retrieves the information about the GET method on an API resource (<code>3kzxbg5sa2</code>) of an API (<code>fugvjdxtri</code>). </p> <pre><code>GET /restapis/fugvjdxtri/resources/3kzxbg5sa2/methods/GET HTTP/1.1 Content-Type: application/json Host: apigateway.us-east-1.amazonaws.com X-Amz-Date: 20160603T210259Z Authorization: AWS4-HMAC-SHA256 Credential={access<em>key</em>ID}/20160603/us-east-1/apigateway/aws4<em>request, SignedHeaders=content-type;host;x-amz-date, Signature={sig4</em>hash}</code></pre> <h5>Response</h5> <p>The successful response returns a <code>200 OK</code> status code and a payload similar to the following:</p> <pre><code>{ &quot;<em>links&quot;: { &quot;curies&quot;: [ { &quot;href&quot;: &quot;https://docs.aws.amazon.com/apigateway/latest/developerguide/restapi-integration-{rel}.html&quot;, &quot;name&quot;: &quot;integration&quot;, &quot;templated&quot;: true }, { &quot;href&quot;: &quot;https://docs.aws.amazon.com/apigateway/latest/developerguide/restapi-integration-response-{rel}.html&quot;, &quot;name&quot;: &quot;integrationresponse&quot;, &quot;templated&quot;: true }, { &quot;href&quot;: &quot;https://docs.aws.amazon.com/apigateway/latest/developerguide/restapi-method-{rel}.html&quot;, &quot;name&quot;: &quot;method&quot;, &quot;templated&quot;: true }, { &quot;href&quot;: &quot;https://docs.aws.amazon.com/apigateway/latest/developerguide/restapi-method-response-{rel}.html&quot;, &quot;name&quot;: &quot;methodresponse&quot;, &quot;templated&quot;: true } ], &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/fugvjdxtri/resources/3kzxbg5sa2/methods/GET&quot;, &quot;name&quot;: &quot;GET&quot;, &quot;title&quot;: &quot;GET&quot; }, &quot;integration:put&quot;: { &quot;href&quot;: &quot;/restapis/fugvjdxtri/resources/3kzxbg5sa2/methods/GET/integration&quot; }, &quot;method:delete&quot;: { &quot;href&quot;: &quot;/restapis/fugvjdxtri/resources/3kzxbg5sa2/methods/GET&quot; }, &quot;method:integration&quot;: { &quot;href&quot;: &quot;/restapis/fugvjdxtri/resources/3kzxbg5sa2/methods/GET/integration&quot; }, &quot;method:responses&quot;: { &quot;href&quot;: &quot;/restapis/fugvjdxtri/resources/3kzxbg5sa2/methods/GET/responses/200&quot;, &quot;name&quot;: &quot;200&quot;, &quot;title&quot;: &quot;200&quot; }, &quot;method:update&quot;: { &quot;href&quot;: &quot;/restapis/fugvjdxtri/resources/3kzxbg5sa2/methods/GET&quot; }, &quot;methodresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/fugvjdxtri/resources/3kzxbg5sa2/methods/GET/responses/{status</em>code}&quot;, &quot;templated&quot;: true } }, &quot;apiKeyRequired&quot;: true, &quot;authorizationType&quot;: &quot;NONE&quot;, &quot;httpMethod&quot;: &quot;GET&quot;, &quot;<em>embedded&quot;: { &quot;method:integration&quot;: { &quot;</em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/fugvjdxtri/resources/3kzxbg5sa2/methods/GET/integration&quot; }, &quot;integration:delete&quot;: { &quot;href&quot;: &quot;/restapis/fugvjdxtri/resources/3kzxbg5sa2/methods/GET/integration&quot; }, &quot;integration:responses&quot;: { &quot;href&quot;: &quot;/restapis/fugvjdxtri/resources/3kzxbg5sa2/methods/GET/integration/responses/200&quot;, &quot;name&quot;: &quot;200&quot;, &quot;title&quot;: &quot;200&quot; }, &quot;integration:update&quot;: { &quot;href&quot;: &quot;/restapis/fugvjdxtri/resources/3kzxbg5sa2/methods/GET/integration&quot; }, &quot;integrationresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/fugvjdxtri/resources/3kzxbg5sa2/methods/GET/integration/responses/{status<em>code}&quot;, &quot;templated&quot;: true } }, &quot;cacheKeyParameters&quot;: [], &quot;cacheNamespace&quot;: &quot;3kzxbg5sa2&quot;, &quot;credentials&quot;: &quot;arn:aws:iam::123456789012:role/apigAwsProxyRole&quot;, &quot;httpMethod&quot;: &quot;POST&quot;, &quot;passthroughBehavior&quot;: &quot;WHEN</em>NO<em>MATCH&quot;, &quot;requestParameters&quot;: { &quot;integration.request.header.Content-Type&quot;: &quot;&#39;application/x-amz-json-1.1&#39;&quot; }, &quot;requestTemplates&quot;: { &quot;application/json&quot;: &quot;{\n}&quot; }, &quot;type&quot;: &quot;AWS&quot;, &quot;uri&quot;: &quot;arn:aws:apigateway:us-east-1:kinesis:action/ListStreams&quot;, &quot;</em>embedded&quot;: { &quot;integration:responses&quot;: { &quot;<em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/fugvjdxtri/resources/3kzxbg5sa2/methods/GET/integration/responses/200&quot;, &quot;name&quot;: &quot;200&quot;, &quot;title&quot;: &quot;200&quot; }, &quot;integrationresponse:delete&quot;: { &quot;href&quot;: &quot;/restapis/fugvjdxtri/resources/3kzxbg5sa2/methods/GET/integration/responses/200&quot; }, &quot;integrationresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/fugvjdxtri/resources/3kzxbg5sa2/methods/GET/integration/responses/200&quot; } }, &quot;responseParameters&quot;: { &quot;method.response.header.Content-Type&quot;: &quot;&#39;application/xml&#39;&quot; }, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;$util.urlDecode(&quot;%3CkinesisStreams%3E%23foreach(%24stream%20in%20%24input.path(%27%24.StreamNames%27))%3Cstream%3E%3Cname%3E%24stream%3C%2Fname%3E%3C%2Fstream%3E%23end%3C%2FkinesisStreams%3E&quot;)&quot; }, &quot;statusCode&quot;: &quot;200&quot; } } }, &quot;method:responses&quot;: { &quot;</em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/fugvjdxtri/resources/3kzxbg5sa2/methods/GET/responses/200&quot;, &quot;name&quot;: &quot;200&quot;, &quot;title&quot;: &quot;200&quot; }, &quot;methodresponse:delete&quot;: { &quot;href&quot;: &quot;/restapis/fugvjdxtri/resources/3kzxbg5sa2/methods/GET/responses/200&quot; }, &quot;methodresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/fugvjdxtri/resources/3kzxbg5sa2/methods/GET/responses/200&quot; } }, &quot;responseModels&quot;: { &quot;application/json&quot;: &quot;Empty&quot; }, &quot;responseParameters&quot;: { &quot;method.response.header.Content-Type&quot;: false }, &quot;statusCode&quot;: &quot;200&quot; } } }</code></pre> <p>In the example above, the response template for the <code>200 OK</code> response maps the JSON output from the <code>ListStreams</code> action in the back end to an XML output. The mapping template is URL-encoded as <code>%3CkinesisStreams%3E%23foreach(%24stream%20in%20%24input.path(%27%24.StreamNames%27))%3Cstream%3E%3Cname%3E%24stream%3C%2Fname%3E%3C%2Fstream%3E%23end%3C%2FkinesisStreams%3E</code> and the output is decoded using the <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/api-gateway-mapping-template-reference.html#util-templat-reference">$util.urlDecode()</a> helper function.</p> </div> <div class="seeAlso"> <a>MethodResponse</a>, <a>Integration</a>, <a>IntegrationResponse</a>, <a>Resource</a>, <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/how-to-method-settings.html">Set up an API&#39;s method</a> </div></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -2305,7 +3779,7 @@ pub struct MethodSetting {
     /// <p>Specifies how to handle unauthorized requests for cache invalidation. The PATCH path for this setting is <code>/{method_setting_key}/caching/unauthorizedCacheControlHeaderStrategy</code>, and the available values are <code>FAIL_WITH_403</code>, <code>SUCCEED_WITH_RESPONSE_HEADER</code>, <code>SUCCEED_WITHOUT_RESPONSE_HEADER</code>.</p>
     #[serde(rename = "unauthorizedCacheControlHeaderStrategy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub unauthorized_cache_control_header_strategy: Option<String>,
+    pub unauthorized_cache_control_header_strategy: Option<UnauthorizedCacheControlHeaderStrategy>,
 }
 
 /// <p>Represents a summary of a <a>Method</a> resource, given a particular date and time.</p>
@@ -2361,6 +3835,119 @@ pub struct Models {
     pub position: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Op {
+    /// `add`
+    Add,
+    /// `remove`
+    Remove,
+    /// `replace`
+    Replace,
+    /// `move`
+    Move,
+    /// `copy`
+    Copy,
+    /// `test`
+    Test,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Op {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Op::Add => "add",
+            Op::Remove => "remove",
+            Op::Replace => "replace",
+            Op::Move => "move",
+            Op::Copy => "copy",
+            Op::Test => "test",
+            Op::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Op {
+    fn default() -> Op {
+        Op::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Op {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Op {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Op {
+    fn from(value: &'a str) -> Op {
+        match value {
+            "add" => Op::Add,
+            "remove" => Op::Remove,
+            "replace" => Op::Replace,
+            "move" => Op::Move,
+            "copy" => Op::Copy,
+            "test" => Op::Test,
+            _ => Op::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Op {
+    fn from(value: String) -> Op {
+        match value.as_str() {
+            "add" => Op::Add,
+            "remove" => Op::Remove,
+            "replace" => Op::Replace,
+            "move" => Op::Move,
+            "copy" => Op::Copy,
+            "test" => Op::Test,
+            _ => Op::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Op {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Op, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Op {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Op {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Op {
+    fn deserialize<D>(deserializer: D) -> Result<Op, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A single patch operation to apply to the specified resource. Please refer to http://tools.ietf.org/html/rfc6902#section-4 for an explanation of how each operation is used.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -2372,7 +3959,7 @@ pub struct PatchOperation {
     /// <p> An update operation to be performed with this PATCH request. The valid value can be <code>add</code>, <code>remove</code>, <code>replace</code> or <code>copy</code>. Not all valid operations are supported for a given resource. Support of the operations depends on specific operational contexts. Attempts to apply an unsupported operation on a resource will return an error message.</p>
     #[serde(rename = "op")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub op: Option<String>,
+    pub op: Option<Op>,
     /// <p>The <code>op</code> operation's target, as identified by a <a href="https://tools.ietf.org/html/draft-ietf-appsawg-json-pointer-08">JSON Pointer</a> value that references a location within the targeted resource. For example, if the target resource has an updateable property of <code>{"name":"value"}</code>, the path for this property is <code>/name</code>. If the <code>name</code> property value is a JSON object (e.g., <code>{"name": {"child/name": "child-value"}}</code>), the path for the <code>child/name</code> property will be <code>/name/child~1name</code>. Any slash ("/") character appearing in path names must be escaped with "~1", as shown in the example above. Each <code>op</code> operation can have only one <code>path</code> associated with it.</p>
     #[serde(rename = "path")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2397,7 +3984,7 @@ pub struct PutGatewayResponseRequest {
     pub response_templates: Option<::std::collections::HashMap<String, String>>,
     /// <p>[Required] <p>The response type of the associated <a>GatewayResponse</a>. Valid values are <ul><li>ACCESS_DENIED</li><li>API_CONFIGURATION_ERROR</li><li>AUTHORIZER_FAILURE</li><li> AUTHORIZER_CONFIGURATION_ERROR</li><li>BAD_REQUEST_PARAMETERS</li><li>BAD_REQUEST_BODY</li><li>DEFAULT_4XX</li><li>DEFAULT_5XX</li><li>EXPIRED_TOKEN</li><li>INVALID_SIGNATURE</li><li>INTEGRATION_FAILURE</li><li>INTEGRATION_TIMEOUT</li><li>INVALID_API_KEY</li><li>MISSING_AUTHENTICATION_TOKEN</li><li> QUOTA_EXCEEDED</li><li>REQUEST_TOO_LARGE</li><li>RESOURCE_NOT_FOUND</li><li>THROTTLED</li><li>UNAUTHORIZED</li><li>UNSUPPORTED_MEDIA_TYPE</li></ul> </p></p>
     #[serde(rename = "responseType")]
-    pub response_type: String,
+    pub response_type: GatewayResponseType,
     /// <p>[Required] The string identifier of the associated <a>RestApi</a>.</p>
     #[serde(rename = "restApiId")]
     pub rest_api_id: String,
@@ -2426,11 +4013,11 @@ pub struct PutIntegrationRequest {
     /// <p>The type of the network connection to the integration endpoint. The valid value is <code>INTERNET</code> for connections through the public routable internet or <code>VPC_LINK</code> for private connections between API Gateway and a network load balancer in a VPC. The default value is <code>INTERNET</code>.</p>
     #[serde(rename = "connectionType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub connection_type: Option<String>,
+    pub connection_type: Option<ConnectionType>,
     /// <p>Specifies how to handle request payload content type conversions. Supported values are <code>CONVERT_TO_BINARY</code> and <code>CONVERT_TO_TEXT</code>, with the following behaviors:</p> <ul> <li><p><code>CONVERT_TO_BINARY</code>: Converts a request payload from a Base64-encoded string to the corresponding binary blob.</p></li> <li><p><code>CONVERT_TO_TEXT</code>: Converts a request payload from a binary blob to a Base64-encoded string.</p></li> </ul> <p>If this property is not defined, the request payload will be passed through from the method request to integration request without modification, provided that the <code>passthroughBehavior</code> is configured to support payload pass-through.</p>
     #[serde(rename = "contentHandling")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub content_handling: Option<String>,
+    pub content_handling: Option<ContentHandlingStrategy>,
     /// <p>Specifies whether credentials are required for a put integration.</p>
     #[serde(rename = "credentials")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2466,7 +4053,7 @@ pub struct PutIntegrationRequest {
     pub timeout_in_millis: Option<i64>,
     /// <p>[Required] Specifies a put integration input's type.</p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: IntegrationType,
     /// <p><p>Specifies Uniform Resource Identifier (URI) of the integration endpoint.</p> <ul> <li><p> For <code>HTTP</code> or <code>HTTP<em>PROXY</code> integrations, the URI must be a fully formed, encoded HTTP(S) URL according to the &lt;a target=&quot;</em>blank&quot; href=&quot;https://en.wikipedia.org/wiki/Uniform<em>Resource</em>Identifier&quot;&gt;RFC-3986 specification</a>, for either standard integration, where <code>connectionType</code> is not <code>VPC<em>LINK</code>, or private integration, where <code>connectionType</code> is <code>VPC</em>LINK</code>. For a private HTTP integration, the URI is not used for routing. </p> </li> <li><p> For <code>AWS</code> or <code>AWS<em>PROXY</code> integrations, the URI is of the form <code>arn:aws:apigateway:{region}:{subdomain.service|service}:path|action/{service</em>api}</code>. Here, <code>{Region}</code> is the API Gateway region (e.g., <code>us-east-1</code>); <code>{service}</code> is the name of the integrated AWS service (e.g., <code>s3</code>); and <code>{subdomain}</code> is a designated subdomain supported by certain AWS service for fast host-name lookup. <code>action</code> can be used for an AWS service action-based API, using an <code>Action={name}&amp;{p1}={v1}&amp;p2={v2}...</code> query string. The ensuing <code>{service<em>api}</code> refers to a supported action <code>{name}</code> plus any required input parameters. Alternatively, <code>path</code> can be used for an AWS service path-based API. The ensuing <code>service</em>api</code> refers to the path to an AWS service resource, including the region of the integrated AWS service, if applicable. For example, for integration with the S3 API of <code><a href="https://docs.aws.amazon.com/AmazonS3/latest/API/RESTObjectGET.html">GetObject</a></code>, the <code>uri</code> can be either <code>arn:aws:apigateway:us-west-2:s3:action/GetObject&amp;Bucket={bucket}&amp;Key={key}</code> or <code>arn:aws:apigateway:us-west-2:s3:path/{bucket}/{key}</code></p> </li></ul></p>
     #[serde(rename = "uri")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2480,7 +4067,7 @@ pub struct PutIntegrationResponseRequest {
     /// <p>Specifies how to handle response payload content type conversions. Supported values are <code>CONVERT_TO_BINARY</code> and <code>CONVERT_TO_TEXT</code>, with the following behaviors:</p> <ul> <li><p><code>CONVERT_TO_BINARY</code>: Converts a response payload from a Base64-encoded string to the corresponding binary blob.</p></li> <li><p><code>CONVERT_TO_TEXT</code>: Converts a response payload from a binary blob to a Base64-encoded string.</p></li> </ul> <p>If this property is not defined, the response payload will be passed through from the integration response to the method response without modification.</p>
     #[serde(rename = "contentHandling")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub content_handling: Option<String>,
+    pub content_handling: Option<ContentHandlingStrategy>,
     /// <p>[Required] Specifies a put integration response request's HTTP method.</p>
     #[serde(rename = "httpMethod")]
     pub http_method: String,
@@ -2579,6 +4166,99 @@ pub struct PutMethodResponseRequest {
     pub status_code: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PutMode {
+    /// `merge`
+    Merge,
+    /// `overwrite`
+    Overwrite,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PutMode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PutMode::Merge => "merge",
+            PutMode::Overwrite => "overwrite",
+            PutMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PutMode {
+    fn default() -> PutMode {
+        PutMode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PutMode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PutMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PutMode {
+    fn from(value: &'a str) -> PutMode {
+        match value {
+            "merge" => PutMode::Merge,
+            "overwrite" => PutMode::Overwrite,
+            _ => PutMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PutMode {
+    fn from(value: String) -> PutMode {
+        match value.as_str() {
+            "merge" => PutMode::Merge,
+            "overwrite" => PutMode::Overwrite,
+            _ => PutMode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PutMode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PutMode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PutMode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PutMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PutMode {
+    fn deserialize<D>(deserializer: D) -> Result<PutMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A PUT request to update an existing API, with external API definitions specified as the request body.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -2598,7 +4278,7 @@ pub struct PutRestApiRequest {
     /// <p>The <code>mode</code> query parameter to specify the update mode. Valid values are "merge" and "overwrite". By default, the update mode is "merge".</p>
     #[serde(rename = "mode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub mode: Option<String>,
+    pub mode: Option<PutMode>,
     /// <p>Custom header parameters as part of the request. For example, to exclude <a>DocumentationParts</a> from an imported API, set <code>ignore=documentation</code> as a <code>parameters</code> value, as in the AWS CLI command of <code>aws apigateway import-rest-api --parameters ignore=documentation --body 'file:///path/to/imported-api-body.json'</code>.</p>
     #[serde(rename = "parameters")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2608,6 +4288,104 @@ pub struct PutRestApiRequest {
     pub rest_api_id: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum QuotaPeriodType {
+    /// `DAY`
+    Day,
+    /// `WEEK`
+    Week,
+    /// `MONTH`
+    Month,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl QuotaPeriodType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            QuotaPeriodType::Day => "DAY",
+            QuotaPeriodType::Week => "WEEK",
+            QuotaPeriodType::Month => "MONTH",
+            QuotaPeriodType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for QuotaPeriodType {
+    fn default() -> QuotaPeriodType {
+        QuotaPeriodType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for QuotaPeriodType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for QuotaPeriodType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for QuotaPeriodType {
+    fn from(value: &'a str) -> QuotaPeriodType {
+        match value {
+            "DAY" => QuotaPeriodType::Day,
+            "WEEK" => QuotaPeriodType::Week,
+            "MONTH" => QuotaPeriodType::Month,
+            _ => QuotaPeriodType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for QuotaPeriodType {
+    fn from(value: String) -> QuotaPeriodType {
+        match value.as_str() {
+            "DAY" => QuotaPeriodType::Day,
+            "WEEK" => QuotaPeriodType::Week,
+            "MONTH" => QuotaPeriodType::Month,
+            _ => QuotaPeriodType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for QuotaPeriodType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<QuotaPeriodType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for QuotaPeriodType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for QuotaPeriodType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for QuotaPeriodType {
+    fn deserialize<D>(deserializer: D) -> Result<QuotaPeriodType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Quotas configured for a usage plan.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct QuotaSettings {
@@ -2622,7 +4400,7 @@ pub struct QuotaSettings {
     /// <p>The time period in which the limit applies. Valid values are "DAY", "WEEK" or "MONTH".</p>
     #[serde(rename = "period")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub period: Option<String>,
+    pub period: Option<QuotaPeriodType>,
 }
 
 /// <p><p>A set of validation rules for incoming <a>Method</a> requests.</p> <div class="remarks"> <p>In OpenAPI, a <a>RequestValidator</a> of an API is defined by the <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/api-gateway-swagger-extensions.html#api-gateway-swagger-extensions-request-validators.requestValidator.html">x-amazon-apigateway-request-validators.requestValidator</a> object. It the referenced using the <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/api-gateway-swagger-extensions.html#api-gateway-swagger-extensions-request-validator">x-amazon-apigateway-request-validator</a> property.</p> </div> <div class="seeAlso"><a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/api-gateway-method-request-validation.html">Enable Basic Request Validation in API Gateway</a></div></p>
@@ -2706,15 +4484,20 @@ pub struct RestApi {
     /// <p>The source of the API key for metering requests according to a usage plan. Valid values are: <ul><li><code>HEADER</code> to read the API key from the <code>X-API-Key</code> header of a request. </li><li><code>AUTHORIZER</code> to read the API key from the <code>UsageIdentifierKey</code> from a custom authorizer.</li></ul> </p>
     #[serde(rename = "apiKeySource")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub api_key_source: Option<String>,
+    pub api_key_source: Option<ApiKeySourceType>,
     /// <p>The list of binary media types supported by the <a>RestApi</a>. By default, the <a>RestApi</a> supports only UTF-8-encoded text payloads.</p>
     #[serde(rename = "binaryMediaTypes")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub binary_media_types: Option<Vec<String>>,
     /// <p>The timestamp when the API was created.</p>
     #[serde(rename = "createdDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The API's description.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2838,6 +4621,99 @@ pub struct SdkTypes {
     pub position: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SecurityPolicy {
+    /// `TLS_1_0`
+    Tls1_0,
+    /// `TLS_1_2`
+    Tls1_2,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SecurityPolicy {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SecurityPolicy::Tls1_0 => "TLS_1_0",
+            SecurityPolicy::Tls1_2 => "TLS_1_2",
+            SecurityPolicy::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SecurityPolicy {
+    fn default() -> SecurityPolicy {
+        SecurityPolicy::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SecurityPolicy {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SecurityPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SecurityPolicy {
+    fn from(value: &'a str) -> SecurityPolicy {
+        match value {
+            "TLS_1_0" => SecurityPolicy::Tls1_0,
+            "TLS_1_2" => SecurityPolicy::Tls1_2,
+            _ => SecurityPolicy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SecurityPolicy {
+    fn from(value: String) -> SecurityPolicy {
+        match value.as_str() {
+            "TLS_1_0" => SecurityPolicy::Tls1_0,
+            "TLS_1_2" => SecurityPolicy::Tls1_2,
+            _ => SecurityPolicy::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SecurityPolicy {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SecurityPolicy, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SecurityPolicy {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SecurityPolicy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SecurityPolicy {
+    fn deserialize<D>(deserializer: D) -> Result<SecurityPolicy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p><p>Represents a unique identifier for a version of a deployed <a>RestApi</a> that is callable by users.</p> <div class="seeAlso"> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/how-to-deploy-api.html">Deploy an API</a> </div></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -2853,11 +4729,11 @@ pub struct Stage {
     /// <p>The size of the cache cluster for the stage, if enabled.</p>
     #[serde(rename = "cacheClusterSize")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub cache_cluster_size: Option<String>,
+    pub cache_cluster_size: Option<CacheClusterSize>,
     /// <p>The status of the cache cluster for the stage, if enabled.</p>
     #[serde(rename = "cacheClusterStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub cache_cluster_status: Option<String>,
+    pub cache_cluster_status: Option<CacheClusterStatus>,
     /// <p>Settings for the canary deployment in this stage.</p>
     #[serde(rename = "canarySettings")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2868,8 +4744,13 @@ pub struct Stage {
     pub client_certificate_id: Option<String>,
     /// <p>The timestamp when the stage was created.</p>
     #[serde(rename = "createdDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The identifier of the <a>Deployment</a> that the stage points to.</p>
     #[serde(rename = "deploymentId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2884,8 +4765,13 @@ pub struct Stage {
     pub documentation_version: Option<String>,
     /// <p>The timestamp when the stage last updated.</p>
     #[serde(rename = "lastUpdatedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date: Option<f64>,
+    pub last_updated_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A map that defines the method settings for a <a>Stage</a> resource. Keys (designated as <code>/{method_setting_key</code> below) are method paths defined as <code>{resource_path}/{http_method}</code> for an individual method override, or <code>/\*/\*</code> for overriding all methods in the stage. </p>
     #[serde(rename = "methodSettings")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3119,6 +5005,116 @@ pub struct ThrottleSettings {
     pub rate_limit: Option<f64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum UnauthorizedCacheControlHeaderStrategy {
+    /// `FAIL_WITH_403`
+    FailWith403,
+    /// `SUCCEED_WITH_RESPONSE_HEADER`
+    SucceedWithResponseHeader,
+    /// `SUCCEED_WITHOUT_RESPONSE_HEADER`
+    SucceedWithoutResponseHeader,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl UnauthorizedCacheControlHeaderStrategy {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            UnauthorizedCacheControlHeaderStrategy::FailWith403 => "FAIL_WITH_403",
+            UnauthorizedCacheControlHeaderStrategy::SucceedWithResponseHeader => {
+                "SUCCEED_WITH_RESPONSE_HEADER"
+            }
+            UnauthorizedCacheControlHeaderStrategy::SucceedWithoutResponseHeader => {
+                "SUCCEED_WITHOUT_RESPONSE_HEADER"
+            }
+            UnauthorizedCacheControlHeaderStrategy::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for UnauthorizedCacheControlHeaderStrategy {
+    fn default() -> UnauthorizedCacheControlHeaderStrategy {
+        UnauthorizedCacheControlHeaderStrategy::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for UnauthorizedCacheControlHeaderStrategy {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for UnauthorizedCacheControlHeaderStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for UnauthorizedCacheControlHeaderStrategy {
+    fn from(value: &'a str) -> UnauthorizedCacheControlHeaderStrategy {
+        match value {
+            "FAIL_WITH_403" => UnauthorizedCacheControlHeaderStrategy::FailWith403,
+            "SUCCEED_WITH_RESPONSE_HEADER" => {
+                UnauthorizedCacheControlHeaderStrategy::SucceedWithResponseHeader
+            }
+            "SUCCEED_WITHOUT_RESPONSE_HEADER" => {
+                UnauthorizedCacheControlHeaderStrategy::SucceedWithoutResponseHeader
+            }
+            _ => UnauthorizedCacheControlHeaderStrategy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for UnauthorizedCacheControlHeaderStrategy {
+    fn from(value: String) -> UnauthorizedCacheControlHeaderStrategy {
+        match value.as_str() {
+            "FAIL_WITH_403" => UnauthorizedCacheControlHeaderStrategy::FailWith403,
+            "SUCCEED_WITH_RESPONSE_HEADER" => {
+                UnauthorizedCacheControlHeaderStrategy::SucceedWithResponseHeader
+            }
+            "SUCCEED_WITHOUT_RESPONSE_HEADER" => {
+                UnauthorizedCacheControlHeaderStrategy::SucceedWithoutResponseHeader
+            }
+            _ => UnauthorizedCacheControlHeaderStrategy::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for UnauthorizedCacheControlHeaderStrategy {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<UnauthorizedCacheControlHeaderStrategy, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for UnauthorizedCacheControlHeaderStrategy {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for UnauthorizedCacheControlHeaderStrategy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for UnauthorizedCacheControlHeaderStrategy {
+    fn deserialize<D>(deserializer: D) -> Result<UnauthorizedCacheControlHeaderStrategy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Removes a tag from a given resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -3270,7 +5266,7 @@ pub struct UpdateGatewayResponseRequest {
     pub patch_operations: Option<Vec<PatchOperation>>,
     /// <p>[Required] <p>The response type of the associated <a>GatewayResponse</a>. Valid values are <ul><li>ACCESS_DENIED</li><li>API_CONFIGURATION_ERROR</li><li>AUTHORIZER_FAILURE</li><li> AUTHORIZER_CONFIGURATION_ERROR</li><li>BAD_REQUEST_PARAMETERS</li><li>BAD_REQUEST_BODY</li><li>DEFAULT_4XX</li><li>DEFAULT_5XX</li><li>EXPIRED_TOKEN</li><li>INVALID_SIGNATURE</li><li>INTEGRATION_FAILURE</li><li>INTEGRATION_TIMEOUT</li><li>INVALID_API_KEY</li><li>MISSING_AUTHENTICATION_TOKEN</li><li> QUOTA_EXCEEDED</li><li>REQUEST_TOO_LARGE</li><li>RESOURCE_NOT_FOUND</li><li>THROTTLED</li><li>UNAUTHORIZED</li><li>UNSUPPORTED_MEDIA_TYPE</li></ul> </p></p>
     #[serde(rename = "responseType")]
-    pub response_type: String,
+    pub response_type: GatewayResponseType,
     /// <p>[Required] The string identifier of the associated <a>RestApi</a>.</p>
     #[serde(rename = "restApiId")]
     pub rest_api_id: String,
@@ -3607,7 +5603,7 @@ pub struct VpcLink {
     /// <p>The status of the VPC link. The valid values are <code>AVAILABLE</code>, <code>PENDING</code>, <code>DELETING</code>, or <code>FAILED</code>. Deploying an API will wait if the status is <code>PENDING</code> and will fail if the status is <code>DELETING</code>. </p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<VpcLinkStatus>,
     /// <p>A description about the VPC link status.</p>
     #[serde(rename = "statusMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3622,6 +5618,109 @@ pub struct VpcLink {
     pub target_arns: Option<Vec<String>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum VpcLinkStatus {
+    /// `AVAILABLE`
+    Available,
+    /// `PENDING`
+    Pending,
+    /// `DELETING`
+    Deleting,
+    /// `FAILED`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl VpcLinkStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            VpcLinkStatus::Available => "AVAILABLE",
+            VpcLinkStatus::Pending => "PENDING",
+            VpcLinkStatus::Deleting => "DELETING",
+            VpcLinkStatus::Failed => "FAILED",
+            VpcLinkStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for VpcLinkStatus {
+    fn default() -> VpcLinkStatus {
+        VpcLinkStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for VpcLinkStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for VpcLinkStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for VpcLinkStatus {
+    fn from(value: &'a str) -> VpcLinkStatus {
+        match value {
+            "AVAILABLE" => VpcLinkStatus::Available,
+            "PENDING" => VpcLinkStatus::Pending,
+            "DELETING" => VpcLinkStatus::Deleting,
+            "FAILED" => VpcLinkStatus::Failed,
+            _ => VpcLinkStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for VpcLinkStatus {
+    fn from(value: String) -> VpcLinkStatus {
+        match value.as_str() {
+            "AVAILABLE" => VpcLinkStatus::Available,
+            "PENDING" => VpcLinkStatus::Pending,
+            "DELETING" => VpcLinkStatus::Deleting,
+            "FAILED" => VpcLinkStatus::Failed,
+            _ => VpcLinkStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for VpcLinkStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<VpcLinkStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for VpcLinkStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for VpcLinkStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for VpcLinkStatus {
+    fn deserialize<D>(deserializer: D) -> Result<VpcLinkStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p><p>The collection of VPC links under the caller&#39;s account in a region.</p> <div class="seeAlso"> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/getting-started-with-private-integration.html">Getting Started with Private Integrations</a>, <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/set-up-private-integration.html">Set up Private Integrations</a> </div></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -10463,11 +12562,12 @@ pub struct ApiGatewayClient {
 impl ApiGatewayClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> ApiGatewayClient {
         ApiGatewayClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "API Gateway"),
         }
     }
 
@@ -10501,6 +12601,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/apikeys";
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateApiKey");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10533,6 +12634,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateAuthorizer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10566,6 +12668,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateBasePathMapping");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10599,6 +12702,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10631,6 +12735,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateDocumentationPart");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10663,6 +12768,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateDocumentationVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10693,6 +12799,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/domainnames";
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateDomainName");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10726,6 +12833,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateModel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10758,6 +12866,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateRequestValidator");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10792,6 +12901,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10822,6 +12932,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/restapis";
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateRestApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10855,6 +12966,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateStage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10884,6 +12996,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/usageplans";
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateUsagePlan");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10917,6 +13030,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateUsagePlanKey");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10947,6 +13061,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/vpclinks";
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateVpcLink");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10977,6 +13092,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/apikeys/{api_key}", api_key = input.api_key);
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteApiKey");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11007,6 +13123,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteAuthorizer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11037,6 +13154,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteBasePathMapping");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11066,6 +13184,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteClientCertificate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11096,6 +13215,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11125,6 +13245,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteDocumentationPart");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11154,6 +13275,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteDocumentationVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11183,6 +13305,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteDomainName");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11213,6 +13336,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteGatewayResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11244,6 +13368,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteIntegration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11270,6 +13395,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/integration/responses/{status_code}", http_method = input.http_method, resource_id = input.resource_id, restapi_id = input.rest_api_id, status_code = input.status_code);
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteIntegrationResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11301,6 +13427,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteMethod");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11327,6 +13454,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/responses/{status_code}", http_method = input.http_method, resource_id = input.resource_id, restapi_id = input.rest_api_id, status_code = input.status_code);
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteMethodResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11357,6 +13485,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteModel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11387,6 +13516,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteRequestValidator");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11417,6 +13547,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11443,6 +13574,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/restapis/{restapi_id}", restapi_id = input.rest_api_id);
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteRestApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11473,6 +13605,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteStage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11502,6 +13635,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteUsagePlan");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11532,6 +13666,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteUsagePlanKey");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11558,6 +13693,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/vpclinks/{vpclink_id}", vpclink_id = input.vpc_link_id);
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteVpcLink");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11588,6 +13724,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("FlushStageAuthorizersCache");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11618,6 +13755,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("FlushStageCache");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11644,6 +13782,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/clientcertificates";
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("GenerateClientCertificate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11671,6 +13810,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/account";
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11698,6 +13838,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/apikeys/{api_key}", api_key = input.api_key);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetApiKey");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -11730,6 +13871,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/apikeys";
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetApiKeys");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -11779,6 +13921,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetAuthorizer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11809,6 +13952,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetAuthorizers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -11849,6 +13993,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetBasePathMapping");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11879,6 +14024,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetBasePathMappings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -11918,6 +14064,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetClientCertificate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11945,6 +14092,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/clientcertificates";
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetClientCertificates");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -11985,6 +14133,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12023,6 +14172,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetDeployments");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12062,6 +14212,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetDocumentationPart");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12091,6 +14242,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetDocumentationParts");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12142,6 +14294,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetDocumentationVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12171,6 +14324,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetDocumentationVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12210,6 +14364,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetDomainName");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12237,6 +14392,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/domainnames";
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetDomainNames");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12278,6 +14434,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetExport");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         if let Some(ref accepts) = input.accepts {
@@ -12330,6 +14487,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetGatewayResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12360,6 +14518,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetGatewayResponses");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12401,6 +14560,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetIntegration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12428,6 +14588,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/integration/responses/{status_code}", http_method = input.http_method, resource_id = input.resource_id, restapi_id = input.rest_api_id, status_code = input.status_code);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetIntegrationResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12460,6 +14621,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetMethod");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12486,6 +14648,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/responses/{status_code}", http_method = input.http_method, resource_id = input.resource_id, restapi_id = input.rest_api_id, status_code = input.status_code);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetMethodResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12514,6 +14677,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetModel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12550,6 +14714,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetModelTemplate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12580,6 +14745,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetModels");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12619,6 +14785,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetRequestValidator");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12649,6 +14816,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetRequestValidators");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12689,6 +14857,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12727,6 +14896,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetResources");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12768,6 +14938,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/restapis/{restapi_id}", restapi_id = input.rest_api_id);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetRestApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12795,6 +14966,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/restapis";
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetRestApis");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12833,6 +15005,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetSdk");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12877,6 +15050,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/sdktypes/{sdktype_id}", sdktype_id = input.id);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetSdkType");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12903,6 +15077,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/sdktypes";
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetSdkTypes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12940,6 +15115,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetStage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12969,6 +15145,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetStages");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12998,6 +15175,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetTags");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -13033,6 +15211,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetUsage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -13076,6 +15255,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetUsagePlan");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -13107,6 +15287,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetUsagePlanKey");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -13137,6 +15318,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetUsagePlanKeys");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -13176,6 +15358,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/usageplans";
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetUsagePlans");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -13215,6 +15398,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/vpclinks/{vpclink_id}", vpclink_id = input.vpc_link_id);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetVpcLink");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -13242,6 +15426,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/vpclinks";
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetVpcLinks");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -13278,6 +15463,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/apikeys";
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("ImportApiKeys");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(input.body.to_owned());
@@ -13318,6 +15504,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
+        request.set_operation("ImportDocumentationParts");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(input.body.to_owned());
@@ -13357,6 +15544,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/restapis";
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("ImportRestApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(input.body.to_owned());
@@ -13403,6 +15591,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
+        request.set_operation("PutGatewayResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13438,6 +15627,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
+        request.set_operation("PutIntegration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13468,6 +15658,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/integration/responses/{status_code}", http_method = input.http_method, resource_id = input.resource_id, restapi_id = input.rest_api_id, status_code = input.status_code);
 
         let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
+        request.set_operation("PutIntegrationResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13503,6 +15694,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
+        request.set_operation("PutMethod");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13532,6 +15724,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/responses/{status_code}", http_method = input.http_method, resource_id = input.resource_id, restapi_id = input.rest_api_id, status_code = input.status_code);
 
         let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
+        request.set_operation("PutMethodResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13562,6 +15755,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/restapis/{restapi_id}", restapi_id = input.rest_api_id);
 
         let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
+        request.set_operation("PutRestApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(input.body.to_owned());
@@ -13606,6 +15800,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13639,6 +15834,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("TestInvokeAuthorizer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13674,6 +15870,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("TestInvokeMethod");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13704,6 +15901,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -13736,6 +15934,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/account";
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13766,6 +15965,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/apikeys/{api_key}", api_key = input.api_key);
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateApiKey");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13799,6 +15999,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateAuthorizer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13833,6 +16034,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateBasePathMapping");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13866,6 +16068,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateClientCertificate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13900,6 +16103,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13933,6 +16137,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateDocumentationPart");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13966,6 +16171,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateDocumentationVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13999,6 +16205,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateDomainName");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14033,6 +16240,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateGatewayResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14068,6 +16276,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateIntegration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14098,6 +16307,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/integration/responses/{status_code}", http_method = input.http_method, resource_id = input.resource_id, restapi_id = input.rest_api_id, status_code = input.status_code);
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateIntegrationResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14133,6 +16343,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateMethod");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14162,6 +16373,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/responses/{status_code}", http_method = input.http_method, resource_id = input.resource_id, restapi_id = input.rest_api_id, status_code = input.status_code);
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateMethodResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14196,6 +16408,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateModel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14229,6 +16442,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateRequestValidator");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14263,6 +16477,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14293,6 +16508,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/restapis/{restapi_id}", restapi_id = input.rest_api_id);
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateRestApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14327,6 +16543,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateStage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14360,6 +16577,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateUsage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14392,6 +16610,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateUsagePlan");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14422,6 +16641,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/vpclinks/{vpclink_id}", vpclink_id = input.vpc_link_id);
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateVpcLink");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14444,3 +16664,552 @@ impl ApiGateway for ApiGatewayClient {
         }
     }
 }
+impl ApiGatewayClient {
+    /// Auto-paginating version of `get_api_keys`, streaming every page of results.
+    pub fn get_api_keys_pages(
+        &self,
+        input: GetApiKeysRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ApiKeys, GetApiKeysError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApiGatewayClient, input: GetApiKeysRequest| async move {
+                client.get_api_keys(input).await
+            },
+            |input: &GetApiKeysRequest, output: &ApiKeys| {
+                Some(GetApiKeysRequest {
+                    position: Some(output.position.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_api_keys`, streaming the `items` of every page of results.
+    pub fn get_api_keys_items(
+        &self,
+        input: GetApiKeysRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ApiKey, GetApiKeysError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_api_keys_pages(input),
+            |output: ApiKeys| output.items.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_authorizers`, streaming every page of results.
+    pub fn get_authorizers_pages(
+        &self,
+        input: GetAuthorizersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Authorizers, GetAuthorizersError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApiGatewayClient, input: GetAuthorizersRequest| async move {
+                client.get_authorizers(input).await
+            },
+            |input: &GetAuthorizersRequest, output: &Authorizers| {
+                Some(GetAuthorizersRequest {
+                    position: Some(output.position.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_authorizers`, streaming the `items` of every page of results.
+    pub fn get_authorizers_items(
+        &self,
+        input: GetAuthorizersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Authorizer, GetAuthorizersError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_authorizers_pages(input),
+            |output: Authorizers| output.items.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_base_path_mappings`, streaming every page of results.
+    pub fn get_base_path_mappings_pages(
+        &self,
+        input: GetBasePathMappingsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<BasePathMappings, GetBasePathMappingsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApiGatewayClient, input: GetBasePathMappingsRequest| async move {
+                client.get_base_path_mappings(input).await
+            },
+            |input: &GetBasePathMappingsRequest, output: &BasePathMappings| {
+                Some(GetBasePathMappingsRequest {
+                    position: Some(output.position.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_base_path_mappings`, streaming the `items` of every page of results.
+    pub fn get_base_path_mappings_items(
+        &self,
+        input: GetBasePathMappingsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<BasePathMapping, GetBasePathMappingsError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_base_path_mappings_pages(input),
+            |output: BasePathMappings| output.items.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_client_certificates`, streaming every page of results.
+    pub fn get_client_certificates_pages(
+        &self,
+        input: GetClientCertificatesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ClientCertificates, GetClientCertificatesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApiGatewayClient, input: GetClientCertificatesRequest| async move {
+                client.get_client_certificates(input).await
+            },
+            |input: &GetClientCertificatesRequest, output: &ClientCertificates| {
+                Some(GetClientCertificatesRequest {
+                    position: Some(output.position.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_client_certificates`, streaming the `items` of every page of results.
+    pub fn get_client_certificates_items(
+        &self,
+        input: GetClientCertificatesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ClientCertificate, GetClientCertificatesError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_client_certificates_pages(input),
+            |output: ClientCertificates| output.items.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_deployments`, streaming every page of results.
+    pub fn get_deployments_pages(
+        &self,
+        input: GetDeploymentsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Deployments, GetDeploymentsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApiGatewayClient, input: GetDeploymentsRequest| async move {
+                client.get_deployments(input).await
+            },
+            |input: &GetDeploymentsRequest, output: &Deployments| {
+                Some(GetDeploymentsRequest {
+                    position: Some(output.position.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_deployments`, streaming the `items` of every page of results.
+    pub fn get_deployments_items(
+        &self,
+        input: GetDeploymentsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Deployment, GetDeploymentsError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_deployments_pages(input),
+            |output: Deployments| output.items.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_documentation_parts`, streaming every page of results.
+    pub fn get_documentation_parts_pages(
+        &self,
+        input: GetDocumentationPartsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DocumentationParts, GetDocumentationPartsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApiGatewayClient, input: GetDocumentationPartsRequest| async move {
+                client.get_documentation_parts(input).await
+            },
+            |input: &GetDocumentationPartsRequest, output: &DocumentationParts| {
+                Some(GetDocumentationPartsRequest {
+                    position: Some(output.position.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_documentation_parts`, streaming the `items` of every page of results.
+    pub fn get_documentation_parts_items(
+        &self,
+        input: GetDocumentationPartsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DocumentationPart, GetDocumentationPartsError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_documentation_parts_pages(input),
+            |output: DocumentationParts| output.items.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_documentation_versions`, streaming every page of results.
+    pub fn get_documentation_versions_pages(
+        &self,
+        input: GetDocumentationVersionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DocumentationVersions, GetDocumentationVersionsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApiGatewayClient, input: GetDocumentationVersionsRequest| async move {
+                client.get_documentation_versions(input).await
+            },
+            |input: &GetDocumentationVersionsRequest, output: &DocumentationVersions| {
+                Some(GetDocumentationVersionsRequest {
+                    position: Some(output.position.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_documentation_versions`, streaming the `items` of every page of results.
+    pub fn get_documentation_versions_items(
+        &self,
+        input: GetDocumentationVersionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DocumentationVersion, GetDocumentationVersionsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.get_documentation_versions_pages(input),
+            |output: DocumentationVersions| output.items.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_domain_names`, streaming every page of results.
+    pub fn get_domain_names_pages(
+        &self,
+        input: GetDomainNamesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DomainNames, GetDomainNamesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApiGatewayClient, input: GetDomainNamesRequest| async move {
+                client.get_domain_names(input).await
+            },
+            |input: &GetDomainNamesRequest, output: &DomainNames| {
+                Some(GetDomainNamesRequest {
+                    position: Some(output.position.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_domain_names`, streaming the `items` of every page of results.
+    pub fn get_domain_names_items(
+        &self,
+        input: GetDomainNamesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DomainName, GetDomainNamesError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_domain_names_pages(input),
+            |output: DomainNames| output.items.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_gateway_responses`, streaming every page of results.
+    pub fn get_gateway_responses_pages(
+        &self,
+        input: GetGatewayResponsesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GatewayResponses, GetGatewayResponsesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApiGatewayClient, input: GetGatewayResponsesRequest| async move {
+                client.get_gateway_responses(input).await
+            },
+            |input: &GetGatewayResponsesRequest, output: &GatewayResponses| {
+                Some(GetGatewayResponsesRequest {
+                    position: Some(output.position.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_gateway_responses`, streaming the `items` of every page of results.
+    pub fn get_gateway_responses_items(
+        &self,
+        input: GetGatewayResponsesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GatewayResponse, GetGatewayResponsesError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_gateway_responses_pages(input),
+            |output: GatewayResponses| output.items.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_models`, streaming every page of results.
+    pub fn get_models_pages(
+        &self,
+        input: GetModelsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Models, GetModelsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApiGatewayClient, input: GetModelsRequest| async move {
+                client.get_models(input).await
+            },
+            |input: &GetModelsRequest, output: &Models| {
+                Some(GetModelsRequest {
+                    position: Some(output.position.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_models`, streaming the `items` of every page of results.
+    pub fn get_models_items(
+        &self,
+        input: GetModelsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Model, GetModelsError> {
+        rusoto_core::pagination::paginate_items(self.get_models_pages(input), |output: Models| {
+            output.items.unwrap_or_default()
+        })
+    }
+
+    /// Auto-paginating version of `get_request_validators`, streaming every page of results.
+    pub fn get_request_validators_pages(
+        &self,
+        input: GetRequestValidatorsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<RequestValidators, GetRequestValidatorsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApiGatewayClient, input: GetRequestValidatorsRequest| async move {
+                client.get_request_validators(input).await
+            },
+            |input: &GetRequestValidatorsRequest, output: &RequestValidators| {
+                Some(GetRequestValidatorsRequest {
+                    position: Some(output.position.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_request_validators`, streaming the `items` of every page of results.
+    pub fn get_request_validators_items(
+        &self,
+        input: GetRequestValidatorsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<RequestValidator, GetRequestValidatorsError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_request_validators_pages(input),
+            |output: RequestValidators| output.items.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_resources`, streaming every page of results.
+    pub fn get_resources_pages(
+        &self,
+        input: GetResourcesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Resources, GetResourcesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApiGatewayClient, input: GetResourcesRequest| async move {
+                client.get_resources(input).await
+            },
+            |input: &GetResourcesRequest, output: &Resources| {
+                Some(GetResourcesRequest {
+                    position: Some(output.position.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_resources`, streaming the `items` of every page of results.
+    pub fn get_resources_items(
+        &self,
+        input: GetResourcesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Resource, GetResourcesError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_resources_pages(input),
+            |output: Resources| output.items.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_rest_apis`, streaming every page of results.
+    pub fn get_rest_apis_pages(
+        &self,
+        input: GetRestApisRequest,
+    ) -> rusoto_core::pagination::RusotoStream<RestApis, GetRestApisError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApiGatewayClient, input: GetRestApisRequest| async move {
+                client.get_rest_apis(input).await
+            },
+            |input: &GetRestApisRequest, output: &RestApis| {
+                Some(GetRestApisRequest {
+                    position: Some(output.position.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_rest_apis`, streaming the `items` of every page of results.
+    pub fn get_rest_apis_items(
+        &self,
+        input: GetRestApisRequest,
+    ) -> rusoto_core::pagination::RusotoStream<RestApi, GetRestApisError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_rest_apis_pages(input),
+            |output: RestApis| output.items.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_sdk_types`, streaming every page of results.
+    pub fn get_sdk_types_pages(
+        &self,
+        input: GetSdkTypesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<SdkTypes, GetSdkTypesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApiGatewayClient, input: GetSdkTypesRequest| async move {
+                client.get_sdk_types(input).await
+            },
+            |input: &GetSdkTypesRequest, output: &SdkTypes| {
+                Some(GetSdkTypesRequest {
+                    position: Some(output.position.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_sdk_types`, streaming the `items` of every page of results.
+    pub fn get_sdk_types_items(
+        &self,
+        input: GetSdkTypesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<SdkType, GetSdkTypesError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_sdk_types_pages(input),
+            |output: SdkTypes| output.items.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_usage`, streaming every page of results.
+    pub fn get_usage_pages(
+        &self,
+        input: GetUsageRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Usage, GetUsageError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApiGatewayClient, input: GetUsageRequest| async move {
+                client.get_usage(input).await
+            },
+            |input: &GetUsageRequest, output: &Usage| {
+                Some(GetUsageRequest {
+                    position: Some(output.position.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_usage_plan_keys`, streaming every page of results.
+    pub fn get_usage_plan_keys_pages(
+        &self,
+        input: GetUsagePlanKeysRequest,
+    ) -> rusoto_core::pagination::RusotoStream<UsagePlanKeys, GetUsagePlanKeysError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApiGatewayClient, input: GetUsagePlanKeysRequest| async move {
+                client.get_usage_plan_keys(input).await
+            },
+            |input: &GetUsagePlanKeysRequest, output: &UsagePlanKeys| {
+                Some(GetUsagePlanKeysRequest {
+                    position: Some(output.position.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_usage_plan_keys`, streaming the `items` of every page of results.
+    pub fn get_usage_plan_keys_items(
+        &self,
+        input: GetUsagePlanKeysRequest,
+    ) -> rusoto_core::pagination::RusotoStream<UsagePlanKey, GetUsagePlanKeysError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_usage_plan_keys_pages(input),
+            |output: UsagePlanKeys| output.items.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_usage_plans`, streaming every page of results.
+    pub fn get_usage_plans_pages(
+        &self,
+        input: GetUsagePlansRequest,
+    ) -> rusoto_core::pagination::RusotoStream<UsagePlans, GetUsagePlansError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApiGatewayClient, input: GetUsagePlansRequest| async move {
+                client.get_usage_plans(input).await
+            },
+            |input: &GetUsagePlansRequest, output: &UsagePlans| {
+                Some(GetUsagePlansRequest {
+                    position: Some(output.position.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_usage_plans`, streaming the `items` of every page of results.
+    pub fn get_usage_plans_items(
+        &self,
+        input: GetUsagePlansRequest,
+    ) -> rusoto_core::pagination::RusotoStream<UsagePlan, GetUsagePlansError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_usage_plans_pages(input),
+            |output: UsagePlans| output.items.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_vpc_links`, streaming every page of results.
+    pub fn get_vpc_links_pages(
+        &self,
+        input: GetVpcLinksRequest,
+    ) -> rusoto_core::pagination::RusotoStream<VpcLinks, GetVpcLinksError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApiGatewayClient, input: GetVpcLinksRequest| async move {
+                client.get_vpc_links(input).await
+            },
+            |input: &GetVpcLinksRequest, output: &VpcLinks| {
+                Some(GetVpcLinksRequest {
+                    position: Some(output.position.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_vpc_links`, streaming the `items` of every page of results.
+    pub fn get_vpc_links_items(
+        &self,
+        input: GetVpcLinksRequest,
+    ) -> rusoto_core::pagination::RusotoStream<VpcLink, GetVpcLinksError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_vpc_links_pages(input),
+            |output: VpcLinks| output.items.unwrap_or_default(),
+        )
+    }
+}
@@ -42,15 +42,25 @@ pub struct GetConnectionRequest {
 pub struct GetConnectionResponse {
     /// <p>The time in ISO 8601 format for when the connection was established.</p>
     #[serde(rename = "ConnectedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub connected_at: Option<f64>,
+    pub connected_at: Option<::chrono::DateTime<::chrono::Utc>>,
     #[serde(rename = "Identity")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub identity: Option<Identity>,
     /// <p>The time in ISO 8601 format for when the connection was last active.</p>
     #[serde(rename = "LastActiveAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_active_at: Option<f64>,
+    pub last_active_at: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -241,11 +251,15 @@ pub struct ApiGatewayManagementApiClient {
 impl ApiGatewayManagementApiClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> ApiGatewayManagementApiClient {
         ApiGatewayManagementApiClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(
+                region,
+                "ApiGatewayManagementApi",
+            ),
         }
     }
 
@@ -285,6 +299,7 @@ impl ApiGatewayManagementApi for ApiGatewayManagementApiClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DeleteConnection");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -314,6 +329,7 @@ impl ApiGatewayManagementApi for ApiGatewayManagementApiClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("GetConnection");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -344,6 +360,7 @@ impl ApiGatewayManagementApi for ApiGatewayManagementApiClient {
         );
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("PostToConnection");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(input.data.to_owned());
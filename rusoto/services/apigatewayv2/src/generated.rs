@@ -60,8 +60,13 @@ pub struct Api {
     pub cors_configuration: Option<Cors>,
     /// <p>The timestamp when the API was created.</p>
     #[serde(rename = "CreatedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The description of the API.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -79,7 +84,7 @@ pub struct Api {
     pub name: String,
     /// <p>The API protocol.</p>
     #[serde(rename = "ProtocolType")]
-    pub protocol_type: String,
+    pub protocol_type: ProtocolType,
     /// <p>The route selection expression for the API. For HTTP APIs, the routeSelectionExpression must be ${request.method} ${request.path}. If not provided, this will be the default for HTTP APIs. This property is required for WebSocket APIs.</p>
     #[serde(rename = "RouteSelectionExpression")]
     pub route_selection_expression: String,
@@ -117,6 +122,110 @@ pub struct ApiMapping {
     pub stage: String,
 }
 
+/// <p>The authorization type. For WebSocket APIs, valid values are NONE for open access, AWS_IAM for using AWS IAM permissions, and CUSTOM for using a Lambda authorizer. For HTTP APIs, valid values are NONE for open access, JWT for using JSON Web Tokens, AWS_IAM for using AWS IAM permissions, and CUSTOM for using a Lambda authorizer.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AuthorizationType {
+    /// `NONE`
+    None,
+    /// `AWS_IAM`
+    AwsIam,
+    /// `CUSTOM`
+    Custom,
+    /// `JWT`
+    Jwt,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AuthorizationType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AuthorizationType::None => "NONE",
+            AuthorizationType::AwsIam => "AWS_IAM",
+            AuthorizationType::Custom => "CUSTOM",
+            AuthorizationType::Jwt => "JWT",
+            AuthorizationType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AuthorizationType {
+    fn default() -> AuthorizationType {
+        AuthorizationType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AuthorizationType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AuthorizationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AuthorizationType {
+    fn from(value: &'a str) -> AuthorizationType {
+        match value {
+            "NONE" => AuthorizationType::None,
+            "AWS_IAM" => AuthorizationType::AwsIam,
+            "CUSTOM" => AuthorizationType::Custom,
+            "JWT" => AuthorizationType::Jwt,
+            _ => AuthorizationType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AuthorizationType {
+    fn from(value: String) -> AuthorizationType {
+        match value.as_str() {
+            "NONE" => AuthorizationType::None,
+            "AWS_IAM" => AuthorizationType::AwsIam,
+            "CUSTOM" => AuthorizationType::Custom,
+            "JWT" => AuthorizationType::Jwt,
+            _ => AuthorizationType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AuthorizationType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AuthorizationType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AuthorizationType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AuthorizationType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AuthorizationType {
+    fn deserialize<D>(deserializer: D) -> Result<AuthorizationType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents an authorizer.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -136,7 +245,7 @@ pub struct Authorizer {
     /// <p>The authorizer type. For WebSocket APIs, specify REQUEST for a Lambda function using incoming request parameters. For HTTP APIs, specify JWT to use JSON Web Tokens.</p>
     #[serde(rename = "AuthorizerType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub authorizer_type: Option<String>,
+    pub authorizer_type: Option<AuthorizerType>,
     /// <p>The authorizer's Uniform Resource Identifier (URI). ForREQUEST authorizers, this must be a well-formed Lambda function URI, for example, arn:aws:apigateway:us-west-2:lambda:path/2015-03-31/functions/arn:aws:lambda:us-west-2:<replaceable>{account_id}</replaceable>:function:<replaceable>{lambda_function_name}</replaceable>/invocations. In general, the URI has this form: arn:aws:apigateway:<replaceable>{region}</replaceable>:lambda:path/<replaceable>{service_api}</replaceable>
     /// , where <replaceable></replaceable>{region} is the same as the region hosting the Lambda function, path indicates that the remaining substring in the URI should be treated as the path to the resource, including the initial /. For Lambda functions, this is usually of the form /2015-03-31/functions/[FunctionARN]/invocations. Supported only for REQUEST authorizers.</p>
     #[serde(rename = "AuthorizerUri")]
@@ -159,6 +268,288 @@ pub struct Authorizer {
     pub name: String,
 }
 
+/// <p>The authorizer type. Specify REQUEST for a Lambda function using incoming request parameters. Specify JWT to use JSON Web Tokens (supported only for HTTP APIs).</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AuthorizerType {
+    /// `REQUEST`
+    Request,
+    /// `JWT`
+    Jwt,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AuthorizerType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AuthorizerType::Request => "REQUEST",
+            AuthorizerType::Jwt => "JWT",
+            AuthorizerType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AuthorizerType {
+    fn default() -> AuthorizerType {
+        AuthorizerType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AuthorizerType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AuthorizerType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AuthorizerType {
+    fn from(value: &'a str) -> AuthorizerType {
+        match value {
+            "REQUEST" => AuthorizerType::Request,
+            "JWT" => AuthorizerType::Jwt,
+            _ => AuthorizerType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AuthorizerType {
+    fn from(value: String) -> AuthorizerType {
+        match value.as_str() {
+            "REQUEST" => AuthorizerType::Request,
+            "JWT" => AuthorizerType::Jwt,
+            _ => AuthorizerType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AuthorizerType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AuthorizerType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AuthorizerType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AuthorizerType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AuthorizerType {
+    fn deserialize<D>(deserializer: D) -> Result<AuthorizerType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Represents a connection type.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ConnectionType {
+    /// `INTERNET`
+    Internet,
+    /// `VPC_LINK`
+    VpcLink,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ConnectionType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ConnectionType::Internet => "INTERNET",
+            ConnectionType::VpcLink => "VPC_LINK",
+            ConnectionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ConnectionType {
+    fn default() -> ConnectionType {
+        ConnectionType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ConnectionType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ConnectionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ConnectionType {
+    fn from(value: &'a str) -> ConnectionType {
+        match value {
+            "INTERNET" => ConnectionType::Internet,
+            "VPC_LINK" => ConnectionType::VpcLink,
+            _ => ConnectionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ConnectionType {
+    fn from(value: String) -> ConnectionType {
+        match value.as_str() {
+            "INTERNET" => ConnectionType::Internet,
+            "VPC_LINK" => ConnectionType::VpcLink,
+            _ => ConnectionType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ConnectionType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ConnectionType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ConnectionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ConnectionType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ConnectionType {
+    fn deserialize<D>(deserializer: D) -> Result<ConnectionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Specifies how to handle response payload content type conversions. Supported only for WebSocket APIs.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ContentHandlingStrategy {
+    /// `CONVERT_TO_BINARY`
+    ConvertToBinary,
+    /// `CONVERT_TO_TEXT`
+    ConvertToText,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ContentHandlingStrategy {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ContentHandlingStrategy::ConvertToBinary => "CONVERT_TO_BINARY",
+            ContentHandlingStrategy::ConvertToText => "CONVERT_TO_TEXT",
+            ContentHandlingStrategy::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ContentHandlingStrategy {
+    fn default() -> ContentHandlingStrategy {
+        ContentHandlingStrategy::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ContentHandlingStrategy {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ContentHandlingStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ContentHandlingStrategy {
+    fn from(value: &'a str) -> ContentHandlingStrategy {
+        match value {
+            "CONVERT_TO_BINARY" => ContentHandlingStrategy::ConvertToBinary,
+            "CONVERT_TO_TEXT" => ContentHandlingStrategy::ConvertToText,
+            _ => ContentHandlingStrategy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ContentHandlingStrategy {
+    fn from(value: String) -> ContentHandlingStrategy {
+        match value.as_str() {
+            "CONVERT_TO_BINARY" => ContentHandlingStrategy::ConvertToBinary,
+            "CONVERT_TO_TEXT" => ContentHandlingStrategy::ConvertToText,
+            _ => ContentHandlingStrategy::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ContentHandlingStrategy {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ContentHandlingStrategy, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ContentHandlingStrategy {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ContentHandlingStrategy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ContentHandlingStrategy {
+    fn deserialize<D>(deserializer: D) -> Result<ContentHandlingStrategy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents a CORS configuration. Supported only for HTTP APIs. See <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/http-api-cors.html">Configuring CORS</a> for more information.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Cors {
@@ -257,7 +648,7 @@ pub struct CreateApiRequest {
     pub name: String,
     /// <p>The API protocol.</p>
     #[serde(rename = "ProtocolType")]
-    pub protocol_type: String,
+    pub protocol_type: ProtocolType,
     /// <p>This property is part of quick create. If you don't specify a routeKey, a default route of $default is created. The $default route acts as a catch-all for any request made to your API, for a particular stage. The $default route key can't be modified. You can add routes after creating the API, and you can update the route keys of additional routes. Supported only for HTTP APIs.</p>
     #[serde(rename = "RouteKey")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -301,8 +692,13 @@ pub struct CreateApiResponse {
     pub cors_configuration: Option<Cors>,
     /// <p>The timestamp when the API was created.</p>
     #[serde(rename = "CreatedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The description of the API.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -322,7 +718,7 @@ pub struct CreateApiResponse {
     /// <p>The API protocol.</p>
     #[serde(rename = "ProtocolType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub protocol_type: Option<String>,
+    pub protocol_type: Option<ProtocolType>,
     /// <p>The route selection expression for the API. For HTTP APIs, the routeSelectionExpression must be ${request.method} ${request.path}. If not provided, this will be the default for HTTP APIs. This property is required for WebSocket APIs.</p>
     #[serde(rename = "RouteSelectionExpression")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -358,7 +754,7 @@ pub struct CreateAuthorizerRequest {
     pub authorizer_result_ttl_in_seconds: Option<i64>,
     /// <p>The authorizer type. For WebSocket APIs, specify REQUEST for a Lambda function using incoming request parameters. For HTTP APIs, specify JWT to use JSON Web Tokens.</p>
     #[serde(rename = "AuthorizerType")]
-    pub authorizer_type: String,
+    pub authorizer_type: AuthorizerType,
     /// <p>The authorizer's Uniform Resource Identifier (URI). For REQUEST authorizers, this must be a well-formed Lambda function URI, for example, arn:aws:apigateway:us-west-2:lambda:path/2015-03-31/functions/arn:aws:lambda:us-west-2:<replaceable>{account_id}</replaceable>:function:<replaceable>{lambda_function_name}</replaceable>/invocations. In general, the URI has this form: arn:aws:apigateway:<replaceable>{region}</replaceable>:lambda:path/<replaceable>{service_api}</replaceable>
     /// , where <replaceable></replaceable>{region} is the same as the region hosting the Lambda function, path indicates that the remaining substring in the URI should be treated as the path to the resource, including the initial /. For Lambda functions, this is usually of the form /2015-03-31/functions/[FunctionARN]/invocations. Supported only for REQUEST authorizers.</p>
     #[serde(rename = "AuthorizerUri")]
@@ -398,7 +794,7 @@ pub struct CreateAuthorizerResponse {
     /// <p>The authorizer type. For WebSocket APIs, specify REQUEST for a Lambda function using incoming request parameters. For HTTP APIs, specify JWT to use JSON Web Tokens.</p>
     #[serde(rename = "AuthorizerType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub authorizer_type: Option<String>,
+    pub authorizer_type: Option<AuthorizerType>,
     /// <p>The authorizer's Uniform Resource Identifier (URI). ForREQUEST authorizers, this must be a well-formed Lambda function URI, for example, arn:aws:apigateway:us-west-2:lambda:path/2015-03-31/functions/arn:aws:lambda:us-west-2:<replaceable>{account_id}</replaceable>:function:<replaceable>{lambda_function_name}</replaceable>/invocations. In general, the URI has this form: arn:aws:apigateway:<replaceable>{region}</replaceable>:lambda:path/<replaceable>{service_api}</replaceable>
     /// , where <replaceable></replaceable>{region} is the same as the region hosting the Lambda function, path indicates that the remaining substring in the URI should be treated as the path to the resource, including the initial /. For Lambda functions, this is usually of the form /2015-03-31/functions/[FunctionARN]/invocations. Supported only for REQUEST authorizers.</p>
     #[serde(rename = "AuthorizerUri")]
@@ -448,8 +844,13 @@ pub struct CreateDeploymentResponse {
     pub auto_deployed: Option<bool>,
     /// <p>The date and time when the Deployment resource was created.</p>
     #[serde(rename = "CreatedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The identifier for the deployment.</p>
     #[serde(rename = "DeploymentId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -457,7 +858,7 @@ pub struct CreateDeploymentResponse {
     /// <p>The status of the deployment: PENDING, FAILED, or SUCCEEDED.</p>
     #[serde(rename = "DeploymentStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deployment_status: Option<String>,
+    pub deployment_status: Option<DeploymentStatus>,
     /// <p>May contain additional feedback on the status of an API deployment.</p>
     #[serde(rename = "DeploymentStatusMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -520,11 +921,11 @@ pub struct CreateIntegrationRequest {
     /// <p>The type of the network connection to the integration endpoint. Currently the only valid value is INTERNET, for connections through the public routable internet.</p>
     #[serde(rename = "ConnectionType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub connection_type: Option<String>,
+    pub connection_type: Option<ConnectionType>,
     /// <p>Supported only for WebSocket APIs. Specifies how to handle response payload content type conversions. Supported values are CONVERT_TO_BINARY and CONVERT_TO_TEXT, with the following behaviors:</p> <p>CONVERT_TO_BINARY: Converts a response payload from a Base64-encoded string to the corresponding binary blob.</p> <p>CONVERT_TO_TEXT: Converts a response payload from a binary blob to a Base64-encoded string.</p> <p>If this property is not defined, the response payload will be passed through from the integration response to the route response or method response without modification.</p>
     #[serde(rename = "ContentHandlingStrategy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub content_handling_strategy: Option<String>,
+    pub content_handling_strategy: Option<ContentHandlingStrategy>,
     /// <p>Specifies the credentials required for the integration, if any. For AWS integrations, three options are available. To specify an IAM Role for API Gateway to assume, use the role's Amazon Resource Name (ARN). To require that the caller's identity be passed through from the request, specify the string arn:aws:iam::*:user/*. To use resource-based permissions on supported AWS services, specify null.</p>
     #[serde(rename = "CredentialsArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -539,7 +940,7 @@ pub struct CreateIntegrationRequest {
     pub integration_method: Option<String>,
     /// <p>The integration type of an integration. One of the following:</p> <p>AWS: for integrating the route or method request with an AWS service action, including the Lambda function-invoking action. With the Lambda function-invoking action, this is referred to as the Lambda custom integration. With any other AWS service action, this is known as AWS integration. Supported only for WebSocket APIs.</p> <p>AWS_PROXY: for integrating the route or method request with the Lambda function-invoking action with the client request passed through as-is. This integration is also referred to as Lambda proxy integration.</p> <p>HTTP: for integrating the route or method request with an HTTP endpoint. This integration is also referred to as the HTTP custom integration. Supported only for WebSocket APIs.</p> <p>HTTP_PROXY: for integrating route or method request with an HTTP endpoint, with the client request passed through as-is. This is also referred to as HTTP proxy integration.</p> <p>MOCK: for integrating the route or method request with API Gateway as a "loopback" endpoint without invoking any backend. Supported only for WebSocket APIs.</p>
     #[serde(rename = "IntegrationType")]
-    pub integration_type: String,
+    pub integration_type: IntegrationType,
     /// <p>For a Lambda proxy integration, this is the URI of the Lambda function.</p>
     #[serde(rename = "IntegrationUri")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -547,7 +948,7 @@ pub struct CreateIntegrationRequest {
     /// <p>Specifies the pass-through behavior for incoming requests based on the Content-Type header in the request, and the available mapping templates specified as the requestTemplates property on the Integration resource. There are three valid values: WHEN_NO_MATCH, WHEN_NO_TEMPLATES, and NEVER. Supported only for WebSocket APIs.</p> <p>WHEN_NO_MATCH passes the request body for unmapped content types through to the integration backend without transformation.</p> <p>NEVER rejects unmapped content types with an HTTP 415 Unsupported Media Type response.</p> <p>WHEN_NO_TEMPLATES allows pass-through when the integration has no content types mapped to templates. However, if there is at least one content type defined, unmapped content types will be rejected with the same HTTP 415 Unsupported Media Type response.</p>
     #[serde(rename = "PassthroughBehavior")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub passthrough_behavior: Option<String>,
+    pub passthrough_behavior: Option<PassthroughBehavior>,
     /// <p>Specifies the format of the payload sent to an integration. Required for HTTP APIs. Currently, the only supported value is 1.0.</p>
     #[serde(rename = "PayloadFormatVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -585,7 +986,7 @@ pub struct CreateIntegrationResponseRequest {
     /// <p>Specifies how to handle response payload content type conversions. Supported values are CONVERT_TO_BINARY and CONVERT_TO_TEXT, with the following behaviors:</p> <p>CONVERT_TO_BINARY: Converts a response payload from a Base64-encoded string to the corresponding binary blob.</p> <p>CONVERT_TO_TEXT: Converts a response payload from a binary blob to a Base64-encoded string.</p> <p>If this property is not defined, the response payload will be passed through from the integration response to the route response or method response without modification.</p>
     #[serde(rename = "ContentHandlingStrategy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub content_handling_strategy: Option<String>,
+    pub content_handling_strategy: Option<ContentHandlingStrategy>,
     /// <p>The integration ID.</p>
     #[serde(rename = "IntegrationId")]
     pub integration_id: String,
@@ -612,7 +1013,7 @@ pub struct CreateIntegrationResponseResponse {
     /// <p>Supported only for WebSocket APIs. Specifies how to handle response payload content type conversions. Supported values are CONVERT_TO_BINARY and CONVERT_TO_TEXT, with the following behaviors:</p> <p>CONVERT_TO_BINARY: Converts a response payload from a Base64-encoded string to the corresponding binary blob.</p> <p>CONVERT_TO_TEXT: Converts a response payload from a binary blob to a Base64-encoded string.</p> <p>If this property is not defined, the response payload will be passed through from the integration response to the route response or method response without modification.</p>
     #[serde(rename = "ContentHandlingStrategy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub content_handling_strategy: Option<String>,
+    pub content_handling_strategy: Option<ContentHandlingStrategy>,
     /// <p>The integration response ID.</p>
     #[serde(rename = "IntegrationResponseId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -649,11 +1050,11 @@ pub struct CreateIntegrationResult {
     /// <p>The type of the network connection to the integration endpoint. Currently the only valid value is INTERNET, for connections through the public routable internet.</p>
     #[serde(rename = "ConnectionType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub connection_type: Option<String>,
+    pub connection_type: Option<ConnectionType>,
     /// <p>Supported only for WebSocket APIs. Specifies how to handle response payload content type conversions. Supported values are CONVERT_TO_BINARY and CONVERT_TO_TEXT, with the following behaviors:</p> <p>CONVERT_TO_BINARY: Converts a response payload from a Base64-encoded string to the corresponding binary blob.</p> <p>CONVERT_TO_TEXT: Converts a response payload from a binary blob to a Base64-encoded string.</p> <p>If this property is not defined, the response payload will be passed through from the integration response to the route response or method response without modification.</p>
     #[serde(rename = "ContentHandlingStrategy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub content_handling_strategy: Option<String>,
+    pub content_handling_strategy: Option<ContentHandlingStrategy>,
     /// <p>Specifies the credentials required for the integration, if any. For AWS integrations, three options are available. To specify an IAM Role for API Gateway to assume, use the role's Amazon Resource Name (ARN). To require that the caller's identity be passed through from the request, specify the string arn:aws:iam::*:user/*. To use resource-based permissions on supported AWS services, specify null.</p>
     #[serde(rename = "CredentialsArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -677,7 +1078,7 @@ pub struct CreateIntegrationResult {
     /// <p>The integration type of an integration. One of the following:</p> <p>AWS: for integrating the route or method request with an AWS service action, including the Lambda function-invoking action. With the Lambda function-invoking action, this is referred to as the Lambda custom integration. With any other AWS service action, this is known as AWS integration. Supported only for WebSocket APIs.</p> <p>AWS_PROXY: for integrating the route or method request with the Lambda function-invoking action with the client request passed through as-is. This integration is also referred to as Lambda proxy integration.</p> <p>HTTP: for integrating the route or method request with an HTTP endpoint. This integration is also referred to as the HTTP custom integration. Supported only for WebSocket APIs.</p> <p>HTTP_PROXY: for integrating route or method request with an HTTP endpoint, with the client request passed through as-is. This is also referred to as HTTP proxy integration.</p> <p>MOCK: for integrating the route or method request with API Gateway as a "loopback" endpoint without invoking any backend. Supported only for WebSocket APIs.</p>
     #[serde(rename = "IntegrationType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub integration_type: Option<String>,
+    pub integration_type: Option<IntegrationType>,
     /// <p>For a Lambda proxy integration, this is the URI of the Lambda function.</p>
     #[serde(rename = "IntegrationUri")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -685,7 +1086,7 @@ pub struct CreateIntegrationResult {
     /// <p>Specifies the pass-through behavior for incoming requests based on the Content-Type header in the request, and the available mapping templates specified as the requestTemplates property on the Integration resource. There are three valid values: WHEN_NO_MATCH, WHEN_NO_TEMPLATES, and NEVER. Supported only for WebSocket APIs.</p> <p>WHEN_NO_MATCH passes the request body for unmapped content types through to the integration backend without transformation.</p> <p>NEVER rejects unmapped content types with an HTTP 415 Unsupported Media Type response.</p> <p>WHEN_NO_TEMPLATES allows pass-through when the integration has no content types mapped to templates. However, if there is at least one content type defined, unmapped content types will be rejected with the same HTTP 415 Unsupported Media Type response.</p>
     #[serde(rename = "PassthroughBehavior")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub passthrough_behavior: Option<String>,
+    pub passthrough_behavior: Option<PassthroughBehavior>,
     /// <p>Specifies the format of the payload sent to an integration. Required for HTTP APIs. Currently, the only supported value is 1.0.</p>
     #[serde(rename = "PayloadFormatVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -779,7 +1180,7 @@ pub struct CreateRouteRequest {
     /// <p>The authorization type for the route. For WebSocket APIs, valid values are NONE for open access, AWS_IAM for using AWS IAM permissions, and CUSTOM for using a Lambda authorizer For HTTP APIs, valid values are NONE for open access, or JWT for using JSON Web Tokens.</p>
     #[serde(rename = "AuthorizationType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub authorization_type: Option<String>,
+    pub authorization_type: Option<AuthorizationType>,
     /// <p>The identifier of the Authorizer resource to be associated with this route. The authorizer identifier is generated by API Gateway when you created the authorizer.</p>
     #[serde(rename = "AuthorizerId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -883,7 +1284,7 @@ pub struct CreateRouteResult {
     /// <p>The authorization type for the route. For WebSocket APIs, valid values are NONE for open access, AWS_IAM for using AWS IAM permissions, and CUSTOM for using a Lambda authorizer For HTTP APIs, valid values are NONE for open access, or JWT for using JSON Web Tokens.</p>
     #[serde(rename = "AuthorizationType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub authorization_type: Option<String>,
+    pub authorization_type: Option<AuthorizationType>,
     /// <p>The identifier of the Authorizer resource to be associated with this route. The authorizer identifier is generated by API Gateway when you created the authorizer.</p>
     #[serde(rename = "AuthorizerId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -991,8 +1392,13 @@ pub struct CreateStageResponse {
     pub client_certificate_id: Option<String>,
     /// <p>The timestamp when the stage was created.</p>
     #[serde(rename = "CreatedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Default route settings for the stage.</p>
     #[serde(rename = "DefaultRouteSettings")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1011,8 +1417,13 @@ pub struct CreateStageResponse {
     pub last_deployment_status_message: Option<String>,
     /// <p>The timestamp when the stage was last updated.</p>
     #[serde(rename = "LastUpdatedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date: Option<f64>,
+    pub last_updated_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Route settings for the stage, by routeKey.</p>
     #[serde(rename = "RouteSettings")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1184,8 +1595,13 @@ pub struct Deployment {
     pub auto_deployed: Option<bool>,
     /// <p>The date and time when the Deployment resource was created.</p>
     #[serde(rename = "CreatedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The identifier for the deployment.</p>
     #[serde(rename = "DeploymentId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1193,7 +1609,7 @@ pub struct Deployment {
     /// <p>The status of the deployment: PENDING, FAILED, or SUCCEEDED.</p>
     #[serde(rename = "DeploymentStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deployment_status: Option<String>,
+    pub deployment_status: Option<DeploymentStatus>,
     /// <p>May contain additional feedback on the status of an API deployment.</p>
     #[serde(rename = "DeploymentStatusMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1204,6 +1620,105 @@ pub struct Deployment {
     pub description: Option<String>,
 }
 
+/// <p>Represents a deployment status.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DeploymentStatus {
+    /// `PENDING`
+    Pending,
+    /// `FAILED`
+    Failed,
+    /// `DEPLOYED`
+    Deployed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DeploymentStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeploymentStatus::Pending => "PENDING",
+            DeploymentStatus::Failed => "FAILED",
+            DeploymentStatus::Deployed => "DEPLOYED",
+            DeploymentStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DeploymentStatus {
+    fn default() -> DeploymentStatus {
+        DeploymentStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DeploymentStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DeploymentStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DeploymentStatus {
+    fn from(value: &'a str) -> DeploymentStatus {
+        match value {
+            "PENDING" => DeploymentStatus::Pending,
+            "FAILED" => DeploymentStatus::Failed,
+            "DEPLOYED" => DeploymentStatus::Deployed,
+            _ => DeploymentStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeploymentStatus {
+    fn from(value: String) -> DeploymentStatus {
+        match value.as_str() {
+            "PENDING" => DeploymentStatus::Pending,
+            "FAILED" => DeploymentStatus::Failed,
+            "DEPLOYED" => DeploymentStatus::Deployed,
+            _ => DeploymentStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DeploymentStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DeploymentStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DeploymentStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DeploymentStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeploymentStatus {
+    fn deserialize<D>(deserializer: D) -> Result<DeploymentStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents a domain name.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1242,12 +1757,17 @@ pub struct DomainNameConfiguration {
     pub certificate_name: Option<String>,
     /// <p>The timestamp when the certificate that was used by edge-optimized endpoint for this domain name was uploaded.</p>
     #[serde(rename = "CertificateUploadDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub certificate_upload_date: Option<f64>,
+    pub certificate_upload_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The status of the domain name migration. The valid values are AVAILABLE and UPDATING. If the status is UPDATING, the domain cannot be modified further until the existing operation is complete. If it is AVAILABLE, the domain can be updated.</p>
     #[serde(rename = "DomainNameStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub domain_name_status: Option<String>,
+    pub domain_name_status: Option<DomainNameStatus>,
     /// <p>An optional text message containing detailed information about status of the domain name migration.</p>
     #[serde(rename = "DomainNameStatusMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1255,7 +1775,7 @@ pub struct DomainNameConfiguration {
     /// <p>The endpoint type.</p>
     #[serde(rename = "EndpointType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub endpoint_type: Option<String>,
+    pub endpoint_type: Option<EndpointType>,
     /// <p>The Amazon Route 53 Hosted Zone ID of the endpoint.</p>
     #[serde(rename = "HostedZoneId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1263,9 +1783,207 @@ pub struct DomainNameConfiguration {
     /// <p>The Transport Layer Security (TLS) version of the security policy for this domain name. The valid values are TLS_1_0 and TLS_1_2.</p>
     #[serde(rename = "SecurityPolicy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub security_policy: Option<String>,
+    pub security_policy: Option<SecurityPolicy>,
+}
+
+/// <p>The status of the domain name migration. The valid values are AVAILABLE, UPDATING, PENDING_CERTIFICATE_REIMPORT, and PENDING_OWNERSHIP_VERIFICATION. If the status is UPDATING, the domain cannot be modified further until the existing operation is complete. If it is AVAILABLE, the domain can be updated.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DomainNameStatus {
+    /// `AVAILABLE`
+    Available,
+    /// `UPDATING`
+    Updating,
+    /// `PENDING_CERTIFICATE_REIMPORT`
+    PendingCertificateReimport,
+    /// `PENDING_OWNERSHIP_VERIFICATION`
+    PendingOwnershipVerification,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DomainNameStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DomainNameStatus::Available => "AVAILABLE",
+            DomainNameStatus::Updating => "UPDATING",
+            DomainNameStatus::PendingCertificateReimport => "PENDING_CERTIFICATE_REIMPORT",
+            DomainNameStatus::PendingOwnershipVerification => "PENDING_OWNERSHIP_VERIFICATION",
+            DomainNameStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DomainNameStatus {
+    fn default() -> DomainNameStatus {
+        DomainNameStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DomainNameStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DomainNameStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DomainNameStatus {
+    fn from(value: &'a str) -> DomainNameStatus {
+        match value {
+            "AVAILABLE" => DomainNameStatus::Available,
+            "UPDATING" => DomainNameStatus::Updating,
+            "PENDING_CERTIFICATE_REIMPORT" => DomainNameStatus::PendingCertificateReimport,
+            "PENDING_OWNERSHIP_VERIFICATION" => DomainNameStatus::PendingOwnershipVerification,
+            _ => DomainNameStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DomainNameStatus {
+    fn from(value: String) -> DomainNameStatus {
+        match value.as_str() {
+            "AVAILABLE" => DomainNameStatus::Available,
+            "UPDATING" => DomainNameStatus::Updating,
+            "PENDING_CERTIFICATE_REIMPORT" => DomainNameStatus::PendingCertificateReimport,
+            "PENDING_OWNERSHIP_VERIFICATION" => DomainNameStatus::PendingOwnershipVerification,
+            _ => DomainNameStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DomainNameStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DomainNameStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DomainNameStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DomainNameStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DomainNameStatus {
+    fn deserialize<D>(deserializer: D) -> Result<DomainNameStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Represents an endpoint type.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EndpointType {
+    /// `REGIONAL`
+    Regional,
+    /// `EDGE`
+    Edge,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EndpointType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EndpointType::Regional => "REGIONAL",
+            EndpointType::Edge => "EDGE",
+            EndpointType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EndpointType {
+    fn default() -> EndpointType {
+        EndpointType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EndpointType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EndpointType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EndpointType {
+    fn from(value: &'a str) -> EndpointType {
+        match value {
+            "REGIONAL" => EndpointType::Regional,
+            "EDGE" => EndpointType::Edge,
+            _ => EndpointType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EndpointType {
+    fn from(value: String) -> EndpointType {
+        match value.as_str() {
+            "REGIONAL" => EndpointType::Regional,
+            "EDGE" => EndpointType::Edge,
+            _ => EndpointType::Unknown(value),
+        }
+    }
 }
 
+impl ::std::str::FromStr for EndpointType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EndpointType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EndpointType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EndpointType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EndpointType {
+    fn deserialize<D>(deserializer: D) -> Result<EndpointType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct GetApiMappingRequest {
@@ -1356,8 +2074,13 @@ pub struct GetApiResponse {
     pub cors_configuration: Option<Cors>,
     /// <p>The timestamp when the API was created.</p>
     #[serde(rename = "CreatedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The description of the API.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1377,7 +2100,7 @@ pub struct GetApiResponse {
     /// <p>The API protocol.</p>
     #[serde(rename = "ProtocolType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub protocol_type: Option<String>,
+    pub protocol_type: Option<ProtocolType>,
     /// <p>The route selection expression for the API. For HTTP APIs, the routeSelectionExpression must be ${request.method} ${request.path}. If not provided, this will be the default for HTTP APIs. This property is required for WebSocket APIs.</p>
     #[serde(rename = "RouteSelectionExpression")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1451,7 +2174,7 @@ pub struct GetAuthorizerResponse {
     /// <p>The authorizer type. For WebSocket APIs, specify REQUEST for a Lambda function using incoming request parameters. For HTTP APIs, specify JWT to use JSON Web Tokens.</p>
     #[serde(rename = "AuthorizerType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub authorizer_type: Option<String>,
+    pub authorizer_type: Option<AuthorizerType>,
     /// <p>The authorizer's Uniform Resource Identifier (URI). ForREQUEST authorizers, this must be a well-formed Lambda function URI, for example, arn:aws:apigateway:us-west-2:lambda:path/2015-03-31/functions/arn:aws:lambda:us-west-2:<replaceable>{account_id}</replaceable>:function:<replaceable>{lambda_function_name}</replaceable>/invocations. In general, the URI has this form: arn:aws:apigateway:<replaceable>{region}</replaceable>:lambda:path/<replaceable>{service_api}</replaceable>
     /// , where <replaceable></replaceable>{region} is the same as the region hosting the Lambda function, path indicates that the remaining substring in the URI should be treated as the path to the resource, including the initial /. For Lambda functions, this is usually of the form /2015-03-31/functions/[FunctionARN]/invocations. Supported only for REQUEST authorizers.</p>
     #[serde(rename = "AuthorizerUri")]
@@ -1524,8 +2247,13 @@ pub struct GetDeploymentResponse {
     pub auto_deployed: Option<bool>,
     /// <p>The date and time when the Deployment resource was created.</p>
     #[serde(rename = "CreatedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The identifier for the deployment.</p>
     #[serde(rename = "DeploymentId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1533,7 +2261,7 @@ pub struct GetDeploymentResponse {
     /// <p>The status of the deployment: PENDING, FAILED, or SUCCEEDED.</p>
     #[serde(rename = "DeploymentStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deployment_status: Option<String>,
+    pub deployment_status: Option<DeploymentStatus>,
     /// <p>May contain additional feedback on the status of an API deployment.</p>
     #[serde(rename = "DeploymentStatusMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1659,7 +2387,7 @@ pub struct GetIntegrationResponseResponse {
     /// <p>Supported only for WebSocket APIs. Specifies how to handle response payload content type conversions. Supported values are CONVERT_TO_BINARY and CONVERT_TO_TEXT, with the following behaviors:</p> <p>CONVERT_TO_BINARY: Converts a response payload from a Base64-encoded string to the corresponding binary blob.</p> <p>CONVERT_TO_TEXT: Converts a response payload from a binary blob to a Base64-encoded string.</p> <p>If this property is not defined, the response payload will be passed through from the integration response to the route response or method response without modification.</p>
     #[serde(rename = "ContentHandlingStrategy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub content_handling_strategy: Option<String>,
+    pub content_handling_strategy: Option<ContentHandlingStrategy>,
     /// <p>The integration response ID.</p>
     #[serde(rename = "IntegrationResponseId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1728,11 +2456,11 @@ pub struct GetIntegrationResult {
     /// <p>The type of the network connection to the integration endpoint. Currently the only valid value is INTERNET, for connections through the public routable internet.</p>
     #[serde(rename = "ConnectionType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub connection_type: Option<String>,
+    pub connection_type: Option<ConnectionType>,
     /// <p>Supported only for WebSocket APIs. Specifies how to handle response payload content type conversions. Supported values are CONVERT_TO_BINARY and CONVERT_TO_TEXT, with the following behaviors:</p> <p>CONVERT_TO_BINARY: Converts a response payload from a Base64-encoded string to the corresponding binary blob.</p> <p>CONVERT_TO_TEXT: Converts a response payload from a binary blob to a Base64-encoded string.</p> <p>If this property is not defined, the response payload will be passed through from the integration response to the route response or method response without modification.</p>
     #[serde(rename = "ContentHandlingStrategy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub content_handling_strategy: Option<String>,
+    pub content_handling_strategy: Option<ContentHandlingStrategy>,
     /// <p>Specifies the credentials required for the integration, if any. For AWS integrations, three options are available. To specify an IAM Role for API Gateway to assume, use the role's Amazon Resource Name (ARN). To require that the caller's identity be passed through from the request, specify the string arn:aws:iam::*:user/*. To use resource-based permissions on supported AWS services, specify null.</p>
     #[serde(rename = "CredentialsArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1756,7 +2484,7 @@ pub struct GetIntegrationResult {
     /// <p>The integration type of an integration. One of the following:</p> <p>AWS: for integrating the route or method request with an AWS service action, including the Lambda function-invoking action. With the Lambda function-invoking action, this is referred to as the Lambda custom integration. With any other AWS service action, this is known as AWS integration. Supported only for WebSocket APIs.</p> <p>AWS_PROXY: for integrating the route or method request with the Lambda function-invoking action with the client request passed through as-is. This integration is also referred to as Lambda proxy integration.</p> <p>HTTP: for integrating the route or method request with an HTTP endpoint. This integration is also referred to as the HTTP custom integration. Supported only for WebSocket APIs.</p> <p>HTTP_PROXY: for integrating route or method request with an HTTP endpoint, with the client request passed through as-is. This is also referred to as HTTP proxy integration.</p> <p>MOCK: for integrating the route or method request with API Gateway as a "loopback" endpoint without invoking any backend. Supported only for WebSocket APIs.</p>
     #[serde(rename = "IntegrationType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub integration_type: Option<String>,
+    pub integration_type: Option<IntegrationType>,
     /// <p>For a Lambda proxy integration, this is the URI of the Lambda function.</p>
     #[serde(rename = "IntegrationUri")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1764,7 +2492,7 @@ pub struct GetIntegrationResult {
     /// <p>Specifies the pass-through behavior for incoming requests based on the Content-Type header in the request, and the available mapping templates specified as the requestTemplates property on the Integration resource. There are three valid values: WHEN_NO_MATCH, WHEN_NO_TEMPLATES, and NEVER. Supported only for WebSocket APIs.</p> <p>WHEN_NO_MATCH passes the request body for unmapped content types through to the integration backend without transformation.</p> <p>NEVER rejects unmapped content types with an HTTP 415 Unsupported Media Type response.</p> <p>WHEN_NO_TEMPLATES allows pass-through when the integration has no content types mapped to templates. However, if there is at least one content type defined, unmapped content types will be rejected with the same HTTP 415 Unsupported Media Type response.</p>
     #[serde(rename = "PassthroughBehavior")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub passthrough_behavior: Option<String>,
+    pub passthrough_behavior: Option<PassthroughBehavior>,
     /// <p>Specifies the format of the payload sent to an integration. Required for HTTP APIs. Currently, the only supported value is 1.0.</p>
     #[serde(rename = "PayloadFormatVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2006,7 +2734,7 @@ pub struct GetRouteResult {
     /// <p>The authorization type for the route. For WebSocket APIs, valid values are NONE for open access, AWS_IAM for using AWS IAM permissions, and CUSTOM for using a Lambda authorizer For HTTP APIs, valid values are NONE for open access, or JWT for using JSON Web Tokens.</p>
     #[serde(rename = "AuthorizationType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub authorization_type: Option<String>,
+    pub authorization_type: Option<AuthorizationType>,
     /// <p>The identifier of the Authorizer resource to be associated with this route. The authorizer identifier is generated by API Gateway when you created the authorizer.</p>
     #[serde(rename = "AuthorizerId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2106,8 +2834,13 @@ pub struct GetStageResponse {
     pub client_certificate_id: Option<String>,
     /// <p>The timestamp when the stage was created.</p>
     #[serde(rename = "CreatedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Default route settings for the stage.</p>
     #[serde(rename = "DefaultRouteSettings")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2126,8 +2859,13 @@ pub struct GetStageResponse {
     pub last_deployment_status_message: Option<String>,
     /// <p>The timestamp when the stage was last updated.</p>
     #[serde(rename = "LastUpdatedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date: Option<f64>,
+    pub last_updated_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Route settings for the stage, by routeKey.</p>
     #[serde(rename = "RouteSettings")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2228,8 +2966,13 @@ pub struct ImportApiResponse {
     pub cors_configuration: Option<Cors>,
     /// <p>The timestamp when the API was created.</p>
     #[serde(rename = "CreatedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The description of the API.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2249,7 +2992,7 @@ pub struct ImportApiResponse {
     /// <p>The API protocol.</p>
     #[serde(rename = "ProtocolType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub protocol_type: Option<String>,
+    pub protocol_type: Option<ProtocolType>,
     /// <p>The route selection expression for the API. For HTTP APIs, the routeSelectionExpression must be ${request.method} ${request.path}. If not provided, this will be the default for HTTP APIs. This property is required for WebSocket APIs.</p>
     #[serde(rename = "RouteSelectionExpression")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2283,11 +3026,11 @@ pub struct Integration {
     /// <p>The type of the network connection to the integration endpoint. Currently the only valid value is INTERNET, for connections through the public routable internet.</p>
     #[serde(rename = "ConnectionType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub connection_type: Option<String>,
+    pub connection_type: Option<ConnectionType>,
     /// <p>Supported only for WebSocket APIs. Specifies how to handle response payload content type conversions. Supported values are CONVERT_TO_BINARY and CONVERT_TO_TEXT, with the following behaviors:</p> <p>CONVERT_TO_BINARY: Converts a response payload from a Base64-encoded string to the corresponding binary blob.</p> <p>CONVERT_TO_TEXT: Converts a response payload from a binary blob to a Base64-encoded string.</p> <p>If this property is not defined, the response payload will be passed through from the integration response to the route response or method response without modification.</p>
     #[serde(rename = "ContentHandlingStrategy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub content_handling_strategy: Option<String>,
+    pub content_handling_strategy: Option<ContentHandlingStrategy>,
     /// <p>Specifies the credentials required for the integration, if any. For AWS integrations, three options are available. To specify an IAM Role for API Gateway to assume, use the role's Amazon Resource Name (ARN). To require that the caller's identity be passed through from the request, specify the string arn:aws:iam::*:user/*. To use resource-based permissions on supported AWS services, specify null.</p>
     #[serde(rename = "CredentialsArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2311,7 +3054,7 @@ pub struct Integration {
     /// <p>The integration type of an integration. One of the following:</p> <p>AWS: for integrating the route or method request with an AWS service action, including the Lambda function-invoking action. With the Lambda function-invoking action, this is referred to as the Lambda custom integration. With any other AWS service action, this is known as AWS integration. Supported only for WebSocket APIs.</p> <p>AWS_PROXY: for integrating the route or method request with the Lambda function-invoking action with the client request passed through as-is. This integration is also referred to as Lambda proxy integration.</p> <p>HTTP: for integrating the route or method request with an HTTP endpoint. This integration is also referred to as the HTTP custom integration. Supported only for WebSocket APIs.</p> <p>HTTP_PROXY: for integrating route or method request with an HTTP endpoint, with the client request passed through as-is. This is also referred to as HTTP proxy integration.</p> <p>MOCK: for integrating the route or method request with API Gateway as a "loopback" endpoint without invoking any backend. Supported only for WebSocket APIs.</p>
     #[serde(rename = "IntegrationType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub integration_type: Option<String>,
+    pub integration_type: Option<IntegrationType>,
     /// <p>For a Lambda proxy integration, this is the URI of the Lambda function.</p>
     #[serde(rename = "IntegrationUri")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2319,7 +3062,7 @@ pub struct Integration {
     /// <p>Specifies the pass-through behavior for incoming requests based on the Content-Type header in the request, and the available mapping templates specified as the requestTemplates property on the Integration resource. There are three valid values: WHEN_NO_MATCH, WHEN_NO_TEMPLATES, and NEVER. Supported only for WebSocket APIs.</p> <p>WHEN_NO_MATCH passes the request body for unmapped content types through to the integration backend without transformation.</p> <p>NEVER rejects unmapped content types with an HTTP 415 Unsupported Media Type response.</p> <p>WHEN_NO_TEMPLATES allows pass-through when the integration has no content types mapped to templates. However, if there is at least one content type defined, unmapped content types will be rejected with the same HTTP 415 Unsupported Media Type response.</p>
     #[serde(rename = "PassthroughBehavior")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub passthrough_behavior: Option<String>,
+    pub passthrough_behavior: Option<PassthroughBehavior>,
     /// <p>Specifies the format of the payload sent to an integration. Required for HTTP APIs. Currently, the only supported value is 1.0.</p>
     #[serde(rename = "PayloadFormatVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2354,7 +3097,7 @@ pub struct IntegrationResponse {
     /// <p>Supported only for WebSocket APIs. Specifies how to handle response payload content type conversions. Supported values are CONVERT_TO_BINARY and CONVERT_TO_TEXT, with the following behaviors:</p> <p>CONVERT_TO_BINARY: Converts a response payload from a Base64-encoded string to the corresponding binary blob.</p> <p>CONVERT_TO_TEXT: Converts a response payload from a binary blob to a Base64-encoded string.</p> <p>If this property is not defined, the response payload will be passed through from the integration response to the route response or method response without modification.</p>
     #[serde(rename = "ContentHandlingStrategy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub content_handling_strategy: Option<String>,
+    pub content_handling_strategy: Option<ContentHandlingStrategy>,
     /// <p>The integration response ID.</p>
     #[serde(rename = "IntegrationResponseId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2376,6 +3119,115 @@ pub struct IntegrationResponse {
     pub template_selection_expression: Option<String>,
 }
 
+/// <p>Represents an API method integration type.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum IntegrationType {
+    /// `AWS`
+    Aws,
+    /// `HTTP`
+    Http,
+    /// `MOCK`
+    Mock,
+    /// `HTTP_PROXY`
+    HttpProxy,
+    /// `AWS_PROXY`
+    AwsProxy,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl IntegrationType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            IntegrationType::Aws => "AWS",
+            IntegrationType::Http => "HTTP",
+            IntegrationType::Mock => "MOCK",
+            IntegrationType::HttpProxy => "HTTP_PROXY",
+            IntegrationType::AwsProxy => "AWS_PROXY",
+            IntegrationType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for IntegrationType {
+    fn default() -> IntegrationType {
+        IntegrationType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for IntegrationType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for IntegrationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for IntegrationType {
+    fn from(value: &'a str) -> IntegrationType {
+        match value {
+            "AWS" => IntegrationType::Aws,
+            "HTTP" => IntegrationType::Http,
+            "MOCK" => IntegrationType::Mock,
+            "HTTP_PROXY" => IntegrationType::HttpProxy,
+            "AWS_PROXY" => IntegrationType::AwsProxy,
+            _ => IntegrationType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for IntegrationType {
+    fn from(value: String) -> IntegrationType {
+        match value.as_str() {
+            "AWS" => IntegrationType::Aws,
+            "HTTP" => IntegrationType::Http,
+            "MOCK" => IntegrationType::Mock,
+            "HTTP_PROXY" => IntegrationType::HttpProxy,
+            "AWS_PROXY" => IntegrationType::AwsProxy,
+            _ => IntegrationType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for IntegrationType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<IntegrationType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for IntegrationType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for IntegrationType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for IntegrationType {
+    fn deserialize<D>(deserializer: D) -> Result<IntegrationType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents the configuration of a JWT authorizer. Required for the JWT authorizer type. Supported only for HTTP APIs.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct JWTConfiguration {
@@ -2390,6 +3242,105 @@ pub struct JWTConfiguration {
     pub issuer: Option<String>,
 }
 
+/// <p>The logging level.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LoggingLevel {
+    /// `ERROR`
+    Error,
+    /// `INFO`
+    Info,
+    /// `OFF`
+    Off,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LoggingLevel {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LoggingLevel::Error => "ERROR",
+            LoggingLevel::Info => "INFO",
+            LoggingLevel::Off => "OFF",
+            LoggingLevel::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LoggingLevel {
+    fn default() -> LoggingLevel {
+        LoggingLevel::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LoggingLevel {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LoggingLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LoggingLevel {
+    fn from(value: &'a str) -> LoggingLevel {
+        match value {
+            "ERROR" => LoggingLevel::Error,
+            "INFO" => LoggingLevel::Info,
+            "OFF" => LoggingLevel::Off,
+            _ => LoggingLevel::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LoggingLevel {
+    fn from(value: String) -> LoggingLevel {
+        match value.as_str() {
+            "ERROR" => LoggingLevel::Error,
+            "INFO" => LoggingLevel::Info,
+            "OFF" => LoggingLevel::Off,
+            _ => LoggingLevel::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LoggingLevel {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LoggingLevel, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LoggingLevel {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LoggingLevel {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LoggingLevel {
+    fn deserialize<D>(deserializer: D) -> Result<LoggingLevel, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents a data model for an API. Supported only for WebSocket APIs. See <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/models-mappings.html">Create Models and Mapping Templates for Request and Response Mappings</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -2424,6 +3375,199 @@ pub struct ParameterConstraints {
     pub required: Option<bool>,
 }
 
+/// <p>Represents passthrough behavior for an integration response. Supported only for WebSocket APIs.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PassthroughBehavior {
+    /// `WHEN_NO_MATCH`
+    WhenNoMatch,
+    /// `NEVER`
+    Never,
+    /// `WHEN_NO_TEMPLATES`
+    WhenNoTemplates,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PassthroughBehavior {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PassthroughBehavior::WhenNoMatch => "WHEN_NO_MATCH",
+            PassthroughBehavior::Never => "NEVER",
+            PassthroughBehavior::WhenNoTemplates => "WHEN_NO_TEMPLATES",
+            PassthroughBehavior::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PassthroughBehavior {
+    fn default() -> PassthroughBehavior {
+        PassthroughBehavior::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PassthroughBehavior {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PassthroughBehavior {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PassthroughBehavior {
+    fn from(value: &'a str) -> PassthroughBehavior {
+        match value {
+            "WHEN_NO_MATCH" => PassthroughBehavior::WhenNoMatch,
+            "NEVER" => PassthroughBehavior::Never,
+            "WHEN_NO_TEMPLATES" => PassthroughBehavior::WhenNoTemplates,
+            _ => PassthroughBehavior::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PassthroughBehavior {
+    fn from(value: String) -> PassthroughBehavior {
+        match value.as_str() {
+            "WHEN_NO_MATCH" => PassthroughBehavior::WhenNoMatch,
+            "NEVER" => PassthroughBehavior::Never,
+            "WHEN_NO_TEMPLATES" => PassthroughBehavior::WhenNoTemplates,
+            _ => PassthroughBehavior::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PassthroughBehavior {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PassthroughBehavior, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PassthroughBehavior {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PassthroughBehavior {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PassthroughBehavior {
+    fn deserialize<D>(deserializer: D) -> Result<PassthroughBehavior, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// Represents a protocol type.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ProtocolType {
+    /// `WEBSOCKET`
+    Websocket,
+    /// `HTTP`
+    Http,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ProtocolType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ProtocolType::Websocket => "WEBSOCKET",
+            ProtocolType::Http => "HTTP",
+            ProtocolType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ProtocolType {
+    fn default() -> ProtocolType {
+        ProtocolType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ProtocolType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ProtocolType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ProtocolType {
+    fn from(value: &'a str) -> ProtocolType {
+        match value {
+            "WEBSOCKET" => ProtocolType::Websocket,
+            "HTTP" => ProtocolType::Http,
+            _ => ProtocolType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ProtocolType {
+    fn from(value: String) -> ProtocolType {
+        match value.as_str() {
+            "WEBSOCKET" => ProtocolType::Websocket,
+            "HTTP" => ProtocolType::Http,
+            _ => ProtocolType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ProtocolType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ProtocolType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ProtocolType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ProtocolType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ProtocolType {
+    fn deserialize<D>(deserializer: D) -> Result<ProtocolType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -2465,8 +3609,13 @@ pub struct ReimportApiResponse {
     pub cors_configuration: Option<Cors>,
     /// <p>The timestamp when the API was created.</p>
     #[serde(rename = "CreatedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The description of the API.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2486,7 +3635,7 @@ pub struct ReimportApiResponse {
     /// <p>The API protocol.</p>
     #[serde(rename = "ProtocolType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub protocol_type: Option<String>,
+    pub protocol_type: Option<ProtocolType>,
     /// <p>The route selection expression for the API. For HTTP APIs, the routeSelectionExpression must be ${request.method} ${request.path}. If not provided, this will be the default for HTTP APIs. This property is required for WebSocket APIs.</p>
     #[serde(rename = "RouteSelectionExpression")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2524,7 +3673,7 @@ pub struct Route {
     /// <p>The authorization type for the route. For WebSocket APIs, valid values are NONE for open access, AWS_IAM for using AWS IAM permissions, and CUSTOM for using a Lambda authorizer For HTTP APIs, valid values are NONE for open access, or JWT for using JSON Web Tokens.</p>
     #[serde(rename = "AuthorizationType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub authorization_type: Option<String>,
+    pub authorization_type: Option<AuthorizationType>,
     /// <p>The identifier of the Authorizer resource to be associated with this route. The authorizer identifier is generated by API Gateway when you created the authorizer.</p>
     #[serde(rename = "AuthorizerId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2601,7 +3750,7 @@ pub struct RouteSettings {
     /// <p>Specifies the logging level for this route: INFO, ERROR, or OFF. This property affects the log entries pushed to Amazon CloudWatch Logs. Supported only for WebSocket APIs.</p>
     #[serde(rename = "LoggingLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub logging_level: Option<String>,
+    pub logging_level: Option<LoggingLevel>,
     /// <p>Specifies the throttling burst limit. Supported only for WebSocket APIs.</p>
     #[serde(rename = "ThrottlingBurstLimit")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2612,6 +3761,100 @@ pub struct RouteSettings {
     pub throttling_rate_limit: Option<f64>,
 }
 
+/// <p>The Transport Layer Security (TLS) version of the security policy for this domain name. The valid values are TLS_1_0 and TLS_1_2.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SecurityPolicy {
+    /// `TLS_1_0`
+    Tls1_0,
+    /// `TLS_1_2`
+    Tls1_2,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SecurityPolicy {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SecurityPolicy::Tls1_0 => "TLS_1_0",
+            SecurityPolicy::Tls1_2 => "TLS_1_2",
+            SecurityPolicy::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SecurityPolicy {
+    fn default() -> SecurityPolicy {
+        SecurityPolicy::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SecurityPolicy {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SecurityPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SecurityPolicy {
+    fn from(value: &'a str) -> SecurityPolicy {
+        match value {
+            "TLS_1_0" => SecurityPolicy::Tls1_0,
+            "TLS_1_2" => SecurityPolicy::Tls1_2,
+            _ => SecurityPolicy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SecurityPolicy {
+    fn from(value: String) -> SecurityPolicy {
+        match value.as_str() {
+            "TLS_1_0" => SecurityPolicy::Tls1_0,
+            "TLS_1_2" => SecurityPolicy::Tls1_2,
+            _ => SecurityPolicy::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SecurityPolicy {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SecurityPolicy, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SecurityPolicy {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SecurityPolicy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SecurityPolicy {
+    fn deserialize<D>(deserializer: D) -> Result<SecurityPolicy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents an API stage.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -2634,8 +3877,13 @@ pub struct Stage {
     pub client_certificate_id: Option<String>,
     /// <p>The timestamp when the stage was created.</p>
     #[serde(rename = "CreatedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Default route settings for the stage.</p>
     #[serde(rename = "DefaultRouteSettings")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2654,8 +3902,13 @@ pub struct Stage {
     pub last_deployment_status_message: Option<String>,
     /// <p>The timestamp when the stage was last updated.</p>
     #[serde(rename = "LastUpdatedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date: Option<f64>,
+    pub last_updated_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Route settings for the stage, by routeKey.</p>
     #[serde(rename = "RouteSettings")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2816,8 +4069,13 @@ pub struct UpdateApiResponse {
     pub cors_configuration: Option<Cors>,
     /// <p>The timestamp when the API was created.</p>
     #[serde(rename = "CreatedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The description of the API.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2837,7 +4095,7 @@ pub struct UpdateApiResponse {
     /// <p>The API protocol.</p>
     #[serde(rename = "ProtocolType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub protocol_type: Option<String>,
+    pub protocol_type: Option<ProtocolType>,
     /// <p>The route selection expression for the API. For HTTP APIs, the routeSelectionExpression must be ${request.method} ${request.path}. If not provided, this will be the default for HTTP APIs. This property is required for WebSocket APIs.</p>
     #[serde(rename = "RouteSelectionExpression")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2877,7 +4135,7 @@ pub struct UpdateAuthorizerRequest {
     /// <p>The authorizer type. For WebSocket APIs, specify REQUEST for a Lambda function using incoming request parameters. For HTTP APIs, specify JWT to use JSON Web Tokens.</p>
     #[serde(rename = "AuthorizerType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub authorizer_type: Option<String>,
+    pub authorizer_type: Option<AuthorizerType>,
     /// <p>The authorizer's Uniform Resource Identifier (URI). For REQUEST authorizers, this must be a well-formed Lambda function URI, for example, arn:aws:apigateway:us-west-2:lambda:path/2015-03-31/functions/arn:aws:lambda:us-west-2:<replaceable>{account_id}</replaceable>:function:<replaceable>{lambda_function_name}</replaceable>/invocations. In general, the URI has this form: arn:aws:apigateway:<replaceable>{region}</replaceable>:lambda:path/<replaceable>{service_api}</replaceable>
     /// , where <replaceable></replaceable>{region} is the same as the region hosting the Lambda function, path indicates that the remaining substring in the URI should be treated as the path to the resource, including the initial /. For Lambda functions, this is usually of the form /2015-03-31/functions/[FunctionARN]/invocations. Supported only for REQUEST authorizers.</p>
     #[serde(rename = "AuthorizerUri")]
@@ -2919,7 +4177,7 @@ pub struct UpdateAuthorizerResponse {
     /// <p>The authorizer type. For WebSocket APIs, specify REQUEST for a Lambda function using incoming request parameters. For HTTP APIs, specify JWT to use JSON Web Tokens.</p>
     #[serde(rename = "AuthorizerType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub authorizer_type: Option<String>,
+    pub authorizer_type: Option<AuthorizerType>,
     /// <p>The authorizer's Uniform Resource Identifier (URI). ForREQUEST authorizers, this must be a well-formed Lambda function URI, for example, arn:aws:apigateway:us-west-2:lambda:path/2015-03-31/functions/arn:aws:lambda:us-west-2:<replaceable>{account_id}</replaceable>:function:<replaceable>{lambda_function_name}</replaceable>/invocations. In general, the URI has this form: arn:aws:apigateway:<replaceable>{region}</replaceable>:lambda:path/<replaceable>{service_api}</replaceable>
     /// , where <replaceable></replaceable>{region} is the same as the region hosting the Lambda function, path indicates that the remaining substring in the URI should be treated as the path to the resource, including the initial /. For Lambda functions, this is usually of the form /2015-03-31/functions/[FunctionARN]/invocations. Supported only for REQUEST authorizers.</p>
     #[serde(rename = "AuthorizerUri")]
@@ -2968,8 +4226,13 @@ pub struct UpdateDeploymentResponse {
     pub auto_deployed: Option<bool>,
     /// <p>The date and time when the Deployment resource was created.</p>
     #[serde(rename = "CreatedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The identifier for the deployment.</p>
     #[serde(rename = "DeploymentId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2977,7 +4240,7 @@ pub struct UpdateDeploymentResponse {
     /// <p>The status of the deployment: PENDING, FAILED, or SUCCEEDED.</p>
     #[serde(rename = "DeploymentStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deployment_status: Option<String>,
+    pub deployment_status: Option<DeploymentStatus>,
     /// <p>May contain additional feedback on the status of an API deployment.</p>
     #[serde(rename = "DeploymentStatusMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3036,11 +4299,11 @@ pub struct UpdateIntegrationRequest {
     /// <p>The type of the network connection to the integration endpoint. Currently the only valid value is INTERNET, for connections through the public routable internet.</p>
     #[serde(rename = "ConnectionType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub connection_type: Option<String>,
+    pub connection_type: Option<ConnectionType>,
     /// <p>Supported only for WebSocket APIs. Specifies how to handle response payload content type conversions. Supported values are CONVERT_TO_BINARY and CONVERT_TO_TEXT, with the following behaviors:</p> <p>CONVERT_TO_BINARY: Converts a response payload from a Base64-encoded string to the corresponding binary blob.</p> <p>CONVERT_TO_TEXT: Converts a response payload from a binary blob to a Base64-encoded string.</p> <p>If this property is not defined, the response payload will be passed through from the integration response to the route response or method response without modification.</p>
     #[serde(rename = "ContentHandlingStrategy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub content_handling_strategy: Option<String>,
+    pub content_handling_strategy: Option<ContentHandlingStrategy>,
     /// <p>Specifies the credentials required for the integration, if any. For AWS integrations, three options are available. To specify an IAM Role for API Gateway to assume, use the role's Amazon Resource Name (ARN). To require that the caller's identity be passed through from the request, specify the string arn:aws:iam::*:user/*. To use resource-based permissions on supported AWS services, specify null.</p>
     #[serde(rename = "CredentialsArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3059,7 +4322,7 @@ pub struct UpdateIntegrationRequest {
     /// <p>The integration type of an integration. One of the following:</p> <p>AWS: for integrating the route or method request with an AWS service action, including the Lambda function-invoking action. With the Lambda function-invoking action, this is referred to as the Lambda custom integration. With any other AWS service action, this is known as AWS integration. Supported only for WebSocket APIs.</p> <p>AWS_PROXY: for integrating the route or method request with the Lambda function-invoking action with the client request passed through as-is. This integration is also referred to as Lambda proxy integration.</p> <p>HTTP: for integrating the route or method request with an HTTP endpoint. This integration is also referred to as the HTTP custom integration. Supported only for WebSocket APIs.</p> <p>HTTP_PROXY: for integrating route or method request with an HTTP endpoint, with the client request passed through as-is. This is also referred to as HTTP proxy integration.</p> <p>MOCK: for integrating the route or method request with API Gateway as a "loopback" endpoint without invoking any backend. Supported only for WebSocket APIs.</p>
     #[serde(rename = "IntegrationType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub integration_type: Option<String>,
+    pub integration_type: Option<IntegrationType>,
     /// <p>For a Lambda proxy integration, this is the URI of the Lambda function.</p>
     #[serde(rename = "IntegrationUri")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3067,7 +4330,7 @@ pub struct UpdateIntegrationRequest {
     /// <p>Specifies the pass-through behavior for incoming requests based on the Content-Type header in the request, and the available mapping templates specified as the requestTemplates property on the Integration resource. There are three valid values: WHEN_NO_MATCH, WHEN_NO_TEMPLATES, and NEVER. Supported only for WebSocket APIs.</p> <p>WHEN_NO_MATCH passes the request body for unmapped content types through to the integration backend without transformation.</p> <p>NEVER rejects unmapped content types with an HTTP 415 Unsupported Media Type response.</p> <p>WHEN_NO_TEMPLATES allows pass-through when the integration has no content types mapped to templates. However, if there is at least one content type defined, unmapped content types will be rejected with the same HTTP 415 Unsupported Media Type response.</p>
     #[serde(rename = "PassthroughBehavior")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub passthrough_behavior: Option<String>,
+    pub passthrough_behavior: Option<PassthroughBehavior>,
     /// <p>Specifies the format of the payload sent to an integration. Required for HTTP APIs. Currently, the only supported value is 1.0.</p>
     #[serde(rename = "PayloadFormatVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3105,7 +4368,7 @@ pub struct UpdateIntegrationResponseRequest {
     /// <p>Supported only for WebSocket APIs. Specifies how to handle response payload content type conversions. Supported values are CONVERT_TO_BINARY and CONVERT_TO_TEXT, with the following behaviors:</p> <p>CONVERT_TO_BINARY: Converts a response payload from a Base64-encoded string to the corresponding binary blob.</p> <p>CONVERT_TO_TEXT: Converts a response payload from a binary blob to a Base64-encoded string.</p> <p>If this property is not defined, the response payload will be passed through from the integration response to the route response or method response without modification.</p>
     #[serde(rename = "ContentHandlingStrategy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub content_handling_strategy: Option<String>,
+    pub content_handling_strategy: Option<ContentHandlingStrategy>,
     /// <p>The integration ID.</p>
     #[serde(rename = "IntegrationId")]
     pub integration_id: String,
@@ -3143,7 +4406,7 @@ pub struct UpdateIntegrationResponseResponse {
     /// <p>Supported only for WebSocket APIs. Specifies how to handle response payload content type conversions. Supported values are CONVERT_TO_BINARY and CONVERT_TO_TEXT, with the following behaviors:</p> <p>CONVERT_TO_BINARY: Converts a response payload from a Base64-encoded string to the corresponding binary blob.</p> <p>CONVERT_TO_TEXT: Converts a response payload from a binary blob to a Base64-encoded string.</p> <p>If this property is not defined, the response payload will be passed through from the integration response to the route response or method response without modification.</p>
     #[serde(rename = "ContentHandlingStrategy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub content_handling_strategy: Option<String>,
+    pub content_handling_strategy: Option<ContentHandlingStrategy>,
     /// <p>The integration response ID.</p>
     #[serde(rename = "IntegrationResponseId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3180,11 +4443,11 @@ pub struct UpdateIntegrationResult {
     /// <p>The type of the network connection to the integration endpoint. Currently the only valid value is INTERNET, for connections through the public routable internet.</p>
     #[serde(rename = "ConnectionType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub connection_type: Option<String>,
+    pub connection_type: Option<ConnectionType>,
     /// <p>Supported only for WebSocket APIs. Specifies how to handle response payload content type conversions. Supported values are CONVERT_TO_BINARY and CONVERT_TO_TEXT, with the following behaviors:</p> <p>CONVERT_TO_BINARY: Converts a response payload from a Base64-encoded string to the corresponding binary blob.</p> <p>CONVERT_TO_TEXT: Converts a response payload from a binary blob to a Base64-encoded string.</p> <p>If this property is not defined, the response payload will be passed through from the integration response to the route response or method response without modification.</p>
     #[serde(rename = "ContentHandlingStrategy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub content_handling_strategy: Option<String>,
+    pub content_handling_strategy: Option<ContentHandlingStrategy>,
     /// <p>Specifies the credentials required for the integration, if any. For AWS integrations, three options are available. To specify an IAM Role for API Gateway to assume, use the role's Amazon Resource Name (ARN). To require that the caller's identity be passed through from the request, specify the string arn:aws:iam::*:user/*. To use resource-based permissions on supported AWS services, specify null.</p>
     #[serde(rename = "CredentialsArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3208,7 +4471,7 @@ pub struct UpdateIntegrationResult {
     /// <p>The integration type of an integration. One of the following:</p> <p>AWS: for integrating the route or method request with an AWS service action, including the Lambda function-invoking action. With the Lambda function-invoking action, this is referred to as the Lambda custom integration. With any other AWS service action, this is known as AWS integration. Supported only for WebSocket APIs.</p> <p>AWS_PROXY: for integrating the route or method request with the Lambda function-invoking action with the client request passed through as-is. This integration is also referred to as Lambda proxy integration.</p> <p>HTTP: for integrating the route or method request with an HTTP endpoint. This integration is also referred to as the HTTP custom integration. Supported only for WebSocket APIs.</p> <p>HTTP_PROXY: for integrating route or method request with an HTTP endpoint, with the client request passed through as-is. This is also referred to as HTTP proxy integration.</p> <p>MOCK: for integrating the route or method request with API Gateway as a "loopback" endpoint without invoking any backend. Supported only for WebSocket APIs.</p>
     #[serde(rename = "IntegrationType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub integration_type: Option<String>,
+    pub integration_type: Option<IntegrationType>,
     /// <p>For a Lambda proxy integration, this is the URI of the Lambda function.</p>
     #[serde(rename = "IntegrationUri")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3216,7 +4479,7 @@ pub struct UpdateIntegrationResult {
     /// <p>Specifies the pass-through behavior for incoming requests based on the Content-Type header in the request, and the available mapping templates specified as the requestTemplates property on the Integration resource. There are three valid values: WHEN_NO_MATCH, WHEN_NO_TEMPLATES, and NEVER. Supported only for WebSocket APIs.</p> <p>WHEN_NO_MATCH passes the request body for unmapped content types through to the integration backend without transformation.</p> <p>NEVER rejects unmapped content types with an HTTP 415 Unsupported Media Type response.</p> <p>WHEN_NO_TEMPLATES allows pass-through when the integration has no content types mapped to templates. However, if there is at least one content type defined, unmapped content types will be rejected with the same HTTP 415 Unsupported Media Type response.</p>
     #[serde(rename = "PassthroughBehavior")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub passthrough_behavior: Option<String>,
+    pub passthrough_behavior: Option<PassthroughBehavior>,
     /// <p>Specifies the format of the payload sent to an integration. Required for HTTP APIs. Currently, the only supported value is 1.0.</p>
     #[serde(rename = "PayloadFormatVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3315,7 +4578,7 @@ pub struct UpdateRouteRequest {
     /// <p>The authorization type for the route. For WebSocket APIs, valid values are NONE for open access, AWS_IAM for using AWS IAM permissions, and CUSTOM for using a Lambda authorizer For HTTP APIs, valid values are NONE for open access, or JWT for using JSON Web Tokens.</p>
     #[serde(rename = "AuthorizationType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub authorization_type: Option<String>,
+    pub authorization_type: Option<AuthorizationType>,
     /// <p>The identifier of the Authorizer resource to be associated with this route. The authorizer identifier is generated by API Gateway when you created the authorizer.</p>
     #[serde(rename = "AuthorizerId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3427,7 +4690,7 @@ pub struct UpdateRouteResult {
     /// <p>The authorization type for the route. For WebSocket APIs, valid values are NONE for open access, AWS_IAM for using AWS IAM permissions, and CUSTOM for using a Lambda authorizer For HTTP APIs, valid values are NONE for open access, or JWT for using JSON Web Tokens.</p>
     #[serde(rename = "AuthorizationType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub authorization_type: Option<String>,
+    pub authorization_type: Option<AuthorizationType>,
     /// <p>The identifier of the Authorizer resource to be associated with this route. The authorizer identifier is generated by API Gateway when you created the authorizer.</p>
     #[serde(rename = "AuthorizerId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3531,8 +4794,13 @@ pub struct UpdateStageResponse {
     pub client_certificate_id: Option<String>,
     /// <p>The timestamp when the stage was created.</p>
     #[serde(rename = "CreatedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Default route settings for the stage.</p>
     #[serde(rename = "DefaultRouteSettings")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3551,8 +4819,13 @@ pub struct UpdateStageResponse {
     pub last_deployment_status_message: Option<String>,
     /// <p>The timestamp when the stage was last updated.</p>
     #[serde(rename = "LastUpdatedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date: Option<f64>,
+    pub last_updated_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Route settings for the stage, by routeKey.</p>
     #[serde(rename = "RouteSettings")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -6648,11 +7921,12 @@ pub struct ApiGatewayV2Client {
 impl ApiGatewayV2Client {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> ApiGatewayV2Client {
         ApiGatewayV2Client {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "ApiGatewayV2"),
         }
     }
 
@@ -6686,6 +7960,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = "/v2/apis";
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6719,6 +7994,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateApiMapping");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6749,6 +8025,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/authorizers", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateAuthorizer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6779,6 +8056,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/deployments", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6809,6 +8087,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = "/v2/domainnames";
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateDomainName");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6839,6 +8118,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/integrations", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateIntegration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6874,6 +8154,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateIntegrationResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6904,6 +8185,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/models", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateModel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6934,6 +8216,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/routes", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateRoute");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6968,6 +8251,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateRouteResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6998,6 +8282,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/stages", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateStage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7025,6 +8310,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}", api_id = input.api_id);
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7055,6 +8341,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteApiMapping");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7085,6 +8372,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteAuthorizer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7111,6 +8399,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/cors", api_id = input.api_id);
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteCorsConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7141,6 +8430,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7170,6 +8460,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteDomainName");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7200,6 +8491,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteIntegration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7226,6 +8518,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/integrations/{integration_id}/integrationresponses/{integration_response_id}", api_id = input.api_id, integration_id = input.integration_id, integration_response_id = input.integration_response_id);
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteIntegrationResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7256,6 +8549,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteModel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7286,6 +8580,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteRoute");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7317,6 +8612,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteRouteResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7348,6 +8644,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteRouteSettings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7378,6 +8675,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteStage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7404,6 +8702,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7435,6 +8734,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetApiMapping");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7465,6 +8765,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetApiMappings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7501,6 +8802,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = "/v2/apis";
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetApis");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7541,6 +8843,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetAuthorizer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7568,6 +8871,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/authorizers", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetAuthorizers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7608,6 +8912,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7635,6 +8940,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/deployments", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetDeployments");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7674,6 +8980,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetDomainName");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7701,6 +9008,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = "/v2/domainnames";
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetDomainNames");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7741,6 +9049,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetIntegration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7768,6 +9077,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/integrations/{integration_id}/integrationresponses/{integration_response_id}", api_id = input.api_id, integration_id = input.integration_id, integration_response_id = input.integration_response_id);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetIntegrationResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7799,6 +9109,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetIntegrationResponses");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7835,6 +9146,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/integrations", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetIntegrations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7875,6 +9187,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetModel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7906,6 +9219,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetModelTemplate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7933,6 +9247,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/models", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetModels");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7973,6 +9288,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetRoute");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8005,6 +9321,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetRouteResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8036,6 +9353,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetRouteResponses");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8072,6 +9390,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/routes", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetRoutes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8112,6 +9431,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetStage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8139,6 +9459,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/stages", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetStages");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8175,6 +9496,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetTags");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8202,6 +9524,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = "/v2/apis";
 
         let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
+        request.set_operation("ImportApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8241,6 +9564,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}", api_id = input.api_id);
 
         let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
+        request.set_operation("ReimportApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8280,6 +9604,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8310,6 +9635,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8342,6 +9668,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}", api_id = input.api_id);
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8376,6 +9703,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateApiMapping");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8410,6 +9738,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateAuthorizer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8444,6 +9773,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8477,6 +9807,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateDomainName");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8511,6 +9842,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateIntegration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8542,6 +9874,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/integrations/{integration_id}/integrationresponses/{integration_response_id}", api_id = input.api_id, integration_id = input.integration_id, integration_response_id = input.integration_response_id);
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateIntegrationResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8576,6 +9909,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateModel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8610,6 +9944,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateRoute");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8645,6 +9980,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateRouteResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8679,6 +10015,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateStage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8701,3 +10038,319 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         }
     }
 }
+impl ApiGatewayV2Client {
+    /// Auto-paginating version of `get_apis`, streaming every page of results.
+    pub fn get_apis_pages(
+        &self,
+        input: GetApisRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetApisResponse, GetApisError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApiGatewayV2Client, input: GetApisRequest| async move {
+                client.get_apis(input).await
+            },
+            |input: &GetApisRequest, output: &GetApisResponse| {
+                Some(GetApisRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_apis`, streaming the `Items` of every page of results.
+    pub fn get_apis_items(
+        &self,
+        input: GetApisRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Api, GetApisError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_apis_pages(input),
+            |output: GetApisResponse| output.items.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_authorizers`, streaming every page of results.
+    pub fn get_authorizers_pages(
+        &self,
+        input: GetAuthorizersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetAuthorizersResponse, GetAuthorizersError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApiGatewayV2Client, input: GetAuthorizersRequest| async move {
+                client.get_authorizers(input).await
+            },
+            |input: &GetAuthorizersRequest, output: &GetAuthorizersResponse| {
+                Some(GetAuthorizersRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_authorizers`, streaming the `Items` of every page of results.
+    pub fn get_authorizers_items(
+        &self,
+        input: GetAuthorizersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Authorizer, GetAuthorizersError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_authorizers_pages(input),
+            |output: GetAuthorizersResponse| output.items.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_deployments`, streaming every page of results.
+    pub fn get_deployments_pages(
+        &self,
+        input: GetDeploymentsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetDeploymentsResponse, GetDeploymentsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApiGatewayV2Client, input: GetDeploymentsRequest| async move {
+                client.get_deployments(input).await
+            },
+            |input: &GetDeploymentsRequest, output: &GetDeploymentsResponse| {
+                Some(GetDeploymentsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_deployments`, streaming the `Items` of every page of results.
+    pub fn get_deployments_items(
+        &self,
+        input: GetDeploymentsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Deployment, GetDeploymentsError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_deployments_pages(input),
+            |output: GetDeploymentsResponse| output.items.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_domain_names`, streaming every page of results.
+    pub fn get_domain_names_pages(
+        &self,
+        input: GetDomainNamesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetDomainNamesResponse, GetDomainNamesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApiGatewayV2Client, input: GetDomainNamesRequest| async move {
+                client.get_domain_names(input).await
+            },
+            |input: &GetDomainNamesRequest, output: &GetDomainNamesResponse| {
+                Some(GetDomainNamesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_domain_names`, streaming the `Items` of every page of results.
+    pub fn get_domain_names_items(
+        &self,
+        input: GetDomainNamesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DomainName, GetDomainNamesError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_domain_names_pages(input),
+            |output: GetDomainNamesResponse| output.items.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_integration_responses`, streaming every page of results.
+    pub fn get_integration_responses_pages(
+        &self,
+        input: GetIntegrationResponsesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        GetIntegrationResponsesResponse,
+        GetIntegrationResponsesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApiGatewayV2Client, input: GetIntegrationResponsesRequest| async move {
+                client.get_integration_responses(input).await
+            },
+            |input: &GetIntegrationResponsesRequest, output: &GetIntegrationResponsesResponse| {
+                Some(GetIntegrationResponsesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_integration_responses`, streaming the `Items` of every page of results.
+    pub fn get_integration_responses_items(
+        &self,
+        input: GetIntegrationResponsesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<IntegrationResponse, GetIntegrationResponsesError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.get_integration_responses_pages(input),
+            |output: GetIntegrationResponsesResponse| output.items.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_integrations`, streaming every page of results.
+    pub fn get_integrations_pages(
+        &self,
+        input: GetIntegrationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetIntegrationsResponse, GetIntegrationsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApiGatewayV2Client, input: GetIntegrationsRequest| async move {
+                client.get_integrations(input).await
+            },
+            |input: &GetIntegrationsRequest, output: &GetIntegrationsResponse| {
+                Some(GetIntegrationsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_integrations`, streaming the `Items` of every page of results.
+    pub fn get_integrations_items(
+        &self,
+        input: GetIntegrationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Integration, GetIntegrationsError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_integrations_pages(input),
+            |output: GetIntegrationsResponse| output.items.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_models`, streaming every page of results.
+    pub fn get_models_pages(
+        &self,
+        input: GetModelsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetModelsResponse, GetModelsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApiGatewayV2Client, input: GetModelsRequest| async move {
+                client.get_models(input).await
+            },
+            |input: &GetModelsRequest, output: &GetModelsResponse| {
+                Some(GetModelsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_models`, streaming the `Items` of every page of results.
+    pub fn get_models_items(
+        &self,
+        input: GetModelsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Model, GetModelsError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_models_pages(input),
+            |output: GetModelsResponse| output.items.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_route_responses`, streaming every page of results.
+    pub fn get_route_responses_pages(
+        &self,
+        input: GetRouteResponsesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetRouteResponsesResponse, GetRouteResponsesError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApiGatewayV2Client, input: GetRouteResponsesRequest| async move {
+                client.get_route_responses(input).await
+            },
+            |input: &GetRouteResponsesRequest, output: &GetRouteResponsesResponse| {
+                Some(GetRouteResponsesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_route_responses`, streaming the `Items` of every page of results.
+    pub fn get_route_responses_items(
+        &self,
+        input: GetRouteResponsesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<RouteResponse, GetRouteResponsesError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_route_responses_pages(input),
+            |output: GetRouteResponsesResponse| output.items.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_routes`, streaming every page of results.
+    pub fn get_routes_pages(
+        &self,
+        input: GetRoutesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetRoutesResponse, GetRoutesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApiGatewayV2Client, input: GetRoutesRequest| async move {
+                client.get_routes(input).await
+            },
+            |input: &GetRoutesRequest, output: &GetRoutesResponse| {
+                Some(GetRoutesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_routes`, streaming the `Items` of every page of results.
+    pub fn get_routes_items(
+        &self,
+        input: GetRoutesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Route, GetRoutesError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_routes_pages(input),
+            |output: GetRoutesResponse| output.items.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_stages`, streaming every page of results.
+    pub fn get_stages_pages(
+        &self,
+        input: GetStagesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetStagesResponse, GetStagesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApiGatewayV2Client, input: GetStagesRequest| async move {
+                client.get_stages(input).await
+            },
+            |input: &GetStagesRequest, output: &GetStagesResponse| {
+                Some(GetStagesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_stages`, streaming the `Items` of every page of results.
+    pub fn get_stages_items(
+        &self,
+        input: GetStagesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Stage, GetStagesError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_stages_pages(input),
+            |output: GetStagesResponse| output.items.unwrap_or_default(),
+        )
+    }
+}
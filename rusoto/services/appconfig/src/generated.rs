@@ -55,6 +55,94 @@ pub struct Applications {
     pub next_token: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BadRequestReason {
+    /// `InvalidConfiguration`
+    InvalidConfiguration,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl BadRequestReason {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BadRequestReason::InvalidConfiguration => "InvalidConfiguration",
+            BadRequestReason::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for BadRequestReason {
+    fn default() -> BadRequestReason {
+        BadRequestReason::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for BadRequestReason {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for BadRequestReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for BadRequestReason {
+    fn from(value: &'a str) -> BadRequestReason {
+        match value {
+            "InvalidConfiguration" => BadRequestReason::InvalidConfiguration,
+            _ => BadRequestReason::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BadRequestReason {
+    fn from(value: String) -> BadRequestReason {
+        match value.as_str() {
+            "InvalidConfiguration" => BadRequestReason::InvalidConfiguration,
+            _ => BadRequestReason::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for BadRequestReason {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<BadRequestReason, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for BadRequestReason {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for BadRequestReason {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BadRequestReason {
+    fn deserialize<D>(deserializer: D) -> Result<BadRequestReason, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct Configuration {
     /// <p>The configuration version.</p>
@@ -121,7 +209,7 @@ pub struct ConfigurationProfileSummary {
     /// <p>The types of validators in the configuration profile.</p>
     #[serde(rename = "ValidatorTypes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub validator_types: Option<Vec<String>>,
+    pub validator_types: Option<Vec<ValidatorType>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -202,13 +290,13 @@ pub struct CreateDeploymentStrategyRequest {
     /// <p>The algorithm used to define how percentage grows over time.</p>
     #[serde(rename = "GrowthType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub growth_type: Option<String>,
+    pub growth_type: Option<GrowthType>,
     /// <p>A name for the deployment strategy.</p>
     #[serde(rename = "Name")]
     pub name: String,
     /// <p>Save the deployment strategy to a Systems Manager (SSM) document.</p>
     #[serde(rename = "ReplicateTo")]
-    pub replicate_to: String,
+    pub replicate_to: ReplicateTo,
     /// <p>Metadata to assign to the deployment strategy. Tags help organize and categorize your AppConfig resources. Each tag consists of a key and an optional value, both of which you define.</p>
     #[serde(rename = "Tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -285,8 +373,13 @@ pub struct Deployment {
     pub application_id: Option<String>,
     /// <p>The time the deployment completed. </p>
     #[serde(rename = "CompletedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub completed_at: Option<f64>,
+    pub completed_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Information about the source location of the configuration.</p>
     #[serde(rename = "ConfigurationLocationUri")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -334,21 +427,139 @@ pub struct Deployment {
     /// <p>The algorithm used to define how percentage grew over time.</p>
     #[serde(rename = "GrowthType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub growth_type: Option<String>,
+    pub growth_type: Option<GrowthType>,
     /// <p>The percentage of targets for which the deployment is available.</p>
     #[serde(rename = "PercentageComplete")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub percentage_complete: Option<f32>,
     /// <p>The time the deployment started.</p>
     #[serde(rename = "StartedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub started_at: Option<f64>,
+    pub started_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The state of the deployment.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<DeploymentState>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DeploymentState {
+    /// `BAKING`
+    Baking,
+    /// `VALIDATING`
+    Validating,
+    /// `DEPLOYING`
+    Deploying,
+    /// `COMPLETE`
+    Complete,
+    /// `ROLLING_BACK`
+    RollingBack,
+    /// `ROLLED_BACK`
+    RolledBack,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DeploymentState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeploymentState::Baking => "BAKING",
+            DeploymentState::Validating => "VALIDATING",
+            DeploymentState::Deploying => "DEPLOYING",
+            DeploymentState::Complete => "COMPLETE",
+            DeploymentState::RollingBack => "ROLLING_BACK",
+            DeploymentState::RolledBack => "ROLLED_BACK",
+            DeploymentState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DeploymentState {
+    fn default() -> DeploymentState {
+        DeploymentState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DeploymentState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DeploymentState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
+impl<'a> From<&'a str> for DeploymentState {
+    fn from(value: &'a str) -> DeploymentState {
+        match value {
+            "BAKING" => DeploymentState::Baking,
+            "VALIDATING" => DeploymentState::Validating,
+            "DEPLOYING" => DeploymentState::Deploying,
+            "COMPLETE" => DeploymentState::Complete,
+            "ROLLING_BACK" => DeploymentState::RollingBack,
+            "ROLLED_BACK" => DeploymentState::RolledBack,
+            _ => DeploymentState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeploymentState {
+    fn from(value: String) -> DeploymentState {
+        match value.as_str() {
+            "BAKING" => DeploymentState::Baking,
+            "VALIDATING" => DeploymentState::Validating,
+            "DEPLOYING" => DeploymentState::Deploying,
+            "COMPLETE" => DeploymentState::Complete,
+            "ROLLING_BACK" => DeploymentState::RollingBack,
+            "ROLLED_BACK" => DeploymentState::RolledBack,
+            _ => DeploymentState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DeploymentState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DeploymentState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DeploymentState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DeploymentState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeploymentState {
+    fn deserialize<D>(deserializer: D) -> Result<DeploymentState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeploymentStrategies {
@@ -384,7 +595,7 @@ pub struct DeploymentStrategy {
     /// <p>The algorithm used to define how percentage grew over time.</p>
     #[serde(rename = "GrowthType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub growth_type: Option<String>,
+    pub growth_type: Option<GrowthType>,
     /// <p>The deployment strategy ID.</p>
     #[serde(rename = "Id")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -396,7 +607,7 @@ pub struct DeploymentStrategy {
     /// <p>Save the deployment strategy to a Systems Manager (SSM) document.</p>
     #[serde(rename = "ReplicateTo")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub replicate_to: Option<String>,
+    pub replicate_to: Option<ReplicateTo>,
 }
 
 /// <p>Information about the deployment.</p>
@@ -405,8 +616,13 @@ pub struct DeploymentStrategy {
 pub struct DeploymentSummary {
     /// <p>Time the deployment completed.</p>
     #[serde(rename = "CompletedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub completed_at: Option<f64>,
+    pub completed_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the configuration.</p>
     #[serde(rename = "ConfigurationName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -434,19 +650,24 @@ pub struct DeploymentSummary {
     /// <p>The algorithm used to define how percentage grows over time.</p>
     #[serde(rename = "GrowthType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub growth_type: Option<String>,
+    pub growth_type: Option<GrowthType>,
     /// <p>The percentage of targets for which the deployment is available.</p>
     #[serde(rename = "PercentageComplete")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub percentage_complete: Option<f32>,
     /// <p>Time the deployment started.</p>
     #[serde(rename = "StartedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub started_at: Option<f64>,
+    pub started_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The state of the deployment.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<DeploymentState>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -488,9 +709,112 @@ pub struct Environment {
     /// <p>The state of the environment. An environment can be in one of the following states: <code>READY_FOR_DEPLOYMENT</code>, <code>DEPLOYING</code>, <code>ROLLING_BACK</code>, or <code>ROLLED_BACK</code> </p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<EnvironmentState>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EnvironmentState {
+    /// `READY_FOR_DEPLOYMENT`
+    ReadyForDeployment,
+    /// `DEPLOYING`
+    Deploying,
+    /// `ROLLING_BACK`
+    RollingBack,
+    /// `ROLLED_BACK`
+    RolledBack,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EnvironmentState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EnvironmentState::ReadyForDeployment => "READY_FOR_DEPLOYMENT",
+            EnvironmentState::Deploying => "DEPLOYING",
+            EnvironmentState::RollingBack => "ROLLING_BACK",
+            EnvironmentState::RolledBack => "ROLLED_BACK",
+            EnvironmentState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EnvironmentState {
+    fn default() -> EnvironmentState {
+        EnvironmentState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EnvironmentState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EnvironmentState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EnvironmentState {
+    fn from(value: &'a str) -> EnvironmentState {
+        match value {
+            "READY_FOR_DEPLOYMENT" => EnvironmentState::ReadyForDeployment,
+            "DEPLOYING" => EnvironmentState::Deploying,
+            "ROLLING_BACK" => EnvironmentState::RollingBack,
+            "ROLLED_BACK" => EnvironmentState::RolledBack,
+            _ => EnvironmentState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EnvironmentState {
+    fn from(value: String) -> EnvironmentState {
+        match value.as_str() {
+            "READY_FOR_DEPLOYMENT" => EnvironmentState::ReadyForDeployment,
+            "DEPLOYING" => EnvironmentState::Deploying,
+            "ROLLING_BACK" => EnvironmentState::RollingBack,
+            "ROLLED_BACK" => EnvironmentState::RolledBack,
+            _ => EnvironmentState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EnvironmentState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EnvironmentState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EnvironmentState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
 }
 
+impl ::serde::Serialize for EnvironmentState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EnvironmentState {
+    fn deserialize<D>(deserializer: D) -> Result<EnvironmentState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Environments {
@@ -577,6 +901,99 @@ pub struct GetEnvironmentRequest {
     pub environment_id: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum GrowthType {
+    /// `LINEAR`
+    Linear,
+    /// `EXPONENTIAL`
+    Exponential,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl GrowthType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            GrowthType::Linear => "LINEAR",
+            GrowthType::Exponential => "EXPONENTIAL",
+            GrowthType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for GrowthType {
+    fn default() -> GrowthType {
+        GrowthType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for GrowthType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for GrowthType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for GrowthType {
+    fn from(value: &'a str) -> GrowthType {
+        match value {
+            "LINEAR" => GrowthType::Linear,
+            "EXPONENTIAL" => GrowthType::Exponential,
+            _ => GrowthType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for GrowthType {
+    fn from(value: String) -> GrowthType {
+        match value.as_str() {
+            "LINEAR" => GrowthType::Linear,
+            "EXPONENTIAL" => GrowthType::Exponential,
+            _ => GrowthType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for GrowthType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<GrowthType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for GrowthType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for GrowthType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for GrowthType {
+    fn deserialize<D>(deserializer: D) -> Result<GrowthType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ListApplicationsRequest {
@@ -675,6 +1092,99 @@ pub struct Monitor {
     pub alarm_role_arn: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ReplicateTo {
+    /// `NONE`
+    None,
+    /// `SSM_DOCUMENT`
+    SsmDocument,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ReplicateTo {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReplicateTo::None => "NONE",
+            ReplicateTo::SsmDocument => "SSM_DOCUMENT",
+            ReplicateTo::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ReplicateTo {
+    fn default() -> ReplicateTo {
+        ReplicateTo::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ReplicateTo {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ReplicateTo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ReplicateTo {
+    fn from(value: &'a str) -> ReplicateTo {
+        match value {
+            "NONE" => ReplicateTo::None,
+            "SSM_DOCUMENT" => ReplicateTo::SsmDocument,
+            _ => ReplicateTo::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReplicateTo {
+    fn from(value: String) -> ReplicateTo {
+        match value.as_str() {
+            "NONE" => ReplicateTo::None,
+            "SSM_DOCUMENT" => ReplicateTo::SsmDocument,
+            _ => ReplicateTo::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ReplicateTo {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ReplicateTo, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ReplicateTo {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ReplicateTo {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ReplicateTo {
+    fn deserialize<D>(deserializer: D) -> Result<ReplicateTo, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ResourceTags {
@@ -816,7 +1326,7 @@ pub struct UpdateDeploymentStrategyRequest {
     /// <p>The algorithm used to define how percentage grows over time.</p>
     #[serde(rename = "GrowthType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub growth_type: Option<String>,
+    pub growth_type: Option<GrowthType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -864,9 +1374,102 @@ pub struct Validator {
     pub content: String,
     /// <p>AppConfig supports validators of type <code>JSON_SCHEMA</code> and <code>LAMBDA</code> </p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: ValidatorType,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ValidatorType {
+    /// `JSON_SCHEMA`
+    JsonSchema,
+    /// `LAMBDA`
+    Lambda,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ValidatorType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ValidatorType::JsonSchema => "JSON_SCHEMA",
+            ValidatorType::Lambda => "LAMBDA",
+            ValidatorType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ValidatorType {
+    fn default() -> ValidatorType {
+        ValidatorType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ValidatorType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ValidatorType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ValidatorType {
+    fn from(value: &'a str) -> ValidatorType {
+        match value {
+            "JSON_SCHEMA" => ValidatorType::JsonSchema,
+            "LAMBDA" => ValidatorType::Lambda,
+            _ => ValidatorType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ValidatorType {
+    fn from(value: String) -> ValidatorType {
+        match value.as_str() {
+            "JSON_SCHEMA" => ValidatorType::JsonSchema,
+            "LAMBDA" => ValidatorType::Lambda,
+            _ => ValidatorType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ValidatorType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ValidatorType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ValidatorType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
 }
 
+impl ::serde::Serialize for ValidatorType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ValidatorType {
+    fn deserialize<D>(deserializer: D) -> Result<ValidatorType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// Errors returned by CreateApplication
 #[derive(Debug, PartialEq)]
 pub enum CreateApplicationError {
@@ -2322,11 +2925,12 @@ pub struct AppConfigClient {
 impl AppConfigClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> AppConfigClient {
         AppConfigClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "AppConfig"),
         }
     }
 
@@ -2360,6 +2964,7 @@ impl AppConfig for AppConfigClient {
         let request_uri = "/applications";
 
         let mut request = SignedRequest::new("POST", "appconfig", &self.region, &request_uri);
+        request.set_operation("CreateApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2393,6 +2998,7 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("POST", "appconfig", &self.region, &request_uri);
+        request.set_operation("CreateConfigurationProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2423,6 +3029,7 @@ impl AppConfig for AppConfigClient {
         let request_uri = "/deploymentstrategies";
 
         let mut request = SignedRequest::new("POST", "appconfig", &self.region, &request_uri);
+        request.set_operation("CreateDeploymentStrategy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2456,6 +3063,7 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("POST", "appconfig", &self.region, &request_uri);
+        request.set_operation("CreateEnvironment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2489,6 +3097,7 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appconfig", &self.region, &request_uri);
+        request.set_operation("DeleteApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2519,6 +3128,7 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appconfig", &self.region, &request_uri);
+        request.set_operation("DeleteConfigurationProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2548,6 +3158,7 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appconfig", &self.region, &request_uri);
+        request.set_operation("DeleteDeploymentStrategy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2578,6 +3189,7 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appconfig", &self.region, &request_uri);
+        request.set_operation("DeleteEnvironment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2607,6 +3219,7 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation("GetApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2639,6 +3252,7 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation("GetConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2687,6 +3301,7 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation("GetConfigurationProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2714,6 +3329,7 @@ impl AppConfig for AppConfigClient {
         let request_uri = format!("/applications/{application_id}/environments/{environment_id}/deployments/{deployment_number}", application_id = input.application_id, deployment_number = input.deployment_number, environment_id = input.environment_id);
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation("GetDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2744,6 +3360,7 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation("GetDeploymentStrategy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2775,6 +3392,7 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation("GetEnvironment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2802,6 +3420,7 @@ impl AppConfig for AppConfigClient {
         let request_uri = "/applications";
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation("ListApplications");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2841,6 +3460,7 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation("ListConfigurationProfiles");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2877,6 +3497,7 @@ impl AppConfig for AppConfigClient {
         let request_uri = "/deploymentstrategies";
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation("ListDeploymentStrategies");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2917,6 +3538,7 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation("ListDeployments");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2956,6 +3578,7 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation("ListEnvironments");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2992,6 +3615,7 @@ impl AppConfig for AppConfigClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3023,6 +3647,7 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("POST", "appconfig", &self.region, &request_uri);
+        request.set_operation("StartDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3053,6 +3678,7 @@ impl AppConfig for AppConfigClient {
         let request_uri = format!("/applications/{application_id}/environments/{environment_id}/deployments/{deployment_number}", application_id = input.application_id, deployment_number = input.deployment_number, environment_id = input.environment_id);
 
         let mut request = SignedRequest::new("DELETE", "appconfig", &self.region, &request_uri);
+        request.set_operation("StopDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3080,6 +3706,7 @@ impl AppConfig for AppConfigClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "appconfig", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3109,6 +3736,7 @@ impl AppConfig for AppConfigClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "appconfig", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3144,6 +3772,7 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "appconfig", &self.region, &request_uri);
+        request.set_operation("UpdateApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3178,6 +3807,7 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "appconfig", &self.region, &request_uri);
+        request.set_operation("UpdateConfigurationProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3211,6 +3841,7 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "appconfig", &self.region, &request_uri);
+        request.set_operation("UpdateDeploymentStrategy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3245,6 +3876,7 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "appconfig", &self.region, &request_uri);
+        request.set_operation("UpdateEnvironment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3275,6 +3907,7 @@ impl AppConfig for AppConfigClient {
         let request_uri = format!("/applications/{application_id}/configurationprofiles/{configuration_profile_id}/validators", application_id = input.application_id, configuration_profile_id = input.configuration_profile_id);
 
         let mut request = SignedRequest::new("POST", "appconfig", &self.region, &request_uri);
+        request.set_operation("ValidateConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3297,3 +3930,165 @@ impl AppConfig for AppConfigClient {
         }
     }
 }
+impl AppConfigClient {
+    /// Auto-paginating version of `list_applications`, streaming every page of results.
+    pub fn list_applications_pages(
+        &self,
+        input: ListApplicationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Applications, ListApplicationsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AppConfigClient, input: ListApplicationsRequest| async move {
+                client.list_applications(input).await
+            },
+            |input: &ListApplicationsRequest, output: &Applications| {
+                Some(ListApplicationsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_applications`, streaming the `Items` of every page of results.
+    pub fn list_applications_items(
+        &self,
+        input: ListApplicationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Application, ListApplicationsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_applications_pages(input),
+            |output: Applications| output.items.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_configuration_profiles`, streaming every page of results.
+    pub fn list_configuration_profiles_pages(
+        &self,
+        input: ListConfigurationProfilesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ConfigurationProfiles, ListConfigurationProfilesError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AppConfigClient, input: ListConfigurationProfilesRequest| async move {
+                client.list_configuration_profiles(input).await
+            },
+            |input: &ListConfigurationProfilesRequest, output: &ConfigurationProfiles| {
+                Some(ListConfigurationProfilesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_configuration_profiles`, streaming the `Items` of every page of results.
+    pub fn list_configuration_profiles_items(
+        &self,
+        input: ListConfigurationProfilesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ConfigurationProfileSummary,
+        ListConfigurationProfilesError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_configuration_profiles_pages(input),
+            |output: ConfigurationProfiles| output.items.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_deployment_strategies`, streaming every page of results.
+    pub fn list_deployment_strategies_pages(
+        &self,
+        input: ListDeploymentStrategiesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DeploymentStrategies, ListDeploymentStrategiesError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AppConfigClient, input: ListDeploymentStrategiesRequest| async move {
+                client.list_deployment_strategies(input).await
+            },
+            |input: &ListDeploymentStrategiesRequest, output: &DeploymentStrategies| {
+                Some(ListDeploymentStrategiesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_deployment_strategies`, streaming the `Items` of every page of results.
+    pub fn list_deployment_strategies_items(
+        &self,
+        input: ListDeploymentStrategiesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DeploymentStrategy, ListDeploymentStrategiesError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_deployment_strategies_pages(input),
+            |output: DeploymentStrategies| output.items.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_deployments`, streaming every page of results.
+    pub fn list_deployments_pages(
+        &self,
+        input: ListDeploymentsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Deployments, ListDeploymentsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AppConfigClient, input: ListDeploymentsRequest| async move {
+                client.list_deployments(input).await
+            },
+            |input: &ListDeploymentsRequest, output: &Deployments| {
+                Some(ListDeploymentsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_deployments`, streaming the `Items` of every page of results.
+    pub fn list_deployments_items(
+        &self,
+        input: ListDeploymentsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DeploymentSummary, ListDeploymentsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_deployments_pages(input),
+            |output: Deployments| output.items.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_environments`, streaming every page of results.
+    pub fn list_environments_pages(
+        &self,
+        input: ListEnvironmentsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Environments, ListEnvironmentsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AppConfigClient, input: ListEnvironmentsRequest| async move {
+                client.list_environments(input).await
+            },
+            |input: &ListEnvironmentsRequest, output: &Environments| {
+                Some(ListEnvironmentsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_environments`, streaming the `Items` of every page of results.
+    pub fn list_environments_items(
+        &self,
+        input: ListEnvironmentsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Environment, ListEnvironmentsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_environments_pages(input),
+            |output: Environments| output.items.unwrap_or_default(),
+        )
+    }
+}
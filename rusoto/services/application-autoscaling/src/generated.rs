@@ -24,6 +24,104 @@ use rusoto_core::signature::SignedRequest;
 #[allow(unused_imports)]
 use serde::{Deserialize, Serialize};
 use serde_json;
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AdjustmentType {
+    /// `ChangeInCapacity`
+    ChangeInCapacity,
+    /// `PercentChangeInCapacity`
+    PercentChangeInCapacity,
+    /// `ExactCapacity`
+    ExactCapacity,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AdjustmentType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AdjustmentType::ChangeInCapacity => "ChangeInCapacity",
+            AdjustmentType::PercentChangeInCapacity => "PercentChangeInCapacity",
+            AdjustmentType::ExactCapacity => "ExactCapacity",
+            AdjustmentType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AdjustmentType {
+    fn default() -> AdjustmentType {
+        AdjustmentType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AdjustmentType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AdjustmentType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AdjustmentType {
+    fn from(value: &'a str) -> AdjustmentType {
+        match value {
+            "ChangeInCapacity" => AdjustmentType::ChangeInCapacity,
+            "PercentChangeInCapacity" => AdjustmentType::PercentChangeInCapacity,
+            "ExactCapacity" => AdjustmentType::ExactCapacity,
+            _ => AdjustmentType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AdjustmentType {
+    fn from(value: String) -> AdjustmentType {
+        match value.as_str() {
+            "ChangeInCapacity" => AdjustmentType::ChangeInCapacity,
+            "PercentChangeInCapacity" => AdjustmentType::PercentChangeInCapacity,
+            "ExactCapacity" => AdjustmentType::ExactCapacity,
+            _ => AdjustmentType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AdjustmentType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AdjustmentType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AdjustmentType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AdjustmentType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AdjustmentType {
+    fn deserialize<D>(deserializer: D) -> Result<AdjustmentType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents a CloudWatch alarm associated with a scaling policy.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -51,7 +149,7 @@ pub struct CustomizedMetricSpecification {
     pub namespace: String,
     /// <p>The statistic of the metric.</p>
     #[serde(rename = "Statistic")]
-    pub statistic: String,
+    pub statistic: MetricStatistic,
     /// <p>The unit of the metric.</p>
     #[serde(rename = "Unit")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -69,10 +167,10 @@ pub struct DeleteScalingPolicyRequest {
     pub resource_id: String,
     /// <p><p>The scalable dimension. This string consists of the service namespace, resource type, and scaling property.</p> <ul> <li> <p> <code>ecs:service:DesiredCount</code> - The desired task count of an ECS service.</p> </li> <li> <p> <code>ec2:spot-fleet-request:TargetCapacity</code> - The target capacity of a Spot Fleet request.</p> </li> <li> <p> <code>elasticmapreduce:instancegroup:InstanceCount</code> - The instance count of an EMR Instance Group.</p> </li> <li> <p> <code>appstream:fleet:DesiredCapacity</code> - The desired capacity of an AppStream 2.0 fleet.</p> </li> <li> <p> <code>dynamodb:table:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:table:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:index:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>dynamodb:index:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>rds:cluster:ReadReplicaCount</code> - The count of Aurora Replicas in an Aurora DB cluster. Available for Aurora MySQL-compatible edition and Aurora PostgreSQL-compatible edition.</p> </li> <li> <p> <code>sagemaker:variant:DesiredInstanceCount</code> - The number of EC2 instances for an Amazon SageMaker model endpoint variant.</p> </li> <li> <p> <code>custom-resource:ResourceType:Property</code> - The scalable dimension for a custom resource provided by your own application or service.</p> </li> <li> <p> <code>comprehend:document-classifier-endpoint:DesiredInferenceUnits</code> - The number of inference units for an Amazon Comprehend document classification endpoint.</p> </li> <li> <p> <code>lambda:function:ProvisionedConcurrency</code> - The provisioned concurrency for a Lambda function.</p> </li> </ul></p>
     #[serde(rename = "ScalableDimension")]
-    pub scalable_dimension: String,
+    pub scalable_dimension: ScalableDimension,
     /// <p>The namespace of the AWS service that provides the resource or <code>custom-resource</code> for a resource provided by your own application or service. For more information, see <a href="http://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html#genref-aws-service-namespaces">AWS Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p>
     #[serde(rename = "ServiceNamespace")]
-    pub service_namespace: String,
+    pub service_namespace: ServiceNamespace,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -87,13 +185,13 @@ pub struct DeleteScheduledActionRequest {
     pub resource_id: String,
     /// <p><p>The scalable dimension. This string consists of the service namespace, resource type, and scaling property.</p> <ul> <li> <p> <code>ecs:service:DesiredCount</code> - The desired task count of an ECS service.</p> </li> <li> <p> <code>ec2:spot-fleet-request:TargetCapacity</code> - The target capacity of a Spot Fleet request.</p> </li> <li> <p> <code>elasticmapreduce:instancegroup:InstanceCount</code> - The instance count of an EMR Instance Group.</p> </li> <li> <p> <code>appstream:fleet:DesiredCapacity</code> - The desired capacity of an AppStream 2.0 fleet.</p> </li> <li> <p> <code>dynamodb:table:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:table:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:index:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>dynamodb:index:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>rds:cluster:ReadReplicaCount</code> - The count of Aurora Replicas in an Aurora DB cluster. Available for Aurora MySQL-compatible edition and Aurora PostgreSQL-compatible edition.</p> </li> <li> <p> <code>sagemaker:variant:DesiredInstanceCount</code> - The number of EC2 instances for an Amazon SageMaker model endpoint variant.</p> </li> <li> <p> <code>custom-resource:ResourceType:Property</code> - The scalable dimension for a custom resource provided by your own application or service.</p> </li> <li> <p> <code>comprehend:document-classifier-endpoint:DesiredInferenceUnits</code> - The number of inference units for an Amazon Comprehend document classification endpoint.</p> </li> <li> <p> <code>lambda:function:ProvisionedConcurrency</code> - The provisioned concurrency for a Lambda function.</p> </li> </ul></p>
     #[serde(rename = "ScalableDimension")]
-    pub scalable_dimension: String,
+    pub scalable_dimension: ScalableDimension,
     /// <p>The name of the scheduled action.</p>
     #[serde(rename = "ScheduledActionName")]
     pub scheduled_action_name: String,
     /// <p>The namespace of the AWS service that provides the resource or <code>custom-resource</code> for a resource provided by your own application or service. For more information, see <a href="http://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html#genref-aws-service-namespaces">AWS Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p>
     #[serde(rename = "ServiceNamespace")]
-    pub service_namespace: String,
+    pub service_namespace: ServiceNamespace,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -108,10 +206,10 @@ pub struct DeregisterScalableTargetRequest {
     pub resource_id: String,
     /// <p><p>The scalable dimension associated with the scalable target. This string consists of the service namespace, resource type, and scaling property.</p> <ul> <li> <p> <code>ecs:service:DesiredCount</code> - The desired task count of an ECS service.</p> </li> <li> <p> <code>ec2:spot-fleet-request:TargetCapacity</code> - The target capacity of a Spot Fleet request.</p> </li> <li> <p> <code>elasticmapreduce:instancegroup:InstanceCount</code> - The instance count of an EMR Instance Group.</p> </li> <li> <p> <code>appstream:fleet:DesiredCapacity</code> - The desired capacity of an AppStream 2.0 fleet.</p> </li> <li> <p> <code>dynamodb:table:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:table:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:index:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>dynamodb:index:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>rds:cluster:ReadReplicaCount</code> - The count of Aurora Replicas in an Aurora DB cluster. Available for Aurora MySQL-compatible edition and Aurora PostgreSQL-compatible edition.</p> </li> <li> <p> <code>sagemaker:variant:DesiredInstanceCount</code> - The number of EC2 instances for an Amazon SageMaker model endpoint variant.</p> </li> <li> <p> <code>custom-resource:ResourceType:Property</code> - The scalable dimension for a custom resource provided by your own application or service.</p> </li> <li> <p> <code>comprehend:document-classifier-endpoint:DesiredInferenceUnits</code> - The number of inference units for an Amazon Comprehend document classification endpoint.</p> </li> <li> <p> <code>lambda:function:ProvisionedConcurrency</code> - The provisioned concurrency for a Lambda function.</p> </li> </ul></p>
     #[serde(rename = "ScalableDimension")]
-    pub scalable_dimension: String,
+    pub scalable_dimension: ScalableDimension,
     /// <p>The namespace of the AWS service that provides the resource or <code>custom-resource</code> for a resource provided by your own application or service. For more information, see <a href="http://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html#genref-aws-service-namespaces">AWS Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p>
     #[serde(rename = "ServiceNamespace")]
-    pub service_namespace: String,
+    pub service_namespace: ServiceNamespace,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -136,10 +234,10 @@ pub struct DescribeScalableTargetsRequest {
     /// <p><p>The scalable dimension associated with the scalable target. This string consists of the service namespace, resource type, and scaling property. If you specify a scalable dimension, you must also specify a resource ID.</p> <ul> <li> <p> <code>ecs:service:DesiredCount</code> - The desired task count of an ECS service.</p> </li> <li> <p> <code>ec2:spot-fleet-request:TargetCapacity</code> - The target capacity of a Spot Fleet request.</p> </li> <li> <p> <code>elasticmapreduce:instancegroup:InstanceCount</code> - The instance count of an EMR Instance Group.</p> </li> <li> <p> <code>appstream:fleet:DesiredCapacity</code> - The desired capacity of an AppStream 2.0 fleet.</p> </li> <li> <p> <code>dynamodb:table:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:table:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:index:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>dynamodb:index:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>rds:cluster:ReadReplicaCount</code> - The count of Aurora Replicas in an Aurora DB cluster. Available for Aurora MySQL-compatible edition and Aurora PostgreSQL-compatible edition.</p> </li> <li> <p> <code>sagemaker:variant:DesiredInstanceCount</code> - The number of EC2 instances for an Amazon SageMaker model endpoint variant.</p> </li> <li> <p> <code>custom-resource:ResourceType:Property</code> - The scalable dimension for a custom resource provided by your own application or service.</p> </li> <li> <p> <code>comprehend:document-classifier-endpoint:DesiredInferenceUnits</code> - The number of inference units for an Amazon Comprehend document classification endpoint.</p> </li> <li> <p> <code>lambda:function:ProvisionedConcurrency</code> - The provisioned concurrency for a Lambda function.</p> </li> </ul></p>
     #[serde(rename = "ScalableDimension")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scalable_dimension: Option<String>,
+    pub scalable_dimension: Option<ScalableDimension>,
     /// <p>The namespace of the AWS service that provides the resource or <code>custom-resource</code> for a resource provided by your own application or service. For more information, see <a href="http://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html#genref-aws-service-namespaces">AWS Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p>
     #[serde(rename = "ServiceNamespace")]
-    pub service_namespace: String,
+    pub service_namespace: ServiceNamespace,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -173,10 +271,10 @@ pub struct DescribeScalingActivitiesRequest {
     /// <p><p>The scalable dimension. This string consists of the service namespace, resource type, and scaling property. If you specify a scalable dimension, you must also specify a resource ID.</p> <ul> <li> <p> <code>ecs:service:DesiredCount</code> - The desired task count of an ECS service.</p> </li> <li> <p> <code>ec2:spot-fleet-request:TargetCapacity</code> - The target capacity of a Spot Fleet request.</p> </li> <li> <p> <code>elasticmapreduce:instancegroup:InstanceCount</code> - The instance count of an EMR Instance Group.</p> </li> <li> <p> <code>appstream:fleet:DesiredCapacity</code> - The desired capacity of an AppStream 2.0 fleet.</p> </li> <li> <p> <code>dynamodb:table:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:table:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:index:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>dynamodb:index:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>rds:cluster:ReadReplicaCount</code> - The count of Aurora Replicas in an Aurora DB cluster. Available for Aurora MySQL-compatible edition and Aurora PostgreSQL-compatible edition.</p> </li> <li> <p> <code>sagemaker:variant:DesiredInstanceCount</code> - The number of EC2 instances for an Amazon SageMaker model endpoint variant.</p> </li> <li> <p> <code>custom-resource:ResourceType:Property</code> - The scalable dimension for a custom resource provided by your own application or service.</p> </li> <li> <p> <code>comprehend:document-classifier-endpoint:DesiredInferenceUnits</code> - The number of inference units for an Amazon Comprehend document classification endpoint.</p> </li> <li> <p> <code>lambda:function:ProvisionedConcurrency</code> - The provisioned concurrency for a Lambda function.</p> </li> </ul></p>
     #[serde(rename = "ScalableDimension")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scalable_dimension: Option<String>,
+    pub scalable_dimension: Option<ScalableDimension>,
     /// <p>The namespace of the AWS service that provides the resource or <code>custom-resource</code> for a resource provided by your own application or service. For more information, see <a href="http://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html#genref-aws-service-namespaces">AWS Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p>
     #[serde(rename = "ServiceNamespace")]
-    pub service_namespace: String,
+    pub service_namespace: ServiceNamespace,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -214,10 +312,10 @@ pub struct DescribeScalingPoliciesRequest {
     /// <p><p>The scalable dimension. This string consists of the service namespace, resource type, and scaling property. If you specify a scalable dimension, you must also specify a resource ID.</p> <ul> <li> <p> <code>ecs:service:DesiredCount</code> - The desired task count of an ECS service.</p> </li> <li> <p> <code>ec2:spot-fleet-request:TargetCapacity</code> - The target capacity of a Spot Fleet request.</p> </li> <li> <p> <code>elasticmapreduce:instancegroup:InstanceCount</code> - The instance count of an EMR Instance Group.</p> </li> <li> <p> <code>appstream:fleet:DesiredCapacity</code> - The desired capacity of an AppStream 2.0 fleet.</p> </li> <li> <p> <code>dynamodb:table:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:table:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:index:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>dynamodb:index:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>rds:cluster:ReadReplicaCount</code> - The count of Aurora Replicas in an Aurora DB cluster. Available for Aurora MySQL-compatible edition and Aurora PostgreSQL-compatible edition.</p> </li> <li> <p> <code>sagemaker:variant:DesiredInstanceCount</code> - The number of EC2 instances for an Amazon SageMaker model endpoint variant.</p> </li> <li> <p> <code>custom-resource:ResourceType:Property</code> - The scalable dimension for a custom resource provided by your own application or service.</p> </li> <li> <p> <code>comprehend:document-classifier-endpoint:DesiredInferenceUnits</code> - The number of inference units for an Amazon Comprehend document classification endpoint.</p> </li> <li> <p> <code>lambda:function:ProvisionedConcurrency</code> - The provisioned concurrency for a Lambda function.</p> </li> </ul></p>
     #[serde(rename = "ScalableDimension")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scalable_dimension: Option<String>,
+    pub scalable_dimension: Option<ScalableDimension>,
     /// <p>The namespace of the AWS service that provides the resource or <code>custom-resource</code> for a resource provided by your own application or service. For more information, see <a href="http://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html#genref-aws-service-namespaces">AWS Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p>
     #[serde(rename = "ServiceNamespace")]
-    pub service_namespace: String,
+    pub service_namespace: ServiceNamespace,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -251,14 +349,14 @@ pub struct DescribeScheduledActionsRequest {
     /// <p><p>The scalable dimension. This string consists of the service namespace, resource type, and scaling property. If you specify a scalable dimension, you must also specify a resource ID.</p> <ul> <li> <p> <code>ecs:service:DesiredCount</code> - The desired task count of an ECS service.</p> </li> <li> <p> <code>ec2:spot-fleet-request:TargetCapacity</code> - The target capacity of a Spot Fleet request.</p> </li> <li> <p> <code>elasticmapreduce:instancegroup:InstanceCount</code> - The instance count of an EMR Instance Group.</p> </li> <li> <p> <code>appstream:fleet:DesiredCapacity</code> - The desired capacity of an AppStream 2.0 fleet.</p> </li> <li> <p> <code>dynamodb:table:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:table:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:index:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>dynamodb:index:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>rds:cluster:ReadReplicaCount</code> - The count of Aurora Replicas in an Aurora DB cluster. Available for Aurora MySQL-compatible edition and Aurora PostgreSQL-compatible edition.</p> </li> <li> <p> <code>sagemaker:variant:DesiredInstanceCount</code> - The number of EC2 instances for an Amazon SageMaker model endpoint variant.</p> </li> <li> <p> <code>custom-resource:ResourceType:Property</code> - The scalable dimension for a custom resource provided by your own application or service.</p> </li> <li> <p> <code>comprehend:document-classifier-endpoint:DesiredInferenceUnits</code> - The number of inference units for an Amazon Comprehend document classification endpoint.</p> </li> <li> <p> <code>lambda:function:ProvisionedConcurrency</code> - The provisioned concurrency for a Lambda function.</p> </li> </ul></p>
     #[serde(rename = "ScalableDimension")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scalable_dimension: Option<String>,
+    pub scalable_dimension: Option<ScalableDimension>,
     /// <p>The names of the scheduled actions to describe.</p>
     #[serde(rename = "ScheduledActionNames")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub scheduled_action_names: Option<Vec<String>>,
     /// <p>The namespace of the AWS service that provides the resource or <code>custom-resource</code> for a resource provided by your own application or service. For more information, see <a href="http://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html#genref-aws-service-namespaces">AWS Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p>
     #[serde(rename = "ServiceNamespace")]
-    pub service_namespace: String,
+    pub service_namespace: ServiceNamespace,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -274,6 +372,104 @@ pub struct DescribeScheduledActionsResponse {
     pub scheduled_actions: Option<Vec<ScheduledAction>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MetricAggregationType {
+    /// `Average`
+    Average,
+    /// `Minimum`
+    Minimum,
+    /// `Maximum`
+    Maximum,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl MetricAggregationType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MetricAggregationType::Average => "Average",
+            MetricAggregationType::Minimum => "Minimum",
+            MetricAggregationType::Maximum => "Maximum",
+            MetricAggregationType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for MetricAggregationType {
+    fn default() -> MetricAggregationType {
+        MetricAggregationType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for MetricAggregationType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for MetricAggregationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for MetricAggregationType {
+    fn from(value: &'a str) -> MetricAggregationType {
+        match value {
+            "Average" => MetricAggregationType::Average,
+            "Minimum" => MetricAggregationType::Minimum,
+            "Maximum" => MetricAggregationType::Maximum,
+            _ => MetricAggregationType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MetricAggregationType {
+    fn from(value: String) -> MetricAggregationType {
+        match value.as_str() {
+            "Average" => MetricAggregationType::Average,
+            "Minimum" => MetricAggregationType::Minimum,
+            "Maximum" => MetricAggregationType::Maximum,
+            _ => MetricAggregationType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for MetricAggregationType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<MetricAggregationType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MetricAggregationType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for MetricAggregationType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MetricAggregationType {
+    fn deserialize<D>(deserializer: D) -> Result<MetricAggregationType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes the dimension names and values associated with a metric.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MetricDimension {
@@ -285,12 +481,494 @@ pub struct MetricDimension {
     pub value: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MetricStatistic {
+    /// `Average`
+    Average,
+    /// `Minimum`
+    Minimum,
+    /// `Maximum`
+    Maximum,
+    /// `SampleCount`
+    SampleCount,
+    /// `Sum`
+    Sum,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl MetricStatistic {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MetricStatistic::Average => "Average",
+            MetricStatistic::Minimum => "Minimum",
+            MetricStatistic::Maximum => "Maximum",
+            MetricStatistic::SampleCount => "SampleCount",
+            MetricStatistic::Sum => "Sum",
+            MetricStatistic::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for MetricStatistic {
+    fn default() -> MetricStatistic {
+        MetricStatistic::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for MetricStatistic {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for MetricStatistic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for MetricStatistic {
+    fn from(value: &'a str) -> MetricStatistic {
+        match value {
+            "Average" => MetricStatistic::Average,
+            "Minimum" => MetricStatistic::Minimum,
+            "Maximum" => MetricStatistic::Maximum,
+            "SampleCount" => MetricStatistic::SampleCount,
+            "Sum" => MetricStatistic::Sum,
+            _ => MetricStatistic::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MetricStatistic {
+    fn from(value: String) -> MetricStatistic {
+        match value.as_str() {
+            "Average" => MetricStatistic::Average,
+            "Minimum" => MetricStatistic::Minimum,
+            "Maximum" => MetricStatistic::Maximum,
+            "SampleCount" => MetricStatistic::SampleCount,
+            "Sum" => MetricStatistic::Sum,
+            _ => MetricStatistic::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for MetricStatistic {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<MetricStatistic, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MetricStatistic {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for MetricStatistic {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MetricStatistic {
+    fn deserialize<D>(deserializer: D) -> Result<MetricStatistic, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MetricType {
+    /// `DynamoDBReadCapacityUtilization`
+    DynamoDBReadCapacityUtilization,
+    /// `DynamoDBWriteCapacityUtilization`
+    DynamoDBWriteCapacityUtilization,
+    /// `ALBRequestCountPerTarget`
+    ALBRequestCountPerTarget,
+    /// `RDSReaderAverageCPUUtilization`
+    RDSReaderAverageCPUUtilization,
+    /// `RDSReaderAverageDatabaseConnections`
+    RDSReaderAverageDatabaseConnections,
+    /// `EC2SpotFleetRequestAverageCPUUtilization`
+    EC2SpotFleetRequestAverageCPUUtilization,
+    /// `EC2SpotFleetRequestAverageNetworkIn`
+    EC2SpotFleetRequestAverageNetworkIn,
+    /// `EC2SpotFleetRequestAverageNetworkOut`
+    EC2SpotFleetRequestAverageNetworkOut,
+    /// `SageMakerVariantInvocationsPerInstance`
+    SageMakerVariantInvocationsPerInstance,
+    /// `ECSServiceAverageCPUUtilization`
+    ECSServiceAverageCPUUtilization,
+    /// `ECSServiceAverageMemoryUtilization`
+    ECSServiceAverageMemoryUtilization,
+    /// `AppStreamAverageCapacityUtilization`
+    AppStreamAverageCapacityUtilization,
+    /// `ComprehendInferenceUtilization`
+    ComprehendInferenceUtilization,
+    /// `LambdaProvisionedConcurrencyUtilization`
+    LambdaProvisionedConcurrencyUtilization,
+    /// `CassandraReadCapacityUtilization`
+    CassandraReadCapacityUtilization,
+    /// `CassandraWriteCapacityUtilization`
+    CassandraWriteCapacityUtilization,
+    /// `KafkaBrokerStorageUtilization`
+    KafkaBrokerStorageUtilization,
+    /// `ElastiCachePrimaryEngineCPUUtilization`
+    ElastiCachePrimaryEngineCPUUtilization,
+    /// `ElastiCacheReplicaEngineCPUUtilization`
+    ElastiCacheReplicaEngineCPUUtilization,
+    /// `ElastiCacheDatabaseMemoryUsageCountedForEvictPercentage`
+    ElastiCacheDatabaseMemoryUsageCountedForEvictPercentage,
+    /// `NeptuneReaderAverageCPUUtilization`
+    NeptuneReaderAverageCPUUtilization,
+    /// `SageMakerVariantProvisionedConcurrencyUtilization`
+    SageMakerVariantProvisionedConcurrencyUtilization,
+    /// `ElastiCacheDatabaseCapacityUsageCountedForEvictPercentage`
+    ElastiCacheDatabaseCapacityUsageCountedForEvictPercentage,
+    /// `SageMakerInferenceComponentInvocationsPerCopy`
+    SageMakerInferenceComponentInvocationsPerCopy,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl MetricType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MetricType::DynamoDBReadCapacityUtilization => "DynamoDBReadCapacityUtilization",
+            MetricType::DynamoDBWriteCapacityUtilization => "DynamoDBWriteCapacityUtilization",
+            MetricType::ALBRequestCountPerTarget => "ALBRequestCountPerTarget",
+            MetricType::RDSReaderAverageCPUUtilization => "RDSReaderAverageCPUUtilization",
+            MetricType::RDSReaderAverageDatabaseConnections => {
+                "RDSReaderAverageDatabaseConnections"
+            }
+            MetricType::EC2SpotFleetRequestAverageCPUUtilization => {
+                "EC2SpotFleetRequestAverageCPUUtilization"
+            }
+            MetricType::EC2SpotFleetRequestAverageNetworkIn => {
+                "EC2SpotFleetRequestAverageNetworkIn"
+            }
+            MetricType::EC2SpotFleetRequestAverageNetworkOut => {
+                "EC2SpotFleetRequestAverageNetworkOut"
+            }
+            MetricType::SageMakerVariantInvocationsPerInstance => {
+                "SageMakerVariantInvocationsPerInstance"
+            }
+            MetricType::ECSServiceAverageCPUUtilization => "ECSServiceAverageCPUUtilization",
+            MetricType::ECSServiceAverageMemoryUtilization => "ECSServiceAverageMemoryUtilization",
+            MetricType::AppStreamAverageCapacityUtilization => {
+                "AppStreamAverageCapacityUtilization"
+            }
+            MetricType::ComprehendInferenceUtilization => "ComprehendInferenceUtilization",
+            MetricType::LambdaProvisionedConcurrencyUtilization => {
+                "LambdaProvisionedConcurrencyUtilization"
+            }
+            MetricType::CassandraReadCapacityUtilization => "CassandraReadCapacityUtilization",
+            MetricType::CassandraWriteCapacityUtilization => "CassandraWriteCapacityUtilization",
+            MetricType::KafkaBrokerStorageUtilization => "KafkaBrokerStorageUtilization",
+            MetricType::ElastiCachePrimaryEngineCPUUtilization => {
+                "ElastiCachePrimaryEngineCPUUtilization"
+            }
+            MetricType::ElastiCacheReplicaEngineCPUUtilization => {
+                "ElastiCacheReplicaEngineCPUUtilization"
+            }
+            MetricType::ElastiCacheDatabaseMemoryUsageCountedForEvictPercentage => {
+                "ElastiCacheDatabaseMemoryUsageCountedForEvictPercentage"
+            }
+            MetricType::NeptuneReaderAverageCPUUtilization => "NeptuneReaderAverageCPUUtilization",
+            MetricType::SageMakerVariantProvisionedConcurrencyUtilization => {
+                "SageMakerVariantProvisionedConcurrencyUtilization"
+            }
+            MetricType::ElastiCacheDatabaseCapacityUsageCountedForEvictPercentage => {
+                "ElastiCacheDatabaseCapacityUsageCountedForEvictPercentage"
+            }
+            MetricType::SageMakerInferenceComponentInvocationsPerCopy => {
+                "SageMakerInferenceComponentInvocationsPerCopy"
+            }
+            MetricType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for MetricType {
+    fn default() -> MetricType {
+        MetricType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for MetricType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for MetricType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for MetricType {
+    fn from(value: &'a str) -> MetricType {
+        match value {
+            "DynamoDBReadCapacityUtilization" => MetricType::DynamoDBReadCapacityUtilization,
+            "DynamoDBWriteCapacityUtilization" => MetricType::DynamoDBWriteCapacityUtilization,
+            "ALBRequestCountPerTarget" => MetricType::ALBRequestCountPerTarget,
+            "RDSReaderAverageCPUUtilization" => MetricType::RDSReaderAverageCPUUtilization,
+            "RDSReaderAverageDatabaseConnections" => {
+                MetricType::RDSReaderAverageDatabaseConnections
+            }
+            "EC2SpotFleetRequestAverageCPUUtilization" => {
+                MetricType::EC2SpotFleetRequestAverageCPUUtilization
+            }
+            "EC2SpotFleetRequestAverageNetworkIn" => {
+                MetricType::EC2SpotFleetRequestAverageNetworkIn
+            }
+            "EC2SpotFleetRequestAverageNetworkOut" => {
+                MetricType::EC2SpotFleetRequestAverageNetworkOut
+            }
+            "SageMakerVariantInvocationsPerInstance" => {
+                MetricType::SageMakerVariantInvocationsPerInstance
+            }
+            "ECSServiceAverageCPUUtilization" => MetricType::ECSServiceAverageCPUUtilization,
+            "ECSServiceAverageMemoryUtilization" => MetricType::ECSServiceAverageMemoryUtilization,
+            "AppStreamAverageCapacityUtilization" => {
+                MetricType::AppStreamAverageCapacityUtilization
+            }
+            "ComprehendInferenceUtilization" => MetricType::ComprehendInferenceUtilization,
+            "LambdaProvisionedConcurrencyUtilization" => {
+                MetricType::LambdaProvisionedConcurrencyUtilization
+            }
+            "CassandraReadCapacityUtilization" => MetricType::CassandraReadCapacityUtilization,
+            "CassandraWriteCapacityUtilization" => MetricType::CassandraWriteCapacityUtilization,
+            "KafkaBrokerStorageUtilization" => MetricType::KafkaBrokerStorageUtilization,
+            "ElastiCachePrimaryEngineCPUUtilization" => {
+                MetricType::ElastiCachePrimaryEngineCPUUtilization
+            }
+            "ElastiCacheReplicaEngineCPUUtilization" => {
+                MetricType::ElastiCacheReplicaEngineCPUUtilization
+            }
+            "ElastiCacheDatabaseMemoryUsageCountedForEvictPercentage" => {
+                MetricType::ElastiCacheDatabaseMemoryUsageCountedForEvictPercentage
+            }
+            "NeptuneReaderAverageCPUUtilization" => MetricType::NeptuneReaderAverageCPUUtilization,
+            "SageMakerVariantProvisionedConcurrencyUtilization" => {
+                MetricType::SageMakerVariantProvisionedConcurrencyUtilization
+            }
+            "ElastiCacheDatabaseCapacityUsageCountedForEvictPercentage" => {
+                MetricType::ElastiCacheDatabaseCapacityUsageCountedForEvictPercentage
+            }
+            "SageMakerInferenceComponentInvocationsPerCopy" => {
+                MetricType::SageMakerInferenceComponentInvocationsPerCopy
+            }
+            _ => MetricType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MetricType {
+    fn from(value: String) -> MetricType {
+        match value.as_str() {
+            "DynamoDBReadCapacityUtilization" => MetricType::DynamoDBReadCapacityUtilization,
+            "DynamoDBWriteCapacityUtilization" => MetricType::DynamoDBWriteCapacityUtilization,
+            "ALBRequestCountPerTarget" => MetricType::ALBRequestCountPerTarget,
+            "RDSReaderAverageCPUUtilization" => MetricType::RDSReaderAverageCPUUtilization,
+            "RDSReaderAverageDatabaseConnections" => {
+                MetricType::RDSReaderAverageDatabaseConnections
+            }
+            "EC2SpotFleetRequestAverageCPUUtilization" => {
+                MetricType::EC2SpotFleetRequestAverageCPUUtilization
+            }
+            "EC2SpotFleetRequestAverageNetworkIn" => {
+                MetricType::EC2SpotFleetRequestAverageNetworkIn
+            }
+            "EC2SpotFleetRequestAverageNetworkOut" => {
+                MetricType::EC2SpotFleetRequestAverageNetworkOut
+            }
+            "SageMakerVariantInvocationsPerInstance" => {
+                MetricType::SageMakerVariantInvocationsPerInstance
+            }
+            "ECSServiceAverageCPUUtilization" => MetricType::ECSServiceAverageCPUUtilization,
+            "ECSServiceAverageMemoryUtilization" => MetricType::ECSServiceAverageMemoryUtilization,
+            "AppStreamAverageCapacityUtilization" => {
+                MetricType::AppStreamAverageCapacityUtilization
+            }
+            "ComprehendInferenceUtilization" => MetricType::ComprehendInferenceUtilization,
+            "LambdaProvisionedConcurrencyUtilization" => {
+                MetricType::LambdaProvisionedConcurrencyUtilization
+            }
+            "CassandraReadCapacityUtilization" => MetricType::CassandraReadCapacityUtilization,
+            "CassandraWriteCapacityUtilization" => MetricType::CassandraWriteCapacityUtilization,
+            "KafkaBrokerStorageUtilization" => MetricType::KafkaBrokerStorageUtilization,
+            "ElastiCachePrimaryEngineCPUUtilization" => {
+                MetricType::ElastiCachePrimaryEngineCPUUtilization
+            }
+            "ElastiCacheReplicaEngineCPUUtilization" => {
+                MetricType::ElastiCacheReplicaEngineCPUUtilization
+            }
+            "ElastiCacheDatabaseMemoryUsageCountedForEvictPercentage" => {
+                MetricType::ElastiCacheDatabaseMemoryUsageCountedForEvictPercentage
+            }
+            "NeptuneReaderAverageCPUUtilization" => MetricType::NeptuneReaderAverageCPUUtilization,
+            "SageMakerVariantProvisionedConcurrencyUtilization" => {
+                MetricType::SageMakerVariantProvisionedConcurrencyUtilization
+            }
+            "ElastiCacheDatabaseCapacityUsageCountedForEvictPercentage" => {
+                MetricType::ElastiCacheDatabaseCapacityUsageCountedForEvictPercentage
+            }
+            "SageMakerInferenceComponentInvocationsPerCopy" => {
+                MetricType::SageMakerInferenceComponentInvocationsPerCopy
+            }
+            _ => MetricType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for MetricType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<MetricType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MetricType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for MetricType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MetricType {
+    fn deserialize<D>(deserializer: D) -> Result<MetricType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PolicyType {
+    /// `StepScaling`
+    StepScaling,
+    /// `TargetTrackingScaling`
+    TargetTrackingScaling,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PolicyType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PolicyType::StepScaling => "StepScaling",
+            PolicyType::TargetTrackingScaling => "TargetTrackingScaling",
+            PolicyType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PolicyType {
+    fn default() -> PolicyType {
+        PolicyType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PolicyType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PolicyType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PolicyType {
+    fn from(value: &'a str) -> PolicyType {
+        match value {
+            "StepScaling" => PolicyType::StepScaling,
+            "TargetTrackingScaling" => PolicyType::TargetTrackingScaling,
+            _ => PolicyType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PolicyType {
+    fn from(value: String) -> PolicyType {
+        match value.as_str() {
+            "StepScaling" => PolicyType::StepScaling,
+            "TargetTrackingScaling" => PolicyType::TargetTrackingScaling,
+            _ => PolicyType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PolicyType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PolicyType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PolicyType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PolicyType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PolicyType {
+    fn deserialize<D>(deserializer: D) -> Result<PolicyType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents a predefined metric for a target tracking scaling policy to use with Application Auto Scaling.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PredefinedMetricSpecification {
     /// <p>The metric type. The <code>ALBRequestCountPerTarget</code> metric type applies only to Spot Fleet requests and ECS services.</p>
     #[serde(rename = "PredefinedMetricType")]
-    pub predefined_metric_type: String,
+    pub predefined_metric_type: MetricType,
     /// <p><p>Identifies the resource associated with the metric type. You can&#39;t specify a resource label unless the metric type is <code>ALBRequestCountPerTarget</code> and there is a target group attached to the Spot Fleet request or ECS service.</p> <p>The format is app/&lt;load-balancer-name&gt;/&lt;load-balancer-id&gt;/targetgroup/&lt;target-group-name&gt;/&lt;target-group-id&gt;, where:</p> <ul> <li> <p>app/&lt;load-balancer-name&gt;/&lt;load-balancer-id&gt; is the final portion of the load balancer ARN</p> </li> <li> <p>targetgroup/&lt;target-group-name&gt;/&lt;target-group-id&gt; is the final portion of the target group ARN.</p> </li> </ul></p>
     #[serde(rename = "ResourceLabel")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -306,16 +984,16 @@ pub struct PutScalingPolicyRequest {
     /// <p>The policy type. This parameter is required if you are creating a scaling policy.</p> <p>The following policy types are supported: </p> <p> <code>TargetTrackingScaling</code>—Not supported for Amazon EMR</p> <p> <code>StepScaling</code>—Not supported for DynamoDB, Amazon Comprehend, or AWS Lambda</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/application/userguide/application-auto-scaling-target-tracking.html">Target Tracking Scaling Policies</a> and <a href="https://docs.aws.amazon.com/autoscaling/application/userguide/application-auto-scaling-step-scaling-policies.html">Step Scaling Policies</a> in the <i>Application Auto Scaling User Guide</i>.</p>
     #[serde(rename = "PolicyType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub policy_type: Option<String>,
+    pub policy_type: Option<PolicyType>,
     /// <p><p>The identifier of the resource associated with the scaling policy. This string consists of the resource type and unique identifier.</p> <ul> <li> <p>ECS service - The resource type is <code>service</code> and the unique identifier is the cluster name and service name. Example: <code>service/default/sample-webapp</code>.</p> </li> <li> <p>Spot Fleet request - The resource type is <code>spot-fleet-request</code> and the unique identifier is the Spot Fleet request ID. Example: <code>spot-fleet-request/sfr-73fbd2ce-aa30-494c-8788-1cee4EXAMPLE</code>.</p> </li> <li> <p>EMR cluster - The resource type is <code>instancegroup</code> and the unique identifier is the cluster ID and instance group ID. Example: <code>instancegroup/j-2EEZNYKUA1NTV/ig-1791Y4E1L8YI0</code>.</p> </li> <li> <p>AppStream 2.0 fleet - The resource type is <code>fleet</code> and the unique identifier is the fleet name. Example: <code>fleet/sample-fleet</code>.</p> </li> <li> <p>DynamoDB table - The resource type is <code>table</code> and the unique identifier is the table name. Example: <code>table/my-table</code>.</p> </li> <li> <p>DynamoDB global secondary index - The resource type is <code>index</code> and the unique identifier is the index name. Example: <code>table/my-table/index/my-table-index</code>.</p> </li> <li> <p>Aurora DB cluster - The resource type is <code>cluster</code> and the unique identifier is the cluster name. Example: <code>cluster:my-db-cluster</code>.</p> </li> <li> <p>Amazon SageMaker endpoint variant - The resource type is <code>variant</code> and the unique identifier is the resource ID. Example: <code>endpoint/my-end-point/variant/KMeansClustering</code>.</p> </li> <li> <p>Custom resources are not supported with a resource type. This parameter must specify the <code>OutputValue</code> from the CloudFormation template stack used to access the resources. The unique identifier is defined by the service provider. More information is available in our <a href="https://github.com/aws/aws-auto-scaling-custom-resource">GitHub repository</a>.</p> </li> <li> <p>Amazon Comprehend document classification endpoint - The resource type and unique identifier are specified using the endpoint ARN. Example: <code>arn:aws:comprehend:us-west-2:123456789012:document-classifier-endpoint/EXAMPLE</code>.</p> </li> <li> <p>Lambda provisioned concurrency - The resource type is <code>function</code> and the unique identifier is the function name with a function version or alias name suffix that is not <code>$LATEST</code>. Example: <code>function:my-function:prod</code> or <code>function:my-function:1</code>.</p> </li> </ul></p>
     #[serde(rename = "ResourceId")]
     pub resource_id: String,
     /// <p><p>The scalable dimension. This string consists of the service namespace, resource type, and scaling property.</p> <ul> <li> <p> <code>ecs:service:DesiredCount</code> - The desired task count of an ECS service.</p> </li> <li> <p> <code>ec2:spot-fleet-request:TargetCapacity</code> - The target capacity of a Spot Fleet request.</p> </li> <li> <p> <code>elasticmapreduce:instancegroup:InstanceCount</code> - The instance count of an EMR Instance Group.</p> </li> <li> <p> <code>appstream:fleet:DesiredCapacity</code> - The desired capacity of an AppStream 2.0 fleet.</p> </li> <li> <p> <code>dynamodb:table:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:table:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:index:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>dynamodb:index:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>rds:cluster:ReadReplicaCount</code> - The count of Aurora Replicas in an Aurora DB cluster. Available for Aurora MySQL-compatible edition and Aurora PostgreSQL-compatible edition.</p> </li> <li> <p> <code>sagemaker:variant:DesiredInstanceCount</code> - The number of EC2 instances for an Amazon SageMaker model endpoint variant.</p> </li> <li> <p> <code>custom-resource:ResourceType:Property</code> - The scalable dimension for a custom resource provided by your own application or service.</p> </li> <li> <p> <code>comprehend:document-classifier-endpoint:DesiredInferenceUnits</code> - The number of inference units for an Amazon Comprehend document classification endpoint.</p> </li> <li> <p> <code>lambda:function:ProvisionedConcurrency</code> - The provisioned concurrency for a Lambda function.</p> </li> </ul></p>
     #[serde(rename = "ScalableDimension")]
-    pub scalable_dimension: String,
+    pub scalable_dimension: ScalableDimension,
     /// <p>The namespace of the AWS service that provides the resource or <code>custom-resource</code> for a resource provided by your own application or service. For more information, see <a href="http://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html#genref-aws-service-namespaces">AWS Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p>
     #[serde(rename = "ServiceNamespace")]
-    pub service_namespace: String,
+    pub service_namespace: ServiceNamespace,
     /// <p>A step scaling policy.</p> <p>This parameter is required if you are creating a policy and the policy type is <code>StepScaling</code>.</p>
     #[serde(rename = "StepScalingPolicyConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -344,14 +1022,19 @@ pub struct PutScalingPolicyResponse {
 pub struct PutScheduledActionRequest {
     /// <p>The date and time for the scheduled action to end.</p>
     #[serde(rename = "EndTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>The identifier of the resource associated with the scheduled action. This string consists of the resource type and unique identifier.</p> <ul> <li> <p>ECS service - The resource type is <code>service</code> and the unique identifier is the cluster name and service name. Example: <code>service/default/sample-webapp</code>.</p> </li> <li> <p>Spot Fleet request - The resource type is <code>spot-fleet-request</code> and the unique identifier is the Spot Fleet request ID. Example: <code>spot-fleet-request/sfr-73fbd2ce-aa30-494c-8788-1cee4EXAMPLE</code>.</p> </li> <li> <p>EMR cluster - The resource type is <code>instancegroup</code> and the unique identifier is the cluster ID and instance group ID. Example: <code>instancegroup/j-2EEZNYKUA1NTV/ig-1791Y4E1L8YI0</code>.</p> </li> <li> <p>AppStream 2.0 fleet - The resource type is <code>fleet</code> and the unique identifier is the fleet name. Example: <code>fleet/sample-fleet</code>.</p> </li> <li> <p>DynamoDB table - The resource type is <code>table</code> and the unique identifier is the table name. Example: <code>table/my-table</code>.</p> </li> <li> <p>DynamoDB global secondary index - The resource type is <code>index</code> and the unique identifier is the index name. Example: <code>table/my-table/index/my-table-index</code>.</p> </li> <li> <p>Aurora DB cluster - The resource type is <code>cluster</code> and the unique identifier is the cluster name. Example: <code>cluster:my-db-cluster</code>.</p> </li> <li> <p>Amazon SageMaker endpoint variant - The resource type is <code>variant</code> and the unique identifier is the resource ID. Example: <code>endpoint/my-end-point/variant/KMeansClustering</code>.</p> </li> <li> <p>Custom resources are not supported with a resource type. This parameter must specify the <code>OutputValue</code> from the CloudFormation template stack used to access the resources. The unique identifier is defined by the service provider. More information is available in our <a href="https://github.com/aws/aws-auto-scaling-custom-resource">GitHub repository</a>.</p> </li> <li> <p>Amazon Comprehend document classification endpoint - The resource type and unique identifier are specified using the endpoint ARN. Example: <code>arn:aws:comprehend:us-west-2:123456789012:document-classifier-endpoint/EXAMPLE</code>.</p> </li> <li> <p>Lambda provisioned concurrency - The resource type is <code>function</code> and the unique identifier is the function name with a function version or alias name suffix that is not <code>$LATEST</code>. Example: <code>function:my-function:prod</code> or <code>function:my-function:1</code>.</p> </li> </ul></p>
     #[serde(rename = "ResourceId")]
     pub resource_id: String,
     /// <p><p>The scalable dimension. This string consists of the service namespace, resource type, and scaling property.</p> <ul> <li> <p> <code>ecs:service:DesiredCount</code> - The desired task count of an ECS service.</p> </li> <li> <p> <code>ec2:spot-fleet-request:TargetCapacity</code> - The target capacity of a Spot Fleet request.</p> </li> <li> <p> <code>elasticmapreduce:instancegroup:InstanceCount</code> - The instance count of an EMR Instance Group.</p> </li> <li> <p> <code>appstream:fleet:DesiredCapacity</code> - The desired capacity of an AppStream 2.0 fleet.</p> </li> <li> <p> <code>dynamodb:table:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:table:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:index:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>dynamodb:index:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>rds:cluster:ReadReplicaCount</code> - The count of Aurora Replicas in an Aurora DB cluster. Available for Aurora MySQL-compatible edition and Aurora PostgreSQL-compatible edition.</p> </li> <li> <p> <code>sagemaker:variant:DesiredInstanceCount</code> - The number of EC2 instances for an Amazon SageMaker model endpoint variant.</p> </li> <li> <p> <code>custom-resource:ResourceType:Property</code> - The scalable dimension for a custom resource provided by your own application or service.</p> </li> <li> <p> <code>comprehend:document-classifier-endpoint:DesiredInferenceUnits</code> - The number of inference units for an Amazon Comprehend document classification endpoint.</p> </li> <li> <p> <code>lambda:function:ProvisionedConcurrency</code> - The provisioned concurrency for a Lambda function.</p> </li> </ul></p>
     #[serde(rename = "ScalableDimension")]
-    pub scalable_dimension: String,
+    pub scalable_dimension: ScalableDimension,
     /// <p>The new minimum and maximum capacity. You can set both values or just one. During the scheduled time, if the current capacity is below the minimum capacity, Application Auto Scaling scales out to the minimum capacity. If the current capacity is above the maximum capacity, Application Auto Scaling scales in to the maximum capacity.</p>
     #[serde(rename = "ScalableTargetAction")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -365,11 +1048,16 @@ pub struct PutScheduledActionRequest {
     pub scheduled_action_name: String,
     /// <p>The namespace of the AWS service that provides the resource or <code>custom-resource</code> for a resource provided by your own application or service. For more information, see <a href="http://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html#genref-aws-service-namespaces">AWS Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p>
     #[serde(rename = "ServiceNamespace")]
-    pub service_namespace: String,
+    pub service_namespace: ServiceNamespace,
     /// <p>The date and time for the scheduled action to start.</p>
     #[serde(rename = "StartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -396,10 +1084,10 @@ pub struct RegisterScalableTargetRequest {
     pub role_arn: Option<String>,
     /// <p><p>The scalable dimension associated with the scalable target. This string consists of the service namespace, resource type, and scaling property.</p> <ul> <li> <p> <code>ecs:service:DesiredCount</code> - The desired task count of an ECS service.</p> </li> <li> <p> <code>ec2:spot-fleet-request:TargetCapacity</code> - The target capacity of a Spot Fleet request.</p> </li> <li> <p> <code>elasticmapreduce:instancegroup:InstanceCount</code> - The instance count of an EMR Instance Group.</p> </li> <li> <p> <code>appstream:fleet:DesiredCapacity</code> - The desired capacity of an AppStream 2.0 fleet.</p> </li> <li> <p> <code>dynamodb:table:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:table:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:index:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>dynamodb:index:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>rds:cluster:ReadReplicaCount</code> - The count of Aurora Replicas in an Aurora DB cluster. Available for Aurora MySQL-compatible edition and Aurora PostgreSQL-compatible edition.</p> </li> <li> <p> <code>sagemaker:variant:DesiredInstanceCount</code> - The number of EC2 instances for an Amazon SageMaker model endpoint variant.</p> </li> <li> <p> <code>custom-resource:ResourceType:Property</code> - The scalable dimension for a custom resource provided by your own application or service.</p> </li> <li> <p> <code>comprehend:document-classifier-endpoint:DesiredInferenceUnits</code> - The number of inference units for an Amazon Comprehend document classification endpoint.</p> </li> <li> <p> <code>lambda:function:ProvisionedConcurrency</code> - The provisioned concurrency for a Lambda function.</p> </li> </ul></p>
     #[serde(rename = "ScalableDimension")]
-    pub scalable_dimension: String,
+    pub scalable_dimension: ScalableDimension,
     /// <p>The namespace of the AWS service that provides the resource or <code>custom-resource</code> for a resource provided by your own application or service. For more information, see <a href="http://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html#genref-aws-service-namespaces">AWS Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>. </p>
     #[serde(rename = "ServiceNamespace")]
-    pub service_namespace: String,
+    pub service_namespace: ServiceNamespace,
     /// <p>An embedded object that contains attributes and attribute values that are used to suspend and resume automatic scaling. Setting the value of an attribute to <code>true</code> suspends the specified scaling activities. Setting it to <code>false</code> (default) resumes the specified scaling activities. </p> <p> <b>Suspension Outcomes</b> </p> <ul> <li> <p>For <code>DynamicScalingInSuspended</code>, while a suspension is in effect, all scale-in activities that are triggered by a scaling policy are suspended.</p> </li> <li> <p>For <code>DynamicScalingOutSuspended</code>, while a suspension is in effect, all scale-out activities that are triggered by a scaling policy are suspended.</p> </li> <li> <p>For <code>ScheduledScalingSuspended</code>, while a suspension is in effect, all scaling activities that involve scheduled actions are suspended. </p> </li> </ul> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/application/userguide/application-auto-scaling-suspend-resume-scaling.html">Suspending and Resuming Scaling</a> in the <i>Application Auto Scaling User Guide</i>.</p>
     #[serde(rename = "SuspendedState")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -410,13 +1098,301 @@ pub struct RegisterScalableTargetRequest {
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RegisterScalableTargetResponse {}
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ScalableDimension {
+    /// `ecs:service:DesiredCount`
+    EcsServiceDesiredCount,
+    /// `ec2:spot-fleet-request:TargetCapacity`
+    Ec2SpotFleetRequestTargetCapacity,
+    /// `elasticmapreduce:instancegroup:InstanceCount`
+    ElasticmapreduceInstancegroupInstanceCount,
+    /// `appstream:fleet:DesiredCapacity`
+    AppstreamFleetDesiredCapacity,
+    /// `dynamodb:table:ReadCapacityUnits`
+    DynamodbTableReadCapacityUnits,
+    /// `dynamodb:table:WriteCapacityUnits`
+    DynamodbTableWriteCapacityUnits,
+    /// `dynamodb:index:ReadCapacityUnits`
+    DynamodbIndexReadCapacityUnits,
+    /// `dynamodb:index:WriteCapacityUnits`
+    DynamodbIndexWriteCapacityUnits,
+    /// `rds:cluster:ReadReplicaCount`
+    RdsClusterReadReplicaCount,
+    /// `sagemaker:variant:DesiredInstanceCount`
+    SagemakerVariantDesiredInstanceCount,
+    /// `custom-resource:ResourceType:Property`
+    CustomResourceResourceTypeProperty,
+    /// `comprehend:document-classifier-endpoint:DesiredInferenceUnits`
+    ComprehendDocumentClassifierEndpointDesiredInferenceUnits,
+    /// `comprehend:entity-recognizer-endpoint:DesiredInferenceUnits`
+    ComprehendEntityRecognizerEndpointDesiredInferenceUnits,
+    /// `lambda:function:ProvisionedConcurrency`
+    LambdaFunctionProvisionedConcurrency,
+    /// `cassandra:table:ReadCapacityUnits`
+    CassandraTableReadCapacityUnits,
+    /// `cassandra:table:WriteCapacityUnits`
+    CassandraTableWriteCapacityUnits,
+    /// `kafka:broker-storage:VolumeSize`
+    KafkaBrokerStorageVolumeSize,
+    /// `elasticache:replication-group:NodeGroups`
+    ElasticacheReplicationGroupNodeGroups,
+    /// `elasticache:replication-group:Replicas`
+    ElasticacheReplicationGroupReplicas,
+    /// `neptune:cluster:ReadReplicaCount`
+    NeptuneClusterReadReplicaCount,
+    /// `sagemaker:variant:DesiredProvisionedConcurrency`
+    SagemakerVariantDesiredProvisionedConcurrency,
+    /// `sagemaker:inference-component:DesiredCopyCount`
+    SagemakerInferenceComponentDesiredCopyCount,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ScalableDimension {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ScalableDimension::EcsServiceDesiredCount => "ecs:service:DesiredCount",
+            ScalableDimension::Ec2SpotFleetRequestTargetCapacity => {
+                "ec2:spot-fleet-request:TargetCapacity"
+            }
+            ScalableDimension::ElasticmapreduceInstancegroupInstanceCount => {
+                "elasticmapreduce:instancegroup:InstanceCount"
+            }
+            ScalableDimension::AppstreamFleetDesiredCapacity => "appstream:fleet:DesiredCapacity",
+            ScalableDimension::DynamodbTableReadCapacityUnits => "dynamodb:table:ReadCapacityUnits",
+            ScalableDimension::DynamodbTableWriteCapacityUnits => {
+                "dynamodb:table:WriteCapacityUnits"
+            }
+            ScalableDimension::DynamodbIndexReadCapacityUnits => "dynamodb:index:ReadCapacityUnits",
+            ScalableDimension::DynamodbIndexWriteCapacityUnits => {
+                "dynamodb:index:WriteCapacityUnits"
+            }
+            ScalableDimension::RdsClusterReadReplicaCount => "rds:cluster:ReadReplicaCount",
+            ScalableDimension::SagemakerVariantDesiredInstanceCount => {
+                "sagemaker:variant:DesiredInstanceCount"
+            }
+            ScalableDimension::CustomResourceResourceTypeProperty => {
+                "custom-resource:ResourceType:Property"
+            }
+            ScalableDimension::ComprehendDocumentClassifierEndpointDesiredInferenceUnits => {
+                "comprehend:document-classifier-endpoint:DesiredInferenceUnits"
+            }
+            ScalableDimension::ComprehendEntityRecognizerEndpointDesiredInferenceUnits => {
+                "comprehend:entity-recognizer-endpoint:DesiredInferenceUnits"
+            }
+            ScalableDimension::LambdaFunctionProvisionedConcurrency => {
+                "lambda:function:ProvisionedConcurrency"
+            }
+            ScalableDimension::CassandraTableReadCapacityUnits => {
+                "cassandra:table:ReadCapacityUnits"
+            }
+            ScalableDimension::CassandraTableWriteCapacityUnits => {
+                "cassandra:table:WriteCapacityUnits"
+            }
+            ScalableDimension::KafkaBrokerStorageVolumeSize => "kafka:broker-storage:VolumeSize",
+            ScalableDimension::ElasticacheReplicationGroupNodeGroups => {
+                "elasticache:replication-group:NodeGroups"
+            }
+            ScalableDimension::ElasticacheReplicationGroupReplicas => {
+                "elasticache:replication-group:Replicas"
+            }
+            ScalableDimension::NeptuneClusterReadReplicaCount => "neptune:cluster:ReadReplicaCount",
+            ScalableDimension::SagemakerVariantDesiredProvisionedConcurrency => {
+                "sagemaker:variant:DesiredProvisionedConcurrency"
+            }
+            ScalableDimension::SagemakerInferenceComponentDesiredCopyCount => {
+                "sagemaker:inference-component:DesiredCopyCount"
+            }
+            ScalableDimension::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ScalableDimension {
+    fn default() -> ScalableDimension {
+        ScalableDimension::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ScalableDimension {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ScalableDimension {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ScalableDimension {
+    fn from(value: &'a str) -> ScalableDimension {
+        match value {
+            "ecs:service:DesiredCount" => ScalableDimension::EcsServiceDesiredCount,
+            "ec2:spot-fleet-request:TargetCapacity" => {
+                ScalableDimension::Ec2SpotFleetRequestTargetCapacity
+            }
+            "elasticmapreduce:instancegroup:InstanceCount" => {
+                ScalableDimension::ElasticmapreduceInstancegroupInstanceCount
+            }
+            "appstream:fleet:DesiredCapacity" => ScalableDimension::AppstreamFleetDesiredCapacity,
+            "dynamodb:table:ReadCapacityUnits" => ScalableDimension::DynamodbTableReadCapacityUnits,
+            "dynamodb:table:WriteCapacityUnits" => {
+                ScalableDimension::DynamodbTableWriteCapacityUnits
+            }
+            "dynamodb:index:ReadCapacityUnits" => ScalableDimension::DynamodbIndexReadCapacityUnits,
+            "dynamodb:index:WriteCapacityUnits" => {
+                ScalableDimension::DynamodbIndexWriteCapacityUnits
+            }
+            "rds:cluster:ReadReplicaCount" => ScalableDimension::RdsClusterReadReplicaCount,
+            "sagemaker:variant:DesiredInstanceCount" => {
+                ScalableDimension::SagemakerVariantDesiredInstanceCount
+            }
+            "custom-resource:ResourceType:Property" => {
+                ScalableDimension::CustomResourceResourceTypeProperty
+            }
+            "comprehend:document-classifier-endpoint:DesiredInferenceUnits" => {
+                ScalableDimension::ComprehendDocumentClassifierEndpointDesiredInferenceUnits
+            }
+            "comprehend:entity-recognizer-endpoint:DesiredInferenceUnits" => {
+                ScalableDimension::ComprehendEntityRecognizerEndpointDesiredInferenceUnits
+            }
+            "lambda:function:ProvisionedConcurrency" => {
+                ScalableDimension::LambdaFunctionProvisionedConcurrency
+            }
+            "cassandra:table:ReadCapacityUnits" => {
+                ScalableDimension::CassandraTableReadCapacityUnits
+            }
+            "cassandra:table:WriteCapacityUnits" => {
+                ScalableDimension::CassandraTableWriteCapacityUnits
+            }
+            "kafka:broker-storage:VolumeSize" => ScalableDimension::KafkaBrokerStorageVolumeSize,
+            "elasticache:replication-group:NodeGroups" => {
+                ScalableDimension::ElasticacheReplicationGroupNodeGroups
+            }
+            "elasticache:replication-group:Replicas" => {
+                ScalableDimension::ElasticacheReplicationGroupReplicas
+            }
+            "neptune:cluster:ReadReplicaCount" => ScalableDimension::NeptuneClusterReadReplicaCount,
+            "sagemaker:variant:DesiredProvisionedConcurrency" => {
+                ScalableDimension::SagemakerVariantDesiredProvisionedConcurrency
+            }
+            "sagemaker:inference-component:DesiredCopyCount" => {
+                ScalableDimension::SagemakerInferenceComponentDesiredCopyCount
+            }
+            _ => ScalableDimension::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ScalableDimension {
+    fn from(value: String) -> ScalableDimension {
+        match value.as_str() {
+            "ecs:service:DesiredCount" => ScalableDimension::EcsServiceDesiredCount,
+            "ec2:spot-fleet-request:TargetCapacity" => {
+                ScalableDimension::Ec2SpotFleetRequestTargetCapacity
+            }
+            "elasticmapreduce:instancegroup:InstanceCount" => {
+                ScalableDimension::ElasticmapreduceInstancegroupInstanceCount
+            }
+            "appstream:fleet:DesiredCapacity" => ScalableDimension::AppstreamFleetDesiredCapacity,
+            "dynamodb:table:ReadCapacityUnits" => ScalableDimension::DynamodbTableReadCapacityUnits,
+            "dynamodb:table:WriteCapacityUnits" => {
+                ScalableDimension::DynamodbTableWriteCapacityUnits
+            }
+            "dynamodb:index:ReadCapacityUnits" => ScalableDimension::DynamodbIndexReadCapacityUnits,
+            "dynamodb:index:WriteCapacityUnits" => {
+                ScalableDimension::DynamodbIndexWriteCapacityUnits
+            }
+            "rds:cluster:ReadReplicaCount" => ScalableDimension::RdsClusterReadReplicaCount,
+            "sagemaker:variant:DesiredInstanceCount" => {
+                ScalableDimension::SagemakerVariantDesiredInstanceCount
+            }
+            "custom-resource:ResourceType:Property" => {
+                ScalableDimension::CustomResourceResourceTypeProperty
+            }
+            "comprehend:document-classifier-endpoint:DesiredInferenceUnits" => {
+                ScalableDimension::ComprehendDocumentClassifierEndpointDesiredInferenceUnits
+            }
+            "comprehend:entity-recognizer-endpoint:DesiredInferenceUnits" => {
+                ScalableDimension::ComprehendEntityRecognizerEndpointDesiredInferenceUnits
+            }
+            "lambda:function:ProvisionedConcurrency" => {
+                ScalableDimension::LambdaFunctionProvisionedConcurrency
+            }
+            "cassandra:table:ReadCapacityUnits" => {
+                ScalableDimension::CassandraTableReadCapacityUnits
+            }
+            "cassandra:table:WriteCapacityUnits" => {
+                ScalableDimension::CassandraTableWriteCapacityUnits
+            }
+            "kafka:broker-storage:VolumeSize" => ScalableDimension::KafkaBrokerStorageVolumeSize,
+            "elasticache:replication-group:NodeGroups" => {
+                ScalableDimension::ElasticacheReplicationGroupNodeGroups
+            }
+            "elasticache:replication-group:Replicas" => {
+                ScalableDimension::ElasticacheReplicationGroupReplicas
+            }
+            "neptune:cluster:ReadReplicaCount" => ScalableDimension::NeptuneClusterReadReplicaCount,
+            "sagemaker:variant:DesiredProvisionedConcurrency" => {
+                ScalableDimension::SagemakerVariantDesiredProvisionedConcurrency
+            }
+            "sagemaker:inference-component:DesiredCopyCount" => {
+                ScalableDimension::SagemakerInferenceComponentDesiredCopyCount
+            }
+            _ => ScalableDimension::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ScalableDimension {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ScalableDimension, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ScalableDimension {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ScalableDimension {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ScalableDimension {
+    fn deserialize<D>(deserializer: D) -> Result<ScalableDimension, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents a scalable target.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ScalableTarget {
     /// <p>The Unix timestamp for when the scalable target was created.</p>
     #[serde(rename = "CreationTime")]
-    pub creation_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub creation_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The maximum value to scale to in response to a scale-out event.</p>
     #[serde(rename = "MaxCapacity")]
     pub max_capacity: i64,
@@ -431,10 +1407,10 @@ pub struct ScalableTarget {
     pub role_arn: String,
     /// <p><p>The scalable dimension associated with the scalable target. This string consists of the service namespace, resource type, and scaling property.</p> <ul> <li> <p> <code>ecs:service:DesiredCount</code> - The desired task count of an ECS service.</p> </li> <li> <p> <code>ec2:spot-fleet-request:TargetCapacity</code> - The target capacity of a Spot Fleet request.</p> </li> <li> <p> <code>elasticmapreduce:instancegroup:InstanceCount</code> - The instance count of an EMR Instance Group.</p> </li> <li> <p> <code>appstream:fleet:DesiredCapacity</code> - The desired capacity of an AppStream 2.0 fleet.</p> </li> <li> <p> <code>dynamodb:table:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:table:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:index:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>dynamodb:index:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>rds:cluster:ReadReplicaCount</code> - The count of Aurora Replicas in an Aurora DB cluster. Available for Aurora MySQL-compatible edition and Aurora PostgreSQL-compatible edition.</p> </li> <li> <p> <code>sagemaker:variant:DesiredInstanceCount</code> - The number of EC2 instances for an Amazon SageMaker model endpoint variant.</p> </li> <li> <p> <code>custom-resource:ResourceType:Property</code> - The scalable dimension for a custom resource provided by your own application or service.</p> </li> <li> <p> <code>comprehend:document-classifier-endpoint:DesiredInferenceUnits</code> - The number of inference units for an Amazon Comprehend document classification endpoint.</p> </li> <li> <p> <code>lambda:function:ProvisionedConcurrency</code> - The provisioned concurrency for a Lambda function.</p> </li> </ul></p>
     #[serde(rename = "ScalableDimension")]
-    pub scalable_dimension: String,
+    pub scalable_dimension: ScalableDimension,
     /// <p>The namespace of the AWS service that provides the resource or <code>custom-resource</code> for a resource provided by your own application or service. For more information, see <a href="http://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html#genref-aws-service-namespaces">AWS Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p>
     #[serde(rename = "ServiceNamespace")]
-    pub service_namespace: String,
+    pub service_namespace: ServiceNamespace,
     #[serde(rename = "SuspendedState")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub suspended_state: Option<SuspendedState>,
@@ -472,29 +1448,152 @@ pub struct ScalingActivity {
     pub details: Option<String>,
     /// <p>The Unix timestamp for when the scaling activity ended.</p>
     #[serde(rename = "EndTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>The identifier of the resource associated with the scaling activity. This string consists of the resource type and unique identifier.</p> <ul> <li> <p>ECS service - The resource type is <code>service</code> and the unique identifier is the cluster name and service name. Example: <code>service/default/sample-webapp</code>.</p> </li> <li> <p>Spot Fleet request - The resource type is <code>spot-fleet-request</code> and the unique identifier is the Spot Fleet request ID. Example: <code>spot-fleet-request/sfr-73fbd2ce-aa30-494c-8788-1cee4EXAMPLE</code>.</p> </li> <li> <p>EMR cluster - The resource type is <code>instancegroup</code> and the unique identifier is the cluster ID and instance group ID. Example: <code>instancegroup/j-2EEZNYKUA1NTV/ig-1791Y4E1L8YI0</code>.</p> </li> <li> <p>AppStream 2.0 fleet - The resource type is <code>fleet</code> and the unique identifier is the fleet name. Example: <code>fleet/sample-fleet</code>.</p> </li> <li> <p>DynamoDB table - The resource type is <code>table</code> and the unique identifier is the table name. Example: <code>table/my-table</code>.</p> </li> <li> <p>DynamoDB global secondary index - The resource type is <code>index</code> and the unique identifier is the index name. Example: <code>table/my-table/index/my-table-index</code>.</p> </li> <li> <p>Aurora DB cluster - The resource type is <code>cluster</code> and the unique identifier is the cluster name. Example: <code>cluster:my-db-cluster</code>.</p> </li> <li> <p>Amazon SageMaker endpoint variant - The resource type is <code>variant</code> and the unique identifier is the resource ID. Example: <code>endpoint/my-end-point/variant/KMeansClustering</code>.</p> </li> <li> <p>Custom resources are not supported with a resource type. This parameter must specify the <code>OutputValue</code> from the CloudFormation template stack used to access the resources. The unique identifier is defined by the service provider. More information is available in our <a href="https://github.com/aws/aws-auto-scaling-custom-resource">GitHub repository</a>.</p> </li> <li> <p>Amazon Comprehend document classification endpoint - The resource type and unique identifier are specified using the endpoint ARN. Example: <code>arn:aws:comprehend:us-west-2:123456789012:document-classifier-endpoint/EXAMPLE</code>.</p> </li> <li> <p>Lambda provisioned concurrency - The resource type is <code>function</code> and the unique identifier is the function name with a function version or alias name suffix that is not <code>$LATEST</code>. Example: <code>function:my-function:prod</code> or <code>function:my-function:1</code>.</p> </li> </ul></p>
     #[serde(rename = "ResourceId")]
     pub resource_id: String,
     /// <p><p>The scalable dimension. This string consists of the service namespace, resource type, and scaling property.</p> <ul> <li> <p> <code>ecs:service:DesiredCount</code> - The desired task count of an ECS service.</p> </li> <li> <p> <code>ec2:spot-fleet-request:TargetCapacity</code> - The target capacity of a Spot Fleet request.</p> </li> <li> <p> <code>elasticmapreduce:instancegroup:InstanceCount</code> - The instance count of an EMR Instance Group.</p> </li> <li> <p> <code>appstream:fleet:DesiredCapacity</code> - The desired capacity of an AppStream 2.0 fleet.</p> </li> <li> <p> <code>dynamodb:table:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:table:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:index:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>dynamodb:index:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>rds:cluster:ReadReplicaCount</code> - The count of Aurora Replicas in an Aurora DB cluster. Available for Aurora MySQL-compatible edition and Aurora PostgreSQL-compatible edition.</p> </li> <li> <p> <code>sagemaker:variant:DesiredInstanceCount</code> - The number of EC2 instances for an Amazon SageMaker model endpoint variant.</p> </li> <li> <p> <code>custom-resource:ResourceType:Property</code> - The scalable dimension for a custom resource provided by your own application or service.</p> </li> <li> <p> <code>comprehend:document-classifier-endpoint:DesiredInferenceUnits</code> - The number of inference units for an Amazon Comprehend document classification endpoint.</p> </li> <li> <p> <code>lambda:function:ProvisionedConcurrency</code> - The provisioned concurrency for a Lambda function.</p> </li> </ul></p>
     #[serde(rename = "ScalableDimension")]
-    pub scalable_dimension: String,
+    pub scalable_dimension: ScalableDimension,
     /// <p>The namespace of the AWS service that provides the resource or <code>custom-resource</code> for a resource provided by your own application or service. For more information, see <a href="http://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html#genref-aws-service-namespaces">AWS Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p>
     #[serde(rename = "ServiceNamespace")]
-    pub service_namespace: String,
+    pub service_namespace: ServiceNamespace,
     /// <p>The Unix timestamp for when the scaling activity began.</p>
     #[serde(rename = "StartTime")]
-    pub start_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub start_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>Indicates the status of the scaling activity.</p>
     #[serde(rename = "StatusCode")]
-    pub status_code: String,
+    pub status_code: ScalingActivityStatusCode,
     /// <p>A simple message about the current status of the scaling activity.</p>
     #[serde(rename = "StatusMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status_message: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ScalingActivityStatusCode {
+    /// `Pending`
+    Pending,
+    /// `InProgress`
+    InProgress,
+    /// `Successful`
+    Successful,
+    /// `Overridden`
+    Overridden,
+    /// `Unfulfilled`
+    Unfulfilled,
+    /// `Failed`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ScalingActivityStatusCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ScalingActivityStatusCode::Pending => "Pending",
+            ScalingActivityStatusCode::InProgress => "InProgress",
+            ScalingActivityStatusCode::Successful => "Successful",
+            ScalingActivityStatusCode::Overridden => "Overridden",
+            ScalingActivityStatusCode::Unfulfilled => "Unfulfilled",
+            ScalingActivityStatusCode::Failed => "Failed",
+            ScalingActivityStatusCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ScalingActivityStatusCode {
+    fn default() -> ScalingActivityStatusCode {
+        ScalingActivityStatusCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ScalingActivityStatusCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ScalingActivityStatusCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ScalingActivityStatusCode {
+    fn from(value: &'a str) -> ScalingActivityStatusCode {
+        match value {
+            "Pending" => ScalingActivityStatusCode::Pending,
+            "InProgress" => ScalingActivityStatusCode::InProgress,
+            "Successful" => ScalingActivityStatusCode::Successful,
+            "Overridden" => ScalingActivityStatusCode::Overridden,
+            "Unfulfilled" => ScalingActivityStatusCode::Unfulfilled,
+            "Failed" => ScalingActivityStatusCode::Failed,
+            _ => ScalingActivityStatusCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ScalingActivityStatusCode {
+    fn from(value: String) -> ScalingActivityStatusCode {
+        match value.as_str() {
+            "Pending" => ScalingActivityStatusCode::Pending,
+            "InProgress" => ScalingActivityStatusCode::InProgress,
+            "Successful" => ScalingActivityStatusCode::Successful,
+            "Overridden" => ScalingActivityStatusCode::Overridden,
+            "Unfulfilled" => ScalingActivityStatusCode::Unfulfilled,
+            "Failed" => ScalingActivityStatusCode::Failed,
+            _ => ScalingActivityStatusCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ScalingActivityStatusCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ScalingActivityStatusCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ScalingActivityStatusCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ScalingActivityStatusCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ScalingActivityStatusCode {
+    fn deserialize<D>(deserializer: D) -> Result<ScalingActivityStatusCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents a scaling policy to use with Application Auto Scaling.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -505,7 +1604,12 @@ pub struct ScalingPolicy {
     pub alarms: Option<Vec<Alarm>>,
     /// <p>The Unix timestamp for when the scaling policy was created.</p>
     #[serde(rename = "CreationTime")]
-    pub creation_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub creation_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The Amazon Resource Name (ARN) of the scaling policy.</p>
     #[serde(rename = "PolicyARN")]
     pub policy_arn: String,
@@ -514,16 +1618,16 @@ pub struct ScalingPolicy {
     pub policy_name: String,
     /// <p>The scaling policy type.</p>
     #[serde(rename = "PolicyType")]
-    pub policy_type: String,
+    pub policy_type: PolicyType,
     /// <p><p>The identifier of the resource associated with the scaling policy. This string consists of the resource type and unique identifier.</p> <ul> <li> <p>ECS service - The resource type is <code>service</code> and the unique identifier is the cluster name and service name. Example: <code>service/default/sample-webapp</code>.</p> </li> <li> <p>Spot Fleet request - The resource type is <code>spot-fleet-request</code> and the unique identifier is the Spot Fleet request ID. Example: <code>spot-fleet-request/sfr-73fbd2ce-aa30-494c-8788-1cee4EXAMPLE</code>.</p> </li> <li> <p>EMR cluster - The resource type is <code>instancegroup</code> and the unique identifier is the cluster ID and instance group ID. Example: <code>instancegroup/j-2EEZNYKUA1NTV/ig-1791Y4E1L8YI0</code>.</p> </li> <li> <p>AppStream 2.0 fleet - The resource type is <code>fleet</code> and the unique identifier is the fleet name. Example: <code>fleet/sample-fleet</code>.</p> </li> <li> <p>DynamoDB table - The resource type is <code>table</code> and the unique identifier is the table name. Example: <code>table/my-table</code>.</p> </li> <li> <p>DynamoDB global secondary index - The resource type is <code>index</code> and the unique identifier is the index name. Example: <code>table/my-table/index/my-table-index</code>.</p> </li> <li> <p>Aurora DB cluster - The resource type is <code>cluster</code> and the unique identifier is the cluster name. Example: <code>cluster:my-db-cluster</code>.</p> </li> <li> <p>Amazon SageMaker endpoint variant - The resource type is <code>variant</code> and the unique identifier is the resource ID. Example: <code>endpoint/my-end-point/variant/KMeansClustering</code>.</p> </li> <li> <p>Custom resources are not supported with a resource type. This parameter must specify the <code>OutputValue</code> from the CloudFormation template stack used to access the resources. The unique identifier is defined by the service provider. More information is available in our <a href="https://github.com/aws/aws-auto-scaling-custom-resource">GitHub repository</a>.</p> </li> <li> <p>Amazon Comprehend document classification endpoint - The resource type and unique identifier are specified using the endpoint ARN. Example: <code>arn:aws:comprehend:us-west-2:123456789012:document-classifier-endpoint/EXAMPLE</code>.</p> </li> <li> <p>Lambda provisioned concurrency - The resource type is <code>function</code> and the unique identifier is the function name with a function version or alias name suffix that is not <code>$LATEST</code>. Example: <code>function:my-function:prod</code> or <code>function:my-function:1</code>.</p> </li> </ul></p>
     #[serde(rename = "ResourceId")]
     pub resource_id: String,
     /// <p><p>The scalable dimension. This string consists of the service namespace, resource type, and scaling property.</p> <ul> <li> <p> <code>ecs:service:DesiredCount</code> - The desired task count of an ECS service.</p> </li> <li> <p> <code>ec2:spot-fleet-request:TargetCapacity</code> - The target capacity of a Spot Fleet request.</p> </li> <li> <p> <code>elasticmapreduce:instancegroup:InstanceCount</code> - The instance count of an EMR Instance Group.</p> </li> <li> <p> <code>appstream:fleet:DesiredCapacity</code> - The desired capacity of an AppStream 2.0 fleet.</p> </li> <li> <p> <code>dynamodb:table:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:table:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:index:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>dynamodb:index:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>rds:cluster:ReadReplicaCount</code> - The count of Aurora Replicas in an Aurora DB cluster. Available for Aurora MySQL-compatible edition and Aurora PostgreSQL-compatible edition.</p> </li> <li> <p> <code>sagemaker:variant:DesiredInstanceCount</code> - The number of EC2 instances for an Amazon SageMaker model endpoint variant.</p> </li> <li> <p> <code>custom-resource:ResourceType:Property</code> - The scalable dimension for a custom resource provided by your own application or service.</p> </li> <li> <p> <code>comprehend:document-classifier-endpoint:DesiredInferenceUnits</code> - The number of inference units for an Amazon Comprehend document classification endpoint.</p> </li> <li> <p> <code>lambda:function:ProvisionedConcurrency</code> - The provisioned concurrency for a Lambda function.</p> </li> </ul></p>
     #[serde(rename = "ScalableDimension")]
-    pub scalable_dimension: String,
+    pub scalable_dimension: ScalableDimension,
     /// <p>The namespace of the AWS service that provides the resource or <code>custom-resource</code> for a resource provided by your own application or service. For more information, see <a href="http://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html#genref-aws-service-namespaces">AWS Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p>
     #[serde(rename = "ServiceNamespace")]
-    pub service_namespace: String,
+    pub service_namespace: ServiceNamespace,
     /// <p>A step scaling policy.</p>
     #[serde(rename = "StepScalingPolicyConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -541,18 +1645,28 @@ pub struct ScalingPolicy {
 pub struct ScheduledAction {
     /// <p>The date and time that the scheduled action was created.</p>
     #[serde(rename = "CreationTime")]
-    pub creation_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub creation_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The date and time that the action is scheduled to end.</p>
     #[serde(rename = "EndTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>The identifier of the resource associated with the scaling policy. This string consists of the resource type and unique identifier.</p> <ul> <li> <p>ECS service - The resource type is <code>service</code> and the unique identifier is the cluster name and service name. Example: <code>service/default/sample-webapp</code>.</p> </li> <li> <p>Spot Fleet request - The resource type is <code>spot-fleet-request</code> and the unique identifier is the Spot Fleet request ID. Example: <code>spot-fleet-request/sfr-73fbd2ce-aa30-494c-8788-1cee4EXAMPLE</code>.</p> </li> <li> <p>EMR cluster - The resource type is <code>instancegroup</code> and the unique identifier is the cluster ID and instance group ID. Example: <code>instancegroup/j-2EEZNYKUA1NTV/ig-1791Y4E1L8YI0</code>.</p> </li> <li> <p>AppStream 2.0 fleet - The resource type is <code>fleet</code> and the unique identifier is the fleet name. Example: <code>fleet/sample-fleet</code>.</p> </li> <li> <p>DynamoDB table - The resource type is <code>table</code> and the unique identifier is the table name. Example: <code>table/my-table</code>.</p> </li> <li> <p>DynamoDB global secondary index - The resource type is <code>index</code> and the unique identifier is the index name. Example: <code>table/my-table/index/my-table-index</code>.</p> </li> <li> <p>Aurora DB cluster - The resource type is <code>cluster</code> and the unique identifier is the cluster name. Example: <code>cluster:my-db-cluster</code>.</p> </li> <li> <p>Amazon SageMaker endpoint variant - The resource type is <code>variant</code> and the unique identifier is the resource ID. Example: <code>endpoint/my-end-point/variant/KMeansClustering</code>.</p> </li> <li> <p>Custom resources are not supported with a resource type. This parameter must specify the <code>OutputValue</code> from the CloudFormation template stack used to access the resources. The unique identifier is defined by the service provider. More information is available in our <a href="https://github.com/aws/aws-auto-scaling-custom-resource">GitHub repository</a>.</p> </li> <li> <p>Amazon Comprehend document classification endpoint - The resource type and unique identifier are specified using the endpoint ARN. Example: <code>arn:aws:comprehend:us-west-2:123456789012:document-classifier-endpoint/EXAMPLE</code>.</p> </li> <li> <p>Lambda provisioned concurrency - The resource type is <code>function</code> and the unique identifier is the function name with a function version or alias name suffix that is not <code>$LATEST</code>. Example: <code>function:my-function:prod</code> or <code>function:my-function:1</code>.</p> </li> </ul></p>
     #[serde(rename = "ResourceId")]
     pub resource_id: String,
     /// <p><p>The scalable dimension. This string consists of the service namespace, resource type, and scaling property.</p> <ul> <li> <p> <code>ecs:service:DesiredCount</code> - The desired task count of an ECS service.</p> </li> <li> <p> <code>ec2:spot-fleet-request:TargetCapacity</code> - The target capacity of a Spot Fleet request.</p> </li> <li> <p> <code>elasticmapreduce:instancegroup:InstanceCount</code> - The instance count of an EMR Instance Group.</p> </li> <li> <p> <code>appstream:fleet:DesiredCapacity</code> - The desired capacity of an AppStream 2.0 fleet.</p> </li> <li> <p> <code>dynamodb:table:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:table:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:index:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>dynamodb:index:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>rds:cluster:ReadReplicaCount</code> - The count of Aurora Replicas in an Aurora DB cluster. Available for Aurora MySQL-compatible edition and Aurora PostgreSQL-compatible edition.</p> </li> <li> <p> <code>sagemaker:variant:DesiredInstanceCount</code> - The number of EC2 instances for an Amazon SageMaker model endpoint variant.</p> </li> <li> <p> <code>custom-resource:ResourceType:Property</code> - The scalable dimension for a custom resource provided by your own application or service.</p> </li> <li> <p> <code>comprehend:document-classifier-endpoint:DesiredInferenceUnits</code> - The number of inference units for an Amazon Comprehend document classification endpoint.</p> </li> <li> <p> <code>lambda:function:ProvisionedConcurrency</code> - The provisioned concurrency for a Lambda function.</p> </li> </ul></p>
     #[serde(rename = "ScalableDimension")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scalable_dimension: Option<String>,
+    pub scalable_dimension: Option<ScalableDimension>,
     /// <p>The new minimum and maximum capacity. You can set both values or just one. During the scheduled time, if the current capacity is below the minimum capacity, Application Auto Scaling scales out to the minimum capacity. If the current capacity is above the maximum capacity, Application Auto Scaling scales in to the maximum capacity.</p>
     #[serde(rename = "ScalableTargetAction")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -568,13 +1682,171 @@ pub struct ScheduledAction {
     pub scheduled_action_name: String,
     /// <p>The namespace of the AWS service that provides the resource or <code>custom-resource</code> for a resource provided by your own application or service. For more information, see <a href="http://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html#genref-aws-service-namespaces">AWS Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p>
     #[serde(rename = "ServiceNamespace")]
-    pub service_namespace: String,
+    pub service_namespace: ServiceNamespace,
     /// <p>The date and time that the action is scheduled to begin.</p>
     #[serde(rename = "StartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ServiceNamespace {
+    /// `ecs`
+    Ecs,
+    /// `elasticmapreduce`
+    Elasticmapreduce,
+    /// `ec2`
+    Ec2,
+    /// `appstream`
+    Appstream,
+    /// `dynamodb`
+    Dynamodb,
+    /// `rds`
+    Rds,
+    /// `sagemaker`
+    Sagemaker,
+    /// `custom-resource`
+    CustomResource,
+    /// `comprehend`
+    Comprehend,
+    /// `lambda`
+    Lambda,
+    /// `cassandra`
+    Cassandra,
+    /// `kafka`
+    Kafka,
+    /// `elasticache`
+    Elasticache,
+    /// `neptune`
+    Neptune,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ServiceNamespace {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ServiceNamespace::Ecs => "ecs",
+            ServiceNamespace::Elasticmapreduce => "elasticmapreduce",
+            ServiceNamespace::Ec2 => "ec2",
+            ServiceNamespace::Appstream => "appstream",
+            ServiceNamespace::Dynamodb => "dynamodb",
+            ServiceNamespace::Rds => "rds",
+            ServiceNamespace::Sagemaker => "sagemaker",
+            ServiceNamespace::CustomResource => "custom-resource",
+            ServiceNamespace::Comprehend => "comprehend",
+            ServiceNamespace::Lambda => "lambda",
+            ServiceNamespace::Cassandra => "cassandra",
+            ServiceNamespace::Kafka => "kafka",
+            ServiceNamespace::Elasticache => "elasticache",
+            ServiceNamespace::Neptune => "neptune",
+            ServiceNamespace::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ServiceNamespace {
+    fn default() -> ServiceNamespace {
+        ServiceNamespace::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ServiceNamespace {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ServiceNamespace {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ServiceNamespace {
+    fn from(value: &'a str) -> ServiceNamespace {
+        match value {
+            "ecs" => ServiceNamespace::Ecs,
+            "elasticmapreduce" => ServiceNamespace::Elasticmapreduce,
+            "ec2" => ServiceNamespace::Ec2,
+            "appstream" => ServiceNamespace::Appstream,
+            "dynamodb" => ServiceNamespace::Dynamodb,
+            "rds" => ServiceNamespace::Rds,
+            "sagemaker" => ServiceNamespace::Sagemaker,
+            "custom-resource" => ServiceNamespace::CustomResource,
+            "comprehend" => ServiceNamespace::Comprehend,
+            "lambda" => ServiceNamespace::Lambda,
+            "cassandra" => ServiceNamespace::Cassandra,
+            "kafka" => ServiceNamespace::Kafka,
+            "elasticache" => ServiceNamespace::Elasticache,
+            "neptune" => ServiceNamespace::Neptune,
+            _ => ServiceNamespace::Unknown(value.to_owned()),
+        }
+    }
 }
 
+impl From<String> for ServiceNamespace {
+    fn from(value: String) -> ServiceNamespace {
+        match value.as_str() {
+            "ecs" => ServiceNamespace::Ecs,
+            "elasticmapreduce" => ServiceNamespace::Elasticmapreduce,
+            "ec2" => ServiceNamespace::Ec2,
+            "appstream" => ServiceNamespace::Appstream,
+            "dynamodb" => ServiceNamespace::Dynamodb,
+            "rds" => ServiceNamespace::Rds,
+            "sagemaker" => ServiceNamespace::Sagemaker,
+            "custom-resource" => ServiceNamespace::CustomResource,
+            "comprehend" => ServiceNamespace::Comprehend,
+            "lambda" => ServiceNamespace::Lambda,
+            "cassandra" => ServiceNamespace::Cassandra,
+            "kafka" => ServiceNamespace::Kafka,
+            "elasticache" => ServiceNamespace::Elasticache,
+            "neptune" => ServiceNamespace::Neptune,
+            _ => ServiceNamespace::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ServiceNamespace {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ServiceNamespace, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ServiceNamespace {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ServiceNamespace {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ServiceNamespace {
+    fn deserialize<D>(deserializer: D) -> Result<ServiceNamespace, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p><p>Represents a step adjustment for a <a>StepScalingPolicyConfiguration</a>. Describes an adjustment based on the difference between the value of the aggregated CloudWatch metric and the breach threshold that you&#39;ve defined for the alarm. </p> <p>For the following examples, suppose that you have an alarm with a breach threshold of 50:</p> <ul> <li> <p>To trigger the adjustment when the metric is greater than or equal to 50 and less than 60, specify a lower bound of 0 and an upper bound of 10.</p> </li> <li> <p>To trigger the adjustment when the metric is greater than 40 and less than or equal to 50, specify a lower bound of -10 and an upper bound of 0.</p> </li> </ul> <p>There are a few rules for the step adjustments for your step policy:</p> <ul> <li> <p>The ranges of your step adjustments can&#39;t overlap or have a gap.</p> </li> <li> <p>At most one step adjustment can have a null lower bound. If one step adjustment has a negative lower bound, then there must be a step adjustment with a null lower bound.</p> </li> <li> <p>At most one step adjustment can have a null upper bound. If one step adjustment has a positive upper bound, then there must be a step adjustment with a null upper bound.</p> </li> <li> <p>The upper and lower bound can&#39;t be null in the same step adjustment.</p> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct StepAdjustment {
@@ -597,7 +1869,7 @@ pub struct StepScalingPolicyConfiguration {
     /// <p>Specifies whether the <code>ScalingAdjustment</code> value in a <a>StepAdjustment</a> is an absolute number or a percentage of the current capacity. </p>
     #[serde(rename = "AdjustmentType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub adjustment_type: Option<String>,
+    pub adjustment_type: Option<AdjustmentType>,
     /// <p>The amount of time, in seconds, after a scaling activity completes where previous trigger-related scaling activities can influence future scaling events.</p> <p>For scale-out policies, while the cooldown period is in effect, the capacity that has been added by the previous scale-out event that initiated the cooldown is calculated as part of the desired capacity for the next scale out. The intention is to continuously (but not excessively) scale out. For example, an alarm triggers a step scaling policy to scale out an Amazon ECS service by 2 tasks, the scaling activity completes successfully, and a cooldown period of 5 minutes starts. During the cooldown period, if the alarm triggers the same policy again but at a more aggressive step adjustment to scale out the service by 3 tasks, the 2 tasks that were added in the previous scale-out event are considered part of that capacity and only 1 additional task is added to the desired count.</p> <p>For scale-in policies, the cooldown period is used to block subsequent scale-in requests until it has expired. The intention is to scale in conservatively to protect your application's availability. However, if another alarm triggers a scale-out policy during the cooldown period after a scale-in, Application Auto Scaling scales out your scalable target immediately.</p>
     #[serde(rename = "Cooldown")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -605,7 +1877,7 @@ pub struct StepScalingPolicyConfiguration {
     /// <p>The aggregation type for the CloudWatch metrics. Valid values are <code>Minimum</code>, <code>Maximum</code>, and <code>Average</code>. If the aggregation type is null, the value is treated as <code>Average</code>.</p>
     #[serde(rename = "MetricAggregationType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub metric_aggregation_type: Option<String>,
+    pub metric_aggregation_type: Option<MetricAggregationType>,
     /// <p>The minimum number to adjust your scalable dimension as a result of a scaling activity. If the adjustment type is <code>PercentChangeInCapacity</code>, the scaling policy changes the scalable dimension of the scalable target by this amount.</p> <p>For example, suppose that you create a step scaling policy to scale out an Amazon ECS service by 25 percent and you specify a <code>MinAdjustmentMagnitude</code> of 2. If the service has 4 tasks and the scaling policy is performed, 25 percent of 4 is 1. However, because you specified a <code>MinAdjustmentMagnitude</code> of 2, Application Auto Scaling scales out the service by 2 tasks.</p>
     #[serde(rename = "MinAdjustmentMagnitude")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1226,11 +2498,15 @@ pub struct ApplicationAutoScalingClient {
 impl ApplicationAutoScalingClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> ApplicationAutoScalingClient {
         ApplicationAutoScalingClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(
+                region,
+                "Application Auto Scaling",
+            ),
         }
     }
 
@@ -1262,6 +2538,7 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
         input: DeleteScalingPolicyRequest,
     ) -> Result<DeleteScalingPolicyResponse, RusotoError<DeleteScalingPolicyError>> {
         let mut request = SignedRequest::new("POST", "application-autoscaling", &self.region, "/");
+        request.set_operation("DeleteScalingPolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1293,6 +2570,7 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
         input: DeleteScheduledActionRequest,
     ) -> Result<DeleteScheduledActionResponse, RusotoError<DeleteScheduledActionError>> {
         let mut request = SignedRequest::new("POST", "application-autoscaling", &self.region, "/");
+        request.set_operation("DeleteScheduledAction");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1324,6 +2602,7 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
         input: DeregisterScalableTargetRequest,
     ) -> Result<DeregisterScalableTargetResponse, RusotoError<DeregisterScalableTargetError>> {
         let mut request = SignedRequest::new("POST", "application-autoscaling", &self.region, "/");
+        request.set_operation("DeregisterScalableTarget");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1355,6 +2634,7 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
         input: DescribeScalableTargetsRequest,
     ) -> Result<DescribeScalableTargetsResponse, RusotoError<DescribeScalableTargetsError>> {
         let mut request = SignedRequest::new("POST", "application-autoscaling", &self.region, "/");
+        request.set_operation("DescribeScalableTargets");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1387,6 +2667,7 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
     ) -> Result<DescribeScalingActivitiesResponse, RusotoError<DescribeScalingActivitiesError>>
     {
         let mut request = SignedRequest::new("POST", "application-autoscaling", &self.region, "/");
+        request.set_operation("DescribeScalingActivities");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1418,6 +2699,7 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
         input: DescribeScalingPoliciesRequest,
     ) -> Result<DescribeScalingPoliciesResponse, RusotoError<DescribeScalingPoliciesError>> {
         let mut request = SignedRequest::new("POST", "application-autoscaling", &self.region, "/");
+        request.set_operation("DescribeScalingPolicies");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1449,6 +2731,7 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
         input: DescribeScheduledActionsRequest,
     ) -> Result<DescribeScheduledActionsResponse, RusotoError<DescribeScheduledActionsError>> {
         let mut request = SignedRequest::new("POST", "application-autoscaling", &self.region, "/");
+        request.set_operation("DescribeScheduledActions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1480,6 +2763,7 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
         input: PutScalingPolicyRequest,
     ) -> Result<PutScalingPolicyResponse, RusotoError<PutScalingPolicyError>> {
         let mut request = SignedRequest::new("POST", "application-autoscaling", &self.region, "/");
+        request.set_operation("PutScalingPolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AnyScaleFrontendService.PutScalingPolicy");
@@ -1508,6 +2792,7 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
         input: PutScheduledActionRequest,
     ) -> Result<PutScheduledActionResponse, RusotoError<PutScheduledActionError>> {
         let mut request = SignedRequest::new("POST", "application-autoscaling", &self.region, "/");
+        request.set_operation("PutScheduledAction");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AnyScaleFrontendService.PutScheduledAction");
@@ -1536,6 +2821,7 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
         input: RegisterScalableTargetRequest,
     ) -> Result<RegisterScalableTargetResponse, RusotoError<RegisterScalableTargetError>> {
         let mut request = SignedRequest::new("POST", "application-autoscaling", &self.region, "/");
+        request.set_operation("RegisterScalableTarget");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1561,3 +2847,144 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
         }
     }
 }
+impl ApplicationAutoScalingClient {
+    /// Auto-paginating version of `describe_scalable_targets`, streaming every page of results.
+    pub fn describe_scalable_targets_pages(
+        &self,
+        input: DescribeScalableTargetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeScalableTargetsResponse,
+        DescribeScalableTargetsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApplicationAutoScalingClient, input: DescribeScalableTargetsRequest| async move {
+                client.describe_scalable_targets(input).await
+            },
+            |input: &DescribeScalableTargetsRequest, output: &DescribeScalableTargetsResponse| {
+                Some(DescribeScalableTargetsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_scalable_targets`, streaming the `ScalableTargets` of every page of results.
+    pub fn describe_scalable_targets_items(
+        &self,
+        input: DescribeScalableTargetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ScalableTarget, DescribeScalableTargetsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_scalable_targets_pages(input),
+            |output: DescribeScalableTargetsResponse| output.scalable_targets.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_scaling_activities`, streaming every page of results.
+    pub fn describe_scaling_activities_pages(
+        &self,
+        input: DescribeScalingActivitiesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeScalingActivitiesResponse,
+        DescribeScalingActivitiesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApplicationAutoScalingClient, input: DescribeScalingActivitiesRequest| async move {
+                client.describe_scaling_activities(input).await
+            },
+            |input: &DescribeScalingActivitiesRequest,
+             output: &DescribeScalingActivitiesResponse| {
+                Some(DescribeScalingActivitiesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_scaling_activities`, streaming the `ScalingActivities` of every page of results.
+    pub fn describe_scaling_activities_items(
+        &self,
+        input: DescribeScalingActivitiesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ScalingActivity, DescribeScalingActivitiesError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_scaling_activities_pages(input),
+            |output: DescribeScalingActivitiesResponse| {
+                output.scaling_activities.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_scaling_policies`, streaming every page of results.
+    pub fn describe_scaling_policies_pages(
+        &self,
+        input: DescribeScalingPoliciesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeScalingPoliciesResponse,
+        DescribeScalingPoliciesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApplicationAutoScalingClient, input: DescribeScalingPoliciesRequest| async move {
+                client.describe_scaling_policies(input).await
+            },
+            |input: &DescribeScalingPoliciesRequest, output: &DescribeScalingPoliciesResponse| {
+                Some(DescribeScalingPoliciesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_scaling_policies`, streaming the `ScalingPolicies` of every page of results.
+    pub fn describe_scaling_policies_items(
+        &self,
+        input: DescribeScalingPoliciesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ScalingPolicy, DescribeScalingPoliciesError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_scaling_policies_pages(input),
+            |output: DescribeScalingPoliciesResponse| output.scaling_policies.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_scheduled_actions`, streaming every page of results.
+    pub fn describe_scheduled_actions_pages(
+        &self,
+        input: DescribeScheduledActionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeScheduledActionsResponse,
+        DescribeScheduledActionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApplicationAutoScalingClient, input: DescribeScheduledActionsRequest| async move {
+                client.describe_scheduled_actions(input).await
+            },
+            |input: &DescribeScheduledActionsRequest, output: &DescribeScheduledActionsResponse| {
+                Some(DescribeScheduledActionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_scheduled_actions`, streaming the `ScheduledActions` of every page of results.
+    pub fn describe_scheduled_actions_items(
+        &self,
+        input: DescribeScheduledActionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ScheduledAction, DescribeScheduledActionsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_scheduled_actions_pages(input),
+            |output: DescribeScheduledActionsResponse| output.scheduled_actions.unwrap_or_default(),
+        )
+    }
+}
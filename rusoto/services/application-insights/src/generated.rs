@@ -43,7 +43,7 @@ pub struct ApplicationComponent {
     /// <p>The stack tier of the application component.</p>
     #[serde(rename = "Tier")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub tier: Option<String>,
+    pub tier: Option<Tier>,
 }
 
 /// <p>Describes the status of the application.</p>
@@ -87,21 +87,227 @@ pub struct ConfigurationEvent {
     /// <p> The resource type that Application Insights attempted to configure, for example, CLOUDWATCH_ALARM. </p>
     #[serde(rename = "EventResourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub event_resource_type: Option<String>,
+    pub event_resource_type: Option<ConfigurationEventResourceType>,
     /// <p> The status of the configuration update event. Possible values include INFO, WARN, and ERROR. </p>
     #[serde(rename = "EventStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub event_status: Option<String>,
+    pub event_status: Option<ConfigurationEventStatus>,
     /// <p> The timestamp of the event. </p>
     #[serde(rename = "EventTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub event_time: Option<f64>,
+    pub event_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p> The resource monitored by Application Insights. </p>
     #[serde(rename = "MonitoredResourceARN")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub monitored_resource_arn: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ConfigurationEventResourceType {
+    /// `CLOUDWATCH_ALARM`
+    CloudwatchAlarm,
+    /// `CLOUDWATCH_LOG`
+    CloudwatchLog,
+    /// `CLOUDFORMATION`
+    Cloudformation,
+    /// `SSM_ASSOCIATION`
+    SsmAssociation,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ConfigurationEventResourceType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ConfigurationEventResourceType::CloudwatchAlarm => "CLOUDWATCH_ALARM",
+            ConfigurationEventResourceType::CloudwatchLog => "CLOUDWATCH_LOG",
+            ConfigurationEventResourceType::Cloudformation => "CLOUDFORMATION",
+            ConfigurationEventResourceType::SsmAssociation => "SSM_ASSOCIATION",
+            ConfigurationEventResourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ConfigurationEventResourceType {
+    fn default() -> ConfigurationEventResourceType {
+        ConfigurationEventResourceType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ConfigurationEventResourceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ConfigurationEventResourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ConfigurationEventResourceType {
+    fn from(value: &'a str) -> ConfigurationEventResourceType {
+        match value {
+            "CLOUDWATCH_ALARM" => ConfigurationEventResourceType::CloudwatchAlarm,
+            "CLOUDWATCH_LOG" => ConfigurationEventResourceType::CloudwatchLog,
+            "CLOUDFORMATION" => ConfigurationEventResourceType::Cloudformation,
+            "SSM_ASSOCIATION" => ConfigurationEventResourceType::SsmAssociation,
+            _ => ConfigurationEventResourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ConfigurationEventResourceType {
+    fn from(value: String) -> ConfigurationEventResourceType {
+        match value.as_str() {
+            "CLOUDWATCH_ALARM" => ConfigurationEventResourceType::CloudwatchAlarm,
+            "CLOUDWATCH_LOG" => ConfigurationEventResourceType::CloudwatchLog,
+            "CLOUDFORMATION" => ConfigurationEventResourceType::Cloudformation,
+            "SSM_ASSOCIATION" => ConfigurationEventResourceType::SsmAssociation,
+            _ => ConfigurationEventResourceType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ConfigurationEventResourceType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ConfigurationEventResourceType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ConfigurationEventResourceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ConfigurationEventResourceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ConfigurationEventResourceType {
+    fn deserialize<D>(deserializer: D) -> Result<ConfigurationEventResourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ConfigurationEventStatus {
+    /// `INFO`
+    Info,
+    /// `WARN`
+    Warn,
+    /// `ERROR`
+    Error,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ConfigurationEventStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ConfigurationEventStatus::Info => "INFO",
+            ConfigurationEventStatus::Warn => "WARN",
+            ConfigurationEventStatus::Error => "ERROR",
+            ConfigurationEventStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ConfigurationEventStatus {
+    fn default() -> ConfigurationEventStatus {
+        ConfigurationEventStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ConfigurationEventStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ConfigurationEventStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ConfigurationEventStatus {
+    fn from(value: &'a str) -> ConfigurationEventStatus {
+        match value {
+            "INFO" => ConfigurationEventStatus::Info,
+            "WARN" => ConfigurationEventStatus::Warn,
+            "ERROR" => ConfigurationEventStatus::Error,
+            _ => ConfigurationEventStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ConfigurationEventStatus {
+    fn from(value: String) -> ConfigurationEventStatus {
+        match value.as_str() {
+            "INFO" => ConfigurationEventStatus::Info,
+            "WARN" => ConfigurationEventStatus::Warn,
+            "ERROR" => ConfigurationEventStatus::Error,
+            _ => ConfigurationEventStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ConfigurationEventStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ConfigurationEventStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ConfigurationEventStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ConfigurationEventStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ConfigurationEventStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ConfigurationEventStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateApplicationRequest {
@@ -255,7 +461,7 @@ pub struct DescribeComponentConfigurationRecommendationRequest {
     pub resource_group_name: String,
     /// <p>The tier of the application component. Supported tiers include <code>DOT_NET_CORE</code>, <code>DOT_NET_WORKER</code>, <code>DOT_NET_WEB</code>, <code>SQL_SERVER</code>, and <code>DEFAULT</code>.</p>
     #[serde(rename = "Tier")]
-    pub tier: String,
+    pub tier: Tier,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -292,7 +498,7 @@ pub struct DescribeComponentConfigurationResponse {
     /// <p>The tier of the application component. Supported tiers include <code>DOT_NET_CORE</code>, <code>DOT_NET_WORKER</code>, <code>DOT_NET_WEB</code>, <code>SQL_SERVER</code>, and <code>DEFAULT</code> </p>
     #[serde(rename = "Tier")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub tier: Option<String>,
+    pub tier: Option<Tier>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -396,6 +602,192 @@ pub struct DescribeProblemResponse {
     pub problem: Option<Problem>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FeedbackKey {
+    /// `INSIGHTS_FEEDBACK`
+    InsightsFeedback,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl FeedbackKey {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FeedbackKey::InsightsFeedback => "INSIGHTS_FEEDBACK",
+            FeedbackKey::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for FeedbackKey {
+    fn default() -> FeedbackKey {
+        FeedbackKey::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for FeedbackKey {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FeedbackKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FeedbackKey {
+    fn from(value: &'a str) -> FeedbackKey {
+        match value {
+            "INSIGHTS_FEEDBACK" => FeedbackKey::InsightsFeedback,
+            _ => FeedbackKey::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FeedbackKey {
+    fn from(value: String) -> FeedbackKey {
+        match value.as_str() {
+            "INSIGHTS_FEEDBACK" => FeedbackKey::InsightsFeedback,
+            _ => FeedbackKey::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for FeedbackKey {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<FeedbackKey, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FeedbackKey {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FeedbackKey {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FeedbackKey {
+    fn deserialize<D>(deserializer: D) -> Result<FeedbackKey, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FeedbackValue {
+    /// `NOT_SPECIFIED`
+    NotSpecified,
+    /// `USEFUL`
+    Useful,
+    /// `NOT_USEFUL`
+    NotUseful,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl FeedbackValue {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FeedbackValue::NotSpecified => "NOT_SPECIFIED",
+            FeedbackValue::Useful => "USEFUL",
+            FeedbackValue::NotUseful => "NOT_USEFUL",
+            FeedbackValue::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for FeedbackValue {
+    fn default() -> FeedbackValue {
+        FeedbackValue::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for FeedbackValue {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FeedbackValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FeedbackValue {
+    fn from(value: &'a str) -> FeedbackValue {
+        match value {
+            "NOT_SPECIFIED" => FeedbackValue::NotSpecified,
+            "USEFUL" => FeedbackValue::Useful,
+            "NOT_USEFUL" => FeedbackValue::NotUseful,
+            _ => FeedbackValue::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FeedbackValue {
+    fn from(value: String) -> FeedbackValue {
+        match value.as_str() {
+            "NOT_SPECIFIED" => FeedbackValue::NotSpecified,
+            "USEFUL" => FeedbackValue::Useful,
+            "NOT_USEFUL" => FeedbackValue::NotUseful,
+            _ => FeedbackValue::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for FeedbackValue {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<FeedbackValue, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FeedbackValue {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FeedbackValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FeedbackValue {
+    fn deserialize<D>(deserializer: D) -> Result<FeedbackValue, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ListApplicationsRequest {
@@ -456,12 +848,17 @@ pub struct ListComponentsResponse {
 pub struct ListConfigurationHistoryRequest {
     /// <p>The end time of the event.</p>
     #[serde(rename = "EndTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The status of the configuration update event. Possible values include INFO, WARN, and ERROR.</p>
     #[serde(rename = "EventStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub event_status: Option<String>,
+    pub event_status: Option<ConfigurationEventStatus>,
     /// <p> The maximum number of results returned by <code>ListConfigurationHistory</code> in paginated output. When this parameter is used, <code>ListConfigurationHistory</code> returns only <code>MaxResults</code> in a single page along with a <code>NextToken</code> response element. The remaining results of the initial request can be seen by sending another <code>ListConfigurationHistory</code> request with the returned <code>NextToken</code> value. If this parameter is not used, then <code>ListConfigurationHistory</code> returns all results. </p>
     #[serde(rename = "MaxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -476,8 +873,13 @@ pub struct ListConfigurationHistoryRequest {
     pub resource_group_name: Option<String>,
     /// <p>The start time of the event. </p>
     #[serde(rename = "StartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -568,8 +970,13 @@ pub struct ListLogPatternsResponse {
 pub struct ListProblemsRequest {
     /// <p>The time when the problem ended, in epoch seconds. If not specified, problems within the past seven days are returned.</p>
     #[serde(rename = "EndTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The maximum number of results to return in a single call. To retrieve the remaining results, make another call with the returned <code>NextToken</code> value.</p>
     #[serde(rename = "MaxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -584,8 +991,13 @@ pub struct ListProblemsRequest {
     pub resource_group_name: Option<String>,
     /// <p>The time when the problem was detected, in epoch seconds. If you don't specify a time frame for the request, problems within the past seven days are returned.</p>
     #[serde(rename = "StartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -618,6 +1030,104 @@ pub struct ListTagsForResourceResponse {
     pub tags: Option<Vec<Tag>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LogFilter {
+    /// `ERROR`
+    Error,
+    /// `WARN`
+    Warn,
+    /// `INFO`
+    Info,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LogFilter {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LogFilter::Error => "ERROR",
+            LogFilter::Warn => "WARN",
+            LogFilter::Info => "INFO",
+            LogFilter::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LogFilter {
+    fn default() -> LogFilter {
+        LogFilter::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LogFilter {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LogFilter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LogFilter {
+    fn from(value: &'a str) -> LogFilter {
+        match value {
+            "ERROR" => LogFilter::Error,
+            "WARN" => LogFilter::Warn,
+            "INFO" => LogFilter::Info,
+            _ => LogFilter::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LogFilter {
+    fn from(value: String) -> LogFilter {
+        match value.as_str() {
+            "ERROR" => LogFilter::Error,
+            "WARN" => LogFilter::Warn,
+            "INFO" => LogFilter::Info,
+            _ => LogFilter::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LogFilter {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LogFilter, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LogFilter {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LogFilter {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LogFilter {
+    fn deserialize<D>(deserializer: D) -> Result<LogFilter, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An object that defines the log patterns that belongs to a <code>LogPatternSet</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -646,20 +1156,30 @@ pub struct LogPattern {
 pub struct Observation {
     /// <p>The time when the observation ended, in epoch seconds.</p>
     #[serde(rename = "EndTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The ID of the observation type.</p>
     #[serde(rename = "Id")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
     /// <p>The timestamp in the CloudWatch Logs that specifies when the matched line occurred.</p>
     #[serde(rename = "LineTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub line_time: Option<f64>,
+    pub line_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The log filter of the observation.</p>
     #[serde(rename = "LogFilter")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub log_filter: Option<String>,
+    pub log_filter: Option<LogFilter>,
     /// <p>The log group name.</p>
     #[serde(rename = "LogGroup")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -686,8 +1206,13 @@ pub struct Observation {
     pub source_type: Option<String>,
     /// <p>The time when the observation was first detected, in epoch seconds.</p>
     #[serde(rename = "StartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The unit of the source observation metric.</p>
     #[serde(rename = "Unit")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -708,12 +1233,17 @@ pub struct Problem {
     pub affected_resource: Option<String>,
     /// <p>The time when the problem ended, in epoch seconds.</p>
     #[serde(rename = "EndTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Feedback provided by the user about the problem.</p>
     #[serde(rename = "Feedback")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub feedback: Option<::std::collections::HashMap<String, String>>,
+    pub feedback: Option<::std::collections::HashMap<FeedbackKey, FeedbackValue>>,
     /// <p>The ID of the problem.</p>
     #[serde(rename = "Id")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -729,15 +1259,20 @@ pub struct Problem {
     /// <p>A measure of the level of impact of the problem.</p>
     #[serde(rename = "SeverityLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub severity_level: Option<String>,
+    pub severity_level: Option<SeverityLevel>,
     /// <p>The time when the problem started, in epoch seconds.</p>
     #[serde(rename = "StartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The status of the problem.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<Status>,
     /// <p>The name of the problem.</p>
     #[serde(rename = "Title")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -754,6 +1289,217 @@ pub struct RelatedObservations {
     pub observation_list: Option<Vec<Observation>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SeverityLevel {
+    /// `Informative`
+    Informative,
+    /// `Low`
+    Low,
+    /// `Medium`
+    Medium,
+    /// `High`
+    High,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SeverityLevel {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SeverityLevel::Informative => "Informative",
+            SeverityLevel::Low => "Low",
+            SeverityLevel::Medium => "Medium",
+            SeverityLevel::High => "High",
+            SeverityLevel::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SeverityLevel {
+    fn default() -> SeverityLevel {
+        SeverityLevel::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SeverityLevel {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SeverityLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SeverityLevel {
+    fn from(value: &'a str) -> SeverityLevel {
+        match value {
+            "Informative" => SeverityLevel::Informative,
+            "Low" => SeverityLevel::Low,
+            "Medium" => SeverityLevel::Medium,
+            "High" => SeverityLevel::High,
+            _ => SeverityLevel::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SeverityLevel {
+    fn from(value: String) -> SeverityLevel {
+        match value.as_str() {
+            "Informative" => SeverityLevel::Informative,
+            "Low" => SeverityLevel::Low,
+            "Medium" => SeverityLevel::Medium,
+            "High" => SeverityLevel::High,
+            _ => SeverityLevel::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SeverityLevel {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SeverityLevel, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SeverityLevel {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SeverityLevel {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SeverityLevel {
+    fn deserialize<D>(deserializer: D) -> Result<SeverityLevel, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Status {
+    /// `IGNORE`
+    Ignore,
+    /// `RESOLVED`
+    Resolved,
+    /// `PENDING`
+    Pending,
+    /// `RECURRING`
+    Recurring,
+    /// `RECOVERING`
+    Recovering,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Status {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Status::Ignore => "IGNORE",
+            Status::Resolved => "RESOLVED",
+            Status::Pending => "PENDING",
+            Status::Recurring => "RECURRING",
+            Status::Recovering => "RECOVERING",
+            Status::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Status {
+    fn default() -> Status {
+        Status::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Status {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Status {
+    fn from(value: &'a str) -> Status {
+        match value {
+            "IGNORE" => Status::Ignore,
+            "RESOLVED" => Status::Resolved,
+            "PENDING" => Status::Pending,
+            "RECURRING" => Status::Recurring,
+            "RECOVERING" => Status::Recovering,
+            _ => Status::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Status {
+    fn from(value: String) -> Status {
+        match value.as_str() {
+            "IGNORE" => Status::Ignore,
+            "RESOLVED" => Status::Resolved,
+            "PENDING" => Status::Pending,
+            "RECURRING" => Status::Recurring,
+            "RECOVERING" => Status::Recovering,
+            _ => Status::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Status {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Status, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Status {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Status {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Status {
+    fn deserialize<D>(deserializer: D) -> Result<Status, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p><p>An object that defines the tags associated with an application. A <i>tag</i> is a label that you optionally define and associate with an application. Tags can help you categorize and manage resources in different ways, such as by purpose, owner, environment, or other criteria.</p> <p>Each tag consists of a required <i>tag key</i> and an associated <i>tag value</i>, both of which you define. A tag key is a general label that acts as a category for a more specific tag value. A tag value acts as a descriptor within a tag key. A tag key can contain as many as 128 characters. A tag value can contain as many as 256 characters. The characters can be Unicode letters, digits, white space, or one of the following symbols: _ . : / = + -. The following additional restrictions apply to tags:</p> <ul> <li> <p>Tag keys and values are case sensitive.</p> </li> <li> <p>For each associated resource, each tag key must be unique and it can have only one value.</p> </li> <li> <p>The <code>aws:</code> prefix is reserved for use by AWS; you can’t use it in any tag keys or values that you define. In addition, you can&#39;t edit or remove tag keys or values that use this prefix. </p> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Tag {
@@ -780,6 +1526,194 @@ pub struct TagResourceRequest {
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TagResourceResponse {}
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Tier {
+    /// `CUSTOM`
+    Custom,
+    /// `DEFAULT`
+    Default,
+    /// `DOT_NET_CORE`
+    DotNetCore,
+    /// `DOT_NET_WORKER`
+    DotNetWorker,
+    /// `DOT_NET_WEB_TIER`
+    DotNetWebTier,
+    /// `DOT_NET_WEB`
+    DotNetWeb,
+    /// `SQL_SERVER`
+    SqlServer,
+    /// `SQL_SERVER_ALWAYSON_AVAILABILITY_GROUP`
+    SqlServerAlwaysonAvailabilityGroup,
+    /// `MYSQL`
+    Mysql,
+    /// `POSTGRESQL`
+    Postgresql,
+    /// `JAVA_JMX`
+    JavaJmx,
+    /// `ORACLE`
+    Oracle,
+    /// `SAP_HANA_MULTI_NODE`
+    SapHanaMultiNode,
+    /// `SAP_HANA_SINGLE_NODE`
+    SapHanaSingleNode,
+    /// `SAP_HANA_HIGH_AVAILABILITY`
+    SapHanaHighAvailability,
+    /// `SQL_SERVER_FAILOVER_CLUSTER_INSTANCE`
+    SqlServerFailoverClusterInstance,
+    /// `SHAREPOINT`
+    Sharepoint,
+    /// `ACTIVE_DIRECTORY`
+    ActiveDirectory,
+    /// `SAP_NETWEAVER_STANDARD`
+    SapNetweaverStandard,
+    /// `SAP_NETWEAVER_DISTRIBUTED`
+    SapNetweaverDistributed,
+    /// `SAP_NETWEAVER_HIGH_AVAILABILITY`
+    SapNetweaverHighAvailability,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Tier {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Tier::Custom => "CUSTOM",
+            Tier::Default => "DEFAULT",
+            Tier::DotNetCore => "DOT_NET_CORE",
+            Tier::DotNetWorker => "DOT_NET_WORKER",
+            Tier::DotNetWebTier => "DOT_NET_WEB_TIER",
+            Tier::DotNetWeb => "DOT_NET_WEB",
+            Tier::SqlServer => "SQL_SERVER",
+            Tier::SqlServerAlwaysonAvailabilityGroup => "SQL_SERVER_ALWAYSON_AVAILABILITY_GROUP",
+            Tier::Mysql => "MYSQL",
+            Tier::Postgresql => "POSTGRESQL",
+            Tier::JavaJmx => "JAVA_JMX",
+            Tier::Oracle => "ORACLE",
+            Tier::SapHanaMultiNode => "SAP_HANA_MULTI_NODE",
+            Tier::SapHanaSingleNode => "SAP_HANA_SINGLE_NODE",
+            Tier::SapHanaHighAvailability => "SAP_HANA_HIGH_AVAILABILITY",
+            Tier::SqlServerFailoverClusterInstance => "SQL_SERVER_FAILOVER_CLUSTER_INSTANCE",
+            Tier::Sharepoint => "SHAREPOINT",
+            Tier::ActiveDirectory => "ACTIVE_DIRECTORY",
+            Tier::SapNetweaverStandard => "SAP_NETWEAVER_STANDARD",
+            Tier::SapNetweaverDistributed => "SAP_NETWEAVER_DISTRIBUTED",
+            Tier::SapNetweaverHighAvailability => "SAP_NETWEAVER_HIGH_AVAILABILITY",
+            Tier::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Tier {
+    fn default() -> Tier {
+        Tier::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Tier {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Tier {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Tier {
+    fn from(value: &'a str) -> Tier {
+        match value {
+            "CUSTOM" => Tier::Custom,
+            "DEFAULT" => Tier::Default,
+            "DOT_NET_CORE" => Tier::DotNetCore,
+            "DOT_NET_WORKER" => Tier::DotNetWorker,
+            "DOT_NET_WEB_TIER" => Tier::DotNetWebTier,
+            "DOT_NET_WEB" => Tier::DotNetWeb,
+            "SQL_SERVER" => Tier::SqlServer,
+            "SQL_SERVER_ALWAYSON_AVAILABILITY_GROUP" => Tier::SqlServerAlwaysonAvailabilityGroup,
+            "MYSQL" => Tier::Mysql,
+            "POSTGRESQL" => Tier::Postgresql,
+            "JAVA_JMX" => Tier::JavaJmx,
+            "ORACLE" => Tier::Oracle,
+            "SAP_HANA_MULTI_NODE" => Tier::SapHanaMultiNode,
+            "SAP_HANA_SINGLE_NODE" => Tier::SapHanaSingleNode,
+            "SAP_HANA_HIGH_AVAILABILITY" => Tier::SapHanaHighAvailability,
+            "SQL_SERVER_FAILOVER_CLUSTER_INSTANCE" => Tier::SqlServerFailoverClusterInstance,
+            "SHAREPOINT" => Tier::Sharepoint,
+            "ACTIVE_DIRECTORY" => Tier::ActiveDirectory,
+            "SAP_NETWEAVER_STANDARD" => Tier::SapNetweaverStandard,
+            "SAP_NETWEAVER_DISTRIBUTED" => Tier::SapNetweaverDistributed,
+            "SAP_NETWEAVER_HIGH_AVAILABILITY" => Tier::SapNetweaverHighAvailability,
+            _ => Tier::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Tier {
+    fn from(value: String) -> Tier {
+        match value.as_str() {
+            "CUSTOM" => Tier::Custom,
+            "DEFAULT" => Tier::Default,
+            "DOT_NET_CORE" => Tier::DotNetCore,
+            "DOT_NET_WORKER" => Tier::DotNetWorker,
+            "DOT_NET_WEB_TIER" => Tier::DotNetWebTier,
+            "DOT_NET_WEB" => Tier::DotNetWeb,
+            "SQL_SERVER" => Tier::SqlServer,
+            "SQL_SERVER_ALWAYSON_AVAILABILITY_GROUP" => Tier::SqlServerAlwaysonAvailabilityGroup,
+            "MYSQL" => Tier::Mysql,
+            "POSTGRESQL" => Tier::Postgresql,
+            "JAVA_JMX" => Tier::JavaJmx,
+            "ORACLE" => Tier::Oracle,
+            "SAP_HANA_MULTI_NODE" => Tier::SapHanaMultiNode,
+            "SAP_HANA_SINGLE_NODE" => Tier::SapHanaSingleNode,
+            "SAP_HANA_HIGH_AVAILABILITY" => Tier::SapHanaHighAvailability,
+            "SQL_SERVER_FAILOVER_CLUSTER_INSTANCE" => Tier::SqlServerFailoverClusterInstance,
+            "SHAREPOINT" => Tier::Sharepoint,
+            "ACTIVE_DIRECTORY" => Tier::ActiveDirectory,
+            "SAP_NETWEAVER_STANDARD" => Tier::SapNetweaverStandard,
+            "SAP_NETWEAVER_DISTRIBUTED" => Tier::SapNetweaverDistributed,
+            "SAP_NETWEAVER_HIGH_AVAILABILITY" => Tier::SapNetweaverHighAvailability,
+            _ => Tier::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Tier {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Tier, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Tier {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Tier {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Tier {
+    fn deserialize<D>(deserializer: D) -> Result<Tier, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct UntagResourceRequest {
@@ -844,7 +1778,7 @@ pub struct UpdateComponentConfigurationRequest {
     /// <p>The tier of the application component. Supported tiers include <code>DOT_NET_WORKER</code>, <code>DOT_NET_WEB</code>, <code>DOT_NET_CORE</code>, <code>SQL_SERVER</code>, and <code>DEFAULT</code>.</p>
     #[serde(rename = "Tier")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub tier: Option<String>,
+    pub tier: Option<Tier>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -2138,11 +3072,12 @@ pub struct ApplicationInsightsClient {
 impl ApplicationInsightsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> ApplicationInsightsClient {
         ApplicationInsightsClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Application Insights"),
         }
     }
 
@@ -2174,6 +3109,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: CreateApplicationRequest,
     ) -> Result<CreateApplicationResponse, RusotoError<CreateApplicationError>> {
         let mut request = SignedRequest::new("POST", "applicationinsights", &self.region, "/");
+        request.set_operation("CreateApplication");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "EC2WindowsBarleyService.CreateApplication");
@@ -2202,6 +3138,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: CreateComponentRequest,
     ) -> Result<CreateComponentResponse, RusotoError<CreateComponentError>> {
         let mut request = SignedRequest::new("POST", "applicationinsights", &self.region, "/");
+        request.set_operation("CreateComponent");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "EC2WindowsBarleyService.CreateComponent");
@@ -2229,6 +3166,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: CreateLogPatternRequest,
     ) -> Result<CreateLogPatternResponse, RusotoError<CreateLogPatternError>> {
         let mut request = SignedRequest::new("POST", "applicationinsights", &self.region, "/");
+        request.set_operation("CreateLogPattern");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "EC2WindowsBarleyService.CreateLogPattern");
@@ -2257,6 +3195,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: DeleteApplicationRequest,
     ) -> Result<DeleteApplicationResponse, RusotoError<DeleteApplicationError>> {
         let mut request = SignedRequest::new("POST", "applicationinsights", &self.region, "/");
+        request.set_operation("DeleteApplication");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "EC2WindowsBarleyService.DeleteApplication");
@@ -2285,6 +3224,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: DeleteComponentRequest,
     ) -> Result<DeleteComponentResponse, RusotoError<DeleteComponentError>> {
         let mut request = SignedRequest::new("POST", "applicationinsights", &self.region, "/");
+        request.set_operation("DeleteComponent");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "EC2WindowsBarleyService.DeleteComponent");
@@ -2312,6 +3252,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: DeleteLogPatternRequest,
     ) -> Result<DeleteLogPatternResponse, RusotoError<DeleteLogPatternError>> {
         let mut request = SignedRequest::new("POST", "applicationinsights", &self.region, "/");
+        request.set_operation("DeleteLogPattern");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "EC2WindowsBarleyService.DeleteLogPattern");
@@ -2340,6 +3281,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: DescribeApplicationRequest,
     ) -> Result<DescribeApplicationResponse, RusotoError<DescribeApplicationError>> {
         let mut request = SignedRequest::new("POST", "applicationinsights", &self.region, "/");
+        request.set_operation("DescribeApplication");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2371,6 +3313,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: DescribeComponentRequest,
     ) -> Result<DescribeComponentResponse, RusotoError<DescribeComponentError>> {
         let mut request = SignedRequest::new("POST", "applicationinsights", &self.region, "/");
+        request.set_operation("DescribeComponent");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "EC2WindowsBarleyService.DescribeComponent");
@@ -2402,6 +3345,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
         RusotoError<DescribeComponentConfigurationError>,
     > {
         let mut request = SignedRequest::new("POST", "applicationinsights", &self.region, "/");
+        request.set_operation("DescribeComponentConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2436,6 +3380,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
         RusotoError<DescribeComponentConfigurationRecommendationError>,
     > {
         let mut request = SignedRequest::new("POST", "applicationinsights", &self.region, "/");
+        request.set_operation("DescribeComponentConfigurationRecommendation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2467,6 +3412,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: DescribeLogPatternRequest,
     ) -> Result<DescribeLogPatternResponse, RusotoError<DescribeLogPatternError>> {
         let mut request = SignedRequest::new("POST", "applicationinsights", &self.region, "/");
+        request.set_operation("DescribeLogPattern");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "EC2WindowsBarleyService.DescribeLogPattern");
@@ -2495,6 +3441,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: DescribeObservationRequest,
     ) -> Result<DescribeObservationResponse, RusotoError<DescribeObservationError>> {
         let mut request = SignedRequest::new("POST", "applicationinsights", &self.region, "/");
+        request.set_operation("DescribeObservation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2526,6 +3473,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: DescribeProblemRequest,
     ) -> Result<DescribeProblemResponse, RusotoError<DescribeProblemError>> {
         let mut request = SignedRequest::new("POST", "applicationinsights", &self.region, "/");
+        request.set_operation("DescribeProblem");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "EC2WindowsBarleyService.DescribeProblem");
@@ -2554,6 +3502,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
     ) -> Result<DescribeProblemObservationsResponse, RusotoError<DescribeProblemObservationsError>>
     {
         let mut request = SignedRequest::new("POST", "applicationinsights", &self.region, "/");
+        request.set_operation("DescribeProblemObservations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2585,6 +3534,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: ListApplicationsRequest,
     ) -> Result<ListApplicationsResponse, RusotoError<ListApplicationsError>> {
         let mut request = SignedRequest::new("POST", "applicationinsights", &self.region, "/");
+        request.set_operation("ListApplications");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "EC2WindowsBarleyService.ListApplications");
@@ -2613,6 +3563,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: ListComponentsRequest,
     ) -> Result<ListComponentsResponse, RusotoError<ListComponentsError>> {
         let mut request = SignedRequest::new("POST", "applicationinsights", &self.region, "/");
+        request.set_operation("ListComponents");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "EC2WindowsBarleyService.ListComponents");
@@ -2640,6 +3591,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: ListConfigurationHistoryRequest,
     ) -> Result<ListConfigurationHistoryResponse, RusotoError<ListConfigurationHistoryError>> {
         let mut request = SignedRequest::new("POST", "applicationinsights", &self.region, "/");
+        request.set_operation("ListConfigurationHistory");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2671,6 +3623,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: ListLogPatternSetsRequest,
     ) -> Result<ListLogPatternSetsResponse, RusotoError<ListLogPatternSetsError>> {
         let mut request = SignedRequest::new("POST", "applicationinsights", &self.region, "/");
+        request.set_operation("ListLogPatternSets");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "EC2WindowsBarleyService.ListLogPatternSets");
@@ -2699,6 +3652,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: ListLogPatternsRequest,
     ) -> Result<ListLogPatternsResponse, RusotoError<ListLogPatternsError>> {
         let mut request = SignedRequest::new("POST", "applicationinsights", &self.region, "/");
+        request.set_operation("ListLogPatterns");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "EC2WindowsBarleyService.ListLogPatterns");
@@ -2726,6 +3680,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: ListProblemsRequest,
     ) -> Result<ListProblemsResponse, RusotoError<ListProblemsError>> {
         let mut request = SignedRequest::new("POST", "applicationinsights", &self.region, "/");
+        request.set_operation("ListProblems");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "EC2WindowsBarleyService.ListProblems");
@@ -2753,6 +3708,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = SignedRequest::new("POST", "applicationinsights", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2784,6 +3740,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = SignedRequest::new("POST", "applicationinsights", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "EC2WindowsBarleyService.TagResource");
@@ -2811,6 +3768,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = SignedRequest::new("POST", "applicationinsights", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "EC2WindowsBarleyService.UntagResource");
@@ -2838,6 +3796,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: UpdateApplicationRequest,
     ) -> Result<UpdateApplicationResponse, RusotoError<UpdateApplicationError>> {
         let mut request = SignedRequest::new("POST", "applicationinsights", &self.region, "/");
+        request.set_operation("UpdateApplication");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "EC2WindowsBarleyService.UpdateApplication");
@@ -2866,6 +3825,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: UpdateComponentRequest,
     ) -> Result<UpdateComponentResponse, RusotoError<UpdateComponentError>> {
         let mut request = SignedRequest::new("POST", "applicationinsights", &self.region, "/");
+        request.set_operation("UpdateComponent");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "EC2WindowsBarleyService.UpdateComponent");
@@ -2894,6 +3854,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
     ) -> Result<UpdateComponentConfigurationResponse, RusotoError<UpdateComponentConfigurationError>>
     {
         let mut request = SignedRequest::new("POST", "applicationinsights", &self.region, "/");
+        request.set_operation("UpdateComponentConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2925,6 +3886,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: UpdateLogPatternRequest,
     ) -> Result<UpdateLogPatternResponse, RusotoError<UpdateLogPatternError>> {
         let mut request = SignedRequest::new("POST", "applicationinsights", &self.region, "/");
+        request.set_operation("UpdateLogPattern");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "EC2WindowsBarleyService.UpdateLogPattern");
@@ -452,13 +452,106 @@ pub struct Duration {
     /// <p>A unit of time.</p>
     #[serde(rename = "unit")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub unit: Option<String>,
+    pub unit: Option<DurationUnit>,
     /// <p>A number of time units.</p>
     #[serde(rename = "value")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<i64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DurationUnit {
+    /// `s`
+    S,
+    /// `ms`
+    Ms,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DurationUnit {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DurationUnit::S => "s",
+            DurationUnit::Ms => "ms",
+            DurationUnit::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DurationUnit {
+    fn default() -> DurationUnit {
+        DurationUnit::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DurationUnit {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DurationUnit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DurationUnit {
+    fn from(value: &'a str) -> DurationUnit {
+        match value {
+            "s" => DurationUnit::S,
+            "ms" => DurationUnit::Ms,
+            _ => DurationUnit::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DurationUnit {
+    fn from(value: String) -> DurationUnit {
+        match value.as_str() {
+            "s" => DurationUnit::S,
+            "ms" => DurationUnit::Ms,
+            _ => DurationUnit::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DurationUnit {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DurationUnit, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DurationUnit {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DurationUnit {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DurationUnit {
+    fn deserialize<D>(deserializer: D) -> Result<DurationUnit, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An object that represents the egress filter rules for a service mesh.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct EgressFilter {
@@ -468,9 +561,102 @@ pub struct EgressFilter {
     /// type to <code>ALLOW_ALL</code> to allow egress to any endpoint inside or outside of the
     /// service mesh.</p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: EgressFilterType,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EgressFilterType {
+    /// `ALLOW_ALL`
+    AllowAll,
+    /// `DROP_ALL`
+    DropAll,
+    /// A value not known to this version of the crate
+    Unknown(String),
 }
 
+impl EgressFilterType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EgressFilterType::AllowAll => "ALLOW_ALL",
+            EgressFilterType::DropAll => "DROP_ALL",
+            EgressFilterType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EgressFilterType {
+    fn default() -> EgressFilterType {
+        EgressFilterType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EgressFilterType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EgressFilterType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EgressFilterType {
+    fn from(value: &'a str) -> EgressFilterType {
+        match value {
+            "ALLOW_ALL" => EgressFilterType::AllowAll,
+            "DROP_ALL" => EgressFilterType::DropAll,
+            _ => EgressFilterType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EgressFilterType {
+    fn from(value: String) -> EgressFilterType {
+        match value.as_str() {
+            "ALLOW_ALL" => EgressFilterType::AllowAll,
+            "DROP_ALL" => EgressFilterType::DropAll,
+            _ => EgressFilterType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EgressFilterType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EgressFilterType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EgressFilterType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EgressFilterType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EgressFilterType {
+    fn deserialize<D>(deserializer: D) -> Result<EgressFilterType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An object that represents an access log file.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FileAccessLog {
@@ -495,7 +681,7 @@ pub struct GrpcRetryPolicy {
     /// <p>Specify at least one of the valid values.</p>
     #[serde(rename = "grpcRetryEvents")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub grpc_retry_events: Option<Vec<String>>,
+    pub grpc_retry_events: Option<Vec<GrpcRetryPolicyEvent>>,
     /// <p>Specify at least one of the following values.</p>
     ///
     /// <pre><code>     &lt;ul&gt;
@@ -532,9 +718,117 @@ pub struct GrpcRetryPolicy {
     /// <p>Specify a valid value.</p>
     #[serde(rename = "tcpRetryEvents")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub tcp_retry_events: Option<Vec<String>>,
+    pub tcp_retry_events: Option<Vec<TcpRetryPolicyEvent>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum GrpcRetryPolicyEvent {
+    /// `cancelled`
+    Cancelled,
+    /// `deadline-exceeded`
+    DeadlineExceeded,
+    /// `internal`
+    Internal,
+    /// `resource-exhausted`
+    ResourceExhausted,
+    /// `unavailable`
+    Unavailable,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl GrpcRetryPolicyEvent {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            GrpcRetryPolicyEvent::Cancelled => "cancelled",
+            GrpcRetryPolicyEvent::DeadlineExceeded => "deadline-exceeded",
+            GrpcRetryPolicyEvent::Internal => "internal",
+            GrpcRetryPolicyEvent::ResourceExhausted => "resource-exhausted",
+            GrpcRetryPolicyEvent::Unavailable => "unavailable",
+            GrpcRetryPolicyEvent::Unknown(ref value) => value,
+        }
+    }
 }
 
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for GrpcRetryPolicyEvent {
+    fn default() -> GrpcRetryPolicyEvent {
+        GrpcRetryPolicyEvent::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for GrpcRetryPolicyEvent {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for GrpcRetryPolicyEvent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for GrpcRetryPolicyEvent {
+    fn from(value: &'a str) -> GrpcRetryPolicyEvent {
+        match value {
+            "cancelled" => GrpcRetryPolicyEvent::Cancelled,
+            "deadline-exceeded" => GrpcRetryPolicyEvent::DeadlineExceeded,
+            "internal" => GrpcRetryPolicyEvent::Internal,
+            "resource-exhausted" => GrpcRetryPolicyEvent::ResourceExhausted,
+            "unavailable" => GrpcRetryPolicyEvent::Unavailable,
+            _ => GrpcRetryPolicyEvent::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for GrpcRetryPolicyEvent {
+    fn from(value: String) -> GrpcRetryPolicyEvent {
+        match value.as_str() {
+            "cancelled" => GrpcRetryPolicyEvent::Cancelled,
+            "deadline-exceeded" => GrpcRetryPolicyEvent::DeadlineExceeded,
+            "internal" => GrpcRetryPolicyEvent::Internal,
+            "resource-exhausted" => GrpcRetryPolicyEvent::ResourceExhausted,
+            "unavailable" => GrpcRetryPolicyEvent::Unavailable,
+            _ => GrpcRetryPolicyEvent::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for GrpcRetryPolicyEvent {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<GrpcRetryPolicyEvent, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for GrpcRetryPolicyEvent {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for GrpcRetryPolicyEvent {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for GrpcRetryPolicyEvent {
+    fn deserialize<D>(deserializer: D) -> Result<GrpcRetryPolicyEvent, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An object that represents a GRPC route type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct GrpcRoute {
@@ -664,7 +958,7 @@ pub struct HealthCheckPolicy {
     pub port: Option<i64>,
     /// <p>The protocol for the health check request.</p>
     #[serde(rename = "protocol")]
-    pub protocol: String,
+    pub protocol: PortProtocol,
     /// <p>The amount of time to wait when receiving a response from the health check, in
     /// milliseconds.</p>
     #[serde(rename = "timeoutMillis")]
@@ -675,6 +969,134 @@ pub struct HealthCheckPolicy {
     pub unhealthy_threshold: i64,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum HttpMethod {
+    /// `GET`
+    Get,
+    /// `HEAD`
+    Head,
+    /// `POST`
+    Post,
+    /// `PUT`
+    Put,
+    /// `DELETE`
+    Delete,
+    /// `CONNECT`
+    Connect,
+    /// `OPTIONS`
+    Options,
+    /// `TRACE`
+    Trace,
+    /// `PATCH`
+    Patch,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl HttpMethod {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            HttpMethod::Get => "GET",
+            HttpMethod::Head => "HEAD",
+            HttpMethod::Post => "POST",
+            HttpMethod::Put => "PUT",
+            HttpMethod::Delete => "DELETE",
+            HttpMethod::Connect => "CONNECT",
+            HttpMethod::Options => "OPTIONS",
+            HttpMethod::Trace => "TRACE",
+            HttpMethod::Patch => "PATCH",
+            HttpMethod::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for HttpMethod {
+    fn default() -> HttpMethod {
+        HttpMethod::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for HttpMethod {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for HttpMethod {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for HttpMethod {
+    fn from(value: &'a str) -> HttpMethod {
+        match value {
+            "GET" => HttpMethod::Get,
+            "HEAD" => HttpMethod::Head,
+            "POST" => HttpMethod::Post,
+            "PUT" => HttpMethod::Put,
+            "DELETE" => HttpMethod::Delete,
+            "CONNECT" => HttpMethod::Connect,
+            "OPTIONS" => HttpMethod::Options,
+            "TRACE" => HttpMethod::Trace,
+            "PATCH" => HttpMethod::Patch,
+            _ => HttpMethod::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for HttpMethod {
+    fn from(value: String) -> HttpMethod {
+        match value.as_str() {
+            "GET" => HttpMethod::Get,
+            "HEAD" => HttpMethod::Head,
+            "POST" => HttpMethod::Post,
+            "PUT" => HttpMethod::Put,
+            "DELETE" => HttpMethod::Delete,
+            "CONNECT" => HttpMethod::Connect,
+            "OPTIONS" => HttpMethod::Options,
+            "TRACE" => HttpMethod::Trace,
+            "PATCH" => HttpMethod::Patch,
+            _ => HttpMethod::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for HttpMethod {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<HttpMethod, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for HttpMethod {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for HttpMethod {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for HttpMethod {
+    fn deserialize<D>(deserializer: D) -> Result<HttpMethod, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An object that represents a retry policy. Specify at least one value for at least one of the types of <code>RetryEvents</code>, a value for <code>maxRetries</code>, and a value for <code>perRetryTimeout</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct HttpRetryPolicy {
@@ -714,7 +1136,7 @@ pub struct HttpRetryPolicy {
     /// <p>Specify a valid value.</p>
     #[serde(rename = "tcpRetryEvents")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub tcp_retry_events: Option<Vec<String>>,
+    pub tcp_retry_events: Option<Vec<TcpRetryPolicyEvent>>,
 }
 
 /// <p>An object that represents an HTTP or HTTP2 route type.</p>
@@ -767,7 +1189,7 @@ pub struct HttpRouteMatch {
     /// <p>The client request method to match on. Specify only one.</p>
     #[serde(rename = "method")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub method: Option<String>,
+    pub method: Option<HttpMethod>,
     /// <p>Specifies the path to match requests with. This parameter must always start with
     /// <code>/</code>, which by itself matches all requests to the virtual service name. You
     /// can also match for path-based routing of requests. For example, if your virtual service
@@ -779,9 +1201,102 @@ pub struct HttpRouteMatch {
     /// <p>The client request scheme to match on. Specify only one.</p>
     #[serde(rename = "scheme")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scheme: Option<String>,
+    pub scheme: Option<HttpScheme>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum HttpScheme {
+    /// `http`
+    Http,
+    /// `https`
+    Https,
+    /// A value not known to this version of the crate
+    Unknown(String),
 }
 
+impl HttpScheme {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            HttpScheme::Http => "http",
+            HttpScheme::Https => "https",
+            HttpScheme::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for HttpScheme {
+    fn default() -> HttpScheme {
+        HttpScheme::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for HttpScheme {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for HttpScheme {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for HttpScheme {
+    fn from(value: &'a str) -> HttpScheme {
+        match value {
+            "http" => HttpScheme::Http,
+            "https" => HttpScheme::Https,
+            _ => HttpScheme::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for HttpScheme {
+    fn from(value: String) -> HttpScheme {
+        match value.as_str() {
+            "http" => HttpScheme::Http,
+            "https" => HttpScheme::Https,
+            _ => HttpScheme::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for HttpScheme {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<HttpScheme, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for HttpScheme {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for HttpScheme {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for HttpScheme {
+    fn deserialize<D>(deserializer: D) -> Result<HttpScheme, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ListMeshesInput {
@@ -1112,9 +1627,107 @@ pub struct MeshStatus {
     /// <p>The current mesh status.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<MeshStatusCode>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MeshStatusCode {
+    /// `ACTIVE`
+    Active,
+    /// `INACTIVE`
+    Inactive,
+    /// `DELETED`
+    Deleted,
+    /// A value not known to this version of the crate
+    Unknown(String),
 }
 
+impl MeshStatusCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MeshStatusCode::Active => "ACTIVE",
+            MeshStatusCode::Inactive => "INACTIVE",
+            MeshStatusCode::Deleted => "DELETED",
+            MeshStatusCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for MeshStatusCode {
+    fn default() -> MeshStatusCode {
+        MeshStatusCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for MeshStatusCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for MeshStatusCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for MeshStatusCode {
+    fn from(value: &'a str) -> MeshStatusCode {
+        match value {
+            "ACTIVE" => MeshStatusCode::Active,
+            "INACTIVE" => MeshStatusCode::Inactive,
+            "DELETED" => MeshStatusCode::Deleted,
+            _ => MeshStatusCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MeshStatusCode {
+    fn from(value: String) -> MeshStatusCode {
+        match value.as_str() {
+            "ACTIVE" => MeshStatusCode::Active,
+            "INACTIVE" => MeshStatusCode::Inactive,
+            "DELETED" => MeshStatusCode::Deleted,
+            _ => MeshStatusCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for MeshStatusCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<MeshStatusCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MeshStatusCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for MeshStatusCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MeshStatusCode {
+    fn deserialize<D>(deserializer: D) -> Result<MeshStatusCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An object that represents a port mapping.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PortMapping {
@@ -1123,9 +1736,112 @@ pub struct PortMapping {
     pub port: i64,
     /// <p>The protocol used for the port mapping. Specify one protocol.</p>
     #[serde(rename = "protocol")]
-    pub protocol: String,
+    pub protocol: PortProtocol,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PortProtocol {
+    /// `http`
+    Http,
+    /// `tcp`
+    Tcp,
+    /// `http2`
+    Http2,
+    /// `grpc`
+    Grpc,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PortProtocol {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PortProtocol::Http => "http",
+            PortProtocol::Tcp => "tcp",
+            PortProtocol::Http2 => "http2",
+            PortProtocol::Grpc => "grpc",
+            PortProtocol::Unknown(ref value) => value,
+        }
+    }
 }
 
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PortProtocol {
+    fn default() -> PortProtocol {
+        PortProtocol::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PortProtocol {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PortProtocol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PortProtocol {
+    fn from(value: &'a str) -> PortProtocol {
+        match value {
+            "http" => PortProtocol::Http,
+            "tcp" => PortProtocol::Tcp,
+            "http2" => PortProtocol::Http2,
+            "grpc" => PortProtocol::Grpc,
+            _ => PortProtocol::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PortProtocol {
+    fn from(value: String) -> PortProtocol {
+        match value.as_str() {
+            "http" => PortProtocol::Http,
+            "tcp" => PortProtocol::Tcp,
+            "http2" => PortProtocol::Http2,
+            "grpc" => PortProtocol::Grpc,
+            _ => PortProtocol::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PortProtocol {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PortProtocol, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PortProtocol {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PortProtocol {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PortProtocol {
+    fn deserialize<D>(deserializer: D) -> Result<PortProtocol, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An object that represents metadata for a resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1135,10 +1851,20 @@ pub struct ResourceMetadata {
     pub arn: String,
     /// <p>The Unix epoch timestamp in seconds for when the resource was created.</p>
     #[serde(rename = "createdAt")]
-    pub created_at: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub created_at: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The Unix epoch timestamp in seconds for when the resource was last updated.</p>
     #[serde(rename = "lastUpdatedAt")]
-    pub last_updated_at: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub last_updated_at: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The unique identifier for the resource.</p>
     #[serde(rename = "uid")]
     pub uid: String,
@@ -1190,41 +1916,139 @@ pub struct RouteRef {
     pub virtual_router_name: String,
 }
 
-/// <p>An object that represents a route specification. Specify one route type.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct RouteSpec {
-    /// <p>An object that represents the specification of a GRPC route.</p>
-    #[serde(rename = "grpcRoute")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub grpc_route: Option<GrpcRoute>,
-    /// <p>An object that represents the specification of an HTTP2 route.</p>
-    #[serde(rename = "http2Route")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub http_2_route: Option<HttpRoute>,
-    /// <p>An object that represents the specification of an HTTP route.</p>
-    #[serde(rename = "httpRoute")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub http_route: Option<HttpRoute>,
-    /// <p>The priority for the route. Routes are matched based on the specified value, where 0 is
-    /// the highest priority.</p>
-    #[serde(rename = "priority")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub priority: Option<i64>,
-    /// <p>An object that represents the specification of a TCP route.</p>
-    #[serde(rename = "tcpRoute")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub tcp_route: Option<TcpRoute>,
+/// <p>An object that represents a route specification. Specify one route type.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RouteSpec {
+    /// <p>An object that represents the specification of a GRPC route.</p>
+    #[serde(rename = "grpcRoute")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub grpc_route: Option<GrpcRoute>,
+    /// <p>An object that represents the specification of an HTTP2 route.</p>
+    #[serde(rename = "http2Route")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_2_route: Option<HttpRoute>,
+    /// <p>An object that represents the specification of an HTTP route.</p>
+    #[serde(rename = "httpRoute")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_route: Option<HttpRoute>,
+    /// <p>The priority for the route. Routes are matched based on the specified value, where 0 is
+    /// the highest priority.</p>
+    #[serde(rename = "priority")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<i64>,
+    /// <p>An object that represents the specification of a TCP route.</p>
+    #[serde(rename = "tcpRoute")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tcp_route: Option<TcpRoute>,
+}
+
+/// <p>An object that represents the current status of a route.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct RouteStatus {
+    /// <p>The current status for the route.</p>
+    #[serde(rename = "status")]
+    pub status: RouteStatusCode,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RouteStatusCode {
+    /// `ACTIVE`
+    Active,
+    /// `INACTIVE`
+    Inactive,
+    /// `DELETED`
+    Deleted,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RouteStatusCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RouteStatusCode::Active => "ACTIVE",
+            RouteStatusCode::Inactive => "INACTIVE",
+            RouteStatusCode::Deleted => "DELETED",
+            RouteStatusCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RouteStatusCode {
+    fn default() -> RouteStatusCode {
+        RouteStatusCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RouteStatusCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RouteStatusCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RouteStatusCode {
+    fn from(value: &'a str) -> RouteStatusCode {
+        match value {
+            "ACTIVE" => RouteStatusCode::Active,
+            "INACTIVE" => RouteStatusCode::Inactive,
+            "DELETED" => RouteStatusCode::Deleted,
+            _ => RouteStatusCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RouteStatusCode {
+    fn from(value: String) -> RouteStatusCode {
+        match value.as_str() {
+            "ACTIVE" => RouteStatusCode::Active,
+            "INACTIVE" => RouteStatusCode::Inactive,
+            "DELETED" => RouteStatusCode::Deleted,
+            _ => RouteStatusCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RouteStatusCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RouteStatusCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RouteStatusCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
 }
 
-/// <p>An object that represents the current status of a route.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct RouteStatus {
-    /// <p>The current status for the route.</p>
-    #[serde(rename = "status")]
-    pub status: String,
+impl ::serde::Serialize for RouteStatusCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for RouteStatusCode {
+    fn deserialize<D>(deserializer: D) -> Result<RouteStatusCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An object that represents the service discovery information for a virtual node.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ServiceDiscovery {
@@ -1272,6 +2096,94 @@ pub struct TagResourceInput {
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TagResourceOutput {}
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TcpRetryPolicyEvent {
+    /// `connection-error`
+    ConnectionError,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TcpRetryPolicyEvent {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TcpRetryPolicyEvent::ConnectionError => "connection-error",
+            TcpRetryPolicyEvent::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TcpRetryPolicyEvent {
+    fn default() -> TcpRetryPolicyEvent {
+        TcpRetryPolicyEvent::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TcpRetryPolicyEvent {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TcpRetryPolicyEvent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TcpRetryPolicyEvent {
+    fn from(value: &'a str) -> TcpRetryPolicyEvent {
+        match value {
+            "connection-error" => TcpRetryPolicyEvent::ConnectionError,
+            _ => TcpRetryPolicyEvent::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TcpRetryPolicyEvent {
+    fn from(value: String) -> TcpRetryPolicyEvent {
+        match value.as_str() {
+            "connection-error" => TcpRetryPolicyEvent::ConnectionError,
+            _ => TcpRetryPolicyEvent::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TcpRetryPolicyEvent {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TcpRetryPolicyEvent, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TcpRetryPolicyEvent {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TcpRetryPolicyEvent {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TcpRetryPolicyEvent {
+    fn deserialize<D>(deserializer: D) -> Result<TcpRetryPolicyEvent, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An object that represents a TCP route type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TcpRoute {
@@ -1512,9 +2424,107 @@ pub struct VirtualNodeSpec {
 pub struct VirtualNodeStatus {
     /// <p>The current status of the virtual node.</p>
     #[serde(rename = "status")]
-    pub status: String,
+    pub status: VirtualNodeStatusCode,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum VirtualNodeStatusCode {
+    /// `ACTIVE`
+    Active,
+    /// `INACTIVE`
+    Inactive,
+    /// `DELETED`
+    Deleted,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl VirtualNodeStatusCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            VirtualNodeStatusCode::Active => "ACTIVE",
+            VirtualNodeStatusCode::Inactive => "INACTIVE",
+            VirtualNodeStatusCode::Deleted => "DELETED",
+            VirtualNodeStatusCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for VirtualNodeStatusCode {
+    fn default() -> VirtualNodeStatusCode {
+        VirtualNodeStatusCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for VirtualNodeStatusCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for VirtualNodeStatusCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for VirtualNodeStatusCode {
+    fn from(value: &'a str) -> VirtualNodeStatusCode {
+        match value {
+            "ACTIVE" => VirtualNodeStatusCode::Active,
+            "INACTIVE" => VirtualNodeStatusCode::Inactive,
+            "DELETED" => VirtualNodeStatusCode::Deleted,
+            _ => VirtualNodeStatusCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for VirtualNodeStatusCode {
+    fn from(value: String) -> VirtualNodeStatusCode {
+        match value.as_str() {
+            "ACTIVE" => VirtualNodeStatusCode::Active,
+            "INACTIVE" => VirtualNodeStatusCode::Inactive,
+            "DELETED" => VirtualNodeStatusCode::Deleted,
+            _ => VirtualNodeStatusCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for VirtualNodeStatusCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<VirtualNodeStatusCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for VirtualNodeStatusCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
 }
 
+impl ::serde::Serialize for VirtualNodeStatusCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for VirtualNodeStatusCode {
+    fn deserialize<D>(deserializer: D) -> Result<VirtualNodeStatusCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An object that represents a virtual router returned by a describe operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1582,9 +2592,107 @@ pub struct VirtualRouterSpec {
 pub struct VirtualRouterStatus {
     /// <p>The current status of the virtual router.</p>
     #[serde(rename = "status")]
-    pub status: String,
+    pub status: VirtualRouterStatusCode,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum VirtualRouterStatusCode {
+    /// `ACTIVE`
+    Active,
+    /// `INACTIVE`
+    Inactive,
+    /// `DELETED`
+    Deleted,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl VirtualRouterStatusCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            VirtualRouterStatusCode::Active => "ACTIVE",
+            VirtualRouterStatusCode::Inactive => "INACTIVE",
+            VirtualRouterStatusCode::Deleted => "DELETED",
+            VirtualRouterStatusCode::Unknown(ref value) => value,
+        }
+    }
 }
 
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for VirtualRouterStatusCode {
+    fn default() -> VirtualRouterStatusCode {
+        VirtualRouterStatusCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for VirtualRouterStatusCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for VirtualRouterStatusCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for VirtualRouterStatusCode {
+    fn from(value: &'a str) -> VirtualRouterStatusCode {
+        match value {
+            "ACTIVE" => VirtualRouterStatusCode::Active,
+            "INACTIVE" => VirtualRouterStatusCode::Inactive,
+            "DELETED" => VirtualRouterStatusCode::Deleted,
+            _ => VirtualRouterStatusCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for VirtualRouterStatusCode {
+    fn from(value: String) -> VirtualRouterStatusCode {
+        match value.as_str() {
+            "ACTIVE" => VirtualRouterStatusCode::Active,
+            "INACTIVE" => VirtualRouterStatusCode::Inactive,
+            "DELETED" => VirtualRouterStatusCode::Deleted,
+            _ => VirtualRouterStatusCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for VirtualRouterStatusCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<VirtualRouterStatusCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for VirtualRouterStatusCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for VirtualRouterStatusCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for VirtualRouterStatusCode {
+    fn deserialize<D>(deserializer: D) -> Result<VirtualRouterStatusCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An object that represents a virtual service backend for a virtual node.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct VirtualServiceBackend {
@@ -1657,9 +2765,107 @@ pub struct VirtualServiceSpec {
 pub struct VirtualServiceStatus {
     /// <p>The current status of the virtual service.</p>
     #[serde(rename = "status")]
-    pub status: String,
+    pub status: VirtualServiceStatusCode,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum VirtualServiceStatusCode {
+    /// `ACTIVE`
+    Active,
+    /// `INACTIVE`
+    Inactive,
+    /// `DELETED`
+    Deleted,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl VirtualServiceStatusCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            VirtualServiceStatusCode::Active => "ACTIVE",
+            VirtualServiceStatusCode::Inactive => "INACTIVE",
+            VirtualServiceStatusCode::Deleted => "DELETED",
+            VirtualServiceStatusCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for VirtualServiceStatusCode {
+    fn default() -> VirtualServiceStatusCode {
+        VirtualServiceStatusCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for VirtualServiceStatusCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for VirtualServiceStatusCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for VirtualServiceStatusCode {
+    fn from(value: &'a str) -> VirtualServiceStatusCode {
+        match value {
+            "ACTIVE" => VirtualServiceStatusCode::Active,
+            "INACTIVE" => VirtualServiceStatusCode::Inactive,
+            "DELETED" => VirtualServiceStatusCode::Deleted,
+            _ => VirtualServiceStatusCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for VirtualServiceStatusCode {
+    fn from(value: String) -> VirtualServiceStatusCode {
+        match value.as_str() {
+            "ACTIVE" => VirtualServiceStatusCode::Active,
+            "INACTIVE" => VirtualServiceStatusCode::Inactive,
+            "DELETED" => VirtualServiceStatusCode::Deleted,
+            _ => VirtualServiceStatusCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for VirtualServiceStatusCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<VirtualServiceStatusCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for VirtualServiceStatusCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for VirtualServiceStatusCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for VirtualServiceStatusCode {
+    fn deserialize<D>(deserializer: D) -> Result<VirtualServiceStatusCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An object that represents a target and its relative weight. Traffic is distributed across
 /// targets according to their relative weight. For example, a weighted target with a relative
 /// weight of 50 receives five times as much traffic as one with a relative weight of
@@ -3924,11 +5130,12 @@ pub struct AppMeshClient {
 impl AppMeshClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> AppMeshClient {
         AppMeshClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "App Mesh"),
         }
     }
 
@@ -3968,6 +5175,7 @@ impl AppMesh for AppMeshClient {
         let request_uri = "/v20190125/meshes";
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("CreateMesh");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4011,6 +5219,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("CreateRoute");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4064,6 +5273,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("CreateVirtualNode");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4104,6 +5314,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("CreateVirtualRouter");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4144,6 +5355,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("CreateVirtualService");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4178,6 +5390,7 @@ impl AppMesh for AppMeshClient {
         let request_uri = format!("/v20190125/meshes/{mesh_name}", mesh_name = input.mesh_name);
 
         let mut request = SignedRequest::new("DELETE", "appmesh", &self.region, &request_uri);
+        request.set_operation("DeleteMesh");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4210,6 +5423,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appmesh", &self.region, &request_uri);
+        request.set_operation("DeleteRoute");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4245,6 +5459,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appmesh", &self.region, &request_uri);
+        request.set_operation("DeleteVirtualNode");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4280,6 +5495,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appmesh", &self.region, &request_uri);
+        request.set_operation("DeleteVirtualRouter");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4311,6 +5527,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appmesh", &self.region, &request_uri);
+        request.set_operation("DeleteVirtualService");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4338,6 +5555,7 @@ impl AppMesh for AppMeshClient {
         let request_uri = format!("/v20190125/meshes/{mesh_name}", mesh_name = input.mesh_name);
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation("DescribeMesh");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4370,6 +5588,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation("DescribeRoute");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4401,6 +5620,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation("DescribeVirtualNode");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4432,6 +5652,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation("DescribeVirtualRouter");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4463,6 +5684,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation("DescribeVirtualService");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4490,6 +5712,7 @@ impl AppMesh for AppMeshClient {
         let request_uri = "/v20190125/meshes";
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation("ListMeshes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4530,6 +5753,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation("ListRoutes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4566,6 +5790,7 @@ impl AppMesh for AppMeshClient {
         let request_uri = "/v20190125/tags";
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4606,6 +5831,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation("ListVirtualNodes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4645,6 +5871,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation("ListVirtualRouters");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4684,6 +5911,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation("ListVirtualServices");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4723,6 +5951,7 @@ impl AppMesh for AppMeshClient {
         let request_uri = "/v20190125/tag";
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4757,6 +5986,7 @@ impl AppMesh for AppMeshClient {
         let request_uri = "/v20190125/untag";
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4791,6 +6021,7 @@ impl AppMesh for AppMeshClient {
         let request_uri = format!("/v20190125/meshes/{mesh_name}", mesh_name = input.mesh_name);
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("UpdateMesh");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4826,6 +6057,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("UpdateRoute");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4860,6 +6092,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("UpdateVirtualNode");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4894,6 +6127,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("UpdateVirtualRouter");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4928,6 +6162,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("UpdateVirtualService");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4950,3 +6185,193 @@ impl AppMesh for AppMeshClient {
         }
     }
 }
+impl AppMeshClient {
+    /// Auto-paginating version of `list_meshes`, streaming every page of results.
+    pub fn list_meshes_pages(
+        &self,
+        input: ListMeshesInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListMeshesOutput, ListMeshesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AppMeshClient, input: ListMeshesInput| async move {
+                client.list_meshes(input).await
+            },
+            |input: &ListMeshesInput, output: &ListMeshesOutput| {
+                Some(ListMeshesInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_meshes`, streaming the `meshes` of every page of results.
+    pub fn list_meshes_items(
+        &self,
+        input: ListMeshesInput,
+    ) -> rusoto_core::pagination::RusotoStream<MeshRef, ListMeshesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_meshes_pages(input),
+            |output: ListMeshesOutput| output.meshes,
+        )
+    }
+
+    /// Auto-paginating version of `list_routes`, streaming every page of results.
+    pub fn list_routes_pages(
+        &self,
+        input: ListRoutesInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListRoutesOutput, ListRoutesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AppMeshClient, input: ListRoutesInput| async move {
+                client.list_routes(input).await
+            },
+            |input: &ListRoutesInput, output: &ListRoutesOutput| {
+                Some(ListRoutesInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_routes`, streaming the `routes` of every page of results.
+    pub fn list_routes_items(
+        &self,
+        input: ListRoutesInput,
+    ) -> rusoto_core::pagination::RusotoStream<RouteRef, ListRoutesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_routes_pages(input),
+            |output: ListRoutesOutput| output.routes,
+        )
+    }
+
+    /// Auto-paginating version of `list_tags_for_resource`, streaming every page of results.
+    pub fn list_tags_for_resource_pages(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListTagsForResourceOutput, ListTagsForResourceError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AppMeshClient, input: ListTagsForResourceInput| async move {
+                client.list_tags_for_resource(input).await
+            },
+            |input: &ListTagsForResourceInput, output: &ListTagsForResourceOutput| {
+                Some(ListTagsForResourceInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_tags_for_resource`, streaming the `tags` of every page of results.
+    pub fn list_tags_for_resource_items(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> rusoto_core::pagination::RusotoStream<TagRef, ListTagsForResourceError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_tags_for_resource_pages(input),
+            |output: ListTagsForResourceOutput| output.tags,
+        )
+    }
+
+    /// Auto-paginating version of `list_virtual_nodes`, streaming every page of results.
+    pub fn list_virtual_nodes_pages(
+        &self,
+        input: ListVirtualNodesInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListVirtualNodesOutput, ListVirtualNodesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AppMeshClient, input: ListVirtualNodesInput| async move {
+                client.list_virtual_nodes(input).await
+            },
+            |input: &ListVirtualNodesInput, output: &ListVirtualNodesOutput| {
+                Some(ListVirtualNodesInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_virtual_nodes`, streaming the `virtualNodes` of every page of results.
+    pub fn list_virtual_nodes_items(
+        &self,
+        input: ListVirtualNodesInput,
+    ) -> rusoto_core::pagination::RusotoStream<VirtualNodeRef, ListVirtualNodesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_virtual_nodes_pages(input),
+            |output: ListVirtualNodesOutput| output.virtual_nodes,
+        )
+    }
+
+    /// Auto-paginating version of `list_virtual_routers`, streaming every page of results.
+    pub fn list_virtual_routers_pages(
+        &self,
+        input: ListVirtualRoutersInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListVirtualRoutersOutput, ListVirtualRoutersError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AppMeshClient, input: ListVirtualRoutersInput| async move {
+                client.list_virtual_routers(input).await
+            },
+            |input: &ListVirtualRoutersInput, output: &ListVirtualRoutersOutput| {
+                Some(ListVirtualRoutersInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_virtual_routers`, streaming the `virtualRouters` of every page of results.
+    pub fn list_virtual_routers_items(
+        &self,
+        input: ListVirtualRoutersInput,
+    ) -> rusoto_core::pagination::RusotoStream<VirtualRouterRef, ListVirtualRoutersError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_virtual_routers_pages(input),
+            |output: ListVirtualRoutersOutput| output.virtual_routers,
+        )
+    }
+
+    /// Auto-paginating version of `list_virtual_services`, streaming every page of results.
+    pub fn list_virtual_services_pages(
+        &self,
+        input: ListVirtualServicesInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListVirtualServicesOutput, ListVirtualServicesError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AppMeshClient, input: ListVirtualServicesInput| async move {
+                client.list_virtual_services(input).await
+            },
+            |input: &ListVirtualServicesInput, output: &ListVirtualServicesOutput| {
+                Some(ListVirtualServicesInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_virtual_services`, streaming the `virtualServices` of every page of results.
+    pub fn list_virtual_services_items(
+        &self,
+        input: ListVirtualServicesInput,
+    ) -> rusoto_core::pagination::RusotoStream<VirtualServiceRef, ListVirtualServicesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_virtual_services_pages(input),
+            |output: ListVirtualServicesOutput| output.virtual_services,
+        )
+    }
+}
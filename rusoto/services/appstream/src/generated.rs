@@ -29,13 +29,219 @@ use serde_json;
 pub struct AccessEndpoint {
     /// <p>The type of interface endpoint.</p>
     #[serde(rename = "EndpointType")]
-    pub endpoint_type: String,
+    pub endpoint_type: AccessEndpointType,
     /// <p>The identifier (ID) of the VPC in which the interface endpoint is used.</p>
     #[serde(rename = "VpceId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub vpce_id: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AccessEndpointType {
+    /// `STREAMING`
+    Streaming,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AccessEndpointType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AccessEndpointType::Streaming => "STREAMING",
+            AccessEndpointType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AccessEndpointType {
+    fn default() -> AccessEndpointType {
+        AccessEndpointType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AccessEndpointType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AccessEndpointType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AccessEndpointType {
+    fn from(value: &'a str) -> AccessEndpointType {
+        match value {
+            "STREAMING" => AccessEndpointType::Streaming,
+            _ => AccessEndpointType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AccessEndpointType {
+    fn from(value: String) -> AccessEndpointType {
+        match value.as_str() {
+            "STREAMING" => AccessEndpointType::Streaming,
+            _ => AccessEndpointType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AccessEndpointType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AccessEndpointType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AccessEndpointType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AccessEndpointType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AccessEndpointType {
+    fn deserialize<D>(deserializer: D) -> Result<AccessEndpointType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Action {
+    /// `CLIPBOARD_COPY_FROM_LOCAL_DEVICE`
+    ClipboardCopyFromLocalDevice,
+    /// `CLIPBOARD_COPY_TO_LOCAL_DEVICE`
+    ClipboardCopyToLocalDevice,
+    /// `FILE_UPLOAD`
+    FileUpload,
+    /// `FILE_DOWNLOAD`
+    FileDownload,
+    /// `PRINTING_TO_LOCAL_DEVICE`
+    PrintingToLocalDevice,
+    /// `DOMAIN_PASSWORD_SIGNIN`
+    DomainPasswordSignin,
+    /// `DOMAIN_SMART_CARD_SIGNIN`
+    DomainSmartCardSignin,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Action {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Action::ClipboardCopyFromLocalDevice => "CLIPBOARD_COPY_FROM_LOCAL_DEVICE",
+            Action::ClipboardCopyToLocalDevice => "CLIPBOARD_COPY_TO_LOCAL_DEVICE",
+            Action::FileUpload => "FILE_UPLOAD",
+            Action::FileDownload => "FILE_DOWNLOAD",
+            Action::PrintingToLocalDevice => "PRINTING_TO_LOCAL_DEVICE",
+            Action::DomainPasswordSignin => "DOMAIN_PASSWORD_SIGNIN",
+            Action::DomainSmartCardSignin => "DOMAIN_SMART_CARD_SIGNIN",
+            Action::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Action {
+    fn default() -> Action {
+        Action::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Action {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Action {
+    fn from(value: &'a str) -> Action {
+        match value {
+            "CLIPBOARD_COPY_FROM_LOCAL_DEVICE" => Action::ClipboardCopyFromLocalDevice,
+            "CLIPBOARD_COPY_TO_LOCAL_DEVICE" => Action::ClipboardCopyToLocalDevice,
+            "FILE_UPLOAD" => Action::FileUpload,
+            "FILE_DOWNLOAD" => Action::FileDownload,
+            "PRINTING_TO_LOCAL_DEVICE" => Action::PrintingToLocalDevice,
+            "DOMAIN_PASSWORD_SIGNIN" => Action::DomainPasswordSignin,
+            "DOMAIN_SMART_CARD_SIGNIN" => Action::DomainSmartCardSignin,
+            _ => Action::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Action {
+    fn from(value: String) -> Action {
+        match value.as_str() {
+            "CLIPBOARD_COPY_FROM_LOCAL_DEVICE" => Action::ClipboardCopyFromLocalDevice,
+            "CLIPBOARD_COPY_TO_LOCAL_DEVICE" => Action::ClipboardCopyToLocalDevice,
+            "FILE_UPLOAD" => Action::FileUpload,
+            "FILE_DOWNLOAD" => Action::FileDownload,
+            "PRINTING_TO_LOCAL_DEVICE" => Action::PrintingToLocalDevice,
+            "DOMAIN_PASSWORD_SIGNIN" => Action::DomainPasswordSignin,
+            "DOMAIN_SMART_CARD_SIGNIN" => Action::DomainSmartCardSignin,
+            _ => Action::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Action {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Action, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Action {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Action {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Action {
+    fn deserialize<D>(deserializer: D) -> Result<Action, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes an application in the application catalog.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -116,6 +322,109 @@ pub struct AssociateFleetRequest {
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AssociateFleetResult {}
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AuthenticationType {
+    /// `API`
+    Api,
+    /// `SAML`
+    Saml,
+    /// `USERPOOL`
+    Userpool,
+    /// `AWS_AD`
+    AwsAd,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AuthenticationType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AuthenticationType::Api => "API",
+            AuthenticationType::Saml => "SAML",
+            AuthenticationType::Userpool => "USERPOOL",
+            AuthenticationType::AwsAd => "AWS_AD",
+            AuthenticationType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AuthenticationType {
+    fn default() -> AuthenticationType {
+        AuthenticationType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AuthenticationType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AuthenticationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AuthenticationType {
+    fn from(value: &'a str) -> AuthenticationType {
+        match value {
+            "API" => AuthenticationType::Api,
+            "SAML" => AuthenticationType::Saml,
+            "USERPOOL" => AuthenticationType::Userpool,
+            "AWS_AD" => AuthenticationType::AwsAd,
+            _ => AuthenticationType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AuthenticationType {
+    fn from(value: String) -> AuthenticationType {
+        match value.as_str() {
+            "API" => AuthenticationType::Api,
+            "SAML" => AuthenticationType::Saml,
+            "USERPOOL" => AuthenticationType::Userpool,
+            "AWS_AD" => AuthenticationType::AwsAd,
+            _ => AuthenticationType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AuthenticationType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AuthenticationType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AuthenticationType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AuthenticationType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AuthenticationType {
+    fn deserialize<D>(deserializer: D) -> Result<AuthenticationType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct BatchAssociateUserStackRequest {
@@ -259,7 +568,7 @@ pub struct CreateFleetRequest {
     /// <p><p>The fleet type.</p> <dl> <dt>ALWAYS<em>ON</dt> <dd> <p>Provides users with instant-on access to their apps. You are charged for all running instances in your fleet, even if no users are streaming apps.</p> </dd> <dt>ON</em>DEMAND</dt> <dd> <p>Provide users with access to applications after they connect, which takes one to two minutes. You are charged for instance streaming when users are connected and a small hourly fee for instances that are not streaming apps.</p> </dd> </dl></p>
     #[serde(rename = "FleetType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub fleet_type: Option<String>,
+    pub fleet_type: Option<FleetType>,
     /// <p>The Amazon Resource Name (ARN) of the IAM role to apply to the fleet. To assume a role, a fleet instance calls the AWS Security Token Service (STS) <code>AssumeRole</code> API operation and passes the ARN of the role to use. The operation creates a new session with temporary credentials. AppStream 2.0 retrieves the temporary credentials and creates the <b>AppStream_Machine_Role</b> credential profile on the instance.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/appstream2/latest/developerguide/using-iam-roles-to-grant-permissions-to-applications-scripts-streaming-instances.html">Using an IAM Role to Grant Permissions to Applications and Scripts Running on AppStream 2.0 Streaming Instances</a> in the <i>Amazon AppStream 2.0 Administration Guide</i>.</p>
     #[serde(rename = "IamRoleArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -386,8 +695,13 @@ pub struct CreateImageBuilderStreamingURLRequest {
 pub struct CreateImageBuilderStreamingURLResult {
     /// <p>The elapsed time, in seconds after the Unix epoch, when this URL expires.</p>
     #[serde(rename = "Expires")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expires: Option<f64>,
+    pub expires: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The URL to start the AppStream 2.0 streaming session.</p>
     #[serde(rename = "StreamingURL")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -482,8 +796,13 @@ pub struct CreateStreamingURLRequest {
 pub struct CreateStreamingURLResult {
     /// <p>The elapsed time, in seconds after the Unix epoch, when this URL expires.</p>
     #[serde(rename = "Expires")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expires: Option<f64>,
+    pub expires: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The URL to start the AppStream 2.0 streaming session.</p>
     #[serde(rename = "StreamingURL")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -504,7 +823,7 @@ pub struct CreateUsageReportSubscriptionResult {
     /// <p>The schedule for generating usage reports.</p>
     #[serde(rename = "Schedule")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub schedule: Option<String>,
+    pub schedule: Option<UsageReportSchedule>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -512,7 +831,7 @@ pub struct CreateUsageReportSubscriptionResult {
 pub struct CreateUserRequest {
     /// <p>The authentication type for the user. You must specify USERPOOL. </p>
     #[serde(rename = "AuthenticationType")]
-    pub authentication_type: String,
+    pub authentication_type: AuthenticationType,
     /// <p>The first name, or given name, of the user.</p>
     #[serde(rename = "FirstName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -524,7 +843,7 @@ pub struct CreateUserRequest {
     /// <p><p>The action to take for the welcome email that is sent to a user after the user is created in the user pool. If you specify SUPPRESS, no email is sent. If you specify RESEND, do not specify the first name or last name of the user. If the value is null, the email is sent. </p> <note> <p>The temporary password in the welcome email is valid for only 7 days. If users don’t set their passwords within 7 days, you must send them a new welcome email.</p> </note></p>
     #[serde(rename = "MessageAction")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub message_action: Option<String>,
+    pub message_action: Option<MessageAction>,
     /// <p><p>The email address of the user.</p> <note> <p>Users&#39; email addresses are case-sensitive. During login, if they specify an email address that doesn&#39;t use the same capitalization as the email address specified when their user pool account was created, a &quot;user does not exist&quot; error message displays.</p> </note></p>
     #[serde(rename = "UserName")]
     pub user_name: String,
@@ -632,7 +951,7 @@ pub struct DeleteUsageReportSubscriptionResult {}
 pub struct DeleteUserRequest {
     /// <p>The authentication type for the user. You must specify USERPOOL.</p>
     #[serde(rename = "AuthenticationType")]
-    pub authentication_type: String,
+    pub authentication_type: AuthenticationType,
     /// <p><p>The email address of the user.</p> <note> <p>Users&#39; email addresses are case-sensitive.</p> </note></p>
     #[serde(rename = "UserName")]
     pub user_name: String,
@@ -787,7 +1106,7 @@ pub struct DescribeImagesRequest {
     /// <p>The type of image (public, private, or shared) to describe. </p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<VisibilityType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -809,7 +1128,7 @@ pub struct DescribeSessionsRequest {
     /// <p>The authentication method. Specify <code>API</code> for a user authenticated using a streaming URL or <code>SAML</code> for a SAML federated user. The default is to authenticate users using a streaming URL.</p>
     #[serde(rename = "AuthenticationType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub authentication_type: Option<String>,
+    pub authentication_type: Option<AuthenticationType>,
     /// <p>The name of the fleet. This value is case-sensitive.</p>
     #[serde(rename = "FleetName")]
     pub fleet_name: String,
@@ -901,7 +1220,7 @@ pub struct DescribeUserStackAssociationsRequest {
     /// <p>The authentication type for the user who is associated with the stack. You must specify USERPOOL.</p>
     #[serde(rename = "AuthenticationType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub authentication_type: Option<String>,
+    pub authentication_type: Option<AuthenticationType>,
     /// <p>The maximum size of each page of results.</p>
     #[serde(rename = "MaxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -938,7 +1257,7 @@ pub struct DescribeUserStackAssociationsResult {
 pub struct DescribeUsersRequest {
     /// <p>The authentication type for the users in the user pool to describe. You must specify USERPOOL.</p>
     #[serde(rename = "AuthenticationType")]
-    pub authentication_type: String,
+    pub authentication_type: AuthenticationType,
     /// <p>The maximum size of each page of results.</p>
     #[serde(rename = "MaxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -968,8 +1287,13 @@ pub struct DescribeUsersResult {
 pub struct DirectoryConfig {
     /// <p>The time the directory configuration was created.</p>
     #[serde(rename = "CreatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time: Option<f64>,
+    pub created_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The fully qualified name of the directory (for example, corp.example.com).</p>
     #[serde(rename = "DirectoryName")]
     pub directory_name: String,
@@ -988,7 +1312,7 @@ pub struct DirectoryConfig {
 pub struct DisableUserRequest {
     /// <p>The authentication type for the user. You must specify USERPOOL.</p>
     #[serde(rename = "AuthenticationType")]
-    pub authentication_type: String,
+    pub authentication_type: AuthenticationType,
     /// <p><p>The email address of the user.</p> <note> <p>Users&#39; email addresses are case-sensitive.</p> </note></p>
     #[serde(rename = "UserName")]
     pub user_name: String,
@@ -1031,7 +1355,7 @@ pub struct DomainJoinInfo {
 pub struct EnableUserRequest {
     /// <p>The authentication type for the user. You must specify USERPOOL.</p>
     #[serde(rename = "AuthenticationType")]
-    pub authentication_type: String,
+    pub authentication_type: AuthenticationType,
     /// <p><p>The email address of the user.</p> <note> <p>Users&#39; email addresses are case-sensitive. During login, if they specify an email address that doesn&#39;t use the same capitalization as the email address specified when their user pool account was created, a &quot;user does not exist&quot; error message displays. </p> </note></p>
     #[serde(rename = "UserName")]
     pub user_name: String,
@@ -1065,8 +1389,13 @@ pub struct Fleet {
     pub compute_capacity_status: ComputeCapacityStatus,
     /// <p>The time the fleet was created.</p>
     #[serde(rename = "CreatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time: Option<f64>,
+    pub created_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The description to display.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1094,7 +1423,7 @@ pub struct Fleet {
     /// <p><p>The fleet type.</p> <dl> <dt>ALWAYS<em>ON</dt> <dd> <p>Provides users with instant-on access to their apps. You are charged for all running instances in your fleet, even if no users are streaming apps.</p> </dd> <dt>ON</em>DEMAND</dt> <dd> <p>Provide users with access to applications after they connect, which takes one to two minutes. You are charged for instance streaming when users are connected and a small hourly fee for instances that are not streaming apps.</p> </dd> </dl></p>
     #[serde(rename = "FleetType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub fleet_type: Option<String>,
+    pub fleet_type: Option<FleetType>,
     /// <p>The ARN of the IAM role that is applied to the fleet. To assume a role, the fleet instance calls the AWS Security Token Service (STS) <code>AssumeRole</code> API operation and passes the ARN of the role to use. The operation creates a new session with temporary credentials. AppStream 2.0 retrieves the temporary credentials and creates the <b>AppStream_Machine_Role</b> credential profile on the instance.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/appstream2/latest/developerguide/using-iam-roles-to-grant-permissions-to-applications-scripts-streaming-instances.html">Using an IAM Role to Grant Permissions to Applications and Scripts Running on AppStream 2.0 Streaming Instances</a> in the <i>Amazon AppStream 2.0 Administration Guide</i>.</p>
     #[serde(rename = "IamRoleArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1123,13 +1452,138 @@ pub struct Fleet {
     pub name: String,
     /// <p>The current state for the fleet.</p>
     #[serde(rename = "State")]
-    pub state: String,
+    pub state: FleetState,
     /// <p>The VPC configuration for the fleet.</p>
     #[serde(rename = "VpcConfig")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub vpc_config: Option<VpcConfig>,
 }
 
+/// <p>The fleet attribute.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FleetAttribute {
+    /// `VPC_CONFIGURATION`
+    VpcConfiguration,
+    /// `VPC_CONFIGURATION_SECURITY_GROUP_IDS`
+    VpcConfigurationSecurityGroupIds,
+    /// `DOMAIN_JOIN_INFO`
+    DomainJoinInfo,
+    /// `IAM_ROLE_ARN`
+    IamRoleArn,
+    /// `USB_DEVICE_FILTER_STRINGS`
+    UsbDeviceFilterStrings,
+    /// `SESSION_SCRIPT_S3_LOCATION`
+    SessionScriptS3Location,
+    /// `MAX_SESSIONS_PER_INSTANCE`
+    MaxSessionsPerInstance,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl FleetAttribute {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FleetAttribute::VpcConfiguration => "VPC_CONFIGURATION",
+            FleetAttribute::VpcConfigurationSecurityGroupIds => {
+                "VPC_CONFIGURATION_SECURITY_GROUP_IDS"
+            }
+            FleetAttribute::DomainJoinInfo => "DOMAIN_JOIN_INFO",
+            FleetAttribute::IamRoleArn => "IAM_ROLE_ARN",
+            FleetAttribute::UsbDeviceFilterStrings => "USB_DEVICE_FILTER_STRINGS",
+            FleetAttribute::SessionScriptS3Location => "SESSION_SCRIPT_S3_LOCATION",
+            FleetAttribute::MaxSessionsPerInstance => "MAX_SESSIONS_PER_INSTANCE",
+            FleetAttribute::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for FleetAttribute {
+    fn default() -> FleetAttribute {
+        FleetAttribute::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for FleetAttribute {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FleetAttribute {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FleetAttribute {
+    fn from(value: &'a str) -> FleetAttribute {
+        match value {
+            "VPC_CONFIGURATION" => FleetAttribute::VpcConfiguration,
+            "VPC_CONFIGURATION_SECURITY_GROUP_IDS" => {
+                FleetAttribute::VpcConfigurationSecurityGroupIds
+            }
+            "DOMAIN_JOIN_INFO" => FleetAttribute::DomainJoinInfo,
+            "IAM_ROLE_ARN" => FleetAttribute::IamRoleArn,
+            "USB_DEVICE_FILTER_STRINGS" => FleetAttribute::UsbDeviceFilterStrings,
+            "SESSION_SCRIPT_S3_LOCATION" => FleetAttribute::SessionScriptS3Location,
+            "MAX_SESSIONS_PER_INSTANCE" => FleetAttribute::MaxSessionsPerInstance,
+            _ => FleetAttribute::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FleetAttribute {
+    fn from(value: String) -> FleetAttribute {
+        match value.as_str() {
+            "VPC_CONFIGURATION" => FleetAttribute::VpcConfiguration,
+            "VPC_CONFIGURATION_SECURITY_GROUP_IDS" => {
+                FleetAttribute::VpcConfigurationSecurityGroupIds
+            }
+            "DOMAIN_JOIN_INFO" => FleetAttribute::DomainJoinInfo,
+            "IAM_ROLE_ARN" => FleetAttribute::IamRoleArn,
+            "USB_DEVICE_FILTER_STRINGS" => FleetAttribute::UsbDeviceFilterStrings,
+            "SESSION_SCRIPT_S3_LOCATION" => FleetAttribute::SessionScriptS3Location,
+            "MAX_SESSIONS_PER_INSTANCE" => FleetAttribute::MaxSessionsPerInstance,
+            _ => FleetAttribute::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for FleetAttribute {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<FleetAttribute, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FleetAttribute {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FleetAttribute {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FleetAttribute {
+    fn deserialize<D>(deserializer: D) -> Result<FleetAttribute, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes a fleet error.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1137,104 +1591,619 @@ pub struct FleetError {
     /// <p>The error code.</p>
     #[serde(rename = "ErrorCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub error_code: Option<String>,
+    pub error_code: Option<FleetErrorCode>,
     /// <p>The error message.</p>
     #[serde(rename = "ErrorMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error_message: Option<String>,
 }
 
-/// <p>Describes an image.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct Image {
-    /// <p>The applications associated with the image.</p>
-    #[serde(rename = "Applications")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub applications: Option<Vec<Application>>,
-    /// <p>The version of the AppStream 2.0 agent to use for instances that are launched from this image. </p>
-    #[serde(rename = "AppstreamAgentVersion")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub appstream_agent_version: Option<String>,
-    /// <p>The ARN of the image.</p>
-    #[serde(rename = "Arn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub arn: Option<String>,
-    /// <p>The ARN of the image from which this image was created.</p>
-    #[serde(rename = "BaseImageArn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub base_image_arn: Option<String>,
-    /// <p>The time the image was created.</p>
-    #[serde(rename = "CreatedTime")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time: Option<f64>,
-    /// <p>The description to display.</p>
-    #[serde(rename = "Description")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub description: Option<String>,
-    /// <p>The image name to display.</p>
-    #[serde(rename = "DisplayName")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub display_name: Option<String>,
-    /// <p>The name of the image builder that was used to create the private image. If the image is shared, this value is null.</p>
-    #[serde(rename = "ImageBuilderName")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub image_builder_name: Option<String>,
-    /// <p>Indicates whether an image builder can be launched from this image.</p>
-    #[serde(rename = "ImageBuilderSupported")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub image_builder_supported: Option<bool>,
-    /// <p>The permissions to provide to the destination AWS account for the specified image.</p>
-    #[serde(rename = "ImagePermissions")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub image_permissions: Option<ImagePermissions>,
-    /// <p>The name of the image.</p>
-    #[serde(rename = "Name")]
-    pub name: String,
-    /// <p>The operating system platform of the image.</p>
-    #[serde(rename = "Platform")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub platform: Option<String>,
-    /// <p>The release date of the public base image. For private images, this date is the release date of the base image from which the image was created.</p>
-    #[serde(rename = "PublicBaseImageReleasedDate")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub public_base_image_released_date: Option<f64>,
-    /// <p>The image starts in the <code>PENDING</code> state. If image creation succeeds, the state is <code>AVAILABLE</code>. If image creation fails, the state is <code>FAILED</code>.</p>
-    #[serde(rename = "State")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
-    /// <p>The reason why the last state change occurred.</p>
-    #[serde(rename = "StateChangeReason")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub state_change_reason: Option<ImageStateChangeReason>,
-    /// <p>Indicates whether the image is public or private.</p>
-    #[serde(rename = "Visibility")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub visibility: Option<String>,
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FleetErrorCode {
+    /// `IAM_SERVICE_ROLE_MISSING_ENI_DESCRIBE_ACTION`
+    IamServiceRoleMissingEniDescribeAction,
+    /// `IAM_SERVICE_ROLE_MISSING_ENI_CREATE_ACTION`
+    IamServiceRoleMissingEniCreateAction,
+    /// `IAM_SERVICE_ROLE_MISSING_ENI_DELETE_ACTION`
+    IamServiceRoleMissingEniDeleteAction,
+    /// `NETWORK_INTERFACE_LIMIT_EXCEEDED`
+    NetworkInterfaceLimitExceeded,
+    /// `INTERNAL_SERVICE_ERROR`
+    InternalServiceError,
+    /// `IAM_SERVICE_ROLE_IS_MISSING`
+    IamServiceRoleIsMissing,
+    /// `MACHINE_ROLE_IS_MISSING`
+    MachineRoleIsMissing,
+    /// `STS_DISABLED_IN_REGION`
+    StsDisabledInRegion,
+    /// `SUBNET_HAS_INSUFFICIENT_IP_ADDRESSES`
+    SubnetHasInsufficientIpAddresses,
+    /// `IAM_SERVICE_ROLE_MISSING_DESCRIBE_SUBNET_ACTION`
+    IamServiceRoleMissingDescribeSubnetAction,
+    /// `SUBNET_NOT_FOUND`
+    SubnetNotFound,
+    /// `IMAGE_NOT_FOUND`
+    ImageNotFound,
+    /// `INVALID_SUBNET_CONFIGURATION`
+    InvalidSubnetConfiguration,
+    /// `SECURITY_GROUPS_NOT_FOUND`
+    SecurityGroupsNotFound,
+    /// `IGW_NOT_ATTACHED`
+    IgwNotAttached,
+    /// `IAM_SERVICE_ROLE_MISSING_DESCRIBE_SECURITY_GROUPS_ACTION`
+    IamServiceRoleMissingDescribeSecurityGroupsAction,
+    /// `FLEET_STOPPED`
+    FleetStopped,
+    /// `FLEET_INSTANCE_PROVISIONING_FAILURE`
+    FleetInstanceProvisioningFailure,
+    /// `DOMAIN_JOIN_ERROR_FILE_NOT_FOUND`
+    DomainJoinErrorFileNotFound,
+    /// `DOMAIN_JOIN_ERROR_ACCESS_DENIED`
+    DomainJoinErrorAccessDenied,
+    /// `DOMAIN_JOIN_ERROR_LOGON_FAILURE`
+    DomainJoinErrorLogonFailure,
+    /// `DOMAIN_JOIN_ERROR_INVALID_PARAMETER`
+    DomainJoinErrorInvalidParameter,
+    /// `DOMAIN_JOIN_ERROR_MORE_DATA`
+    DomainJoinErrorMoreData,
+    /// `DOMAIN_JOIN_ERROR_NO_SUCH_DOMAIN`
+    DomainJoinErrorNoSuchDomain,
+    /// `DOMAIN_JOIN_ERROR_NOT_SUPPORTED`
+    DomainJoinErrorNotSupported,
+    /// `DOMAIN_JOIN_NERR_INVALID_WORKGROUP_NAME`
+    DomainJoinNerrInvalidWorkgroupName,
+    /// `DOMAIN_JOIN_NERR_WORKSTATION_NOT_STARTED`
+    DomainJoinNerrWorkstationNotStarted,
+    /// `DOMAIN_JOIN_ERROR_DS_MACHINE_ACCOUNT_QUOTA_EXCEEDED`
+    DomainJoinErrorDsMachineAccountQuotaExceeded,
+    /// `DOMAIN_JOIN_NERR_PASSWORD_EXPIRED`
+    DomainJoinNerrPasswordExpired,
+    /// `DOMAIN_JOIN_INTERNAL_SERVICE_ERROR`
+    DomainJoinInternalServiceError,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl FleetErrorCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FleetErrorCode::IamServiceRoleMissingEniDescribeAction => {
+                "IAM_SERVICE_ROLE_MISSING_ENI_DESCRIBE_ACTION"
+            }
+            FleetErrorCode::IamServiceRoleMissingEniCreateAction => {
+                "IAM_SERVICE_ROLE_MISSING_ENI_CREATE_ACTION"
+            }
+            FleetErrorCode::IamServiceRoleMissingEniDeleteAction => {
+                "IAM_SERVICE_ROLE_MISSING_ENI_DELETE_ACTION"
+            }
+            FleetErrorCode::NetworkInterfaceLimitExceeded => "NETWORK_INTERFACE_LIMIT_EXCEEDED",
+            FleetErrorCode::InternalServiceError => "INTERNAL_SERVICE_ERROR",
+            FleetErrorCode::IamServiceRoleIsMissing => "IAM_SERVICE_ROLE_IS_MISSING",
+            FleetErrorCode::MachineRoleIsMissing => "MACHINE_ROLE_IS_MISSING",
+            FleetErrorCode::StsDisabledInRegion => "STS_DISABLED_IN_REGION",
+            FleetErrorCode::SubnetHasInsufficientIpAddresses => {
+                "SUBNET_HAS_INSUFFICIENT_IP_ADDRESSES"
+            }
+            FleetErrorCode::IamServiceRoleMissingDescribeSubnetAction => {
+                "IAM_SERVICE_ROLE_MISSING_DESCRIBE_SUBNET_ACTION"
+            }
+            FleetErrorCode::SubnetNotFound => "SUBNET_NOT_FOUND",
+            FleetErrorCode::ImageNotFound => "IMAGE_NOT_FOUND",
+            FleetErrorCode::InvalidSubnetConfiguration => "INVALID_SUBNET_CONFIGURATION",
+            FleetErrorCode::SecurityGroupsNotFound => "SECURITY_GROUPS_NOT_FOUND",
+            FleetErrorCode::IgwNotAttached => "IGW_NOT_ATTACHED",
+            FleetErrorCode::IamServiceRoleMissingDescribeSecurityGroupsAction => {
+                "IAM_SERVICE_ROLE_MISSING_DESCRIBE_SECURITY_GROUPS_ACTION"
+            }
+            FleetErrorCode::FleetStopped => "FLEET_STOPPED",
+            FleetErrorCode::FleetInstanceProvisioningFailure => {
+                "FLEET_INSTANCE_PROVISIONING_FAILURE"
+            }
+            FleetErrorCode::DomainJoinErrorFileNotFound => "DOMAIN_JOIN_ERROR_FILE_NOT_FOUND",
+            FleetErrorCode::DomainJoinErrorAccessDenied => "DOMAIN_JOIN_ERROR_ACCESS_DENIED",
+            FleetErrorCode::DomainJoinErrorLogonFailure => "DOMAIN_JOIN_ERROR_LOGON_FAILURE",
+            FleetErrorCode::DomainJoinErrorInvalidParameter => {
+                "DOMAIN_JOIN_ERROR_INVALID_PARAMETER"
+            }
+            FleetErrorCode::DomainJoinErrorMoreData => "DOMAIN_JOIN_ERROR_MORE_DATA",
+            FleetErrorCode::DomainJoinErrorNoSuchDomain => "DOMAIN_JOIN_ERROR_NO_SUCH_DOMAIN",
+            FleetErrorCode::DomainJoinErrorNotSupported => "DOMAIN_JOIN_ERROR_NOT_SUPPORTED",
+            FleetErrorCode::DomainJoinNerrInvalidWorkgroupName => {
+                "DOMAIN_JOIN_NERR_INVALID_WORKGROUP_NAME"
+            }
+            FleetErrorCode::DomainJoinNerrWorkstationNotStarted => {
+                "DOMAIN_JOIN_NERR_WORKSTATION_NOT_STARTED"
+            }
+            FleetErrorCode::DomainJoinErrorDsMachineAccountQuotaExceeded => {
+                "DOMAIN_JOIN_ERROR_DS_MACHINE_ACCOUNT_QUOTA_EXCEEDED"
+            }
+            FleetErrorCode::DomainJoinNerrPasswordExpired => "DOMAIN_JOIN_NERR_PASSWORD_EXPIRED",
+            FleetErrorCode::DomainJoinInternalServiceError => "DOMAIN_JOIN_INTERNAL_SERVICE_ERROR",
+            FleetErrorCode::Unknown(ref value) => value,
+        }
+    }
 }
 
-/// <p>Describes a virtual machine that is used to create an image. </p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct ImageBuilder {
-    /// <p>The list of virtual private cloud (VPC) interface endpoint objects. Administrators can connect to the image builder only through the specified endpoints.</p>
-    #[serde(rename = "AccessEndpoints")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub access_endpoints: Option<Vec<AccessEndpoint>>,
-    /// <p>The version of the AppStream 2.0 agent that is currently being used by the image builder. </p>
-    #[serde(rename = "AppstreamAgentVersion")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub appstream_agent_version: Option<String>,
-    /// <p>The ARN for the image builder.</p>
-    #[serde(rename = "Arn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub arn: Option<String>,
-    /// <p>The time stamp when the image builder was created.</p>
-    #[serde(rename = "CreatedTime")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time: Option<f64>,
-    /// <p>The description to display.</p>
-    #[serde(rename = "Description")]
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for FleetErrorCode {
+    fn default() -> FleetErrorCode {
+        FleetErrorCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for FleetErrorCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FleetErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FleetErrorCode {
+    fn from(value: &'a str) -> FleetErrorCode {
+        match value {
+            "IAM_SERVICE_ROLE_MISSING_ENI_DESCRIBE_ACTION" => {
+                FleetErrorCode::IamServiceRoleMissingEniDescribeAction
+            }
+            "IAM_SERVICE_ROLE_MISSING_ENI_CREATE_ACTION" => {
+                FleetErrorCode::IamServiceRoleMissingEniCreateAction
+            }
+            "IAM_SERVICE_ROLE_MISSING_ENI_DELETE_ACTION" => {
+                FleetErrorCode::IamServiceRoleMissingEniDeleteAction
+            }
+            "NETWORK_INTERFACE_LIMIT_EXCEEDED" => FleetErrorCode::NetworkInterfaceLimitExceeded,
+            "INTERNAL_SERVICE_ERROR" => FleetErrorCode::InternalServiceError,
+            "IAM_SERVICE_ROLE_IS_MISSING" => FleetErrorCode::IamServiceRoleIsMissing,
+            "MACHINE_ROLE_IS_MISSING" => FleetErrorCode::MachineRoleIsMissing,
+            "STS_DISABLED_IN_REGION" => FleetErrorCode::StsDisabledInRegion,
+            "SUBNET_HAS_INSUFFICIENT_IP_ADDRESSES" => {
+                FleetErrorCode::SubnetHasInsufficientIpAddresses
+            }
+            "IAM_SERVICE_ROLE_MISSING_DESCRIBE_SUBNET_ACTION" => {
+                FleetErrorCode::IamServiceRoleMissingDescribeSubnetAction
+            }
+            "SUBNET_NOT_FOUND" => FleetErrorCode::SubnetNotFound,
+            "IMAGE_NOT_FOUND" => FleetErrorCode::ImageNotFound,
+            "INVALID_SUBNET_CONFIGURATION" => FleetErrorCode::InvalidSubnetConfiguration,
+            "SECURITY_GROUPS_NOT_FOUND" => FleetErrorCode::SecurityGroupsNotFound,
+            "IGW_NOT_ATTACHED" => FleetErrorCode::IgwNotAttached,
+            "IAM_SERVICE_ROLE_MISSING_DESCRIBE_SECURITY_GROUPS_ACTION" => {
+                FleetErrorCode::IamServiceRoleMissingDescribeSecurityGroupsAction
+            }
+            "FLEET_STOPPED" => FleetErrorCode::FleetStopped,
+            "FLEET_INSTANCE_PROVISIONING_FAILURE" => {
+                FleetErrorCode::FleetInstanceProvisioningFailure
+            }
+            "DOMAIN_JOIN_ERROR_FILE_NOT_FOUND" => FleetErrorCode::DomainJoinErrorFileNotFound,
+            "DOMAIN_JOIN_ERROR_ACCESS_DENIED" => FleetErrorCode::DomainJoinErrorAccessDenied,
+            "DOMAIN_JOIN_ERROR_LOGON_FAILURE" => FleetErrorCode::DomainJoinErrorLogonFailure,
+            "DOMAIN_JOIN_ERROR_INVALID_PARAMETER" => {
+                FleetErrorCode::DomainJoinErrorInvalidParameter
+            }
+            "DOMAIN_JOIN_ERROR_MORE_DATA" => FleetErrorCode::DomainJoinErrorMoreData,
+            "DOMAIN_JOIN_ERROR_NO_SUCH_DOMAIN" => FleetErrorCode::DomainJoinErrorNoSuchDomain,
+            "DOMAIN_JOIN_ERROR_NOT_SUPPORTED" => FleetErrorCode::DomainJoinErrorNotSupported,
+            "DOMAIN_JOIN_NERR_INVALID_WORKGROUP_NAME" => {
+                FleetErrorCode::DomainJoinNerrInvalidWorkgroupName
+            }
+            "DOMAIN_JOIN_NERR_WORKSTATION_NOT_STARTED" => {
+                FleetErrorCode::DomainJoinNerrWorkstationNotStarted
+            }
+            "DOMAIN_JOIN_ERROR_DS_MACHINE_ACCOUNT_QUOTA_EXCEEDED" => {
+                FleetErrorCode::DomainJoinErrorDsMachineAccountQuotaExceeded
+            }
+            "DOMAIN_JOIN_NERR_PASSWORD_EXPIRED" => FleetErrorCode::DomainJoinNerrPasswordExpired,
+            "DOMAIN_JOIN_INTERNAL_SERVICE_ERROR" => FleetErrorCode::DomainJoinInternalServiceError,
+            _ => FleetErrorCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FleetErrorCode {
+    fn from(value: String) -> FleetErrorCode {
+        match value.as_str() {
+            "IAM_SERVICE_ROLE_MISSING_ENI_DESCRIBE_ACTION" => {
+                FleetErrorCode::IamServiceRoleMissingEniDescribeAction
+            }
+            "IAM_SERVICE_ROLE_MISSING_ENI_CREATE_ACTION" => {
+                FleetErrorCode::IamServiceRoleMissingEniCreateAction
+            }
+            "IAM_SERVICE_ROLE_MISSING_ENI_DELETE_ACTION" => {
+                FleetErrorCode::IamServiceRoleMissingEniDeleteAction
+            }
+            "NETWORK_INTERFACE_LIMIT_EXCEEDED" => FleetErrorCode::NetworkInterfaceLimitExceeded,
+            "INTERNAL_SERVICE_ERROR" => FleetErrorCode::InternalServiceError,
+            "IAM_SERVICE_ROLE_IS_MISSING" => FleetErrorCode::IamServiceRoleIsMissing,
+            "MACHINE_ROLE_IS_MISSING" => FleetErrorCode::MachineRoleIsMissing,
+            "STS_DISABLED_IN_REGION" => FleetErrorCode::StsDisabledInRegion,
+            "SUBNET_HAS_INSUFFICIENT_IP_ADDRESSES" => {
+                FleetErrorCode::SubnetHasInsufficientIpAddresses
+            }
+            "IAM_SERVICE_ROLE_MISSING_DESCRIBE_SUBNET_ACTION" => {
+                FleetErrorCode::IamServiceRoleMissingDescribeSubnetAction
+            }
+            "SUBNET_NOT_FOUND" => FleetErrorCode::SubnetNotFound,
+            "IMAGE_NOT_FOUND" => FleetErrorCode::ImageNotFound,
+            "INVALID_SUBNET_CONFIGURATION" => FleetErrorCode::InvalidSubnetConfiguration,
+            "SECURITY_GROUPS_NOT_FOUND" => FleetErrorCode::SecurityGroupsNotFound,
+            "IGW_NOT_ATTACHED" => FleetErrorCode::IgwNotAttached,
+            "IAM_SERVICE_ROLE_MISSING_DESCRIBE_SECURITY_GROUPS_ACTION" => {
+                FleetErrorCode::IamServiceRoleMissingDescribeSecurityGroupsAction
+            }
+            "FLEET_STOPPED" => FleetErrorCode::FleetStopped,
+            "FLEET_INSTANCE_PROVISIONING_FAILURE" => {
+                FleetErrorCode::FleetInstanceProvisioningFailure
+            }
+            "DOMAIN_JOIN_ERROR_FILE_NOT_FOUND" => FleetErrorCode::DomainJoinErrorFileNotFound,
+            "DOMAIN_JOIN_ERROR_ACCESS_DENIED" => FleetErrorCode::DomainJoinErrorAccessDenied,
+            "DOMAIN_JOIN_ERROR_LOGON_FAILURE" => FleetErrorCode::DomainJoinErrorLogonFailure,
+            "DOMAIN_JOIN_ERROR_INVALID_PARAMETER" => {
+                FleetErrorCode::DomainJoinErrorInvalidParameter
+            }
+            "DOMAIN_JOIN_ERROR_MORE_DATA" => FleetErrorCode::DomainJoinErrorMoreData,
+            "DOMAIN_JOIN_ERROR_NO_SUCH_DOMAIN" => FleetErrorCode::DomainJoinErrorNoSuchDomain,
+            "DOMAIN_JOIN_ERROR_NOT_SUPPORTED" => FleetErrorCode::DomainJoinErrorNotSupported,
+            "DOMAIN_JOIN_NERR_INVALID_WORKGROUP_NAME" => {
+                FleetErrorCode::DomainJoinNerrInvalidWorkgroupName
+            }
+            "DOMAIN_JOIN_NERR_WORKSTATION_NOT_STARTED" => {
+                FleetErrorCode::DomainJoinNerrWorkstationNotStarted
+            }
+            "DOMAIN_JOIN_ERROR_DS_MACHINE_ACCOUNT_QUOTA_EXCEEDED" => {
+                FleetErrorCode::DomainJoinErrorDsMachineAccountQuotaExceeded
+            }
+            "DOMAIN_JOIN_NERR_PASSWORD_EXPIRED" => FleetErrorCode::DomainJoinNerrPasswordExpired,
+            "DOMAIN_JOIN_INTERNAL_SERVICE_ERROR" => FleetErrorCode::DomainJoinInternalServiceError,
+            _ => FleetErrorCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for FleetErrorCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<FleetErrorCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FleetErrorCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FleetErrorCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FleetErrorCode {
+    fn deserialize<D>(deserializer: D) -> Result<FleetErrorCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FleetState {
+    /// `STARTING`
+    Starting,
+    /// `RUNNING`
+    Running,
+    /// `STOPPING`
+    Stopping,
+    /// `STOPPED`
+    Stopped,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl FleetState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FleetState::Starting => "STARTING",
+            FleetState::Running => "RUNNING",
+            FleetState::Stopping => "STOPPING",
+            FleetState::Stopped => "STOPPED",
+            FleetState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for FleetState {
+    fn default() -> FleetState {
+        FleetState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for FleetState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FleetState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FleetState {
+    fn from(value: &'a str) -> FleetState {
+        match value {
+            "STARTING" => FleetState::Starting,
+            "RUNNING" => FleetState::Running,
+            "STOPPING" => FleetState::Stopping,
+            "STOPPED" => FleetState::Stopped,
+            _ => FleetState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FleetState {
+    fn from(value: String) -> FleetState {
+        match value.as_str() {
+            "STARTING" => FleetState::Starting,
+            "RUNNING" => FleetState::Running,
+            "STOPPING" => FleetState::Stopping,
+            "STOPPED" => FleetState::Stopped,
+            _ => FleetState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for FleetState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<FleetState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FleetState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FleetState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FleetState {
+    fn deserialize<D>(deserializer: D) -> Result<FleetState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FleetType {
+    /// `ALWAYS_ON`
+    AlwaysOn,
+    /// `ON_DEMAND`
+    OnDemand,
+    /// `ELASTIC`
+    Elastic,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl FleetType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FleetType::AlwaysOn => "ALWAYS_ON",
+            FleetType::OnDemand => "ON_DEMAND",
+            FleetType::Elastic => "ELASTIC",
+            FleetType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for FleetType {
+    fn default() -> FleetType {
+        FleetType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for FleetType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FleetType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FleetType {
+    fn from(value: &'a str) -> FleetType {
+        match value {
+            "ALWAYS_ON" => FleetType::AlwaysOn,
+            "ON_DEMAND" => FleetType::OnDemand,
+            "ELASTIC" => FleetType::Elastic,
+            _ => FleetType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FleetType {
+    fn from(value: String) -> FleetType {
+        match value.as_str() {
+            "ALWAYS_ON" => FleetType::AlwaysOn,
+            "ON_DEMAND" => FleetType::OnDemand,
+            "ELASTIC" => FleetType::Elastic,
+            _ => FleetType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for FleetType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<FleetType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FleetType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FleetType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FleetType {
+    fn deserialize<D>(deserializer: D) -> Result<FleetType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Describes an image.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct Image {
+    /// <p>The applications associated with the image.</p>
+    #[serde(rename = "Applications")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub applications: Option<Vec<Application>>,
+    /// <p>The version of the AppStream 2.0 agent to use for instances that are launched from this image. </p>
+    #[serde(rename = "AppstreamAgentVersion")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub appstream_agent_version: Option<String>,
+    /// <p>The ARN of the image.</p>
+    #[serde(rename = "Arn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub arn: Option<String>,
+    /// <p>The ARN of the image from which this image was created.</p>
+    #[serde(rename = "BaseImageArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base_image_arn: Option<String>,
+    /// <p>The time the image was created.</p>
+    #[serde(rename = "CreatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_time: Option<::chrono::DateTime<::chrono::Utc>>,
+    /// <p>The description to display.</p>
+    #[serde(rename = "Description")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// <p>The image name to display.</p>
+    #[serde(rename = "DisplayName")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    /// <p>The name of the image builder that was used to create the private image. If the image is shared, this value is null.</p>
+    #[serde(rename = "ImageBuilderName")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_builder_name: Option<String>,
+    /// <p>Indicates whether an image builder can be launched from this image.</p>
+    #[serde(rename = "ImageBuilderSupported")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_builder_supported: Option<bool>,
+    /// <p>The permissions to provide to the destination AWS account for the specified image.</p>
+    #[serde(rename = "ImagePermissions")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_permissions: Option<ImagePermissions>,
+    /// <p>The name of the image.</p>
+    #[serde(rename = "Name")]
+    pub name: String,
+    /// <p>The operating system platform of the image.</p>
+    #[serde(rename = "Platform")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub platform: Option<PlatformType>,
+    /// <p>The release date of the public base image. For private images, this date is the release date of the base image from which the image was created.</p>
+    #[serde(rename = "PublicBaseImageReleasedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_base_image_released_date: Option<::chrono::DateTime<::chrono::Utc>>,
+    /// <p>The image starts in the <code>PENDING</code> state. If image creation succeeds, the state is <code>AVAILABLE</code>. If image creation fails, the state is <code>FAILED</code>.</p>
+    #[serde(rename = "State")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<ImageState>,
+    /// <p>The reason why the last state change occurred.</p>
+    #[serde(rename = "StateChangeReason")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state_change_reason: Option<ImageStateChangeReason>,
+    /// <p>Indicates whether the image is public or private.</p>
+    #[serde(rename = "Visibility")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<VisibilityType>,
+}
+
+/// <p>Describes a virtual machine that is used to create an image. </p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct ImageBuilder {
+    /// <p>The list of virtual private cloud (VPC) interface endpoint objects. Administrators can connect to the image builder only through the specified endpoints.</p>
+    #[serde(rename = "AccessEndpoints")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub access_endpoints: Option<Vec<AccessEndpoint>>,
+    /// <p>The version of the AppStream 2.0 agent that is currently being used by the image builder. </p>
+    #[serde(rename = "AppstreamAgentVersion")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub appstream_agent_version: Option<String>,
+    /// <p>The ARN for the image builder.</p>
+    #[serde(rename = "Arn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub arn: Option<String>,
+    /// <p>The time stamp when the image builder was created.</p>
+    #[serde(rename = "CreatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_time: Option<::chrono::DateTime<::chrono::Utc>>,
+    /// <p>The description to display.</p>
+    #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     /// <p>The image builder name to display.</p>
@@ -1274,11 +2243,11 @@ pub struct ImageBuilder {
     /// <p>The operating system platform of the image builder.</p>
     #[serde(rename = "Platform")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub platform: Option<String>,
+    pub platform: Option<PlatformType>,
     /// <p>The state of the image builder.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<ImageBuilderState>,
     /// <p>The reason why the last state change occurred.</p>
     #[serde(rename = "StateChangeReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1289,20 +2258,251 @@ pub struct ImageBuilder {
     pub vpc_config: Option<VpcConfig>,
 }
 
-/// <p>Describes the reason why the last image builder state change occurred.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct ImageBuilderStateChangeReason {
-    /// <p>The state change reason code.</p>
-    #[serde(rename = "Code")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub code: Option<String>,
-    /// <p>The state change reason message.</p>
-    #[serde(rename = "Message")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub message: Option<String>,
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ImageBuilderState {
+    /// `PENDING`
+    Pending,
+    /// `UPDATING_AGENT`
+    UpdatingAgent,
+    /// `RUNNING`
+    Running,
+    /// `STOPPING`
+    Stopping,
+    /// `STOPPED`
+    Stopped,
+    /// `REBOOTING`
+    Rebooting,
+    /// `SNAPSHOTTING`
+    Snapshotting,
+    /// `DELETING`
+    Deleting,
+    /// `FAILED`
+    Failed,
+    /// `UPDATING`
+    Updating,
+    /// `PENDING_QUALIFICATION`
+    PendingQualification,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ImageBuilderState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ImageBuilderState::Pending => "PENDING",
+            ImageBuilderState::UpdatingAgent => "UPDATING_AGENT",
+            ImageBuilderState::Running => "RUNNING",
+            ImageBuilderState::Stopping => "STOPPING",
+            ImageBuilderState::Stopped => "STOPPED",
+            ImageBuilderState::Rebooting => "REBOOTING",
+            ImageBuilderState::Snapshotting => "SNAPSHOTTING",
+            ImageBuilderState::Deleting => "DELETING",
+            ImageBuilderState::Failed => "FAILED",
+            ImageBuilderState::Updating => "UPDATING",
+            ImageBuilderState::PendingQualification => "PENDING_QUALIFICATION",
+            ImageBuilderState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ImageBuilderState {
+    fn default() -> ImageBuilderState {
+        ImageBuilderState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ImageBuilderState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ImageBuilderState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ImageBuilderState {
+    fn from(value: &'a str) -> ImageBuilderState {
+        match value {
+            "PENDING" => ImageBuilderState::Pending,
+            "UPDATING_AGENT" => ImageBuilderState::UpdatingAgent,
+            "RUNNING" => ImageBuilderState::Running,
+            "STOPPING" => ImageBuilderState::Stopping,
+            "STOPPED" => ImageBuilderState::Stopped,
+            "REBOOTING" => ImageBuilderState::Rebooting,
+            "SNAPSHOTTING" => ImageBuilderState::Snapshotting,
+            "DELETING" => ImageBuilderState::Deleting,
+            "FAILED" => ImageBuilderState::Failed,
+            "UPDATING" => ImageBuilderState::Updating,
+            "PENDING_QUALIFICATION" => ImageBuilderState::PendingQualification,
+            _ => ImageBuilderState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ImageBuilderState {
+    fn from(value: String) -> ImageBuilderState {
+        match value.as_str() {
+            "PENDING" => ImageBuilderState::Pending,
+            "UPDATING_AGENT" => ImageBuilderState::UpdatingAgent,
+            "RUNNING" => ImageBuilderState::Running,
+            "STOPPING" => ImageBuilderState::Stopping,
+            "STOPPED" => ImageBuilderState::Stopped,
+            "REBOOTING" => ImageBuilderState::Rebooting,
+            "SNAPSHOTTING" => ImageBuilderState::Snapshotting,
+            "DELETING" => ImageBuilderState::Deleting,
+            "FAILED" => ImageBuilderState::Failed,
+            "UPDATING" => ImageBuilderState::Updating,
+            "PENDING_QUALIFICATION" => ImageBuilderState::PendingQualification,
+            _ => ImageBuilderState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ImageBuilderState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ImageBuilderState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ImageBuilderState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ImageBuilderState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ImageBuilderState {
+    fn deserialize<D>(deserializer: D) -> Result<ImageBuilderState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Describes the reason why the last image builder state change occurred.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct ImageBuilderStateChangeReason {
+    /// <p>The state change reason code.</p>
+    #[serde(rename = "Code")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<ImageBuilderStateChangeReasonCode>,
+    /// <p>The state change reason message.</p>
+    #[serde(rename = "Message")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ImageBuilderStateChangeReasonCode {
+    /// `INTERNAL_ERROR`
+    InternalError,
+    /// `IMAGE_UNAVAILABLE`
+    ImageUnavailable,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ImageBuilderStateChangeReasonCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ImageBuilderStateChangeReasonCode::InternalError => "INTERNAL_ERROR",
+            ImageBuilderStateChangeReasonCode::ImageUnavailable => "IMAGE_UNAVAILABLE",
+            ImageBuilderStateChangeReasonCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ImageBuilderStateChangeReasonCode {
+    fn default() -> ImageBuilderStateChangeReasonCode {
+        ImageBuilderStateChangeReasonCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ImageBuilderStateChangeReasonCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ImageBuilderStateChangeReasonCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ImageBuilderStateChangeReasonCode {
+    fn from(value: &'a str) -> ImageBuilderStateChangeReasonCode {
+        match value {
+            "INTERNAL_ERROR" => ImageBuilderStateChangeReasonCode::InternalError,
+            "IMAGE_UNAVAILABLE" => ImageBuilderStateChangeReasonCode::ImageUnavailable,
+            _ => ImageBuilderStateChangeReasonCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ImageBuilderStateChangeReasonCode {
+    fn from(value: String) -> ImageBuilderStateChangeReasonCode {
+        match value.as_str() {
+            "INTERNAL_ERROR" => ImageBuilderStateChangeReasonCode::InternalError,
+            "IMAGE_UNAVAILABLE" => ImageBuilderStateChangeReasonCode::ImageUnavailable,
+            _ => ImageBuilderStateChangeReasonCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ImageBuilderStateChangeReasonCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ImageBuilderStateChangeReasonCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ImageBuilderStateChangeReasonCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ImageBuilderStateChangeReasonCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for ImageBuilderStateChangeReasonCode {
+    fn deserialize<D>(deserializer: D) -> Result<ImageBuilderStateChangeReasonCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes the permissions for an image. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ImagePermissions {
@@ -1316,6 +2516,124 @@ pub struct ImagePermissions {
     pub allow_image_builder: Option<bool>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ImageState {
+    /// `PENDING`
+    Pending,
+    /// `AVAILABLE`
+    Available,
+    /// `FAILED`
+    Failed,
+    /// `COPYING`
+    Copying,
+    /// `DELETING`
+    Deleting,
+    /// `CREATING`
+    Creating,
+    /// `IMPORTING`
+    Importing,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ImageState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ImageState::Pending => "PENDING",
+            ImageState::Available => "AVAILABLE",
+            ImageState::Failed => "FAILED",
+            ImageState::Copying => "COPYING",
+            ImageState::Deleting => "DELETING",
+            ImageState::Creating => "CREATING",
+            ImageState::Importing => "IMPORTING",
+            ImageState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ImageState {
+    fn default() -> ImageState {
+        ImageState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ImageState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ImageState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ImageState {
+    fn from(value: &'a str) -> ImageState {
+        match value {
+            "PENDING" => ImageState::Pending,
+            "AVAILABLE" => ImageState::Available,
+            "FAILED" => ImageState::Failed,
+            "COPYING" => ImageState::Copying,
+            "DELETING" => ImageState::Deleting,
+            "CREATING" => ImageState::Creating,
+            "IMPORTING" => ImageState::Importing,
+            _ => ImageState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ImageState {
+    fn from(value: String) -> ImageState {
+        match value.as_str() {
+            "PENDING" => ImageState::Pending,
+            "AVAILABLE" => ImageState::Available,
+            "FAILED" => ImageState::Failed,
+            "COPYING" => ImageState::Copying,
+            "DELETING" => ImageState::Deleting,
+            "CREATING" => ImageState::Creating,
+            "IMPORTING" => ImageState::Importing,
+            _ => ImageState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ImageState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ImageState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ImageState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ImageState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ImageState {
+    fn deserialize<D>(deserializer: D) -> Result<ImageState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes the reason why the last image state change occurred.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1323,13 +2641,111 @@ pub struct ImageStateChangeReason {
     /// <p>The state change reason code.</p>
     #[serde(rename = "Code")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub code: Option<String>,
+    pub code: Option<ImageStateChangeReasonCode>,
     /// <p>The state change reason message.</p>
     #[serde(rename = "Message")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub message: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ImageStateChangeReasonCode {
+    /// `INTERNAL_ERROR`
+    InternalError,
+    /// `IMAGE_BUILDER_NOT_AVAILABLE`
+    ImageBuilderNotAvailable,
+    /// `IMAGE_COPY_FAILURE`
+    ImageCopyFailure,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ImageStateChangeReasonCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ImageStateChangeReasonCode::InternalError => "INTERNAL_ERROR",
+            ImageStateChangeReasonCode::ImageBuilderNotAvailable => "IMAGE_BUILDER_NOT_AVAILABLE",
+            ImageStateChangeReasonCode::ImageCopyFailure => "IMAGE_COPY_FAILURE",
+            ImageStateChangeReasonCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ImageStateChangeReasonCode {
+    fn default() -> ImageStateChangeReasonCode {
+        ImageStateChangeReasonCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ImageStateChangeReasonCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ImageStateChangeReasonCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ImageStateChangeReasonCode {
+    fn from(value: &'a str) -> ImageStateChangeReasonCode {
+        match value {
+            "INTERNAL_ERROR" => ImageStateChangeReasonCode::InternalError,
+            "IMAGE_BUILDER_NOT_AVAILABLE" => ImageStateChangeReasonCode::ImageBuilderNotAvailable,
+            "IMAGE_COPY_FAILURE" => ImageStateChangeReasonCode::ImageCopyFailure,
+            _ => ImageStateChangeReasonCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ImageStateChangeReasonCode {
+    fn from(value: String) -> ImageStateChangeReasonCode {
+        match value.as_str() {
+            "INTERNAL_ERROR" => ImageStateChangeReasonCode::InternalError,
+            "IMAGE_BUILDER_NOT_AVAILABLE" => ImageStateChangeReasonCode::ImageBuilderNotAvailable,
+            "IMAGE_COPY_FAILURE" => ImageStateChangeReasonCode::ImageCopyFailure,
+            _ => ImageStateChangeReasonCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ImageStateChangeReasonCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ImageStateChangeReasonCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ImageStateChangeReasonCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ImageStateChangeReasonCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ImageStateChangeReasonCode {
+    fn deserialize<D>(deserializer: D) -> Result<ImageStateChangeReasonCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes the error that is returned when a usage report can't be generated.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1337,7 +2753,7 @@ pub struct LastReportGenerationExecutionError {
     /// <p>The error code for the error that is returned when a usage report can't be generated.</p>
     #[serde(rename = "ErrorCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub error_code: Option<String>,
+    pub error_code: Option<UsageReportExecutionErrorCode>,
     /// <p>The error message for the error that is returned when a usage report can't be generated.</p>
     #[serde(rename = "ErrorMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1369,62 +2785,356 @@ pub struct ListAssociatedFleetsResult {
     pub next_token: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct ListAssociatedStacksRequest {
-    /// <p>The name of the fleet.</p>
-    #[serde(rename = "FleetName")]
-    pub fleet_name: String,
-    /// <p>The pagination token to use to retrieve the next page of results for this operation. If this value is null, it retrieves the first page.</p>
-    #[serde(rename = "NextToken")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_token: Option<String>,
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct ListAssociatedStacksRequest {
+    /// <p>The name of the fleet.</p>
+    #[serde(rename = "FleetName")]
+    pub fleet_name: String,
+    /// <p>The pagination token to use to retrieve the next page of results for this operation. If this value is null, it retrieves the first page.</p>
+    #[serde(rename = "NextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct ListAssociatedStacksResult {
+    /// <p>The name of the stack.</p>
+    #[serde(rename = "Names")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub names: Option<Vec<String>>,
+    /// <p>The pagination token to use to retrieve the next page of results for this operation. If there are no more pages, this value is null.</p>
+    #[serde(rename = "NextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct ListTagsForResourceRequest {
+    /// <p>The Amazon Resource Name (ARN) of the resource.</p>
+    #[serde(rename = "ResourceArn")]
+    pub resource_arn: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct ListTagsForResourceResponse {
+    /// <p>The information about the tags.</p>
+    #[serde(rename = "Tags")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MessageAction {
+    /// `SUPPRESS`
+    Suppress,
+    /// `RESEND`
+    Resend,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl MessageAction {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MessageAction::Suppress => "SUPPRESS",
+            MessageAction::Resend => "RESEND",
+            MessageAction::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for MessageAction {
+    fn default() -> MessageAction {
+        MessageAction::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for MessageAction {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for MessageAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for MessageAction {
+    fn from(value: &'a str) -> MessageAction {
+        match value {
+            "SUPPRESS" => MessageAction::Suppress,
+            "RESEND" => MessageAction::Resend,
+            _ => MessageAction::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MessageAction {
+    fn from(value: String) -> MessageAction {
+        match value.as_str() {
+            "SUPPRESS" => MessageAction::Suppress,
+            "RESEND" => MessageAction::Resend,
+            _ => MessageAction::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for MessageAction {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<MessageAction, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MessageAction {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for MessageAction {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MessageAction {
+    fn deserialize<D>(deserializer: D) -> Result<MessageAction, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Describes the network details of the fleet or image builder instance.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct NetworkAccessConfiguration {
+    /// <p>The resource identifier of the elastic network interface that is attached to instances in your VPC. All network interfaces have the eni-xxxxxxxx resource identifier.</p>
+    #[serde(rename = "EniId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub eni_id: Option<String>,
+    /// <p>The private IP address of the elastic network interface that is attached to instances in your VPC.</p>
+    #[serde(rename = "EniPrivateIpAddress")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub eni_private_ip_address: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Permission {
+    /// `ENABLED`
+    Enabled,
+    /// `DISABLED`
+    Disabled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Permission {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Permission::Enabled => "ENABLED",
+            Permission::Disabled => "DISABLED",
+            Permission::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Permission {
+    fn default() -> Permission {
+        Permission::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Permission {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Permission {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Permission {
+    fn from(value: &'a str) -> Permission {
+        match value {
+            "ENABLED" => Permission::Enabled,
+            "DISABLED" => Permission::Disabled,
+            _ => Permission::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Permission {
+    fn from(value: String) -> Permission {
+        match value.as_str() {
+            "ENABLED" => Permission::Enabled,
+            "DISABLED" => Permission::Disabled,
+            _ => Permission::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Permission {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Permission, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Permission {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Permission {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Permission {
+    fn deserialize<D>(deserializer: D) -> Result<Permission, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PlatformType {
+    /// `WINDOWS`
+    Windows,
+    /// `WINDOWS_SERVER_2016`
+    WindowsServer2016,
+    /// `WINDOWS_SERVER_2019`
+    WindowsServer2019,
+    /// `WINDOWS_SERVER_2022`
+    WindowsServer2022,
+    /// `AMAZON_LINUX2`
+    AmazonLinux2,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PlatformType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PlatformType::Windows => "WINDOWS",
+            PlatformType::WindowsServer2016 => "WINDOWS_SERVER_2016",
+            PlatformType::WindowsServer2019 => "WINDOWS_SERVER_2019",
+            PlatformType::WindowsServer2022 => "WINDOWS_SERVER_2022",
+            PlatformType::AmazonLinux2 => "AMAZON_LINUX2",
+            PlatformType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PlatformType {
+    fn default() -> PlatformType {
+        PlatformType::Unknown(String::new())
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct ListAssociatedStacksResult {
-    /// <p>The name of the stack.</p>
-    #[serde(rename = "Names")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub names: Option<Vec<String>>,
-    /// <p>The pagination token to use to retrieve the next page of results for this operation. If there are no more pages, this value is null.</p>
-    #[serde(rename = "NextToken")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_token: Option<String>,
+impl AsRef<str> for PlatformType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct ListTagsForResourceRequest {
-    /// <p>The Amazon Resource Name (ARN) of the resource.</p>
-    #[serde(rename = "ResourceArn")]
-    pub resource_arn: String,
+impl fmt::Display for PlatformType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct ListTagsForResourceResponse {
-    /// <p>The information about the tags.</p>
-    #[serde(rename = "Tags")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub tags: Option<::std::collections::HashMap<String, String>>,
+impl<'a> From<&'a str> for PlatformType {
+    fn from(value: &'a str) -> PlatformType {
+        match value {
+            "WINDOWS" => PlatformType::Windows,
+            "WINDOWS_SERVER_2016" => PlatformType::WindowsServer2016,
+            "WINDOWS_SERVER_2019" => PlatformType::WindowsServer2019,
+            "WINDOWS_SERVER_2022" => PlatformType::WindowsServer2022,
+            "AMAZON_LINUX2" => PlatformType::AmazonLinux2,
+            _ => PlatformType::Unknown(value.to_owned()),
+        }
+    }
 }
 
-/// <p>Describes the network details of the fleet or image builder instance.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct NetworkAccessConfiguration {
-    /// <p>The resource identifier of the elastic network interface that is attached to instances in your VPC. All network interfaces have the eni-xxxxxxxx resource identifier.</p>
-    #[serde(rename = "EniId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub eni_id: Option<String>,
-    /// <p>The private IP address of the elastic network interface that is attached to instances in your VPC.</p>
-    #[serde(rename = "EniPrivateIpAddress")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub eni_private_ip_address: Option<String>,
+impl From<String> for PlatformType {
+    fn from(value: String) -> PlatformType {
+        match value.as_str() {
+            "WINDOWS" => PlatformType::Windows,
+            "WINDOWS_SERVER_2016" => PlatformType::WindowsServer2016,
+            "WINDOWS_SERVER_2019" => PlatformType::WindowsServer2019,
+            "WINDOWS_SERVER_2022" => PlatformType::WindowsServer2022,
+            "AMAZON_LINUX2" => PlatformType::AmazonLinux2,
+            _ => PlatformType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PlatformType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PlatformType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PlatformType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PlatformType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for PlatformType {
+    fn deserialize<D>(deserializer: D) -> Result<PlatformType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes a resource error.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1432,15 +3142,20 @@ pub struct ResourceError {
     /// <p>The error code.</p>
     #[serde(rename = "ErrorCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub error_code: Option<String>,
+    pub error_code: Option<FleetErrorCode>,
     /// <p>The error message.</p>
     #[serde(rename = "ErrorMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error_message: Option<String>,
     /// <p>The time the error occurred.</p>
     #[serde(rename = "ErrorTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub error_timestamp: Option<f64>,
+    pub error_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>Describes the credentials for the service account used by the fleet or image builder to connect to the directory.</p>
@@ -1461,11 +3176,11 @@ pub struct Session {
     /// <p>The authentication method. The user is authenticated using a streaming URL (<code>API</code>) or SAML 2.0 federation (<code>SAML</code>).</p>
     #[serde(rename = "AuthenticationType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub authentication_type: Option<String>,
+    pub authentication_type: Option<AuthenticationType>,
     /// <p>Specifies whether a user is connected to the streaming session.</p>
     #[serde(rename = "ConnectionState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub connection_state: Option<String>,
+    pub connection_state: Option<SessionConnectionState>,
     /// <p>The name of the fleet for the streaming session.</p>
     #[serde(rename = "FleetName")]
     pub fleet_name: String,
@@ -1474,8 +3189,13 @@ pub struct Session {
     pub id: String,
     /// <p>The time when the streaming session is set to expire. This time is based on the <code>MaxUserDurationinSeconds</code> value, which determines the maximum length of time that a streaming session can run. A streaming session might end earlier than the time specified in <code>SessionMaxExpirationTime</code>, when the <code>DisconnectTimeOutInSeconds</code> elapses or the user chooses to end his or her session. If the <code>DisconnectTimeOutInSeconds</code> elapses, or the user chooses to end his or her session, the streaming instance is terminated and the streaming session ends.</p>
     #[serde(rename = "MaxExpirationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub max_expiration_time: Option<f64>,
+    pub max_expiration_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The network details for the streaming session.</p>
     #[serde(rename = "NetworkAccessConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1485,16 +3205,213 @@ pub struct Session {
     pub stack_name: String,
     /// <p>The time when a streaming instance is dedicated for the user.</p>
     #[serde(rename = "StartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The current state of the streaming session.</p>
     #[serde(rename = "State")]
-    pub state: String,
+    pub state: SessionState,
     /// <p>The identifier of the user for whom the session was created.</p>
     #[serde(rename = "UserId")]
     pub user_id: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SessionConnectionState {
+    /// `CONNECTED`
+    Connected,
+    /// `NOT_CONNECTED`
+    NotConnected,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SessionConnectionState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SessionConnectionState::Connected => "CONNECTED",
+            SessionConnectionState::NotConnected => "NOT_CONNECTED",
+            SessionConnectionState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SessionConnectionState {
+    fn default() -> SessionConnectionState {
+        SessionConnectionState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SessionConnectionState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SessionConnectionState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SessionConnectionState {
+    fn from(value: &'a str) -> SessionConnectionState {
+        match value {
+            "CONNECTED" => SessionConnectionState::Connected,
+            "NOT_CONNECTED" => SessionConnectionState::NotConnected,
+            _ => SessionConnectionState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SessionConnectionState {
+    fn from(value: String) -> SessionConnectionState {
+        match value.as_str() {
+            "CONNECTED" => SessionConnectionState::Connected,
+            "NOT_CONNECTED" => SessionConnectionState::NotConnected,
+            _ => SessionConnectionState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SessionConnectionState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SessionConnectionState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SessionConnectionState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SessionConnectionState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SessionConnectionState {
+    fn deserialize<D>(deserializer: D) -> Result<SessionConnectionState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Possible values for the state of a streaming session.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SessionState {
+    /// `ACTIVE`
+    Active,
+    /// `PENDING`
+    Pending,
+    /// `EXPIRED`
+    Expired,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SessionState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SessionState::Active => "ACTIVE",
+            SessionState::Pending => "PENDING",
+            SessionState::Expired => "EXPIRED",
+            SessionState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SessionState {
+    fn default() -> SessionState {
+        SessionState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SessionState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SessionState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SessionState {
+    fn from(value: &'a str) -> SessionState {
+        match value {
+            "ACTIVE" => SessionState::Active,
+            "PENDING" => SessionState::Pending,
+            "EXPIRED" => SessionState::Expired,
+            _ => SessionState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SessionState {
+    fn from(value: String) -> SessionState {
+        match value.as_str() {
+            "ACTIVE" => SessionState::Active,
+            "PENDING" => SessionState::Pending,
+            "EXPIRED" => SessionState::Expired,
+            _ => SessionState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SessionState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SessionState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SessionState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SessionState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SessionState {
+    fn deserialize<D>(deserializer: D) -> Result<SessionState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes the permissions that are available to the specified AWS account for a shared image.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1525,8 +3442,13 @@ pub struct Stack {
     pub arn: Option<String>,
     /// <p>The time the stack was created.</p>
     #[serde(rename = "CreatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time: Option<f64>,
+    pub created_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The description to display.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1564,6 +3486,149 @@ pub struct Stack {
     pub user_settings: Option<Vec<UserSetting>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StackAttribute {
+    /// `STORAGE_CONNECTORS`
+    StorageConnectors,
+    /// `STORAGE_CONNECTOR_HOMEFOLDERS`
+    StorageConnectorHomefolders,
+    /// `STORAGE_CONNECTOR_GOOGLE_DRIVE`
+    StorageConnectorGoogleDrive,
+    /// `STORAGE_CONNECTOR_ONE_DRIVE`
+    StorageConnectorOneDrive,
+    /// `REDIRECT_URL`
+    RedirectUrl,
+    /// `FEEDBACK_URL`
+    FeedbackUrl,
+    /// `THEME_NAME`
+    ThemeName,
+    /// `USER_SETTINGS`
+    UserSettings,
+    /// `EMBED_HOST_DOMAINS`
+    EmbedHostDomains,
+    /// `IAM_ROLE_ARN`
+    IamRoleArn,
+    /// `ACCESS_ENDPOINTS`
+    AccessEndpoints,
+    /// `STREAMING_EXPERIENCE_SETTINGS`
+    StreamingExperienceSettings,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl StackAttribute {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StackAttribute::StorageConnectors => "STORAGE_CONNECTORS",
+            StackAttribute::StorageConnectorHomefolders => "STORAGE_CONNECTOR_HOMEFOLDERS",
+            StackAttribute::StorageConnectorGoogleDrive => "STORAGE_CONNECTOR_GOOGLE_DRIVE",
+            StackAttribute::StorageConnectorOneDrive => "STORAGE_CONNECTOR_ONE_DRIVE",
+            StackAttribute::RedirectUrl => "REDIRECT_URL",
+            StackAttribute::FeedbackUrl => "FEEDBACK_URL",
+            StackAttribute::ThemeName => "THEME_NAME",
+            StackAttribute::UserSettings => "USER_SETTINGS",
+            StackAttribute::EmbedHostDomains => "EMBED_HOST_DOMAINS",
+            StackAttribute::IamRoleArn => "IAM_ROLE_ARN",
+            StackAttribute::AccessEndpoints => "ACCESS_ENDPOINTS",
+            StackAttribute::StreamingExperienceSettings => "STREAMING_EXPERIENCE_SETTINGS",
+            StackAttribute::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for StackAttribute {
+    fn default() -> StackAttribute {
+        StackAttribute::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for StackAttribute {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for StackAttribute {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for StackAttribute {
+    fn from(value: &'a str) -> StackAttribute {
+        match value {
+            "STORAGE_CONNECTORS" => StackAttribute::StorageConnectors,
+            "STORAGE_CONNECTOR_HOMEFOLDERS" => StackAttribute::StorageConnectorHomefolders,
+            "STORAGE_CONNECTOR_GOOGLE_DRIVE" => StackAttribute::StorageConnectorGoogleDrive,
+            "STORAGE_CONNECTOR_ONE_DRIVE" => StackAttribute::StorageConnectorOneDrive,
+            "REDIRECT_URL" => StackAttribute::RedirectUrl,
+            "FEEDBACK_URL" => StackAttribute::FeedbackUrl,
+            "THEME_NAME" => StackAttribute::ThemeName,
+            "USER_SETTINGS" => StackAttribute::UserSettings,
+            "EMBED_HOST_DOMAINS" => StackAttribute::EmbedHostDomains,
+            "IAM_ROLE_ARN" => StackAttribute::IamRoleArn,
+            "ACCESS_ENDPOINTS" => StackAttribute::AccessEndpoints,
+            "STREAMING_EXPERIENCE_SETTINGS" => StackAttribute::StreamingExperienceSettings,
+            _ => StackAttribute::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StackAttribute {
+    fn from(value: String) -> StackAttribute {
+        match value.as_str() {
+            "STORAGE_CONNECTORS" => StackAttribute::StorageConnectors,
+            "STORAGE_CONNECTOR_HOMEFOLDERS" => StackAttribute::StorageConnectorHomefolders,
+            "STORAGE_CONNECTOR_GOOGLE_DRIVE" => StackAttribute::StorageConnectorGoogleDrive,
+            "STORAGE_CONNECTOR_ONE_DRIVE" => StackAttribute::StorageConnectorOneDrive,
+            "REDIRECT_URL" => StackAttribute::RedirectUrl,
+            "FEEDBACK_URL" => StackAttribute::FeedbackUrl,
+            "THEME_NAME" => StackAttribute::ThemeName,
+            "USER_SETTINGS" => StackAttribute::UserSettings,
+            "EMBED_HOST_DOMAINS" => StackAttribute::EmbedHostDomains,
+            "IAM_ROLE_ARN" => StackAttribute::IamRoleArn,
+            "ACCESS_ENDPOINTS" => StackAttribute::AccessEndpoints,
+            "STREAMING_EXPERIENCE_SETTINGS" => StackAttribute::StreamingExperienceSettings,
+            _ => StackAttribute::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for StackAttribute {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<StackAttribute, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StackAttribute {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for StackAttribute {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StackAttribute {
+    fn deserialize<D>(deserializer: D) -> Result<StackAttribute, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes a stack error.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1571,13 +3636,106 @@ pub struct StackError {
     /// <p>The error code.</p>
     #[serde(rename = "ErrorCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub error_code: Option<String>,
+    pub error_code: Option<StackErrorCode>,
     /// <p>The error message.</p>
     #[serde(rename = "ErrorMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error_message: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StackErrorCode {
+    /// `STORAGE_CONNECTOR_ERROR`
+    StorageConnectorError,
+    /// `INTERNAL_SERVICE_ERROR`
+    InternalServiceError,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl StackErrorCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StackErrorCode::StorageConnectorError => "STORAGE_CONNECTOR_ERROR",
+            StackErrorCode::InternalServiceError => "INTERNAL_SERVICE_ERROR",
+            StackErrorCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for StackErrorCode {
+    fn default() -> StackErrorCode {
+        StackErrorCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for StackErrorCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for StackErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for StackErrorCode {
+    fn from(value: &'a str) -> StackErrorCode {
+        match value {
+            "STORAGE_CONNECTOR_ERROR" => StackErrorCode::StorageConnectorError,
+            "INTERNAL_SERVICE_ERROR" => StackErrorCode::InternalServiceError,
+            _ => StackErrorCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StackErrorCode {
+    fn from(value: String) -> StackErrorCode {
+        match value.as_str() {
+            "STORAGE_CONNECTOR_ERROR" => StackErrorCode::StorageConnectorError,
+            "INTERNAL_SERVICE_ERROR" => StackErrorCode::InternalServiceError,
+            _ => StackErrorCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for StackErrorCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<StackErrorCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StackErrorCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for StackErrorCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StackErrorCode {
+    fn deserialize<D>(deserializer: D) -> Result<StackErrorCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct StartFleetRequest {
@@ -1645,7 +3803,7 @@ pub struct StopImageBuilderResult {
 pub struct StorageConnector {
     /// <p>The type of storage connector.</p>
     #[serde(rename = "ConnectorType")]
-    pub connector_type: String,
+    pub connector_type: StorageConnectorType,
     /// <p>The names of the domains for the account.</p>
     #[serde(rename = "Domains")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1656,6 +3814,105 @@ pub struct StorageConnector {
     pub resource_identifier: Option<String>,
 }
 
+/// <p>The type of storage connector.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StorageConnectorType {
+    /// `HOMEFOLDERS`
+    Homefolders,
+    /// `GOOGLE_DRIVE`
+    GoogleDrive,
+    /// `ONE_DRIVE`
+    OneDrive,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl StorageConnectorType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StorageConnectorType::Homefolders => "HOMEFOLDERS",
+            StorageConnectorType::GoogleDrive => "GOOGLE_DRIVE",
+            StorageConnectorType::OneDrive => "ONE_DRIVE",
+            StorageConnectorType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for StorageConnectorType {
+    fn default() -> StorageConnectorType {
+        StorageConnectorType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for StorageConnectorType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for StorageConnectorType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for StorageConnectorType {
+    fn from(value: &'a str) -> StorageConnectorType {
+        match value {
+            "HOMEFOLDERS" => StorageConnectorType::Homefolders,
+            "GOOGLE_DRIVE" => StorageConnectorType::GoogleDrive,
+            "ONE_DRIVE" => StorageConnectorType::OneDrive,
+            _ => StorageConnectorType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StorageConnectorType {
+    fn from(value: String) -> StorageConnectorType {
+        match value.as_str() {
+            "HOMEFOLDERS" => StorageConnectorType::Homefolders,
+            "GOOGLE_DRIVE" => StorageConnectorType::GoogleDrive,
+            "ONE_DRIVE" => StorageConnectorType::OneDrive,
+            _ => StorageConnectorType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for StorageConnectorType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<StorageConnectorType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StorageConnectorType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for StorageConnectorType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StorageConnectorType {
+    fn deserialize<D>(deserializer: D) -> Result<StorageConnectorType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct TagResourceRequest {
@@ -1717,7 +3974,7 @@ pub struct UpdateFleetRequest {
     /// <p>The fleet attributes to delete.</p>
     #[serde(rename = "AttributesToDelete")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub attributes_to_delete: Option<Vec<String>>,
+    pub attributes_to_delete: Option<Vec<FleetAttribute>>,
     /// <p>The desired capacity for the fleet.</p>
     #[serde(rename = "ComputeCapacity")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1817,7 +4074,7 @@ pub struct UpdateStackRequest {
     /// <p>The stack attributes to delete.</p>
     #[serde(rename = "AttributesToDelete")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub attributes_to_delete: Option<Vec<String>>,
+    pub attributes_to_delete: Option<Vec<StackAttribute>>,
     /// <p>The description to display.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1860,14 +4117,205 @@ pub struct UpdateStackResult {
     pub stack: Option<Stack>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum UsageReportExecutionErrorCode {
+    /// `RESOURCE_NOT_FOUND`
+    ResourceNotFound,
+    /// `ACCESS_DENIED`
+    AccessDenied,
+    /// `INTERNAL_SERVICE_ERROR`
+    InternalServiceError,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl UsageReportExecutionErrorCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            UsageReportExecutionErrorCode::ResourceNotFound => "RESOURCE_NOT_FOUND",
+            UsageReportExecutionErrorCode::AccessDenied => "ACCESS_DENIED",
+            UsageReportExecutionErrorCode::InternalServiceError => "INTERNAL_SERVICE_ERROR",
+            UsageReportExecutionErrorCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for UsageReportExecutionErrorCode {
+    fn default() -> UsageReportExecutionErrorCode {
+        UsageReportExecutionErrorCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for UsageReportExecutionErrorCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for UsageReportExecutionErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for UsageReportExecutionErrorCode {
+    fn from(value: &'a str) -> UsageReportExecutionErrorCode {
+        match value {
+            "RESOURCE_NOT_FOUND" => UsageReportExecutionErrorCode::ResourceNotFound,
+            "ACCESS_DENIED" => UsageReportExecutionErrorCode::AccessDenied,
+            "INTERNAL_SERVICE_ERROR" => UsageReportExecutionErrorCode::InternalServiceError,
+            _ => UsageReportExecutionErrorCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for UsageReportExecutionErrorCode {
+    fn from(value: String) -> UsageReportExecutionErrorCode {
+        match value.as_str() {
+            "RESOURCE_NOT_FOUND" => UsageReportExecutionErrorCode::ResourceNotFound,
+            "ACCESS_DENIED" => UsageReportExecutionErrorCode::AccessDenied,
+            "INTERNAL_SERVICE_ERROR" => UsageReportExecutionErrorCode::InternalServiceError,
+            _ => UsageReportExecutionErrorCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for UsageReportExecutionErrorCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<UsageReportExecutionErrorCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for UsageReportExecutionErrorCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for UsageReportExecutionErrorCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for UsageReportExecutionErrorCode {
+    fn deserialize<D>(deserializer: D) -> Result<UsageReportExecutionErrorCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum UsageReportSchedule {
+    /// `DAILY`
+    Daily,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl UsageReportSchedule {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            UsageReportSchedule::Daily => "DAILY",
+            UsageReportSchedule::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for UsageReportSchedule {
+    fn default() -> UsageReportSchedule {
+        UsageReportSchedule::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for UsageReportSchedule {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for UsageReportSchedule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for UsageReportSchedule {
+    fn from(value: &'a str) -> UsageReportSchedule {
+        match value {
+            "DAILY" => UsageReportSchedule::Daily,
+            _ => UsageReportSchedule::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for UsageReportSchedule {
+    fn from(value: String) -> UsageReportSchedule {
+        match value.as_str() {
+            "DAILY" => UsageReportSchedule::Daily,
+            _ => UsageReportSchedule::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for UsageReportSchedule {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<UsageReportSchedule, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for UsageReportSchedule {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for UsageReportSchedule {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for UsageReportSchedule {
+    fn deserialize<D>(deserializer: D) -> Result<UsageReportSchedule, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes information about the usage report subscription.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UsageReportSubscription {
     /// <p>The time when the last usage report was generated.</p>
     #[serde(rename = "LastGeneratedReportDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_generated_report_date: Option<f64>,
+    pub last_generated_report_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The Amazon S3 bucket where generated reports are stored.</p> <p>If you enabled on-instance session scripts and Amazon S3 logging for your session script configuration, AppStream 2.0 created an S3 bucket to store the script output. The bucket is unique to your account and Region. When you enable usage reporting in this case, AppStream 2.0 uses the same bucket to store your usage reports. If you haven't already enabled on-instance session scripts, when you enable usage reports, AppStream 2.0 creates a new S3 bucket.</p>
     #[serde(rename = "S3BucketName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1875,7 +4323,7 @@ pub struct UsageReportSubscription {
     /// <p>The schedule for generating usage reports.</p>
     #[serde(rename = "Schedule")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub schedule: Option<String>,
+    pub schedule: Option<UsageReportSchedule>,
     /// <p>The errors that were returned if usage reports couldn't be generated.</p>
     #[serde(rename = "SubscriptionErrors")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1892,11 +4340,16 @@ pub struct User {
     pub arn: Option<String>,
     /// <p>The authentication type for the user.</p>
     #[serde(rename = "AuthenticationType")]
-    pub authentication_type: String,
+    pub authentication_type: AuthenticationType,
     /// <p>The date and time the user was created in the user pool.</p>
     #[serde(rename = "CreatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time: Option<f64>,
+    pub created_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Specifies whether the user in the user pool is enabled.</p>
     #[serde(rename = "Enabled")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1924,10 +4377,10 @@ pub struct User {
 pub struct UserSetting {
     /// <p>The action that is enabled or disabled.</p>
     #[serde(rename = "Action")]
-    pub action: String,
+    pub action: Action,
     /// <p>Indicates whether the action is enabled or disabled.</p>
     #[serde(rename = "Permission")]
-    pub permission: String,
+    pub permission: Permission,
 }
 
 /// <p>Describes a user in the user pool and the associated stack.</p>
@@ -1935,7 +4388,7 @@ pub struct UserSetting {
 pub struct UserStackAssociation {
     /// <p>The authentication type for the user.</p>
     #[serde(rename = "AuthenticationType")]
-    pub authentication_type: String,
+    pub authentication_type: AuthenticationType,
     /// <p>Specifies whether a welcome email is sent to a user after the user is created in the user pool.</p>
     #[serde(rename = "SendEmailNotification")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1955,7 +4408,7 @@ pub struct UserStackAssociationError {
     /// <p>The error code for the error that is returned when a user can’t be associated with or disassociated from a stack.</p>
     #[serde(rename = "ErrorCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub error_code: Option<String>,
+    pub error_code: Option<UserStackAssociationErrorCode>,
     /// <p>The error message for the error that is returned when a user can’t be associated with or disassociated from a stack.</p>
     #[serde(rename = "ErrorMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1966,6 +4419,207 @@ pub struct UserStackAssociationError {
     pub user_stack_association: Option<UserStackAssociation>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum UserStackAssociationErrorCode {
+    /// `STACK_NOT_FOUND`
+    StackNotFound,
+    /// `USER_NAME_NOT_FOUND`
+    UserNameNotFound,
+    /// `DIRECTORY_NOT_FOUND`
+    DirectoryNotFound,
+    /// `INTERNAL_ERROR`
+    InternalError,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl UserStackAssociationErrorCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            UserStackAssociationErrorCode::StackNotFound => "STACK_NOT_FOUND",
+            UserStackAssociationErrorCode::UserNameNotFound => "USER_NAME_NOT_FOUND",
+            UserStackAssociationErrorCode::DirectoryNotFound => "DIRECTORY_NOT_FOUND",
+            UserStackAssociationErrorCode::InternalError => "INTERNAL_ERROR",
+            UserStackAssociationErrorCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for UserStackAssociationErrorCode {
+    fn default() -> UserStackAssociationErrorCode {
+        UserStackAssociationErrorCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for UserStackAssociationErrorCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for UserStackAssociationErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for UserStackAssociationErrorCode {
+    fn from(value: &'a str) -> UserStackAssociationErrorCode {
+        match value {
+            "STACK_NOT_FOUND" => UserStackAssociationErrorCode::StackNotFound,
+            "USER_NAME_NOT_FOUND" => UserStackAssociationErrorCode::UserNameNotFound,
+            "DIRECTORY_NOT_FOUND" => UserStackAssociationErrorCode::DirectoryNotFound,
+            "INTERNAL_ERROR" => UserStackAssociationErrorCode::InternalError,
+            _ => UserStackAssociationErrorCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for UserStackAssociationErrorCode {
+    fn from(value: String) -> UserStackAssociationErrorCode {
+        match value.as_str() {
+            "STACK_NOT_FOUND" => UserStackAssociationErrorCode::StackNotFound,
+            "USER_NAME_NOT_FOUND" => UserStackAssociationErrorCode::UserNameNotFound,
+            "DIRECTORY_NOT_FOUND" => UserStackAssociationErrorCode::DirectoryNotFound,
+            "INTERNAL_ERROR" => UserStackAssociationErrorCode::InternalError,
+            _ => UserStackAssociationErrorCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for UserStackAssociationErrorCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<UserStackAssociationErrorCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for UserStackAssociationErrorCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for UserStackAssociationErrorCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for UserStackAssociationErrorCode {
+    fn deserialize<D>(deserializer: D) -> Result<UserStackAssociationErrorCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum VisibilityType {
+    /// `PUBLIC`
+    Public,
+    /// `PRIVATE`
+    Private,
+    /// `SHARED`
+    Shared,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl VisibilityType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            VisibilityType::Public => "PUBLIC",
+            VisibilityType::Private => "PRIVATE",
+            VisibilityType::Shared => "SHARED",
+            VisibilityType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for VisibilityType {
+    fn default() -> VisibilityType {
+        VisibilityType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for VisibilityType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for VisibilityType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for VisibilityType {
+    fn from(value: &'a str) -> VisibilityType {
+        match value {
+            "PUBLIC" => VisibilityType::Public,
+            "PRIVATE" => VisibilityType::Private,
+            "SHARED" => VisibilityType::Shared,
+            _ => VisibilityType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for VisibilityType {
+    fn from(value: String) -> VisibilityType {
+        match value.as_str() {
+            "PUBLIC" => VisibilityType::Public,
+            "PRIVATE" => VisibilityType::Private,
+            "SHARED" => VisibilityType::Shared,
+            _ => VisibilityType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for VisibilityType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<VisibilityType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for VisibilityType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for VisibilityType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for VisibilityType {
+    fn deserialize<D>(deserializer: D) -> Result<VisibilityType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes VPC configuration information for fleets and image builders.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct VpcConfig {
@@ -4396,11 +7050,12 @@ pub struct AppStreamClient {
 impl AppStreamClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> AppStreamClient {
         AppStreamClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "AppStream"),
         }
     }
 
@@ -4432,6 +7087,7 @@ impl AppStream for AppStreamClient {
         input: AssociateFleetRequest,
     ) -> Result<AssociateFleetResult, RusotoError<AssociateFleetError>> {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("AssociateFleet");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "PhotonAdminProxyService.AssociateFleet");
@@ -4459,6 +7115,7 @@ impl AppStream for AppStreamClient {
         input: BatchAssociateUserStackRequest,
     ) -> Result<BatchAssociateUserStackResult, RusotoError<BatchAssociateUserStackError>> {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("BatchAssociateUserStack");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4491,6 +7148,7 @@ impl AppStream for AppStreamClient {
     ) -> Result<BatchDisassociateUserStackResult, RusotoError<BatchDisassociateUserStackError>>
     {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("BatchDisassociateUserStack");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4522,6 +7180,7 @@ impl AppStream for AppStreamClient {
         input: CopyImageRequest,
     ) -> Result<CopyImageResponse, RusotoError<CopyImageError>> {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("CopyImage");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "PhotonAdminProxyService.CopyImage");
@@ -4549,6 +7208,7 @@ impl AppStream for AppStreamClient {
         input: CreateDirectoryConfigRequest,
     ) -> Result<CreateDirectoryConfigResult, RusotoError<CreateDirectoryConfigError>> {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("CreateDirectoryConfig");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4580,6 +7240,7 @@ impl AppStream for AppStreamClient {
         input: CreateFleetRequest,
     ) -> Result<CreateFleetResult, RusotoError<CreateFleetError>> {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("CreateFleet");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "PhotonAdminProxyService.CreateFleet");
@@ -4607,6 +7268,7 @@ impl AppStream for AppStreamClient {
         input: CreateImageBuilderRequest,
     ) -> Result<CreateImageBuilderResult, RusotoError<CreateImageBuilderError>> {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("CreateImageBuilder");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "PhotonAdminProxyService.CreateImageBuilder");
@@ -4638,6 +7300,7 @@ impl AppStream for AppStreamClient {
         RusotoError<CreateImageBuilderStreamingURLError>,
     > {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("CreateImageBuilderStreamingURL");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4669,6 +7332,7 @@ impl AppStream for AppStreamClient {
         input: CreateStackRequest,
     ) -> Result<CreateStackResult, RusotoError<CreateStackError>> {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("CreateStack");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "PhotonAdminProxyService.CreateStack");
@@ -4696,6 +7360,7 @@ impl AppStream for AppStreamClient {
         input: CreateStreamingURLRequest,
     ) -> Result<CreateStreamingURLResult, RusotoError<CreateStreamingURLError>> {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("CreateStreamingURL");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "PhotonAdminProxyService.CreateStreamingURL");
@@ -4724,6 +7389,7 @@ impl AppStream for AppStreamClient {
     ) -> Result<CreateUsageReportSubscriptionResult, RusotoError<CreateUsageReportSubscriptionError>>
     {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("CreateUsageReportSubscription");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4754,6 +7420,7 @@ impl AppStream for AppStreamClient {
         input: CreateUserRequest,
     ) -> Result<CreateUserResult, RusotoError<CreateUserError>> {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("CreateUser");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "PhotonAdminProxyService.CreateUser");
@@ -4781,6 +7448,7 @@ impl AppStream for AppStreamClient {
         input: DeleteDirectoryConfigRequest,
     ) -> Result<DeleteDirectoryConfigResult, RusotoError<DeleteDirectoryConfigError>> {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("DeleteDirectoryConfig");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4812,6 +7480,7 @@ impl AppStream for AppStreamClient {
         input: DeleteFleetRequest,
     ) -> Result<DeleteFleetResult, RusotoError<DeleteFleetError>> {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("DeleteFleet");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "PhotonAdminProxyService.DeleteFleet");
@@ -4839,6 +7508,7 @@ impl AppStream for AppStreamClient {
         input: DeleteImageRequest,
     ) -> Result<DeleteImageResult, RusotoError<DeleteImageError>> {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("DeleteImage");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "PhotonAdminProxyService.DeleteImage");
@@ -4866,6 +7536,7 @@ impl AppStream for AppStreamClient {
         input: DeleteImageBuilderRequest,
     ) -> Result<DeleteImageBuilderResult, RusotoError<DeleteImageBuilderError>> {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("DeleteImageBuilder");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "PhotonAdminProxyService.DeleteImageBuilder");
@@ -4894,6 +7565,7 @@ impl AppStream for AppStreamClient {
         input: DeleteImagePermissionsRequest,
     ) -> Result<DeleteImagePermissionsResult, RusotoError<DeleteImagePermissionsError>> {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("DeleteImagePermissions");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4925,6 +7597,7 @@ impl AppStream for AppStreamClient {
         input: DeleteStackRequest,
     ) -> Result<DeleteStackResult, RusotoError<DeleteStackError>> {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("DeleteStack");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "PhotonAdminProxyService.DeleteStack");
@@ -4952,6 +7625,7 @@ impl AppStream for AppStreamClient {
     ) -> Result<DeleteUsageReportSubscriptionResult, RusotoError<DeleteUsageReportSubscriptionError>>
     {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("DeleteUsageReportSubscription");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4982,6 +7656,7 @@ impl AppStream for AppStreamClient {
         input: DeleteUserRequest,
     ) -> Result<DeleteUserResult, RusotoError<DeleteUserError>> {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("DeleteUser");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "PhotonAdminProxyService.DeleteUser");
@@ -5009,6 +7684,7 @@ impl AppStream for AppStreamClient {
         input: DescribeDirectoryConfigsRequest,
     ) -> Result<DescribeDirectoryConfigsResult, RusotoError<DescribeDirectoryConfigsError>> {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("DescribeDirectoryConfigs");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5040,6 +7716,7 @@ impl AppStream for AppStreamClient {
         input: DescribeFleetsRequest,
     ) -> Result<DescribeFleetsResult, RusotoError<DescribeFleetsError>> {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("DescribeFleets");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "PhotonAdminProxyService.DescribeFleets");
@@ -5067,6 +7744,7 @@ impl AppStream for AppStreamClient {
         input: DescribeImageBuildersRequest,
     ) -> Result<DescribeImageBuildersResult, RusotoError<DescribeImageBuildersError>> {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("DescribeImageBuilders");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5098,6 +7776,7 @@ impl AppStream for AppStreamClient {
         input: DescribeImagePermissionsRequest,
     ) -> Result<DescribeImagePermissionsResult, RusotoError<DescribeImagePermissionsError>> {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("DescribeImagePermissions");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5129,6 +7808,7 @@ impl AppStream for AppStreamClient {
         input: DescribeImagesRequest,
     ) -> Result<DescribeImagesResult, RusotoError<DescribeImagesError>> {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("DescribeImages");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "PhotonAdminProxyService.DescribeImages");
@@ -5156,6 +7836,7 @@ impl AppStream for AppStreamClient {
         input: DescribeSessionsRequest,
     ) -> Result<DescribeSessionsResult, RusotoError<DescribeSessionsError>> {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("DescribeSessions");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "PhotonAdminProxyService.DescribeSessions");
@@ -5183,6 +7864,7 @@ impl AppStream for AppStreamClient {
         input: DescribeStacksRequest,
     ) -> Result<DescribeStacksResult, RusotoError<DescribeStacksError>> {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("DescribeStacks");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "PhotonAdminProxyService.DescribeStacks");
@@ -5213,6 +7895,7 @@ impl AppStream for AppStreamClient {
         RusotoError<DescribeUsageReportSubscriptionsError>,
     > {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("DescribeUsageReportSubscriptions");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5247,6 +7930,7 @@ impl AppStream for AppStreamClient {
     ) -> Result<DescribeUserStackAssociationsResult, RusotoError<DescribeUserStackAssociationsError>>
     {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("DescribeUserStackAssociations");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5278,6 +7962,7 @@ impl AppStream for AppStreamClient {
         input: DescribeUsersRequest,
     ) -> Result<DescribeUsersResult, RusotoError<DescribeUsersError>> {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("DescribeUsers");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "PhotonAdminProxyService.DescribeUsers");
@@ -5305,6 +7990,7 @@ impl AppStream for AppStreamClient {
         input: DisableUserRequest,
     ) -> Result<DisableUserResult, RusotoError<DisableUserError>> {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("DisableUser");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "PhotonAdminProxyService.DisableUser");
@@ -5332,6 +8018,7 @@ impl AppStream for AppStreamClient {
         input: DisassociateFleetRequest,
     ) -> Result<DisassociateFleetResult, RusotoError<DisassociateFleetError>> {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("DisassociateFleet");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "PhotonAdminProxyService.DisassociateFleet");
@@ -5359,6 +8046,7 @@ impl AppStream for AppStreamClient {
         input: EnableUserRequest,
     ) -> Result<EnableUserResult, RusotoError<EnableUserError>> {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("EnableUser");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "PhotonAdminProxyService.EnableUser");
@@ -5386,6 +8074,7 @@ impl AppStream for AppStreamClient {
         input: ExpireSessionRequest,
     ) -> Result<ExpireSessionResult, RusotoError<ExpireSessionError>> {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("ExpireSession");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "PhotonAdminProxyService.ExpireSession");
@@ -5413,6 +8102,7 @@ impl AppStream for AppStreamClient {
         input: ListAssociatedFleetsRequest,
     ) -> Result<ListAssociatedFleetsResult, RusotoError<ListAssociatedFleetsError>> {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("ListAssociatedFleets");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5444,6 +8134,7 @@ impl AppStream for AppStreamClient {
         input: ListAssociatedStacksRequest,
     ) -> Result<ListAssociatedStacksResult, RusotoError<ListAssociatedStacksError>> {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("ListAssociatedStacks");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5475,6 +8166,7 @@ impl AppStream for AppStreamClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("ListTagsForResource");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5506,6 +8198,7 @@ impl AppStream for AppStreamClient {
         input: StartFleetRequest,
     ) -> Result<StartFleetResult, RusotoError<StartFleetError>> {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("StartFleet");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "PhotonAdminProxyService.StartFleet");
@@ -5533,6 +8226,7 @@ impl AppStream for AppStreamClient {
         input: StartImageBuilderRequest,
     ) -> Result<StartImageBuilderResult, RusotoError<StartImageBuilderError>> {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("StartImageBuilder");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "PhotonAdminProxyService.StartImageBuilder");
@@ -5560,6 +8254,7 @@ impl AppStream for AppStreamClient {
         input: StopFleetRequest,
     ) -> Result<StopFleetResult, RusotoError<StopFleetError>> {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("StopFleet");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "PhotonAdminProxyService.StopFleet");
@@ -5587,6 +8282,7 @@ impl AppStream for AppStreamClient {
         input: StopImageBuilderRequest,
     ) -> Result<StopImageBuilderResult, RusotoError<StopImageBuilderError>> {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("StopImageBuilder");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "PhotonAdminProxyService.StopImageBuilder");
@@ -5614,6 +8310,7 @@ impl AppStream for AppStreamClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("TagResource");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "PhotonAdminProxyService.TagResource");
@@ -5641,6 +8338,7 @@ impl AppStream for AppStreamClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("UntagResource");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "PhotonAdminProxyService.UntagResource");
@@ -5668,6 +8366,7 @@ impl AppStream for AppStreamClient {
         input: UpdateDirectoryConfigRequest,
     ) -> Result<UpdateDirectoryConfigResult, RusotoError<UpdateDirectoryConfigError>> {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("UpdateDirectoryConfig");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5699,6 +8398,7 @@ impl AppStream for AppStreamClient {
         input: UpdateFleetRequest,
     ) -> Result<UpdateFleetResult, RusotoError<UpdateFleetError>> {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("UpdateFleet");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "PhotonAdminProxyService.UpdateFleet");
@@ -5726,6 +8426,7 @@ impl AppStream for AppStreamClient {
         input: UpdateImagePermissionsRequest,
     ) -> Result<UpdateImagePermissionsResult, RusotoError<UpdateImagePermissionsError>> {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("UpdateImagePermissions");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5757,6 +8458,7 @@ impl AppStream for AppStreamClient {
         input: UpdateStackRequest,
     ) -> Result<UpdateStackResult, RusotoError<UpdateStackError>> {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("UpdateStack");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "PhotonAdminProxyService.UpdateStack");
@@ -5778,3 +8480,450 @@ impl AppStream for AppStreamClient {
         }
     }
 }
+impl AppStreamClient {
+    /// Auto-paginating version of `describe_directory_configs`, streaming every page of results.
+    pub fn describe_directory_configs_pages(
+        &self,
+        input: DescribeDirectoryConfigsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeDirectoryConfigsResult,
+        DescribeDirectoryConfigsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AppStreamClient, input: DescribeDirectoryConfigsRequest| async move {
+                client.describe_directory_configs(input).await
+            },
+            |input: &DescribeDirectoryConfigsRequest, output: &DescribeDirectoryConfigsResult| {
+                Some(DescribeDirectoryConfigsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_directory_configs`, streaming the `DirectoryConfigs` of every page of results.
+    pub fn describe_directory_configs_items(
+        &self,
+        input: DescribeDirectoryConfigsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DirectoryConfig, DescribeDirectoryConfigsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_directory_configs_pages(input),
+            |output: DescribeDirectoryConfigsResult| output.directory_configs.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_fleets`, streaming every page of results.
+    pub fn describe_fleets_pages(
+        &self,
+        input: DescribeFleetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeFleetsResult, DescribeFleetsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AppStreamClient, input: DescribeFleetsRequest| async move {
+                client.describe_fleets(input).await
+            },
+            |input: &DescribeFleetsRequest, output: &DescribeFleetsResult| {
+                Some(DescribeFleetsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_fleets`, streaming the `Fleets` of every page of results.
+    pub fn describe_fleets_items(
+        &self,
+        input: DescribeFleetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Fleet, DescribeFleetsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_fleets_pages(input),
+            |output: DescribeFleetsResult| output.fleets.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_image_builders`, streaming every page of results.
+    pub fn describe_image_builders_pages(
+        &self,
+        input: DescribeImageBuildersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeImageBuildersResult,
+        DescribeImageBuildersError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AppStreamClient, input: DescribeImageBuildersRequest| async move {
+                client.describe_image_builders(input).await
+            },
+            |input: &DescribeImageBuildersRequest, output: &DescribeImageBuildersResult| {
+                Some(DescribeImageBuildersRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_image_builders`, streaming the `ImageBuilders` of every page of results.
+    pub fn describe_image_builders_items(
+        &self,
+        input: DescribeImageBuildersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ImageBuilder, DescribeImageBuildersError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_image_builders_pages(input),
+            |output: DescribeImageBuildersResult| output.image_builders.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_images`, streaming every page of results.
+    pub fn describe_images_pages(
+        &self,
+        input: DescribeImagesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeImagesResult, DescribeImagesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AppStreamClient, input: DescribeImagesRequest| async move {
+                client.describe_images(input).await
+            },
+            |input: &DescribeImagesRequest, output: &DescribeImagesResult| {
+                Some(DescribeImagesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_images`, streaming the `Images` of every page of results.
+    pub fn describe_images_items(
+        &self,
+        input: DescribeImagesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Image, DescribeImagesError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_images_pages(input),
+            |output: DescribeImagesResult| output.images.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_sessions`, streaming every page of results.
+    pub fn describe_sessions_pages(
+        &self,
+        input: DescribeSessionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeSessionsResult, DescribeSessionsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AppStreamClient, input: DescribeSessionsRequest| async move {
+                client.describe_sessions(input).await
+            },
+            |input: &DescribeSessionsRequest, output: &DescribeSessionsResult| {
+                Some(DescribeSessionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_sessions`, streaming the `Sessions` of every page of results.
+    pub fn describe_sessions_items(
+        &self,
+        input: DescribeSessionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Session, DescribeSessionsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_sessions_pages(input),
+            |output: DescribeSessionsResult| output.sessions.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_stacks`, streaming every page of results.
+    pub fn describe_stacks_pages(
+        &self,
+        input: DescribeStacksRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeStacksResult, DescribeStacksError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AppStreamClient, input: DescribeStacksRequest| async move {
+                client.describe_stacks(input).await
+            },
+            |input: &DescribeStacksRequest, output: &DescribeStacksResult| {
+                Some(DescribeStacksRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_stacks`, streaming the `Stacks` of every page of results.
+    pub fn describe_stacks_items(
+        &self,
+        input: DescribeStacksRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Stack, DescribeStacksError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_stacks_pages(input),
+            |output: DescribeStacksResult| output.stacks.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_user_stack_associations`, streaming every page of results.
+    pub fn describe_user_stack_associations_pages(
+        &self,
+        input: DescribeUserStackAssociationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeUserStackAssociationsResult,
+        DescribeUserStackAssociationsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AppStreamClient, input: DescribeUserStackAssociationsRequest| async move {
+                client.describe_user_stack_associations(input).await
+            },
+            |input: &DescribeUserStackAssociationsRequest,
+             output: &DescribeUserStackAssociationsResult| {
+                Some(DescribeUserStackAssociationsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_user_stack_associations`, streaming the `UserStackAssociations` of every page of results.
+    pub fn describe_user_stack_associations_items(
+        &self,
+        input: DescribeUserStackAssociationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        UserStackAssociation,
+        DescribeUserStackAssociationsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_user_stack_associations_pages(input),
+            |output: DescribeUserStackAssociationsResult| {
+                output.user_stack_associations.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_users`, streaming every page of results.
+    pub fn describe_users_pages(
+        &self,
+        input: DescribeUsersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeUsersResult, DescribeUsersError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AppStreamClient, input: DescribeUsersRequest| async move {
+                client.describe_users(input).await
+            },
+            |input: &DescribeUsersRequest, output: &DescribeUsersResult| {
+                Some(DescribeUsersRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_users`, streaming the `Users` of every page of results.
+    pub fn describe_users_items(
+        &self,
+        input: DescribeUsersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<User, DescribeUsersError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_users_pages(input),
+            |output: DescribeUsersResult| output.users.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_associated_fleets`, streaming every page of results.
+    pub fn list_associated_fleets_pages(
+        &self,
+        input: ListAssociatedFleetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListAssociatedFleetsResult, ListAssociatedFleetsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AppStreamClient, input: ListAssociatedFleetsRequest| async move {
+                client.list_associated_fleets(input).await
+            },
+            |input: &ListAssociatedFleetsRequest, output: &ListAssociatedFleetsResult| {
+                Some(ListAssociatedFleetsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_associated_fleets`, streaming the `Names` of every page of results.
+    pub fn list_associated_fleets_items(
+        &self,
+        input: ListAssociatedFleetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListAssociatedFleetsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_associated_fleets_pages(input),
+            |output: ListAssociatedFleetsResult| output.names.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_associated_stacks`, streaming every page of results.
+    pub fn list_associated_stacks_pages(
+        &self,
+        input: ListAssociatedStacksRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListAssociatedStacksResult, ListAssociatedStacksError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AppStreamClient, input: ListAssociatedStacksRequest| async move {
+                client.list_associated_stacks(input).await
+            },
+            |input: &ListAssociatedStacksRequest, output: &ListAssociatedStacksResult| {
+                Some(ListAssociatedStacksRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_associated_stacks`, streaming the `Names` of every page of results.
+    pub fn list_associated_stacks_items(
+        &self,
+        input: ListAssociatedStacksRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListAssociatedStacksError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_associated_stacks_pages(input),
+            |output: ListAssociatedStacksResult| output.names.unwrap_or_default(),
+        )
+    }
+}
+impl AppStreamClient {
+    /// Waits until `describe_fleets` reports the `FleetStarted` state, polling every 30 seconds up to 40 times.
+    pub async fn wait_until_fleet_started(
+        &self,
+        input: DescribeFleetsRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeFleetsError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(30), 40);
+        self.wait_until_fleet_started_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_fleet_started`, polling as often and as many times as `config` says.
+    pub async fn wait_until_fleet_started_with_config(
+        &self,
+        input: DescribeFleetsRequest,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeFleetsError>> {
+        rusoto_core::waiter::wait(
+            "FleetStarted",
+            &config,
+            || self.describe_fleets(input.clone()),
+            |result: &Result<DescribeFleetsResult, RusotoError<DescribeFleetsError>>| match result {
+                Ok(output)
+                    if rusoto_core::waiter::all_match(
+                        output
+                            .fleets
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .map(|v| &v.state),
+                        |v| *v == FleetState::Running,
+                    ) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if output
+                        .fleets
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .map(|v| &v.state)
+                        .any(|v| *v == FleetState::Stopping) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .fleets
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .map(|v| &v.state)
+                        .any(|v| *v == FleetState::Stopped) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+
+    /// Waits until `describe_fleets` reports the `FleetStopped` state, polling every 30 seconds up to 40 times.
+    pub async fn wait_until_fleet_stopped(
+        &self,
+        input: DescribeFleetsRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeFleetsError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(30), 40);
+        self.wait_until_fleet_stopped_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_fleet_stopped`, polling as often and as many times as `config` says.
+    pub async fn wait_until_fleet_stopped_with_config(
+        &self,
+        input: DescribeFleetsRequest,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeFleetsError>> {
+        rusoto_core::waiter::wait(
+            "FleetStopped",
+            &config,
+            || self.describe_fleets(input.clone()),
+            |result: &Result<DescribeFleetsResult, RusotoError<DescribeFleetsError>>| match result {
+                Ok(output)
+                    if rusoto_core::waiter::all_match(
+                        output
+                            .fleets
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .map(|v| &v.state),
+                        |v| *v == FleetState::Stopped,
+                    ) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if output
+                        .fleets
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .map(|v| &v.state)
+                        .any(|v| *v == FleetState::Starting) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .fleets
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .map(|v| &v.state)
+                        .any(|v| *v == FleetState::Running) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+}
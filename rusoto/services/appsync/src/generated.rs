@@ -31,7 +31,7 @@ pub struct AdditionalAuthenticationProvider {
     /// <p>The authentication type: API key, AWS IAM, OIDC, or Amazon Cognito user pools.</p>
     #[serde(rename = "authenticationType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub authentication_type: Option<String>,
+    pub authentication_type: Option<AuthenticationType>,
     /// <p>The OpenID Connect configuration.</p>
     #[serde(rename = "openIDConnectConfig")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -48,7 +48,7 @@ pub struct ApiCache {
     /// <p><p>Caching behavior.</p> <ul> <li> <p> <b>FULL<em>REQUEST</em>CACHING</b>: All requests are fully cached.</p> </li> <li> <p> <b>PER<em>RESOLVER</em>CACHING</b>: Individual resovlers that you specify are cached.</p> </li> </ul></p>
     #[serde(rename = "apiCachingBehavior")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub api_caching_behavior: Option<String>,
+    pub api_caching_behavior: Option<ApiCachingBehavior>,
     /// <p>At rest encryption flag for cache. This setting cannot be updated after creation.</p>
     #[serde(rename = "atRestEncryptionEnabled")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -56,7 +56,7 @@ pub struct ApiCache {
     /// <p><p>The cache instance status.</p> <ul> <li> <p> <b>AVAILABLE</b>: The instance is available for use.</p> </li> <li> <p> <b>CREATING</b>: The instance is currently creating.</p> </li> <li> <p> <b>DELETING</b>: The instance is currently deleting.</p> </li> <li> <p> <b>MODIFYING</b>: The instance is currently modifying.</p> </li> <li> <p> <b>FAILED</b>: The instance has failed creation.</p> </li> </ul></p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ApiCacheStatus>,
     /// <p>Transit encryption flag when connecting to cache. This setting cannot be updated after creation.</p>
     #[serde(rename = "transitEncryptionEnabled")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -68,9 +68,368 @@ pub struct ApiCache {
     /// <p><p>The cache instance type.</p> <ul> <li> <p> <b>T2<em>SMALL</b>: A t2.small instance type.</p> </li> <li> <p> <b>T2</em>MEDIUM</b>: A t2.medium instance type.</p> </li> <li> <p> <b>R4<em>LARGE</b>: A r4.large instance type.</p> </li> <li> <p> <b>R4</em>XLARGE</b>: A r4.xlarge instance type.</p> </li> <li> <p> <b>R4<em>2XLARGE</b>: A r4.2xlarge instance type.</p> </li> <li> <p> <b>R4</em>4XLARGE</b>: A r4.4xlarge instance type.</p> </li> <li> <p> <b>R4_8XLARGE</b>: A r4.8xlarge instance type.</p> </li> </ul></p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<ApiCacheType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ApiCacheStatus {
+    /// `AVAILABLE`
+    Available,
+    /// `CREATING`
+    Creating,
+    /// `DELETING`
+    Deleting,
+    /// `MODIFYING`
+    Modifying,
+    /// `FAILED`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ApiCacheStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ApiCacheStatus::Available => "AVAILABLE",
+            ApiCacheStatus::Creating => "CREATING",
+            ApiCacheStatus::Deleting => "DELETING",
+            ApiCacheStatus::Modifying => "MODIFYING",
+            ApiCacheStatus::Failed => "FAILED",
+            ApiCacheStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ApiCacheStatus {
+    fn default() -> ApiCacheStatus {
+        ApiCacheStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ApiCacheStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ApiCacheStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ApiCacheStatus {
+    fn from(value: &'a str) -> ApiCacheStatus {
+        match value {
+            "AVAILABLE" => ApiCacheStatus::Available,
+            "CREATING" => ApiCacheStatus::Creating,
+            "DELETING" => ApiCacheStatus::Deleting,
+            "MODIFYING" => ApiCacheStatus::Modifying,
+            "FAILED" => ApiCacheStatus::Failed,
+            _ => ApiCacheStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ApiCacheStatus {
+    fn from(value: String) -> ApiCacheStatus {
+        match value.as_str() {
+            "AVAILABLE" => ApiCacheStatus::Available,
+            "CREATING" => ApiCacheStatus::Creating,
+            "DELETING" => ApiCacheStatus::Deleting,
+            "MODIFYING" => ApiCacheStatus::Modifying,
+            "FAILED" => ApiCacheStatus::Failed,
+            _ => ApiCacheStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ApiCacheStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ApiCacheStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ApiCacheStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ApiCacheStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ApiCacheStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ApiCacheStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ApiCacheType {
+    /// `T2_SMALL`
+    T2Small,
+    /// `T2_MEDIUM`
+    T2Medium,
+    /// `R4_LARGE`
+    R4Large,
+    /// `R4_XLARGE`
+    R4Xlarge,
+    /// `R4_2XLARGE`
+    R4_2xlarge,
+    /// `R4_4XLARGE`
+    R4_4xlarge,
+    /// `R4_8XLARGE`
+    R4_8xlarge,
+    /// `SMALL`
+    Small,
+    /// `MEDIUM`
+    Medium,
+    /// `LARGE`
+    Large,
+    /// `XLARGE`
+    Xlarge,
+    /// `LARGE_2X`
+    Large2x,
+    /// `LARGE_4X`
+    Large4x,
+    /// `LARGE_8X`
+    Large8x,
+    /// `LARGE_12X`
+    Large12x,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ApiCacheType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ApiCacheType::T2Small => "T2_SMALL",
+            ApiCacheType::T2Medium => "T2_MEDIUM",
+            ApiCacheType::R4Large => "R4_LARGE",
+            ApiCacheType::R4Xlarge => "R4_XLARGE",
+            ApiCacheType::R4_2xlarge => "R4_2XLARGE",
+            ApiCacheType::R4_4xlarge => "R4_4XLARGE",
+            ApiCacheType::R4_8xlarge => "R4_8XLARGE",
+            ApiCacheType::Small => "SMALL",
+            ApiCacheType::Medium => "MEDIUM",
+            ApiCacheType::Large => "LARGE",
+            ApiCacheType::Xlarge => "XLARGE",
+            ApiCacheType::Large2x => "LARGE_2X",
+            ApiCacheType::Large4x => "LARGE_4X",
+            ApiCacheType::Large8x => "LARGE_8X",
+            ApiCacheType::Large12x => "LARGE_12X",
+            ApiCacheType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ApiCacheType {
+    fn default() -> ApiCacheType {
+        ApiCacheType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ApiCacheType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ApiCacheType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ApiCacheType {
+    fn from(value: &'a str) -> ApiCacheType {
+        match value {
+            "T2_SMALL" => ApiCacheType::T2Small,
+            "T2_MEDIUM" => ApiCacheType::T2Medium,
+            "R4_LARGE" => ApiCacheType::R4Large,
+            "R4_XLARGE" => ApiCacheType::R4Xlarge,
+            "R4_2XLARGE" => ApiCacheType::R4_2xlarge,
+            "R4_4XLARGE" => ApiCacheType::R4_4xlarge,
+            "R4_8XLARGE" => ApiCacheType::R4_8xlarge,
+            "SMALL" => ApiCacheType::Small,
+            "MEDIUM" => ApiCacheType::Medium,
+            "LARGE" => ApiCacheType::Large,
+            "XLARGE" => ApiCacheType::Xlarge,
+            "LARGE_2X" => ApiCacheType::Large2x,
+            "LARGE_4X" => ApiCacheType::Large4x,
+            "LARGE_8X" => ApiCacheType::Large8x,
+            "LARGE_12X" => ApiCacheType::Large12x,
+            _ => ApiCacheType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ApiCacheType {
+    fn from(value: String) -> ApiCacheType {
+        match value.as_str() {
+            "T2_SMALL" => ApiCacheType::T2Small,
+            "T2_MEDIUM" => ApiCacheType::T2Medium,
+            "R4_LARGE" => ApiCacheType::R4Large,
+            "R4_XLARGE" => ApiCacheType::R4Xlarge,
+            "R4_2XLARGE" => ApiCacheType::R4_2xlarge,
+            "R4_4XLARGE" => ApiCacheType::R4_4xlarge,
+            "R4_8XLARGE" => ApiCacheType::R4_8xlarge,
+            "SMALL" => ApiCacheType::Small,
+            "MEDIUM" => ApiCacheType::Medium,
+            "LARGE" => ApiCacheType::Large,
+            "XLARGE" => ApiCacheType::Xlarge,
+            "LARGE_2X" => ApiCacheType::Large2x,
+            "LARGE_4X" => ApiCacheType::Large4x,
+            "LARGE_8X" => ApiCacheType::Large8x,
+            "LARGE_12X" => ApiCacheType::Large12x,
+            _ => ApiCacheType::Unknown(value),
+        }
+    }
 }
 
+impl ::std::str::FromStr for ApiCacheType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ApiCacheType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ApiCacheType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ApiCacheType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ApiCacheType {
+    fn deserialize<D>(deserializer: D) -> Result<ApiCacheType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ApiCachingBehavior {
+    /// `FULL_REQUEST_CACHING`
+    FullRequestCaching,
+    /// `PER_RESOLVER_CACHING`
+    PerResolverCaching,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ApiCachingBehavior {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ApiCachingBehavior::FullRequestCaching => "FULL_REQUEST_CACHING",
+            ApiCachingBehavior::PerResolverCaching => "PER_RESOLVER_CACHING",
+            ApiCachingBehavior::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ApiCachingBehavior {
+    fn default() -> ApiCachingBehavior {
+        ApiCachingBehavior::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ApiCachingBehavior {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ApiCachingBehavior {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ApiCachingBehavior {
+    fn from(value: &'a str) -> ApiCachingBehavior {
+        match value {
+            "FULL_REQUEST_CACHING" => ApiCachingBehavior::FullRequestCaching,
+            "PER_RESOLVER_CACHING" => ApiCachingBehavior::PerResolverCaching,
+            _ => ApiCachingBehavior::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ApiCachingBehavior {
+    fn from(value: String) -> ApiCachingBehavior {
+        match value.as_str() {
+            "FULL_REQUEST_CACHING" => ApiCachingBehavior::FullRequestCaching,
+            "PER_RESOLVER_CACHING" => ApiCachingBehavior::PerResolverCaching,
+            _ => ApiCachingBehavior::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ApiCachingBehavior {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ApiCachingBehavior, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ApiCachingBehavior {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ApiCachingBehavior {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ApiCachingBehavior {
+    fn deserialize<D>(deserializer: D) -> Result<ApiCachingBehavior, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p><p>Describes an API key.</p> <p>Customers invoke AWS AppSync GraphQL API operations with API keys as an identity mechanism. There are two key versions:</p> <p> <b>da1</b>: This version was introduced at launch in November 2017. These keys always expire after 7 days. Key expiration is managed by Amazon DynamoDB TTL. The keys ceased to be valid after February 21, 2018 and should not be used after that date.</p> <ul> <li> <p> <code>ListApiKeys</code> returns the expiration time in milliseconds.</p> </li> <li> <p> <code>CreateApiKey</code> returns the expiration time in milliseconds.</p> </li> <li> <p> <code>UpdateApiKey</code> is not available for this key version.</p> </li> <li> <p> <code>DeleteApiKey</code> deletes the item from the table.</p> </li> <li> <p>Expiration is stored in Amazon DynamoDB as milliseconds. This results in a bug where keys are not automatically deleted because DynamoDB expects the TTL to be stored in seconds. As a one-time action, we will delete these keys from the table after February 21, 2018.</p> </li> </ul> <p> <b>da2</b>: This version was introduced in February 2018 when AppSync added support to extend key expiration.</p> <ul> <li> <p> <code>ListApiKeys</code> returns the expiration time in seconds.</p> </li> <li> <p> <code>CreateApiKey</code> returns the expiration time in seconds and accepts a user-provided expiration time in seconds.</p> </li> <li> <p> <code>UpdateApiKey</code> returns the expiration time in seconds and accepts a user-provided expiration time in seconds. Key expiration can only be updated while the key has not expired.</p> </li> <li> <p> <code>DeleteApiKey</code> deletes the item from the table.</p> </li> <li> <p>Expiration is stored in Amazon DynamoDB as seconds.</p> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -89,18 +448,214 @@ pub struct ApiKey {
     pub id: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AuthenticationType {
+    /// `API_KEY`
+    ApiKey,
+    /// `AWS_IAM`
+    AwsIam,
+    /// `AMAZON_COGNITO_USER_POOLS`
+    AmazonCognitoUserPools,
+    /// `OPENID_CONNECT`
+    OpenidConnect,
+    /// `AWS_LAMBDA`
+    AwsLambda,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AuthenticationType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AuthenticationType::ApiKey => "API_KEY",
+            AuthenticationType::AwsIam => "AWS_IAM",
+            AuthenticationType::AmazonCognitoUserPools => "AMAZON_COGNITO_USER_POOLS",
+            AuthenticationType::OpenidConnect => "OPENID_CONNECT",
+            AuthenticationType::AwsLambda => "AWS_LAMBDA",
+            AuthenticationType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AuthenticationType {
+    fn default() -> AuthenticationType {
+        AuthenticationType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AuthenticationType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AuthenticationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AuthenticationType {
+    fn from(value: &'a str) -> AuthenticationType {
+        match value {
+            "API_KEY" => AuthenticationType::ApiKey,
+            "AWS_IAM" => AuthenticationType::AwsIam,
+            "AMAZON_COGNITO_USER_POOLS" => AuthenticationType::AmazonCognitoUserPools,
+            "OPENID_CONNECT" => AuthenticationType::OpenidConnect,
+            "AWS_LAMBDA" => AuthenticationType::AwsLambda,
+            _ => AuthenticationType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AuthenticationType {
+    fn from(value: String) -> AuthenticationType {
+        match value.as_str() {
+            "API_KEY" => AuthenticationType::ApiKey,
+            "AWS_IAM" => AuthenticationType::AwsIam,
+            "AMAZON_COGNITO_USER_POOLS" => AuthenticationType::AmazonCognitoUserPools,
+            "OPENID_CONNECT" => AuthenticationType::OpenidConnect,
+            "AWS_LAMBDA" => AuthenticationType::AwsLambda,
+            _ => AuthenticationType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AuthenticationType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AuthenticationType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AuthenticationType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AuthenticationType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AuthenticationType {
+    fn deserialize<D>(deserializer: D) -> Result<AuthenticationType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The authorization config in case the HTTP endpoint requires authorization.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AuthorizationConfig {
     /// <p><p>The authorization type required by the HTTP endpoint.</p> <ul> <li> <p> <b>AWS_IAM</b>: The authorization type is Sigv4.</p> </li> </ul></p>
     #[serde(rename = "authorizationType")]
-    pub authorization_type: String,
+    pub authorization_type: AuthorizationType,
     /// <p>The AWS IAM settings.</p>
     #[serde(rename = "awsIamConfig")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub aws_iam_config: Option<AwsIamConfig>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AuthorizationType {
+    /// `AWS_IAM`
+    AwsIam,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AuthorizationType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AuthorizationType::AwsIam => "AWS_IAM",
+            AuthorizationType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AuthorizationType {
+    fn default() -> AuthorizationType {
+        AuthorizationType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AuthorizationType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AuthorizationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AuthorizationType {
+    fn from(value: &'a str) -> AuthorizationType {
+        match value {
+            "AWS_IAM" => AuthorizationType::AwsIam,
+            _ => AuthorizationType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AuthorizationType {
+    fn from(value: String) -> AuthorizationType {
+        match value.as_str() {
+            "AWS_IAM" => AuthorizationType::AwsIam,
+            _ => AuthorizationType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AuthorizationType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AuthorizationType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AuthorizationType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AuthorizationType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AuthorizationType {
+    fn deserialize<D>(deserializer: D) -> Result<AuthorizationType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The AWS IAM configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AwsIamConfig {
@@ -114,6 +669,95 @@ pub struct AwsIamConfig {
     pub signing_service_name: Option<String>,
 }
 
+/// <p>Provides context for the cause of the bad request. The only supported value is <code>CODE_ERROR</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BadRequestReason {
+    /// `CODE_ERROR`
+    CodeError,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl BadRequestReason {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BadRequestReason::CodeError => "CODE_ERROR",
+            BadRequestReason::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for BadRequestReason {
+    fn default() -> BadRequestReason {
+        BadRequestReason::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for BadRequestReason {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for BadRequestReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for BadRequestReason {
+    fn from(value: &'a str) -> BadRequestReason {
+        match value {
+            "CODE_ERROR" => BadRequestReason::CodeError,
+            _ => BadRequestReason::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BadRequestReason {
+    fn from(value: String) -> BadRequestReason {
+        match value.as_str() {
+            "CODE_ERROR" => BadRequestReason::CodeError,
+            _ => BadRequestReason::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for BadRequestReason {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<BadRequestReason, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for BadRequestReason {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for BadRequestReason {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BadRequestReason {
+    fn deserialize<D>(deserializer: D) -> Result<BadRequestReason, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The caching configuration for a resolver that has caching enabled.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CachingConfig {
@@ -142,13 +786,209 @@ pub struct CognitoUserPoolConfig {
     pub user_pool_id: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ConflictDetectionType {
+    /// `VERSION`
+    Version,
+    /// `NONE`
+    None,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ConflictDetectionType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ConflictDetectionType::Version => "VERSION",
+            ConflictDetectionType::None => "NONE",
+            ConflictDetectionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ConflictDetectionType {
+    fn default() -> ConflictDetectionType {
+        ConflictDetectionType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ConflictDetectionType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ConflictDetectionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ConflictDetectionType {
+    fn from(value: &'a str) -> ConflictDetectionType {
+        match value {
+            "VERSION" => ConflictDetectionType::Version,
+            "NONE" => ConflictDetectionType::None,
+            _ => ConflictDetectionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ConflictDetectionType {
+    fn from(value: String) -> ConflictDetectionType {
+        match value.as_str() {
+            "VERSION" => ConflictDetectionType::Version,
+            "NONE" => ConflictDetectionType::None,
+            _ => ConflictDetectionType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ConflictDetectionType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ConflictDetectionType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ConflictDetectionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ConflictDetectionType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ConflictDetectionType {
+    fn deserialize<D>(deserializer: D) -> Result<ConflictDetectionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ConflictHandlerType {
+    /// `OPTIMISTIC_CONCURRENCY`
+    OptimisticConcurrency,
+    /// `LAMBDA`
+    Lambda,
+    /// `AUTOMERGE`
+    Automerge,
+    /// `NONE`
+    None,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ConflictHandlerType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ConflictHandlerType::OptimisticConcurrency => "OPTIMISTIC_CONCURRENCY",
+            ConflictHandlerType::Lambda => "LAMBDA",
+            ConflictHandlerType::Automerge => "AUTOMERGE",
+            ConflictHandlerType::None => "NONE",
+            ConflictHandlerType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ConflictHandlerType {
+    fn default() -> ConflictHandlerType {
+        ConflictHandlerType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ConflictHandlerType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ConflictHandlerType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ConflictHandlerType {
+    fn from(value: &'a str) -> ConflictHandlerType {
+        match value {
+            "OPTIMISTIC_CONCURRENCY" => ConflictHandlerType::OptimisticConcurrency,
+            "LAMBDA" => ConflictHandlerType::Lambda,
+            "AUTOMERGE" => ConflictHandlerType::Automerge,
+            "NONE" => ConflictHandlerType::None,
+            _ => ConflictHandlerType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ConflictHandlerType {
+    fn from(value: String) -> ConflictHandlerType {
+        match value.as_str() {
+            "OPTIMISTIC_CONCURRENCY" => ConflictHandlerType::OptimisticConcurrency,
+            "LAMBDA" => ConflictHandlerType::Lambda,
+            "AUTOMERGE" => ConflictHandlerType::Automerge,
+            "NONE" => ConflictHandlerType::None,
+            _ => ConflictHandlerType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ConflictHandlerType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ConflictHandlerType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ConflictHandlerType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ConflictHandlerType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ConflictHandlerType {
+    fn deserialize<D>(deserializer: D) -> Result<ConflictHandlerType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents the input of a <code>CreateApiCache</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateApiCacheRequest {
     /// <p><p>Caching behavior.</p> <ul> <li> <p> <b>FULL<em>REQUEST</em>CACHING</b>: All requests are fully cached.</p> </li> <li> <p> <b>PER<em>RESOLVER</em>CACHING</b>: Individual resovlers that you specify are cached.</p> </li> </ul></p>
     #[serde(rename = "apiCachingBehavior")]
-    pub api_caching_behavior: String,
+    pub api_caching_behavior: ApiCachingBehavior,
     /// <p>The GraphQL API Id.</p>
     #[serde(rename = "apiId")]
     pub api_id: String,
@@ -165,7 +1005,7 @@ pub struct CreateApiCacheRequest {
     pub ttl: i64,
     /// <p><p>The cache instance type.</p> <ul> <li> <p> <b>T2<em>SMALL</b>: A t2.small instance type.</p> </li> <li> <p> <b>T2</em>MEDIUM</b>: A t2.medium instance type.</p> </li> <li> <p> <b>R4<em>LARGE</b>: A r4.large instance type.</p> </li> <li> <p> <b>R4</em>XLARGE</b>: A r4.xlarge instance type.</p> </li> <li> <p> <b>R4<em>2XLARGE</b>: A r4.2xlarge instance type.</p> </li> <li> <p> <b>R4</em>4XLARGE</b>: A r4.4xlarge instance type.</p> </li> <li> <p> <b>R4_8XLARGE</b>: A r4.8xlarge instance type.</p> </li> </ul></p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: ApiCacheType,
 }
 
 /// <p>Represents the output of a <code>CreateApiCache</code> operation.</p>
@@ -242,7 +1082,7 @@ pub struct CreateDataSourceRequest {
     pub service_role_arn: Option<String>,
     /// <p>The type of the <code>DataSource</code>.</p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: DataSourceType,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -300,7 +1140,7 @@ pub struct CreateGraphqlApiRequest {
     pub additional_authentication_providers: Option<Vec<AdditionalAuthenticationProvider>>,
     /// <p>The authentication type: API key, AWS IAM, OIDC, or Amazon Cognito user pools.</p>
     #[serde(rename = "authenticationType")]
-    pub authentication_type: String,
+    pub authentication_type: AuthenticationType,
     /// <p>The Amazon CloudWatch Logs configuration.</p>
     #[serde(rename = "logConfig")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -351,7 +1191,7 @@ pub struct CreateResolverRequest {
     /// <p><p>The resolver type.</p> <ul> <li> <p> <b>UNIT</b>: A UNIT resolver type. A UNIT resolver is the default resolver type. A UNIT resolver enables you to execute a GraphQL query against a single data source.</p> </li> <li> <p> <b>PIPELINE</b>: A PIPELINE resolver type. A PIPELINE resolver enables you to execute a series of <code>Function</code> in a serial manner. You can use a pipeline resolver to execute a GraphQL query against multiple data sources.</p> </li> </ul></p>
     #[serde(rename = "kind")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub kind: Option<String>,
+    pub kind: Option<ResolverKind>,
     /// <p>The <code>PipelineConfig</code>.</p>
     #[serde(rename = "pipelineConfig")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -392,7 +1232,7 @@ pub struct CreateTypeRequest {
     pub definition: String,
     /// <p>The type format: SDL or JSON.</p>
     #[serde(rename = "format")]
-    pub format: String,
+    pub format: TypeDefinitionFormat,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -447,9 +1287,225 @@ pub struct DataSource {
     /// <p><p>The type of the data source.</p> <ul> <li> <p> <b>AMAZON<em>DYNAMODB</b>: The data source is an Amazon DynamoDB table.</p> </li> <li> <p> <b>AMAZON</em>ELASTICSEARCH</b>: The data source is an Amazon Elasticsearch Service domain.</p> </li> <li> <p> <b>AWS<em>LAMBDA</b>: The data source is an AWS Lambda function.</p> </li> <li> <p> <b>NONE</b>: There is no data source. This type is used when you wish to invoke a GraphQL operation without connecting to a data source, such as performing data transformation with resolvers or triggering a subscription to be invoked from a mutation.</p> </li> <li> <p> <b>HTTP</b>: The data source is an HTTP endpoint.</p> </li> <li> <p> <b>RELATIONAL</em>DATABASE</b>: The data source is a relational database.</p> </li> </ul></p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<DataSourceType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DataSourceType {
+    /// `AWS_LAMBDA`
+    AwsLambda,
+    /// `AMAZON_DYNAMODB`
+    AmazonDynamodb,
+    /// `AMAZON_ELASTICSEARCH`
+    AmazonElasticsearch,
+    /// `NONE`
+    None,
+    /// `HTTP`
+    Http,
+    /// `RELATIONAL_DATABASE`
+    RelationalDatabase,
+    /// `AMAZON_OPENSEARCH_SERVICE`
+    AmazonOpensearchService,
+    /// `AMAZON_EVENTBRIDGE`
+    AmazonEventbridge,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DataSourceType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DataSourceType::AwsLambda => "AWS_LAMBDA",
+            DataSourceType::AmazonDynamodb => "AMAZON_DYNAMODB",
+            DataSourceType::AmazonElasticsearch => "AMAZON_ELASTICSEARCH",
+            DataSourceType::None => "NONE",
+            DataSourceType::Http => "HTTP",
+            DataSourceType::RelationalDatabase => "RELATIONAL_DATABASE",
+            DataSourceType::AmazonOpensearchService => "AMAZON_OPENSEARCH_SERVICE",
+            DataSourceType::AmazonEventbridge => "AMAZON_EVENTBRIDGE",
+            DataSourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DataSourceType {
+    fn default() -> DataSourceType {
+        DataSourceType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DataSourceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DataSourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DataSourceType {
+    fn from(value: &'a str) -> DataSourceType {
+        match value {
+            "AWS_LAMBDA" => DataSourceType::AwsLambda,
+            "AMAZON_DYNAMODB" => DataSourceType::AmazonDynamodb,
+            "AMAZON_ELASTICSEARCH" => DataSourceType::AmazonElasticsearch,
+            "NONE" => DataSourceType::None,
+            "HTTP" => DataSourceType::Http,
+            "RELATIONAL_DATABASE" => DataSourceType::RelationalDatabase,
+            "AMAZON_OPENSEARCH_SERVICE" => DataSourceType::AmazonOpensearchService,
+            "AMAZON_EVENTBRIDGE" => DataSourceType::AmazonEventbridge,
+            _ => DataSourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DataSourceType {
+    fn from(value: String) -> DataSourceType {
+        match value.as_str() {
+            "AWS_LAMBDA" => DataSourceType::AwsLambda,
+            "AMAZON_DYNAMODB" => DataSourceType::AmazonDynamodb,
+            "AMAZON_ELASTICSEARCH" => DataSourceType::AmazonElasticsearch,
+            "NONE" => DataSourceType::None,
+            "HTTP" => DataSourceType::Http,
+            "RELATIONAL_DATABASE" => DataSourceType::RelationalDatabase,
+            "AMAZON_OPENSEARCH_SERVICE" => DataSourceType::AmazonOpensearchService,
+            "AMAZON_EVENTBRIDGE" => DataSourceType::AmazonEventbridge,
+            _ => DataSourceType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DataSourceType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DataSourceType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DataSourceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DataSourceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for DataSourceType {
+    fn deserialize<D>(deserializer: D) -> Result<DataSourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DefaultAction {
+    /// `ALLOW`
+    Allow,
+    /// `DENY`
+    Deny,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DefaultAction {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DefaultAction::Allow => "ALLOW",
+            DefaultAction::Deny => "DENY",
+            DefaultAction::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DefaultAction {
+    fn default() -> DefaultAction {
+        DefaultAction::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DefaultAction {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DefaultAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DefaultAction {
+    fn from(value: &'a str) -> DefaultAction {
+        match value {
+            "ALLOW" => DefaultAction::Allow,
+            "DENY" => DefaultAction::Deny,
+            _ => DefaultAction::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DefaultAction {
+    fn from(value: String) -> DefaultAction {
+        match value.as_str() {
+            "ALLOW" => DefaultAction::Allow,
+            "DENY" => DefaultAction::Deny,
+            _ => DefaultAction::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DefaultAction {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DefaultAction, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DefaultAction {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DefaultAction {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DefaultAction {
+    fn deserialize<D>(deserializer: D) -> Result<DefaultAction, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents the input of a <code>DeleteApiCache</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -605,6 +1661,104 @@ pub struct ElasticsearchDataSourceConfig {
     pub endpoint: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FieldLogLevel {
+    /// `NONE`
+    None,
+    /// `ERROR`
+    Error,
+    /// `ALL`
+    All,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl FieldLogLevel {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FieldLogLevel::None => "NONE",
+            FieldLogLevel::Error => "ERROR",
+            FieldLogLevel::All => "ALL",
+            FieldLogLevel::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for FieldLogLevel {
+    fn default() -> FieldLogLevel {
+        FieldLogLevel::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for FieldLogLevel {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FieldLogLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FieldLogLevel {
+    fn from(value: &'a str) -> FieldLogLevel {
+        match value {
+            "NONE" => FieldLogLevel::None,
+            "ERROR" => FieldLogLevel::Error,
+            "ALL" => FieldLogLevel::All,
+            _ => FieldLogLevel::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FieldLogLevel {
+    fn from(value: String) -> FieldLogLevel {
+        match value.as_str() {
+            "NONE" => FieldLogLevel::None,
+            "ERROR" => FieldLogLevel::Error,
+            "ALL" => FieldLogLevel::All,
+            _ => FieldLogLevel::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for FieldLogLevel {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<FieldLogLevel, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FieldLogLevel {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FieldLogLevel {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FieldLogLevel {
+    fn deserialize<D>(deserializer: D) -> Result<FieldLogLevel, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents the input of a <code>FlushApiCache</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -740,7 +1894,7 @@ pub struct GetIntrospectionSchemaRequest {
     pub api_id: String,
     /// <p>The schema format: SDL or JSON.</p>
     #[serde(rename = "format")]
-    pub format: String,
+    pub format: OutputType,
     /// <p>A flag that specifies whether the schema introspection should contain directives.</p>
     #[serde(rename = "includeDirectives")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -794,7 +1948,7 @@ pub struct GetSchemaCreationStatusResponse {
     /// <p>The current state of the schema (PROCESSING, FAILED, SUCCESS, or NOT_APPLICABLE). When the schema is in the ACTIVE state, you can add data.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<SchemaStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -805,7 +1959,7 @@ pub struct GetTypeRequest {
     pub api_id: String,
     /// <p>The type format: SDL or JSON.</p>
     #[serde(rename = "format")]
-    pub format: String,
+    pub format: TypeDefinitionFormat,
     /// <p>The type name.</p>
     #[serde(rename = "typeName")]
     pub type_name: String,
@@ -839,7 +1993,7 @@ pub struct GraphqlApi {
     /// <p>The authentication type.</p>
     #[serde(rename = "authenticationType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub authentication_type: Option<String>,
+    pub authentication_type: Option<AuthenticationType>,
     /// <p>The Amazon CloudWatch Logs configuration.</p>
     #[serde(rename = "logConfig")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1097,7 +2251,7 @@ pub struct ListTypesRequest {
     pub api_id: String,
     /// <p>The type format: SDL or JSON.</p>
     #[serde(rename = "format")]
-    pub format: String,
+    pub format: TypeDefinitionFormat,
     /// <p>The maximum number of results you want the request to return.</p>
     #[serde(rename = "maxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1133,29 +2287,122 @@ pub struct LogConfig {
     pub exclude_verbose_content: Option<bool>,
     /// <p><p>The field logging level. Values can be NONE, ERROR, or ALL. </p> <ul> <li> <p> <b>NONE</b>: No field-level logs are captured.</p> </li> <li> <p> <b>ERROR</b>: Logs the following information only for the fields that are in error:</p> <ul> <li> <p>The error section in the server response.</p> </li> <li> <p>Field-level errors.</p> </li> <li> <p>The generated request/response functions that got resolved for error fields.</p> </li> </ul> </li> <li> <p> <b>ALL</b>: The following information is logged for all fields in the query:</p> <ul> <li> <p>Field-level tracing information.</p> </li> <li> <p>The generated request/response functions that got resolved for each field.</p> </li> </ul> </li> </ul></p>
     #[serde(rename = "fieldLogLevel")]
-    pub field_log_level: String,
+    pub field_log_level: FieldLogLevel,
+}
+
+/// <p>Describes an OpenID Connect configuration.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OpenIDConnectConfig {
+    /// <p>The number of milliseconds a token is valid after being authenticated.</p>
+    #[serde(rename = "authTTL")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auth_ttl: Option<i64>,
+    /// <p>The client identifier of the Relying party at the OpenID identity provider. This identifier is typically obtained when the Relying party is registered with the OpenID identity provider. You can specify a regular expression so the AWS AppSync can validate against multiple client identifiers at a time.</p>
+    #[serde(rename = "clientId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+    /// <p>The number of milliseconds a token is valid after being issued to a user.</p>
+    #[serde(rename = "iatTTL")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iat_ttl: Option<i64>,
+    /// <p>The issuer for the OpenID Connect configuration. The issuer returned by discovery must exactly match the value of <code>iss</code> in the ID token.</p>
+    #[serde(rename = "issuer")]
+    pub issuer: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OutputType {
+    /// `SDL`
+    Sdl,
+    /// `JSON`
+    Json,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl OutputType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OutputType::Sdl => "SDL",
+            OutputType::Json => "JSON",
+            OutputType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for OutputType {
+    fn default() -> OutputType {
+        OutputType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for OutputType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for OutputType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for OutputType {
+    fn from(value: &'a str) -> OutputType {
+        match value {
+            "SDL" => OutputType::Sdl,
+            "JSON" => OutputType::Json,
+            _ => OutputType::Unknown(value.to_owned()),
+        }
+    }
 }
 
-/// <p>Describes an OpenID Connect configuration.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct OpenIDConnectConfig {
-    /// <p>The number of milliseconds a token is valid after being authenticated.</p>
-    #[serde(rename = "authTTL")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub auth_ttl: Option<i64>,
-    /// <p>The client identifier of the Relying party at the OpenID identity provider. This identifier is typically obtained when the Relying party is registered with the OpenID identity provider. You can specify a regular expression so the AWS AppSync can validate against multiple client identifiers at a time.</p>
-    #[serde(rename = "clientId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub client_id: Option<String>,
-    /// <p>The number of milliseconds a token is valid after being issued to a user.</p>
-    #[serde(rename = "iatTTL")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub iat_ttl: Option<i64>,
-    /// <p>The issuer for the OpenID Connect configuration. The issuer returned by discovery must exactly match the value of <code>iss</code> in the ID token.</p>
-    #[serde(rename = "issuer")]
-    pub issuer: String,
+impl From<String> for OutputType {
+    fn from(value: String) -> OutputType {
+        match value.as_str() {
+            "SDL" => OutputType::Sdl,
+            "JSON" => OutputType::Json,
+            _ => OutputType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for OutputType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<OutputType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OutputType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for OutputType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for OutputType {
+    fn deserialize<D>(deserializer: D) -> Result<OutputType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The pipeline configuration for a resolver of kind <code>PIPELINE</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PipelineConfig {
@@ -1200,9 +2447,97 @@ pub struct RelationalDatabaseDataSourceConfig {
     /// <p><p>Source type for the relational database.</p> <ul> <li> <p> <b>RDS<em>HTTP</em>ENDPOINT</b>: The relational database source type is an Amazon RDS HTTP endpoint.</p> </li> </ul></p>
     #[serde(rename = "relationalDatabaseSourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub relational_database_source_type: Option<String>,
+    pub relational_database_source_type: Option<RelationalDatabaseSourceType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RelationalDatabaseSourceType {
+    /// `RDS_HTTP_ENDPOINT`
+    RdsHttpEndpoint,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RelationalDatabaseSourceType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RelationalDatabaseSourceType::RdsHttpEndpoint => "RDS_HTTP_ENDPOINT",
+            RelationalDatabaseSourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RelationalDatabaseSourceType {
+    fn default() -> RelationalDatabaseSourceType {
+        RelationalDatabaseSourceType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RelationalDatabaseSourceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RelationalDatabaseSourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RelationalDatabaseSourceType {
+    fn from(value: &'a str) -> RelationalDatabaseSourceType {
+        match value {
+            "RDS_HTTP_ENDPOINT" => RelationalDatabaseSourceType::RdsHttpEndpoint,
+            _ => RelationalDatabaseSourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RelationalDatabaseSourceType {
+    fn from(value: String) -> RelationalDatabaseSourceType {
+        match value.as_str() {
+            "RDS_HTTP_ENDPOINT" => RelationalDatabaseSourceType::RdsHttpEndpoint,
+            _ => RelationalDatabaseSourceType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RelationalDatabaseSourceType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RelationalDatabaseSourceType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RelationalDatabaseSourceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RelationalDatabaseSourceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for RelationalDatabaseSourceType {
+    fn deserialize<D>(deserializer: D) -> Result<RelationalDatabaseSourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes a resolver.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1222,7 +2557,7 @@ pub struct Resolver {
     /// <p><p>The resolver type.</p> <ul> <li> <p> <b>UNIT</b>: A UNIT resolver type. A UNIT resolver is the default resolver type. A UNIT resolver enables you to execute a GraphQL query against a single data source.</p> </li> <li> <p> <b>PIPELINE</b>: A PIPELINE resolver type. A PIPELINE resolver enables you to execute a series of <code>Function</code> in a serial manner. You can use a pipeline resolver to execute a GraphQL query against multiple data sources.</p> </li> </ul></p>
     #[serde(rename = "kind")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub kind: Option<String>,
+    pub kind: Option<ResolverKind>,
     /// <p>The <code>PipelineConfig</code>.</p>
     #[serde(rename = "pipelineConfig")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1249,6 +2584,212 @@ pub struct Resolver {
     pub type_name: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ResolverKind {
+    /// `UNIT`
+    Unit,
+    /// `PIPELINE`
+    Pipeline,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ResolverKind {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResolverKind::Unit => "UNIT",
+            ResolverKind::Pipeline => "PIPELINE",
+            ResolverKind::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ResolverKind {
+    fn default() -> ResolverKind {
+        ResolverKind::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ResolverKind {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ResolverKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ResolverKind {
+    fn from(value: &'a str) -> ResolverKind {
+        match value {
+            "UNIT" => ResolverKind::Unit,
+            "PIPELINE" => ResolverKind::Pipeline,
+            _ => ResolverKind::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResolverKind {
+    fn from(value: String) -> ResolverKind {
+        match value.as_str() {
+            "UNIT" => ResolverKind::Unit,
+            "PIPELINE" => ResolverKind::Pipeline,
+            _ => ResolverKind::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ResolverKind {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ResolverKind, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ResolverKind {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ResolverKind {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ResolverKind {
+    fn deserialize<D>(deserializer: D) -> Result<ResolverKind, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SchemaStatus {
+    /// `PROCESSING`
+    Processing,
+    /// `ACTIVE`
+    Active,
+    /// `DELETING`
+    Deleting,
+    /// `FAILED`
+    Failed,
+    /// `SUCCESS`
+    Success,
+    /// `NOT_APPLICABLE`
+    NotApplicable,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SchemaStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SchemaStatus::Processing => "PROCESSING",
+            SchemaStatus::Active => "ACTIVE",
+            SchemaStatus::Deleting => "DELETING",
+            SchemaStatus::Failed => "FAILED",
+            SchemaStatus::Success => "SUCCESS",
+            SchemaStatus::NotApplicable => "NOT_APPLICABLE",
+            SchemaStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SchemaStatus {
+    fn default() -> SchemaStatus {
+        SchemaStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SchemaStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SchemaStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SchemaStatus {
+    fn from(value: &'a str) -> SchemaStatus {
+        match value {
+            "PROCESSING" => SchemaStatus::Processing,
+            "ACTIVE" => SchemaStatus::Active,
+            "DELETING" => SchemaStatus::Deleting,
+            "FAILED" => SchemaStatus::Failed,
+            "SUCCESS" => SchemaStatus::Success,
+            "NOT_APPLICABLE" => SchemaStatus::NotApplicable,
+            _ => SchemaStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SchemaStatus {
+    fn from(value: String) -> SchemaStatus {
+        match value.as_str() {
+            "PROCESSING" => SchemaStatus::Processing,
+            "ACTIVE" => SchemaStatus::Active,
+            "DELETING" => SchemaStatus::Deleting,
+            "FAILED" => SchemaStatus::Failed,
+            "SUCCESS" => SchemaStatus::Success,
+            "NOT_APPLICABLE" => SchemaStatus::NotApplicable,
+            _ => SchemaStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SchemaStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SchemaStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SchemaStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SchemaStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SchemaStatus {
+    fn deserialize<D>(deserializer: D) -> Result<SchemaStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct StartSchemaCreationRequest {
@@ -1271,7 +2812,7 @@ pub struct StartSchemaCreationResponse {
     /// <p>The current state of the schema (PROCESSING, FAILED, SUCCESS, or NOT_APPLICABLE). When the schema is in the ACTIVE state, you can add data.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<SchemaStatus>,
 }
 
 /// <p>Describes a Sync configuration for a resolver.</p> <p>Contains information on which Conflict Detection as well as Resolution strategy should be performed when the resolver is invoked.</p>
@@ -1280,11 +2821,11 @@ pub struct SyncConfig {
     /// <p><p>The Conflict Detection strategy to use.</p> <ul> <li> <p> <b>VERSION</b>: Detect conflicts based on object versions for this resolver.</p> </li> <li> <p> <b>NONE</b>: Do not detect conflicts when executing this resolver.</p> </li> </ul></p>
     #[serde(rename = "conflictDetection")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub conflict_detection: Option<String>,
+    pub conflict_detection: Option<ConflictDetectionType>,
     /// <p><p>The Conflict Resolution strategy to perform in the event of a conflict.</p> <ul> <li> <p> <b>OPTIMISTIC_CONCURRENCY</b>: Resolve conflicts by rejecting mutations when versions do not match the latest version at the server.</p> </li> <li> <p> <b>AUTOMERGE</b>: Resolve conflicts with the Automerge conflict resolution strategy.</p> </li> <li> <p> <b>LAMBDA</b>: Resolve conflicts with a Lambda function supplied in the LambdaConflictHandlerConfig.</p> </li> </ul></p>
     #[serde(rename = "conflictHandler")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub conflict_handler: Option<String>,
+    pub conflict_handler: Option<ConflictHandlerType>,
     /// <p>The <code>LambdaConflictHandlerConfig</code> when configuring LAMBDA as the Conflict Handler.</p>
     #[serde(rename = "lambdaConflictHandlerConfig")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1325,13 +2866,106 @@ pub struct Type {
     /// <p>The type format: SDL or JSON.</p>
     #[serde(rename = "format")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub format: Option<String>,
+    pub format: Option<TypeDefinitionFormat>,
     /// <p>The type name.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TypeDefinitionFormat {
+    /// `SDL`
+    Sdl,
+    /// `JSON`
+    Json,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TypeDefinitionFormat {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TypeDefinitionFormat::Sdl => "SDL",
+            TypeDefinitionFormat::Json => "JSON",
+            TypeDefinitionFormat::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TypeDefinitionFormat {
+    fn default() -> TypeDefinitionFormat {
+        TypeDefinitionFormat::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TypeDefinitionFormat {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TypeDefinitionFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TypeDefinitionFormat {
+    fn from(value: &'a str) -> TypeDefinitionFormat {
+        match value {
+            "SDL" => TypeDefinitionFormat::Sdl,
+            "JSON" => TypeDefinitionFormat::Json,
+            _ => TypeDefinitionFormat::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TypeDefinitionFormat {
+    fn from(value: String) -> TypeDefinitionFormat {
+        match value.as_str() {
+            "SDL" => TypeDefinitionFormat::Sdl,
+            "JSON" => TypeDefinitionFormat::Json,
+            _ => TypeDefinitionFormat::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TypeDefinitionFormat {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TypeDefinitionFormat, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TypeDefinitionFormat {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TypeDefinitionFormat {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TypeDefinitionFormat {
+    fn deserialize<D>(deserializer: D) -> Result<TypeDefinitionFormat, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct UntagResourceRequest {
@@ -1353,7 +2987,7 @@ pub struct UntagResourceResponse {}
 pub struct UpdateApiCacheRequest {
     /// <p><p>Caching behavior.</p> <ul> <li> <p> <b>FULL<em>REQUEST</em>CACHING</b>: All requests are fully cached.</p> </li> <li> <p> <b>PER<em>RESOLVER</em>CACHING</b>: Individual resovlers that you specify are cached.</p> </li> </ul></p>
     #[serde(rename = "apiCachingBehavior")]
-    pub api_caching_behavior: String,
+    pub api_caching_behavior: ApiCachingBehavior,
     /// <p>The GraphQL API Id.</p>
     #[serde(rename = "apiId")]
     pub api_id: String,
@@ -1362,7 +2996,7 @@ pub struct UpdateApiCacheRequest {
     pub ttl: i64,
     /// <p><p>The cache instance type.</p> <ul> <li> <p> <b>T2<em>SMALL</b>: A t2.small instance type.</p> </li> <li> <p> <b>T2</em>MEDIUM</b>: A t2.medium instance type.</p> </li> <li> <p> <b>R4<em>LARGE</b>: A r4.large instance type.</p> </li> <li> <p> <b>R4</em>XLARGE</b>: A r4.xlarge instance type.</p> </li> <li> <p> <b>R4<em>2XLARGE</b>: A r4.2xlarge instance type.</p> </li> <li> <p> <b>R4</em>4XLARGE</b>: A r4.4xlarge instance type.</p> </li> <li> <p> <b>R4_8XLARGE</b>: A r4.8xlarge instance type.</p> </li> </ul></p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: ApiCacheType,
 }
 
 /// <p>Represents the output of a <code>UpdateApiCache</code> operation.</p>
@@ -1442,7 +3076,7 @@ pub struct UpdateDataSourceRequest {
     pub service_role_arn: Option<String>,
     /// <p>The new data source type.</p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: DataSourceType,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1507,7 +3141,7 @@ pub struct UpdateGraphqlApiRequest {
     /// <p>The new authentication type for the <code>GraphqlApi</code> object.</p>
     #[serde(rename = "authenticationType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub authentication_type: Option<String>,
+    pub authentication_type: Option<AuthenticationType>,
     /// <p>The Amazon CloudWatch Logs configuration for the <code>GraphqlApi</code> object.</p>
     #[serde(rename = "logConfig")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1554,7 +3188,7 @@ pub struct UpdateResolverRequest {
     /// <p><p>The resolver type.</p> <ul> <li> <p> <b>UNIT</b>: A UNIT resolver type. A UNIT resolver is the default resolver type. A UNIT resolver enables you to execute a GraphQL query against a single data source.</p> </li> <li> <p> <b>PIPELINE</b>: A PIPELINE resolver type. A PIPELINE resolver enables you to execute a series of <code>Function</code> in a serial manner. You can use a pipeline resolver to execute a GraphQL query against multiple data sources.</p> </li> </ul></p>
     #[serde(rename = "kind")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub kind: Option<String>,
+    pub kind: Option<ResolverKind>,
     /// <p>The <code>PipelineConfig</code>.</p>
     #[serde(rename = "pipelineConfig")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1596,7 +3230,7 @@ pub struct UpdateTypeRequest {
     pub definition: Option<String>,
     /// <p>The new type format: SDL or JSON.</p>
     #[serde(rename = "format")]
-    pub format: String,
+    pub format: TypeDefinitionFormat,
     /// <p>The new type name.</p>
     #[serde(rename = "typeName")]
     pub type_name: String,
@@ -1623,7 +3257,7 @@ pub struct UserPoolConfig {
     pub aws_region: String,
     /// <p>The action that you want your GraphQL API to take when a request that uses Amazon Cognito user pool authentication doesn't match the Amazon Cognito user pool configuration.</p>
     #[serde(rename = "defaultAction")]
-    pub default_action: String,
+    pub default_action: DefaultAction,
     /// <p>The user pool ID.</p>
     #[serde(rename = "userPoolId")]
     pub user_pool_id: String,
@@ -4088,11 +5722,12 @@ pub struct AppSyncClient {
 impl AppSyncClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> AppSyncClient {
         AppSyncClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "AppSync"),
         }
     }
 
@@ -4126,6 +5761,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/ApiCaches", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("CreateApiCache");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4156,6 +5792,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/apikeys", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("CreateApiKey");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4186,6 +5823,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/datasources", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("CreateDataSource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4216,6 +5854,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/functions", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("CreateFunction");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4246,6 +5885,7 @@ impl AppSync for AppSyncClient {
         let request_uri = "/v1/apis";
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("CreateGraphqlApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4280,6 +5920,7 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("CreateResolver");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4310,6 +5951,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/types", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("CreateType");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4340,6 +5982,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/ApiCaches", api_id = input.api_id);
 
         let mut request = SignedRequest::new("DELETE", "appsync", &self.region, &request_uri);
+        request.set_operation("DeleteApiCache");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4371,6 +6014,7 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appsync", &self.region, &request_uri);
+        request.set_operation("DeleteApiKey");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4402,6 +6046,7 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appsync", &self.region, &request_uri);
+        request.set_operation("DeleteDataSource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4433,6 +6078,7 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appsync", &self.region, &request_uri);
+        request.set_operation("DeleteFunction");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4460,6 +6106,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}", api_id = input.api_id);
 
         let mut request = SignedRequest::new("DELETE", "appsync", &self.region, &request_uri);
+        request.set_operation("DeleteGraphqlApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4492,6 +6139,7 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appsync", &self.region, &request_uri);
+        request.set_operation("DeleteResolver");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4523,6 +6171,7 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appsync", &self.region, &request_uri);
+        request.set_operation("DeleteType");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4550,6 +6199,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/FlushCache", api_id = input.api_id);
 
         let mut request = SignedRequest::new("DELETE", "appsync", &self.region, &request_uri);
+        request.set_operation("FlushApiCache");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4577,6 +6227,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/ApiCaches", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("GetApiCache");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4608,6 +6259,7 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("GetDataSource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4639,6 +6291,7 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("GetFunction");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4666,6 +6319,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("GetGraphqlApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4693,6 +6347,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/schema", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("GetIntrospectionSchema");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4733,6 +6388,7 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("GetResolver");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4760,6 +6416,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/schemacreation", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("GetSchemaCreationStatus");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4791,6 +6448,7 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("GetType");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4822,6 +6480,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/apikeys", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("ListApiKeys");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4858,6 +6517,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/datasources", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("ListDataSources");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4894,6 +6554,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/functions", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("ListFunctions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4930,6 +6591,7 @@ impl AppSync for AppSyncClient {
         let request_uri = "/v1/apis";
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("ListGraphqlApis");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4970,6 +6632,7 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("ListResolvers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5010,6 +6673,7 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("ListResolversByFunction");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5046,6 +6710,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5073,6 +6738,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/types", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("ListTypes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5110,6 +6776,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/schemacreation", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("StartSchemaCreation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5140,6 +6807,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5170,6 +6838,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "appsync", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5203,6 +6872,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/ApiCaches/update", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("UpdateApiCache");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5237,6 +6907,7 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("UpdateApiKey");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5271,6 +6942,7 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("UpdateDataSource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5305,6 +6977,7 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("UpdateFunction");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5335,6 +7008,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("UpdateGraphqlApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5370,6 +7044,7 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("UpdateResolver");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5404,6 +7079,7 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("UpdateType");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5426,3 +7102,224 @@ impl AppSync for AppSyncClient {
         }
     }
 }
+impl AppSyncClient {
+    /// Auto-paginating version of `list_api_keys`, streaming every page of results.
+    pub fn list_api_keys_pages(
+        &self,
+        input: ListApiKeysRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListApiKeysResponse, ListApiKeysError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AppSyncClient, input: ListApiKeysRequest| async move {
+                client.list_api_keys(input).await
+            },
+            |input: &ListApiKeysRequest, output: &ListApiKeysResponse| {
+                Some(ListApiKeysRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_api_keys`, streaming the `apiKeys` of every page of results.
+    pub fn list_api_keys_items(
+        &self,
+        input: ListApiKeysRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ApiKey, ListApiKeysError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_api_keys_pages(input),
+            |output: ListApiKeysResponse| output.api_keys.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_data_sources`, streaming every page of results.
+    pub fn list_data_sources_pages(
+        &self,
+        input: ListDataSourcesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListDataSourcesResponse, ListDataSourcesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AppSyncClient, input: ListDataSourcesRequest| async move {
+                client.list_data_sources(input).await
+            },
+            |input: &ListDataSourcesRequest, output: &ListDataSourcesResponse| {
+                Some(ListDataSourcesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_data_sources`, streaming the `dataSources` of every page of results.
+    pub fn list_data_sources_items(
+        &self,
+        input: ListDataSourcesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DataSource, ListDataSourcesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_data_sources_pages(input),
+            |output: ListDataSourcesResponse| output.data_sources.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_functions`, streaming every page of results.
+    pub fn list_functions_pages(
+        &self,
+        input: ListFunctionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListFunctionsResponse, ListFunctionsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AppSyncClient, input: ListFunctionsRequest| async move {
+                client.list_functions(input).await
+            },
+            |input: &ListFunctionsRequest, output: &ListFunctionsResponse| {
+                Some(ListFunctionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_functions`, streaming the `functions` of every page of results.
+    pub fn list_functions_items(
+        &self,
+        input: ListFunctionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<FunctionConfiguration, ListFunctionsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_functions_pages(input),
+            |output: ListFunctionsResponse| output.functions.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_graphql_apis`, streaming every page of results.
+    pub fn list_graphql_apis_pages(
+        &self,
+        input: ListGraphqlApisRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListGraphqlApisResponse, ListGraphqlApisError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AppSyncClient, input: ListGraphqlApisRequest| async move {
+                client.list_graphql_apis(input).await
+            },
+            |input: &ListGraphqlApisRequest, output: &ListGraphqlApisResponse| {
+                Some(ListGraphqlApisRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_graphql_apis`, streaming the `graphqlApis` of every page of results.
+    pub fn list_graphql_apis_items(
+        &self,
+        input: ListGraphqlApisRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GraphqlApi, ListGraphqlApisError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_graphql_apis_pages(input),
+            |output: ListGraphqlApisResponse| output.graphql_apis.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_resolvers`, streaming every page of results.
+    pub fn list_resolvers_pages(
+        &self,
+        input: ListResolversRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListResolversResponse, ListResolversError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AppSyncClient, input: ListResolversRequest| async move {
+                client.list_resolvers(input).await
+            },
+            |input: &ListResolversRequest, output: &ListResolversResponse| {
+                Some(ListResolversRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_resolvers`, streaming the `resolvers` of every page of results.
+    pub fn list_resolvers_items(
+        &self,
+        input: ListResolversRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Resolver, ListResolversError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_resolvers_pages(input),
+            |output: ListResolversResponse| output.resolvers.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_resolvers_by_function`, streaming every page of results.
+    pub fn list_resolvers_by_function_pages(
+        &self,
+        input: ListResolversByFunctionRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListResolversByFunctionResponse,
+        ListResolversByFunctionError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AppSyncClient, input: ListResolversByFunctionRequest| async move {
+                client.list_resolvers_by_function(input).await
+            },
+            |input: &ListResolversByFunctionRequest, output: &ListResolversByFunctionResponse| {
+                Some(ListResolversByFunctionRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_resolvers_by_function`, streaming the `resolvers` of every page of results.
+    pub fn list_resolvers_by_function_items(
+        &self,
+        input: ListResolversByFunctionRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Resolver, ListResolversByFunctionError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_resolvers_by_function_pages(input),
+            |output: ListResolversByFunctionResponse| output.resolvers.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_types`, streaming every page of results.
+    pub fn list_types_pages(
+        &self,
+        input: ListTypesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListTypesResponse, ListTypesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AppSyncClient, input: ListTypesRequest| async move {
+                client.list_types(input).await
+            },
+            |input: &ListTypesRequest, output: &ListTypesResponse| {
+                Some(ListTypesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_types`, streaming the `types` of every page of results.
+    pub fn list_types_items(
+        &self,
+        input: ListTypesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Type, ListTypesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_types_pages(input),
+            |output: ListTypesResponse| output.types.unwrap_or_default(),
+        )
+    }
+}
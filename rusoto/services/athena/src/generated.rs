@@ -88,7 +88,7 @@ pub struct ColumnInfo {
     /// <p>Indicates the column's nullable status.</p>
     #[serde(rename = "Nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub nullable: Option<String>,
+    pub nullable: Option<ColumnNullable>,
     /// <p>For <code>DECIMAL</code> data types, specifies the total number of digits, up to 38. For performance reasons, we recommend up to 18 digits.</p>
     #[serde(rename = "Precision")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -110,6 +110,104 @@ pub struct ColumnInfo {
     pub type_: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ColumnNullable {
+    /// `NOT_NULL`
+    NotNull,
+    /// `NULLABLE`
+    Nullable,
+    /// `UNKNOWN`
+    UnknownValue,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ColumnNullable {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ColumnNullable::NotNull => "NOT_NULL",
+            ColumnNullable::Nullable => "NULLABLE",
+            ColumnNullable::UnknownValue => "UNKNOWN",
+            ColumnNullable::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ColumnNullable {
+    fn default() -> ColumnNullable {
+        ColumnNullable::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ColumnNullable {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ColumnNullable {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ColumnNullable {
+    fn from(value: &'a str) -> ColumnNullable {
+        match value {
+            "NOT_NULL" => ColumnNullable::NotNull,
+            "NULLABLE" => ColumnNullable::Nullable,
+            "UNKNOWN" => ColumnNullable::UnknownValue,
+            _ => ColumnNullable::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ColumnNullable {
+    fn from(value: String) -> ColumnNullable {
+        match value.as_str() {
+            "NOT_NULL" => ColumnNullable::NotNull,
+            "NULLABLE" => ColumnNullable::Nullable,
+            "UNKNOWN" => ColumnNullable::UnknownValue,
+            _ => ColumnNullable::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ColumnNullable {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ColumnNullable, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ColumnNullable {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ColumnNullable {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ColumnNullable {
+    fn deserialize<D>(deserializer: D) -> Result<ColumnNullable, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateNamedQueryInput {
@@ -212,13 +310,111 @@ pub struct DeleteWorkGroupOutput {}
 pub struct EncryptionConfiguration {
     /// <p>Indicates whether Amazon S3 server-side encryption with Amazon S3-managed keys (<code>SSE-S3</code>), server-side encryption with KMS-managed keys (<code>SSE-KMS</code>), or client-side encryption with KMS-managed keys (CSE-KMS) is used.</p> <p>If a query runs in a workgroup and the workgroup overrides client-side settings, then the workgroup's setting for encryption is used. It specifies whether query results must be encrypted, for all queries that run in this workgroup. </p>
     #[serde(rename = "EncryptionOption")]
-    pub encryption_option: String,
+    pub encryption_option: EncryptionOption,
     /// <p>For <code>SSE-KMS</code> and <code>CSE-KMS</code>, this is the KMS key ARN or ID.</p>
     #[serde(rename = "KmsKey")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub kms_key: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EncryptionOption {
+    /// `SSE_S3`
+    SseS3,
+    /// `SSE_KMS`
+    SseKms,
+    /// `CSE_KMS`
+    CseKms,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EncryptionOption {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EncryptionOption::SseS3 => "SSE_S3",
+            EncryptionOption::SseKms => "SSE_KMS",
+            EncryptionOption::CseKms => "CSE_KMS",
+            EncryptionOption::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EncryptionOption {
+    fn default() -> EncryptionOption {
+        EncryptionOption::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EncryptionOption {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EncryptionOption {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EncryptionOption {
+    fn from(value: &'a str) -> EncryptionOption {
+        match value {
+            "SSE_S3" => EncryptionOption::SseS3,
+            "SSE_KMS" => EncryptionOption::SseKms,
+            "CSE_KMS" => EncryptionOption::CseKms,
+            _ => EncryptionOption::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EncryptionOption {
+    fn from(value: String) -> EncryptionOption {
+        match value.as_str() {
+            "SSE_S3" => EncryptionOption::SseS3,
+            "SSE_KMS" => EncryptionOption::SseKms,
+            "CSE_KMS" => EncryptionOption::CseKms,
+            _ => EncryptionOption::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EncryptionOption {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EncryptionOption, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EncryptionOption {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EncryptionOption {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EncryptionOption {
+    fn deserialize<D>(deserializer: D) -> Result<EncryptionOption, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct GetNamedQueryInput {
@@ -468,7 +664,7 @@ pub struct QueryExecution {
     /// <p>The type of query statement that was run. <code>DDL</code> indicates DDL query statements. <code>DML</code> indicates DML (Data Manipulation Language) query statements, such as <code>CREATE TABLE AS SELECT</code>. <code>UTILITY</code> indicates query statements other than DDL and DML, such as <code>SHOW CREATE TABLE</code>, or <code>DESCRIBE &lt;table&gt;</code>.</p>
     #[serde(rename = "StatementType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub statement_type: Option<String>,
+    pub statement_type: Option<StatementType>,
     /// <p>The amount of data scanned during the query execution and the amount of time that it took to execute, and the type of statement that was run.</p>
     #[serde(rename = "Statistics")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -492,6 +688,114 @@ pub struct QueryExecutionContext {
     pub database: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum QueryExecutionState {
+    /// `QUEUED`
+    Queued,
+    /// `RUNNING`
+    Running,
+    /// `SUCCEEDED`
+    Succeeded,
+    /// `FAILED`
+    Failed,
+    /// `CANCELLED`
+    Cancelled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl QueryExecutionState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            QueryExecutionState::Queued => "QUEUED",
+            QueryExecutionState::Running => "RUNNING",
+            QueryExecutionState::Succeeded => "SUCCEEDED",
+            QueryExecutionState::Failed => "FAILED",
+            QueryExecutionState::Cancelled => "CANCELLED",
+            QueryExecutionState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for QueryExecutionState {
+    fn default() -> QueryExecutionState {
+        QueryExecutionState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for QueryExecutionState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for QueryExecutionState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for QueryExecutionState {
+    fn from(value: &'a str) -> QueryExecutionState {
+        match value {
+            "QUEUED" => QueryExecutionState::Queued,
+            "RUNNING" => QueryExecutionState::Running,
+            "SUCCEEDED" => QueryExecutionState::Succeeded,
+            "FAILED" => QueryExecutionState::Failed,
+            "CANCELLED" => QueryExecutionState::Cancelled,
+            _ => QueryExecutionState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for QueryExecutionState {
+    fn from(value: String) -> QueryExecutionState {
+        match value.as_str() {
+            "QUEUED" => QueryExecutionState::Queued,
+            "RUNNING" => QueryExecutionState::Running,
+            "SUCCEEDED" => QueryExecutionState::Succeeded,
+            "FAILED" => QueryExecutionState::Failed,
+            "CANCELLED" => QueryExecutionState::Cancelled,
+            _ => QueryExecutionState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for QueryExecutionState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<QueryExecutionState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for QueryExecutionState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for QueryExecutionState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for QueryExecutionState {
+    fn deserialize<D>(deserializer: D) -> Result<QueryExecutionState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The amount of data scanned during the query execution and the amount of time that it took to execute, and the type of statement that was run.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -532,20 +836,30 @@ pub struct QueryExecutionStatistics {
 pub struct QueryExecutionStatus {
     /// <p>The date and time that the query completed.</p>
     #[serde(rename = "CompletionDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub completion_date_time: Option<f64>,
+    pub completion_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The state of query execution. <code>QUEUED</code> state is listed but is not used by Athena and is reserved for future use. <code>RUNNING</code> indicates that the query has been submitted to the service, and Athena will execute the query as soon as resources are available. <code>SUCCEEDED</code> indicates that the query completed without errors. <code>FAILED</code> indicates that the query experienced an error and did not complete processing. <code>CANCELLED</code> indicates that a user input interrupted query execution. </p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<QueryExecutionState>,
     /// <p>Further detail about the status of the query.</p>
     #[serde(rename = "StateChangeReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub state_change_reason: Option<String>,
     /// <p>The date and time that the query was submitted.</p>
     #[serde(rename = "SubmissionDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submission_date_time: Option<f64>,
+    pub submission_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>The location in Amazon S3 where query results are stored and the encryption option, if any, used for query results. These are known as "client-side settings". If workgroup settings override client-side settings, then the query uses the workgroup settings.</p>
@@ -650,6 +964,104 @@ pub struct StartQueryExecutionOutput {
     pub query_execution_id: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StatementType {
+    /// `DDL`
+    Ddl,
+    /// `DML`
+    Dml,
+    /// `UTILITY`
+    Utility,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl StatementType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StatementType::Ddl => "DDL",
+            StatementType::Dml => "DML",
+            StatementType::Utility => "UTILITY",
+            StatementType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for StatementType {
+    fn default() -> StatementType {
+        StatementType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for StatementType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for StatementType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for StatementType {
+    fn from(value: &'a str) -> StatementType {
+        match value {
+            "DDL" => StatementType::Ddl,
+            "DML" => StatementType::Dml,
+            "UTILITY" => StatementType::Utility,
+            _ => StatementType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StatementType {
+    fn from(value: String) -> StatementType {
+        match value.as_str() {
+            "DDL" => StatementType::Ddl,
+            "DML" => StatementType::Dml,
+            "UTILITY" => StatementType::Utility,
+            _ => StatementType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for StatementType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<StatementType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StatementType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for StatementType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StatementType {
+    fn deserialize<D>(deserializer: D) -> Result<StatementType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct StopQueryExecutionInput {
@@ -690,6 +1102,95 @@ pub struct TagResourceInput {
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TagResourceOutput {}
 
+/// <p>The reason for the query throttling, for example, when it exceeds the concurrent query limit.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ThrottleReason {
+    /// `CONCURRENT_QUERY_LIMIT_EXCEEDED`
+    ConcurrentQueryLimitExceeded,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ThrottleReason {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ThrottleReason::ConcurrentQueryLimitExceeded => "CONCURRENT_QUERY_LIMIT_EXCEEDED",
+            ThrottleReason::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ThrottleReason {
+    fn default() -> ThrottleReason {
+        ThrottleReason::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ThrottleReason {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ThrottleReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ThrottleReason {
+    fn from(value: &'a str) -> ThrottleReason {
+        match value {
+            "CONCURRENT_QUERY_LIMIT_EXCEEDED" => ThrottleReason::ConcurrentQueryLimitExceeded,
+            _ => ThrottleReason::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ThrottleReason {
+    fn from(value: String) -> ThrottleReason {
+        match value.as_str() {
+            "CONCURRENT_QUERY_LIMIT_EXCEEDED" => ThrottleReason::ConcurrentQueryLimitExceeded,
+            _ => ThrottleReason::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ThrottleReason {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ThrottleReason, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ThrottleReason {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ThrottleReason {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ThrottleReason {
+    fn deserialize<D>(deserializer: D) -> Result<ThrottleReason, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about a named query ID that could not be processed.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -755,7 +1256,7 @@ pub struct UpdateWorkGroupInput {
     /// <p>The workgroup state that will be updated for the given workgroup.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<WorkGroupState>,
     /// <p>The specified workgroup that will be updated.</p>
     #[serde(rename = "WorkGroup")]
     pub work_group: String,
@@ -775,8 +1276,13 @@ pub struct WorkGroup {
     pub configuration: Option<WorkGroupConfiguration>,
     /// <p>The date and time the workgroup was created.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The workgroup description.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -787,7 +1293,7 @@ pub struct WorkGroup {
     /// <p>The state of the workgroup: ENABLED or DISABLED.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<WorkGroupState>,
 }
 
 /// <p>The configuration of the workgroup, which includes the location in Amazon S3 where query results are stored, the encryption option, if any, used for query results, whether the Amazon CloudWatch Metrics are enabled for the workgroup and whether workgroup settings override query settings, and the data usage limits for the amount of data scanned per query or per workgroup. The workgroup settings override is specified in EnforceWorkGroupConfiguration (true/false) in the WorkGroupConfiguration. See <a>WorkGroupConfiguration$EnforceWorkGroupConfiguration</a>. </p>
@@ -845,14 +1351,112 @@ pub struct WorkGroupConfigurationUpdates {
     pub result_configuration_updates: Option<ResultConfigurationUpdates>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum WorkGroupState {
+    /// `ENABLED`
+    Enabled,
+    /// `DISABLED`
+    Disabled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl WorkGroupState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            WorkGroupState::Enabled => "ENABLED",
+            WorkGroupState::Disabled => "DISABLED",
+            WorkGroupState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for WorkGroupState {
+    fn default() -> WorkGroupState {
+        WorkGroupState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for WorkGroupState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for WorkGroupState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for WorkGroupState {
+    fn from(value: &'a str) -> WorkGroupState {
+        match value {
+            "ENABLED" => WorkGroupState::Enabled,
+            "DISABLED" => WorkGroupState::Disabled,
+            _ => WorkGroupState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for WorkGroupState {
+    fn from(value: String) -> WorkGroupState {
+        match value.as_str() {
+            "ENABLED" => WorkGroupState::Enabled,
+            "DISABLED" => WorkGroupState::Disabled,
+            _ => WorkGroupState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for WorkGroupState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<WorkGroupState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for WorkGroupState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for WorkGroupState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for WorkGroupState {
+    fn deserialize<D>(deserializer: D) -> Result<WorkGroupState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The summary information for the workgroup, which includes its name, state, description, and the date and time it was created.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct WorkGroupSummary {
     /// <p>The workgroup creation date and time.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The workgroup description.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -864,7 +1468,7 @@ pub struct WorkGroupSummary {
     /// <p>The state of the workgroup.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<WorkGroupState>,
 }
 
 /// Errors returned by BatchGetNamedQuery
@@ -1708,11 +2312,12 @@ pub struct AthenaClient {
 impl AthenaClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> AthenaClient {
         AthenaClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Athena"),
         }
     }
 
@@ -1744,6 +2349,7 @@ impl Athena for AthenaClient {
         input: BatchGetNamedQueryInput,
     ) -> Result<BatchGetNamedQueryOutput, RusotoError<BatchGetNamedQueryError>> {
         let mut request = SignedRequest::new("POST", "athena", &self.region, "/");
+        request.set_operation("BatchGetNamedQuery");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonAthena.BatchGetNamedQuery");
@@ -1772,6 +2378,7 @@ impl Athena for AthenaClient {
         input: BatchGetQueryExecutionInput,
     ) -> Result<BatchGetQueryExecutionOutput, RusotoError<BatchGetQueryExecutionError>> {
         let mut request = SignedRequest::new("POST", "athena", &self.region, "/");
+        request.set_operation("BatchGetQueryExecution");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonAthena.BatchGetQueryExecution");
@@ -1800,6 +2407,7 @@ impl Athena for AthenaClient {
         input: CreateNamedQueryInput,
     ) -> Result<CreateNamedQueryOutput, RusotoError<CreateNamedQueryError>> {
         let mut request = SignedRequest::new("POST", "athena", &self.region, "/");
+        request.set_operation("CreateNamedQuery");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonAthena.CreateNamedQuery");
@@ -1827,6 +2435,7 @@ impl Athena for AthenaClient {
         input: CreateWorkGroupInput,
     ) -> Result<CreateWorkGroupOutput, RusotoError<CreateWorkGroupError>> {
         let mut request = SignedRequest::new("POST", "athena", &self.region, "/");
+        request.set_operation("CreateWorkGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonAthena.CreateWorkGroup");
@@ -1854,6 +2463,7 @@ impl Athena for AthenaClient {
         input: DeleteNamedQueryInput,
     ) -> Result<DeleteNamedQueryOutput, RusotoError<DeleteNamedQueryError>> {
         let mut request = SignedRequest::new("POST", "athena", &self.region, "/");
+        request.set_operation("DeleteNamedQuery");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonAthena.DeleteNamedQuery");
@@ -1881,6 +2491,7 @@ impl Athena for AthenaClient {
         input: DeleteWorkGroupInput,
     ) -> Result<DeleteWorkGroupOutput, RusotoError<DeleteWorkGroupError>> {
         let mut request = SignedRequest::new("POST", "athena", &self.region, "/");
+        request.set_operation("DeleteWorkGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonAthena.DeleteWorkGroup");
@@ -1908,6 +2519,7 @@ impl Athena for AthenaClient {
         input: GetNamedQueryInput,
     ) -> Result<GetNamedQueryOutput, RusotoError<GetNamedQueryError>> {
         let mut request = SignedRequest::new("POST", "athena", &self.region, "/");
+        request.set_operation("GetNamedQuery");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonAthena.GetNamedQuery");
@@ -1935,6 +2547,7 @@ impl Athena for AthenaClient {
         input: GetQueryExecutionInput,
     ) -> Result<GetQueryExecutionOutput, RusotoError<GetQueryExecutionError>> {
         let mut request = SignedRequest::new("POST", "athena", &self.region, "/");
+        request.set_operation("GetQueryExecution");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonAthena.GetQueryExecution");
@@ -1962,6 +2575,7 @@ impl Athena for AthenaClient {
         input: GetQueryResultsInput,
     ) -> Result<GetQueryResultsOutput, RusotoError<GetQueryResultsError>> {
         let mut request = SignedRequest::new("POST", "athena", &self.region, "/");
+        request.set_operation("GetQueryResults");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonAthena.GetQueryResults");
@@ -1989,6 +2603,7 @@ impl Athena for AthenaClient {
         input: GetWorkGroupInput,
     ) -> Result<GetWorkGroupOutput, RusotoError<GetWorkGroupError>> {
         let mut request = SignedRequest::new("POST", "athena", &self.region, "/");
+        request.set_operation("GetWorkGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonAthena.GetWorkGroup");
@@ -2016,6 +2631,7 @@ impl Athena for AthenaClient {
         input: ListNamedQueriesInput,
     ) -> Result<ListNamedQueriesOutput, RusotoError<ListNamedQueriesError>> {
         let mut request = SignedRequest::new("POST", "athena", &self.region, "/");
+        request.set_operation("ListNamedQueries");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonAthena.ListNamedQueries");
@@ -2043,6 +2659,7 @@ impl Athena for AthenaClient {
         input: ListQueryExecutionsInput,
     ) -> Result<ListQueryExecutionsOutput, RusotoError<ListQueryExecutionsError>> {
         let mut request = SignedRequest::new("POST", "athena", &self.region, "/");
+        request.set_operation("ListQueryExecutions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonAthena.ListQueryExecutions");
@@ -2071,6 +2688,7 @@ impl Athena for AthenaClient {
         input: ListTagsForResourceInput,
     ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
         let mut request = SignedRequest::new("POST", "athena", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonAthena.ListTagsForResource");
@@ -2099,6 +2717,7 @@ impl Athena for AthenaClient {
         input: ListWorkGroupsInput,
     ) -> Result<ListWorkGroupsOutput, RusotoError<ListWorkGroupsError>> {
         let mut request = SignedRequest::new("POST", "athena", &self.region, "/");
+        request.set_operation("ListWorkGroups");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonAthena.ListWorkGroups");
@@ -2126,6 +2745,7 @@ impl Athena for AthenaClient {
         input: StartQueryExecutionInput,
     ) -> Result<StartQueryExecutionOutput, RusotoError<StartQueryExecutionError>> {
         let mut request = SignedRequest::new("POST", "athena", &self.region, "/");
+        request.set_operation("StartQueryExecution");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonAthena.StartQueryExecution");
@@ -2154,6 +2774,7 @@ impl Athena for AthenaClient {
         input: StopQueryExecutionInput,
     ) -> Result<StopQueryExecutionOutput, RusotoError<StopQueryExecutionError>> {
         let mut request = SignedRequest::new("POST", "athena", &self.region, "/");
+        request.set_operation("StopQueryExecution");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonAthena.StopQueryExecution");
@@ -2182,6 +2803,7 @@ impl Athena for AthenaClient {
         input: TagResourceInput,
     ) -> Result<TagResourceOutput, RusotoError<TagResourceError>> {
         let mut request = SignedRequest::new("POST", "athena", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonAthena.TagResource");
@@ -2209,6 +2831,7 @@ impl Athena for AthenaClient {
         input: UntagResourceInput,
     ) -> Result<UntagResourceOutput, RusotoError<UntagResourceError>> {
         let mut request = SignedRequest::new("POST", "athena", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonAthena.UntagResource");
@@ -2236,6 +2859,7 @@ impl Athena for AthenaClient {
         input: UpdateWorkGroupInput,
     ) -> Result<UpdateWorkGroupOutput, RusotoError<UpdateWorkGroupError>> {
         let mut request = SignedRequest::new("POST", "athena", &self.region, "/");
+        request.set_operation("UpdateWorkGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonAthena.UpdateWorkGroup");
@@ -2257,3 +2881,132 @@ impl Athena for AthenaClient {
         }
     }
 }
+impl AthenaClient {
+    /// Auto-paginating version of `get_query_results`, streaming every page of results.
+    pub fn get_query_results_pages(
+        &self,
+        input: GetQueryResultsInput,
+    ) -> rusoto_core::pagination::RusotoStream<GetQueryResultsOutput, GetQueryResultsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AthenaClient, input: GetQueryResultsInput| async move {
+                client.get_query_results(input).await
+            },
+            |input: &GetQueryResultsInput, output: &GetQueryResultsOutput| {
+                Some(GetQueryResultsInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_query_results`, streaming the `ResultSet.Rows` of every page of results.
+    pub fn get_query_results_items(
+        &self,
+        input: GetQueryResultsInput,
+    ) -> rusoto_core::pagination::RusotoStream<Row, GetQueryResultsError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_query_results_pages(input),
+            |output: GetQueryResultsOutput| {
+                output.result_set.and_then(|v| v.rows).unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_named_queries`, streaming every page of results.
+    pub fn list_named_queries_pages(
+        &self,
+        input: ListNamedQueriesInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListNamedQueriesOutput, ListNamedQueriesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AthenaClient, input: ListNamedQueriesInput| async move {
+                client.list_named_queries(input).await
+            },
+            |input: &ListNamedQueriesInput, output: &ListNamedQueriesOutput| {
+                Some(ListNamedQueriesInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_named_queries`, streaming the `NamedQueryIds` of every page of results.
+    pub fn list_named_queries_items(
+        &self,
+        input: ListNamedQueriesInput,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListNamedQueriesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_named_queries_pages(input),
+            |output: ListNamedQueriesOutput| output.named_query_ids.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_query_executions`, streaming every page of results.
+    pub fn list_query_executions_pages(
+        &self,
+        input: ListQueryExecutionsInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListQueryExecutionsOutput, ListQueryExecutionsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AthenaClient, input: ListQueryExecutionsInput| async move {
+                client.list_query_executions(input).await
+            },
+            |input: &ListQueryExecutionsInput, output: &ListQueryExecutionsOutput| {
+                Some(ListQueryExecutionsInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_query_executions`, streaming the `QueryExecutionIds` of every page of results.
+    pub fn list_query_executions_items(
+        &self,
+        input: ListQueryExecutionsInput,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListQueryExecutionsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_query_executions_pages(input),
+            |output: ListQueryExecutionsOutput| output.query_execution_ids.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_tags_for_resource`, streaming every page of results.
+    pub fn list_tags_for_resource_pages(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListTagsForResourceOutput, ListTagsForResourceError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AthenaClient, input: ListTagsForResourceInput| async move {
+                client.list_tags_for_resource(input).await
+            },
+            |input: &ListTagsForResourceInput, output: &ListTagsForResourceOutput| {
+                Some(ListTagsForResourceInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_tags_for_resource`, streaming the `Tags` of every page of results.
+    pub fn list_tags_for_resource_items(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> rusoto_core::pagination::RusotoStream<Tag, ListTagsForResourceError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_tags_for_resource_pages(input),
+            |output: ListTagsForResourceOutput| output.tags.unwrap_or_default(),
+        )
+    }
+}
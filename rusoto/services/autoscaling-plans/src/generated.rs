@@ -74,7 +74,7 @@ pub struct CustomizedLoadMetricSpecification {
     pub namespace: String,
     /// <p>The statistic of the metric. Currently, the value must always be <code>Sum</code>. </p>
     #[serde(rename = "Statistic")]
-    pub statistic: String,
+    pub statistic: MetricStatistic,
     /// <p>The unit of the metric.</p>
     #[serde(rename = "Unit")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -96,7 +96,7 @@ pub struct CustomizedScalingMetricSpecification {
     pub namespace: String,
     /// <p>The statistic of the metric.</p>
     #[serde(rename = "Statistic")]
-    pub statistic: String,
+    pub statistic: MetricStatistic,
     /// <p>The unit of the metric. </p>
     #[serde(rename = "Unit")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -109,8 +109,13 @@ pub struct CustomizedScalingMetricSpecification {
 pub struct Datapoint {
     /// <p>The time stamp for the data point in UTC format.</p>
     #[serde(rename = "Timestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub timestamp: Option<f64>,
+    pub timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The value of the data point.</p>
     #[serde(rename = "Value")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -202,21 +207,129 @@ pub struct DescribeScalingPlansResponse {
     pub scaling_plans: Option<Vec<ScalingPlan>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ForecastDataType {
+    /// `CapacityForecast`
+    CapacityForecast,
+    /// `LoadForecast`
+    LoadForecast,
+    /// `ScheduledActionMinCapacity`
+    ScheduledActionMinCapacity,
+    /// `ScheduledActionMaxCapacity`
+    ScheduledActionMaxCapacity,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ForecastDataType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ForecastDataType::CapacityForecast => "CapacityForecast",
+            ForecastDataType::LoadForecast => "LoadForecast",
+            ForecastDataType::ScheduledActionMinCapacity => "ScheduledActionMinCapacity",
+            ForecastDataType::ScheduledActionMaxCapacity => "ScheduledActionMaxCapacity",
+            ForecastDataType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ForecastDataType {
+    fn default() -> ForecastDataType {
+        ForecastDataType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ForecastDataType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ForecastDataType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ForecastDataType {
+    fn from(value: &'a str) -> ForecastDataType {
+        match value {
+            "CapacityForecast" => ForecastDataType::CapacityForecast,
+            "LoadForecast" => ForecastDataType::LoadForecast,
+            "ScheduledActionMinCapacity" => ForecastDataType::ScheduledActionMinCapacity,
+            "ScheduledActionMaxCapacity" => ForecastDataType::ScheduledActionMaxCapacity,
+            _ => ForecastDataType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ForecastDataType {
+    fn from(value: String) -> ForecastDataType {
+        match value.as_str() {
+            "CapacityForecast" => ForecastDataType::CapacityForecast,
+            "LoadForecast" => ForecastDataType::LoadForecast,
+            "ScheduledActionMinCapacity" => ForecastDataType::ScheduledActionMinCapacity,
+            "ScheduledActionMaxCapacity" => ForecastDataType::ScheduledActionMaxCapacity,
+            _ => ForecastDataType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ForecastDataType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ForecastDataType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ForecastDataType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ForecastDataType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ForecastDataType {
+    fn deserialize<D>(deserializer: D) -> Result<ForecastDataType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct GetScalingPlanResourceForecastDataRequest {
     /// <p>The exclusive end time of the time range for the forecast data to get. The maximum time duration between the start and end time is seven days. </p> <p>Although this parameter can accept a date and time that is more than two days in the future, the availability of forecast data has limits. AWS Auto Scaling only issues forecasts for periods of two days in advance.</p>
     #[serde(rename = "EndTime")]
-    pub end_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub end_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p><p>The type of forecast data to get.</p> <ul> <li> <p> <code>LoadForecast</code>: The load metric forecast. </p> </li> <li> <p> <code>CapacityForecast</code>: The capacity forecast. </p> </li> <li> <p> <code>ScheduledActionMinCapacity</code>: The minimum capacity for each scheduled scaling action. This data is calculated as the larger of two values: the capacity forecast or the minimum capacity in the scaling instruction.</p> </li> <li> <p> <code>ScheduledActionMaxCapacity</code>: The maximum capacity for each scheduled scaling action. The calculation used is determined by the predictive scaling maximum capacity behavior setting in the scaling instruction.</p> </li> </ul></p>
     #[serde(rename = "ForecastDataType")]
-    pub forecast_data_type: String,
+    pub forecast_data_type: ForecastDataType,
     /// <p><p>The ID of the resource. This string consists of the resource type and unique identifier. </p> <ul> <li> <p>Auto Scaling group - The resource type is <code>autoScalingGroup</code> and the unique identifier is the name of the Auto Scaling group. Example: <code>autoScalingGroup/my-asg</code>.</p> </li> <li> <p>ECS service - The resource type is <code>service</code> and the unique identifier is the cluster name and service name. Example: <code>service/default/sample-webapp</code>.</p> </li> <li> <p>Spot Fleet request - The resource type is <code>spot-fleet-request</code> and the unique identifier is the Spot Fleet request ID. Example: <code>spot-fleet-request/sfr-73fbd2ce-aa30-494c-8788-1cee4EXAMPLE</code>.</p> </li> <li> <p>DynamoDB table - The resource type is <code>table</code> and the unique identifier is the resource ID. Example: <code>table/my-table</code>.</p> </li> <li> <p>DynamoDB global secondary index - The resource type is <code>index</code> and the unique identifier is the resource ID. Example: <code>table/my-table/index/my-table-index</code>.</p> </li> <li> <p>Aurora DB cluster - The resource type is <code>cluster</code> and the unique identifier is the cluster name. Example: <code>cluster:my-db-cluster</code>.</p> </li> </ul></p>
     #[serde(rename = "ResourceId")]
     pub resource_id: String,
     /// <p>The scalable dimension for the resource.</p>
     #[serde(rename = "ScalableDimension")]
-    pub scalable_dimension: String,
+    pub scalable_dimension: ScalableDimension,
     /// <p>The name of the scaling plan.</p>
     #[serde(rename = "ScalingPlanName")]
     pub scaling_plan_name: String,
@@ -225,10 +338,15 @@ pub struct GetScalingPlanResourceForecastDataRequest {
     pub scaling_plan_version: i64,
     /// <p>The namespace of the AWS service.</p>
     #[serde(rename = "ServiceNamespace")]
-    pub service_namespace: String,
+    pub service_namespace: ServiceNamespace,
     /// <p>The inclusive start time of the time range for the forecast data to get. The date and time can be at most 56 days before the current date and time. </p>
     #[serde(rename = "StartTime")]
-    pub start_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub start_time: ::chrono::DateTime<::chrono::Utc>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -239,6 +357,109 @@ pub struct GetScalingPlanResourceForecastDataResponse {
     pub datapoints: Vec<Datapoint>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LoadMetricType {
+    /// `ASGTotalCPUUtilization`
+    ASGTotalCPUUtilization,
+    /// `ASGTotalNetworkIn`
+    ASGTotalNetworkIn,
+    /// `ASGTotalNetworkOut`
+    ASGTotalNetworkOut,
+    /// `ALBTargetGroupRequestCount`
+    ALBTargetGroupRequestCount,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LoadMetricType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LoadMetricType::ASGTotalCPUUtilization => "ASGTotalCPUUtilization",
+            LoadMetricType::ASGTotalNetworkIn => "ASGTotalNetworkIn",
+            LoadMetricType::ASGTotalNetworkOut => "ASGTotalNetworkOut",
+            LoadMetricType::ALBTargetGroupRequestCount => "ALBTargetGroupRequestCount",
+            LoadMetricType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LoadMetricType {
+    fn default() -> LoadMetricType {
+        LoadMetricType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LoadMetricType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LoadMetricType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LoadMetricType {
+    fn from(value: &'a str) -> LoadMetricType {
+        match value {
+            "ASGTotalCPUUtilization" => LoadMetricType::ASGTotalCPUUtilization,
+            "ASGTotalNetworkIn" => LoadMetricType::ASGTotalNetworkIn,
+            "ASGTotalNetworkOut" => LoadMetricType::ASGTotalNetworkOut,
+            "ALBTargetGroupRequestCount" => LoadMetricType::ALBTargetGroupRequestCount,
+            _ => LoadMetricType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LoadMetricType {
+    fn from(value: String) -> LoadMetricType {
+        match value.as_str() {
+            "ASGTotalCPUUtilization" => LoadMetricType::ASGTotalCPUUtilization,
+            "ASGTotalNetworkIn" => LoadMetricType::ASGTotalNetworkIn,
+            "ASGTotalNetworkOut" => LoadMetricType::ASGTotalNetworkOut,
+            "ALBTargetGroupRequestCount" => LoadMetricType::ALBTargetGroupRequestCount,
+            _ => LoadMetricType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LoadMetricType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LoadMetricType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LoadMetricType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LoadMetricType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LoadMetricType {
+    fn deserialize<D>(deserializer: D) -> Result<LoadMetricType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents a dimension for a customized metric.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MetricDimension {
@@ -250,12 +471,208 @@ pub struct MetricDimension {
     pub value: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MetricStatistic {
+    /// `Average`
+    Average,
+    /// `Minimum`
+    Minimum,
+    /// `Maximum`
+    Maximum,
+    /// `SampleCount`
+    SampleCount,
+    /// `Sum`
+    Sum,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl MetricStatistic {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MetricStatistic::Average => "Average",
+            MetricStatistic::Minimum => "Minimum",
+            MetricStatistic::Maximum => "Maximum",
+            MetricStatistic::SampleCount => "SampleCount",
+            MetricStatistic::Sum => "Sum",
+            MetricStatistic::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for MetricStatistic {
+    fn default() -> MetricStatistic {
+        MetricStatistic::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for MetricStatistic {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for MetricStatistic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for MetricStatistic {
+    fn from(value: &'a str) -> MetricStatistic {
+        match value {
+            "Average" => MetricStatistic::Average,
+            "Minimum" => MetricStatistic::Minimum,
+            "Maximum" => MetricStatistic::Maximum,
+            "SampleCount" => MetricStatistic::SampleCount,
+            "Sum" => MetricStatistic::Sum,
+            _ => MetricStatistic::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MetricStatistic {
+    fn from(value: String) -> MetricStatistic {
+        match value.as_str() {
+            "Average" => MetricStatistic::Average,
+            "Minimum" => MetricStatistic::Minimum,
+            "Maximum" => MetricStatistic::Maximum,
+            "SampleCount" => MetricStatistic::SampleCount,
+            "Sum" => MetricStatistic::Sum,
+            _ => MetricStatistic::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for MetricStatistic {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<MetricStatistic, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MetricStatistic {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for MetricStatistic {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MetricStatistic {
+    fn deserialize<D>(deserializer: D) -> Result<MetricStatistic, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PolicyType {
+    /// `TargetTrackingScaling`
+    TargetTrackingScaling,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PolicyType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PolicyType::TargetTrackingScaling => "TargetTrackingScaling",
+            PolicyType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PolicyType {
+    fn default() -> PolicyType {
+        PolicyType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PolicyType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PolicyType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PolicyType {
+    fn from(value: &'a str) -> PolicyType {
+        match value {
+            "TargetTrackingScaling" => PolicyType::TargetTrackingScaling,
+            _ => PolicyType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PolicyType {
+    fn from(value: String) -> PolicyType {
+        match value.as_str() {
+            "TargetTrackingScaling" => PolicyType::TargetTrackingScaling,
+            _ => PolicyType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PolicyType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PolicyType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PolicyType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PolicyType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PolicyType {
+    fn deserialize<D>(deserializer: D) -> Result<PolicyType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents a predefined metric that can be used for predictive scaling. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PredefinedLoadMetricSpecification {
     /// <p>The metric type.</p>
     #[serde(rename = "PredefinedLoadMetricType")]
-    pub predefined_load_metric_type: String,
+    pub predefined_load_metric_type: LoadMetricType,
     /// <p><p>Identifies the resource associated with the metric type. You can&#39;t specify a resource label unless the metric type is <code>ALBRequestCountPerTarget</code> and there is a target group for an Application Load Balancer attached to the Auto Scaling group.</p> <p>The format is app/&lt;load-balancer-name&gt;/&lt;load-balancer-id&gt;/targetgroup/&lt;target-group-name&gt;/&lt;target-group-id&gt;, where:</p> <ul> <li> <p>app/&lt;load-balancer-name&gt;/&lt;load-balancer-id&gt; is the final portion of the load balancer ARN.</p> </li> <li> <p>targetgroup/&lt;target-group-name&gt;/&lt;target-group-id&gt; is the final portion of the target group ARN.</p> </li> </ul></p>
     #[serde(rename = "ResourceLabel")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -267,13 +684,369 @@ pub struct PredefinedLoadMetricSpecification {
 pub struct PredefinedScalingMetricSpecification {
     /// <p>The metric type. The <code>ALBRequestCountPerTarget</code> metric type applies only to Auto Scaling groups, Spot Fleet requests, and ECS services.</p>
     #[serde(rename = "PredefinedScalingMetricType")]
-    pub predefined_scaling_metric_type: String,
+    pub predefined_scaling_metric_type: ScalingMetricType,
     /// <p><p>Identifies the resource associated with the metric type. You can&#39;t specify a resource label unless the metric type is <code>ALBRequestCountPerTarget</code> and there is a target group for an Application Load Balancer attached to the Auto Scaling group, Spot Fleet request, or ECS service.</p> <p>The format is app/&lt;load-balancer-name&gt;/&lt;load-balancer-id&gt;/targetgroup/&lt;target-group-name&gt;/&lt;target-group-id&gt;, where:</p> <ul> <li> <p>app/&lt;load-balancer-name&gt;/&lt;load-balancer-id&gt; is the final portion of the load balancer ARN.</p> </li> <li> <p>targetgroup/&lt;target-group-name&gt;/&lt;target-group-id&gt; is the final portion of the target group ARN.</p> </li> </ul></p>
     #[serde(rename = "ResourceLabel")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub resource_label: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PredictiveScalingMaxCapacityBehavior {
+    /// `SetForecastCapacityToMaxCapacity`
+    SetForecastCapacityToMaxCapacity,
+    /// `SetMaxCapacityToForecastCapacity`
+    SetMaxCapacityToForecastCapacity,
+    /// `SetMaxCapacityAboveForecastCapacity`
+    SetMaxCapacityAboveForecastCapacity,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PredictiveScalingMaxCapacityBehavior {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PredictiveScalingMaxCapacityBehavior::SetForecastCapacityToMaxCapacity => {
+                "SetForecastCapacityToMaxCapacity"
+            }
+            PredictiveScalingMaxCapacityBehavior::SetMaxCapacityToForecastCapacity => {
+                "SetMaxCapacityToForecastCapacity"
+            }
+            PredictiveScalingMaxCapacityBehavior::SetMaxCapacityAboveForecastCapacity => {
+                "SetMaxCapacityAboveForecastCapacity"
+            }
+            PredictiveScalingMaxCapacityBehavior::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PredictiveScalingMaxCapacityBehavior {
+    fn default() -> PredictiveScalingMaxCapacityBehavior {
+        PredictiveScalingMaxCapacityBehavior::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PredictiveScalingMaxCapacityBehavior {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PredictiveScalingMaxCapacityBehavior {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PredictiveScalingMaxCapacityBehavior {
+    fn from(value: &'a str) -> PredictiveScalingMaxCapacityBehavior {
+        match value {
+            "SetForecastCapacityToMaxCapacity" => {
+                PredictiveScalingMaxCapacityBehavior::SetForecastCapacityToMaxCapacity
+            }
+            "SetMaxCapacityToForecastCapacity" => {
+                PredictiveScalingMaxCapacityBehavior::SetMaxCapacityToForecastCapacity
+            }
+            "SetMaxCapacityAboveForecastCapacity" => {
+                PredictiveScalingMaxCapacityBehavior::SetMaxCapacityAboveForecastCapacity
+            }
+            _ => PredictiveScalingMaxCapacityBehavior::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PredictiveScalingMaxCapacityBehavior {
+    fn from(value: String) -> PredictiveScalingMaxCapacityBehavior {
+        match value.as_str() {
+            "SetForecastCapacityToMaxCapacity" => {
+                PredictiveScalingMaxCapacityBehavior::SetForecastCapacityToMaxCapacity
+            }
+            "SetMaxCapacityToForecastCapacity" => {
+                PredictiveScalingMaxCapacityBehavior::SetMaxCapacityToForecastCapacity
+            }
+            "SetMaxCapacityAboveForecastCapacity" => {
+                PredictiveScalingMaxCapacityBehavior::SetMaxCapacityAboveForecastCapacity
+            }
+            _ => PredictiveScalingMaxCapacityBehavior::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PredictiveScalingMaxCapacityBehavior {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PredictiveScalingMaxCapacityBehavior, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PredictiveScalingMaxCapacityBehavior {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PredictiveScalingMaxCapacityBehavior {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PredictiveScalingMaxCapacityBehavior {
+    fn deserialize<D>(deserializer: D) -> Result<PredictiveScalingMaxCapacityBehavior, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PredictiveScalingMode {
+    /// `ForecastAndScale`
+    ForecastAndScale,
+    /// `ForecastOnly`
+    ForecastOnly,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PredictiveScalingMode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PredictiveScalingMode::ForecastAndScale => "ForecastAndScale",
+            PredictiveScalingMode::ForecastOnly => "ForecastOnly",
+            PredictiveScalingMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PredictiveScalingMode {
+    fn default() -> PredictiveScalingMode {
+        PredictiveScalingMode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PredictiveScalingMode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PredictiveScalingMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PredictiveScalingMode {
+    fn from(value: &'a str) -> PredictiveScalingMode {
+        match value {
+            "ForecastAndScale" => PredictiveScalingMode::ForecastAndScale,
+            "ForecastOnly" => PredictiveScalingMode::ForecastOnly,
+            _ => PredictiveScalingMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PredictiveScalingMode {
+    fn from(value: String) -> PredictiveScalingMode {
+        match value.as_str() {
+            "ForecastAndScale" => PredictiveScalingMode::ForecastAndScale,
+            "ForecastOnly" => PredictiveScalingMode::ForecastOnly,
+            _ => PredictiveScalingMode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PredictiveScalingMode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PredictiveScalingMode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PredictiveScalingMode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PredictiveScalingMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PredictiveScalingMode {
+    fn deserialize<D>(deserializer: D) -> Result<PredictiveScalingMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ScalableDimension {
+    /// `autoscaling:autoScalingGroup:DesiredCapacity`
+    AutoscalingAutoScalingGroupDesiredCapacity,
+    /// `ecs:service:DesiredCount`
+    EcsServiceDesiredCount,
+    /// `ec2:spot-fleet-request:TargetCapacity`
+    Ec2SpotFleetRequestTargetCapacity,
+    /// `rds:cluster:ReadReplicaCount`
+    RdsClusterReadReplicaCount,
+    /// `dynamodb:table:ReadCapacityUnits`
+    DynamodbTableReadCapacityUnits,
+    /// `dynamodb:table:WriteCapacityUnits`
+    DynamodbTableWriteCapacityUnits,
+    /// `dynamodb:index:ReadCapacityUnits`
+    DynamodbIndexReadCapacityUnits,
+    /// `dynamodb:index:WriteCapacityUnits`
+    DynamodbIndexWriteCapacityUnits,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ScalableDimension {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ScalableDimension::AutoscalingAutoScalingGroupDesiredCapacity => {
+                "autoscaling:autoScalingGroup:DesiredCapacity"
+            }
+            ScalableDimension::EcsServiceDesiredCount => "ecs:service:DesiredCount",
+            ScalableDimension::Ec2SpotFleetRequestTargetCapacity => {
+                "ec2:spot-fleet-request:TargetCapacity"
+            }
+            ScalableDimension::RdsClusterReadReplicaCount => "rds:cluster:ReadReplicaCount",
+            ScalableDimension::DynamodbTableReadCapacityUnits => "dynamodb:table:ReadCapacityUnits",
+            ScalableDimension::DynamodbTableWriteCapacityUnits => {
+                "dynamodb:table:WriteCapacityUnits"
+            }
+            ScalableDimension::DynamodbIndexReadCapacityUnits => "dynamodb:index:ReadCapacityUnits",
+            ScalableDimension::DynamodbIndexWriteCapacityUnits => {
+                "dynamodb:index:WriteCapacityUnits"
+            }
+            ScalableDimension::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ScalableDimension {
+    fn default() -> ScalableDimension {
+        ScalableDimension::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ScalableDimension {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ScalableDimension {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ScalableDimension {
+    fn from(value: &'a str) -> ScalableDimension {
+        match value {
+            "autoscaling:autoScalingGroup:DesiredCapacity" => {
+                ScalableDimension::AutoscalingAutoScalingGroupDesiredCapacity
+            }
+            "ecs:service:DesiredCount" => ScalableDimension::EcsServiceDesiredCount,
+            "ec2:spot-fleet-request:TargetCapacity" => {
+                ScalableDimension::Ec2SpotFleetRequestTargetCapacity
+            }
+            "rds:cluster:ReadReplicaCount" => ScalableDimension::RdsClusterReadReplicaCount,
+            "dynamodb:table:ReadCapacityUnits" => ScalableDimension::DynamodbTableReadCapacityUnits,
+            "dynamodb:table:WriteCapacityUnits" => {
+                ScalableDimension::DynamodbTableWriteCapacityUnits
+            }
+            "dynamodb:index:ReadCapacityUnits" => ScalableDimension::DynamodbIndexReadCapacityUnits,
+            "dynamodb:index:WriteCapacityUnits" => {
+                ScalableDimension::DynamodbIndexWriteCapacityUnits
+            }
+            _ => ScalableDimension::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ScalableDimension {
+    fn from(value: String) -> ScalableDimension {
+        match value.as_str() {
+            "autoscaling:autoScalingGroup:DesiredCapacity" => {
+                ScalableDimension::AutoscalingAutoScalingGroupDesiredCapacity
+            }
+            "ecs:service:DesiredCount" => ScalableDimension::EcsServiceDesiredCount,
+            "ec2:spot-fleet-request:TargetCapacity" => {
+                ScalableDimension::Ec2SpotFleetRequestTargetCapacity
+            }
+            "rds:cluster:ReadReplicaCount" => ScalableDimension::RdsClusterReadReplicaCount,
+            "dynamodb:table:ReadCapacityUnits" => ScalableDimension::DynamodbTableReadCapacityUnits,
+            "dynamodb:table:WriteCapacityUnits" => {
+                ScalableDimension::DynamodbTableWriteCapacityUnits
+            }
+            "dynamodb:index:ReadCapacityUnits" => ScalableDimension::DynamodbIndexReadCapacityUnits,
+            "dynamodb:index:WriteCapacityUnits" => {
+                ScalableDimension::DynamodbIndexWriteCapacityUnits
+            }
+            _ => ScalableDimension::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ScalableDimension {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ScalableDimension, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ScalableDimension {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ScalableDimension {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ScalableDimension {
+    fn deserialize<D>(deserializer: D) -> Result<ScalableDimension, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes a scaling instruction for a scalable resource.</p> <p>The scaling instruction is used in combination with a scaling plan, which is a set of instructions for configuring dynamic scaling and predictive scaling for the scalable resources in your application. Each scaling instruction applies to one resource.</p> <p>AWS Auto Scaling creates target tracking scaling policies based on the scaling instructions. Target tracking scaling policies adjust the capacity of your scalable resource as required to maintain resource utilization at the target value that you specified. </p> <p>AWS Auto Scaling also configures predictive scaling for your Amazon EC2 Auto Scaling groups using a subset of parameters, including the load metric, the scaling metric, the target value for the scaling metric, the predictive scaling mode (forecast and scale or forecast only), and the desired behavior when the forecast capacity exceeds the maximum capacity of the resource. With predictive scaling, AWS Auto Scaling generates forecasts with traffic predictions for the two days ahead and schedules scaling actions that proactively add and remove resource capacity to match the forecast. </p> <p>We recommend waiting a minimum of 24 hours after creating an Auto Scaling group to configure predictive scaling. At minimum, there must be 24 hours of historical data to generate a forecast.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/plans/userguide/auto-scaling-getting-started.html">Getting Started with AWS Auto Scaling</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ScalingInstruction {
@@ -298,7 +1071,7 @@ pub struct ScalingInstruction {
     /// <p>Defines the behavior that should be applied if the forecast capacity approaches or exceeds the maximum capacity specified for the resource. The default value is <code>SetForecastCapacityToMaxCapacity</code>.</p> <p>The following are possible values:</p> <ul> <li> <p> <code>SetForecastCapacityToMaxCapacity</code> - AWS Auto Scaling cannot scale resource capacity higher than the maximum capacity. The maximum capacity is enforced as a hard limit. </p> </li> <li> <p> <code>SetMaxCapacityToForecastCapacity</code> - AWS Auto Scaling may scale resource capacity higher than the maximum capacity to equal but not exceed forecast capacity.</p> </li> <li> <p> <code>SetMaxCapacityAboveForecastCapacity</code> - AWS Auto Scaling may scale resource capacity higher than the maximum capacity by a specified buffer value. The intention is to give the target tracking scaling policy extra capacity if unexpected traffic occurs. </p> </li> </ul> <p>Only valid when configuring predictive scaling.</p>
     #[serde(rename = "PredictiveScalingMaxCapacityBehavior")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub predictive_scaling_max_capacity_behavior: Option<String>,
+    pub predictive_scaling_max_capacity_behavior: Option<PredictiveScalingMaxCapacityBehavior>,
     /// <p>The size of the capacity buffer to use when the forecast capacity is close to or exceeds the maximum capacity. The value is specified as a percentage relative to the forecast capacity. For example, if the buffer is 10, this means a 10 percent buffer, such that if the forecast capacity is 50, and the maximum capacity is 40, then the effective maximum capacity is 55.</p> <p>Only valid when configuring predictive scaling. Required if the <b>PredictiveScalingMaxCapacityBehavior</b> is set to <code>SetMaxCapacityAboveForecastCapacity</code>, and cannot be used otherwise.</p> <p>The range is 1-100.</p>
     #[serde(rename = "PredictiveScalingMaxCapacityBuffer")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -306,29 +1079,213 @@ pub struct ScalingInstruction {
     /// <p>The predictive scaling mode. The default value is <code>ForecastAndScale</code>. Otherwise, AWS Auto Scaling forecasts capacity but does not create any scheduled scaling actions based on the capacity forecast. </p>
     #[serde(rename = "PredictiveScalingMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub predictive_scaling_mode: Option<String>,
+    pub predictive_scaling_mode: Option<PredictiveScalingMode>,
     /// <p><p>The ID of the resource. This string consists of the resource type and unique identifier.</p> <ul> <li> <p>Auto Scaling group - The resource type is <code>autoScalingGroup</code> and the unique identifier is the name of the Auto Scaling group. Example: <code>autoScalingGroup/my-asg</code>.</p> </li> <li> <p>ECS service - The resource type is <code>service</code> and the unique identifier is the cluster name and service name. Example: <code>service/default/sample-webapp</code>.</p> </li> <li> <p>Spot Fleet request - The resource type is <code>spot-fleet-request</code> and the unique identifier is the Spot Fleet request ID. Example: <code>spot-fleet-request/sfr-73fbd2ce-aa30-494c-8788-1cee4EXAMPLE</code>.</p> </li> <li> <p>DynamoDB table - The resource type is <code>table</code> and the unique identifier is the resource ID. Example: <code>table/my-table</code>.</p> </li> <li> <p>DynamoDB global secondary index - The resource type is <code>index</code> and the unique identifier is the resource ID. Example: <code>table/my-table/index/my-table-index</code>.</p> </li> <li> <p>Aurora DB cluster - The resource type is <code>cluster</code> and the unique identifier is the cluster name. Example: <code>cluster:my-db-cluster</code>.</p> </li> </ul></p>
     #[serde(rename = "ResourceId")]
     pub resource_id: String,
     /// <p><p>The scalable dimension associated with the resource.</p> <ul> <li> <p> <code>autoscaling:autoScalingGroup:DesiredCapacity</code> - The desired capacity of an Auto Scaling group.</p> </li> <li> <p> <code>ecs:service:DesiredCount</code> - The desired task count of an ECS service.</p> </li> <li> <p> <code>ec2:spot-fleet-request:TargetCapacity</code> - The target capacity of a Spot Fleet request.</p> </li> <li> <p> <code>dynamodb:table:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:table:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:index:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>dynamodb:index:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>rds:cluster:ReadReplicaCount</code> - The count of Aurora Replicas in an Aurora DB cluster. Available for Aurora MySQL-compatible edition and Aurora PostgreSQL-compatible edition.</p> </li> </ul></p>
     #[serde(rename = "ScalableDimension")]
-    pub scalable_dimension: String,
+    pub scalable_dimension: ScalableDimension,
     /// <p>Controls whether a resource's externally created scaling policies are kept or replaced. </p> <p>The default value is <code>KeepExternalPolicies</code>. If the parameter is set to <code>ReplaceExternalPolicies</code>, any scaling policies that are external to AWS Auto Scaling are deleted and new target tracking scaling policies created. </p> <p>Only valid when configuring dynamic scaling. </p> <p>Condition: The number of existing policies to be replaced must be less than or equal to 50. If there are more than 50 policies to be replaced, AWS Auto Scaling keeps all existing policies and does not create new ones.</p>
     #[serde(rename = "ScalingPolicyUpdateBehavior")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scaling_policy_update_behavior: Option<String>,
+    pub scaling_policy_update_behavior: Option<ScalingPolicyUpdateBehavior>,
     /// <p>The amount of time, in seconds, to buffer the run time of scheduled scaling actions when scaling out. For example, if the forecast says to add capacity at 10:00 AM, and the buffer time is 5 minutes, then the run time of the corresponding scheduled scaling action will be 9:55 AM. The intention is to give resources time to be provisioned. For example, it can take a few minutes to launch an EC2 instance. The actual amount of time required depends on several factors, such as the size of the instance and whether there are startup scripts to complete. </p> <p>The value must be less than the forecast interval duration of 3600 seconds (60 minutes). The default is 300 seconds. </p> <p>Only valid when configuring predictive scaling. </p>
     #[serde(rename = "ScheduledActionBufferTime")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub scheduled_action_buffer_time: Option<i64>,
     /// <p>The namespace of the AWS service.</p>
     #[serde(rename = "ServiceNamespace")]
-    pub service_namespace: String,
+    pub service_namespace: ServiceNamespace,
     /// <p>The structure that defines new target tracking configurations (up to 10). Each of these structures includes a specific scaling metric and a target value for the metric, along with various parameters to use with dynamic scaling. </p> <p>With predictive scaling and dynamic scaling, the resource scales based on the target tracking configuration that provides the largest capacity for both scale in and scale out. </p> <p>Condition: The scaling metric must be unique across target tracking configurations.</p>
     #[serde(rename = "TargetTrackingConfigurations")]
     pub target_tracking_configurations: Vec<TargetTrackingConfiguration>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ScalingMetricType {
+    /// `ASGAverageCPUUtilization`
+    ASGAverageCPUUtilization,
+    /// `ASGAverageNetworkIn`
+    ASGAverageNetworkIn,
+    /// `ASGAverageNetworkOut`
+    ASGAverageNetworkOut,
+    /// `DynamoDBReadCapacityUtilization`
+    DynamoDBReadCapacityUtilization,
+    /// `DynamoDBWriteCapacityUtilization`
+    DynamoDBWriteCapacityUtilization,
+    /// `ECSServiceAverageCPUUtilization`
+    ECSServiceAverageCPUUtilization,
+    /// `ECSServiceAverageMemoryUtilization`
+    ECSServiceAverageMemoryUtilization,
+    /// `ALBRequestCountPerTarget`
+    ALBRequestCountPerTarget,
+    /// `RDSReaderAverageCPUUtilization`
+    RDSReaderAverageCPUUtilization,
+    /// `RDSReaderAverageDatabaseConnections`
+    RDSReaderAverageDatabaseConnections,
+    /// `EC2SpotFleetRequestAverageCPUUtilization`
+    EC2SpotFleetRequestAverageCPUUtilization,
+    /// `EC2SpotFleetRequestAverageNetworkIn`
+    EC2SpotFleetRequestAverageNetworkIn,
+    /// `EC2SpotFleetRequestAverageNetworkOut`
+    EC2SpotFleetRequestAverageNetworkOut,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ScalingMetricType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ScalingMetricType::ASGAverageCPUUtilization => "ASGAverageCPUUtilization",
+            ScalingMetricType::ASGAverageNetworkIn => "ASGAverageNetworkIn",
+            ScalingMetricType::ASGAverageNetworkOut => "ASGAverageNetworkOut",
+            ScalingMetricType::DynamoDBReadCapacityUtilization => "DynamoDBReadCapacityUtilization",
+            ScalingMetricType::DynamoDBWriteCapacityUtilization => {
+                "DynamoDBWriteCapacityUtilization"
+            }
+            ScalingMetricType::ECSServiceAverageCPUUtilization => "ECSServiceAverageCPUUtilization",
+            ScalingMetricType::ECSServiceAverageMemoryUtilization => {
+                "ECSServiceAverageMemoryUtilization"
+            }
+            ScalingMetricType::ALBRequestCountPerTarget => "ALBRequestCountPerTarget",
+            ScalingMetricType::RDSReaderAverageCPUUtilization => "RDSReaderAverageCPUUtilization",
+            ScalingMetricType::RDSReaderAverageDatabaseConnections => {
+                "RDSReaderAverageDatabaseConnections"
+            }
+            ScalingMetricType::EC2SpotFleetRequestAverageCPUUtilization => {
+                "EC2SpotFleetRequestAverageCPUUtilization"
+            }
+            ScalingMetricType::EC2SpotFleetRequestAverageNetworkIn => {
+                "EC2SpotFleetRequestAverageNetworkIn"
+            }
+            ScalingMetricType::EC2SpotFleetRequestAverageNetworkOut => {
+                "EC2SpotFleetRequestAverageNetworkOut"
+            }
+            ScalingMetricType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ScalingMetricType {
+    fn default() -> ScalingMetricType {
+        ScalingMetricType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ScalingMetricType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ScalingMetricType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ScalingMetricType {
+    fn from(value: &'a str) -> ScalingMetricType {
+        match value {
+            "ASGAverageCPUUtilization" => ScalingMetricType::ASGAverageCPUUtilization,
+            "ASGAverageNetworkIn" => ScalingMetricType::ASGAverageNetworkIn,
+            "ASGAverageNetworkOut" => ScalingMetricType::ASGAverageNetworkOut,
+            "DynamoDBReadCapacityUtilization" => ScalingMetricType::DynamoDBReadCapacityUtilization,
+            "DynamoDBWriteCapacityUtilization" => {
+                ScalingMetricType::DynamoDBWriteCapacityUtilization
+            }
+            "ECSServiceAverageCPUUtilization" => ScalingMetricType::ECSServiceAverageCPUUtilization,
+            "ECSServiceAverageMemoryUtilization" => {
+                ScalingMetricType::ECSServiceAverageMemoryUtilization
+            }
+            "ALBRequestCountPerTarget" => ScalingMetricType::ALBRequestCountPerTarget,
+            "RDSReaderAverageCPUUtilization" => ScalingMetricType::RDSReaderAverageCPUUtilization,
+            "RDSReaderAverageDatabaseConnections" => {
+                ScalingMetricType::RDSReaderAverageDatabaseConnections
+            }
+            "EC2SpotFleetRequestAverageCPUUtilization" => {
+                ScalingMetricType::EC2SpotFleetRequestAverageCPUUtilization
+            }
+            "EC2SpotFleetRequestAverageNetworkIn" => {
+                ScalingMetricType::EC2SpotFleetRequestAverageNetworkIn
+            }
+            "EC2SpotFleetRequestAverageNetworkOut" => {
+                ScalingMetricType::EC2SpotFleetRequestAverageNetworkOut
+            }
+            _ => ScalingMetricType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ScalingMetricType {
+    fn from(value: String) -> ScalingMetricType {
+        match value.as_str() {
+            "ASGAverageCPUUtilization" => ScalingMetricType::ASGAverageCPUUtilization,
+            "ASGAverageNetworkIn" => ScalingMetricType::ASGAverageNetworkIn,
+            "ASGAverageNetworkOut" => ScalingMetricType::ASGAverageNetworkOut,
+            "DynamoDBReadCapacityUtilization" => ScalingMetricType::DynamoDBReadCapacityUtilization,
+            "DynamoDBWriteCapacityUtilization" => {
+                ScalingMetricType::DynamoDBWriteCapacityUtilization
+            }
+            "ECSServiceAverageCPUUtilization" => ScalingMetricType::ECSServiceAverageCPUUtilization,
+            "ECSServiceAverageMemoryUtilization" => {
+                ScalingMetricType::ECSServiceAverageMemoryUtilization
+            }
+            "ALBRequestCountPerTarget" => ScalingMetricType::ALBRequestCountPerTarget,
+            "RDSReaderAverageCPUUtilization" => ScalingMetricType::RDSReaderAverageCPUUtilization,
+            "RDSReaderAverageDatabaseConnections" => {
+                ScalingMetricType::RDSReaderAverageDatabaseConnections
+            }
+            "EC2SpotFleetRequestAverageCPUUtilization" => {
+                ScalingMetricType::EC2SpotFleetRequestAverageCPUUtilization
+            }
+            "EC2SpotFleetRequestAverageNetworkIn" => {
+                ScalingMetricType::EC2SpotFleetRequestAverageNetworkIn
+            }
+            "EC2SpotFleetRequestAverageNetworkOut" => {
+                ScalingMetricType::EC2SpotFleetRequestAverageNetworkOut
+            }
+            _ => ScalingMetricType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ScalingMetricType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ScalingMetricType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ScalingMetricType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ScalingMetricType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ScalingMetricType {
+    fn deserialize<D>(deserializer: D) -> Result<ScalingMetricType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents a scaling plan.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -338,8 +1295,13 @@ pub struct ScalingPlan {
     pub application_source: ApplicationSource,
     /// <p>The Unix time stamp when the scaling plan was created.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The scaling instructions.</p>
     #[serde(rename = "ScalingInstructions")]
     pub scaling_instructions: Vec<ScalingInstruction>,
@@ -351,15 +1313,20 @@ pub struct ScalingPlan {
     pub scaling_plan_version: i64,
     /// <p><p>The status of the scaling plan.</p> <ul> <li> <p> <code>Active</code> - The scaling plan is active.</p> </li> <li> <p> <code>ActiveWithProblems</code> - The scaling plan is active, but the scaling configuration for one or more resources could not be applied.</p> </li> <li> <p> <code>CreationInProgress</code> - The scaling plan is being created.</p> </li> <li> <p> <code>CreationFailed</code> - The scaling plan could not be created.</p> </li> <li> <p> <code>DeletionInProgress</code> - The scaling plan is being deleted.</p> </li> <li> <p> <code>DeletionFailed</code> - The scaling plan could not be deleted.</p> </li> <li> <p> <code>UpdateInProgress</code> - The scaling plan is being updated.</p> </li> <li> <p> <code>UpdateFailed</code> - The scaling plan could not be updated.</p> </li> </ul></p>
     #[serde(rename = "StatusCode")]
-    pub status_code: String,
+    pub status_code: ScalingPlanStatusCode,
     /// <p>A simple message about the current status of the scaling plan.</p>
     #[serde(rename = "StatusMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status_message: Option<String>,
     /// <p>The Unix time stamp when the scaling plan entered the current status.</p>
     #[serde(rename = "StatusStartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status_start_time: Option<f64>,
+    pub status_start_time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>Represents a scalable resource.</p>
@@ -371,7 +1338,7 @@ pub struct ScalingPlanResource {
     pub resource_id: String,
     /// <p><p>The scalable dimension for the resource.</p> <ul> <li> <p> <code>autoscaling:autoScalingGroup:DesiredCapacity</code> - The desired capacity of an Auto Scaling group.</p> </li> <li> <p> <code>ecs:service:DesiredCount</code> - The desired task count of an ECS service.</p> </li> <li> <p> <code>ec2:spot-fleet-request:TargetCapacity</code> - The target capacity of a Spot Fleet request.</p> </li> <li> <p> <code>dynamodb:table:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:table:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:index:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>dynamodb:index:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>rds:cluster:ReadReplicaCount</code> - The count of Aurora Replicas in an Aurora DB cluster. Available for Aurora MySQL-compatible edition and Aurora PostgreSQL-compatible edition.</p> </li> </ul></p>
     #[serde(rename = "ScalableDimension")]
-    pub scalable_dimension: String,
+    pub scalable_dimension: ScalableDimension,
     /// <p>The name of the scaling plan.</p>
     #[serde(rename = "ScalingPlanName")]
     pub scaling_plan_name: String,
@@ -384,16 +1351,139 @@ pub struct ScalingPlanResource {
     pub scaling_policies: Option<Vec<ScalingPolicy>>,
     /// <p><p>The scaling status of the resource.</p> <ul> <li> <p> <code>Active</code> - The scaling configuration is active.</p> </li> <li> <p> <code>Inactive</code> - The scaling configuration is not active because the scaling plan is being created or the scaling configuration could not be applied. Check the status message for more information.</p> </li> <li> <p> <code>PartiallyActive</code> - The scaling configuration is partially active because the scaling plan is being created or deleted or the scaling configuration could not be fully applied. Check the status message for more information.</p> </li> </ul></p>
     #[serde(rename = "ScalingStatusCode")]
-    pub scaling_status_code: String,
+    pub scaling_status_code: ScalingStatusCode,
     /// <p>A simple message about the current scaling status of the resource.</p>
     #[serde(rename = "ScalingStatusMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub scaling_status_message: Option<String>,
     /// <p>The namespace of the AWS service.</p>
     #[serde(rename = "ServiceNamespace")]
-    pub service_namespace: String,
+    pub service_namespace: ServiceNamespace,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ScalingPlanStatusCode {
+    /// `Active`
+    Active,
+    /// `ActiveWithProblems`
+    ActiveWithProblems,
+    /// `CreationInProgress`
+    CreationInProgress,
+    /// `CreationFailed`
+    CreationFailed,
+    /// `DeletionInProgress`
+    DeletionInProgress,
+    /// `DeletionFailed`
+    DeletionFailed,
+    /// `UpdateInProgress`
+    UpdateInProgress,
+    /// `UpdateFailed`
+    UpdateFailed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ScalingPlanStatusCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ScalingPlanStatusCode::Active => "Active",
+            ScalingPlanStatusCode::ActiveWithProblems => "ActiveWithProblems",
+            ScalingPlanStatusCode::CreationInProgress => "CreationInProgress",
+            ScalingPlanStatusCode::CreationFailed => "CreationFailed",
+            ScalingPlanStatusCode::DeletionInProgress => "DeletionInProgress",
+            ScalingPlanStatusCode::DeletionFailed => "DeletionFailed",
+            ScalingPlanStatusCode::UpdateInProgress => "UpdateInProgress",
+            ScalingPlanStatusCode::UpdateFailed => "UpdateFailed",
+            ScalingPlanStatusCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ScalingPlanStatusCode {
+    fn default() -> ScalingPlanStatusCode {
+        ScalingPlanStatusCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ScalingPlanStatusCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ScalingPlanStatusCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ScalingPlanStatusCode {
+    fn from(value: &'a str) -> ScalingPlanStatusCode {
+        match value {
+            "Active" => ScalingPlanStatusCode::Active,
+            "ActiveWithProblems" => ScalingPlanStatusCode::ActiveWithProblems,
+            "CreationInProgress" => ScalingPlanStatusCode::CreationInProgress,
+            "CreationFailed" => ScalingPlanStatusCode::CreationFailed,
+            "DeletionInProgress" => ScalingPlanStatusCode::DeletionInProgress,
+            "DeletionFailed" => ScalingPlanStatusCode::DeletionFailed,
+            "UpdateInProgress" => ScalingPlanStatusCode::UpdateInProgress,
+            "UpdateFailed" => ScalingPlanStatusCode::UpdateFailed,
+            _ => ScalingPlanStatusCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ScalingPlanStatusCode {
+    fn from(value: String) -> ScalingPlanStatusCode {
+        match value.as_str() {
+            "Active" => ScalingPlanStatusCode::Active,
+            "ActiveWithProblems" => ScalingPlanStatusCode::ActiveWithProblems,
+            "CreationInProgress" => ScalingPlanStatusCode::CreationInProgress,
+            "CreationFailed" => ScalingPlanStatusCode::CreationFailed,
+            "DeletionInProgress" => ScalingPlanStatusCode::DeletionInProgress,
+            "DeletionFailed" => ScalingPlanStatusCode::DeletionFailed,
+            "UpdateInProgress" => ScalingPlanStatusCode::UpdateInProgress,
+            "UpdateFailed" => ScalingPlanStatusCode::UpdateFailed,
+            _ => ScalingPlanStatusCode::Unknown(value),
+        }
+    }
 }
 
+impl ::std::str::FromStr for ScalingPlanStatusCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ScalingPlanStatusCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ScalingPlanStatusCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ScalingPlanStatusCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ScalingPlanStatusCode {
+    fn deserialize<D>(deserializer: D) -> Result<ScalingPlanStatusCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents a scaling policy.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -403,13 +1493,312 @@ pub struct ScalingPolicy {
     pub policy_name: String,
     /// <p>The type of scaling policy.</p>
     #[serde(rename = "PolicyType")]
-    pub policy_type: String,
+    pub policy_type: PolicyType,
     /// <p>The target tracking scaling policy. Includes support for predefined or customized metrics.</p>
     #[serde(rename = "TargetTrackingConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub target_tracking_configuration: Option<TargetTrackingConfiguration>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ScalingPolicyUpdateBehavior {
+    /// `KeepExternalPolicies`
+    KeepExternalPolicies,
+    /// `ReplaceExternalPolicies`
+    ReplaceExternalPolicies,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ScalingPolicyUpdateBehavior {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ScalingPolicyUpdateBehavior::KeepExternalPolicies => "KeepExternalPolicies",
+            ScalingPolicyUpdateBehavior::ReplaceExternalPolicies => "ReplaceExternalPolicies",
+            ScalingPolicyUpdateBehavior::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ScalingPolicyUpdateBehavior {
+    fn default() -> ScalingPolicyUpdateBehavior {
+        ScalingPolicyUpdateBehavior::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ScalingPolicyUpdateBehavior {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ScalingPolicyUpdateBehavior {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ScalingPolicyUpdateBehavior {
+    fn from(value: &'a str) -> ScalingPolicyUpdateBehavior {
+        match value {
+            "KeepExternalPolicies" => ScalingPolicyUpdateBehavior::KeepExternalPolicies,
+            "ReplaceExternalPolicies" => ScalingPolicyUpdateBehavior::ReplaceExternalPolicies,
+            _ => ScalingPolicyUpdateBehavior::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ScalingPolicyUpdateBehavior {
+    fn from(value: String) -> ScalingPolicyUpdateBehavior {
+        match value.as_str() {
+            "KeepExternalPolicies" => ScalingPolicyUpdateBehavior::KeepExternalPolicies,
+            "ReplaceExternalPolicies" => ScalingPolicyUpdateBehavior::ReplaceExternalPolicies,
+            _ => ScalingPolicyUpdateBehavior::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ScalingPolicyUpdateBehavior {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ScalingPolicyUpdateBehavior, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ScalingPolicyUpdateBehavior {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ScalingPolicyUpdateBehavior {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ScalingPolicyUpdateBehavior {
+    fn deserialize<D>(deserializer: D) -> Result<ScalingPolicyUpdateBehavior, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ScalingStatusCode {
+    /// `Inactive`
+    Inactive,
+    /// `PartiallyActive`
+    PartiallyActive,
+    /// `Active`
+    Active,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ScalingStatusCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ScalingStatusCode::Inactive => "Inactive",
+            ScalingStatusCode::PartiallyActive => "PartiallyActive",
+            ScalingStatusCode::Active => "Active",
+            ScalingStatusCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ScalingStatusCode {
+    fn default() -> ScalingStatusCode {
+        ScalingStatusCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ScalingStatusCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ScalingStatusCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ScalingStatusCode {
+    fn from(value: &'a str) -> ScalingStatusCode {
+        match value {
+            "Inactive" => ScalingStatusCode::Inactive,
+            "PartiallyActive" => ScalingStatusCode::PartiallyActive,
+            "Active" => ScalingStatusCode::Active,
+            _ => ScalingStatusCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ScalingStatusCode {
+    fn from(value: String) -> ScalingStatusCode {
+        match value.as_str() {
+            "Inactive" => ScalingStatusCode::Inactive,
+            "PartiallyActive" => ScalingStatusCode::PartiallyActive,
+            "Active" => ScalingStatusCode::Active,
+            _ => ScalingStatusCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ScalingStatusCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ScalingStatusCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ScalingStatusCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ScalingStatusCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ScalingStatusCode {
+    fn deserialize<D>(deserializer: D) -> Result<ScalingStatusCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ServiceNamespace {
+    /// `autoscaling`
+    Autoscaling,
+    /// `ecs`
+    Ecs,
+    /// `ec2`
+    Ec2,
+    /// `rds`
+    Rds,
+    /// `dynamodb`
+    Dynamodb,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ServiceNamespace {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ServiceNamespace::Autoscaling => "autoscaling",
+            ServiceNamespace::Ecs => "ecs",
+            ServiceNamespace::Ec2 => "ec2",
+            ServiceNamespace::Rds => "rds",
+            ServiceNamespace::Dynamodb => "dynamodb",
+            ServiceNamespace::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ServiceNamespace {
+    fn default() -> ServiceNamespace {
+        ServiceNamespace::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ServiceNamespace {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ServiceNamespace {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ServiceNamespace {
+    fn from(value: &'a str) -> ServiceNamespace {
+        match value {
+            "autoscaling" => ServiceNamespace::Autoscaling,
+            "ecs" => ServiceNamespace::Ecs,
+            "ec2" => ServiceNamespace::Ec2,
+            "rds" => ServiceNamespace::Rds,
+            "dynamodb" => ServiceNamespace::Dynamodb,
+            _ => ServiceNamespace::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ServiceNamespace {
+    fn from(value: String) -> ServiceNamespace {
+        match value.as_str() {
+            "autoscaling" => ServiceNamespace::Autoscaling,
+            "ecs" => ServiceNamespace::Ecs,
+            "ec2" => ServiceNamespace::Ec2,
+            "rds" => ServiceNamespace::Rds,
+            "dynamodb" => ServiceNamespace::Dynamodb,
+            _ => ServiceNamespace::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ServiceNamespace {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ServiceNamespace, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ServiceNamespace {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ServiceNamespace {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ServiceNamespace {
+    fn deserialize<D>(deserializer: D) -> Result<ServiceNamespace, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents a tag.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TagFilter {
@@ -794,11 +2183,12 @@ pub struct AutoscalingPlansClient {
 impl AutoscalingPlansClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> AutoscalingPlansClient {
         AutoscalingPlansClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Auto Scaling Plans"),
         }
     }
 
@@ -830,6 +2220,7 @@ impl AutoscalingPlans for AutoscalingPlansClient {
         input: CreateScalingPlanRequest,
     ) -> Result<CreateScalingPlanResponse, RusotoError<CreateScalingPlanError>> {
         let mut request = SignedRequest::new("POST", "autoscaling-plans", &self.region, "/");
+        request.set_operation("CreateScalingPlan");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -861,6 +2252,7 @@ impl AutoscalingPlans for AutoscalingPlansClient {
         input: DeleteScalingPlanRequest,
     ) -> Result<DeleteScalingPlanResponse, RusotoError<DeleteScalingPlanError>> {
         let mut request = SignedRequest::new("POST", "autoscaling-plans", &self.region, "/");
+        request.set_operation("DeleteScalingPlan");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -893,6 +2285,7 @@ impl AutoscalingPlans for AutoscalingPlansClient {
     ) -> Result<DescribeScalingPlanResourcesResponse, RusotoError<DescribeScalingPlanResourcesError>>
     {
         let mut request = SignedRequest::new("POST", "autoscaling-plans", &self.region, "/");
+        request.set_operation("DescribeScalingPlanResources");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -924,6 +2317,7 @@ impl AutoscalingPlans for AutoscalingPlansClient {
         input: DescribeScalingPlansRequest,
     ) -> Result<DescribeScalingPlansResponse, RusotoError<DescribeScalingPlansError>> {
         let mut request = SignedRequest::new("POST", "autoscaling-plans", &self.region, "/");
+        request.set_operation("DescribeScalingPlans");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -958,6 +2352,7 @@ impl AutoscalingPlans for AutoscalingPlansClient {
         RusotoError<GetScalingPlanResourceForecastDataError>,
     > {
         let mut request = SignedRequest::new("POST", "autoscaling-plans", &self.region, "/");
+        request.set_operation("GetScalingPlanResourceForecastData");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -991,6 +2386,7 @@ impl AutoscalingPlans for AutoscalingPlansClient {
         input: UpdateScalingPlanRequest,
     ) -> Result<UpdateScalingPlanResponse, RusotoError<UpdateScalingPlanError>> {
         let mut request = SignedRequest::new("POST", "autoscaling-plans", &self.region, "/");
+        request.set_operation("UpdateScalingPlan");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1016,3 +2412,76 @@ impl AutoscalingPlans for AutoscalingPlansClient {
         }
     }
 }
+impl AutoscalingPlansClient {
+    /// Auto-paginating version of `describe_scaling_plan_resources`, streaming every page of results.
+    pub fn describe_scaling_plan_resources_pages(
+        &self,
+        input: DescribeScalingPlanResourcesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeScalingPlanResourcesResponse,
+        DescribeScalingPlanResourcesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AutoscalingPlansClient, input: DescribeScalingPlanResourcesRequest| async move {
+                client.describe_scaling_plan_resources(input).await
+            },
+            |input: &DescribeScalingPlanResourcesRequest,
+             output: &DescribeScalingPlanResourcesResponse| {
+                Some(DescribeScalingPlanResourcesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_scaling_plan_resources`, streaming the `ScalingPlanResources` of every page of results.
+    pub fn describe_scaling_plan_resources_items(
+        &self,
+        input: DescribeScalingPlanResourcesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ScalingPlanResource, DescribeScalingPlanResourcesError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_scaling_plan_resources_pages(input),
+            |output: DescribeScalingPlanResourcesResponse| {
+                output.scaling_plan_resources.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_scaling_plans`, streaming every page of results.
+    pub fn describe_scaling_plans_pages(
+        &self,
+        input: DescribeScalingPlansRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeScalingPlansResponse,
+        DescribeScalingPlansError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AutoscalingPlansClient, input: DescribeScalingPlansRequest| async move {
+                client.describe_scaling_plans(input).await
+            },
+            |input: &DescribeScalingPlansRequest, output: &DescribeScalingPlansResponse| {
+                Some(DescribeScalingPlansRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_scaling_plans`, streaming the `ScalingPlans` of every page of results.
+    pub fn describe_scaling_plans_items(
+        &self,
+        input: DescribeScalingPlansRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ScalingPlan, DescribeScalingPlansError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_scaling_plans_pages(input),
+            |output: DescribeScalingPlansResponse| output.scaling_plans.unwrap_or_default(),
+        )
+    }
+}
@@ -95,13 +95,13 @@ pub struct Activity {
     /// <p>The details about the activity.</p>
     pub details: Option<String>,
     /// <p>The end time of the activity.</p>
-    pub end_time: Option<String>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A value between 0 and 100 that indicates the progress of the activity.</p>
     pub progress: Option<i64>,
     /// <p>The start time of the activity.</p>
-    pub start_time: String,
+    pub start_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The current status of the activity.</p>
-    pub status_code: String,
+    pub status_code: ScalingActivityStatusCode,
     /// <p>A friendly, more verbose description of the activity status.</p>
     pub status_message: Option<String>,
 }
@@ -453,7 +453,7 @@ pub struct AutoScalingGroup {
     /// <p>One or more Availability Zones for the group.</p>
     pub availability_zones: Vec<String>,
     /// <p>The date and time the group was created.</p>
-    pub created_time: String,
+    pub created_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The amount of time, in seconds, after a scaling activity completes before another scaling activity can start.</p>
     pub default_cooldown: i64,
     /// <p>The desired size of the group.</p>
@@ -1731,7 +1731,7 @@ pub struct CustomizedMetricSpecification {
     /// <p>The namespace of the metric.</p>
     pub namespace: String,
     /// <p>The statistic of the metric.</p>
-    pub statistic: String,
+    pub statistic: MetricStatistic,
     /// <p>The unit of the metric.</p>
     pub unit: Option<String>,
 }
@@ -2601,7 +2601,7 @@ pub struct DescribeScheduledActionsType {
     /// <p>The name of the Auto Scaling group.</p>
     pub auto_scaling_group_name: Option<String>,
     /// <p>The latest scheduled start time to return. If scheduled action names are provided, this parameter is ignored.</p>
-    pub end_time: Option<String>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The maximum number of items to return with this call. The default value is <code>50</code> and the maximum value is <code>100</code>.</p>
     pub max_records: Option<i64>,
     /// <p>The token for the next set of items to return. (You received this token from a previous call.)</p>
@@ -2609,7 +2609,7 @@ pub struct DescribeScheduledActionsType {
     /// <p>The names of one or more scheduled actions. You can specify up to 50 actions. If you omit this parameter, all scheduled actions are described. If you specify an unknown scheduled action, it is ignored with no error.</p>
     pub scheduled_action_names: Option<Vec<String>>,
     /// <p>The earliest scheduled start time to return. If scheduled action names are provided, this parameter is ignored.</p>
-    pub start_time: Option<String>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// Serialize `DescribeScheduledActionsType` contents to a `SignedRequest`.
@@ -2628,7 +2628,10 @@ impl DescribeScheduledActionsTypeSerializer {
             );
         }
         if let Some(ref field_value) = obj.end_time {
-            params.put(&format!("{}{}", prefix, "EndTime"), &field_value);
+            params.put(
+                &format!("{}{}", prefix, "EndTime"),
+                &rusoto_core::serialization::TimestampFormat::Iso8601.format(field_value),
+            );
         }
         if let Some(ref field_value) = obj.max_records {
             params.put(&format!("{}{}", prefix, "MaxRecords"), &field_value);
@@ -2644,7 +2647,10 @@ impl DescribeScheduledActionsTypeSerializer {
             );
         }
         if let Some(ref field_value) = obj.start_time {
-            params.put(&format!("{}{}", prefix, "StartTime"), &field_value);
+            params.put(
+                &format!("{}{}", prefix, "StartTime"),
+                &rusoto_core::serialization::TimestampFormat::Iso8601.format(field_value),
+            );
         }
     }
 }
@@ -3448,7 +3454,7 @@ pub struct Instance {
     /// <p>The launch template for the instance.</p>
     pub launch_template: Option<LaunchTemplateSpecification>,
     /// <p>A description of the current lifecycle state. The <code>Quarantined</code> state is not used.</p>
-    pub lifecycle_state: String,
+    pub lifecycle_state: LifecycleState,
     /// <p>Indicates whether the instance is protected from termination by Amazon EC2 Auto Scaling when scaling in.</p>
     pub protected_from_scale_in: bool,
     /// <p>The number of capacity units contributed by the instance based on its instance type.</p> <p>Valid Range: Minimum value of 1. Maximum value of 999.</p>
@@ -3730,7 +3736,7 @@ pub struct LaunchConfiguration {
     /// <p>The IDs of one or more security groups for the VPC specified in <code>ClassicLinkVPCId</code>.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/vpc-classiclink.html">ClassicLink</a> in the <i>Amazon EC2 User Guide for Linux Instances</i> and <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/asg-in-vpc.html#as-ClassicLink">Linking EC2-Classic Instances to a VPC</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
     pub classic_link_vpc_security_groups: Option<Vec<String>>,
     /// <p>The creation date and time for the launch configuration.</p>
-    pub created_time: String,
+    pub created_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>Specifies whether the launch configuration is optimized for EBS I/O (<code>true</code>) or not (<code>false</code>).</p> <p>For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/EBSOptimized.html">Amazon EBS-Optimized Instances</a> in the <i>Amazon EC2 User Guide for Linux Instances</i>.</p>
     pub ebs_optimized: Option<bool>,
     /// <p>The name or the Amazon Resource Name (ARN) of the instance profile associated with the IAM role for the instance. The instance profile contains the IAM role.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/us-iam-role.html">IAM Role for Applications That Run on Amazon EC2 Instances</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
@@ -4418,12 +4424,215 @@ impl LifecycleHooksDeserializer {
         })
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LifecycleState {
+    /// `Pending`
+    Pending,
+    /// `Pending:Wait`
+    PendingWait,
+    /// `Pending:Proceed`
+    PendingProceed,
+    /// `Quarantined`
+    Quarantined,
+    /// `InService`
+    InService,
+    /// `Terminating`
+    Terminating,
+    /// `Terminating:Wait`
+    TerminatingWait,
+    /// `Terminating:Proceed`
+    TerminatingProceed,
+    /// `Terminated`
+    Terminated,
+    /// `Detaching`
+    Detaching,
+    /// `Detached`
+    Detached,
+    /// `EnteringStandby`
+    EnteringStandby,
+    /// `Standby`
+    Standby,
+    /// `Warmed:Pending`
+    WarmedPending,
+    /// `Warmed:Pending:Wait`
+    WarmedPendingWait,
+    /// `Warmed:Pending:Proceed`
+    WarmedPendingProceed,
+    /// `Warmed:Terminating`
+    WarmedTerminating,
+    /// `Warmed:Terminating:Wait`
+    WarmedTerminatingWait,
+    /// `Warmed:Terminating:Proceed`
+    WarmedTerminatingProceed,
+    /// `Warmed:Terminated`
+    WarmedTerminated,
+    /// `Warmed:Stopped`
+    WarmedStopped,
+    /// `Warmed:Running`
+    WarmedRunning,
+    /// `Warmed:Hibernated`
+    WarmedHibernated,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LifecycleState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LifecycleState::Pending => "Pending",
+            LifecycleState::PendingWait => "Pending:Wait",
+            LifecycleState::PendingProceed => "Pending:Proceed",
+            LifecycleState::Quarantined => "Quarantined",
+            LifecycleState::InService => "InService",
+            LifecycleState::Terminating => "Terminating",
+            LifecycleState::TerminatingWait => "Terminating:Wait",
+            LifecycleState::TerminatingProceed => "Terminating:Proceed",
+            LifecycleState::Terminated => "Terminated",
+            LifecycleState::Detaching => "Detaching",
+            LifecycleState::Detached => "Detached",
+            LifecycleState::EnteringStandby => "EnteringStandby",
+            LifecycleState::Standby => "Standby",
+            LifecycleState::WarmedPending => "Warmed:Pending",
+            LifecycleState::WarmedPendingWait => "Warmed:Pending:Wait",
+            LifecycleState::WarmedPendingProceed => "Warmed:Pending:Proceed",
+            LifecycleState::WarmedTerminating => "Warmed:Terminating",
+            LifecycleState::WarmedTerminatingWait => "Warmed:Terminating:Wait",
+            LifecycleState::WarmedTerminatingProceed => "Warmed:Terminating:Proceed",
+            LifecycleState::WarmedTerminated => "Warmed:Terminated",
+            LifecycleState::WarmedStopped => "Warmed:Stopped",
+            LifecycleState::WarmedRunning => "Warmed:Running",
+            LifecycleState::WarmedHibernated => "Warmed:Hibernated",
+            LifecycleState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LifecycleState {
+    fn default() -> LifecycleState {
+        LifecycleState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LifecycleState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LifecycleState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LifecycleState {
+    fn from(value: &'a str) -> LifecycleState {
+        match value {
+            "Pending" => LifecycleState::Pending,
+            "Pending:Wait" => LifecycleState::PendingWait,
+            "Pending:Proceed" => LifecycleState::PendingProceed,
+            "Quarantined" => LifecycleState::Quarantined,
+            "InService" => LifecycleState::InService,
+            "Terminating" => LifecycleState::Terminating,
+            "Terminating:Wait" => LifecycleState::TerminatingWait,
+            "Terminating:Proceed" => LifecycleState::TerminatingProceed,
+            "Terminated" => LifecycleState::Terminated,
+            "Detaching" => LifecycleState::Detaching,
+            "Detached" => LifecycleState::Detached,
+            "EnteringStandby" => LifecycleState::EnteringStandby,
+            "Standby" => LifecycleState::Standby,
+            "Warmed:Pending" => LifecycleState::WarmedPending,
+            "Warmed:Pending:Wait" => LifecycleState::WarmedPendingWait,
+            "Warmed:Pending:Proceed" => LifecycleState::WarmedPendingProceed,
+            "Warmed:Terminating" => LifecycleState::WarmedTerminating,
+            "Warmed:Terminating:Wait" => LifecycleState::WarmedTerminatingWait,
+            "Warmed:Terminating:Proceed" => LifecycleState::WarmedTerminatingProceed,
+            "Warmed:Terminated" => LifecycleState::WarmedTerminated,
+            "Warmed:Stopped" => LifecycleState::WarmedStopped,
+            "Warmed:Running" => LifecycleState::WarmedRunning,
+            "Warmed:Hibernated" => LifecycleState::WarmedHibernated,
+            _ => LifecycleState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LifecycleState {
+    fn from(value: String) -> LifecycleState {
+        match value.as_str() {
+            "Pending" => LifecycleState::Pending,
+            "Pending:Wait" => LifecycleState::PendingWait,
+            "Pending:Proceed" => LifecycleState::PendingProceed,
+            "Quarantined" => LifecycleState::Quarantined,
+            "InService" => LifecycleState::InService,
+            "Terminating" => LifecycleState::Terminating,
+            "Terminating:Wait" => LifecycleState::TerminatingWait,
+            "Terminating:Proceed" => LifecycleState::TerminatingProceed,
+            "Terminated" => LifecycleState::Terminated,
+            "Detaching" => LifecycleState::Detaching,
+            "Detached" => LifecycleState::Detached,
+            "EnteringStandby" => LifecycleState::EnteringStandby,
+            "Standby" => LifecycleState::Standby,
+            "Warmed:Pending" => LifecycleState::WarmedPending,
+            "Warmed:Pending:Wait" => LifecycleState::WarmedPendingWait,
+            "Warmed:Pending:Proceed" => LifecycleState::WarmedPendingProceed,
+            "Warmed:Terminating" => LifecycleState::WarmedTerminating,
+            "Warmed:Terminating:Wait" => LifecycleState::WarmedTerminatingWait,
+            "Warmed:Terminating:Proceed" => LifecycleState::WarmedTerminatingProceed,
+            "Warmed:Terminated" => LifecycleState::WarmedTerminated,
+            "Warmed:Stopped" => LifecycleState::WarmedStopped,
+            "Warmed:Running" => LifecycleState::WarmedRunning,
+            "Warmed:Hibernated" => LifecycleState::WarmedHibernated,
+            _ => LifecycleState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LifecycleState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LifecycleState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LifecycleState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for LifecycleState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for LifecycleState {
+    fn deserialize<D>(deserializer: D) -> Result<LifecycleState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct LifecycleStateDeserializer;
 impl LifecycleStateDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<LifecycleState, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -4840,23 +5049,244 @@ impl MetricScaleDeserializer {
         Ok(obj)
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MetricStatistic {
+    /// `Average`
+    Average,
+    /// `Minimum`
+    Minimum,
+    /// `Maximum`
+    Maximum,
+    /// `SampleCount`
+    SampleCount,
+    /// `Sum`
+    Sum,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl MetricStatistic {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MetricStatistic::Average => "Average",
+            MetricStatistic::Minimum => "Minimum",
+            MetricStatistic::Maximum => "Maximum",
+            MetricStatistic::SampleCount => "SampleCount",
+            MetricStatistic::Sum => "Sum",
+            MetricStatistic::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for MetricStatistic {
+    fn default() -> MetricStatistic {
+        MetricStatistic::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for MetricStatistic {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for MetricStatistic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for MetricStatistic {
+    fn from(value: &'a str) -> MetricStatistic {
+        match value {
+            "Average" => MetricStatistic::Average,
+            "Minimum" => MetricStatistic::Minimum,
+            "Maximum" => MetricStatistic::Maximum,
+            "SampleCount" => MetricStatistic::SampleCount,
+            "Sum" => MetricStatistic::Sum,
+            _ => MetricStatistic::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MetricStatistic {
+    fn from(value: String) -> MetricStatistic {
+        match value.as_str() {
+            "Average" => MetricStatistic::Average,
+            "Minimum" => MetricStatistic::Minimum,
+            "Maximum" => MetricStatistic::Maximum,
+            "SampleCount" => MetricStatistic::SampleCount,
+            "Sum" => MetricStatistic::Sum,
+            _ => MetricStatistic::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for MetricStatistic {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<MetricStatistic, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MetricStatistic {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for MetricStatistic {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for MetricStatistic {
+    fn deserialize<D>(deserializer: D) -> Result<MetricStatistic, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct MetricStatisticDeserializer;
 impl MetricStatisticDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<MetricStatistic, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MetricType {
+    /// `ASGAverageCPUUtilization`
+    ASGAverageCPUUtilization,
+    /// `ASGAverageNetworkIn`
+    ASGAverageNetworkIn,
+    /// `ASGAverageNetworkOut`
+    ASGAverageNetworkOut,
+    /// `ALBRequestCountPerTarget`
+    ALBRequestCountPerTarget,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl MetricType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MetricType::ASGAverageCPUUtilization => "ASGAverageCPUUtilization",
+            MetricType::ASGAverageNetworkIn => "ASGAverageNetworkIn",
+            MetricType::ASGAverageNetworkOut => "ASGAverageNetworkOut",
+            MetricType::ALBRequestCountPerTarget => "ALBRequestCountPerTarget",
+            MetricType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for MetricType {
+    fn default() -> MetricType {
+        MetricType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for MetricType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for MetricType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for MetricType {
+    fn from(value: &'a str) -> MetricType {
+        match value {
+            "ASGAverageCPUUtilization" => MetricType::ASGAverageCPUUtilization,
+            "ASGAverageNetworkIn" => MetricType::ASGAverageNetworkIn,
+            "ASGAverageNetworkOut" => MetricType::ASGAverageNetworkOut,
+            "ALBRequestCountPerTarget" => MetricType::ALBRequestCountPerTarget,
+            _ => MetricType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MetricType {
+    fn from(value: String) -> MetricType {
+        match value.as_str() {
+            "ASGAverageCPUUtilization" => MetricType::ASGAverageCPUUtilization,
+            "ASGAverageNetworkIn" => MetricType::ASGAverageNetworkIn,
+            "ASGAverageNetworkOut" => MetricType::ASGAverageNetworkOut,
+            "ALBRequestCountPerTarget" => MetricType::ALBRequestCountPerTarget,
+            _ => MetricType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for MetricType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<MetricType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MetricType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for MetricType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for MetricType {
+    fn deserialize<D>(deserializer: D) -> Result<MetricType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct MetricTypeDeserializer;
 impl MetricTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<MetricType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -5253,7 +5683,7 @@ impl PolicyTypesSerializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct PredefinedMetricSpecification {
     /// <p><p>The metric type. The following predefined metrics are available:</p> <ul> <li> <p> <code>ASGAverageCPUUtilization</code> - Average CPU utilization of the Auto Scaling group.</p> </li> <li> <p> <code>ASGAverageNetworkIn</code> - Average number of bytes received on all network interfaces by the Auto Scaling group.</p> </li> <li> <p> <code>ASGAverageNetworkOut</code> - Average number of bytes sent out on all network interfaces by the Auto Scaling group.</p> </li> <li> <p> <code>ALBRequestCountPerTarget</code> - Number of requests completed per target in an Application Load Balancer target group.</p> </li> </ul></p>
-    pub predefined_metric_type: String,
+    pub predefined_metric_type: MetricType,
     /// <p><p>Identifies the resource associated with the metric type. You can&#39;t specify a resource label unless the metric type is <code>ALBRequestCountPerTarget</code> and there is a target group attached to the Auto Scaling group.</p> <p>The format is <code>app/<i>load-balancer-name</i>/<i>load-balancer-id</i>/targetgroup/<i>target-group-name</i>/<i>target-group-id</i> </code>, where </p> <ul> <li> <p> <code>app/<i>load-balancer-name</i>/<i>load-balancer-id</i> </code> is the final portion of the load balancer ARN, and</p> </li> <li> <p> <code>targetgroup/<i>target-group-name</i>/<i>target-group-id</i> </code> is the final portion of the target group ARN.</p> </li> </ul></p>
     pub resource_label: Option<String>,
 }
@@ -5633,7 +6063,7 @@ pub struct PutScheduledUpdateGroupActionType {
     /// <p>The number of EC2 instances that should be running in the Auto Scaling group.</p>
     pub desired_capacity: Option<i64>,
     /// <p>The date and time for the recurring schedule to end. Amazon EC2 Auto Scaling does not perform the action after this time.</p>
-    pub end_time: Option<String>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The maximum number of instances in the Auto Scaling group.</p>
     pub max_size: Option<i64>,
     /// <p>The minimum number of instances in the Auto Scaling group.</p>
@@ -5643,9 +6073,9 @@ pub struct PutScheduledUpdateGroupActionType {
     /// <p>The name of this scaling action.</p>
     pub scheduled_action_name: String,
     /// <p>The date and time for this action to start, in YYYY-MM-DDThh:mm:ssZ format in UTC/GMT only and in quotes (for example, <code>"2019-06-01T00:00:00Z"</code>).</p> <p>If you specify <code>Recurrence</code> and <code>StartTime</code>, Amazon EC2 Auto Scaling performs the action at this time, and then performs the action based on the specified recurrence.</p> <p>If you try to schedule your action in the past, Amazon EC2 Auto Scaling returns an error message.</p>
-    pub start_time: Option<String>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>This parameter is no longer used.</p>
-    pub time: Option<String>,
+    pub time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// Serialize `PutScheduledUpdateGroupActionType` contents to a `SignedRequest`.
@@ -5665,7 +6095,10 @@ impl PutScheduledUpdateGroupActionTypeSerializer {
             params.put(&format!("{}{}", prefix, "DesiredCapacity"), &field_value);
         }
         if let Some(ref field_value) = obj.end_time {
-            params.put(&format!("{}{}", prefix, "EndTime"), &field_value);
+            params.put(
+                &format!("{}{}", prefix, "EndTime"),
+                &rusoto_core::serialization::TimestampFormat::Iso8601.format(field_value),
+            );
         }
         if let Some(ref field_value) = obj.max_size {
             params.put(&format!("{}{}", prefix, "MaxSize"), &field_value);
@@ -5681,10 +6114,16 @@ impl PutScheduledUpdateGroupActionTypeSerializer {
             &obj.scheduled_action_name,
         );
         if let Some(ref field_value) = obj.start_time {
-            params.put(&format!("{}{}", prefix, "StartTime"), &field_value);
+            params.put(
+                &format!("{}{}", prefix, "StartTime"),
+                &rusoto_core::serialization::TimestampFormat::Iso8601.format(field_value),
+            );
         }
         if let Some(ref field_value) = obj.time {
-            params.put(&format!("{}{}", prefix, "Time"), &field_value);
+            params.put(
+                &format!("{}{}", prefix, "Time"),
+                &rusoto_core::serialization::TimestampFormat::Iso8601.format(field_value),
+            );
         }
     }
 }
@@ -5762,12 +6201,183 @@ impl ResourceNameDeserializer {
         Ok(obj)
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ScalingActivityStatusCode {
+    /// `PendingSpotBidPlacement`
+    PendingSpotBidPlacement,
+    /// `WaitingForSpotInstanceRequestId`
+    WaitingForSpotInstanceRequestId,
+    /// `WaitingForSpotInstanceId`
+    WaitingForSpotInstanceId,
+    /// `WaitingForInstanceId`
+    WaitingForInstanceId,
+    /// `PreInService`
+    PreInService,
+    /// `InProgress`
+    InProgress,
+    /// `WaitingForELBConnectionDraining`
+    WaitingForELBConnectionDraining,
+    /// `MidLifecycleAction`
+    MidLifecycleAction,
+    /// `WaitingForInstanceWarmup`
+    WaitingForInstanceWarmup,
+    /// `Successful`
+    Successful,
+    /// `Failed`
+    Failed,
+    /// `Cancelled`
+    Cancelled,
+    /// `WaitingForConnectionDraining`
+    WaitingForConnectionDraining,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ScalingActivityStatusCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ScalingActivityStatusCode::PendingSpotBidPlacement => "PendingSpotBidPlacement",
+            ScalingActivityStatusCode::WaitingForSpotInstanceRequestId => {
+                "WaitingForSpotInstanceRequestId"
+            }
+            ScalingActivityStatusCode::WaitingForSpotInstanceId => "WaitingForSpotInstanceId",
+            ScalingActivityStatusCode::WaitingForInstanceId => "WaitingForInstanceId",
+            ScalingActivityStatusCode::PreInService => "PreInService",
+            ScalingActivityStatusCode::InProgress => "InProgress",
+            ScalingActivityStatusCode::WaitingForELBConnectionDraining => {
+                "WaitingForELBConnectionDraining"
+            }
+            ScalingActivityStatusCode::MidLifecycleAction => "MidLifecycleAction",
+            ScalingActivityStatusCode::WaitingForInstanceWarmup => "WaitingForInstanceWarmup",
+            ScalingActivityStatusCode::Successful => "Successful",
+            ScalingActivityStatusCode::Failed => "Failed",
+            ScalingActivityStatusCode::Cancelled => "Cancelled",
+            ScalingActivityStatusCode::WaitingForConnectionDraining => {
+                "WaitingForConnectionDraining"
+            }
+            ScalingActivityStatusCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ScalingActivityStatusCode {
+    fn default() -> ScalingActivityStatusCode {
+        ScalingActivityStatusCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ScalingActivityStatusCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ScalingActivityStatusCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ScalingActivityStatusCode {
+    fn from(value: &'a str) -> ScalingActivityStatusCode {
+        match value {
+            "PendingSpotBidPlacement" => ScalingActivityStatusCode::PendingSpotBidPlacement,
+            "WaitingForSpotInstanceRequestId" => {
+                ScalingActivityStatusCode::WaitingForSpotInstanceRequestId
+            }
+            "WaitingForSpotInstanceId" => ScalingActivityStatusCode::WaitingForSpotInstanceId,
+            "WaitingForInstanceId" => ScalingActivityStatusCode::WaitingForInstanceId,
+            "PreInService" => ScalingActivityStatusCode::PreInService,
+            "InProgress" => ScalingActivityStatusCode::InProgress,
+            "WaitingForELBConnectionDraining" => {
+                ScalingActivityStatusCode::WaitingForELBConnectionDraining
+            }
+            "MidLifecycleAction" => ScalingActivityStatusCode::MidLifecycleAction,
+            "WaitingForInstanceWarmup" => ScalingActivityStatusCode::WaitingForInstanceWarmup,
+            "Successful" => ScalingActivityStatusCode::Successful,
+            "Failed" => ScalingActivityStatusCode::Failed,
+            "Cancelled" => ScalingActivityStatusCode::Cancelled,
+            "WaitingForConnectionDraining" => {
+                ScalingActivityStatusCode::WaitingForConnectionDraining
+            }
+            _ => ScalingActivityStatusCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ScalingActivityStatusCode {
+    fn from(value: String) -> ScalingActivityStatusCode {
+        match value.as_str() {
+            "PendingSpotBidPlacement" => ScalingActivityStatusCode::PendingSpotBidPlacement,
+            "WaitingForSpotInstanceRequestId" => {
+                ScalingActivityStatusCode::WaitingForSpotInstanceRequestId
+            }
+            "WaitingForSpotInstanceId" => ScalingActivityStatusCode::WaitingForSpotInstanceId,
+            "WaitingForInstanceId" => ScalingActivityStatusCode::WaitingForInstanceId,
+            "PreInService" => ScalingActivityStatusCode::PreInService,
+            "InProgress" => ScalingActivityStatusCode::InProgress,
+            "WaitingForELBConnectionDraining" => {
+                ScalingActivityStatusCode::WaitingForELBConnectionDraining
+            }
+            "MidLifecycleAction" => ScalingActivityStatusCode::MidLifecycleAction,
+            "WaitingForInstanceWarmup" => ScalingActivityStatusCode::WaitingForInstanceWarmup,
+            "Successful" => ScalingActivityStatusCode::Successful,
+            "Failed" => ScalingActivityStatusCode::Failed,
+            "Cancelled" => ScalingActivityStatusCode::Cancelled,
+            "WaitingForConnectionDraining" => {
+                ScalingActivityStatusCode::WaitingForConnectionDraining
+            }
+            _ => ScalingActivityStatusCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ScalingActivityStatusCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ScalingActivityStatusCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ScalingActivityStatusCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ScalingActivityStatusCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ScalingActivityStatusCode {
+    fn deserialize<D>(deserializer: D) -> Result<ScalingActivityStatusCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct ScalingActivityStatusCodeDeserializer;
 impl ScalingActivityStatusCodeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ScalingActivityStatusCode, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -6005,7 +6615,7 @@ pub struct ScheduledUpdateGroupAction {
     /// <p>The number of instances you prefer to maintain in the group.</p>
     pub desired_capacity: Option<i64>,
     /// <p>The date and time in UTC for the recurring schedule to end. For example, <code>"2019-06-01T00:00:00Z"</code>. </p>
-    pub end_time: Option<String>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The maximum number of instances in the Auto Scaling group.</p>
     pub max_size: Option<i64>,
     /// <p>The minimum number of instances in the Auto Scaling group.</p>
@@ -6017,9 +6627,9 @@ pub struct ScheduledUpdateGroupAction {
     /// <p>The name of the scheduled action.</p>
     pub scheduled_action_name: Option<String>,
     /// <p>The date and time in UTC for this action to start. For example, <code>"2019-06-01T00:00:00Z"</code>. </p>
-    pub start_time: Option<String>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>This parameter is no longer used.</p>
-    pub time: Option<String>,
+    pub time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 struct ScheduledUpdateGroupActionDeserializer;
@@ -6102,7 +6712,7 @@ pub struct ScheduledUpdateGroupActionRequest {
     /// <p>The number of EC2 instances that should be running in the group.</p>
     pub desired_capacity: Option<i64>,
     /// <p>The date and time for the recurring schedule to end. Amazon EC2 Auto Scaling does not perform the action after this time.</p>
-    pub end_time: Option<String>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The maximum number of instances in the Auto Scaling group.</p>
     pub max_size: Option<i64>,
     /// <p>The minimum number of instances in the Auto Scaling group.</p>
@@ -6112,7 +6722,7 @@ pub struct ScheduledUpdateGroupActionRequest {
     /// <p>The name of the scaling action.</p>
     pub scheduled_action_name: String,
     /// <p>The date and time for the action to start, in YYYY-MM-DDThh:mm:ssZ format in UTC/GMT only and in quotes (for example, <code>"2019-06-01T00:00:00Z"</code>).</p> <p>If you specify <code>Recurrence</code> and <code>StartTime</code>, Amazon EC2 Auto Scaling performs the action at this time, and then performs the action based on the specified recurrence.</p> <p>If you try to schedule the action in the past, Amazon EC2 Auto Scaling returns an error message.</p>
-    pub start_time: Option<String>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// Serialize `ScheduledUpdateGroupActionRequest` contents to a `SignedRequest`.
@@ -6128,7 +6738,10 @@ impl ScheduledUpdateGroupActionRequestSerializer {
             params.put(&format!("{}{}", prefix, "DesiredCapacity"), &field_value);
         }
         if let Some(ref field_value) = obj.end_time {
-            params.put(&format!("{}{}", prefix, "EndTime"), &field_value);
+            params.put(
+                &format!("{}{}", prefix, "EndTime"),
+                &rusoto_core::serialization::TimestampFormat::Iso8601.format(field_value),
+            );
         }
         if let Some(ref field_value) = obj.max_size {
             params.put(&format!("{}{}", prefix, "MaxSize"), &field_value);
@@ -6144,7 +6757,10 @@ impl ScheduledUpdateGroupActionRequestSerializer {
             &obj.scheduled_action_name,
         );
         if let Some(ref field_value) = obj.start_time {
-            params.put(&format!("{}{}", prefix, "StartTime"), &field_value);
+            params.put(
+                &format!("{}{}", prefix, "StartTime"),
+                &rusoto_core::serialization::TimestampFormat::Iso8601.format(field_value),
+            );
         }
     }
 }
@@ -6862,9 +7478,12 @@ impl TerminationPoliciesSerializer {
 struct TimestampTypeDeserializer;
 impl TimestampTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<::chrono::DateTime<::chrono::Utc>, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::proto::xml::util::timestamp_characters(stack)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -10436,11 +11055,12 @@ pub struct AutoscalingClient {
 impl AutoscalingClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> AutoscalingClient {
         AutoscalingClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Auto Scaling"),
         }
     }
 
@@ -10472,6 +11092,7 @@ impl Autoscaling for AutoscalingClient {
         input: AttachInstancesQuery,
     ) -> Result<(), RusotoError<AttachInstancesError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("AttachInstances");
         let mut params = Params::new();
 
         params.put("Action", "AttachInstances");
@@ -10503,6 +11124,7 @@ impl Autoscaling for AutoscalingClient {
         RusotoError<AttachLoadBalancerTargetGroupsError>,
     > {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("AttachLoadBalancerTargetGroups");
         let mut params = Params::new();
 
         params.put("Action", "AttachLoadBalancerTargetGroups");
@@ -10552,6 +11174,7 @@ impl Autoscaling for AutoscalingClient {
         input: AttachLoadBalancersType,
     ) -> Result<AttachLoadBalancersResultType, RusotoError<AttachLoadBalancersError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("AttachLoadBalancers");
         let mut params = Params::new();
 
         params.put("Action", "AttachLoadBalancers");
@@ -10602,6 +11225,7 @@ impl Autoscaling for AutoscalingClient {
     ) -> Result<BatchDeleteScheduledActionAnswer, RusotoError<BatchDeleteScheduledActionError>>
     {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("BatchDeleteScheduledAction");
         let mut params = Params::new();
 
         params.put("Action", "BatchDeleteScheduledAction");
@@ -10654,6 +11278,7 @@ impl Autoscaling for AutoscalingClient {
         RusotoError<BatchPutScheduledUpdateGroupActionError>,
     > {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("BatchPutScheduledUpdateGroupAction");
         let mut params = Params::new();
 
         params.put("Action", "BatchPutScheduledUpdateGroupAction");
@@ -10705,6 +11330,7 @@ impl Autoscaling for AutoscalingClient {
         input: CompleteLifecycleActionType,
     ) -> Result<CompleteLifecycleActionAnswer, RusotoError<CompleteLifecycleActionError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("CompleteLifecycleAction");
         let mut params = Params::new();
 
         params.put("Action", "CompleteLifecycleAction");
@@ -10754,6 +11380,7 @@ impl Autoscaling for AutoscalingClient {
         input: CreateAutoScalingGroupType,
     ) -> Result<(), RusotoError<CreateAutoScalingGroupError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("CreateAutoScalingGroup");
         let mut params = Params::new();
 
         params.put("Action", "CreateAutoScalingGroup");
@@ -10782,6 +11409,7 @@ impl Autoscaling for AutoscalingClient {
         input: CreateLaunchConfigurationType,
     ) -> Result<(), RusotoError<CreateLaunchConfigurationError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("CreateLaunchConfiguration");
         let mut params = Params::new();
 
         params.put("Action", "CreateLaunchConfiguration");
@@ -10810,6 +11438,7 @@ impl Autoscaling for AutoscalingClient {
         input: CreateOrUpdateTagsType,
     ) -> Result<(), RusotoError<CreateOrUpdateTagsError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("CreateOrUpdateTags");
         let mut params = Params::new();
 
         params.put("Action", "CreateOrUpdateTags");
@@ -10838,6 +11467,7 @@ impl Autoscaling for AutoscalingClient {
         input: DeleteAutoScalingGroupType,
     ) -> Result<(), RusotoError<DeleteAutoScalingGroupError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DeleteAutoScalingGroup");
         let mut params = Params::new();
 
         params.put("Action", "DeleteAutoScalingGroup");
@@ -10866,6 +11496,7 @@ impl Autoscaling for AutoscalingClient {
         input: LaunchConfigurationNameType,
     ) -> Result<(), RusotoError<DeleteLaunchConfigurationError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DeleteLaunchConfiguration");
         let mut params = Params::new();
 
         params.put("Action", "DeleteLaunchConfiguration");
@@ -10894,6 +11525,7 @@ impl Autoscaling for AutoscalingClient {
         input: DeleteLifecycleHookType,
     ) -> Result<DeleteLifecycleHookAnswer, RusotoError<DeleteLifecycleHookError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DeleteLifecycleHook");
         let mut params = Params::new();
 
         params.put("Action", "DeleteLifecycleHook");
@@ -10943,6 +11575,7 @@ impl Autoscaling for AutoscalingClient {
         input: DeleteNotificationConfigurationType,
     ) -> Result<(), RusotoError<DeleteNotificationConfigurationError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DeleteNotificationConfiguration");
         let mut params = Params::new();
 
         params.put("Action", "DeleteNotificationConfiguration");
@@ -10973,6 +11606,7 @@ impl Autoscaling for AutoscalingClient {
         input: DeletePolicyType,
     ) -> Result<(), RusotoError<DeletePolicyError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DeletePolicy");
         let mut params = Params::new();
 
         params.put("Action", "DeletePolicy");
@@ -11001,6 +11635,7 @@ impl Autoscaling for AutoscalingClient {
         input: DeleteScheduledActionType,
     ) -> Result<(), RusotoError<DeleteScheduledActionError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DeleteScheduledAction");
         let mut params = Params::new();
 
         params.put("Action", "DeleteScheduledAction");
@@ -11026,6 +11661,7 @@ impl Autoscaling for AutoscalingClient {
     /// <p>Deletes the specified tags.</p>
     async fn delete_tags(&self, input: DeleteTagsType) -> Result<(), RusotoError<DeleteTagsError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DeleteTags");
         let mut params = Params::new();
 
         params.put("Action", "DeleteTags");
@@ -11053,6 +11689,7 @@ impl Autoscaling for AutoscalingClient {
         &self,
     ) -> Result<DescribeAccountLimitsAnswer, RusotoError<DescribeAccountLimitsError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeAccountLimits");
         let mut params = Params::new();
 
         params.put("Action", "DescribeAccountLimits");
@@ -11101,6 +11738,7 @@ impl Autoscaling for AutoscalingClient {
         &self,
     ) -> Result<DescribeAdjustmentTypesAnswer, RusotoError<DescribeAdjustmentTypesError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeAdjustmentTypes");
         let mut params = Params::new();
 
         params.put("Action", "DescribeAdjustmentTypes");
@@ -11150,6 +11788,7 @@ impl Autoscaling for AutoscalingClient {
         input: AutoScalingGroupNamesType,
     ) -> Result<AutoScalingGroupsType, RusotoError<DescribeAutoScalingGroupsError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeAutoScalingGroups");
         let mut params = Params::new();
 
         params.put("Action", "DescribeAutoScalingGroups");
@@ -11199,6 +11838,7 @@ impl Autoscaling for AutoscalingClient {
         input: DescribeAutoScalingInstancesType,
     ) -> Result<AutoScalingInstancesType, RusotoError<DescribeAutoScalingInstancesError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeAutoScalingInstances");
         let mut params = Params::new();
 
         params.put("Action", "DescribeAutoScalingInstances");
@@ -11250,6 +11890,7 @@ impl Autoscaling for AutoscalingClient {
         RusotoError<DescribeAutoScalingNotificationTypesError>,
     > {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeAutoScalingNotificationTypes");
         let mut params = Params::new();
 
         params.put("Action", "DescribeAutoScalingNotificationTypes");
@@ -11301,6 +11942,7 @@ impl Autoscaling for AutoscalingClient {
         input: LaunchConfigurationNamesType,
     ) -> Result<LaunchConfigurationsType, RusotoError<DescribeLaunchConfigurationsError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeLaunchConfigurations");
         let mut params = Params::new();
 
         params.put("Action", "DescribeLaunchConfigurations");
@@ -11350,6 +11992,7 @@ impl Autoscaling for AutoscalingClient {
     ) -> Result<DescribeLifecycleHookTypesAnswer, RusotoError<DescribeLifecycleHookTypesError>>
     {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeLifecycleHookTypes");
         let mut params = Params::new();
 
         params.put("Action", "DescribeLifecycleHookTypes");
@@ -11399,6 +12042,7 @@ impl Autoscaling for AutoscalingClient {
         input: DescribeLifecycleHooksType,
     ) -> Result<DescribeLifecycleHooksAnswer, RusotoError<DescribeLifecycleHooksError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeLifecycleHooks");
         let mut params = Params::new();
 
         params.put("Action", "DescribeLifecycleHooks");
@@ -11451,6 +12095,7 @@ impl Autoscaling for AutoscalingClient {
         RusotoError<DescribeLoadBalancerTargetGroupsError>,
     > {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeLoadBalancerTargetGroups");
         let mut params = Params::new();
 
         params.put("Action", "DescribeLoadBalancerTargetGroups");
@@ -11502,6 +12147,7 @@ impl Autoscaling for AutoscalingClient {
         input: DescribeLoadBalancersRequest,
     ) -> Result<DescribeLoadBalancersResponse, RusotoError<DescribeLoadBalancersError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeLoadBalancers");
         let mut params = Params::new();
 
         params.put("Action", "DescribeLoadBalancers");
@@ -11551,6 +12197,7 @@ impl Autoscaling for AutoscalingClient {
     ) -> Result<DescribeMetricCollectionTypesAnswer, RusotoError<DescribeMetricCollectionTypesError>>
     {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeMetricCollectionTypes");
         let mut params = Params::new();
 
         params.put("Action", "DescribeMetricCollectionTypes");
@@ -11603,6 +12250,7 @@ impl Autoscaling for AutoscalingClient {
         RusotoError<DescribeNotificationConfigurationsError>,
     > {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeNotificationConfigurations");
         let mut params = Params::new();
 
         params.put("Action", "DescribeNotificationConfigurations");
@@ -11654,6 +12302,7 @@ impl Autoscaling for AutoscalingClient {
         input: DescribePoliciesType,
     ) -> Result<PoliciesType, RusotoError<DescribePoliciesError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribePolicies");
         let mut params = Params::new();
 
         params.put("Action", "DescribePolicies");
@@ -11700,6 +12349,7 @@ impl Autoscaling for AutoscalingClient {
         input: DescribeScalingActivitiesType,
     ) -> Result<ActivitiesType, RusotoError<DescribeScalingActivitiesError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeScalingActivities");
         let mut params = Params::new();
 
         params.put("Action", "DescribeScalingActivities");
@@ -11748,6 +12398,7 @@ impl Autoscaling for AutoscalingClient {
         &self,
     ) -> Result<ProcessesType, RusotoError<DescribeScalingProcessTypesError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeScalingProcessTypes");
         let mut params = Params::new();
 
         params.put("Action", "DescribeScalingProcessTypes");
@@ -11797,6 +12448,7 @@ impl Autoscaling for AutoscalingClient {
         input: DescribeScheduledActionsType,
     ) -> Result<ScheduledActionsType, RusotoError<DescribeScheduledActionsError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeScheduledActions");
         let mut params = Params::new();
 
         params.put("Action", "DescribeScheduledActions");
@@ -11846,6 +12498,7 @@ impl Autoscaling for AutoscalingClient {
         input: DescribeTagsType,
     ) -> Result<TagsType, RusotoError<DescribeTagsError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeTags");
         let mut params = Params::new();
 
         params.put("Action", "DescribeTags");
@@ -11894,6 +12547,7 @@ impl Autoscaling for AutoscalingClient {
         RusotoError<DescribeTerminationPolicyTypesError>,
     > {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeTerminationPolicyTypes");
         let mut params = Params::new();
 
         params.put("Action", "DescribeTerminationPolicyTypes");
@@ -11943,6 +12597,7 @@ impl Autoscaling for AutoscalingClient {
         input: DetachInstancesQuery,
     ) -> Result<DetachInstancesAnswer, RusotoError<DetachInstancesError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DetachInstances");
         let mut params = Params::new();
 
         params.put("Action", "DetachInstances");
@@ -11995,6 +12650,7 @@ impl Autoscaling for AutoscalingClient {
         RusotoError<DetachLoadBalancerTargetGroupsError>,
     > {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DetachLoadBalancerTargetGroups");
         let mut params = Params::new();
 
         params.put("Action", "DetachLoadBalancerTargetGroups");
@@ -12044,6 +12700,7 @@ impl Autoscaling for AutoscalingClient {
         input: DetachLoadBalancersType,
     ) -> Result<DetachLoadBalancersResultType, RusotoError<DetachLoadBalancersError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DetachLoadBalancers");
         let mut params = Params::new();
 
         params.put("Action", "DetachLoadBalancers");
@@ -12093,6 +12750,7 @@ impl Autoscaling for AutoscalingClient {
         input: DisableMetricsCollectionQuery,
     ) -> Result<(), RusotoError<DisableMetricsCollectionError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DisableMetricsCollection");
         let mut params = Params::new();
 
         params.put("Action", "DisableMetricsCollection");
@@ -12121,6 +12779,7 @@ impl Autoscaling for AutoscalingClient {
         input: EnableMetricsCollectionQuery,
     ) -> Result<(), RusotoError<EnableMetricsCollectionError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("EnableMetricsCollection");
         let mut params = Params::new();
 
         params.put("Action", "EnableMetricsCollection");
@@ -12149,6 +12808,7 @@ impl Autoscaling for AutoscalingClient {
         input: EnterStandbyQuery,
     ) -> Result<EnterStandbyAnswer, RusotoError<EnterStandbyError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("EnterStandby");
         let mut params = Params::new();
 
         params.put("Action", "EnterStandby");
@@ -12195,6 +12855,7 @@ impl Autoscaling for AutoscalingClient {
         input: ExecutePolicyType,
     ) -> Result<(), RusotoError<ExecutePolicyError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("ExecutePolicy");
         let mut params = Params::new();
 
         params.put("Action", "ExecutePolicy");
@@ -12223,6 +12884,7 @@ impl Autoscaling for AutoscalingClient {
         input: ExitStandbyQuery,
     ) -> Result<ExitStandbyAnswer, RusotoError<ExitStandbyError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("ExitStandby");
         let mut params = Params::new();
 
         params.put("Action", "ExitStandby");
@@ -12269,6 +12931,7 @@ impl Autoscaling for AutoscalingClient {
         input: PutLifecycleHookType,
     ) -> Result<PutLifecycleHookAnswer, RusotoError<PutLifecycleHookError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("PutLifecycleHook");
         let mut params = Params::new();
 
         params.put("Action", "PutLifecycleHook");
@@ -12318,6 +12981,7 @@ impl Autoscaling for AutoscalingClient {
         input: PutNotificationConfigurationType,
     ) -> Result<(), RusotoError<PutNotificationConfigurationError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("PutNotificationConfiguration");
         let mut params = Params::new();
 
         params.put("Action", "PutNotificationConfiguration");
@@ -12346,6 +13010,7 @@ impl Autoscaling for AutoscalingClient {
         input: PutScalingPolicyType,
     ) -> Result<PolicyARNType, RusotoError<PutScalingPolicyError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("PutScalingPolicy");
         let mut params = Params::new();
 
         params.put("Action", "PutScalingPolicy");
@@ -12392,6 +13057,7 @@ impl Autoscaling for AutoscalingClient {
         input: PutScheduledUpdateGroupActionType,
     ) -> Result<(), RusotoError<PutScheduledUpdateGroupActionError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("PutScheduledUpdateGroupAction");
         let mut params = Params::new();
 
         params.put("Action", "PutScheduledUpdateGroupAction");
@@ -12423,6 +13089,7 @@ impl Autoscaling for AutoscalingClient {
         RusotoError<RecordLifecycleActionHeartbeatError>,
     > {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("RecordLifecycleActionHeartbeat");
         let mut params = Params::new();
 
         params.put("Action", "RecordLifecycleActionHeartbeat");
@@ -12472,6 +13139,7 @@ impl Autoscaling for AutoscalingClient {
         input: ScalingProcessQuery,
     ) -> Result<(), RusotoError<ResumeProcessesError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("ResumeProcesses");
         let mut params = Params::new();
 
         params.put("Action", "ResumeProcesses");
@@ -12500,6 +13168,7 @@ impl Autoscaling for AutoscalingClient {
         input: SetDesiredCapacityType,
     ) -> Result<(), RusotoError<SetDesiredCapacityError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("SetDesiredCapacity");
         let mut params = Params::new();
 
         params.put("Action", "SetDesiredCapacity");
@@ -12528,6 +13197,7 @@ impl Autoscaling for AutoscalingClient {
         input: SetInstanceHealthQuery,
     ) -> Result<(), RusotoError<SetInstanceHealthError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("SetInstanceHealth");
         let mut params = Params::new();
 
         params.put("Action", "SetInstanceHealth");
@@ -12556,6 +13226,7 @@ impl Autoscaling for AutoscalingClient {
         input: SetInstanceProtectionQuery,
     ) -> Result<SetInstanceProtectionAnswer, RusotoError<SetInstanceProtectionError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("SetInstanceProtection");
         let mut params = Params::new();
 
         params.put("Action", "SetInstanceProtection");
@@ -12605,6 +13276,7 @@ impl Autoscaling for AutoscalingClient {
         input: ScalingProcessQuery,
     ) -> Result<(), RusotoError<SuspendProcessesError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("SuspendProcesses");
         let mut params = Params::new();
 
         params.put("Action", "SuspendProcesses");
@@ -12633,6 +13305,7 @@ impl Autoscaling for AutoscalingClient {
         input: TerminateInstanceInAutoScalingGroupType,
     ) -> Result<ActivityType, RusotoError<TerminateInstanceInAutoScalingGroupError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("TerminateInstanceInAutoScalingGroup");
         let mut params = Params::new();
 
         params.put("Action", "TerminateInstanceInAutoScalingGroup");
@@ -12684,6 +13357,7 @@ impl Autoscaling for AutoscalingClient {
         input: UpdateAutoScalingGroupType,
     ) -> Result<(), RusotoError<UpdateAutoScalingGroupError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("UpdateAutoScalingGroup");
         let mut params = Params::new();
 
         params.put("Action", "UpdateAutoScalingGroup");
@@ -12706,6 +13380,354 @@ impl Autoscaling for AutoscalingClient {
         Ok(())
     }
 }
+impl AutoscalingClient {
+    /// Auto-paginating version of `describe_auto_scaling_groups`, streaming every page of results.
+    pub fn describe_auto_scaling_groups_pages(
+        &self,
+        input: AutoScalingGroupNamesType,
+    ) -> rusoto_core::pagination::RusotoStream<AutoScalingGroupsType, DescribeAutoScalingGroupsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AutoscalingClient, input: AutoScalingGroupNamesType| async move {
+                client.describe_auto_scaling_groups(input).await
+            },
+            |input: &AutoScalingGroupNamesType, output: &AutoScalingGroupsType| {
+                Some(AutoScalingGroupNamesType {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_auto_scaling_groups`, streaming the `AutoScalingGroups` of every page of results.
+    pub fn describe_auto_scaling_groups_items(
+        &self,
+        input: AutoScalingGroupNamesType,
+    ) -> rusoto_core::pagination::RusotoStream<AutoScalingGroup, DescribeAutoScalingGroupsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_auto_scaling_groups_pages(input),
+            |output: AutoScalingGroupsType| output.auto_scaling_groups,
+        )
+    }
+
+    /// Auto-paginating version of `describe_auto_scaling_instances`, streaming every page of results.
+    pub fn describe_auto_scaling_instances_pages(
+        &self,
+        input: DescribeAutoScalingInstancesType,
+    ) -> rusoto_core::pagination::RusotoStream<
+        AutoScalingInstancesType,
+        DescribeAutoScalingInstancesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AutoscalingClient, input: DescribeAutoScalingInstancesType| async move {
+                client.describe_auto_scaling_instances(input).await
+            },
+            |input: &DescribeAutoScalingInstancesType, output: &AutoScalingInstancesType| {
+                Some(DescribeAutoScalingInstancesType {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_auto_scaling_instances`, streaming the `AutoScalingInstances` of every page of results.
+    pub fn describe_auto_scaling_instances_items(
+        &self,
+        input: DescribeAutoScalingInstancesType,
+    ) -> rusoto_core::pagination::RusotoStream<
+        AutoScalingInstanceDetails,
+        DescribeAutoScalingInstancesError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_auto_scaling_instances_pages(input),
+            |output: AutoScalingInstancesType| output.auto_scaling_instances.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_launch_configurations`, streaming every page of results.
+    pub fn describe_launch_configurations_pages(
+        &self,
+        input: LaunchConfigurationNamesType,
+    ) -> rusoto_core::pagination::RusotoStream<
+        LaunchConfigurationsType,
+        DescribeLaunchConfigurationsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AutoscalingClient, input: LaunchConfigurationNamesType| async move {
+                client.describe_launch_configurations(input).await
+            },
+            |input: &LaunchConfigurationNamesType, output: &LaunchConfigurationsType| {
+                Some(LaunchConfigurationNamesType {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_launch_configurations`, streaming the `LaunchConfigurations` of every page of results.
+    pub fn describe_launch_configurations_items(
+        &self,
+        input: LaunchConfigurationNamesType,
+    ) -> rusoto_core::pagination::RusotoStream<LaunchConfiguration, DescribeLaunchConfigurationsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_launch_configurations_pages(input),
+            |output: LaunchConfigurationsType| output.launch_configurations,
+        )
+    }
+
+    /// Auto-paginating version of `describe_load_balancer_target_groups`, streaming every page of results.
+    pub fn describe_load_balancer_target_groups_pages(
+        &self,
+        input: DescribeLoadBalancerTargetGroupsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeLoadBalancerTargetGroupsResponse,
+        DescribeLoadBalancerTargetGroupsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AutoscalingClient, input: DescribeLoadBalancerTargetGroupsRequest| async move {
+                client.describe_load_balancer_target_groups(input).await
+            },
+            |input: &DescribeLoadBalancerTargetGroupsRequest,
+             output: &DescribeLoadBalancerTargetGroupsResponse| {
+                Some(DescribeLoadBalancerTargetGroupsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_load_balancer_target_groups`, streaming the `LoadBalancerTargetGroups` of every page of results.
+    pub fn describe_load_balancer_target_groups_items(
+        &self,
+        input: DescribeLoadBalancerTargetGroupsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        LoadBalancerTargetGroupState,
+        DescribeLoadBalancerTargetGroupsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_load_balancer_target_groups_pages(input),
+            |output: DescribeLoadBalancerTargetGroupsResponse| {
+                output.load_balancer_target_groups.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_load_balancers`, streaming every page of results.
+    pub fn describe_load_balancers_pages(
+        &self,
+        input: DescribeLoadBalancersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeLoadBalancersResponse,
+        DescribeLoadBalancersError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AutoscalingClient, input: DescribeLoadBalancersRequest| async move {
+                client.describe_load_balancers(input).await
+            },
+            |input: &DescribeLoadBalancersRequest, output: &DescribeLoadBalancersResponse| {
+                Some(DescribeLoadBalancersRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_load_balancers`, streaming the `LoadBalancers` of every page of results.
+    pub fn describe_load_balancers_items(
+        &self,
+        input: DescribeLoadBalancersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<LoadBalancerState, DescribeLoadBalancersError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_load_balancers_pages(input),
+            |output: DescribeLoadBalancersResponse| output.load_balancers.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_notification_configurations`, streaming every page of results.
+    pub fn describe_notification_configurations_pages(
+        &self,
+        input: DescribeNotificationConfigurationsType,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeNotificationConfigurationsAnswer,
+        DescribeNotificationConfigurationsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AutoscalingClient, input: DescribeNotificationConfigurationsType| async move {
+                client.describe_notification_configurations(input).await
+            },
+            |input: &DescribeNotificationConfigurationsType,
+             output: &DescribeNotificationConfigurationsAnswer| {
+                Some(DescribeNotificationConfigurationsType {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_notification_configurations`, streaming the `NotificationConfigurations` of every page of results.
+    pub fn describe_notification_configurations_items(
+        &self,
+        input: DescribeNotificationConfigurationsType,
+    ) -> rusoto_core::pagination::RusotoStream<
+        NotificationConfiguration,
+        DescribeNotificationConfigurationsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_notification_configurations_pages(input),
+            |output: DescribeNotificationConfigurationsAnswer| output.notification_configurations,
+        )
+    }
+
+    /// Auto-paginating version of `describe_policies`, streaming every page of results.
+    pub fn describe_policies_pages(
+        &self,
+        input: DescribePoliciesType,
+    ) -> rusoto_core::pagination::RusotoStream<PoliciesType, DescribePoliciesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AutoscalingClient, input: DescribePoliciesType| async move {
+                client.describe_policies(input).await
+            },
+            |input: &DescribePoliciesType, output: &PoliciesType| {
+                Some(DescribePoliciesType {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_policies`, streaming the `ScalingPolicies` of every page of results.
+    pub fn describe_policies_items(
+        &self,
+        input: DescribePoliciesType,
+    ) -> rusoto_core::pagination::RusotoStream<ScalingPolicy, DescribePoliciesError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_policies_pages(input),
+            |output: PoliciesType| output.scaling_policies.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_scaling_activities`, streaming every page of results.
+    pub fn describe_scaling_activities_pages(
+        &self,
+        input: DescribeScalingActivitiesType,
+    ) -> rusoto_core::pagination::RusotoStream<ActivitiesType, DescribeScalingActivitiesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AutoscalingClient, input: DescribeScalingActivitiesType| async move {
+                client.describe_scaling_activities(input).await
+            },
+            |input: &DescribeScalingActivitiesType, output: &ActivitiesType| {
+                Some(DescribeScalingActivitiesType {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_scaling_activities`, streaming the `Activities` of every page of results.
+    pub fn describe_scaling_activities_items(
+        &self,
+        input: DescribeScalingActivitiesType,
+    ) -> rusoto_core::pagination::RusotoStream<Activity, DescribeScalingActivitiesError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_scaling_activities_pages(input),
+            |output: ActivitiesType| output.activities,
+        )
+    }
+
+    /// Auto-paginating version of `describe_scheduled_actions`, streaming every page of results.
+    pub fn describe_scheduled_actions_pages(
+        &self,
+        input: DescribeScheduledActionsType,
+    ) -> rusoto_core::pagination::RusotoStream<ScheduledActionsType, DescribeScheduledActionsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AutoscalingClient, input: DescribeScheduledActionsType| async move {
+                client.describe_scheduled_actions(input).await
+            },
+            |input: &DescribeScheduledActionsType, output: &ScheduledActionsType| {
+                Some(DescribeScheduledActionsType {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_scheduled_actions`, streaming the `ScheduledUpdateGroupActions` of every page of results.
+    pub fn describe_scheduled_actions_items(
+        &self,
+        input: DescribeScheduledActionsType,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ScheduledUpdateGroupAction,
+        DescribeScheduledActionsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_scheduled_actions_pages(input),
+            |output: ScheduledActionsType| {
+                output.scheduled_update_group_actions.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_tags`, streaming every page of results.
+    pub fn describe_tags_pages(
+        &self,
+        input: DescribeTagsType,
+    ) -> rusoto_core::pagination::RusotoStream<TagsType, DescribeTagsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AutoscalingClient, input: DescribeTagsType| async move {
+                client.describe_tags(input).await
+            },
+            |input: &DescribeTagsType, output: &TagsType| {
+                Some(DescribeTagsType {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_tags`, streaming the `Tags` of every page of results.
+    pub fn describe_tags_items(
+        &self,
+        input: DescribeTagsType,
+    ) -> rusoto_core::pagination::RusotoStream<TagDescription, DescribeTagsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_tags_pages(input),
+            |output: TagsType| output.tags.unwrap_or_default(),
+        )
+    }
+}
 
 #[cfg(test)]
 mod protocol_tests {
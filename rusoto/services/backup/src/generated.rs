@@ -51,20 +51,35 @@ pub struct BackupJob {
     pub bytes_transferred: Option<i64>,
     /// <p>The date and time a job to create a backup job is completed, in Unix format and Coordinated Universal Time (UTC). The value of <code>CompletionDate</code> is accurate to milliseconds. For example, the value 1516925490.087 represents Friday, January 26, 2018 12:11:30.087 AM.</p>
     #[serde(rename = "CompletionDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub completion_date: Option<f64>,
+    pub completion_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Contains identifying information about the creation of a backup job, including the <code>BackupPlanArn</code>, <code>BackupPlanId</code>, <code>BackupPlanVersion</code>, and <code>BackupRuleId</code> of the backup plan used to create it.</p>
     #[serde(rename = "CreatedBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub created_by: Option<RecoveryPointCreator>,
     /// <p>The date and time a backup job is created, in Unix format and Coordinated Universal Time (UTC). The value of <code>CreationDate</code> is accurate to milliseconds. For example, the value 1516925490.087 represents Friday, January 26, 2018 12:11:30.087 AM.</p>
     #[serde(rename = "CreationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The date and time a job to back up resources is expected to be completed, in Unix format and Coordinated Universal Time (UTC). The value of <code>ExpectedCompletionDate</code> is accurate to milliseconds. For example, the value 1516925490.087 represents Friday, January 26, 2018 12:11:30.087 AM.</p>
     #[serde(rename = "ExpectedCompletionDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expected_completion_date: Option<f64>,
+    pub expected_completion_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Specifies the IAM role ARN used to create the target recovery point; for example, <code>arn:aws:iam::123456789012:role/S3Access</code>.</p>
     #[serde(rename = "IamRoleArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -87,18 +102,151 @@ pub struct BackupJob {
     pub resource_type: Option<String>,
     /// <p>Specifies the time in Unix format and Coordinated Universal Time (UTC) when a backup job must be started before it is canceled. The value is calculated by adding the start window to the scheduled time. So if the scheduled time were 6:00 PM and the start window is 2 hours, the <code>StartBy</code> time would be 8:00 PM on the date specified. The value of <code>StartBy</code> is accurate to milliseconds. For example, the value 1516925490.087 represents Friday, January 26, 2018 12:11:30.087 AM.</p>
     #[serde(rename = "StartBy")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_by: Option<f64>,
+    pub start_by: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The current state of a resource recovery point.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<BackupJobState>,
     /// <p>A detailed message explaining the status of the job to back up a resource.</p>
     #[serde(rename = "StatusMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status_message: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BackupJobState {
+    /// `CREATED`
+    Created,
+    /// `PENDING`
+    Pending,
+    /// `RUNNING`
+    Running,
+    /// `ABORTING`
+    Aborting,
+    /// `ABORTED`
+    Aborted,
+    /// `COMPLETED`
+    Completed,
+    /// `FAILED`
+    Failed,
+    /// `EXPIRED`
+    Expired,
+    /// `PARTIAL`
+    Partial,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl BackupJobState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BackupJobState::Created => "CREATED",
+            BackupJobState::Pending => "PENDING",
+            BackupJobState::Running => "RUNNING",
+            BackupJobState::Aborting => "ABORTING",
+            BackupJobState::Aborted => "ABORTED",
+            BackupJobState::Completed => "COMPLETED",
+            BackupJobState::Failed => "FAILED",
+            BackupJobState::Expired => "EXPIRED",
+            BackupJobState::Partial => "PARTIAL",
+            BackupJobState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for BackupJobState {
+    fn default() -> BackupJobState {
+        BackupJobState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for BackupJobState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for BackupJobState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for BackupJobState {
+    fn from(value: &'a str) -> BackupJobState {
+        match value {
+            "CREATED" => BackupJobState::Created,
+            "PENDING" => BackupJobState::Pending,
+            "RUNNING" => BackupJobState::Running,
+            "ABORTING" => BackupJobState::Aborting,
+            "ABORTED" => BackupJobState::Aborted,
+            "COMPLETED" => BackupJobState::Completed,
+            "FAILED" => BackupJobState::Failed,
+            "EXPIRED" => BackupJobState::Expired,
+            "PARTIAL" => BackupJobState::Partial,
+            _ => BackupJobState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BackupJobState {
+    fn from(value: String) -> BackupJobState {
+        match value.as_str() {
+            "CREATED" => BackupJobState::Created,
+            "PENDING" => BackupJobState::Pending,
+            "RUNNING" => BackupJobState::Running,
+            "ABORTING" => BackupJobState::Aborting,
+            "ABORTED" => BackupJobState::Aborted,
+            "COMPLETED" => BackupJobState::Completed,
+            "FAILED" => BackupJobState::Failed,
+            "EXPIRED" => BackupJobState::Expired,
+            "PARTIAL" => BackupJobState::Partial,
+            _ => BackupJobState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for BackupJobState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<BackupJobState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for BackupJobState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for BackupJobState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BackupJobState {
+    fn deserialize<D>(deserializer: D) -> Result<BackupJobState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Contains an optional backup plan display name and an array of <code>BackupRule</code> objects, each of which specifies a backup rule. Each rule in a backup plan is a separate scheduled task and can back up a different selection of AWS resources.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -155,20 +303,35 @@ pub struct BackupPlansListMember {
     pub backup_plan_name: Option<String>,
     /// <p>The date and time a resource backup plan is created, in Unix format and Coordinated Universal Time (UTC). The value of <code>CreationDate</code> is accurate to milliseconds. For example, the value 1516925490.087 represents Friday, January 26, 2018 12:11:30.087 AM.</p>
     #[serde(rename = "CreationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A unique string that identifies the request and allows failed requests to be retried without the risk of executing the operation twice.</p>
     #[serde(rename = "CreatorRequestId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub creator_request_id: Option<String>,
     /// <p>The date and time a backup plan is deleted, in Unix format and Coordinated Universal Time (UTC). The value of <code>DeletionDate</code> is accurate to milliseconds. For example, the value 1516925490.087 represents Friday, January 26, 2018 12:11:30.087 AM.</p>
     #[serde(rename = "DeletionDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deletion_date: Option<f64>,
+    pub deletion_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The last time a job to back up resources was executed with this rule. A date and time, in Unix format and Coordinated Universal Time (UTC). The value of <code>LastExecutionDate</code> is accurate to milliseconds. For example, the value 1516925490.087 represents Friday, January 26, 2018 12:11:30.087 AM.</p>
     #[serde(rename = "LastExecutionDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_execution_date: Option<f64>,
+    pub last_execution_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Unique, randomly generated, Unicode, UTF-8 encoded strings that are at most 1,024 bytes long. Version IDs cannot be edited.</p>
     #[serde(rename = "VersionId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -280,8 +443,13 @@ pub struct BackupSelectionsListMember {
     pub backup_plan_id: Option<String>,
     /// <p>The date and time a backup plan is created, in Unix format and Coordinated Universal Time (UTC). The value of <code>CreationDate</code> is accurate to milliseconds. For example, the value 1516925490.087 represents Friday, January 26, 2018 12:11:30.087 AM.</p>
     #[serde(rename = "CreationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A unique string that identifies the request and allows failed requests to be retried without the risk of executing the operation twice.</p>
     #[serde(rename = "CreatorRequestId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -300,6 +468,174 @@ pub struct BackupSelectionsListMember {
     pub selection_name: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BackupVaultEvent {
+    /// `BACKUP_JOB_STARTED`
+    BackupJobStarted,
+    /// `BACKUP_JOB_COMPLETED`
+    BackupJobCompleted,
+    /// `BACKUP_JOB_SUCCESSFUL`
+    BackupJobSuccessful,
+    /// `BACKUP_JOB_FAILED`
+    BackupJobFailed,
+    /// `BACKUP_JOB_EXPIRED`
+    BackupJobExpired,
+    /// `RESTORE_JOB_STARTED`
+    RestoreJobStarted,
+    /// `RESTORE_JOB_COMPLETED`
+    RestoreJobCompleted,
+    /// `RESTORE_JOB_SUCCESSFUL`
+    RestoreJobSuccessful,
+    /// `RESTORE_JOB_FAILED`
+    RestoreJobFailed,
+    /// `COPY_JOB_STARTED`
+    CopyJobStarted,
+    /// `COPY_JOB_SUCCESSFUL`
+    CopyJobSuccessful,
+    /// `COPY_JOB_FAILED`
+    CopyJobFailed,
+    /// `RECOVERY_POINT_MODIFIED`
+    RecoveryPointModified,
+    /// `BACKUP_PLAN_CREATED`
+    BackupPlanCreated,
+    /// `BACKUP_PLAN_MODIFIED`
+    BackupPlanModified,
+    /// `S3_BACKUP_OBJECT_FAILED`
+    S3BackupObjectFailed,
+    /// `S3_RESTORE_OBJECT_FAILED`
+    S3RestoreObjectFailed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl BackupVaultEvent {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BackupVaultEvent::BackupJobStarted => "BACKUP_JOB_STARTED",
+            BackupVaultEvent::BackupJobCompleted => "BACKUP_JOB_COMPLETED",
+            BackupVaultEvent::BackupJobSuccessful => "BACKUP_JOB_SUCCESSFUL",
+            BackupVaultEvent::BackupJobFailed => "BACKUP_JOB_FAILED",
+            BackupVaultEvent::BackupJobExpired => "BACKUP_JOB_EXPIRED",
+            BackupVaultEvent::RestoreJobStarted => "RESTORE_JOB_STARTED",
+            BackupVaultEvent::RestoreJobCompleted => "RESTORE_JOB_COMPLETED",
+            BackupVaultEvent::RestoreJobSuccessful => "RESTORE_JOB_SUCCESSFUL",
+            BackupVaultEvent::RestoreJobFailed => "RESTORE_JOB_FAILED",
+            BackupVaultEvent::CopyJobStarted => "COPY_JOB_STARTED",
+            BackupVaultEvent::CopyJobSuccessful => "COPY_JOB_SUCCESSFUL",
+            BackupVaultEvent::CopyJobFailed => "COPY_JOB_FAILED",
+            BackupVaultEvent::RecoveryPointModified => "RECOVERY_POINT_MODIFIED",
+            BackupVaultEvent::BackupPlanCreated => "BACKUP_PLAN_CREATED",
+            BackupVaultEvent::BackupPlanModified => "BACKUP_PLAN_MODIFIED",
+            BackupVaultEvent::S3BackupObjectFailed => "S3_BACKUP_OBJECT_FAILED",
+            BackupVaultEvent::S3RestoreObjectFailed => "S3_RESTORE_OBJECT_FAILED",
+            BackupVaultEvent::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for BackupVaultEvent {
+    fn default() -> BackupVaultEvent {
+        BackupVaultEvent::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for BackupVaultEvent {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for BackupVaultEvent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for BackupVaultEvent {
+    fn from(value: &'a str) -> BackupVaultEvent {
+        match value {
+            "BACKUP_JOB_STARTED" => BackupVaultEvent::BackupJobStarted,
+            "BACKUP_JOB_COMPLETED" => BackupVaultEvent::BackupJobCompleted,
+            "BACKUP_JOB_SUCCESSFUL" => BackupVaultEvent::BackupJobSuccessful,
+            "BACKUP_JOB_FAILED" => BackupVaultEvent::BackupJobFailed,
+            "BACKUP_JOB_EXPIRED" => BackupVaultEvent::BackupJobExpired,
+            "RESTORE_JOB_STARTED" => BackupVaultEvent::RestoreJobStarted,
+            "RESTORE_JOB_COMPLETED" => BackupVaultEvent::RestoreJobCompleted,
+            "RESTORE_JOB_SUCCESSFUL" => BackupVaultEvent::RestoreJobSuccessful,
+            "RESTORE_JOB_FAILED" => BackupVaultEvent::RestoreJobFailed,
+            "COPY_JOB_STARTED" => BackupVaultEvent::CopyJobStarted,
+            "COPY_JOB_SUCCESSFUL" => BackupVaultEvent::CopyJobSuccessful,
+            "COPY_JOB_FAILED" => BackupVaultEvent::CopyJobFailed,
+            "RECOVERY_POINT_MODIFIED" => BackupVaultEvent::RecoveryPointModified,
+            "BACKUP_PLAN_CREATED" => BackupVaultEvent::BackupPlanCreated,
+            "BACKUP_PLAN_MODIFIED" => BackupVaultEvent::BackupPlanModified,
+            "S3_BACKUP_OBJECT_FAILED" => BackupVaultEvent::S3BackupObjectFailed,
+            "S3_RESTORE_OBJECT_FAILED" => BackupVaultEvent::S3RestoreObjectFailed,
+            _ => BackupVaultEvent::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BackupVaultEvent {
+    fn from(value: String) -> BackupVaultEvent {
+        match value.as_str() {
+            "BACKUP_JOB_STARTED" => BackupVaultEvent::BackupJobStarted,
+            "BACKUP_JOB_COMPLETED" => BackupVaultEvent::BackupJobCompleted,
+            "BACKUP_JOB_SUCCESSFUL" => BackupVaultEvent::BackupJobSuccessful,
+            "BACKUP_JOB_FAILED" => BackupVaultEvent::BackupJobFailed,
+            "BACKUP_JOB_EXPIRED" => BackupVaultEvent::BackupJobExpired,
+            "RESTORE_JOB_STARTED" => BackupVaultEvent::RestoreJobStarted,
+            "RESTORE_JOB_COMPLETED" => BackupVaultEvent::RestoreJobCompleted,
+            "RESTORE_JOB_SUCCESSFUL" => BackupVaultEvent::RestoreJobSuccessful,
+            "RESTORE_JOB_FAILED" => BackupVaultEvent::RestoreJobFailed,
+            "COPY_JOB_STARTED" => BackupVaultEvent::CopyJobStarted,
+            "COPY_JOB_SUCCESSFUL" => BackupVaultEvent::CopyJobSuccessful,
+            "COPY_JOB_FAILED" => BackupVaultEvent::CopyJobFailed,
+            "RECOVERY_POINT_MODIFIED" => BackupVaultEvent::RecoveryPointModified,
+            "BACKUP_PLAN_CREATED" => BackupVaultEvent::BackupPlanCreated,
+            "BACKUP_PLAN_MODIFIED" => BackupVaultEvent::BackupPlanModified,
+            "S3_BACKUP_OBJECT_FAILED" => BackupVaultEvent::S3BackupObjectFailed,
+            "S3_RESTORE_OBJECT_FAILED" => BackupVaultEvent::S3RestoreObjectFailed,
+            _ => BackupVaultEvent::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for BackupVaultEvent {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<BackupVaultEvent, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for BackupVaultEvent {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for BackupVaultEvent {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BackupVaultEvent {
+    fn deserialize<D>(deserializer: D) -> Result<BackupVaultEvent, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Contains metadata about a backup vault.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -314,8 +650,13 @@ pub struct BackupVaultListMember {
     pub backup_vault_name: Option<String>,
     /// <p>The date and time a resource backup is created, in Unix format and Coordinated Universal Time (UTC). The value of <code>CreationDate</code> is accurate to milliseconds. For example, the value 1516925490.087 represents Friday, January 26, 2018 12:11:30.087 AM.</p>
     #[serde(rename = "CreationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A unique string that identifies the request and allows failed requests to be retried without the risk of executing the operation twice.</p>
     #[serde(rename = "CreatorRequestId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -336,12 +677,22 @@ pub struct BackupVaultListMember {
 pub struct CalculatedLifecycle {
     /// <p>A timestamp that specifies when to delete a recovery point.</p>
     #[serde(rename = "DeleteAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub delete_at: Option<f64>,
+    pub delete_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A timestamp that specifies when to transition a recovery point to cold storage.</p>
     #[serde(rename = "MoveToColdStorageAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub move_to_cold_storage_at: Option<f64>,
+    pub move_to_cold_storage_at: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>Contains an array of triplets made up of a condition type (such as <code>STRINGEQUALS</code>), a key, and a value. Conditions are used to filter resources in a selection that is assigned to a backup plan.</p>
@@ -352,12 +703,100 @@ pub struct Condition {
     pub condition_key: String,
     /// <p>An operation, such as <code>STRINGEQUALS</code>, that is applied to a key-value pair used to filter resources in a selection.</p>
     #[serde(rename = "ConditionType")]
-    pub condition_type: String,
+    pub condition_type: ConditionType,
     /// <p>The value in a key-value pair. For example, in <code>"ec2:ResourceTag/Department": "accounting"</code>, <code>"accounting"</code> is the value.</p>
     #[serde(rename = "ConditionValue")]
     pub condition_value: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ConditionType {
+    /// `STRINGEQUALS`
+    Stringequals,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ConditionType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ConditionType::Stringequals => "STRINGEQUALS",
+            ConditionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ConditionType {
+    fn default() -> ConditionType {
+        ConditionType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ConditionType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ConditionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ConditionType {
+    fn from(value: &'a str) -> ConditionType {
+        match value {
+            "STRINGEQUALS" => ConditionType::Stringequals,
+            _ => ConditionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ConditionType {
+    fn from(value: String) -> ConditionType {
+        match value.as_str() {
+            "STRINGEQUALS" => ConditionType::Stringequals,
+            _ => ConditionType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ConditionType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ConditionType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ConditionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ConditionType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ConditionType {
+    fn deserialize<D>(deserializer: D) -> Result<ConditionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The details of the copy operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CopyAction {
@@ -379,8 +818,13 @@ pub struct CopyJob {
     pub backup_size_in_bytes: Option<i64>,
     /// <p>The date and time a job to create a copy job is completed, in Unix format and Coordinated Universal Time (UTC). The value of CompletionDate is accurate to milliseconds. For example, the value 1516925490.087 represents Friday, January 26, 2018 12:11:30.087 AM. </p>
     #[serde(rename = "CompletionDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub completion_date: Option<f64>,
+    pub completion_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Uniquely identifies a request to AWS Backup to copy a resource.</p>
     #[serde(rename = "CopyJobId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -390,8 +834,13 @@ pub struct CopyJob {
     pub created_by: Option<RecoveryPointCreator>,
     /// <p>The date and time a copy job is created, in Unix format and Coordinated Universal Time (UTC). The value of CreationDate is accurate to milliseconds. For example, the value 1516925490.087 represents Friday, January 26, 2018 12:11:30.087 AM. </p>
     #[serde(rename = "CreationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>An Amazon Resource Name (ARN) that uniquely identifies a destination copy vault; for example, arn:aws:backup:us-east-1:123456789012:vault:aBackupVault.</p>
     #[serde(rename = "DestinationBackupVaultArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -423,13 +872,121 @@ pub struct CopyJob {
     /// <p>The current state of a resource recovery point.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<CopyJobState>,
     /// <p>A detailed message explaining the status of the job that to copy a resource.</p>
     #[serde(rename = "StatusMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status_message: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CopyJobState {
+    /// `CREATED`
+    Created,
+    /// `RUNNING`
+    Running,
+    /// `COMPLETED`
+    Completed,
+    /// `FAILED`
+    Failed,
+    /// `PARTIAL`
+    Partial,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CopyJobState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CopyJobState::Created => "CREATED",
+            CopyJobState::Running => "RUNNING",
+            CopyJobState::Completed => "COMPLETED",
+            CopyJobState::Failed => "FAILED",
+            CopyJobState::Partial => "PARTIAL",
+            CopyJobState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CopyJobState {
+    fn default() -> CopyJobState {
+        CopyJobState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CopyJobState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CopyJobState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CopyJobState {
+    fn from(value: &'a str) -> CopyJobState {
+        match value {
+            "CREATED" => CopyJobState::Created,
+            "RUNNING" => CopyJobState::Running,
+            "COMPLETED" => CopyJobState::Completed,
+            "FAILED" => CopyJobState::Failed,
+            "PARTIAL" => CopyJobState::Partial,
+            _ => CopyJobState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CopyJobState {
+    fn from(value: String) -> CopyJobState {
+        match value.as_str() {
+            "CREATED" => CopyJobState::Created,
+            "RUNNING" => CopyJobState::Running,
+            "COMPLETED" => CopyJobState::Completed,
+            "FAILED" => CopyJobState::Failed,
+            "PARTIAL" => CopyJobState::Partial,
+            _ => CopyJobState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CopyJobState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CopyJobState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CopyJobState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CopyJobState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CopyJobState {
+    fn deserialize<D>(deserializer: D) -> Result<CopyJobState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateBackupPlanInput {
@@ -459,8 +1016,13 @@ pub struct CreateBackupPlanOutput {
     pub backup_plan_id: Option<String>,
     /// <p>The date and time that a backup plan is created, in Unix format and Coordinated Universal Time (UTC). The value of <code>CreationDate</code> is accurate to milliseconds. For example, the value 1516925490.087 represents Friday, January 26, 2018 12:11:30.087 AM.</p>
     #[serde(rename = "CreationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Unique, randomly generated, Unicode, UTF-8 encoded strings that are at most 1024 bytes long. They cannot be edited.</p>
     #[serde(rename = "VersionId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -491,8 +1053,13 @@ pub struct CreateBackupSelectionOutput {
     pub backup_plan_id: Option<String>,
     /// <p>The date and time a backup selection is created, in Unix format and Coordinated Universal Time (UTC). The value of <code>CreationDate</code> is accurate to milliseconds. For example, the value 1516925490.087 represents Friday, January 26, 2018 12:11:30.087 AM.</p>
     #[serde(rename = "CreationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Uniquely identifies the body of a request to assign a set of resources to a backup plan.</p>
     #[serde(rename = "SelectionId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -532,8 +1099,13 @@ pub struct CreateBackupVaultOutput {
     pub backup_vault_name: Option<String>,
     /// <p>The date and time a backup vault is created, in Unix format and Coordinated Universal Time (UTC). The value of <code>CreationDate</code> is accurate to milliseconds. For example, the value 1516925490.087 represents Friday, January 26, 2018 12:11:30.087 AM.</p>
     #[serde(rename = "CreationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -557,8 +1129,13 @@ pub struct DeleteBackupPlanOutput {
     pub backup_plan_id: Option<String>,
     /// <p>The date and time a backup plan is deleted, in Unix format and Coordinated Universal Time (UTC). The value of <code>CreationDate</code> is accurate to milliseconds. For example, the value 1516925490.087 represents Friday, January 26, 2018 12:11:30.087 AM.</p>
     #[serde(rename = "DeletionDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deletion_date: Option<f64>,
+    pub deletion_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Unique, randomly generated, Unicode, UTF-8 encoded strings that are at most 1,024 bytes long. Version Ids cannot be edited.</p>
     #[serde(rename = "VersionId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -644,20 +1221,35 @@ pub struct DescribeBackupJobOutput {
     pub bytes_transferred: Option<i64>,
     /// <p>The date and time that a job to create a backup job is completed, in Unix format and Coordinated Universal Time (UTC). The value of <code>CreationDate</code> is accurate to milliseconds. For example, the value 1516925490.087 represents Friday, January 26, 2018 12:11:30.087 AM.</p>
     #[serde(rename = "CompletionDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub completion_date: Option<f64>,
+    pub completion_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Contains identifying information about the creation of a backup job, including the <code>BackupPlanArn</code>, <code>BackupPlanId</code>, <code>BackupPlanVersion</code>, and <code>BackupRuleId</code> of the backup plan that is used to create it.</p>
     #[serde(rename = "CreatedBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub created_by: Option<RecoveryPointCreator>,
     /// <p>The date and time that a backup job is created, in Unix format and Coordinated Universal Time (UTC). The value of <code>CreationDate</code> is accurate to milliseconds. For example, the value 1516925490.087 represents Friday, January 26, 2018 12:11:30.087 AM.</p>
     #[serde(rename = "CreationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The date and time that a job to back up resources is expected to be completed, in Unix format and Coordinated Universal Time (UTC). The value of <code>ExpectedCompletionDate</code> is accurate to milliseconds. For example, the value 1516925490.087 represents Friday, January 26, 2018 12:11:30.087 AM.</p>
     #[serde(rename = "ExpectedCompletionDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expected_completion_date: Option<f64>,
+    pub expected_completion_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Specifies the IAM role ARN used to create the target recovery point; for example, <code>arn:aws:iam::123456789012:role/S3Access</code>.</p>
     #[serde(rename = "IamRoleArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -680,12 +1272,17 @@ pub struct DescribeBackupJobOutput {
     pub resource_type: Option<String>,
     /// <p>Specifies the time in Unix format and Coordinated Universal Time (UTC) when a backup job must be started before it is canceled. The value is calculated by adding the start window to the scheduled time. So if the scheduled time were 6:00 PM and the start window is 2 hours, the <code>StartBy</code> time would be 8:00 PM on the date specified. The value of <code>StartBy</code> is accurate to milliseconds. For example, the value 1516925490.087 represents Friday, January 26, 2018 12:11:30.087 AM.</p>
     #[serde(rename = "StartBy")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_by: Option<f64>,
+    pub start_by: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The current state of a resource recovery point.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<BackupJobState>,
     /// <p>A detailed message explaining the status of the job to back up a resource.</p>
     #[serde(rename = "StatusMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -713,8 +1310,13 @@ pub struct DescribeBackupVaultOutput {
     pub backup_vault_name: Option<String>,
     /// <p>The date and time that a backup vault is created, in Unix format and Coordinated Universal Time (UTC). The value of <code>CreationDate</code> is accurate to milliseconds. For example, the value 1516925490.087 represents Friday, January 26, 2018 12:11:30.087 AM.</p>
     #[serde(rename = "CreationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A unique string that identifies the request and allows failed requests to be retried without the risk of executing the operation twice.</p>
     #[serde(rename = "CreatorRequestId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -759,8 +1361,13 @@ pub struct DescribeProtectedResourceInput {
 pub struct DescribeProtectedResourceOutput {
     /// <p>The date and time that a resource was last backed up, in Unix format and Coordinated Universal Time (UTC). The value of <code>LastBackupTime</code> is accurate to milliseconds. For example, the value 1516925490.087 represents Friday, January 26, 2018 12:11:30.087 AM.</p>
     #[serde(rename = "LastBackupTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_backup_time: Option<f64>,
+    pub last_backup_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>An ARN that uniquely identifies a resource. The format of the ARN depends on the resource type.</p>
     #[serde(rename = "ResourceArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -803,16 +1410,26 @@ pub struct DescribeRecoveryPointOutput {
     pub calculated_lifecycle: Option<CalculatedLifecycle>,
     /// <p>The date and time that a job to create a recovery point is completed, in Unix format and Coordinated Universal Time (UTC). The value of <code>CompletionDate</code> is accurate to milliseconds. For example, the value 1516925490.087 represents Friday, January 26, 2018 12:11:30.087 AM.</p>
     #[serde(rename = "CompletionDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub completion_date: Option<f64>,
+    pub completion_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Contains identifying information about the creation of a recovery point, including the <code>BackupPlanArn</code>, <code>BackupPlanId</code>, <code>BackupPlanVersion</code>, and <code>BackupRuleId</code> of the backup plan used to create it.</p>
     #[serde(rename = "CreatedBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub created_by: Option<RecoveryPointCreator>,
     /// <p>The date and time that a recovery point is created, in Unix format and Coordinated Universal Time (UTC). The value of <code>CreationDate</code> is accurate to milliseconds. For example, the value 1516925490.087 represents Friday, January 26, 2018 12:11:30.087 AM.</p>
     #[serde(rename = "CreationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The server-side encryption key used to protect your backups; for example, <code>arn:aws:kms:us-west-2:111122223333:key/1234abcd-12ab-34cd-56ef-1234567890ab</code>.</p>
     #[serde(rename = "EncryptionKeyArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -827,8 +1444,13 @@ pub struct DescribeRecoveryPointOutput {
     pub is_encrypted: Option<bool>,
     /// <p>The date and time that a recovery point was last restored, in Unix format and Coordinated Universal Time (UTC). The value of <code>LastRestoreTime</code> is accurate to milliseconds. For example, the value 1516925490.087 represents Friday, January 26, 2018 12:11:30.087 AM.</p>
     #[serde(rename = "LastRestoreTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_restore_time: Option<f64>,
+    pub last_restore_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The lifecycle defines when a protected resource is transitioned to cold storage and when it expires. AWS Backup transitions and expires backups automatically according to the lifecycle that you define. </p> <p>Backups that are transitioned to cold storage must be stored in cold storage for a minimum of 90 days. Therefore, the “expire after days” setting must be 90 days greater than the “transition to cold after days” setting. The “transition to cold after days” setting cannot be changed after a backup has been transitioned to cold. </p>
     #[serde(rename = "Lifecycle")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -848,11 +1470,11 @@ pub struct DescribeRecoveryPointOutput {
     /// <p><p>A status code specifying the state of the recovery point.</p> <note> <p>A partial status indicates that the recovery point was not successfully re-created and must be retried.</p> </note></p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<RecoveryPointStatus>,
     /// <p>Specifies the storage class of the recovery point. Valid values are <code>WARM</code> or <code>COLD</code>.</p>
     #[serde(rename = "StorageClass")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub storage_class: Option<String>,
+    pub storage_class: Option<StorageClass>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -872,16 +1494,26 @@ pub struct DescribeRestoreJobOutput {
     pub backup_size_in_bytes: Option<i64>,
     /// <p>The date and time that a job to restore a recovery point is completed, in Unix format and Coordinated Universal Time (UTC). The value of <code>CompletionDate</code> is accurate to milliseconds. For example, the value 1516925490.087 represents Friday, January 26, 2018 12:11:30.087 AM.</p>
     #[serde(rename = "CompletionDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub completion_date: Option<f64>,
+    pub completion_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>An Amazon Resource Name (ARN) that uniquely identifies a resource whose recovery point is being restored. The format of the ARN depends on the resource type of the backed-up resource.</p>
     #[serde(rename = "CreatedResourceArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub created_resource_arn: Option<String>,
     /// <p>The date and time that a restore job is created, in Unix format and Coordinated Universal Time (UTC). The value of <code>CreationDate</code> is accurate to milliseconds. For example, the value 1516925490.087 represents Friday, January 26, 2018 12:11:30.087 AM.</p>
     #[serde(rename = "CreationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The amount of time in minutes that a job restoring a recovery point is expected to take.</p>
     #[serde(rename = "ExpectedCompletionTimeMinutes")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -905,7 +1537,7 @@ pub struct DescribeRestoreJobOutput {
     /// <p>Status code specifying the state of the job that is initiated by AWS Backup to restore a recovery point.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<RestoreJobStatus>,
     /// <p>A detailed message explaining the status of a job to restore a recovery point.</p>
     #[serde(rename = "StatusMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -992,20 +1624,35 @@ pub struct GetBackupPlanOutput {
     pub backup_plan_id: Option<String>,
     /// <p>The date and time that a backup plan is created, in Unix format and Coordinated Universal Time (UTC). The value of <code>CreationDate</code> is accurate to milliseconds. For example, the value 1516925490.087 represents Friday, January 26, 2018 12:11:30.087 AM.</p>
     #[serde(rename = "CreationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A unique string that identifies the request and allows failed requests to be retried without the risk of executing the operation twice.</p>
     #[serde(rename = "CreatorRequestId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub creator_request_id: Option<String>,
     /// <p>The date and time that a backup plan is deleted, in Unix format and Coordinated Universal Time (UTC). The value of <code>CreationDate</code> is accurate to milliseconds. For example, the value 1516925490.087 represents Friday, January 26, 2018 12:11:30.087 AM.</p>
     #[serde(rename = "DeletionDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deletion_date: Option<f64>,
+    pub deletion_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The last time a job to back up resources was executed with this backup plan. A date and time, in Unix format and Coordinated Universal Time (UTC). The value of <code>LastExecutionDate</code> is accurate to milliseconds. For example, the value 1516925490.087 represents Friday, January 26, 2018 12:11:30.087 AM.</p>
     #[serde(rename = "LastExecutionDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_execution_date: Option<f64>,
+    pub last_execution_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Unique, randomly generated, Unicode, UTF-8 encoded strings that are at most 1,024 bytes long. Version IDs cannot be edited.</p>
     #[serde(rename = "VersionId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1036,8 +1683,13 @@ pub struct GetBackupSelectionOutput {
     pub backup_selection: Option<BackupSelection>,
     /// <p>The date and time a backup selection is created, in Unix format and Coordinated Universal Time (UTC). The value of <code>CreationDate</code> is accurate to milliseconds. For example, the value 1516925490.087 represents Friday, January 26, 2018 12:11:30.087 AM.</p>
     #[serde(rename = "CreationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A unique string that identifies the request and allows failed requests to be retried without the risk of executing the operation twice.</p>
     #[serde(rename = "CreatorRequestId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1091,7 +1743,7 @@ pub struct GetBackupVaultNotificationsOutput {
     /// <p>An array of events that indicate the status of jobs to back up resources to the backup vault.</p>
     #[serde(rename = "BackupVaultEvents")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub backup_vault_events: Option<Vec<String>>,
+    pub backup_vault_events: Option<Vec<BackupVaultEvent>>,
     /// <p>The name of a logical container where backups are stored. Backup vaults are identified by names that are unique to the account used to create them and the Region where they are created. They consist of lowercase letters, numbers, and hyphens.</p>
     #[serde(rename = "BackupVaultName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1161,12 +1813,22 @@ pub struct ListBackupJobsInput {
     pub by_backup_vault_name: Option<String>,
     /// <p>Returns only backup jobs that were created after the specified date.</p>
     #[serde(rename = "ByCreatedAfter")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub by_created_after: Option<f64>,
+    pub by_created_after: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Returns only backup jobs that were created before the specified date.</p>
     #[serde(rename = "ByCreatedBefore")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub by_created_before: Option<f64>,
+    pub by_created_before: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Returns only backup jobs that match the specified resource Amazon Resource Name (ARN).</p>
     #[serde(rename = "ByResourceArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1178,7 +1840,7 @@ pub struct ListBackupJobsInput {
     /// <p>Returns only backup jobs that are in the specified state.</p>
     #[serde(rename = "ByState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub by_state: Option<String>,
+    pub by_state: Option<BackupJobState>,
     /// <p>The maximum number of items to be returned.</p>
     #[serde(rename = "MaxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1347,12 +2009,22 @@ pub struct ListBackupVaultsOutput {
 pub struct ListCopyJobsInput {
     /// <p>Returns only copy jobs that were created after the specified date.</p>
     #[serde(rename = "ByCreatedAfter")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub by_created_after: Option<f64>,
+    pub by_created_after: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Returns only copy jobs that were created before the specified date.</p>
     #[serde(rename = "ByCreatedBefore")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub by_created_before: Option<f64>,
+    pub by_created_before: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>An Amazon Resource Name (ARN) that uniquely identifies a source backup vault to copy from; for example, arn:aws:backup:us-east-1:123456789012:vault:aBackupVault. </p>
     #[serde(rename = "ByDestinationVaultArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1368,7 +2040,7 @@ pub struct ListCopyJobsInput {
     /// <p>Returns only copy jobs that are in the specified state.</p>
     #[serde(rename = "ByState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub by_state: Option<String>,
+    pub by_state: Option<CopyJobState>,
     /// <p>The maximum number of items to be returned.</p>
     #[serde(rename = "MaxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1430,12 +2102,22 @@ pub struct ListRecoveryPointsByBackupVaultInput {
     pub by_backup_plan_id: Option<String>,
     /// <p>Returns only recovery points that were created after the specified timestamp.</p>
     #[serde(rename = "ByCreatedAfter")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub by_created_after: Option<f64>,
+    pub by_created_after: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Returns only recovery points that were created before the specified timestamp.</p>
     #[serde(rename = "ByCreatedBefore")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub by_created_before: Option<f64>,
+    pub by_created_before: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Returns only recovery points that match the specified resource Amazon Resource Name (ARN).</p>
     #[serde(rename = "ByResourceArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1557,8 +2239,13 @@ pub struct ListTagsOutput {
 pub struct ProtectedResource {
     /// <p>The date and time a resource was last backed up, in Unix format and Coordinated Universal Time (UTC). The value of <code>LastBackupTime</code> is accurate to milliseconds. For example, the value 1516925490.087 represents Friday, January 26, 2018 12:11:30.087 AM.</p>
     #[serde(rename = "LastBackupTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_backup_time: Option<f64>,
+    pub last_backup_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>An Amazon Resource Name (ARN) that uniquely identifies a resource. The format of the ARN depends on the resource type.</p>
     #[serde(rename = "ResourceArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1586,7 +2273,7 @@ pub struct PutBackupVaultAccessPolicyInput {
 pub struct PutBackupVaultNotificationsInput {
     /// <p>An array of events that indicate the status of jobs to back up resources to the backup vault.</p>
     #[serde(rename = "BackupVaultEvents")]
-    pub backup_vault_events: Vec<String>,
+    pub backup_vault_events: Vec<BackupVaultEvent>,
     /// <p>The name of a logical container where backups are stored. Backup vaults are identified by names that are unique to the account used to create them and the AWS Region where they are created. They consist of lowercase letters, numbers, and hyphens.</p>
     #[serde(rename = "BackupVaultName")]
     pub backup_vault_name: String,
@@ -1617,16 +2304,26 @@ pub struct RecoveryPointByBackupVault {
     pub calculated_lifecycle: Option<CalculatedLifecycle>,
     /// <p>The date and time a job to restore a recovery point is completed, in Unix format and Coordinated Universal Time (UTC). The value of <code>CompletionDate</code> is accurate to milliseconds. For example, the value 1516925490.087 represents Friday, January 26, 2018 12:11:30.087 AM.</p>
     #[serde(rename = "CompletionDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub completion_date: Option<f64>,
+    pub completion_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Contains identifying information about the creation of a recovery point, including the <code>BackupPlanArn</code>, <code>BackupPlanId</code>, <code>BackupPlanVersion</code>, and <code>BackupRuleId</code> of the backup plan that is used to create it.</p>
     #[serde(rename = "CreatedBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub created_by: Option<RecoveryPointCreator>,
     /// <p>The date and time a recovery point is created, in Unix format and Coordinated Universal Time (UTC). The value of <code>CreationDate</code> is accurate to milliseconds. For example, the value 1516925490.087 represents Friday, January 26, 2018 12:11:30.087 AM.</p>
     #[serde(rename = "CreationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The server-side encryption key that is used to protect your backups; for example, <code>arn:aws:kms:us-west-2:111122223333:key/1234abcd-12ab-34cd-56ef-1234567890ab</code>.</p>
     #[serde(rename = "EncryptionKeyArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1641,8 +2338,13 @@ pub struct RecoveryPointByBackupVault {
     pub is_encrypted: Option<bool>,
     /// <p>The date and time a recovery point was last restored, in Unix format and Coordinated Universal Time (UTC). The value of <code>LastRestoreTime</code> is accurate to milliseconds. For example, the value 1516925490.087 represents Friday, January 26, 2018 12:11:30.087 AM.</p>
     #[serde(rename = "LastRestoreTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_restore_time: Option<f64>,
+    pub last_restore_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The lifecycle defines when a protected resource is transitioned to cold storage and when it expires. AWS Backup transitions and expires backups automatically according to the lifecycle that you define. </p> <p>Backups transitioned to cold storage must be stored in cold storage for a minimum of 90 days. Therefore, the “expire after days” setting must be 90 days greater than the “transition to cold after days” setting. The “transition to cold after days” setting cannot be changed after a backup has been transitioned to cold. </p>
     #[serde(rename = "Lifecycle")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1662,7 +2364,7 @@ pub struct RecoveryPointByBackupVault {
     /// <p>A status code specifying the state of the recovery point.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<RecoveryPointStatus>,
 }
 
 /// <p>Contains detailed information about a saved recovery point.</p>
@@ -1679,8 +2381,13 @@ pub struct RecoveryPointByResource {
     pub backup_vault_name: Option<String>,
     /// <p>The date and time a recovery point is created, in Unix format and Coordinated Universal Time (UTC). The value of <code>CreationDate</code> is accurate to milliseconds. For example, the value 1516925490.087 represents Friday, January 26, 2018 12:11:30.087 AM.</p>
     #[serde(rename = "CreationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The server-side encryption key that is used to protect your backups; for example, <code>arn:aws:kms:us-west-2:111122223333:key/1234abcd-12ab-34cd-56ef-1234567890ab</code>.</p>
     #[serde(rename = "EncryptionKeyArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1692,7 +2399,7 @@ pub struct RecoveryPointByResource {
     /// <p>A status code specifying the state of the recovery point.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<RecoveryPointStatus>,
 }
 
 /// <p>Contains information about the backup plan and rule that AWS Backup used to initiate the recovery point backup.</p>
@@ -1717,6 +2424,217 @@ pub struct RecoveryPointCreator {
     pub backup_rule_id: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RecoveryPointStatus {
+    /// `COMPLETED`
+    Completed,
+    /// `PARTIAL`
+    Partial,
+    /// `DELETING`
+    Deleting,
+    /// `EXPIRED`
+    Expired,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RecoveryPointStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RecoveryPointStatus::Completed => "COMPLETED",
+            RecoveryPointStatus::Partial => "PARTIAL",
+            RecoveryPointStatus::Deleting => "DELETING",
+            RecoveryPointStatus::Expired => "EXPIRED",
+            RecoveryPointStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RecoveryPointStatus {
+    fn default() -> RecoveryPointStatus {
+        RecoveryPointStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RecoveryPointStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RecoveryPointStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RecoveryPointStatus {
+    fn from(value: &'a str) -> RecoveryPointStatus {
+        match value {
+            "COMPLETED" => RecoveryPointStatus::Completed,
+            "PARTIAL" => RecoveryPointStatus::Partial,
+            "DELETING" => RecoveryPointStatus::Deleting,
+            "EXPIRED" => RecoveryPointStatus::Expired,
+            _ => RecoveryPointStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RecoveryPointStatus {
+    fn from(value: String) -> RecoveryPointStatus {
+        match value.as_str() {
+            "COMPLETED" => RecoveryPointStatus::Completed,
+            "PARTIAL" => RecoveryPointStatus::Partial,
+            "DELETING" => RecoveryPointStatus::Deleting,
+            "EXPIRED" => RecoveryPointStatus::Expired,
+            _ => RecoveryPointStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RecoveryPointStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RecoveryPointStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RecoveryPointStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RecoveryPointStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RecoveryPointStatus {
+    fn deserialize<D>(deserializer: D) -> Result<RecoveryPointStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RestoreJobStatus {
+    /// `PENDING`
+    Pending,
+    /// `RUNNING`
+    Running,
+    /// `COMPLETED`
+    Completed,
+    /// `ABORTED`
+    Aborted,
+    /// `FAILED`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RestoreJobStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RestoreJobStatus::Pending => "PENDING",
+            RestoreJobStatus::Running => "RUNNING",
+            RestoreJobStatus::Completed => "COMPLETED",
+            RestoreJobStatus::Aborted => "ABORTED",
+            RestoreJobStatus::Failed => "FAILED",
+            RestoreJobStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RestoreJobStatus {
+    fn default() -> RestoreJobStatus {
+        RestoreJobStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RestoreJobStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RestoreJobStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RestoreJobStatus {
+    fn from(value: &'a str) -> RestoreJobStatus {
+        match value {
+            "PENDING" => RestoreJobStatus::Pending,
+            "RUNNING" => RestoreJobStatus::Running,
+            "COMPLETED" => RestoreJobStatus::Completed,
+            "ABORTED" => RestoreJobStatus::Aborted,
+            "FAILED" => RestoreJobStatus::Failed,
+            _ => RestoreJobStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RestoreJobStatus {
+    fn from(value: String) -> RestoreJobStatus {
+        match value.as_str() {
+            "PENDING" => RestoreJobStatus::Pending,
+            "RUNNING" => RestoreJobStatus::Running,
+            "COMPLETED" => RestoreJobStatus::Completed,
+            "ABORTED" => RestoreJobStatus::Aborted,
+            "FAILED" => RestoreJobStatus::Failed,
+            _ => RestoreJobStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RestoreJobStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RestoreJobStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RestoreJobStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RestoreJobStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RestoreJobStatus {
+    fn deserialize<D>(deserializer: D) -> Result<RestoreJobStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Contains metadata about a restore job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1727,16 +2645,26 @@ pub struct RestoreJobsListMember {
     pub backup_size_in_bytes: Option<i64>,
     /// <p>The date and time a job to restore a recovery point is completed, in Unix format and Coordinated Universal Time (UTC). The value of <code>CompletionDate</code> is accurate to milliseconds. For example, the value 1516925490.087 represents Friday, January 26, 2018 12:11:30.087 AM.</p>
     #[serde(rename = "CompletionDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub completion_date: Option<f64>,
+    pub completion_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>An Amazon Resource Name (ARN) that uniquely identifies a resource. The format of the ARN depends on the resource type.</p>
     #[serde(rename = "CreatedResourceArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub created_resource_arn: Option<String>,
     /// <p>The date and time a restore job is created, in Unix format and Coordinated Universal Time (UTC). The value of <code>CreationDate</code> is accurate to milliseconds. For example, the value 1516925490.087 represents Friday, January 26, 2018 12:11:30.087 AM.</p>
     #[serde(rename = "CreationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The amount of time in minutes that a job restoring a recovery point is expected to take.</p>
     #[serde(rename = "ExpectedCompletionTimeMinutes")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1760,7 +2688,7 @@ pub struct RestoreJobsListMember {
     /// <p>A status code specifying the state of the job initiated by AWS Backup to restore a recovery point.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<RestoreJobStatus>,
     /// <p>A detailed message explaining the status of the job to restore a recovery point.</p>
     #[serde(rename = "StatusMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1810,8 +2738,13 @@ pub struct StartBackupJobOutput {
     pub backup_job_id: Option<String>,
     /// <p>The date and time that a backup job is started, in Unix format and Coordinated Universal Time (UTC). The value of <code>CreationDate</code> is accurate to milliseconds. For example, the value 1516925490.087 represents Friday, January 26, 2018 12:11:30.087 AM.</p>
     #[serde(rename = "CreationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>An ARN that uniquely identifies a recovery point; for example, <code>arn:aws:backup:us-east-1:123456789012:recovery-point:1EB3B5E7-9EB0-435A-A80B-108B488B0D45</code>.</p>
     #[serde(rename = "RecoveryPointArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1851,8 +2784,13 @@ pub struct StartCopyJobOutput {
     pub copy_job_id: Option<String>,
     /// <p>The date and time that a backup job is started, in Unix format and Coordinated Universal Time (UTC). The value of CreationDate is accurate to milliseconds. For example, the value 1516925490.087 represents Friday, January 26, 2018 12:11:30.087 AM. &gt;</p>
     #[serde(rename = "CreationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -1894,6 +2832,104 @@ pub struct StopBackupJobInput {
     pub backup_job_id: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StorageClass {
+    /// `WARM`
+    Warm,
+    /// `COLD`
+    Cold,
+    /// `DELETED`
+    Deleted,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl StorageClass {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StorageClass::Warm => "WARM",
+            StorageClass::Cold => "COLD",
+            StorageClass::Deleted => "DELETED",
+            StorageClass::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for StorageClass {
+    fn default() -> StorageClass {
+        StorageClass::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for StorageClass {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for StorageClass {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for StorageClass {
+    fn from(value: &'a str) -> StorageClass {
+        match value {
+            "WARM" => StorageClass::Warm,
+            "COLD" => StorageClass::Cold,
+            "DELETED" => StorageClass::Deleted,
+            _ => StorageClass::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StorageClass {
+    fn from(value: String) -> StorageClass {
+        match value.as_str() {
+            "WARM" => StorageClass::Warm,
+            "COLD" => StorageClass::Cold,
+            "DELETED" => StorageClass::Deleted,
+            _ => StorageClass::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for StorageClass {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<StorageClass, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StorageClass {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for StorageClass {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StorageClass {
+    fn deserialize<D>(deserializer: D) -> Result<StorageClass, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct TagResourceInput {
@@ -1940,8 +2976,13 @@ pub struct UpdateBackupPlanOutput {
     pub backup_plan_id: Option<String>,
     /// <p>The date and time a backup plan is updated, in Unix format and Coordinated Universal Time (UTC). The value of <code>CreationDate</code> is accurate to milliseconds. For example, the value 1516925490.087 represents Friday, January 26, 2018 12:11:30.087 AM.</p>
     #[serde(rename = "CreationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Unique, randomly generated, Unicode, UTF-8 encoded strings that are at most 1,024 bytes long. Version Ids cannot be edited.</p>
     #[serde(rename = "VersionId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4874,11 +5915,12 @@ pub struct BackupClient {
 impl BackupClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> BackupClient {
         BackupClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Backup"),
         }
     }
 
@@ -4912,6 +5954,7 @@ impl Backup for BackupClient {
         let request_uri = "/backup/plans/";
 
         let mut request = SignedRequest::new("PUT", "backup", &self.region, &request_uri);
+        request.set_operation("CreateBackupPlan");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4945,6 +5988,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("PUT", "backup", &self.region, &request_uri);
+        request.set_operation("CreateBackupSelection");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4978,6 +6022,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("PUT", "backup", &self.region, &request_uri);
+        request.set_operation("CreateBackupVault");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5011,6 +6056,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "backup", &self.region, &request_uri);
+        request.set_operation("DeleteBackupPlan");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5042,6 +6088,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "backup", &self.region, &request_uri);
+        request.set_operation("DeleteBackupSelection");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5071,6 +6118,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "backup", &self.region, &request_uri);
+        request.set_operation("DeleteBackupVault");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5100,6 +6148,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "backup", &self.region, &request_uri);
+        request.set_operation("DeleteBackupVaultAccessPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5129,6 +6178,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "backup", &self.region, &request_uri);
+        request.set_operation("DeleteBackupVaultNotifications");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5159,6 +6209,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "backup", &self.region, &request_uri);
+        request.set_operation("DeleteRecoveryPoint");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5188,6 +6239,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("DescribeBackupJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5218,6 +6270,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("DescribeBackupVault");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5245,6 +6298,7 @@ impl Backup for BackupClient {
         let request_uri = format!("/copy-jobs/{copy_job_id}", copy_job_id = input.copy_job_id);
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("DescribeCopyJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5275,6 +6329,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("DescribeProtectedResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5306,6 +6361,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("DescribeRecoveryPoint");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5336,6 +6392,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("DescribeRestoreJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5366,6 +6423,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("ExportBackupPlanTemplate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5396,6 +6454,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("GetBackupPlan");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5429,6 +6488,7 @@ impl Backup for BackupClient {
         let request_uri = "/backup/template/json/toPlan";
 
         let mut request = SignedRequest::new("POST", "backup", &self.region, &request_uri);
+        request.set_operation("GetBackupPlanFromJSON");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5462,6 +6522,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("GetBackupPlanFromTemplate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5493,6 +6554,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("GetBackupSelection");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5524,6 +6586,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("GetBackupVaultAccessPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5555,6 +6618,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("GetBackupVaultNotifications");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5585,6 +6649,7 @@ impl Backup for BackupClient {
         let request_uri = format!("/backup-vaults/{backup_vault_name}/recovery-points/{recovery_point_arn}/restore-metadata", backup_vault_name = input.backup_vault_name, recovery_point_arn = input.recovery_point_arn);
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("GetRecoveryPointRestoreMetadata");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5613,6 +6678,7 @@ impl Backup for BackupClient {
         let request_uri = "/supported-resource-types";
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("GetSupportedResourceTypes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5640,6 +6706,7 @@ impl Backup for BackupClient {
         let request_uri = "/backup-jobs/";
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("ListBackupJobs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5647,10 +6714,16 @@ impl Backup for BackupClient {
             params.put("backupVaultName", x);
         }
         if let Some(ref x) = input.by_created_after {
-            params.put("createdAfter", x);
+            params.put(
+                "createdAfter",
+                &rusoto_core::serialization::TimestampFormat::Iso8601.format(x),
+            );
         }
         if let Some(ref x) = input.by_created_before {
-            params.put("createdBefore", x);
+            params.put(
+                "createdBefore",
+                &rusoto_core::serialization::TimestampFormat::Iso8601.format(x),
+            );
         }
         if let Some(ref x) = input.by_resource_arn {
             params.put("resourceArn", x);
@@ -5694,6 +6767,7 @@ impl Backup for BackupClient {
         let request_uri = "/backup/template/plans";
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("ListBackupPlanTemplates");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5733,6 +6807,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("ListBackupPlanVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5769,6 +6844,7 @@ impl Backup for BackupClient {
         let request_uri = "/backup/plans/";
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("ListBackupPlans");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5811,6 +6887,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("ListBackupSelections");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5847,6 +6924,7 @@ impl Backup for BackupClient {
         let request_uri = "/backup-vaults/";
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("ListBackupVaults");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5883,14 +6961,21 @@ impl Backup for BackupClient {
         let request_uri = "/copy-jobs/";
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("ListCopyJobs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
         if let Some(ref x) = input.by_created_after {
-            params.put("createdAfter", x);
+            params.put(
+                "createdAfter",
+                &rusoto_core::serialization::TimestampFormat::Iso8601.format(x),
+            );
         }
         if let Some(ref x) = input.by_created_before {
-            params.put("createdBefore", x);
+            params.put(
+                "createdBefore",
+                &rusoto_core::serialization::TimestampFormat::Iso8601.format(x),
+            );
         }
         if let Some(ref x) = input.by_destination_vault_arn {
             params.put("destinationVaultArn", x);
@@ -5937,6 +7022,7 @@ impl Backup for BackupClient {
         let request_uri = "/resources/";
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("ListProtectedResources");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5979,6 +7065,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("ListRecoveryPointsByBackupVault");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5986,10 +7073,16 @@ impl Backup for BackupClient {
             params.put("backupPlanId", x);
         }
         if let Some(ref x) = input.by_created_after {
-            params.put("createdAfter", x);
+            params.put(
+                "createdAfter",
+                &rusoto_core::serialization::TimestampFormat::Iso8601.format(x),
+            );
         }
         if let Some(ref x) = input.by_created_before {
-            params.put("createdBefore", x);
+            params.put(
+                "createdBefore",
+                &rusoto_core::serialization::TimestampFormat::Iso8601.format(x),
+            );
         }
         if let Some(ref x) = input.by_resource_arn {
             params.put("resourceArn", x);
@@ -6036,6 +7129,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("ListRecoveryPointsByResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6072,6 +7166,7 @@ impl Backup for BackupClient {
         let request_uri = "/restore-jobs/";
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("ListRestoreJobs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6108,6 +7203,7 @@ impl Backup for BackupClient {
         let request_uri = format!("/tags/{resource_arn}/", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("ListTags");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6147,6 +7243,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("PUT", "backup", &self.region, &request_uri);
+        request.set_operation("PutBackupVaultAccessPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6179,6 +7276,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("PUT", "backup", &self.region, &request_uri);
+        request.set_operation("PutBackupVaultNotifications");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6208,6 +7306,7 @@ impl Backup for BackupClient {
         let request_uri = "/backup-jobs";
 
         let mut request = SignedRequest::new("PUT", "backup", &self.region, &request_uri);
+        request.set_operation("StartBackupJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6238,6 +7337,7 @@ impl Backup for BackupClient {
         let request_uri = "/copy-jobs";
 
         let mut request = SignedRequest::new("PUT", "backup", &self.region, &request_uri);
+        request.set_operation("StartCopyJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6268,6 +7368,7 @@ impl Backup for BackupClient {
         let request_uri = "/restore-jobs";
 
         let mut request = SignedRequest::new("PUT", "backup", &self.region, &request_uri);
+        request.set_operation("StartRestoreJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6301,6 +7402,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("POST", "backup", &self.region, &request_uri);
+        request.set_operation("StopBackupJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -6327,6 +7429,7 @@ impl Backup for BackupClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "backup", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6356,6 +7459,7 @@ impl Backup for BackupClient {
         let request_uri = format!("/untag/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "backup", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6388,6 +7492,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("POST", "backup", &self.region, &request_uri);
+        request.set_operation("UpdateBackupPlan");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6423,6 +7528,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("POST", "backup", &self.region, &request_uri);
+        request.set_operation("UpdateRecoveryPointLifecycle");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6445,3 +7551,380 @@ impl Backup for BackupClient {
         }
     }
 }
+impl BackupClient {
+    /// Auto-paginating version of `list_backup_jobs`, streaming every page of results.
+    pub fn list_backup_jobs_pages(
+        &self,
+        input: ListBackupJobsInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListBackupJobsOutput, ListBackupJobsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: BackupClient, input: ListBackupJobsInput| async move {
+                client.list_backup_jobs(input).await
+            },
+            |input: &ListBackupJobsInput, output: &ListBackupJobsOutput| {
+                Some(ListBackupJobsInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_backup_jobs`, streaming the `BackupJobs` of every page of results.
+    pub fn list_backup_jobs_items(
+        &self,
+        input: ListBackupJobsInput,
+    ) -> rusoto_core::pagination::RusotoStream<BackupJob, ListBackupJobsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_backup_jobs_pages(input),
+            |output: ListBackupJobsOutput| output.backup_jobs.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_backup_plan_templates`, streaming every page of results.
+    pub fn list_backup_plan_templates_pages(
+        &self,
+        input: ListBackupPlanTemplatesInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListBackupPlanTemplatesOutput,
+        ListBackupPlanTemplatesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: BackupClient, input: ListBackupPlanTemplatesInput| async move {
+                client.list_backup_plan_templates(input).await
+            },
+            |input: &ListBackupPlanTemplatesInput, output: &ListBackupPlanTemplatesOutput| {
+                Some(ListBackupPlanTemplatesInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_backup_plan_templates`, streaming the `BackupPlanTemplatesList` of every page of results.
+    pub fn list_backup_plan_templates_items(
+        &self,
+        input: ListBackupPlanTemplatesInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        BackupPlanTemplatesListMember,
+        ListBackupPlanTemplatesError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_backup_plan_templates_pages(input),
+            |output: ListBackupPlanTemplatesOutput| {
+                output.backup_plan_templates_list.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_backup_plan_versions`, streaming every page of results.
+    pub fn list_backup_plan_versions_pages(
+        &self,
+        input: ListBackupPlanVersionsInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListBackupPlanVersionsOutput,
+        ListBackupPlanVersionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: BackupClient, input: ListBackupPlanVersionsInput| async move {
+                client.list_backup_plan_versions(input).await
+            },
+            |input: &ListBackupPlanVersionsInput, output: &ListBackupPlanVersionsOutput| {
+                Some(ListBackupPlanVersionsInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_backup_plan_versions`, streaming the `BackupPlanVersionsList` of every page of results.
+    pub fn list_backup_plan_versions_items(
+        &self,
+        input: ListBackupPlanVersionsInput,
+    ) -> rusoto_core::pagination::RusotoStream<BackupPlansListMember, ListBackupPlanVersionsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_backup_plan_versions_pages(input),
+            |output: ListBackupPlanVersionsOutput| {
+                output.backup_plan_versions_list.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_backup_plans`, streaming every page of results.
+    pub fn list_backup_plans_pages(
+        &self,
+        input: ListBackupPlansInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListBackupPlansOutput, ListBackupPlansError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: BackupClient, input: ListBackupPlansInput| async move {
+                client.list_backup_plans(input).await
+            },
+            |input: &ListBackupPlansInput, output: &ListBackupPlansOutput| {
+                Some(ListBackupPlansInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_backup_plans`, streaming the `BackupPlansList` of every page of results.
+    pub fn list_backup_plans_items(
+        &self,
+        input: ListBackupPlansInput,
+    ) -> rusoto_core::pagination::RusotoStream<BackupPlansListMember, ListBackupPlansError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_backup_plans_pages(input),
+            |output: ListBackupPlansOutput| output.backup_plans_list.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_backup_selections`, streaming every page of results.
+    pub fn list_backup_selections_pages(
+        &self,
+        input: ListBackupSelectionsInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListBackupSelectionsOutput, ListBackupSelectionsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: BackupClient, input: ListBackupSelectionsInput| async move {
+                client.list_backup_selections(input).await
+            },
+            |input: &ListBackupSelectionsInput, output: &ListBackupSelectionsOutput| {
+                Some(ListBackupSelectionsInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_backup_selections`, streaming the `BackupSelectionsList` of every page of results.
+    pub fn list_backup_selections_items(
+        &self,
+        input: ListBackupSelectionsInput,
+    ) -> rusoto_core::pagination::RusotoStream<BackupSelectionsListMember, ListBackupSelectionsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_backup_selections_pages(input),
+            |output: ListBackupSelectionsOutput| output.backup_selections_list.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_backup_vaults`, streaming every page of results.
+    pub fn list_backup_vaults_pages(
+        &self,
+        input: ListBackupVaultsInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListBackupVaultsOutput, ListBackupVaultsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: BackupClient, input: ListBackupVaultsInput| async move {
+                client.list_backup_vaults(input).await
+            },
+            |input: &ListBackupVaultsInput, output: &ListBackupVaultsOutput| {
+                Some(ListBackupVaultsInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_backup_vaults`, streaming the `BackupVaultList` of every page of results.
+    pub fn list_backup_vaults_items(
+        &self,
+        input: ListBackupVaultsInput,
+    ) -> rusoto_core::pagination::RusotoStream<BackupVaultListMember, ListBackupVaultsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_backup_vaults_pages(input),
+            |output: ListBackupVaultsOutput| output.backup_vault_list.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_copy_jobs`, streaming every page of results.
+    pub fn list_copy_jobs_pages(
+        &self,
+        input: ListCopyJobsInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListCopyJobsOutput, ListCopyJobsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: BackupClient, input: ListCopyJobsInput| async move {
+                client.list_copy_jobs(input).await
+            },
+            |input: &ListCopyJobsInput, output: &ListCopyJobsOutput| {
+                Some(ListCopyJobsInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_copy_jobs`, streaming the `CopyJobs` of every page of results.
+    pub fn list_copy_jobs_items(
+        &self,
+        input: ListCopyJobsInput,
+    ) -> rusoto_core::pagination::RusotoStream<CopyJob, ListCopyJobsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_copy_jobs_pages(input),
+            |output: ListCopyJobsOutput| output.copy_jobs.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_protected_resources`, streaming every page of results.
+    pub fn list_protected_resources_pages(
+        &self,
+        input: ListProtectedResourcesInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListProtectedResourcesOutput,
+        ListProtectedResourcesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: BackupClient, input: ListProtectedResourcesInput| async move {
+                client.list_protected_resources(input).await
+            },
+            |input: &ListProtectedResourcesInput, output: &ListProtectedResourcesOutput| {
+                Some(ListProtectedResourcesInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_protected_resources`, streaming the `Results` of every page of results.
+    pub fn list_protected_resources_items(
+        &self,
+        input: ListProtectedResourcesInput,
+    ) -> rusoto_core::pagination::RusotoStream<ProtectedResource, ListProtectedResourcesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_protected_resources_pages(input),
+            |output: ListProtectedResourcesOutput| output.results.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_recovery_points_by_backup_vault`, streaming every page of results.
+    pub fn list_recovery_points_by_backup_vault_pages(
+        &self,
+        input: ListRecoveryPointsByBackupVaultInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListRecoveryPointsByBackupVaultOutput,
+        ListRecoveryPointsByBackupVaultError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: BackupClient, input: ListRecoveryPointsByBackupVaultInput| async move {
+                client.list_recovery_points_by_backup_vault(input).await
+            },
+            |input: &ListRecoveryPointsByBackupVaultInput,
+             output: &ListRecoveryPointsByBackupVaultOutput| {
+                Some(ListRecoveryPointsByBackupVaultInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_recovery_points_by_backup_vault`, streaming the `RecoveryPoints` of every page of results.
+    pub fn list_recovery_points_by_backup_vault_items(
+        &self,
+        input: ListRecoveryPointsByBackupVaultInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        RecoveryPointByBackupVault,
+        ListRecoveryPointsByBackupVaultError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_recovery_points_by_backup_vault_pages(input),
+            |output: ListRecoveryPointsByBackupVaultOutput| {
+                output.recovery_points.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_recovery_points_by_resource`, streaming every page of results.
+    pub fn list_recovery_points_by_resource_pages(
+        &self,
+        input: ListRecoveryPointsByResourceInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListRecoveryPointsByResourceOutput,
+        ListRecoveryPointsByResourceError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: BackupClient, input: ListRecoveryPointsByResourceInput| async move {
+                client.list_recovery_points_by_resource(input).await
+            },
+            |input: &ListRecoveryPointsByResourceInput,
+             output: &ListRecoveryPointsByResourceOutput| {
+                Some(ListRecoveryPointsByResourceInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_recovery_points_by_resource`, streaming the `RecoveryPoints` of every page of results.
+    pub fn list_recovery_points_by_resource_items(
+        &self,
+        input: ListRecoveryPointsByResourceInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        RecoveryPointByResource,
+        ListRecoveryPointsByResourceError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_recovery_points_by_resource_pages(input),
+            |output: ListRecoveryPointsByResourceOutput| output.recovery_points.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_restore_jobs`, streaming every page of results.
+    pub fn list_restore_jobs_pages(
+        &self,
+        input: ListRestoreJobsInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListRestoreJobsOutput, ListRestoreJobsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: BackupClient, input: ListRestoreJobsInput| async move {
+                client.list_restore_jobs(input).await
+            },
+            |input: &ListRestoreJobsInput, output: &ListRestoreJobsOutput| {
+                Some(ListRestoreJobsInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_restore_jobs`, streaming the `RestoreJobs` of every page of results.
+    pub fn list_restore_jobs_items(
+        &self,
+        input: ListRestoreJobsInput,
+    ) -> rusoto_core::pagination::RusotoStream<RestoreJobsListMember, ListRestoreJobsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_restore_jobs_pages(input),
+            |output: ListRestoreJobsOutput| output.restore_jobs.unwrap_or_default(),
+        )
+    }
+}
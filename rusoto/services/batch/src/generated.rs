@@ -24,6 +24,99 @@ use rusoto_core::signature::SignedRequest;
 #[allow(unused_imports)]
 use serde::{Deserialize, Serialize};
 use serde_json;
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ArrayJobDependency {
+    /// `N_TO_N`
+    NToN,
+    /// `SEQUENTIAL`
+    Sequential,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ArrayJobDependency {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ArrayJobDependency::NToN => "N_TO_N",
+            ArrayJobDependency::Sequential => "SEQUENTIAL",
+            ArrayJobDependency::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ArrayJobDependency {
+    fn default() -> ArrayJobDependency {
+        ArrayJobDependency::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ArrayJobDependency {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ArrayJobDependency {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ArrayJobDependency {
+    fn from(value: &'a str) -> ArrayJobDependency {
+        match value {
+            "N_TO_N" => ArrayJobDependency::NToN,
+            "SEQUENTIAL" => ArrayJobDependency::Sequential,
+            _ => ArrayJobDependency::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ArrayJobDependency {
+    fn from(value: String) -> ArrayJobDependency {
+        match value.as_str() {
+            "N_TO_N" => ArrayJobDependency::NToN,
+            "SEQUENTIAL" => ArrayJobDependency::Sequential,
+            _ => ArrayJobDependency::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ArrayJobDependency {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ArrayJobDependency, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ArrayJobDependency {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ArrayJobDependency {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ArrayJobDependency {
+    fn deserialize<D>(deserializer: D) -> Result<ArrayJobDependency, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An object representing an AWS Batch array job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -118,6 +211,511 @@ pub struct AttemptDetail {
     pub stopped_at: Option<i64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CEState {
+    /// `ENABLED`
+    Enabled,
+    /// `DISABLED`
+    Disabled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CEState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CEState::Enabled => "ENABLED",
+            CEState::Disabled => "DISABLED",
+            CEState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CEState {
+    fn default() -> CEState {
+        CEState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CEState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CEState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CEState {
+    fn from(value: &'a str) -> CEState {
+        match value {
+            "ENABLED" => CEState::Enabled,
+            "DISABLED" => CEState::Disabled,
+            _ => CEState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CEState {
+    fn from(value: String) -> CEState {
+        match value.as_str() {
+            "ENABLED" => CEState::Enabled,
+            "DISABLED" => CEState::Disabled,
+            _ => CEState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CEState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CEState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CEState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CEState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CEState {
+    fn deserialize<D>(deserializer: D) -> Result<CEState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CEStatus {
+    /// `CREATING`
+    Creating,
+    /// `UPDATING`
+    Updating,
+    /// `DELETING`
+    Deleting,
+    /// `DELETED`
+    Deleted,
+    /// `VALID`
+    Valid,
+    /// `INVALID`
+    Invalid,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CEStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CEStatus::Creating => "CREATING",
+            CEStatus::Updating => "UPDATING",
+            CEStatus::Deleting => "DELETING",
+            CEStatus::Deleted => "DELETED",
+            CEStatus::Valid => "VALID",
+            CEStatus::Invalid => "INVALID",
+            CEStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CEStatus {
+    fn default() -> CEStatus {
+        CEStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CEStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CEStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CEStatus {
+    fn from(value: &'a str) -> CEStatus {
+        match value {
+            "CREATING" => CEStatus::Creating,
+            "UPDATING" => CEStatus::Updating,
+            "DELETING" => CEStatus::Deleting,
+            "DELETED" => CEStatus::Deleted,
+            "VALID" => CEStatus::Valid,
+            "INVALID" => CEStatus::Invalid,
+            _ => CEStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CEStatus {
+    fn from(value: String) -> CEStatus {
+        match value.as_str() {
+            "CREATING" => CEStatus::Creating,
+            "UPDATING" => CEStatus::Updating,
+            "DELETING" => CEStatus::Deleting,
+            "DELETED" => CEStatus::Deleted,
+            "VALID" => CEStatus::Valid,
+            "INVALID" => CEStatus::Invalid,
+            _ => CEStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CEStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CEStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CEStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CEStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CEStatus {
+    fn deserialize<D>(deserializer: D) -> Result<CEStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CEType {
+    /// `MANAGED`
+    Managed,
+    /// `UNMANAGED`
+    Unmanaged,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CEType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CEType::Managed => "MANAGED",
+            CEType::Unmanaged => "UNMANAGED",
+            CEType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CEType {
+    fn default() -> CEType {
+        CEType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CEType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CEType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CEType {
+    fn from(value: &'a str) -> CEType {
+        match value {
+            "MANAGED" => CEType::Managed,
+            "UNMANAGED" => CEType::Unmanaged,
+            _ => CEType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CEType {
+    fn from(value: String) -> CEType {
+        match value.as_str() {
+            "MANAGED" => CEType::Managed,
+            "UNMANAGED" => CEType::Unmanaged,
+            _ => CEType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CEType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CEType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CEType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CEType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CEType {
+    fn deserialize<D>(deserializer: D) -> Result<CEType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CRAllocationStrategy {
+    /// `BEST_FIT`
+    BestFit,
+    /// `BEST_FIT_PROGRESSIVE`
+    BestFitProgressive,
+    /// `SPOT_CAPACITY_OPTIMIZED`
+    SpotCapacityOptimized,
+    /// `SPOT_PRICE_CAPACITY_OPTIMIZED`
+    SpotPriceCapacityOptimized,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CRAllocationStrategy {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CRAllocationStrategy::BestFit => "BEST_FIT",
+            CRAllocationStrategy::BestFitProgressive => "BEST_FIT_PROGRESSIVE",
+            CRAllocationStrategy::SpotCapacityOptimized => "SPOT_CAPACITY_OPTIMIZED",
+            CRAllocationStrategy::SpotPriceCapacityOptimized => "SPOT_PRICE_CAPACITY_OPTIMIZED",
+            CRAllocationStrategy::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CRAllocationStrategy {
+    fn default() -> CRAllocationStrategy {
+        CRAllocationStrategy::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CRAllocationStrategy {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CRAllocationStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CRAllocationStrategy {
+    fn from(value: &'a str) -> CRAllocationStrategy {
+        match value {
+            "BEST_FIT" => CRAllocationStrategy::BestFit,
+            "BEST_FIT_PROGRESSIVE" => CRAllocationStrategy::BestFitProgressive,
+            "SPOT_CAPACITY_OPTIMIZED" => CRAllocationStrategy::SpotCapacityOptimized,
+            "SPOT_PRICE_CAPACITY_OPTIMIZED" => CRAllocationStrategy::SpotPriceCapacityOptimized,
+            _ => CRAllocationStrategy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CRAllocationStrategy {
+    fn from(value: String) -> CRAllocationStrategy {
+        match value.as_str() {
+            "BEST_FIT" => CRAllocationStrategy::BestFit,
+            "BEST_FIT_PROGRESSIVE" => CRAllocationStrategy::BestFitProgressive,
+            "SPOT_CAPACITY_OPTIMIZED" => CRAllocationStrategy::SpotCapacityOptimized,
+            "SPOT_PRICE_CAPACITY_OPTIMIZED" => CRAllocationStrategy::SpotPriceCapacityOptimized,
+            _ => CRAllocationStrategy::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CRAllocationStrategy {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CRAllocationStrategy, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CRAllocationStrategy {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CRAllocationStrategy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CRAllocationStrategy {
+    fn deserialize<D>(deserializer: D) -> Result<CRAllocationStrategy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CRType {
+    /// `EC2`
+    Ec2,
+    /// `SPOT`
+    Spot,
+    /// `FARGATE`
+    Fargate,
+    /// `FARGATE_SPOT`
+    FargateSpot,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CRType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CRType::Ec2 => "EC2",
+            CRType::Spot => "SPOT",
+            CRType::Fargate => "FARGATE",
+            CRType::FargateSpot => "FARGATE_SPOT",
+            CRType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CRType {
+    fn default() -> CRType {
+        CRType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CRType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CRType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CRType {
+    fn from(value: &'a str) -> CRType {
+        match value {
+            "EC2" => CRType::Ec2,
+            "SPOT" => CRType::Spot,
+            "FARGATE" => CRType::Fargate,
+            "FARGATE_SPOT" => CRType::FargateSpot,
+            _ => CRType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CRType {
+    fn from(value: String) -> CRType {
+        match value.as_str() {
+            "EC2" => CRType::Ec2,
+            "SPOT" => CRType::Spot,
+            "FARGATE" => CRType::Fargate,
+            "FARGATE_SPOT" => CRType::FargateSpot,
+            _ => CRType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CRType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CRType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CRType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CRType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CRType {
+    fn deserialize<D>(deserializer: D) -> Result<CRType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CancelJobRequest {
@@ -157,11 +755,11 @@ pub struct ComputeEnvironmentDetail {
     /// <p>The state of the compute environment. The valid values are <code>ENABLED</code> or <code>DISABLED</code>.</p> <p>If the state is <code>ENABLED</code>, then the AWS Batch scheduler can attempt to place jobs from an associated job queue on the compute resources within the environment. If the compute environment is managed, then it can scale its instances out or in automatically, based on the job queue demand.</p> <p>If the state is <code>DISABLED</code>, then the AWS Batch scheduler does not attempt to place jobs within the environment. Jobs in a <code>STARTING</code> or <code>RUNNING</code> state continue to progress normally. Managed compute environments in the <code>DISABLED</code> state do not scale out. However, they scale in to <code>minvCpus</code> value after instances become idle.</p>
     #[serde(rename = "state")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<CEState>,
     /// <p>The current status of the compute environment (for example, <code>CREATING</code> or <code>VALID</code>).</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<CEStatus>,
     /// <p>A short, human-readable string to provide additional details about the current status of the compute environment.</p>
     #[serde(rename = "statusReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -169,7 +767,7 @@ pub struct ComputeEnvironmentDetail {
     /// <p>The type of the compute environment.</p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<CEType>,
 }
 
 /// <p>The order in which compute environments are tried for job placement within a queue. Compute environments are tried in ascending order. For example, if two compute environments are associated with a job queue, the compute environment with a lower order integer value is tried for job placement first.</p>
@@ -189,7 +787,7 @@ pub struct ComputeResource {
     /// <p>The allocation strategy to use for the compute resource in case not enough instances of the best fitting instance type can be allocated. This could be due to availability of the instance type in the region or <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ec2-resource-limits.html">Amazon EC2 service limits</a>. If this is not specified, the default is <code>BEST_FIT</code>, which will use only the best fitting instance type, waiting for additional capacity if it's not available. This allocation strategy keeps costs lower but can limit scaling. If you are using Spot Fleets with <code>BEST_FIT</code> then the Spot Fleet IAM Role must be specified. <code>BEST_FIT_PROGRESSIVE</code> will select additional instance types that are large enough to meet the requirements of the jobs in the queue, with a preference for instance types with a lower cost per vCPU. <code>SPOT_CAPACITY_OPTIMIZED</code> is only available for Spot Instance compute resources and will select additional instance types that are large enough to meet the requirements of the jobs in the queue, with a preference for instance types that are less likely to be interrupted. For more information, see <a href="https://docs.aws.amazon.com/batch/latest/userguide/allocation-strategies.html ">Allocation Strategies</a> in the <i>AWS Batch User Guide</i>.</p>
     #[serde(rename = "allocationStrategy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub allocation_strategy: Option<String>,
+    pub allocation_strategy: Option<CRAllocationStrategy>,
     /// <p>The maximum percentage that a Spot Instance price can be when compared with the On-Demand price for that instance type before instances are launched. For example, if your maximum percentage is 20%, then the Spot price must be below 20% of the current On-Demand price for that Amazon EC2 instance. You always pay the lowest (market) price and never more than your maximum percentage. If you leave this field empty, the default value is 100% of the On-Demand price.</p>
     #[serde(rename = "bidPercentage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -243,7 +841,7 @@ pub struct ComputeResource {
     pub tags: Option<::std::collections::HashMap<String, String>>,
     /// <p>The type of compute environment: <code>EC2</code> or <code>SPOT</code>.</p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: CRType,
 }
 
 /// <p>An object representing the attributes of a compute environment that can be updated.</p>
@@ -479,10 +1077,10 @@ pub struct CreateComputeEnvironmentRequest {
     /// <p>The state of the compute environment. If the state is <code>ENABLED</code>, then the compute environment accepts jobs from a queue and can scale out automatically based on queues.</p>
     #[serde(rename = "state")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<CEState>,
     /// <p>The type of the compute environment. For more information, see <a href="https://docs.aws.amazon.com/batch/latest/userguide/compute_environments.html">Compute Environments</a> in the <i>AWS Batch User Guide</i>.</p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: CEType,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -513,7 +1111,7 @@ pub struct CreateJobQueueRequest {
     /// <p>The state of the job queue. If the job queue state is <code>ENABLED</code>, it is able to accept jobs.</p>
     #[serde(rename = "state")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<JQState>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -618,91 +1216,395 @@ pub struct DescribeJobDefinitionsRequest {
     pub status: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct DescribeJobDefinitionsResponse {
-    /// <p>The list of job definitions.</p>
-    #[serde(rename = "jobDefinitions")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_definitions: Option<Vec<JobDefinition>>,
-    /// <p>The <code>nextToken</code> value to include in a future <code>DescribeJobDefinitions</code> request. When the results of a <code>DescribeJobDefinitions</code> request exceed <code>maxResults</code>, this value can be used to retrieve the next page of results. This value is <code>null</code> when there are no more results to return.</p>
-    #[serde(rename = "nextToken")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_token: Option<String>,
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct DescribeJobDefinitionsResponse {
+    /// <p>The list of job definitions.</p>
+    #[serde(rename = "jobDefinitions")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub job_definitions: Option<Vec<JobDefinition>>,
+    /// <p>The <code>nextToken</code> value to include in a future <code>DescribeJobDefinitions</code> request. When the results of a <code>DescribeJobDefinitions</code> request exceed <code>maxResults</code>, this value can be used to retrieve the next page of results. This value is <code>null</code> when there are no more results to return.</p>
+    #[serde(rename = "nextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct DescribeJobQueuesRequest {
+    /// <p>A list of up to 100 queue names or full queue Amazon Resource Name (ARN) entries.</p>
+    #[serde(rename = "jobQueues")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub job_queues: Option<Vec<String>>,
+    /// <p>The maximum number of results returned by <code>DescribeJobQueues</code> in paginated output. When this parameter is used, <code>DescribeJobQueues</code> only returns <code>maxResults</code> results in a single page along with a <code>nextToken</code> response element. The remaining results of the initial request can be seen by sending another <code>DescribeJobQueues</code> request with the returned <code>nextToken</code> value. This value can be between 1 and 100. If this parameter is not used, then <code>DescribeJobQueues</code> returns up to 100 results and a <code>nextToken</code> value if applicable.</p>
+    #[serde(rename = "maxResults")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_results: Option<i64>,
+    /// <p><p>The <code>nextToken</code> value returned from a previous paginated <code>DescribeJobQueues</code> request where <code>maxResults</code> was used and the results exceeded the value of that parameter. Pagination continues from the end of the previous results that returned the <code>nextToken</code> value. This value is <code>null</code> when there are no more results to return.</p> <note> <p>This token should be treated as an opaque identifier that is only used to retrieve the next items in a list and not for other programmatic purposes.</p> </note></p>
+    #[serde(rename = "nextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct DescribeJobQueuesResponse {
+    /// <p>The list of job queues.</p>
+    #[serde(rename = "jobQueues")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub job_queues: Option<Vec<JobQueueDetail>>,
+    /// <p>The <code>nextToken</code> value to include in a future <code>DescribeJobQueues</code> request. When the results of a <code>DescribeJobQueues</code> request exceed <code>maxResults</code>, this value can be used to retrieve the next page of results. This value is <code>null</code> when there are no more results to return.</p>
+    #[serde(rename = "nextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct DescribeJobsRequest {
+    /// <p>A list of up to 100 job IDs.</p>
+    #[serde(rename = "jobs")]
+    pub jobs: Vec<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct DescribeJobsResponse {
+    /// <p>The list of jobs.</p>
+    #[serde(rename = "jobs")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub jobs: Option<Vec<JobDetail>>,
+}
+
+/// <p>An object representing a container instance host device.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Device {
+    /// <p>The path inside the container at which to expose the host device. By default the <code>hostPath</code> value is used.</p>
+    #[serde(rename = "containerPath")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub container_path: Option<String>,
+    /// <p>The path for the device on the host container instance.</p>
+    #[serde(rename = "hostPath")]
+    pub host_path: String,
+    /// <p>The explicit permissions to provide to the container for the device. By default, the container has permissions for <code>read</code>, <code>write</code>, and <code>mknod</code> for the device.</p>
+    #[serde(rename = "permissions")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<Vec<DeviceCgroupPermission>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DeviceCgroupPermission {
+    /// `READ`
+    Read,
+    /// `WRITE`
+    Write,
+    /// `MKNOD`
+    Mknod,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DeviceCgroupPermission {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeviceCgroupPermission::Read => "READ",
+            DeviceCgroupPermission::Write => "WRITE",
+            DeviceCgroupPermission::Mknod => "MKNOD",
+            DeviceCgroupPermission::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DeviceCgroupPermission {
+    fn default() -> DeviceCgroupPermission {
+        DeviceCgroupPermission::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DeviceCgroupPermission {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DeviceCgroupPermission {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DeviceCgroupPermission {
+    fn from(value: &'a str) -> DeviceCgroupPermission {
+        match value {
+            "READ" => DeviceCgroupPermission::Read,
+            "WRITE" => DeviceCgroupPermission::Write,
+            "MKNOD" => DeviceCgroupPermission::Mknod,
+            _ => DeviceCgroupPermission::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeviceCgroupPermission {
+    fn from(value: String) -> DeviceCgroupPermission {
+        match value.as_str() {
+            "READ" => DeviceCgroupPermission::Read,
+            "WRITE" => DeviceCgroupPermission::Write,
+            "MKNOD" => DeviceCgroupPermission::Mknod,
+            _ => DeviceCgroupPermission::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DeviceCgroupPermission {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DeviceCgroupPermission, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DeviceCgroupPermission {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DeviceCgroupPermission {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeviceCgroupPermission {
+    fn deserialize<D>(deserializer: D) -> Result<DeviceCgroupPermission, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Determine whether your data volume persists on the host container instance and where it is stored. If this parameter is empty, then the Docker daemon assigns a host path for your data volume, but the data is not guaranteed to persist after the containers associated with it stop running.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Host {
+    /// <p>The path on the host container instance that is presented to the container. If this parameter is empty, then the Docker daemon has assigned a host path for you. If this parameter contains a file location, then the data volume persists at the specified location on the host container instance until you delete it manually. If the source path location does not exist on the host container instance, the Docker daemon creates it. If the location does exist, the contents of the source path folder are exported.</p>
+    #[serde(rename = "sourcePath")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_path: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum JQState {
+    /// `ENABLED`
+    Enabled,
+    /// `DISABLED`
+    Disabled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl JQState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            JQState::Enabled => "ENABLED",
+            JQState::Disabled => "DISABLED",
+            JQState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for JQState {
+    fn default() -> JQState {
+        JQState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for JQState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for JQState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for JQState {
+    fn from(value: &'a str) -> JQState {
+        match value {
+            "ENABLED" => JQState::Enabled,
+            "DISABLED" => JQState::Disabled,
+            _ => JQState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for JQState {
+    fn from(value: String) -> JQState {
+        match value.as_str() {
+            "ENABLED" => JQState::Enabled,
+            "DISABLED" => JQState::Disabled,
+            _ => JQState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for JQState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<JQState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for JQState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for JQState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for JQState {
+    fn deserialize<D>(deserializer: D) -> Result<JQState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum JQStatus {
+    /// `CREATING`
+    Creating,
+    /// `UPDATING`
+    Updating,
+    /// `DELETING`
+    Deleting,
+    /// `DELETED`
+    Deleted,
+    /// `VALID`
+    Valid,
+    /// `INVALID`
+    Invalid,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl JQStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            JQStatus::Creating => "CREATING",
+            JQStatus::Updating => "UPDATING",
+            JQStatus::Deleting => "DELETING",
+            JQStatus::Deleted => "DELETED",
+            JQStatus::Valid => "VALID",
+            JQStatus::Invalid => "INVALID",
+            JQStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for JQStatus {
+    fn default() -> JQStatus {
+        JQStatus::Unknown(String::new())
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct DescribeJobQueuesRequest {
-    /// <p>A list of up to 100 queue names or full queue Amazon Resource Name (ARN) entries.</p>
-    #[serde(rename = "jobQueues")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_queues: Option<Vec<String>>,
-    /// <p>The maximum number of results returned by <code>DescribeJobQueues</code> in paginated output. When this parameter is used, <code>DescribeJobQueues</code> only returns <code>maxResults</code> results in a single page along with a <code>nextToken</code> response element. The remaining results of the initial request can be seen by sending another <code>DescribeJobQueues</code> request with the returned <code>nextToken</code> value. This value can be between 1 and 100. If this parameter is not used, then <code>DescribeJobQueues</code> returns up to 100 results and a <code>nextToken</code> value if applicable.</p>
-    #[serde(rename = "maxResults")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub max_results: Option<i64>,
-    /// <p><p>The <code>nextToken</code> value returned from a previous paginated <code>DescribeJobQueues</code> request where <code>maxResults</code> was used and the results exceeded the value of that parameter. Pagination continues from the end of the previous results that returned the <code>nextToken</code> value. This value is <code>null</code> when there are no more results to return.</p> <note> <p>This token should be treated as an opaque identifier that is only used to retrieve the next items in a list and not for other programmatic purposes.</p> </note></p>
-    #[serde(rename = "nextToken")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_token: Option<String>,
+impl AsRef<str> for JQStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct DescribeJobQueuesResponse {
-    /// <p>The list of job queues.</p>
-    #[serde(rename = "jobQueues")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_queues: Option<Vec<JobQueueDetail>>,
-    /// <p>The <code>nextToken</code> value to include in a future <code>DescribeJobQueues</code> request. When the results of a <code>DescribeJobQueues</code> request exceed <code>maxResults</code>, this value can be used to retrieve the next page of results. This value is <code>null</code> when there are no more results to return.</p>
-    #[serde(rename = "nextToken")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_token: Option<String>,
+impl fmt::Display for JQStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct DescribeJobsRequest {
-    /// <p>A list of up to 100 job IDs.</p>
-    #[serde(rename = "jobs")]
-    pub jobs: Vec<String>,
+impl<'a> From<&'a str> for JQStatus {
+    fn from(value: &'a str) -> JQStatus {
+        match value {
+            "CREATING" => JQStatus::Creating,
+            "UPDATING" => JQStatus::Updating,
+            "DELETING" => JQStatus::Deleting,
+            "DELETED" => JQStatus::Deleted,
+            "VALID" => JQStatus::Valid,
+            "INVALID" => JQStatus::Invalid,
+            _ => JQStatus::Unknown(value.to_owned()),
+        }
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct DescribeJobsResponse {
-    /// <p>The list of jobs.</p>
-    #[serde(rename = "jobs")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub jobs: Option<Vec<JobDetail>>,
+impl From<String> for JQStatus {
+    fn from(value: String) -> JQStatus {
+        match value.as_str() {
+            "CREATING" => JQStatus::Creating,
+            "UPDATING" => JQStatus::Updating,
+            "DELETING" => JQStatus::Deleting,
+            "DELETED" => JQStatus::Deleted,
+            "VALID" => JQStatus::Valid,
+            "INVALID" => JQStatus::Invalid,
+            _ => JQStatus::Unknown(value),
+        }
+    }
 }
 
-/// <p>An object representing a container instance host device.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct Device {
-    /// <p>The path inside the container at which to expose the host device. By default the <code>hostPath</code> value is used.</p>
-    #[serde(rename = "containerPath")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub container_path: Option<String>,
-    /// <p>The path for the device on the host container instance.</p>
-    #[serde(rename = "hostPath")]
-    pub host_path: String,
-    /// <p>The explicit permissions to provide to the container for the device. By default, the container has permissions for <code>read</code>, <code>write</code>, and <code>mknod</code> for the device.</p>
-    #[serde(rename = "permissions")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub permissions: Option<Vec<String>>,
+impl ::std::str::FromStr for JQStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<JQStatus, Self::Err> {
+        Ok(value.into())
+    }
 }
 
-/// <p>Determine whether your data volume persists on the host container instance and where it is stored. If this parameter is empty, then the Docker daemon assigns a host path for your data volume, but the data is not guaranteed to persist after the containers associated with it stop running.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct Host {
-    /// <p>The path on the host container instance that is presented to the container. If this parameter is empty, then the Docker daemon has assigned a host path for you. If this parameter contains a file location, then the data volume persists at the specified location on the host container instance until you delete it manually. If the source path location does not exist on the host container instance, the Docker daemon creates it. If the location does exist, the contents of the source path folder are exported.</p>
-    #[serde(rename = "sourcePath")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub source_path: Option<String>,
+impl ::rusoto_core::param::ToParam for JQStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for JQStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for JQStatus {
+    fn deserialize<D>(deserializer: D) -> Result<JQStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An object representing an AWS Batch job definition.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -745,6 +1647,99 @@ pub struct JobDefinition {
     pub type_: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum JobDefinitionType {
+    /// `container`
+    Container,
+    /// `multinode`
+    Multinode,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl JobDefinitionType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            JobDefinitionType::Container => "container",
+            JobDefinitionType::Multinode => "multinode",
+            JobDefinitionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for JobDefinitionType {
+    fn default() -> JobDefinitionType {
+        JobDefinitionType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for JobDefinitionType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for JobDefinitionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for JobDefinitionType {
+    fn from(value: &'a str) -> JobDefinitionType {
+        match value {
+            "container" => JobDefinitionType::Container,
+            "multinode" => JobDefinitionType::Multinode,
+            _ => JobDefinitionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for JobDefinitionType {
+    fn from(value: String) -> JobDefinitionType {
+        match value.as_str() {
+            "container" => JobDefinitionType::Container,
+            "multinode" => JobDefinitionType::Multinode,
+            _ => JobDefinitionType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for JobDefinitionType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<JobDefinitionType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for JobDefinitionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for JobDefinitionType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for JobDefinitionType {
+    fn deserialize<D>(deserializer: D) -> Result<JobDefinitionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An object representing an AWS Batch job dependency.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct JobDependency {
@@ -755,7 +1750,7 @@ pub struct JobDependency {
     /// <p>The type of the job dependency.</p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<ArrayJobDependency>,
 }
 
 /// <p>An object representing an AWS Batch job.</p>
@@ -815,7 +1810,7 @@ pub struct JobDetail {
     pub started_at: i64,
     /// <p><p>The current status for the job.</p> <note> <p>If your jobs do not progress to <code>STARTING</code>, see <a href="https://docs.aws.amazon.com/batch/latest/userguide/troubleshooting.html#job_stuck_in_runnable">Jobs Stuck in RUNNABLE Status</a> in the troubleshooting section of the <i>AWS Batch User Guide</i>.</p> </note></p>
     #[serde(rename = "status")]
-    pub status: String,
+    pub status: JobStatus,
     /// <p>A short, human-readable string to provide additional details about the current status of the job.</p>
     #[serde(rename = "statusReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -848,17 +1843,135 @@ pub struct JobQueueDetail {
     pub priority: i64,
     /// <p>Describes the ability of the queue to accept new jobs.</p>
     #[serde(rename = "state")]
-    pub state: String,
+    pub state: JQState,
     /// <p>The status of the job queue (for example, <code>CREATING</code> or <code>VALID</code>).</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<JQStatus>,
     /// <p>A short, human-readable string to provide additional details about the current status of the job queue.</p>
     #[serde(rename = "statusReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status_reason: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum JobStatus {
+    /// `SUBMITTED`
+    Submitted,
+    /// `PENDING`
+    Pending,
+    /// `RUNNABLE`
+    Runnable,
+    /// `STARTING`
+    Starting,
+    /// `RUNNING`
+    Running,
+    /// `SUCCEEDED`
+    Succeeded,
+    /// `FAILED`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl JobStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            JobStatus::Submitted => "SUBMITTED",
+            JobStatus::Pending => "PENDING",
+            JobStatus::Runnable => "RUNNABLE",
+            JobStatus::Starting => "STARTING",
+            JobStatus::Running => "RUNNING",
+            JobStatus::Succeeded => "SUCCEEDED",
+            JobStatus::Failed => "FAILED",
+            JobStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for JobStatus {
+    fn default() -> JobStatus {
+        JobStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for JobStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for JobStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for JobStatus {
+    fn from(value: &'a str) -> JobStatus {
+        match value {
+            "SUBMITTED" => JobStatus::Submitted,
+            "PENDING" => JobStatus::Pending,
+            "RUNNABLE" => JobStatus::Runnable,
+            "STARTING" => JobStatus::Starting,
+            "RUNNING" => JobStatus::Running,
+            "SUCCEEDED" => JobStatus::Succeeded,
+            "FAILED" => JobStatus::Failed,
+            _ => JobStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for JobStatus {
+    fn from(value: String) -> JobStatus {
+        match value.as_str() {
+            "SUBMITTED" => JobStatus::Submitted,
+            "PENDING" => JobStatus::Pending,
+            "RUNNABLE" => JobStatus::Runnable,
+            "STARTING" => JobStatus::Starting,
+            "RUNNING" => JobStatus::Running,
+            "SUCCEEDED" => JobStatus::Succeeded,
+            "FAILED" => JobStatus::Failed,
+            _ => JobStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for JobStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<JobStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for JobStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for JobStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for JobStatus {
+    fn deserialize<D>(deserializer: D) -> Result<JobStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An object representing summary details of a job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -892,7 +2005,7 @@ pub struct JobSummary {
     /// <p>The current status for the job.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<JobStatus>,
     /// <p>A short, human-readable string to provide additional details about the current status of the job.</p>
     #[serde(rename = "statusReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -965,7 +2078,7 @@ pub struct ListJobsRequest {
     /// <p>The job status with which to filter jobs in the specified queue. If you do not specify a status, only <code>RUNNING</code> jobs are returned.</p>
     #[serde(rename = "jobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<JobStatus>,
     /// <p>The maximum number of results returned by <code>ListJobs</code> in paginated output. When this parameter is used, <code>ListJobs</code> only returns <code>maxResults</code> results in a single page along with a <code>nextToken</code> response element. The remaining results of the initial request can be seen by sending another <code>ListJobs</code> request with the returned <code>nextToken</code> value. This value can be between 1 and 100. If this parameter is not used, then <code>ListJobs</code> returns up to 100 results and a <code>nextToken</code> value if applicable.</p>
     #[serde(rename = "maxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1140,7 +2253,7 @@ pub struct RegisterJobDefinitionRequest {
     pub timeout: Option<JobTimeout>,
     /// <p>The type of job definition.</p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: JobDefinitionType,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1162,12 +2275,110 @@ pub struct RegisterJobDefinitionResponse {
 pub struct ResourceRequirement {
     /// <p>The type of resource to assign to a container. Currently, the only supported resource type is <code>GPU</code>.</p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: ResourceType,
     /// <p>The number of physical GPUs to reserve for the container. The number of GPUs reserved for all containers in a job should not exceed the number of available GPUs on the compute resource that the job is launched on.</p>
     #[serde(rename = "value")]
     pub value: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ResourceType {
+    /// `GPU`
+    Gpu,
+    /// `VCPU`
+    Vcpu,
+    /// `MEMORY`
+    Memory,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ResourceType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceType::Gpu => "GPU",
+            ResourceType::Vcpu => "VCPU",
+            ResourceType::Memory => "MEMORY",
+            ResourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ResourceType {
+    fn default() -> ResourceType {
+        ResourceType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ResourceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ResourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ResourceType {
+    fn from(value: &'a str) -> ResourceType {
+        match value {
+            "GPU" => ResourceType::Gpu,
+            "VCPU" => ResourceType::Vcpu,
+            "MEMORY" => ResourceType::Memory,
+            _ => ResourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceType {
+    fn from(value: String) -> ResourceType {
+        match value.as_str() {
+            "GPU" => ResourceType::Gpu,
+            "VCPU" => ResourceType::Vcpu,
+            "MEMORY" => ResourceType::Memory,
+            _ => ResourceType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ResourceType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ResourceType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ResourceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ResourceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ResourceType {
+    fn deserialize<D>(deserializer: D) -> Result<ResourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The retry strategy associated with a job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RetryStrategy {
@@ -1276,7 +2487,7 @@ pub struct UpdateComputeEnvironmentRequest {
     /// <p>The state of the compute environment. Compute environments in the <code>ENABLED</code> state can accept jobs from a queue and scale in or out automatically based on the workload demand of its associated queues.</p>
     #[serde(rename = "state")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<CEState>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1309,7 +2520,7 @@ pub struct UpdateJobQueueRequest {
     /// <p>Describes the queue's ability to accept new jobs.</p>
     #[serde(rename = "state")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<JQState>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -2013,11 +3224,12 @@ pub struct BatchClient {
 impl BatchClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> BatchClient {
         BatchClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Batch"),
         }
     }
 
@@ -2051,6 +3263,7 @@ impl Batch for BatchClient {
         let request_uri = "/v1/canceljob";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("CancelJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2081,6 +3294,7 @@ impl Batch for BatchClient {
         let request_uri = "/v1/createcomputeenvironment";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("CreateComputeEnvironment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2111,6 +3325,7 @@ impl Batch for BatchClient {
         let request_uri = "/v1/createjobqueue";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("CreateJobQueue");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2141,6 +3356,7 @@ impl Batch for BatchClient {
         let request_uri = "/v1/deletecomputeenvironment";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("DeleteComputeEnvironment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2171,6 +3387,7 @@ impl Batch for BatchClient {
         let request_uri = "/v1/deletejobqueue";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("DeleteJobQueue");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2201,6 +3418,7 @@ impl Batch for BatchClient {
         let request_uri = "/v1/deregisterjobdefinition";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("DeregisterJobDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2232,6 +3450,7 @@ impl Batch for BatchClient {
         let request_uri = "/v1/describecomputeenvironments";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("DescribeComputeEnvironments");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2262,6 +3481,7 @@ impl Batch for BatchClient {
         let request_uri = "/v1/describejobdefinitions";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("DescribeJobDefinitions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2292,6 +3512,7 @@ impl Batch for BatchClient {
         let request_uri = "/v1/describejobqueues";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("DescribeJobQueues");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2322,6 +3543,7 @@ impl Batch for BatchClient {
         let request_uri = "/v1/describejobs";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("DescribeJobs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2352,6 +3574,7 @@ impl Batch for BatchClient {
         let request_uri = "/v1/listjobs";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("ListJobs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2382,6 +3605,7 @@ impl Batch for BatchClient {
         let request_uri = "/v1/registerjobdefinition";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("RegisterJobDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2412,6 +3636,7 @@ impl Batch for BatchClient {
         let request_uri = "/v1/submitjob";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("SubmitJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2442,6 +3667,7 @@ impl Batch for BatchClient {
         let request_uri = "/v1/terminatejob";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("TerminateJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2472,6 +3698,7 @@ impl Batch for BatchClient {
         let request_uri = "/v1/updatecomputeenvironment";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("UpdateComputeEnvironment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2502,6 +3729,7 @@ impl Batch for BatchClient {
         let request_uri = "/v1/updatejobqueue";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("UpdateJobQueue");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2524,3 +3752,139 @@ impl Batch for BatchClient {
         }
     }
 }
+impl BatchClient {
+    /// Auto-paginating version of `describe_compute_environments`, streaming every page of results.
+    pub fn describe_compute_environments_pages(
+        &self,
+        input: DescribeComputeEnvironmentsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeComputeEnvironmentsResponse,
+        DescribeComputeEnvironmentsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: BatchClient, input: DescribeComputeEnvironmentsRequest| async move {
+                client.describe_compute_environments(input).await
+            },
+            |input: &DescribeComputeEnvironmentsRequest,
+             output: &DescribeComputeEnvironmentsResponse| {
+                Some(DescribeComputeEnvironmentsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_compute_environments`, streaming the `computeEnvironments` of every page of results.
+    pub fn describe_compute_environments_items(
+        &self,
+        input: DescribeComputeEnvironmentsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ComputeEnvironmentDetail,
+        DescribeComputeEnvironmentsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_compute_environments_pages(input),
+            |output: DescribeComputeEnvironmentsResponse| {
+                output.compute_environments.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_job_definitions`, streaming every page of results.
+    pub fn describe_job_definitions_pages(
+        &self,
+        input: DescribeJobDefinitionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeJobDefinitionsResponse,
+        DescribeJobDefinitionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: BatchClient, input: DescribeJobDefinitionsRequest| async move {
+                client.describe_job_definitions(input).await
+            },
+            |input: &DescribeJobDefinitionsRequest, output: &DescribeJobDefinitionsResponse| {
+                Some(DescribeJobDefinitionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_job_definitions`, streaming the `jobDefinitions` of every page of results.
+    pub fn describe_job_definitions_items(
+        &self,
+        input: DescribeJobDefinitionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<JobDefinition, DescribeJobDefinitionsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_job_definitions_pages(input),
+            |output: DescribeJobDefinitionsResponse| output.job_definitions.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_job_queues`, streaming every page of results.
+    pub fn describe_job_queues_pages(
+        &self,
+        input: DescribeJobQueuesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeJobQueuesResponse, DescribeJobQueuesError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: BatchClient, input: DescribeJobQueuesRequest| async move {
+                client.describe_job_queues(input).await
+            },
+            |input: &DescribeJobQueuesRequest, output: &DescribeJobQueuesResponse| {
+                Some(DescribeJobQueuesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_job_queues`, streaming the `jobQueues` of every page of results.
+    pub fn describe_job_queues_items(
+        &self,
+        input: DescribeJobQueuesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<JobQueueDetail, DescribeJobQueuesError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_job_queues_pages(input),
+            |output: DescribeJobQueuesResponse| output.job_queues.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_jobs`, streaming every page of results.
+    pub fn list_jobs_pages(
+        &self,
+        input: ListJobsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListJobsResponse, ListJobsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: BatchClient, input: ListJobsRequest| async move { client.list_jobs(input).await },
+            |input: &ListJobsRequest, output: &ListJobsResponse| {
+                Some(ListJobsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_jobs`, streaming the `jobSummaryList` of every page of results.
+    pub fn list_jobs_items(
+        &self,
+        input: ListJobsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<JobSummary, ListJobsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_jobs_pages(input),
+            |output: ListJobsResponse| output.job_summary_list,
+        )
+    }
+}
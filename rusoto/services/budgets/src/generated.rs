@@ -36,7 +36,7 @@ pub struct Budget {
     pub budget_name: String,
     /// <p>Whether this budget tracks costs, usage, RI utilization, RI coverage, Savings Plans utilization, or Savings Plans coverage.</p>
     #[serde(rename = "BudgetType")]
-    pub budget_type: String,
+    pub budget_type: BudgetType,
     /// <p>The actual and forecasted cost or usage that the budget tracks.</p>
     #[serde(rename = "CalculatedSpend")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -51,8 +51,13 @@ pub struct Budget {
     pub cost_types: Option<CostTypes>,
     /// <p>The last time that you updated this budget.</p>
     #[serde(rename = "LastUpdatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_time: Option<f64>,
+    pub last_updated_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A map containing multiple <code>BudgetLimit</code>, including current or future limits.</p> <p> <code>PlannedBudgetLimits</code> is available for cost or usage budget and supports monthly and quarterly <code>TimeUnit</code>. </p> <p>For monthly budgets, provide 12 months of <code>PlannedBudgetLimits</code> values. This must start from the current month and include the next 11 months. The <code>key</code> is the start of the month, <code>UTC</code> in epoch seconds. </p> <p>For quarterly budgets, provide 4 quarters of <code>PlannedBudgetLimits</code> value entries in standard calendar quarter increments. This must start from the current quarter and include the next 3 quarters. The <code>key</code> is the start of the quarter, <code>UTC</code> in epoch seconds. </p> <p>If the planned budget expires before 12 months for monthly or 4 quarters for quarterly, provide the <code>PlannedBudgetLimits</code> values only for the remaining periods.</p> <p>If the budget begins at a date in the future, provide <code>PlannedBudgetLimits</code> values from the start date of the budget. </p> <p>After all of the <code>BudgetLimit</code> values in <code>PlannedBudgetLimits</code> are used, the budget continues to use the last limit as the <code>BudgetLimit</code>. At that point, the planned budget provides the same experience as a fixed budget. </p> <p> <code>DescribeBudget</code> and <code>DescribeBudgets</code> response along with <code>PlannedBudgetLimits</code> will also contain <code>BudgetLimit</code> representing the current month or quarter limit present in <code>PlannedBudgetLimits</code>. This only applies to budgets created with <code>PlannedBudgetLimits</code>. Budgets created without <code>PlannedBudgetLimits</code> will only contain <code>BudgetLimit</code>, and no <code>PlannedBudgetLimits</code>.</p>
     #[serde(rename = "PlannedBudgetLimits")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -63,7 +68,7 @@ pub struct Budget {
     pub time_period: Option<TimePeriod>,
     /// <p>The length of time until a budget resets the actual and forecasted spend. <code>DAILY</code> is available only for <code>RI_UTILIZATION</code>, <code>RI_COVERAGE</code>, <code>Savings_Plans_Utilization</code>, and <code>Savings_Plans_Coverage</code> budgets.</p>
     #[serde(rename = "TimeUnit")]
-    pub time_unit: String,
+    pub time_unit: TimeUnit,
 }
 
 /// <p>A history of the state of a budget at the end of the budget's specified time period.</p>
@@ -75,7 +80,7 @@ pub struct BudgetPerformanceHistory {
     pub budget_name: Option<String>,
     #[serde(rename = "BudgetType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub budget_type: Option<String>,
+    pub budget_type: Option<BudgetType>,
     /// <p>A list of amounts of cost or usage that you created budgets for, compared to your actual costs or usage.</p>
     #[serde(rename = "BudgetedAndActualAmountsList")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -90,9 +95,123 @@ pub struct BudgetPerformanceHistory {
     pub cost_types: Option<CostTypes>,
     #[serde(rename = "TimeUnit")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub time_unit: Option<String>,
+    pub time_unit: Option<TimeUnit>,
+}
+
+/// <p> The type of a budget. It must be one of the following types: </p> <p> <code>COST</code>, <code>USAGE</code>, <code>RI_UTILIZATION</code>, <code>RI_COVERAGE</code>, <code>SAVINGS_PLANS_UTILIZATION</code>, or <code>SAVINGS_PLANS_COVERAGE</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BudgetType {
+    /// `USAGE`
+    Usage,
+    /// `COST`
+    Cost,
+    /// `RI_UTILIZATION`
+    RiUtilization,
+    /// `RI_COVERAGE`
+    RiCoverage,
+    /// `SAVINGS_PLANS_UTILIZATION`
+    SavingsPlansUtilization,
+    /// `SAVINGS_PLANS_COVERAGE`
+    SavingsPlansCoverage,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl BudgetType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BudgetType::Usage => "USAGE",
+            BudgetType::Cost => "COST",
+            BudgetType::RiUtilization => "RI_UTILIZATION",
+            BudgetType::RiCoverage => "RI_COVERAGE",
+            BudgetType::SavingsPlansUtilization => "SAVINGS_PLANS_UTILIZATION",
+            BudgetType::SavingsPlansCoverage => "SAVINGS_PLANS_COVERAGE",
+            BudgetType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for BudgetType {
+    fn default() -> BudgetType {
+        BudgetType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for BudgetType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for BudgetType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for BudgetType {
+    fn from(value: &'a str) -> BudgetType {
+        match value {
+            "USAGE" => BudgetType::Usage,
+            "COST" => BudgetType::Cost,
+            "RI_UTILIZATION" => BudgetType::RiUtilization,
+            "RI_COVERAGE" => BudgetType::RiCoverage,
+            "SAVINGS_PLANS_UTILIZATION" => BudgetType::SavingsPlansUtilization,
+            "SAVINGS_PLANS_COVERAGE" => BudgetType::SavingsPlansCoverage,
+            _ => BudgetType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BudgetType {
+    fn from(value: String) -> BudgetType {
+        match value.as_str() {
+            "USAGE" => BudgetType::Usage,
+            "COST" => BudgetType::Cost,
+            "RI_UTILIZATION" => BudgetType::RiUtilization,
+            "RI_COVERAGE" => BudgetType::RiCoverage,
+            "SAVINGS_PLANS_UTILIZATION" => BudgetType::SavingsPlansUtilization,
+            "SAVINGS_PLANS_COVERAGE" => BudgetType::SavingsPlansCoverage,
+            _ => BudgetType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for BudgetType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<BudgetType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for BudgetType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for BudgetType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for BudgetType {
+    fn deserialize<D>(deserializer: D) -> Result<BudgetType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The amount of cost or usage that you created the budget for, compared to your actual costs or usage.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -123,6 +242,105 @@ pub struct CalculatedSpend {
     pub forecasted_spend: Option<Spend>,
 }
 
+/// <p> The comparison operator of a notification. Currently, the service supports the following operators:</p> <p> <code>GREATER_THAN</code>, <code>LESS_THAN</code>, <code>EQUAL_TO</code> </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ComparisonOperator {
+    /// `GREATER_THAN`
+    GreaterThan,
+    /// `LESS_THAN`
+    LessThan,
+    /// `EQUAL_TO`
+    EqualTo,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ComparisonOperator {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ComparisonOperator::GreaterThan => "GREATER_THAN",
+            ComparisonOperator::LessThan => "LESS_THAN",
+            ComparisonOperator::EqualTo => "EQUAL_TO",
+            ComparisonOperator::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ComparisonOperator {
+    fn default() -> ComparisonOperator {
+        ComparisonOperator::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ComparisonOperator {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ComparisonOperator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ComparisonOperator {
+    fn from(value: &'a str) -> ComparisonOperator {
+        match value {
+            "GREATER_THAN" => ComparisonOperator::GreaterThan,
+            "LESS_THAN" => ComparisonOperator::LessThan,
+            "EQUAL_TO" => ComparisonOperator::EqualTo,
+            _ => ComparisonOperator::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ComparisonOperator {
+    fn from(value: String) -> ComparisonOperator {
+        match value.as_str() {
+            "GREATER_THAN" => ComparisonOperator::GreaterThan,
+            "LESS_THAN" => ComparisonOperator::LessThan,
+            "EQUAL_TO" => ComparisonOperator::EqualTo,
+            _ => ComparisonOperator::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ComparisonOperator {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ComparisonOperator, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ComparisonOperator {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ComparisonOperator {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ComparisonOperator {
+    fn deserialize<D>(deserializer: D) -> Result<ComparisonOperator, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The types of cost that are included in a <code>COST</code> budget, such as tax and subscriptions.</p> <p> <code>USAGE</code>, <code>RI_UTILIZATION</code>, and <code>RI_COVERAGE</code> budgets do not have <code>CostTypes</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CostTypes {
@@ -459,23 +677,210 @@ pub struct DescribeSubscribersForNotificationResponse {
 pub struct Notification {
     /// <p>The comparison that is used for this notification.</p>
     #[serde(rename = "ComparisonOperator")]
-    pub comparison_operator: String,
+    pub comparison_operator: ComparisonOperator,
     /// <p>Whether this notification is in alarm. If a budget notification is in the <code>ALARM</code> state, you have passed the set threshold for the budget.</p>
     #[serde(rename = "NotificationState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub notification_state: Option<String>,
+    pub notification_state: Option<NotificationState>,
     /// <p>Whether the notification is for how much you have spent (<code>ACTUAL</code>) or for how much you're forecasted to spend (<code>FORECASTED</code>).</p>
     #[serde(rename = "NotificationType")]
-    pub notification_type: String,
+    pub notification_type: NotificationType,
     /// <p>The threshold that is associated with a notification. Thresholds are always a percentage.</p>
     #[serde(rename = "Threshold")]
     pub threshold: f64,
     /// <p>The type of threshold for a notification. For <code>ABSOLUTE_VALUE</code> thresholds, AWS notifies you when you go over or are forecasted to go over your total cost threshold. For <code>PERCENTAGE</code> thresholds, AWS notifies you when you go over or are forecasted to go over a certain percentage of your forecasted spend. For example, if you have a budget for 200 dollars and you have a <code>PERCENTAGE</code> threshold of 80%, AWS notifies you when you go over 160 dollars.</p>
     #[serde(rename = "ThresholdType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub threshold_type: Option<String>,
+    pub threshold_type: Option<ThresholdType>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum NotificationState {
+    /// `OK`
+    Ok,
+    /// `ALARM`
+    Alarm,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl NotificationState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            NotificationState::Ok => "OK",
+            NotificationState::Alarm => "ALARM",
+            NotificationState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for NotificationState {
+    fn default() -> NotificationState {
+        NotificationState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for NotificationState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for NotificationState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for NotificationState {
+    fn from(value: &'a str) -> NotificationState {
+        match value {
+            "OK" => NotificationState::Ok,
+            "ALARM" => NotificationState::Alarm,
+            _ => NotificationState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for NotificationState {
+    fn from(value: String) -> NotificationState {
+        match value.as_str() {
+            "OK" => NotificationState::Ok,
+            "ALARM" => NotificationState::Alarm,
+            _ => NotificationState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for NotificationState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<NotificationState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for NotificationState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for NotificationState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for NotificationState {
+    fn deserialize<D>(deserializer: D) -> Result<NotificationState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p> The type of a notification. It must be ACTUAL or FORECASTED.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum NotificationType {
+    /// `ACTUAL`
+    Actual,
+    /// `FORECASTED`
+    Forecasted,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl NotificationType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            NotificationType::Actual => "ACTUAL",
+            NotificationType::Forecasted => "FORECASTED",
+            NotificationType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for NotificationType {
+    fn default() -> NotificationType {
+        NotificationType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for NotificationType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for NotificationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for NotificationType {
+    fn from(value: &'a str) -> NotificationType {
+        match value {
+            "ACTUAL" => NotificationType::Actual,
+            "FORECASTED" => NotificationType::Forecasted,
+            _ => NotificationType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for NotificationType {
+    fn from(value: String) -> NotificationType {
+        match value.as_str() {
+            "ACTUAL" => NotificationType::Actual,
+            "FORECASTED" => NotificationType::Forecasted,
+            _ => NotificationType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for NotificationType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<NotificationType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for NotificationType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for NotificationType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for NotificationType {
+    fn deserialize<D>(deserializer: D) -> Result<NotificationType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A notification with subscribers. A notification can have one SNS subscriber and up to 10 email subscribers, for a total of 11 subscribers.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -507,22 +912,324 @@ pub struct Subscriber {
     pub address: String,
     /// <p>The type of notification that AWS sends to a subscriber.</p>
     #[serde(rename = "SubscriptionType")]
-    pub subscription_type: String,
+    pub subscription_type: SubscriptionType,
+}
+
+/// <p> The subscription type of the subscriber. It can be SMS or EMAIL.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SubscriptionType {
+    /// `SNS`
+    Sns,
+    /// `EMAIL`
+    Email,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SubscriptionType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SubscriptionType::Sns => "SNS",
+            SubscriptionType::Email => "EMAIL",
+            SubscriptionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SubscriptionType {
+    fn default() -> SubscriptionType {
+        SubscriptionType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SubscriptionType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SubscriptionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SubscriptionType {
+    fn from(value: &'a str) -> SubscriptionType {
+        match value {
+            "SNS" => SubscriptionType::Sns,
+            "EMAIL" => SubscriptionType::Email,
+            _ => SubscriptionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SubscriptionType {
+    fn from(value: String) -> SubscriptionType {
+        match value.as_str() {
+            "SNS" => SubscriptionType::Sns,
+            "EMAIL" => SubscriptionType::Email,
+            _ => SubscriptionType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SubscriptionType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SubscriptionType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SubscriptionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SubscriptionType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SubscriptionType {
+    fn deserialize<D>(deserializer: D) -> Result<SubscriptionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p> The type of threshold for a notification.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ThresholdType {
+    /// `PERCENTAGE`
+    Percentage,
+    /// `ABSOLUTE_VALUE`
+    AbsoluteValue,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ThresholdType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ThresholdType::Percentage => "PERCENTAGE",
+            ThresholdType::AbsoluteValue => "ABSOLUTE_VALUE",
+            ThresholdType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ThresholdType {
+    fn default() -> ThresholdType {
+        ThresholdType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ThresholdType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ThresholdType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
+impl<'a> From<&'a str> for ThresholdType {
+    fn from(value: &'a str) -> ThresholdType {
+        match value {
+            "PERCENTAGE" => ThresholdType::Percentage,
+            "ABSOLUTE_VALUE" => ThresholdType::AbsoluteValue,
+            _ => ThresholdType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ThresholdType {
+    fn from(value: String) -> ThresholdType {
+        match value.as_str() {
+            "PERCENTAGE" => ThresholdType::Percentage,
+            "ABSOLUTE_VALUE" => ThresholdType::AbsoluteValue,
+            _ => ThresholdType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ThresholdType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ThresholdType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ThresholdType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ThresholdType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ThresholdType {
+    fn deserialize<D>(deserializer: D) -> Result<ThresholdType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The period of time that is covered by a budget. The period has a start date and an end date. The start date must come before the end date. There are no restrictions on the end date. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TimePeriod {
     /// <p>The end date for a budget. If you didn't specify an end date, AWS set your end date to <code>06/15/87 00:00 UTC</code>. The defaults are the same for the AWS Billing and Cost Management console and the API.</p> <p>After the end date, AWS deletes the budget and all associated notifications and subscribers. You can change your end date with the <code>UpdateBudget</code> operation.</p>
     #[serde(rename = "End")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end: Option<f64>,
+    pub end: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The start date for a budget. If you created your budget and didn't specify a start date, AWS defaults to the start of your chosen time period (DAILY, MONTHLY, QUARTERLY, or ANNUALLY). For example, if you created your budget on January 24, 2018, chose <code>DAILY</code>, and didn't set a start date, AWS set your start date to <code>01/24/18 00:00 UTC</code>. If you chose <code>MONTHLY</code>, AWS set your start date to <code>01/01/18 00:00 UTC</code>. The defaults are the same for the AWS Billing and Cost Management console and the API.</p> <p>You can change your start date with the <code>UpdateBudget</code> operation.</p>
     #[serde(rename = "Start")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start: Option<f64>,
+    pub start: Option<::chrono::DateTime<::chrono::Utc>>,
+}
+
+/// <p> The time unit of the budget, such as MONTHLY or QUARTERLY.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TimeUnit {
+    /// `DAILY`
+    Daily,
+    /// `MONTHLY`
+    Monthly,
+    /// `QUARTERLY`
+    Quarterly,
+    /// `ANNUALLY`
+    Annually,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TimeUnit {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TimeUnit::Daily => "DAILY",
+            TimeUnit::Monthly => "MONTHLY",
+            TimeUnit::Quarterly => "QUARTERLY",
+            TimeUnit::Annually => "ANNUALLY",
+            TimeUnit::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TimeUnit {
+    fn default() -> TimeUnit {
+        TimeUnit::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TimeUnit {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TimeUnit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TimeUnit {
+    fn from(value: &'a str) -> TimeUnit {
+        match value {
+            "DAILY" => TimeUnit::Daily,
+            "MONTHLY" => TimeUnit::Monthly,
+            "QUARTERLY" => TimeUnit::Quarterly,
+            "ANNUALLY" => TimeUnit::Annually,
+            _ => TimeUnit::Unknown(value.to_owned()),
+        }
+    }
 }
 
+impl From<String> for TimeUnit {
+    fn from(value: String) -> TimeUnit {
+        match value.as_str() {
+            "DAILY" => TimeUnit::Daily,
+            "MONTHLY" => TimeUnit::Monthly,
+            "QUARTERLY" => TimeUnit::Quarterly,
+            "ANNUALLY" => TimeUnit::Annually,
+            _ => TimeUnit::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TimeUnit {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TimeUnit, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TimeUnit {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TimeUnit {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TimeUnit {
+    fn deserialize<D>(deserializer: D) -> Result<TimeUnit, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p> Request of UpdateBudget </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -1529,11 +2236,12 @@ pub struct BudgetsClient {
 impl BudgetsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> BudgetsClient {
         BudgetsClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Budgets"),
         }
     }
 
@@ -1565,6 +2273,7 @@ impl Budgets for BudgetsClient {
         input: CreateBudgetRequest,
     ) -> Result<CreateBudgetResponse, RusotoError<CreateBudgetError>> {
         let mut request = SignedRequest::new("POST", "budgets", &self.region, "/");
+        request.set_operation("CreateBudget");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSBudgetServiceGateway.CreateBudget");
@@ -1592,6 +2301,7 @@ impl Budgets for BudgetsClient {
         input: CreateNotificationRequest,
     ) -> Result<CreateNotificationResponse, RusotoError<CreateNotificationError>> {
         let mut request = SignedRequest::new("POST", "budgets", &self.region, "/");
+        request.set_operation("CreateNotification");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSBudgetServiceGateway.CreateNotification");
@@ -1620,6 +2330,7 @@ impl Budgets for BudgetsClient {
         input: CreateSubscriberRequest,
     ) -> Result<CreateSubscriberResponse, RusotoError<CreateSubscriberError>> {
         let mut request = SignedRequest::new("POST", "budgets", &self.region, "/");
+        request.set_operation("CreateSubscriber");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSBudgetServiceGateway.CreateSubscriber");
@@ -1648,6 +2359,7 @@ impl Budgets for BudgetsClient {
         input: DeleteBudgetRequest,
     ) -> Result<DeleteBudgetResponse, RusotoError<DeleteBudgetError>> {
         let mut request = SignedRequest::new("POST", "budgets", &self.region, "/");
+        request.set_operation("DeleteBudget");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSBudgetServiceGateway.DeleteBudget");
@@ -1675,6 +2387,7 @@ impl Budgets for BudgetsClient {
         input: DeleteNotificationRequest,
     ) -> Result<DeleteNotificationResponse, RusotoError<DeleteNotificationError>> {
         let mut request = SignedRequest::new("POST", "budgets", &self.region, "/");
+        request.set_operation("DeleteNotification");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSBudgetServiceGateway.DeleteNotification");
@@ -1703,6 +2416,7 @@ impl Budgets for BudgetsClient {
         input: DeleteSubscriberRequest,
     ) -> Result<DeleteSubscriberResponse, RusotoError<DeleteSubscriberError>> {
         let mut request = SignedRequest::new("POST", "budgets", &self.region, "/");
+        request.set_operation("DeleteSubscriber");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSBudgetServiceGateway.DeleteSubscriber");
@@ -1731,6 +2445,7 @@ impl Budgets for BudgetsClient {
         input: DescribeBudgetRequest,
     ) -> Result<DescribeBudgetResponse, RusotoError<DescribeBudgetError>> {
         let mut request = SignedRequest::new("POST", "budgets", &self.region, "/");
+        request.set_operation("DescribeBudget");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSBudgetServiceGateway.DescribeBudget");
@@ -1761,6 +2476,7 @@ impl Budgets for BudgetsClient {
         RusotoError<DescribeBudgetPerformanceHistoryError>,
     > {
         let mut request = SignedRequest::new("POST", "budgets", &self.region, "/");
+        request.set_operation("DescribeBudgetPerformanceHistory");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1794,6 +2510,7 @@ impl Budgets for BudgetsClient {
         input: DescribeBudgetsRequest,
     ) -> Result<DescribeBudgetsResponse, RusotoError<DescribeBudgetsError>> {
         let mut request = SignedRequest::new("POST", "budgets", &self.region, "/");
+        request.set_operation("DescribeBudgets");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSBudgetServiceGateway.DescribeBudgets");
@@ -1824,6 +2541,7 @@ impl Budgets for BudgetsClient {
         RusotoError<DescribeNotificationsForBudgetError>,
     > {
         let mut request = SignedRequest::new("POST", "budgets", &self.region, "/");
+        request.set_operation("DescribeNotificationsForBudget");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1858,6 +2576,7 @@ impl Budgets for BudgetsClient {
         RusotoError<DescribeSubscribersForNotificationError>,
     > {
         let mut request = SignedRequest::new("POST", "budgets", &self.region, "/");
+        request.set_operation("DescribeSubscribersForNotification");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1891,6 +2610,7 @@ impl Budgets for BudgetsClient {
         input: UpdateBudgetRequest,
     ) -> Result<UpdateBudgetResponse, RusotoError<UpdateBudgetError>> {
         let mut request = SignedRequest::new("POST", "budgets", &self.region, "/");
+        request.set_operation("UpdateBudget");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSBudgetServiceGateway.UpdateBudget");
@@ -1918,6 +2638,7 @@ impl Budgets for BudgetsClient {
         input: UpdateNotificationRequest,
     ) -> Result<UpdateNotificationResponse, RusotoError<UpdateNotificationError>> {
         let mut request = SignedRequest::new("POST", "budgets", &self.region, "/");
+        request.set_operation("UpdateNotification");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSBudgetServiceGateway.UpdateNotification");
@@ -1946,6 +2667,7 @@ impl Budgets for BudgetsClient {
         input: UpdateSubscriberRequest,
     ) -> Result<UpdateSubscriberResponse, RusotoError<UpdateSubscriberError>> {
         let mut request = SignedRequest::new("POST", "budgets", &self.region, "/");
+        request.set_operation("UpdateSubscriber");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSBudgetServiceGateway.UpdateSubscriber");
@@ -1968,3 +2690,135 @@ impl Budgets for BudgetsClient {
         }
     }
 }
+impl BudgetsClient {
+    /// Auto-paginating version of `describe_budget_performance_history`, streaming every page of results.
+    pub fn describe_budget_performance_history_pages(
+        &self,
+        input: DescribeBudgetPerformanceHistoryRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeBudgetPerformanceHistoryResponse,
+        DescribeBudgetPerformanceHistoryError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: BudgetsClient, input: DescribeBudgetPerformanceHistoryRequest| async move {
+                client.describe_budget_performance_history(input).await
+            },
+            |input: &DescribeBudgetPerformanceHistoryRequest,
+             output: &DescribeBudgetPerformanceHistoryResponse| {
+                Some(DescribeBudgetPerformanceHistoryRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_budgets`, streaming every page of results.
+    pub fn describe_budgets_pages(
+        &self,
+        input: DescribeBudgetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeBudgetsResponse, DescribeBudgetsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: BudgetsClient, input: DescribeBudgetsRequest| async move {
+                client.describe_budgets(input).await
+            },
+            |input: &DescribeBudgetsRequest, output: &DescribeBudgetsResponse| {
+                Some(DescribeBudgetsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_budgets`, streaming the `Budgets` of every page of results.
+    pub fn describe_budgets_items(
+        &self,
+        input: DescribeBudgetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Budget, DescribeBudgetsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_budgets_pages(input),
+            |output: DescribeBudgetsResponse| output.budgets.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_notifications_for_budget`, streaming every page of results.
+    pub fn describe_notifications_for_budget_pages(
+        &self,
+        input: DescribeNotificationsForBudgetRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeNotificationsForBudgetResponse,
+        DescribeNotificationsForBudgetError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: BudgetsClient, input: DescribeNotificationsForBudgetRequest| async move {
+                client.describe_notifications_for_budget(input).await
+            },
+            |input: &DescribeNotificationsForBudgetRequest,
+             output: &DescribeNotificationsForBudgetResponse| {
+                Some(DescribeNotificationsForBudgetRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_notifications_for_budget`, streaming the `Notifications` of every page of results.
+    pub fn describe_notifications_for_budget_items(
+        &self,
+        input: DescribeNotificationsForBudgetRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Notification, DescribeNotificationsForBudgetError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_notifications_for_budget_pages(input),
+            |output: DescribeNotificationsForBudgetResponse| {
+                output.notifications.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_subscribers_for_notification`, streaming every page of results.
+    pub fn describe_subscribers_for_notification_pages(
+        &self,
+        input: DescribeSubscribersForNotificationRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeSubscribersForNotificationResponse,
+        DescribeSubscribersForNotificationError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: BudgetsClient, input: DescribeSubscribersForNotificationRequest| async move {
+                client.describe_subscribers_for_notification(input).await
+            },
+            |input: &DescribeSubscribersForNotificationRequest,
+             output: &DescribeSubscribersForNotificationResponse| {
+                Some(DescribeSubscribersForNotificationRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_subscribers_for_notification`, streaming the `Subscribers` of every page of results.
+    pub fn describe_subscribers_for_notification_items(
+        &self,
+        input: DescribeSubscribersForNotificationRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Subscriber, DescribeSubscribersForNotificationError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_subscribers_for_notification_pages(input),
+            |output: DescribeSubscribersForNotificationResponse| {
+                output.subscribers.unwrap_or_default()
+            },
+        )
+    }
+}
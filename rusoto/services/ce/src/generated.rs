@@ -24,6 +24,197 @@ use rusoto_core::signature::SignedRequest;
 #[allow(unused_imports)]
 use serde::{Deserialize, Serialize};
 use serde_json;
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AccountScope {
+    /// `PAYER`
+    Payer,
+    /// `LINKED`
+    Linked,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AccountScope {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AccountScope::Payer => "PAYER",
+            AccountScope::Linked => "LINKED",
+            AccountScope::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AccountScope {
+    fn default() -> AccountScope {
+        AccountScope::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AccountScope {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AccountScope {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AccountScope {
+    fn from(value: &'a str) -> AccountScope {
+        match value {
+            "PAYER" => AccountScope::Payer,
+            "LINKED" => AccountScope::Linked,
+            _ => AccountScope::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AccountScope {
+    fn from(value: String) -> AccountScope {
+        match value.as_str() {
+            "PAYER" => AccountScope::Payer,
+            "LINKED" => AccountScope::Linked,
+            _ => AccountScope::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AccountScope {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AccountScope, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AccountScope {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AccountScope {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AccountScope {
+    fn deserialize<D>(deserializer: D) -> Result<AccountScope, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Context {
+    /// `COST_AND_USAGE`
+    CostAndUsage,
+    /// `RESERVATIONS`
+    Reservations,
+    /// `SAVINGS_PLANS`
+    SavingsPlans,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Context {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Context::CostAndUsage => "COST_AND_USAGE",
+            Context::Reservations => "RESERVATIONS",
+            Context::SavingsPlans => "SAVINGS_PLANS",
+            Context::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Context {
+    fn default() -> Context {
+        Context::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Context {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Context {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Context {
+    fn from(value: &'a str) -> Context {
+        match value {
+            "COST_AND_USAGE" => Context::CostAndUsage,
+            "RESERVATIONS" => Context::Reservations,
+            "SAVINGS_PLANS" => Context::SavingsPlans,
+            _ => Context::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Context {
+    fn from(value: String) -> Context {
+        match value.as_str() {
+            "COST_AND_USAGE" => Context::CostAndUsage,
+            "RESERVATIONS" => Context::Reservations,
+            "SAVINGS_PLANS" => Context::SavingsPlans,
+            _ => Context::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Context {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Context, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Context {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Context {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Context {
+    fn deserialize<D>(deserializer: D) -> Result<Context, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p><important> <p> <i> <b>Cost Category is in public beta for AWS Billing and Cost Management and is subject to change. Your use of Cost Categories is subject to the Beta Service Participation terms of the <a href="https://aws.amazon.com/service-terms/">AWS Service Terms</a> (Section 1.10).</b> </i> </p> </important> <p>The structure of Cost Categories. This includes detailed metadata and the set of rules for the <code>CostCategory</code> object.</p></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -41,7 +232,7 @@ pub struct CostCategory {
     #[serde(rename = "Name")]
     pub name: String,
     #[serde(rename = "RuleVersion")]
-    pub rule_version: String,
+    pub rule_version: CostCategoryRuleVersion,
     /// <p> Rules are processed in order. If there are multiple rules that match the line item, then the first rule to match is used to determine that Cost Category value. </p>
     #[serde(rename = "Rules")]
     pub rules: Vec<CostCategoryRule>,
@@ -78,6 +269,95 @@ pub struct CostCategoryRule {
     pub value: String,
 }
 
+/// <p>The rule schema version in this particular Cost Category.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CostCategoryRuleVersion {
+    /// `CostCategoryExpression.v1`
+    CostCategoryExpressionV1,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CostCategoryRuleVersion {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CostCategoryRuleVersion::CostCategoryExpressionV1 => "CostCategoryExpression.v1",
+            CostCategoryRuleVersion::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CostCategoryRuleVersion {
+    fn default() -> CostCategoryRuleVersion {
+        CostCategoryRuleVersion::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CostCategoryRuleVersion {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CostCategoryRuleVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CostCategoryRuleVersion {
+    fn from(value: &'a str) -> CostCategoryRuleVersion {
+        match value {
+            "CostCategoryExpression.v1" => CostCategoryRuleVersion::CostCategoryExpressionV1,
+            _ => CostCategoryRuleVersion::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CostCategoryRuleVersion {
+    fn from(value: String) -> CostCategoryRuleVersion {
+        match value.as_str() {
+            "CostCategoryExpression.v1" => CostCategoryRuleVersion::CostCategoryExpressionV1,
+            _ => CostCategoryRuleVersion::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CostCategoryRuleVersion {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CostCategoryRuleVersion, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CostCategoryRuleVersion {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CostCategoryRuleVersion {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CostCategoryRuleVersion {
+    fn deserialize<D>(deserializer: D) -> Result<CostCategoryRuleVersion, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p><important> <p> <i> <b>Cost Category is in public beta for AWS Billing and Cost Management and is subject to change. Your use of Cost Categories is subject to the Beta Service Participation terms of the <a href="https://aws.amazon.com/service-terms/">AWS Service Terms</a> (Section 1.10).</b> </i> </p> </important> <p>The values that are available for Cost Categories.</p></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CostCategoryValues {
@@ -186,7 +466,7 @@ pub struct CreateCostCategoryDefinitionRequest {
     #[serde(rename = "Name")]
     pub name: String,
     #[serde(rename = "RuleVersion")]
-    pub rule_version: String,
+    pub rule_version: CostCategoryRuleVersion,
     /// <p> <code>CreateCostCategoryDefinition</code> supports dimensions, Tags, and nested expressions. Currently the only dimensions supported is <code>LINKED_ACCOUNT</code>.</p> <p>Root level <code>OR</code> is not supported. We recommend you create a separate rule instead.</p> <p>Rules are processed in order. If there are multiple rules that match the line item, then the first rule to match is used to determine that Cost Category value. </p>
     #[serde(rename = "Rules")]
     pub rules: Vec<CostCategoryRule>,
@@ -303,13 +583,266 @@ pub struct DescribeCostCategoryDefinitionResponse {
     pub cost_category: Option<CostCategory>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Dimension {
+    /// `AZ`
+    Az,
+    /// `INSTANCE_TYPE`
+    InstanceType,
+    /// `LINKED_ACCOUNT`
+    LinkedAccount,
+    /// `LINKED_ACCOUNT_NAME`
+    LinkedAccountName,
+    /// `OPERATION`
+    Operation,
+    /// `PURCHASE_TYPE`
+    PurchaseType,
+    /// `REGION`
+    Region,
+    /// `SERVICE`
+    Service,
+    /// `SERVICE_CODE`
+    ServiceCode,
+    /// `USAGE_TYPE`
+    UsageType,
+    /// `USAGE_TYPE_GROUP`
+    UsageTypeGroup,
+    /// `RECORD_TYPE`
+    RecordType,
+    /// `OPERATING_SYSTEM`
+    OperatingSystem,
+    /// `TENANCY`
+    Tenancy,
+    /// `SCOPE`
+    Scope,
+    /// `PLATFORM`
+    Platform,
+    /// `SUBSCRIPTION_ID`
+    SubscriptionId,
+    /// `LEGAL_ENTITY_NAME`
+    LegalEntityName,
+    /// `DEPLOYMENT_OPTION`
+    DeploymentOption,
+    /// `DATABASE_ENGINE`
+    DatabaseEngine,
+    /// `CACHE_ENGINE`
+    CacheEngine,
+    /// `INSTANCE_TYPE_FAMILY`
+    InstanceTypeFamily,
+    /// `BILLING_ENTITY`
+    BillingEntity,
+    /// `RESERVATION_ID`
+    ReservationId,
+    /// `RESOURCE_ID`
+    ResourceId,
+    /// `RIGHTSIZING_TYPE`
+    RightsizingType,
+    /// `SAVINGS_PLANS_TYPE`
+    SavingsPlansType,
+    /// `SAVINGS_PLAN_ARN`
+    SavingsPlanArn,
+    /// `PAYMENT_OPTION`
+    PaymentOption,
+    /// `AGREEMENT_END_DATE_TIME_AFTER`
+    AgreementEndDateTimeAfter,
+    /// `AGREEMENT_END_DATE_TIME_BEFORE`
+    AgreementEndDateTimeBefore,
+    /// `INVOICING_ENTITY`
+    InvoicingEntity,
+    /// `ANOMALY_TOTAL_IMPACT_ABSOLUTE`
+    AnomalyTotalImpactAbsolute,
+    /// `ANOMALY_TOTAL_IMPACT_PERCENTAGE`
+    AnomalyTotalImpactPercentage,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Dimension {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Dimension::Az => "AZ",
+            Dimension::InstanceType => "INSTANCE_TYPE",
+            Dimension::LinkedAccount => "LINKED_ACCOUNT",
+            Dimension::LinkedAccountName => "LINKED_ACCOUNT_NAME",
+            Dimension::Operation => "OPERATION",
+            Dimension::PurchaseType => "PURCHASE_TYPE",
+            Dimension::Region => "REGION",
+            Dimension::Service => "SERVICE",
+            Dimension::ServiceCode => "SERVICE_CODE",
+            Dimension::UsageType => "USAGE_TYPE",
+            Dimension::UsageTypeGroup => "USAGE_TYPE_GROUP",
+            Dimension::RecordType => "RECORD_TYPE",
+            Dimension::OperatingSystem => "OPERATING_SYSTEM",
+            Dimension::Tenancy => "TENANCY",
+            Dimension::Scope => "SCOPE",
+            Dimension::Platform => "PLATFORM",
+            Dimension::SubscriptionId => "SUBSCRIPTION_ID",
+            Dimension::LegalEntityName => "LEGAL_ENTITY_NAME",
+            Dimension::DeploymentOption => "DEPLOYMENT_OPTION",
+            Dimension::DatabaseEngine => "DATABASE_ENGINE",
+            Dimension::CacheEngine => "CACHE_ENGINE",
+            Dimension::InstanceTypeFamily => "INSTANCE_TYPE_FAMILY",
+            Dimension::BillingEntity => "BILLING_ENTITY",
+            Dimension::ReservationId => "RESERVATION_ID",
+            Dimension::ResourceId => "RESOURCE_ID",
+            Dimension::RightsizingType => "RIGHTSIZING_TYPE",
+            Dimension::SavingsPlansType => "SAVINGS_PLANS_TYPE",
+            Dimension::SavingsPlanArn => "SAVINGS_PLAN_ARN",
+            Dimension::PaymentOption => "PAYMENT_OPTION",
+            Dimension::AgreementEndDateTimeAfter => "AGREEMENT_END_DATE_TIME_AFTER",
+            Dimension::AgreementEndDateTimeBefore => "AGREEMENT_END_DATE_TIME_BEFORE",
+            Dimension::InvoicingEntity => "INVOICING_ENTITY",
+            Dimension::AnomalyTotalImpactAbsolute => "ANOMALY_TOTAL_IMPACT_ABSOLUTE",
+            Dimension::AnomalyTotalImpactPercentage => "ANOMALY_TOTAL_IMPACT_PERCENTAGE",
+            Dimension::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Dimension {
+    fn default() -> Dimension {
+        Dimension::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Dimension {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Dimension {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Dimension {
+    fn from(value: &'a str) -> Dimension {
+        match value {
+            "AZ" => Dimension::Az,
+            "INSTANCE_TYPE" => Dimension::InstanceType,
+            "LINKED_ACCOUNT" => Dimension::LinkedAccount,
+            "LINKED_ACCOUNT_NAME" => Dimension::LinkedAccountName,
+            "OPERATION" => Dimension::Operation,
+            "PURCHASE_TYPE" => Dimension::PurchaseType,
+            "REGION" => Dimension::Region,
+            "SERVICE" => Dimension::Service,
+            "SERVICE_CODE" => Dimension::ServiceCode,
+            "USAGE_TYPE" => Dimension::UsageType,
+            "USAGE_TYPE_GROUP" => Dimension::UsageTypeGroup,
+            "RECORD_TYPE" => Dimension::RecordType,
+            "OPERATING_SYSTEM" => Dimension::OperatingSystem,
+            "TENANCY" => Dimension::Tenancy,
+            "SCOPE" => Dimension::Scope,
+            "PLATFORM" => Dimension::Platform,
+            "SUBSCRIPTION_ID" => Dimension::SubscriptionId,
+            "LEGAL_ENTITY_NAME" => Dimension::LegalEntityName,
+            "DEPLOYMENT_OPTION" => Dimension::DeploymentOption,
+            "DATABASE_ENGINE" => Dimension::DatabaseEngine,
+            "CACHE_ENGINE" => Dimension::CacheEngine,
+            "INSTANCE_TYPE_FAMILY" => Dimension::InstanceTypeFamily,
+            "BILLING_ENTITY" => Dimension::BillingEntity,
+            "RESERVATION_ID" => Dimension::ReservationId,
+            "RESOURCE_ID" => Dimension::ResourceId,
+            "RIGHTSIZING_TYPE" => Dimension::RightsizingType,
+            "SAVINGS_PLANS_TYPE" => Dimension::SavingsPlansType,
+            "SAVINGS_PLAN_ARN" => Dimension::SavingsPlanArn,
+            "PAYMENT_OPTION" => Dimension::PaymentOption,
+            "AGREEMENT_END_DATE_TIME_AFTER" => Dimension::AgreementEndDateTimeAfter,
+            "AGREEMENT_END_DATE_TIME_BEFORE" => Dimension::AgreementEndDateTimeBefore,
+            "INVOICING_ENTITY" => Dimension::InvoicingEntity,
+            "ANOMALY_TOTAL_IMPACT_ABSOLUTE" => Dimension::AnomalyTotalImpactAbsolute,
+            "ANOMALY_TOTAL_IMPACT_PERCENTAGE" => Dimension::AnomalyTotalImpactPercentage,
+            _ => Dimension::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Dimension {
+    fn from(value: String) -> Dimension {
+        match value.as_str() {
+            "AZ" => Dimension::Az,
+            "INSTANCE_TYPE" => Dimension::InstanceType,
+            "LINKED_ACCOUNT" => Dimension::LinkedAccount,
+            "LINKED_ACCOUNT_NAME" => Dimension::LinkedAccountName,
+            "OPERATION" => Dimension::Operation,
+            "PURCHASE_TYPE" => Dimension::PurchaseType,
+            "REGION" => Dimension::Region,
+            "SERVICE" => Dimension::Service,
+            "SERVICE_CODE" => Dimension::ServiceCode,
+            "USAGE_TYPE" => Dimension::UsageType,
+            "USAGE_TYPE_GROUP" => Dimension::UsageTypeGroup,
+            "RECORD_TYPE" => Dimension::RecordType,
+            "OPERATING_SYSTEM" => Dimension::OperatingSystem,
+            "TENANCY" => Dimension::Tenancy,
+            "SCOPE" => Dimension::Scope,
+            "PLATFORM" => Dimension::Platform,
+            "SUBSCRIPTION_ID" => Dimension::SubscriptionId,
+            "LEGAL_ENTITY_NAME" => Dimension::LegalEntityName,
+            "DEPLOYMENT_OPTION" => Dimension::DeploymentOption,
+            "DATABASE_ENGINE" => Dimension::DatabaseEngine,
+            "CACHE_ENGINE" => Dimension::CacheEngine,
+            "INSTANCE_TYPE_FAMILY" => Dimension::InstanceTypeFamily,
+            "BILLING_ENTITY" => Dimension::BillingEntity,
+            "RESERVATION_ID" => Dimension::ReservationId,
+            "RESOURCE_ID" => Dimension::ResourceId,
+            "RIGHTSIZING_TYPE" => Dimension::RightsizingType,
+            "SAVINGS_PLANS_TYPE" => Dimension::SavingsPlansType,
+            "SAVINGS_PLAN_ARN" => Dimension::SavingsPlanArn,
+            "PAYMENT_OPTION" => Dimension::PaymentOption,
+            "AGREEMENT_END_DATE_TIME_AFTER" => Dimension::AgreementEndDateTimeAfter,
+            "AGREEMENT_END_DATE_TIME_BEFORE" => Dimension::AgreementEndDateTimeBefore,
+            "INVOICING_ENTITY" => Dimension::InvoicingEntity,
+            "ANOMALY_TOTAL_IMPACT_ABSOLUTE" => Dimension::AnomalyTotalImpactAbsolute,
+            "ANOMALY_TOTAL_IMPACT_PERCENTAGE" => Dimension::AnomalyTotalImpactPercentage,
+            _ => Dimension::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Dimension {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Dimension, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Dimension {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Dimension {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Dimension {
+    fn deserialize<D>(deserializer: D) -> Result<Dimension, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The metadata that you can use to filter and group your results. You can use <code>GetDimensionValues</code> to find specific values.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DimensionValues {
     /// <p>The names of the metadata types that you can use to filter and group your results. For example, <code>AZ</code> returns a list of Availability Zones.</p>
     #[serde(rename = "Key")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub key: Option<String>,
+    pub key: Option<Dimension>,
     /// <p>The metadata values that you can use to filter and group your results. You can use <code>GetDimensionValues</code> to find specific values.</p> <p>Valid values for the <code>SERVICE</code> dimension are <code>Amazon Elastic Compute Cloud - Compute</code>, <code>Amazon Elasticsearch Service</code>, <code>Amazon ElastiCache</code>, <code>Amazon Redshift</code>, and <code>Amazon Relational Database Service</code>.</p>
     #[serde(rename = "Values")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -434,7 +967,7 @@ pub struct EC2Specification {
     /// <p>Whether you want a recommendation for standard or convertible reservations.</p>
     #[serde(rename = "OfferingClass")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub offering_class: Option<String>,
+    pub offering_class: Option<OfferingClass>,
 }
 
 /// <p>Details about the Amazon ES instances that AWS recommends that you purchase.</p>
@@ -554,7 +1087,7 @@ pub struct GetCostAndUsageRequest {
     /// <p>Sets the AWS cost granularity to <code>MONTHLY</code> or <code>DAILY</code>, or <code>HOURLY</code>. If <code>Granularity</code> isn't set, the response object doesn't include the <code>Granularity</code>, either <code>MONTHLY</code> or <code>DAILY</code>, or <code>HOURLY</code>. </p>
     #[serde(rename = "Granularity")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub granularity: Option<String>,
+    pub granularity: Option<Granularity>,
     /// <p>You can group AWS costs using up to two different groups, either dimensions, tag keys, or both.</p> <p>When you group by tag key, you get all tag values, including empty strings.</p> <p>Valid values are <code>AZ</code>, <code>INSTANCE_TYPE</code>, <code>LEGAL_ENTITY_NAME</code>, <code>LINKED_ACCOUNT</code>, <code>OPERATION</code>, <code>PLATFORM</code>, <code>PURCHASE_TYPE</code>, <code>SERVICE</code>, <code>TAGS</code>, <code>TENANCY</code>, <code>RECORD_TYPE</code>, and <code>USAGE_TYPE</code>.</p>
     #[serde(rename = "GroupBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -599,7 +1132,7 @@ pub struct GetCostAndUsageWithResourcesRequest {
     /// <p>Sets the AWS cost granularity to <code>MONTHLY</code>, <code>DAILY</code>, or <code>HOURLY</code>. If <code>Granularity</code> isn't set, the response object doesn't include the <code>Granularity</code>, <code>MONTHLY</code>, <code>DAILY</code>, or <code>HOURLY</code>. </p>
     #[serde(rename = "Granularity")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub granularity: Option<String>,
+    pub granularity: Option<Granularity>,
     /// <p>You can group Amazon Web Services costs using up to two different groups: either dimensions, tag keys, or both.</p>
     #[serde(rename = "GroupBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -643,10 +1176,10 @@ pub struct GetCostForecastRequest {
     pub filter: Option<Expression>,
     /// <p>How granular you want the forecast to be. You can get 3 months of <code>DAILY</code> forecasts or 12 months of <code>MONTHLY</code> forecasts.</p> <p>The <code>GetCostForecast</code> operation supports only <code>DAILY</code> and <code>MONTHLY</code> granularities.</p>
     #[serde(rename = "Granularity")]
-    pub granularity: String,
+    pub granularity: Granularity,
     /// <p><p>Which metric Cost Explorer uses to create your forecast. For more information about blended and unblended rates, see <a href="https://aws.amazon.com/premiumsupport/knowledge-center/blended-rates-intro/">Why does the &quot;blended&quot; annotation appear on some line items in my bill?</a>. </p> <p>Valid values for a <code>GetCostForecast</code> call are the following:</p> <ul> <li> <p>AMORTIZED<em>COST</p> </li> <li> <p>BLENDED</em>COST</p> </li> <li> <p>NET<em>AMORTIZED</em>COST</p> </li> <li> <p>NET<em>UNBLENDED</em>COST</p> </li> <li> <p>UNBLENDED_COST</p> </li> </ul></p>
     #[serde(rename = "Metric")]
-    pub metric: String,
+    pub metric: Metric,
     /// <p>Cost Explorer always returns the mean forecast as a single point. You can request a prediction interval around the mean by specifying a confidence level. The higher the confidence level, the more confident Cost Explorer is about the actual value falling in the prediction interval. Higher confidence levels result in wider prediction intervals.</p>
     #[serde(rename = "PredictionIntervalLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -675,10 +1208,10 @@ pub struct GetDimensionValuesRequest {
     /// <p><p>The context for the call to <code>GetDimensionValues</code>. This can be <code>RESERVATIONS</code> or <code>COST<em>AND</em>USAGE</code>. The default value is <code>COST<em>AND</em>USAGE</code>. If the context is set to <code>RESERVATIONS</code>, the resulting dimension values can be used in the <code>GetReservationUtilization</code> operation. If the context is set to <code>COST<em>AND</em>USAGE</code>, the resulting dimension values can be used in the <code>GetCostAndUsage</code> operation.</p> <p>If you set the context to <code>COST<em>AND</em>USAGE</code>, you can use the following dimensions for searching:</p> <ul> <li> <p>AZ - The Availability Zone. An example is <code>us-east-1a</code>.</p> </li> <li> <p>DATABASE<em>ENGINE - The Amazon Relational Database Service database. Examples are Aurora or MySQL.</p> </li> <li> <p>INSTANCE</em>TYPE - The type of Amazon EC2 instance. An example is <code>m4.xlarge</code>.</p> </li> <li> <p>LEGAL<em>ENTITY</em>NAME - The name of the organization that sells you AWS services, such as Amazon Web Services.</p> </li> <li> <p>LINKED<em>ACCOUNT - The description in the attribute map that includes the full name of the member account. The value field contains the AWS ID of the member account.</p> </li> <li> <p>OPERATING</em>SYSTEM - The operating system. Examples are Windows or Linux.</p> </li> <li> <p>OPERATION - The action performed. Examples include <code>RunInstance</code> and <code>CreateBucket</code>.</p> </li> <li> <p>PLATFORM - The Amazon EC2 operating system. Examples are Windows or Linux.</p> </li> <li> <p>PURCHASE<em>TYPE - The reservation type of the purchase to which this usage is related. Examples include On-Demand Instances and Standard Reserved Instances.</p> </li> <li> <p>SERVICE - The AWS service such as Amazon DynamoDB.</p> </li> <li> <p>USAGE</em>TYPE - The type of usage. An example is DataTransfer-In-Bytes. The response for the <code>GetDimensionValues</code> operation includes a unit attribute. Examples include GB and Hrs.</p> </li> <li> <p>USAGE<em>TYPE</em>GROUP - The grouping of common usage types. An example is Amazon EC2: CloudWatch – Alarms. The response for this operation includes a unit attribute.</p> </li> <li> <p>RECORD<em>TYPE - The different types of charges such as RI fees, usage costs, tax refunds, and credits.</p> </li> <li> <p>RESOURCE</em>ID - The unique identifier of the resource. ResourceId is an opt-in feature only available for last 14 days for EC2-Compute Service.</p> </li> </ul> <p>If you set the context to <code>RESERVATIONS</code>, you can use the following dimensions for searching:</p> <ul> <li> <p>AZ - The Availability Zone. An example is <code>us-east-1a</code>.</p> </li> <li> <p>CACHE<em>ENGINE - The Amazon ElastiCache operating system. Examples are Windows or Linux.</p> </li> <li> <p>DEPLOYMENT</em>OPTION - The scope of Amazon Relational Database Service deployments. Valid values are <code>SingleAZ</code> and <code>MultiAZ</code>.</p> </li> <li> <p>INSTANCE<em>TYPE - The type of Amazon EC2 instance. An example is <code>m4.xlarge</code>.</p> </li> <li> <p>LINKED</em>ACCOUNT - The description in the attribute map that includes the full name of the member account. The value field contains the AWS ID of the member account.</p> </li> <li> <p>PLATFORM - The Amazon EC2 operating system. Examples are Windows or Linux.</p> </li> <li> <p>REGION - The AWS Region.</p> </li> <li> <p>SCOPE (Utilization only) - The scope of a Reserved Instance (RI). Values are regional or a single Availability Zone.</p> </li> <li> <p>TAG (Coverage only) - The tags that are associated with a Reserved Instance (RI).</p> </li> <li> <p>TENANCY - The tenancy of a resource. Examples are shared or dedicated.</p> </li> </ul> <p>If you set the context to <code>SAVINGS<em>PLANS</code>, you can use the following dimensions for searching:</p> <ul> <li> <p>SAVINGS</em>PLANS<em>TYPE - Type of Savings Plans (EC2 Instance or Compute)</p> </li> <li> <p>PAYMENT</em>OPTION - Payment option for the given Savings Plans (for example, All Upfront)</p> </li> <li> <p>REGION - The AWS Region.</p> </li> <li> <p>INSTANCE<em>TYPE</em>FAMILY - The family of instances (For example, <code>m5</code>)</p> </li> <li> <p>LINKED<em>ACCOUNT - The description in the attribute map that includes the full name of the member account. The value field contains the AWS ID of the member account.</p> </li> <li> <p>SAVINGS</em>PLAN_ARN - The unique identifier for your Savings Plan</p> </li> </ul></p>
     #[serde(rename = "Context")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub context: Option<String>,
+    pub context: Option<Context>,
     /// <p>The name of the dimension. Each <code>Dimension</code> is available for a different <code>Context</code>. For more information, see <code>Context</code>. </p>
     #[serde(rename = "Dimension")]
-    pub dimension: String,
+    pub dimension: Dimension,
     /// <p>The token to retrieve the next set of results. AWS provides the token when the response from a previous call has more results than the maximum page size.</p>
     #[serde(rename = "NextPageToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -721,7 +1254,7 @@ pub struct GetReservationCoverageRequest {
     /// <p>The granularity of the AWS cost data for the reservation. Valid values are <code>MONTHLY</code> and <code>DAILY</code>.</p> <p>If <code>GroupBy</code> is set, <code>Granularity</code> can't be set. If <code>Granularity</code> isn't set, the response object doesn't include <code>Granularity</code>, either <code>MONTHLY</code> or <code>DAILY</code>.</p> <p>The <code>GetReservationCoverage</code> operation supports only <code>DAILY</code> and <code>MONTHLY</code> granularities.</p>
     #[serde(rename = "Granularity")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub granularity: Option<String>,
+    pub granularity: Option<Granularity>,
     /// <p><p>You can group the data by the following attributes:</p> <ul> <li> <p>AZ</p> </li> <li> <p>CACHE<em>ENGINE</p> </li> <li> <p>DATABASE</em>ENGINE</p> </li> <li> <p>DEPLOYMENT<em>OPTION</p> </li> <li> <p>INSTANCE</em>TYPE</p> </li> <li> <p>LINKED<em>ACCOUNT</p> </li> <li> <p>OPERATING</em>SYSTEM</p> </li> <li> <p>PLATFORM</p> </li> <li> <p>REGION</p> </li> <li> <p>TENANCY</p> </li> </ul></p>
     #[serde(rename = "GroupBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -765,11 +1298,11 @@ pub struct GetReservationPurchaseRecommendationRequest {
     /// <p>The account scope that you want recommendations for. <code>PAYER</code> means that AWS includes the master account and any member accounts when it calculates its recommendations. <code>LINKED</code> means that AWS includes only member accounts when it calculates its recommendations.</p> <p>Valid values are <code>PAYER</code> and <code>LINKED</code>.</p>
     #[serde(rename = "AccountScope")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub account_scope: Option<String>,
+    pub account_scope: Option<AccountScope>,
     /// <p>The number of previous days that you want AWS to consider when it calculates your recommendations.</p>
     #[serde(rename = "LookbackPeriodInDays")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub lookback_period_in_days: Option<String>,
+    pub lookback_period_in_days: Option<LookbackPeriodInDays>,
     /// <p>The pagination token that indicates the next set of results that you want to retrieve.</p>
     #[serde(rename = "NextPageToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -781,7 +1314,7 @@ pub struct GetReservationPurchaseRecommendationRequest {
     /// <p>The reservation purchase option that you want recommendations for.</p>
     #[serde(rename = "PaymentOption")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub payment_option: Option<String>,
+    pub payment_option: Option<PaymentOption>,
     /// <p>The specific service that you want recommendations for.</p>
     #[serde(rename = "Service")]
     pub service: String,
@@ -792,7 +1325,7 @@ pub struct GetReservationPurchaseRecommendationRequest {
     /// <p>The reservation term that you want recommendations for.</p>
     #[serde(rename = "TermInYears")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub term_in_years: Option<String>,
+    pub term_in_years: Option<TermInYears>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -822,7 +1355,7 @@ pub struct GetReservationUtilizationRequest {
     /// <p>If <code>GroupBy</code> is set, <code>Granularity</code> can't be set. If <code>Granularity</code> isn't set, the response object doesn't include <code>Granularity</code>, either <code>MONTHLY</code> or <code>DAILY</code>. If both <code>GroupBy</code> and <code>Granularity</code> aren't set, <code>GetReservationUtilization</code> defaults to <code>DAILY</code>.</p> <p>The <code>GetReservationUtilization</code> operation supports only <code>DAILY</code> and <code>MONTHLY</code> granularities.</p>
     #[serde(rename = "Granularity")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub granularity: Option<String>,
+    pub granularity: Option<Granularity>,
     /// <p>Groups only by <code>SUBSCRIPTION_ID</code>. Metadata is included.</p>
     #[serde(rename = "GroupBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -902,7 +1435,7 @@ pub struct GetSavingsPlansCoverageRequest {
     /// <p>The granularity of the Amazon Web Services cost data for your Savings Plans. <code>Granularity</code> can't be set if <code>GroupBy</code> is set.</p> <p>The <code>GetSavingsPlansCoverage</code> operation supports only <code>DAILY</code> and <code>MONTHLY</code> granularities.</p>
     #[serde(rename = "Granularity")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub granularity: Option<String>,
+    pub granularity: Option<Granularity>,
     /// <p>You can group the data using the attributes <code>INSTANCE_FAMILY</code>, <code>REGION</code>, or <code>SERVICE</code>.</p>
     #[serde(rename = "GroupBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -941,7 +1474,7 @@ pub struct GetSavingsPlansCoverageResponse {
 pub struct GetSavingsPlansPurchaseRecommendationRequest {
     /// <p>The lookback period used to generate the recommendation.</p>
     #[serde(rename = "LookbackPeriodInDays")]
-    pub lookback_period_in_days: String,
+    pub lookback_period_in_days: LookbackPeriodInDays,
     /// <p>The token to retrieve the next set of results. Amazon Web Services provides the token when the response from a previous call has more results than the maximum page size.</p>
     #[serde(rename = "NextPageToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -952,13 +1485,13 @@ pub struct GetSavingsPlansPurchaseRecommendationRequest {
     pub page_size: Option<i64>,
     /// <p>The payment option used to generate these recommendations.</p>
     #[serde(rename = "PaymentOption")]
-    pub payment_option: String,
+    pub payment_option: PaymentOption,
     /// <p>The Savings Plans recommendation type requested.</p>
     #[serde(rename = "SavingsPlansType")]
-    pub savings_plans_type: String,
+    pub savings_plans_type: SupportedSavingsPlansType,
     /// <p>The savings plan recommendation term used to generated these recommendations.</p>
     #[serde(rename = "TermInYears")]
-    pub term_in_years: String,
+    pub term_in_years: TermInYears,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1026,7 +1559,7 @@ pub struct GetSavingsPlansUtilizationRequest {
     /// <p>The granularity of the Amazon Web Services utillization data for your Savings Plans.</p> <p>The <code>GetSavingsPlansUtilization</code> operation supports only <code>DAILY</code> and <code>MONTHLY</code> granularities.</p>
     #[serde(rename = "Granularity")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub granularity: Option<String>,
+    pub granularity: Option<Granularity>,
     /// <p>The time period that you want the usage and costs for. The <code>Start</code> date must be within 13 months. The <code>End</code> date must be after the <code>Start</code> date, and before the current date. Future dates can't be used as an <code>End</code> date.</p>
     #[serde(rename = "TimePeriod")]
     pub time_period: DateInterval,
@@ -1091,10 +1624,10 @@ pub struct GetUsageForecastRequest {
     pub filter: Option<Expression>,
     /// <p>How granular you want the forecast to be. You can get 3 months of <code>DAILY</code> forecasts or 12 months of <code>MONTHLY</code> forecasts.</p> <p>The <code>GetUsageForecast</code> operation supports only <code>DAILY</code> and <code>MONTHLY</code> granularities.</p>
     #[serde(rename = "Granularity")]
-    pub granularity: String,
+    pub granularity: Granularity,
     /// <p><p>Which metric Cost Explorer uses to create your forecast.</p> <p>Valid values for a <code>GetUsageForecast</code> call are the following:</p> <ul> <li> <p>USAGE<em>QUANTITY</p> </li> <li> <p>NORMALIZED</em>USAGE_AMOUNT</p> </li> </ul></p>
     #[serde(rename = "Metric")]
-    pub metric: String,
+    pub metric: Metric,
     /// <p>Cost Explorer always returns the mean forecast as a single point. You can request a prediction interval around the mean by specifying a confidence level. The higher the confidence level, the more confident Cost Explorer is about the actual value falling in the prediction interval. Higher confidence levels result in wider prediction intervals.</p>
     #[serde(rename = "PredictionIntervalLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1117,6 +1650,104 @@ pub struct GetUsageForecastResponse {
     pub total: Option<MetricValue>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Granularity {
+    /// `DAILY`
+    Daily,
+    /// `MONTHLY`
+    Monthly,
+    /// `HOURLY`
+    Hourly,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Granularity {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Granularity::Daily => "DAILY",
+            Granularity::Monthly => "MONTHLY",
+            Granularity::Hourly => "HOURLY",
+            Granularity::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Granularity {
+    fn default() -> Granularity {
+        Granularity::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Granularity {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Granularity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Granularity {
+    fn from(value: &'a str) -> Granularity {
+        match value {
+            "DAILY" => Granularity::Daily,
+            "MONTHLY" => Granularity::Monthly,
+            "HOURLY" => Granularity::Hourly,
+            _ => Granularity::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Granularity {
+    fn from(value: String) -> Granularity {
+        match value.as_str() {
+            "DAILY" => Granularity::Daily,
+            "MONTHLY" => Granularity::Monthly,
+            "HOURLY" => Granularity::Hourly,
+            _ => Granularity::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Granularity {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Granularity, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Granularity {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Granularity {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Granularity {
+    fn deserialize<D>(deserializer: D) -> Result<Granularity, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>One level of grouped data in the results.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1141,9 +1772,107 @@ pub struct GroupDefinition {
     /// <p>The string that represents the type of group.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<GroupDefinitionType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum GroupDefinitionType {
+    /// `DIMENSION`
+    Dimension,
+    /// `TAG`
+    Tag,
+    /// `COST_CATEGORY`
+    CostCategory,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl GroupDefinitionType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            GroupDefinitionType::Dimension => "DIMENSION",
+            GroupDefinitionType::Tag => "TAG",
+            GroupDefinitionType::CostCategory => "COST_CATEGORY",
+            GroupDefinitionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for GroupDefinitionType {
+    fn default() -> GroupDefinitionType {
+        GroupDefinitionType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for GroupDefinitionType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for GroupDefinitionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for GroupDefinitionType {
+    fn from(value: &'a str) -> GroupDefinitionType {
+        match value {
+            "DIMENSION" => GroupDefinitionType::Dimension,
+            "TAG" => GroupDefinitionType::Tag,
+            "COST_CATEGORY" => GroupDefinitionType::CostCategory,
+            _ => GroupDefinitionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for GroupDefinitionType {
+    fn from(value: String) -> GroupDefinitionType {
+        match value.as_str() {
+            "DIMENSION" => GroupDefinitionType::Dimension,
+            "TAG" => GroupDefinitionType::Tag,
+            "COST_CATEGORY" => GroupDefinitionType::CostCategory,
+            _ => GroupDefinitionType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for GroupDefinitionType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<GroupDefinitionType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for GroupDefinitionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for GroupDefinitionType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for GroupDefinitionType {
+    fn deserialize<D>(deserializer: D) -> Result<GroupDefinitionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Details about the instances that AWS recommends that you purchase.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1170,32 +1899,248 @@ pub struct InstanceDetails {
     pub redshift_instance_details: Option<RedshiftInstanceDetails>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct ListCostCategoryDefinitionsRequest {
-    /// <p> The date when the Cost Category was effective. </p>
-    #[serde(rename = "EffectiveOn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub effective_on: Option<String>,
-    /// <p> The token to retrieve the next set of results. Amazon Web Services provides the token when the response from a previous call has more results than the maximum page size. </p> <p>You can use this information to retrieve the full Cost Category information using <code>DescribeCostCategory</code>.</p>
-    #[serde(rename = "NextToken")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_token: Option<String>,
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct ListCostCategoryDefinitionsRequest {
+    /// <p> The date when the Cost Category was effective. </p>
+    #[serde(rename = "EffectiveOn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub effective_on: Option<String>,
+    /// <p> The token to retrieve the next set of results. Amazon Web Services provides the token when the response from a previous call has more results than the maximum page size. </p> <p>You can use this information to retrieve the full Cost Category information using <code>DescribeCostCategory</code>.</p>
+    #[serde(rename = "NextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct ListCostCategoryDefinitionsResponse {
+    /// <p> A reference to a Cost Category containing enough information to identify the Cost Category. </p>
+    #[serde(rename = "CostCategoryReferences")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cost_category_references: Option<Vec<CostCategoryReference>>,
+    /// <p> The token to retrieve the next set of results. Amazon Web Services provides the token when the response from a previous call has more results than the maximum page size. </p>
+    #[serde(rename = "NextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LookbackPeriodInDays {
+    /// `SEVEN_DAYS`
+    SevenDays,
+    /// `THIRTY_DAYS`
+    ThirtyDays,
+    /// `SIXTY_DAYS`
+    SixtyDays,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LookbackPeriodInDays {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LookbackPeriodInDays::SevenDays => "SEVEN_DAYS",
+            LookbackPeriodInDays::ThirtyDays => "THIRTY_DAYS",
+            LookbackPeriodInDays::SixtyDays => "SIXTY_DAYS",
+            LookbackPeriodInDays::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LookbackPeriodInDays {
+    fn default() -> LookbackPeriodInDays {
+        LookbackPeriodInDays::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LookbackPeriodInDays {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LookbackPeriodInDays {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LookbackPeriodInDays {
+    fn from(value: &'a str) -> LookbackPeriodInDays {
+        match value {
+            "SEVEN_DAYS" => LookbackPeriodInDays::SevenDays,
+            "THIRTY_DAYS" => LookbackPeriodInDays::ThirtyDays,
+            "SIXTY_DAYS" => LookbackPeriodInDays::SixtyDays,
+            _ => LookbackPeriodInDays::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LookbackPeriodInDays {
+    fn from(value: String) -> LookbackPeriodInDays {
+        match value.as_str() {
+            "SEVEN_DAYS" => LookbackPeriodInDays::SevenDays,
+            "THIRTY_DAYS" => LookbackPeriodInDays::ThirtyDays,
+            "SIXTY_DAYS" => LookbackPeriodInDays::SixtyDays,
+            _ => LookbackPeriodInDays::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LookbackPeriodInDays {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LookbackPeriodInDays, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LookbackPeriodInDays {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LookbackPeriodInDays {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LookbackPeriodInDays {
+    fn deserialize<D>(deserializer: D) -> Result<LookbackPeriodInDays, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Metric {
+    /// `BLENDED_COST`
+    BlendedCost,
+    /// `UNBLENDED_COST`
+    UnblendedCost,
+    /// `AMORTIZED_COST`
+    AmortizedCost,
+    /// `NET_UNBLENDED_COST`
+    NetUnblendedCost,
+    /// `NET_AMORTIZED_COST`
+    NetAmortizedCost,
+    /// `USAGE_QUANTITY`
+    UsageQuantity,
+    /// `NORMALIZED_USAGE_AMOUNT`
+    NormalizedUsageAmount,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Metric {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Metric::BlendedCost => "BLENDED_COST",
+            Metric::UnblendedCost => "UNBLENDED_COST",
+            Metric::AmortizedCost => "AMORTIZED_COST",
+            Metric::NetUnblendedCost => "NET_UNBLENDED_COST",
+            Metric::NetAmortizedCost => "NET_AMORTIZED_COST",
+            Metric::UsageQuantity => "USAGE_QUANTITY",
+            Metric::NormalizedUsageAmount => "NORMALIZED_USAGE_AMOUNT",
+            Metric::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Metric {
+    fn default() -> Metric {
+        Metric::Unknown(String::new())
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct ListCostCategoryDefinitionsResponse {
-    /// <p> A reference to a Cost Category containing enough information to identify the Cost Category. </p>
-    #[serde(rename = "CostCategoryReferences")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub cost_category_references: Option<Vec<CostCategoryReference>>,
-    /// <p> The token to retrieve the next set of results. Amazon Web Services provides the token when the response from a previous call has more results than the maximum page size. </p>
-    #[serde(rename = "NextToken")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_token: Option<String>,
+impl AsRef<str> for Metric {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Metric {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Metric {
+    fn from(value: &'a str) -> Metric {
+        match value {
+            "BLENDED_COST" => Metric::BlendedCost,
+            "UNBLENDED_COST" => Metric::UnblendedCost,
+            "AMORTIZED_COST" => Metric::AmortizedCost,
+            "NET_UNBLENDED_COST" => Metric::NetUnblendedCost,
+            "NET_AMORTIZED_COST" => Metric::NetAmortizedCost,
+            "USAGE_QUANTITY" => Metric::UsageQuantity,
+            "NORMALIZED_USAGE_AMOUNT" => Metric::NormalizedUsageAmount,
+            _ => Metric::Unknown(value.to_owned()),
+        }
+    }
 }
 
+impl From<String> for Metric {
+    fn from(value: String) -> Metric {
+        match value.as_str() {
+            "BLENDED_COST" => Metric::BlendedCost,
+            "UNBLENDED_COST" => Metric::UnblendedCost,
+            "AMORTIZED_COST" => Metric::AmortizedCost,
+            "NET_UNBLENDED_COST" => Metric::NetUnblendedCost,
+            "NET_AMORTIZED_COST" => Metric::NetAmortizedCost,
+            "USAGE_QUANTITY" => Metric::UsageQuantity,
+            "NORMALIZED_USAGE_AMOUNT" => Metric::NormalizedUsageAmount,
+            _ => Metric::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Metric {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Metric, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Metric {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Metric {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Metric {
+    fn deserialize<D>(deserializer: D) -> Result<Metric, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The aggregated value for a metric.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1220,6 +2165,212 @@ pub struct ModifyRecommendationDetail {
     pub target_instances: Option<Vec<TargetInstance>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OfferingClass {
+    /// `STANDARD`
+    Standard,
+    /// `CONVERTIBLE`
+    Convertible,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl OfferingClass {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OfferingClass::Standard => "STANDARD",
+            OfferingClass::Convertible => "CONVERTIBLE",
+            OfferingClass::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for OfferingClass {
+    fn default() -> OfferingClass {
+        OfferingClass::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for OfferingClass {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for OfferingClass {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for OfferingClass {
+    fn from(value: &'a str) -> OfferingClass {
+        match value {
+            "STANDARD" => OfferingClass::Standard,
+            "CONVERTIBLE" => OfferingClass::Convertible,
+            _ => OfferingClass::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OfferingClass {
+    fn from(value: String) -> OfferingClass {
+        match value.as_str() {
+            "STANDARD" => OfferingClass::Standard,
+            "CONVERTIBLE" => OfferingClass::Convertible,
+            _ => OfferingClass::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for OfferingClass {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<OfferingClass, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OfferingClass {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for OfferingClass {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OfferingClass {
+    fn deserialize<D>(deserializer: D) -> Result<OfferingClass, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PaymentOption {
+    /// `NO_UPFRONT`
+    NoUpfront,
+    /// `PARTIAL_UPFRONT`
+    PartialUpfront,
+    /// `ALL_UPFRONT`
+    AllUpfront,
+    /// `LIGHT_UTILIZATION`
+    LightUtilization,
+    /// `MEDIUM_UTILIZATION`
+    MediumUtilization,
+    /// `HEAVY_UTILIZATION`
+    HeavyUtilization,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PaymentOption {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PaymentOption::NoUpfront => "NO_UPFRONT",
+            PaymentOption::PartialUpfront => "PARTIAL_UPFRONT",
+            PaymentOption::AllUpfront => "ALL_UPFRONT",
+            PaymentOption::LightUtilization => "LIGHT_UTILIZATION",
+            PaymentOption::MediumUtilization => "MEDIUM_UTILIZATION",
+            PaymentOption::HeavyUtilization => "HEAVY_UTILIZATION",
+            PaymentOption::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PaymentOption {
+    fn default() -> PaymentOption {
+        PaymentOption::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PaymentOption {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PaymentOption {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PaymentOption {
+    fn from(value: &'a str) -> PaymentOption {
+        match value {
+            "NO_UPFRONT" => PaymentOption::NoUpfront,
+            "PARTIAL_UPFRONT" => PaymentOption::PartialUpfront,
+            "ALL_UPFRONT" => PaymentOption::AllUpfront,
+            "LIGHT_UTILIZATION" => PaymentOption::LightUtilization,
+            "MEDIUM_UTILIZATION" => PaymentOption::MediumUtilization,
+            "HEAVY_UTILIZATION" => PaymentOption::HeavyUtilization,
+            _ => PaymentOption::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PaymentOption {
+    fn from(value: String) -> PaymentOption {
+        match value.as_str() {
+            "NO_UPFRONT" => PaymentOption::NoUpfront,
+            "PARTIAL_UPFRONT" => PaymentOption::PartialUpfront,
+            "ALL_UPFRONT" => PaymentOption::AllUpfront,
+            "LIGHT_UTILIZATION" => PaymentOption::LightUtilization,
+            "MEDIUM_UTILIZATION" => PaymentOption::MediumUtilization,
+            "HEAVY_UTILIZATION" => PaymentOption::HeavyUtilization,
+            _ => PaymentOption::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PaymentOption {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PaymentOption, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PaymentOption {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PaymentOption {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PaymentOption {
+    fn deserialize<D>(deserializer: D) -> Result<PaymentOption, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Details about the Amazon RDS instances that AWS recommends that you purchase.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1371,15 +2522,15 @@ pub struct ReservationPurchaseRecommendation {
     /// <p>The account scope that AWS recommends that you purchase this instance for. For example, you can purchase this reservation for an entire organization in AWS Organizations.</p>
     #[serde(rename = "AccountScope")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub account_scope: Option<String>,
+    pub account_scope: Option<AccountScope>,
     /// <p>How many days of previous usage that AWS considers when making this recommendation.</p>
     #[serde(rename = "LookbackPeriodInDays")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub lookback_period_in_days: Option<String>,
+    pub lookback_period_in_days: Option<LookbackPeriodInDays>,
     /// <p>The payment option for the reservation. For example, <code>AllUpfront</code> or <code>NoUpfront</code>.</p>
     #[serde(rename = "PaymentOption")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub payment_option: Option<String>,
+    pub payment_option: Option<PaymentOption>,
     /// <p>Details about the recommended purchases.</p>
     #[serde(rename = "RecommendationDetails")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1395,7 +2546,7 @@ pub struct ReservationPurchaseRecommendation {
     /// <p>The term of the reservation that you want recommendations for, in years.</p>
     #[serde(rename = "TermInYears")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub term_in_years: Option<String>,
+    pub term_in_years: Option<TermInYears>,
 }
 
 /// <p>Details about your recommended reservation purchase.</p>
@@ -1595,7 +2746,7 @@ pub struct RightsizingRecommendation {
     /// <p>Recommendation to either terminate or modify the resource.</p>
     #[serde(rename = "RightsizingType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub rightsizing_type: Option<String>,
+    pub rightsizing_type: Option<RightsizingType>,
     /// <p>Details for termination recommendations.</p>
     #[serde(rename = "TerminateRecommendationDetail")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1613,7 +2764,7 @@ pub struct RightsizingRecommendationMetadata {
     /// <p> How many days of previous usage that Amazon Web Services considers when making this recommendation.</p>
     #[serde(rename = "LookbackPeriodInDays")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub lookback_period_in_days: Option<String>,
+    pub lookback_period_in_days: Option<LookbackPeriodInDays>,
     /// <p> The ID for this specific recommendation.</p>
     #[serde(rename = "RecommendationId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1642,6 +2793,99 @@ pub struct RightsizingRecommendationSummary {
     pub total_recommendation_count: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RightsizingType {
+    /// `TERMINATE`
+    Terminate,
+    /// `MODIFY`
+    Modify,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RightsizingType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RightsizingType::Terminate => "TERMINATE",
+            RightsizingType::Modify => "MODIFY",
+            RightsizingType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RightsizingType {
+    fn default() -> RightsizingType {
+        RightsizingType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RightsizingType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RightsizingType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RightsizingType {
+    fn from(value: &'a str) -> RightsizingType {
+        match value {
+            "TERMINATE" => RightsizingType::Terminate,
+            "MODIFY" => RightsizingType::Modify,
+            _ => RightsizingType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RightsizingType {
+    fn from(value: String) -> RightsizingType {
+        match value.as_str() {
+            "TERMINATE" => RightsizingType::Terminate,
+            "MODIFY" => RightsizingType::Modify,
+            _ => RightsizingType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RightsizingType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RightsizingType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RightsizingType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RightsizingType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RightsizingType {
+    fn deserialize<D>(deserializer: D) -> Result<RightsizingType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The amortized amount of Savings Plans purchased in a specific account during a specific time interval.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1724,11 +2968,11 @@ pub struct SavingsPlansPurchaseRecommendation {
     /// <p>The lookback period in days, used to generate the recommendation.</p>
     #[serde(rename = "LookbackPeriodInDays")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub lookback_period_in_days: Option<String>,
+    pub lookback_period_in_days: Option<LookbackPeriodInDays>,
     /// <p>The payment option used to generate the recommendation.</p>
     #[serde(rename = "PaymentOption")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub payment_option: Option<String>,
+    pub payment_option: Option<PaymentOption>,
     /// <p>Details for the Savings Plans we recommend you to purchase to cover existing, Savings Plans eligible workloads.</p>
     #[serde(rename = "SavingsPlansPurchaseRecommendationDetails")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1742,11 +2986,11 @@ pub struct SavingsPlansPurchaseRecommendation {
     /// <p>The requested Savings Plans recommendation type.</p>
     #[serde(rename = "SavingsPlansType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub savings_plans_type: Option<String>,
+    pub savings_plans_type: Option<SupportedSavingsPlansType>,
     /// <p>The Savings Plans recommendation term in years, used to generate the recommendation.</p>
     #[serde(rename = "TermInYears")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub term_in_years: Option<String>,
+    pub term_in_years: Option<TermInYears>,
 }
 
 /// <p>Details for your recommended Savings Plans.</p>
@@ -1990,6 +3234,104 @@ pub struct ServiceSpecification {
     pub ec2_specification: Option<EC2Specification>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SupportedSavingsPlansType {
+    /// `COMPUTE_SP`
+    ComputeSp,
+    /// `EC2_INSTANCE_SP`
+    Ec2InstanceSp,
+    /// `SAGEMAKER_SP`
+    SagemakerSp,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SupportedSavingsPlansType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SupportedSavingsPlansType::ComputeSp => "COMPUTE_SP",
+            SupportedSavingsPlansType::Ec2InstanceSp => "EC2_INSTANCE_SP",
+            SupportedSavingsPlansType::SagemakerSp => "SAGEMAKER_SP",
+            SupportedSavingsPlansType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SupportedSavingsPlansType {
+    fn default() -> SupportedSavingsPlansType {
+        SupportedSavingsPlansType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SupportedSavingsPlansType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SupportedSavingsPlansType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SupportedSavingsPlansType {
+    fn from(value: &'a str) -> SupportedSavingsPlansType {
+        match value {
+            "COMPUTE_SP" => SupportedSavingsPlansType::ComputeSp,
+            "EC2_INSTANCE_SP" => SupportedSavingsPlansType::Ec2InstanceSp,
+            "SAGEMAKER_SP" => SupportedSavingsPlansType::SagemakerSp,
+            _ => SupportedSavingsPlansType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SupportedSavingsPlansType {
+    fn from(value: String) -> SupportedSavingsPlansType {
+        match value.as_str() {
+            "COMPUTE_SP" => SupportedSavingsPlansType::ComputeSp,
+            "EC2_INSTANCE_SP" => SupportedSavingsPlansType::Ec2InstanceSp,
+            "SAGEMAKER_SP" => SupportedSavingsPlansType::SagemakerSp,
+            _ => SupportedSavingsPlansType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SupportedSavingsPlansType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SupportedSavingsPlansType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SupportedSavingsPlansType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SupportedSavingsPlansType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SupportedSavingsPlansType {
+    fn deserialize<D>(deserializer: D) -> Result<SupportedSavingsPlansType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The values that are available for a tag.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TagValues {
@@ -2033,6 +3375,99 @@ pub struct TargetInstance {
     pub resource_details: Option<ResourceDetails>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TermInYears {
+    /// `ONE_YEAR`
+    OneYear,
+    /// `THREE_YEARS`
+    ThreeYears,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TermInYears {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TermInYears::OneYear => "ONE_YEAR",
+            TermInYears::ThreeYears => "THREE_YEARS",
+            TermInYears::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TermInYears {
+    fn default() -> TermInYears {
+        TermInYears::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TermInYears {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TermInYears {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TermInYears {
+    fn from(value: &'a str) -> TermInYears {
+        match value {
+            "ONE_YEAR" => TermInYears::OneYear,
+            "THREE_YEARS" => TermInYears::ThreeYears,
+            _ => TermInYears::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TermInYears {
+    fn from(value: String) -> TermInYears {
+        match value.as_str() {
+            "ONE_YEAR" => TermInYears::OneYear,
+            "THREE_YEARS" => TermInYears::ThreeYears,
+            _ => TermInYears::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TermInYears {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TermInYears, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TermInYears {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TermInYears {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TermInYears {
+    fn deserialize<D>(deserializer: D) -> Result<TermInYears, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p> Details on termination recommendation. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -2054,7 +3489,7 @@ pub struct UpdateCostCategoryDefinitionRequest {
     #[serde(rename = "CostCategoryArn")]
     pub cost_category_arn: String,
     #[serde(rename = "RuleVersion")]
-    pub rule_version: String,
+    pub rule_version: CostCategoryRuleVersion,
     /// <p> <code>UpdateCostCategoryDefinition</code> supports dimensions, Tags, and nested expressions. Currently the only dimensions supported is <code>LINKED_ACCOUNT</code>.</p> <p>Root level <code>OR</code> is not supported. We recommend you create a separate rule instead.</p> <p>Rules are processed in order. If there are multiple rules that match the line item, then the first rule to match is used to determine that Cost Category value. </p>
     #[serde(rename = "Rules")]
     pub rules: Vec<CostCategoryRule>,
@@ -3148,11 +4583,12 @@ pub struct CostExplorerClient {
 impl CostExplorerClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> CostExplorerClient {
         CostExplorerClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Cost Explorer"),
         }
     }
 
@@ -3185,6 +4621,7 @@ impl CostExplorer for CostExplorerClient {
     ) -> Result<CreateCostCategoryDefinitionResponse, RusotoError<CreateCostCategoryDefinitionError>>
     {
         let mut request = SignedRequest::new("POST", "ce", &self.region, "/");
+        request.set_operation("CreateCostCategoryDefinition");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3217,6 +4654,7 @@ impl CostExplorer for CostExplorerClient {
     ) -> Result<DeleteCostCategoryDefinitionResponse, RusotoError<DeleteCostCategoryDefinitionError>>
     {
         let mut request = SignedRequest::new("POST", "ce", &self.region, "/");
+        request.set_operation("DeleteCostCategoryDefinition");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3251,6 +4689,7 @@ impl CostExplorer for CostExplorerClient {
         RusotoError<DescribeCostCategoryDefinitionError>,
     > {
         let mut request = SignedRequest::new("POST", "ce", &self.region, "/");
+        request.set_operation("DescribeCostCategoryDefinition");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3282,6 +4721,7 @@ impl CostExplorer for CostExplorerClient {
         input: GetCostAndUsageRequest,
     ) -> Result<GetCostAndUsageResponse, RusotoError<GetCostAndUsageError>> {
         let mut request = SignedRequest::new("POST", "ce", &self.region, "/");
+        request.set_operation("GetCostAndUsage");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSInsightsIndexService.GetCostAndUsage");
@@ -3310,6 +4750,7 @@ impl CostExplorer for CostExplorerClient {
     ) -> Result<GetCostAndUsageWithResourcesResponse, RusotoError<GetCostAndUsageWithResourcesError>>
     {
         let mut request = SignedRequest::new("POST", "ce", &self.region, "/");
+        request.set_operation("GetCostAndUsageWithResources");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3341,6 +4782,7 @@ impl CostExplorer for CostExplorerClient {
         input: GetCostForecastRequest,
     ) -> Result<GetCostForecastResponse, RusotoError<GetCostForecastError>> {
         let mut request = SignedRequest::new("POST", "ce", &self.region, "/");
+        request.set_operation("GetCostForecast");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSInsightsIndexService.GetCostForecast");
@@ -3368,6 +4810,7 @@ impl CostExplorer for CostExplorerClient {
         input: GetDimensionValuesRequest,
     ) -> Result<GetDimensionValuesResponse, RusotoError<GetDimensionValuesError>> {
         let mut request = SignedRequest::new("POST", "ce", &self.region, "/");
+        request.set_operation("GetDimensionValues");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSInsightsIndexService.GetDimensionValues");
@@ -3396,6 +4839,7 @@ impl CostExplorer for CostExplorerClient {
         input: GetReservationCoverageRequest,
     ) -> Result<GetReservationCoverageResponse, RusotoError<GetReservationCoverageError>> {
         let mut request = SignedRequest::new("POST", "ce", &self.region, "/");
+        request.set_operation("GetReservationCoverage");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3430,6 +4874,7 @@ impl CostExplorer for CostExplorerClient {
         RusotoError<GetReservationPurchaseRecommendationError>,
     > {
         let mut request = SignedRequest::new("POST", "ce", &self.region, "/");
+        request.set_operation("GetReservationPurchaseRecommendation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3464,6 +4909,7 @@ impl CostExplorer for CostExplorerClient {
     ) -> Result<GetReservationUtilizationResponse, RusotoError<GetReservationUtilizationError>>
     {
         let mut request = SignedRequest::new("POST", "ce", &self.region, "/");
+        request.set_operation("GetReservationUtilization");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3496,6 +4942,7 @@ impl CostExplorer for CostExplorerClient {
     ) -> Result<GetRightsizingRecommendationResponse, RusotoError<GetRightsizingRecommendationError>>
     {
         let mut request = SignedRequest::new("POST", "ce", &self.region, "/");
+        request.set_operation("GetRightsizingRecommendation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3527,6 +4974,7 @@ impl CostExplorer for CostExplorerClient {
         input: GetSavingsPlansCoverageRequest,
     ) -> Result<GetSavingsPlansCoverageResponse, RusotoError<GetSavingsPlansCoverageError>> {
         let mut request = SignedRequest::new("POST", "ce", &self.region, "/");
+        request.set_operation("GetSavingsPlansCoverage");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3561,6 +5009,7 @@ impl CostExplorer for CostExplorerClient {
         RusotoError<GetSavingsPlansPurchaseRecommendationError>,
     > {
         let mut request = SignedRequest::new("POST", "ce", &self.region, "/");
+        request.set_operation("GetSavingsPlansPurchaseRecommendation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3595,6 +5044,7 @@ impl CostExplorer for CostExplorerClient {
     ) -> Result<GetSavingsPlansUtilizationResponse, RusotoError<GetSavingsPlansUtilizationError>>
     {
         let mut request = SignedRequest::new("POST", "ce", &self.region, "/");
+        request.set_operation("GetSavingsPlansUtilization");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3629,6 +5079,7 @@ impl CostExplorer for CostExplorerClient {
         RusotoError<GetSavingsPlansUtilizationDetailsError>,
     > {
         let mut request = SignedRequest::new("POST", "ce", &self.region, "/");
+        request.set_operation("GetSavingsPlansUtilizationDetails");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3662,6 +5113,7 @@ impl CostExplorer for CostExplorerClient {
         input: GetTagsRequest,
     ) -> Result<GetTagsResponse, RusotoError<GetTagsError>> {
         let mut request = SignedRequest::new("POST", "ce", &self.region, "/");
+        request.set_operation("GetTags");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSInsightsIndexService.GetTags");
@@ -3689,6 +5141,7 @@ impl CostExplorer for CostExplorerClient {
         input: GetUsageForecastRequest,
     ) -> Result<GetUsageForecastResponse, RusotoError<GetUsageForecastError>> {
         let mut request = SignedRequest::new("POST", "ce", &self.region, "/");
+        request.set_operation("GetUsageForecast");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSInsightsIndexService.GetUsageForecast");
@@ -3718,6 +5171,7 @@ impl CostExplorer for CostExplorerClient {
     ) -> Result<ListCostCategoryDefinitionsResponse, RusotoError<ListCostCategoryDefinitionsError>>
     {
         let mut request = SignedRequest::new("POST", "ce", &self.region, "/");
+        request.set_operation("ListCostCategoryDefinitions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3750,6 +5204,7 @@ impl CostExplorer for CostExplorerClient {
     ) -> Result<UpdateCostCategoryDefinitionResponse, RusotoError<UpdateCostCategoryDefinitionError>>
     {
         let mut request = SignedRequest::new("POST", "ce", &self.region, "/");
+        request.set_operation("UpdateCostCategoryDefinition");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
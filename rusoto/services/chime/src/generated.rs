@@ -35,18 +35,23 @@ pub struct Account {
     /// <p>The Amazon Chime account type. For more information about different account types, see <a href="https://docs.aws.amazon.com/chime/latest/ag/manage-chime-account.html">Managing Your Amazon Chime Accounts</a> in the <i>Amazon Chime Administration Guide</i>.</p>
     #[serde(rename = "AccountType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub account_type: Option<String>,
+    pub account_type: Option<AccountType>,
     /// <p>The AWS account ID.</p>
     #[serde(rename = "AwsAccountId")]
     pub aws_account_id: String,
     /// <p>The Amazon Chime account creation timestamp, in ISO 8601 format.</p>
     #[serde(rename = "CreatedTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_timestamp: Option<f64>,
+    pub created_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The default license for the Amazon Chime account.</p>
     #[serde(rename = "DefaultLicense")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub default_license: Option<String>,
+    pub default_license: Option<License>,
     /// <p>The Amazon Chime account name.</p>
     #[serde(rename = "Name")]
     pub name: String,
@@ -57,7 +62,7 @@ pub struct Account {
     /// <p>Supported licenses for the Amazon Chime account.</p>
     #[serde(rename = "SupportedLicenses")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub supported_licenses: Option<Vec<String>>,
+    pub supported_licenses: Option<Vec<License>>,
 }
 
 /// <p>Settings related to the Amazon Chime account. This includes settings that start or stop remote control of shared screens, or start or stop the dial-out option in the Amazon Chime web application. For more information about these settings, see <a href="https://docs.aws.amazon.com/chime/latest/ag/policies.html">Use the Policies Page</a> in the <i>Amazon Chime Administration Guide</i>.</p>
@@ -73,6 +78,109 @@ pub struct AccountSettings {
     pub enable_dial_out: Option<bool>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AccountType {
+    /// `Team`
+    Team,
+    /// `EnterpriseDirectory`
+    EnterpriseDirectory,
+    /// `EnterpriseLWA`
+    EnterpriseLWA,
+    /// `EnterpriseOIDC`
+    EnterpriseOIDC,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AccountType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AccountType::Team => "Team",
+            AccountType::EnterpriseDirectory => "EnterpriseDirectory",
+            AccountType::EnterpriseLWA => "EnterpriseLWA",
+            AccountType::EnterpriseOIDC => "EnterpriseOIDC",
+            AccountType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AccountType {
+    fn default() -> AccountType {
+        AccountType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AccountType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AccountType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AccountType {
+    fn from(value: &'a str) -> AccountType {
+        match value {
+            "Team" => AccountType::Team,
+            "EnterpriseDirectory" => AccountType::EnterpriseDirectory,
+            "EnterpriseLWA" => AccountType::EnterpriseLWA,
+            "EnterpriseOIDC" => AccountType::EnterpriseOIDC,
+            _ => AccountType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AccountType {
+    fn from(value: String) -> AccountType {
+        match value.as_str() {
+            "Team" => AccountType::Team,
+            "EnterpriseDirectory" => AccountType::EnterpriseDirectory,
+            "EnterpriseLWA" => AccountType::EnterpriseLWA,
+            "EnterpriseOIDC" => AccountType::EnterpriseOIDC,
+            _ => AccountType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AccountType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AccountType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AccountType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AccountType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AccountType {
+    fn deserialize<D>(deserializer: D) -> Result<AccountType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The Alexa for Business metadata associated with an Amazon Chime user, used to integrate Alexa for Business with a device.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AlexaForBusinessMetadata {
@@ -343,11 +451,16 @@ pub struct Bot {
     /// <p>The bot type.</p>
     #[serde(rename = "BotType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub bot_type: Option<String>,
+    pub bot_type: Option<BotType>,
     /// <p>The bot creation timestamp, in ISO 8601 format.</p>
     #[serde(rename = "CreatedTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_timestamp: Option<f64>,
+    pub created_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>When true, the bot is stopped from running in your account.</p>
     #[serde(rename = "Disabled")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -362,14 +475,107 @@ pub struct Bot {
     pub security_token: Option<String>,
     /// <p>The updated bot timestamp, in ISO 8601 format.</p>
     #[serde(rename = "UpdatedTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_timestamp: Option<f64>,
+    pub updated_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The unique ID for the bot user.</p>
     #[serde(rename = "UserId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub user_id: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BotType {
+    /// `ChatBot`
+    ChatBot,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl BotType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BotType::ChatBot => "ChatBot",
+            BotType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for BotType {
+    fn default() -> BotType {
+        BotType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for BotType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for BotType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for BotType {
+    fn from(value: &'a str) -> BotType {
+        match value {
+            "ChatBot" => BotType::ChatBot,
+            _ => BotType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BotType {
+    fn from(value: String) -> BotType {
+        match value.as_str() {
+            "ChatBot" => BotType::ChatBot,
+            _ => BotType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for BotType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<BotType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for BotType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for BotType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BotType {
+    fn deserialize<D>(deserializer: D) -> Result<BotType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The Amazon Chime Business Calling settings for the administrator's AWS account. Includes any Amazon S3 buckets designated for storing call detail records.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct BusinessCallingSettings {
@@ -379,6 +585,109 @@ pub struct BusinessCallingSettings {
     pub cdr_bucket: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CallingNameStatus {
+    /// `Unassigned`
+    Unassigned,
+    /// `UpdateInProgress`
+    UpdateInProgress,
+    /// `UpdateSucceeded`
+    UpdateSucceeded,
+    /// `UpdateFailed`
+    UpdateFailed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CallingNameStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CallingNameStatus::Unassigned => "Unassigned",
+            CallingNameStatus::UpdateInProgress => "UpdateInProgress",
+            CallingNameStatus::UpdateSucceeded => "UpdateSucceeded",
+            CallingNameStatus::UpdateFailed => "UpdateFailed",
+            CallingNameStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CallingNameStatus {
+    fn default() -> CallingNameStatus {
+        CallingNameStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CallingNameStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CallingNameStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CallingNameStatus {
+    fn from(value: &'a str) -> CallingNameStatus {
+        match value {
+            "Unassigned" => CallingNameStatus::Unassigned,
+            "UpdateInProgress" => CallingNameStatus::UpdateInProgress,
+            "UpdateSucceeded" => CallingNameStatus::UpdateSucceeded,
+            "UpdateFailed" => CallingNameStatus::UpdateFailed,
+            _ => CallingNameStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CallingNameStatus {
+    fn from(value: String) -> CallingNameStatus {
+        match value.as_str() {
+            "Unassigned" => CallingNameStatus::Unassigned,
+            "UpdateInProgress" => CallingNameStatus::UpdateInProgress,
+            "UpdateSucceeded" => CallingNameStatus::UpdateSucceeded,
+            "UpdateFailed" => CallingNameStatus::UpdateFailed,
+            _ => CallingNameStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CallingNameStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CallingNameStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CallingNameStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CallingNameStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CallingNameStatus {
+    fn deserialize<D>(deserializer: D) -> Result<CallingNameStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateAccountRequest {
@@ -504,7 +813,7 @@ pub struct CreatePhoneNumberOrderRequest {
     pub e164_phone_numbers: Vec<String>,
     /// <p>The phone number product type.</p>
     #[serde(rename = "ProductType")]
-    pub product_type: String,
+    pub product_type: PhoneNumberProductType,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -528,7 +837,7 @@ pub struct CreateRoomMembershipRequest {
     /// <p>The role of the member.</p>
     #[serde(rename = "Role")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub role: Option<String>,
+    pub role: Option<RoomMembershipRole>,
     /// <p>The room ID.</p>
     #[serde(rename = "RoomId")]
     pub room_id: String,
@@ -580,7 +889,7 @@ pub struct CreateUserRequest {
     /// <p>The user type.</p>
     #[serde(rename = "UserType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub user_type: Option<String>,
+    pub user_type: Option<UserType>,
     /// <p>The user name.</p>
     #[serde(rename = "Username")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -622,7 +931,7 @@ pub struct CreateVoiceConnectorRequest {
     /// <p>The AWS Region in which the Amazon Chime Voice Connector is created. Default value: <code>us-east-1</code>.</p>
     #[serde(rename = "AwsRegion")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub aws_region: Option<String>,
+    pub aws_region: Option<VoiceConnectorAwsRegion>,
     /// <p>The name of the Amazon Chime Voice Connector.</p>
     #[serde(rename = "Name")]
     pub name: String,
@@ -853,6 +1162,268 @@ pub struct DisassociateSigninDelegateGroupsFromAccountRequest {
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DisassociateSigninDelegateGroupsFromAccountResponse {}
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EmailStatus {
+    /// `NotSent`
+    NotSent,
+    /// `Sent`
+    Sent,
+    /// `Failed`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EmailStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EmailStatus::NotSent => "NotSent",
+            EmailStatus::Sent => "Sent",
+            EmailStatus::Failed => "Failed",
+            EmailStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EmailStatus {
+    fn default() -> EmailStatus {
+        EmailStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EmailStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EmailStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EmailStatus {
+    fn from(value: &'a str) -> EmailStatus {
+        match value {
+            "NotSent" => EmailStatus::NotSent,
+            "Sent" => EmailStatus::Sent,
+            "Failed" => EmailStatus::Failed,
+            _ => EmailStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EmailStatus {
+    fn from(value: String) -> EmailStatus {
+        match value.as_str() {
+            "NotSent" => EmailStatus::NotSent,
+            "Sent" => EmailStatus::Sent,
+            "Failed" => EmailStatus::Failed,
+            _ => EmailStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EmailStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EmailStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EmailStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EmailStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EmailStatus {
+    fn deserialize<D>(deserializer: D) -> Result<EmailStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorCode {
+    /// `BadRequest`
+    BadRequest,
+    /// `Conflict`
+    Conflict,
+    /// `Forbidden`
+    Forbidden,
+    /// `NotFound`
+    NotFound,
+    /// `PreconditionFailed`
+    PreconditionFailed,
+    /// `ResourceLimitExceeded`
+    ResourceLimitExceeded,
+    /// `ServiceFailure`
+    ServiceFailure,
+    /// `AccessDenied`
+    AccessDenied,
+    /// `ServiceUnavailable`
+    ServiceUnavailable,
+    /// `Throttled`
+    Throttled,
+    /// `Throttling`
+    Throttling,
+    /// `Unauthorized`
+    Unauthorized,
+    /// `Unprocessable`
+    Unprocessable,
+    /// `VoiceConnectorGroupAssociationsExist`
+    VoiceConnectorGroupAssociationsExist,
+    /// `PhoneNumberAssociationsExist`
+    PhoneNumberAssociationsExist,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ErrorCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ErrorCode::BadRequest => "BadRequest",
+            ErrorCode::Conflict => "Conflict",
+            ErrorCode::Forbidden => "Forbidden",
+            ErrorCode::NotFound => "NotFound",
+            ErrorCode::PreconditionFailed => "PreconditionFailed",
+            ErrorCode::ResourceLimitExceeded => "ResourceLimitExceeded",
+            ErrorCode::ServiceFailure => "ServiceFailure",
+            ErrorCode::AccessDenied => "AccessDenied",
+            ErrorCode::ServiceUnavailable => "ServiceUnavailable",
+            ErrorCode::Throttled => "Throttled",
+            ErrorCode::Throttling => "Throttling",
+            ErrorCode::Unauthorized => "Unauthorized",
+            ErrorCode::Unprocessable => "Unprocessable",
+            ErrorCode::VoiceConnectorGroupAssociationsExist => {
+                "VoiceConnectorGroupAssociationsExist"
+            }
+            ErrorCode::PhoneNumberAssociationsExist => "PhoneNumberAssociationsExist",
+            ErrorCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ErrorCode {
+    fn default() -> ErrorCode {
+        ErrorCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ErrorCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ErrorCode {
+    fn from(value: &'a str) -> ErrorCode {
+        match value {
+            "BadRequest" => ErrorCode::BadRequest,
+            "Conflict" => ErrorCode::Conflict,
+            "Forbidden" => ErrorCode::Forbidden,
+            "NotFound" => ErrorCode::NotFound,
+            "PreconditionFailed" => ErrorCode::PreconditionFailed,
+            "ResourceLimitExceeded" => ErrorCode::ResourceLimitExceeded,
+            "ServiceFailure" => ErrorCode::ServiceFailure,
+            "AccessDenied" => ErrorCode::AccessDenied,
+            "ServiceUnavailable" => ErrorCode::ServiceUnavailable,
+            "Throttled" => ErrorCode::Throttled,
+            "Throttling" => ErrorCode::Throttling,
+            "Unauthorized" => ErrorCode::Unauthorized,
+            "Unprocessable" => ErrorCode::Unprocessable,
+            "VoiceConnectorGroupAssociationsExist" => {
+                ErrorCode::VoiceConnectorGroupAssociationsExist
+            }
+            "PhoneNumberAssociationsExist" => ErrorCode::PhoneNumberAssociationsExist,
+            _ => ErrorCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ErrorCode {
+    fn from(value: String) -> ErrorCode {
+        match value.as_str() {
+            "BadRequest" => ErrorCode::BadRequest,
+            "Conflict" => ErrorCode::Conflict,
+            "Forbidden" => ErrorCode::Forbidden,
+            "NotFound" => ErrorCode::NotFound,
+            "PreconditionFailed" => ErrorCode::PreconditionFailed,
+            "ResourceLimitExceeded" => ErrorCode::ResourceLimitExceeded,
+            "ServiceFailure" => ErrorCode::ServiceFailure,
+            "AccessDenied" => ErrorCode::AccessDenied,
+            "ServiceUnavailable" => ErrorCode::ServiceUnavailable,
+            "Throttled" => ErrorCode::Throttled,
+            "Throttling" => ErrorCode::Throttling,
+            "Unauthorized" => ErrorCode::Unauthorized,
+            "Unprocessable" => ErrorCode::Unprocessable,
+            "VoiceConnectorGroupAssociationsExist" => {
+                ErrorCode::VoiceConnectorGroupAssociationsExist
+            }
+            "PhoneNumberAssociationsExist" => ErrorCode::PhoneNumberAssociationsExist,
+            _ => ErrorCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ErrorCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ErrorCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ErrorCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ErrorCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ErrorCode {
+    fn deserialize<D>(deserializer: D) -> Result<ErrorCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The configuration that allows a bot to receive outgoing events. Can be either an HTTPS endpoint or a Lambda function ARN.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1038,8 +1609,13 @@ pub struct GetPhoneNumberSettingsResponse {
     pub calling_name: Option<String>,
     /// <p>The updated outbound calling name timestamp, in ISO 8601 format.</p>
     #[serde(rename = "CallingNameUpdatedTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub calling_name_updated_timestamp: Option<f64>,
+    pub calling_name_updated_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -1232,7 +1808,7 @@ pub struct Invite {
     /// <p>The status of the invite email.</p>
     #[serde(rename = "EmailStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub email_status: Option<String>,
+    pub email_status: Option<EmailStatus>,
     /// <p>The invite ID.</p>
     #[serde(rename = "InviteId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1240,9 +1816,107 @@ pub struct Invite {
     /// <p>The status of the invite.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<InviteStatus>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum InviteStatus {
+    /// `Pending`
+    Pending,
+    /// `Accepted`
+    Accepted,
+    /// `Failed`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl InviteStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InviteStatus::Pending => "Pending",
+            InviteStatus::Accepted => "Accepted",
+            InviteStatus::Failed => "Failed",
+            InviteStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for InviteStatus {
+    fn default() -> InviteStatus {
+        InviteStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for InviteStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for InviteStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for InviteStatus {
+    fn from(value: &'a str) -> InviteStatus {
+        match value {
+            "Pending" => InviteStatus::Pending,
+            "Accepted" => InviteStatus::Accepted,
+            "Failed" => InviteStatus::Failed,
+            _ => InviteStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InviteStatus {
+    fn from(value: String) -> InviteStatus {
+        match value.as_str() {
+            "Pending" => InviteStatus::Pending,
+            "Accepted" => InviteStatus::Accepted,
+            "Failed" => InviteStatus::Failed,
+            _ => InviteStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for InviteStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<InviteStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for InviteStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for InviteStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for InviteStatus {
+    fn deserialize<D>(deserializer: D) -> Result<InviteStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct InviteUsersRequest {
@@ -1255,7 +1929,7 @@ pub struct InviteUsersRequest {
     /// <p>The user type.</p>
     #[serde(rename = "UserType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub user_type: Option<String>,
+    pub user_type: Option<UserType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1267,23 +1941,126 @@ pub struct InviteUsersResponse {
     pub invites: Option<Vec<Invite>>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct ListAccountsRequest {
-    /// <p>The maximum number of results to return in a single call. Defaults to 100.</p>
-    #[serde(rename = "MaxResults")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub max_results: Option<i64>,
-    /// <p>Amazon Chime account name prefix with which to filter results.</p>
-    #[serde(rename = "Name")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
-    /// <p>The token to use to retrieve the next page of results.</p>
-    #[serde(rename = "NextToken")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_token: Option<String>,
-    /// <p>User email address with which to filter results.</p>
-    #[serde(rename = "UserEmail")]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum License {
+    /// `Basic`
+    Basic,
+    /// `Plus`
+    Plus,
+    /// `Pro`
+    Pro,
+    /// `ProTrial`
+    ProTrial,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl License {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            License::Basic => "Basic",
+            License::Plus => "Plus",
+            License::Pro => "Pro",
+            License::ProTrial => "ProTrial",
+            License::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for License {
+    fn default() -> License {
+        License::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for License {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for License {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for License {
+    fn from(value: &'a str) -> License {
+        match value {
+            "Basic" => License::Basic,
+            "Plus" => License::Plus,
+            "Pro" => License::Pro,
+            "ProTrial" => License::ProTrial,
+            _ => License::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for License {
+    fn from(value: String) -> License {
+        match value.as_str() {
+            "Basic" => License::Basic,
+            "Plus" => License::Plus,
+            "Pro" => License::Pro,
+            "ProTrial" => License::ProTrial,
+            _ => License::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for License {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<License, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for License {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for License {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for License {
+    fn deserialize<D>(deserializer: D) -> Result<License, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct ListAccountsRequest {
+    /// <p>The maximum number of results to return in a single call. Defaults to 100.</p>
+    #[serde(rename = "MaxResults")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_results: Option<i64>,
+    /// <p>Amazon Chime account name prefix with which to filter results.</p>
+    #[serde(rename = "Name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// <p>The token to use to retrieve the next page of results.</p>
+    #[serde(rename = "NextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+    /// <p>User email address with which to filter results.</p>
+    #[serde(rename = "UserEmail")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub user_email: Option<String>,
 }
@@ -1417,7 +2194,7 @@ pub struct ListPhoneNumbersRequest {
     /// <p>The filter to use to limit the number of results.</p>
     #[serde(rename = "FilterName")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub filter_name: Option<String>,
+    pub filter_name: Option<PhoneNumberAssociationName>,
     /// <p>The value to use for the filter.</p>
     #[serde(rename = "FilterValue")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1433,11 +2210,11 @@ pub struct ListPhoneNumbersRequest {
     /// <p>The phone number product type.</p>
     #[serde(rename = "ProductType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub product_type: Option<String>,
+    pub product_type: Option<PhoneNumberProductType>,
     /// <p>The phone number status.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<PhoneNumberStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1539,7 +2316,7 @@ pub struct ListUsersRequest {
     /// <p>The user type.</p>
     #[serde(rename = "UserType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub user_type: Option<String>,
+    pub user_type: Option<UserType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1733,7 +2510,7 @@ pub struct Member {
     /// <p>The member type.</p>
     #[serde(rename = "MemberType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub member_type: Option<String>,
+    pub member_type: Option<MemberType>,
 }
 
 /// <p>The list of errors returned when a member action results in an error.</p>
@@ -1743,7 +2520,7 @@ pub struct MemberError {
     /// <p>The error code.</p>
     #[serde(rename = "ErrorCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub error_code: Option<String>,
+    pub error_code: Option<ErrorCode>,
     /// <p>The error message.</p>
     #[serde(rename = "ErrorMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1754,6 +2531,104 @@ pub struct MemberError {
     pub member_id: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MemberType {
+    /// `User`
+    User,
+    /// `Bot`
+    Bot,
+    /// `Webhook`
+    Webhook,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl MemberType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MemberType::User => "User",
+            MemberType::Bot => "Bot",
+            MemberType::Webhook => "Webhook",
+            MemberType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for MemberType {
+    fn default() -> MemberType {
+        MemberType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for MemberType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for MemberType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for MemberType {
+    fn from(value: &'a str) -> MemberType {
+        match value {
+            "User" => MemberType::User,
+            "Bot" => MemberType::Bot,
+            "Webhook" => MemberType::Webhook,
+            _ => MemberType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MemberType {
+    fn from(value: String) -> MemberType {
+        match value.as_str() {
+            "User" => MemberType::User,
+            "Bot" => MemberType::Bot,
+            "Webhook" => MemberType::Webhook,
+            _ => MemberType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for MemberType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<MemberType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MemberType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for MemberType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MemberType {
+    fn deserialize<D>(deserializer: D) -> Result<MemberType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Membership details, such as member ID and member role.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -1765,7 +2640,7 @@ pub struct MembershipItem {
     /// <p>The member role.</p>
     #[serde(rename = "Role")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub role: Option<String>,
+    pub role: Option<RoomMembershipRole>,
 }
 
 /// <p>A phone number for which an order has been placed.</p>
@@ -1779,9 +2654,107 @@ pub struct OrderedPhoneNumber {
     /// <p>The phone number status.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<OrderedPhoneNumberStatus>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OrderedPhoneNumberStatus {
+    /// `Processing`
+    Processing,
+    /// `Acquired`
+    Acquired,
+    /// `Failed`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl OrderedPhoneNumberStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OrderedPhoneNumberStatus::Processing => "Processing",
+            OrderedPhoneNumberStatus::Acquired => "Acquired",
+            OrderedPhoneNumberStatus::Failed => "Failed",
+            OrderedPhoneNumberStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for OrderedPhoneNumberStatus {
+    fn default() -> OrderedPhoneNumberStatus {
+        OrderedPhoneNumberStatus::Unknown(String::new())
+    }
 }
 
+impl AsRef<str> for OrderedPhoneNumberStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for OrderedPhoneNumberStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for OrderedPhoneNumberStatus {
+    fn from(value: &'a str) -> OrderedPhoneNumberStatus {
+        match value {
+            "Processing" => OrderedPhoneNumberStatus::Processing,
+            "Acquired" => OrderedPhoneNumberStatus::Acquired,
+            "Failed" => OrderedPhoneNumberStatus::Failed,
+            _ => OrderedPhoneNumberStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OrderedPhoneNumberStatus {
+    fn from(value: String) -> OrderedPhoneNumberStatus {
+        match value.as_str() {
+            "Processing" => OrderedPhoneNumberStatus::Processing,
+            "Acquired" => OrderedPhoneNumberStatus::Acquired,
+            "Failed" => OrderedPhoneNumberStatus::Failed,
+            _ => OrderedPhoneNumberStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for OrderedPhoneNumberStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<OrderedPhoneNumberStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OrderedPhoneNumberStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for OrderedPhoneNumberStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OrderedPhoneNumberStatus {
+    fn deserialize<D>(deserializer: D) -> Result<OrderedPhoneNumberStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Origination settings enable your SIP hosts to receive inbound calls using your Amazon Chime Voice Connector.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Origination {
@@ -1813,13 +2786,106 @@ pub struct OriginationRoute {
     /// <p>The protocol to use for the origination route. Encryption-enabled Amazon Chime Voice Connectors use TCP protocol by default.</p>
     #[serde(rename = "Protocol")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub protocol: Option<String>,
+    pub protocol: Option<OriginationRouteProtocol>,
     /// <p>The weight associated with the host. If hosts are equal in priority, calls are distributed among them based on their relative weight.</p>
     #[serde(rename = "Weight")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub weight: Option<i64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OriginationRouteProtocol {
+    /// `TCP`
+    Tcp,
+    /// `UDP`
+    Udp,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl OriginationRouteProtocol {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OriginationRouteProtocol::Tcp => "TCP",
+            OriginationRouteProtocol::Udp => "UDP",
+            OriginationRouteProtocol::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for OriginationRouteProtocol {
+    fn default() -> OriginationRouteProtocol {
+        OriginationRouteProtocol::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for OriginationRouteProtocol {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for OriginationRouteProtocol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for OriginationRouteProtocol {
+    fn from(value: &'a str) -> OriginationRouteProtocol {
+        match value {
+            "TCP" => OriginationRouteProtocol::Tcp,
+            "UDP" => OriginationRouteProtocol::Udp,
+            _ => OriginationRouteProtocol::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OriginationRouteProtocol {
+    fn from(value: String) -> OriginationRouteProtocol {
+        match value.as_str() {
+            "TCP" => OriginationRouteProtocol::Tcp,
+            "UDP" => OriginationRouteProtocol::Udp,
+            _ => OriginationRouteProtocol::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for OriginationRouteProtocol {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<OriginationRouteProtocol, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OriginationRouteProtocol {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for OriginationRouteProtocol {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OriginationRouteProtocol {
+    fn deserialize<D>(deserializer: D) -> Result<OriginationRouteProtocol, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A phone number used for Amazon Chime Business Calling or an Amazon Chime Voice Connector.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1835,19 +2901,29 @@ pub struct PhoneNumber {
     /// <p>The outbound calling name status.</p>
     #[serde(rename = "CallingNameStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub calling_name_status: Option<String>,
+    pub calling_name_status: Option<CallingNameStatus>,
     /// <p>The phone number capabilities.</p>
     #[serde(rename = "Capabilities")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub capabilities: Option<PhoneNumberCapabilities>,
     /// <p>The phone number creation timestamp, in ISO 8601 format.</p>
     #[serde(rename = "CreatedTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_timestamp: Option<f64>,
+    pub created_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The deleted phone number timestamp, in ISO 8601 format.</p>
     #[serde(rename = "DeletionTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deletion_timestamp: Option<f64>,
+    pub deletion_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The phone number, in E.164 format.</p>
     #[serde(rename = "E164PhoneNumber")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1859,19 +2935,24 @@ pub struct PhoneNumber {
     /// <p>The phone number product type.</p>
     #[serde(rename = "ProductType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub product_type: Option<String>,
+    pub product_type: Option<PhoneNumberProductType>,
     /// <p>The phone number status.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<PhoneNumberStatus>,
     /// <p>The phone number type.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<PhoneNumberType>,
     /// <p>The updated phone number timestamp, in ISO 8601 format.</p>
     #[serde(rename = "UpdatedTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_timestamp: Option<f64>,
+    pub updated_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>The phone number associations, such as Amazon Chime account ID, Amazon Chime user ID, Amazon Chime Voice Connector ID, or Amazon Chime Voice Connector group ID.</p>
@@ -1880,96 +2961,636 @@ pub struct PhoneNumber {
 pub struct PhoneNumberAssociation {
     /// <p>The timestamp of the phone number association, in ISO 8601 format.</p>
     #[serde(rename = "AssociatedTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub associated_timestamp: Option<f64>,
+    pub associated_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Defines the association with an Amazon Chime account ID, user ID, Amazon Chime Voice Connector ID, or Amazon Chime Voice Connector group ID.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
+    pub name: Option<PhoneNumberAssociationName>,
     /// <p>Contains the ID for the entity specified in Name.</p>
     #[serde(rename = "Value")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<String>,
 }
 
-/// <p>The phone number capabilities for Amazon Chime Business Calling phone numbers, such as enabled inbound and outbound calling and text messaging.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct PhoneNumberCapabilities {
-    /// <p>Allows or denies inbound calling for the specified phone number.</p>
-    #[serde(rename = "InboundCall")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub inbound_call: Option<bool>,
-    /// <p>Allows or denies inbound MMS messaging for the specified phone number.</p>
-    #[serde(rename = "InboundMMS")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub inbound_mms: Option<bool>,
-    /// <p>Allows or denies inbound SMS messaging for the specified phone number.</p>
-    #[serde(rename = "InboundSMS")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub inbound_sms: Option<bool>,
-    /// <p>Allows or denies outbound calling for the specified phone number.</p>
-    #[serde(rename = "OutboundCall")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub outbound_call: Option<bool>,
-    /// <p>Allows or denies outbound MMS messaging for the specified phone number.</p>
-    #[serde(rename = "OutboundMMS")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub outbound_mms: Option<bool>,
-    /// <p>Allows or denies outbound SMS messaging for the specified phone number.</p>
-    #[serde(rename = "OutboundSMS")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub outbound_sms: Option<bool>,
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PhoneNumberAssociationName {
+    /// `AccountId`
+    AccountId,
+    /// `UserId`
+    UserId,
+    /// `VoiceConnectorId`
+    VoiceConnectorId,
+    /// `VoiceConnectorGroupId`
+    VoiceConnectorGroupId,
+    /// `SipRuleId`
+    SipRuleId,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PhoneNumberAssociationName {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PhoneNumberAssociationName::AccountId => "AccountId",
+            PhoneNumberAssociationName::UserId => "UserId",
+            PhoneNumberAssociationName::VoiceConnectorId => "VoiceConnectorId",
+            PhoneNumberAssociationName::VoiceConnectorGroupId => "VoiceConnectorGroupId",
+            PhoneNumberAssociationName::SipRuleId => "SipRuleId",
+            PhoneNumberAssociationName::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PhoneNumberAssociationName {
+    fn default() -> PhoneNumberAssociationName {
+        PhoneNumberAssociationName::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PhoneNumberAssociationName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PhoneNumberAssociationName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PhoneNumberAssociationName {
+    fn from(value: &'a str) -> PhoneNumberAssociationName {
+        match value {
+            "AccountId" => PhoneNumberAssociationName::AccountId,
+            "UserId" => PhoneNumberAssociationName::UserId,
+            "VoiceConnectorId" => PhoneNumberAssociationName::VoiceConnectorId,
+            "VoiceConnectorGroupId" => PhoneNumberAssociationName::VoiceConnectorGroupId,
+            "SipRuleId" => PhoneNumberAssociationName::SipRuleId,
+            _ => PhoneNumberAssociationName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PhoneNumberAssociationName {
+    fn from(value: String) -> PhoneNumberAssociationName {
+        match value.as_str() {
+            "AccountId" => PhoneNumberAssociationName::AccountId,
+            "UserId" => PhoneNumberAssociationName::UserId,
+            "VoiceConnectorId" => PhoneNumberAssociationName::VoiceConnectorId,
+            "VoiceConnectorGroupId" => PhoneNumberAssociationName::VoiceConnectorGroupId,
+            "SipRuleId" => PhoneNumberAssociationName::SipRuleId,
+            _ => PhoneNumberAssociationName::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PhoneNumberAssociationName {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PhoneNumberAssociationName, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PhoneNumberAssociationName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PhoneNumberAssociationName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PhoneNumberAssociationName {
+    fn deserialize<D>(deserializer: D) -> Result<PhoneNumberAssociationName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>The phone number capabilities for Amazon Chime Business Calling phone numbers, such as enabled inbound and outbound calling and text messaging.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct PhoneNumberCapabilities {
+    /// <p>Allows or denies inbound calling for the specified phone number.</p>
+    #[serde(rename = "InboundCall")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inbound_call: Option<bool>,
+    /// <p>Allows or denies inbound MMS messaging for the specified phone number.</p>
+    #[serde(rename = "InboundMMS")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inbound_mms: Option<bool>,
+    /// <p>Allows or denies inbound SMS messaging for the specified phone number.</p>
+    #[serde(rename = "InboundSMS")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inbound_sms: Option<bool>,
+    /// <p>Allows or denies outbound calling for the specified phone number.</p>
+    #[serde(rename = "OutboundCall")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub outbound_call: Option<bool>,
+    /// <p>Allows or denies outbound MMS messaging for the specified phone number.</p>
+    #[serde(rename = "OutboundMMS")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub outbound_mms: Option<bool>,
+    /// <p>Allows or denies outbound SMS messaging for the specified phone number.</p>
+    #[serde(rename = "OutboundSMS")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub outbound_sms: Option<bool>,
+}
+
+/// <p>If the phone number action fails for one or more of the phone numbers in the request, a list of the phone numbers is returned, along with error codes and error messages.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct PhoneNumberError {
+    /// <p>The error code.</p>
+    #[serde(rename = "ErrorCode")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<ErrorCode>,
+    /// <p>The error message.</p>
+    #[serde(rename = "ErrorMessage")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_message: Option<String>,
+    /// <p>The phone number ID for which the action failed.</p>
+    #[serde(rename = "PhoneNumberId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number_id: Option<String>,
+}
+
+/// <p>The details of a phone number order created for Amazon Chime.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct PhoneNumberOrder {
+    /// <p>The phone number order creation timestamp, in ISO 8601 format.</p>
+    #[serde(rename = "CreatedTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
+    /// <p>The ordered phone number details, such as the phone number in E.164 format and the phone number status.</p>
+    #[serde(rename = "OrderedPhoneNumbers")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ordered_phone_numbers: Option<Vec<OrderedPhoneNumber>>,
+    /// <p>The phone number order ID.</p>
+    #[serde(rename = "PhoneNumberOrderId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number_order_id: Option<String>,
+    /// <p>The phone number order product type.</p>
+    #[serde(rename = "ProductType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product_type: Option<PhoneNumberProductType>,
+    /// <p>The status of the phone number order.</p>
+    #[serde(rename = "Status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<PhoneNumberOrderStatus>,
+    /// <p>The updated phone number order timestamp, in ISO 8601 format.</p>
+    #[serde(rename = "UpdatedTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PhoneNumberOrderStatus {
+    /// `Processing`
+    Processing,
+    /// `Successful`
+    Successful,
+    /// `Failed`
+    Failed,
+    /// `Partial`
+    Partial,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PhoneNumberOrderStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PhoneNumberOrderStatus::Processing => "Processing",
+            PhoneNumberOrderStatus::Successful => "Successful",
+            PhoneNumberOrderStatus::Failed => "Failed",
+            PhoneNumberOrderStatus::Partial => "Partial",
+            PhoneNumberOrderStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PhoneNumberOrderStatus {
+    fn default() -> PhoneNumberOrderStatus {
+        PhoneNumberOrderStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PhoneNumberOrderStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PhoneNumberOrderStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PhoneNumberOrderStatus {
+    fn from(value: &'a str) -> PhoneNumberOrderStatus {
+        match value {
+            "Processing" => PhoneNumberOrderStatus::Processing,
+            "Successful" => PhoneNumberOrderStatus::Successful,
+            "Failed" => PhoneNumberOrderStatus::Failed,
+            "Partial" => PhoneNumberOrderStatus::Partial,
+            _ => PhoneNumberOrderStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PhoneNumberOrderStatus {
+    fn from(value: String) -> PhoneNumberOrderStatus {
+        match value.as_str() {
+            "Processing" => PhoneNumberOrderStatus::Processing,
+            "Successful" => PhoneNumberOrderStatus::Successful,
+            "Failed" => PhoneNumberOrderStatus::Failed,
+            "Partial" => PhoneNumberOrderStatus::Partial,
+            _ => PhoneNumberOrderStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PhoneNumberOrderStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PhoneNumberOrderStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PhoneNumberOrderStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PhoneNumberOrderStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PhoneNumberOrderStatus {
+    fn deserialize<D>(deserializer: D) -> Result<PhoneNumberOrderStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PhoneNumberProductType {
+    /// `BusinessCalling`
+    BusinessCalling,
+    /// `VoiceConnector`
+    VoiceConnector,
+    /// `SipMediaApplicationDialIn`
+    SipMediaApplicationDialIn,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PhoneNumberProductType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PhoneNumberProductType::BusinessCalling => "BusinessCalling",
+            PhoneNumberProductType::VoiceConnector => "VoiceConnector",
+            PhoneNumberProductType::SipMediaApplicationDialIn => "SipMediaApplicationDialIn",
+            PhoneNumberProductType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PhoneNumberProductType {
+    fn default() -> PhoneNumberProductType {
+        PhoneNumberProductType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PhoneNumberProductType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PhoneNumberProductType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PhoneNumberProductType {
+    fn from(value: &'a str) -> PhoneNumberProductType {
+        match value {
+            "BusinessCalling" => PhoneNumberProductType::BusinessCalling,
+            "VoiceConnector" => PhoneNumberProductType::VoiceConnector,
+            "SipMediaApplicationDialIn" => PhoneNumberProductType::SipMediaApplicationDialIn,
+            _ => PhoneNumberProductType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PhoneNumberProductType {
+    fn from(value: String) -> PhoneNumberProductType {
+        match value.as_str() {
+            "BusinessCalling" => PhoneNumberProductType::BusinessCalling,
+            "VoiceConnector" => PhoneNumberProductType::VoiceConnector,
+            "SipMediaApplicationDialIn" => PhoneNumberProductType::SipMediaApplicationDialIn,
+            _ => PhoneNumberProductType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PhoneNumberProductType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PhoneNumberProductType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PhoneNumberProductType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PhoneNumberProductType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PhoneNumberProductType {
+    fn deserialize<D>(deserializer: D) -> Result<PhoneNumberProductType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PhoneNumberStatus {
+    /// `AcquireInProgress`
+    AcquireInProgress,
+    /// `AcquireFailed`
+    AcquireFailed,
+    /// `Unassigned`
+    Unassigned,
+    /// `Assigned`
+    Assigned,
+    /// `ReleaseInProgress`
+    ReleaseInProgress,
+    /// `DeleteInProgress`
+    DeleteInProgress,
+    /// `ReleaseFailed`
+    ReleaseFailed,
+    /// `DeleteFailed`
+    DeleteFailed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PhoneNumberStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PhoneNumberStatus::AcquireInProgress => "AcquireInProgress",
+            PhoneNumberStatus::AcquireFailed => "AcquireFailed",
+            PhoneNumberStatus::Unassigned => "Unassigned",
+            PhoneNumberStatus::Assigned => "Assigned",
+            PhoneNumberStatus::ReleaseInProgress => "ReleaseInProgress",
+            PhoneNumberStatus::DeleteInProgress => "DeleteInProgress",
+            PhoneNumberStatus::ReleaseFailed => "ReleaseFailed",
+            PhoneNumberStatus::DeleteFailed => "DeleteFailed",
+            PhoneNumberStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PhoneNumberStatus {
+    fn default() -> PhoneNumberStatus {
+        PhoneNumberStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PhoneNumberStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PhoneNumberStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PhoneNumberStatus {
+    fn from(value: &'a str) -> PhoneNumberStatus {
+        match value {
+            "AcquireInProgress" => PhoneNumberStatus::AcquireInProgress,
+            "AcquireFailed" => PhoneNumberStatus::AcquireFailed,
+            "Unassigned" => PhoneNumberStatus::Unassigned,
+            "Assigned" => PhoneNumberStatus::Assigned,
+            "ReleaseInProgress" => PhoneNumberStatus::ReleaseInProgress,
+            "DeleteInProgress" => PhoneNumberStatus::DeleteInProgress,
+            "ReleaseFailed" => PhoneNumberStatus::ReleaseFailed,
+            "DeleteFailed" => PhoneNumberStatus::DeleteFailed,
+            _ => PhoneNumberStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PhoneNumberStatus {
+    fn from(value: String) -> PhoneNumberStatus {
+        match value.as_str() {
+            "AcquireInProgress" => PhoneNumberStatus::AcquireInProgress,
+            "AcquireFailed" => PhoneNumberStatus::AcquireFailed,
+            "Unassigned" => PhoneNumberStatus::Unassigned,
+            "Assigned" => PhoneNumberStatus::Assigned,
+            "ReleaseInProgress" => PhoneNumberStatus::ReleaseInProgress,
+            "DeleteInProgress" => PhoneNumberStatus::DeleteInProgress,
+            "ReleaseFailed" => PhoneNumberStatus::ReleaseFailed,
+            "DeleteFailed" => PhoneNumberStatus::DeleteFailed,
+            _ => PhoneNumberStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PhoneNumberStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PhoneNumberStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PhoneNumberStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PhoneNumberStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PhoneNumberStatus {
+    fn deserialize<D>(deserializer: D) -> Result<PhoneNumberStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PhoneNumberType {
+    /// `Local`
+    Local,
+    /// `TollFree`
+    TollFree,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PhoneNumberType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PhoneNumberType::Local => "Local",
+            PhoneNumberType::TollFree => "TollFree",
+            PhoneNumberType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PhoneNumberType {
+    fn default() -> PhoneNumberType {
+        PhoneNumberType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PhoneNumberType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PhoneNumberType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PhoneNumberType {
+    fn from(value: &'a str) -> PhoneNumberType {
+        match value {
+            "Local" => PhoneNumberType::Local,
+            "TollFree" => PhoneNumberType::TollFree,
+            _ => PhoneNumberType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PhoneNumberType {
+    fn from(value: String) -> PhoneNumberType {
+        match value.as_str() {
+            "Local" => PhoneNumberType::Local,
+            "TollFree" => PhoneNumberType::TollFree,
+            _ => PhoneNumberType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PhoneNumberType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PhoneNumberType, Self::Err> {
+        Ok(value.into())
+    }
 }
 
-/// <p>If the phone number action fails for one or more of the phone numbers in the request, a list of the phone numbers is returned, along with error codes and error messages.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct PhoneNumberError {
-    /// <p>The error code.</p>
-    #[serde(rename = "ErrorCode")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub error_code: Option<String>,
-    /// <p>The error message.</p>
-    #[serde(rename = "ErrorMessage")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub error_message: Option<String>,
-    /// <p>The phone number ID for which the action failed.</p>
-    #[serde(rename = "PhoneNumberId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub phone_number_id: Option<String>,
+impl ::rusoto_core::param::ToParam for PhoneNumberType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
 }
 
-/// <p>The details of a phone number order created for Amazon Chime.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct PhoneNumberOrder {
-    /// <p>The phone number order creation timestamp, in ISO 8601 format.</p>
-    #[serde(rename = "CreatedTimestamp")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_timestamp: Option<f64>,
-    /// <p>The ordered phone number details, such as the phone number in E.164 format and the phone number status.</p>
-    #[serde(rename = "OrderedPhoneNumbers")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub ordered_phone_numbers: Option<Vec<OrderedPhoneNumber>>,
-    /// <p>The phone number order ID.</p>
-    #[serde(rename = "PhoneNumberOrderId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub phone_number_order_id: Option<String>,
-    /// <p>The phone number order product type.</p>
-    #[serde(rename = "ProductType")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub product_type: Option<String>,
-    /// <p>The status of the phone number order.</p>
-    #[serde(rename = "Status")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
-    /// <p>The updated phone number order timestamp, in ISO 8601 format.</p>
-    #[serde(rename = "UpdatedTimestamp")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_timestamp: Option<f64>,
+impl ::serde::Serialize for PhoneNumberType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for PhoneNumberType {
+    fn deserialize<D>(deserializer: D) -> Result<PhoneNumberType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct PutEventsConfigurationRequest {
@@ -2108,6 +3729,104 @@ pub struct RegenerateSecurityTokenResponse {
     pub bot: Option<Bot>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RegistrationStatus {
+    /// `Unregistered`
+    Unregistered,
+    /// `Registered`
+    Registered,
+    /// `Suspended`
+    Suspended,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RegistrationStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RegistrationStatus::Unregistered => "Unregistered",
+            RegistrationStatus::Registered => "Registered",
+            RegistrationStatus::Suspended => "Suspended",
+            RegistrationStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RegistrationStatus {
+    fn default() -> RegistrationStatus {
+        RegistrationStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RegistrationStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RegistrationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RegistrationStatus {
+    fn from(value: &'a str) -> RegistrationStatus {
+        match value {
+            "Unregistered" => RegistrationStatus::Unregistered,
+            "Registered" => RegistrationStatus::Registered,
+            "Suspended" => RegistrationStatus::Suspended,
+            _ => RegistrationStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RegistrationStatus {
+    fn from(value: String) -> RegistrationStatus {
+        match value.as_str() {
+            "Unregistered" => RegistrationStatus::Unregistered,
+            "Registered" => RegistrationStatus::Registered,
+            "Suspended" => RegistrationStatus::Suspended,
+            _ => RegistrationStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RegistrationStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RegistrationStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RegistrationStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RegistrationStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RegistrationStatus {
+    fn deserialize<D>(deserializer: D) -> Result<RegistrationStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ResetPersonalPINRequest {
@@ -2159,8 +3878,13 @@ pub struct Room {
     pub created_by: Option<String>,
     /// <p>The room creation timestamp, in ISO 8601 format.</p>
     #[serde(rename = "CreatedTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_timestamp: Option<f64>,
+    pub created_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The room name.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2171,8 +3895,13 @@ pub struct Room {
     pub room_id: Option<String>,
     /// <p>The room update timestamp, in ISO 8601 format.</p>
     #[serde(rename = "UpdatedTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_timestamp: Option<f64>,
+    pub updated_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>The room membership details.</p>
@@ -2189,17 +3918,115 @@ pub struct RoomMembership {
     /// <p>The membership role.</p>
     #[serde(rename = "Role")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub role: Option<String>,
+    pub role: Option<RoomMembershipRole>,
     /// <p>The room ID.</p>
     #[serde(rename = "RoomId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub room_id: Option<String>,
     /// <p>The room membership update timestamp, in ISO 8601 format.</p>
     #[serde(rename = "UpdatedTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_timestamp: Option<f64>,
+    pub updated_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RoomMembershipRole {
+    /// `Administrator`
+    Administrator,
+    /// `Member`
+    Member,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RoomMembershipRole {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RoomMembershipRole::Administrator => "Administrator",
+            RoomMembershipRole::Member => "Member",
+            RoomMembershipRole::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RoomMembershipRole {
+    fn default() -> RoomMembershipRole {
+        RoomMembershipRole::Unknown(String::new())
+    }
 }
 
+impl AsRef<str> for RoomMembershipRole {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RoomMembershipRole {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RoomMembershipRole {
+    fn from(value: &'a str) -> RoomMembershipRole {
+        match value {
+            "Administrator" => RoomMembershipRole::Administrator,
+            "Member" => RoomMembershipRole::Member,
+            _ => RoomMembershipRole::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RoomMembershipRole {
+    fn from(value: String) -> RoomMembershipRole {
+        match value.as_str() {
+            "Administrator" => RoomMembershipRole::Administrator,
+            "Member" => RoomMembershipRole::Member,
+            _ => RoomMembershipRole::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RoomMembershipRole {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RoomMembershipRole, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RoomMembershipRole {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RoomMembershipRole {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RoomMembershipRole {
+    fn deserialize<D>(deserializer: D) -> Result<RoomMembershipRole, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct SearchAvailablePhoneNumbersRequest {
@@ -2312,8 +4139,13 @@ pub struct TerminationHealth {
     pub source: Option<String>,
     /// <p>The timestamp, in ISO 8601 format.</p>
     #[serde(rename = "Timestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub timestamp: Option<f64>,
+    pub timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -2400,7 +4232,7 @@ pub struct UpdatePhoneNumberRequest {
     /// <p>The product type.</p>
     #[serde(rename = "ProductType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub product_type: Option<String>,
+    pub product_type: Option<PhoneNumberProductType>,
 }
 
 /// <p>The phone number ID, product type, or calling name fields to update, used with the <a>BatchUpdatePhoneNumber</a> and <a>UpdatePhoneNumber</a> actions.</p>
@@ -2417,7 +4249,7 @@ pub struct UpdatePhoneNumberRequestItem {
     /// <p>The product type to update.</p>
     #[serde(rename = "ProductType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub product_type: Option<String>,
+    pub product_type: Option<PhoneNumberProductType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -2449,7 +4281,7 @@ pub struct UpdateRoomMembershipRequest {
     /// <p>The role of the member.</p>
     #[serde(rename = "Role")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub role: Option<String>,
+    pub role: Option<RoomMembershipRole>,
     /// <p>The room ID.</p>
     #[serde(rename = "RoomId")]
     pub room_id: String,
@@ -2501,14 +4333,14 @@ pub struct UpdateUserRequest {
     /// <p>The user license type to update. This must be a supported license type for the Amazon Chime account that the user belongs to.</p>
     #[serde(rename = "LicenseType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub license_type: Option<String>,
+    pub license_type: Option<License>,
     /// <p>The user ID.</p>
     #[serde(rename = "UserId")]
     pub user_id: String,
     /// <p>The user type.</p>
     #[serde(rename = "UserType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub user_type: Option<String>,
+    pub user_type: Option<UserType>,
 }
 
 /// <p>The user ID and user fields to update, used with the <a>BatchUpdateUser</a> action.</p>
@@ -2522,14 +4354,14 @@ pub struct UpdateUserRequestItem {
     /// <p>The user license type.</p>
     #[serde(rename = "LicenseType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub license_type: Option<String>,
+    pub license_type: Option<License>,
     /// <p>The user ID.</p>
     #[serde(rename = "UserId")]
     pub user_id: String,
     /// <p>The user type.</p>
     #[serde(rename = "UserType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub user_type: Option<String>,
+    pub user_type: Option<UserType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -2619,12 +4451,17 @@ pub struct User {
     pub display_name: Option<String>,
     /// <p>Date and time when the user is invited to the Amazon Chime account, in ISO 8601 format.</p>
     #[serde(rename = "InvitedOn")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub invited_on: Option<f64>,
+    pub invited_on: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The license type for the user.</p>
     #[serde(rename = "LicenseType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub license_type: Option<String>,
+    pub license_type: Option<License>,
     /// <p>The user's personal meeting PIN.</p>
     #[serde(rename = "PersonalPIN")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2639,23 +4476,28 @@ pub struct User {
     pub primary_provisioned_number: Option<String>,
     /// <p>Date and time when the user is registered, in ISO 8601 format.</p>
     #[serde(rename = "RegisteredOn")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub registered_on: Option<f64>,
+    pub registered_on: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The user ID.</p>
     #[serde(rename = "UserId")]
     pub user_id: String,
     /// <p>The user invite status.</p>
     #[serde(rename = "UserInvitationStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub user_invitation_status: Option<String>,
+    pub user_invitation_status: Option<InviteStatus>,
     /// <p>The user registration status.</p>
     #[serde(rename = "UserRegistrationStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub user_registration_status: Option<String>,
+    pub user_registration_status: Option<RegistrationStatus>,
     /// <p>The user type.</p>
     #[serde(rename = "UserType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub user_type: Option<String>,
+    pub user_type: Option<UserType>,
 }
 
 /// <p>The list of errors returned when errors are encountered during the <a>BatchSuspendUser</a>, <a>BatchUnsuspendUser</a>, or <a>BatchUpdateUser</a> actions. This includes user IDs, error codes, and error messages.</p>
@@ -2665,7 +4507,7 @@ pub struct UserError {
     /// <p>The error code.</p>
     #[serde(rename = "ErrorCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub error_code: Option<String>,
+    pub error_code: Option<ErrorCode>,
     /// <p>The error message.</p>
     #[serde(rename = "ErrorMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2684,6 +4526,99 @@ pub struct UserSettings {
     pub telephony: TelephonySettings,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum UserType {
+    /// `PrivateUser`
+    PrivateUser,
+    /// `SharedDevice`
+    SharedDevice,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl UserType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            UserType::PrivateUser => "PrivateUser",
+            UserType::SharedDevice => "SharedDevice",
+            UserType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for UserType {
+    fn default() -> UserType {
+        UserType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for UserType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for UserType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for UserType {
+    fn from(value: &'a str) -> UserType {
+        match value {
+            "PrivateUser" => UserType::PrivateUser,
+            "SharedDevice" => UserType::SharedDevice,
+            _ => UserType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for UserType {
+    fn from(value: String) -> UserType {
+        match value.as_str() {
+            "PrivateUser" => UserType::PrivateUser,
+            "SharedDevice" => UserType::SharedDevice,
+            _ => UserType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for UserType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<UserType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for UserType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for UserType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for UserType {
+    fn deserialize<D>(deserializer: D) -> Result<UserType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The Amazon Chime Voice Connector configuration, including outbound host name and encryption settings.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -2691,11 +4626,16 @@ pub struct VoiceConnector {
     /// <p>The AWS Region in which the Amazon Chime Voice Connector is created. Default: <code>us-east-1</code>.</p>
     #[serde(rename = "AwsRegion")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub aws_region: Option<String>,
+    pub aws_region: Option<VoiceConnectorAwsRegion>,
     /// <p>The Amazon Chime Voice Connector creation timestamp, in ISO 8601 format.</p>
     #[serde(rename = "CreatedTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_timestamp: Option<f64>,
+    pub created_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the Amazon Chime Voice Connector.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2710,30 +4650,138 @@ pub struct VoiceConnector {
     pub require_encryption: Option<bool>,
     /// <p>The updated Amazon Chime Voice Connector timestamp, in ISO 8601 format.</p>
     #[serde(rename = "UpdatedTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_timestamp: Option<f64>,
+    pub updated_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The Amazon Chime Voice Connector ID.</p>
     #[serde(rename = "VoiceConnectorId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub voice_connector_id: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum VoiceConnectorAwsRegion {
+    /// `us-east-1`
+    UsEast1,
+    /// `us-west-2`
+    UsWest2,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl VoiceConnectorAwsRegion {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            VoiceConnectorAwsRegion::UsEast1 => "us-east-1",
+            VoiceConnectorAwsRegion::UsWest2 => "us-west-2",
+            VoiceConnectorAwsRegion::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for VoiceConnectorAwsRegion {
+    fn default() -> VoiceConnectorAwsRegion {
+        VoiceConnectorAwsRegion::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for VoiceConnectorAwsRegion {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for VoiceConnectorAwsRegion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for VoiceConnectorAwsRegion {
+    fn from(value: &'a str) -> VoiceConnectorAwsRegion {
+        match value {
+            "us-east-1" => VoiceConnectorAwsRegion::UsEast1,
+            "us-west-2" => VoiceConnectorAwsRegion::UsWest2,
+            _ => VoiceConnectorAwsRegion::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for VoiceConnectorAwsRegion {
+    fn from(value: String) -> VoiceConnectorAwsRegion {
+        match value.as_str() {
+            "us-east-1" => VoiceConnectorAwsRegion::UsEast1,
+            "us-west-2" => VoiceConnectorAwsRegion::UsWest2,
+            _ => VoiceConnectorAwsRegion::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for VoiceConnectorAwsRegion {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<VoiceConnectorAwsRegion, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for VoiceConnectorAwsRegion {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for VoiceConnectorAwsRegion {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for VoiceConnectorAwsRegion {
+    fn deserialize<D>(deserializer: D) -> Result<VoiceConnectorAwsRegion, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The Amazon Chime Voice Connector group configuration, including associated Amazon Chime Voice Connectors. You can include Amazon Chime Voice Connectors from different AWS Regions in your group. This creates a fault tolerant mechanism for fallback in case of availability events.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct VoiceConnectorGroup {
     /// <p>The Amazon Chime Voice Connector group creation timestamp, in ISO 8601 format.</p>
     #[serde(rename = "CreatedTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_timestamp: Option<f64>,
+    pub created_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the Amazon Chime Voice Connector group.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
     /// <p>The updated Amazon Chime Voice Connector group timestamp, in ISO 8601 format.</p>
     #[serde(rename = "UpdatedTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_timestamp: Option<f64>,
+    pub updated_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The Amazon Chime Voice Connector group ID.</p>
     #[serde(rename = "VoiceConnectorGroupId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -10576,11 +12624,12 @@ pub struct ChimeClient {
 impl ChimeClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> ChimeClient {
         ChimeClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Chime"),
         }
     }
 
@@ -10619,6 +12668,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("AssociatePhoneNumberWithUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10659,6 +12709,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("AssociatePhoneNumbersWithVoiceConnector");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10701,6 +12752,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("AssociatePhoneNumbersWithVoiceConnectorGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10739,6 +12791,7 @@ impl Chime for ChimeClient {
         let request_uri = format!("/accounts/{account_id}", account_id = input.account_id);
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("AssociateSigninDelegateGroupsWithAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10776,6 +12829,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("BatchCreateAttendee");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10815,6 +12869,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("BatchCreateRoomMembership");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10849,6 +12904,7 @@ impl Chime for ChimeClient {
         let request_uri = "/phone-numbers";
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("BatchDeletePhoneNumber");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10886,6 +12942,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("BatchSuspendUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10923,6 +12980,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("BatchUnsuspendUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10957,6 +13015,7 @@ impl Chime for ChimeClient {
         let request_uri = "/phone-numbers";
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("BatchUpdatePhoneNumber");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10994,6 +13053,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("BatchUpdateUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11024,6 +13084,7 @@ impl Chime for ChimeClient {
         let request_uri = "/accounts";
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("CreateAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11057,6 +13118,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("CreateAttendee");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11087,6 +13149,7 @@ impl Chime for ChimeClient {
         let request_uri = format!("/accounts/{account_id}/bots", account_id = input.account_id);
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("CreateBot");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11117,6 +13180,7 @@ impl Chime for ChimeClient {
         let request_uri = "/meetings";
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("CreateMeeting");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11147,6 +13211,7 @@ impl Chime for ChimeClient {
         let request_uri = "/phone-number-orders";
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("CreatePhoneNumberOrder");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11180,6 +13245,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("CreateRoom");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11214,6 +13280,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("CreateRoomMembership");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11247,6 +13314,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("CreateUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11281,6 +13349,7 @@ impl Chime for ChimeClient {
         let request_uri = "/voice-connectors";
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("CreateVoiceConnector");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11312,6 +13381,7 @@ impl Chime for ChimeClient {
         let request_uri = "/voice-connector-groups";
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("CreateVoiceConnectorGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11342,6 +13412,7 @@ impl Chime for ChimeClient {
         let request_uri = format!("/accounts/{account_id}", account_id = input.account_id);
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11373,6 +13444,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteAttendee");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11403,6 +13475,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteEventsConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11429,6 +13502,7 @@ impl Chime for ChimeClient {
         let request_uri = format!("/meetings/{meeting_id}", meeting_id = input.meeting_id);
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteMeeting");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11458,6 +13532,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeletePhoneNumber");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11488,6 +13563,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteRoom");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11519,6 +13595,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteRoomMembership");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11548,6 +13625,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteVoiceConnector");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11577,6 +13655,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteVoiceConnectorGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11606,6 +13685,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteVoiceConnectorOrigination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11637,6 +13717,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteVoiceConnectorStreamingConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11666,6 +13747,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteVoiceConnectorTermination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11697,6 +13779,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteVoiceConnectorTerminationCredentials");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11737,6 +13820,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("DisassociatePhoneNumberFromUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -11776,6 +13860,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("DisassociatePhoneNumbersFromVoiceConnector");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11816,6 +13901,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("DisassociatePhoneNumbersFromVoiceConnectorGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11854,6 +13940,7 @@ impl Chime for ChimeClient {
         let request_uri = format!("/accounts/{account_id}", account_id = input.account_id);
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("DisassociateSigninDelegateGroupsFromAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11888,6 +13975,7 @@ impl Chime for ChimeClient {
         let request_uri = format!("/accounts/{account_id}", account_id = input.account_id);
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11918,6 +14006,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetAccountSettings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11949,6 +14038,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetAttendee");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11980,6 +14070,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetBot");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12011,6 +14102,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetEventsConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12037,6 +14129,7 @@ impl Chime for ChimeClient {
         let request_uri = "/settings";
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetGlobalSettings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12064,6 +14157,7 @@ impl Chime for ChimeClient {
         let request_uri = format!("/meetings/{meeting_id}", meeting_id = input.meeting_id);
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetMeeting");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12094,6 +14188,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetPhoneNumber");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12124,6 +14219,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetPhoneNumberOrder");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12150,6 +14246,7 @@ impl Chime for ChimeClient {
         let request_uri = "/settings/phone-number";
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetPhoneNumberSettings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12181,6 +14278,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetRoom");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12212,6 +14310,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12243,6 +14342,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetUserSettings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12273,6 +14373,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetVoiceConnector");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12303,6 +14404,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetVoiceConnectorGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12336,6 +14438,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetVoiceConnectorLoggingConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12369,6 +14472,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetVoiceConnectorOrigination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12402,6 +14506,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetVoiceConnectorStreamingConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12435,6 +14540,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetVoiceConnectorTermination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12468,6 +14574,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetVoiceConnectorTerminationHealth");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12500,6 +14607,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("InviteUsers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -12534,6 +14642,7 @@ impl Chime for ChimeClient {
         let request_uri = "/accounts";
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListAccounts");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12579,6 +14688,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListAttendees");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12615,6 +14725,7 @@ impl Chime for ChimeClient {
         let request_uri = format!("/accounts/{account_id}/bots", account_id = input.account_id);
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListBots");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12651,6 +14762,7 @@ impl Chime for ChimeClient {
         let request_uri = "/meetings";
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListMeetings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12687,6 +14799,7 @@ impl Chime for ChimeClient {
         let request_uri = "/phone-number-orders";
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListPhoneNumberOrders");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12723,6 +14836,7 @@ impl Chime for ChimeClient {
         let request_uri = "/phone-numbers";
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListPhoneNumbers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12775,6 +14889,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListRoomMemberships");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12814,6 +14929,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListRooms");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12856,6 +14972,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListUsers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12898,6 +15015,7 @@ impl Chime for ChimeClient {
         let request_uri = "/voice-connector-groups";
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListVoiceConnectorGroups");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12940,6 +15058,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListVoiceConnectorTerminationCredentials");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12967,6 +15086,7 @@ impl Chime for ChimeClient {
         let request_uri = "/voice-connectors";
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListVoiceConnectors");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -13007,6 +15127,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("LogoutUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -13042,6 +15163,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("PutEventsConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13078,6 +15200,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("PutVoiceConnectorLoggingConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13114,6 +15237,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("PutVoiceConnectorOrigination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13150,6 +15274,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("PutVoiceConnectorStreamingConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13186,6 +15311,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("PutVoiceConnectorTermination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13219,6 +15345,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("PutVoiceConnectorTerminationCredentials");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13258,6 +15385,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("RegenerateSecurityToken");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -13293,6 +15421,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("ResetPersonalPIN");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -13327,6 +15456,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("RestorePhoneNumber");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -13359,6 +15489,7 @@ impl Chime for ChimeClient {
         let request_uri = "/search";
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("SearchAvailablePhoneNumbers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -13411,6 +15542,7 @@ impl Chime for ChimeClient {
         let request_uri = format!("/accounts/{account_id}", account_id = input.account_id);
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("UpdateAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13444,6 +15576,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("UpdateAccountSettings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13478,6 +15611,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("UpdateBot");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13508,6 +15642,7 @@ impl Chime for ChimeClient {
         let request_uri = "/settings";
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("UpdateGlobalSettings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13540,6 +15675,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("UpdatePhoneNumber");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13570,6 +15706,7 @@ impl Chime for ChimeClient {
         let request_uri = "/settings/phone-number";
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("UpdatePhoneNumberSettings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13603,6 +15740,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("UpdateRoom");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13638,6 +15776,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("UpdateRoomMembership");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13672,6 +15811,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("UpdateUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13706,6 +15846,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("UpdateUserSettings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13738,6 +15879,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("UpdateVoiceConnector");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13772,6 +15914,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("UpdateVoiceConnectorGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13794,3 +15937,64 @@ impl Chime for ChimeClient {
         }
     }
 }
+impl ChimeClient {
+    /// Auto-paginating version of `list_accounts`, streaming every page of results.
+    pub fn list_accounts_pages(
+        &self,
+        input: ListAccountsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListAccountsResponse, ListAccountsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ChimeClient, input: ListAccountsRequest| async move {
+                client.list_accounts(input).await
+            },
+            |input: &ListAccountsRequest, output: &ListAccountsResponse| {
+                Some(ListAccountsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_accounts`, streaming the `Accounts` of every page of results.
+    pub fn list_accounts_items(
+        &self,
+        input: ListAccountsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Account, ListAccountsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_accounts_pages(input),
+            |output: ListAccountsResponse| output.accounts.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_users`, streaming every page of results.
+    pub fn list_users_pages(
+        &self,
+        input: ListUsersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListUsersResponse, ListUsersError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ChimeClient, input: ListUsersRequest| async move { client.list_users(input).await },
+            |input: &ListUsersRequest, output: &ListUsersResponse| {
+                Some(ListUsersRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_users`, streaming the `Users` of every page of results.
+    pub fn list_users_items(
+        &self,
+        input: ListUsersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<User, ListUsersError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_users_pages(input),
+            |output: ListUsersResponse| output.users.unwrap_or_default(),
+        )
+    }
+}
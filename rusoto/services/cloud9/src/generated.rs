@@ -72,7 +72,7 @@ pub struct CreateEnvironmentMembershipRequest {
     pub environment_id: String,
     /// <p><p>The type of environment member permissions you want to associate with this environment member. Available values include:</p> <ul> <li> <p> <code>read-only</code>: Has read-only access to the environment.</p> </li> <li> <p> <code>read-write</code>: Has read-write access to the environment.</p> </li> </ul></p>
     #[serde(rename = "permissions")]
-    pub permissions: String,
+    pub permissions: MemberPermissions,
     /// <p>The Amazon Resource Name (ARN) of the environment member you want to add.</p>
     #[serde(rename = "userArn")]
     pub user_arn: String,
@@ -132,7 +132,7 @@ pub struct DescribeEnvironmentMembershipsRequest {
     /// <p>The type of environment member permissions to get information about. Available values include:</p> <ul> <li> <p> <code>owner</code>: Owns the environment.</p> </li> <li> <p> <code>read-only</code>: Has read-only access to the environment.</p> </li> <li> <p> <code>read-write</code>: Has read-write access to the environment.</p> </li> </ul> <p>If no value is specified, information about all environment members are returned.</p>
     #[serde(rename = "permissions")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub permissions: Option<Vec<String>>,
+    pub permissions: Option<Vec<Permissions>>,
     /// <p>The Amazon Resource Name (ARN) of an individual environment member to get information about. If no value is specified, information about all environment members are returned.</p>
     #[serde(rename = "userArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -170,7 +170,7 @@ pub struct DescribeEnvironmentStatusResult {
     /// <p><p>The status of the environment. Available values include:</p> <ul> <li> <p> <code>connecting</code>: The environment is connecting.</p> </li> <li> <p> <code>creating</code>: The environment is being created.</p> </li> <li> <p> <code>deleting</code>: The environment is being deleted.</p> </li> <li> <p> <code>error</code>: The environment is in an error state.</p> </li> <li> <p> <code>ready</code>: The environment is ready.</p> </li> <li> <p> <code>stopped</code>: The environment is stopped.</p> </li> <li> <p> <code>stopping</code>: The environment is stopping.</p> </li> </ul></p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<EnvironmentStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -221,7 +221,7 @@ pub struct Environment {
     /// <p><p>The type of environment. Valid values include the following:</p> <ul> <li> <p> <code>ec2</code>: An Amazon Elastic Compute Cloud (Amazon EC2) instance connects to the environment.</p> </li> <li> <p> <code>ssh</code>: Your own server connects to the environment.</p> </li> </ul></p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<EnvironmentType>,
 }
 
 /// <p>Information about the current creation or deletion lifecycle state of an AWS Cloud9 development environment.</p>
@@ -239,9 +239,117 @@ pub struct EnvironmentLifecycle {
     /// <p><p>The current creation or deletion lifecycle state of the environment.</p> <ul> <li> <p> <code>CREATING</code>: The environment is in the process of being created.</p> </li> <li> <p> <code>CREATED</code>: The environment was successfully created.</p> </li> <li> <p> <code>CREATE<em>FAILED</code>: The environment failed to be created.</p> </li> <li> <p> <code>DELETING</code>: The environment is in the process of being deleted.</p> </li> <li> <p> <code>DELETE</em>FAILED</code>: The environment failed to delete.</p> </li> </ul></p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<EnvironmentLifecycleStatus>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EnvironmentLifecycleStatus {
+    /// `CREATING`
+    Creating,
+    /// `CREATED`
+    Created,
+    /// `CREATE_FAILED`
+    CreateFailed,
+    /// `DELETING`
+    Deleting,
+    /// `DELETE_FAILED`
+    DeleteFailed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EnvironmentLifecycleStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EnvironmentLifecycleStatus::Creating => "CREATING",
+            EnvironmentLifecycleStatus::Created => "CREATED",
+            EnvironmentLifecycleStatus::CreateFailed => "CREATE_FAILED",
+            EnvironmentLifecycleStatus::Deleting => "DELETING",
+            EnvironmentLifecycleStatus::DeleteFailed => "DELETE_FAILED",
+            EnvironmentLifecycleStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EnvironmentLifecycleStatus {
+    fn default() -> EnvironmentLifecycleStatus {
+        EnvironmentLifecycleStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EnvironmentLifecycleStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EnvironmentLifecycleStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EnvironmentLifecycleStatus {
+    fn from(value: &'a str) -> EnvironmentLifecycleStatus {
+        match value {
+            "CREATING" => EnvironmentLifecycleStatus::Creating,
+            "CREATED" => EnvironmentLifecycleStatus::Created,
+            "CREATE_FAILED" => EnvironmentLifecycleStatus::CreateFailed,
+            "DELETING" => EnvironmentLifecycleStatus::Deleting,
+            "DELETE_FAILED" => EnvironmentLifecycleStatus::DeleteFailed,
+            _ => EnvironmentLifecycleStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EnvironmentLifecycleStatus {
+    fn from(value: String) -> EnvironmentLifecycleStatus {
+        match value.as_str() {
+            "CREATING" => EnvironmentLifecycleStatus::Creating,
+            "CREATED" => EnvironmentLifecycleStatus::Created,
+            "CREATE_FAILED" => EnvironmentLifecycleStatus::CreateFailed,
+            "DELETING" => EnvironmentLifecycleStatus::Deleting,
+            "DELETE_FAILED" => EnvironmentLifecycleStatus::DeleteFailed,
+            _ => EnvironmentLifecycleStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EnvironmentLifecycleStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EnvironmentLifecycleStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EnvironmentLifecycleStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EnvironmentLifecycleStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EnvironmentLifecycleStatus {
+    fn deserialize<D>(deserializer: D) -> Result<EnvironmentLifecycleStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about an environment member for an AWS Cloud9 development environment.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -252,12 +360,17 @@ pub struct EnvironmentMember {
     pub environment_id: Option<String>,
     /// <p>The time, expressed in epoch time format, when the environment member last opened the environment.</p>
     #[serde(rename = "lastAccess")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_access: Option<f64>,
+    pub last_access: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>The type of environment member permissions associated with this environment member. Available values include:</p> <ul> <li> <p> <code>owner</code>: Owns the environment.</p> </li> <li> <p> <code>read-only</code>: Has read-only access to the environment.</p> </li> <li> <p> <code>read-write</code>: Has read-write access to the environment.</p> </li> </ul></p>
     #[serde(rename = "permissions")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub permissions: Option<String>,
+    pub permissions: Option<Permissions>,
     /// <p>The Amazon Resource Name (ARN) of the environment member.</p>
     #[serde(rename = "userArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -268,6 +381,217 @@ pub struct EnvironmentMember {
     pub user_id: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EnvironmentStatus {
+    /// `error`
+    Error,
+    /// `creating`
+    Creating,
+    /// `connecting`
+    Connecting,
+    /// `ready`
+    Ready,
+    /// `stopping`
+    Stopping,
+    /// `stopped`
+    Stopped,
+    /// `deleting`
+    Deleting,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EnvironmentStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EnvironmentStatus::Error => "error",
+            EnvironmentStatus::Creating => "creating",
+            EnvironmentStatus::Connecting => "connecting",
+            EnvironmentStatus::Ready => "ready",
+            EnvironmentStatus::Stopping => "stopping",
+            EnvironmentStatus::Stopped => "stopped",
+            EnvironmentStatus::Deleting => "deleting",
+            EnvironmentStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EnvironmentStatus {
+    fn default() -> EnvironmentStatus {
+        EnvironmentStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EnvironmentStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EnvironmentStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EnvironmentStatus {
+    fn from(value: &'a str) -> EnvironmentStatus {
+        match value {
+            "error" => EnvironmentStatus::Error,
+            "creating" => EnvironmentStatus::Creating,
+            "connecting" => EnvironmentStatus::Connecting,
+            "ready" => EnvironmentStatus::Ready,
+            "stopping" => EnvironmentStatus::Stopping,
+            "stopped" => EnvironmentStatus::Stopped,
+            "deleting" => EnvironmentStatus::Deleting,
+            _ => EnvironmentStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EnvironmentStatus {
+    fn from(value: String) -> EnvironmentStatus {
+        match value.as_str() {
+            "error" => EnvironmentStatus::Error,
+            "creating" => EnvironmentStatus::Creating,
+            "connecting" => EnvironmentStatus::Connecting,
+            "ready" => EnvironmentStatus::Ready,
+            "stopping" => EnvironmentStatus::Stopping,
+            "stopped" => EnvironmentStatus::Stopped,
+            "deleting" => EnvironmentStatus::Deleting,
+            _ => EnvironmentStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EnvironmentStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EnvironmentStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EnvironmentStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EnvironmentStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EnvironmentStatus {
+    fn deserialize<D>(deserializer: D) -> Result<EnvironmentStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EnvironmentType {
+    /// `ssh`
+    Ssh,
+    /// `ec2`
+    Ec2,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EnvironmentType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EnvironmentType::Ssh => "ssh",
+            EnvironmentType::Ec2 => "ec2",
+            EnvironmentType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EnvironmentType {
+    fn default() -> EnvironmentType {
+        EnvironmentType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EnvironmentType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EnvironmentType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EnvironmentType {
+    fn from(value: &'a str) -> EnvironmentType {
+        match value {
+            "ssh" => EnvironmentType::Ssh,
+            "ec2" => EnvironmentType::Ec2,
+            _ => EnvironmentType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EnvironmentType {
+    fn from(value: String) -> EnvironmentType {
+        match value.as_str() {
+            "ssh" => EnvironmentType::Ssh,
+            "ec2" => EnvironmentType::Ec2,
+            _ => EnvironmentType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EnvironmentType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EnvironmentType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EnvironmentType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EnvironmentType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EnvironmentType {
+    fn deserialize<D>(deserializer: D) -> Result<EnvironmentType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ListEnvironmentsRequest {
@@ -294,6 +618,197 @@ pub struct ListEnvironmentsResult {
     pub next_token: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MemberPermissions {
+    /// `read-write`
+    ReadWrite,
+    /// `read-only`
+    ReadOnly,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl MemberPermissions {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MemberPermissions::ReadWrite => "read-write",
+            MemberPermissions::ReadOnly => "read-only",
+            MemberPermissions::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for MemberPermissions {
+    fn default() -> MemberPermissions {
+        MemberPermissions::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for MemberPermissions {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for MemberPermissions {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for MemberPermissions {
+    fn from(value: &'a str) -> MemberPermissions {
+        match value {
+            "read-write" => MemberPermissions::ReadWrite,
+            "read-only" => MemberPermissions::ReadOnly,
+            _ => MemberPermissions::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MemberPermissions {
+    fn from(value: String) -> MemberPermissions {
+        match value.as_str() {
+            "read-write" => MemberPermissions::ReadWrite,
+            "read-only" => MemberPermissions::ReadOnly,
+            _ => MemberPermissions::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for MemberPermissions {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<MemberPermissions, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MemberPermissions {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for MemberPermissions {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MemberPermissions {
+    fn deserialize<D>(deserializer: D) -> Result<MemberPermissions, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Permissions {
+    /// `owner`
+    Owner,
+    /// `read-write`
+    ReadWrite,
+    /// `read-only`
+    ReadOnly,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Permissions {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Permissions::Owner => "owner",
+            Permissions::ReadWrite => "read-write",
+            Permissions::ReadOnly => "read-only",
+            Permissions::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Permissions {
+    fn default() -> Permissions {
+        Permissions::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Permissions {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Permissions {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Permissions {
+    fn from(value: &'a str) -> Permissions {
+        match value {
+            "owner" => Permissions::Owner,
+            "read-write" => Permissions::ReadWrite,
+            "read-only" => Permissions::ReadOnly,
+            _ => Permissions::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Permissions {
+    fn from(value: String) -> Permissions {
+        match value.as_str() {
+            "owner" => Permissions::Owner,
+            "read-write" => Permissions::ReadWrite,
+            "read-only" => Permissions::ReadOnly,
+            _ => Permissions::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Permissions {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Permissions, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Permissions {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Permissions {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Permissions {
+    fn deserialize<D>(deserializer: D) -> Result<Permissions, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct UpdateEnvironmentMembershipRequest {
@@ -302,7 +817,7 @@ pub struct UpdateEnvironmentMembershipRequest {
     pub environment_id: String,
     /// <p><p>The replacement type of environment member permissions you want to associate with this environment member. Available values include:</p> <ul> <li> <p> <code>read-only</code>: Has read-only access to the environment.</p> </li> <li> <p> <code>read-write</code>: Has read-write access to the environment.</p> </li> </ul></p>
     #[serde(rename = "permissions")]
-    pub permissions: String,
+    pub permissions: MemberPermissions,
     /// <p>The Amazon Resource Name (ARN) of the environment member whose settings you want to change.</p>
     #[serde(rename = "userArn")]
     pub user_arn: String,
@@ -1171,11 +1686,12 @@ pub struct Cloud9Client {
 impl Cloud9Client {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> Cloud9Client {
         Cloud9Client {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Cloud9"),
         }
     }
 
@@ -1207,6 +1723,7 @@ impl Cloud9 for Cloud9Client {
         input: CreateEnvironmentEC2Request,
     ) -> Result<CreateEnvironmentEC2Result, RusotoError<CreateEnvironmentEC2Error>> {
         let mut request = SignedRequest::new("POST", "cloud9", &self.region, "/");
+        request.set_operation("CreateEnvironmentEC2");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1239,6 +1756,7 @@ impl Cloud9 for Cloud9Client {
     ) -> Result<CreateEnvironmentMembershipResult, RusotoError<CreateEnvironmentMembershipError>>
     {
         let mut request = SignedRequest::new("POST", "cloud9", &self.region, "/");
+        request.set_operation("CreateEnvironmentMembership");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1270,6 +1788,7 @@ impl Cloud9 for Cloud9Client {
         input: DeleteEnvironmentRequest,
     ) -> Result<DeleteEnvironmentResult, RusotoError<DeleteEnvironmentError>> {
         let mut request = SignedRequest::new("POST", "cloud9", &self.region, "/");
+        request.set_operation("DeleteEnvironment");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1301,6 +1820,7 @@ impl Cloud9 for Cloud9Client {
     ) -> Result<DeleteEnvironmentMembershipResult, RusotoError<DeleteEnvironmentMembershipError>>
     {
         let mut request = SignedRequest::new("POST", "cloud9", &self.region, "/");
+        request.set_operation("DeleteEnvironmentMembership");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1335,6 +1855,7 @@ impl Cloud9 for Cloud9Client {
         RusotoError<DescribeEnvironmentMembershipsError>,
     > {
         let mut request = SignedRequest::new("POST", "cloud9", &self.region, "/");
+        request.set_operation("DescribeEnvironmentMemberships");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1366,6 +1887,7 @@ impl Cloud9 for Cloud9Client {
         input: DescribeEnvironmentStatusRequest,
     ) -> Result<DescribeEnvironmentStatusResult, RusotoError<DescribeEnvironmentStatusError>> {
         let mut request = SignedRequest::new("POST", "cloud9", &self.region, "/");
+        request.set_operation("DescribeEnvironmentStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1397,6 +1919,7 @@ impl Cloud9 for Cloud9Client {
         input: DescribeEnvironmentsRequest,
     ) -> Result<DescribeEnvironmentsResult, RusotoError<DescribeEnvironmentsError>> {
         let mut request = SignedRequest::new("POST", "cloud9", &self.region, "/");
+        request.set_operation("DescribeEnvironments");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1428,6 +1951,7 @@ impl Cloud9 for Cloud9Client {
         input: ListEnvironmentsRequest,
     ) -> Result<ListEnvironmentsResult, RusotoError<ListEnvironmentsError>> {
         let mut request = SignedRequest::new("POST", "cloud9", &self.region, "/");
+        request.set_operation("ListEnvironments");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1458,6 +1982,7 @@ impl Cloud9 for Cloud9Client {
         input: UpdateEnvironmentRequest,
     ) -> Result<UpdateEnvironmentResult, RusotoError<UpdateEnvironmentError>> {
         let mut request = SignedRequest::new("POST", "cloud9", &self.region, "/");
+        request.set_operation("UpdateEnvironment");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1489,6 +2014,7 @@ impl Cloud9 for Cloud9Client {
     ) -> Result<UpdateEnvironmentMembershipResult, RusotoError<UpdateEnvironmentMembershipError>>
     {
         let mut request = SignedRequest::new("POST", "cloud9", &self.region, "/");
+        request.set_operation("UpdateEnvironmentMembership");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1514,3 +2040,71 @@ impl Cloud9 for Cloud9Client {
         }
     }
 }
+impl Cloud9Client {
+    /// Auto-paginating version of `describe_environment_memberships`, streaming every page of results.
+    pub fn describe_environment_memberships_pages(
+        &self,
+        input: DescribeEnvironmentMembershipsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeEnvironmentMembershipsResult,
+        DescribeEnvironmentMembershipsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: Cloud9Client, input: DescribeEnvironmentMembershipsRequest| async move {
+                client.describe_environment_memberships(input).await
+            },
+            |input: &DescribeEnvironmentMembershipsRequest,
+             output: &DescribeEnvironmentMembershipsResult| {
+                Some(DescribeEnvironmentMembershipsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_environment_memberships`, streaming the `memberships` of every page of results.
+    pub fn describe_environment_memberships_items(
+        &self,
+        input: DescribeEnvironmentMembershipsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<EnvironmentMember, DescribeEnvironmentMembershipsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_environment_memberships_pages(input),
+            |output: DescribeEnvironmentMembershipsResult| output.memberships.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_environments`, streaming every page of results.
+    pub fn list_environments_pages(
+        &self,
+        input: ListEnvironmentsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListEnvironmentsResult, ListEnvironmentsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: Cloud9Client, input: ListEnvironmentsRequest| async move {
+                client.list_environments(input).await
+            },
+            |input: &ListEnvironmentsRequest, output: &ListEnvironmentsResult| {
+                Some(ListEnvironmentsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_environments`, streaming the `environmentIds` of every page of results.
+    pub fn list_environments_items(
+        &self,
+        input: ListEnvironmentsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListEnvironmentsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_environments_pages(input),
+            |output: ListEnvironmentsResult| output.environment_ids.unwrap_or_default(),
+        )
+    }
+}
@@ -914,9 +914,163 @@ pub struct BatchReadException {
     /// <p>A type of exception, such as <code>InvalidArnException</code>.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<BatchReadExceptionType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BatchReadExceptionType {
+    /// `ValidationException`
+    ValidationException,
+    /// `InvalidArnException`
+    InvalidArnException,
+    /// `ResourceNotFoundException`
+    ResourceNotFoundException,
+    /// `InvalidNextTokenException`
+    InvalidNextTokenException,
+    /// `AccessDeniedException`
+    AccessDeniedException,
+    /// `NotNodeException`
+    NotNodeException,
+    /// `FacetValidationException`
+    FacetValidationException,
+    /// `CannotListParentOfRootException`
+    CannotListParentOfRootException,
+    /// `NotIndexException`
+    NotIndexException,
+    /// `NotPolicyException`
+    NotPolicyException,
+    /// `DirectoryNotEnabledException`
+    DirectoryNotEnabledException,
+    /// `LimitExceededException`
+    LimitExceededException,
+    /// `InternalServiceException`
+    InternalServiceException,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl BatchReadExceptionType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BatchReadExceptionType::ValidationException => "ValidationException",
+            BatchReadExceptionType::InvalidArnException => "InvalidArnException",
+            BatchReadExceptionType::ResourceNotFoundException => "ResourceNotFoundException",
+            BatchReadExceptionType::InvalidNextTokenException => "InvalidNextTokenException",
+            BatchReadExceptionType::AccessDeniedException => "AccessDeniedException",
+            BatchReadExceptionType::NotNodeException => "NotNodeException",
+            BatchReadExceptionType::FacetValidationException => "FacetValidationException",
+            BatchReadExceptionType::CannotListParentOfRootException => {
+                "CannotListParentOfRootException"
+            }
+            BatchReadExceptionType::NotIndexException => "NotIndexException",
+            BatchReadExceptionType::NotPolicyException => "NotPolicyException",
+            BatchReadExceptionType::DirectoryNotEnabledException => "DirectoryNotEnabledException",
+            BatchReadExceptionType::LimitExceededException => "LimitExceededException",
+            BatchReadExceptionType::InternalServiceException => "InternalServiceException",
+            BatchReadExceptionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for BatchReadExceptionType {
+    fn default() -> BatchReadExceptionType {
+        BatchReadExceptionType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for BatchReadExceptionType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for BatchReadExceptionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for BatchReadExceptionType {
+    fn from(value: &'a str) -> BatchReadExceptionType {
+        match value {
+            "ValidationException" => BatchReadExceptionType::ValidationException,
+            "InvalidArnException" => BatchReadExceptionType::InvalidArnException,
+            "ResourceNotFoundException" => BatchReadExceptionType::ResourceNotFoundException,
+            "InvalidNextTokenException" => BatchReadExceptionType::InvalidNextTokenException,
+            "AccessDeniedException" => BatchReadExceptionType::AccessDeniedException,
+            "NotNodeException" => BatchReadExceptionType::NotNodeException,
+            "FacetValidationException" => BatchReadExceptionType::FacetValidationException,
+            "CannotListParentOfRootException" => {
+                BatchReadExceptionType::CannotListParentOfRootException
+            }
+            "NotIndexException" => BatchReadExceptionType::NotIndexException,
+            "NotPolicyException" => BatchReadExceptionType::NotPolicyException,
+            "DirectoryNotEnabledException" => BatchReadExceptionType::DirectoryNotEnabledException,
+            "LimitExceededException" => BatchReadExceptionType::LimitExceededException,
+            "InternalServiceException" => BatchReadExceptionType::InternalServiceException,
+            _ => BatchReadExceptionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BatchReadExceptionType {
+    fn from(value: String) -> BatchReadExceptionType {
+        match value.as_str() {
+            "ValidationException" => BatchReadExceptionType::ValidationException,
+            "InvalidArnException" => BatchReadExceptionType::InvalidArnException,
+            "ResourceNotFoundException" => BatchReadExceptionType::ResourceNotFoundException,
+            "InvalidNextTokenException" => BatchReadExceptionType::InvalidNextTokenException,
+            "AccessDeniedException" => BatchReadExceptionType::AccessDeniedException,
+            "NotNodeException" => BatchReadExceptionType::NotNodeException,
+            "FacetValidationException" => BatchReadExceptionType::FacetValidationException,
+            "CannotListParentOfRootException" => {
+                BatchReadExceptionType::CannotListParentOfRootException
+            }
+            "NotIndexException" => BatchReadExceptionType::NotIndexException,
+            "NotPolicyException" => BatchReadExceptionType::NotPolicyException,
+            "DirectoryNotEnabledException" => BatchReadExceptionType::DirectoryNotEnabledException,
+            "LimitExceededException" => BatchReadExceptionType::LimitExceededException,
+            "InternalServiceException" => BatchReadExceptionType::InternalServiceException,
+            _ => BatchReadExceptionType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for BatchReadExceptionType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<BatchReadExceptionType, Self::Err> {
+        Ok(value.into())
+    }
 }
 
+impl ::rusoto_core::param::ToParam for BatchReadExceptionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for BatchReadExceptionType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BatchReadExceptionType {
+    fn deserialize<D>(deserializer: D) -> Result<BatchReadExceptionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents the output of a <code>BatchRead</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -998,7 +1152,7 @@ pub struct BatchReadRequest {
     /// <p>Represents the manner and timing in which the successful write or update of an object is reflected in a subsequent read operation of that same object.</p>
     #[serde(rename = "ConsistencyLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub consistency_level: Option<String>,
+    pub consistency_level: Option<ConsistencyLevel>,
     /// <p>The Amazon Resource Name (ARN) that is associated with the <a>Directory</a>. For more information, see <a>arns</a>.</p>
     #[serde(rename = "DirectoryArn")]
     pub directory_arn: String,
@@ -1133,6 +1287,203 @@ pub struct BatchUpdateObjectAttributesResponse {
     pub object_identifier: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BatchWriteExceptionType {
+    /// `InternalServiceException`
+    InternalServiceException,
+    /// `ValidationException`
+    ValidationException,
+    /// `InvalidArnException`
+    InvalidArnException,
+    /// `LinkNameAlreadyInUseException`
+    LinkNameAlreadyInUseException,
+    /// `StillContainsLinksException`
+    StillContainsLinksException,
+    /// `FacetValidationException`
+    FacetValidationException,
+    /// `ObjectNotDetachedException`
+    ObjectNotDetachedException,
+    /// `ResourceNotFoundException`
+    ResourceNotFoundException,
+    /// `AccessDeniedException`
+    AccessDeniedException,
+    /// `InvalidAttachmentException`
+    InvalidAttachmentException,
+    /// `NotIndexException`
+    NotIndexException,
+    /// `NotNodeException`
+    NotNodeException,
+    /// `IndexedAttributeMissingException`
+    IndexedAttributeMissingException,
+    /// `ObjectAlreadyDetachedException`
+    ObjectAlreadyDetachedException,
+    /// `NotPolicyException`
+    NotPolicyException,
+    /// `DirectoryNotEnabledException`
+    DirectoryNotEnabledException,
+    /// `LimitExceededException`
+    LimitExceededException,
+    /// `UnsupportedIndexTypeException`
+    UnsupportedIndexTypeException,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl BatchWriteExceptionType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BatchWriteExceptionType::InternalServiceException => "InternalServiceException",
+            BatchWriteExceptionType::ValidationException => "ValidationException",
+            BatchWriteExceptionType::InvalidArnException => "InvalidArnException",
+            BatchWriteExceptionType::LinkNameAlreadyInUseException => {
+                "LinkNameAlreadyInUseException"
+            }
+            BatchWriteExceptionType::StillContainsLinksException => "StillContainsLinksException",
+            BatchWriteExceptionType::FacetValidationException => "FacetValidationException",
+            BatchWriteExceptionType::ObjectNotDetachedException => "ObjectNotDetachedException",
+            BatchWriteExceptionType::ResourceNotFoundException => "ResourceNotFoundException",
+            BatchWriteExceptionType::AccessDeniedException => "AccessDeniedException",
+            BatchWriteExceptionType::InvalidAttachmentException => "InvalidAttachmentException",
+            BatchWriteExceptionType::NotIndexException => "NotIndexException",
+            BatchWriteExceptionType::NotNodeException => "NotNodeException",
+            BatchWriteExceptionType::IndexedAttributeMissingException => {
+                "IndexedAttributeMissingException"
+            }
+            BatchWriteExceptionType::ObjectAlreadyDetachedException => {
+                "ObjectAlreadyDetachedException"
+            }
+            BatchWriteExceptionType::NotPolicyException => "NotPolicyException",
+            BatchWriteExceptionType::DirectoryNotEnabledException => "DirectoryNotEnabledException",
+            BatchWriteExceptionType::LimitExceededException => "LimitExceededException",
+            BatchWriteExceptionType::UnsupportedIndexTypeException => {
+                "UnsupportedIndexTypeException"
+            }
+            BatchWriteExceptionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for BatchWriteExceptionType {
+    fn default() -> BatchWriteExceptionType {
+        BatchWriteExceptionType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for BatchWriteExceptionType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for BatchWriteExceptionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for BatchWriteExceptionType {
+    fn from(value: &'a str) -> BatchWriteExceptionType {
+        match value {
+            "InternalServiceException" => BatchWriteExceptionType::InternalServiceException,
+            "ValidationException" => BatchWriteExceptionType::ValidationException,
+            "InvalidArnException" => BatchWriteExceptionType::InvalidArnException,
+            "LinkNameAlreadyInUseException" => {
+                BatchWriteExceptionType::LinkNameAlreadyInUseException
+            }
+            "StillContainsLinksException" => BatchWriteExceptionType::StillContainsLinksException,
+            "FacetValidationException" => BatchWriteExceptionType::FacetValidationException,
+            "ObjectNotDetachedException" => BatchWriteExceptionType::ObjectNotDetachedException,
+            "ResourceNotFoundException" => BatchWriteExceptionType::ResourceNotFoundException,
+            "AccessDeniedException" => BatchWriteExceptionType::AccessDeniedException,
+            "InvalidAttachmentException" => BatchWriteExceptionType::InvalidAttachmentException,
+            "NotIndexException" => BatchWriteExceptionType::NotIndexException,
+            "NotNodeException" => BatchWriteExceptionType::NotNodeException,
+            "IndexedAttributeMissingException" => {
+                BatchWriteExceptionType::IndexedAttributeMissingException
+            }
+            "ObjectAlreadyDetachedException" => {
+                BatchWriteExceptionType::ObjectAlreadyDetachedException
+            }
+            "NotPolicyException" => BatchWriteExceptionType::NotPolicyException,
+            "DirectoryNotEnabledException" => BatchWriteExceptionType::DirectoryNotEnabledException,
+            "LimitExceededException" => BatchWriteExceptionType::LimitExceededException,
+            "UnsupportedIndexTypeException" => {
+                BatchWriteExceptionType::UnsupportedIndexTypeException
+            }
+            _ => BatchWriteExceptionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BatchWriteExceptionType {
+    fn from(value: String) -> BatchWriteExceptionType {
+        match value.as_str() {
+            "InternalServiceException" => BatchWriteExceptionType::InternalServiceException,
+            "ValidationException" => BatchWriteExceptionType::ValidationException,
+            "InvalidArnException" => BatchWriteExceptionType::InvalidArnException,
+            "LinkNameAlreadyInUseException" => {
+                BatchWriteExceptionType::LinkNameAlreadyInUseException
+            }
+            "StillContainsLinksException" => BatchWriteExceptionType::StillContainsLinksException,
+            "FacetValidationException" => BatchWriteExceptionType::FacetValidationException,
+            "ObjectNotDetachedException" => BatchWriteExceptionType::ObjectNotDetachedException,
+            "ResourceNotFoundException" => BatchWriteExceptionType::ResourceNotFoundException,
+            "AccessDeniedException" => BatchWriteExceptionType::AccessDeniedException,
+            "InvalidAttachmentException" => BatchWriteExceptionType::InvalidAttachmentException,
+            "NotIndexException" => BatchWriteExceptionType::NotIndexException,
+            "NotNodeException" => BatchWriteExceptionType::NotNodeException,
+            "IndexedAttributeMissingException" => {
+                BatchWriteExceptionType::IndexedAttributeMissingException
+            }
+            "ObjectAlreadyDetachedException" => {
+                BatchWriteExceptionType::ObjectAlreadyDetachedException
+            }
+            "NotPolicyException" => BatchWriteExceptionType::NotPolicyException,
+            "DirectoryNotEnabledException" => BatchWriteExceptionType::DirectoryNotEnabledException,
+            "LimitExceededException" => BatchWriteExceptionType::LimitExceededException,
+            "UnsupportedIndexTypeException" => {
+                BatchWriteExceptionType::UnsupportedIndexTypeException
+            }
+            _ => BatchWriteExceptionType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for BatchWriteExceptionType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<BatchWriteExceptionType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for BatchWriteExceptionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for BatchWriteExceptionType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BatchWriteExceptionType {
+    fn deserialize<D>(deserializer: D) -> Result<BatchWriteExceptionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents the output of a <code>BatchWrite</code> operation. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -1285,6 +1636,99 @@ pub struct BatchWriteResponse {
     pub responses: Option<Vec<BatchWriteOperationResponse>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ConsistencyLevel {
+    /// `SERIALIZABLE`
+    Serializable,
+    /// `EVENTUAL`
+    Eventual,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ConsistencyLevel {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ConsistencyLevel::Serializable => "SERIALIZABLE",
+            ConsistencyLevel::Eventual => "EVENTUAL",
+            ConsistencyLevel::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ConsistencyLevel {
+    fn default() -> ConsistencyLevel {
+        ConsistencyLevel::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ConsistencyLevel {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ConsistencyLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ConsistencyLevel {
+    fn from(value: &'a str) -> ConsistencyLevel {
+        match value {
+            "SERIALIZABLE" => ConsistencyLevel::Serializable,
+            "EVENTUAL" => ConsistencyLevel::Eventual,
+            _ => ConsistencyLevel::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ConsistencyLevel {
+    fn from(value: String) -> ConsistencyLevel {
+        match value.as_str() {
+            "SERIALIZABLE" => ConsistencyLevel::Serializable,
+            "EVENTUAL" => ConsistencyLevel::Eventual,
+            _ => ConsistencyLevel::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ConsistencyLevel {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ConsistencyLevel, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ConsistencyLevel {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ConsistencyLevel {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ConsistencyLevel {
+    fn deserialize<D>(deserializer: D) -> Result<ConsistencyLevel, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateDirectoryRequest {
@@ -1323,14 +1767,14 @@ pub struct CreateFacetRequest {
     /// <p>There are two different styles that you can define on any given facet, <code>Static</code> and <code>Dynamic</code>. For static facets, all attributes must be defined in the schema. For dynamic facets, attributes can be defined during data plane operations.</p>
     #[serde(rename = "FacetStyle")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub facet_style: Option<String>,
+    pub facet_style: Option<FacetStyle>,
     /// <p>The name of the <a>Facet</a>, which is unique for a given schema.</p>
     #[serde(rename = "Name")]
     pub name: String,
     /// <p><p>Specifies whether a given object created from this facet is of type node, leaf node, policy or index.</p> <ul> <li> <p>Node: Can have multiple children but one parent.</p> </li> </ul> <ul> <li> <p>Leaf node: Cannot have children but can have multiple parents.</p> </li> </ul> <ul> <li> <p>Policy: Allows you to store a policy document and policy type. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/key_concepts_directory.html#key_concepts_policies">Policies</a>.</p> </li> </ul> <ul> <li> <p>Index: Can be created with the Index API.</p> </li> </ul></p>
     #[serde(rename = "ObjectType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub object_type: Option<String>,
+    pub object_type: Option<ObjectType>,
     /// <p>The schema ARN in which the new <a>Facet</a> will be created. For more information, see <a>arns</a>.</p>
     #[serde(rename = "SchemaArn")]
     pub schema_arn: String,
@@ -1594,8 +2038,13 @@ pub struct DetachTypedLinkRequest {
 pub struct Directory {
     /// <p>The date and time when the directory was created.</p>
     #[serde(rename = "CreationDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date_time: Option<f64>,
+    pub creation_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The Amazon Resource Name (ARN) that is associated with the directory. For more information, see <a>arns</a>.</p>
     #[serde(rename = "DirectoryArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1607,9 +2056,107 @@ pub struct Directory {
     /// <p>The state of the directory. Can be either <code>Enabled</code>, <code>Disabled</code>, or <code>Deleted</code>.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<DirectoryState>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DirectoryState {
+    /// `ENABLED`
+    Enabled,
+    /// `DISABLED`
+    Disabled,
+    /// `DELETED`
+    Deleted,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DirectoryState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DirectoryState::Enabled => "ENABLED",
+            DirectoryState::Disabled => "DISABLED",
+            DirectoryState::Deleted => "DELETED",
+            DirectoryState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DirectoryState {
+    fn default() -> DirectoryState {
+        DirectoryState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DirectoryState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
 }
 
+impl fmt::Display for DirectoryState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DirectoryState {
+    fn from(value: &'a str) -> DirectoryState {
+        match value {
+            "ENABLED" => DirectoryState::Enabled,
+            "DISABLED" => DirectoryState::Disabled,
+            "DELETED" => DirectoryState::Deleted,
+            _ => DirectoryState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DirectoryState {
+    fn from(value: String) -> DirectoryState {
+        match value.as_str() {
+            "ENABLED" => DirectoryState::Enabled,
+            "DISABLED" => DirectoryState::Disabled,
+            "DELETED" => DirectoryState::Deleted,
+            _ => DirectoryState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DirectoryState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DirectoryState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DirectoryState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DirectoryState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DirectoryState {
+    fn deserialize<D>(deserializer: D) -> Result<DirectoryState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct DisableDirectoryRequest {
@@ -1649,7 +2196,7 @@ pub struct Facet {
     /// <p>There are two different styles that you can define on any given facet, <code>Static</code> and <code>Dynamic</code>. For static facets, all attributes must be defined in the schema. For dynamic facets, attributes can be defined during data plane operations.</p>
     #[serde(rename = "FacetStyle")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub facet_style: Option<String>,
+    pub facet_style: Option<FacetStyle>,
     /// <p>The name of the <a>Facet</a>.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1657,7 +2204,7 @@ pub struct Facet {
     /// <p>The object type that is associated with the facet. See <a>CreateFacetRequest$ObjectType</a> for more details.</p>
     #[serde(rename = "ObjectType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub object_type: Option<String>,
+    pub object_type: Option<ObjectType>,
 }
 
 /// <p>An attribute that is associated with the <a>Facet</a>.</p>
@@ -1677,7 +2224,7 @@ pub struct FacetAttribute {
     /// <p>The required behavior of the <code>FacetAttribute</code>.</p>
     #[serde(rename = "RequiredBehavior")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub required_behavior: Option<String>,
+    pub required_behavior: Option<RequiredAttributeBehavior>,
 }
 
 /// <p>A facet attribute definition. See <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/schemas_attributereferences.html">Attribute References</a> for more information.</p>
@@ -1697,7 +2244,7 @@ pub struct FacetAttributeDefinition {
     pub rules: Option<::std::collections::HashMap<String, Rule>>,
     /// <p>The type of the attribute.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: FacetAttributeType,
 }
 
 /// <p>The facet attribute reference that specifies the attribute definition that contains the attribute facet name and attribute name.</p>
@@ -1711,6 +2258,119 @@ pub struct FacetAttributeReference {
     pub target_facet_name: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FacetAttributeType {
+    /// `STRING`
+    String,
+    /// `BINARY`
+    Binary,
+    /// `BOOLEAN`
+    Boolean,
+    /// `NUMBER`
+    Number,
+    /// `DATETIME`
+    Datetime,
+    /// `VARIANT`
+    Variant,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl FacetAttributeType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FacetAttributeType::String => "STRING",
+            FacetAttributeType::Binary => "BINARY",
+            FacetAttributeType::Boolean => "BOOLEAN",
+            FacetAttributeType::Number => "NUMBER",
+            FacetAttributeType::Datetime => "DATETIME",
+            FacetAttributeType::Variant => "VARIANT",
+            FacetAttributeType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for FacetAttributeType {
+    fn default() -> FacetAttributeType {
+        FacetAttributeType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for FacetAttributeType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FacetAttributeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FacetAttributeType {
+    fn from(value: &'a str) -> FacetAttributeType {
+        match value {
+            "STRING" => FacetAttributeType::String,
+            "BINARY" => FacetAttributeType::Binary,
+            "BOOLEAN" => FacetAttributeType::Boolean,
+            "NUMBER" => FacetAttributeType::Number,
+            "DATETIME" => FacetAttributeType::Datetime,
+            "VARIANT" => FacetAttributeType::Variant,
+            _ => FacetAttributeType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FacetAttributeType {
+    fn from(value: String) -> FacetAttributeType {
+        match value.as_str() {
+            "STRING" => FacetAttributeType::String,
+            "BINARY" => FacetAttributeType::Binary,
+            "BOOLEAN" => FacetAttributeType::Boolean,
+            "NUMBER" => FacetAttributeType::Number,
+            "DATETIME" => FacetAttributeType::Datetime,
+            "VARIANT" => FacetAttributeType::Variant,
+            _ => FacetAttributeType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for FacetAttributeType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<FacetAttributeType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FacetAttributeType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FacetAttributeType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FacetAttributeType {
+    fn deserialize<D>(deserializer: D) -> Result<FacetAttributeType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A structure that contains information used to update an attribute.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -1718,13 +2378,106 @@ pub struct FacetAttributeUpdate {
     /// <p>The action to perform when updating the attribute.</p>
     #[serde(rename = "Action")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub action: Option<String>,
+    pub action: Option<UpdateActionType>,
     /// <p>The attribute to update.</p>
     #[serde(rename = "Attribute")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub attribute: Option<FacetAttribute>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FacetStyle {
+    /// `STATIC`
+    Static,
+    /// `DYNAMIC`
+    Dynamic,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl FacetStyle {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FacetStyle::Static => "STATIC",
+            FacetStyle::Dynamic => "DYNAMIC",
+            FacetStyle::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for FacetStyle {
+    fn default() -> FacetStyle {
+        FacetStyle::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for FacetStyle {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FacetStyle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FacetStyle {
+    fn from(value: &'a str) -> FacetStyle {
+        match value {
+            "STATIC" => FacetStyle::Static,
+            "DYNAMIC" => FacetStyle::Dynamic,
+            _ => FacetStyle::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FacetStyle {
+    fn from(value: String) -> FacetStyle {
+        match value.as_str() {
+            "STATIC" => FacetStyle::Static,
+            "DYNAMIC" => FacetStyle::Dynamic,
+            _ => FacetStyle::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for FacetStyle {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<FacetStyle, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FacetStyle {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FacetStyle {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FacetStyle {
+    fn deserialize<D>(deserializer: D) -> Result<FacetStyle, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct GetAppliedSchemaVersionRequest {
@@ -1787,7 +2540,7 @@ pub struct GetLinkAttributesRequest {
     /// <p>The consistency level at which to retrieve the attributes on a typed link.</p>
     #[serde(rename = "ConsistencyLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub consistency_level: Option<String>,
+    pub consistency_level: Option<ConsistencyLevel>,
     /// <p>The Amazon Resource Name (ARN) that is associated with the Directory where the typed link resides. For more information, see <a>arns</a> or <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/directory_objects_links.html#directory_objects_links_typedlink">Typed Links</a>.</p>
     #[serde(rename = "DirectoryArn")]
     pub directory_arn: String,
@@ -1814,7 +2567,7 @@ pub struct GetObjectAttributesRequest {
     /// <p>The consistency level at which to retrieve the attributes on an object.</p>
     #[serde(rename = "ConsistencyLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub consistency_level: Option<String>,
+    pub consistency_level: Option<ConsistencyLevel>,
     /// <p>The Amazon Resource Name (ARN) that is associated with the <a>Directory</a> where the object resides.</p>
     #[serde(rename = "DirectoryArn")]
     pub directory_arn: String,
@@ -1841,7 +2594,7 @@ pub struct GetObjectInformationRequest {
     /// <p>The consistency level at which to retrieve the object information.</p>
     #[serde(rename = "ConsistencyLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub consistency_level: Option<String>,
+    pub consistency_level: Option<ConsistencyLevel>,
     /// <p>The ARN of the directory being retrieved.</p>
     #[serde(rename = "DirectoryArn")]
     pub directory_arn: String,
@@ -1925,7 +2678,7 @@ pub struct LinkAttributeAction {
     /// <p>A type that can be either <code>UPDATE_OR_CREATE</code> or <code>DELETE</code>.</p>
     #[serde(rename = "AttributeActionType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub attribute_action_type: Option<String>,
+    pub attribute_action_type: Option<UpdateActionType>,
     /// <p>The value that you want to update to.</p>
     #[serde(rename = "AttributeUpdateValue")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1985,7 +2738,7 @@ pub struct ListAttachedIndicesRequest {
     /// <p>The consistency level to use for this operation.</p>
     #[serde(rename = "ConsistencyLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub consistency_level: Option<String>,
+    pub consistency_level: Option<ConsistencyLevel>,
     /// <p>The ARN of the directory.</p>
     #[serde(rename = "DirectoryArn")]
     pub directory_arn: String,
@@ -2055,7 +2808,7 @@ pub struct ListDirectoriesRequest {
     /// <p>The state of the directories in the list. Can be either Enabled, Disabled, or Deleted.</p>
     #[serde(rename = "state")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<DirectoryState>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -2137,7 +2890,7 @@ pub struct ListIncomingTypedLinksRequest {
     /// <p>The consistency level to execute the request at.</p>
     #[serde(rename = "ConsistencyLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub consistency_level: Option<String>,
+    pub consistency_level: Option<ConsistencyLevel>,
     /// <p>The Amazon Resource Name (ARN) of the directory where you want to list the typed links.</p>
     #[serde(rename = "DirectoryArn")]
     pub directory_arn: String,
@@ -2181,7 +2934,7 @@ pub struct ListIndexRequest {
     /// <p>The consistency level to execute the request at.</p>
     #[serde(rename = "ConsistencyLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub consistency_level: Option<String>,
+    pub consistency_level: Option<ConsistencyLevel>,
     /// <p>The ARN of the directory that the index exists in.</p>
     #[serde(rename = "DirectoryArn")]
     pub directory_arn: String,
@@ -2251,7 +3004,7 @@ pub struct ListObjectAttributesRequest {
     /// <p>Represents the manner and timing in which the successful write or update of an object is reflected in a subsequent read operation of that same object.</p>
     #[serde(rename = "ConsistencyLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub consistency_level: Option<String>,
+    pub consistency_level: Option<ConsistencyLevel>,
     /// <p>The Amazon Resource Name (ARN) that is associated with the <a>Directory</a> where the object resides. For more information, see <a>arns</a>.</p>
     #[serde(rename = "DirectoryArn")]
     pub directory_arn: String,
@@ -2291,7 +3044,7 @@ pub struct ListObjectChildrenRequest {
     /// <p>Represents the manner and timing in which the successful write or update of an object is reflected in a subsequent read operation of that same object.</p>
     #[serde(rename = "ConsistencyLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub consistency_level: Option<String>,
+    pub consistency_level: Option<ConsistencyLevel>,
     /// <p>The Amazon Resource Name (ARN) that is associated with the <a>Directory</a> where the object resides. For more information, see <a>arns</a>.</p>
     #[serde(rename = "DirectoryArn")]
     pub directory_arn: String,
@@ -2359,7 +3112,7 @@ pub struct ListObjectParentsRequest {
     /// <p>Represents the manner and timing in which the successful write or update of an object is reflected in a subsequent read operation of that same object.</p>
     #[serde(rename = "ConsistencyLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub consistency_level: Option<String>,
+    pub consistency_level: Option<ConsistencyLevel>,
     /// <p>The Amazon Resource Name (ARN) that is associated with the <a>Directory</a> where the object resides. For more information, see <a>arns</a>.</p>
     #[serde(rename = "DirectoryArn")]
     pub directory_arn: String,
@@ -2403,7 +3156,7 @@ pub struct ListObjectPoliciesRequest {
     /// <p>Represents the manner and timing in which the successful write or update of an object is reflected in a subsequent read operation of that same object.</p>
     #[serde(rename = "ConsistencyLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub consistency_level: Option<String>,
+    pub consistency_level: Option<ConsistencyLevel>,
     /// <p>The Amazon Resource Name (ARN) that is associated with the <a>Directory</a> where objects reside. For more information, see <a>arns</a>.</p>
     #[serde(rename = "DirectoryArn")]
     pub directory_arn: String,
@@ -2439,7 +3192,7 @@ pub struct ListOutgoingTypedLinksRequest {
     /// <p>The consistency level to execute the request at.</p>
     #[serde(rename = "ConsistencyLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub consistency_level: Option<String>,
+    pub consistency_level: Option<ConsistencyLevel>,
     /// <p>The Amazon Resource Name (ARN) of the directory where you want to list the typed links.</p>
     #[serde(rename = "DirectoryArn")]
     pub directory_arn: String,
@@ -2483,7 +3236,7 @@ pub struct ListPolicyAttachmentsRequest {
     /// <p>Represents the manner and timing in which the successful write or update of an object is reflected in a subsequent read operation of that same object.</p>
     #[serde(rename = "ConsistencyLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub consistency_level: Option<String>,
+    pub consistency_level: Option<ConsistencyLevel>,
     /// <p>The Amazon Resource Name (ARN) that is associated with the <a>Directory</a> where objects reside. For more information, see <a>arns</a>.</p>
     #[serde(rename = "DirectoryArn")]
     pub directory_arn: String,
@@ -2672,7 +3425,7 @@ pub struct ObjectAttributeAction {
     /// <p>A type that can be either <code>Update</code> or <code>Delete</code>.</p>
     #[serde(rename = "ObjectAttributeActionType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub object_attribute_action_type: Option<String>,
+    pub object_attribute_action_type: Option<UpdateActionType>,
     /// <p>The value that you want to update to.</p>
     #[serde(rename = "ObjectAttributeUpdateValue")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2730,6 +3483,109 @@ pub struct ObjectReference {
     pub selector: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ObjectType {
+    /// `NODE`
+    Node,
+    /// `LEAF_NODE`
+    LeafNode,
+    /// `POLICY`
+    Policy,
+    /// `INDEX`
+    Index,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ObjectType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ObjectType::Node => "NODE",
+            ObjectType::LeafNode => "LEAF_NODE",
+            ObjectType::Policy => "POLICY",
+            ObjectType::Index => "INDEX",
+            ObjectType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ObjectType {
+    fn default() -> ObjectType {
+        ObjectType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ObjectType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ObjectType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ObjectType {
+    fn from(value: &'a str) -> ObjectType {
+        match value {
+            "NODE" => ObjectType::Node,
+            "LEAF_NODE" => ObjectType::LeafNode,
+            "POLICY" => ObjectType::Policy,
+            "INDEX" => ObjectType::Index,
+            _ => ObjectType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ObjectType {
+    fn from(value: String) -> ObjectType {
+        match value.as_str() {
+            "NODE" => ObjectType::Node,
+            "LEAF_NODE" => ObjectType::LeafNode,
+            "POLICY" => ObjectType::Policy,
+            "INDEX" => ObjectType::Index,
+            _ => ObjectType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ObjectType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ObjectType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ObjectType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ObjectType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ObjectType {
+    fn deserialize<D>(deserializer: D) -> Result<ObjectType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Returns the path to the <code>ObjectIdentifiers</code> that is associated with the directory.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -2824,6 +3680,114 @@ pub struct PutSchemaFromJsonResponse {
     pub arn: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RangeMode {
+    /// `FIRST`
+    First,
+    /// `LAST`
+    Last,
+    /// `LAST_BEFORE_MISSING_VALUES`
+    LastBeforeMissingValues,
+    /// `INCLUSIVE`
+    Inclusive,
+    /// `EXCLUSIVE`
+    Exclusive,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RangeMode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RangeMode::First => "FIRST",
+            RangeMode::Last => "LAST",
+            RangeMode::LastBeforeMissingValues => "LAST_BEFORE_MISSING_VALUES",
+            RangeMode::Inclusive => "INCLUSIVE",
+            RangeMode::Exclusive => "EXCLUSIVE",
+            RangeMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RangeMode {
+    fn default() -> RangeMode {
+        RangeMode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RangeMode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RangeMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RangeMode {
+    fn from(value: &'a str) -> RangeMode {
+        match value {
+            "FIRST" => RangeMode::First,
+            "LAST" => RangeMode::Last,
+            "LAST_BEFORE_MISSING_VALUES" => RangeMode::LastBeforeMissingValues,
+            "INCLUSIVE" => RangeMode::Inclusive,
+            "EXCLUSIVE" => RangeMode::Exclusive,
+            _ => RangeMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RangeMode {
+    fn from(value: String) -> RangeMode {
+        match value.as_str() {
+            "FIRST" => RangeMode::First,
+            "LAST" => RangeMode::Last,
+            "LAST_BEFORE_MISSING_VALUES" => RangeMode::LastBeforeMissingValues,
+            "INCLUSIVE" => RangeMode::Inclusive,
+            "EXCLUSIVE" => RangeMode::Exclusive,
+            _ => RangeMode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RangeMode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RangeMode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RangeMode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RangeMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RangeMode {
+    fn deserialize<D>(deserializer: D) -> Result<RangeMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct RemoveFacetFromObjectRequest {
@@ -2842,6 +3806,99 @@ pub struct RemoveFacetFromObjectRequest {
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RemoveFacetFromObjectResponse {}
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RequiredAttributeBehavior {
+    /// `REQUIRED_ALWAYS`
+    RequiredAlways,
+    /// `NOT_REQUIRED`
+    NotRequired,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RequiredAttributeBehavior {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RequiredAttributeBehavior::RequiredAlways => "REQUIRED_ALWAYS",
+            RequiredAttributeBehavior::NotRequired => "NOT_REQUIRED",
+            RequiredAttributeBehavior::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RequiredAttributeBehavior {
+    fn default() -> RequiredAttributeBehavior {
+        RequiredAttributeBehavior::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RequiredAttributeBehavior {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RequiredAttributeBehavior {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RequiredAttributeBehavior {
+    fn from(value: &'a str) -> RequiredAttributeBehavior {
+        match value {
+            "REQUIRED_ALWAYS" => RequiredAttributeBehavior::RequiredAlways,
+            "NOT_REQUIRED" => RequiredAttributeBehavior::NotRequired,
+            _ => RequiredAttributeBehavior::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RequiredAttributeBehavior {
+    fn from(value: String) -> RequiredAttributeBehavior {
+        match value.as_str() {
+            "REQUIRED_ALWAYS" => RequiredAttributeBehavior::RequiredAlways,
+            "NOT_REQUIRED" => RequiredAttributeBehavior::NotRequired,
+            _ => RequiredAttributeBehavior::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RequiredAttributeBehavior {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RequiredAttributeBehavior, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RequiredAttributeBehavior {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RequiredAttributeBehavior {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RequiredAttributeBehavior {
+    fn deserialize<D>(deserializer: D) -> Result<RequiredAttributeBehavior, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Contains an Amazon Resource Name (ARN) and parameters that are associated with the rule.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Rule {
@@ -2852,9 +3909,112 @@ pub struct Rule {
     /// <p>The type of attribute validation rule.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<RuleType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RuleType {
+    /// `BINARY_LENGTH`
+    BinaryLength,
+    /// `NUMBER_COMPARISON`
+    NumberComparison,
+    /// `STRING_FROM_SET`
+    StringFromSet,
+    /// `STRING_LENGTH`
+    StringLength,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RuleType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RuleType::BinaryLength => "BINARY_LENGTH",
+            RuleType::NumberComparison => "NUMBER_COMPARISON",
+            RuleType::StringFromSet => "STRING_FROM_SET",
+            RuleType::StringLength => "STRING_LENGTH",
+            RuleType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RuleType {
+    fn default() -> RuleType {
+        RuleType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RuleType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RuleType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
+impl<'a> From<&'a str> for RuleType {
+    fn from(value: &'a str) -> RuleType {
+        match value {
+            "BINARY_LENGTH" => RuleType::BinaryLength,
+            "NUMBER_COMPARISON" => RuleType::NumberComparison,
+            "STRING_FROM_SET" => RuleType::StringFromSet,
+            "STRING_LENGTH" => RuleType::StringLength,
+            _ => RuleType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RuleType {
+    fn from(value: String) -> RuleType {
+        match value.as_str() {
+            "BINARY_LENGTH" => RuleType::BinaryLength,
+            "NUMBER_COMPARISON" => RuleType::NumberComparison,
+            "STRING_FROM_SET" => RuleType::StringFromSet,
+            "STRING_LENGTH" => RuleType::StringLength,
+            _ => RuleType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RuleType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RuleType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RuleType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RuleType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RuleType {
+    fn deserialize<D>(deserializer: D) -> Result<RuleType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A facet.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SchemaFacet {
@@ -2914,8 +4074,13 @@ pub struct TypedAttributeValue {
     pub boolean_value: Option<bool>,
     /// <p>A date and time value.</p>
     #[serde(rename = "DatetimeValue")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub datetime_value: Option<f64>,
+    pub datetime_value: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A number data value.</p>
     #[serde(rename = "NumberValue")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2932,14 +4097,14 @@ pub struct TypedAttributeValue {
 pub struct TypedAttributeValueRange {
     /// <p>The inclusive or exclusive range end.</p>
     #[serde(rename = "EndMode")]
-    pub end_mode: String,
+    pub end_mode: RangeMode,
     /// <p>The attribute value to terminate the range at.</p>
     #[serde(rename = "EndValue")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub end_value: Option<TypedAttributeValue>,
     /// <p>The inclusive or exclusive range start.</p>
     #[serde(rename = "StartMode")]
-    pub start_mode: String,
+    pub start_mode: RangeMode,
     /// <p>The value to start the range at.</p>
     #[serde(rename = "StartValue")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2962,14 +4127,14 @@ pub struct TypedLinkAttributeDefinition {
     pub name: String,
     /// <p>The required behavior of the <code>TypedLinkAttributeDefinition</code>.</p>
     #[serde(rename = "RequiredBehavior")]
-    pub required_behavior: String,
+    pub required_behavior: RequiredAttributeBehavior,
     /// <p>Validation rules that are attached to the attribute definition.</p>
     #[serde(rename = "Rules")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub rules: Option<::std::collections::HashMap<String, Rule>>,
     /// <p>The type of the attribute.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: FacetAttributeType,
 }
 
 /// <p>Identifies the range of attributes that are used by a specified filter.</p>
@@ -3006,7 +4171,7 @@ pub struct TypedLinkFacet {
 pub struct TypedLinkFacetAttributeUpdate {
     /// <p>The action to perform when updating the attribute.</p>
     #[serde(rename = "Action")]
-    pub action: String,
+    pub action: UpdateActionType,
     /// <p>The attribute to update.</p>
     #[serde(rename = "Attribute")]
     pub attribute: TypedLinkAttributeDefinition,
@@ -3055,6 +4220,99 @@ pub struct UntagResourceRequest {
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UntagResourceResponse {}
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum UpdateActionType {
+    /// `CREATE_OR_UPDATE`
+    CreateOrUpdate,
+    /// `DELETE`
+    Delete,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl UpdateActionType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            UpdateActionType::CreateOrUpdate => "CREATE_OR_UPDATE",
+            UpdateActionType::Delete => "DELETE",
+            UpdateActionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for UpdateActionType {
+    fn default() -> UpdateActionType {
+        UpdateActionType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for UpdateActionType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for UpdateActionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for UpdateActionType {
+    fn from(value: &'a str) -> UpdateActionType {
+        match value {
+            "CREATE_OR_UPDATE" => UpdateActionType::CreateOrUpdate,
+            "DELETE" => UpdateActionType::Delete,
+            _ => UpdateActionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for UpdateActionType {
+    fn from(value: String) -> UpdateActionType {
+        match value.as_str() {
+            "CREATE_OR_UPDATE" => UpdateActionType::CreateOrUpdate,
+            "DELETE" => UpdateActionType::Delete,
+            _ => UpdateActionType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for UpdateActionType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<UpdateActionType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for UpdateActionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for UpdateActionType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for UpdateActionType {
+    fn deserialize<D>(deserializer: D) -> Result<UpdateActionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct UpdateFacetRequest {
@@ -3068,7 +4326,7 @@ pub struct UpdateFacetRequest {
     /// <p>The object type that is associated with the facet. See <a>CreateFacetRequest$ObjectType</a> for more details.</p>
     #[serde(rename = "ObjectType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub object_type: Option<String>,
+    pub object_type: Option<ObjectType>,
     /// <p>The Amazon Resource Name (ARN) that is associated with the <a>Facet</a>. For more information, see <a>arns</a>.</p>
     #[serde(rename = "SchemaArn")]
     pub schema_arn: String,
@@ -8622,11 +9880,12 @@ pub struct CloudDirectoryClient {
 impl CloudDirectoryClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> CloudDirectoryClient {
         CloudDirectoryClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "CloudDirectory"),
         }
     }
 
@@ -8660,6 +9919,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/object/facets";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("AddFacetToObject");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8691,6 +9951,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/schema/apply";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ApplySchema");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8722,6 +9983,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/object/attach";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("AttachObject");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8753,6 +10015,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/policy/attach";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("AttachPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8784,6 +10047,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/index/attach";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("AttachToIndex");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8815,6 +10079,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/attach";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("AttachTypedLink");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8846,6 +10111,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/batchread";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("BatchRead");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8881,6 +10147,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/batchwrite";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("BatchWrite");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8912,6 +10179,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/directory/create";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("CreateDirectory");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8943,6 +10211,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/facet/create";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("CreateFacet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8974,6 +10243,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/index";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("CreateIndex");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9005,6 +10275,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/object";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("CreateObject");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9036,6 +10307,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/schema/create";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("CreateSchema");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9066,6 +10338,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/facet/create";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("CreateTypedLinkFacet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9097,6 +10370,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/directory";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("DeleteDirectory");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_header("x-amz-data-partition", &input.directory_arn);
@@ -9126,6 +10400,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/facet/delete";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("DeleteFacet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9157,6 +10432,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/object/delete";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("DeleteObject");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9188,6 +10464,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/schema";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("DeleteSchema");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_header("x-amz-data-partition", &input.schema_arn);
@@ -9217,6 +10494,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/facet/delete";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("DeleteTypedLinkFacet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9248,6 +10526,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/index/detach";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("DetachFromIndex");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9279,6 +10558,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/object/detach";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("DetachObject");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9310,6 +10590,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/policy/detach";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("DetachPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9341,6 +10622,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/detach";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("DetachTypedLink");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9371,6 +10653,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/directory/disable";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("DisableDirectory");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_header("x-amz-data-partition", &input.directory_arn);
@@ -9400,6 +10683,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/directory/enable";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("EnableDirectory");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_header("x-amz-data-partition", &input.directory_arn);
@@ -9429,6 +10713,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/schema/getappliedschema";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("GetAppliedSchemaVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9459,6 +10744,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/directory/get";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("GetDirectory");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_header("x-amz-data-partition", &input.directory_arn);
@@ -9488,6 +10774,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/facet";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("GetFacet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9519,6 +10806,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/attributes/get";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("GetLinkAttributes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9550,6 +10838,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/object/attributes/get";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("GetObjectAttributes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9585,6 +10874,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/object/information";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("GetObjectInformation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9620,6 +10910,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/schema/json";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("GetSchemaAsJson");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_header("x-amz-data-partition", &input.schema_arn);
@@ -9650,6 +10941,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/facet/get";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("GetTypedLinkFacetInformation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9681,6 +10973,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/schema/applied";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListAppliedSchemaArns");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9711,6 +11004,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/object/indices";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListAttachedIndices");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9747,6 +11041,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/schema/development";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListDevelopmentSchemaArns");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9777,6 +11072,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/directory/list";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListDirectories");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9807,6 +11103,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/facet/attributes";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListFacetAttributes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9838,6 +11135,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/facet/list";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListFacetNames");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9869,6 +11167,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/incoming";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListIncomingTypedLinks");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9900,6 +11199,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/index/targets";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListIndex");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9935,6 +11235,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/schema/managed";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListManagedSchemaArns");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9965,6 +11266,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/object/attributes";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListObjectAttributes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10000,6 +11302,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/object/children";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListObjectChildren");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10035,6 +11338,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/object/parentpaths";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListObjectParentPaths");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10066,6 +11370,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/object/parent";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListObjectParents");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10101,6 +11406,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/object/policy";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListObjectPolicies");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10136,6 +11442,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/outgoing";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListOutgoingTypedLinks");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10167,6 +11474,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/policy/attachment";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListPolicyAttachments");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10202,6 +11510,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/schema/published";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListPublishedSchemaArns");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10232,6 +11541,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/tags";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10263,6 +11573,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/facet/attributes";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListTypedLinkFacetAttributes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10294,6 +11605,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/facet/list";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListTypedLinkFacetNames");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10325,6 +11637,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/policy/lookup";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("LookupPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10356,6 +11669,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/schema/publish";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("PublishSchema");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10387,6 +11701,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/schema/json";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("PutSchemaFromJson");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10418,6 +11733,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/object/facets/delete";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("RemoveFacetFromObject");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10449,6 +11765,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/tags/add";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10479,6 +11796,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/tags/remove";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10509,6 +11827,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/facet";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("UpdateFacet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10540,6 +11859,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/attributes/update";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("UpdateLinkAttributes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10571,6 +11891,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/object/update";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("UpdateObjectAttributes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10602,6 +11923,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/schema/update";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("UpdateSchema");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10633,6 +11955,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/facet";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("UpdateTypedLinkFacet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10664,6 +11987,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/schema/upgradeapplied";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("UpgradeAppliedSchema");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10694,6 +12018,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/schema/upgradepublished";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("UpgradePublishedSchema");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10716,3 +12041,643 @@ impl CloudDirectory for CloudDirectoryClient {
         }
     }
 }
+impl CloudDirectoryClient {
+    /// Auto-paginating version of `list_applied_schema_arns`, streaming every page of results.
+    pub fn list_applied_schema_arns_pages(
+        &self,
+        input: ListAppliedSchemaArnsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListAppliedSchemaArnsResponse,
+        ListAppliedSchemaArnsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudDirectoryClient, input: ListAppliedSchemaArnsRequest| async move {
+                client.list_applied_schema_arns(input).await
+            },
+            |input: &ListAppliedSchemaArnsRequest, output: &ListAppliedSchemaArnsResponse| {
+                Some(ListAppliedSchemaArnsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_applied_schema_arns`, streaming the `SchemaArns` of every page of results.
+    pub fn list_applied_schema_arns_items(
+        &self,
+        input: ListAppliedSchemaArnsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListAppliedSchemaArnsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_applied_schema_arns_pages(input),
+            |output: ListAppliedSchemaArnsResponse| output.schema_arns.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_attached_indices`, streaming every page of results.
+    pub fn list_attached_indices_pages(
+        &self,
+        input: ListAttachedIndicesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListAttachedIndicesResponse, ListAttachedIndicesError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudDirectoryClient, input: ListAttachedIndicesRequest| async move {
+                client.list_attached_indices(input).await
+            },
+            |input: &ListAttachedIndicesRequest, output: &ListAttachedIndicesResponse| {
+                Some(ListAttachedIndicesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_attached_indices`, streaming the `IndexAttachments` of every page of results.
+    pub fn list_attached_indices_items(
+        &self,
+        input: ListAttachedIndicesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<IndexAttachment, ListAttachedIndicesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_attached_indices_pages(input),
+            |output: ListAttachedIndicesResponse| output.index_attachments.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_development_schema_arns`, streaming every page of results.
+    pub fn list_development_schema_arns_pages(
+        &self,
+        input: ListDevelopmentSchemaArnsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListDevelopmentSchemaArnsResponse,
+        ListDevelopmentSchemaArnsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudDirectoryClient, input: ListDevelopmentSchemaArnsRequest| async move {
+                client.list_development_schema_arns(input).await
+            },
+            |input: &ListDevelopmentSchemaArnsRequest,
+             output: &ListDevelopmentSchemaArnsResponse| {
+                Some(ListDevelopmentSchemaArnsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_development_schema_arns`, streaming the `SchemaArns` of every page of results.
+    pub fn list_development_schema_arns_items(
+        &self,
+        input: ListDevelopmentSchemaArnsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListDevelopmentSchemaArnsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_development_schema_arns_pages(input),
+            |output: ListDevelopmentSchemaArnsResponse| output.schema_arns.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_directories`, streaming every page of results.
+    pub fn list_directories_pages(
+        &self,
+        input: ListDirectoriesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListDirectoriesResponse, ListDirectoriesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudDirectoryClient, input: ListDirectoriesRequest| async move {
+                client.list_directories(input).await
+            },
+            |input: &ListDirectoriesRequest, output: &ListDirectoriesResponse| {
+                Some(ListDirectoriesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_directories`, streaming the `Directories` of every page of results.
+    pub fn list_directories_items(
+        &self,
+        input: ListDirectoriesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Directory, ListDirectoriesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_directories_pages(input),
+            |output: ListDirectoriesResponse| output.directories,
+        )
+    }
+
+    /// Auto-paginating version of `list_facet_attributes`, streaming every page of results.
+    pub fn list_facet_attributes_pages(
+        &self,
+        input: ListFacetAttributesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListFacetAttributesResponse, ListFacetAttributesError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudDirectoryClient, input: ListFacetAttributesRequest| async move {
+                client.list_facet_attributes(input).await
+            },
+            |input: &ListFacetAttributesRequest, output: &ListFacetAttributesResponse| {
+                Some(ListFacetAttributesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_facet_attributes`, streaming the `Attributes` of every page of results.
+    pub fn list_facet_attributes_items(
+        &self,
+        input: ListFacetAttributesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<FacetAttribute, ListFacetAttributesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_facet_attributes_pages(input),
+            |output: ListFacetAttributesResponse| output.attributes.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_facet_names`, streaming every page of results.
+    pub fn list_facet_names_pages(
+        &self,
+        input: ListFacetNamesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListFacetNamesResponse, ListFacetNamesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudDirectoryClient, input: ListFacetNamesRequest| async move {
+                client.list_facet_names(input).await
+            },
+            |input: &ListFacetNamesRequest, output: &ListFacetNamesResponse| {
+                Some(ListFacetNamesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_facet_names`, streaming the `FacetNames` of every page of results.
+    pub fn list_facet_names_items(
+        &self,
+        input: ListFacetNamesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListFacetNamesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_facet_names_pages(input),
+            |output: ListFacetNamesResponse| output.facet_names.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_incoming_typed_links`, streaming every page of results.
+    pub fn list_incoming_typed_links_pages(
+        &self,
+        input: ListIncomingTypedLinksRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListIncomingTypedLinksResponse,
+        ListIncomingTypedLinksError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudDirectoryClient, input: ListIncomingTypedLinksRequest| async move {
+                client.list_incoming_typed_links(input).await
+            },
+            |input: &ListIncomingTypedLinksRequest, output: &ListIncomingTypedLinksResponse| {
+                Some(ListIncomingTypedLinksRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_incoming_typed_links`, streaming the `LinkSpecifiers` of every page of results.
+    pub fn list_incoming_typed_links_items(
+        &self,
+        input: ListIncomingTypedLinksRequest,
+    ) -> rusoto_core::pagination::RusotoStream<TypedLinkSpecifier, ListIncomingTypedLinksError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_incoming_typed_links_pages(input),
+            |output: ListIncomingTypedLinksResponse| output.link_specifiers.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_index`, streaming every page of results.
+    pub fn list_index_pages(
+        &self,
+        input: ListIndexRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListIndexResponse, ListIndexError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudDirectoryClient, input: ListIndexRequest| async move {
+                client.list_index(input).await
+            },
+            |input: &ListIndexRequest, output: &ListIndexResponse| {
+                Some(ListIndexRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_index`, streaming the `IndexAttachments` of every page of results.
+    pub fn list_index_items(
+        &self,
+        input: ListIndexRequest,
+    ) -> rusoto_core::pagination::RusotoStream<IndexAttachment, ListIndexError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_index_pages(input),
+            |output: ListIndexResponse| output.index_attachments.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_managed_schema_arns`, streaming every page of results.
+    pub fn list_managed_schema_arns_pages(
+        &self,
+        input: ListManagedSchemaArnsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListManagedSchemaArnsResponse,
+        ListManagedSchemaArnsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudDirectoryClient, input: ListManagedSchemaArnsRequest| async move {
+                client.list_managed_schema_arns(input).await
+            },
+            |input: &ListManagedSchemaArnsRequest, output: &ListManagedSchemaArnsResponse| {
+                Some(ListManagedSchemaArnsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_managed_schema_arns`, streaming the `SchemaArns` of every page of results.
+    pub fn list_managed_schema_arns_items(
+        &self,
+        input: ListManagedSchemaArnsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListManagedSchemaArnsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_managed_schema_arns_pages(input),
+            |output: ListManagedSchemaArnsResponse| output.schema_arns.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_object_attributes`, streaming every page of results.
+    pub fn list_object_attributes_pages(
+        &self,
+        input: ListObjectAttributesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListObjectAttributesResponse,
+        ListObjectAttributesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudDirectoryClient, input: ListObjectAttributesRequest| async move {
+                client.list_object_attributes(input).await
+            },
+            |input: &ListObjectAttributesRequest, output: &ListObjectAttributesResponse| {
+                Some(ListObjectAttributesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_object_attributes`, streaming the `Attributes` of every page of results.
+    pub fn list_object_attributes_items(
+        &self,
+        input: ListObjectAttributesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<AttributeKeyAndValue, ListObjectAttributesError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_object_attributes_pages(input),
+            |output: ListObjectAttributesResponse| output.attributes.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_object_parent_paths`, streaming every page of results.
+    pub fn list_object_parent_paths_pages(
+        &self,
+        input: ListObjectParentPathsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListObjectParentPathsResponse,
+        ListObjectParentPathsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudDirectoryClient, input: ListObjectParentPathsRequest| async move {
+                client.list_object_parent_paths(input).await
+            },
+            |input: &ListObjectParentPathsRequest, output: &ListObjectParentPathsResponse| {
+                Some(ListObjectParentPathsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_object_parent_paths`, streaming the `PathToObjectIdentifiersList` of every page of results.
+    pub fn list_object_parent_paths_items(
+        &self,
+        input: ListObjectParentPathsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<PathToObjectIdentifiers, ListObjectParentPathsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_object_parent_paths_pages(input),
+            |output: ListObjectParentPathsResponse| {
+                output.path_to_object_identifiers_list.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_object_policies`, streaming every page of results.
+    pub fn list_object_policies_pages(
+        &self,
+        input: ListObjectPoliciesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListObjectPoliciesResponse, ListObjectPoliciesError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudDirectoryClient, input: ListObjectPoliciesRequest| async move {
+                client.list_object_policies(input).await
+            },
+            |input: &ListObjectPoliciesRequest, output: &ListObjectPoliciesResponse| {
+                Some(ListObjectPoliciesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_object_policies`, streaming the `AttachedPolicyIds` of every page of results.
+    pub fn list_object_policies_items(
+        &self,
+        input: ListObjectPoliciesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListObjectPoliciesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_object_policies_pages(input),
+            |output: ListObjectPoliciesResponse| output.attached_policy_ids.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_outgoing_typed_links`, streaming every page of results.
+    pub fn list_outgoing_typed_links_pages(
+        &self,
+        input: ListOutgoingTypedLinksRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListOutgoingTypedLinksResponse,
+        ListOutgoingTypedLinksError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudDirectoryClient, input: ListOutgoingTypedLinksRequest| async move {
+                client.list_outgoing_typed_links(input).await
+            },
+            |input: &ListOutgoingTypedLinksRequest, output: &ListOutgoingTypedLinksResponse| {
+                Some(ListOutgoingTypedLinksRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_outgoing_typed_links`, streaming the `TypedLinkSpecifiers` of every page of results.
+    pub fn list_outgoing_typed_links_items(
+        &self,
+        input: ListOutgoingTypedLinksRequest,
+    ) -> rusoto_core::pagination::RusotoStream<TypedLinkSpecifier, ListOutgoingTypedLinksError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_outgoing_typed_links_pages(input),
+            |output: ListOutgoingTypedLinksResponse| {
+                output.typed_link_specifiers.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_policy_attachments`, streaming every page of results.
+    pub fn list_policy_attachments_pages(
+        &self,
+        input: ListPolicyAttachmentsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListPolicyAttachmentsResponse,
+        ListPolicyAttachmentsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudDirectoryClient, input: ListPolicyAttachmentsRequest| async move {
+                client.list_policy_attachments(input).await
+            },
+            |input: &ListPolicyAttachmentsRequest, output: &ListPolicyAttachmentsResponse| {
+                Some(ListPolicyAttachmentsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_policy_attachments`, streaming the `ObjectIdentifiers` of every page of results.
+    pub fn list_policy_attachments_items(
+        &self,
+        input: ListPolicyAttachmentsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListPolicyAttachmentsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_policy_attachments_pages(input),
+            |output: ListPolicyAttachmentsResponse| output.object_identifiers.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_published_schema_arns`, streaming every page of results.
+    pub fn list_published_schema_arns_pages(
+        &self,
+        input: ListPublishedSchemaArnsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListPublishedSchemaArnsResponse,
+        ListPublishedSchemaArnsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudDirectoryClient, input: ListPublishedSchemaArnsRequest| async move {
+                client.list_published_schema_arns(input).await
+            },
+            |input: &ListPublishedSchemaArnsRequest, output: &ListPublishedSchemaArnsResponse| {
+                Some(ListPublishedSchemaArnsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_published_schema_arns`, streaming the `SchemaArns` of every page of results.
+    pub fn list_published_schema_arns_items(
+        &self,
+        input: ListPublishedSchemaArnsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListPublishedSchemaArnsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_published_schema_arns_pages(input),
+            |output: ListPublishedSchemaArnsResponse| output.schema_arns.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_tags_for_resource`, streaming every page of results.
+    pub fn list_tags_for_resource_pages(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListTagsForResourceResponse, ListTagsForResourceError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudDirectoryClient, input: ListTagsForResourceRequest| async move {
+                client.list_tags_for_resource(input).await
+            },
+            |input: &ListTagsForResourceRequest, output: &ListTagsForResourceResponse| {
+                Some(ListTagsForResourceRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_tags_for_resource`, streaming the `Tags` of every page of results.
+    pub fn list_tags_for_resource_items(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Tag, ListTagsForResourceError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_tags_for_resource_pages(input),
+            |output: ListTagsForResourceResponse| output.tags.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_typed_link_facet_attributes`, streaming every page of results.
+    pub fn list_typed_link_facet_attributes_pages(
+        &self,
+        input: ListTypedLinkFacetAttributesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListTypedLinkFacetAttributesResponse,
+        ListTypedLinkFacetAttributesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudDirectoryClient, input: ListTypedLinkFacetAttributesRequest| async move {
+                client.list_typed_link_facet_attributes(input).await
+            },
+            |input: &ListTypedLinkFacetAttributesRequest,
+             output: &ListTypedLinkFacetAttributesResponse| {
+                Some(ListTypedLinkFacetAttributesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_typed_link_facet_attributes`, streaming the `Attributes` of every page of results.
+    pub fn list_typed_link_facet_attributes_items(
+        &self,
+        input: ListTypedLinkFacetAttributesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        TypedLinkAttributeDefinition,
+        ListTypedLinkFacetAttributesError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_typed_link_facet_attributes_pages(input),
+            |output: ListTypedLinkFacetAttributesResponse| output.attributes.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_typed_link_facet_names`, streaming every page of results.
+    pub fn list_typed_link_facet_names_pages(
+        &self,
+        input: ListTypedLinkFacetNamesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListTypedLinkFacetNamesResponse,
+        ListTypedLinkFacetNamesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudDirectoryClient, input: ListTypedLinkFacetNamesRequest| async move {
+                client.list_typed_link_facet_names(input).await
+            },
+            |input: &ListTypedLinkFacetNamesRequest, output: &ListTypedLinkFacetNamesResponse| {
+                Some(ListTypedLinkFacetNamesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_typed_link_facet_names`, streaming the `FacetNames` of every page of results.
+    pub fn list_typed_link_facet_names_items(
+        &self,
+        input: ListTypedLinkFacetNamesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListTypedLinkFacetNamesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_typed_link_facet_names_pages(input),
+            |output: ListTypedLinkFacetNamesResponse| output.facet_names.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `lookup_policy`, streaming every page of results.
+    pub fn lookup_policy_pages(
+        &self,
+        input: LookupPolicyRequest,
+    ) -> rusoto_core::pagination::RusotoStream<LookupPolicyResponse, LookupPolicyError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudDirectoryClient, input: LookupPolicyRequest| async move {
+                client.lookup_policy(input).await
+            },
+            |input: &LookupPolicyRequest, output: &LookupPolicyResponse| {
+                Some(LookupPolicyRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `lookup_policy`, streaming the `PolicyToPathList` of every page of results.
+    pub fn lookup_policy_items(
+        &self,
+        input: LookupPolicyRequest,
+    ) -> rusoto_core::pagination::RusotoStream<PolicyToPath, LookupPolicyError> {
+        rusoto_core::pagination::paginate_items(
+            self.lookup_policy_pages(input),
+            |output: LookupPolicyResponse| output.policy_to_path_list.unwrap_or_default(),
+        )
+    }
+}
@@ -48,7 +48,7 @@ impl AccountDeserializer {
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct AccountGateResult {
     /// <p><p>The status of the account gate function.</p> <ul> <li> <p> <code>SUCCEEDED</code>: The account gate function has determined that the account and region passes any requirements for a stack set operation to occur. AWS CloudFormation proceeds with the stack operation in that account and region. </p> </li> <li> <p> <code>FAILED</code>: The account gate function has determined that the account and region does not meet the requirements for a stack set operation to occur. AWS CloudFormation cancels the stack set operation in that account and region, and sets the stack set operation result status for that account and region to <code>FAILED</code>. </p> </li> <li> <p> <code>SKIPPED</code>: AWS CloudFormation has skipped calling the account gate function for this account and region, for one of the following reasons:</p> <ul> <li> <p>An account gate function has not been specified for the account and region. AWS CloudFormation proceeds with the stack set operation in this account and region.</p> </li> <li> <p>The <code>AWSCloudFormationStackSetExecutionRole</code> of the stack set adminstration account lacks permissions to invoke the function. AWS CloudFormation proceeds with the stack set operation in this account and region.</p> </li> <li> <p>Either no action is necessary, or no action is possible, on the stack. AWS CloudFormation skips the stack set operation in this account and region.</p> </li> </ul> </li> </ul></p>
-    pub status: Option<String>,
+    pub status: Option<AccountGateStatus>,
     /// <p>The reason for the account gate status assigned to this account and region for the stack set operation.</p>
     pub status_reason: Option<String>,
 }
@@ -77,12 +77,115 @@ impl AccountGateResultDeserializer {
         })
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AccountGateStatus {
+    /// `SUCCEEDED`
+    Succeeded,
+    /// `FAILED`
+    Failed,
+    /// `SKIPPED`
+    Skipped,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AccountGateStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AccountGateStatus::Succeeded => "SUCCEEDED",
+            AccountGateStatus::Failed => "FAILED",
+            AccountGateStatus::Skipped => "SKIPPED",
+            AccountGateStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AccountGateStatus {
+    fn default() -> AccountGateStatus {
+        AccountGateStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AccountGateStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AccountGateStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AccountGateStatus {
+    fn from(value: &'a str) -> AccountGateStatus {
+        match value {
+            "SUCCEEDED" => AccountGateStatus::Succeeded,
+            "FAILED" => AccountGateStatus::Failed,
+            "SKIPPED" => AccountGateStatus::Skipped,
+            _ => AccountGateStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AccountGateStatus {
+    fn from(value: String) -> AccountGateStatus {
+        match value.as_str() {
+            "SUCCEEDED" => AccountGateStatus::Succeeded,
+            "FAILED" => AccountGateStatus::Failed,
+            "SKIPPED" => AccountGateStatus::Skipped,
+            _ => AccountGateStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AccountGateStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AccountGateStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AccountGateStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for AccountGateStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for AccountGateStatus {
+    fn deserialize<D>(deserializer: D) -> Result<AccountGateStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct AccountGateStatusDeserializer;
 impl AccountGateStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<AccountGateStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -241,7 +344,7 @@ impl CapabilitiesDeserializer {
     fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
-    ) -> Result<Vec<String>, XmlParseError> {
+    ) -> Result<Vec<Capability>, XmlParseError> {
         deserialize_elements::<_, Vec<_>, _>(tag_name, stack, |name, stack, obj| {
             if name == "member" {
                 obj.push(CapabilityDeserializer::deserialize("member", stack)?);
@@ -256,7 +359,7 @@ impl CapabilitiesDeserializer {
 /// Serialize `Capabilities` contents to a `SignedRequest`.
 struct CapabilitiesSerializer;
 impl CapabilitiesSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &Vec<String>) {
+    fn serialize(params: &mut Params, name: &str, obj: &Vec<Capability>) {
         for (index, obj) in obj.iter().enumerate() {
             let key = format!("{}.member.{}", name, index + 1);
             params.put(&key, &obj);
@@ -275,12 +378,115 @@ impl CapabilitiesReasonDeserializer {
         Ok(obj)
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Capability {
+    /// `CAPABILITY_IAM`
+    CapabilityIam,
+    /// `CAPABILITY_NAMED_IAM`
+    CapabilityNamedIam,
+    /// `CAPABILITY_AUTO_EXPAND`
+    CapabilityAutoExpand,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Capability {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Capability::CapabilityIam => "CAPABILITY_IAM",
+            Capability::CapabilityNamedIam => "CAPABILITY_NAMED_IAM",
+            Capability::CapabilityAutoExpand => "CAPABILITY_AUTO_EXPAND",
+            Capability::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Capability {
+    fn default() -> Capability {
+        Capability::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Capability {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Capability {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Capability {
+    fn from(value: &'a str) -> Capability {
+        match value {
+            "CAPABILITY_IAM" => Capability::CapabilityIam,
+            "CAPABILITY_NAMED_IAM" => Capability::CapabilityNamedIam,
+            "CAPABILITY_AUTO_EXPAND" => Capability::CapabilityAutoExpand,
+            _ => Capability::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Capability {
+    fn from(value: String) -> Capability {
+        match value.as_str() {
+            "CAPABILITY_IAM" => Capability::CapabilityIam,
+            "CAPABILITY_NAMED_IAM" => Capability::CapabilityNamedIam,
+            "CAPABILITY_AUTO_EXPAND" => Capability::CapabilityAutoExpand,
+            _ => Capability::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Capability {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Capability, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Capability {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for Capability {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for Capability {
+    fn deserialize<D>(deserializer: D) -> Result<Capability, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct CapabilityDeserializer;
 impl CapabilityDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Capability, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -304,7 +510,7 @@ pub struct Change {
     /// <p>A <code>ResourceChange</code> structure that describes the resource and action that AWS CloudFormation will perform.</p>
     pub resource_change: Option<ResourceChange>,
     /// <p>The type of entity that AWS CloudFormation changes. Currently, the only entity type is <code>Resource</code>.</p>
-    pub type_: Option<String>,
+    pub type_: Option<ChangeType>,
 }
 
 struct ChangeDeserializer;
@@ -328,12 +534,125 @@ impl ChangeDeserializer {
         })
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ChangeAction {
+    /// `Add`
+    Add,
+    /// `Modify`
+    Modify,
+    /// `Remove`
+    Remove,
+    /// `Import`
+    Import,
+    /// `Dynamic`
+    Dynamic,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ChangeAction {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ChangeAction::Add => "Add",
+            ChangeAction::Modify => "Modify",
+            ChangeAction::Remove => "Remove",
+            ChangeAction::Import => "Import",
+            ChangeAction::Dynamic => "Dynamic",
+            ChangeAction::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ChangeAction {
+    fn default() -> ChangeAction {
+        ChangeAction::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ChangeAction {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ChangeAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ChangeAction {
+    fn from(value: &'a str) -> ChangeAction {
+        match value {
+            "Add" => ChangeAction::Add,
+            "Modify" => ChangeAction::Modify,
+            "Remove" => ChangeAction::Remove,
+            "Import" => ChangeAction::Import,
+            "Dynamic" => ChangeAction::Dynamic,
+            _ => ChangeAction::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ChangeAction {
+    fn from(value: String) -> ChangeAction {
+        match value.as_str() {
+            "Add" => ChangeAction::Add,
+            "Modify" => ChangeAction::Modify,
+            "Remove" => ChangeAction::Remove,
+            "Import" => ChangeAction::Import,
+            "Dynamic" => ChangeAction::Dynamic,
+            _ => ChangeAction::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ChangeAction {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ChangeAction, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ChangeAction {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ChangeAction {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ChangeAction {
+    fn deserialize<D>(deserializer: D) -> Result<ChangeAction, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct ChangeActionDeserializer;
 impl ChangeActionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ChangeAction, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -361,12 +680,140 @@ impl ChangeSetNameDeserializer {
         Ok(obj)
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ChangeSetStatus {
+    /// `CREATE_PENDING`
+    CreatePending,
+    /// `CREATE_IN_PROGRESS`
+    CreateInProgress,
+    /// `CREATE_COMPLETE`
+    CreateComplete,
+    /// `DELETE_PENDING`
+    DeletePending,
+    /// `DELETE_IN_PROGRESS`
+    DeleteInProgress,
+    /// `DELETE_COMPLETE`
+    DeleteComplete,
+    /// `DELETE_FAILED`
+    DeleteFailed,
+    /// `FAILED`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ChangeSetStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ChangeSetStatus::CreatePending => "CREATE_PENDING",
+            ChangeSetStatus::CreateInProgress => "CREATE_IN_PROGRESS",
+            ChangeSetStatus::CreateComplete => "CREATE_COMPLETE",
+            ChangeSetStatus::DeletePending => "DELETE_PENDING",
+            ChangeSetStatus::DeleteInProgress => "DELETE_IN_PROGRESS",
+            ChangeSetStatus::DeleteComplete => "DELETE_COMPLETE",
+            ChangeSetStatus::DeleteFailed => "DELETE_FAILED",
+            ChangeSetStatus::Failed => "FAILED",
+            ChangeSetStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ChangeSetStatus {
+    fn default() -> ChangeSetStatus {
+        ChangeSetStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ChangeSetStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ChangeSetStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ChangeSetStatus {
+    fn from(value: &'a str) -> ChangeSetStatus {
+        match value {
+            "CREATE_PENDING" => ChangeSetStatus::CreatePending,
+            "CREATE_IN_PROGRESS" => ChangeSetStatus::CreateInProgress,
+            "CREATE_COMPLETE" => ChangeSetStatus::CreateComplete,
+            "DELETE_PENDING" => ChangeSetStatus::DeletePending,
+            "DELETE_IN_PROGRESS" => ChangeSetStatus::DeleteInProgress,
+            "DELETE_COMPLETE" => ChangeSetStatus::DeleteComplete,
+            "DELETE_FAILED" => ChangeSetStatus::DeleteFailed,
+            "FAILED" => ChangeSetStatus::Failed,
+            _ => ChangeSetStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ChangeSetStatus {
+    fn from(value: String) -> ChangeSetStatus {
+        match value.as_str() {
+            "CREATE_PENDING" => ChangeSetStatus::CreatePending,
+            "CREATE_IN_PROGRESS" => ChangeSetStatus::CreateInProgress,
+            "CREATE_COMPLETE" => ChangeSetStatus::CreateComplete,
+            "DELETE_PENDING" => ChangeSetStatus::DeletePending,
+            "DELETE_IN_PROGRESS" => ChangeSetStatus::DeleteInProgress,
+            "DELETE_COMPLETE" => ChangeSetStatus::DeleteComplete,
+            "DELETE_FAILED" => ChangeSetStatus::DeleteFailed,
+            "FAILED" => ChangeSetStatus::Failed,
+            _ => ChangeSetStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ChangeSetStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ChangeSetStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ChangeSetStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ChangeSetStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ChangeSetStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ChangeSetStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct ChangeSetStatusDeserializer;
 impl ChangeSetStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ChangeSetStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -409,17 +856,17 @@ pub struct ChangeSetSummary {
     /// <p>The name of the change set.</p>
     pub change_set_name: Option<String>,
     /// <p>The start time when the change set was created, in UTC.</p>
-    pub creation_time: Option<String>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Descriptive information about the change set.</p>
     pub description: Option<String>,
     /// <p>If the change set execution status is <code>AVAILABLE</code>, you can execute the change set. If you can’t execute the change set, the status indicates why. For example, a change set might be in an <code>UNAVAILABLE</code> state because AWS CloudFormation is still creating it or in an <code>OBSOLETE</code> state because the stack was already updated.</p>
-    pub execution_status: Option<String>,
+    pub execution_status: Option<ExecutionStatus>,
     /// <p>The ID of the stack with which the change set is associated.</p>
     pub stack_id: Option<String>,
     /// <p>The name of the stack with which the change set is associated.</p>
     pub stack_name: Option<String>,
     /// <p>The state of the change set, such as <code>CREATE_IN_PROGRESS</code>, <code>CREATE_COMPLETE</code>, or <code>FAILED</code>.</p>
-    pub status: Option<String>,
+    pub status: Option<ChangeSetStatus>,
     /// <p>A description of the change set's status. For example, if your change set is in the <code>FAILED</code> state, AWS CloudFormation shows the error message.</p>
     pub status_reason: Option<String>,
 }
@@ -480,88 +927,394 @@ impl ChangeSetSummaryDeserializer {
         })
     }
 }
-struct ChangeSourceDeserializer;
-impl ChangeSourceDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
-        start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
-        end_element(tag_name, stack)?;
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ChangeSetType {
+    /// `CREATE`
+    Create,
+    /// `UPDATE`
+    Update,
+    /// `IMPORT`
+    Import,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
 
-        Ok(obj)
+impl ChangeSetType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ChangeSetType::Create => "CREATE",
+            ChangeSetType::Update => "UPDATE",
+            ChangeSetType::Import => "IMPORT",
+            ChangeSetType::Unknown(ref value) => value,
+        }
     }
 }
-struct ChangeTypeDeserializer;
-impl ChangeTypeDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
-        start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
-        end_element(tag_name, stack)?;
 
-        Ok(obj)
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ChangeSetType {
+    fn default() -> ChangeSetType {
+        ChangeSetType::Unknown(String::new())
     }
 }
-struct ChangesDeserializer;
-impl ChangesDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
-        tag_name: &str,
-        stack: &mut T,
-    ) -> Result<Vec<Change>, XmlParseError> {
-        deserialize_elements::<_, Vec<_>, _>(tag_name, stack, |name, stack, obj| {
-            if name == "member" {
-                obj.push(ChangeDeserializer::deserialize("member", stack)?);
-            } else {
-                skip_tree(stack);
-            }
-            Ok(())
-        })
+
+impl AsRef<str> for ChangeSetType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
     }
 }
-struct ClientRequestTokenDeserializer;
-impl ClientRequestTokenDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
-        start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
-        end_element(tag_name, stack)?;
 
-        Ok(obj)
+impl fmt::Display for ChangeSetType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
     }
 }
-/// <p>The input for the <a>ContinueUpdateRollback</a> action.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct ContinueUpdateRollbackInput {
-    /// <p>A unique identifier for this <code>ContinueUpdateRollback</code> request. Specify this token if you plan to retry requests so that AWS CloudFormation knows that you're not attempting to continue the rollback to a stack with the same name. You might retry <code>ContinueUpdateRollback</code> requests to ensure that AWS CloudFormation successfully received them.</p>
-    pub client_request_token: Option<String>,
-    /// <p><p>A list of the logical IDs of the resources that AWS CloudFormation skips during the continue update rollback operation. You can specify only resources that are in the <code>UPDATE<em>FAILED</code> state because a rollback failed. You can&#39;t specify resources that are in the <code>UPDATE</em>FAILED</code> state for other reasons, for example, because an update was cancelled. To check why a resource update failed, use the <a>DescribeStackResources</a> action, and view the resource status reason. </p> <important> <p>Specify this property to skip rolling back resources that AWS CloudFormation can&#39;t successfully roll back. We recommend that you <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/troubleshooting.html#troubleshooting-errors-update-rollback-failed"> troubleshoot</a> resources before skipping them. AWS CloudFormation sets the status of the specified resources to <code>UPDATE<em>COMPLETE</code> and continues to roll back the stack. After the rollback is complete, the state of the skipped resources will be inconsistent with the state of the resources in the stack template. Before performing another stack update, you must update the stack or resources to be consistent with each other. If you don&#39;t, subsequent stack updates might fail, and the stack will become unrecoverable. </p> </important> <p>Specify the minimum number of resources required to successfully roll back your stack. For example, a failed resource update might cause dependent resources to fail. In this case, it might not be necessary to skip the dependent resources. </p> <p>To skip resources that are part of nested stacks, use the following format: <code>NestedStackName.ResourceLogicalID</code>. If you want to specify the logical ID of a stack resource (<code>Type: AWS::CloudFormation::Stack</code>) in the <code>ResourcesToSkip</code> list, then its corresponding embedded stack must be in one of the following states: <code>DELETE</em>IN<em>PROGRESS</code>, <code>DELETE</em>COMPLETE</code>, or <code>DELETE_FAILED</code>. </p> <note> <p>Don&#39;t confuse a child stack&#39;s name with its corresponding logical ID defined in the parent stack. For an example of a continue update rollback operation with nested stacks, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-updating-stacks-continueupdaterollback.html#nested-stacks">Using ResourcesToSkip to recover a nested stacks hierarchy</a>. </p> </note></p>
-    pub resources_to_skip: Option<Vec<String>>,
-    /// <p>The Amazon Resource Name (ARN) of an AWS Identity and Access Management (IAM) role that AWS CloudFormation assumes to roll back the stack. AWS CloudFormation uses the role's credentials to make calls on your behalf. AWS CloudFormation always uses this role for all future operations on the stack. As long as users have permission to operate on the stack, AWS CloudFormation uses this role even if the users don't have permission to pass it. Ensure that the role grants least privilege.</p> <p>If you don't specify a value, AWS CloudFormation uses the role that was previously associated with the stack. If no role is available, AWS CloudFormation uses a temporary session that is generated from your user credentials.</p>
-    pub role_arn: Option<String>,
-    /// <p><p>The name or the unique ID of the stack that you want to continue rolling back.</p> <note> <p>Don&#39;t specify the name of a nested stack (a stack that was created by using the <code>AWS::CloudFormation::Stack</code> resource). Instead, use this operation on the parent stack (the stack that contains the <code>AWS::CloudFormation::Stack</code> resource).</p> </note></p>
-    pub stack_name: String,
-}
 
-/// Serialize `ContinueUpdateRollbackInput` contents to a `SignedRequest`.
-struct ContinueUpdateRollbackInputSerializer;
-impl ContinueUpdateRollbackInputSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &ContinueUpdateRollbackInput) {
-        let mut prefix = name.to_string();
-        if prefix != "" {
-            prefix.push_str(".");
+impl<'a> From<&'a str> for ChangeSetType {
+    fn from(value: &'a str) -> ChangeSetType {
+        match value {
+            "CREATE" => ChangeSetType::Create,
+            "UPDATE" => ChangeSetType::Update,
+            "IMPORT" => ChangeSetType::Import,
+            _ => ChangeSetType::Unknown(value.to_owned()),
         }
+    }
+}
 
-        if let Some(ref field_value) = obj.client_request_token {
-            params.put(&format!("{}{}", prefix, "ClientRequestToken"), &field_value);
+impl From<String> for ChangeSetType {
+    fn from(value: String) -> ChangeSetType {
+        match value.as_str() {
+            "CREATE" => ChangeSetType::Create,
+            "UPDATE" => ChangeSetType::Update,
+            "IMPORT" => ChangeSetType::Import,
+            _ => ChangeSetType::Unknown(value),
         }
-        if let Some(ref field_value) = obj.resources_to_skip {
-            ResourcesToSkipSerializer::serialize(
-                params,
-                &format!("{}{}", prefix, "ResourcesToSkip"),
-                field_value,
-            );
+    }
+}
+
+impl ::std::str::FromStr for ChangeSetType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ChangeSetType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ChangeSetType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ChangeSetType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ChangeSetType {
+    fn deserialize<D>(deserializer: D) -> Result<ChangeSetType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ChangeSource {
+    /// `ResourceReference`
+    ResourceReference,
+    /// `ParameterReference`
+    ParameterReference,
+    /// `ResourceAttribute`
+    ResourceAttribute,
+    /// `DirectModification`
+    DirectModification,
+    /// `Automatic`
+    Automatic,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ChangeSource {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ChangeSource::ResourceReference => "ResourceReference",
+            ChangeSource::ParameterReference => "ParameterReference",
+            ChangeSource::ResourceAttribute => "ResourceAttribute",
+            ChangeSource::DirectModification => "DirectModification",
+            ChangeSource::Automatic => "Automatic",
+            ChangeSource::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ChangeSource {
+    fn default() -> ChangeSource {
+        ChangeSource::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ChangeSource {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ChangeSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ChangeSource {
+    fn from(value: &'a str) -> ChangeSource {
+        match value {
+            "ResourceReference" => ChangeSource::ResourceReference,
+            "ParameterReference" => ChangeSource::ParameterReference,
+            "ResourceAttribute" => ChangeSource::ResourceAttribute,
+            "DirectModification" => ChangeSource::DirectModification,
+            "Automatic" => ChangeSource::Automatic,
+            _ => ChangeSource::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ChangeSource {
+    fn from(value: String) -> ChangeSource {
+        match value.as_str() {
+            "ResourceReference" => ChangeSource::ResourceReference,
+            "ParameterReference" => ChangeSource::ParameterReference,
+            "ResourceAttribute" => ChangeSource::ResourceAttribute,
+            "DirectModification" => ChangeSource::DirectModification,
+            "Automatic" => ChangeSource::Automatic,
+            _ => ChangeSource::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ChangeSource {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ChangeSource, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ChangeSource {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ChangeSource {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ChangeSource {
+    fn deserialize<D>(deserializer: D) -> Result<ChangeSource, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+struct ChangeSourceDeserializer;
+impl ChangeSourceDeserializer {
+    #[allow(unused_variables)]
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ChangeSource, XmlParseError> {
+        start_element(tag_name, stack)?;
+        let obj = characters(stack)?.into();
+        end_element(tag_name, stack)?;
+
+        Ok(obj)
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ChangeType {
+    /// `Resource`
+    Resource,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ChangeType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ChangeType::Resource => "Resource",
+            ChangeType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ChangeType {
+    fn default() -> ChangeType {
+        ChangeType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ChangeType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ChangeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ChangeType {
+    fn from(value: &'a str) -> ChangeType {
+        match value {
+            "Resource" => ChangeType::Resource,
+            _ => ChangeType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ChangeType {
+    fn from(value: String) -> ChangeType {
+        match value.as_str() {
+            "Resource" => ChangeType::Resource,
+            _ => ChangeType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ChangeType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ChangeType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ChangeType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ChangeType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ChangeType {
+    fn deserialize<D>(deserializer: D) -> Result<ChangeType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+struct ChangeTypeDeserializer;
+impl ChangeTypeDeserializer {
+    #[allow(unused_variables)]
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ChangeType, XmlParseError> {
+        start_element(tag_name, stack)?;
+        let obj = characters(stack)?.into();
+        end_element(tag_name, stack)?;
+
+        Ok(obj)
+    }
+}
+struct ChangesDeserializer;
+impl ChangesDeserializer {
+    #[allow(unused_variables)]
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Vec<Change>, XmlParseError> {
+        deserialize_elements::<_, Vec<_>, _>(tag_name, stack, |name, stack, obj| {
+            if name == "member" {
+                obj.push(ChangeDeserializer::deserialize("member", stack)?);
+            } else {
+                skip_tree(stack);
+            }
+            Ok(())
+        })
+    }
+}
+struct ClientRequestTokenDeserializer;
+impl ClientRequestTokenDeserializer {
+    #[allow(unused_variables)]
+    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+        start_element(tag_name, stack)?;
+        let obj = characters(stack)?;
+        end_element(tag_name, stack)?;
+
+        Ok(obj)
+    }
+}
+/// <p>The input for the <a>ContinueUpdateRollback</a> action.</p>
+#[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct ContinueUpdateRollbackInput {
+    /// <p>A unique identifier for this <code>ContinueUpdateRollback</code> request. Specify this token if you plan to retry requests so that AWS CloudFormation knows that you're not attempting to continue the rollback to a stack with the same name. You might retry <code>ContinueUpdateRollback</code> requests to ensure that AWS CloudFormation successfully received them.</p>
+    pub client_request_token: Option<String>,
+    /// <p><p>A list of the logical IDs of the resources that AWS CloudFormation skips during the continue update rollback operation. You can specify only resources that are in the <code>UPDATE<em>FAILED</code> state because a rollback failed. You can&#39;t specify resources that are in the <code>UPDATE</em>FAILED</code> state for other reasons, for example, because an update was cancelled. To check why a resource update failed, use the <a>DescribeStackResources</a> action, and view the resource status reason. </p> <important> <p>Specify this property to skip rolling back resources that AWS CloudFormation can&#39;t successfully roll back. We recommend that you <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/troubleshooting.html#troubleshooting-errors-update-rollback-failed"> troubleshoot</a> resources before skipping them. AWS CloudFormation sets the status of the specified resources to <code>UPDATE<em>COMPLETE</code> and continues to roll back the stack. After the rollback is complete, the state of the skipped resources will be inconsistent with the state of the resources in the stack template. Before performing another stack update, you must update the stack or resources to be consistent with each other. If you don&#39;t, subsequent stack updates might fail, and the stack will become unrecoverable. </p> </important> <p>Specify the minimum number of resources required to successfully roll back your stack. For example, a failed resource update might cause dependent resources to fail. In this case, it might not be necessary to skip the dependent resources. </p> <p>To skip resources that are part of nested stacks, use the following format: <code>NestedStackName.ResourceLogicalID</code>. If you want to specify the logical ID of a stack resource (<code>Type: AWS::CloudFormation::Stack</code>) in the <code>ResourcesToSkip</code> list, then its corresponding embedded stack must be in one of the following states: <code>DELETE</em>IN<em>PROGRESS</code>, <code>DELETE</em>COMPLETE</code>, or <code>DELETE_FAILED</code>. </p> <note> <p>Don&#39;t confuse a child stack&#39;s name with its corresponding logical ID defined in the parent stack. For an example of a continue update rollback operation with nested stacks, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-updating-stacks-continueupdaterollback.html#nested-stacks">Using ResourcesToSkip to recover a nested stacks hierarchy</a>. </p> </note></p>
+    pub resources_to_skip: Option<Vec<String>>,
+    /// <p>The Amazon Resource Name (ARN) of an AWS Identity and Access Management (IAM) role that AWS CloudFormation assumes to roll back the stack. AWS CloudFormation uses the role's credentials to make calls on your behalf. AWS CloudFormation always uses this role for all future operations on the stack. As long as users have permission to operate on the stack, AWS CloudFormation uses this role even if the users don't have permission to pass it. Ensure that the role grants least privilege.</p> <p>If you don't specify a value, AWS CloudFormation uses the role that was previously associated with the stack. If no role is available, AWS CloudFormation uses a temporary session that is generated from your user credentials.</p>
+    pub role_arn: Option<String>,
+    /// <p><p>The name or the unique ID of the stack that you want to continue rolling back.</p> <note> <p>Don&#39;t specify the name of a nested stack (a stack that was created by using the <code>AWS::CloudFormation::Stack</code> resource). Instead, use this operation on the parent stack (the stack that contains the <code>AWS::CloudFormation::Stack</code> resource).</p> </note></p>
+    pub stack_name: String,
+}
+
+/// Serialize `ContinueUpdateRollbackInput` contents to a `SignedRequest`.
+struct ContinueUpdateRollbackInputSerializer;
+impl ContinueUpdateRollbackInputSerializer {
+    fn serialize(params: &mut Params, name: &str, obj: &ContinueUpdateRollbackInput) {
+        let mut prefix = name.to_string();
+        if prefix != "" {
+            prefix.push_str(".");
+        }
+
+        if let Some(ref field_value) = obj.client_request_token {
+            params.put(&format!("{}{}", prefix, "ClientRequestToken"), &field_value);
+        }
+        if let Some(ref field_value) = obj.resources_to_skip {
+            ResourcesToSkipSerializer::serialize(
+                params,
+                &format!("{}{}", prefix, "ResourcesToSkip"),
+                field_value,
+            );
         }
         if let Some(ref field_value) = obj.role_arn {
             params.put(&format!("{}{}", prefix, "RoleARN"), &field_value);
@@ -596,11 +1349,11 @@ impl ContinueUpdateRollbackOutputDeserializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateChangeSetInput {
     /// <p><p>In some cases, you must explicitly acknowledge that your stack template contains certain capabilities in order for AWS CloudFormation to create the stack.</p> <ul> <li> <p> <code>CAPABILITY<em>IAM</code> and <code>CAPABILITY</em>NAMED<em>IAM</code> </p> <p>Some stack templates might include resources that can affect permissions in your AWS account; for example, by creating new AWS Identity and Access Management (IAM) users. For those stacks, you must explicitly acknowledge this by specifying one of these capabilities.</p> <p>The following IAM resources require you to specify either the <code>CAPABILITY</em>IAM</code> or <code>CAPABILITY<em>NAMED</em>IAM</code> capability.</p> <ul> <li> <p>If you have IAM resources, you can specify either capability. </p> </li> <li> <p>If you have IAM resources with custom names, you <i>must</i> specify <code>CAPABILITY<em>NAMED</em>IAM</code>. </p> </li> <li> <p>If you don&#39;t specify either of these capabilities, AWS CloudFormation returns an <code>InsufficientCapabilities</code> error.</p> </li> </ul> <p>If your stack template contains these resources, we recommend that you review all permissions associated with them and edit their permissions if necessary.</p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-accesskey.html"> AWS::IAM::AccessKey</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-group.html"> AWS::IAM::Group</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-resource-iam-instanceprofile.html"> AWS::IAM::InstanceProfile</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-policy.html"> AWS::IAM::Policy</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-resource-iam-role.html"> AWS::IAM::Role</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-user.html"> AWS::IAM::User</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-addusertogroup.html"> AWS::IAM::UserToGroupAddition</a> </p> </li> </ul> <p>For more information, see <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-iam-template.html#capabilities">Acknowledging IAM Resources in AWS CloudFormation Templates</a>.</p> </li> <li> <p> <code>CAPABILITY<em>AUTO</em>EXPAND</code> </p> <p>Some template contain macros. Macros perform custom processing on templates; this can include simple actions like find-and-replace operations, all the way to extensive transformations of entire templates. Because of this, users typically create a change set from the processed template, so that they can review the changes resulting from the macros before actually creating the stack. If your stack template contains one or more macros, and you choose to create a stack directly from the processed template, without first reviewing the resulting changes in a change set, you must acknowledge this capability. This includes the <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/create-reusable-transform-function-snippets-and-add-to-your-template-with-aws-include-transform.html">AWS::Include</a> and <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/transform-aws-serverless.html">AWS::Serverless</a> transforms, which are macros hosted by AWS CloudFormation.</p> <note> <p>This capacity does not apply to creating change sets, and specifying it when creating change sets has no effect.</p> <p>Also, change sets do not currently support nested stacks. If you want to create a stack from a stack template that contains macros <i>and</i> nested stacks, you must create or update the stack directly from the template using the <a>CreateStack</a> or <a>UpdateStack</a> action, and specifying this capability.</p> </note> <p>For more information on macros, see <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/template-macros.html">Using AWS CloudFormation Macros to Perform Custom Processing on Templates</a>.</p> </li> </ul></p>
-    pub capabilities: Option<Vec<String>>,
+    pub capabilities: Option<Vec<Capability>>,
     /// <p>The name of the change set. The name must be unique among all change sets that are associated with the specified stack.</p> <p>A change set name can contain only alphanumeric, case sensitive characters and hyphens. It must start with an alphabetic character and cannot exceed 128 characters.</p>
     pub change_set_name: String,
     /// <p>The type of change set operation. To create a change set for a new stack, specify <code>CREATE</code>. To create a change set for an existing stack, specify <code>UPDATE</code>. To create a change set for an import operation, specify <code>IMPORT</code>.</p> <p>If you create a change set for a new stack, AWS Cloudformation creates a stack with a unique stack ID, but no template or resources. The stack will be in the <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-describing-stacks.html#d0e11995"> <code>REVIEW_IN_PROGRESS</code> </a> state until you execute the change set.</p> <p>By default, AWS CloudFormation specifies <code>UPDATE</code>. You can't use the <code>UPDATE</code> type to create a change set for a new stack or the <code>CREATE</code> type to create a change set for an existing stack.</p>
-    pub change_set_type: Option<String>,
+    pub change_set_type: Option<ChangeSetType>,
     /// <p>A unique identifier for this <code>CreateChangeSet</code> request. Specify this token if you plan to retry requests so that AWS CloudFormation knows that you're not attempting to create another change set with the same name. You might retry <code>CreateChangeSet</code> requests to ensure that AWS CloudFormation successfully received them.</p>
     pub client_token: Option<String>,
     /// <p>A description to help you identify this change set.</p>
@@ -751,7 +1504,7 @@ impl CreateChangeSetOutputDeserializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateStackInput {
     /// <p><p>In some cases, you must explicitly acknowledge that your stack template contains certain capabilities in order for AWS CloudFormation to create the stack.</p> <ul> <li> <p> <code>CAPABILITY<em>IAM</code> and <code>CAPABILITY</em>NAMED<em>IAM</code> </p> <p>Some stack templates might include resources that can affect permissions in your AWS account; for example, by creating new AWS Identity and Access Management (IAM) users. For those stacks, you must explicitly acknowledge this by specifying one of these capabilities.</p> <p>The following IAM resources require you to specify either the <code>CAPABILITY</em>IAM</code> or <code>CAPABILITY<em>NAMED</em>IAM</code> capability.</p> <ul> <li> <p>If you have IAM resources, you can specify either capability. </p> </li> <li> <p>If you have IAM resources with custom names, you <i>must</i> specify <code>CAPABILITY<em>NAMED</em>IAM</code>. </p> </li> <li> <p>If you don&#39;t specify either of these capabilities, AWS CloudFormation returns an <code>InsufficientCapabilities</code> error.</p> </li> </ul> <p>If your stack template contains these resources, we recommend that you review all permissions associated with them and edit their permissions if necessary.</p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-accesskey.html"> AWS::IAM::AccessKey</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-group.html"> AWS::IAM::Group</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-resource-iam-instanceprofile.html"> AWS::IAM::InstanceProfile</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-policy.html"> AWS::IAM::Policy</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-resource-iam-role.html"> AWS::IAM::Role</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-user.html"> AWS::IAM::User</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-addusertogroup.html"> AWS::IAM::UserToGroupAddition</a> </p> </li> </ul> <p>For more information, see <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-iam-template.html#capabilities">Acknowledging IAM Resources in AWS CloudFormation Templates</a>.</p> </li> <li> <p> <code>CAPABILITY<em>AUTO</em>EXPAND</code> </p> <p>Some template contain macros. Macros perform custom processing on templates; this can include simple actions like find-and-replace operations, all the way to extensive transformations of entire templates. Because of this, users typically create a change set from the processed template, so that they can review the changes resulting from the macros before actually creating the stack. If your stack template contains one or more macros, and you choose to create a stack directly from the processed template, without first reviewing the resulting changes in a change set, you must acknowledge this capability. This includes the <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/create-reusable-transform-function-snippets-and-add-to-your-template-with-aws-include-transform.html">AWS::Include</a> and <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/transform-aws-serverless.html">AWS::Serverless</a> transforms, which are macros hosted by AWS CloudFormation.</p> <p>Change sets do not currently support nested stacks. If you want to create a stack from a stack template that contains macros <i>and</i> nested stacks, you must create the stack directly from the template using this capability.</p> <important> <p>You should only create stacks directly from a stack template that contains macros if you know what processing the macro performs.</p> <p>Each macro relies on an underlying Lambda service function for processing stack templates. Be aware that the Lambda function owner can update the function operation without AWS CloudFormation being notified.</p> </important> <p>For more information, see <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/template-macros.html">Using AWS CloudFormation Macros to Perform Custom Processing on Templates</a>.</p> </li> </ul></p>
-    pub capabilities: Option<Vec<String>>,
+    pub capabilities: Option<Vec<Capability>>,
     /// <p>A unique identifier for this <code>CreateStack</code> request. Specify this token if you plan to retry requests so that AWS CloudFormation knows that you're not attempting to create a stack with the same name. You might retry <code>CreateStack</code> requests to ensure that AWS CloudFormation successfully received them.</p> <p>All events triggered by a given stack operation are assigned the same client request token, which you can use to track operations. For example, if you execute a <code>CreateStack</code> operation with the token <code>token1</code>, then all the <code>StackEvents</code> generated by that operation will have <code>ClientRequestToken</code> set as <code>token1</code>.</p> <p>In the console, stack operations display the client request token on the Events tab. Stack operations that are initiated from the console use the token format <i>Console-StackOperation-ID</i>, which helps you easily identify the stack operation . For example, if you create a stack using the console, each stack event would be assigned the same token in the following format: <code>Console-CreateStack-7f59c3cf-00d2-40c7-b2ff-e75db0987002</code>. </p>
     pub client_request_token: Option<String>,
     /// <p>Set to <code>true</code> to disable rollback of the stack if stack creation failed. You can specify either <code>DisableRollback</code> or <code>OnFailure</code>, but not both.</p> <p>Default: <code>false</code> </p>
@@ -761,7 +1514,7 @@ pub struct CreateStackInput {
     /// <p>The Simple Notification Service (SNS) topic ARNs to publish stack related events. You can find your SNS topic ARNs using the SNS console or your Command Line Interface (CLI).</p>
     pub notification_ar_ns: Option<Vec<String>>,
     /// <p>Determines what action will be taken if stack creation fails. This must be one of: DO_NOTHING, ROLLBACK, or DELETE. You can specify either <code>OnFailure</code> or <code>DisableRollback</code>, but not both.</p> <p>Default: <code>ROLLBACK</code> </p>
-    pub on_failure: Option<String>,
+    pub on_failure: Option<OnFailure>,
     /// <p>A list of <code>Parameter</code> structures that specify input parameters for the stack. For more information, see the <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/APIReference/API_Parameter.html">Parameter</a> data type.</p>
     pub parameters: Option<Vec<Parameter>>,
     /// <p>The template resource types that you have permissions to work with for this create stack action, such as <code>AWS::EC2::Instance</code>, <code>AWS::EC2::*</code>, or <code>Custom::MyCustomInstance</code>. Use the following syntax to describe template resource types: <code>AWS::*</code> (for all AWS resource), <code>Custom::*</code> (for all custom resources), <code>Custom::<i>logical_ID</i> </code> (for a specific custom resource), <code>AWS::<i>service_name</i>::*</code> (for all resources of a particular AWS service), and <code>AWS::<i>service_name</i>::<i>resource_logical_ID</i> </code> (for a specific AWS resource).</p> <p>If the list of resource types doesn't include a resource that you're creating, the stack creation fails. By default, AWS CloudFormation grants permissions to all resource types. AWS Identity and Access Management (IAM) uses this parameter for AWS CloudFormation-specific condition keys in IAM policies. For more information, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-iam-template.html">Controlling Access with AWS Identity and Access Management</a>.</p>
@@ -990,7 +1743,7 @@ pub struct CreateStackSetInput {
     /// <p>The Amazon Resource Number (ARN) of the IAM role to use to create this stack set. </p> <p>Specify an IAM role only if you are using customized administrator roles to control which users or groups can manage specific stack sets within the same administrator account. For more information, see <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/stacksets-prereqs.html">Prerequisites: Granting Permissions for Stack Set Operations</a> in the <i>AWS CloudFormation User Guide</i>.</p>
     pub administration_role_arn: Option<String>,
     /// <p><p>In some cases, you must explicitly acknowledge that your stack set template contains certain capabilities in order for AWS CloudFormation to create the stack set and related stack instances.</p> <ul> <li> <p> <code>CAPABILITY<em>IAM</code> and <code>CAPABILITY</em>NAMED<em>IAM</code> </p> <p>Some stack templates might include resources that can affect permissions in your AWS account; for example, by creating new AWS Identity and Access Management (IAM) users. For those stack sets, you must explicitly acknowledge this by specifying one of these capabilities.</p> <p>The following IAM resources require you to specify either the <code>CAPABILITY</em>IAM</code> or <code>CAPABILITY<em>NAMED</em>IAM</code> capability.</p> <ul> <li> <p>If you have IAM resources, you can specify either capability. </p> </li> <li> <p>If you have IAM resources with custom names, you <i>must</i> specify <code>CAPABILITY<em>NAMED</em>IAM</code>. </p> </li> <li> <p>If you don&#39;t specify either of these capabilities, AWS CloudFormation returns an <code>InsufficientCapabilities</code> error.</p> </li> </ul> <p>If your stack template contains these resources, we recommend that you review all permissions associated with them and edit their permissions if necessary.</p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-accesskey.html"> AWS::IAM::AccessKey</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-group.html"> AWS::IAM::Group</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-resource-iam-instanceprofile.html"> AWS::IAM::InstanceProfile</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-policy.html"> AWS::IAM::Policy</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-resource-iam-role.html"> AWS::IAM::Role</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-user.html"> AWS::IAM::User</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-addusertogroup.html"> AWS::IAM::UserToGroupAddition</a> </p> </li> </ul> <p>For more information, see <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-iam-template.html#capabilities">Acknowledging IAM Resources in AWS CloudFormation Templates</a>.</p> </li> <li> <p> <code>CAPABILITY<em>AUTO</em>EXPAND</code> </p> <p>Some templates contain macros. If your stack template contains one or more macros, and you choose to create a stack directly from the processed template, without first reviewing the resulting changes in a change set, you must acknowledge this capability. For more information, see <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/template-macros.html">Using AWS CloudFormation Macros to Perform Custom Processing on Templates</a>.</p> <note> <p>Stack sets do not currently support macros in stack templates. (This includes the <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/create-reusable-transform-function-snippets-and-add-to-your-template-with-aws-include-transform.html">AWS::Include</a> and <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/transform-aws-serverless.html">AWS::Serverless</a> transforms, which are macros hosted by AWS CloudFormation.) Even if you specify this capability, if you include a macro in your template the stack set operation will fail.</p> </note> </li> </ul></p>
-    pub capabilities: Option<Vec<String>>,
+    pub capabilities: Option<Vec<Capability>>,
     /// <p>A unique identifier for this <code>CreateStackSet</code> request. Specify this token if you plan to retry requests so that AWS CloudFormation knows that you're not attempting to create another stack set with the same name. You might retry <code>CreateStackSet</code> requests to ensure that AWS CloudFormation successfully received them.</p> <p>If you don't specify an operation ID, the SDK generates one automatically. </p>
     pub client_request_token: Option<String>,
     /// <p>A description of the stack set. You can use the description to identify the stack set's purpose or other important information.</p>
@@ -1092,9 +1845,12 @@ impl CreateStackSetOutputDeserializer {
 struct CreationTimeDeserializer;
 impl CreationTimeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<::chrono::DateTime<::chrono::Utc>, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::proto::xml::util::timestamp_characters(stack)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -1318,20 +2074,121 @@ impl DeleteStackSetOutputDeserializer {
 struct DeletionTimeDeserializer;
 impl DeletionTimeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<::chrono::DateTime<::chrono::Utc>, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::proto::xml::util::timestamp_characters(stack)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DeprecatedStatus {
+    /// `LIVE`
+    Live,
+    /// `DEPRECATED`
+    Deprecated,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DeprecatedStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeprecatedStatus::Live => "LIVE",
+            DeprecatedStatus::Deprecated => "DEPRECATED",
+            DeprecatedStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DeprecatedStatus {
+    fn default() -> DeprecatedStatus {
+        DeprecatedStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DeprecatedStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DeprecatedStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DeprecatedStatus {
+    fn from(value: &'a str) -> DeprecatedStatus {
+        match value {
+            "LIVE" => DeprecatedStatus::Live,
+            "DEPRECATED" => DeprecatedStatus::Deprecated,
+            _ => DeprecatedStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeprecatedStatus {
+    fn from(value: String) -> DeprecatedStatus {
+        match value.as_str() {
+            "LIVE" => DeprecatedStatus::Live,
+            "DEPRECATED" => DeprecatedStatus::Deprecated,
+            _ => DeprecatedStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DeprecatedStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DeprecatedStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DeprecatedStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for DeprecatedStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for DeprecatedStatus {
+    fn deserialize<D>(deserializer: D) -> Result<DeprecatedStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct DeprecatedStatusDeserializer;
 impl DeprecatedStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<DeprecatedStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -1343,7 +2200,7 @@ pub struct DeregisterTypeInput {
     /// <p>The Amazon Resource Name (ARN) of the type.</p> <p>Conditional: You must specify <code>TypeName</code> or <code>Arn</code>.</p>
     pub arn: Option<String>,
     /// <p>The kind of type.</p> <p>Currently the only valid value is <code>RESOURCE</code>.</p>
-    pub type_: Option<String>,
+    pub type_: Option<RegistryType>,
     /// <p>The name of the type.</p> <p>Conditional: You must specify <code>TypeName</code> or <code>Arn</code>.</p>
     pub type_name: Option<String>,
     /// <p>The ID of a specific version of the type. The version ID is the value at the end of the Amazon Resource Name (ARN) assigned to the type version when it is registered.</p>
@@ -1494,7 +2351,7 @@ impl DescribeChangeSetInputSerializer {
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct DescribeChangeSetOutput {
     /// <p>If you execute the change set, the list of capabilities that were explicitly acknowledged when the change set was created.</p>
-    pub capabilities: Option<Vec<String>>,
+    pub capabilities: Option<Vec<Capability>>,
     /// <p>The ARN of the change set.</p>
     pub change_set_id: Option<String>,
     /// <p>The name of the change set.</p>
@@ -1502,11 +2359,11 @@ pub struct DescribeChangeSetOutput {
     /// <p>A list of <code>Change</code> structures that describes the resources AWS CloudFormation changes if you execute the change set.</p>
     pub changes: Option<Vec<Change>>,
     /// <p>The start time when the change set was created, in UTC.</p>
-    pub creation_time: Option<String>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Information about the change set.</p>
     pub description: Option<String>,
     /// <p>If the change set execution status is <code>AVAILABLE</code>, you can execute the change set. If you can’t execute the change set, the status indicates why. For example, a change set might be in an <code>UNAVAILABLE</code> state because AWS CloudFormation is still creating it or in an <code>OBSOLETE</code> state because the stack was already updated.</p>
-    pub execution_status: Option<String>,
+    pub execution_status: Option<ExecutionStatus>,
     /// <p>If the output exceeds 1 MB, a string that identifies the next page of changes. If there is no additional page, this value is null.</p>
     pub next_token: Option<String>,
     /// <p>The ARNs of the Amazon Simple Notification Service (Amazon SNS) topics that will be associated with the stack if you execute the change set.</p>
@@ -1520,7 +2377,7 @@ pub struct DescribeChangeSetOutput {
     /// <p>The name of the stack that is associated with the change set.</p>
     pub stack_name: Option<String>,
     /// <p>The current status of the change set, such as <code>CREATE_IN_PROGRESS</code>, <code>CREATE_COMPLETE</code>, or <code>FAILED</code>.</p>
-    pub status: Option<String>,
+    pub status: Option<ChangeSetStatus>,
     /// <p>A description of the change set's status. For example, if your attempt to create a change set failed, AWS CloudFormation shows the error message.</p>
     pub status_reason: Option<String>,
     /// <p>If you execute the change set, the tags that will be associated with the stack.</p>
@@ -1652,7 +2509,7 @@ impl DescribeStackDriftDetectionStatusInputSerializer {
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct DescribeStackDriftDetectionStatusOutput {
     /// <p><p>The status of the stack drift detection operation.</p> <ul> <li> <p> <code>DETECTION<em>COMPLETE</code>: The stack drift detection operation has successfully completed for all resources in the stack that support drift detection. (Resources that do not currently support stack detection remain unchecked.)</p> <p>If you specified logical resource IDs for AWS CloudFormation to use as a filter for the stack drift detection operation, only the resources with those logical IDs are checked for drift.</p> </li> <li> <p> <code>DETECTION</em>FAILED</code>: The stack drift detection operation has failed for at least one resource in the stack. Results will be available for resources on which AWS CloudFormation successfully completed drift detection.</p> </li> <li> <p> <code>DETECTION<em>IN</em>PROGRESS</code>: The stack drift detection operation is currently in progress.</p> </li> </ul></p>
-    pub detection_status: String,
+    pub detection_status: StackDriftDetectionStatus,
     /// <p>The reason the stack drift detection operation has its current status.</p>
     pub detection_status_reason: Option<String>,
     /// <p>Total number of stack resources that have drifted. This is NULL until the drift detection operation reaches a status of <code>DETECTION_COMPLETE</code>. This value will be 0 for stacks whose drift status is <code>IN_SYNC</code>.</p>
@@ -1660,11 +2517,11 @@ pub struct DescribeStackDriftDetectionStatusOutput {
     /// <p>The ID of the drift detection results of this operation. </p> <p>AWS CloudFormation generates new results, with a new drift detection ID, each time this operation is run. However, the number of reports AWS CloudFormation retains for any given stack, and for how long, may vary.</p>
     pub stack_drift_detection_id: String,
     /// <p><p>Status of the stack&#39;s actual configuration compared to its expected configuration. </p> <ul> <li> <p> <code>DRIFTED</code>: The stack differs from its expected template configuration. A stack is considered to have drifted if one or more of its resources have drifted.</p> </li> <li> <p> <code>NOT<em>CHECKED</code>: AWS CloudFormation has not checked if the stack differs from its expected template configuration.</p> </li> <li> <p> <code>IN</em>SYNC</code>: The stack&#39;s actual configuration matches its expected template configuration.</p> </li> <li> <p> <code>UNKNOWN</code>: This value is reserved for future use.</p> </li> </ul></p>
-    pub stack_drift_status: Option<String>,
+    pub stack_drift_status: Option<StackDriftStatus>,
     /// <p>The ID of the stack.</p>
     pub stack_id: String,
     /// <p>Time at which the stack drift detection operation was initiated.</p>
-    pub timestamp: String,
+    pub timestamp: ::chrono::DateTime<::chrono::Utc>,
 }
 
 struct DescribeStackDriftDetectionStatusOutputDeserializer;
@@ -1868,7 +2725,7 @@ pub struct DescribeStackResourceDriftsInput {
     /// <p>The name of the stack for which you want drift information.</p>
     pub stack_name: String,
     /// <p><p>The resource drift status values to use as filters for the resource drift results returned.</p> <ul> <li> <p> <code>DELETED</code>: The resource differs from its expected template configuration in that the resource has been deleted.</p> </li> <li> <p> <code>MODIFIED</code>: One or more resource properties differ from their expected template values.</p> </li> <li> <p> <code>IN<em>SYNC</code>: The resources&#39;s actual configuration matches its expected template configuration.</p> </li> <li> <p> <code>NOT</em>CHECKED</code>: AWS CloudFormation does not currently return this value.</p> </li> </ul></p>
-    pub stack_resource_drift_status_filters: Option<Vec<String>>,
+    pub stack_resource_drift_status_filters: Option<Vec<StackResourceDriftStatus>>,
 }
 
 /// Serialize `DescribeStackResourceDriftsInput` contents to a `SignedRequest`.
@@ -2236,7 +3093,7 @@ pub struct DescribeTypeInput {
     /// <p>The Amazon Resource Name (ARN) of the type.</p> <p>Conditional: You must specify <code>TypeName</code> or <code>Arn</code>.</p>
     pub arn: Option<String>,
     /// <p>The kind of type. </p> <p>Currently the only valid value is <code>RESOURCE</code>.</p>
-    pub type_: Option<String>,
+    pub type_: Option<RegistryType>,
     /// <p>The name of the type.</p> <p>Conditional: You must specify <code>TypeName</code> or <code>Arn</code>.</p>
     pub type_name: Option<String>,
     /// <p>The ID of a specific version of the type. The version ID is the value at the end of the Amazon Resource Name (ARN) assigned to the type version when it is registered.</p> <p>If you specify a <code>VersionId</code>, <code>DescribeType</code> returns information about that specific type version. Otherwise, it returns information about the default type version.</p>
@@ -2275,7 +3132,7 @@ pub struct DescribeTypeOutput {
     /// <p>The ID of the default version of the type. The default version is used when the type version is not specified.</p> <p>To set the default version of a type, use <code> <a>SetTypeDefaultVersion</a> </code>. </p>
     pub default_version_id: Option<String>,
     /// <p><p>The deprecation status of the type.</p> <p>Valid values include:</p> <ul> <li> <p> <code>LIVE</code>: The type is registered and can be used in CloudFormation operations, dependent on its provisioning behavior and visibility scope.</p> </li> <li> <p> <code>DEPRECATED</code>: The type has been deregistered and can no longer be used in CloudFormation operations. </p> </li> </ul></p>
-    pub deprecated_status: Option<String>,
+    pub deprecated_status: Option<DeprecatedStatus>,
     /// <p>The description of the registered type.</p>
     pub description: Option<String>,
     /// <p>The URL of a page providing detailed documentation for this type.</p>
@@ -2283,23 +3140,23 @@ pub struct DescribeTypeOutput {
     /// <p>The Amazon Resource Name (ARN) of the IAM execution role used to register the type. If your resource type calls AWS APIs in any of its handlers, you must create an <i> <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_roles.html">IAM execution role</a> </i> that includes the necessary permissions to call those AWS APIs, and provision that execution role in your account. CloudFormation then assumes that execution role to provide your resource type with the appropriate credentials.</p>
     pub execution_role_arn: Option<String>,
     /// <p>When the specified type version was registered.</p>
-    pub last_updated: Option<String>,
+    pub last_updated: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Contains logging configuration information for a type.</p>
     pub logging_config: Option<LoggingConfig>,
     /// <p><p>The provisioning behavior of the type. AWS CloudFormation determines the provisioning type during registration, based on the types of handlers in the schema handler package submitted.</p> <p>Valid values include:</p> <ul> <li> <p> <code>FULLY<em>MUTABLE</code>: The type includes an update handler to process updates to the type during stack update operations.</p> </li> <li> <p> <code>IMMUTABLE</code>: The type does not include an update handler, so the type cannot be updated and must instead be replaced during stack update operations.</p> </li> <li> <p> <code>NON</em>PROVISIONABLE</code>: The type does not include all of the following handlers, and therefore cannot actually be provisioned.</p> <ul> <li> <p>create</p> </li> <li> <p>read</p> </li> <li> <p>delete</p> </li> </ul> </li> </ul></p>
-    pub provisioning_type: Option<String>,
+    pub provisioning_type: Option<ProvisioningType>,
     /// <p>The schema that defines the type.</p> <p>For more information on type schemas, see <a href="https://docs.aws.amazon.com/cloudformation-cli/latest/userguide/resource-type-schema.html">Resource Provider Schema</a> in the <i>CloudFormation CLI User Guide</i>.</p>
     pub schema: Option<String>,
     /// <p>The URL of the source code for the type.</p>
     pub source_url: Option<String>,
     /// <p>When the specified type version was registered.</p>
-    pub time_created: Option<String>,
+    pub time_created: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The kind of type. </p> <p>Currently the only valid value is <code>RESOURCE</code>.</p>
-    pub type_: Option<String>,
+    pub type_: Option<RegistryType>,
     /// <p>The name of the registered type.</p>
     pub type_name: Option<String>,
     /// <p><p>The scope at which the type is visible and usable in CloudFormation operations.</p> <p>Valid values include:</p> <ul> <li> <p> <code>PRIVATE</code>: The type is only visible and usable within the account in which it is registered. Currently, AWS CloudFormation marks any types you register as <code>PRIVATE</code>.</p> </li> <li> <p> <code>PUBLIC</code>: The type is publically visible and usable within any Amazon account.</p> </li> </ul></p>
-    pub visibility: Option<String>,
+    pub visibility: Option<Visibility>,
 }
 
 struct DescribeTypeOutputDeserializer;
@@ -2414,7 +3271,7 @@ pub struct DescribeTypeRegistrationOutput {
     /// <p>The description of the type registration request.</p>
     pub description: Option<String>,
     /// <p>The current status of the type registration request.</p>
-    pub progress_status: Option<String>,
+    pub progress_status: Option<RegistrationStatus>,
     /// <p>The Amazon Resource Name (ARN) of the type being registered.</p> <p>For registration requests with a <code>ProgressStatus</code> of other than <code>COMPLETE</code>, this will be <code>null</code>.</p>
     pub type_arn: Option<String>,
     /// <p>The Amazon Resource Name (ARN) of this specific version of the type being registered.</p> <p>For registration requests with a <code>ProgressStatus</code> of other than <code>COMPLETE</code>, this will be <code>null</code>.</p>
@@ -2651,26 +3508,129 @@ impl DetectStackSetDriftOutputDeserializer {
         )
     }
 }
-struct DifferenceTypeDeserializer;
-impl DifferenceTypeDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
-        start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
-        end_element(tag_name, stack)?;
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DifferenceType {
+    /// `ADD`
+    Add,
+    /// `REMOVE`
+    Remove,
+    /// `NOT_EQUAL`
+    NotEqual,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
 
-        Ok(obj)
+impl DifferenceType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DifferenceType::Add => "ADD",
+            DifferenceType::Remove => "REMOVE",
+            DifferenceType::NotEqual => "NOT_EQUAL",
+            DifferenceType::Unknown(ref value) => value,
+        }
     }
 }
-struct DisableRollbackDeserializer;
-impl DisableRollbackDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
-        start_element(tag_name, stack)?;
-        let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
-        end_element(tag_name, stack)?;
 
-        Ok(obj)
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DifferenceType {
+    fn default() -> DifferenceType {
+        DifferenceType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DifferenceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DifferenceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DifferenceType {
+    fn from(value: &'a str) -> DifferenceType {
+        match value {
+            "ADD" => DifferenceType::Add,
+            "REMOVE" => DifferenceType::Remove,
+            "NOT_EQUAL" => DifferenceType::NotEqual,
+            _ => DifferenceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DifferenceType {
+    fn from(value: String) -> DifferenceType {
+        match value.as_str() {
+            "ADD" => DifferenceType::Add,
+            "REMOVE" => DifferenceType::Remove,
+            "NOT_EQUAL" => DifferenceType::NotEqual,
+            _ => DifferenceType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DifferenceType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DifferenceType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DifferenceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for DifferenceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for DifferenceType {
+    fn deserialize<D>(deserializer: D) -> Result<DifferenceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+struct DifferenceTypeDeserializer;
+impl DifferenceTypeDeserializer {
+    #[allow(unused_variables)]
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<DifferenceType, XmlParseError> {
+        start_element(tag_name, stack)?;
+        let obj = characters(stack)?.into();
+        end_element(tag_name, stack)?;
+
+        Ok(obj)
+    }
+}
+struct DisableRollbackDeserializer;
+impl DisableRollbackDeserializer {
+    #[allow(unused_variables)]
+    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
+        start_element(tag_name, stack)?;
+        let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
+        end_element(tag_name, stack)?;
+
+        Ok(obj)
     }
 }
 struct DriftedStackInstancesCountDeserializer;
@@ -2762,12 +3722,110 @@ impl EstimateTemplateCostOutputDeserializer {
         )
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EvaluationType {
+    /// `Static`
+    Static,
+    /// `Dynamic`
+    Dynamic,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EvaluationType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EvaluationType::Static => "Static",
+            EvaluationType::Dynamic => "Dynamic",
+            EvaluationType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EvaluationType {
+    fn default() -> EvaluationType {
+        EvaluationType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EvaluationType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EvaluationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EvaluationType {
+    fn from(value: &'a str) -> EvaluationType {
+        match value {
+            "Static" => EvaluationType::Static,
+            "Dynamic" => EvaluationType::Dynamic,
+            _ => EvaluationType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EvaluationType {
+    fn from(value: String) -> EvaluationType {
+        match value.as_str() {
+            "Static" => EvaluationType::Static,
+            "Dynamic" => EvaluationType::Dynamic,
+            _ => EvaluationType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EvaluationType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EvaluationType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EvaluationType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for EvaluationType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for EvaluationType {
+    fn deserialize<D>(deserializer: D) -> Result<EvaluationType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct EvaluationTypeDeserializer;
 impl EvaluationTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<EvaluationType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -2850,12 +3908,130 @@ impl ExecutionRoleNameDeserializer {
         Ok(obj)
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ExecutionStatus {
+    /// `UNAVAILABLE`
+    Unavailable,
+    /// `AVAILABLE`
+    Available,
+    /// `EXECUTE_IN_PROGRESS`
+    ExecuteInProgress,
+    /// `EXECUTE_COMPLETE`
+    ExecuteComplete,
+    /// `EXECUTE_FAILED`
+    ExecuteFailed,
+    /// `OBSOLETE`
+    Obsolete,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ExecutionStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ExecutionStatus::Unavailable => "UNAVAILABLE",
+            ExecutionStatus::Available => "AVAILABLE",
+            ExecutionStatus::ExecuteInProgress => "EXECUTE_IN_PROGRESS",
+            ExecutionStatus::ExecuteComplete => "EXECUTE_COMPLETE",
+            ExecutionStatus::ExecuteFailed => "EXECUTE_FAILED",
+            ExecutionStatus::Obsolete => "OBSOLETE",
+            ExecutionStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ExecutionStatus {
+    fn default() -> ExecutionStatus {
+        ExecutionStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ExecutionStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ExecutionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ExecutionStatus {
+    fn from(value: &'a str) -> ExecutionStatus {
+        match value {
+            "UNAVAILABLE" => ExecutionStatus::Unavailable,
+            "AVAILABLE" => ExecutionStatus::Available,
+            "EXECUTE_IN_PROGRESS" => ExecutionStatus::ExecuteInProgress,
+            "EXECUTE_COMPLETE" => ExecutionStatus::ExecuteComplete,
+            "EXECUTE_FAILED" => ExecutionStatus::ExecuteFailed,
+            "OBSOLETE" => ExecutionStatus::Obsolete,
+            _ => ExecutionStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ExecutionStatus {
+    fn from(value: String) -> ExecutionStatus {
+        match value.as_str() {
+            "UNAVAILABLE" => ExecutionStatus::Unavailable,
+            "AVAILABLE" => ExecutionStatus::Available,
+            "EXECUTE_IN_PROGRESS" => ExecutionStatus::ExecuteInProgress,
+            "EXECUTE_COMPLETE" => ExecutionStatus::ExecuteComplete,
+            "EXECUTE_FAILED" => ExecutionStatus::ExecuteFailed,
+            "OBSOLETE" => ExecutionStatus::Obsolete,
+            _ => ExecutionStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ExecutionStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ExecutionStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ExecutionStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ExecutionStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ExecutionStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ExecutionStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct ExecutionStatusDeserializer;
 impl ExecutionStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ExecutionStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -3026,7 +4202,7 @@ pub struct GetTemplateInput {
     /// <p>The name or the unique stack ID that is associated with the stack, which are not always interchangeable:</p> <ul> <li> <p>Running stacks: You can specify either the stack's name or its unique stack ID.</p> </li> <li> <p>Deleted stacks: You must specify the unique stack ID.</p> </li> </ul> <p>Default: There is no default value.</p>
     pub stack_name: Option<String>,
     /// <p>For templates that include transforms, the stage of the template that AWS CloudFormation returns. To get the user-submitted template, specify <code>Original</code>. To get the template after AWS CloudFormation has processed all transforms, specify <code>Processed</code>. </p> <p>If the template doesn't include transforms, <code>Original</code> and <code>Processed</code> return the same template. By default, AWS CloudFormation specifies <code>Original</code>. </p>
-    pub template_stage: Option<String>,
+    pub template_stage: Option<TemplateStage>,
 }
 
 /// Serialize `GetTemplateInput` contents to a `SignedRequest`.
@@ -3055,7 +4231,7 @@ impl GetTemplateInputSerializer {
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct GetTemplateOutput {
     /// <p>The stage of the template that you can retrieve. For stacks, the <code>Original</code> and <code>Processed</code> templates are always available. For change sets, the <code>Original</code> template is always available. After AWS CloudFormation finishes creating the change set, the <code>Processed</code> template becomes available.</p>
-    pub stages_available: Option<Vec<String>>,
+    pub stages_available: Option<Vec<TemplateStage>>,
     /// <p>Structure containing the template body. (For more information, go to <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/template-anatomy.html">Template Anatomy</a> in the AWS CloudFormation User Guide.)</p> <p>AWS CloudFormation returns the same template that was used when the stack was created.</p>
     pub template_body: Option<String>,
 }
@@ -3129,7 +4305,7 @@ impl GetTemplateSummaryInputSerializer {
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct GetTemplateSummaryOutput {
     /// <p>The capabilities found within the template. If your template contains IAM resources, you must specify the CAPABILITY_IAM or CAPABILITY_NAMED_IAM value for this parameter when you use the <a>CreateStack</a> or <a>UpdateStack</a> actions with your template; otherwise, those actions return an InsufficientCapabilities error.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-iam-template.html#capabilities">Acknowledging IAM Resources in AWS CloudFormation Templates</a>.</p>
-    pub capabilities: Option<Vec<String>>,
+    pub capabilities: Option<Vec<Capability>>,
     /// <p>The list of resources that generated the values in the <code>Capabilities</code> response element.</p>
     pub capabilities_reason: Option<String>,
     /// <p>A list of the transforms that are declared in the template.</p>
@@ -3212,6 +4388,186 @@ impl GetTemplateSummaryOutputDeserializer {
         )
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum HandlerErrorCode {
+    /// `NotUpdatable`
+    NotUpdatable,
+    /// `InvalidRequest`
+    InvalidRequest,
+    /// `AccessDenied`
+    AccessDenied,
+    /// `InvalidCredentials`
+    InvalidCredentials,
+    /// `AlreadyExists`
+    AlreadyExists,
+    /// `NotFound`
+    NotFound,
+    /// `ResourceConflict`
+    ResourceConflict,
+    /// `Throttling`
+    Throttling,
+    /// `ServiceLimitExceeded`
+    ServiceLimitExceeded,
+    /// `NotStabilized`
+    NotStabilized,
+    /// `GeneralServiceException`
+    GeneralServiceException,
+    /// `ServiceInternalError`
+    ServiceInternalError,
+    /// `NetworkFailure`
+    NetworkFailure,
+    /// `InternalFailure`
+    InternalFailure,
+    /// `InvalidTypeConfiguration`
+    InvalidTypeConfiguration,
+    /// `HandlerInternalFailure`
+    HandlerInternalFailure,
+    /// `NonCompliant`
+    NonCompliant,
+    /// `Unknown`
+    UnknownValue,
+    /// `UnsupportedTarget`
+    UnsupportedTarget,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl HandlerErrorCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            HandlerErrorCode::NotUpdatable => "NotUpdatable",
+            HandlerErrorCode::InvalidRequest => "InvalidRequest",
+            HandlerErrorCode::AccessDenied => "AccessDenied",
+            HandlerErrorCode::InvalidCredentials => "InvalidCredentials",
+            HandlerErrorCode::AlreadyExists => "AlreadyExists",
+            HandlerErrorCode::NotFound => "NotFound",
+            HandlerErrorCode::ResourceConflict => "ResourceConflict",
+            HandlerErrorCode::Throttling => "Throttling",
+            HandlerErrorCode::ServiceLimitExceeded => "ServiceLimitExceeded",
+            HandlerErrorCode::NotStabilized => "NotStabilized",
+            HandlerErrorCode::GeneralServiceException => "GeneralServiceException",
+            HandlerErrorCode::ServiceInternalError => "ServiceInternalError",
+            HandlerErrorCode::NetworkFailure => "NetworkFailure",
+            HandlerErrorCode::InternalFailure => "InternalFailure",
+            HandlerErrorCode::InvalidTypeConfiguration => "InvalidTypeConfiguration",
+            HandlerErrorCode::HandlerInternalFailure => "HandlerInternalFailure",
+            HandlerErrorCode::NonCompliant => "NonCompliant",
+            HandlerErrorCode::UnknownValue => "Unknown",
+            HandlerErrorCode::UnsupportedTarget => "UnsupportedTarget",
+            HandlerErrorCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for HandlerErrorCode {
+    fn default() -> HandlerErrorCode {
+        HandlerErrorCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for HandlerErrorCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for HandlerErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for HandlerErrorCode {
+    fn from(value: &'a str) -> HandlerErrorCode {
+        match value {
+            "NotUpdatable" => HandlerErrorCode::NotUpdatable,
+            "InvalidRequest" => HandlerErrorCode::InvalidRequest,
+            "AccessDenied" => HandlerErrorCode::AccessDenied,
+            "InvalidCredentials" => HandlerErrorCode::InvalidCredentials,
+            "AlreadyExists" => HandlerErrorCode::AlreadyExists,
+            "NotFound" => HandlerErrorCode::NotFound,
+            "ResourceConflict" => HandlerErrorCode::ResourceConflict,
+            "Throttling" => HandlerErrorCode::Throttling,
+            "ServiceLimitExceeded" => HandlerErrorCode::ServiceLimitExceeded,
+            "NotStabilized" => HandlerErrorCode::NotStabilized,
+            "GeneralServiceException" => HandlerErrorCode::GeneralServiceException,
+            "ServiceInternalError" => HandlerErrorCode::ServiceInternalError,
+            "NetworkFailure" => HandlerErrorCode::NetworkFailure,
+            "InternalFailure" => HandlerErrorCode::InternalFailure,
+            "InvalidTypeConfiguration" => HandlerErrorCode::InvalidTypeConfiguration,
+            "HandlerInternalFailure" => HandlerErrorCode::HandlerInternalFailure,
+            "NonCompliant" => HandlerErrorCode::NonCompliant,
+            "Unknown" => HandlerErrorCode::UnknownValue,
+            "UnsupportedTarget" => HandlerErrorCode::UnsupportedTarget,
+            _ => HandlerErrorCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for HandlerErrorCode {
+    fn from(value: String) -> HandlerErrorCode {
+        match value.as_str() {
+            "NotUpdatable" => HandlerErrorCode::NotUpdatable,
+            "InvalidRequest" => HandlerErrorCode::InvalidRequest,
+            "AccessDenied" => HandlerErrorCode::AccessDenied,
+            "InvalidCredentials" => HandlerErrorCode::InvalidCredentials,
+            "AlreadyExists" => HandlerErrorCode::AlreadyExists,
+            "NotFound" => HandlerErrorCode::NotFound,
+            "ResourceConflict" => HandlerErrorCode::ResourceConflict,
+            "Throttling" => HandlerErrorCode::Throttling,
+            "ServiceLimitExceeded" => HandlerErrorCode::ServiceLimitExceeded,
+            "NotStabilized" => HandlerErrorCode::NotStabilized,
+            "GeneralServiceException" => HandlerErrorCode::GeneralServiceException,
+            "ServiceInternalError" => HandlerErrorCode::ServiceInternalError,
+            "NetworkFailure" => HandlerErrorCode::NetworkFailure,
+            "InternalFailure" => HandlerErrorCode::InternalFailure,
+            "InvalidTypeConfiguration" => HandlerErrorCode::InvalidTypeConfiguration,
+            "HandlerInternalFailure" => HandlerErrorCode::HandlerInternalFailure,
+            "NonCompliant" => HandlerErrorCode::NonCompliant,
+            "Unknown" => HandlerErrorCode::UnknownValue,
+            "UnsupportedTarget" => HandlerErrorCode::UnsupportedTarget,
+            _ => HandlerErrorCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for HandlerErrorCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<HandlerErrorCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for HandlerErrorCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for HandlerErrorCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for HandlerErrorCode {
+    fn deserialize<D>(deserializer: D) -> Result<HandlerErrorCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct ImportsDeserializer;
 impl ImportsDeserializer {
     #[allow(unused_variables)]
@@ -3265,9 +4621,12 @@ impl KeyDeserializer {
 struct LastUpdatedTimeDeserializer;
 impl LastUpdatedTimeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<::chrono::DateTime<::chrono::Utc>, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::proto::xml::util::timestamp_characters(stack)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -3775,7 +5134,7 @@ pub struct ListStackSetsInput {
     /// <p>If the previous paginated request didn't return all of the remaining results, the response object's <code>NextToken</code> parameter value is set to a token. To retrieve the next set of results, call <code>ListStackSets</code> again and assign that token to the request object's <code>NextToken</code> parameter. If there are no remaining results, the previous response object's <code>NextToken</code> parameter is set to <code>null</code>.</p>
     pub next_token: Option<String>,
     /// <p>The status of the stack sets that you want to get summary information about.</p>
-    pub status: Option<String>,
+    pub status: Option<StackSetStatus>,
 }
 
 /// Serialize `ListStackSetsInput` contents to a `SignedRequest`.
@@ -3838,7 +5197,7 @@ pub struct ListStacksInput {
     /// <p>A string that identifies the next page of stacks that you want to retrieve.</p>
     pub next_token: Option<String>,
     /// <p>Stack status to use as a filter. Specify one or more stack status codes to list only stacks with the specified status codes. For a complete list of stack status codes, see the <code>StackStatus</code> parameter of the <a>Stack</a> data type.</p>
-    pub stack_status_filter: Option<Vec<String>>,
+    pub stack_status_filter: Option<Vec<StackStatus>>,
 }
 
 /// Serialize `ListStacksInput` contents to a `SignedRequest`.
@@ -3904,9 +5263,9 @@ pub struct ListTypeRegistrationsInput {
     /// <p>If the previous paginated request didn't return all of the remaining results, the response object's <code>NextToken</code> parameter value is set to a token. To retrieve the next set of results, call this action again and assign that token to the request object's <code>NextToken</code> parameter. If there are no remaining results, the previous response object's <code>NextToken</code> parameter is set to <code>null</code>.</p>
     pub next_token: Option<String>,
     /// <p>The current status of the type registration request.</p>
-    pub registration_status_filter: Option<String>,
+    pub registration_status_filter: Option<RegistrationStatus>,
     /// <p>The kind of type.</p> <p>Currently the only valid value is <code>RESOURCE</code>.</p>
-    pub type_: Option<String>,
+    pub type_: Option<RegistryType>,
     /// <p>The Amazon Resource Name (ARN) of the type.</p> <p>Conditional: You must specify <code>TypeName</code> or <code>Arn</code>.</p>
     pub type_arn: Option<String>,
     /// <p>The name of the type.</p> <p>Conditional: You must specify <code>TypeName</code> or <code>Arn</code>.</p>
@@ -3992,13 +5351,13 @@ pub struct ListTypeVersionsInput {
     /// <p>The Amazon Resource Name (ARN) of the type for which you want version summary information.</p> <p>Conditional: You must specify <code>TypeName</code> or <code>Arn</code>.</p>
     pub arn: Option<String>,
     /// <p><p>The deprecation status of the type versions that you want to get summary information about.</p> <p>Valid values include:</p> <ul> <li> <p> <code>LIVE</code>: The type version is registered and can be used in CloudFormation operations, dependent on its provisioning behavior and visibility scope.</p> </li> <li> <p> <code>DEPRECATED</code>: The type version has been deregistered and can no longer be used in CloudFormation operations. </p> </li> </ul></p>
-    pub deprecated_status: Option<String>,
+    pub deprecated_status: Option<DeprecatedStatus>,
     /// <p>The maximum number of results to be returned with a single call. If the number of available results exceeds this maximum, the response includes a <code>NextToken</code> value that you can assign to the <code>NextToken</code> request parameter to get the next set of results.</p>
     pub max_results: Option<i64>,
     /// <p>If the previous paginated request didn't return all of the remaining results, the response object's <code>NextToken</code> parameter value is set to a token. To retrieve the next set of results, call this action again and assign that token to the request object's <code>NextToken</code> parameter. If there are no remaining results, the previous response object's <code>NextToken</code> parameter is set to <code>null</code>.</p>
     pub next_token: Option<String>,
     /// <p>The kind of the type.</p> <p>Currently the only valid value is <code>RESOURCE</code>.</p>
-    pub type_: Option<String>,
+    pub type_: Option<RegistryType>,
     /// <p>The name of the type for which you want version summary information.</p> <p>Conditional: You must specify <code>TypeName</code> or <code>Arn</code>.</p>
     pub type_name: Option<String>,
 }
@@ -4072,15 +5431,15 @@ impl ListTypeVersionsOutputDeserializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ListTypesInput {
     /// <p><p>The deprecation status of the types that you want to get summary information about.</p> <p>Valid values include:</p> <ul> <li> <p> <code>LIVE</code>: The type is registered for use in CloudFormation operations.</p> </li> <li> <p> <code>DEPRECATED</code>: The type has been deregistered and can no longer be used in CloudFormation operations. </p> </li> </ul></p>
-    pub deprecated_status: Option<String>,
+    pub deprecated_status: Option<DeprecatedStatus>,
     /// <p>The maximum number of results to be returned with a single call. If the number of available results exceeds this maximum, the response includes a <code>NextToken</code> value that you can assign to the <code>NextToken</code> request parameter to get the next set of results.</p>
     pub max_results: Option<i64>,
     /// <p>If the previous paginated request didn't return all of the remaining results, the response object's <code>NextToken</code> parameter value is set to a token. To retrieve the next set of results, call this action again and assign that token to the request object's <code>NextToken</code> parameter. If there are no remaining results, the previous response object's <code>NextToken</code> parameter is set to <code>null</code>.</p>
     pub next_token: Option<String>,
     /// <p><p>The provisioning behavior of the type. AWS CloudFormation determines the provisioning type during registration, based on the types of handlers in the schema handler package submitted.</p> <p>Valid values include:</p> <ul> <li> <p> <code>FULLY<em>MUTABLE</code>: The type includes an update handler to process updates to the type during stack update operations.</p> </li> <li> <p> <code>IMMUTABLE</code>: The type does not include an update handler, so the type cannot be updated and must instead be replaced during stack update operations.</p> </li> <li> <p> <code>NON</em>PROVISIONABLE</code>: The type does not include create, read, and delete handlers, and therefore cannot actually be provisioned.</p> </li> </ul></p>
-    pub provisioning_type: Option<String>,
+    pub provisioning_type: Option<ProvisioningType>,
     /// <p><p>The scope at which the type is visible and usable in CloudFormation operations.</p> <p>Valid values include:</p> <ul> <li> <p> <code>PRIVATE</code>: The type is only visible and usable within the account in which it is registered. Currently, AWS CloudFormation marks any types you create as <code>PRIVATE</code>.</p> </li> <li> <p> <code>PUBLIC</code>: The type is publically visible and usable within any Amazon account.</p> </li> </ul></p>
-    pub visibility: Option<String>,
+    pub visibility: Option<Visibility>,
 }
 
 /// Serialize `ListTypesInput` contents to a `SignedRequest`.
@@ -4350,81 +5709,286 @@ impl NotificationARNsSerializer {
     }
 }
 
-struct OptionalSecureUrlDeserializer;
-impl OptionalSecureUrlDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
-        start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
-        end_element(tag_name, stack)?;
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OnFailure {
+    /// `DO_NOTHING`
+    DoNothing,
+    /// `ROLLBACK`
+    Rollback,
+    /// `DELETE`
+    Delete,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
 
-        Ok(obj)
+impl OnFailure {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OnFailure::DoNothing => "DO_NOTHING",
+            OnFailure::Rollback => "ROLLBACK",
+            OnFailure::Delete => "DELETE",
+            OnFailure::Unknown(ref value) => value,
+        }
     }
 }
-/// <p>The Output data type.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
-#[cfg_attr(feature = "serialize_structs", derive(Serialize))]
-pub struct Output {
-    /// <p>User defined description associated with the output.</p>
-    pub description: Option<String>,
-    /// <p>The name of the export associated with the output.</p>
-    pub export_name: Option<String>,
-    /// <p>The key associated with the output.</p>
-    pub output_key: Option<String>,
-    /// <p>The value associated with the output.</p>
-    pub output_value: Option<String>,
-}
 
-struct OutputDeserializer;
-impl OutputDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<Output, XmlParseError> {
-        deserialize_elements::<_, Output, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "Description" => {
-                    obj.description =
-                        Some(DescriptionDeserializer::deserialize("Description", stack)?);
-                }
-                "ExportName" => {
-                    obj.export_name =
-                        Some(ExportNameDeserializer::deserialize("ExportName", stack)?);
-                }
-                "OutputKey" => {
-                    obj.output_key = Some(OutputKeyDeserializer::deserialize("OutputKey", stack)?);
-                }
-                "OutputValue" => {
-                    obj.output_value =
-                        Some(OutputValueDeserializer::deserialize("OutputValue", stack)?);
-                }
-                _ => skip_tree(stack),
-            }
-            Ok(())
-        })
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for OnFailure {
+    fn default() -> OnFailure {
+        OnFailure::Unknown(String::new())
     }
 }
-struct OutputKeyDeserializer;
-impl OutputKeyDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
-        start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
-        end_element(tag_name, stack)?;
 
-        Ok(obj)
+impl AsRef<str> for OnFailure {
+    fn as_ref(&self) -> &str {
+        self.as_str()
     }
 }
-struct OutputValueDeserializer;
-impl OutputValueDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
-        start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
-        end_element(tag_name, stack)?;
 
-        Ok(obj)
+impl fmt::Display for OnFailure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
     }
 }
-struct OutputsDeserializer;
+
+impl<'a> From<&'a str> for OnFailure {
+    fn from(value: &'a str) -> OnFailure {
+        match value {
+            "DO_NOTHING" => OnFailure::DoNothing,
+            "ROLLBACK" => OnFailure::Rollback,
+            "DELETE" => OnFailure::Delete,
+            _ => OnFailure::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OnFailure {
+    fn from(value: String) -> OnFailure {
+        match value.as_str() {
+            "DO_NOTHING" => OnFailure::DoNothing,
+            "ROLLBACK" => OnFailure::Rollback,
+            "DELETE" => OnFailure::Delete,
+            _ => OnFailure::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for OnFailure {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<OnFailure, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OnFailure {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for OnFailure {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for OnFailure {
+    fn deserialize<D>(deserializer: D) -> Result<OnFailure, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OperationStatus {
+    /// `PENDING`
+    Pending,
+    /// `IN_PROGRESS`
+    InProgress,
+    /// `SUCCESS`
+    Success,
+    /// `FAILED`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl OperationStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OperationStatus::Pending => "PENDING",
+            OperationStatus::InProgress => "IN_PROGRESS",
+            OperationStatus::Success => "SUCCESS",
+            OperationStatus::Failed => "FAILED",
+            OperationStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for OperationStatus {
+    fn default() -> OperationStatus {
+        OperationStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for OperationStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for OperationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for OperationStatus {
+    fn from(value: &'a str) -> OperationStatus {
+        match value {
+            "PENDING" => OperationStatus::Pending,
+            "IN_PROGRESS" => OperationStatus::InProgress,
+            "SUCCESS" => OperationStatus::Success,
+            "FAILED" => OperationStatus::Failed,
+            _ => OperationStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OperationStatus {
+    fn from(value: String) -> OperationStatus {
+        match value.as_str() {
+            "PENDING" => OperationStatus::Pending,
+            "IN_PROGRESS" => OperationStatus::InProgress,
+            "SUCCESS" => OperationStatus::Success,
+            "FAILED" => OperationStatus::Failed,
+            _ => OperationStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for OperationStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<OperationStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OperationStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for OperationStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for OperationStatus {
+    fn deserialize<D>(deserializer: D) -> Result<OperationStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+struct OptionalSecureUrlDeserializer;
+impl OptionalSecureUrlDeserializer {
+    #[allow(unused_variables)]
+    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+        start_element(tag_name, stack)?;
+        let obj = characters(stack)?;
+        end_element(tag_name, stack)?;
+
+        Ok(obj)
+    }
+}
+/// <p>The Output data type.</p>
+#[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize_structs", derive(Serialize))]
+pub struct Output {
+    /// <p>User defined description associated with the output.</p>
+    pub description: Option<String>,
+    /// <p>The name of the export associated with the output.</p>
+    pub export_name: Option<String>,
+    /// <p>The key associated with the output.</p>
+    pub output_key: Option<String>,
+    /// <p>The value associated with the output.</p>
+    pub output_value: Option<String>,
+}
+
+struct OutputDeserializer;
+impl OutputDeserializer {
+    #[allow(unused_variables)]
+    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<Output, XmlParseError> {
+        deserialize_elements::<_, Output, _>(tag_name, stack, |name, stack, obj| {
+            match name {
+                "Description" => {
+                    obj.description =
+                        Some(DescriptionDeserializer::deserialize("Description", stack)?);
+                }
+                "ExportName" => {
+                    obj.export_name =
+                        Some(ExportNameDeserializer::deserialize("ExportName", stack)?);
+                }
+                "OutputKey" => {
+                    obj.output_key = Some(OutputKeyDeserializer::deserialize("OutputKey", stack)?);
+                }
+                "OutputValue" => {
+                    obj.output_value =
+                        Some(OutputValueDeserializer::deserialize("OutputValue", stack)?);
+                }
+                _ => skip_tree(stack),
+            }
+            Ok(())
+        })
+    }
+}
+struct OutputKeyDeserializer;
+impl OutputKeyDeserializer {
+    #[allow(unused_variables)]
+    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+        start_element(tag_name, stack)?;
+        let obj = characters(stack)?;
+        end_element(tag_name, stack)?;
+
+        Ok(obj)
+    }
+}
+struct OutputValueDeserializer;
+impl OutputValueDeserializer {
+    #[allow(unused_variables)]
+    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+        start_element(tag_name, stack)?;
+        let obj = characters(stack)?;
+        end_element(tag_name, stack)?;
+
+        Ok(obj)
+    }
+}
+struct OutputsDeserializer;
 impl OutputsDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(
@@ -4779,7 +6343,7 @@ pub struct PropertyDifference {
     /// <p>The actual property value of the resource property.</p>
     pub actual_value: String,
     /// <p><p>The type of property difference.</p> <ul> <li> <p> <code>ADD</code>: A value has been added to a resource property that is an array or list data type.</p> </li> <li> <p> <code>REMOVE</code>: The property has been removed from the current resource configuration.</p> </li> <li> <p> <code>NOT_EQUAL</code>: The current property value differs from its expected value (as defined in the stack template and any values specified as template parameters).</p> </li> </ul></p>
-    pub difference_type: String,
+    pub difference_type: DifferenceType,
     /// <p>The expected property value of the resource property, as defined in the stack template and any values specified as template parameters.</p>
     pub expected_value: String,
     /// <p>The fully-qualified path to the resource property.</p>
@@ -4869,12 +6433,115 @@ impl PropertyValueDeserializer {
         Ok(obj)
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ProvisioningType {
+    /// `NON_PROVISIONABLE`
+    NonProvisionable,
+    /// `IMMUTABLE`
+    Immutable,
+    /// `FULLY_MUTABLE`
+    FullyMutable,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ProvisioningType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ProvisioningType::NonProvisionable => "NON_PROVISIONABLE",
+            ProvisioningType::Immutable => "IMMUTABLE",
+            ProvisioningType::FullyMutable => "FULLY_MUTABLE",
+            ProvisioningType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ProvisioningType {
+    fn default() -> ProvisioningType {
+        ProvisioningType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ProvisioningType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ProvisioningType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ProvisioningType {
+    fn from(value: &'a str) -> ProvisioningType {
+        match value {
+            "NON_PROVISIONABLE" => ProvisioningType::NonProvisionable,
+            "IMMUTABLE" => ProvisioningType::Immutable,
+            "FULLY_MUTABLE" => ProvisioningType::FullyMutable,
+            _ => ProvisioningType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ProvisioningType {
+    fn from(value: String) -> ProvisioningType {
+        match value.as_str() {
+            "NON_PROVISIONABLE" => ProvisioningType::NonProvisionable,
+            "IMMUTABLE" => ProvisioningType::Immutable,
+            "FULLY_MUTABLE" => ProvisioningType::FullyMutable,
+            _ => ProvisioningType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ProvisioningType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ProvisioningType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ProvisioningType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ProvisioningType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ProvisioningType {
+    fn deserialize<D>(deserializer: D) -> Result<ProvisioningType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct ProvisioningTypeDeserializer;
 impl ProvisioningTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ProvisioningType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -4899,11 +6566,11 @@ pub struct RecordHandlerProgressInput {
     /// <p>Reserved for use by the <a href="https://docs.aws.amazon.com/cloudformation-cli/latest/userguide/what-is-cloudformation-cli.html">CloudFormation CLI</a>.</p>
     pub client_request_token: Option<String>,
     /// <p>Reserved for use by the <a href="https://docs.aws.amazon.com/cloudformation-cli/latest/userguide/what-is-cloudformation-cli.html">CloudFormation CLI</a>.</p>
-    pub current_operation_status: Option<String>,
+    pub current_operation_status: Option<OperationStatus>,
     /// <p>Reserved for use by the <a href="https://docs.aws.amazon.com/cloudformation-cli/latest/userguide/what-is-cloudformation-cli.html">CloudFormation CLI</a>.</p>
-    pub error_code: Option<String>,
+    pub error_code: Option<HandlerErrorCode>,
     /// <p>Reserved for use by the <a href="https://docs.aws.amazon.com/cloudformation-cli/latest/userguide/what-is-cloudformation-cli.html">CloudFormation CLI</a>.</p>
-    pub operation_status: String,
+    pub operation_status: OperationStatus,
     /// <p>Reserved for use by the <a href="https://docs.aws.amazon.com/cloudformation-cli/latest/userguide/what-is-cloudformation-cli.html">CloudFormation CLI</a>.</p>
     pub resource_model: Option<String>,
     /// <p>Reserved for use by the <a href="https://docs.aws.amazon.com/cloudformation-cli/latest/userguide/what-is-cloudformation-cli.html">CloudFormation CLI</a>.</p>
@@ -5017,7 +6684,7 @@ pub struct RegisterTypeInput {
     /// <p>A url to the S3 bucket containing the schema handler package that contains the schema, event handlers, and associated files for the type you want to register.</p> <p>For information on generating a schema handler package for the type you want to register, see <a href="https://docs.aws.amazon.com/cloudformation-cli/latest/userguide/resource-type-cli-submit.html">submit</a> in the <i>CloudFormation CLI User Guide</i>.</p>
     pub schema_handler_package: String,
     /// <p>The kind of type.</p> <p>Currently, the only valid value is <code>RESOURCE</code>.</p>
-    pub type_: Option<String>,
+    pub type_: Option<RegistryType>,
     /// <p><p>The name of the type being registered.</p> <p>We recommend that type names adhere to the following pattern: <i>company<em>or</em>organization</i>::<i>service</i>::<i>type</i>.</p> <note> <p>The following organization namespaces are reserved and cannot be used in your resource type names:</p> <ul> <li> <p> <code>Alexa</code> </p> </li> <li> <p> <code>AMZN</code> </p> </li> <li> <p> <code>Amazon</code> </p> </li> <li> <p> <code>AWS</code> </p> </li> <li> <p> <code>Custom</code> </p> </li> <li> <p> <code>Dev</code> </p> </li> </ul> </note></p>
     pub type_name: String,
 }
@@ -5083,12 +6750,115 @@ impl RegisterTypeOutputDeserializer {
         })
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RegistrationStatus {
+    /// `COMPLETE`
+    Complete,
+    /// `IN_PROGRESS`
+    InProgress,
+    /// `FAILED`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RegistrationStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RegistrationStatus::Complete => "COMPLETE",
+            RegistrationStatus::InProgress => "IN_PROGRESS",
+            RegistrationStatus::Failed => "FAILED",
+            RegistrationStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RegistrationStatus {
+    fn default() -> RegistrationStatus {
+        RegistrationStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RegistrationStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RegistrationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RegistrationStatus {
+    fn from(value: &'a str) -> RegistrationStatus {
+        match value {
+            "COMPLETE" => RegistrationStatus::Complete,
+            "IN_PROGRESS" => RegistrationStatus::InProgress,
+            "FAILED" => RegistrationStatus::Failed,
+            _ => RegistrationStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RegistrationStatus {
+    fn from(value: String) -> RegistrationStatus {
+        match value.as_str() {
+            "COMPLETE" => RegistrationStatus::Complete,
+            "IN_PROGRESS" => RegistrationStatus::InProgress,
+            "FAILED" => RegistrationStatus::Failed,
+            _ => RegistrationStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RegistrationStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RegistrationStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RegistrationStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for RegistrationStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for RegistrationStatus {
+    fn deserialize<D>(deserializer: D) -> Result<RegistrationStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct RegistrationStatusDeserializer;
 impl RegistrationStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<RegistrationStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -5122,45 +6892,477 @@ impl RegistrationTokenListDeserializer {
         })
     }
 }
-struct RegistryTypeDeserializer;
-impl RegistryTypeDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
-        start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
-        end_element(tag_name, stack)?;
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RegistryType {
+    /// `RESOURCE`
+    Resource,
+    /// `MODULE`
+    Module,
+    /// `HOOK`
+    Hook,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
 
-        Ok(obj)
+impl RegistryType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RegistryType::Resource => "RESOURCE",
+            RegistryType::Module => "MODULE",
+            RegistryType::Hook => "HOOK",
+            RegistryType::Unknown(ref value) => value,
+        }
     }
 }
-struct ReplacementDeserializer;
-impl ReplacementDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
-        start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
-        end_element(tag_name, stack)?;
 
-        Ok(obj)
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RegistryType {
+    fn default() -> RegistryType {
+        RegistryType::Unknown(String::new())
     }
 }
-struct RequiresRecreationDeserializer;
-impl RequiresRecreationDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
-        start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
-        end_element(tag_name, stack)?;
 
-        Ok(obj)
+impl AsRef<str> for RegistryType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RegistryType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RegistryType {
+    fn from(value: &'a str) -> RegistryType {
+        match value {
+            "RESOURCE" => RegistryType::Resource,
+            "MODULE" => RegistryType::Module,
+            "HOOK" => RegistryType::Hook,
+            _ => RegistryType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RegistryType {
+    fn from(value: String) -> RegistryType {
+        match value.as_str() {
+            "RESOURCE" => RegistryType::Resource,
+            "MODULE" => RegistryType::Module,
+            "HOOK" => RegistryType::Hook,
+            _ => RegistryType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RegistryType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RegistryType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RegistryType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for RegistryType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for RegistryType {
+    fn deserialize<D>(deserializer: D) -> Result<RegistryType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+struct RegistryTypeDeserializer;
+impl RegistryTypeDeserializer {
+    #[allow(unused_variables)]
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<RegistryType, XmlParseError> {
+        start_element(tag_name, stack)?;
+        let obj = characters(stack)?.into();
+        end_element(tag_name, stack)?;
+
+        Ok(obj)
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Replacement {
+    /// `True`
+    True,
+    /// `False`
+    False,
+    /// `Conditional`
+    Conditional,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Replacement {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Replacement::True => "True",
+            Replacement::False => "False",
+            Replacement::Conditional => "Conditional",
+            Replacement::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Replacement {
+    fn default() -> Replacement {
+        Replacement::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Replacement {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Replacement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Replacement {
+    fn from(value: &'a str) -> Replacement {
+        match value {
+            "True" => Replacement::True,
+            "False" => Replacement::False,
+            "Conditional" => Replacement::Conditional,
+            _ => Replacement::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Replacement {
+    fn from(value: String) -> Replacement {
+        match value.as_str() {
+            "True" => Replacement::True,
+            "False" => Replacement::False,
+            "Conditional" => Replacement::Conditional,
+            _ => Replacement::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Replacement {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Replacement, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Replacement {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for Replacement {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for Replacement {
+    fn deserialize<D>(deserializer: D) -> Result<Replacement, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+struct ReplacementDeserializer;
+impl ReplacementDeserializer {
+    #[allow(unused_variables)]
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Replacement, XmlParseError> {
+        start_element(tag_name, stack)?;
+        let obj = characters(stack)?.into();
+        end_element(tag_name, stack)?;
+
+        Ok(obj)
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RequiresRecreation {
+    /// `Never`
+    Never,
+    /// `Conditionally`
+    Conditionally,
+    /// `Always`
+    Always,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RequiresRecreation {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RequiresRecreation::Never => "Never",
+            RequiresRecreation::Conditionally => "Conditionally",
+            RequiresRecreation::Always => "Always",
+            RequiresRecreation::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RequiresRecreation {
+    fn default() -> RequiresRecreation {
+        RequiresRecreation::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RequiresRecreation {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RequiresRecreation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RequiresRecreation {
+    fn from(value: &'a str) -> RequiresRecreation {
+        match value {
+            "Never" => RequiresRecreation::Never,
+            "Conditionally" => RequiresRecreation::Conditionally,
+            "Always" => RequiresRecreation::Always,
+            _ => RequiresRecreation::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RequiresRecreation {
+    fn from(value: String) -> RequiresRecreation {
+        match value.as_str() {
+            "Never" => RequiresRecreation::Never,
+            "Conditionally" => RequiresRecreation::Conditionally,
+            "Always" => RequiresRecreation::Always,
+            _ => RequiresRecreation::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RequiresRecreation {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RequiresRecreation, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RequiresRecreation {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for RequiresRecreation {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for RequiresRecreation {
+    fn deserialize<D>(deserializer: D) -> Result<RequiresRecreation, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+struct RequiresRecreationDeserializer;
+impl RequiresRecreationDeserializer {
+    #[allow(unused_variables)]
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<RequiresRecreation, XmlParseError> {
+        start_element(tag_name, stack)?;
+        let obj = characters(stack)?.into();
+        end_element(tag_name, stack)?;
+
+        Ok(obj)
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ResourceAttribute {
+    /// `Properties`
+    Properties,
+    /// `Metadata`
+    Metadata,
+    /// `CreationPolicy`
+    CreationPolicy,
+    /// `UpdatePolicy`
+    UpdatePolicy,
+    /// `DeletionPolicy`
+    DeletionPolicy,
+    /// `UpdateReplacePolicy`
+    UpdateReplacePolicy,
+    /// `Tags`
+    Tags,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ResourceAttribute {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceAttribute::Properties => "Properties",
+            ResourceAttribute::Metadata => "Metadata",
+            ResourceAttribute::CreationPolicy => "CreationPolicy",
+            ResourceAttribute::UpdatePolicy => "UpdatePolicy",
+            ResourceAttribute::DeletionPolicy => "DeletionPolicy",
+            ResourceAttribute::UpdateReplacePolicy => "UpdateReplacePolicy",
+            ResourceAttribute::Tags => "Tags",
+            ResourceAttribute::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ResourceAttribute {
+    fn default() -> ResourceAttribute {
+        ResourceAttribute::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ResourceAttribute {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ResourceAttribute {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ResourceAttribute {
+    fn from(value: &'a str) -> ResourceAttribute {
+        match value {
+            "Properties" => ResourceAttribute::Properties,
+            "Metadata" => ResourceAttribute::Metadata,
+            "CreationPolicy" => ResourceAttribute::CreationPolicy,
+            "UpdatePolicy" => ResourceAttribute::UpdatePolicy,
+            "DeletionPolicy" => ResourceAttribute::DeletionPolicy,
+            "UpdateReplacePolicy" => ResourceAttribute::UpdateReplacePolicy,
+            "Tags" => ResourceAttribute::Tags,
+            _ => ResourceAttribute::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceAttribute {
+    fn from(value: String) -> ResourceAttribute {
+        match value.as_str() {
+            "Properties" => ResourceAttribute::Properties,
+            "Metadata" => ResourceAttribute::Metadata,
+            "CreationPolicy" => ResourceAttribute::CreationPolicy,
+            "UpdatePolicy" => ResourceAttribute::UpdatePolicy,
+            "DeletionPolicy" => ResourceAttribute::DeletionPolicy,
+            "UpdateReplacePolicy" => ResourceAttribute::UpdateReplacePolicy,
+            "Tags" => ResourceAttribute::Tags,
+            _ => ResourceAttribute::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ResourceAttribute {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ResourceAttribute, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ResourceAttribute {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ResourceAttribute {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ResourceAttribute {
+    fn deserialize<D>(deserializer: D) -> Result<ResourceAttribute, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
     }
 }
 struct ResourceAttributeDeserializer;
 impl ResourceAttributeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ResourceAttribute, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -5171,7 +7373,7 @@ impl ResourceAttributeDeserializer {
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct ResourceChange {
     /// <p>The action that AWS CloudFormation takes on the resource, such as <code>Add</code> (adds a new resource), <code>Modify</code> (changes a resource), or <code>Remove</code> (deletes a resource).</p>
-    pub action: Option<String>,
+    pub action: Option<ChangeAction>,
     /// <p>For the <code>Modify</code> action, a list of <code>ResourceChangeDetail</code> structures that describes the changes that AWS CloudFormation will make to the resource. </p>
     pub details: Option<Vec<ResourceChangeDetail>>,
     /// <p>The resource's logical ID, which is defined in the stack's template.</p>
@@ -5179,11 +7381,11 @@ pub struct ResourceChange {
     /// <p>The resource's physical ID (resource name). Resources that you are adding don't have physical IDs because they haven't been created.</p>
     pub physical_resource_id: Option<String>,
     /// <p>For the <code>Modify</code> action, indicates whether AWS CloudFormation will replace the resource by creating a new one and deleting the old one. This value depends on the value of the <code>RequiresRecreation</code> property in the <code>ResourceTargetDefinition</code> structure. For example, if the <code>RequiresRecreation</code> field is <code>Always</code> and the <code>Evaluation</code> field is <code>Static</code>, <code>Replacement</code> is <code>True</code>. If the <code>RequiresRecreation</code> field is <code>Always</code> and the <code>Evaluation</code> field is <code>Dynamic</code>, <code>Replacement</code> is <code>Conditionally</code>.</p> <p>If you have multiple changes with different <code>RequiresRecreation</code> values, the <code>Replacement</code> value depends on the change with the most impact. A <code>RequiresRecreation</code> value of <code>Always</code> has the most impact, followed by <code>Conditionally</code>, and then <code>Never</code>.</p>
-    pub replacement: Option<String>,
+    pub replacement: Option<Replacement>,
     /// <p>The type of AWS CloudFormation resource, such as <code>AWS::S3::Bucket</code>.</p>
     pub resource_type: Option<String>,
     /// <p>For the <code>Modify</code> action, indicates which resource attribute is triggering this update, such as a change in the resource attribute's <code>Metadata</code>, <code>Properties</code>, or <code>Tags</code>.</p>
-    pub scope: Option<Vec<String>>,
+    pub scope: Option<Vec<ResourceAttribute>>,
 }
 
 struct ResourceChangeDeserializer;
@@ -5243,9 +7445,9 @@ pub struct ResourceChangeDetail {
     /// <p>The identity of the entity that triggered this change. This entity is a member of the group that is specified by the <code>ChangeSource</code> field. For example, if you modified the value of the <code>KeyPairName</code> parameter, the <code>CausingEntity</code> is the name of the parameter (<code>KeyPairName</code>).</p> <p>If the <code>ChangeSource</code> value is <code>DirectModification</code>, no value is given for <code>CausingEntity</code>.</p>
     pub causing_entity: Option<String>,
     /// <p><p>The group to which the <code>CausingEntity</code> value belongs. There are five entity groups:</p> <ul> <li> <p> <code>ResourceReference</code> entities are <code>Ref</code> intrinsic functions that refer to resources in the template, such as <code>{ &quot;Ref&quot; : &quot;MyEC2InstanceResource&quot; }</code>.</p> </li> <li> <p> <code>ParameterReference</code> entities are <code>Ref</code> intrinsic functions that get template parameter values, such as <code>{ &quot;Ref&quot; : &quot;MyPasswordParameter&quot; }</code>.</p> </li> <li> <p> <code>ResourceAttribute</code> entities are <code>Fn::GetAtt</code> intrinsic functions that get resource attribute values, such as <code>{ &quot;Fn::GetAtt&quot; : [ &quot;MyEC2InstanceResource&quot;, &quot;PublicDnsName&quot; ] }</code>.</p> </li> <li> <p> <code>DirectModification</code> entities are changes that are made directly to the template.</p> </li> <li> <p> <code>Automatic</code> entities are <code>AWS::CloudFormation::Stack</code> resource types, which are also known as nested stacks. If you made no changes to the <code>AWS::CloudFormation::Stack</code> resource, AWS CloudFormation sets the <code>ChangeSource</code> to <code>Automatic</code> because the nested stack&#39;s template might have changed. Changes to a nested stack&#39;s template aren&#39;t visible to AWS CloudFormation until you run an update on the parent stack.</p> </li> </ul></p>
-    pub change_source: Option<String>,
+    pub change_source: Option<ChangeSource>,
     /// <p>Indicates whether AWS CloudFormation can determine the target value, and whether the target value will change before you execute a change set.</p> <p>For <code>Static</code> evaluations, AWS CloudFormation can determine that the target value will change, and its value. For example, if you directly modify the <code>InstanceType</code> property of an EC2 instance, AWS CloudFormation knows that this property value will change, and its value, so this is a <code>Static</code> evaluation.</p> <p>For <code>Dynamic</code> evaluations, cannot determine the target value because it depends on the result of an intrinsic function, such as a <code>Ref</code> or <code>Fn::GetAtt</code> intrinsic function, when the stack is updated. For example, if your template includes a reference to a resource that is conditionally recreated, the value of the reference (the physical ID of the resource) might change, depending on if the resource is recreated. If the resource is recreated, it will have a new physical ID, so all references to that resource will also be updated.</p>
-    pub evaluation: Option<String>,
+    pub evaluation: Option<EvaluationType>,
     /// <p>A <code>ResourceTargetDefinition</code> structure that describes the field that AWS CloudFormation will change and whether the resource will be recreated.</p>
     pub target: Option<ResourceTargetDefinition>,
 }
@@ -5437,12 +7639,305 @@ impl ResourcePropertiesDeserializer {
         Ok(obj)
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ResourceSignalStatus {
+    /// `SUCCESS`
+    Success,
+    /// `FAILURE`
+    Failure,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ResourceSignalStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceSignalStatus::Success => "SUCCESS",
+            ResourceSignalStatus::Failure => "FAILURE",
+            ResourceSignalStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ResourceSignalStatus {
+    fn default() -> ResourceSignalStatus {
+        ResourceSignalStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ResourceSignalStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ResourceSignalStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ResourceSignalStatus {
+    fn from(value: &'a str) -> ResourceSignalStatus {
+        match value {
+            "SUCCESS" => ResourceSignalStatus::Success,
+            "FAILURE" => ResourceSignalStatus::Failure,
+            _ => ResourceSignalStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceSignalStatus {
+    fn from(value: String) -> ResourceSignalStatus {
+        match value.as_str() {
+            "SUCCESS" => ResourceSignalStatus::Success,
+            "FAILURE" => ResourceSignalStatus::Failure,
+            _ => ResourceSignalStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ResourceSignalStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ResourceSignalStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ResourceSignalStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ResourceSignalStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ResourceSignalStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ResourceSignalStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ResourceStatus {
+    /// `CREATE_IN_PROGRESS`
+    CreateInProgress,
+    /// `CREATE_FAILED`
+    CreateFailed,
+    /// `CREATE_COMPLETE`
+    CreateComplete,
+    /// `DELETE_IN_PROGRESS`
+    DeleteInProgress,
+    /// `DELETE_FAILED`
+    DeleteFailed,
+    /// `DELETE_COMPLETE`
+    DeleteComplete,
+    /// `DELETE_SKIPPED`
+    DeleteSkipped,
+    /// `UPDATE_IN_PROGRESS`
+    UpdateInProgress,
+    /// `UPDATE_FAILED`
+    UpdateFailed,
+    /// `UPDATE_COMPLETE`
+    UpdateComplete,
+    /// `IMPORT_FAILED`
+    ImportFailed,
+    /// `IMPORT_COMPLETE`
+    ImportComplete,
+    /// `IMPORT_IN_PROGRESS`
+    ImportInProgress,
+    /// `IMPORT_ROLLBACK_IN_PROGRESS`
+    ImportRollbackInProgress,
+    /// `IMPORT_ROLLBACK_FAILED`
+    ImportRollbackFailed,
+    /// `IMPORT_ROLLBACK_COMPLETE`
+    ImportRollbackComplete,
+    /// `UPDATE_ROLLBACK_IN_PROGRESS`
+    UpdateRollbackInProgress,
+    /// `UPDATE_ROLLBACK_COMPLETE`
+    UpdateRollbackComplete,
+    /// `UPDATE_ROLLBACK_FAILED`
+    UpdateRollbackFailed,
+    /// `ROLLBACK_IN_PROGRESS`
+    RollbackInProgress,
+    /// `ROLLBACK_COMPLETE`
+    RollbackComplete,
+    /// `ROLLBACK_FAILED`
+    RollbackFailed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ResourceStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceStatus::CreateInProgress => "CREATE_IN_PROGRESS",
+            ResourceStatus::CreateFailed => "CREATE_FAILED",
+            ResourceStatus::CreateComplete => "CREATE_COMPLETE",
+            ResourceStatus::DeleteInProgress => "DELETE_IN_PROGRESS",
+            ResourceStatus::DeleteFailed => "DELETE_FAILED",
+            ResourceStatus::DeleteComplete => "DELETE_COMPLETE",
+            ResourceStatus::DeleteSkipped => "DELETE_SKIPPED",
+            ResourceStatus::UpdateInProgress => "UPDATE_IN_PROGRESS",
+            ResourceStatus::UpdateFailed => "UPDATE_FAILED",
+            ResourceStatus::UpdateComplete => "UPDATE_COMPLETE",
+            ResourceStatus::ImportFailed => "IMPORT_FAILED",
+            ResourceStatus::ImportComplete => "IMPORT_COMPLETE",
+            ResourceStatus::ImportInProgress => "IMPORT_IN_PROGRESS",
+            ResourceStatus::ImportRollbackInProgress => "IMPORT_ROLLBACK_IN_PROGRESS",
+            ResourceStatus::ImportRollbackFailed => "IMPORT_ROLLBACK_FAILED",
+            ResourceStatus::ImportRollbackComplete => "IMPORT_ROLLBACK_COMPLETE",
+            ResourceStatus::UpdateRollbackInProgress => "UPDATE_ROLLBACK_IN_PROGRESS",
+            ResourceStatus::UpdateRollbackComplete => "UPDATE_ROLLBACK_COMPLETE",
+            ResourceStatus::UpdateRollbackFailed => "UPDATE_ROLLBACK_FAILED",
+            ResourceStatus::RollbackInProgress => "ROLLBACK_IN_PROGRESS",
+            ResourceStatus::RollbackComplete => "ROLLBACK_COMPLETE",
+            ResourceStatus::RollbackFailed => "ROLLBACK_FAILED",
+            ResourceStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ResourceStatus {
+    fn default() -> ResourceStatus {
+        ResourceStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ResourceStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ResourceStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ResourceStatus {
+    fn from(value: &'a str) -> ResourceStatus {
+        match value {
+            "CREATE_IN_PROGRESS" => ResourceStatus::CreateInProgress,
+            "CREATE_FAILED" => ResourceStatus::CreateFailed,
+            "CREATE_COMPLETE" => ResourceStatus::CreateComplete,
+            "DELETE_IN_PROGRESS" => ResourceStatus::DeleteInProgress,
+            "DELETE_FAILED" => ResourceStatus::DeleteFailed,
+            "DELETE_COMPLETE" => ResourceStatus::DeleteComplete,
+            "DELETE_SKIPPED" => ResourceStatus::DeleteSkipped,
+            "UPDATE_IN_PROGRESS" => ResourceStatus::UpdateInProgress,
+            "UPDATE_FAILED" => ResourceStatus::UpdateFailed,
+            "UPDATE_COMPLETE" => ResourceStatus::UpdateComplete,
+            "IMPORT_FAILED" => ResourceStatus::ImportFailed,
+            "IMPORT_COMPLETE" => ResourceStatus::ImportComplete,
+            "IMPORT_IN_PROGRESS" => ResourceStatus::ImportInProgress,
+            "IMPORT_ROLLBACK_IN_PROGRESS" => ResourceStatus::ImportRollbackInProgress,
+            "IMPORT_ROLLBACK_FAILED" => ResourceStatus::ImportRollbackFailed,
+            "IMPORT_ROLLBACK_COMPLETE" => ResourceStatus::ImportRollbackComplete,
+            "UPDATE_ROLLBACK_IN_PROGRESS" => ResourceStatus::UpdateRollbackInProgress,
+            "UPDATE_ROLLBACK_COMPLETE" => ResourceStatus::UpdateRollbackComplete,
+            "UPDATE_ROLLBACK_FAILED" => ResourceStatus::UpdateRollbackFailed,
+            "ROLLBACK_IN_PROGRESS" => ResourceStatus::RollbackInProgress,
+            "ROLLBACK_COMPLETE" => ResourceStatus::RollbackComplete,
+            "ROLLBACK_FAILED" => ResourceStatus::RollbackFailed,
+            _ => ResourceStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceStatus {
+    fn from(value: String) -> ResourceStatus {
+        match value.as_str() {
+            "CREATE_IN_PROGRESS" => ResourceStatus::CreateInProgress,
+            "CREATE_FAILED" => ResourceStatus::CreateFailed,
+            "CREATE_COMPLETE" => ResourceStatus::CreateComplete,
+            "DELETE_IN_PROGRESS" => ResourceStatus::DeleteInProgress,
+            "DELETE_FAILED" => ResourceStatus::DeleteFailed,
+            "DELETE_COMPLETE" => ResourceStatus::DeleteComplete,
+            "DELETE_SKIPPED" => ResourceStatus::DeleteSkipped,
+            "UPDATE_IN_PROGRESS" => ResourceStatus::UpdateInProgress,
+            "UPDATE_FAILED" => ResourceStatus::UpdateFailed,
+            "UPDATE_COMPLETE" => ResourceStatus::UpdateComplete,
+            "IMPORT_FAILED" => ResourceStatus::ImportFailed,
+            "IMPORT_COMPLETE" => ResourceStatus::ImportComplete,
+            "IMPORT_IN_PROGRESS" => ResourceStatus::ImportInProgress,
+            "IMPORT_ROLLBACK_IN_PROGRESS" => ResourceStatus::ImportRollbackInProgress,
+            "IMPORT_ROLLBACK_FAILED" => ResourceStatus::ImportRollbackFailed,
+            "IMPORT_ROLLBACK_COMPLETE" => ResourceStatus::ImportRollbackComplete,
+            "UPDATE_ROLLBACK_IN_PROGRESS" => ResourceStatus::UpdateRollbackInProgress,
+            "UPDATE_ROLLBACK_COMPLETE" => ResourceStatus::UpdateRollbackComplete,
+            "UPDATE_ROLLBACK_FAILED" => ResourceStatus::UpdateRollbackFailed,
+            "ROLLBACK_IN_PROGRESS" => ResourceStatus::RollbackInProgress,
+            "ROLLBACK_COMPLETE" => ResourceStatus::RollbackComplete,
+            "ROLLBACK_FAILED" => ResourceStatus::RollbackFailed,
+            _ => ResourceStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ResourceStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ResourceStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ResourceStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ResourceStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ResourceStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ResourceStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct ResourceStatusDeserializer;
 impl ResourceStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ResourceStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -5464,11 +7959,11 @@ impl ResourceStatusReasonDeserializer {
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct ResourceTargetDefinition {
     /// <p>Indicates which resource attribute is triggering this update, such as a change in the resource attribute's <code>Metadata</code>, <code>Properties</code>, or <code>Tags</code>.</p>
-    pub attribute: Option<String>,
+    pub attribute: Option<ResourceAttribute>,
     /// <p>If the <code>Attribute</code> value is <code>Properties</code>, the name of the property. For all other attributes, the value is null.</p>
     pub name: Option<String>,
     /// <p>If the <code>Attribute</code> value is <code>Properties</code>, indicates whether a change to this property causes the resource to be recreated. The value can be <code>Never</code>, <code>Always</code>, or <code>Conditionally</code>. To determine the conditions for a <code>Conditionally</code> recreation, see the update behavior for that <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-template-resource-type-ref.html">property</a> in the AWS CloudFormation User Guide.</p>
-    pub requires_recreation: Option<String>,
+    pub requires_recreation: Option<RequiresRecreation>,
 }
 
 struct ResourceTargetDefinitionDeserializer;
@@ -5792,7 +8287,7 @@ impl ScopeDeserializer {
     fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
-    ) -> Result<Vec<String>, XmlParseError> {
+    ) -> Result<Vec<ResourceAttribute>, XmlParseError> {
         deserialize_elements::<_, Vec<_>, _>(tag_name, stack, |name, stack, obj| {
             if name == "member" {
                 obj.push(ResourceAttributeDeserializer::deserialize("member", stack)?);
@@ -5840,7 +8335,7 @@ pub struct SetTypeDefaultVersionInput {
     /// <p>The Amazon Resource Name (ARN) of the type for which you want version summary information.</p> <p>Conditional: You must specify <code>TypeName</code> or <code>Arn</code>.</p>
     pub arn: Option<String>,
     /// <p>The kind of type.</p>
-    pub type_: Option<String>,
+    pub type_: Option<RegistryType>,
     /// <p>The name of the type.</p> <p>Conditional: You must specify <code>TypeName</code> or <code>Arn</code>.</p>
     pub type_name: Option<String>,
     /// <p>The ID of a specific version of the type. The version ID is the value at the end of the Amazon Resource Name (ARN) assigned to the type version when it is registered.</p>
@@ -5900,7 +8395,7 @@ pub struct SignalResourceInput {
     /// <p>The stack name or unique stack ID that includes the resource that you want to signal.</p>
     pub stack_name: String,
     /// <p>The status of the signal, which is either success or failure. A failure signal causes AWS CloudFormation to immediately fail the stack creation or update.</p>
-    pub status: String,
+    pub status: ResourceSignalStatus,
     /// <p>A unique ID of the signal. When you signal Amazon EC2 instances or Auto Scaling groups, specify the instance ID that you are signaling as the unique ID. If you send multiple signals to a single resource (such as signaling a wait condition), each signal requires a different unique ID.</p>
     pub unique_id: String,
 }
@@ -5929,13 +8424,13 @@ impl SignalResourceInputSerializer {
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct Stack {
     /// <p>The capabilities allowed in the stack.</p>
-    pub capabilities: Option<Vec<String>>,
+    pub capabilities: Option<Vec<Capability>>,
     /// <p>The unique ID of the change set.</p>
     pub change_set_id: Option<String>,
     /// <p>The time at which the stack was created.</p>
-    pub creation_time: String,
+    pub creation_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The time the stack was deleted.</p>
-    pub deletion_time: Option<String>,
+    pub deletion_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A user-defined description associated with the stack.</p>
     pub description: Option<String>,
     /// <p><p>Boolean to enable or disable rollback on stack creation failures:</p> <ul> <li> <p> <code>true</code>: disable rollback</p> </li> <li> <p> <code>false</code>: enable rollback</p> </li> </ul></p>
@@ -5945,7 +8440,7 @@ pub struct Stack {
     /// <p>Whether termination protection is enabled for the stack.</p> <p> For <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-nested-stacks.html">nested stacks</a>, termination protection is set on the root stack and cannot be changed directly on the nested stack. For more information, see <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-protect-stacks.html">Protecting a Stack From Being Deleted</a> in the <i>AWS CloudFormation User Guide</i>.</p>
     pub enable_termination_protection: Option<bool>,
     /// <p>The time the stack was last updated. This field will only be returned if the stack has been updated at least once.</p>
-    pub last_updated_time: Option<String>,
+    pub last_updated_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>SNS topic ARNs to which stack related events are published.</p>
     pub notification_ar_ns: Option<Vec<String>>,
     /// <p>A list of output structures.</p>
@@ -5965,7 +8460,7 @@ pub struct Stack {
     /// <p>The name associated with the stack.</p>
     pub stack_name: String,
     /// <p>Current status of the stack.</p>
-    pub stack_status: String,
+    pub stack_status: StackStatus,
     /// <p>Success/failure message associated with the stack status.</p>
     pub stack_status_reason: Option<String>,
     /// <p>A list of <code>Tag</code>s that specify information about the stack.</p>
@@ -6102,12 +8597,115 @@ impl StackDriftDetectionIdDeserializer {
         Ok(obj)
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StackDriftDetectionStatus {
+    /// `DETECTION_IN_PROGRESS`
+    DetectionInProgress,
+    /// `DETECTION_FAILED`
+    DetectionFailed,
+    /// `DETECTION_COMPLETE`
+    DetectionComplete,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl StackDriftDetectionStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StackDriftDetectionStatus::DetectionInProgress => "DETECTION_IN_PROGRESS",
+            StackDriftDetectionStatus::DetectionFailed => "DETECTION_FAILED",
+            StackDriftDetectionStatus::DetectionComplete => "DETECTION_COMPLETE",
+            StackDriftDetectionStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for StackDriftDetectionStatus {
+    fn default() -> StackDriftDetectionStatus {
+        StackDriftDetectionStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for StackDriftDetectionStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for StackDriftDetectionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for StackDriftDetectionStatus {
+    fn from(value: &'a str) -> StackDriftDetectionStatus {
+        match value {
+            "DETECTION_IN_PROGRESS" => StackDriftDetectionStatus::DetectionInProgress,
+            "DETECTION_FAILED" => StackDriftDetectionStatus::DetectionFailed,
+            "DETECTION_COMPLETE" => StackDriftDetectionStatus::DetectionComplete,
+            _ => StackDriftDetectionStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StackDriftDetectionStatus {
+    fn from(value: String) -> StackDriftDetectionStatus {
+        match value.as_str() {
+            "DETECTION_IN_PROGRESS" => StackDriftDetectionStatus::DetectionInProgress,
+            "DETECTION_FAILED" => StackDriftDetectionStatus::DetectionFailed,
+            "DETECTION_COMPLETE" => StackDriftDetectionStatus::DetectionComplete,
+            _ => StackDriftDetectionStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for StackDriftDetectionStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<StackDriftDetectionStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StackDriftDetectionStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for StackDriftDetectionStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for StackDriftDetectionStatus {
+    fn deserialize<D>(deserializer: D) -> Result<StackDriftDetectionStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct StackDriftDetectionStatusDeserializer;
 impl StackDriftDetectionStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<StackDriftDetectionStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -6129,9 +8727,9 @@ impl StackDriftDetectionStatusReasonDeserializer {
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct StackDriftInformation {
     /// <p>Most recent time when a drift detection operation was initiated on the stack, or any of its individual resources that support drift detection.</p>
-    pub last_check_timestamp: Option<String>,
+    pub last_check_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>Status of the stack&#39;s actual configuration compared to its expected template configuration. </p> <ul> <li> <p> <code>DRIFTED</code>: The stack differs from its expected template configuration. A stack is considered to have drifted if one or more of its resources have drifted.</p> </li> <li> <p> <code>NOT<em>CHECKED</code>: AWS CloudFormation has not checked if the stack differs from its expected template configuration.</p> </li> <li> <p> <code>IN</em>SYNC</code>: The stack&#39;s actual configuration matches its expected template configuration.</p> </li> <li> <p> <code>UNKNOWN</code>: This value is reserved for future use.</p> </li> </ul></p>
-    pub stack_drift_status: String,
+    pub stack_drift_status: StackDriftStatus,
 }
 
 struct StackDriftInformationDeserializer;
@@ -6164,9 +8762,9 @@ impl StackDriftInformationDeserializer {
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct StackDriftInformationSummary {
     /// <p>Most recent time when a drift detection operation was initiated on the stack, or any of its individual resources that support drift detection.</p>
-    pub last_check_timestamp: Option<String>,
+    pub last_check_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>Status of the stack&#39;s actual configuration compared to its expected template configuration. </p> <ul> <li> <p> <code>DRIFTED</code>: The stack differs from its expected template configuration. A stack is considered to have drifted if one or more of its resources have drifted.</p> </li> <li> <p> <code>NOT<em>CHECKED</code>: AWS CloudFormation has not checked if the stack differs from its expected template configuration.</p> </li> <li> <p> <code>IN</em>SYNC</code>: The stack&#39;s actual configuration matches its expected template configuration.</p> </li> <li> <p> <code>UNKNOWN</code>: This value is reserved for future use.</p> </li> </ul></p>
-    pub stack_drift_status: String,
+    pub stack_drift_status: StackDriftStatus,
 }
 
 struct StackDriftInformationSummaryDeserializer;
@@ -6198,12 +8796,120 @@ impl StackDriftInformationSummaryDeserializer {
         )
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StackDriftStatus {
+    /// `DRIFTED`
+    Drifted,
+    /// `IN_SYNC`
+    InSync,
+    /// `UNKNOWN`
+    UnknownValue,
+    /// `NOT_CHECKED`
+    NotChecked,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl StackDriftStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StackDriftStatus::Drifted => "DRIFTED",
+            StackDriftStatus::InSync => "IN_SYNC",
+            StackDriftStatus::UnknownValue => "UNKNOWN",
+            StackDriftStatus::NotChecked => "NOT_CHECKED",
+            StackDriftStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for StackDriftStatus {
+    fn default() -> StackDriftStatus {
+        StackDriftStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for StackDriftStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for StackDriftStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for StackDriftStatus {
+    fn from(value: &'a str) -> StackDriftStatus {
+        match value {
+            "DRIFTED" => StackDriftStatus::Drifted,
+            "IN_SYNC" => StackDriftStatus::InSync,
+            "UNKNOWN" => StackDriftStatus::UnknownValue,
+            "NOT_CHECKED" => StackDriftStatus::NotChecked,
+            _ => StackDriftStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StackDriftStatus {
+    fn from(value: String) -> StackDriftStatus {
+        match value.as_str() {
+            "DRIFTED" => StackDriftStatus::Drifted,
+            "IN_SYNC" => StackDriftStatus::InSync,
+            "UNKNOWN" => StackDriftStatus::UnknownValue,
+            "NOT_CHECKED" => StackDriftStatus::NotChecked,
+            _ => StackDriftStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for StackDriftStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<StackDriftStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StackDriftStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for StackDriftStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for StackDriftStatus {
+    fn deserialize<D>(deserializer: D) -> Result<StackDriftStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct StackDriftStatusDeserializer;
 impl StackDriftStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<StackDriftStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -6224,7 +8930,7 @@ pub struct StackEvent {
     /// <p>BLOB of the properties used to create the resource.</p>
     pub resource_properties: Option<String>,
     /// <p>Current status of the resource.</p>
-    pub resource_status: Option<String>,
+    pub resource_status: Option<ResourceStatus>,
     /// <p>Success/failure message associated with the resource.</p>
     pub resource_status_reason: Option<String>,
     /// <p>Type of resource. (For more information, go to <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-template-resource-type-ref.html"> AWS Resource Types Reference</a> in the AWS CloudFormation User Guide.)</p>
@@ -6234,7 +8940,7 @@ pub struct StackEvent {
     /// <p>The name associated with a stack.</p>
     pub stack_name: String,
     /// <p>Time the status was updated.</p>
-    pub timestamp: String,
+    pub timestamp: ::chrono::DateTime<::chrono::Utc>,
 }
 
 struct StackEventDeserializer;
@@ -6342,9 +9048,9 @@ pub struct StackInstance {
     /// <p>The name of the AWS account that the stack instance is associated with.</p>
     pub account: Option<String>,
     /// <p><p>Status of the stack instance&#39;s actual configuration compared to the expected template and parameter configuration of the stack set to which it belongs. </p> <ul> <li> <p> <code>DRIFTED</code>: The stack differs from the expected template and parameter configuration of the stack set to which it belongs. A stack instance is considered to have drifted if one or more of the resources in the associated stack have drifted.</p> </li> <li> <p> <code>NOT<em>CHECKED</code>: AWS CloudFormation has not checked if the stack instance differs from its expected stack set configuration.</p> </li> <li> <p> <code>IN</em>SYNC</code>: The stack instance&#39;s actual configuration matches its expected stack set configuration.</p> </li> <li> <p> <code>UNKNOWN</code>: This value is reserved for future use.</p> </li> </ul></p>
-    pub drift_status: Option<String>,
+    pub drift_status: Option<StackDriftStatus>,
     /// <p>Most recent time when CloudFormation performed a drift detection operation on the stack instance. This value will be <code>NULL</code> for any stack instance on which drift detection has not yet been performed.</p>
-    pub last_drift_check_timestamp: Option<String>,
+    pub last_drift_check_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A list of parameters from the stack set template whose values have been overridden in this stack instance.</p>
     pub parameter_overrides: Option<Vec<Parameter>>,
     /// <p>The name of the AWS region that the stack instance is associated with.</p>
@@ -6354,7 +9060,7 @@ pub struct StackInstance {
     /// <p>The name or unique ID of the stack set that the stack instance is associated with.</p>
     pub stack_set_id: Option<String>,
     /// <p><p>The status of the stack instance, in terms of its synchronization with its associated stack set.</p> <ul> <li> <p> <code>INOPERABLE</code>: A <code>DeleteStackInstances</code> operation has failed and left the stack in an unstable state. Stacks in this state are excluded from further <code>UpdateStackSet</code> operations. You might need to perform a <code>DeleteStackInstances</code> operation, with <code>RetainStacks</code> set to <code>true</code>, to delete the stack instance, and then delete the stack manually.</p> </li> <li> <p> <code>OUTDATED</code>: The stack isn&#39;t currently up to date with the stack set because:</p> <ul> <li> <p>The associated stack failed during a <code>CreateStackSet</code> or <code>UpdateStackSet</code> operation. </p> </li> <li> <p>The stack was part of a <code>CreateStackSet</code> or <code>UpdateStackSet</code> operation that failed or was stopped before the stack was created or updated. </p> </li> </ul> </li> <li> <p> <code>CURRENT</code>: The stack is currently up to date with the stack set.</p> </li> </ul></p>
-    pub status: Option<String>,
+    pub status: Option<StackInstanceStatus>,
     /// <p>The explanation for the specific status code that is assigned to this stack instance.</p>
     pub status_reason: Option<String>,
 }
@@ -6413,12 +9119,115 @@ impl StackInstanceDeserializer {
         })
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StackInstanceStatus {
+    /// `CURRENT`
+    Current,
+    /// `OUTDATED`
+    Outdated,
+    /// `INOPERABLE`
+    Inoperable,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl StackInstanceStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StackInstanceStatus::Current => "CURRENT",
+            StackInstanceStatus::Outdated => "OUTDATED",
+            StackInstanceStatus::Inoperable => "INOPERABLE",
+            StackInstanceStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for StackInstanceStatus {
+    fn default() -> StackInstanceStatus {
+        StackInstanceStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for StackInstanceStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for StackInstanceStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for StackInstanceStatus {
+    fn from(value: &'a str) -> StackInstanceStatus {
+        match value {
+            "CURRENT" => StackInstanceStatus::Current,
+            "OUTDATED" => StackInstanceStatus::Outdated,
+            "INOPERABLE" => StackInstanceStatus::Inoperable,
+            _ => StackInstanceStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StackInstanceStatus {
+    fn from(value: String) -> StackInstanceStatus {
+        match value.as_str() {
+            "CURRENT" => StackInstanceStatus::Current,
+            "OUTDATED" => StackInstanceStatus::Outdated,
+            "INOPERABLE" => StackInstanceStatus::Inoperable,
+            _ => StackInstanceStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for StackInstanceStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<StackInstanceStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StackInstanceStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for StackInstanceStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for StackInstanceStatus {
+    fn deserialize<D>(deserializer: D) -> Result<StackInstanceStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct StackInstanceStatusDeserializer;
 impl StackInstanceStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<StackInstanceStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -6450,9 +9259,9 @@ pub struct StackInstanceSummary {
     /// <p>The name of the AWS account that the stack instance is associated with.</p>
     pub account: Option<String>,
     /// <p><p>Status of the stack instance&#39;s actual configuration compared to the expected template and parameter configuration of the stack set to which it belongs. </p> <ul> <li> <p> <code>DRIFTED</code>: The stack differs from the expected template and parameter configuration of the stack set to which it belongs. A stack instance is considered to have drifted if one or more of the resources in the associated stack have drifted.</p> </li> <li> <p> <code>NOT<em>CHECKED</code>: AWS CloudFormation has not checked if the stack instance differs from its expected stack set configuration.</p> </li> <li> <p> <code>IN</em>SYNC</code>: The stack instance&#39;s actual configuration matches its expected stack set configuration.</p> </li> <li> <p> <code>UNKNOWN</code>: This value is reserved for future use.</p> </li> </ul></p>
-    pub drift_status: Option<String>,
+    pub drift_status: Option<StackDriftStatus>,
     /// <p>Most recent time when CloudFormation performed a drift detection operation on the stack instance. This value will be <code>NULL</code> for any stack instance on which drift detection has not yet been performed.</p>
-    pub last_drift_check_timestamp: Option<String>,
+    pub last_drift_check_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the AWS region that the stack instance is associated with.</p>
     pub region: Option<String>,
     /// <p>The ID of the stack instance.</p>
@@ -6460,7 +9269,7 @@ pub struct StackInstanceSummary {
     /// <p>The name or unique ID of the stack set that the stack instance is associated with.</p>
     pub stack_set_id: Option<String>,
     /// <p><p>The status of the stack instance, in terms of its synchronization with its associated stack set.</p> <ul> <li> <p> <code>INOPERABLE</code>: A <code>DeleteStackInstances</code> operation has failed and left the stack in an unstable state. Stacks in this state are excluded from further <code>UpdateStackSet</code> operations. You might need to perform a <code>DeleteStackInstances</code> operation, with <code>RetainStacks</code> set to <code>true</code>, to delete the stack instance, and then delete the stack manually.</p> </li> <li> <p> <code>OUTDATED</code>: The stack isn&#39;t currently up to date with the stack set because:</p> <ul> <li> <p>The associated stack failed during a <code>CreateStackSet</code> or <code>UpdateStackSet</code> operation. </p> </li> <li> <p>The stack was part of a <code>CreateStackSet</code> or <code>UpdateStackSet</code> operation that failed or was stopped before the stack was created or updated. </p> </li> </ul> </li> <li> <p> <code>CURRENT</code>: The stack is currently up to date with the stack set.</p> </li> </ul></p>
-    pub status: Option<String>,
+    pub status: Option<StackInstanceStatus>,
     /// <p>The explanation for the specific status code assigned to this stack instance.</p>
     pub status_reason: Option<String>,
 }
@@ -6549,7 +9358,7 @@ pub struct StackResource {
     /// <p>The name or unique identifier that corresponds to a physical instance ID of a resource supported by AWS CloudFormation.</p>
     pub physical_resource_id: Option<String>,
     /// <p>Current status of the resource.</p>
-    pub resource_status: String,
+    pub resource_status: ResourceStatus,
     /// <p>Success/failure message associated with the resource.</p>
     pub resource_status_reason: Option<String>,
     /// <p>Type of resource. (For more information, go to <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-template-resource-type-ref.html"> AWS Resource Types Reference</a> in the AWS CloudFormation User Guide.)</p>
@@ -6559,7 +9368,7 @@ pub struct StackResource {
     /// <p>The name associated with the stack.</p>
     pub stack_name: Option<String>,
     /// <p>Time the status was updated.</p>
-    pub timestamp: String,
+    pub timestamp: ::chrono::DateTime<::chrono::Utc>,
 }
 
 struct StackResourceDeserializer;
@@ -6631,7 +9440,7 @@ pub struct StackResourceDetail {
     /// <p>Information about whether the resource's actual configuration differs, or has <i>drifted</i>, from its expected configuration, as defined in the stack template and any values specified as template parameters. For more information, see <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-stack-drift.html">Detecting Unregulated Configuration Changes to Stacks and Resources</a>.</p>
     pub drift_information: Option<StackResourceDriftInformation>,
     /// <p>Time the status was updated.</p>
-    pub last_updated_timestamp: String,
+    pub last_updated_timestamp: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The logical name of the resource specified in the template.</p>
     pub logical_resource_id: String,
     /// <p>The content of the <code>Metadata</code> attribute declared for the resource. For more information, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-attribute-metadata.html">Metadata Attribute</a> in the AWS CloudFormation User Guide.</p>
@@ -6639,7 +9448,7 @@ pub struct StackResourceDetail {
     /// <p>The name or unique identifier that corresponds to a physical instance ID of a resource supported by AWS CloudFormation.</p>
     pub physical_resource_id: Option<String>,
     /// <p>Current status of the resource.</p>
-    pub resource_status: String,
+    pub resource_status: ResourceStatus,
     /// <p>Success/failure message associated with the resource.</p>
     pub resource_status_reason: Option<String>,
     /// <p>Type of resource. ((For more information, go to <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-template-resource-type-ref.html"> AWS Resource Types Reference</a> in the AWS CloudFormation User Guide.)</p>
@@ -6735,9 +9544,9 @@ pub struct StackResourceDrift {
     /// <p>The ID of the stack.</p>
     pub stack_id: String,
     /// <p><p>Status of the resource&#39;s actual configuration compared to its expected configuration</p> <ul> <li> <p> <code>DELETED</code>: The resource differs from its expected template configuration because the resource has been deleted.</p> </li> <li> <p> <code>MODIFIED</code>: One or more resource properties differ from their expected values (as defined in the stack template and any values specified as template parameters).</p> </li> <li> <p> <code>IN<em>SYNC</code>: The resources&#39;s actual configuration matches its expected template configuration.</p> </li> <li> <p> <code>NOT</em>CHECKED</code>: AWS CloudFormation does not currently return this value.</p> </li> </ul></p>
-    pub stack_resource_drift_status: String,
+    pub stack_resource_drift_status: StackResourceDriftStatus,
     /// <p>Time at which AWS CloudFormation performed drift detection on the stack resource.</p>
-    pub timestamp: String,
+    pub timestamp: ::chrono::DateTime<::chrono::Utc>,
 }
 
 struct StackResourceDriftDeserializer;
@@ -6812,9 +9621,9 @@ impl StackResourceDriftDeserializer {
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct StackResourceDriftInformation {
     /// <p>When AWS CloudFormation last checked if the resource had drifted from its expected configuration.</p>
-    pub last_check_timestamp: Option<String>,
+    pub last_check_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>Status of the resource&#39;s actual configuration compared to its expected configuration</p> <ul> <li> <p> <code>DELETED</code>: The resource differs from its expected configuration in that it has been deleted.</p> </li> <li> <p> <code>MODIFIED</code>: The resource differs from its expected configuration.</p> </li> <li> <p> <code>NOT<em>CHECKED</code>: AWS CloudFormation has not checked if the resource differs from its expected configuration.</p> <p>Any resources that do not currently support drift detection have a status of <code>NOT</em>CHECKED</code>. For more information, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-stack-drift-resource-list.html">Resources that Support Drift Detection</a>. </p> </li> <li> <p> <code>IN_SYNC</code>: The resources&#39;s actual configuration matches its expected configuration.</p> </li> </ul></p>
-    pub stack_resource_drift_status: String,
+    pub stack_resource_drift_status: StackResourceDriftStatus,
 }
 
 struct StackResourceDriftInformationDeserializer;
@@ -6854,9 +9663,9 @@ impl StackResourceDriftInformationDeserializer {
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct StackResourceDriftInformationSummary {
     /// <p>When AWS CloudFormation last checked if the resource had drifted from its expected configuration.</p>
-    pub last_check_timestamp: Option<String>,
+    pub last_check_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>Status of the resource&#39;s actual configuration compared to its expected configuration</p> <ul> <li> <p> <code>DELETED</code>: The resource differs from its expected configuration in that it has been deleted.</p> </li> <li> <p> <code>MODIFIED</code>: The resource differs from its expected configuration.</p> </li> <li> <p> <code>NOT<em>CHECKED</code>: AWS CloudFormation has not checked if the resource differs from its expected configuration.</p> <p>Any resources that do not currently support drift detection have a status of <code>NOT</em>CHECKED</code>. For more information, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-stack-drift-resource-list.html">Resources that Support Drift Detection</a>. If you performed an <a>ContinueUpdateRollback</a> operation on a stack, any resources included in <code>ResourcesToSkip</code> will also have a status of <code>NOT<em>CHECKED</code>. For more information on skipping resources during rollback operations, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-updating-stacks-continueupdaterollback.html">Continue Rolling Back an Update</a> in the AWS CloudFormation User Guide.</p> </li> <li> <p> <code>IN</em>SYNC</code>: The resources&#39;s actual configuration matches its expected configuration.</p> </li> </ul></p>
-    pub stack_resource_drift_status: String,
+    pub stack_resource_drift_status: StackResourceDriftStatus,
 }
 
 struct StackResourceDriftInformationSummaryDeserializer;
@@ -6891,12 +9700,120 @@ impl StackResourceDriftInformationSummaryDeserializer {
         )
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StackResourceDriftStatus {
+    /// `IN_SYNC`
+    InSync,
+    /// `MODIFIED`
+    Modified,
+    /// `DELETED`
+    Deleted,
+    /// `NOT_CHECKED`
+    NotChecked,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl StackResourceDriftStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StackResourceDriftStatus::InSync => "IN_SYNC",
+            StackResourceDriftStatus::Modified => "MODIFIED",
+            StackResourceDriftStatus::Deleted => "DELETED",
+            StackResourceDriftStatus::NotChecked => "NOT_CHECKED",
+            StackResourceDriftStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for StackResourceDriftStatus {
+    fn default() -> StackResourceDriftStatus {
+        StackResourceDriftStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for StackResourceDriftStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for StackResourceDriftStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for StackResourceDriftStatus {
+    fn from(value: &'a str) -> StackResourceDriftStatus {
+        match value {
+            "IN_SYNC" => StackResourceDriftStatus::InSync,
+            "MODIFIED" => StackResourceDriftStatus::Modified,
+            "DELETED" => StackResourceDriftStatus::Deleted,
+            "NOT_CHECKED" => StackResourceDriftStatus::NotChecked,
+            _ => StackResourceDriftStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StackResourceDriftStatus {
+    fn from(value: String) -> StackResourceDriftStatus {
+        match value.as_str() {
+            "IN_SYNC" => StackResourceDriftStatus::InSync,
+            "MODIFIED" => StackResourceDriftStatus::Modified,
+            "DELETED" => StackResourceDriftStatus::Deleted,
+            "NOT_CHECKED" => StackResourceDriftStatus::NotChecked,
+            _ => StackResourceDriftStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for StackResourceDriftStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<StackResourceDriftStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StackResourceDriftStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for StackResourceDriftStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for StackResourceDriftStatus {
+    fn deserialize<D>(deserializer: D) -> Result<StackResourceDriftStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct StackResourceDriftStatusDeserializer;
 impl StackResourceDriftStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<StackResourceDriftStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -6906,7 +9823,7 @@ impl StackResourceDriftStatusDeserializer {
 /// Serialize `StackResourceDriftStatusFilters` contents to a `SignedRequest`.
 struct StackResourceDriftStatusFiltersSerializer;
 impl StackResourceDriftStatusFiltersSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &Vec<String>) {
+    fn serialize(params: &mut Params, name: &str, obj: &Vec<StackResourceDriftStatus>) {
         for (index, obj) in obj.iter().enumerate() {
             let key = format!("{}.member.{}", name, index + 1);
             params.put(&key, &obj);
@@ -6959,13 +9876,13 @@ pub struct StackResourceSummary {
     /// <p>Information about whether the resource's actual configuration differs, or has <i>drifted</i>, from its expected configuration, as defined in the stack template and any values specified as template parameters. For more information, see <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-stack-drift.html">Detecting Unregulated Configuration Changes to Stacks and Resources</a>.</p>
     pub drift_information: Option<StackResourceDriftInformationSummary>,
     /// <p>Time the status was updated.</p>
-    pub last_updated_timestamp: String,
+    pub last_updated_timestamp: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The logical name of the resource specified in the template.</p>
     pub logical_resource_id: String,
     /// <p>The name or unique identifier that corresponds to a physical instance ID of the resource.</p>
     pub physical_resource_id: Option<String>,
     /// <p>Current status of the resource.</p>
-    pub resource_status: String,
+    pub resource_status: ResourceStatus,
     /// <p>Success/failure message associated with the resource.</p>
     pub resource_status_reason: Option<String>,
     /// <p>Type of resource. (For more information, go to <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-template-resource-type-ref.html"> AWS Resource Types Reference</a> in the AWS CloudFormation User Guide.)</p>
@@ -7048,7 +9965,7 @@ pub struct StackSet {
     /// <p>The Amazon Resource Number (ARN) of the IAM role used to create or update the stack set.</p> <p>Use customized administrator roles to control which users or groups can manage specific stack sets within the same administrator account. For more information, see <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/stacksets-prereqs.html">Prerequisites: Granting Permissions for Stack Set Operations</a> in the <i>AWS CloudFormation User Guide</i>.</p>
     pub administration_role_arn: Option<String>,
     /// <p>The capabilities that are allowed in the stack set. Some stack set templates might include resources that can affect permissions in your AWS account—for example, by creating new AWS Identity and Access Management (IAM) users. For more information, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-iam-template.html#capabilities">Acknowledging IAM Resources in AWS CloudFormation Templates.</a> </p>
-    pub capabilities: Option<Vec<String>>,
+    pub capabilities: Option<Vec<Capability>>,
     /// <p>A description of the stack set that you specify when the stack set is created or updated.</p>
     pub description: Option<String>,
     /// <p>The name of the IAM execution role used to create or update the stack set. </p> <p>Use customized execution roles to control which stack resources users and groups can include in their stack sets. </p>
@@ -7064,7 +9981,7 @@ pub struct StackSet {
     /// <p>The name that's associated with the stack set.</p>
     pub stack_set_name: Option<String>,
     /// <p>The status of the stack set.</p>
-    pub status: Option<String>,
+    pub status: Option<StackSetStatus>,
     /// <p>A list of tags that specify information about the stack set. A maximum number of 50 tags can be specified.</p>
     pub tags: Option<Vec<Tag>>,
     /// <p>The structure that contains the body of the template that was used to create or update the stack set.</p>
@@ -7163,9 +10080,9 @@ impl StackSetARNDeserializer {
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct StackSetDriftDetectionDetails {
     /// <p><p>The status of the stack set drift detection operation.</p> <ul> <li> <p> <code>COMPLETED</code>: The drift detection operation completed without failing on any stack instances.</p> </li> <li> <p> <code>FAILED</code>: The drift detection operation exceeded the specified failure tolerance. </p> </li> <li> <p> <code>PARTIAL<em>SUCCESS</code>: The drift detection operation completed without exceeding the failure tolerance for the operation.</p> </li> <li> <p> <code>IN</em>PROGRESS</code>: The drift detection operation is currently being performed.</p> </li> <li> <p> <code>STOPPED</code>: The user has cancelled the drift detection operation.</p> </li> </ul></p>
-    pub drift_detection_status: Option<String>,
+    pub drift_detection_status: Option<StackSetDriftDetectionStatus>,
     /// <p><p>Status of the stack set&#39;s actual configuration compared to its expected template and parameter configuration. A stack set is considered to have drifted if one or more of its stack instances have drifted from their expected template and parameter configuration.</p> <ul> <li> <p> <code>DRIFTED</code>: One or more of the stack instances belonging to the stack set stack differs from the expected template and parameter configuration. A stack instance is considered to have drifted if one or more of the resources in the associated stack have drifted.</p> </li> <li> <p> <code>NOT<em>CHECKED</code>: AWS CloudFormation has not checked the stack set for drift.</p> </li> <li> <p> <code>IN</em>SYNC</code>: All of the stack instances belonging to the stack set stack match from the expected template and parameter configuration.</p> </li> </ul></p>
-    pub drift_status: Option<String>,
+    pub drift_status: Option<StackSetDriftStatus>,
     /// <p>The number of stack instances that have drifted from the expected template and parameter configuration of the stack set. A stack instance is considered to have drifted if one or more of the resources in the associated stack do not match their expected configuration.</p>
     pub drifted_stack_instances_count: Option<i64>,
     /// <p>The number of stack instances for which the drift detection operation failed.</p>
@@ -7175,7 +10092,7 @@ pub struct StackSetDriftDetectionDetails {
     /// <p>The number of stack instances which match the expected template and parameter configuration of the stack set.</p>
     pub in_sync_stack_instances_count: Option<i64>,
     /// <p>Most recent time when CloudFormation performed a drift detection operation on the stack set. This value will be <code>NULL</code> for any stack set on which drift detection has not yet been performed.</p>
-    pub last_drift_check_timestamp: Option<String>,
+    pub last_drift_check_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>The total number of stack instances belonging to this stack set. </p> <p>The total number of stack instances is equal to the total of:</p> <ul> <li> <p>Stack instances that match the stack set configuration. </p> </li> <li> <p>Stack instances that have drifted from the stack set configuration. </p> </li> <li> <p>Stack instances where the drift detection operation has failed.</p> </li> <li> <p>Stack instances currently being checked for drift.</p> </li> </ul></p>
     pub total_stack_instances_count: Option<i64>,
 }
@@ -7253,62 +10170,278 @@ impl StackSetDriftDetectionDetailsDeserializer {
         )
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StackSetDriftDetectionStatus {
+    /// `COMPLETED`
+    Completed,
+    /// `FAILED`
+    Failed,
+    /// `PARTIAL_SUCCESS`
+    PartialSuccess,
+    /// `IN_PROGRESS`
+    InProgress,
+    /// `STOPPED`
+    Stopped,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl StackSetDriftDetectionStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StackSetDriftDetectionStatus::Completed => "COMPLETED",
+            StackSetDriftDetectionStatus::Failed => "FAILED",
+            StackSetDriftDetectionStatus::PartialSuccess => "PARTIAL_SUCCESS",
+            StackSetDriftDetectionStatus::InProgress => "IN_PROGRESS",
+            StackSetDriftDetectionStatus::Stopped => "STOPPED",
+            StackSetDriftDetectionStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for StackSetDriftDetectionStatus {
+    fn default() -> StackSetDriftDetectionStatus {
+        StackSetDriftDetectionStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for StackSetDriftDetectionStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for StackSetDriftDetectionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for StackSetDriftDetectionStatus {
+    fn from(value: &'a str) -> StackSetDriftDetectionStatus {
+        match value {
+            "COMPLETED" => StackSetDriftDetectionStatus::Completed,
+            "FAILED" => StackSetDriftDetectionStatus::Failed,
+            "PARTIAL_SUCCESS" => StackSetDriftDetectionStatus::PartialSuccess,
+            "IN_PROGRESS" => StackSetDriftDetectionStatus::InProgress,
+            "STOPPED" => StackSetDriftDetectionStatus::Stopped,
+            _ => StackSetDriftDetectionStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StackSetDriftDetectionStatus {
+    fn from(value: String) -> StackSetDriftDetectionStatus {
+        match value.as_str() {
+            "COMPLETED" => StackSetDriftDetectionStatus::Completed,
+            "FAILED" => StackSetDriftDetectionStatus::Failed,
+            "PARTIAL_SUCCESS" => StackSetDriftDetectionStatus::PartialSuccess,
+            "IN_PROGRESS" => StackSetDriftDetectionStatus::InProgress,
+            "STOPPED" => StackSetDriftDetectionStatus::Stopped,
+            _ => StackSetDriftDetectionStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for StackSetDriftDetectionStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<StackSetDriftDetectionStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StackSetDriftDetectionStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for StackSetDriftDetectionStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for StackSetDriftDetectionStatus {
+    fn deserialize<D>(deserializer: D) -> Result<StackSetDriftDetectionStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct StackSetDriftDetectionStatusDeserializer;
 impl StackSetDriftDetectionStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<StackSetDriftDetectionStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct StackSetDriftStatusDeserializer;
-impl StackSetDriftStatusDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
-        start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
-        end_element(tag_name, stack)?;
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StackSetDriftStatus {
+    /// `DRIFTED`
+    Drifted,
+    /// `IN_SYNC`
+    InSync,
+    /// `NOT_CHECKED`
+    NotChecked,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
 
-        Ok(obj)
+impl StackSetDriftStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StackSetDriftStatus::Drifted => "DRIFTED",
+            StackSetDriftStatus::InSync => "IN_SYNC",
+            StackSetDriftStatus::NotChecked => "NOT_CHECKED",
+            StackSetDriftStatus::Unknown(ref value) => value,
+        }
     }
 }
-struct StackSetIdDeserializer;
-impl StackSetIdDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
-        start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
-        end_element(tag_name, stack)?;
 
-        Ok(obj)
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for StackSetDriftStatus {
+    fn default() -> StackSetDriftStatus {
+        StackSetDriftStatus::Unknown(String::new())
     }
 }
-struct StackSetNameDeserializer;
-impl StackSetNameDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
-        start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
-        end_element(tag_name, stack)?;
 
-        Ok(obj)
+impl AsRef<str> for StackSetDriftStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
     }
 }
-/// <p>The structure that contains information about a stack set operation. </p>
-#[derive(Default, Debug, Clone, PartialEq)]
-#[cfg_attr(feature = "serialize_structs", derive(Serialize))]
+
+impl fmt::Display for StackSetDriftStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for StackSetDriftStatus {
+    fn from(value: &'a str) -> StackSetDriftStatus {
+        match value {
+            "DRIFTED" => StackSetDriftStatus::Drifted,
+            "IN_SYNC" => StackSetDriftStatus::InSync,
+            "NOT_CHECKED" => StackSetDriftStatus::NotChecked,
+            _ => StackSetDriftStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StackSetDriftStatus {
+    fn from(value: String) -> StackSetDriftStatus {
+        match value.as_str() {
+            "DRIFTED" => StackSetDriftStatus::Drifted,
+            "IN_SYNC" => StackSetDriftStatus::InSync,
+            "NOT_CHECKED" => StackSetDriftStatus::NotChecked,
+            _ => StackSetDriftStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for StackSetDriftStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<StackSetDriftStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StackSetDriftStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for StackSetDriftStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for StackSetDriftStatus {
+    fn deserialize<D>(deserializer: D) -> Result<StackSetDriftStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+struct StackSetDriftStatusDeserializer;
+impl StackSetDriftStatusDeserializer {
+    #[allow(unused_variables)]
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<StackSetDriftStatus, XmlParseError> {
+        start_element(tag_name, stack)?;
+        let obj = characters(stack)?.into();
+        end_element(tag_name, stack)?;
+
+        Ok(obj)
+    }
+}
+struct StackSetIdDeserializer;
+impl StackSetIdDeserializer {
+    #[allow(unused_variables)]
+    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+        start_element(tag_name, stack)?;
+        let obj = characters(stack)?;
+        end_element(tag_name, stack)?;
+
+        Ok(obj)
+    }
+}
+struct StackSetNameDeserializer;
+impl StackSetNameDeserializer {
+    #[allow(unused_variables)]
+    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+        start_element(tag_name, stack)?;
+        let obj = characters(stack)?;
+        end_element(tag_name, stack)?;
+
+        Ok(obj)
+    }
+}
+/// <p>The structure that contains information about a stack set operation. </p>
+#[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct StackSetOperation {
     /// <p>The type of stack set operation: <code>CREATE</code>, <code>UPDATE</code>, or <code>DELETE</code>. Create and delete operations affect only the specified stack set instances that are associated with the specified stack set. Update operations affect both the stack set itself, as well as <i>all</i> associated stack set instances.</p>
-    pub action: Option<String>,
+    pub action: Option<StackSetOperationAction>,
     /// <p>The Amazon Resource Number (ARN) of the IAM role used to perform this stack set operation. </p> <p>Use customized administrator roles to control which users or groups can manage specific stack sets within the same administrator account. For more information, see <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/stacksets-prereqs.html">Define Permissions for Multiple Administrators</a> in the <i>AWS CloudFormation User Guide</i>.</p>
     pub administration_role_arn: Option<String>,
     /// <p>The time at which the operation was initiated. Note that the creation times for the stack set operation might differ from the creation time of the individual stacks themselves. This is because AWS CloudFormation needs to perform preparatory work for the operation, such as dispatching the work to the requested regions, before actually creating the first stacks.</p>
-    pub creation_timestamp: Option<String>,
+    pub creation_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The time at which the stack set operation ended, across all accounts and regions specified. Note that this doesn't necessarily mean that the stack set operation was successful, or even attempted, in each account or region.</p>
-    pub end_timestamp: Option<String>,
+    pub end_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the IAM execution role used to create or update the stack set.</p> <p>Use customized execution roles to control which stack resources users and groups can include in their stack sets. </p>
     pub execution_role_name: Option<String>,
     /// <p>The unique ID of a stack set operation.</p>
@@ -7322,7 +10455,7 @@ pub struct StackSetOperation {
     /// <p>The ID of the stack set.</p>
     pub stack_set_id: Option<String>,
     /// <p><p>The status of the operation. </p> <ul> <li> <p> <code>FAILED</code>: The operation exceeded the specified failure tolerance. The failure tolerance value that you&#39;ve set for an operation is applied for each region during stack create and update operations. If the number of failed stacks within a region exceeds the failure tolerance, the status of the operation in the region is set to <code>FAILED</code>. This in turn sets the status of the operation as a whole to <code>FAILED</code>, and AWS CloudFormation cancels the operation in any remaining regions.</p> </li> <li> <p> <code>RUNNING</code>: The operation is currently being performed.</p> </li> <li> <p> <code>STOPPED</code>: The user has cancelled the operation.</p> </li> <li> <p> <code>STOPPING</code>: The operation is in the process of stopping, at user request. </p> </li> <li> <p> <code>SUCCEEDED</code>: The operation completed creating or updating all the specified stacks without exceeding the failure tolerance for the operation.</p> </li> </ul></p>
-    pub status: Option<String>,
+    pub status: Option<StackSetOperationStatus>,
 }
 
 struct StackSetOperationDeserializer;
@@ -7402,12 +10535,120 @@ impl StackSetOperationDeserializer {
         })
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StackSetOperationAction {
+    /// `CREATE`
+    Create,
+    /// `UPDATE`
+    Update,
+    /// `DELETE`
+    Delete,
+    /// `DETECT_DRIFT`
+    DetectDrift,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl StackSetOperationAction {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StackSetOperationAction::Create => "CREATE",
+            StackSetOperationAction::Update => "UPDATE",
+            StackSetOperationAction::Delete => "DELETE",
+            StackSetOperationAction::DetectDrift => "DETECT_DRIFT",
+            StackSetOperationAction::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for StackSetOperationAction {
+    fn default() -> StackSetOperationAction {
+        StackSetOperationAction::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for StackSetOperationAction {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for StackSetOperationAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for StackSetOperationAction {
+    fn from(value: &'a str) -> StackSetOperationAction {
+        match value {
+            "CREATE" => StackSetOperationAction::Create,
+            "UPDATE" => StackSetOperationAction::Update,
+            "DELETE" => StackSetOperationAction::Delete,
+            "DETECT_DRIFT" => StackSetOperationAction::DetectDrift,
+            _ => StackSetOperationAction::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StackSetOperationAction {
+    fn from(value: String) -> StackSetOperationAction {
+        match value.as_str() {
+            "CREATE" => StackSetOperationAction::Create,
+            "UPDATE" => StackSetOperationAction::Update,
+            "DELETE" => StackSetOperationAction::Delete,
+            "DETECT_DRIFT" => StackSetOperationAction::DetectDrift,
+            _ => StackSetOperationAction::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for StackSetOperationAction {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<StackSetOperationAction, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StackSetOperationAction {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for StackSetOperationAction {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for StackSetOperationAction {
+    fn deserialize<D>(deserializer: D) -> Result<StackSetOperationAction, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct StackSetOperationActionDeserializer;
 impl StackSetOperationActionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<StackSetOperationAction, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -7523,12 +10764,125 @@ impl StackSetOperationPreferencesSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StackSetOperationResultStatus {
+    /// `PENDING`
+    Pending,
+    /// `RUNNING`
+    Running,
+    /// `SUCCEEDED`
+    Succeeded,
+    /// `FAILED`
+    Failed,
+    /// `CANCELLED`
+    Cancelled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl StackSetOperationResultStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StackSetOperationResultStatus::Pending => "PENDING",
+            StackSetOperationResultStatus::Running => "RUNNING",
+            StackSetOperationResultStatus::Succeeded => "SUCCEEDED",
+            StackSetOperationResultStatus::Failed => "FAILED",
+            StackSetOperationResultStatus::Cancelled => "CANCELLED",
+            StackSetOperationResultStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for StackSetOperationResultStatus {
+    fn default() -> StackSetOperationResultStatus {
+        StackSetOperationResultStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for StackSetOperationResultStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for StackSetOperationResultStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for StackSetOperationResultStatus {
+    fn from(value: &'a str) -> StackSetOperationResultStatus {
+        match value {
+            "PENDING" => StackSetOperationResultStatus::Pending,
+            "RUNNING" => StackSetOperationResultStatus::Running,
+            "SUCCEEDED" => StackSetOperationResultStatus::Succeeded,
+            "FAILED" => StackSetOperationResultStatus::Failed,
+            "CANCELLED" => StackSetOperationResultStatus::Cancelled,
+            _ => StackSetOperationResultStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StackSetOperationResultStatus {
+    fn from(value: String) -> StackSetOperationResultStatus {
+        match value.as_str() {
+            "PENDING" => StackSetOperationResultStatus::Pending,
+            "RUNNING" => StackSetOperationResultStatus::Running,
+            "SUCCEEDED" => StackSetOperationResultStatus::Succeeded,
+            "FAILED" => StackSetOperationResultStatus::Failed,
+            "CANCELLED" => StackSetOperationResultStatus::Cancelled,
+            _ => StackSetOperationResultStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for StackSetOperationResultStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<StackSetOperationResultStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StackSetOperationResultStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for StackSetOperationResultStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for StackSetOperationResultStatus {
+    fn deserialize<D>(deserializer: D) -> Result<StackSetOperationResultStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct StackSetOperationResultStatusDeserializer;
 impl StackSetOperationResultStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<StackSetOperationResultStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -7564,7 +10918,7 @@ pub struct StackSetOperationResultSummary {
     /// <p>The name of the AWS region for this operation result.</p>
     pub region: Option<String>,
     /// <p><p>The result status of the stack set operation for the given account in the given region.</p> <ul> <li> <p> <code>CANCELLED</code>: The operation in the specified account and region has been cancelled. This is either because a user has stopped the stack set operation, or because the failure tolerance of the stack set operation has been exceeded.</p> </li> <li> <p> <code>FAILED</code>: The operation in the specified account and region failed. </p> <p>If the stack set operation fails in enough accounts within a region, the failure tolerance for the stack set operation as a whole might be exceeded. </p> </li> <li> <p> <code>RUNNING</code>: The operation in the specified account and region is currently in progress.</p> </li> <li> <p> <code>PENDING</code>: The operation in the specified account and region has yet to start. </p> </li> <li> <p> <code>SUCCEEDED</code>: The operation in the specified account and region completed successfully.</p> </li> </ul></p>
-    pub status: Option<String>,
+    pub status: Option<StackSetOperationResultStatus>,
     /// <p>The reason for the assigned result status.</p>
     pub status_reason: Option<String>,
 }
@@ -7609,12 +10963,130 @@ impl StackSetOperationResultSummaryDeserializer {
         )
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StackSetOperationStatus {
+    /// `RUNNING`
+    Running,
+    /// `SUCCEEDED`
+    Succeeded,
+    /// `FAILED`
+    Failed,
+    /// `STOPPING`
+    Stopping,
+    /// `STOPPED`
+    Stopped,
+    /// `QUEUED`
+    Queued,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl StackSetOperationStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StackSetOperationStatus::Running => "RUNNING",
+            StackSetOperationStatus::Succeeded => "SUCCEEDED",
+            StackSetOperationStatus::Failed => "FAILED",
+            StackSetOperationStatus::Stopping => "STOPPING",
+            StackSetOperationStatus::Stopped => "STOPPED",
+            StackSetOperationStatus::Queued => "QUEUED",
+            StackSetOperationStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for StackSetOperationStatus {
+    fn default() -> StackSetOperationStatus {
+        StackSetOperationStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for StackSetOperationStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for StackSetOperationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for StackSetOperationStatus {
+    fn from(value: &'a str) -> StackSetOperationStatus {
+        match value {
+            "RUNNING" => StackSetOperationStatus::Running,
+            "SUCCEEDED" => StackSetOperationStatus::Succeeded,
+            "FAILED" => StackSetOperationStatus::Failed,
+            "STOPPING" => StackSetOperationStatus::Stopping,
+            "STOPPED" => StackSetOperationStatus::Stopped,
+            "QUEUED" => StackSetOperationStatus::Queued,
+            _ => StackSetOperationStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StackSetOperationStatus {
+    fn from(value: String) -> StackSetOperationStatus {
+        match value.as_str() {
+            "RUNNING" => StackSetOperationStatus::Running,
+            "SUCCEEDED" => StackSetOperationStatus::Succeeded,
+            "FAILED" => StackSetOperationStatus::Failed,
+            "STOPPING" => StackSetOperationStatus::Stopping,
+            "STOPPED" => StackSetOperationStatus::Stopped,
+            "QUEUED" => StackSetOperationStatus::Queued,
+            _ => StackSetOperationStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for StackSetOperationStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<StackSetOperationStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StackSetOperationStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for StackSetOperationStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for StackSetOperationStatus {
+    fn deserialize<D>(deserializer: D) -> Result<StackSetOperationStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct StackSetOperationStatusDeserializer;
 impl StackSetOperationStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<StackSetOperationStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -7644,15 +11116,15 @@ impl StackSetOperationSummariesDeserializer {
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct StackSetOperationSummary {
     /// <p>The type of operation: <code>CREATE</code>, <code>UPDATE</code>, or <code>DELETE</code>. Create and delete operations affect only the specified stack instances that are associated with the specified stack set. Update operations affect both the stack set itself as well as <i>all</i> associated stack set instances.</p>
-    pub action: Option<String>,
+    pub action: Option<StackSetOperationAction>,
     /// <p>The time at which the operation was initiated. Note that the creation times for the stack set operation might differ from the creation time of the individual stacks themselves. This is because AWS CloudFormation needs to perform preparatory work for the operation, such as dispatching the work to the requested regions, before actually creating the first stacks.</p>
-    pub creation_timestamp: Option<String>,
+    pub creation_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The time at which the stack set operation ended, across all accounts and regions specified. Note that this doesn't necessarily mean that the stack set operation was successful, or even attempted, in each account or region.</p>
-    pub end_timestamp: Option<String>,
+    pub end_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The unique ID of the stack set operation.</p>
     pub operation_id: Option<String>,
     /// <p><p>The overall status of the operation.</p> <ul> <li> <p> <code>FAILED</code>: The operation exceeded the specified failure tolerance. The failure tolerance value that you&#39;ve set for an operation is applied for each region during stack create and update operations. If the number of failed stacks within a region exceeds the failure tolerance, the status of the operation in the region is set to <code>FAILED</code>. This in turn sets the status of the operation as a whole to <code>FAILED</code>, and AWS CloudFormation cancels the operation in any remaining regions.</p> </li> <li> <p> <code>RUNNING</code>: The operation is currently being performed.</p> </li> <li> <p> <code>STOPPED</code>: The user has cancelled the operation.</p> </li> <li> <p> <code>STOPPING</code>: The operation is in the process of stopping, at user request. </p> </li> <li> <p> <code>SUCCEEDED</code>: The operation completed creating or updating all the specified stacks without exceeding the failure tolerance for the operation.</p> </li> </ul></p>
-    pub status: Option<String>,
+    pub status: Option<StackSetOperationStatus>,
 }
 
 struct StackSetOperationSummaryDeserializer;
@@ -7700,12 +11172,110 @@ impl StackSetOperationSummaryDeserializer {
         )
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StackSetStatus {
+    /// `ACTIVE`
+    Active,
+    /// `DELETED`
+    Deleted,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl StackSetStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StackSetStatus::Active => "ACTIVE",
+            StackSetStatus::Deleted => "DELETED",
+            StackSetStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for StackSetStatus {
+    fn default() -> StackSetStatus {
+        StackSetStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for StackSetStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for StackSetStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for StackSetStatus {
+    fn from(value: &'a str) -> StackSetStatus {
+        match value {
+            "ACTIVE" => StackSetStatus::Active,
+            "DELETED" => StackSetStatus::Deleted,
+            _ => StackSetStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StackSetStatus {
+    fn from(value: String) -> StackSetStatus {
+        match value.as_str() {
+            "ACTIVE" => StackSetStatus::Active,
+            "DELETED" => StackSetStatus::Deleted,
+            _ => StackSetStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for StackSetStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<StackSetStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StackSetStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for StackSetStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for StackSetStatus {
+    fn deserialize<D>(deserializer: D) -> Result<StackSetStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct StackSetStatusDeserializer;
 impl StackSetStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<StackSetStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -7735,15 +11305,15 @@ pub struct StackSetSummary {
     /// <p>A description of the stack set that you specify when the stack set is created or updated.</p>
     pub description: Option<String>,
     /// <p><p>Status of the stack set&#39;s actual configuration compared to its expected template and parameter configuration. A stack set is considered to have drifted if one or more of its stack instances have drifted from their expected template and parameter configuration.</p> <ul> <li> <p> <code>DRIFTED</code>: One or more of the stack instances belonging to the stack set stack differs from the expected template and parameter configuration. A stack instance is considered to have drifted if one or more of the resources in the associated stack have drifted.</p> </li> <li> <p> <code>NOT<em>CHECKED</code>: AWS CloudFormation has not checked the stack set for drift.</p> </li> <li> <p> <code>IN</em>SYNC</code>: All of the stack instances belonging to the stack set stack match from the expected template and parameter configuration.</p> </li> <li> <p> <code>UNKNOWN</code>: This value is reserved for future use.</p> </li> </ul></p>
-    pub drift_status: Option<String>,
+    pub drift_status: Option<StackDriftStatus>,
     /// <p>Most recent time when CloudFormation performed a drift detection operation on the stack set. This value will be <code>NULL</code> for any stack set on which drift detection has not yet been performed.</p>
-    pub last_drift_check_timestamp: Option<String>,
+    pub last_drift_check_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The ID of the stack set.</p>
     pub stack_set_id: Option<String>,
     /// <p>The name of the stack set.</p>
     pub stack_set_name: Option<String>,
     /// <p>The status of the stack set.</p>
-    pub status: Option<String>,
+    pub status: Option<StackSetStatus>,
 }
 
 struct StackSetSummaryDeserializer;
@@ -7790,22 +11360,231 @@ impl StackSetSummaryDeserializer {
         })
     }
 }
-struct StackStatusDeserializer;
-impl StackStatusDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
-        start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
-        end_element(tag_name, stack)?;
-
-        Ok(obj)
-    }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StackStatus {
+    /// `CREATE_IN_PROGRESS`
+    CreateInProgress,
+    /// `CREATE_FAILED`
+    CreateFailed,
+    /// `CREATE_COMPLETE`
+    CreateComplete,
+    /// `ROLLBACK_IN_PROGRESS`
+    RollbackInProgress,
+    /// `ROLLBACK_FAILED`
+    RollbackFailed,
+    /// `ROLLBACK_COMPLETE`
+    RollbackComplete,
+    /// `DELETE_IN_PROGRESS`
+    DeleteInProgress,
+    /// `DELETE_FAILED`
+    DeleteFailed,
+    /// `DELETE_COMPLETE`
+    DeleteComplete,
+    /// `UPDATE_IN_PROGRESS`
+    UpdateInProgress,
+    /// `UPDATE_COMPLETE_CLEANUP_IN_PROGRESS`
+    UpdateCompleteCleanupInProgress,
+    /// `UPDATE_COMPLETE`
+    UpdateComplete,
+    /// `UPDATE_FAILED`
+    UpdateFailed,
+    /// `UPDATE_ROLLBACK_IN_PROGRESS`
+    UpdateRollbackInProgress,
+    /// `UPDATE_ROLLBACK_FAILED`
+    UpdateRollbackFailed,
+    /// `UPDATE_ROLLBACK_COMPLETE_CLEANUP_IN_PROGRESS`
+    UpdateRollbackCompleteCleanupInProgress,
+    /// `UPDATE_ROLLBACK_COMPLETE`
+    UpdateRollbackComplete,
+    /// `REVIEW_IN_PROGRESS`
+    ReviewInProgress,
+    /// `IMPORT_IN_PROGRESS`
+    ImportInProgress,
+    /// `IMPORT_COMPLETE`
+    ImportComplete,
+    /// `IMPORT_ROLLBACK_IN_PROGRESS`
+    ImportRollbackInProgress,
+    /// `IMPORT_ROLLBACK_FAILED`
+    ImportRollbackFailed,
+    /// `IMPORT_ROLLBACK_COMPLETE`
+    ImportRollbackComplete,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl StackStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StackStatus::CreateInProgress => "CREATE_IN_PROGRESS",
+            StackStatus::CreateFailed => "CREATE_FAILED",
+            StackStatus::CreateComplete => "CREATE_COMPLETE",
+            StackStatus::RollbackInProgress => "ROLLBACK_IN_PROGRESS",
+            StackStatus::RollbackFailed => "ROLLBACK_FAILED",
+            StackStatus::RollbackComplete => "ROLLBACK_COMPLETE",
+            StackStatus::DeleteInProgress => "DELETE_IN_PROGRESS",
+            StackStatus::DeleteFailed => "DELETE_FAILED",
+            StackStatus::DeleteComplete => "DELETE_COMPLETE",
+            StackStatus::UpdateInProgress => "UPDATE_IN_PROGRESS",
+            StackStatus::UpdateCompleteCleanupInProgress => "UPDATE_COMPLETE_CLEANUP_IN_PROGRESS",
+            StackStatus::UpdateComplete => "UPDATE_COMPLETE",
+            StackStatus::UpdateFailed => "UPDATE_FAILED",
+            StackStatus::UpdateRollbackInProgress => "UPDATE_ROLLBACK_IN_PROGRESS",
+            StackStatus::UpdateRollbackFailed => "UPDATE_ROLLBACK_FAILED",
+            StackStatus::UpdateRollbackCompleteCleanupInProgress => {
+                "UPDATE_ROLLBACK_COMPLETE_CLEANUP_IN_PROGRESS"
+            }
+            StackStatus::UpdateRollbackComplete => "UPDATE_ROLLBACK_COMPLETE",
+            StackStatus::ReviewInProgress => "REVIEW_IN_PROGRESS",
+            StackStatus::ImportInProgress => "IMPORT_IN_PROGRESS",
+            StackStatus::ImportComplete => "IMPORT_COMPLETE",
+            StackStatus::ImportRollbackInProgress => "IMPORT_ROLLBACK_IN_PROGRESS",
+            StackStatus::ImportRollbackFailed => "IMPORT_ROLLBACK_FAILED",
+            StackStatus::ImportRollbackComplete => "IMPORT_ROLLBACK_COMPLETE",
+            StackStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for StackStatus {
+    fn default() -> StackStatus {
+        StackStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for StackStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for StackStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for StackStatus {
+    fn from(value: &'a str) -> StackStatus {
+        match value {
+            "CREATE_IN_PROGRESS" => StackStatus::CreateInProgress,
+            "CREATE_FAILED" => StackStatus::CreateFailed,
+            "CREATE_COMPLETE" => StackStatus::CreateComplete,
+            "ROLLBACK_IN_PROGRESS" => StackStatus::RollbackInProgress,
+            "ROLLBACK_FAILED" => StackStatus::RollbackFailed,
+            "ROLLBACK_COMPLETE" => StackStatus::RollbackComplete,
+            "DELETE_IN_PROGRESS" => StackStatus::DeleteInProgress,
+            "DELETE_FAILED" => StackStatus::DeleteFailed,
+            "DELETE_COMPLETE" => StackStatus::DeleteComplete,
+            "UPDATE_IN_PROGRESS" => StackStatus::UpdateInProgress,
+            "UPDATE_COMPLETE_CLEANUP_IN_PROGRESS" => StackStatus::UpdateCompleteCleanupInProgress,
+            "UPDATE_COMPLETE" => StackStatus::UpdateComplete,
+            "UPDATE_FAILED" => StackStatus::UpdateFailed,
+            "UPDATE_ROLLBACK_IN_PROGRESS" => StackStatus::UpdateRollbackInProgress,
+            "UPDATE_ROLLBACK_FAILED" => StackStatus::UpdateRollbackFailed,
+            "UPDATE_ROLLBACK_COMPLETE_CLEANUP_IN_PROGRESS" => {
+                StackStatus::UpdateRollbackCompleteCleanupInProgress
+            }
+            "UPDATE_ROLLBACK_COMPLETE" => StackStatus::UpdateRollbackComplete,
+            "REVIEW_IN_PROGRESS" => StackStatus::ReviewInProgress,
+            "IMPORT_IN_PROGRESS" => StackStatus::ImportInProgress,
+            "IMPORT_COMPLETE" => StackStatus::ImportComplete,
+            "IMPORT_ROLLBACK_IN_PROGRESS" => StackStatus::ImportRollbackInProgress,
+            "IMPORT_ROLLBACK_FAILED" => StackStatus::ImportRollbackFailed,
+            "IMPORT_ROLLBACK_COMPLETE" => StackStatus::ImportRollbackComplete,
+            _ => StackStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StackStatus {
+    fn from(value: String) -> StackStatus {
+        match value.as_str() {
+            "CREATE_IN_PROGRESS" => StackStatus::CreateInProgress,
+            "CREATE_FAILED" => StackStatus::CreateFailed,
+            "CREATE_COMPLETE" => StackStatus::CreateComplete,
+            "ROLLBACK_IN_PROGRESS" => StackStatus::RollbackInProgress,
+            "ROLLBACK_FAILED" => StackStatus::RollbackFailed,
+            "ROLLBACK_COMPLETE" => StackStatus::RollbackComplete,
+            "DELETE_IN_PROGRESS" => StackStatus::DeleteInProgress,
+            "DELETE_FAILED" => StackStatus::DeleteFailed,
+            "DELETE_COMPLETE" => StackStatus::DeleteComplete,
+            "UPDATE_IN_PROGRESS" => StackStatus::UpdateInProgress,
+            "UPDATE_COMPLETE_CLEANUP_IN_PROGRESS" => StackStatus::UpdateCompleteCleanupInProgress,
+            "UPDATE_COMPLETE" => StackStatus::UpdateComplete,
+            "UPDATE_FAILED" => StackStatus::UpdateFailed,
+            "UPDATE_ROLLBACK_IN_PROGRESS" => StackStatus::UpdateRollbackInProgress,
+            "UPDATE_ROLLBACK_FAILED" => StackStatus::UpdateRollbackFailed,
+            "UPDATE_ROLLBACK_COMPLETE_CLEANUP_IN_PROGRESS" => {
+                StackStatus::UpdateRollbackCompleteCleanupInProgress
+            }
+            "UPDATE_ROLLBACK_COMPLETE" => StackStatus::UpdateRollbackComplete,
+            "REVIEW_IN_PROGRESS" => StackStatus::ReviewInProgress,
+            "IMPORT_IN_PROGRESS" => StackStatus::ImportInProgress,
+            "IMPORT_COMPLETE" => StackStatus::ImportComplete,
+            "IMPORT_ROLLBACK_IN_PROGRESS" => StackStatus::ImportRollbackInProgress,
+            "IMPORT_ROLLBACK_FAILED" => StackStatus::ImportRollbackFailed,
+            "IMPORT_ROLLBACK_COMPLETE" => StackStatus::ImportRollbackComplete,
+            _ => StackStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for StackStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<StackStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StackStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for StackStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for StackStatus {
+    fn deserialize<D>(deserializer: D) -> Result<StackStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+struct StackStatusDeserializer;
+impl StackStatusDeserializer {
+    #[allow(unused_variables)]
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<StackStatus, XmlParseError> {
+        start_element(tag_name, stack)?;
+        let obj = characters(stack)?.into();
+        end_element(tag_name, stack)?;
+
+        Ok(obj)
+    }
 }
 
 /// Serialize `StackStatusFilter` contents to a `SignedRequest`.
 struct StackStatusFilterSerializer;
 impl StackStatusFilterSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &Vec<String>) {
+    fn serialize(params: &mut Params, name: &str, obj: &Vec<StackStatus>) {
         for (index, obj) in obj.iter().enumerate() {
             let key = format!("{}.member.{}", name, index + 1);
             params.put(&key, &obj);
@@ -7846,13 +11625,13 @@ impl StackSummariesDeserializer {
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct StackSummary {
     /// <p>The time the stack was created.</p>
-    pub creation_time: String,
+    pub creation_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The time the stack was deleted.</p>
-    pub deletion_time: Option<String>,
+    pub deletion_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Summarizes information on whether a stack's actual configuration differs, or has <i>drifted</i>, from it's expected configuration, as defined in the stack template and any values specified as template parameters. For more information, see <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-stack-drift.html">Detecting Unregulated Configuration Changes to Stacks and Resources</a>.</p>
     pub drift_information: Option<StackDriftInformationSummary>,
     /// <p>The time the stack was last updated. This field will only be returned if the stack has been updated at least once.</p>
-    pub last_updated_time: Option<String>,
+    pub last_updated_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>For nested stacks--stacks created as resources for another stack--the stack ID of the direct parent of this stack. For the first level of nested stacks, the root stack is also the parent stack.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-nested-stacks.html">Working with Nested Stacks</a> in the <i>AWS CloudFormation User Guide</i>.</p>
     pub parent_id: Option<String>,
     /// <p>For nested stacks--stacks created as resources for another stack--the stack ID of the top-level stack to which the nested stack ultimately belongs.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-nested-stacks.html">Working with Nested Stacks</a> in the <i>AWS CloudFormation User Guide</i>.</p>
@@ -7862,7 +11641,7 @@ pub struct StackSummary {
     /// <p>The name associated with the stack.</p>
     pub stack_name: String,
     /// <p>The current status of the stack.</p>
-    pub stack_status: String,
+    pub stack_status: StackStatus,
     /// <p>Success/Failure message associated with the stack status.</p>
     pub stack_status_reason: Option<String>,
     /// <p>The template description of the template used to create the stack.</p>
@@ -7957,7 +11736,7 @@ impl StageListDeserializer {
     fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
-    ) -> Result<Vec<String>, XmlParseError> {
+    ) -> Result<Vec<TemplateStage>, XmlParseError> {
         deserialize_elements::<_, Vec<_>, _>(tag_name, stack, |name, stack, obj| {
             if name == "member" {
                 obj.push(TemplateStageDeserializer::deserialize("member", stack)?);
@@ -8196,12 +11975,110 @@ impl TemplateParametersDeserializer {
         })
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TemplateStage {
+    /// `Original`
+    Original,
+    /// `Processed`
+    Processed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TemplateStage {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TemplateStage::Original => "Original",
+            TemplateStage::Processed => "Processed",
+            TemplateStage::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TemplateStage {
+    fn default() -> TemplateStage {
+        TemplateStage::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TemplateStage {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TemplateStage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TemplateStage {
+    fn from(value: &'a str) -> TemplateStage {
+        match value {
+            "Original" => TemplateStage::Original,
+            "Processed" => TemplateStage::Processed,
+            _ => TemplateStage::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TemplateStage {
+    fn from(value: String) -> TemplateStage {
+        match value.as_str() {
+            "Original" => TemplateStage::Original,
+            "Processed" => TemplateStage::Processed,
+            _ => TemplateStage::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TemplateStage {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TemplateStage, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TemplateStage {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for TemplateStage {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for TemplateStage {
+    fn deserialize<D>(deserializer: D) -> Result<TemplateStage, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct TemplateStageDeserializer;
 impl TemplateStageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<TemplateStage, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -8221,9 +12098,12 @@ impl TimeoutMinutesDeserializer {
 struct TimestampDeserializer;
 impl TimestampDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<::chrono::DateTime<::chrono::Utc>, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::proto::xml::util::timestamp_characters(stack)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -8338,9 +12218,9 @@ pub struct TypeSummary {
     /// <p>The description of the type.</p>
     pub description: Option<String>,
     /// <p>When the current default version of the type was registered.</p>
-    pub last_updated: Option<String>,
+    pub last_updated: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The kind of type.</p>
-    pub type_: Option<String>,
+    pub type_: Option<RegistryType>,
     /// <p>The Amazon Resource Name (ARN) of the type.</p>
     pub type_arn: Option<String>,
     /// <p>The name of the type.</p>
@@ -8424,9 +12304,9 @@ pub struct TypeVersionSummary {
     /// <p>The description of the type version.</p>
     pub description: Option<String>,
     /// <p>When the version was registered.</p>
-    pub time_created: Option<String>,
+    pub time_created: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The kind of type.</p>
-    pub type_: Option<String>,
+    pub type_: Option<RegistryType>,
     /// <p>The name of the type.</p>
     pub type_name: Option<String>,
     /// <p>The ID of a specific version of the type. The version ID is the value at the end of the Amazon Resource Name (ARN) assigned to the type version when it is registered.</p>
@@ -8474,7 +12354,7 @@ impl TypeVersionSummaryDeserializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct UpdateStackInput {
     /// <p><p>In some cases, you must explicitly acknowledge that your stack template contains certain capabilities in order for AWS CloudFormation to update the stack.</p> <ul> <li> <p> <code>CAPABILITY<em>IAM</code> and <code>CAPABILITY</em>NAMED<em>IAM</code> </p> <p>Some stack templates might include resources that can affect permissions in your AWS account; for example, by creating new AWS Identity and Access Management (IAM) users. For those stacks, you must explicitly acknowledge this by specifying one of these capabilities.</p> <p>The following IAM resources require you to specify either the <code>CAPABILITY</em>IAM</code> or <code>CAPABILITY<em>NAMED</em>IAM</code> capability.</p> <ul> <li> <p>If you have IAM resources, you can specify either capability. </p> </li> <li> <p>If you have IAM resources with custom names, you <i>must</i> specify <code>CAPABILITY<em>NAMED</em>IAM</code>. </p> </li> <li> <p>If you don&#39;t specify either of these capabilities, AWS CloudFormation returns an <code>InsufficientCapabilities</code> error.</p> </li> </ul> <p>If your stack template contains these resources, we recommend that you review all permissions associated with them and edit their permissions if necessary.</p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-accesskey.html"> AWS::IAM::AccessKey</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-group.html"> AWS::IAM::Group</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-resource-iam-instanceprofile.html"> AWS::IAM::InstanceProfile</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-policy.html"> AWS::IAM::Policy</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-resource-iam-role.html"> AWS::IAM::Role</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-user.html"> AWS::IAM::User</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-addusertogroup.html"> AWS::IAM::UserToGroupAddition</a> </p> </li> </ul> <p>For more information, see <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-iam-template.html#capabilities">Acknowledging IAM Resources in AWS CloudFormation Templates</a>.</p> </li> <li> <p> <code>CAPABILITY<em>AUTO</em>EXPAND</code> </p> <p>Some template contain macros. Macros perform custom processing on templates; this can include simple actions like find-and-replace operations, all the way to extensive transformations of entire templates. Because of this, users typically create a change set from the processed template, so that they can review the changes resulting from the macros before actually updating the stack. If your stack template contains one or more macros, and you choose to update a stack directly from the processed template, without first reviewing the resulting changes in a change set, you must acknowledge this capability. This includes the <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/create-reusable-transform-function-snippets-and-add-to-your-template-with-aws-include-transform.html">AWS::Include</a> and <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/transform-aws-serverless.html">AWS::Serverless</a> transforms, which are macros hosted by AWS CloudFormation.</p> <p>Change sets do not currently support nested stacks. If you want to update a stack from a stack template that contains macros <i>and</i> nested stacks, you must update the stack directly from the template using this capability.</p> <important> <p>You should only update stacks directly from a stack template that contains macros if you know what processing the macro performs.</p> <p>Each macro relies on an underlying Lambda service function for processing stack templates. Be aware that the Lambda function owner can update the function operation without AWS CloudFormation being notified.</p> </important> <p>For more information, see <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/template-macros.html">Using AWS CloudFormation Macros to Perform Custom Processing on Templates</a>.</p> </li> </ul></p>
-    pub capabilities: Option<Vec<String>>,
+    pub capabilities: Option<Vec<Capability>>,
     /// <p>A unique identifier for this <code>UpdateStack</code> request. Specify this token if you plan to retry requests so that AWS CloudFormation knows that you're not attempting to update a stack with the same name. You might retry <code>UpdateStack</code> requests to ensure that AWS CloudFormation successfully received them.</p> <p>All events triggered by a given stack operation are assigned the same client request token, which you can use to track operations. For example, if you execute a <code>CreateStack</code> operation with the token <code>token1</code>, then all the <code>StackEvents</code> generated by that operation will have <code>ClientRequestToken</code> set as <code>token1</code>.</p> <p>In the console, stack operations display the client request token on the Events tab. Stack operations that are initiated from the console use the token format <i>Console-StackOperation-ID</i>, which helps you easily identify the stack operation . For example, if you create a stack using the console, each stack event would be assigned the same token in the following format: <code>Console-CreateStack-7f59c3cf-00d2-40c7-b2ff-e75db0987002</code>. </p>
     pub client_request_token: Option<String>,
     /// <p>Amazon Simple Notification Service topic Amazon Resource Names (ARNs) that AWS CloudFormation associates with the stack. Specify an empty list to remove all notification topics.</p>
@@ -8716,7 +12596,7 @@ pub struct UpdateStackSetInput {
     /// <p>The Amazon Resource Number (ARN) of the IAM role to use to update this stack set.</p> <p>Specify an IAM role only if you are using customized administrator roles to control which users or groups can manage specific stack sets within the same administrator account. For more information, see <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/stacksets-prereqs.html">Granting Permissions for Stack Set Operations</a> in the <i>AWS CloudFormation User Guide</i>.</p> <p>If you specified a customized administrator role when you created the stack set, you must specify a customized administrator role, even if it is the same customized administrator role used with this stack set previously.</p>
     pub administration_role_arn: Option<String>,
     /// <p><p>In some cases, you must explicitly acknowledge that your stack template contains certain capabilities in order for AWS CloudFormation to update the stack set and its associated stack instances.</p> <ul> <li> <p> <code>CAPABILITY<em>IAM</code> and <code>CAPABILITY</em>NAMED<em>IAM</code> </p> <p>Some stack templates might include resources that can affect permissions in your AWS account; for example, by creating new AWS Identity and Access Management (IAM) users. For those stacks sets, you must explicitly acknowledge this by specifying one of these capabilities.</p> <p>The following IAM resources require you to specify either the <code>CAPABILITY</em>IAM</code> or <code>CAPABILITY<em>NAMED</em>IAM</code> capability.</p> <ul> <li> <p>If you have IAM resources, you can specify either capability. </p> </li> <li> <p>If you have IAM resources with custom names, you <i>must</i> specify <code>CAPABILITY<em>NAMED</em>IAM</code>. </p> </li> <li> <p>If you don&#39;t specify either of these capabilities, AWS CloudFormation returns an <code>InsufficientCapabilities</code> error.</p> </li> </ul> <p>If your stack template contains these resources, we recommend that you review all permissions associated with them and edit their permissions if necessary.</p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-accesskey.html"> AWS::IAM::AccessKey</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-group.html"> AWS::IAM::Group</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-resource-iam-instanceprofile.html"> AWS::IAM::InstanceProfile</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-policy.html"> AWS::IAM::Policy</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-resource-iam-role.html"> AWS::IAM::Role</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-user.html"> AWS::IAM::User</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-addusertogroup.html"> AWS::IAM::UserToGroupAddition</a> </p> </li> </ul> <p>For more information, see <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-iam-template.html#capabilities">Acknowledging IAM Resources in AWS CloudFormation Templates</a>.</p> </li> <li> <p> <code>CAPABILITY<em>AUTO</em>EXPAND</code> </p> <p>Some templates contain macros. If your stack template contains one or more macros, and you choose to update a stack directly from the processed template, without first reviewing the resulting changes in a change set, you must acknowledge this capability. For more information, see <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/template-macros.html">Using AWS CloudFormation Macros to Perform Custom Processing on Templates</a>.</p> <important> <p>Stack sets do not currently support macros in stack templates. (This includes the <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/create-reusable-transform-function-snippets-and-add-to-your-template-with-aws-include-transform.html">AWS::Include</a> and <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/transform-aws-serverless.html">AWS::Serverless</a> transforms, which are macros hosted by AWS CloudFormation.) Even if you specify this capability, if you include a macro in your template the stack set operation will fail.</p> </important> </li> </ul></p>
-    pub capabilities: Option<Vec<String>>,
+    pub capabilities: Option<Vec<Capability>>,
     /// <p>A brief description of updates that you are making.</p>
     pub description: Option<String>,
     /// <p>The name of the IAM execution role to use to update the stack set. If you do not specify an execution role, AWS CloudFormation uses the <code>AWSCloudFormationStackSetExecutionRole</code> role for the stack set operation.</p> <p>Specify an IAM role only if you are using customized execution roles to control which stack resources users and groups can include in their stack sets. </p> <p> If you specify a customized execution role, AWS CloudFormation uses that role to update the stack. If you do not specify a customized execution role, AWS CloudFormation performs the update using the role previously associated with the stack set, so long as you have permissions to perform operations on the stack set.</p>
@@ -8960,7 +12840,7 @@ impl ValidateTemplateInputSerializer {
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct ValidateTemplateOutput {
     /// <p>The capabilities found within the template. If your template contains IAM resources, you must specify the CAPABILITY_IAM or CAPABILITY_NAMED_IAM value for this parameter when you use the <a>CreateStack</a> or <a>UpdateStack</a> actions with your template; otherwise, those actions return an InsufficientCapabilities error.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-iam-template.html#capabilities">Acknowledging IAM Resources in AWS CloudFormation Templates</a>.</p>
-    pub capabilities: Option<Vec<String>>,
+    pub capabilities: Option<Vec<Capability>>,
     /// <p>The list of resources that generated the values in the <code>Capabilities</code> response element.</p>
     pub capabilities_reason: Option<String>,
     /// <p>A list of the transforms that are declared in the template.</p>
@@ -9033,12 +12913,110 @@ impl VersionDeserializer {
         Ok(obj)
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Visibility {
+    /// `PUBLIC`
+    Public,
+    /// `PRIVATE`
+    Private,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Visibility {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Visibility::Public => "PUBLIC",
+            Visibility::Private => "PRIVATE",
+            Visibility::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Visibility {
+    fn default() -> Visibility {
+        Visibility::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Visibility {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Visibility {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Visibility {
+    fn from(value: &'a str) -> Visibility {
+        match value {
+            "PUBLIC" => Visibility::Public,
+            "PRIVATE" => Visibility::Private,
+            _ => Visibility::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Visibility {
+    fn from(value: String) -> Visibility {
+        match value.as_str() {
+            "PUBLIC" => Visibility::Public,
+            "PRIVATE" => Visibility::Private,
+            _ => Visibility::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Visibility {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Visibility, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Visibility {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for Visibility {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for Visibility {
+    fn deserialize<D>(deserializer: D) -> Result<Visibility, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct VisibilityDeserializer;
 impl VisibilityDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Visibility, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -11970,11 +15948,12 @@ pub struct CloudFormationClient {
 impl CloudFormationClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> CloudFormationClient {
         CloudFormationClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "CloudFormation"),
         }
     }
 
@@ -12006,6 +15985,7 @@ impl CloudFormation for CloudFormationClient {
         input: CancelUpdateStackInput,
     ) -> Result<(), RusotoError<CancelUpdateStackError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("CancelUpdateStack");
         let mut params = Params::new();
 
         params.put("Action", "CancelUpdateStack");
@@ -12034,6 +16014,7 @@ impl CloudFormation for CloudFormationClient {
         input: ContinueUpdateRollbackInput,
     ) -> Result<ContinueUpdateRollbackOutput, RusotoError<ContinueUpdateRollbackError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("ContinueUpdateRollback");
         let mut params = Params::new();
 
         params.put("Action", "ContinueUpdateRollback");
@@ -12083,6 +16064,7 @@ impl CloudFormation for CloudFormationClient {
         input: CreateChangeSetInput,
     ) -> Result<CreateChangeSetOutput, RusotoError<CreateChangeSetError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("CreateChangeSet");
         let mut params = Params::new();
 
         params.put("Action", "CreateChangeSet");
@@ -12132,6 +16114,7 @@ impl CloudFormation for CloudFormationClient {
         input: CreateStackInput,
     ) -> Result<CreateStackOutput, RusotoError<CreateStackError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("CreateStack");
         let mut params = Params::new();
 
         params.put("Action", "CreateStack");
@@ -12178,6 +16161,7 @@ impl CloudFormation for CloudFormationClient {
         input: CreateStackInstancesInput,
     ) -> Result<CreateStackInstancesOutput, RusotoError<CreateStackInstancesError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("CreateStackInstances");
         let mut params = Params::new();
 
         params.put("Action", "CreateStackInstances");
@@ -12227,6 +16211,7 @@ impl CloudFormation for CloudFormationClient {
         input: CreateStackSetInput,
     ) -> Result<CreateStackSetOutput, RusotoError<CreateStackSetError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("CreateStackSet");
         let mut params = Params::new();
 
         params.put("Action", "CreateStackSet");
@@ -12274,6 +16259,7 @@ impl CloudFormation for CloudFormationClient {
         input: DeleteChangeSetInput,
     ) -> Result<DeleteChangeSetOutput, RusotoError<DeleteChangeSetError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DeleteChangeSet");
         let mut params = Params::new();
 
         params.put("Action", "DeleteChangeSet");
@@ -12323,6 +16309,7 @@ impl CloudFormation for CloudFormationClient {
         input: DeleteStackInput,
     ) -> Result<(), RusotoError<DeleteStackError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DeleteStack");
         let mut params = Params::new();
 
         params.put("Action", "DeleteStack");
@@ -12351,6 +16338,7 @@ impl CloudFormation for CloudFormationClient {
         input: DeleteStackInstancesInput,
     ) -> Result<DeleteStackInstancesOutput, RusotoError<DeleteStackInstancesError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DeleteStackInstances");
         let mut params = Params::new();
 
         params.put("Action", "DeleteStackInstances");
@@ -12400,6 +16388,7 @@ impl CloudFormation for CloudFormationClient {
         input: DeleteStackSetInput,
     ) -> Result<DeleteStackSetOutput, RusotoError<DeleteStackSetError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DeleteStackSet");
         let mut params = Params::new();
 
         params.put("Action", "DeleteStackSet");
@@ -12447,6 +16436,7 @@ impl CloudFormation for CloudFormationClient {
         input: DeregisterTypeInput,
     ) -> Result<DeregisterTypeOutput, RusotoError<DeregisterTypeError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DeregisterType");
         let mut params = Params::new();
 
         params.put("Action", "DeregisterType");
@@ -12494,6 +16484,7 @@ impl CloudFormation for CloudFormationClient {
         input: DescribeAccountLimitsInput,
     ) -> Result<DescribeAccountLimitsOutput, RusotoError<DescribeAccountLimitsError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DescribeAccountLimits");
         let mut params = Params::new();
 
         params.put("Action", "DescribeAccountLimits");
@@ -12543,6 +16534,7 @@ impl CloudFormation for CloudFormationClient {
         input: DescribeChangeSetInput,
     ) -> Result<DescribeChangeSetOutput, RusotoError<DescribeChangeSetError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DescribeChangeSet");
         let mut params = Params::new();
 
         params.put("Action", "DescribeChangeSet");
@@ -12595,6 +16587,7 @@ impl CloudFormation for CloudFormationClient {
         RusotoError<DescribeStackDriftDetectionStatusError>,
     > {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DescribeStackDriftDetectionStatus");
         let mut params = Params::new();
 
         params.put("Action", "DescribeStackDriftDetectionStatus");
@@ -12646,6 +16639,7 @@ impl CloudFormation for CloudFormationClient {
         input: DescribeStackEventsInput,
     ) -> Result<DescribeStackEventsOutput, RusotoError<DescribeStackEventsError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DescribeStackEvents");
         let mut params = Params::new();
 
         params.put("Action", "DescribeStackEvents");
@@ -12695,6 +16689,7 @@ impl CloudFormation for CloudFormationClient {
         input: DescribeStackInstanceInput,
     ) -> Result<DescribeStackInstanceOutput, RusotoError<DescribeStackInstanceError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DescribeStackInstance");
         let mut params = Params::new();
 
         params.put("Action", "DescribeStackInstance");
@@ -12744,6 +16739,7 @@ impl CloudFormation for CloudFormationClient {
         input: DescribeStackResourceInput,
     ) -> Result<DescribeStackResourceOutput, RusotoError<DescribeStackResourceError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DescribeStackResource");
         let mut params = Params::new();
 
         params.put("Action", "DescribeStackResource");
@@ -12794,6 +16790,7 @@ impl CloudFormation for CloudFormationClient {
     ) -> Result<DescribeStackResourceDriftsOutput, RusotoError<DescribeStackResourceDriftsError>>
     {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DescribeStackResourceDrifts");
         let mut params = Params::new();
 
         params.put("Action", "DescribeStackResourceDrifts");
@@ -12843,6 +16840,7 @@ impl CloudFormation for CloudFormationClient {
         input: DescribeStackResourcesInput,
     ) -> Result<DescribeStackResourcesOutput, RusotoError<DescribeStackResourcesError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DescribeStackResources");
         let mut params = Params::new();
 
         params.put("Action", "DescribeStackResources");
@@ -12892,6 +16890,7 @@ impl CloudFormation for CloudFormationClient {
         input: DescribeStackSetInput,
     ) -> Result<DescribeStackSetOutput, RusotoError<DescribeStackSetError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DescribeStackSet");
         let mut params = Params::new();
 
         params.put("Action", "DescribeStackSet");
@@ -12941,6 +16940,7 @@ impl CloudFormation for CloudFormationClient {
         input: DescribeStackSetOperationInput,
     ) -> Result<DescribeStackSetOperationOutput, RusotoError<DescribeStackSetOperationError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DescribeStackSetOperation");
         let mut params = Params::new();
 
         params.put("Action", "DescribeStackSetOperation");
@@ -12990,6 +16990,7 @@ impl CloudFormation for CloudFormationClient {
         input: DescribeStacksInput,
     ) -> Result<DescribeStacksOutput, RusotoError<DescribeStacksError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DescribeStacks");
         let mut params = Params::new();
 
         params.put("Action", "DescribeStacks");
@@ -13037,6 +17038,7 @@ impl CloudFormation for CloudFormationClient {
         input: DescribeTypeInput,
     ) -> Result<DescribeTypeOutput, RusotoError<DescribeTypeError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DescribeType");
         let mut params = Params::new();
 
         params.put("Action", "DescribeType");
@@ -13083,6 +17085,7 @@ impl CloudFormation for CloudFormationClient {
         input: DescribeTypeRegistrationInput,
     ) -> Result<DescribeTypeRegistrationOutput, RusotoError<DescribeTypeRegistrationError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DescribeTypeRegistration");
         let mut params = Params::new();
 
         params.put("Action", "DescribeTypeRegistration");
@@ -13132,6 +17135,7 @@ impl CloudFormation for CloudFormationClient {
         input: DetectStackDriftInput,
     ) -> Result<DetectStackDriftOutput, RusotoError<DetectStackDriftError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DetectStackDrift");
         let mut params = Params::new();
 
         params.put("Action", "DetectStackDrift");
@@ -13181,6 +17185,7 @@ impl CloudFormation for CloudFormationClient {
         input: DetectStackResourceDriftInput,
     ) -> Result<DetectStackResourceDriftOutput, RusotoError<DetectStackResourceDriftError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DetectStackResourceDrift");
         let mut params = Params::new();
 
         params.put("Action", "DetectStackResourceDrift");
@@ -13230,6 +17235,7 @@ impl CloudFormation for CloudFormationClient {
         input: DetectStackSetDriftInput,
     ) -> Result<DetectStackSetDriftOutput, RusotoError<DetectStackSetDriftError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DetectStackSetDrift");
         let mut params = Params::new();
 
         params.put("Action", "DetectStackSetDrift");
@@ -13279,6 +17285,7 @@ impl CloudFormation for CloudFormationClient {
         input: EstimateTemplateCostInput,
     ) -> Result<EstimateTemplateCostOutput, RusotoError<EstimateTemplateCostError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("EstimateTemplateCost");
         let mut params = Params::new();
 
         params.put("Action", "EstimateTemplateCost");
@@ -13328,6 +17335,7 @@ impl CloudFormation for CloudFormationClient {
         input: ExecuteChangeSetInput,
     ) -> Result<ExecuteChangeSetOutput, RusotoError<ExecuteChangeSetError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("ExecuteChangeSet");
         let mut params = Params::new();
 
         params.put("Action", "ExecuteChangeSet");
@@ -13377,6 +17385,7 @@ impl CloudFormation for CloudFormationClient {
         input: GetStackPolicyInput,
     ) -> Result<GetStackPolicyOutput, RusotoError<GetStackPolicyError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("GetStackPolicy");
         let mut params = Params::new();
 
         params.put("Action", "GetStackPolicy");
@@ -13424,6 +17433,7 @@ impl CloudFormation for CloudFormationClient {
         input: GetTemplateInput,
     ) -> Result<GetTemplateOutput, RusotoError<GetTemplateError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("GetTemplate");
         let mut params = Params::new();
 
         params.put("Action", "GetTemplate");
@@ -13470,6 +17480,7 @@ impl CloudFormation for CloudFormationClient {
         input: GetTemplateSummaryInput,
     ) -> Result<GetTemplateSummaryOutput, RusotoError<GetTemplateSummaryError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("GetTemplateSummary");
         let mut params = Params::new();
 
         params.put("Action", "GetTemplateSummary");
@@ -13519,6 +17530,7 @@ impl CloudFormation for CloudFormationClient {
         input: ListChangeSetsInput,
     ) -> Result<ListChangeSetsOutput, RusotoError<ListChangeSetsError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("ListChangeSets");
         let mut params = Params::new();
 
         params.put("Action", "ListChangeSets");
@@ -13566,6 +17578,7 @@ impl CloudFormation for CloudFormationClient {
         input: ListExportsInput,
     ) -> Result<ListExportsOutput, RusotoError<ListExportsError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("ListExports");
         let mut params = Params::new();
 
         params.put("Action", "ListExports");
@@ -13612,6 +17625,7 @@ impl CloudFormation for CloudFormationClient {
         input: ListImportsInput,
     ) -> Result<ListImportsOutput, RusotoError<ListImportsError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("ListImports");
         let mut params = Params::new();
 
         params.put("Action", "ListImports");
@@ -13658,6 +17672,7 @@ impl CloudFormation for CloudFormationClient {
         input: ListStackInstancesInput,
     ) -> Result<ListStackInstancesOutput, RusotoError<ListStackInstancesError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("ListStackInstances");
         let mut params = Params::new();
 
         params.put("Action", "ListStackInstances");
@@ -13707,6 +17722,7 @@ impl CloudFormation for CloudFormationClient {
         input: ListStackResourcesInput,
     ) -> Result<ListStackResourcesOutput, RusotoError<ListStackResourcesError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("ListStackResources");
         let mut params = Params::new();
 
         params.put("Action", "ListStackResources");
@@ -13757,6 +17773,7 @@ impl CloudFormation for CloudFormationClient {
     ) -> Result<ListStackSetOperationResultsOutput, RusotoError<ListStackSetOperationResultsError>>
     {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("ListStackSetOperationResults");
         let mut params = Params::new();
 
         params.put("Action", "ListStackSetOperationResults");
@@ -13806,6 +17823,7 @@ impl CloudFormation for CloudFormationClient {
         input: ListStackSetOperationsInput,
     ) -> Result<ListStackSetOperationsOutput, RusotoError<ListStackSetOperationsError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("ListStackSetOperations");
         let mut params = Params::new();
 
         params.put("Action", "ListStackSetOperations");
@@ -13855,6 +17873,7 @@ impl CloudFormation for CloudFormationClient {
         input: ListStackSetsInput,
     ) -> Result<ListStackSetsOutput, RusotoError<ListStackSetsError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("ListStackSets");
         let mut params = Params::new();
 
         params.put("Action", "ListStackSets");
@@ -13902,6 +17921,7 @@ impl CloudFormation for CloudFormationClient {
         input: ListStacksInput,
     ) -> Result<ListStacksOutput, RusotoError<ListStacksError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("ListStacks");
         let mut params = Params::new();
 
         params.put("Action", "ListStacks");
@@ -13948,6 +17968,7 @@ impl CloudFormation for CloudFormationClient {
         input: ListTypeRegistrationsInput,
     ) -> Result<ListTypeRegistrationsOutput, RusotoError<ListTypeRegistrationsError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("ListTypeRegistrations");
         let mut params = Params::new();
 
         params.put("Action", "ListTypeRegistrations");
@@ -13997,6 +18018,7 @@ impl CloudFormation for CloudFormationClient {
         input: ListTypeVersionsInput,
     ) -> Result<ListTypeVersionsOutput, RusotoError<ListTypeVersionsError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("ListTypeVersions");
         let mut params = Params::new();
 
         params.put("Action", "ListTypeVersions");
@@ -14046,6 +18068,7 @@ impl CloudFormation for CloudFormationClient {
         input: ListTypesInput,
     ) -> Result<ListTypesOutput, RusotoError<ListTypesError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("ListTypes");
         let mut params = Params::new();
 
         params.put("Action", "ListTypes");
@@ -14092,6 +18115,7 @@ impl CloudFormation for CloudFormationClient {
         input: RecordHandlerProgressInput,
     ) -> Result<RecordHandlerProgressOutput, RusotoError<RecordHandlerProgressError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("RecordHandlerProgress");
         let mut params = Params::new();
 
         params.put("Action", "RecordHandlerProgress");
@@ -14141,6 +18165,7 @@ impl CloudFormation for CloudFormationClient {
         input: RegisterTypeInput,
     ) -> Result<RegisterTypeOutput, RusotoError<RegisterTypeError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("RegisterType");
         let mut params = Params::new();
 
         params.put("Action", "RegisterType");
@@ -14187,6 +18212,7 @@ impl CloudFormation for CloudFormationClient {
         input: SetStackPolicyInput,
     ) -> Result<(), RusotoError<SetStackPolicyError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("SetStackPolicy");
         let mut params = Params::new();
 
         params.put("Action", "SetStackPolicy");
@@ -14215,6 +18241,7 @@ impl CloudFormation for CloudFormationClient {
         input: SetTypeDefaultVersionInput,
     ) -> Result<SetTypeDefaultVersionOutput, RusotoError<SetTypeDefaultVersionError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("SetTypeDefaultVersion");
         let mut params = Params::new();
 
         params.put("Action", "SetTypeDefaultVersion");
@@ -14264,6 +18291,7 @@ impl CloudFormation for CloudFormationClient {
         input: SignalResourceInput,
     ) -> Result<(), RusotoError<SignalResourceError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("SignalResource");
         let mut params = Params::new();
 
         params.put("Action", "SignalResource");
@@ -14292,6 +18320,7 @@ impl CloudFormation for CloudFormationClient {
         input: StopStackSetOperationInput,
     ) -> Result<StopStackSetOperationOutput, RusotoError<StopStackSetOperationError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("StopStackSetOperation");
         let mut params = Params::new();
 
         params.put("Action", "StopStackSetOperation");
@@ -14341,6 +18370,7 @@ impl CloudFormation for CloudFormationClient {
         input: UpdateStackInput,
     ) -> Result<UpdateStackOutput, RusotoError<UpdateStackError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("UpdateStack");
         let mut params = Params::new();
 
         params.put("Action", "UpdateStack");
@@ -14387,6 +18417,7 @@ impl CloudFormation for CloudFormationClient {
         input: UpdateStackInstancesInput,
     ) -> Result<UpdateStackInstancesOutput, RusotoError<UpdateStackInstancesError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("UpdateStackInstances");
         let mut params = Params::new();
 
         params.put("Action", "UpdateStackInstances");
@@ -14436,6 +18467,7 @@ impl CloudFormation for CloudFormationClient {
         input: UpdateStackSetInput,
     ) -> Result<UpdateStackSetOutput, RusotoError<UpdateStackSetError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("UpdateStackSet");
         let mut params = Params::new();
 
         params.put("Action", "UpdateStackSet");
@@ -14484,6 +18516,7 @@ impl CloudFormation for CloudFormationClient {
     ) -> Result<UpdateTerminationProtectionOutput, RusotoError<UpdateTerminationProtectionError>>
     {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("UpdateTerminationProtection");
         let mut params = Params::new();
 
         params.put("Action", "UpdateTerminationProtection");
@@ -14533,6 +18566,7 @@ impl CloudFormation for CloudFormationClient {
         input: ValidateTemplateInput,
     ) -> Result<ValidateTemplateOutput, RusotoError<ValidateTemplateError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("ValidateTemplate");
         let mut params = Params::new();
 
         params.put("Action", "ValidateTemplate");
@@ -14576,6 +18610,1142 @@ impl CloudFormation for CloudFormationClient {
         Ok(result)
     }
 }
+impl CloudFormationClient {
+    /// Auto-paginating version of `describe_account_limits`, streaming every page of results.
+    pub fn describe_account_limits_pages(
+        &self,
+        input: DescribeAccountLimitsInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeAccountLimitsOutput,
+        DescribeAccountLimitsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudFormationClient, input: DescribeAccountLimitsInput| async move {
+                client.describe_account_limits(input).await
+            },
+            |_input: &DescribeAccountLimitsInput, output: &DescribeAccountLimitsOutput| {
+                Some(DescribeAccountLimitsInput {
+                    next_token: Some(output.next_token.clone()?),
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_account_limits`, streaming the `AccountLimits` of every page of results.
+    pub fn describe_account_limits_items(
+        &self,
+        input: DescribeAccountLimitsInput,
+    ) -> rusoto_core::pagination::RusotoStream<AccountLimit, DescribeAccountLimitsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_account_limits_pages(input),
+            |output: DescribeAccountLimitsOutput| output.account_limits.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_change_set`, streaming every page of results.
+    pub fn describe_change_set_pages(
+        &self,
+        input: DescribeChangeSetInput,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeChangeSetOutput, DescribeChangeSetError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudFormationClient, input: DescribeChangeSetInput| async move {
+                client.describe_change_set(input).await
+            },
+            |input: &DescribeChangeSetInput, output: &DescribeChangeSetOutput| {
+                Some(DescribeChangeSetInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_change_set`, streaming the `Changes` of every page of results.
+    pub fn describe_change_set_items(
+        &self,
+        input: DescribeChangeSetInput,
+    ) -> rusoto_core::pagination::RusotoStream<Change, DescribeChangeSetError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_change_set_pages(input),
+            |output: DescribeChangeSetOutput| output.changes.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_stack_events`, streaming every page of results.
+    pub fn describe_stack_events_pages(
+        &self,
+        input: DescribeStackEventsInput,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeStackEventsOutput, DescribeStackEventsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudFormationClient, input: DescribeStackEventsInput| async move {
+                client.describe_stack_events(input).await
+            },
+            |input: &DescribeStackEventsInput, output: &DescribeStackEventsOutput| {
+                Some(DescribeStackEventsInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_stack_events`, streaming the `StackEvents` of every page of results.
+    pub fn describe_stack_events_items(
+        &self,
+        input: DescribeStackEventsInput,
+    ) -> rusoto_core::pagination::RusotoStream<StackEvent, DescribeStackEventsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_stack_events_pages(input),
+            |output: DescribeStackEventsOutput| output.stack_events.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_stacks`, streaming every page of results.
+    pub fn describe_stacks_pages(
+        &self,
+        input: DescribeStacksInput,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeStacksOutput, DescribeStacksError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudFormationClient, input: DescribeStacksInput| async move {
+                client.describe_stacks(input).await
+            },
+            |input: &DescribeStacksInput, output: &DescribeStacksOutput| {
+                Some(DescribeStacksInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_stacks`, streaming the `Stacks` of every page of results.
+    pub fn describe_stacks_items(
+        &self,
+        input: DescribeStacksInput,
+    ) -> rusoto_core::pagination::RusotoStream<Stack, DescribeStacksError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_stacks_pages(input),
+            |output: DescribeStacksOutput| output.stacks.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_change_sets`, streaming every page of results.
+    pub fn list_change_sets_pages(
+        &self,
+        input: ListChangeSetsInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListChangeSetsOutput, ListChangeSetsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudFormationClient, input: ListChangeSetsInput| async move {
+                client.list_change_sets(input).await
+            },
+            |input: &ListChangeSetsInput, output: &ListChangeSetsOutput| {
+                Some(ListChangeSetsInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_change_sets`, streaming the `Summaries` of every page of results.
+    pub fn list_change_sets_items(
+        &self,
+        input: ListChangeSetsInput,
+    ) -> rusoto_core::pagination::RusotoStream<ChangeSetSummary, ListChangeSetsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_change_sets_pages(input),
+            |output: ListChangeSetsOutput| output.summaries.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_exports`, streaming every page of results.
+    pub fn list_exports_pages(
+        &self,
+        input: ListExportsInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListExportsOutput, ListExportsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudFormationClient, input: ListExportsInput| async move {
+                client.list_exports(input).await
+            },
+            |_input: &ListExportsInput, output: &ListExportsOutput| {
+                Some(ListExportsInput {
+                    next_token: Some(output.next_token.clone()?),
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_exports`, streaming the `Exports` of every page of results.
+    pub fn list_exports_items(
+        &self,
+        input: ListExportsInput,
+    ) -> rusoto_core::pagination::RusotoStream<Export, ListExportsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_exports_pages(input),
+            |output: ListExportsOutput| output.exports.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_imports`, streaming every page of results.
+    pub fn list_imports_pages(
+        &self,
+        input: ListImportsInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListImportsOutput, ListImportsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudFormationClient, input: ListImportsInput| async move {
+                client.list_imports(input).await
+            },
+            |input: &ListImportsInput, output: &ListImportsOutput| {
+                Some(ListImportsInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_imports`, streaming the `Imports` of every page of results.
+    pub fn list_imports_items(
+        &self,
+        input: ListImportsInput,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListImportsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_imports_pages(input),
+            |output: ListImportsOutput| output.imports.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_stack_instances`, streaming every page of results.
+    pub fn list_stack_instances_pages(
+        &self,
+        input: ListStackInstancesInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListStackInstancesOutput, ListStackInstancesError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudFormationClient, input: ListStackInstancesInput| async move {
+                client.list_stack_instances(input).await
+            },
+            |input: &ListStackInstancesInput, output: &ListStackInstancesOutput| {
+                Some(ListStackInstancesInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_stack_instances`, streaming the `Summaries` of every page of results.
+    pub fn list_stack_instances_items(
+        &self,
+        input: ListStackInstancesInput,
+    ) -> rusoto_core::pagination::RusotoStream<StackInstanceSummary, ListStackInstancesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_stack_instances_pages(input),
+            |output: ListStackInstancesOutput| output.summaries.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_stack_resources`, streaming every page of results.
+    pub fn list_stack_resources_pages(
+        &self,
+        input: ListStackResourcesInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListStackResourcesOutput, ListStackResourcesError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudFormationClient, input: ListStackResourcesInput| async move {
+                client.list_stack_resources(input).await
+            },
+            |input: &ListStackResourcesInput, output: &ListStackResourcesOutput| {
+                Some(ListStackResourcesInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_stack_resources`, streaming the `StackResourceSummaries` of every page of results.
+    pub fn list_stack_resources_items(
+        &self,
+        input: ListStackResourcesInput,
+    ) -> rusoto_core::pagination::RusotoStream<StackResourceSummary, ListStackResourcesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_stack_resources_pages(input),
+            |output: ListStackResourcesOutput| output.stack_resource_summaries.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_stack_set_operation_results`, streaming every page of results.
+    pub fn list_stack_set_operation_results_pages(
+        &self,
+        input: ListStackSetOperationResultsInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListStackSetOperationResultsOutput,
+        ListStackSetOperationResultsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudFormationClient, input: ListStackSetOperationResultsInput| async move {
+                client.list_stack_set_operation_results(input).await
+            },
+            |input: &ListStackSetOperationResultsInput,
+             output: &ListStackSetOperationResultsOutput| {
+                Some(ListStackSetOperationResultsInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_stack_set_operation_results`, streaming the `Summaries` of every page of results.
+    pub fn list_stack_set_operation_results_items(
+        &self,
+        input: ListStackSetOperationResultsInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        StackSetOperationResultSummary,
+        ListStackSetOperationResultsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_stack_set_operation_results_pages(input),
+            |output: ListStackSetOperationResultsOutput| output.summaries.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_stack_set_operations`, streaming every page of results.
+    pub fn list_stack_set_operations_pages(
+        &self,
+        input: ListStackSetOperationsInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListStackSetOperationsOutput,
+        ListStackSetOperationsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudFormationClient, input: ListStackSetOperationsInput| async move {
+                client.list_stack_set_operations(input).await
+            },
+            |input: &ListStackSetOperationsInput, output: &ListStackSetOperationsOutput| {
+                Some(ListStackSetOperationsInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_stack_set_operations`, streaming the `Summaries` of every page of results.
+    pub fn list_stack_set_operations_items(
+        &self,
+        input: ListStackSetOperationsInput,
+    ) -> rusoto_core::pagination::RusotoStream<StackSetOperationSummary, ListStackSetOperationsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_stack_set_operations_pages(input),
+            |output: ListStackSetOperationsOutput| output.summaries.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_stack_sets`, streaming every page of results.
+    pub fn list_stack_sets_pages(
+        &self,
+        input: ListStackSetsInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListStackSetsOutput, ListStackSetsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudFormationClient, input: ListStackSetsInput| async move {
+                client.list_stack_sets(input).await
+            },
+            |input: &ListStackSetsInput, output: &ListStackSetsOutput| {
+                Some(ListStackSetsInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_stack_sets`, streaming the `Summaries` of every page of results.
+    pub fn list_stack_sets_items(
+        &self,
+        input: ListStackSetsInput,
+    ) -> rusoto_core::pagination::RusotoStream<StackSetSummary, ListStackSetsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_stack_sets_pages(input),
+            |output: ListStackSetsOutput| output.summaries.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_stacks`, streaming every page of results.
+    pub fn list_stacks_pages(
+        &self,
+        input: ListStacksInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListStacksOutput, ListStacksError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudFormationClient, input: ListStacksInput| async move {
+                client.list_stacks(input).await
+            },
+            |input: &ListStacksInput, output: &ListStacksOutput| {
+                Some(ListStacksInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_stacks`, streaming the `StackSummaries` of every page of results.
+    pub fn list_stacks_items(
+        &self,
+        input: ListStacksInput,
+    ) -> rusoto_core::pagination::RusotoStream<StackSummary, ListStacksError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_stacks_pages(input),
+            |output: ListStacksOutput| output.stack_summaries.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_types`, streaming every page of results.
+    pub fn list_types_pages(
+        &self,
+        input: ListTypesInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListTypesOutput, ListTypesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudFormationClient, input: ListTypesInput| async move {
+                client.list_types(input).await
+            },
+            |input: &ListTypesInput, output: &ListTypesOutput| {
+                Some(ListTypesInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_types`, streaming the `TypeSummaries` of every page of results.
+    pub fn list_types_items(
+        &self,
+        input: ListTypesInput,
+    ) -> rusoto_core::pagination::RusotoStream<TypeSummary, ListTypesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_types_pages(input),
+            |output: ListTypesOutput| output.type_summaries.unwrap_or_default(),
+        )
+    }
+}
+impl CloudFormationClient {
+    /// Waits until `describe_change_set` reports the `ChangeSetCreateComplete` state, polling every 30 seconds up to 120 times.
+    pub async fn wait_until_change_set_create_complete(
+        &self,
+        input: DescribeChangeSetInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeChangeSetError>> {
+        let config =
+            rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(30), 120);
+        self.wait_until_change_set_create_complete_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_change_set_create_complete`, polling as often and as many times as `config` says.
+    pub async fn wait_until_change_set_create_complete_with_config(
+        &self,
+        input: DescribeChangeSetInput,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeChangeSetError>> {
+        rusoto_core::waiter::wait(
+            "ChangeSetCreateComplete",
+            &config,
+            || self.describe_change_set(input.clone()),
+            |result: &Result<DescribeChangeSetOutput, RusotoError<DescribeChangeSetError>>| {
+                match result {
+                    Ok(output)
+                        if output
+                            .status
+                            .iter()
+                            .any(|v| *v == ChangeSetStatus::CreateComplete) =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Success)
+                    }
+                    Ok(output) if output.status.iter().any(|v| *v == ChangeSetStatus::Failed) => {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    Err(RusotoError::Unknown(response))
+                        if rusoto_core::retry::error_code(response).as_deref()
+                            == Some("ValidationError") =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    _ => None,
+                }
+            },
+        )
+        .await
+    }
+
+    /// Waits until `describe_stacks` reports the `StackCreateComplete` state, polling every 30 seconds up to 120 times.
+    pub async fn wait_until_stack_create_complete(
+        &self,
+        input: DescribeStacksInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeStacksError>> {
+        let config =
+            rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(30), 120);
+        self.wait_until_stack_create_complete_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_stack_create_complete`, polling as often and as many times as `config` says.
+    pub async fn wait_until_stack_create_complete_with_config(
+        &self,
+        input: DescribeStacksInput,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeStacksError>> {
+        rusoto_core::waiter::wait(
+            "StackCreateComplete",
+            &config,
+            || self.describe_stacks(input.clone()),
+            |result: &Result<DescribeStacksOutput, RusotoError<DescribeStacksError>>| match result {
+                Ok(output)
+                    if rusoto_core::waiter::all_match(
+                        output
+                            .stacks
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .map(|v| &v.stack_status),
+                        |v| *v == StackStatus::CreateComplete,
+                    ) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if rusoto_core::waiter::all_match(
+                        output
+                            .stacks
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .map(|v| &v.stack_status),
+                        |v| *v == StackStatus::UpdateComplete,
+                    ) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if rusoto_core::waiter::all_match(
+                        output
+                            .stacks
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .map(|v| &v.stack_status),
+                        |v| *v == StackStatus::UpdateInProgress,
+                    ) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if rusoto_core::waiter::all_match(
+                        output
+                            .stacks
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .map(|v| &v.stack_status),
+                        |v| *v == StackStatus::UpdateCompleteCleanupInProgress,
+                    ) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if rusoto_core::waiter::all_match(
+                        output
+                            .stacks
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .map(|v| &v.stack_status),
+                        |v| *v == StackStatus::UpdateFailed,
+                    ) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if rusoto_core::waiter::all_match(
+                        output
+                            .stacks
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .map(|v| &v.stack_status),
+                        |v| *v == StackStatus::UpdateRollbackInProgress,
+                    ) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if rusoto_core::waiter::all_match(
+                        output
+                            .stacks
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .map(|v| &v.stack_status),
+                        |v| *v == StackStatus::UpdateRollbackFailed,
+                    ) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if rusoto_core::waiter::all_match(
+                        output
+                            .stacks
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .map(|v| &v.stack_status),
+                        |v| *v == StackStatus::UpdateRollbackCompleteCleanupInProgress,
+                    ) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if rusoto_core::waiter::all_match(
+                        output
+                            .stacks
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .map(|v| &v.stack_status),
+                        |v| *v == StackStatus::UpdateRollbackComplete,
+                    ) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if output
+                        .stacks
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .map(|v| &v.stack_status)
+                        .any(|v| *v == StackStatus::CreateFailed) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .stacks
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .map(|v| &v.stack_status)
+                        .any(|v| *v == StackStatus::DeleteComplete) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .stacks
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .map(|v| &v.stack_status)
+                        .any(|v| *v == StackStatus::DeleteFailed) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .stacks
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .map(|v| &v.stack_status)
+                        .any(|v| *v == StackStatus::RollbackFailed) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .stacks
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .map(|v| &v.stack_status)
+                        .any(|v| *v == StackStatus::RollbackComplete) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Err(RusotoError::Unknown(response))
+                    if rusoto_core::retry::error_code(response).as_deref()
+                        == Some("ValidationError") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+
+    /// Waits until `describe_stacks` reports the `StackDeleteComplete` state, polling every 30 seconds up to 120 times.
+    pub async fn wait_until_stack_delete_complete(
+        &self,
+        input: DescribeStacksInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeStacksError>> {
+        let config =
+            rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(30), 120);
+        self.wait_until_stack_delete_complete_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_stack_delete_complete`, polling as often and as many times as `config` says.
+    pub async fn wait_until_stack_delete_complete_with_config(
+        &self,
+        input: DescribeStacksInput,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeStacksError>> {
+        rusoto_core::waiter::wait(
+            "StackDeleteComplete",
+            &config,
+            || self.describe_stacks(input.clone()),
+            |result: &Result<DescribeStacksOutput, RusotoError<DescribeStacksError>>| match result {
+                Ok(output)
+                    if rusoto_core::waiter::all_match(
+                        output
+                            .stacks
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .map(|v| &v.stack_status),
+                        |v| *v == StackStatus::DeleteComplete,
+                    ) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Err(RusotoError::Unknown(response))
+                    if rusoto_core::retry::error_code(response).as_deref()
+                        == Some("ValidationError") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if output
+                        .stacks
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .map(|v| &v.stack_status)
+                        .any(|v| *v == StackStatus::DeleteFailed) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .stacks
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .map(|v| &v.stack_status)
+                        .any(|v| *v == StackStatus::CreateFailed) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .stacks
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .map(|v| &v.stack_status)
+                        .any(|v| *v == StackStatus::RollbackFailed) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .stacks
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .map(|v| &v.stack_status)
+                        .any(|v| *v == StackStatus::UpdateRollbackInProgress) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .stacks
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .map(|v| &v.stack_status)
+                        .any(|v| *v == StackStatus::UpdateRollbackFailed) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .stacks
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .map(|v| &v.stack_status)
+                        .any(|v| *v == StackStatus::UpdateRollbackComplete) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .stacks
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .map(|v| &v.stack_status)
+                        .any(|v| *v == StackStatus::UpdateComplete) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+
+    /// Waits until `describe_stacks` reports the `StackExists` state, polling every 5 seconds up to 20 times.
+    pub async fn wait_until_stack_exists(
+        &self,
+        input: DescribeStacksInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeStacksError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(5), 20);
+        self.wait_until_stack_exists_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_stack_exists`, polling as often and as many times as `config` says.
+    pub async fn wait_until_stack_exists_with_config(
+        &self,
+        input: DescribeStacksInput,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeStacksError>> {
+        rusoto_core::waiter::wait(
+            "StackExists",
+            &config,
+            || self.describe_stacks(input.clone()),
+            |result: &Result<DescribeStacksOutput, RusotoError<DescribeStacksError>>| match result {
+                Ok(_) => Some(rusoto_core::waiter::WaiterState::Success),
+                Err(RusotoError::Unknown(response))
+                    if rusoto_core::retry::error_code(response).as_deref()
+                        == Some("ValidationError") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Retry)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+
+    /// Waits until `describe_stacks` reports the `StackImportComplete` state, polling every 30 seconds up to 120 times.
+    pub async fn wait_until_stack_import_complete(
+        &self,
+        input: DescribeStacksInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeStacksError>> {
+        let config =
+            rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(30), 120);
+        self.wait_until_stack_import_complete_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_stack_import_complete`, polling as often and as many times as `config` says.
+    pub async fn wait_until_stack_import_complete_with_config(
+        &self,
+        input: DescribeStacksInput,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeStacksError>> {
+        rusoto_core::waiter::wait(
+            "StackImportComplete",
+            &config,
+            || self.describe_stacks(input.clone()),
+            |result: &Result<DescribeStacksOutput, RusotoError<DescribeStacksError>>| match result {
+                Ok(output)
+                    if rusoto_core::waiter::all_match(
+                        output
+                            .stacks
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .map(|v| &v.stack_status),
+                        |v| *v == StackStatus::ImportComplete,
+                    ) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if output
+                        .stacks
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .map(|v| &v.stack_status)
+                        .any(|v| *v == StackStatus::RollbackComplete) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .stacks
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .map(|v| &v.stack_status)
+                        .any(|v| *v == StackStatus::RollbackFailed) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .stacks
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .map(|v| &v.stack_status)
+                        .any(|v| *v == StackStatus::ImportRollbackInProgress) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .stacks
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .map(|v| &v.stack_status)
+                        .any(|v| *v == StackStatus::ImportRollbackFailed) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .stacks
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .map(|v| &v.stack_status)
+                        .any(|v| *v == StackStatus::ImportRollbackComplete) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Err(RusotoError::Unknown(response))
+                    if rusoto_core::retry::error_code(response).as_deref()
+                        == Some("ValidationError") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+
+    /// Waits until `describe_stacks` reports the `StackRollbackComplete` state, polling every 30 seconds up to 120 times.
+    pub async fn wait_until_stack_rollback_complete(
+        &self,
+        input: DescribeStacksInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeStacksError>> {
+        let config =
+            rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(30), 120);
+        self.wait_until_stack_rollback_complete_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_stack_rollback_complete`, polling as often and as many times as `config` says.
+    pub async fn wait_until_stack_rollback_complete_with_config(
+        &self,
+        input: DescribeStacksInput,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeStacksError>> {
+        rusoto_core::waiter::wait(
+            "StackRollbackComplete",
+            &config,
+            || self.describe_stacks(input.clone()),
+            |result: &Result<DescribeStacksOutput, RusotoError<DescribeStacksError>>| match result {
+                Ok(output)
+                    if rusoto_core::waiter::all_match(
+                        output
+                            .stacks
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .map(|v| &v.stack_status),
+                        |v| *v == StackStatus::UpdateRollbackComplete,
+                    ) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if output
+                        .stacks
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .map(|v| &v.stack_status)
+                        .any(|v| *v == StackStatus::UpdateFailed) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .stacks
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .map(|v| &v.stack_status)
+                        .any(|v| *v == StackStatus::UpdateRollbackFailed) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .stacks
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .map(|v| &v.stack_status)
+                        .any(|v| *v == StackStatus::DeleteFailed) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Err(RusotoError::Unknown(response))
+                    if rusoto_core::retry::error_code(response).as_deref()
+                        == Some("ValidationError") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+
+    /// Waits until `describe_stacks` reports the `StackUpdateComplete` state, polling every 30 seconds up to 120 times.
+    pub async fn wait_until_stack_update_complete(
+        &self,
+        input: DescribeStacksInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeStacksError>> {
+        let config =
+            rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(30), 120);
+        self.wait_until_stack_update_complete_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_stack_update_complete`, polling as often and as many times as `config` says.
+    pub async fn wait_until_stack_update_complete_with_config(
+        &self,
+        input: DescribeStacksInput,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeStacksError>> {
+        rusoto_core::waiter::wait(
+            "StackUpdateComplete",
+            &config,
+            || self.describe_stacks(input.clone()),
+            |result: &Result<DescribeStacksOutput, RusotoError<DescribeStacksError>>| match result {
+                Ok(output)
+                    if rusoto_core::waiter::all_match(
+                        output
+                            .stacks
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .map(|v| &v.stack_status),
+                        |v| *v == StackStatus::UpdateComplete,
+                    ) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if output
+                        .stacks
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .map(|v| &v.stack_status)
+                        .any(|v| *v == StackStatus::UpdateFailed) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .stacks
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .map(|v| &v.stack_status)
+                        .any(|v| *v == StackStatus::UpdateRollbackFailed) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .stacks
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .map(|v| &v.stack_status)
+                        .any(|v| *v == StackStatus::UpdateRollbackComplete) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Err(RusotoError::Unknown(response))
+                    if rusoto_core::retry::error_code(response).as_deref()
+                        == Some("ValidationError") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+
+    /// Waits until `describe_type_registration` reports the `TypeRegistrationComplete` state, polling every 30 seconds up to 120 times.
+    pub async fn wait_until_type_registration_complete(
+        &self,
+        input: DescribeTypeRegistrationInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeTypeRegistrationError>> {
+        let config =
+            rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(30), 120);
+        self.wait_until_type_registration_complete_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_type_registration_complete`, polling as often and as many times as `config` says.
+    pub async fn wait_until_type_registration_complete_with_config(
+        &self,
+        input: DescribeTypeRegistrationInput,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeTypeRegistrationError>> {
+        rusoto_core::waiter::wait(
+            "TypeRegistrationComplete",
+            &config,
+            || self.describe_type_registration(input.clone()),
+            |result: &Result<
+                DescribeTypeRegistrationOutput,
+                RusotoError<DescribeTypeRegistrationError>,
+            >| match result {
+                Ok(output)
+                    if output
+                        .progress_status
+                        .iter()
+                        .any(|v| *v == RegistrationStatus::Complete) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if output
+                        .progress_status
+                        .iter()
+                        .any(|v| *v == RegistrationStatus::Failed) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+}
 
 #[cfg(test)]
 mod protocol_tests {
@@ -79,7 +79,7 @@ pub struct AliasICPRecordal {
     /// <p>A domain name associated with a distribution. </p>
     pub cname: Option<String>,
     /// <p><p>The Internet Content Provider (ICP) recordal status for a CNAME. The ICPRecordalStatus is set to APPROVED for all CNAMEs (aliases) in regions outside of China. </p> <p>The status values returned are the following:</p> <ul> <li> <p> <b>APPROVED</b> indicates that the associated CNAME has a valid ICP recordal number. Multiple CNAMEs can be associated with a distribution, and CNAMEs can correspond to different ICP recordals. To be marked as APPROVED, that is, valid to use with China region, a CNAME must have one ICP recordal number associated with it.</p> </li> <li> <p> <b>SUSPENDED</b> indicates that the associated CNAME does not have a valid ICP recordal number.</p> </li> <li> <p> <b>PENDING</b> indicates that CloudFront can&#39;t determine the ICP recordal status of the CNAME associated with the distribution because there was an error in trying to determine the status. You can try again to see if the error is resolved in which case CloudFront returns an APPROVED or SUSPENDED status.</p> </li> </ul></p>
-    pub icp_recordal_status: Option<String>,
+    pub icp_recordal_status: Option<ICPRecordalStatus>,
 }
 
 struct AliasICPRecordalDeserializer;
@@ -217,7 +217,7 @@ impl AliasesSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Quantity"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.quantity
+            value = &obj.quantity
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::end_element())
@@ -231,7 +231,7 @@ impl AliasesSerializer {
 pub struct AllowedMethods {
     pub cached_methods: Option<CachedMethods>,
     /// <p>A complex type that contains the HTTP methods that you want CloudFront to process and forward to your origin.</p>
-    pub items: Vec<String>,
+    pub items: Vec<Method>,
     /// <p>The number of HTTP methods that you want CloudFront to forward to your origin. Valid values are 2 (for <code>GET</code> and <code>HEAD</code> requests), 3 (for <code>GET</code>, <code>HEAD</code>, and <code>OPTIONS</code> requests) and 7 (for <code>GET, HEAD, OPTIONS, PUT, PATCH, POST</code>, and <code>DELETE</code> requests).</p>
     pub quantity: i64,
 }
@@ -284,7 +284,7 @@ impl AllowedMethodsSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Quantity"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.quantity
+            value = &obj.quantity
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::end_element())
@@ -390,7 +390,7 @@ pub struct CacheBehavior {
     /// <p>A complex type that specifies the AWS accounts, if any, that you want to allow to create signed URLs for private content.</p> <p>If you want to require signed URLs in requests for objects in the target origin that match the <code>PathPattern</code> for this cache behavior, specify <code>true</code> for <code>Enabled</code>, and specify the applicable values for <code>Quantity</code> and <code>Items</code>. For more information, see <a href="https://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/PrivateContent.html">Serving Private Content through CloudFront</a> in the <i>Amazon CloudFront Developer Guide</i>. </p> <p>If you don't want to require signed URLs in requests for objects that match <code>PathPattern</code>, specify <code>false</code> for <code>Enabled</code> and <code>0</code> for <code>Quantity</code>. Omit <code>Items</code>.</p> <p>To add, change, or remove one or more trusted signers, change <code>Enabled</code> to <code>true</code> (if it's currently <code>false</code>), change <code>Quantity</code> as applicable, and specify all of the trusted signers that you want to include in the updated distribution.</p>
     pub trusted_signers: TrustedSigners,
     /// <p><p>The protocol that viewers can use to access the files in the origin specified by <code>TargetOriginId</code> when a request matches the path pattern in <code>PathPattern</code>. You can specify the following options:</p> <ul> <li> <p> <code>allow-all</code>: Viewers can use HTTP or HTTPS.</p> </li> <li> <p> <code>redirect-to-https</code>: If a viewer submits an HTTP request, CloudFront returns an HTTP status code of 301 (Moved Permanently) to the viewer along with the HTTPS URL. The viewer then resubmits the request using the new URL. </p> </li> <li> <p> <code>https-only</code>: If a viewer sends an HTTP request, CloudFront returns an HTTP status code of 403 (Forbidden). </p> </li> </ul> <p>For more information about requiring the HTTPS protocol, see <a href="https://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/SecureConnections.html">Using an HTTPS Connection to Access Your Objects</a> in the <i>Amazon CloudFront Developer Guide</i>.</p> <note> <p>The only way to guarantee that viewers retrieve an object that was fetched from the origin using HTTPS is never to use any other protocol to fetch the object. If you have recently changed from HTTP to HTTPS, we recommend that you clear your objects&#39; cache because cached objects are protocol agnostic. That means that an edge location will return an object from the cache regardless of whether the current request protocol matches the protocol used previously. For more information, see <a href="https://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/Expiration.html">Managing How Long Content Stays in an Edge Cache (Expiration)</a> in the <i>Amazon CloudFront Developer Guide</i>.</p> </note></p>
-    pub viewer_protocol_policy: String,
+    pub viewer_protocol_policy: ViewerProtocolPolicy,
 }
 
 struct CacheBehaviorDeserializer;
@@ -529,13 +529,13 @@ impl CacheBehaviorSerializer {
         writer.write(xml::writer::XmlEvent::start_element("MinTTL"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.min_ttl
+            value = &obj.min_ttl
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::start_element("PathPattern"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.path_pattern
+            value = &obj.path_pattern
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         if let Some(ref value) = obj.smooth_streaming {
@@ -549,14 +549,14 @@ impl CacheBehaviorSerializer {
         writer.write(xml::writer::XmlEvent::start_element("TargetOriginId"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.target_origin_id
+            value = &obj.target_origin_id
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         TrustedSignersSerializer::serialize(&mut writer, "TrustedSigners", &obj.trusted_signers)?;
         writer.write(xml::writer::XmlEvent::start_element("ViewerProtocolPolicy"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.viewer_protocol_policy
+            value = &obj.viewer_protocol_policy
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::end_element())
@@ -657,7 +657,7 @@ impl CacheBehaviorsSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Quantity"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.quantity
+            value = &obj.quantity
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::end_element())
@@ -670,7 +670,7 @@ impl CacheBehaviorsSerializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CachedMethods {
     /// <p>A complex type that contains the HTTP methods that you want CloudFront to cache responses to.</p>
-    pub items: Vec<String>,
+    pub items: Vec<Method>,
     /// <p>The number of HTTP methods for which you want CloudFront to cache responses. Valid values are <code>2</code> (for caching responses to <code>GET</code> and <code>HEAD</code> requests) and <code>3</code> (for caching responses to <code>GET</code>, <code>HEAD</code>, and <code>OPTIONS</code> requests).</p>
     pub quantity: i64,
 }
@@ -714,13 +714,113 @@ impl CachedMethodsSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Quantity"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.quantity
+            value = &obj.quantity
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::end_element())
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CertificateSource {
+    /// `cloudfront`
+    Cloudfront,
+    /// `iam`
+    Iam,
+    /// `acm`
+    Acm,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CertificateSource {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CertificateSource::Cloudfront => "cloudfront",
+            CertificateSource::Iam => "iam",
+            CertificateSource::Acm => "acm",
+            CertificateSource::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CertificateSource {
+    fn default() -> CertificateSource {
+        CertificateSource::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CertificateSource {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CertificateSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CertificateSource {
+    fn from(value: &'a str) -> CertificateSource {
+        match value {
+            "cloudfront" => CertificateSource::Cloudfront,
+            "iam" => CertificateSource::Iam,
+            "acm" => CertificateSource::Acm,
+            _ => CertificateSource::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CertificateSource {
+    fn from(value: String) -> CertificateSource {
+        match value.as_str() {
+            "cloudfront" => CertificateSource::Cloudfront,
+            "iam" => CertificateSource::Iam,
+            "acm" => CertificateSource::Acm,
+            _ => CertificateSource::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CertificateSource {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CertificateSource, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CertificateSource {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for CertificateSource {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for CertificateSource {
+    fn deserialize<D>(deserializer: D) -> Result<CertificateSource, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>CloudFront origin access identity.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
@@ -820,13 +920,13 @@ impl CloudFrontOriginAccessIdentityConfigSerializer {
         writer.write(xml::writer::XmlEvent::start_element("CallerReference"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.caller_reference
+            value = &obj.caller_reference
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::start_element("Comment"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.comment
+            value = &obj.comment
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::end_element())
@@ -996,7 +1096,7 @@ pub struct ContentTypeProfile {
     /// <p>The content type for a field-level encryption content type-profile mapping. </p>
     pub content_type: String,
     /// <p>The format for a field-level encryption content type-profile mapping. </p>
-    pub format: String,
+    pub format: Format,
     /// <p>The profile ID for a field-level encryption content type-profile mapping. </p>
     pub profile_id: Option<String>,
 }
@@ -1041,13 +1141,13 @@ impl ContentTypeProfileSerializer {
         writer.write(xml::writer::XmlEvent::start_element("ContentType"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.content_type
+            value = &obj.content_type
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::start_element("Format"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.format
+            value = &obj.format
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         if let Some(ref value) = obj.profile_id {
@@ -1127,7 +1227,7 @@ impl ContentTypeProfileConfigSerializer {
         ))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.forward_when_content_type_is_unknown
+            value = &obj.forward_when_content_type_is_unknown
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::end_element())
@@ -1228,7 +1328,7 @@ impl ContentTypeProfilesSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Quantity"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.quantity
+            value = &obj.quantity
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::end_element())
@@ -1326,7 +1426,7 @@ impl CookieNamesSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Quantity"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.quantity
+            value = &obj.quantity
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::end_element())
@@ -1339,7 +1439,7 @@ impl CookieNamesSerializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CookiePreference {
     /// <p>Specifies which cookies to forward to the origin for this cache behavior: all, none, or the list of cookies specified in the <code>WhitelistedNames</code> complex type.</p> <p>Amazon S3 doesn't process cookies. When the cache behavior is forwarding requests to an Amazon S3 origin, specify none for the <code>Forward</code> element. </p>
-    pub forward: String,
+    pub forward: ItemSelection,
     /// <p>Required if you specify <code>whitelist</code> for the value of <code>Forward:</code>. A complex type that specifies how many different cookies you want CloudFront to forward to the origin for this cache behavior and, if you want to forward selected cookies, the names of those cookies.</p> <p>If you specify <code>all</code> or none for the value of <code>Forward</code>, omit <code>WhitelistedNames</code>. If you change the value of <code>Forward</code> from <code>whitelist</code> to all or none and you don't delete the <code>WhitelistedNames</code> element and its child elements, CloudFront deletes them automatically.</p> <p>For the current limit on the number of cookie names that you can whitelist for each cache behavior, see <a href="https://docs.aws.amazon.com/general/latest/gr/xrefaws_service_limits.html#limits_cloudfront"> CloudFront Limits</a> in the <i>AWS General Reference</i>.</p>
     pub whitelisted_names: Option<CookieNames>,
 }
@@ -1384,7 +1484,7 @@ impl CookiePreferenceSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Forward"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.forward
+            value = &obj.forward
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         if let Some(ref value) = obj.whitelisted_names {
@@ -1782,7 +1882,7 @@ impl CustomErrorResponseSerializer {
         writer.write(xml::writer::XmlEvent::start_element("ErrorCode"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.error_code
+            value = &obj.error_code
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         if let Some(ref value) = obj.response_code {
@@ -1899,7 +1999,7 @@ impl CustomErrorResponsesSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Quantity"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.quantity
+            value = &obj.quantity
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::end_element())
@@ -1959,7 +2059,7 @@ impl CustomHeadersSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Quantity"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.quantity
+            value = &obj.quantity
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::end_element())
@@ -1978,7 +2078,7 @@ pub struct CustomOriginConfig {
     /// <p>You can create a custom keep-alive timeout. All timeout units are in seconds. The default keep-alive timeout is 5 seconds, but you can configure custom timeout lengths using the CloudFront API. The minimum timeout length is 1 second; the maximum is 60 seconds.</p> <p>If you need to increase the maximum time limit, contact the <a href="https://console.aws.amazon.com/support/home#/">AWS Support Center</a>.</p>
     pub origin_keepalive_timeout: Option<i64>,
     /// <p>The origin protocol policy to apply to your origin.</p>
-    pub origin_protocol_policy: String,
+    pub origin_protocol_policy: OriginProtocolPolicy,
     /// <p>You can create a custom origin read timeout. All timeout units are in seconds. The default origin read timeout is 30 seconds, but you can configure custom timeout lengths using the CloudFront API. The minimum timeout length is 4 seconds; the maximum is 60 seconds.</p> <p>If you need to increase the maximum time limit, contact the <a href="https://console.aws.amazon.com/support/home#/">AWS Support Center</a>.</p>
     pub origin_read_timeout: Option<i64>,
     /// <p>The SSL/TLS protocols that you want CloudFront to use when communicating with your origin over HTTPS.</p>
@@ -2046,13 +2146,13 @@ impl CustomOriginConfigSerializer {
         writer.write(xml::writer::XmlEvent::start_element("HTTPPort"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.http_port
+            value = &obj.http_port
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::start_element("HTTPSPort"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.https_port
+            value = &obj.https_port
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         if let Some(ref value) = obj.origin_keepalive_timeout {
@@ -2068,7 +2168,7 @@ impl CustomOriginConfigSerializer {
         writer.write(xml::writer::XmlEvent::start_element("OriginProtocolPolicy"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.origin_protocol_policy
+            value = &obj.origin_protocol_policy
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         if let Some(ref value) = obj.origin_read_timeout {
@@ -2113,7 +2213,7 @@ pub struct DefaultCacheBehavior {
     /// <p>A complex type that specifies the AWS accounts, if any, that you want to allow to create signed URLs for private content.</p> <p>If you want to require signed URLs in requests for objects in the target origin that match the <code>PathPattern</code> for this cache behavior, specify <code>true</code> for <code>Enabled</code>, and specify the applicable values for <code>Quantity</code> and <code>Items</code>. For more information, see <a href="https://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/PrivateContent.html">Serving Private Content through CloudFront</a> in the <i> Amazon CloudFront Developer Guide</i>.</p> <p>If you don't want to require signed URLs in requests for objects that match <code>PathPattern</code>, specify <code>false</code> for <code>Enabled</code> and <code>0</code> for <code>Quantity</code>. Omit <code>Items</code>.</p> <p>To add, change, or remove one or more trusted signers, change <code>Enabled</code> to <code>true</code> (if it's currently <code>false</code>), change <code>Quantity</code> as applicable, and specify all of the trusted signers that you want to include in the updated distribution.</p>
     pub trusted_signers: TrustedSigners,
     /// <p><p>The protocol that viewers can use to access the files in the origin specified by <code>TargetOriginId</code> when a request matches the path pattern in <code>PathPattern</code>. You can specify the following options:</p> <ul> <li> <p> <code>allow-all</code>: Viewers can use HTTP or HTTPS.</p> </li> <li> <p> <code>redirect-to-https</code>: If a viewer submits an HTTP request, CloudFront returns an HTTP status code of 301 (Moved Permanently) to the viewer along with the HTTPS URL. The viewer then resubmits the request using the new URL.</p> </li> <li> <p> <code>https-only</code>: If a viewer sends an HTTP request, CloudFront returns an HTTP status code of 403 (Forbidden).</p> </li> </ul> <p>For more information about requiring the HTTPS protocol, see <a href="https://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/SecureConnections.html">Using an HTTPS Connection to Access Your Objects</a> in the <i>Amazon CloudFront Developer Guide</i>.</p> <note> <p>The only way to guarantee that viewers retrieve an object that was fetched from the origin using HTTPS is never to use any other protocol to fetch the object. If you have recently changed from HTTP to HTTPS, we recommend that you clear your objects&#39; cache because cached objects are protocol agnostic. That means that an edge location will return an object from the cache regardless of whether the current request protocol matches the protocol used previously. For more information, see <a href="https://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/Expiration.html">Managing How Long Content Stays in an Edge Cache (Expiration)</a> in the <i>Amazon CloudFront Developer Guide</i>.</p> </note></p>
-    pub viewer_protocol_policy: String,
+    pub viewer_protocol_policy: ViewerProtocolPolicy,
 }
 
 struct DefaultCacheBehaviorDeserializer;
@@ -2249,7 +2349,7 @@ impl DefaultCacheBehaviorSerializer {
         writer.write(xml::writer::XmlEvent::start_element("MinTTL"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.min_ttl
+            value = &obj.min_ttl
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         if let Some(ref value) = obj.smooth_streaming {
@@ -2263,14 +2363,14 @@ impl DefaultCacheBehaviorSerializer {
         writer.write(xml::writer::XmlEvent::start_element("TargetOriginId"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.target_origin_id
+            value = &obj.target_origin_id
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         TrustedSignersSerializer::serialize(&mut writer, "TrustedSigners", &obj.trusted_signers)?;
         writer.write(xml::writer::XmlEvent::start_element("ViewerProtocolPolicy"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.viewer_protocol_policy
+            value = &obj.viewer_protocol_policy
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::end_element())
@@ -2353,7 +2453,7 @@ pub struct Distribution {
     /// <p>The number of invalidation batches currently in progress. </p>
     pub in_progress_invalidation_batches: i64,
     /// <p>The date and time the distribution was last modified. </p>
-    pub last_modified_time: String,
+    pub last_modified_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>This response element indicates the current status of the distribution. When the status is <code>Deployed</code>, the distribution's information is fully propagated to all CloudFront edge locations. </p>
     pub status: String,
 }
@@ -2430,7 +2530,7 @@ pub struct DistributionConfig {
     /// <p>From this field, you can enable or disable the selected distribution.</p>
     pub enabled: bool,
     /// <p>(Optional) Specify the maximum HTTP version that you want viewers to use to communicate with CloudFront. The default value for new web distributions is http2. Viewers that don't support HTTP/2 automatically use an earlier HTTP version.</p> <p>For viewers and CloudFront to use HTTP/2, viewers must support TLS 1.2 or later, and must support Server Name Identification (SNI).</p> <p>In general, configuring CloudFront to communicate with viewers using HTTP/2 reduces latency. You can improve performance by optimizing for HTTP/2. For more information, do an Internet search for "http/2 optimization." </p>
-    pub http_version: Option<String>,
+    pub http_version: Option<HttpVersion>,
     /// <p>If you want CloudFront to respond to IPv6 DNS requests with an IPv6 address for your distribution, specify <code>true</code>. If you specify <code>false</code>, CloudFront responds to IPv6 DNS requests with the DNS response code <code>NOERROR</code> and with no IP addresses. This allows viewers to submit a second request, for an IPv4 address for your distribution. </p> <p>In general, you should enable IPv6 if you have users on IPv6 networks who want to access your content. However, if you're using signed URLs or signed cookies to restrict access to your content, and if you're using a custom policy that includes the <code>IpAddress</code> parameter to restrict the IP addresses that can access your content, don't enable IPv6. If you want to restrict access to some content by IP address and not restrict access to other content (or restrict access but not by IP address), you can create two distributions. For more information, see <a href="https://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/private-content-creating-signed-url-custom-policy.html">Creating a Signed URL Using a Custom Policy</a> in the <i>Amazon CloudFront Developer Guide</i>.</p> <p>If you're using an Amazon Route 53 alias resource record set to route traffic to your CloudFront distribution, you need to create a second alias resource record set when both of the following are true:</p> <ul> <li> <p>You enable IPv6 for the distribution</p> </li> <li> <p>You're using alternate domain names in the URLs for your objects</p> </li> </ul> <p>For more information, see <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/routing-to-cloudfront-distribution.html">Routing Traffic to an Amazon CloudFront Web Distribution by Using Your Domain Name</a> in the <i>Amazon Route 53 Developer Guide</i>.</p> <p>If you created a CNAME resource record set, either with Amazon Route 53 or with another DNS service, you don't need to make any changes. A CNAME record will route traffic to your distribution regardless of the IP address format of the viewer request.</p>
     pub is_ipv6_enabled: Option<bool>,
     /// <p>A complex type that controls whether access logs are written for the distribution.</p> <p>For more information about logging, see <a href="https://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/AccessLogs.html">Access Logs</a> in the <i>Amazon CloudFront Developer Guide</i>.</p>
@@ -2440,7 +2540,7 @@ pub struct DistributionConfig {
     /// <p>A complex type that contains information about origins for this distribution. </p>
     pub origins: Origins,
     /// <p>The price class that corresponds with the maximum price that you want to pay for CloudFront service. If you specify <code>PriceClass_All</code>, CloudFront responds to requests for your objects from all CloudFront edge locations.</p> <p>If you specify a price class other than <code>PriceClass_All</code>, CloudFront serves your objects from the CloudFront edge location that has the lowest latency among the edge locations in your price class. Viewers who are in or near regions that are excluded from your specified price class may encounter slower performance.</p> <p>For more information about price classes, see <a href="https://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/PriceClass.html">Choosing the Price Class for a CloudFront Distribution</a> in the <i>Amazon CloudFront Developer Guide</i>. For information about CloudFront pricing, including how price classes (such as Price Class 100) map to CloudFront regions, see <a href="http://aws.amazon.com/cloudfront/pricing/">Amazon CloudFront Pricing</a>. For price class information, scroll down to see the table at the bottom of the page.</p>
-    pub price_class: Option<String>,
+    pub price_class: Option<PriceClass>,
     /// <p>A complex type that identifies ways in which you want to restrict distribution of your content.</p>
     pub restrictions: Option<Restrictions>,
     /// <p>A complex type that specifies whether you want viewers to use HTTP or HTTPS to request your objects, whether you're using an alternate domain name with HTTPS, and if so, if you're using AWS Certificate Manager (ACM) or a third-party certificate authority.</p>
@@ -2561,13 +2661,13 @@ impl DistributionConfigSerializer {
         writer.write(xml::writer::XmlEvent::start_element("CallerReference"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.caller_reference
+            value = &obj.caller_reference
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::start_element("Comment"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.comment
+            value = &obj.comment
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         if let Some(ref value) = obj.custom_error_responses {
@@ -2589,7 +2689,7 @@ impl DistributionConfigSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Enabled"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.enabled
+            value = &obj.enabled
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         if let Some(ref value) = obj.http_version {
@@ -2749,19 +2849,19 @@ pub struct DistributionSummary {
     /// <p>Whether the distribution is enabled to accept user requests for content.</p>
     pub enabled: bool,
     /// <p> Specify the maximum HTTP version that you want viewers to use to communicate with CloudFront. The default value for new web distributions is <code>http2</code>. Viewers that don't support <code>HTTP/2</code> will automatically use an earlier version.</p>
-    pub http_version: String,
+    pub http_version: HttpVersion,
     /// <p>The identifier for the distribution. For example: <code>EDFDVBD632BHDS5</code>.</p>
     pub id: String,
     /// <p>Whether CloudFront responds to IPv6 DNS requests with an IPv6 address for your distribution.</p>
     pub is_ipv6_enabled: bool,
     /// <p>The date and time the distribution was last modified.</p>
-    pub last_modified_time: String,
+    pub last_modified_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p> A complex type that contains information about origin groups for this distribution.</p>
     pub origin_groups: Option<OriginGroups>,
     /// <p>A complex type that contains information about origins for this distribution.</p>
     pub origins: Origins,
     /// <p>A complex type that contains information about price class for this streaming distribution. </p>
-    pub price_class: String,
+    pub price_class: PriceClass,
     /// <p>A complex type that identifies ways in which you want to restrict distribution of your content.</p>
     pub restrictions: Restrictions,
     /// <p>The current status of the distribution. When the status is <code>Deployed</code>, the distribution's information is propagated to all CloudFront edge locations.</p>
@@ -2935,7 +3035,7 @@ impl EncryptionEntitiesSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Quantity"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.quantity
+            value = &obj.quantity
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::end_element())
@@ -2997,13 +3097,13 @@ impl EncryptionEntitySerializer {
         writer.write(xml::writer::XmlEvent::start_element("ProviderId"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.provider_id
+            value = &obj.provider_id
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::start_element("PublicKeyId"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.public_key_id
+            value = &obj.public_key_id
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::end_element())
@@ -3051,12 +3151,120 @@ impl EncryptionEntityListSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EventType {
+    /// `viewer-request`
+    ViewerRequest,
+    /// `viewer-response`
+    ViewerResponse,
+    /// `origin-request`
+    OriginRequest,
+    /// `origin-response`
+    OriginResponse,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EventType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EventType::ViewerRequest => "viewer-request",
+            EventType::ViewerResponse => "viewer-response",
+            EventType::OriginRequest => "origin-request",
+            EventType::OriginResponse => "origin-response",
+            EventType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EventType {
+    fn default() -> EventType {
+        EventType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EventType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EventType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EventType {
+    fn from(value: &'a str) -> EventType {
+        match value {
+            "viewer-request" => EventType::ViewerRequest,
+            "viewer-response" => EventType::ViewerResponse,
+            "origin-request" => EventType::OriginRequest,
+            "origin-response" => EventType::OriginResponse,
+            _ => EventType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EventType {
+    fn from(value: String) -> EventType {
+        match value.as_str() {
+            "viewer-request" => EventType::ViewerRequest,
+            "viewer-response" => EventType::ViewerResponse,
+            "origin-request" => EventType::OriginRequest,
+            "origin-response" => EventType::OriginResponse,
+            _ => EventType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EventType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EventType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EventType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for EventType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for EventType {
+    fn deserialize<D>(deserializer: D) -> Result<EventType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct EventTypeDeserializer;
 impl EventTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<EventType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -3069,7 +3277,7 @@ impl EventTypeSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &EventType,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -3092,7 +3300,7 @@ pub struct FieldLevelEncryption {
     /// <p>The configuration ID for a field-level encryption configuration which includes a set of profiles that specify certain selected data fields to be encrypted by specific public keys.</p>
     pub id: String,
     /// <p>The last time the field-level encryption configuration was changed. </p>
-    pub last_modified_time: String,
+    pub last_modified_time: ::chrono::DateTime<::chrono::Utc>,
 }
 
 struct FieldLevelEncryptionDeserializer;
@@ -3195,7 +3403,7 @@ impl FieldLevelEncryptionConfigSerializer {
         writer.write(xml::writer::XmlEvent::start_element("CallerReference"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.caller_reference
+            value = &obj.caller_reference
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         if let Some(ref value) = obj.comment {
@@ -3283,7 +3491,7 @@ pub struct FieldLevelEncryptionProfile {
     /// <p>The ID for a field-level encryption profile configuration which includes a set of profiles that specify certain selected data fields to be encrypted by specific public keys.</p>
     pub id: String,
     /// <p>The last time the field-level encryption profile was updated.</p>
-    pub last_modified_time: String,
+    pub last_modified_time: ::chrono::DateTime<::chrono::Utc>,
 }
 
 struct FieldLevelEncryptionProfileDeserializer;
@@ -3385,7 +3593,7 @@ impl FieldLevelEncryptionProfileConfigSerializer {
         writer.write(xml::writer::XmlEvent::start_element("CallerReference"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.caller_reference
+            value = &obj.caller_reference
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         if let Some(ref value) = obj.comment {
@@ -3404,7 +3612,7 @@ impl FieldLevelEncryptionProfileConfigSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Name"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.name
+            value = &obj.name
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::end_element())
@@ -3472,7 +3680,7 @@ pub struct FieldLevelEncryptionProfileSummary {
     /// <p>ID for the field-level encryption profile summary.</p>
     pub id: String,
     /// <p>The time when the the field-level encryption profile summary was last updated.</p>
-    pub last_modified_time: String,
+    pub last_modified_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>Name for the field-level encryption profile summary.</p>
     pub name: String,
 }
@@ -3546,7 +3754,7 @@ pub struct FieldLevelEncryptionSummary {
     /// <p>The unique ID of a field-level encryption item.</p>
     pub id: String,
     /// <p>The last time that the summary of field-level encryption items was modified.</p>
-    pub last_modified_time: String,
+    pub last_modified_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p> A summary of a query argument-profile mapping. </p>
     pub query_arg_profile_config: Option<QueryArgProfileConfig>,
 }
@@ -3705,19 +3913,109 @@ impl FieldPatternsSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Quantity"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.quantity
+            value = &obj.quantity
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::end_element())
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Format {
+    /// `URLEncoded`
+    URLEncoded,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Format {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Format::URLEncoded => "URLEncoded",
+            Format::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Format {
+    fn default() -> Format {
+        Format::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Format {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Format {
+    fn from(value: &'a str) -> Format {
+        match value {
+            "URLEncoded" => Format::URLEncoded,
+            _ => Format::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Format {
+    fn from(value: String) -> Format {
+        match value.as_str() {
+            "URLEncoded" => Format::URLEncoded,
+            _ => Format::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Format {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Format, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Format {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for Format {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for Format {
+    fn deserialize<D>(deserializer: D) -> Result<Format, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct FormatDeserializer;
 impl FormatDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<Format, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -3730,7 +4028,7 @@ impl FormatSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &Format,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -3810,7 +4108,7 @@ impl ForwardedValuesSerializer {
         writer.write(xml::writer::XmlEvent::start_element("QueryString"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.query_string
+            value = &obj.query_string
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         if let Some(ref value) = obj.query_string_cache_keys {
@@ -3830,7 +4128,7 @@ pub struct GeoRestriction {
     /// <p>When geo restriction is <code>enabled</code>, this is the number of countries in your <code>whitelist</code> or <code>blacklist</code>. Otherwise, when it is not enabled, <code>Quantity</code> is <code>0</code>, and you can omit <code>Items</code>.</p>
     pub quantity: i64,
     /// <p><p>The method that you want to use to restrict distribution of your content by country:</p> <ul> <li> <p> <code>none</code>: No geo restriction is enabled, meaning access to content is not restricted by client geo location.</p> </li> <li> <p> <code>blacklist</code>: The <code>Location</code> elements specify the countries in which you don&#39;t want CloudFront to distribute your content.</p> </li> <li> <p> <code>whitelist</code>: The <code>Location</code> elements specify the countries in which you want CloudFront to distribute your content.</p> </li> </ul></p>
-    pub restriction_type: String,
+    pub restriction_type: GeoRestrictionType,
 }
 
 struct GeoRestrictionDeserializer;
@@ -3879,25 +4177,128 @@ impl GeoRestrictionSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Quantity"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.quantity
+            value = &obj.quantity
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::start_element("RestrictionType"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.restriction_type
+            value = &obj.restriction_type
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::end_element())
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum GeoRestrictionType {
+    /// `blacklist`
+    Blacklist,
+    /// `whitelist`
+    Whitelist,
+    /// `none`
+    None,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl GeoRestrictionType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            GeoRestrictionType::Blacklist => "blacklist",
+            GeoRestrictionType::Whitelist => "whitelist",
+            GeoRestrictionType::None => "none",
+            GeoRestrictionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for GeoRestrictionType {
+    fn default() -> GeoRestrictionType {
+        GeoRestrictionType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for GeoRestrictionType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for GeoRestrictionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for GeoRestrictionType {
+    fn from(value: &'a str) -> GeoRestrictionType {
+        match value {
+            "blacklist" => GeoRestrictionType::Blacklist,
+            "whitelist" => GeoRestrictionType::Whitelist,
+            "none" => GeoRestrictionType::None,
+            _ => GeoRestrictionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for GeoRestrictionType {
+    fn from(value: String) -> GeoRestrictionType {
+        match value.as_str() {
+            "blacklist" => GeoRestrictionType::Blacklist,
+            "whitelist" => GeoRestrictionType::Whitelist,
+            "none" => GeoRestrictionType::None,
+            _ => GeoRestrictionType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for GeoRestrictionType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<GeoRestrictionType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for GeoRestrictionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for GeoRestrictionType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for GeoRestrictionType {
+    fn deserialize<D>(deserializer: D) -> Result<GeoRestrictionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct GeoRestrictionTypeDeserializer;
 impl GeoRestrictionTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<GeoRestrictionType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -3910,7 +4311,7 @@ impl GeoRestrictionTypeSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &GeoRestrictionType,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -4454,19 +4855,117 @@ impl HeadersSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Quantity"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.quantity
+            value = &obj.quantity
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::end_element())
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum HttpVersion {
+    /// `http1.1`
+    Http1_1,
+    /// `http2`
+    Http2,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl HttpVersion {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            HttpVersion::Http1_1 => "http1.1",
+            HttpVersion::Http2 => "http2",
+            HttpVersion::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for HttpVersion {
+    fn default() -> HttpVersion {
+        HttpVersion::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for HttpVersion {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for HttpVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for HttpVersion {
+    fn from(value: &'a str) -> HttpVersion {
+        match value {
+            "http1.1" => HttpVersion::Http1_1,
+            "http2" => HttpVersion::Http2,
+            _ => HttpVersion::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for HttpVersion {
+    fn from(value: String) -> HttpVersion {
+        match value.as_str() {
+            "http1.1" => HttpVersion::Http1_1,
+            "http2" => HttpVersion::Http2,
+            _ => HttpVersion::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for HttpVersion {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<HttpVersion, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for HttpVersion {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for HttpVersion {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for HttpVersion {
+    fn deserialize<D>(deserializer: D) -> Result<HttpVersion, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct HttpVersionDeserializer;
 impl HttpVersionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<HttpVersion, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -4479,7 +4978,7 @@ impl HttpVersionSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &HttpVersion,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -4493,12 +4992,115 @@ impl HttpVersionSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ICPRecordalStatus {
+    /// `APPROVED`
+    Approved,
+    /// `SUSPENDED`
+    Suspended,
+    /// `PENDING`
+    Pending,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ICPRecordalStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ICPRecordalStatus::Approved => "APPROVED",
+            ICPRecordalStatus::Suspended => "SUSPENDED",
+            ICPRecordalStatus::Pending => "PENDING",
+            ICPRecordalStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ICPRecordalStatus {
+    fn default() -> ICPRecordalStatus {
+        ICPRecordalStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ICPRecordalStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ICPRecordalStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ICPRecordalStatus {
+    fn from(value: &'a str) -> ICPRecordalStatus {
+        match value {
+            "APPROVED" => ICPRecordalStatus::Approved,
+            "SUSPENDED" => ICPRecordalStatus::Suspended,
+            "PENDING" => ICPRecordalStatus::Pending,
+            _ => ICPRecordalStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ICPRecordalStatus {
+    fn from(value: String) -> ICPRecordalStatus {
+        match value.as_str() {
+            "APPROVED" => ICPRecordalStatus::Approved,
+            "SUSPENDED" => ICPRecordalStatus::Suspended,
+            "PENDING" => ICPRecordalStatus::Pending,
+            _ => ICPRecordalStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ICPRecordalStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ICPRecordalStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ICPRecordalStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ICPRecordalStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ICPRecordalStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ICPRecordalStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct ICPRecordalStatusDeserializer;
 impl ICPRecordalStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ICPRecordalStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -4541,7 +5143,7 @@ impl IntegerSerializer {
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct Invalidation {
     /// <p>The date and time the invalidation request was first made. </p>
-    pub create_time: String,
+    pub create_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The identifier for the invalidation request. For example: <code>IDFDVBD632BHDS5</code>.</p>
     pub id: String,
     /// <p>The current invalidation information for the batch request. </p>
@@ -4627,7 +5229,7 @@ impl InvalidationBatchSerializer {
         writer.write(xml::writer::XmlEvent::start_element("CallerReference"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.caller_reference
+            value = &obj.caller_reference
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         PathsSerializer::serialize(&mut writer, "Paths", &obj.paths)?;
@@ -4693,7 +5295,7 @@ impl InvalidationListDeserializer {
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct InvalidationSummary {
     /// <p>The time that an invalidation request was created.</p>
-    pub create_time: String,
+    pub create_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The unique ID for an invalidation request.</p>
     pub id: String,
     /// <p>The status of an invalidation request.</p>
@@ -4744,12 +5346,115 @@ impl InvalidationSummaryListDeserializer {
         })
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ItemSelection {
+    /// `none`
+    None,
+    /// `whitelist`
+    Whitelist,
+    /// `all`
+    All,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ItemSelection {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ItemSelection::None => "none",
+            ItemSelection::Whitelist => "whitelist",
+            ItemSelection::All => "all",
+            ItemSelection::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ItemSelection {
+    fn default() -> ItemSelection {
+        ItemSelection::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ItemSelection {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ItemSelection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ItemSelection {
+    fn from(value: &'a str) -> ItemSelection {
+        match value {
+            "none" => ItemSelection::None,
+            "whitelist" => ItemSelection::Whitelist,
+            "all" => ItemSelection::All,
+            _ => ItemSelection::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ItemSelection {
+    fn from(value: String) -> ItemSelection {
+        match value.as_str() {
+            "none" => ItemSelection::None,
+            "whitelist" => ItemSelection::Whitelist,
+            "all" => ItemSelection::All,
+            _ => ItemSelection::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ItemSelection {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ItemSelection, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ItemSelection {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ItemSelection {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ItemSelection {
+    fn deserialize<D>(deserializer: D) -> Result<ItemSelection, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct ItemSelectionDeserializer;
 impl ItemSelectionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ItemSelection, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -4762,7 +5467,7 @@ impl ItemSelectionSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &ItemSelection,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -4864,7 +5569,7 @@ impl LambdaFunctionARNSerializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct LambdaFunctionAssociation {
     /// <p><p>Specifies the event type that triggers a Lambda function invocation. You can specify the following values:</p> <ul> <li> <p> <code>viewer-request</code>: The function executes when CloudFront receives a request from a viewer and before it checks to see whether the requested object is in the edge cache. </p> </li> <li> <p> <code>origin-request</code>: The function executes only when CloudFront forwards a request to your origin. When the requested object is in the edge cache, the function doesn&#39;t execute.</p> </li> <li> <p> <code>origin-response</code>: The function executes after CloudFront receives a response from the origin and before it caches the object in the response. When the requested object is in the edge cache, the function doesn&#39;t execute.</p> </li> <li> <p> <code>viewer-response</code>: The function executes before CloudFront returns the requested object to the viewer. The function executes regardless of whether the object was already in the edge cache.</p> <p>If the origin returns an HTTP status code other than HTTP 200 (OK), the function doesn&#39;t execute.</p> </li> </ul></p>
-    pub event_type: String,
+    pub event_type: EventType,
     /// <p>A flag that allows a Lambda function to have read access to the body content. For more information, see <a href="https://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/lambda-include-body-access.html">Accessing the Request Body by Choosing the Include Body Option</a> in the Amazon CloudFront Developer Guide.</p>
     pub include_body: Option<bool>,
     /// <p>The ARN of the Lambda function. You must specify the ARN of a function version; you can't specify a Lambda alias or $LATEST.</p>
@@ -4917,7 +5622,7 @@ impl LambdaFunctionAssociationSerializer {
         writer.write(xml::writer::XmlEvent::start_element("EventType"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.event_type
+            value = &obj.event_type
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         if let Some(ref value) = obj.include_body {
@@ -4931,7 +5636,7 @@ impl LambdaFunctionAssociationSerializer {
         writer.write(xml::writer::XmlEvent::start_element("LambdaFunctionARN"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.lambda_function_arn
+            value = &obj.lambda_function_arn
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::end_element())
@@ -5040,7 +5745,7 @@ impl LambdaFunctionAssociationsSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Quantity"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.quantity
+            value = &obj.quantity
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::end_element())
@@ -5447,25 +6152,25 @@ impl LoggingConfigSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Bucket"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.bucket
+            value = &obj.bucket
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::start_element("Enabled"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.enabled
+            value = &obj.enabled
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::start_element("IncludeCookies"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.include_cookies
+            value = &obj.include_cookies
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::start_element("Prefix"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.prefix
+            value = &obj.prefix
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::end_element())
@@ -5504,12 +6209,132 @@ impl LongSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Method {
+    /// `GET`
+    Get,
+    /// `HEAD`
+    Head,
+    /// `POST`
+    Post,
+    /// `PUT`
+    Put,
+    /// `PATCH`
+    Patch,
+    /// `OPTIONS`
+    Options,
+    /// `DELETE`
+    Delete,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Method {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Method::Get => "GET",
+            Method::Head => "HEAD",
+            Method::Post => "POST",
+            Method::Put => "PUT",
+            Method::Patch => "PATCH",
+            Method::Options => "OPTIONS",
+            Method::Delete => "DELETE",
+            Method::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Method {
+    fn default() -> Method {
+        Method::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Method {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Method {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Method {
+    fn from(value: &'a str) -> Method {
+        match value {
+            "GET" => Method::Get,
+            "HEAD" => Method::Head,
+            "POST" => Method::Post,
+            "PUT" => Method::Put,
+            "PATCH" => Method::Patch,
+            "OPTIONS" => Method::Options,
+            "DELETE" => Method::Delete,
+            _ => Method::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Method {
+    fn from(value: String) -> Method {
+        match value.as_str() {
+            "GET" => Method::Get,
+            "HEAD" => Method::Head,
+            "POST" => Method::Post,
+            "PUT" => Method::Put,
+            "PATCH" => Method::Patch,
+            "OPTIONS" => Method::Options,
+            "DELETE" => Method::Delete,
+            _ => Method::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Method {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Method, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Method {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for Method {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for Method {
+    fn deserialize<D>(deserializer: D) -> Result<Method, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct MethodDeserializer;
 impl MethodDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<Method, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -5522,7 +6347,7 @@ impl MethodSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &Method,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -5542,7 +6367,7 @@ impl MethodsListDeserializer {
     fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
-    ) -> Result<Vec<String>, XmlParseError> {
+    ) -> Result<Vec<Method>, XmlParseError> {
         deserialize_elements::<_, Vec<_>, _>(tag_name, stack, |name, stack, obj| {
             if name == "Method" {
                 obj.push(MethodDeserializer::deserialize("Method", stack)?);
@@ -5560,7 +6385,7 @@ impl MethodsListSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &Vec<String>,
+        obj: &Vec<Method>,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -5574,12 +6399,130 @@ impl MethodsListSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MinimumProtocolVersion {
+    /// `SSLv3`
+    SSLv3,
+    /// `TLSv1`
+    TLSv1,
+    /// `TLSv1_2016`
+    TLSv1_2016,
+    /// `TLSv1.1_2016`
+    TLSv1_1_2016,
+    /// `TLSv1.2_2018`
+    TLSv1_2_2018,
+    /// `TLSv1.2_2019`
+    TLSv1_2_2019,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl MinimumProtocolVersion {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MinimumProtocolVersion::SSLv3 => "SSLv3",
+            MinimumProtocolVersion::TLSv1 => "TLSv1",
+            MinimumProtocolVersion::TLSv1_2016 => "TLSv1_2016",
+            MinimumProtocolVersion::TLSv1_1_2016 => "TLSv1.1_2016",
+            MinimumProtocolVersion::TLSv1_2_2018 => "TLSv1.2_2018",
+            MinimumProtocolVersion::TLSv1_2_2019 => "TLSv1.2_2019",
+            MinimumProtocolVersion::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for MinimumProtocolVersion {
+    fn default() -> MinimumProtocolVersion {
+        MinimumProtocolVersion::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for MinimumProtocolVersion {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for MinimumProtocolVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for MinimumProtocolVersion {
+    fn from(value: &'a str) -> MinimumProtocolVersion {
+        match value {
+            "SSLv3" => MinimumProtocolVersion::SSLv3,
+            "TLSv1" => MinimumProtocolVersion::TLSv1,
+            "TLSv1_2016" => MinimumProtocolVersion::TLSv1_2016,
+            "TLSv1.1_2016" => MinimumProtocolVersion::TLSv1_1_2016,
+            "TLSv1.2_2018" => MinimumProtocolVersion::TLSv1_2_2018,
+            "TLSv1.2_2019" => MinimumProtocolVersion::TLSv1_2_2019,
+            _ => MinimumProtocolVersion::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MinimumProtocolVersion {
+    fn from(value: String) -> MinimumProtocolVersion {
+        match value.as_str() {
+            "SSLv3" => MinimumProtocolVersion::SSLv3,
+            "TLSv1" => MinimumProtocolVersion::TLSv1,
+            "TLSv1_2016" => MinimumProtocolVersion::TLSv1_2016,
+            "TLSv1.1_2016" => MinimumProtocolVersion::TLSv1_1_2016,
+            "TLSv1.2_2018" => MinimumProtocolVersion::TLSv1_2_2018,
+            "TLSv1.2_2019" => MinimumProtocolVersion::TLSv1_2_2019,
+            _ => MinimumProtocolVersion::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for MinimumProtocolVersion {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<MinimumProtocolVersion, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MinimumProtocolVersion {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for MinimumProtocolVersion {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for MinimumProtocolVersion {
+    fn deserialize<D>(deserializer: D) -> Result<MinimumProtocolVersion, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct MinimumProtocolVersionDeserializer;
 impl MinimumProtocolVersionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<MinimumProtocolVersion, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -5592,7 +6535,7 @@ impl MinimumProtocolVersionSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &MinimumProtocolVersion,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -5686,13 +6629,13 @@ impl OriginSerializer {
         writer.write(xml::writer::XmlEvent::start_element("DomainName"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.domain_name
+            value = &obj.domain_name
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::start_element("Id"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.id
+            value = &obj.id
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         if let Some(ref value) = obj.origin_path {
@@ -5758,13 +6701,13 @@ impl OriginCustomHeaderSerializer {
         writer.write(xml::writer::XmlEvent::start_element("HeaderName"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.header_name
+            value = &obj.header_name
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::start_element("HeaderValue"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.header_value
+            value = &obj.header_value
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::end_element())
@@ -5873,7 +6816,7 @@ impl OriginGroupSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Id"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.id
+            value = &obj.id
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         OriginGroupMembersSerializer::serialize(&mut writer, "Members", &obj.members)?;
@@ -6012,7 +6955,7 @@ impl OriginGroupMemberSerializer {
         writer.write(xml::writer::XmlEvent::start_element("OriginId"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.origin_id
+            value = &obj.origin_id
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::end_element())
@@ -6112,7 +7055,7 @@ impl OriginGroupMembersSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Quantity"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.quantity
+            value = &obj.quantity
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::end_element())
@@ -6172,7 +7115,7 @@ impl OriginGroupsSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Quantity"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.quantity
+            value = &obj.quantity
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::end_element())
@@ -6217,12 +7160,115 @@ impl OriginListSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OriginProtocolPolicy {
+    /// `http-only`
+    HttpOnly,
+    /// `match-viewer`
+    MatchViewer,
+    /// `https-only`
+    HttpsOnly,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl OriginProtocolPolicy {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OriginProtocolPolicy::HttpOnly => "http-only",
+            OriginProtocolPolicy::MatchViewer => "match-viewer",
+            OriginProtocolPolicy::HttpsOnly => "https-only",
+            OriginProtocolPolicy::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for OriginProtocolPolicy {
+    fn default() -> OriginProtocolPolicy {
+        OriginProtocolPolicy::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for OriginProtocolPolicy {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for OriginProtocolPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for OriginProtocolPolicy {
+    fn from(value: &'a str) -> OriginProtocolPolicy {
+        match value {
+            "http-only" => OriginProtocolPolicy::HttpOnly,
+            "match-viewer" => OriginProtocolPolicy::MatchViewer,
+            "https-only" => OriginProtocolPolicy::HttpsOnly,
+            _ => OriginProtocolPolicy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OriginProtocolPolicy {
+    fn from(value: String) -> OriginProtocolPolicy {
+        match value.as_str() {
+            "http-only" => OriginProtocolPolicy::HttpOnly,
+            "match-viewer" => OriginProtocolPolicy::MatchViewer,
+            "https-only" => OriginProtocolPolicy::HttpsOnly,
+            _ => OriginProtocolPolicy::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for OriginProtocolPolicy {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<OriginProtocolPolicy, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OriginProtocolPolicy {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for OriginProtocolPolicy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for OriginProtocolPolicy {
+    fn deserialize<D>(deserializer: D) -> Result<OriginProtocolPolicy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct OriginProtocolPolicyDeserializer;
 impl OriginProtocolPolicyDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<OriginProtocolPolicy, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -6235,7 +7281,7 @@ impl OriginProtocolPolicySerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &OriginProtocolPolicy,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -6255,7 +7301,7 @@ impl OriginProtocolPolicySerializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct OriginSslProtocols {
     /// <p>A list that contains allowed SSL/TLS protocols for this distribution.</p>
-    pub items: Vec<String>,
+    pub items: Vec<SslProtocol>,
     /// <p>The number of SSL/TLS protocols that you want to allow CloudFront to use when establishing an HTTPS connection with this origin. </p>
     pub quantity: i64,
 }
@@ -6299,7 +7345,7 @@ impl OriginSslProtocolsSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Quantity"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.quantity
+            value = &obj.quantity
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::end_element())
@@ -6356,7 +7402,7 @@ impl OriginsSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Quantity"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.quantity
+            value = &obj.quantity
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::end_element())
@@ -6451,19 +7497,122 @@ impl PathsSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Quantity"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.quantity
+            value = &obj.quantity
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::end_element())
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PriceClass {
+    /// `PriceClass_100`
+    PriceClass100,
+    /// `PriceClass_200`
+    PriceClass200,
+    /// `PriceClass_All`
+    PriceClassAll,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PriceClass {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PriceClass::PriceClass100 => "PriceClass_100",
+            PriceClass::PriceClass200 => "PriceClass_200",
+            PriceClass::PriceClassAll => "PriceClass_All",
+            PriceClass::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PriceClass {
+    fn default() -> PriceClass {
+        PriceClass::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PriceClass {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PriceClass {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PriceClass {
+    fn from(value: &'a str) -> PriceClass {
+        match value {
+            "PriceClass_100" => PriceClass::PriceClass100,
+            "PriceClass_200" => PriceClass::PriceClass200,
+            "PriceClass_All" => PriceClass::PriceClassAll,
+            _ => PriceClass::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PriceClass {
+    fn from(value: String) -> PriceClass {
+        match value.as_str() {
+            "PriceClass_100" => PriceClass::PriceClass100,
+            "PriceClass_200" => PriceClass::PriceClass200,
+            "PriceClass_All" => PriceClass::PriceClassAll,
+            _ => PriceClass::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PriceClass {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PriceClass, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PriceClass {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for PriceClass {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for PriceClass {
+    fn deserialize<D>(deserializer: D) -> Result<PriceClass, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct PriceClassDeserializer;
 impl PriceClassDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<PriceClass, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -6476,7 +7625,7 @@ impl PriceClassSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &PriceClass,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -6495,7 +7644,7 @@ impl PriceClassSerializer {
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct PublicKey {
     /// <p>A time you added a public key to CloudFront.</p>
-    pub created_time: String,
+    pub created_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>A unique ID assigned to a public key you've added to CloudFront.</p>
     pub id: String,
     /// <p>A complex data type for a public key you add to CloudFront to use with features like field-level encryption.</p>
@@ -6586,7 +7735,7 @@ impl PublicKeyConfigSerializer {
         writer.write(xml::writer::XmlEvent::start_element("CallerReference"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.caller_reference
+            value = &obj.caller_reference
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         if let Some(ref value) = obj.comment {
@@ -6600,13 +7749,13 @@ impl PublicKeyConfigSerializer {
         writer.write(xml::writer::XmlEvent::start_element("EncodedKey"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.encoded_key
+            value = &obj.encoded_key
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::start_element("Name"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.name
+            value = &obj.name
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::end_element())
@@ -6663,7 +7812,7 @@ pub struct PublicKeySummary {
     /// <p> Comment for public key information summary. </p>
     pub comment: Option<String>,
     /// <p> Creation time for public key information summary. </p>
-    pub created_time: String,
+    pub created_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p> Encoded key for public key information summary. </p>
     pub encoded_key: String,
     /// <p> ID for public key information summary. </p>
@@ -6770,13 +7919,13 @@ impl QueryArgProfileSerializer {
         writer.write(xml::writer::XmlEvent::start_element("ProfileId"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.profile_id
+            value = &obj.profile_id
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::start_element("QueryArg"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.query_arg
+            value = &obj.query_arg
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::end_element())
@@ -6840,7 +7989,7 @@ impl QueryArgProfileConfigSerializer {
         ))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.forward_when_query_arg_profile_is_unknown
+            value = &obj.forward_when_query_arg_profile_is_unknown
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         if let Some(ref value) = obj.query_arg_profiles {
@@ -6944,7 +8093,7 @@ impl QueryArgProfilesSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Quantity"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.quantity
+            value = &obj.quantity
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::end_element())
@@ -7004,7 +8153,7 @@ impl QueryStringCacheKeysSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Quantity"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.quantity
+            value = &obj.quantity
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::end_element())
@@ -7164,13 +8313,13 @@ impl S3OriginSerializer {
         writer.write(xml::writer::XmlEvent::start_element("DomainName"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.domain_name
+            value = &obj.domain_name
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::start_element("OriginAccessIdentity"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.origin_access_identity
+            value = &obj.origin_access_identity
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::end_element())
@@ -7221,19 +8370,117 @@ impl S3OriginConfigSerializer {
         writer.write(xml::writer::XmlEvent::start_element("OriginAccessIdentity"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.origin_access_identity
+            value = &obj.origin_access_identity
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::end_element())
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SSLSupportMethod {
+    /// `sni-only`
+    SniOnly,
+    /// `vip`
+    Vip,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SSLSupportMethod {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SSLSupportMethod::SniOnly => "sni-only",
+            SSLSupportMethod::Vip => "vip",
+            SSLSupportMethod::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SSLSupportMethod {
+    fn default() -> SSLSupportMethod {
+        SSLSupportMethod::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SSLSupportMethod {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SSLSupportMethod {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SSLSupportMethod {
+    fn from(value: &'a str) -> SSLSupportMethod {
+        match value {
+            "sni-only" => SSLSupportMethod::SniOnly,
+            "vip" => SSLSupportMethod::Vip,
+            _ => SSLSupportMethod::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SSLSupportMethod {
+    fn from(value: String) -> SSLSupportMethod {
+        match value.as_str() {
+            "sni-only" => SSLSupportMethod::SniOnly,
+            "vip" => SSLSupportMethod::Vip,
+            _ => SSLSupportMethod::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SSLSupportMethod {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SSLSupportMethod, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SSLSupportMethod {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for SSLSupportMethod {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for SSLSupportMethod {
+    fn deserialize<D>(deserializer: D) -> Result<SSLSupportMethod, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct SSLSupportMethodDeserializer;
 impl SSLSupportMethodDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<SSLSupportMethod, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -7246,7 +8493,7 @@ impl SSLSupportMethodSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &SSLSupportMethod,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -7307,12 +8554,120 @@ impl SignerListDeserializer {
         })
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SslProtocol {
+    /// `SSLv3`
+    SSLv3,
+    /// `TLSv1`
+    TLSv1,
+    /// `TLSv1.1`
+    TLSv1_1,
+    /// `TLSv1.2`
+    TLSv1_2,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SslProtocol {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SslProtocol::SSLv3 => "SSLv3",
+            SslProtocol::TLSv1 => "TLSv1",
+            SslProtocol::TLSv1_1 => "TLSv1.1",
+            SslProtocol::TLSv1_2 => "TLSv1.2",
+            SslProtocol::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SslProtocol {
+    fn default() -> SslProtocol {
+        SslProtocol::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SslProtocol {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SslProtocol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SslProtocol {
+    fn from(value: &'a str) -> SslProtocol {
+        match value {
+            "SSLv3" => SslProtocol::SSLv3,
+            "TLSv1" => SslProtocol::TLSv1,
+            "TLSv1.1" => SslProtocol::TLSv1_1,
+            "TLSv1.2" => SslProtocol::TLSv1_2,
+            _ => SslProtocol::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SslProtocol {
+    fn from(value: String) -> SslProtocol {
+        match value.as_str() {
+            "SSLv3" => SslProtocol::SSLv3,
+            "TLSv1" => SslProtocol::TLSv1,
+            "TLSv1.1" => SslProtocol::TLSv1_1,
+            "TLSv1.2" => SslProtocol::TLSv1_2,
+            _ => SslProtocol::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SslProtocol {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SslProtocol, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SslProtocol {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for SslProtocol {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for SslProtocol {
+    fn deserialize<D>(deserializer: D) -> Result<SslProtocol, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct SslProtocolDeserializer;
 impl SslProtocolDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<SslProtocol, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -7325,7 +8680,7 @@ impl SslProtocolSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &SslProtocol,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -7345,7 +8700,7 @@ impl SslProtocolsListDeserializer {
     fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
-    ) -> Result<Vec<String>, XmlParseError> {
+    ) -> Result<Vec<SslProtocol>, XmlParseError> {
         deserialize_elements::<_, Vec<_>, _>(tag_name, stack, |name, stack, obj| {
             if name == "SslProtocol" {
                 obj.push(SslProtocolDeserializer::deserialize("SslProtocol", stack)?);
@@ -7363,7 +8718,7 @@ impl SslProtocolsListSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &Vec<String>,
+        obj: &Vec<SslProtocol>,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -7465,7 +8820,7 @@ impl StatusCodesSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Quantity"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.quantity
+            value = &obj.quantity
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::end_element())
@@ -7485,7 +8840,7 @@ pub struct StreamingDistribution {
     /// <p>The identifier for the RTMP distribution. For example: <code>EGTXBD79EXAMPLE</code>.</p>
     pub id: String,
     /// <p>The date and time that the distribution was last modified. </p>
-    pub last_modified_time: Option<String>,
+    pub last_modified_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The current status of the RTMP distribution. When the status is <code>Deployed</code>, the distribution's information is propagated to all CloudFront edge locations.</p>
     pub status: String,
     /// <p>The current configuration information for the RTMP distribution.</p>
@@ -7554,7 +8909,7 @@ pub struct StreamingDistributionConfig {
     /// <p>A complex type that controls whether access logs are written for the streaming distribution. </p>
     pub logging: Option<StreamingLoggingConfig>,
     /// <p>A complex type that contains information about price class for this streaming distribution. </p>
-    pub price_class: Option<String>,
+    pub price_class: Option<PriceClass>,
     /// <p>A complex type that contains information about the Amazon S3 bucket from which you want CloudFront to get your media files for distribution. </p>
     pub s3_origin: S3Origin,
     /// <p>A complex type that specifies any AWS accounts that you want to permit to create signed URLs for private content. If you want the distribution to use signed URLs, include this element; if you want the distribution to use public URLs, remove this element. For more information, see <a href="https://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/PrivateContent.html">Serving Private Content through CloudFront</a> in the <i>Amazon CloudFront Developer Guide</i>. </p>
@@ -7628,19 +8983,19 @@ impl StreamingDistributionConfigSerializer {
         writer.write(xml::writer::XmlEvent::start_element("CallerReference"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.caller_reference
+            value = &obj.caller_reference
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::start_element("Comment"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.comment
+            value = &obj.comment
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::start_element("Enabled"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.enabled
+            value = &obj.enabled
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         if let Some(ref value) = obj.logging {
@@ -7769,9 +9124,9 @@ pub struct StreamingDistributionSummary {
     /// <p>The identifier for the distribution, for example, <code>EDFDVBD632BHDS5</code>.</p>
     pub id: String,
     /// <p>The date and time the distribution was last modified.</p>
-    pub last_modified_time: String,
+    pub last_modified_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>A complex type that contains information about price class for this streaming distribution. </p>
-    pub price_class: String,
+    pub price_class: PriceClass,
     /// <p>A complex type that contains information about the Amazon S3 bucket from which you want CloudFront to get your media files for distribution.</p>
     pub s3_origin: S3Origin,
     /// <p> Indicates the current status of the distribution. When the status is <code>Deployed</code>, the distribution's information is fully propagated throughout the Amazon CloudFront system.</p>
@@ -7907,19 +9262,19 @@ impl StreamingLoggingConfigSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Bucket"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.bucket
+            value = &obj.bucket
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::start_element("Enabled"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.enabled
+            value = &obj.enabled
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::start_element("Prefix"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.prefix
+            value = &obj.prefix
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::end_element())
@@ -8003,7 +9358,7 @@ impl TagSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Key"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.key
+            value = &obj.key
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         if let Some(ref value) = obj.value {
@@ -8226,9 +9581,12 @@ impl TagsSerializer {
 struct TimestampDeserializer;
 impl TimestampDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<::chrono::DateTime<::chrono::Utc>, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::proto::xml::util::timestamp_characters(stack)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -8289,7 +9647,7 @@ impl TrustedSignersSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Enabled"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.enabled
+            value = &obj.enabled
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         if let Some(ref value) = obj.items {
@@ -8298,7 +9656,7 @@ impl TrustedSignersSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Quantity"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.quantity
+            value = &obj.quantity
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::end_element())
@@ -8550,9 +9908,9 @@ pub struct ViewerCertificate {
     /// <p>If you want viewers to use HTTPS to request your objects and you're using an alternate domain name, you must choose the type of certificate that you want to use. If you purchased your certificate from a third-party certificate authority and uploaded it to the IAM certificate store, specify the certificate ID that you want to use for this distribution.</p> <p>If you specify a certificate ID, you must also specify an SSL support method (<code>sni-only</code> or <code>vip</code>).</p>
     pub iam_certificate_id: Option<String>,
     /// <p>Specify the security policy that you want CloudFront to use for HTTPS connections. A security policy determines two settings:</p> <ul> <li> <p>The minimum SSL/TLS protocol that CloudFront uses to communicate with viewers.</p> </li> <li> <p>The cipher that CloudFront uses to encrypt the content that it returns to viewers.</p> </li> </ul> <note> <p>On the CloudFront console, this setting is called <b>Security Policy</b>.</p> </note> <p>We recommend that you specify <code>TLSv1.1_2016</code> unless your viewers are using browsers or devices that do not support TLSv1.1 or later.</p> <p>When both of the following are true, you must specify <code>TLSv1</code> or later for the security policy: </p> <ul> <li> <p>You're using a custom certificate; that is, you specified a value for <code>ACMCertificateArn</code> or for <code>IAMCertificateId</code>.</p> </li> <li> <p>You're using SNI; that is, you specified <code>sni-only</code> for <code>SSLSupportMethod</code>.</p> </li> </ul> <p>If you specify <code>true</code> for <code>CloudFrontDefaultCertificate</code>, CloudFront automatically sets the security policy to <code>TLSv1</code> regardless of the value that you specify here.</p> <p>For information about the relationship between the security policy that you choose and the protocols and ciphers that CloudFront uses to communicate with viewers, see <a href="https://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/secure-connections-supported-viewer-protocols-ciphers.html#secure-connections-supported-ciphers"> Supported SSL/TLS Protocols and Ciphers for Communication Between Viewers and CloudFront</a> in the <i>Amazon CloudFront Developer Guide</i>.</p>
-    pub minimum_protocol_version: Option<String>,
+    pub minimum_protocol_version: Option<MinimumProtocolVersion>,
     /// <p>If you specify a value for <a href="https://docs.aws.amazon.com/cloudfront/latest/APIReference/API_ViewerCertificate.html#cloudfront-Type-ViewerCertificate-ACMCertificateArn">ACMCertificateArn</a> or for <a href="https://docs.aws.amazon.com/cloudfront/latest/APIReference/API_ViewerCertificate.html#cloudfront-Type-ViewerCertificate-IAMCertificateId">IAMCertificateId</a>, you must also specify how you want CloudFront to serve HTTPS requests: using a method that works for browsers and clients released after 2010, or one that works for all clients.</p> <ul> <li> <p> <code>sni-only</code>: CloudFront can respond to HTTPS requests from viewers that support Server Name Indication (SNI). All modern browsers support SNI, but there are a few that don't. For a current list of the browsers that support SNI, see the <a href="http://en.wikipedia.org/wiki/Server_Name_Indication">Wikipedia entry Server Name Indication</a>. To learn about options to explore if you have viewers with browsers that don't include SNI support, see <a href="https://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/cnames-https-dedicated-ip-or-sni.html">Choosing How CloudFront Serves HTTPS Requests</a> in the <i>Amazon CloudFront Developer Guide</i>.</p> </li> <li> <p> <code>vip</code>: CloudFront uses dedicated IP addresses for your content and can respond to HTTPS requests from any viewer. However, there are additional monthly charges. For details, including specific pricing information, see <a href="http://aws.amazon.com/cloudfront/custom-ssl-domains/">Custom SSL options for Amazon CloudFront</a> on the AWS marketing site.</p> </li> </ul> <p>Don't specify a value here if you specified <code>CloudFrontDefaultCertificate</code> as <code>true</code>.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/cnames-https-dedicated-ip-or-sni.html">Choosing How CloudFront Serves HTTPS Requests</a> in the <i>Amazon CloudFront Developer Guide</i>.</p>
-    pub ssl_support_method: Option<String>,
+    pub ssl_support_method: Option<SSLSupportMethod>,
 }
 
 struct ViewerCertificateDeserializer;
@@ -8658,12 +10016,115 @@ impl ViewerCertificateSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ViewerProtocolPolicy {
+    /// `allow-all`
+    AllowAll,
+    /// `https-only`
+    HttpsOnly,
+    /// `redirect-to-https`
+    RedirectToHttps,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ViewerProtocolPolicy {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ViewerProtocolPolicy::AllowAll => "allow-all",
+            ViewerProtocolPolicy::HttpsOnly => "https-only",
+            ViewerProtocolPolicy::RedirectToHttps => "redirect-to-https",
+            ViewerProtocolPolicy::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ViewerProtocolPolicy {
+    fn default() -> ViewerProtocolPolicy {
+        ViewerProtocolPolicy::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ViewerProtocolPolicy {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ViewerProtocolPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ViewerProtocolPolicy {
+    fn from(value: &'a str) -> ViewerProtocolPolicy {
+        match value {
+            "allow-all" => ViewerProtocolPolicy::AllowAll,
+            "https-only" => ViewerProtocolPolicy::HttpsOnly,
+            "redirect-to-https" => ViewerProtocolPolicy::RedirectToHttps,
+            _ => ViewerProtocolPolicy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ViewerProtocolPolicy {
+    fn from(value: String) -> ViewerProtocolPolicy {
+        match value.as_str() {
+            "allow-all" => ViewerProtocolPolicy::AllowAll,
+            "https-only" => ViewerProtocolPolicy::HttpsOnly,
+            "redirect-to-https" => ViewerProtocolPolicy::RedirectToHttps,
+            _ => ViewerProtocolPolicy::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ViewerProtocolPolicy {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ViewerProtocolPolicy, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ViewerProtocolPolicy {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ViewerProtocolPolicy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ViewerProtocolPolicy {
+    fn deserialize<D>(deserializer: D) -> Result<ViewerProtocolPolicy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct ViewerProtocolPolicyDeserializer;
 impl ViewerProtocolPolicyDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ViewerProtocolPolicy, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -8676,7 +10137,7 @@ impl ViewerProtocolPolicySerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &ViewerProtocolPolicy,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -12427,11 +13888,12 @@ pub struct CloudFrontClient {
 impl CloudFrontClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> CloudFrontClient {
         CloudFrontClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "CloudFront"),
         }
     }
 
@@ -12469,6 +13931,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2019-03-26/origin-access-identity/cloudfront";
 
         let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation("CreateCloudFrontOriginAccessIdentity2019_03_26");
 
         let mut writer = EventWriter::new(Vec::new());
         CloudFrontOriginAccessIdentityConfigSerializer::serialize(
@@ -12528,6 +13991,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2019-03-26/distribution";
 
         let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation("CreateDistribution2019_03_26");
 
         let mut writer = EventWriter::new(Vec::new());
         DistributionConfigSerializer::serialize(
@@ -12584,6 +14048,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2019-03-26/distribution";
 
         let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation("CreateDistributionWithTags2019_03_26");
 
         let mut params = Params::new();
         params.put_key("WithTags");
@@ -12647,6 +14112,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2019-03-26/field-level-encryption";
 
         let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation("CreateFieldLevelEncryptionConfig2019_03_26");
 
         let mut writer = EventWriter::new(Vec::new());
         FieldLevelEncryptionConfigSerializer::serialize(
@@ -12709,6 +14175,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2019-03-26/field-level-encryption-profile";
 
         let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation("CreateFieldLevelEncryptionProfile2019_03_26");
 
         let mut writer = EventWriter::new(Vec::new());
         FieldLevelEncryptionProfileConfigSerializer::serialize(
@@ -12771,6 +14238,7 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation("CreateInvalidation2019_03_26");
 
         let mut writer = EventWriter::new(Vec::new());
         InvalidationBatchSerializer::serialize(
@@ -12822,6 +14290,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2019-03-26/public-key";
 
         let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation("CreatePublicKey2019_03_26");
 
         let mut writer = EventWriter::new(Vec::new());
         PublicKeyConfigSerializer::serialize(
@@ -12877,6 +14346,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2019-03-26/streaming-distribution";
 
         let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation("CreateStreamingDistribution2019_03_26");
 
         let mut writer = EventWriter::new(Vec::new());
         StreamingDistributionConfigSerializer::serialize(
@@ -12937,6 +14407,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2019-03-26/streaming-distribution";
 
         let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation("CreateStreamingDistributionWithTags2019_03_26");
 
         let mut params = Params::new();
         params.put_key("WithTags");
@@ -13002,6 +14473,7 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "cloudfront", &self.region, &request_uri);
+        request.set_operation("DeleteCloudFrontOriginAccessIdentity2019_03_26");
 
         if let Some(ref if_match) = input.if_match {
             request.add_header("If-Match", &if_match.to_string());
@@ -13032,6 +14504,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = format!("/2019-03-26/distribution/{id}", id = input.id);
 
         let mut request = SignedRequest::new("DELETE", "cloudfront", &self.region, &request_uri);
+        request.set_operation("DeleteDistribution2019_03_26");
 
         if let Some(ref if_match) = input.if_match {
             request.add_header("If-Match", &if_match.to_string());
@@ -13060,6 +14533,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = format!("/2019-03-26/field-level-encryption/{id}", id = input.id);
 
         let mut request = SignedRequest::new("DELETE", "cloudfront", &self.region, &request_uri);
+        request.set_operation("DeleteFieldLevelEncryptionConfig2019_03_26");
 
         if let Some(ref if_match) = input.if_match {
             request.add_header("If-Match", &if_match.to_string());
@@ -13093,6 +14567,7 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "cloudfront", &self.region, &request_uri);
+        request.set_operation("DeleteFieldLevelEncryptionProfile2019_03_26");
 
         if let Some(ref if_match) = input.if_match {
             request.add_header("If-Match", &if_match.to_string());
@@ -13123,6 +14598,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = format!("/2019-03-26/public-key/{id}", id = input.id);
 
         let mut request = SignedRequest::new("DELETE", "cloudfront", &self.region, &request_uri);
+        request.set_operation("DeletePublicKey2019_03_26");
 
         if let Some(ref if_match) = input.if_match {
             request.add_header("If-Match", &if_match.to_string());
@@ -13151,6 +14627,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = format!("/2019-03-26/streaming-distribution/{id}", id = input.id);
 
         let mut request = SignedRequest::new("DELETE", "cloudfront", &self.region, &request_uri);
+        request.set_operation("DeleteStreamingDistribution2019_03_26");
 
         if let Some(ref if_match) = input.if_match {
             request.add_header("If-Match", &if_match.to_string());
@@ -13185,6 +14662,7 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetCloudFrontOriginAccessIdentity2019_03_26");
 
         let mut response = self
             .client
@@ -13238,6 +14716,7 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetCloudFrontOriginAccessIdentityConfig2019_03_26");
 
         let mut response = self
             .client
@@ -13285,6 +14764,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = format!("/2019-03-26/distribution/{id}", id = input.id);
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetDistribution2019_03_26");
 
         let mut response = self
             .client
@@ -13327,6 +14807,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = format!("/2019-03-26/distribution/{id}/config", id = input.id);
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetDistributionConfig2019_03_26");
 
         let mut response = self
             .client
@@ -13370,6 +14851,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = format!("/2019-03-26/field-level-encryption/{id}", id = input.id);
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetFieldLevelEncryption2019_03_26");
 
         let mut response = self
             .client
@@ -13419,6 +14901,7 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetFieldLevelEncryptionConfig2019_03_26");
 
         let mut response = self
             .client
@@ -13470,6 +14953,7 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetFieldLevelEncryptionProfile2019_03_26");
 
         let mut response = self
             .client
@@ -13521,6 +15005,7 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetFieldLevelEncryptionProfileConfig2019_03_26");
 
         let mut response = self
             .client
@@ -13572,6 +15057,7 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetInvalidation2019_03_26");
 
         let mut response = self
             .client
@@ -13611,6 +15097,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = format!("/2019-03-26/public-key/{id}", id = input.id);
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetPublicKey2019_03_26");
 
         let mut response = self
             .client
@@ -13653,6 +15140,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = format!("/2019-03-26/public-key/{id}/config", id = input.id);
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetPublicKeyConfig2019_03_26");
 
         let mut response = self
             .client
@@ -13696,6 +15184,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = format!("/2019-03-26/streaming-distribution/{id}", id = input.id);
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetStreamingDistribution2019_03_26");
 
         let mut response = self
             .client
@@ -13747,6 +15236,7 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetStreamingDistributionConfig2019_03_26");
 
         let mut response = self
             .client
@@ -13795,6 +15285,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2019-03-26/origin-access-identity/cloudfront";
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("ListCloudFrontOriginAccessIdentities2019_03_26");
 
         let mut params = Params::new();
         if let Some(ref x) = input.marker {
@@ -13848,6 +15339,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2019-03-26/distribution";
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("ListDistributions2019_03_26");
 
         let mut params = Params::new();
         if let Some(ref x) = input.marker {
@@ -13901,6 +15393,7 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("ListDistributionsByWebACLId2019_03_26");
 
         let mut params = Params::new();
         if let Some(ref x) = input.marker {
@@ -13955,6 +15448,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2019-03-26/field-level-encryption";
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("ListFieldLevelEncryptionConfigs2019_03_26");
 
         let mut params = Params::new();
         if let Some(ref x) = input.marker {
@@ -14011,6 +15505,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2019-03-26/field-level-encryption-profile";
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("ListFieldLevelEncryptionProfiles2019_03_26");
 
         let mut params = Params::new();
         if let Some(ref x) = input.marker {
@@ -14067,6 +15562,7 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("ListInvalidations2019_03_26");
 
         let mut params = Params::new();
         if let Some(ref x) = input.marker {
@@ -14116,6 +15612,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2019-03-26/public-key";
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("ListPublicKeys2019_03_26");
 
         let mut params = Params::new();
         if let Some(ref x) = input.marker {
@@ -14165,6 +15662,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2019-03-26/streaming-distribution";
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("ListStreamingDistributions2019_03_26");
 
         let mut params = Params::new();
         if let Some(ref x) = input.marker {
@@ -14216,6 +15714,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2019-03-26/tagging";
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource2019_03_26");
 
         let mut params = Params::new();
         params.put("Resource", &input.resource);
@@ -14260,6 +15759,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2019-03-26/tagging";
 
         let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation("TagResource2019_03_26");
 
         let mut params = Params::new();
         params.put("Resource", &input.resource);
@@ -14292,6 +15792,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2019-03-26/tagging";
 
         let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation("UntagResource2019_03_26");
 
         let mut params = Params::new();
         params.put("Resource", &input.resource);
@@ -14330,6 +15831,7 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("PUT", "cloudfront", &self.region, &request_uri);
+        request.set_operation("UpdateCloudFrontOriginAccessIdentity2019_03_26");
 
         if let Some(ref if_match) = input.if_match {
             request.add_header("If-Match", &if_match.to_string());
@@ -14389,6 +15891,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = format!("/2019-03-26/distribution/{id}/config", id = input.id);
 
         let mut request = SignedRequest::new("PUT", "cloudfront", &self.region, &request_uri);
+        request.set_operation("UpdateDistribution2019_03_26");
 
         if let Some(ref if_match) = input.if_match {
             request.add_header("If-Match", &if_match.to_string());
@@ -14450,6 +15953,7 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("PUT", "cloudfront", &self.region, &request_uri);
+        request.set_operation("UpdateFieldLevelEncryptionConfig2019_03_26");
 
         if let Some(ref if_match) = input.if_match {
             request.add_header("If-Match", &if_match.to_string());
@@ -14515,6 +16019,7 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("PUT", "cloudfront", &self.region, &request_uri);
+        request.set_operation("UpdateFieldLevelEncryptionProfile2019_03_26");
 
         if let Some(ref if_match) = input.if_match {
             request.add_header("If-Match", &if_match.to_string());
@@ -14574,6 +16079,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = format!("/2019-03-26/public-key/{id}/config", id = input.id);
 
         let mut request = SignedRequest::new("PUT", "cloudfront", &self.region, &request_uri);
+        request.set_operation("UpdatePublicKey2019_03_26");
 
         if let Some(ref if_match) = input.if_match {
             request.add_header("If-Match", &if_match.to_string());
@@ -14632,6 +16138,7 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("PUT", "cloudfront", &self.region, &request_uri);
+        request.set_operation("UpdateStreamingDistribution2019_03_26");
 
         if let Some(ref if_match) = input.if_match {
             request.add_header("If-Match", &if_match.to_string());
@@ -14680,6 +16187,335 @@ impl CloudFront for CloudFrontClient {
         Ok(result)
     }
 }
+impl CloudFrontClient {
+    /// Auto-paginating version of `list_cloud_front_origin_access_identities`, streaming every page of results.
+    pub fn list_cloud_front_origin_access_identities_pages(
+        &self,
+        input: ListCloudFrontOriginAccessIdentitiesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListCloudFrontOriginAccessIdentitiesResult,
+        ListCloudFrontOriginAccessIdentitiesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudFrontClient, input: ListCloudFrontOriginAccessIdentitiesRequest| async move {
+                client
+                    .list_cloud_front_origin_access_identities(input)
+                    .await
+            },
+            |input: &ListCloudFrontOriginAccessIdentitiesRequest,
+             output: &ListCloudFrontOriginAccessIdentitiesResult| {
+                if !output
+                    .cloud_front_origin_access_identity_list
+                    .as_ref()
+                    .map(|v| v.is_truncated)
+                    .unwrap_or(false)
+                {
+                    return None;
+                }
+                Some(ListCloudFrontOriginAccessIdentitiesRequest {
+                    marker: Some(
+                        output
+                            .cloud_front_origin_access_identity_list
+                            .as_ref()
+                            .and_then(|v| v.next_marker.clone())?,
+                    ),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_cloud_front_origin_access_identities`, streaming the `CloudFrontOriginAccessIdentityList.Items` of every page of results.
+    pub fn list_cloud_front_origin_access_identities_items(
+        &self,
+        input: ListCloudFrontOriginAccessIdentitiesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        CloudFrontOriginAccessIdentitySummary,
+        ListCloudFrontOriginAccessIdentitiesError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_cloud_front_origin_access_identities_pages(input),
+            |output: ListCloudFrontOriginAccessIdentitiesResult| {
+                output
+                    .cloud_front_origin_access_identity_list
+                    .and_then(|v| v.items)
+                    .unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_distributions`, streaming every page of results.
+    pub fn list_distributions_pages(
+        &self,
+        input: ListDistributionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListDistributionsResult, ListDistributionsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudFrontClient, input: ListDistributionsRequest| async move {
+                client.list_distributions(input).await
+            },
+            |input: &ListDistributionsRequest, output: &ListDistributionsResult| {
+                if !output
+                    .distribution_list
+                    .as_ref()
+                    .map(|v| v.is_truncated)
+                    .unwrap_or(false)
+                {
+                    return None;
+                }
+                Some(ListDistributionsRequest {
+                    marker: Some(
+                        output
+                            .distribution_list
+                            .as_ref()
+                            .and_then(|v| v.next_marker.clone())?,
+                    ),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_distributions`, streaming the `DistributionList.Items` of every page of results.
+    pub fn list_distributions_items(
+        &self,
+        input: ListDistributionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DistributionSummary, ListDistributionsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_distributions_pages(input),
+            |output: ListDistributionsResult| {
+                output
+                    .distribution_list
+                    .and_then(|v| v.items)
+                    .unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_invalidations`, streaming every page of results.
+    pub fn list_invalidations_pages(
+        &self,
+        input: ListInvalidationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListInvalidationsResult, ListInvalidationsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudFrontClient, input: ListInvalidationsRequest| async move {
+                client.list_invalidations(input).await
+            },
+            |input: &ListInvalidationsRequest, output: &ListInvalidationsResult| {
+                if !output
+                    .invalidation_list
+                    .as_ref()
+                    .map(|v| v.is_truncated)
+                    .unwrap_or(false)
+                {
+                    return None;
+                }
+                Some(ListInvalidationsRequest {
+                    marker: Some(
+                        output
+                            .invalidation_list
+                            .as_ref()
+                            .and_then(|v| v.next_marker.clone())?,
+                    ),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_invalidations`, streaming the `InvalidationList.Items` of every page of results.
+    pub fn list_invalidations_items(
+        &self,
+        input: ListInvalidationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<InvalidationSummary, ListInvalidationsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_invalidations_pages(input),
+            |output: ListInvalidationsResult| {
+                output
+                    .invalidation_list
+                    .and_then(|v| v.items)
+                    .unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_streaming_distributions`, streaming every page of results.
+    pub fn list_streaming_distributions_pages(
+        &self,
+        input: ListStreamingDistributionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListStreamingDistributionsResult,
+        ListStreamingDistributionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudFrontClient, input: ListStreamingDistributionsRequest| async move {
+                client.list_streaming_distributions(input).await
+            },
+            |input: &ListStreamingDistributionsRequest,
+             output: &ListStreamingDistributionsResult| {
+                if !output
+                    .streaming_distribution_list
+                    .as_ref()
+                    .map(|v| v.is_truncated)
+                    .unwrap_or(false)
+                {
+                    return None;
+                }
+                Some(ListStreamingDistributionsRequest {
+                    marker: Some(
+                        output
+                            .streaming_distribution_list
+                            .as_ref()
+                            .and_then(|v| v.next_marker.clone())?,
+                    ),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_streaming_distributions`, streaming the `StreamingDistributionList.Items` of every page of results.
+    pub fn list_streaming_distributions_items(
+        &self,
+        input: ListStreamingDistributionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        StreamingDistributionSummary,
+        ListStreamingDistributionsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_streaming_distributions_pages(input),
+            |output: ListStreamingDistributionsResult| {
+                output
+                    .streaming_distribution_list
+                    .and_then(|v| v.items)
+                    .unwrap_or_default()
+            },
+        )
+    }
+}
+impl CloudFrontClient {
+    /// Waits until `get_distribution` reports the `DistributionDeployed` state, polling every 60 seconds up to 35 times.
+    pub async fn wait_until_distribution_deployed(
+        &self,
+        input: GetDistributionRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetDistributionError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(60), 35);
+        self.wait_until_distribution_deployed_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_distribution_deployed`, polling as often and as many times as `config` says.
+    pub async fn wait_until_distribution_deployed_with_config(
+        &self,
+        input: GetDistributionRequest,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetDistributionError>> {
+        rusoto_core::waiter::wait(
+            "DistributionDeployed",
+            &config,
+            || self.get_distribution(input.clone()),
+            |result: &Result<GetDistributionResult, RusotoError<GetDistributionError>>| match result
+            {
+                Ok(output)
+                    if output
+                        .distribution
+                        .iter()
+                        .map(|v| &v.status)
+                        .any(|v| v == "Deployed") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+
+    /// Waits until `get_invalidation` reports the `InvalidationCompleted` state, polling every 20 seconds up to 30 times.
+    pub async fn wait_until_invalidation_completed(
+        &self,
+        input: GetInvalidationRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetInvalidationError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(20), 30);
+        self.wait_until_invalidation_completed_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_invalidation_completed`, polling as often and as many times as `config` says.
+    pub async fn wait_until_invalidation_completed_with_config(
+        &self,
+        input: GetInvalidationRequest,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetInvalidationError>> {
+        rusoto_core::waiter::wait(
+            "InvalidationCompleted",
+            &config,
+            || self.get_invalidation(input.clone()),
+            |result: &Result<GetInvalidationResult, RusotoError<GetInvalidationError>>| match result
+            {
+                Ok(output)
+                    if output
+                        .invalidation
+                        .iter()
+                        .map(|v| &v.status)
+                        .any(|v| v == "Completed") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+
+    /// Waits until `get_streaming_distribution` reports the `StreamingDistributionDeployed` state, polling every 60 seconds up to 25 times.
+    pub async fn wait_until_streaming_distribution_deployed(
+        &self,
+        input: GetStreamingDistributionRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetStreamingDistributionError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(60), 25);
+        self.wait_until_streaming_distribution_deployed_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_streaming_distribution_deployed`, polling as often and as many times as `config` says.
+    pub async fn wait_until_streaming_distribution_deployed_with_config(
+        &self,
+        input: GetStreamingDistributionRequest,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetStreamingDistributionError>> {
+        rusoto_core::waiter::wait(
+            "StreamingDistributionDeployed",
+            &config,
+            || self.get_streaming_distribution(input.clone()),
+            |result: &Result<
+                GetStreamingDistributionResult,
+                RusotoError<GetStreamingDistributionError>,
+            >| match result {
+                Ok(output)
+                    if output
+                        .streaming_distribution
+                        .iter()
+                        .map(|v| &v.status)
+                        .any(|v| v == "Deployed") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+}
 
 #[cfg(test)]
 mod protocol_tests {
@@ -43,6 +43,197 @@ pub struct AddTagsToResourceResponse {
     pub status: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ClientVersion {
+    /// `5.1`
+    V5_1,
+    /// `5.3`
+    V5_3,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ClientVersion {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ClientVersion::V5_1 => "5.1",
+            ClientVersion::V5_3 => "5.3",
+            ClientVersion::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ClientVersion {
+    fn default() -> ClientVersion {
+        ClientVersion::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ClientVersion {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ClientVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ClientVersion {
+    fn from(value: &'a str) -> ClientVersion {
+        match value {
+            "5.1" => ClientVersion::V5_1,
+            "5.3" => ClientVersion::V5_3,
+            _ => ClientVersion::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ClientVersion {
+    fn from(value: String) -> ClientVersion {
+        match value.as_str() {
+            "5.1" => ClientVersion::V5_1,
+            "5.3" => ClientVersion::V5_3,
+            _ => ClientVersion::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ClientVersion {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ClientVersion, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ClientVersion {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ClientVersion {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ClientVersion {
+    fn deserialize<D>(deserializer: D) -> Result<ClientVersion, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CloudHsmObjectState {
+    /// `READY`
+    Ready,
+    /// `UPDATING`
+    Updating,
+    /// `DEGRADED`
+    Degraded,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CloudHsmObjectState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CloudHsmObjectState::Ready => "READY",
+            CloudHsmObjectState::Updating => "UPDATING",
+            CloudHsmObjectState::Degraded => "DEGRADED",
+            CloudHsmObjectState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CloudHsmObjectState {
+    fn default() -> CloudHsmObjectState {
+        CloudHsmObjectState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CloudHsmObjectState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CloudHsmObjectState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CloudHsmObjectState {
+    fn from(value: &'a str) -> CloudHsmObjectState {
+        match value {
+            "READY" => CloudHsmObjectState::Ready,
+            "UPDATING" => CloudHsmObjectState::Updating,
+            "DEGRADED" => CloudHsmObjectState::Degraded,
+            _ => CloudHsmObjectState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CloudHsmObjectState {
+    fn from(value: String) -> CloudHsmObjectState {
+        match value.as_str() {
+            "READY" => CloudHsmObjectState::Ready,
+            "UPDATING" => CloudHsmObjectState::Updating,
+            "DEGRADED" => CloudHsmObjectState::Degraded,
+            _ => CloudHsmObjectState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CloudHsmObjectState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CloudHsmObjectState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CloudHsmObjectState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CloudHsmObjectState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CloudHsmObjectState {
+    fn deserialize<D>(deserializer: D) -> Result<CloudHsmObjectState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Contains the inputs for the <a>CreateHapgRequest</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -88,7 +279,7 @@ pub struct CreateHsmRequest {
     #[serde(rename = "SubnetId")]
     pub subnet_id: String,
     #[serde(rename = "SubscriptionType")]
-    pub subscription_type: String,
+    pub subscription_type: SubscriptionType,
     /// <p>The IP address for the syslog monitoring server. The AWS CloudHSM service only supports one syslog monitoring server.</p>
     #[serde(rename = "SyslogIp")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -228,7 +419,7 @@ pub struct DescribeHapgResponse {
     /// <p>The state of the high-availability partition group.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<CloudHsmObjectState>,
 }
 
 /// <p>Contains the inputs for the <a>DescribeHsm</a> operation.</p>
@@ -304,7 +495,7 @@ pub struct DescribeHsmResponse {
     /// <p>The status of the HSM.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<HsmStatus>,
     /// <p>Contains additional information about the status of the HSM.</p>
     #[serde(rename = "StatusDetails")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -323,7 +514,7 @@ pub struct DescribeHsmResponse {
     pub subscription_start_date: Option<String>,
     #[serde(rename = "SubscriptionType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub subscription_type: Option<String>,
+    pub subscription_type: Option<SubscriptionType>,
     /// <p>The name of the HSM vendor.</p>
     #[serde(rename = "VendorName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -380,7 +571,7 @@ pub struct GetConfigRequest {
     pub client_arn: String,
     /// <p>The client version.</p>
     #[serde(rename = "ClientVersion")]
-    pub client_version: String,
+    pub client_version: ClientVersion,
     /// <p>A list of ARNs that identify the high-availability partition groups that are associated with the client.</p>
     #[serde(rename = "HapgList")]
     pub hapg_list: Vec<String>,
@@ -403,6 +594,124 @@ pub struct GetConfigResponse {
     pub config_type: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum HsmStatus {
+    /// `PENDING`
+    Pending,
+    /// `RUNNING`
+    Running,
+    /// `UPDATING`
+    Updating,
+    /// `SUSPENDED`
+    Suspended,
+    /// `TERMINATING`
+    Terminating,
+    /// `TERMINATED`
+    Terminated,
+    /// `DEGRADED`
+    Degraded,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl HsmStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            HsmStatus::Pending => "PENDING",
+            HsmStatus::Running => "RUNNING",
+            HsmStatus::Updating => "UPDATING",
+            HsmStatus::Suspended => "SUSPENDED",
+            HsmStatus::Terminating => "TERMINATING",
+            HsmStatus::Terminated => "TERMINATED",
+            HsmStatus::Degraded => "DEGRADED",
+            HsmStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for HsmStatus {
+    fn default() -> HsmStatus {
+        HsmStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for HsmStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for HsmStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for HsmStatus {
+    fn from(value: &'a str) -> HsmStatus {
+        match value {
+            "PENDING" => HsmStatus::Pending,
+            "RUNNING" => HsmStatus::Running,
+            "UPDATING" => HsmStatus::Updating,
+            "SUSPENDED" => HsmStatus::Suspended,
+            "TERMINATING" => HsmStatus::Terminating,
+            "TERMINATED" => HsmStatus::Terminated,
+            "DEGRADED" => HsmStatus::Degraded,
+            _ => HsmStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for HsmStatus {
+    fn from(value: String) -> HsmStatus {
+        match value.as_str() {
+            "PENDING" => HsmStatus::Pending,
+            "RUNNING" => HsmStatus::Running,
+            "UPDATING" => HsmStatus::Updating,
+            "SUSPENDED" => HsmStatus::Suspended,
+            "TERMINATING" => HsmStatus::Terminating,
+            "TERMINATED" => HsmStatus::Terminated,
+            "DEGRADED" => HsmStatus::Degraded,
+            _ => HsmStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for HsmStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<HsmStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for HsmStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for HsmStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for HsmStatus {
+    fn deserialize<D>(deserializer: D) -> Result<HsmStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Contains the inputs for the <a>ListAvailableZones</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -601,6 +910,95 @@ pub struct RemoveTagsFromResourceResponse {
     pub status: String,
 }
 
+/// <p>Specifies the type of subscription for the HSM.</p> <ul> <li> <p> <b>PRODUCTION</b> - The HSM is being used in a production environment.</p> </li> <li> <p> <b>TRIAL</b> - The HSM is being used in a product trial.</p> </li> </ul>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SubscriptionType {
+    /// `PRODUCTION`
+    Production,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SubscriptionType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SubscriptionType::Production => "PRODUCTION",
+            SubscriptionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SubscriptionType {
+    fn default() -> SubscriptionType {
+        SubscriptionType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SubscriptionType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SubscriptionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SubscriptionType {
+    fn from(value: &'a str) -> SubscriptionType {
+        match value {
+            "PRODUCTION" => SubscriptionType::Production,
+            _ => SubscriptionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SubscriptionType {
+    fn from(value: String) -> SubscriptionType {
+        match value.as_str() {
+            "PRODUCTION" => SubscriptionType::Production,
+            _ => SubscriptionType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SubscriptionType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SubscriptionType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SubscriptionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SubscriptionType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SubscriptionType {
+    fn deserialize<D>(deserializer: D) -> Result<SubscriptionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A key-value pair that identifies or specifies metadata about an AWS CloudHSM resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Tag {
@@ -1580,11 +1978,12 @@ pub struct CloudHsmClient {
 impl CloudHsmClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> CloudHsmClient {
         CloudHsmClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "CloudHSM"),
         }
     }
 
@@ -1616,6 +2015,7 @@ impl CloudHsm for CloudHsmClient {
         input: AddTagsToResourceRequest,
     ) -> Result<AddTagsToResourceResponse, RusotoError<AddTagsToResourceError>> {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("AddTagsToResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CloudHsmFrontendService.AddTagsToResource");
@@ -1644,6 +2044,7 @@ impl CloudHsm for CloudHsmClient {
         input: CreateHapgRequest,
     ) -> Result<CreateHapgResponse, RusotoError<CreateHapgError>> {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("CreateHapg");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CloudHsmFrontendService.CreateHapg");
@@ -1671,6 +2072,7 @@ impl CloudHsm for CloudHsmClient {
         input: CreateHsmRequest,
     ) -> Result<CreateHsmResponse, RusotoError<CreateHsmError>> {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("CreateHsm");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CloudHsmFrontendService.CreateHsm");
@@ -1698,6 +2100,7 @@ impl CloudHsm for CloudHsmClient {
         input: CreateLunaClientRequest,
     ) -> Result<CreateLunaClientResponse, RusotoError<CreateLunaClientError>> {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("CreateLunaClient");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CloudHsmFrontendService.CreateLunaClient");
@@ -1726,6 +2129,7 @@ impl CloudHsm for CloudHsmClient {
         input: DeleteHapgRequest,
     ) -> Result<DeleteHapgResponse, RusotoError<DeleteHapgError>> {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("DeleteHapg");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CloudHsmFrontendService.DeleteHapg");
@@ -1753,6 +2157,7 @@ impl CloudHsm for CloudHsmClient {
         input: DeleteHsmRequest,
     ) -> Result<DeleteHsmResponse, RusotoError<DeleteHsmError>> {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("DeleteHsm");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CloudHsmFrontendService.DeleteHsm");
@@ -1780,6 +2185,7 @@ impl CloudHsm for CloudHsmClient {
         input: DeleteLunaClientRequest,
     ) -> Result<DeleteLunaClientResponse, RusotoError<DeleteLunaClientError>> {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("DeleteLunaClient");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CloudHsmFrontendService.DeleteLunaClient");
@@ -1808,6 +2214,7 @@ impl CloudHsm for CloudHsmClient {
         input: DescribeHapgRequest,
     ) -> Result<DescribeHapgResponse, RusotoError<DescribeHapgError>> {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("DescribeHapg");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CloudHsmFrontendService.DescribeHapg");
@@ -1835,6 +2242,7 @@ impl CloudHsm for CloudHsmClient {
         input: DescribeHsmRequest,
     ) -> Result<DescribeHsmResponse, RusotoError<DescribeHsmError>> {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("DescribeHsm");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CloudHsmFrontendService.DescribeHsm");
@@ -1862,6 +2270,7 @@ impl CloudHsm for CloudHsmClient {
         input: DescribeLunaClientRequest,
     ) -> Result<DescribeLunaClientResponse, RusotoError<DescribeLunaClientError>> {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("DescribeLunaClient");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CloudHsmFrontendService.DescribeLunaClient");
@@ -1890,6 +2299,7 @@ impl CloudHsm for CloudHsmClient {
         input: GetConfigRequest,
     ) -> Result<GetConfigResponse, RusotoError<GetConfigError>> {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("GetConfig");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CloudHsmFrontendService.GetConfig");
@@ -1916,6 +2326,7 @@ impl CloudHsm for CloudHsmClient {
         &self,
     ) -> Result<ListAvailableZonesResponse, RusotoError<ListAvailableZonesError>> {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("ListAvailableZones");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CloudHsmFrontendService.ListAvailableZones");
@@ -1943,6 +2354,7 @@ impl CloudHsm for CloudHsmClient {
         input: ListHapgsRequest,
     ) -> Result<ListHapgsResponse, RusotoError<ListHapgsError>> {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("ListHapgs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CloudHsmFrontendService.ListHapgs");
@@ -1970,6 +2382,7 @@ impl CloudHsm for CloudHsmClient {
         input: ListHsmsRequest,
     ) -> Result<ListHsmsResponse, RusotoError<ListHsmsError>> {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("ListHsms");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CloudHsmFrontendService.ListHsms");
@@ -1997,6 +2410,7 @@ impl CloudHsm for CloudHsmClient {
         input: ListLunaClientsRequest,
     ) -> Result<ListLunaClientsResponse, RusotoError<ListLunaClientsError>> {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("ListLunaClients");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CloudHsmFrontendService.ListLunaClients");
@@ -2024,6 +2438,7 @@ impl CloudHsm for CloudHsmClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2055,6 +2470,7 @@ impl CloudHsm for CloudHsmClient {
         input: ModifyHapgRequest,
     ) -> Result<ModifyHapgResponse, RusotoError<ModifyHapgError>> {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("ModifyHapg");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CloudHsmFrontendService.ModifyHapg");
@@ -2082,6 +2498,7 @@ impl CloudHsm for CloudHsmClient {
         input: ModifyHsmRequest,
     ) -> Result<ModifyHsmResponse, RusotoError<ModifyHsmError>> {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("ModifyHsm");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CloudHsmFrontendService.ModifyHsm");
@@ -2109,6 +2526,7 @@ impl CloudHsm for CloudHsmClient {
         input: ModifyLunaClientRequest,
     ) -> Result<ModifyLunaClientResponse, RusotoError<ModifyLunaClientError>> {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("ModifyLunaClient");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CloudHsmFrontendService.ModifyLunaClient");
@@ -2137,6 +2555,7 @@ impl CloudHsm for CloudHsmClient {
         input: RemoveTagsFromResourceRequest,
     ) -> Result<RemoveTagsFromResourceResponse, RusotoError<RemoveTagsFromResourceError>> {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("RemoveTagsFromResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2162,3 +2581,94 @@ impl CloudHsm for CloudHsmClient {
         }
     }
 }
+impl CloudHsmClient {
+    /// Auto-paginating version of `list_hapgs`, streaming every page of results.
+    pub fn list_hapgs_pages(
+        &self,
+        input: ListHapgsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListHapgsResponse, ListHapgsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudHsmClient, input: ListHapgsRequest| async move {
+                client.list_hapgs(input).await
+            },
+            |_input: &ListHapgsRequest, output: &ListHapgsResponse| {
+                Some(ListHapgsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_hapgs`, streaming the `HapgList` of every page of results.
+    pub fn list_hapgs_items(
+        &self,
+        input: ListHapgsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListHapgsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_hapgs_pages(input),
+            |output: ListHapgsResponse| output.hapg_list,
+        )
+    }
+
+    /// Auto-paginating version of `list_hsms`, streaming every page of results.
+    pub fn list_hsms_pages(
+        &self,
+        input: ListHsmsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListHsmsResponse, ListHsmsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudHsmClient, input: ListHsmsRequest| async move {
+                client.list_hsms(input).await
+            },
+            |_input: &ListHsmsRequest, output: &ListHsmsResponse| {
+                Some(ListHsmsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_hsms`, streaming the `HsmList` of every page of results.
+    pub fn list_hsms_items(
+        &self,
+        input: ListHsmsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListHsmsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_hsms_pages(input),
+            |output: ListHsmsResponse| output.hsm_list.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_luna_clients`, streaming every page of results.
+    pub fn list_luna_clients_pages(
+        &self,
+        input: ListLunaClientsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListLunaClientsResponse, ListLunaClientsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudHsmClient, input: ListLunaClientsRequest| async move {
+                client.list_luna_clients(input).await
+            },
+            |_input: &ListLunaClientsRequest, output: &ListLunaClientsResponse| {
+                Some(ListLunaClientsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_luna_clients`, streaming the `ClientList` of every page of results.
+    pub fn list_luna_clients_items(
+        &self,
+        input: ListLunaClientsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListLunaClientsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_luna_clients_pages(input),
+            |output: ListLunaClientsResponse| output.client_list,
+        )
+    }
+}
@@ -34,23 +34,38 @@ pub struct Backup {
     /// <p>The state of the backup.</p>
     #[serde(rename = "BackupState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub backup_state: Option<String>,
+    pub backup_state: Option<BackupState>,
     /// <p>The identifier (ID) of the cluster that was backed up.</p>
     #[serde(rename = "ClusterId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cluster_id: Option<String>,
     /// <p>The date and time when the backup was copied from a source backup.</p>
     #[serde(rename = "CopyTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub copy_timestamp: Option<f64>,
+    pub copy_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The date and time when the backup was created.</p>
     #[serde(rename = "CreateTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub create_timestamp: Option<f64>,
+    pub create_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The date and time when the backup will be permanently deleted.</p>
     #[serde(rename = "DeleteTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub delete_timestamp: Option<f64>,
+    pub delete_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The identifier (ID) of the source backup from which the new backup was copied.</p>
     #[serde(rename = "SourceBackup")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -68,6 +83,197 @@ pub struct Backup {
     pub tag_list: Option<Vec<Tag>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BackupPolicy {
+    /// `DEFAULT`
+    Default,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl BackupPolicy {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BackupPolicy::Default => "DEFAULT",
+            BackupPolicy::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for BackupPolicy {
+    fn default() -> BackupPolicy {
+        BackupPolicy::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for BackupPolicy {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for BackupPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for BackupPolicy {
+    fn from(value: &'a str) -> BackupPolicy {
+        match value {
+            "DEFAULT" => BackupPolicy::Default,
+            _ => BackupPolicy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BackupPolicy {
+    fn from(value: String) -> BackupPolicy {
+        match value.as_str() {
+            "DEFAULT" => BackupPolicy::Default,
+            _ => BackupPolicy::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for BackupPolicy {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<BackupPolicy, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for BackupPolicy {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for BackupPolicy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BackupPolicy {
+    fn deserialize<D>(deserializer: D) -> Result<BackupPolicy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BackupState {
+    /// `CREATE_IN_PROGRESS`
+    CreateInProgress,
+    /// `READY`
+    Ready,
+    /// `DELETED`
+    Deleted,
+    /// `PENDING_DELETION`
+    PendingDeletion,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl BackupState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BackupState::CreateInProgress => "CREATE_IN_PROGRESS",
+            BackupState::Ready => "READY",
+            BackupState::Deleted => "DELETED",
+            BackupState::PendingDeletion => "PENDING_DELETION",
+            BackupState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for BackupState {
+    fn default() -> BackupState {
+        BackupState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for BackupState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for BackupState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for BackupState {
+    fn from(value: &'a str) -> BackupState {
+        match value {
+            "CREATE_IN_PROGRESS" => BackupState::CreateInProgress,
+            "READY" => BackupState::Ready,
+            "DELETED" => BackupState::Deleted,
+            "PENDING_DELETION" => BackupState::PendingDeletion,
+            _ => BackupState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BackupState {
+    fn from(value: String) -> BackupState {
+        match value.as_str() {
+            "CREATE_IN_PROGRESS" => BackupState::CreateInProgress,
+            "READY" => BackupState::Ready,
+            "DELETED" => BackupState::Deleted,
+            "PENDING_DELETION" => BackupState::PendingDeletion,
+            _ => BackupState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for BackupState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<BackupState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for BackupState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for BackupState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BackupState {
+    fn deserialize<D>(deserializer: D) -> Result<BackupState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Contains one or more certificates or a certificate signing request (CSR).</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -101,7 +307,7 @@ pub struct Cluster {
     /// <p>The cluster's backup policy.</p>
     #[serde(rename = "BackupPolicy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub backup_policy: Option<String>,
+    pub backup_policy: Option<BackupPolicy>,
     /// <p>Contains one or more certificates or a certificate signing request (CSR).</p>
     #[serde(rename = "Certificates")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -112,8 +318,13 @@ pub struct Cluster {
     pub cluster_id: Option<String>,
     /// <p>The date and time when the cluster was created.</p>
     #[serde(rename = "CreateTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub create_timestamp: Option<f64>,
+    pub create_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The type of HSM that the cluster contains.</p>
     #[serde(rename = "HsmType")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -137,7 +348,7 @@ pub struct Cluster {
     /// <p>The cluster's state.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<ClusterState>,
     /// <p>A description of the cluster's state.</p>
     #[serde(rename = "StateMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -155,6 +366,134 @@ pub struct Cluster {
     pub vpc_id: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ClusterState {
+    /// `CREATE_IN_PROGRESS`
+    CreateInProgress,
+    /// `UNINITIALIZED`
+    Uninitialized,
+    /// `INITIALIZE_IN_PROGRESS`
+    InitializeInProgress,
+    /// `INITIALIZED`
+    Initialized,
+    /// `ACTIVE`
+    Active,
+    /// `UPDATE_IN_PROGRESS`
+    UpdateInProgress,
+    /// `DELETE_IN_PROGRESS`
+    DeleteInProgress,
+    /// `DELETED`
+    Deleted,
+    /// `DEGRADED`
+    Degraded,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ClusterState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ClusterState::CreateInProgress => "CREATE_IN_PROGRESS",
+            ClusterState::Uninitialized => "UNINITIALIZED",
+            ClusterState::InitializeInProgress => "INITIALIZE_IN_PROGRESS",
+            ClusterState::Initialized => "INITIALIZED",
+            ClusterState::Active => "ACTIVE",
+            ClusterState::UpdateInProgress => "UPDATE_IN_PROGRESS",
+            ClusterState::DeleteInProgress => "DELETE_IN_PROGRESS",
+            ClusterState::Deleted => "DELETED",
+            ClusterState::Degraded => "DEGRADED",
+            ClusterState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ClusterState {
+    fn default() -> ClusterState {
+        ClusterState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ClusterState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ClusterState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ClusterState {
+    fn from(value: &'a str) -> ClusterState {
+        match value {
+            "CREATE_IN_PROGRESS" => ClusterState::CreateInProgress,
+            "UNINITIALIZED" => ClusterState::Uninitialized,
+            "INITIALIZE_IN_PROGRESS" => ClusterState::InitializeInProgress,
+            "INITIALIZED" => ClusterState::Initialized,
+            "ACTIVE" => ClusterState::Active,
+            "UPDATE_IN_PROGRESS" => ClusterState::UpdateInProgress,
+            "DELETE_IN_PROGRESS" => ClusterState::DeleteInProgress,
+            "DELETED" => ClusterState::Deleted,
+            "DEGRADED" => ClusterState::Degraded,
+            _ => ClusterState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ClusterState {
+    fn from(value: String) -> ClusterState {
+        match value.as_str() {
+            "CREATE_IN_PROGRESS" => ClusterState::CreateInProgress,
+            "UNINITIALIZED" => ClusterState::Uninitialized,
+            "INITIALIZE_IN_PROGRESS" => ClusterState::InitializeInProgress,
+            "INITIALIZED" => ClusterState::Initialized,
+            "ACTIVE" => ClusterState::Active,
+            "UPDATE_IN_PROGRESS" => ClusterState::UpdateInProgress,
+            "DELETE_IN_PROGRESS" => ClusterState::DeleteInProgress,
+            "DELETED" => ClusterState::Deleted,
+            "DEGRADED" => ClusterState::Degraded,
+            _ => ClusterState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ClusterState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ClusterState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ClusterState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ClusterState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ClusterState {
+    fn deserialize<D>(deserializer: D) -> Result<ClusterState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CopyBackupToRegionRequest {
@@ -362,8 +701,13 @@ pub struct DescribeClustersResponse {
 pub struct DestinationBackup {
     /// <p>The date and time when both the source backup was created.</p>
     #[serde(rename = "CreateTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub create_timestamp: Option<f64>,
+    pub create_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The identifier (ID) of the source backup from which the new backup was copied.</p>
     #[serde(rename = "SourceBackup")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -404,7 +748,7 @@ pub struct Hsm {
     /// <p>The HSM's state.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<HsmState>,
     /// <p>A description of the HSM's state.</p>
     #[serde(rename = "StateMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -415,6 +759,114 @@ pub struct Hsm {
     pub subnet_id: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum HsmState {
+    /// `CREATE_IN_PROGRESS`
+    CreateInProgress,
+    /// `ACTIVE`
+    Active,
+    /// `DEGRADED`
+    Degraded,
+    /// `DELETE_IN_PROGRESS`
+    DeleteInProgress,
+    /// `DELETED`
+    Deleted,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl HsmState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            HsmState::CreateInProgress => "CREATE_IN_PROGRESS",
+            HsmState::Active => "ACTIVE",
+            HsmState::Degraded => "DEGRADED",
+            HsmState::DeleteInProgress => "DELETE_IN_PROGRESS",
+            HsmState::Deleted => "DELETED",
+            HsmState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for HsmState {
+    fn default() -> HsmState {
+        HsmState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for HsmState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for HsmState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for HsmState {
+    fn from(value: &'a str) -> HsmState {
+        match value {
+            "CREATE_IN_PROGRESS" => HsmState::CreateInProgress,
+            "ACTIVE" => HsmState::Active,
+            "DEGRADED" => HsmState::Degraded,
+            "DELETE_IN_PROGRESS" => HsmState::DeleteInProgress,
+            "DELETED" => HsmState::Deleted,
+            _ => HsmState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for HsmState {
+    fn from(value: String) -> HsmState {
+        match value.as_str() {
+            "CREATE_IN_PROGRESS" => HsmState::CreateInProgress,
+            "ACTIVE" => HsmState::Active,
+            "DEGRADED" => HsmState::Degraded,
+            "DELETE_IN_PROGRESS" => HsmState::DeleteInProgress,
+            "DELETED" => HsmState::Deleted,
+            _ => HsmState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for HsmState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<HsmState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for HsmState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for HsmState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for HsmState {
+    fn deserialize<D>(deserializer: D) -> Result<HsmState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct InitializeClusterRequest {
@@ -435,7 +887,7 @@ pub struct InitializeClusterResponse {
     /// <p>The cluster's state.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<ClusterState>,
     /// <p>A description of the cluster's state.</p>
     #[serde(rename = "StateMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1423,11 +1875,12 @@ pub struct CloudHsmv2Client {
 impl CloudHsmv2Client {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> CloudHsmv2Client {
         CloudHsmv2Client {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "CloudHSM V2"),
         }
     }
 
@@ -1459,6 +1912,7 @@ impl CloudHsmv2 for CloudHsmv2Client {
         input: CopyBackupToRegionRequest,
     ) -> Result<CopyBackupToRegionResponse, RusotoError<CopyBackupToRegionError>> {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("CopyBackupToRegion");
         request.set_endpoint_prefix("cloudhsmv2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "BaldrApiService.CopyBackupToRegion");
@@ -1487,6 +1941,7 @@ impl CloudHsmv2 for CloudHsmv2Client {
         input: CreateClusterRequest,
     ) -> Result<CreateClusterResponse, RusotoError<CreateClusterError>> {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("CreateCluster");
         request.set_endpoint_prefix("cloudhsmv2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "BaldrApiService.CreateCluster");
@@ -1514,6 +1969,7 @@ impl CloudHsmv2 for CloudHsmv2Client {
         input: CreateHsmRequest,
     ) -> Result<CreateHsmResponse, RusotoError<CreateHsmError>> {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("CreateHsm");
         request.set_endpoint_prefix("cloudhsmv2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "BaldrApiService.CreateHsm");
@@ -1541,6 +1997,7 @@ impl CloudHsmv2 for CloudHsmv2Client {
         input: DeleteBackupRequest,
     ) -> Result<DeleteBackupResponse, RusotoError<DeleteBackupError>> {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("DeleteBackup");
         request.set_endpoint_prefix("cloudhsmv2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "BaldrApiService.DeleteBackup");
@@ -1568,6 +2025,7 @@ impl CloudHsmv2 for CloudHsmv2Client {
         input: DeleteClusterRequest,
     ) -> Result<DeleteClusterResponse, RusotoError<DeleteClusterError>> {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("DeleteCluster");
         request.set_endpoint_prefix("cloudhsmv2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "BaldrApiService.DeleteCluster");
@@ -1595,6 +2053,7 @@ impl CloudHsmv2 for CloudHsmv2Client {
         input: DeleteHsmRequest,
     ) -> Result<DeleteHsmResponse, RusotoError<DeleteHsmError>> {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("DeleteHsm");
         request.set_endpoint_prefix("cloudhsmv2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "BaldrApiService.DeleteHsm");
@@ -1622,6 +2081,7 @@ impl CloudHsmv2 for CloudHsmv2Client {
         input: DescribeBackupsRequest,
     ) -> Result<DescribeBackupsResponse, RusotoError<DescribeBackupsError>> {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("DescribeBackups");
         request.set_endpoint_prefix("cloudhsmv2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "BaldrApiService.DescribeBackups");
@@ -1649,6 +2109,7 @@ impl CloudHsmv2 for CloudHsmv2Client {
         input: DescribeClustersRequest,
     ) -> Result<DescribeClustersResponse, RusotoError<DescribeClustersError>> {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("DescribeClusters");
         request.set_endpoint_prefix("cloudhsmv2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "BaldrApiService.DescribeClusters");
@@ -1677,6 +2138,7 @@ impl CloudHsmv2 for CloudHsmv2Client {
         input: InitializeClusterRequest,
     ) -> Result<InitializeClusterResponse, RusotoError<InitializeClusterError>> {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("InitializeCluster");
         request.set_endpoint_prefix("cloudhsmv2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "BaldrApiService.InitializeCluster");
@@ -1705,6 +2167,7 @@ impl CloudHsmv2 for CloudHsmv2Client {
         input: ListTagsRequest,
     ) -> Result<ListTagsResponse, RusotoError<ListTagsError>> {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("ListTags");
         request.set_endpoint_prefix("cloudhsmv2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "BaldrApiService.ListTags");
@@ -1732,6 +2195,7 @@ impl CloudHsmv2 for CloudHsmv2Client {
         input: RestoreBackupRequest,
     ) -> Result<RestoreBackupResponse, RusotoError<RestoreBackupError>> {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("RestoreBackup");
         request.set_endpoint_prefix("cloudhsmv2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "BaldrApiService.RestoreBackup");
@@ -1759,6 +2223,7 @@ impl CloudHsmv2 for CloudHsmv2Client {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("TagResource");
         request.set_endpoint_prefix("cloudhsmv2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "BaldrApiService.TagResource");
@@ -1786,6 +2251,7 @@ impl CloudHsmv2 for CloudHsmv2Client {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("UntagResource");
         request.set_endpoint_prefix("cloudhsmv2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "BaldrApiService.UntagResource");
@@ -1807,3 +2273,98 @@ impl CloudHsmv2 for CloudHsmv2Client {
         }
     }
 }
+impl CloudHsmv2Client {
+    /// Auto-paginating version of `describe_backups`, streaming every page of results.
+    pub fn describe_backups_pages(
+        &self,
+        input: DescribeBackupsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeBackupsResponse, DescribeBackupsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudHsmv2Client, input: DescribeBackupsRequest| async move {
+                client.describe_backups(input).await
+            },
+            |input: &DescribeBackupsRequest, output: &DescribeBackupsResponse| {
+                Some(DescribeBackupsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_backups`, streaming the `Backups` of every page of results.
+    pub fn describe_backups_items(
+        &self,
+        input: DescribeBackupsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Backup, DescribeBackupsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_backups_pages(input),
+            |output: DescribeBackupsResponse| output.backups.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_clusters`, streaming every page of results.
+    pub fn describe_clusters_pages(
+        &self,
+        input: DescribeClustersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeClustersResponse, DescribeClustersError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudHsmv2Client, input: DescribeClustersRequest| async move {
+                client.describe_clusters(input).await
+            },
+            |input: &DescribeClustersRequest, output: &DescribeClustersResponse| {
+                Some(DescribeClustersRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_clusters`, streaming the `Clusters` of every page of results.
+    pub fn describe_clusters_items(
+        &self,
+        input: DescribeClustersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Cluster, DescribeClustersError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_clusters_pages(input),
+            |output: DescribeClustersResponse| output.clusters.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_tags`, streaming every page of results.
+    pub fn list_tags_pages(
+        &self,
+        input: ListTagsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListTagsResponse, ListTagsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudHsmv2Client, input: ListTagsRequest| async move {
+                client.list_tags(input).await
+            },
+            |input: &ListTagsRequest, output: &ListTagsResponse| {
+                Some(ListTagsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_tags`, streaming the `TagList` of every page of results.
+    pub fn list_tags_items(
+        &self,
+        input: ListTagsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Tag, ListTagsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_tags_pages(input),
+            |output: ListTagsResponse| output.tag_list,
+        )
+    }
+}
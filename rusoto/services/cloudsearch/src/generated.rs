@@ -83,12 +83,120 @@ impl AccessPoliciesStatusDeserializer {
         })
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AlgorithmicStemming {
+    /// `none`
+    None,
+    /// `minimal`
+    Minimal,
+    /// `light`
+    Light,
+    /// `full`
+    Full,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AlgorithmicStemming {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AlgorithmicStemming::None => "none",
+            AlgorithmicStemming::Minimal => "minimal",
+            AlgorithmicStemming::Light => "light",
+            AlgorithmicStemming::Full => "full",
+            AlgorithmicStemming::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AlgorithmicStemming {
+    fn default() -> AlgorithmicStemming {
+        AlgorithmicStemming::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AlgorithmicStemming {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AlgorithmicStemming {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AlgorithmicStemming {
+    fn from(value: &'a str) -> AlgorithmicStemming {
+        match value {
+            "none" => AlgorithmicStemming::None,
+            "minimal" => AlgorithmicStemming::Minimal,
+            "light" => AlgorithmicStemming::Light,
+            "full" => AlgorithmicStemming::Full,
+            _ => AlgorithmicStemming::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AlgorithmicStemming {
+    fn from(value: String) -> AlgorithmicStemming {
+        match value.as_str() {
+            "none" => AlgorithmicStemming::None,
+            "minimal" => AlgorithmicStemming::Minimal,
+            "light" => AlgorithmicStemming::Light,
+            "full" => AlgorithmicStemming::Full,
+            _ => AlgorithmicStemming::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AlgorithmicStemming {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AlgorithmicStemming, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AlgorithmicStemming {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for AlgorithmicStemming {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for AlgorithmicStemming {
+    fn deserialize<D>(deserializer: D) -> Result<AlgorithmicStemming, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct AlgorithmicStemmingDeserializer;
 impl AlgorithmicStemmingDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<AlgorithmicStemming, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -100,7 +208,7 @@ impl AlgorithmicStemmingDeserializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct AnalysisOptions {
     /// <p>The level of algorithmic stemming to perform: <code>none</code>, <code>minimal</code>, <code>light</code>, or <code>full</code>. The available levels vary depending on the language. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/text-processing.html#text-processing-settings" target="_blank">Language Specific Text Processing Settings</a> in the <i>Amazon CloudSearch Developer Guide</i> </p>
-    pub algorithmic_stemming: Option<String>,
+    pub algorithmic_stemming: Option<AlgorithmicStemming>,
     /// <p>A JSON array that contains a collection of terms, tokens, readings and part of speech for Japanese Tokenizaiton. The Japanese tokenization dictionary enables you to override the default tokenization for selected terms. This is only valid for Japanese language fields.</p>
     pub japanese_tokenization_dictionary: Option<String>,
     /// <p>A JSON object that contains a collection of string:value pairs that each map a term to its stem. For example, <code>{"term1": "stem1", "term2": "stem2", "term3": "stem3"}</code>. The stemming dictionary is applied in addition to any algorithmic stemming. This enables you to override the results of the algorithmic stemming to correct specific cases of overstemming or understemming. The maximum size of a stemming dictionary is 500 KB.</p>
@@ -190,7 +298,7 @@ impl AnalysisOptionsSerializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct AnalysisScheme {
     pub analysis_options: Option<AnalysisOptions>,
-    pub analysis_scheme_language: String,
+    pub analysis_scheme_language: AnalysisSchemeLanguage,
     pub analysis_scheme_name: String,
 }
 
@@ -253,12 +361,276 @@ impl AnalysisSchemeSerializer {
     }
 }
 
+/// <p>An <a href="http://tools.ietf.org/html/rfc4646" target="_blank">IETF RFC 4646</a> language code or <code>mul</code> for multiple languages.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AnalysisSchemeLanguage {
+    /// `ar`
+    Ar,
+    /// `bg`
+    Bg,
+    /// `ca`
+    Ca,
+    /// `cs`
+    Cs,
+    /// `da`
+    Da,
+    /// `de`
+    De,
+    /// `el`
+    El,
+    /// `en`
+    En,
+    /// `es`
+    Es,
+    /// `eu`
+    Eu,
+    /// `fa`
+    Fa,
+    /// `fi`
+    Fi,
+    /// `fr`
+    Fr,
+    /// `ga`
+    Ga,
+    /// `gl`
+    Gl,
+    /// `he`
+    He,
+    /// `hi`
+    Hi,
+    /// `hu`
+    Hu,
+    /// `hy`
+    Hy,
+    /// `id`
+    Id,
+    /// `it`
+    It,
+    /// `ja`
+    Ja,
+    /// `ko`
+    Ko,
+    /// `lv`
+    Lv,
+    /// `mul`
+    Mul,
+    /// `nl`
+    Nl,
+    /// `no`
+    No,
+    /// `pt`
+    Pt,
+    /// `ro`
+    Ro,
+    /// `ru`
+    Ru,
+    /// `sv`
+    Sv,
+    /// `th`
+    Th,
+    /// `tr`
+    Tr,
+    /// `zh-Hans`
+    ZhHans,
+    /// `zh-Hant`
+    ZhHant,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AnalysisSchemeLanguage {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AnalysisSchemeLanguage::Ar => "ar",
+            AnalysisSchemeLanguage::Bg => "bg",
+            AnalysisSchemeLanguage::Ca => "ca",
+            AnalysisSchemeLanguage::Cs => "cs",
+            AnalysisSchemeLanguage::Da => "da",
+            AnalysisSchemeLanguage::De => "de",
+            AnalysisSchemeLanguage::El => "el",
+            AnalysisSchemeLanguage::En => "en",
+            AnalysisSchemeLanguage::Es => "es",
+            AnalysisSchemeLanguage::Eu => "eu",
+            AnalysisSchemeLanguage::Fa => "fa",
+            AnalysisSchemeLanguage::Fi => "fi",
+            AnalysisSchemeLanguage::Fr => "fr",
+            AnalysisSchemeLanguage::Ga => "ga",
+            AnalysisSchemeLanguage::Gl => "gl",
+            AnalysisSchemeLanguage::He => "he",
+            AnalysisSchemeLanguage::Hi => "hi",
+            AnalysisSchemeLanguage::Hu => "hu",
+            AnalysisSchemeLanguage::Hy => "hy",
+            AnalysisSchemeLanguage::Id => "id",
+            AnalysisSchemeLanguage::It => "it",
+            AnalysisSchemeLanguage::Ja => "ja",
+            AnalysisSchemeLanguage::Ko => "ko",
+            AnalysisSchemeLanguage::Lv => "lv",
+            AnalysisSchemeLanguage::Mul => "mul",
+            AnalysisSchemeLanguage::Nl => "nl",
+            AnalysisSchemeLanguage::No => "no",
+            AnalysisSchemeLanguage::Pt => "pt",
+            AnalysisSchemeLanguage::Ro => "ro",
+            AnalysisSchemeLanguage::Ru => "ru",
+            AnalysisSchemeLanguage::Sv => "sv",
+            AnalysisSchemeLanguage::Th => "th",
+            AnalysisSchemeLanguage::Tr => "tr",
+            AnalysisSchemeLanguage::ZhHans => "zh-Hans",
+            AnalysisSchemeLanguage::ZhHant => "zh-Hant",
+            AnalysisSchemeLanguage::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AnalysisSchemeLanguage {
+    fn default() -> AnalysisSchemeLanguage {
+        AnalysisSchemeLanguage::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AnalysisSchemeLanguage {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AnalysisSchemeLanguage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AnalysisSchemeLanguage {
+    fn from(value: &'a str) -> AnalysisSchemeLanguage {
+        match value {
+            "ar" => AnalysisSchemeLanguage::Ar,
+            "bg" => AnalysisSchemeLanguage::Bg,
+            "ca" => AnalysisSchemeLanguage::Ca,
+            "cs" => AnalysisSchemeLanguage::Cs,
+            "da" => AnalysisSchemeLanguage::Da,
+            "de" => AnalysisSchemeLanguage::De,
+            "el" => AnalysisSchemeLanguage::El,
+            "en" => AnalysisSchemeLanguage::En,
+            "es" => AnalysisSchemeLanguage::Es,
+            "eu" => AnalysisSchemeLanguage::Eu,
+            "fa" => AnalysisSchemeLanguage::Fa,
+            "fi" => AnalysisSchemeLanguage::Fi,
+            "fr" => AnalysisSchemeLanguage::Fr,
+            "ga" => AnalysisSchemeLanguage::Ga,
+            "gl" => AnalysisSchemeLanguage::Gl,
+            "he" => AnalysisSchemeLanguage::He,
+            "hi" => AnalysisSchemeLanguage::Hi,
+            "hu" => AnalysisSchemeLanguage::Hu,
+            "hy" => AnalysisSchemeLanguage::Hy,
+            "id" => AnalysisSchemeLanguage::Id,
+            "it" => AnalysisSchemeLanguage::It,
+            "ja" => AnalysisSchemeLanguage::Ja,
+            "ko" => AnalysisSchemeLanguage::Ko,
+            "lv" => AnalysisSchemeLanguage::Lv,
+            "mul" => AnalysisSchemeLanguage::Mul,
+            "nl" => AnalysisSchemeLanguage::Nl,
+            "no" => AnalysisSchemeLanguage::No,
+            "pt" => AnalysisSchemeLanguage::Pt,
+            "ro" => AnalysisSchemeLanguage::Ro,
+            "ru" => AnalysisSchemeLanguage::Ru,
+            "sv" => AnalysisSchemeLanguage::Sv,
+            "th" => AnalysisSchemeLanguage::Th,
+            "tr" => AnalysisSchemeLanguage::Tr,
+            "zh-Hans" => AnalysisSchemeLanguage::ZhHans,
+            "zh-Hant" => AnalysisSchemeLanguage::ZhHant,
+            _ => AnalysisSchemeLanguage::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AnalysisSchemeLanguage {
+    fn from(value: String) -> AnalysisSchemeLanguage {
+        match value.as_str() {
+            "ar" => AnalysisSchemeLanguage::Ar,
+            "bg" => AnalysisSchemeLanguage::Bg,
+            "ca" => AnalysisSchemeLanguage::Ca,
+            "cs" => AnalysisSchemeLanguage::Cs,
+            "da" => AnalysisSchemeLanguage::Da,
+            "de" => AnalysisSchemeLanguage::De,
+            "el" => AnalysisSchemeLanguage::El,
+            "en" => AnalysisSchemeLanguage::En,
+            "es" => AnalysisSchemeLanguage::Es,
+            "eu" => AnalysisSchemeLanguage::Eu,
+            "fa" => AnalysisSchemeLanguage::Fa,
+            "fi" => AnalysisSchemeLanguage::Fi,
+            "fr" => AnalysisSchemeLanguage::Fr,
+            "ga" => AnalysisSchemeLanguage::Ga,
+            "gl" => AnalysisSchemeLanguage::Gl,
+            "he" => AnalysisSchemeLanguage::He,
+            "hi" => AnalysisSchemeLanguage::Hi,
+            "hu" => AnalysisSchemeLanguage::Hu,
+            "hy" => AnalysisSchemeLanguage::Hy,
+            "id" => AnalysisSchemeLanguage::Id,
+            "it" => AnalysisSchemeLanguage::It,
+            "ja" => AnalysisSchemeLanguage::Ja,
+            "ko" => AnalysisSchemeLanguage::Ko,
+            "lv" => AnalysisSchemeLanguage::Lv,
+            "mul" => AnalysisSchemeLanguage::Mul,
+            "nl" => AnalysisSchemeLanguage::Nl,
+            "no" => AnalysisSchemeLanguage::No,
+            "pt" => AnalysisSchemeLanguage::Pt,
+            "ro" => AnalysisSchemeLanguage::Ro,
+            "ru" => AnalysisSchemeLanguage::Ru,
+            "sv" => AnalysisSchemeLanguage::Sv,
+            "th" => AnalysisSchemeLanguage::Th,
+            "tr" => AnalysisSchemeLanguage::Tr,
+            "zh-Hans" => AnalysisSchemeLanguage::ZhHans,
+            "zh-Hant" => AnalysisSchemeLanguage::ZhHant,
+            _ => AnalysisSchemeLanguage::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AnalysisSchemeLanguage {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AnalysisSchemeLanguage, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AnalysisSchemeLanguage {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for AnalysisSchemeLanguage {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for AnalysisSchemeLanguage {
+    fn deserialize<D>(deserializer: D) -> Result<AnalysisSchemeLanguage, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct AnalysisSchemeLanguageDeserializer;
 impl AnalysisSchemeLanguageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<AnalysisSchemeLanguage, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -1701,7 +2073,7 @@ impl DescribeSuggestersResponseDeserializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct DocumentSuggesterOptions {
     /// <p>The level of fuzziness allowed when suggesting matches for a string: <code>none</code>, <code>low</code>, or <code>high</code>. With none, the specified string is treated as an exact prefix. With low, suggestions must differ from the specified string by no more than one character. With high, suggestions can differ by up to two characters. The default is none. </p>
-    pub fuzzy_matching: Option<String>,
+    pub fuzzy_matching: Option<SuggesterFuzzyMatching>,
     /// <p>An expression that computes a score for each suggestion to control how they are sorted. The scores are rounded to the nearest integer, with a floor of 0 and a ceiling of 2^31-1. A document's relevance score is not computed for suggestions, so sort expressions cannot reference the <code>_score</code> value. To sort suggestions using a numeric field or existing expression, simply specify the name of the field or expression. If no expression is configured for the suggester, the suggestions are sorted with the closest matches listed first.</p>
     pub sort_expression: Option<String>,
     /// <p>The name of the index field you want to use for suggestions. </p>
@@ -1769,7 +2141,7 @@ pub struct DomainEndpointOptions {
     /// <p>Whether the domain is HTTPS only enabled.</p>
     pub enforce_https: Option<bool>,
     /// <p>The minimum required TLS version</p>
-    pub tls_security_policy: Option<String>,
+    pub tls_security_policy: Option<TLSSecurityPolicy>,
 }
 
 struct DomainEndpointOptionsDeserializer;
@@ -2449,7 +2821,7 @@ pub struct IndexField {
     pub double_options: Option<DoubleOptions>,
     /// <p>A string that represents the name of an index field. CloudSearch supports regular index fields as well as dynamic fields. A dynamic field's name defines a pattern that begins or ends with a wildcard. Any document fields that don't map to a regular index field but do match a dynamic field's pattern are configured with the dynamic field's indexing options. </p> <p>Regular field names begin with a letter and can contain the following characters: a-z (lowercase), 0-9, and _ (underscore). Dynamic field names must begin or end with a wildcard (*). The wildcard can also be the only character in a dynamic field name. Multiple wildcards, and wildcards embedded within a string are not supported. </p> <p>The name <code>score</code> is reserved and cannot be used as a field name. To reference a document's ID, you can use the name <code>_id</code>. </p>
     pub index_field_name: String,
-    pub index_field_type: String,
+    pub index_field_type: IndexFieldType,
     pub int_array_options: Option<IntArrayOptions>,
     pub int_options: Option<IntOptions>,
     pub lat_lon_options: Option<LatLonOptions>,
@@ -2686,12 +3058,156 @@ impl IndexFieldStatusListDeserializer {
         })
     }
 }
+/// <p>The type of field. The valid options for a field depend on the field type. For more information about the supported field types, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/configuring-index-fields.html" target="_blank">Configuring Index Fields</a> in the <i>Amazon CloudSearch Developer Guide</i>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum IndexFieldType {
+    /// `int`
+    Int,
+    /// `double`
+    Double,
+    /// `literal`
+    Literal,
+    /// `text`
+    Text,
+    /// `date`
+    Date,
+    /// `latlon`
+    Latlon,
+    /// `int-array`
+    IntArray,
+    /// `double-array`
+    DoubleArray,
+    /// `literal-array`
+    LiteralArray,
+    /// `text-array`
+    TextArray,
+    /// `date-array`
+    DateArray,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl IndexFieldType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            IndexFieldType::Int => "int",
+            IndexFieldType::Double => "double",
+            IndexFieldType::Literal => "literal",
+            IndexFieldType::Text => "text",
+            IndexFieldType::Date => "date",
+            IndexFieldType::Latlon => "latlon",
+            IndexFieldType::IntArray => "int-array",
+            IndexFieldType::DoubleArray => "double-array",
+            IndexFieldType::LiteralArray => "literal-array",
+            IndexFieldType::TextArray => "text-array",
+            IndexFieldType::DateArray => "date-array",
+            IndexFieldType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for IndexFieldType {
+    fn default() -> IndexFieldType {
+        IndexFieldType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for IndexFieldType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for IndexFieldType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for IndexFieldType {
+    fn from(value: &'a str) -> IndexFieldType {
+        match value {
+            "int" => IndexFieldType::Int,
+            "double" => IndexFieldType::Double,
+            "literal" => IndexFieldType::Literal,
+            "text" => IndexFieldType::Text,
+            "date" => IndexFieldType::Date,
+            "latlon" => IndexFieldType::Latlon,
+            "int-array" => IndexFieldType::IntArray,
+            "double-array" => IndexFieldType::DoubleArray,
+            "literal-array" => IndexFieldType::LiteralArray,
+            "text-array" => IndexFieldType::TextArray,
+            "date-array" => IndexFieldType::DateArray,
+            _ => IndexFieldType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for IndexFieldType {
+    fn from(value: String) -> IndexFieldType {
+        match value.as_str() {
+            "int" => IndexFieldType::Int,
+            "double" => IndexFieldType::Double,
+            "literal" => IndexFieldType::Literal,
+            "text" => IndexFieldType::Text,
+            "date" => IndexFieldType::Date,
+            "latlon" => IndexFieldType::Latlon,
+            "int-array" => IndexFieldType::IntArray,
+            "double-array" => IndexFieldType::DoubleArray,
+            "literal-array" => IndexFieldType::LiteralArray,
+            "text-array" => IndexFieldType::TextArray,
+            "date-array" => IndexFieldType::DateArray,
+            _ => IndexFieldType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for IndexFieldType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<IndexFieldType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for IndexFieldType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for IndexFieldType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for IndexFieldType {
+    fn deserialize<D>(deserializer: D) -> Result<IndexFieldType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct IndexFieldTypeDeserializer;
 impl IndexFieldTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<IndexFieldType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -3244,12 +3760,121 @@ impl MultiAZDeserializer {
         Ok(obj)
     }
 }
+/// <p>The state of processing a change to an option. One of:</p> <ul> <li>RequiresIndexDocuments: The option's latest value will not be deployed until <a>IndexDocuments</a> has been called and indexing is complete.</li> <li>Processing: The option's latest value is in the process of being activated.</li> <li>Active: The option's latest value is fully deployed. </li> <li>FailedToValidate: The option value is not compatible with the domain's data and cannot be used to index the data. You must either modify the option value or update or remove the incompatible documents.</li> </ul>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OptionState {
+    /// `RequiresIndexDocuments`
+    RequiresIndexDocuments,
+    /// `Processing`
+    Processing,
+    /// `Active`
+    Active,
+    /// `FailedToValidate`
+    FailedToValidate,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl OptionState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OptionState::RequiresIndexDocuments => "RequiresIndexDocuments",
+            OptionState::Processing => "Processing",
+            OptionState::Active => "Active",
+            OptionState::FailedToValidate => "FailedToValidate",
+            OptionState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for OptionState {
+    fn default() -> OptionState {
+        OptionState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for OptionState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for OptionState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for OptionState {
+    fn from(value: &'a str) -> OptionState {
+        match value {
+            "RequiresIndexDocuments" => OptionState::RequiresIndexDocuments,
+            "Processing" => OptionState::Processing,
+            "Active" => OptionState::Active,
+            "FailedToValidate" => OptionState::FailedToValidate,
+            _ => OptionState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OptionState {
+    fn from(value: String) -> OptionState {
+        match value.as_str() {
+            "RequiresIndexDocuments" => OptionState::RequiresIndexDocuments,
+            "Processing" => OptionState::Processing,
+            "Active" => OptionState::Active,
+            "FailedToValidate" => OptionState::FailedToValidate,
+            _ => OptionState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for OptionState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<OptionState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OptionState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for OptionState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for OptionState {
+    fn deserialize<D>(deserializer: D) -> Result<OptionState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct OptionStateDeserializer;
 impl OptionStateDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<OptionState, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -3260,13 +3885,13 @@ impl OptionStateDeserializer {
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct OptionStatus {
     /// <p>A timestamp for when this option was created.</p>
-    pub creation_date: String,
+    pub creation_date: ::chrono::DateTime<::chrono::Utc>,
     /// <p>Indicates that the option will be deleted once processing is complete.</p>
     pub pending_deletion: Option<bool>,
     /// <p><p>The state of processing a change to an option. Possible values:</p><ul> <li><code>RequiresIndexDocuments</code>: the option&#39;s latest value will not be deployed until <a>IndexDocuments</a> has been called and indexing is complete.</li> <li><code>Processing</code>: the option&#39;s latest value is in the process of being activated. </li> <li><code>Active</code>: the option&#39;s latest value is completely deployed.</li> <li><code>FailedToValidate</code>: the option value is not compatible with the domain&#39;s data and cannot be used to index the data. You must either modify the option value or update or remove the incompatible documents.</li> </ul></p>
-    pub state: String,
+    pub state: OptionState,
     /// <p>A timestamp for when this option was last updated.</p>
-    pub update_date: String,
+    pub update_date: ::chrono::DateTime<::chrono::Utc>,
     /// <p>A unique integer that indicates when this option was last updated.</p>
     pub update_version: Option<i64>,
 }
@@ -3316,12 +3941,210 @@ impl PartitionCountDeserializer {
         Ok(obj)
     }
 }
+/// <p>The instance type (such as <code>search.m1.small</code>) on which an index partition is hosted.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PartitionInstanceType {
+    /// `search.m1.small`
+    SearchM1Small,
+    /// `search.m1.large`
+    SearchM1Large,
+    /// `search.m2.xlarge`
+    SearchM2Xlarge,
+    /// `search.m2.2xlarge`
+    SearchM2_2xlarge,
+    /// `search.m3.medium`
+    SearchM3Medium,
+    /// `search.m3.large`
+    SearchM3Large,
+    /// `search.m3.xlarge`
+    SearchM3Xlarge,
+    /// `search.m3.2xlarge`
+    SearchM3_2xlarge,
+    /// `search.small`
+    SearchSmall,
+    /// `search.medium`
+    SearchMedium,
+    /// `search.large`
+    SearchLarge,
+    /// `search.xlarge`
+    SearchXlarge,
+    /// `search.2xlarge`
+    Search2xlarge,
+    /// `search.previousgeneration.small`
+    SearchPreviousgenerationSmall,
+    /// `search.previousgeneration.large`
+    SearchPreviousgenerationLarge,
+    /// `search.previousgeneration.xlarge`
+    SearchPreviousgenerationXlarge,
+    /// `search.previousgeneration.2xlarge`
+    SearchPreviousgeneration2xlarge,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PartitionInstanceType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PartitionInstanceType::SearchM1Small => "search.m1.small",
+            PartitionInstanceType::SearchM1Large => "search.m1.large",
+            PartitionInstanceType::SearchM2Xlarge => "search.m2.xlarge",
+            PartitionInstanceType::SearchM2_2xlarge => "search.m2.2xlarge",
+            PartitionInstanceType::SearchM3Medium => "search.m3.medium",
+            PartitionInstanceType::SearchM3Large => "search.m3.large",
+            PartitionInstanceType::SearchM3Xlarge => "search.m3.xlarge",
+            PartitionInstanceType::SearchM3_2xlarge => "search.m3.2xlarge",
+            PartitionInstanceType::SearchSmall => "search.small",
+            PartitionInstanceType::SearchMedium => "search.medium",
+            PartitionInstanceType::SearchLarge => "search.large",
+            PartitionInstanceType::SearchXlarge => "search.xlarge",
+            PartitionInstanceType::Search2xlarge => "search.2xlarge",
+            PartitionInstanceType::SearchPreviousgenerationSmall => {
+                "search.previousgeneration.small"
+            }
+            PartitionInstanceType::SearchPreviousgenerationLarge => {
+                "search.previousgeneration.large"
+            }
+            PartitionInstanceType::SearchPreviousgenerationXlarge => {
+                "search.previousgeneration.xlarge"
+            }
+            PartitionInstanceType::SearchPreviousgeneration2xlarge => {
+                "search.previousgeneration.2xlarge"
+            }
+            PartitionInstanceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PartitionInstanceType {
+    fn default() -> PartitionInstanceType {
+        PartitionInstanceType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PartitionInstanceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PartitionInstanceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PartitionInstanceType {
+    fn from(value: &'a str) -> PartitionInstanceType {
+        match value {
+            "search.m1.small" => PartitionInstanceType::SearchM1Small,
+            "search.m1.large" => PartitionInstanceType::SearchM1Large,
+            "search.m2.xlarge" => PartitionInstanceType::SearchM2Xlarge,
+            "search.m2.2xlarge" => PartitionInstanceType::SearchM2_2xlarge,
+            "search.m3.medium" => PartitionInstanceType::SearchM3Medium,
+            "search.m3.large" => PartitionInstanceType::SearchM3Large,
+            "search.m3.xlarge" => PartitionInstanceType::SearchM3Xlarge,
+            "search.m3.2xlarge" => PartitionInstanceType::SearchM3_2xlarge,
+            "search.small" => PartitionInstanceType::SearchSmall,
+            "search.medium" => PartitionInstanceType::SearchMedium,
+            "search.large" => PartitionInstanceType::SearchLarge,
+            "search.xlarge" => PartitionInstanceType::SearchXlarge,
+            "search.2xlarge" => PartitionInstanceType::Search2xlarge,
+            "search.previousgeneration.small" => {
+                PartitionInstanceType::SearchPreviousgenerationSmall
+            }
+            "search.previousgeneration.large" => {
+                PartitionInstanceType::SearchPreviousgenerationLarge
+            }
+            "search.previousgeneration.xlarge" => {
+                PartitionInstanceType::SearchPreviousgenerationXlarge
+            }
+            "search.previousgeneration.2xlarge" => {
+                PartitionInstanceType::SearchPreviousgeneration2xlarge
+            }
+            _ => PartitionInstanceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PartitionInstanceType {
+    fn from(value: String) -> PartitionInstanceType {
+        match value.as_str() {
+            "search.m1.small" => PartitionInstanceType::SearchM1Small,
+            "search.m1.large" => PartitionInstanceType::SearchM1Large,
+            "search.m2.xlarge" => PartitionInstanceType::SearchM2Xlarge,
+            "search.m2.2xlarge" => PartitionInstanceType::SearchM2_2xlarge,
+            "search.m3.medium" => PartitionInstanceType::SearchM3Medium,
+            "search.m3.large" => PartitionInstanceType::SearchM3Large,
+            "search.m3.xlarge" => PartitionInstanceType::SearchM3Xlarge,
+            "search.m3.2xlarge" => PartitionInstanceType::SearchM3_2xlarge,
+            "search.small" => PartitionInstanceType::SearchSmall,
+            "search.medium" => PartitionInstanceType::SearchMedium,
+            "search.large" => PartitionInstanceType::SearchLarge,
+            "search.xlarge" => PartitionInstanceType::SearchXlarge,
+            "search.2xlarge" => PartitionInstanceType::Search2xlarge,
+            "search.previousgeneration.small" => {
+                PartitionInstanceType::SearchPreviousgenerationSmall
+            }
+            "search.previousgeneration.large" => {
+                PartitionInstanceType::SearchPreviousgenerationLarge
+            }
+            "search.previousgeneration.xlarge" => {
+                PartitionInstanceType::SearchPreviousgenerationXlarge
+            }
+            "search.previousgeneration.2xlarge" => {
+                PartitionInstanceType::SearchPreviousgeneration2xlarge
+            }
+            _ => PartitionInstanceType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PartitionInstanceType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PartitionInstanceType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PartitionInstanceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for PartitionInstanceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for PartitionInstanceType {
+    fn deserialize<D>(deserializer: D) -> Result<PartitionInstanceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct PartitionInstanceTypeDeserializer;
 impl PartitionInstanceTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<PartitionInstanceType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -3344,7 +4167,7 @@ impl PolicyDocumentDeserializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ScalingParameters {
     /// <p>The instance type that you want to preconfigure for your domain. For example, <code>search.m1.small</code>.</p>
-    pub desired_instance_type: Option<String>,
+    pub desired_instance_type: Option<PartitionInstanceType>,
     /// <p>The number of partitions you want to preconfigure for your domain. Only valid when you select <code>m2.2xlarge</code> as the desired instance type.</p>
     pub desired_partition_count: Option<i64>,
     /// <p>The number of replicas you want to preconfigure for each index partition.</p>
@@ -3587,12 +4410,115 @@ impl SuggesterSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SuggesterFuzzyMatching {
+    /// `none`
+    None,
+    /// `low`
+    Low,
+    /// `high`
+    High,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SuggesterFuzzyMatching {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SuggesterFuzzyMatching::None => "none",
+            SuggesterFuzzyMatching::Low => "low",
+            SuggesterFuzzyMatching::High => "high",
+            SuggesterFuzzyMatching::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SuggesterFuzzyMatching {
+    fn default() -> SuggesterFuzzyMatching {
+        SuggesterFuzzyMatching::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SuggesterFuzzyMatching {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SuggesterFuzzyMatching {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SuggesterFuzzyMatching {
+    fn from(value: &'a str) -> SuggesterFuzzyMatching {
+        match value {
+            "none" => SuggesterFuzzyMatching::None,
+            "low" => SuggesterFuzzyMatching::Low,
+            "high" => SuggesterFuzzyMatching::High,
+            _ => SuggesterFuzzyMatching::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SuggesterFuzzyMatching {
+    fn from(value: String) -> SuggesterFuzzyMatching {
+        match value.as_str() {
+            "none" => SuggesterFuzzyMatching::None,
+            "low" => SuggesterFuzzyMatching::Low,
+            "high" => SuggesterFuzzyMatching::High,
+            _ => SuggesterFuzzyMatching::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SuggesterFuzzyMatching {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SuggesterFuzzyMatching, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SuggesterFuzzyMatching {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for SuggesterFuzzyMatching {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for SuggesterFuzzyMatching {
+    fn deserialize<D>(deserializer: D) -> Result<SuggesterFuzzyMatching, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct SuggesterFuzzyMatchingDeserializer;
 impl SuggesterFuzzyMatchingDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<SuggesterFuzzyMatching, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -3644,12 +4570,111 @@ impl SuggesterStatusListDeserializer {
         })
     }
 }
+/// <p>The minimum required TLS version.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TLSSecurityPolicy {
+    /// `Policy-Min-TLS-1-0-2019-07`
+    PolicyMinTls1_0_2019_07,
+    /// `Policy-Min-TLS-1-2-2019-07`
+    PolicyMinTls1_2_2019_07,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TLSSecurityPolicy {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TLSSecurityPolicy::PolicyMinTls1_0_2019_07 => "Policy-Min-TLS-1-0-2019-07",
+            TLSSecurityPolicy::PolicyMinTls1_2_2019_07 => "Policy-Min-TLS-1-2-2019-07",
+            TLSSecurityPolicy::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TLSSecurityPolicy {
+    fn default() -> TLSSecurityPolicy {
+        TLSSecurityPolicy::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TLSSecurityPolicy {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TLSSecurityPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TLSSecurityPolicy {
+    fn from(value: &'a str) -> TLSSecurityPolicy {
+        match value {
+            "Policy-Min-TLS-1-0-2019-07" => TLSSecurityPolicy::PolicyMinTls1_0_2019_07,
+            "Policy-Min-TLS-1-2-2019-07" => TLSSecurityPolicy::PolicyMinTls1_2_2019_07,
+            _ => TLSSecurityPolicy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TLSSecurityPolicy {
+    fn from(value: String) -> TLSSecurityPolicy {
+        match value.as_str() {
+            "Policy-Min-TLS-1-0-2019-07" => TLSSecurityPolicy::PolicyMinTls1_0_2019_07,
+            "Policy-Min-TLS-1-2-2019-07" => TLSSecurityPolicy::PolicyMinTls1_2_2019_07,
+            _ => TLSSecurityPolicy::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TLSSecurityPolicy {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TLSSecurityPolicy, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TLSSecurityPolicy {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for TLSSecurityPolicy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for TLSSecurityPolicy {
+    fn deserialize<D>(deserializer: D) -> Result<TLSSecurityPolicy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct TLSSecurityPolicyDeserializer;
 impl TLSSecurityPolicyDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<TLSSecurityPolicy, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -4073,9 +5098,12 @@ impl UpdateServiceAccessPoliciesResponseDeserializer {
 struct UpdateTimestampDeserializer;
 impl UpdateTimestampDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<::chrono::DateTime<::chrono::Utc>, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::proto::xml::util::timestamp_characters(stack)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -6058,11 +7086,12 @@ pub struct CloudSearchClient {
 impl CloudSearchClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> CloudSearchClient {
         CloudSearchClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "CloudSearch"),
         }
     }
 
@@ -6094,6 +7123,7 @@ impl CloudSearch for CloudSearchClient {
         input: BuildSuggestersRequest,
     ) -> Result<BuildSuggestersResponse, RusotoError<BuildSuggestersError>> {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("BuildSuggesters");
         let mut params = Params::new();
 
         params.put("Action", "BuildSuggesters");
@@ -6143,6 +7173,7 @@ impl CloudSearch for CloudSearchClient {
         input: CreateDomainRequest,
     ) -> Result<CreateDomainResponse, RusotoError<CreateDomainError>> {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("CreateDomain");
         let mut params = Params::new();
 
         params.put("Action", "CreateDomain");
@@ -6190,6 +7221,7 @@ impl CloudSearch for CloudSearchClient {
         input: DefineAnalysisSchemeRequest,
     ) -> Result<DefineAnalysisSchemeResponse, RusotoError<DefineAnalysisSchemeError>> {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DefineAnalysisScheme");
         let mut params = Params::new();
 
         params.put("Action", "DefineAnalysisScheme");
@@ -6239,6 +7271,7 @@ impl CloudSearch for CloudSearchClient {
         input: DefineExpressionRequest,
     ) -> Result<DefineExpressionResponse, RusotoError<DefineExpressionError>> {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DefineExpression");
         let mut params = Params::new();
 
         params.put("Action", "DefineExpression");
@@ -6288,6 +7321,7 @@ impl CloudSearch for CloudSearchClient {
         input: DefineIndexFieldRequest,
     ) -> Result<DefineIndexFieldResponse, RusotoError<DefineIndexFieldError>> {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DefineIndexField");
         let mut params = Params::new();
 
         params.put("Action", "DefineIndexField");
@@ -6337,6 +7371,7 @@ impl CloudSearch for CloudSearchClient {
         input: DefineSuggesterRequest,
     ) -> Result<DefineSuggesterResponse, RusotoError<DefineSuggesterError>> {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DefineSuggester");
         let mut params = Params::new();
 
         params.put("Action", "DefineSuggester");
@@ -6386,6 +7421,7 @@ impl CloudSearch for CloudSearchClient {
         input: DeleteAnalysisSchemeRequest,
     ) -> Result<DeleteAnalysisSchemeResponse, RusotoError<DeleteAnalysisSchemeError>> {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DeleteAnalysisScheme");
         let mut params = Params::new();
 
         params.put("Action", "DeleteAnalysisScheme");
@@ -6435,6 +7471,7 @@ impl CloudSearch for CloudSearchClient {
         input: DeleteDomainRequest,
     ) -> Result<DeleteDomainResponse, RusotoError<DeleteDomainError>> {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DeleteDomain");
         let mut params = Params::new();
 
         params.put("Action", "DeleteDomain");
@@ -6482,6 +7519,7 @@ impl CloudSearch for CloudSearchClient {
         input: DeleteExpressionRequest,
     ) -> Result<DeleteExpressionResponse, RusotoError<DeleteExpressionError>> {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DeleteExpression");
         let mut params = Params::new();
 
         params.put("Action", "DeleteExpression");
@@ -6531,6 +7569,7 @@ impl CloudSearch for CloudSearchClient {
         input: DeleteIndexFieldRequest,
     ) -> Result<DeleteIndexFieldResponse, RusotoError<DeleteIndexFieldError>> {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DeleteIndexField");
         let mut params = Params::new();
 
         params.put("Action", "DeleteIndexField");
@@ -6580,6 +7619,7 @@ impl CloudSearch for CloudSearchClient {
         input: DeleteSuggesterRequest,
     ) -> Result<DeleteSuggesterResponse, RusotoError<DeleteSuggesterError>> {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DeleteSuggester");
         let mut params = Params::new();
 
         params.put("Action", "DeleteSuggester");
@@ -6629,6 +7669,7 @@ impl CloudSearch for CloudSearchClient {
         input: DescribeAnalysisSchemesRequest,
     ) -> Result<DescribeAnalysisSchemesResponse, RusotoError<DescribeAnalysisSchemesError>> {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DescribeAnalysisSchemes");
         let mut params = Params::new();
 
         params.put("Action", "DescribeAnalysisSchemes");
@@ -6679,6 +7720,7 @@ impl CloudSearch for CloudSearchClient {
     ) -> Result<DescribeAvailabilityOptionsResponse, RusotoError<DescribeAvailabilityOptionsError>>
     {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DescribeAvailabilityOptions");
         let mut params = Params::new();
 
         params.put("Action", "DescribeAvailabilityOptions");
@@ -6731,6 +7773,7 @@ impl CloudSearch for CloudSearchClient {
         RusotoError<DescribeDomainEndpointOptionsError>,
     > {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DescribeDomainEndpointOptions");
         let mut params = Params::new();
 
         params.put("Action", "DescribeDomainEndpointOptions");
@@ -6780,6 +7823,7 @@ impl CloudSearch for CloudSearchClient {
         input: DescribeDomainsRequest,
     ) -> Result<DescribeDomainsResponse, RusotoError<DescribeDomainsError>> {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DescribeDomains");
         let mut params = Params::new();
 
         params.put("Action", "DescribeDomains");
@@ -6829,6 +7873,7 @@ impl CloudSearch for CloudSearchClient {
         input: DescribeExpressionsRequest,
     ) -> Result<DescribeExpressionsResponse, RusotoError<DescribeExpressionsError>> {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DescribeExpressions");
         let mut params = Params::new();
 
         params.put("Action", "DescribeExpressions");
@@ -6878,6 +7923,7 @@ impl CloudSearch for CloudSearchClient {
         input: DescribeIndexFieldsRequest,
     ) -> Result<DescribeIndexFieldsResponse, RusotoError<DescribeIndexFieldsError>> {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DescribeIndexFields");
         let mut params = Params::new();
 
         params.put("Action", "DescribeIndexFields");
@@ -6928,6 +7974,7 @@ impl CloudSearch for CloudSearchClient {
     ) -> Result<DescribeScalingParametersResponse, RusotoError<DescribeScalingParametersError>>
     {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DescribeScalingParameters");
         let mut params = Params::new();
 
         params.put("Action", "DescribeScalingParameters");
@@ -6980,6 +8027,7 @@ impl CloudSearch for CloudSearchClient {
         RusotoError<DescribeServiceAccessPoliciesError>,
     > {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DescribeServiceAccessPolicies");
         let mut params = Params::new();
 
         params.put("Action", "DescribeServiceAccessPolicies");
@@ -7029,6 +8077,7 @@ impl CloudSearch for CloudSearchClient {
         input: DescribeSuggestersRequest,
     ) -> Result<DescribeSuggestersResponse, RusotoError<DescribeSuggestersError>> {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DescribeSuggesters");
         let mut params = Params::new();
 
         params.put("Action", "DescribeSuggesters");
@@ -7078,6 +8127,7 @@ impl CloudSearch for CloudSearchClient {
         input: IndexDocumentsRequest,
     ) -> Result<IndexDocumentsResponse, RusotoError<IndexDocumentsError>> {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("IndexDocuments");
         let mut params = Params::new();
 
         params.put("Action", "IndexDocuments");
@@ -7126,6 +8176,7 @@ impl CloudSearch for CloudSearchClient {
         &self,
     ) -> Result<ListDomainNamesResponse, RusotoError<ListDomainNamesError>> {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("ListDomainNames");
         let mut params = Params::new();
 
         params.put("Action", "ListDomainNames");
@@ -7176,6 +8227,7 @@ impl CloudSearch for CloudSearchClient {
     ) -> Result<UpdateAvailabilityOptionsResponse, RusotoError<UpdateAvailabilityOptionsError>>
     {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("UpdateAvailabilityOptions");
         let mut params = Params::new();
 
         params.put("Action", "UpdateAvailabilityOptions");
@@ -7226,6 +8278,7 @@ impl CloudSearch for CloudSearchClient {
     ) -> Result<UpdateDomainEndpointOptionsResponse, RusotoError<UpdateDomainEndpointOptionsError>>
     {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("UpdateDomainEndpointOptions");
         let mut params = Params::new();
 
         params.put("Action", "UpdateDomainEndpointOptions");
@@ -7275,6 +8328,7 @@ impl CloudSearch for CloudSearchClient {
         input: UpdateScalingParametersRequest,
     ) -> Result<UpdateScalingParametersResponse, RusotoError<UpdateScalingParametersError>> {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("UpdateScalingParameters");
         let mut params = Params::new();
 
         params.put("Action", "UpdateScalingParameters");
@@ -7325,6 +8379,7 @@ impl CloudSearch for CloudSearchClient {
     ) -> Result<UpdateServiceAccessPoliciesResponse, RusotoError<UpdateServiceAccessPoliciesError>>
     {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("UpdateServiceAccessPolicies");
         let mut params = Params::new();
 
         params.put("Action", "UpdateServiceAccessPolicies");
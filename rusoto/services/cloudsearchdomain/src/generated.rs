@@ -48,6 +48,99 @@ pub struct BucketInfo {
     pub buckets: Option<Vec<Bucket>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ContentType {
+    /// `application/json`
+    ApplicationJson,
+    /// `application/xml`
+    ApplicationXml,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ContentType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ContentType::ApplicationJson => "application/json",
+            ContentType::ApplicationXml => "application/xml",
+            ContentType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ContentType {
+    fn default() -> ContentType {
+        ContentType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ContentType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ContentType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ContentType {
+    fn from(value: &'a str) -> ContentType {
+        match value {
+            "application/json" => ContentType::ApplicationJson,
+            "application/xml" => ContentType::ApplicationXml,
+            _ => ContentType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ContentType {
+    fn from(value: String) -> ContentType {
+        match value.as_str() {
+            "application/json" => ContentType::ApplicationJson,
+            "application/xml" => ContentType::ApplicationXml,
+            _ => ContentType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ContentType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ContentType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ContentType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ContentType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ContentType {
+    fn deserialize<D>(deserializer: D) -> Result<ContentType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A warning returned by the document service when an issue is discovered while processing an upload request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -140,6 +233,109 @@ pub struct Hits {
     pub start: Option<i64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum QueryParser {
+    /// `simple`
+    Simple,
+    /// `structured`
+    Structured,
+    /// `lucene`
+    Lucene,
+    /// `dismax`
+    Dismax,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl QueryParser {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            QueryParser::Simple => "simple",
+            QueryParser::Structured => "structured",
+            QueryParser::Lucene => "lucene",
+            QueryParser::Dismax => "dismax",
+            QueryParser::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for QueryParser {
+    fn default() -> QueryParser {
+        QueryParser::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for QueryParser {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for QueryParser {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for QueryParser {
+    fn from(value: &'a str) -> QueryParser {
+        match value {
+            "simple" => QueryParser::Simple,
+            "structured" => QueryParser::Structured,
+            "lucene" => QueryParser::Lucene,
+            "dismax" => QueryParser::Dismax,
+            _ => QueryParser::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for QueryParser {
+    fn from(value: String) -> QueryParser {
+        match value.as_str() {
+            "simple" => QueryParser::Simple,
+            "structured" => QueryParser::Structured,
+            "lucene" => QueryParser::Lucene,
+            "dismax" => QueryParser::Dismax,
+            _ => QueryParser::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for QueryParser {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<QueryParser, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for QueryParser {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for QueryParser {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for QueryParser {
+    fn deserialize<D>(deserializer: D) -> Result<QueryParser, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Container for the parameters to the <code>Search</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -178,7 +374,7 @@ pub struct SearchRequest {
     /// <p><p>Specifies which query parser to use to process the request. If <code>queryParser</code> is not specified, Amazon CloudSearch uses the <code>simple</code> query parser. </p> <p>Amazon CloudSearch supports four query parsers:</p> <ul> <li> <code>simple</code>: perform simple searches of <code>text</code> and <code>text-array</code> fields. By default, the <code>simple</code> query parser searches all <code>text</code> and <code>text-array</code> fields. You can specify which fields to search by with the <code>queryOptions</code> parameter. If you prefix a search term with a plus sign (+) documents must contain the term to be considered a match. (This is the default, unless you configure the default operator with the <code>queryOptions</code> parameter.) You can use the <code>-</code> (NOT), <code>|</code> (OR), and <code>*</code> (wildcard) operators to exclude particular terms, find results that match any of the specified terms, or search for a prefix. To search for a phrase rather than individual terms, enclose the phrase in double quotes. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/searching-text.html">Searching for Text</a> in the <i>Amazon CloudSearch Developer Guide</i>. </li> <li> <code>structured</code>: perform advanced searches by combining multiple expressions to define the search criteria. You can also search within particular fields, search for values and ranges of values, and use advanced options such as term boosting, <code>matchall</code>, and <code>near</code>. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/searching-compound-queries.html">Constructing Compound Queries</a> in the <i>Amazon CloudSearch Developer Guide</i>. </li> <li> <code>lucene</code>: search using the Apache Lucene query parser syntax. For more information, see <a href="http://lucene.apache.org/core/4_6_0/queryparser/org/apache/lucene/queryparser/classic/package-summary.html#package_description">Apache Lucene Query Parser Syntax</a>. </li> <li> <code>dismax</code>: search using the simplified subset of the Apache Lucene query parser syntax defined by the DisMax query parser. For more information, see <a href="http://wiki.apache.org/solr/DisMaxQParserPlugin#Query_Syntax">DisMax Query Parser Syntax</a>. </li> </ul></p>
     #[serde(rename = "queryParser")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub query_parser: Option<String>,
+    pub query_parser: Option<QueryParser>,
     /// <p>Specifies the field and expression values to include in the response. Multiple fields or expressions are specified as a comma-separated list. By default, a search response includes all return enabled fields (<code>_all_fields</code>). To return only the document IDs for the matching documents, specify <code>_no_fields</code>. To retrieve the relevance score calculated for each document, specify <code>_score</code>. </p>
     #[serde(rename = "return")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -323,7 +519,7 @@ pub struct SuggestionMatch {
 pub struct UploadDocumentsRequest {
     /// <p><p>The format of the batch you are uploading. Amazon CloudSearch supports two document batch formats:</p> <ul> <li>application/json</li> <li>application/xml</li> </ul></p>
     #[serde(rename = "contentType")]
-    pub content_type: String,
+    pub content_type: ContentType,
     /// <p>A batch of documents formatted in JSON or HTML.</p>
     #[serde(rename = "documents")]
     #[serde(
@@ -473,11 +669,12 @@ pub struct CloudSearchDomainClient {
 impl CloudSearchDomainClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> CloudSearchDomainClient {
         CloudSearchDomainClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "CloudSearch Domain"),
         }
     }
 
@@ -511,6 +708,7 @@ impl CloudSearchDomain for CloudSearchDomainClient {
         let request_uri = "/2013-01-01/search";
 
         let mut request = SignedRequest::new("GET", "cloudsearch", &self.region, &request_uri);
+        request.set_operation("Search");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("cloudsearchdomain".to_string());
@@ -585,6 +783,7 @@ impl CloudSearchDomain for CloudSearchDomainClient {
         let request_uri = "/2013-01-01/suggest";
 
         let mut request = SignedRequest::new("GET", "cloudsearch", &self.region, &request_uri);
+        request.set_operation("Suggest");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("cloudsearchdomain".to_string());
@@ -624,12 +823,13 @@ impl CloudSearchDomain for CloudSearchDomainClient {
         let request_uri = "/2013-01-01/documents/batch";
 
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, &request_uri);
+        request.set_operation("UploadDocuments");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("cloudsearchdomain".to_string());
         let encoded = Some(input.documents.to_owned());
         request.set_payload(encoded);
-        request.add_header("Content-Type", &input.content_type);
+        request.add_header("Content-Type", input.content_type.as_str());
         let mut params = Params::new();
         params.put("format", "sdk");
         request.set_params(params);
@@ -224,8 +224,13 @@ pub struct Event {
     pub event_source: Option<String>,
     /// <p>The date and time of the event returned.</p>
     #[serde(rename = "EventTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub event_time: Option<f64>,
+    pub event_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Information about whether the event is a write event or a read event. </p>
     #[serde(rename = "ReadOnly")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -240,6 +245,94 @@ pub struct Event {
     pub username: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EventCategory {
+    /// `insight`
+    Insight,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EventCategory {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EventCategory::Insight => "insight",
+            EventCategory::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EventCategory {
+    fn default() -> EventCategory {
+        EventCategory::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EventCategory {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EventCategory {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EventCategory {
+    fn from(value: &'a str) -> EventCategory {
+        match value {
+            "insight" => EventCategory::Insight,
+            _ => EventCategory::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EventCategory {
+    fn from(value: String) -> EventCategory {
+        match value.as_str() {
+            "insight" => EventCategory::Insight,
+            _ => EventCategory::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EventCategory {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EventCategory, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EventCategory {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EventCategory {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EventCategory {
+    fn deserialize<D>(deserializer: D) -> Result<EventCategory, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Use event selectors to further specify the management and data event settings for your trail. By default, trails created without specific event selectors will be configured to log all read and write management events, and no data events. When an event occurs in your account, CloudTrail evaluates the event selector for all trails. For each trail, if the event matches any event selector, the trail processes and logs the event. If the event doesn't match any event selector, the trail doesn't log the event.</p> <p>You can configure up to five event selectors for a trail.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct EventSelector {
@@ -258,7 +351,7 @@ pub struct EventSelector {
     /// <p>Specify if you want your trail to log read-only events, write-only events, or all. For example, the EC2 <code>GetConsoleOutput</code> is a read-only API operation and <code>RunInstances</code> is a write-only API operation.</p> <p> By default, the value is <code>All</code>.</p>
     #[serde(rename = "ReadWriteType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub read_write_type: Option<String>,
+    pub read_write_type: Option<ReadWriteType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -342,8 +435,13 @@ pub struct GetTrailStatusResponse {
     pub latest_cloud_watch_logs_delivery_error: Option<String>,
     /// <p>Displays the most recent date and time when CloudTrail delivered logs to CloudWatch Logs.</p>
     #[serde(rename = "LatestCloudWatchLogsDeliveryTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub latest_cloud_watch_logs_delivery_time: Option<f64>,
+    pub latest_cloud_watch_logs_delivery_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>This field is no longer in use.</p>
     #[serde(rename = "LatestDeliveryAttemptSucceeded")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -358,16 +456,26 @@ pub struct GetTrailStatusResponse {
     pub latest_delivery_error: Option<String>,
     /// <p>Specifies the date and time that CloudTrail last delivered log files to an account's Amazon S3 bucket.</p>
     #[serde(rename = "LatestDeliveryTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub latest_delivery_time: Option<f64>,
+    pub latest_delivery_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>Displays any Amazon S3 error that CloudTrail encountered when attempting to deliver a digest file to the designated bucket. For more information see the topic <a href="https://docs.aws.amazon.com/AmazonS3/latest/API/ErrorResponses.html">Error Responses</a> in the Amazon S3 API Reference. </p> <note> <p>This error occurs only when there is a problem with the destination S3 bucket and will not occur for timeouts. To resolve the issue, create a new bucket and call <code>UpdateTrail</code> to specify the new bucket, or fix the existing objects so that CloudTrail can again write to the bucket.</p> </note></p>
     #[serde(rename = "LatestDigestDeliveryError")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub latest_digest_delivery_error: Option<String>,
     /// <p>Specifies the date and time that CloudTrail last delivered a digest file to an account's Amazon S3 bucket.</p>
     #[serde(rename = "LatestDigestDeliveryTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub latest_digest_delivery_time: Option<f64>,
+    pub latest_digest_delivery_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>This field is no longer in use.</p>
     #[serde(rename = "LatestNotificationAttemptSucceeded")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -382,16 +490,31 @@ pub struct GetTrailStatusResponse {
     pub latest_notification_error: Option<String>,
     /// <p>Specifies the date and time of the most recent Amazon SNS notification that CloudTrail has written a new log file to an account's Amazon S3 bucket.</p>
     #[serde(rename = "LatestNotificationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub latest_notification_time: Option<f64>,
+    pub latest_notification_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Specifies the most recent date and time when CloudTrail started recording API calls for an AWS account.</p>
     #[serde(rename = "StartLoggingTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_logging_time: Option<f64>,
+    pub start_logging_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Specifies the most recent date and time when CloudTrail stopped recording API calls for an AWS account.</p>
     #[serde(rename = "StopLoggingTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stop_logging_time: Option<f64>,
+    pub stop_logging_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>This field is no longer in use.</p>
     #[serde(rename = "TimeLoggingStarted")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -408,25 +531,128 @@ pub struct InsightSelector {
     /// <p>The type of insights to log on a trail. In this release, only <code>ApiCallRateInsight</code> is supported as an insight type.</p>
     #[serde(rename = "InsightType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub insight_type: Option<String>,
+    pub insight_type: Option<InsightType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum InsightType {
+    /// `ApiCallRateInsight`
+    ApiCallRateInsight,
+    /// `ApiErrorRateInsight`
+    ApiErrorRateInsight,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl InsightType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InsightType::ApiCallRateInsight => "ApiCallRateInsight",
+            InsightType::ApiErrorRateInsight => "ApiErrorRateInsight",
+            InsightType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for InsightType {
+    fn default() -> InsightType {
+        InsightType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for InsightType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for InsightType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
+impl<'a> From<&'a str> for InsightType {
+    fn from(value: &'a str) -> InsightType {
+        match value {
+            "ApiCallRateInsight" => InsightType::ApiCallRateInsight,
+            "ApiErrorRateInsight" => InsightType::ApiErrorRateInsight,
+            _ => InsightType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InsightType {
+    fn from(value: String) -> InsightType {
+        match value.as_str() {
+            "ApiCallRateInsight" => InsightType::ApiCallRateInsight,
+            "ApiErrorRateInsight" => InsightType::ApiErrorRateInsight,
+            _ => InsightType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for InsightType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<InsightType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for InsightType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for InsightType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InsightType {
+    fn deserialize<D>(deserializer: D) -> Result<InsightType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Requests the public keys for a specified time range.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ListPublicKeysRequest {
     /// <p>Optionally specifies, in UTC, the end of the time range to look up public keys for CloudTrail digest files. If not specified, the current time is used.</p>
     #[serde(rename = "EndTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Reserved for future use.</p>
     #[serde(rename = "NextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub next_token: Option<String>,
     /// <p>Optionally specifies, in UTC, the start of the time range to look up public keys for CloudTrail digest files. If not specified, the current time is used, and the current public key is returned.</p>
     #[serde(rename = "StartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>Returns the objects or data listed below if successful. Otherwise, returns an error.</p>
@@ -498,24 +724,152 @@ pub struct ListTrailsResponse {
 pub struct LookupAttribute {
     /// <p>Specifies an attribute on which to filter the events returned.</p>
     #[serde(rename = "AttributeKey")]
-    pub attribute_key: String,
+    pub attribute_key: LookupAttributeKey,
     /// <p>Specifies a value for the specified AttributeKey.</p>
     #[serde(rename = "AttributeValue")]
     pub attribute_value: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LookupAttributeKey {
+    /// `EventId`
+    EventId,
+    /// `EventName`
+    EventName,
+    /// `ReadOnly`
+    ReadOnly,
+    /// `Username`
+    Username,
+    /// `ResourceType`
+    ResourceType,
+    /// `ResourceName`
+    ResourceName,
+    /// `EventSource`
+    EventSource,
+    /// `AccessKeyId`
+    AccessKeyId,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LookupAttributeKey {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LookupAttributeKey::EventId => "EventId",
+            LookupAttributeKey::EventName => "EventName",
+            LookupAttributeKey::ReadOnly => "ReadOnly",
+            LookupAttributeKey::Username => "Username",
+            LookupAttributeKey::ResourceType => "ResourceType",
+            LookupAttributeKey::ResourceName => "ResourceName",
+            LookupAttributeKey::EventSource => "EventSource",
+            LookupAttributeKey::AccessKeyId => "AccessKeyId",
+            LookupAttributeKey::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LookupAttributeKey {
+    fn default() -> LookupAttributeKey {
+        LookupAttributeKey::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LookupAttributeKey {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LookupAttributeKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LookupAttributeKey {
+    fn from(value: &'a str) -> LookupAttributeKey {
+        match value {
+            "EventId" => LookupAttributeKey::EventId,
+            "EventName" => LookupAttributeKey::EventName,
+            "ReadOnly" => LookupAttributeKey::ReadOnly,
+            "Username" => LookupAttributeKey::Username,
+            "ResourceType" => LookupAttributeKey::ResourceType,
+            "ResourceName" => LookupAttributeKey::ResourceName,
+            "EventSource" => LookupAttributeKey::EventSource,
+            "AccessKeyId" => LookupAttributeKey::AccessKeyId,
+            _ => LookupAttributeKey::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LookupAttributeKey {
+    fn from(value: String) -> LookupAttributeKey {
+        match value.as_str() {
+            "EventId" => LookupAttributeKey::EventId,
+            "EventName" => LookupAttributeKey::EventName,
+            "ReadOnly" => LookupAttributeKey::ReadOnly,
+            "Username" => LookupAttributeKey::Username,
+            "ResourceType" => LookupAttributeKey::ResourceType,
+            "ResourceName" => LookupAttributeKey::ResourceName,
+            "EventSource" => LookupAttributeKey::EventSource,
+            "AccessKeyId" => LookupAttributeKey::AccessKeyId,
+            _ => LookupAttributeKey::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LookupAttributeKey {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LookupAttributeKey, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LookupAttributeKey {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LookupAttributeKey {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LookupAttributeKey {
+    fn deserialize<D>(deserializer: D) -> Result<LookupAttributeKey, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Contains a request for LookupEvents.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct LookupEventsRequest {
     /// <p>Specifies that only events that occur before or at the specified time are returned. If the specified end time is before the specified start time, an error is returned.</p>
     #[serde(rename = "EndTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Specifies the event category. If you do not specify an event category, events of the category are not returned in the response. For example, if you do not specify <code>insight</code> as the value of <code>EventCategory</code>, no Insights events are returned.</p>
     #[serde(rename = "EventCategory")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub event_category: Option<String>,
+    pub event_category: Option<EventCategory>,
     /// <p>Contains a list of lookup attributes. Currently the list can contain only one item.</p>
     #[serde(rename = "LookupAttributes")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -530,8 +884,13 @@ pub struct LookupEventsRequest {
     pub next_token: Option<String>,
     /// <p>Specifies that only events that occur after or at the specified time are returned. If the specified start time is after the specified end time, an error is returned.</p>
     #[serde(rename = "StartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>Contains a response to a LookupEvents action.</p>
@@ -558,12 +917,22 @@ pub struct PublicKey {
     pub fingerprint: Option<String>,
     /// <p>The ending time of validity of the public key.</p>
     #[serde(rename = "ValidityEndTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub validity_end_time: Option<f64>,
+    pub validity_end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The starting time of validity of the public key.</p>
     #[serde(rename = "ValidityStartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub validity_start_time: Option<f64>,
+    pub validity_start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The DER encoded public key value in PKCS#1 format.</p>
     #[serde(rename = "Value")]
     #[serde(
@@ -623,6 +992,104 @@ pub struct PutInsightSelectorsResponse {
     pub trail_arn: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ReadWriteType {
+    /// `ReadOnly`
+    ReadOnly,
+    /// `WriteOnly`
+    WriteOnly,
+    /// `All`
+    All,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ReadWriteType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReadWriteType::ReadOnly => "ReadOnly",
+            ReadWriteType::WriteOnly => "WriteOnly",
+            ReadWriteType::All => "All",
+            ReadWriteType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ReadWriteType {
+    fn default() -> ReadWriteType {
+        ReadWriteType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ReadWriteType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ReadWriteType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ReadWriteType {
+    fn from(value: &'a str) -> ReadWriteType {
+        match value {
+            "ReadOnly" => ReadWriteType::ReadOnly,
+            "WriteOnly" => ReadWriteType::WriteOnly,
+            "All" => ReadWriteType::All,
+            _ => ReadWriteType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReadWriteType {
+    fn from(value: String) -> ReadWriteType {
+        match value.as_str() {
+            "ReadOnly" => ReadWriteType::ReadOnly,
+            "WriteOnly" => ReadWriteType::WriteOnly,
+            "All" => ReadWriteType::All,
+            _ => ReadWriteType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ReadWriteType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ReadWriteType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ReadWriteType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ReadWriteType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ReadWriteType {
+    fn deserialize<D>(deserializer: D) -> Result<ReadWriteType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Specifies the tags to remove from a trail.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -2473,11 +2940,12 @@ pub struct CloudTrailClient {
 impl CloudTrailClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> CloudTrailClient {
         CloudTrailClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "CloudTrail"),
         }
     }
 
@@ -2509,6 +2977,7 @@ impl CloudTrail for CloudTrailClient {
         input: AddTagsRequest,
     ) -> Result<AddTagsResponse, RusotoError<AddTagsError>> {
         let mut request = SignedRequest::new("POST", "cloudtrail", &self.region, "/");
+        request.set_operation("AddTags");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2539,6 +3008,7 @@ impl CloudTrail for CloudTrailClient {
         input: CreateTrailRequest,
     ) -> Result<CreateTrailResponse, RusotoError<CreateTrailError>> {
         let mut request = SignedRequest::new("POST", "cloudtrail", &self.region, "/");
+        request.set_operation("CreateTrail");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2569,6 +3039,7 @@ impl CloudTrail for CloudTrailClient {
         input: DeleteTrailRequest,
     ) -> Result<DeleteTrailResponse, RusotoError<DeleteTrailError>> {
         let mut request = SignedRequest::new("POST", "cloudtrail", &self.region, "/");
+        request.set_operation("DeleteTrail");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2599,6 +3070,7 @@ impl CloudTrail for CloudTrailClient {
         input: DescribeTrailsRequest,
     ) -> Result<DescribeTrailsResponse, RusotoError<DescribeTrailsError>> {
         let mut request = SignedRequest::new("POST", "cloudtrail", &self.region, "/");
+        request.set_operation("DescribeTrails");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2629,6 +3101,7 @@ impl CloudTrail for CloudTrailClient {
         input: GetEventSelectorsRequest,
     ) -> Result<GetEventSelectorsResponse, RusotoError<GetEventSelectorsError>> {
         let mut request = SignedRequest::new("POST", "cloudtrail", &self.region, "/");
+        request.set_operation("GetEventSelectors");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2660,6 +3133,7 @@ impl CloudTrail for CloudTrailClient {
         input: GetInsightSelectorsRequest,
     ) -> Result<GetInsightSelectorsResponse, RusotoError<GetInsightSelectorsError>> {
         let mut request = SignedRequest::new("POST", "cloudtrail", &self.region, "/");
+        request.set_operation("GetInsightSelectors");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2691,6 +3165,7 @@ impl CloudTrail for CloudTrailClient {
         input: GetTrailRequest,
     ) -> Result<GetTrailResponse, RusotoError<GetTrailError>> {
         let mut request = SignedRequest::new("POST", "cloudtrail", &self.region, "/");
+        request.set_operation("GetTrail");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2721,6 +3196,7 @@ impl CloudTrail for CloudTrailClient {
         input: GetTrailStatusRequest,
     ) -> Result<GetTrailStatusResponse, RusotoError<GetTrailStatusError>> {
         let mut request = SignedRequest::new("POST", "cloudtrail", &self.region, "/");
+        request.set_operation("GetTrailStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2751,6 +3227,7 @@ impl CloudTrail for CloudTrailClient {
         input: ListPublicKeysRequest,
     ) -> Result<ListPublicKeysResponse, RusotoError<ListPublicKeysError>> {
         let mut request = SignedRequest::new("POST", "cloudtrail", &self.region, "/");
+        request.set_operation("ListPublicKeys");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2781,6 +3258,7 @@ impl CloudTrail for CloudTrailClient {
         input: ListTagsRequest,
     ) -> Result<ListTagsResponse, RusotoError<ListTagsError>> {
         let mut request = SignedRequest::new("POST", "cloudtrail", &self.region, "/");
+        request.set_operation("ListTags");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2811,6 +3289,7 @@ impl CloudTrail for CloudTrailClient {
         input: ListTrailsRequest,
     ) -> Result<ListTrailsResponse, RusotoError<ListTrailsError>> {
         let mut request = SignedRequest::new("POST", "cloudtrail", &self.region, "/");
+        request.set_operation("ListTrails");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2841,6 +3320,7 @@ impl CloudTrail for CloudTrailClient {
         input: LookupEventsRequest,
     ) -> Result<LookupEventsResponse, RusotoError<LookupEventsError>> {
         let mut request = SignedRequest::new("POST", "cloudtrail", &self.region, "/");
+        request.set_operation("LookupEvents");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2871,6 +3351,7 @@ impl CloudTrail for CloudTrailClient {
         input: PutEventSelectorsRequest,
     ) -> Result<PutEventSelectorsResponse, RusotoError<PutEventSelectorsError>> {
         let mut request = SignedRequest::new("POST", "cloudtrail", &self.region, "/");
+        request.set_operation("PutEventSelectors");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2902,6 +3383,7 @@ impl CloudTrail for CloudTrailClient {
         input: PutInsightSelectorsRequest,
     ) -> Result<PutInsightSelectorsResponse, RusotoError<PutInsightSelectorsError>> {
         let mut request = SignedRequest::new("POST", "cloudtrail", &self.region, "/");
+        request.set_operation("PutInsightSelectors");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2933,6 +3415,7 @@ impl CloudTrail for CloudTrailClient {
         input: RemoveTagsRequest,
     ) -> Result<RemoveTagsResponse, RusotoError<RemoveTagsError>> {
         let mut request = SignedRequest::new("POST", "cloudtrail", &self.region, "/");
+        request.set_operation("RemoveTags");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2963,6 +3446,7 @@ impl CloudTrail for CloudTrailClient {
         input: StartLoggingRequest,
     ) -> Result<StartLoggingResponse, RusotoError<StartLoggingError>> {
         let mut request = SignedRequest::new("POST", "cloudtrail", &self.region, "/");
+        request.set_operation("StartLogging");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2993,6 +3477,7 @@ impl CloudTrail for CloudTrailClient {
         input: StopLoggingRequest,
     ) -> Result<StopLoggingResponse, RusotoError<StopLoggingError>> {
         let mut request = SignedRequest::new("POST", "cloudtrail", &self.region, "/");
+        request.set_operation("StopLogging");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3023,6 +3508,7 @@ impl CloudTrail for CloudTrailClient {
         input: UpdateTrailRequest,
     ) -> Result<UpdateTrailResponse, RusotoError<UpdateTrailError>> {
         let mut request = SignedRequest::new("POST", "cloudtrail", &self.region, "/");
+        request.set_operation("UpdateTrail");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3047,3 +3533,127 @@ impl CloudTrail for CloudTrailClient {
         }
     }
 }
+impl CloudTrailClient {
+    /// Auto-paginating version of `list_public_keys`, streaming every page of results.
+    pub fn list_public_keys_pages(
+        &self,
+        input: ListPublicKeysRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListPublicKeysResponse, ListPublicKeysError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudTrailClient, input: ListPublicKeysRequest| async move {
+                client.list_public_keys(input).await
+            },
+            |input: &ListPublicKeysRequest, output: &ListPublicKeysResponse| {
+                Some(ListPublicKeysRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_public_keys`, streaming the `PublicKeyList` of every page of results.
+    pub fn list_public_keys_items(
+        &self,
+        input: ListPublicKeysRequest,
+    ) -> rusoto_core::pagination::RusotoStream<PublicKey, ListPublicKeysError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_public_keys_pages(input),
+            |output: ListPublicKeysResponse| output.public_key_list.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_tags`, streaming every page of results.
+    pub fn list_tags_pages(
+        &self,
+        input: ListTagsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListTagsResponse, ListTagsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudTrailClient, input: ListTagsRequest| async move {
+                client.list_tags(input).await
+            },
+            |input: &ListTagsRequest, output: &ListTagsResponse| {
+                Some(ListTagsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_tags`, streaming the `ResourceTagList` of every page of results.
+    pub fn list_tags_items(
+        &self,
+        input: ListTagsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ResourceTag, ListTagsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_tags_pages(input),
+            |output: ListTagsResponse| output.resource_tag_list.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_trails`, streaming every page of results.
+    pub fn list_trails_pages(
+        &self,
+        input: ListTrailsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListTrailsResponse, ListTrailsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudTrailClient, input: ListTrailsRequest| async move {
+                client.list_trails(input).await
+            },
+            |_input: &ListTrailsRequest, output: &ListTrailsResponse| {
+                Some(ListTrailsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_trails`, streaming the `Trails` of every page of results.
+    pub fn list_trails_items(
+        &self,
+        input: ListTrailsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<TrailInfo, ListTrailsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_trails_pages(input),
+            |output: ListTrailsResponse| output.trails.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `lookup_events`, streaming every page of results.
+    pub fn lookup_events_pages(
+        &self,
+        input: LookupEventsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<LookupEventsResponse, LookupEventsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudTrailClient, input: LookupEventsRequest| async move {
+                client.lookup_events(input).await
+            },
+            |input: &LookupEventsRequest, output: &LookupEventsResponse| {
+                Some(LookupEventsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `lookup_events`, streaming the `Events` of every page of results.
+    pub fn lookup_events_items(
+        &self,
+        input: LookupEventsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Event, LookupEventsError> {
+        rusoto_core::pagination::paginate_items(
+            self.lookup_events_pages(input),
+            |output: LookupEventsResponse| output.events.unwrap_or_default(),
+        )
+    }
+}
@@ -74,11 +74,11 @@ pub struct AlarmHistoryItem {
     /// <p>Data about the alarm, in JSON format.</p>
     pub history_data: Option<String>,
     /// <p>The type of alarm history item.</p>
-    pub history_item_type: Option<String>,
+    pub history_item_type: Option<HistoryItemType>,
     /// <p>A summary of the alarm history, in text format.</p>
     pub history_summary: Option<String>,
     /// <p>The time stamp for the alarm history item.</p>
-    pub timestamp: Option<String>,
+    pub timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 struct AlarmHistoryItemDeserializer;
@@ -173,7 +173,7 @@ pub struct AnomalyDetector {
     /// <p>The statistic associated with the anomaly detection model.</p>
     pub stat: Option<String>,
     /// <p>The current status of the anomaly detector's training. The possible values are <code>TRAINED | PENDING_TRAINING | TRAINED_INSUFFICIENT_DATA</code> </p>
-    pub state_value: Option<String>,
+    pub state_value: Option<AnomalyDetectorStateValue>,
 }
 
 struct AnomalyDetectorDeserializer;
@@ -327,12 +327,115 @@ impl AnomalyDetectorMetricTimezoneDeserializer {
         Ok(obj)
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AnomalyDetectorStateValue {
+    /// `PENDING_TRAINING`
+    PendingTraining,
+    /// `TRAINED_INSUFFICIENT_DATA`
+    TrainedInsufficientData,
+    /// `TRAINED`
+    Trained,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AnomalyDetectorStateValue {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AnomalyDetectorStateValue::PendingTraining => "PENDING_TRAINING",
+            AnomalyDetectorStateValue::TrainedInsufficientData => "TRAINED_INSUFFICIENT_DATA",
+            AnomalyDetectorStateValue::Trained => "TRAINED",
+            AnomalyDetectorStateValue::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AnomalyDetectorStateValue {
+    fn default() -> AnomalyDetectorStateValue {
+        AnomalyDetectorStateValue::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AnomalyDetectorStateValue {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AnomalyDetectorStateValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AnomalyDetectorStateValue {
+    fn from(value: &'a str) -> AnomalyDetectorStateValue {
+        match value {
+            "PENDING_TRAINING" => AnomalyDetectorStateValue::PendingTraining,
+            "TRAINED_INSUFFICIENT_DATA" => AnomalyDetectorStateValue::TrainedInsufficientData,
+            "TRAINED" => AnomalyDetectorStateValue::Trained,
+            _ => AnomalyDetectorStateValue::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AnomalyDetectorStateValue {
+    fn from(value: String) -> AnomalyDetectorStateValue {
+        match value.as_str() {
+            "PENDING_TRAINING" => AnomalyDetectorStateValue::PendingTraining,
+            "TRAINED_INSUFFICIENT_DATA" => AnomalyDetectorStateValue::TrainedInsufficientData,
+            "TRAINED" => AnomalyDetectorStateValue::Trained,
+            _ => AnomalyDetectorStateValue::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AnomalyDetectorStateValue {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AnomalyDetectorStateValue, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AnomalyDetectorStateValue {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for AnomalyDetectorStateValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for AnomalyDetectorStateValue {
+    fn deserialize<D>(deserializer: D) -> Result<AnomalyDetectorStateValue, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct AnomalyDetectorStateValueDeserializer;
 impl AnomalyDetectorStateValueDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<AnomalyDetectorStateValue, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -372,12 +475,141 @@ impl BatchFailuresDeserializer {
         })
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ComparisonOperator {
+    /// `GreaterThanOrEqualToThreshold`
+    GreaterThanOrEqualToThreshold,
+    /// `GreaterThanThreshold`
+    GreaterThanThreshold,
+    /// `LessThanThreshold`
+    LessThanThreshold,
+    /// `LessThanOrEqualToThreshold`
+    LessThanOrEqualToThreshold,
+    /// `LessThanLowerOrGreaterThanUpperThreshold`
+    LessThanLowerOrGreaterThanUpperThreshold,
+    /// `LessThanLowerThreshold`
+    LessThanLowerThreshold,
+    /// `GreaterThanUpperThreshold`
+    GreaterThanUpperThreshold,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ComparisonOperator {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ComparisonOperator::GreaterThanOrEqualToThreshold => "GreaterThanOrEqualToThreshold",
+            ComparisonOperator::GreaterThanThreshold => "GreaterThanThreshold",
+            ComparisonOperator::LessThanThreshold => "LessThanThreshold",
+            ComparisonOperator::LessThanOrEqualToThreshold => "LessThanOrEqualToThreshold",
+            ComparisonOperator::LessThanLowerOrGreaterThanUpperThreshold => {
+                "LessThanLowerOrGreaterThanUpperThreshold"
+            }
+            ComparisonOperator::LessThanLowerThreshold => "LessThanLowerThreshold",
+            ComparisonOperator::GreaterThanUpperThreshold => "GreaterThanUpperThreshold",
+            ComparisonOperator::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ComparisonOperator {
+    fn default() -> ComparisonOperator {
+        ComparisonOperator::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ComparisonOperator {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ComparisonOperator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ComparisonOperator {
+    fn from(value: &'a str) -> ComparisonOperator {
+        match value {
+            "GreaterThanOrEqualToThreshold" => ComparisonOperator::GreaterThanOrEqualToThreshold,
+            "GreaterThanThreshold" => ComparisonOperator::GreaterThanThreshold,
+            "LessThanThreshold" => ComparisonOperator::LessThanThreshold,
+            "LessThanOrEqualToThreshold" => ComparisonOperator::LessThanOrEqualToThreshold,
+            "LessThanLowerOrGreaterThanUpperThreshold" => {
+                ComparisonOperator::LessThanLowerOrGreaterThanUpperThreshold
+            }
+            "LessThanLowerThreshold" => ComparisonOperator::LessThanLowerThreshold,
+            "GreaterThanUpperThreshold" => ComparisonOperator::GreaterThanUpperThreshold,
+            _ => ComparisonOperator::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ComparisonOperator {
+    fn from(value: String) -> ComparisonOperator {
+        match value.as_str() {
+            "GreaterThanOrEqualToThreshold" => ComparisonOperator::GreaterThanOrEqualToThreshold,
+            "GreaterThanThreshold" => ComparisonOperator::GreaterThanThreshold,
+            "LessThanThreshold" => ComparisonOperator::LessThanThreshold,
+            "LessThanOrEqualToThreshold" => ComparisonOperator::LessThanOrEqualToThreshold,
+            "LessThanLowerOrGreaterThanUpperThreshold" => {
+                ComparisonOperator::LessThanLowerOrGreaterThanUpperThreshold
+            }
+            "LessThanLowerThreshold" => ComparisonOperator::LessThanLowerThreshold,
+            "GreaterThanUpperThreshold" => ComparisonOperator::GreaterThanUpperThreshold,
+            _ => ComparisonOperator::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ComparisonOperator {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ComparisonOperator, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ComparisonOperator {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ComparisonOperator {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ComparisonOperator {
+    fn deserialize<D>(deserializer: D) -> Result<ComparisonOperator, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct ComparisonOperatorDeserializer;
 impl ComparisonOperatorDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ComparisonOperator, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -443,7 +675,7 @@ pub struct DashboardEntry {
     /// <p>The name of the dashboard.</p>
     pub dashboard_name: Option<String>,
     /// <p>The time stamp of when the dashboard was last modified, either by an API call or through the console. This number is expressed as the number of milliseconds since Jan 1, 1970 00:00:00 UTC.</p>
-    pub last_modified: Option<String>,
+    pub last_modified: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The size of the dashboard, in bytes.</p>
     pub size: Option<i64>,
 }
@@ -589,9 +821,9 @@ pub struct Datapoint {
     /// <p>The sum of the metric values for the data point.</p>
     pub sum: Option<f64>,
     /// <p>The time stamp used for the data point.</p>
-    pub timestamp: Option<String>,
+    pub timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The standard unit for the data point.</p>
-    pub unit: Option<String>,
+    pub unit: Option<StandardUnit>,
 }
 
 struct DatapointDeserializer;
@@ -902,15 +1134,15 @@ pub struct DescribeAlarmHistoryInput {
     /// <p>The name of the alarm.</p>
     pub alarm_name: Option<String>,
     /// <p>The ending date to retrieve alarm history.</p>
-    pub end_date: Option<String>,
+    pub end_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The type of alarm histories to retrieve.</p>
-    pub history_item_type: Option<String>,
+    pub history_item_type: Option<HistoryItemType>,
     /// <p>The maximum number of alarm history records to retrieve.</p>
     pub max_records: Option<i64>,
     /// <p>The token returned by a previous call to indicate that there is more data available.</p>
     pub next_token: Option<String>,
     /// <p>The starting date to retrieve alarm history.</p>
-    pub start_date: Option<String>,
+    pub start_date: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// Serialize `DescribeAlarmHistoryInput` contents to a `SignedRequest`.
@@ -926,7 +1158,10 @@ impl DescribeAlarmHistoryInputSerializer {
             params.put(&format!("{}{}", prefix, "AlarmName"), &field_value);
         }
         if let Some(ref field_value) = obj.end_date {
-            params.put(&format!("{}{}", prefix, "EndDate"), &field_value);
+            params.put(
+                &format!("{}{}", prefix, "EndDate"),
+                &rusoto_core::serialization::TimestampFormat::Iso8601.format(field_value),
+            );
         }
         if let Some(ref field_value) = obj.history_item_type {
             params.put(&format!("{}{}", prefix, "HistoryItemType"), &field_value);
@@ -938,7 +1173,10 @@ impl DescribeAlarmHistoryInputSerializer {
             params.put(&format!("{}{}", prefix, "NextToken"), &field_value);
         }
         if let Some(ref field_value) = obj.start_date {
-            params.put(&format!("{}{}", prefix, "StartDate"), &field_value);
+            params.put(
+                &format!("{}{}", prefix, "StartDate"),
+                &rusoto_core::serialization::TimestampFormat::Iso8601.format(field_value),
+            );
         }
     }
 }
@@ -994,9 +1232,9 @@ pub struct DescribeAlarmsForMetricInput {
     /// <p>The period, in seconds, over which the statistic is applied.</p>
     pub period: Option<i64>,
     /// <p>The statistic for the metric, other than percentiles. For percentile statistics, use <code>ExtendedStatistics</code>.</p>
-    pub statistic: Option<String>,
+    pub statistic: Option<Statistic>,
     /// <p>The unit for the metric.</p>
-    pub unit: Option<String>,
+    pub unit: Option<StandardUnit>,
 }
 
 /// Serialize `DescribeAlarmsForMetricInput` contents to a `SignedRequest`.
@@ -1077,7 +1315,7 @@ pub struct DescribeAlarmsInput {
     /// <p>The token returned by a previous call to indicate that there is more data available.</p>
     pub next_token: Option<String>,
     /// <p>The state value to be used in matching alarms.</p>
-    pub state_value: Option<String>,
+    pub state_value: Option<StateValue>,
 }
 
 /// Serialize `DescribeAlarmsInput` contents to a `SignedRequest`.
@@ -1746,7 +1984,7 @@ impl GetDashboardOutputDeserializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct GetInsightRuleReportInput {
     /// <p>The end time of the data to use in the report. When used in a raw HTTP Query API, it is formatted as <code>yyyy-MM-dd'T'HH:mm:ss</code>. For example, <code>2019-07-01T23:59:59</code>.</p>
-    pub end_time: String,
+    pub end_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The maximum number of contributors to include in the report. The range is 1 to 100. If you omit this, the default of 10 is used.</p>
     pub max_contributor_count: Option<i64>,
     /// <p><p>Specifies which metrics to use for aggregation of contributor values for the report. You can specify one or more of the following metrics:</p> <ul> <li> <p> <code>UniqueContributors</code> -- the number of unique contributors for each data point.</p> </li> <li> <p> <code>MaxContributorValue</code> -- the value of the top contributor for each data point. The identity of the contributor may change for each data point in the graph.</p> <p>If this rule aggregates by COUNT, the top contributor for each data point is the contributor with the most occurrences in that period. If the rule aggregates by SUM, the top contributor is the contributor with the highest sum in the log field specified by the rule&#39;s <code>Value</code>, during that period.</p> </li> <li> <p> <code>SampleCount</code> -- the number of data points matched by the rule.</p> </li> <li> <p> <code>Sum</code> -- the sum of the values from all contributors during the time period represented by that data point.</p> </li> <li> <p> <code>Minimum</code> -- the minimum value from a single observation during the time period represented by that data point.</p> </li> <li> <p> <code>Maximum</code> -- the maximum value from a single observation during the time period represented by that data point.</p> </li> <li> <p> <code>Average</code> -- the average value from all contributors during the time period represented by that data point.</p> </li> </ul></p>
@@ -1758,7 +1996,7 @@ pub struct GetInsightRuleReportInput {
     /// <p>The name of the rule that you want to see data from.</p>
     pub rule_name: String,
     /// <p>The start time of the data to use in the report. When used in a raw HTTP Query API, it is formatted as <code>yyyy-MM-dd'T'HH:mm:ss</code>. For example, <code>2019-07-01T23:59:59</code>.</p>
-    pub start_time: String,
+    pub start_time: ::chrono::DateTime<::chrono::Utc>,
 }
 
 /// Serialize `GetInsightRuleReportInput` contents to a `SignedRequest`.
@@ -1770,7 +2008,10 @@ impl GetInsightRuleReportInputSerializer {
             prefix.push_str(".");
         }
 
-        params.put(&format!("{}{}", prefix, "EndTime"), &obj.end_time);
+        params.put(
+            &format!("{}{}", prefix, "EndTime"),
+            &rusoto_core::serialization::TimestampFormat::Iso8601.format(&obj.end_time),
+        );
         if let Some(ref field_value) = obj.max_contributor_count {
             params.put(
                 &format!("{}{}", prefix, "MaxContributorCount"),
@@ -1789,7 +2030,10 @@ impl GetInsightRuleReportInputSerializer {
         }
         params.put(&format!("{}{}", prefix, "Period"), &obj.period);
         params.put(&format!("{}{}", prefix, "RuleName"), &obj.rule_name);
-        params.put(&format!("{}{}", prefix, "StartTime"), &obj.start_time);
+        params.put(
+            &format!("{}{}", prefix, "StartTime"),
+            &rusoto_core::serialization::TimestampFormat::Iso8601.format(&obj.start_time),
+        );
     }
 }
 
@@ -1878,7 +2122,7 @@ impl GetInsightRuleReportOutputDeserializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct GetMetricDataInput {
     /// <p>The time stamp indicating the latest data to be returned.</p> <p>The value specified is exclusive; results include data points up to the specified time stamp.</p> <p>For better performance, specify <code>StartTime</code> and <code>EndTime</code> values that align with the value of the metric's <code>Period</code> and sync up with the beginning and end of an hour. For example, if the <code>Period</code> of a metric is 5 minutes, specifying 12:05 or 12:30 as <code>EndTime</code> can get a faster response from CloudWatch than setting 12:07 or 12:29 as the <code>EndTime</code>.</p>
-    pub end_time: String,
+    pub end_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The maximum number of data points the request should return before paginating. If you omit this, the default of 100,800 is used.</p>
     pub max_datapoints: Option<i64>,
     /// <p>The metric queries to be returned. A single <code>GetMetricData</code> call can include as many as 100 <code>MetricDataQuery</code> structures. Each of these structures can specify either a metric to retrieve, or a math expression to perform on retrieved data. </p>
@@ -1886,9 +2130,9 @@ pub struct GetMetricDataInput {
     /// <p>Include this value, if it was returned by the previous call, to get the next set of data points.</p>
     pub next_token: Option<String>,
     /// <p>The order in which data points should be returned. <code>TimestampDescending</code> returns the newest data first and paginates when the <code>MaxDatapoints</code> limit is reached. <code>TimestampAscending</code> returns the oldest data first and paginates when the <code>MaxDatapoints</code> limit is reached.</p>
-    pub scan_by: Option<String>,
+    pub scan_by: Option<ScanBy>,
     /// <p>The time stamp indicating the earliest data to be returned.</p> <p>The value specified is inclusive; results include data points with the specified time stamp. </p> <p>CloudWatch rounds the specified time stamp as follows:</p> <ul> <li> <p>Start time less than 15 days ago - Round down to the nearest whole minute. For example, 12:32:34 is rounded down to 12:32:00.</p> </li> <li> <p>Start time between 15 and 63 days ago - Round down to the nearest 5-minute clock interval. For example, 12:32:34 is rounded down to 12:30:00.</p> </li> <li> <p>Start time greater than 63 days ago - Round down to the nearest 1-hour clock interval. For example, 12:32:34 is rounded down to 12:00:00.</p> </li> </ul> <p>If you set <code>Period</code> to 5, 10, or 30, the start time of your request is rounded down to the nearest time that corresponds to even 5-, 10-, or 30-second divisions of a minute. For example, if you make a query at (HH:mm:ss) 01:05:23 for the previous 10-second period, the start time of your request is rounded down and you receive data from 01:05:10 to 01:05:20. If you make a query at 15:07:17 for the previous 5 minutes of data, using a period of 5 seconds, you receive data timestamped between 15:02:15 and 15:07:15. </p> <p>For better performance, specify <code>StartTime</code> and <code>EndTime</code> values that align with the value of the metric's <code>Period</code> and sync up with the beginning and end of an hour. For example, if the <code>Period</code> of a metric is 5 minutes, specifying 12:05 or 12:30 as <code>StartTime</code> can get a faster response from CloudWatch than setting 12:07 or 12:29 as the <code>StartTime</code>.</p>
-    pub start_time: String,
+    pub start_time: ::chrono::DateTime<::chrono::Utc>,
 }
 
 /// Serialize `GetMetricDataInput` contents to a `SignedRequest`.
@@ -1900,7 +2144,10 @@ impl GetMetricDataInputSerializer {
             prefix.push_str(".");
         }
 
-        params.put(&format!("{}{}", prefix, "EndTime"), &obj.end_time);
+        params.put(
+            &format!("{}{}", prefix, "EndTime"),
+            &rusoto_core::serialization::TimestampFormat::Iso8601.format(&obj.end_time),
+        );
         if let Some(ref field_value) = obj.max_datapoints {
             params.put(&format!("{}{}", prefix, "MaxDatapoints"), &field_value);
         }
@@ -1915,7 +2162,10 @@ impl GetMetricDataInputSerializer {
         if let Some(ref field_value) = obj.scan_by {
             params.put(&format!("{}{}", prefix, "ScanBy"), &field_value);
         }
-        params.put(&format!("{}{}", prefix, "StartTime"), &obj.start_time);
+        params.put(
+            &format!("{}{}", prefix, "StartTime"),
+            &rusoto_core::serialization::TimestampFormat::Iso8601.format(&obj.start_time),
+        );
     }
 }
 
@@ -1964,7 +2214,7 @@ pub struct GetMetricStatisticsInput {
     /// <p>The dimensions. If the metric contains multiple dimensions, you must include a value for each dimension. CloudWatch treats each unique combination of dimensions as a separate metric. If a specific combination of dimensions was not published, you can't retrieve statistics for it. You must specify the same dimensions that were used when the metrics were created. For an example, see <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/monitoring/cloudwatch_concepts.html#dimension-combinations">Dimension Combinations</a> in the <i>Amazon CloudWatch User Guide</i>. For more information about specifying dimensions, see <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/monitoring/publishingMetrics.html">Publishing Metrics</a> in the <i>Amazon CloudWatch User Guide</i>.</p>
     pub dimensions: Option<Vec<Dimension>>,
     /// <p>The time stamp that determines the last data point to return.</p> <p>The value specified is exclusive; results include data points up to the specified time stamp. In a raw HTTP query, the time stamp must be in ISO 8601 UTC format (for example, 2016-10-10T23:00:00Z).</p>
-    pub end_time: String,
+    pub end_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The percentile statistics. Specify values between p0.0 and p100. When calling <code>GetMetricStatistics</code>, you must specify either <code>Statistics</code> or <code>ExtendedStatistics</code>, but not both. Percentile statistics are not available for metrics when any of the metric values are negative numbers.</p>
     pub extended_statistics: Option<Vec<String>>,
     /// <p>The name of the metric, with or without spaces.</p>
@@ -1974,11 +2224,11 @@ pub struct GetMetricStatisticsInput {
     /// <p><p>The granularity, in seconds, of the returned data points. For metrics with regular resolution, a period can be as short as one minute (60 seconds) and must be a multiple of 60. For high-resolution metrics that are collected at intervals of less than one minute, the period can be 1, 5, 10, 30, 60, or any multiple of 60. High-resolution metrics are those metrics stored by a <code>PutMetricData</code> call that includes a <code>StorageResolution</code> of 1 second.</p> <p>If the <code>StartTime</code> parameter specifies a time stamp that is greater than 3 hours ago, you must specify the period as follows or no data points in that time range is returned:</p> <ul> <li> <p>Start time between 3 hours and 15 days ago - Use a multiple of 60 seconds (1 minute).</p> </li> <li> <p>Start time between 15 and 63 days ago - Use a multiple of 300 seconds (5 minutes).</p> </li> <li> <p>Start time greater than 63 days ago - Use a multiple of 3600 seconds (1 hour).</p> </li> </ul></p>
     pub period: i64,
     /// <p>The time stamp that determines the first data point to return. Start times are evaluated relative to the time that CloudWatch receives the request.</p> <p>The value specified is inclusive; results include data points with the specified time stamp. In a raw HTTP query, the time stamp must be in ISO 8601 UTC format (for example, 2016-10-03T23:00:00Z).</p> <p>CloudWatch rounds the specified time stamp as follows:</p> <ul> <li> <p>Start time less than 15 days ago - Round down to the nearest whole minute. For example, 12:32:34 is rounded down to 12:32:00.</p> </li> <li> <p>Start time between 15 and 63 days ago - Round down to the nearest 5-minute clock interval. For example, 12:32:34 is rounded down to 12:30:00.</p> </li> <li> <p>Start time greater than 63 days ago - Round down to the nearest 1-hour clock interval. For example, 12:32:34 is rounded down to 12:00:00.</p> </li> </ul> <p>If you set <code>Period</code> to 5, 10, or 30, the start time of your request is rounded down to the nearest time that corresponds to even 5-, 10-, or 30-second divisions of a minute. For example, if you make a query at (HH:mm:ss) 01:05:23 for the previous 10-second period, the start time of your request is rounded down and you receive data from 01:05:10 to 01:05:20. If you make a query at 15:07:17 for the previous 5 minutes of data, using a period of 5 seconds, you receive data timestamped between 15:02:15 and 15:07:15. </p>
-    pub start_time: String,
+    pub start_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The metric statistics, other than percentile. For percentile statistics, use <code>ExtendedStatistics</code>. When calling <code>GetMetricStatistics</code>, you must specify either <code>Statistics</code> or <code>ExtendedStatistics</code>, but not both.</p>
-    pub statistics: Option<Vec<String>>,
+    pub statistics: Option<Vec<Statistic>>,
     /// <p>The unit for a given metric. If you omit <code>Unit</code>, all data that was collected with any unit is returned, along with the corresponding units that were specified when the data was reported to CloudWatch. If you specify a unit, the operation returns only data data that was collected with that unit specified. If you specify a unit that does not match the data collected, the results of the operation are null. CloudWatch does not perform unit conversions.</p>
-    pub unit: Option<String>,
+    pub unit: Option<StandardUnit>,
 }
 
 /// Serialize `GetMetricStatisticsInput` contents to a `SignedRequest`.
@@ -1997,7 +2247,10 @@ impl GetMetricStatisticsInputSerializer {
                 field_value,
             );
         }
-        params.put(&format!("{}{}", prefix, "EndTime"), &obj.end_time);
+        params.put(
+            &format!("{}{}", prefix, "EndTime"),
+            &rusoto_core::serialization::TimestampFormat::Iso8601.format(&obj.end_time),
+        );
         if let Some(ref field_value) = obj.extended_statistics {
             ExtendedStatisticsSerializer::serialize(
                 params,
@@ -2008,7 +2261,10 @@ impl GetMetricStatisticsInputSerializer {
         params.put(&format!("{}{}", prefix, "MetricName"), &obj.metric_name);
         params.put(&format!("{}{}", prefix, "Namespace"), &obj.namespace);
         params.put(&format!("{}{}", prefix, "Period"), &obj.period);
-        params.put(&format!("{}{}", prefix, "StartTime"), &obj.start_time);
+        params.put(
+            &format!("{}{}", prefix, "StartTime"),
+            &rusoto_core::serialization::TimestampFormat::Iso8601.format(&obj.start_time),
+        );
         if let Some(ref field_value) = obj.statistics {
             StatisticsSerializer::serialize(
                 params,
@@ -2126,12 +2382,115 @@ impl HistoryDataDeserializer {
         Ok(obj)
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum HistoryItemType {
+    /// `ConfigurationUpdate`
+    ConfigurationUpdate,
+    /// `StateUpdate`
+    StateUpdate,
+    /// `Action`
+    Action,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl HistoryItemType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            HistoryItemType::ConfigurationUpdate => "ConfigurationUpdate",
+            HistoryItemType::StateUpdate => "StateUpdate",
+            HistoryItemType::Action => "Action",
+            HistoryItemType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for HistoryItemType {
+    fn default() -> HistoryItemType {
+        HistoryItemType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for HistoryItemType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for HistoryItemType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for HistoryItemType {
+    fn from(value: &'a str) -> HistoryItemType {
+        match value {
+            "ConfigurationUpdate" => HistoryItemType::ConfigurationUpdate,
+            "StateUpdate" => HistoryItemType::StateUpdate,
+            "Action" => HistoryItemType::Action,
+            _ => HistoryItemType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for HistoryItemType {
+    fn from(value: String) -> HistoryItemType {
+        match value.as_str() {
+            "ConfigurationUpdate" => HistoryItemType::ConfigurationUpdate,
+            "StateUpdate" => HistoryItemType::StateUpdate,
+            "Action" => HistoryItemType::Action,
+            _ => HistoryItemType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for HistoryItemType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<HistoryItemType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for HistoryItemType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for HistoryItemType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for HistoryItemType {
+    fn deserialize<D>(deserializer: D) -> Result<HistoryItemType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct HistoryItemTypeDeserializer;
 impl HistoryItemTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<HistoryItemType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -2256,7 +2615,7 @@ pub struct InsightRuleContributorDatapoint {
     /// <p>The approximate value that this contributor added during this timestamp.</p>
     pub approximate_value: f64,
     /// <p>The timestamp of the data point.</p>
-    pub timestamp: String,
+    pub timestamp: ::chrono::DateTime<::chrono::Utc>,
 }
 
 struct InsightRuleContributorDatapointDeserializer;
@@ -2413,7 +2772,7 @@ pub struct InsightRuleMetricDatapoint {
     /// <p>The sum of the values from all contributors during the time period represented by that data point.</p> <p>This statistic is returned only if you included it in the <code>Metrics</code> array in your request.</p>
     pub sum: Option<f64>,
     /// <p>The timestamp of the data point.</p>
-    pub timestamp: String,
+    pub timestamp: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The number of unique contributors who published data during this timestamp.</p> <p>This statistic is returned only if you included it in the <code>Metrics</code> array in your request.</p>
     pub unique_contributors: Option<f64>,
 }
@@ -2598,9 +2957,12 @@ impl InsightRulesDeserializer {
 struct LastModifiedDeserializer;
 impl LastModifiedDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<::chrono::DateTime<::chrono::Utc>, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::proto::xml::util::timestamp_characters(stack)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -2930,13 +3292,13 @@ pub struct MetricAlarm {
     /// <p>The Amazon Resource Name (ARN) of the alarm.</p>
     pub alarm_arn: Option<String>,
     /// <p>The time stamp of the last update to the alarm configuration.</p>
-    pub alarm_configuration_updated_timestamp: Option<String>,
+    pub alarm_configuration_updated_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The description of the alarm.</p>
     pub alarm_description: Option<String>,
     /// <p>The name of the alarm.</p>
     pub alarm_name: Option<String>,
     /// <p>The arithmetic operation to use when comparing the specified statistic and threshold. The specified statistic value is used as the first operand.</p>
-    pub comparison_operator: Option<String>,
+    pub comparison_operator: Option<ComparisonOperator>,
     /// <p>The number of data points that must be breaching to trigger the alarm.</p>
     pub datapoints_to_alarm: Option<i64>,
     /// <p>The dimensions for the metric associated with the alarm.</p>
@@ -2964,11 +3326,11 @@ pub struct MetricAlarm {
     /// <p>An explanation for the alarm state, in JSON format.</p>
     pub state_reason_data: Option<String>,
     /// <p>The time stamp of the last update to the alarm state.</p>
-    pub state_updated_timestamp: Option<String>,
+    pub state_updated_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The state value for the alarm.</p>
-    pub state_value: Option<String>,
+    pub state_value: Option<StateValue>,
     /// <p>The statistic for the metric associated with the alarm, other than percentile. For percentile statistics, use <code>ExtendedStatistic</code>.</p>
-    pub statistic: Option<String>,
+    pub statistic: Option<Statistic>,
     /// <p>The value to compare with the specified statistic.</p>
     pub threshold: Option<f64>,
     /// <p>In an alarm based on an anomaly detection model, this is the ID of the <code>ANOMALY_DETECTION_BAND</code> function used as the threshold for the alarm.</p>
@@ -2976,7 +3338,7 @@ pub struct MetricAlarm {
     /// <p>Sets how this alarm is to handle missing data points. If this parameter is omitted, the default behavior of <code>missing</code> is used.</p>
     pub treat_missing_data: Option<String>,
     /// <p>The unit of the metric associated with the alarm.</p>
-    pub unit: Option<String>,
+    pub unit: Option<StandardUnit>,
 }
 
 struct MetricAlarmDeserializer;
@@ -3285,9 +3647,9 @@ pub struct MetricDataResult {
     /// <p>A list of messages with additional information about the data returned.</p>
     pub messages: Option<Vec<MessageData>>,
     /// <p>The status of the returned data. <code>Complete</code> indicates that all data points in the requested time range were returned. <code>PartialData</code> means that an incomplete set of data points were returned. You can use the <code>NextToken</code> value that was returned and repeat your request to get more data points. <code>NextToken</code> is not returned if you are performing a math expression. <code>InternalError</code> indicates that an error occurred. Retry your request using <code>NextToken</code>, if present.</p>
-    pub status_code: Option<String>,
+    pub status_code: Option<StatusCode>,
     /// <p>The timestamps for the data points, formatted in Unix timestamp format. The number of timestamps always matches the number of values and the value for Timestamps[x] is Values[x].</p>
-    pub timestamps: Option<Vec<String>>,
+    pub timestamps: Option<Vec<::chrono::DateTime<::chrono::Utc>>>,
     /// <p>The data points for the metric corresponding to <code>Timestamps</code>. The number of values always matches the number of timestamps and the timestamp for Values[x] is Timestamps[x].</p>
     pub values: Option<Vec<f64>>,
 }
@@ -3381,9 +3743,9 @@ pub struct MetricDatum {
     /// <p>Valid values are 1 and 60. Setting this to 1 specifies this metric as a high-resolution metric, so that CloudWatch stores the metric with sub-minute resolution down to one second. Setting this to 60 specifies this metric as a regular-resolution metric, which CloudWatch stores at 1-minute resolution. Currently, high resolution is available only for custom metrics. For more information about high-resolution metrics, see <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/monitoring/publishingMetrics.html#high-resolution-metrics">High-Resolution Metrics</a> in the <i>Amazon CloudWatch User Guide</i>. </p> <p>This field is optional, if you do not specify it the default of 60 is used.</p>
     pub storage_resolution: Option<i64>,
     /// <p>The time the metric data was received, expressed as the number of milliseconds since Jan 1, 1970 00:00:00 UTC.</p>
-    pub timestamp: Option<String>,
+    pub timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>When you are using a <code>Put</code> operation, this defines what unit you want to use when storing the metric.</p> <p>In a <code>Get</code> operation, this displays the unit that is used for the metric.</p>
-    pub unit: Option<String>,
+    pub unit: Option<StandardUnit>,
     /// <p>The value for the metric.</p> <p>Although the parameter accepts numbers of type Double, CloudWatch rejects values that are either too small or too large. Values must be in the range of -2^360 to 2^360. In addition, special values (for example, NaN, +Infinity, -Infinity) are not supported.</p>
     pub value: Option<f64>,
     /// <p>Array of numbers representing the values for the metric during the period. Each unique value is listed just once in this array, and the corresponding number in the <code>Counts</code> array specifies the number of times that value occurred during the period. You can include up to 150 unique values in each <code>PutMetricData</code> action that specifies a <code>Values</code> array.</p> <p>Although the <code>Values</code> array accepts numbers of type <code>Double</code>, CloudWatch rejects values that are either too small or too large. Values must be in the range of -2^360 to 2^360. In addition, special values (for example, NaN, +Infinity, -Infinity) are not supported.</p>
@@ -3421,7 +3783,10 @@ impl MetricDatumSerializer {
             params.put(&format!("{}{}", prefix, "StorageResolution"), &field_value);
         }
         if let Some(ref field_value) = obj.timestamp {
-            params.put(&format!("{}{}", prefix, "Timestamp"), &field_value);
+            params.put(
+                &format!("{}{}", prefix, "Timestamp"),
+                &rusoto_core::serialization::TimestampFormat::Iso8601.format(field_value),
+            );
         }
         if let Some(ref field_value) = obj.unit {
             params.put(&format!("{}{}", prefix, "Unit"), &field_value);
@@ -3491,7 +3856,7 @@ pub struct MetricStat {
     /// <p>The statistic to return. It can include any CloudWatch statistic or extended statistic.</p>
     pub stat: String,
     /// <p>When you are using a <code>Put</code> operation, this defines what unit you want to use when storing the metric.</p> <p>In a <code>Get</code> operation, if you omit <code>Unit</code> then all data that was collected with any unit is returned, along with the corresponding units that were specified when the data was reported to CloudWatch. If you specify a unit, the operation returns only data data that was collected with that unit specified. If you specify a unit that does not match the data collected, the results of the operation are null. CloudWatch does not perform unit conversions.</p>
-    pub unit: Option<String>,
+    pub unit: Option<StandardUnit>,
 }
 
 struct MetricStatDeserializer;
@@ -3841,7 +4206,7 @@ pub struct PutMetricAlarmInput {
     /// <p>The name for the alarm. This name must be unique within your AWS account.</p>
     pub alarm_name: String,
     /// <p> The arithmetic operation to use when comparing the specified statistic and threshold. The specified statistic value is used as the first operand.</p> <p>The values <code>LessThanLowerOrGreaterThanUpperThreshold</code>, <code>LessThanLowerThreshold</code>, and <code>GreaterThanUpperThreshold</code> are used only for alarms based on anomaly detection models.</p>
-    pub comparison_operator: String,
+    pub comparison_operator: ComparisonOperator,
     /// <p>The number of data points that must be breaching to trigger the alarm. This is used only if you are setting an "M out of N" alarm. In that case, this value is the M. For more information, see <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/monitoring/AlarmThatSendsEmail.html#alarm-evaluation">Evaluating an Alarm</a> in the <i>Amazon CloudWatch User Guide</i>.</p>
     pub datapoints_to_alarm: Option<i64>,
     /// <p>The dimensions for the metric specified in <code>MetricName</code>.</p>
@@ -3865,7 +4230,7 @@ pub struct PutMetricAlarmInput {
     /// <p>The length, in seconds, used each time the metric specified in <code>MetricName</code> is evaluated. Valid values are 10, 30, and any multiple of 60.</p> <p> <code>Period</code> is required for alarms based on static thresholds. If you are creating an alarm based on a metric math expression, you specify the period for each metric within the objects in the <code>Metrics</code> array.</p> <p>Be sure to specify 10 or 30 only for metrics that are stored by a <code>PutMetricData</code> call with a <code>StorageResolution</code> of 1. If you specify a period of 10 or 30 for a metric that does not have sub-minute resolution, the alarm still attempts to gather data at the period rate that you specify. In this case, it does not receive data for the attempts that do not correspond to a one-minute data resolution, and the alarm may often lapse into INSUFFICENT_DATA status. Specifying 10 or 30 also sets this alarm as a high-resolution alarm, which has a higher charge than other alarms. For more information about pricing, see <a href="https://aws.amazon.com/cloudwatch/pricing/">Amazon CloudWatch Pricing</a>.</p> <p>An alarm's total current evaluation period can be no longer than one day, so <code>Period</code> multiplied by <code>EvaluationPeriods</code> cannot be more than 86,400 seconds.</p>
     pub period: Option<i64>,
     /// <p>The statistic for the metric specified in <code>MetricName</code>, other than percentile. For percentile statistics, use <code>ExtendedStatistic</code>. When you call <code>PutMetricAlarm</code> and specify a <code>MetricName</code>, you must specify either <code>Statistic</code> or <code>ExtendedStatistic,</code> but not both.</p>
-    pub statistic: Option<String>,
+    pub statistic: Option<Statistic>,
     /// <p>A list of key-value pairs to associate with the alarm. You can associate as many as 50 tags with an alarm.</p> <p>Tags can help you organize and categorize your resources. You can also use them to scope user permissions, by granting a user permission to access or change only resources with certain tag values.</p>
     pub tags: Option<Vec<Tag>>,
     /// <p>The value against which the specified statistic is compared.</p> <p>This parameter is required for alarms based on static thresholds, but should not be used for alarms based on anomaly detection models.</p>
@@ -3875,7 +4240,7 @@ pub struct PutMetricAlarmInput {
     /// <p> Sets how this alarm is to handle missing data points. If <code>TreatMissingData</code> is omitted, the default behavior of <code>missing</code> is used. For more information, see <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/monitoring/AlarmThatSendsEmail.html#alarms-and-missing-data">Configuring How CloudWatch Alarms Treats Missing Data</a>.</p> <p>Valid Values: <code>breaching | notBreaching | ignore | missing</code> </p>
     pub treat_missing_data: Option<String>,
     /// <p>The unit of measure for the statistic. For example, the units for the Amazon EC2 NetworkIn metric are Bytes because NetworkIn tracks the number of bytes that an instance receives on all network interfaces. You can also specify a unit when you create a custom metric. Units help provide conceptual meaning to your data. Metric data points that specify a unit of measure, such as Percent, are aggregated separately.</p> <p>If you don't specify <code>Unit</code>, CloudWatch retrieves all unit types that have been published for the metric and attempts to evaluate the alarm. Usually metrics are published with only one unit, so the alarm will work as intended.</p> <p>However, if the metric is published with multiple types of units and you don't specify a unit, the alarm's behavior is not defined and will behave un-predictably.</p> <p>We recommend omitting <code>Unit</code> so that you don't inadvertently specify an incorrect unit that is not published for this metric. Doing so causes the alarm to be stuck in the <code>INSUFFICIENT DATA</code> state.</p>
-    pub unit: Option<String>,
+    pub unit: Option<StandardUnit>,
 }
 
 /// Serialize `PutMetricAlarmInput` contents to a `SignedRequest`.
@@ -4012,9 +4377,9 @@ impl PutMetricDataInputSerializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct Range {
     /// <p>The end time of the range to exclude. The format is <code>yyyy-MM-dd'T'HH:mm:ss</code>. For example, <code>2019-07-01T23:59:59</code>.</p>
-    pub end_time: String,
+    pub end_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The start time of the range to exclude. The format is <code>yyyy-MM-dd'T'HH:mm:ss</code>. For example, <code>2019-07-01T23:59:59</code>.</p>
-    pub start_time: String,
+    pub start_time: ::chrono::DateTime<::chrono::Utc>,
 }
 
 struct RangeDeserializer;
@@ -4045,8 +4410,14 @@ impl RangeSerializer {
             prefix.push_str(".");
         }
 
-        params.put(&format!("{}{}", prefix, "EndTime"), &obj.end_time);
-        params.put(&format!("{}{}", prefix, "StartTime"), &obj.start_time);
+        params.put(
+            &format!("{}{}", prefix, "EndTime"),
+            &rusoto_core::serialization::TimestampFormat::Iso8601.format(&obj.end_time),
+        );
+        params.put(
+            &format!("{}{}", prefix, "StartTime"),
+            &rusoto_core::serialization::TimestampFormat::Iso8601.format(&obj.start_time),
+        );
     }
 }
 
@@ -4101,6 +4472,101 @@ impl ReturnDataDeserializer {
         Ok(obj)
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ScanBy {
+    /// `TimestampDescending`
+    TimestampDescending,
+    /// `TimestampAscending`
+    TimestampAscending,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ScanBy {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ScanBy::TimestampDescending => "TimestampDescending",
+            ScanBy::TimestampAscending => "TimestampAscending",
+            ScanBy::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ScanBy {
+    fn default() -> ScanBy {
+        ScanBy::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ScanBy {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ScanBy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ScanBy {
+    fn from(value: &'a str) -> ScanBy {
+        match value {
+            "TimestampDescending" => ScanBy::TimestampDescending,
+            "TimestampAscending" => ScanBy::TimestampAscending,
+            _ => ScanBy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ScanBy {
+    fn from(value: String) -> ScanBy {
+        match value.as_str() {
+            "TimestampDescending" => ScanBy::TimestampDescending,
+            "TimestampAscending" => ScanBy::TimestampAscending,
+            _ => ScanBy::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ScanBy {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ScanBy, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ScanBy {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ScanBy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ScanBy {
+    fn deserialize<D>(deserializer: D) -> Result<ScanBy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct SetAlarmStateInput {
@@ -4111,7 +4577,7 @@ pub struct SetAlarmStateInput {
     /// <p>The reason that this alarm is set to this specific state, in JSON format.</p>
     pub state_reason_data: Option<String>,
     /// <p>The value of the state.</p>
-    pub state_value: String,
+    pub state_value: StateValue,
 }
 
 /// Serialize `SetAlarmStateInput` contents to a `SignedRequest`.
@@ -4143,12 +4609,235 @@ impl SizeDeserializer {
         Ok(obj)
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StandardUnit {
+    /// `Seconds`
+    Seconds,
+    /// `Microseconds`
+    Microseconds,
+    /// `Milliseconds`
+    Milliseconds,
+    /// `Bytes`
+    Bytes,
+    /// `Kilobytes`
+    Kilobytes,
+    /// `Megabytes`
+    Megabytes,
+    /// `Gigabytes`
+    Gigabytes,
+    /// `Terabytes`
+    Terabytes,
+    /// `Bits`
+    Bits,
+    /// `Kilobits`
+    Kilobits,
+    /// `Megabits`
+    Megabits,
+    /// `Gigabits`
+    Gigabits,
+    /// `Terabits`
+    Terabits,
+    /// `Percent`
+    Percent,
+    /// `Count`
+    Count,
+    /// `Bytes/Second`
+    BytesSecond,
+    /// `Kilobytes/Second`
+    KilobytesSecond,
+    /// `Megabytes/Second`
+    MegabytesSecond,
+    /// `Gigabytes/Second`
+    GigabytesSecond,
+    /// `Terabytes/Second`
+    TerabytesSecond,
+    /// `Bits/Second`
+    BitsSecond,
+    /// `Kilobits/Second`
+    KilobitsSecond,
+    /// `Megabits/Second`
+    MegabitsSecond,
+    /// `Gigabits/Second`
+    GigabitsSecond,
+    /// `Terabits/Second`
+    TerabitsSecond,
+    /// `Count/Second`
+    CountSecond,
+    /// `None`
+    None,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl StandardUnit {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StandardUnit::Seconds => "Seconds",
+            StandardUnit::Microseconds => "Microseconds",
+            StandardUnit::Milliseconds => "Milliseconds",
+            StandardUnit::Bytes => "Bytes",
+            StandardUnit::Kilobytes => "Kilobytes",
+            StandardUnit::Megabytes => "Megabytes",
+            StandardUnit::Gigabytes => "Gigabytes",
+            StandardUnit::Terabytes => "Terabytes",
+            StandardUnit::Bits => "Bits",
+            StandardUnit::Kilobits => "Kilobits",
+            StandardUnit::Megabits => "Megabits",
+            StandardUnit::Gigabits => "Gigabits",
+            StandardUnit::Terabits => "Terabits",
+            StandardUnit::Percent => "Percent",
+            StandardUnit::Count => "Count",
+            StandardUnit::BytesSecond => "Bytes/Second",
+            StandardUnit::KilobytesSecond => "Kilobytes/Second",
+            StandardUnit::MegabytesSecond => "Megabytes/Second",
+            StandardUnit::GigabytesSecond => "Gigabytes/Second",
+            StandardUnit::TerabytesSecond => "Terabytes/Second",
+            StandardUnit::BitsSecond => "Bits/Second",
+            StandardUnit::KilobitsSecond => "Kilobits/Second",
+            StandardUnit::MegabitsSecond => "Megabits/Second",
+            StandardUnit::GigabitsSecond => "Gigabits/Second",
+            StandardUnit::TerabitsSecond => "Terabits/Second",
+            StandardUnit::CountSecond => "Count/Second",
+            StandardUnit::None => "None",
+            StandardUnit::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for StandardUnit {
+    fn default() -> StandardUnit {
+        StandardUnit::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for StandardUnit {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for StandardUnit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for StandardUnit {
+    fn from(value: &'a str) -> StandardUnit {
+        match value {
+            "Seconds" => StandardUnit::Seconds,
+            "Microseconds" => StandardUnit::Microseconds,
+            "Milliseconds" => StandardUnit::Milliseconds,
+            "Bytes" => StandardUnit::Bytes,
+            "Kilobytes" => StandardUnit::Kilobytes,
+            "Megabytes" => StandardUnit::Megabytes,
+            "Gigabytes" => StandardUnit::Gigabytes,
+            "Terabytes" => StandardUnit::Terabytes,
+            "Bits" => StandardUnit::Bits,
+            "Kilobits" => StandardUnit::Kilobits,
+            "Megabits" => StandardUnit::Megabits,
+            "Gigabits" => StandardUnit::Gigabits,
+            "Terabits" => StandardUnit::Terabits,
+            "Percent" => StandardUnit::Percent,
+            "Count" => StandardUnit::Count,
+            "Bytes/Second" => StandardUnit::BytesSecond,
+            "Kilobytes/Second" => StandardUnit::KilobytesSecond,
+            "Megabytes/Second" => StandardUnit::MegabytesSecond,
+            "Gigabytes/Second" => StandardUnit::GigabytesSecond,
+            "Terabytes/Second" => StandardUnit::TerabytesSecond,
+            "Bits/Second" => StandardUnit::BitsSecond,
+            "Kilobits/Second" => StandardUnit::KilobitsSecond,
+            "Megabits/Second" => StandardUnit::MegabitsSecond,
+            "Gigabits/Second" => StandardUnit::GigabitsSecond,
+            "Terabits/Second" => StandardUnit::TerabitsSecond,
+            "Count/Second" => StandardUnit::CountSecond,
+            "None" => StandardUnit::None,
+            _ => StandardUnit::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StandardUnit {
+    fn from(value: String) -> StandardUnit {
+        match value.as_str() {
+            "Seconds" => StandardUnit::Seconds,
+            "Microseconds" => StandardUnit::Microseconds,
+            "Milliseconds" => StandardUnit::Milliseconds,
+            "Bytes" => StandardUnit::Bytes,
+            "Kilobytes" => StandardUnit::Kilobytes,
+            "Megabytes" => StandardUnit::Megabytes,
+            "Gigabytes" => StandardUnit::Gigabytes,
+            "Terabytes" => StandardUnit::Terabytes,
+            "Bits" => StandardUnit::Bits,
+            "Kilobits" => StandardUnit::Kilobits,
+            "Megabits" => StandardUnit::Megabits,
+            "Gigabits" => StandardUnit::Gigabits,
+            "Terabits" => StandardUnit::Terabits,
+            "Percent" => StandardUnit::Percent,
+            "Count" => StandardUnit::Count,
+            "Bytes/Second" => StandardUnit::BytesSecond,
+            "Kilobytes/Second" => StandardUnit::KilobytesSecond,
+            "Megabytes/Second" => StandardUnit::MegabytesSecond,
+            "Gigabytes/Second" => StandardUnit::GigabytesSecond,
+            "Terabytes/Second" => StandardUnit::TerabytesSecond,
+            "Bits/Second" => StandardUnit::BitsSecond,
+            "Kilobits/Second" => StandardUnit::KilobitsSecond,
+            "Megabits/Second" => StandardUnit::MegabitsSecond,
+            "Gigabits/Second" => StandardUnit::GigabitsSecond,
+            "Terabits/Second" => StandardUnit::TerabitsSecond,
+            "Count/Second" => StandardUnit::CountSecond,
+            "None" => StandardUnit::None,
+            _ => StandardUnit::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for StandardUnit {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<StandardUnit, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StandardUnit {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for StandardUnit {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for StandardUnit {
+    fn deserialize<D>(deserializer: D) -> Result<StandardUnit, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct StandardUnitDeserializer;
 impl StandardUnitDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<StandardUnit, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -4187,23 +4876,239 @@ impl StateReasonDataDeserializer {
         Ok(obj)
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StateValue {
+    /// `OK`
+    Ok,
+    /// `ALARM`
+    Alarm,
+    /// `INSUFFICIENT_DATA`
+    InsufficientData,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl StateValue {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StateValue::Ok => "OK",
+            StateValue::Alarm => "ALARM",
+            StateValue::InsufficientData => "INSUFFICIENT_DATA",
+            StateValue::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for StateValue {
+    fn default() -> StateValue {
+        StateValue::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for StateValue {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for StateValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for StateValue {
+    fn from(value: &'a str) -> StateValue {
+        match value {
+            "OK" => StateValue::Ok,
+            "ALARM" => StateValue::Alarm,
+            "INSUFFICIENT_DATA" => StateValue::InsufficientData,
+            _ => StateValue::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StateValue {
+    fn from(value: String) -> StateValue {
+        match value.as_str() {
+            "OK" => StateValue::Ok,
+            "ALARM" => StateValue::Alarm,
+            "INSUFFICIENT_DATA" => StateValue::InsufficientData,
+            _ => StateValue::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for StateValue {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<StateValue, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StateValue {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for StateValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for StateValue {
+    fn deserialize<D>(deserializer: D) -> Result<StateValue, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct StateValueDeserializer;
 impl StateValueDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<StateValue, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Statistic {
+    /// `SampleCount`
+    SampleCount,
+    /// `Average`
+    Average,
+    /// `Sum`
+    Sum,
+    /// `Minimum`
+    Minimum,
+    /// `Maximum`
+    Maximum,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Statistic {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Statistic::SampleCount => "SampleCount",
+            Statistic::Average => "Average",
+            Statistic::Sum => "Sum",
+            Statistic::Minimum => "Minimum",
+            Statistic::Maximum => "Maximum",
+            Statistic::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Statistic {
+    fn default() -> Statistic {
+        Statistic::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Statistic {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Statistic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Statistic {
+    fn from(value: &'a str) -> Statistic {
+        match value {
+            "SampleCount" => Statistic::SampleCount,
+            "Average" => Statistic::Average,
+            "Sum" => Statistic::Sum,
+            "Minimum" => Statistic::Minimum,
+            "Maximum" => Statistic::Maximum,
+            _ => Statistic::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Statistic {
+    fn from(value: String) -> Statistic {
+        match value.as_str() {
+            "SampleCount" => Statistic::SampleCount,
+            "Average" => Statistic::Average,
+            "Sum" => Statistic::Sum,
+            "Minimum" => Statistic::Minimum,
+            "Maximum" => Statistic::Maximum,
+            _ => Statistic::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Statistic {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Statistic, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Statistic {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for Statistic {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for Statistic {
+    fn deserialize<D>(deserializer: D) -> Result<Statistic, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct StatisticDeserializer;
 impl StatisticDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Statistic, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -4242,7 +5147,7 @@ impl StatisticSetSerializer {
 /// Serialize `Statistics` contents to a `SignedRequest`.
 struct StatisticsSerializer;
 impl StatisticsSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &Vec<String>) {
+    fn serialize(params: &mut Params, name: &str, obj: &Vec<Statistic>) {
         for (index, obj) in obj.iter().enumerate() {
             let key = format!("{}.member.{}", name, index + 1);
             params.put(&key, &obj);
@@ -4250,12 +5155,120 @@ impl StatisticsSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StatusCode {
+    /// `Complete`
+    Complete,
+    /// `InternalError`
+    InternalError,
+    /// `PartialData`
+    PartialData,
+    /// `Forbidden`
+    Forbidden,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl StatusCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StatusCode::Complete => "Complete",
+            StatusCode::InternalError => "InternalError",
+            StatusCode::PartialData => "PartialData",
+            StatusCode::Forbidden => "Forbidden",
+            StatusCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for StatusCode {
+    fn default() -> StatusCode {
+        StatusCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for StatusCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for StatusCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for StatusCode {
+    fn from(value: &'a str) -> StatusCode {
+        match value {
+            "Complete" => StatusCode::Complete,
+            "InternalError" => StatusCode::InternalError,
+            "PartialData" => StatusCode::PartialData,
+            "Forbidden" => StatusCode::Forbidden,
+            _ => StatusCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StatusCode {
+    fn from(value: String) -> StatusCode {
+        match value.as_str() {
+            "Complete" => StatusCode::Complete,
+            "InternalError" => StatusCode::InternalError,
+            "PartialData" => StatusCode::PartialData,
+            "Forbidden" => StatusCode::Forbidden,
+            _ => StatusCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for StatusCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<StatusCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StatusCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for StatusCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for StatusCode {
+    fn deserialize<D>(deserializer: D) -> Result<StatusCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct StatusCodeDeserializer;
 impl StatusCodeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<StatusCode, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -4425,9 +5438,12 @@ impl ThresholdDeserializer {
 struct TimestampDeserializer;
 impl TimestampDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<::chrono::DateTime<::chrono::Utc>, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::proto::xml::util::timestamp_characters(stack)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -4439,7 +5455,7 @@ impl TimestampsDeserializer {
     fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
-    ) -> Result<Vec<String>, XmlParseError> {
+    ) -> Result<Vec<::chrono::DateTime<::chrono::Utc>>, XmlParseError> {
         deserialize_elements::<_, Vec<_>, _>(tag_name, stack, |name, stack, obj| {
             if name == "member" {
                 obj.push(TimestampDeserializer::deserialize("member", stack)?);
@@ -6267,11 +7283,12 @@ pub struct CloudWatchClient {
 impl CloudWatchClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> CloudWatchClient {
         CloudWatchClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "CloudWatch"),
         }
     }
 
@@ -6303,6 +7320,7 @@ impl CloudWatch for CloudWatchClient {
         input: DeleteAlarmsInput,
     ) -> Result<(), RusotoError<DeleteAlarmsError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("DeleteAlarms");
         let mut params = Params::new();
 
         params.put("Action", "DeleteAlarms");
@@ -6331,6 +7349,7 @@ impl CloudWatch for CloudWatchClient {
         input: DeleteAnomalyDetectorInput,
     ) -> Result<DeleteAnomalyDetectorOutput, RusotoError<DeleteAnomalyDetectorError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("DeleteAnomalyDetector");
         let mut params = Params::new();
 
         params.put("Action", "DeleteAnomalyDetector");
@@ -6380,6 +7399,7 @@ impl CloudWatch for CloudWatchClient {
         input: DeleteDashboardsInput,
     ) -> Result<DeleteDashboardsOutput, RusotoError<DeleteDashboardsError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("DeleteDashboards");
         let mut params = Params::new();
 
         params.put("Action", "DeleteDashboards");
@@ -6429,6 +7449,7 @@ impl CloudWatch for CloudWatchClient {
         input: DeleteInsightRulesInput,
     ) -> Result<DeleteInsightRulesOutput, RusotoError<DeleteInsightRulesError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("DeleteInsightRules");
         let mut params = Params::new();
 
         params.put("Action", "DeleteInsightRules");
@@ -6478,6 +7499,7 @@ impl CloudWatch for CloudWatchClient {
         input: DescribeAlarmHistoryInput,
     ) -> Result<DescribeAlarmHistoryOutput, RusotoError<DescribeAlarmHistoryError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("DescribeAlarmHistory");
         let mut params = Params::new();
 
         params.put("Action", "DescribeAlarmHistory");
@@ -6527,6 +7549,7 @@ impl CloudWatch for CloudWatchClient {
         input: DescribeAlarmsInput,
     ) -> Result<DescribeAlarmsOutput, RusotoError<DescribeAlarmsError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("DescribeAlarms");
         let mut params = Params::new();
 
         params.put("Action", "DescribeAlarms");
@@ -6574,6 +7597,7 @@ impl CloudWatch for CloudWatchClient {
         input: DescribeAlarmsForMetricInput,
     ) -> Result<DescribeAlarmsForMetricOutput, RusotoError<DescribeAlarmsForMetricError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("DescribeAlarmsForMetric");
         let mut params = Params::new();
 
         params.put("Action", "DescribeAlarmsForMetric");
@@ -6623,6 +7647,7 @@ impl CloudWatch for CloudWatchClient {
         input: DescribeAnomalyDetectorsInput,
     ) -> Result<DescribeAnomalyDetectorsOutput, RusotoError<DescribeAnomalyDetectorsError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("DescribeAnomalyDetectors");
         let mut params = Params::new();
 
         params.put("Action", "DescribeAnomalyDetectors");
@@ -6672,6 +7697,7 @@ impl CloudWatch for CloudWatchClient {
         input: DescribeInsightRulesInput,
     ) -> Result<DescribeInsightRulesOutput, RusotoError<DescribeInsightRulesError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("DescribeInsightRules");
         let mut params = Params::new();
 
         params.put("Action", "DescribeInsightRules");
@@ -6721,6 +7747,7 @@ impl CloudWatch for CloudWatchClient {
         input: DisableAlarmActionsInput,
     ) -> Result<(), RusotoError<DisableAlarmActionsError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("DisableAlarmActions");
         let mut params = Params::new();
 
         params.put("Action", "DisableAlarmActions");
@@ -6749,6 +7776,7 @@ impl CloudWatch for CloudWatchClient {
         input: DisableInsightRulesInput,
     ) -> Result<DisableInsightRulesOutput, RusotoError<DisableInsightRulesError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("DisableInsightRules");
         let mut params = Params::new();
 
         params.put("Action", "DisableInsightRules");
@@ -6798,6 +7826,7 @@ impl CloudWatch for CloudWatchClient {
         input: EnableAlarmActionsInput,
     ) -> Result<(), RusotoError<EnableAlarmActionsError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("EnableAlarmActions");
         let mut params = Params::new();
 
         params.put("Action", "EnableAlarmActions");
@@ -6826,6 +7855,7 @@ impl CloudWatch for CloudWatchClient {
         input: EnableInsightRulesInput,
     ) -> Result<EnableInsightRulesOutput, RusotoError<EnableInsightRulesError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("EnableInsightRules");
         let mut params = Params::new();
 
         params.put("Action", "EnableInsightRules");
@@ -6875,6 +7905,7 @@ impl CloudWatch for CloudWatchClient {
         input: GetDashboardInput,
     ) -> Result<GetDashboardOutput, RusotoError<GetDashboardError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("GetDashboard");
         let mut params = Params::new();
 
         params.put("Action", "GetDashboard");
@@ -6921,6 +7952,7 @@ impl CloudWatch for CloudWatchClient {
         input: GetInsightRuleReportInput,
     ) -> Result<GetInsightRuleReportOutput, RusotoError<GetInsightRuleReportError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("GetInsightRuleReport");
         let mut params = Params::new();
 
         params.put("Action", "GetInsightRuleReport");
@@ -6970,6 +8002,7 @@ impl CloudWatch for CloudWatchClient {
         input: GetMetricDataInput,
     ) -> Result<GetMetricDataOutput, RusotoError<GetMetricDataError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("GetMetricData");
         let mut params = Params::new();
 
         params.put("Action", "GetMetricData");
@@ -7017,6 +8050,7 @@ impl CloudWatch for CloudWatchClient {
         input: GetMetricStatisticsInput,
     ) -> Result<GetMetricStatisticsOutput, RusotoError<GetMetricStatisticsError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("GetMetricStatistics");
         let mut params = Params::new();
 
         params.put("Action", "GetMetricStatistics");
@@ -7066,6 +8100,7 @@ impl CloudWatch for CloudWatchClient {
         input: GetMetricWidgetImageInput,
     ) -> Result<GetMetricWidgetImageOutput, RusotoError<GetMetricWidgetImageError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("GetMetricWidgetImage");
         let mut params = Params::new();
 
         params.put("Action", "GetMetricWidgetImage");
@@ -7115,6 +8150,7 @@ impl CloudWatch for CloudWatchClient {
         input: ListDashboardsInput,
     ) -> Result<ListDashboardsOutput, RusotoError<ListDashboardsError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("ListDashboards");
         let mut params = Params::new();
 
         params.put("Action", "ListDashboards");
@@ -7162,6 +8198,7 @@ impl CloudWatch for CloudWatchClient {
         input: ListMetricsInput,
     ) -> Result<ListMetricsOutput, RusotoError<ListMetricsError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("ListMetrics");
         let mut params = Params::new();
 
         params.put("Action", "ListMetrics");
@@ -7208,6 +8245,7 @@ impl CloudWatch for CloudWatchClient {
         input: ListTagsForResourceInput,
     ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("ListTagsForResource");
         let mut params = Params::new();
 
         params.put("Action", "ListTagsForResource");
@@ -7257,6 +8295,7 @@ impl CloudWatch for CloudWatchClient {
         input: PutAnomalyDetectorInput,
     ) -> Result<PutAnomalyDetectorOutput, RusotoError<PutAnomalyDetectorError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("PutAnomalyDetector");
         let mut params = Params::new();
 
         params.put("Action", "PutAnomalyDetector");
@@ -7306,6 +8345,7 @@ impl CloudWatch for CloudWatchClient {
         input: PutDashboardInput,
     ) -> Result<PutDashboardOutput, RusotoError<PutDashboardError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("PutDashboard");
         let mut params = Params::new();
 
         params.put("Action", "PutDashboard");
@@ -7352,6 +8392,7 @@ impl CloudWatch for CloudWatchClient {
         input: PutInsightRuleInput,
     ) -> Result<PutInsightRuleOutput, RusotoError<PutInsightRuleError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("PutInsightRule");
         let mut params = Params::new();
 
         params.put("Action", "PutInsightRule");
@@ -7399,6 +8440,7 @@ impl CloudWatch for CloudWatchClient {
         input: PutMetricAlarmInput,
     ) -> Result<(), RusotoError<PutMetricAlarmError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("PutMetricAlarm");
         let mut params = Params::new();
 
         params.put("Action", "PutMetricAlarm");
@@ -7427,6 +8469,7 @@ impl CloudWatch for CloudWatchClient {
         input: PutMetricDataInput,
     ) -> Result<(), RusotoError<PutMetricDataError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("PutMetricData");
         let mut params = Params::new();
 
         params.put("Action", "PutMetricData");
@@ -7455,6 +8498,7 @@ impl CloudWatch for CloudWatchClient {
         input: SetAlarmStateInput,
     ) -> Result<(), RusotoError<SetAlarmStateError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("SetAlarmState");
         let mut params = Params::new();
 
         params.put("Action", "SetAlarmState");
@@ -7483,6 +8527,7 @@ impl CloudWatch for CloudWatchClient {
         input: TagResourceInput,
     ) -> Result<TagResourceOutput, RusotoError<TagResourceError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("TagResource");
         let mut params = Params::new();
 
         params.put("Action", "TagResource");
@@ -7529,6 +8574,7 @@ impl CloudWatch for CloudWatchClient {
         input: UntagResourceInput,
     ) -> Result<UntagResourceOutput, RusotoError<UntagResourceError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("UntagResource");
         let mut params = Params::new();
 
         params.put("Action", "UntagResource");
@@ -7570,6 +8616,166 @@ impl CloudWatch for CloudWatchClient {
         Ok(result)
     }
 }
+impl CloudWatchClient {
+    /// Auto-paginating version of `describe_alarm_history`, streaming every page of results.
+    pub fn describe_alarm_history_pages(
+        &self,
+        input: DescribeAlarmHistoryInput,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeAlarmHistoryOutput, DescribeAlarmHistoryError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudWatchClient, input: DescribeAlarmHistoryInput| async move {
+                client.describe_alarm_history(input).await
+            },
+            |input: &DescribeAlarmHistoryInput, output: &DescribeAlarmHistoryOutput| {
+                Some(DescribeAlarmHistoryInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_alarm_history`, streaming the `AlarmHistoryItems` of every page of results.
+    pub fn describe_alarm_history_items(
+        &self,
+        input: DescribeAlarmHistoryInput,
+    ) -> rusoto_core::pagination::RusotoStream<AlarmHistoryItem, DescribeAlarmHistoryError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_alarm_history_pages(input),
+            |output: DescribeAlarmHistoryOutput| output.alarm_history_items.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_anomaly_detectors`, streaming every page of results.
+    pub fn describe_anomaly_detectors_pages(
+        &self,
+        input: DescribeAnomalyDetectorsInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeAnomalyDetectorsOutput,
+        DescribeAnomalyDetectorsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudWatchClient, input: DescribeAnomalyDetectorsInput| async move {
+                client.describe_anomaly_detectors(input).await
+            },
+            |input: &DescribeAnomalyDetectorsInput, output: &DescribeAnomalyDetectorsOutput| {
+                Some(DescribeAnomalyDetectorsInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_anomaly_detectors`, streaming the `AnomalyDetectors` of every page of results.
+    pub fn describe_anomaly_detectors_items(
+        &self,
+        input: DescribeAnomalyDetectorsInput,
+    ) -> rusoto_core::pagination::RusotoStream<AnomalyDetector, DescribeAnomalyDetectorsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_anomaly_detectors_pages(input),
+            |output: DescribeAnomalyDetectorsOutput| output.anomaly_detectors.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_metric_data`, streaming every page of results.
+    pub fn get_metric_data_pages(
+        &self,
+        input: GetMetricDataInput,
+    ) -> rusoto_core::pagination::RusotoStream<GetMetricDataOutput, GetMetricDataError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudWatchClient, input: GetMetricDataInput| async move {
+                client.get_metric_data(input).await
+            },
+            |input: &GetMetricDataInput, output: &GetMetricDataOutput| {
+                Some(GetMetricDataInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_metric_data`, streaming the `MetricDataResults` of every page of results.
+    pub fn get_metric_data_items(
+        &self,
+        input: GetMetricDataInput,
+    ) -> rusoto_core::pagination::RusotoStream<MetricDataResult, GetMetricDataError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_metric_data_pages(input),
+            |output: GetMetricDataOutput| output.metric_data_results.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_dashboards`, streaming every page of results.
+    pub fn list_dashboards_pages(
+        &self,
+        input: ListDashboardsInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListDashboardsOutput, ListDashboardsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudWatchClient, input: ListDashboardsInput| async move {
+                client.list_dashboards(input).await
+            },
+            |input: &ListDashboardsInput, output: &ListDashboardsOutput| {
+                Some(ListDashboardsInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_dashboards`, streaming the `DashboardEntries` of every page of results.
+    pub fn list_dashboards_items(
+        &self,
+        input: ListDashboardsInput,
+    ) -> rusoto_core::pagination::RusotoStream<DashboardEntry, ListDashboardsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_dashboards_pages(input),
+            |output: ListDashboardsOutput| output.dashboard_entries.unwrap_or_default(),
+        )
+    }
+}
+impl CloudWatchClient {
+    /// Waits until `describe_alarms` reports the `AlarmExists` state, polling every 5 seconds up to 40 times.
+    pub async fn wait_until_alarm_exists(
+        &self,
+        input: DescribeAlarmsInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeAlarmsError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(5), 40);
+        self.wait_until_alarm_exists_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_alarm_exists`, polling as often and as many times as `config` says.
+    pub async fn wait_until_alarm_exists_with_config(
+        &self,
+        input: DescribeAlarmsInput,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeAlarmsError>> {
+        rusoto_core::waiter::wait(
+            "AlarmExists",
+            &config,
+            || self.describe_alarms(input.clone()),
+            |result: &Result<DescribeAlarmsOutput, RusotoError<DescribeAlarmsError>>| match result {
+                Ok(output) if output.metric_alarms.iter().flat_map(|v| v.iter()).count() > 0 => {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+}
 
 #[cfg(test)]
 mod protocol_tests {
@@ -24,6 +24,388 @@ use rusoto_core::signature::SignedRequest;
 #[allow(unused_imports)]
 use serde::{Deserialize, Serialize};
 use serde_json;
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ArtifactNamespace {
+    /// `NONE`
+    None,
+    /// `BUILD_ID`
+    BuildId,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ArtifactNamespace {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ArtifactNamespace::None => "NONE",
+            ArtifactNamespace::BuildId => "BUILD_ID",
+            ArtifactNamespace::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ArtifactNamespace {
+    fn default() -> ArtifactNamespace {
+        ArtifactNamespace::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ArtifactNamespace {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ArtifactNamespace {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ArtifactNamespace {
+    fn from(value: &'a str) -> ArtifactNamespace {
+        match value {
+            "NONE" => ArtifactNamespace::None,
+            "BUILD_ID" => ArtifactNamespace::BuildId,
+            _ => ArtifactNamespace::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ArtifactNamespace {
+    fn from(value: String) -> ArtifactNamespace {
+        match value.as_str() {
+            "NONE" => ArtifactNamespace::None,
+            "BUILD_ID" => ArtifactNamespace::BuildId,
+            _ => ArtifactNamespace::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ArtifactNamespace {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ArtifactNamespace, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ArtifactNamespace {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ArtifactNamespace {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ArtifactNamespace {
+    fn deserialize<D>(deserializer: D) -> Result<ArtifactNamespace, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ArtifactPackaging {
+    /// `NONE`
+    None,
+    /// `ZIP`
+    Zip,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ArtifactPackaging {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ArtifactPackaging::None => "NONE",
+            ArtifactPackaging::Zip => "ZIP",
+            ArtifactPackaging::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ArtifactPackaging {
+    fn default() -> ArtifactPackaging {
+        ArtifactPackaging::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ArtifactPackaging {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ArtifactPackaging {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ArtifactPackaging {
+    fn from(value: &'a str) -> ArtifactPackaging {
+        match value {
+            "NONE" => ArtifactPackaging::None,
+            "ZIP" => ArtifactPackaging::Zip,
+            _ => ArtifactPackaging::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ArtifactPackaging {
+    fn from(value: String) -> ArtifactPackaging {
+        match value.as_str() {
+            "NONE" => ArtifactPackaging::None,
+            "ZIP" => ArtifactPackaging::Zip,
+            _ => ArtifactPackaging::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ArtifactPackaging {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ArtifactPackaging, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ArtifactPackaging {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ArtifactPackaging {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ArtifactPackaging {
+    fn deserialize<D>(deserializer: D) -> Result<ArtifactPackaging, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ArtifactsType {
+    /// `CODEPIPELINE`
+    Codepipeline,
+    /// `S3`
+    S3,
+    /// `NO_ARTIFACTS`
+    NoArtifacts,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ArtifactsType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ArtifactsType::Codepipeline => "CODEPIPELINE",
+            ArtifactsType::S3 => "S3",
+            ArtifactsType::NoArtifacts => "NO_ARTIFACTS",
+            ArtifactsType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ArtifactsType {
+    fn default() -> ArtifactsType {
+        ArtifactsType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ArtifactsType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ArtifactsType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ArtifactsType {
+    fn from(value: &'a str) -> ArtifactsType {
+        match value {
+            "CODEPIPELINE" => ArtifactsType::Codepipeline,
+            "S3" => ArtifactsType::S3,
+            "NO_ARTIFACTS" => ArtifactsType::NoArtifacts,
+            _ => ArtifactsType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ArtifactsType {
+    fn from(value: String) -> ArtifactsType {
+        match value.as_str() {
+            "CODEPIPELINE" => ArtifactsType::Codepipeline,
+            "S3" => ArtifactsType::S3,
+            "NO_ARTIFACTS" => ArtifactsType::NoArtifacts,
+            _ => ArtifactsType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ArtifactsType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ArtifactsType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ArtifactsType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ArtifactsType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ArtifactsType {
+    fn deserialize<D>(deserializer: D) -> Result<ArtifactsType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AuthType {
+    /// `OAUTH`
+    Oauth,
+    /// `BASIC_AUTH`
+    BasicAuth,
+    /// `PERSONAL_ACCESS_TOKEN`
+    PersonalAccessToken,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AuthType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AuthType::Oauth => "OAUTH",
+            AuthType::BasicAuth => "BASIC_AUTH",
+            AuthType::PersonalAccessToken => "PERSONAL_ACCESS_TOKEN",
+            AuthType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AuthType {
+    fn default() -> AuthType {
+        AuthType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AuthType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AuthType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AuthType {
+    fn from(value: &'a str) -> AuthType {
+        match value {
+            "OAUTH" => AuthType::Oauth,
+            "BASIC_AUTH" => AuthType::BasicAuth,
+            "PERSONAL_ACCESS_TOKEN" => AuthType::PersonalAccessToken,
+            _ => AuthType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AuthType {
+    fn from(value: String) -> AuthType {
+        match value.as_str() {
+            "OAUTH" => AuthType::Oauth,
+            "BASIC_AUTH" => AuthType::BasicAuth,
+            "PERSONAL_ACCESS_TOKEN" => AuthType::PersonalAccessToken,
+            _ => AuthType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AuthType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AuthType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AuthType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AuthType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AuthType {
+    fn deserialize<D>(deserializer: D) -> Result<AuthType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct BatchDeleteBuildsInput {
@@ -152,7 +534,7 @@ pub struct Build {
     /// <p><p>The current status of the build. Valid values include:</p> <ul> <li> <p> <code>FAILED</code>: The build failed.</p> </li> <li> <p> <code>FAULT</code>: The build faulted.</p> </li> <li> <p> <code>IN<em>PROGRESS</code>: The build is still in progress.</p> </li> <li> <p> <code>STOPPED</code>: The build stopped.</p> </li> <li> <p> <code>SUCCEEDED</code>: The build succeeded.</p> </li> <li> <p> <code>TIMED</em>OUT</code>: The build timed out.</p> </li> </ul></p>
     #[serde(rename = "buildStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub build_status: Option<String>,
+    pub build_status: Option<StatusType>,
     /// <p>Information about the cache for the build.</p>
     #[serde(rename = "cache")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -167,8 +549,13 @@ pub struct Build {
     pub encryption_key: Option<String>,
     /// <p>When the build process ended, expressed in Unix time format.</p>
     #[serde(rename = "endTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Information about the build environment for this build.</p>
     #[serde(rename = "environment")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -239,8 +626,13 @@ pub struct Build {
     pub source_version: Option<String>,
     /// <p>When the build process started, expressed in Unix time format.</p>
     #[serde(rename = "startTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>How long, in minutes, for AWS CodeBuild to wait before timing out this build if it does not get marked as completed.</p>
     #[serde(rename = "timeoutInMinutes")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -309,117 +701,594 @@ pub struct BuildPhase {
     pub duration_in_seconds: Option<i64>,
     /// <p>When the build phase ended, expressed in Unix time format.</p>
     #[serde(rename = "endTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>The current status of the build phase. Valid values include:</p> <ul> <li> <p> <code>FAILED</code>: The build phase failed.</p> </li> <li> <p> <code>FAULT</code>: The build phase faulted.</p> </li> <li> <p> <code>IN<em>PROGRESS</code>: The build phase is still in progress.</p> </li> <li> <p> <code>QUEUED</code>: The build has been submitted and is queued behind other submitted builds.</p> </li> <li> <p> <code>STOPPED</code>: The build phase stopped.</p> </li> <li> <p> <code>SUCCEEDED</code>: The build phase succeeded.</p> </li> <li> <p> <code>TIMED</em>OUT</code>: The build phase timed out.</p> </li> </ul></p>
     #[serde(rename = "phaseStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub phase_status: Option<String>,
+    pub phase_status: Option<StatusType>,
     /// <p><p>The name of the build phase. Valid values include:</p> <ul> <li> <p> <code>BUILD</code>: Core build activities typically occur in this build phase.</p> </li> <li> <p> <code>COMPLETED</code>: The build has been completed.</p> </li> <li> <p> <code>DOWNLOAD<em>SOURCE</code>: Source code is being downloaded in this build phase.</p> </li> <li> <p> <code>FINALIZING</code>: The build process is completing in this build phase.</p> </li> <li> <p> <code>INSTALL</code>: Installation activities typically occur in this build phase.</p> </li> <li> <p> <code>POST</em>BUILD</code>: Post-build activities typically occur in this build phase.</p> </li> <li> <p> <code>PRE<em>BUILD</code>: Pre-build activities typically occur in this build phase.</p> </li> <li> <p> <code>PROVISIONING</code>: The build environment is being set up.</p> </li> <li> <p> <code>QUEUED</code>: The build has been submitted and is queued behind other submitted builds.</p> </li> <li> <p> <code>SUBMITTED</code>: The build has been submitted.</p> </li> <li> <p> <code>UPLOAD</em>ARTIFACTS</code>: Build output artifacts are being uploaded to the output location.</p> </li> </ul></p>
     #[serde(rename = "phaseType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub phase_type: Option<String>,
+    pub phase_type: Option<BuildPhaseType>,
     /// <p>When the build phase started, expressed in Unix time format.</p>
     #[serde(rename = "startTime")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BuildPhaseType {
+    /// `SUBMITTED`
+    Submitted,
+    /// `QUEUED`
+    Queued,
+    /// `PROVISIONING`
+    Provisioning,
+    /// `DOWNLOAD_SOURCE`
+    DownloadSource,
+    /// `INSTALL`
+    Install,
+    /// `PRE_BUILD`
+    PreBuild,
+    /// `BUILD`
+    Build,
+    /// `POST_BUILD`
+    PostBuild,
+    /// `UPLOAD_ARTIFACTS`
+    UploadArtifacts,
+    /// `FINALIZING`
+    Finalizing,
+    /// `COMPLETED`
+    Completed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl BuildPhaseType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BuildPhaseType::Submitted => "SUBMITTED",
+            BuildPhaseType::Queued => "QUEUED",
+            BuildPhaseType::Provisioning => "PROVISIONING",
+            BuildPhaseType::DownloadSource => "DOWNLOAD_SOURCE",
+            BuildPhaseType::Install => "INSTALL",
+            BuildPhaseType::PreBuild => "PRE_BUILD",
+            BuildPhaseType::Build => "BUILD",
+            BuildPhaseType::PostBuild => "POST_BUILD",
+            BuildPhaseType::UploadArtifacts => "UPLOAD_ARTIFACTS",
+            BuildPhaseType::Finalizing => "FINALIZING",
+            BuildPhaseType::Completed => "COMPLETED",
+            BuildPhaseType::Unknown(ref value) => value,
+        }
+    }
 }
 
-/// <p> Information about Amazon CloudWatch Logs for a build project. </p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct CloudWatchLogsConfig {
-    /// <p> The group name of the logs in Amazon CloudWatch Logs. For more information, see <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/logs/Working-with-log-groups-and-streams.html">Working with Log Groups and Log Streams</a>. </p>
-    #[serde(rename = "groupName")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub group_name: Option<String>,
-    /// <p><p>The current status of the logs in Amazon CloudWatch Logs for a build project. Valid values are:</p> <ul> <li> <p> <code>ENABLED</code>: Amazon CloudWatch Logs are enabled for this build project.</p> </li> <li> <p> <code>DISABLED</code>: Amazon CloudWatch Logs are not enabled for this build project.</p> </li> </ul></p>
-    #[serde(rename = "status")]
-    pub status: String,
-    /// <p> The prefix of the stream name of the Amazon CloudWatch Logs. For more information, see <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/logs/Working-with-log-groups-and-streams.html">Working with Log Groups and Log Streams</a>. </p>
-    #[serde(rename = "streamName")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub stream_name: Option<String>,
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for BuildPhaseType {
+    fn default() -> BuildPhaseType {
+        BuildPhaseType::Unknown(String::new())
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct CreateProjectInput {
-    /// <p>Information about the build output artifacts for the build project.</p>
-    #[serde(rename = "artifacts")]
-    pub artifacts: ProjectArtifacts,
-    /// <p>Set this to true to generate a publicly accessible URL for your project's build badge.</p>
-    #[serde(rename = "badgeEnabled")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub badge_enabled: Option<bool>,
-    /// <p>Stores recently used information so that it can be quickly accessed at a later time.</p>
-    #[serde(rename = "cache")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub cache: Option<ProjectCache>,
-    /// <p>A description that makes the build project easy to identify.</p>
-    #[serde(rename = "description")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub description: Option<String>,
-    /// <p>The AWS Key Management Service (AWS KMS) customer master key (CMK) to be used for encrypting the build output artifacts.</p> <note> <p> You can use a cross-account KMS key to encrypt the build output artifacts if your service role has permission to that key. </p> </note> <p>You can specify either the Amazon Resource Name (ARN) of the CMK or, if available, the CMK's alias (using the format <code>alias/<i>alias-name</i> </code>).</p>
-    #[serde(rename = "encryptionKey")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub encryption_key: Option<String>,
-    /// <p>Information about the build environment for the build project.</p>
-    #[serde(rename = "environment")]
-    pub environment: ProjectEnvironment,
-    /// <p> Information about logs for the build project. These can be logs in Amazon CloudWatch Logs, logs uploaded to a specified S3 bucket, or both. </p>
-    #[serde(rename = "logsConfig")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub logs_config: Option<LogsConfig>,
-    /// <p>The name of the build project.</p>
-    #[serde(rename = "name")]
-    pub name: String,
-    /// <p> The number of minutes a build is allowed to be queued before it times out. </p>
-    #[serde(rename = "queuedTimeoutInMinutes")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub queued_timeout_in_minutes: Option<i64>,
-    /// <p> An array of <code>ProjectArtifacts</code> objects. </p>
-    #[serde(rename = "secondaryArtifacts")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub secondary_artifacts: Option<Vec<ProjectArtifacts>>,
-    /// <p> An array of <code>ProjectSourceVersion</code> objects. If <code>secondarySourceVersions</code> is specified at the build level, then they take precedence over these <code>secondarySourceVersions</code> (at the project level). </p>
-    #[serde(rename = "secondarySourceVersions")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub secondary_source_versions: Option<Vec<ProjectSourceVersion>>,
-    /// <p> An array of <code>ProjectSource</code> objects. </p>
-    #[serde(rename = "secondarySources")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub secondary_sources: Option<Vec<ProjectSource>>,
-    /// <p>The ARN of the AWS Identity and Access Management (IAM) role that enables AWS CodeBuild to interact with dependent AWS services on behalf of the AWS account.</p>
-    #[serde(rename = "serviceRole")]
-    pub service_role: String,
-    /// <p>Information about the build input source code for the build project.</p>
-    #[serde(rename = "source")]
-    pub source: ProjectSource,
-    /// <p> A version of the build input to be built for this project. If not specified, the latest version is used. If specified, it must be one of: </p> <ul> <li> <p>For AWS CodeCommit: the commit ID, branch, or Git tag to use.</p> </li> <li> <p>For GitHub: the commit ID, pull request ID, branch name, or tag name that corresponds to the version of the source code you want to build. If a pull request ID is specified, it must use the format <code>pr/pull-request-ID</code> (for example <code>pr/25</code>). If a branch name is specified, the branch's HEAD commit ID is used. If not specified, the default branch's HEAD commit ID is used.</p> </li> <li> <p>For Bitbucket: the commit ID, branch name, or tag name that corresponds to the version of the source code you want to build. If a branch name is specified, the branch's HEAD commit ID is used. If not specified, the default branch's HEAD commit ID is used.</p> </li> <li> <p>For Amazon Simple Storage Service (Amazon S3): the version ID of the object that represents the build input ZIP file to use.</p> </li> </ul> <p> If <code>sourceVersion</code> is specified at the build level, then that version takes precedence over this <code>sourceVersion</code> (at the project level). </p> <p> For more information, see <a href="https://docs.aws.amazon.com/codebuild/latest/userguide/sample-source-version.html">Source Version Sample with CodeBuild</a> in the <i>AWS CodeBuild User Guide</i>. </p>
-    #[serde(rename = "sourceVersion")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub source_version: Option<String>,
-    /// <p>A set of tags for this build project.</p> <p>These tags are available for use by AWS services that support AWS CodeBuild build project tags.</p>
-    #[serde(rename = "tags")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub tags: Option<Vec<Tag>>,
-    /// <p>How long, in minutes, from 5 to 480 (8 hours), for AWS CodeBuild to wait before it times out any build that has not been marked as completed. The default is 60 minutes.</p>
-    #[serde(rename = "timeoutInMinutes")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub timeout_in_minutes: Option<i64>,
-    /// <p>VpcConfig enables AWS CodeBuild to access resources in an Amazon VPC.</p>
-    #[serde(rename = "vpcConfig")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub vpc_config: Option<VpcConfig>,
+impl AsRef<str> for BuildPhaseType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct CreateProjectOutput {
-    /// <p>Information about the build project that was created.</p>
-    #[serde(rename = "project")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub project: Option<Project>,
+impl fmt::Display for BuildPhaseType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for BuildPhaseType {
+    fn from(value: &'a str) -> BuildPhaseType {
+        match value {
+            "SUBMITTED" => BuildPhaseType::Submitted,
+            "QUEUED" => BuildPhaseType::Queued,
+            "PROVISIONING" => BuildPhaseType::Provisioning,
+            "DOWNLOAD_SOURCE" => BuildPhaseType::DownloadSource,
+            "INSTALL" => BuildPhaseType::Install,
+            "PRE_BUILD" => BuildPhaseType::PreBuild,
+            "BUILD" => BuildPhaseType::Build,
+            "POST_BUILD" => BuildPhaseType::PostBuild,
+            "UPLOAD_ARTIFACTS" => BuildPhaseType::UploadArtifacts,
+            "FINALIZING" => BuildPhaseType::Finalizing,
+            "COMPLETED" => BuildPhaseType::Completed,
+            _ => BuildPhaseType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BuildPhaseType {
+    fn from(value: String) -> BuildPhaseType {
+        match value.as_str() {
+            "SUBMITTED" => BuildPhaseType::Submitted,
+            "QUEUED" => BuildPhaseType::Queued,
+            "PROVISIONING" => BuildPhaseType::Provisioning,
+            "DOWNLOAD_SOURCE" => BuildPhaseType::DownloadSource,
+            "INSTALL" => BuildPhaseType::Install,
+            "PRE_BUILD" => BuildPhaseType::PreBuild,
+            "BUILD" => BuildPhaseType::Build,
+            "POST_BUILD" => BuildPhaseType::PostBuild,
+            "UPLOAD_ARTIFACTS" => BuildPhaseType::UploadArtifacts,
+            "FINALIZING" => BuildPhaseType::Finalizing,
+            "COMPLETED" => BuildPhaseType::Completed,
+            _ => BuildPhaseType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for BuildPhaseType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<BuildPhaseType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for BuildPhaseType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for BuildPhaseType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BuildPhaseType {
+    fn deserialize<D>(deserializer: D) -> Result<BuildPhaseType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CacheMode {
+    /// `LOCAL_DOCKER_LAYER_CACHE`
+    LocalDockerLayerCache,
+    /// `LOCAL_SOURCE_CACHE`
+    LocalSourceCache,
+    /// `LOCAL_CUSTOM_CACHE`
+    LocalCustomCache,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CacheMode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CacheMode::LocalDockerLayerCache => "LOCAL_DOCKER_LAYER_CACHE",
+            CacheMode::LocalSourceCache => "LOCAL_SOURCE_CACHE",
+            CacheMode::LocalCustomCache => "LOCAL_CUSTOM_CACHE",
+            CacheMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CacheMode {
+    fn default() -> CacheMode {
+        CacheMode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CacheMode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CacheMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CacheMode {
+    fn from(value: &'a str) -> CacheMode {
+        match value {
+            "LOCAL_DOCKER_LAYER_CACHE" => CacheMode::LocalDockerLayerCache,
+            "LOCAL_SOURCE_CACHE" => CacheMode::LocalSourceCache,
+            "LOCAL_CUSTOM_CACHE" => CacheMode::LocalCustomCache,
+            _ => CacheMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CacheMode {
+    fn from(value: String) -> CacheMode {
+        match value.as_str() {
+            "LOCAL_DOCKER_LAYER_CACHE" => CacheMode::LocalDockerLayerCache,
+            "LOCAL_SOURCE_CACHE" => CacheMode::LocalSourceCache,
+            "LOCAL_CUSTOM_CACHE" => CacheMode::LocalCustomCache,
+            _ => CacheMode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CacheMode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CacheMode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CacheMode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CacheMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CacheMode {
+    fn deserialize<D>(deserializer: D) -> Result<CacheMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CacheType {
+    /// `NO_CACHE`
+    NoCache,
+    /// `S3`
+    S3,
+    /// `LOCAL`
+    Local,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CacheType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CacheType::NoCache => "NO_CACHE",
+            CacheType::S3 => "S3",
+            CacheType::Local => "LOCAL",
+            CacheType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CacheType {
+    fn default() -> CacheType {
+        CacheType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CacheType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CacheType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CacheType {
+    fn from(value: &'a str) -> CacheType {
+        match value {
+            "NO_CACHE" => CacheType::NoCache,
+            "S3" => CacheType::S3,
+            "LOCAL" => CacheType::Local,
+            _ => CacheType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CacheType {
+    fn from(value: String) -> CacheType {
+        match value.as_str() {
+            "NO_CACHE" => CacheType::NoCache,
+            "S3" => CacheType::S3,
+            "LOCAL" => CacheType::Local,
+            _ => CacheType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CacheType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CacheType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CacheType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CacheType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CacheType {
+    fn deserialize<D>(deserializer: D) -> Result<CacheType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p> Information about Amazon CloudWatch Logs for a build project. </p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CloudWatchLogsConfig {
+    /// <p> The group name of the logs in Amazon CloudWatch Logs. For more information, see <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/logs/Working-with-log-groups-and-streams.html">Working with Log Groups and Log Streams</a>. </p>
+    #[serde(rename = "groupName")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_name: Option<String>,
+    /// <p><p>The current status of the logs in Amazon CloudWatch Logs for a build project. Valid values are:</p> <ul> <li> <p> <code>ENABLED</code>: Amazon CloudWatch Logs are enabled for this build project.</p> </li> <li> <p> <code>DISABLED</code>: Amazon CloudWatch Logs are not enabled for this build project.</p> </li> </ul></p>
+    #[serde(rename = "status")]
+    pub status: LogsConfigStatusType,
+    /// <p> The prefix of the stream name of the Amazon CloudWatch Logs. For more information, see <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/logs/Working-with-log-groups-and-streams.html">Working with Log Groups and Log Streams</a>. </p>
+    #[serde(rename = "streamName")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stream_name: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ComputeType {
+    /// `BUILD_GENERAL1_SMALL`
+    BuildGeneral1Small,
+    /// `BUILD_GENERAL1_MEDIUM`
+    BuildGeneral1Medium,
+    /// `BUILD_GENERAL1_LARGE`
+    BuildGeneral1Large,
+    /// `BUILD_GENERAL1_XLARGE`
+    BuildGeneral1Xlarge,
+    /// `BUILD_GENERAL1_2XLARGE`
+    BuildGeneral1_2xlarge,
+    /// `BUILD_LAMBDA_1GB`
+    BuildLambda1gb,
+    /// `BUILD_LAMBDA_2GB`
+    BuildLambda2gb,
+    /// `BUILD_LAMBDA_4GB`
+    BuildLambda4gb,
+    /// `BUILD_LAMBDA_8GB`
+    BuildLambda8gb,
+    /// `BUILD_LAMBDA_10GB`
+    BuildLambda10gb,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ComputeType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ComputeType::BuildGeneral1Small => "BUILD_GENERAL1_SMALL",
+            ComputeType::BuildGeneral1Medium => "BUILD_GENERAL1_MEDIUM",
+            ComputeType::BuildGeneral1Large => "BUILD_GENERAL1_LARGE",
+            ComputeType::BuildGeneral1Xlarge => "BUILD_GENERAL1_XLARGE",
+            ComputeType::BuildGeneral1_2xlarge => "BUILD_GENERAL1_2XLARGE",
+            ComputeType::BuildLambda1gb => "BUILD_LAMBDA_1GB",
+            ComputeType::BuildLambda2gb => "BUILD_LAMBDA_2GB",
+            ComputeType::BuildLambda4gb => "BUILD_LAMBDA_4GB",
+            ComputeType::BuildLambda8gb => "BUILD_LAMBDA_8GB",
+            ComputeType::BuildLambda10gb => "BUILD_LAMBDA_10GB",
+            ComputeType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ComputeType {
+    fn default() -> ComputeType {
+        ComputeType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ComputeType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ComputeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ComputeType {
+    fn from(value: &'a str) -> ComputeType {
+        match value {
+            "BUILD_GENERAL1_SMALL" => ComputeType::BuildGeneral1Small,
+            "BUILD_GENERAL1_MEDIUM" => ComputeType::BuildGeneral1Medium,
+            "BUILD_GENERAL1_LARGE" => ComputeType::BuildGeneral1Large,
+            "BUILD_GENERAL1_XLARGE" => ComputeType::BuildGeneral1Xlarge,
+            "BUILD_GENERAL1_2XLARGE" => ComputeType::BuildGeneral1_2xlarge,
+            "BUILD_LAMBDA_1GB" => ComputeType::BuildLambda1gb,
+            "BUILD_LAMBDA_2GB" => ComputeType::BuildLambda2gb,
+            "BUILD_LAMBDA_4GB" => ComputeType::BuildLambda4gb,
+            "BUILD_LAMBDA_8GB" => ComputeType::BuildLambda8gb,
+            "BUILD_LAMBDA_10GB" => ComputeType::BuildLambda10gb,
+            _ => ComputeType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ComputeType {
+    fn from(value: String) -> ComputeType {
+        match value.as_str() {
+            "BUILD_GENERAL1_SMALL" => ComputeType::BuildGeneral1Small,
+            "BUILD_GENERAL1_MEDIUM" => ComputeType::BuildGeneral1Medium,
+            "BUILD_GENERAL1_LARGE" => ComputeType::BuildGeneral1Large,
+            "BUILD_GENERAL1_XLARGE" => ComputeType::BuildGeneral1Xlarge,
+            "BUILD_GENERAL1_2XLARGE" => ComputeType::BuildGeneral1_2xlarge,
+            "BUILD_LAMBDA_1GB" => ComputeType::BuildLambda1gb,
+            "BUILD_LAMBDA_2GB" => ComputeType::BuildLambda2gb,
+            "BUILD_LAMBDA_4GB" => ComputeType::BuildLambda4gb,
+            "BUILD_LAMBDA_8GB" => ComputeType::BuildLambda8gb,
+            "BUILD_LAMBDA_10GB" => ComputeType::BuildLambda10gb,
+            _ => ComputeType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ComputeType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ComputeType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ComputeType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ComputeType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ComputeType {
+    fn deserialize<D>(deserializer: D) -> Result<ComputeType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct CreateProjectInput {
+    /// <p>Information about the build output artifacts for the build project.</p>
+    #[serde(rename = "artifacts")]
+    pub artifacts: ProjectArtifacts,
+    /// <p>Set this to true to generate a publicly accessible URL for your project's build badge.</p>
+    #[serde(rename = "badgeEnabled")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub badge_enabled: Option<bool>,
+    /// <p>Stores recently used information so that it can be quickly accessed at a later time.</p>
+    #[serde(rename = "cache")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache: Option<ProjectCache>,
+    /// <p>A description that makes the build project easy to identify.</p>
+    #[serde(rename = "description")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// <p>The AWS Key Management Service (AWS KMS) customer master key (CMK) to be used for encrypting the build output artifacts.</p> <note> <p> You can use a cross-account KMS key to encrypt the build output artifacts if your service role has permission to that key. </p> </note> <p>You can specify either the Amazon Resource Name (ARN) of the CMK or, if available, the CMK's alias (using the format <code>alias/<i>alias-name</i> </code>).</p>
+    #[serde(rename = "encryptionKey")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encryption_key: Option<String>,
+    /// <p>Information about the build environment for the build project.</p>
+    #[serde(rename = "environment")]
+    pub environment: ProjectEnvironment,
+    /// <p> Information about logs for the build project. These can be logs in Amazon CloudWatch Logs, logs uploaded to a specified S3 bucket, or both. </p>
+    #[serde(rename = "logsConfig")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logs_config: Option<LogsConfig>,
+    /// <p>The name of the build project.</p>
+    #[serde(rename = "name")]
+    pub name: String,
+    /// <p> The number of minutes a build is allowed to be queued before it times out. </p>
+    #[serde(rename = "queuedTimeoutInMinutes")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub queued_timeout_in_minutes: Option<i64>,
+    /// <p> An array of <code>ProjectArtifacts</code> objects. </p>
+    #[serde(rename = "secondaryArtifacts")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secondary_artifacts: Option<Vec<ProjectArtifacts>>,
+    /// <p> An array of <code>ProjectSourceVersion</code> objects. If <code>secondarySourceVersions</code> is specified at the build level, then they take precedence over these <code>secondarySourceVersions</code> (at the project level). </p>
+    #[serde(rename = "secondarySourceVersions")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secondary_source_versions: Option<Vec<ProjectSourceVersion>>,
+    /// <p> An array of <code>ProjectSource</code> objects. </p>
+    #[serde(rename = "secondarySources")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secondary_sources: Option<Vec<ProjectSource>>,
+    /// <p>The ARN of the AWS Identity and Access Management (IAM) role that enables AWS CodeBuild to interact with dependent AWS services on behalf of the AWS account.</p>
+    #[serde(rename = "serviceRole")]
+    pub service_role: String,
+    /// <p>Information about the build input source code for the build project.</p>
+    #[serde(rename = "source")]
+    pub source: ProjectSource,
+    /// <p> A version of the build input to be built for this project. If not specified, the latest version is used. If specified, it must be one of: </p> <ul> <li> <p>For AWS CodeCommit: the commit ID, branch, or Git tag to use.</p> </li> <li> <p>For GitHub: the commit ID, pull request ID, branch name, or tag name that corresponds to the version of the source code you want to build. If a pull request ID is specified, it must use the format <code>pr/pull-request-ID</code> (for example <code>pr/25</code>). If a branch name is specified, the branch's HEAD commit ID is used. If not specified, the default branch's HEAD commit ID is used.</p> </li> <li> <p>For Bitbucket: the commit ID, branch name, or tag name that corresponds to the version of the source code you want to build. If a branch name is specified, the branch's HEAD commit ID is used. If not specified, the default branch's HEAD commit ID is used.</p> </li> <li> <p>For Amazon Simple Storage Service (Amazon S3): the version ID of the object that represents the build input ZIP file to use.</p> </li> </ul> <p> If <code>sourceVersion</code> is specified at the build level, then that version takes precedence over this <code>sourceVersion</code> (at the project level). </p> <p> For more information, see <a href="https://docs.aws.amazon.com/codebuild/latest/userguide/sample-source-version.html">Source Version Sample with CodeBuild</a> in the <i>AWS CodeBuild User Guide</i>. </p>
+    #[serde(rename = "sourceVersion")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_version: Option<String>,
+    /// <p>A set of tags for this build project.</p> <p>These tags are available for use by AWS services that support AWS CodeBuild build project tags.</p>
+    #[serde(rename = "tags")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<Tag>>,
+    /// <p>How long, in minutes, from 5 to 480 (8 hours), for AWS CodeBuild to wait before it times out any build that has not been marked as completed. The default is 60 minutes.</p>
+    #[serde(rename = "timeoutInMinutes")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout_in_minutes: Option<i64>,
+    /// <p>VpcConfig enables AWS CodeBuild to access resources in an Amazon VPC.</p>
+    #[serde(rename = "vpcConfig")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vpc_config: Option<VpcConfig>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct CreateProjectOutput {
+    /// <p>Information about the build project that was created.</p>
+    #[serde(rename = "project")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project: Option<Project>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -433,7 +1302,7 @@ pub struct CreateReportGroupInput {
     pub name: String,
     /// <p> The type of report group. </p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: ReportType,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -470,24 +1339,112 @@ pub struct CreateWebhookOutput {
     pub webhook: Option<Webhook>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct DeleteProjectInput {
-    /// <p>The name of the build project.</p>
-    #[serde(rename = "name")]
-    pub name: String,
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CredentialProviderType {
+    /// `SECRETS_MANAGER`
+    SecretsManager,
+    /// A value not known to this version of the crate
+    Unknown(String),
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct DeleteProjectOutput {}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct DeleteReportGroupInput {
-    /// <p> The ARN of the report group to delete. </p>
-    #[serde(rename = "arn")]
-    pub arn: String,
+impl CredentialProviderType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CredentialProviderType::SecretsManager => "SECRETS_MANAGER",
+            CredentialProviderType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CredentialProviderType {
+    fn default() -> CredentialProviderType {
+        CredentialProviderType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CredentialProviderType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CredentialProviderType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CredentialProviderType {
+    fn from(value: &'a str) -> CredentialProviderType {
+        match value {
+            "SECRETS_MANAGER" => CredentialProviderType::SecretsManager,
+            _ => CredentialProviderType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CredentialProviderType {
+    fn from(value: String) -> CredentialProviderType {
+        match value.as_str() {
+            "SECRETS_MANAGER" => CredentialProviderType::SecretsManager,
+            _ => CredentialProviderType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CredentialProviderType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CredentialProviderType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CredentialProviderType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CredentialProviderType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CredentialProviderType {
+    fn deserialize<D>(deserializer: D) -> Result<CredentialProviderType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct DeleteProjectInput {
+    /// <p>The name of the build project.</p>
+    #[serde(rename = "name")]
+    pub name: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct DeleteProjectOutput {}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct DeleteReportGroupInput {
+    /// <p> The ARN of the report group to delete. </p>
+    #[serde(rename = "arn")]
+    pub arn: String,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -609,7 +1566,7 @@ pub struct EnvironmentLanguage {
     /// <p>The programming language for the Docker images.</p>
     #[serde(rename = "language")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub language: Option<String>,
+    pub language: Option<LanguageType>,
 }
 
 /// <p>A set of Docker images that are related by platform and are managed by AWS CodeBuild.</p>
@@ -623,9 +1580,127 @@ pub struct EnvironmentPlatform {
     /// <p>The platform's name.</p>
     #[serde(rename = "platform")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub platform: Option<String>,
+    pub platform: Option<PlatformType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EnvironmentType {
+    /// `WINDOWS_CONTAINER`
+    WindowsContainer,
+    /// `LINUX_CONTAINER`
+    LinuxContainer,
+    /// `LINUX_GPU_CONTAINER`
+    LinuxGpuContainer,
+    /// `ARM_CONTAINER`
+    ArmContainer,
+    /// `WINDOWS_SERVER_2019_CONTAINER`
+    WindowsServer2019Container,
+    /// `LINUX_LAMBDA_CONTAINER`
+    LinuxLambdaContainer,
+    /// `ARM_LAMBDA_CONTAINER`
+    ArmLambdaContainer,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EnvironmentType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EnvironmentType::WindowsContainer => "WINDOWS_CONTAINER",
+            EnvironmentType::LinuxContainer => "LINUX_CONTAINER",
+            EnvironmentType::LinuxGpuContainer => "LINUX_GPU_CONTAINER",
+            EnvironmentType::ArmContainer => "ARM_CONTAINER",
+            EnvironmentType::WindowsServer2019Container => "WINDOWS_SERVER_2019_CONTAINER",
+            EnvironmentType::LinuxLambdaContainer => "LINUX_LAMBDA_CONTAINER",
+            EnvironmentType::ArmLambdaContainer => "ARM_LAMBDA_CONTAINER",
+            EnvironmentType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EnvironmentType {
+    fn default() -> EnvironmentType {
+        EnvironmentType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EnvironmentType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EnvironmentType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EnvironmentType {
+    fn from(value: &'a str) -> EnvironmentType {
+        match value {
+            "WINDOWS_CONTAINER" => EnvironmentType::WindowsContainer,
+            "LINUX_CONTAINER" => EnvironmentType::LinuxContainer,
+            "LINUX_GPU_CONTAINER" => EnvironmentType::LinuxGpuContainer,
+            "ARM_CONTAINER" => EnvironmentType::ArmContainer,
+            "WINDOWS_SERVER_2019_CONTAINER" => EnvironmentType::WindowsServer2019Container,
+            "LINUX_LAMBDA_CONTAINER" => EnvironmentType::LinuxLambdaContainer,
+            "ARM_LAMBDA_CONTAINER" => EnvironmentType::ArmLambdaContainer,
+            _ => EnvironmentType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EnvironmentType {
+    fn from(value: String) -> EnvironmentType {
+        match value.as_str() {
+            "WINDOWS_CONTAINER" => EnvironmentType::WindowsContainer,
+            "LINUX_CONTAINER" => EnvironmentType::LinuxContainer,
+            "LINUX_GPU_CONTAINER" => EnvironmentType::LinuxGpuContainer,
+            "ARM_CONTAINER" => EnvironmentType::ArmContainer,
+            "WINDOWS_SERVER_2019_CONTAINER" => EnvironmentType::WindowsServer2019Container,
+            "LINUX_LAMBDA_CONTAINER" => EnvironmentType::LinuxLambdaContainer,
+            "ARM_LAMBDA_CONTAINER" => EnvironmentType::ArmLambdaContainer,
+            _ => EnvironmentType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EnvironmentType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EnvironmentType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EnvironmentType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EnvironmentType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for EnvironmentType {
+    fn deserialize<D>(deserializer: D) -> Result<EnvironmentType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about an environment variable for a build project or a build.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct EnvironmentVariable {
@@ -635,12 +1710,110 @@ pub struct EnvironmentVariable {
     /// <p><p>The type of environment variable. Valid values include:</p> <ul> <li> <p> <code>PARAMETER<em>STORE</code>: An environment variable stored in Amazon EC2 Systems Manager Parameter Store.</p> </li> <li> <p> <code>PLAINTEXT</code>: An environment variable in plain text format. This is the default value.</p> </li> <li> <p> <code>SECRETS</em>MANAGER</code>: An environment variable stored in AWS Secrets Manager.</p> </li> </ul></p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<EnvironmentVariableType>,
     /// <p><p>The value of the environment variable.</p> <important> <p>We strongly discourage the use of environment variables to store sensitive values, especially AWS secret key IDs and secret access keys. Environment variables can be displayed in plain text using the AWS CodeBuild console and the AWS Command Line Interface (AWS CLI).</p> </important></p>
     #[serde(rename = "value")]
     pub value: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EnvironmentVariableType {
+    /// `PLAINTEXT`
+    Plaintext,
+    /// `PARAMETER_STORE`
+    ParameterStore,
+    /// `SECRETS_MANAGER`
+    SecretsManager,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EnvironmentVariableType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EnvironmentVariableType::Plaintext => "PLAINTEXT",
+            EnvironmentVariableType::ParameterStore => "PARAMETER_STORE",
+            EnvironmentVariableType::SecretsManager => "SECRETS_MANAGER",
+            EnvironmentVariableType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EnvironmentVariableType {
+    fn default() -> EnvironmentVariableType {
+        EnvironmentVariableType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EnvironmentVariableType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EnvironmentVariableType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EnvironmentVariableType {
+    fn from(value: &'a str) -> EnvironmentVariableType {
+        match value {
+            "PLAINTEXT" => EnvironmentVariableType::Plaintext,
+            "PARAMETER_STORE" => EnvironmentVariableType::ParameterStore,
+            "SECRETS_MANAGER" => EnvironmentVariableType::SecretsManager,
+            _ => EnvironmentVariableType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EnvironmentVariableType {
+    fn from(value: String) -> EnvironmentVariableType {
+        match value.as_str() {
+            "PLAINTEXT" => EnvironmentVariableType::Plaintext,
+            "PARAMETER_STORE" => EnvironmentVariableType::ParameterStore,
+            "SECRETS_MANAGER" => EnvironmentVariableType::SecretsManager,
+            _ => EnvironmentVariableType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EnvironmentVariableType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EnvironmentVariableType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EnvironmentVariableType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EnvironmentVariableType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EnvironmentVariableType {
+    fn deserialize<D>(deserializer: D) -> Result<EnvironmentVariableType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p> Information about an exported environment variable. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -680,15 +1853,108 @@ pub struct GitSubmodulesConfig {
     pub fetch_submodules: bool,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ImagePullCredentialsType {
+    /// `CODEBUILD`
+    Codebuild,
+    /// `SERVICE_ROLE`
+    ServiceRole,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ImagePullCredentialsType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ImagePullCredentialsType::Codebuild => "CODEBUILD",
+            ImagePullCredentialsType::ServiceRole => "SERVICE_ROLE",
+            ImagePullCredentialsType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ImagePullCredentialsType {
+    fn default() -> ImagePullCredentialsType {
+        ImagePullCredentialsType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ImagePullCredentialsType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ImagePullCredentialsType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ImagePullCredentialsType {
+    fn from(value: &'a str) -> ImagePullCredentialsType {
+        match value {
+            "CODEBUILD" => ImagePullCredentialsType::Codebuild,
+            "SERVICE_ROLE" => ImagePullCredentialsType::ServiceRole,
+            _ => ImagePullCredentialsType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ImagePullCredentialsType {
+    fn from(value: String) -> ImagePullCredentialsType {
+        match value.as_str() {
+            "CODEBUILD" => ImagePullCredentialsType::Codebuild,
+            "SERVICE_ROLE" => ImagePullCredentialsType::ServiceRole,
+            _ => ImagePullCredentialsType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ImagePullCredentialsType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ImagePullCredentialsType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ImagePullCredentialsType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ImagePullCredentialsType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ImagePullCredentialsType {
+    fn deserialize<D>(deserializer: D) -> Result<ImagePullCredentialsType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ImportSourceCredentialsInput {
     /// <p> The type of authentication used to connect to a GitHub, GitHub Enterprise, or Bitbucket repository. An OAUTH connection is not supported by the API and must be created using the AWS CodeBuild console. </p>
     #[serde(rename = "authType")]
-    pub auth_type: String,
+    pub auth_type: AuthType,
     /// <p> The source provider used for this project. </p>
     #[serde(rename = "serverType")]
-    pub server_type: String,
+    pub server_type: ServerType,
     /// <p> Set to <code>false</code> to prevent overwriting the repository source credentials. Set to <code>true</code> to overwrite the repository source credentials. The default value is <code>true</code>. </p>
     #[serde(rename = "shouldOverwrite")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -723,6 +1989,139 @@ pub struct InvalidateProjectCacheInput {
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InvalidateProjectCacheOutput {}
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LanguageType {
+    /// `JAVA`
+    Java,
+    /// `PYTHON`
+    Python,
+    /// `NODE_JS`
+    NodeJs,
+    /// `RUBY`
+    Ruby,
+    /// `GOLANG`
+    Golang,
+    /// `DOCKER`
+    Docker,
+    /// `ANDROID`
+    Android,
+    /// `DOTNET`
+    Dotnet,
+    /// `BASE`
+    Base,
+    /// `PHP`
+    Php,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LanguageType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LanguageType::Java => "JAVA",
+            LanguageType::Python => "PYTHON",
+            LanguageType::NodeJs => "NODE_JS",
+            LanguageType::Ruby => "RUBY",
+            LanguageType::Golang => "GOLANG",
+            LanguageType::Docker => "DOCKER",
+            LanguageType::Android => "ANDROID",
+            LanguageType::Dotnet => "DOTNET",
+            LanguageType::Base => "BASE",
+            LanguageType::Php => "PHP",
+            LanguageType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LanguageType {
+    fn default() -> LanguageType {
+        LanguageType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LanguageType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LanguageType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LanguageType {
+    fn from(value: &'a str) -> LanguageType {
+        match value {
+            "JAVA" => LanguageType::Java,
+            "PYTHON" => LanguageType::Python,
+            "NODE_JS" => LanguageType::NodeJs,
+            "RUBY" => LanguageType::Ruby,
+            "GOLANG" => LanguageType::Golang,
+            "DOCKER" => LanguageType::Docker,
+            "ANDROID" => LanguageType::Android,
+            "DOTNET" => LanguageType::Dotnet,
+            "BASE" => LanguageType::Base,
+            "PHP" => LanguageType::Php,
+            _ => LanguageType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LanguageType {
+    fn from(value: String) -> LanguageType {
+        match value.as_str() {
+            "JAVA" => LanguageType::Java,
+            "PYTHON" => LanguageType::Python,
+            "NODE_JS" => LanguageType::NodeJs,
+            "RUBY" => LanguageType::Ruby,
+            "GOLANG" => LanguageType::Golang,
+            "DOCKER" => LanguageType::Docker,
+            "ANDROID" => LanguageType::Android,
+            "DOTNET" => LanguageType::Dotnet,
+            "BASE" => LanguageType::Base,
+            "PHP" => LanguageType::Php,
+            _ => LanguageType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LanguageType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LanguageType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LanguageType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LanguageType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LanguageType {
+    fn deserialize<D>(deserializer: D) -> Result<LanguageType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ListBuildsForProjectInput {
@@ -736,7 +2135,7 @@ pub struct ListBuildsForProjectInput {
     /// <p><p>The order to list build IDs. Valid values include:</p> <ul> <li> <p> <code>ASCENDING</code>: List the build IDs in ascending order by build ID.</p> </li> <li> <p> <code>DESCENDING</code>: List the build IDs in descending order by build ID.</p> </li> </ul></p>
     #[serde(rename = "sortOrder")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort_order: Option<String>,
+    pub sort_order: Option<SortOrderType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -762,7 +2161,7 @@ pub struct ListBuildsInput {
     /// <p><p>The order to list build IDs. Valid values include:</p> <ul> <li> <p> <code>ASCENDING</code>: List the build IDs in ascending order by build ID.</p> </li> <li> <p> <code>DESCENDING</code>: List the build IDs in descending order by build ID.</p> </li> </ul></p>
     #[serde(rename = "sortOrder")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort_order: Option<String>,
+    pub sort_order: Option<SortOrderType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -801,11 +2200,11 @@ pub struct ListProjectsInput {
     /// <p>The criterion to be used to list build project names. Valid values include:</p> <ul> <li> <p> <code>CREATED_TIME</code>: List based on when each build project was created.</p> </li> <li> <p> <code>LAST_MODIFIED_TIME</code>: List based on when information about each build project was last changed.</p> </li> <li> <p> <code>NAME</code>: List based on each build project's name.</p> </li> </ul> <p>Use <code>sortOrder</code> to specify in what order to list the build project names based on the preceding criteria.</p>
     #[serde(rename = "sortBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort_by: Option<String>,
+    pub sort_by: Option<ProjectSortByType>,
     /// <p>The order in which to list build projects. Valid values include:</p> <ul> <li> <p> <code>ASCENDING</code>: List in ascending order.</p> </li> <li> <p> <code>DESCENDING</code>: List in descending order.</p> </li> </ul> <p>Use <code>sortBy</code> to specify the criterion to be used to list build project names.</p>
     #[serde(rename = "sortOrder")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort_order: Option<String>,
+    pub sort_order: Option<SortOrderType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -835,11 +2234,11 @@ pub struct ListReportGroupsInput {
     /// <p><p> The criterion to be used to list build report groups. Valid values include: </p> <ul> <li> <p> <code>CREATED<em>TIME</code>: List based on when each report group was created.</p> </li> <li> <p> <code>LAST</em>MODIFIED_TIME</code>: List based on when each report group was last changed.</p> </li> <li> <p> <code>NAME</code>: List based on each report group&#39;s name.</p> </li> </ul></p>
     #[serde(rename = "sortBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort_by: Option<String>,
+    pub sort_by: Option<ReportGroupSortByType>,
     /// <p> Used to specify the order to sort the list of returned report groups. Valid values are <code>ASCENDING</code> and <code>DESCENDING</code>. </p>
     #[serde(rename = "sortOrder")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort_order: Option<String>,
+    pub sort_order: Option<SortOrderType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -876,7 +2275,7 @@ pub struct ListReportsForReportGroupInput {
     /// <p> Use to specify whether the results are returned in ascending or descending order. </p>
     #[serde(rename = "sortOrder")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort_order: Option<String>,
+    pub sort_order: Option<SortOrderType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -910,7 +2309,7 @@ pub struct ListReportsInput {
     /// <p><p> Specifies the sort order for the list of returned reports. Valid values are: </p> <ul> <li> <p> <code>ASCENDING</code>: return reports in chronological order based on their creation date. </p> </li> <li> <p> <code>DESCENDING</code>: return reports in the reverse chronological order based on their creation date. </p> </li> </ul></p>
     #[serde(rename = "sortOrder")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort_order: Option<String>,
+    pub sort_order: Option<SortOrderType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -940,11 +2339,11 @@ pub struct ListSharedProjectsInput {
     /// <p><p> The criterion to be used to list build projects shared with the current AWS account or user. Valid values include: </p> <ul> <li> <p> <code>ARN</code>: List based on the ARN. </p> </li> <li> <p> <code>MODIFIED_TIME</code>: List based on when information about the shared project was last changed. </p> </li> </ul></p>
     #[serde(rename = "sortBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort_by: Option<String>,
+    pub sort_by: Option<SharedResourceSortByType>,
     /// <p><p>The order in which to list shared build projects. Valid values include:</p> <ul> <li> <p> <code>ASCENDING</code>: List in ascending order.</p> </li> <li> <p> <code>DESCENDING</code>: List in descending order.</p> </li> </ul></p>
     #[serde(rename = "sortOrder")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort_order: Option<String>,
+    pub sort_order: Option<SortOrderType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -974,11 +2373,11 @@ pub struct ListSharedReportGroupsInput {
     /// <p><p> The criterion to be used to list report groups shared with the current AWS account or user. Valid values include: </p> <ul> <li> <p> <code>ARN</code>: List based on the ARN. </p> </li> <li> <p> <code>MODIFIED_TIME</code>: List based on when information about the shared report group was last changed. </p> </li> </ul></p>
     #[serde(rename = "sortBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort_by: Option<String>,
+    pub sort_by: Option<SharedResourceSortByType>,
     /// <p><p>The order in which to list shared report groups. Valid values include:</p> <ul> <li> <p> <code>ASCENDING</code>: List in ascending order.</p> </li> <li> <p> <code>DESCENDING</code>: List in descending order.</p> </li> </ul></p>
     #[serde(rename = "sortOrder")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort_order: Option<String>,
+    pub sort_order: Option<SortOrderType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1020,6 +2419,99 @@ pub struct LogsConfig {
     pub s_3_logs: Option<S3LogsConfig>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LogsConfigStatusType {
+    /// `ENABLED`
+    Enabled,
+    /// `DISABLED`
+    Disabled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LogsConfigStatusType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LogsConfigStatusType::Enabled => "ENABLED",
+            LogsConfigStatusType::Disabled => "DISABLED",
+            LogsConfigStatusType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LogsConfigStatusType {
+    fn default() -> LogsConfigStatusType {
+        LogsConfigStatusType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LogsConfigStatusType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LogsConfigStatusType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LogsConfigStatusType {
+    fn from(value: &'a str) -> LogsConfigStatusType {
+        match value {
+            "ENABLED" => LogsConfigStatusType::Enabled,
+            "DISABLED" => LogsConfigStatusType::Disabled,
+            _ => LogsConfigStatusType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LogsConfigStatusType {
+    fn from(value: String) -> LogsConfigStatusType {
+        match value.as_str() {
+            "ENABLED" => LogsConfigStatusType::Enabled,
+            "DISABLED" => LogsConfigStatusType::Disabled,
+            _ => LogsConfigStatusType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LogsConfigStatusType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LogsConfigStatusType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LogsConfigStatusType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LogsConfigStatusType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LogsConfigStatusType {
+    fn deserialize<D>(deserializer: D) -> Result<LogsConfigStatusType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about build logs in Amazon CloudWatch Logs.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1086,9 +2578,112 @@ pub struct PhaseContext {
     pub status_code: Option<String>,
 }
 
-/// <p>Information about a build project.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PlatformType {
+    /// `DEBIAN`
+    Debian,
+    /// `AMAZON_LINUX`
+    AmazonLinux,
+    /// `UBUNTU`
+    Ubuntu,
+    /// `WINDOWS_SERVER`
+    WindowsServer,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PlatformType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PlatformType::Debian => "DEBIAN",
+            PlatformType::AmazonLinux => "AMAZON_LINUX",
+            PlatformType::Ubuntu => "UBUNTU",
+            PlatformType::WindowsServer => "WINDOWS_SERVER",
+            PlatformType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PlatformType {
+    fn default() -> PlatformType {
+        PlatformType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PlatformType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PlatformType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PlatformType {
+    fn from(value: &'a str) -> PlatformType {
+        match value {
+            "DEBIAN" => PlatformType::Debian,
+            "AMAZON_LINUX" => PlatformType::AmazonLinux,
+            "UBUNTU" => PlatformType::Ubuntu,
+            "WINDOWS_SERVER" => PlatformType::WindowsServer,
+            _ => PlatformType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PlatformType {
+    fn from(value: String) -> PlatformType {
+        match value.as_str() {
+            "DEBIAN" => PlatformType::Debian,
+            "AMAZON_LINUX" => PlatformType::AmazonLinux,
+            "UBUNTU" => PlatformType::Ubuntu,
+            "WINDOWS_SERVER" => PlatformType::WindowsServer,
+            _ => PlatformType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PlatformType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PlatformType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PlatformType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PlatformType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PlatformType {
+    fn deserialize<D>(deserializer: D) -> Result<PlatformType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Information about a build project.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Project {
     /// <p>The Amazon Resource Name (ARN) of the build project.</p>
     #[serde(rename = "arn")]
@@ -1108,8 +2703,13 @@ pub struct Project {
     pub cache: Option<ProjectCache>,
     /// <p>When the build project was created, expressed in Unix time format.</p>
     #[serde(rename = "created")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created: Option<f64>,
+    pub created: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A description that makes the build project easy to identify.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1124,8 +2724,13 @@ pub struct Project {
     pub environment: Option<ProjectEnvironment>,
     /// <p>When the build project's settings were last modified, expressed in Unix time format.</p>
     #[serde(rename = "lastModified")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified: Option<f64>,
+    pub last_modified: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p> Information about logs for the build project. A project can create logs in Amazon CloudWatch Logs, an S3 bucket, or both. </p>
     #[serde(rename = "logsConfig")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1202,7 +2807,7 @@ pub struct ProjectArtifacts {
     /// <p>Along with <code>path</code> and <code>name</code>, the pattern that AWS CodeBuild uses to determine the name and location to store the output artifact:</p> <ul> <li> <p>If <code>type</code> is set to <code>CODEPIPELINE</code>, AWS CodePipeline ignores this value if specified. This is because AWS CodePipeline manages its build output names instead of AWS CodeBuild.</p> </li> <li> <p>If <code>type</code> is set to <code>NO_ARTIFACTS</code>, this value is ignored if specified, because no build output is produced.</p> </li> <li> <p>If <code>type</code> is set to <code>S3</code>, valid values include:</p> <ul> <li> <p> <code>BUILD_ID</code>: Include the build ID in the location of the build output artifact.</p> </li> <li> <p> <code>NONE</code>: Do not include the build ID. This is the default if <code>namespaceType</code> is not specified.</p> </li> </ul> </li> </ul> <p>For example, if <code>path</code> is set to <code>MyArtifacts</code>, <code>namespaceType</code> is set to <code>BUILD_ID</code>, and <code>name</code> is set to <code>MyArtifact.zip</code>, the output artifact is stored in <code>MyArtifacts/<i>build-ID</i>/MyArtifact.zip</code>.</p>
     #[serde(rename = "namespaceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub namespace_type: Option<String>,
+    pub namespace_type: Option<ArtifactNamespace>,
     /// <p> If this flag is set, a name specified in the build spec file overrides the artifact name. The name specified in a build spec file is calculated at build time and uses the Shell Command Language. For example, you can append a date and time to your artifact name so that it is always unique. </p>
     #[serde(rename = "overrideArtifactName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1210,14 +2815,14 @@ pub struct ProjectArtifacts {
     /// <p><p>The type of build output artifact to create:</p> <ul> <li> <p>If <code>type</code> is set to <code>CODEPIPELINE</code>, AWS CodePipeline ignores this value if specified. This is because AWS CodePipeline manages its build output artifacts instead of AWS CodeBuild.</p> </li> <li> <p>If <code>type</code> is set to <code>NO_ARTIFACTS</code>, this value is ignored if specified, because no build output is produced.</p> </li> <li> <p>If <code>type</code> is set to <code>S3</code>, valid values include:</p> <ul> <li> <p> <code>NONE</code>: AWS CodeBuild creates in the output bucket a folder that contains the build output. This is the default if <code>packaging</code> is not specified.</p> </li> <li> <p> <code>ZIP</code>: AWS CodeBuild creates in the output bucket a ZIP file that contains the build output.</p> </li> </ul> </li> </ul></p>
     #[serde(rename = "packaging")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub packaging: Option<String>,
+    pub packaging: Option<ArtifactPackaging>,
     /// <p>Along with <code>namespaceType</code> and <code>name</code>, the pattern that AWS CodeBuild uses to name and store the output artifact:</p> <ul> <li> <p>If <code>type</code> is set to <code>CODEPIPELINE</code>, AWS CodePipeline ignores this value if specified. This is because AWS CodePipeline manages its build output names instead of AWS CodeBuild.</p> </li> <li> <p>If <code>type</code> is set to <code>NO_ARTIFACTS</code>, this value is ignored if specified, because no build output is produced.</p> </li> <li> <p>If <code>type</code> is set to <code>S3</code>, this is the path to the output artifact. If <code>path</code> is not specified, <code>path</code> is not used.</p> </li> </ul> <p>For example, if <code>path</code> is set to <code>MyArtifacts</code>, <code>namespaceType</code> is set to <code>NONE</code>, and <code>name</code> is set to <code>MyArtifact.zip</code>, the output artifact is stored in the output bucket at <code>MyArtifacts/MyArtifact.zip</code>.</p>
     #[serde(rename = "path")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub path: Option<String>,
     /// <p><p>The type of build output artifact. Valid values include:</p> <ul> <li> <p> <code>CODEPIPELINE</code>: The build project has build output generated through AWS CodePipeline. </p> <note> <p>The <code>CODEPIPELINE</code> type is not supported for <code>secondaryArtifacts</code>.</p> </note> </li> <li> <p> <code>NO_ARTIFACTS</code>: The build project does not produce any build output.</p> </li> <li> <p> <code>S3</code>: The build project stores build output in Amazon Simple Storage Service (Amazon S3).</p> </li> </ul></p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: ArtifactsType,
 }
 
 /// <p>Information about the build badge for the build project.</p>
@@ -1244,10 +2849,10 @@ pub struct ProjectCache {
     /// <p><p> If you use a <code>LOCAL</code> cache, the local cache mode. You can use one or more local cache modes at the same time. </p> <ul> <li> <p> <code>LOCAL<em>SOURCE</em>CACHE</code> mode caches Git metadata for primary and secondary sources. After the cache is created, subsequent builds pull only the change between commits. This mode is a good choice for projects with a clean working directory and a source that is a large Git repository. If you choose this option and your project does not use a Git repository (GitHub, GitHub Enterprise, or Bitbucket), the option is ignored. </p> </li> <li> <p> <code>LOCAL<em>DOCKER</em>LAYER<em>CACHE</code> mode caches existing Docker layers. This mode is a good choice for projects that build or pull large Docker images. It can prevent the performance issues caused by pulling large Docker images down from the network. </p> <note> <ul> <li> <p> You can use a Docker layer cache in the Linux environment only. </p> </li> <li> <p> The <code>privileged</code> flag must be set so that your project has the required Docker permissions. </p> </li> <li> <p> You should consider the security implications before you use a Docker layer cache. </p> </li> </ul> </note> </li> </ul> <ul> <li> <p> <code>LOCAL</em>CUSTOM_CACHE</code> mode caches directories you specify in the buildspec file. This mode is a good choice if your build scenario is not suited to one of the other three local cache modes. If you use a custom cache: </p> <ul> <li> <p> Only directories can be specified for caching. You cannot specify individual files. </p> </li> <li> <p> Symlinks are used to reference cached directories. </p> </li> <li> <p> Cached directories are linked to your build before it downloads its project sources. Cached items are overridden if a source item has the same name. Directories are specified using cache paths in the buildspec file. </p> </li> </ul> </li> </ul></p>
     #[serde(rename = "modes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub modes: Option<Vec<String>>,
+    pub modes: Option<Vec<CacheMode>>,
     /// <p><p>The type of cache used by the build project. Valid values include:</p> <ul> <li> <p> <code>NO_CACHE</code>: The build project does not use any cache.</p> </li> <li> <p> <code>S3</code>: The build project reads and writes from and to S3.</p> </li> <li> <p> <code>LOCAL</code>: The build project stores a cache locally on a build host that is only available to that build host.</p> </li> </ul></p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: CacheType,
 }
 
 /// <p>Information about the build environment of the build project.</p>
@@ -1259,7 +2864,7 @@ pub struct ProjectEnvironment {
     pub certificate: Option<String>,
     /// <p>Information about the compute resources the build project uses. Available values include:</p> <ul> <li> <p> <code>BUILD_GENERAL1_SMALL</code>: Use up to 3 GB memory and 2 vCPUs for builds.</p> </li> <li> <p> <code>BUILD_GENERAL1_MEDIUM</code>: Use up to 7 GB memory and 4 vCPUs for builds.</p> </li> <li> <p> <code>BUILD_GENERAL1_LARGE</code>: Use up to 16 GB memory and 8 vCPUs for builds, depending on your environment type.</p> </li> <li> <p> <code>BUILD_GENERAL1_2XLARGE</code>: Use up to 145 GB memory, 72 vCPUs, and 824 GB of SSD storage for builds. This compute type supports Docker images up to 100 GB uncompressed.</p> </li> </ul> <p> If you use <code>BUILD_GENERAL1_LARGE</code>: </p> <ul> <li> <p> For environment type <code>LINUX_CONTAINER</code>, you can use up to 15 GB memory and 8 vCPUs for builds. </p> </li> <li> <p> For environment type <code>LINUX_GPU_CONTAINER</code>, you can use up to 255 GB memory, 32 vCPUs, and 4 NVIDIA Tesla V100 GPUs for builds.</p> </li> <li> <p> For environment type <code>ARM_CONTAINER</code>, you can use up to 16 GB memory and 8 vCPUs on ARM-based processors for builds.</p> </li> </ul> <p> For more information, see <a href="https://docs.aws.amazon.com/codebuild/latest/userguide/build-env-ref-compute-types.html">Build Environment Compute Types</a> in the <i>AWS CodeBuild User Guide.</i> </p>
     #[serde(rename = "computeType")]
-    pub compute_type: String,
+    pub compute_type: ComputeType,
     /// <p>A set of environment variables to make available to builds for this build project.</p>
     #[serde(rename = "environmentVariables")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1270,7 +2875,7 @@ pub struct ProjectEnvironment {
     /// <p> The type of credentials AWS CodeBuild uses to pull images in your build. There are two valid values: </p> <ul> <li> <p> <code>CODEBUILD</code> specifies that AWS CodeBuild uses its own credentials. This requires that you modify your ECR repository policy to trust AWS CodeBuild's service principal. </p> </li> <li> <p> <code>SERVICE_ROLE</code> specifies that AWS CodeBuild uses your build project's service role. </p> </li> </ul> <p> When you use a cross-account or private registry image, you must use SERVICE_ROLE credentials. When you use an AWS CodeBuild curated image, you must use CODEBUILD credentials. </p>
     #[serde(rename = "imagePullCredentialsType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub image_pull_credentials_type: Option<String>,
+    pub image_pull_credentials_type: Option<ImagePullCredentialsType>,
     /// <p>Enables running the Docker daemon inside a Docker container. Set to true only if the build project is used to build Docker images. Otherwise, a build that attempts to interact with the Docker daemon fails. The default setting is <code>false</code>.</p> <p>You can initialize the Docker daemon during the install phase of your build by adding one of the following sets of commands to the install phase of your buildspec file:</p> <p>If the operating system's base image is Ubuntu Linux:</p> <p> <code>- nohup /usr/local/bin/dockerd --host=unix:///var/run/docker.sock --host=tcp://0.0.0.0:2375 --storage-driver=overlay&amp;</code> </p> <p> <code>- timeout 15 sh -c "until docker info; do echo .; sleep 1; done"</code> </p> <p>If the operating system's base image is Alpine Linux and the previous command does not work, add the <code>-t</code> argument to <code>timeout</code>:</p> <p> <code>- nohup /usr/local/bin/dockerd --host=unix:///var/run/docker.sock --host=tcp://0.0.0.0:2375 --storage-driver=overlay&amp;</code> </p> <p> <code>- timeout -t 15 sh -c "until docker info; do echo .; sleep 1; done"</code> </p>
     #[serde(rename = "privilegedMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1281,9 +2886,107 @@ pub struct ProjectEnvironment {
     pub registry_credential: Option<RegistryCredential>,
     /// <p><p>The type of build environment to use for related builds.</p> <ul> <li> <p>The environment type <code>ARM<em>CONTAINER</code> is available only in regions US East (N. Virginia), US East (Ohio), US West (Oregon), EU (Ireland), Asia Pacific (Mumbai), Asia Pacific (Tokyo), Asia Pacific (Sydney), and EU (Frankfurt).</p> </li> <li> <p>The environment type <code>LINUX</em>CONTAINER</code> with compute type <code>build.general1.2xlarge</code> is available only in regions US East (N. Virginia), US East (N. Virginia), US West (Oregon), Canada (Central), EU (Ireland), EU (London), EU (Frankfurt), Asia Pacific (Tokyo), Asia Pacific (Seoul), Asia Pacific (Singapore), Asia Pacific (Sydney), China (Beijing), and China (Ningxia).</p> </li> <li> <p>The environment type <code>LINUX<em>GPU</em>CONTAINER</code> is available only in regions US East (N. Virginia), US East (N. Virginia), US West (Oregon), Canada (Central), EU (Ireland), EU (London), EU (Frankfurt), Asia Pacific (Tokyo), Asia Pacific (Seoul), Asia Pacific (Singapore), Asia Pacific (Sydney) , China (Beijing), and China (Ningxia).</p> </li> </ul></p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: EnvironmentType,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ProjectSortByType {
+    /// `NAME`
+    Name,
+    /// `CREATED_TIME`
+    CreatedTime,
+    /// `LAST_MODIFIED_TIME`
+    LastModifiedTime,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ProjectSortByType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ProjectSortByType::Name => "NAME",
+            ProjectSortByType::CreatedTime => "CREATED_TIME",
+            ProjectSortByType::LastModifiedTime => "LAST_MODIFIED_TIME",
+            ProjectSortByType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ProjectSortByType {
+    fn default() -> ProjectSortByType {
+        ProjectSortByType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ProjectSortByType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ProjectSortByType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ProjectSortByType {
+    fn from(value: &'a str) -> ProjectSortByType {
+        match value {
+            "NAME" => ProjectSortByType::Name,
+            "CREATED_TIME" => ProjectSortByType::CreatedTime,
+            "LAST_MODIFIED_TIME" => ProjectSortByType::LastModifiedTime,
+            _ => ProjectSortByType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ProjectSortByType {
+    fn from(value: String) -> ProjectSortByType {
+        match value.as_str() {
+            "NAME" => ProjectSortByType::Name,
+            "CREATED_TIME" => ProjectSortByType::CreatedTime,
+            "LAST_MODIFIED_TIME" => ProjectSortByType::LastModifiedTime,
+            _ => ProjectSortByType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ProjectSortByType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ProjectSortByType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ProjectSortByType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ProjectSortByType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for ProjectSortByType {
+    fn deserialize<D>(deserializer: D) -> Result<ProjectSortByType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about the build input source code for the build project.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ProjectSource {
@@ -1321,154 +3024,659 @@ pub struct ProjectSource {
     pub source_identifier: Option<String>,
     /// <p><p>The type of repository that contains the source code to be built. Valid values include:</p> <ul> <li> <p> <code>BITBUCKET</code>: The source code is in a Bitbucket repository.</p> </li> <li> <p> <code>CODECOMMIT</code>: The source code is in an AWS CodeCommit repository.</p> </li> <li> <p> <code>CODEPIPELINE</code>: The source code settings are specified in the source action of a pipeline in AWS CodePipeline.</p> </li> <li> <p> <code>GITHUB</code>: The source code is in a GitHub repository.</p> </li> <li> <p> <code>GITHUB<em>ENTERPRISE</code>: The source code is in a GitHub Enterprise repository.</p> </li> <li> <p> <code>NO</em>SOURCE</code>: The project does not have input source code.</p> </li> <li> <p> <code>S3</code>: The source code is in an Amazon Simple Storage Service (Amazon S3) input bucket.</p> </li> </ul></p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: SourceType,
+}
+
+/// <p> A source identifier and its corresponding version. </p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProjectSourceVersion {
+    /// <p>An identifier for a source in the build project.</p>
+    #[serde(rename = "sourceIdentifier")]
+    pub source_identifier: String,
+    /// <p>The source version for the corresponding source identifier. If specified, must be one of:</p> <ul> <li> <p>For AWS CodeCommit: the commit ID, branch, or Git tag to use.</p> </li> <li> <p>For GitHub: the commit ID, pull request ID, branch name, or tag name that corresponds to the version of the source code you want to build. If a pull request ID is specified, it must use the format <code>pr/pull-request-ID</code> (for example, <code>pr/25</code>). If a branch name is specified, the branch's HEAD commit ID is used. If not specified, the default branch's HEAD commit ID is used.</p> </li> <li> <p>For Bitbucket: the commit ID, branch name, or tag name that corresponds to the version of the source code you want to build. If a branch name is specified, the branch's HEAD commit ID is used. If not specified, the default branch's HEAD commit ID is used.</p> </li> <li> <p>For Amazon Simple Storage Service (Amazon S3): the version ID of the object that represents the build input ZIP file to use.</p> </li> </ul> <p> For more information, see <a href="https://docs.aws.amazon.com/codebuild/latest/userguide/sample-source-version.html">Source Version Sample with CodeBuild</a> in the <i>AWS CodeBuild User Guide</i>. </p>
+    #[serde(rename = "sourceVersion")]
+    pub source_version: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct PutResourcePolicyInput {
+    /// <p> A JSON-formatted resource policy. For more information, see <a href="https://docs.aws.amazon.com/codebuild/latest/userguide/project-sharing.html#project-sharing-share">Sharing a Project</a> and <a href="https://docs.aws.amazon.com/codebuild/latest/userguide/report-groups-sharing.html#report-groups-sharing-share">Sharing a Report Group</a> in the <i>AWS CodeBuild User Guide</i>. </p>
+    #[serde(rename = "policy")]
+    pub policy: String,
+    /// <p> The ARN of the <code>Project</code> or <code>ReportGroup</code> resource you want to associate with a resource policy. </p>
+    #[serde(rename = "resourceArn")]
+    pub resource_arn: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct PutResourcePolicyOutput {
+    /// <p> The ARN of the <code>Project</code> or <code>ReportGroup</code> resource that is associated with a resource policy. </p>
+    #[serde(rename = "resourceArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_arn: Option<String>,
+}
+
+/// <p> Information about credentials that provide access to a private Docker registry. When this is set: </p> <ul> <li> <p> <code>imagePullCredentialsType</code> must be set to <code>SERVICE_ROLE</code>. </p> </li> <li> <p> images cannot be curated or an Amazon ECR image.</p> </li> </ul> <p> For more information, see <a href="https://docs.aws.amazon.com/codebuild/latest/userguide/sample-private-registry.html">Private Registry with AWS Secrets Manager Sample for AWS CodeBuild</a>. </p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RegistryCredential {
+    /// <p><p> The Amazon Resource Name (ARN) or name of credentials created using AWS Secrets Manager. </p> <note> <p> The <code>credential</code> can use the name of the credentials only if they exist in your current region. </p> </note></p>
+    #[serde(rename = "credential")]
+    pub credential: String,
+    /// <p> The service that created the credentials to access a private Docker registry. The valid value, SECRETS_MANAGER, is for AWS Secrets Manager. </p>
+    #[serde(rename = "credentialProvider")]
+    pub credential_provider: CredentialProviderType,
+}
+
+/// <p> Information about the results from running a series of test cases during the run of a build project. The test cases are specified in the buildspec for the build project using one or more paths to the test case files. You can specify any type of tests you want, such as unit tests, integration tests, and functional tests. </p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct Report {
+    /// <p> The ARN of the report run. </p>
+    #[serde(rename = "arn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub arn: Option<String>,
+    /// <p> The date and time this report run occurred. </p>
+    #[serde(rename = "created")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<::chrono::DateTime<::chrono::Utc>>,
+    /// <p> The ARN of the build run that generated this report. </p>
+    #[serde(rename = "executionId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub execution_id: Option<String>,
+    /// <p> The date and time a report expires. A report expires 30 days after it is created. An expired report is not available to view in CodeBuild. </p>
+    #[serde(rename = "expired")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expired: Option<::chrono::DateTime<::chrono::Utc>>,
+    /// <p> Information about where the raw data used to generate this report was exported. </p>
+    #[serde(rename = "exportConfig")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub export_config: Option<ReportExportConfig>,
+    /// <p> The name of the report that was run. </p>
+    #[serde(rename = "name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// <p> The ARN of the report group associated with this report. </p>
+    #[serde(rename = "reportGroupArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub report_group_arn: Option<String>,
+    /// <p> The status of this report. </p>
+    #[serde(rename = "status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<ReportStatusType>,
+    /// <p> A <code>TestReportSummary</code> object that contains information about this test report. </p>
+    #[serde(rename = "testSummary")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub test_summary: Option<TestReportSummary>,
+    /// <p> A boolean that specifies if this report run is truncated. The list of test cases is truncated after the maximum number of test cases is reached. </p>
+    #[serde(rename = "truncated")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub truncated: Option<bool>,
+    /// <p> The type of the report that was run. </p>
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_: Option<ReportType>,
+}
+
+/// <p> Information about the location where the run of a report is exported. </p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReportExportConfig {
+    /// <p><p> The export configuration type. Valid values are: </p> <ul> <li> <p> <code>S3</code>: The report results are exported to an S3 bucket. </p> </li> <li> <p> <code>NO_EXPORT</code>: The report results are not exported. </p> </li> </ul></p>
+    #[serde(rename = "exportConfigType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub export_config_type: Option<ReportExportConfigType>,
+    /// <p> A <code>S3ReportExportConfig</code> object that contains information about the S3 bucket where the run of a report is exported. </p>
+    #[serde(rename = "s3Destination")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub s_3_destination: Option<S3ReportExportConfig>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ReportExportConfigType {
+    /// `S3`
+    S3,
+    /// `NO_EXPORT`
+    NoExport,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ReportExportConfigType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReportExportConfigType::S3 => "S3",
+            ReportExportConfigType::NoExport => "NO_EXPORT",
+            ReportExportConfigType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ReportExportConfigType {
+    fn default() -> ReportExportConfigType {
+        ReportExportConfigType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ReportExportConfigType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ReportExportConfigType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ReportExportConfigType {
+    fn from(value: &'a str) -> ReportExportConfigType {
+        match value {
+            "S3" => ReportExportConfigType::S3,
+            "NO_EXPORT" => ReportExportConfigType::NoExport,
+            _ => ReportExportConfigType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReportExportConfigType {
+    fn from(value: String) -> ReportExportConfigType {
+        match value.as_str() {
+            "S3" => ReportExportConfigType::S3,
+            "NO_EXPORT" => ReportExportConfigType::NoExport,
+            _ => ReportExportConfigType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ReportExportConfigType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ReportExportConfigType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ReportExportConfigType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ReportExportConfigType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ReportExportConfigType {
+    fn deserialize<D>(deserializer: D) -> Result<ReportExportConfigType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p> A filter used to return reports with the status specified by the input <code>status</code> parameter. </p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct ReportFilter {
+    /// <p> The status used to filter reports. You can filter using one status only. </p>
+    #[serde(rename = "status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<ReportStatusType>,
+}
+
+/// <p> A series of reports. Each report contains information about the results from running a series of test cases. You specify the test cases for a report group in the buildspec for a build project using one or more paths to the test case files. </p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct ReportGroup {
+    /// <p> The ARN of a <code>ReportGroup</code>. </p>
+    #[serde(rename = "arn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub arn: Option<String>,
+    /// <p> The date and time this <code>ReportGroup</code> was created. </p>
+    #[serde(rename = "created")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<::chrono::DateTime<::chrono::Utc>>,
+    /// <p> Information about the destination where the raw data of this <code>ReportGroup</code> is exported. </p>
+    #[serde(rename = "exportConfig")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub export_config: Option<ReportExportConfig>,
+    /// <p> The date and time this <code>ReportGroup</code> was last modified. </p>
+    #[serde(rename = "lastModified")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_modified: Option<::chrono::DateTime<::chrono::Utc>>,
+    /// <p> The name of a <code>ReportGroup</code>. </p>
+    #[serde(rename = "name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// <p> The type of the <code>ReportGroup</code>. The one valid value is <code>TEST</code>. </p>
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_: Option<ReportType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ReportGroupSortByType {
+    /// `NAME`
+    Name,
+    /// `CREATED_TIME`
+    CreatedTime,
+    /// `LAST_MODIFIED_TIME`
+    LastModifiedTime,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ReportGroupSortByType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReportGroupSortByType::Name => "NAME",
+            ReportGroupSortByType::CreatedTime => "CREATED_TIME",
+            ReportGroupSortByType::LastModifiedTime => "LAST_MODIFIED_TIME",
+            ReportGroupSortByType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ReportGroupSortByType {
+    fn default() -> ReportGroupSortByType {
+        ReportGroupSortByType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ReportGroupSortByType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ReportGroupSortByType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ReportGroupSortByType {
+    fn from(value: &'a str) -> ReportGroupSortByType {
+        match value {
+            "NAME" => ReportGroupSortByType::Name,
+            "CREATED_TIME" => ReportGroupSortByType::CreatedTime,
+            "LAST_MODIFIED_TIME" => ReportGroupSortByType::LastModifiedTime,
+            _ => ReportGroupSortByType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReportGroupSortByType {
+    fn from(value: String) -> ReportGroupSortByType {
+        match value.as_str() {
+            "NAME" => ReportGroupSortByType::Name,
+            "CREATED_TIME" => ReportGroupSortByType::CreatedTime,
+            "LAST_MODIFIED_TIME" => ReportGroupSortByType::LastModifiedTime,
+            _ => ReportGroupSortByType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ReportGroupSortByType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ReportGroupSortByType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ReportGroupSortByType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ReportGroupSortByType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ReportGroupSortByType {
+    fn deserialize<D>(deserializer: D) -> Result<ReportGroupSortByType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ReportPackagingType {
+    /// `ZIP`
+    Zip,
+    /// `NONE`
+    None,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ReportPackagingType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReportPackagingType::Zip => "ZIP",
+            ReportPackagingType::None => "NONE",
+            ReportPackagingType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ReportPackagingType {
+    fn default() -> ReportPackagingType {
+        ReportPackagingType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ReportPackagingType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ReportPackagingType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ReportPackagingType {
+    fn from(value: &'a str) -> ReportPackagingType {
+        match value {
+            "ZIP" => ReportPackagingType::Zip,
+            "NONE" => ReportPackagingType::None,
+            _ => ReportPackagingType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReportPackagingType {
+    fn from(value: String) -> ReportPackagingType {
+        match value.as_str() {
+            "ZIP" => ReportPackagingType::Zip,
+            "NONE" => ReportPackagingType::None,
+            _ => ReportPackagingType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ReportPackagingType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ReportPackagingType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ReportPackagingType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ReportPackagingType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ReportPackagingType {
+    fn deserialize<D>(deserializer: D) -> Result<ReportPackagingType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ReportStatusType {
+    /// `GENERATING`
+    Generating,
+    /// `SUCCEEDED`
+    Succeeded,
+    /// `FAILED`
+    Failed,
+    /// `INCOMPLETE`
+    Incomplete,
+    /// `DELETING`
+    Deleting,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ReportStatusType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReportStatusType::Generating => "GENERATING",
+            ReportStatusType::Succeeded => "SUCCEEDED",
+            ReportStatusType::Failed => "FAILED",
+            ReportStatusType::Incomplete => "INCOMPLETE",
+            ReportStatusType::Deleting => "DELETING",
+            ReportStatusType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ReportStatusType {
+    fn default() -> ReportStatusType {
+        ReportStatusType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ReportStatusType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ReportStatusType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ReportStatusType {
+    fn from(value: &'a str) -> ReportStatusType {
+        match value {
+            "GENERATING" => ReportStatusType::Generating,
+            "SUCCEEDED" => ReportStatusType::Succeeded,
+            "FAILED" => ReportStatusType::Failed,
+            "INCOMPLETE" => ReportStatusType::Incomplete,
+            "DELETING" => ReportStatusType::Deleting,
+            _ => ReportStatusType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReportStatusType {
+    fn from(value: String) -> ReportStatusType {
+        match value.as_str() {
+            "GENERATING" => ReportStatusType::Generating,
+            "SUCCEEDED" => ReportStatusType::Succeeded,
+            "FAILED" => ReportStatusType::Failed,
+            "INCOMPLETE" => ReportStatusType::Incomplete,
+            "DELETING" => ReportStatusType::Deleting,
+            _ => ReportStatusType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ReportStatusType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ReportStatusType, Self::Err> {
+        Ok(value.into())
+    }
 }
 
-/// <p> A source identifier and its corresponding version. </p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct ProjectSourceVersion {
-    /// <p>An identifier for a source in the build project.</p>
-    #[serde(rename = "sourceIdentifier")]
-    pub source_identifier: String,
-    /// <p>The source version for the corresponding source identifier. If specified, must be one of:</p> <ul> <li> <p>For AWS CodeCommit: the commit ID, branch, or Git tag to use.</p> </li> <li> <p>For GitHub: the commit ID, pull request ID, branch name, or tag name that corresponds to the version of the source code you want to build. If a pull request ID is specified, it must use the format <code>pr/pull-request-ID</code> (for example, <code>pr/25</code>). If a branch name is specified, the branch's HEAD commit ID is used. If not specified, the default branch's HEAD commit ID is used.</p> </li> <li> <p>For Bitbucket: the commit ID, branch name, or tag name that corresponds to the version of the source code you want to build. If a branch name is specified, the branch's HEAD commit ID is used. If not specified, the default branch's HEAD commit ID is used.</p> </li> <li> <p>For Amazon Simple Storage Service (Amazon S3): the version ID of the object that represents the build input ZIP file to use.</p> </li> </ul> <p> For more information, see <a href="https://docs.aws.amazon.com/codebuild/latest/userguide/sample-source-version.html">Source Version Sample with CodeBuild</a> in the <i>AWS CodeBuild User Guide</i>. </p>
-    #[serde(rename = "sourceVersion")]
-    pub source_version: String,
+impl ::rusoto_core::param::ToParam for ReportStatusType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct PutResourcePolicyInput {
-    /// <p> A JSON-formatted resource policy. For more information, see <a href="https://docs.aws.amazon.com/codebuild/latest/userguide/project-sharing.html#project-sharing-share">Sharing a Project</a> and <a href="https://docs.aws.amazon.com/codebuild/latest/userguide/report-groups-sharing.html#report-groups-sharing-share">Sharing a Report Group</a> in the <i>AWS CodeBuild User Guide</i>. </p>
-    #[serde(rename = "policy")]
-    pub policy: String,
-    /// <p> The ARN of the <code>Project</code> or <code>ReportGroup</code> resource you want to associate with a resource policy. </p>
-    #[serde(rename = "resourceArn")]
-    pub resource_arn: String,
+impl ::serde::Serialize for ReportStatusType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct PutResourcePolicyOutput {
-    /// <p> The ARN of the <code>Project</code> or <code>ReportGroup</code> resource that is associated with a resource policy. </p>
-    #[serde(rename = "resourceArn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_arn: Option<String>,
+impl<'de> ::serde::Deserialize<'de> for ReportStatusType {
+    fn deserialize<D>(deserializer: D) -> Result<ReportStatusType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ReportType {
+    /// `TEST`
+    Test,
+    /// `CODE_COVERAGE`
+    CodeCoverage,
+    /// A value not known to this version of the crate
+    Unknown(String),
 }
 
-/// <p> Information about credentials that provide access to a private Docker registry. When this is set: </p> <ul> <li> <p> <code>imagePullCredentialsType</code> must be set to <code>SERVICE_ROLE</code>. </p> </li> <li> <p> images cannot be curated or an Amazon ECR image.</p> </li> </ul> <p> For more information, see <a href="https://docs.aws.amazon.com/codebuild/latest/userguide/sample-private-registry.html">Private Registry with AWS Secrets Manager Sample for AWS CodeBuild</a>. </p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct RegistryCredential {
-    /// <p><p> The Amazon Resource Name (ARN) or name of credentials created using AWS Secrets Manager. </p> <note> <p> The <code>credential</code> can use the name of the credentials only if they exist in your current region. </p> </note></p>
-    #[serde(rename = "credential")]
-    pub credential: String,
-    /// <p> The service that created the credentials to access a private Docker registry. The valid value, SECRETS_MANAGER, is for AWS Secrets Manager. </p>
-    #[serde(rename = "credentialProvider")]
-    pub credential_provider: String,
+impl ReportType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReportType::Test => "TEST",
+            ReportType::CodeCoverage => "CODE_COVERAGE",
+            ReportType::Unknown(ref value) => value,
+        }
+    }
 }
 
-/// <p> Information about the results from running a series of test cases during the run of a build project. The test cases are specified in the buildspec for the build project using one or more paths to the test case files. You can specify any type of tests you want, such as unit tests, integration tests, and functional tests. </p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct Report {
-    /// <p> The ARN of the report run. </p>
-    #[serde(rename = "arn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub arn: Option<String>,
-    /// <p> The date and time this report run occurred. </p>
-    #[serde(rename = "created")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub created: Option<f64>,
-    /// <p> The ARN of the build run that generated this report. </p>
-    #[serde(rename = "executionId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub execution_id: Option<String>,
-    /// <p> The date and time a report expires. A report expires 30 days after it is created. An expired report is not available to view in CodeBuild. </p>
-    #[serde(rename = "expired")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub expired: Option<f64>,
-    /// <p> Information about where the raw data used to generate this report was exported. </p>
-    #[serde(rename = "exportConfig")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub export_config: Option<ReportExportConfig>,
-    /// <p> The name of the report that was run. </p>
-    #[serde(rename = "name")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
-    /// <p> The ARN of the report group associated with this report. </p>
-    #[serde(rename = "reportGroupArn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub report_group_arn: Option<String>,
-    /// <p> The status of this report. </p>
-    #[serde(rename = "status")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
-    /// <p> A <code>TestReportSummary</code> object that contains information about this test report. </p>
-    #[serde(rename = "testSummary")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub test_summary: Option<TestReportSummary>,
-    /// <p> A boolean that specifies if this report run is truncated. The list of test cases is truncated after the maximum number of test cases is reached. </p>
-    #[serde(rename = "truncated")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub truncated: Option<bool>,
-    /// <p> The type of the report that was run. </p>
-    #[serde(rename = "type")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ReportType {
+    fn default() -> ReportType {
+        ReportType::Unknown(String::new())
+    }
 }
 
-/// <p> Information about the location where the run of a report is exported. </p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct ReportExportConfig {
-    /// <p><p> The export configuration type. Valid values are: </p> <ul> <li> <p> <code>S3</code>: The report results are exported to an S3 bucket. </p> </li> <li> <p> <code>NO_EXPORT</code>: The report results are not exported. </p> </li> </ul></p>
-    #[serde(rename = "exportConfigType")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub export_config_type: Option<String>,
-    /// <p> A <code>S3ReportExportConfig</code> object that contains information about the S3 bucket where the run of a report is exported. </p>
-    #[serde(rename = "s3Destination")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub s_3_destination: Option<S3ReportExportConfig>,
+impl AsRef<str> for ReportType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
 }
 
-/// <p> A filter used to return reports with the status specified by the input <code>status</code> parameter. </p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct ReportFilter {
-    /// <p> The status used to filter reports. You can filter using one status only. </p>
-    #[serde(rename = "status")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+impl fmt::Display for ReportType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
-/// <p> A series of reports. Each report contains information about the results from running a series of test cases. You specify the test cases for a report group in the buildspec for a build project using one or more paths to the test case files. </p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct ReportGroup {
-    /// <p> The ARN of a <code>ReportGroup</code>. </p>
-    #[serde(rename = "arn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub arn: Option<String>,
-    /// <p> The date and time this <code>ReportGroup</code> was created. </p>
-    #[serde(rename = "created")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub created: Option<f64>,
-    /// <p> Information about the destination where the raw data of this <code>ReportGroup</code> is exported. </p>
-    #[serde(rename = "exportConfig")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub export_config: Option<ReportExportConfig>,
-    /// <p> The date and time this <code>ReportGroup</code> was last modified. </p>
-    #[serde(rename = "lastModified")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified: Option<f64>,
-    /// <p> The name of a <code>ReportGroup</code>. </p>
-    #[serde(rename = "name")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
-    /// <p> The type of the <code>ReportGroup</code>. The one valid value is <code>TEST</code>. </p>
-    #[serde(rename = "type")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+impl<'a> From<&'a str> for ReportType {
+    fn from(value: &'a str) -> ReportType {
+        match value {
+            "TEST" => ReportType::Test,
+            "CODE_COVERAGE" => ReportType::CodeCoverage,
+            _ => ReportType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReportType {
+    fn from(value: String) -> ReportType {
+        match value.as_str() {
+            "TEST" => ReportType::Test,
+            "CODE_COVERAGE" => ReportType::CodeCoverage,
+            _ => ReportType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ReportType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ReportType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ReportType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ReportType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for ReportType {
+    fn deserialize<D>(deserializer: D) -> Result<ReportType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p> Information about S3 logs for a build project. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct S3LogsConfig {
@@ -1482,7 +3690,7 @@ pub struct S3LogsConfig {
     pub location: Option<String>,
     /// <p><p>The current status of the S3 build logs. Valid values are:</p> <ul> <li> <p> <code>ENABLED</code>: S3 build logs are enabled for this build project.</p> </li> <li> <p> <code>DISABLED</code>: S3 build logs are not enabled for this build project.</p> </li> </ul></p>
     #[serde(rename = "status")]
-    pub status: String,
+    pub status: LogsConfigStatusType,
 }
 
 /// <p> Information about the S3 bucket where the raw data of a report are exported. </p>
@@ -1503,13 +3711,297 @@ pub struct S3ReportExportConfig {
     /// <p><p> The type of build output artifact to create. Valid values include: </p> <ul> <li> <p> <code>NONE</code>: AWS CodeBuild creates the raw data in the output bucket. This is the default if packaging is not specified. </p> </li> <li> <p> <code>ZIP</code>: AWS CodeBuild creates a ZIP file with the raw data in the output bucket. </p> </li> </ul></p>
     #[serde(rename = "packaging")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub packaging: Option<String>,
+    pub packaging: Option<ReportPackagingType>,
     /// <p> The path to the exported report's raw data results. </p>
     #[serde(rename = "path")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub path: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ServerType {
+    /// `GITHUB`
+    Github,
+    /// `BITBUCKET`
+    Bitbucket,
+    /// `GITHUB_ENTERPRISE`
+    GithubEnterprise,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ServerType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ServerType::Github => "GITHUB",
+            ServerType::Bitbucket => "BITBUCKET",
+            ServerType::GithubEnterprise => "GITHUB_ENTERPRISE",
+            ServerType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ServerType {
+    fn default() -> ServerType {
+        ServerType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ServerType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ServerType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ServerType {
+    fn from(value: &'a str) -> ServerType {
+        match value {
+            "GITHUB" => ServerType::Github,
+            "BITBUCKET" => ServerType::Bitbucket,
+            "GITHUB_ENTERPRISE" => ServerType::GithubEnterprise,
+            _ => ServerType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ServerType {
+    fn from(value: String) -> ServerType {
+        match value.as_str() {
+            "GITHUB" => ServerType::Github,
+            "BITBUCKET" => ServerType::Bitbucket,
+            "GITHUB_ENTERPRISE" => ServerType::GithubEnterprise,
+            _ => ServerType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ServerType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ServerType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ServerType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ServerType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ServerType {
+    fn deserialize<D>(deserializer: D) -> Result<ServerType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SharedResourceSortByType {
+    /// `ARN`
+    Arn,
+    /// `MODIFIED_TIME`
+    ModifiedTime,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SharedResourceSortByType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SharedResourceSortByType::Arn => "ARN",
+            SharedResourceSortByType::ModifiedTime => "MODIFIED_TIME",
+            SharedResourceSortByType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SharedResourceSortByType {
+    fn default() -> SharedResourceSortByType {
+        SharedResourceSortByType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SharedResourceSortByType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SharedResourceSortByType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SharedResourceSortByType {
+    fn from(value: &'a str) -> SharedResourceSortByType {
+        match value {
+            "ARN" => SharedResourceSortByType::Arn,
+            "MODIFIED_TIME" => SharedResourceSortByType::ModifiedTime,
+            _ => SharedResourceSortByType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SharedResourceSortByType {
+    fn from(value: String) -> SharedResourceSortByType {
+        match value.as_str() {
+            "ARN" => SharedResourceSortByType::Arn,
+            "MODIFIED_TIME" => SharedResourceSortByType::ModifiedTime,
+            _ => SharedResourceSortByType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SharedResourceSortByType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SharedResourceSortByType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SharedResourceSortByType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SharedResourceSortByType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SharedResourceSortByType {
+    fn deserialize<D>(deserializer: D) -> Result<SharedResourceSortByType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SortOrderType {
+    /// `ASCENDING`
+    Ascending,
+    /// `DESCENDING`
+    Descending,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SortOrderType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SortOrderType::Ascending => "ASCENDING",
+            SortOrderType::Descending => "DESCENDING",
+            SortOrderType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SortOrderType {
+    fn default() -> SortOrderType {
+        SortOrderType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SortOrderType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SortOrderType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SortOrderType {
+    fn from(value: &'a str) -> SortOrderType {
+        match value {
+            "ASCENDING" => SortOrderType::Ascending,
+            "DESCENDING" => SortOrderType::Descending,
+            _ => SortOrderType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SortOrderType {
+    fn from(value: String) -> SortOrderType {
+        match value.as_str() {
+            "ASCENDING" => SortOrderType::Ascending,
+            "DESCENDING" => SortOrderType::Descending,
+            _ => SortOrderType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SortOrderType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SortOrderType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SortOrderType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SortOrderType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SortOrderType {
+    fn deserialize<D>(deserializer: D) -> Result<SortOrderType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about the authorization settings for AWS CodeBuild to access the source code to be built.</p> <p>This information is for the AWS CodeBuild console's use only. Your code should not get or set this information directly.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SourceAuth {
@@ -1519,9 +4011,97 @@ pub struct SourceAuth {
     pub resource: Option<String>,
     /// <p><note> <p> This data type is deprecated and is no longer accurate or used. </p> </note> <p>The authorization type to use. The only valid value is <code>OAUTH</code>, which represents the OAuth authorization type.</p></p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: SourceAuthType,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SourceAuthType {
+    /// `OAUTH`
+    Oauth,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SourceAuthType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SourceAuthType::Oauth => "OAUTH",
+            SourceAuthType::Unknown(ref value) => value,
+        }
+    }
 }
 
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SourceAuthType {
+    fn default() -> SourceAuthType {
+        SourceAuthType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SourceAuthType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SourceAuthType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SourceAuthType {
+    fn from(value: &'a str) -> SourceAuthType {
+        match value {
+            "OAUTH" => SourceAuthType::Oauth,
+            _ => SourceAuthType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SourceAuthType {
+    fn from(value: String) -> SourceAuthType {
+        match value.as_str() {
+            "OAUTH" => SourceAuthType::Oauth,
+            _ => SourceAuthType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SourceAuthType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SourceAuthType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SourceAuthType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SourceAuthType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SourceAuthType {
+    fn deserialize<D>(deserializer: D) -> Result<SourceAuthType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p> Information about the credentials for a GitHub, GitHub Enterprise, or Bitbucket repository. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1533,13 +4113,131 @@ pub struct SourceCredentialsInfo {
     /// <p> The type of authentication used by the credentials. Valid options are OAUTH, BASIC_AUTH, or PERSONAL_ACCESS_TOKEN. </p>
     #[serde(rename = "authType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub auth_type: Option<String>,
+    pub auth_type: Option<AuthType>,
     /// <p> The type of source provider. The valid options are GITHUB, GITHUB_ENTERPRISE, or BITBUCKET. </p>
     #[serde(rename = "serverType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub server_type: Option<String>,
+    pub server_type: Option<ServerType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SourceType {
+    /// `CODECOMMIT`
+    Codecommit,
+    /// `CODEPIPELINE`
+    Codepipeline,
+    /// `GITHUB`
+    Github,
+    /// `S3`
+    S3,
+    /// `BITBUCKET`
+    Bitbucket,
+    /// `GITHUB_ENTERPRISE`
+    GithubEnterprise,
+    /// `NO_SOURCE`
+    NoSource,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SourceType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SourceType::Codecommit => "CODECOMMIT",
+            SourceType::Codepipeline => "CODEPIPELINE",
+            SourceType::Github => "GITHUB",
+            SourceType::S3 => "S3",
+            SourceType::Bitbucket => "BITBUCKET",
+            SourceType::GithubEnterprise => "GITHUB_ENTERPRISE",
+            SourceType::NoSource => "NO_SOURCE",
+            SourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SourceType {
+    fn default() -> SourceType {
+        SourceType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SourceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SourceType {
+    fn from(value: &'a str) -> SourceType {
+        match value {
+            "CODECOMMIT" => SourceType::Codecommit,
+            "CODEPIPELINE" => SourceType::Codepipeline,
+            "GITHUB" => SourceType::Github,
+            "S3" => SourceType::S3,
+            "BITBUCKET" => SourceType::Bitbucket,
+            "GITHUB_ENTERPRISE" => SourceType::GithubEnterprise,
+            "NO_SOURCE" => SourceType::NoSource,
+            _ => SourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SourceType {
+    fn from(value: String) -> SourceType {
+        match value.as_str() {
+            "CODECOMMIT" => SourceType::Codecommit,
+            "CODEPIPELINE" => SourceType::Codepipeline,
+            "GITHUB" => SourceType::Github,
+            "S3" => SourceType::S3,
+            "BITBUCKET" => SourceType::Bitbucket,
+            "GITHUB_ENTERPRISE" => SourceType::GithubEnterprise,
+            "NO_SOURCE" => SourceType::NoSource,
+            _ => SourceType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SourceType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SourceType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SourceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SourceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for SourceType {
+    fn deserialize<D>(deserializer: D) -> Result<SourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct StartBuildInput {
@@ -1562,7 +4260,7 @@ pub struct StartBuildInput {
     /// <p>The name of a compute type for this build that overrides the one specified in the build project.</p>
     #[serde(rename = "computeTypeOverride")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compute_type_override: Option<String>,
+    pub compute_type_override: Option<ComputeType>,
     /// <p>The AWS Key Management Service (AWS KMS) customer master key (CMK) that overrides the one specified in the build project. The CMK key encrypts the build output artifacts.</p> <note> <p> You can use a cross-account KMS key to encrypt the build output artifacts if your service role has permission to that key. </p> </note> <p>You can specify either the Amazon Resource Name (ARN) of the CMK or, if available, the CMK's alias (using the format <code>alias/<i>alias-name</i> </code>).</p>
     #[serde(rename = "encryptionKeyOverride")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1570,7 +4268,7 @@ pub struct StartBuildInput {
     /// <p>A container type for this build that overrides the one specified in the build project.</p>
     #[serde(rename = "environmentTypeOverride")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub environment_type_override: Option<String>,
+    pub environment_type_override: Option<EnvironmentType>,
     /// <p>A set of environment variables that overrides, for this build only, the latest ones already defined in the build project.</p>
     #[serde(rename = "environmentVariablesOverride")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1594,7 +4292,7 @@ pub struct StartBuildInput {
     /// <p> The type of credentials AWS CodeBuild uses to pull images in your build. There are two valid values: </p> <ul> <li> <p> <code>CODEBUILD</code> specifies that AWS CodeBuild uses its own credentials. This requires that you modify your ECR repository policy to trust AWS CodeBuild's service principal.</p> </li> <li> <p> <code>SERVICE_ROLE</code> specifies that AWS CodeBuild uses your build project's service role. </p> </li> </ul> <p> When using a cross-account or private registry image, you must use SERVICE_ROLE credentials. When using an AWS CodeBuild curated image, you must use CODEBUILD credentials. </p>
     #[serde(rename = "imagePullCredentialsTypeOverride")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub image_pull_credentials_type_override: Option<String>,
+    pub image_pull_credentials_type_override: Option<ImagePullCredentialsType>,
     /// <p>Enable this flag to override the insecure SSL setting that is specified in the build project. The insecure SSL setting determines whether to ignore SSL warnings while connecting to the project source code. This override applies only if the build's source is GitHub Enterprise.</p>
     #[serde(rename = "insecureSslOverride")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1649,7 +4347,7 @@ pub struct StartBuildInput {
     /// <p>A source input type, for this build, that overrides the source input defined in the build project.</p>
     #[serde(rename = "sourceTypeOverride")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub source_type_override: Option<String>,
+    pub source_type_override: Option<SourceType>,
     /// <p>A version of the build input to be built, for this build only. If not specified, the latest version is used. If specified, must be one of:</p> <ul> <li> <p>For AWS CodeCommit: the commit ID, branch, or Git tag to use.</p> </li> <li> <p>For GitHub: the commit ID, pull request ID, branch name, or tag name that corresponds to the version of the source code you want to build. If a pull request ID is specified, it must use the format <code>pr/pull-request-ID</code> (for example <code>pr/25</code>). If a branch name is specified, the branch's HEAD commit ID is used. If not specified, the default branch's HEAD commit ID is used.</p> </li> <li> <p>For Bitbucket: the commit ID, branch name, or tag name that corresponds to the version of the source code you want to build. If a branch name is specified, the branch's HEAD commit ID is used. If not specified, the default branch's HEAD commit ID is used.</p> </li> <li> <p>For Amazon Simple Storage Service (Amazon S3): the version ID of the object that represents the build input ZIP file to use.</p> </li> </ul> <p> If <code>sourceVersion</code> is specified at the project level, then this <code>sourceVersion</code> (at the build level) takes precedence. </p> <p> For more information, see <a href="https://docs.aws.amazon.com/codebuild/latest/userguide/sample-source-version.html">Source Version Sample with CodeBuild</a> in the <i>AWS CodeBuild User Guide</i>. </p>
     #[serde(rename = "sourceVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1669,6 +4367,119 @@ pub struct StartBuildOutput {
     pub build: Option<Build>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StatusType {
+    /// `SUCCEEDED`
+    Succeeded,
+    /// `FAILED`
+    Failed,
+    /// `FAULT`
+    Fault,
+    /// `TIMED_OUT`
+    TimedOut,
+    /// `IN_PROGRESS`
+    InProgress,
+    /// `STOPPED`
+    Stopped,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl StatusType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StatusType::Succeeded => "SUCCEEDED",
+            StatusType::Failed => "FAILED",
+            StatusType::Fault => "FAULT",
+            StatusType::TimedOut => "TIMED_OUT",
+            StatusType::InProgress => "IN_PROGRESS",
+            StatusType::Stopped => "STOPPED",
+            StatusType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for StatusType {
+    fn default() -> StatusType {
+        StatusType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for StatusType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for StatusType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for StatusType {
+    fn from(value: &'a str) -> StatusType {
+        match value {
+            "SUCCEEDED" => StatusType::Succeeded,
+            "FAILED" => StatusType::Failed,
+            "FAULT" => StatusType::Fault,
+            "TIMED_OUT" => StatusType::TimedOut,
+            "IN_PROGRESS" => StatusType::InProgress,
+            "STOPPED" => StatusType::Stopped,
+            _ => StatusType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StatusType {
+    fn from(value: String) -> StatusType {
+        match value.as_str() {
+            "SUCCEEDED" => StatusType::Succeeded,
+            "FAILED" => StatusType::Failed,
+            "FAULT" => StatusType::Fault,
+            "TIMED_OUT" => StatusType::TimedOut,
+            "IN_PROGRESS" => StatusType::InProgress,
+            "STOPPED" => StatusType::Stopped,
+            _ => StatusType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for StatusType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<StatusType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StatusType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for StatusType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StatusType {
+    fn deserialize<D>(deserializer: D) -> Result<StatusType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct StopBuildInput {
@@ -1709,8 +4520,13 @@ pub struct TestCase {
     pub duration_in_nano_seconds: Option<i64>,
     /// <p> The date and time a test case expires. A test case expires 30 days after it is created. An expired test case is not available to view in CodeBuild. </p>
     #[serde(rename = "expired")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expired: Option<f64>,
+    pub expired: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p> A message associated with a test case. For example, an error message or stack trace. </p>
     #[serde(rename = "message")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1928,8 +4744,13 @@ pub struct Webhook {
     pub filter_groups: Option<Vec<Vec<WebhookFilter>>>,
     /// <p> A timestamp that indicates the last time a repository's secret token was modified. </p>
     #[serde(rename = "lastModifiedSecret")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_secret: Option<f64>,
+    pub last_modified_secret: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p> The AWS CodeBuild endpoint where webhook events are sent.</p>
     #[serde(rename = "payloadUrl")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1956,9 +4777,122 @@ pub struct WebhookFilter {
     pub pattern: String,
     /// <p><p> The type of webhook filter. There are five webhook filter types: <code>EVENT</code>, <code>ACTOR<em>ACCOUNT</em>ID</code>, <code>HEAD<em>REF</code>, <code>BASE</em>REF</code>, and <code>FILE<em>PATH</code>. </p> <dl> <dt> EVENT </dt> <dd> <p> A webhook event triggers a build when the provided <code>pattern</code> matches one of four event types: <code>PUSH</code>, <code>PULL</em>REQUEST<em>CREATED</code>, <code>PULL</em>REQUEST<em>UPDATED</code>, and <code>PULL</em>REQUEST<em>REOPENED</code>. The <code>EVENT</code> patterns are specified as a comma-separated string. For example, <code>PUSH, PULL</em>REQUEST<em>CREATED, PULL</em>REQUEST<em>UPDATED</code> filters all push, pull request created, and pull request updated events. </p> <note> <p> The <code>PULL</em>REQUEST<em>REOPENED</code> works with GitHub and GitHub Enterprise only. </p> </note> </dd> <dt> ACTOR</em>ACCOUNT<em>ID </dt> <dd> <p> A webhook event triggers a build when a GitHub, GitHub Enterprise, or Bitbucket account ID matches the regular expression <code>pattern</code>. </p> </dd> <dt> HEAD</em>REF </dt> <dd> <p> A webhook event triggers a build when the head reference matches the regular expression <code>pattern</code>. For example, <code>refs/heads/branch-name</code> and <code>refs/tags/tag-name</code>. </p> <p> Works with GitHub and GitHub Enterprise push, GitHub and GitHub Enterprise pull request, Bitbucket push, and Bitbucket pull request events. </p> </dd> <dt> BASE<em>REF </dt> <dd> <p> A webhook event triggers a build when the base reference matches the regular expression <code>pattern</code>. For example, <code>refs/heads/branch-name</code>. </p> <note> <p> Works with pull request events only. </p> </note> </dd> <dt> FILE</em>PATH </dt> <dd> <p> A webhook triggers a build when the path of a changed file matches the regular expression <code>pattern</code>. </p> <note> <p> Works with GitHub and GitHub Enterprise push events only. </p> </note> </dd> </dl></p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: WebhookFilterType,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum WebhookFilterType {
+    /// `EVENT`
+    Event,
+    /// `BASE_REF`
+    BaseRef,
+    /// `HEAD_REF`
+    HeadRef,
+    /// `ACTOR_ACCOUNT_ID`
+    ActorAccountId,
+    /// `FILE_PATH`
+    FilePath,
+    /// `COMMIT_MESSAGE`
+    CommitMessage,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl WebhookFilterType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            WebhookFilterType::Event => "EVENT",
+            WebhookFilterType::BaseRef => "BASE_REF",
+            WebhookFilterType::HeadRef => "HEAD_REF",
+            WebhookFilterType::ActorAccountId => "ACTOR_ACCOUNT_ID",
+            WebhookFilterType::FilePath => "FILE_PATH",
+            WebhookFilterType::CommitMessage => "COMMIT_MESSAGE",
+            WebhookFilterType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for WebhookFilterType {
+    fn default() -> WebhookFilterType {
+        WebhookFilterType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for WebhookFilterType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for WebhookFilterType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for WebhookFilterType {
+    fn from(value: &'a str) -> WebhookFilterType {
+        match value {
+            "EVENT" => WebhookFilterType::Event,
+            "BASE_REF" => WebhookFilterType::BaseRef,
+            "HEAD_REF" => WebhookFilterType::HeadRef,
+            "ACTOR_ACCOUNT_ID" => WebhookFilterType::ActorAccountId,
+            "FILE_PATH" => WebhookFilterType::FilePath,
+            "COMMIT_MESSAGE" => WebhookFilterType::CommitMessage,
+            _ => WebhookFilterType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for WebhookFilterType {
+    fn from(value: String) -> WebhookFilterType {
+        match value.as_str() {
+            "EVENT" => WebhookFilterType::Event,
+            "BASE_REF" => WebhookFilterType::BaseRef,
+            "HEAD_REF" => WebhookFilterType::HeadRef,
+            "ACTOR_ACCOUNT_ID" => WebhookFilterType::ActorAccountId,
+            "FILE_PATH" => WebhookFilterType::FilePath,
+            "COMMIT_MESSAGE" => WebhookFilterType::CommitMessage,
+            _ => WebhookFilterType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for WebhookFilterType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<WebhookFilterType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for WebhookFilterType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for WebhookFilterType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for WebhookFilterType {
+    fn deserialize<D>(deserializer: D) -> Result<WebhookFilterType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// Errors returned by BatchDeleteBuilds
 #[derive(Debug, PartialEq)]
 pub enum BatchDeleteBuildsError {
@@ -3354,11 +6288,12 @@ pub struct CodeBuildClient {
 impl CodeBuildClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> CodeBuildClient {
         CodeBuildClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "CodeBuild"),
         }
     }
 
@@ -3390,6 +6325,7 @@ impl CodeBuild for CodeBuildClient {
         input: BatchDeleteBuildsInput,
     ) -> Result<BatchDeleteBuildsOutput, RusotoError<BatchDeleteBuildsError>> {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("BatchDeleteBuilds");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.BatchDeleteBuilds");
@@ -3417,6 +6353,7 @@ impl CodeBuild for CodeBuildClient {
         input: BatchGetBuildsInput,
     ) -> Result<BatchGetBuildsOutput, RusotoError<BatchGetBuildsError>> {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("BatchGetBuilds");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.BatchGetBuilds");
@@ -3444,6 +6381,7 @@ impl CodeBuild for CodeBuildClient {
         input: BatchGetProjectsInput,
     ) -> Result<BatchGetProjectsOutput, RusotoError<BatchGetProjectsError>> {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("BatchGetProjects");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.BatchGetProjects");
@@ -3471,6 +6409,7 @@ impl CodeBuild for CodeBuildClient {
         input: BatchGetReportGroupsInput,
     ) -> Result<BatchGetReportGroupsOutput, RusotoError<BatchGetReportGroupsError>> {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("BatchGetReportGroups");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.BatchGetReportGroups");
@@ -3499,6 +6438,7 @@ impl CodeBuild for CodeBuildClient {
         input: BatchGetReportsInput,
     ) -> Result<BatchGetReportsOutput, RusotoError<BatchGetReportsError>> {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("BatchGetReports");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.BatchGetReports");
@@ -3526,6 +6466,7 @@ impl CodeBuild for CodeBuildClient {
         input: CreateProjectInput,
     ) -> Result<CreateProjectOutput, RusotoError<CreateProjectError>> {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("CreateProject");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.CreateProject");
@@ -3553,6 +6494,7 @@ impl CodeBuild for CodeBuildClient {
         input: CreateReportGroupInput,
     ) -> Result<CreateReportGroupOutput, RusotoError<CreateReportGroupError>> {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("CreateReportGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.CreateReportGroup");
@@ -3580,6 +6522,7 @@ impl CodeBuild for CodeBuildClient {
         input: CreateWebhookInput,
     ) -> Result<CreateWebhookOutput, RusotoError<CreateWebhookError>> {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("CreateWebhook");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.CreateWebhook");
@@ -3607,6 +6550,7 @@ impl CodeBuild for CodeBuildClient {
         input: DeleteProjectInput,
     ) -> Result<DeleteProjectOutput, RusotoError<DeleteProjectError>> {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("DeleteProject");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.DeleteProject");
@@ -3634,6 +6578,7 @@ impl CodeBuild for CodeBuildClient {
         input: DeleteReportInput,
     ) -> Result<DeleteReportOutput, RusotoError<DeleteReportError>> {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("DeleteReport");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.DeleteReport");
@@ -3661,6 +6606,7 @@ impl CodeBuild for CodeBuildClient {
         input: DeleteReportGroupInput,
     ) -> Result<DeleteReportGroupOutput, RusotoError<DeleteReportGroupError>> {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("DeleteReportGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.DeleteReportGroup");
@@ -3688,6 +6634,7 @@ impl CodeBuild for CodeBuildClient {
         input: DeleteResourcePolicyInput,
     ) -> Result<DeleteResourcePolicyOutput, RusotoError<DeleteResourcePolicyError>> {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("DeleteResourcePolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.DeleteResourcePolicy");
@@ -3716,6 +6663,7 @@ impl CodeBuild for CodeBuildClient {
         input: DeleteSourceCredentialsInput,
     ) -> Result<DeleteSourceCredentialsOutput, RusotoError<DeleteSourceCredentialsError>> {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("DeleteSourceCredentials");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.DeleteSourceCredentials");
@@ -3744,6 +6692,7 @@ impl CodeBuild for CodeBuildClient {
         input: DeleteWebhookInput,
     ) -> Result<DeleteWebhookOutput, RusotoError<DeleteWebhookError>> {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("DeleteWebhook");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.DeleteWebhook");
@@ -3771,6 +6720,7 @@ impl CodeBuild for CodeBuildClient {
         input: DescribeTestCasesInput,
     ) -> Result<DescribeTestCasesOutput, RusotoError<DescribeTestCasesError>> {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("DescribeTestCases");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.DescribeTestCases");
@@ -3798,6 +6748,7 @@ impl CodeBuild for CodeBuildClient {
         input: GetResourcePolicyInput,
     ) -> Result<GetResourcePolicyOutput, RusotoError<GetResourcePolicyError>> {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("GetResourcePolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.GetResourcePolicy");
@@ -3825,6 +6776,7 @@ impl CodeBuild for CodeBuildClient {
         input: ImportSourceCredentialsInput,
     ) -> Result<ImportSourceCredentialsOutput, RusotoError<ImportSourceCredentialsError>> {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("ImportSourceCredentials");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.ImportSourceCredentials");
@@ -3853,6 +6805,7 @@ impl CodeBuild for CodeBuildClient {
         input: InvalidateProjectCacheInput,
     ) -> Result<InvalidateProjectCacheOutput, RusotoError<InvalidateProjectCacheError>> {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("InvalidateProjectCache");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.InvalidateProjectCache");
@@ -3881,6 +6834,7 @@ impl CodeBuild for CodeBuildClient {
         input: ListBuildsInput,
     ) -> Result<ListBuildsOutput, RusotoError<ListBuildsError>> {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("ListBuilds");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.ListBuilds");
@@ -3908,6 +6862,7 @@ impl CodeBuild for CodeBuildClient {
         input: ListBuildsForProjectInput,
     ) -> Result<ListBuildsForProjectOutput, RusotoError<ListBuildsForProjectError>> {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("ListBuildsForProject");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.ListBuildsForProject");
@@ -3936,6 +6891,7 @@ impl CodeBuild for CodeBuildClient {
     ) -> Result<ListCuratedEnvironmentImagesOutput, RusotoError<ListCuratedEnvironmentImagesError>>
     {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("ListCuratedEnvironmentImages");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3966,6 +6922,7 @@ impl CodeBuild for CodeBuildClient {
         input: ListProjectsInput,
     ) -> Result<ListProjectsOutput, RusotoError<ListProjectsError>> {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("ListProjects");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.ListProjects");
@@ -3993,6 +6950,7 @@ impl CodeBuild for CodeBuildClient {
         input: ListReportGroupsInput,
     ) -> Result<ListReportGroupsOutput, RusotoError<ListReportGroupsError>> {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("ListReportGroups");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.ListReportGroups");
@@ -4020,6 +6978,7 @@ impl CodeBuild for CodeBuildClient {
         input: ListReportsInput,
     ) -> Result<ListReportsOutput, RusotoError<ListReportsError>> {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("ListReports");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.ListReports");
@@ -4047,6 +7006,7 @@ impl CodeBuild for CodeBuildClient {
         input: ListReportsForReportGroupInput,
     ) -> Result<ListReportsForReportGroupOutput, RusotoError<ListReportsForReportGroupError>> {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("ListReportsForReportGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4078,6 +7038,7 @@ impl CodeBuild for CodeBuildClient {
         input: ListSharedProjectsInput,
     ) -> Result<ListSharedProjectsOutput, RusotoError<ListSharedProjectsError>> {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("ListSharedProjects");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.ListSharedProjects");
@@ -4106,6 +7067,7 @@ impl CodeBuild for CodeBuildClient {
         input: ListSharedReportGroupsInput,
     ) -> Result<ListSharedReportGroupsOutput, RusotoError<ListSharedReportGroupsError>> {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("ListSharedReportGroups");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.ListSharedReportGroups");
@@ -4133,6 +7095,7 @@ impl CodeBuild for CodeBuildClient {
         &self,
     ) -> Result<ListSourceCredentialsOutput, RusotoError<ListSourceCredentialsError>> {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("ListSourceCredentials");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.ListSourceCredentials");
@@ -4160,6 +7123,7 @@ impl CodeBuild for CodeBuildClient {
         input: PutResourcePolicyInput,
     ) -> Result<PutResourcePolicyOutput, RusotoError<PutResourcePolicyError>> {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("PutResourcePolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.PutResourcePolicy");
@@ -4187,6 +7151,7 @@ impl CodeBuild for CodeBuildClient {
         input: StartBuildInput,
     ) -> Result<StartBuildOutput, RusotoError<StartBuildError>> {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("StartBuild");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.StartBuild");
@@ -4214,6 +7179,7 @@ impl CodeBuild for CodeBuildClient {
         input: StopBuildInput,
     ) -> Result<StopBuildOutput, RusotoError<StopBuildError>> {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("StopBuild");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.StopBuild");
@@ -4241,6 +7207,7 @@ impl CodeBuild for CodeBuildClient {
         input: UpdateProjectInput,
     ) -> Result<UpdateProjectOutput, RusotoError<UpdateProjectError>> {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("UpdateProject");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.UpdateProject");
@@ -4268,6 +7235,7 @@ impl CodeBuild for CodeBuildClient {
         input: UpdateReportGroupInput,
     ) -> Result<UpdateReportGroupOutput, RusotoError<UpdateReportGroupError>> {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("UpdateReportGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.UpdateReportGroup");
@@ -4295,6 +7263,7 @@ impl CodeBuild for CodeBuildClient {
         input: UpdateWebhookInput,
     ) -> Result<UpdateWebhookOutput, RusotoError<UpdateWebhookError>> {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("UpdateWebhook");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.UpdateWebhook");
@@ -4316,3 +7285,292 @@ impl CodeBuild for CodeBuildClient {
         }
     }
 }
+impl CodeBuildClient {
+    /// Auto-paginating version of `describe_test_cases`, streaming every page of results.
+    pub fn describe_test_cases_pages(
+        &self,
+        input: DescribeTestCasesInput,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeTestCasesOutput, DescribeTestCasesError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeBuildClient, input: DescribeTestCasesInput| async move {
+                client.describe_test_cases(input).await
+            },
+            |input: &DescribeTestCasesInput, output: &DescribeTestCasesOutput| {
+                Some(DescribeTestCasesInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_test_cases`, streaming the `testCases` of every page of results.
+    pub fn describe_test_cases_items(
+        &self,
+        input: DescribeTestCasesInput,
+    ) -> rusoto_core::pagination::RusotoStream<TestCase, DescribeTestCasesError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_test_cases_pages(input),
+            |output: DescribeTestCasesOutput| output.test_cases.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_builds`, streaming every page of results.
+    pub fn list_builds_pages(
+        &self,
+        input: ListBuildsInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListBuildsOutput, ListBuildsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeBuildClient, input: ListBuildsInput| async move {
+                client.list_builds(input).await
+            },
+            |input: &ListBuildsInput, output: &ListBuildsOutput| {
+                Some(ListBuildsInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_builds`, streaming the `ids` of every page of results.
+    pub fn list_builds_items(
+        &self,
+        input: ListBuildsInput,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListBuildsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_builds_pages(input),
+            |output: ListBuildsOutput| output.ids.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_builds_for_project`, streaming every page of results.
+    pub fn list_builds_for_project_pages(
+        &self,
+        input: ListBuildsForProjectInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListBuildsForProjectOutput, ListBuildsForProjectError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeBuildClient, input: ListBuildsForProjectInput| async move {
+                client.list_builds_for_project(input).await
+            },
+            |input: &ListBuildsForProjectInput, output: &ListBuildsForProjectOutput| {
+                Some(ListBuildsForProjectInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_builds_for_project`, streaming the `ids` of every page of results.
+    pub fn list_builds_for_project_items(
+        &self,
+        input: ListBuildsForProjectInput,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListBuildsForProjectError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_builds_for_project_pages(input),
+            |output: ListBuildsForProjectOutput| output.ids.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_projects`, streaming every page of results.
+    pub fn list_projects_pages(
+        &self,
+        input: ListProjectsInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListProjectsOutput, ListProjectsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeBuildClient, input: ListProjectsInput| async move {
+                client.list_projects(input).await
+            },
+            |input: &ListProjectsInput, output: &ListProjectsOutput| {
+                Some(ListProjectsInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_projects`, streaming the `projects` of every page of results.
+    pub fn list_projects_items(
+        &self,
+        input: ListProjectsInput,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListProjectsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_projects_pages(input),
+            |output: ListProjectsOutput| output.projects.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_report_groups`, streaming every page of results.
+    pub fn list_report_groups_pages(
+        &self,
+        input: ListReportGroupsInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListReportGroupsOutput, ListReportGroupsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeBuildClient, input: ListReportGroupsInput| async move {
+                client.list_report_groups(input).await
+            },
+            |input: &ListReportGroupsInput, output: &ListReportGroupsOutput| {
+                Some(ListReportGroupsInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_report_groups`, streaming the `reportGroups` of every page of results.
+    pub fn list_report_groups_items(
+        &self,
+        input: ListReportGroupsInput,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListReportGroupsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_report_groups_pages(input),
+            |output: ListReportGroupsOutput| output.report_groups.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_reports`, streaming every page of results.
+    pub fn list_reports_pages(
+        &self,
+        input: ListReportsInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListReportsOutput, ListReportsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeBuildClient, input: ListReportsInput| async move {
+                client.list_reports(input).await
+            },
+            |input: &ListReportsInput, output: &ListReportsOutput| {
+                Some(ListReportsInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_reports`, streaming the `reports` of every page of results.
+    pub fn list_reports_items(
+        &self,
+        input: ListReportsInput,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListReportsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_reports_pages(input),
+            |output: ListReportsOutput| output.reports.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_reports_for_report_group`, streaming every page of results.
+    pub fn list_reports_for_report_group_pages(
+        &self,
+        input: ListReportsForReportGroupInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListReportsForReportGroupOutput,
+        ListReportsForReportGroupError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeBuildClient, input: ListReportsForReportGroupInput| async move {
+                client.list_reports_for_report_group(input).await
+            },
+            |input: &ListReportsForReportGroupInput, output: &ListReportsForReportGroupOutput| {
+                Some(ListReportsForReportGroupInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_reports_for_report_group`, streaming the `reports` of every page of results.
+    pub fn list_reports_for_report_group_items(
+        &self,
+        input: ListReportsForReportGroupInput,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListReportsForReportGroupError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_reports_for_report_group_pages(input),
+            |output: ListReportsForReportGroupOutput| output.reports.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_shared_projects`, streaming every page of results.
+    pub fn list_shared_projects_pages(
+        &self,
+        input: ListSharedProjectsInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListSharedProjectsOutput, ListSharedProjectsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeBuildClient, input: ListSharedProjectsInput| async move {
+                client.list_shared_projects(input).await
+            },
+            |input: &ListSharedProjectsInput, output: &ListSharedProjectsOutput| {
+                Some(ListSharedProjectsInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_shared_projects`, streaming the `projects` of every page of results.
+    pub fn list_shared_projects_items(
+        &self,
+        input: ListSharedProjectsInput,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListSharedProjectsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_shared_projects_pages(input),
+            |output: ListSharedProjectsOutput| output.projects.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_shared_report_groups`, streaming every page of results.
+    pub fn list_shared_report_groups_pages(
+        &self,
+        input: ListSharedReportGroupsInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListSharedReportGroupsOutput,
+        ListSharedReportGroupsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeBuildClient, input: ListSharedReportGroupsInput| async move {
+                client.list_shared_report_groups(input).await
+            },
+            |input: &ListSharedReportGroupsInput, output: &ListSharedReportGroupsOutput| {
+                Some(ListSharedReportGroupsInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_shared_report_groups`, streaming the `reportGroups` of every page of results.
+    pub fn list_shared_report_groups_items(
+        &self,
+        input: ListSharedReportGroupsInput,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListSharedReportGroupsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_shared_report_groups_pages(input),
+            |output: ListSharedReportGroupsOutput| output.report_groups.unwrap_or_default(),
+        )
+    }
+}
@@ -31,7 +31,7 @@ pub struct Approval {
     /// <p>The state of the approval, APPROVE or REVOKE. REVOKE states are not stored.</p>
     #[serde(rename = "approvalState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub approval_state: Option<String>,
+    pub approval_state: Option<ApprovalState>,
     /// <p>The Amazon Resource Name (ARN) of the user.</p>
     #[serde(rename = "userArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -56,12 +56,22 @@ pub struct ApprovalRule {
     pub approval_rule_name: Option<String>,
     /// <p>The date the approval rule was created, in timestamp format.</p>
     #[serde(rename = "creationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The date the approval rule was most recently changed, in timestamp format.</p>
     #[serde(rename = "lastModifiedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_date: Option<f64>,
+    pub last_modified_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The Amazon Resource Name (ARN) of the user who made the most recent changes to the approval rule.</p>
     #[serde(rename = "lastModifiedUser")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -101,7 +111,7 @@ pub struct ApprovalRuleOverriddenEventMetadata {
     /// <p>The status of the override event.</p>
     #[serde(rename = "overrideStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub override_status: Option<String>,
+    pub override_status: Option<OverrideStatus>,
     /// <p>The revision ID of the pull request when the override event occurred.</p>
     #[serde(rename = "revisionId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -130,12 +140,22 @@ pub struct ApprovalRuleTemplate {
     pub approval_rule_template_name: Option<String>,
     /// <p>The date the approval rule template was created, in timestamp format.</p>
     #[serde(rename = "creationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The date the approval rule template was most recently changed, in timestamp format.</p>
     #[serde(rename = "lastModifiedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_date: Option<f64>,
+    pub last_modified_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The Amazon Resource Name (ARN) of the user who made the most recent changes to the approval rule template.</p>
     #[serde(rename = "lastModifiedUser")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -146,6 +166,99 @@ pub struct ApprovalRuleTemplate {
     pub rule_content_sha_256: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ApprovalState {
+    /// `APPROVE`
+    Approve,
+    /// `REVOKE`
+    Revoke,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ApprovalState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ApprovalState::Approve => "APPROVE",
+            ApprovalState::Revoke => "REVOKE",
+            ApprovalState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ApprovalState {
+    fn default() -> ApprovalState {
+        ApprovalState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ApprovalState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ApprovalState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ApprovalState {
+    fn from(value: &'a str) -> ApprovalState {
+        match value {
+            "APPROVE" => ApprovalState::Approve,
+            "REVOKE" => ApprovalState::Revoke,
+            _ => ApprovalState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ApprovalState {
+    fn from(value: String) -> ApprovalState {
+        match value.as_str() {
+            "APPROVE" => ApprovalState::Approve,
+            "REVOKE" => ApprovalState::Revoke,
+            _ => ApprovalState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ApprovalState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ApprovalState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ApprovalState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ApprovalState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ApprovalState {
+    fn deserialize<D>(deserializer: D) -> Result<ApprovalState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Returns information about a change in the approval state for a pull request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -153,7 +266,7 @@ pub struct ApprovalStateChangedEventMetadata {
     /// <p>The approval status for the pull request.</p>
     #[serde(rename = "approvalStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub approval_status: Option<String>,
+    pub approval_status: Option<ApprovalState>,
     /// <p>The revision ID of the pull request when the approval state changed.</p>
     #[serde(rename = "revisionId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -232,11 +345,11 @@ pub struct BatchDescribeMergeConflictsInput {
     /// <p>The level of conflict detail to use. If unspecified, the default FILE_LEVEL is used, which returns a not-mergeable result if the same file has differences in both branches. If LINE_LEVEL is specified, a conflict is considered not mergeable if the same file in both branches has differences on the same line.</p>
     #[serde(rename = "conflictDetailLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub conflict_detail_level: Option<String>,
+    pub conflict_detail_level: Option<ConflictDetailLevelTypeEnum>,
     /// <p>Specifies which branch to use when resolving conflicts, or whether to attempt automatically merging two versions of a file. The default is NONE, which requires any conflicts to be resolved manually before the merge operation is successful.</p>
     #[serde(rename = "conflictResolutionStrategy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub conflict_resolution_strategy: Option<String>,
+    pub conflict_resolution_strategy: Option<ConflictResolutionStrategyTypeEnum>,
     /// <p>The branch, tag, HEAD, or other fully qualified reference used to identify a commit (for example, a branch name or a full commit ID).</p>
     #[serde(rename = "destinationCommitSpecifier")]
     pub destination_commit_specifier: String,
@@ -254,7 +367,7 @@ pub struct BatchDescribeMergeConflictsInput {
     pub max_merge_hunks: Option<i64>,
     /// <p>The merge option or strategy you want to use to merge the code.</p>
     #[serde(rename = "mergeOption")]
-    pub merge_option: String,
+    pub merge_option: MergeOptionTypeEnum,
     /// <p>An enumeration token that, when provided in a request, returns the next batch of the results.</p>
     #[serde(rename = "nextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -430,6 +543,104 @@ pub struct BranchInfo {
     pub commit_id: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ChangeTypeEnum {
+    /// `A`
+    A,
+    /// `M`
+    M,
+    /// `D`
+    D,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ChangeTypeEnum {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ChangeTypeEnum::A => "A",
+            ChangeTypeEnum::M => "M",
+            ChangeTypeEnum::D => "D",
+            ChangeTypeEnum::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ChangeTypeEnum {
+    fn default() -> ChangeTypeEnum {
+        ChangeTypeEnum::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ChangeTypeEnum {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ChangeTypeEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ChangeTypeEnum {
+    fn from(value: &'a str) -> ChangeTypeEnum {
+        match value {
+            "A" => ChangeTypeEnum::A,
+            "M" => ChangeTypeEnum::M,
+            "D" => ChangeTypeEnum::D,
+            _ => ChangeTypeEnum::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ChangeTypeEnum {
+    fn from(value: String) -> ChangeTypeEnum {
+        match value.as_str() {
+            "A" => ChangeTypeEnum::A,
+            "M" => ChangeTypeEnum::M,
+            "D" => ChangeTypeEnum::D,
+            _ => ChangeTypeEnum::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ChangeTypeEnum {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ChangeTypeEnum, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ChangeTypeEnum {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ChangeTypeEnum {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ChangeTypeEnum {
+    fn deserialize<D>(deserializer: D) -> Result<ChangeTypeEnum, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Returns information about a specific comment.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -452,8 +663,13 @@ pub struct Comment {
     pub content: Option<String>,
     /// <p>The date and time the comment was created, in timestamp format.</p>
     #[serde(rename = "creationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A Boolean value indicating whether the comment has been deleted.</p>
     #[serde(rename = "deleted")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -464,8 +680,13 @@ pub struct Comment {
     pub in_reply_to: Option<String>,
     /// <p>The date and time the comment was most recently modified, in timestamp format.</p>
     #[serde(rename = "lastModifiedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_date: Option<f64>,
+    pub last_modified_date: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>Returns information about comments on the comparison between two commits.</p>
@@ -588,6 +809,99 @@ pub struct Conflict {
     pub merge_hunks: Option<Vec<MergeHunk>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ConflictDetailLevelTypeEnum {
+    /// `FILE_LEVEL`
+    FileLevel,
+    /// `LINE_LEVEL`
+    LineLevel,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ConflictDetailLevelTypeEnum {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ConflictDetailLevelTypeEnum::FileLevel => "FILE_LEVEL",
+            ConflictDetailLevelTypeEnum::LineLevel => "LINE_LEVEL",
+            ConflictDetailLevelTypeEnum::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ConflictDetailLevelTypeEnum {
+    fn default() -> ConflictDetailLevelTypeEnum {
+        ConflictDetailLevelTypeEnum::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ConflictDetailLevelTypeEnum {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ConflictDetailLevelTypeEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ConflictDetailLevelTypeEnum {
+    fn from(value: &'a str) -> ConflictDetailLevelTypeEnum {
+        match value {
+            "FILE_LEVEL" => ConflictDetailLevelTypeEnum::FileLevel,
+            "LINE_LEVEL" => ConflictDetailLevelTypeEnum::LineLevel,
+            _ => ConflictDetailLevelTypeEnum::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ConflictDetailLevelTypeEnum {
+    fn from(value: String) -> ConflictDetailLevelTypeEnum {
+        match value.as_str() {
+            "FILE_LEVEL" => ConflictDetailLevelTypeEnum::FileLevel,
+            "LINE_LEVEL" => ConflictDetailLevelTypeEnum::LineLevel,
+            _ => ConflictDetailLevelTypeEnum::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ConflictDetailLevelTypeEnum {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ConflictDetailLevelTypeEnum, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ConflictDetailLevelTypeEnum {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ConflictDetailLevelTypeEnum {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ConflictDetailLevelTypeEnum {
+    fn deserialize<D>(deserializer: D) -> Result<ConflictDetailLevelTypeEnum, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about the metadata for a conflict in a merge operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -652,6 +966,109 @@ pub struct ConflictResolution {
     pub set_file_modes: Option<Vec<SetFileModeEntry>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ConflictResolutionStrategyTypeEnum {
+    /// `NONE`
+    None,
+    /// `ACCEPT_SOURCE`
+    AcceptSource,
+    /// `ACCEPT_DESTINATION`
+    AcceptDestination,
+    /// `AUTOMERGE`
+    Automerge,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ConflictResolutionStrategyTypeEnum {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ConflictResolutionStrategyTypeEnum::None => "NONE",
+            ConflictResolutionStrategyTypeEnum::AcceptSource => "ACCEPT_SOURCE",
+            ConflictResolutionStrategyTypeEnum::AcceptDestination => "ACCEPT_DESTINATION",
+            ConflictResolutionStrategyTypeEnum::Automerge => "AUTOMERGE",
+            ConflictResolutionStrategyTypeEnum::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ConflictResolutionStrategyTypeEnum {
+    fn default() -> ConflictResolutionStrategyTypeEnum {
+        ConflictResolutionStrategyTypeEnum::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ConflictResolutionStrategyTypeEnum {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ConflictResolutionStrategyTypeEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ConflictResolutionStrategyTypeEnum {
+    fn from(value: &'a str) -> ConflictResolutionStrategyTypeEnum {
+        match value {
+            "NONE" => ConflictResolutionStrategyTypeEnum::None,
+            "ACCEPT_SOURCE" => ConflictResolutionStrategyTypeEnum::AcceptSource,
+            "ACCEPT_DESTINATION" => ConflictResolutionStrategyTypeEnum::AcceptDestination,
+            "AUTOMERGE" => ConflictResolutionStrategyTypeEnum::Automerge,
+            _ => ConflictResolutionStrategyTypeEnum::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ConflictResolutionStrategyTypeEnum {
+    fn from(value: String) -> ConflictResolutionStrategyTypeEnum {
+        match value.as_str() {
+            "NONE" => ConflictResolutionStrategyTypeEnum::None,
+            "ACCEPT_SOURCE" => ConflictResolutionStrategyTypeEnum::AcceptSource,
+            "ACCEPT_DESTINATION" => ConflictResolutionStrategyTypeEnum::AcceptDestination,
+            "AUTOMERGE" => ConflictResolutionStrategyTypeEnum::Automerge,
+            _ => ConflictResolutionStrategyTypeEnum::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ConflictResolutionStrategyTypeEnum {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ConflictResolutionStrategyTypeEnum, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ConflictResolutionStrategyTypeEnum {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ConflictResolutionStrategyTypeEnum {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ConflictResolutionStrategyTypeEnum {
+    fn deserialize<D>(deserializer: D) -> Result<ConflictResolutionStrategyTypeEnum, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateApprovalRuleTemplateInput {
@@ -848,7 +1265,7 @@ pub struct CreateUnreferencedMergeCommitInput {
     /// <p>The level of conflict detail to use. If unspecified, the default FILE_LEVEL is used, which returns a not-mergeable result if the same file has differences in both branches. If LINE_LEVEL is specified, a conflict is considered not mergeable if the same file in both branches has differences on the same line.</p>
     #[serde(rename = "conflictDetailLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub conflict_detail_level: Option<String>,
+    pub conflict_detail_level: Option<ConflictDetailLevelTypeEnum>,
     /// <p>If AUTOMERGE is the conflict resolution strategy, a list of inputs to use when resolving conflicts during a merge.</p>
     #[serde(rename = "conflictResolution")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -856,7 +1273,7 @@ pub struct CreateUnreferencedMergeCommitInput {
     /// <p>Specifies which branch to use when resolving conflicts, or whether to attempt automatically merging two versions of a file. The default is NONE, which requires any conflicts to be resolved manually before the merge operation is successful.</p>
     #[serde(rename = "conflictResolutionStrategy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub conflict_resolution_strategy: Option<String>,
+    pub conflict_resolution_strategy: Option<ConflictResolutionStrategyTypeEnum>,
     /// <p>The branch, tag, HEAD, or other fully qualified reference used to identify a commit (for example, a branch name or a full commit ID).</p>
     #[serde(rename = "destinationCommitSpecifier")]
     pub destination_commit_specifier: String,
@@ -870,7 +1287,7 @@ pub struct CreateUnreferencedMergeCommitInput {
     pub keep_empty_folders: Option<bool>,
     /// <p>The merge option or strategy you want to use to merge the code.</p>
     #[serde(rename = "mergeOption")]
-    pub merge_option: String,
+    pub merge_option: MergeOptionTypeEnum,
     /// <p>The name of the repository where you want to create the unreferenced merge commit.</p>
     #[serde(rename = "repositoryName")]
     pub repository_name: String,
@@ -1050,11 +1467,11 @@ pub struct DescribeMergeConflictsInput {
     /// <p>The level of conflict detail to use. If unspecified, the default FILE_LEVEL is used, which returns a not-mergeable result if the same file has differences in both branches. If LINE_LEVEL is specified, a conflict is considered not mergeable if the same file in both branches has differences on the same line.</p>
     #[serde(rename = "conflictDetailLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub conflict_detail_level: Option<String>,
+    pub conflict_detail_level: Option<ConflictDetailLevelTypeEnum>,
     /// <p>Specifies which branch to use when resolving conflicts, or whether to attempt automatically merging two versions of a file. The default is NONE, which requires any conflicts to be resolved manually before the merge operation is successful.</p>
     #[serde(rename = "conflictResolutionStrategy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub conflict_resolution_strategy: Option<String>,
+    pub conflict_resolution_strategy: Option<ConflictResolutionStrategyTypeEnum>,
     /// <p>The branch, tag, HEAD, or other fully qualified reference used to identify a commit (for example, a branch name or a full commit ID).</p>
     #[serde(rename = "destinationCommitSpecifier")]
     pub destination_commit_specifier: String,
@@ -1067,7 +1484,7 @@ pub struct DescribeMergeConflictsInput {
     pub max_merge_hunks: Option<i64>,
     /// <p>The merge option or strategy you want to use to merge the code.</p>
     #[serde(rename = "mergeOption")]
-    pub merge_option: String,
+    pub merge_option: MergeOptionTypeEnum,
     /// <p>An enumeration token that, when provided in a request, returns the next batch of the results.</p>
     #[serde(rename = "nextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1123,7 +1540,7 @@ pub struct DescribePullRequestEventsInput {
     /// <p>Optional. The pull request event type about which you want to return information.</p>
     #[serde(rename = "pullRequestEventType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub pull_request_event_type: Option<String>,
+    pub pull_request_event_type: Option<PullRequestEventType>,
     /// <p>The system-generated ID of the pull request. To get this ID, use <a>ListPullRequests</a>.</p>
     #[serde(rename = "pullRequestId")]
     pub pull_request_id: String,
@@ -1156,7 +1573,7 @@ pub struct Difference {
     /// <p>Whether the change type of the difference is an addition (A), deletion (D), or modification (M).</p>
     #[serde(rename = "changeType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub change_type: Option<String>,
+    pub change_type: Option<ChangeTypeEnum>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -1226,7 +1643,7 @@ pub struct File {
     /// <p>The extrapolated file mode permissions for the file. Valid values include EXECUTABLE and NORMAL.</p>
     #[serde(rename = "fileMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub file_mode: Option<String>,
+    pub file_mode: Option<FileModeTypeEnum>,
     /// <p>The relative path of the file from the folder where the query originated.</p>
     #[serde(rename = "relativePath")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1248,9 +1665,107 @@ pub struct FileMetadata {
     /// <p>The extrapolated file mode permissions for the file. Valid values include EXECUTABLE and NORMAL.</p>
     #[serde(rename = "fileMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub file_mode: Option<String>,
+    pub file_mode: Option<FileModeTypeEnum>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FileModeTypeEnum {
+    /// `EXECUTABLE`
+    Executable,
+    /// `NORMAL`
+    Normal,
+    /// `SYMLINK`
+    Symlink,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl FileModeTypeEnum {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FileModeTypeEnum::Executable => "EXECUTABLE",
+            FileModeTypeEnum::Normal => "NORMAL",
+            FileModeTypeEnum::Symlink => "SYMLINK",
+            FileModeTypeEnum::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for FileModeTypeEnum {
+    fn default() -> FileModeTypeEnum {
+        FileModeTypeEnum::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for FileModeTypeEnum {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FileModeTypeEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FileModeTypeEnum {
+    fn from(value: &'a str) -> FileModeTypeEnum {
+        match value {
+            "EXECUTABLE" => FileModeTypeEnum::Executable,
+            "NORMAL" => FileModeTypeEnum::Normal,
+            "SYMLINK" => FileModeTypeEnum::Symlink,
+            _ => FileModeTypeEnum::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FileModeTypeEnum {
+    fn from(value: String) -> FileModeTypeEnum {
+        match value.as_str() {
+            "EXECUTABLE" => FileModeTypeEnum::Executable,
+            "NORMAL" => FileModeTypeEnum::Normal,
+            "SYMLINK" => FileModeTypeEnum::Symlink,
+            _ => FileModeTypeEnum::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for FileModeTypeEnum {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<FileModeTypeEnum, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FileModeTypeEnum {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FileModeTypeEnum {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FileModeTypeEnum {
+    fn deserialize<D>(deserializer: D) -> Result<FileModeTypeEnum, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about file modes in a merge or pull request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1258,15 +1773,15 @@ pub struct FileModes {
     /// <p>The file mode of a file in the base of a merge or pull request.</p>
     #[serde(rename = "base")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub base: Option<String>,
+    pub base: Option<FileModeTypeEnum>,
     /// <p>The file mode of a file in the destination of a merge or pull request.</p>
     #[serde(rename = "destination")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub destination: Option<String>,
+    pub destination: Option<FileModeTypeEnum>,
     /// <p>The file mode of a file in the source of a merge or pull request.</p>
     #[serde(rename = "source")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub source: Option<String>,
+    pub source: Option<FileModeTypeEnum>,
 }
 
 /// <p>Information about the size of files in a merge or pull request.</p>
@@ -1564,7 +2079,7 @@ pub struct GetFileOutput {
     pub file_content: bytes::Bytes,
     /// <p><p>The extrapolated file mode permissions of the blob. Valid values include strings such as EXECUTABLE and not numeric values.</p> <note> <p>The file mode permissions returned by this API are not the standard file mode permission values, such as 100644, but rather extrapolated values. See the supported return values.</p> </note></p>
     #[serde(rename = "fileMode")]
-    pub file_mode: String,
+    pub file_mode: FileModeTypeEnum,
     /// <p>The fully qualified path to the specified file. Returns the name and extension of the file.</p>
     #[serde(rename = "filePath")]
     pub file_path: String,
@@ -1625,11 +2140,11 @@ pub struct GetMergeCommitInput {
     /// <p>The level of conflict detail to use. If unspecified, the default FILE_LEVEL is used, which returns a not-mergeable result if the same file has differences in both branches. If LINE_LEVEL is specified, a conflict is considered not mergeable if the same file in both branches has differences on the same line.</p>
     #[serde(rename = "conflictDetailLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub conflict_detail_level: Option<String>,
+    pub conflict_detail_level: Option<ConflictDetailLevelTypeEnum>,
     /// <p>Specifies which branch to use when resolving conflicts, or whether to attempt automatically merging two versions of a file. The default is NONE, which requires any conflicts to be resolved manually before the merge operation is successful.</p>
     #[serde(rename = "conflictResolutionStrategy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub conflict_resolution_strategy: Option<String>,
+    pub conflict_resolution_strategy: Option<ConflictResolutionStrategyTypeEnum>,
     /// <p>The branch, tag, HEAD, or other fully qualified reference used to identify a commit (for example, a branch name or a full commit ID).</p>
     #[serde(rename = "destinationCommitSpecifier")]
     pub destination_commit_specifier: String,
@@ -1668,11 +2183,11 @@ pub struct GetMergeConflictsInput {
     /// <p>The level of conflict detail to use. If unspecified, the default FILE_LEVEL is used, which returns a not-mergeable result if the same file has differences in both branches. If LINE_LEVEL is specified, a conflict is considered not mergeable if the same file in both branches has differences on the same line.</p>
     #[serde(rename = "conflictDetailLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub conflict_detail_level: Option<String>,
+    pub conflict_detail_level: Option<ConflictDetailLevelTypeEnum>,
     /// <p>Specifies which branch to use when resolving conflicts, or whether to attempt automatically merging two versions of a file. The default is NONE, which requires any conflicts to be resolved manually before the merge operation is successful.</p>
     #[serde(rename = "conflictResolutionStrategy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub conflict_resolution_strategy: Option<String>,
+    pub conflict_resolution_strategy: Option<ConflictResolutionStrategyTypeEnum>,
     /// <p>The branch, tag, HEAD, or other fully qualified reference used to identify a commit (for example, a branch name or a full commit ID).</p>
     #[serde(rename = "destinationCommitSpecifier")]
     pub destination_commit_specifier: String,
@@ -1682,7 +2197,7 @@ pub struct GetMergeConflictsInput {
     pub max_conflict_files: Option<i64>,
     /// <p>The merge option or strategy you want to use to merge the code. </p>
     #[serde(rename = "mergeOption")]
-    pub merge_option: String,
+    pub merge_option: MergeOptionTypeEnum,
     /// <p>An enumeration token that, when provided in a request, returns the next batch of the results.</p>
     #[serde(rename = "nextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1726,11 +2241,11 @@ pub struct GetMergeOptionsInput {
     /// <p>The level of conflict detail to use. If unspecified, the default FILE_LEVEL is used, which returns a not-mergeable result if the same file has differences in both branches. If LINE_LEVEL is specified, a conflict is considered not mergeable if the same file in both branches has differences on the same line.</p>
     #[serde(rename = "conflictDetailLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub conflict_detail_level: Option<String>,
+    pub conflict_detail_level: Option<ConflictDetailLevelTypeEnum>,
     /// <p>Specifies which branch to use when resolving conflicts, or whether to attempt automatically merging two versions of a file. The default is NONE, which requires any conflicts to be resolved manually before the merge operation is successful.</p>
     #[serde(rename = "conflictResolutionStrategy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub conflict_resolution_strategy: Option<String>,
+    pub conflict_resolution_strategy: Option<ConflictResolutionStrategyTypeEnum>,
     /// <p>The branch, tag, HEAD, or other fully qualified reference used to identify a commit (for example, a branch name or a full commit ID).</p>
     #[serde(rename = "destinationCommitSpecifier")]
     pub destination_commit_specifier: String,
@@ -1753,7 +2268,7 @@ pub struct GetMergeOptionsOutput {
     pub destination_commit_id: String,
     /// <p>The merge option or strategy used to merge the code.</p>
     #[serde(rename = "mergeOptions")]
-    pub merge_options: Vec<String>,
+    pub merge_options: Vec<MergeOptionTypeEnum>,
     /// <p>The commit ID of the source commit specifier that was used in the merge evaluation.</p>
     #[serde(rename = "sourceCommitId")]
     pub source_commit_id: String,
@@ -1979,7 +2494,7 @@ pub struct ListPullRequestsInput {
     /// <p>Optional. The status of the pull request. If used, this refines the results to the pull requests that match the specified status.</p>
     #[serde(rename = "pullRequestStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub pull_request_status: Option<String>,
+    pub pull_request_status: Option<PullRequestStatusEnum>,
     /// <p>The name of the repository for which you want to list pull requests.</p>
     #[serde(rename = "repositoryName")]
     pub repository_name: String,
@@ -2037,11 +2552,11 @@ pub struct ListRepositoriesInput {
     /// <p>The order in which to sort the results of a list repositories operation.</p>
     #[serde(rename = "order")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub order: Option<String>,
+    pub order: Option<OrderEnum>,
     /// <p>The criteria used to sort the results of a list repositories operation.</p>
     #[serde(rename = "sortBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort_by: Option<String>,
+    pub sort_by: Option<SortByEnum>,
 }
 
 /// <p>Represents the output of a list repositories operation.</p>
@@ -2097,7 +2612,7 @@ pub struct Location {
     /// <p>In a comparison of commits or a pull request, whether the change is in the before or after of that comparison.</p>
     #[serde(rename = "relativeFileVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub relative_file_version: Option<String>,
+    pub relative_file_version: Option<RelativeFileVersionEnum>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -2145,7 +2660,7 @@ pub struct MergeBranchesBySquashInput {
     /// <p>The level of conflict detail to use. If unspecified, the default FILE_LEVEL is used, which returns a not-mergeable result if the same file has differences in both branches. If LINE_LEVEL is specified, a conflict is considered not mergeable if the same file in both branches has differences on the same line.</p>
     #[serde(rename = "conflictDetailLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub conflict_detail_level: Option<String>,
+    pub conflict_detail_level: Option<ConflictDetailLevelTypeEnum>,
     /// <p>If AUTOMERGE is the conflict resolution strategy, a list of inputs to use when resolving conflicts during a merge.</p>
     #[serde(rename = "conflictResolution")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2153,7 +2668,7 @@ pub struct MergeBranchesBySquashInput {
     /// <p>Specifies which branch to use when resolving conflicts, or whether to attempt automatically merging two versions of a file. The default is NONE, which requires any conflicts to be resolved manually before the merge operation is successful.</p>
     #[serde(rename = "conflictResolutionStrategy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub conflict_resolution_strategy: Option<String>,
+    pub conflict_resolution_strategy: Option<ConflictResolutionStrategyTypeEnum>,
     /// <p>The branch, tag, HEAD, or other fully qualified reference used to identify a commit (for example, a branch name or a full commit ID).</p>
     #[serde(rename = "destinationCommitSpecifier")]
     pub destination_commit_specifier: String,
@@ -2204,7 +2719,7 @@ pub struct MergeBranchesByThreeWayInput {
     /// <p>The level of conflict detail to use. If unspecified, the default FILE_LEVEL is used, which returns a not-mergeable result if the same file has differences in both branches. If LINE_LEVEL is specified, a conflict is considered not mergeable if the same file in both branches has differences on the same line.</p>
     #[serde(rename = "conflictDetailLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub conflict_detail_level: Option<String>,
+    pub conflict_detail_level: Option<ConflictDetailLevelTypeEnum>,
     /// <p>If AUTOMERGE is the conflict resolution strategy, a list of inputs to use when resolving conflicts during a merge.</p>
     #[serde(rename = "conflictResolution")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2212,7 +2727,7 @@ pub struct MergeBranchesByThreeWayInput {
     /// <p>Specifies which branch to use when resolving conflicts, or whether to attempt automatically merging two versions of a file. The default is NONE, which requires any conflicts to be resolved manually before the merge operation is successful.</p>
     #[serde(rename = "conflictResolutionStrategy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub conflict_resolution_strategy: Option<String>,
+    pub conflict_resolution_strategy: Option<ConflictResolutionStrategyTypeEnum>,
     /// <p>The branch, tag, HEAD, or other fully qualified reference used to identify a commit (for example, a branch name or a full commit ID).</p>
     #[serde(rename = "destinationCommitSpecifier")]
     pub destination_commit_specifier: String,
@@ -2304,7 +2819,7 @@ pub struct MergeMetadata {
     /// <p>The merge strategy used in the merge.</p>
     #[serde(rename = "mergeOption")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub merge_option: Option<String>,
+    pub merge_option: Option<MergeOptionTypeEnum>,
     /// <p>The Amazon Resource Name (ARN) of the user who merged the branches.</p>
     #[serde(rename = "mergedBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2318,13 +2833,111 @@ pub struct MergeOperations {
     /// <p>The operation on a file in the destination of a merge or pull request.</p>
     #[serde(rename = "destination")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub destination: Option<String>,
+    pub destination: Option<ChangeTypeEnum>,
     /// <p>The operation (add, modify, or delete) on a file in the source of a merge or pull request.</p>
     #[serde(rename = "source")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub source: Option<String>,
+    pub source: Option<ChangeTypeEnum>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MergeOptionTypeEnum {
+    /// `FAST_FORWARD_MERGE`
+    FastForwardMerge,
+    /// `SQUASH_MERGE`
+    SquashMerge,
+    /// `THREE_WAY_MERGE`
+    ThreeWayMerge,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl MergeOptionTypeEnum {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MergeOptionTypeEnum::FastForwardMerge => "FAST_FORWARD_MERGE",
+            MergeOptionTypeEnum::SquashMerge => "SQUASH_MERGE",
+            MergeOptionTypeEnum::ThreeWayMerge => "THREE_WAY_MERGE",
+            MergeOptionTypeEnum::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for MergeOptionTypeEnum {
+    fn default() -> MergeOptionTypeEnum {
+        MergeOptionTypeEnum::Unknown(String::new())
+    }
 }
 
+impl AsRef<str> for MergeOptionTypeEnum {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for MergeOptionTypeEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for MergeOptionTypeEnum {
+    fn from(value: &'a str) -> MergeOptionTypeEnum {
+        match value {
+            "FAST_FORWARD_MERGE" => MergeOptionTypeEnum::FastForwardMerge,
+            "SQUASH_MERGE" => MergeOptionTypeEnum::SquashMerge,
+            "THREE_WAY_MERGE" => MergeOptionTypeEnum::ThreeWayMerge,
+            _ => MergeOptionTypeEnum::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MergeOptionTypeEnum {
+    fn from(value: String) -> MergeOptionTypeEnum {
+        match value.as_str() {
+            "FAST_FORWARD_MERGE" => MergeOptionTypeEnum::FastForwardMerge,
+            "SQUASH_MERGE" => MergeOptionTypeEnum::SquashMerge,
+            "THREE_WAY_MERGE" => MergeOptionTypeEnum::ThreeWayMerge,
+            _ => MergeOptionTypeEnum::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for MergeOptionTypeEnum {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<MergeOptionTypeEnum, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MergeOptionTypeEnum {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for MergeOptionTypeEnum {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MergeOptionTypeEnum {
+    fn deserialize<D>(deserializer: D) -> Result<MergeOptionTypeEnum, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct MergePullRequestByFastForwardInput {
@@ -2363,7 +2976,7 @@ pub struct MergePullRequestBySquashInput {
     /// <p>The level of conflict detail to use. If unspecified, the default FILE_LEVEL is used, which returns a not-mergeable result if the same file has differences in both branches. If LINE_LEVEL is specified, a conflict is considered not mergeable if the same file in both branches has differences on the same line.</p>
     #[serde(rename = "conflictDetailLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub conflict_detail_level: Option<String>,
+    pub conflict_detail_level: Option<ConflictDetailLevelTypeEnum>,
     /// <p>If AUTOMERGE is the conflict resolution strategy, a list of inputs to use when resolving conflicts during a merge.</p>
     #[serde(rename = "conflictResolution")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2371,7 +2984,7 @@ pub struct MergePullRequestBySquashInput {
     /// <p>Specifies which branch to use when resolving conflicts, or whether to attempt automatically merging two versions of a file. The default is NONE, which requires any conflicts to be resolved manually before the merge operation is successful.</p>
     #[serde(rename = "conflictResolutionStrategy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub conflict_resolution_strategy: Option<String>,
+    pub conflict_resolution_strategy: Option<ConflictResolutionStrategyTypeEnum>,
     /// <p>The email address of the person merging the branches. This information is used in the commit information for the merge.</p>
     #[serde(rename = "email")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2414,7 +3027,7 @@ pub struct MergePullRequestByThreeWayInput {
     /// <p>The level of conflict detail to use. If unspecified, the default FILE_LEVEL is used, which returns a not-mergeable result if the same file has differences in both branches. If LINE_LEVEL is specified, a conflict is considered not mergeable if the same file in both branches has differences on the same line.</p>
     #[serde(rename = "conflictDetailLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub conflict_detail_level: Option<String>,
+    pub conflict_detail_level: Option<ConflictDetailLevelTypeEnum>,
     /// <p>If AUTOMERGE is the conflict resolution strategy, a list of inputs to use when resolving conflicts during a merge.</p>
     #[serde(rename = "conflictResolution")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2422,7 +3035,7 @@ pub struct MergePullRequestByThreeWayInput {
     /// <p>Specifies which branch to use when resolving conflicts, or whether to attempt automatically merging two versions of a file. The default is NONE, which requires any conflicts to be resolved manually before the merge operation is successful.</p>
     #[serde(rename = "conflictResolutionStrategy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub conflict_resolution_strategy: Option<String>,
+    pub conflict_resolution_strategy: Option<ConflictResolutionStrategyTypeEnum>,
     /// <p>The email address of the person merging the branches. This information is used in the commit information for the merge.</p>
     #[serde(rename = "email")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2451,6 +3064,109 @@ pub struct MergePullRequestByThreeWayOutput {
     pub pull_request: Option<PullRequest>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ObjectTypeEnum {
+    /// `FILE`
+    File,
+    /// `DIRECTORY`
+    Directory,
+    /// `GIT_LINK`
+    GitLink,
+    /// `SYMBOLIC_LINK`
+    SymbolicLink,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ObjectTypeEnum {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ObjectTypeEnum::File => "FILE",
+            ObjectTypeEnum::Directory => "DIRECTORY",
+            ObjectTypeEnum::GitLink => "GIT_LINK",
+            ObjectTypeEnum::SymbolicLink => "SYMBOLIC_LINK",
+            ObjectTypeEnum::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ObjectTypeEnum {
+    fn default() -> ObjectTypeEnum {
+        ObjectTypeEnum::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ObjectTypeEnum {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ObjectTypeEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ObjectTypeEnum {
+    fn from(value: &'a str) -> ObjectTypeEnum {
+        match value {
+            "FILE" => ObjectTypeEnum::File,
+            "DIRECTORY" => ObjectTypeEnum::Directory,
+            "GIT_LINK" => ObjectTypeEnum::GitLink,
+            "SYMBOLIC_LINK" => ObjectTypeEnum::SymbolicLink,
+            _ => ObjectTypeEnum::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ObjectTypeEnum {
+    fn from(value: String) -> ObjectTypeEnum {
+        match value.as_str() {
+            "FILE" => ObjectTypeEnum::File,
+            "DIRECTORY" => ObjectTypeEnum::Directory,
+            "GIT_LINK" => ObjectTypeEnum::GitLink,
+            "SYMBOLIC_LINK" => ObjectTypeEnum::SymbolicLink,
+            _ => ObjectTypeEnum::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ObjectTypeEnum {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ObjectTypeEnum, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ObjectTypeEnum {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ObjectTypeEnum {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ObjectTypeEnum {
+    fn deserialize<D>(deserializer: D) -> Result<ObjectTypeEnum, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about the type of an object in a merge operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -2458,17 +3174,110 @@ pub struct ObjectTypes {
     /// <p>The type of the object in the base commit of the merge.</p>
     #[serde(rename = "base")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub base: Option<String>,
+    pub base: Option<ObjectTypeEnum>,
     /// <p>The type of the object in the destination branch.</p>
     #[serde(rename = "destination")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub destination: Option<String>,
+    pub destination: Option<ObjectTypeEnum>,
     /// <p>The type of the object in the source branch.</p>
     #[serde(rename = "source")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub source: Option<String>,
+    pub source: Option<ObjectTypeEnum>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OrderEnum {
+    /// `ascending`
+    Ascending,
+    /// `descending`
+    Descending,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl OrderEnum {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OrderEnum::Ascending => "ascending",
+            OrderEnum::Descending => "descending",
+            OrderEnum::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for OrderEnum {
+    fn default() -> OrderEnum {
+        OrderEnum::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for OrderEnum {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for OrderEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for OrderEnum {
+    fn from(value: &'a str) -> OrderEnum {
+        match value {
+            "ascending" => OrderEnum::Ascending,
+            "descending" => OrderEnum::Descending,
+            _ => OrderEnum::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OrderEnum {
+    fn from(value: String) -> OrderEnum {
+        match value.as_str() {
+            "ascending" => OrderEnum::Ascending,
+            "descending" => OrderEnum::Descending,
+            _ => OrderEnum::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for OrderEnum {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<OrderEnum, Self::Err> {
+        Ok(value.into())
+    }
 }
 
+impl ::rusoto_core::param::ToParam for OrderEnum {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for OrderEnum {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OrderEnum {
+    fn deserialize<D>(deserializer: D) -> Result<OrderEnum, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Returns information about the template that created the approval rule for a pull request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -2488,7 +3297,7 @@ pub struct OriginApprovalRuleTemplate {
 pub struct OverridePullRequestApprovalRulesInput {
     /// <p>Whether you want to set aside approval rule requirements for the pull request (OVERRIDE) or revoke a previous override and apply approval rule requirements (REVOKE). REVOKE status is not stored.</p>
     #[serde(rename = "overrideStatus")]
-    pub override_status: String,
+    pub override_status: OverrideStatus,
     /// <p>The system-generated ID of the pull request for which you want to override all approval rule requirements. To get this information, use <a>GetPullRequest</a>.</p>
     #[serde(rename = "pullRequestId")]
     pub pull_request_id: String,
@@ -2497,6 +3306,99 @@ pub struct OverridePullRequestApprovalRulesInput {
     pub revision_id: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OverrideStatus {
+    /// `OVERRIDE`
+    Override,
+    /// `REVOKE`
+    Revoke,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl OverrideStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OverrideStatus::Override => "OVERRIDE",
+            OverrideStatus::Revoke => "REVOKE",
+            OverrideStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for OverrideStatus {
+    fn default() -> OverrideStatus {
+        OverrideStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for OverrideStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for OverrideStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for OverrideStatus {
+    fn from(value: &'a str) -> OverrideStatus {
+        match value {
+            "OVERRIDE" => OverrideStatus::Override,
+            "REVOKE" => OverrideStatus::Revoke,
+            _ => OverrideStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OverrideStatus {
+    fn from(value: String) -> OverrideStatus {
+        match value.as_str() {
+            "OVERRIDE" => OverrideStatus::Override,
+            "REVOKE" => OverrideStatus::Revoke,
+            _ => OverrideStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for OverrideStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<OverrideStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OverrideStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for OverrideStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OverrideStatus {
+    fn deserialize<D>(deserializer: D) -> Result<OverrideStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct PostCommentForComparedCommitInput {
@@ -2663,16 +3565,26 @@ pub struct PullRequest {
     pub client_request_token: Option<String>,
     /// <p>The date and time the pull request was originally created, in timestamp format.</p>
     #[serde(rename = "creationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The user-defined description of the pull request. This description can be used to clarify what should be reviewed and other details of the request.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     /// <p>The day and time of the last user or system activity on the pull request, in timestamp format.</p>
     #[serde(rename = "lastActivityDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_activity_date: Option<f64>,
+    pub last_activity_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The system-generated ID of the pull request. </p>
     #[serde(rename = "pullRequestId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2680,7 +3592,7 @@ pub struct PullRequest {
     /// <p>The status of the pull request. Pull request status can only change from <code>OPEN</code> to <code>CLOSED</code>.</p>
     #[serde(rename = "pullRequestStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub pull_request_status: Option<String>,
+    pub pull_request_status: Option<PullRequestStatusEnum>,
     /// <p>The targets of the pull request, including the source branch and destination branch for the pull request.</p>
     #[serde(rename = "pullRequestTargets")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2739,8 +3651,13 @@ pub struct PullRequestEvent {
     pub approval_state_changed_event_metadata: Option<ApprovalStateChangedEventMetadata>,
     /// <p>The day and time of the pull request event, in timestamp format.</p>
     #[serde(rename = "eventDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub event_date: Option<f64>,
+    pub event_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Information about the source and destination branches for the pull request.</p>
     #[serde(rename = "pullRequestCreatedEventMetadata")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2748,7 +3665,7 @@ pub struct PullRequestEvent {
     /// <p>The type of the pull request event (for example, a status change event (PULL_REQUEST_STATUS_CHANGED) or update event (PULL_REQUEST_SOURCE_REFERENCE_UPDATED)).</p>
     #[serde(rename = "pullRequestEventType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub pull_request_event_type: Option<String>,
+    pub pull_request_event_type: Option<PullRequestEventType>,
     /// <p>The system-generated ID of the pull request.</p>
     #[serde(rename = "pullRequestId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2769,6 +3686,176 @@ pub struct PullRequestEvent {
     pub pull_request_status_changed_event_metadata: Option<PullRequestStatusChangedEventMetadata>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PullRequestEventType {
+    /// `PULL_REQUEST_CREATED`
+    PullRequestCreated,
+    /// `PULL_REQUEST_STATUS_CHANGED`
+    PullRequestStatusChanged,
+    /// `PULL_REQUEST_SOURCE_REFERENCE_UPDATED`
+    PullRequestSourceReferenceUpdated,
+    /// `PULL_REQUEST_MERGE_STATE_CHANGED`
+    PullRequestMergeStateChanged,
+    /// `PULL_REQUEST_APPROVAL_RULE_CREATED`
+    PullRequestApprovalRuleCreated,
+    /// `PULL_REQUEST_APPROVAL_RULE_UPDATED`
+    PullRequestApprovalRuleUpdated,
+    /// `PULL_REQUEST_APPROVAL_RULE_DELETED`
+    PullRequestApprovalRuleDeleted,
+    /// `PULL_REQUEST_APPROVAL_RULE_OVERRIDDEN`
+    PullRequestApprovalRuleOverridden,
+    /// `PULL_REQUEST_APPROVAL_STATE_CHANGED`
+    PullRequestApprovalStateChanged,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PullRequestEventType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PullRequestEventType::PullRequestCreated => "PULL_REQUEST_CREATED",
+            PullRequestEventType::PullRequestStatusChanged => "PULL_REQUEST_STATUS_CHANGED",
+            PullRequestEventType::PullRequestSourceReferenceUpdated => {
+                "PULL_REQUEST_SOURCE_REFERENCE_UPDATED"
+            }
+            PullRequestEventType::PullRequestMergeStateChanged => {
+                "PULL_REQUEST_MERGE_STATE_CHANGED"
+            }
+            PullRequestEventType::PullRequestApprovalRuleCreated => {
+                "PULL_REQUEST_APPROVAL_RULE_CREATED"
+            }
+            PullRequestEventType::PullRequestApprovalRuleUpdated => {
+                "PULL_REQUEST_APPROVAL_RULE_UPDATED"
+            }
+            PullRequestEventType::PullRequestApprovalRuleDeleted => {
+                "PULL_REQUEST_APPROVAL_RULE_DELETED"
+            }
+            PullRequestEventType::PullRequestApprovalRuleOverridden => {
+                "PULL_REQUEST_APPROVAL_RULE_OVERRIDDEN"
+            }
+            PullRequestEventType::PullRequestApprovalStateChanged => {
+                "PULL_REQUEST_APPROVAL_STATE_CHANGED"
+            }
+            PullRequestEventType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PullRequestEventType {
+    fn default() -> PullRequestEventType {
+        PullRequestEventType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PullRequestEventType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PullRequestEventType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PullRequestEventType {
+    fn from(value: &'a str) -> PullRequestEventType {
+        match value {
+            "PULL_REQUEST_CREATED" => PullRequestEventType::PullRequestCreated,
+            "PULL_REQUEST_STATUS_CHANGED" => PullRequestEventType::PullRequestStatusChanged,
+            "PULL_REQUEST_SOURCE_REFERENCE_UPDATED" => {
+                PullRequestEventType::PullRequestSourceReferenceUpdated
+            }
+            "PULL_REQUEST_MERGE_STATE_CHANGED" => {
+                PullRequestEventType::PullRequestMergeStateChanged
+            }
+            "PULL_REQUEST_APPROVAL_RULE_CREATED" => {
+                PullRequestEventType::PullRequestApprovalRuleCreated
+            }
+            "PULL_REQUEST_APPROVAL_RULE_UPDATED" => {
+                PullRequestEventType::PullRequestApprovalRuleUpdated
+            }
+            "PULL_REQUEST_APPROVAL_RULE_DELETED" => {
+                PullRequestEventType::PullRequestApprovalRuleDeleted
+            }
+            "PULL_REQUEST_APPROVAL_RULE_OVERRIDDEN" => {
+                PullRequestEventType::PullRequestApprovalRuleOverridden
+            }
+            "PULL_REQUEST_APPROVAL_STATE_CHANGED" => {
+                PullRequestEventType::PullRequestApprovalStateChanged
+            }
+            _ => PullRequestEventType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PullRequestEventType {
+    fn from(value: String) -> PullRequestEventType {
+        match value.as_str() {
+            "PULL_REQUEST_CREATED" => PullRequestEventType::PullRequestCreated,
+            "PULL_REQUEST_STATUS_CHANGED" => PullRequestEventType::PullRequestStatusChanged,
+            "PULL_REQUEST_SOURCE_REFERENCE_UPDATED" => {
+                PullRequestEventType::PullRequestSourceReferenceUpdated
+            }
+            "PULL_REQUEST_MERGE_STATE_CHANGED" => {
+                PullRequestEventType::PullRequestMergeStateChanged
+            }
+            "PULL_REQUEST_APPROVAL_RULE_CREATED" => {
+                PullRequestEventType::PullRequestApprovalRuleCreated
+            }
+            "PULL_REQUEST_APPROVAL_RULE_UPDATED" => {
+                PullRequestEventType::PullRequestApprovalRuleUpdated
+            }
+            "PULL_REQUEST_APPROVAL_RULE_DELETED" => {
+                PullRequestEventType::PullRequestApprovalRuleDeleted
+            }
+            "PULL_REQUEST_APPROVAL_RULE_OVERRIDDEN" => {
+                PullRequestEventType::PullRequestApprovalRuleOverridden
+            }
+            "PULL_REQUEST_APPROVAL_STATE_CHANGED" => {
+                PullRequestEventType::PullRequestApprovalStateChanged
+            }
+            _ => PullRequestEventType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PullRequestEventType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PullRequestEventType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PullRequestEventType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PullRequestEventType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PullRequestEventType {
+    fn deserialize<D>(deserializer: D) -> Result<PullRequestEventType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Returns information about the change in the merge state for a pull request event. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -2809,16 +3896,109 @@ pub struct PullRequestSourceReferenceUpdatedEventMetadata {
     pub repository_name: Option<String>,
 }
 
-/// <p>Information about a change to the status of a pull request.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct PullRequestStatusChangedEventMetadata {
-    /// <p>The changed status of the pull request.</p>
-    #[serde(rename = "pullRequestStatus")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub pull_request_status: Option<String>,
+/// <p>Information about a change to the status of a pull request.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct PullRequestStatusChangedEventMetadata {
+    /// <p>The changed status of the pull request.</p>
+    #[serde(rename = "pullRequestStatus")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pull_request_status: Option<PullRequestStatusEnum>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PullRequestStatusEnum {
+    /// `OPEN`
+    Open,
+    /// `CLOSED`
+    Closed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PullRequestStatusEnum {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PullRequestStatusEnum::Open => "OPEN",
+            PullRequestStatusEnum::Closed => "CLOSED",
+            PullRequestStatusEnum::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PullRequestStatusEnum {
+    fn default() -> PullRequestStatusEnum {
+        PullRequestStatusEnum::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PullRequestStatusEnum {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PullRequestStatusEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PullRequestStatusEnum {
+    fn from(value: &'a str) -> PullRequestStatusEnum {
+        match value {
+            "OPEN" => PullRequestStatusEnum::Open,
+            "CLOSED" => PullRequestStatusEnum::Closed,
+            _ => PullRequestStatusEnum::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PullRequestStatusEnum {
+    fn from(value: String) -> PullRequestStatusEnum {
+        match value.as_str() {
+            "OPEN" => PullRequestStatusEnum::Open,
+            "CLOSED" => PullRequestStatusEnum::Closed,
+            _ => PullRequestStatusEnum::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PullRequestStatusEnum {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PullRequestStatusEnum, Self::Err> {
+        Ok(value.into())
+    }
 }
 
+impl ::rusoto_core::param::ToParam for PullRequestStatusEnum {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PullRequestStatusEnum {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PullRequestStatusEnum {
+    fn deserialize<D>(deserializer: D) -> Result<PullRequestStatusEnum, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Returns information about a pull request target.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -2869,7 +4049,7 @@ pub struct PutFileEntry {
     /// <p>The extrapolated file mode permissions for the file. Valid values include EXECUTABLE and NORMAL.</p>
     #[serde(rename = "fileMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub file_mode: Option<String>,
+    pub file_mode: Option<FileModeTypeEnum>,
     /// <p>The full path to the file in the repository, including the name of the file.</p>
     #[serde(rename = "filePath")]
     pub file_path: String,
@@ -2904,7 +4084,7 @@ pub struct PutFileInput {
     /// <p>The file mode permissions of the blob. Valid file mode permissions are listed here.</p>
     #[serde(rename = "fileMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub file_mode: Option<String>,
+    pub file_mode: Option<FileModeTypeEnum>,
     /// <p><p>The name of the file you want to add or update, including the relative path to the file in the repository.</p> <note> <p>If the path does not currently exist in the repository, the path is created as part of adding the file.</p> </note></p>
     #[serde(rename = "filePath")]
     pub file_path: String,
@@ -2957,6 +4137,99 @@ pub struct PutRepositoryTriggersOutput {
     pub configuration_id: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RelativeFileVersionEnum {
+    /// `BEFORE`
+    Before,
+    /// `AFTER`
+    After,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RelativeFileVersionEnum {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RelativeFileVersionEnum::Before => "BEFORE",
+            RelativeFileVersionEnum::After => "AFTER",
+            RelativeFileVersionEnum::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RelativeFileVersionEnum {
+    fn default() -> RelativeFileVersionEnum {
+        RelativeFileVersionEnum::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RelativeFileVersionEnum {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RelativeFileVersionEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RelativeFileVersionEnum {
+    fn from(value: &'a str) -> RelativeFileVersionEnum {
+        match value {
+            "BEFORE" => RelativeFileVersionEnum::Before,
+            "AFTER" => RelativeFileVersionEnum::After,
+            _ => RelativeFileVersionEnum::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RelativeFileVersionEnum {
+    fn from(value: String) -> RelativeFileVersionEnum {
+        match value.as_str() {
+            "BEFORE" => RelativeFileVersionEnum::Before,
+            "AFTER" => RelativeFileVersionEnum::After,
+            _ => RelativeFileVersionEnum::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RelativeFileVersionEnum {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RelativeFileVersionEnum, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RelativeFileVersionEnum {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RelativeFileVersionEnum {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RelativeFileVersionEnum {
+    fn deserialize<D>(deserializer: D) -> Result<RelativeFileVersionEnum, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about a replacement content entry in the conflict of a merge or pull request operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -2973,15 +4246,118 @@ pub struct ReplaceContentEntry {
     /// <p>The file mode to apply during conflict resoltion.</p>
     #[serde(rename = "fileMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub file_mode: Option<String>,
+    pub file_mode: Option<FileModeTypeEnum>,
     /// <p>The path of the conflicting file.</p>
     #[serde(rename = "filePath")]
     pub file_path: String,
     /// <p>The replacement type to use when determining how to resolve the conflict.</p>
     #[serde(rename = "replacementType")]
-    pub replacement_type: String,
+    pub replacement_type: ReplacementTypeEnum,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ReplacementTypeEnum {
+    /// `KEEP_BASE`
+    KeepBase,
+    /// `KEEP_SOURCE`
+    KeepSource,
+    /// `KEEP_DESTINATION`
+    KeepDestination,
+    /// `USE_NEW_CONTENT`
+    UseNewContent,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ReplacementTypeEnum {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReplacementTypeEnum::KeepBase => "KEEP_BASE",
+            ReplacementTypeEnum::KeepSource => "KEEP_SOURCE",
+            ReplacementTypeEnum::KeepDestination => "KEEP_DESTINATION",
+            ReplacementTypeEnum::UseNewContent => "USE_NEW_CONTENT",
+            ReplacementTypeEnum::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ReplacementTypeEnum {
+    fn default() -> ReplacementTypeEnum {
+        ReplacementTypeEnum::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ReplacementTypeEnum {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ReplacementTypeEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ReplacementTypeEnum {
+    fn from(value: &'a str) -> ReplacementTypeEnum {
+        match value {
+            "KEEP_BASE" => ReplacementTypeEnum::KeepBase,
+            "KEEP_SOURCE" => ReplacementTypeEnum::KeepSource,
+            "KEEP_DESTINATION" => ReplacementTypeEnum::KeepDestination,
+            "USE_NEW_CONTENT" => ReplacementTypeEnum::UseNewContent,
+            _ => ReplacementTypeEnum::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReplacementTypeEnum {
+    fn from(value: String) -> ReplacementTypeEnum {
+        match value.as_str() {
+            "KEEP_BASE" => ReplacementTypeEnum::KeepBase,
+            "KEEP_SOURCE" => ReplacementTypeEnum::KeepSource,
+            "KEEP_DESTINATION" => ReplacementTypeEnum::KeepDestination,
+            "USE_NEW_CONTENT" => ReplacementTypeEnum::UseNewContent,
+            _ => ReplacementTypeEnum::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ReplacementTypeEnum {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ReplacementTypeEnum, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ReplacementTypeEnum {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ReplacementTypeEnum {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for ReplacementTypeEnum {
+    fn deserialize<D>(deserializer: D) -> Result<ReplacementTypeEnum, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about a repository.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -3004,16 +4380,26 @@ pub struct RepositoryMetadata {
     pub clone_url_ssh: Option<String>,
     /// <p>The date and time the repository was created, in timestamp format.</p>
     #[serde(rename = "creationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The repository's default branch name.</p>
     #[serde(rename = "defaultBranch")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub default_branch: Option<String>,
     /// <p>The date and time the repository was last modified, in timestamp format.</p>
     #[serde(rename = "lastModifiedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_date: Option<f64>,
+    pub last_modified_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A comment or description about the repository.</p>
     #[serde(rename = "repositoryDescription")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3058,12 +4444,115 @@ pub struct RepositoryTrigger {
     pub destination_arn: String,
     /// <p><p>The repository events that cause the trigger to run actions in another service, such as sending a notification through Amazon SNS. </p> <note> <p>The valid value &quot;all&quot; cannot be used with any other values.</p> </note></p>
     #[serde(rename = "events")]
-    pub events: Vec<String>,
+    pub events: Vec<RepositoryTriggerEventEnum>,
     /// <p>The name of the trigger.</p>
     #[serde(rename = "name")]
     pub name: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RepositoryTriggerEventEnum {
+    /// `all`
+    All,
+    /// `updateReference`
+    UpdateReference,
+    /// `createReference`
+    CreateReference,
+    /// `deleteReference`
+    DeleteReference,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RepositoryTriggerEventEnum {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RepositoryTriggerEventEnum::All => "all",
+            RepositoryTriggerEventEnum::UpdateReference => "updateReference",
+            RepositoryTriggerEventEnum::CreateReference => "createReference",
+            RepositoryTriggerEventEnum::DeleteReference => "deleteReference",
+            RepositoryTriggerEventEnum::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RepositoryTriggerEventEnum {
+    fn default() -> RepositoryTriggerEventEnum {
+        RepositoryTriggerEventEnum::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RepositoryTriggerEventEnum {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RepositoryTriggerEventEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RepositoryTriggerEventEnum {
+    fn from(value: &'a str) -> RepositoryTriggerEventEnum {
+        match value {
+            "all" => RepositoryTriggerEventEnum::All,
+            "updateReference" => RepositoryTriggerEventEnum::UpdateReference,
+            "createReference" => RepositoryTriggerEventEnum::CreateReference,
+            "deleteReference" => RepositoryTriggerEventEnum::DeleteReference,
+            _ => RepositoryTriggerEventEnum::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RepositoryTriggerEventEnum {
+    fn from(value: String) -> RepositoryTriggerEventEnum {
+        match value.as_str() {
+            "all" => RepositoryTriggerEventEnum::All,
+            "updateReference" => RepositoryTriggerEventEnum::UpdateReference,
+            "createReference" => RepositoryTriggerEventEnum::CreateReference,
+            "deleteReference" => RepositoryTriggerEventEnum::DeleteReference,
+            _ => RepositoryTriggerEventEnum::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RepositoryTriggerEventEnum {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RepositoryTriggerEventEnum, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RepositoryTriggerEventEnum {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RepositoryTriggerEventEnum {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RepositoryTriggerEventEnum {
+    fn deserialize<D>(deserializer: D) -> Result<RepositoryTriggerEventEnum, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A trigger failed to run.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -3084,12 +4573,105 @@ pub struct RepositoryTriggerExecutionFailure {
 pub struct SetFileModeEntry {
     /// <p>The file mode for the file.</p>
     #[serde(rename = "fileMode")]
-    pub file_mode: String,
+    pub file_mode: FileModeTypeEnum,
     /// <p>The full path to the file, including the name of the file.</p>
     #[serde(rename = "filePath")]
     pub file_path: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SortByEnum {
+    /// `repositoryName`
+    RepositoryName,
+    /// `lastModifiedDate`
+    LastModifiedDate,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SortByEnum {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SortByEnum::RepositoryName => "repositoryName",
+            SortByEnum::LastModifiedDate => "lastModifiedDate",
+            SortByEnum::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SortByEnum {
+    fn default() -> SortByEnum {
+        SortByEnum::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SortByEnum {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SortByEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SortByEnum {
+    fn from(value: &'a str) -> SortByEnum {
+        match value {
+            "repositoryName" => SortByEnum::RepositoryName,
+            "lastModifiedDate" => SortByEnum::LastModifiedDate,
+            _ => SortByEnum::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SortByEnum {
+    fn from(value: String) -> SortByEnum {
+        match value.as_str() {
+            "repositoryName" => SortByEnum::RepositoryName,
+            "lastModifiedDate" => SortByEnum::LastModifiedDate,
+            _ => SortByEnum::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SortByEnum {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SortByEnum, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SortByEnum {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SortByEnum {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SortByEnum {
+    fn deserialize<D>(deserializer: D) -> Result<SortByEnum, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about a source file that is part of changes made in a commit.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -3136,7 +4718,7 @@ pub struct SymbolicLink {
     /// <p>The file mode permissions of the blob that cotains information about the symbolic link.</p>
     #[serde(rename = "fileMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub file_mode: Option<String>,
+    pub file_mode: Option<FileModeTypeEnum>,
     /// <p>The relative path of the symbolic link from the folder where the query originated.</p>
     #[serde(rename = "relativePath")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3330,7 +4912,7 @@ pub struct UpdatePullRequestApprovalRuleContentOutput {
 pub struct UpdatePullRequestApprovalStateInput {
     /// <p>The approval state to associate with the user on the pull request.</p>
     #[serde(rename = "approvalState")]
-    pub approval_state: String,
+    pub approval_state: ApprovalState,
     /// <p>The system-generated ID of the pull request.</p>
     #[serde(rename = "pullRequestId")]
     pub pull_request_id: String,
@@ -3366,7 +4948,7 @@ pub struct UpdatePullRequestStatusInput {
     pub pull_request_id: String,
     /// <p>The status of the pull request. The only valid operations are to update the status from <code>OPEN</code> to <code>OPEN</code>, <code>OPEN</code> to <code>CLOSED</code> or from <code>CLOSED</code> to <code>CLOSED</code>.</p>
     #[serde(rename = "pullRequestStatus")]
-    pub pull_request_status: String,
+    pub pull_request_status: PullRequestStatusEnum,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -14699,11 +16281,12 @@ pub struct CodeCommitClient {
 impl CodeCommitClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> CodeCommitClient {
         CodeCommitClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "CodeCommit"),
         }
     }
 
@@ -14735,6 +16318,7 @@ impl CodeCommit for CodeCommitClient {
         input: AssociateApprovalRuleTemplateWithRepositoryInput,
     ) -> Result<(), RusotoError<AssociateApprovalRuleTemplateWithRepositoryError>> {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("AssociateApprovalRuleTemplateWithRepository");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14768,6 +16352,7 @@ impl CodeCommit for CodeCommitClient {
         RusotoError<BatchAssociateApprovalRuleTemplateWithRepositoriesError>,
     > {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("BatchAssociateApprovalRuleTemplateWithRepositories");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14800,6 +16385,7 @@ impl CodeCommit for CodeCommitClient {
     ) -> Result<BatchDescribeMergeConflictsOutput, RusotoError<BatchDescribeMergeConflictsError>>
     {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("BatchDescribeMergeConflicts");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14834,6 +16420,7 @@ impl CodeCommit for CodeCommitClient {
         RusotoError<BatchDisassociateApprovalRuleTemplateFromRepositoriesError>,
     > {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("BatchDisassociateApprovalRuleTemplateFromRepositories");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14865,6 +16452,7 @@ impl CodeCommit for CodeCommitClient {
         input: BatchGetCommitsInput,
     ) -> Result<BatchGetCommitsOutput, RusotoError<BatchGetCommitsError>> {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("BatchGetCommits");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.BatchGetCommits");
@@ -14892,6 +16480,7 @@ impl CodeCommit for CodeCommitClient {
         input: BatchGetRepositoriesInput,
     ) -> Result<BatchGetRepositoriesOutput, RusotoError<BatchGetRepositoriesError>> {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("BatchGetRepositories");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.BatchGetRepositories");
@@ -14921,6 +16510,7 @@ impl CodeCommit for CodeCommitClient {
     ) -> Result<CreateApprovalRuleTemplateOutput, RusotoError<CreateApprovalRuleTemplateError>>
     {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("CreateApprovalRuleTemplate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14952,6 +16542,7 @@ impl CodeCommit for CodeCommitClient {
         input: CreateBranchInput,
     ) -> Result<(), RusotoError<CreateBranchError>> {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("CreateBranch");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.CreateBranch");
@@ -14979,6 +16570,7 @@ impl CodeCommit for CodeCommitClient {
         input: CreateCommitInput,
     ) -> Result<CreateCommitOutput, RusotoError<CreateCommitError>> {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("CreateCommit");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.CreateCommit");
@@ -15006,6 +16598,7 @@ impl CodeCommit for CodeCommitClient {
         input: CreatePullRequestInput,
     ) -> Result<CreatePullRequestOutput, RusotoError<CreatePullRequestError>> {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("CreatePullRequest");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.CreatePullRequest");
@@ -15034,6 +16627,7 @@ impl CodeCommit for CodeCommitClient {
     ) -> Result<CreatePullRequestApprovalRuleOutput, RusotoError<CreatePullRequestApprovalRuleError>>
     {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("CreatePullRequestApprovalRule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15065,6 +16659,7 @@ impl CodeCommit for CodeCommitClient {
         input: CreateRepositoryInput,
     ) -> Result<CreateRepositoryOutput, RusotoError<CreateRepositoryError>> {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("CreateRepository");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.CreateRepository");
@@ -15093,6 +16688,7 @@ impl CodeCommit for CodeCommitClient {
     ) -> Result<CreateUnreferencedMergeCommitOutput, RusotoError<CreateUnreferencedMergeCommitError>>
     {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("CreateUnreferencedMergeCommit");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15125,6 +16721,7 @@ impl CodeCommit for CodeCommitClient {
     ) -> Result<DeleteApprovalRuleTemplateOutput, RusotoError<DeleteApprovalRuleTemplateError>>
     {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("DeleteApprovalRuleTemplate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15156,6 +16753,7 @@ impl CodeCommit for CodeCommitClient {
         input: DeleteBranchInput,
     ) -> Result<DeleteBranchOutput, RusotoError<DeleteBranchError>> {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("DeleteBranch");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.DeleteBranch");
@@ -15183,6 +16781,7 @@ impl CodeCommit for CodeCommitClient {
         input: DeleteCommentContentInput,
     ) -> Result<DeleteCommentContentOutput, RusotoError<DeleteCommentContentError>> {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("DeleteCommentContent");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.DeleteCommentContent");
@@ -15211,6 +16810,7 @@ impl CodeCommit for CodeCommitClient {
         input: DeleteFileInput,
     ) -> Result<DeleteFileOutput, RusotoError<DeleteFileError>> {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("DeleteFile");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.DeleteFile");
@@ -15239,6 +16839,7 @@ impl CodeCommit for CodeCommitClient {
     ) -> Result<DeletePullRequestApprovalRuleOutput, RusotoError<DeletePullRequestApprovalRuleError>>
     {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("DeletePullRequestApprovalRule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15270,6 +16871,7 @@ impl CodeCommit for CodeCommitClient {
         input: DeleteRepositoryInput,
     ) -> Result<DeleteRepositoryOutput, RusotoError<DeleteRepositoryError>> {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("DeleteRepository");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.DeleteRepository");
@@ -15297,6 +16899,7 @@ impl CodeCommit for CodeCommitClient {
         input: DescribeMergeConflictsInput,
     ) -> Result<DescribeMergeConflictsOutput, RusotoError<DescribeMergeConflictsError>> {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("DescribeMergeConflicts");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.DescribeMergeConflicts");
@@ -15325,6 +16928,7 @@ impl CodeCommit for CodeCommitClient {
         input: DescribePullRequestEventsInput,
     ) -> Result<DescribePullRequestEventsOutput, RusotoError<DescribePullRequestEventsError>> {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("DescribePullRequestEvents");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15356,6 +16960,7 @@ impl CodeCommit for CodeCommitClient {
         input: DisassociateApprovalRuleTemplateFromRepositoryInput,
     ) -> Result<(), RusotoError<DisassociateApprovalRuleTemplateFromRepositoryError>> {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("DisassociateApprovalRuleTemplateFromRepository");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15389,6 +16994,7 @@ impl CodeCommit for CodeCommitClient {
         RusotoError<EvaluatePullRequestApprovalRulesError>,
     > {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("EvaluatePullRequestApprovalRules");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15422,6 +17028,7 @@ impl CodeCommit for CodeCommitClient {
         input: GetApprovalRuleTemplateInput,
     ) -> Result<GetApprovalRuleTemplateOutput, RusotoError<GetApprovalRuleTemplateError>> {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("GetApprovalRuleTemplate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15453,6 +17060,7 @@ impl CodeCommit for CodeCommitClient {
         input: GetBlobInput,
     ) -> Result<GetBlobOutput, RusotoError<GetBlobError>> {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("GetBlob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.GetBlob");
@@ -15480,6 +17088,7 @@ impl CodeCommit for CodeCommitClient {
         input: GetBranchInput,
     ) -> Result<GetBranchOutput, RusotoError<GetBranchError>> {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("GetBranch");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.GetBranch");
@@ -15507,6 +17116,7 @@ impl CodeCommit for CodeCommitClient {
         input: GetCommentInput,
     ) -> Result<GetCommentOutput, RusotoError<GetCommentError>> {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("GetComment");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.GetComment");
@@ -15535,6 +17145,7 @@ impl CodeCommit for CodeCommitClient {
     ) -> Result<GetCommentsForComparedCommitOutput, RusotoError<GetCommentsForComparedCommitError>>
     {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("GetCommentsForComparedCommit");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15566,6 +17177,7 @@ impl CodeCommit for CodeCommitClient {
         input: GetCommentsForPullRequestInput,
     ) -> Result<GetCommentsForPullRequestOutput, RusotoError<GetCommentsForPullRequestError>> {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("GetCommentsForPullRequest");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15597,6 +17209,7 @@ impl CodeCommit for CodeCommitClient {
         input: GetCommitInput,
     ) -> Result<GetCommitOutput, RusotoError<GetCommitError>> {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("GetCommit");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.GetCommit");
@@ -15624,6 +17237,7 @@ impl CodeCommit for CodeCommitClient {
         input: GetDifferencesInput,
     ) -> Result<GetDifferencesOutput, RusotoError<GetDifferencesError>> {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("GetDifferences");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.GetDifferences");
@@ -15651,6 +17265,7 @@ impl CodeCommit for CodeCommitClient {
         input: GetFileInput,
     ) -> Result<GetFileOutput, RusotoError<GetFileError>> {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("GetFile");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.GetFile");
@@ -15678,6 +17293,7 @@ impl CodeCommit for CodeCommitClient {
         input: GetFolderInput,
     ) -> Result<GetFolderOutput, RusotoError<GetFolderError>> {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("GetFolder");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.GetFolder");
@@ -15705,6 +17321,7 @@ impl CodeCommit for CodeCommitClient {
         input: GetMergeCommitInput,
     ) -> Result<GetMergeCommitOutput, RusotoError<GetMergeCommitError>> {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("GetMergeCommit");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.GetMergeCommit");
@@ -15732,6 +17349,7 @@ impl CodeCommit for CodeCommitClient {
         input: GetMergeConflictsInput,
     ) -> Result<GetMergeConflictsOutput, RusotoError<GetMergeConflictsError>> {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("GetMergeConflicts");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.GetMergeConflicts");
@@ -15759,6 +17377,7 @@ impl CodeCommit for CodeCommitClient {
         input: GetMergeOptionsInput,
     ) -> Result<GetMergeOptionsOutput, RusotoError<GetMergeOptionsError>> {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("GetMergeOptions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.GetMergeOptions");
@@ -15786,6 +17405,7 @@ impl CodeCommit for CodeCommitClient {
         input: GetPullRequestInput,
     ) -> Result<GetPullRequestOutput, RusotoError<GetPullRequestError>> {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("GetPullRequest");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.GetPullRequest");
@@ -15814,6 +17434,7 @@ impl CodeCommit for CodeCommitClient {
     ) -> Result<GetPullRequestApprovalStatesOutput, RusotoError<GetPullRequestApprovalStatesError>>
     {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("GetPullRequestApprovalStates");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15846,6 +17467,7 @@ impl CodeCommit for CodeCommitClient {
     ) -> Result<GetPullRequestOverrideStateOutput, RusotoError<GetPullRequestOverrideStateError>>
     {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("GetPullRequestOverrideState");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15877,6 +17499,7 @@ impl CodeCommit for CodeCommitClient {
         input: GetRepositoryInput,
     ) -> Result<GetRepositoryOutput, RusotoError<GetRepositoryError>> {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("GetRepository");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.GetRepository");
@@ -15904,6 +17527,7 @@ impl CodeCommit for CodeCommitClient {
         input: GetRepositoryTriggersInput,
     ) -> Result<GetRepositoryTriggersOutput, RusotoError<GetRepositoryTriggersError>> {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("GetRepositoryTriggers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.GetRepositoryTriggers");
@@ -15932,6 +17556,7 @@ impl CodeCommit for CodeCommitClient {
         input: ListApprovalRuleTemplatesInput,
     ) -> Result<ListApprovalRuleTemplatesOutput, RusotoError<ListApprovalRuleTemplatesError>> {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("ListApprovalRuleTemplates");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15966,6 +17591,7 @@ impl CodeCommit for CodeCommitClient {
         RusotoError<ListAssociatedApprovalRuleTemplatesForRepositoryError>,
     > {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("ListAssociatedApprovalRuleTemplatesForRepository");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15997,6 +17623,7 @@ impl CodeCommit for CodeCommitClient {
         input: ListBranchesInput,
     ) -> Result<ListBranchesOutput, RusotoError<ListBranchesError>> {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("ListBranches");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.ListBranches");
@@ -16024,6 +17651,7 @@ impl CodeCommit for CodeCommitClient {
         input: ListPullRequestsInput,
     ) -> Result<ListPullRequestsOutput, RusotoError<ListPullRequestsError>> {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("ListPullRequests");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.ListPullRequests");
@@ -16051,6 +17679,7 @@ impl CodeCommit for CodeCommitClient {
         input: ListRepositoriesInput,
     ) -> Result<ListRepositoriesOutput, RusotoError<ListRepositoriesError>> {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("ListRepositories");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.ListRepositories");
@@ -16081,6 +17710,7 @@ impl CodeCommit for CodeCommitClient {
         RusotoError<ListRepositoriesForApprovalRuleTemplateError>,
     > {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("ListRepositoriesForApprovalRuleTemplate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16114,6 +17744,7 @@ impl CodeCommit for CodeCommitClient {
         input: ListTagsForResourceInput,
     ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.ListTagsForResource");
@@ -16143,6 +17774,7 @@ impl CodeCommit for CodeCommitClient {
     ) -> Result<MergeBranchesByFastForwardOutput, RusotoError<MergeBranchesByFastForwardError>>
     {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("MergeBranchesByFastForward");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16174,6 +17806,7 @@ impl CodeCommit for CodeCommitClient {
         input: MergeBranchesBySquashInput,
     ) -> Result<MergeBranchesBySquashOutput, RusotoError<MergeBranchesBySquashError>> {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("MergeBranchesBySquash");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.MergeBranchesBySquash");
@@ -16202,6 +17835,7 @@ impl CodeCommit for CodeCommitClient {
         input: MergeBranchesByThreeWayInput,
     ) -> Result<MergeBranchesByThreeWayOutput, RusotoError<MergeBranchesByThreeWayError>> {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("MergeBranchesByThreeWay");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16234,6 +17868,7 @@ impl CodeCommit for CodeCommitClient {
     ) -> Result<MergePullRequestByFastForwardOutput, RusotoError<MergePullRequestByFastForwardError>>
     {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("MergePullRequestByFastForward");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16265,6 +17900,7 @@ impl CodeCommit for CodeCommitClient {
         input: MergePullRequestBySquashInput,
     ) -> Result<MergePullRequestBySquashOutput, RusotoError<MergePullRequestBySquashError>> {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("MergePullRequestBySquash");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16297,6 +17933,7 @@ impl CodeCommit for CodeCommitClient {
     ) -> Result<MergePullRequestByThreeWayOutput, RusotoError<MergePullRequestByThreeWayError>>
     {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("MergePullRequestByThreeWay");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16328,6 +17965,7 @@ impl CodeCommit for CodeCommitClient {
         input: OverridePullRequestApprovalRulesInput,
     ) -> Result<(), RusotoError<OverridePullRequestApprovalRulesError>> {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("OverridePullRequestApprovalRules");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16361,6 +17999,7 @@ impl CodeCommit for CodeCommitClient {
     ) -> Result<PostCommentForComparedCommitOutput, RusotoError<PostCommentForComparedCommitError>>
     {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("PostCommentForComparedCommit");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16392,6 +18031,7 @@ impl CodeCommit for CodeCommitClient {
         input: PostCommentForPullRequestInput,
     ) -> Result<PostCommentForPullRequestOutput, RusotoError<PostCommentForPullRequestError>> {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("PostCommentForPullRequest");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16423,6 +18063,7 @@ impl CodeCommit for CodeCommitClient {
         input: PostCommentReplyInput,
     ) -> Result<PostCommentReplyOutput, RusotoError<PostCommentReplyError>> {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("PostCommentReply");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.PostCommentReply");
@@ -16450,6 +18091,7 @@ impl CodeCommit for CodeCommitClient {
         input: PutFileInput,
     ) -> Result<PutFileOutput, RusotoError<PutFileError>> {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("PutFile");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.PutFile");
@@ -16477,6 +18119,7 @@ impl CodeCommit for CodeCommitClient {
         input: PutRepositoryTriggersInput,
     ) -> Result<PutRepositoryTriggersOutput, RusotoError<PutRepositoryTriggersError>> {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("PutRepositoryTriggers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.PutRepositoryTriggers");
@@ -16505,6 +18148,7 @@ impl CodeCommit for CodeCommitClient {
         input: TagResourceInput,
     ) -> Result<(), RusotoError<TagResourceError>> {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.TagResource");
@@ -16532,6 +18176,7 @@ impl CodeCommit for CodeCommitClient {
         input: TestRepositoryTriggersInput,
     ) -> Result<TestRepositoryTriggersOutput, RusotoError<TestRepositoryTriggersError>> {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("TestRepositoryTriggers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.TestRepositoryTriggers");
@@ -16560,6 +18205,7 @@ impl CodeCommit for CodeCommitClient {
         input: UntagResourceInput,
     ) -> Result<(), RusotoError<UntagResourceError>> {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.UntagResource");
@@ -16590,6 +18236,7 @@ impl CodeCommit for CodeCommitClient {
         RusotoError<UpdateApprovalRuleTemplateContentError>,
     > {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("UpdateApprovalRuleTemplateContent");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16626,6 +18273,7 @@ impl CodeCommit for CodeCommitClient {
         RusotoError<UpdateApprovalRuleTemplateDescriptionError>,
     > {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("UpdateApprovalRuleTemplateDescription");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16662,6 +18310,7 @@ impl CodeCommit for CodeCommitClient {
         RusotoError<UpdateApprovalRuleTemplateNameError>,
     > {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("UpdateApprovalRuleTemplateName");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16693,6 +18342,7 @@ impl CodeCommit for CodeCommitClient {
         input: UpdateCommentInput,
     ) -> Result<UpdateCommentOutput, RusotoError<UpdateCommentError>> {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("UpdateComment");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.UpdateComment");
@@ -16720,6 +18370,7 @@ impl CodeCommit for CodeCommitClient {
         input: UpdateDefaultBranchInput,
     ) -> Result<(), RusotoError<UpdateDefaultBranchError>> {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("UpdateDefaultBranch");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.UpdateDefaultBranch");
@@ -16750,6 +18401,7 @@ impl CodeCommit for CodeCommitClient {
         RusotoError<UpdatePullRequestApprovalRuleContentError>,
     > {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("UpdatePullRequestApprovalRuleContent");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16783,6 +18435,7 @@ impl CodeCommit for CodeCommitClient {
         input: UpdatePullRequestApprovalStateInput,
     ) -> Result<(), RusotoError<UpdatePullRequestApprovalStateError>> {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("UpdatePullRequestApprovalState");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16814,6 +18467,7 @@ impl CodeCommit for CodeCommitClient {
     ) -> Result<UpdatePullRequestDescriptionOutput, RusotoError<UpdatePullRequestDescriptionError>>
     {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("UpdatePullRequestDescription");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16845,6 +18499,7 @@ impl CodeCommit for CodeCommitClient {
         input: UpdatePullRequestStatusInput,
     ) -> Result<UpdatePullRequestStatusOutput, RusotoError<UpdatePullRequestStatusError>> {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("UpdatePullRequestStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16876,6 +18531,7 @@ impl CodeCommit for CodeCommitClient {
         input: UpdatePullRequestTitleInput,
     ) -> Result<UpdatePullRequestTitleOutput, RusotoError<UpdatePullRequestTitleError>> {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("UpdatePullRequestTitle");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.UpdatePullRequestTitle");
@@ -16904,6 +18560,7 @@ impl CodeCommit for CodeCommitClient {
         input: UpdateRepositoryDescriptionInput,
     ) -> Result<(), RusotoError<UpdateRepositoryDescriptionError>> {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("UpdateRepositoryDescription");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16934,6 +18591,7 @@ impl CodeCommit for CodeCommitClient {
         input: UpdateRepositoryNameInput,
     ) -> Result<(), RusotoError<UpdateRepositoryNameError>> {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("UpdateRepositoryName");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.UpdateRepositoryName");
@@ -16955,3 +18613,240 @@ impl CodeCommit for CodeCommitClient {
         }
     }
 }
+impl CodeCommitClient {
+    /// Auto-paginating version of `describe_pull_request_events`, streaming every page of results.
+    pub fn describe_pull_request_events_pages(
+        &self,
+        input: DescribePullRequestEventsInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribePullRequestEventsOutput,
+        DescribePullRequestEventsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeCommitClient, input: DescribePullRequestEventsInput| async move {
+                client.describe_pull_request_events(input).await
+            },
+            |input: &DescribePullRequestEventsInput, output: &DescribePullRequestEventsOutput| {
+                Some(DescribePullRequestEventsInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_pull_request_events`, streaming the `pullRequestEvents` of every page of results.
+    pub fn describe_pull_request_events_items(
+        &self,
+        input: DescribePullRequestEventsInput,
+    ) -> rusoto_core::pagination::RusotoStream<PullRequestEvent, DescribePullRequestEventsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_pull_request_events_pages(input),
+            |output: DescribePullRequestEventsOutput| output.pull_request_events,
+        )
+    }
+
+    /// Auto-paginating version of `get_comments_for_compared_commit`, streaming every page of results.
+    pub fn get_comments_for_compared_commit_pages(
+        &self,
+        input: GetCommentsForComparedCommitInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        GetCommentsForComparedCommitOutput,
+        GetCommentsForComparedCommitError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeCommitClient, input: GetCommentsForComparedCommitInput| async move {
+                client.get_comments_for_compared_commit(input).await
+            },
+            |input: &GetCommentsForComparedCommitInput,
+             output: &GetCommentsForComparedCommitOutput| {
+                Some(GetCommentsForComparedCommitInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_comments_for_compared_commit`, streaming the `commentsForComparedCommitData` of every page of results.
+    pub fn get_comments_for_compared_commit_items(
+        &self,
+        input: GetCommentsForComparedCommitInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        CommentsForComparedCommit,
+        GetCommentsForComparedCommitError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.get_comments_for_compared_commit_pages(input),
+            |output: GetCommentsForComparedCommitOutput| {
+                output.comments_for_compared_commit_data.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_comments_for_pull_request`, streaming every page of results.
+    pub fn get_comments_for_pull_request_pages(
+        &self,
+        input: GetCommentsForPullRequestInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        GetCommentsForPullRequestOutput,
+        GetCommentsForPullRequestError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeCommitClient, input: GetCommentsForPullRequestInput| async move {
+                client.get_comments_for_pull_request(input).await
+            },
+            |input: &GetCommentsForPullRequestInput, output: &GetCommentsForPullRequestOutput| {
+                Some(GetCommentsForPullRequestInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_comments_for_pull_request`, streaming the `commentsForPullRequestData` of every page of results.
+    pub fn get_comments_for_pull_request_items(
+        &self,
+        input: GetCommentsForPullRequestInput,
+    ) -> rusoto_core::pagination::RusotoStream<CommentsForPullRequest, GetCommentsForPullRequestError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.get_comments_for_pull_request_pages(input),
+            |output: GetCommentsForPullRequestOutput| {
+                output.comments_for_pull_request_data.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_differences`, streaming every page of results.
+    pub fn get_differences_pages(
+        &self,
+        input: GetDifferencesInput,
+    ) -> rusoto_core::pagination::RusotoStream<GetDifferencesOutput, GetDifferencesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeCommitClient, input: GetDifferencesInput| async move {
+                client.get_differences(input).await
+            },
+            |input: &GetDifferencesInput, output: &GetDifferencesOutput| {
+                Some(GetDifferencesInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_differences`, streaming the `differences` of every page of results.
+    pub fn get_differences_items(
+        &self,
+        input: GetDifferencesInput,
+    ) -> rusoto_core::pagination::RusotoStream<Difference, GetDifferencesError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_differences_pages(input),
+            |output: GetDifferencesOutput| output.differences.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_branches`, streaming every page of results.
+    pub fn list_branches_pages(
+        &self,
+        input: ListBranchesInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListBranchesOutput, ListBranchesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeCommitClient, input: ListBranchesInput| async move {
+                client.list_branches(input).await
+            },
+            |input: &ListBranchesInput, output: &ListBranchesOutput| {
+                Some(ListBranchesInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_branches`, streaming the `branches` of every page of results.
+    pub fn list_branches_items(
+        &self,
+        input: ListBranchesInput,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListBranchesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_branches_pages(input),
+            |output: ListBranchesOutput| output.branches.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_pull_requests`, streaming every page of results.
+    pub fn list_pull_requests_pages(
+        &self,
+        input: ListPullRequestsInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListPullRequestsOutput, ListPullRequestsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeCommitClient, input: ListPullRequestsInput| async move {
+                client.list_pull_requests(input).await
+            },
+            |input: &ListPullRequestsInput, output: &ListPullRequestsOutput| {
+                Some(ListPullRequestsInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_pull_requests`, streaming the `pullRequestIds` of every page of results.
+    pub fn list_pull_requests_items(
+        &self,
+        input: ListPullRequestsInput,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListPullRequestsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_pull_requests_pages(input),
+            |output: ListPullRequestsOutput| output.pull_request_ids,
+        )
+    }
+
+    /// Auto-paginating version of `list_repositories`, streaming every page of results.
+    pub fn list_repositories_pages(
+        &self,
+        input: ListRepositoriesInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListRepositoriesOutput, ListRepositoriesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeCommitClient, input: ListRepositoriesInput| async move {
+                client.list_repositories(input).await
+            },
+            |input: &ListRepositoriesInput, output: &ListRepositoriesOutput| {
+                Some(ListRepositoriesInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_repositories`, streaming the `repositories` of every page of results.
+    pub fn list_repositories_items(
+        &self,
+        input: ListRepositoriesInput,
+    ) -> rusoto_core::pagination::RusotoStream<RepositoryNameIdPair, ListRepositoriesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_repositories_pages(input),
+            |output: ListRepositoriesOutput| output.repositories.unwrap_or_default(),
+        )
+    }
+}
@@ -90,11 +90,16 @@ pub struct ApplicationInfo {
     /// <p>The destination platform type for deployment of the application (<code>Lambda</code> or <code>Server</code>).</p>
     #[serde(rename = "computePlatform")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compute_platform: Option<String>,
+    pub compute_platform: Option<ComputePlatform>,
     /// <p>The time at which the application was created.</p>
     #[serde(rename = "createTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub create_time: Option<f64>,
+    pub create_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name for a connection to a GitHub account.</p>
     #[serde(rename = "gitHubAccountName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -105,6 +110,104 @@ pub struct ApplicationInfo {
     pub linked_to_git_hub: Option<bool>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ApplicationRevisionSortBy {
+    /// `registerTime`
+    RegisterTime,
+    /// `firstUsedTime`
+    FirstUsedTime,
+    /// `lastUsedTime`
+    LastUsedTime,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ApplicationRevisionSortBy {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ApplicationRevisionSortBy::RegisterTime => "registerTime",
+            ApplicationRevisionSortBy::FirstUsedTime => "firstUsedTime",
+            ApplicationRevisionSortBy::LastUsedTime => "lastUsedTime",
+            ApplicationRevisionSortBy::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ApplicationRevisionSortBy {
+    fn default() -> ApplicationRevisionSortBy {
+        ApplicationRevisionSortBy::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ApplicationRevisionSortBy {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ApplicationRevisionSortBy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ApplicationRevisionSortBy {
+    fn from(value: &'a str) -> ApplicationRevisionSortBy {
+        match value {
+            "registerTime" => ApplicationRevisionSortBy::RegisterTime,
+            "firstUsedTime" => ApplicationRevisionSortBy::FirstUsedTime,
+            "lastUsedTime" => ApplicationRevisionSortBy::LastUsedTime,
+            _ => ApplicationRevisionSortBy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ApplicationRevisionSortBy {
+    fn from(value: String) -> ApplicationRevisionSortBy {
+        match value.as_str() {
+            "registerTime" => ApplicationRevisionSortBy::RegisterTime,
+            "firstUsedTime" => ApplicationRevisionSortBy::FirstUsedTime,
+            "lastUsedTime" => ApplicationRevisionSortBy::LastUsedTime,
+            _ => ApplicationRevisionSortBy::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ApplicationRevisionSortBy {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ApplicationRevisionSortBy, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ApplicationRevisionSortBy {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ApplicationRevisionSortBy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ApplicationRevisionSortBy {
+    fn deserialize<D>(deserializer: D) -> Result<ApplicationRevisionSortBy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about a configuration for automatically rolling back to a previous version of an application revision when a deployment is not completed successfully.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AutoRollbackConfiguration {
@@ -115,9 +218,107 @@ pub struct AutoRollbackConfiguration {
     /// <p>The event type or types that trigger a rollback.</p>
     #[serde(rename = "events")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub events: Option<Vec<String>>,
+    pub events: Option<Vec<AutoRollbackEvent>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AutoRollbackEvent {
+    /// `DEPLOYMENT_FAILURE`
+    DeploymentFailure,
+    /// `DEPLOYMENT_STOP_ON_ALARM`
+    DeploymentStopOnAlarm,
+    /// `DEPLOYMENT_STOP_ON_REQUEST`
+    DeploymentStopOnRequest,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AutoRollbackEvent {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AutoRollbackEvent::DeploymentFailure => "DEPLOYMENT_FAILURE",
+            AutoRollbackEvent::DeploymentStopOnAlarm => "DEPLOYMENT_STOP_ON_ALARM",
+            AutoRollbackEvent::DeploymentStopOnRequest => "DEPLOYMENT_STOP_ON_REQUEST",
+            AutoRollbackEvent::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AutoRollbackEvent {
+    fn default() -> AutoRollbackEvent {
+        AutoRollbackEvent::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AutoRollbackEvent {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AutoRollbackEvent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AutoRollbackEvent {
+    fn from(value: &'a str) -> AutoRollbackEvent {
+        match value {
+            "DEPLOYMENT_FAILURE" => AutoRollbackEvent::DeploymentFailure,
+            "DEPLOYMENT_STOP_ON_ALARM" => AutoRollbackEvent::DeploymentStopOnAlarm,
+            "DEPLOYMENT_STOP_ON_REQUEST" => AutoRollbackEvent::DeploymentStopOnRequest,
+            _ => AutoRollbackEvent::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AutoRollbackEvent {
+    fn from(value: String) -> AutoRollbackEvent {
+        match value.as_str() {
+            "DEPLOYMENT_FAILURE" => AutoRollbackEvent::DeploymentFailure,
+            "DEPLOYMENT_STOP_ON_ALARM" => AutoRollbackEvent::DeploymentStopOnAlarm,
+            "DEPLOYMENT_STOP_ON_REQUEST" => AutoRollbackEvent::DeploymentStopOnRequest,
+            _ => AutoRollbackEvent::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AutoRollbackEvent {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AutoRollbackEvent, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AutoRollbackEvent {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AutoRollbackEvent {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for AutoRollbackEvent {
+    fn deserialize<D>(deserializer: D) -> Result<AutoRollbackEvent, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about an Auto Scaling group.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -316,13 +517,219 @@ pub struct BlueInstanceTerminationOption {
     /// <p><p>The action to take on instances in the original environment after a successful blue/green deployment.</p> <ul> <li> <p>TERMINATE: Instances are terminated after a specified wait time.</p> </li> <li> <p>KEEP_ALIVE: Instances are left running after they are deregistered from the load balancer and removed from the deployment group.</p> </li> </ul></p>
     #[serde(rename = "action")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub action: Option<String>,
+    pub action: Option<InstanceAction>,
     /// <p>For an Amazon EC2 deployment, the number of minutes to wait after a successful blue/green deployment before terminating instances from the original environment.</p> <p> For an Amazon ECS deployment, the number of minutes before deleting the original (blue) task set. During an Amazon ECS deployment, CodeDeploy shifts traffic from the original (blue) task set to a replacement (green) task set. </p> <p> The maximum setting is 2880 minutes (2 days). </p>
     #[serde(rename = "terminationWaitTimeInMinutes")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub termination_wait_time_in_minutes: Option<i64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BundleType {
+    /// `tar`
+    Tar,
+    /// `tgz`
+    Tgz,
+    /// `zip`
+    Zip,
+    /// `YAML`
+    Yaml,
+    /// `JSON`
+    Json,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl BundleType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BundleType::Tar => "tar",
+            BundleType::Tgz => "tgz",
+            BundleType::Zip => "zip",
+            BundleType::Yaml => "YAML",
+            BundleType::Json => "JSON",
+            BundleType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for BundleType {
+    fn default() -> BundleType {
+        BundleType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for BundleType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for BundleType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for BundleType {
+    fn from(value: &'a str) -> BundleType {
+        match value {
+            "tar" => BundleType::Tar,
+            "tgz" => BundleType::Tgz,
+            "zip" => BundleType::Zip,
+            "YAML" => BundleType::Yaml,
+            "JSON" => BundleType::Json,
+            _ => BundleType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BundleType {
+    fn from(value: String) -> BundleType {
+        match value.as_str() {
+            "tar" => BundleType::Tar,
+            "tgz" => BundleType::Tgz,
+            "zip" => BundleType::Zip,
+            "YAML" => BundleType::Yaml,
+            "JSON" => BundleType::Json,
+            _ => BundleType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for BundleType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<BundleType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for BundleType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for BundleType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BundleType {
+    fn deserialize<D>(deserializer: D) -> Result<BundleType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ComputePlatform {
+    /// `Server`
+    Server,
+    /// `Lambda`
+    Lambda,
+    /// `ECS`
+    Ecs,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ComputePlatform {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ComputePlatform::Server => "Server",
+            ComputePlatform::Lambda => "Lambda",
+            ComputePlatform::Ecs => "ECS",
+            ComputePlatform::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ComputePlatform {
+    fn default() -> ComputePlatform {
+        ComputePlatform::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ComputePlatform {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ComputePlatform {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ComputePlatform {
+    fn from(value: &'a str) -> ComputePlatform {
+        match value {
+            "Server" => ComputePlatform::Server,
+            "Lambda" => ComputePlatform::Lambda,
+            "ECS" => ComputePlatform::Ecs,
+            _ => ComputePlatform::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ComputePlatform {
+    fn from(value: String) -> ComputePlatform {
+        match value.as_str() {
+            "Server" => ComputePlatform::Server,
+            "Lambda" => ComputePlatform::Lambda,
+            "ECS" => ComputePlatform::Ecs,
+            _ => ComputePlatform::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ComputePlatform {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ComputePlatform, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ComputePlatform {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ComputePlatform {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ComputePlatform {
+    fn deserialize<D>(deserializer: D) -> Result<ComputePlatform, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ContinueDeploymentInput {
@@ -333,7 +740,7 @@ pub struct ContinueDeploymentInput {
     /// <p> The status of the deployment's waiting period. READY_WAIT indicates the deployment is ready to start shifting traffic. TERMINATION_WAIT indicates the traffic is shifted, but the original target is not terminated. </p>
     #[serde(rename = "deploymentWaitType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deployment_wait_type: Option<String>,
+    pub deployment_wait_type: Option<DeploymentWaitType>,
 }
 
 /// <p>Represents the input of a CreateApplication operation.</p>
@@ -346,7 +753,7 @@ pub struct CreateApplicationInput {
     /// <p> The destination platform type for the deployment (<code>Lambda</code>, <code>Server</code>, or <code>ECS</code>).</p>
     #[serde(rename = "computePlatform")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compute_platform: Option<String>,
+    pub compute_platform: Option<ComputePlatform>,
     /// <p> The metadata that you apply to CodeDeploy applications to help you organize and categorize them. Each tag consists of a key and an optional value, both of which you define. </p>
     #[serde(rename = "tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -370,7 +777,7 @@ pub struct CreateDeploymentConfigInput {
     /// <p>The destination platform type for the deployment (<code>Lambda</code>, <code>Server</code>, or <code>ECS</code>).</p>
     #[serde(rename = "computePlatform")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compute_platform: Option<String>,
+    pub compute_platform: Option<ComputePlatform>,
     /// <p>The name of the deployment configuration to create.</p>
     #[serde(rename = "deploymentConfigName")]
     pub deployment_config_name: String,
@@ -501,7 +908,7 @@ pub struct CreateDeploymentInput {
     /// <p><p>Information about how AWS CodeDeploy handles files that already exist in a deployment target location but weren&#39;t part of the previous successful deployment.</p> <p>The fileExistsBehavior parameter takes any of the following values:</p> <ul> <li> <p>DISALLOW: The deployment fails. This is also the default behavior if no option is specified.</p> </li> <li> <p>OVERWRITE: The version of the file from the application revision currently being deployed replaces the version already on the instance.</p> </li> <li> <p>RETAIN: The version of the file already on the instance is kept and used as part of the new deployment.</p> </li> </ul></p>
     #[serde(rename = "fileExistsBehavior")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub file_exists_behavior: Option<String>,
+    pub file_exists_behavior: Option<FileExistsBehavior>,
     /// <p> If true, then if an ApplicationStop, BeforeBlockTraffic, or AfterBlockTraffic deployment lifecycle event to an instance fails, then the deployment continues to the next deployment lifecycle event. For example, if ApplicationStop fails, the deployment continues with DownloadBundle. If BeforeBlockTraffic fails, the deployment continues with BlockTraffic. If AfterBlockTraffic fails, the deployment continues with ApplicationStop. </p> <p> If false or not specified, then if a lifecycle event fails during a deployment to an instance, that deployment fails. If deployment to that instance is part of an overall deployment and the number of healthy hosts is not less than the minimum number of healthy hosts, then a deployment to the next instance is attempted. </p> <p> During a deployment, the AWS CodeDeploy agent runs the scripts specified for ApplicationStop, BeforeBlockTraffic, and AfterBlockTraffic in the AppSpec file from the previous successful deployment. (All other scripts are run from the AppSpec file in the current deployment.) If one of these scripts contains an error and does not run successfully, the deployment can fail. </p> <p> If the cause of the failure is a script from the last successful deployment that will never run successfully, create a new deployment and use <code>ignoreApplicationStopFailures</code> to specify that the ApplicationStop, BeforeBlockTraffic, and AfterBlockTraffic failures should be ignored. </p>
     #[serde(rename = "ignoreApplicationStopFailures")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -597,11 +1004,16 @@ pub struct DeploymentConfigInfo {
     /// <p>The destination platform type for the deployment (<code>Lambda</code>, <code>Server</code>, or <code>ECS</code>).</p>
     #[serde(rename = "computePlatform")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compute_platform: Option<String>,
+    pub compute_platform: Option<ComputePlatform>,
     /// <p>The time at which the deployment configuration was created.</p>
     #[serde(rename = "createTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub create_time: Option<f64>,
+    pub create_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The deployment configuration ID.</p>
     #[serde(rename = "deploymentConfigId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -620,6 +1032,129 @@ pub struct DeploymentConfigInfo {
     pub traffic_routing_config: Option<TrafficRoutingConfig>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DeploymentCreator {
+    /// `user`
+    User,
+    /// `autoscaling`
+    Autoscaling,
+    /// `codeDeployRollback`
+    CodeDeployRollback,
+    /// `CodeDeploy`
+    CodeDeploy,
+    /// `CodeDeployAutoUpdate`
+    CodeDeployAutoUpdate,
+    /// `CloudFormation`
+    CloudFormation,
+    /// `CloudFormationRollback`
+    CloudFormationRollback,
+    /// `autoscalingTermination`
+    AutoscalingTermination,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DeploymentCreator {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeploymentCreator::User => "user",
+            DeploymentCreator::Autoscaling => "autoscaling",
+            DeploymentCreator::CodeDeployRollback => "codeDeployRollback",
+            DeploymentCreator::CodeDeploy => "CodeDeploy",
+            DeploymentCreator::CodeDeployAutoUpdate => "CodeDeployAutoUpdate",
+            DeploymentCreator::CloudFormation => "CloudFormation",
+            DeploymentCreator::CloudFormationRollback => "CloudFormationRollback",
+            DeploymentCreator::AutoscalingTermination => "autoscalingTermination",
+            DeploymentCreator::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DeploymentCreator {
+    fn default() -> DeploymentCreator {
+        DeploymentCreator::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DeploymentCreator {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DeploymentCreator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DeploymentCreator {
+    fn from(value: &'a str) -> DeploymentCreator {
+        match value {
+            "user" => DeploymentCreator::User,
+            "autoscaling" => DeploymentCreator::Autoscaling,
+            "codeDeployRollback" => DeploymentCreator::CodeDeployRollback,
+            "CodeDeploy" => DeploymentCreator::CodeDeploy,
+            "CodeDeployAutoUpdate" => DeploymentCreator::CodeDeployAutoUpdate,
+            "CloudFormation" => DeploymentCreator::CloudFormation,
+            "CloudFormationRollback" => DeploymentCreator::CloudFormationRollback,
+            "autoscalingTermination" => DeploymentCreator::AutoscalingTermination,
+            _ => DeploymentCreator::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeploymentCreator {
+    fn from(value: String) -> DeploymentCreator {
+        match value.as_str() {
+            "user" => DeploymentCreator::User,
+            "autoscaling" => DeploymentCreator::Autoscaling,
+            "codeDeployRollback" => DeploymentCreator::CodeDeployRollback,
+            "CodeDeploy" => DeploymentCreator::CodeDeploy,
+            "CodeDeployAutoUpdate" => DeploymentCreator::CodeDeployAutoUpdate,
+            "CloudFormation" => DeploymentCreator::CloudFormation,
+            "CloudFormationRollback" => DeploymentCreator::CloudFormationRollback,
+            "autoscalingTermination" => DeploymentCreator::AutoscalingTermination,
+            _ => DeploymentCreator::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DeploymentCreator {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DeploymentCreator, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DeploymentCreator {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DeploymentCreator {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeploymentCreator {
+    fn deserialize<D>(deserializer: D) -> Result<DeploymentCreator, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about a deployment group.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -647,7 +1182,7 @@ pub struct DeploymentGroupInfo {
     /// <p>The destination platform type for the deployment (<code>Lambda</code>, <code>Server</code>, or <code>ECS</code>).</p>
     #[serde(rename = "computePlatform")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compute_platform: Option<String>,
+    pub compute_platform: Option<ComputePlatform>,
     /// <p>The deployment configuration name.</p>
     #[serde(rename = "deploymentConfigName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -732,20 +1267,30 @@ pub struct DeploymentInfo {
     pub blue_green_deployment_configuration: Option<BlueGreenDeploymentConfiguration>,
     /// <p>A timestamp that indicates when the deployment was complete.</p>
     #[serde(rename = "completeTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub complete_time: Option<f64>,
+    pub complete_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The destination platform type for the deployment (<code>Lambda</code>, <code>Server</code>, or <code>ECS</code>).</p>
     #[serde(rename = "computePlatform")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compute_platform: Option<String>,
+    pub compute_platform: Option<ComputePlatform>,
     /// <p>A timestamp that indicates when the deployment was created.</p>
     #[serde(rename = "createTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub create_time: Option<f64>,
+    pub create_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>The means by which the deployment was created:</p> <ul> <li> <p>user: A user created the deployment.</p> </li> <li> <p>autoscaling: Amazon EC2 Auto Scaling created the deployment.</p> </li> <li> <p>codeDeployRollback: A rollback process created the deployment.</p> </li> </ul></p>
     #[serde(rename = "creator")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creator: Option<String>,
+    pub creator: Option<DeploymentCreator>,
     /// <p> The deployment configuration name. </p>
     #[serde(rename = "deploymentConfigName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -781,7 +1326,7 @@ pub struct DeploymentInfo {
     /// <p><p>Information about how AWS CodeDeploy handles files that already exist in a deployment target location but weren&#39;t part of the previous successful deployment.</p> <ul> <li> <p>DISALLOW: The deployment fails. This is also the default behavior if no option is specified.</p> </li> <li> <p>OVERWRITE: The version of the file from the application revision currently being deployed replaces the version already on the instance.</p> </li> <li> <p>RETAIN: The version of the file already on the instance is kept and used as part of the new deployment.</p> </li> </ul></p>
     #[serde(rename = "fileExistsBehavior")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub file_exists_behavior: Option<String>,
+    pub file_exists_behavior: Option<FileExistsBehavior>,
     /// <p> If true, then if an <code>ApplicationStop</code>, <code>BeforeBlockTraffic</code>, or <code>AfterBlockTraffic</code> deployment lifecycle event to an instance fails, then the deployment continues to the next deployment lifecycle event. For example, if <code>ApplicationStop</code> fails, the deployment continues with DownloadBundle. If <code>BeforeBlockTraffic</code> fails, the deployment continues with <code>BlockTraffic</code>. If <code>AfterBlockTraffic</code> fails, the deployment continues with <code>ApplicationStop</code>. </p> <p> If false or not specified, then if a lifecycle event fails during a deployment to an instance, that deployment fails. If deployment to that instance is part of an overall deployment and the number of healthy hosts is not less than the minimum number of healthy hosts, then a deployment to the next instance is attempted. </p> <p> During a deployment, the AWS CodeDeploy agent runs the scripts specified for <code>ApplicationStop</code>, <code>BeforeBlockTraffic</code>, and <code>AfterBlockTraffic</code> in the AppSpec file from the previous successful deployment. (All other scripts are run from the AppSpec file in the current deployment.) If one of these scripts contains an error and does not run successfully, the deployment can fail. </p> <p> If the cause of the failure is a script from the last successful deployment that will never run successfully, create a new deployment and use <code>ignoreApplicationStopFailures</code> to specify that the <code>ApplicationStop</code>, <code>BeforeBlockTraffic</code>, and <code>AfterBlockTraffic</code> failures should be ignored. </p>
     #[serde(rename = "ignoreApplicationStopFailures")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -808,12 +1353,17 @@ pub struct DeploymentInfo {
     pub rollback_info: Option<RollbackInfo>,
     /// <p>A timestamp that indicates when the deployment was deployed to the deployment group.</p> <p>In some cases, the reported value of the start time might be later than the complete time. This is due to differences in the clock settings of backend servers that participate in the deployment process.</p>
     #[serde(rename = "startTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The current state of the deployment as a whole.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<DeploymentStatus>,
     /// <p>Information about the instances that belong to the replacement environment in a blue/green deployment.</p>
     #[serde(rename = "targetInstances")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -824,6 +1374,99 @@ pub struct DeploymentInfo {
     pub update_outdated_instances_only: Option<bool>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DeploymentOption {
+    /// `WITH_TRAFFIC_CONTROL`
+    WithTrafficControl,
+    /// `WITHOUT_TRAFFIC_CONTROL`
+    WithoutTrafficControl,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DeploymentOption {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeploymentOption::WithTrafficControl => "WITH_TRAFFIC_CONTROL",
+            DeploymentOption::WithoutTrafficControl => "WITHOUT_TRAFFIC_CONTROL",
+            DeploymentOption::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DeploymentOption {
+    fn default() -> DeploymentOption {
+        DeploymentOption::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DeploymentOption {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DeploymentOption {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DeploymentOption {
+    fn from(value: &'a str) -> DeploymentOption {
+        match value {
+            "WITH_TRAFFIC_CONTROL" => DeploymentOption::WithTrafficControl,
+            "WITHOUT_TRAFFIC_CONTROL" => DeploymentOption::WithoutTrafficControl,
+            _ => DeploymentOption::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeploymentOption {
+    fn from(value: String) -> DeploymentOption {
+        match value.as_str() {
+            "WITH_TRAFFIC_CONTROL" => DeploymentOption::WithTrafficControl,
+            "WITHOUT_TRAFFIC_CONTROL" => DeploymentOption::WithoutTrafficControl,
+            _ => DeploymentOption::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DeploymentOption {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DeploymentOption, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DeploymentOption {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DeploymentOption {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeploymentOption {
+    fn deserialize<D>(deserializer: D) -> Result<DeploymentOption, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about the deployment status of the instances in the deployment.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -854,30 +1497,246 @@ pub struct DeploymentOverview {
     pub succeeded: Option<i64>,
 }
 
-/// <p>Information about how traffic is rerouted to instances in a replacement environment in a blue/green deployment.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct DeploymentReadyOption {
-    /// <p><p>Information about when to reroute traffic from an original environment to a replacement environment in a blue/green deployment.</p> <ul> <li> <p>CONTINUE<em>DEPLOYMENT: Register new instances with the load balancer immediately after the new application revision is installed on the instances in the replacement environment.</p> </li> <li> <p>STOP</em>DEPLOYMENT: Do not register new instances with a load balancer unless traffic rerouting is started using <a>ContinueDeployment</a>. If traffic rerouting is not started before the end of the specified wait period, the deployment status is changed to Stopped.</p> </li> </ul></p>
-    #[serde(rename = "actionOnTimeout")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub action_on_timeout: Option<String>,
-    /// <p>The number of minutes to wait before the status of a blue/green deployment is changed to Stopped if rerouting is not started manually. Applies only to the STOP_DEPLOYMENT option for actionOnTimeout</p>
-    #[serde(rename = "waitTimeInMinutes")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub wait_time_in_minutes: Option<i64>,
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DeploymentReadyAction {
+    /// `CONTINUE_DEPLOYMENT`
+    ContinueDeployment,
+    /// `STOP_DEPLOYMENT`
+    StopDeployment,
+    /// A value not known to this version of the crate
+    Unknown(String),
 }
 
-/// <p>Information about the type of deployment, either in-place or blue/green, you want to run and whether to route deployment traffic behind a load balancer.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct DeploymentStyle {
-    /// <p>Indicates whether to route deployment traffic behind a load balancer.</p>
-    #[serde(rename = "deploymentOption")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub deployment_option: Option<String>,
-    /// <p>Indicates whether to run an in-place deployment or a blue/green deployment.</p>
+impl DeploymentReadyAction {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeploymentReadyAction::ContinueDeployment => "CONTINUE_DEPLOYMENT",
+            DeploymentReadyAction::StopDeployment => "STOP_DEPLOYMENT",
+            DeploymentReadyAction::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DeploymentReadyAction {
+    fn default() -> DeploymentReadyAction {
+        DeploymentReadyAction::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DeploymentReadyAction {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DeploymentReadyAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DeploymentReadyAction {
+    fn from(value: &'a str) -> DeploymentReadyAction {
+        match value {
+            "CONTINUE_DEPLOYMENT" => DeploymentReadyAction::ContinueDeployment,
+            "STOP_DEPLOYMENT" => DeploymentReadyAction::StopDeployment,
+            _ => DeploymentReadyAction::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeploymentReadyAction {
+    fn from(value: String) -> DeploymentReadyAction {
+        match value.as_str() {
+            "CONTINUE_DEPLOYMENT" => DeploymentReadyAction::ContinueDeployment,
+            "STOP_DEPLOYMENT" => DeploymentReadyAction::StopDeployment,
+            _ => DeploymentReadyAction::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DeploymentReadyAction {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DeploymentReadyAction, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DeploymentReadyAction {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DeploymentReadyAction {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeploymentReadyAction {
+    fn deserialize<D>(deserializer: D) -> Result<DeploymentReadyAction, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Information about how traffic is rerouted to instances in a replacement environment in a blue/green deployment.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeploymentReadyOption {
+    /// <p><p>Information about when to reroute traffic from an original environment to a replacement environment in a blue/green deployment.</p> <ul> <li> <p>CONTINUE<em>DEPLOYMENT: Register new instances with the load balancer immediately after the new application revision is installed on the instances in the replacement environment.</p> </li> <li> <p>STOP</em>DEPLOYMENT: Do not register new instances with a load balancer unless traffic rerouting is started using <a>ContinueDeployment</a>. If traffic rerouting is not started before the end of the specified wait period, the deployment status is changed to Stopped.</p> </li> </ul></p>
+    #[serde(rename = "actionOnTimeout")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_on_timeout: Option<DeploymentReadyAction>,
+    /// <p>The number of minutes to wait before the status of a blue/green deployment is changed to Stopped if rerouting is not started manually. Applies only to the STOP_DEPLOYMENT option for actionOnTimeout</p>
+    #[serde(rename = "waitTimeInMinutes")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wait_time_in_minutes: Option<i64>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DeploymentStatus {
+    /// `Created`
+    Created,
+    /// `Queued`
+    Queued,
+    /// `InProgress`
+    InProgress,
+    /// `Baking`
+    Baking,
+    /// `Succeeded`
+    Succeeded,
+    /// `Failed`
+    Failed,
+    /// `Stopped`
+    Stopped,
+    /// `Ready`
+    Ready,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DeploymentStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeploymentStatus::Created => "Created",
+            DeploymentStatus::Queued => "Queued",
+            DeploymentStatus::InProgress => "InProgress",
+            DeploymentStatus::Baking => "Baking",
+            DeploymentStatus::Succeeded => "Succeeded",
+            DeploymentStatus::Failed => "Failed",
+            DeploymentStatus::Stopped => "Stopped",
+            DeploymentStatus::Ready => "Ready",
+            DeploymentStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DeploymentStatus {
+    fn default() -> DeploymentStatus {
+        DeploymentStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DeploymentStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DeploymentStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DeploymentStatus {
+    fn from(value: &'a str) -> DeploymentStatus {
+        match value {
+            "Created" => DeploymentStatus::Created,
+            "Queued" => DeploymentStatus::Queued,
+            "InProgress" => DeploymentStatus::InProgress,
+            "Baking" => DeploymentStatus::Baking,
+            "Succeeded" => DeploymentStatus::Succeeded,
+            "Failed" => DeploymentStatus::Failed,
+            "Stopped" => DeploymentStatus::Stopped,
+            "Ready" => DeploymentStatus::Ready,
+            _ => DeploymentStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeploymentStatus {
+    fn from(value: String) -> DeploymentStatus {
+        match value.as_str() {
+            "Created" => DeploymentStatus::Created,
+            "Queued" => DeploymentStatus::Queued,
+            "InProgress" => DeploymentStatus::InProgress,
+            "Baking" => DeploymentStatus::Baking,
+            "Succeeded" => DeploymentStatus::Succeeded,
+            "Failed" => DeploymentStatus::Failed,
+            "Stopped" => DeploymentStatus::Stopped,
+            "Ready" => DeploymentStatus::Ready,
+            _ => DeploymentStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DeploymentStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DeploymentStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DeploymentStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DeploymentStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeploymentStatus {
+    fn deserialize<D>(deserializer: D) -> Result<DeploymentStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Information about the type of deployment, either in-place or blue/green, you want to run and whether to route deployment traffic behind a load balancer.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeploymentStyle {
+    /// <p>Indicates whether to route deployment traffic behind a load balancer.</p>
+    #[serde(rename = "deploymentOption")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deployment_option: Option<DeploymentOption>,
+    /// <p>Indicates whether to run an in-place deployment or a blue/green deployment.</p>
     #[serde(rename = "deploymentType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deployment_type: Option<String>,
+    pub deployment_type: Option<DeploymentType>,
 }
 
 /// <p> Information about the deployment target. </p>
@@ -887,7 +1746,7 @@ pub struct DeploymentTarget {
     /// <p> The deployment type that is specific to the deployment's compute platform. </p>
     #[serde(rename = "deploymentTargetType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deployment_target_type: Option<String>,
+    pub deployment_target_type: Option<DeploymentTargetType>,
     /// <p> Information about the target for a deployment that uses the Amazon ECS compute platform. </p>
     #[serde(rename = "ecsTarget")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -902,6 +1761,295 @@ pub struct DeploymentTarget {
     pub lambda_target: Option<LambdaTarget>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DeploymentTargetType {
+    /// `InstanceTarget`
+    InstanceTarget,
+    /// `LambdaTarget`
+    LambdaTarget,
+    /// `ECSTarget`
+    ECSTarget,
+    /// `CloudFormationTarget`
+    CloudFormationTarget,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DeploymentTargetType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeploymentTargetType::InstanceTarget => "InstanceTarget",
+            DeploymentTargetType::LambdaTarget => "LambdaTarget",
+            DeploymentTargetType::ECSTarget => "ECSTarget",
+            DeploymentTargetType::CloudFormationTarget => "CloudFormationTarget",
+            DeploymentTargetType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DeploymentTargetType {
+    fn default() -> DeploymentTargetType {
+        DeploymentTargetType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DeploymentTargetType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DeploymentTargetType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DeploymentTargetType {
+    fn from(value: &'a str) -> DeploymentTargetType {
+        match value {
+            "InstanceTarget" => DeploymentTargetType::InstanceTarget,
+            "LambdaTarget" => DeploymentTargetType::LambdaTarget,
+            "ECSTarget" => DeploymentTargetType::ECSTarget,
+            "CloudFormationTarget" => DeploymentTargetType::CloudFormationTarget,
+            _ => DeploymentTargetType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeploymentTargetType {
+    fn from(value: String) -> DeploymentTargetType {
+        match value.as_str() {
+            "InstanceTarget" => DeploymentTargetType::InstanceTarget,
+            "LambdaTarget" => DeploymentTargetType::LambdaTarget,
+            "ECSTarget" => DeploymentTargetType::ECSTarget,
+            "CloudFormationTarget" => DeploymentTargetType::CloudFormationTarget,
+            _ => DeploymentTargetType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DeploymentTargetType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DeploymentTargetType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DeploymentTargetType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DeploymentTargetType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeploymentTargetType {
+    fn deserialize<D>(deserializer: D) -> Result<DeploymentTargetType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DeploymentType {
+    /// `IN_PLACE`
+    InPlace,
+    /// `BLUE_GREEN`
+    BlueGreen,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DeploymentType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeploymentType::InPlace => "IN_PLACE",
+            DeploymentType::BlueGreen => "BLUE_GREEN",
+            DeploymentType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DeploymentType {
+    fn default() -> DeploymentType {
+        DeploymentType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DeploymentType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DeploymentType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DeploymentType {
+    fn from(value: &'a str) -> DeploymentType {
+        match value {
+            "IN_PLACE" => DeploymentType::InPlace,
+            "BLUE_GREEN" => DeploymentType::BlueGreen,
+            _ => DeploymentType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeploymentType {
+    fn from(value: String) -> DeploymentType {
+        match value.as_str() {
+            "IN_PLACE" => DeploymentType::InPlace,
+            "BLUE_GREEN" => DeploymentType::BlueGreen,
+            _ => DeploymentType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DeploymentType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DeploymentType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DeploymentType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DeploymentType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeploymentType {
+    fn deserialize<D>(deserializer: D) -> Result<DeploymentType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DeploymentWaitType {
+    /// `READY_WAIT`
+    ReadyWait,
+    /// `TERMINATION_WAIT`
+    TerminationWait,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DeploymentWaitType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeploymentWaitType::ReadyWait => "READY_WAIT",
+            DeploymentWaitType::TerminationWait => "TERMINATION_WAIT",
+            DeploymentWaitType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DeploymentWaitType {
+    fn default() -> DeploymentWaitType {
+        DeploymentWaitType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DeploymentWaitType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DeploymentWaitType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DeploymentWaitType {
+    fn from(value: &'a str) -> DeploymentWaitType {
+        match value {
+            "READY_WAIT" => DeploymentWaitType::ReadyWait,
+            "TERMINATION_WAIT" => DeploymentWaitType::TerminationWait,
+            _ => DeploymentWaitType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeploymentWaitType {
+    fn from(value: String) -> DeploymentWaitType {
+        match value.as_str() {
+            "READY_WAIT" => DeploymentWaitType::ReadyWait,
+            "TERMINATION_WAIT" => DeploymentWaitType::TerminationWait,
+            _ => DeploymentWaitType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DeploymentWaitType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DeploymentWaitType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DeploymentWaitType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DeploymentWaitType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeploymentWaitType {
+    fn deserialize<D>(deserializer: D) -> Result<DeploymentWaitType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents the input of a DeregisterOnPremisesInstance operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -918,7 +2066,7 @@ pub struct Diagnostics {
     /// <p><p>The associated error code:</p> <ul> <li> <p>Success: The specified script ran.</p> </li> <li> <p>ScriptMissing: The specified script was not found in the specified location.</p> </li> <li> <p>ScriptNotExecutable: The specified script is not a recognized executable file type.</p> </li> <li> <p>ScriptTimedOut: The specified script did not finish running in the specified time period.</p> </li> <li> <p>ScriptFailed: The specified script failed to run as expected.</p> </li> <li> <p>UnknownError: The specified script did not run for an unknown reason.</p> </li> </ul></p>
     #[serde(rename = "errorCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub error_code: Option<String>,
+    pub error_code: Option<LifecycleErrorCode>,
     /// <p>The last portion of the diagnostic log.</p> <p>If available, AWS CodeDeploy returns up to the last 4 KB of the diagnostic log.</p>
     #[serde(rename = "logTail")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -943,13 +2091,111 @@ pub struct EC2TagFilter {
     /// <p><p>The tag filter type:</p> <ul> <li> <p>KEY<em>ONLY: Key only.</p> </li> <li> <p>VALUE</em>ONLY: Value only.</p> </li> <li> <p>KEY<em>AND</em>VALUE: Key and value.</p> </li> </ul></p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<EC2TagFilterType>,
     /// <p>The tag filter value.</p>
     #[serde(rename = "Value")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EC2TagFilterType {
+    /// `KEY_ONLY`
+    KeyOnly,
+    /// `VALUE_ONLY`
+    ValueOnly,
+    /// `KEY_AND_VALUE`
+    KeyAndValue,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EC2TagFilterType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EC2TagFilterType::KeyOnly => "KEY_ONLY",
+            EC2TagFilterType::ValueOnly => "VALUE_ONLY",
+            EC2TagFilterType::KeyAndValue => "KEY_AND_VALUE",
+            EC2TagFilterType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EC2TagFilterType {
+    fn default() -> EC2TagFilterType {
+        EC2TagFilterType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EC2TagFilterType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EC2TagFilterType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EC2TagFilterType {
+    fn from(value: &'a str) -> EC2TagFilterType {
+        match value {
+            "KEY_ONLY" => EC2TagFilterType::KeyOnly,
+            "VALUE_ONLY" => EC2TagFilterType::ValueOnly,
+            "KEY_AND_VALUE" => EC2TagFilterType::KeyAndValue,
+            _ => EC2TagFilterType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EC2TagFilterType {
+    fn from(value: String) -> EC2TagFilterType {
+        match value.as_str() {
+            "KEY_ONLY" => EC2TagFilterType::KeyOnly,
+            "VALUE_ONLY" => EC2TagFilterType::ValueOnly,
+            "KEY_AND_VALUE" => EC2TagFilterType::KeyAndValue,
+            _ => EC2TagFilterType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EC2TagFilterType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EC2TagFilterType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EC2TagFilterType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EC2TagFilterType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EC2TagFilterType {
+    fn deserialize<D>(deserializer: D) -> Result<EC2TagFilterType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about groups of EC2 instance tags.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct EC2TagSet {
@@ -982,8 +2228,13 @@ pub struct ECSTarget {
     pub deployment_id: Option<String>,
     /// <p> The date and time when the target Amazon ECS application was updated by a deployment. </p>
     #[serde(rename = "lastUpdatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_at: Option<f64>,
+    pub last_updated_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p> The lifecycle events of the deployment to this target Amazon ECS application. </p>
     #[serde(rename = "lifecycleEvents")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -991,7 +2242,7 @@ pub struct ECSTarget {
     /// <p> The status an Amazon ECS deployment's target ECS application. </p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<TargetStatus>,
     /// <p> The ARN of the target. </p>
     #[serde(rename = "targetArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1037,7 +2288,7 @@ pub struct ECSTaskSet {
     /// <p> A label that identifies whether the ECS task set is an original target (<code>BLUE</code>) or a replacement target (<code>GREEN</code>). </p>
     #[serde(rename = "taskSetLabel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub task_set_label: Option<String>,
+    pub task_set_label: Option<TargetLabel>,
     /// <p> The percentage of traffic served by this task set. </p>
     #[serde(rename = "trafficWeight")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1053,6 +2304,265 @@ pub struct ELBInfo {
     pub name: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorCode {
+    /// `AGENT_ISSUE`
+    AgentIssue,
+    /// `ALARM_ACTIVE`
+    AlarmActive,
+    /// `APPLICATION_MISSING`
+    ApplicationMissing,
+    /// `AUTOSCALING_VALIDATION_ERROR`
+    AutoscalingValidationError,
+    /// `AUTO_SCALING_CONFIGURATION`
+    AutoScalingConfiguration,
+    /// `AUTO_SCALING_IAM_ROLE_PERMISSIONS`
+    AutoScalingIamRolePermissions,
+    /// `CODEDEPLOY_RESOURCE_CANNOT_BE_FOUND`
+    CodedeployResourceCannotBeFound,
+    /// `CUSTOMER_APPLICATION_UNHEALTHY`
+    CustomerApplicationUnhealthy,
+    /// `DEPLOYMENT_GROUP_MISSING`
+    DeploymentGroupMissing,
+    /// `ECS_UPDATE_ERROR`
+    EcsUpdateError,
+    /// `ELASTIC_LOAD_BALANCING_INVALID`
+    ElasticLoadBalancingInvalid,
+    /// `ELB_INVALID_INSTANCE`
+    ElbInvalidInstance,
+    /// `HEALTH_CONSTRAINTS`
+    HealthConstraints,
+    /// `HEALTH_CONSTRAINTS_INVALID`
+    HealthConstraintsInvalid,
+    /// `HOOK_EXECUTION_FAILURE`
+    HookExecutionFailure,
+    /// `IAM_ROLE_MISSING`
+    IamRoleMissing,
+    /// `IAM_ROLE_PERMISSIONS`
+    IamRolePermissions,
+    /// `INTERNAL_ERROR`
+    InternalError,
+    /// `INVALID_ECS_SERVICE`
+    InvalidEcsService,
+    /// `INVALID_LAMBDA_CONFIGURATION`
+    InvalidLambdaConfiguration,
+    /// `INVALID_LAMBDA_FUNCTION`
+    InvalidLambdaFunction,
+    /// `INVALID_REVISION`
+    InvalidRevision,
+    /// `MANUAL_STOP`
+    ManualStop,
+    /// `MISSING_BLUE_GREEN_DEPLOYMENT_CONFIGURATION`
+    MissingBlueGreenDeploymentConfiguration,
+    /// `MISSING_ELB_INFORMATION`
+    MissingElbInformation,
+    /// `MISSING_GITHUB_TOKEN`
+    MissingGithubToken,
+    /// `NO_EC2_SUBSCRIPTION`
+    NoEc2Subscription,
+    /// `NO_INSTANCES`
+    NoInstances,
+    /// `OVER_MAX_INSTANCES`
+    OverMaxInstances,
+    /// `RESOURCE_LIMIT_EXCEEDED`
+    ResourceLimitExceeded,
+    /// `REVISION_MISSING`
+    RevisionMissing,
+    /// `THROTTLED`
+    Throttled,
+    /// `TIMEOUT`
+    Timeout,
+    /// `CLOUDFORMATION_STACK_FAILURE`
+    CloudformationStackFailure,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ErrorCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ErrorCode::AgentIssue => "AGENT_ISSUE",
+            ErrorCode::AlarmActive => "ALARM_ACTIVE",
+            ErrorCode::ApplicationMissing => "APPLICATION_MISSING",
+            ErrorCode::AutoscalingValidationError => "AUTOSCALING_VALIDATION_ERROR",
+            ErrorCode::AutoScalingConfiguration => "AUTO_SCALING_CONFIGURATION",
+            ErrorCode::AutoScalingIamRolePermissions => "AUTO_SCALING_IAM_ROLE_PERMISSIONS",
+            ErrorCode::CodedeployResourceCannotBeFound => "CODEDEPLOY_RESOURCE_CANNOT_BE_FOUND",
+            ErrorCode::CustomerApplicationUnhealthy => "CUSTOMER_APPLICATION_UNHEALTHY",
+            ErrorCode::DeploymentGroupMissing => "DEPLOYMENT_GROUP_MISSING",
+            ErrorCode::EcsUpdateError => "ECS_UPDATE_ERROR",
+            ErrorCode::ElasticLoadBalancingInvalid => "ELASTIC_LOAD_BALANCING_INVALID",
+            ErrorCode::ElbInvalidInstance => "ELB_INVALID_INSTANCE",
+            ErrorCode::HealthConstraints => "HEALTH_CONSTRAINTS",
+            ErrorCode::HealthConstraintsInvalid => "HEALTH_CONSTRAINTS_INVALID",
+            ErrorCode::HookExecutionFailure => "HOOK_EXECUTION_FAILURE",
+            ErrorCode::IamRoleMissing => "IAM_ROLE_MISSING",
+            ErrorCode::IamRolePermissions => "IAM_ROLE_PERMISSIONS",
+            ErrorCode::InternalError => "INTERNAL_ERROR",
+            ErrorCode::InvalidEcsService => "INVALID_ECS_SERVICE",
+            ErrorCode::InvalidLambdaConfiguration => "INVALID_LAMBDA_CONFIGURATION",
+            ErrorCode::InvalidLambdaFunction => "INVALID_LAMBDA_FUNCTION",
+            ErrorCode::InvalidRevision => "INVALID_REVISION",
+            ErrorCode::ManualStop => "MANUAL_STOP",
+            ErrorCode::MissingBlueGreenDeploymentConfiguration => {
+                "MISSING_BLUE_GREEN_DEPLOYMENT_CONFIGURATION"
+            }
+            ErrorCode::MissingElbInformation => "MISSING_ELB_INFORMATION",
+            ErrorCode::MissingGithubToken => "MISSING_GITHUB_TOKEN",
+            ErrorCode::NoEc2Subscription => "NO_EC2_SUBSCRIPTION",
+            ErrorCode::NoInstances => "NO_INSTANCES",
+            ErrorCode::OverMaxInstances => "OVER_MAX_INSTANCES",
+            ErrorCode::ResourceLimitExceeded => "RESOURCE_LIMIT_EXCEEDED",
+            ErrorCode::RevisionMissing => "REVISION_MISSING",
+            ErrorCode::Throttled => "THROTTLED",
+            ErrorCode::Timeout => "TIMEOUT",
+            ErrorCode::CloudformationStackFailure => "CLOUDFORMATION_STACK_FAILURE",
+            ErrorCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ErrorCode {
+    fn default() -> ErrorCode {
+        ErrorCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ErrorCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ErrorCode {
+    fn from(value: &'a str) -> ErrorCode {
+        match value {
+            "AGENT_ISSUE" => ErrorCode::AgentIssue,
+            "ALARM_ACTIVE" => ErrorCode::AlarmActive,
+            "APPLICATION_MISSING" => ErrorCode::ApplicationMissing,
+            "AUTOSCALING_VALIDATION_ERROR" => ErrorCode::AutoscalingValidationError,
+            "AUTO_SCALING_CONFIGURATION" => ErrorCode::AutoScalingConfiguration,
+            "AUTO_SCALING_IAM_ROLE_PERMISSIONS" => ErrorCode::AutoScalingIamRolePermissions,
+            "CODEDEPLOY_RESOURCE_CANNOT_BE_FOUND" => ErrorCode::CodedeployResourceCannotBeFound,
+            "CUSTOMER_APPLICATION_UNHEALTHY" => ErrorCode::CustomerApplicationUnhealthy,
+            "DEPLOYMENT_GROUP_MISSING" => ErrorCode::DeploymentGroupMissing,
+            "ECS_UPDATE_ERROR" => ErrorCode::EcsUpdateError,
+            "ELASTIC_LOAD_BALANCING_INVALID" => ErrorCode::ElasticLoadBalancingInvalid,
+            "ELB_INVALID_INSTANCE" => ErrorCode::ElbInvalidInstance,
+            "HEALTH_CONSTRAINTS" => ErrorCode::HealthConstraints,
+            "HEALTH_CONSTRAINTS_INVALID" => ErrorCode::HealthConstraintsInvalid,
+            "HOOK_EXECUTION_FAILURE" => ErrorCode::HookExecutionFailure,
+            "IAM_ROLE_MISSING" => ErrorCode::IamRoleMissing,
+            "IAM_ROLE_PERMISSIONS" => ErrorCode::IamRolePermissions,
+            "INTERNAL_ERROR" => ErrorCode::InternalError,
+            "INVALID_ECS_SERVICE" => ErrorCode::InvalidEcsService,
+            "INVALID_LAMBDA_CONFIGURATION" => ErrorCode::InvalidLambdaConfiguration,
+            "INVALID_LAMBDA_FUNCTION" => ErrorCode::InvalidLambdaFunction,
+            "INVALID_REVISION" => ErrorCode::InvalidRevision,
+            "MANUAL_STOP" => ErrorCode::ManualStop,
+            "MISSING_BLUE_GREEN_DEPLOYMENT_CONFIGURATION" => {
+                ErrorCode::MissingBlueGreenDeploymentConfiguration
+            }
+            "MISSING_ELB_INFORMATION" => ErrorCode::MissingElbInformation,
+            "MISSING_GITHUB_TOKEN" => ErrorCode::MissingGithubToken,
+            "NO_EC2_SUBSCRIPTION" => ErrorCode::NoEc2Subscription,
+            "NO_INSTANCES" => ErrorCode::NoInstances,
+            "OVER_MAX_INSTANCES" => ErrorCode::OverMaxInstances,
+            "RESOURCE_LIMIT_EXCEEDED" => ErrorCode::ResourceLimitExceeded,
+            "REVISION_MISSING" => ErrorCode::RevisionMissing,
+            "THROTTLED" => ErrorCode::Throttled,
+            "TIMEOUT" => ErrorCode::Timeout,
+            "CLOUDFORMATION_STACK_FAILURE" => ErrorCode::CloudformationStackFailure,
+            _ => ErrorCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ErrorCode {
+    fn from(value: String) -> ErrorCode {
+        match value.as_str() {
+            "AGENT_ISSUE" => ErrorCode::AgentIssue,
+            "ALARM_ACTIVE" => ErrorCode::AlarmActive,
+            "APPLICATION_MISSING" => ErrorCode::ApplicationMissing,
+            "AUTOSCALING_VALIDATION_ERROR" => ErrorCode::AutoscalingValidationError,
+            "AUTO_SCALING_CONFIGURATION" => ErrorCode::AutoScalingConfiguration,
+            "AUTO_SCALING_IAM_ROLE_PERMISSIONS" => ErrorCode::AutoScalingIamRolePermissions,
+            "CODEDEPLOY_RESOURCE_CANNOT_BE_FOUND" => ErrorCode::CodedeployResourceCannotBeFound,
+            "CUSTOMER_APPLICATION_UNHEALTHY" => ErrorCode::CustomerApplicationUnhealthy,
+            "DEPLOYMENT_GROUP_MISSING" => ErrorCode::DeploymentGroupMissing,
+            "ECS_UPDATE_ERROR" => ErrorCode::EcsUpdateError,
+            "ELASTIC_LOAD_BALANCING_INVALID" => ErrorCode::ElasticLoadBalancingInvalid,
+            "ELB_INVALID_INSTANCE" => ErrorCode::ElbInvalidInstance,
+            "HEALTH_CONSTRAINTS" => ErrorCode::HealthConstraints,
+            "HEALTH_CONSTRAINTS_INVALID" => ErrorCode::HealthConstraintsInvalid,
+            "HOOK_EXECUTION_FAILURE" => ErrorCode::HookExecutionFailure,
+            "IAM_ROLE_MISSING" => ErrorCode::IamRoleMissing,
+            "IAM_ROLE_PERMISSIONS" => ErrorCode::IamRolePermissions,
+            "INTERNAL_ERROR" => ErrorCode::InternalError,
+            "INVALID_ECS_SERVICE" => ErrorCode::InvalidEcsService,
+            "INVALID_LAMBDA_CONFIGURATION" => ErrorCode::InvalidLambdaConfiguration,
+            "INVALID_LAMBDA_FUNCTION" => ErrorCode::InvalidLambdaFunction,
+            "INVALID_REVISION" => ErrorCode::InvalidRevision,
+            "MANUAL_STOP" => ErrorCode::ManualStop,
+            "MISSING_BLUE_GREEN_DEPLOYMENT_CONFIGURATION" => {
+                ErrorCode::MissingBlueGreenDeploymentConfiguration
+            }
+            "MISSING_ELB_INFORMATION" => ErrorCode::MissingElbInformation,
+            "MISSING_GITHUB_TOKEN" => ErrorCode::MissingGithubToken,
+            "NO_EC2_SUBSCRIPTION" => ErrorCode::NoEc2Subscription,
+            "NO_INSTANCES" => ErrorCode::NoInstances,
+            "OVER_MAX_INSTANCES" => ErrorCode::OverMaxInstances,
+            "RESOURCE_LIMIT_EXCEEDED" => ErrorCode::ResourceLimitExceeded,
+            "REVISION_MISSING" => ErrorCode::RevisionMissing,
+            "THROTTLED" => ErrorCode::Throttled,
+            "TIMEOUT" => ErrorCode::Timeout,
+            "CLOUDFORMATION_STACK_FAILURE" => ErrorCode::CloudformationStackFailure,
+            _ => ErrorCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ErrorCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ErrorCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ErrorCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ErrorCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ErrorCode {
+    fn deserialize<D>(deserializer: D) -> Result<ErrorCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about a deployment error.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1060,13 +2570,111 @@ pub struct ErrorInformation {
     /// <p><p>For more information, see <a href="https://docs.aws.amazon.com/codedeploy/latest/userguide/error-codes.html">Error Codes for AWS CodeDeploy</a> in the <a href="https://docs.aws.amazon.com/codedeploy/latest/userguide">AWS CodeDeploy User Guide</a>.</p> <p>The error code:</p> <ul> <li> <p>APPLICATION<em>MISSING: The application was missing. This error code is most likely raised if the application is deleted after the deployment is created, but before it is started.</p> </li> <li> <p>DEPLOYMENT</em>GROUP<em>MISSING: The deployment group was missing. This error code is most likely raised if the deployment group is deleted after the deployment is created, but before it is started.</p> </li> <li> <p>HEALTH</em>CONSTRAINTS: The deployment failed on too many instances to be successfully deployed within the instance health constraints specified.</p> </li> <li> <p>HEALTH<em>CONSTRAINTS</em>INVALID: The revision cannot be successfully deployed within the instance health constraints specified.</p> </li> <li> <p>IAM<em>ROLE</em>MISSING: The service role cannot be accessed.</p> </li> <li> <p>IAM<em>ROLE</em>PERMISSIONS: The service role does not have the correct permissions.</p> </li> <li> <p>INTERNAL<em>ERROR: There was an internal error.</p> </li> <li> <p>NO</em>EC2<em>SUBSCRIPTION: The calling account is not subscribed to Amazon EC2.</p> </li> <li> <p>NO</em>INSTANCES: No instances were specified, or no instances can be found.</p> </li> <li> <p>OVER<em>MAX</em>INSTANCES: The maximum number of instances was exceeded.</p> </li> <li> <p>THROTTLED: The operation was throttled because the calling account exceeded the throttling limits of one or more AWS services.</p> </li> <li> <p>TIMEOUT: The deployment has timed out.</p> </li> <li> <p>REVISION_MISSING: The revision ID was missing. This error code is most likely raised if the revision is deleted after the deployment is created, but before it is started.</p> </li> </ul></p>
     #[serde(rename = "code")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub code: Option<String>,
+    pub code: Option<ErrorCode>,
     /// <p>An accompanying error message.</p>
     #[serde(rename = "message")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub message: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FileExistsBehavior {
+    /// `DISALLOW`
+    Disallow,
+    /// `OVERWRITE`
+    Overwrite,
+    /// `RETAIN`
+    Retain,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl FileExistsBehavior {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FileExistsBehavior::Disallow => "DISALLOW",
+            FileExistsBehavior::Overwrite => "OVERWRITE",
+            FileExistsBehavior::Retain => "RETAIN",
+            FileExistsBehavior::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for FileExistsBehavior {
+    fn default() -> FileExistsBehavior {
+        FileExistsBehavior::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for FileExistsBehavior {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FileExistsBehavior {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FileExistsBehavior {
+    fn from(value: &'a str) -> FileExistsBehavior {
+        match value {
+            "DISALLOW" => FileExistsBehavior::Disallow,
+            "OVERWRITE" => FileExistsBehavior::Overwrite,
+            "RETAIN" => FileExistsBehavior::Retain,
+            _ => FileExistsBehavior::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FileExistsBehavior {
+    fn from(value: String) -> FileExistsBehavior {
+        match value.as_str() {
+            "DISALLOW" => FileExistsBehavior::Disallow,
+            "OVERWRITE" => FileExistsBehavior::Overwrite,
+            "RETAIN" => FileExistsBehavior::Retain,
+            _ => FileExistsBehavior::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for FileExistsBehavior {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<FileExistsBehavior, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FileExistsBehavior {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FileExistsBehavior {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FileExistsBehavior {
+    fn deserialize<D>(deserializer: D) -> Result<FileExistsBehavior, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about an application revision.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1081,16 +2689,31 @@ pub struct GenericRevisionInfo {
     pub description: Option<String>,
     /// <p>When the revision was first used by AWS CodeDeploy.</p>
     #[serde(rename = "firstUsedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub first_used_time: Option<f64>,
+    pub first_used_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>When the revision was last used by AWS CodeDeploy.</p>
     #[serde(rename = "lastUsedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_used_time: Option<f64>,
+    pub last_used_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>When the revision was registered with AWS CodeDeploy.</p>
     #[serde(rename = "registerTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub register_time: Option<f64>,
+    pub register_time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>Represents the input of a GetApplication operation.</p>
@@ -1265,36 +2888,227 @@ pub struct GetOnPremisesInstanceOutput {
     pub instance_info: Option<InstanceInfo>,
 }
 
-/// <p>Information about the location of application artifacts stored in GitHub.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct GitHubLocation {
-    /// <p>The SHA1 commit ID of the GitHub commit that represents the bundled artifacts for the application revision.</p>
-    #[serde(rename = "commitId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub commit_id: Option<String>,
-    /// <p>The GitHub account and repository pair that stores a reference to the commit that represents the bundled artifacts for the application revision. </p> <p>Specified as account/repository.</p>
-    #[serde(rename = "repository")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub repository: Option<String>,
+/// <p>Information about the location of application artifacts stored in GitHub.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GitHubLocation {
+    /// <p>The SHA1 commit ID of the GitHub commit that represents the bundled artifacts for the application revision.</p>
+    #[serde(rename = "commitId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commit_id: Option<String>,
+    /// <p>The GitHub account and repository pair that stores a reference to the commit that represents the bundled artifacts for the application revision. </p> <p>Specified as account/repository.</p>
+    #[serde(rename = "repository")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repository: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum GreenFleetProvisioningAction {
+    /// `DISCOVER_EXISTING`
+    DiscoverExisting,
+    /// `COPY_AUTO_SCALING_GROUP`
+    CopyAutoScalingGroup,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl GreenFleetProvisioningAction {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            GreenFleetProvisioningAction::DiscoverExisting => "DISCOVER_EXISTING",
+            GreenFleetProvisioningAction::CopyAutoScalingGroup => "COPY_AUTO_SCALING_GROUP",
+            GreenFleetProvisioningAction::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for GreenFleetProvisioningAction {
+    fn default() -> GreenFleetProvisioningAction {
+        GreenFleetProvisioningAction::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for GreenFleetProvisioningAction {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for GreenFleetProvisioningAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for GreenFleetProvisioningAction {
+    fn from(value: &'a str) -> GreenFleetProvisioningAction {
+        match value {
+            "DISCOVER_EXISTING" => GreenFleetProvisioningAction::DiscoverExisting,
+            "COPY_AUTO_SCALING_GROUP" => GreenFleetProvisioningAction::CopyAutoScalingGroup,
+            _ => GreenFleetProvisioningAction::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for GreenFleetProvisioningAction {
+    fn from(value: String) -> GreenFleetProvisioningAction {
+        match value.as_str() {
+            "DISCOVER_EXISTING" => GreenFleetProvisioningAction::DiscoverExisting,
+            "COPY_AUTO_SCALING_GROUP" => GreenFleetProvisioningAction::CopyAutoScalingGroup,
+            _ => GreenFleetProvisioningAction::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for GreenFleetProvisioningAction {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<GreenFleetProvisioningAction, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for GreenFleetProvisioningAction {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for GreenFleetProvisioningAction {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for GreenFleetProvisioningAction {
+    fn deserialize<D>(deserializer: D) -> Result<GreenFleetProvisioningAction, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Information about the instances that belong to the replacement environment in a blue/green deployment.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GreenFleetProvisioningOption {
+    /// <p><p>The method used to add instances to a replacement environment.</p> <ul> <li> <p>DISCOVER<em>EXISTING: Use instances that already exist or will be created manually.</p> </li> <li> <p>COPY</em>AUTO<em>SCALING</em>GROUP: Use settings from a specified Auto Scaling group to define and create instances in a new Auto Scaling group.</p> </li> </ul></p>
+    #[serde(rename = "action")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<GreenFleetProvisioningAction>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum InstanceAction {
+    /// `TERMINATE`
+    Terminate,
+    /// `KEEP_ALIVE`
+    KeepAlive,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl InstanceAction {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InstanceAction::Terminate => "TERMINATE",
+            InstanceAction::KeepAlive => "KEEP_ALIVE",
+            InstanceAction::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for InstanceAction {
+    fn default() -> InstanceAction {
+        InstanceAction::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for InstanceAction {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for InstanceAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for InstanceAction {
+    fn from(value: &'a str) -> InstanceAction {
+        match value {
+            "TERMINATE" => InstanceAction::Terminate,
+            "KEEP_ALIVE" => InstanceAction::KeepAlive,
+            _ => InstanceAction::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InstanceAction {
+    fn from(value: String) -> InstanceAction {
+        match value.as_str() {
+            "TERMINATE" => InstanceAction::Terminate,
+            "KEEP_ALIVE" => InstanceAction::KeepAlive,
+            _ => InstanceAction::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for InstanceAction {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<InstanceAction, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for InstanceAction {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
 }
 
-/// <p>Information about the instances that belong to the replacement environment in a blue/green deployment.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct GreenFleetProvisioningOption {
-    /// <p><p>The method used to add instances to a replacement environment.</p> <ul> <li> <p>DISCOVER<em>EXISTING: Use instances that already exist or will be created manually.</p> </li> <li> <p>COPY</em>AUTO<em>SCALING</em>GROUP: Use settings from a specified Auto Scaling group to define and create instances in a new Auto Scaling group.</p> </li> </ul></p>
-    #[serde(rename = "action")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub action: Option<String>,
+impl ::serde::Serialize for InstanceAction {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for InstanceAction {
+    fn deserialize<D>(deserializer: D) -> Result<InstanceAction, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about an on-premises instance.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InstanceInfo {
     /// <p>If the on-premises instance was deregistered, the time at which the on-premises instance was deregistered.</p>
     #[serde(rename = "deregisterTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deregister_time: Option<f64>,
+    pub deregister_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The ARN of the IAM session associated with the on-premises instance.</p>
     #[serde(rename = "iamSessionArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1313,14 +3127,137 @@ pub struct InstanceInfo {
     pub instance_name: Option<String>,
     /// <p>The time at which the on-premises instance was registered.</p>
     #[serde(rename = "registerTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub register_time: Option<f64>,
+    pub register_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The tags currently associated with the on-premises instance.</p>
     #[serde(rename = "tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tags: Option<Vec<Tag>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum InstanceStatus {
+    /// `Pending`
+    Pending,
+    /// `InProgress`
+    InProgress,
+    /// `Succeeded`
+    Succeeded,
+    /// `Failed`
+    Failed,
+    /// `Skipped`
+    Skipped,
+    /// `Unknown`
+    UnknownValue,
+    /// `Ready`
+    Ready,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl InstanceStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InstanceStatus::Pending => "Pending",
+            InstanceStatus::InProgress => "InProgress",
+            InstanceStatus::Succeeded => "Succeeded",
+            InstanceStatus::Failed => "Failed",
+            InstanceStatus::Skipped => "Skipped",
+            InstanceStatus::UnknownValue => "Unknown",
+            InstanceStatus::Ready => "Ready",
+            InstanceStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for InstanceStatus {
+    fn default() -> InstanceStatus {
+        InstanceStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for InstanceStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for InstanceStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for InstanceStatus {
+    fn from(value: &'a str) -> InstanceStatus {
+        match value {
+            "Pending" => InstanceStatus::Pending,
+            "InProgress" => InstanceStatus::InProgress,
+            "Succeeded" => InstanceStatus::Succeeded,
+            "Failed" => InstanceStatus::Failed,
+            "Skipped" => InstanceStatus::Skipped,
+            "Unknown" => InstanceStatus::UnknownValue,
+            "Ready" => InstanceStatus::Ready,
+            _ => InstanceStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InstanceStatus {
+    fn from(value: String) -> InstanceStatus {
+        match value.as_str() {
+            "Pending" => InstanceStatus::Pending,
+            "InProgress" => InstanceStatus::InProgress,
+            "Succeeded" => InstanceStatus::Succeeded,
+            "Failed" => InstanceStatus::Failed,
+            "Skipped" => InstanceStatus::Skipped,
+            "Unknown" => InstanceStatus::UnknownValue,
+            "Ready" => InstanceStatus::Ready,
+            _ => InstanceStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for InstanceStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<InstanceStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for InstanceStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for InstanceStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InstanceStatus {
+    fn deserialize<D>(deserializer: D) -> Result<InstanceStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about an instance in a deployment.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1336,11 +3273,16 @@ pub struct InstanceSummary {
     /// <p><p>Information about which environment an instance belongs to in a blue/green deployment.</p> <ul> <li> <p>BLUE: The instance is part of the original environment.</p> </li> <li> <p>GREEN: The instance is part of the replacement environment.</p> </li> </ul></p>
     #[serde(rename = "instanceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub instance_type: Option<String>,
+    pub instance_type: Option<InstanceType>,
     /// <p>A timestamp that indicaties when the instance information was last updated.</p>
     #[serde(rename = "lastUpdatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_at: Option<f64>,
+    pub last_updated_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A list of lifecycle events for this instance.</p>
     #[serde(rename = "lifecycleEvents")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1348,7 +3290,7 @@ pub struct InstanceSummary {
     /// <p><p>The deployment status for this instance:</p> <ul> <li> <p>Pending: The deployment is pending for this instance.</p> </li> <li> <p>In Progress: The deployment is in progress for this instance.</p> </li> <li> <p>Succeeded: The deployment has succeeded for this instance.</p> </li> <li> <p>Failed: The deployment has failed for this instance.</p> </li> <li> <p>Skipped: The deployment has been skipped for this instance.</p> </li> <li> <p>Unknown: The deployment status is unknown for this instance.</p> </li> </ul></p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<InstanceStatus>,
 }
 
 /// <p> A target Amazon EC2 or on-premises instance during a deployment that uses the EC2/On-premises compute platform. </p>
@@ -1362,11 +3304,16 @@ pub struct InstanceTarget {
     /// <p> A label that identifies whether the instance is an original target (<code>BLUE</code>) or a replacement target (<code>GREEN</code>). </p>
     #[serde(rename = "instanceLabel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub instance_label: Option<String>,
+    pub instance_label: Option<TargetLabel>,
     /// <p> The date and time when the target instance was updated by a deployment. </p>
     #[serde(rename = "lastUpdatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_at: Option<f64>,
+    pub last_updated_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p> The lifecycle events of the deployment to this target instance. </p>
     #[serde(rename = "lifecycleEvents")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1374,7 +3321,7 @@ pub struct InstanceTarget {
     /// <p> The status an EC2/On-premises deployment's target instance. </p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<TargetStatus>,
     /// <p> The ARN of the target. </p>
     #[serde(rename = "targetArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1385,6 +3332,99 @@ pub struct InstanceTarget {
     pub target_id: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum InstanceType {
+    /// `Blue`
+    Blue,
+    /// `Green`
+    Green,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl InstanceType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InstanceType::Blue => "Blue",
+            InstanceType::Green => "Green",
+            InstanceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for InstanceType {
+    fn default() -> InstanceType {
+        InstanceType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for InstanceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for InstanceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for InstanceType {
+    fn from(value: &'a str) -> InstanceType {
+        match value {
+            "Blue" => InstanceType::Blue,
+            "Green" => InstanceType::Green,
+            _ => InstanceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InstanceType {
+    fn from(value: String) -> InstanceType {
+        match value.as_str() {
+            "Blue" => InstanceType::Blue,
+            "Green" => InstanceType::Green,
+            _ => InstanceType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for InstanceType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<InstanceType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for InstanceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for InstanceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InstanceType {
+    fn deserialize<D>(deserializer: D) -> Result<InstanceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p> Information about a Lambda function specified in a deployment. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1425,8 +3465,13 @@ pub struct LambdaTarget {
     pub lambda_function_info: Option<LambdaFunctionInfo>,
     /// <p> The date and time when the target Lambda function was updated by a deployment. </p>
     #[serde(rename = "lastUpdatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_at: Option<f64>,
+    pub last_updated_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p> The lifecycle events of the deployment to this target Lambda function. </p>
     #[serde(rename = "lifecycleEvents")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1434,7 +3479,7 @@ pub struct LambdaTarget {
     /// <p> The status an AWS Lambda deployment's target Lambda function. </p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<TargetStatus>,
     /// <p> The ARN of the target. </p>
     #[serde(rename = "targetArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1451,22 +3496,145 @@ pub struct LambdaTarget {
 pub struct LastDeploymentInfo {
     /// <p>A timestamp that indicates when the most recent deployment to the deployment group started.</p>
     #[serde(rename = "createTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub create_time: Option<f64>,
+    pub create_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p> The unique ID of a deployment. </p>
     #[serde(rename = "deploymentId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub deployment_id: Option<String>,
     /// <p>A timestamp that indicates when the most recent deployment to the deployment group was complete.</p>
     #[serde(rename = "endTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The status of the most recent deployment.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<DeploymentStatus>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LifecycleErrorCode {
+    /// `Success`
+    Success,
+    /// `ScriptMissing`
+    ScriptMissing,
+    /// `ScriptNotExecutable`
+    ScriptNotExecutable,
+    /// `ScriptTimedOut`
+    ScriptTimedOut,
+    /// `ScriptFailed`
+    ScriptFailed,
+    /// `UnknownError`
+    UnknownError,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LifecycleErrorCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LifecycleErrorCode::Success => "Success",
+            LifecycleErrorCode::ScriptMissing => "ScriptMissing",
+            LifecycleErrorCode::ScriptNotExecutable => "ScriptNotExecutable",
+            LifecycleErrorCode::ScriptTimedOut => "ScriptTimedOut",
+            LifecycleErrorCode::ScriptFailed => "ScriptFailed",
+            LifecycleErrorCode::UnknownError => "UnknownError",
+            LifecycleErrorCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LifecycleErrorCode {
+    fn default() -> LifecycleErrorCode {
+        LifecycleErrorCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LifecycleErrorCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LifecycleErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LifecycleErrorCode {
+    fn from(value: &'a str) -> LifecycleErrorCode {
+        match value {
+            "Success" => LifecycleErrorCode::Success,
+            "ScriptMissing" => LifecycleErrorCode::ScriptMissing,
+            "ScriptNotExecutable" => LifecycleErrorCode::ScriptNotExecutable,
+            "ScriptTimedOut" => LifecycleErrorCode::ScriptTimedOut,
+            "ScriptFailed" => LifecycleErrorCode::ScriptFailed,
+            "UnknownError" => LifecycleErrorCode::UnknownError,
+            _ => LifecycleErrorCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LifecycleErrorCode {
+    fn from(value: String) -> LifecycleErrorCode {
+        match value.as_str() {
+            "Success" => LifecycleErrorCode::Success,
+            "ScriptMissing" => LifecycleErrorCode::ScriptMissing,
+            "ScriptNotExecutable" => LifecycleErrorCode::ScriptNotExecutable,
+            "ScriptTimedOut" => LifecycleErrorCode::ScriptTimedOut,
+            "ScriptFailed" => LifecycleErrorCode::ScriptFailed,
+            "UnknownError" => LifecycleErrorCode::UnknownError,
+            _ => LifecycleErrorCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LifecycleErrorCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LifecycleErrorCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LifecycleErrorCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LifecycleErrorCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for LifecycleErrorCode {
+    fn deserialize<D>(deserializer: D) -> Result<LifecycleErrorCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about a deployment lifecycle event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1477,22 +3645,145 @@ pub struct LifecycleEvent {
     pub diagnostics: Option<Diagnostics>,
     /// <p>A timestamp that indicates when the deployment lifecycle event ended.</p>
     #[serde(rename = "endTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The deployment lifecycle event name, such as ApplicationStop, BeforeInstall, AfterInstall, ApplicationStart, or ValidateService.</p>
     #[serde(rename = "lifecycleEventName")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub lifecycle_event_name: Option<String>,
     /// <p>A timestamp that indicates when the deployment lifecycle event started.</p>
     #[serde(rename = "startTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>The deployment lifecycle event status:</p> <ul> <li> <p>Pending: The deployment lifecycle event is pending.</p> </li> <li> <p>InProgress: The deployment lifecycle event is in progress.</p> </li> <li> <p>Succeeded: The deployment lifecycle event ran successfully.</p> </li> <li> <p>Failed: The deployment lifecycle event has failed.</p> </li> <li> <p>Skipped: The deployment lifecycle event has been skipped.</p> </li> <li> <p>Unknown: The deployment lifecycle event is unknown.</p> </li> </ul></p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<LifecycleEventStatus>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LifecycleEventStatus {
+    /// `Pending`
+    Pending,
+    /// `InProgress`
+    InProgress,
+    /// `Succeeded`
+    Succeeded,
+    /// `Failed`
+    Failed,
+    /// `Skipped`
+    Skipped,
+    /// `Unknown`
+    UnknownValue,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LifecycleEventStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LifecycleEventStatus::Pending => "Pending",
+            LifecycleEventStatus::InProgress => "InProgress",
+            LifecycleEventStatus::Succeeded => "Succeeded",
+            LifecycleEventStatus::Failed => "Failed",
+            LifecycleEventStatus::Skipped => "Skipped",
+            LifecycleEventStatus::UnknownValue => "Unknown",
+            LifecycleEventStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LifecycleEventStatus {
+    fn default() -> LifecycleEventStatus {
+        LifecycleEventStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LifecycleEventStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LifecycleEventStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
+impl<'a> From<&'a str> for LifecycleEventStatus {
+    fn from(value: &'a str) -> LifecycleEventStatus {
+        match value {
+            "Pending" => LifecycleEventStatus::Pending,
+            "InProgress" => LifecycleEventStatus::InProgress,
+            "Succeeded" => LifecycleEventStatus::Succeeded,
+            "Failed" => LifecycleEventStatus::Failed,
+            "Skipped" => LifecycleEventStatus::Skipped,
+            "Unknown" => LifecycleEventStatus::UnknownValue,
+            _ => LifecycleEventStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LifecycleEventStatus {
+    fn from(value: String) -> LifecycleEventStatus {
+        match value.as_str() {
+            "Pending" => LifecycleEventStatus::Pending,
+            "InProgress" => LifecycleEventStatus::InProgress,
+            "Succeeded" => LifecycleEventStatus::Succeeded,
+            "Failed" => LifecycleEventStatus::Failed,
+            "Skipped" => LifecycleEventStatus::Skipped,
+            "Unknown" => LifecycleEventStatus::UnknownValue,
+            _ => LifecycleEventStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LifecycleEventStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LifecycleEventStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LifecycleEventStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LifecycleEventStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LifecycleEventStatus {
+    fn deserialize<D>(deserializer: D) -> Result<LifecycleEventStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p> Represents the input of a ListApplicationRevisions operation. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -1503,7 +3794,7 @@ pub struct ListApplicationRevisionsInput {
     /// <p><p> Whether to list revisions based on whether the revision is the target revision of an deployment group: </p> <ul> <li> <p>include: List revisions that are target revisions of a deployment group.</p> </li> <li> <p>exclude: Do not list revisions that are target revisions of a deployment group.</p> </li> <li> <p>ignore: List all revisions.</p> </li> </ul></p>
     #[serde(rename = "deployed")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deployed: Option<String>,
+    pub deployed: Option<ListStateFilterAction>,
     /// <p>An identifier returned from the previous <code>ListApplicationRevisions</code> call. It can be used to return the next set of applications in the list.</p>
     #[serde(rename = "nextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1519,11 +3810,11 @@ pub struct ListApplicationRevisionsInput {
     /// <p>The column name to use to sort the list results:</p> <ul> <li> <p>registerTime: Sort by the time the revisions were registered with AWS CodeDeploy.</p> </li> <li> <p>firstUsedTime: Sort by the time the revisions were first used in a deployment.</p> </li> <li> <p>lastUsedTime: Sort by the time the revisions were last used in a deployment.</p> </li> </ul> <p> If not specified or set to null, the results are returned in an arbitrary order. </p>
     #[serde(rename = "sortBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort_by: Option<String>,
+    pub sort_by: Option<ApplicationRevisionSortBy>,
     /// <p> The order in which to sort the list results: </p> <ul> <li> <p>ascending: ascending order.</p> </li> <li> <p>descending: descending order.</p> </li> </ul> <p>If not specified, the results are sorted in ascending order.</p> <p>If set to null, the results are sorted in an arbitrary order.</p>
     #[serde(rename = "sortOrder")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort_order: Option<String>,
+    pub sort_order: Option<SortOrder>,
 }
 
 /// <p>Represents the output of a ListApplicationRevisions operation.</p>
@@ -1629,11 +3920,11 @@ pub struct ListDeploymentInstancesInput {
     /// <p><p>A subset of instances to list by status:</p> <ul> <li> <p>Pending: Include those instances with pending deployments.</p> </li> <li> <p>InProgress: Include those instances where deployments are still in progress.</p> </li> <li> <p>Succeeded: Include those instances with successful deployments.</p> </li> <li> <p>Failed: Include those instances with failed deployments.</p> </li> <li> <p>Skipped: Include those instances with skipped deployments.</p> </li> <li> <p>Unknown: Include those instances with deployments in an unknown state.</p> </li> </ul></p>
     #[serde(rename = "instanceStatusFilter")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub instance_status_filter: Option<Vec<String>>,
+    pub instance_status_filter: Option<Vec<InstanceStatus>>,
     /// <p>The set of instances in a blue/green deployment, either those in the original environment ("BLUE") or those in the replacement environment ("GREEN"), for which you want to view instance information.</p>
     #[serde(rename = "instanceTypeFilter")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub instance_type_filter: Option<Vec<String>>,
+    pub instance_type_filter: Option<Vec<InstanceType>>,
     /// <p>An identifier returned from the previous list deployment instances call. It can be used to return the next set of deployment instances in the list.</p>
     #[serde(rename = "nextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1668,7 +3959,7 @@ pub struct ListDeploymentTargetsInput {
     /// <p><p> A key used to filter the returned targets. The two valid values are:</p> <ul> <li> <p> <code>TargetStatus</code> - A <code>TargetStatus</code> filter string can be <code>Failed</code>, <code>InProgress</code>, <code>Pending</code>, <code>Ready</code>, <code>Skipped</code>, <code>Succeeded</code>, or <code>Unknown</code>. </p> </li> <li> <p> <code>ServerInstanceLabel</code> - A <code>ServerInstanceLabel</code> filter string can be <code>Blue</code> or <code>Green</code>. </p> </li> </ul></p>
     #[serde(rename = "targetFilters")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub target_filters: Option<::std::collections::HashMap<String, Vec<String>>>,
+    pub target_filters: Option<::std::collections::HashMap<TargetFilterName, Vec<String>>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1703,7 +3994,7 @@ pub struct ListDeploymentsInput {
     /// <p><p>A subset of deployments to list by status:</p> <ul> <li> <p>Created: Include created deployments in the resulting list.</p> </li> <li> <p>Queued: Include queued deployments in the resulting list.</p> </li> <li> <p>In Progress: Include in-progress deployments in the resulting list.</p> </li> <li> <p>Succeeded: Include successful deployments in the resulting list.</p> </li> <li> <p>Failed: Include failed deployments in the resulting list.</p> </li> <li> <p>Stopped: Include stopped deployments in the resulting list.</p> </li> </ul></p>
     #[serde(rename = "includeOnlyStatuses")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub include_only_statuses: Option<Vec<String>>,
+    pub include_only_statuses: Option<Vec<DeploymentStatus>>,
     /// <p>An identifier returned from the previous list deployments call. It can be used to return the next set of deployments in the list.</p>
     #[serde(rename = "nextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1759,7 +4050,7 @@ pub struct ListOnPremisesInstancesInput {
     /// <p><p>The registration status of the on-premises instances:</p> <ul> <li> <p>Deregistered: Include deregistered on-premises instances in the resulting list.</p> </li> <li> <p>Registered: Include registered on-premises instances in the resulting list.</p> </li> </ul></p>
     #[serde(rename = "registrationStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub registration_status: Option<String>,
+    pub registration_status: Option<RegistrationStatus>,
     /// <p>The on-premises instance tags that are used to restrict the on-premises instance names returned.</p>
     #[serde(rename = "tagFilters")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1780,6 +4071,104 @@ pub struct ListOnPremisesInstancesOutput {
     pub next_token: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ListStateFilterAction {
+    /// `include`
+    Include,
+    /// `exclude`
+    Exclude,
+    /// `ignore`
+    Ignore,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ListStateFilterAction {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ListStateFilterAction::Include => "include",
+            ListStateFilterAction::Exclude => "exclude",
+            ListStateFilterAction::Ignore => "ignore",
+            ListStateFilterAction::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ListStateFilterAction {
+    fn default() -> ListStateFilterAction {
+        ListStateFilterAction::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ListStateFilterAction {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ListStateFilterAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ListStateFilterAction {
+    fn from(value: &'a str) -> ListStateFilterAction {
+        match value {
+            "include" => ListStateFilterAction::Include,
+            "exclude" => ListStateFilterAction::Exclude,
+            "ignore" => ListStateFilterAction::Ignore,
+            _ => ListStateFilterAction::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ListStateFilterAction {
+    fn from(value: String) -> ListStateFilterAction {
+        match value.as_str() {
+            "include" => ListStateFilterAction::Include,
+            "exclude" => ListStateFilterAction::Exclude,
+            "ignore" => ListStateFilterAction::Ignore,
+            _ => ListStateFilterAction::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ListStateFilterAction {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ListStateFilterAction, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ListStateFilterAction {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ListStateFilterAction {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ListStateFilterAction {
+    fn deserialize<D>(deserializer: D) -> Result<ListStateFilterAction, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ListTagsForResourceInput {
@@ -1828,13 +4217,106 @@ pub struct MinimumHealthyHosts {
     /// <p>The minimum healthy instance type:</p> <ul> <li> <p>HOST_COUNT: The minimum number of healthy instance as an absolute value.</p> </li> <li> <p>FLEET_PERCENT: The minimum number of healthy instance as a percentage of the total number of instance in the deployment.</p> </li> </ul> <p>In an example of nine instance, if a HOST_COUNT of six is specified, deploy to up to three instances at a time. The deployment is successful if six or more instances are deployed to successfully. Otherwise, the deployment fails. If a FLEET_PERCENT of 40 is specified, deploy to up to five instance at a time. The deployment is successful if four or more instance are deployed to successfully. Otherwise, the deployment fails.</p> <note> <p>In a call to the <code>GetDeploymentConfig</code>, CodeDeployDefault.OneAtATime returns a minimum healthy instance type of MOST_CONCURRENCY and a value of 1. This means a deployment to only one instance at a time. (You cannot set the type to MOST_CONCURRENCY, only to HOST_COUNT or FLEET_PERCENT.) In addition, with CodeDeployDefault.OneAtATime, AWS CodeDeploy attempts to ensure that all instances but one are kept in a healthy state during the deployment. Although this allows one instance at a time to be taken offline for a new deployment, it also means that if the deployment to the last instance fails, the overall deployment is still successful.</p> </note> <p>For more information, see <a href="https://docs.aws.amazon.com/codedeploy/latest/userguide/instances-health.html">AWS CodeDeploy Instance Health</a> in the <i>AWS CodeDeploy User Guide</i>.</p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<MinimumHealthyHostsType>,
     /// <p>The minimum healthy instance value.</p>
     #[serde(rename = "value")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<i64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MinimumHealthyHostsType {
+    /// `HOST_COUNT`
+    HostCount,
+    /// `FLEET_PERCENT`
+    FleetPercent,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl MinimumHealthyHostsType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MinimumHealthyHostsType::HostCount => "HOST_COUNT",
+            MinimumHealthyHostsType::FleetPercent => "FLEET_PERCENT",
+            MinimumHealthyHostsType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for MinimumHealthyHostsType {
+    fn default() -> MinimumHealthyHostsType {
+        MinimumHealthyHostsType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for MinimumHealthyHostsType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for MinimumHealthyHostsType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for MinimumHealthyHostsType {
+    fn from(value: &'a str) -> MinimumHealthyHostsType {
+        match value {
+            "HOST_COUNT" => MinimumHealthyHostsType::HostCount,
+            "FLEET_PERCENT" => MinimumHealthyHostsType::FleetPercent,
+            _ => MinimumHealthyHostsType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MinimumHealthyHostsType {
+    fn from(value: String) -> MinimumHealthyHostsType {
+        match value.as_str() {
+            "HOST_COUNT" => MinimumHealthyHostsType::HostCount,
+            "FLEET_PERCENT" => MinimumHealthyHostsType::FleetPercent,
+            _ => MinimumHealthyHostsType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for MinimumHealthyHostsType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<MinimumHealthyHostsType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MinimumHealthyHostsType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for MinimumHealthyHostsType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MinimumHealthyHostsType {
+    fn deserialize<D>(deserializer: D) -> Result<MinimumHealthyHostsType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about groups of on-premises instance tags.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct OnPremisesTagSet {
@@ -1858,7 +4340,7 @@ pub struct PutLifecycleEventHookExecutionStatusInput {
     /// <p>The result of a Lambda function that validates a deployment lifecycle event (<code>Succeeded</code> or <code>Failed</code>).</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<LifecycleEventStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1916,6 +4398,99 @@ pub struct RegisterOnPremisesInstanceInput {
     pub instance_name: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RegistrationStatus {
+    /// `Registered`
+    Registered,
+    /// `Deregistered`
+    Deregistered,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RegistrationStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RegistrationStatus::Registered => "Registered",
+            RegistrationStatus::Deregistered => "Deregistered",
+            RegistrationStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RegistrationStatus {
+    fn default() -> RegistrationStatus {
+        RegistrationStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RegistrationStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RegistrationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RegistrationStatus {
+    fn from(value: &'a str) -> RegistrationStatus {
+        match value {
+            "Registered" => RegistrationStatus::Registered,
+            "Deregistered" => RegistrationStatus::Deregistered,
+            _ => RegistrationStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RegistrationStatus {
+    fn from(value: String) -> RegistrationStatus {
+        match value.as_str() {
+            "Registered" => RegistrationStatus::Registered,
+            "Deregistered" => RegistrationStatus::Deregistered,
+            _ => RegistrationStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RegistrationStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RegistrationStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RegistrationStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RegistrationStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RegistrationStatus {
+    fn deserialize<D>(deserializer: D) -> Result<RegistrationStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents the input of a RemoveTagsFromOnPremisesInstances operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -1956,7 +4531,7 @@ pub struct RevisionLocation {
     /// <p><p>The type of application revision:</p> <ul> <li> <p>S3: An application revision stored in Amazon S3.</p> </li> <li> <p>GitHub: An application revision stored in GitHub (EC2/On-premises deployments only).</p> </li> <li> <p>String: A YAML-formatted or JSON-formatted string (AWS Lambda deployments only).</p> </li> </ul></p>
     #[serde(rename = "revisionType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub revision_type: Option<String>,
+    pub revision_type: Option<RevisionLocationType>,
     /// <p>Information about the location of a revision stored in Amazon S3. </p>
     #[serde(rename = "s3Location")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1967,6 +4542,109 @@ pub struct RevisionLocation {
     pub string: Option<RawString>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RevisionLocationType {
+    /// `S3`
+    S3,
+    /// `GitHub`
+    GitHub,
+    /// `String`
+    String,
+    /// `AppSpecContent`
+    AppSpecContent,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RevisionLocationType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RevisionLocationType::S3 => "S3",
+            RevisionLocationType::GitHub => "GitHub",
+            RevisionLocationType::String => "String",
+            RevisionLocationType::AppSpecContent => "AppSpecContent",
+            RevisionLocationType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RevisionLocationType {
+    fn default() -> RevisionLocationType {
+        RevisionLocationType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RevisionLocationType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RevisionLocationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RevisionLocationType {
+    fn from(value: &'a str) -> RevisionLocationType {
+        match value {
+            "S3" => RevisionLocationType::S3,
+            "GitHub" => RevisionLocationType::GitHub,
+            "String" => RevisionLocationType::String,
+            "AppSpecContent" => RevisionLocationType::AppSpecContent,
+            _ => RevisionLocationType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RevisionLocationType {
+    fn from(value: String) -> RevisionLocationType {
+        match value.as_str() {
+            "S3" => RevisionLocationType::S3,
+            "GitHub" => RevisionLocationType::GitHub,
+            "String" => RevisionLocationType::String,
+            "AppSpecContent" => RevisionLocationType::AppSpecContent,
+            _ => RevisionLocationType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RevisionLocationType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RevisionLocationType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RevisionLocationType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RevisionLocationType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RevisionLocationType {
+    fn deserialize<D>(deserializer: D) -> Result<RevisionLocationType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about a deployment rollback.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1995,7 +4673,7 @@ pub struct S3Location {
     /// <p><p>The file type of the application revision. Must be one of the following:</p> <ul> <li> <p>tar: A tar archive file.</p> </li> <li> <p>tgz: A compressed tar archive file.</p> </li> <li> <p>zip: A zip archive file.</p> </li> </ul></p>
     #[serde(rename = "bundleType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub bundle_type: Option<String>,
+    pub bundle_type: Option<BundleType>,
     /// <p>The ETag of the Amazon S3 object that represents the bundled artifacts for the application revision.</p> <p>If the ETag is not specified as an input parameter, ETag validation of the object is skipped.</p>
     #[serde(rename = "eTag")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2019,6 +4697,99 @@ pub struct SkipWaitTimeForInstanceTerminationInput {
     pub deployment_id: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SortOrder {
+    /// `ascending`
+    Ascending,
+    /// `descending`
+    Descending,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SortOrder {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SortOrder::Ascending => "ascending",
+            SortOrder::Descending => "descending",
+            SortOrder::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SortOrder {
+    fn default() -> SortOrder {
+        SortOrder::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SortOrder {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SortOrder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SortOrder {
+    fn from(value: &'a str) -> SortOrder {
+        match value {
+            "ascending" => SortOrder::Ascending,
+            "descending" => SortOrder::Descending,
+            _ => SortOrder::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SortOrder {
+    fn from(value: String) -> SortOrder {
+        match value.as_str() {
+            "ascending" => SortOrder::Ascending,
+            "descending" => SortOrder::Descending,
+            _ => SortOrder::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SortOrder {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SortOrder, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SortOrder {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SortOrder {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SortOrder {
+    fn deserialize<D>(deserializer: D) -> Result<SortOrder, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p> Represents the input of a StopDeployment operation. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -2039,13 +4810,106 @@ pub struct StopDeploymentOutput {
     /// <p><p>The status of the stop deployment operation:</p> <ul> <li> <p>Pending: The stop operation is pending.</p> </li> <li> <p>Succeeded: The stop operation was successful.</p> </li> </ul></p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<StopStatus>,
     /// <p>An accompanying status message.</p>
     #[serde(rename = "statusMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status_message: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StopStatus {
+    /// `Pending`
+    Pending,
+    /// `Succeeded`
+    Succeeded,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl StopStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StopStatus::Pending => "Pending",
+            StopStatus::Succeeded => "Succeeded",
+            StopStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for StopStatus {
+    fn default() -> StopStatus {
+        StopStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for StopStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for StopStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for StopStatus {
+    fn from(value: &'a str) -> StopStatus {
+        match value {
+            "Pending" => StopStatus::Pending,
+            "Succeeded" => StopStatus::Succeeded,
+            _ => StopStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StopStatus {
+    fn from(value: String) -> StopStatus {
+        match value.as_str() {
+            "Pending" => StopStatus::Pending,
+            "Succeeded" => StopStatus::Succeeded,
+            _ => StopStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for StopStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<StopStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StopStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for StopStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StopStatus {
+    fn deserialize<D>(deserializer: D) -> Result<StopStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about a tag.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Tag {
@@ -2069,28 +4933,219 @@ pub struct TagFilter {
     /// <p><p>The on-premises instance tag filter type:</p> <ul> <li> <p>KEY<em>ONLY: Key only.</p> </li> <li> <p>VALUE</em>ONLY: Value only.</p> </li> <li> <p>KEY<em>AND</em>VALUE: Key and value.</p> </li> </ul></p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<TagFilterType>,
     /// <p>The on-premises instance tag filter value.</p>
     #[serde(rename = "Value")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct TagResourceInput {
-    /// <p> The ARN of a resource, such as a CodeDeploy application or deployment group. </p>
-    #[serde(rename = "ResourceArn")]
-    pub resource_arn: String,
-    /// <p> A list of tags that <code>TagResource</code> associates with a resource. The resource is identified by the <code>ResourceArn</code> input parameter. </p>
-    #[serde(rename = "Tags")]
-    pub tags: Vec<Tag>,
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TagFilterType {
+    /// `KEY_ONLY`
+    KeyOnly,
+    /// `VALUE_ONLY`
+    ValueOnly,
+    /// `KEY_AND_VALUE`
+    KeyAndValue,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TagFilterType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TagFilterType::KeyOnly => "KEY_ONLY",
+            TagFilterType::ValueOnly => "VALUE_ONLY",
+            TagFilterType::KeyAndValue => "KEY_AND_VALUE",
+            TagFilterType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TagFilterType {
+    fn default() -> TagFilterType {
+        TagFilterType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TagFilterType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TagFilterType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TagFilterType {
+    fn from(value: &'a str) -> TagFilterType {
+        match value {
+            "KEY_ONLY" => TagFilterType::KeyOnly,
+            "VALUE_ONLY" => TagFilterType::ValueOnly,
+            "KEY_AND_VALUE" => TagFilterType::KeyAndValue,
+            _ => TagFilterType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TagFilterType {
+    fn from(value: String) -> TagFilterType {
+        match value.as_str() {
+            "KEY_ONLY" => TagFilterType::KeyOnly,
+            "VALUE_ONLY" => TagFilterType::ValueOnly,
+            "KEY_AND_VALUE" => TagFilterType::KeyAndValue,
+            _ => TagFilterType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TagFilterType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TagFilterType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TagFilterType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TagFilterType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TagFilterType {
+    fn deserialize<D>(deserializer: D) -> Result<TagFilterType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct TagResourceInput {
+    /// <p> The ARN of a resource, such as a CodeDeploy application or deployment group. </p>
+    #[serde(rename = "ResourceArn")]
+    pub resource_arn: String,
+    /// <p> A list of tags that <code>TagResource</code> associates with a resource. The resource is identified by the <code>ResourceArn</code> input parameter. </p>
+    #[serde(rename = "Tags")]
+    pub tags: Vec<Tag>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct TagResourceOutput {}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TargetFilterName {
+    /// `TargetStatus`
+    TargetStatus,
+    /// `ServerInstanceLabel`
+    ServerInstanceLabel,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TargetFilterName {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TargetFilterName::TargetStatus => "TargetStatus",
+            TargetFilterName::ServerInstanceLabel => "ServerInstanceLabel",
+            TargetFilterName::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TargetFilterName {
+    fn default() -> TargetFilterName {
+        TargetFilterName::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TargetFilterName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TargetFilterName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TargetFilterName {
+    fn from(value: &'a str) -> TargetFilterName {
+        match value {
+            "TargetStatus" => TargetFilterName::TargetStatus,
+            "ServerInstanceLabel" => TargetFilterName::ServerInstanceLabel,
+            _ => TargetFilterName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TargetFilterName {
+    fn from(value: String) -> TargetFilterName {
+        match value.as_str() {
+            "TargetStatus" => TargetFilterName::TargetStatus,
+            "ServerInstanceLabel" => TargetFilterName::ServerInstanceLabel,
+            _ => TargetFilterName::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TargetFilterName {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TargetFilterName, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TargetFilterName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct TagResourceOutput {}
+impl ::serde::Serialize for TargetFilterName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
 
+impl<'de> ::serde::Deserialize<'de> for TargetFilterName {
+    fn deserialize<D>(deserializer: D) -> Result<TargetFilterName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about a target group in Elastic Load Balancing to use in a deployment. Instances are registered as targets in a target group, and traffic is routed to the target group.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TargetGroupInfo {
@@ -2134,6 +5189,217 @@ pub struct TargetInstances {
     pub tag_filters: Option<Vec<EC2TagFilter>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TargetLabel {
+    /// `Blue`
+    Blue,
+    /// `Green`
+    Green,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TargetLabel {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TargetLabel::Blue => "Blue",
+            TargetLabel::Green => "Green",
+            TargetLabel::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TargetLabel {
+    fn default() -> TargetLabel {
+        TargetLabel::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TargetLabel {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TargetLabel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TargetLabel {
+    fn from(value: &'a str) -> TargetLabel {
+        match value {
+            "Blue" => TargetLabel::Blue,
+            "Green" => TargetLabel::Green,
+            _ => TargetLabel::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TargetLabel {
+    fn from(value: String) -> TargetLabel {
+        match value.as_str() {
+            "Blue" => TargetLabel::Blue,
+            "Green" => TargetLabel::Green,
+            _ => TargetLabel::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TargetLabel {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TargetLabel, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TargetLabel {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TargetLabel {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TargetLabel {
+    fn deserialize<D>(deserializer: D) -> Result<TargetLabel, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TargetStatus {
+    /// `Pending`
+    Pending,
+    /// `InProgress`
+    InProgress,
+    /// `Succeeded`
+    Succeeded,
+    /// `Failed`
+    Failed,
+    /// `Skipped`
+    Skipped,
+    /// `Unknown`
+    UnknownValue,
+    /// `Ready`
+    Ready,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TargetStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TargetStatus::Pending => "Pending",
+            TargetStatus::InProgress => "InProgress",
+            TargetStatus::Succeeded => "Succeeded",
+            TargetStatus::Failed => "Failed",
+            TargetStatus::Skipped => "Skipped",
+            TargetStatus::UnknownValue => "Unknown",
+            TargetStatus::Ready => "Ready",
+            TargetStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TargetStatus {
+    fn default() -> TargetStatus {
+        TargetStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TargetStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TargetStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TargetStatus {
+    fn from(value: &'a str) -> TargetStatus {
+        match value {
+            "Pending" => TargetStatus::Pending,
+            "InProgress" => TargetStatus::InProgress,
+            "Succeeded" => TargetStatus::Succeeded,
+            "Failed" => TargetStatus::Failed,
+            "Skipped" => TargetStatus::Skipped,
+            "Unknown" => TargetStatus::UnknownValue,
+            "Ready" => TargetStatus::Ready,
+            _ => TargetStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TargetStatus {
+    fn from(value: String) -> TargetStatus {
+        match value.as_str() {
+            "Pending" => TargetStatus::Pending,
+            "InProgress" => TargetStatus::InProgress,
+            "Succeeded" => TargetStatus::Succeeded,
+            "Failed" => TargetStatus::Failed,
+            "Skipped" => TargetStatus::Skipped,
+            "Unknown" => TargetStatus::UnknownValue,
+            "Ready" => TargetStatus::Ready,
+            _ => TargetStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TargetStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TargetStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TargetStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TargetStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TargetStatus {
+    fn deserialize<D>(deserializer: D) -> Result<TargetStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A configuration that shifts traffic from one version of a Lambda function to another in two increments. The original and target Lambda function versions are specified in the deployment's AppSpec file.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TimeBasedCanary {
@@ -2166,12 +5432,22 @@ pub struct TimeBasedLinear {
 pub struct TimeRange {
     /// <p><p>The end time of the time range.</p> <note> <p>Specify null to leave the end time open-ended.</p> </note></p>
     #[serde(rename = "end")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end: Option<f64>,
+    pub end: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>The start time of the time range.</p> <note> <p>Specify null to leave the start time open-ended.</p> </note></p>
     #[serde(rename = "start")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start: Option<f64>,
+    pub start: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p> Information about a listener. The listener contains the path used to route traffic that is received from the load balancer to a target group. </p>
@@ -2197,16 +5473,114 @@ pub struct TrafficRoutingConfig {
     /// <p>The type of traffic shifting (<code>TimeBasedCanary</code> or <code>TimeBasedLinear</code>) used by a deployment configuration .</p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<TrafficRoutingType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TrafficRoutingType {
+    /// `TimeBasedCanary`
+    TimeBasedCanary,
+    /// `TimeBasedLinear`
+    TimeBasedLinear,
+    /// `AllAtOnce`
+    AllAtOnce,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TrafficRoutingType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TrafficRoutingType::TimeBasedCanary => "TimeBasedCanary",
+            TrafficRoutingType::TimeBasedLinear => "TimeBasedLinear",
+            TrafficRoutingType::AllAtOnce => "AllAtOnce",
+            TrafficRoutingType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TrafficRoutingType {
+    fn default() -> TrafficRoutingType {
+        TrafficRoutingType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TrafficRoutingType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TrafficRoutingType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TrafficRoutingType {
+    fn from(value: &'a str) -> TrafficRoutingType {
+        match value {
+            "TimeBasedCanary" => TrafficRoutingType::TimeBasedCanary,
+            "TimeBasedLinear" => TrafficRoutingType::TimeBasedLinear,
+            "AllAtOnce" => TrafficRoutingType::AllAtOnce,
+            _ => TrafficRoutingType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TrafficRoutingType {
+    fn from(value: String) -> TrafficRoutingType {
+        match value.as_str() {
+            "TimeBasedCanary" => TrafficRoutingType::TimeBasedCanary,
+            "TimeBasedLinear" => TrafficRoutingType::TimeBasedLinear,
+            "AllAtOnce" => TrafficRoutingType::AllAtOnce,
+            _ => TrafficRoutingType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TrafficRoutingType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TrafficRoutingType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TrafficRoutingType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TrafficRoutingType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for TrafficRoutingType {
+    fn deserialize<D>(deserializer: D) -> Result<TrafficRoutingType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about notification triggers for the deployment group.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TriggerConfig {
     /// <p>The event type or types for which notifications are triggered.</p>
     #[serde(rename = "triggerEvents")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub trigger_events: Option<Vec<String>>,
+    pub trigger_events: Option<Vec<TriggerEventType>>,
     /// <p>The name of the notification trigger.</p>
     #[serde(rename = "triggerName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2217,6 +5591,139 @@ pub struct TriggerConfig {
     pub trigger_target_arn: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TriggerEventType {
+    /// `DeploymentStart`
+    DeploymentStart,
+    /// `DeploymentSuccess`
+    DeploymentSuccess,
+    /// `DeploymentFailure`
+    DeploymentFailure,
+    /// `DeploymentStop`
+    DeploymentStop,
+    /// `DeploymentRollback`
+    DeploymentRollback,
+    /// `DeploymentReady`
+    DeploymentReady,
+    /// `InstanceStart`
+    InstanceStart,
+    /// `InstanceSuccess`
+    InstanceSuccess,
+    /// `InstanceFailure`
+    InstanceFailure,
+    /// `InstanceReady`
+    InstanceReady,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TriggerEventType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TriggerEventType::DeploymentStart => "DeploymentStart",
+            TriggerEventType::DeploymentSuccess => "DeploymentSuccess",
+            TriggerEventType::DeploymentFailure => "DeploymentFailure",
+            TriggerEventType::DeploymentStop => "DeploymentStop",
+            TriggerEventType::DeploymentRollback => "DeploymentRollback",
+            TriggerEventType::DeploymentReady => "DeploymentReady",
+            TriggerEventType::InstanceStart => "InstanceStart",
+            TriggerEventType::InstanceSuccess => "InstanceSuccess",
+            TriggerEventType::InstanceFailure => "InstanceFailure",
+            TriggerEventType::InstanceReady => "InstanceReady",
+            TriggerEventType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TriggerEventType {
+    fn default() -> TriggerEventType {
+        TriggerEventType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TriggerEventType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TriggerEventType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TriggerEventType {
+    fn from(value: &'a str) -> TriggerEventType {
+        match value {
+            "DeploymentStart" => TriggerEventType::DeploymentStart,
+            "DeploymentSuccess" => TriggerEventType::DeploymentSuccess,
+            "DeploymentFailure" => TriggerEventType::DeploymentFailure,
+            "DeploymentStop" => TriggerEventType::DeploymentStop,
+            "DeploymentRollback" => TriggerEventType::DeploymentRollback,
+            "DeploymentReady" => TriggerEventType::DeploymentReady,
+            "InstanceStart" => TriggerEventType::InstanceStart,
+            "InstanceSuccess" => TriggerEventType::InstanceSuccess,
+            "InstanceFailure" => TriggerEventType::InstanceFailure,
+            "InstanceReady" => TriggerEventType::InstanceReady,
+            _ => TriggerEventType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TriggerEventType {
+    fn from(value: String) -> TriggerEventType {
+        match value.as_str() {
+            "DeploymentStart" => TriggerEventType::DeploymentStart,
+            "DeploymentSuccess" => TriggerEventType::DeploymentSuccess,
+            "DeploymentFailure" => TriggerEventType::DeploymentFailure,
+            "DeploymentStop" => TriggerEventType::DeploymentStop,
+            "DeploymentRollback" => TriggerEventType::DeploymentRollback,
+            "DeploymentReady" => TriggerEventType::DeploymentReady,
+            "InstanceStart" => TriggerEventType::InstanceStart,
+            "InstanceSuccess" => TriggerEventType::InstanceSuccess,
+            "InstanceFailure" => TriggerEventType::InstanceFailure,
+            "InstanceReady" => TriggerEventType::InstanceReady,
+            _ => TriggerEventType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TriggerEventType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TriggerEventType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TriggerEventType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TriggerEventType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TriggerEventType {
+    fn deserialize<D>(deserializer: D) -> Result<TriggerEventType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct UntagResourceInput {
@@ -6548,11 +10055,12 @@ pub struct CodeDeployClient {
 impl CodeDeployClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> CodeDeployClient {
         CodeDeployClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "CodeDeploy"),
         }
     }
 
@@ -6584,6 +10092,7 @@ impl CodeDeploy for CodeDeployClient {
         input: AddTagsToOnPremisesInstancesInput,
     ) -> Result<(), RusotoError<AddTagsToOnPremisesInstancesError>> {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("AddTagsToOnPremisesInstances");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6615,6 +10124,7 @@ impl CodeDeploy for CodeDeployClient {
     ) -> Result<BatchGetApplicationRevisionsOutput, RusotoError<BatchGetApplicationRevisionsError>>
     {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("BatchGetApplicationRevisions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6646,6 +10156,7 @@ impl CodeDeploy for CodeDeployClient {
         input: BatchGetApplicationsInput,
     ) -> Result<BatchGetApplicationsOutput, RusotoError<BatchGetApplicationsError>> {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("BatchGetApplications");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeDeploy_20141006.BatchGetApplications");
@@ -6674,6 +10185,7 @@ impl CodeDeploy for CodeDeployClient {
         input: BatchGetDeploymentGroupsInput,
     ) -> Result<BatchGetDeploymentGroupsOutput, RusotoError<BatchGetDeploymentGroupsError>> {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("BatchGetDeploymentGroups");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6706,6 +10218,7 @@ impl CodeDeploy for CodeDeployClient {
     ) -> Result<BatchGetDeploymentInstancesOutput, RusotoError<BatchGetDeploymentInstancesError>>
     {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("BatchGetDeploymentInstances");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6737,6 +10250,7 @@ impl CodeDeploy for CodeDeployClient {
         input: BatchGetDeploymentTargetsInput,
     ) -> Result<BatchGetDeploymentTargetsOutput, RusotoError<BatchGetDeploymentTargetsError>> {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("BatchGetDeploymentTargets");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6768,6 +10282,7 @@ impl CodeDeploy for CodeDeployClient {
         input: BatchGetDeploymentsInput,
     ) -> Result<BatchGetDeploymentsOutput, RusotoError<BatchGetDeploymentsError>> {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("BatchGetDeployments");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeDeploy_20141006.BatchGetDeployments");
@@ -6797,6 +10312,7 @@ impl CodeDeploy for CodeDeployClient {
     ) -> Result<BatchGetOnPremisesInstancesOutput, RusotoError<BatchGetOnPremisesInstancesError>>
     {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("BatchGetOnPremisesInstances");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6828,6 +10344,7 @@ impl CodeDeploy for CodeDeployClient {
         input: ContinueDeploymentInput,
     ) -> Result<(), RusotoError<ContinueDeploymentError>> {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("ContinueDeployment");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeDeploy_20141006.ContinueDeployment");
@@ -6855,6 +10372,7 @@ impl CodeDeploy for CodeDeployClient {
         input: CreateApplicationInput,
     ) -> Result<CreateApplicationOutput, RusotoError<CreateApplicationError>> {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("CreateApplication");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeDeploy_20141006.CreateApplication");
@@ -6882,6 +10400,7 @@ impl CodeDeploy for CodeDeployClient {
         input: CreateDeploymentInput,
     ) -> Result<CreateDeploymentOutput, RusotoError<CreateDeploymentError>> {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("CreateDeployment");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeDeploy_20141006.CreateDeployment");
@@ -6909,6 +10428,7 @@ impl CodeDeploy for CodeDeployClient {
         input: CreateDeploymentConfigInput,
     ) -> Result<CreateDeploymentConfigOutput, RusotoError<CreateDeploymentConfigError>> {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("CreateDeploymentConfig");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeDeploy_20141006.CreateDeploymentConfig");
@@ -6937,6 +10457,7 @@ impl CodeDeploy for CodeDeployClient {
         input: CreateDeploymentGroupInput,
     ) -> Result<CreateDeploymentGroupOutput, RusotoError<CreateDeploymentGroupError>> {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("CreateDeploymentGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeDeploy_20141006.CreateDeploymentGroup");
@@ -6965,6 +10486,7 @@ impl CodeDeploy for CodeDeployClient {
         input: DeleteApplicationInput,
     ) -> Result<(), RusotoError<DeleteApplicationError>> {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("DeleteApplication");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeDeploy_20141006.DeleteApplication");
@@ -6992,6 +10514,7 @@ impl CodeDeploy for CodeDeployClient {
         input: DeleteDeploymentConfigInput,
     ) -> Result<(), RusotoError<DeleteDeploymentConfigError>> {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("DeleteDeploymentConfig");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeDeploy_20141006.DeleteDeploymentConfig");
@@ -7019,6 +10542,7 @@ impl CodeDeploy for CodeDeployClient {
         input: DeleteDeploymentGroupInput,
     ) -> Result<DeleteDeploymentGroupOutput, RusotoError<DeleteDeploymentGroupError>> {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("DeleteDeploymentGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeDeploy_20141006.DeleteDeploymentGroup");
@@ -7047,6 +10571,7 @@ impl CodeDeploy for CodeDeployClient {
         input: DeleteGitHubAccountTokenInput,
     ) -> Result<DeleteGitHubAccountTokenOutput, RusotoError<DeleteGitHubAccountTokenError>> {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("DeleteGitHubAccountToken");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7078,6 +10603,7 @@ impl CodeDeploy for CodeDeployClient {
         input: DeregisterOnPremisesInstanceInput,
     ) -> Result<(), RusotoError<DeregisterOnPremisesInstanceError>> {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("DeregisterOnPremisesInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7108,6 +10634,7 @@ impl CodeDeploy for CodeDeployClient {
         input: GetApplicationInput,
     ) -> Result<GetApplicationOutput, RusotoError<GetApplicationError>> {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("GetApplication");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeDeploy_20141006.GetApplication");
@@ -7135,6 +10662,7 @@ impl CodeDeploy for CodeDeployClient {
         input: GetApplicationRevisionInput,
     ) -> Result<GetApplicationRevisionOutput, RusotoError<GetApplicationRevisionError>> {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("GetApplicationRevision");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeDeploy_20141006.GetApplicationRevision");
@@ -7163,6 +10691,7 @@ impl CodeDeploy for CodeDeployClient {
         input: GetDeploymentInput,
     ) -> Result<GetDeploymentOutput, RusotoError<GetDeploymentError>> {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("GetDeployment");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeDeploy_20141006.GetDeployment");
@@ -7190,6 +10719,7 @@ impl CodeDeploy for CodeDeployClient {
         input: GetDeploymentConfigInput,
     ) -> Result<GetDeploymentConfigOutput, RusotoError<GetDeploymentConfigError>> {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("GetDeploymentConfig");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeDeploy_20141006.GetDeploymentConfig");
@@ -7218,6 +10748,7 @@ impl CodeDeploy for CodeDeployClient {
         input: GetDeploymentGroupInput,
     ) -> Result<GetDeploymentGroupOutput, RusotoError<GetDeploymentGroupError>> {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("GetDeploymentGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeDeploy_20141006.GetDeploymentGroup");
@@ -7246,6 +10777,7 @@ impl CodeDeploy for CodeDeployClient {
         input: GetDeploymentInstanceInput,
     ) -> Result<GetDeploymentInstanceOutput, RusotoError<GetDeploymentInstanceError>> {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("GetDeploymentInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeDeploy_20141006.GetDeploymentInstance");
@@ -7274,6 +10806,7 @@ impl CodeDeploy for CodeDeployClient {
         input: GetDeploymentTargetInput,
     ) -> Result<GetDeploymentTargetOutput, RusotoError<GetDeploymentTargetError>> {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("GetDeploymentTarget");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeDeploy_20141006.GetDeploymentTarget");
@@ -7302,6 +10835,7 @@ impl CodeDeploy for CodeDeployClient {
         input: GetOnPremisesInstanceInput,
     ) -> Result<GetOnPremisesInstanceOutput, RusotoError<GetOnPremisesInstanceError>> {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("GetOnPremisesInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeDeploy_20141006.GetOnPremisesInstance");
@@ -7330,6 +10864,7 @@ impl CodeDeploy for CodeDeployClient {
         input: ListApplicationRevisionsInput,
     ) -> Result<ListApplicationRevisionsOutput, RusotoError<ListApplicationRevisionsError>> {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("ListApplicationRevisions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7361,6 +10896,7 @@ impl CodeDeploy for CodeDeployClient {
         input: ListApplicationsInput,
     ) -> Result<ListApplicationsOutput, RusotoError<ListApplicationsError>> {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("ListApplications");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeDeploy_20141006.ListApplications");
@@ -7388,6 +10924,7 @@ impl CodeDeploy for CodeDeployClient {
         input: ListDeploymentConfigsInput,
     ) -> Result<ListDeploymentConfigsOutput, RusotoError<ListDeploymentConfigsError>> {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("ListDeploymentConfigs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeDeploy_20141006.ListDeploymentConfigs");
@@ -7416,6 +10953,7 @@ impl CodeDeploy for CodeDeployClient {
         input: ListDeploymentGroupsInput,
     ) -> Result<ListDeploymentGroupsOutput, RusotoError<ListDeploymentGroupsError>> {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("ListDeploymentGroups");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeDeploy_20141006.ListDeploymentGroups");
@@ -7444,6 +10982,7 @@ impl CodeDeploy for CodeDeployClient {
         input: ListDeploymentInstancesInput,
     ) -> Result<ListDeploymentInstancesOutput, RusotoError<ListDeploymentInstancesError>> {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("ListDeploymentInstances");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7475,6 +11014,7 @@ impl CodeDeploy for CodeDeployClient {
         input: ListDeploymentTargetsInput,
     ) -> Result<ListDeploymentTargetsOutput, RusotoError<ListDeploymentTargetsError>> {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("ListDeploymentTargets");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeDeploy_20141006.ListDeploymentTargets");
@@ -7503,6 +11043,7 @@ impl CodeDeploy for CodeDeployClient {
         input: ListDeploymentsInput,
     ) -> Result<ListDeploymentsOutput, RusotoError<ListDeploymentsError>> {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("ListDeployments");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeDeploy_20141006.ListDeployments");
@@ -7531,6 +11072,7 @@ impl CodeDeploy for CodeDeployClient {
     ) -> Result<ListGitHubAccountTokenNamesOutput, RusotoError<ListGitHubAccountTokenNamesError>>
     {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("ListGitHubAccountTokenNames");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7562,6 +11104,7 @@ impl CodeDeploy for CodeDeployClient {
         input: ListOnPremisesInstancesInput,
     ) -> Result<ListOnPremisesInstancesOutput, RusotoError<ListOnPremisesInstancesError>> {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("ListOnPremisesInstances");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7593,6 +11136,7 @@ impl CodeDeploy for CodeDeployClient {
         input: ListTagsForResourceInput,
     ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeDeploy_20141006.ListTagsForResource");
@@ -7624,6 +11168,7 @@ impl CodeDeploy for CodeDeployClient {
         RusotoError<PutLifecycleEventHookExecutionStatusError>,
     > {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("PutLifecycleEventHookExecutionStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7657,6 +11202,7 @@ impl CodeDeploy for CodeDeployClient {
         input: RegisterApplicationRevisionInput,
     ) -> Result<(), RusotoError<RegisterApplicationRevisionError>> {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("RegisterApplicationRevision");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7687,6 +11233,7 @@ impl CodeDeploy for CodeDeployClient {
         input: RegisterOnPremisesInstanceInput,
     ) -> Result<(), RusotoError<RegisterOnPremisesInstanceError>> {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("RegisterOnPremisesInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7717,6 +11264,7 @@ impl CodeDeploy for CodeDeployClient {
         input: RemoveTagsFromOnPremisesInstancesInput,
     ) -> Result<(), RusotoError<RemoveTagsFromOnPremisesInstancesError>> {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("RemoveTagsFromOnPremisesInstances");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7749,6 +11297,7 @@ impl CodeDeploy for CodeDeployClient {
         input: SkipWaitTimeForInstanceTerminationInput,
     ) -> Result<(), RusotoError<SkipWaitTimeForInstanceTerminationError>> {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("SkipWaitTimeForInstanceTermination");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7781,6 +11330,7 @@ impl CodeDeploy for CodeDeployClient {
         input: StopDeploymentInput,
     ) -> Result<StopDeploymentOutput, RusotoError<StopDeploymentError>> {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("StopDeployment");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeDeploy_20141006.StopDeployment");
@@ -7808,6 +11358,7 @@ impl CodeDeploy for CodeDeployClient {
         input: TagResourceInput,
     ) -> Result<TagResourceOutput, RusotoError<TagResourceError>> {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeDeploy_20141006.TagResource");
@@ -7835,6 +11386,7 @@ impl CodeDeploy for CodeDeployClient {
         input: UntagResourceInput,
     ) -> Result<UntagResourceOutput, RusotoError<UntagResourceError>> {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeDeploy_20141006.UntagResource");
@@ -7862,6 +11414,7 @@ impl CodeDeploy for CodeDeployClient {
         input: UpdateApplicationInput,
     ) -> Result<(), RusotoError<UpdateApplicationError>> {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("UpdateApplication");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeDeploy_20141006.UpdateApplication");
@@ -7889,6 +11442,7 @@ impl CodeDeploy for CodeDeployClient {
         input: UpdateDeploymentGroupInput,
     ) -> Result<UpdateDeploymentGroupOutput, RusotoError<UpdateDeploymentGroupError>> {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("UpdateDeploymentGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeDeploy_20141006.UpdateDeploymentGroup");
@@ -7911,3 +11465,359 @@ impl CodeDeploy for CodeDeployClient {
         }
     }
 }
+impl CodeDeployClient {
+    /// Auto-paginating version of `list_application_revisions`, streaming every page of results.
+    pub fn list_application_revisions_pages(
+        &self,
+        input: ListApplicationRevisionsInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListApplicationRevisionsOutput,
+        ListApplicationRevisionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeDeployClient, input: ListApplicationRevisionsInput| async move {
+                client.list_application_revisions(input).await
+            },
+            |input: &ListApplicationRevisionsInput, output: &ListApplicationRevisionsOutput| {
+                Some(ListApplicationRevisionsInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_application_revisions`, streaming the `revisions` of every page of results.
+    pub fn list_application_revisions_items(
+        &self,
+        input: ListApplicationRevisionsInput,
+    ) -> rusoto_core::pagination::RusotoStream<RevisionLocation, ListApplicationRevisionsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_application_revisions_pages(input),
+            |output: ListApplicationRevisionsOutput| output.revisions.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_applications`, streaming every page of results.
+    pub fn list_applications_pages(
+        &self,
+        input: ListApplicationsInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListApplicationsOutput, ListApplicationsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeDeployClient, input: ListApplicationsInput| async move {
+                client.list_applications(input).await
+            },
+            |_input: &ListApplicationsInput, output: &ListApplicationsOutput| {
+                Some(ListApplicationsInput {
+                    next_token: Some(output.next_token.clone()?),
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_applications`, streaming the `applications` of every page of results.
+    pub fn list_applications_items(
+        &self,
+        input: ListApplicationsInput,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListApplicationsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_applications_pages(input),
+            |output: ListApplicationsOutput| output.applications.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_deployment_configs`, streaming every page of results.
+    pub fn list_deployment_configs_pages(
+        &self,
+        input: ListDeploymentConfigsInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListDeploymentConfigsOutput,
+        ListDeploymentConfigsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeDeployClient, input: ListDeploymentConfigsInput| async move {
+                client.list_deployment_configs(input).await
+            },
+            |_input: &ListDeploymentConfigsInput, output: &ListDeploymentConfigsOutput| {
+                Some(ListDeploymentConfigsInput {
+                    next_token: Some(output.next_token.clone()?),
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_deployment_configs`, streaming the `deploymentConfigsList` of every page of results.
+    pub fn list_deployment_configs_items(
+        &self,
+        input: ListDeploymentConfigsInput,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListDeploymentConfigsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_deployment_configs_pages(input),
+            |output: ListDeploymentConfigsOutput| {
+                output.deployment_configs_list.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_deployment_groups`, streaming every page of results.
+    pub fn list_deployment_groups_pages(
+        &self,
+        input: ListDeploymentGroupsInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListDeploymentGroupsOutput, ListDeploymentGroupsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeDeployClient, input: ListDeploymentGroupsInput| async move {
+                client.list_deployment_groups(input).await
+            },
+            |input: &ListDeploymentGroupsInput, output: &ListDeploymentGroupsOutput| {
+                Some(ListDeploymentGroupsInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_deployment_groups`, streaming the `deploymentGroups` of every page of results.
+    pub fn list_deployment_groups_items(
+        &self,
+        input: ListDeploymentGroupsInput,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListDeploymentGroupsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_deployment_groups_pages(input),
+            |output: ListDeploymentGroupsOutput| output.deployment_groups.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_deployment_instances`, streaming every page of results.
+    pub fn list_deployment_instances_pages(
+        &self,
+        input: ListDeploymentInstancesInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListDeploymentInstancesOutput,
+        ListDeploymentInstancesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeDeployClient, input: ListDeploymentInstancesInput| async move {
+                client.list_deployment_instances(input).await
+            },
+            |input: &ListDeploymentInstancesInput, output: &ListDeploymentInstancesOutput| {
+                Some(ListDeploymentInstancesInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_deployment_instances`, streaming the `instancesList` of every page of results.
+    pub fn list_deployment_instances_items(
+        &self,
+        input: ListDeploymentInstancesInput,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListDeploymentInstancesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_deployment_instances_pages(input),
+            |output: ListDeploymentInstancesOutput| output.instances_list.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_deployment_targets`, streaming every page of results.
+    pub fn list_deployment_targets_pages(
+        &self,
+        input: ListDeploymentTargetsInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListDeploymentTargetsOutput,
+        ListDeploymentTargetsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeDeployClient, input: ListDeploymentTargetsInput| async move {
+                client.list_deployment_targets(input).await
+            },
+            |input: &ListDeploymentTargetsInput, output: &ListDeploymentTargetsOutput| {
+                Some(ListDeploymentTargetsInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_deployment_targets`, streaming the `targetIds` of every page of results.
+    pub fn list_deployment_targets_items(
+        &self,
+        input: ListDeploymentTargetsInput,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListDeploymentTargetsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_deployment_targets_pages(input),
+            |output: ListDeploymentTargetsOutput| output.target_ids.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_deployments`, streaming every page of results.
+    pub fn list_deployments_pages(
+        &self,
+        input: ListDeploymentsInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListDeploymentsOutput, ListDeploymentsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeDeployClient, input: ListDeploymentsInput| async move {
+                client.list_deployments(input).await
+            },
+            |input: &ListDeploymentsInput, output: &ListDeploymentsOutput| {
+                Some(ListDeploymentsInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_deployments`, streaming the `deployments` of every page of results.
+    pub fn list_deployments_items(
+        &self,
+        input: ListDeploymentsInput,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListDeploymentsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_deployments_pages(input),
+            |output: ListDeploymentsOutput| output.deployments.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_git_hub_account_token_names`, streaming every page of results.
+    pub fn list_git_hub_account_token_names_pages(
+        &self,
+        input: ListGitHubAccountTokenNamesInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListGitHubAccountTokenNamesOutput,
+        ListGitHubAccountTokenNamesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeDeployClient, input: ListGitHubAccountTokenNamesInput| async move {
+                client.list_git_hub_account_token_names(input).await
+            },
+            |_input: &ListGitHubAccountTokenNamesInput,
+             output: &ListGitHubAccountTokenNamesOutput| {
+                Some(ListGitHubAccountTokenNamesInput {
+                    next_token: Some(output.next_token.clone()?),
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_git_hub_account_token_names`, streaming the `tokenNameList` of every page of results.
+    pub fn list_git_hub_account_token_names_items(
+        &self,
+        input: ListGitHubAccountTokenNamesInput,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListGitHubAccountTokenNamesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_git_hub_account_token_names_pages(input),
+            |output: ListGitHubAccountTokenNamesOutput| output.token_name_list.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_on_premises_instances`, streaming every page of results.
+    pub fn list_on_premises_instances_pages(
+        &self,
+        input: ListOnPremisesInstancesInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListOnPremisesInstancesOutput,
+        ListOnPremisesInstancesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeDeployClient, input: ListOnPremisesInstancesInput| async move {
+                client.list_on_premises_instances(input).await
+            },
+            |input: &ListOnPremisesInstancesInput, output: &ListOnPremisesInstancesOutput| {
+                Some(ListOnPremisesInstancesInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_on_premises_instances`, streaming the `instanceNames` of every page of results.
+    pub fn list_on_premises_instances_items(
+        &self,
+        input: ListOnPremisesInstancesInput,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListOnPremisesInstancesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_on_premises_instances_pages(input),
+            |output: ListOnPremisesInstancesOutput| output.instance_names.unwrap_or_default(),
+        )
+    }
+}
+impl CodeDeployClient {
+    /// Waits until `get_deployment` reports the `DeploymentSuccessful` state, polling every 15 seconds up to 120 times.
+    pub async fn wait_until_deployment_successful(
+        &self,
+        input: GetDeploymentInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetDeploymentError>> {
+        let config =
+            rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(15), 120);
+        self.wait_until_deployment_successful_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_deployment_successful`, polling as often and as many times as `config` says.
+    pub async fn wait_until_deployment_successful_with_config(
+        &self,
+        input: GetDeploymentInput,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetDeploymentError>> {
+        rusoto_core::waiter::wait(
+            "DeploymentSuccessful",
+            &config,
+            || self.get_deployment(input.clone()),
+            |result: &Result<GetDeploymentOutput, RusotoError<GetDeploymentError>>| match result {
+                Ok(output)
+                    if output
+                        .deployment_info
+                        .iter()
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| *v == DeploymentStatus::Succeeded) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if output
+                        .deployment_info
+                        .iter()
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| *v == DeploymentStatus::Failed) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .deployment_info
+                        .iter()
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| *v == DeploymentStatus::Stopped) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+}
@@ -111,11 +111,11 @@ pub struct ListRepositoryAssociationsRequest {
     /// <p>List of provider types to use as a filter.</p>
     #[serde(rename = "ProviderTypes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub provider_types: Option<Vec<String>>,
+    pub provider_types: Option<Vec<ProviderType>>,
     /// <p>List of states to use as a filter.</p>
     #[serde(rename = "States")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub states: Option<Vec<String>>,
+    pub states: Option<Vec<RepositoryAssociationState>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -131,6 +131,114 @@ pub struct ListRepositoryAssociationsResponse {
     pub repository_association_summaries: Option<Vec<RepositoryAssociationSummary>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ProviderType {
+    /// `CodeCommit`
+    CodeCommit,
+    /// `GitHub`
+    GitHub,
+    /// `Bitbucket`
+    Bitbucket,
+    /// `GitHubEnterpriseServer`
+    GitHubEnterpriseServer,
+    /// `S3Bucket`
+    S3Bucket,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ProviderType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ProviderType::CodeCommit => "CodeCommit",
+            ProviderType::GitHub => "GitHub",
+            ProviderType::Bitbucket => "Bitbucket",
+            ProviderType::GitHubEnterpriseServer => "GitHubEnterpriseServer",
+            ProviderType::S3Bucket => "S3Bucket",
+            ProviderType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ProviderType {
+    fn default() -> ProviderType {
+        ProviderType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ProviderType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ProviderType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ProviderType {
+    fn from(value: &'a str) -> ProviderType {
+        match value {
+            "CodeCommit" => ProviderType::CodeCommit,
+            "GitHub" => ProviderType::GitHub,
+            "Bitbucket" => ProviderType::Bitbucket,
+            "GitHubEnterpriseServer" => ProviderType::GitHubEnterpriseServer,
+            "S3Bucket" => ProviderType::S3Bucket,
+            _ => ProviderType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ProviderType {
+    fn from(value: String) -> ProviderType {
+        match value.as_str() {
+            "CodeCommit" => ProviderType::CodeCommit,
+            "GitHub" => ProviderType::GitHub,
+            "Bitbucket" => ProviderType::Bitbucket,
+            "GitHubEnterpriseServer" => ProviderType::GitHubEnterpriseServer,
+            "S3Bucket" => ProviderType::S3Bucket,
+            _ => ProviderType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ProviderType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ProviderType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ProviderType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ProviderType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ProviderType {
+    fn deserialize<D>(deserializer: D) -> Result<ProviderType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about a repository.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -155,12 +263,22 @@ pub struct RepositoryAssociation {
     pub association_id: Option<String>,
     /// <p>The time, in milliseconds since the epoch, when the repository association was created.</p>
     #[serde(rename = "CreatedTimeStamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time_stamp: Option<f64>,
+    pub created_time_stamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The time, in milliseconds since the epoch, when the repository association was last updated.</p>
     #[serde(rename = "LastUpdatedTimeStamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_time_stamp: Option<f64>,
+    pub last_updated_time_stamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the repository.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -172,17 +290,125 @@ pub struct RepositoryAssociation {
     /// <p>The provider type of the repository association.</p>
     #[serde(rename = "ProviderType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub provider_type: Option<String>,
+    pub provider_type: Option<ProviderType>,
     /// <p>The state of the repository association.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<RepositoryAssociationState>,
     /// <p>A description of why the repository association is in the current state.</p>
     #[serde(rename = "StateReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub state_reason: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RepositoryAssociationState {
+    /// `Associated`
+    Associated,
+    /// `Associating`
+    Associating,
+    /// `Failed`
+    Failed,
+    /// `Disassociating`
+    Disassociating,
+    /// `Disassociated`
+    Disassociated,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RepositoryAssociationState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RepositoryAssociationState::Associated => "Associated",
+            RepositoryAssociationState::Associating => "Associating",
+            RepositoryAssociationState::Failed => "Failed",
+            RepositoryAssociationState::Disassociating => "Disassociating",
+            RepositoryAssociationState::Disassociated => "Disassociated",
+            RepositoryAssociationState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RepositoryAssociationState {
+    fn default() -> RepositoryAssociationState {
+        RepositoryAssociationState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RepositoryAssociationState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RepositoryAssociationState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RepositoryAssociationState {
+    fn from(value: &'a str) -> RepositoryAssociationState {
+        match value {
+            "Associated" => RepositoryAssociationState::Associated,
+            "Associating" => RepositoryAssociationState::Associating,
+            "Failed" => RepositoryAssociationState::Failed,
+            "Disassociating" => RepositoryAssociationState::Disassociating,
+            "Disassociated" => RepositoryAssociationState::Disassociated,
+            _ => RepositoryAssociationState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RepositoryAssociationState {
+    fn from(value: String) -> RepositoryAssociationState {
+        match value.as_str() {
+            "Associated" => RepositoryAssociationState::Associated,
+            "Associating" => RepositoryAssociationState::Associating,
+            "Failed" => RepositoryAssociationState::Failed,
+            "Disassociating" => RepositoryAssociationState::Disassociating,
+            "Disassociated" => RepositoryAssociationState::Disassociated,
+            _ => RepositoryAssociationState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RepositoryAssociationState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RepositoryAssociationState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RepositoryAssociationState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RepositoryAssociationState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RepositoryAssociationState {
+    fn deserialize<D>(deserializer: D) -> Result<RepositoryAssociationState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about a repository association.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -197,8 +423,13 @@ pub struct RepositoryAssociationSummary {
     pub association_id: Option<String>,
     /// <p>The time, in milliseconds since the epoch, since the repository association was last updated. </p>
     #[serde(rename = "LastUpdatedTimeStamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_time_stamp: Option<f64>,
+    pub last_updated_time_stamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the repository association.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -210,11 +441,11 @@ pub struct RepositoryAssociationSummary {
     /// <p>The provider type of the repository association.</p>
     #[serde(rename = "ProviderType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub provider_type: Option<String>,
+    pub provider_type: Option<ProviderType>,
     /// <p><p>The state of the repository association.</p> <dl> <dt>Associated</dt> <dd> <p>Amazon CodeGuru Reviewer is associated with the repository. </p> </dd> <dt>Associating</dt> <dd> <p>The association is in progress. </p> </dd> <dt>Failed</dt> <dd> <p>The association failed. For more information about troubleshooting (or why it failed), see [troubleshooting topic]. </p> </dd> <dt>Disassociating</dt> <dd> <p>Amazon CodeGuru Reviewer is in the process of disassociating with the repository. </p> </dd> </dl></p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<RepositoryAssociationState>,
 }
 
 /// Errors returned by AssociateRepository
@@ -461,11 +692,12 @@ pub struct CodeGuruReviewerClient {
 impl CodeGuruReviewerClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> CodeGuruReviewerClient {
         CodeGuruReviewerClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "CodeGuru Reviewer"),
         }
     }
 
@@ -500,6 +732,7 @@ impl CodeGuruReviewer for CodeGuruReviewerClient {
 
         let mut request =
             SignedRequest::new("POST", "codeguru-reviewer", &self.region, &request_uri);
+        request.set_operation("AssociateRepository");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -537,6 +770,7 @@ impl CodeGuruReviewer for CodeGuruReviewerClient {
 
         let mut request =
             SignedRequest::new("GET", "codeguru-reviewer", &self.region, &request_uri);
+        request.set_operation("DescribeRepositoryAssociation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -568,6 +802,7 @@ impl CodeGuruReviewer for CodeGuruReviewerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "codeguru-reviewer", &self.region, &request_uri);
+        request.set_operation("DisassociateRepository");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -597,6 +832,7 @@ impl CodeGuruReviewer for CodeGuruReviewerClient {
 
         let mut request =
             SignedRequest::new("GET", "codeguru-reviewer", &self.region, &request_uri);
+        request.set_operation("ListRepositoryAssociations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -645,3 +881,102 @@ impl CodeGuruReviewer for CodeGuruReviewerClient {
         }
     }
 }
+impl CodeGuruReviewerClient {
+    /// Auto-paginating version of `list_repository_associations`, streaming every page of results.
+    pub fn list_repository_associations_pages(
+        &self,
+        input: ListRepositoryAssociationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListRepositoryAssociationsResponse,
+        ListRepositoryAssociationsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeGuruReviewerClient, input: ListRepositoryAssociationsRequest| async move {
+                client.list_repository_associations(input).await
+            },
+            |input: &ListRepositoryAssociationsRequest,
+             output: &ListRepositoryAssociationsResponse| {
+                Some(ListRepositoryAssociationsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_repository_associations`, streaming the `RepositoryAssociationSummaries` of every page of results.
+    pub fn list_repository_associations_items(
+        &self,
+        input: ListRepositoryAssociationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        RepositoryAssociationSummary,
+        ListRepositoryAssociationsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_repository_associations_pages(input),
+            |output: ListRepositoryAssociationsResponse| {
+                output.repository_association_summaries.unwrap_or_default()
+            },
+        )
+    }
+}
+impl CodeGuruReviewerClient {
+    /// Waits until `describe_repository_association` reports the `RepositoryAssociationSucceeded` state, polling every 10 seconds up to 30 times.
+    pub async fn wait_until_repository_association_succeeded(
+        &self,
+        input: DescribeRepositoryAssociationRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeRepositoryAssociationError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(10), 30);
+        self.wait_until_repository_association_succeeded_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_repository_association_succeeded`, polling as often and as many times as `config` says.
+    pub async fn wait_until_repository_association_succeeded_with_config(
+        &self,
+        input: DescribeRepositoryAssociationRequest,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeRepositoryAssociationError>> {
+        rusoto_core::waiter::wait(
+            "RepositoryAssociationSucceeded",
+            &config,
+            || self.describe_repository_association(input.clone()),
+            |result: &Result<
+                DescribeRepositoryAssociationResponse,
+                RusotoError<DescribeRepositoryAssociationError>,
+            >| match result {
+                Ok(output)
+                    if output
+                        .repository_association
+                        .iter()
+                        .flat_map(|v| v.state.iter())
+                        .any(|v| *v == RepositoryAssociationState::Associated) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if output
+                        .repository_association
+                        .iter()
+                        .flat_map(|v| v.state.iter())
+                        .any(|v| *v == RepositoryAssociationState::Failed) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .repository_association
+                        .iter()
+                        .flat_map(|v| v.state.iter())
+                        .any(|v| *v == RepositoryAssociationState::Associating) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Retry)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+}
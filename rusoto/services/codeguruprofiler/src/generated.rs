@@ -52,13 +52,116 @@ pub struct AggregatedProfileTime {
     /// <p>The aggregation period of the aggregated profile.</p>
     #[serde(rename = "period")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub period: Option<String>,
+    pub period: Option<AggregationPeriod>,
     /// <p>The start time of the aggregated profile.</p>
     #[serde(rename = "start")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start: Option<f64>,
+    pub start: Option<::chrono::DateTime<::chrono::Utc>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AggregationPeriod {
+    /// `PT5M`
+    Pt5m,
+    /// `PT1H`
+    Pt1h,
+    /// `P1D`
+    P1d,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AggregationPeriod {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AggregationPeriod::Pt5m => "PT5M",
+            AggregationPeriod::Pt1h => "PT1H",
+            AggregationPeriod::P1d => "P1D",
+            AggregationPeriod::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AggregationPeriod {
+    fn default() -> AggregationPeriod {
+        AggregationPeriod::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AggregationPeriod {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AggregationPeriod {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AggregationPeriod {
+    fn from(value: &'a str) -> AggregationPeriod {
+        match value {
+            "PT5M" => AggregationPeriod::Pt5m,
+            "PT1H" => AggregationPeriod::Pt1h,
+            "P1D" => AggregationPeriod::P1d,
+            _ => AggregationPeriod::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AggregationPeriod {
+    fn from(value: String) -> AggregationPeriod {
+        match value.as_str() {
+            "PT5M" => AggregationPeriod::Pt5m,
+            "PT1H" => AggregationPeriod::Pt1h,
+            "P1D" => AggregationPeriod::P1d,
+            _ => AggregationPeriod::Unknown(value),
+        }
+    }
 }
 
+impl ::std::str::FromStr for AggregationPeriod {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AggregationPeriod, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AggregationPeriod {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AggregationPeriod {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AggregationPeriod {
+    fn deserialize<D>(deserializer: D) -> Result<AggregationPeriod, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Request for ConfigureAgent operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -139,8 +242,13 @@ pub struct GetProfileRequest {
     pub accept: Option<String>,
     /// <p>The end time of the profile to get. Either period or endTime must be specified. Must be greater than start and the overall time range to be in the past and not larger than a week.</p>
     #[serde(rename = "endTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     #[serde(rename = "maxDepth")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub max_depth: Option<i64>,
@@ -152,8 +260,13 @@ pub struct GetProfileRequest {
     pub profiling_group_name: String,
     /// <p>The start time of the profile to get.</p>
     #[serde(rename = "startTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>Response for GetProfile operation.</p>
@@ -172,7 +285,12 @@ pub struct GetProfileResponse {
 pub struct ListProfileTimesRequest {
     /// <p>The end time of the time range to list profiles until.</p>
     #[serde(rename = "endTime")]
-    pub end_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
+    pub end_time: ::chrono::DateTime<::chrono::Utc>,
     #[serde(rename = "maxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub max_results: Option<i64>,
@@ -182,15 +300,20 @@ pub struct ListProfileTimesRequest {
     /// <p>The order (ascending or descending by start time of the profile) to list the profiles by. Defaults to TIMESTAMP_DESCENDING.</p>
     #[serde(rename = "orderBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub order_by: Option<String>,
+    pub order_by: Option<OrderBy>,
     /// <p>The aggregation period to list the profiles for.</p>
     #[serde(rename = "period")]
-    pub period: String,
+    pub period: AggregationPeriod,
     #[serde(rename = "profilingGroupName")]
     pub profiling_group_name: String,
     /// <p>The start time of the time range to list the profiles from.</p>
     #[serde(rename = "startTime")]
-    pub start_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
+    pub start_time: ::chrono::DateTime<::chrono::Utc>,
 }
 
 /// <p>Response for ListProfileTimes operation.</p>
@@ -235,6 +358,99 @@ pub struct ListProfilingGroupsResponse {
     pub profiling_groups: Option<Vec<ProfilingGroupDescription>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OrderBy {
+    /// `TimestampDescending`
+    TimestampDescending,
+    /// `TimestampAscending`
+    TimestampAscending,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl OrderBy {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OrderBy::TimestampDescending => "TimestampDescending",
+            OrderBy::TimestampAscending => "TimestampAscending",
+            OrderBy::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for OrderBy {
+    fn default() -> OrderBy {
+        OrderBy::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for OrderBy {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for OrderBy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for OrderBy {
+    fn from(value: &'a str) -> OrderBy {
+        match value {
+            "TimestampDescending" => OrderBy::TimestampDescending,
+            "TimestampAscending" => OrderBy::TimestampAscending,
+            _ => OrderBy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OrderBy {
+    fn from(value: String) -> OrderBy {
+        match value.as_str() {
+            "TimestampDescending" => OrderBy::TimestampDescending,
+            "TimestampAscending" => OrderBy::TimestampAscending,
+            _ => OrderBy::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for OrderBy {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<OrderBy, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OrderBy {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for OrderBy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OrderBy {
+    fn deserialize<D>(deserializer: D) -> Result<OrderBy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Request for PostAgentProfile operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -268,8 +484,13 @@ pub struct PostAgentProfileResponse {}
 pub struct ProfileTime {
     /// <p>The start time of the profile.</p>
     #[serde(rename = "start")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start: Option<f64>,
+    pub start: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>The description of a profiling group.</p>
@@ -284,8 +505,13 @@ pub struct ProfilingGroupDescription {
     pub arn: Option<String>,
     /// <p>The timestamp of when the profiling group was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
@@ -294,8 +520,13 @@ pub struct ProfilingGroupDescription {
     pub profiling_status: Option<ProfilingStatus>,
     /// <p>The timestamp of when the profiling group was last updated.</p>
     #[serde(rename = "updatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<f64>,
+    pub updated_at: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>The status of profiling of a profiling group.</p>
@@ -304,12 +535,22 @@ pub struct ProfilingGroupDescription {
 pub struct ProfilingStatus {
     /// <p>Timestamp of when the last interaction of the agent with configureAgent API for orchestration.</p>
     #[serde(rename = "latestAgentOrchestratedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub latest_agent_orchestrated_at: Option<f64>,
+    pub latest_agent_orchestrated_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Timestamp of when the latest agent profile was successfully reported.</p>
     #[serde(rename = "latestAgentProfileReportedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub latest_agent_profile_reported_at: Option<f64>,
+    pub latest_agent_profile_reported_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The time range of latest aggregated profile available.</p>
     #[serde(rename = "latestAggregatedProfile")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -796,11 +1037,12 @@ pub struct CodeGuruProfilerClient {
 impl CodeGuruProfilerClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> CodeGuruProfilerClient {
         CodeGuruProfilerClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "CodeGuruProfiler"),
         }
     }
 
@@ -838,6 +1080,7 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("POST", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation("ConfigureAgent");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -869,6 +1112,7 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("POST", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation("CreateProfilingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -907,6 +1151,7 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation("DeleteProfilingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -938,6 +1183,7 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("GET", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation("DescribeProfilingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -969,6 +1215,7 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("GET", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation("GetProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         if let Some(ref accept) = input.accept {
@@ -976,7 +1223,10 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
         }
         let mut params = Params::new();
         if let Some(ref x) = input.end_time {
-            params.put("endTime", x);
+            params.put(
+                "endTime",
+                &rusoto_core::serialization::TimestampFormat::Iso8601.format(x),
+            );
         }
         if let Some(ref x) = input.max_depth {
             params.put("maxDepth", x);
@@ -985,7 +1235,10 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
             params.put("period", x);
         }
         if let Some(ref x) = input.start_time {
-            params.put("startTime", x);
+            params.put(
+                "startTime",
+                &rusoto_core::serialization::TimestampFormat::Iso8601.format(x),
+            );
         }
         request.set_params(params);
 
@@ -1026,10 +1279,14 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("GET", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation("ListProfileTimes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
-        params.put("endTime", &input.end_time);
+        params.put(
+            "endTime",
+            &rusoto_core::serialization::TimestampFormat::Iso8601.format(&input.end_time),
+        );
         if let Some(ref x) = input.max_results {
             params.put("maxResults", x);
         }
@@ -1040,7 +1297,10 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
             params.put("orderBy", x);
         }
         params.put("period", &input.period);
-        params.put("startTime", &input.start_time);
+        params.put(
+            "startTime",
+            &rusoto_core::serialization::TimestampFormat::Iso8601.format(&input.start_time),
+        );
         request.set_params(params);
 
         let mut response = self
@@ -1069,6 +1329,7 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("GET", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation("ListProfilingGroups");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1112,6 +1373,7 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("POST", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation("PostAgentProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(input.agent_profile.to_owned());
@@ -1152,6 +1414,7 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("PUT", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation("UpdateProfilingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1174,3 +1437,36 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
         }
     }
 }
+impl CodeGuruProfilerClient {
+    /// Auto-paginating version of `list_profile_times`, streaming every page of results.
+    pub fn list_profile_times_pages(
+        &self,
+        input: ListProfileTimesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListProfileTimesResponse, ListProfileTimesError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeGuruProfilerClient, input: ListProfileTimesRequest| async move {
+                client.list_profile_times(input).await
+            },
+            |input: &ListProfileTimesRequest, output: &ListProfileTimesResponse| {
+                Some(ListProfileTimesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_profile_times`, streaming the `profileTimes` of every page of results.
+    pub fn list_profile_times_items(
+        &self,
+        input: ListProfileTimesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ProfileTime, ListProfileTimesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_profile_times_pages(input),
+            |output: ListProfileTimesResponse| output.profile_times,
+        )
+    }
+}
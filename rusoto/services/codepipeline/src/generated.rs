@@ -58,7 +58,7 @@ pub struct AcknowledgeJobOutput {
     /// <p>Whether the job worker has received the specified job.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<JobStatus>,
 }
 
 /// <p>Represents the input of an AcknowledgeThirdPartyJob action.</p>
@@ -83,9 +83,122 @@ pub struct AcknowledgeThirdPartyJobOutput {
     /// <p>The status information for the third party job, if any.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<JobStatus>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ActionCategory {
+    /// `Source`
+    Source,
+    /// `Build`
+    Build,
+    /// `Deploy`
+    Deploy,
+    /// `Test`
+    Test,
+    /// `Invoke`
+    Invoke,
+    /// `Approval`
+    Approval,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ActionCategory {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ActionCategory::Source => "Source",
+            ActionCategory::Build => "Build",
+            ActionCategory::Deploy => "Deploy",
+            ActionCategory::Test => "Test",
+            ActionCategory::Invoke => "Invoke",
+            ActionCategory::Approval => "Approval",
+            ActionCategory::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ActionCategory {
+    fn default() -> ActionCategory {
+        ActionCategory::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ActionCategory {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ActionCategory {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ActionCategory {
+    fn from(value: &'a str) -> ActionCategory {
+        match value {
+            "Source" => ActionCategory::Source,
+            "Build" => ActionCategory::Build,
+            "Deploy" => ActionCategory::Deploy,
+            "Test" => ActionCategory::Test,
+            "Invoke" => ActionCategory::Invoke,
+            "Approval" => ActionCategory::Approval,
+            _ => ActionCategory::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ActionCategory {
+    fn from(value: String) -> ActionCategory {
+        match value.as_str() {
+            "Source" => ActionCategory::Source,
+            "Build" => ActionCategory::Build,
+            "Deploy" => ActionCategory::Deploy,
+            "Test" => ActionCategory::Test,
+            "Invoke" => ActionCategory::Invoke,
+            "Approval" => ActionCategory::Approval,
+            _ => ActionCategory::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ActionCategory {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ActionCategory, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ActionCategory {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ActionCategory {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for ActionCategory {
+    fn deserialize<D>(deserializer: D) -> Result<ActionCategory, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents information about an action configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -122,9 +235,107 @@ pub struct ActionConfigurationProperty {
     /// <p>The type of the configuration property.</p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<ActionConfigurationPropertyType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ActionConfigurationPropertyType {
+    /// `String`
+    String,
+    /// `Number`
+    Number,
+    /// `Boolean`
+    Boolean,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ActionConfigurationPropertyType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ActionConfigurationPropertyType::String => "String",
+            ActionConfigurationPropertyType::Number => "Number",
+            ActionConfigurationPropertyType::Boolean => "Boolean",
+            ActionConfigurationPropertyType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ActionConfigurationPropertyType {
+    fn default() -> ActionConfigurationPropertyType {
+        ActionConfigurationPropertyType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ActionConfigurationPropertyType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ActionConfigurationPropertyType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ActionConfigurationPropertyType {
+    fn from(value: &'a str) -> ActionConfigurationPropertyType {
+        match value {
+            "String" => ActionConfigurationPropertyType::String,
+            "Number" => ActionConfigurationPropertyType::Number,
+            "Boolean" => ActionConfigurationPropertyType::Boolean,
+            _ => ActionConfigurationPropertyType::Unknown(value.to_owned()),
+        }
+    }
 }
 
+impl From<String> for ActionConfigurationPropertyType {
+    fn from(value: String) -> ActionConfigurationPropertyType {
+        match value.as_str() {
+            "String" => ActionConfigurationPropertyType::String,
+            "Number" => ActionConfigurationPropertyType::Number,
+            "Boolean" => ActionConfigurationPropertyType::Boolean,
+            _ => ActionConfigurationPropertyType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ActionConfigurationPropertyType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ActionConfigurationPropertyType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ActionConfigurationPropertyType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ActionConfigurationPropertyType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ActionConfigurationPropertyType {
+    fn deserialize<D>(deserializer: D) -> Result<ActionConfigurationPropertyType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents the context of an action in the stage of a pipeline to a job worker.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -196,8 +407,13 @@ pub struct ActionExecution {
     pub external_execution_url: Option<String>,
     /// <p>The last status change of the action.</p>
     #[serde(rename = "lastStatusChange")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_status_change: Option<f64>,
+    pub last_status_change: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The ARN of the user who last changed the pipeline.</p>
     #[serde(rename = "lastUpdatedBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -209,7 +425,7 @@ pub struct ActionExecution {
     /// <p>The status of the action, or for a completed action, the last status of the action.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ActionExecutionStatus>,
     /// <p>A summary of the run of the action.</p>
     #[serde(rename = "summary")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -238,8 +454,13 @@ pub struct ActionExecutionDetail {
     pub input: Option<ActionExecutionInput>,
     /// <p>The last update time of the action execution.</p>
     #[serde(rename = "lastUpdateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_update_time: Option<f64>,
+    pub last_update_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Output details for the action execution, such as the action execution result.</p>
     #[serde(rename = "output")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -258,12 +479,17 @@ pub struct ActionExecutionDetail {
     pub stage_name: Option<String>,
     /// <p>The start time of the action execution.</p>
     #[serde(rename = "startTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p> The status of the action execution. Status categories are <code>InProgress</code>, <code>Succeeded</code>, and <code>Failed</code>.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ActionExecutionStatus>,
 }
 
 /// <p>Filter values for the action execution.</p>
@@ -345,12 +571,218 @@ pub struct ActionExecutionResult {
     pub external_execution_url: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ActionExecutionStatus {
+    /// `InProgress`
+    InProgress,
+    /// `Abandoned`
+    Abandoned,
+    /// `Succeeded`
+    Succeeded,
+    /// `Failed`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ActionExecutionStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ActionExecutionStatus::InProgress => "InProgress",
+            ActionExecutionStatus::Abandoned => "Abandoned",
+            ActionExecutionStatus::Succeeded => "Succeeded",
+            ActionExecutionStatus::Failed => "Failed",
+            ActionExecutionStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ActionExecutionStatus {
+    fn default() -> ActionExecutionStatus {
+        ActionExecutionStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ActionExecutionStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ActionExecutionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ActionExecutionStatus {
+    fn from(value: &'a str) -> ActionExecutionStatus {
+        match value {
+            "InProgress" => ActionExecutionStatus::InProgress,
+            "Abandoned" => ActionExecutionStatus::Abandoned,
+            "Succeeded" => ActionExecutionStatus::Succeeded,
+            "Failed" => ActionExecutionStatus::Failed,
+            _ => ActionExecutionStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ActionExecutionStatus {
+    fn from(value: String) -> ActionExecutionStatus {
+        match value.as_str() {
+            "InProgress" => ActionExecutionStatus::InProgress,
+            "Abandoned" => ActionExecutionStatus::Abandoned,
+            "Succeeded" => ActionExecutionStatus::Succeeded,
+            "Failed" => ActionExecutionStatus::Failed,
+            _ => ActionExecutionStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ActionExecutionStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ActionExecutionStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ActionExecutionStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ActionExecutionStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ActionExecutionStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ActionExecutionStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ActionOwner {
+    /// `AWS`
+    Aws,
+    /// `ThirdParty`
+    ThirdParty,
+    /// `Custom`
+    Custom,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ActionOwner {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ActionOwner::Aws => "AWS",
+            ActionOwner::ThirdParty => "ThirdParty",
+            ActionOwner::Custom => "Custom",
+            ActionOwner::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ActionOwner {
+    fn default() -> ActionOwner {
+        ActionOwner::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ActionOwner {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ActionOwner {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ActionOwner {
+    fn from(value: &'a str) -> ActionOwner {
+        match value {
+            "AWS" => ActionOwner::Aws,
+            "ThirdParty" => ActionOwner::ThirdParty,
+            "Custom" => ActionOwner::Custom,
+            _ => ActionOwner::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ActionOwner {
+    fn from(value: String) -> ActionOwner {
+        match value.as_str() {
+            "AWS" => ActionOwner::Aws,
+            "ThirdParty" => ActionOwner::ThirdParty,
+            "Custom" => ActionOwner::Custom,
+            _ => ActionOwner::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ActionOwner {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ActionOwner, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ActionOwner {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ActionOwner {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ActionOwner {
+    fn deserialize<D>(deserializer: D) -> Result<ActionOwner, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents information about the version (or revision) of an action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ActionRevision {
     /// <p>The date and time when the most recent version of the action was created, in timestamp format.</p>
     #[serde(rename = "created")]
-    pub created: Option<f64>,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub created: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The unique identifier of the change that set the state to this revision (for example, a deployment ID or timestamp).</p>
     #[serde(rename = "revisionChangeId")]
     pub revision_change_id: Option<String>,
@@ -413,10 +845,10 @@ pub struct ActionType {
 pub struct ActionTypeId {
     /// <p>A category defines what kind of action can be taken in the stage, and constrains the provider type for the action. Valid categories are limited to one of the following values. </p>
     #[serde(rename = "category")]
-    pub category: String,
+    pub category: ActionCategory,
     /// <p>The creator of the action being called.</p>
     #[serde(rename = "owner")]
-    pub owner: String,
+    pub owner: ActionOwner,
     /// <p>The provider of the service being called by the action. Valid providers are determined by the action category. For example, an action in the Deploy category type might have a provider of AWS CodeDeploy, which would be specified as CodeDeploy. For more information, see <a href="https://docs.aws.amazon.com/codepipeline/latest/userguide/reference-pipeline-structure.html#actions-valid-providers">Valid Action Types and Providers in CodePipeline</a>.</p>
     #[serde(rename = "provider")]
     pub provider: String,
@@ -452,12 +884,105 @@ pub struct ActionTypeSettings {
 pub struct ApprovalResult {
     /// <p>The response submitted by a reviewer assigned to an approval action request.</p>
     #[serde(rename = "status")]
-    pub status: String,
+    pub status: ApprovalStatus,
     /// <p>The summary of the current status of the approval request.</p>
     #[serde(rename = "summary")]
     pub summary: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ApprovalStatus {
+    /// `Approved`
+    Approved,
+    /// `Rejected`
+    Rejected,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ApprovalStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ApprovalStatus::Approved => "Approved",
+            ApprovalStatus::Rejected => "Rejected",
+            ApprovalStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ApprovalStatus {
+    fn default() -> ApprovalStatus {
+        ApprovalStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ApprovalStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ApprovalStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ApprovalStatus {
+    fn from(value: &'a str) -> ApprovalStatus {
+        match value {
+            "Approved" => ApprovalStatus::Approved,
+            "Rejected" => ApprovalStatus::Rejected,
+            _ => ApprovalStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ApprovalStatus {
+    fn from(value: String) -> ApprovalStatus {
+        match value.as_str() {
+            "Approved" => ApprovalStatus::Approved,
+            "Rejected" => ApprovalStatus::Rejected,
+            _ => ApprovalStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ApprovalStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ApprovalStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ApprovalStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ApprovalStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ApprovalStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ApprovalStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents information about an artifact that is worked on by actions in the pipeline.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -512,17 +1037,110 @@ pub struct ArtifactLocation {
     /// <p>The type of artifact in the location.</p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<ArtifactLocationType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ArtifactLocationType {
+    /// `S3`
+    S3,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ArtifactLocationType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ArtifactLocationType::S3 => "S3",
+            ArtifactLocationType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ArtifactLocationType {
+    fn default() -> ArtifactLocationType {
+        ArtifactLocationType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ArtifactLocationType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ArtifactLocationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ArtifactLocationType {
+    fn from(value: &'a str) -> ArtifactLocationType {
+        match value {
+            "S3" => ArtifactLocationType::S3,
+            _ => ArtifactLocationType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ArtifactLocationType {
+    fn from(value: String) -> ArtifactLocationType {
+        match value.as_str() {
+            "S3" => ArtifactLocationType::S3,
+            _ => ArtifactLocationType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ArtifactLocationType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ArtifactLocationType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ArtifactLocationType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ArtifactLocationType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for ArtifactLocationType {
+    fn deserialize<D>(deserializer: D) -> Result<ArtifactLocationType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents revision details of an artifact. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ArtifactRevision {
     /// <p>The date and time when the most recent revision of the artifact was created, in timestamp format.</p>
     #[serde(rename = "created")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created: Option<f64>,
+    pub created: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of an artifact. This name might be system-generated, such as "MyApp", or defined by the user when an action is created.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -557,9 +1175,97 @@ pub struct ArtifactStore {
     pub location: String,
     /// <p>The type of the artifact store, such as S3.</p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: ArtifactStoreType,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ArtifactStoreType {
+    /// `S3`
+    S3,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ArtifactStoreType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ArtifactStoreType::S3 => "S3",
+            ArtifactStoreType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ArtifactStoreType {
+    fn default() -> ArtifactStoreType {
+        ArtifactStoreType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ArtifactStoreType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ArtifactStoreType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ArtifactStoreType {
+    fn from(value: &'a str) -> ArtifactStoreType {
+        match value {
+            "S3" => ArtifactStoreType::S3,
+            _ => ArtifactStoreType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ArtifactStoreType {
+    fn from(value: String) -> ArtifactStoreType {
+        match value.as_str() {
+            "S3" => ArtifactStoreType::S3,
+            _ => ArtifactStoreType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ArtifactStoreType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ArtifactStoreType, Self::Err> {
+        Ok(value.into())
+    }
 }
 
+impl ::rusoto_core::param::ToParam for ArtifactStoreType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ArtifactStoreType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ArtifactStoreType {
+    fn deserialize<D>(deserializer: D) -> Result<ArtifactStoreType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Reserved for future use.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct BlockerDeclaration {
@@ -568,16 +1274,104 @@ pub struct BlockerDeclaration {
     pub name: String,
     /// <p>Reserved for future use.</p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: BlockerType,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BlockerType {
+    /// `Schedule`
+    Schedule,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl BlockerType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BlockerType::Schedule => "Schedule",
+            BlockerType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for BlockerType {
+    fn default() -> BlockerType {
+        BlockerType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for BlockerType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for BlockerType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
+impl<'a> From<&'a str> for BlockerType {
+    fn from(value: &'a str) -> BlockerType {
+        match value {
+            "Schedule" => BlockerType::Schedule,
+            _ => BlockerType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BlockerType {
+    fn from(value: String) -> BlockerType {
+        match value.as_str() {
+            "Schedule" => BlockerType::Schedule,
+            _ => BlockerType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for BlockerType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<BlockerType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for BlockerType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for BlockerType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BlockerType {
+    fn deserialize<D>(deserializer: D) -> Result<BlockerType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents the input of a CreateCustomActionType operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateCustomActionTypeInput {
     /// <p><p>The category of the custom action, such as a build action or a test action.</p> <note> <p>Although <code>Source</code> and <code>Approval</code> are listed as valid values, they are not currently functional. These values are reserved for future use.</p> </note></p>
     #[serde(rename = "category")]
-    pub category: String,
+    pub category: ActionCategory,
     /// <p><p>The configuration properties for the custom action.</p> <note> <p>You can refer to a name in the configuration properties of the custom action within the URL templates by following the format of {Config:name}, as long as the configuration property is both required and not secret. For more information, see <a href="https://docs.aws.amazon.com/codepipeline/latest/userguide/how-to-create-custom-action.html">Create a Custom Action for a Pipeline</a>.</p> </note></p>
     #[serde(rename = "configurationProperties")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -653,8 +1447,13 @@ pub struct CurrentRevision {
     pub change_identifier: String,
     /// <p>The date and time when the most recent revision of the artifact was created, in timestamp format.</p>
     #[serde(rename = "created")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created: Option<f64>,
+    pub created: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The revision ID of the current version of an artifact.</p>
     #[serde(rename = "revision")]
     pub revision: String,
@@ -670,7 +1469,7 @@ pub struct CurrentRevision {
 pub struct DeleteCustomActionTypeInput {
     /// <p>The category of the custom action that you want to delete, such as source or deploy.</p>
     #[serde(rename = "category")]
-    pub category: String,
+    pub category: ActionCategory,
     /// <p>The provider of the service used in the custom action, such as AWS CodeDeploy.</p>
     #[serde(rename = "provider")]
     pub provider: String,
@@ -728,7 +1527,7 @@ pub struct DisableStageTransitionInput {
     pub stage_name: String,
     /// <p>Specifies whether artifacts are prevented from transitioning into the stage and being processed by the actions in that stage (inbound), or prevented from transitioning from the stage after they have been processed by the actions in that stage (outbound).</p>
     #[serde(rename = "transitionType")]
-    pub transition_type: String,
+    pub transition_type: StageTransitionType,
 }
 
 /// <p>Represents the input of an <code>EnableStageTransition</code> action.</p>
@@ -743,7 +1542,7 @@ pub struct EnableStageTransitionInput {
     pub stage_name: String,
     /// <p>Specifies whether artifacts are allowed to enter the stage and be processed by the actions in that stage (inbound) or whether already processed artifacts are allowed to transition to the next stage (outbound).</p>
     #[serde(rename = "transitionType")]
-    pub transition_type: String,
+    pub transition_type: StageTransitionType,
 }
 
 /// <p>Represents information about the key used to encrypt data in the artifact store, such as an AWS Key Management Service (AWS KMS) key.</p>
@@ -754,9 +1553,97 @@ pub struct EncryptionKey {
     pub id: String,
     /// <p>The type of encryption key, such as an AWS Key Management Service (AWS KMS) key. When creating or updating a pipeline, the value must be set to 'KMS'.</p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: EncryptionKeyType,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EncryptionKeyType {
+    /// `KMS`
+    Kms,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EncryptionKeyType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EncryptionKeyType::Kms => "KMS",
+            EncryptionKeyType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EncryptionKeyType {
+    fn default() -> EncryptionKeyType {
+        EncryptionKeyType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EncryptionKeyType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EncryptionKeyType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EncryptionKeyType {
+    fn from(value: &'a str) -> EncryptionKeyType {
+        match value {
+            "KMS" => EncryptionKeyType::Kms,
+            _ => EncryptionKeyType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EncryptionKeyType {
+    fn from(value: String) -> EncryptionKeyType {
+        match value.as_str() {
+            "KMS" => EncryptionKeyType::Kms,
+            _ => EncryptionKeyType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EncryptionKeyType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EncryptionKeyType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EncryptionKeyType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
 }
 
+impl ::serde::Serialize for EncryptionKeyType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EncryptionKeyType {
+    fn deserialize<D>(deserializer: D) -> Result<EncryptionKeyType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents information about an error in AWS CodePipeline.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -800,7 +1687,7 @@ pub struct ExecutionTrigger {
     /// <p>The type of change-detection method, command, or user interaction that started a pipeline execution.</p>
     #[serde(rename = "triggerType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub trigger_type: Option<String>,
+    pub trigger_type: Option<TriggerType>,
 }
 
 /// <p>Represents information about failure details.</p>
@@ -816,9 +1703,122 @@ pub struct FailureDetails {
     pub message: String,
     /// <p>The type of the failure.</p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: FailureType,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FailureType {
+    /// `JobFailed`
+    JobFailed,
+    /// `ConfigurationError`
+    ConfigurationError,
+    /// `PermissionError`
+    PermissionError,
+    /// `RevisionOutOfSync`
+    RevisionOutOfSync,
+    /// `RevisionUnavailable`
+    RevisionUnavailable,
+    /// `SystemUnavailable`
+    SystemUnavailable,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl FailureType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FailureType::JobFailed => "JobFailed",
+            FailureType::ConfigurationError => "ConfigurationError",
+            FailureType::PermissionError => "PermissionError",
+            FailureType::RevisionOutOfSync => "RevisionOutOfSync",
+            FailureType::RevisionUnavailable => "RevisionUnavailable",
+            FailureType::SystemUnavailable => "SystemUnavailable",
+            FailureType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for FailureType {
+    fn default() -> FailureType {
+        FailureType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for FailureType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FailureType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FailureType {
+    fn from(value: &'a str) -> FailureType {
+        match value {
+            "JobFailed" => FailureType::JobFailed,
+            "ConfigurationError" => FailureType::ConfigurationError,
+            "PermissionError" => FailureType::PermissionError,
+            "RevisionOutOfSync" => FailureType::RevisionOutOfSync,
+            "RevisionUnavailable" => FailureType::RevisionUnavailable,
+            "SystemUnavailable" => FailureType::SystemUnavailable,
+            _ => FailureType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FailureType {
+    fn from(value: String) -> FailureType {
+        match value.as_str() {
+            "JobFailed" => FailureType::JobFailed,
+            "ConfigurationError" => FailureType::ConfigurationError,
+            "PermissionError" => FailureType::PermissionError,
+            "RevisionOutOfSync" => FailureType::RevisionOutOfSync,
+            "RevisionUnavailable" => FailureType::RevisionUnavailable,
+            "SystemUnavailable" => FailureType::SystemUnavailable,
+            _ => FailureType::Unknown(value),
+        }
+    }
 }
 
+impl ::std::str::FromStr for FailureType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<FailureType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FailureType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FailureType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FailureType {
+    fn deserialize<D>(deserializer: D) -> Result<FailureType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents the input of a <code>GetJobDetails</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -902,8 +1902,13 @@ pub struct GetPipelineStateInput {
 pub struct GetPipelineStateOutput {
     /// <p>The date and time the pipeline was created, in timestamp format.</p>
     #[serde(rename = "created")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created: Option<f64>,
+    pub created: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the pipeline for which you want to get the state.</p>
     #[serde(rename = "pipelineName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -918,8 +1923,13 @@ pub struct GetPipelineStateOutput {
     pub stage_states: Option<Vec<StageState>>,
     /// <p>The date and time the pipeline was last updated, in timestamp format.</p>
     #[serde(rename = "updated")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated: Option<f64>,
+    pub updated: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>Represents the input of a <code>GetThirdPartyJobDetails</code> action.</p>
@@ -1030,6 +2040,124 @@ pub struct JobDetails {
     pub id: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum JobStatus {
+    /// `Created`
+    Created,
+    /// `Queued`
+    Queued,
+    /// `Dispatched`
+    Dispatched,
+    /// `InProgress`
+    InProgress,
+    /// `TimedOut`
+    TimedOut,
+    /// `Succeeded`
+    Succeeded,
+    /// `Failed`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl JobStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            JobStatus::Created => "Created",
+            JobStatus::Queued => "Queued",
+            JobStatus::Dispatched => "Dispatched",
+            JobStatus::InProgress => "InProgress",
+            JobStatus::TimedOut => "TimedOut",
+            JobStatus::Succeeded => "Succeeded",
+            JobStatus::Failed => "Failed",
+            JobStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for JobStatus {
+    fn default() -> JobStatus {
+        JobStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for JobStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for JobStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for JobStatus {
+    fn from(value: &'a str) -> JobStatus {
+        match value {
+            "Created" => JobStatus::Created,
+            "Queued" => JobStatus::Queued,
+            "Dispatched" => JobStatus::Dispatched,
+            "InProgress" => JobStatus::InProgress,
+            "TimedOut" => JobStatus::TimedOut,
+            "Succeeded" => JobStatus::Succeeded,
+            "Failed" => JobStatus::Failed,
+            _ => JobStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for JobStatus {
+    fn from(value: String) -> JobStatus {
+        match value.as_str() {
+            "Created" => JobStatus::Created,
+            "Queued" => JobStatus::Queued,
+            "Dispatched" => JobStatus::Dispatched,
+            "InProgress" => JobStatus::InProgress,
+            "TimedOut" => JobStatus::TimedOut,
+            "Succeeded" => JobStatus::Succeeded,
+            "Failed" => JobStatus::Failed,
+            _ => JobStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for JobStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<JobStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for JobStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for JobStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for JobStatus {
+    fn deserialize<D>(deserializer: D) -> Result<JobStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ListActionExecutionsInput {
@@ -1070,7 +2198,7 @@ pub struct ListActionTypesInput {
     /// <p>Filters the list of action types to those created by a specified entity.</p>
     #[serde(rename = "actionOwnerFilter")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub action_owner_filter: Option<String>,
+    pub action_owner_filter: Option<ActionOwner>,
     /// <p>An identifier that was returned from the previous list action types call, which can be used to return the next set of action types in the list.</p>
     #[serde(rename = "nextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1195,8 +2323,13 @@ pub struct ListWebhookItem {
     pub error_message: Option<String>,
     /// <p>The date and time a webhook was last successfully triggered, in timestamp format.</p>
     #[serde(rename = "lastTriggered")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_triggered: Option<f64>,
+    pub last_triggered: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Specifies the tags applied to the webhook.</p>
     #[serde(rename = "tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1315,17 +2448,140 @@ pub struct PipelineExecution {
     /// <p><p>The status of the pipeline execution.</p> <ul> <li> <p>InProgress: The pipeline execution is currently running.</p> </li> <li> <p>Stopped: The pipeline execution was manually stopped. For more information, see <a href="https://docs.aws.amazon.com/codepipeline/latest/userguide/concepts.html#concepts-executions-stopped">Stopped Executions</a>.</p> </li> <li> <p>Stopping: The pipeline execution received a request to be manually stopped. Depending on the selected stop mode, the execution is either completing or abandoning in-progress actions. For more information, see <a href="https://docs.aws.amazon.com/codepipeline/latest/userguide/concepts.html#concepts-executions-stopped">Stopped Executions</a>.</p> </li> <li> <p>Succeeded: The pipeline execution was completed successfully. </p> </li> <li> <p>Superseded: While this pipeline execution was waiting for the next stage to be completed, a newer pipeline execution advanced and continued through the pipeline instead. For more information, see <a href="https://docs.aws.amazon.com/codepipeline/latest/userguide/concepts.html#concepts-superseded">Superseded Executions</a>.</p> </li> <li> <p>Failed: The pipeline execution was not completed successfully.</p> </li> </ul></p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<PipelineExecutionStatus>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PipelineExecutionStatus {
+    /// `Cancelled`
+    Cancelled,
+    /// `InProgress`
+    InProgress,
+    /// `Stopped`
+    Stopped,
+    /// `Stopping`
+    Stopping,
+    /// `Succeeded`
+    Succeeded,
+    /// `Superseded`
+    Superseded,
+    /// `Failed`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PipelineExecutionStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PipelineExecutionStatus::Cancelled => "Cancelled",
+            PipelineExecutionStatus::InProgress => "InProgress",
+            PipelineExecutionStatus::Stopped => "Stopped",
+            PipelineExecutionStatus::Stopping => "Stopping",
+            PipelineExecutionStatus::Succeeded => "Succeeded",
+            PipelineExecutionStatus::Superseded => "Superseded",
+            PipelineExecutionStatus::Failed => "Failed",
+            PipelineExecutionStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PipelineExecutionStatus {
+    fn default() -> PipelineExecutionStatus {
+        PipelineExecutionStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PipelineExecutionStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PipelineExecutionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PipelineExecutionStatus {
+    fn from(value: &'a str) -> PipelineExecutionStatus {
+        match value {
+            "Cancelled" => PipelineExecutionStatus::Cancelled,
+            "InProgress" => PipelineExecutionStatus::InProgress,
+            "Stopped" => PipelineExecutionStatus::Stopped,
+            "Stopping" => PipelineExecutionStatus::Stopping,
+            "Succeeded" => PipelineExecutionStatus::Succeeded,
+            "Superseded" => PipelineExecutionStatus::Superseded,
+            "Failed" => PipelineExecutionStatus::Failed,
+            _ => PipelineExecutionStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PipelineExecutionStatus {
+    fn from(value: String) -> PipelineExecutionStatus {
+        match value.as_str() {
+            "Cancelled" => PipelineExecutionStatus::Cancelled,
+            "InProgress" => PipelineExecutionStatus::InProgress,
+            "Stopped" => PipelineExecutionStatus::Stopped,
+            "Stopping" => PipelineExecutionStatus::Stopping,
+            "Succeeded" => PipelineExecutionStatus::Succeeded,
+            "Superseded" => PipelineExecutionStatus::Superseded,
+            "Failed" => PipelineExecutionStatus::Failed,
+            _ => PipelineExecutionStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PipelineExecutionStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PipelineExecutionStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PipelineExecutionStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PipelineExecutionStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for PipelineExecutionStatus {
+    fn deserialize<D>(deserializer: D) -> Result<PipelineExecutionStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Summary information about a pipeline execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PipelineExecutionSummary {
     /// <p>The date and time of the last change to the pipeline execution, in timestamp format.</p>
     #[serde(rename = "lastUpdateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_update_time: Option<f64>,
+    pub last_update_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The ID of the pipeline execution.</p>
     #[serde(rename = "pipelineExecutionId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1336,12 +2592,17 @@ pub struct PipelineExecutionSummary {
     pub source_revisions: Option<Vec<SourceRevision>>,
     /// <p>The date and time when the pipeline execution began, in timestamp format.</p>
     #[serde(rename = "startTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>The status of the pipeline execution.</p> <ul> <li> <p>InProgress: The pipeline execution is currently running.</p> </li> <li> <p>Stopped: The pipeline execution was manually stopped. For more information, see <a href="https://docs.aws.amazon.com/codepipeline/latest/userguide/concepts.html#concepts-executions-stopped">Stopped Executions</a>.</p> </li> <li> <p>Stopping: The pipeline execution received a request to be manually stopped. Depending on the selected stop mode, the execution is either completing or abandoning in-progress actions. For more information, see <a href="https://docs.aws.amazon.com/codepipeline/latest/userguide/concepts.html#concepts-executions-stopped">Stopped Executions</a>.</p> </li> <li> <p>Succeeded: The pipeline execution was completed successfully. </p> </li> <li> <p>Superseded: While this pipeline execution was waiting for the next stage to be completed, a newer pipeline execution advanced and continued through the pipeline instead. For more information, see <a href="https://docs.aws.amazon.com/codepipeline/latest/userguide/concepts.html#concepts-superseded">Superseded Executions</a>.</p> </li> <li> <p>Failed: The pipeline execution was not completed successfully.</p> </li> </ul></p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<PipelineExecutionStatus>,
     /// <p>The interaction that stopped a pipeline execution.</p>
     #[serde(rename = "stopTrigger")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1358,16 +2619,26 @@ pub struct PipelineExecutionSummary {
 pub struct PipelineMetadata {
     /// <p>The date and time the pipeline was created, in timestamp format.</p>
     #[serde(rename = "created")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created: Option<f64>,
+    pub created: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The Amazon Resource Name (ARN) of the pipeline.</p>
     #[serde(rename = "pipelineArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub pipeline_arn: Option<String>,
     /// <p>The date and time the pipeline was last updated, in timestamp format.</p>
     #[serde(rename = "updated")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated: Option<f64>,
+    pub updated: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>Returns a summary of a pipeline.</p>
@@ -1376,16 +2647,26 @@ pub struct PipelineMetadata {
 pub struct PipelineSummary {
     /// <p>The date and time the pipeline was created, in timestamp format.</p>
     #[serde(rename = "created")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created: Option<f64>,
+    pub created: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the pipeline.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
     /// <p>The date and time of the last update to the pipeline, in timestamp format.</p>
     #[serde(rename = "updated")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated: Option<f64>,
+    pub updated: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The version number of the pipeline.</p>
     #[serde(rename = "version")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1501,8 +2782,13 @@ pub struct PutApprovalResultInput {
 pub struct PutApprovalResultOutput {
     /// <p>The timestamp showing when the approval or rejection was submitted.</p>
     #[serde(rename = "approvedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub approved_at: Option<f64>,
+    pub approved_at: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>Represents the input of a <code>PutJobFailureResult</code> action.</p>
@@ -1627,7 +2913,7 @@ pub struct RetryStageExecutionInput {
     pub pipeline_name: String,
     /// <p>The scope of the retry attempt. Currently, the only supported value is FAILED_ACTIONS.</p>
     #[serde(rename = "retryMode")]
-    pub retry_mode: String,
+    pub retry_mode: StageRetryMode,
     /// <p>The name of the failed stage to be retried.</p>
     #[serde(rename = "stageName")]
     pub stage_name: String,
@@ -1724,9 +3010,215 @@ pub struct StageExecution {
     pub pipeline_execution_id: String,
     /// <p>The status of the stage, or for a completed stage, the last status of the stage.</p>
     #[serde(rename = "status")]
-    pub status: String,
+    pub status: StageExecutionStatus,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StageExecutionStatus {
+    /// `Cancelled`
+    Cancelled,
+    /// `InProgress`
+    InProgress,
+    /// `Failed`
+    Failed,
+    /// `Stopped`
+    Stopped,
+    /// `Stopping`
+    Stopping,
+    /// `Succeeded`
+    Succeeded,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl StageExecutionStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StageExecutionStatus::Cancelled => "Cancelled",
+            StageExecutionStatus::InProgress => "InProgress",
+            StageExecutionStatus::Failed => "Failed",
+            StageExecutionStatus::Stopped => "Stopped",
+            StageExecutionStatus::Stopping => "Stopping",
+            StageExecutionStatus::Succeeded => "Succeeded",
+            StageExecutionStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for StageExecutionStatus {
+    fn default() -> StageExecutionStatus {
+        StageExecutionStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for StageExecutionStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for StageExecutionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for StageExecutionStatus {
+    fn from(value: &'a str) -> StageExecutionStatus {
+        match value {
+            "Cancelled" => StageExecutionStatus::Cancelled,
+            "InProgress" => StageExecutionStatus::InProgress,
+            "Failed" => StageExecutionStatus::Failed,
+            "Stopped" => StageExecutionStatus::Stopped,
+            "Stopping" => StageExecutionStatus::Stopping,
+            "Succeeded" => StageExecutionStatus::Succeeded,
+            _ => StageExecutionStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StageExecutionStatus {
+    fn from(value: String) -> StageExecutionStatus {
+        match value.as_str() {
+            "Cancelled" => StageExecutionStatus::Cancelled,
+            "InProgress" => StageExecutionStatus::InProgress,
+            "Failed" => StageExecutionStatus::Failed,
+            "Stopped" => StageExecutionStatus::Stopped,
+            "Stopping" => StageExecutionStatus::Stopping,
+            "Succeeded" => StageExecutionStatus::Succeeded,
+            _ => StageExecutionStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for StageExecutionStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<StageExecutionStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StageExecutionStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for StageExecutionStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StageExecutionStatus {
+    fn deserialize<D>(deserializer: D) -> Result<StageExecutionStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StageRetryMode {
+    /// `FAILED_ACTIONS`
+    FailedActions,
+    /// `ALL_ACTIONS`
+    AllActions,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl StageRetryMode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StageRetryMode::FailedActions => "FAILED_ACTIONS",
+            StageRetryMode::AllActions => "ALL_ACTIONS",
+            StageRetryMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for StageRetryMode {
+    fn default() -> StageRetryMode {
+        StageRetryMode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for StageRetryMode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for StageRetryMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
+impl<'a> From<&'a str> for StageRetryMode {
+    fn from(value: &'a str) -> StageRetryMode {
+        match value {
+            "FAILED_ACTIONS" => StageRetryMode::FailedActions,
+            "ALL_ACTIONS" => StageRetryMode::AllActions,
+            _ => StageRetryMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StageRetryMode {
+    fn from(value: String) -> StageRetryMode {
+        match value.as_str() {
+            "FAILED_ACTIONS" => StageRetryMode::FailedActions,
+            "ALL_ACTIONS" => StageRetryMode::AllActions,
+            _ => StageRetryMode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for StageRetryMode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<StageRetryMode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StageRetryMode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for StageRetryMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StageRetryMode {
+    fn deserialize<D>(deserializer: D) -> Result<StageRetryMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents information about the state of the stage.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1749,6 +3241,99 @@ pub struct StageState {
     pub stage_name: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StageTransitionType {
+    /// `Inbound`
+    Inbound,
+    /// `Outbound`
+    Outbound,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl StageTransitionType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StageTransitionType::Inbound => "Inbound",
+            StageTransitionType::Outbound => "Outbound",
+            StageTransitionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for StageTransitionType {
+    fn default() -> StageTransitionType {
+        StageTransitionType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for StageTransitionType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for StageTransitionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for StageTransitionType {
+    fn from(value: &'a str) -> StageTransitionType {
+        match value {
+            "Inbound" => StageTransitionType::Inbound,
+            "Outbound" => StageTransitionType::Outbound,
+            _ => StageTransitionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StageTransitionType {
+    fn from(value: String) -> StageTransitionType {
+        match value.as_str() {
+            "Inbound" => StageTransitionType::Inbound,
+            "Outbound" => StageTransitionType::Outbound,
+            _ => StageTransitionType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for StageTransitionType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<StageTransitionType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StageTransitionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for StageTransitionType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StageTransitionType {
+    fn deserialize<D>(deserializer: D) -> Result<StageTransitionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents the input of a <code>StartPipelineExecution</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -1920,14 +3505,137 @@ pub struct TransitionState {
     pub enabled: Option<bool>,
     /// <p>The timestamp when the transition state was last changed.</p>
     #[serde(rename = "lastChangedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_changed_at: Option<f64>,
+    pub last_changed_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The ID of the user who last changed the transition state.</p>
     #[serde(rename = "lastChangedBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub last_changed_by: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TriggerType {
+    /// `CreatePipeline`
+    CreatePipeline,
+    /// `StartPipelineExecution`
+    StartPipelineExecution,
+    /// `PollForSourceChanges`
+    PollForSourceChanges,
+    /// `Webhook`
+    Webhook,
+    /// `CloudWatchEvent`
+    CloudWatchEvent,
+    /// `PutActionRevision`
+    PutActionRevision,
+    /// `WebhookV2`
+    WebhookV2,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TriggerType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TriggerType::CreatePipeline => "CreatePipeline",
+            TriggerType::StartPipelineExecution => "StartPipelineExecution",
+            TriggerType::PollForSourceChanges => "PollForSourceChanges",
+            TriggerType::Webhook => "Webhook",
+            TriggerType::CloudWatchEvent => "CloudWatchEvent",
+            TriggerType::PutActionRevision => "PutActionRevision",
+            TriggerType::WebhookV2 => "WebhookV2",
+            TriggerType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TriggerType {
+    fn default() -> TriggerType {
+        TriggerType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TriggerType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TriggerType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TriggerType {
+    fn from(value: &'a str) -> TriggerType {
+        match value {
+            "CreatePipeline" => TriggerType::CreatePipeline,
+            "StartPipelineExecution" => TriggerType::StartPipelineExecution,
+            "PollForSourceChanges" => TriggerType::PollForSourceChanges,
+            "Webhook" => TriggerType::Webhook,
+            "CloudWatchEvent" => TriggerType::CloudWatchEvent,
+            "PutActionRevision" => TriggerType::PutActionRevision,
+            "WebhookV2" => TriggerType::WebhookV2,
+            _ => TriggerType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TriggerType {
+    fn from(value: String) -> TriggerType {
+        match value.as_str() {
+            "CreatePipeline" => TriggerType::CreatePipeline,
+            "StartPipelineExecution" => TriggerType::StartPipelineExecution,
+            "PollForSourceChanges" => TriggerType::PollForSourceChanges,
+            "Webhook" => TriggerType::Webhook,
+            "CloudWatchEvent" => TriggerType::CloudWatchEvent,
+            "PutActionRevision" => TriggerType::PutActionRevision,
+            "WebhookV2" => TriggerType::WebhookV2,
+            _ => TriggerType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TriggerType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TriggerType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TriggerType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TriggerType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TriggerType {
+    fn deserialize<D>(deserializer: D) -> Result<TriggerType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct UntagResourceInput {
@@ -1975,12 +3683,110 @@ pub struct WebhookAuthConfiguration {
     pub secret_token: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum WebhookAuthenticationType {
+    /// `GITHUB_HMAC`
+    GithubHmac,
+    /// `IP`
+    Ip,
+    /// `UNAUTHENTICATED`
+    Unauthenticated,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl WebhookAuthenticationType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            WebhookAuthenticationType::GithubHmac => "GITHUB_HMAC",
+            WebhookAuthenticationType::Ip => "IP",
+            WebhookAuthenticationType::Unauthenticated => "UNAUTHENTICATED",
+            WebhookAuthenticationType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for WebhookAuthenticationType {
+    fn default() -> WebhookAuthenticationType {
+        WebhookAuthenticationType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for WebhookAuthenticationType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for WebhookAuthenticationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for WebhookAuthenticationType {
+    fn from(value: &'a str) -> WebhookAuthenticationType {
+        match value {
+            "GITHUB_HMAC" => WebhookAuthenticationType::GithubHmac,
+            "IP" => WebhookAuthenticationType::Ip,
+            "UNAUTHENTICATED" => WebhookAuthenticationType::Unauthenticated,
+            _ => WebhookAuthenticationType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for WebhookAuthenticationType {
+    fn from(value: String) -> WebhookAuthenticationType {
+        match value.as_str() {
+            "GITHUB_HMAC" => WebhookAuthenticationType::GithubHmac,
+            "IP" => WebhookAuthenticationType::Ip,
+            "UNAUTHENTICATED" => WebhookAuthenticationType::Unauthenticated,
+            _ => WebhookAuthenticationType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for WebhookAuthenticationType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<WebhookAuthenticationType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for WebhookAuthenticationType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for WebhookAuthenticationType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for WebhookAuthenticationType {
+    fn deserialize<D>(deserializer: D) -> Result<WebhookAuthenticationType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents information about a webhook and its definition.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct WebhookDefinition {
     /// <p><p>Supported options are GITHUB<em>HMAC, IP, and UNAUTHENTICATED.</p> <ul> <li> <p>For information about the authentication scheme implemented by GITHUB</em>HMAC, see <a href="https://developer.github.com/webhooks/securing/">Securing your webhooks</a> on the GitHub Developer website.</p> </li> <li> <p> IP rejects webhooks trigger requests unless they originate from an IP address in the IP range whitelisted in the authentication configuration.</p> </li> <li> <p> UNAUTHENTICATED accepts all webhook trigger requests regardless of origin.</p> </li> </ul></p>
     #[serde(rename = "authentication")]
-    pub authentication: String,
+    pub authentication: WebhookAuthenticationType,
     /// <p>Properties that configure the authentication applied to incoming webhook trigger requests. The required properties depend on the authentication type. For GITHUB_HMAC, only the <code>SecretToken </code>property must be set. For IP, only the <code>AllowedIPRange </code>property must be set to a valid CIDR range. For UNAUTHENTICATED, no properties can be set.</p>
     #[serde(rename = "authenticationConfiguration")]
     pub authentication_configuration: WebhookAuthConfiguration,
@@ -3854,11 +5660,12 @@ pub struct CodePipelineClient {
 impl CodePipelineClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> CodePipelineClient {
         CodePipelineClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "CodePipeline"),
         }
     }
 
@@ -3890,6 +5697,7 @@ impl CodePipeline for CodePipelineClient {
         input: AcknowledgeJobInput,
     ) -> Result<AcknowledgeJobOutput, RusotoError<AcknowledgeJobError>> {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("AcknowledgeJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodePipeline_20150709.AcknowledgeJob");
@@ -3917,6 +5725,7 @@ impl CodePipeline for CodePipelineClient {
         input: AcknowledgeThirdPartyJobInput,
     ) -> Result<AcknowledgeThirdPartyJobOutput, RusotoError<AcknowledgeThirdPartyJobError>> {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("AcknowledgeThirdPartyJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3948,6 +5757,7 @@ impl CodePipeline for CodePipelineClient {
         input: CreateCustomActionTypeInput,
     ) -> Result<CreateCustomActionTypeOutput, RusotoError<CreateCustomActionTypeError>> {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("CreateCustomActionType");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3979,6 +5789,7 @@ impl CodePipeline for CodePipelineClient {
         input: CreatePipelineInput,
     ) -> Result<CreatePipelineOutput, RusotoError<CreatePipelineError>> {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("CreatePipeline");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodePipeline_20150709.CreatePipeline");
@@ -4006,6 +5817,7 @@ impl CodePipeline for CodePipelineClient {
         input: DeleteCustomActionTypeInput,
     ) -> Result<(), RusotoError<DeleteCustomActionTypeError>> {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("DeleteCustomActionType");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4036,6 +5848,7 @@ impl CodePipeline for CodePipelineClient {
         input: DeletePipelineInput,
     ) -> Result<(), RusotoError<DeletePipelineError>> {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("DeletePipeline");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodePipeline_20150709.DeletePipeline");
@@ -4063,6 +5876,7 @@ impl CodePipeline for CodePipelineClient {
         input: DeleteWebhookInput,
     ) -> Result<DeleteWebhookOutput, RusotoError<DeleteWebhookError>> {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("DeleteWebhook");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodePipeline_20150709.DeleteWebhook");
@@ -4093,6 +5907,7 @@ impl CodePipeline for CodePipelineClient {
         RusotoError<DeregisterWebhookWithThirdPartyError>,
     > {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("DeregisterWebhookWithThirdParty");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4126,6 +5941,7 @@ impl CodePipeline for CodePipelineClient {
         input: DisableStageTransitionInput,
     ) -> Result<(), RusotoError<DisableStageTransitionError>> {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("DisableStageTransition");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4156,6 +5972,7 @@ impl CodePipeline for CodePipelineClient {
         input: EnableStageTransitionInput,
     ) -> Result<(), RusotoError<EnableStageTransitionError>> {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("EnableStageTransition");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4186,6 +6003,7 @@ impl CodePipeline for CodePipelineClient {
         input: GetJobDetailsInput,
     ) -> Result<GetJobDetailsOutput, RusotoError<GetJobDetailsError>> {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("GetJobDetails");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodePipeline_20150709.GetJobDetails");
@@ -4213,6 +6031,7 @@ impl CodePipeline for CodePipelineClient {
         input: GetPipelineInput,
     ) -> Result<GetPipelineOutput, RusotoError<GetPipelineError>> {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("GetPipeline");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodePipeline_20150709.GetPipeline");
@@ -4240,6 +6059,7 @@ impl CodePipeline for CodePipelineClient {
         input: GetPipelineExecutionInput,
     ) -> Result<GetPipelineExecutionOutput, RusotoError<GetPipelineExecutionError>> {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("GetPipelineExecution");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodePipeline_20150709.GetPipelineExecution");
@@ -4268,6 +6088,7 @@ impl CodePipeline for CodePipelineClient {
         input: GetPipelineStateInput,
     ) -> Result<GetPipelineStateOutput, RusotoError<GetPipelineStateError>> {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("GetPipelineState");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodePipeline_20150709.GetPipelineState");
@@ -4295,6 +6116,7 @@ impl CodePipeline for CodePipelineClient {
         input: GetThirdPartyJobDetailsInput,
     ) -> Result<GetThirdPartyJobDetailsOutput, RusotoError<GetThirdPartyJobDetailsError>> {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("GetThirdPartyJobDetails");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4326,6 +6148,7 @@ impl CodePipeline for CodePipelineClient {
         input: ListActionExecutionsInput,
     ) -> Result<ListActionExecutionsOutput, RusotoError<ListActionExecutionsError>> {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("ListActionExecutions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodePipeline_20150709.ListActionExecutions");
@@ -4354,6 +6177,7 @@ impl CodePipeline for CodePipelineClient {
         input: ListActionTypesInput,
     ) -> Result<ListActionTypesOutput, RusotoError<ListActionTypesError>> {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("ListActionTypes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodePipeline_20150709.ListActionTypes");
@@ -4381,6 +6205,7 @@ impl CodePipeline for CodePipelineClient {
         input: ListPipelineExecutionsInput,
     ) -> Result<ListPipelineExecutionsOutput, RusotoError<ListPipelineExecutionsError>> {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("ListPipelineExecutions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4412,6 +6237,7 @@ impl CodePipeline for CodePipelineClient {
         input: ListPipelinesInput,
     ) -> Result<ListPipelinesOutput, RusotoError<ListPipelinesError>> {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("ListPipelines");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodePipeline_20150709.ListPipelines");
@@ -4439,6 +6265,7 @@ impl CodePipeline for CodePipelineClient {
         input: ListTagsForResourceInput,
     ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodePipeline_20150709.ListTagsForResource");
@@ -4467,6 +6294,7 @@ impl CodePipeline for CodePipelineClient {
         input: ListWebhooksInput,
     ) -> Result<ListWebhooksOutput, RusotoError<ListWebhooksError>> {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("ListWebhooks");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodePipeline_20150709.ListWebhooks");
@@ -4494,6 +6322,7 @@ impl CodePipeline for CodePipelineClient {
         input: PollForJobsInput,
     ) -> Result<PollForJobsOutput, RusotoError<PollForJobsError>> {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("PollForJobs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodePipeline_20150709.PollForJobs");
@@ -4521,6 +6350,7 @@ impl CodePipeline for CodePipelineClient {
         input: PollForThirdPartyJobsInput,
     ) -> Result<PollForThirdPartyJobsOutput, RusotoError<PollForThirdPartyJobsError>> {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("PollForThirdPartyJobs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4552,6 +6382,7 @@ impl CodePipeline for CodePipelineClient {
         input: PutActionRevisionInput,
     ) -> Result<PutActionRevisionOutput, RusotoError<PutActionRevisionError>> {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("PutActionRevision");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodePipeline_20150709.PutActionRevision");
@@ -4579,6 +6410,7 @@ impl CodePipeline for CodePipelineClient {
         input: PutApprovalResultInput,
     ) -> Result<PutApprovalResultOutput, RusotoError<PutApprovalResultError>> {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("PutApprovalResult");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodePipeline_20150709.PutApprovalResult");
@@ -4606,6 +6438,7 @@ impl CodePipeline for CodePipelineClient {
         input: PutJobFailureResultInput,
     ) -> Result<(), RusotoError<PutJobFailureResultError>> {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("PutJobFailureResult");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodePipeline_20150709.PutJobFailureResult");
@@ -4633,6 +6466,7 @@ impl CodePipeline for CodePipelineClient {
         input: PutJobSuccessResultInput,
     ) -> Result<(), RusotoError<PutJobSuccessResultError>> {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("PutJobSuccessResult");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodePipeline_20150709.PutJobSuccessResult");
@@ -4660,6 +6494,7 @@ impl CodePipeline for CodePipelineClient {
         input: PutThirdPartyJobFailureResultInput,
     ) -> Result<(), RusotoError<PutThirdPartyJobFailureResultError>> {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("PutThirdPartyJobFailureResult");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4690,6 +6525,7 @@ impl CodePipeline for CodePipelineClient {
         input: PutThirdPartyJobSuccessResultInput,
     ) -> Result<(), RusotoError<PutThirdPartyJobSuccessResultError>> {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("PutThirdPartyJobSuccessResult");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4720,6 +6556,7 @@ impl CodePipeline for CodePipelineClient {
         input: PutWebhookInput,
     ) -> Result<PutWebhookOutput, RusotoError<PutWebhookError>> {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("PutWebhook");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodePipeline_20150709.PutWebhook");
@@ -4748,6 +6585,7 @@ impl CodePipeline for CodePipelineClient {
     ) -> Result<RegisterWebhookWithThirdPartyOutput, RusotoError<RegisterWebhookWithThirdPartyError>>
     {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("RegisterWebhookWithThirdParty");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4779,6 +6617,7 @@ impl CodePipeline for CodePipelineClient {
         input: RetryStageExecutionInput,
     ) -> Result<RetryStageExecutionOutput, RusotoError<RetryStageExecutionError>> {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("RetryStageExecution");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodePipeline_20150709.RetryStageExecution");
@@ -4807,6 +6646,7 @@ impl CodePipeline for CodePipelineClient {
         input: StartPipelineExecutionInput,
     ) -> Result<StartPipelineExecutionOutput, RusotoError<StartPipelineExecutionError>> {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("StartPipelineExecution");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4838,6 +6678,7 @@ impl CodePipeline for CodePipelineClient {
         input: StopPipelineExecutionInput,
     ) -> Result<StopPipelineExecutionOutput, RusotoError<StopPipelineExecutionError>> {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("StopPipelineExecution");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4869,6 +6710,7 @@ impl CodePipeline for CodePipelineClient {
         input: TagResourceInput,
     ) -> Result<TagResourceOutput, RusotoError<TagResourceError>> {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodePipeline_20150709.TagResource");
@@ -4896,6 +6738,7 @@ impl CodePipeline for CodePipelineClient {
         input: UntagResourceInput,
     ) -> Result<UntagResourceOutput, RusotoError<UntagResourceError>> {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodePipeline_20150709.UntagResource");
@@ -4923,6 +6766,7 @@ impl CodePipeline for CodePipelineClient {
         input: UpdatePipelineInput,
     ) -> Result<UpdatePipelineOutput, RusotoError<UpdatePipelineError>> {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("UpdatePipeline");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodePipeline_20150709.UpdatePipeline");
@@ -4944,3 +6788,170 @@ impl CodePipeline for CodePipelineClient {
         }
     }
 }
+impl CodePipelineClient {
+    /// Auto-paginating version of `list_action_executions`, streaming every page of results.
+    pub fn list_action_executions_pages(
+        &self,
+        input: ListActionExecutionsInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListActionExecutionsOutput, ListActionExecutionsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodePipelineClient, input: ListActionExecutionsInput| async move {
+                client.list_action_executions(input).await
+            },
+            |input: &ListActionExecutionsInput, output: &ListActionExecutionsOutput| {
+                Some(ListActionExecutionsInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_action_executions`, streaming the `actionExecutionDetails` of every page of results.
+    pub fn list_action_executions_items(
+        &self,
+        input: ListActionExecutionsInput,
+    ) -> rusoto_core::pagination::RusotoStream<ActionExecutionDetail, ListActionExecutionsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_action_executions_pages(input),
+            |output: ListActionExecutionsOutput| {
+                output.action_execution_details.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_action_types`, streaming every page of results.
+    pub fn list_action_types_pages(
+        &self,
+        input: ListActionTypesInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListActionTypesOutput, ListActionTypesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodePipelineClient, input: ListActionTypesInput| async move {
+                client.list_action_types(input).await
+            },
+            |input: &ListActionTypesInput, output: &ListActionTypesOutput| {
+                Some(ListActionTypesInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_action_types`, streaming the `actionTypes` of every page of results.
+    pub fn list_action_types_items(
+        &self,
+        input: ListActionTypesInput,
+    ) -> rusoto_core::pagination::RusotoStream<ActionType, ListActionTypesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_action_types_pages(input),
+            |output: ListActionTypesOutput| output.action_types,
+        )
+    }
+
+    /// Auto-paginating version of `list_pipeline_executions`, streaming every page of results.
+    pub fn list_pipeline_executions_pages(
+        &self,
+        input: ListPipelineExecutionsInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListPipelineExecutionsOutput,
+        ListPipelineExecutionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodePipelineClient, input: ListPipelineExecutionsInput| async move {
+                client.list_pipeline_executions(input).await
+            },
+            |input: &ListPipelineExecutionsInput, output: &ListPipelineExecutionsOutput| {
+                Some(ListPipelineExecutionsInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_pipeline_executions`, streaming the `pipelineExecutionSummaries` of every page of results.
+    pub fn list_pipeline_executions_items(
+        &self,
+        input: ListPipelineExecutionsInput,
+    ) -> rusoto_core::pagination::RusotoStream<PipelineExecutionSummary, ListPipelineExecutionsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_pipeline_executions_pages(input),
+            |output: ListPipelineExecutionsOutput| {
+                output.pipeline_execution_summaries.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_tags_for_resource`, streaming every page of results.
+    pub fn list_tags_for_resource_pages(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListTagsForResourceOutput, ListTagsForResourceError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodePipelineClient, input: ListTagsForResourceInput| async move {
+                client.list_tags_for_resource(input).await
+            },
+            |input: &ListTagsForResourceInput, output: &ListTagsForResourceOutput| {
+                Some(ListTagsForResourceInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_tags_for_resource`, streaming the `tags` of every page of results.
+    pub fn list_tags_for_resource_items(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> rusoto_core::pagination::RusotoStream<Tag, ListTagsForResourceError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_tags_for_resource_pages(input),
+            |output: ListTagsForResourceOutput| output.tags.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_webhooks`, streaming every page of results.
+    pub fn list_webhooks_pages(
+        &self,
+        input: ListWebhooksInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListWebhooksOutput, ListWebhooksError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodePipelineClient, input: ListWebhooksInput| async move {
+                client.list_webhooks(input).await
+            },
+            |input: &ListWebhooksInput, output: &ListWebhooksOutput| {
+                Some(ListWebhooksInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_webhooks`, streaming the `webhooks` of every page of results.
+    pub fn list_webhooks_items(
+        &self,
+        input: ListWebhooksInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListWebhookItem, ListWebhooksError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_webhooks_pages(input),
+            |output: ListWebhooksOutput| output.webhooks.unwrap_or_default(),
+        )
+    }
+}
@@ -39,7 +39,7 @@ pub struct Connection {
     /// <p>The current status of the connection. </p>
     #[serde(rename = "ConnectionStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub connection_status: Option<String>,
+    pub connection_status: Option<ConnectionStatus>,
     /// <p>The name of the external provider where your third-party code repository is configured. For Bitbucket, this is the account ID of the owner of the Bitbucket repository.</p>
     #[serde(rename = "OwnerAccountId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -47,9 +47,107 @@ pub struct Connection {
     /// <p>The name of the external provider where your third-party code repository is configured. Currently, the valid provider type is Bitbucket.</p>
     #[serde(rename = "ProviderType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub provider_type: Option<String>,
+    pub provider_type: Option<ProviderType>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ConnectionStatus {
+    /// `PENDING`
+    Pending,
+    /// `AVAILABLE`
+    Available,
+    /// `ERROR`
+    Error,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ConnectionStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ConnectionStatus::Pending => "PENDING",
+            ConnectionStatus::Available => "AVAILABLE",
+            ConnectionStatus::Error => "ERROR",
+            ConnectionStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ConnectionStatus {
+    fn default() -> ConnectionStatus {
+        ConnectionStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ConnectionStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ConnectionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ConnectionStatus {
+    fn from(value: &'a str) -> ConnectionStatus {
+        match value {
+            "PENDING" => ConnectionStatus::Pending,
+            "AVAILABLE" => ConnectionStatus::Available,
+            "ERROR" => ConnectionStatus::Error,
+            _ => ConnectionStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ConnectionStatus {
+    fn from(value: String) -> ConnectionStatus {
+        match value.as_str() {
+            "PENDING" => ConnectionStatus::Pending,
+            "AVAILABLE" => ConnectionStatus::Available,
+            "ERROR" => ConnectionStatus::Error,
+            _ => ConnectionStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ConnectionStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ConnectionStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ConnectionStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ConnectionStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ConnectionStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ConnectionStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateConnectionInput {
@@ -58,7 +156,7 @@ pub struct CreateConnectionInput {
     pub connection_name: String,
     /// <p>The name of the external provider where your third-party code repository is configured. Currently, the valid provider type is Bitbucket.</p>
     #[serde(rename = "ProviderType")]
-    pub provider_type: String,
+    pub provider_type: ProviderType,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -112,7 +210,7 @@ pub struct ListConnectionsInput {
     /// <p>Filters the list of connections to those associated with a specified provider, such as Bitbucket.</p>
     #[serde(rename = "ProviderTypeFilter")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub provider_type_filter: Option<String>,
+    pub provider_type_filter: Option<ProviderType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -128,6 +226,114 @@ pub struct ListConnectionsOutput {
     pub next_token: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ProviderType {
+    /// `Bitbucket`
+    Bitbucket,
+    /// `GitHub`
+    GitHub,
+    /// `GitHubEnterpriseServer`
+    GitHubEnterpriseServer,
+    /// `GitLab`
+    GitLab,
+    /// `GitLabSelfManaged`
+    GitLabSelfManaged,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ProviderType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ProviderType::Bitbucket => "Bitbucket",
+            ProviderType::GitHub => "GitHub",
+            ProviderType::GitHubEnterpriseServer => "GitHubEnterpriseServer",
+            ProviderType::GitLab => "GitLab",
+            ProviderType::GitLabSelfManaged => "GitLabSelfManaged",
+            ProviderType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ProviderType {
+    fn default() -> ProviderType {
+        ProviderType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ProviderType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ProviderType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ProviderType {
+    fn from(value: &'a str) -> ProviderType {
+        match value {
+            "Bitbucket" => ProviderType::Bitbucket,
+            "GitHub" => ProviderType::GitHub,
+            "GitHubEnterpriseServer" => ProviderType::GitHubEnterpriseServer,
+            "GitLab" => ProviderType::GitLab,
+            "GitLabSelfManaged" => ProviderType::GitLabSelfManaged,
+            _ => ProviderType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ProviderType {
+    fn from(value: String) -> ProviderType {
+        match value.as_str() {
+            "Bitbucket" => ProviderType::Bitbucket,
+            "GitHub" => ProviderType::GitHub,
+            "GitHubEnterpriseServer" => ProviderType::GitHubEnterpriseServer,
+            "GitLab" => ProviderType::GitLab,
+            "GitLabSelfManaged" => ProviderType::GitLabSelfManaged,
+            _ => ProviderType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ProviderType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ProviderType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ProviderType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ProviderType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ProviderType {
+    fn deserialize<D>(deserializer: D) -> Result<ProviderType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// Errors returned by CreateConnection
 #[derive(Debug, PartialEq)]
 pub enum CreateConnectionError {
@@ -277,11 +483,12 @@ pub struct CodeStarConnectionsClient {
 impl CodeStarConnectionsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> CodeStarConnectionsClient {
         CodeStarConnectionsClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "CodeStar connections"),
         }
     }
 
@@ -313,6 +520,7 @@ impl CodeStarConnections for CodeStarConnectionsClient {
         input: CreateConnectionInput,
     ) -> Result<CreateConnectionOutput, RusotoError<CreateConnectionError>> {
         let mut request = SignedRequest::new("POST", "codestar-connections", &self.region, "/");
+        request.set_operation("CreateConnection");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -343,6 +551,7 @@ impl CodeStarConnections for CodeStarConnectionsClient {
         input: DeleteConnectionInput,
     ) -> Result<DeleteConnectionOutput, RusotoError<DeleteConnectionError>> {
         let mut request = SignedRequest::new("POST", "codestar-connections", &self.region, "/");
+        request.set_operation("DeleteConnection");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -373,6 +582,7 @@ impl CodeStarConnections for CodeStarConnectionsClient {
         input: GetConnectionInput,
     ) -> Result<GetConnectionOutput, RusotoError<GetConnectionError>> {
         let mut request = SignedRequest::new("POST", "codestar-connections", &self.region, "/");
+        request.set_operation("GetConnection");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -403,6 +613,7 @@ impl CodeStarConnections for CodeStarConnectionsClient {
         input: ListConnectionsInput,
     ) -> Result<ListConnectionsOutput, RusotoError<ListConnectionsError>> {
         let mut request = SignedRequest::new("POST", "codestar-connections", &self.region, "/");
+        request.set_operation("ListConnections");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
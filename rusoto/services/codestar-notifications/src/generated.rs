@@ -33,7 +33,7 @@ pub struct CreateNotificationRuleRequest {
     pub client_request_token: Option<String>,
     /// <p>The level of detail to include in the notifications for this resource. BASIC will include only the contents of the event as it would appear in AWS CloudWatch. FULL will include any supplemental information provided by AWS CodeStar Notifications and/or the service for the resource for which the notification is created.</p>
     #[serde(rename = "DetailType")]
-    pub detail_type: String,
+    pub detail_type: DetailType,
     /// <p>A list of event types associated with this notification rule. For a list of allowed events, see <a>EventTypeSummary</a>.</p>
     #[serde(rename = "EventTypeIds")]
     pub event_type_ids: Vec<String>,
@@ -46,7 +46,7 @@ pub struct CreateNotificationRuleRequest {
     /// <p>The status of the notification rule. The default value is ENABLED. If the status is set to DISABLED, notifications aren't sent for the notification rule.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<NotificationRuleStatus>,
     /// <p>A list of tags to apply to this notification rule. Key names cannot start with "aws". </p>
     #[serde(rename = "Tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -118,20 +118,30 @@ pub struct DescribeNotificationRuleResult {
     pub created_by: Option<String>,
     /// <p>The date and time the notification rule was created, in timestamp format.</p>
     #[serde(rename = "CreatedTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_timestamp: Option<f64>,
+    pub created_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The level of detail included in the notifications for this resource. BASIC will include only the contents of the event as it would appear in AWS CloudWatch. FULL will include any supplemental information provided by AWS CodeStar Notifications and/or the service for the resource for which the notification is created.</p>
     #[serde(rename = "DetailType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub detail_type: Option<String>,
+    pub detail_type: Option<DetailType>,
     /// <p>A list of the event types associated with the notification rule.</p>
     #[serde(rename = "EventTypes")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub event_types: Option<Vec<EventTypeSummary>>,
     /// <p>The date and time the notification rule was most recently updated, in timestamp format.</p>
     #[serde(rename = "LastModifiedTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_timestamp: Option<f64>,
+    pub last_modified_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the notification rule.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -143,7 +153,7 @@ pub struct DescribeNotificationRuleResult {
     /// <p>The status of the notification rule. Valid statuses are on (sending notifications) or off (not sending notifications).</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<NotificationRuleStatus>,
     /// <p>The tags associated with the notification rule.</p>
     #[serde(rename = "Tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -154,6 +164,99 @@ pub struct DescribeNotificationRuleResult {
     pub targets: Option<Vec<TargetSummary>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DetailType {
+    /// `BASIC`
+    Basic,
+    /// `FULL`
+    Full,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DetailType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DetailType::Basic => "BASIC",
+            DetailType::Full => "FULL",
+            DetailType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DetailType {
+    fn default() -> DetailType {
+        DetailType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DetailType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DetailType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DetailType {
+    fn from(value: &'a str) -> DetailType {
+        match value {
+            "BASIC" => DetailType::Basic,
+            "FULL" => DetailType::Full,
+            _ => DetailType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DetailType {
+    fn from(value: String) -> DetailType {
+        match value.as_str() {
+            "BASIC" => DetailType::Basic,
+            "FULL" => DetailType::Full,
+            _ => DetailType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DetailType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DetailType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DetailType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DetailType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DetailType {
+    fn deserialize<D>(deserializer: D) -> Result<DetailType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Returns information about an event that has triggered a notification rule.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -182,12 +285,105 @@ pub struct EventTypeSummary {
 pub struct ListEventTypesFilter {
     /// <p>The system-generated name of the filter type you want to filter by.</p>
     #[serde(rename = "Name")]
-    pub name: String,
+    pub name: ListEventTypesFilterName,
     /// <p>The name of the resource type (for example, pipeline) or service name (for example, CodePipeline) that you want to filter by.</p>
     #[serde(rename = "Value")]
     pub value: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ListEventTypesFilterName {
+    /// `RESOURCE_TYPE`
+    ResourceType,
+    /// `SERVICE_NAME`
+    ServiceName,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ListEventTypesFilterName {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ListEventTypesFilterName::ResourceType => "RESOURCE_TYPE",
+            ListEventTypesFilterName::ServiceName => "SERVICE_NAME",
+            ListEventTypesFilterName::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ListEventTypesFilterName {
+    fn default() -> ListEventTypesFilterName {
+        ListEventTypesFilterName::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ListEventTypesFilterName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ListEventTypesFilterName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ListEventTypesFilterName {
+    fn from(value: &'a str) -> ListEventTypesFilterName {
+        match value {
+            "RESOURCE_TYPE" => ListEventTypesFilterName::ResourceType,
+            "SERVICE_NAME" => ListEventTypesFilterName::ServiceName,
+            _ => ListEventTypesFilterName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ListEventTypesFilterName {
+    fn from(value: String) -> ListEventTypesFilterName {
+        match value.as_str() {
+            "RESOURCE_TYPE" => ListEventTypesFilterName::ResourceType,
+            "SERVICE_NAME" => ListEventTypesFilterName::ServiceName,
+            _ => ListEventTypesFilterName::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ListEventTypesFilterName {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ListEventTypesFilterName, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ListEventTypesFilterName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ListEventTypesFilterName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ListEventTypesFilterName {
+    fn deserialize<D>(deserializer: D) -> Result<ListEventTypesFilterName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ListEventTypesRequest {
@@ -224,12 +420,115 @@ pub struct ListEventTypesResult {
 pub struct ListNotificationRulesFilter {
     /// <p>The name of the attribute you want to use to filter the returned notification rules.</p>
     #[serde(rename = "Name")]
-    pub name: String,
+    pub name: ListNotificationRulesFilterName,
     /// <p>The value of the attribute you want to use to filter the returned notification rules. For example, if you specify filtering by <i>RESOURCE</i> in Name, you might specify the ARN of a pipeline in AWS CodePipeline for the value.</p>
     #[serde(rename = "Value")]
     pub value: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ListNotificationRulesFilterName {
+    /// `EVENT_TYPE_ID`
+    EventTypeId,
+    /// `CREATED_BY`
+    CreatedBy,
+    /// `RESOURCE`
+    Resource,
+    /// `TARGET_ADDRESS`
+    TargetAddress,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ListNotificationRulesFilterName {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ListNotificationRulesFilterName::EventTypeId => "EVENT_TYPE_ID",
+            ListNotificationRulesFilterName::CreatedBy => "CREATED_BY",
+            ListNotificationRulesFilterName::Resource => "RESOURCE",
+            ListNotificationRulesFilterName::TargetAddress => "TARGET_ADDRESS",
+            ListNotificationRulesFilterName::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ListNotificationRulesFilterName {
+    fn default() -> ListNotificationRulesFilterName {
+        ListNotificationRulesFilterName::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ListNotificationRulesFilterName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ListNotificationRulesFilterName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ListNotificationRulesFilterName {
+    fn from(value: &'a str) -> ListNotificationRulesFilterName {
+        match value {
+            "EVENT_TYPE_ID" => ListNotificationRulesFilterName::EventTypeId,
+            "CREATED_BY" => ListNotificationRulesFilterName::CreatedBy,
+            "RESOURCE" => ListNotificationRulesFilterName::Resource,
+            "TARGET_ADDRESS" => ListNotificationRulesFilterName::TargetAddress,
+            _ => ListNotificationRulesFilterName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ListNotificationRulesFilterName {
+    fn from(value: String) -> ListNotificationRulesFilterName {
+        match value.as_str() {
+            "EVENT_TYPE_ID" => ListNotificationRulesFilterName::EventTypeId,
+            "CREATED_BY" => ListNotificationRulesFilterName::CreatedBy,
+            "RESOURCE" => ListNotificationRulesFilterName::Resource,
+            "TARGET_ADDRESS" => ListNotificationRulesFilterName::TargetAddress,
+            _ => ListNotificationRulesFilterName::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ListNotificationRulesFilterName {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ListNotificationRulesFilterName, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ListNotificationRulesFilterName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ListNotificationRulesFilterName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ListNotificationRulesFilterName {
+    fn deserialize<D>(deserializer: D) -> Result<ListNotificationRulesFilterName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ListNotificationRulesRequest {
@@ -283,12 +582,110 @@ pub struct ListTagsForResourceResult {
 pub struct ListTargetsFilter {
     /// <p>The name of the attribute you want to use to filter the returned targets.</p>
     #[serde(rename = "Name")]
-    pub name: String,
+    pub name: ListTargetsFilterName,
     /// <p>The value of the attribute you want to use to filter the returned targets. For example, if you specify <i>SNS</i> for the Target type, you could specify an Amazon Resource Name (ARN) for a topic as the value.</p>
     #[serde(rename = "Value")]
     pub value: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ListTargetsFilterName {
+    /// `TARGET_TYPE`
+    TargetType,
+    /// `TARGET_ADDRESS`
+    TargetAddress,
+    /// `TARGET_STATUS`
+    TargetStatus,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ListTargetsFilterName {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ListTargetsFilterName::TargetType => "TARGET_TYPE",
+            ListTargetsFilterName::TargetAddress => "TARGET_ADDRESS",
+            ListTargetsFilterName::TargetStatus => "TARGET_STATUS",
+            ListTargetsFilterName::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ListTargetsFilterName {
+    fn default() -> ListTargetsFilterName {
+        ListTargetsFilterName::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ListTargetsFilterName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ListTargetsFilterName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ListTargetsFilterName {
+    fn from(value: &'a str) -> ListTargetsFilterName {
+        match value {
+            "TARGET_TYPE" => ListTargetsFilterName::TargetType,
+            "TARGET_ADDRESS" => ListTargetsFilterName::TargetAddress,
+            "TARGET_STATUS" => ListTargetsFilterName::TargetStatus,
+            _ => ListTargetsFilterName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ListTargetsFilterName {
+    fn from(value: String) -> ListTargetsFilterName {
+        match value.as_str() {
+            "TARGET_TYPE" => ListTargetsFilterName::TargetType,
+            "TARGET_ADDRESS" => ListTargetsFilterName::TargetAddress,
+            "TARGET_STATUS" => ListTargetsFilterName::TargetStatus,
+            _ => ListTargetsFilterName::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ListTargetsFilterName {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ListTargetsFilterName, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ListTargetsFilterName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ListTargetsFilterName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ListTargetsFilterName {
+    fn deserialize<D>(deserializer: D) -> Result<ListTargetsFilterName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ListTargetsRequest {
@@ -319,6 +716,99 @@ pub struct ListTargetsResult {
     pub targets: Option<Vec<TargetSummary>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum NotificationRuleStatus {
+    /// `ENABLED`
+    Enabled,
+    /// `DISABLED`
+    Disabled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl NotificationRuleStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            NotificationRuleStatus::Enabled => "ENABLED",
+            NotificationRuleStatus::Disabled => "DISABLED",
+            NotificationRuleStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for NotificationRuleStatus {
+    fn default() -> NotificationRuleStatus {
+        NotificationRuleStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for NotificationRuleStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for NotificationRuleStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for NotificationRuleStatus {
+    fn from(value: &'a str) -> NotificationRuleStatus {
+        match value {
+            "ENABLED" => NotificationRuleStatus::Enabled,
+            "DISABLED" => NotificationRuleStatus::Disabled,
+            _ => NotificationRuleStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for NotificationRuleStatus {
+    fn from(value: String) -> NotificationRuleStatus {
+        match value.as_str() {
+            "ENABLED" => NotificationRuleStatus::Enabled,
+            "DISABLED" => NotificationRuleStatus::Disabled,
+            _ => NotificationRuleStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for NotificationRuleStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<NotificationRuleStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for NotificationRuleStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for NotificationRuleStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for NotificationRuleStatus {
+    fn deserialize<D>(deserializer: D) -> Result<NotificationRuleStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about a specified notification rule.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -390,6 +880,114 @@ pub struct Target {
     pub target_type: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TargetStatus {
+    /// `PENDING`
+    Pending,
+    /// `ACTIVE`
+    Active,
+    /// `UNREACHABLE`
+    Unreachable,
+    /// `INACTIVE`
+    Inactive,
+    /// `DEACTIVATED`
+    Deactivated,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TargetStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TargetStatus::Pending => "PENDING",
+            TargetStatus::Active => "ACTIVE",
+            TargetStatus::Unreachable => "UNREACHABLE",
+            TargetStatus::Inactive => "INACTIVE",
+            TargetStatus::Deactivated => "DEACTIVATED",
+            TargetStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TargetStatus {
+    fn default() -> TargetStatus {
+        TargetStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TargetStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TargetStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TargetStatus {
+    fn from(value: &'a str) -> TargetStatus {
+        match value {
+            "PENDING" => TargetStatus::Pending,
+            "ACTIVE" => TargetStatus::Active,
+            "UNREACHABLE" => TargetStatus::Unreachable,
+            "INACTIVE" => TargetStatus::Inactive,
+            "DEACTIVATED" => TargetStatus::Deactivated,
+            _ => TargetStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TargetStatus {
+    fn from(value: String) -> TargetStatus {
+        match value.as_str() {
+            "PENDING" => TargetStatus::Pending,
+            "ACTIVE" => TargetStatus::Active,
+            "UNREACHABLE" => TargetStatus::Unreachable,
+            "INACTIVE" => TargetStatus::Inactive,
+            "DEACTIVATED" => TargetStatus::Deactivated,
+            _ => TargetStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TargetStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TargetStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TargetStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TargetStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TargetStatus {
+    fn deserialize<D>(deserializer: D) -> Result<TargetStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about the targets specified for a notification rule.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -401,7 +999,7 @@ pub struct TargetSummary {
     /// <p>The status of the target.</p>
     #[serde(rename = "TargetStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub target_status: Option<String>,
+    pub target_status: Option<TargetStatus>,
     /// <p>The type of the target (for example, SNS).</p>
     #[serde(rename = "TargetType")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -451,7 +1049,7 @@ pub struct UpdateNotificationRuleRequest {
     /// <p>The level of detail to include in the notifications for this resource. BASIC will include only the contents of the event as it would appear in AWS CloudWatch. FULL will include any supplemental information provided by AWS CodeStar Notifications and/or the service for the resource for which the notification is created.</p>
     #[serde(rename = "DetailType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub detail_type: Option<String>,
+    pub detail_type: Option<DetailType>,
     /// <p>A list of event types associated with this notification rule.</p>
     #[serde(rename = "EventTypeIds")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -463,7 +1061,7 @@ pub struct UpdateNotificationRuleRequest {
     /// <p>The status of the notification rule. Valid statuses include enabled (sending notifications) or disabled (not sending notifications).</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<NotificationRuleStatus>,
     /// <p>The address and type of the targets to receive notifications from this notification rule.</p>
     #[serde(rename = "Targets")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1007,11 +1605,15 @@ pub struct CodeStarNotificationsClient {
 impl CodeStarNotificationsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> CodeStarNotificationsClient {
         CodeStarNotificationsClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(
+                region,
+                "codestar notifications",
+            ),
         }
     }
 
@@ -1046,6 +1648,7 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation("CreateNotificationRule");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1077,6 +1680,7 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation("DeleteNotificationRule");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1108,6 +1712,7 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation("DeleteTarget");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1139,6 +1744,7 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation("DescribeNotificationRule");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1170,6 +1776,7 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation("ListEventTypes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1201,6 +1808,7 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation("ListNotificationRules");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1232,6 +1840,7 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1263,6 +1872,7 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation("ListTargets");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1294,6 +1904,7 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation("Subscribe");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1325,6 +1936,7 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1356,6 +1968,7 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation("Unsubscribe");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1387,6 +2000,7 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1418,6 +2032,7 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation("UpdateNotificationRule");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1440,3 +2055,101 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
         }
     }
 }
+impl CodeStarNotificationsClient {
+    /// Auto-paginating version of `list_event_types`, streaming every page of results.
+    pub fn list_event_types_pages(
+        &self,
+        input: ListEventTypesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListEventTypesResult, ListEventTypesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeStarNotificationsClient, input: ListEventTypesRequest| async move {
+                client.list_event_types(input).await
+            },
+            |input: &ListEventTypesRequest, output: &ListEventTypesResult| {
+                Some(ListEventTypesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_event_types`, streaming the `EventTypes` of every page of results.
+    pub fn list_event_types_items(
+        &self,
+        input: ListEventTypesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<EventTypeSummary, ListEventTypesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_event_types_pages(input),
+            |output: ListEventTypesResult| output.event_types.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_notification_rules`, streaming every page of results.
+    pub fn list_notification_rules_pages(
+        &self,
+        input: ListNotificationRulesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListNotificationRulesResult,
+        ListNotificationRulesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeStarNotificationsClient, input: ListNotificationRulesRequest| async move {
+                client.list_notification_rules(input).await
+            },
+            |input: &ListNotificationRulesRequest, output: &ListNotificationRulesResult| {
+                Some(ListNotificationRulesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_notification_rules`, streaming the `NotificationRules` of every page of results.
+    pub fn list_notification_rules_items(
+        &self,
+        input: ListNotificationRulesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<NotificationRuleSummary, ListNotificationRulesError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_notification_rules_pages(input),
+            |output: ListNotificationRulesResult| output.notification_rules.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_targets`, streaming every page of results.
+    pub fn list_targets_pages(
+        &self,
+        input: ListTargetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListTargetsResult, ListTargetsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeStarNotificationsClient, input: ListTargetsRequest| async move {
+                client.list_targets(input).await
+            },
+            |input: &ListTargetsRequest, output: &ListTargetsResult| {
+                Some(ListTargetsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_targets`, streaming the `Targets` of every page of results.
+    pub fn list_targets_items(
+        &self,
+        input: ListTargetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<TargetSummary, ListTargetsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_targets_pages(input),
+            |output: ListTargetsResult| output.targets.unwrap_or_default(),
+        )
+    }
+}
@@ -172,8 +172,13 @@ pub struct CreateUserProfileRequest {
 pub struct CreateUserProfileResult {
     /// <p>The date the user profile was created, in timestamp format.</p>
     #[serde(rename = "createdTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_timestamp: Option<f64>,
+    pub created_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name that is displayed as the friendly name for the user in AWS CodeStar.</p>
     #[serde(rename = "displayName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -184,8 +189,13 @@ pub struct CreateUserProfileResult {
     pub email_address: Option<String>,
     /// <p>The date the user profile was last modified, in timestamp format.</p>
     #[serde(rename = "lastModifiedTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_timestamp: Option<f64>,
+    pub last_modified_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The SSH public key associated with the user in AWS CodeStar. This is the public portion of the public/private keypair the user can use to access project resources if a project owner allows the user remote access to those resources.</p>
     #[serde(rename = "sshPublicKey")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -261,8 +271,13 @@ pub struct DescribeProjectResult {
     pub client_request_token: Option<String>,
     /// <p>The date and time the project was created, in timestamp format.</p>
     #[serde(rename = "createdTimeStamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time_stamp: Option<f64>,
+    pub created_time_stamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The description of the project, if any.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -302,7 +317,12 @@ pub struct DescribeUserProfileRequest {
 pub struct DescribeUserProfileResult {
     /// <p>The date and time when the user profile was created in AWS CodeStar, in timestamp format.</p>
     #[serde(rename = "createdTimestamp")]
-    pub created_timestamp: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub created_timestamp: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The display name shown for the user in AWS CodeStar projects. For example, this could be set to both first and last name ("Mary Major") or a single name ("Mary"). The display name is also used to generate the initial icon associated with the user in AWS CodeStar projects. If spaces are included in the display name, the first character that appears after the space will be used as the second character in the user initial icon. The initial icon displays a maximum of two characters, so a display name with more than one space (for example "Mary Jane Major") would generate an initial icon using the first character and the first character after the space ("MJ", not "MM").</p>
     #[serde(rename = "displayName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -313,7 +333,12 @@ pub struct DescribeUserProfileResult {
     pub email_address: Option<String>,
     /// <p>The date and time when the user profile was last modified, in timestamp format.</p>
     #[serde(rename = "lastModifiedTimestamp")]
-    pub last_modified_timestamp: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub last_modified_timestamp: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The SSH public key associated with the user. This SSH public key is associated with the user profile, and can be used in conjunction with the associated private key for access to project resources, such as Amazon EC2 instances, if a project owner grants remote access to those resources.</p>
     #[serde(rename = "sshPublicKey")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -710,8 +735,13 @@ pub struct UpdateUserProfileRequest {
 pub struct UpdateUserProfileResult {
     /// <p>The date the user profile was created, in timestamp format.</p>
     #[serde(rename = "createdTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_timestamp: Option<f64>,
+    pub created_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name that is displayed as the friendly name for the user in AWS CodeStar.</p>
     #[serde(rename = "displayName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -722,8 +752,13 @@ pub struct UpdateUserProfileResult {
     pub email_address: Option<String>,
     /// <p>The date the user profile was last modified, in timestamp format.</p>
     #[serde(rename = "lastModifiedTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_timestamp: Option<f64>,
+    pub last_modified_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The SSH public key associated with the user in AWS CodeStar. This is the public portion of the public/private keypair the user can use to access project resources if a project owner allows the user remote access to those resources.</p>
     #[serde(rename = "sshPublicKey")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1612,11 +1647,12 @@ pub struct CodeStarClient {
 impl CodeStarClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> CodeStarClient {
         CodeStarClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "CodeStar"),
         }
     }
 
@@ -1648,6 +1684,7 @@ impl CodeStar for CodeStarClient {
         input: AssociateTeamMemberRequest,
     ) -> Result<AssociateTeamMemberResult, RusotoError<AssociateTeamMemberError>> {
         let mut request = SignedRequest::new("POST", "codestar", &self.region, "/");
+        request.set_operation("AssociateTeamMember");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeStar_20170419.AssociateTeamMember");
@@ -1676,6 +1713,7 @@ impl CodeStar for CodeStarClient {
         input: CreateProjectRequest,
     ) -> Result<CreateProjectResult, RusotoError<CreateProjectError>> {
         let mut request = SignedRequest::new("POST", "codestar", &self.region, "/");
+        request.set_operation("CreateProject");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeStar_20170419.CreateProject");
@@ -1703,6 +1741,7 @@ impl CodeStar for CodeStarClient {
         input: CreateUserProfileRequest,
     ) -> Result<CreateUserProfileResult, RusotoError<CreateUserProfileError>> {
         let mut request = SignedRequest::new("POST", "codestar", &self.region, "/");
+        request.set_operation("CreateUserProfile");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeStar_20170419.CreateUserProfile");
@@ -1730,6 +1769,7 @@ impl CodeStar for CodeStarClient {
         input: DeleteProjectRequest,
     ) -> Result<DeleteProjectResult, RusotoError<DeleteProjectError>> {
         let mut request = SignedRequest::new("POST", "codestar", &self.region, "/");
+        request.set_operation("DeleteProject");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeStar_20170419.DeleteProject");
@@ -1757,6 +1797,7 @@ impl CodeStar for CodeStarClient {
         input: DeleteUserProfileRequest,
     ) -> Result<DeleteUserProfileResult, RusotoError<DeleteUserProfileError>> {
         let mut request = SignedRequest::new("POST", "codestar", &self.region, "/");
+        request.set_operation("DeleteUserProfile");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeStar_20170419.DeleteUserProfile");
@@ -1784,6 +1825,7 @@ impl CodeStar for CodeStarClient {
         input: DescribeProjectRequest,
     ) -> Result<DescribeProjectResult, RusotoError<DescribeProjectError>> {
         let mut request = SignedRequest::new("POST", "codestar", &self.region, "/");
+        request.set_operation("DescribeProject");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeStar_20170419.DescribeProject");
@@ -1811,6 +1853,7 @@ impl CodeStar for CodeStarClient {
         input: DescribeUserProfileRequest,
     ) -> Result<DescribeUserProfileResult, RusotoError<DescribeUserProfileError>> {
         let mut request = SignedRequest::new("POST", "codestar", &self.region, "/");
+        request.set_operation("DescribeUserProfile");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeStar_20170419.DescribeUserProfile");
@@ -1839,6 +1882,7 @@ impl CodeStar for CodeStarClient {
         input: DisassociateTeamMemberRequest,
     ) -> Result<DisassociateTeamMemberResult, RusotoError<DisassociateTeamMemberError>> {
         let mut request = SignedRequest::new("POST", "codestar", &self.region, "/");
+        request.set_operation("DisassociateTeamMember");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeStar_20170419.DisassociateTeamMember");
@@ -1867,6 +1911,7 @@ impl CodeStar for CodeStarClient {
         input: ListProjectsRequest,
     ) -> Result<ListProjectsResult, RusotoError<ListProjectsError>> {
         let mut request = SignedRequest::new("POST", "codestar", &self.region, "/");
+        request.set_operation("ListProjects");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeStar_20170419.ListProjects");
@@ -1894,6 +1939,7 @@ impl CodeStar for CodeStarClient {
         input: ListResourcesRequest,
     ) -> Result<ListResourcesResult, RusotoError<ListResourcesError>> {
         let mut request = SignedRequest::new("POST", "codestar", &self.region, "/");
+        request.set_operation("ListResources");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeStar_20170419.ListResources");
@@ -1921,6 +1967,7 @@ impl CodeStar for CodeStarClient {
         input: ListTagsForProjectRequest,
     ) -> Result<ListTagsForProjectResult, RusotoError<ListTagsForProjectError>> {
         let mut request = SignedRequest::new("POST", "codestar", &self.region, "/");
+        request.set_operation("ListTagsForProject");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeStar_20170419.ListTagsForProject");
@@ -1949,6 +1996,7 @@ impl CodeStar for CodeStarClient {
         input: ListTeamMembersRequest,
     ) -> Result<ListTeamMembersResult, RusotoError<ListTeamMembersError>> {
         let mut request = SignedRequest::new("POST", "codestar", &self.region, "/");
+        request.set_operation("ListTeamMembers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeStar_20170419.ListTeamMembers");
@@ -1976,6 +2024,7 @@ impl CodeStar for CodeStarClient {
         input: ListUserProfilesRequest,
     ) -> Result<ListUserProfilesResult, RusotoError<ListUserProfilesError>> {
         let mut request = SignedRequest::new("POST", "codestar", &self.region, "/");
+        request.set_operation("ListUserProfiles");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeStar_20170419.ListUserProfiles");
@@ -2003,6 +2052,7 @@ impl CodeStar for CodeStarClient {
         input: TagProjectRequest,
     ) -> Result<TagProjectResult, RusotoError<TagProjectError>> {
         let mut request = SignedRequest::new("POST", "codestar", &self.region, "/");
+        request.set_operation("TagProject");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeStar_20170419.TagProject");
@@ -2030,6 +2080,7 @@ impl CodeStar for CodeStarClient {
         input: UntagProjectRequest,
     ) -> Result<UntagProjectResult, RusotoError<UntagProjectError>> {
         let mut request = SignedRequest::new("POST", "codestar", &self.region, "/");
+        request.set_operation("UntagProject");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeStar_20170419.UntagProject");
@@ -2057,6 +2108,7 @@ impl CodeStar for CodeStarClient {
         input: UpdateProjectRequest,
     ) -> Result<UpdateProjectResult, RusotoError<UpdateProjectError>> {
         let mut request = SignedRequest::new("POST", "codestar", &self.region, "/");
+        request.set_operation("UpdateProject");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeStar_20170419.UpdateProject");
@@ -2084,6 +2136,7 @@ impl CodeStar for CodeStarClient {
         input: UpdateTeamMemberRequest,
     ) -> Result<UpdateTeamMemberResult, RusotoError<UpdateTeamMemberError>> {
         let mut request = SignedRequest::new("POST", "codestar", &self.region, "/");
+        request.set_operation("UpdateTeamMember");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeStar_20170419.UpdateTeamMember");
@@ -2111,6 +2164,7 @@ impl CodeStar for CodeStarClient {
         input: UpdateUserProfileRequest,
     ) -> Result<UpdateUserProfileResult, RusotoError<UpdateUserProfileError>> {
         let mut request = SignedRequest::new("POST", "codestar", &self.region, "/");
+        request.set_operation("UpdateUserProfile");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeStar_20170419.UpdateUserProfile");
@@ -2132,3 +2186,128 @@ impl CodeStar for CodeStarClient {
         }
     }
 }
+impl CodeStarClient {
+    /// Auto-paginating version of `list_projects`, streaming every page of results.
+    pub fn list_projects_pages(
+        &self,
+        input: ListProjectsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListProjectsResult, ListProjectsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeStarClient, input: ListProjectsRequest| async move {
+                client.list_projects(input).await
+            },
+            |input: &ListProjectsRequest, output: &ListProjectsResult| {
+                Some(ListProjectsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_projects`, streaming the `projects` of every page of results.
+    pub fn list_projects_items(
+        &self,
+        input: ListProjectsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ProjectSummary, ListProjectsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_projects_pages(input),
+            |output: ListProjectsResult| output.projects,
+        )
+    }
+
+    /// Auto-paginating version of `list_resources`, streaming every page of results.
+    pub fn list_resources_pages(
+        &self,
+        input: ListResourcesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListResourcesResult, ListResourcesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeStarClient, input: ListResourcesRequest| async move {
+                client.list_resources(input).await
+            },
+            |input: &ListResourcesRequest, output: &ListResourcesResult| {
+                Some(ListResourcesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_resources`, streaming the `resources` of every page of results.
+    pub fn list_resources_items(
+        &self,
+        input: ListResourcesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Resource, ListResourcesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_resources_pages(input),
+            |output: ListResourcesResult| output.resources.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_team_members`, streaming every page of results.
+    pub fn list_team_members_pages(
+        &self,
+        input: ListTeamMembersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListTeamMembersResult, ListTeamMembersError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeStarClient, input: ListTeamMembersRequest| async move {
+                client.list_team_members(input).await
+            },
+            |input: &ListTeamMembersRequest, output: &ListTeamMembersResult| {
+                Some(ListTeamMembersRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_team_members`, streaming the `teamMembers` of every page of results.
+    pub fn list_team_members_items(
+        &self,
+        input: ListTeamMembersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<TeamMember, ListTeamMembersError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_team_members_pages(input),
+            |output: ListTeamMembersResult| output.team_members,
+        )
+    }
+
+    /// Auto-paginating version of `list_user_profiles`, streaming every page of results.
+    pub fn list_user_profiles_pages(
+        &self,
+        input: ListUserProfilesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListUserProfilesResult, ListUserProfilesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeStarClient, input: ListUserProfilesRequest| async move {
+                client.list_user_profiles(input).await
+            },
+            |input: &ListUserProfilesRequest, output: &ListUserProfilesResult| {
+                Some(ListUserProfilesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_user_profiles`, streaming the `userProfiles` of every page of results.
+    pub fn list_user_profiles_items(
+        &self,
+        input: ListUserProfilesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<UserProfileSummary, ListUserProfilesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_user_profiles_pages(input),
+            |output: ListUserProfilesResult| output.user_profiles,
+        )
+    }
+}
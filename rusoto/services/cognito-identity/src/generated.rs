@@ -24,6 +24,99 @@ use rusoto_core::signature::SignedRequest;
 #[allow(unused_imports)]
 use serde::{Deserialize, Serialize};
 use serde_json;
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AmbiguousRoleResolutionType {
+    /// `AuthenticatedRole`
+    AuthenticatedRole,
+    /// `Deny`
+    Deny,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AmbiguousRoleResolutionType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AmbiguousRoleResolutionType::AuthenticatedRole => "AuthenticatedRole",
+            AmbiguousRoleResolutionType::Deny => "Deny",
+            AmbiguousRoleResolutionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AmbiguousRoleResolutionType {
+    fn default() -> AmbiguousRoleResolutionType {
+        AmbiguousRoleResolutionType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AmbiguousRoleResolutionType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AmbiguousRoleResolutionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AmbiguousRoleResolutionType {
+    fn from(value: &'a str) -> AmbiguousRoleResolutionType {
+        match value {
+            "AuthenticatedRole" => AmbiguousRoleResolutionType::AuthenticatedRole,
+            "Deny" => AmbiguousRoleResolutionType::Deny,
+            _ => AmbiguousRoleResolutionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AmbiguousRoleResolutionType {
+    fn from(value: String) -> AmbiguousRoleResolutionType {
+        match value.as_str() {
+            "AuthenticatedRole" => AmbiguousRoleResolutionType::AuthenticatedRole,
+            "Deny" => AmbiguousRoleResolutionType::Deny,
+            _ => AmbiguousRoleResolutionType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AmbiguousRoleResolutionType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AmbiguousRoleResolutionType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AmbiguousRoleResolutionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AmbiguousRoleResolutionType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AmbiguousRoleResolutionType {
+    fn deserialize<D>(deserializer: D) -> Result<AmbiguousRoleResolutionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A provider representing an Amazon Cognito user pool and its client ID.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CognitoIdentityProvider {
@@ -91,8 +184,13 @@ pub struct Credentials {
     pub access_key_id: Option<String>,
     /// <p>The date at which these credentials will expire.</p>
     #[serde(rename = "Expiration")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expiration: Option<f64>,
+    pub expiration: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The Secret Access Key portion of the credentials</p>
     #[serde(rename = "SecretKey")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -149,6 +247,99 @@ pub struct DescribeIdentityPoolInput {
     pub identity_pool_id: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorCode {
+    /// `AccessDenied`
+    AccessDenied,
+    /// `InternalServerError`
+    InternalServerError,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ErrorCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ErrorCode::AccessDenied => "AccessDenied",
+            ErrorCode::InternalServerError => "InternalServerError",
+            ErrorCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ErrorCode {
+    fn default() -> ErrorCode {
+        ErrorCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ErrorCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ErrorCode {
+    fn from(value: &'a str) -> ErrorCode {
+        match value {
+            "AccessDenied" => ErrorCode::AccessDenied,
+            "InternalServerError" => ErrorCode::InternalServerError,
+            _ => ErrorCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ErrorCode {
+    fn from(value: String) -> ErrorCode {
+        match value.as_str() {
+            "AccessDenied" => ErrorCode::AccessDenied,
+            "InternalServerError" => ErrorCode::InternalServerError,
+            _ => ErrorCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ErrorCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ErrorCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ErrorCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ErrorCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ErrorCode {
+    fn deserialize<D>(deserializer: D) -> Result<ErrorCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Input to the <code>GetCredentialsForIdentity</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -301,16 +492,26 @@ pub struct GetOpenIdTokenResponse {
 pub struct IdentityDescription {
     /// <p>Date on which the identity was created.</p>
     #[serde(rename = "CreationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A unique identifier in the format REGION:GUID.</p>
     #[serde(rename = "IdentityId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub identity_id: Option<String>,
     /// <p>Date on which the identity was last modified.</p>
     #[serde(rename = "LastModifiedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_date: Option<f64>,
+    pub last_modified_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The provider names.</p>
     #[serde(rename = "Logins")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -506,7 +707,7 @@ pub struct MappingRule {
     pub claim: String,
     /// <p>The match condition that specifies how closely the claim value in the IdP token must match <code>Value</code>.</p>
     #[serde(rename = "MatchType")]
-    pub match_type: String,
+    pub match_type: MappingRuleMatchType,
     /// <p>The role ARN.</p>
     #[serde(rename = "RoleARN")]
     pub role_arn: String,
@@ -515,6 +716,109 @@ pub struct MappingRule {
     pub value: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MappingRuleMatchType {
+    /// `Equals`
+    Equals,
+    /// `Contains`
+    Contains,
+    /// `StartsWith`
+    StartsWith,
+    /// `NotEqual`
+    NotEqual,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl MappingRuleMatchType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MappingRuleMatchType::Equals => "Equals",
+            MappingRuleMatchType::Contains => "Contains",
+            MappingRuleMatchType::StartsWith => "StartsWith",
+            MappingRuleMatchType::NotEqual => "NotEqual",
+            MappingRuleMatchType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for MappingRuleMatchType {
+    fn default() -> MappingRuleMatchType {
+        MappingRuleMatchType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for MappingRuleMatchType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for MappingRuleMatchType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for MappingRuleMatchType {
+    fn from(value: &'a str) -> MappingRuleMatchType {
+        match value {
+            "Equals" => MappingRuleMatchType::Equals,
+            "Contains" => MappingRuleMatchType::Contains,
+            "StartsWith" => MappingRuleMatchType::StartsWith,
+            "NotEqual" => MappingRuleMatchType::NotEqual,
+            _ => MappingRuleMatchType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MappingRuleMatchType {
+    fn from(value: String) -> MappingRuleMatchType {
+        match value.as_str() {
+            "Equals" => MappingRuleMatchType::Equals,
+            "Contains" => MappingRuleMatchType::Contains,
+            "StartsWith" => MappingRuleMatchType::StartsWith,
+            "NotEqual" => MappingRuleMatchType::NotEqual,
+            _ => MappingRuleMatchType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for MappingRuleMatchType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<MappingRuleMatchType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MappingRuleMatchType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for MappingRuleMatchType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MappingRuleMatchType {
+    fn deserialize<D>(deserializer: D) -> Result<MappingRuleMatchType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Input to the <code>MergeDeveloperIdentities</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -549,16 +853,109 @@ pub struct RoleMapping {
     /// <p>If you specify Token or Rules as the <code>Type</code>, <code>AmbiguousRoleResolution</code> is required.</p> <p>Specifies the action to be taken if either no rules match the claim value for the <code>Rules</code> type, or there is no <code>cognito:preferred_role</code> claim and there are multiple <code>cognito:roles</code> matches for the <code>Token</code> type.</p>
     #[serde(rename = "AmbiguousRoleResolution")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ambiguous_role_resolution: Option<String>,
+    pub ambiguous_role_resolution: Option<AmbiguousRoleResolutionType>,
     /// <p>The rules to be used for mapping users to roles.</p> <p>If you specify Rules as the role mapping type, <code>RulesConfiguration</code> is required.</p>
     #[serde(rename = "RulesConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub rules_configuration: Option<RulesConfigurationType>,
     /// <p>The role mapping type. Token will use <code>cognito:roles</code> and <code>cognito:preferred_role</code> claims from the Cognito identity provider token to map groups to roles. Rules will attempt to match claims from the token to map to a role.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: RoleMappingType,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RoleMappingType {
+    /// `Token`
+    Token,
+    /// `Rules`
+    Rules,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RoleMappingType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RoleMappingType::Token => "Token",
+            RoleMappingType::Rules => "Rules",
+            RoleMappingType::Unknown(ref value) => value,
+        }
+    }
 }
 
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RoleMappingType {
+    fn default() -> RoleMappingType {
+        RoleMappingType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RoleMappingType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RoleMappingType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RoleMappingType {
+    fn from(value: &'a str) -> RoleMappingType {
+        match value {
+            "Token" => RoleMappingType::Token,
+            "Rules" => RoleMappingType::Rules,
+            _ => RoleMappingType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RoleMappingType {
+    fn from(value: String) -> RoleMappingType {
+        match value.as_str() {
+            "Token" => RoleMappingType::Token,
+            "Rules" => RoleMappingType::Rules,
+            _ => RoleMappingType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RoleMappingType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RoleMappingType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RoleMappingType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RoleMappingType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RoleMappingType {
+    fn deserialize<D>(deserializer: D) -> Result<RoleMappingType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A container for rules.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RulesConfigurationType {
@@ -638,7 +1035,7 @@ pub struct UnprocessedIdentityId {
     /// <p>The error code indicating the type of error that occurred.</p>
     #[serde(rename = "ErrorCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub error_code: Option<String>,
+    pub error_code: Option<ErrorCode>,
     /// <p>A unique identifier in the format REGION:GUID.</p>
     #[serde(rename = "IdentityId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2169,11 +2566,12 @@ pub struct CognitoIdentityClient {
 impl CognitoIdentityClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> CognitoIdentityClient {
         CognitoIdentityClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Cognito Identity"),
         }
     }
 
@@ -2205,6 +2603,7 @@ impl CognitoIdentity for CognitoIdentityClient {
         input: CreateIdentityPoolInput,
     ) -> Result<IdentityPool, RusotoError<CreateIdentityPoolError>> {
         let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+        request.set_operation("CreateIdentityPool");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2235,6 +2634,7 @@ impl CognitoIdentity for CognitoIdentityClient {
         input: DeleteIdentitiesInput,
     ) -> Result<DeleteIdentitiesResponse, RusotoError<DeleteIdentitiesError>> {
         let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+        request.set_operation("DeleteIdentities");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSCognitoIdentityService.DeleteIdentities");
@@ -2263,6 +2663,7 @@ impl CognitoIdentity for CognitoIdentityClient {
         input: DeleteIdentityPoolInput,
     ) -> Result<(), RusotoError<DeleteIdentityPoolError>> {
         let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+        request.set_operation("DeleteIdentityPool");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2293,6 +2694,7 @@ impl CognitoIdentity for CognitoIdentityClient {
         input: DescribeIdentityInput,
     ) -> Result<IdentityDescription, RusotoError<DescribeIdentityError>> {
         let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+        request.set_operation("DescribeIdentity");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSCognitoIdentityService.DescribeIdentity");
@@ -2320,6 +2722,7 @@ impl CognitoIdentity for CognitoIdentityClient {
         input: DescribeIdentityPoolInput,
     ) -> Result<IdentityPool, RusotoError<DescribeIdentityPoolError>> {
         let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+        request.set_operation("DescribeIdentityPool");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2351,6 +2754,7 @@ impl CognitoIdentity for CognitoIdentityClient {
     ) -> Result<GetCredentialsForIdentityResponse, RusotoError<GetCredentialsForIdentityError>>
     {
         let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+        request.set_operation("GetCredentialsForIdentity");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2379,6 +2783,7 @@ impl CognitoIdentity for CognitoIdentityClient {
     /// <p>Generates (or retrieves) a Cognito ID. Supplying multiple logins will create an implicit linked account.</p> <p>This is a public API. You do not need any credentials to call this API.</p>
     async fn get_id(&self, input: GetIdInput) -> Result<GetIdResponse, RusotoError<GetIdError>> {
         let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+        request.set_operation("GetId");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSCognitoIdentityService.GetId");
@@ -2406,6 +2811,7 @@ impl CognitoIdentity for CognitoIdentityClient {
         input: GetIdentityPoolRolesInput,
     ) -> Result<GetIdentityPoolRolesResponse, RusotoError<GetIdentityPoolRolesError>> {
         let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+        request.set_operation("GetIdentityPoolRoles");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2437,6 +2843,7 @@ impl CognitoIdentity for CognitoIdentityClient {
         input: GetOpenIdTokenInput,
     ) -> Result<GetOpenIdTokenResponse, RusotoError<GetOpenIdTokenError>> {
         let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+        request.set_operation("GetOpenIdToken");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSCognitoIdentityService.GetOpenIdToken");
@@ -2467,6 +2874,7 @@ impl CognitoIdentity for CognitoIdentityClient {
         RusotoError<GetOpenIdTokenForDeveloperIdentityError>,
     > {
         let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+        request.set_operation("GetOpenIdTokenForDeveloperIdentity");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2500,6 +2908,7 @@ impl CognitoIdentity for CognitoIdentityClient {
         input: ListIdentitiesInput,
     ) -> Result<ListIdentitiesResponse, RusotoError<ListIdentitiesError>> {
         let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+        request.set_operation("ListIdentities");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSCognitoIdentityService.ListIdentities");
@@ -2527,6 +2936,7 @@ impl CognitoIdentity for CognitoIdentityClient {
         input: ListIdentityPoolsInput,
     ) -> Result<ListIdentityPoolsResponse, RusotoError<ListIdentityPoolsError>> {
         let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+        request.set_operation("ListIdentityPools");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2558,6 +2968,7 @@ impl CognitoIdentity for CognitoIdentityClient {
         input: ListTagsForResourceInput,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2589,6 +3000,7 @@ impl CognitoIdentity for CognitoIdentityClient {
         input: LookupDeveloperIdentityInput,
     ) -> Result<LookupDeveloperIdentityResponse, RusotoError<LookupDeveloperIdentityError>> {
         let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+        request.set_operation("LookupDeveloperIdentity");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2620,6 +3032,7 @@ impl CognitoIdentity for CognitoIdentityClient {
         input: MergeDeveloperIdentitiesInput,
     ) -> Result<MergeDeveloperIdentitiesResponse, RusotoError<MergeDeveloperIdentitiesError>> {
         let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+        request.set_operation("MergeDeveloperIdentities");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2651,6 +3064,7 @@ impl CognitoIdentity for CognitoIdentityClient {
         input: SetIdentityPoolRolesInput,
     ) -> Result<(), RusotoError<SetIdentityPoolRolesError>> {
         let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+        request.set_operation("SetIdentityPoolRoles");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2681,6 +3095,7 @@ impl CognitoIdentity for CognitoIdentityClient {
         input: TagResourceInput,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSCognitoIdentityService.TagResource");
@@ -2708,6 +3123,7 @@ impl CognitoIdentity for CognitoIdentityClient {
         input: UnlinkDeveloperIdentityInput,
     ) -> Result<(), RusotoError<UnlinkDeveloperIdentityError>> {
         let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+        request.set_operation("UnlinkDeveloperIdentity");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2738,6 +3154,7 @@ impl CognitoIdentity for CognitoIdentityClient {
         input: UnlinkIdentityInput,
     ) -> Result<(), RusotoError<UnlinkIdentityError>> {
         let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+        request.set_operation("UnlinkIdentity");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSCognitoIdentityService.UnlinkIdentity");
@@ -2765,6 +3182,7 @@ impl CognitoIdentity for CognitoIdentityClient {
         input: UntagResourceInput,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSCognitoIdentityService.UntagResource");
@@ -2792,6 +3210,7 @@ impl CognitoIdentity for CognitoIdentityClient {
         input: IdentityPool,
     ) -> Result<IdentityPool, RusotoError<UpdateIdentityPoolError>> {
         let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+        request.set_operation("UpdateIdentityPool");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2816,3 +3235,37 @@ impl CognitoIdentity for CognitoIdentityClient {
         }
     }
 }
+impl CognitoIdentityClient {
+    /// Auto-paginating version of `list_identity_pools`, streaming every page of results.
+    pub fn list_identity_pools_pages(
+        &self,
+        input: ListIdentityPoolsInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListIdentityPoolsResponse, ListIdentityPoolsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CognitoIdentityClient, input: ListIdentityPoolsInput| async move {
+                client.list_identity_pools(input).await
+            },
+            |input: &ListIdentityPoolsInput, output: &ListIdentityPoolsResponse| {
+                Some(ListIdentityPoolsInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_identity_pools`, streaming the `IdentityPools` of every page of results.
+    pub fn list_identity_pools_items(
+        &self,
+        input: ListIdentityPoolsInput,
+    ) -> rusoto_core::pagination::RusotoStream<IdentityPoolShortDescription, ListIdentityPoolsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_identity_pools_pages(input),
+            |output: ListIdentityPoolsResponse| output.identity_pools.unwrap_or_default(),
+        )
+    }
+}
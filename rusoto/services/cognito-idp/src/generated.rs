@@ -38,7 +38,7 @@ pub struct AccountRecoverySettingType {
 pub struct AccountTakeoverActionType {
     /// <p><p>The event action.</p> <ul> <li> <p> <code>BLOCK</code> Choosing this action will block the request.</p> </li> <li> <p> <code>MFA<em>IF</em>CONFIGURED</code> Throw MFA challenge if user has configured it, else allow the request.</p> </li> <li> <p> <code>MFA<em>REQUIRED</code> Throw MFA challenge if user has configured it, else block the request.</p> </li> <li> <p> <code>NO</em>ACTION</code> Allow the user sign-in.</p> </li> </ul></p>
     #[serde(rename = "EventAction")]
-    pub event_action: String,
+    pub event_action: AccountTakeoverEventActionType,
     /// <p>Flag specifying whether to send a notification.</p>
     #[serde(rename = "Notify")]
     pub notify: bool,
@@ -61,6 +61,109 @@ pub struct AccountTakeoverActionsType {
     pub medium_action: Option<AccountTakeoverActionType>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AccountTakeoverEventActionType {
+    /// `BLOCK`
+    Block,
+    /// `MFA_IF_CONFIGURED`
+    MfaIfConfigured,
+    /// `MFA_REQUIRED`
+    MfaRequired,
+    /// `NO_ACTION`
+    NoAction,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AccountTakeoverEventActionType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AccountTakeoverEventActionType::Block => "BLOCK",
+            AccountTakeoverEventActionType::MfaIfConfigured => "MFA_IF_CONFIGURED",
+            AccountTakeoverEventActionType::MfaRequired => "MFA_REQUIRED",
+            AccountTakeoverEventActionType::NoAction => "NO_ACTION",
+            AccountTakeoverEventActionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AccountTakeoverEventActionType {
+    fn default() -> AccountTakeoverEventActionType {
+        AccountTakeoverEventActionType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AccountTakeoverEventActionType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AccountTakeoverEventActionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AccountTakeoverEventActionType {
+    fn from(value: &'a str) -> AccountTakeoverEventActionType {
+        match value {
+            "BLOCK" => AccountTakeoverEventActionType::Block,
+            "MFA_IF_CONFIGURED" => AccountTakeoverEventActionType::MfaIfConfigured,
+            "MFA_REQUIRED" => AccountTakeoverEventActionType::MfaRequired,
+            "NO_ACTION" => AccountTakeoverEventActionType::NoAction,
+            _ => AccountTakeoverEventActionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AccountTakeoverEventActionType {
+    fn from(value: String) -> AccountTakeoverEventActionType {
+        match value.as_str() {
+            "BLOCK" => AccountTakeoverEventActionType::Block,
+            "MFA_IF_CONFIGURED" => AccountTakeoverEventActionType::MfaIfConfigured,
+            "MFA_REQUIRED" => AccountTakeoverEventActionType::MfaRequired,
+            "NO_ACTION" => AccountTakeoverEventActionType::NoAction,
+            _ => AccountTakeoverEventActionType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AccountTakeoverEventActionType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AccountTakeoverEventActionType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AccountTakeoverEventActionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AccountTakeoverEventActionType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AccountTakeoverEventActionType {
+    fn deserialize<D>(deserializer: D) -> Result<AccountTakeoverEventActionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Configuration for mitigation actions and notification for different levels of risk detected for a potential account takeover.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AccountTakeoverRiskConfigurationType {
@@ -153,7 +256,7 @@ pub struct AdminCreateUserRequest {
     /// <p>Specify <code>"EMAIL"</code> if email will be used to send the welcome message. Specify <code>"SMS"</code> if the phone number will be used. The default value is <code>"SMS"</code>. More than one value can be specified.</p>
     #[serde(rename = "DesiredDeliveryMediums")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub desired_delivery_mediums: Option<Vec<String>>,
+    pub desired_delivery_mediums: Option<Vec<DeliveryMediumType>>,
     /// <p>This parameter is only used if the <code>phone_number_verified</code> or <code>email_verified</code> attribute is set to <code>True</code>. Otherwise, it is ignored.</p> <p>If this parameter is set to <code>True</code> and the phone number or email address specified in the UserAttributes parameter already exists as an alias with a different user, the API call will migrate the alias from the previous user to the newly created user. The previous user will no longer be able to log in using that alias.</p> <p>If this parameter is set to <code>False</code>, the API throws an <code>AliasExistsException</code> error if the alias already exists. The default value is <code>False</code>.</p>
     #[serde(rename = "ForceAliasCreation")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -161,7 +264,7 @@ pub struct AdminCreateUserRequest {
     /// <p>Set to <code>"RESEND"</code> to resend the invitation message to a user that already exists and reset the expiration limit on the user's account. Set to <code>"SUPPRESS"</code> to suppress sending the message. Only one value can be specified.</p>
     #[serde(rename = "MessageAction")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub message_action: Option<String>,
+    pub message_action: Option<MessageActionType>,
     /// <p>The user's temporary password. This password must conform to the password policy that you specified when you created the user pool.</p> <p>The temporary password is valid only once. To complete the Admin Create User flow, the user must enter the temporary password in the sign-in page along with a new password to be used in all future sign-ins.</p> <p>This parameter is not required. If you do not specify a value, Amazon Cognito generates one for you.</p> <p>The temporary password can only be used until the user account expiration limit that you specified when you created the user pool. To reset the account after that time limit, you must call <code>AdminCreateUser</code> again, specifying <code>"RESEND"</code> for the <code>MessageAction</code> parameter.</p>
     #[serde(rename = "TemporaryPassword")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -346,12 +449,22 @@ pub struct AdminGetUserResponse {
     pub user_attributes: Option<Vec<AttributeType>>,
     /// <p>The date the user was created.</p>
     #[serde(rename = "UserCreateDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub user_create_date: Option<f64>,
+    pub user_create_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The date the user was last modified.</p>
     #[serde(rename = "UserLastModifiedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub user_last_modified_date: Option<f64>,
+    pub user_last_modified_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The MFA options that are enabled for the user. The possible values in this list are <code>SMS_MFA</code> and <code>SOFTWARE_TOKEN_MFA</code>.</p>
     #[serde(rename = "UserMFASettingList")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -359,7 +472,7 @@ pub struct AdminGetUserResponse {
     /// <p><p>The user status. Can be one of the following:</p> <ul> <li> <p>UNCONFIRMED - User has been created but not confirmed.</p> </li> <li> <p>CONFIRMED - User has been confirmed.</p> </li> <li> <p>ARCHIVED - User is no longer active.</p> </li> <li> <p>COMPROMISED - User is disabled due to a potential security threat.</p> </li> <li> <p>UNKNOWN - User status is not known.</p> </li> <li> <p>RESET<em>REQUIRED - User is confirmed, but the user must request a code and reset his or her password before he or she can sign in.</p> </li> <li> <p>FORCE</em>CHANGE_PASSWORD - The user is confirmed and the user can sign in using a temporary password, but on first sign-in, the user must change his or her password to a new value before doing anything else. </p> </li> </ul></p>
     #[serde(rename = "UserStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub user_status: Option<String>,
+    pub user_status: Option<UserStatusType>,
     /// <p>The user name of the user about whom you are receiving information.</p>
     #[serde(rename = "Username")]
     pub username: String,
@@ -375,7 +488,7 @@ pub struct AdminInitiateAuthRequest {
     pub analytics_metadata: Option<AnalyticsMetadataType>,
     /// <p><p>The authentication flow for this call to execute. The API action will depend on this value. For example:</p> <ul> <li> <p> <code>REFRESH<em>TOKEN</em>AUTH</code> will take in a valid refresh token and return new tokens.</p> </li> <li> <p> <code>USER<em>SRP</em>AUTH</code> will take in <code>USERNAME</code> and <code>SRP<em>A</code> and return the SRP variables to be used for next challenge execution.</p> </li> <li> <p> <code>USER</em>PASSWORD<em>AUTH</code> will take in <code>USERNAME</code> and <code>PASSWORD</code> and return the next challenge or tokens.</p> </li> </ul> <p>Valid values include:</p> <ul> <li> <p> <code>USER</em>SRP<em>AUTH</code>: Authentication flow for the Secure Remote Password (SRP) protocol.</p> </li> <li> <p> <code>REFRESH</em>TOKEN<em>AUTH</code>/<code>REFRESH</em>TOKEN</code>: Authentication flow for refreshing the access token and ID token by supplying a valid refresh token.</p> </li> <li> <p> <code>CUSTOM<em>AUTH</code>: Custom authentication flow.</p> </li> <li> <p> <code>ADMIN</em>NO<em>SRP</em>AUTH</code>: Non-SRP authentication flow; you can pass in the USERNAME and PASSWORD directly if the flow is enabled for calling the app client.</p> </li> <li> <p> <code>USER<em>PASSWORD</em>AUTH</code>: Non-SRP authentication flow; USERNAME and PASSWORD are passed directly. If a user migration Lambda trigger is set, this flow will invoke the user migration Lambda if the USERNAME is not found in the user pool. </p> </li> <li> <p> <code>ADMIN<em>USER</em>PASSWORD<em>AUTH</code>: Admin-based user password authentication. This replaces the <code>ADMIN</em>NO<em>SRP</em>AUTH</code> authentication flow. In this flow, Cognito receives the password in the request instead of using the SRP process to verify passwords.</p> </li> </ul></p>
     #[serde(rename = "AuthFlow")]
-    pub auth_flow: String,
+    pub auth_flow: AuthFlowType,
     /// <p><p>The authentication parameters. These are inputs corresponding to the <code>AuthFlow</code> that you are invoking. The required values depend on the value of <code>AuthFlow</code>:</p> <ul> <li> <p>For <code>USER<em>SRP</em>AUTH</code>: <code>USERNAME</code> (required), <code>SRP<em>A</code> (required), <code>SECRET</em>HASH</code> (required if the app client is configured with a client secret), <code>DEVICE<em>KEY</code> </p> </li> <li> <p>For <code>REFRESH</em>TOKEN<em>AUTH/REFRESH</em>TOKEN</code>: <code>REFRESH<em>TOKEN</code> (required), <code>SECRET</em>HASH</code> (required if the app client is configured with a client secret), <code>DEVICE<em>KEY</code> </p> </li> <li> <p>For <code>ADMIN</em>NO<em>SRP</em>AUTH</code>: <code>USERNAME</code> (required), <code>SECRET<em>HASH</code> (if app client is configured with client secret), <code>PASSWORD</code> (required), <code>DEVICE</em>KEY</code> </p> </li> <li> <p>For <code>CUSTOM<em>AUTH</code>: <code>USERNAME</code> (required), <code>SECRET</em>HASH</code> (if app client is configured with client secret), <code>DEVICE_KEY</code> </p> </li> </ul></p>
     #[serde(rename = "AuthParameters")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -407,7 +520,7 @@ pub struct AdminInitiateAuthResponse {
     /// <p><p>The name of the challenge which you are responding to with this call. This is returned to you in the <code>AdminInitiateAuth</code> response if you need to pass another challenge.</p> <ul> <li> <p> <code>MFA<em>SETUP</code>: If MFA is required, users who do not have at least one of the MFA methods set up are presented with an <code>MFA</em>SETUP</code> challenge. The user must set up at least one MFA type to continue to authenticate.</p> </li> <li> <p> <code>SELECT<em>MFA</em>TYPE</code>: Selects the MFA type. Valid MFA options are <code>SMS<em>MFA</code> for text SMS MFA, and <code>SOFTWARE</em>TOKEN<em>MFA</code> for TOTP software token MFA.</p> </li> <li> <p> <code>SMS</em>MFA</code>: Next challenge is to supply an <code>SMS<em>MFA</em>CODE</code>, delivered via SMS.</p> </li> <li> <p> <code>PASSWORD<em>VERIFIER</code>: Next challenge is to supply <code>PASSWORD</em>CLAIM<em>SIGNATURE</code>, <code>PASSWORD</em>CLAIM<em>SECRET</em>BLOCK</code>, and <code>TIMESTAMP</code> after the client-side SRP calculations.</p> </li> <li> <p> <code>CUSTOM<em>CHALLENGE</code>: This is returned if your custom authentication flow determines that the user should pass another challenge before tokens are issued.</p> </li> <li> <p> <code>DEVICE</em>SRP<em>AUTH</code>: If device tracking was enabled on your user pool and the previous challenges were passed, this challenge is returned so that Amazon Cognito can start tracking this device.</p> </li> <li> <p> <code>DEVICE</em>PASSWORD<em>VERIFIER</code>: Similar to <code>PASSWORD</em>VERIFIER</code>, but for devices only.</p> </li> <li> <p> <code>ADMIN<em>NO</em>SRP<em>AUTH</code>: This is returned if you need to authenticate with <code>USERNAME</code> and <code>PASSWORD</code> directly. An app client must be enabled to use this flow.</p> </li> <li> <p> <code>NEW</em>PASSWORD<em>REQUIRED</code>: For users which are required to change their passwords after successful first login. This challenge should be passed with <code>NEW</em>PASSWORD</code> and any other required attributes.</p> </li> </ul></p>
     #[serde(rename = "ChallengeName")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub challenge_name: Option<String>,
+    pub challenge_name: Option<ChallengeNameType>,
     /// <p>The challenge parameters. These are returned to you in the <code>AdminInitiateAuth</code> response if you need to pass another challenge. The responses in this parameter should be used to compute inputs to the next call (<code>AdminRespondToAuthChallenge</code>).</p> <p>All challenges require <code>USERNAME</code> and <code>SECRET_HASH</code> (if applicable).</p> <p>The value of the <code>USER_ID_FOR_SRP</code> attribute will be the user's actual username, not an alias (such as email address or phone number), even if you specified an alias in your call to <code>AdminInitiateAuth</code>. This is because, in the <code>AdminRespondToAuthChallenge</code> API <code>ChallengeResponses</code>, the <code>USERNAME</code> attribute cannot be an alias.</p>
     #[serde(rename = "ChallengeParameters")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -579,7 +692,7 @@ pub struct AdminRespondToAuthChallengeRequest {
     pub analytics_metadata: Option<AnalyticsMetadataType>,
     /// <p>The challenge name. For more information, see .</p>
     #[serde(rename = "ChallengeName")]
-    pub challenge_name: String,
+    pub challenge_name: ChallengeNameType,
     /// <p>The challenge responses. These are inputs corresponding to the value of <code>ChallengeName</code>, for example:</p> <ul> <li> <p> <code>SMS_MFA</code>: <code>SMS_MFA_CODE</code>, <code>USERNAME</code>, <code>SECRET_HASH</code> (if app client is configured with client secret).</p> </li> <li> <p> <code>PASSWORD_VERIFIER</code>: <code>PASSWORD_CLAIM_SIGNATURE</code>, <code>PASSWORD_CLAIM_SECRET_BLOCK</code>, <code>TIMESTAMP</code>, <code>USERNAME</code>, <code>SECRET_HASH</code> (if app client is configured with client secret).</p> </li> <li> <p> <code>ADMIN_NO_SRP_AUTH</code>: <code>PASSWORD</code>, <code>USERNAME</code>, <code>SECRET_HASH</code> (if app client is configured with client secret). </p> </li> <li> <p> <code>NEW_PASSWORD_REQUIRED</code>: <code>NEW_PASSWORD</code>, any other required attributes, <code>USERNAME</code>, <code>SECRET_HASH</code> (if app client is configured with client secret). </p> </li> </ul> <p>The value of the <code>USERNAME</code> attribute must be the user's actual username, not an alias (such as email address or phone number). To make this easier, the <code>AdminInitiateAuth</code> response includes the actual username value in the <code>USERNAMEUSER_ID_FOR_SRP</code> attribute, even if you specified an alias in your call to <code>AdminInitiateAuth</code>.</p>
     #[serde(rename = "ChallengeResponses")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -615,7 +728,7 @@ pub struct AdminRespondToAuthChallengeResponse {
     /// <p>The name of the challenge. For more information, see .</p>
     #[serde(rename = "ChallengeName")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub challenge_name: Option<String>,
+    pub challenge_name: Option<ChallengeNameType>,
     /// <p>The challenge parameters. For more information, see .</p>
     #[serde(rename = "ChallengeParameters")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -699,7 +812,7 @@ pub struct AdminUpdateAuthEventFeedbackRequest {
     pub event_id: String,
     /// <p>The authentication event feedback value.</p>
     #[serde(rename = "FeedbackValue")]
-    pub feedback_value: String,
+    pub feedback_value: FeedbackValueType,
     /// <p>The user pool ID.</p>
     #[serde(rename = "UserPoolId")]
     pub user_pool_id: String,
@@ -722,7 +835,7 @@ pub struct AdminUpdateDeviceStatusRequest {
     /// <p>The status indicating whether a device has been remembered or not.</p>
     #[serde(rename = "DeviceRememberedStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub device_remembered_status: Option<String>,
+    pub device_remembered_status: Option<DeviceRememberedStatusType>,
     /// <p>The user pool ID.</p>
     #[serde(rename = "UserPoolId")]
     pub user_pool_id: String,
@@ -777,6 +890,202 @@ pub struct AdminUserGlobalSignOutRequest {
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AdminUserGlobalSignOutResponse {}
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AdvancedSecurityModeType {
+    /// `OFF`
+    Off,
+    /// `AUDIT`
+    Audit,
+    /// `ENFORCED`
+    Enforced,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AdvancedSecurityModeType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AdvancedSecurityModeType::Off => "OFF",
+            AdvancedSecurityModeType::Audit => "AUDIT",
+            AdvancedSecurityModeType::Enforced => "ENFORCED",
+            AdvancedSecurityModeType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AdvancedSecurityModeType {
+    fn default() -> AdvancedSecurityModeType {
+        AdvancedSecurityModeType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AdvancedSecurityModeType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AdvancedSecurityModeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AdvancedSecurityModeType {
+    fn from(value: &'a str) -> AdvancedSecurityModeType {
+        match value {
+            "OFF" => AdvancedSecurityModeType::Off,
+            "AUDIT" => AdvancedSecurityModeType::Audit,
+            "ENFORCED" => AdvancedSecurityModeType::Enforced,
+            _ => AdvancedSecurityModeType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AdvancedSecurityModeType {
+    fn from(value: String) -> AdvancedSecurityModeType {
+        match value.as_str() {
+            "OFF" => AdvancedSecurityModeType::Off,
+            "AUDIT" => AdvancedSecurityModeType::Audit,
+            "ENFORCED" => AdvancedSecurityModeType::Enforced,
+            _ => AdvancedSecurityModeType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AdvancedSecurityModeType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AdvancedSecurityModeType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AdvancedSecurityModeType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AdvancedSecurityModeType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AdvancedSecurityModeType {
+    fn deserialize<D>(deserializer: D) -> Result<AdvancedSecurityModeType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AliasAttributeType {
+    /// `phone_number`
+    PhoneNumber,
+    /// `email`
+    Email,
+    /// `preferred_username`
+    PreferredUsername,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AliasAttributeType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AliasAttributeType::PhoneNumber => "phone_number",
+            AliasAttributeType::Email => "email",
+            AliasAttributeType::PreferredUsername => "preferred_username",
+            AliasAttributeType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AliasAttributeType {
+    fn default() -> AliasAttributeType {
+        AliasAttributeType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AliasAttributeType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AliasAttributeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AliasAttributeType {
+    fn from(value: &'a str) -> AliasAttributeType {
+        match value {
+            "phone_number" => AliasAttributeType::PhoneNumber,
+            "email" => AliasAttributeType::Email,
+            "preferred_username" => AliasAttributeType::PreferredUsername,
+            _ => AliasAttributeType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AliasAttributeType {
+    fn from(value: String) -> AliasAttributeType {
+        match value.as_str() {
+            "phone_number" => AliasAttributeType::PhoneNumber,
+            "email" => AliasAttributeType::Email,
+            "preferred_username" => AliasAttributeType::PreferredUsername,
+            _ => AliasAttributeType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AliasAttributeType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AliasAttributeType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AliasAttributeType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AliasAttributeType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AliasAttributeType {
+    fn deserialize<D>(deserializer: D) -> Result<AliasAttributeType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The Amazon Pinpoint analytics configuration for collecting metrics for a user pool.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AnalyticsConfigurationType {
@@ -831,6 +1140,109 @@ pub struct AssociateSoftwareTokenResponse {
     pub session: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AttributeDataType {
+    /// `String`
+    String,
+    /// `Number`
+    Number,
+    /// `DateTime`
+    DateTime,
+    /// `Boolean`
+    Boolean,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AttributeDataType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AttributeDataType::String => "String",
+            AttributeDataType::Number => "Number",
+            AttributeDataType::DateTime => "DateTime",
+            AttributeDataType::Boolean => "Boolean",
+            AttributeDataType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AttributeDataType {
+    fn default() -> AttributeDataType {
+        AttributeDataType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AttributeDataType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AttributeDataType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AttributeDataType {
+    fn from(value: &'a str) -> AttributeDataType {
+        match value {
+            "String" => AttributeDataType::String,
+            "Number" => AttributeDataType::Number,
+            "DateTime" => AttributeDataType::DateTime,
+            "Boolean" => AttributeDataType::Boolean,
+            _ => AttributeDataType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AttributeDataType {
+    fn from(value: String) -> AttributeDataType {
+        match value.as_str() {
+            "String" => AttributeDataType::String,
+            "Number" => AttributeDataType::Number,
+            "DateTime" => AttributeDataType::DateTime,
+            "Boolean" => AttributeDataType::Boolean,
+            _ => AttributeDataType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AttributeDataType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AttributeDataType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AttributeDataType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AttributeDataType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AttributeDataType {
+    fn deserialize<D>(deserializer: D) -> Result<AttributeDataType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Specifies whether the attribute is standard or custom.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AttributeType {
@@ -853,8 +1265,13 @@ pub struct AuthEventType {
     pub challenge_responses: Option<Vec<ChallengeResponseType>>,
     /// <p>The creation date</p>
     #[serde(rename = "CreationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The user context data captured at the time of an event request. It provides additional information about the client from which event the request is received.</p>
     #[serde(rename = "EventContextData")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -870,7 +1287,7 @@ pub struct AuthEventType {
     /// <p>The event response.</p>
     #[serde(rename = "EventResponse")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub event_response: Option<String>,
+    pub event_response: Option<EventResponseType>,
     /// <p>The event risk.</p>
     #[serde(rename = "EventRisk")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -878,9 +1295,127 @@ pub struct AuthEventType {
     /// <p>The event type.</p>
     #[serde(rename = "EventType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub event_type: Option<String>,
+    pub event_type: Option<EventType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AuthFlowType {
+    /// `USER_SRP_AUTH`
+    UserSrpAuth,
+    /// `REFRESH_TOKEN_AUTH`
+    RefreshTokenAuth,
+    /// `REFRESH_TOKEN`
+    RefreshToken,
+    /// `CUSTOM_AUTH`
+    CustomAuth,
+    /// `ADMIN_NO_SRP_AUTH`
+    AdminNoSrpAuth,
+    /// `USER_PASSWORD_AUTH`
+    UserPasswordAuth,
+    /// `ADMIN_USER_PASSWORD_AUTH`
+    AdminUserPasswordAuth,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AuthFlowType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AuthFlowType::UserSrpAuth => "USER_SRP_AUTH",
+            AuthFlowType::RefreshTokenAuth => "REFRESH_TOKEN_AUTH",
+            AuthFlowType::RefreshToken => "REFRESH_TOKEN",
+            AuthFlowType::CustomAuth => "CUSTOM_AUTH",
+            AuthFlowType::AdminNoSrpAuth => "ADMIN_NO_SRP_AUTH",
+            AuthFlowType::UserPasswordAuth => "USER_PASSWORD_AUTH",
+            AuthFlowType::AdminUserPasswordAuth => "ADMIN_USER_PASSWORD_AUTH",
+            AuthFlowType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AuthFlowType {
+    fn default() -> AuthFlowType {
+        AuthFlowType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AuthFlowType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AuthFlowType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AuthFlowType {
+    fn from(value: &'a str) -> AuthFlowType {
+        match value {
+            "USER_SRP_AUTH" => AuthFlowType::UserSrpAuth,
+            "REFRESH_TOKEN_AUTH" => AuthFlowType::RefreshTokenAuth,
+            "REFRESH_TOKEN" => AuthFlowType::RefreshToken,
+            "CUSTOM_AUTH" => AuthFlowType::CustomAuth,
+            "ADMIN_NO_SRP_AUTH" => AuthFlowType::AdminNoSrpAuth,
+            "USER_PASSWORD_AUTH" => AuthFlowType::UserPasswordAuth,
+            "ADMIN_USER_PASSWORD_AUTH" => AuthFlowType::AdminUserPasswordAuth,
+            _ => AuthFlowType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AuthFlowType {
+    fn from(value: String) -> AuthFlowType {
+        match value.as_str() {
+            "USER_SRP_AUTH" => AuthFlowType::UserSrpAuth,
+            "REFRESH_TOKEN_AUTH" => AuthFlowType::RefreshTokenAuth,
+            "REFRESH_TOKEN" => AuthFlowType::RefreshToken,
+            "CUSTOM_AUTH" => AuthFlowType::CustomAuth,
+            "ADMIN_NO_SRP_AUTH" => AuthFlowType::AdminNoSrpAuth,
+            "USER_PASSWORD_AUTH" => AuthFlowType::UserPasswordAuth,
+            "ADMIN_USER_PASSWORD_AUTH" => AuthFlowType::AdminUserPasswordAuth,
+            _ => AuthFlowType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AuthFlowType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AuthFlowType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AuthFlowType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AuthFlowType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for AuthFlowType {
+    fn deserialize<D>(deserializer: D) -> Result<AuthFlowType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The authentication result.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -911,67 +1446,479 @@ pub struct AuthenticationResultType {
     pub token_type: Option<String>,
 }
 
-/// <p>The challenge response type.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct ChallengeResponseType {
-    /// <p>The challenge name</p>
-    #[serde(rename = "ChallengeName")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub challenge_name: Option<String>,
-    /// <p>The challenge response.</p>
-    #[serde(rename = "ChallengeResponse")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub challenge_response: Option<String>,
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ChallengeName {
+    /// `Password`
+    Password,
+    /// `Mfa`
+    Mfa,
+    /// A value not known to this version of the crate
+    Unknown(String),
 }
 
-/// <p>Represents the request to change a user password.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct ChangePasswordRequest {
-    /// <p>The access token.</p>
-    #[serde(rename = "AccessToken")]
-    pub access_token: String,
-    /// <p>The old password.</p>
-    #[serde(rename = "PreviousPassword")]
-    pub previous_password: String,
-    /// <p>The new password.</p>
-    #[serde(rename = "ProposedPassword")]
-    pub proposed_password: String,
+impl ChallengeName {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ChallengeName::Password => "Password",
+            ChallengeName::Mfa => "Mfa",
+            ChallengeName::Unknown(ref value) => value,
+        }
+    }
 }
 
-/// <p>The response from the server to the change password request.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct ChangePasswordResponse {}
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ChallengeName {
+    fn default() -> ChallengeName {
+        ChallengeName::Unknown(String::new())
+    }
+}
 
-/// <p>The code delivery details being returned from the server.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct CodeDeliveryDetailsType {
-    /// <p>The attribute name.</p>
-    #[serde(rename = "AttributeName")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub attribute_name: Option<String>,
-    /// <p>The delivery medium (email message or phone number).</p>
-    #[serde(rename = "DeliveryMedium")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub delivery_medium: Option<String>,
-    /// <p>The destination for the code delivery details.</p>
-    #[serde(rename = "Destination")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub destination: Option<String>,
+impl AsRef<str> for ChallengeName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
 }
 
-/// <p>The compromised credentials actions type</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct CompromisedCredentialsActionsType {
-    /// <p>The event action.</p>
-    #[serde(rename = "EventAction")]
-    pub event_action: String,
+impl fmt::Display for ChallengeName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
-/// <p>The compromised credentials risk configuration type.</p>
+impl<'a> From<&'a str> for ChallengeName {
+    fn from(value: &'a str) -> ChallengeName {
+        match value {
+            "Password" => ChallengeName::Password,
+            "Mfa" => ChallengeName::Mfa,
+            _ => ChallengeName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ChallengeName {
+    fn from(value: String) -> ChallengeName {
+        match value.as_str() {
+            "Password" => ChallengeName::Password,
+            "Mfa" => ChallengeName::Mfa,
+            _ => ChallengeName::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ChallengeName {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ChallengeName, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ChallengeName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ChallengeName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ChallengeName {
+    fn deserialize<D>(deserializer: D) -> Result<ChallengeName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ChallengeNameType {
+    /// `SMS_MFA`
+    SmsMfa,
+    /// `SOFTWARE_TOKEN_MFA`
+    SoftwareTokenMfa,
+    /// `SELECT_MFA_TYPE`
+    SelectMfaType,
+    /// `MFA_SETUP`
+    MfaSetup,
+    /// `PASSWORD_VERIFIER`
+    PasswordVerifier,
+    /// `CUSTOM_CHALLENGE`
+    CustomChallenge,
+    /// `DEVICE_SRP_AUTH`
+    DeviceSrpAuth,
+    /// `DEVICE_PASSWORD_VERIFIER`
+    DevicePasswordVerifier,
+    /// `ADMIN_NO_SRP_AUTH`
+    AdminNoSrpAuth,
+    /// `NEW_PASSWORD_REQUIRED`
+    NewPasswordRequired,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ChallengeNameType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ChallengeNameType::SmsMfa => "SMS_MFA",
+            ChallengeNameType::SoftwareTokenMfa => "SOFTWARE_TOKEN_MFA",
+            ChallengeNameType::SelectMfaType => "SELECT_MFA_TYPE",
+            ChallengeNameType::MfaSetup => "MFA_SETUP",
+            ChallengeNameType::PasswordVerifier => "PASSWORD_VERIFIER",
+            ChallengeNameType::CustomChallenge => "CUSTOM_CHALLENGE",
+            ChallengeNameType::DeviceSrpAuth => "DEVICE_SRP_AUTH",
+            ChallengeNameType::DevicePasswordVerifier => "DEVICE_PASSWORD_VERIFIER",
+            ChallengeNameType::AdminNoSrpAuth => "ADMIN_NO_SRP_AUTH",
+            ChallengeNameType::NewPasswordRequired => "NEW_PASSWORD_REQUIRED",
+            ChallengeNameType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ChallengeNameType {
+    fn default() -> ChallengeNameType {
+        ChallengeNameType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ChallengeNameType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ChallengeNameType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ChallengeNameType {
+    fn from(value: &'a str) -> ChallengeNameType {
+        match value {
+            "SMS_MFA" => ChallengeNameType::SmsMfa,
+            "SOFTWARE_TOKEN_MFA" => ChallengeNameType::SoftwareTokenMfa,
+            "SELECT_MFA_TYPE" => ChallengeNameType::SelectMfaType,
+            "MFA_SETUP" => ChallengeNameType::MfaSetup,
+            "PASSWORD_VERIFIER" => ChallengeNameType::PasswordVerifier,
+            "CUSTOM_CHALLENGE" => ChallengeNameType::CustomChallenge,
+            "DEVICE_SRP_AUTH" => ChallengeNameType::DeviceSrpAuth,
+            "DEVICE_PASSWORD_VERIFIER" => ChallengeNameType::DevicePasswordVerifier,
+            "ADMIN_NO_SRP_AUTH" => ChallengeNameType::AdminNoSrpAuth,
+            "NEW_PASSWORD_REQUIRED" => ChallengeNameType::NewPasswordRequired,
+            _ => ChallengeNameType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ChallengeNameType {
+    fn from(value: String) -> ChallengeNameType {
+        match value.as_str() {
+            "SMS_MFA" => ChallengeNameType::SmsMfa,
+            "SOFTWARE_TOKEN_MFA" => ChallengeNameType::SoftwareTokenMfa,
+            "SELECT_MFA_TYPE" => ChallengeNameType::SelectMfaType,
+            "MFA_SETUP" => ChallengeNameType::MfaSetup,
+            "PASSWORD_VERIFIER" => ChallengeNameType::PasswordVerifier,
+            "CUSTOM_CHALLENGE" => ChallengeNameType::CustomChallenge,
+            "DEVICE_SRP_AUTH" => ChallengeNameType::DeviceSrpAuth,
+            "DEVICE_PASSWORD_VERIFIER" => ChallengeNameType::DevicePasswordVerifier,
+            "ADMIN_NO_SRP_AUTH" => ChallengeNameType::AdminNoSrpAuth,
+            "NEW_PASSWORD_REQUIRED" => ChallengeNameType::NewPasswordRequired,
+            _ => ChallengeNameType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ChallengeNameType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ChallengeNameType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ChallengeNameType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ChallengeNameType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ChallengeNameType {
+    fn deserialize<D>(deserializer: D) -> Result<ChallengeNameType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ChallengeResponse {
+    /// `Success`
+    Success,
+    /// `Failure`
+    Failure,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ChallengeResponse {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ChallengeResponse::Success => "Success",
+            ChallengeResponse::Failure => "Failure",
+            ChallengeResponse::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ChallengeResponse {
+    fn default() -> ChallengeResponse {
+        ChallengeResponse::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ChallengeResponse {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ChallengeResponse {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ChallengeResponse {
+    fn from(value: &'a str) -> ChallengeResponse {
+        match value {
+            "Success" => ChallengeResponse::Success,
+            "Failure" => ChallengeResponse::Failure,
+            _ => ChallengeResponse::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ChallengeResponse {
+    fn from(value: String) -> ChallengeResponse {
+        match value.as_str() {
+            "Success" => ChallengeResponse::Success,
+            "Failure" => ChallengeResponse::Failure,
+            _ => ChallengeResponse::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ChallengeResponse {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ChallengeResponse, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ChallengeResponse {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ChallengeResponse {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ChallengeResponse {
+    fn deserialize<D>(deserializer: D) -> Result<ChallengeResponse, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>The challenge response type.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct ChallengeResponseType {
+    /// <p>The challenge name</p>
+    #[serde(rename = "ChallengeName")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub challenge_name: Option<ChallengeName>,
+    /// <p>The challenge response.</p>
+    #[serde(rename = "ChallengeResponse")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub challenge_response: Option<ChallengeResponse>,
+}
+
+/// <p>Represents the request to change a user password.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct ChangePasswordRequest {
+    /// <p>The access token.</p>
+    #[serde(rename = "AccessToken")]
+    pub access_token: String,
+    /// <p>The old password.</p>
+    #[serde(rename = "PreviousPassword")]
+    pub previous_password: String,
+    /// <p>The new password.</p>
+    #[serde(rename = "ProposedPassword")]
+    pub proposed_password: String,
+}
+
+/// <p>The response from the server to the change password request.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct ChangePasswordResponse {}
+
+/// <p>The code delivery details being returned from the server.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct CodeDeliveryDetailsType {
+    /// <p>The attribute name.</p>
+    #[serde(rename = "AttributeName")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attribute_name: Option<String>,
+    /// <p>The delivery medium (email message or phone number).</p>
+    #[serde(rename = "DeliveryMedium")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delivery_medium: Option<DeliveryMediumType>,
+    /// <p>The destination for the code delivery details.</p>
+    #[serde(rename = "Destination")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination: Option<String>,
+}
+
+/// <p>The compromised credentials actions type</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CompromisedCredentialsActionsType {
+    /// <p>The event action.</p>
+    #[serde(rename = "EventAction")]
+    pub event_action: CompromisedCredentialsEventActionType,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CompromisedCredentialsEventActionType {
+    /// `BLOCK`
+    Block,
+    /// `NO_ACTION`
+    NoAction,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CompromisedCredentialsEventActionType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CompromisedCredentialsEventActionType::Block => "BLOCK",
+            CompromisedCredentialsEventActionType::NoAction => "NO_ACTION",
+            CompromisedCredentialsEventActionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CompromisedCredentialsEventActionType {
+    fn default() -> CompromisedCredentialsEventActionType {
+        CompromisedCredentialsEventActionType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CompromisedCredentialsEventActionType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CompromisedCredentialsEventActionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CompromisedCredentialsEventActionType {
+    fn from(value: &'a str) -> CompromisedCredentialsEventActionType {
+        match value {
+            "BLOCK" => CompromisedCredentialsEventActionType::Block,
+            "NO_ACTION" => CompromisedCredentialsEventActionType::NoAction,
+            _ => CompromisedCredentialsEventActionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CompromisedCredentialsEventActionType {
+    fn from(value: String) -> CompromisedCredentialsEventActionType {
+        match value.as_str() {
+            "BLOCK" => CompromisedCredentialsEventActionType::Block,
+            "NO_ACTION" => CompromisedCredentialsEventActionType::NoAction,
+            _ => CompromisedCredentialsEventActionType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CompromisedCredentialsEventActionType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CompromisedCredentialsEventActionType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CompromisedCredentialsEventActionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CompromisedCredentialsEventActionType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CompromisedCredentialsEventActionType {
+    fn deserialize<D>(deserializer: D) -> Result<CompromisedCredentialsEventActionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>The compromised credentials risk configuration type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CompromisedCredentialsRiskConfigurationType {
     /// <p>The compromised credentials risk configuration actions.</p>
@@ -980,7 +1927,7 @@ pub struct CompromisedCredentialsRiskConfigurationType {
     /// <p>Perform the action for these events. The default is to perform all events if no event filter is specified.</p>
     #[serde(rename = "EventFilter")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub event_filter: Option<Vec<String>>,
+    pub event_filter: Option<Vec<EventFilterType>>,
 }
 
 /// <p>Confirms the device request.</p>
@@ -1165,7 +2112,7 @@ pub struct CreateIdentityProviderRequest {
     pub provider_name: String,
     /// <p>The identity provider type.</p>
     #[serde(rename = "ProviderType")]
-    pub provider_type: String,
+    pub provider_type: IdentityProviderTypeType,
     /// <p>The user pool ID.</p>
     #[serde(rename = "UserPoolId")]
     pub user_pool_id: String,
@@ -1237,7 +2184,7 @@ pub struct CreateUserPoolClientRequest {
     /// <p>Set to <code>code</code> to initiate a code grant flow, which provides an authorization code as the response. This code can be exchanged for access tokens with the token endpoint.</p> <p>Set to <code>token</code> to specify that the client should get the access token (and, optionally, ID token, based on scopes) directly.</p>
     #[serde(rename = "AllowedOAuthFlows")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub allowed_o_auth_flows: Option<Vec<String>>,
+    pub allowed_o_auth_flows: Option<Vec<OAuthFlowType>>,
     /// <p>Set to <code>True</code> if the client is allowed to follow the OAuth protocol when interacting with Cognito user pools.</p>
     #[serde(rename = "AllowedOAuthFlowsUserPoolClient")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1264,7 +2211,7 @@ pub struct CreateUserPoolClientRequest {
     /// <p><p>The authentication flows that are supported by the user pool clients. Flow names without the <code>ALLOW<em></code> prefix are deprecated in favor of new names with the <code>ALLOW</em></code> prefix. Note that values with <code>ALLOW<em></code> prefix cannot be used along with values without <code>ALLOW</em></code> prefix.</p> <p>Valid values include:</p> <ul> <li> <p> <code>ALLOW<em>ADMIN</em>USER<em>PASSWORD</em>AUTH</code>: Enable admin based user password authentication flow <code>ADMIN<em>USER</em>PASSWORD<em>AUTH</code>. This setting replaces the <code>ADMIN</em>NO<em>SRP</em>AUTH</code> setting. With this authentication flow, Cognito receives the password in the request instead of using the SRP (Secure Remote Password protocol) protocol to verify passwords.</p> </li> <li> <p> <code>ALLOW<em>CUSTOM</em>AUTH</code>: Enable Lambda trigger based authentication.</p> </li> <li> <p> <code>ALLOW<em>USER</em>PASSWORD<em>AUTH</code>: Enable user password-based authentication. In this flow, Cognito receives the password in the request instead of using the SRP protocol to verify passwords.</p> </li> <li> <p> <code>ALLOW</em>USER<em>SRP</em>AUTH</code>: Enable SRP based authentication.</p> </li> <li> <p> <code>ALLOW<em>REFRESH</em>TOKEN_AUTH</code>: Enable authflow to refresh tokens.</p> </li> </ul></p>
     #[serde(rename = "ExplicitAuthFlows")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub explicit_auth_flows: Option<Vec<String>>,
+    pub explicit_auth_flows: Option<Vec<ExplicitAuthFlowsType>>,
     /// <p>Boolean to specify whether you want to generate a secret for the user pool client being created.</p>
     #[serde(rename = "GenerateSecret")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1276,7 +2223,7 @@ pub struct CreateUserPoolClientRequest {
     /// <p><p>Use this setting to choose which errors and responses are returned by Cognito APIs during authentication, account confirmation, and password recovery when the user does not exist in the user pool. When set to <code>ENABLED</code> and the user does not exist, authentication returns an error indicating either the username or password was incorrect, and account confirmation and password recovery return a response indicating a code was sent to a simulated destination. When set to <code>LEGACY</code>, those APIs will return a <code>UserNotFoundException</code> exception if the user does not exist in the user pool.</p> <p>Valid values include:</p> <ul> <li> <p> <code>ENABLED</code> - This prevents user existence-related errors.</p> </li> <li> <p> <code>LEGACY</code> - This represents the old behavior of Cognito where user existence related errors are not prevented.</p> </li> </ul> <p>This setting affects the behavior of following APIs:</p> <ul> <li> <p> <a>AdminInitiateAuth</a> </p> </li> <li> <p> <a>AdminRespondToAuthChallenge</a> </p> </li> <li> <p> <a>InitiateAuth</a> </p> </li> <li> <p> <a>RespondToAuthChallenge</a> </p> </li> <li> <p> <a>ForgotPassword</a> </p> </li> <li> <p> <a>ConfirmForgotPassword</a> </p> </li> <li> <p> <a>ConfirmSignUp</a> </p> </li> <li> <p> <a>ResendConfirmationCode</a> </p> </li> </ul> <note> <p>After January 1st 2020, the value of <code>PreventUserExistenceErrors</code> will default to <code>ENABLED</code> for newly created user pool clients if no value is provided.</p> </note></p>
     #[serde(rename = "PreventUserExistenceErrors")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub prevent_user_existence_errors: Option<String>,
+    pub prevent_user_existence_errors: Option<PreventUserExistenceErrorTypes>,
     /// <p>The read attributes.</p>
     #[serde(rename = "ReadAttributes")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1347,11 +2294,11 @@ pub struct CreateUserPoolRequest {
     /// <p>Attributes supported as an alias for this user pool. Possible values: <b>phone_number</b>, <b>email</b>, or <b>preferred_username</b>.</p>
     #[serde(rename = "AliasAttributes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub alias_attributes: Option<Vec<String>>,
+    pub alias_attributes: Option<Vec<AliasAttributeType>>,
     /// <p>The attributes to be auto-verified. Possible values: <b>email</b>, <b>phone_number</b>.</p>
     #[serde(rename = "AutoVerifiedAttributes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub auto_verified_attributes: Option<Vec<String>>,
+    pub auto_verified_attributes: Option<Vec<VerifiedAttributeType>>,
     /// <p>The device configuration.</p>
     #[serde(rename = "DeviceConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1375,7 +2322,7 @@ pub struct CreateUserPoolRequest {
     /// <p>Specifies MFA configuration details.</p>
     #[serde(rename = "MfaConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub mfa_configuration: Option<String>,
+    pub mfa_configuration: Option<UserPoolMfaType>,
     /// <p>The policies associated with the new user pool.</p>
     #[serde(rename = "Policies")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1410,7 +2357,7 @@ pub struct CreateUserPoolRequest {
     /// <p>Specifies whether email addresses or phone numbers can be specified as usernames when a user signs up.</p>
     #[serde(rename = "UsernameAttributes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub username_attributes: Option<Vec<String>>,
+    pub username_attributes: Option<Vec<UsernameAttributeType>>,
     /// <p>The template for the verification message that the user sees when the app requests permission to access the user's information.</p>
     #[serde(rename = "VerificationMessageTemplate")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1435,6 +2382,99 @@ pub struct CustomDomainConfigType {
     pub certificate_arn: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DefaultEmailOptionType {
+    /// `CONFIRM_WITH_LINK`
+    ConfirmWithLink,
+    /// `CONFIRM_WITH_CODE`
+    ConfirmWithCode,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DefaultEmailOptionType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DefaultEmailOptionType::ConfirmWithLink => "CONFIRM_WITH_LINK",
+            DefaultEmailOptionType::ConfirmWithCode => "CONFIRM_WITH_CODE",
+            DefaultEmailOptionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DefaultEmailOptionType {
+    fn default() -> DefaultEmailOptionType {
+        DefaultEmailOptionType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DefaultEmailOptionType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DefaultEmailOptionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DefaultEmailOptionType {
+    fn from(value: &'a str) -> DefaultEmailOptionType {
+        match value {
+            "CONFIRM_WITH_LINK" => DefaultEmailOptionType::ConfirmWithLink,
+            "CONFIRM_WITH_CODE" => DefaultEmailOptionType::ConfirmWithCode,
+            _ => DefaultEmailOptionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DefaultEmailOptionType {
+    fn from(value: String) -> DefaultEmailOptionType {
+        match value.as_str() {
+            "CONFIRM_WITH_LINK" => DefaultEmailOptionType::ConfirmWithLink,
+            "CONFIRM_WITH_CODE" => DefaultEmailOptionType::ConfirmWithCode,
+            _ => DefaultEmailOptionType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DefaultEmailOptionType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DefaultEmailOptionType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DefaultEmailOptionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DefaultEmailOptionType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DefaultEmailOptionType {
+    fn deserialize<D>(deserializer: D) -> Result<DefaultEmailOptionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct DeleteGroupRequest {
@@ -1530,23 +2570,116 @@ pub struct DeleteUserRequest {
     pub access_token: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct DescribeIdentityProviderRequest {
-    /// <p>The identity provider name.</p>
-    #[serde(rename = "ProviderName")]
-    pub provider_name: String,
-    /// <p>The user pool ID.</p>
-    #[serde(rename = "UserPoolId")]
-    pub user_pool_id: String,
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DeliveryMediumType {
+    /// `SMS`
+    Sms,
+    /// `EMAIL`
+    Email,
+    /// A value not known to this version of the crate
+    Unknown(String),
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct DescribeIdentityProviderResponse {
-    /// <p>The identity provider that was deleted.</p>
-    #[serde(rename = "IdentityProvider")]
-    pub identity_provider: IdentityProviderType,
+impl DeliveryMediumType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeliveryMediumType::Sms => "SMS",
+            DeliveryMediumType::Email => "EMAIL",
+            DeliveryMediumType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DeliveryMediumType {
+    fn default() -> DeliveryMediumType {
+        DeliveryMediumType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DeliveryMediumType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DeliveryMediumType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DeliveryMediumType {
+    fn from(value: &'a str) -> DeliveryMediumType {
+        match value {
+            "SMS" => DeliveryMediumType::Sms,
+            "EMAIL" => DeliveryMediumType::Email,
+            _ => DeliveryMediumType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeliveryMediumType {
+    fn from(value: String) -> DeliveryMediumType {
+        match value.as_str() {
+            "SMS" => DeliveryMediumType::Sms,
+            "EMAIL" => DeliveryMediumType::Email,
+            _ => DeliveryMediumType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DeliveryMediumType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DeliveryMediumType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DeliveryMediumType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DeliveryMediumType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeliveryMediumType {
+    fn deserialize<D>(deserializer: D) -> Result<DeliveryMediumType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct DescribeIdentityProviderRequest {
+    /// <p>The identity provider name.</p>
+    #[serde(rename = "ProviderName")]
+    pub provider_name: String,
+    /// <p>The user pool ID.</p>
+    #[serde(rename = "UserPoolId")]
+    pub user_pool_id: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct DescribeIdentityProviderResponse {
+    /// <p>The identity provider that was deleted.</p>
+    #[serde(rename = "IdentityProvider")]
+    pub identity_provider: IdentityProviderType,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -1681,6 +2814,99 @@ pub struct DeviceConfigurationType {
     pub device_only_remembered_on_user_prompt: Option<bool>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DeviceRememberedStatusType {
+    /// `remembered`
+    Remembered,
+    /// `not_remembered`
+    NotRemembered,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DeviceRememberedStatusType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeviceRememberedStatusType::Remembered => "remembered",
+            DeviceRememberedStatusType::NotRemembered => "not_remembered",
+            DeviceRememberedStatusType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DeviceRememberedStatusType {
+    fn default() -> DeviceRememberedStatusType {
+        DeviceRememberedStatusType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DeviceRememberedStatusType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DeviceRememberedStatusType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DeviceRememberedStatusType {
+    fn from(value: &'a str) -> DeviceRememberedStatusType {
+        match value {
+            "remembered" => DeviceRememberedStatusType::Remembered,
+            "not_remembered" => DeviceRememberedStatusType::NotRemembered,
+            _ => DeviceRememberedStatusType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeviceRememberedStatusType {
+    fn from(value: String) -> DeviceRememberedStatusType {
+        match value.as_str() {
+            "remembered" => DeviceRememberedStatusType::Remembered,
+            "not_remembered" => DeviceRememberedStatusType::NotRemembered,
+            _ => DeviceRememberedStatusType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DeviceRememberedStatusType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DeviceRememberedStatusType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DeviceRememberedStatusType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DeviceRememberedStatusType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeviceRememberedStatusType {
+    fn deserialize<D>(deserializer: D) -> Result<DeviceRememberedStatusType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The device verifier against which it will be authenticated.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -1705,20 +2931,35 @@ pub struct DeviceType {
     pub device_attributes: Option<Vec<AttributeType>>,
     /// <p>The creation date of the device.</p>
     #[serde(rename = "DeviceCreateDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub device_create_date: Option<f64>,
+    pub device_create_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The device key.</p>
     #[serde(rename = "DeviceKey")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub device_key: Option<String>,
     /// <p>The date in which the device was last authenticated.</p>
     #[serde(rename = "DeviceLastAuthenticatedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub device_last_authenticated_date: Option<f64>,
+    pub device_last_authenticated_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The last modified date of the device.</p>
     #[serde(rename = "DeviceLastModifiedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub device_last_modified_date: Option<f64>,
+    pub device_last_modified_date: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>A container for information about a domain.</p>
@@ -1748,7 +2989,7 @@ pub struct DomainDescriptionType {
     /// <p>The domain status.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<DomainStatusType>,
     /// <p>The user pool ID.</p>
     #[serde(rename = "UserPoolId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1759,6 +3000,114 @@ pub struct DomainDescriptionType {
     pub version: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DomainStatusType {
+    /// `CREATING`
+    Creating,
+    /// `DELETING`
+    Deleting,
+    /// `UPDATING`
+    Updating,
+    /// `ACTIVE`
+    Active,
+    /// `FAILED`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DomainStatusType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DomainStatusType::Creating => "CREATING",
+            DomainStatusType::Deleting => "DELETING",
+            DomainStatusType::Updating => "UPDATING",
+            DomainStatusType::Active => "ACTIVE",
+            DomainStatusType::Failed => "FAILED",
+            DomainStatusType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DomainStatusType {
+    fn default() -> DomainStatusType {
+        DomainStatusType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DomainStatusType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DomainStatusType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DomainStatusType {
+    fn from(value: &'a str) -> DomainStatusType {
+        match value {
+            "CREATING" => DomainStatusType::Creating,
+            "DELETING" => DomainStatusType::Deleting,
+            "UPDATING" => DomainStatusType::Updating,
+            "ACTIVE" => DomainStatusType::Active,
+            "FAILED" => DomainStatusType::Failed,
+            _ => DomainStatusType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DomainStatusType {
+    fn from(value: String) -> DomainStatusType {
+        match value.as_str() {
+            "CREATING" => DomainStatusType::Creating,
+            "DELETING" => DomainStatusType::Deleting,
+            "UPDATING" => DomainStatusType::Updating,
+            "ACTIVE" => DomainStatusType::Active,
+            "FAILED" => DomainStatusType::Failed,
+            _ => DomainStatusType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DomainStatusType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DomainStatusType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DomainStatusType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DomainStatusType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DomainStatusType {
+    fn deserialize<D>(deserializer: D) -> Result<DomainStatusType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The email configuration type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct EmailConfigurationType {
@@ -1769,7 +3118,7 @@ pub struct EmailConfigurationType {
     /// <p><p>Specifies whether Amazon Cognito emails your users by using its built-in email functionality or your Amazon SES email configuration. Specify one of the following values:</p> <dl> <dt>COGNITO_DEFAULT</dt> <dd> <p>When Amazon Cognito emails your users, it uses its built-in email functionality. When you use the default option, Amazon Cognito allows only a limited number of emails each day for your user pool. For typical production environments, the default email limit is below the required delivery volume. To achieve a higher delivery volume, specify DEVELOPER to use your Amazon SES email configuration.</p> <p>To look up the email delivery limit for the default option, see <a href="https://docs.aws.amazon.com/cognito/latest/developerguide/limits.html">Limits in Amazon Cognito</a> in the <i>Amazon Cognito Developer Guide</i>.</p> <p>The default FROM address is no-reply@verificationemail.com. To customize the FROM address, provide the ARN of an Amazon SES verified email address for the <code>SourceArn</code> parameter.</p> </dd> <dt>DEVELOPER</dt> <dd> <p>When Amazon Cognito emails your users, it uses your Amazon SES configuration. Amazon Cognito calls Amazon SES on your behalf to send email from your verified email address. When you use this option, the email delivery limits are the same limits that apply to your Amazon SES verified email address in your AWS account.</p> <p>If you use this option, you must provide the ARN of an Amazon SES verified email address for the <code>SourceArn</code> parameter.</p> <p>Before Amazon Cognito can email your users, it requires additional permissions to call Amazon SES on your behalf. When you update your user pool with this option, Amazon Cognito creates a <i>service-linked role</i>, which is a type of IAM role, in your AWS account. This role contains the permissions that allow Amazon Cognito to access Amazon SES and send email messages with your address. For more information about the service-linked role that Amazon Cognito creates, see <a href="https://docs.aws.amazon.com/cognito/latest/developerguide/using-service-linked-roles.html">Using Service-Linked Roles for Amazon Cognito</a> in the <i>Amazon Cognito Developer Guide</i>.</p> </dd> </dl></p>
     #[serde(rename = "EmailSendingAccount")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub email_sending_account: Option<String>,
+    pub email_sending_account: Option<EmailSendingAccountType>,
     /// <p>Identifies either the sender’s email address or the sender’s name with their email address. For example, <code>testuser@example.com</code> or <code>Test User &lt;testuser@example.com&gt;</code>. This address will appear before the body of the email.</p>
     #[serde(rename = "From")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1784,6 +3133,99 @@ pub struct EmailConfigurationType {
     pub source_arn: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EmailSendingAccountType {
+    /// `COGNITO_DEFAULT`
+    CognitoDefault,
+    /// `DEVELOPER`
+    Developer,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EmailSendingAccountType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EmailSendingAccountType::CognitoDefault => "COGNITO_DEFAULT",
+            EmailSendingAccountType::Developer => "DEVELOPER",
+            EmailSendingAccountType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EmailSendingAccountType {
+    fn default() -> EmailSendingAccountType {
+        EmailSendingAccountType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EmailSendingAccountType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EmailSendingAccountType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EmailSendingAccountType {
+    fn from(value: &'a str) -> EmailSendingAccountType {
+        match value {
+            "COGNITO_DEFAULT" => EmailSendingAccountType::CognitoDefault,
+            "DEVELOPER" => EmailSendingAccountType::Developer,
+            _ => EmailSendingAccountType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EmailSendingAccountType {
+    fn from(value: String) -> EmailSendingAccountType {
+        match value.as_str() {
+            "COGNITO_DEFAULT" => EmailSendingAccountType::CognitoDefault,
+            "DEVELOPER" => EmailSendingAccountType::Developer,
+            _ => EmailSendingAccountType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EmailSendingAccountType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EmailSendingAccountType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EmailSendingAccountType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EmailSendingAccountType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EmailSendingAccountType {
+    fn deserialize<D>(deserializer: D) -> Result<EmailSendingAccountType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Specifies the user context data captured at the time of an event request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1816,16 +3258,217 @@ pub struct EventContextDataType {
 pub struct EventFeedbackType {
     /// <p>The event feedback date.</p>
     #[serde(rename = "FeedbackDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub feedback_date: Option<f64>,
+    pub feedback_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The event feedback value.</p>
     #[serde(rename = "FeedbackValue")]
-    pub feedback_value: String,
+    pub feedback_value: FeedbackValueType,
     /// <p>The provider.</p>
     #[serde(rename = "Provider")]
     pub provider: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EventFilterType {
+    /// `SIGN_IN`
+    SignIn,
+    /// `PASSWORD_CHANGE`
+    PasswordChange,
+    /// `SIGN_UP`
+    SignUp,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EventFilterType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EventFilterType::SignIn => "SIGN_IN",
+            EventFilterType::PasswordChange => "PASSWORD_CHANGE",
+            EventFilterType::SignUp => "SIGN_UP",
+            EventFilterType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EventFilterType {
+    fn default() -> EventFilterType {
+        EventFilterType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EventFilterType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EventFilterType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EventFilterType {
+    fn from(value: &'a str) -> EventFilterType {
+        match value {
+            "SIGN_IN" => EventFilterType::SignIn,
+            "PASSWORD_CHANGE" => EventFilterType::PasswordChange,
+            "SIGN_UP" => EventFilterType::SignUp,
+            _ => EventFilterType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EventFilterType {
+    fn from(value: String) -> EventFilterType {
+        match value.as_str() {
+            "SIGN_IN" => EventFilterType::SignIn,
+            "PASSWORD_CHANGE" => EventFilterType::PasswordChange,
+            "SIGN_UP" => EventFilterType::SignUp,
+            _ => EventFilterType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EventFilterType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EventFilterType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EventFilterType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EventFilterType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EventFilterType {
+    fn deserialize<D>(deserializer: D) -> Result<EventFilterType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EventResponseType {
+    /// `Pass`
+    Pass,
+    /// `Fail`
+    Fail,
+    /// `InProgress`
+    InProgress,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EventResponseType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EventResponseType::Pass => "Pass",
+            EventResponseType::Fail => "Fail",
+            EventResponseType::InProgress => "InProgress",
+            EventResponseType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EventResponseType {
+    fn default() -> EventResponseType {
+        EventResponseType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EventResponseType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EventResponseType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EventResponseType {
+    fn from(value: &'a str) -> EventResponseType {
+        match value {
+            "Pass" => EventResponseType::Pass,
+            "Fail" => EventResponseType::Fail,
+            "InProgress" => EventResponseType::InProgress,
+            _ => EventResponseType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EventResponseType {
+    fn from(value: String) -> EventResponseType {
+        match value.as_str() {
+            "Pass" => EventResponseType::Pass,
+            "Fail" => EventResponseType::Fail,
+            "InProgress" => EventResponseType::InProgress,
+            _ => EventResponseType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EventResponseType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EventResponseType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EventResponseType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EventResponseType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EventResponseType {
+    fn deserialize<D>(deserializer: D) -> Result<EventResponseType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The event risk type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1833,13 +3476,337 @@ pub struct EventRiskType {
     /// <p>The risk decision.</p>
     #[serde(rename = "RiskDecision")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub risk_decision: Option<String>,
+    pub risk_decision: Option<RiskDecisionType>,
     /// <p>The risk level.</p>
     #[serde(rename = "RiskLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub risk_level: Option<String>,
+    pub risk_level: Option<RiskLevelType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EventType {
+    /// `SignIn`
+    SignIn,
+    /// `SignUp`
+    SignUp,
+    /// `ForgotPassword`
+    ForgotPassword,
+    /// `PasswordChange`
+    PasswordChange,
+    /// `ResendCode`
+    ResendCode,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EventType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EventType::SignIn => "SignIn",
+            EventType::SignUp => "SignUp",
+            EventType::ForgotPassword => "ForgotPassword",
+            EventType::PasswordChange => "PasswordChange",
+            EventType::ResendCode => "ResendCode",
+            EventType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EventType {
+    fn default() -> EventType {
+        EventType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EventType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EventType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EventType {
+    fn from(value: &'a str) -> EventType {
+        match value {
+            "SignIn" => EventType::SignIn,
+            "SignUp" => EventType::SignUp,
+            "ForgotPassword" => EventType::ForgotPassword,
+            "PasswordChange" => EventType::PasswordChange,
+            "ResendCode" => EventType::ResendCode,
+            _ => EventType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EventType {
+    fn from(value: String) -> EventType {
+        match value.as_str() {
+            "SignIn" => EventType::SignIn,
+            "SignUp" => EventType::SignUp,
+            "ForgotPassword" => EventType::ForgotPassword,
+            "PasswordChange" => EventType::PasswordChange,
+            "ResendCode" => EventType::ResendCode,
+            _ => EventType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EventType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EventType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EventType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EventType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EventType {
+    fn deserialize<D>(deserializer: D) -> Result<EventType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ExplicitAuthFlowsType {
+    /// `ADMIN_NO_SRP_AUTH`
+    AdminNoSrpAuth,
+    /// `CUSTOM_AUTH_FLOW_ONLY`
+    CustomAuthFlowOnly,
+    /// `USER_PASSWORD_AUTH`
+    UserPasswordAuth,
+    /// `ALLOW_ADMIN_USER_PASSWORD_AUTH`
+    AllowAdminUserPasswordAuth,
+    /// `ALLOW_CUSTOM_AUTH`
+    AllowCustomAuth,
+    /// `ALLOW_USER_PASSWORD_AUTH`
+    AllowUserPasswordAuth,
+    /// `ALLOW_USER_SRP_AUTH`
+    AllowUserSrpAuth,
+    /// `ALLOW_REFRESH_TOKEN_AUTH`
+    AllowRefreshTokenAuth,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ExplicitAuthFlowsType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ExplicitAuthFlowsType::AdminNoSrpAuth => "ADMIN_NO_SRP_AUTH",
+            ExplicitAuthFlowsType::CustomAuthFlowOnly => "CUSTOM_AUTH_FLOW_ONLY",
+            ExplicitAuthFlowsType::UserPasswordAuth => "USER_PASSWORD_AUTH",
+            ExplicitAuthFlowsType::AllowAdminUserPasswordAuth => "ALLOW_ADMIN_USER_PASSWORD_AUTH",
+            ExplicitAuthFlowsType::AllowCustomAuth => "ALLOW_CUSTOM_AUTH",
+            ExplicitAuthFlowsType::AllowUserPasswordAuth => "ALLOW_USER_PASSWORD_AUTH",
+            ExplicitAuthFlowsType::AllowUserSrpAuth => "ALLOW_USER_SRP_AUTH",
+            ExplicitAuthFlowsType::AllowRefreshTokenAuth => "ALLOW_REFRESH_TOKEN_AUTH",
+            ExplicitAuthFlowsType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ExplicitAuthFlowsType {
+    fn default() -> ExplicitAuthFlowsType {
+        ExplicitAuthFlowsType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ExplicitAuthFlowsType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ExplicitAuthFlowsType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ExplicitAuthFlowsType {
+    fn from(value: &'a str) -> ExplicitAuthFlowsType {
+        match value {
+            "ADMIN_NO_SRP_AUTH" => ExplicitAuthFlowsType::AdminNoSrpAuth,
+            "CUSTOM_AUTH_FLOW_ONLY" => ExplicitAuthFlowsType::CustomAuthFlowOnly,
+            "USER_PASSWORD_AUTH" => ExplicitAuthFlowsType::UserPasswordAuth,
+            "ALLOW_ADMIN_USER_PASSWORD_AUTH" => ExplicitAuthFlowsType::AllowAdminUserPasswordAuth,
+            "ALLOW_CUSTOM_AUTH" => ExplicitAuthFlowsType::AllowCustomAuth,
+            "ALLOW_USER_PASSWORD_AUTH" => ExplicitAuthFlowsType::AllowUserPasswordAuth,
+            "ALLOW_USER_SRP_AUTH" => ExplicitAuthFlowsType::AllowUserSrpAuth,
+            "ALLOW_REFRESH_TOKEN_AUTH" => ExplicitAuthFlowsType::AllowRefreshTokenAuth,
+            _ => ExplicitAuthFlowsType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ExplicitAuthFlowsType {
+    fn from(value: String) -> ExplicitAuthFlowsType {
+        match value.as_str() {
+            "ADMIN_NO_SRP_AUTH" => ExplicitAuthFlowsType::AdminNoSrpAuth,
+            "CUSTOM_AUTH_FLOW_ONLY" => ExplicitAuthFlowsType::CustomAuthFlowOnly,
+            "USER_PASSWORD_AUTH" => ExplicitAuthFlowsType::UserPasswordAuth,
+            "ALLOW_ADMIN_USER_PASSWORD_AUTH" => ExplicitAuthFlowsType::AllowAdminUserPasswordAuth,
+            "ALLOW_CUSTOM_AUTH" => ExplicitAuthFlowsType::AllowCustomAuth,
+            "ALLOW_USER_PASSWORD_AUTH" => ExplicitAuthFlowsType::AllowUserPasswordAuth,
+            "ALLOW_USER_SRP_AUTH" => ExplicitAuthFlowsType::AllowUserSrpAuth,
+            "ALLOW_REFRESH_TOKEN_AUTH" => ExplicitAuthFlowsType::AllowRefreshTokenAuth,
+            _ => ExplicitAuthFlowsType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ExplicitAuthFlowsType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ExplicitAuthFlowsType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ExplicitAuthFlowsType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ExplicitAuthFlowsType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ExplicitAuthFlowsType {
+    fn deserialize<D>(deserializer: D) -> Result<ExplicitAuthFlowsType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FeedbackValueType {
+    /// `Valid`
+    Valid,
+    /// `Invalid`
+    Invalid,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl FeedbackValueType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FeedbackValueType::Valid => "Valid",
+            FeedbackValueType::Invalid => "Invalid",
+            FeedbackValueType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for FeedbackValueType {
+    fn default() -> FeedbackValueType {
+        FeedbackValueType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for FeedbackValueType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FeedbackValueType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FeedbackValueType {
+    fn from(value: &'a str) -> FeedbackValueType {
+        match value {
+            "Valid" => FeedbackValueType::Valid,
+            "Invalid" => FeedbackValueType::Invalid,
+            _ => FeedbackValueType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FeedbackValueType {
+    fn from(value: String) -> FeedbackValueType {
+        match value.as_str() {
+            "Valid" => FeedbackValueType::Valid,
+            "Invalid" => FeedbackValueType::Invalid,
+            _ => FeedbackValueType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for FeedbackValueType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<FeedbackValueType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FeedbackValueType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FeedbackValueType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for FeedbackValueType {
+    fn deserialize<D>(deserializer: D) -> Result<FeedbackValueType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents the request to forget the device.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -2054,7 +4021,7 @@ pub struct GetUserPoolMfaConfigResponse {
     /// <p><p>The multi-factor (MFA) configuration. Valid values include:</p> <ul> <li> <p> <code>OFF</code> MFA will not be used for any users.</p> </li> <li> <p> <code>ON</code> MFA is required for all users to sign in.</p> </li> <li> <p> <code>OPTIONAL</code> MFA will be required only for individual users who have an MFA factor enabled.</p> </li> </ul></p>
     #[serde(rename = "MfaConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub mfa_configuration: Option<String>,
+    pub mfa_configuration: Option<UserPoolMfaType>,
     /// <p>The SMS text message multi-factor (MFA) configuration.</p>
     #[serde(rename = "SmsMfaConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2118,8 +4085,13 @@ pub struct GlobalSignOutResponse {}
 pub struct GroupType {
     /// <p>The date the group was created.</p>
     #[serde(rename = "CreationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A string containing the description of the group.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2130,8 +4102,13 @@ pub struct GroupType {
     pub group_name: Option<String>,
     /// <p>The date the group was last modified.</p>
     #[serde(rename = "LastModifiedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_date: Option<f64>,
+    pub last_modified_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A nonnegative integer value that specifies the precedence of this group relative to the other groups that a user can belong to in the user pool. If a user belongs to two or more groups, it is the group with the highest precedence whose role ARN will be used in the <code>cognito:roles</code> and <code>cognito:preferred_role</code> claims in the user's tokens. Groups with higher <code>Precedence</code> values take precedence over groups with lower <code>Precedence</code> values or with null <code>Precedence</code> values.</p> <p>Two groups can have the same <code>Precedence</code> value. If this happens, neither group takes precedence over the other. If two groups with the same <code>Precedence</code> have the same role ARN, that role is used in the <code>cognito:preferred_role</code> claim in tokens for users in each group. If the two groups have different role ARNs, the <code>cognito:preferred_role</code> claim is not set in users' tokens.</p> <p>The default <code>Precedence</code> value is null.</p>
     #[serde(rename = "Precedence")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2170,16 +4147,26 @@ pub struct IdentityProviderType {
     pub attribute_mapping: Option<::std::collections::HashMap<String, String>>,
     /// <p>The date the identity provider was created.</p>
     #[serde(rename = "CreationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A list of identity provider identifiers.</p>
     #[serde(rename = "IdpIdentifiers")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub idp_identifiers: Option<Vec<String>>,
     /// <p>The date the identity provider was last modified.</p>
     #[serde(rename = "LastModifiedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_date: Option<f64>,
+    pub last_modified_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The identity provider details, such as <code>MetadataURL</code> and <code>MetadataFile</code>.</p>
     #[serde(rename = "ProviderDetails")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2191,13 +4178,126 @@ pub struct IdentityProviderType {
     /// <p>The identity provider type.</p>
     #[serde(rename = "ProviderType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub provider_type: Option<String>,
+    pub provider_type: Option<IdentityProviderTypeType>,
     /// <p>The user pool ID.</p>
     #[serde(rename = "UserPoolId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub user_pool_id: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum IdentityProviderTypeType {
+    /// `SAML`
+    Saml,
+    /// `Facebook`
+    Facebook,
+    /// `Google`
+    Google,
+    /// `LoginWithAmazon`
+    LoginWithAmazon,
+    /// `SignInWithApple`
+    SignInWithApple,
+    /// `OIDC`
+    Oidc,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl IdentityProviderTypeType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            IdentityProviderTypeType::Saml => "SAML",
+            IdentityProviderTypeType::Facebook => "Facebook",
+            IdentityProviderTypeType::Google => "Google",
+            IdentityProviderTypeType::LoginWithAmazon => "LoginWithAmazon",
+            IdentityProviderTypeType::SignInWithApple => "SignInWithApple",
+            IdentityProviderTypeType::Oidc => "OIDC",
+            IdentityProviderTypeType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for IdentityProviderTypeType {
+    fn default() -> IdentityProviderTypeType {
+        IdentityProviderTypeType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for IdentityProviderTypeType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for IdentityProviderTypeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for IdentityProviderTypeType {
+    fn from(value: &'a str) -> IdentityProviderTypeType {
+        match value {
+            "SAML" => IdentityProviderTypeType::Saml,
+            "Facebook" => IdentityProviderTypeType::Facebook,
+            "Google" => IdentityProviderTypeType::Google,
+            "LoginWithAmazon" => IdentityProviderTypeType::LoginWithAmazon,
+            "SignInWithApple" => IdentityProviderTypeType::SignInWithApple,
+            "OIDC" => IdentityProviderTypeType::Oidc,
+            _ => IdentityProviderTypeType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for IdentityProviderTypeType {
+    fn from(value: String) -> IdentityProviderTypeType {
+        match value.as_str() {
+            "SAML" => IdentityProviderTypeType::Saml,
+            "Facebook" => IdentityProviderTypeType::Facebook,
+            "Google" => IdentityProviderTypeType::Google,
+            "LoginWithAmazon" => IdentityProviderTypeType::LoginWithAmazon,
+            "SignInWithApple" => IdentityProviderTypeType::SignInWithApple,
+            "OIDC" => IdentityProviderTypeType::Oidc,
+            _ => IdentityProviderTypeType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for IdentityProviderTypeType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<IdentityProviderTypeType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for IdentityProviderTypeType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for IdentityProviderTypeType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for IdentityProviderTypeType {
+    fn deserialize<D>(deserializer: D) -> Result<IdentityProviderTypeType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Initiates the authentication request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -2208,7 +4308,7 @@ pub struct InitiateAuthRequest {
     pub analytics_metadata: Option<AnalyticsMetadataType>,
     /// <p>The authentication flow for this call to execute. The API action will depend on this value. For example: </p> <ul> <li> <p> <code>REFRESH_TOKEN_AUTH</code> will take in a valid refresh token and return new tokens.</p> </li> <li> <p> <code>USER_SRP_AUTH</code> will take in <code>USERNAME</code> and <code>SRP_A</code> and return the SRP variables to be used for next challenge execution.</p> </li> <li> <p> <code>USER_PASSWORD_AUTH</code> will take in <code>USERNAME</code> and <code>PASSWORD</code> and return the next challenge or tokens.</p> </li> </ul> <p>Valid values include:</p> <ul> <li> <p> <code>USER_SRP_AUTH</code>: Authentication flow for the Secure Remote Password (SRP) protocol.</p> </li> <li> <p> <code>REFRESH_TOKEN_AUTH</code>/<code>REFRESH_TOKEN</code>: Authentication flow for refreshing the access token and ID token by supplying a valid refresh token.</p> </li> <li> <p> <code>CUSTOM_AUTH</code>: Custom authentication flow.</p> </li> <li> <p> <code>USER_PASSWORD_AUTH</code>: Non-SRP authentication flow; USERNAME and PASSWORD are passed directly. If a user migration Lambda trigger is set, this flow will invoke the user migration Lambda if the USERNAME is not found in the user pool. </p> </li> <li> <p> <code>ADMIN_USER_PASSWORD_AUTH</code>: Admin-based user password authentication. This replaces the <code>ADMIN_NO_SRP_AUTH</code> authentication flow. In this flow, Cognito receives the password in the request instead of using the SRP process to verify passwords.</p> </li> </ul> <p> <code>ADMIN_NO_SRP_AUTH</code> is not a valid value.</p>
     #[serde(rename = "AuthFlow")]
-    pub auth_flow: String,
+    pub auth_flow: AuthFlowType,
     /// <p><p>The authentication parameters. These are inputs corresponding to the <code>AuthFlow</code> that you are invoking. The required values depend on the value of <code>AuthFlow</code>:</p> <ul> <li> <p>For <code>USER<em>SRP</em>AUTH</code>: <code>USERNAME</code> (required), <code>SRP<em>A</code> (required), <code>SECRET</em>HASH</code> (required if the app client is configured with a client secret), <code>DEVICE<em>KEY</code> </p> </li> <li> <p>For <code>REFRESH</em>TOKEN<em>AUTH/REFRESH</em>TOKEN</code>: <code>REFRESH<em>TOKEN</code> (required), <code>SECRET</em>HASH</code> (required if the app client is configured with a client secret), <code>DEVICE<em>KEY</code> </p> </li> <li> <p>For <code>CUSTOM</em>AUTH</code>: <code>USERNAME</code> (required), <code>SECRET<em>HASH</code> (if app client is configured with client secret), <code>DEVICE</em>KEY</code> </p> </li> </ul></p>
     #[serde(rename = "AuthParameters")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2237,7 +4337,7 @@ pub struct InitiateAuthResponse {
     /// <p><p>The name of the challenge which you are responding to with this call. This is returned to you in the <code>AdminInitiateAuth</code> response if you need to pass another challenge.</p> <p>Valid values include the following. Note that all of these challenges require <code>USERNAME</code> and <code>SECRET<em>HASH</code> (if applicable) in the parameters.</p> <ul> <li> <p> <code>SMS</em>MFA</code>: Next challenge is to supply an <code>SMS<em>MFA</em>CODE</code>, delivered via SMS.</p> </li> <li> <p> <code>PASSWORD<em>VERIFIER</code>: Next challenge is to supply <code>PASSWORD</em>CLAIM<em>SIGNATURE</code>, <code>PASSWORD</em>CLAIM<em>SECRET</em>BLOCK</code>, and <code>TIMESTAMP</code> after the client-side SRP calculations.</p> </li> <li> <p> <code>CUSTOM<em>CHALLENGE</code>: This is returned if your custom authentication flow determines that the user should pass another challenge before tokens are issued.</p> </li> <li> <p> <code>DEVICE</em>SRP<em>AUTH</code>: If device tracking was enabled on your user pool and the previous challenges were passed, this challenge is returned so that Amazon Cognito can start tracking this device.</p> </li> <li> <p> <code>DEVICE</em>PASSWORD<em>VERIFIER</code>: Similar to <code>PASSWORD</em>VERIFIER</code>, but for devices only.</p> </li> <li> <p> <code>NEW<em>PASSWORD</em>REQUIRED</code>: For users which are required to change their passwords after successful first login. This challenge should be passed with <code>NEW_PASSWORD</code> and any other required attributes.</p> </li> </ul></p>
     #[serde(rename = "ChallengeName")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub challenge_name: Option<String>,
+    pub challenge_name: Option<ChallengeNameType>,
     /// <p>The challenge parameters. These are returned to you in the <code>InitiateAuth</code> response if you need to pass another challenge. The responses in this parameter should be used to compute inputs to the next call (<code>RespondToAuthChallenge</code>). </p> <p>All challenges require <code>USERNAME</code> and <code>SECRET_HASH</code> (if applicable).</p>
     #[serde(rename = "ChallengeParameters")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2595,9 +4695,102 @@ pub struct MFAOptionType {
     /// <p>The delivery medium to send the MFA code. You can use this parameter to set only the <code>SMS</code> delivery medium value.</p>
     #[serde(rename = "DeliveryMedium")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub delivery_medium: Option<String>,
+    pub delivery_medium: Option<DeliveryMediumType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MessageActionType {
+    /// `RESEND`
+    Resend,
+    /// `SUPPRESS`
+    Suppress,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl MessageActionType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MessageActionType::Resend => "RESEND",
+            MessageActionType::Suppress => "SUPPRESS",
+            MessageActionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for MessageActionType {
+    fn default() -> MessageActionType {
+        MessageActionType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for MessageActionType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for MessageActionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for MessageActionType {
+    fn from(value: &'a str) -> MessageActionType {
+        match value {
+            "RESEND" => MessageActionType::Resend,
+            "SUPPRESS" => MessageActionType::Suppress,
+            _ => MessageActionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MessageActionType {
+    fn from(value: String) -> MessageActionType {
+        match value.as_str() {
+            "RESEND" => MessageActionType::Resend,
+            "SUPPRESS" => MessageActionType::Suppress,
+            _ => MessageActionType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for MessageActionType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<MessageActionType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MessageActionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for MessageActionType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for MessageActionType {
+    fn deserialize<D>(deserializer: D) -> Result<MessageActionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The message template structure.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MessageTemplateType {
@@ -2686,6 +4879,104 @@ pub struct NumberAttributeConstraintsType {
     pub min_value: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OAuthFlowType {
+    /// `code`
+    Code,
+    /// `implicit`
+    Implicit,
+    /// `client_credentials`
+    ClientCredentials,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl OAuthFlowType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OAuthFlowType::Code => "code",
+            OAuthFlowType::Implicit => "implicit",
+            OAuthFlowType::ClientCredentials => "client_credentials",
+            OAuthFlowType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for OAuthFlowType {
+    fn default() -> OAuthFlowType {
+        OAuthFlowType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for OAuthFlowType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for OAuthFlowType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for OAuthFlowType {
+    fn from(value: &'a str) -> OAuthFlowType {
+        match value {
+            "code" => OAuthFlowType::Code,
+            "implicit" => OAuthFlowType::Implicit,
+            "client_credentials" => OAuthFlowType::ClientCredentials,
+            _ => OAuthFlowType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OAuthFlowType {
+    fn from(value: String) -> OAuthFlowType {
+        match value.as_str() {
+            "code" => OAuthFlowType::Code,
+            "implicit" => OAuthFlowType::Implicit,
+            "client_credentials" => OAuthFlowType::ClientCredentials,
+            _ => OAuthFlowType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for OAuthFlowType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<OAuthFlowType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OAuthFlowType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for OAuthFlowType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OAuthFlowType {
+    fn deserialize<D>(deserializer: D) -> Result<OAuthFlowType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The password policy type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PasswordPolicyType {
@@ -2715,52 +5006,253 @@ pub struct PasswordPolicyType {
     pub temporary_password_validity_days: Option<i64>,
 }
 
-/// <p>A container for identity provider details.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct ProviderDescription {
-    /// <p>The date the provider was added to the user pool.</p>
-    #[serde(rename = "CreationDate")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
-    /// <p>The date the provider was last modified.</p>
-    #[serde(rename = "LastModifiedDate")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_date: Option<f64>,
-    /// <p>The identity provider name.</p>
-    #[serde(rename = "ProviderName")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub provider_name: Option<String>,
-    /// <p>The identity provider type.</p>
-    #[serde(rename = "ProviderType")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub provider_type: Option<String>,
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PreventUserExistenceErrorTypes {
+    /// `LEGACY`
+    Legacy,
+    /// `ENABLED`
+    Enabled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PreventUserExistenceErrorTypes {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PreventUserExistenceErrorTypes::Legacy => "LEGACY",
+            PreventUserExistenceErrorTypes::Enabled => "ENABLED",
+            PreventUserExistenceErrorTypes::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PreventUserExistenceErrorTypes {
+    fn default() -> PreventUserExistenceErrorTypes {
+        PreventUserExistenceErrorTypes::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PreventUserExistenceErrorTypes {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PreventUserExistenceErrorTypes {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PreventUserExistenceErrorTypes {
+    fn from(value: &'a str) -> PreventUserExistenceErrorTypes {
+        match value {
+            "LEGACY" => PreventUserExistenceErrorTypes::Legacy,
+            "ENABLED" => PreventUserExistenceErrorTypes::Enabled,
+            _ => PreventUserExistenceErrorTypes::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PreventUserExistenceErrorTypes {
+    fn from(value: String) -> PreventUserExistenceErrorTypes {
+        match value.as_str() {
+            "LEGACY" => PreventUserExistenceErrorTypes::Legacy,
+            "ENABLED" => PreventUserExistenceErrorTypes::Enabled,
+            _ => PreventUserExistenceErrorTypes::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PreventUserExistenceErrorTypes {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PreventUserExistenceErrorTypes, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PreventUserExistenceErrorTypes {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PreventUserExistenceErrorTypes {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PreventUserExistenceErrorTypes {
+    fn deserialize<D>(deserializer: D) -> Result<PreventUserExistenceErrorTypes, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>A container for identity provider details.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct ProviderDescription {
+    /// <p>The date the provider was added to the user pool.</p>
+    #[serde(rename = "CreationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
+    /// <p>The date the provider was last modified.</p>
+    #[serde(rename = "LastModifiedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_modified_date: Option<::chrono::DateTime<::chrono::Utc>>,
+    /// <p>The identity provider name.</p>
+    #[serde(rename = "ProviderName")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider_name: Option<String>,
+    /// <p>The identity provider type.</p>
+    #[serde(rename = "ProviderType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider_type: Option<IdentityProviderTypeType>,
+}
+
+/// <p>A container for information about an identity provider for a user pool.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct ProviderUserIdentifierType {
+    /// <p>The name of the provider attribute to link to, for example, <code>NameID</code>.</p>
+    #[serde(rename = "ProviderAttributeName")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider_attribute_name: Option<String>,
+    /// <p>The value of the provider attribute to link to, for example, <code>xxxxx_account</code>.</p>
+    #[serde(rename = "ProviderAttributeValue")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider_attribute_value: Option<String>,
+    /// <p>The name of the provider, for example, Facebook, Google, or Login with Amazon.</p>
+    #[serde(rename = "ProviderName")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider_name: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RecoveryOptionNameType {
+    /// `verified_email`
+    VerifiedEmail,
+    /// `verified_phone_number`
+    VerifiedPhoneNumber,
+    /// `admin_only`
+    AdminOnly,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RecoveryOptionNameType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RecoveryOptionNameType::VerifiedEmail => "verified_email",
+            RecoveryOptionNameType::VerifiedPhoneNumber => "verified_phone_number",
+            RecoveryOptionNameType::AdminOnly => "admin_only",
+            RecoveryOptionNameType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RecoveryOptionNameType {
+    fn default() -> RecoveryOptionNameType {
+        RecoveryOptionNameType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RecoveryOptionNameType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RecoveryOptionNameType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RecoveryOptionNameType {
+    fn from(value: &'a str) -> RecoveryOptionNameType {
+        match value {
+            "verified_email" => RecoveryOptionNameType::VerifiedEmail,
+            "verified_phone_number" => RecoveryOptionNameType::VerifiedPhoneNumber,
+            "admin_only" => RecoveryOptionNameType::AdminOnly,
+            _ => RecoveryOptionNameType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RecoveryOptionNameType {
+    fn from(value: String) -> RecoveryOptionNameType {
+        match value.as_str() {
+            "verified_email" => RecoveryOptionNameType::VerifiedEmail,
+            "verified_phone_number" => RecoveryOptionNameType::VerifiedPhoneNumber,
+            "admin_only" => RecoveryOptionNameType::AdminOnly,
+            _ => RecoveryOptionNameType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RecoveryOptionNameType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RecoveryOptionNameType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RecoveryOptionNameType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RecoveryOptionNameType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
-/// <p>A container for information about an identity provider for a user pool.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct ProviderUserIdentifierType {
-    /// <p>The name of the provider attribute to link to, for example, <code>NameID</code>.</p>
-    #[serde(rename = "ProviderAttributeName")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub provider_attribute_name: Option<String>,
-    /// <p>The value of the provider attribute to link to, for example, <code>xxxxx_account</code>.</p>
-    #[serde(rename = "ProviderAttributeValue")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub provider_attribute_value: Option<String>,
-    /// <p>The name of the provider, for example, Facebook, Google, or Login with Amazon.</p>
-    #[serde(rename = "ProviderName")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub provider_name: Option<String>,
+impl<'de> ::serde::Deserialize<'de> for RecoveryOptionNameType {
+    fn deserialize<D>(deserializer: D) -> Result<RecoveryOptionNameType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
 }
-
 /// <p>A map containing a priority as a key, and recovery method name as a value.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RecoveryOptionType {
     /// <p>Specifies the recovery method for a user.</p>
     #[serde(rename = "Name")]
-    pub name: String,
+    pub name: RecoveryOptionNameType,
     /// <p>A positive integer specifying priority of a method with 1 being the highest priority.</p>
     #[serde(rename = "Priority")]
     pub priority: i64,
@@ -2847,7 +5339,7 @@ pub struct RespondToAuthChallengeRequest {
     pub analytics_metadata: Option<AnalyticsMetadataType>,
     /// <p>The challenge name. For more information, see .</p> <p> <code>ADMIN_NO_SRP_AUTH</code> is not a valid value.</p>
     #[serde(rename = "ChallengeName")]
-    pub challenge_name: String,
+    pub challenge_name: ChallengeNameType,
     /// <p><p>The challenge responses. These are inputs corresponding to the value of <code>ChallengeName</code>, for example:</p> <note> <p> <code>SECRET<em>HASH</code> (if app client is configured with client secret) applies to all inputs below (including <code>SOFTWARE</em>TOKEN<em>MFA</code>).</p> </note> <ul> <li> <p> <code>SMS</em>MFA</code>: <code>SMS<em>MFA</em>CODE</code>, <code>USERNAME</code>.</p> </li> <li> <p> <code>PASSWORD<em>VERIFIER</code>: <code>PASSWORD</em>CLAIM<em>SIGNATURE</code>, <code>PASSWORD</em>CLAIM<em>SECRET</em>BLOCK</code>, <code>TIMESTAMP</code>, <code>USERNAME</code>.</p> </li> <li> <p> <code>NEW<em>PASSWORD</em>REQUIRED</code>: <code>NEW<em>PASSWORD</code>, any other required attributes, <code>USERNAME</code>. </p> </li> <li> <p> <code>SOFTWARE</em>TOKEN<em>MFA</code>: <code>USERNAME</code> and <code>SOFTWARE</em>TOKEN<em>MFA</em>CODE</code> are required attributes.</p> </li> <li> <p> <code>DEVICE<em>SRP</em>AUTH</code> requires <code>USERNAME</code>, <code>DEVICE<em>KEY</code>, <code>SRP</em>A</code> (and <code>SECRET<em>HASH</code>).</p> </li> <li> <p> <code>DEVICE</em>PASSWORD<em>VERIFIER</code> requires everything that <code>PASSWORD</em>VERIFIER</code> requires plus <code>DEVICE_KEY</code>.</p> </li> </ul></p>
     #[serde(rename = "ChallengeResponses")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2880,7 +5372,7 @@ pub struct RespondToAuthChallengeResponse {
     /// <p>The challenge name. For more information, see .</p>
     #[serde(rename = "ChallengeName")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub challenge_name: Option<String>,
+    pub challenge_name: Option<ChallengeNameType>,
     /// <p>The challenge parameters. For more information, see .</p>
     #[serde(rename = "ChallengeParameters")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2910,8 +5402,13 @@ pub struct RiskConfigurationType {
         Option<CompromisedCredentialsRiskConfigurationType>,
     /// <p>The last modified date.</p>
     #[serde(rename = "LastModifiedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_date: Option<f64>,
+    pub last_modified_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The configuration to override the risk decision.</p>
     #[serde(rename = "RiskExceptionConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2922,6 +5419,104 @@ pub struct RiskConfigurationType {
     pub user_pool_id: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RiskDecisionType {
+    /// `NoRisk`
+    NoRisk,
+    /// `AccountTakeover`
+    AccountTakeover,
+    /// `Block`
+    Block,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RiskDecisionType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RiskDecisionType::NoRisk => "NoRisk",
+            RiskDecisionType::AccountTakeover => "AccountTakeover",
+            RiskDecisionType::Block => "Block",
+            RiskDecisionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RiskDecisionType {
+    fn default() -> RiskDecisionType {
+        RiskDecisionType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RiskDecisionType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RiskDecisionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RiskDecisionType {
+    fn from(value: &'a str) -> RiskDecisionType {
+        match value {
+            "NoRisk" => RiskDecisionType::NoRisk,
+            "AccountTakeover" => RiskDecisionType::AccountTakeover,
+            "Block" => RiskDecisionType::Block,
+            _ => RiskDecisionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RiskDecisionType {
+    fn from(value: String) -> RiskDecisionType {
+        match value.as_str() {
+            "NoRisk" => RiskDecisionType::NoRisk,
+            "AccountTakeover" => RiskDecisionType::AccountTakeover,
+            "Block" => RiskDecisionType::Block,
+            _ => RiskDecisionType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RiskDecisionType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RiskDecisionType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RiskDecisionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RiskDecisionType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RiskDecisionType {
+    fn deserialize<D>(deserializer: D) -> Result<RiskDecisionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The type of the configuration to override the risk decision.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RiskExceptionConfigurationType {
@@ -2935,6 +5530,104 @@ pub struct RiskExceptionConfigurationType {
     pub skipped_ip_range_list: Option<Vec<String>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RiskLevelType {
+    /// `Low`
+    Low,
+    /// `Medium`
+    Medium,
+    /// `High`
+    High,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RiskLevelType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RiskLevelType::Low => "Low",
+            RiskLevelType::Medium => "Medium",
+            RiskLevelType::High => "High",
+            RiskLevelType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RiskLevelType {
+    fn default() -> RiskLevelType {
+        RiskLevelType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RiskLevelType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RiskLevelType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RiskLevelType {
+    fn from(value: &'a str) -> RiskLevelType {
+        match value {
+            "Low" => RiskLevelType::Low,
+            "Medium" => RiskLevelType::Medium,
+            "High" => RiskLevelType::High,
+            _ => RiskLevelType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RiskLevelType {
+    fn from(value: String) -> RiskLevelType {
+        match value.as_str() {
+            "Low" => RiskLevelType::Low,
+            "Medium" => RiskLevelType::Medium,
+            "High" => RiskLevelType::High,
+            _ => RiskLevelType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RiskLevelType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RiskLevelType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RiskLevelType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RiskLevelType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RiskLevelType {
+    fn deserialize<D>(deserializer: D) -> Result<RiskLevelType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The type used for enabling SMS MFA at the user level.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -2955,7 +5648,7 @@ pub struct SchemaAttributeType {
     /// <p>The attribute data type.</p>
     #[serde(rename = "AttributeDataType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub attribute_data_type: Option<String>,
+    pub attribute_data_type: Option<AttributeDataType>,
     /// <p>Specifies whether the attribute type is developer only.</p>
     #[serde(rename = "DeveloperOnlyAttribute")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3074,7 +5767,7 @@ pub struct SetUserPoolMfaConfigRequest {
     /// <p><p>The MFA configuration. Valid values include:</p> <ul> <li> <p> <code>OFF</code> MFA will not be used for any users.</p> </li> <li> <p> <code>ON</code> MFA is required for all users to sign in.</p> </li> <li> <p> <code>OPTIONAL</code> MFA will be required only for individual users who have an MFA factor enabled.</p> </li> </ul></p>
     #[serde(rename = "MfaConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub mfa_configuration: Option<String>,
+    pub mfa_configuration: Option<UserPoolMfaType>,
     /// <p>The SMS text message MFA configuration.</p>
     #[serde(rename = "SmsMfaConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3094,7 +5787,7 @@ pub struct SetUserPoolMfaConfigResponse {
     /// <p><p>The MFA configuration. Valid values include:</p> <ul> <li> <p> <code>OFF</code> MFA will not be used for any users.</p> </li> <li> <p> <code>ON</code> MFA is required for all users to sign in.</p> </li> <li> <p> <code>OPTIONAL</code> MFA will be required only for individual users who have an MFA factor enabled.</p> </li> </ul></p>
     #[serde(rename = "MfaConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub mfa_configuration: Option<String>,
+    pub mfa_configuration: Option<UserPoolMfaType>,
     /// <p>The SMS text message MFA configuration.</p>
     #[serde(rename = "SmsMfaConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3247,6 +5940,99 @@ pub struct StartUserImportJobResponse {
     pub user_import_job: Option<UserImportJobType>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StatusType {
+    /// `Enabled`
+    Enabled,
+    /// `Disabled`
+    Disabled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl StatusType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StatusType::Enabled => "Enabled",
+            StatusType::Disabled => "Disabled",
+            StatusType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for StatusType {
+    fn default() -> StatusType {
+        StatusType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for StatusType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for StatusType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for StatusType {
+    fn from(value: &'a str) -> StatusType {
+        match value {
+            "Enabled" => StatusType::Enabled,
+            "Disabled" => StatusType::Disabled,
+            _ => StatusType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StatusType {
+    fn from(value: String) -> StatusType {
+        match value.as_str() {
+            "Enabled" => StatusType::Enabled,
+            "Disabled" => StatusType::Disabled,
+            _ => StatusType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for StatusType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<StatusType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StatusType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for StatusType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StatusType {
+    fn deserialize<D>(deserializer: D) -> Result<StatusType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents the request to stop the user import job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -3315,16 +6101,26 @@ pub struct UICustomizationType {
     pub client_id: Option<String>,
     /// <p>The creation date for the UI customization.</p>
     #[serde(rename = "CreationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The logo image for the UI customization.</p>
     #[serde(rename = "ImageUrl")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub image_url: Option<String>,
     /// <p>The last-modified date for the UI customization.</p>
     #[serde(rename = "LastModifiedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_date: Option<f64>,
+    pub last_modified_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The user pool ID for the user pool.</p>
     #[serde(rename = "UserPoolId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3357,7 +6153,7 @@ pub struct UpdateAuthEventFeedbackRequest {
     pub feedback_token: String,
     /// <p>The authentication event feedback value.</p>
     #[serde(rename = "FeedbackValue")]
-    pub feedback_value: String,
+    pub feedback_value: FeedbackValueType,
     /// <p>The user pool ID.</p>
     #[serde(rename = "UserPoolId")]
     pub user_pool_id: String,
@@ -3383,7 +6179,7 @@ pub struct UpdateDeviceStatusRequest {
     /// <p>The status of whether a device is remembered.</p>
     #[serde(rename = "DeviceRememberedStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub device_remembered_status: Option<String>,
+    pub device_remembered_status: Option<DeviceRememberedStatusType>,
 }
 
 /// <p>The response to the request to update the device status.</p>
@@ -3513,7 +6309,7 @@ pub struct UpdateUserPoolClientRequest {
     /// <p>Set to <code>code</code> to initiate a code grant flow, which provides an authorization code as the response. This code can be exchanged for access tokens with the token endpoint.</p>
     #[serde(rename = "AllowedOAuthFlows")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub allowed_o_auth_flows: Option<Vec<String>>,
+    pub allowed_o_auth_flows: Option<Vec<OAuthFlowType>>,
     /// <p>Set to TRUE if the client is allowed to follow the OAuth protocol when interacting with Cognito user pools.</p>
     #[serde(rename = "AllowedOAuthFlowsUserPoolClient")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3544,7 +6340,7 @@ pub struct UpdateUserPoolClientRequest {
     /// <p><p>The authentication flows that are supported by the user pool clients. Flow names without the <code>ALLOW<em></code> prefix are deprecated in favor of new names with the <code>ALLOW</em></code> prefix. Note that values with <code>ALLOW<em></code> prefix cannot be used along with values without <code>ALLOW</em></code> prefix.</p> <p>Valid values include:</p> <ul> <li> <p> <code>ALLOW<em>ADMIN</em>USER<em>PASSWORD</em>AUTH</code>: Enable admin based user password authentication flow <code>ADMIN<em>USER</em>PASSWORD<em>AUTH</code>. This setting replaces the <code>ADMIN</em>NO<em>SRP</em>AUTH</code> setting. With this authentication flow, Cognito receives the password in the request instead of using the SRP (Secure Remote Password protocol) protocol to verify passwords.</p> </li> <li> <p> <code>ALLOW<em>CUSTOM</em>AUTH</code>: Enable Lambda trigger based authentication.</p> </li> <li> <p> <code>ALLOW<em>USER</em>PASSWORD<em>AUTH</code>: Enable user password-based authentication. In this flow, Cognito receives the password in the request instead of using the SRP protocol to verify passwords.</p> </li> <li> <p> <code>ALLOW</em>USER<em>SRP</em>AUTH</code>: Enable SRP based authentication.</p> </li> <li> <p> <code>ALLOW<em>REFRESH</em>TOKEN_AUTH</code>: Enable authflow to refresh tokens.</p> </li> </ul></p>
     #[serde(rename = "ExplicitAuthFlows")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub explicit_auth_flows: Option<Vec<String>>,
+    pub explicit_auth_flows: Option<Vec<ExplicitAuthFlowsType>>,
     /// <p>A list of allowed logout URLs for the identity providers.</p>
     #[serde(rename = "LogoutURLs")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3552,7 +6348,7 @@ pub struct UpdateUserPoolClientRequest {
     /// <p><p>Use this setting to choose which errors and responses are returned by Cognito APIs during authentication, account confirmation, and password recovery when the user does not exist in the user pool. When set to <code>ENABLED</code> and the user does not exist, authentication returns an error indicating either the username or password was incorrect, and account confirmation and password recovery return a response indicating a code was sent to a simulated destination. When set to <code>LEGACY</code>, those APIs will return a <code>UserNotFoundException</code> exception if the user does not exist in the user pool.</p> <p>Valid values include:</p> <ul> <li> <p> <code>ENABLED</code> - This prevents user existence-related errors.</p> </li> <li> <p> <code>LEGACY</code> - This represents the old behavior of Cognito where user existence related errors are not prevented.</p> </li> </ul> <p>This setting affects the behavior of following APIs:</p> <ul> <li> <p> <a>AdminInitiateAuth</a> </p> </li> <li> <p> <a>AdminRespondToAuthChallenge</a> </p> </li> <li> <p> <a>InitiateAuth</a> </p> </li> <li> <p> <a>RespondToAuthChallenge</a> </p> </li> <li> <p> <a>ForgotPassword</a> </p> </li> <li> <p> <a>ConfirmForgotPassword</a> </p> </li> <li> <p> <a>ConfirmSignUp</a> </p> </li> <li> <p> <a>ResendConfirmationCode</a> </p> </li> </ul> <note> <p>After January 1st 2020, the value of <code>PreventUserExistenceErrors</code> will default to <code>ENABLED</code> for newly created user pool clients if no value is provided.</p> </note></p>
     #[serde(rename = "PreventUserExistenceErrors")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub prevent_user_existence_errors: Option<String>,
+    pub prevent_user_existence_errors: Option<PreventUserExistenceErrorTypes>,
     /// <p>The read-only attributes of the user pool.</p>
     #[serde(rename = "ReadAttributes")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3624,7 +6420,7 @@ pub struct UpdateUserPoolRequest {
     /// <p>The attributes that are automatically verified when the Amazon Cognito service makes a request to update user pools.</p>
     #[serde(rename = "AutoVerifiedAttributes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub auto_verified_attributes: Option<Vec<String>>,
+    pub auto_verified_attributes: Option<Vec<VerifiedAttributeType>>,
     /// <p>Device configuration.</p>
     #[serde(rename = "DeviceConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3648,7 +6444,7 @@ pub struct UpdateUserPoolRequest {
     /// <p><p>Can be one of the following values:</p> <ul> <li> <p> <code>OFF</code> - MFA tokens are not required and cannot be specified during user registration.</p> </li> <li> <p> <code>ON</code> - MFA tokens are required for all user registrations. You can only specify required when you are initially creating a user pool.</p> </li> <li> <p> <code>OPTIONAL</code> - Users have the option when registering to create an MFA token.</p> </li> </ul></p>
     #[serde(rename = "MfaConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub mfa_configuration: Option<String>,
+    pub mfa_configuration: Option<UserPoolMfaType>,
     /// <p>A container with the policies you wish to update in a user pool.</p>
     #[serde(rename = "Policies")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3697,6 +6493,129 @@ pub struct UserContextDataType {
     pub encoded_data: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum UserImportJobStatusType {
+    /// `Created`
+    Created,
+    /// `Pending`
+    Pending,
+    /// `InProgress`
+    InProgress,
+    /// `Stopping`
+    Stopping,
+    /// `Expired`
+    Expired,
+    /// `Stopped`
+    Stopped,
+    /// `Failed`
+    Failed,
+    /// `Succeeded`
+    Succeeded,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl UserImportJobStatusType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            UserImportJobStatusType::Created => "Created",
+            UserImportJobStatusType::Pending => "Pending",
+            UserImportJobStatusType::InProgress => "InProgress",
+            UserImportJobStatusType::Stopping => "Stopping",
+            UserImportJobStatusType::Expired => "Expired",
+            UserImportJobStatusType::Stopped => "Stopped",
+            UserImportJobStatusType::Failed => "Failed",
+            UserImportJobStatusType::Succeeded => "Succeeded",
+            UserImportJobStatusType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for UserImportJobStatusType {
+    fn default() -> UserImportJobStatusType {
+        UserImportJobStatusType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for UserImportJobStatusType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for UserImportJobStatusType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for UserImportJobStatusType {
+    fn from(value: &'a str) -> UserImportJobStatusType {
+        match value {
+            "Created" => UserImportJobStatusType::Created,
+            "Pending" => UserImportJobStatusType::Pending,
+            "InProgress" => UserImportJobStatusType::InProgress,
+            "Stopping" => UserImportJobStatusType::Stopping,
+            "Expired" => UserImportJobStatusType::Expired,
+            "Stopped" => UserImportJobStatusType::Stopped,
+            "Failed" => UserImportJobStatusType::Failed,
+            "Succeeded" => UserImportJobStatusType::Succeeded,
+            _ => UserImportJobStatusType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for UserImportJobStatusType {
+    fn from(value: String) -> UserImportJobStatusType {
+        match value.as_str() {
+            "Created" => UserImportJobStatusType::Created,
+            "Pending" => UserImportJobStatusType::Pending,
+            "InProgress" => UserImportJobStatusType::InProgress,
+            "Stopping" => UserImportJobStatusType::Stopping,
+            "Expired" => UserImportJobStatusType::Expired,
+            "Stopped" => UserImportJobStatusType::Stopped,
+            "Failed" => UserImportJobStatusType::Failed,
+            "Succeeded" => UserImportJobStatusType::Succeeded,
+            _ => UserImportJobStatusType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for UserImportJobStatusType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<UserImportJobStatusType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for UserImportJobStatusType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for UserImportJobStatusType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for UserImportJobStatusType {
+    fn deserialize<D>(deserializer: D) -> Result<UserImportJobStatusType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The user import job type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -3707,16 +6626,26 @@ pub struct UserImportJobType {
     pub cloud_watch_logs_role_arn: Option<String>,
     /// <p>The date when the user import job was completed.</p>
     #[serde(rename = "CompletionDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub completion_date: Option<f64>,
+    pub completion_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The message returned when the user import job is completed.</p>
     #[serde(rename = "CompletionMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub completion_message: Option<String>,
     /// <p>The date the user import job was created.</p>
     #[serde(rename = "CreationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The number of users that could not be imported.</p>
     #[serde(rename = "FailedUsers")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3743,12 +6672,17 @@ pub struct UserImportJobType {
     pub skipped_users: Option<i64>,
     /// <p>The date when the user import job was started.</p>
     #[serde(rename = "StartDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_date: Option<f64>,
+    pub start_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>The status of the user import job. One of the following:</p> <ul> <li> <p> <code>Created</code> - The job was created but not started.</p> </li> <li> <p> <code>Pending</code> - A transition state. You have started the job, but it has not begun importing users yet.</p> </li> <li> <p> <code>InProgress</code> - The job has started, and users are being imported.</p> </li> <li> <p> <code>Stopping</code> - You have stopped the job, but the job has not stopped importing users yet.</p> </li> <li> <p> <code>Stopped</code> - You have stopped the job, and the job has stopped importing users.</p> </li> <li> <p> <code>Succeeded</code> - The job has completed successfully.</p> </li> <li> <p> <code>Failed</code> - The job has stopped due to an error.</p> </li> <li> <p> <code>Expired</code> - You created a job, but did not start the job within 24-48 hours. All data associated with the job was deleted, and the job cannot be started.</p> </li> </ul></p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<UserImportJobStatusType>,
     /// <p>The user pool ID for the user pool that the users are being imported into.</p>
     #[serde(rename = "UserPoolId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3760,7 +6694,7 @@ pub struct UserImportJobType {
 pub struct UserPoolAddOnsType {
     /// <p>The advanced security mode.</p>
     #[serde(rename = "AdvancedSecurityMode")]
-    pub advanced_security_mode: String,
+    pub advanced_security_mode: AdvancedSecurityModeType,
 }
 
 /// <p>The description of the user pool client.</p>
@@ -3788,7 +6722,7 @@ pub struct UserPoolClientType {
     /// <p>Set to <code>code</code> to initiate a code grant flow, which provides an authorization code as the response. This code can be exchanged for access tokens with the token endpoint.</p> <p>Set to <code>token</code> to specify that the client should get the access token (and, optionally, ID token, based on scopes) directly.</p>
     #[serde(rename = "AllowedOAuthFlows")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub allowed_o_auth_flows: Option<Vec<String>>,
+    pub allowed_o_auth_flows: Option<Vec<OAuthFlowType>>,
     /// <p>Set to TRUE if the client is allowed to follow the OAuth protocol when interacting with Cognito user pools.</p>
     #[serde(rename = "AllowedOAuthFlowsUserPoolClient")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3819,8 +6753,13 @@ pub struct UserPoolClientType {
     pub client_secret: Option<String>,
     /// <p>The date the user pool client was created.</p>
     #[serde(rename = "CreationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The default redirect URI. Must be in the <code>CallbackURLs</code> list.</p> <p>A redirect URI must:</p> <ul> <li> <p>Be an absolute URI.</p> </li> <li> <p>Be registered with the authorization server.</p> </li> <li> <p>Not include a fragment component.</p> </li> </ul> <p>See <a href="https://tools.ietf.org/html/rfc6749#section-3.1.2">OAuth 2.0 - Redirection Endpoint</a>.</p> <p>Amazon Cognito requires HTTPS over HTTP except for http://localhost for testing purposes only.</p> <p>App callback URLs such as myapp://example are also supported.</p>
     #[serde(rename = "DefaultRedirectURI")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3828,11 +6767,16 @@ pub struct UserPoolClientType {
     /// <p><p>The authentication flows that are supported by the user pool clients. Flow names without the <code>ALLOW<em></code> prefix are deprecated in favor of new names with the <code>ALLOW</em></code> prefix. Note that values with <code>ALLOW<em></code> prefix cannot be used along with values without <code>ALLOW</em></code> prefix.</p> <p>Valid values include:</p> <ul> <li> <p> <code>ALLOW<em>ADMIN</em>USER<em>PASSWORD</em>AUTH</code>: Enable admin based user password authentication flow <code>ADMIN<em>USER</em>PASSWORD<em>AUTH</code>. This setting replaces the <code>ADMIN</em>NO<em>SRP</em>AUTH</code> setting. With this authentication flow, Cognito receives the password in the request instead of using the SRP (Secure Remote Password protocol) protocol to verify passwords.</p> </li> <li> <p> <code>ALLOW<em>CUSTOM</em>AUTH</code>: Enable Lambda trigger based authentication.</p> </li> <li> <p> <code>ALLOW<em>USER</em>PASSWORD<em>AUTH</code>: Enable user password-based authentication. In this flow, Cognito receives the password in the request instead of using the SRP protocol to verify passwords.</p> </li> <li> <p> <code>ALLOW</em>USER<em>SRP</em>AUTH</code>: Enable SRP based authentication.</p> </li> <li> <p> <code>ALLOW<em>REFRESH</em>TOKEN_AUTH</code>: Enable authflow to refresh tokens.</p> </li> </ul></p>
     #[serde(rename = "ExplicitAuthFlows")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub explicit_auth_flows: Option<Vec<String>>,
+    pub explicit_auth_flows: Option<Vec<ExplicitAuthFlowsType>>,
     /// <p>The date the user pool client was last modified.</p>
     #[serde(rename = "LastModifiedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_date: Option<f64>,
+    pub last_modified_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A list of allowed logout URLs for the identity providers.</p>
     #[serde(rename = "LogoutURLs")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3840,7 +6784,7 @@ pub struct UserPoolClientType {
     /// <p><p>Use this setting to choose which errors and responses are returned by Cognito APIs during authentication, account confirmation, and password recovery when the user does not exist in the user pool. When set to <code>ENABLED</code> and the user does not exist, authentication returns an error indicating either the username or password was incorrect, and account confirmation and password recovery return a response indicating a code was sent to a simulated destination. When set to <code>LEGACY</code>, those APIs will return a <code>UserNotFoundException</code> exception if the user does not exist in the user pool.</p> <p>Valid values include:</p> <ul> <li> <p> <code>ENABLED</code> - This prevents user existence-related errors.</p> </li> <li> <p> <code>LEGACY</code> - This represents the old behavior of Cognito where user existence related errors are not prevented.</p> </li> </ul> <p>This setting affects the behavior of following APIs:</p> <ul> <li> <p> <a>AdminInitiateAuth</a> </p> </li> <li> <p> <a>AdminRespondToAuthChallenge</a> </p> </li> <li> <p> <a>InitiateAuth</a> </p> </li> <li> <p> <a>RespondToAuthChallenge</a> </p> </li> <li> <p> <a>ForgotPassword</a> </p> </li> <li> <p> <a>ConfirmForgotPassword</a> </p> </li> <li> <p> <a>ConfirmSignUp</a> </p> </li> <li> <p> <a>ResendConfirmationCode</a> </p> </li> </ul> <note> <p>After January 1st 2020, the value of <code>PreventUserExistenceErrors</code> will default to <code>ENABLED</code> for newly created user pool clients if no value is provided.</p> </note></p>
     #[serde(rename = "PreventUserExistenceErrors")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub prevent_user_existence_errors: Option<String>,
+    pub prevent_user_existence_errors: Option<PreventUserExistenceErrorTypes>,
     /// <p>The Read-only attributes.</p>
     #[serde(rename = "ReadAttributes")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3869,8 +6813,13 @@ pub struct UserPoolClientType {
 pub struct UserPoolDescriptionType {
     /// <p>The date the user pool description was created.</p>
     #[serde(rename = "CreationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The ID in a user pool description.</p>
     #[serde(rename = "Id")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3881,8 +6830,13 @@ pub struct UserPoolDescriptionType {
     pub lambda_config: Option<LambdaConfigType>,
     /// <p>The date the user pool description was last modified.</p>
     #[serde(rename = "LastModifiedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_date: Option<f64>,
+    pub last_modified_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name in a user pool description.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3890,9 +6844,107 @@ pub struct UserPoolDescriptionType {
     /// <p>The user pool status in a user pool description.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<StatusType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum UserPoolMfaType {
+    /// `OFF`
+    Off,
+    /// `ON`
+    On,
+    /// `OPTIONAL`
+    Optional,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl UserPoolMfaType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            UserPoolMfaType::Off => "OFF",
+            UserPoolMfaType::On => "ON",
+            UserPoolMfaType::Optional => "OPTIONAL",
+            UserPoolMfaType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for UserPoolMfaType {
+    fn default() -> UserPoolMfaType {
+        UserPoolMfaType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for UserPoolMfaType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for UserPoolMfaType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for UserPoolMfaType {
+    fn from(value: &'a str) -> UserPoolMfaType {
+        match value {
+            "OFF" => UserPoolMfaType::Off,
+            "ON" => UserPoolMfaType::On,
+            "OPTIONAL" => UserPoolMfaType::Optional,
+            _ => UserPoolMfaType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for UserPoolMfaType {
+    fn from(value: String) -> UserPoolMfaType {
+        match value.as_str() {
+            "OFF" => UserPoolMfaType::Off,
+            "ON" => UserPoolMfaType::On,
+            "OPTIONAL" => UserPoolMfaType::Optional,
+            _ => UserPoolMfaType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for UserPoolMfaType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<UserPoolMfaType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for UserPoolMfaType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for UserPoolMfaType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for UserPoolMfaType {
+    fn deserialize<D>(deserializer: D) -> Result<UserPoolMfaType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The policy associated with a user pool.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct UserPoolPolicyType {
@@ -3917,7 +6969,7 @@ pub struct UserPoolType {
     /// <p>Specifies the attributes that are aliased in a user pool.</p>
     #[serde(rename = "AliasAttributes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub alias_attributes: Option<Vec<String>>,
+    pub alias_attributes: Option<Vec<AliasAttributeType>>,
     /// <p>The Amazon Resource Name (ARN) for the user pool.</p>
     #[serde(rename = "Arn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3925,11 +6977,16 @@ pub struct UserPoolType {
     /// <p>Specifies the attributes that are auto-verified in a user pool.</p>
     #[serde(rename = "AutoVerifiedAttributes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub auto_verified_attributes: Option<Vec<String>>,
+    pub auto_verified_attributes: Option<Vec<VerifiedAttributeType>>,
     /// <p>The date the user pool was created.</p>
     #[serde(rename = "CreationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A custom domain name that you provide to Amazon Cognito. This parameter applies only if you use a custom domain to host the sign-up and sign-in pages for your application. For example: <code>auth.example.com</code>.</p> <p>For more information about adding a custom domain to your user pool, see <a href="https://docs.aws.amazon.com/cognito/latest/developerguide/cognito-user-pools-add-custom-domain.html">Using Your Own Domain for the Hosted UI</a>.</p>
     #[serde(rename = "CustomDomain")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3972,12 +7029,17 @@ pub struct UserPoolType {
     pub lambda_config: Option<LambdaConfigType>,
     /// <p>The date the user pool was last modified.</p>
     #[serde(rename = "LastModifiedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_date: Option<f64>,
+    pub last_modified_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>Can be one of the following values:</p> <ul> <li> <p> <code>OFF</code> - MFA tokens are not required and cannot be specified during user registration.</p> </li> <li> <p> <code>ON</code> - MFA tokens are required for all user registrations. You can only specify required when you are initially creating a user pool.</p> </li> <li> <p> <code>OPTIONAL</code> - Users have the option when registering to create an MFA token.</p> </li> </ul></p>
     #[serde(rename = "MfaConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub mfa_configuration: Option<String>,
+    pub mfa_configuration: Option<UserPoolMfaType>,
     /// <p>The name of the user pool.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4009,7 +7071,7 @@ pub struct UserPoolType {
     /// <p>The status of a user pool.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<StatusType>,
     /// <p>The user pool add-ons.</p>
     #[serde(rename = "UserPoolAddOns")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4021,13 +7083,131 @@ pub struct UserPoolType {
     /// <p>Specifies whether email addresses or phone numbers can be specified as usernames when a user signs up.</p>
     #[serde(rename = "UsernameAttributes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub username_attributes: Option<Vec<String>>,
+    pub username_attributes: Option<Vec<UsernameAttributeType>>,
     /// <p>The template for verification messages.</p>
     #[serde(rename = "VerificationMessageTemplate")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub verification_message_template: Option<VerificationMessageTemplateType>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum UserStatusType {
+    /// `UNCONFIRMED`
+    Unconfirmed,
+    /// `CONFIRMED`
+    Confirmed,
+    /// `ARCHIVED`
+    Archived,
+    /// `COMPROMISED`
+    Compromised,
+    /// `UNKNOWN`
+    UnknownValue,
+    /// `RESET_REQUIRED`
+    ResetRequired,
+    /// `FORCE_CHANGE_PASSWORD`
+    ForceChangePassword,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl UserStatusType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            UserStatusType::Unconfirmed => "UNCONFIRMED",
+            UserStatusType::Confirmed => "CONFIRMED",
+            UserStatusType::Archived => "ARCHIVED",
+            UserStatusType::Compromised => "COMPROMISED",
+            UserStatusType::UnknownValue => "UNKNOWN",
+            UserStatusType::ResetRequired => "RESET_REQUIRED",
+            UserStatusType::ForceChangePassword => "FORCE_CHANGE_PASSWORD",
+            UserStatusType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for UserStatusType {
+    fn default() -> UserStatusType {
+        UserStatusType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for UserStatusType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for UserStatusType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for UserStatusType {
+    fn from(value: &'a str) -> UserStatusType {
+        match value {
+            "UNCONFIRMED" => UserStatusType::Unconfirmed,
+            "CONFIRMED" => UserStatusType::Confirmed,
+            "ARCHIVED" => UserStatusType::Archived,
+            "COMPROMISED" => UserStatusType::Compromised,
+            "UNKNOWN" => UserStatusType::UnknownValue,
+            "RESET_REQUIRED" => UserStatusType::ResetRequired,
+            "FORCE_CHANGE_PASSWORD" => UserStatusType::ForceChangePassword,
+            _ => UserStatusType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for UserStatusType {
+    fn from(value: String) -> UserStatusType {
+        match value.as_str() {
+            "UNCONFIRMED" => UserStatusType::Unconfirmed,
+            "CONFIRMED" => UserStatusType::Confirmed,
+            "ARCHIVED" => UserStatusType::Archived,
+            "COMPROMISED" => UserStatusType::Compromised,
+            "UNKNOWN" => UserStatusType::UnknownValue,
+            "RESET_REQUIRED" => UserStatusType::ResetRequired,
+            "FORCE_CHANGE_PASSWORD" => UserStatusType::ForceChangePassword,
+            _ => UserStatusType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for UserStatusType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<UserStatusType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for UserStatusType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for UserStatusType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for UserStatusType {
+    fn deserialize<D>(deserializer: D) -> Result<UserStatusType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The user type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -4046,29 +7226,132 @@ pub struct UserType {
     pub mfa_options: Option<Vec<MFAOptionType>>,
     /// <p>The creation date of the user.</p>
     #[serde(rename = "UserCreateDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub user_create_date: Option<f64>,
+    pub user_create_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The last modified date of the user.</p>
     #[serde(rename = "UserLastModifiedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub user_last_modified_date: Option<f64>,
+    pub user_last_modified_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>The user status. Can be one of the following:</p> <ul> <li> <p>UNCONFIRMED - User has been created but not confirmed.</p> </li> <li> <p>CONFIRMED - User has been confirmed.</p> </li> <li> <p>ARCHIVED - User is no longer active.</p> </li> <li> <p>COMPROMISED - User is disabled due to a potential security threat.</p> </li> <li> <p>UNKNOWN - User status is not known.</p> </li> <li> <p>RESET<em>REQUIRED - User is confirmed, but the user must request a code and reset his or her password before he or she can sign in.</p> </li> <li> <p>FORCE</em>CHANGE_PASSWORD - The user is confirmed and the user can sign in using a temporary password, but on first sign-in, the user must change his or her password to a new value before doing anything else. </p> </li> </ul></p>
     #[serde(rename = "UserStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub user_status: Option<String>,
+    pub user_status: Option<UserStatusType>,
     /// <p>The user name of the user you wish to describe.</p>
     #[serde(rename = "Username")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub username: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum UsernameAttributeType {
+    /// `phone_number`
+    PhoneNumber,
+    /// `email`
+    Email,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl UsernameAttributeType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            UsernameAttributeType::PhoneNumber => "phone_number",
+            UsernameAttributeType::Email => "email",
+            UsernameAttributeType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for UsernameAttributeType {
+    fn default() -> UsernameAttributeType {
+        UsernameAttributeType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for UsernameAttributeType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for UsernameAttributeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for UsernameAttributeType {
+    fn from(value: &'a str) -> UsernameAttributeType {
+        match value {
+            "phone_number" => UsernameAttributeType::PhoneNumber,
+            "email" => UsernameAttributeType::Email,
+            _ => UsernameAttributeType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for UsernameAttributeType {
+    fn from(value: String) -> UsernameAttributeType {
+        match value.as_str() {
+            "phone_number" => UsernameAttributeType::PhoneNumber,
+            "email" => UsernameAttributeType::Email,
+            _ => UsernameAttributeType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for UsernameAttributeType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<UsernameAttributeType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for UsernameAttributeType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for UsernameAttributeType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for UsernameAttributeType {
+    fn deserialize<D>(deserializer: D) -> Result<UsernameAttributeType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The template for verification messages.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct VerificationMessageTemplateType {
     /// <p>The default email option.</p>
     #[serde(rename = "DefaultEmailOption")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub default_email_option: Option<String>,
+    pub default_email_option: Option<DefaultEmailOptionType>,
     /// <p>The email message template.</p>
     #[serde(rename = "EmailMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4091,6 +7374,99 @@ pub struct VerificationMessageTemplateType {
     pub sms_message: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum VerifiedAttributeType {
+    /// `phone_number`
+    PhoneNumber,
+    /// `email`
+    Email,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl VerifiedAttributeType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            VerifiedAttributeType::PhoneNumber => "phone_number",
+            VerifiedAttributeType::Email => "email",
+            VerifiedAttributeType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for VerifiedAttributeType {
+    fn default() -> VerifiedAttributeType {
+        VerifiedAttributeType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for VerifiedAttributeType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for VerifiedAttributeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for VerifiedAttributeType {
+    fn from(value: &'a str) -> VerifiedAttributeType {
+        match value {
+            "phone_number" => VerifiedAttributeType::PhoneNumber,
+            "email" => VerifiedAttributeType::Email,
+            _ => VerifiedAttributeType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for VerifiedAttributeType {
+    fn from(value: String) -> VerifiedAttributeType {
+        match value.as_str() {
+            "phone_number" => VerifiedAttributeType::PhoneNumber,
+            "email" => VerifiedAttributeType::Email,
+            _ => VerifiedAttributeType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for VerifiedAttributeType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<VerifiedAttributeType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for VerifiedAttributeType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for VerifiedAttributeType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for VerifiedAttributeType {
+    fn deserialize<D>(deserializer: D) -> Result<VerifiedAttributeType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct VerifySoftwareTokenRequest {
@@ -4121,9 +7497,102 @@ pub struct VerifySoftwareTokenResponse {
     /// <p>The status of the verify software token.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<VerifySoftwareTokenResponseType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum VerifySoftwareTokenResponseType {
+    /// `SUCCESS`
+    Success,
+    /// `ERROR`
+    Error,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl VerifySoftwareTokenResponseType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            VerifySoftwareTokenResponseType::Success => "SUCCESS",
+            VerifySoftwareTokenResponseType::Error => "ERROR",
+            VerifySoftwareTokenResponseType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for VerifySoftwareTokenResponseType {
+    fn default() -> VerifySoftwareTokenResponseType {
+        VerifySoftwareTokenResponseType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for VerifySoftwareTokenResponseType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for VerifySoftwareTokenResponseType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for VerifySoftwareTokenResponseType {
+    fn from(value: &'a str) -> VerifySoftwareTokenResponseType {
+        match value {
+            "SUCCESS" => VerifySoftwareTokenResponseType::Success,
+            "ERROR" => VerifySoftwareTokenResponseType::Error,
+            _ => VerifySoftwareTokenResponseType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for VerifySoftwareTokenResponseType {
+    fn from(value: String) -> VerifySoftwareTokenResponseType {
+        match value.as_str() {
+            "SUCCESS" => VerifySoftwareTokenResponseType::Success,
+            "ERROR" => VerifySoftwareTokenResponseType::Error,
+            _ => VerifySoftwareTokenResponseType::Unknown(value),
+        }
+    }
 }
 
+impl ::std::str::FromStr for VerifySoftwareTokenResponseType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<VerifySoftwareTokenResponseType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for VerifySoftwareTokenResponseType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for VerifySoftwareTokenResponseType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for VerifySoftwareTokenResponseType {
+    fn deserialize<D>(deserializer: D) -> Result<VerifySoftwareTokenResponseType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents the request to verify user attributes.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -12673,11 +16142,15 @@ pub struct CognitoIdentityProviderClient {
 impl CognitoIdentityProviderClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> CognitoIdentityProviderClient {
         CognitoIdentityProviderClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(
+                region,
+                "Cognito Identity Provider",
+            ),
         }
     }
 
@@ -12712,6 +16185,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AddCustomAttributesRequest,
     ) -> Result<AddCustomAttributesResponse, RusotoError<AddCustomAttributesError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AddCustomAttributes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -12743,6 +16217,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AdminAddUserToGroupRequest,
     ) -> Result<(), RusotoError<AdminAddUserToGroupError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminAddUserToGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -12773,6 +16248,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AdminConfirmSignUpRequest,
     ) -> Result<AdminConfirmSignUpResponse, RusotoError<AdminConfirmSignUpError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminConfirmSignUp");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -12804,6 +16280,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AdminCreateUserRequest,
     ) -> Result<AdminCreateUserResponse, RusotoError<AdminCreateUserError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminCreateUser");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -12834,6 +16311,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AdminDeleteUserRequest,
     ) -> Result<(), RusotoError<AdminDeleteUserError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminDeleteUser");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -12865,6 +16343,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
     ) -> Result<AdminDeleteUserAttributesResponse, RusotoError<AdminDeleteUserAttributesError>>
     {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminDeleteUserAttributes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -12897,6 +16376,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
     ) -> Result<AdminDisableProviderForUserResponse, RusotoError<AdminDisableProviderForUserError>>
     {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminDisableProviderForUser");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -12928,6 +16408,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AdminDisableUserRequest,
     ) -> Result<AdminDisableUserResponse, RusotoError<AdminDisableUserError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminDisableUser");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -12959,6 +16440,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AdminEnableUserRequest,
     ) -> Result<AdminEnableUserResponse, RusotoError<AdminEnableUserError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminEnableUser");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -12989,6 +16471,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AdminForgetDeviceRequest,
     ) -> Result<(), RusotoError<AdminForgetDeviceError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminForgetDevice");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13019,6 +16502,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AdminGetDeviceRequest,
     ) -> Result<AdminGetDeviceResponse, RusotoError<AdminGetDeviceError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminGetDevice");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13049,6 +16533,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AdminGetUserRequest,
     ) -> Result<AdminGetUserResponse, RusotoError<AdminGetUserError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminGetUser");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13079,6 +16564,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AdminInitiateAuthRequest,
     ) -> Result<AdminInitiateAuthResponse, RusotoError<AdminInitiateAuthError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminInitiateAuth");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13110,6 +16596,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AdminLinkProviderForUserRequest,
     ) -> Result<AdminLinkProviderForUserResponse, RusotoError<AdminLinkProviderForUserError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminLinkProviderForUser");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13141,6 +16628,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AdminListDevicesRequest,
     ) -> Result<AdminListDevicesResponse, RusotoError<AdminListDevicesError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminListDevices");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13172,6 +16660,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AdminListGroupsForUserRequest,
     ) -> Result<AdminListGroupsForUserResponse, RusotoError<AdminListGroupsForUserError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminListGroupsForUser");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13203,6 +16692,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AdminListUserAuthEventsRequest,
     ) -> Result<AdminListUserAuthEventsResponse, RusotoError<AdminListUserAuthEventsError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminListUserAuthEvents");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13234,6 +16724,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AdminRemoveUserFromGroupRequest,
     ) -> Result<(), RusotoError<AdminRemoveUserFromGroupError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminRemoveUserFromGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13264,6 +16755,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AdminResetUserPasswordRequest,
     ) -> Result<AdminResetUserPasswordResponse, RusotoError<AdminResetUserPasswordError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminResetUserPassword");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13296,6 +16788,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
     ) -> Result<AdminRespondToAuthChallengeResponse, RusotoError<AdminRespondToAuthChallengeError>>
     {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminRespondToAuthChallenge");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13328,6 +16821,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
     ) -> Result<AdminSetUserMFAPreferenceResponse, RusotoError<AdminSetUserMFAPreferenceError>>
     {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminSetUserMFAPreference");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13359,6 +16853,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AdminSetUserPasswordRequest,
     ) -> Result<AdminSetUserPasswordResponse, RusotoError<AdminSetUserPasswordError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminSetUserPassword");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13390,6 +16885,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AdminSetUserSettingsRequest,
     ) -> Result<AdminSetUserSettingsResponse, RusotoError<AdminSetUserSettingsError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminSetUserSettings");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13422,6 +16918,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
     ) -> Result<AdminUpdateAuthEventFeedbackResponse, RusotoError<AdminUpdateAuthEventFeedbackError>>
     {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminUpdateAuthEventFeedback");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13453,6 +16950,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AdminUpdateDeviceStatusRequest,
     ) -> Result<AdminUpdateDeviceStatusResponse, RusotoError<AdminUpdateDeviceStatusError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminUpdateDeviceStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13485,6 +16983,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
     ) -> Result<AdminUpdateUserAttributesResponse, RusotoError<AdminUpdateUserAttributesError>>
     {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminUpdateUserAttributes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13516,6 +17015,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AdminUserGlobalSignOutRequest,
     ) -> Result<AdminUserGlobalSignOutResponse, RusotoError<AdminUserGlobalSignOutError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminUserGlobalSignOut");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13547,6 +17047,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AssociateSoftwareTokenRequest,
     ) -> Result<AssociateSoftwareTokenResponse, RusotoError<AssociateSoftwareTokenError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AssociateSoftwareToken");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13578,6 +17079,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: ChangePasswordRequest,
     ) -> Result<ChangePasswordResponse, RusotoError<ChangePasswordError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("ChangePassword");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13608,6 +17110,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: ConfirmDeviceRequest,
     ) -> Result<ConfirmDeviceResponse, RusotoError<ConfirmDeviceError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("ConfirmDevice");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13638,6 +17141,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: ConfirmForgotPasswordRequest,
     ) -> Result<ConfirmForgotPasswordResponse, RusotoError<ConfirmForgotPasswordError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("ConfirmForgotPassword");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13669,6 +17173,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: ConfirmSignUpRequest,
     ) -> Result<ConfirmSignUpResponse, RusotoError<ConfirmSignUpError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("ConfirmSignUp");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13699,6 +17204,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: CreateGroupRequest,
     ) -> Result<CreateGroupResponse, RusotoError<CreateGroupError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("CreateGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13729,6 +17235,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: CreateIdentityProviderRequest,
     ) -> Result<CreateIdentityProviderResponse, RusotoError<CreateIdentityProviderError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("CreateIdentityProvider");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13760,6 +17267,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: CreateResourceServerRequest,
     ) -> Result<CreateResourceServerResponse, RusotoError<CreateResourceServerError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("CreateResourceServer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13791,6 +17299,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: CreateUserImportJobRequest,
     ) -> Result<CreateUserImportJobResponse, RusotoError<CreateUserImportJobError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("CreateUserImportJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13822,6 +17331,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: CreateUserPoolRequest,
     ) -> Result<CreateUserPoolResponse, RusotoError<CreateUserPoolError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("CreateUserPool");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13852,6 +17362,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: CreateUserPoolClientRequest,
     ) -> Result<CreateUserPoolClientResponse, RusotoError<CreateUserPoolClientError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("CreateUserPoolClient");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13883,6 +17394,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: CreateUserPoolDomainRequest,
     ) -> Result<CreateUserPoolDomainResponse, RusotoError<CreateUserPoolDomainError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("CreateUserPoolDomain");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13914,6 +17426,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: DeleteGroupRequest,
     ) -> Result<(), RusotoError<DeleteGroupError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("DeleteGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13944,6 +17457,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: DeleteIdentityProviderRequest,
     ) -> Result<(), RusotoError<DeleteIdentityProviderError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("DeleteIdentityProvider");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13974,6 +17488,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: DeleteResourceServerRequest,
     ) -> Result<(), RusotoError<DeleteResourceServerError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("DeleteResourceServer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14004,6 +17519,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: DeleteUserRequest,
     ) -> Result<(), RusotoError<DeleteUserError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("DeleteUser");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14034,6 +17550,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: DeleteUserAttributesRequest,
     ) -> Result<DeleteUserAttributesResponse, RusotoError<DeleteUserAttributesError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("DeleteUserAttributes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14065,6 +17582,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: DeleteUserPoolRequest,
     ) -> Result<(), RusotoError<DeleteUserPoolError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("DeleteUserPool");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14095,6 +17613,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: DeleteUserPoolClientRequest,
     ) -> Result<(), RusotoError<DeleteUserPoolClientError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("DeleteUserPoolClient");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14125,6 +17644,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: DeleteUserPoolDomainRequest,
     ) -> Result<DeleteUserPoolDomainResponse, RusotoError<DeleteUserPoolDomainError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("DeleteUserPoolDomain");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14156,6 +17676,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: DescribeIdentityProviderRequest,
     ) -> Result<DescribeIdentityProviderResponse, RusotoError<DescribeIdentityProviderError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("DescribeIdentityProvider");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14187,6 +17708,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: DescribeResourceServerRequest,
     ) -> Result<DescribeResourceServerResponse, RusotoError<DescribeResourceServerError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("DescribeResourceServer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14219,6 +17741,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
     ) -> Result<DescribeRiskConfigurationResponse, RusotoError<DescribeRiskConfigurationError>>
     {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("DescribeRiskConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14250,6 +17773,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: DescribeUserImportJobRequest,
     ) -> Result<DescribeUserImportJobResponse, RusotoError<DescribeUserImportJobError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("DescribeUserImportJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14281,6 +17805,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: DescribeUserPoolRequest,
     ) -> Result<DescribeUserPoolResponse, RusotoError<DescribeUserPoolError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("DescribeUserPool");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14312,6 +17837,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: DescribeUserPoolClientRequest,
     ) -> Result<DescribeUserPoolClientResponse, RusotoError<DescribeUserPoolClientError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("DescribeUserPoolClient");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14343,6 +17869,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: DescribeUserPoolDomainRequest,
     ) -> Result<DescribeUserPoolDomainResponse, RusotoError<DescribeUserPoolDomainError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("DescribeUserPoolDomain");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14374,6 +17901,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: ForgetDeviceRequest,
     ) -> Result<(), RusotoError<ForgetDeviceError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("ForgetDevice");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14404,6 +17932,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: ForgotPasswordRequest,
     ) -> Result<ForgotPasswordResponse, RusotoError<ForgotPasswordError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("ForgotPassword");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14434,6 +17963,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: GetCSVHeaderRequest,
     ) -> Result<GetCSVHeaderResponse, RusotoError<GetCSVHeaderError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("GetCSVHeader");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14464,6 +17994,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: GetDeviceRequest,
     ) -> Result<GetDeviceResponse, RusotoError<GetDeviceError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("GetDevice");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14494,6 +18025,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: GetGroupRequest,
     ) -> Result<GetGroupResponse, RusotoError<GetGroupError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("GetGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSCognitoIdentityProviderService.GetGroup");
@@ -14524,6 +18056,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         RusotoError<GetIdentityProviderByIdentifierError>,
     > {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("GetIdentityProviderByIdentifier");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14557,6 +18090,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: GetSigningCertificateRequest,
     ) -> Result<GetSigningCertificateResponse, RusotoError<GetSigningCertificateError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("GetSigningCertificate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14588,6 +18122,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: GetUICustomizationRequest,
     ) -> Result<GetUICustomizationResponse, RusotoError<GetUICustomizationError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("GetUICustomization");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14619,6 +18154,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: GetUserRequest,
     ) -> Result<GetUserResponse, RusotoError<GetUserError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("GetUser");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSCognitoIdentityProviderService.GetUser");
@@ -14649,6 +18185,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         RusotoError<GetUserAttributeVerificationCodeError>,
     > {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("GetUserAttributeVerificationCode");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14682,6 +18219,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: GetUserPoolMfaConfigRequest,
     ) -> Result<GetUserPoolMfaConfigResponse, RusotoError<GetUserPoolMfaConfigError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("GetUserPoolMfaConfig");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14713,6 +18251,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: GlobalSignOutRequest,
     ) -> Result<GlobalSignOutResponse, RusotoError<GlobalSignOutError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("GlobalSignOut");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14743,6 +18282,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: InitiateAuthRequest,
     ) -> Result<InitiateAuthResponse, RusotoError<InitiateAuthError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("InitiateAuth");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14773,6 +18313,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: ListDevicesRequest,
     ) -> Result<ListDevicesResponse, RusotoError<ListDevicesError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("ListDevices");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14803,6 +18344,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: ListGroupsRequest,
     ) -> Result<ListGroupsResponse, RusotoError<ListGroupsError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("ListGroups");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14833,6 +18375,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: ListIdentityProvidersRequest,
     ) -> Result<ListIdentityProvidersResponse, RusotoError<ListIdentityProvidersError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("ListIdentityProviders");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14864,6 +18407,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: ListResourceServersRequest,
     ) -> Result<ListResourceServersResponse, RusotoError<ListResourceServersError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("ListResourceServers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14895,6 +18439,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14926,6 +18471,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: ListUserImportJobsRequest,
     ) -> Result<ListUserImportJobsResponse, RusotoError<ListUserImportJobsError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("ListUserImportJobs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14957,6 +18503,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: ListUserPoolClientsRequest,
     ) -> Result<ListUserPoolClientsResponse, RusotoError<ListUserPoolClientsError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("ListUserPoolClients");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14988,6 +18535,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: ListUserPoolsRequest,
     ) -> Result<ListUserPoolsResponse, RusotoError<ListUserPoolsError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("ListUserPools");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15018,6 +18566,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: ListUsersRequest,
     ) -> Result<ListUsersResponse, RusotoError<ListUsersError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("ListUsers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15048,6 +18597,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: ListUsersInGroupRequest,
     ) -> Result<ListUsersInGroupResponse, RusotoError<ListUsersInGroupError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("ListUsersInGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15079,6 +18629,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: ResendConfirmationCodeRequest,
     ) -> Result<ResendConfirmationCodeResponse, RusotoError<ResendConfirmationCodeError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("ResendConfirmationCode");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15110,6 +18661,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: RespondToAuthChallengeRequest,
     ) -> Result<RespondToAuthChallengeResponse, RusotoError<RespondToAuthChallengeError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("RespondToAuthChallenge");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15141,6 +18693,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: SetRiskConfigurationRequest,
     ) -> Result<SetRiskConfigurationResponse, RusotoError<SetRiskConfigurationError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("SetRiskConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15172,6 +18725,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: SetUICustomizationRequest,
     ) -> Result<SetUICustomizationResponse, RusotoError<SetUICustomizationError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("SetUICustomization");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15203,6 +18757,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: SetUserMFAPreferenceRequest,
     ) -> Result<SetUserMFAPreferenceResponse, RusotoError<SetUserMFAPreferenceError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("SetUserMFAPreference");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15234,6 +18789,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: SetUserPoolMfaConfigRequest,
     ) -> Result<SetUserPoolMfaConfigResponse, RusotoError<SetUserPoolMfaConfigError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("SetUserPoolMfaConfig");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15265,6 +18821,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: SetUserSettingsRequest,
     ) -> Result<SetUserSettingsResponse, RusotoError<SetUserSettingsError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("SetUserSettings");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15295,6 +18852,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: SignUpRequest,
     ) -> Result<SignUpResponse, RusotoError<SignUpError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("SignUp");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSCognitoIdentityProviderService.SignUp");
@@ -15322,6 +18880,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: StartUserImportJobRequest,
     ) -> Result<StartUserImportJobResponse, RusotoError<StartUserImportJobError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("StartUserImportJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15353,6 +18912,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: StopUserImportJobRequest,
     ) -> Result<StopUserImportJobResponse, RusotoError<StopUserImportJobError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("StopUserImportJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15384,6 +18944,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15414,6 +18975,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15444,6 +19006,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: UpdateAuthEventFeedbackRequest,
     ) -> Result<UpdateAuthEventFeedbackResponse, RusotoError<UpdateAuthEventFeedbackError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("UpdateAuthEventFeedback");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15475,6 +19038,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: UpdateDeviceStatusRequest,
     ) -> Result<UpdateDeviceStatusResponse, RusotoError<UpdateDeviceStatusError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("UpdateDeviceStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15506,6 +19070,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: UpdateGroupRequest,
     ) -> Result<UpdateGroupResponse, RusotoError<UpdateGroupError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("UpdateGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15536,6 +19101,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: UpdateIdentityProviderRequest,
     ) -> Result<UpdateIdentityProviderResponse, RusotoError<UpdateIdentityProviderError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("UpdateIdentityProvider");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15567,6 +19133,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: UpdateResourceServerRequest,
     ) -> Result<UpdateResourceServerResponse, RusotoError<UpdateResourceServerError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("UpdateResourceServer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15598,6 +19165,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: UpdateUserAttributesRequest,
     ) -> Result<UpdateUserAttributesResponse, RusotoError<UpdateUserAttributesError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("UpdateUserAttributes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15629,6 +19197,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: UpdateUserPoolRequest,
     ) -> Result<UpdateUserPoolResponse, RusotoError<UpdateUserPoolError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("UpdateUserPool");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15659,6 +19228,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: UpdateUserPoolClientRequest,
     ) -> Result<UpdateUserPoolClientResponse, RusotoError<UpdateUserPoolClientError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("UpdateUserPoolClient");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15690,6 +19260,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: UpdateUserPoolDomainRequest,
     ) -> Result<UpdateUserPoolDomainResponse, RusotoError<UpdateUserPoolDomainError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("UpdateUserPoolDomain");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15721,6 +19292,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: VerifySoftwareTokenRequest,
     ) -> Result<VerifySoftwareTokenResponse, RusotoError<VerifySoftwareTokenError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("VerifySoftwareToken");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15752,6 +19324,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: VerifyUserAttributeRequest,
     ) -> Result<VerifyUserAttributeResponse, RusotoError<VerifyUserAttributeError>> {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("VerifyUserAttribute");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15777,3 +19350,297 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         }
     }
 }
+impl CognitoIdentityProviderClient {
+    /// Auto-paginating version of `admin_list_groups_for_user`, streaming every page of results.
+    pub fn admin_list_groups_for_user_pages(
+        &self,
+        input: AdminListGroupsForUserRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        AdminListGroupsForUserResponse,
+        AdminListGroupsForUserError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CognitoIdentityProviderClient, input: AdminListGroupsForUserRequest| async move {
+                client.admin_list_groups_for_user(input).await
+            },
+            |input: &AdminListGroupsForUserRequest, output: &AdminListGroupsForUserResponse| {
+                Some(AdminListGroupsForUserRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `admin_list_groups_for_user`, streaming the `Groups` of every page of results.
+    pub fn admin_list_groups_for_user_items(
+        &self,
+        input: AdminListGroupsForUserRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GroupType, AdminListGroupsForUserError> {
+        rusoto_core::pagination::paginate_items(
+            self.admin_list_groups_for_user_pages(input),
+            |output: AdminListGroupsForUserResponse| output.groups.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `admin_list_user_auth_events`, streaming every page of results.
+    pub fn admin_list_user_auth_events_pages(
+        &self,
+        input: AdminListUserAuthEventsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        AdminListUserAuthEventsResponse,
+        AdminListUserAuthEventsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CognitoIdentityProviderClient, input: AdminListUserAuthEventsRequest| async move {
+                client.admin_list_user_auth_events(input).await
+            },
+            |input: &AdminListUserAuthEventsRequest, output: &AdminListUserAuthEventsResponse| {
+                Some(AdminListUserAuthEventsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `admin_list_user_auth_events`, streaming the `AuthEvents` of every page of results.
+    pub fn admin_list_user_auth_events_items(
+        &self,
+        input: AdminListUserAuthEventsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<AuthEventType, AdminListUserAuthEventsError> {
+        rusoto_core::pagination::paginate_items(
+            self.admin_list_user_auth_events_pages(input),
+            |output: AdminListUserAuthEventsResponse| output.auth_events.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_groups`, streaming every page of results.
+    pub fn list_groups_pages(
+        &self,
+        input: ListGroupsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListGroupsResponse, ListGroupsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CognitoIdentityProviderClient, input: ListGroupsRequest| async move {
+                client.list_groups(input).await
+            },
+            |input: &ListGroupsRequest, output: &ListGroupsResponse| {
+                Some(ListGroupsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_groups`, streaming the `Groups` of every page of results.
+    pub fn list_groups_items(
+        &self,
+        input: ListGroupsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GroupType, ListGroupsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_groups_pages(input),
+            |output: ListGroupsResponse| output.groups.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_identity_providers`, streaming every page of results.
+    pub fn list_identity_providers_pages(
+        &self,
+        input: ListIdentityProvidersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListIdentityProvidersResponse,
+        ListIdentityProvidersError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CognitoIdentityProviderClient, input: ListIdentityProvidersRequest| async move {
+                client.list_identity_providers(input).await
+            },
+            |input: &ListIdentityProvidersRequest, output: &ListIdentityProvidersResponse| {
+                Some(ListIdentityProvidersRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_identity_providers`, streaming the `Providers` of every page of results.
+    pub fn list_identity_providers_items(
+        &self,
+        input: ListIdentityProvidersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ProviderDescription, ListIdentityProvidersError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_identity_providers_pages(input),
+            |output: ListIdentityProvidersResponse| output.providers,
+        )
+    }
+
+    /// Auto-paginating version of `list_resource_servers`, streaming every page of results.
+    pub fn list_resource_servers_pages(
+        &self,
+        input: ListResourceServersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListResourceServersResponse, ListResourceServersError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CognitoIdentityProviderClient, input: ListResourceServersRequest| async move {
+                client.list_resource_servers(input).await
+            },
+            |input: &ListResourceServersRequest, output: &ListResourceServersResponse| {
+                Some(ListResourceServersRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_resource_servers`, streaming the `ResourceServers` of every page of results.
+    pub fn list_resource_servers_items(
+        &self,
+        input: ListResourceServersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ResourceServerType, ListResourceServersError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_resource_servers_pages(input),
+            |output: ListResourceServersResponse| output.resource_servers,
+        )
+    }
+
+    /// Auto-paginating version of `list_user_pool_clients`, streaming every page of results.
+    pub fn list_user_pool_clients_pages(
+        &self,
+        input: ListUserPoolClientsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListUserPoolClientsResponse, ListUserPoolClientsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CognitoIdentityProviderClient, input: ListUserPoolClientsRequest| async move {
+                client.list_user_pool_clients(input).await
+            },
+            |input: &ListUserPoolClientsRequest, output: &ListUserPoolClientsResponse| {
+                Some(ListUserPoolClientsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_user_pool_clients`, streaming the `UserPoolClients` of every page of results.
+    pub fn list_user_pool_clients_items(
+        &self,
+        input: ListUserPoolClientsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<UserPoolClientDescription, ListUserPoolClientsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_user_pool_clients_pages(input),
+            |output: ListUserPoolClientsResponse| output.user_pool_clients.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_user_pools`, streaming every page of results.
+    pub fn list_user_pools_pages(
+        &self,
+        input: ListUserPoolsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListUserPoolsResponse, ListUserPoolsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CognitoIdentityProviderClient, input: ListUserPoolsRequest| async move {
+                client.list_user_pools(input).await
+            },
+            |input: &ListUserPoolsRequest, output: &ListUserPoolsResponse| {
+                Some(ListUserPoolsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_user_pools`, streaming the `UserPools` of every page of results.
+    pub fn list_user_pools_items(
+        &self,
+        input: ListUserPoolsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<UserPoolDescriptionType, ListUserPoolsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_user_pools_pages(input),
+            |output: ListUserPoolsResponse| output.user_pools.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_users`, streaming every page of results.
+    pub fn list_users_pages(
+        &self,
+        input: ListUsersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListUsersResponse, ListUsersError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CognitoIdentityProviderClient, input: ListUsersRequest| async move {
+                client.list_users(input).await
+            },
+            |input: &ListUsersRequest, output: &ListUsersResponse| {
+                Some(ListUsersRequest {
+                    pagination_token: Some(output.pagination_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_users`, streaming the `Users` of every page of results.
+    pub fn list_users_items(
+        &self,
+        input: ListUsersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<UserType, ListUsersError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_users_pages(input),
+            |output: ListUsersResponse| output.users.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_users_in_group`, streaming every page of results.
+    pub fn list_users_in_group_pages(
+        &self,
+        input: ListUsersInGroupRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListUsersInGroupResponse, ListUsersInGroupError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CognitoIdentityProviderClient, input: ListUsersInGroupRequest| async move {
+                client.list_users_in_group(input).await
+            },
+            |input: &ListUsersInGroupRequest, output: &ListUsersInGroupResponse| {
+                Some(ListUsersInGroupRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_users_in_group`, streaming the `Users` of every page of results.
+    pub fn list_users_in_group_items(
+        &self,
+        input: ListUsersInGroupRequest,
+    ) -> rusoto_core::pagination::RusotoStream<UserType, ListUsersInGroupError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_users_in_group_pages(input),
+            |output: ListUsersInGroupResponse| output.users.unwrap_or_default(),
+        )
+    }
+}
@@ -44,6 +44,109 @@ pub struct BulkPublishResponse {
     pub identity_pool_id: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BulkPublishStatus {
+    /// `NOT_STARTED`
+    NotStarted,
+    /// `IN_PROGRESS`
+    InProgress,
+    /// `FAILED`
+    Failed,
+    /// `SUCCEEDED`
+    Succeeded,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl BulkPublishStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BulkPublishStatus::NotStarted => "NOT_STARTED",
+            BulkPublishStatus::InProgress => "IN_PROGRESS",
+            BulkPublishStatus::Failed => "FAILED",
+            BulkPublishStatus::Succeeded => "SUCCEEDED",
+            BulkPublishStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for BulkPublishStatus {
+    fn default() -> BulkPublishStatus {
+        BulkPublishStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for BulkPublishStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for BulkPublishStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for BulkPublishStatus {
+    fn from(value: &'a str) -> BulkPublishStatus {
+        match value {
+            "NOT_STARTED" => BulkPublishStatus::NotStarted,
+            "IN_PROGRESS" => BulkPublishStatus::InProgress,
+            "FAILED" => BulkPublishStatus::Failed,
+            "SUCCEEDED" => BulkPublishStatus::Succeeded,
+            _ => BulkPublishStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BulkPublishStatus {
+    fn from(value: String) -> BulkPublishStatus {
+        match value.as_str() {
+            "NOT_STARTED" => BulkPublishStatus::NotStarted,
+            "IN_PROGRESS" => BulkPublishStatus::InProgress,
+            "FAILED" => BulkPublishStatus::Failed,
+            "SUCCEEDED" => BulkPublishStatus::Succeeded,
+            _ => BulkPublishStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for BulkPublishStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<BulkPublishStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for BulkPublishStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for BulkPublishStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BulkPublishStatus {
+    fn deserialize<D>(deserializer: D) -> Result<BulkPublishStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Configuration options for configure Cognito streams.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CognitoStreams {
@@ -58,7 +161,7 @@ pub struct CognitoStreams {
     /// <p>Status of the Cognito streams. Valid values are: <p>ENABLED - Streaming of updates to identity pool is enabled.</p> <p>DISABLED - Streaming of updates to identity pool is disabled. Bulk publish will also fail if StreamingStatus is DISABLED.</p></p>
     #[serde(rename = "StreamingStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub streaming_status: Option<String>,
+    pub streaming_status: Option<StreamingStatus>,
 }
 
 /// <p>A collection of data for an identity pool. An identity pool can have multiple datasets. A dataset is per identity and can be general or associated with a particular entity in an application (like a saved game). Datasets are automatically created if they don&#39;t exist. Data is synced by dataset, and a dataset can hold up to 1MB of key-value pairs.</p>
@@ -67,8 +170,13 @@ pub struct CognitoStreams {
 pub struct Dataset {
     /// <p>Date on which the dataset was created.</p>
     #[serde(rename = "CreationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Total size in bytes of the records in this dataset.</p>
     #[serde(rename = "DataStorage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -87,8 +195,13 @@ pub struct Dataset {
     pub last_modified_by: Option<String>,
     /// <p>Date when the dataset was last modified.</p>
     #[serde(rename = "LastModifiedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_date: Option<f64>,
+    pub last_modified_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Number of records in this dataset.</p>
     #[serde(rename = "NumRecords")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -201,16 +314,26 @@ pub struct GetBulkPublishDetailsRequest {
 pub struct GetBulkPublishDetailsResponse {
     /// <p>If BulkPublishStatus is SUCCEEDED, the time the last bulk publish operation completed.</p>
     #[serde(rename = "BulkPublishCompleteTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub bulk_publish_complete_time: Option<f64>,
+    pub bulk_publish_complete_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The date/time at which the last bulk publish was initiated.</p>
     #[serde(rename = "BulkPublishStartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub bulk_publish_start_time: Option<f64>,
+    pub bulk_publish_start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Status of the last bulk publish operation, valid values are: <p>NOT<em>STARTED - No bulk publish has been requested for this identity pool</p> <p>IN</em>PROGRESS - Data is being published to the configured stream</p> <p>SUCCEEDED - All data for the identity pool has been published to the configured stream</p> <p>FAILED - Some portion of the data has failed to publish, check FailureMessage for the cause.</p></p>
     #[serde(rename = "BulkPublishStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub bulk_publish_status: Option<String>,
+    pub bulk_publish_status: Option<BulkPublishStatus>,
     /// <p>If BulkPublishStatus is FAILED this field will contain the error message that caused the bulk publish to fail.</p>
     #[serde(rename = "FailureMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -281,8 +404,13 @@ pub struct IdentityPoolUsage {
     pub identity_pool_id: Option<String>,
     /// <p>Date on which the identity pool was last modified.</p>
     #[serde(rename = "LastModifiedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_date: Option<f64>,
+    pub last_modified_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Number of sync sessions for the identity pool.</p>
     #[serde(rename = "SyncSessionsCount")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -311,8 +439,13 @@ pub struct IdentityUsage {
     pub identity_pool_id: Option<String>,
     /// <p>Date on which the identity was last modified.</p>
     #[serde(rename = "LastModifiedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_date: Option<f64>,
+    pub last_modified_date: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>Request for a list of datasets for an identity.</p>
@@ -462,6 +595,202 @@ pub struct ListRecordsResponse {
     pub sync_session_token: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Operation {
+    /// `replace`
+    Replace,
+    /// `remove`
+    Remove,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Operation {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Operation::Replace => "replace",
+            Operation::Remove => "remove",
+            Operation::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Operation {
+    fn default() -> Operation {
+        Operation::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Operation {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Operation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Operation {
+    fn from(value: &'a str) -> Operation {
+        match value {
+            "replace" => Operation::Replace,
+            "remove" => Operation::Remove,
+            _ => Operation::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Operation {
+    fn from(value: String) -> Operation {
+        match value.as_str() {
+            "replace" => Operation::Replace,
+            "remove" => Operation::Remove,
+            _ => Operation::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Operation {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Operation, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Operation {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Operation {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Operation {
+    fn deserialize<D>(deserializer: D) -> Result<Operation, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Platform {
+    /// `APNS`
+    Apns,
+    /// `APNS_SANDBOX`
+    ApnsSandbox,
+    /// `GCM`
+    Gcm,
+    /// `ADM`
+    Adm,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Platform {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Platform::Apns => "APNS",
+            Platform::ApnsSandbox => "APNS_SANDBOX",
+            Platform::Gcm => "GCM",
+            Platform::Adm => "ADM",
+            Platform::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Platform {
+    fn default() -> Platform {
+        Platform::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Platform {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Platform {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Platform {
+    fn from(value: &'a str) -> Platform {
+        match value {
+            "APNS" => Platform::Apns,
+            "APNS_SANDBOX" => Platform::ApnsSandbox,
+            "GCM" => Platform::Gcm,
+            "ADM" => Platform::Adm,
+            _ => Platform::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Platform {
+    fn from(value: String) -> Platform {
+        match value.as_str() {
+            "APNS" => Platform::Apns,
+            "APNS_SANDBOX" => Platform::ApnsSandbox,
+            "GCM" => Platform::Gcm,
+            "ADM" => Platform::Adm,
+            _ => Platform::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Platform {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Platform, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Platform {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Platform {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Platform {
+    fn deserialize<D>(deserializer: D) -> Result<Platform, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Configuration options to be applied to the identity pool.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PushSync {
@@ -481,8 +810,13 @@ pub struct PushSync {
 pub struct Record {
     /// <p>The last modified date of the client device.</p>
     #[serde(rename = "DeviceLastModifiedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub device_last_modified_date: Option<f64>,
+    pub device_last_modified_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The key for the record.</p>
     #[serde(rename = "Key")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -493,8 +827,13 @@ pub struct Record {
     pub last_modified_by: Option<String>,
     /// <p>The date on which the record was last modified.</p>
     #[serde(rename = "LastModifiedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_date: Option<f64>,
+    pub last_modified_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The server sync count for this record.</p>
     #[serde(rename = "SyncCount")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -511,14 +850,19 @@ pub struct Record {
 pub struct RecordPatch {
     /// <p>The last modified date of the client device.</p>
     #[serde(rename = "DeviceLastModifiedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub device_last_modified_date: Option<f64>,
+    pub device_last_modified_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The key associated with the record patch.</p>
     #[serde(rename = "Key")]
     pub key: String,
     /// <p>An operation, either replace or remove.</p>
     #[serde(rename = "Op")]
-    pub op: String,
+    pub op: Operation,
     /// <p>Last known server sync count for this record. Set to 0 if unknown.</p>
     #[serde(rename = "SyncCount")]
     pub sync_count: i64,
@@ -540,7 +884,7 @@ pub struct RegisterDeviceRequest {
     pub identity_pool_id: String,
     /// <p>The SNS platform type (e.g. GCM, SDM, APNS, APNS_SANDBOX).</p>
     #[serde(rename = "Platform")]
-    pub platform: String,
+    pub platform: Platform,
     /// <p>The push token.</p>
     #[serde(rename = "Token")]
     pub token: String,
@@ -603,6 +947,99 @@ pub struct SetIdentityPoolConfigurationResponse {
     pub push_sync: Option<PushSync>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StreamingStatus {
+    /// `ENABLED`
+    Enabled,
+    /// `DISABLED`
+    Disabled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl StreamingStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StreamingStatus::Enabled => "ENABLED",
+            StreamingStatus::Disabled => "DISABLED",
+            StreamingStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for StreamingStatus {
+    fn default() -> StreamingStatus {
+        StreamingStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for StreamingStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for StreamingStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for StreamingStatus {
+    fn from(value: &'a str) -> StreamingStatus {
+        match value {
+            "ENABLED" => StreamingStatus::Enabled,
+            "DISABLED" => StreamingStatus::Disabled,
+            _ => StreamingStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StreamingStatus {
+    fn from(value: String) -> StreamingStatus {
+        match value.as_str() {
+            "ENABLED" => StreamingStatus::Enabled,
+            "DISABLED" => StreamingStatus::Disabled,
+            _ => StreamingStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for StreamingStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<StreamingStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StreamingStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for StreamingStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StreamingStatus {
+    fn deserialize<D>(deserializer: D) -> Result<StreamingStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A request to SubscribeToDatasetRequest.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -1834,11 +2271,12 @@ pub struct CognitoSyncClient {
 impl CognitoSyncClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> CognitoSyncClient {
         CognitoSyncClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Cognito Sync"),
         }
     }
 
@@ -1875,6 +2313,7 @@ impl CognitoSync for CognitoSyncClient {
         );
 
         let mut request = SignedRequest::new("POST", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("BulkPublish");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1907,6 +2346,7 @@ impl CognitoSync for CognitoSyncClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("DeleteDataset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1939,6 +2379,7 @@ impl CognitoSync for CognitoSyncClient {
         );
 
         let mut request = SignedRequest::new("GET", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("DescribeDataset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1970,6 +2411,7 @@ impl CognitoSync for CognitoSyncClient {
         );
 
         let mut request = SignedRequest::new("GET", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("DescribeIdentityPoolUsage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2001,6 +2443,7 @@ impl CognitoSync for CognitoSyncClient {
         );
 
         let mut request = SignedRequest::new("GET", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("DescribeIdentityUsage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2031,6 +2474,7 @@ impl CognitoSync for CognitoSyncClient {
         );
 
         let mut request = SignedRequest::new("POST", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("GetBulkPublishDetails");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2061,6 +2505,7 @@ impl CognitoSync for CognitoSyncClient {
         );
 
         let mut request = SignedRequest::new("GET", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("GetCognitoEvents");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2092,6 +2537,7 @@ impl CognitoSync for CognitoSyncClient {
         );
 
         let mut request = SignedRequest::new("GET", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("GetIdentityPoolConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2123,6 +2569,7 @@ impl CognitoSync for CognitoSyncClient {
         );
 
         let mut request = SignedRequest::new("GET", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("ListDatasets");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2159,6 +2606,7 @@ impl CognitoSync for CognitoSyncClient {
         let request_uri = "/identitypools";
 
         let mut request = SignedRequest::new("GET", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("ListIdentityPoolUsage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2195,6 +2643,7 @@ impl CognitoSync for CognitoSyncClient {
         let request_uri = format!("/identitypools/{identity_pool_id}/identities/{identity_id}/datasets/{dataset_name}/records", dataset_name = input.dataset_name, identity_id = input.identity_id, identity_pool_id = input.identity_pool_id);
 
         let mut request = SignedRequest::new("GET", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("ListRecords");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2241,6 +2690,7 @@ impl CognitoSync for CognitoSyncClient {
         );
 
         let mut request = SignedRequest::new("POST", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("RegisterDevice");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2274,6 +2724,7 @@ impl CognitoSync for CognitoSyncClient {
         );
 
         let mut request = SignedRequest::new("POST", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("SetCognitoEvents");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2307,6 +2758,7 @@ impl CognitoSync for CognitoSyncClient {
         );
 
         let mut request = SignedRequest::new("POST", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("SetIdentityPoolConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2337,6 +2789,7 @@ impl CognitoSync for CognitoSyncClient {
         let request_uri = format!("/identitypools/{identity_pool_id}/identities/{identity_id}/datasets/{dataset_name}/subscriptions/{device_id}", dataset_name = input.dataset_name, device_id = input.device_id, identity_id = input.identity_id, identity_pool_id = input.identity_pool_id);
 
         let mut request = SignedRequest::new("POST", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("SubscribeToDataset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2364,6 +2817,7 @@ impl CognitoSync for CognitoSyncClient {
         let request_uri = format!("/identitypools/{identity_pool_id}/identities/{identity_id}/datasets/{dataset_name}/subscriptions/{device_id}", dataset_name = input.dataset_name, device_id = input.device_id, identity_id = input.identity_id, identity_pool_id = input.identity_pool_id);
 
         let mut request = SignedRequest::new("DELETE", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("UnsubscribeFromDataset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2396,6 +2850,7 @@ impl CognitoSync for CognitoSyncClient {
         );
 
         let mut request = SignedRequest::new("POST", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("UpdateRecords");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -76,7 +76,7 @@ pub struct BatchDetectEntitiesItemResult {
 pub struct BatchDetectEntitiesRequest {
     /// <p>The language of the input documents. You can specify any of the primary languages supported by Amazon Comprehend. All documents must be in the same language.</p>
     #[serde(rename = "LanguageCode")]
-    pub language_code: String,
+    pub language_code: LanguageCode,
     /// <p>A list containing the text of the input documents. The list can contain a maximum of 25 documents. Each document must contain fewer than 5,000 bytes of UTF-8 encoded characters.</p>
     #[serde(rename = "TextList")]
     pub text_list: Vec<String>,
@@ -112,7 +112,7 @@ pub struct BatchDetectKeyPhrasesItemResult {
 pub struct BatchDetectKeyPhrasesRequest {
     /// <p>The language of the input documents. You can specify any of the primary languages supported by Amazon Comprehend. All documents must be in the same language.</p>
     #[serde(rename = "LanguageCode")]
-    pub language_code: String,
+    pub language_code: LanguageCode,
     /// <p>A list containing the text of the input documents. The list can contain a maximum of 25 documents. Each document must contain fewer that 5,000 bytes of UTF-8 encoded characters.</p>
     #[serde(rename = "TextList")]
     pub text_list: Vec<String>,
@@ -140,7 +140,7 @@ pub struct BatchDetectSentimentItemResult {
     /// <p>The sentiment detected in the document.</p>
     #[serde(rename = "Sentiment")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sentiment: Option<String>,
+    pub sentiment: Option<SentimentType>,
     /// <p>The level of confidence that Amazon Comprehend has in the accuracy of its sentiment detection.</p>
     #[serde(rename = "SentimentScore")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -152,7 +152,7 @@ pub struct BatchDetectSentimentItemResult {
 pub struct BatchDetectSentimentRequest {
     /// <p>The language of the input documents. You can specify any of the primary languages supported by Amazon Comprehend. All documents must be in the same language.</p>
     #[serde(rename = "LanguageCode")]
-    pub language_code: String,
+    pub language_code: LanguageCode,
     /// <p>A list containing the text of the input documents. The list can contain a maximum of 25 documents. Each document must contain fewer that 5,000 bytes of UTF-8 encoded characters.</p>
     #[serde(rename = "TextList")]
     pub text_list: Vec<String>,
@@ -188,7 +188,7 @@ pub struct BatchDetectSyntaxItemResult {
 pub struct BatchDetectSyntaxRequest {
     /// <p>The language of the input documents. You can specify any of the following languages supported by Amazon Comprehend: German ("de"), English ("en"), Spanish ("es"), French ("fr"), Italian ("it"), or Portuguese ("pt"). All documents must be in the same language.</p>
     #[serde(rename = "LanguageCode")]
-    pub language_code: String,
+    pub language_code: SyntaxLanguageCode,
     /// <p>A list containing the text of the input documents. The list can contain a maximum of 25 documents. Each document must contain fewer that 5,000 bytes of UTF-8 encoded characters.</p>
     #[serde(rename = "TextList")]
     pub text_list: Vec<String>,
@@ -325,11 +325,11 @@ pub struct CreateDocumentClassifierRequest {
     pub input_data_config: DocumentClassifierInputDataConfig,
     /// <p>The language of the input documents. You can specify any of the following languages supported by Amazon Comprehend: German ("de"), English ("en"), Spanish ("es"), French ("fr"), Italian ("it"), or Portuguese ("pt"). All documents must be in the same language.</p>
     #[serde(rename = "LanguageCode")]
-    pub language_code: String,
+    pub language_code: LanguageCode,
     /// <p>Indicates the mode in which the classifier will be trained. The classifier can be trained in multi-class mode, which identifies one and only one class for each document, or multi-label mode, which identifies one or more labels for each document. In multi-label mode, multiple labels for an individual document are separated by a delimiter. The default delimiter between labels is a pipe (|).</p>
     #[serde(rename = "Mode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub mode: Option<String>,
+    pub mode: Option<DocumentClassifierMode>,
     /// <p>Enables the addition of output results configuration parameters for custom classifier jobs.</p>
     #[serde(rename = "OutputDataConfig")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -403,7 +403,7 @@ pub struct CreateEntityRecognizerRequest {
     pub input_data_config: EntityRecognizerInputDataConfig,
     /// <p> The language of the input documents. All documents must be in the same language. Only English ("en") is currently supported. </p>
     #[serde(rename = "LanguageCode")]
-    pub language_code: String,
+    pub language_code: LanguageCode,
     /// <p>The name given to the newly created recognizer. Recognizer names can be a maximum of 256 characters. Alphanumeric characters, hyphens (-) and underscores (_) are allowed. The name must be unique in the account/region.</p>
     #[serde(rename = "RecognizerName")]
     pub recognizer_name: String,
@@ -641,7 +641,7 @@ pub struct DetectDominantLanguageResponse {
 pub struct DetectEntitiesRequest {
     /// <p>The language of the input documents. You can specify any of the primary languages supported by Amazon Comprehend. All documents must be in the same language.</p>
     #[serde(rename = "LanguageCode")]
-    pub language_code: String,
+    pub language_code: LanguageCode,
     /// <p>A UTF-8 text string. Each string must contain fewer that 5,000 bytes of UTF-8 encoded characters.</p>
     #[serde(rename = "Text")]
     pub text: String,
@@ -661,7 +661,7 @@ pub struct DetectEntitiesResponse {
 pub struct DetectKeyPhrasesRequest {
     /// <p>The language of the input documents. You can specify any of the primary languages supported by Amazon Comprehend. All documents must be in the same language.</p>
     #[serde(rename = "LanguageCode")]
-    pub language_code: String,
+    pub language_code: LanguageCode,
     /// <p>A UTF-8 text string. Each string must contain fewer that 5,000 bytes of UTF-8 encoded characters.</p>
     #[serde(rename = "Text")]
     pub text: String,
@@ -681,7 +681,7 @@ pub struct DetectKeyPhrasesResponse {
 pub struct DetectSentimentRequest {
     /// <p>The language of the input documents. You can specify any of the primary languages supported by Amazon Comprehend. All documents must be in the same language.</p>
     #[serde(rename = "LanguageCode")]
-    pub language_code: String,
+    pub language_code: LanguageCode,
     /// <p>A UTF-8 text string. Each string must contain fewer that 5,000 bytes of UTF-8 encoded characters.</p>
     #[serde(rename = "Text")]
     pub text: String,
@@ -693,7 +693,7 @@ pub struct DetectSentimentResponse {
     /// <p>The inferred sentiment that Amazon Comprehend has the highest level of confidence in.</p>
     #[serde(rename = "Sentiment")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sentiment: Option<String>,
+    pub sentiment: Option<SentimentType>,
     /// <p>An object that lists the sentiments, and their corresponding confidence levels.</p>
     #[serde(rename = "SentimentScore")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -705,7 +705,7 @@ pub struct DetectSentimentResponse {
 pub struct DetectSyntaxRequest {
     /// <p>The language code of the input documents. You can specify any of the following languages supported by Amazon Comprehend: German ("de"), English ("en"), Spanish ("es"), French ("fr"), Italian ("it"), or Portuguese ("pt").</p>
     #[serde(rename = "LanguageCode")]
-    pub language_code: String,
+    pub language_code: SyntaxLanguageCode,
     /// <p>A UTF-8 string. Each string must contain fewer that 5,000 bytes of UTF encoded characters.</p>
     #[serde(rename = "Text")]
     pub text: String,
@@ -745,15 +745,25 @@ pub struct DocumentClassificationJobFilter {
     /// <p>Filters the list based on job status. Returns only jobs with the specified status.</p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<JobStatus>,
     /// <p>Filters the list of jobs based on the time that the job was submitted for processing. Returns only jobs submitted before the specified time. Jobs are returned in descending order, newest to oldest.</p>
     #[serde(rename = "SubmitTimeAfter")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submit_time_after: Option<f64>,
+    pub submit_time_after: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Filters the list of jobs based on the time that the job was submitted for processing. Returns only jobs submitted after the specified time. Jobs are returned in ascending order, oldest to newest.</p>
     #[serde(rename = "SubmitTimeBefore")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submit_time_before: Option<f64>,
+    pub submit_time_before: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>Provides information about a document classification job.</p>
@@ -770,8 +780,13 @@ pub struct DocumentClassificationJobProperties {
     pub document_classifier_arn: Option<String>,
     /// <p>The time that the document classification job completed.</p>
     #[serde(rename = "EndTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The input data configuration that you supplied when you created the document classification job.</p>
     #[serde(rename = "InputDataConfig")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -787,7 +802,7 @@ pub struct DocumentClassificationJobProperties {
     /// <p>The current status of the document classification job. If the status is <code>FAILED</code>, the <code>Message</code> field shows the reason for the failure.</p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<JobStatus>,
     /// <p>A description of the status of the job.</p>
     #[serde(rename = "Message")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -798,8 +813,13 @@ pub struct DocumentClassificationJobProperties {
     pub output_data_config: Option<OutputDataConfig>,
     /// <p>The time that the document classification job was submitted for processing.</p>
     #[serde(rename = "SubmitTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submit_time: Option<f64>,
+    pub submit_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>ID for the AWS Key Management Service (KMS) key that Amazon Comprehend uses to encrypt data on the storage volume attached to the ML compute instance(s) that process the analysis job. The VolumeKmsKeyId can be either of the following formats:</p> <ul> <li> <p>KMS Key ID: <code>&quot;1234abcd-12ab-34cd-56ef-1234567890ab&quot;</code> </p> </li> <li> <p>Amazon Resource Name (ARN) of a KMS Key: <code>&quot;arn:aws:kms:us-west-2:111122223333:key/1234abcd-12ab-34cd-56ef-1234567890ab&quot;</code> </p> </li> </ul></p>
     #[serde(rename = "VolumeKmsKeyId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -817,15 +837,25 @@ pub struct DocumentClassifierFilter {
     /// <p>Filters the list of classifiers based on status. </p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ModelStatus>,
     /// <p>Filters the list of classifiers based on the time that the classifier was submitted for processing. Returns only classifiers submitted after the specified time. Classifiers are returned in descending order, newest to oldest.</p>
     #[serde(rename = "SubmitTimeAfter")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submit_time_after: Option<f64>,
+    pub submit_time_after: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Filters the list of classifiers based on the time that the classifier was submitted for processing. Returns only classifiers submitted before the specified time. Classifiers are returned in ascending order, oldest to newest.</p>
     #[serde(rename = "SubmitTimeBefore")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submit_time_before: Option<f64>,
+    pub submit_time_before: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>The input properties for training a document classifier. </p> <p>For more information on how the input file is formatted, see <a>how-document-classification-training-data</a>. </p>
@@ -840,6 +870,99 @@ pub struct DocumentClassifierInputDataConfig {
     pub s3_uri: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DocumentClassifierMode {
+    /// `MULTI_CLASS`
+    MultiClass,
+    /// `MULTI_LABEL`
+    MultiLabel,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DocumentClassifierMode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DocumentClassifierMode::MultiClass => "MULTI_CLASS",
+            DocumentClassifierMode::MultiLabel => "MULTI_LABEL",
+            DocumentClassifierMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DocumentClassifierMode {
+    fn default() -> DocumentClassifierMode {
+        DocumentClassifierMode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DocumentClassifierMode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DocumentClassifierMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DocumentClassifierMode {
+    fn from(value: &'a str) -> DocumentClassifierMode {
+        match value {
+            "MULTI_CLASS" => DocumentClassifierMode::MultiClass,
+            "MULTI_LABEL" => DocumentClassifierMode::MultiLabel,
+            _ => DocumentClassifierMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DocumentClassifierMode {
+    fn from(value: String) -> DocumentClassifierMode {
+        match value.as_str() {
+            "MULTI_CLASS" => DocumentClassifierMode::MultiClass,
+            "MULTI_LABEL" => DocumentClassifierMode::MultiLabel,
+            _ => DocumentClassifierMode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DocumentClassifierMode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DocumentClassifierMode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DocumentClassifierMode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DocumentClassifierMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DocumentClassifierMode {
+    fn deserialize<D>(deserializer: D) -> Result<DocumentClassifierMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Provides output results configuration parameters for custom classifier jobs. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DocumentClassifierOutputDataConfig {
@@ -871,8 +994,13 @@ pub struct DocumentClassifierProperties {
     pub document_classifier_arn: Option<String>,
     /// <p>The time that training the document classifier completed.</p>
     #[serde(rename = "EndTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The input data configuration that you supplied when you created the document classifier for training.</p>
     #[serde(rename = "InputDataConfig")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -880,7 +1008,7 @@ pub struct DocumentClassifierProperties {
     /// <p>The language code for the language of the documents that the classifier was trained on.</p>
     #[serde(rename = "LanguageCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub language_code: Option<String>,
+    pub language_code: Option<LanguageCode>,
     /// <p>Additional information about the status of the classifier.</p>
     #[serde(rename = "Message")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -888,7 +1016,7 @@ pub struct DocumentClassifierProperties {
     /// <p>Indicates the mode in which the specific classifier was trained. This also indicates the format of input documents and the format of the confusion matrix. Each classifier can only be trained in one mode and this cannot be changed once the classifier is trained.</p>
     #[serde(rename = "Mode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub mode: Option<String>,
+    pub mode: Option<DocumentClassifierMode>,
     /// <p> Provides output results configuration parameters for custom classifier jobs.</p>
     #[serde(rename = "OutputDataConfig")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -896,19 +1024,34 @@ pub struct DocumentClassifierProperties {
     /// <p>The status of the document classifier. If the status is <code>TRAINED</code> the classifier is ready to use. If the status is <code>FAILED</code> you can see additional information about why the classifier wasn't trained in the <code>Message</code> field.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ModelStatus>,
     /// <p>The time that the document classifier was submitted for training.</p>
     #[serde(rename = "SubmitTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submit_time: Option<f64>,
+    pub submit_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The time that training of the document classifier was completed. Indicates the time when the training completes on documentation classifiers. You are billed for the time interval between this time and the value of TrainingStartTime.</p>
     #[serde(rename = "TrainingEndTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub training_end_time: Option<f64>,
+    pub training_end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Indicates the time when the training starts on documentation classifiers. You are billed for the time interval between this time and the value of TrainingEndTime. </p>
     #[serde(rename = "TrainingStartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub training_start_time: Option<f64>,
+    pub training_start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>ID for the AWS Key Management Service (KMS) key that Amazon Comprehend uses to encrypt data on the storage volume attached to the ML compute instance(s) that process the analysis job. The VolumeKmsKeyId can be either of the following formats:</p> <ul> <li> <p>KMS Key ID: <code>&quot;1234abcd-12ab-34cd-56ef-1234567890ab&quot;</code> </p> </li> <li> <p>Amazon Resource Name (ARN) of a KMS Key: <code>&quot;arn:aws:kms:us-west-2:111122223333:key/1234abcd-12ab-34cd-56ef-1234567890ab&quot;</code> </p> </li> </ul></p>
     #[serde(rename = "VolumeKmsKeyId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -958,15 +1101,25 @@ pub struct DominantLanguageDetectionJobFilter {
     /// <p>Filters the list of jobs based on job status. Returns only jobs with the specified status.</p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<JobStatus>,
     /// <p>Filters the list of jobs based on the time that the job was submitted for processing. Returns only jobs submitted after the specified time. Jobs are returned in descending order, newest to oldest.</p>
     #[serde(rename = "SubmitTimeAfter")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submit_time_after: Option<f64>,
+    pub submit_time_after: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Filters the list of jobs based on the time that the job was submitted for processing. Returns only jobs submitted before the specified time. Jobs are returned in ascending order, oldest to newest.</p>
     #[serde(rename = "SubmitTimeBefore")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submit_time_before: Option<f64>,
+    pub submit_time_before: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>Provides information about a dominant language detection job.</p>
@@ -979,8 +1132,13 @@ pub struct DominantLanguageDetectionJobProperties {
     pub data_access_role_arn: Option<String>,
     /// <p>The time that the dominant language detection job completed.</p>
     #[serde(rename = "EndTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The input data configuration that you supplied when you created the dominant language detection job.</p>
     #[serde(rename = "InputDataConfig")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -996,7 +1154,7 @@ pub struct DominantLanguageDetectionJobProperties {
     /// <p>The current status of the dominant language detection job. If the status is <code>FAILED</code>, the <code>Message</code> field shows the reason for the failure.</p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<JobStatus>,
     /// <p>A description for the status of a job.</p>
     #[serde(rename = "Message")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1007,8 +1165,13 @@ pub struct DominantLanguageDetectionJobProperties {
     pub output_data_config: Option<OutputDataConfig>,
     /// <p>The time that the dominant language detection job was submitted for processing.</p>
     #[serde(rename = "SubmitTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submit_time: Option<f64>,
+    pub submit_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>ID for the AWS Key Management Service (KMS) key that Amazon Comprehend uses to encrypt data on the storage volume attached to the ML compute instance(s) that process the analysis job. The VolumeKmsKeyId can be either of the following formats:</p> <ul> <li> <p>KMS Key ID: <code>&quot;1234abcd-12ab-34cd-56ef-1234567890ab&quot;</code> </p> </li> <li> <p>Amazon Resource Name (ARN) of a KMS Key: <code>&quot;arn:aws:kms:us-west-2:111122223333:key/1234abcd-12ab-34cd-56ef-1234567890ab&quot;</code> </p> </li> </ul></p>
     #[serde(rename = "VolumeKmsKeyId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1025,12 +1188,22 @@ pub struct DominantLanguageDetectionJobProperties {
 pub struct EndpointFilter {
     /// <p>Specifies a date after which the returned endpoint or endpoints were created.</p>
     #[serde(rename = "CreationTimeAfter")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time_after: Option<f64>,
+    pub creation_time_after: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Specifies a date before which the returned endpoint or endpoints were created.</p>
     #[serde(rename = "CreationTimeBefore")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time_before: Option<f64>,
+    pub creation_time_before: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The Amazon Resource Number (ARN) of the model to which the endpoint is attached.</p>
     #[serde(rename = "ModelArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1038,7 +1211,7 @@ pub struct EndpointFilter {
     /// <p>Specifies the status of the endpoint being returned. Possible values are: Creating, Ready, Updating, Deleting, Failed.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<EndpointStatus>,
 }
 
 /// <p>Specifies information about the specified endpoint.</p>
@@ -1047,8 +1220,13 @@ pub struct EndpointFilter {
 pub struct EndpointProperties {
     /// <p>The creation date and time of the endpoint.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The number of inference units currently used by the model using this endpoint.</p>
     #[serde(rename = "CurrentInferenceUnits")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1063,8 +1241,13 @@ pub struct EndpointProperties {
     pub endpoint_arn: Option<String>,
     /// <p>The date and time that the endpoint was last modified.</p>
     #[serde(rename = "LastModifiedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_time: Option<f64>,
+    pub last_modified_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Specifies a reason for failure in cases of <code>Failed</code> status.</p>
     #[serde(rename = "Message")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1076,9 +1259,117 @@ pub struct EndpointProperties {
     /// <p>Specifies the status of the endpoint. Because the endpoint updates and creation are asynchronous, so customers will need to wait for the endpoint to be <code>Ready</code> status before making inference requests.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<EndpointStatus>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EndpointStatus {
+    /// `CREATING`
+    Creating,
+    /// `DELETING`
+    Deleting,
+    /// `FAILED`
+    Failed,
+    /// `IN_SERVICE`
+    InService,
+    /// `UPDATING`
+    Updating,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EndpointStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EndpointStatus::Creating => "CREATING",
+            EndpointStatus::Deleting => "DELETING",
+            EndpointStatus::Failed => "FAILED",
+            EndpointStatus::InService => "IN_SERVICE",
+            EndpointStatus::Updating => "UPDATING",
+            EndpointStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EndpointStatus {
+    fn default() -> EndpointStatus {
+        EndpointStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EndpointStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EndpointStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EndpointStatus {
+    fn from(value: &'a str) -> EndpointStatus {
+        match value {
+            "CREATING" => EndpointStatus::Creating,
+            "DELETING" => EndpointStatus::Deleting,
+            "FAILED" => EndpointStatus::Failed,
+            "IN_SERVICE" => EndpointStatus::InService,
+            "UPDATING" => EndpointStatus::Updating,
+            _ => EndpointStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EndpointStatus {
+    fn from(value: String) -> EndpointStatus {
+        match value.as_str() {
+            "CREATING" => EndpointStatus::Creating,
+            "DELETING" => EndpointStatus::Deleting,
+            "FAILED" => EndpointStatus::Failed,
+            "IN_SERVICE" => EndpointStatus::InService,
+            "UPDATING" => EndpointStatus::Updating,
+            _ => EndpointStatus::Unknown(value),
+        }
+    }
 }
 
+impl ::std::str::FromStr for EndpointStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EndpointStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EndpointStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EndpointStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EndpointStatus {
+    fn deserialize<D>(deserializer: D) -> Result<EndpointStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Provides information for filtering a list of dominant language detection jobs. For more information, see the operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -1090,15 +1381,25 @@ pub struct EntitiesDetectionJobFilter {
     /// <p>Filters the list of jobs based on job status. Returns only jobs with the specified status.</p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<JobStatus>,
     /// <p>Filters the list of jobs based on the time that the job was submitted for processing. Returns only jobs submitted after the specified time. Jobs are returned in descending order, newest to oldest.</p>
     #[serde(rename = "SubmitTimeAfter")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submit_time_after: Option<f64>,
+    pub submit_time_after: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Filters the list of jobs based on the time that the job was submitted for processing. Returns only jobs submitted before the specified time. Jobs are returned in ascending order, oldest to newest.</p>
     #[serde(rename = "SubmitTimeBefore")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submit_time_before: Option<f64>,
+    pub submit_time_before: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>Provides information about an entities detection job.</p>
@@ -1111,8 +1412,13 @@ pub struct EntitiesDetectionJobProperties {
     pub data_access_role_arn: Option<String>,
     /// <p>The time that the entities detection job completed</p>
     #[serde(rename = "EndTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The Amazon Resource Name (ARN) that identifies the entity recognizer.</p>
     #[serde(rename = "EntityRecognizerArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1132,11 +1438,11 @@ pub struct EntitiesDetectionJobProperties {
     /// <p>The current status of the entities detection job. If the status is <code>FAILED</code>, the <code>Message</code> field shows the reason for the failure.</p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<JobStatus>,
     /// <p>The language code of the input documents.</p>
     #[serde(rename = "LanguageCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub language_code: Option<String>,
+    pub language_code: Option<LanguageCode>,
     /// <p>A description of the status of a job.</p>
     #[serde(rename = "Message")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1147,8 +1453,13 @@ pub struct EntitiesDetectionJobProperties {
     pub output_data_config: Option<OutputDataConfig>,
     /// <p>The time that the entities detection job was submitted for processing.</p>
     #[serde(rename = "SubmitTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submit_time: Option<f64>,
+    pub submit_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>ID for the AWS Key Management Service (KMS) key that Amazon Comprehend uses to encrypt data on the storage volume attached to the ML compute instance(s) that process the analysis job. The VolumeKmsKeyId can be either of the following formats:</p> <ul> <li> <p>KMS Key ID: <code>&quot;1234abcd-12ab-34cd-56ef-1234567890ab&quot;</code> </p> </li> <li> <p>Amazon Resource Name (ARN) of a KMS Key: <code>&quot;arn:aws:kms:us-west-2:111122223333:key/1234abcd-12ab-34cd-56ef-1234567890ab&quot;</code> </p> </li> </ul></p>
     #[serde(rename = "VolumeKmsKeyId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1182,7 +1493,7 @@ pub struct Entity {
     /// <p>The entity's type.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<EntityType>,
 }
 
 /// <p>Describes the annotations associated with a entity recognizer.</p>
@@ -1234,15 +1545,25 @@ pub struct EntityRecognizerFilter {
     /// <p>The status of an entity recognizer.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ModelStatus>,
     /// <p>Filters the list of entities based on the time that the list was submitted for processing. Returns only jobs submitted after the specified time. Jobs are returned in ascending order, oldest to newest.</p>
     #[serde(rename = "SubmitTimeAfter")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submit_time_after: Option<f64>,
+    pub submit_time_after: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Filters the list of entities based on the time that the list was submitted for processing. Returns only jobs submitted before the specified time. Jobs are returned in descending order, newest to oldest.</p>
     #[serde(rename = "SubmitTimeBefore")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submit_time_before: Option<f64>,
+    pub submit_time_before: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>Specifies the format and location of the input data.</p>
@@ -1314,8 +1635,13 @@ pub struct EntityRecognizerProperties {
     pub data_access_role_arn: Option<String>,
     /// <p>The time that the recognizer creation completed.</p>
     #[serde(rename = "EndTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The Amazon Resource Name (ARN) that identifies the entity recognizer.</p>
     #[serde(rename = "EntityRecognizerArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1327,7 +1653,7 @@ pub struct EntityRecognizerProperties {
     /// <p> The language of the input documents. All documents must be in the same language. Only English ("en") is currently supported.</p>
     #[serde(rename = "LanguageCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub language_code: Option<String>,
+    pub language_code: Option<LanguageCode>,
     /// <p> A description of the status of the recognizer.</p>
     #[serde(rename = "Message")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1339,19 +1665,34 @@ pub struct EntityRecognizerProperties {
     /// <p>Provides the status of the entity recognizer.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ModelStatus>,
     /// <p>The time that the recognizer was submitted for processing.</p>
     #[serde(rename = "SubmitTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submit_time: Option<f64>,
+    pub submit_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The time that training of the entity recognizer was completed.</p>
     #[serde(rename = "TrainingEndTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub training_end_time: Option<f64>,
+    pub training_end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The time that training of the entity recognizer started.</p>
     #[serde(rename = "TrainingStartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub training_start_time: Option<f64>,
+    pub training_start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>ID for the AWS Key Management Service (KMS) key that Amazon Comprehend uses to encrypt data on the storage volume attached to the ML compute instance(s) that process the analysis job. The VolumeKmsKeyId can be either of the following formats:</p> <ul> <li> <p>KMS Key ID: <code>&quot;1234abcd-12ab-34cd-56ef-1234567890ab&quot;</code> </p> </li> <li> <p>Amazon Resource Name (ARN) of a KMS Key: <code>&quot;arn:aws:kms:us-west-2:111122223333:key/1234abcd-12ab-34cd-56ef-1234567890ab&quot;</code> </p> </li> </ul></p>
     #[serde(rename = "VolumeKmsKeyId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1362,6 +1703,134 @@ pub struct EntityRecognizerProperties {
     pub vpc_config: Option<VpcConfig>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EntityType {
+    /// `PERSON`
+    Person,
+    /// `LOCATION`
+    Location,
+    /// `ORGANIZATION`
+    Organization,
+    /// `COMMERCIAL_ITEM`
+    CommercialItem,
+    /// `EVENT`
+    Event,
+    /// `DATE`
+    Date,
+    /// `QUANTITY`
+    Quantity,
+    /// `TITLE`
+    Title,
+    /// `OTHER`
+    Other,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EntityType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EntityType::Person => "PERSON",
+            EntityType::Location => "LOCATION",
+            EntityType::Organization => "ORGANIZATION",
+            EntityType::CommercialItem => "COMMERCIAL_ITEM",
+            EntityType::Event => "EVENT",
+            EntityType::Date => "DATE",
+            EntityType::Quantity => "QUANTITY",
+            EntityType::Title => "TITLE",
+            EntityType::Other => "OTHER",
+            EntityType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EntityType {
+    fn default() -> EntityType {
+        EntityType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EntityType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EntityType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EntityType {
+    fn from(value: &'a str) -> EntityType {
+        match value {
+            "PERSON" => EntityType::Person,
+            "LOCATION" => EntityType::Location,
+            "ORGANIZATION" => EntityType::Organization,
+            "COMMERCIAL_ITEM" => EntityType::CommercialItem,
+            "EVENT" => EntityType::Event,
+            "DATE" => EntityType::Date,
+            "QUANTITY" => EntityType::Quantity,
+            "TITLE" => EntityType::Title,
+            "OTHER" => EntityType::Other,
+            _ => EntityType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EntityType {
+    fn from(value: String) -> EntityType {
+        match value.as_str() {
+            "PERSON" => EntityType::Person,
+            "LOCATION" => EntityType::Location,
+            "ORGANIZATION" => EntityType::Organization,
+            "COMMERCIAL_ITEM" => EntityType::CommercialItem,
+            "EVENT" => EntityType::Event,
+            "DATE" => EntityType::Date,
+            "QUANTITY" => EntityType::Quantity,
+            "TITLE" => EntityType::Title,
+            "OTHER" => EntityType::Other,
+            _ => EntityType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EntityType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EntityType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EntityType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EntityType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EntityType {
+    fn deserialize<D>(deserializer: D) -> Result<EntityType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Detailed information about the accuracy of an entity recognizer for a specific entity type. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1394,12 +1863,306 @@ pub struct InputDataConfig {
     /// <p><p>Specifies how the text in an input file should be processed:</p> <ul> <li> <p> <code>ONE<em>DOC</em>PER<em>FILE</code> - Each file is considered a separate document. Use this option when you are processing large documents, such as newspaper articles or scientific papers.</p> </li> <li> <p> <code>ONE</em>DOC<em>PER</em>LINE</code> - Each line in a file is considered a separate document. Use this option when you are processing many short documents, such as text messages.</p> </li> </ul></p>
     #[serde(rename = "InputFormat")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub input_format: Option<String>,
+    pub input_format: Option<InputFormat>,
     /// <p>The Amazon S3 URI for the input data. The URI must be in same region as the API endpoint that you are calling. The URI can point to a single input file or it can provide the prefix for a collection of data files. </p> <p>For example, if you use the URI <code>S3://bucketName/prefix</code>, if the prefix is a single file, Amazon Comprehend uses that file as input. If more than one file begins with the prefix, Amazon Comprehend uses all of them as input.</p>
     #[serde(rename = "S3Uri")]
     pub s3_uri: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum InputFormat {
+    /// `ONE_DOC_PER_FILE`
+    OneDocPerFile,
+    /// `ONE_DOC_PER_LINE`
+    OneDocPerLine,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl InputFormat {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InputFormat::OneDocPerFile => "ONE_DOC_PER_FILE",
+            InputFormat::OneDocPerLine => "ONE_DOC_PER_LINE",
+            InputFormat::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for InputFormat {
+    fn default() -> InputFormat {
+        InputFormat::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for InputFormat {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for InputFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for InputFormat {
+    fn from(value: &'a str) -> InputFormat {
+        match value {
+            "ONE_DOC_PER_FILE" => InputFormat::OneDocPerFile,
+            "ONE_DOC_PER_LINE" => InputFormat::OneDocPerLine,
+            _ => InputFormat::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InputFormat {
+    fn from(value: String) -> InputFormat {
+        match value.as_str() {
+            "ONE_DOC_PER_FILE" => InputFormat::OneDocPerFile,
+            "ONE_DOC_PER_LINE" => InputFormat::OneDocPerLine,
+            _ => InputFormat::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for InputFormat {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<InputFormat, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for InputFormat {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for InputFormat {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InputFormat {
+    fn deserialize<D>(deserializer: D) -> Result<InputFormat, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum InvalidRequestReason {
+    /// `INVALID_DOCUMENT`
+    InvalidDocument,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl InvalidRequestReason {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InvalidRequestReason::InvalidDocument => "INVALID_DOCUMENT",
+            InvalidRequestReason::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for InvalidRequestReason {
+    fn default() -> InvalidRequestReason {
+        InvalidRequestReason::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for InvalidRequestReason {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for InvalidRequestReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for InvalidRequestReason {
+    fn from(value: &'a str) -> InvalidRequestReason {
+        match value {
+            "INVALID_DOCUMENT" => InvalidRequestReason::InvalidDocument,
+            _ => InvalidRequestReason::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InvalidRequestReason {
+    fn from(value: String) -> InvalidRequestReason {
+        match value.as_str() {
+            "INVALID_DOCUMENT" => InvalidRequestReason::InvalidDocument,
+            _ => InvalidRequestReason::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for InvalidRequestReason {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<InvalidRequestReason, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for InvalidRequestReason {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for InvalidRequestReason {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InvalidRequestReason {
+    fn deserialize<D>(deserializer: D) -> Result<InvalidRequestReason, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum JobStatus {
+    /// `SUBMITTED`
+    Submitted,
+    /// `IN_PROGRESS`
+    InProgress,
+    /// `COMPLETED`
+    Completed,
+    /// `FAILED`
+    Failed,
+    /// `STOP_REQUESTED`
+    StopRequested,
+    /// `STOPPED`
+    Stopped,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl JobStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            JobStatus::Submitted => "SUBMITTED",
+            JobStatus::InProgress => "IN_PROGRESS",
+            JobStatus::Completed => "COMPLETED",
+            JobStatus::Failed => "FAILED",
+            JobStatus::StopRequested => "STOP_REQUESTED",
+            JobStatus::Stopped => "STOPPED",
+            JobStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for JobStatus {
+    fn default() -> JobStatus {
+        JobStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for JobStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for JobStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for JobStatus {
+    fn from(value: &'a str) -> JobStatus {
+        match value {
+            "SUBMITTED" => JobStatus::Submitted,
+            "IN_PROGRESS" => JobStatus::InProgress,
+            "COMPLETED" => JobStatus::Completed,
+            "FAILED" => JobStatus::Failed,
+            "STOP_REQUESTED" => JobStatus::StopRequested,
+            "STOPPED" => JobStatus::Stopped,
+            _ => JobStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for JobStatus {
+    fn from(value: String) -> JobStatus {
+        match value.as_str() {
+            "SUBMITTED" => JobStatus::Submitted,
+            "IN_PROGRESS" => JobStatus::InProgress,
+            "COMPLETED" => JobStatus::Completed,
+            "FAILED" => JobStatus::Failed,
+            "STOP_REQUESTED" => JobStatus::StopRequested,
+            "STOPPED" => JobStatus::Stopped,
+            _ => JobStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for JobStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<JobStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for JobStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for JobStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for JobStatus {
+    fn deserialize<D>(deserializer: D) -> Result<JobStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes a key noun phrase.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1433,15 +2196,25 @@ pub struct KeyPhrasesDetectionJobFilter {
     /// <p>Filters the list of jobs based on job status. Returns only jobs with the specified status.</p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<JobStatus>,
     /// <p>Filters the list of jobs based on the time that the job was submitted for processing. Returns only jobs submitted after the specified time. Jobs are returned in descending order, newest to oldest.</p>
     #[serde(rename = "SubmitTimeAfter")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submit_time_after: Option<f64>,
+    pub submit_time_after: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Filters the list of jobs based on the time that the job was submitted for processing. Returns only jobs submitted before the specified time. Jobs are returned in ascending order, oldest to newest.</p>
     #[serde(rename = "SubmitTimeBefore")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submit_time_before: Option<f64>,
+    pub submit_time_before: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>Provides information about a key phrases detection job.</p>
@@ -1454,8 +2227,13 @@ pub struct KeyPhrasesDetectionJobProperties {
     pub data_access_role_arn: Option<String>,
     /// <p>The time that the key phrases detection job completed.</p>
     #[serde(rename = "EndTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The input data configuration that you supplied when you created the key phrases detection job.</p>
     #[serde(rename = "InputDataConfig")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1471,11 +2249,11 @@ pub struct KeyPhrasesDetectionJobProperties {
     /// <p>The current status of the key phrases detection job. If the status is <code>FAILED</code>, the <code>Message</code> field shows the reason for the failure.</p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<JobStatus>,
     /// <p>The language code of the input documents.</p>
     #[serde(rename = "LanguageCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub language_code: Option<String>,
+    pub language_code: Option<LanguageCode>,
     /// <p>A description of the status of a job.</p>
     #[serde(rename = "Message")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1486,8 +2264,13 @@ pub struct KeyPhrasesDetectionJobProperties {
     pub output_data_config: Option<OutputDataConfig>,
     /// <p>The time that the key phrases detection job was submitted for processing.</p>
     #[serde(rename = "SubmitTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submit_time: Option<f64>,
+    pub submit_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>ID for the AWS Key Management Service (KMS) key that Amazon Comprehend uses to encrypt data on the storage volume attached to the ML compute instance(s) that process the analysis job. The VolumeKmsKeyId can be either of the following formats:</p> <ul> <li> <p>KMS Key ID: <code>&quot;1234abcd-12ab-34cd-56ef-1234567890ab&quot;</code> </p> </li> <li> <p>Amazon Resource Name (ARN) of a KMS Key: <code>&quot;arn:aws:kms:us-west-2:111122223333:key/1234abcd-12ab-34cd-56ef-1234567890ab&quot;</code> </p> </li> </ul></p>
     #[serde(rename = "VolumeKmsKeyId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1498,6 +2281,149 @@ pub struct KeyPhrasesDetectionJobProperties {
     pub vpc_config: Option<VpcConfig>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LanguageCode {
+    /// `en`
+    En,
+    /// `es`
+    Es,
+    /// `fr`
+    Fr,
+    /// `de`
+    De,
+    /// `it`
+    It,
+    /// `pt`
+    Pt,
+    /// `ar`
+    Ar,
+    /// `hi`
+    Hi,
+    /// `ja`
+    Ja,
+    /// `ko`
+    Ko,
+    /// `zh`
+    Zh,
+    /// `zh-TW`
+    ZhTw,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LanguageCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LanguageCode::En => "en",
+            LanguageCode::Es => "es",
+            LanguageCode::Fr => "fr",
+            LanguageCode::De => "de",
+            LanguageCode::It => "it",
+            LanguageCode::Pt => "pt",
+            LanguageCode::Ar => "ar",
+            LanguageCode::Hi => "hi",
+            LanguageCode::Ja => "ja",
+            LanguageCode::Ko => "ko",
+            LanguageCode::Zh => "zh",
+            LanguageCode::ZhTw => "zh-TW",
+            LanguageCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LanguageCode {
+    fn default() -> LanguageCode {
+        LanguageCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LanguageCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LanguageCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LanguageCode {
+    fn from(value: &'a str) -> LanguageCode {
+        match value {
+            "en" => LanguageCode::En,
+            "es" => LanguageCode::Es,
+            "fr" => LanguageCode::Fr,
+            "de" => LanguageCode::De,
+            "it" => LanguageCode::It,
+            "pt" => LanguageCode::Pt,
+            "ar" => LanguageCode::Ar,
+            "hi" => LanguageCode::Hi,
+            "ja" => LanguageCode::Ja,
+            "ko" => LanguageCode::Ko,
+            "zh" => LanguageCode::Zh,
+            "zh-TW" => LanguageCode::ZhTw,
+            _ => LanguageCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LanguageCode {
+    fn from(value: String) -> LanguageCode {
+        match value.as_str() {
+            "en" => LanguageCode::En,
+            "es" => LanguageCode::Es,
+            "fr" => LanguageCode::Fr,
+            "de" => LanguageCode::De,
+            "it" => LanguageCode::It,
+            "pt" => LanguageCode::Pt,
+            "ar" => LanguageCode::Ar,
+            "hi" => LanguageCode::Hi,
+            "ja" => LanguageCode::Ja,
+            "ko" => LanguageCode::Ko,
+            "zh" => LanguageCode::Zh,
+            "zh-TW" => LanguageCode::ZhTw,
+            _ => LanguageCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LanguageCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LanguageCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LanguageCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LanguageCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LanguageCode {
+    fn deserialize<D>(deserializer: D) -> Result<LanguageCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ListDocumentClassificationJobsRequest {
@@ -1791,6 +2717,129 @@ pub struct ListTopicsDetectionJobsResponse {
     pub topics_detection_job_properties_list: Option<Vec<TopicsDetectionJobProperties>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ModelStatus {
+    /// `SUBMITTED`
+    Submitted,
+    /// `TRAINING`
+    Training,
+    /// `DELETING`
+    Deleting,
+    /// `STOP_REQUESTED`
+    StopRequested,
+    /// `STOPPED`
+    Stopped,
+    /// `IN_ERROR`
+    InError,
+    /// `TRAINED`
+    Trained,
+    /// `TRAINED_WITH_WARNING`
+    TrainedWithWarning,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ModelStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ModelStatus::Submitted => "SUBMITTED",
+            ModelStatus::Training => "TRAINING",
+            ModelStatus::Deleting => "DELETING",
+            ModelStatus::StopRequested => "STOP_REQUESTED",
+            ModelStatus::Stopped => "STOPPED",
+            ModelStatus::InError => "IN_ERROR",
+            ModelStatus::Trained => "TRAINED",
+            ModelStatus::TrainedWithWarning => "TRAINED_WITH_WARNING",
+            ModelStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ModelStatus {
+    fn default() -> ModelStatus {
+        ModelStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ModelStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ModelStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ModelStatus {
+    fn from(value: &'a str) -> ModelStatus {
+        match value {
+            "SUBMITTED" => ModelStatus::Submitted,
+            "TRAINING" => ModelStatus::Training,
+            "DELETING" => ModelStatus::Deleting,
+            "STOP_REQUESTED" => ModelStatus::StopRequested,
+            "STOPPED" => ModelStatus::Stopped,
+            "IN_ERROR" => ModelStatus::InError,
+            "TRAINED" => ModelStatus::Trained,
+            "TRAINED_WITH_WARNING" => ModelStatus::TrainedWithWarning,
+            _ => ModelStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ModelStatus {
+    fn from(value: String) -> ModelStatus {
+        match value.as_str() {
+            "SUBMITTED" => ModelStatus::Submitted,
+            "TRAINING" => ModelStatus::Training,
+            "DELETING" => ModelStatus::Deleting,
+            "STOP_REQUESTED" => ModelStatus::StopRequested,
+            "STOPPED" => ModelStatus::Stopped,
+            "IN_ERROR" => ModelStatus::InError,
+            "TRAINED" => ModelStatus::Trained,
+            "TRAINED_WITH_WARNING" => ModelStatus::TrainedWithWarning,
+            _ => ModelStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ModelStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ModelStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ModelStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ModelStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ModelStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ModelStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p><p>Provides configuration parameters for the output of topic detection jobs.</p> <p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct OutputDataConfig {
@@ -1814,9 +2863,182 @@ pub struct PartOfSpeechTag {
     /// <p>Identifies the part of speech that the token represents.</p>
     #[serde(rename = "Tag")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub tag: Option<String>,
+    pub tag: Option<PartOfSpeechTagType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PartOfSpeechTagType {
+    /// `ADJ`
+    Adj,
+    /// `ADP`
+    Adp,
+    /// `ADV`
+    Adv,
+    /// `AUX`
+    Aux,
+    /// `CONJ`
+    Conj,
+    /// `CCONJ`
+    Cconj,
+    /// `DET`
+    Det,
+    /// `INTJ`
+    Intj,
+    /// `NOUN`
+    Noun,
+    /// `NUM`
+    Num,
+    /// `O`
+    O,
+    /// `PART`
+    Part,
+    /// `PRON`
+    Pron,
+    /// `PROPN`
+    Propn,
+    /// `PUNCT`
+    Punct,
+    /// `SCONJ`
+    Sconj,
+    /// `SYM`
+    Sym,
+    /// `VERB`
+    Verb,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PartOfSpeechTagType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PartOfSpeechTagType::Adj => "ADJ",
+            PartOfSpeechTagType::Adp => "ADP",
+            PartOfSpeechTagType::Adv => "ADV",
+            PartOfSpeechTagType::Aux => "AUX",
+            PartOfSpeechTagType::Conj => "CONJ",
+            PartOfSpeechTagType::Cconj => "CCONJ",
+            PartOfSpeechTagType::Det => "DET",
+            PartOfSpeechTagType::Intj => "INTJ",
+            PartOfSpeechTagType::Noun => "NOUN",
+            PartOfSpeechTagType::Num => "NUM",
+            PartOfSpeechTagType::O => "O",
+            PartOfSpeechTagType::Part => "PART",
+            PartOfSpeechTagType::Pron => "PRON",
+            PartOfSpeechTagType::Propn => "PROPN",
+            PartOfSpeechTagType::Punct => "PUNCT",
+            PartOfSpeechTagType::Sconj => "SCONJ",
+            PartOfSpeechTagType::Sym => "SYM",
+            PartOfSpeechTagType::Verb => "VERB",
+            PartOfSpeechTagType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PartOfSpeechTagType {
+    fn default() -> PartOfSpeechTagType {
+        PartOfSpeechTagType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PartOfSpeechTagType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PartOfSpeechTagType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PartOfSpeechTagType {
+    fn from(value: &'a str) -> PartOfSpeechTagType {
+        match value {
+            "ADJ" => PartOfSpeechTagType::Adj,
+            "ADP" => PartOfSpeechTagType::Adp,
+            "ADV" => PartOfSpeechTagType::Adv,
+            "AUX" => PartOfSpeechTagType::Aux,
+            "CONJ" => PartOfSpeechTagType::Conj,
+            "CCONJ" => PartOfSpeechTagType::Cconj,
+            "DET" => PartOfSpeechTagType::Det,
+            "INTJ" => PartOfSpeechTagType::Intj,
+            "NOUN" => PartOfSpeechTagType::Noun,
+            "NUM" => PartOfSpeechTagType::Num,
+            "O" => PartOfSpeechTagType::O,
+            "PART" => PartOfSpeechTagType::Part,
+            "PRON" => PartOfSpeechTagType::Pron,
+            "PROPN" => PartOfSpeechTagType::Propn,
+            "PUNCT" => PartOfSpeechTagType::Punct,
+            "SCONJ" => PartOfSpeechTagType::Sconj,
+            "SYM" => PartOfSpeechTagType::Sym,
+            "VERB" => PartOfSpeechTagType::Verb,
+            _ => PartOfSpeechTagType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PartOfSpeechTagType {
+    fn from(value: String) -> PartOfSpeechTagType {
+        match value.as_str() {
+            "ADJ" => PartOfSpeechTagType::Adj,
+            "ADP" => PartOfSpeechTagType::Adp,
+            "ADV" => PartOfSpeechTagType::Adv,
+            "AUX" => PartOfSpeechTagType::Aux,
+            "CONJ" => PartOfSpeechTagType::Conj,
+            "CCONJ" => PartOfSpeechTagType::Cconj,
+            "DET" => PartOfSpeechTagType::Det,
+            "INTJ" => PartOfSpeechTagType::Intj,
+            "NOUN" => PartOfSpeechTagType::Noun,
+            "NUM" => PartOfSpeechTagType::Num,
+            "O" => PartOfSpeechTagType::O,
+            "PART" => PartOfSpeechTagType::Part,
+            "PRON" => PartOfSpeechTagType::Pron,
+            "PROPN" => PartOfSpeechTagType::Propn,
+            "PUNCT" => PartOfSpeechTagType::Punct,
+            "SCONJ" => PartOfSpeechTagType::Sconj,
+            "SYM" => PartOfSpeechTagType::Sym,
+            "VERB" => PartOfSpeechTagType::Verb,
+            _ => PartOfSpeechTagType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PartOfSpeechTagType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PartOfSpeechTagType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PartOfSpeechTagType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PartOfSpeechTagType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for PartOfSpeechTagType {
+    fn deserialize<D>(deserializer: D) -> Result<PartOfSpeechTagType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Provides information for filtering a list of dominant language detection jobs. For more information, see the operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -1828,15 +3050,25 @@ pub struct SentimentDetectionJobFilter {
     /// <p>Filters the list of jobs based on job status. Returns only jobs with the specified status.</p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<JobStatus>,
     /// <p>Filters the list of jobs based on the time that the job was submitted for processing. Returns only jobs submitted after the specified time. Jobs are returned in descending order, newest to oldest.</p>
     #[serde(rename = "SubmitTimeAfter")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submit_time_after: Option<f64>,
+    pub submit_time_after: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Filters the list of jobs based on the time that the job was submitted for processing. Returns only jobs submitted before the specified time. Jobs are returned in ascending order, oldest to newest.</p>
     #[serde(rename = "SubmitTimeBefore")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submit_time_before: Option<f64>,
+    pub submit_time_before: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>Provides information about a sentiment detection job.</p>
@@ -1849,8 +3081,13 @@ pub struct SentimentDetectionJobProperties {
     pub data_access_role_arn: Option<String>,
     /// <p>The time that the sentiment detection job ended.</p>
     #[serde(rename = "EndTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The input data configuration that you supplied when you created the sentiment detection job.</p>
     #[serde(rename = "InputDataConfig")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1866,11 +3103,11 @@ pub struct SentimentDetectionJobProperties {
     /// <p>The current status of the sentiment detection job. If the status is <code>FAILED</code>, the <code>Messages</code> field shows the reason for the failure.</p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<JobStatus>,
     /// <p>The language code of the input documents.</p>
     #[serde(rename = "LanguageCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub language_code: Option<String>,
+    pub language_code: Option<LanguageCode>,
     /// <p>A description of the status of a job.</p>
     #[serde(rename = "Message")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1881,8 +3118,13 @@ pub struct SentimentDetectionJobProperties {
     pub output_data_config: Option<OutputDataConfig>,
     /// <p>The time that the sentiment detection job was submitted for processing.</p>
     #[serde(rename = "SubmitTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submit_time: Option<f64>,
+    pub submit_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>ID for the AWS Key Management Service (KMS) key that Amazon Comprehend uses to encrypt data on the storage volume attached to the ML compute instance(s) that process the analysis job. The VolumeKmsKeyId can be either of the following formats:</p> <ul> <li> <p>KMS Key ID: <code>&quot;1234abcd-12ab-34cd-56ef-1234567890ab&quot;</code> </p> </li> <li> <p>Amazon Resource Name (ARN) of a KMS Key: <code>&quot;arn:aws:kms:us-west-2:111122223333:key/1234abcd-12ab-34cd-56ef-1234567890ab&quot;</code> </p> </li> </ul></p>
     #[serde(rename = "VolumeKmsKeyId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1915,6 +3157,109 @@ pub struct SentimentScore {
     pub positive: Option<f32>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SentimentType {
+    /// `POSITIVE`
+    Positive,
+    /// `NEGATIVE`
+    Negative,
+    /// `NEUTRAL`
+    Neutral,
+    /// `MIXED`
+    Mixed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SentimentType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SentimentType::Positive => "POSITIVE",
+            SentimentType::Negative => "NEGATIVE",
+            SentimentType::Neutral => "NEUTRAL",
+            SentimentType::Mixed => "MIXED",
+            SentimentType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SentimentType {
+    fn default() -> SentimentType {
+        SentimentType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SentimentType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SentimentType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SentimentType {
+    fn from(value: &'a str) -> SentimentType {
+        match value {
+            "POSITIVE" => SentimentType::Positive,
+            "NEGATIVE" => SentimentType::Negative,
+            "NEUTRAL" => SentimentType::Neutral,
+            "MIXED" => SentimentType::Mixed,
+            _ => SentimentType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SentimentType {
+    fn from(value: String) -> SentimentType {
+        match value.as_str() {
+            "POSITIVE" => SentimentType::Positive,
+            "NEGATIVE" => SentimentType::Negative,
+            "NEUTRAL" => SentimentType::Neutral,
+            "MIXED" => SentimentType::Mixed,
+            _ => SentimentType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SentimentType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SentimentType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SentimentType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SentimentType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SentimentType {
+    fn deserialize<D>(deserializer: D) -> Result<SentimentType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct StartDocumentClassificationJobRequest {
@@ -1958,7 +3303,7 @@ pub struct StartDocumentClassificationJobResponse {
     /// <p><p>The status of the job:</p> <ul> <li> <p>SUBMITTED - The job has been received and queued for processing.</p> </li> <li> <p>IN<em>PROGRESS - Amazon Comprehend is processing the job.</p> </li> <li> <p>COMPLETED - The job was successfully completed and the output is available.</p> </li> <li> <p>FAILED - The job did not complete. For details, use the operation.</p> </li> <li> <p>STOP</em>REQUESTED - Amazon Comprehend has received a stop request for the job and is processing the request.</p> </li> <li> <p>STOPPED - The job was successfully stopped without completing.</p> </li> </ul></p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<JobStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -2001,7 +3346,7 @@ pub struct StartDominantLanguageDetectionJobResponse {
     /// <p><p>The status of the job. </p> <ul> <li> <p>SUBMITTED - The job has been received and is queued for processing.</p> </li> <li> <p>IN_PROGRESS - Amazon Comprehend is processing the job.</p> </li> <li> <p>COMPLETED - The job was successfully completed and the output is available.</p> </li> <li> <p>FAILED - The job did not complete. To get details, use the operation.</p> </li> </ul></p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<JobStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -2027,7 +3372,7 @@ pub struct StartEntitiesDetectionJobRequest {
     pub job_name: Option<String>,
     /// <p>The language of the input documents. All documents must be in the same language. You can specify any of the languages supported by Amazon Comprehend. If custom entities recognition is used, this parameter is ignored and the language used for training the model is used instead.</p>
     #[serde(rename = "LanguageCode")]
-    pub language_code: String,
+    pub language_code: LanguageCode,
     /// <p>Specifies where to send the output files.</p>
     #[serde(rename = "OutputDataConfig")]
     pub output_data_config: OutputDataConfig,
@@ -2051,7 +3396,7 @@ pub struct StartEntitiesDetectionJobResponse {
     /// <p><p>The status of the job. </p> <ul> <li> <p>SUBMITTED - The job has been received and is queued for processing.</p> </li> <li> <p>IN<em>PROGRESS - Amazon Comprehend is processing the job.</p> </li> <li> <p>COMPLETED - The job was successfully completed and the output is available.</p> </li> <li> <p>FAILED - The job did not complete. To get details, use the operation.</p> </li> <li> <p>STOP</em>REQUESTED - Amazon Comprehend has received a stop request for the job and is processing the request.</p> </li> <li> <p>STOPPED - The job was successfully stopped without completing.</p> </li> </ul></p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<JobStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -2073,7 +3418,7 @@ pub struct StartKeyPhrasesDetectionJobRequest {
     pub job_name: Option<String>,
     /// <p>The language of the input documents. You can specify any of the primary languages supported by Amazon Comprehend. All documents must be in the same language.</p>
     #[serde(rename = "LanguageCode")]
-    pub language_code: String,
+    pub language_code: LanguageCode,
     /// <p>Specifies where to send the output files.</p>
     #[serde(rename = "OutputDataConfig")]
     pub output_data_config: OutputDataConfig,
@@ -2097,7 +3442,7 @@ pub struct StartKeyPhrasesDetectionJobResponse {
     /// <p><p>The status of the job. </p> <ul> <li> <p>SUBMITTED - The job has been received and is queued for processing.</p> </li> <li> <p>IN_PROGRESS - Amazon Comprehend is processing the job.</p> </li> <li> <p>COMPLETED - The job was successfully completed and the output is available.</p> </li> <li> <p>FAILED - The job did not complete. To get details, use the operation.</p> </li> </ul></p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<JobStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -2119,7 +3464,7 @@ pub struct StartSentimentDetectionJobRequest {
     pub job_name: Option<String>,
     /// <p>The language of the input documents. You can specify any of the primary languages supported by Amazon Comprehend. All documents must be in the same language.</p>
     #[serde(rename = "LanguageCode")]
-    pub language_code: String,
+    pub language_code: LanguageCode,
     /// <p>Specifies where to send the output files. </p>
     #[serde(rename = "OutputDataConfig")]
     pub output_data_config: OutputDataConfig,
@@ -2143,7 +3488,7 @@ pub struct StartSentimentDetectionJobResponse {
     /// <p><p>The status of the job. </p> <ul> <li> <p>SUBMITTED - The job has been received and is queued for processing.</p> </li> <li> <p>IN_PROGRESS - Amazon Comprehend is processing the job.</p> </li> <li> <p>COMPLETED - The job was successfully completed and the output is available.</p> </li> <li> <p>FAILED - The job did not complete. To get details, use the operation.</p> </li> </ul></p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<JobStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -2190,7 +3535,7 @@ pub struct StartTopicsDetectionJobResponse {
     /// <p><p>The status of the job: </p> <ul> <li> <p>SUBMITTED - The job has been received and is queued for processing.</p> </li> <li> <p>IN_PROGRESS - Amazon Comprehend is processing the job.</p> </li> <li> <p>COMPLETED - The job was successfully completed and the output is available.</p> </li> <li> <p>FAILED - The job did not complete. To get details, use the <code>DescribeTopicDetectionJob</code> operation.</p> </li> </ul></p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<JobStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -2211,7 +3556,7 @@ pub struct StopDominantLanguageDetectionJobResponse {
     /// <p>Either <code>STOP_REQUESTED</code> if the job is currently running, or <code>STOPPED</code> if the job was previously stopped with the <code>StopDominantLanguageDetectionJob</code> operation.</p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<JobStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -2232,7 +3577,7 @@ pub struct StopEntitiesDetectionJobResponse {
     /// <p>Either <code>STOP_REQUESTED</code> if the job is currently running, or <code>STOPPED</code> if the job was previously stopped with the <code>StopEntitiesDetectionJob</code> operation.</p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<JobStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -2253,7 +3598,7 @@ pub struct StopKeyPhrasesDetectionJobResponse {
     /// <p>Either <code>STOP_REQUESTED</code> if the job is currently running, or <code>STOPPED</code> if the job was previously stopped with the <code>StopKeyPhrasesDetectionJob</code> operation.</p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<JobStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -2274,7 +3619,7 @@ pub struct StopSentimentDetectionJobResponse {
     /// <p>Either <code>STOP_REQUESTED</code> if the job is currently running, or <code>STOPPED</code> if the job was previously stopped with the <code>StopSentimentDetectionJob</code> operation.</p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<JobStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -2301,6 +3646,119 @@ pub struct StopTrainingEntityRecognizerRequest {
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StopTrainingEntityRecognizerResponse {}
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SyntaxLanguageCode {
+    /// `en`
+    En,
+    /// `es`
+    Es,
+    /// `fr`
+    Fr,
+    /// `de`
+    De,
+    /// `it`
+    It,
+    /// `pt`
+    Pt,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SyntaxLanguageCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SyntaxLanguageCode::En => "en",
+            SyntaxLanguageCode::Es => "es",
+            SyntaxLanguageCode::Fr => "fr",
+            SyntaxLanguageCode::De => "de",
+            SyntaxLanguageCode::It => "it",
+            SyntaxLanguageCode::Pt => "pt",
+            SyntaxLanguageCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SyntaxLanguageCode {
+    fn default() -> SyntaxLanguageCode {
+        SyntaxLanguageCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SyntaxLanguageCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SyntaxLanguageCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SyntaxLanguageCode {
+    fn from(value: &'a str) -> SyntaxLanguageCode {
+        match value {
+            "en" => SyntaxLanguageCode::En,
+            "es" => SyntaxLanguageCode::Es,
+            "fr" => SyntaxLanguageCode::Fr,
+            "de" => SyntaxLanguageCode::De,
+            "it" => SyntaxLanguageCode::It,
+            "pt" => SyntaxLanguageCode::Pt,
+            _ => SyntaxLanguageCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SyntaxLanguageCode {
+    fn from(value: String) -> SyntaxLanguageCode {
+        match value.as_str() {
+            "en" => SyntaxLanguageCode::En,
+            "es" => SyntaxLanguageCode::Es,
+            "fr" => SyntaxLanguageCode::Fr,
+            "de" => SyntaxLanguageCode::De,
+            "it" => SyntaxLanguageCode::It,
+            "pt" => SyntaxLanguageCode::Pt,
+            _ => SyntaxLanguageCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SyntaxLanguageCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SyntaxLanguageCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SyntaxLanguageCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SyntaxLanguageCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SyntaxLanguageCode {
+    fn deserialize<D>(deserializer: D) -> Result<SyntaxLanguageCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents a work in the input text that was recognized and assigned a part of speech. There is one syntax token record for each word in the source text.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -2365,15 +3823,25 @@ pub struct TopicsDetectionJobFilter {
     /// <p>Filters the list of topic detection jobs based on job status. Returns only jobs with the specified status.</p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<JobStatus>,
     /// <p>Filters the list of jobs based on the time that the job was submitted for processing. Only returns jobs submitted after the specified time. Jobs are returned in ascending order, oldest to newest.</p>
     #[serde(rename = "SubmitTimeAfter")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submit_time_after: Option<f64>,
+    pub submit_time_after: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Filters the list of jobs based on the time that the job was submitted for processing. Only returns jobs submitted before the specified time. Jobs are returned in descending order, newest to oldest.</p>
     #[serde(rename = "SubmitTimeBefore")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submit_time_before: Option<f64>,
+    pub submit_time_before: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>Provides information about a topic detection job.</p>
@@ -2386,8 +3854,13 @@ pub struct TopicsDetectionJobProperties {
     pub data_access_role_arn: Option<String>,
     /// <p>The time that the topic detection job was completed.</p>
     #[serde(rename = "EndTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The input data configuration supplied when you created the topic detection job.</p>
     #[serde(rename = "InputDataConfig")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2403,7 +3876,7 @@ pub struct TopicsDetectionJobProperties {
     /// <p>The current status of the topic detection job. If the status is <code>Failed</code>, the reason for the failure is shown in the <code>Message</code> field.</p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<JobStatus>,
     /// <p>A description for the status of a job.</p>
     #[serde(rename = "Message")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2418,8 +3891,13 @@ pub struct TopicsDetectionJobProperties {
     pub output_data_config: Option<OutputDataConfig>,
     /// <p>The time that the topic detection job was submitted for processing.</p>
     #[serde(rename = "SubmitTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submit_time: Option<f64>,
+    pub submit_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>ID for the AWS Key Management Service (KMS) key that Amazon Comprehend uses to encrypt data on the storage volume attached to the ML compute instance(s) that process the analysis job. The VolumeKmsKeyId can be either of the following formats:</p> <ul> <li> <p>KMS Key ID: <code>&quot;1234abcd-12ab-34cd-56ef-1234567890ab&quot;</code> </p> </li> <li> <p>Amazon Resource Name (ARN) of a KMS Key: <code>&quot;arn:aws:kms:us-west-2:111122223333:key/1234abcd-12ab-34cd-56ef-1234567890ab&quot;</code> </p> </li> </ul></p>
     #[serde(rename = "VolumeKmsKeyId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -5864,11 +7342,12 @@ pub struct ComprehendClient {
 impl ComprehendClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> ComprehendClient {
         ComprehendClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Comprehend"),
         }
     }
 
@@ -5901,6 +7380,7 @@ impl Comprehend for ComprehendClient {
     ) -> Result<BatchDetectDominantLanguageResponse, RusotoError<BatchDetectDominantLanguageError>>
     {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("BatchDetectDominantLanguage");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5932,6 +7412,7 @@ impl Comprehend for ComprehendClient {
         input: BatchDetectEntitiesRequest,
     ) -> Result<BatchDetectEntitiesResponse, RusotoError<BatchDetectEntitiesError>> {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("BatchDetectEntities");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Comprehend_20171127.BatchDetectEntities");
@@ -5960,6 +7441,7 @@ impl Comprehend for ComprehendClient {
         input: BatchDetectKeyPhrasesRequest,
     ) -> Result<BatchDetectKeyPhrasesResponse, RusotoError<BatchDetectKeyPhrasesError>> {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("BatchDetectKeyPhrases");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Comprehend_20171127.BatchDetectKeyPhrases");
@@ -5988,6 +7470,7 @@ impl Comprehend for ComprehendClient {
         input: BatchDetectSentimentRequest,
     ) -> Result<BatchDetectSentimentResponse, RusotoError<BatchDetectSentimentError>> {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("BatchDetectSentiment");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Comprehend_20171127.BatchDetectSentiment");
@@ -6016,6 +7499,7 @@ impl Comprehend for ComprehendClient {
         input: BatchDetectSyntaxRequest,
     ) -> Result<BatchDetectSyntaxResponse, RusotoError<BatchDetectSyntaxError>> {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("BatchDetectSyntax");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Comprehend_20171127.BatchDetectSyntax");
@@ -6044,6 +7528,7 @@ impl Comprehend for ComprehendClient {
         input: ClassifyDocumentRequest,
     ) -> Result<ClassifyDocumentResponse, RusotoError<ClassifyDocumentError>> {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("ClassifyDocument");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Comprehend_20171127.ClassifyDocument");
@@ -6072,6 +7557,7 @@ impl Comprehend for ComprehendClient {
         input: CreateDocumentClassifierRequest,
     ) -> Result<CreateDocumentClassifierResponse, RusotoError<CreateDocumentClassifierError>> {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("CreateDocumentClassifier");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6103,6 +7589,7 @@ impl Comprehend for ComprehendClient {
         input: CreateEndpointRequest,
     ) -> Result<CreateEndpointResponse, RusotoError<CreateEndpointError>> {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("CreateEndpoint");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Comprehend_20171127.CreateEndpoint");
@@ -6130,6 +7617,7 @@ impl Comprehend for ComprehendClient {
         input: CreateEntityRecognizerRequest,
     ) -> Result<CreateEntityRecognizerResponse, RusotoError<CreateEntityRecognizerError>> {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("CreateEntityRecognizer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Comprehend_20171127.CreateEntityRecognizer");
@@ -6158,6 +7646,7 @@ impl Comprehend for ComprehendClient {
         input: DeleteDocumentClassifierRequest,
     ) -> Result<DeleteDocumentClassifierResponse, RusotoError<DeleteDocumentClassifierError>> {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("DeleteDocumentClassifier");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6189,6 +7678,7 @@ impl Comprehend for ComprehendClient {
         input: DeleteEndpointRequest,
     ) -> Result<DeleteEndpointResponse, RusotoError<DeleteEndpointError>> {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("DeleteEndpoint");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Comprehend_20171127.DeleteEndpoint");
@@ -6216,6 +7706,7 @@ impl Comprehend for ComprehendClient {
         input: DeleteEntityRecognizerRequest,
     ) -> Result<DeleteEntityRecognizerResponse, RusotoError<DeleteEntityRecognizerError>> {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("DeleteEntityRecognizer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Comprehend_20171127.DeleteEntityRecognizer");
@@ -6247,6 +7738,7 @@ impl Comprehend for ComprehendClient {
         RusotoError<DescribeDocumentClassificationJobError>,
     > {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("DescribeDocumentClassificationJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6281,6 +7773,7 @@ impl Comprehend for ComprehendClient {
     ) -> Result<DescribeDocumentClassifierResponse, RusotoError<DescribeDocumentClassifierError>>
     {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("DescribeDocumentClassifier");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6315,6 +7808,7 @@ impl Comprehend for ComprehendClient {
         RusotoError<DescribeDominantLanguageDetectionJobError>,
     > {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("DescribeDominantLanguageDetectionJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6348,6 +7842,7 @@ impl Comprehend for ComprehendClient {
         input: DescribeEndpointRequest,
     ) -> Result<DescribeEndpointResponse, RusotoError<DescribeEndpointError>> {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("DescribeEndpoint");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Comprehend_20171127.DescribeEndpoint");
@@ -6377,6 +7872,7 @@ impl Comprehend for ComprehendClient {
     ) -> Result<DescribeEntitiesDetectionJobResponse, RusotoError<DescribeEntitiesDetectionJobError>>
     {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("DescribeEntitiesDetectionJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6408,6 +7904,7 @@ impl Comprehend for ComprehendClient {
         input: DescribeEntityRecognizerRequest,
     ) -> Result<DescribeEntityRecognizerResponse, RusotoError<DescribeEntityRecognizerError>> {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("DescribeEntityRecognizer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6442,6 +7939,7 @@ impl Comprehend for ComprehendClient {
         RusotoError<DescribeKeyPhrasesDetectionJobError>,
     > {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("DescribeKeyPhrasesDetectionJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6476,6 +7974,7 @@ impl Comprehend for ComprehendClient {
         RusotoError<DescribeSentimentDetectionJobError>,
     > {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("DescribeSentimentDetectionJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6508,6 +8007,7 @@ impl Comprehend for ComprehendClient {
     ) -> Result<DescribeTopicsDetectionJobResponse, RusotoError<DescribeTopicsDetectionJobError>>
     {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("DescribeTopicsDetectionJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6539,6 +8039,7 @@ impl Comprehend for ComprehendClient {
         input: DetectDominantLanguageRequest,
     ) -> Result<DetectDominantLanguageResponse, RusotoError<DetectDominantLanguageError>> {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("DetectDominantLanguage");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Comprehend_20171127.DetectDominantLanguage");
@@ -6567,6 +8068,7 @@ impl Comprehend for ComprehendClient {
         input: DetectEntitiesRequest,
     ) -> Result<DetectEntitiesResponse, RusotoError<DetectEntitiesError>> {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("DetectEntities");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Comprehend_20171127.DetectEntities");
@@ -6594,6 +8096,7 @@ impl Comprehend for ComprehendClient {
         input: DetectKeyPhrasesRequest,
     ) -> Result<DetectKeyPhrasesResponse, RusotoError<DetectKeyPhrasesError>> {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("DetectKeyPhrases");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Comprehend_20171127.DetectKeyPhrases");
@@ -6622,6 +8125,7 @@ impl Comprehend for ComprehendClient {
         input: DetectSentimentRequest,
     ) -> Result<DetectSentimentResponse, RusotoError<DetectSentimentError>> {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("DetectSentiment");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Comprehend_20171127.DetectSentiment");
@@ -6649,6 +8153,7 @@ impl Comprehend for ComprehendClient {
         input: DetectSyntaxRequest,
     ) -> Result<DetectSyntaxResponse, RusotoError<DetectSyntaxError>> {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("DetectSyntax");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Comprehend_20171127.DetectSyntax");
@@ -6679,6 +8184,7 @@ impl Comprehend for ComprehendClient {
         RusotoError<ListDocumentClassificationJobsError>,
     > {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("ListDocumentClassificationJobs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6710,6 +8216,7 @@ impl Comprehend for ComprehendClient {
         input: ListDocumentClassifiersRequest,
     ) -> Result<ListDocumentClassifiersResponse, RusotoError<ListDocumentClassifiersError>> {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("ListDocumentClassifiers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6744,6 +8251,7 @@ impl Comprehend for ComprehendClient {
         RusotoError<ListDominantLanguageDetectionJobsError>,
     > {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("ListDominantLanguageDetectionJobs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6777,6 +8285,7 @@ impl Comprehend for ComprehendClient {
         input: ListEndpointsRequest,
     ) -> Result<ListEndpointsResponse, RusotoError<ListEndpointsError>> {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("ListEndpoints");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Comprehend_20171127.ListEndpoints");
@@ -6805,6 +8314,7 @@ impl Comprehend for ComprehendClient {
     ) -> Result<ListEntitiesDetectionJobsResponse, RusotoError<ListEntitiesDetectionJobsError>>
     {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("ListEntitiesDetectionJobs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6836,6 +8346,7 @@ impl Comprehend for ComprehendClient {
         input: ListEntityRecognizersRequest,
     ) -> Result<ListEntityRecognizersResponse, RusotoError<ListEntityRecognizersError>> {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("ListEntityRecognizers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Comprehend_20171127.ListEntityRecognizers");
@@ -6865,6 +8376,7 @@ impl Comprehend for ComprehendClient {
     ) -> Result<ListKeyPhrasesDetectionJobsResponse, RusotoError<ListKeyPhrasesDetectionJobsError>>
     {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("ListKeyPhrasesDetectionJobs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6897,6 +8409,7 @@ impl Comprehend for ComprehendClient {
     ) -> Result<ListSentimentDetectionJobsResponse, RusotoError<ListSentimentDetectionJobsError>>
     {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("ListSentimentDetectionJobs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6928,6 +8441,7 @@ impl Comprehend for ComprehendClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Comprehend_20171127.ListTagsForResource");
@@ -6956,6 +8470,7 @@ impl Comprehend for ComprehendClient {
         input: ListTopicsDetectionJobsRequest,
     ) -> Result<ListTopicsDetectionJobsResponse, RusotoError<ListTopicsDetectionJobsError>> {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("ListTopicsDetectionJobs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6990,6 +8505,7 @@ impl Comprehend for ComprehendClient {
         RusotoError<StartDocumentClassificationJobError>,
     > {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("StartDocumentClassificationJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7024,6 +8540,7 @@ impl Comprehend for ComprehendClient {
         RusotoError<StartDominantLanguageDetectionJobError>,
     > {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("StartDominantLanguageDetectionJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7058,6 +8575,7 @@ impl Comprehend for ComprehendClient {
     ) -> Result<StartEntitiesDetectionJobResponse, RusotoError<StartEntitiesDetectionJobError>>
     {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("StartEntitiesDetectionJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7090,6 +8608,7 @@ impl Comprehend for ComprehendClient {
     ) -> Result<StartKeyPhrasesDetectionJobResponse, RusotoError<StartKeyPhrasesDetectionJobError>>
     {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("StartKeyPhrasesDetectionJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7122,6 +8641,7 @@ impl Comprehend for ComprehendClient {
     ) -> Result<StartSentimentDetectionJobResponse, RusotoError<StartSentimentDetectionJobError>>
     {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("StartSentimentDetectionJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7153,6 +8673,7 @@ impl Comprehend for ComprehendClient {
         input: StartTopicsDetectionJobRequest,
     ) -> Result<StartTopicsDetectionJobResponse, RusotoError<StartTopicsDetectionJobError>> {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("StartTopicsDetectionJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7187,6 +8708,7 @@ impl Comprehend for ComprehendClient {
         RusotoError<StopDominantLanguageDetectionJobError>,
     > {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("StopDominantLanguageDetectionJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7220,6 +8742,7 @@ impl Comprehend for ComprehendClient {
         input: StopEntitiesDetectionJobRequest,
     ) -> Result<StopEntitiesDetectionJobResponse, RusotoError<StopEntitiesDetectionJobError>> {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("StopEntitiesDetectionJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7252,6 +8775,7 @@ impl Comprehend for ComprehendClient {
     ) -> Result<StopKeyPhrasesDetectionJobResponse, RusotoError<StopKeyPhrasesDetectionJobError>>
     {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("StopKeyPhrasesDetectionJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7284,6 +8808,7 @@ impl Comprehend for ComprehendClient {
     ) -> Result<StopSentimentDetectionJobResponse, RusotoError<StopSentimentDetectionJobError>>
     {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("StopSentimentDetectionJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7318,6 +8843,7 @@ impl Comprehend for ComprehendClient {
         RusotoError<StopTrainingDocumentClassifierError>,
     > {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("StopTrainingDocumentClassifier");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7350,6 +8876,7 @@ impl Comprehend for ComprehendClient {
     ) -> Result<StopTrainingEntityRecognizerResponse, RusotoError<StopTrainingEntityRecognizerError>>
     {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("StopTrainingEntityRecognizer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7381,6 +8908,7 @@ impl Comprehend for ComprehendClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Comprehend_20171127.TagResource");
@@ -7408,6 +8936,7 @@ impl Comprehend for ComprehendClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Comprehend_20171127.UntagResource");
@@ -7435,6 +8964,7 @@ impl Comprehend for ComprehendClient {
         input: UpdateEndpointRequest,
     ) -> Result<UpdateEndpointResponse, RusotoError<UpdateEndpointError>> {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("UpdateEndpoint");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Comprehend_20171127.UpdateEndpoint");
@@ -7456,3 +8986,364 @@ impl Comprehend for ComprehendClient {
         }
     }
 }
+impl ComprehendClient {
+    /// Auto-paginating version of `list_document_classification_jobs`, streaming every page of results.
+    pub fn list_document_classification_jobs_pages(
+        &self,
+        input: ListDocumentClassificationJobsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListDocumentClassificationJobsResponse,
+        ListDocumentClassificationJobsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ComprehendClient, input: ListDocumentClassificationJobsRequest| async move {
+                client.list_document_classification_jobs(input).await
+            },
+            |input: &ListDocumentClassificationJobsRequest,
+             output: &ListDocumentClassificationJobsResponse| {
+                Some(ListDocumentClassificationJobsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_document_classification_jobs`, streaming the `DocumentClassificationJobPropertiesList` of every page of results.
+    pub fn list_document_classification_jobs_items(
+        &self,
+        input: ListDocumentClassificationJobsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DocumentClassificationJobProperties,
+        ListDocumentClassificationJobsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_document_classification_jobs_pages(input),
+            |output: ListDocumentClassificationJobsResponse| {
+                output
+                    .document_classification_job_properties_list
+                    .unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_document_classifiers`, streaming every page of results.
+    pub fn list_document_classifiers_pages(
+        &self,
+        input: ListDocumentClassifiersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListDocumentClassifiersResponse,
+        ListDocumentClassifiersError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ComprehendClient, input: ListDocumentClassifiersRequest| async move {
+                client.list_document_classifiers(input).await
+            },
+            |input: &ListDocumentClassifiersRequest, output: &ListDocumentClassifiersResponse| {
+                Some(ListDocumentClassifiersRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_document_classifiers`, streaming the `DocumentClassifierPropertiesList` of every page of results.
+    pub fn list_document_classifiers_items(
+        &self,
+        input: ListDocumentClassifiersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DocumentClassifierProperties,
+        ListDocumentClassifiersError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_document_classifiers_pages(input),
+            |output: ListDocumentClassifiersResponse| {
+                output
+                    .document_classifier_properties_list
+                    .unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_dominant_language_detection_jobs`, streaming every page of results.
+    pub fn list_dominant_language_detection_jobs_pages(
+        &self,
+        input: ListDominantLanguageDetectionJobsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListDominantLanguageDetectionJobsResponse,
+        ListDominantLanguageDetectionJobsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ComprehendClient, input: ListDominantLanguageDetectionJobsRequest| async move {
+                client.list_dominant_language_detection_jobs(input).await
+            },
+            |input: &ListDominantLanguageDetectionJobsRequest,
+             output: &ListDominantLanguageDetectionJobsResponse| {
+                Some(ListDominantLanguageDetectionJobsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_dominant_language_detection_jobs`, streaming the `DominantLanguageDetectionJobPropertiesList` of every page of results.
+    pub fn list_dominant_language_detection_jobs_items(
+        &self,
+        input: ListDominantLanguageDetectionJobsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DominantLanguageDetectionJobProperties,
+        ListDominantLanguageDetectionJobsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_dominant_language_detection_jobs_pages(input),
+            |output: ListDominantLanguageDetectionJobsResponse| {
+                output
+                    .dominant_language_detection_job_properties_list
+                    .unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_endpoints`, streaming every page of results.
+    pub fn list_endpoints_pages(
+        &self,
+        input: ListEndpointsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListEndpointsResponse, ListEndpointsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ComprehendClient, input: ListEndpointsRequest| async move {
+                client.list_endpoints(input).await
+            },
+            |input: &ListEndpointsRequest, output: &ListEndpointsResponse| {
+                Some(ListEndpointsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_endpoints`, streaming the `EndpointPropertiesList` of every page of results.
+    pub fn list_endpoints_items(
+        &self,
+        input: ListEndpointsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<EndpointProperties, ListEndpointsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_endpoints_pages(input),
+            |output: ListEndpointsResponse| output.endpoint_properties_list.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_entities_detection_jobs`, streaming every page of results.
+    pub fn list_entities_detection_jobs_pages(
+        &self,
+        input: ListEntitiesDetectionJobsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListEntitiesDetectionJobsResponse,
+        ListEntitiesDetectionJobsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ComprehendClient, input: ListEntitiesDetectionJobsRequest| async move {
+                client.list_entities_detection_jobs(input).await
+            },
+            |input: &ListEntitiesDetectionJobsRequest,
+             output: &ListEntitiesDetectionJobsResponse| {
+                Some(ListEntitiesDetectionJobsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_entities_detection_jobs`, streaming the `EntitiesDetectionJobPropertiesList` of every page of results.
+    pub fn list_entities_detection_jobs_items(
+        &self,
+        input: ListEntitiesDetectionJobsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        EntitiesDetectionJobProperties,
+        ListEntitiesDetectionJobsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_entities_detection_jobs_pages(input),
+            |output: ListEntitiesDetectionJobsResponse| {
+                output
+                    .entities_detection_job_properties_list
+                    .unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_entity_recognizers`, streaming every page of results.
+    pub fn list_entity_recognizers_pages(
+        &self,
+        input: ListEntityRecognizersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListEntityRecognizersResponse,
+        ListEntityRecognizersError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ComprehendClient, input: ListEntityRecognizersRequest| async move {
+                client.list_entity_recognizers(input).await
+            },
+            |input: &ListEntityRecognizersRequest, output: &ListEntityRecognizersResponse| {
+                Some(ListEntityRecognizersRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_entity_recognizers`, streaming the `EntityRecognizerPropertiesList` of every page of results.
+    pub fn list_entity_recognizers_items(
+        &self,
+        input: ListEntityRecognizersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<EntityRecognizerProperties, ListEntityRecognizersError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_entity_recognizers_pages(input),
+            |output: ListEntityRecognizersResponse| {
+                output.entity_recognizer_properties_list.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_key_phrases_detection_jobs`, streaming every page of results.
+    pub fn list_key_phrases_detection_jobs_pages(
+        &self,
+        input: ListKeyPhrasesDetectionJobsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListKeyPhrasesDetectionJobsResponse,
+        ListKeyPhrasesDetectionJobsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ComprehendClient, input: ListKeyPhrasesDetectionJobsRequest| async move {
+                client.list_key_phrases_detection_jobs(input).await
+            },
+            |input: &ListKeyPhrasesDetectionJobsRequest,
+             output: &ListKeyPhrasesDetectionJobsResponse| {
+                Some(ListKeyPhrasesDetectionJobsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_key_phrases_detection_jobs`, streaming the `KeyPhrasesDetectionJobPropertiesList` of every page of results.
+    pub fn list_key_phrases_detection_jobs_items(
+        &self,
+        input: ListKeyPhrasesDetectionJobsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        KeyPhrasesDetectionJobProperties,
+        ListKeyPhrasesDetectionJobsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_key_phrases_detection_jobs_pages(input),
+            |output: ListKeyPhrasesDetectionJobsResponse| {
+                output
+                    .key_phrases_detection_job_properties_list
+                    .unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_sentiment_detection_jobs`, streaming every page of results.
+    pub fn list_sentiment_detection_jobs_pages(
+        &self,
+        input: ListSentimentDetectionJobsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListSentimentDetectionJobsResponse,
+        ListSentimentDetectionJobsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ComprehendClient, input: ListSentimentDetectionJobsRequest| async move {
+                client.list_sentiment_detection_jobs(input).await
+            },
+            |input: &ListSentimentDetectionJobsRequest,
+             output: &ListSentimentDetectionJobsResponse| {
+                Some(ListSentimentDetectionJobsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_sentiment_detection_jobs`, streaming the `SentimentDetectionJobPropertiesList` of every page of results.
+    pub fn list_sentiment_detection_jobs_items(
+        &self,
+        input: ListSentimentDetectionJobsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        SentimentDetectionJobProperties,
+        ListSentimentDetectionJobsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_sentiment_detection_jobs_pages(input),
+            |output: ListSentimentDetectionJobsResponse| {
+                output
+                    .sentiment_detection_job_properties_list
+                    .unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_topics_detection_jobs`, streaming every page of results.
+    pub fn list_topics_detection_jobs_pages(
+        &self,
+        input: ListTopicsDetectionJobsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListTopicsDetectionJobsResponse,
+        ListTopicsDetectionJobsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ComprehendClient, input: ListTopicsDetectionJobsRequest| async move {
+                client.list_topics_detection_jobs(input).await
+            },
+            |input: &ListTopicsDetectionJobsRequest, output: &ListTopicsDetectionJobsResponse| {
+                Some(ListTopicsDetectionJobsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_topics_detection_jobs`, streaming the `TopicsDetectionJobPropertiesList` of every page of results.
+    pub fn list_topics_detection_jobs_items(
+        &self,
+        input: ListTopicsDetectionJobsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        TopicsDetectionJobProperties,
+        ListTopicsDetectionJobsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_topics_detection_jobs_pages(input),
+            |output: ListTopicsDetectionJobsResponse| {
+                output
+                    .topics_detection_job_properties_list
+                    .unwrap_or_default()
+            },
+        )
+    }
+}
@@ -59,9 +59,137 @@ pub struct Attribute {
     /// <p> The type of attribute. </p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<EntitySubType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AttributeName {
+    /// `SIGN`
+    Sign,
+    /// `SYMPTOM`
+    Symptom,
+    /// `DIAGNOSIS`
+    Diagnosis,
+    /// `NEGATION`
+    Negation,
+    /// `PERTAINS_TO_FAMILY`
+    PertainsToFamily,
+    /// `HYPOTHETICAL`
+    Hypothetical,
+    /// `LOW_CONFIDENCE`
+    LowConfidence,
+    /// `PAST_HISTORY`
+    PastHistory,
+    /// `FUTURE`
+    Future,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AttributeName {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AttributeName::Sign => "SIGN",
+            AttributeName::Symptom => "SYMPTOM",
+            AttributeName::Diagnosis => "DIAGNOSIS",
+            AttributeName::Negation => "NEGATION",
+            AttributeName::PertainsToFamily => "PERTAINS_TO_FAMILY",
+            AttributeName::Hypothetical => "HYPOTHETICAL",
+            AttributeName::LowConfidence => "LOW_CONFIDENCE",
+            AttributeName::PastHistory => "PAST_HISTORY",
+            AttributeName::Future => "FUTURE",
+            AttributeName::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AttributeName {
+    fn default() -> AttributeName {
+        AttributeName::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AttributeName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AttributeName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AttributeName {
+    fn from(value: &'a str) -> AttributeName {
+        match value {
+            "SIGN" => AttributeName::Sign,
+            "SYMPTOM" => AttributeName::Symptom,
+            "DIAGNOSIS" => AttributeName::Diagnosis,
+            "NEGATION" => AttributeName::Negation,
+            "PERTAINS_TO_FAMILY" => AttributeName::PertainsToFamily,
+            "HYPOTHETICAL" => AttributeName::Hypothetical,
+            "LOW_CONFIDENCE" => AttributeName::LowConfidence,
+            "PAST_HISTORY" => AttributeName::PastHistory,
+            "FUTURE" => AttributeName::Future,
+            _ => AttributeName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AttributeName {
+    fn from(value: String) -> AttributeName {
+        match value.as_str() {
+            "SIGN" => AttributeName::Sign,
+            "SYMPTOM" => AttributeName::Symptom,
+            "DIAGNOSIS" => AttributeName::Diagnosis,
+            "NEGATION" => AttributeName::Negation,
+            "PERTAINS_TO_FAMILY" => AttributeName::PertainsToFamily,
+            "HYPOTHETICAL" => AttributeName::Hypothetical,
+            "LOW_CONFIDENCE" => AttributeName::LowConfidence,
+            "PAST_HISTORY" => AttributeName::PastHistory,
+            "FUTURE" => AttributeName::Future,
+            _ => AttributeName::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AttributeName {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AttributeName, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AttributeName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AttributeName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for AttributeName {
+    fn deserialize<D>(deserializer: D) -> Result<AttributeName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Provides information for filtering a list of detection jobs.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -73,15 +201,25 @@ pub struct ComprehendMedicalAsyncJobFilter {
     /// <p>Filters the list of jobs based on job status. Returns only jobs with the specified status.</p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<JobStatus>,
     /// <p>Filters the list of jobs based on the time that the job was submitted for processing. Returns only jobs submitted after the specified time. Jobs are returned in descending order, newest to oldest.</p>
     #[serde(rename = "SubmitTimeAfter")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submit_time_after: Option<f64>,
+    pub submit_time_after: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Filters the list of jobs based on the time that the job was submitted for processing. Returns only jobs submitted before the specified time. Jobs are returned in ascending order, oldest to newest.</p>
     #[serde(rename = "SubmitTimeBefore")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submit_time_before: Option<f64>,
+    pub submit_time_before: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>Provides information about a detection job.</p>
@@ -94,12 +232,22 @@ pub struct ComprehendMedicalAsyncJobProperties {
     pub data_access_role_arn: Option<String>,
     /// <p>The time that the detection job completed.</p>
     #[serde(rename = "EndTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The date and time that job metadata is deleted from the server. Output files in your S3 bucket will not be deleted. After the metadata is deleted, the job will no longer appear in the results of the <code>ListEntitiesDetectionV2Job</code> or the <code>ListPHIDetectionJobs</code> operation.</p>
     #[serde(rename = "ExpirationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expiration_time: Option<f64>,
+    pub expiration_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The input data configuration that you supplied when you created the detection job.</p>
     #[serde(rename = "InputDataConfig")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -115,7 +263,7 @@ pub struct ComprehendMedicalAsyncJobProperties {
     /// <p>The current status of the detection job. If the status is <code>FAILED</code>, the <code>Message</code> field shows the reason for the failure.</p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<JobStatus>,
     /// <p>The AWS Key Management Service key, if any, used to encrypt the output files. </p>
     #[serde(rename = "KMSKey")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -123,7 +271,7 @@ pub struct ComprehendMedicalAsyncJobProperties {
     /// <p>The language code of the input documents.</p>
     #[serde(rename = "LanguageCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub language_code: Option<String>,
+    pub language_code: Option<LanguageCode>,
     /// <p>The path to the file that describes the results of a batch job.</p>
     #[serde(rename = "ManifestFilePath")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -142,8 +290,13 @@ pub struct ComprehendMedicalAsyncJobProperties {
     pub output_data_config: Option<OutputDataConfig>,
     /// <p>The time that the detection job was submitted for processing.</p>
     #[serde(rename = "SubmitTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submit_time: Option<f64>,
+    pub submit_time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -272,7 +425,7 @@ pub struct Entity {
     /// <p> The category of the entity.</p>
     #[serde(rename = "Category")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub category: Option<String>,
+    pub category: Option<EntityType>,
     /// <p> The 0-based character offset in the input text that shows where the entity ends. The offset returns the UTF-8 code point in the string. </p>
     #[serde(rename = "EndOffset")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -296,9 +449,435 @@ pub struct Entity {
     /// <p> Describes the specific type of entity with category of entities.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<EntitySubType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EntitySubType {
+    /// `NAME`
+    Name,
+    /// `DX_NAME`
+    DxName,
+    /// `DOSAGE`
+    Dosage,
+    /// `ROUTE_OR_MODE`
+    RouteOrMode,
+    /// `FORM`
+    Form,
+    /// `FREQUENCY`
+    Frequency,
+    /// `DURATION`
+    Duration,
+    /// `GENERIC_NAME`
+    GenericName,
+    /// `BRAND_NAME`
+    BrandName,
+    /// `STRENGTH`
+    Strength,
+    /// `RATE`
+    Rate,
+    /// `ACUITY`
+    Acuity,
+    /// `TEST_NAME`
+    TestName,
+    /// `TEST_VALUE`
+    TestValue,
+    /// `TEST_UNITS`
+    TestUnits,
+    /// `TEST_UNIT`
+    TestUnit,
+    /// `PROCEDURE_NAME`
+    ProcedureName,
+    /// `TREATMENT_NAME`
+    TreatmentName,
+    /// `DATE`
+    Date,
+    /// `AGE`
+    Age,
+    /// `CONTACT_POINT`
+    ContactPoint,
+    /// `PHONE_OR_FAX`
+    PhoneOrFax,
+    /// `EMAIL`
+    Email,
+    /// `IDENTIFIER`
+    Identifier,
+    /// `ID`
+    Id,
+    /// `URL`
+    Url,
+    /// `ADDRESS`
+    Address,
+    /// `PROFESSION`
+    Profession,
+    /// `SYSTEM_ORGAN_SITE`
+    SystemOrganSite,
+    /// `DIRECTION`
+    Direction,
+    /// `QUALITY`
+    Quality,
+    /// `QUANTITY`
+    Quantity,
+    /// `TIME_EXPRESSION`
+    TimeExpression,
+    /// `TIME_TO_MEDICATION_NAME`
+    TimeToMedicationName,
+    /// `TIME_TO_DX_NAME`
+    TimeToDxName,
+    /// `TIME_TO_TEST_NAME`
+    TimeToTestName,
+    /// `TIME_TO_PROCEDURE_NAME`
+    TimeToProcedureName,
+    /// `TIME_TO_TREATMENT_NAME`
+    TimeToTreatmentName,
+    /// `AMOUNT`
+    Amount,
+    /// `GENDER`
+    Gender,
+    /// `RACE_ETHNICITY`
+    RaceEthnicity,
+    /// `ALLERGIES`
+    Allergies,
+    /// `TOBACCO_USE`
+    TobaccoUse,
+    /// `ALCOHOL_CONSUMPTION`
+    AlcoholConsumption,
+    /// `REC_DRUG_USE`
+    RecDrugUse,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EntitySubType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EntitySubType::Name => "NAME",
+            EntitySubType::DxName => "DX_NAME",
+            EntitySubType::Dosage => "DOSAGE",
+            EntitySubType::RouteOrMode => "ROUTE_OR_MODE",
+            EntitySubType::Form => "FORM",
+            EntitySubType::Frequency => "FREQUENCY",
+            EntitySubType::Duration => "DURATION",
+            EntitySubType::GenericName => "GENERIC_NAME",
+            EntitySubType::BrandName => "BRAND_NAME",
+            EntitySubType::Strength => "STRENGTH",
+            EntitySubType::Rate => "RATE",
+            EntitySubType::Acuity => "ACUITY",
+            EntitySubType::TestName => "TEST_NAME",
+            EntitySubType::TestValue => "TEST_VALUE",
+            EntitySubType::TestUnits => "TEST_UNITS",
+            EntitySubType::TestUnit => "TEST_UNIT",
+            EntitySubType::ProcedureName => "PROCEDURE_NAME",
+            EntitySubType::TreatmentName => "TREATMENT_NAME",
+            EntitySubType::Date => "DATE",
+            EntitySubType::Age => "AGE",
+            EntitySubType::ContactPoint => "CONTACT_POINT",
+            EntitySubType::PhoneOrFax => "PHONE_OR_FAX",
+            EntitySubType::Email => "EMAIL",
+            EntitySubType::Identifier => "IDENTIFIER",
+            EntitySubType::Id => "ID",
+            EntitySubType::Url => "URL",
+            EntitySubType::Address => "ADDRESS",
+            EntitySubType::Profession => "PROFESSION",
+            EntitySubType::SystemOrganSite => "SYSTEM_ORGAN_SITE",
+            EntitySubType::Direction => "DIRECTION",
+            EntitySubType::Quality => "QUALITY",
+            EntitySubType::Quantity => "QUANTITY",
+            EntitySubType::TimeExpression => "TIME_EXPRESSION",
+            EntitySubType::TimeToMedicationName => "TIME_TO_MEDICATION_NAME",
+            EntitySubType::TimeToDxName => "TIME_TO_DX_NAME",
+            EntitySubType::TimeToTestName => "TIME_TO_TEST_NAME",
+            EntitySubType::TimeToProcedureName => "TIME_TO_PROCEDURE_NAME",
+            EntitySubType::TimeToTreatmentName => "TIME_TO_TREATMENT_NAME",
+            EntitySubType::Amount => "AMOUNT",
+            EntitySubType::Gender => "GENDER",
+            EntitySubType::RaceEthnicity => "RACE_ETHNICITY",
+            EntitySubType::Allergies => "ALLERGIES",
+            EntitySubType::TobaccoUse => "TOBACCO_USE",
+            EntitySubType::AlcoholConsumption => "ALCOHOL_CONSUMPTION",
+            EntitySubType::RecDrugUse => "REC_DRUG_USE",
+            EntitySubType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EntitySubType {
+    fn default() -> EntitySubType {
+        EntitySubType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EntitySubType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EntitySubType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EntitySubType {
+    fn from(value: &'a str) -> EntitySubType {
+        match value {
+            "NAME" => EntitySubType::Name,
+            "DX_NAME" => EntitySubType::DxName,
+            "DOSAGE" => EntitySubType::Dosage,
+            "ROUTE_OR_MODE" => EntitySubType::RouteOrMode,
+            "FORM" => EntitySubType::Form,
+            "FREQUENCY" => EntitySubType::Frequency,
+            "DURATION" => EntitySubType::Duration,
+            "GENERIC_NAME" => EntitySubType::GenericName,
+            "BRAND_NAME" => EntitySubType::BrandName,
+            "STRENGTH" => EntitySubType::Strength,
+            "RATE" => EntitySubType::Rate,
+            "ACUITY" => EntitySubType::Acuity,
+            "TEST_NAME" => EntitySubType::TestName,
+            "TEST_VALUE" => EntitySubType::TestValue,
+            "TEST_UNITS" => EntitySubType::TestUnits,
+            "TEST_UNIT" => EntitySubType::TestUnit,
+            "PROCEDURE_NAME" => EntitySubType::ProcedureName,
+            "TREATMENT_NAME" => EntitySubType::TreatmentName,
+            "DATE" => EntitySubType::Date,
+            "AGE" => EntitySubType::Age,
+            "CONTACT_POINT" => EntitySubType::ContactPoint,
+            "PHONE_OR_FAX" => EntitySubType::PhoneOrFax,
+            "EMAIL" => EntitySubType::Email,
+            "IDENTIFIER" => EntitySubType::Identifier,
+            "ID" => EntitySubType::Id,
+            "URL" => EntitySubType::Url,
+            "ADDRESS" => EntitySubType::Address,
+            "PROFESSION" => EntitySubType::Profession,
+            "SYSTEM_ORGAN_SITE" => EntitySubType::SystemOrganSite,
+            "DIRECTION" => EntitySubType::Direction,
+            "QUALITY" => EntitySubType::Quality,
+            "QUANTITY" => EntitySubType::Quantity,
+            "TIME_EXPRESSION" => EntitySubType::TimeExpression,
+            "TIME_TO_MEDICATION_NAME" => EntitySubType::TimeToMedicationName,
+            "TIME_TO_DX_NAME" => EntitySubType::TimeToDxName,
+            "TIME_TO_TEST_NAME" => EntitySubType::TimeToTestName,
+            "TIME_TO_PROCEDURE_NAME" => EntitySubType::TimeToProcedureName,
+            "TIME_TO_TREATMENT_NAME" => EntitySubType::TimeToTreatmentName,
+            "AMOUNT" => EntitySubType::Amount,
+            "GENDER" => EntitySubType::Gender,
+            "RACE_ETHNICITY" => EntitySubType::RaceEthnicity,
+            "ALLERGIES" => EntitySubType::Allergies,
+            "TOBACCO_USE" => EntitySubType::TobaccoUse,
+            "ALCOHOL_CONSUMPTION" => EntitySubType::AlcoholConsumption,
+            "REC_DRUG_USE" => EntitySubType::RecDrugUse,
+            _ => EntitySubType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EntitySubType {
+    fn from(value: String) -> EntitySubType {
+        match value.as_str() {
+            "NAME" => EntitySubType::Name,
+            "DX_NAME" => EntitySubType::DxName,
+            "DOSAGE" => EntitySubType::Dosage,
+            "ROUTE_OR_MODE" => EntitySubType::RouteOrMode,
+            "FORM" => EntitySubType::Form,
+            "FREQUENCY" => EntitySubType::Frequency,
+            "DURATION" => EntitySubType::Duration,
+            "GENERIC_NAME" => EntitySubType::GenericName,
+            "BRAND_NAME" => EntitySubType::BrandName,
+            "STRENGTH" => EntitySubType::Strength,
+            "RATE" => EntitySubType::Rate,
+            "ACUITY" => EntitySubType::Acuity,
+            "TEST_NAME" => EntitySubType::TestName,
+            "TEST_VALUE" => EntitySubType::TestValue,
+            "TEST_UNITS" => EntitySubType::TestUnits,
+            "TEST_UNIT" => EntitySubType::TestUnit,
+            "PROCEDURE_NAME" => EntitySubType::ProcedureName,
+            "TREATMENT_NAME" => EntitySubType::TreatmentName,
+            "DATE" => EntitySubType::Date,
+            "AGE" => EntitySubType::Age,
+            "CONTACT_POINT" => EntitySubType::ContactPoint,
+            "PHONE_OR_FAX" => EntitySubType::PhoneOrFax,
+            "EMAIL" => EntitySubType::Email,
+            "IDENTIFIER" => EntitySubType::Identifier,
+            "ID" => EntitySubType::Id,
+            "URL" => EntitySubType::Url,
+            "ADDRESS" => EntitySubType::Address,
+            "PROFESSION" => EntitySubType::Profession,
+            "SYSTEM_ORGAN_SITE" => EntitySubType::SystemOrganSite,
+            "DIRECTION" => EntitySubType::Direction,
+            "QUALITY" => EntitySubType::Quality,
+            "QUANTITY" => EntitySubType::Quantity,
+            "TIME_EXPRESSION" => EntitySubType::TimeExpression,
+            "TIME_TO_MEDICATION_NAME" => EntitySubType::TimeToMedicationName,
+            "TIME_TO_DX_NAME" => EntitySubType::TimeToDxName,
+            "TIME_TO_TEST_NAME" => EntitySubType::TimeToTestName,
+            "TIME_TO_PROCEDURE_NAME" => EntitySubType::TimeToProcedureName,
+            "TIME_TO_TREATMENT_NAME" => EntitySubType::TimeToTreatmentName,
+            "AMOUNT" => EntitySubType::Amount,
+            "GENDER" => EntitySubType::Gender,
+            "RACE_ETHNICITY" => EntitySubType::RaceEthnicity,
+            "ALLERGIES" => EntitySubType::Allergies,
+            "TOBACCO_USE" => EntitySubType::TobaccoUse,
+            "ALCOHOL_CONSUMPTION" => EntitySubType::AlcoholConsumption,
+            "REC_DRUG_USE" => EntitySubType::RecDrugUse,
+            _ => EntitySubType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EntitySubType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EntitySubType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EntitySubType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EntitySubType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EntitySubType {
+    fn deserialize<D>(deserializer: D) -> Result<EntitySubType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EntityType {
+    /// `MEDICATION`
+    Medication,
+    /// `MEDICAL_CONDITION`
+    MedicalCondition,
+    /// `PROTECTED_HEALTH_INFORMATION`
+    ProtectedHealthInformation,
+    /// `TEST_TREATMENT_PROCEDURE`
+    TestTreatmentProcedure,
+    /// `ANATOMY`
+    Anatomy,
+    /// `TIME_EXPRESSION`
+    TimeExpression,
+    /// `BEHAVIORAL_ENVIRONMENTAL_SOCIAL`
+    BehavioralEnvironmentalSocial,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EntityType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EntityType::Medication => "MEDICATION",
+            EntityType::MedicalCondition => "MEDICAL_CONDITION",
+            EntityType::ProtectedHealthInformation => "PROTECTED_HEALTH_INFORMATION",
+            EntityType::TestTreatmentProcedure => "TEST_TREATMENT_PROCEDURE",
+            EntityType::Anatomy => "ANATOMY",
+            EntityType::TimeExpression => "TIME_EXPRESSION",
+            EntityType::BehavioralEnvironmentalSocial => "BEHAVIORAL_ENVIRONMENTAL_SOCIAL",
+            EntityType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EntityType {
+    fn default() -> EntityType {
+        EntityType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EntityType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EntityType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EntityType {
+    fn from(value: &'a str) -> EntityType {
+        match value {
+            "MEDICATION" => EntityType::Medication,
+            "MEDICAL_CONDITION" => EntityType::MedicalCondition,
+            "PROTECTED_HEALTH_INFORMATION" => EntityType::ProtectedHealthInformation,
+            "TEST_TREATMENT_PROCEDURE" => EntityType::TestTreatmentProcedure,
+            "ANATOMY" => EntityType::Anatomy,
+            "TIME_EXPRESSION" => EntityType::TimeExpression,
+            "BEHAVIORAL_ENVIRONMENTAL_SOCIAL" => EntityType::BehavioralEnvironmentalSocial,
+            _ => EntityType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EntityType {
+    fn from(value: String) -> EntityType {
+        match value.as_str() {
+            "MEDICATION" => EntityType::Medication,
+            "MEDICAL_CONDITION" => EntityType::MedicalCondition,
+            "PROTECTED_HEALTH_INFORMATION" => EntityType::ProtectedHealthInformation,
+            "TEST_TREATMENT_PROCEDURE" => EntityType::TestTreatmentProcedure,
+            "ANATOMY" => EntityType::Anatomy,
+            "TIME_EXPRESSION" => EntityType::TimeExpression,
+            "BEHAVIORAL_ENVIRONMENTAL_SOCIAL" => EntityType::BehavioralEnvironmentalSocial,
+            _ => EntityType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EntityType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EntityType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EntityType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
 }
 
+impl ::serde::Serialize for EntityType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EntityType {
+    fn deserialize<D>(deserializer: D) -> Result<EntityType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The detected attributes that relate to an entity. This includes an extracted segment of the text that is an attribute of an entity, or otherwise related to an entity. InferICD10CM detects the following attributes: <code>Direction</code>, <code>System, Organ or Site</code>, and <code>Acuity</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -334,9 +913,127 @@ pub struct ICD10CMAttribute {
     /// <p>The type of attribute. InferICD10CM detects entities of the type <code>DX_NAME</code>. </p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<ICD10CMAttributeType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ICD10CMAttributeType {
+    /// `ACUITY`
+    Acuity,
+    /// `DIRECTION`
+    Direction,
+    /// `SYSTEM_ORGAN_SITE`
+    SystemOrganSite,
+    /// `QUALITY`
+    Quality,
+    /// `QUANTITY`
+    Quantity,
+    /// `TIME_TO_DX_NAME`
+    TimeToDxName,
+    /// `TIME_EXPRESSION`
+    TimeExpression,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ICD10CMAttributeType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ICD10CMAttributeType::Acuity => "ACUITY",
+            ICD10CMAttributeType::Direction => "DIRECTION",
+            ICD10CMAttributeType::SystemOrganSite => "SYSTEM_ORGAN_SITE",
+            ICD10CMAttributeType::Quality => "QUALITY",
+            ICD10CMAttributeType::Quantity => "QUANTITY",
+            ICD10CMAttributeType::TimeToDxName => "TIME_TO_DX_NAME",
+            ICD10CMAttributeType::TimeExpression => "TIME_EXPRESSION",
+            ICD10CMAttributeType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ICD10CMAttributeType {
+    fn default() -> ICD10CMAttributeType {
+        ICD10CMAttributeType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ICD10CMAttributeType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ICD10CMAttributeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ICD10CMAttributeType {
+    fn from(value: &'a str) -> ICD10CMAttributeType {
+        match value {
+            "ACUITY" => ICD10CMAttributeType::Acuity,
+            "DIRECTION" => ICD10CMAttributeType::Direction,
+            "SYSTEM_ORGAN_SITE" => ICD10CMAttributeType::SystemOrganSite,
+            "QUALITY" => ICD10CMAttributeType::Quality,
+            "QUANTITY" => ICD10CMAttributeType::Quantity,
+            "TIME_TO_DX_NAME" => ICD10CMAttributeType::TimeToDxName,
+            "TIME_EXPRESSION" => ICD10CMAttributeType::TimeExpression,
+            _ => ICD10CMAttributeType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ICD10CMAttributeType {
+    fn from(value: String) -> ICD10CMAttributeType {
+        match value.as_str() {
+            "ACUITY" => ICD10CMAttributeType::Acuity,
+            "DIRECTION" => ICD10CMAttributeType::Direction,
+            "SYSTEM_ORGAN_SITE" => ICD10CMAttributeType::SystemOrganSite,
+            "QUALITY" => ICD10CMAttributeType::Quality,
+            "QUANTITY" => ICD10CMAttributeType::Quantity,
+            "TIME_TO_DX_NAME" => ICD10CMAttributeType::TimeToDxName,
+            "TIME_EXPRESSION" => ICD10CMAttributeType::TimeExpression,
+            _ => ICD10CMAttributeType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ICD10CMAttributeType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ICD10CMAttributeType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ICD10CMAttributeType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ICD10CMAttributeType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for ICD10CMAttributeType {
+    fn deserialize<D>(deserializer: D) -> Result<ICD10CMAttributeType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p> The ICD-10-CM concepts that the entity could refer to, along with a score indicating the likelihood of the match.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -370,7 +1067,7 @@ pub struct ICD10CMEntity {
     /// <p> The category of the entity. InferICD10CM detects entities in the <code>MEDICAL_CONDITION</code> category. </p>
     #[serde(rename = "Category")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub category: Option<String>,
+    pub category: Option<ICD10CMEntityCategory>,
     /// <p>The 0-based character offset in the input text that shows where the entity ends. The offset returns the UTF-8 code point in the string.</p>
     #[serde(rename = "EndOffset")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -398,9 +1095,190 @@ pub struct ICD10CMEntity {
     /// <p>Describes the specific type of entity with category of entities. InferICD10CM detects entities of the type <code>DX_NAME</code>.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<ICD10CMEntityType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ICD10CMEntityCategory {
+    /// `MEDICAL_CONDITION`
+    MedicalCondition,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ICD10CMEntityCategory {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ICD10CMEntityCategory::MedicalCondition => "MEDICAL_CONDITION",
+            ICD10CMEntityCategory::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ICD10CMEntityCategory {
+    fn default() -> ICD10CMEntityCategory {
+        ICD10CMEntityCategory::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ICD10CMEntityCategory {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ICD10CMEntityCategory {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ICD10CMEntityCategory {
+    fn from(value: &'a str) -> ICD10CMEntityCategory {
+        match value {
+            "MEDICAL_CONDITION" => ICD10CMEntityCategory::MedicalCondition,
+            _ => ICD10CMEntityCategory::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ICD10CMEntityCategory {
+    fn from(value: String) -> ICD10CMEntityCategory {
+        match value.as_str() {
+            "MEDICAL_CONDITION" => ICD10CMEntityCategory::MedicalCondition,
+            _ => ICD10CMEntityCategory::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ICD10CMEntityCategory {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ICD10CMEntityCategory, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ICD10CMEntityCategory {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ICD10CMEntityCategory {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ICD10CMEntityCategory {
+    fn deserialize<D>(deserializer: D) -> Result<ICD10CMEntityCategory, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ICD10CMEntityType {
+    /// `DX_NAME`
+    DxName,
+    /// `TIME_EXPRESSION`
+    TimeExpression,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ICD10CMEntityType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ICD10CMEntityType::DxName => "DX_NAME",
+            ICD10CMEntityType::TimeExpression => "TIME_EXPRESSION",
+            ICD10CMEntityType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ICD10CMEntityType {
+    fn default() -> ICD10CMEntityType {
+        ICD10CMEntityType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ICD10CMEntityType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ICD10CMEntityType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ICD10CMEntityType {
+    fn from(value: &'a str) -> ICD10CMEntityType {
+        match value {
+            "DX_NAME" => ICD10CMEntityType::DxName,
+            "TIME_EXPRESSION" => ICD10CMEntityType::TimeExpression,
+            _ => ICD10CMEntityType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ICD10CMEntityType {
+    fn from(value: String) -> ICD10CMEntityType {
+        match value.as_str() {
+            "DX_NAME" => ICD10CMEntityType::DxName,
+            "TIME_EXPRESSION" => ICD10CMEntityType::TimeExpression,
+            _ => ICD10CMEntityType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ICD10CMEntityType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ICD10CMEntityType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ICD10CMEntityType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ICD10CMEntityType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for ICD10CMEntityType {
+    fn deserialize<D>(deserializer: D) -> Result<ICD10CMEntityType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Contextual information for the entity. The traits recognized by InferICD10CM are <code>DIAGNOSIS</code>, <code>SIGN</code>, <code>SYMPTOM</code>, and <code>NEGATION</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -408,13 +1286,131 @@ pub struct ICD10CMTrait {
     /// <p>Provides a name or contextual description about the trait.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
+    pub name: Option<ICD10CMTraitName>,
     /// <p>The level of confidence that Amazon Comprehend Medical has that the segment of text is correctly recognized as a trait.</p>
     #[serde(rename = "Score")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub score: Option<f32>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ICD10CMTraitName {
+    /// `NEGATION`
+    Negation,
+    /// `DIAGNOSIS`
+    Diagnosis,
+    /// `SIGN`
+    Sign,
+    /// `SYMPTOM`
+    Symptom,
+    /// `PERTAINS_TO_FAMILY`
+    PertainsToFamily,
+    /// `HYPOTHETICAL`
+    Hypothetical,
+    /// `LOW_CONFIDENCE`
+    LowConfidence,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ICD10CMTraitName {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ICD10CMTraitName::Negation => "NEGATION",
+            ICD10CMTraitName::Diagnosis => "DIAGNOSIS",
+            ICD10CMTraitName::Sign => "SIGN",
+            ICD10CMTraitName::Symptom => "SYMPTOM",
+            ICD10CMTraitName::PertainsToFamily => "PERTAINS_TO_FAMILY",
+            ICD10CMTraitName::Hypothetical => "HYPOTHETICAL",
+            ICD10CMTraitName::LowConfidence => "LOW_CONFIDENCE",
+            ICD10CMTraitName::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ICD10CMTraitName {
+    fn default() -> ICD10CMTraitName {
+        ICD10CMTraitName::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ICD10CMTraitName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ICD10CMTraitName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ICD10CMTraitName {
+    fn from(value: &'a str) -> ICD10CMTraitName {
+        match value {
+            "NEGATION" => ICD10CMTraitName::Negation,
+            "DIAGNOSIS" => ICD10CMTraitName::Diagnosis,
+            "SIGN" => ICD10CMTraitName::Sign,
+            "SYMPTOM" => ICD10CMTraitName::Symptom,
+            "PERTAINS_TO_FAMILY" => ICD10CMTraitName::PertainsToFamily,
+            "HYPOTHETICAL" => ICD10CMTraitName::Hypothetical,
+            "LOW_CONFIDENCE" => ICD10CMTraitName::LowConfidence,
+            _ => ICD10CMTraitName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ICD10CMTraitName {
+    fn from(value: String) -> ICD10CMTraitName {
+        match value.as_str() {
+            "NEGATION" => ICD10CMTraitName::Negation,
+            "DIAGNOSIS" => ICD10CMTraitName::Diagnosis,
+            "SIGN" => ICD10CMTraitName::Sign,
+            "SYMPTOM" => ICD10CMTraitName::Symptom,
+            "PERTAINS_TO_FAMILY" => ICD10CMTraitName::PertainsToFamily,
+            "HYPOTHETICAL" => ICD10CMTraitName::Hypothetical,
+            "LOW_CONFIDENCE" => ICD10CMTraitName::LowConfidence,
+            _ => ICD10CMTraitName::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ICD10CMTraitName {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ICD10CMTraitName, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ICD10CMTraitName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ICD10CMTraitName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ICD10CMTraitName {
+    fn deserialize<D>(deserializer: D) -> Result<ICD10CMTraitName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct InferICD10CMRequest {
@@ -475,6 +1471,212 @@ pub struct InputDataConfig {
     pub s3_key: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum JobStatus {
+    /// `SUBMITTED`
+    Submitted,
+    /// `IN_PROGRESS`
+    InProgress,
+    /// `COMPLETED`
+    Completed,
+    /// `PARTIAL_SUCCESS`
+    PartialSuccess,
+    /// `FAILED`
+    Failed,
+    /// `STOP_REQUESTED`
+    StopRequested,
+    /// `STOPPED`
+    Stopped,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl JobStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            JobStatus::Submitted => "SUBMITTED",
+            JobStatus::InProgress => "IN_PROGRESS",
+            JobStatus::Completed => "COMPLETED",
+            JobStatus::PartialSuccess => "PARTIAL_SUCCESS",
+            JobStatus::Failed => "FAILED",
+            JobStatus::StopRequested => "STOP_REQUESTED",
+            JobStatus::Stopped => "STOPPED",
+            JobStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for JobStatus {
+    fn default() -> JobStatus {
+        JobStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for JobStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for JobStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for JobStatus {
+    fn from(value: &'a str) -> JobStatus {
+        match value {
+            "SUBMITTED" => JobStatus::Submitted,
+            "IN_PROGRESS" => JobStatus::InProgress,
+            "COMPLETED" => JobStatus::Completed,
+            "PARTIAL_SUCCESS" => JobStatus::PartialSuccess,
+            "FAILED" => JobStatus::Failed,
+            "STOP_REQUESTED" => JobStatus::StopRequested,
+            "STOPPED" => JobStatus::Stopped,
+            _ => JobStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for JobStatus {
+    fn from(value: String) -> JobStatus {
+        match value.as_str() {
+            "SUBMITTED" => JobStatus::Submitted,
+            "IN_PROGRESS" => JobStatus::InProgress,
+            "COMPLETED" => JobStatus::Completed,
+            "PARTIAL_SUCCESS" => JobStatus::PartialSuccess,
+            "FAILED" => JobStatus::Failed,
+            "STOP_REQUESTED" => JobStatus::StopRequested,
+            "STOPPED" => JobStatus::Stopped,
+            _ => JobStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for JobStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<JobStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for JobStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for JobStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for JobStatus {
+    fn deserialize<D>(deserializer: D) -> Result<JobStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LanguageCode {
+    /// `en`
+    En,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LanguageCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LanguageCode::En => "en",
+            LanguageCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LanguageCode {
+    fn default() -> LanguageCode {
+        LanguageCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LanguageCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LanguageCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LanguageCode {
+    fn from(value: &'a str) -> LanguageCode {
+        match value {
+            "en" => LanguageCode::En,
+            _ => LanguageCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LanguageCode {
+    fn from(value: String) -> LanguageCode {
+        match value.as_str() {
+            "en" => LanguageCode::En,
+            _ => LanguageCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LanguageCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LanguageCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LanguageCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LanguageCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LanguageCode {
+    fn deserialize<D>(deserializer: D) -> Result<LanguageCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ListEntitiesDetectionV2JobsRequest {
@@ -584,9 +1786,127 @@ pub struct RxNormAttribute {
     /// <p>The type of attribute. The types of attributes recognized by InferRxNorm are <code>BRAND_NAME</code> and <code>GENERIC_NAME</code>.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<RxNormAttributeType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RxNormAttributeType {
+    /// `DOSAGE`
+    Dosage,
+    /// `DURATION`
+    Duration,
+    /// `FORM`
+    Form,
+    /// `FREQUENCY`
+    Frequency,
+    /// `RATE`
+    Rate,
+    /// `ROUTE_OR_MODE`
+    RouteOrMode,
+    /// `STRENGTH`
+    Strength,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RxNormAttributeType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RxNormAttributeType::Dosage => "DOSAGE",
+            RxNormAttributeType::Duration => "DURATION",
+            RxNormAttributeType::Form => "FORM",
+            RxNormAttributeType::Frequency => "FREQUENCY",
+            RxNormAttributeType::Rate => "RATE",
+            RxNormAttributeType::RouteOrMode => "ROUTE_OR_MODE",
+            RxNormAttributeType::Strength => "STRENGTH",
+            RxNormAttributeType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RxNormAttributeType {
+    fn default() -> RxNormAttributeType {
+        RxNormAttributeType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RxNormAttributeType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RxNormAttributeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RxNormAttributeType {
+    fn from(value: &'a str) -> RxNormAttributeType {
+        match value {
+            "DOSAGE" => RxNormAttributeType::Dosage,
+            "DURATION" => RxNormAttributeType::Duration,
+            "FORM" => RxNormAttributeType::Form,
+            "FREQUENCY" => RxNormAttributeType::Frequency,
+            "RATE" => RxNormAttributeType::Rate,
+            "ROUTE_OR_MODE" => RxNormAttributeType::RouteOrMode,
+            "STRENGTH" => RxNormAttributeType::Strength,
+            _ => RxNormAttributeType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RxNormAttributeType {
+    fn from(value: String) -> RxNormAttributeType {
+        match value.as_str() {
+            "DOSAGE" => RxNormAttributeType::Dosage,
+            "DURATION" => RxNormAttributeType::Duration,
+            "FORM" => RxNormAttributeType::Form,
+            "FREQUENCY" => RxNormAttributeType::Frequency,
+            "RATE" => RxNormAttributeType::Rate,
+            "ROUTE_OR_MODE" => RxNormAttributeType::RouteOrMode,
+            "STRENGTH" => RxNormAttributeType::Strength,
+            _ => RxNormAttributeType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RxNormAttributeType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RxNormAttributeType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RxNormAttributeType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RxNormAttributeType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for RxNormAttributeType {
+    fn deserialize<D>(deserializer: D) -> Result<RxNormAttributeType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The RxNorm concept that the entity could refer to, along with a score indicating the likelihood of the match.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -620,7 +1940,7 @@ pub struct RxNormEntity {
     /// <p>The category of the entity. The recognized categories are <code>GENERIC</code> or <code>BRAND_NAME</code>.</p>
     #[serde(rename = "Category")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub category: Option<String>,
+    pub category: Option<RxNormEntityCategory>,
     /// <p>The 0-based character offset in the input text that shows where the entity ends. The offset returns the UTF-8 code point in the string.</p>
     #[serde(rename = "EndOffset")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -648,9 +1968,190 @@ pub struct RxNormEntity {
     /// <p> Describes the specific type of entity. For InferRxNorm, the recognized entity type is <code>MEDICATION</code>.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<RxNormEntityType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RxNormEntityCategory {
+    /// `MEDICATION`
+    Medication,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RxNormEntityCategory {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RxNormEntityCategory::Medication => "MEDICATION",
+            RxNormEntityCategory::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RxNormEntityCategory {
+    fn default() -> RxNormEntityCategory {
+        RxNormEntityCategory::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RxNormEntityCategory {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RxNormEntityCategory {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RxNormEntityCategory {
+    fn from(value: &'a str) -> RxNormEntityCategory {
+        match value {
+            "MEDICATION" => RxNormEntityCategory::Medication,
+            _ => RxNormEntityCategory::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RxNormEntityCategory {
+    fn from(value: String) -> RxNormEntityCategory {
+        match value.as_str() {
+            "MEDICATION" => RxNormEntityCategory::Medication,
+            _ => RxNormEntityCategory::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RxNormEntityCategory {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RxNormEntityCategory, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RxNormEntityCategory {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RxNormEntityCategory {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RxNormEntityCategory {
+    fn deserialize<D>(deserializer: D) -> Result<RxNormEntityCategory, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RxNormEntityType {
+    /// `BRAND_NAME`
+    BrandName,
+    /// `GENERIC_NAME`
+    GenericName,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RxNormEntityType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RxNormEntityType::BrandName => "BRAND_NAME",
+            RxNormEntityType::GenericName => "GENERIC_NAME",
+            RxNormEntityType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RxNormEntityType {
+    fn default() -> RxNormEntityType {
+        RxNormEntityType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RxNormEntityType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RxNormEntityType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RxNormEntityType {
+    fn from(value: &'a str) -> RxNormEntityType {
+        match value {
+            "BRAND_NAME" => RxNormEntityType::BrandName,
+            "GENERIC_NAME" => RxNormEntityType::GenericName,
+            _ => RxNormEntityType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RxNormEntityType {
+    fn from(value: String) -> RxNormEntityType {
+        match value.as_str() {
+            "BRAND_NAME" => RxNormEntityType::BrandName,
+            "GENERIC_NAME" => RxNormEntityType::GenericName,
+            _ => RxNormEntityType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RxNormEntityType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RxNormEntityType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RxNormEntityType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RxNormEntityType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for RxNormEntityType {
+    fn deserialize<D>(deserializer: D) -> Result<RxNormEntityType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The contextual information for the entity. InferRxNorm recognizes the trait <code>NEGATION</code>, which is any indication that the patient is not taking a medication. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -658,13 +2159,106 @@ pub struct RxNormTrait {
     /// <p>Provides a name or contextual description about the trait.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
+    pub name: Option<RxNormTraitName>,
     /// <p>The level of confidence that Amazon Comprehend Medical has in the accuracy of the detected trait.</p>
     #[serde(rename = "Score")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub score: Option<f32>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RxNormTraitName {
+    /// `NEGATION`
+    Negation,
+    /// `PAST_HISTORY`
+    PastHistory,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RxNormTraitName {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RxNormTraitName::Negation => "NEGATION",
+            RxNormTraitName::PastHistory => "PAST_HISTORY",
+            RxNormTraitName::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RxNormTraitName {
+    fn default() -> RxNormTraitName {
+        RxNormTraitName::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RxNormTraitName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RxNormTraitName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RxNormTraitName {
+    fn from(value: &'a str) -> RxNormTraitName {
+        match value {
+            "NEGATION" => RxNormTraitName::Negation,
+            "PAST_HISTORY" => RxNormTraitName::PastHistory,
+            _ => RxNormTraitName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RxNormTraitName {
+    fn from(value: String) -> RxNormTraitName {
+        match value.as_str() {
+            "NEGATION" => RxNormTraitName::Negation,
+            "PAST_HISTORY" => RxNormTraitName::PastHistory,
+            _ => RxNormTraitName::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RxNormTraitName {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RxNormTraitName, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RxNormTraitName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RxNormTraitName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RxNormTraitName {
+    fn deserialize<D>(deserializer: D) -> Result<RxNormTraitName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct StartEntitiesDetectionV2JobRequest {
@@ -688,7 +2282,7 @@ pub struct StartEntitiesDetectionV2JobRequest {
     pub kms_key: Option<String>,
     /// <p>The language of the input documents. All documents must be in the same language.</p>
     #[serde(rename = "LanguageCode")]
-    pub language_code: String,
+    pub language_code: LanguageCode,
     /// <p>Specifies where to send the output files.</p>
     #[serde(rename = "OutputDataConfig")]
     pub output_data_config: OutputDataConfig,
@@ -726,7 +2320,7 @@ pub struct StartPHIDetectionJobRequest {
     pub kms_key: Option<String>,
     /// <p>The language of the input documents. All documents must be in the same language.</p>
     #[serde(rename = "LanguageCode")]
-    pub language_code: String,
+    pub language_code: LanguageCode,
     /// <p>Specifies where to send the output files.</p>
     #[serde(rename = "OutputDataConfig")]
     pub output_data_config: OutputDataConfig,
@@ -782,7 +2376,7 @@ pub struct Trait {
     /// <p> Provides a name or contextual description about the trait. </p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
+    pub name: Option<AttributeName>,
     /// <p> The level of confidence that Amazon Comprehend Medical has in the accuracy of this trait.</p>
     #[serde(rename = "Score")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -800,7 +2394,7 @@ pub struct UnmappedAttribute {
     /// <p> The type of the attribute, could be one of the following values: "MEDICATION", "MEDICAL_CONDITION", "ANATOMY", "TEST_AND_TREATMENT_PROCEDURE" or "PROTECTED_HEALTH_INFORMATION". </p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<EntityType>,
 }
 
 /// Errors returned by DescribeEntitiesDetectionV2Job
@@ -1621,11 +3215,12 @@ pub struct ComprehendMedicalClient {
 impl ComprehendMedicalClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> ComprehendMedicalClient {
         ComprehendMedicalClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "ComprehendMedical"),
         }
     }
 
@@ -1660,6 +3255,7 @@ impl ComprehendMedical for ComprehendMedicalClient {
         RusotoError<DescribeEntitiesDetectionV2JobError>,
     > {
         let mut request = SignedRequest::new("POST", "comprehendmedical", &self.region, "/");
+        request.set_operation("DescribeEntitiesDetectionV2Job");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1691,6 +3287,7 @@ impl ComprehendMedical for ComprehendMedicalClient {
         input: DescribePHIDetectionJobRequest,
     ) -> Result<DescribePHIDetectionJobResponse, RusotoError<DescribePHIDetectionJobError>> {
         let mut request = SignedRequest::new("POST", "comprehendmedical", &self.region, "/");
+        request.set_operation("DescribePHIDetectionJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1722,6 +3319,7 @@ impl ComprehendMedical for ComprehendMedicalClient {
         input: DetectEntitiesRequest,
     ) -> Result<DetectEntitiesResponse, RusotoError<DetectEntitiesError>> {
         let mut request = SignedRequest::new("POST", "comprehendmedical", &self.region, "/");
+        request.set_operation("DetectEntities");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ComprehendMedical_20181030.DetectEntities");
@@ -1749,6 +3347,7 @@ impl ComprehendMedical for ComprehendMedicalClient {
         input: DetectEntitiesV2Request,
     ) -> Result<DetectEntitiesV2Response, RusotoError<DetectEntitiesV2Error>> {
         let mut request = SignedRequest::new("POST", "comprehendmedical", &self.region, "/");
+        request.set_operation("DetectEntitiesV2");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1780,6 +3379,7 @@ impl ComprehendMedical for ComprehendMedicalClient {
         input: DetectPHIRequest,
     ) -> Result<DetectPHIResponse, RusotoError<DetectPHIError>> {
         let mut request = SignedRequest::new("POST", "comprehendmedical", &self.region, "/");
+        request.set_operation("DetectPHI");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ComprehendMedical_20181030.DetectPHI");
@@ -1807,6 +3407,7 @@ impl ComprehendMedical for ComprehendMedicalClient {
         input: InferICD10CMRequest,
     ) -> Result<InferICD10CMResponse, RusotoError<InferICD10CMError>> {
         let mut request = SignedRequest::new("POST", "comprehendmedical", &self.region, "/");
+        request.set_operation("InferICD10CM");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ComprehendMedical_20181030.InferICD10CM");
@@ -1834,6 +3435,7 @@ impl ComprehendMedical for ComprehendMedicalClient {
         input: InferRxNormRequest,
     ) -> Result<InferRxNormResponse, RusotoError<InferRxNormError>> {
         let mut request = SignedRequest::new("POST", "comprehendmedical", &self.region, "/");
+        request.set_operation("InferRxNorm");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ComprehendMedical_20181030.InferRxNorm");
@@ -1862,6 +3464,7 @@ impl ComprehendMedical for ComprehendMedicalClient {
     ) -> Result<ListEntitiesDetectionV2JobsResponse, RusotoError<ListEntitiesDetectionV2JobsError>>
     {
         let mut request = SignedRequest::new("POST", "comprehendmedical", &self.region, "/");
+        request.set_operation("ListEntitiesDetectionV2Jobs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1893,6 +3496,7 @@ impl ComprehendMedical for ComprehendMedicalClient {
         input: ListPHIDetectionJobsRequest,
     ) -> Result<ListPHIDetectionJobsResponse, RusotoError<ListPHIDetectionJobsError>> {
         let mut request = SignedRequest::new("POST", "comprehendmedical", &self.region, "/");
+        request.set_operation("ListPHIDetectionJobs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1925,6 +3529,7 @@ impl ComprehendMedical for ComprehendMedicalClient {
     ) -> Result<StartEntitiesDetectionV2JobResponse, RusotoError<StartEntitiesDetectionV2JobError>>
     {
         let mut request = SignedRequest::new("POST", "comprehendmedical", &self.region, "/");
+        request.set_operation("StartEntitiesDetectionV2Job");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1956,6 +3561,7 @@ impl ComprehendMedical for ComprehendMedicalClient {
         input: StartPHIDetectionJobRequest,
     ) -> Result<StartPHIDetectionJobResponse, RusotoError<StartPHIDetectionJobError>> {
         let mut request = SignedRequest::new("POST", "comprehendmedical", &self.region, "/");
+        request.set_operation("StartPHIDetectionJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1988,6 +3594,7 @@ impl ComprehendMedical for ComprehendMedicalClient {
     ) -> Result<StopEntitiesDetectionV2JobResponse, RusotoError<StopEntitiesDetectionV2JobError>>
     {
         let mut request = SignedRequest::new("POST", "comprehendmedical", &self.region, "/");
+        request.set_operation("StopEntitiesDetectionV2Job");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2019,6 +3626,7 @@ impl ComprehendMedical for ComprehendMedicalClient {
         input: StopPHIDetectionJobRequest,
     ) -> Result<StopPHIDetectionJobResponse, RusotoError<StopPHIDetectionJobError>> {
         let mut request = SignedRequest::new("POST", "comprehendmedical", &self.region, "/");
+        request.set_operation("StopPHIDetectionJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -69,11 +69,16 @@ pub struct AutoScalingGroupRecommendation {
     /// <p><p>The finding classification for the Auto Scaling group.</p> <p>Findings for Auto Scaling groups include:</p> <ul> <li> <p> <b> <code>NotOptimized</code> </b>—An Auto Scaling group is considered not optimized when AWS Compute Optimizer identifies a recommendation that can provide better performance for your workload.</p> </li> <li> <p> <b> <code>Optimized</code> </b>—An Auto Scaling group is considered optimized when Compute Optimizer determines that the group is correctly provisioned to run your workload based on the chosen instance type. For optimized resources, Compute Optimizer might recommend a new generation instance type.</p> </li> </ul> <note> <p>The values that are returned might be <code>NOT_OPTIMIZED</code> or <code>OPTIMIZED</code>.</p> </note></p>
     #[serde(rename = "finding")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub finding: Option<String>,
+    pub finding: Option<Finding>,
     /// <p>The time stamp of when the Auto Scaling group recommendation was last refreshed.</p>
     #[serde(rename = "lastRefreshTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_refresh_timestamp: Option<f64>,
+    pub last_refresh_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The number of days for which utilization metrics were analyzed for the Auto Scaling group.</p>
     #[serde(rename = "lookBackPeriodInDays")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -117,13 +122,219 @@ pub struct Filter {
     /// <p>The name of the filter.</p> <p>Specify <code>Finding</code> to filter the results to a specific findings classification.</p> <p>Specify <code>RecommendationSourceType</code> to filter the results to a specific resource type.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
+    pub name: Option<FilterName>,
     /// <p>The value of the filter.</p> <p>If you specify the <code>name</code> parameter as <code>Finding</code>, and you're recommendations for an <i>instance</i>, then the valid values are <code>Underprovisioned</code>, <code>Overprovisioned</code>, <code>NotOptimized</code>, or <code>Optimized</code>.</p> <p>If you specify the <code>name</code> parameter as <code>Finding</code>, and you're recommendations for an <i>Auto Scaling group</i>, then the valid values are <code>Optimized</code>, or <code>NotOptimized</code>.</p> <p>If you specify the <code>name</code> parameter as <code>RecommendationSourceType</code>, then the valid values are <code>EC2Instance</code>, or <code>AutoScalingGroup</code>.</p>
     #[serde(rename = "values")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub values: Option<Vec<String>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FilterName {
+    /// `Finding`
+    Finding,
+    /// `FindingReasonCodes`
+    FindingReasonCodes,
+    /// `RecommendationSourceType`
+    RecommendationSourceType,
+    /// `InferredWorkloadTypes`
+    InferredWorkloadTypes,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl FilterName {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FilterName::Finding => "Finding",
+            FilterName::FindingReasonCodes => "FindingReasonCodes",
+            FilterName::RecommendationSourceType => "RecommendationSourceType",
+            FilterName::InferredWorkloadTypes => "InferredWorkloadTypes",
+            FilterName::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for FilterName {
+    fn default() -> FilterName {
+        FilterName::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for FilterName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FilterName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FilterName {
+    fn from(value: &'a str) -> FilterName {
+        match value {
+            "Finding" => FilterName::Finding,
+            "FindingReasonCodes" => FilterName::FindingReasonCodes,
+            "RecommendationSourceType" => FilterName::RecommendationSourceType,
+            "InferredWorkloadTypes" => FilterName::InferredWorkloadTypes,
+            _ => FilterName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FilterName {
+    fn from(value: String) -> FilterName {
+        match value.as_str() {
+            "Finding" => FilterName::Finding,
+            "FindingReasonCodes" => FilterName::FindingReasonCodes,
+            "RecommendationSourceType" => FilterName::RecommendationSourceType,
+            "InferredWorkloadTypes" => FilterName::InferredWorkloadTypes,
+            _ => FilterName::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for FilterName {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<FilterName, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FilterName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FilterName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FilterName {
+    fn deserialize<D>(deserializer: D) -> Result<FilterName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Finding {
+    /// `Underprovisioned`
+    Underprovisioned,
+    /// `Overprovisioned`
+    Overprovisioned,
+    /// `Optimized`
+    Optimized,
+    /// `NotOptimized`
+    NotOptimized,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Finding {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Finding::Underprovisioned => "Underprovisioned",
+            Finding::Overprovisioned => "Overprovisioned",
+            Finding::Optimized => "Optimized",
+            Finding::NotOptimized => "NotOptimized",
+            Finding::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Finding {
+    fn default() -> Finding {
+        Finding::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Finding {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Finding {
+    fn from(value: &'a str) -> Finding {
+        match value {
+            "Underprovisioned" => Finding::Underprovisioned,
+            "Overprovisioned" => Finding::Overprovisioned,
+            "Optimized" => Finding::Optimized,
+            "NotOptimized" => Finding::NotOptimized,
+            _ => Finding::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Finding {
+    fn from(value: String) -> Finding {
+        match value.as_str() {
+            "Underprovisioned" => Finding::Underprovisioned,
+            "Overprovisioned" => Finding::Overprovisioned,
+            "Optimized" => Finding::Optimized,
+            "NotOptimized" => Finding::NotOptimized,
+            _ => Finding::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Finding {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Finding, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Finding {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Finding {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Finding {
+    fn deserialize<D>(deserializer: D) -> Result<Finding, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct GetAutoScalingGroupRecommendationsRequest {
@@ -213,7 +424,12 @@ pub struct GetEC2InstanceRecommendationsResponse {
 pub struct GetEC2RecommendationProjectedMetricsRequest {
     /// <p>The time stamp of the last projected metrics data point to return.</p>
     #[serde(rename = "endTime")]
-    pub end_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub end_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The Amazon Resource Name (ARN) of the instances for which to return recommendation projected metrics.</p>
     #[serde(rename = "instanceArn")]
     pub instance_arn: String,
@@ -222,10 +438,15 @@ pub struct GetEC2RecommendationProjectedMetricsRequest {
     pub period: i64,
     /// <p>The time stamp of the first projected metrics data point to return.</p>
     #[serde(rename = "startTime")]
-    pub start_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub start_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The statistic of the projected metrics.</p>
     #[serde(rename = "stat")]
-    pub stat: String,
+    pub stat: MetricStatistic,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -251,7 +472,7 @@ pub struct GetEnrollmentStatusResponse {
     /// <p>The enrollment status of the account.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<Status>,
     /// <p>The reason for the enrollment status of the account.</p> <p>For example, an account might show a status of <code>Pending</code> because member accounts of an organization require more time to be enrolled in the service.</p>
     #[serde(rename = "statusReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -321,7 +542,7 @@ pub struct InstanceRecommendation {
     /// <p><p>The finding classification for the instance.</p> <p>Findings for instances include:</p> <ul> <li> <p> <b> <code>Underprovisioned</code> </b>—An instance is considered under-provisioned when at least one specification of your instance, such as CPU, memory, or network, does not meet the performance requirements of your workload. Under-provisioned instances may lead to poor application performance.</p> </li> <li> <p> <b> <code>Overprovisioned</code> </b>—An instance is considered over-provisioned when at least one specification of your instance, such as CPU, memory, or network, can be sized down while still meeting the performance requirements of your workload, and no specification is under-provisioned. Over-provisioned instances may lead to unnecessary infrastructure cost.</p> </li> <li> <p> <b> <code>Optimized</code> </b>—An instance is considered optimized when all specifications of your instance, such as CPU, memory, and network, meet the performance requirements of your workload and is not over provisioned. An optimized instance runs your workloads with optimal performance and infrastructure cost. For optimized resources, AWS Compute Optimizer might recommend a new generation instance type.</p> </li> </ul> <note> <p>The values that are returned might be <code>UNDER<em>PROVISIONED</code>, <code>OVER</em>PROVISIONED</code>, or <code>OPTIMIZED</code>.</p> </note></p>
     #[serde(rename = "finding")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub finding: Option<String>,
+    pub finding: Option<Finding>,
     /// <p>The Amazon Resource Name (ARN) of the current instance.</p>
     #[serde(rename = "instanceArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -332,8 +553,13 @@ pub struct InstanceRecommendation {
     pub instance_name: Option<String>,
     /// <p>The time stamp of when the instance recommendation was last refreshed.</p>
     #[serde(rename = "lastRefreshTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_refresh_timestamp: Option<f64>,
+    pub last_refresh_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The number of days for which utilization metrics were analyzed for the instance.</p>
     #[serde(rename = "lookBackPeriodInDays")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -374,6 +600,262 @@ pub struct InstanceRecommendationOption {
     pub rank: Option<i64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MetricName {
+    /// `Cpu`
+    Cpu,
+    /// `Memory`
+    Memory,
+    /// `EBS_READ_OPS_PER_SECOND`
+    EbsReadOpsPerSecond,
+    /// `EBS_WRITE_OPS_PER_SECOND`
+    EbsWriteOpsPerSecond,
+    /// `EBS_READ_BYTES_PER_SECOND`
+    EbsReadBytesPerSecond,
+    /// `EBS_WRITE_BYTES_PER_SECOND`
+    EbsWriteBytesPerSecond,
+    /// `DISK_READ_OPS_PER_SECOND`
+    DiskReadOpsPerSecond,
+    /// `DISK_WRITE_OPS_PER_SECOND`
+    DiskWriteOpsPerSecond,
+    /// `DISK_READ_BYTES_PER_SECOND`
+    DiskReadBytesPerSecond,
+    /// `DISK_WRITE_BYTES_PER_SECOND`
+    DiskWriteBytesPerSecond,
+    /// `NETWORK_IN_BYTES_PER_SECOND`
+    NetworkInBytesPerSecond,
+    /// `NETWORK_OUT_BYTES_PER_SECOND`
+    NetworkOutBytesPerSecond,
+    /// `NETWORK_PACKETS_IN_PER_SECOND`
+    NetworkPacketsInPerSecond,
+    /// `NETWORK_PACKETS_OUT_PER_SECOND`
+    NetworkPacketsOutPerSecond,
+    /// `GPU_PERCENTAGE`
+    GpuPercentage,
+    /// `GPU_MEMORY_PERCENTAGE`
+    GpuMemoryPercentage,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl MetricName {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MetricName::Cpu => "Cpu",
+            MetricName::Memory => "Memory",
+            MetricName::EbsReadOpsPerSecond => "EBS_READ_OPS_PER_SECOND",
+            MetricName::EbsWriteOpsPerSecond => "EBS_WRITE_OPS_PER_SECOND",
+            MetricName::EbsReadBytesPerSecond => "EBS_READ_BYTES_PER_SECOND",
+            MetricName::EbsWriteBytesPerSecond => "EBS_WRITE_BYTES_PER_SECOND",
+            MetricName::DiskReadOpsPerSecond => "DISK_READ_OPS_PER_SECOND",
+            MetricName::DiskWriteOpsPerSecond => "DISK_WRITE_OPS_PER_SECOND",
+            MetricName::DiskReadBytesPerSecond => "DISK_READ_BYTES_PER_SECOND",
+            MetricName::DiskWriteBytesPerSecond => "DISK_WRITE_BYTES_PER_SECOND",
+            MetricName::NetworkInBytesPerSecond => "NETWORK_IN_BYTES_PER_SECOND",
+            MetricName::NetworkOutBytesPerSecond => "NETWORK_OUT_BYTES_PER_SECOND",
+            MetricName::NetworkPacketsInPerSecond => "NETWORK_PACKETS_IN_PER_SECOND",
+            MetricName::NetworkPacketsOutPerSecond => "NETWORK_PACKETS_OUT_PER_SECOND",
+            MetricName::GpuPercentage => "GPU_PERCENTAGE",
+            MetricName::GpuMemoryPercentage => "GPU_MEMORY_PERCENTAGE",
+            MetricName::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for MetricName {
+    fn default() -> MetricName {
+        MetricName::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for MetricName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for MetricName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for MetricName {
+    fn from(value: &'a str) -> MetricName {
+        match value {
+            "Cpu" => MetricName::Cpu,
+            "Memory" => MetricName::Memory,
+            "EBS_READ_OPS_PER_SECOND" => MetricName::EbsReadOpsPerSecond,
+            "EBS_WRITE_OPS_PER_SECOND" => MetricName::EbsWriteOpsPerSecond,
+            "EBS_READ_BYTES_PER_SECOND" => MetricName::EbsReadBytesPerSecond,
+            "EBS_WRITE_BYTES_PER_SECOND" => MetricName::EbsWriteBytesPerSecond,
+            "DISK_READ_OPS_PER_SECOND" => MetricName::DiskReadOpsPerSecond,
+            "DISK_WRITE_OPS_PER_SECOND" => MetricName::DiskWriteOpsPerSecond,
+            "DISK_READ_BYTES_PER_SECOND" => MetricName::DiskReadBytesPerSecond,
+            "DISK_WRITE_BYTES_PER_SECOND" => MetricName::DiskWriteBytesPerSecond,
+            "NETWORK_IN_BYTES_PER_SECOND" => MetricName::NetworkInBytesPerSecond,
+            "NETWORK_OUT_BYTES_PER_SECOND" => MetricName::NetworkOutBytesPerSecond,
+            "NETWORK_PACKETS_IN_PER_SECOND" => MetricName::NetworkPacketsInPerSecond,
+            "NETWORK_PACKETS_OUT_PER_SECOND" => MetricName::NetworkPacketsOutPerSecond,
+            "GPU_PERCENTAGE" => MetricName::GpuPercentage,
+            "GPU_MEMORY_PERCENTAGE" => MetricName::GpuMemoryPercentage,
+            _ => MetricName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MetricName {
+    fn from(value: String) -> MetricName {
+        match value.as_str() {
+            "Cpu" => MetricName::Cpu,
+            "Memory" => MetricName::Memory,
+            "EBS_READ_OPS_PER_SECOND" => MetricName::EbsReadOpsPerSecond,
+            "EBS_WRITE_OPS_PER_SECOND" => MetricName::EbsWriteOpsPerSecond,
+            "EBS_READ_BYTES_PER_SECOND" => MetricName::EbsReadBytesPerSecond,
+            "EBS_WRITE_BYTES_PER_SECOND" => MetricName::EbsWriteBytesPerSecond,
+            "DISK_READ_OPS_PER_SECOND" => MetricName::DiskReadOpsPerSecond,
+            "DISK_WRITE_OPS_PER_SECOND" => MetricName::DiskWriteOpsPerSecond,
+            "DISK_READ_BYTES_PER_SECOND" => MetricName::DiskReadBytesPerSecond,
+            "DISK_WRITE_BYTES_PER_SECOND" => MetricName::DiskWriteBytesPerSecond,
+            "NETWORK_IN_BYTES_PER_SECOND" => MetricName::NetworkInBytesPerSecond,
+            "NETWORK_OUT_BYTES_PER_SECOND" => MetricName::NetworkOutBytesPerSecond,
+            "NETWORK_PACKETS_IN_PER_SECOND" => MetricName::NetworkPacketsInPerSecond,
+            "NETWORK_PACKETS_OUT_PER_SECOND" => MetricName::NetworkPacketsOutPerSecond,
+            "GPU_PERCENTAGE" => MetricName::GpuPercentage,
+            "GPU_MEMORY_PERCENTAGE" => MetricName::GpuMemoryPercentage,
+            _ => MetricName::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for MetricName {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<MetricName, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MetricName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for MetricName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MetricName {
+    fn deserialize<D>(deserializer: D) -> Result<MetricName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MetricStatistic {
+    /// `Maximum`
+    Maximum,
+    /// `Average`
+    Average,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl MetricStatistic {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MetricStatistic::Maximum => "Maximum",
+            MetricStatistic::Average => "Average",
+            MetricStatistic::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for MetricStatistic {
+    fn default() -> MetricStatistic {
+        MetricStatistic::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for MetricStatistic {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for MetricStatistic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for MetricStatistic {
+    fn from(value: &'a str) -> MetricStatistic {
+        match value {
+            "Maximum" => MetricStatistic::Maximum,
+            "Average" => MetricStatistic::Average,
+            _ => MetricStatistic::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MetricStatistic {
+    fn from(value: String) -> MetricStatistic {
+        match value.as_str() {
+            "Maximum" => MetricStatistic::Maximum,
+            "Average" => MetricStatistic::Average,
+            _ => MetricStatistic::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for MetricStatistic {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<MetricStatistic, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MetricStatistic {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for MetricStatistic {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MetricStatistic {
+    fn deserialize<D>(deserializer: D) -> Result<MetricStatistic, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes a projected utilization metric of a recommendation option, such as an Amazon EC2 instance.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -381,11 +863,16 @@ pub struct ProjectedMetric {
     /// <p><p>The name of the projected utilization metric.</p> <note> <p>Memory metrics are only returned for resources that have the unified CloudWatch agent installed on them. For more information, see <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/monitoring/Install-CloudWatch-Agent.html">Enabling Memory Utilization with the CloudWatch Agent</a>.</p> </note></p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
+    pub name: Option<MetricName>,
     /// <p>The time stamps of the projected utilization metric.</p>
     #[serde(rename = "timestamps")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestampList::deserialize_timestamp_list",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestampList::serialize_unix_timestamp_list",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub timestamps: Option<Vec<f64>>,
+    pub timestamps: Option<Vec<::chrono::DateTime<::chrono::Utc>>>,
     /// <p>The values of the projected utilization metrics.</p>
     #[serde(rename = "values")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -403,9 +890,122 @@ pub struct RecommendationSource {
     /// <p>The resource type of the recommendation source.</p>
     #[serde(rename = "recommendationSourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub recommendation_source_type: Option<String>,
+    pub recommendation_source_type: Option<RecommendationSourceType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RecommendationSourceType {
+    /// `Ec2Instance`
+    Ec2Instance,
+    /// `AutoScalingGroup`
+    AutoScalingGroup,
+    /// `EbsVolume`
+    EbsVolume,
+    /// `LambdaFunction`
+    LambdaFunction,
+    /// `EcsService`
+    EcsService,
+    /// `License`
+    License,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RecommendationSourceType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RecommendationSourceType::Ec2Instance => "Ec2Instance",
+            RecommendationSourceType::AutoScalingGroup => "AutoScalingGroup",
+            RecommendationSourceType::EbsVolume => "EbsVolume",
+            RecommendationSourceType::LambdaFunction => "LambdaFunction",
+            RecommendationSourceType::EcsService => "EcsService",
+            RecommendationSourceType::License => "License",
+            RecommendationSourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RecommendationSourceType {
+    fn default() -> RecommendationSourceType {
+        RecommendationSourceType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RecommendationSourceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RecommendationSourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RecommendationSourceType {
+    fn from(value: &'a str) -> RecommendationSourceType {
+        match value {
+            "Ec2Instance" => RecommendationSourceType::Ec2Instance,
+            "AutoScalingGroup" => RecommendationSourceType::AutoScalingGroup,
+            "EbsVolume" => RecommendationSourceType::EbsVolume,
+            "LambdaFunction" => RecommendationSourceType::LambdaFunction,
+            "EcsService" => RecommendationSourceType::EcsService,
+            "License" => RecommendationSourceType::License,
+            _ => RecommendationSourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RecommendationSourceType {
+    fn from(value: String) -> RecommendationSourceType {
+        match value.as_str() {
+            "Ec2Instance" => RecommendationSourceType::Ec2Instance,
+            "AutoScalingGroup" => RecommendationSourceType::AutoScalingGroup,
+            "EbsVolume" => RecommendationSourceType::EbsVolume,
+            "LambdaFunction" => RecommendationSourceType::LambdaFunction,
+            "EcsService" => RecommendationSourceType::EcsService,
+            "License" => RecommendationSourceType::License,
+            _ => RecommendationSourceType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RecommendationSourceType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RecommendationSourceType, Self::Err> {
+        Ok(value.into())
+    }
 }
 
+impl ::rusoto_core::param::ToParam for RecommendationSourceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RecommendationSourceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RecommendationSourceType {
+    fn deserialize<D>(deserializer: D) -> Result<RecommendationSourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A summary of a recommendation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -417,7 +1017,7 @@ pub struct RecommendationSummary {
     /// <p>The resource type of the recommendation.</p>
     #[serde(rename = "recommendationResourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub recommendation_resource_type: Option<String>,
+    pub recommendation_resource_type: Option<RecommendationSourceType>,
     /// <p>An array of objects that describe a recommendation summary.</p>
     #[serde(rename = "summaries")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -442,6 +1042,109 @@ pub struct RecommendedOptionProjectedMetric {
     pub recommended_instance_type: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Status {
+    /// `Active`
+    Active,
+    /// `Inactive`
+    Inactive,
+    /// `Pending`
+    Pending,
+    /// `Failed`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Status {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Status::Active => "Active",
+            Status::Inactive => "Inactive",
+            Status::Pending => "Pending",
+            Status::Failed => "Failed",
+            Status::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Status {
+    fn default() -> Status {
+        Status::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Status {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Status {
+    fn from(value: &'a str) -> Status {
+        match value {
+            "Active" => Status::Active,
+            "Inactive" => Status::Inactive,
+            "Pending" => Status::Pending,
+            "Failed" => Status::Failed,
+            _ => Status::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Status {
+    fn from(value: String) -> Status {
+        match value.as_str() {
+            "Active" => Status::Active,
+            "Inactive" => Status::Inactive,
+            "Pending" => Status::Pending,
+            "Failed" => Status::Failed,
+            _ => Status::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Status {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Status, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Status {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Status {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Status {
+    fn deserialize<D>(deserializer: D) -> Result<Status, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The summary of a recommendation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -449,7 +1152,7 @@ pub struct Summary {
     /// <p>The finding classification of the recommendation.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
+    pub name: Option<Finding>,
     /// <p>The value of the recommendation summary.</p>
     #[serde(rename = "value")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -465,7 +1168,7 @@ pub struct UpdateEnrollmentStatusRequest {
     pub include_member_accounts: Option<bool>,
     /// <p>The new enrollment status of the account.</p> <p>Accepted options are <code>Active</code> or <code>Inactive</code>. You will get an error if <code>Pending</code> or <code>Failed</code> are specified.</p>
     #[serde(rename = "status")]
-    pub status: String,
+    pub status: Status,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -474,7 +1177,7 @@ pub struct UpdateEnrollmentStatusResponse {
     /// <p>The enrollment status of the account.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<Status>,
     /// <p>The reason for the enrollment status of the account. For example, an account might show a status of <code>Pending</code> because member accounts of an organization require more time to be enrolled in the service.</p>
     #[serde(rename = "statusReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -488,11 +1191,11 @@ pub struct UtilizationMetric {
     /// <p><p>The name of the utilization metric.</p> <note> <p>Memory metrics are only returned for resources that have the unified CloudWatch agent installed on them. For more information, see <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/monitoring/Install-CloudWatch-Agent.html">Enabling Memory Utilization with the CloudWatch Agent</a>.</p> </note></p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
+    pub name: Option<MetricName>,
     /// <p>The statistic of the utilization metric.</p>
     #[serde(rename = "statistic")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub statistic: Option<String>,
+    pub statistic: Option<MetricStatistic>,
     /// <p>The value of the utilization metric.</p>
     #[serde(rename = "value")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1096,11 +1799,12 @@ pub struct ComputeOptimizerClient {
 impl ComputeOptimizerClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> ComputeOptimizerClient {
         ComputeOptimizerClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Compute Optimizer"),
         }
     }
 
@@ -1135,6 +1839,7 @@ impl ComputeOptimizer for ComputeOptimizerClient {
         RusotoError<GetAutoScalingGroupRecommendationsError>,
     > {
         let mut request = SignedRequest::new("POST", "compute-optimizer", &self.region, "/");
+        request.set_operation("GetAutoScalingGroupRecommendations");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -1171,6 +1876,7 @@ impl ComputeOptimizer for ComputeOptimizerClient {
         RusotoError<GetEC2InstanceRecommendationsError>,
     > {
         let mut request = SignedRequest::new("POST", "compute-optimizer", &self.region, "/");
+        request.set_operation("GetEC2InstanceRecommendations");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -1205,6 +1911,7 @@ impl ComputeOptimizer for ComputeOptimizerClient {
         RusotoError<GetEC2RecommendationProjectedMetricsError>,
     > {
         let mut request = SignedRequest::new("POST", "compute-optimizer", &self.region, "/");
+        request.set_operation("GetEC2RecommendationProjectedMetrics");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -1237,6 +1944,7 @@ impl ComputeOptimizer for ComputeOptimizerClient {
         &self,
     ) -> Result<GetEnrollmentStatusResponse, RusotoError<GetEnrollmentStatusError>> {
         let mut request = SignedRequest::new("POST", "compute-optimizer", &self.region, "/");
+        request.set_operation("GetEnrollmentStatus");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -1268,6 +1976,7 @@ impl ComputeOptimizer for ComputeOptimizerClient {
     ) -> Result<GetRecommendationSummariesResponse, RusotoError<GetRecommendationSummariesError>>
     {
         let mut request = SignedRequest::new("POST", "compute-optimizer", &self.region, "/");
+        request.set_operation("GetRecommendationSummaries");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -1299,6 +2008,7 @@ impl ComputeOptimizer for ComputeOptimizerClient {
         input: UpdateEnrollmentStatusRequest,
     ) -> Result<UpdateEnrollmentStatusResponse, RusotoError<UpdateEnrollmentStatusError>> {
         let mut request = SignedRequest::new("POST", "compute-optimizer", &self.region, "/");
+        request.set_operation("UpdateEnrollmentStatus");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -1324,3 +2034,42 @@ impl ComputeOptimizer for ComputeOptimizerClient {
         }
     }
 }
+impl ComputeOptimizerClient {
+    /// Auto-paginating version of `get_recommendation_summaries`, streaming every page of results.
+    pub fn get_recommendation_summaries_pages(
+        &self,
+        input: GetRecommendationSummariesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        GetRecommendationSummariesResponse,
+        GetRecommendationSummariesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ComputeOptimizerClient, input: GetRecommendationSummariesRequest| async move {
+                client.get_recommendation_summaries(input).await
+            },
+            |input: &GetRecommendationSummariesRequest,
+             output: &GetRecommendationSummariesResponse| {
+                Some(GetRecommendationSummariesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_recommendation_summaries`, streaming the `recommendationSummaries` of every page of results.
+    pub fn get_recommendation_summaries_items(
+        &self,
+        input: GetRecommendationSummariesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<RecommendationSummary, GetRecommendationSummariesError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.get_recommendation_summaries_pages(input),
+            |output: GetRecommendationSummariesResponse| {
+                output.recommendation_summaries.unwrap_or_default()
+            },
+        )
+    }
+}
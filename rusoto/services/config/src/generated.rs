@@ -95,19 +95,29 @@ pub struct AggregateEvaluationResult {
     /// <p>The resource compliance status.</p> <p>For the <code>AggregationEvaluationResult</code> data type, AWS Config supports only the <code>COMPLIANT</code> and <code>NON_COMPLIANT</code>. AWS Config does not support the <code>NOT_APPLICABLE</code> and <code>INSUFFICIENT_DATA</code> value.</p>
     #[serde(rename = "ComplianceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compliance_type: Option<String>,
+    pub compliance_type: Option<ComplianceType>,
     /// <p>The time when the AWS Config rule evaluated the AWS resource.</p>
     #[serde(rename = "ConfigRuleInvokedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub config_rule_invoked_time: Option<f64>,
+    pub config_rule_invoked_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Uniquely identifies the evaluation result.</p>
     #[serde(rename = "EvaluationResultIdentifier")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub evaluation_result_identifier: Option<EvaluationResultIdentifier>,
     /// <p>The time when AWS Config recorded the aggregate evaluation result.</p>
     #[serde(rename = "ResultRecordedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub result_recorded_time: Option<f64>,
+    pub result_recorded_time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>The details that identify a resource that is collected by AWS Config aggregator, including the resource type, ID, (if available) the custom resource name, the source account, and source region.</p>
@@ -122,7 +132,7 @@ pub struct AggregateResourceIdentifier {
     pub resource_name: Option<String>,
     /// <p>The type of the AWS resource.</p>
     #[serde(rename = "ResourceType")]
-    pub resource_type: String,
+    pub resource_type: ResourceType,
     /// <p>The 12-digit account ID of the source account.</p>
     #[serde(rename = "SourceAccountId")]
     pub source_account_id: String,
@@ -150,11 +160,16 @@ pub struct AggregatedSourceStatus {
     /// <p><p>Filters the last updated status type.</p> <ul> <li> <p>Valid value FAILED indicates errors while moving data.</p> </li> <li> <p>Valid value SUCCEEDED indicates the data was successfully moved.</p> </li> <li> <p>Valid value OUTDATED indicates the data is not the most recent.</p> </li> </ul></p>
     #[serde(rename = "LastUpdateStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_update_status: Option<String>,
+    pub last_update_status: Option<AggregatedSourceStatusType>,
     /// <p>The time of the last update.</p>
     #[serde(rename = "LastUpdateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_update_time: Option<f64>,
+    pub last_update_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The source account ID or an organization.</p>
     #[serde(rename = "SourceId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -162,9 +177,200 @@ pub struct AggregatedSourceStatus {
     /// <p>The source account or an organization.</p>
     #[serde(rename = "SourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub source_type: Option<String>,
+    pub source_type: Option<AggregatedSourceType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AggregatedSourceStatusType {
+    /// `FAILED`
+    Failed,
+    /// `SUCCEEDED`
+    Succeeded,
+    /// `OUTDATED`
+    Outdated,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AggregatedSourceStatusType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AggregatedSourceStatusType::Failed => "FAILED",
+            AggregatedSourceStatusType::Succeeded => "SUCCEEDED",
+            AggregatedSourceStatusType::Outdated => "OUTDATED",
+            AggregatedSourceStatusType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AggregatedSourceStatusType {
+    fn default() -> AggregatedSourceStatusType {
+        AggregatedSourceStatusType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AggregatedSourceStatusType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AggregatedSourceStatusType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AggregatedSourceStatusType {
+    fn from(value: &'a str) -> AggregatedSourceStatusType {
+        match value {
+            "FAILED" => AggregatedSourceStatusType::Failed,
+            "SUCCEEDED" => AggregatedSourceStatusType::Succeeded,
+            "OUTDATED" => AggregatedSourceStatusType::Outdated,
+            _ => AggregatedSourceStatusType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AggregatedSourceStatusType {
+    fn from(value: String) -> AggregatedSourceStatusType {
+        match value.as_str() {
+            "FAILED" => AggregatedSourceStatusType::Failed,
+            "SUCCEEDED" => AggregatedSourceStatusType::Succeeded,
+            "OUTDATED" => AggregatedSourceStatusType::Outdated,
+            _ => AggregatedSourceStatusType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AggregatedSourceStatusType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AggregatedSourceStatusType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AggregatedSourceStatusType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AggregatedSourceStatusType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AggregatedSourceStatusType {
+    fn deserialize<D>(deserializer: D) -> Result<AggregatedSourceStatusType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AggregatedSourceType {
+    /// `ACCOUNT`
+    Account,
+    /// `ORGANIZATION`
+    Organization,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AggregatedSourceType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AggregatedSourceType::Account => "ACCOUNT",
+            AggregatedSourceType::Organization => "ORGANIZATION",
+            AggregatedSourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AggregatedSourceType {
+    fn default() -> AggregatedSourceType {
+        AggregatedSourceType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AggregatedSourceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AggregatedSourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AggregatedSourceType {
+    fn from(value: &'a str) -> AggregatedSourceType {
+        match value {
+            "ACCOUNT" => AggregatedSourceType::Account,
+            "ORGANIZATION" => AggregatedSourceType::Organization,
+            _ => AggregatedSourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AggregatedSourceType {
+    fn from(value: String) -> AggregatedSourceType {
+        match value.as_str() {
+            "ACCOUNT" => AggregatedSourceType::Account,
+            "ORGANIZATION" => AggregatedSourceType::Organization,
+            _ => AggregatedSourceType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AggregatedSourceType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AggregatedSourceType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AggregatedSourceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AggregatedSourceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for AggregatedSourceType {
+    fn deserialize<D>(deserializer: D) -> Result<AggregatedSourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An object that represents the authorizations granted to aggregator accounts and regions.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -183,8 +389,13 @@ pub struct AggregationAuthorization {
     pub authorized_aws_region: Option<String>,
     /// <p>The time stamp when the aggregation authorization was created.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>The detailed configuration of a specified resource.</p>
@@ -213,20 +424,30 @@ pub struct BaseConfigurationItem {
     pub configuration: Option<String>,
     /// <p>The time when the configuration recording was initiated.</p>
     #[serde(rename = "configurationItemCaptureTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub configuration_item_capture_time: Option<f64>,
+    pub configuration_item_capture_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The configuration item status.</p>
     #[serde(rename = "configurationItemStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub configuration_item_status: Option<String>,
+    pub configuration_item_status: Option<ConfigurationItemStatus>,
     /// <p>An identifier that indicates the ordering of the configuration items of a resource.</p>
     #[serde(rename = "configurationStateId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub configuration_state_id: Option<String>,
     /// <p>The time stamp when the resource was created.</p>
     #[serde(rename = "resourceCreationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_creation_time: Option<f64>,
+    pub resource_creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The ID of the resource (for example., sg-xxxxxx).</p>
     #[serde(rename = "resourceId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -238,7 +459,7 @@ pub struct BaseConfigurationItem {
     /// <p>The type of AWS resource.</p>
     #[serde(rename = "resourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ResourceType>,
     /// <p>Configuration attributes that AWS Config returns for certain resource types to supplement the information returned for the configuration parameter.</p>
     #[serde(rename = "supplementaryConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -294,6 +515,99 @@ pub struct BatchGetResourceConfigResponse {
     pub unprocessed_resource_keys: Option<Vec<ResourceKey>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ChronologicalOrder {
+    /// `Reverse`
+    Reverse,
+    /// `Forward`
+    Forward,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ChronologicalOrder {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ChronologicalOrder::Reverse => "Reverse",
+            ChronologicalOrder::Forward => "Forward",
+            ChronologicalOrder::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ChronologicalOrder {
+    fn default() -> ChronologicalOrder {
+        ChronologicalOrder::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ChronologicalOrder {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ChronologicalOrder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ChronologicalOrder {
+    fn from(value: &'a str) -> ChronologicalOrder {
+        match value {
+            "Reverse" => ChronologicalOrder::Reverse,
+            "Forward" => ChronologicalOrder::Forward,
+            _ => ChronologicalOrder::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ChronologicalOrder {
+    fn from(value: String) -> ChronologicalOrder {
+        match value.as_str() {
+            "Reverse" => ChronologicalOrder::Reverse,
+            "Forward" => ChronologicalOrder::Forward,
+            _ => ChronologicalOrder::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ChronologicalOrder {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ChronologicalOrder, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ChronologicalOrder {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ChronologicalOrder {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ChronologicalOrder {
+    fn deserialize<D>(deserializer: D) -> Result<ChronologicalOrder, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Indicates whether an AWS resource or AWS Config rule is compliant and provides the number of contributors that affect the compliance.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -305,7 +619,7 @@ pub struct Compliance {
     /// <p>Indicates whether an AWS resource or AWS Config rule is compliant.</p> <p>A resource is compliant if it complies with all of the AWS Config rules that evaluate it. A resource is noncompliant if it does not comply with one or more of these rules.</p> <p>A rule is compliant if all of the resources that the rule evaluates comply with it. A rule is noncompliant if any of these resources do not comply.</p> <p>AWS Config returns the <code>INSUFFICIENT_DATA</code> value when no evaluation results are available for the AWS resource or AWS Config rule.</p> <p>For the <code>Compliance</code> data type, AWS Config supports only <code>COMPLIANT</code>, <code>NON_COMPLIANT</code>, and <code>INSUFFICIENT_DATA</code> values. AWS Config does not support the <code>NOT_APPLICABLE</code> value for the <code>Compliance</code> data type.</p>
     #[serde(rename = "ComplianceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compliance_type: Option<String>,
+    pub compliance_type: Option<ComplianceType>,
 }
 
 /// <p>Indicates whether an AWS Config rule is compliant. A rule is compliant if all of the resources that the rule evaluated comply with it. A rule is noncompliant if any of these resources do not comply.</p>
@@ -360,8 +674,13 @@ pub struct ComplianceContributorCount {
 pub struct ComplianceSummary {
     /// <p>The time that AWS Config created the compliance summary.</p>
     #[serde(rename = "ComplianceSummaryTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compliance_summary_timestamp: Option<f64>,
+    pub compliance_summary_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The number of AWS Config rules or AWS resources that are compliant, up to a maximum of 25 for rules and 100 for resources.</p>
     #[serde(rename = "CompliantResourceCount")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -386,14 +705,122 @@ pub struct ComplianceSummaryByResourceType {
     pub resource_type: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ComplianceType {
+    /// `COMPLIANT`
+    Compliant,
+    /// `NON_COMPLIANT`
+    NonCompliant,
+    /// `NOT_APPLICABLE`
+    NotApplicable,
+    /// `INSUFFICIENT_DATA`
+    InsufficientData,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ComplianceType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ComplianceType::Compliant => "COMPLIANT",
+            ComplianceType::NonCompliant => "NON_COMPLIANT",
+            ComplianceType::NotApplicable => "NOT_APPLICABLE",
+            ComplianceType::InsufficientData => "INSUFFICIENT_DATA",
+            ComplianceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ComplianceType {
+    fn default() -> ComplianceType {
+        ComplianceType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ComplianceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ComplianceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ComplianceType {
+    fn from(value: &'a str) -> ComplianceType {
+        match value {
+            "COMPLIANT" => ComplianceType::Compliant,
+            "NON_COMPLIANT" => ComplianceType::NonCompliant,
+            "NOT_APPLICABLE" => ComplianceType::NotApplicable,
+            "INSUFFICIENT_DATA" => ComplianceType::InsufficientData,
+            _ => ComplianceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ComplianceType {
+    fn from(value: String) -> ComplianceType {
+        match value.as_str() {
+            "COMPLIANT" => ComplianceType::Compliant,
+            "NON_COMPLIANT" => ComplianceType::NonCompliant,
+            "NOT_APPLICABLE" => ComplianceType::NotApplicable,
+            "INSUFFICIENT_DATA" => ComplianceType::InsufficientData,
+            _ => ComplianceType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ComplianceType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ComplianceType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ComplianceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ComplianceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ComplianceType {
+    fn deserialize<D>(deserializer: D) -> Result<ComplianceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Provides status of the delivery of the snapshot or the configuration history to the specified Amazon S3 bucket. Also provides the status of notifications about the Amazon S3 delivery to the specified Amazon SNS topic.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ConfigExportDeliveryInfo {
     /// <p>The time of the last attempted delivery.</p>
     #[serde(rename = "lastAttemptTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_attempt_time: Option<f64>,
+    pub last_attempt_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The error code from the last attempted delivery.</p>
     #[serde(rename = "lastErrorCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -405,15 +832,25 @@ pub struct ConfigExportDeliveryInfo {
     /// <p>Status of the last attempted delivery.</p>
     #[serde(rename = "lastStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_status: Option<String>,
+    pub last_status: Option<DeliveryStatus>,
     /// <p>The time of the last successful delivery.</p>
     #[serde(rename = "lastSuccessfulTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_successful_time: Option<f64>,
+    pub last_successful_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The time that the next delivery occurs.</p>
     #[serde(rename = "nextDeliveryTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_delivery_time: Option<f64>,
+    pub next_delivery_time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>An AWS Config rule represents an AWS Lambda function that you create for a custom rule or a predefined function for an AWS managed rule. The function evaluates configuration items to assess whether your AWS resources comply with your desired configurations. This function can run when AWS Config detects a configuration change to an AWS resource and at a periodic frequency that you choose (for example, every 24 hours).</p> <note> <p>You can use the AWS CLI and AWS SDKs if you want to create a rule that triggers evaluations for your resources when AWS Config delivers the configuration snapshot. For more information, see <a>ConfigSnapshotDeliveryProperties</a>.</p> </note> <p>For more information about developing and using AWS Config rules, see <a href="https://docs.aws.amazon.com/config/latest/developerguide/evaluate-config.html">Evaluating AWS Resource Configurations with AWS Config</a> in the <i>AWS Config Developer Guide</i>.</p>
@@ -434,7 +871,7 @@ pub struct ConfigRule {
     /// <p>Indicates whether the AWS Config rule is active or is currently being deleted by AWS Config. It can also indicate the evaluation status for the AWS Config rule.</p> <p>AWS Config sets the state of the rule to <code>EVALUATING</code> temporarily after you use the <code>StartConfigRulesEvaluation</code> request to evaluate your resources against the AWS Config rule.</p> <p>AWS Config sets the state of the rule to <code>DELETING_RESULTS</code> temporarily after you use the <code>DeleteEvaluationResults</code> request to delete the current evaluation results for the AWS Config rule.</p> <p>AWS Config temporarily sets the state of a rule to <code>DELETING</code> after you use the <code>DeleteConfigRule</code> request to delete the rule. After AWS Config deletes the rule, the rule and all of its evaluations are erased and are no longer available.</p>
     #[serde(rename = "ConfigRuleState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub config_rule_state: Option<String>,
+    pub config_rule_state: Option<ConfigRuleState>,
     /// <p><p>Service principal name of the service that created the rule.</p> <note> <p>The field is populated only if the service linked rule is created by a service. The field is empty if you create your own rule.</p> </note></p>
     #[serde(rename = "CreatedBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -450,7 +887,7 @@ pub struct ConfigRule {
     /// <p><p>The maximum frequency with which AWS Config runs evaluations for a rule. You can specify a value for <code>MaximumExecutionFrequency</code> when:</p> <ul> <li> <p>You are using an AWS managed rule that is triggered at a periodic frequency.</p> </li> <li> <p>Your custom rule is triggered when AWS Config delivers the configuration snapshot. For more information, see <a>ConfigSnapshotDeliveryProperties</a>.</p> </li> </ul> <note> <p>By default, rules with a periodic trigger are evaluated every 24 hours. To change the frequency, specify a valid value for the <code>MaximumExecutionFrequency</code> parameter.</p> </note></p>
     #[serde(rename = "MaximumExecutionFrequency")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub maximum_execution_frequency: Option<String>,
+    pub maximum_execution_frequency: Option<MaximumExecutionFrequency>,
     /// <p>Defines which resources can trigger an evaluation for the rule. The scope can include one or more resource types, a combination of one resource type and one resource ID, or a combination of a tag key and value. Specify a scope to constrain the resources that can trigger an evaluation for the rule. If you do not specify a scope, evaluations are triggered when any resource in the recording group changes.</p>
     #[serde(rename = "Scope")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -475,7 +912,7 @@ pub struct ConfigRuleComplianceFilters {
     /// <p>The rule compliance status.</p> <p>For the <code>ConfigRuleComplianceFilters</code> data type, AWS Config supports only <code>COMPLIANT</code> and <code>NON_COMPLIANT</code>. AWS Config does not support the <code>NOT_APPLICABLE</code> and the <code>INSUFFICIENT_DATA</code> values.</p>
     #[serde(rename = "ComplianceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compliance_type: Option<String>,
+    pub compliance_type: Option<ComplianceType>,
     /// <p>The name of the AWS Config rule.</p>
     #[serde(rename = "ConfigRuleName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -496,6 +933,99 @@ pub struct ConfigRuleComplianceSummaryFilters {
     pub aws_region: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ConfigRuleComplianceSummaryGroupKey {
+    /// `ACCOUNT_ID`
+    AccountId,
+    /// `AWS_REGION`
+    AwsRegion,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ConfigRuleComplianceSummaryGroupKey {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ConfigRuleComplianceSummaryGroupKey::AccountId => "ACCOUNT_ID",
+            ConfigRuleComplianceSummaryGroupKey::AwsRegion => "AWS_REGION",
+            ConfigRuleComplianceSummaryGroupKey::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ConfigRuleComplianceSummaryGroupKey {
+    fn default() -> ConfigRuleComplianceSummaryGroupKey {
+        ConfigRuleComplianceSummaryGroupKey::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ConfigRuleComplianceSummaryGroupKey {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ConfigRuleComplianceSummaryGroupKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ConfigRuleComplianceSummaryGroupKey {
+    fn from(value: &'a str) -> ConfigRuleComplianceSummaryGroupKey {
+        match value {
+            "ACCOUNT_ID" => ConfigRuleComplianceSummaryGroupKey::AccountId,
+            "AWS_REGION" => ConfigRuleComplianceSummaryGroupKey::AwsRegion,
+            _ => ConfigRuleComplianceSummaryGroupKey::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ConfigRuleComplianceSummaryGroupKey {
+    fn from(value: String) -> ConfigRuleComplianceSummaryGroupKey {
+        match value.as_str() {
+            "ACCOUNT_ID" => ConfigRuleComplianceSummaryGroupKey::AccountId,
+            "AWS_REGION" => ConfigRuleComplianceSummaryGroupKey::AwsRegion,
+            _ => ConfigRuleComplianceSummaryGroupKey::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ConfigRuleComplianceSummaryGroupKey {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ConfigRuleComplianceSummaryGroupKey, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ConfigRuleComplianceSummaryGroupKey {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ConfigRuleComplianceSummaryGroupKey {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ConfigRuleComplianceSummaryGroupKey {
+    fn deserialize<D>(deserializer: D) -> Result<ConfigRuleComplianceSummaryGroupKey, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Status information for your AWS managed Config rules. The status includes information such as the last time the rule ran, the last time it failed, and the related error for the last failure.</p> <p>This action does not return status information about custom AWS Config rules.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -514,8 +1044,13 @@ pub struct ConfigRuleEvaluationStatus {
     pub config_rule_name: Option<String>,
     /// <p>The time that you first activated the AWS Config rule.</p>
     #[serde(rename = "FirstActivatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub first_activated_time: Option<f64>,
+    pub first_activated_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>Indicates whether AWS Config has evaluated your resources against the rule at least once.</p> <ul> <li> <p> <code>true</code> - AWS Config has evaluated your AWS resources against the rule at least once.</p> </li> <li> <p> <code>false</code> - AWS Config has not once finished evaluating your AWS resources against the rule.</p> </li> </ul></p>
     #[serde(rename = "FirstEvaluationStarted")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -530,29 +1065,152 @@ pub struct ConfigRuleEvaluationStatus {
     pub last_error_message: Option<String>,
     /// <p>The time that AWS Config last failed to evaluate your AWS resources against the rule.</p>
     #[serde(rename = "LastFailedEvaluationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_failed_evaluation_time: Option<f64>,
+    pub last_failed_evaluation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The time that AWS Config last failed to invoke the AWS Config rule to evaluate your AWS resources.</p>
     #[serde(rename = "LastFailedInvocationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_failed_invocation_time: Option<f64>,
+    pub last_failed_invocation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The time that AWS Config last successfully evaluated your AWS resources against the rule.</p>
     #[serde(rename = "LastSuccessfulEvaluationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_successful_evaluation_time: Option<f64>,
+    pub last_successful_evaluation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The time that AWS Config last successfully invoked the AWS Config rule to evaluate your AWS resources.</p>
     #[serde(rename = "LastSuccessfulInvocationTime")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_successful_invocation_time: Option<f64>,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_successful_invocation_time: Option<::chrono::DateTime<::chrono::Utc>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ConfigRuleState {
+    /// `ACTIVE`
+    Active,
+    /// `DELETING`
+    Deleting,
+    /// `DELETING_RESULTS`
+    DeletingResults,
+    /// `EVALUATING`
+    Evaluating,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ConfigRuleState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ConfigRuleState::Active => "ACTIVE",
+            ConfigRuleState::Deleting => "DELETING",
+            ConfigRuleState::DeletingResults => "DELETING_RESULTS",
+            ConfigRuleState::Evaluating => "EVALUATING",
+            ConfigRuleState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ConfigRuleState {
+    fn default() -> ConfigRuleState {
+        ConfigRuleState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ConfigRuleState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ConfigRuleState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ConfigRuleState {
+    fn from(value: &'a str) -> ConfigRuleState {
+        match value {
+            "ACTIVE" => ConfigRuleState::Active,
+            "DELETING" => ConfigRuleState::Deleting,
+            "DELETING_RESULTS" => ConfigRuleState::DeletingResults,
+            "EVALUATING" => ConfigRuleState::Evaluating,
+            _ => ConfigRuleState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ConfigRuleState {
+    fn from(value: String) -> ConfigRuleState {
+        match value.as_str() {
+            "ACTIVE" => ConfigRuleState::Active,
+            "DELETING" => ConfigRuleState::Deleting,
+            "DELETING_RESULTS" => ConfigRuleState::DeletingResults,
+            "EVALUATING" => ConfigRuleState::Evaluating,
+            _ => ConfigRuleState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ConfigRuleState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ConfigRuleState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ConfigRuleState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ConfigRuleState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for ConfigRuleState {
+    fn deserialize<D>(deserializer: D) -> Result<ConfigRuleState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Provides options for how often AWS Config delivers configuration snapshots to the Amazon S3 bucket in your delivery channel.</p> <p>The frequency for a rule that triggers evaluations for your resources when AWS Config delivers the configuration snapshot is set by one of two values, depending on which is less frequent:</p> <ul> <li> <p>The value for the <code>deliveryFrequency</code> parameter within the delivery channel configuration, which sets how often AWS Config delivers configuration snapshots. This value also sets how often AWS Config invokes evaluations for AWS Config rules.</p> </li> <li> <p>The value for the <code>MaximumExecutionFrequency</code> parameter, which sets the maximum frequency with which AWS Config invokes evaluations for the rule. For more information, see <a>ConfigRule</a>.</p> </li> </ul> <p>If the <code>deliveryFrequency</code> value is less frequent than the <code>MaximumExecutionFrequency</code> value for a rule, AWS Config invokes the rule only as often as the <code>deliveryFrequency</code> value.</p> <ol> <li> <p>For example, you want your rule to run evaluations when AWS Config delivers the configuration snapshot.</p> </li> <li> <p>You specify the <code>MaximumExecutionFrequency</code> value for <code>Six_Hours</code>. </p> </li> <li> <p>You then specify the delivery channel <code>deliveryFrequency</code> value for <code>TwentyFour_Hours</code>.</p> </li> <li> <p>Because the value for <code>deliveryFrequency</code> is less frequent than <code>MaximumExecutionFrequency</code>, AWS Config invokes evaluations for the rule every 24 hours. </p> </li> </ol> <p>You should set the <code>MaximumExecutionFrequency</code> value to be at least as frequent as the <code>deliveryFrequency</code> value. You can view the <code>deliveryFrequency</code> value by using the <code>DescribeDeliveryChannnels</code> action.</p> <p>To update the <code>deliveryFrequency</code> with which AWS Config delivers your configuration snapshots, use the <code>PutDeliveryChannel</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ConfigSnapshotDeliveryProperties {
     /// <p>The frequency with which AWS Config delivers configuration snapshots.</p>
     #[serde(rename = "deliveryFrequency")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub delivery_frequency: Option<String>,
+    pub delivery_frequency: Option<MaximumExecutionFrequency>,
 }
 
 /// <p>A list that contains the status of the delivery of the configuration stream notification to the Amazon SNS topic.</p>
@@ -570,11 +1228,16 @@ pub struct ConfigStreamDeliveryInfo {
     /// <p>Status of the last attempted delivery.</p> <p> <b>Note</b> Providing an SNS topic on a <a href="https://docs.aws.amazon.com/config/latest/APIReference/API_DeliveryChannel.html">DeliveryChannel</a> for AWS Config is optional. If the SNS delivery is turned off, the last status will be <b>Not_Applicable</b>.</p>
     #[serde(rename = "lastStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_status: Option<String>,
+    pub last_status: Option<DeliveryStatus>,
     /// <p>The time from the last status change.</p>
     #[serde(rename = "lastStatusChangeTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_status_change_time: Option<f64>,
+    pub last_status_change_time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>The details about the configuration aggregator, including information about source accounts, regions, and metadata of the aggregator. </p>
@@ -595,12 +1258,22 @@ pub struct ConfigurationAggregator {
     pub configuration_aggregator_name: Option<String>,
     /// <p>The time stamp when the configuration aggregator was created.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The time of the last update.</p>
     #[serde(rename = "LastUpdatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_time: Option<f64>,
+    pub last_updated_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Provides an organization and list of regions to be aggregated.</p>
     #[serde(rename = "OrganizationAggregationSource")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -633,8 +1306,13 @@ pub struct ConfigurationItem {
     pub configuration: Option<String>,
     /// <p>The time when the configuration recording was initiated.</p>
     #[serde(rename = "configurationItemCaptureTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub configuration_item_capture_time: Option<f64>,
+    pub configuration_item_capture_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Unique MD5 hash that represents the configuration item's state.</p> <p>You can use MD5 hash to compare the states of two or more configuration items that are associated with the same resource.</p>
     #[serde(rename = "configurationItemMD5Hash")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -642,7 +1320,7 @@ pub struct ConfigurationItem {
     /// <p>The configuration item status.</p>
     #[serde(rename = "configurationItemStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub configuration_item_status: Option<String>,
+    pub configuration_item_status: Option<ConfigurationItemStatus>,
     /// <p>An identifier that indicates the ordering of the configuration items of a resource.</p>
     #[serde(rename = "configurationStateId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -657,8 +1335,13 @@ pub struct ConfigurationItem {
     pub relationships: Option<Vec<Relationship>>,
     /// <p>The time stamp when the resource was created.</p>
     #[serde(rename = "resourceCreationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_creation_time: Option<f64>,
+    pub resource_creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The ID of the resource (for example, <code>sg-xxxxxx</code>).</p>
     #[serde(rename = "resourceId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -670,7 +1353,7 @@ pub struct ConfigurationItem {
     /// <p>The type of AWS resource.</p>
     #[serde(rename = "resourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ResourceType>,
     /// <p>Configuration attributes that AWS Config returns for certain resource types to supplement the information returned for the <code>configuration</code> parameter.</p>
     #[serde(rename = "supplementaryConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -685,18 +1368,126 @@ pub struct ConfigurationItem {
     pub version: Option<String>,
 }
 
-/// <p>An object that represents the recording of configuration changes of an AWS resource.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct ConfigurationRecorder {
-    /// <p>The name of the recorder. By default, AWS Config automatically assigns the name "default" when creating the configuration recorder. You cannot change the assigned name.</p>
-    #[serde(rename = "name")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
-    /// <p>Specifies the types of AWS resources for which AWS Config records configuration changes.</p>
-    #[serde(rename = "recordingGroup")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub recording_group: Option<RecordingGroup>,
-    /// <p>Amazon Resource Name (ARN) of the IAM role used to describe the AWS resources associated with the account.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ConfigurationItemStatus {
+    /// `OK`
+    Ok,
+    /// `ResourceDiscovered`
+    ResourceDiscovered,
+    /// `ResourceNotRecorded`
+    ResourceNotRecorded,
+    /// `ResourceDeleted`
+    ResourceDeleted,
+    /// `ResourceDeletedNotRecorded`
+    ResourceDeletedNotRecorded,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ConfigurationItemStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ConfigurationItemStatus::Ok => "OK",
+            ConfigurationItemStatus::ResourceDiscovered => "ResourceDiscovered",
+            ConfigurationItemStatus::ResourceNotRecorded => "ResourceNotRecorded",
+            ConfigurationItemStatus::ResourceDeleted => "ResourceDeleted",
+            ConfigurationItemStatus::ResourceDeletedNotRecorded => "ResourceDeletedNotRecorded",
+            ConfigurationItemStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ConfigurationItemStatus {
+    fn default() -> ConfigurationItemStatus {
+        ConfigurationItemStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ConfigurationItemStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ConfigurationItemStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ConfigurationItemStatus {
+    fn from(value: &'a str) -> ConfigurationItemStatus {
+        match value {
+            "OK" => ConfigurationItemStatus::Ok,
+            "ResourceDiscovered" => ConfigurationItemStatus::ResourceDiscovered,
+            "ResourceNotRecorded" => ConfigurationItemStatus::ResourceNotRecorded,
+            "ResourceDeleted" => ConfigurationItemStatus::ResourceDeleted,
+            "ResourceDeletedNotRecorded" => ConfigurationItemStatus::ResourceDeletedNotRecorded,
+            _ => ConfigurationItemStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ConfigurationItemStatus {
+    fn from(value: String) -> ConfigurationItemStatus {
+        match value.as_str() {
+            "OK" => ConfigurationItemStatus::Ok,
+            "ResourceDiscovered" => ConfigurationItemStatus::ResourceDiscovered,
+            "ResourceNotRecorded" => ConfigurationItemStatus::ResourceNotRecorded,
+            "ResourceDeleted" => ConfigurationItemStatus::ResourceDeleted,
+            "ResourceDeletedNotRecorded" => ConfigurationItemStatus::ResourceDeletedNotRecorded,
+            _ => ConfigurationItemStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ConfigurationItemStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ConfigurationItemStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ConfigurationItemStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ConfigurationItemStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ConfigurationItemStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ConfigurationItemStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>An object that represents the recording of configuration changes of an AWS resource.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConfigurationRecorder {
+    /// <p>The name of the recorder. By default, AWS Config automatically assigns the name "default" when creating the configuration recorder. You cannot change the assigned name.</p>
+    #[serde(rename = "name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// <p>Specifies the types of AWS resources for which AWS Config records configuration changes.</p>
+    #[serde(rename = "recordingGroup")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recording_group: Option<RecordingGroup>,
+    /// <p>Amazon Resource Name (ARN) of the IAM role used to describe the AWS resources associated with the account.</p>
     #[serde(rename = "roleARN")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub role_arn: Option<String>,
@@ -716,20 +1507,35 @@ pub struct ConfigurationRecorderStatus {
     pub last_error_message: Option<String>,
     /// <p>The time the recorder was last started.</p>
     #[serde(rename = "lastStartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_start_time: Option<f64>,
+    pub last_start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The last (previous) status of the recorder.</p>
     #[serde(rename = "lastStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_status: Option<String>,
+    pub last_status: Option<RecorderStatus>,
     /// <p>The time when the status was last changed.</p>
     #[serde(rename = "lastStatusChangeTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_status_change_time: Option<f64>,
+    pub last_status_change_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The time the recorder was last stopped.</p>
     #[serde(rename = "lastStopTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_stop_time: Option<f64>,
+    pub last_stop_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the configuration recorder.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -747,7 +1553,7 @@ pub struct ConformancePackComplianceFilters {
     /// <p>Filters the results by compliance.</p> <p>The allowed values are <code>COMPLIANT</code> and <code>NON_COMPLIANT</code>.</p>
     #[serde(rename = "ComplianceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compliance_type: Option<String>,
+    pub compliance_type: Option<ConformancePackComplianceType>,
     /// <p>Filters the results by AWS Config rule names.</p>
     #[serde(rename = "ConfigRuleNames")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -760,12 +1566,110 @@ pub struct ConformancePackComplianceFilters {
 pub struct ConformancePackComplianceSummary {
     /// <p>The status of the conformance pack. The allowed values are COMPLIANT and NON_COMPLIANT. </p>
     #[serde(rename = "ConformancePackComplianceStatus")]
-    pub conformance_pack_compliance_status: String,
+    pub conformance_pack_compliance_status: ConformancePackComplianceType,
     /// <p>The name of the conformance pack name.</p>
     #[serde(rename = "ConformancePackName")]
     pub conformance_pack_name: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ConformancePackComplianceType {
+    /// `COMPLIANT`
+    Compliant,
+    /// `NON_COMPLIANT`
+    NonCompliant,
+    /// `INSUFFICIENT_DATA`
+    InsufficientData,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ConformancePackComplianceType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ConformancePackComplianceType::Compliant => "COMPLIANT",
+            ConformancePackComplianceType::NonCompliant => "NON_COMPLIANT",
+            ConformancePackComplianceType::InsufficientData => "INSUFFICIENT_DATA",
+            ConformancePackComplianceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ConformancePackComplianceType {
+    fn default() -> ConformancePackComplianceType {
+        ConformancePackComplianceType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ConformancePackComplianceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ConformancePackComplianceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ConformancePackComplianceType {
+    fn from(value: &'a str) -> ConformancePackComplianceType {
+        match value {
+            "COMPLIANT" => ConformancePackComplianceType::Compliant,
+            "NON_COMPLIANT" => ConformancePackComplianceType::NonCompliant,
+            "INSUFFICIENT_DATA" => ConformancePackComplianceType::InsufficientData,
+            _ => ConformancePackComplianceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ConformancePackComplianceType {
+    fn from(value: String) -> ConformancePackComplianceType {
+        match value.as_str() {
+            "COMPLIANT" => ConformancePackComplianceType::Compliant,
+            "NON_COMPLIANT" => ConformancePackComplianceType::NonCompliant,
+            "INSUFFICIENT_DATA" => ConformancePackComplianceType::InsufficientData,
+            _ => ConformancePackComplianceType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ConformancePackComplianceType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ConformancePackComplianceType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ConformancePackComplianceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ConformancePackComplianceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ConformancePackComplianceType {
+    fn deserialize<D>(deserializer: D) -> Result<ConformancePackComplianceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Returns details of a conformance pack. A conformance pack is a collection of AWS Config rules and remediation actions that can be easily deployed in an account and a region.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -796,8 +1700,13 @@ pub struct ConformancePackDetail {
     pub delivery_s3_key_prefix: Option<String>,
     /// <p>Last time when conformation pack update was requested. </p>
     #[serde(rename = "LastUpdateRequestedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_update_requested_time: Option<f64>,
+    pub last_update_requested_time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>Filters a conformance pack by AWS Config rule names, compliance types, AWS resource types, and resource IDs.</p>
@@ -807,7 +1716,7 @@ pub struct ConformancePackEvaluationFilters {
     /// <p>Filters the results by compliance.</p> <p>The allowed values are <code>COMPLIANT</code> and <code>NON_COMPLIANT</code>.</p>
     #[serde(rename = "ComplianceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compliance_type: Option<String>,
+    pub compliance_type: Option<ConformancePackComplianceType>,
     /// <p>Filters the results by AWS Config rule names.</p>
     #[serde(rename = "ConfigRuleNames")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -832,15 +1741,25 @@ pub struct ConformancePackEvaluationResult {
     pub annotation: Option<String>,
     /// <p>The compliance type. The allowed values are <code>COMPLIANT</code> and <code>NON_COMPLIANT</code>. </p>
     #[serde(rename = "ComplianceType")]
-    pub compliance_type: String,
+    pub compliance_type: ConformancePackComplianceType,
     /// <p>The time when AWS Config rule evaluated AWS resource.</p>
     #[serde(rename = "ConfigRuleInvokedTime")]
-    pub config_rule_invoked_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub config_rule_invoked_time: ::chrono::DateTime<::chrono::Utc>,
     #[serde(rename = "EvaluationResultIdentifier")]
     pub evaluation_result_identifier: EvaluationResultIdentifier,
     /// <p>The time when AWS Config recorded the evaluation result. </p>
     #[serde(rename = "ResultRecordedTime")]
-    pub result_recorded_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub result_recorded_time: ::chrono::DateTime<::chrono::Utc>,
 }
 
 /// <p>Input parameters in the form of key-value pairs for the conformance pack, both of which you define. Keys can have a maximum character length of 128 characters, and values can have a maximum length of 256 characters.</p>
@@ -861,13 +1780,121 @@ pub struct ConformancePackRuleCompliance {
     /// <p>Compliance of the AWS Config rule</p> <p>The allowed values are <code>COMPLIANT</code> and <code>NON_COMPLIANT</code>.</p>
     #[serde(rename = "ComplianceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compliance_type: Option<String>,
+    pub compliance_type: Option<ConformancePackComplianceType>,
     /// <p>Name of the config rule.</p>
     #[serde(rename = "ConfigRuleName")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub config_rule_name: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ConformancePackState {
+    /// `CREATE_IN_PROGRESS`
+    CreateInProgress,
+    /// `CREATE_COMPLETE`
+    CreateComplete,
+    /// `CREATE_FAILED`
+    CreateFailed,
+    /// `DELETE_IN_PROGRESS`
+    DeleteInProgress,
+    /// `DELETE_FAILED`
+    DeleteFailed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ConformancePackState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ConformancePackState::CreateInProgress => "CREATE_IN_PROGRESS",
+            ConformancePackState::CreateComplete => "CREATE_COMPLETE",
+            ConformancePackState::CreateFailed => "CREATE_FAILED",
+            ConformancePackState::DeleteInProgress => "DELETE_IN_PROGRESS",
+            ConformancePackState::DeleteFailed => "DELETE_FAILED",
+            ConformancePackState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ConformancePackState {
+    fn default() -> ConformancePackState {
+        ConformancePackState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ConformancePackState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ConformancePackState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ConformancePackState {
+    fn from(value: &'a str) -> ConformancePackState {
+        match value {
+            "CREATE_IN_PROGRESS" => ConformancePackState::CreateInProgress,
+            "CREATE_COMPLETE" => ConformancePackState::CreateComplete,
+            "CREATE_FAILED" => ConformancePackState::CreateFailed,
+            "DELETE_IN_PROGRESS" => ConformancePackState::DeleteInProgress,
+            "DELETE_FAILED" => ConformancePackState::DeleteFailed,
+            _ => ConformancePackState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ConformancePackState {
+    fn from(value: String) -> ConformancePackState {
+        match value.as_str() {
+            "CREATE_IN_PROGRESS" => ConformancePackState::CreateInProgress,
+            "CREATE_COMPLETE" => ConformancePackState::CreateComplete,
+            "CREATE_FAILED" => ConformancePackState::CreateFailed,
+            "DELETE_IN_PROGRESS" => ConformancePackState::DeleteInProgress,
+            "DELETE_FAILED" => ConformancePackState::DeleteFailed,
+            _ => ConformancePackState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ConformancePackState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ConformancePackState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ConformancePackState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ConformancePackState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ConformancePackState {
+    fn deserialize<D>(deserializer: D) -> Result<ConformancePackState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Status details of a conformance pack.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -883,18 +1910,28 @@ pub struct ConformancePackStatusDetail {
     pub conformance_pack_name: String,
     /// <p><p>Indicates deployment status of conformance pack.</p> <p>AWS Config sets the state of the conformance pack to:</p> <ul> <li> <p>CREATE<em>IN</em>PROGRESS when a conformance pack creation is in progress for an account.</p> </li> <li> <p>CREATE<em>COMPLETE when a conformance pack has been successfully created in your account.</p> </li> <li> <p>CREATE</em>FAILED when a conformance pack creation failed in your account.</p> </li> <li> <p>DELETE<em>IN</em>PROGRESS when a conformance pack deletion is in progress. </p> </li> <li> <p>DELETE_FAILED when a conformance pack deletion failed in your account.</p> </li> </ul></p>
     #[serde(rename = "ConformancePackState")]
-    pub conformance_pack_state: String,
+    pub conformance_pack_state: ConformancePackState,
     /// <p>The reason of conformance pack creation failure.</p>
     #[serde(rename = "ConformancePackStatusReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub conformance_pack_status_reason: Option<String>,
     /// <p>Last time when conformation pack creation and update was successful.</p>
     #[serde(rename = "LastUpdateCompletedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_update_completed_time: Option<f64>,
+    pub last_update_completed_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Last time when conformation pack creation and update was requested.</p>
     #[serde(rename = "LastUpdateRequestedTime")]
-    pub last_update_requested_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub last_update_requested_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>Amazon Resource Name (ARN) of AWS CloudFormation stack. </p>
     #[serde(rename = "StackArn")]
     pub stack_arn: String,
@@ -1116,6 +2153,104 @@ pub struct DeliveryChannelStatus {
     pub name: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DeliveryStatus {
+    /// `Success`
+    Success,
+    /// `Failure`
+    Failure,
+    /// `Not_Applicable`
+    NotApplicable,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DeliveryStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeliveryStatus::Success => "Success",
+            DeliveryStatus::Failure => "Failure",
+            DeliveryStatus::NotApplicable => "Not_Applicable",
+            DeliveryStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DeliveryStatus {
+    fn default() -> DeliveryStatus {
+        DeliveryStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DeliveryStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DeliveryStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DeliveryStatus {
+    fn from(value: &'a str) -> DeliveryStatus {
+        match value {
+            "Success" => DeliveryStatus::Success,
+            "Failure" => DeliveryStatus::Failure,
+            "Not_Applicable" => DeliveryStatus::NotApplicable,
+            _ => DeliveryStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeliveryStatus {
+    fn from(value: String) -> DeliveryStatus {
+        match value.as_str() {
+            "Success" => DeliveryStatus::Success,
+            "Failure" => DeliveryStatus::Failure,
+            "Not_Applicable" => DeliveryStatus::NotApplicable,
+            _ => DeliveryStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DeliveryStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DeliveryStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DeliveryStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DeliveryStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeliveryStatus {
+    fn deserialize<D>(deserializer: D) -> Result<DeliveryStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct DescribeAggregateComplianceByConfigRulesRequest {
@@ -1182,7 +2317,7 @@ pub struct DescribeComplianceByConfigRuleRequest {
     /// <p>Filters the results by compliance.</p> <p>The allowed values are <code>COMPLIANT</code> and <code>NON_COMPLIANT</code>.</p>
     #[serde(rename = "ComplianceTypes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compliance_types: Option<Vec<String>>,
+    pub compliance_types: Option<Vec<ComplianceType>>,
     /// <p>Specify one or more AWS Config rule names to filter the results by rule.</p>
     #[serde(rename = "ConfigRuleNames")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1214,7 +2349,7 @@ pub struct DescribeComplianceByResourceRequest {
     /// <p>Filters the results by compliance.</p> <p>The allowed values are <code>COMPLIANT</code>, <code>NON_COMPLIANT</code>, and <code>INSUFFICIENT_DATA</code>.</p>
     #[serde(rename = "ComplianceTypes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compliance_types: Option<Vec<String>>,
+    pub compliance_types: Option<Vec<ComplianceType>>,
     /// <p>The maximum number of evaluation results returned on each page. The default is 10. You cannot specify a number greater than 100. If you specify 0, AWS Config uses the default.</p>
     #[serde(rename = "Limit")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1324,7 +2459,7 @@ pub struct DescribeConfigurationAggregatorSourcesStatusRequest {
     /// <p><p>Filters the status type.</p> <ul> <li> <p>Valid value FAILED indicates errors while moving data.</p> </li> <li> <p>Valid value SUCCEEDED indicates the data was successfully moved.</p> </li> <li> <p>Valid value OUTDATED indicates the data is not the most recent.</p> </li> </ul></p>
     #[serde(rename = "UpdateStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub update_status: Option<Vec<String>>,
+    pub update_status: Option<Vec<AggregatedSourceStatusType>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1815,10 +2950,15 @@ pub struct Evaluation {
     pub compliance_resource_type: String,
     /// <p>Indicates whether the AWS resource complies with the AWS Config rule that it was evaluated against.</p> <p>For the <code>Evaluation</code> data type, AWS Config supports only the <code>COMPLIANT</code>, <code>NON_COMPLIANT</code>, and <code>NOT_APPLICABLE</code> values. AWS Config does not support the <code>INSUFFICIENT_DATA</code> value for this data type.</p> <p>Similarly, AWS Config does not accept <code>INSUFFICIENT_DATA</code> as the value for <code>ComplianceType</code> from a <code>PutEvaluations</code> request. For example, an AWS Lambda function for a custom AWS Config rule cannot pass an <code>INSUFFICIENT_DATA</code> value to AWS Config.</p>
     #[serde(rename = "ComplianceType")]
-    pub compliance_type: String,
+    pub compliance_type: ComplianceType,
     /// <p>The time of the event in AWS Config that triggered the evaluation. For event-based evaluations, the time indicates when AWS Config created the configuration item that triggered the evaluation. For periodic evaluations, the time indicates when AWS Config triggered the evaluation at the frequency that you specified (for example, every 24 hours).</p>
     #[serde(rename = "OrderingTimestamp")]
-    pub ordering_timestamp: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub ordering_timestamp: ::chrono::DateTime<::chrono::Utc>,
 }
 
 /// <p>The details of an AWS Config evaluation. Provides the AWS resource that was evaluated, the compliance of the resource, related time stamps, and supplementary information.</p>
@@ -1832,19 +2972,29 @@ pub struct EvaluationResult {
     /// <p>Indicates whether the AWS resource complies with the AWS Config rule that evaluated it.</p> <p>For the <code>EvaluationResult</code> data type, AWS Config supports only the <code>COMPLIANT</code>, <code>NON_COMPLIANT</code>, and <code>NOT_APPLICABLE</code> values. AWS Config does not support the <code>INSUFFICIENT_DATA</code> value for the <code>EvaluationResult</code> data type.</p>
     #[serde(rename = "ComplianceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compliance_type: Option<String>,
+    pub compliance_type: Option<ComplianceType>,
     /// <p>The time when the AWS Config rule evaluated the AWS resource.</p>
     #[serde(rename = "ConfigRuleInvokedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub config_rule_invoked_time: Option<f64>,
+    pub config_rule_invoked_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Uniquely identifies the evaluation result.</p>
     #[serde(rename = "EvaluationResultIdentifier")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub evaluation_result_identifier: Option<EvaluationResultIdentifier>,
     /// <p>The time when AWS Config recorded the evaluation result.</p>
     #[serde(rename = "ResultRecordedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub result_recorded_time: Option<f64>,
+    pub result_recorded_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>An encrypted token that associates an evaluation with an AWS Config rule. The token identifies the rule, the AWS resource being evaluated, and the event that triggered the evaluation.</p>
     #[serde(rename = "ResultToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1861,8 +3011,13 @@ pub struct EvaluationResultIdentifier {
     pub evaluation_result_qualifier: Option<EvaluationResultQualifier>,
     /// <p>The time of the event that triggered the evaluation of your AWS resources. The time can indicate when AWS Config delivered a configuration item change notification, or it can indicate when AWS Config delivered the configuration snapshot, depending on which event triggered the evaluation.</p>
     #[serde(rename = "OrderingTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ordering_timestamp: Option<f64>,
+    pub ordering_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>Identifies an AWS Config rule that evaluated an AWS resource, and provides the type and ID of the resource that the rule evaluated.</p>
@@ -1883,6 +3038,94 @@ pub struct EvaluationResultQualifier {
     pub resource_type: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EventSource {
+    /// `aws.config`
+    AwsConfig,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EventSource {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EventSource::AwsConfig => "aws.config",
+            EventSource::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EventSource {
+    fn default() -> EventSource {
+        EventSource::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EventSource {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EventSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EventSource {
+    fn from(value: &'a str) -> EventSource {
+        match value {
+            "aws.config" => EventSource::AwsConfig,
+            _ => EventSource::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EventSource {
+    fn from(value: String) -> EventSource {
+        match value.as_str() {
+            "aws.config" => EventSource::AwsConfig,
+            _ => EventSource::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EventSource {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EventSource, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EventSource {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EventSource {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EventSource {
+    fn deserialize<D>(deserializer: D) -> Result<EventSource, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The controls that AWS Config uses for executing remediations.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ExecutionControls {
@@ -1956,7 +3199,7 @@ pub struct GetAggregateComplianceDetailsByConfigRuleRequest {
     /// <p><p>The resource compliance status.</p> <note> <p>For the <code>GetAggregateComplianceDetailsByConfigRuleRequest</code> data type, AWS Config supports only the <code>COMPLIANT</code> and <code>NON<em>COMPLIANT</code>. AWS Config does not support the <code>NOT</em>APPLICABLE</code> and <code>INSUFFICIENT_DATA</code> values.</p> </note></p>
     #[serde(rename = "ComplianceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compliance_type: Option<String>,
+    pub compliance_type: Option<ComplianceType>,
     /// <p>The name of the AWS Config rule for which you want compliance information.</p>
     #[serde(rename = "ConfigRuleName")]
     pub config_rule_name: String,
@@ -1999,7 +3242,7 @@ pub struct GetAggregateConfigRuleComplianceSummaryRequest {
     /// <p>Groups the result based on ACCOUNT_ID or AWS_REGION.</p>
     #[serde(rename = "GroupByKey")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub group_by_key: Option<String>,
+    pub group_by_key: Option<ConfigRuleComplianceSummaryGroupKey>,
     /// <p>The maximum number of evaluation results returned on each page. The default is 1000. You cannot specify a number greater than 1000. If you specify 0, AWS Config uses the default.</p>
     #[serde(rename = "Limit")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2040,7 +3283,7 @@ pub struct GetAggregateDiscoveredResourceCountsRequest {
     /// <p>The key to group the resource counts.</p>
     #[serde(rename = "GroupByKey")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub group_by_key: Option<String>,
+    pub group_by_key: Option<ResourceCountGroupKey>,
     /// <p>The maximum number of <a>GroupedResourceCount</a> objects returned on each page. The default is 1000. You cannot specify a number greater than 1000. If you specify 0, AWS Config uses the default.</p>
     #[serde(rename = "Limit")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2098,7 +3341,7 @@ pub struct GetComplianceDetailsByConfigRuleRequest {
     /// <p>Filters the results by compliance.</p> <p>The allowed values are <code>COMPLIANT</code>, <code>NON_COMPLIANT</code>, and <code>NOT_APPLICABLE</code>.</p>
     #[serde(rename = "ComplianceTypes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compliance_types: Option<Vec<String>>,
+    pub compliance_types: Option<Vec<ComplianceType>>,
     /// <p>The name of the AWS Config rule for which you want compliance information.</p>
     #[serde(rename = "ConfigRuleName")]
     pub config_rule_name: String,
@@ -2133,7 +3376,7 @@ pub struct GetComplianceDetailsByResourceRequest {
     /// <p>Filters the results by compliance.</p> <p>The allowed values are <code>COMPLIANT</code>, <code>NON_COMPLIANT</code>, and <code>NOT_APPLICABLE</code>.</p>
     #[serde(rename = "ComplianceTypes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compliance_types: Option<Vec<String>>,
+    pub compliance_types: Option<Vec<ComplianceType>>,
     /// <p>The <code>nextToken</code> string returned on a previous page that you use to get the next page of results in a paginated response.</p>
     #[serde(rename = "NextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2363,15 +3606,25 @@ pub struct GetResourceConfigHistoryRequest {
     /// <p>The chronological order for configuration items listed. By default, the results are listed in reverse chronological order.</p>
     #[serde(rename = "chronologicalOrder")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub chronological_order: Option<String>,
+    pub chronological_order: Option<ChronologicalOrder>,
     /// <p>The time stamp that indicates an earlier time. If not specified, the action returns paginated results that contain configuration items that start when the first configuration item was recorded.</p>
     #[serde(rename = "earlierTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub earlier_time: Option<f64>,
+    pub earlier_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The time stamp that indicates a later time. If not specified, current time is taken.</p>
     #[serde(rename = "laterTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub later_time: Option<f64>,
+    pub later_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The maximum number of configuration items returned on each page. The default is 10. You cannot specify a number greater than 100. If you specify 0, AWS Config uses the default.</p>
     #[serde(rename = "limit")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2385,7 +3638,7 @@ pub struct GetResourceConfigHistoryRequest {
     pub resource_id: String,
     /// <p>The resource type.</p>
     #[serde(rename = "resourceType")]
-    pub resource_type: String,
+    pub resource_type: ResourceType,
 }
 
 /// <p>The output for the <a>GetResourceConfigHistory</a> action.</p>
@@ -2434,7 +3687,7 @@ pub struct ListAggregateDiscoveredResourcesRequest {
     pub next_token: Option<String>,
     /// <p>The type of resources that you want AWS Config to list in the response.</p>
     #[serde(rename = "ResourceType")]
-    pub resource_type: String,
+    pub resource_type: ResourceType,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -2476,7 +3729,7 @@ pub struct ListDiscoveredResourcesRequest {
     pub resource_name: Option<String>,
     /// <p>The type of resources that you want AWS Config to list in the response.</p>
     #[serde(rename = "resourceType")]
-    pub resource_type: String,
+    pub resource_type: ResourceType,
 }
 
 /// <p><p/></p>
@@ -2522,45 +3775,407 @@ pub struct ListTagsForResourceResponse {
     pub tags: Option<Vec<Tag>>,
 }
 
-/// <p>Organization config rule creation or deletion status in each member account. This includes the name of the rule, the status, error code and error message when the rule creation or deletion failed.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct MemberAccountStatus {
-    /// <p>The 12-digit account ID of a member account.</p>
-    #[serde(rename = "AccountId")]
-    pub account_id: String,
-    /// <p>The name of config rule deployed in the member account.</p>
-    #[serde(rename = "ConfigRuleName")]
-    pub config_rule_name: String,
-    /// <p>An error code that is returned when config rule creation or deletion failed in the member account.</p>
-    #[serde(rename = "ErrorCode")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub error_code: Option<String>,
-    /// <p>An error message indicating that config rule account creation or deletion has failed due to an error in the member account.</p>
-    #[serde(rename = "ErrorMessage")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub error_message: Option<String>,
-    /// <p>The timestamp of the last status update.</p>
-    #[serde(rename = "LastUpdateTime")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_update_time: Option<f64>,
-    /// <p><p>Indicates deployment status for config rule in the member account. When master account calls <code>PutOrganizationConfigRule</code> action for the first time, config rule status is created in the member account. When master account calls <code>PutOrganizationConfigRule</code> action for the second time, config rule status is updated in the member account. Config rule status is deleted when the master account deletes <code>OrganizationConfigRule</code> and disables service access for <code>config-multiaccountsetup.amazonaws.com</code>. </p> <p> AWS Config sets the state of the rule to:</p> <ul> <li> <p> <code>CREATE<em>SUCCESSFUL</code> when config rule has been created in the member account. </p> </li> <li> <p> <code>CREATE</em>IN<em>PROGRESS</code> when config rule is being created in the member account.</p> </li> <li> <p> <code>CREATE</em>FAILED</code> when config rule creation has failed in the member account.</p> </li> <li> <p> <code>DELETE<em>FAILED</code> when config rule deletion has failed in the member account.</p> </li> <li> <p> <code>DELETE</em>IN<em>PROGRESS</code> when config rule is being deleted in the member account.</p> </li> <li> <p> <code>DELETE</em>SUCCESSFUL</code> when config rule has been deleted in the member account. </p> </li> <li> <p> <code>UPDATE<em>SUCCESSFUL</code> when config rule has been updated in the member account.</p> </li> <li> <p> <code>UPDATE</em>IN<em>PROGRESS</code> when config rule is being updated in the member account.</p> </li> <li> <p> <code>UPDATE</em>FAILED</code> when config rule deletion has failed in the member account.</p> </li> </ul></p>
-    #[serde(rename = "MemberAccountRuleStatus")]
-    pub member_account_rule_status: String,
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MaximumExecutionFrequency {
+    /// `One_Hour`
+    OneHour,
+    /// `Three_Hours`
+    ThreeHours,
+    /// `Six_Hours`
+    SixHours,
+    /// `Twelve_Hours`
+    TwelveHours,
+    /// `TwentyFour_Hours`
+    TwentyFourHours,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl MaximumExecutionFrequency {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MaximumExecutionFrequency::OneHour => "One_Hour",
+            MaximumExecutionFrequency::ThreeHours => "Three_Hours",
+            MaximumExecutionFrequency::SixHours => "Six_Hours",
+            MaximumExecutionFrequency::TwelveHours => "Twelve_Hours",
+            MaximumExecutionFrequency::TwentyFourHours => "TwentyFour_Hours",
+            MaximumExecutionFrequency::Unknown(ref value) => value,
+        }
+    }
 }
 
-/// <p>This object contains regions to set up the aggregator and an IAM role to retrieve organization details.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct OrganizationAggregationSource {
-    /// <p>If true, aggregate existing AWS Config regions and future regions.</p>
-    #[serde(rename = "AllAwsRegions")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub all_aws_regions: Option<bool>,
-    /// <p>The source regions being aggregated.</p>
-    #[serde(rename = "AwsRegions")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub aws_regions: Option<Vec<String>>,
-    /// <p>ARN of the IAM role used to retrieve AWS Organization details associated with the aggregator account.</p>
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for MaximumExecutionFrequency {
+    fn default() -> MaximumExecutionFrequency {
+        MaximumExecutionFrequency::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for MaximumExecutionFrequency {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for MaximumExecutionFrequency {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for MaximumExecutionFrequency {
+    fn from(value: &'a str) -> MaximumExecutionFrequency {
+        match value {
+            "One_Hour" => MaximumExecutionFrequency::OneHour,
+            "Three_Hours" => MaximumExecutionFrequency::ThreeHours,
+            "Six_Hours" => MaximumExecutionFrequency::SixHours,
+            "Twelve_Hours" => MaximumExecutionFrequency::TwelveHours,
+            "TwentyFour_Hours" => MaximumExecutionFrequency::TwentyFourHours,
+            _ => MaximumExecutionFrequency::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MaximumExecutionFrequency {
+    fn from(value: String) -> MaximumExecutionFrequency {
+        match value.as_str() {
+            "One_Hour" => MaximumExecutionFrequency::OneHour,
+            "Three_Hours" => MaximumExecutionFrequency::ThreeHours,
+            "Six_Hours" => MaximumExecutionFrequency::SixHours,
+            "Twelve_Hours" => MaximumExecutionFrequency::TwelveHours,
+            "TwentyFour_Hours" => MaximumExecutionFrequency::TwentyFourHours,
+            _ => MaximumExecutionFrequency::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for MaximumExecutionFrequency {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<MaximumExecutionFrequency, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MaximumExecutionFrequency {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for MaximumExecutionFrequency {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MaximumExecutionFrequency {
+    fn deserialize<D>(deserializer: D) -> Result<MaximumExecutionFrequency, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MemberAccountRuleStatus {
+    /// `CREATE_SUCCESSFUL`
+    CreateSuccessful,
+    /// `CREATE_IN_PROGRESS`
+    CreateInProgress,
+    /// `CREATE_FAILED`
+    CreateFailed,
+    /// `DELETE_SUCCESSFUL`
+    DeleteSuccessful,
+    /// `DELETE_FAILED`
+    DeleteFailed,
+    /// `DELETE_IN_PROGRESS`
+    DeleteInProgress,
+    /// `UPDATE_SUCCESSFUL`
+    UpdateSuccessful,
+    /// `UPDATE_IN_PROGRESS`
+    UpdateInProgress,
+    /// `UPDATE_FAILED`
+    UpdateFailed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl MemberAccountRuleStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MemberAccountRuleStatus::CreateSuccessful => "CREATE_SUCCESSFUL",
+            MemberAccountRuleStatus::CreateInProgress => "CREATE_IN_PROGRESS",
+            MemberAccountRuleStatus::CreateFailed => "CREATE_FAILED",
+            MemberAccountRuleStatus::DeleteSuccessful => "DELETE_SUCCESSFUL",
+            MemberAccountRuleStatus::DeleteFailed => "DELETE_FAILED",
+            MemberAccountRuleStatus::DeleteInProgress => "DELETE_IN_PROGRESS",
+            MemberAccountRuleStatus::UpdateSuccessful => "UPDATE_SUCCESSFUL",
+            MemberAccountRuleStatus::UpdateInProgress => "UPDATE_IN_PROGRESS",
+            MemberAccountRuleStatus::UpdateFailed => "UPDATE_FAILED",
+            MemberAccountRuleStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for MemberAccountRuleStatus {
+    fn default() -> MemberAccountRuleStatus {
+        MemberAccountRuleStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for MemberAccountRuleStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for MemberAccountRuleStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for MemberAccountRuleStatus {
+    fn from(value: &'a str) -> MemberAccountRuleStatus {
+        match value {
+            "CREATE_SUCCESSFUL" => MemberAccountRuleStatus::CreateSuccessful,
+            "CREATE_IN_PROGRESS" => MemberAccountRuleStatus::CreateInProgress,
+            "CREATE_FAILED" => MemberAccountRuleStatus::CreateFailed,
+            "DELETE_SUCCESSFUL" => MemberAccountRuleStatus::DeleteSuccessful,
+            "DELETE_FAILED" => MemberAccountRuleStatus::DeleteFailed,
+            "DELETE_IN_PROGRESS" => MemberAccountRuleStatus::DeleteInProgress,
+            "UPDATE_SUCCESSFUL" => MemberAccountRuleStatus::UpdateSuccessful,
+            "UPDATE_IN_PROGRESS" => MemberAccountRuleStatus::UpdateInProgress,
+            "UPDATE_FAILED" => MemberAccountRuleStatus::UpdateFailed,
+            _ => MemberAccountRuleStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MemberAccountRuleStatus {
+    fn from(value: String) -> MemberAccountRuleStatus {
+        match value.as_str() {
+            "CREATE_SUCCESSFUL" => MemberAccountRuleStatus::CreateSuccessful,
+            "CREATE_IN_PROGRESS" => MemberAccountRuleStatus::CreateInProgress,
+            "CREATE_FAILED" => MemberAccountRuleStatus::CreateFailed,
+            "DELETE_SUCCESSFUL" => MemberAccountRuleStatus::DeleteSuccessful,
+            "DELETE_FAILED" => MemberAccountRuleStatus::DeleteFailed,
+            "DELETE_IN_PROGRESS" => MemberAccountRuleStatus::DeleteInProgress,
+            "UPDATE_SUCCESSFUL" => MemberAccountRuleStatus::UpdateSuccessful,
+            "UPDATE_IN_PROGRESS" => MemberAccountRuleStatus::UpdateInProgress,
+            "UPDATE_FAILED" => MemberAccountRuleStatus::UpdateFailed,
+            _ => MemberAccountRuleStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for MemberAccountRuleStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<MemberAccountRuleStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MemberAccountRuleStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for MemberAccountRuleStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MemberAccountRuleStatus {
+    fn deserialize<D>(deserializer: D) -> Result<MemberAccountRuleStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Organization config rule creation or deletion status in each member account. This includes the name of the rule, the status, error code and error message when the rule creation or deletion failed.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct MemberAccountStatus {
+    /// <p>The 12-digit account ID of a member account.</p>
+    #[serde(rename = "AccountId")]
+    pub account_id: String,
+    /// <p>The name of config rule deployed in the member account.</p>
+    #[serde(rename = "ConfigRuleName")]
+    pub config_rule_name: String,
+    /// <p>An error code that is returned when config rule creation or deletion failed in the member account.</p>
+    #[serde(rename = "ErrorCode")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<String>,
+    /// <p>An error message indicating that config rule account creation or deletion has failed due to an error in the member account.</p>
+    #[serde(rename = "ErrorMessage")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_message: Option<String>,
+    /// <p>The timestamp of the last status update.</p>
+    #[serde(rename = "LastUpdateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_update_time: Option<::chrono::DateTime<::chrono::Utc>>,
+    /// <p><p>Indicates deployment status for config rule in the member account. When master account calls <code>PutOrganizationConfigRule</code> action for the first time, config rule status is created in the member account. When master account calls <code>PutOrganizationConfigRule</code> action for the second time, config rule status is updated in the member account. Config rule status is deleted when the master account deletes <code>OrganizationConfigRule</code> and disables service access for <code>config-multiaccountsetup.amazonaws.com</code>. </p> <p> AWS Config sets the state of the rule to:</p> <ul> <li> <p> <code>CREATE<em>SUCCESSFUL</code> when config rule has been created in the member account. </p> </li> <li> <p> <code>CREATE</em>IN<em>PROGRESS</code> when config rule is being created in the member account.</p> </li> <li> <p> <code>CREATE</em>FAILED</code> when config rule creation has failed in the member account.</p> </li> <li> <p> <code>DELETE<em>FAILED</code> when config rule deletion has failed in the member account.</p> </li> <li> <p> <code>DELETE</em>IN<em>PROGRESS</code> when config rule is being deleted in the member account.</p> </li> <li> <p> <code>DELETE</em>SUCCESSFUL</code> when config rule has been deleted in the member account. </p> </li> <li> <p> <code>UPDATE<em>SUCCESSFUL</code> when config rule has been updated in the member account.</p> </li> <li> <p> <code>UPDATE</em>IN<em>PROGRESS</code> when config rule is being updated in the member account.</p> </li> <li> <p> <code>UPDATE</em>FAILED</code> when config rule deletion has failed in the member account.</p> </li> </ul></p>
+    #[serde(rename = "MemberAccountRuleStatus")]
+    pub member_account_rule_status: MemberAccountRuleStatus,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MessageType {
+    /// `ConfigurationItemChangeNotification`
+    ConfigurationItemChangeNotification,
+    /// `ConfigurationSnapshotDeliveryCompleted`
+    ConfigurationSnapshotDeliveryCompleted,
+    /// `ScheduledNotification`
+    ScheduledNotification,
+    /// `OversizedConfigurationItemChangeNotification`
+    OversizedConfigurationItemChangeNotification,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl MessageType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MessageType::ConfigurationItemChangeNotification => {
+                "ConfigurationItemChangeNotification"
+            }
+            MessageType::ConfigurationSnapshotDeliveryCompleted => {
+                "ConfigurationSnapshotDeliveryCompleted"
+            }
+            MessageType::ScheduledNotification => "ScheduledNotification",
+            MessageType::OversizedConfigurationItemChangeNotification => {
+                "OversizedConfigurationItemChangeNotification"
+            }
+            MessageType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for MessageType {
+    fn default() -> MessageType {
+        MessageType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for MessageType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for MessageType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for MessageType {
+    fn from(value: &'a str) -> MessageType {
+        match value {
+            "ConfigurationItemChangeNotification" => {
+                MessageType::ConfigurationItemChangeNotification
+            }
+            "ConfigurationSnapshotDeliveryCompleted" => {
+                MessageType::ConfigurationSnapshotDeliveryCompleted
+            }
+            "ScheduledNotification" => MessageType::ScheduledNotification,
+            "OversizedConfigurationItemChangeNotification" => {
+                MessageType::OversizedConfigurationItemChangeNotification
+            }
+            _ => MessageType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MessageType {
+    fn from(value: String) -> MessageType {
+        match value.as_str() {
+            "ConfigurationItemChangeNotification" => {
+                MessageType::ConfigurationItemChangeNotification
+            }
+            "ConfigurationSnapshotDeliveryCompleted" => {
+                MessageType::ConfigurationSnapshotDeliveryCompleted
+            }
+            "ScheduledNotification" => MessageType::ScheduledNotification,
+            "OversizedConfigurationItemChangeNotification" => {
+                MessageType::OversizedConfigurationItemChangeNotification
+            }
+            _ => MessageType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for MessageType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<MessageType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MessageType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for MessageType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MessageType {
+    fn deserialize<D>(deserializer: D) -> Result<MessageType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>This object contains regions to set up the aggregator and an IAM role to retrieve organization details.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OrganizationAggregationSource {
+    /// <p>If true, aggregate existing AWS Config regions and future regions.</p>
+    #[serde(rename = "AllAwsRegions")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub all_aws_regions: Option<bool>,
+    /// <p>The source regions being aggregated.</p>
+    #[serde(rename = "AwsRegions")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aws_regions: Option<Vec<String>>,
+    /// <p>ARN of the IAM role used to retrieve AWS Organization details associated with the aggregator account.</p>
     #[serde(rename = "RoleArn")]
     pub role_arn: String,
 }
@@ -2575,8 +4190,13 @@ pub struct OrganizationConfigRule {
     pub excluded_accounts: Option<Vec<String>>,
     /// <p>The timestamp of the last update.</p>
     #[serde(rename = "LastUpdateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_update_time: Option<f64>,
+    pub last_update_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Amazon Resource Name (ARN) of organization config rule.</p>
     #[serde(rename = "OrganizationConfigRuleArn")]
     pub organization_config_rule_arn: String,
@@ -2607,16 +4227,131 @@ pub struct OrganizationConfigRuleStatus {
     pub error_message: Option<String>,
     /// <p>The timestamp of the last update.</p>
     #[serde(rename = "LastUpdateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_update_time: Option<f64>,
+    pub last_update_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name that you assign to organization config rule.</p>
     #[serde(rename = "OrganizationConfigRuleName")]
     pub organization_config_rule_name: String,
     /// <p><p>Indicates deployment status of an organization config rule. When master account calls PutOrganizationConfigRule action for the first time, config rule status is created in all the member accounts. When master account calls PutOrganizationConfigRule action for the second time, config rule status is updated in all the member accounts. Additionally, config rule status is updated when one or more member accounts join or leave an organization. Config rule status is deleted when the master account deletes OrganizationConfigRule in all the member accounts and disables service access for <code>config-multiaccountsetup.amazonaws.com</code>.</p> <p>AWS Config sets the state of the rule to:</p> <ul> <li> <p> <code>CREATE<em>SUCCESSFUL</code> when an organization config rule has been successfully created in all the member accounts. </p> </li> <li> <p> <code>CREATE</em>IN<em>PROGRESS</code> when an organization config rule creation is in progress.</p> </li> <li> <p> <code>CREATE</em>FAILED</code> when an organization config rule creation failed in one or more member accounts within that organization.</p> </li> <li> <p> <code>DELETE<em>FAILED</code> when an organization config rule deletion failed in one or more member accounts within that organization.</p> </li> <li> <p> <code>DELETE</em>IN<em>PROGRESS</code> when an organization config rule deletion is in progress.</p> </li> <li> <p> <code>DELETE</em>SUCCESSFUL</code> when an organization config rule has been successfully deleted from all the member accounts.</p> </li> <li> <p> <code>UPDATE<em>SUCCESSFUL</code> when an organization config rule has been successfully updated in all the member accounts.</p> </li> <li> <p> <code>UPDATE</em>IN<em>PROGRESS</code> when an organization config rule update is in progress.</p> </li> <li> <p> <code>UPDATE</em>FAILED</code> when an organization config rule update failed in one or more member accounts within that organization.</p> </li> </ul></p>
     #[serde(rename = "OrganizationRuleStatus")]
-    pub organization_rule_status: String,
+    pub organization_rule_status: OrganizationRuleStatus,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OrganizationConfigRuleTriggerType {
+    /// `ConfigurationItemChangeNotification`
+    ConfigurationItemChangeNotification,
+    /// `OversizedConfigurationItemChangeNotification`
+    OversizedConfigurationItemChangeNotification,
+    /// `ScheduledNotification`
+    ScheduledNotification,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl OrganizationConfigRuleTriggerType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OrganizationConfigRuleTriggerType::ConfigurationItemChangeNotification => {
+                "ConfigurationItemChangeNotification"
+            }
+            OrganizationConfigRuleTriggerType::OversizedConfigurationItemChangeNotification => {
+                "OversizedConfigurationItemChangeNotification"
+            }
+            OrganizationConfigRuleTriggerType::ScheduledNotification => "ScheduledNotification",
+            OrganizationConfigRuleTriggerType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for OrganizationConfigRuleTriggerType {
+    fn default() -> OrganizationConfigRuleTriggerType {
+        OrganizationConfigRuleTriggerType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for OrganizationConfigRuleTriggerType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for OrganizationConfigRuleTriggerType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for OrganizationConfigRuleTriggerType {
+    fn from(value: &'a str) -> OrganizationConfigRuleTriggerType {
+        match value {
+            "ConfigurationItemChangeNotification" => {
+                OrganizationConfigRuleTriggerType::ConfigurationItemChangeNotification
+            }
+            "OversizedConfigurationItemChangeNotification" => {
+                OrganizationConfigRuleTriggerType::OversizedConfigurationItemChangeNotification
+            }
+            "ScheduledNotification" => OrganizationConfigRuleTriggerType::ScheduledNotification,
+            _ => OrganizationConfigRuleTriggerType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OrganizationConfigRuleTriggerType {
+    fn from(value: String) -> OrganizationConfigRuleTriggerType {
+        match value.as_str() {
+            "ConfigurationItemChangeNotification" => {
+                OrganizationConfigRuleTriggerType::ConfigurationItemChangeNotification
+            }
+            "OversizedConfigurationItemChangeNotification" => {
+                OrganizationConfigRuleTriggerType::OversizedConfigurationItemChangeNotification
+            }
+            "ScheduledNotification" => OrganizationConfigRuleTriggerType::ScheduledNotification,
+            _ => OrganizationConfigRuleTriggerType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for OrganizationConfigRuleTriggerType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<OrganizationConfigRuleTriggerType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OrganizationConfigRuleTriggerType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for OrganizationConfigRuleTriggerType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for OrganizationConfigRuleTriggerType {
+    fn deserialize<D>(deserializer: D) -> Result<OrganizationConfigRuleTriggerType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An organization conformance pack that has information about conformance packs that AWS Config creates in member accounts. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -2638,7 +4373,12 @@ pub struct OrganizationConformancePack {
     pub excluded_accounts: Option<Vec<String>>,
     /// <p>Last time when organization conformation pack was updated.</p>
     #[serde(rename = "LastUpdateTime")]
-    pub last_update_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub last_update_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>Amazon Resource Name (ARN) of organization conformance pack.</p>
     #[serde(rename = "OrganizationConformancePackArn")]
     pub organization_conformance_pack_arn: String,
@@ -2667,11 +4407,16 @@ pub struct OrganizationConformancePackDetailedStatus {
     pub error_message: Option<String>,
     /// <p>The timestamp of the last status update.</p>
     #[serde(rename = "LastUpdateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_update_time: Option<f64>,
+    pub last_update_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>Indicates deployment status for conformance pack in a member account. When master account calls <code>PutOrganizationConformancePack</code> action for the first time, conformance pack status is created in the member account. When master account calls <code>PutOrganizationConformancePack</code> action for the second time, conformance pack status is updated in the member account. Conformance pack status is deleted when the master account deletes <code>OrganizationConformancePack</code> and disables service access for <code>config-multiaccountsetup.amazonaws.com</code>. </p> <p> AWS Config sets the state of the conformance pack to:</p> <ul> <li> <p> <code>CREATE<em>SUCCESSFUL</code> when conformance pack has been created in the member account. </p> </li> <li> <p> <code>CREATE</em>IN<em>PROGRESS</code> when conformance pack is being created in the member account.</p> </li> <li> <p> <code>CREATE</em>FAILED</code> when conformance pack creation has failed in the member account.</p> </li> <li> <p> <code>DELETE<em>FAILED</code> when conformance pack deletion has failed in the member account.</p> </li> <li> <p> <code>DELETE</em>IN<em>PROGRESS</code> when conformance pack is being deleted in the member account.</p> </li> <li> <p> <code>DELETE</em>SUCCESSFUL</code> when conformance pack has been deleted in the member account. </p> </li> <li> <p> <code>UPDATE<em>SUCCESSFUL</code> when conformance pack has been updated in the member account.</p> </li> <li> <p> <code>UPDATE</em>IN<em>PROGRESS</code> when conformance pack is being updated in the member account.</p> </li> <li> <p> <code>UPDATE</em>FAILED</code> when conformance pack deletion has failed in the member account.</p> </li> </ul></p>
     #[serde(rename = "Status")]
-    pub status: String,
+    pub status: OrganizationResourceDetailedStatus,
 }
 
 /// <p>Returns the status for an organization conformance pack in an organization.</p>
@@ -2688,14 +4433,19 @@ pub struct OrganizationConformancePackStatus {
     pub error_message: Option<String>,
     /// <p>The timestamp of the last update.</p>
     #[serde(rename = "LastUpdateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_update_time: Option<f64>,
+    pub last_update_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name that you assign to organization conformance pack.</p>
     #[serde(rename = "OrganizationConformancePackName")]
     pub organization_conformance_pack_name: String,
     /// <p><p>Indicates deployment status of an organization conformance pack. When master account calls PutOrganizationConformancePack for the first time, conformance pack status is created in all the member accounts. When master account calls PutOrganizationConformancePack for the second time, conformance pack status is updated in all the member accounts. Additionally, conformance pack status is updated when one or more member accounts join or leave an organization. Conformance pack status is deleted when the master account deletes OrganizationConformancePack in all the member accounts and disables service access for <code>config-multiaccountsetup.amazonaws.com</code>.</p> <p>AWS Config sets the state of the conformance pack to:</p> <ul> <li> <p> <code>CREATE<em>SUCCESSFUL</code> when an organization conformance pack has been successfully created in all the member accounts. </p> </li> <li> <p> <code>CREATE</em>IN<em>PROGRESS</code> when an organization conformance pack creation is in progress.</p> </li> <li> <p> <code>CREATE</em>FAILED</code> when an organization conformance pack creation failed in one or more member accounts within that organization.</p> </li> <li> <p> <code>DELETE<em>FAILED</code> when an organization conformance pack deletion failed in one or more member accounts within that organization.</p> </li> <li> <p> <code>DELETE</em>IN<em>PROGRESS</code> when an organization conformance pack deletion is in progress.</p> </li> <li> <p> <code>DELETE</em>SUCCESSFUL</code> when an organization conformance pack has been successfully deleted from all the member accounts.</p> </li> <li> <p> <code>UPDATE<em>SUCCESSFUL</code> when an organization conformance pack has been successfully updated in all the member accounts.</p> </li> <li> <p> <code>UPDATE</em>IN<em>PROGRESS</code> when an organization conformance pack update is in progress.</p> </li> <li> <p> <code>UPDATE</em>FAILED</code> when an organization conformance pack update failed in one or more member accounts within that organization.</p> </li> </ul></p>
     #[serde(rename = "Status")]
-    pub status: String,
+    pub status: OrganizationResourceStatus,
 }
 
 /// <p>An object that specifies organization custom rule metadata such as resource type, resource ID of AWS resource, Lamdba function ARN, and organization trigger types that trigger AWS Config to evaluate your AWS resources against a rule. It also provides the frequency with which you want AWS Config to run evaluations for the rule if the trigger type is periodic.</p>
@@ -2715,10 +4465,10 @@ pub struct OrganizationCustomRuleMetadata {
     /// <p><p>The maximum frequency with which AWS Config runs evaluations for a rule. Your custom rule is triggered when AWS Config delivers the configuration snapshot. For more information, see <a>ConfigSnapshotDeliveryProperties</a>.</p> <note> <p>By default, rules with a periodic trigger are evaluated every 24 hours. To change the frequency, specify a valid value for the <code>MaximumExecutionFrequency</code> parameter.</p> </note></p>
     #[serde(rename = "MaximumExecutionFrequency")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub maximum_execution_frequency: Option<String>,
+    pub maximum_execution_frequency: Option<MaximumExecutionFrequency>,
     /// <p><p>The type of notification that triggers AWS Config to run an evaluation for a rule. You can specify the following notification types:</p> <ul> <li> <p> <code>ConfigurationItemChangeNotification</code> - Triggers an evaluation when AWS Config delivers a configuration item as a result of a resource change.</p> </li> <li> <p> <code>OversizedConfigurationItemChangeNotification</code> - Triggers an evaluation when AWS Config delivers an oversized configuration item. AWS Config may generate this notification type when a resource changes and the notification exceeds the maximum size allowed by Amazon SNS.</p> </li> <li> <p> <code>ScheduledNotification</code> - Triggers a periodic evaluation at the frequency specified for <code>MaximumExecutionFrequency</code>.</p> </li> </ul></p>
     #[serde(rename = "OrganizationConfigRuleTriggerTypes")]
-    pub organization_config_rule_trigger_types: Vec<String>,
+    pub organization_config_rule_trigger_types: Vec<OrganizationConfigRuleTriggerType>,
     /// <p>The ID of the AWS resource that was evaluated.</p>
     #[serde(rename = "ResourceIdScope")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2751,7 +4501,7 @@ pub struct OrganizationManagedRuleMetadata {
     /// <p><p>The maximum frequency with which AWS Config runs evaluations for a rule. You are using an AWS managed rule that is triggered at a periodic frequency.</p> <note> <p>By default, rules with a periodic trigger are evaluated every 24 hours. To change the frequency, specify a valid value for the <code>MaximumExecutionFrequency</code> parameter.</p> </note></p>
     #[serde(rename = "MaximumExecutionFrequency")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub maximum_execution_frequency: Option<String>,
+    pub maximum_execution_frequency: Option<MaximumExecutionFrequency>,
     /// <p>The ID of the AWS resource that was evaluated.</p>
     #[serde(rename = "ResourceIdScope")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2773,6 +4523,134 @@ pub struct OrganizationManagedRuleMetadata {
     pub tag_value_scope: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OrganizationResourceDetailedStatus {
+    /// `CREATE_SUCCESSFUL`
+    CreateSuccessful,
+    /// `CREATE_IN_PROGRESS`
+    CreateInProgress,
+    /// `CREATE_FAILED`
+    CreateFailed,
+    /// `DELETE_SUCCESSFUL`
+    DeleteSuccessful,
+    /// `DELETE_FAILED`
+    DeleteFailed,
+    /// `DELETE_IN_PROGRESS`
+    DeleteInProgress,
+    /// `UPDATE_SUCCESSFUL`
+    UpdateSuccessful,
+    /// `UPDATE_IN_PROGRESS`
+    UpdateInProgress,
+    /// `UPDATE_FAILED`
+    UpdateFailed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl OrganizationResourceDetailedStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OrganizationResourceDetailedStatus::CreateSuccessful => "CREATE_SUCCESSFUL",
+            OrganizationResourceDetailedStatus::CreateInProgress => "CREATE_IN_PROGRESS",
+            OrganizationResourceDetailedStatus::CreateFailed => "CREATE_FAILED",
+            OrganizationResourceDetailedStatus::DeleteSuccessful => "DELETE_SUCCESSFUL",
+            OrganizationResourceDetailedStatus::DeleteFailed => "DELETE_FAILED",
+            OrganizationResourceDetailedStatus::DeleteInProgress => "DELETE_IN_PROGRESS",
+            OrganizationResourceDetailedStatus::UpdateSuccessful => "UPDATE_SUCCESSFUL",
+            OrganizationResourceDetailedStatus::UpdateInProgress => "UPDATE_IN_PROGRESS",
+            OrganizationResourceDetailedStatus::UpdateFailed => "UPDATE_FAILED",
+            OrganizationResourceDetailedStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for OrganizationResourceDetailedStatus {
+    fn default() -> OrganizationResourceDetailedStatus {
+        OrganizationResourceDetailedStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for OrganizationResourceDetailedStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for OrganizationResourceDetailedStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for OrganizationResourceDetailedStatus {
+    fn from(value: &'a str) -> OrganizationResourceDetailedStatus {
+        match value {
+            "CREATE_SUCCESSFUL" => OrganizationResourceDetailedStatus::CreateSuccessful,
+            "CREATE_IN_PROGRESS" => OrganizationResourceDetailedStatus::CreateInProgress,
+            "CREATE_FAILED" => OrganizationResourceDetailedStatus::CreateFailed,
+            "DELETE_SUCCESSFUL" => OrganizationResourceDetailedStatus::DeleteSuccessful,
+            "DELETE_FAILED" => OrganizationResourceDetailedStatus::DeleteFailed,
+            "DELETE_IN_PROGRESS" => OrganizationResourceDetailedStatus::DeleteInProgress,
+            "UPDATE_SUCCESSFUL" => OrganizationResourceDetailedStatus::UpdateSuccessful,
+            "UPDATE_IN_PROGRESS" => OrganizationResourceDetailedStatus::UpdateInProgress,
+            "UPDATE_FAILED" => OrganizationResourceDetailedStatus::UpdateFailed,
+            _ => OrganizationResourceDetailedStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OrganizationResourceDetailedStatus {
+    fn from(value: String) -> OrganizationResourceDetailedStatus {
+        match value.as_str() {
+            "CREATE_SUCCESSFUL" => OrganizationResourceDetailedStatus::CreateSuccessful,
+            "CREATE_IN_PROGRESS" => OrganizationResourceDetailedStatus::CreateInProgress,
+            "CREATE_FAILED" => OrganizationResourceDetailedStatus::CreateFailed,
+            "DELETE_SUCCESSFUL" => OrganizationResourceDetailedStatus::DeleteSuccessful,
+            "DELETE_FAILED" => OrganizationResourceDetailedStatus::DeleteFailed,
+            "DELETE_IN_PROGRESS" => OrganizationResourceDetailedStatus::DeleteInProgress,
+            "UPDATE_SUCCESSFUL" => OrganizationResourceDetailedStatus::UpdateSuccessful,
+            "UPDATE_IN_PROGRESS" => OrganizationResourceDetailedStatus::UpdateInProgress,
+            "UPDATE_FAILED" => OrganizationResourceDetailedStatus::UpdateFailed,
+            _ => OrganizationResourceDetailedStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for OrganizationResourceDetailedStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<OrganizationResourceDetailedStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OrganizationResourceDetailedStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for OrganizationResourceDetailedStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OrganizationResourceDetailedStatus {
+    fn deserialize<D>(deserializer: D) -> Result<OrganizationResourceDetailedStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Status filter object to filter results based on specific member account ID or status type for an organization conformance pack.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -2784,20 +4662,374 @@ pub struct OrganizationResourceDetailedStatusFilters {
     /// <p><p>Indicates deployment status for conformance pack in a member account. When master account calls <code>PutOrganizationConformancePack</code> action for the first time, conformance pack status is created in the member account. When master account calls <code>PutOrganizationConformancePack</code> action for the second time, conformance pack status is updated in the member account. Conformance pack status is deleted when the master account deletes <code>OrganizationConformancePack</code> and disables service access for <code>config-multiaccountsetup.amazonaws.com</code>. </p> <p> AWS Config sets the state of the conformance pack to:</p> <ul> <li> <p> <code>CREATE<em>SUCCESSFUL</code> when conformance pack has been created in the member account. </p> </li> <li> <p> <code>CREATE</em>IN<em>PROGRESS</code> when conformance pack is being created in the member account.</p> </li> <li> <p> <code>CREATE</em>FAILED</code> when conformance pack creation has failed in the member account.</p> </li> <li> <p> <code>DELETE<em>FAILED</code> when conformance pack deletion has failed in the member account.</p> </li> <li> <p> <code>DELETE</em>IN<em>PROGRESS</code> when conformance pack is being deleted in the member account.</p> </li> <li> <p> <code>DELETE</em>SUCCESSFUL</code> when conformance pack has been deleted in the member account. </p> </li> <li> <p> <code>UPDATE<em>SUCCESSFUL</code> when conformance pack has been updated in the member account.</p> </li> <li> <p> <code>UPDATE</em>IN<em>PROGRESS</code> when conformance pack is being updated in the member account.</p> </li> <li> <p> <code>UPDATE</em>FAILED</code> when conformance pack deletion has failed in the member account.</p> </li> </ul></p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<OrganizationResourceDetailedStatus>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OrganizationResourceStatus {
+    /// `CREATE_SUCCESSFUL`
+    CreateSuccessful,
+    /// `CREATE_IN_PROGRESS`
+    CreateInProgress,
+    /// `CREATE_FAILED`
+    CreateFailed,
+    /// `DELETE_SUCCESSFUL`
+    DeleteSuccessful,
+    /// `DELETE_FAILED`
+    DeleteFailed,
+    /// `DELETE_IN_PROGRESS`
+    DeleteInProgress,
+    /// `UPDATE_SUCCESSFUL`
+    UpdateSuccessful,
+    /// `UPDATE_IN_PROGRESS`
+    UpdateInProgress,
+    /// `UPDATE_FAILED`
+    UpdateFailed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl OrganizationResourceStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OrganizationResourceStatus::CreateSuccessful => "CREATE_SUCCESSFUL",
+            OrganizationResourceStatus::CreateInProgress => "CREATE_IN_PROGRESS",
+            OrganizationResourceStatus::CreateFailed => "CREATE_FAILED",
+            OrganizationResourceStatus::DeleteSuccessful => "DELETE_SUCCESSFUL",
+            OrganizationResourceStatus::DeleteFailed => "DELETE_FAILED",
+            OrganizationResourceStatus::DeleteInProgress => "DELETE_IN_PROGRESS",
+            OrganizationResourceStatus::UpdateSuccessful => "UPDATE_SUCCESSFUL",
+            OrganizationResourceStatus::UpdateInProgress => "UPDATE_IN_PROGRESS",
+            OrganizationResourceStatus::UpdateFailed => "UPDATE_FAILED",
+            OrganizationResourceStatus::Unknown(ref value) => value,
+        }
+    }
 }
 
-/// <p>An object that represents the account ID and region of an aggregator account that is requesting authorization but is not yet authorized.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct PendingAggregationRequest {
-    /// <p>The 12-digit account ID of the account requesting to aggregate data.</p>
-    #[serde(rename = "RequesterAccountId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub requester_account_id: Option<String>,
-    /// <p>The region requesting to aggregate data. </p>
-    #[serde(rename = "RequesterAwsRegion")]
-    #[serde(skip_serializing_if = "Option::is_none")]
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for OrganizationResourceStatus {
+    fn default() -> OrganizationResourceStatus {
+        OrganizationResourceStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for OrganizationResourceStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for OrganizationResourceStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for OrganizationResourceStatus {
+    fn from(value: &'a str) -> OrganizationResourceStatus {
+        match value {
+            "CREATE_SUCCESSFUL" => OrganizationResourceStatus::CreateSuccessful,
+            "CREATE_IN_PROGRESS" => OrganizationResourceStatus::CreateInProgress,
+            "CREATE_FAILED" => OrganizationResourceStatus::CreateFailed,
+            "DELETE_SUCCESSFUL" => OrganizationResourceStatus::DeleteSuccessful,
+            "DELETE_FAILED" => OrganizationResourceStatus::DeleteFailed,
+            "DELETE_IN_PROGRESS" => OrganizationResourceStatus::DeleteInProgress,
+            "UPDATE_SUCCESSFUL" => OrganizationResourceStatus::UpdateSuccessful,
+            "UPDATE_IN_PROGRESS" => OrganizationResourceStatus::UpdateInProgress,
+            "UPDATE_FAILED" => OrganizationResourceStatus::UpdateFailed,
+            _ => OrganizationResourceStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OrganizationResourceStatus {
+    fn from(value: String) -> OrganizationResourceStatus {
+        match value.as_str() {
+            "CREATE_SUCCESSFUL" => OrganizationResourceStatus::CreateSuccessful,
+            "CREATE_IN_PROGRESS" => OrganizationResourceStatus::CreateInProgress,
+            "CREATE_FAILED" => OrganizationResourceStatus::CreateFailed,
+            "DELETE_SUCCESSFUL" => OrganizationResourceStatus::DeleteSuccessful,
+            "DELETE_FAILED" => OrganizationResourceStatus::DeleteFailed,
+            "DELETE_IN_PROGRESS" => OrganizationResourceStatus::DeleteInProgress,
+            "UPDATE_SUCCESSFUL" => OrganizationResourceStatus::UpdateSuccessful,
+            "UPDATE_IN_PROGRESS" => OrganizationResourceStatus::UpdateInProgress,
+            "UPDATE_FAILED" => OrganizationResourceStatus::UpdateFailed,
+            _ => OrganizationResourceStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for OrganizationResourceStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<OrganizationResourceStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OrganizationResourceStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for OrganizationResourceStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OrganizationResourceStatus {
+    fn deserialize<D>(deserializer: D) -> Result<OrganizationResourceStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OrganizationRuleStatus {
+    /// `CREATE_SUCCESSFUL`
+    CreateSuccessful,
+    /// `CREATE_IN_PROGRESS`
+    CreateInProgress,
+    /// `CREATE_FAILED`
+    CreateFailed,
+    /// `DELETE_SUCCESSFUL`
+    DeleteSuccessful,
+    /// `DELETE_FAILED`
+    DeleteFailed,
+    /// `DELETE_IN_PROGRESS`
+    DeleteInProgress,
+    /// `UPDATE_SUCCESSFUL`
+    UpdateSuccessful,
+    /// `UPDATE_IN_PROGRESS`
+    UpdateInProgress,
+    /// `UPDATE_FAILED`
+    UpdateFailed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl OrganizationRuleStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OrganizationRuleStatus::CreateSuccessful => "CREATE_SUCCESSFUL",
+            OrganizationRuleStatus::CreateInProgress => "CREATE_IN_PROGRESS",
+            OrganizationRuleStatus::CreateFailed => "CREATE_FAILED",
+            OrganizationRuleStatus::DeleteSuccessful => "DELETE_SUCCESSFUL",
+            OrganizationRuleStatus::DeleteFailed => "DELETE_FAILED",
+            OrganizationRuleStatus::DeleteInProgress => "DELETE_IN_PROGRESS",
+            OrganizationRuleStatus::UpdateSuccessful => "UPDATE_SUCCESSFUL",
+            OrganizationRuleStatus::UpdateInProgress => "UPDATE_IN_PROGRESS",
+            OrganizationRuleStatus::UpdateFailed => "UPDATE_FAILED",
+            OrganizationRuleStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for OrganizationRuleStatus {
+    fn default() -> OrganizationRuleStatus {
+        OrganizationRuleStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for OrganizationRuleStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for OrganizationRuleStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for OrganizationRuleStatus {
+    fn from(value: &'a str) -> OrganizationRuleStatus {
+        match value {
+            "CREATE_SUCCESSFUL" => OrganizationRuleStatus::CreateSuccessful,
+            "CREATE_IN_PROGRESS" => OrganizationRuleStatus::CreateInProgress,
+            "CREATE_FAILED" => OrganizationRuleStatus::CreateFailed,
+            "DELETE_SUCCESSFUL" => OrganizationRuleStatus::DeleteSuccessful,
+            "DELETE_FAILED" => OrganizationRuleStatus::DeleteFailed,
+            "DELETE_IN_PROGRESS" => OrganizationRuleStatus::DeleteInProgress,
+            "UPDATE_SUCCESSFUL" => OrganizationRuleStatus::UpdateSuccessful,
+            "UPDATE_IN_PROGRESS" => OrganizationRuleStatus::UpdateInProgress,
+            "UPDATE_FAILED" => OrganizationRuleStatus::UpdateFailed,
+            _ => OrganizationRuleStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OrganizationRuleStatus {
+    fn from(value: String) -> OrganizationRuleStatus {
+        match value.as_str() {
+            "CREATE_SUCCESSFUL" => OrganizationRuleStatus::CreateSuccessful,
+            "CREATE_IN_PROGRESS" => OrganizationRuleStatus::CreateInProgress,
+            "CREATE_FAILED" => OrganizationRuleStatus::CreateFailed,
+            "DELETE_SUCCESSFUL" => OrganizationRuleStatus::DeleteSuccessful,
+            "DELETE_FAILED" => OrganizationRuleStatus::DeleteFailed,
+            "DELETE_IN_PROGRESS" => OrganizationRuleStatus::DeleteInProgress,
+            "UPDATE_SUCCESSFUL" => OrganizationRuleStatus::UpdateSuccessful,
+            "UPDATE_IN_PROGRESS" => OrganizationRuleStatus::UpdateInProgress,
+            "UPDATE_FAILED" => OrganizationRuleStatus::UpdateFailed,
+            _ => OrganizationRuleStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for OrganizationRuleStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<OrganizationRuleStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OrganizationRuleStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for OrganizationRuleStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OrganizationRuleStatus {
+    fn deserialize<D>(deserializer: D) -> Result<OrganizationRuleStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Owner {
+    /// `CUSTOM_LAMBDA`
+    CustomLambda,
+    /// `AWS`
+    Aws,
+    /// `CUSTOM_POLICY`
+    CustomPolicy,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Owner {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Owner::CustomLambda => "CUSTOM_LAMBDA",
+            Owner::Aws => "AWS",
+            Owner::CustomPolicy => "CUSTOM_POLICY",
+            Owner::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Owner {
+    fn default() -> Owner {
+        Owner::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Owner {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Owner {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Owner {
+    fn from(value: &'a str) -> Owner {
+        match value {
+            "CUSTOM_LAMBDA" => Owner::CustomLambda,
+            "AWS" => Owner::Aws,
+            "CUSTOM_POLICY" => Owner::CustomPolicy,
+            _ => Owner::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Owner {
+    fn from(value: String) -> Owner {
+        match value.as_str() {
+            "CUSTOM_LAMBDA" => Owner::CustomLambda,
+            "AWS" => Owner::Aws,
+            "CUSTOM_POLICY" => Owner::CustomPolicy,
+            _ => Owner::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Owner {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Owner, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Owner {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Owner {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Owner {
+    fn deserialize<D>(deserializer: D) -> Result<Owner, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>An object that represents the account ID and region of an aggregator account that is requesting authorization but is not yet authorized.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct PendingAggregationRequest {
+    /// <p>The 12-digit account ID of the account requesting to aggregate data.</p>
+    #[serde(rename = "RequesterAccountId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requester_account_id: Option<String>,
+    /// <p>The region requesting to aggregate data. </p>
+    #[serde(rename = "RequesterAwsRegion")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub requester_aws_region: Option<String>,
 }
 
@@ -3041,8 +5273,13 @@ pub struct PutRemediationExceptionsRequest {
     pub config_rule_name: String,
     /// <p>The exception is automatically deleted after the expiration date.</p>
     #[serde(rename = "ExpirationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expiration_time: Option<f64>,
+    pub expiration_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The message contains an explanation of the exception.</p>
     #[serde(rename = "Message")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3113,6 +5350,104 @@ pub struct QueryInfo {
     pub select_fields: Option<Vec<FieldInfo>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RecorderStatus {
+    /// `Pending`
+    Pending,
+    /// `Success`
+    Success,
+    /// `Failure`
+    Failure,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RecorderStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RecorderStatus::Pending => "Pending",
+            RecorderStatus::Success => "Success",
+            RecorderStatus::Failure => "Failure",
+            RecorderStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RecorderStatus {
+    fn default() -> RecorderStatus {
+        RecorderStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RecorderStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RecorderStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RecorderStatus {
+    fn from(value: &'a str) -> RecorderStatus {
+        match value {
+            "Pending" => RecorderStatus::Pending,
+            "Success" => RecorderStatus::Success,
+            "Failure" => RecorderStatus::Failure,
+            _ => RecorderStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RecorderStatus {
+    fn from(value: String) -> RecorderStatus {
+        match value.as_str() {
+            "Pending" => RecorderStatus::Pending,
+            "Success" => RecorderStatus::Success,
+            "Failure" => RecorderStatus::Failure,
+            _ => RecorderStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RecorderStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RecorderStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RecorderStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RecorderStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RecorderStatus {
+    fn deserialize<D>(deserializer: D) -> Result<RecorderStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Specifies the types of AWS resource for which AWS Config records configuration changes.</p> <p>In the recording group, you specify whether all supported types or specific types of resources are recorded.</p> <p>By default, AWS Config records configuration changes for all supported types of regional resources that AWS Config discovers in the region in which it is running. Regional resources are tied to a region and can be used only in that region. Examples of regional resources are EC2 instances and EBS volumes.</p> <p>You can also have AWS Config record configuration changes for supported types of global resources (for example, IAM resources). Global resources are not tied to an individual region and can be used in all regions.</p> <important> <p>The configuration details for any global resource are the same in all regions. If you customize AWS Config in multiple regions to record global resources, it will create multiple configuration items each time a global resource changes: one configuration item for each region. These configuration items will contain identical data. To prevent duplicate configuration items, you should consider customizing AWS Config in only one region to record global resources, unless you want the configuration items to be available in multiple regions.</p> </important> <p>If you don't want AWS Config to record all resources, you can specify which types of resources it will record with the <code>resourceTypes</code> parameter.</p> <p>For a list of supported resource types, see <a href="https://docs.aws.amazon.com/config/latest/developerguide/resource-config-reference.html#supported-resources">Supported Resource Types</a>.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/config/latest/developerguide/select-resources.html">Selecting Which Resources AWS Config Records</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RecordingGroup {
@@ -3127,7 +5462,7 @@ pub struct RecordingGroup {
     /// <p>A comma-separated list that specifies the types of AWS resources for which AWS Config records configuration changes (for example, <code>AWS::EC2::Instance</code> or <code>AWS::CloudTrail::Trail</code>).</p> <p>Before you can set this option to <code>true</code>, you must set the <code>allSupported</code> option to <code>false</code>.</p> <p>If you set this option to <code>true</code>, when AWS Config adds support for a new type of resource, it will not record resources of that type unless you manually add that type to your recording group.</p> <p>For a list of valid <code>resourceTypes</code> values, see the <b>resourceType Value</b> column in <a href="https://docs.aws.amazon.com/config/latest/developerguide/resource-config-reference.html#supported-resources">Supported AWS Resource Types</a>.</p>
     #[serde(rename = "resourceTypes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_types: Option<Vec<String>>,
+    pub resource_types: Option<Vec<ResourceType>>,
 }
 
 /// <p>The relationship of the related resource to the main resource.</p>
@@ -3149,7 +5484,7 @@ pub struct Relationship {
     /// <p>The resource type of the related resource.</p>
     #[serde(rename = "resourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ResourceType>,
 }
 
 /// <p>An object that represents the details about the remediation configuration that includes the remediation action, parameters, and data to execute the action.</p>
@@ -3195,7 +5530,7 @@ pub struct RemediationConfiguration {
     pub target_id: String,
     /// <p>The type of the target. Target executes remediation. For example, SSM document.</p>
     #[serde(rename = "TargetType")]
-    pub target_type: String,
+    pub target_type: RemediationTargetType,
     /// <p>Version of the target. For example, version of the SSM document.</p>
     #[serde(rename = "TargetVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3211,8 +5546,13 @@ pub struct RemediationException {
     pub config_rule_name: String,
     /// <p>The time when the remediation exception will be deleted.</p>
     #[serde(rename = "ExpirationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expiration_time: Option<f64>,
+    pub expiration_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>An explanation of an remediation exception.</p>
     #[serde(rename = "Message")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3238,25 +5578,138 @@ pub struct RemediationExceptionResourceKey {
     pub resource_type: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RemediationExecutionState {
+    /// `QUEUED`
+    Queued,
+    /// `IN_PROGRESS`
+    InProgress,
+    /// `SUCCEEDED`
+    Succeeded,
+    /// `FAILED`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RemediationExecutionState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RemediationExecutionState::Queued => "QUEUED",
+            RemediationExecutionState::InProgress => "IN_PROGRESS",
+            RemediationExecutionState::Succeeded => "SUCCEEDED",
+            RemediationExecutionState::Failed => "FAILED",
+            RemediationExecutionState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RemediationExecutionState {
+    fn default() -> RemediationExecutionState {
+        RemediationExecutionState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RemediationExecutionState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RemediationExecutionState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RemediationExecutionState {
+    fn from(value: &'a str) -> RemediationExecutionState {
+        match value {
+            "QUEUED" => RemediationExecutionState::Queued,
+            "IN_PROGRESS" => RemediationExecutionState::InProgress,
+            "SUCCEEDED" => RemediationExecutionState::Succeeded,
+            "FAILED" => RemediationExecutionState::Failed,
+            _ => RemediationExecutionState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RemediationExecutionState {
+    fn from(value: String) -> RemediationExecutionState {
+        match value.as_str() {
+            "QUEUED" => RemediationExecutionState::Queued,
+            "IN_PROGRESS" => RemediationExecutionState::InProgress,
+            "SUCCEEDED" => RemediationExecutionState::Succeeded,
+            "FAILED" => RemediationExecutionState::Failed,
+            _ => RemediationExecutionState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RemediationExecutionState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RemediationExecutionState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RemediationExecutionState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RemediationExecutionState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RemediationExecutionState {
+    fn deserialize<D>(deserializer: D) -> Result<RemediationExecutionState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Provides details of the current status of the invoked remediation action for that resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RemediationExecutionStatus {
     /// <p>Start time when the remediation was executed.</p>
     #[serde(rename = "InvocationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub invocation_time: Option<f64>,
+    pub invocation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The time when the remediation execution was last updated.</p>
     #[serde(rename = "LastUpdatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_time: Option<f64>,
+    pub last_updated_time: Option<::chrono::DateTime<::chrono::Utc>>,
     #[serde(rename = "ResourceKey")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub resource_key: Option<ResourceKey>,
     /// <p>ENUM of the values.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<RemediationExecutionState>,
     /// <p>Details of every step.</p>
     #[serde(rename = "StepDetails")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3277,18 +5730,126 @@ pub struct RemediationExecutionStep {
     pub name: Option<String>,
     /// <p>The time when the step started.</p>
     #[serde(rename = "StartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The valid status of the step.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<RemediationExecutionStepState>,
     /// <p>The time when the step stopped.</p>
     #[serde(rename = "StopTime")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub stop_time: Option<f64>,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop_time: Option<::chrono::DateTime<::chrono::Utc>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RemediationExecutionStepState {
+    /// `SUCCEEDED`
+    Succeeded,
+    /// `PENDING`
+    Pending,
+    /// `FAILED`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RemediationExecutionStepState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RemediationExecutionStepState::Succeeded => "SUCCEEDED",
+            RemediationExecutionStepState::Pending => "PENDING",
+            RemediationExecutionStepState::Failed => "FAILED",
+            RemediationExecutionStepState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RemediationExecutionStepState {
+    fn default() -> RemediationExecutionStepState {
+        RemediationExecutionStepState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RemediationExecutionStepState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RemediationExecutionStepState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RemediationExecutionStepState {
+    fn from(value: &'a str) -> RemediationExecutionStepState {
+        match value {
+            "SUCCEEDED" => RemediationExecutionStepState::Succeeded,
+            "PENDING" => RemediationExecutionStepState::Pending,
+            "FAILED" => RemediationExecutionStepState::Failed,
+            _ => RemediationExecutionStepState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RemediationExecutionStepState {
+    fn from(value: String) -> RemediationExecutionStepState {
+        match value.as_str() {
+            "SUCCEEDED" => RemediationExecutionStepState::Succeeded,
+            "PENDING" => RemediationExecutionStepState::Pending,
+            "FAILED" => RemediationExecutionStepState::Failed,
+            _ => RemediationExecutionStepState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RemediationExecutionStepState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RemediationExecutionStepState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RemediationExecutionStepState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RemediationExecutionStepState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for RemediationExecutionStepState {
+    fn deserialize<D>(deserializer: D) -> Result<RemediationExecutionStepState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The value is either a dynamic (resource) value or a static value. You must select either a dynamic value or a static value.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RemediationParameterValue {
@@ -3302,21 +5863,109 @@ pub struct RemediationParameterValue {
     pub static_value: Option<StaticValue>,
 }
 
-/// <p>An object that contains the resource type and the number of resources.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct ResourceCount {
-    /// <p>The number of resources.</p>
-    #[serde(rename = "count")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub count: Option<i64>,
-    /// <p>The resource type (for example, <code>"AWS::EC2::Instance"</code>).</p>
-    #[serde(rename = "resourceType")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RemediationTargetType {
+    /// `SSM_DOCUMENT`
+    SsmDocument,
+    /// A value not known to this version of the crate
+    Unknown(String),
 }
 
-/// <p>Filters the resource count based on account ID, region, and resource type.</p>
+impl RemediationTargetType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RemediationTargetType::SsmDocument => "SSM_DOCUMENT",
+            RemediationTargetType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RemediationTargetType {
+    fn default() -> RemediationTargetType {
+        RemediationTargetType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RemediationTargetType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RemediationTargetType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RemediationTargetType {
+    fn from(value: &'a str) -> RemediationTargetType {
+        match value {
+            "SSM_DOCUMENT" => RemediationTargetType::SsmDocument,
+            _ => RemediationTargetType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RemediationTargetType {
+    fn from(value: String) -> RemediationTargetType {
+        match value.as_str() {
+            "SSM_DOCUMENT" => RemediationTargetType::SsmDocument,
+            _ => RemediationTargetType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RemediationTargetType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RemediationTargetType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RemediationTargetType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RemediationTargetType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RemediationTargetType {
+    fn deserialize<D>(deserializer: D) -> Result<RemediationTargetType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>An object that contains the resource type and the number of resources.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct ResourceCount {
+    /// <p>The number of resources.</p>
+    #[serde(rename = "count")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<i64>,
+    /// <p>The resource type (for example, <code>"AWS::EC2::Instance"</code>).</p>
+    #[serde(rename = "resourceType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_type: Option<ResourceType>,
+}
+
+/// <p>Filters the resource count based on account ID, region, and resource type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ResourceCountFilters {
@@ -3331,9 +5980,107 @@ pub struct ResourceCountFilters {
     /// <p>The type of the AWS resource.</p>
     #[serde(rename = "ResourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ResourceType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ResourceCountGroupKey {
+    /// `RESOURCE_TYPE`
+    ResourceType,
+    /// `ACCOUNT_ID`
+    AccountId,
+    /// `AWS_REGION`
+    AwsRegion,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ResourceCountGroupKey {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceCountGroupKey::ResourceType => "RESOURCE_TYPE",
+            ResourceCountGroupKey::AccountId => "ACCOUNT_ID",
+            ResourceCountGroupKey::AwsRegion => "AWS_REGION",
+            ResourceCountGroupKey::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ResourceCountGroupKey {
+    fn default() -> ResourceCountGroupKey {
+        ResourceCountGroupKey::Unknown(String::new())
+    }
 }
 
+impl AsRef<str> for ResourceCountGroupKey {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ResourceCountGroupKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ResourceCountGroupKey {
+    fn from(value: &'a str) -> ResourceCountGroupKey {
+        match value {
+            "RESOURCE_TYPE" => ResourceCountGroupKey::ResourceType,
+            "ACCOUNT_ID" => ResourceCountGroupKey::AccountId,
+            "AWS_REGION" => ResourceCountGroupKey::AwsRegion,
+            _ => ResourceCountGroupKey::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceCountGroupKey {
+    fn from(value: String) -> ResourceCountGroupKey {
+        match value.as_str() {
+            "RESOURCE_TYPE" => ResourceCountGroupKey::ResourceType,
+            "ACCOUNT_ID" => ResourceCountGroupKey::AccountId,
+            "AWS_REGION" => ResourceCountGroupKey::AwsRegion,
+            _ => ResourceCountGroupKey::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ResourceCountGroupKey {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ResourceCountGroupKey, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ResourceCountGroupKey {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ResourceCountGroupKey {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ResourceCountGroupKey {
+    fn deserialize<D>(deserializer: D) -> Result<ResourceCountGroupKey, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Filters the results by resource account ID, region, resource ID, and resource name.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -3362,8 +6109,13 @@ pub struct ResourceFilters {
 pub struct ResourceIdentifier {
     /// <p>The time that the resource was deleted.</p>
     #[serde(rename = "resourceDeletionTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_deletion_time: Option<f64>,
+    pub resource_deletion_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The ID of the resource (for example, <code>sg-xxxxxx</code>).</p>
     #[serde(rename = "resourceId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3375,7 +6127,7 @@ pub struct ResourceIdentifier {
     /// <p>The type of resource.</p>
     #[serde(rename = "resourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ResourceType>,
 }
 
 /// <p>The details that identify a resource within AWS Config, including the resource type and resource ID.</p>
@@ -3386,119 +6138,2643 @@ pub struct ResourceKey {
     pub resource_id: String,
     /// <p>The resource type.</p>
     #[serde(rename = "resourceType")]
-    pub resource_type: String,
-}
-
-/// <p>The dynamic value of the resource.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct ResourceValue {
-    /// <p>The value is a resource ID.</p>
-    #[serde(rename = "Value")]
-    pub value: String,
-}
-
-/// <p>An object with the name of the retention configuration and the retention period in days. The object stores the configuration for data retention in AWS Config.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct RetentionConfiguration {
-    /// <p>The name of the retention configuration object.</p>
-    #[serde(rename = "Name")]
-    pub name: String,
-    /// <p><p>Number of days AWS Config stores your historical information.</p> <note> <p>Currently, only applicable to the configuration item history.</p> </note></p>
-    #[serde(rename = "RetentionPeriodInDays")]
-    pub retention_period_in_days: i64,
-}
-
-/// <p>Defines which resources trigger an evaluation for an AWS Config rule. The scope can include one or more resource types, a combination of a tag key and value, or a combination of one resource type and one resource ID. Specify a scope to constrain which resources trigger an evaluation for a rule. Otherwise, evaluations for the rule are triggered when any resource in your recording group changes in configuration.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct Scope {
-    /// <p>The ID of the only AWS resource that you want to trigger an evaluation for the rule. If you specify a resource ID, you must specify one resource type for <code>ComplianceResourceTypes</code>.</p>
-    #[serde(rename = "ComplianceResourceId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub compliance_resource_id: Option<String>,
-    /// <p>The resource types of only those AWS resources that you want to trigger an evaluation for the rule. You can only specify one type if you also specify a resource ID for <code>ComplianceResourceId</code>.</p>
-    #[serde(rename = "ComplianceResourceTypes")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub compliance_resource_types: Option<Vec<String>>,
-    /// <p>The tag key that is applied to only those AWS resources that you want to trigger an evaluation for the rule.</p>
-    #[serde(rename = "TagKey")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub tag_key: Option<String>,
-    /// <p>The tag value applied to only those AWS resources that you want to trigger an evaluation for the rule. If you specify a value for <code>TagValue</code>, you must also specify a value for <code>TagKey</code>.</p>
-    #[serde(rename = "TagValue")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub tag_value: Option<String>,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct SelectResourceConfigRequest {
-    /// <p>The SQL query <code>SELECT</code> command.</p>
-    #[serde(rename = "Expression")]
-    pub expression: String,
-    /// <p>The maximum number of query results returned on each page. </p>
-    #[serde(rename = "Limit")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub limit: Option<i64>,
-    /// <p>The <code>nextToken</code> string returned in a previous request that you use to request the next page of results in a paginated response. </p>
-    #[serde(rename = "NextToken")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_token: Option<String>,
+    pub resource_type: ResourceType,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ResourceType {
+    /// `AWS::EC2::CustomerGateway`
+    AwsEc2CustomerGateway,
+    /// `AWS::EC2::EIP`
+    AwsEc2Eip,
+    /// `AWS::EC2::Host`
+    AwsEc2Host,
+    /// `AWS::EC2::Instance`
+    AwsEc2Instance,
+    /// `AWS::EC2::InternetGateway`
+    AwsEc2InternetGateway,
+    /// `AWS::EC2::NetworkAcl`
+    AwsEc2NetworkAcl,
+    /// `AWS::EC2::NetworkInterface`
+    AwsEc2NetworkInterface,
+    /// `AWS::EC2::RouteTable`
+    AwsEc2RouteTable,
+    /// `AWS::EC2::SecurityGroup`
+    AwsEc2SecurityGroup,
+    /// `AWS::EC2::Subnet`
+    AwsEc2Subnet,
+    /// `AWS::CloudTrail::Trail`
+    AwsCloudTrailTrail,
+    /// `AWS::EC2::Volume`
+    AwsEc2Volume,
+    /// `AWS::EC2::VPC`
+    AwsEc2Vpc,
+    /// `AWS::EC2::VPNConnection`
+    AwsEc2VPNConnection,
+    /// `AWS::EC2::VPNGateway`
+    AwsEc2VPNGateway,
+    /// `AWS::EC2::RegisteredHAInstance`
+    AwsEc2RegisteredHAInstance,
+    /// `AWS::EC2::NatGateway`
+    AwsEc2NatGateway,
+    /// `AWS::EC2::EgressOnlyInternetGateway`
+    AwsEc2EgressOnlyInternetGateway,
+    /// `AWS::EC2::VPCEndpoint`
+    AwsEc2VPCEndpoint,
+    /// `AWS::EC2::VPCEndpointService`
+    AwsEc2VPCEndpointService,
+    /// `AWS::EC2::FlowLog`
+    AwsEc2FlowLog,
+    /// `AWS::EC2::VPCPeeringConnection`
+    AwsEc2VPCPeeringConnection,
+    /// `AWS::Elasticsearch::Domain`
+    AwsElasticsearchDomain,
+    /// `AWS::IAM::Group`
+    AwsIamGroup,
+    /// `AWS::IAM::Policy`
+    AwsIamPolicy,
+    /// `AWS::IAM::Role`
+    AwsIamRole,
+    /// `AWS::IAM::User`
+    AwsIamUser,
+    /// `AWS::ElasticLoadBalancingV2::LoadBalancer`
+    AwsElasticLoadBalancingV2LoadBalancer,
+    /// `AWS::ACM::Certificate`
+    AwsAcmCertificate,
+    /// `AWS::RDS::DBInstance`
+    AwsRdsDBInstance,
+    /// `AWS::RDS::DBSubnetGroup`
+    AwsRdsDBSubnetGroup,
+    /// `AWS::RDS::DBSecurityGroup`
+    AwsRdsDBSecurityGroup,
+    /// `AWS::RDS::DBSnapshot`
+    AwsRdsDBSnapshot,
+    /// `AWS::RDS::DBCluster`
+    AwsRdsDBCluster,
+    /// `AWS::RDS::DBClusterSnapshot`
+    AwsRdsDBClusterSnapshot,
+    /// `AWS::RDS::EventSubscription`
+    AwsRdsEventSubscription,
+    /// `AWS::S3::Bucket`
+    AwsS3Bucket,
+    /// `AWS::S3::AccountPublicAccessBlock`
+    AwsS3AccountPublicAccessBlock,
+    /// `AWS::Redshift::Cluster`
+    AwsRedshiftCluster,
+    /// `AWS::Redshift::ClusterSnapshot`
+    AwsRedshiftClusterSnapshot,
+    /// `AWS::Redshift::ClusterParameterGroup`
+    AwsRedshiftClusterParameterGroup,
+    /// `AWS::Redshift::ClusterSecurityGroup`
+    AwsRedshiftClusterSecurityGroup,
+    /// `AWS::Redshift::ClusterSubnetGroup`
+    AwsRedshiftClusterSubnetGroup,
+    /// `AWS::Redshift::EventSubscription`
+    AwsRedshiftEventSubscription,
+    /// `AWS::SSM::ManagedInstanceInventory`
+    AwsSsmManagedInstanceInventory,
+    /// `AWS::CloudWatch::Alarm`
+    AwsCloudWatchAlarm,
+    /// `AWS::CloudFormation::Stack`
+    AwsCloudFormationStack,
+    /// `AWS::ElasticLoadBalancing::LoadBalancer`
+    AwsElasticLoadBalancingLoadBalancer,
+    /// `AWS::AutoScaling::AutoScalingGroup`
+    AwsAutoScalingAutoScalingGroup,
+    /// `AWS::AutoScaling::LaunchConfiguration`
+    AwsAutoScalingLaunchConfiguration,
+    /// `AWS::AutoScaling::ScalingPolicy`
+    AwsAutoScalingScalingPolicy,
+    /// `AWS::AutoScaling::ScheduledAction`
+    AwsAutoScalingScheduledAction,
+    /// `AWS::DynamoDB::Table`
+    AwsDynamoDBTable,
+    /// `AWS::CodeBuild::Project`
+    AwsCodeBuildProject,
+    /// `AWS::WAF::RateBasedRule`
+    AwsWafRateBasedRule,
+    /// `AWS::WAF::Rule`
+    AwsWafRule,
+    /// `AWS::WAF::RuleGroup`
+    AwsWafRuleGroup,
+    /// `AWS::WAF::WebACL`
+    AwsWafWebACL,
+    /// `AWS::WAFRegional::RateBasedRule`
+    AwsWAFRegionalRateBasedRule,
+    /// `AWS::WAFRegional::Rule`
+    AwsWAFRegionalRule,
+    /// `AWS::WAFRegional::RuleGroup`
+    AwsWAFRegionalRuleGroup,
+    /// `AWS::WAFRegional::WebACL`
+    AwsWAFRegionalWebACL,
+    /// `AWS::CloudFront::Distribution`
+    AwsCloudFrontDistribution,
+    /// `AWS::CloudFront::StreamingDistribution`
+    AwsCloudFrontStreamingDistribution,
+    /// `AWS::Lambda::Function`
+    AwsLambdaFunction,
+    /// `AWS::NetworkFirewall::Firewall`
+    AwsNetworkFirewallFirewall,
+    /// `AWS::NetworkFirewall::FirewallPolicy`
+    AwsNetworkFirewallFirewallPolicy,
+    /// `AWS::NetworkFirewall::RuleGroup`
+    AwsNetworkFirewallRuleGroup,
+    /// `AWS::ElasticBeanstalk::Application`
+    AwsElasticBeanstalkApplication,
+    /// `AWS::ElasticBeanstalk::ApplicationVersion`
+    AwsElasticBeanstalkApplicationVersion,
+    /// `AWS::ElasticBeanstalk::Environment`
+    AwsElasticBeanstalkEnvironment,
+    /// `AWS::WAFv2::WebACL`
+    AwsWAFv2WebACL,
+    /// `AWS::WAFv2::RuleGroup`
+    AwsWAFv2RuleGroup,
+    /// `AWS::WAFv2::IPSet`
+    AwsWAFv2IPSet,
+    /// `AWS::WAFv2::RegexPatternSet`
+    AwsWAFv2RegexPatternSet,
+    /// `AWS::WAFv2::ManagedRuleSet`
+    AwsWAFv2ManagedRuleSet,
+    /// `AWS::XRay::EncryptionConfig`
+    AwsXRayEncryptionConfig,
+    /// `AWS::SSM::AssociationCompliance`
+    AwsSsmAssociationCompliance,
+    /// `AWS::SSM::PatchCompliance`
+    AwsSsmPatchCompliance,
+    /// `AWS::Shield::Protection`
+    AwsShieldProtection,
+    /// `AWS::ShieldRegional::Protection`
+    AwsShieldRegionalProtection,
+    /// `AWS::Config::ConformancePackCompliance`
+    AwsConfigConformancePackCompliance,
+    /// `AWS::Config::ResourceCompliance`
+    AwsConfigResourceCompliance,
+    /// `AWS::ApiGateway::Stage`
+    AwsApiGatewayStage,
+    /// `AWS::ApiGateway::RestApi`
+    AwsApiGatewayRestApi,
+    /// `AWS::ApiGatewayV2::Stage`
+    AwsApiGatewayV2Stage,
+    /// `AWS::ApiGatewayV2::Api`
+    AwsApiGatewayV2Api,
+    /// `AWS::CodePipeline::Pipeline`
+    AwsCodePipelinePipeline,
+    /// `AWS::ServiceCatalog::CloudFormationProvisionedProduct`
+    AwsServiceCatalogCloudFormationProvisionedProduct,
+    /// `AWS::ServiceCatalog::CloudFormationProduct`
+    AwsServiceCatalogCloudFormationProduct,
+    /// `AWS::ServiceCatalog::Portfolio`
+    AwsServiceCatalogPortfolio,
+    /// `AWS::SQS::Queue`
+    AwsSqsQueue,
+    /// `AWS::KMS::Key`
+    AwsKmsKey,
+    /// `AWS::QLDB::Ledger`
+    AwsQldbLedger,
+    /// `AWS::SecretsManager::Secret`
+    AwsSecretsManagerSecret,
+    /// `AWS::SNS::Topic`
+    AwsSnsTopic,
+    /// `AWS::SSM::FileData`
+    AwsSsmFileData,
+    /// `AWS::Backup::BackupPlan`
+    AwsBackupBackupPlan,
+    /// `AWS::Backup::BackupSelection`
+    AwsBackupBackupSelection,
+    /// `AWS::Backup::BackupVault`
+    AwsBackupBackupVault,
+    /// `AWS::Backup::RecoveryPoint`
+    AwsBackupRecoveryPoint,
+    /// `AWS::ECR::Repository`
+    AwsEcrRepository,
+    /// `AWS::ECS::Cluster`
+    AwsEcsCluster,
+    /// `AWS::ECS::Service`
+    AwsEcsService,
+    /// `AWS::ECS::TaskDefinition`
+    AwsEcsTaskDefinition,
+    /// `AWS::EFS::AccessPoint`
+    AwsEfsAccessPoint,
+    /// `AWS::EFS::FileSystem`
+    AwsEfsFileSystem,
+    /// `AWS::EKS::Cluster`
+    AwsEksCluster,
+    /// `AWS::OpenSearch::Domain`
+    AwsOpenSearchDomain,
+    /// `AWS::EC2::TransitGateway`
+    AwsEc2TransitGateway,
+    /// `AWS::Kinesis::Stream`
+    AwsKinesisStream,
+    /// `AWS::Kinesis::StreamConsumer`
+    AwsKinesisStreamConsumer,
+    /// `AWS::CodeDeploy::Application`
+    AwsCodeDeployApplication,
+    /// `AWS::CodeDeploy::DeploymentConfig`
+    AwsCodeDeployDeploymentConfig,
+    /// `AWS::CodeDeploy::DeploymentGroup`
+    AwsCodeDeployDeploymentGroup,
+    /// `AWS::EC2::LaunchTemplate`
+    AwsEc2LaunchTemplate,
+    /// `AWS::ECR::PublicRepository`
+    AwsEcrPublicRepository,
+    /// `AWS::GuardDuty::Detector`
+    AwsGuardDutyDetector,
+    /// `AWS::EMR::SecurityConfiguration`
+    AwsEmrSecurityConfiguration,
+    /// `AWS::SageMaker::CodeRepository`
+    AwsSageMakerCodeRepository,
+    /// `AWS::Route53Resolver::ResolverEndpoint`
+    AwsRoute53ResolverResolverEndpoint,
+    /// `AWS::Route53Resolver::ResolverRule`
+    AwsRoute53ResolverResolverRule,
+    /// `AWS::Route53Resolver::ResolverRuleAssociation`
+    AwsRoute53ResolverResolverRuleAssociation,
+    /// `AWS::DMS::ReplicationSubnetGroup`
+    AwsDmsReplicationSubnetGroup,
+    /// `AWS::DMS::EventSubscription`
+    AwsDmsEventSubscription,
+    /// `AWS::MSK::Cluster`
+    AwsMskCluster,
+    /// `AWS::StepFunctions::Activity`
+    AwsStepFunctionsActivity,
+    /// `AWS::WorkSpaces::Workspace`
+    AwsWorkSpacesWorkspace,
+    /// `AWS::WorkSpaces::ConnectionAlias`
+    AwsWorkSpacesConnectionAlias,
+    /// `AWS::SageMaker::Model`
+    AwsSageMakerModel,
+    /// `AWS::ElasticLoadBalancingV2::Listener`
+    AwsElasticLoadBalancingV2Listener,
+    /// `AWS::StepFunctions::StateMachine`
+    AwsStepFunctionsStateMachine,
+    /// `AWS::Batch::JobQueue`
+    AwsBatchJobQueue,
+    /// `AWS::Batch::ComputeEnvironment`
+    AwsBatchComputeEnvironment,
+    /// `AWS::AccessAnalyzer::Analyzer`
+    AwsAccessAnalyzerAnalyzer,
+    /// `AWS::Athena::WorkGroup`
+    AwsAthenaWorkGroup,
+    /// `AWS::Athena::DataCatalog`
+    AwsAthenaDataCatalog,
+    /// `AWS::Detective::Graph`
+    AwsDetectiveGraph,
+    /// `AWS::GlobalAccelerator::Accelerator`
+    AwsGlobalAcceleratorAccelerator,
+    /// `AWS::GlobalAccelerator::EndpointGroup`
+    AwsGlobalAcceleratorEndpointGroup,
+    /// `AWS::GlobalAccelerator::Listener`
+    AwsGlobalAcceleratorListener,
+    /// `AWS::EC2::TransitGatewayAttachment`
+    AwsEc2TransitGatewayAttachment,
+    /// `AWS::EC2::TransitGatewayRouteTable`
+    AwsEc2TransitGatewayRouteTable,
+    /// `AWS::DMS::Certificate`
+    AwsDmsCertificate,
+    /// `AWS::AppConfig::Application`
+    AwsAppConfigApplication,
+    /// `AWS::AppSync::GraphQLApi`
+    AwsAppSyncGraphQLApi,
+    /// `AWS::DataSync::LocationSMB`
+    AwsDataSyncLocationSMB,
+    /// `AWS::DataSync::LocationFSxLustre`
+    AwsDataSyncLocationFSxLustre,
+    /// `AWS::DataSync::LocationS3`
+    AwsDataSyncLocationS3,
+    /// `AWS::DataSync::LocationEFS`
+    AwsDataSyncLocationEFS,
+    /// `AWS::DataSync::Task`
+    AwsDataSyncTask,
+    /// `AWS::DataSync::LocationNFS`
+    AwsDataSyncLocationNFS,
+    /// `AWS::EC2::NetworkInsightsAccessScopeAnalysis`
+    AwsEc2NetworkInsightsAccessScopeAnalysis,
+    /// `AWS::EKS::FargateProfile`
+    AwsEksFargateProfile,
+    /// `AWS::Glue::Job`
+    AwsGlueJob,
+    /// `AWS::GuardDuty::ThreatIntelSet`
+    AwsGuardDutyThreatIntelSet,
+    /// `AWS::GuardDuty::IPSet`
+    AwsGuardDutyIPSet,
+    /// `AWS::SageMaker::Workteam`
+    AwsSageMakerWorkteam,
+    /// `AWS::SageMaker::NotebookInstanceLifecycleConfig`
+    AwsSageMakerNotebookInstanceLifecycleConfig,
+    /// `AWS::ServiceDiscovery::Service`
+    AwsServiceDiscoveryService,
+    /// `AWS::ServiceDiscovery::PublicDnsNamespace`
+    AwsServiceDiscoveryPublicDnsNamespace,
+    /// `AWS::SES::ContactList`
+    AwsSesContactList,
+    /// `AWS::SES::ConfigurationSet`
+    AwsSesConfigurationSet,
+    /// `AWS::Route53::HostedZone`
+    AwsRoute53HostedZone,
+    /// `AWS::IoTEvents::Input`
+    AwsIoTEventsInput,
+    /// `AWS::IoTEvents::DetectorModel`
+    AwsIoTEventsDetectorModel,
+    /// `AWS::IoTEvents::AlarmModel`
+    AwsIoTEventsAlarmModel,
+    /// `AWS::ServiceDiscovery::HttpNamespace`
+    AwsServiceDiscoveryHttpNamespace,
+    /// `AWS::Events::EventBus`
+    AwsEventsEventBus,
+    /// `AWS::ImageBuilder::ContainerRecipe`
+    AwsImageBuilderContainerRecipe,
+    /// `AWS::ImageBuilder::DistributionConfiguration`
+    AwsImageBuilderDistributionConfiguration,
+    /// `AWS::ImageBuilder::InfrastructureConfiguration`
+    AwsImageBuilderInfrastructureConfiguration,
+    /// `AWS::DataSync::LocationObjectStorage`
+    AwsDataSyncLocationObjectStorage,
+    /// `AWS::DataSync::LocationHDFS`
+    AwsDataSyncLocationHDFS,
+    /// `AWS::Glue::Classifier`
+    AwsGlueClassifier,
+    /// `AWS::Route53RecoveryReadiness::Cell`
+    AwsRoute53RecoveryReadinessCell,
+    /// `AWS::Route53RecoveryReadiness::ReadinessCheck`
+    AwsRoute53RecoveryReadinessReadinessCheck,
+    /// `AWS::ECR::RegistryPolicy`
+    AwsEcrRegistryPolicy,
+    /// `AWS::Backup::ReportPlan`
+    AwsBackupReportPlan,
+    /// `AWS::Lightsail::Certificate`
+    AwsLightsailCertificate,
+    /// `AWS::RUM::AppMonitor`
+    AwsRumAppMonitor,
+    /// `AWS::Events::Endpoint`
+    AwsEventsEndpoint,
+    /// `AWS::SES::ReceiptRuleSet`
+    AwsSesReceiptRuleSet,
+    /// `AWS::Events::Archive`
+    AwsEventsArchive,
+    /// `AWS::Events::ApiDestination`
+    AwsEventsApiDestination,
+    /// `AWS::Lightsail::Disk`
+    AwsLightsailDisk,
+    /// `AWS::FIS::ExperimentTemplate`
+    AwsFisExperimentTemplate,
+    /// `AWS::DataSync::LocationFSxWindows`
+    AwsDataSyncLocationFSxWindows,
+    /// `AWS::SES::ReceiptFilter`
+    AwsSesReceiptFilter,
+    /// `AWS::GuardDuty::Filter`
+    AwsGuardDutyFilter,
+    /// `AWS::SES::Template`
+    AwsSesTemplate,
+    /// `AWS::AmazonMQ::Broker`
+    AwsAmazonMQBroker,
+    /// `AWS::AppConfig::Environment`
+    AwsAppConfigEnvironment,
+    /// `AWS::AppConfig::ConfigurationProfile`
+    AwsAppConfigConfigurationProfile,
+    /// `AWS::Cloud9::EnvironmentEC2`
+    AwsCloud9EnvironmentEC2,
+    /// `AWS::EventSchemas::Registry`
+    AwsEventSchemasRegistry,
+    /// `AWS::EventSchemas::RegistryPolicy`
+    AwsEventSchemasRegistryPolicy,
+    /// `AWS::EventSchemas::Discoverer`
+    AwsEventSchemasDiscoverer,
+    /// `AWS::FraudDetector::Label`
+    AwsFraudDetectorLabel,
+    /// `AWS::FraudDetector::EntityType`
+    AwsFraudDetectorEntityType,
+    /// `AWS::FraudDetector::Variable`
+    AwsFraudDetectorVariable,
+    /// `AWS::FraudDetector::Outcome`
+    AwsFraudDetectorOutcome,
+    /// `AWS::IoT::Authorizer`
+    AwsIoTAuthorizer,
+    /// `AWS::IoT::SecurityProfile`
+    AwsIoTSecurityProfile,
+    /// `AWS::IoT::RoleAlias`
+    AwsIoTRoleAlias,
+    /// `AWS::IoT::Dimension`
+    AwsIoTDimension,
+    /// `AWS::IoTAnalytics::Datastore`
+    AwsIoTAnalyticsDatastore,
+    /// `AWS::Lightsail::Bucket`
+    AwsLightsailBucket,
+    /// `AWS::Lightsail::StaticIp`
+    AwsLightsailStaticIp,
+    /// `AWS::MediaPackage::PackagingGroup`
+    AwsMediaPackagePackagingGroup,
+    /// `AWS::Route53RecoveryReadiness::RecoveryGroup`
+    AwsRoute53RecoveryReadinessRecoveryGroup,
+    /// `AWS::ResilienceHub::ResiliencyPolicy`
+    AwsResilienceHubResiliencyPolicy,
+    /// `AWS::Transfer::Workflow`
+    AwsTransferWorkflow,
+    /// `AWS::EKS::IdentityProviderConfig`
+    AwsEksIdentityProviderConfig,
+    /// `AWS::EKS::Addon`
+    AwsEksAddon,
+    /// `AWS::Glue::MLTransform`
+    AwsGlueMLTransform,
+    /// `AWS::IoT::Policy`
+    AwsIoTPolicy,
+    /// `AWS::IoT::MitigationAction`
+    AwsIoTMitigationAction,
+    /// `AWS::IoTTwinMaker::Workspace`
+    AwsIoTTwinMakerWorkspace,
+    /// `AWS::IoTTwinMaker::Entity`
+    AwsIoTTwinMakerEntity,
+    /// `AWS::IoTAnalytics::Dataset`
+    AwsIoTAnalyticsDataset,
+    /// `AWS::IoTAnalytics::Pipeline`
+    AwsIoTAnalyticsPipeline,
+    /// `AWS::IoTAnalytics::Channel`
+    AwsIoTAnalyticsChannel,
+    /// `AWS::IoTSiteWise::Dashboard`
+    AwsIoTSiteWiseDashboard,
+    /// `AWS::IoTSiteWise::Project`
+    AwsIoTSiteWiseProject,
+    /// `AWS::IoTSiteWise::Portal`
+    AwsIoTSiteWisePortal,
+    /// `AWS::IoTSiteWise::AssetModel`
+    AwsIoTSiteWiseAssetModel,
+    /// `AWS::IVS::Channel`
+    AwsIvsChannel,
+    /// `AWS::IVS::RecordingConfiguration`
+    AwsIvsRecordingConfiguration,
+    /// `AWS::IVS::PlaybackKeyPair`
+    AwsIvsPlaybackKeyPair,
+    /// `AWS::KinesisAnalyticsV2::Application`
+    AwsKinesisAnalyticsV2Application,
+    /// `AWS::RDS::GlobalCluster`
+    AwsRdsGlobalCluster,
+    /// `AWS::S3::MultiRegionAccessPoint`
+    AwsS3MultiRegionAccessPoint,
+    /// `AWS::DeviceFarm::TestGridProject`
+    AwsDeviceFarmTestGridProject,
+    /// `AWS::Budgets::BudgetsAction`
+    AwsBudgetsBudgetsAction,
+    /// `AWS::Lex::Bot`
+    AwsLexBot,
+    /// `AWS::CodeGuruReviewer::RepositoryAssociation`
+    AwsCodeGuruReviewerRepositoryAssociation,
+    /// `AWS::IoT::CustomMetric`
+    AwsIoTCustomMetric,
+    /// `AWS::Route53Resolver::FirewallDomainList`
+    AwsRoute53ResolverFirewallDomainList,
+    /// `AWS::RoboMaker::RobotApplicationVersion`
+    AwsRoboMakerRobotApplicationVersion,
+    /// `AWS::EC2::TrafficMirrorSession`
+    AwsEc2TrafficMirrorSession,
+    /// `AWS::IoTSiteWise::Gateway`
+    AwsIoTSiteWiseGateway,
+    /// `AWS::Lex::BotAlias`
+    AwsLexBotAlias,
+    /// `AWS::LookoutMetrics::Alert`
+    AwsLookoutMetricsAlert,
+    /// `AWS::IoT::AccountAuditConfiguration`
+    AwsIoTAccountAuditConfiguration,
+    /// `AWS::EC2::TrafficMirrorTarget`
+    AwsEc2TrafficMirrorTarget,
+    /// `AWS::S3::StorageLens`
+    AwsS3StorageLens,
+    /// `AWS::IoT::ScheduledAudit`
+    AwsIoTScheduledAudit,
+    /// `AWS::Events::Connection`
+    AwsEventsConnection,
+    /// `AWS::EventSchemas::Schema`
+    AwsEventSchemasSchema,
+    /// `AWS::MediaPackage::PackagingConfiguration`
+    AwsMediaPackagePackagingConfiguration,
+    /// `AWS::KinesisVideo::SignalingChannel`
+    AwsKinesisVideoSignalingChannel,
+    /// `AWS::AppStream::DirectoryConfig`
+    AwsAppStreamDirectoryConfig,
+    /// `AWS::LookoutVision::Project`
+    AwsLookoutVisionProject,
+    /// `AWS::Route53RecoveryControl::Cluster`
+    AwsRoute53RecoveryControlCluster,
+    /// `AWS::Route53RecoveryControl::SafetyRule`
+    AwsRoute53RecoveryControlSafetyRule,
+    /// `AWS::Route53RecoveryControl::ControlPanel`
+    AwsRoute53RecoveryControlControlPanel,
+    /// `AWS::Route53RecoveryControl::RoutingControl`
+    AwsRoute53RecoveryControlRoutingControl,
+    /// `AWS::Route53RecoveryReadiness::ResourceSet`
+    AwsRoute53RecoveryReadinessResourceSet,
+    /// `AWS::RoboMaker::SimulationApplication`
+    AwsRoboMakerSimulationApplication,
+    /// `AWS::RoboMaker::RobotApplication`
+    AwsRoboMakerRobotApplication,
+    /// `AWS::HealthLake::FHIRDatastore`
+    AwsHealthLakeFHIRDatastore,
+    /// `AWS::Pinpoint::Segment`
+    AwsPinpointSegment,
+    /// `AWS::Pinpoint::ApplicationSettings`
+    AwsPinpointApplicationSettings,
+    /// `AWS::Events::Rule`
+    AwsEventsRule,
+    /// `AWS::EC2::DHCPOptions`
+    AwsEc2DHCPOptions,
+    /// `AWS::EC2::NetworkInsightsPath`
+    AwsEc2NetworkInsightsPath,
+    /// `AWS::EC2::TrafficMirrorFilter`
+    AwsEc2TrafficMirrorFilter,
+    /// `AWS::EC2::IPAM`
+    AwsEc2Ipam,
+    /// `AWS::IoTTwinMaker::Scene`
+    AwsIoTTwinMakerScene,
+    /// `AWS::NetworkManager::TransitGatewayRegistration`
+    AwsNetworkManagerTransitGatewayRegistration,
+    /// `AWS::CustomerProfiles::Domain`
+    AwsCustomerProfilesDomain,
+    /// `AWS::AutoScaling::WarmPool`
+    AwsAutoScalingWarmPool,
+    /// `AWS::Connect::PhoneNumber`
+    AwsConnectPhoneNumber,
+    /// `AWS::AppConfig::DeploymentStrategy`
+    AwsAppConfigDeploymentStrategy,
+    /// `AWS::AppFlow::Flow`
+    AwsAppFlowFlow,
+    /// `AWS::AuditManager::Assessment`
+    AwsAuditManagerAssessment,
+    /// `AWS::CloudWatch::MetricStream`
+    AwsCloudWatchMetricStream,
+    /// `AWS::DeviceFarm::InstanceProfile`
+    AwsDeviceFarmInstanceProfile,
+    /// `AWS::DeviceFarm::Project`
+    AwsDeviceFarmProject,
+    /// `AWS::EC2::EC2Fleet`
+    AwsEc2EC2Fleet,
+    /// `AWS::EC2::SubnetRouteTableAssociation`
+    AwsEc2SubnetRouteTableAssociation,
+    /// `AWS::ECR::PullThroughCacheRule`
+    AwsEcrPullThroughCacheRule,
+    /// `AWS::GroundStation::Config`
+    AwsGroundStationConfig,
+    /// `AWS::ImageBuilder::ImagePipeline`
+    AwsImageBuilderImagePipeline,
+    /// `AWS::IoT::FleetMetric`
+    AwsIoTFleetMetric,
+    /// `AWS::IoTWireless::ServiceProfile`
+    AwsIoTWirelessServiceProfile,
+    /// `AWS::NetworkManager::Device`
+    AwsNetworkManagerDevice,
+    /// `AWS::NetworkManager::GlobalNetwork`
+    AwsNetworkManagerGlobalNetwork,
+    /// `AWS::NetworkManager::Link`
+    AwsNetworkManagerLink,
+    /// `AWS::NetworkManager::Site`
+    AwsNetworkManagerSite,
+    /// `AWS::Panorama::Package`
+    AwsPanoramaPackage,
+    /// `AWS::Pinpoint::App`
+    AwsPinpointApp,
+    /// `AWS::Redshift::ScheduledAction`
+    AwsRedshiftScheduledAction,
+    /// `AWS::Route53Resolver::FirewallRuleGroupAssociation`
+    AwsRoute53ResolverFirewallRuleGroupAssociation,
+    /// `AWS::SageMaker::AppImageConfig`
+    AwsSageMakerAppImageConfig,
+    /// `AWS::SageMaker::Image`
+    AwsSageMakerImage,
+    /// `AWS::ECS::TaskSet`
+    AwsEcsTaskSet,
+    /// `AWS::Cassandra::Keyspace`
+    AwsCassandraKeyspace,
+    /// `AWS::Signer::SigningProfile`
+    AwsSignerSigningProfile,
+    /// `AWS::Amplify::App`
+    AwsAmplifyApp,
+    /// `AWS::AppMesh::VirtualNode`
+    AwsAppMeshVirtualNode,
+    /// `AWS::AppMesh::VirtualService`
+    AwsAppMeshVirtualService,
+    /// `AWS::AppRunner::VpcConnector`
+    AwsAppRunnerVpcConnector,
+    /// `AWS::AppStream::Application`
+    AwsAppStreamApplication,
+    /// `AWS::CodeArtifact::Repository`
+    AwsCodeArtifactRepository,
+    /// `AWS::EC2::PrefixList`
+    AwsEc2PrefixList,
+    /// `AWS::EC2::SpotFleet`
+    AwsEc2SpotFleet,
+    /// `AWS::Evidently::Project`
+    AwsEvidentlyProject,
+    /// `AWS::Forecast::Dataset`
+    AwsForecastDataset,
+    /// `AWS::IAM::SAMLProvider`
+    AwsIamSAMLProvider,
+    /// `AWS::IAM::ServerCertificate`
+    AwsIamServerCertificate,
+    /// `AWS::Pinpoint::Campaign`
+    AwsPinpointCampaign,
+    /// `AWS::Pinpoint::InAppTemplate`
+    AwsPinpointInAppTemplate,
+    /// `AWS::SageMaker::Domain`
+    AwsSageMakerDomain,
+    /// `AWS::Transfer::Agreement`
+    AwsTransferAgreement,
+    /// `AWS::Transfer::Connector`
+    AwsTransferConnector,
+    /// `AWS::KinesisFirehose::DeliveryStream`
+    AwsKinesisFirehoseDeliveryStream,
+    /// `AWS::Amplify::Branch`
+    AwsAmplifyBranch,
+    /// `AWS::AppIntegrations::EventIntegration`
+    AwsAppIntegrationsEventIntegration,
+    /// `AWS::AppMesh::Route`
+    AwsAppMeshRoute,
+    /// `AWS::Athena::PreparedStatement`
+    AwsAthenaPreparedStatement,
+    /// `AWS::EC2::IPAMScope`
+    AwsEc2IPAMScope,
+    /// `AWS::Evidently::Launch`
+    AwsEvidentlyLaunch,
+    /// `AWS::Forecast::DatasetGroup`
+    AwsForecastDatasetGroup,
+    /// `AWS::GreengrassV2::ComponentVersion`
+    AwsGreengrassV2ComponentVersion,
+    /// `AWS::GroundStation::MissionProfile`
+    AwsGroundStationMissionProfile,
+    /// `AWS::MediaConnect::FlowEntitlement`
+    AwsMediaConnectFlowEntitlement,
+    /// `AWS::MediaConnect::FlowVpcInterface`
+    AwsMediaConnectFlowVpcInterface,
+    /// `AWS::MediaTailor::PlaybackConfiguration`
+    AwsMediaTailorPlaybackConfiguration,
+    /// `AWS::MSK::Configuration`
+    AwsMskConfiguration,
+    /// `AWS::Personalize::Dataset`
+    AwsPersonalizeDataset,
+    /// `AWS::Personalize::Schema`
+    AwsPersonalizeSchema,
+    /// `AWS::Personalize::Solution`
+    AwsPersonalizeSolution,
+    /// `AWS::Pinpoint::EmailTemplate`
+    AwsPinpointEmailTemplate,
+    /// `AWS::Pinpoint::EventStream`
+    AwsPinpointEventStream,
+    /// `AWS::ResilienceHub::App`
+    AwsResilienceHubApp,
+    /// `AWS::ACMPCA::CertificateAuthority`
+    AwsAcmpcaCertificateAuthority,
+    /// `AWS::AppConfig::HostedConfigurationVersion`
+    AwsAppConfigHostedConfigurationVersion,
+    /// `AWS::AppMesh::VirtualGateway`
+    AwsAppMeshVirtualGateway,
+    /// `AWS::AppMesh::VirtualRouter`
+    AwsAppMeshVirtualRouter,
+    /// `AWS::AppRunner::Service`
+    AwsAppRunnerService,
+    /// `AWS::CustomerProfiles::ObjectType`
+    AwsCustomerProfilesObjectType,
+    /// `AWS::DMS::Endpoint`
+    AwsDmsEndpoint,
+    /// `AWS::EC2::CapacityReservation`
+    AwsEc2CapacityReservation,
+    /// `AWS::EC2::ClientVpnEndpoint`
+    AwsEc2ClientVpnEndpoint,
+    /// `AWS::Kendra::Index`
+    AwsKendraIndex,
+    /// `AWS::KinesisVideo::Stream`
+    AwsKinesisVideoStream,
+    /// `AWS::Logs::Destination`
+    AwsLogsDestination,
+    /// `AWS::Pinpoint::EmailChannel`
+    AwsPinpointEmailChannel,
+    /// `AWS::S3::AccessPoint`
+    AwsS3AccessPoint,
+    /// `AWS::NetworkManager::CustomerGatewayAssociation`
+    AwsNetworkManagerCustomerGatewayAssociation,
+    /// `AWS::NetworkManager::LinkAssociation`
+    AwsNetworkManagerLinkAssociation,
+    /// `AWS::IoTWireless::MulticastGroup`
+    AwsIoTWirelessMulticastGroup,
+    /// `AWS::Personalize::DatasetGroup`
+    AwsPersonalizeDatasetGroup,
+    /// `AWS::IoTTwinMaker::ComponentType`
+    AwsIoTTwinMakerComponentType,
+    /// `AWS::CodeBuild::ReportGroup`
+    AwsCodeBuildReportGroup,
+    /// `AWS::SageMaker::FeatureGroup`
+    AwsSageMakerFeatureGroup,
+    /// `AWS::MSK::BatchScramSecret`
+    AwsMskBatchScramSecret,
+    /// `AWS::AppStream::Stack`
+    AwsAppStreamStack,
+    /// `AWS::IoT::JobTemplate`
+    AwsIoTJobTemplate,
+    /// `AWS::IoTWireless::FuotaTask`
+    AwsIoTWirelessFuotaTask,
+    /// `AWS::IoT::ProvisioningTemplate`
+    AwsIoTProvisioningTemplate,
+    /// `AWS::InspectorV2::Filter`
+    AwsInspectorV2Filter,
+    /// `AWS::Route53Resolver::ResolverQueryLoggingConfigAssociation`
+    AwsRoute53ResolverResolverQueryLoggingConfigAssociation,
+    /// `AWS::ServiceDiscovery::Instance`
+    AwsServiceDiscoveryInstance,
+    /// `AWS::Transfer::Certificate`
+    AwsTransferCertificate,
+    /// `AWS::MediaConnect::FlowSource`
+    AwsMediaConnectFlowSource,
+    /// `AWS::APS::RuleGroupsNamespace`
+    AwsApsRuleGroupsNamespace,
+    /// `AWS::CodeGuruProfiler::ProfilingGroup`
+    AwsCodeGuruProfilerProfilingGroup,
+    /// `AWS::Route53Resolver::ResolverQueryLoggingConfig`
+    AwsRoute53ResolverResolverQueryLoggingConfig,
+    /// `AWS::Batch::SchedulingPolicy`
+    AwsBatchSchedulingPolicy,
+    /// `AWS::ACMPCA::CertificateAuthorityActivation`
+    AwsAcmpcaCertificateAuthorityActivation,
+    /// `AWS::AppMesh::GatewayRoute`
+    AwsAppMeshGatewayRoute,
+    /// `AWS::AppMesh::Mesh`
+    AwsAppMeshMesh,
+    /// `AWS::Connect::Instance`
+    AwsConnectInstance,
+    /// `AWS::Connect::QuickConnect`
+    AwsConnectQuickConnect,
+    /// `AWS::EC2::CarrierGateway`
+    AwsEc2CarrierGateway,
+    /// `AWS::EC2::IPAMPool`
+    AwsEc2IPAMPool,
+    /// `AWS::EC2::TransitGatewayConnect`
+    AwsEc2TransitGatewayConnect,
+    /// `AWS::EC2::TransitGatewayMulticastDomain`
+    AwsEc2TransitGatewayMulticastDomain,
+    /// `AWS::ECS::CapacityProvider`
+    AwsEcsCapacityProvider,
+    /// `AWS::IAM::InstanceProfile`
+    AwsIamInstanceProfile,
+    /// `AWS::IoT::CACertificate`
+    AwsIoTCACertificate,
+    /// `AWS::IoTTwinMaker::SyncJob`
+    AwsIoTTwinMakerSyncJob,
+    /// `AWS::KafkaConnect::Connector`
+    AwsKafkaConnectConnector,
+    /// `AWS::Lambda::CodeSigningConfig`
+    AwsLambdaCodeSigningConfig,
+    /// `AWS::NetworkManager::ConnectPeer`
+    AwsNetworkManagerConnectPeer,
+    /// `AWS::ResourceExplorer2::Index`
+    AwsResourceExplorer2Index,
+    /// `AWS::AppStream::Fleet`
+    AwsAppStreamFleet,
+    /// `AWS::Cognito::UserPool`
+    AwsCognitoUserPool,
+    /// `AWS::Cognito::UserPoolClient`
+    AwsCognitoUserPoolClient,
+    /// `AWS::Cognito::UserPoolGroup`
+    AwsCognitoUserPoolGroup,
+    /// `AWS::EC2::NetworkInsightsAccessScope`
+    AwsEc2NetworkInsightsAccessScope,
+    /// `AWS::EC2::NetworkInsightsAnalysis`
+    AwsEc2NetworkInsightsAnalysis,
+    /// `AWS::Grafana::Workspace`
+    AwsGrafanaWorkspace,
+    /// `AWS::GroundStation::DataflowEndpointGroup`
+    AwsGroundStationDataflowEndpointGroup,
+    /// `AWS::ImageBuilder::ImageRecipe`
+    AwsImageBuilderImageRecipe,
+    /// `AWS::KMS::Alias`
+    AwsKmsAlias,
+    /// `AWS::M2::Environment`
+    AwsM2Environment,
+    /// `AWS::QuickSight::DataSource`
+    AwsQuickSightDataSource,
+    /// `AWS::QuickSight::Template`
+    AwsQuickSightTemplate,
+    /// `AWS::QuickSight::Theme`
+    AwsQuickSightTheme,
+    /// `AWS::RDS::OptionGroup`
+    AwsRdsOptionGroup,
+    /// `AWS::Redshift::EndpointAccess`
+    AwsRedshiftEndpointAccess,
+    /// `AWS::Route53Resolver::FirewallRuleGroup`
+    AwsRoute53ResolverFirewallRuleGroup,
+    /// `AWS::SSM::Document`
+    AwsSsmDocument,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ResourceType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceType::AwsEc2CustomerGateway => "AWS::EC2::CustomerGateway",
+            ResourceType::AwsEc2Eip => "AWS::EC2::EIP",
+            ResourceType::AwsEc2Host => "AWS::EC2::Host",
+            ResourceType::AwsEc2Instance => "AWS::EC2::Instance",
+            ResourceType::AwsEc2InternetGateway => "AWS::EC2::InternetGateway",
+            ResourceType::AwsEc2NetworkAcl => "AWS::EC2::NetworkAcl",
+            ResourceType::AwsEc2NetworkInterface => "AWS::EC2::NetworkInterface",
+            ResourceType::AwsEc2RouteTable => "AWS::EC2::RouteTable",
+            ResourceType::AwsEc2SecurityGroup => "AWS::EC2::SecurityGroup",
+            ResourceType::AwsEc2Subnet => "AWS::EC2::Subnet",
+            ResourceType::AwsCloudTrailTrail => "AWS::CloudTrail::Trail",
+            ResourceType::AwsEc2Volume => "AWS::EC2::Volume",
+            ResourceType::AwsEc2Vpc => "AWS::EC2::VPC",
+            ResourceType::AwsEc2VPNConnection => "AWS::EC2::VPNConnection",
+            ResourceType::AwsEc2VPNGateway => "AWS::EC2::VPNGateway",
+            ResourceType::AwsEc2RegisteredHAInstance => "AWS::EC2::RegisteredHAInstance",
+            ResourceType::AwsEc2NatGateway => "AWS::EC2::NatGateway",
+            ResourceType::AwsEc2EgressOnlyInternetGateway => "AWS::EC2::EgressOnlyInternetGateway",
+            ResourceType::AwsEc2VPCEndpoint => "AWS::EC2::VPCEndpoint",
+            ResourceType::AwsEc2VPCEndpointService => "AWS::EC2::VPCEndpointService",
+            ResourceType::AwsEc2FlowLog => "AWS::EC2::FlowLog",
+            ResourceType::AwsEc2VPCPeeringConnection => "AWS::EC2::VPCPeeringConnection",
+            ResourceType::AwsElasticsearchDomain => "AWS::Elasticsearch::Domain",
+            ResourceType::AwsIamGroup => "AWS::IAM::Group",
+            ResourceType::AwsIamPolicy => "AWS::IAM::Policy",
+            ResourceType::AwsIamRole => "AWS::IAM::Role",
+            ResourceType::AwsIamUser => "AWS::IAM::User",
+            ResourceType::AwsElasticLoadBalancingV2LoadBalancer => {
+                "AWS::ElasticLoadBalancingV2::LoadBalancer"
+            }
+            ResourceType::AwsAcmCertificate => "AWS::ACM::Certificate",
+            ResourceType::AwsRdsDBInstance => "AWS::RDS::DBInstance",
+            ResourceType::AwsRdsDBSubnetGroup => "AWS::RDS::DBSubnetGroup",
+            ResourceType::AwsRdsDBSecurityGroup => "AWS::RDS::DBSecurityGroup",
+            ResourceType::AwsRdsDBSnapshot => "AWS::RDS::DBSnapshot",
+            ResourceType::AwsRdsDBCluster => "AWS::RDS::DBCluster",
+            ResourceType::AwsRdsDBClusterSnapshot => "AWS::RDS::DBClusterSnapshot",
+            ResourceType::AwsRdsEventSubscription => "AWS::RDS::EventSubscription",
+            ResourceType::AwsS3Bucket => "AWS::S3::Bucket",
+            ResourceType::AwsS3AccountPublicAccessBlock => "AWS::S3::AccountPublicAccessBlock",
+            ResourceType::AwsRedshiftCluster => "AWS::Redshift::Cluster",
+            ResourceType::AwsRedshiftClusterSnapshot => "AWS::Redshift::ClusterSnapshot",
+            ResourceType::AwsRedshiftClusterParameterGroup => {
+                "AWS::Redshift::ClusterParameterGroup"
+            }
+            ResourceType::AwsRedshiftClusterSecurityGroup => "AWS::Redshift::ClusterSecurityGroup",
+            ResourceType::AwsRedshiftClusterSubnetGroup => "AWS::Redshift::ClusterSubnetGroup",
+            ResourceType::AwsRedshiftEventSubscription => "AWS::Redshift::EventSubscription",
+            ResourceType::AwsSsmManagedInstanceInventory => "AWS::SSM::ManagedInstanceInventory",
+            ResourceType::AwsCloudWatchAlarm => "AWS::CloudWatch::Alarm",
+            ResourceType::AwsCloudFormationStack => "AWS::CloudFormation::Stack",
+            ResourceType::AwsElasticLoadBalancingLoadBalancer => {
+                "AWS::ElasticLoadBalancing::LoadBalancer"
+            }
+            ResourceType::AwsAutoScalingAutoScalingGroup => "AWS::AutoScaling::AutoScalingGroup",
+            ResourceType::AwsAutoScalingLaunchConfiguration => {
+                "AWS::AutoScaling::LaunchConfiguration"
+            }
+            ResourceType::AwsAutoScalingScalingPolicy => "AWS::AutoScaling::ScalingPolicy",
+            ResourceType::AwsAutoScalingScheduledAction => "AWS::AutoScaling::ScheduledAction",
+            ResourceType::AwsDynamoDBTable => "AWS::DynamoDB::Table",
+            ResourceType::AwsCodeBuildProject => "AWS::CodeBuild::Project",
+            ResourceType::AwsWafRateBasedRule => "AWS::WAF::RateBasedRule",
+            ResourceType::AwsWafRule => "AWS::WAF::Rule",
+            ResourceType::AwsWafRuleGroup => "AWS::WAF::RuleGroup",
+            ResourceType::AwsWafWebACL => "AWS::WAF::WebACL",
+            ResourceType::AwsWAFRegionalRateBasedRule => "AWS::WAFRegional::RateBasedRule",
+            ResourceType::AwsWAFRegionalRule => "AWS::WAFRegional::Rule",
+            ResourceType::AwsWAFRegionalRuleGroup => "AWS::WAFRegional::RuleGroup",
+            ResourceType::AwsWAFRegionalWebACL => "AWS::WAFRegional::WebACL",
+            ResourceType::AwsCloudFrontDistribution => "AWS::CloudFront::Distribution",
+            ResourceType::AwsCloudFrontStreamingDistribution => {
+                "AWS::CloudFront::StreamingDistribution"
+            }
+            ResourceType::AwsLambdaFunction => "AWS::Lambda::Function",
+            ResourceType::AwsNetworkFirewallFirewall => "AWS::NetworkFirewall::Firewall",
+            ResourceType::AwsNetworkFirewallFirewallPolicy => {
+                "AWS::NetworkFirewall::FirewallPolicy"
+            }
+            ResourceType::AwsNetworkFirewallRuleGroup => "AWS::NetworkFirewall::RuleGroup",
+            ResourceType::AwsElasticBeanstalkApplication => "AWS::ElasticBeanstalk::Application",
+            ResourceType::AwsElasticBeanstalkApplicationVersion => {
+                "AWS::ElasticBeanstalk::ApplicationVersion"
+            }
+            ResourceType::AwsElasticBeanstalkEnvironment => "AWS::ElasticBeanstalk::Environment",
+            ResourceType::AwsWAFv2WebACL => "AWS::WAFv2::WebACL",
+            ResourceType::AwsWAFv2RuleGroup => "AWS::WAFv2::RuleGroup",
+            ResourceType::AwsWAFv2IPSet => "AWS::WAFv2::IPSet",
+            ResourceType::AwsWAFv2RegexPatternSet => "AWS::WAFv2::RegexPatternSet",
+            ResourceType::AwsWAFv2ManagedRuleSet => "AWS::WAFv2::ManagedRuleSet",
+            ResourceType::AwsXRayEncryptionConfig => "AWS::XRay::EncryptionConfig",
+            ResourceType::AwsSsmAssociationCompliance => "AWS::SSM::AssociationCompliance",
+            ResourceType::AwsSsmPatchCompliance => "AWS::SSM::PatchCompliance",
+            ResourceType::AwsShieldProtection => "AWS::Shield::Protection",
+            ResourceType::AwsShieldRegionalProtection => "AWS::ShieldRegional::Protection",
+            ResourceType::AwsConfigConformancePackCompliance => {
+                "AWS::Config::ConformancePackCompliance"
+            }
+            ResourceType::AwsConfigResourceCompliance => "AWS::Config::ResourceCompliance",
+            ResourceType::AwsApiGatewayStage => "AWS::ApiGateway::Stage",
+            ResourceType::AwsApiGatewayRestApi => "AWS::ApiGateway::RestApi",
+            ResourceType::AwsApiGatewayV2Stage => "AWS::ApiGatewayV2::Stage",
+            ResourceType::AwsApiGatewayV2Api => "AWS::ApiGatewayV2::Api",
+            ResourceType::AwsCodePipelinePipeline => "AWS::CodePipeline::Pipeline",
+            ResourceType::AwsServiceCatalogCloudFormationProvisionedProduct => {
+                "AWS::ServiceCatalog::CloudFormationProvisionedProduct"
+            }
+            ResourceType::AwsServiceCatalogCloudFormationProduct => {
+                "AWS::ServiceCatalog::CloudFormationProduct"
+            }
+            ResourceType::AwsServiceCatalogPortfolio => "AWS::ServiceCatalog::Portfolio",
+            ResourceType::AwsSqsQueue => "AWS::SQS::Queue",
+            ResourceType::AwsKmsKey => "AWS::KMS::Key",
+            ResourceType::AwsQldbLedger => "AWS::QLDB::Ledger",
+            ResourceType::AwsSecretsManagerSecret => "AWS::SecretsManager::Secret",
+            ResourceType::AwsSnsTopic => "AWS::SNS::Topic",
+            ResourceType::AwsSsmFileData => "AWS::SSM::FileData",
+            ResourceType::AwsBackupBackupPlan => "AWS::Backup::BackupPlan",
+            ResourceType::AwsBackupBackupSelection => "AWS::Backup::BackupSelection",
+            ResourceType::AwsBackupBackupVault => "AWS::Backup::BackupVault",
+            ResourceType::AwsBackupRecoveryPoint => "AWS::Backup::RecoveryPoint",
+            ResourceType::AwsEcrRepository => "AWS::ECR::Repository",
+            ResourceType::AwsEcsCluster => "AWS::ECS::Cluster",
+            ResourceType::AwsEcsService => "AWS::ECS::Service",
+            ResourceType::AwsEcsTaskDefinition => "AWS::ECS::TaskDefinition",
+            ResourceType::AwsEfsAccessPoint => "AWS::EFS::AccessPoint",
+            ResourceType::AwsEfsFileSystem => "AWS::EFS::FileSystem",
+            ResourceType::AwsEksCluster => "AWS::EKS::Cluster",
+            ResourceType::AwsOpenSearchDomain => "AWS::OpenSearch::Domain",
+            ResourceType::AwsEc2TransitGateway => "AWS::EC2::TransitGateway",
+            ResourceType::AwsKinesisStream => "AWS::Kinesis::Stream",
+            ResourceType::AwsKinesisStreamConsumer => "AWS::Kinesis::StreamConsumer",
+            ResourceType::AwsCodeDeployApplication => "AWS::CodeDeploy::Application",
+            ResourceType::AwsCodeDeployDeploymentConfig => "AWS::CodeDeploy::DeploymentConfig",
+            ResourceType::AwsCodeDeployDeploymentGroup => "AWS::CodeDeploy::DeploymentGroup",
+            ResourceType::AwsEc2LaunchTemplate => "AWS::EC2::LaunchTemplate",
+            ResourceType::AwsEcrPublicRepository => "AWS::ECR::PublicRepository",
+            ResourceType::AwsGuardDutyDetector => "AWS::GuardDuty::Detector",
+            ResourceType::AwsEmrSecurityConfiguration => "AWS::EMR::SecurityConfiguration",
+            ResourceType::AwsSageMakerCodeRepository => "AWS::SageMaker::CodeRepository",
+            ResourceType::AwsRoute53ResolverResolverEndpoint => {
+                "AWS::Route53Resolver::ResolverEndpoint"
+            }
+            ResourceType::AwsRoute53ResolverResolverRule => "AWS::Route53Resolver::ResolverRule",
+            ResourceType::AwsRoute53ResolverResolverRuleAssociation => {
+                "AWS::Route53Resolver::ResolverRuleAssociation"
+            }
+            ResourceType::AwsDmsReplicationSubnetGroup => "AWS::DMS::ReplicationSubnetGroup",
+            ResourceType::AwsDmsEventSubscription => "AWS::DMS::EventSubscription",
+            ResourceType::AwsMskCluster => "AWS::MSK::Cluster",
+            ResourceType::AwsStepFunctionsActivity => "AWS::StepFunctions::Activity",
+            ResourceType::AwsWorkSpacesWorkspace => "AWS::WorkSpaces::Workspace",
+            ResourceType::AwsWorkSpacesConnectionAlias => "AWS::WorkSpaces::ConnectionAlias",
+            ResourceType::AwsSageMakerModel => "AWS::SageMaker::Model",
+            ResourceType::AwsElasticLoadBalancingV2Listener => {
+                "AWS::ElasticLoadBalancingV2::Listener"
+            }
+            ResourceType::AwsStepFunctionsStateMachine => "AWS::StepFunctions::StateMachine",
+            ResourceType::AwsBatchJobQueue => "AWS::Batch::JobQueue",
+            ResourceType::AwsBatchComputeEnvironment => "AWS::Batch::ComputeEnvironment",
+            ResourceType::AwsAccessAnalyzerAnalyzer => "AWS::AccessAnalyzer::Analyzer",
+            ResourceType::AwsAthenaWorkGroup => "AWS::Athena::WorkGroup",
+            ResourceType::AwsAthenaDataCatalog => "AWS::Athena::DataCatalog",
+            ResourceType::AwsDetectiveGraph => "AWS::Detective::Graph",
+            ResourceType::AwsGlobalAcceleratorAccelerator => "AWS::GlobalAccelerator::Accelerator",
+            ResourceType::AwsGlobalAcceleratorEndpointGroup => {
+                "AWS::GlobalAccelerator::EndpointGroup"
+            }
+            ResourceType::AwsGlobalAcceleratorListener => "AWS::GlobalAccelerator::Listener",
+            ResourceType::AwsEc2TransitGatewayAttachment => "AWS::EC2::TransitGatewayAttachment",
+            ResourceType::AwsEc2TransitGatewayRouteTable => "AWS::EC2::TransitGatewayRouteTable",
+            ResourceType::AwsDmsCertificate => "AWS::DMS::Certificate",
+            ResourceType::AwsAppConfigApplication => "AWS::AppConfig::Application",
+            ResourceType::AwsAppSyncGraphQLApi => "AWS::AppSync::GraphQLApi",
+            ResourceType::AwsDataSyncLocationSMB => "AWS::DataSync::LocationSMB",
+            ResourceType::AwsDataSyncLocationFSxLustre => "AWS::DataSync::LocationFSxLustre",
+            ResourceType::AwsDataSyncLocationS3 => "AWS::DataSync::LocationS3",
+            ResourceType::AwsDataSyncLocationEFS => "AWS::DataSync::LocationEFS",
+            ResourceType::AwsDataSyncTask => "AWS::DataSync::Task",
+            ResourceType::AwsDataSyncLocationNFS => "AWS::DataSync::LocationNFS",
+            ResourceType::AwsEc2NetworkInsightsAccessScopeAnalysis => {
+                "AWS::EC2::NetworkInsightsAccessScopeAnalysis"
+            }
+            ResourceType::AwsEksFargateProfile => "AWS::EKS::FargateProfile",
+            ResourceType::AwsGlueJob => "AWS::Glue::Job",
+            ResourceType::AwsGuardDutyThreatIntelSet => "AWS::GuardDuty::ThreatIntelSet",
+            ResourceType::AwsGuardDutyIPSet => "AWS::GuardDuty::IPSet",
+            ResourceType::AwsSageMakerWorkteam => "AWS::SageMaker::Workteam",
+            ResourceType::AwsSageMakerNotebookInstanceLifecycleConfig => {
+                "AWS::SageMaker::NotebookInstanceLifecycleConfig"
+            }
+            ResourceType::AwsServiceDiscoveryService => "AWS::ServiceDiscovery::Service",
+            ResourceType::AwsServiceDiscoveryPublicDnsNamespace => {
+                "AWS::ServiceDiscovery::PublicDnsNamespace"
+            }
+            ResourceType::AwsSesContactList => "AWS::SES::ContactList",
+            ResourceType::AwsSesConfigurationSet => "AWS::SES::ConfigurationSet",
+            ResourceType::AwsRoute53HostedZone => "AWS::Route53::HostedZone",
+            ResourceType::AwsIoTEventsInput => "AWS::IoTEvents::Input",
+            ResourceType::AwsIoTEventsDetectorModel => "AWS::IoTEvents::DetectorModel",
+            ResourceType::AwsIoTEventsAlarmModel => "AWS::IoTEvents::AlarmModel",
+            ResourceType::AwsServiceDiscoveryHttpNamespace => {
+                "AWS::ServiceDiscovery::HttpNamespace"
+            }
+            ResourceType::AwsEventsEventBus => "AWS::Events::EventBus",
+            ResourceType::AwsImageBuilderContainerRecipe => "AWS::ImageBuilder::ContainerRecipe",
+            ResourceType::AwsImageBuilderDistributionConfiguration => {
+                "AWS::ImageBuilder::DistributionConfiguration"
+            }
+            ResourceType::AwsImageBuilderInfrastructureConfiguration => {
+                "AWS::ImageBuilder::InfrastructureConfiguration"
+            }
+            ResourceType::AwsDataSyncLocationObjectStorage => {
+                "AWS::DataSync::LocationObjectStorage"
+            }
+            ResourceType::AwsDataSyncLocationHDFS => "AWS::DataSync::LocationHDFS",
+            ResourceType::AwsGlueClassifier => "AWS::Glue::Classifier",
+            ResourceType::AwsRoute53RecoveryReadinessCell => "AWS::Route53RecoveryReadiness::Cell",
+            ResourceType::AwsRoute53RecoveryReadinessReadinessCheck => {
+                "AWS::Route53RecoveryReadiness::ReadinessCheck"
+            }
+            ResourceType::AwsEcrRegistryPolicy => "AWS::ECR::RegistryPolicy",
+            ResourceType::AwsBackupReportPlan => "AWS::Backup::ReportPlan",
+            ResourceType::AwsLightsailCertificate => "AWS::Lightsail::Certificate",
+            ResourceType::AwsRumAppMonitor => "AWS::RUM::AppMonitor",
+            ResourceType::AwsEventsEndpoint => "AWS::Events::Endpoint",
+            ResourceType::AwsSesReceiptRuleSet => "AWS::SES::ReceiptRuleSet",
+            ResourceType::AwsEventsArchive => "AWS::Events::Archive",
+            ResourceType::AwsEventsApiDestination => "AWS::Events::ApiDestination",
+            ResourceType::AwsLightsailDisk => "AWS::Lightsail::Disk",
+            ResourceType::AwsFisExperimentTemplate => "AWS::FIS::ExperimentTemplate",
+            ResourceType::AwsDataSyncLocationFSxWindows => "AWS::DataSync::LocationFSxWindows",
+            ResourceType::AwsSesReceiptFilter => "AWS::SES::ReceiptFilter",
+            ResourceType::AwsGuardDutyFilter => "AWS::GuardDuty::Filter",
+            ResourceType::AwsSesTemplate => "AWS::SES::Template",
+            ResourceType::AwsAmazonMQBroker => "AWS::AmazonMQ::Broker",
+            ResourceType::AwsAppConfigEnvironment => "AWS::AppConfig::Environment",
+            ResourceType::AwsAppConfigConfigurationProfile => {
+                "AWS::AppConfig::ConfigurationProfile"
+            }
+            ResourceType::AwsCloud9EnvironmentEC2 => "AWS::Cloud9::EnvironmentEC2",
+            ResourceType::AwsEventSchemasRegistry => "AWS::EventSchemas::Registry",
+            ResourceType::AwsEventSchemasRegistryPolicy => "AWS::EventSchemas::RegistryPolicy",
+            ResourceType::AwsEventSchemasDiscoverer => "AWS::EventSchemas::Discoverer",
+            ResourceType::AwsFraudDetectorLabel => "AWS::FraudDetector::Label",
+            ResourceType::AwsFraudDetectorEntityType => "AWS::FraudDetector::EntityType",
+            ResourceType::AwsFraudDetectorVariable => "AWS::FraudDetector::Variable",
+            ResourceType::AwsFraudDetectorOutcome => "AWS::FraudDetector::Outcome",
+            ResourceType::AwsIoTAuthorizer => "AWS::IoT::Authorizer",
+            ResourceType::AwsIoTSecurityProfile => "AWS::IoT::SecurityProfile",
+            ResourceType::AwsIoTRoleAlias => "AWS::IoT::RoleAlias",
+            ResourceType::AwsIoTDimension => "AWS::IoT::Dimension",
+            ResourceType::AwsIoTAnalyticsDatastore => "AWS::IoTAnalytics::Datastore",
+            ResourceType::AwsLightsailBucket => "AWS::Lightsail::Bucket",
+            ResourceType::AwsLightsailStaticIp => "AWS::Lightsail::StaticIp",
+            ResourceType::AwsMediaPackagePackagingGroup => "AWS::MediaPackage::PackagingGroup",
+            ResourceType::AwsRoute53RecoveryReadinessRecoveryGroup => {
+                "AWS::Route53RecoveryReadiness::RecoveryGroup"
+            }
+            ResourceType::AwsResilienceHubResiliencyPolicy => {
+                "AWS::ResilienceHub::ResiliencyPolicy"
+            }
+            ResourceType::AwsTransferWorkflow => "AWS::Transfer::Workflow",
+            ResourceType::AwsEksIdentityProviderConfig => "AWS::EKS::IdentityProviderConfig",
+            ResourceType::AwsEksAddon => "AWS::EKS::Addon",
+            ResourceType::AwsGlueMLTransform => "AWS::Glue::MLTransform",
+            ResourceType::AwsIoTPolicy => "AWS::IoT::Policy",
+            ResourceType::AwsIoTMitigationAction => "AWS::IoT::MitigationAction",
+            ResourceType::AwsIoTTwinMakerWorkspace => "AWS::IoTTwinMaker::Workspace",
+            ResourceType::AwsIoTTwinMakerEntity => "AWS::IoTTwinMaker::Entity",
+            ResourceType::AwsIoTAnalyticsDataset => "AWS::IoTAnalytics::Dataset",
+            ResourceType::AwsIoTAnalyticsPipeline => "AWS::IoTAnalytics::Pipeline",
+            ResourceType::AwsIoTAnalyticsChannel => "AWS::IoTAnalytics::Channel",
+            ResourceType::AwsIoTSiteWiseDashboard => "AWS::IoTSiteWise::Dashboard",
+            ResourceType::AwsIoTSiteWiseProject => "AWS::IoTSiteWise::Project",
+            ResourceType::AwsIoTSiteWisePortal => "AWS::IoTSiteWise::Portal",
+            ResourceType::AwsIoTSiteWiseAssetModel => "AWS::IoTSiteWise::AssetModel",
+            ResourceType::AwsIvsChannel => "AWS::IVS::Channel",
+            ResourceType::AwsIvsRecordingConfiguration => "AWS::IVS::RecordingConfiguration",
+            ResourceType::AwsIvsPlaybackKeyPair => "AWS::IVS::PlaybackKeyPair",
+            ResourceType::AwsKinesisAnalyticsV2Application => {
+                "AWS::KinesisAnalyticsV2::Application"
+            }
+            ResourceType::AwsRdsGlobalCluster => "AWS::RDS::GlobalCluster",
+            ResourceType::AwsS3MultiRegionAccessPoint => "AWS::S3::MultiRegionAccessPoint",
+            ResourceType::AwsDeviceFarmTestGridProject => "AWS::DeviceFarm::TestGridProject",
+            ResourceType::AwsBudgetsBudgetsAction => "AWS::Budgets::BudgetsAction",
+            ResourceType::AwsLexBot => "AWS::Lex::Bot",
+            ResourceType::AwsCodeGuruReviewerRepositoryAssociation => {
+                "AWS::CodeGuruReviewer::RepositoryAssociation"
+            }
+            ResourceType::AwsIoTCustomMetric => "AWS::IoT::CustomMetric",
+            ResourceType::AwsRoute53ResolverFirewallDomainList => {
+                "AWS::Route53Resolver::FirewallDomainList"
+            }
+            ResourceType::AwsRoboMakerRobotApplicationVersion => {
+                "AWS::RoboMaker::RobotApplicationVersion"
+            }
+            ResourceType::AwsEc2TrafficMirrorSession => "AWS::EC2::TrafficMirrorSession",
+            ResourceType::AwsIoTSiteWiseGateway => "AWS::IoTSiteWise::Gateway",
+            ResourceType::AwsLexBotAlias => "AWS::Lex::BotAlias",
+            ResourceType::AwsLookoutMetricsAlert => "AWS::LookoutMetrics::Alert",
+            ResourceType::AwsIoTAccountAuditConfiguration => "AWS::IoT::AccountAuditConfiguration",
+            ResourceType::AwsEc2TrafficMirrorTarget => "AWS::EC2::TrafficMirrorTarget",
+            ResourceType::AwsS3StorageLens => "AWS::S3::StorageLens",
+            ResourceType::AwsIoTScheduledAudit => "AWS::IoT::ScheduledAudit",
+            ResourceType::AwsEventsConnection => "AWS::Events::Connection",
+            ResourceType::AwsEventSchemasSchema => "AWS::EventSchemas::Schema",
+            ResourceType::AwsMediaPackagePackagingConfiguration => {
+                "AWS::MediaPackage::PackagingConfiguration"
+            }
+            ResourceType::AwsKinesisVideoSignalingChannel => "AWS::KinesisVideo::SignalingChannel",
+            ResourceType::AwsAppStreamDirectoryConfig => "AWS::AppStream::DirectoryConfig",
+            ResourceType::AwsLookoutVisionProject => "AWS::LookoutVision::Project",
+            ResourceType::AwsRoute53RecoveryControlCluster => {
+                "AWS::Route53RecoveryControl::Cluster"
+            }
+            ResourceType::AwsRoute53RecoveryControlSafetyRule => {
+                "AWS::Route53RecoveryControl::SafetyRule"
+            }
+            ResourceType::AwsRoute53RecoveryControlControlPanel => {
+                "AWS::Route53RecoveryControl::ControlPanel"
+            }
+            ResourceType::AwsRoute53RecoveryControlRoutingControl => {
+                "AWS::Route53RecoveryControl::RoutingControl"
+            }
+            ResourceType::AwsRoute53RecoveryReadinessResourceSet => {
+                "AWS::Route53RecoveryReadiness::ResourceSet"
+            }
+            ResourceType::AwsRoboMakerSimulationApplication => {
+                "AWS::RoboMaker::SimulationApplication"
+            }
+            ResourceType::AwsRoboMakerRobotApplication => "AWS::RoboMaker::RobotApplication",
+            ResourceType::AwsHealthLakeFHIRDatastore => "AWS::HealthLake::FHIRDatastore",
+            ResourceType::AwsPinpointSegment => "AWS::Pinpoint::Segment",
+            ResourceType::AwsPinpointApplicationSettings => "AWS::Pinpoint::ApplicationSettings",
+            ResourceType::AwsEventsRule => "AWS::Events::Rule",
+            ResourceType::AwsEc2DHCPOptions => "AWS::EC2::DHCPOptions",
+            ResourceType::AwsEc2NetworkInsightsPath => "AWS::EC2::NetworkInsightsPath",
+            ResourceType::AwsEc2TrafficMirrorFilter => "AWS::EC2::TrafficMirrorFilter",
+            ResourceType::AwsEc2Ipam => "AWS::EC2::IPAM",
+            ResourceType::AwsIoTTwinMakerScene => "AWS::IoTTwinMaker::Scene",
+            ResourceType::AwsNetworkManagerTransitGatewayRegistration => {
+                "AWS::NetworkManager::TransitGatewayRegistration"
+            }
+            ResourceType::AwsCustomerProfilesDomain => "AWS::CustomerProfiles::Domain",
+            ResourceType::AwsAutoScalingWarmPool => "AWS::AutoScaling::WarmPool",
+            ResourceType::AwsConnectPhoneNumber => "AWS::Connect::PhoneNumber",
+            ResourceType::AwsAppConfigDeploymentStrategy => "AWS::AppConfig::DeploymentStrategy",
+            ResourceType::AwsAppFlowFlow => "AWS::AppFlow::Flow",
+            ResourceType::AwsAuditManagerAssessment => "AWS::AuditManager::Assessment",
+            ResourceType::AwsCloudWatchMetricStream => "AWS::CloudWatch::MetricStream",
+            ResourceType::AwsDeviceFarmInstanceProfile => "AWS::DeviceFarm::InstanceProfile",
+            ResourceType::AwsDeviceFarmProject => "AWS::DeviceFarm::Project",
+            ResourceType::AwsEc2EC2Fleet => "AWS::EC2::EC2Fleet",
+            ResourceType::AwsEc2SubnetRouteTableAssociation => {
+                "AWS::EC2::SubnetRouteTableAssociation"
+            }
+            ResourceType::AwsEcrPullThroughCacheRule => "AWS::ECR::PullThroughCacheRule",
+            ResourceType::AwsGroundStationConfig => "AWS::GroundStation::Config",
+            ResourceType::AwsImageBuilderImagePipeline => "AWS::ImageBuilder::ImagePipeline",
+            ResourceType::AwsIoTFleetMetric => "AWS::IoT::FleetMetric",
+            ResourceType::AwsIoTWirelessServiceProfile => "AWS::IoTWireless::ServiceProfile",
+            ResourceType::AwsNetworkManagerDevice => "AWS::NetworkManager::Device",
+            ResourceType::AwsNetworkManagerGlobalNetwork => "AWS::NetworkManager::GlobalNetwork",
+            ResourceType::AwsNetworkManagerLink => "AWS::NetworkManager::Link",
+            ResourceType::AwsNetworkManagerSite => "AWS::NetworkManager::Site",
+            ResourceType::AwsPanoramaPackage => "AWS::Panorama::Package",
+            ResourceType::AwsPinpointApp => "AWS::Pinpoint::App",
+            ResourceType::AwsRedshiftScheduledAction => "AWS::Redshift::ScheduledAction",
+            ResourceType::AwsRoute53ResolverFirewallRuleGroupAssociation => {
+                "AWS::Route53Resolver::FirewallRuleGroupAssociation"
+            }
+            ResourceType::AwsSageMakerAppImageConfig => "AWS::SageMaker::AppImageConfig",
+            ResourceType::AwsSageMakerImage => "AWS::SageMaker::Image",
+            ResourceType::AwsEcsTaskSet => "AWS::ECS::TaskSet",
+            ResourceType::AwsCassandraKeyspace => "AWS::Cassandra::Keyspace",
+            ResourceType::AwsSignerSigningProfile => "AWS::Signer::SigningProfile",
+            ResourceType::AwsAmplifyApp => "AWS::Amplify::App",
+            ResourceType::AwsAppMeshVirtualNode => "AWS::AppMesh::VirtualNode",
+            ResourceType::AwsAppMeshVirtualService => "AWS::AppMesh::VirtualService",
+            ResourceType::AwsAppRunnerVpcConnector => "AWS::AppRunner::VpcConnector",
+            ResourceType::AwsAppStreamApplication => "AWS::AppStream::Application",
+            ResourceType::AwsCodeArtifactRepository => "AWS::CodeArtifact::Repository",
+            ResourceType::AwsEc2PrefixList => "AWS::EC2::PrefixList",
+            ResourceType::AwsEc2SpotFleet => "AWS::EC2::SpotFleet",
+            ResourceType::AwsEvidentlyProject => "AWS::Evidently::Project",
+            ResourceType::AwsForecastDataset => "AWS::Forecast::Dataset",
+            ResourceType::AwsIamSAMLProvider => "AWS::IAM::SAMLProvider",
+            ResourceType::AwsIamServerCertificate => "AWS::IAM::ServerCertificate",
+            ResourceType::AwsPinpointCampaign => "AWS::Pinpoint::Campaign",
+            ResourceType::AwsPinpointInAppTemplate => "AWS::Pinpoint::InAppTemplate",
+            ResourceType::AwsSageMakerDomain => "AWS::SageMaker::Domain",
+            ResourceType::AwsTransferAgreement => "AWS::Transfer::Agreement",
+            ResourceType::AwsTransferConnector => "AWS::Transfer::Connector",
+            ResourceType::AwsKinesisFirehoseDeliveryStream => {
+                "AWS::KinesisFirehose::DeliveryStream"
+            }
+            ResourceType::AwsAmplifyBranch => "AWS::Amplify::Branch",
+            ResourceType::AwsAppIntegrationsEventIntegration => {
+                "AWS::AppIntegrations::EventIntegration"
+            }
+            ResourceType::AwsAppMeshRoute => "AWS::AppMesh::Route",
+            ResourceType::AwsAthenaPreparedStatement => "AWS::Athena::PreparedStatement",
+            ResourceType::AwsEc2IPAMScope => "AWS::EC2::IPAMScope",
+            ResourceType::AwsEvidentlyLaunch => "AWS::Evidently::Launch",
+            ResourceType::AwsForecastDatasetGroup => "AWS::Forecast::DatasetGroup",
+            ResourceType::AwsGreengrassV2ComponentVersion => "AWS::GreengrassV2::ComponentVersion",
+            ResourceType::AwsGroundStationMissionProfile => "AWS::GroundStation::MissionProfile",
+            ResourceType::AwsMediaConnectFlowEntitlement => "AWS::MediaConnect::FlowEntitlement",
+            ResourceType::AwsMediaConnectFlowVpcInterface => "AWS::MediaConnect::FlowVpcInterface",
+            ResourceType::AwsMediaTailorPlaybackConfiguration => {
+                "AWS::MediaTailor::PlaybackConfiguration"
+            }
+            ResourceType::AwsMskConfiguration => "AWS::MSK::Configuration",
+            ResourceType::AwsPersonalizeDataset => "AWS::Personalize::Dataset",
+            ResourceType::AwsPersonalizeSchema => "AWS::Personalize::Schema",
+            ResourceType::AwsPersonalizeSolution => "AWS::Personalize::Solution",
+            ResourceType::AwsPinpointEmailTemplate => "AWS::Pinpoint::EmailTemplate",
+            ResourceType::AwsPinpointEventStream => "AWS::Pinpoint::EventStream",
+            ResourceType::AwsResilienceHubApp => "AWS::ResilienceHub::App",
+            ResourceType::AwsAcmpcaCertificateAuthority => "AWS::ACMPCA::CertificateAuthority",
+            ResourceType::AwsAppConfigHostedConfigurationVersion => {
+                "AWS::AppConfig::HostedConfigurationVersion"
+            }
+            ResourceType::AwsAppMeshVirtualGateway => "AWS::AppMesh::VirtualGateway",
+            ResourceType::AwsAppMeshVirtualRouter => "AWS::AppMesh::VirtualRouter",
+            ResourceType::AwsAppRunnerService => "AWS::AppRunner::Service",
+            ResourceType::AwsCustomerProfilesObjectType => "AWS::CustomerProfiles::ObjectType",
+            ResourceType::AwsDmsEndpoint => "AWS::DMS::Endpoint",
+            ResourceType::AwsEc2CapacityReservation => "AWS::EC2::CapacityReservation",
+            ResourceType::AwsEc2ClientVpnEndpoint => "AWS::EC2::ClientVpnEndpoint",
+            ResourceType::AwsKendraIndex => "AWS::Kendra::Index",
+            ResourceType::AwsKinesisVideoStream => "AWS::KinesisVideo::Stream",
+            ResourceType::AwsLogsDestination => "AWS::Logs::Destination",
+            ResourceType::AwsPinpointEmailChannel => "AWS::Pinpoint::EmailChannel",
+            ResourceType::AwsS3AccessPoint => "AWS::S3::AccessPoint",
+            ResourceType::AwsNetworkManagerCustomerGatewayAssociation => {
+                "AWS::NetworkManager::CustomerGatewayAssociation"
+            }
+            ResourceType::AwsNetworkManagerLinkAssociation => {
+                "AWS::NetworkManager::LinkAssociation"
+            }
+            ResourceType::AwsIoTWirelessMulticastGroup => "AWS::IoTWireless::MulticastGroup",
+            ResourceType::AwsPersonalizeDatasetGroup => "AWS::Personalize::DatasetGroup",
+            ResourceType::AwsIoTTwinMakerComponentType => "AWS::IoTTwinMaker::ComponentType",
+            ResourceType::AwsCodeBuildReportGroup => "AWS::CodeBuild::ReportGroup",
+            ResourceType::AwsSageMakerFeatureGroup => "AWS::SageMaker::FeatureGroup",
+            ResourceType::AwsMskBatchScramSecret => "AWS::MSK::BatchScramSecret",
+            ResourceType::AwsAppStreamStack => "AWS::AppStream::Stack",
+            ResourceType::AwsIoTJobTemplate => "AWS::IoT::JobTemplate",
+            ResourceType::AwsIoTWirelessFuotaTask => "AWS::IoTWireless::FuotaTask",
+            ResourceType::AwsIoTProvisioningTemplate => "AWS::IoT::ProvisioningTemplate",
+            ResourceType::AwsInspectorV2Filter => "AWS::InspectorV2::Filter",
+            ResourceType::AwsRoute53ResolverResolverQueryLoggingConfigAssociation => {
+                "AWS::Route53Resolver::ResolverQueryLoggingConfigAssociation"
+            }
+            ResourceType::AwsServiceDiscoveryInstance => "AWS::ServiceDiscovery::Instance",
+            ResourceType::AwsTransferCertificate => "AWS::Transfer::Certificate",
+            ResourceType::AwsMediaConnectFlowSource => "AWS::MediaConnect::FlowSource",
+            ResourceType::AwsApsRuleGroupsNamespace => "AWS::APS::RuleGroupsNamespace",
+            ResourceType::AwsCodeGuruProfilerProfilingGroup => {
+                "AWS::CodeGuruProfiler::ProfilingGroup"
+            }
+            ResourceType::AwsRoute53ResolverResolverQueryLoggingConfig => {
+                "AWS::Route53Resolver::ResolverQueryLoggingConfig"
+            }
+            ResourceType::AwsBatchSchedulingPolicy => "AWS::Batch::SchedulingPolicy",
+            ResourceType::AwsAcmpcaCertificateAuthorityActivation => {
+                "AWS::ACMPCA::CertificateAuthorityActivation"
+            }
+            ResourceType::AwsAppMeshGatewayRoute => "AWS::AppMesh::GatewayRoute",
+            ResourceType::AwsAppMeshMesh => "AWS::AppMesh::Mesh",
+            ResourceType::AwsConnectInstance => "AWS::Connect::Instance",
+            ResourceType::AwsConnectQuickConnect => "AWS::Connect::QuickConnect",
+            ResourceType::AwsEc2CarrierGateway => "AWS::EC2::CarrierGateway",
+            ResourceType::AwsEc2IPAMPool => "AWS::EC2::IPAMPool",
+            ResourceType::AwsEc2TransitGatewayConnect => "AWS::EC2::TransitGatewayConnect",
+            ResourceType::AwsEc2TransitGatewayMulticastDomain => {
+                "AWS::EC2::TransitGatewayMulticastDomain"
+            }
+            ResourceType::AwsEcsCapacityProvider => "AWS::ECS::CapacityProvider",
+            ResourceType::AwsIamInstanceProfile => "AWS::IAM::InstanceProfile",
+            ResourceType::AwsIoTCACertificate => "AWS::IoT::CACertificate",
+            ResourceType::AwsIoTTwinMakerSyncJob => "AWS::IoTTwinMaker::SyncJob",
+            ResourceType::AwsKafkaConnectConnector => "AWS::KafkaConnect::Connector",
+            ResourceType::AwsLambdaCodeSigningConfig => "AWS::Lambda::CodeSigningConfig",
+            ResourceType::AwsNetworkManagerConnectPeer => "AWS::NetworkManager::ConnectPeer",
+            ResourceType::AwsResourceExplorer2Index => "AWS::ResourceExplorer2::Index",
+            ResourceType::AwsAppStreamFleet => "AWS::AppStream::Fleet",
+            ResourceType::AwsCognitoUserPool => "AWS::Cognito::UserPool",
+            ResourceType::AwsCognitoUserPoolClient => "AWS::Cognito::UserPoolClient",
+            ResourceType::AwsCognitoUserPoolGroup => "AWS::Cognito::UserPoolGroup",
+            ResourceType::AwsEc2NetworkInsightsAccessScope => {
+                "AWS::EC2::NetworkInsightsAccessScope"
+            }
+            ResourceType::AwsEc2NetworkInsightsAnalysis => "AWS::EC2::NetworkInsightsAnalysis",
+            ResourceType::AwsGrafanaWorkspace => "AWS::Grafana::Workspace",
+            ResourceType::AwsGroundStationDataflowEndpointGroup => {
+                "AWS::GroundStation::DataflowEndpointGroup"
+            }
+            ResourceType::AwsImageBuilderImageRecipe => "AWS::ImageBuilder::ImageRecipe",
+            ResourceType::AwsKmsAlias => "AWS::KMS::Alias",
+            ResourceType::AwsM2Environment => "AWS::M2::Environment",
+            ResourceType::AwsQuickSightDataSource => "AWS::QuickSight::DataSource",
+            ResourceType::AwsQuickSightTemplate => "AWS::QuickSight::Template",
+            ResourceType::AwsQuickSightTheme => "AWS::QuickSight::Theme",
+            ResourceType::AwsRdsOptionGroup => "AWS::RDS::OptionGroup",
+            ResourceType::AwsRedshiftEndpointAccess => "AWS::Redshift::EndpointAccess",
+            ResourceType::AwsRoute53ResolverFirewallRuleGroup => {
+                "AWS::Route53Resolver::FirewallRuleGroup"
+            }
+            ResourceType::AwsSsmDocument => "AWS::SSM::Document",
+            ResourceType::Unknown(ref value) => value,
+        }
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct SelectResourceConfigResponse {
-    /// <p>The <code>nextToken</code> string returned in a previous request that you use to request the next page of results in a paginated response. </p>
-    #[serde(rename = "NextToken")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_token: Option<String>,
-    /// <p>Returns the <code>QueryInfo</code> object.</p>
-    #[serde(rename = "QueryInfo")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub query_info: Option<QueryInfo>,
-    /// <p>Returns the results for the SQL query.</p>
-    #[serde(rename = "Results")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub results: Option<Vec<String>>,
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ResourceType {
+    fn default() -> ResourceType {
+        ResourceType::Unknown(String::new())
+    }
 }
 
-/// <p>Provides the AWS Config rule owner (AWS or customer), the rule identifier, and the events that trigger the evaluation of your AWS resources.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct Source {
-    /// <p>Indicates whether AWS or the customer owns and manages the AWS Config rule.</p>
-    #[serde(rename = "Owner")]
-    pub owner: String,
-    /// <p>Provides the source and type of the event that causes AWS Config to evaluate your AWS resources.</p>
-    #[serde(rename = "SourceDetails")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub source_details: Option<Vec<SourceDetail>>,
-    /// <p>For AWS Config managed rules, a predefined identifier from a list. For example, <code>IAM_PASSWORD_POLICY</code> is a managed rule. To reference a managed rule, see <a href="https://docs.aws.amazon.com/config/latest/developerguide/evaluate-config_use-managed-rules.html">Using AWS Managed Config Rules</a>.</p> <p>For custom rules, the identifier is the Amazon Resource Name (ARN) of the rule's AWS Lambda function, such as <code>arn:aws:lambda:us-east-2:123456789012:function:custom_rule_name</code>.</p>
-    #[serde(rename = "SourceIdentifier")]
-    pub source_identifier: String,
+impl AsRef<str> for ResourceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
 }
 
-/// <p>Provides the source and the message types that trigger AWS Config to evaluate your AWS resources against a rule. It also provides the frequency with which you want AWS Config to run evaluations for the rule if the trigger type is periodic. You can specify the parameter values for <code>SourceDetail</code> only for custom rules. </p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct SourceDetail {
-    /// <p>The source of the event, such as an AWS service, that triggers AWS Config to evaluate your AWS resources.</p>
-    #[serde(rename = "EventSource")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub event_source: Option<String>,
-    /// <p><p>The frequency at which you want AWS Config to run evaluations for a custom rule with a periodic trigger. If you specify a value for <code>MaximumExecutionFrequency</code>, then <code>MessageType</code> must use the <code>ScheduledNotification</code> value.</p> <note> <p>By default, rules with a periodic trigger are evaluated every 24 hours. To change the frequency, specify a valid value for the <code>MaximumExecutionFrequency</code> parameter.</p> <p>Based on the valid value you choose, AWS Config runs evaluations once for each valid value. For example, if you choose <code>Three<em>Hours</code>, AWS Config runs evaluations once every three hours. In this case, <code>Three</em>Hours</code> is the frequency of this rule. </p> </note></p>
-    #[serde(rename = "MaximumExecutionFrequency")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub maximum_execution_frequency: Option<String>,
-    /// <p>The type of notification that triggers AWS Config to run an evaluation for a rule. You can specify the following notification types:</p> <ul> <li> <p> <code>ConfigurationItemChangeNotification</code> - Triggers an evaluation when AWS Config delivers a configuration item as a result of a resource change.</p> </li> <li> <p> <code>OversizedConfigurationItemChangeNotification</code> - Triggers an evaluation when AWS Config delivers an oversized configuration item. AWS Config may generate this notification type when a resource changes and the notification exceeds the maximum size allowed by Amazon SNS.</p> </li> <li> <p> <code>ScheduledNotification</code> - Triggers a periodic evaluation at the frequency specified for <code>MaximumExecutionFrequency</code>.</p> </li> <li> <p> <code>ConfigurationSnapshotDeliveryCompleted</code> - Triggers a periodic evaluation when AWS Config delivers a configuration snapshot.</p> </li> </ul> <p>If you want your custom rule to be triggered by configuration changes, specify two SourceDetail objects, one for <code>ConfigurationItemChangeNotification</code> and one for <code>OversizedConfigurationItemChangeNotification</code>.</p>
-    #[serde(rename = "MessageType")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub message_type: Option<String>,
+impl fmt::Display for ResourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
-/// <p>AWS Systems Manager (SSM) specific remediation controls.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct SsmControls {
-    /// <p>The maximum percentage of remediation actions allowed to run in parallel on the non-compliant resources for that specific rule. You can specify a percentage, such as 10%. The default value is 10. </p>
+impl<'a> From<&'a str> for ResourceType {
+    fn from(value: &'a str) -> ResourceType {
+        match value {
+            "AWS::EC2::CustomerGateway" => ResourceType::AwsEc2CustomerGateway,
+            "AWS::EC2::EIP" => ResourceType::AwsEc2Eip,
+            "AWS::EC2::Host" => ResourceType::AwsEc2Host,
+            "AWS::EC2::Instance" => ResourceType::AwsEc2Instance,
+            "AWS::EC2::InternetGateway" => ResourceType::AwsEc2InternetGateway,
+            "AWS::EC2::NetworkAcl" => ResourceType::AwsEc2NetworkAcl,
+            "AWS::EC2::NetworkInterface" => ResourceType::AwsEc2NetworkInterface,
+            "AWS::EC2::RouteTable" => ResourceType::AwsEc2RouteTable,
+            "AWS::EC2::SecurityGroup" => ResourceType::AwsEc2SecurityGroup,
+            "AWS::EC2::Subnet" => ResourceType::AwsEc2Subnet,
+            "AWS::CloudTrail::Trail" => ResourceType::AwsCloudTrailTrail,
+            "AWS::EC2::Volume" => ResourceType::AwsEc2Volume,
+            "AWS::EC2::VPC" => ResourceType::AwsEc2Vpc,
+            "AWS::EC2::VPNConnection" => ResourceType::AwsEc2VPNConnection,
+            "AWS::EC2::VPNGateway" => ResourceType::AwsEc2VPNGateway,
+            "AWS::EC2::RegisteredHAInstance" => ResourceType::AwsEc2RegisteredHAInstance,
+            "AWS::EC2::NatGateway" => ResourceType::AwsEc2NatGateway,
+            "AWS::EC2::EgressOnlyInternetGateway" => ResourceType::AwsEc2EgressOnlyInternetGateway,
+            "AWS::EC2::VPCEndpoint" => ResourceType::AwsEc2VPCEndpoint,
+            "AWS::EC2::VPCEndpointService" => ResourceType::AwsEc2VPCEndpointService,
+            "AWS::EC2::FlowLog" => ResourceType::AwsEc2FlowLog,
+            "AWS::EC2::VPCPeeringConnection" => ResourceType::AwsEc2VPCPeeringConnection,
+            "AWS::Elasticsearch::Domain" => ResourceType::AwsElasticsearchDomain,
+            "AWS::IAM::Group" => ResourceType::AwsIamGroup,
+            "AWS::IAM::Policy" => ResourceType::AwsIamPolicy,
+            "AWS::IAM::Role" => ResourceType::AwsIamRole,
+            "AWS::IAM::User" => ResourceType::AwsIamUser,
+            "AWS::ElasticLoadBalancingV2::LoadBalancer" => {
+                ResourceType::AwsElasticLoadBalancingV2LoadBalancer
+            }
+            "AWS::ACM::Certificate" => ResourceType::AwsAcmCertificate,
+            "AWS::RDS::DBInstance" => ResourceType::AwsRdsDBInstance,
+            "AWS::RDS::DBSubnetGroup" => ResourceType::AwsRdsDBSubnetGroup,
+            "AWS::RDS::DBSecurityGroup" => ResourceType::AwsRdsDBSecurityGroup,
+            "AWS::RDS::DBSnapshot" => ResourceType::AwsRdsDBSnapshot,
+            "AWS::RDS::DBCluster" => ResourceType::AwsRdsDBCluster,
+            "AWS::RDS::DBClusterSnapshot" => ResourceType::AwsRdsDBClusterSnapshot,
+            "AWS::RDS::EventSubscription" => ResourceType::AwsRdsEventSubscription,
+            "AWS::S3::Bucket" => ResourceType::AwsS3Bucket,
+            "AWS::S3::AccountPublicAccessBlock" => ResourceType::AwsS3AccountPublicAccessBlock,
+            "AWS::Redshift::Cluster" => ResourceType::AwsRedshiftCluster,
+            "AWS::Redshift::ClusterSnapshot" => ResourceType::AwsRedshiftClusterSnapshot,
+            "AWS::Redshift::ClusterParameterGroup" => {
+                ResourceType::AwsRedshiftClusterParameterGroup
+            }
+            "AWS::Redshift::ClusterSecurityGroup" => ResourceType::AwsRedshiftClusterSecurityGroup,
+            "AWS::Redshift::ClusterSubnetGroup" => ResourceType::AwsRedshiftClusterSubnetGroup,
+            "AWS::Redshift::EventSubscription" => ResourceType::AwsRedshiftEventSubscription,
+            "AWS::SSM::ManagedInstanceInventory" => ResourceType::AwsSsmManagedInstanceInventory,
+            "AWS::CloudWatch::Alarm" => ResourceType::AwsCloudWatchAlarm,
+            "AWS::CloudFormation::Stack" => ResourceType::AwsCloudFormationStack,
+            "AWS::ElasticLoadBalancing::LoadBalancer" => {
+                ResourceType::AwsElasticLoadBalancingLoadBalancer
+            }
+            "AWS::AutoScaling::AutoScalingGroup" => ResourceType::AwsAutoScalingAutoScalingGroup,
+            "AWS::AutoScaling::LaunchConfiguration" => {
+                ResourceType::AwsAutoScalingLaunchConfiguration
+            }
+            "AWS::AutoScaling::ScalingPolicy" => ResourceType::AwsAutoScalingScalingPolicy,
+            "AWS::AutoScaling::ScheduledAction" => ResourceType::AwsAutoScalingScheduledAction,
+            "AWS::DynamoDB::Table" => ResourceType::AwsDynamoDBTable,
+            "AWS::CodeBuild::Project" => ResourceType::AwsCodeBuildProject,
+            "AWS::WAF::RateBasedRule" => ResourceType::AwsWafRateBasedRule,
+            "AWS::WAF::Rule" => ResourceType::AwsWafRule,
+            "AWS::WAF::RuleGroup" => ResourceType::AwsWafRuleGroup,
+            "AWS::WAF::WebACL" => ResourceType::AwsWafWebACL,
+            "AWS::WAFRegional::RateBasedRule" => ResourceType::AwsWAFRegionalRateBasedRule,
+            "AWS::WAFRegional::Rule" => ResourceType::AwsWAFRegionalRule,
+            "AWS::WAFRegional::RuleGroup" => ResourceType::AwsWAFRegionalRuleGroup,
+            "AWS::WAFRegional::WebACL" => ResourceType::AwsWAFRegionalWebACL,
+            "AWS::CloudFront::Distribution" => ResourceType::AwsCloudFrontDistribution,
+            "AWS::CloudFront::StreamingDistribution" => {
+                ResourceType::AwsCloudFrontStreamingDistribution
+            }
+            "AWS::Lambda::Function" => ResourceType::AwsLambdaFunction,
+            "AWS::NetworkFirewall::Firewall" => ResourceType::AwsNetworkFirewallFirewall,
+            "AWS::NetworkFirewall::FirewallPolicy" => {
+                ResourceType::AwsNetworkFirewallFirewallPolicy
+            }
+            "AWS::NetworkFirewall::RuleGroup" => ResourceType::AwsNetworkFirewallRuleGroup,
+            "AWS::ElasticBeanstalk::Application" => ResourceType::AwsElasticBeanstalkApplication,
+            "AWS::ElasticBeanstalk::ApplicationVersion" => {
+                ResourceType::AwsElasticBeanstalkApplicationVersion
+            }
+            "AWS::ElasticBeanstalk::Environment" => ResourceType::AwsElasticBeanstalkEnvironment,
+            "AWS::WAFv2::WebACL" => ResourceType::AwsWAFv2WebACL,
+            "AWS::WAFv2::RuleGroup" => ResourceType::AwsWAFv2RuleGroup,
+            "AWS::WAFv2::IPSet" => ResourceType::AwsWAFv2IPSet,
+            "AWS::WAFv2::RegexPatternSet" => ResourceType::AwsWAFv2RegexPatternSet,
+            "AWS::WAFv2::ManagedRuleSet" => ResourceType::AwsWAFv2ManagedRuleSet,
+            "AWS::XRay::EncryptionConfig" => ResourceType::AwsXRayEncryptionConfig,
+            "AWS::SSM::AssociationCompliance" => ResourceType::AwsSsmAssociationCompliance,
+            "AWS::SSM::PatchCompliance" => ResourceType::AwsSsmPatchCompliance,
+            "AWS::Shield::Protection" => ResourceType::AwsShieldProtection,
+            "AWS::ShieldRegional::Protection" => ResourceType::AwsShieldRegionalProtection,
+            "AWS::Config::ConformancePackCompliance" => {
+                ResourceType::AwsConfigConformancePackCompliance
+            }
+            "AWS::Config::ResourceCompliance" => ResourceType::AwsConfigResourceCompliance,
+            "AWS::ApiGateway::Stage" => ResourceType::AwsApiGatewayStage,
+            "AWS::ApiGateway::RestApi" => ResourceType::AwsApiGatewayRestApi,
+            "AWS::ApiGatewayV2::Stage" => ResourceType::AwsApiGatewayV2Stage,
+            "AWS::ApiGatewayV2::Api" => ResourceType::AwsApiGatewayV2Api,
+            "AWS::CodePipeline::Pipeline" => ResourceType::AwsCodePipelinePipeline,
+            "AWS::ServiceCatalog::CloudFormationProvisionedProduct" => {
+                ResourceType::AwsServiceCatalogCloudFormationProvisionedProduct
+            }
+            "AWS::ServiceCatalog::CloudFormationProduct" => {
+                ResourceType::AwsServiceCatalogCloudFormationProduct
+            }
+            "AWS::ServiceCatalog::Portfolio" => ResourceType::AwsServiceCatalogPortfolio,
+            "AWS::SQS::Queue" => ResourceType::AwsSqsQueue,
+            "AWS::KMS::Key" => ResourceType::AwsKmsKey,
+            "AWS::QLDB::Ledger" => ResourceType::AwsQldbLedger,
+            "AWS::SecretsManager::Secret" => ResourceType::AwsSecretsManagerSecret,
+            "AWS::SNS::Topic" => ResourceType::AwsSnsTopic,
+            "AWS::SSM::FileData" => ResourceType::AwsSsmFileData,
+            "AWS::Backup::BackupPlan" => ResourceType::AwsBackupBackupPlan,
+            "AWS::Backup::BackupSelection" => ResourceType::AwsBackupBackupSelection,
+            "AWS::Backup::BackupVault" => ResourceType::AwsBackupBackupVault,
+            "AWS::Backup::RecoveryPoint" => ResourceType::AwsBackupRecoveryPoint,
+            "AWS::ECR::Repository" => ResourceType::AwsEcrRepository,
+            "AWS::ECS::Cluster" => ResourceType::AwsEcsCluster,
+            "AWS::ECS::Service" => ResourceType::AwsEcsService,
+            "AWS::ECS::TaskDefinition" => ResourceType::AwsEcsTaskDefinition,
+            "AWS::EFS::AccessPoint" => ResourceType::AwsEfsAccessPoint,
+            "AWS::EFS::FileSystem" => ResourceType::AwsEfsFileSystem,
+            "AWS::EKS::Cluster" => ResourceType::AwsEksCluster,
+            "AWS::OpenSearch::Domain" => ResourceType::AwsOpenSearchDomain,
+            "AWS::EC2::TransitGateway" => ResourceType::AwsEc2TransitGateway,
+            "AWS::Kinesis::Stream" => ResourceType::AwsKinesisStream,
+            "AWS::Kinesis::StreamConsumer" => ResourceType::AwsKinesisStreamConsumer,
+            "AWS::CodeDeploy::Application" => ResourceType::AwsCodeDeployApplication,
+            "AWS::CodeDeploy::DeploymentConfig" => ResourceType::AwsCodeDeployDeploymentConfig,
+            "AWS::CodeDeploy::DeploymentGroup" => ResourceType::AwsCodeDeployDeploymentGroup,
+            "AWS::EC2::LaunchTemplate" => ResourceType::AwsEc2LaunchTemplate,
+            "AWS::ECR::PublicRepository" => ResourceType::AwsEcrPublicRepository,
+            "AWS::GuardDuty::Detector" => ResourceType::AwsGuardDutyDetector,
+            "AWS::EMR::SecurityConfiguration" => ResourceType::AwsEmrSecurityConfiguration,
+            "AWS::SageMaker::CodeRepository" => ResourceType::AwsSageMakerCodeRepository,
+            "AWS::Route53Resolver::ResolverEndpoint" => {
+                ResourceType::AwsRoute53ResolverResolverEndpoint
+            }
+            "AWS::Route53Resolver::ResolverRule" => ResourceType::AwsRoute53ResolverResolverRule,
+            "AWS::Route53Resolver::ResolverRuleAssociation" => {
+                ResourceType::AwsRoute53ResolverResolverRuleAssociation
+            }
+            "AWS::DMS::ReplicationSubnetGroup" => ResourceType::AwsDmsReplicationSubnetGroup,
+            "AWS::DMS::EventSubscription" => ResourceType::AwsDmsEventSubscription,
+            "AWS::MSK::Cluster" => ResourceType::AwsMskCluster,
+            "AWS::StepFunctions::Activity" => ResourceType::AwsStepFunctionsActivity,
+            "AWS::WorkSpaces::Workspace" => ResourceType::AwsWorkSpacesWorkspace,
+            "AWS::WorkSpaces::ConnectionAlias" => ResourceType::AwsWorkSpacesConnectionAlias,
+            "AWS::SageMaker::Model" => ResourceType::AwsSageMakerModel,
+            "AWS::ElasticLoadBalancingV2::Listener" => {
+                ResourceType::AwsElasticLoadBalancingV2Listener
+            }
+            "AWS::StepFunctions::StateMachine" => ResourceType::AwsStepFunctionsStateMachine,
+            "AWS::Batch::JobQueue" => ResourceType::AwsBatchJobQueue,
+            "AWS::Batch::ComputeEnvironment" => ResourceType::AwsBatchComputeEnvironment,
+            "AWS::AccessAnalyzer::Analyzer" => ResourceType::AwsAccessAnalyzerAnalyzer,
+            "AWS::Athena::WorkGroup" => ResourceType::AwsAthenaWorkGroup,
+            "AWS::Athena::DataCatalog" => ResourceType::AwsAthenaDataCatalog,
+            "AWS::Detective::Graph" => ResourceType::AwsDetectiveGraph,
+            "AWS::GlobalAccelerator::Accelerator" => ResourceType::AwsGlobalAcceleratorAccelerator,
+            "AWS::GlobalAccelerator::EndpointGroup" => {
+                ResourceType::AwsGlobalAcceleratorEndpointGroup
+            }
+            "AWS::GlobalAccelerator::Listener" => ResourceType::AwsGlobalAcceleratorListener,
+            "AWS::EC2::TransitGatewayAttachment" => ResourceType::AwsEc2TransitGatewayAttachment,
+            "AWS::EC2::TransitGatewayRouteTable" => ResourceType::AwsEc2TransitGatewayRouteTable,
+            "AWS::DMS::Certificate" => ResourceType::AwsDmsCertificate,
+            "AWS::AppConfig::Application" => ResourceType::AwsAppConfigApplication,
+            "AWS::AppSync::GraphQLApi" => ResourceType::AwsAppSyncGraphQLApi,
+            "AWS::DataSync::LocationSMB" => ResourceType::AwsDataSyncLocationSMB,
+            "AWS::DataSync::LocationFSxLustre" => ResourceType::AwsDataSyncLocationFSxLustre,
+            "AWS::DataSync::LocationS3" => ResourceType::AwsDataSyncLocationS3,
+            "AWS::DataSync::LocationEFS" => ResourceType::AwsDataSyncLocationEFS,
+            "AWS::DataSync::Task" => ResourceType::AwsDataSyncTask,
+            "AWS::DataSync::LocationNFS" => ResourceType::AwsDataSyncLocationNFS,
+            "AWS::EC2::NetworkInsightsAccessScopeAnalysis" => {
+                ResourceType::AwsEc2NetworkInsightsAccessScopeAnalysis
+            }
+            "AWS::EKS::FargateProfile" => ResourceType::AwsEksFargateProfile,
+            "AWS::Glue::Job" => ResourceType::AwsGlueJob,
+            "AWS::GuardDuty::ThreatIntelSet" => ResourceType::AwsGuardDutyThreatIntelSet,
+            "AWS::GuardDuty::IPSet" => ResourceType::AwsGuardDutyIPSet,
+            "AWS::SageMaker::Workteam" => ResourceType::AwsSageMakerWorkteam,
+            "AWS::SageMaker::NotebookInstanceLifecycleConfig" => {
+                ResourceType::AwsSageMakerNotebookInstanceLifecycleConfig
+            }
+            "AWS::ServiceDiscovery::Service" => ResourceType::AwsServiceDiscoveryService,
+            "AWS::ServiceDiscovery::PublicDnsNamespace" => {
+                ResourceType::AwsServiceDiscoveryPublicDnsNamespace
+            }
+            "AWS::SES::ContactList" => ResourceType::AwsSesContactList,
+            "AWS::SES::ConfigurationSet" => ResourceType::AwsSesConfigurationSet,
+            "AWS::Route53::HostedZone" => ResourceType::AwsRoute53HostedZone,
+            "AWS::IoTEvents::Input" => ResourceType::AwsIoTEventsInput,
+            "AWS::IoTEvents::DetectorModel" => ResourceType::AwsIoTEventsDetectorModel,
+            "AWS::IoTEvents::AlarmModel" => ResourceType::AwsIoTEventsAlarmModel,
+            "AWS::ServiceDiscovery::HttpNamespace" => {
+                ResourceType::AwsServiceDiscoveryHttpNamespace
+            }
+            "AWS::Events::EventBus" => ResourceType::AwsEventsEventBus,
+            "AWS::ImageBuilder::ContainerRecipe" => ResourceType::AwsImageBuilderContainerRecipe,
+            "AWS::ImageBuilder::DistributionConfiguration" => {
+                ResourceType::AwsImageBuilderDistributionConfiguration
+            }
+            "AWS::ImageBuilder::InfrastructureConfiguration" => {
+                ResourceType::AwsImageBuilderInfrastructureConfiguration
+            }
+            "AWS::DataSync::LocationObjectStorage" => {
+                ResourceType::AwsDataSyncLocationObjectStorage
+            }
+            "AWS::DataSync::LocationHDFS" => ResourceType::AwsDataSyncLocationHDFS,
+            "AWS::Glue::Classifier" => ResourceType::AwsGlueClassifier,
+            "AWS::Route53RecoveryReadiness::Cell" => ResourceType::AwsRoute53RecoveryReadinessCell,
+            "AWS::Route53RecoveryReadiness::ReadinessCheck" => {
+                ResourceType::AwsRoute53RecoveryReadinessReadinessCheck
+            }
+            "AWS::ECR::RegistryPolicy" => ResourceType::AwsEcrRegistryPolicy,
+            "AWS::Backup::ReportPlan" => ResourceType::AwsBackupReportPlan,
+            "AWS::Lightsail::Certificate" => ResourceType::AwsLightsailCertificate,
+            "AWS::RUM::AppMonitor" => ResourceType::AwsRumAppMonitor,
+            "AWS::Events::Endpoint" => ResourceType::AwsEventsEndpoint,
+            "AWS::SES::ReceiptRuleSet" => ResourceType::AwsSesReceiptRuleSet,
+            "AWS::Events::Archive" => ResourceType::AwsEventsArchive,
+            "AWS::Events::ApiDestination" => ResourceType::AwsEventsApiDestination,
+            "AWS::Lightsail::Disk" => ResourceType::AwsLightsailDisk,
+            "AWS::FIS::ExperimentTemplate" => ResourceType::AwsFisExperimentTemplate,
+            "AWS::DataSync::LocationFSxWindows" => ResourceType::AwsDataSyncLocationFSxWindows,
+            "AWS::SES::ReceiptFilter" => ResourceType::AwsSesReceiptFilter,
+            "AWS::GuardDuty::Filter" => ResourceType::AwsGuardDutyFilter,
+            "AWS::SES::Template" => ResourceType::AwsSesTemplate,
+            "AWS::AmazonMQ::Broker" => ResourceType::AwsAmazonMQBroker,
+            "AWS::AppConfig::Environment" => ResourceType::AwsAppConfigEnvironment,
+            "AWS::AppConfig::ConfigurationProfile" => {
+                ResourceType::AwsAppConfigConfigurationProfile
+            }
+            "AWS::Cloud9::EnvironmentEC2" => ResourceType::AwsCloud9EnvironmentEC2,
+            "AWS::EventSchemas::Registry" => ResourceType::AwsEventSchemasRegistry,
+            "AWS::EventSchemas::RegistryPolicy" => ResourceType::AwsEventSchemasRegistryPolicy,
+            "AWS::EventSchemas::Discoverer" => ResourceType::AwsEventSchemasDiscoverer,
+            "AWS::FraudDetector::Label" => ResourceType::AwsFraudDetectorLabel,
+            "AWS::FraudDetector::EntityType" => ResourceType::AwsFraudDetectorEntityType,
+            "AWS::FraudDetector::Variable" => ResourceType::AwsFraudDetectorVariable,
+            "AWS::FraudDetector::Outcome" => ResourceType::AwsFraudDetectorOutcome,
+            "AWS::IoT::Authorizer" => ResourceType::AwsIoTAuthorizer,
+            "AWS::IoT::SecurityProfile" => ResourceType::AwsIoTSecurityProfile,
+            "AWS::IoT::RoleAlias" => ResourceType::AwsIoTRoleAlias,
+            "AWS::IoT::Dimension" => ResourceType::AwsIoTDimension,
+            "AWS::IoTAnalytics::Datastore" => ResourceType::AwsIoTAnalyticsDatastore,
+            "AWS::Lightsail::Bucket" => ResourceType::AwsLightsailBucket,
+            "AWS::Lightsail::StaticIp" => ResourceType::AwsLightsailStaticIp,
+            "AWS::MediaPackage::PackagingGroup" => ResourceType::AwsMediaPackagePackagingGroup,
+            "AWS::Route53RecoveryReadiness::RecoveryGroup" => {
+                ResourceType::AwsRoute53RecoveryReadinessRecoveryGroup
+            }
+            "AWS::ResilienceHub::ResiliencyPolicy" => {
+                ResourceType::AwsResilienceHubResiliencyPolicy
+            }
+            "AWS::Transfer::Workflow" => ResourceType::AwsTransferWorkflow,
+            "AWS::EKS::IdentityProviderConfig" => ResourceType::AwsEksIdentityProviderConfig,
+            "AWS::EKS::Addon" => ResourceType::AwsEksAddon,
+            "AWS::Glue::MLTransform" => ResourceType::AwsGlueMLTransform,
+            "AWS::IoT::Policy" => ResourceType::AwsIoTPolicy,
+            "AWS::IoT::MitigationAction" => ResourceType::AwsIoTMitigationAction,
+            "AWS::IoTTwinMaker::Workspace" => ResourceType::AwsIoTTwinMakerWorkspace,
+            "AWS::IoTTwinMaker::Entity" => ResourceType::AwsIoTTwinMakerEntity,
+            "AWS::IoTAnalytics::Dataset" => ResourceType::AwsIoTAnalyticsDataset,
+            "AWS::IoTAnalytics::Pipeline" => ResourceType::AwsIoTAnalyticsPipeline,
+            "AWS::IoTAnalytics::Channel" => ResourceType::AwsIoTAnalyticsChannel,
+            "AWS::IoTSiteWise::Dashboard" => ResourceType::AwsIoTSiteWiseDashboard,
+            "AWS::IoTSiteWise::Project" => ResourceType::AwsIoTSiteWiseProject,
+            "AWS::IoTSiteWise::Portal" => ResourceType::AwsIoTSiteWisePortal,
+            "AWS::IoTSiteWise::AssetModel" => ResourceType::AwsIoTSiteWiseAssetModel,
+            "AWS::IVS::Channel" => ResourceType::AwsIvsChannel,
+            "AWS::IVS::RecordingConfiguration" => ResourceType::AwsIvsRecordingConfiguration,
+            "AWS::IVS::PlaybackKeyPair" => ResourceType::AwsIvsPlaybackKeyPair,
+            "AWS::KinesisAnalyticsV2::Application" => {
+                ResourceType::AwsKinesisAnalyticsV2Application
+            }
+            "AWS::RDS::GlobalCluster" => ResourceType::AwsRdsGlobalCluster,
+            "AWS::S3::MultiRegionAccessPoint" => ResourceType::AwsS3MultiRegionAccessPoint,
+            "AWS::DeviceFarm::TestGridProject" => ResourceType::AwsDeviceFarmTestGridProject,
+            "AWS::Budgets::BudgetsAction" => ResourceType::AwsBudgetsBudgetsAction,
+            "AWS::Lex::Bot" => ResourceType::AwsLexBot,
+            "AWS::CodeGuruReviewer::RepositoryAssociation" => {
+                ResourceType::AwsCodeGuruReviewerRepositoryAssociation
+            }
+            "AWS::IoT::CustomMetric" => ResourceType::AwsIoTCustomMetric,
+            "AWS::Route53Resolver::FirewallDomainList" => {
+                ResourceType::AwsRoute53ResolverFirewallDomainList
+            }
+            "AWS::RoboMaker::RobotApplicationVersion" => {
+                ResourceType::AwsRoboMakerRobotApplicationVersion
+            }
+            "AWS::EC2::TrafficMirrorSession" => ResourceType::AwsEc2TrafficMirrorSession,
+            "AWS::IoTSiteWise::Gateway" => ResourceType::AwsIoTSiteWiseGateway,
+            "AWS::Lex::BotAlias" => ResourceType::AwsLexBotAlias,
+            "AWS::LookoutMetrics::Alert" => ResourceType::AwsLookoutMetricsAlert,
+            "AWS::IoT::AccountAuditConfiguration" => ResourceType::AwsIoTAccountAuditConfiguration,
+            "AWS::EC2::TrafficMirrorTarget" => ResourceType::AwsEc2TrafficMirrorTarget,
+            "AWS::S3::StorageLens" => ResourceType::AwsS3StorageLens,
+            "AWS::IoT::ScheduledAudit" => ResourceType::AwsIoTScheduledAudit,
+            "AWS::Events::Connection" => ResourceType::AwsEventsConnection,
+            "AWS::EventSchemas::Schema" => ResourceType::AwsEventSchemasSchema,
+            "AWS::MediaPackage::PackagingConfiguration" => {
+                ResourceType::AwsMediaPackagePackagingConfiguration
+            }
+            "AWS::KinesisVideo::SignalingChannel" => ResourceType::AwsKinesisVideoSignalingChannel,
+            "AWS::AppStream::DirectoryConfig" => ResourceType::AwsAppStreamDirectoryConfig,
+            "AWS::LookoutVision::Project" => ResourceType::AwsLookoutVisionProject,
+            "AWS::Route53RecoveryControl::Cluster" => {
+                ResourceType::AwsRoute53RecoveryControlCluster
+            }
+            "AWS::Route53RecoveryControl::SafetyRule" => {
+                ResourceType::AwsRoute53RecoveryControlSafetyRule
+            }
+            "AWS::Route53RecoveryControl::ControlPanel" => {
+                ResourceType::AwsRoute53RecoveryControlControlPanel
+            }
+            "AWS::Route53RecoveryControl::RoutingControl" => {
+                ResourceType::AwsRoute53RecoveryControlRoutingControl
+            }
+            "AWS::Route53RecoveryReadiness::ResourceSet" => {
+                ResourceType::AwsRoute53RecoveryReadinessResourceSet
+            }
+            "AWS::RoboMaker::SimulationApplication" => {
+                ResourceType::AwsRoboMakerSimulationApplication
+            }
+            "AWS::RoboMaker::RobotApplication" => ResourceType::AwsRoboMakerRobotApplication,
+            "AWS::HealthLake::FHIRDatastore" => ResourceType::AwsHealthLakeFHIRDatastore,
+            "AWS::Pinpoint::Segment" => ResourceType::AwsPinpointSegment,
+            "AWS::Pinpoint::ApplicationSettings" => ResourceType::AwsPinpointApplicationSettings,
+            "AWS::Events::Rule" => ResourceType::AwsEventsRule,
+            "AWS::EC2::DHCPOptions" => ResourceType::AwsEc2DHCPOptions,
+            "AWS::EC2::NetworkInsightsPath" => ResourceType::AwsEc2NetworkInsightsPath,
+            "AWS::EC2::TrafficMirrorFilter" => ResourceType::AwsEc2TrafficMirrorFilter,
+            "AWS::EC2::IPAM" => ResourceType::AwsEc2Ipam,
+            "AWS::IoTTwinMaker::Scene" => ResourceType::AwsIoTTwinMakerScene,
+            "AWS::NetworkManager::TransitGatewayRegistration" => {
+                ResourceType::AwsNetworkManagerTransitGatewayRegistration
+            }
+            "AWS::CustomerProfiles::Domain" => ResourceType::AwsCustomerProfilesDomain,
+            "AWS::AutoScaling::WarmPool" => ResourceType::AwsAutoScalingWarmPool,
+            "AWS::Connect::PhoneNumber" => ResourceType::AwsConnectPhoneNumber,
+            "AWS::AppConfig::DeploymentStrategy" => ResourceType::AwsAppConfigDeploymentStrategy,
+            "AWS::AppFlow::Flow" => ResourceType::AwsAppFlowFlow,
+            "AWS::AuditManager::Assessment" => ResourceType::AwsAuditManagerAssessment,
+            "AWS::CloudWatch::MetricStream" => ResourceType::AwsCloudWatchMetricStream,
+            "AWS::DeviceFarm::InstanceProfile" => ResourceType::AwsDeviceFarmInstanceProfile,
+            "AWS::DeviceFarm::Project" => ResourceType::AwsDeviceFarmProject,
+            "AWS::EC2::EC2Fleet" => ResourceType::AwsEc2EC2Fleet,
+            "AWS::EC2::SubnetRouteTableAssociation" => {
+                ResourceType::AwsEc2SubnetRouteTableAssociation
+            }
+            "AWS::ECR::PullThroughCacheRule" => ResourceType::AwsEcrPullThroughCacheRule,
+            "AWS::GroundStation::Config" => ResourceType::AwsGroundStationConfig,
+            "AWS::ImageBuilder::ImagePipeline" => ResourceType::AwsImageBuilderImagePipeline,
+            "AWS::IoT::FleetMetric" => ResourceType::AwsIoTFleetMetric,
+            "AWS::IoTWireless::ServiceProfile" => ResourceType::AwsIoTWirelessServiceProfile,
+            "AWS::NetworkManager::Device" => ResourceType::AwsNetworkManagerDevice,
+            "AWS::NetworkManager::GlobalNetwork" => ResourceType::AwsNetworkManagerGlobalNetwork,
+            "AWS::NetworkManager::Link" => ResourceType::AwsNetworkManagerLink,
+            "AWS::NetworkManager::Site" => ResourceType::AwsNetworkManagerSite,
+            "AWS::Panorama::Package" => ResourceType::AwsPanoramaPackage,
+            "AWS::Pinpoint::App" => ResourceType::AwsPinpointApp,
+            "AWS::Redshift::ScheduledAction" => ResourceType::AwsRedshiftScheduledAction,
+            "AWS::Route53Resolver::FirewallRuleGroupAssociation" => {
+                ResourceType::AwsRoute53ResolverFirewallRuleGroupAssociation
+            }
+            "AWS::SageMaker::AppImageConfig" => ResourceType::AwsSageMakerAppImageConfig,
+            "AWS::SageMaker::Image" => ResourceType::AwsSageMakerImage,
+            "AWS::ECS::TaskSet" => ResourceType::AwsEcsTaskSet,
+            "AWS::Cassandra::Keyspace" => ResourceType::AwsCassandraKeyspace,
+            "AWS::Signer::SigningProfile" => ResourceType::AwsSignerSigningProfile,
+            "AWS::Amplify::App" => ResourceType::AwsAmplifyApp,
+            "AWS::AppMesh::VirtualNode" => ResourceType::AwsAppMeshVirtualNode,
+            "AWS::AppMesh::VirtualService" => ResourceType::AwsAppMeshVirtualService,
+            "AWS::AppRunner::VpcConnector" => ResourceType::AwsAppRunnerVpcConnector,
+            "AWS::AppStream::Application" => ResourceType::AwsAppStreamApplication,
+            "AWS::CodeArtifact::Repository" => ResourceType::AwsCodeArtifactRepository,
+            "AWS::EC2::PrefixList" => ResourceType::AwsEc2PrefixList,
+            "AWS::EC2::SpotFleet" => ResourceType::AwsEc2SpotFleet,
+            "AWS::Evidently::Project" => ResourceType::AwsEvidentlyProject,
+            "AWS::Forecast::Dataset" => ResourceType::AwsForecastDataset,
+            "AWS::IAM::SAMLProvider" => ResourceType::AwsIamSAMLProvider,
+            "AWS::IAM::ServerCertificate" => ResourceType::AwsIamServerCertificate,
+            "AWS::Pinpoint::Campaign" => ResourceType::AwsPinpointCampaign,
+            "AWS::Pinpoint::InAppTemplate" => ResourceType::AwsPinpointInAppTemplate,
+            "AWS::SageMaker::Domain" => ResourceType::AwsSageMakerDomain,
+            "AWS::Transfer::Agreement" => ResourceType::AwsTransferAgreement,
+            "AWS::Transfer::Connector" => ResourceType::AwsTransferConnector,
+            "AWS::KinesisFirehose::DeliveryStream" => {
+                ResourceType::AwsKinesisFirehoseDeliveryStream
+            }
+            "AWS::Amplify::Branch" => ResourceType::AwsAmplifyBranch,
+            "AWS::AppIntegrations::EventIntegration" => {
+                ResourceType::AwsAppIntegrationsEventIntegration
+            }
+            "AWS::AppMesh::Route" => ResourceType::AwsAppMeshRoute,
+            "AWS::Athena::PreparedStatement" => ResourceType::AwsAthenaPreparedStatement,
+            "AWS::EC2::IPAMScope" => ResourceType::AwsEc2IPAMScope,
+            "AWS::Evidently::Launch" => ResourceType::AwsEvidentlyLaunch,
+            "AWS::Forecast::DatasetGroup" => ResourceType::AwsForecastDatasetGroup,
+            "AWS::GreengrassV2::ComponentVersion" => ResourceType::AwsGreengrassV2ComponentVersion,
+            "AWS::GroundStation::MissionProfile" => ResourceType::AwsGroundStationMissionProfile,
+            "AWS::MediaConnect::FlowEntitlement" => ResourceType::AwsMediaConnectFlowEntitlement,
+            "AWS::MediaConnect::FlowVpcInterface" => ResourceType::AwsMediaConnectFlowVpcInterface,
+            "AWS::MediaTailor::PlaybackConfiguration" => {
+                ResourceType::AwsMediaTailorPlaybackConfiguration
+            }
+            "AWS::MSK::Configuration" => ResourceType::AwsMskConfiguration,
+            "AWS::Personalize::Dataset" => ResourceType::AwsPersonalizeDataset,
+            "AWS::Personalize::Schema" => ResourceType::AwsPersonalizeSchema,
+            "AWS::Personalize::Solution" => ResourceType::AwsPersonalizeSolution,
+            "AWS::Pinpoint::EmailTemplate" => ResourceType::AwsPinpointEmailTemplate,
+            "AWS::Pinpoint::EventStream" => ResourceType::AwsPinpointEventStream,
+            "AWS::ResilienceHub::App" => ResourceType::AwsResilienceHubApp,
+            "AWS::ACMPCA::CertificateAuthority" => ResourceType::AwsAcmpcaCertificateAuthority,
+            "AWS::AppConfig::HostedConfigurationVersion" => {
+                ResourceType::AwsAppConfigHostedConfigurationVersion
+            }
+            "AWS::AppMesh::VirtualGateway" => ResourceType::AwsAppMeshVirtualGateway,
+            "AWS::AppMesh::VirtualRouter" => ResourceType::AwsAppMeshVirtualRouter,
+            "AWS::AppRunner::Service" => ResourceType::AwsAppRunnerService,
+            "AWS::CustomerProfiles::ObjectType" => ResourceType::AwsCustomerProfilesObjectType,
+            "AWS::DMS::Endpoint" => ResourceType::AwsDmsEndpoint,
+            "AWS::EC2::CapacityReservation" => ResourceType::AwsEc2CapacityReservation,
+            "AWS::EC2::ClientVpnEndpoint" => ResourceType::AwsEc2ClientVpnEndpoint,
+            "AWS::Kendra::Index" => ResourceType::AwsKendraIndex,
+            "AWS::KinesisVideo::Stream" => ResourceType::AwsKinesisVideoStream,
+            "AWS::Logs::Destination" => ResourceType::AwsLogsDestination,
+            "AWS::Pinpoint::EmailChannel" => ResourceType::AwsPinpointEmailChannel,
+            "AWS::S3::AccessPoint" => ResourceType::AwsS3AccessPoint,
+            "AWS::NetworkManager::CustomerGatewayAssociation" => {
+                ResourceType::AwsNetworkManagerCustomerGatewayAssociation
+            }
+            "AWS::NetworkManager::LinkAssociation" => {
+                ResourceType::AwsNetworkManagerLinkAssociation
+            }
+            "AWS::IoTWireless::MulticastGroup" => ResourceType::AwsIoTWirelessMulticastGroup,
+            "AWS::Personalize::DatasetGroup" => ResourceType::AwsPersonalizeDatasetGroup,
+            "AWS::IoTTwinMaker::ComponentType" => ResourceType::AwsIoTTwinMakerComponentType,
+            "AWS::CodeBuild::ReportGroup" => ResourceType::AwsCodeBuildReportGroup,
+            "AWS::SageMaker::FeatureGroup" => ResourceType::AwsSageMakerFeatureGroup,
+            "AWS::MSK::BatchScramSecret" => ResourceType::AwsMskBatchScramSecret,
+            "AWS::AppStream::Stack" => ResourceType::AwsAppStreamStack,
+            "AWS::IoT::JobTemplate" => ResourceType::AwsIoTJobTemplate,
+            "AWS::IoTWireless::FuotaTask" => ResourceType::AwsIoTWirelessFuotaTask,
+            "AWS::IoT::ProvisioningTemplate" => ResourceType::AwsIoTProvisioningTemplate,
+            "AWS::InspectorV2::Filter" => ResourceType::AwsInspectorV2Filter,
+            "AWS::Route53Resolver::ResolverQueryLoggingConfigAssociation" => {
+                ResourceType::AwsRoute53ResolverResolverQueryLoggingConfigAssociation
+            }
+            "AWS::ServiceDiscovery::Instance" => ResourceType::AwsServiceDiscoveryInstance,
+            "AWS::Transfer::Certificate" => ResourceType::AwsTransferCertificate,
+            "AWS::MediaConnect::FlowSource" => ResourceType::AwsMediaConnectFlowSource,
+            "AWS::APS::RuleGroupsNamespace" => ResourceType::AwsApsRuleGroupsNamespace,
+            "AWS::CodeGuruProfiler::ProfilingGroup" => {
+                ResourceType::AwsCodeGuruProfilerProfilingGroup
+            }
+            "AWS::Route53Resolver::ResolverQueryLoggingConfig" => {
+                ResourceType::AwsRoute53ResolverResolverQueryLoggingConfig
+            }
+            "AWS::Batch::SchedulingPolicy" => ResourceType::AwsBatchSchedulingPolicy,
+            "AWS::ACMPCA::CertificateAuthorityActivation" => {
+                ResourceType::AwsAcmpcaCertificateAuthorityActivation
+            }
+            "AWS::AppMesh::GatewayRoute" => ResourceType::AwsAppMeshGatewayRoute,
+            "AWS::AppMesh::Mesh" => ResourceType::AwsAppMeshMesh,
+            "AWS::Connect::Instance" => ResourceType::AwsConnectInstance,
+            "AWS::Connect::QuickConnect" => ResourceType::AwsConnectQuickConnect,
+            "AWS::EC2::CarrierGateway" => ResourceType::AwsEc2CarrierGateway,
+            "AWS::EC2::IPAMPool" => ResourceType::AwsEc2IPAMPool,
+            "AWS::EC2::TransitGatewayConnect" => ResourceType::AwsEc2TransitGatewayConnect,
+            "AWS::EC2::TransitGatewayMulticastDomain" => {
+                ResourceType::AwsEc2TransitGatewayMulticastDomain
+            }
+            "AWS::ECS::CapacityProvider" => ResourceType::AwsEcsCapacityProvider,
+            "AWS::IAM::InstanceProfile" => ResourceType::AwsIamInstanceProfile,
+            "AWS::IoT::CACertificate" => ResourceType::AwsIoTCACertificate,
+            "AWS::IoTTwinMaker::SyncJob" => ResourceType::AwsIoTTwinMakerSyncJob,
+            "AWS::KafkaConnect::Connector" => ResourceType::AwsKafkaConnectConnector,
+            "AWS::Lambda::CodeSigningConfig" => ResourceType::AwsLambdaCodeSigningConfig,
+            "AWS::NetworkManager::ConnectPeer" => ResourceType::AwsNetworkManagerConnectPeer,
+            "AWS::ResourceExplorer2::Index" => ResourceType::AwsResourceExplorer2Index,
+            "AWS::AppStream::Fleet" => ResourceType::AwsAppStreamFleet,
+            "AWS::Cognito::UserPool" => ResourceType::AwsCognitoUserPool,
+            "AWS::Cognito::UserPoolClient" => ResourceType::AwsCognitoUserPoolClient,
+            "AWS::Cognito::UserPoolGroup" => ResourceType::AwsCognitoUserPoolGroup,
+            "AWS::EC2::NetworkInsightsAccessScope" => {
+                ResourceType::AwsEc2NetworkInsightsAccessScope
+            }
+            "AWS::EC2::NetworkInsightsAnalysis" => ResourceType::AwsEc2NetworkInsightsAnalysis,
+            "AWS::Grafana::Workspace" => ResourceType::AwsGrafanaWorkspace,
+            "AWS::GroundStation::DataflowEndpointGroup" => {
+                ResourceType::AwsGroundStationDataflowEndpointGroup
+            }
+            "AWS::ImageBuilder::ImageRecipe" => ResourceType::AwsImageBuilderImageRecipe,
+            "AWS::KMS::Alias" => ResourceType::AwsKmsAlias,
+            "AWS::M2::Environment" => ResourceType::AwsM2Environment,
+            "AWS::QuickSight::DataSource" => ResourceType::AwsQuickSightDataSource,
+            "AWS::QuickSight::Template" => ResourceType::AwsQuickSightTemplate,
+            "AWS::QuickSight::Theme" => ResourceType::AwsQuickSightTheme,
+            "AWS::RDS::OptionGroup" => ResourceType::AwsRdsOptionGroup,
+            "AWS::Redshift::EndpointAccess" => ResourceType::AwsRedshiftEndpointAccess,
+            "AWS::Route53Resolver::FirewallRuleGroup" => {
+                ResourceType::AwsRoute53ResolverFirewallRuleGroup
+            }
+            "AWS::SSM::Document" => ResourceType::AwsSsmDocument,
+            _ => ResourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceType {
+    fn from(value: String) -> ResourceType {
+        match value.as_str() {
+            "AWS::EC2::CustomerGateway" => ResourceType::AwsEc2CustomerGateway,
+            "AWS::EC2::EIP" => ResourceType::AwsEc2Eip,
+            "AWS::EC2::Host" => ResourceType::AwsEc2Host,
+            "AWS::EC2::Instance" => ResourceType::AwsEc2Instance,
+            "AWS::EC2::InternetGateway" => ResourceType::AwsEc2InternetGateway,
+            "AWS::EC2::NetworkAcl" => ResourceType::AwsEc2NetworkAcl,
+            "AWS::EC2::NetworkInterface" => ResourceType::AwsEc2NetworkInterface,
+            "AWS::EC2::RouteTable" => ResourceType::AwsEc2RouteTable,
+            "AWS::EC2::SecurityGroup" => ResourceType::AwsEc2SecurityGroup,
+            "AWS::EC2::Subnet" => ResourceType::AwsEc2Subnet,
+            "AWS::CloudTrail::Trail" => ResourceType::AwsCloudTrailTrail,
+            "AWS::EC2::Volume" => ResourceType::AwsEc2Volume,
+            "AWS::EC2::VPC" => ResourceType::AwsEc2Vpc,
+            "AWS::EC2::VPNConnection" => ResourceType::AwsEc2VPNConnection,
+            "AWS::EC2::VPNGateway" => ResourceType::AwsEc2VPNGateway,
+            "AWS::EC2::RegisteredHAInstance" => ResourceType::AwsEc2RegisteredHAInstance,
+            "AWS::EC2::NatGateway" => ResourceType::AwsEc2NatGateway,
+            "AWS::EC2::EgressOnlyInternetGateway" => ResourceType::AwsEc2EgressOnlyInternetGateway,
+            "AWS::EC2::VPCEndpoint" => ResourceType::AwsEc2VPCEndpoint,
+            "AWS::EC2::VPCEndpointService" => ResourceType::AwsEc2VPCEndpointService,
+            "AWS::EC2::FlowLog" => ResourceType::AwsEc2FlowLog,
+            "AWS::EC2::VPCPeeringConnection" => ResourceType::AwsEc2VPCPeeringConnection,
+            "AWS::Elasticsearch::Domain" => ResourceType::AwsElasticsearchDomain,
+            "AWS::IAM::Group" => ResourceType::AwsIamGroup,
+            "AWS::IAM::Policy" => ResourceType::AwsIamPolicy,
+            "AWS::IAM::Role" => ResourceType::AwsIamRole,
+            "AWS::IAM::User" => ResourceType::AwsIamUser,
+            "AWS::ElasticLoadBalancingV2::LoadBalancer" => {
+                ResourceType::AwsElasticLoadBalancingV2LoadBalancer
+            }
+            "AWS::ACM::Certificate" => ResourceType::AwsAcmCertificate,
+            "AWS::RDS::DBInstance" => ResourceType::AwsRdsDBInstance,
+            "AWS::RDS::DBSubnetGroup" => ResourceType::AwsRdsDBSubnetGroup,
+            "AWS::RDS::DBSecurityGroup" => ResourceType::AwsRdsDBSecurityGroup,
+            "AWS::RDS::DBSnapshot" => ResourceType::AwsRdsDBSnapshot,
+            "AWS::RDS::DBCluster" => ResourceType::AwsRdsDBCluster,
+            "AWS::RDS::DBClusterSnapshot" => ResourceType::AwsRdsDBClusterSnapshot,
+            "AWS::RDS::EventSubscription" => ResourceType::AwsRdsEventSubscription,
+            "AWS::S3::Bucket" => ResourceType::AwsS3Bucket,
+            "AWS::S3::AccountPublicAccessBlock" => ResourceType::AwsS3AccountPublicAccessBlock,
+            "AWS::Redshift::Cluster" => ResourceType::AwsRedshiftCluster,
+            "AWS::Redshift::ClusterSnapshot" => ResourceType::AwsRedshiftClusterSnapshot,
+            "AWS::Redshift::ClusterParameterGroup" => {
+                ResourceType::AwsRedshiftClusterParameterGroup
+            }
+            "AWS::Redshift::ClusterSecurityGroup" => ResourceType::AwsRedshiftClusterSecurityGroup,
+            "AWS::Redshift::ClusterSubnetGroup" => ResourceType::AwsRedshiftClusterSubnetGroup,
+            "AWS::Redshift::EventSubscription" => ResourceType::AwsRedshiftEventSubscription,
+            "AWS::SSM::ManagedInstanceInventory" => ResourceType::AwsSsmManagedInstanceInventory,
+            "AWS::CloudWatch::Alarm" => ResourceType::AwsCloudWatchAlarm,
+            "AWS::CloudFormation::Stack" => ResourceType::AwsCloudFormationStack,
+            "AWS::ElasticLoadBalancing::LoadBalancer" => {
+                ResourceType::AwsElasticLoadBalancingLoadBalancer
+            }
+            "AWS::AutoScaling::AutoScalingGroup" => ResourceType::AwsAutoScalingAutoScalingGroup,
+            "AWS::AutoScaling::LaunchConfiguration" => {
+                ResourceType::AwsAutoScalingLaunchConfiguration
+            }
+            "AWS::AutoScaling::ScalingPolicy" => ResourceType::AwsAutoScalingScalingPolicy,
+            "AWS::AutoScaling::ScheduledAction" => ResourceType::AwsAutoScalingScheduledAction,
+            "AWS::DynamoDB::Table" => ResourceType::AwsDynamoDBTable,
+            "AWS::CodeBuild::Project" => ResourceType::AwsCodeBuildProject,
+            "AWS::WAF::RateBasedRule" => ResourceType::AwsWafRateBasedRule,
+            "AWS::WAF::Rule" => ResourceType::AwsWafRule,
+            "AWS::WAF::RuleGroup" => ResourceType::AwsWafRuleGroup,
+            "AWS::WAF::WebACL" => ResourceType::AwsWafWebACL,
+            "AWS::WAFRegional::RateBasedRule" => ResourceType::AwsWAFRegionalRateBasedRule,
+            "AWS::WAFRegional::Rule" => ResourceType::AwsWAFRegionalRule,
+            "AWS::WAFRegional::RuleGroup" => ResourceType::AwsWAFRegionalRuleGroup,
+            "AWS::WAFRegional::WebACL" => ResourceType::AwsWAFRegionalWebACL,
+            "AWS::CloudFront::Distribution" => ResourceType::AwsCloudFrontDistribution,
+            "AWS::CloudFront::StreamingDistribution" => {
+                ResourceType::AwsCloudFrontStreamingDistribution
+            }
+            "AWS::Lambda::Function" => ResourceType::AwsLambdaFunction,
+            "AWS::NetworkFirewall::Firewall" => ResourceType::AwsNetworkFirewallFirewall,
+            "AWS::NetworkFirewall::FirewallPolicy" => {
+                ResourceType::AwsNetworkFirewallFirewallPolicy
+            }
+            "AWS::NetworkFirewall::RuleGroup" => ResourceType::AwsNetworkFirewallRuleGroup,
+            "AWS::ElasticBeanstalk::Application" => ResourceType::AwsElasticBeanstalkApplication,
+            "AWS::ElasticBeanstalk::ApplicationVersion" => {
+                ResourceType::AwsElasticBeanstalkApplicationVersion
+            }
+            "AWS::ElasticBeanstalk::Environment" => ResourceType::AwsElasticBeanstalkEnvironment,
+            "AWS::WAFv2::WebACL" => ResourceType::AwsWAFv2WebACL,
+            "AWS::WAFv2::RuleGroup" => ResourceType::AwsWAFv2RuleGroup,
+            "AWS::WAFv2::IPSet" => ResourceType::AwsWAFv2IPSet,
+            "AWS::WAFv2::RegexPatternSet" => ResourceType::AwsWAFv2RegexPatternSet,
+            "AWS::WAFv2::ManagedRuleSet" => ResourceType::AwsWAFv2ManagedRuleSet,
+            "AWS::XRay::EncryptionConfig" => ResourceType::AwsXRayEncryptionConfig,
+            "AWS::SSM::AssociationCompliance" => ResourceType::AwsSsmAssociationCompliance,
+            "AWS::SSM::PatchCompliance" => ResourceType::AwsSsmPatchCompliance,
+            "AWS::Shield::Protection" => ResourceType::AwsShieldProtection,
+            "AWS::ShieldRegional::Protection" => ResourceType::AwsShieldRegionalProtection,
+            "AWS::Config::ConformancePackCompliance" => {
+                ResourceType::AwsConfigConformancePackCompliance
+            }
+            "AWS::Config::ResourceCompliance" => ResourceType::AwsConfigResourceCompliance,
+            "AWS::ApiGateway::Stage" => ResourceType::AwsApiGatewayStage,
+            "AWS::ApiGateway::RestApi" => ResourceType::AwsApiGatewayRestApi,
+            "AWS::ApiGatewayV2::Stage" => ResourceType::AwsApiGatewayV2Stage,
+            "AWS::ApiGatewayV2::Api" => ResourceType::AwsApiGatewayV2Api,
+            "AWS::CodePipeline::Pipeline" => ResourceType::AwsCodePipelinePipeline,
+            "AWS::ServiceCatalog::CloudFormationProvisionedProduct" => {
+                ResourceType::AwsServiceCatalogCloudFormationProvisionedProduct
+            }
+            "AWS::ServiceCatalog::CloudFormationProduct" => {
+                ResourceType::AwsServiceCatalogCloudFormationProduct
+            }
+            "AWS::ServiceCatalog::Portfolio" => ResourceType::AwsServiceCatalogPortfolio,
+            "AWS::SQS::Queue" => ResourceType::AwsSqsQueue,
+            "AWS::KMS::Key" => ResourceType::AwsKmsKey,
+            "AWS::QLDB::Ledger" => ResourceType::AwsQldbLedger,
+            "AWS::SecretsManager::Secret" => ResourceType::AwsSecretsManagerSecret,
+            "AWS::SNS::Topic" => ResourceType::AwsSnsTopic,
+            "AWS::SSM::FileData" => ResourceType::AwsSsmFileData,
+            "AWS::Backup::BackupPlan" => ResourceType::AwsBackupBackupPlan,
+            "AWS::Backup::BackupSelection" => ResourceType::AwsBackupBackupSelection,
+            "AWS::Backup::BackupVault" => ResourceType::AwsBackupBackupVault,
+            "AWS::Backup::RecoveryPoint" => ResourceType::AwsBackupRecoveryPoint,
+            "AWS::ECR::Repository" => ResourceType::AwsEcrRepository,
+            "AWS::ECS::Cluster" => ResourceType::AwsEcsCluster,
+            "AWS::ECS::Service" => ResourceType::AwsEcsService,
+            "AWS::ECS::TaskDefinition" => ResourceType::AwsEcsTaskDefinition,
+            "AWS::EFS::AccessPoint" => ResourceType::AwsEfsAccessPoint,
+            "AWS::EFS::FileSystem" => ResourceType::AwsEfsFileSystem,
+            "AWS::EKS::Cluster" => ResourceType::AwsEksCluster,
+            "AWS::OpenSearch::Domain" => ResourceType::AwsOpenSearchDomain,
+            "AWS::EC2::TransitGateway" => ResourceType::AwsEc2TransitGateway,
+            "AWS::Kinesis::Stream" => ResourceType::AwsKinesisStream,
+            "AWS::Kinesis::StreamConsumer" => ResourceType::AwsKinesisStreamConsumer,
+            "AWS::CodeDeploy::Application" => ResourceType::AwsCodeDeployApplication,
+            "AWS::CodeDeploy::DeploymentConfig" => ResourceType::AwsCodeDeployDeploymentConfig,
+            "AWS::CodeDeploy::DeploymentGroup" => ResourceType::AwsCodeDeployDeploymentGroup,
+            "AWS::EC2::LaunchTemplate" => ResourceType::AwsEc2LaunchTemplate,
+            "AWS::ECR::PublicRepository" => ResourceType::AwsEcrPublicRepository,
+            "AWS::GuardDuty::Detector" => ResourceType::AwsGuardDutyDetector,
+            "AWS::EMR::SecurityConfiguration" => ResourceType::AwsEmrSecurityConfiguration,
+            "AWS::SageMaker::CodeRepository" => ResourceType::AwsSageMakerCodeRepository,
+            "AWS::Route53Resolver::ResolverEndpoint" => {
+                ResourceType::AwsRoute53ResolverResolverEndpoint
+            }
+            "AWS::Route53Resolver::ResolverRule" => ResourceType::AwsRoute53ResolverResolverRule,
+            "AWS::Route53Resolver::ResolverRuleAssociation" => {
+                ResourceType::AwsRoute53ResolverResolverRuleAssociation
+            }
+            "AWS::DMS::ReplicationSubnetGroup" => ResourceType::AwsDmsReplicationSubnetGroup,
+            "AWS::DMS::EventSubscription" => ResourceType::AwsDmsEventSubscription,
+            "AWS::MSK::Cluster" => ResourceType::AwsMskCluster,
+            "AWS::StepFunctions::Activity" => ResourceType::AwsStepFunctionsActivity,
+            "AWS::WorkSpaces::Workspace" => ResourceType::AwsWorkSpacesWorkspace,
+            "AWS::WorkSpaces::ConnectionAlias" => ResourceType::AwsWorkSpacesConnectionAlias,
+            "AWS::SageMaker::Model" => ResourceType::AwsSageMakerModel,
+            "AWS::ElasticLoadBalancingV2::Listener" => {
+                ResourceType::AwsElasticLoadBalancingV2Listener
+            }
+            "AWS::StepFunctions::StateMachine" => ResourceType::AwsStepFunctionsStateMachine,
+            "AWS::Batch::JobQueue" => ResourceType::AwsBatchJobQueue,
+            "AWS::Batch::ComputeEnvironment" => ResourceType::AwsBatchComputeEnvironment,
+            "AWS::AccessAnalyzer::Analyzer" => ResourceType::AwsAccessAnalyzerAnalyzer,
+            "AWS::Athena::WorkGroup" => ResourceType::AwsAthenaWorkGroup,
+            "AWS::Athena::DataCatalog" => ResourceType::AwsAthenaDataCatalog,
+            "AWS::Detective::Graph" => ResourceType::AwsDetectiveGraph,
+            "AWS::GlobalAccelerator::Accelerator" => ResourceType::AwsGlobalAcceleratorAccelerator,
+            "AWS::GlobalAccelerator::EndpointGroup" => {
+                ResourceType::AwsGlobalAcceleratorEndpointGroup
+            }
+            "AWS::GlobalAccelerator::Listener" => ResourceType::AwsGlobalAcceleratorListener,
+            "AWS::EC2::TransitGatewayAttachment" => ResourceType::AwsEc2TransitGatewayAttachment,
+            "AWS::EC2::TransitGatewayRouteTable" => ResourceType::AwsEc2TransitGatewayRouteTable,
+            "AWS::DMS::Certificate" => ResourceType::AwsDmsCertificate,
+            "AWS::AppConfig::Application" => ResourceType::AwsAppConfigApplication,
+            "AWS::AppSync::GraphQLApi" => ResourceType::AwsAppSyncGraphQLApi,
+            "AWS::DataSync::LocationSMB" => ResourceType::AwsDataSyncLocationSMB,
+            "AWS::DataSync::LocationFSxLustre" => ResourceType::AwsDataSyncLocationFSxLustre,
+            "AWS::DataSync::LocationS3" => ResourceType::AwsDataSyncLocationS3,
+            "AWS::DataSync::LocationEFS" => ResourceType::AwsDataSyncLocationEFS,
+            "AWS::DataSync::Task" => ResourceType::AwsDataSyncTask,
+            "AWS::DataSync::LocationNFS" => ResourceType::AwsDataSyncLocationNFS,
+            "AWS::EC2::NetworkInsightsAccessScopeAnalysis" => {
+                ResourceType::AwsEc2NetworkInsightsAccessScopeAnalysis
+            }
+            "AWS::EKS::FargateProfile" => ResourceType::AwsEksFargateProfile,
+            "AWS::Glue::Job" => ResourceType::AwsGlueJob,
+            "AWS::GuardDuty::ThreatIntelSet" => ResourceType::AwsGuardDutyThreatIntelSet,
+            "AWS::GuardDuty::IPSet" => ResourceType::AwsGuardDutyIPSet,
+            "AWS::SageMaker::Workteam" => ResourceType::AwsSageMakerWorkteam,
+            "AWS::SageMaker::NotebookInstanceLifecycleConfig" => {
+                ResourceType::AwsSageMakerNotebookInstanceLifecycleConfig
+            }
+            "AWS::ServiceDiscovery::Service" => ResourceType::AwsServiceDiscoveryService,
+            "AWS::ServiceDiscovery::PublicDnsNamespace" => {
+                ResourceType::AwsServiceDiscoveryPublicDnsNamespace
+            }
+            "AWS::SES::ContactList" => ResourceType::AwsSesContactList,
+            "AWS::SES::ConfigurationSet" => ResourceType::AwsSesConfigurationSet,
+            "AWS::Route53::HostedZone" => ResourceType::AwsRoute53HostedZone,
+            "AWS::IoTEvents::Input" => ResourceType::AwsIoTEventsInput,
+            "AWS::IoTEvents::DetectorModel" => ResourceType::AwsIoTEventsDetectorModel,
+            "AWS::IoTEvents::AlarmModel" => ResourceType::AwsIoTEventsAlarmModel,
+            "AWS::ServiceDiscovery::HttpNamespace" => {
+                ResourceType::AwsServiceDiscoveryHttpNamespace
+            }
+            "AWS::Events::EventBus" => ResourceType::AwsEventsEventBus,
+            "AWS::ImageBuilder::ContainerRecipe" => ResourceType::AwsImageBuilderContainerRecipe,
+            "AWS::ImageBuilder::DistributionConfiguration" => {
+                ResourceType::AwsImageBuilderDistributionConfiguration
+            }
+            "AWS::ImageBuilder::InfrastructureConfiguration" => {
+                ResourceType::AwsImageBuilderInfrastructureConfiguration
+            }
+            "AWS::DataSync::LocationObjectStorage" => {
+                ResourceType::AwsDataSyncLocationObjectStorage
+            }
+            "AWS::DataSync::LocationHDFS" => ResourceType::AwsDataSyncLocationHDFS,
+            "AWS::Glue::Classifier" => ResourceType::AwsGlueClassifier,
+            "AWS::Route53RecoveryReadiness::Cell" => ResourceType::AwsRoute53RecoveryReadinessCell,
+            "AWS::Route53RecoveryReadiness::ReadinessCheck" => {
+                ResourceType::AwsRoute53RecoveryReadinessReadinessCheck
+            }
+            "AWS::ECR::RegistryPolicy" => ResourceType::AwsEcrRegistryPolicy,
+            "AWS::Backup::ReportPlan" => ResourceType::AwsBackupReportPlan,
+            "AWS::Lightsail::Certificate" => ResourceType::AwsLightsailCertificate,
+            "AWS::RUM::AppMonitor" => ResourceType::AwsRumAppMonitor,
+            "AWS::Events::Endpoint" => ResourceType::AwsEventsEndpoint,
+            "AWS::SES::ReceiptRuleSet" => ResourceType::AwsSesReceiptRuleSet,
+            "AWS::Events::Archive" => ResourceType::AwsEventsArchive,
+            "AWS::Events::ApiDestination" => ResourceType::AwsEventsApiDestination,
+            "AWS::Lightsail::Disk" => ResourceType::AwsLightsailDisk,
+            "AWS::FIS::ExperimentTemplate" => ResourceType::AwsFisExperimentTemplate,
+            "AWS::DataSync::LocationFSxWindows" => ResourceType::AwsDataSyncLocationFSxWindows,
+            "AWS::SES::ReceiptFilter" => ResourceType::AwsSesReceiptFilter,
+            "AWS::GuardDuty::Filter" => ResourceType::AwsGuardDutyFilter,
+            "AWS::SES::Template" => ResourceType::AwsSesTemplate,
+            "AWS::AmazonMQ::Broker" => ResourceType::AwsAmazonMQBroker,
+            "AWS::AppConfig::Environment" => ResourceType::AwsAppConfigEnvironment,
+            "AWS::AppConfig::ConfigurationProfile" => {
+                ResourceType::AwsAppConfigConfigurationProfile
+            }
+            "AWS::Cloud9::EnvironmentEC2" => ResourceType::AwsCloud9EnvironmentEC2,
+            "AWS::EventSchemas::Registry" => ResourceType::AwsEventSchemasRegistry,
+            "AWS::EventSchemas::RegistryPolicy" => ResourceType::AwsEventSchemasRegistryPolicy,
+            "AWS::EventSchemas::Discoverer" => ResourceType::AwsEventSchemasDiscoverer,
+            "AWS::FraudDetector::Label" => ResourceType::AwsFraudDetectorLabel,
+            "AWS::FraudDetector::EntityType" => ResourceType::AwsFraudDetectorEntityType,
+            "AWS::FraudDetector::Variable" => ResourceType::AwsFraudDetectorVariable,
+            "AWS::FraudDetector::Outcome" => ResourceType::AwsFraudDetectorOutcome,
+            "AWS::IoT::Authorizer" => ResourceType::AwsIoTAuthorizer,
+            "AWS::IoT::SecurityProfile" => ResourceType::AwsIoTSecurityProfile,
+            "AWS::IoT::RoleAlias" => ResourceType::AwsIoTRoleAlias,
+            "AWS::IoT::Dimension" => ResourceType::AwsIoTDimension,
+            "AWS::IoTAnalytics::Datastore" => ResourceType::AwsIoTAnalyticsDatastore,
+            "AWS::Lightsail::Bucket" => ResourceType::AwsLightsailBucket,
+            "AWS::Lightsail::StaticIp" => ResourceType::AwsLightsailStaticIp,
+            "AWS::MediaPackage::PackagingGroup" => ResourceType::AwsMediaPackagePackagingGroup,
+            "AWS::Route53RecoveryReadiness::RecoveryGroup" => {
+                ResourceType::AwsRoute53RecoveryReadinessRecoveryGroup
+            }
+            "AWS::ResilienceHub::ResiliencyPolicy" => {
+                ResourceType::AwsResilienceHubResiliencyPolicy
+            }
+            "AWS::Transfer::Workflow" => ResourceType::AwsTransferWorkflow,
+            "AWS::EKS::IdentityProviderConfig" => ResourceType::AwsEksIdentityProviderConfig,
+            "AWS::EKS::Addon" => ResourceType::AwsEksAddon,
+            "AWS::Glue::MLTransform" => ResourceType::AwsGlueMLTransform,
+            "AWS::IoT::Policy" => ResourceType::AwsIoTPolicy,
+            "AWS::IoT::MitigationAction" => ResourceType::AwsIoTMitigationAction,
+            "AWS::IoTTwinMaker::Workspace" => ResourceType::AwsIoTTwinMakerWorkspace,
+            "AWS::IoTTwinMaker::Entity" => ResourceType::AwsIoTTwinMakerEntity,
+            "AWS::IoTAnalytics::Dataset" => ResourceType::AwsIoTAnalyticsDataset,
+            "AWS::IoTAnalytics::Pipeline" => ResourceType::AwsIoTAnalyticsPipeline,
+            "AWS::IoTAnalytics::Channel" => ResourceType::AwsIoTAnalyticsChannel,
+            "AWS::IoTSiteWise::Dashboard" => ResourceType::AwsIoTSiteWiseDashboard,
+            "AWS::IoTSiteWise::Project" => ResourceType::AwsIoTSiteWiseProject,
+            "AWS::IoTSiteWise::Portal" => ResourceType::AwsIoTSiteWisePortal,
+            "AWS::IoTSiteWise::AssetModel" => ResourceType::AwsIoTSiteWiseAssetModel,
+            "AWS::IVS::Channel" => ResourceType::AwsIvsChannel,
+            "AWS::IVS::RecordingConfiguration" => ResourceType::AwsIvsRecordingConfiguration,
+            "AWS::IVS::PlaybackKeyPair" => ResourceType::AwsIvsPlaybackKeyPair,
+            "AWS::KinesisAnalyticsV2::Application" => {
+                ResourceType::AwsKinesisAnalyticsV2Application
+            }
+            "AWS::RDS::GlobalCluster" => ResourceType::AwsRdsGlobalCluster,
+            "AWS::S3::MultiRegionAccessPoint" => ResourceType::AwsS3MultiRegionAccessPoint,
+            "AWS::DeviceFarm::TestGridProject" => ResourceType::AwsDeviceFarmTestGridProject,
+            "AWS::Budgets::BudgetsAction" => ResourceType::AwsBudgetsBudgetsAction,
+            "AWS::Lex::Bot" => ResourceType::AwsLexBot,
+            "AWS::CodeGuruReviewer::RepositoryAssociation" => {
+                ResourceType::AwsCodeGuruReviewerRepositoryAssociation
+            }
+            "AWS::IoT::CustomMetric" => ResourceType::AwsIoTCustomMetric,
+            "AWS::Route53Resolver::FirewallDomainList" => {
+                ResourceType::AwsRoute53ResolverFirewallDomainList
+            }
+            "AWS::RoboMaker::RobotApplicationVersion" => {
+                ResourceType::AwsRoboMakerRobotApplicationVersion
+            }
+            "AWS::EC2::TrafficMirrorSession" => ResourceType::AwsEc2TrafficMirrorSession,
+            "AWS::IoTSiteWise::Gateway" => ResourceType::AwsIoTSiteWiseGateway,
+            "AWS::Lex::BotAlias" => ResourceType::AwsLexBotAlias,
+            "AWS::LookoutMetrics::Alert" => ResourceType::AwsLookoutMetricsAlert,
+            "AWS::IoT::AccountAuditConfiguration" => ResourceType::AwsIoTAccountAuditConfiguration,
+            "AWS::EC2::TrafficMirrorTarget" => ResourceType::AwsEc2TrafficMirrorTarget,
+            "AWS::S3::StorageLens" => ResourceType::AwsS3StorageLens,
+            "AWS::IoT::ScheduledAudit" => ResourceType::AwsIoTScheduledAudit,
+            "AWS::Events::Connection" => ResourceType::AwsEventsConnection,
+            "AWS::EventSchemas::Schema" => ResourceType::AwsEventSchemasSchema,
+            "AWS::MediaPackage::PackagingConfiguration" => {
+                ResourceType::AwsMediaPackagePackagingConfiguration
+            }
+            "AWS::KinesisVideo::SignalingChannel" => ResourceType::AwsKinesisVideoSignalingChannel,
+            "AWS::AppStream::DirectoryConfig" => ResourceType::AwsAppStreamDirectoryConfig,
+            "AWS::LookoutVision::Project" => ResourceType::AwsLookoutVisionProject,
+            "AWS::Route53RecoveryControl::Cluster" => {
+                ResourceType::AwsRoute53RecoveryControlCluster
+            }
+            "AWS::Route53RecoveryControl::SafetyRule" => {
+                ResourceType::AwsRoute53RecoveryControlSafetyRule
+            }
+            "AWS::Route53RecoveryControl::ControlPanel" => {
+                ResourceType::AwsRoute53RecoveryControlControlPanel
+            }
+            "AWS::Route53RecoveryControl::RoutingControl" => {
+                ResourceType::AwsRoute53RecoveryControlRoutingControl
+            }
+            "AWS::Route53RecoveryReadiness::ResourceSet" => {
+                ResourceType::AwsRoute53RecoveryReadinessResourceSet
+            }
+            "AWS::RoboMaker::SimulationApplication" => {
+                ResourceType::AwsRoboMakerSimulationApplication
+            }
+            "AWS::RoboMaker::RobotApplication" => ResourceType::AwsRoboMakerRobotApplication,
+            "AWS::HealthLake::FHIRDatastore" => ResourceType::AwsHealthLakeFHIRDatastore,
+            "AWS::Pinpoint::Segment" => ResourceType::AwsPinpointSegment,
+            "AWS::Pinpoint::ApplicationSettings" => ResourceType::AwsPinpointApplicationSettings,
+            "AWS::Events::Rule" => ResourceType::AwsEventsRule,
+            "AWS::EC2::DHCPOptions" => ResourceType::AwsEc2DHCPOptions,
+            "AWS::EC2::NetworkInsightsPath" => ResourceType::AwsEc2NetworkInsightsPath,
+            "AWS::EC2::TrafficMirrorFilter" => ResourceType::AwsEc2TrafficMirrorFilter,
+            "AWS::EC2::IPAM" => ResourceType::AwsEc2Ipam,
+            "AWS::IoTTwinMaker::Scene" => ResourceType::AwsIoTTwinMakerScene,
+            "AWS::NetworkManager::TransitGatewayRegistration" => {
+                ResourceType::AwsNetworkManagerTransitGatewayRegistration
+            }
+            "AWS::CustomerProfiles::Domain" => ResourceType::AwsCustomerProfilesDomain,
+            "AWS::AutoScaling::WarmPool" => ResourceType::AwsAutoScalingWarmPool,
+            "AWS::Connect::PhoneNumber" => ResourceType::AwsConnectPhoneNumber,
+            "AWS::AppConfig::DeploymentStrategy" => ResourceType::AwsAppConfigDeploymentStrategy,
+            "AWS::AppFlow::Flow" => ResourceType::AwsAppFlowFlow,
+            "AWS::AuditManager::Assessment" => ResourceType::AwsAuditManagerAssessment,
+            "AWS::CloudWatch::MetricStream" => ResourceType::AwsCloudWatchMetricStream,
+            "AWS::DeviceFarm::InstanceProfile" => ResourceType::AwsDeviceFarmInstanceProfile,
+            "AWS::DeviceFarm::Project" => ResourceType::AwsDeviceFarmProject,
+            "AWS::EC2::EC2Fleet" => ResourceType::AwsEc2EC2Fleet,
+            "AWS::EC2::SubnetRouteTableAssociation" => {
+                ResourceType::AwsEc2SubnetRouteTableAssociation
+            }
+            "AWS::ECR::PullThroughCacheRule" => ResourceType::AwsEcrPullThroughCacheRule,
+            "AWS::GroundStation::Config" => ResourceType::AwsGroundStationConfig,
+            "AWS::ImageBuilder::ImagePipeline" => ResourceType::AwsImageBuilderImagePipeline,
+            "AWS::IoT::FleetMetric" => ResourceType::AwsIoTFleetMetric,
+            "AWS::IoTWireless::ServiceProfile" => ResourceType::AwsIoTWirelessServiceProfile,
+            "AWS::NetworkManager::Device" => ResourceType::AwsNetworkManagerDevice,
+            "AWS::NetworkManager::GlobalNetwork" => ResourceType::AwsNetworkManagerGlobalNetwork,
+            "AWS::NetworkManager::Link" => ResourceType::AwsNetworkManagerLink,
+            "AWS::NetworkManager::Site" => ResourceType::AwsNetworkManagerSite,
+            "AWS::Panorama::Package" => ResourceType::AwsPanoramaPackage,
+            "AWS::Pinpoint::App" => ResourceType::AwsPinpointApp,
+            "AWS::Redshift::ScheduledAction" => ResourceType::AwsRedshiftScheduledAction,
+            "AWS::Route53Resolver::FirewallRuleGroupAssociation" => {
+                ResourceType::AwsRoute53ResolverFirewallRuleGroupAssociation
+            }
+            "AWS::SageMaker::AppImageConfig" => ResourceType::AwsSageMakerAppImageConfig,
+            "AWS::SageMaker::Image" => ResourceType::AwsSageMakerImage,
+            "AWS::ECS::TaskSet" => ResourceType::AwsEcsTaskSet,
+            "AWS::Cassandra::Keyspace" => ResourceType::AwsCassandraKeyspace,
+            "AWS::Signer::SigningProfile" => ResourceType::AwsSignerSigningProfile,
+            "AWS::Amplify::App" => ResourceType::AwsAmplifyApp,
+            "AWS::AppMesh::VirtualNode" => ResourceType::AwsAppMeshVirtualNode,
+            "AWS::AppMesh::VirtualService" => ResourceType::AwsAppMeshVirtualService,
+            "AWS::AppRunner::VpcConnector" => ResourceType::AwsAppRunnerVpcConnector,
+            "AWS::AppStream::Application" => ResourceType::AwsAppStreamApplication,
+            "AWS::CodeArtifact::Repository" => ResourceType::AwsCodeArtifactRepository,
+            "AWS::EC2::PrefixList" => ResourceType::AwsEc2PrefixList,
+            "AWS::EC2::SpotFleet" => ResourceType::AwsEc2SpotFleet,
+            "AWS::Evidently::Project" => ResourceType::AwsEvidentlyProject,
+            "AWS::Forecast::Dataset" => ResourceType::AwsForecastDataset,
+            "AWS::IAM::SAMLProvider" => ResourceType::AwsIamSAMLProvider,
+            "AWS::IAM::ServerCertificate" => ResourceType::AwsIamServerCertificate,
+            "AWS::Pinpoint::Campaign" => ResourceType::AwsPinpointCampaign,
+            "AWS::Pinpoint::InAppTemplate" => ResourceType::AwsPinpointInAppTemplate,
+            "AWS::SageMaker::Domain" => ResourceType::AwsSageMakerDomain,
+            "AWS::Transfer::Agreement" => ResourceType::AwsTransferAgreement,
+            "AWS::Transfer::Connector" => ResourceType::AwsTransferConnector,
+            "AWS::KinesisFirehose::DeliveryStream" => {
+                ResourceType::AwsKinesisFirehoseDeliveryStream
+            }
+            "AWS::Amplify::Branch" => ResourceType::AwsAmplifyBranch,
+            "AWS::AppIntegrations::EventIntegration" => {
+                ResourceType::AwsAppIntegrationsEventIntegration
+            }
+            "AWS::AppMesh::Route" => ResourceType::AwsAppMeshRoute,
+            "AWS::Athena::PreparedStatement" => ResourceType::AwsAthenaPreparedStatement,
+            "AWS::EC2::IPAMScope" => ResourceType::AwsEc2IPAMScope,
+            "AWS::Evidently::Launch" => ResourceType::AwsEvidentlyLaunch,
+            "AWS::Forecast::DatasetGroup" => ResourceType::AwsForecastDatasetGroup,
+            "AWS::GreengrassV2::ComponentVersion" => ResourceType::AwsGreengrassV2ComponentVersion,
+            "AWS::GroundStation::MissionProfile" => ResourceType::AwsGroundStationMissionProfile,
+            "AWS::MediaConnect::FlowEntitlement" => ResourceType::AwsMediaConnectFlowEntitlement,
+            "AWS::MediaConnect::FlowVpcInterface" => ResourceType::AwsMediaConnectFlowVpcInterface,
+            "AWS::MediaTailor::PlaybackConfiguration" => {
+                ResourceType::AwsMediaTailorPlaybackConfiguration
+            }
+            "AWS::MSK::Configuration" => ResourceType::AwsMskConfiguration,
+            "AWS::Personalize::Dataset" => ResourceType::AwsPersonalizeDataset,
+            "AWS::Personalize::Schema" => ResourceType::AwsPersonalizeSchema,
+            "AWS::Personalize::Solution" => ResourceType::AwsPersonalizeSolution,
+            "AWS::Pinpoint::EmailTemplate" => ResourceType::AwsPinpointEmailTemplate,
+            "AWS::Pinpoint::EventStream" => ResourceType::AwsPinpointEventStream,
+            "AWS::ResilienceHub::App" => ResourceType::AwsResilienceHubApp,
+            "AWS::ACMPCA::CertificateAuthority" => ResourceType::AwsAcmpcaCertificateAuthority,
+            "AWS::AppConfig::HostedConfigurationVersion" => {
+                ResourceType::AwsAppConfigHostedConfigurationVersion
+            }
+            "AWS::AppMesh::VirtualGateway" => ResourceType::AwsAppMeshVirtualGateway,
+            "AWS::AppMesh::VirtualRouter" => ResourceType::AwsAppMeshVirtualRouter,
+            "AWS::AppRunner::Service" => ResourceType::AwsAppRunnerService,
+            "AWS::CustomerProfiles::ObjectType" => ResourceType::AwsCustomerProfilesObjectType,
+            "AWS::DMS::Endpoint" => ResourceType::AwsDmsEndpoint,
+            "AWS::EC2::CapacityReservation" => ResourceType::AwsEc2CapacityReservation,
+            "AWS::EC2::ClientVpnEndpoint" => ResourceType::AwsEc2ClientVpnEndpoint,
+            "AWS::Kendra::Index" => ResourceType::AwsKendraIndex,
+            "AWS::KinesisVideo::Stream" => ResourceType::AwsKinesisVideoStream,
+            "AWS::Logs::Destination" => ResourceType::AwsLogsDestination,
+            "AWS::Pinpoint::EmailChannel" => ResourceType::AwsPinpointEmailChannel,
+            "AWS::S3::AccessPoint" => ResourceType::AwsS3AccessPoint,
+            "AWS::NetworkManager::CustomerGatewayAssociation" => {
+                ResourceType::AwsNetworkManagerCustomerGatewayAssociation
+            }
+            "AWS::NetworkManager::LinkAssociation" => {
+                ResourceType::AwsNetworkManagerLinkAssociation
+            }
+            "AWS::IoTWireless::MulticastGroup" => ResourceType::AwsIoTWirelessMulticastGroup,
+            "AWS::Personalize::DatasetGroup" => ResourceType::AwsPersonalizeDatasetGroup,
+            "AWS::IoTTwinMaker::ComponentType" => ResourceType::AwsIoTTwinMakerComponentType,
+            "AWS::CodeBuild::ReportGroup" => ResourceType::AwsCodeBuildReportGroup,
+            "AWS::SageMaker::FeatureGroup" => ResourceType::AwsSageMakerFeatureGroup,
+            "AWS::MSK::BatchScramSecret" => ResourceType::AwsMskBatchScramSecret,
+            "AWS::AppStream::Stack" => ResourceType::AwsAppStreamStack,
+            "AWS::IoT::JobTemplate" => ResourceType::AwsIoTJobTemplate,
+            "AWS::IoTWireless::FuotaTask" => ResourceType::AwsIoTWirelessFuotaTask,
+            "AWS::IoT::ProvisioningTemplate" => ResourceType::AwsIoTProvisioningTemplate,
+            "AWS::InspectorV2::Filter" => ResourceType::AwsInspectorV2Filter,
+            "AWS::Route53Resolver::ResolverQueryLoggingConfigAssociation" => {
+                ResourceType::AwsRoute53ResolverResolverQueryLoggingConfigAssociation
+            }
+            "AWS::ServiceDiscovery::Instance" => ResourceType::AwsServiceDiscoveryInstance,
+            "AWS::Transfer::Certificate" => ResourceType::AwsTransferCertificate,
+            "AWS::MediaConnect::FlowSource" => ResourceType::AwsMediaConnectFlowSource,
+            "AWS::APS::RuleGroupsNamespace" => ResourceType::AwsApsRuleGroupsNamespace,
+            "AWS::CodeGuruProfiler::ProfilingGroup" => {
+                ResourceType::AwsCodeGuruProfilerProfilingGroup
+            }
+            "AWS::Route53Resolver::ResolverQueryLoggingConfig" => {
+                ResourceType::AwsRoute53ResolverResolverQueryLoggingConfig
+            }
+            "AWS::Batch::SchedulingPolicy" => ResourceType::AwsBatchSchedulingPolicy,
+            "AWS::ACMPCA::CertificateAuthorityActivation" => {
+                ResourceType::AwsAcmpcaCertificateAuthorityActivation
+            }
+            "AWS::AppMesh::GatewayRoute" => ResourceType::AwsAppMeshGatewayRoute,
+            "AWS::AppMesh::Mesh" => ResourceType::AwsAppMeshMesh,
+            "AWS::Connect::Instance" => ResourceType::AwsConnectInstance,
+            "AWS::Connect::QuickConnect" => ResourceType::AwsConnectQuickConnect,
+            "AWS::EC2::CarrierGateway" => ResourceType::AwsEc2CarrierGateway,
+            "AWS::EC2::IPAMPool" => ResourceType::AwsEc2IPAMPool,
+            "AWS::EC2::TransitGatewayConnect" => ResourceType::AwsEc2TransitGatewayConnect,
+            "AWS::EC2::TransitGatewayMulticastDomain" => {
+                ResourceType::AwsEc2TransitGatewayMulticastDomain
+            }
+            "AWS::ECS::CapacityProvider" => ResourceType::AwsEcsCapacityProvider,
+            "AWS::IAM::InstanceProfile" => ResourceType::AwsIamInstanceProfile,
+            "AWS::IoT::CACertificate" => ResourceType::AwsIoTCACertificate,
+            "AWS::IoTTwinMaker::SyncJob" => ResourceType::AwsIoTTwinMakerSyncJob,
+            "AWS::KafkaConnect::Connector" => ResourceType::AwsKafkaConnectConnector,
+            "AWS::Lambda::CodeSigningConfig" => ResourceType::AwsLambdaCodeSigningConfig,
+            "AWS::NetworkManager::ConnectPeer" => ResourceType::AwsNetworkManagerConnectPeer,
+            "AWS::ResourceExplorer2::Index" => ResourceType::AwsResourceExplorer2Index,
+            "AWS::AppStream::Fleet" => ResourceType::AwsAppStreamFleet,
+            "AWS::Cognito::UserPool" => ResourceType::AwsCognitoUserPool,
+            "AWS::Cognito::UserPoolClient" => ResourceType::AwsCognitoUserPoolClient,
+            "AWS::Cognito::UserPoolGroup" => ResourceType::AwsCognitoUserPoolGroup,
+            "AWS::EC2::NetworkInsightsAccessScope" => {
+                ResourceType::AwsEc2NetworkInsightsAccessScope
+            }
+            "AWS::EC2::NetworkInsightsAnalysis" => ResourceType::AwsEc2NetworkInsightsAnalysis,
+            "AWS::Grafana::Workspace" => ResourceType::AwsGrafanaWorkspace,
+            "AWS::GroundStation::DataflowEndpointGroup" => {
+                ResourceType::AwsGroundStationDataflowEndpointGroup
+            }
+            "AWS::ImageBuilder::ImageRecipe" => ResourceType::AwsImageBuilderImageRecipe,
+            "AWS::KMS::Alias" => ResourceType::AwsKmsAlias,
+            "AWS::M2::Environment" => ResourceType::AwsM2Environment,
+            "AWS::QuickSight::DataSource" => ResourceType::AwsQuickSightDataSource,
+            "AWS::QuickSight::Template" => ResourceType::AwsQuickSightTemplate,
+            "AWS::QuickSight::Theme" => ResourceType::AwsQuickSightTheme,
+            "AWS::RDS::OptionGroup" => ResourceType::AwsRdsOptionGroup,
+            "AWS::Redshift::EndpointAccess" => ResourceType::AwsRedshiftEndpointAccess,
+            "AWS::Route53Resolver::FirewallRuleGroup" => {
+                ResourceType::AwsRoute53ResolverFirewallRuleGroup
+            }
+            "AWS::SSM::Document" => ResourceType::AwsSsmDocument,
+            _ => ResourceType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ResourceType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ResourceType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ResourceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ResourceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ResourceType {
+    fn deserialize<D>(deserializer: D) -> Result<ResourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>The dynamic value of the resource.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResourceValue {
+    /// <p>The value is a resource ID.</p>
+    #[serde(rename = "Value")]
+    pub value: ResourceValueType,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ResourceValueType {
+    /// `RESOURCE_ID`
+    ResourceId,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ResourceValueType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceValueType::ResourceId => "RESOURCE_ID",
+            ResourceValueType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ResourceValueType {
+    fn default() -> ResourceValueType {
+        ResourceValueType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ResourceValueType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ResourceValueType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ResourceValueType {
+    fn from(value: &'a str) -> ResourceValueType {
+        match value {
+            "RESOURCE_ID" => ResourceValueType::ResourceId,
+            _ => ResourceValueType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceValueType {
+    fn from(value: String) -> ResourceValueType {
+        match value.as_str() {
+            "RESOURCE_ID" => ResourceValueType::ResourceId,
+            _ => ResourceValueType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ResourceValueType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ResourceValueType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ResourceValueType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ResourceValueType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ResourceValueType {
+    fn deserialize<D>(deserializer: D) -> Result<ResourceValueType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>An object with the name of the retention configuration and the retention period in days. The object stores the configuration for data retention in AWS Config.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct RetentionConfiguration {
+    /// <p>The name of the retention configuration object.</p>
+    #[serde(rename = "Name")]
+    pub name: String,
+    /// <p><p>Number of days AWS Config stores your historical information.</p> <note> <p>Currently, only applicable to the configuration item history.</p> </note></p>
+    #[serde(rename = "RetentionPeriodInDays")]
+    pub retention_period_in_days: i64,
+}
+
+/// <p>Defines which resources trigger an evaluation for an AWS Config rule. The scope can include one or more resource types, a combination of a tag key and value, or a combination of one resource type and one resource ID. Specify a scope to constrain which resources trigger an evaluation for a rule. Otherwise, evaluations for the rule are triggered when any resource in your recording group changes in configuration.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Scope {
+    /// <p>The ID of the only AWS resource that you want to trigger an evaluation for the rule. If you specify a resource ID, you must specify one resource type for <code>ComplianceResourceTypes</code>.</p>
+    #[serde(rename = "ComplianceResourceId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compliance_resource_id: Option<String>,
+    /// <p>The resource types of only those AWS resources that you want to trigger an evaluation for the rule. You can only specify one type if you also specify a resource ID for <code>ComplianceResourceId</code>.</p>
+    #[serde(rename = "ComplianceResourceTypes")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compliance_resource_types: Option<Vec<String>>,
+    /// <p>The tag key that is applied to only those AWS resources that you want to trigger an evaluation for the rule.</p>
+    #[serde(rename = "TagKey")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag_key: Option<String>,
+    /// <p>The tag value applied to only those AWS resources that you want to trigger an evaluation for the rule. If you specify a value for <code>TagValue</code>, you must also specify a value for <code>TagKey</code>.</p>
+    #[serde(rename = "TagValue")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag_value: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct SelectResourceConfigRequest {
+    /// <p>The SQL query <code>SELECT</code> command.</p>
+    #[serde(rename = "Expression")]
+    pub expression: String,
+    /// <p>The maximum number of query results returned on each page. </p>
+    #[serde(rename = "Limit")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    /// <p>The <code>nextToken</code> string returned in a previous request that you use to request the next page of results in a paginated response. </p>
+    #[serde(rename = "NextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct SelectResourceConfigResponse {
+    /// <p>The <code>nextToken</code> string returned in a previous request that you use to request the next page of results in a paginated response. </p>
+    #[serde(rename = "NextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+    /// <p>Returns the <code>QueryInfo</code> object.</p>
+    #[serde(rename = "QueryInfo")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_info: Option<QueryInfo>,
+    /// <p>Returns the results for the SQL query.</p>
+    #[serde(rename = "Results")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub results: Option<Vec<String>>,
+}
+
+/// <p>Provides the AWS Config rule owner (AWS or customer), the rule identifier, and the events that trigger the evaluation of your AWS resources.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Source {
+    /// <p>Indicates whether AWS or the customer owns and manages the AWS Config rule.</p>
+    #[serde(rename = "Owner")]
+    pub owner: Owner,
+    /// <p>Provides the source and type of the event that causes AWS Config to evaluate your AWS resources.</p>
+    #[serde(rename = "SourceDetails")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_details: Option<Vec<SourceDetail>>,
+    /// <p>For AWS Config managed rules, a predefined identifier from a list. For example, <code>IAM_PASSWORD_POLICY</code> is a managed rule. To reference a managed rule, see <a href="https://docs.aws.amazon.com/config/latest/developerguide/evaluate-config_use-managed-rules.html">Using AWS Managed Config Rules</a>.</p> <p>For custom rules, the identifier is the Amazon Resource Name (ARN) of the rule's AWS Lambda function, such as <code>arn:aws:lambda:us-east-2:123456789012:function:custom_rule_name</code>.</p>
+    #[serde(rename = "SourceIdentifier")]
+    pub source_identifier: String,
+}
+
+/// <p>Provides the source and the message types that trigger AWS Config to evaluate your AWS resources against a rule. It also provides the frequency with which you want AWS Config to run evaluations for the rule if the trigger type is periodic. You can specify the parameter values for <code>SourceDetail</code> only for custom rules. </p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SourceDetail {
+    /// <p>The source of the event, such as an AWS service, that triggers AWS Config to evaluate your AWS resources.</p>
+    #[serde(rename = "EventSource")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_source: Option<EventSource>,
+    /// <p><p>The frequency at which you want AWS Config to run evaluations for a custom rule with a periodic trigger. If you specify a value for <code>MaximumExecutionFrequency</code>, then <code>MessageType</code> must use the <code>ScheduledNotification</code> value.</p> <note> <p>By default, rules with a periodic trigger are evaluated every 24 hours. To change the frequency, specify a valid value for the <code>MaximumExecutionFrequency</code> parameter.</p> <p>Based on the valid value you choose, AWS Config runs evaluations once for each valid value. For example, if you choose <code>Three<em>Hours</code>, AWS Config runs evaluations once every three hours. In this case, <code>Three</em>Hours</code> is the frequency of this rule. </p> </note></p>
+    #[serde(rename = "MaximumExecutionFrequency")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maximum_execution_frequency: Option<MaximumExecutionFrequency>,
+    /// <p>The type of notification that triggers AWS Config to run an evaluation for a rule. You can specify the following notification types:</p> <ul> <li> <p> <code>ConfigurationItemChangeNotification</code> - Triggers an evaluation when AWS Config delivers a configuration item as a result of a resource change.</p> </li> <li> <p> <code>OversizedConfigurationItemChangeNotification</code> - Triggers an evaluation when AWS Config delivers an oversized configuration item. AWS Config may generate this notification type when a resource changes and the notification exceeds the maximum size allowed by Amazon SNS.</p> </li> <li> <p> <code>ScheduledNotification</code> - Triggers a periodic evaluation at the frequency specified for <code>MaximumExecutionFrequency</code>.</p> </li> <li> <p> <code>ConfigurationSnapshotDeliveryCompleted</code> - Triggers a periodic evaluation when AWS Config delivers a configuration snapshot.</p> </li> </ul> <p>If you want your custom rule to be triggered by configuration changes, specify two SourceDetail objects, one for <code>ConfigurationItemChangeNotification</code> and one for <code>OversizedConfigurationItemChangeNotification</code>.</p>
+    #[serde(rename = "MessageType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_type: Option<MessageType>,
+}
+
+/// <p>AWS Systems Manager (SSM) specific remediation controls.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SsmControls {
+    /// <p>The maximum percentage of remediation actions allowed to run in parallel on the non-compliant resources for that specific rule. You can specify a percentage, such as 10%. The default value is 10. </p>
     #[serde(rename = "ConcurrentExecutionRatePercentage")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub concurrent_execution_rate_percentage: Option<i64>,
@@ -3575,7 +8851,7 @@ pub struct StatusDetailFilters {
     /// <p><p>Indicates deployment status for config rule in the member account. When master account calls <code>PutOrganizationConfigRule</code> action for the first time, config rule status is created in the member account. When master account calls <code>PutOrganizationConfigRule</code> action for the second time, config rule status is updated in the member account. Config rule status is deleted when the master account deletes <code>OrganizationConfigRule</code> and disables service access for <code>config-multiaccountsetup.amazonaws.com</code>. </p> <p>AWS Config sets the state of the rule to:</p> <ul> <li> <p> <code>CREATE<em>SUCCESSFUL</code> when config rule has been created in the member account.</p> </li> <li> <p> <code>CREATE</em>IN<em>PROGRESS</code> when config rule is being created in the member account.</p> </li> <li> <p> <code>CREATE</em>FAILED</code> when config rule creation has failed in the member account.</p> </li> <li> <p> <code>DELETE<em>FAILED</code> when config rule deletion has failed in the member account.</p> </li> <li> <p> <code>DELETE</em>IN<em>PROGRESS</code> when config rule is being deleted in the member account.</p> </li> <li> <p> <code>DELETE</em>SUCCESSFUL</code> when config rule has been deleted in the member account.</p> </li> <li> <p> <code>UPDATE<em>SUCCESSFUL</code> when config rule has been updated in the member account.</p> </li> <li> <p> <code>UPDATE</em>IN<em>PROGRESS</code> when config rule is being updated in the member account.</p> </li> <li> <p> <code>UPDATE</em>FAILED</code> when config rule deletion has failed in the member account.</p> </li> </ul></p>
     #[serde(rename = "MemberAccountRuleStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub member_account_rule_status: Option<String>,
+    pub member_account_rule_status: Option<MemberAccountRuleStatus>,
 }
 
 /// <p>The input for the <a>StopConfigurationRecorder</a> action.</p>
@@ -8093,11 +13369,12 @@ pub struct ConfigServiceClient {
 impl ConfigServiceClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> ConfigServiceClient {
         ConfigServiceClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Config Service"),
         }
     }
 
@@ -8132,6 +13409,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<BatchGetAggregateResourceConfigError>,
     > {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("BatchGetAggregateResourceConfig");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8165,6 +13443,7 @@ impl ConfigService for ConfigServiceClient {
         input: BatchGetResourceConfigRequest,
     ) -> Result<BatchGetResourceConfigResponse, RusotoError<BatchGetResourceConfigError>> {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("BatchGetResourceConfig");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StarlingDoveService.BatchGetResourceConfig");
@@ -8193,6 +13472,7 @@ impl ConfigService for ConfigServiceClient {
         input: DeleteAggregationAuthorizationRequest,
     ) -> Result<(), RusotoError<DeleteAggregationAuthorizationError>> {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DeleteAggregationAuthorization");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8223,6 +13503,7 @@ impl ConfigService for ConfigServiceClient {
         input: DeleteConfigRuleRequest,
     ) -> Result<(), RusotoError<DeleteConfigRuleError>> {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DeleteConfigRule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StarlingDoveService.DeleteConfigRule");
@@ -8250,6 +13531,7 @@ impl ConfigService for ConfigServiceClient {
         input: DeleteConfigurationAggregatorRequest,
     ) -> Result<(), RusotoError<DeleteConfigurationAggregatorError>> {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DeleteConfigurationAggregator");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8280,6 +13562,7 @@ impl ConfigService for ConfigServiceClient {
         input: DeleteConfigurationRecorderRequest,
     ) -> Result<(), RusotoError<DeleteConfigurationRecorderError>> {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DeleteConfigurationRecorder");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8310,6 +13593,7 @@ impl ConfigService for ConfigServiceClient {
         input: DeleteConformancePackRequest,
     ) -> Result<(), RusotoError<DeleteConformancePackError>> {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DeleteConformancePack");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StarlingDoveService.DeleteConformancePack");
@@ -8337,6 +13621,7 @@ impl ConfigService for ConfigServiceClient {
         input: DeleteDeliveryChannelRequest,
     ) -> Result<(), RusotoError<DeleteDeliveryChannelError>> {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DeleteDeliveryChannel");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StarlingDoveService.DeleteDeliveryChannel");
@@ -8364,6 +13649,7 @@ impl ConfigService for ConfigServiceClient {
         input: DeleteEvaluationResultsRequest,
     ) -> Result<DeleteEvaluationResultsResponse, RusotoError<DeleteEvaluationResultsError>> {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DeleteEvaluationResults");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8395,6 +13681,7 @@ impl ConfigService for ConfigServiceClient {
         input: DeleteOrganizationConfigRuleRequest,
     ) -> Result<(), RusotoError<DeleteOrganizationConfigRuleError>> {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DeleteOrganizationConfigRule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8425,6 +13712,7 @@ impl ConfigService for ConfigServiceClient {
         input: DeleteOrganizationConformancePackRequest,
     ) -> Result<(), RusotoError<DeleteOrganizationConformancePackError>> {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DeleteOrganizationConformancePack");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8457,6 +13745,7 @@ impl ConfigService for ConfigServiceClient {
         input: DeletePendingAggregationRequestRequest,
     ) -> Result<(), RusotoError<DeletePendingAggregationRequestError>> {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DeletePendingAggregationRequest");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8492,6 +13781,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<DeleteRemediationConfigurationError>,
     > {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DeleteRemediationConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8524,6 +13814,7 @@ impl ConfigService for ConfigServiceClient {
     ) -> Result<DeleteRemediationExceptionsResponse, RusotoError<DeleteRemediationExceptionsError>>
     {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DeleteRemediationExceptions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8555,6 +13846,7 @@ impl ConfigService for ConfigServiceClient {
         input: DeleteResourceConfigRequest,
     ) -> Result<(), RusotoError<DeleteResourceConfigError>> {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DeleteResourceConfig");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StarlingDoveService.DeleteResourceConfig");
@@ -8582,6 +13874,7 @@ impl ConfigService for ConfigServiceClient {
         input: DeleteRetentionConfigurationRequest,
     ) -> Result<(), RusotoError<DeleteRetentionConfigurationError>> {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DeleteRetentionConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8612,6 +13905,7 @@ impl ConfigService for ConfigServiceClient {
         input: DeliverConfigSnapshotRequest,
     ) -> Result<DeliverConfigSnapshotResponse, RusotoError<DeliverConfigSnapshotError>> {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DeliverConfigSnapshot");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StarlingDoveService.DeliverConfigSnapshot");
@@ -8643,6 +13937,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<DescribeAggregateComplianceByConfigRulesError>,
     > {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeAggregateComplianceByConfigRules");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8677,6 +13972,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<DescribeAggregationAuthorizationsError>,
     > {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeAggregationAuthorizations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8713,6 +14009,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<DescribeComplianceByConfigRuleError>,
     > {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeComplianceByConfigRule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8745,6 +14042,7 @@ impl ConfigService for ConfigServiceClient {
     ) -> Result<DescribeComplianceByResourceResponse, RusotoError<DescribeComplianceByResourceError>>
     {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeComplianceByResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8779,6 +14077,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<DescribeConfigRuleEvaluationStatusError>,
     > {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeConfigRuleEvaluationStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8812,6 +14111,7 @@ impl ConfigService for ConfigServiceClient {
         input: DescribeConfigRulesRequest,
     ) -> Result<DescribeConfigRulesResponse, RusotoError<DescribeConfigRulesError>> {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeConfigRules");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StarlingDoveService.DescribeConfigRules");
@@ -8843,6 +14143,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<DescribeConfigurationAggregatorSourcesStatusError>,
     > {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeConfigurationAggregatorSourcesStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8877,6 +14178,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<DescribeConfigurationAggregatorsError>,
     > {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeConfigurationAggregators");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8913,6 +14215,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<DescribeConfigurationRecorderStatusError>,
     > {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeConfigurationRecorderStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8949,6 +14252,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<DescribeConfigurationRecordersError>,
     > {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeConfigurationRecorders");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8983,6 +14287,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<DescribeConformancePackComplianceError>,
     > {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeConformancePackCompliance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9019,6 +14324,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<DescribeConformancePackStatusError>,
     > {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeConformancePackStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9050,6 +14356,7 @@ impl ConfigService for ConfigServiceClient {
         input: DescribeConformancePacksRequest,
     ) -> Result<DescribeConformancePacksResponse, RusotoError<DescribeConformancePacksError>> {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeConformancePacks");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9084,6 +14391,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<DescribeDeliveryChannelStatusError>,
     > {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeDeliveryChannelStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9115,6 +14423,7 @@ impl ConfigService for ConfigServiceClient {
         input: DescribeDeliveryChannelsRequest,
     ) -> Result<DescribeDeliveryChannelsResponse, RusotoError<DescribeDeliveryChannelsError>> {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeDeliveryChannels");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9149,6 +14458,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<DescribeOrganizationConfigRuleStatusesError>,
     > {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeOrganizationConfigRuleStatuses");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9185,6 +14495,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<DescribeOrganizationConfigRulesError>,
     > {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeOrganizationConfigRules");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9221,6 +14532,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<DescribeOrganizationConformancePackStatusesError>,
     > {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeOrganizationConformancePackStatuses");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9255,6 +14567,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<DescribeOrganizationConformancePacksError>,
     > {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeOrganizationConformancePacks");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9291,6 +14604,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<DescribePendingAggregationRequestsError>,
     > {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribePendingAggregationRequests");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9327,6 +14641,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<DescribeRemediationConfigurationsError>,
     > {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeRemediationConfigurations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9363,6 +14678,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<DescribeRemediationExceptionsError>,
     > {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeRemediationExceptions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9397,6 +14713,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<DescribeRemediationExecutionStatusError>,
     > {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeRemediationExecutionStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9433,6 +14750,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<DescribeRetentionConfigurationsError>,
     > {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeRetentionConfigurations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9469,6 +14787,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<GetAggregateComplianceDetailsByConfigRuleError>,
     > {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("GetAggregateComplianceDetailsByConfigRule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9503,6 +14822,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<GetAggregateConfigRuleComplianceSummaryError>,
     > {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("GetAggregateConfigRuleComplianceSummary");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9539,6 +14859,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<GetAggregateDiscoveredResourceCountsError>,
     > {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("GetAggregateDiscoveredResourceCounts");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9573,6 +14894,7 @@ impl ConfigService for ConfigServiceClient {
     ) -> Result<GetAggregateResourceConfigResponse, RusotoError<GetAggregateResourceConfigError>>
     {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("GetAggregateResourceConfig");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9607,6 +14929,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<GetComplianceDetailsByConfigRuleError>,
     > {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("GetComplianceDetailsByConfigRule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9643,6 +14966,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<GetComplianceDetailsByResourceError>,
     > {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("GetComplianceDetailsByResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9676,6 +15000,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<GetComplianceSummaryByConfigRuleError>,
     > {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("GetComplianceSummaryByConfigRule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9711,6 +15036,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<GetComplianceSummaryByResourceTypeError>,
     > {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("GetComplianceSummaryByResourceType");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9747,6 +15073,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<GetConformancePackComplianceDetailsError>,
     > {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("GetConformancePackComplianceDetails");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9783,6 +15110,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<GetConformancePackComplianceSummaryError>,
     > {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("GetConformancePackComplianceSummary");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9817,6 +15145,7 @@ impl ConfigService for ConfigServiceClient {
     ) -> Result<GetDiscoveredResourceCountsResponse, RusotoError<GetDiscoveredResourceCountsError>>
     {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("GetDiscoveredResourceCounts");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9851,6 +15180,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<GetOrganizationConfigRuleDetailedStatusError>,
     > {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("GetOrganizationConfigRuleDetailedStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9887,6 +15217,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<GetOrganizationConformancePackDetailedStatusError>,
     > {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("GetOrganizationConformancePackDetailedStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9918,6 +15249,7 @@ impl ConfigService for ConfigServiceClient {
         input: GetResourceConfigHistoryRequest,
     ) -> Result<GetResourceConfigHistoryResponse, RusotoError<GetResourceConfigHistoryError>> {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("GetResourceConfigHistory");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9952,6 +15284,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<ListAggregateDiscoveredResourcesError>,
     > {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("ListAggregateDiscoveredResources");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9985,6 +15318,7 @@ impl ConfigService for ConfigServiceClient {
         input: ListDiscoveredResourcesRequest,
     ) -> Result<ListDiscoveredResourcesResponse, RusotoError<ListDiscoveredResourcesError>> {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("ListDiscoveredResources");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -10016,6 +15350,7 @@ impl ConfigService for ConfigServiceClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StarlingDoveService.ListTagsForResource");
@@ -10045,6 +15380,7 @@ impl ConfigService for ConfigServiceClient {
     ) -> Result<PutAggregationAuthorizationResponse, RusotoError<PutAggregationAuthorizationError>>
     {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("PutAggregationAuthorization");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -10076,6 +15412,7 @@ impl ConfigService for ConfigServiceClient {
         input: PutConfigRuleRequest,
     ) -> Result<(), RusotoError<PutConfigRuleError>> {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("PutConfigRule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StarlingDoveService.PutConfigRule");
@@ -10104,6 +15441,7 @@ impl ConfigService for ConfigServiceClient {
     ) -> Result<PutConfigurationAggregatorResponse, RusotoError<PutConfigurationAggregatorError>>
     {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("PutConfigurationAggregator");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -10135,6 +15473,7 @@ impl ConfigService for ConfigServiceClient {
         input: PutConfigurationRecorderRequest,
     ) -> Result<(), RusotoError<PutConfigurationRecorderError>> {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("PutConfigurationRecorder");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -10165,6 +15504,7 @@ impl ConfigService for ConfigServiceClient {
         input: PutConformancePackRequest,
     ) -> Result<PutConformancePackResponse, RusotoError<PutConformancePackError>> {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("PutConformancePack");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StarlingDoveService.PutConformancePack");
@@ -10193,6 +15533,7 @@ impl ConfigService for ConfigServiceClient {
         input: PutDeliveryChannelRequest,
     ) -> Result<(), RusotoError<PutDeliveryChannelError>> {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("PutDeliveryChannel");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StarlingDoveService.PutDeliveryChannel");
@@ -10220,6 +15561,7 @@ impl ConfigService for ConfigServiceClient {
         input: PutEvaluationsRequest,
     ) -> Result<PutEvaluationsResponse, RusotoError<PutEvaluationsError>> {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("PutEvaluations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StarlingDoveService.PutEvaluations");
@@ -10248,6 +15590,7 @@ impl ConfigService for ConfigServiceClient {
     ) -> Result<PutOrganizationConfigRuleResponse, RusotoError<PutOrganizationConfigRuleError>>
     {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("PutOrganizationConfigRule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -10282,11 +15625,232 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<PutOrganizationConformancePackError>,
     > {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("PutOrganizationConformancePack");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StarlingDoveService.PutOrganizationConformancePack",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        if response.status.is_success() {
+            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutOrganizationConformancePackResponse, _>()
+        } else {
+            let try_response = response.buffer().await;
+            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            Err(PutOrganizationConformancePackError::from_response(response))
+        }
+    }
+
+    /// <p>Adds or updates the remediation configuration with a specific AWS Config rule with the selected target or action. The API creates the <code>RemediationConfiguration</code> object for the AWS Config rule. The AWS Config rule must already exist for you to add a remediation configuration. The target (SSM document) must exist and have permissions to use the target. </p>
+    async fn put_remediation_configurations(
+        &self,
+        input: PutRemediationConfigurationsRequest,
+    ) -> Result<PutRemediationConfigurationsResponse, RusotoError<PutRemediationConfigurationsError>>
+    {
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("PutRemediationConfigurations");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StarlingDoveService.PutRemediationConfigurations",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        if response.status.is_success() {
+            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutRemediationConfigurationsResponse, _>()
+        } else {
+            let try_response = response.buffer().await;
+            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            Err(PutRemediationConfigurationsError::from_response(response))
+        }
+    }
+
+    /// <p>A remediation exception is when a specific resource is no longer considered for auto-remediation. This API adds a new exception or updates an exisiting exception for a specific resource with a specific AWS Config rule. </p>
+    async fn put_remediation_exceptions(
+        &self,
+        input: PutRemediationExceptionsRequest,
+    ) -> Result<PutRemediationExceptionsResponse, RusotoError<PutRemediationExceptionsError>> {
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("PutRemediationExceptions");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StarlingDoveService.PutRemediationExceptions",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        if response.status.is_success() {
+            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutRemediationExceptionsResponse, _>()
+        } else {
+            let try_response = response.buffer().await;
+            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            Err(PutRemediationExceptionsError::from_response(response))
+        }
+    }
+
+    /// <p><p>Records the configuration state for the resource provided in the request. The configuration state of a resource is represented in AWS Config as Configuration Items. Once this API records the configuration item, you can retrieve the list of configuration items for the custom resource type using existing AWS Config APIs. </p> <note> <p>The custom resource type must be registered with AWS CloudFormation. This API accepts the configuration item registered with AWS CloudFormation.</p> <p>When you call this API, AWS Config only stores configuration state of the resource provided in the request. This API does not change or remediate the configuration of the resource. </p> </note></p>
+    async fn put_resource_config(
+        &self,
+        input: PutResourceConfigRequest,
+    ) -> Result<(), RusotoError<PutResourceConfigError>> {
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("PutResourceConfig");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "StarlingDoveService.PutResourceConfig");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        if response.status.is_success() {
+            std::mem::drop(response);
+            Ok(())
+        } else {
+            let try_response = response.buffer().await;
+            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            Err(PutResourceConfigError::from_response(response))
+        }
+    }
+
+    /// <p><p>Creates and updates the retention configuration with details about retention period (number of days) that AWS Config stores your historical information. The API creates the <code>RetentionConfiguration</code> object and names the object as <b>default</b>. When you have a <code>RetentionConfiguration</code> object named <b>default</b>, calling the API modifies the default object. </p> <note> <p>Currently, AWS Config supports only one retention configuration per region in your account.</p> </note></p>
+    async fn put_retention_configuration(
+        &self,
+        input: PutRetentionConfigurationRequest,
+    ) -> Result<PutRetentionConfigurationResponse, RusotoError<PutRetentionConfigurationError>>
+    {
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("PutRetentionConfiguration");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StarlingDoveService.PutRetentionConfiguration",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        if response.status.is_success() {
+            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutRetentionConfigurationResponse, _>()
+        } else {
+            let try_response = response.buffer().await;
+            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            Err(PutRetentionConfigurationError::from_response(response))
+        }
+    }
+
+    /// <p>Accepts a structured query language (SQL) <code>SELECT</code> command, performs the corresponding search, and returns resource configurations matching the properties.</p> <p>For more information about query components, see the <a href="https://docs.aws.amazon.com/config/latest/developerguide/query-components.html"> <b>Query Components</b> </a> section in the AWS Config Developer Guide.</p>
+    async fn select_resource_config(
+        &self,
+        input: SelectResourceConfigRequest,
+    ) -> Result<SelectResourceConfigResponse, RusotoError<SelectResourceConfigError>> {
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("SelectResourceConfig");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "StarlingDoveService.SelectResourceConfig");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        if response.status.is_success() {
+            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            proto::json::ResponsePayload::new(&response)
+                .deserialize::<SelectResourceConfigResponse, _>()
+        } else {
+            let try_response = response.buffer().await;
+            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            Err(SelectResourceConfigError::from_response(response))
+        }
+    }
+
+    /// <p><p>Runs an on-demand evaluation for the specified AWS Config rules against the last known configuration state of the resources. Use <code>StartConfigRulesEvaluation</code> when you want to test that a rule you updated is working as expected. <code>StartConfigRulesEvaluation</code> does not re-record the latest configuration state for your resources. It re-runs an evaluation against the last known state of your resources. </p> <p>You can specify up to 25 AWS Config rules per request. </p> <p>An existing <code>StartConfigRulesEvaluation</code> call for the specified rules must complete before you can call the API again. If you chose to have AWS Config stream to an Amazon SNS topic, you will receive a <code>ConfigRuleEvaluationStarted</code> notification when the evaluation starts.</p> <note> <p>You don&#39;t need to call the <code>StartConfigRulesEvaluation</code> API to run an evaluation for a new rule. When you create a rule, AWS Config evaluates your resources against the rule automatically. </p> </note> <p>The <code>StartConfigRulesEvaluation</code> API is useful if you want to run on-demand evaluations, such as the following example:</p> <ol> <li> <p>You have a custom rule that evaluates your IAM resources every 24 hours.</p> </li> <li> <p>You update your Lambda function to add additional conditions to your rule.</p> </li> <li> <p>Instead of waiting for the next periodic evaluation, you call the <code>StartConfigRulesEvaluation</code> API.</p> </li> <li> <p>AWS Config invokes your Lambda function and evaluates your IAM resources.</p> </li> <li> <p>Your custom rule will still run periodic evaluations every 24 hours.</p> </li> </ol></p>
+    async fn start_config_rules_evaluation(
+        &self,
+        input: StartConfigRulesEvaluationRequest,
+    ) -> Result<StartConfigRulesEvaluationResponse, RusotoError<StartConfigRulesEvaluationError>>
+    {
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("StartConfigRulesEvaluation");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StarlingDoveService.StartConfigRulesEvaluation",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        if response.status.is_success() {
+            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartConfigRulesEvaluationResponse, _>()
+        } else {
+            let try_response = response.buffer().await;
+            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            Err(StartConfigRulesEvaluationError::from_response(response))
+        }
+    }
+
+    /// <p>Starts recording configurations of the AWS resources you have selected to record in your AWS account.</p> <p>You must have created at least one delivery channel to successfully start the configuration recorder.</p>
+    async fn start_configuration_recorder(
+        &self,
+        input: StartConfigurationRecorderRequest,
+    ) -> Result<(), RusotoError<StartConfigurationRecorderError>> {
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("StartConfigurationRecorder");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
             "x-amz-target",
-            "StarlingDoveService.PutOrganizationConformancePack",
+            "StarlingDoveService.StartConfigurationRecorder",
         );
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10297,28 +15861,28 @@ impl ConfigService for ConfigServiceClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutOrganizationConformancePackResponse, _>()
+            std::mem::drop(response);
+            Ok(())
         } else {
             let try_response = response.buffer().await;
             let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutOrganizationConformancePackError::from_response(response))
+            Err(StartConfigurationRecorderError::from_response(response))
         }
     }
 
-    /// <p>Adds or updates the remediation configuration with a specific AWS Config rule with the selected target or action. The API creates the <code>RemediationConfiguration</code> object for the AWS Config rule. The AWS Config rule must already exist for you to add a remediation configuration. The target (SSM document) must exist and have permissions to use the target. </p>
-    async fn put_remediation_configurations(
+    /// <p>Runs an on-demand remediation for the specified AWS Config rules against the last known remediation configuration. It runs an execution against the current state of your resources. Remediation execution is asynchronous.</p> <p>You can specify up to 100 resource keys per request. An existing StartRemediationExecution call for the specified resource keys must complete before you can call the API again.</p>
+    async fn start_remediation_execution(
         &self,
-        input: PutRemediationConfigurationsRequest,
-    ) -> Result<PutRemediationConfigurationsResponse, RusotoError<PutRemediationConfigurationsError>>
+        input: StartRemediationExecutionRequest,
+    ) -> Result<StartRemediationExecutionResponse, RusotoError<StartRemediationExecutionError>>
     {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("StartRemediationExecution");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
             "x-amz-target",
-            "StarlingDoveService.PutRemediationConfigurations",
+            "StarlingDoveService.StartRemediationExecution",
         );
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10331,25 +15895,26 @@ impl ConfigService for ConfigServiceClient {
         if response.status.is_success() {
             let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
             proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutRemediationConfigurationsResponse, _>()
+                .deserialize::<StartRemediationExecutionResponse, _>()
         } else {
             let try_response = response.buffer().await;
             let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutRemediationConfigurationsError::from_response(response))
+            Err(StartRemediationExecutionError::from_response(response))
         }
     }
 
-    /// <p>A remediation exception is when a specific resource is no longer considered for auto-remediation. This API adds a new exception or updates an exisiting exception for a specific resource with a specific AWS Config rule. </p>
-    async fn put_remediation_exceptions(
+    /// <p>Stops recording configurations of the AWS resources you have selected to record in your AWS account.</p>
+    async fn stop_configuration_recorder(
         &self,
-        input: PutRemediationExceptionsRequest,
-    ) -> Result<PutRemediationExceptionsResponse, RusotoError<PutRemediationExceptionsError>> {
+        input: StopConfigurationRecorderRequest,
+    ) -> Result<(), RusotoError<StopConfigurationRecorderError>> {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("StopConfigurationRecorder");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
             "x-amz-target",
-            "StarlingDoveService.PutRemediationExceptions",
+            "StarlingDoveService.StopConfigurationRecorder",
         );
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10360,25 +15925,25 @@ impl ConfigService for ConfigServiceClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutRemediationExceptionsResponse, _>()
+            std::mem::drop(response);
+            Ok(())
         } else {
             let try_response = response.buffer().await;
             let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutRemediationExceptionsError::from_response(response))
+            Err(StopConfigurationRecorderError::from_response(response))
         }
     }
 
-    /// <p><p>Records the configuration state for the resource provided in the request. The configuration state of a resource is represented in AWS Config as Configuration Items. Once this API records the configuration item, you can retrieve the list of configuration items for the custom resource type using existing AWS Config APIs. </p> <note> <p>The custom resource type must be registered with AWS CloudFormation. This API accepts the configuration item registered with AWS CloudFormation.</p> <p>When you call this API, AWS Config only stores configuration state of the resource provided in the request. This API does not change or remediate the configuration of the resource. </p> </note></p>
-    async fn put_resource_config(
+    /// <p>Associates the specified tags to a resource with the specified resourceArn. If existing tags on a resource are not specified in the request parameters, they are not changed. When a resource is deleted, the tags associated with that resource are deleted as well.</p>
+    async fn tag_resource(
         &self,
-        input: PutResourceConfigRequest,
-    ) -> Result<(), RusotoError<PutResourceConfigError>> {
+        input: TagResourceRequest,
+    ) -> Result<(), RusotoError<TagResourceError>> {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "StarlingDoveService.PutResourceConfig");
+        request.add_header("x-amz-target", "StarlingDoveService.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
@@ -10393,23 +15958,20 @@ impl ConfigService for ConfigServiceClient {
         } else {
             let try_response = response.buffer().await;
             let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutResourceConfigError::from_response(response))
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p><p>Creates and updates the retention configuration with details about retention period (number of days) that AWS Config stores your historical information. The API creates the <code>RetentionConfiguration</code> object and names the object as <b>default</b>. When you have a <code>RetentionConfiguration</code> object named <b>default</b>, calling the API modifies the default object. </p> <note> <p>Currently, AWS Config supports only one retention configuration per region in your account.</p> </note></p>
-    async fn put_retention_configuration(
+    /// <p>Deletes specified tags from a resource.</p>
+    async fn untag_resource(
         &self,
-        input: PutRetentionConfigurationRequest,
-    ) -> Result<PutRetentionConfigurationResponse, RusotoError<PutRetentionConfigurationError>>
-    {
+        input: UntagResourceRequest,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StarlingDoveService.PutRetentionConfiguration",
-        );
+        request.add_header("x-amz-target", "StarlingDoveService.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
@@ -10419,219 +15981,1129 @@ impl ConfigService for ConfigServiceClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutRetentionConfigurationResponse, _>()
+            std::mem::drop(response);
+            Ok(())
         } else {
             let try_response = response.buffer().await;
             let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutRetentionConfigurationError::from_response(response))
+            Err(UntagResourceError::from_response(response))
         }
     }
+}
+impl ConfigServiceClient {
+    /// Auto-paginating version of `describe_aggregate_compliance_by_config_rules`, streaming every page of results.
+    pub fn describe_aggregate_compliance_by_config_rules_pages(
+        &self,
+        input: DescribeAggregateComplianceByConfigRulesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeAggregateComplianceByConfigRulesResponse,
+        DescribeAggregateComplianceByConfigRulesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConfigServiceClient,
+             input: DescribeAggregateComplianceByConfigRulesRequest| async move {
+                client
+                    .describe_aggregate_compliance_by_config_rules(input)
+                    .await
+            },
+            |input: &DescribeAggregateComplianceByConfigRulesRequest,
+             output: &DescribeAggregateComplianceByConfigRulesResponse| {
+                Some(DescribeAggregateComplianceByConfigRulesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_aggregate_compliance_by_config_rules`, streaming the `AggregateComplianceByConfigRules` of every page of results.
+    pub fn describe_aggregate_compliance_by_config_rules_items(
+        &self,
+        input: DescribeAggregateComplianceByConfigRulesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        AggregateComplianceByConfigRule,
+        DescribeAggregateComplianceByConfigRulesError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_aggregate_compliance_by_config_rules_pages(input),
+            |output: DescribeAggregateComplianceByConfigRulesResponse| {
+                output
+                    .aggregate_compliance_by_config_rules
+                    .unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_aggregation_authorizations`, streaming every page of results.
+    pub fn describe_aggregation_authorizations_pages(
+        &self,
+        input: DescribeAggregationAuthorizationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeAggregationAuthorizationsResponse,
+        DescribeAggregationAuthorizationsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConfigServiceClient, input: DescribeAggregationAuthorizationsRequest| async move {
+                client.describe_aggregation_authorizations(input).await
+            },
+            |input: &DescribeAggregationAuthorizationsRequest,
+             output: &DescribeAggregationAuthorizationsResponse| {
+                Some(DescribeAggregationAuthorizationsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_aggregation_authorizations`, streaming the `AggregationAuthorizations` of every page of results.
+    pub fn describe_aggregation_authorizations_items(
+        &self,
+        input: DescribeAggregationAuthorizationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        AggregationAuthorization,
+        DescribeAggregationAuthorizationsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_aggregation_authorizations_pages(input),
+            |output: DescribeAggregationAuthorizationsResponse| {
+                output.aggregation_authorizations.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_compliance_by_config_rule`, streaming every page of results.
+    pub fn describe_compliance_by_config_rule_pages(
+        &self,
+        input: DescribeComplianceByConfigRuleRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeComplianceByConfigRuleResponse,
+        DescribeComplianceByConfigRuleError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConfigServiceClient, input: DescribeComplianceByConfigRuleRequest| async move {
+                client.describe_compliance_by_config_rule(input).await
+            },
+            |input: &DescribeComplianceByConfigRuleRequest,
+             output: &DescribeComplianceByConfigRuleResponse| {
+                Some(DescribeComplianceByConfigRuleRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_compliance_by_config_rule`, streaming the `ComplianceByConfigRules` of every page of results.
+    pub fn describe_compliance_by_config_rule_items(
+        &self,
+        input: DescribeComplianceByConfigRuleRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ComplianceByConfigRule,
+        DescribeComplianceByConfigRuleError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_compliance_by_config_rule_pages(input),
+            |output: DescribeComplianceByConfigRuleResponse| {
+                output.compliance_by_config_rules.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_compliance_by_resource`, streaming every page of results.
+    pub fn describe_compliance_by_resource_pages(
+        &self,
+        input: DescribeComplianceByResourceRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeComplianceByResourceResponse,
+        DescribeComplianceByResourceError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConfigServiceClient, input: DescribeComplianceByResourceRequest| async move {
+                client.describe_compliance_by_resource(input).await
+            },
+            |input: &DescribeComplianceByResourceRequest,
+             output: &DescribeComplianceByResourceResponse| {
+                Some(DescribeComplianceByResourceRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_compliance_by_resource`, streaming the `ComplianceByResources` of every page of results.
+    pub fn describe_compliance_by_resource_items(
+        &self,
+        input: DescribeComplianceByResourceRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ComplianceByResource,
+        DescribeComplianceByResourceError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_compliance_by_resource_pages(input),
+            |output: DescribeComplianceByResourceResponse| {
+                output.compliance_by_resources.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_config_rule_evaluation_status`, streaming every page of results.
+    pub fn describe_config_rule_evaluation_status_pages(
+        &self,
+        input: DescribeConfigRuleEvaluationStatusRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeConfigRuleEvaluationStatusResponse,
+        DescribeConfigRuleEvaluationStatusError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConfigServiceClient, input: DescribeConfigRuleEvaluationStatusRequest| async move {
+                client.describe_config_rule_evaluation_status(input).await
+            },
+            |input: &DescribeConfigRuleEvaluationStatusRequest,
+             output: &DescribeConfigRuleEvaluationStatusResponse| {
+                Some(DescribeConfigRuleEvaluationStatusRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_config_rule_evaluation_status`, streaming the `ConfigRulesEvaluationStatus` of every page of results.
+    pub fn describe_config_rule_evaluation_status_items(
+        &self,
+        input: DescribeConfigRuleEvaluationStatusRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ConfigRuleEvaluationStatus,
+        DescribeConfigRuleEvaluationStatusError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_config_rule_evaluation_status_pages(input),
+            |output: DescribeConfigRuleEvaluationStatusResponse| {
+                output.config_rules_evaluation_status.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_config_rules`, streaming every page of results.
+    pub fn describe_config_rules_pages(
+        &self,
+        input: DescribeConfigRulesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeConfigRulesResponse, DescribeConfigRulesError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConfigServiceClient, input: DescribeConfigRulesRequest| async move {
+                client.describe_config_rules(input).await
+            },
+            |input: &DescribeConfigRulesRequest, output: &DescribeConfigRulesResponse| {
+                Some(DescribeConfigRulesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_config_rules`, streaming the `ConfigRules` of every page of results.
+    pub fn describe_config_rules_items(
+        &self,
+        input: DescribeConfigRulesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ConfigRule, DescribeConfigRulesError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_config_rules_pages(input),
+            |output: DescribeConfigRulesResponse| output.config_rules.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_configuration_aggregator_sources_status`, streaming every page of results.
+    pub fn describe_configuration_aggregator_sources_status_pages(
+        &self,
+        input: DescribeConfigurationAggregatorSourcesStatusRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeConfigurationAggregatorSourcesStatusResponse,
+        DescribeConfigurationAggregatorSourcesStatusError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConfigServiceClient,
+             input: DescribeConfigurationAggregatorSourcesStatusRequest| async move {
+                client
+                    .describe_configuration_aggregator_sources_status(input)
+                    .await
+            },
+            |input: &DescribeConfigurationAggregatorSourcesStatusRequest,
+             output: &DescribeConfigurationAggregatorSourcesStatusResponse| {
+                Some(DescribeConfigurationAggregatorSourcesStatusRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_configuration_aggregator_sources_status`, streaming the `AggregatedSourceStatusList` of every page of results.
+    pub fn describe_configuration_aggregator_sources_status_items(
+        &self,
+        input: DescribeConfigurationAggregatorSourcesStatusRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        AggregatedSourceStatus,
+        DescribeConfigurationAggregatorSourcesStatusError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_configuration_aggregator_sources_status_pages(input),
+            |output: DescribeConfigurationAggregatorSourcesStatusResponse| {
+                output.aggregated_source_status_list.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_configuration_aggregators`, streaming every page of results.
+    pub fn describe_configuration_aggregators_pages(
+        &self,
+        input: DescribeConfigurationAggregatorsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeConfigurationAggregatorsResponse,
+        DescribeConfigurationAggregatorsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConfigServiceClient, input: DescribeConfigurationAggregatorsRequest| async move {
+                client.describe_configuration_aggregators(input).await
+            },
+            |input: &DescribeConfigurationAggregatorsRequest,
+             output: &DescribeConfigurationAggregatorsResponse| {
+                Some(DescribeConfigurationAggregatorsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_configuration_aggregators`, streaming the `ConfigurationAggregators` of every page of results.
+    pub fn describe_configuration_aggregators_items(
+        &self,
+        input: DescribeConfigurationAggregatorsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ConfigurationAggregator,
+        DescribeConfigurationAggregatorsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_configuration_aggregators_pages(input),
+            |output: DescribeConfigurationAggregatorsResponse| {
+                output.configuration_aggregators.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_conformance_pack_status`, streaming every page of results.
+    pub fn describe_conformance_pack_status_pages(
+        &self,
+        input: DescribeConformancePackStatusRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeConformancePackStatusResponse,
+        DescribeConformancePackStatusError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConfigServiceClient, input: DescribeConformancePackStatusRequest| async move {
+                client.describe_conformance_pack_status(input).await
+            },
+            |input: &DescribeConformancePackStatusRequest,
+             output: &DescribeConformancePackStatusResponse| {
+                Some(DescribeConformancePackStatusRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_conformance_pack_status`, streaming the `ConformancePackStatusDetails` of every page of results.
+    pub fn describe_conformance_pack_status_items(
+        &self,
+        input: DescribeConformancePackStatusRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ConformancePackStatusDetail,
+        DescribeConformancePackStatusError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_conformance_pack_status_pages(input),
+            |output: DescribeConformancePackStatusResponse| {
+                output.conformance_pack_status_details.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_conformance_packs`, streaming every page of results.
+    pub fn describe_conformance_packs_pages(
+        &self,
+        input: DescribeConformancePacksRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeConformancePacksResponse,
+        DescribeConformancePacksError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConfigServiceClient, input: DescribeConformancePacksRequest| async move {
+                client.describe_conformance_packs(input).await
+            },
+            |input: &DescribeConformancePacksRequest, output: &DescribeConformancePacksResponse| {
+                Some(DescribeConformancePacksRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_conformance_packs`, streaming the `ConformancePackDetails` of every page of results.
+    pub fn describe_conformance_packs_items(
+        &self,
+        input: DescribeConformancePacksRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ConformancePackDetail, DescribeConformancePacksError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_conformance_packs_pages(input),
+            |output: DescribeConformancePacksResponse| {
+                output.conformance_pack_details.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_organization_config_rule_statuses`, streaming every page of results.
+    pub fn describe_organization_config_rule_statuses_pages(
+        &self,
+        input: DescribeOrganizationConfigRuleStatusesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeOrganizationConfigRuleStatusesResponse,
+        DescribeOrganizationConfigRuleStatusesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConfigServiceClient, input: DescribeOrganizationConfigRuleStatusesRequest| async move {
+                client
+                    .describe_organization_config_rule_statuses(input)
+                    .await
+            },
+            |input: &DescribeOrganizationConfigRuleStatusesRequest,
+             output: &DescribeOrganizationConfigRuleStatusesResponse| {
+                Some(DescribeOrganizationConfigRuleStatusesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_organization_config_rule_statuses`, streaming the `OrganizationConfigRuleStatuses` of every page of results.
+    pub fn describe_organization_config_rule_statuses_items(
+        &self,
+        input: DescribeOrganizationConfigRuleStatusesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        OrganizationConfigRuleStatus,
+        DescribeOrganizationConfigRuleStatusesError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_organization_config_rule_statuses_pages(input),
+            |output: DescribeOrganizationConfigRuleStatusesResponse| {
+                output.organization_config_rule_statuses.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_organization_config_rules`, streaming every page of results.
+    pub fn describe_organization_config_rules_pages(
+        &self,
+        input: DescribeOrganizationConfigRulesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeOrganizationConfigRulesResponse,
+        DescribeOrganizationConfigRulesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConfigServiceClient, input: DescribeOrganizationConfigRulesRequest| async move {
+                client.describe_organization_config_rules(input).await
+            },
+            |input: &DescribeOrganizationConfigRulesRequest,
+             output: &DescribeOrganizationConfigRulesResponse| {
+                Some(DescribeOrganizationConfigRulesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_organization_config_rules`, streaming the `OrganizationConfigRules` of every page of results.
+    pub fn describe_organization_config_rules_items(
+        &self,
+        input: DescribeOrganizationConfigRulesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        OrganizationConfigRule,
+        DescribeOrganizationConfigRulesError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_organization_config_rules_pages(input),
+            |output: DescribeOrganizationConfigRulesResponse| {
+                output.organization_config_rules.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_organization_conformance_pack_statuses`, streaming every page of results.
+    pub fn describe_organization_conformance_pack_statuses_pages(
+        &self,
+        input: DescribeOrganizationConformancePackStatusesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeOrganizationConformancePackStatusesResponse,
+        DescribeOrganizationConformancePackStatusesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConfigServiceClient,
+             input: DescribeOrganizationConformancePackStatusesRequest| async move {
+                client
+                    .describe_organization_conformance_pack_statuses(input)
+                    .await
+            },
+            |input: &DescribeOrganizationConformancePackStatusesRequest,
+             output: &DescribeOrganizationConformancePackStatusesResponse| {
+                Some(DescribeOrganizationConformancePackStatusesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_organization_conformance_pack_statuses`, streaming the `OrganizationConformancePackStatuses` of every page of results.
+    pub fn describe_organization_conformance_pack_statuses_items(
+        &self,
+        input: DescribeOrganizationConformancePackStatusesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        OrganizationConformancePackStatus,
+        DescribeOrganizationConformancePackStatusesError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_organization_conformance_pack_statuses_pages(input),
+            |output: DescribeOrganizationConformancePackStatusesResponse| {
+                output
+                    .organization_conformance_pack_statuses
+                    .unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_organization_conformance_packs`, streaming every page of results.
+    pub fn describe_organization_conformance_packs_pages(
+        &self,
+        input: DescribeOrganizationConformancePacksRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeOrganizationConformancePacksResponse,
+        DescribeOrganizationConformancePacksError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConfigServiceClient, input: DescribeOrganizationConformancePacksRequest| async move {
+                client.describe_organization_conformance_packs(input).await
+            },
+            |input: &DescribeOrganizationConformancePacksRequest,
+             output: &DescribeOrganizationConformancePacksResponse| {
+                Some(DescribeOrganizationConformancePacksRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_organization_conformance_packs`, streaming the `OrganizationConformancePacks` of every page of results.
+    pub fn describe_organization_conformance_packs_items(
+        &self,
+        input: DescribeOrganizationConformancePacksRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        OrganizationConformancePack,
+        DescribeOrganizationConformancePacksError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_organization_conformance_packs_pages(input),
+            |output: DescribeOrganizationConformancePacksResponse| {
+                output.organization_conformance_packs.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_pending_aggregation_requests`, streaming every page of results.
+    pub fn describe_pending_aggregation_requests_pages(
+        &self,
+        input: DescribePendingAggregationRequestsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribePendingAggregationRequestsResponse,
+        DescribePendingAggregationRequestsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConfigServiceClient, input: DescribePendingAggregationRequestsRequest| async move {
+                client.describe_pending_aggregation_requests(input).await
+            },
+            |input: &DescribePendingAggregationRequestsRequest,
+             output: &DescribePendingAggregationRequestsResponse| {
+                Some(DescribePendingAggregationRequestsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_pending_aggregation_requests`, streaming the `PendingAggregationRequests` of every page of results.
+    pub fn describe_pending_aggregation_requests_items(
+        &self,
+        input: DescribePendingAggregationRequestsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        PendingAggregationRequest,
+        DescribePendingAggregationRequestsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_pending_aggregation_requests_pages(input),
+            |output: DescribePendingAggregationRequestsResponse| {
+                output.pending_aggregation_requests.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_remediation_execution_status`, streaming every page of results.
+    pub fn describe_remediation_execution_status_pages(
+        &self,
+        input: DescribeRemediationExecutionStatusRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeRemediationExecutionStatusResponse,
+        DescribeRemediationExecutionStatusError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConfigServiceClient, input: DescribeRemediationExecutionStatusRequest| async move {
+                client.describe_remediation_execution_status(input).await
+            },
+            |input: &DescribeRemediationExecutionStatusRequest,
+             output: &DescribeRemediationExecutionStatusResponse| {
+                Some(DescribeRemediationExecutionStatusRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_remediation_execution_status`, streaming the `RemediationExecutionStatuses` of every page of results.
+    pub fn describe_remediation_execution_status_items(
+        &self,
+        input: DescribeRemediationExecutionStatusRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        RemediationExecutionStatus,
+        DescribeRemediationExecutionStatusError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_remediation_execution_status_pages(input),
+            |output: DescribeRemediationExecutionStatusResponse| {
+                output.remediation_execution_statuses.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_retention_configurations`, streaming every page of results.
+    pub fn describe_retention_configurations_pages(
+        &self,
+        input: DescribeRetentionConfigurationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeRetentionConfigurationsResponse,
+        DescribeRetentionConfigurationsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConfigServiceClient, input: DescribeRetentionConfigurationsRequest| async move {
+                client.describe_retention_configurations(input).await
+            },
+            |input: &DescribeRetentionConfigurationsRequest,
+             output: &DescribeRetentionConfigurationsResponse| {
+                Some(DescribeRetentionConfigurationsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
 
-    /// <p>Accepts a structured query language (SQL) <code>SELECT</code> command, performs the corresponding search, and returns resource configurations matching the properties.</p> <p>For more information about query components, see the <a href="https://docs.aws.amazon.com/config/latest/developerguide/query-components.html"> <b>Query Components</b> </a> section in the AWS Config Developer Guide.</p>
-    async fn select_resource_config(
+    /// Auto-paginating version of `describe_retention_configurations`, streaming the `RetentionConfigurations` of every page of results.
+    pub fn describe_retention_configurations_items(
         &self,
-        input: SelectResourceConfigRequest,
-    ) -> Result<SelectResourceConfigResponse, RusotoError<SelectResourceConfigError>> {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        input: DescribeRetentionConfigurationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        RetentionConfiguration,
+        DescribeRetentionConfigurationsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_retention_configurations_pages(input),
+            |output: DescribeRetentionConfigurationsResponse| {
+                output.retention_configurations.unwrap_or_default()
+            },
+        )
+    }
 
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "StarlingDoveService.SelectResourceConfig");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
+    /// Auto-paginating version of `get_aggregate_compliance_details_by_config_rule`, streaming every page of results.
+    pub fn get_aggregate_compliance_details_by_config_rule_pages(
+        &self,
+        input: GetAggregateComplianceDetailsByConfigRuleRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        GetAggregateComplianceDetailsByConfigRuleResponse,
+        GetAggregateComplianceDetailsByConfigRuleError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConfigServiceClient,
+             input: GetAggregateComplianceDetailsByConfigRuleRequest| async move {
+                client
+                    .get_aggregate_compliance_details_by_config_rule(input)
+                    .await
+            },
+            |input: &GetAggregateComplianceDetailsByConfigRuleRequest,
+             output: &GetAggregateComplianceDetailsByConfigRuleResponse| {
+                Some(GetAggregateComplianceDetailsByConfigRuleRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<SelectResourceConfigResponse, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(SelectResourceConfigError::from_response(response))
-        }
+    /// Auto-paginating version of `get_aggregate_compliance_details_by_config_rule`, streaming the `AggregateEvaluationResults` of every page of results.
+    pub fn get_aggregate_compliance_details_by_config_rule_items(
+        &self,
+        input: GetAggregateComplianceDetailsByConfigRuleRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        AggregateEvaluationResult,
+        GetAggregateComplianceDetailsByConfigRuleError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.get_aggregate_compliance_details_by_config_rule_pages(input),
+            |output: GetAggregateComplianceDetailsByConfigRuleResponse| {
+                output.aggregate_evaluation_results.unwrap_or_default()
+            },
+        )
     }
 
-    /// <p><p>Runs an on-demand evaluation for the specified AWS Config rules against the last known configuration state of the resources. Use <code>StartConfigRulesEvaluation</code> when you want to test that a rule you updated is working as expected. <code>StartConfigRulesEvaluation</code> does not re-record the latest configuration state for your resources. It re-runs an evaluation against the last known state of your resources. </p> <p>You can specify up to 25 AWS Config rules per request. </p> <p>An existing <code>StartConfigRulesEvaluation</code> call for the specified rules must complete before you can call the API again. If you chose to have AWS Config stream to an Amazon SNS topic, you will receive a <code>ConfigRuleEvaluationStarted</code> notification when the evaluation starts.</p> <note> <p>You don&#39;t need to call the <code>StartConfigRulesEvaluation</code> API to run an evaluation for a new rule. When you create a rule, AWS Config evaluates your resources against the rule automatically. </p> </note> <p>The <code>StartConfigRulesEvaluation</code> API is useful if you want to run on-demand evaluations, such as the following example:</p> <ol> <li> <p>You have a custom rule that evaluates your IAM resources every 24 hours.</p> </li> <li> <p>You update your Lambda function to add additional conditions to your rule.</p> </li> <li> <p>Instead of waiting for the next periodic evaluation, you call the <code>StartConfigRulesEvaluation</code> API.</p> </li> <li> <p>AWS Config invokes your Lambda function and evaluates your IAM resources.</p> </li> <li> <p>Your custom rule will still run periodic evaluations every 24 hours.</p> </li> </ol></p>
-    async fn start_config_rules_evaluation(
+    /// Auto-paginating version of `get_compliance_details_by_config_rule`, streaming every page of results.
+    pub fn get_compliance_details_by_config_rule_pages(
         &self,
-        input: StartConfigRulesEvaluationRequest,
-    ) -> Result<StartConfigRulesEvaluationResponse, RusotoError<StartConfigRulesEvaluationError>>
-    {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        input: GetComplianceDetailsByConfigRuleRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        GetComplianceDetailsByConfigRuleResponse,
+        GetComplianceDetailsByConfigRuleError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConfigServiceClient, input: GetComplianceDetailsByConfigRuleRequest| async move {
+                client.get_compliance_details_by_config_rule(input).await
+            },
+            |input: &GetComplianceDetailsByConfigRuleRequest,
+             output: &GetComplianceDetailsByConfigRuleResponse| {
+                Some(GetComplianceDetailsByConfigRuleRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
 
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StarlingDoveService.StartConfigRulesEvaluation",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
+    /// Auto-paginating version of `get_compliance_details_by_config_rule`, streaming the `EvaluationResults` of every page of results.
+    pub fn get_compliance_details_by_config_rule_items(
+        &self,
+        input: GetComplianceDetailsByConfigRuleRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        EvaluationResult,
+        GetComplianceDetailsByConfigRuleError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.get_compliance_details_by_config_rule_pages(input),
+            |output: GetComplianceDetailsByConfigRuleResponse| {
+                output.evaluation_results.unwrap_or_default()
+            },
+        )
+    }
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartConfigRulesEvaluationResponse, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartConfigRulesEvaluationError::from_response(response))
-        }
+    /// Auto-paginating version of `get_compliance_details_by_resource`, streaming every page of results.
+    pub fn get_compliance_details_by_resource_pages(
+        &self,
+        input: GetComplianceDetailsByResourceRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        GetComplianceDetailsByResourceResponse,
+        GetComplianceDetailsByResourceError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConfigServiceClient, input: GetComplianceDetailsByResourceRequest| async move {
+                client.get_compliance_details_by_resource(input).await
+            },
+            |input: &GetComplianceDetailsByResourceRequest,
+             output: &GetComplianceDetailsByResourceResponse| {
+                Some(GetComplianceDetailsByResourceRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
     }
 
-    /// <p>Starts recording configurations of the AWS resources you have selected to record in your AWS account.</p> <p>You must have created at least one delivery channel to successfully start the configuration recorder.</p>
-    async fn start_configuration_recorder(
+    /// Auto-paginating version of `get_compliance_details_by_resource`, streaming the `EvaluationResults` of every page of results.
+    pub fn get_compliance_details_by_resource_items(
         &self,
-        input: StartConfigurationRecorderRequest,
-    ) -> Result<(), RusotoError<StartConfigurationRecorderError>> {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        input: GetComplianceDetailsByResourceRequest,
+    ) -> rusoto_core::pagination::RusotoStream<EvaluationResult, GetComplianceDetailsByResourceError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.get_compliance_details_by_resource_pages(input),
+            |output: GetComplianceDetailsByResourceResponse| {
+                output.evaluation_results.unwrap_or_default()
+            },
+        )
+    }
 
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StarlingDoveService.StartConfigurationRecorder",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
+    /// Auto-paginating version of `get_conformance_pack_compliance_summary`, streaming every page of results.
+    pub fn get_conformance_pack_compliance_summary_pages(
+        &self,
+        input: GetConformancePackComplianceSummaryRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        GetConformancePackComplianceSummaryResponse,
+        GetConformancePackComplianceSummaryError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConfigServiceClient, input: GetConformancePackComplianceSummaryRequest| async move {
+                client.get_conformance_pack_compliance_summary(input).await
+            },
+            |input: &GetConformancePackComplianceSummaryRequest,
+             output: &GetConformancePackComplianceSummaryResponse| {
+                Some(GetConformancePackComplianceSummaryRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartConfigurationRecorderError::from_response(response))
-        }
+    /// Auto-paginating version of `get_conformance_pack_compliance_summary`, streaming the `ConformancePackComplianceSummaryList` of every page of results.
+    pub fn get_conformance_pack_compliance_summary_items(
+        &self,
+        input: GetConformancePackComplianceSummaryRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ConformancePackComplianceSummary,
+        GetConformancePackComplianceSummaryError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.get_conformance_pack_compliance_summary_pages(input),
+            |output: GetConformancePackComplianceSummaryResponse| {
+                output
+                    .conformance_pack_compliance_summary_list
+                    .unwrap_or_default()
+            },
+        )
     }
 
-    /// <p>Runs an on-demand remediation for the specified AWS Config rules against the last known remediation configuration. It runs an execution against the current state of your resources. Remediation execution is asynchronous.</p> <p>You can specify up to 100 resource keys per request. An existing StartRemediationExecution call for the specified resource keys must complete before you can call the API again.</p>
-    async fn start_remediation_execution(
+    /// Auto-paginating version of `get_organization_config_rule_detailed_status`, streaming every page of results.
+    pub fn get_organization_config_rule_detailed_status_pages(
         &self,
-        input: StartRemediationExecutionRequest,
-    ) -> Result<StartRemediationExecutionResponse, RusotoError<StartRemediationExecutionError>>
-    {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        input: GetOrganizationConfigRuleDetailedStatusRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        GetOrganizationConfigRuleDetailedStatusResponse,
+        GetOrganizationConfigRuleDetailedStatusError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConfigServiceClient, input: GetOrganizationConfigRuleDetailedStatusRequest| async move {
+                client
+                    .get_organization_config_rule_detailed_status(input)
+                    .await
+            },
+            |input: &GetOrganizationConfigRuleDetailedStatusRequest,
+             output: &GetOrganizationConfigRuleDetailedStatusResponse| {
+                Some(GetOrganizationConfigRuleDetailedStatusRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
 
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StarlingDoveService.StartRemediationExecution",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
+    /// Auto-paginating version of `get_organization_config_rule_detailed_status`, streaming the `OrganizationConfigRuleDetailedStatus` of every page of results.
+    pub fn get_organization_config_rule_detailed_status_items(
+        &self,
+        input: GetOrganizationConfigRuleDetailedStatusRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        MemberAccountStatus,
+        GetOrganizationConfigRuleDetailedStatusError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.get_organization_config_rule_detailed_status_pages(input),
+            |output: GetOrganizationConfigRuleDetailedStatusResponse| {
+                output
+                    .organization_config_rule_detailed_status
+                    .unwrap_or_default()
+            },
+        )
+    }
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartRemediationExecutionResponse, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartRemediationExecutionError::from_response(response))
-        }
+    /// Auto-paginating version of `get_organization_conformance_pack_detailed_status`, streaming every page of results.
+    pub fn get_organization_conformance_pack_detailed_status_pages(
+        &self,
+        input: GetOrganizationConformancePackDetailedStatusRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        GetOrganizationConformancePackDetailedStatusResponse,
+        GetOrganizationConformancePackDetailedStatusError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConfigServiceClient,
+             input: GetOrganizationConformancePackDetailedStatusRequest| async move {
+                client
+                    .get_organization_conformance_pack_detailed_status(input)
+                    .await
+            },
+            |input: &GetOrganizationConformancePackDetailedStatusRequest,
+             output: &GetOrganizationConformancePackDetailedStatusResponse| {
+                Some(GetOrganizationConformancePackDetailedStatusRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
     }
 
-    /// <p>Stops recording configurations of the AWS resources you have selected to record in your AWS account.</p>
-    async fn stop_configuration_recorder(
+    /// Auto-paginating version of `get_organization_conformance_pack_detailed_status`, streaming the `OrganizationConformancePackDetailedStatuses` of every page of results.
+    pub fn get_organization_conformance_pack_detailed_status_items(
         &self,
-        input: StopConfigurationRecorderRequest,
-    ) -> Result<(), RusotoError<StopConfigurationRecorderError>> {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        input: GetOrganizationConformancePackDetailedStatusRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        OrganizationConformancePackDetailedStatus,
+        GetOrganizationConformancePackDetailedStatusError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.get_organization_conformance_pack_detailed_status_pages(input),
+            |output: GetOrganizationConformancePackDetailedStatusResponse| {
+                output
+                    .organization_conformance_pack_detailed_statuses
+                    .unwrap_or_default()
+            },
+        )
+    }
 
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StarlingDoveService.StopConfigurationRecorder",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
+    /// Auto-paginating version of `get_resource_config_history`, streaming every page of results.
+    pub fn get_resource_config_history_pages(
+        &self,
+        input: GetResourceConfigHistoryRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        GetResourceConfigHistoryResponse,
+        GetResourceConfigHistoryError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConfigServiceClient, input: GetResourceConfigHistoryRequest| async move {
+                client.get_resource_config_history(input).await
+            },
+            |input: &GetResourceConfigHistoryRequest, output: &GetResourceConfigHistoryResponse| {
+                Some(GetResourceConfigHistoryRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StopConfigurationRecorderError::from_response(response))
-        }
+    /// Auto-paginating version of `get_resource_config_history`, streaming the `configurationItems` of every page of results.
+    pub fn get_resource_config_history_items(
+        &self,
+        input: GetResourceConfigHistoryRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ConfigurationItem, GetResourceConfigHistoryError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.get_resource_config_history_pages(input),
+            |output: GetResourceConfigHistoryResponse| {
+                output.configuration_items.unwrap_or_default()
+            },
+        )
     }
 
-    /// <p>Associates the specified tags to a resource with the specified resourceArn. If existing tags on a resource are not specified in the request parameters, they are not changed. When a resource is deleted, the tags associated with that resource are deleted as well.</p>
-    async fn tag_resource(
+    /// Auto-paginating version of `list_aggregate_discovered_resources`, streaming every page of results.
+    pub fn list_aggregate_discovered_resources_pages(
         &self,
-        input: TagResourceRequest,
-    ) -> Result<(), RusotoError<TagResourceError>> {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        input: ListAggregateDiscoveredResourcesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListAggregateDiscoveredResourcesResponse,
+        ListAggregateDiscoveredResourcesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConfigServiceClient, input: ListAggregateDiscoveredResourcesRequest| async move {
+                client.list_aggregate_discovered_resources(input).await
+            },
+            |input: &ListAggregateDiscoveredResourcesRequest,
+             output: &ListAggregateDiscoveredResourcesResponse| {
+                Some(ListAggregateDiscoveredResourcesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
 
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "StarlingDoveService.TagResource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
+    /// Auto-paginating version of `list_aggregate_discovered_resources`, streaming the `ResourceIdentifiers` of every page of results.
+    pub fn list_aggregate_discovered_resources_items(
+        &self,
+        input: ListAggregateDiscoveredResourcesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        AggregateResourceIdentifier,
+        ListAggregateDiscoveredResourcesError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_aggregate_discovered_resources_pages(input),
+            |output: ListAggregateDiscoveredResourcesResponse| {
+                output.resource_identifiers.unwrap_or_default()
+            },
+        )
+    }
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
-        }
+    /// Auto-paginating version of `list_discovered_resources`, streaming every page of results.
+    pub fn list_discovered_resources_pages(
+        &self,
+        input: ListDiscoveredResourcesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListDiscoveredResourcesResponse,
+        ListDiscoveredResourcesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConfigServiceClient, input: ListDiscoveredResourcesRequest| async move {
+                client.list_discovered_resources(input).await
+            },
+            |input: &ListDiscoveredResourcesRequest, output: &ListDiscoveredResourcesResponse| {
+                Some(ListDiscoveredResourcesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
     }
 
-    /// <p>Deletes specified tags from a resource.</p>
-    async fn untag_resource(
+    /// Auto-paginating version of `list_discovered_resources`, streaming the `resourceIdentifiers` of every page of results.
+    pub fn list_discovered_resources_items(
         &self,
-        input: UntagResourceRequest,
-    ) -> Result<(), RusotoError<UntagResourceError>> {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        input: ListDiscoveredResourcesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ResourceIdentifier, ListDiscoveredResourcesError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_discovered_resources_pages(input),
+            |output: ListDiscoveredResourcesResponse| {
+                output.resource_identifiers.unwrap_or_default()
+            },
+        )
+    }
 
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "StarlingDoveService.UntagResource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
+    /// Auto-paginating version of `list_tags_for_resource`, streaming every page of results.
+    pub fn list_tags_for_resource_pages(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListTagsForResourceResponse, ListTagsForResourceError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConfigServiceClient, input: ListTagsForResourceRequest| async move {
+                client.list_tags_for_resource(input).await
+            },
+            |input: &ListTagsForResourceRequest, output: &ListTagsForResourceResponse| {
+                Some(ListTagsForResourceRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
-        }
+    /// Auto-paginating version of `list_tags_for_resource`, streaming the `Tags` of every page of results.
+    pub fn list_tags_for_resource_items(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Tag, ListTagsForResourceError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_tags_for_resource_pages(input),
+            |output: ListTagsForResourceResponse| output.tags.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `select_resource_config`, streaming every page of results.
+    pub fn select_resource_config_pages(
+        &self,
+        input: SelectResourceConfigRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        SelectResourceConfigResponse,
+        SelectResourceConfigError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConfigServiceClient, input: SelectResourceConfigRequest| async move {
+                client.select_resource_config(input).await
+            },
+            |input: &SelectResourceConfigRequest, output: &SelectResourceConfigResponse| {
+                Some(SelectResourceConfigRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `select_resource_config`, streaming the `Results` of every page of results.
+    pub fn select_resource_config_items(
+        &self,
+        input: SelectResourceConfigRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, SelectResourceConfigError> {
+        rusoto_core::pagination::paginate_items(
+            self.select_resource_config_pages(input),
+            |output: SelectResourceConfigResponse| output.results.unwrap_or_default(),
+        )
     }
 }
@@ -25,6 +25,104 @@ use rusoto_core::signature::SignedRequest;
 #[allow(unused_imports)]
 use serde::{Deserialize, Serialize};
 use serde_json;
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Channel {
+    /// `VOICE`
+    Voice,
+    /// `CHAT`
+    Chat,
+    /// `TASK`
+    Task,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Channel {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Channel::Voice => "VOICE",
+            Channel::Chat => "CHAT",
+            Channel::Task => "TASK",
+            Channel::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Channel {
+    fn default() -> Channel {
+        Channel::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Channel {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Channel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Channel {
+    fn from(value: &'a str) -> Channel {
+        match value {
+            "VOICE" => Channel::Voice,
+            "CHAT" => Channel::Chat,
+            "TASK" => Channel::Task,
+            _ => Channel::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Channel {
+    fn from(value: String) -> Channel {
+        match value.as_str() {
+            "VOICE" => Channel::Voice,
+            "CHAT" => Channel::Chat,
+            "TASK" => Channel::Task,
+            _ => Channel::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Channel {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Channel, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Channel {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Channel {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Channel {
+    fn deserialize<D>(deserializer: D) -> Result<Channel, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A chat message.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -37,6 +135,94 @@ pub struct ChatMessage {
     pub content_type: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Comparison {
+    /// `LT`
+    Lt,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Comparison {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Comparison::Lt => "LT",
+            Comparison::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Comparison {
+    fn default() -> Comparison {
+        Comparison::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Comparison {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Comparison {
+    fn from(value: &'a str) -> Comparison {
+        match value {
+            "LT" => Comparison::Lt,
+            _ => Comparison::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Comparison {
+    fn from(value: String) -> Comparison {
+        match value.as_str() {
+            "LT" => Comparison::Lt,
+            _ => Comparison::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Comparison {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Comparison, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Comparison {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Comparison {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Comparison {
+    fn deserialize<D>(deserializer: D) -> Result<Comparison, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Contains summary information about a contact flow.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -48,7 +234,7 @@ pub struct ContactFlowSummary {
     /// <p>The type of contact flow.</p>
     #[serde(rename = "ContactFlowType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub contact_flow_type: Option<String>,
+    pub contact_flow_type: Option<ContactFlowType>,
     /// <p>The identifier of the contact flow.</p>
     #[serde(rename = "Id")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -59,6 +245,134 @@ pub struct ContactFlowSummary {
     pub name: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ContactFlowType {
+    /// `CONTACT_FLOW`
+    ContactFlow,
+    /// `CUSTOMER_QUEUE`
+    CustomerQueue,
+    /// `CUSTOMER_HOLD`
+    CustomerHold,
+    /// `CUSTOMER_WHISPER`
+    CustomerWhisper,
+    /// `AGENT_HOLD`
+    AgentHold,
+    /// `AGENT_WHISPER`
+    AgentWhisper,
+    /// `OUTBOUND_WHISPER`
+    OutboundWhisper,
+    /// `AGENT_TRANSFER`
+    AgentTransfer,
+    /// `QUEUE_TRANSFER`
+    QueueTransfer,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ContactFlowType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ContactFlowType::ContactFlow => "CONTACT_FLOW",
+            ContactFlowType::CustomerQueue => "CUSTOMER_QUEUE",
+            ContactFlowType::CustomerHold => "CUSTOMER_HOLD",
+            ContactFlowType::CustomerWhisper => "CUSTOMER_WHISPER",
+            ContactFlowType::AgentHold => "AGENT_HOLD",
+            ContactFlowType::AgentWhisper => "AGENT_WHISPER",
+            ContactFlowType::OutboundWhisper => "OUTBOUND_WHISPER",
+            ContactFlowType::AgentTransfer => "AGENT_TRANSFER",
+            ContactFlowType::QueueTransfer => "QUEUE_TRANSFER",
+            ContactFlowType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ContactFlowType {
+    fn default() -> ContactFlowType {
+        ContactFlowType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ContactFlowType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ContactFlowType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ContactFlowType {
+    fn from(value: &'a str) -> ContactFlowType {
+        match value {
+            "CONTACT_FLOW" => ContactFlowType::ContactFlow,
+            "CUSTOMER_QUEUE" => ContactFlowType::CustomerQueue,
+            "CUSTOMER_HOLD" => ContactFlowType::CustomerHold,
+            "CUSTOMER_WHISPER" => ContactFlowType::CustomerWhisper,
+            "AGENT_HOLD" => ContactFlowType::AgentHold,
+            "AGENT_WHISPER" => ContactFlowType::AgentWhisper,
+            "OUTBOUND_WHISPER" => ContactFlowType::OutboundWhisper,
+            "AGENT_TRANSFER" => ContactFlowType::AgentTransfer,
+            "QUEUE_TRANSFER" => ContactFlowType::QueueTransfer,
+            _ => ContactFlowType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ContactFlowType {
+    fn from(value: String) -> ContactFlowType {
+        match value.as_str() {
+            "CONTACT_FLOW" => ContactFlowType::ContactFlow,
+            "CUSTOMER_QUEUE" => ContactFlowType::CustomerQueue,
+            "CUSTOMER_HOLD" => ContactFlowType::CustomerHold,
+            "CUSTOMER_WHISPER" => ContactFlowType::CustomerWhisper,
+            "AGENT_HOLD" => ContactFlowType::AgentHold,
+            "AGENT_WHISPER" => ContactFlowType::AgentWhisper,
+            "OUTBOUND_WHISPER" => ContactFlowType::OutboundWhisper,
+            "AGENT_TRANSFER" => ContactFlowType::AgentTransfer,
+            "QUEUE_TRANSFER" => ContactFlowType::QueueTransfer,
+            _ => ContactFlowType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ContactFlowType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ContactFlowType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ContactFlowType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ContactFlowType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ContactFlowType {
+    fn deserialize<D>(deserializer: D) -> Result<ContactFlowType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateUserRequest {
@@ -122,16 +436,26 @@ pub struct Credentials {
     pub access_token: Option<String>,
     /// <p>A token generated with an expiration time for the session a user is logged in to Amazon Connect.</p>
     #[serde(rename = "AccessTokenExpiration")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub access_token_expiration: Option<f64>,
+    pub access_token_expiration: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Renews a token generated for a user to access the Amazon Connect instance.</p>
     #[serde(rename = "RefreshToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub refresh_token: Option<String>,
     /// <p>Renews the expiration timer for a generated token.</p>
     #[serde(rename = "RefreshTokenExpiration")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub refresh_token_expiration: Option<f64>,
+    pub refresh_token_expiration: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>Contains information about a real-time metric.</p>
@@ -140,11 +464,11 @@ pub struct CurrentMetric {
     /// <p>The name of the metric.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
+    pub name: Option<CurrentMetricName>,
     /// <p>The unit for the metric.</p>
     #[serde(rename = "Unit")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub unit: Option<String>,
+    pub unit: Option<Unit>,
 }
 
 /// <p>Contains the data for a real-time metric.</p>
@@ -161,6 +485,155 @@ pub struct CurrentMetricData {
     pub value: Option<f64>,
 }
 
+/// <p>The current metric names.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CurrentMetricName {
+    /// `AGENTS_ONLINE`
+    AgentsOnline,
+    /// `AGENTS_AVAILABLE`
+    AgentsAvailable,
+    /// `AGENTS_ON_CALL`
+    AgentsOnCall,
+    /// `AGENTS_NON_PRODUCTIVE`
+    AgentsNonProductive,
+    /// `AGENTS_AFTER_CONTACT_WORK`
+    AgentsAfterContactWork,
+    /// `AGENTS_ERROR`
+    AgentsError,
+    /// `AGENTS_STAFFED`
+    AgentsStaffed,
+    /// `CONTACTS_IN_QUEUE`
+    ContactsInQueue,
+    /// `OLDEST_CONTACT_AGE`
+    OldestContactAge,
+    /// `CONTACTS_SCHEDULED`
+    ContactsScheduled,
+    /// `AGENTS_ON_CONTACT`
+    AgentsOnContact,
+    /// `SLOTS_ACTIVE`
+    SlotsActive,
+    /// `SLOTS_AVAILABLE`
+    SlotsAvailable,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CurrentMetricName {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CurrentMetricName::AgentsOnline => "AGENTS_ONLINE",
+            CurrentMetricName::AgentsAvailable => "AGENTS_AVAILABLE",
+            CurrentMetricName::AgentsOnCall => "AGENTS_ON_CALL",
+            CurrentMetricName::AgentsNonProductive => "AGENTS_NON_PRODUCTIVE",
+            CurrentMetricName::AgentsAfterContactWork => "AGENTS_AFTER_CONTACT_WORK",
+            CurrentMetricName::AgentsError => "AGENTS_ERROR",
+            CurrentMetricName::AgentsStaffed => "AGENTS_STAFFED",
+            CurrentMetricName::ContactsInQueue => "CONTACTS_IN_QUEUE",
+            CurrentMetricName::OldestContactAge => "OLDEST_CONTACT_AGE",
+            CurrentMetricName::ContactsScheduled => "CONTACTS_SCHEDULED",
+            CurrentMetricName::AgentsOnContact => "AGENTS_ON_CONTACT",
+            CurrentMetricName::SlotsActive => "SLOTS_ACTIVE",
+            CurrentMetricName::SlotsAvailable => "SLOTS_AVAILABLE",
+            CurrentMetricName::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CurrentMetricName {
+    fn default() -> CurrentMetricName {
+        CurrentMetricName::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CurrentMetricName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CurrentMetricName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CurrentMetricName {
+    fn from(value: &'a str) -> CurrentMetricName {
+        match value {
+            "AGENTS_ONLINE" => CurrentMetricName::AgentsOnline,
+            "AGENTS_AVAILABLE" => CurrentMetricName::AgentsAvailable,
+            "AGENTS_ON_CALL" => CurrentMetricName::AgentsOnCall,
+            "AGENTS_NON_PRODUCTIVE" => CurrentMetricName::AgentsNonProductive,
+            "AGENTS_AFTER_CONTACT_WORK" => CurrentMetricName::AgentsAfterContactWork,
+            "AGENTS_ERROR" => CurrentMetricName::AgentsError,
+            "AGENTS_STAFFED" => CurrentMetricName::AgentsStaffed,
+            "CONTACTS_IN_QUEUE" => CurrentMetricName::ContactsInQueue,
+            "OLDEST_CONTACT_AGE" => CurrentMetricName::OldestContactAge,
+            "CONTACTS_SCHEDULED" => CurrentMetricName::ContactsScheduled,
+            "AGENTS_ON_CONTACT" => CurrentMetricName::AgentsOnContact,
+            "SLOTS_ACTIVE" => CurrentMetricName::SlotsActive,
+            "SLOTS_AVAILABLE" => CurrentMetricName::SlotsAvailable,
+            _ => CurrentMetricName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CurrentMetricName {
+    fn from(value: String) -> CurrentMetricName {
+        match value.as_str() {
+            "AGENTS_ONLINE" => CurrentMetricName::AgentsOnline,
+            "AGENTS_AVAILABLE" => CurrentMetricName::AgentsAvailable,
+            "AGENTS_ON_CALL" => CurrentMetricName::AgentsOnCall,
+            "AGENTS_NON_PRODUCTIVE" => CurrentMetricName::AgentsNonProductive,
+            "AGENTS_AFTER_CONTACT_WORK" => CurrentMetricName::AgentsAfterContactWork,
+            "AGENTS_ERROR" => CurrentMetricName::AgentsError,
+            "AGENTS_STAFFED" => CurrentMetricName::AgentsStaffed,
+            "CONTACTS_IN_QUEUE" => CurrentMetricName::ContactsInQueue,
+            "OLDEST_CONTACT_AGE" => CurrentMetricName::OldestContactAge,
+            "CONTACTS_SCHEDULED" => CurrentMetricName::ContactsScheduled,
+            "AGENTS_ON_CONTACT" => CurrentMetricName::AgentsOnContact,
+            "SLOTS_ACTIVE" => CurrentMetricName::SlotsActive,
+            "SLOTS_AVAILABLE" => CurrentMetricName::SlotsAvailable,
+            _ => CurrentMetricName::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CurrentMetricName {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CurrentMetricName, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CurrentMetricName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CurrentMetricName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CurrentMetricName {
+    fn deserialize<D>(deserializer: D) -> Result<CurrentMetricName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Contains information about a set of real-time metrics.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -250,7 +723,7 @@ pub struct Dimensions {
     /// <p>The channel used for grouping and filters.</p>
     #[serde(rename = "Channel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub channel: Option<String>,
+    pub channel: Option<Channel>,
     /// <p>Information about the queue for which metrics are returned.</p>
     #[serde(rename = "Queue")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -264,7 +737,7 @@ pub struct Filters {
     /// <p>The channel to use to filter the metrics.</p>
     #[serde(rename = "Channels")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub channels: Option<Vec<String>>,
+    pub channels: Option<Vec<Channel>>,
     /// <p>The queues to use to filter the metrics. You can specify up to 100 queues per request.</p>
     #[serde(rename = "Queues")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -303,7 +776,7 @@ pub struct GetCurrentMetricDataRequest {
     /// <p>The grouping applied to the metrics returned. For example, when grouped by <code>QUEUE</code>, the metrics returned apply to each queue rather than aggregated for all queues. If you group by <code>CHANNEL</code>, you should include a Channels filter. The only supported channel is <code>VOICE</code>.</p> <p>If no <code>Grouping</code> is included in the request, a summary of metrics is returned.</p>
     #[serde(rename = "Groupings")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub groupings: Option<Vec<String>>,
+    pub groupings: Option<Vec<Grouping>>,
     /// <p>The identifier of the Amazon Connect instance.</p>
     #[serde(rename = "InstanceId")]
     pub instance_id: String,
@@ -322,8 +795,13 @@ pub struct GetCurrentMetricDataRequest {
 pub struct GetCurrentMetricDataResponse {
     /// <p>The time at which the metrics were retrieved and cached for pagination.</p>
     #[serde(rename = "DataSnapshotTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub data_snapshot_time: Option<f64>,
+    pub data_snapshot_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Information about the real-time metrics.</p>
     #[serde(rename = "MetricResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -356,14 +834,19 @@ pub struct GetFederationTokenResponse {
 pub struct GetMetricDataRequest {
     /// <p>The timestamp, in UNIX Epoch time format, at which to end the reporting interval for the retrieval of historical metrics data. The time must be specified using an interval of 5 minutes, such as 11:00, 11:05, 11:10, and must be later than the start time timestamp.</p> <p>The time range between the start and end time must be less than 24 hours.</p>
     #[serde(rename = "EndTime")]
-    pub end_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub end_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The queues, up to 100, or channels, to use to filter the metrics returned. Metric data is retrieved only for the resources associated with the queues or channels included in the filter. You can include both queue IDs and queue ARNs in the same request. The only supported channel is <code>VOICE</code>.</p>
     #[serde(rename = "Filters")]
     pub filters: Filters,
     /// <p>The grouping applied to the metrics returned. For example, when results are grouped by queue, the metrics returned are grouped by queue. The values returned apply to the metrics for each queue rather than aggregated for all queues.</p> <p>The only supported grouping is <code>QUEUE</code>.</p> <p>If no grouping is specified, a summary of metrics for all queues is returned.</p>
     #[serde(rename = "Groupings")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub groupings: Option<Vec<String>>,
+    pub groupings: Option<Vec<Grouping>>,
     /// <p><p>The metrics to retrieve. Specify the name, unit, and statistic for each metric. The following historical metrics are available:</p> <dl> <dt>ABANDON<em>TIME</dt> <dd> <p>Unit: SECONDS</p> <p>Statistic: AVG</p> </dd> <dt>AFTER</em>CONTACT<em>WORK</em>TIME</dt> <dd> <p>Unit: SECONDS</p> <p>Statistic: AVG</p> </dd> <dt>API<em>CONTACTS</em>HANDLED</dt> <dd> <p>Unit: COUNT</p> <p>Statistic: SUM</p> </dd> <dt>CALLBACK<em>CONTACTS</em>HANDLED</dt> <dd> <p>Unit: COUNT</p> <p>Statistic: SUM</p> </dd> <dt>CONTACTS<em>ABANDONED</dt> <dd> <p>Unit: COUNT</p> <p>Statistic: SUM</p> </dd> <dt>CONTACTS</em>AGENT<em>HUNG</em>UP<em>FIRST</dt> <dd> <p>Unit: COUNT</p> <p>Statistic: SUM</p> </dd> <dt>CONTACTS</em>CONSULTED</dt> <dd> <p>Unit: COUNT</p> <p>Statistic: SUM</p> </dd> <dt>CONTACTS<em>HANDLED</dt> <dd> <p>Unit: COUNT</p> <p>Statistic: SUM</p> </dd> <dt>CONTACTS</em>HANDLED<em>INCOMING</dt> <dd> <p>Unit: COUNT</p> <p>Statistic: SUM</p> </dd> <dt>CONTACTS</em>HANDLED<em>OUTBOUND</dt> <dd> <p>Unit: COUNT</p> <p>Statistic: SUM</p> </dd> <dt>CONTACTS</em>HOLD<em>ABANDONS</dt> <dd> <p>Unit: COUNT</p> <p>Statistic: SUM</p> </dd> <dt>CONTACTS</em>MISSED</dt> <dd> <p>Unit: COUNT</p> <p>Statistic: SUM</p> </dd> <dt>CONTACTS<em>QUEUED</dt> <dd> <p>Unit: COUNT</p> <p>Statistic: SUM</p> </dd> <dt>CONTACTS</em>TRANSFERRED<em>IN</dt> <dd> <p>Unit: COUNT</p> <p>Statistic: SUM</p> </dd> <dt>CONTACTS</em>TRANSFERRED<em>IN</em>FROM<em>QUEUE</dt> <dd> <p>Unit: COUNT</p> <p>Statistic: SUM</p> </dd> <dt>CONTACTS</em>TRANSFERRED<em>OUT</dt> <dd> <p>Unit: COUNT</p> <p>Statistic: SUM</p> </dd> <dt>CONTACTS</em>TRANSFERRED<em>OUT</em>FROM<em>QUEUE</dt> <dd> <p>Unit: COUNT</p> <p>Statistic: SUM</p> </dd> <dt>HANDLE</em>TIME</dt> <dd> <p>Unit: SECONDS</p> <p>Statistic: AVG</p> </dd> <dt>HOLD<em>TIME</dt> <dd> <p>Unit: SECONDS</p> <p>Statistic: AVG</p> </dd> <dt>INTERACTION</em>AND<em>HOLD</em>TIME</dt> <dd> <p>Unit: SECONDS</p> <p>Statistic: AVG</p> </dd> <dt>INTERACTION<em>TIME</dt> <dd> <p>Unit: SECONDS</p> <p>Statistic: AVG</p> </dd> <dt>OCCUPANCY</dt> <dd> <p>Unit: PERCENT</p> <p>Statistic: AVG</p> </dd> <dt>QUEUE</em>ANSWER<em>TIME</dt> <dd> <p>Unit: SECONDS</p> <p>Statistic: AVG</p> </dd> <dt>QUEUED</em>TIME</dt> <dd> <p>Unit: SECONDS</p> <p>Statistic: MAX</p> </dd> <dt>SERVICE_LEVEL</dt> <dd> <p>Unit: PERCENT</p> <p>Statistic: AVG</p> <p>Threshold: Only &quot;Less than&quot; comparisons are supported, with the following service level thresholds: 15, 20, 25, 30, 45, 60, 90, 120, 180, 240, 300, 600</p> </dd> </dl></p>
     #[serde(rename = "HistoricalMetrics")]
     pub historical_metrics: Vec<HistoricalMetric>,
@@ -380,7 +863,12 @@ pub struct GetMetricDataRequest {
     pub next_token: Option<String>,
     /// <p>The timestamp, in UNIX Epoch time format, at which to start the reporting interval for the retrieval of historical metrics data. The time must be specified using a multiple of 5 minutes, such as 10:05, 10:10, 10:15.</p> <p>The start time cannot be earlier than 24 hours before the time of the request. Historical metrics are available only for 24 hours.</p>
     #[serde(rename = "StartTime")]
-    pub start_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub start_time: ::chrono::DateTime<::chrono::Utc>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -396,6 +884,109 @@ pub struct GetMetricDataResponse {
     pub next_token: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Grouping {
+    /// `QUEUE`
+    Queue,
+    /// `CHANNEL`
+    Channel,
+    /// `ROUTING_PROFILE`
+    RoutingProfile,
+    /// `ROUTING_STEP_EXPRESSION`
+    RoutingStepExpression,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Grouping {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Grouping::Queue => "QUEUE",
+            Grouping::Channel => "CHANNEL",
+            Grouping::RoutingProfile => "ROUTING_PROFILE",
+            Grouping::RoutingStepExpression => "ROUTING_STEP_EXPRESSION",
+            Grouping::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Grouping {
+    fn default() -> Grouping {
+        Grouping::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Grouping {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Grouping {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Grouping {
+    fn from(value: &'a str) -> Grouping {
+        match value {
+            "QUEUE" => Grouping::Queue,
+            "CHANNEL" => Grouping::Channel,
+            "ROUTING_PROFILE" => Grouping::RoutingProfile,
+            "ROUTING_STEP_EXPRESSION" => Grouping::RoutingStepExpression,
+            _ => Grouping::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Grouping {
+    fn from(value: String) -> Grouping {
+        match value.as_str() {
+            "QUEUE" => Grouping::Queue,
+            "CHANNEL" => Grouping::Channel,
+            "ROUTING_PROFILE" => Grouping::RoutingProfile,
+            "ROUTING_STEP_EXPRESSION" => Grouping::RoutingStepExpression,
+            _ => Grouping::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Grouping {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Grouping, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Grouping {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Grouping {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Grouping {
+    fn deserialize<D>(deserializer: D) -> Result<Grouping, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Contains information about a hierarchy group.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -516,11 +1107,11 @@ pub struct HistoricalMetric {
     /// <p>The name of the metric.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
+    pub name: Option<HistoricalMetricName>,
     /// <p>The statistic for the metric.</p>
     #[serde(rename = "Statistic")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub statistic: Option<String>,
+    pub statistic: Option<Statistic>,
     /// <p>The threshold for the metric, used with service level metrics.</p>
     #[serde(rename = "Threshold")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -528,7 +1119,7 @@ pub struct HistoricalMetric {
     /// <p>The unit for the metric.</p>
     #[serde(rename = "Unit")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub unit: Option<String>,
+    pub unit: Option<Unit>,
 }
 
 /// <p>Contains the data for a historical metric.</p>
@@ -545,6 +1136,227 @@ pub struct HistoricalMetricData {
     pub value: Option<f64>,
 }
 
+/// <p>The historical metric names.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum HistoricalMetricName {
+    /// `CONTACTS_QUEUED`
+    ContactsQueued,
+    /// `CONTACTS_HANDLED`
+    ContactsHandled,
+    /// `CONTACTS_ABANDONED`
+    ContactsAbandoned,
+    /// `CONTACTS_CONSULTED`
+    ContactsConsulted,
+    /// `CONTACTS_AGENT_HUNG_UP_FIRST`
+    ContactsAgentHungUpFirst,
+    /// `CONTACTS_HANDLED_INCOMING`
+    ContactsHandledIncoming,
+    /// `CONTACTS_HANDLED_OUTBOUND`
+    ContactsHandledOutbound,
+    /// `CONTACTS_HOLD_ABANDONS`
+    ContactsHoldAbandons,
+    /// `CONTACTS_TRANSFERRED_IN`
+    ContactsTransferredIn,
+    /// `CONTACTS_TRANSFERRED_OUT`
+    ContactsTransferredOut,
+    /// `CONTACTS_TRANSFERRED_IN_FROM_QUEUE`
+    ContactsTransferredInFromQueue,
+    /// `CONTACTS_TRANSFERRED_OUT_FROM_QUEUE`
+    ContactsTransferredOutFromQueue,
+    /// `CONTACTS_MISSED`
+    ContactsMissed,
+    /// `CALLBACK_CONTACTS_HANDLED`
+    CallbackContactsHandled,
+    /// `API_CONTACTS_HANDLED`
+    ApiContactsHandled,
+    /// `OCCUPANCY`
+    Occupancy,
+    /// `HANDLE_TIME`
+    HandleTime,
+    /// `AFTER_CONTACT_WORK_TIME`
+    AfterContactWorkTime,
+    /// `QUEUED_TIME`
+    QueuedTime,
+    /// `ABANDON_TIME`
+    AbandonTime,
+    /// `QUEUE_ANSWER_TIME`
+    QueueAnswerTime,
+    /// `HOLD_TIME`
+    HoldTime,
+    /// `INTERACTION_TIME`
+    InteractionTime,
+    /// `INTERACTION_AND_HOLD_TIME`
+    InteractionAndHoldTime,
+    /// `SERVICE_LEVEL`
+    ServiceLevel,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl HistoricalMetricName {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            HistoricalMetricName::ContactsQueued => "CONTACTS_QUEUED",
+            HistoricalMetricName::ContactsHandled => "CONTACTS_HANDLED",
+            HistoricalMetricName::ContactsAbandoned => "CONTACTS_ABANDONED",
+            HistoricalMetricName::ContactsConsulted => "CONTACTS_CONSULTED",
+            HistoricalMetricName::ContactsAgentHungUpFirst => "CONTACTS_AGENT_HUNG_UP_FIRST",
+            HistoricalMetricName::ContactsHandledIncoming => "CONTACTS_HANDLED_INCOMING",
+            HistoricalMetricName::ContactsHandledOutbound => "CONTACTS_HANDLED_OUTBOUND",
+            HistoricalMetricName::ContactsHoldAbandons => "CONTACTS_HOLD_ABANDONS",
+            HistoricalMetricName::ContactsTransferredIn => "CONTACTS_TRANSFERRED_IN",
+            HistoricalMetricName::ContactsTransferredOut => "CONTACTS_TRANSFERRED_OUT",
+            HistoricalMetricName::ContactsTransferredInFromQueue => {
+                "CONTACTS_TRANSFERRED_IN_FROM_QUEUE"
+            }
+            HistoricalMetricName::ContactsTransferredOutFromQueue => {
+                "CONTACTS_TRANSFERRED_OUT_FROM_QUEUE"
+            }
+            HistoricalMetricName::ContactsMissed => "CONTACTS_MISSED",
+            HistoricalMetricName::CallbackContactsHandled => "CALLBACK_CONTACTS_HANDLED",
+            HistoricalMetricName::ApiContactsHandled => "API_CONTACTS_HANDLED",
+            HistoricalMetricName::Occupancy => "OCCUPANCY",
+            HistoricalMetricName::HandleTime => "HANDLE_TIME",
+            HistoricalMetricName::AfterContactWorkTime => "AFTER_CONTACT_WORK_TIME",
+            HistoricalMetricName::QueuedTime => "QUEUED_TIME",
+            HistoricalMetricName::AbandonTime => "ABANDON_TIME",
+            HistoricalMetricName::QueueAnswerTime => "QUEUE_ANSWER_TIME",
+            HistoricalMetricName::HoldTime => "HOLD_TIME",
+            HistoricalMetricName::InteractionTime => "INTERACTION_TIME",
+            HistoricalMetricName::InteractionAndHoldTime => "INTERACTION_AND_HOLD_TIME",
+            HistoricalMetricName::ServiceLevel => "SERVICE_LEVEL",
+            HistoricalMetricName::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for HistoricalMetricName {
+    fn default() -> HistoricalMetricName {
+        HistoricalMetricName::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for HistoricalMetricName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for HistoricalMetricName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for HistoricalMetricName {
+    fn from(value: &'a str) -> HistoricalMetricName {
+        match value {
+            "CONTACTS_QUEUED" => HistoricalMetricName::ContactsQueued,
+            "CONTACTS_HANDLED" => HistoricalMetricName::ContactsHandled,
+            "CONTACTS_ABANDONED" => HistoricalMetricName::ContactsAbandoned,
+            "CONTACTS_CONSULTED" => HistoricalMetricName::ContactsConsulted,
+            "CONTACTS_AGENT_HUNG_UP_FIRST" => HistoricalMetricName::ContactsAgentHungUpFirst,
+            "CONTACTS_HANDLED_INCOMING" => HistoricalMetricName::ContactsHandledIncoming,
+            "CONTACTS_HANDLED_OUTBOUND" => HistoricalMetricName::ContactsHandledOutbound,
+            "CONTACTS_HOLD_ABANDONS" => HistoricalMetricName::ContactsHoldAbandons,
+            "CONTACTS_TRANSFERRED_IN" => HistoricalMetricName::ContactsTransferredIn,
+            "CONTACTS_TRANSFERRED_OUT" => HistoricalMetricName::ContactsTransferredOut,
+            "CONTACTS_TRANSFERRED_IN_FROM_QUEUE" => {
+                HistoricalMetricName::ContactsTransferredInFromQueue
+            }
+            "CONTACTS_TRANSFERRED_OUT_FROM_QUEUE" => {
+                HistoricalMetricName::ContactsTransferredOutFromQueue
+            }
+            "CONTACTS_MISSED" => HistoricalMetricName::ContactsMissed,
+            "CALLBACK_CONTACTS_HANDLED" => HistoricalMetricName::CallbackContactsHandled,
+            "API_CONTACTS_HANDLED" => HistoricalMetricName::ApiContactsHandled,
+            "OCCUPANCY" => HistoricalMetricName::Occupancy,
+            "HANDLE_TIME" => HistoricalMetricName::HandleTime,
+            "AFTER_CONTACT_WORK_TIME" => HistoricalMetricName::AfterContactWorkTime,
+            "QUEUED_TIME" => HistoricalMetricName::QueuedTime,
+            "ABANDON_TIME" => HistoricalMetricName::AbandonTime,
+            "QUEUE_ANSWER_TIME" => HistoricalMetricName::QueueAnswerTime,
+            "HOLD_TIME" => HistoricalMetricName::HoldTime,
+            "INTERACTION_TIME" => HistoricalMetricName::InteractionTime,
+            "INTERACTION_AND_HOLD_TIME" => HistoricalMetricName::InteractionAndHoldTime,
+            "SERVICE_LEVEL" => HistoricalMetricName::ServiceLevel,
+            _ => HistoricalMetricName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for HistoricalMetricName {
+    fn from(value: String) -> HistoricalMetricName {
+        match value.as_str() {
+            "CONTACTS_QUEUED" => HistoricalMetricName::ContactsQueued,
+            "CONTACTS_HANDLED" => HistoricalMetricName::ContactsHandled,
+            "CONTACTS_ABANDONED" => HistoricalMetricName::ContactsAbandoned,
+            "CONTACTS_CONSULTED" => HistoricalMetricName::ContactsConsulted,
+            "CONTACTS_AGENT_HUNG_UP_FIRST" => HistoricalMetricName::ContactsAgentHungUpFirst,
+            "CONTACTS_HANDLED_INCOMING" => HistoricalMetricName::ContactsHandledIncoming,
+            "CONTACTS_HANDLED_OUTBOUND" => HistoricalMetricName::ContactsHandledOutbound,
+            "CONTACTS_HOLD_ABANDONS" => HistoricalMetricName::ContactsHoldAbandons,
+            "CONTACTS_TRANSFERRED_IN" => HistoricalMetricName::ContactsTransferredIn,
+            "CONTACTS_TRANSFERRED_OUT" => HistoricalMetricName::ContactsTransferredOut,
+            "CONTACTS_TRANSFERRED_IN_FROM_QUEUE" => {
+                HistoricalMetricName::ContactsTransferredInFromQueue
+            }
+            "CONTACTS_TRANSFERRED_OUT_FROM_QUEUE" => {
+                HistoricalMetricName::ContactsTransferredOutFromQueue
+            }
+            "CONTACTS_MISSED" => HistoricalMetricName::ContactsMissed,
+            "CALLBACK_CONTACTS_HANDLED" => HistoricalMetricName::CallbackContactsHandled,
+            "API_CONTACTS_HANDLED" => HistoricalMetricName::ApiContactsHandled,
+            "OCCUPANCY" => HistoricalMetricName::Occupancy,
+            "HANDLE_TIME" => HistoricalMetricName::HandleTime,
+            "AFTER_CONTACT_WORK_TIME" => HistoricalMetricName::AfterContactWorkTime,
+            "QUEUED_TIME" => HistoricalMetricName::QueuedTime,
+            "ABANDON_TIME" => HistoricalMetricName::AbandonTime,
+            "QUEUE_ANSWER_TIME" => HistoricalMetricName::QueueAnswerTime,
+            "HOLD_TIME" => HistoricalMetricName::HoldTime,
+            "INTERACTION_TIME" => HistoricalMetricName::InteractionTime,
+            "INTERACTION_AND_HOLD_TIME" => HistoricalMetricName::InteractionAndHoldTime,
+            "SERVICE_LEVEL" => HistoricalMetricName::ServiceLevel,
+            _ => HistoricalMetricName::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for HistoricalMetricName {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<HistoricalMetricName, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for HistoricalMetricName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for HistoricalMetricName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for HistoricalMetricName {
+    fn deserialize<D>(deserializer: D) -> Result<HistoricalMetricName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Contains information about the historical metrics retrieved.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -583,7 +1395,7 @@ pub struct ListContactFlowsRequest {
     /// <p>The type of contact flow.</p>
     #[serde(rename = "ContactFlowTypes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub contact_flow_types: Option<Vec<String>>,
+    pub contact_flow_types: Option<Vec<ContactFlowType>>,
     /// <p>The identifier of the Amazon Connect instance.</p>
     #[serde(rename = "InstanceId")]
     pub instance_id: String,
@@ -656,11 +1468,11 @@ pub struct ListPhoneNumbersRequest {
     /// <p>The ISO country code.</p>
     #[serde(rename = "PhoneNumberCountryCodes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub phone_number_country_codes: Option<Vec<String>>,
+    pub phone_number_country_codes: Option<Vec<PhoneNumberCountryCode>>,
     /// <p>The type of phone number.</p>
     #[serde(rename = "PhoneNumberTypes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub phone_number_types: Option<Vec<String>>,
+    pub phone_number_types: Option<Vec<PhoneNumberType>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -693,7 +1505,7 @@ pub struct ListQueuesRequest {
     /// <p>The type of queue.</p>
     #[serde(rename = "QueueTypes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub queue_types: Option<Vec<String>>,
+    pub queue_types: Option<Vec<QueueType>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -829,54 +1641,1533 @@ pub struct ListUsersRequest {
     pub next_token: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct ListUsersResponse {
-    /// <p>If there are additional results, this is the token for the next set of results.</p>
-    #[serde(rename = "NextToken")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_token: Option<String>,
-    /// <p>Information about the users.</p>
-    #[serde(rename = "UserSummaryList")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub user_summary_list: Option<Vec<UserSummary>>,
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct ListUsersResponse {
+    /// <p>If there are additional results, this is the token for the next set of results.</p>
+    #[serde(rename = "NextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+    /// <p>Information about the users.</p>
+    #[serde(rename = "UserSummaryList")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_summary_list: Option<Vec<UserSummary>>,
+}
+
+/// <p>The customer's details.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct ParticipantDetails {
+    /// <p>Display name of the participant.</p>
+    #[serde(rename = "DisplayName")]
+    pub display_name: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PhoneNumberCountryCode {
+    /// `AF`
+    Af,
+    /// `AL`
+    Al,
+    /// `DZ`
+    Dz,
+    /// `AS`
+    As,
+    /// `AD`
+    Ad,
+    /// `AO`
+    Ao,
+    /// `AI`
+    Ai,
+    /// `AQ`
+    Aq,
+    /// `AG`
+    Ag,
+    /// `AR`
+    Ar,
+    /// `AM`
+    Am,
+    /// `AW`
+    Aw,
+    /// `AU`
+    Au,
+    /// `AT`
+    At,
+    /// `AZ`
+    Az,
+    /// `BS`
+    Bs,
+    /// `BH`
+    Bh,
+    /// `BD`
+    Bd,
+    /// `BB`
+    Bb,
+    /// `BY`
+    By,
+    /// `BE`
+    Be,
+    /// `BZ`
+    Bz,
+    /// `BJ`
+    Bj,
+    /// `BM`
+    Bm,
+    /// `BT`
+    Bt,
+    /// `BO`
+    Bo,
+    /// `BA`
+    Ba,
+    /// `BW`
+    Bw,
+    /// `BR`
+    Br,
+    /// `IO`
+    Io,
+    /// `VG`
+    Vg,
+    /// `BN`
+    Bn,
+    /// `BG`
+    Bg,
+    /// `BF`
+    Bf,
+    /// `BI`
+    Bi,
+    /// `KH`
+    Kh,
+    /// `CM`
+    Cm,
+    /// `CA`
+    Ca,
+    /// `CV`
+    Cv,
+    /// `KY`
+    Ky,
+    /// `CF`
+    Cf,
+    /// `TD`
+    Td,
+    /// `CL`
+    Cl,
+    /// `CN`
+    Cn,
+    /// `CX`
+    Cx,
+    /// `CC`
+    Cc,
+    /// `CO`
+    Co,
+    /// `KM`
+    Km,
+    /// `CK`
+    Ck,
+    /// `CR`
+    Cr,
+    /// `HR`
+    Hr,
+    /// `CU`
+    Cu,
+    /// `CW`
+    Cw,
+    /// `CY`
+    Cy,
+    /// `CZ`
+    Cz,
+    /// `CD`
+    Cd,
+    /// `DK`
+    Dk,
+    /// `DJ`
+    Dj,
+    /// `DM`
+    Dm,
+    /// `DO`
+    Do,
+    /// `TL`
+    Tl,
+    /// `EC`
+    Ec,
+    /// `EG`
+    Eg,
+    /// `SV`
+    Sv,
+    /// `GQ`
+    Gq,
+    /// `ER`
+    Er,
+    /// `EE`
+    Ee,
+    /// `ET`
+    Et,
+    /// `FK`
+    Fk,
+    /// `FO`
+    Fo,
+    /// `FJ`
+    Fj,
+    /// `FI`
+    Fi,
+    /// `FR`
+    Fr,
+    /// `PF`
+    Pf,
+    /// `GA`
+    Ga,
+    /// `GM`
+    Gm,
+    /// `GE`
+    Ge,
+    /// `DE`
+    De,
+    /// `GH`
+    Gh,
+    /// `GI`
+    Gi,
+    /// `GR`
+    Gr,
+    /// `GL`
+    Gl,
+    /// `GD`
+    Gd,
+    /// `GU`
+    Gu,
+    /// `GT`
+    Gt,
+    /// `GG`
+    Gg,
+    /// `GN`
+    Gn,
+    /// `GW`
+    Gw,
+    /// `GY`
+    Gy,
+    /// `HT`
+    Ht,
+    /// `HN`
+    Hn,
+    /// `HK`
+    Hk,
+    /// `HU`
+    Hu,
+    /// `IS`
+    Is,
+    /// `IN`
+    In,
+    /// `ID`
+    Id,
+    /// `IR`
+    Ir,
+    /// `IQ`
+    Iq,
+    /// `IE`
+    Ie,
+    /// `IM`
+    Im,
+    /// `IL`
+    Il,
+    /// `IT`
+    It,
+    /// `CI`
+    Ci,
+    /// `JM`
+    Jm,
+    /// `JP`
+    Jp,
+    /// `JE`
+    Je,
+    /// `JO`
+    Jo,
+    /// `KZ`
+    Kz,
+    /// `KE`
+    Ke,
+    /// `KI`
+    Ki,
+    /// `KW`
+    Kw,
+    /// `KG`
+    Kg,
+    /// `LA`
+    La,
+    /// `LV`
+    Lv,
+    /// `LB`
+    Lb,
+    /// `LS`
+    Ls,
+    /// `LR`
+    Lr,
+    /// `LY`
+    Ly,
+    /// `LI`
+    Li,
+    /// `LT`
+    Lt,
+    /// `LU`
+    Lu,
+    /// `MO`
+    Mo,
+    /// `MK`
+    Mk,
+    /// `MG`
+    Mg,
+    /// `MW`
+    Mw,
+    /// `MY`
+    My,
+    /// `MV`
+    Mv,
+    /// `ML`
+    Ml,
+    /// `MT`
+    Mt,
+    /// `MH`
+    Mh,
+    /// `MR`
+    Mr,
+    /// `MU`
+    Mu,
+    /// `YT`
+    Yt,
+    /// `MX`
+    Mx,
+    /// `FM`
+    Fm,
+    /// `MD`
+    Md,
+    /// `MC`
+    Mc,
+    /// `MN`
+    Mn,
+    /// `ME`
+    Me,
+    /// `MS`
+    Ms,
+    /// `MA`
+    Ma,
+    /// `MZ`
+    Mz,
+    /// `MM`
+    Mm,
+    /// `NA`
+    Na,
+    /// `NR`
+    Nr,
+    /// `NP`
+    Np,
+    /// `NL`
+    Nl,
+    /// `AN`
+    An,
+    /// `NC`
+    Nc,
+    /// `NZ`
+    Nz,
+    /// `NI`
+    Ni,
+    /// `NE`
+    Ne,
+    /// `NG`
+    Ng,
+    /// `NU`
+    Nu,
+    /// `KP`
+    Kp,
+    /// `MP`
+    Mp,
+    /// `NO`
+    No,
+    /// `OM`
+    Om,
+    /// `PK`
+    Pk,
+    /// `PW`
+    Pw,
+    /// `PA`
+    Pa,
+    /// `PG`
+    Pg,
+    /// `PY`
+    Py,
+    /// `PE`
+    Pe,
+    /// `PH`
+    Ph,
+    /// `PN`
+    Pn,
+    /// `PL`
+    Pl,
+    /// `PT`
+    Pt,
+    /// `PR`
+    Pr,
+    /// `QA`
+    Qa,
+    /// `CG`
+    Cg,
+    /// `RE`
+    Re,
+    /// `RO`
+    Ro,
+    /// `RU`
+    Ru,
+    /// `RW`
+    Rw,
+    /// `BL`
+    Bl,
+    /// `SH`
+    Sh,
+    /// `KN`
+    Kn,
+    /// `LC`
+    Lc,
+    /// `MF`
+    Mf,
+    /// `PM`
+    Pm,
+    /// `VC`
+    Vc,
+    /// `WS`
+    Ws,
+    /// `SM`
+    Sm,
+    /// `ST`
+    St,
+    /// `SA`
+    Sa,
+    /// `SN`
+    Sn,
+    /// `RS`
+    Rs,
+    /// `SC`
+    Sc,
+    /// `SL`
+    Sl,
+    /// `SG`
+    Sg,
+    /// `SX`
+    Sx,
+    /// `SK`
+    Sk,
+    /// `SI`
+    Si,
+    /// `SB`
+    Sb,
+    /// `SO`
+    So,
+    /// `ZA`
+    Za,
+    /// `KR`
+    Kr,
+    /// `ES`
+    Es,
+    /// `LK`
+    Lk,
+    /// `SD`
+    Sd,
+    /// `SR`
+    Sr,
+    /// `SJ`
+    Sj,
+    /// `SZ`
+    Sz,
+    /// `SE`
+    Se,
+    /// `CH`
+    Ch,
+    /// `SY`
+    Sy,
+    /// `TW`
+    Tw,
+    /// `TJ`
+    Tj,
+    /// `TZ`
+    Tz,
+    /// `TH`
+    Th,
+    /// `TG`
+    Tg,
+    /// `TK`
+    Tk,
+    /// `TO`
+    To,
+    /// `TT`
+    Tt,
+    /// `TN`
+    Tn,
+    /// `TR`
+    Tr,
+    /// `TM`
+    Tm,
+    /// `TC`
+    Tc,
+    /// `TV`
+    Tv,
+    /// `VI`
+    Vi,
+    /// `UG`
+    Ug,
+    /// `UA`
+    Ua,
+    /// `AE`
+    Ae,
+    /// `GB`
+    Gb,
+    /// `US`
+    Us,
+    /// `UY`
+    Uy,
+    /// `UZ`
+    Uz,
+    /// `VU`
+    Vu,
+    /// `VA`
+    Va,
+    /// `VE`
+    Ve,
+    /// `VN`
+    Vn,
+    /// `WF`
+    Wf,
+    /// `EH`
+    Eh,
+    /// `YE`
+    Ye,
+    /// `ZM`
+    Zm,
+    /// `ZW`
+    Zw,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PhoneNumberCountryCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PhoneNumberCountryCode::Af => "AF",
+            PhoneNumberCountryCode::Al => "AL",
+            PhoneNumberCountryCode::Dz => "DZ",
+            PhoneNumberCountryCode::As => "AS",
+            PhoneNumberCountryCode::Ad => "AD",
+            PhoneNumberCountryCode::Ao => "AO",
+            PhoneNumberCountryCode::Ai => "AI",
+            PhoneNumberCountryCode::Aq => "AQ",
+            PhoneNumberCountryCode::Ag => "AG",
+            PhoneNumberCountryCode::Ar => "AR",
+            PhoneNumberCountryCode::Am => "AM",
+            PhoneNumberCountryCode::Aw => "AW",
+            PhoneNumberCountryCode::Au => "AU",
+            PhoneNumberCountryCode::At => "AT",
+            PhoneNumberCountryCode::Az => "AZ",
+            PhoneNumberCountryCode::Bs => "BS",
+            PhoneNumberCountryCode::Bh => "BH",
+            PhoneNumberCountryCode::Bd => "BD",
+            PhoneNumberCountryCode::Bb => "BB",
+            PhoneNumberCountryCode::By => "BY",
+            PhoneNumberCountryCode::Be => "BE",
+            PhoneNumberCountryCode::Bz => "BZ",
+            PhoneNumberCountryCode::Bj => "BJ",
+            PhoneNumberCountryCode::Bm => "BM",
+            PhoneNumberCountryCode::Bt => "BT",
+            PhoneNumberCountryCode::Bo => "BO",
+            PhoneNumberCountryCode::Ba => "BA",
+            PhoneNumberCountryCode::Bw => "BW",
+            PhoneNumberCountryCode::Br => "BR",
+            PhoneNumberCountryCode::Io => "IO",
+            PhoneNumberCountryCode::Vg => "VG",
+            PhoneNumberCountryCode::Bn => "BN",
+            PhoneNumberCountryCode::Bg => "BG",
+            PhoneNumberCountryCode::Bf => "BF",
+            PhoneNumberCountryCode::Bi => "BI",
+            PhoneNumberCountryCode::Kh => "KH",
+            PhoneNumberCountryCode::Cm => "CM",
+            PhoneNumberCountryCode::Ca => "CA",
+            PhoneNumberCountryCode::Cv => "CV",
+            PhoneNumberCountryCode::Ky => "KY",
+            PhoneNumberCountryCode::Cf => "CF",
+            PhoneNumberCountryCode::Td => "TD",
+            PhoneNumberCountryCode::Cl => "CL",
+            PhoneNumberCountryCode::Cn => "CN",
+            PhoneNumberCountryCode::Cx => "CX",
+            PhoneNumberCountryCode::Cc => "CC",
+            PhoneNumberCountryCode::Co => "CO",
+            PhoneNumberCountryCode::Km => "KM",
+            PhoneNumberCountryCode::Ck => "CK",
+            PhoneNumberCountryCode::Cr => "CR",
+            PhoneNumberCountryCode::Hr => "HR",
+            PhoneNumberCountryCode::Cu => "CU",
+            PhoneNumberCountryCode::Cw => "CW",
+            PhoneNumberCountryCode::Cy => "CY",
+            PhoneNumberCountryCode::Cz => "CZ",
+            PhoneNumberCountryCode::Cd => "CD",
+            PhoneNumberCountryCode::Dk => "DK",
+            PhoneNumberCountryCode::Dj => "DJ",
+            PhoneNumberCountryCode::Dm => "DM",
+            PhoneNumberCountryCode::Do => "DO",
+            PhoneNumberCountryCode::Tl => "TL",
+            PhoneNumberCountryCode::Ec => "EC",
+            PhoneNumberCountryCode::Eg => "EG",
+            PhoneNumberCountryCode::Sv => "SV",
+            PhoneNumberCountryCode::Gq => "GQ",
+            PhoneNumberCountryCode::Er => "ER",
+            PhoneNumberCountryCode::Ee => "EE",
+            PhoneNumberCountryCode::Et => "ET",
+            PhoneNumberCountryCode::Fk => "FK",
+            PhoneNumberCountryCode::Fo => "FO",
+            PhoneNumberCountryCode::Fj => "FJ",
+            PhoneNumberCountryCode::Fi => "FI",
+            PhoneNumberCountryCode::Fr => "FR",
+            PhoneNumberCountryCode::Pf => "PF",
+            PhoneNumberCountryCode::Ga => "GA",
+            PhoneNumberCountryCode::Gm => "GM",
+            PhoneNumberCountryCode::Ge => "GE",
+            PhoneNumberCountryCode::De => "DE",
+            PhoneNumberCountryCode::Gh => "GH",
+            PhoneNumberCountryCode::Gi => "GI",
+            PhoneNumberCountryCode::Gr => "GR",
+            PhoneNumberCountryCode::Gl => "GL",
+            PhoneNumberCountryCode::Gd => "GD",
+            PhoneNumberCountryCode::Gu => "GU",
+            PhoneNumberCountryCode::Gt => "GT",
+            PhoneNumberCountryCode::Gg => "GG",
+            PhoneNumberCountryCode::Gn => "GN",
+            PhoneNumberCountryCode::Gw => "GW",
+            PhoneNumberCountryCode::Gy => "GY",
+            PhoneNumberCountryCode::Ht => "HT",
+            PhoneNumberCountryCode::Hn => "HN",
+            PhoneNumberCountryCode::Hk => "HK",
+            PhoneNumberCountryCode::Hu => "HU",
+            PhoneNumberCountryCode::Is => "IS",
+            PhoneNumberCountryCode::In => "IN",
+            PhoneNumberCountryCode::Id => "ID",
+            PhoneNumberCountryCode::Ir => "IR",
+            PhoneNumberCountryCode::Iq => "IQ",
+            PhoneNumberCountryCode::Ie => "IE",
+            PhoneNumberCountryCode::Im => "IM",
+            PhoneNumberCountryCode::Il => "IL",
+            PhoneNumberCountryCode::It => "IT",
+            PhoneNumberCountryCode::Ci => "CI",
+            PhoneNumberCountryCode::Jm => "JM",
+            PhoneNumberCountryCode::Jp => "JP",
+            PhoneNumberCountryCode::Je => "JE",
+            PhoneNumberCountryCode::Jo => "JO",
+            PhoneNumberCountryCode::Kz => "KZ",
+            PhoneNumberCountryCode::Ke => "KE",
+            PhoneNumberCountryCode::Ki => "KI",
+            PhoneNumberCountryCode::Kw => "KW",
+            PhoneNumberCountryCode::Kg => "KG",
+            PhoneNumberCountryCode::La => "LA",
+            PhoneNumberCountryCode::Lv => "LV",
+            PhoneNumberCountryCode::Lb => "LB",
+            PhoneNumberCountryCode::Ls => "LS",
+            PhoneNumberCountryCode::Lr => "LR",
+            PhoneNumberCountryCode::Ly => "LY",
+            PhoneNumberCountryCode::Li => "LI",
+            PhoneNumberCountryCode::Lt => "LT",
+            PhoneNumberCountryCode::Lu => "LU",
+            PhoneNumberCountryCode::Mo => "MO",
+            PhoneNumberCountryCode::Mk => "MK",
+            PhoneNumberCountryCode::Mg => "MG",
+            PhoneNumberCountryCode::Mw => "MW",
+            PhoneNumberCountryCode::My => "MY",
+            PhoneNumberCountryCode::Mv => "MV",
+            PhoneNumberCountryCode::Ml => "ML",
+            PhoneNumberCountryCode::Mt => "MT",
+            PhoneNumberCountryCode::Mh => "MH",
+            PhoneNumberCountryCode::Mr => "MR",
+            PhoneNumberCountryCode::Mu => "MU",
+            PhoneNumberCountryCode::Yt => "YT",
+            PhoneNumberCountryCode::Mx => "MX",
+            PhoneNumberCountryCode::Fm => "FM",
+            PhoneNumberCountryCode::Md => "MD",
+            PhoneNumberCountryCode::Mc => "MC",
+            PhoneNumberCountryCode::Mn => "MN",
+            PhoneNumberCountryCode::Me => "ME",
+            PhoneNumberCountryCode::Ms => "MS",
+            PhoneNumberCountryCode::Ma => "MA",
+            PhoneNumberCountryCode::Mz => "MZ",
+            PhoneNumberCountryCode::Mm => "MM",
+            PhoneNumberCountryCode::Na => "NA",
+            PhoneNumberCountryCode::Nr => "NR",
+            PhoneNumberCountryCode::Np => "NP",
+            PhoneNumberCountryCode::Nl => "NL",
+            PhoneNumberCountryCode::An => "AN",
+            PhoneNumberCountryCode::Nc => "NC",
+            PhoneNumberCountryCode::Nz => "NZ",
+            PhoneNumberCountryCode::Ni => "NI",
+            PhoneNumberCountryCode::Ne => "NE",
+            PhoneNumberCountryCode::Ng => "NG",
+            PhoneNumberCountryCode::Nu => "NU",
+            PhoneNumberCountryCode::Kp => "KP",
+            PhoneNumberCountryCode::Mp => "MP",
+            PhoneNumberCountryCode::No => "NO",
+            PhoneNumberCountryCode::Om => "OM",
+            PhoneNumberCountryCode::Pk => "PK",
+            PhoneNumberCountryCode::Pw => "PW",
+            PhoneNumberCountryCode::Pa => "PA",
+            PhoneNumberCountryCode::Pg => "PG",
+            PhoneNumberCountryCode::Py => "PY",
+            PhoneNumberCountryCode::Pe => "PE",
+            PhoneNumberCountryCode::Ph => "PH",
+            PhoneNumberCountryCode::Pn => "PN",
+            PhoneNumberCountryCode::Pl => "PL",
+            PhoneNumberCountryCode::Pt => "PT",
+            PhoneNumberCountryCode::Pr => "PR",
+            PhoneNumberCountryCode::Qa => "QA",
+            PhoneNumberCountryCode::Cg => "CG",
+            PhoneNumberCountryCode::Re => "RE",
+            PhoneNumberCountryCode::Ro => "RO",
+            PhoneNumberCountryCode::Ru => "RU",
+            PhoneNumberCountryCode::Rw => "RW",
+            PhoneNumberCountryCode::Bl => "BL",
+            PhoneNumberCountryCode::Sh => "SH",
+            PhoneNumberCountryCode::Kn => "KN",
+            PhoneNumberCountryCode::Lc => "LC",
+            PhoneNumberCountryCode::Mf => "MF",
+            PhoneNumberCountryCode::Pm => "PM",
+            PhoneNumberCountryCode::Vc => "VC",
+            PhoneNumberCountryCode::Ws => "WS",
+            PhoneNumberCountryCode::Sm => "SM",
+            PhoneNumberCountryCode::St => "ST",
+            PhoneNumberCountryCode::Sa => "SA",
+            PhoneNumberCountryCode::Sn => "SN",
+            PhoneNumberCountryCode::Rs => "RS",
+            PhoneNumberCountryCode::Sc => "SC",
+            PhoneNumberCountryCode::Sl => "SL",
+            PhoneNumberCountryCode::Sg => "SG",
+            PhoneNumberCountryCode::Sx => "SX",
+            PhoneNumberCountryCode::Sk => "SK",
+            PhoneNumberCountryCode::Si => "SI",
+            PhoneNumberCountryCode::Sb => "SB",
+            PhoneNumberCountryCode::So => "SO",
+            PhoneNumberCountryCode::Za => "ZA",
+            PhoneNumberCountryCode::Kr => "KR",
+            PhoneNumberCountryCode::Es => "ES",
+            PhoneNumberCountryCode::Lk => "LK",
+            PhoneNumberCountryCode::Sd => "SD",
+            PhoneNumberCountryCode::Sr => "SR",
+            PhoneNumberCountryCode::Sj => "SJ",
+            PhoneNumberCountryCode::Sz => "SZ",
+            PhoneNumberCountryCode::Se => "SE",
+            PhoneNumberCountryCode::Ch => "CH",
+            PhoneNumberCountryCode::Sy => "SY",
+            PhoneNumberCountryCode::Tw => "TW",
+            PhoneNumberCountryCode::Tj => "TJ",
+            PhoneNumberCountryCode::Tz => "TZ",
+            PhoneNumberCountryCode::Th => "TH",
+            PhoneNumberCountryCode::Tg => "TG",
+            PhoneNumberCountryCode::Tk => "TK",
+            PhoneNumberCountryCode::To => "TO",
+            PhoneNumberCountryCode::Tt => "TT",
+            PhoneNumberCountryCode::Tn => "TN",
+            PhoneNumberCountryCode::Tr => "TR",
+            PhoneNumberCountryCode::Tm => "TM",
+            PhoneNumberCountryCode::Tc => "TC",
+            PhoneNumberCountryCode::Tv => "TV",
+            PhoneNumberCountryCode::Vi => "VI",
+            PhoneNumberCountryCode::Ug => "UG",
+            PhoneNumberCountryCode::Ua => "UA",
+            PhoneNumberCountryCode::Ae => "AE",
+            PhoneNumberCountryCode::Gb => "GB",
+            PhoneNumberCountryCode::Us => "US",
+            PhoneNumberCountryCode::Uy => "UY",
+            PhoneNumberCountryCode::Uz => "UZ",
+            PhoneNumberCountryCode::Vu => "VU",
+            PhoneNumberCountryCode::Va => "VA",
+            PhoneNumberCountryCode::Ve => "VE",
+            PhoneNumberCountryCode::Vn => "VN",
+            PhoneNumberCountryCode::Wf => "WF",
+            PhoneNumberCountryCode::Eh => "EH",
+            PhoneNumberCountryCode::Ye => "YE",
+            PhoneNumberCountryCode::Zm => "ZM",
+            PhoneNumberCountryCode::Zw => "ZW",
+            PhoneNumberCountryCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PhoneNumberCountryCode {
+    fn default() -> PhoneNumberCountryCode {
+        PhoneNumberCountryCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PhoneNumberCountryCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PhoneNumberCountryCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PhoneNumberCountryCode {
+    fn from(value: &'a str) -> PhoneNumberCountryCode {
+        match value {
+            "AF" => PhoneNumberCountryCode::Af,
+            "AL" => PhoneNumberCountryCode::Al,
+            "DZ" => PhoneNumberCountryCode::Dz,
+            "AS" => PhoneNumberCountryCode::As,
+            "AD" => PhoneNumberCountryCode::Ad,
+            "AO" => PhoneNumberCountryCode::Ao,
+            "AI" => PhoneNumberCountryCode::Ai,
+            "AQ" => PhoneNumberCountryCode::Aq,
+            "AG" => PhoneNumberCountryCode::Ag,
+            "AR" => PhoneNumberCountryCode::Ar,
+            "AM" => PhoneNumberCountryCode::Am,
+            "AW" => PhoneNumberCountryCode::Aw,
+            "AU" => PhoneNumberCountryCode::Au,
+            "AT" => PhoneNumberCountryCode::At,
+            "AZ" => PhoneNumberCountryCode::Az,
+            "BS" => PhoneNumberCountryCode::Bs,
+            "BH" => PhoneNumberCountryCode::Bh,
+            "BD" => PhoneNumberCountryCode::Bd,
+            "BB" => PhoneNumberCountryCode::Bb,
+            "BY" => PhoneNumberCountryCode::By,
+            "BE" => PhoneNumberCountryCode::Be,
+            "BZ" => PhoneNumberCountryCode::Bz,
+            "BJ" => PhoneNumberCountryCode::Bj,
+            "BM" => PhoneNumberCountryCode::Bm,
+            "BT" => PhoneNumberCountryCode::Bt,
+            "BO" => PhoneNumberCountryCode::Bo,
+            "BA" => PhoneNumberCountryCode::Ba,
+            "BW" => PhoneNumberCountryCode::Bw,
+            "BR" => PhoneNumberCountryCode::Br,
+            "IO" => PhoneNumberCountryCode::Io,
+            "VG" => PhoneNumberCountryCode::Vg,
+            "BN" => PhoneNumberCountryCode::Bn,
+            "BG" => PhoneNumberCountryCode::Bg,
+            "BF" => PhoneNumberCountryCode::Bf,
+            "BI" => PhoneNumberCountryCode::Bi,
+            "KH" => PhoneNumberCountryCode::Kh,
+            "CM" => PhoneNumberCountryCode::Cm,
+            "CA" => PhoneNumberCountryCode::Ca,
+            "CV" => PhoneNumberCountryCode::Cv,
+            "KY" => PhoneNumberCountryCode::Ky,
+            "CF" => PhoneNumberCountryCode::Cf,
+            "TD" => PhoneNumberCountryCode::Td,
+            "CL" => PhoneNumberCountryCode::Cl,
+            "CN" => PhoneNumberCountryCode::Cn,
+            "CX" => PhoneNumberCountryCode::Cx,
+            "CC" => PhoneNumberCountryCode::Cc,
+            "CO" => PhoneNumberCountryCode::Co,
+            "KM" => PhoneNumberCountryCode::Km,
+            "CK" => PhoneNumberCountryCode::Ck,
+            "CR" => PhoneNumberCountryCode::Cr,
+            "HR" => PhoneNumberCountryCode::Hr,
+            "CU" => PhoneNumberCountryCode::Cu,
+            "CW" => PhoneNumberCountryCode::Cw,
+            "CY" => PhoneNumberCountryCode::Cy,
+            "CZ" => PhoneNumberCountryCode::Cz,
+            "CD" => PhoneNumberCountryCode::Cd,
+            "DK" => PhoneNumberCountryCode::Dk,
+            "DJ" => PhoneNumberCountryCode::Dj,
+            "DM" => PhoneNumberCountryCode::Dm,
+            "DO" => PhoneNumberCountryCode::Do,
+            "TL" => PhoneNumberCountryCode::Tl,
+            "EC" => PhoneNumberCountryCode::Ec,
+            "EG" => PhoneNumberCountryCode::Eg,
+            "SV" => PhoneNumberCountryCode::Sv,
+            "GQ" => PhoneNumberCountryCode::Gq,
+            "ER" => PhoneNumberCountryCode::Er,
+            "EE" => PhoneNumberCountryCode::Ee,
+            "ET" => PhoneNumberCountryCode::Et,
+            "FK" => PhoneNumberCountryCode::Fk,
+            "FO" => PhoneNumberCountryCode::Fo,
+            "FJ" => PhoneNumberCountryCode::Fj,
+            "FI" => PhoneNumberCountryCode::Fi,
+            "FR" => PhoneNumberCountryCode::Fr,
+            "PF" => PhoneNumberCountryCode::Pf,
+            "GA" => PhoneNumberCountryCode::Ga,
+            "GM" => PhoneNumberCountryCode::Gm,
+            "GE" => PhoneNumberCountryCode::Ge,
+            "DE" => PhoneNumberCountryCode::De,
+            "GH" => PhoneNumberCountryCode::Gh,
+            "GI" => PhoneNumberCountryCode::Gi,
+            "GR" => PhoneNumberCountryCode::Gr,
+            "GL" => PhoneNumberCountryCode::Gl,
+            "GD" => PhoneNumberCountryCode::Gd,
+            "GU" => PhoneNumberCountryCode::Gu,
+            "GT" => PhoneNumberCountryCode::Gt,
+            "GG" => PhoneNumberCountryCode::Gg,
+            "GN" => PhoneNumberCountryCode::Gn,
+            "GW" => PhoneNumberCountryCode::Gw,
+            "GY" => PhoneNumberCountryCode::Gy,
+            "HT" => PhoneNumberCountryCode::Ht,
+            "HN" => PhoneNumberCountryCode::Hn,
+            "HK" => PhoneNumberCountryCode::Hk,
+            "HU" => PhoneNumberCountryCode::Hu,
+            "IS" => PhoneNumberCountryCode::Is,
+            "IN" => PhoneNumberCountryCode::In,
+            "ID" => PhoneNumberCountryCode::Id,
+            "IR" => PhoneNumberCountryCode::Ir,
+            "IQ" => PhoneNumberCountryCode::Iq,
+            "IE" => PhoneNumberCountryCode::Ie,
+            "IM" => PhoneNumberCountryCode::Im,
+            "IL" => PhoneNumberCountryCode::Il,
+            "IT" => PhoneNumberCountryCode::It,
+            "CI" => PhoneNumberCountryCode::Ci,
+            "JM" => PhoneNumberCountryCode::Jm,
+            "JP" => PhoneNumberCountryCode::Jp,
+            "JE" => PhoneNumberCountryCode::Je,
+            "JO" => PhoneNumberCountryCode::Jo,
+            "KZ" => PhoneNumberCountryCode::Kz,
+            "KE" => PhoneNumberCountryCode::Ke,
+            "KI" => PhoneNumberCountryCode::Ki,
+            "KW" => PhoneNumberCountryCode::Kw,
+            "KG" => PhoneNumberCountryCode::Kg,
+            "LA" => PhoneNumberCountryCode::La,
+            "LV" => PhoneNumberCountryCode::Lv,
+            "LB" => PhoneNumberCountryCode::Lb,
+            "LS" => PhoneNumberCountryCode::Ls,
+            "LR" => PhoneNumberCountryCode::Lr,
+            "LY" => PhoneNumberCountryCode::Ly,
+            "LI" => PhoneNumberCountryCode::Li,
+            "LT" => PhoneNumberCountryCode::Lt,
+            "LU" => PhoneNumberCountryCode::Lu,
+            "MO" => PhoneNumberCountryCode::Mo,
+            "MK" => PhoneNumberCountryCode::Mk,
+            "MG" => PhoneNumberCountryCode::Mg,
+            "MW" => PhoneNumberCountryCode::Mw,
+            "MY" => PhoneNumberCountryCode::My,
+            "MV" => PhoneNumberCountryCode::Mv,
+            "ML" => PhoneNumberCountryCode::Ml,
+            "MT" => PhoneNumberCountryCode::Mt,
+            "MH" => PhoneNumberCountryCode::Mh,
+            "MR" => PhoneNumberCountryCode::Mr,
+            "MU" => PhoneNumberCountryCode::Mu,
+            "YT" => PhoneNumberCountryCode::Yt,
+            "MX" => PhoneNumberCountryCode::Mx,
+            "FM" => PhoneNumberCountryCode::Fm,
+            "MD" => PhoneNumberCountryCode::Md,
+            "MC" => PhoneNumberCountryCode::Mc,
+            "MN" => PhoneNumberCountryCode::Mn,
+            "ME" => PhoneNumberCountryCode::Me,
+            "MS" => PhoneNumberCountryCode::Ms,
+            "MA" => PhoneNumberCountryCode::Ma,
+            "MZ" => PhoneNumberCountryCode::Mz,
+            "MM" => PhoneNumberCountryCode::Mm,
+            "NA" => PhoneNumberCountryCode::Na,
+            "NR" => PhoneNumberCountryCode::Nr,
+            "NP" => PhoneNumberCountryCode::Np,
+            "NL" => PhoneNumberCountryCode::Nl,
+            "AN" => PhoneNumberCountryCode::An,
+            "NC" => PhoneNumberCountryCode::Nc,
+            "NZ" => PhoneNumberCountryCode::Nz,
+            "NI" => PhoneNumberCountryCode::Ni,
+            "NE" => PhoneNumberCountryCode::Ne,
+            "NG" => PhoneNumberCountryCode::Ng,
+            "NU" => PhoneNumberCountryCode::Nu,
+            "KP" => PhoneNumberCountryCode::Kp,
+            "MP" => PhoneNumberCountryCode::Mp,
+            "NO" => PhoneNumberCountryCode::No,
+            "OM" => PhoneNumberCountryCode::Om,
+            "PK" => PhoneNumberCountryCode::Pk,
+            "PW" => PhoneNumberCountryCode::Pw,
+            "PA" => PhoneNumberCountryCode::Pa,
+            "PG" => PhoneNumberCountryCode::Pg,
+            "PY" => PhoneNumberCountryCode::Py,
+            "PE" => PhoneNumberCountryCode::Pe,
+            "PH" => PhoneNumberCountryCode::Ph,
+            "PN" => PhoneNumberCountryCode::Pn,
+            "PL" => PhoneNumberCountryCode::Pl,
+            "PT" => PhoneNumberCountryCode::Pt,
+            "PR" => PhoneNumberCountryCode::Pr,
+            "QA" => PhoneNumberCountryCode::Qa,
+            "CG" => PhoneNumberCountryCode::Cg,
+            "RE" => PhoneNumberCountryCode::Re,
+            "RO" => PhoneNumberCountryCode::Ro,
+            "RU" => PhoneNumberCountryCode::Ru,
+            "RW" => PhoneNumberCountryCode::Rw,
+            "BL" => PhoneNumberCountryCode::Bl,
+            "SH" => PhoneNumberCountryCode::Sh,
+            "KN" => PhoneNumberCountryCode::Kn,
+            "LC" => PhoneNumberCountryCode::Lc,
+            "MF" => PhoneNumberCountryCode::Mf,
+            "PM" => PhoneNumberCountryCode::Pm,
+            "VC" => PhoneNumberCountryCode::Vc,
+            "WS" => PhoneNumberCountryCode::Ws,
+            "SM" => PhoneNumberCountryCode::Sm,
+            "ST" => PhoneNumberCountryCode::St,
+            "SA" => PhoneNumberCountryCode::Sa,
+            "SN" => PhoneNumberCountryCode::Sn,
+            "RS" => PhoneNumberCountryCode::Rs,
+            "SC" => PhoneNumberCountryCode::Sc,
+            "SL" => PhoneNumberCountryCode::Sl,
+            "SG" => PhoneNumberCountryCode::Sg,
+            "SX" => PhoneNumberCountryCode::Sx,
+            "SK" => PhoneNumberCountryCode::Sk,
+            "SI" => PhoneNumberCountryCode::Si,
+            "SB" => PhoneNumberCountryCode::Sb,
+            "SO" => PhoneNumberCountryCode::So,
+            "ZA" => PhoneNumberCountryCode::Za,
+            "KR" => PhoneNumberCountryCode::Kr,
+            "ES" => PhoneNumberCountryCode::Es,
+            "LK" => PhoneNumberCountryCode::Lk,
+            "SD" => PhoneNumberCountryCode::Sd,
+            "SR" => PhoneNumberCountryCode::Sr,
+            "SJ" => PhoneNumberCountryCode::Sj,
+            "SZ" => PhoneNumberCountryCode::Sz,
+            "SE" => PhoneNumberCountryCode::Se,
+            "CH" => PhoneNumberCountryCode::Ch,
+            "SY" => PhoneNumberCountryCode::Sy,
+            "TW" => PhoneNumberCountryCode::Tw,
+            "TJ" => PhoneNumberCountryCode::Tj,
+            "TZ" => PhoneNumberCountryCode::Tz,
+            "TH" => PhoneNumberCountryCode::Th,
+            "TG" => PhoneNumberCountryCode::Tg,
+            "TK" => PhoneNumberCountryCode::Tk,
+            "TO" => PhoneNumberCountryCode::To,
+            "TT" => PhoneNumberCountryCode::Tt,
+            "TN" => PhoneNumberCountryCode::Tn,
+            "TR" => PhoneNumberCountryCode::Tr,
+            "TM" => PhoneNumberCountryCode::Tm,
+            "TC" => PhoneNumberCountryCode::Tc,
+            "TV" => PhoneNumberCountryCode::Tv,
+            "VI" => PhoneNumberCountryCode::Vi,
+            "UG" => PhoneNumberCountryCode::Ug,
+            "UA" => PhoneNumberCountryCode::Ua,
+            "AE" => PhoneNumberCountryCode::Ae,
+            "GB" => PhoneNumberCountryCode::Gb,
+            "US" => PhoneNumberCountryCode::Us,
+            "UY" => PhoneNumberCountryCode::Uy,
+            "UZ" => PhoneNumberCountryCode::Uz,
+            "VU" => PhoneNumberCountryCode::Vu,
+            "VA" => PhoneNumberCountryCode::Va,
+            "VE" => PhoneNumberCountryCode::Ve,
+            "VN" => PhoneNumberCountryCode::Vn,
+            "WF" => PhoneNumberCountryCode::Wf,
+            "EH" => PhoneNumberCountryCode::Eh,
+            "YE" => PhoneNumberCountryCode::Ye,
+            "ZM" => PhoneNumberCountryCode::Zm,
+            "ZW" => PhoneNumberCountryCode::Zw,
+            _ => PhoneNumberCountryCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PhoneNumberCountryCode {
+    fn from(value: String) -> PhoneNumberCountryCode {
+        match value.as_str() {
+            "AF" => PhoneNumberCountryCode::Af,
+            "AL" => PhoneNumberCountryCode::Al,
+            "DZ" => PhoneNumberCountryCode::Dz,
+            "AS" => PhoneNumberCountryCode::As,
+            "AD" => PhoneNumberCountryCode::Ad,
+            "AO" => PhoneNumberCountryCode::Ao,
+            "AI" => PhoneNumberCountryCode::Ai,
+            "AQ" => PhoneNumberCountryCode::Aq,
+            "AG" => PhoneNumberCountryCode::Ag,
+            "AR" => PhoneNumberCountryCode::Ar,
+            "AM" => PhoneNumberCountryCode::Am,
+            "AW" => PhoneNumberCountryCode::Aw,
+            "AU" => PhoneNumberCountryCode::Au,
+            "AT" => PhoneNumberCountryCode::At,
+            "AZ" => PhoneNumberCountryCode::Az,
+            "BS" => PhoneNumberCountryCode::Bs,
+            "BH" => PhoneNumberCountryCode::Bh,
+            "BD" => PhoneNumberCountryCode::Bd,
+            "BB" => PhoneNumberCountryCode::Bb,
+            "BY" => PhoneNumberCountryCode::By,
+            "BE" => PhoneNumberCountryCode::Be,
+            "BZ" => PhoneNumberCountryCode::Bz,
+            "BJ" => PhoneNumberCountryCode::Bj,
+            "BM" => PhoneNumberCountryCode::Bm,
+            "BT" => PhoneNumberCountryCode::Bt,
+            "BO" => PhoneNumberCountryCode::Bo,
+            "BA" => PhoneNumberCountryCode::Ba,
+            "BW" => PhoneNumberCountryCode::Bw,
+            "BR" => PhoneNumberCountryCode::Br,
+            "IO" => PhoneNumberCountryCode::Io,
+            "VG" => PhoneNumberCountryCode::Vg,
+            "BN" => PhoneNumberCountryCode::Bn,
+            "BG" => PhoneNumberCountryCode::Bg,
+            "BF" => PhoneNumberCountryCode::Bf,
+            "BI" => PhoneNumberCountryCode::Bi,
+            "KH" => PhoneNumberCountryCode::Kh,
+            "CM" => PhoneNumberCountryCode::Cm,
+            "CA" => PhoneNumberCountryCode::Ca,
+            "CV" => PhoneNumberCountryCode::Cv,
+            "KY" => PhoneNumberCountryCode::Ky,
+            "CF" => PhoneNumberCountryCode::Cf,
+            "TD" => PhoneNumberCountryCode::Td,
+            "CL" => PhoneNumberCountryCode::Cl,
+            "CN" => PhoneNumberCountryCode::Cn,
+            "CX" => PhoneNumberCountryCode::Cx,
+            "CC" => PhoneNumberCountryCode::Cc,
+            "CO" => PhoneNumberCountryCode::Co,
+            "KM" => PhoneNumberCountryCode::Km,
+            "CK" => PhoneNumberCountryCode::Ck,
+            "CR" => PhoneNumberCountryCode::Cr,
+            "HR" => PhoneNumberCountryCode::Hr,
+            "CU" => PhoneNumberCountryCode::Cu,
+            "CW" => PhoneNumberCountryCode::Cw,
+            "CY" => PhoneNumberCountryCode::Cy,
+            "CZ" => PhoneNumberCountryCode::Cz,
+            "CD" => PhoneNumberCountryCode::Cd,
+            "DK" => PhoneNumberCountryCode::Dk,
+            "DJ" => PhoneNumberCountryCode::Dj,
+            "DM" => PhoneNumberCountryCode::Dm,
+            "DO" => PhoneNumberCountryCode::Do,
+            "TL" => PhoneNumberCountryCode::Tl,
+            "EC" => PhoneNumberCountryCode::Ec,
+            "EG" => PhoneNumberCountryCode::Eg,
+            "SV" => PhoneNumberCountryCode::Sv,
+            "GQ" => PhoneNumberCountryCode::Gq,
+            "ER" => PhoneNumberCountryCode::Er,
+            "EE" => PhoneNumberCountryCode::Ee,
+            "ET" => PhoneNumberCountryCode::Et,
+            "FK" => PhoneNumberCountryCode::Fk,
+            "FO" => PhoneNumberCountryCode::Fo,
+            "FJ" => PhoneNumberCountryCode::Fj,
+            "FI" => PhoneNumberCountryCode::Fi,
+            "FR" => PhoneNumberCountryCode::Fr,
+            "PF" => PhoneNumberCountryCode::Pf,
+            "GA" => PhoneNumberCountryCode::Ga,
+            "GM" => PhoneNumberCountryCode::Gm,
+            "GE" => PhoneNumberCountryCode::Ge,
+            "DE" => PhoneNumberCountryCode::De,
+            "GH" => PhoneNumberCountryCode::Gh,
+            "GI" => PhoneNumberCountryCode::Gi,
+            "GR" => PhoneNumberCountryCode::Gr,
+            "GL" => PhoneNumberCountryCode::Gl,
+            "GD" => PhoneNumberCountryCode::Gd,
+            "GU" => PhoneNumberCountryCode::Gu,
+            "GT" => PhoneNumberCountryCode::Gt,
+            "GG" => PhoneNumberCountryCode::Gg,
+            "GN" => PhoneNumberCountryCode::Gn,
+            "GW" => PhoneNumberCountryCode::Gw,
+            "GY" => PhoneNumberCountryCode::Gy,
+            "HT" => PhoneNumberCountryCode::Ht,
+            "HN" => PhoneNumberCountryCode::Hn,
+            "HK" => PhoneNumberCountryCode::Hk,
+            "HU" => PhoneNumberCountryCode::Hu,
+            "IS" => PhoneNumberCountryCode::Is,
+            "IN" => PhoneNumberCountryCode::In,
+            "ID" => PhoneNumberCountryCode::Id,
+            "IR" => PhoneNumberCountryCode::Ir,
+            "IQ" => PhoneNumberCountryCode::Iq,
+            "IE" => PhoneNumberCountryCode::Ie,
+            "IM" => PhoneNumberCountryCode::Im,
+            "IL" => PhoneNumberCountryCode::Il,
+            "IT" => PhoneNumberCountryCode::It,
+            "CI" => PhoneNumberCountryCode::Ci,
+            "JM" => PhoneNumberCountryCode::Jm,
+            "JP" => PhoneNumberCountryCode::Jp,
+            "JE" => PhoneNumberCountryCode::Je,
+            "JO" => PhoneNumberCountryCode::Jo,
+            "KZ" => PhoneNumberCountryCode::Kz,
+            "KE" => PhoneNumberCountryCode::Ke,
+            "KI" => PhoneNumberCountryCode::Ki,
+            "KW" => PhoneNumberCountryCode::Kw,
+            "KG" => PhoneNumberCountryCode::Kg,
+            "LA" => PhoneNumberCountryCode::La,
+            "LV" => PhoneNumberCountryCode::Lv,
+            "LB" => PhoneNumberCountryCode::Lb,
+            "LS" => PhoneNumberCountryCode::Ls,
+            "LR" => PhoneNumberCountryCode::Lr,
+            "LY" => PhoneNumberCountryCode::Ly,
+            "LI" => PhoneNumberCountryCode::Li,
+            "LT" => PhoneNumberCountryCode::Lt,
+            "LU" => PhoneNumberCountryCode::Lu,
+            "MO" => PhoneNumberCountryCode::Mo,
+            "MK" => PhoneNumberCountryCode::Mk,
+            "MG" => PhoneNumberCountryCode::Mg,
+            "MW" => PhoneNumberCountryCode::Mw,
+            "MY" => PhoneNumberCountryCode::My,
+            "MV" => PhoneNumberCountryCode::Mv,
+            "ML" => PhoneNumberCountryCode::Ml,
+            "MT" => PhoneNumberCountryCode::Mt,
+            "MH" => PhoneNumberCountryCode::Mh,
+            "MR" => PhoneNumberCountryCode::Mr,
+            "MU" => PhoneNumberCountryCode::Mu,
+            "YT" => PhoneNumberCountryCode::Yt,
+            "MX" => PhoneNumberCountryCode::Mx,
+            "FM" => PhoneNumberCountryCode::Fm,
+            "MD" => PhoneNumberCountryCode::Md,
+            "MC" => PhoneNumberCountryCode::Mc,
+            "MN" => PhoneNumberCountryCode::Mn,
+            "ME" => PhoneNumberCountryCode::Me,
+            "MS" => PhoneNumberCountryCode::Ms,
+            "MA" => PhoneNumberCountryCode::Ma,
+            "MZ" => PhoneNumberCountryCode::Mz,
+            "MM" => PhoneNumberCountryCode::Mm,
+            "NA" => PhoneNumberCountryCode::Na,
+            "NR" => PhoneNumberCountryCode::Nr,
+            "NP" => PhoneNumberCountryCode::Np,
+            "NL" => PhoneNumberCountryCode::Nl,
+            "AN" => PhoneNumberCountryCode::An,
+            "NC" => PhoneNumberCountryCode::Nc,
+            "NZ" => PhoneNumberCountryCode::Nz,
+            "NI" => PhoneNumberCountryCode::Ni,
+            "NE" => PhoneNumberCountryCode::Ne,
+            "NG" => PhoneNumberCountryCode::Ng,
+            "NU" => PhoneNumberCountryCode::Nu,
+            "KP" => PhoneNumberCountryCode::Kp,
+            "MP" => PhoneNumberCountryCode::Mp,
+            "NO" => PhoneNumberCountryCode::No,
+            "OM" => PhoneNumberCountryCode::Om,
+            "PK" => PhoneNumberCountryCode::Pk,
+            "PW" => PhoneNumberCountryCode::Pw,
+            "PA" => PhoneNumberCountryCode::Pa,
+            "PG" => PhoneNumberCountryCode::Pg,
+            "PY" => PhoneNumberCountryCode::Py,
+            "PE" => PhoneNumberCountryCode::Pe,
+            "PH" => PhoneNumberCountryCode::Ph,
+            "PN" => PhoneNumberCountryCode::Pn,
+            "PL" => PhoneNumberCountryCode::Pl,
+            "PT" => PhoneNumberCountryCode::Pt,
+            "PR" => PhoneNumberCountryCode::Pr,
+            "QA" => PhoneNumberCountryCode::Qa,
+            "CG" => PhoneNumberCountryCode::Cg,
+            "RE" => PhoneNumberCountryCode::Re,
+            "RO" => PhoneNumberCountryCode::Ro,
+            "RU" => PhoneNumberCountryCode::Ru,
+            "RW" => PhoneNumberCountryCode::Rw,
+            "BL" => PhoneNumberCountryCode::Bl,
+            "SH" => PhoneNumberCountryCode::Sh,
+            "KN" => PhoneNumberCountryCode::Kn,
+            "LC" => PhoneNumberCountryCode::Lc,
+            "MF" => PhoneNumberCountryCode::Mf,
+            "PM" => PhoneNumberCountryCode::Pm,
+            "VC" => PhoneNumberCountryCode::Vc,
+            "WS" => PhoneNumberCountryCode::Ws,
+            "SM" => PhoneNumberCountryCode::Sm,
+            "ST" => PhoneNumberCountryCode::St,
+            "SA" => PhoneNumberCountryCode::Sa,
+            "SN" => PhoneNumberCountryCode::Sn,
+            "RS" => PhoneNumberCountryCode::Rs,
+            "SC" => PhoneNumberCountryCode::Sc,
+            "SL" => PhoneNumberCountryCode::Sl,
+            "SG" => PhoneNumberCountryCode::Sg,
+            "SX" => PhoneNumberCountryCode::Sx,
+            "SK" => PhoneNumberCountryCode::Sk,
+            "SI" => PhoneNumberCountryCode::Si,
+            "SB" => PhoneNumberCountryCode::Sb,
+            "SO" => PhoneNumberCountryCode::So,
+            "ZA" => PhoneNumberCountryCode::Za,
+            "KR" => PhoneNumberCountryCode::Kr,
+            "ES" => PhoneNumberCountryCode::Es,
+            "LK" => PhoneNumberCountryCode::Lk,
+            "SD" => PhoneNumberCountryCode::Sd,
+            "SR" => PhoneNumberCountryCode::Sr,
+            "SJ" => PhoneNumberCountryCode::Sj,
+            "SZ" => PhoneNumberCountryCode::Sz,
+            "SE" => PhoneNumberCountryCode::Se,
+            "CH" => PhoneNumberCountryCode::Ch,
+            "SY" => PhoneNumberCountryCode::Sy,
+            "TW" => PhoneNumberCountryCode::Tw,
+            "TJ" => PhoneNumberCountryCode::Tj,
+            "TZ" => PhoneNumberCountryCode::Tz,
+            "TH" => PhoneNumberCountryCode::Th,
+            "TG" => PhoneNumberCountryCode::Tg,
+            "TK" => PhoneNumberCountryCode::Tk,
+            "TO" => PhoneNumberCountryCode::To,
+            "TT" => PhoneNumberCountryCode::Tt,
+            "TN" => PhoneNumberCountryCode::Tn,
+            "TR" => PhoneNumberCountryCode::Tr,
+            "TM" => PhoneNumberCountryCode::Tm,
+            "TC" => PhoneNumberCountryCode::Tc,
+            "TV" => PhoneNumberCountryCode::Tv,
+            "VI" => PhoneNumberCountryCode::Vi,
+            "UG" => PhoneNumberCountryCode::Ug,
+            "UA" => PhoneNumberCountryCode::Ua,
+            "AE" => PhoneNumberCountryCode::Ae,
+            "GB" => PhoneNumberCountryCode::Gb,
+            "US" => PhoneNumberCountryCode::Us,
+            "UY" => PhoneNumberCountryCode::Uy,
+            "UZ" => PhoneNumberCountryCode::Uz,
+            "VU" => PhoneNumberCountryCode::Vu,
+            "VA" => PhoneNumberCountryCode::Va,
+            "VE" => PhoneNumberCountryCode::Ve,
+            "VN" => PhoneNumberCountryCode::Vn,
+            "WF" => PhoneNumberCountryCode::Wf,
+            "EH" => PhoneNumberCountryCode::Eh,
+            "YE" => PhoneNumberCountryCode::Ye,
+            "ZM" => PhoneNumberCountryCode::Zm,
+            "ZW" => PhoneNumberCountryCode::Zw,
+            _ => PhoneNumberCountryCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PhoneNumberCountryCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PhoneNumberCountryCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PhoneNumberCountryCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PhoneNumberCountryCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PhoneNumberCountryCode {
+    fn deserialize<D>(deserializer: D) -> Result<PhoneNumberCountryCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Contains summary information about a phone number for a contact center.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct PhoneNumberSummary {
+    /// <p>The Amazon Resource Name (ARN) of the phone number.</p>
+    #[serde(rename = "Arn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub arn: Option<String>,
+    /// <p>The identifier of the phone number.</p>
+    #[serde(rename = "Id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// <p>The phone number.</p>
+    #[serde(rename = "PhoneNumber")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number: Option<String>,
+    /// <p>The ISO country code.</p>
+    #[serde(rename = "PhoneNumberCountryCode")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number_country_code: Option<PhoneNumberCountryCode>,
+    /// <p>The type of phone number.</p>
+    #[serde(rename = "PhoneNumberType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number_type: Option<PhoneNumberType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PhoneNumberType {
+    /// `TOLL_FREE`
+    TollFree,
+    /// `DID`
+    Did,
+    /// `UIFN`
+    Uifn,
+    /// `SHARED`
+    Shared,
+    /// `THIRD_PARTY_TF`
+    ThirdPartyTf,
+    /// `THIRD_PARTY_DID`
+    ThirdPartyDid,
+    /// `SHORT_CODE`
+    ShortCode,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PhoneNumberType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PhoneNumberType::TollFree => "TOLL_FREE",
+            PhoneNumberType::Did => "DID",
+            PhoneNumberType::Uifn => "UIFN",
+            PhoneNumberType::Shared => "SHARED",
+            PhoneNumberType::ThirdPartyTf => "THIRD_PARTY_TF",
+            PhoneNumberType::ThirdPartyDid => "THIRD_PARTY_DID",
+            PhoneNumberType::ShortCode => "SHORT_CODE",
+            PhoneNumberType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PhoneNumberType {
+    fn default() -> PhoneNumberType {
+        PhoneNumberType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PhoneNumberType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PhoneNumberType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PhoneNumberType {
+    fn from(value: &'a str) -> PhoneNumberType {
+        match value {
+            "TOLL_FREE" => PhoneNumberType::TollFree,
+            "DID" => PhoneNumberType::Did,
+            "UIFN" => PhoneNumberType::Uifn,
+            "SHARED" => PhoneNumberType::Shared,
+            "THIRD_PARTY_TF" => PhoneNumberType::ThirdPartyTf,
+            "THIRD_PARTY_DID" => PhoneNumberType::ThirdPartyDid,
+            "SHORT_CODE" => PhoneNumberType::ShortCode,
+            _ => PhoneNumberType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PhoneNumberType {
+    fn from(value: String) -> PhoneNumberType {
+        match value.as_str() {
+            "TOLL_FREE" => PhoneNumberType::TollFree,
+            "DID" => PhoneNumberType::Did,
+            "UIFN" => PhoneNumberType::Uifn,
+            "SHARED" => PhoneNumberType::Shared,
+            "THIRD_PARTY_TF" => PhoneNumberType::ThirdPartyTf,
+            "THIRD_PARTY_DID" => PhoneNumberType::ThirdPartyDid,
+            "SHORT_CODE" => PhoneNumberType::ShortCode,
+            _ => PhoneNumberType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PhoneNumberType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PhoneNumberType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PhoneNumberType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PhoneNumberType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PhoneNumberType {
+    fn deserialize<D>(deserializer: D) -> Result<PhoneNumberType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PhoneType {
+    /// `SOFT_PHONE`
+    SoftPhone,
+    /// `DESK_PHONE`
+    DeskPhone,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PhoneType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PhoneType::SoftPhone => "SOFT_PHONE",
+            PhoneType::DeskPhone => "DESK_PHONE",
+            PhoneType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PhoneType {
+    fn default() -> PhoneType {
+        PhoneType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PhoneType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PhoneType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PhoneType {
+    fn from(value: &'a str) -> PhoneType {
+        match value {
+            "SOFT_PHONE" => PhoneType::SoftPhone,
+            "DESK_PHONE" => PhoneType::DeskPhone,
+            _ => PhoneType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PhoneType {
+    fn from(value: String) -> PhoneType {
+        match value.as_str() {
+            "SOFT_PHONE" => PhoneType::SoftPhone,
+            "DESK_PHONE" => PhoneType::DeskPhone,
+            _ => PhoneType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PhoneType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PhoneType, Self::Err> {
+        Ok(value.into())
+    }
 }
 
-/// <p>The customer's details.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct ParticipantDetails {
-    /// <p>Display name of the participant.</p>
-    #[serde(rename = "DisplayName")]
-    pub display_name: String,
+impl ::rusoto_core::param::ToParam for PhoneType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
 }
 
-/// <p>Contains summary information about a phone number for a contact center.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct PhoneNumberSummary {
-    /// <p>The Amazon Resource Name (ARN) of the phone number.</p>
-    #[serde(rename = "Arn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub arn: Option<String>,
-    /// <p>The identifier of the phone number.</p>
-    #[serde(rename = "Id")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
-    /// <p>The phone number.</p>
-    #[serde(rename = "PhoneNumber")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub phone_number: Option<String>,
-    /// <p>The ISO country code.</p>
-    #[serde(rename = "PhoneNumberCountryCode")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub phone_number_country_code: Option<String>,
-    /// <p>The type of phone number.</p>
-    #[serde(rename = "PhoneNumberType")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub phone_number_type: Option<String>,
+impl ::serde::Serialize for PhoneType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for PhoneType {
+    fn deserialize<D>(deserializer: D) -> Result<PhoneType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Contains information about a queue resource for which metrics are returned.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -910,9 +3201,102 @@ pub struct QueueSummary {
     /// <p>The type of queue.</p>
     #[serde(rename = "QueueType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub queue_type: Option<String>,
+    pub queue_type: Option<QueueType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum QueueType {
+    /// `STANDARD`
+    Standard,
+    /// `AGENT`
+    Agent,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl QueueType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            QueueType::Standard => "STANDARD",
+            QueueType::Agent => "AGENT",
+            QueueType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for QueueType {
+    fn default() -> QueueType {
+        QueueType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for QueueType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for QueueType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for QueueType {
+    fn from(value: &'a str) -> QueueType {
+        match value {
+            "STANDARD" => QueueType::Standard,
+            "AGENT" => QueueType::Agent,
+            _ => QueueType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for QueueType {
+    fn from(value: String) -> QueueType {
+        match value.as_str() {
+            "STANDARD" => QueueType::Standard,
+            "AGENT" => QueueType::Agent,
+            _ => QueueType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for QueueType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<QueueType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for QueueType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for QueueType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for QueueType {
+    fn deserialize<D>(deserializer: D) -> Result<QueueType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Contains summary information about a routing profile.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1031,6 +3415,104 @@ pub struct StartOutboundVoiceContactResponse {
     pub contact_id: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Statistic {
+    /// `SUM`
+    Sum,
+    /// `MAX`
+    Max,
+    /// `AVG`
+    Avg,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Statistic {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Statistic::Sum => "SUM",
+            Statistic::Max => "MAX",
+            Statistic::Avg => "AVG",
+            Statistic::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Statistic {
+    fn default() -> Statistic {
+        Statistic::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Statistic {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Statistic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Statistic {
+    fn from(value: &'a str) -> Statistic {
+        match value {
+            "SUM" => Statistic::Sum,
+            "MAX" => Statistic::Max,
+            "AVG" => Statistic::Avg,
+            _ => Statistic::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Statistic {
+    fn from(value: String) -> Statistic {
+        match value.as_str() {
+            "SUM" => Statistic::Sum,
+            "MAX" => Statistic::Max,
+            "AVG" => Statistic::Avg,
+            _ => Statistic::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Statistic {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Statistic, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Statistic {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Statistic {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Statistic {
+    fn deserialize<D>(deserializer: D) -> Result<Statistic, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct StopContactRequest {
@@ -1063,13 +3545,111 @@ pub struct Threshold {
     /// <p>The type of comparison. Only "less than" (LT) comparisons are supported.</p>
     #[serde(rename = "Comparison")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub comparison: Option<String>,
+    pub comparison: Option<Comparison>,
     /// <p>The threshold value to compare.</p>
     #[serde(rename = "ThresholdValue")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub threshold_value: Option<f64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Unit {
+    /// `SECONDS`
+    Seconds,
+    /// `COUNT`
+    Count,
+    /// `PERCENT`
+    Percent,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Unit {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Unit::Seconds => "SECONDS",
+            Unit::Count => "COUNT",
+            Unit::Percent => "PERCENT",
+            Unit::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Unit {
+    fn default() -> Unit {
+        Unit::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Unit {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Unit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Unit {
+    fn from(value: &'a str) -> Unit {
+        match value {
+            "SECONDS" => Unit::Seconds,
+            "COUNT" => Unit::Count,
+            "PERCENT" => Unit::Percent,
+            _ => Unit::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Unit {
+    fn from(value: String) -> Unit {
+        match value.as_str() {
+            "SECONDS" => Unit::Seconds,
+            "COUNT" => Unit::Count,
+            "PERCENT" => Unit::Percent,
+            _ => Unit::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Unit {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Unit, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Unit {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Unit {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Unit {
+    fn deserialize<D>(deserializer: D) -> Result<Unit, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct UntagResourceRequest {
@@ -1250,7 +3830,7 @@ pub struct UserPhoneConfig {
     pub desk_phone_number: Option<String>,
     /// <p>The phone type.</p>
     #[serde(rename = "PhoneType")]
-    pub phone_type: String,
+    pub phone_type: PhoneType,
 }
 
 /// <p>Contains summary information about a user.</p>
@@ -3179,11 +5759,12 @@ pub struct ConnectClient {
 impl ConnectClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> ConnectClient {
         ConnectClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Connect"),
         }
     }
 
@@ -3217,6 +5798,7 @@ impl Connect for ConnectClient {
         let request_uri = format!("/users/{instance_id}", instance_id = input.instance_id);
 
         let mut request = SignedRequest::new("PUT", "connect", &self.region, &request_uri);
+        request.set_operation("CreateUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3251,6 +5833,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "connect", &self.region, &request_uri);
+        request.set_operation("DeleteUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3281,6 +5864,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("DescribeUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3313,6 +5897,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("DescribeUserHierarchyGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3346,6 +5931,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("DescribeUserHierarchyStructure");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3377,6 +5963,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("GetContactAttributes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3407,6 +5994,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation("GetCurrentMetricData");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3440,6 +6028,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("GetFederationToken");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3470,6 +6059,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation("GetMetricData");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3503,6 +6093,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("ListContactFlows");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3547,6 +6138,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("ListHoursOfOperations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3586,6 +6178,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("ListPhoneNumbers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3635,6 +6228,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("ListQueues");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3679,6 +6273,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("ListRoutingProfiles");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3718,6 +6313,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("ListSecurityProfiles");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3754,6 +6350,7 @@ impl Connect for ConnectClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3784,6 +6381,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("ListUserHierarchyGroups");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3823,6 +6421,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("ListUsers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3859,6 +6458,7 @@ impl Connect for ConnectClient {
         let request_uri = "/contact/chat";
 
         let mut request = SignedRequest::new("PUT", "connect", &self.region, &request_uri);
+        request.set_operation("StartChatContact");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3890,6 +6490,7 @@ impl Connect for ConnectClient {
         let request_uri = "/contact/outbound-voice";
 
         let mut request = SignedRequest::new("PUT", "connect", &self.region, &request_uri);
+        request.set_operation("StartOutboundVoiceContact");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3920,6 +6521,7 @@ impl Connect for ConnectClient {
         let request_uri = "/contact/stop";
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation("StopContact");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3950,6 +6552,7 @@ impl Connect for ConnectClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3979,6 +6582,7 @@ impl Connect for ConnectClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "connect", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4011,6 +6615,7 @@ impl Connect for ConnectClient {
         let request_uri = "/contact/attributes";
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation("UpdateContactAttributes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4045,6 +6650,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation("UpdateUserHierarchy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4078,6 +6684,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation("UpdateUserIdentityInfo");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4111,6 +6718,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation("UpdateUserPhoneConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4144,6 +6752,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation("UpdateUserRoutingProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4177,6 +6786,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation("UpdateUserSecurityProfiles");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4198,3 +6808,307 @@ impl Connect for ConnectClient {
         }
     }
 }
+impl ConnectClient {
+    /// Auto-paginating version of `get_metric_data`, streaming every page of results.
+    pub fn get_metric_data_pages(
+        &self,
+        input: GetMetricDataRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetMetricDataResponse, GetMetricDataError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConnectClient, input: GetMetricDataRequest| async move {
+                client.get_metric_data(input).await
+            },
+            |input: &GetMetricDataRequest, output: &GetMetricDataResponse| {
+                Some(GetMetricDataRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_metric_data`, streaming the `MetricResults` of every page of results.
+    pub fn get_metric_data_items(
+        &self,
+        input: GetMetricDataRequest,
+    ) -> rusoto_core::pagination::RusotoStream<HistoricalMetricResult, GetMetricDataError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_metric_data_pages(input),
+            |output: GetMetricDataResponse| output.metric_results.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_contact_flows`, streaming every page of results.
+    pub fn list_contact_flows_pages(
+        &self,
+        input: ListContactFlowsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListContactFlowsResponse, ListContactFlowsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConnectClient, input: ListContactFlowsRequest| async move {
+                client.list_contact_flows(input).await
+            },
+            |input: &ListContactFlowsRequest, output: &ListContactFlowsResponse| {
+                Some(ListContactFlowsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_contact_flows`, streaming the `ContactFlowSummaryList` of every page of results.
+    pub fn list_contact_flows_items(
+        &self,
+        input: ListContactFlowsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ContactFlowSummary, ListContactFlowsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_contact_flows_pages(input),
+            |output: ListContactFlowsResponse| output.contact_flow_summary_list.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_hours_of_operations`, streaming every page of results.
+    pub fn list_hours_of_operations_pages(
+        &self,
+        input: ListHoursOfOperationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListHoursOfOperationsResponse,
+        ListHoursOfOperationsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConnectClient, input: ListHoursOfOperationsRequest| async move {
+                client.list_hours_of_operations(input).await
+            },
+            |input: &ListHoursOfOperationsRequest, output: &ListHoursOfOperationsResponse| {
+                Some(ListHoursOfOperationsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_hours_of_operations`, streaming the `HoursOfOperationSummaryList` of every page of results.
+    pub fn list_hours_of_operations_items(
+        &self,
+        input: ListHoursOfOperationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<HoursOfOperationSummary, ListHoursOfOperationsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_hours_of_operations_pages(input),
+            |output: ListHoursOfOperationsResponse| {
+                output.hours_of_operation_summary_list.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_phone_numbers`, streaming every page of results.
+    pub fn list_phone_numbers_pages(
+        &self,
+        input: ListPhoneNumbersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListPhoneNumbersResponse, ListPhoneNumbersError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConnectClient, input: ListPhoneNumbersRequest| async move {
+                client.list_phone_numbers(input).await
+            },
+            |input: &ListPhoneNumbersRequest, output: &ListPhoneNumbersResponse| {
+                Some(ListPhoneNumbersRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_phone_numbers`, streaming the `PhoneNumberSummaryList` of every page of results.
+    pub fn list_phone_numbers_items(
+        &self,
+        input: ListPhoneNumbersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<PhoneNumberSummary, ListPhoneNumbersError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_phone_numbers_pages(input),
+            |output: ListPhoneNumbersResponse| output.phone_number_summary_list.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_queues`, streaming every page of results.
+    pub fn list_queues_pages(
+        &self,
+        input: ListQueuesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListQueuesResponse, ListQueuesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConnectClient, input: ListQueuesRequest| async move {
+                client.list_queues(input).await
+            },
+            |input: &ListQueuesRequest, output: &ListQueuesResponse| {
+                Some(ListQueuesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_queues`, streaming the `QueueSummaryList` of every page of results.
+    pub fn list_queues_items(
+        &self,
+        input: ListQueuesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<QueueSummary, ListQueuesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_queues_pages(input),
+            |output: ListQueuesResponse| output.queue_summary_list.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_routing_profiles`, streaming every page of results.
+    pub fn list_routing_profiles_pages(
+        &self,
+        input: ListRoutingProfilesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListRoutingProfilesResponse, ListRoutingProfilesError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConnectClient, input: ListRoutingProfilesRequest| async move {
+                client.list_routing_profiles(input).await
+            },
+            |input: &ListRoutingProfilesRequest, output: &ListRoutingProfilesResponse| {
+                Some(ListRoutingProfilesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_routing_profiles`, streaming the `RoutingProfileSummaryList` of every page of results.
+    pub fn list_routing_profiles_items(
+        &self,
+        input: ListRoutingProfilesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<RoutingProfileSummary, ListRoutingProfilesError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_routing_profiles_pages(input),
+            |output: ListRoutingProfilesResponse| {
+                output.routing_profile_summary_list.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_security_profiles`, streaming every page of results.
+    pub fn list_security_profiles_pages(
+        &self,
+        input: ListSecurityProfilesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListSecurityProfilesResponse,
+        ListSecurityProfilesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConnectClient, input: ListSecurityProfilesRequest| async move {
+                client.list_security_profiles(input).await
+            },
+            |input: &ListSecurityProfilesRequest, output: &ListSecurityProfilesResponse| {
+                Some(ListSecurityProfilesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_security_profiles`, streaming the `SecurityProfileSummaryList` of every page of results.
+    pub fn list_security_profiles_items(
+        &self,
+        input: ListSecurityProfilesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<SecurityProfileSummary, ListSecurityProfilesError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_security_profiles_pages(input),
+            |output: ListSecurityProfilesResponse| {
+                output.security_profile_summary_list.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_user_hierarchy_groups`, streaming every page of results.
+    pub fn list_user_hierarchy_groups_pages(
+        &self,
+        input: ListUserHierarchyGroupsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListUserHierarchyGroupsResponse,
+        ListUserHierarchyGroupsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConnectClient, input: ListUserHierarchyGroupsRequest| async move {
+                client.list_user_hierarchy_groups(input).await
+            },
+            |input: &ListUserHierarchyGroupsRequest, output: &ListUserHierarchyGroupsResponse| {
+                Some(ListUserHierarchyGroupsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_user_hierarchy_groups`, streaming the `UserHierarchyGroupSummaryList` of every page of results.
+    pub fn list_user_hierarchy_groups_items(
+        &self,
+        input: ListUserHierarchyGroupsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<HierarchyGroupSummary, ListUserHierarchyGroupsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_user_hierarchy_groups_pages(input),
+            |output: ListUserHierarchyGroupsResponse| {
+                output.user_hierarchy_group_summary_list.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_users`, streaming every page of results.
+    pub fn list_users_pages(
+        &self,
+        input: ListUsersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListUsersResponse, ListUsersError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConnectClient, input: ListUsersRequest| async move {
+                client.list_users(input).await
+            },
+            |input: &ListUsersRequest, output: &ListUsersResponse| {
+                Some(ListUsersRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_users`, streaming the `UserSummaryList` of every page of results.
+    pub fn list_users_items(
+        &self,
+        input: ListUsersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<UserSummary, ListUsersError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_users_pages(input),
+            |output: ListUsersResponse| output.user_summary_list.unwrap_or_default(),
+        )
+    }
+}
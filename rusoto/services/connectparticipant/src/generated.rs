@@ -24,6 +24,149 @@ use rusoto_core::signature::SignedRequest;
 #[allow(unused_imports)]
 use serde::{Deserialize, Serialize};
 use serde_json;
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ChatItemType {
+    /// `TYPING`
+    Typing,
+    /// `PARTICIPANT_JOINED`
+    ParticipantJoined,
+    /// `PARTICIPANT_LEFT`
+    ParticipantLeft,
+    /// `CHAT_ENDED`
+    ChatEnded,
+    /// `TRANSFER_SUCCEEDED`
+    TransferSucceeded,
+    /// `TRANSFER_FAILED`
+    TransferFailed,
+    /// `MESSAGE`
+    Message,
+    /// `EVENT`
+    Event,
+    /// `ATTACHMENT`
+    Attachment,
+    /// `CONNECTION_ACK`
+    ConnectionAck,
+    /// `MESSAGE_DELIVERED`
+    MessageDelivered,
+    /// `MESSAGE_READ`
+    MessageRead,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ChatItemType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ChatItemType::Typing => "TYPING",
+            ChatItemType::ParticipantJoined => "PARTICIPANT_JOINED",
+            ChatItemType::ParticipantLeft => "PARTICIPANT_LEFT",
+            ChatItemType::ChatEnded => "CHAT_ENDED",
+            ChatItemType::TransferSucceeded => "TRANSFER_SUCCEEDED",
+            ChatItemType::TransferFailed => "TRANSFER_FAILED",
+            ChatItemType::Message => "MESSAGE",
+            ChatItemType::Event => "EVENT",
+            ChatItemType::Attachment => "ATTACHMENT",
+            ChatItemType::ConnectionAck => "CONNECTION_ACK",
+            ChatItemType::MessageDelivered => "MESSAGE_DELIVERED",
+            ChatItemType::MessageRead => "MESSAGE_READ",
+            ChatItemType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ChatItemType {
+    fn default() -> ChatItemType {
+        ChatItemType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ChatItemType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ChatItemType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ChatItemType {
+    fn from(value: &'a str) -> ChatItemType {
+        match value {
+            "TYPING" => ChatItemType::Typing,
+            "PARTICIPANT_JOINED" => ChatItemType::ParticipantJoined,
+            "PARTICIPANT_LEFT" => ChatItemType::ParticipantLeft,
+            "CHAT_ENDED" => ChatItemType::ChatEnded,
+            "TRANSFER_SUCCEEDED" => ChatItemType::TransferSucceeded,
+            "TRANSFER_FAILED" => ChatItemType::TransferFailed,
+            "MESSAGE" => ChatItemType::Message,
+            "EVENT" => ChatItemType::Event,
+            "ATTACHMENT" => ChatItemType::Attachment,
+            "CONNECTION_ACK" => ChatItemType::ConnectionAck,
+            "MESSAGE_DELIVERED" => ChatItemType::MessageDelivered,
+            "MESSAGE_READ" => ChatItemType::MessageRead,
+            _ => ChatItemType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ChatItemType {
+    fn from(value: String) -> ChatItemType {
+        match value.as_str() {
+            "TYPING" => ChatItemType::Typing,
+            "PARTICIPANT_JOINED" => ChatItemType::ParticipantJoined,
+            "PARTICIPANT_LEFT" => ChatItemType::ParticipantLeft,
+            "CHAT_ENDED" => ChatItemType::ChatEnded,
+            "TRANSFER_SUCCEEDED" => ChatItemType::TransferSucceeded,
+            "TRANSFER_FAILED" => ChatItemType::TransferFailed,
+            "MESSAGE" => ChatItemType::Message,
+            "EVENT" => ChatItemType::Event,
+            "ATTACHMENT" => ChatItemType::Attachment,
+            "CONNECTION_ACK" => ChatItemType::ConnectionAck,
+            "MESSAGE_DELIVERED" => ChatItemType::MessageDelivered,
+            "MESSAGE_READ" => ChatItemType::MessageRead,
+            _ => ChatItemType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ChatItemType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ChatItemType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ChatItemType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ChatItemType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ChatItemType {
+    fn deserialize<D>(deserializer: D) -> Result<ChatItemType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Connection credentials. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -38,6 +181,99 @@ pub struct ConnectionCredentials {
     pub expiry: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ConnectionType {
+    /// `WEBSOCKET`
+    Websocket,
+    /// `CONNECTION_CREDENTIALS`
+    ConnectionCredentials,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ConnectionType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ConnectionType::Websocket => "WEBSOCKET",
+            ConnectionType::ConnectionCredentials => "CONNECTION_CREDENTIALS",
+            ConnectionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ConnectionType {
+    fn default() -> ConnectionType {
+        ConnectionType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ConnectionType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ConnectionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ConnectionType {
+    fn from(value: &'a str) -> ConnectionType {
+        match value {
+            "WEBSOCKET" => ConnectionType::Websocket,
+            "CONNECTION_CREDENTIALS" => ConnectionType::ConnectionCredentials,
+            _ => ConnectionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ConnectionType {
+    fn from(value: String) -> ConnectionType {
+        match value.as_str() {
+            "WEBSOCKET" => ConnectionType::Websocket,
+            "CONNECTION_CREDENTIALS" => ConnectionType::ConnectionCredentials,
+            _ => ConnectionType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ConnectionType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ConnectionType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ConnectionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ConnectionType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ConnectionType {
+    fn deserialize<D>(deserializer: D) -> Result<ConnectionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateParticipantConnectionRequest {
@@ -46,7 +282,7 @@ pub struct CreateParticipantConnectionRequest {
     pub participant_token: String,
     /// <p>Type of connection information required.</p>
     #[serde(rename = "Type")]
-    pub type_: Vec<String>,
+    pub type_: Vec<ConnectionType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -99,11 +335,11 @@ pub struct GetTranscriptRequest {
     /// <p>The direction from StartPosition from which to retrieve message. Default: BACKWARD when no StartPosition is provided, FORWARD with StartPosition. </p>
     #[serde(rename = "ScanDirection")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scan_direction: Option<String>,
+    pub scan_direction: Option<ScanDirection>,
     /// <p>The sort order for the records. Default: DESCENDING.</p>
     #[serde(rename = "SortOrder")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort_order: Option<String>,
+    pub sort_order: Option<SortKey>,
     /// <p>A filtering option for where to start.</p>
     #[serde(rename = "StartPosition")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -158,13 +394,214 @@ pub struct Item {
     /// <p>The role of the sender. For example, is it a customer, agent, or system.</p>
     #[serde(rename = "ParticipantRole")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub participant_role: Option<String>,
+    pub participant_role: Option<ParticipantRole>,
     /// <p>Type of the item: message or event. </p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<ChatItemType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ParticipantRole {
+    /// `AGENT`
+    Agent,
+    /// `CUSTOMER`
+    Customer,
+    /// `SYSTEM`
+    System,
+    /// `CUSTOM_BOT`
+    CustomBot,
+    /// `SUPERVISOR`
+    Supervisor,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ParticipantRole {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ParticipantRole::Agent => "AGENT",
+            ParticipantRole::Customer => "CUSTOMER",
+            ParticipantRole::System => "SYSTEM",
+            ParticipantRole::CustomBot => "CUSTOM_BOT",
+            ParticipantRole::Supervisor => "SUPERVISOR",
+            ParticipantRole::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ParticipantRole {
+    fn default() -> ParticipantRole {
+        ParticipantRole::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ParticipantRole {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ParticipantRole {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ParticipantRole {
+    fn from(value: &'a str) -> ParticipantRole {
+        match value {
+            "AGENT" => ParticipantRole::Agent,
+            "CUSTOMER" => ParticipantRole::Customer,
+            "SYSTEM" => ParticipantRole::System,
+            "CUSTOM_BOT" => ParticipantRole::CustomBot,
+            "SUPERVISOR" => ParticipantRole::Supervisor,
+            _ => ParticipantRole::Unknown(value.to_owned()),
+        }
+    }
 }
 
+impl From<String> for ParticipantRole {
+    fn from(value: String) -> ParticipantRole {
+        match value.as_str() {
+            "AGENT" => ParticipantRole::Agent,
+            "CUSTOMER" => ParticipantRole::Customer,
+            "SYSTEM" => ParticipantRole::System,
+            "CUSTOM_BOT" => ParticipantRole::CustomBot,
+            "SUPERVISOR" => ParticipantRole::Supervisor,
+            _ => ParticipantRole::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ParticipantRole {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ParticipantRole, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ParticipantRole {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ParticipantRole {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ParticipantRole {
+    fn deserialize<D>(deserializer: D) -> Result<ParticipantRole, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ScanDirection {
+    /// `FORWARD`
+    Forward,
+    /// `BACKWARD`
+    Backward,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ScanDirection {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ScanDirection::Forward => "FORWARD",
+            ScanDirection::Backward => "BACKWARD",
+            ScanDirection::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ScanDirection {
+    fn default() -> ScanDirection {
+        ScanDirection::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ScanDirection {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ScanDirection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ScanDirection {
+    fn from(value: &'a str) -> ScanDirection {
+        match value {
+            "FORWARD" => ScanDirection::Forward,
+            "BACKWARD" => ScanDirection::Backward,
+            _ => ScanDirection::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ScanDirection {
+    fn from(value: String) -> ScanDirection {
+        match value.as_str() {
+            "FORWARD" => ScanDirection::Forward,
+            "BACKWARD" => ScanDirection::Backward,
+            _ => ScanDirection::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ScanDirection {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ScanDirection, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ScanDirection {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ScanDirection {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ScanDirection {
+    fn deserialize<D>(deserializer: D) -> Result<ScanDirection, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct SendEventRequest {
@@ -228,6 +665,99 @@ pub struct SendMessageResponse {
     pub id: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SortKey {
+    /// `DESCENDING`
+    Descending,
+    /// `ASCENDING`
+    Ascending,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SortKey {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SortKey::Descending => "DESCENDING",
+            SortKey::Ascending => "ASCENDING",
+            SortKey::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SortKey {
+    fn default() -> SortKey {
+        SortKey::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SortKey {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SortKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SortKey {
+    fn from(value: &'a str) -> SortKey {
+        match value {
+            "DESCENDING" => SortKey::Descending,
+            "ASCENDING" => SortKey::Ascending,
+            _ => SortKey::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SortKey {
+    fn from(value: String) -> SortKey {
+        match value.as_str() {
+            "DESCENDING" => SortKey::Descending,
+            "ASCENDING" => SortKey::Ascending,
+            _ => SortKey::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SortKey {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SortKey, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SortKey {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SortKey {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SortKey {
+    fn deserialize<D>(deserializer: D) -> Result<SortKey, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A filtering option for where to start. For example, if you sent 100 messages, start with message 50. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -523,11 +1053,12 @@ pub struct ConnectParticipantClient {
 impl ConnectParticipantClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> ConnectParticipantClient {
         ConnectParticipantClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "ConnectParticipant"),
         }
     }
 
@@ -562,6 +1093,7 @@ impl ConnectParticipant for ConnectParticipantClient {
         let request_uri = "/participant/connection";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("CreateParticipantConnection");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("participant.connect".to_string());
@@ -594,6 +1126,7 @@ impl ConnectParticipant for ConnectParticipantClient {
         let request_uri = "/participant/disconnect";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("DisconnectParticipant");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("participant.connect".to_string());
@@ -626,6 +1159,7 @@ impl ConnectParticipant for ConnectParticipantClient {
         let request_uri = "/participant/transcript";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("GetTranscript");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("participant.connect".to_string());
@@ -658,6 +1192,7 @@ impl ConnectParticipant for ConnectParticipantClient {
         let request_uri = "/participant/event";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("SendEvent");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("participant.connect".to_string());
@@ -690,6 +1225,7 @@ impl ConnectParticipant for ConnectParticipantClient {
         let request_uri = "/participant/message";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("SendMessage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("participant.connect".to_string());
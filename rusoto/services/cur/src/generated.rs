@@ -24,6 +24,428 @@ use rusoto_core::signature::SignedRequest;
 #[allow(unused_imports)]
 use serde::{Deserialize, Serialize};
 use serde_json;
+/// <p>The region of the S3 bucket that Amazon Web Services delivers the report into.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AWSRegion {
+    /// `af-south-1`
+    AfSouth1,
+    /// `ap-east-1`
+    ApEast1,
+    /// `ap-south-1`
+    ApSouth1,
+    /// `ap-south-2`
+    ApSouth2,
+    /// `ap-southeast-1`
+    ApSoutheast1,
+    /// `ap-southeast-2`
+    ApSoutheast2,
+    /// `ap-southeast-3`
+    ApSoutheast3,
+    /// `ap-northeast-1`
+    ApNortheast1,
+    /// `ap-northeast-2`
+    ApNortheast2,
+    /// `ap-northeast-3`
+    ApNortheast3,
+    /// `ca-central-1`
+    CaCentral1,
+    /// `eu-central-1`
+    EuCentral1,
+    /// `eu-central-2`
+    EuCentral2,
+    /// `eu-west-1`
+    EuWest1,
+    /// `eu-west-2`
+    EuWest2,
+    /// `eu-west-3`
+    EuWest3,
+    /// `eu-north-1`
+    EuNorth1,
+    /// `eu-south-1`
+    EuSouth1,
+    /// `eu-south-2`
+    EuSouth2,
+    /// `me-central-1`
+    MeCentral1,
+    /// `me-south-1`
+    MeSouth1,
+    /// `sa-east-1`
+    SaEast1,
+    /// `us-east-1`
+    UsEast1,
+    /// `us-east-2`
+    UsEast2,
+    /// `us-west-1`
+    UsWest1,
+    /// `us-west-2`
+    UsWest2,
+    /// `cn-north-1`
+    CnNorth1,
+    /// `cn-northwest-1`
+    CnNorthwest1,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AWSRegion {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AWSRegion::AfSouth1 => "af-south-1",
+            AWSRegion::ApEast1 => "ap-east-1",
+            AWSRegion::ApSouth1 => "ap-south-1",
+            AWSRegion::ApSouth2 => "ap-south-2",
+            AWSRegion::ApSoutheast1 => "ap-southeast-1",
+            AWSRegion::ApSoutheast2 => "ap-southeast-2",
+            AWSRegion::ApSoutheast3 => "ap-southeast-3",
+            AWSRegion::ApNortheast1 => "ap-northeast-1",
+            AWSRegion::ApNortheast2 => "ap-northeast-2",
+            AWSRegion::ApNortheast3 => "ap-northeast-3",
+            AWSRegion::CaCentral1 => "ca-central-1",
+            AWSRegion::EuCentral1 => "eu-central-1",
+            AWSRegion::EuCentral2 => "eu-central-2",
+            AWSRegion::EuWest1 => "eu-west-1",
+            AWSRegion::EuWest2 => "eu-west-2",
+            AWSRegion::EuWest3 => "eu-west-3",
+            AWSRegion::EuNorth1 => "eu-north-1",
+            AWSRegion::EuSouth1 => "eu-south-1",
+            AWSRegion::EuSouth2 => "eu-south-2",
+            AWSRegion::MeCentral1 => "me-central-1",
+            AWSRegion::MeSouth1 => "me-south-1",
+            AWSRegion::SaEast1 => "sa-east-1",
+            AWSRegion::UsEast1 => "us-east-1",
+            AWSRegion::UsEast2 => "us-east-2",
+            AWSRegion::UsWest1 => "us-west-1",
+            AWSRegion::UsWest2 => "us-west-2",
+            AWSRegion::CnNorth1 => "cn-north-1",
+            AWSRegion::CnNorthwest1 => "cn-northwest-1",
+            AWSRegion::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AWSRegion {
+    fn default() -> AWSRegion {
+        AWSRegion::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AWSRegion {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AWSRegion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AWSRegion {
+    fn from(value: &'a str) -> AWSRegion {
+        match value {
+            "af-south-1" => AWSRegion::AfSouth1,
+            "ap-east-1" => AWSRegion::ApEast1,
+            "ap-south-1" => AWSRegion::ApSouth1,
+            "ap-south-2" => AWSRegion::ApSouth2,
+            "ap-southeast-1" => AWSRegion::ApSoutheast1,
+            "ap-southeast-2" => AWSRegion::ApSoutheast2,
+            "ap-southeast-3" => AWSRegion::ApSoutheast3,
+            "ap-northeast-1" => AWSRegion::ApNortheast1,
+            "ap-northeast-2" => AWSRegion::ApNortheast2,
+            "ap-northeast-3" => AWSRegion::ApNortheast3,
+            "ca-central-1" => AWSRegion::CaCentral1,
+            "eu-central-1" => AWSRegion::EuCentral1,
+            "eu-central-2" => AWSRegion::EuCentral2,
+            "eu-west-1" => AWSRegion::EuWest1,
+            "eu-west-2" => AWSRegion::EuWest2,
+            "eu-west-3" => AWSRegion::EuWest3,
+            "eu-north-1" => AWSRegion::EuNorth1,
+            "eu-south-1" => AWSRegion::EuSouth1,
+            "eu-south-2" => AWSRegion::EuSouth2,
+            "me-central-1" => AWSRegion::MeCentral1,
+            "me-south-1" => AWSRegion::MeSouth1,
+            "sa-east-1" => AWSRegion::SaEast1,
+            "us-east-1" => AWSRegion::UsEast1,
+            "us-east-2" => AWSRegion::UsEast2,
+            "us-west-1" => AWSRegion::UsWest1,
+            "us-west-2" => AWSRegion::UsWest2,
+            "cn-north-1" => AWSRegion::CnNorth1,
+            "cn-northwest-1" => AWSRegion::CnNorthwest1,
+            _ => AWSRegion::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AWSRegion {
+    fn from(value: String) -> AWSRegion {
+        match value.as_str() {
+            "af-south-1" => AWSRegion::AfSouth1,
+            "ap-east-1" => AWSRegion::ApEast1,
+            "ap-south-1" => AWSRegion::ApSouth1,
+            "ap-south-2" => AWSRegion::ApSouth2,
+            "ap-southeast-1" => AWSRegion::ApSoutheast1,
+            "ap-southeast-2" => AWSRegion::ApSoutheast2,
+            "ap-southeast-3" => AWSRegion::ApSoutheast3,
+            "ap-northeast-1" => AWSRegion::ApNortheast1,
+            "ap-northeast-2" => AWSRegion::ApNortheast2,
+            "ap-northeast-3" => AWSRegion::ApNortheast3,
+            "ca-central-1" => AWSRegion::CaCentral1,
+            "eu-central-1" => AWSRegion::EuCentral1,
+            "eu-central-2" => AWSRegion::EuCentral2,
+            "eu-west-1" => AWSRegion::EuWest1,
+            "eu-west-2" => AWSRegion::EuWest2,
+            "eu-west-3" => AWSRegion::EuWest3,
+            "eu-north-1" => AWSRegion::EuNorth1,
+            "eu-south-1" => AWSRegion::EuSouth1,
+            "eu-south-2" => AWSRegion::EuSouth2,
+            "me-central-1" => AWSRegion::MeCentral1,
+            "me-south-1" => AWSRegion::MeSouth1,
+            "sa-east-1" => AWSRegion::SaEast1,
+            "us-east-1" => AWSRegion::UsEast1,
+            "us-east-2" => AWSRegion::UsEast2,
+            "us-west-1" => AWSRegion::UsWest1,
+            "us-west-2" => AWSRegion::UsWest2,
+            "cn-north-1" => AWSRegion::CnNorth1,
+            "cn-northwest-1" => AWSRegion::CnNorthwest1,
+            _ => AWSRegion::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AWSRegion {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AWSRegion, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AWSRegion {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AWSRegion {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AWSRegion {
+    fn deserialize<D>(deserializer: D) -> Result<AWSRegion, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>The types of manifest that you want Amazon Web Services to create for this report.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AdditionalArtifact {
+    /// `REDSHIFT`
+    Redshift,
+    /// `QUICKSIGHT`
+    Quicksight,
+    /// `ATHENA`
+    Athena,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AdditionalArtifact {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AdditionalArtifact::Redshift => "REDSHIFT",
+            AdditionalArtifact::Quicksight => "QUICKSIGHT",
+            AdditionalArtifact::Athena => "ATHENA",
+            AdditionalArtifact::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AdditionalArtifact {
+    fn default() -> AdditionalArtifact {
+        AdditionalArtifact::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AdditionalArtifact {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AdditionalArtifact {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AdditionalArtifact {
+    fn from(value: &'a str) -> AdditionalArtifact {
+        match value {
+            "REDSHIFT" => AdditionalArtifact::Redshift,
+            "QUICKSIGHT" => AdditionalArtifact::Quicksight,
+            "ATHENA" => AdditionalArtifact::Athena,
+            _ => AdditionalArtifact::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AdditionalArtifact {
+    fn from(value: String) -> AdditionalArtifact {
+        match value.as_str() {
+            "REDSHIFT" => AdditionalArtifact::Redshift,
+            "QUICKSIGHT" => AdditionalArtifact::Quicksight,
+            "ATHENA" => AdditionalArtifact::Athena,
+            _ => AdditionalArtifact::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AdditionalArtifact {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AdditionalArtifact, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AdditionalArtifact {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AdditionalArtifact {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AdditionalArtifact {
+    fn deserialize<D>(deserializer: D) -> Result<AdditionalArtifact, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>The compression format that Amazon Web Services uses for the report.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CompressionFormat {
+    /// `ZIP`
+    Zip,
+    /// `GZIP`
+    Gzip,
+    /// `Parquet`
+    Parquet,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CompressionFormat {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CompressionFormat::Zip => "ZIP",
+            CompressionFormat::Gzip => "GZIP",
+            CompressionFormat::Parquet => "Parquet",
+            CompressionFormat::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CompressionFormat {
+    fn default() -> CompressionFormat {
+        CompressionFormat::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CompressionFormat {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CompressionFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CompressionFormat {
+    fn from(value: &'a str) -> CompressionFormat {
+        match value {
+            "ZIP" => CompressionFormat::Zip,
+            "GZIP" => CompressionFormat::Gzip,
+            "Parquet" => CompressionFormat::Parquet,
+            _ => CompressionFormat::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CompressionFormat {
+    fn from(value: String) -> CompressionFormat {
+        match value.as_str() {
+            "ZIP" => CompressionFormat::Zip,
+            "GZIP" => CompressionFormat::Gzip,
+            "Parquet" => CompressionFormat::Parquet,
+            _ => CompressionFormat::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CompressionFormat {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CompressionFormat, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CompressionFormat {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CompressionFormat {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CompressionFormat {
+    fn deserialize<D>(deserializer: D) -> Result<CompressionFormat, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Deletes the specified report.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -100,14 +522,14 @@ pub struct ReportDefinition {
     /// <p>A list of manifests that you want Amazon Web Services to create for this report.</p>
     #[serde(rename = "AdditionalArtifacts")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub additional_artifacts: Option<Vec<String>>,
+    pub additional_artifacts: Option<Vec<AdditionalArtifact>>,
     /// <p>A list of strings that indicate additional content that Amazon Web Services includes in the report, such as individual resource IDs. </p>
     #[serde(rename = "AdditionalSchemaElements")]
-    pub additional_schema_elements: Vec<String>,
+    pub additional_schema_elements: Vec<SchemaElement>,
     #[serde(rename = "Compression")]
-    pub compression: String,
+    pub compression: CompressionFormat,
     #[serde(rename = "Format")]
-    pub format: String,
+    pub format: ReportFormat,
     /// <p>Whether you want Amazon Web Services to update your reports after they have been finalized if Amazon Web Services detects charges related to previous months. These charges can include refunds, credits, or support fees.</p>
     #[serde(rename = "RefreshClosedReports")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -117,17 +539,402 @@ pub struct ReportDefinition {
     /// <p>Whether you want Amazon Web Services to overwrite the previous version of each report or to deliver the report in addition to the previous versions.</p>
     #[serde(rename = "ReportVersioning")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub report_versioning: Option<String>,
+    pub report_versioning: Option<ReportVersioning>,
     #[serde(rename = "S3Bucket")]
     pub s3_bucket: String,
     #[serde(rename = "S3Prefix")]
     pub s3_prefix: String,
     #[serde(rename = "S3Region")]
-    pub s3_region: String,
+    pub s3_region: AWSRegion,
     #[serde(rename = "TimeUnit")]
-    pub time_unit: String,
+    pub time_unit: TimeUnit,
+}
+
+/// <p>The format that Amazon Web Services saves the report in.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ReportFormat {
+    /// `textORcsv`
+    TextORcsv,
+    /// `Parquet`
+    Parquet,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ReportFormat {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReportFormat::TextORcsv => "textORcsv",
+            ReportFormat::Parquet => "Parquet",
+            ReportFormat::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ReportFormat {
+    fn default() -> ReportFormat {
+        ReportFormat::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ReportFormat {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ReportFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ReportFormat {
+    fn from(value: &'a str) -> ReportFormat {
+        match value {
+            "textORcsv" => ReportFormat::TextORcsv,
+            "Parquet" => ReportFormat::Parquet,
+            _ => ReportFormat::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReportFormat {
+    fn from(value: String) -> ReportFormat {
+        match value.as_str() {
+            "textORcsv" => ReportFormat::TextORcsv,
+            "Parquet" => ReportFormat::Parquet,
+            _ => ReportFormat::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ReportFormat {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ReportFormat, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ReportFormat {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ReportFormat {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ReportFormat {
+    fn deserialize<D>(deserializer: D) -> Result<ReportFormat, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ReportVersioning {
+    /// `CREATE_NEW_REPORT`
+    CreateNewReport,
+    /// `OVERWRITE_REPORT`
+    OverwriteReport,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ReportVersioning {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReportVersioning::CreateNewReport => "CREATE_NEW_REPORT",
+            ReportVersioning::OverwriteReport => "OVERWRITE_REPORT",
+            ReportVersioning::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ReportVersioning {
+    fn default() -> ReportVersioning {
+        ReportVersioning::Unknown(String::new())
+    }
 }
 
+impl AsRef<str> for ReportVersioning {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ReportVersioning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ReportVersioning {
+    fn from(value: &'a str) -> ReportVersioning {
+        match value {
+            "CREATE_NEW_REPORT" => ReportVersioning::CreateNewReport,
+            "OVERWRITE_REPORT" => ReportVersioning::OverwriteReport,
+            _ => ReportVersioning::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReportVersioning {
+    fn from(value: String) -> ReportVersioning {
+        match value.as_str() {
+            "CREATE_NEW_REPORT" => ReportVersioning::CreateNewReport,
+            "OVERWRITE_REPORT" => ReportVersioning::OverwriteReport,
+            _ => ReportVersioning::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ReportVersioning {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ReportVersioning, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ReportVersioning {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ReportVersioning {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ReportVersioning {
+    fn deserialize<D>(deserializer: D) -> Result<ReportVersioning, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Whether or not Amazon Web Services includes resource IDs in the report. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SchemaElement {
+    /// `RESOURCES`
+    Resources,
+    /// `SPLIT_COST_ALLOCATION_DATA`
+    SplitCostAllocationData,
+    /// `MANUAL_DISCOUNT_COMPATIBILITY`
+    ManualDiscountCompatibility,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SchemaElement {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SchemaElement::Resources => "RESOURCES",
+            SchemaElement::SplitCostAllocationData => "SPLIT_COST_ALLOCATION_DATA",
+            SchemaElement::ManualDiscountCompatibility => "MANUAL_DISCOUNT_COMPATIBILITY",
+            SchemaElement::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SchemaElement {
+    fn default() -> SchemaElement {
+        SchemaElement::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SchemaElement {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SchemaElement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SchemaElement {
+    fn from(value: &'a str) -> SchemaElement {
+        match value {
+            "RESOURCES" => SchemaElement::Resources,
+            "SPLIT_COST_ALLOCATION_DATA" => SchemaElement::SplitCostAllocationData,
+            "MANUAL_DISCOUNT_COMPATIBILITY" => SchemaElement::ManualDiscountCompatibility,
+            _ => SchemaElement::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SchemaElement {
+    fn from(value: String) -> SchemaElement {
+        match value.as_str() {
+            "RESOURCES" => SchemaElement::Resources,
+            "SPLIT_COST_ALLOCATION_DATA" => SchemaElement::SplitCostAllocationData,
+            "MANUAL_DISCOUNT_COMPATIBILITY" => SchemaElement::ManualDiscountCompatibility,
+            _ => SchemaElement::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SchemaElement {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SchemaElement, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SchemaElement {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SchemaElement {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SchemaElement {
+    fn deserialize<D>(deserializer: D) -> Result<SchemaElement, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>The length of time covered by the report. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TimeUnit {
+    /// `HOURLY`
+    Hourly,
+    /// `DAILY`
+    Daily,
+    /// `MONTHLY`
+    Monthly,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TimeUnit {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TimeUnit::Hourly => "HOURLY",
+            TimeUnit::Daily => "DAILY",
+            TimeUnit::Monthly => "MONTHLY",
+            TimeUnit::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TimeUnit {
+    fn default() -> TimeUnit {
+        TimeUnit::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TimeUnit {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TimeUnit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TimeUnit {
+    fn from(value: &'a str) -> TimeUnit {
+        match value {
+            "HOURLY" => TimeUnit::Hourly,
+            "DAILY" => TimeUnit::Daily,
+            "MONTHLY" => TimeUnit::Monthly,
+            _ => TimeUnit::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TimeUnit {
+    fn from(value: String) -> TimeUnit {
+        match value.as_str() {
+            "HOURLY" => TimeUnit::Hourly,
+            "DAILY" => TimeUnit::Daily,
+            "MONTHLY" => TimeUnit::Monthly,
+            _ => TimeUnit::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TimeUnit {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TimeUnit, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TimeUnit {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TimeUnit {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TimeUnit {
+    fn deserialize<D>(deserializer: D) -> Result<TimeUnit, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// Errors returned by DeleteReportDefinition
 #[derive(Debug, PartialEq)]
 pub enum DeleteReportDefinitionError {
@@ -307,11 +1114,15 @@ pub struct CostAndUsageReportClient {
 impl CostAndUsageReportClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> CostAndUsageReportClient {
         CostAndUsageReportClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(
+                region,
+                "Cost and Usage Report Service",
+            ),
         }
     }
 
@@ -343,6 +1154,7 @@ impl CostAndUsageReport for CostAndUsageReportClient {
         input: DeleteReportDefinitionRequest,
     ) -> Result<DeleteReportDefinitionResponse, RusotoError<DeleteReportDefinitionError>> {
         let mut request = SignedRequest::new("POST", "cur", &self.region, "/");
+        request.set_operation("DeleteReportDefinition");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -375,6 +1187,7 @@ impl CostAndUsageReport for CostAndUsageReportClient {
     ) -> Result<DescribeReportDefinitionsResponse, RusotoError<DescribeReportDefinitionsError>>
     {
         let mut request = SignedRequest::new("POST", "cur", &self.region, "/");
+        request.set_operation("DescribeReportDefinitions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -406,6 +1219,7 @@ impl CostAndUsageReport for CostAndUsageReportClient {
         input: ModifyReportDefinitionRequest,
     ) -> Result<ModifyReportDefinitionResponse, RusotoError<ModifyReportDefinitionError>> {
         let mut request = SignedRequest::new("POST", "cur", &self.region, "/");
+        request.set_operation("ModifyReportDefinition");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -437,6 +1251,7 @@ impl CostAndUsageReport for CostAndUsageReportClient {
         input: PutReportDefinitionRequest,
     ) -> Result<PutReportDefinitionResponse, RusotoError<PutReportDefinitionError>> {
         let mut request = SignedRequest::new("POST", "cur", &self.region, "/");
+        request.set_operation("PutReportDefinition");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -462,3 +1277,42 @@ impl CostAndUsageReport for CostAndUsageReportClient {
         }
     }
 }
+impl CostAndUsageReportClient {
+    /// Auto-paginating version of `describe_report_definitions`, streaming every page of results.
+    pub fn describe_report_definitions_pages(
+        &self,
+        input: DescribeReportDefinitionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeReportDefinitionsResponse,
+        DescribeReportDefinitionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CostAndUsageReportClient, input: DescribeReportDefinitionsRequest| async move {
+                client.describe_report_definitions(input).await
+            },
+            |input: &DescribeReportDefinitionsRequest,
+             output: &DescribeReportDefinitionsResponse| {
+                Some(DescribeReportDefinitionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_report_definitions`, streaming the `ReportDefinitions` of every page of results.
+    pub fn describe_report_definitions_items(
+        &self,
+        input: DescribeReportDefinitionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ReportDefinition, DescribeReportDefinitionsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_report_definitions_pages(input),
+            |output: DescribeReportDefinitionsResponse| {
+                output.report_definitions.unwrap_or_default()
+            },
+        )
+    }
+}
@@ -60,10 +60,15 @@ pub struct AssetEntry {
     pub asset_details: AssetDetails,
     /// <p>The type of file your data is stored in. Currently, the supported asset type is S3_SNAPSHOT.</p>
     #[serde(rename = "AssetType")]
-    pub asset_type: String,
+    pub asset_type: AssetType,
     /// <p>The date and time that the asset was created, in ISO 8601 format.</p>
     #[serde(rename = "CreatedAt")]
-    pub created_at: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
+    pub created_at: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The unique identifier for the data set associated with this asset.</p>
     #[serde(rename = "DataSetId")]
     pub data_set_id: String,
@@ -82,7 +87,12 @@ pub struct AssetEntry {
     pub source_id: Option<String>,
     /// <p>The date and time that the asset was last updated, in ISO 8601 format.</p>
     #[serde(rename = "UpdatedAt")]
-    pub updated_at: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
+    pub updated_at: ::chrono::DateTime<::chrono::Utc>,
 }
 
 /// <p>The source of the assets.</p>
@@ -96,6 +106,114 @@ pub struct AssetSourceEntry {
     pub key: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AssetType {
+    /// `S3_SNAPSHOT`
+    S3Snapshot,
+    /// `REDSHIFT_DATA_SHARE`
+    RedshiftDataShare,
+    /// `API_GATEWAY_API`
+    ApiGatewayApi,
+    /// `S3_DATA_ACCESS`
+    S3DataAccess,
+    /// `LAKE_FORMATION_DATA_PERMISSION`
+    LakeFormationDataPermission,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AssetType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AssetType::S3Snapshot => "S3_SNAPSHOT",
+            AssetType::RedshiftDataShare => "REDSHIFT_DATA_SHARE",
+            AssetType::ApiGatewayApi => "API_GATEWAY_API",
+            AssetType::S3DataAccess => "S3_DATA_ACCESS",
+            AssetType::LakeFormationDataPermission => "LAKE_FORMATION_DATA_PERMISSION",
+            AssetType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AssetType {
+    fn default() -> AssetType {
+        AssetType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AssetType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AssetType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AssetType {
+    fn from(value: &'a str) -> AssetType {
+        match value {
+            "S3_SNAPSHOT" => AssetType::S3Snapshot,
+            "REDSHIFT_DATA_SHARE" => AssetType::RedshiftDataShare,
+            "API_GATEWAY_API" => AssetType::ApiGatewayApi,
+            "S3_DATA_ACCESS" => AssetType::S3DataAccess,
+            "LAKE_FORMATION_DATA_PERMISSION" => AssetType::LakeFormationDataPermission,
+            _ => AssetType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AssetType {
+    fn from(value: String) -> AssetType {
+        match value.as_str() {
+            "S3_SNAPSHOT" => AssetType::S3Snapshot,
+            "REDSHIFT_DATA_SHARE" => AssetType::RedshiftDataShare,
+            "API_GATEWAY_API" => AssetType::ApiGatewayApi,
+            "S3_DATA_ACCESS" => AssetType::S3DataAccess,
+            "LAKE_FORMATION_DATA_PERMISSION" => AssetType::LakeFormationDataPermission,
+            _ => AssetType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AssetType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AssetType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AssetType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AssetType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AssetType {
+    fn deserialize<D>(deserializer: D) -> Result<AssetType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CancelJobRequest {
@@ -104,13 +222,131 @@ pub struct CancelJobRequest {
     pub job_id: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Code {
+    /// `ACCESS_DENIED_EXCEPTION`
+    AccessDeniedException,
+    /// `INTERNAL_SERVER_EXCEPTION`
+    InternalServerException,
+    /// `MALWARE_DETECTED`
+    MalwareDetected,
+    /// `RESOURCE_NOT_FOUND_EXCEPTION`
+    ResourceNotFoundException,
+    /// `SERVICE_QUOTA_EXCEEDED_EXCEPTION`
+    ServiceQuotaExceededException,
+    /// `VALIDATION_EXCEPTION`
+    ValidationException,
+    /// `MALWARE_SCAN_ENCRYPTED_FILE`
+    MalwareScanEncryptedFile,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Code {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Code::AccessDeniedException => "ACCESS_DENIED_EXCEPTION",
+            Code::InternalServerException => "INTERNAL_SERVER_EXCEPTION",
+            Code::MalwareDetected => "MALWARE_DETECTED",
+            Code::ResourceNotFoundException => "RESOURCE_NOT_FOUND_EXCEPTION",
+            Code::ServiceQuotaExceededException => "SERVICE_QUOTA_EXCEEDED_EXCEPTION",
+            Code::ValidationException => "VALIDATION_EXCEPTION",
+            Code::MalwareScanEncryptedFile => "MALWARE_SCAN_ENCRYPTED_FILE",
+            Code::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Code {
+    fn default() -> Code {
+        Code::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Code {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Code {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Code {
+    fn from(value: &'a str) -> Code {
+        match value {
+            "ACCESS_DENIED_EXCEPTION" => Code::AccessDeniedException,
+            "INTERNAL_SERVER_EXCEPTION" => Code::InternalServerException,
+            "MALWARE_DETECTED" => Code::MalwareDetected,
+            "RESOURCE_NOT_FOUND_EXCEPTION" => Code::ResourceNotFoundException,
+            "SERVICE_QUOTA_EXCEEDED_EXCEPTION" => Code::ServiceQuotaExceededException,
+            "VALIDATION_EXCEPTION" => Code::ValidationException,
+            "MALWARE_SCAN_ENCRYPTED_FILE" => Code::MalwareScanEncryptedFile,
+            _ => Code::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Code {
+    fn from(value: String) -> Code {
+        match value.as_str() {
+            "ACCESS_DENIED_EXCEPTION" => Code::AccessDeniedException,
+            "INTERNAL_SERVER_EXCEPTION" => Code::InternalServerException,
+            "MALWARE_DETECTED" => Code::MalwareDetected,
+            "RESOURCE_NOT_FOUND_EXCEPTION" => Code::ResourceNotFoundException,
+            "SERVICE_QUOTA_EXCEEDED_EXCEPTION" => Code::ServiceQuotaExceededException,
+            "VALIDATION_EXCEPTION" => Code::ValidationException,
+            "MALWARE_SCAN_ENCRYPTED_FILE" => Code::MalwareScanEncryptedFile,
+            _ => Code::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Code {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Code, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Code {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Code {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Code {
+    fn deserialize<D>(deserializer: D) -> Result<Code, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The request body for CreateDataSet.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateDataSetRequest {
     /// <p>The type of file your data is stored in. Currently, the supported asset type is S3_SNAPSHOT.</p>
     #[serde(rename = "AssetType")]
-    pub asset_type: String,
+    pub asset_type: AssetType,
     /// <p>A description for the data set. This value can be up to 16,348 characters long.</p>
     #[serde(rename = "Description")]
     pub description: String,
@@ -133,11 +369,16 @@ pub struct CreateDataSetResponse {
     /// <p>The type of file your data is stored in. Currently, the supported asset type is S3_SNAPSHOT.</p>
     #[serde(rename = "AssetType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub asset_type: Option<String>,
+    pub asset_type: Option<AssetType>,
     /// <p>The date and time that the data set was created, in ISO 8601 format.</p>
     #[serde(rename = "CreatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The description for the data set.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -153,7 +394,7 @@ pub struct CreateDataSetResponse {
     /// <p>A property that defines the data set as OWNED by the account (for providers) or ENTITLED to the account (for subscribers).</p>
     #[serde(rename = "Origin")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub origin: Option<String>,
+    pub origin: Option<Origin>,
     /// <p>If the origin of this data set is ENTITLED, includes the details for the product on AWS Marketplace.</p>
     #[serde(rename = "OriginDetails")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -168,8 +409,13 @@ pub struct CreateDataSetResponse {
     pub tags: Option<::std::collections::HashMap<String, String>>,
     /// <p>The date and time that the data set was last updated, in ISO 8601 format.</p>
     #[serde(rename = "UpdatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<f64>,
+    pub updated_at: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>The request body for CreateJob.</p>
@@ -181,7 +427,7 @@ pub struct CreateJobRequest {
     pub details: RequestDetails,
     /// <p>The type of job to be created.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: Type,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -193,8 +439,13 @@ pub struct CreateJobResponse {
     pub arn: Option<String>,
     /// <p>The date and time that the job was created, in ISO 8601 format.</p>
     #[serde(rename = "CreatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Details about the job.</p>
     #[serde(rename = "Details")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -210,15 +461,20 @@ pub struct CreateJobResponse {
     /// <p>The state of the job.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<State>,
     /// <p>The job type.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<Type>,
     /// <p>The date and time that the job was last updated, in ISO 8601 format.</p>
     #[serde(rename = "UpdatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<f64>,
+    pub updated_at: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>The request body for CreateRevision.</p>
@@ -251,8 +507,13 @@ pub struct CreateRevisionResponse {
     pub comment: Option<String>,
     /// <p>The date and time that the revision was created, in ISO 8601 format.</p>
     #[serde(rename = "CreatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The unique identifier for the data set associated with this revision.</p>
     #[serde(rename = "DataSetId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -275,8 +536,13 @@ pub struct CreateRevisionResponse {
     pub tags: Option<::std::collections::HashMap<String, String>>,
     /// <p>The date and time that the revision was last updated, in ISO 8601 format.</p>
     #[serde(rename = "UpdatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<f64>,
+    pub updated_at: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>A data set is an AWS resource with one or more revisions.</p>
@@ -288,10 +554,15 @@ pub struct DataSetEntry {
     pub arn: String,
     /// <p>The type of file your data is stored in. Currently, the supported asset type is S3_SNAPSHOT.</p>
     #[serde(rename = "AssetType")]
-    pub asset_type: String,
+    pub asset_type: AssetType,
     /// <p>The date and time that the data set was created, in ISO 8601 format.</p>
     #[serde(rename = "CreatedAt")]
-    pub created_at: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
+    pub created_at: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The description for the data set.</p>
     #[serde(rename = "Description")]
     pub description: String,
@@ -303,7 +574,7 @@ pub struct DataSetEntry {
     pub name: String,
     /// <p>A property that defines the data set as OWNED by the account (for providers) or ENTITLED to the account (for subscribers).</p>
     #[serde(rename = "Origin")]
-    pub origin: String,
+    pub origin: Origin,
     /// <p>If the origin of this data set is ENTITLED, includes the details for the product on AWS Marketplace.</p>
     #[serde(rename = "OriginDetails")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -314,7 +585,12 @@ pub struct DataSetEntry {
     pub source_id: Option<String>,
     /// <p>The date and time that the data set was last updated, in ISO 8601 format.</p>
     #[serde(rename = "UpdatedAt")]
-    pub updated_at: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
+    pub updated_at: ::chrono::DateTime<::chrono::Utc>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -396,8 +672,13 @@ pub struct ExportAssetToSignedUrlResponseDetails {
     pub signed_url: Option<String>,
     /// <p>The date and time that the signed URL expires, in ISO 8601 format.</p>
     #[serde(rename = "SignedUrlExpiresAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub signed_url_expires_at: Option<f64>,
+    pub signed_url_expires_at: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>Details of the operation to be performed by the job.</p>
@@ -458,11 +739,16 @@ pub struct GetAssetResponse {
     /// <p>The type of file your data is stored in. Currently, the supported asset type is S3_SNAPSHOT.</p>
     #[serde(rename = "AssetType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub asset_type: Option<String>,
+    pub asset_type: Option<AssetType>,
     /// <p>The date and time that the asset was created, in ISO 8601 format.</p>
     #[serde(rename = "CreatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The unique identifier for the data set associated with this asset.</p>
     #[serde(rename = "DataSetId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -485,8 +771,13 @@ pub struct GetAssetResponse {
     pub source_id: Option<String>,
     /// <p>The date and time that the asset was last updated, in ISO 8601 format.</p>
     #[serde(rename = "UpdatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<f64>,
+    pub updated_at: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -507,11 +798,16 @@ pub struct GetDataSetResponse {
     /// <p>The type of file your data is stored in. Currently, the supported asset type is S3_SNAPSHOT.</p>
     #[serde(rename = "AssetType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub asset_type: Option<String>,
+    pub asset_type: Option<AssetType>,
     /// <p>The date and time that the data set was created, in ISO 8601 format.</p>
     #[serde(rename = "CreatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The description for the data set.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -527,7 +823,7 @@ pub struct GetDataSetResponse {
     /// <p>A property that defines the data set as OWNED by the account (for providers) or ENTITLED to the account (for subscribers).</p>
     #[serde(rename = "Origin")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub origin: Option<String>,
+    pub origin: Option<Origin>,
     /// <p>If the origin of this data set is ENTITLED, includes the details for the product on AWS Marketplace.</p>
     #[serde(rename = "OriginDetails")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -542,8 +838,13 @@ pub struct GetDataSetResponse {
     pub tags: Option<::std::collections::HashMap<String, String>>,
     /// <p>The date and time that the data set was last updated, in ISO 8601 format.</p>
     #[serde(rename = "UpdatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<f64>,
+    pub updated_at: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -563,8 +864,13 @@ pub struct GetJobResponse {
     pub arn: Option<String>,
     /// <p>The date and time that the job was created, in ISO 8601 format.</p>
     #[serde(rename = "CreatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Details about the job.</p>
     #[serde(rename = "Details")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -580,15 +886,20 @@ pub struct GetJobResponse {
     /// <p>The state of the job.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<State>,
     /// <p>The job type.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<Type>,
     /// <p>The date and time that the job was last updated, in ISO 8601 format.</p>
     #[serde(rename = "UpdatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<f64>,
+    pub updated_at: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -615,8 +926,13 @@ pub struct GetRevisionResponse {
     pub comment: Option<String>,
     /// <p>The date and time that the revision was created, in ISO 8601 format.</p>
     #[serde(rename = "CreatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The unique identifier for the data set associated with this revision.</p>
     #[serde(rename = "DataSetId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -639,8 +955,13 @@ pub struct GetRevisionResponse {
     pub tags: Option<::std::collections::HashMap<String, String>>,
     /// <p>The date and time that the revision was last updated, in ISO 8601 format.</p>
     #[serde(rename = "UpdatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<f64>,
+    pub updated_at: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -691,8 +1012,13 @@ pub struct ImportAssetFromSignedUrlResponseDetails {
     pub signed_url: Option<String>,
     /// <p>The time and date at which the signed URL expires, in ISO 8601 format.</p>
     #[serde(rename = "SignedUrlExpiresAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub signed_url_expires_at: Option<f64>,
+    pub signed_url_expires_at: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>Details of the operation to be performed by the job.</p>
@@ -734,7 +1060,12 @@ pub struct JobEntry {
     pub arn: String,
     /// <p>The date and time that the job was created, in ISO 8601 format.</p>
     #[serde(rename = "CreatedAt")]
-    pub created_at: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
+    pub created_at: ::chrono::DateTime<::chrono::Utc>,
     /// <p>Details of the operation to be performed by the job, such as export destination details or import source details.</p>
     #[serde(rename = "Details")]
     pub details: ResponseDetails,
@@ -747,13 +1078,18 @@ pub struct JobEntry {
     pub id: String,
     /// <p>The state of the job.</p>
     #[serde(rename = "State")]
-    pub state: String,
+    pub state: State,
     /// <p>The job type.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: Type,
     /// <p>The date and time that the job was last updated, in ISO 8601 format.</p>
     #[serde(rename = "UpdatedAt")]
-    pub updated_at: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
+    pub updated_at: ::chrono::DateTime<::chrono::Utc>,
 }
 
 /// <p>An error that occurred with the job request.</p>
@@ -762,14 +1098,14 @@ pub struct JobEntry {
 pub struct JobError {
     /// <p>The code for the job error.</p>
     #[serde(rename = "Code")]
-    pub code: String,
+    pub code: Code,
     #[serde(rename = "Details")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub details: Option<Details>,
     /// <p>The name of the limit that was reached.</p>
     #[serde(rename = "LimitName")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub limit_name: Option<String>,
+    pub limit_name: Option<JobErrorLimitName>,
     /// <p>The value of the exceeded limit.</p>
     #[serde(rename = "LimitValue")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -784,9 +1120,492 @@ pub struct JobError {
     /// <p>The type of resource related to the error.</p>
     #[serde(rename = "ResourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<JobErrorResourceTypes>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum JobErrorLimitName {
+    /// `Assets per revision`
+    AssetsPerRevision,
+    /// `Asset size in GB`
+    AssetSizeInGb,
+    /// `Amazon Redshift datashare assets per revision`
+    AmazonRedshiftDatashareAssetsPerRevision,
+    /// `AWS Lake Formation data permission assets per revision`
+    AwsLakeFormationDataPermissionAssetsPerRevision,
+    /// `Amazon S3 data access assets per revision`
+    AmazonS3DataAccessAssetsPerRevision,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl JobErrorLimitName {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            JobErrorLimitName::AssetsPerRevision => "Assets per revision",
+            JobErrorLimitName::AssetSizeInGb => "Asset size in GB",
+            JobErrorLimitName::AmazonRedshiftDatashareAssetsPerRevision => {
+                "Amazon Redshift datashare assets per revision"
+            }
+            JobErrorLimitName::AwsLakeFormationDataPermissionAssetsPerRevision => {
+                "AWS Lake Formation data permission assets per revision"
+            }
+            JobErrorLimitName::AmazonS3DataAccessAssetsPerRevision => {
+                "Amazon S3 data access assets per revision"
+            }
+            JobErrorLimitName::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for JobErrorLimitName {
+    fn default() -> JobErrorLimitName {
+        JobErrorLimitName::Unknown(String::new())
+    }
 }
 
+impl AsRef<str> for JobErrorLimitName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for JobErrorLimitName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for JobErrorLimitName {
+    fn from(value: &'a str) -> JobErrorLimitName {
+        match value {
+            "Assets per revision" => JobErrorLimitName::AssetsPerRevision,
+            "Asset size in GB" => JobErrorLimitName::AssetSizeInGb,
+            "Amazon Redshift datashare assets per revision" => {
+                JobErrorLimitName::AmazonRedshiftDatashareAssetsPerRevision
+            }
+            "AWS Lake Formation data permission assets per revision" => {
+                JobErrorLimitName::AwsLakeFormationDataPermissionAssetsPerRevision
+            }
+            "Amazon S3 data access assets per revision" => {
+                JobErrorLimitName::AmazonS3DataAccessAssetsPerRevision
+            }
+            _ => JobErrorLimitName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for JobErrorLimitName {
+    fn from(value: String) -> JobErrorLimitName {
+        match value.as_str() {
+            "Assets per revision" => JobErrorLimitName::AssetsPerRevision,
+            "Asset size in GB" => JobErrorLimitName::AssetSizeInGb,
+            "Amazon Redshift datashare assets per revision" => {
+                JobErrorLimitName::AmazonRedshiftDatashareAssetsPerRevision
+            }
+            "AWS Lake Formation data permission assets per revision" => {
+                JobErrorLimitName::AwsLakeFormationDataPermissionAssetsPerRevision
+            }
+            "Amazon S3 data access assets per revision" => {
+                JobErrorLimitName::AmazonS3DataAccessAssetsPerRevision
+            }
+            _ => JobErrorLimitName::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for JobErrorLimitName {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<JobErrorLimitName, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for JobErrorLimitName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for JobErrorLimitName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for JobErrorLimitName {
+    fn deserialize<D>(deserializer: D) -> Result<JobErrorLimitName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum JobErrorResourceTypes {
+    /// `REVISION`
+    Revision,
+    /// `ASSET`
+    Asset,
+    /// `DATA_SET`
+    DataSet,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl JobErrorResourceTypes {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            JobErrorResourceTypes::Revision => "REVISION",
+            JobErrorResourceTypes::Asset => "ASSET",
+            JobErrorResourceTypes::DataSet => "DATA_SET",
+            JobErrorResourceTypes::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for JobErrorResourceTypes {
+    fn default() -> JobErrorResourceTypes {
+        JobErrorResourceTypes::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for JobErrorResourceTypes {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for JobErrorResourceTypes {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for JobErrorResourceTypes {
+    fn from(value: &'a str) -> JobErrorResourceTypes {
+        match value {
+            "REVISION" => JobErrorResourceTypes::Revision,
+            "ASSET" => JobErrorResourceTypes::Asset,
+            "DATA_SET" => JobErrorResourceTypes::DataSet,
+            _ => JobErrorResourceTypes::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for JobErrorResourceTypes {
+    fn from(value: String) -> JobErrorResourceTypes {
+        match value.as_str() {
+            "REVISION" => JobErrorResourceTypes::Revision,
+            "ASSET" => JobErrorResourceTypes::Asset,
+            "DATA_SET" => JobErrorResourceTypes::DataSet,
+            _ => JobErrorResourceTypes::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for JobErrorResourceTypes {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<JobErrorResourceTypes, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for JobErrorResourceTypes {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for JobErrorResourceTypes {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for JobErrorResourceTypes {
+    fn deserialize<D>(deserializer: D) -> Result<JobErrorResourceTypes, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LimitName {
+    /// `Products per account`
+    ProductsPerAccount,
+    /// `Data sets per account`
+    DataSetsPerAccount,
+    /// `Data sets per product`
+    DataSetsPerProduct,
+    /// `Revisions per data set`
+    RevisionsPerDataSet,
+    /// `Assets per revision`
+    AssetsPerRevision,
+    /// `Assets per import job from Amazon S3`
+    AssetsPerImportJobFromAmazonS3,
+    /// `Asset per export job from Amazon S3`
+    AssetPerExportJobFromAmazonS3,
+    /// `Asset size in GB`
+    AssetSizeInGb,
+    /// `Concurrent in progress jobs to export assets to Amazon S3`
+    ConcurrentInProgressJobsToExportAssetsToAmazonS3,
+    /// `Concurrent in progress jobs to export assets to a signed URL`
+    ConcurrentInProgressJobsToExportAssetsToASignedUrl,
+    /// `Concurrent in progress jobs to import assets from Amazon S3`
+    ConcurrentInProgressJobsToImportAssetsFromAmazonS3,
+    /// `Concurrent in progress jobs to import assets from a signed URL`
+    ConcurrentInProgressJobsToImportAssetsFromASignedUrl,
+    /// `Concurrent in progress jobs to export revisions to Amazon S3`
+    ConcurrentInProgressJobsToExportRevisionsToAmazonS3,
+    /// `Event actions per account`
+    EventActionsPerAccount,
+    /// `Auto export event actions per data set`
+    AutoExportEventActionsPerDataSet,
+    /// `Amazon Redshift datashare assets per import job from Redshift`
+    AmazonRedshiftDatashareAssetsPerImportJobFromRedshift,
+    /// `Concurrent in progress jobs to import assets from Amazon Redshift datashares`
+    ConcurrentInProgressJobsToImportAssetsFromAmazonRedshiftDatashares,
+    /// `Revisions per Amazon Redshift datashare data set`
+    RevisionsPerAmazonRedshiftDatashareDataSet,
+    /// `Amazon Redshift datashare assets per revision`
+    AmazonRedshiftDatashareAssetsPerRevision,
+    /// `Concurrent in progress jobs to import assets from an API Gateway API`
+    ConcurrentInProgressJobsToImportAssetsFromAnApiGatewayApi,
+    /// `Amazon API Gateway API assets per revision`
+    AmazonApiGatewayApiAssetsPerRevision,
+    /// `Revisions per Amazon API Gateway API data set`
+    RevisionsPerAmazonApiGatewayApiDataSet,
+    /// `Concurrent in progress jobs to import assets from an AWS Lake Formation tag policy`
+    ConcurrentInProgressJobsToImportAssetsFromAnAwsLakeFormationTagPolicy,
+    /// `AWS Lake Formation data permission assets per revision`
+    AwsLakeFormationDataPermissionAssetsPerRevision,
+    /// `Revisions per AWS Lake Formation data permission data set`
+    RevisionsPerAwsLakeFormationDataPermissionDataSet,
+    /// `Revisions per Amazon S3 data access data set`
+    RevisionsPerAmazonS3DataAccessDataSet,
+    /// `Amazon S3 data access assets per revision`
+    AmazonS3DataAccessAssetsPerRevision,
+    /// `Concurrent in progress jobs to create Amazon S3 data access assets from S3 buckets`
+    ConcurrentInProgressJobsToCreateAmazonS3DataAccessAssetsFromS3Buckets,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LimitName {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LimitName::ProductsPerAccount => "Products per account",
+            LimitName::DataSetsPerAccount => "Data sets per account",
+            LimitName::DataSetsPerProduct => "Data sets per product",
+            LimitName::RevisionsPerDataSet => "Revisions per data set",
+            LimitName::AssetsPerRevision => "Assets per revision",
+            LimitName::AssetsPerImportJobFromAmazonS3 => "Assets per import job from Amazon S3",
+            LimitName::AssetPerExportJobFromAmazonS3 => "Asset per export job from Amazon S3",
+            LimitName::AssetSizeInGb => "Asset size in GB",
+            LimitName::ConcurrentInProgressJobsToExportAssetsToAmazonS3 => {
+                "Concurrent in progress jobs to export assets to Amazon S3"
+            }
+            LimitName::ConcurrentInProgressJobsToExportAssetsToASignedUrl => {
+                "Concurrent in progress jobs to export assets to a signed URL"
+            }
+            LimitName::ConcurrentInProgressJobsToImportAssetsFromAmazonS3 => {
+                "Concurrent in progress jobs to import assets from Amazon S3"
+            }
+            LimitName::ConcurrentInProgressJobsToImportAssetsFromASignedUrl => {
+                "Concurrent in progress jobs to import assets from a signed URL"
+            }
+            LimitName::ConcurrentInProgressJobsToExportRevisionsToAmazonS3 => {
+                "Concurrent in progress jobs to export revisions to Amazon S3"
+            }
+            LimitName::EventActionsPerAccount => "Event actions per account",
+            LimitName::AutoExportEventActionsPerDataSet => "Auto export event actions per data set",
+            LimitName::AmazonRedshiftDatashareAssetsPerImportJobFromRedshift => {
+                "Amazon Redshift datashare assets per import job from Redshift"
+            }
+            LimitName::ConcurrentInProgressJobsToImportAssetsFromAmazonRedshiftDatashares => {
+                "Concurrent in progress jobs to import assets from Amazon Redshift datashares"
+            }
+            LimitName::RevisionsPerAmazonRedshiftDatashareDataSet => {
+                "Revisions per Amazon Redshift datashare data set"
+            }
+            LimitName::AmazonRedshiftDatashareAssetsPerRevision => {
+                "Amazon Redshift datashare assets per revision"
+            }
+            LimitName::ConcurrentInProgressJobsToImportAssetsFromAnApiGatewayApi => {
+                "Concurrent in progress jobs to import assets from an API Gateway API"
+            }
+            LimitName::AmazonApiGatewayApiAssetsPerRevision => {
+                "Amazon API Gateway API assets per revision"
+            }
+            LimitName::RevisionsPerAmazonApiGatewayApiDataSet => {
+                "Revisions per Amazon API Gateway API data set"
+            }
+            LimitName::ConcurrentInProgressJobsToImportAssetsFromAnAwsLakeFormationTagPolicy => {
+                "Concurrent in progress jobs to import assets from an AWS Lake Formation tag policy"
+            }
+            LimitName::AwsLakeFormationDataPermissionAssetsPerRevision => {
+                "AWS Lake Formation data permission assets per revision"
+            }
+            LimitName::RevisionsPerAwsLakeFormationDataPermissionDataSet => {
+                "Revisions per AWS Lake Formation data permission data set"
+            }
+            LimitName::RevisionsPerAmazonS3DataAccessDataSet => {
+                "Revisions per Amazon S3 data access data set"
+            }
+            LimitName::AmazonS3DataAccessAssetsPerRevision => {
+                "Amazon S3 data access assets per revision"
+            }
+            LimitName::ConcurrentInProgressJobsToCreateAmazonS3DataAccessAssetsFromS3Buckets => {
+                "Concurrent in progress jobs to create Amazon S3 data access assets from S3 buckets"
+            }
+            LimitName::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LimitName {
+    fn default() -> LimitName {
+        LimitName::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LimitName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LimitName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LimitName {
+    fn from(value: &'a str) -> LimitName {
+        match value {
+                    "Products per account" => LimitName::ProductsPerAccount,
+"Data sets per account" => LimitName::DataSetsPerAccount,
+"Data sets per product" => LimitName::DataSetsPerProduct,
+"Revisions per data set" => LimitName::RevisionsPerDataSet,
+"Assets per revision" => LimitName::AssetsPerRevision,
+"Assets per import job from Amazon S3" => LimitName::AssetsPerImportJobFromAmazonS3,
+"Asset per export job from Amazon S3" => LimitName::AssetPerExportJobFromAmazonS3,
+"Asset size in GB" => LimitName::AssetSizeInGb,
+"Concurrent in progress jobs to export assets to Amazon S3" => LimitName::ConcurrentInProgressJobsToExportAssetsToAmazonS3,
+"Concurrent in progress jobs to export assets to a signed URL" => LimitName::ConcurrentInProgressJobsToExportAssetsToASignedUrl,
+"Concurrent in progress jobs to import assets from Amazon S3" => LimitName::ConcurrentInProgressJobsToImportAssetsFromAmazonS3,
+"Concurrent in progress jobs to import assets from a signed URL" => LimitName::ConcurrentInProgressJobsToImportAssetsFromASignedUrl,
+"Concurrent in progress jobs to export revisions to Amazon S3" => LimitName::ConcurrentInProgressJobsToExportRevisionsToAmazonS3,
+"Event actions per account" => LimitName::EventActionsPerAccount,
+"Auto export event actions per data set" => LimitName::AutoExportEventActionsPerDataSet,
+"Amazon Redshift datashare assets per import job from Redshift" => LimitName::AmazonRedshiftDatashareAssetsPerImportJobFromRedshift,
+"Concurrent in progress jobs to import assets from Amazon Redshift datashares" => LimitName::ConcurrentInProgressJobsToImportAssetsFromAmazonRedshiftDatashares,
+"Revisions per Amazon Redshift datashare data set" => LimitName::RevisionsPerAmazonRedshiftDatashareDataSet,
+"Amazon Redshift datashare assets per revision" => LimitName::AmazonRedshiftDatashareAssetsPerRevision,
+"Concurrent in progress jobs to import assets from an API Gateway API" => LimitName::ConcurrentInProgressJobsToImportAssetsFromAnApiGatewayApi,
+"Amazon API Gateway API assets per revision" => LimitName::AmazonApiGatewayApiAssetsPerRevision,
+"Revisions per Amazon API Gateway API data set" => LimitName::RevisionsPerAmazonApiGatewayApiDataSet,
+"Concurrent in progress jobs to import assets from an AWS Lake Formation tag policy" => LimitName::ConcurrentInProgressJobsToImportAssetsFromAnAwsLakeFormationTagPolicy,
+"AWS Lake Formation data permission assets per revision" => LimitName::AwsLakeFormationDataPermissionAssetsPerRevision,
+"Revisions per AWS Lake Formation data permission data set" => LimitName::RevisionsPerAwsLakeFormationDataPermissionDataSet,
+"Revisions per Amazon S3 data access data set" => LimitName::RevisionsPerAmazonS3DataAccessDataSet,
+"Amazon S3 data access assets per revision" => LimitName::AmazonS3DataAccessAssetsPerRevision,
+"Concurrent in progress jobs to create Amazon S3 data access assets from S3 buckets" => LimitName::ConcurrentInProgressJobsToCreateAmazonS3DataAccessAssetsFromS3Buckets,
+                    _ => LimitName::Unknown(value.to_owned()),
+                }
+    }
+}
+
+impl From<String> for LimitName {
+    fn from(value: String) -> LimitName {
+        match value.as_str() {
+                    "Products per account" => LimitName::ProductsPerAccount,
+"Data sets per account" => LimitName::DataSetsPerAccount,
+"Data sets per product" => LimitName::DataSetsPerProduct,
+"Revisions per data set" => LimitName::RevisionsPerDataSet,
+"Assets per revision" => LimitName::AssetsPerRevision,
+"Assets per import job from Amazon S3" => LimitName::AssetsPerImportJobFromAmazonS3,
+"Asset per export job from Amazon S3" => LimitName::AssetPerExportJobFromAmazonS3,
+"Asset size in GB" => LimitName::AssetSizeInGb,
+"Concurrent in progress jobs to export assets to Amazon S3" => LimitName::ConcurrentInProgressJobsToExportAssetsToAmazonS3,
+"Concurrent in progress jobs to export assets to a signed URL" => LimitName::ConcurrentInProgressJobsToExportAssetsToASignedUrl,
+"Concurrent in progress jobs to import assets from Amazon S3" => LimitName::ConcurrentInProgressJobsToImportAssetsFromAmazonS3,
+"Concurrent in progress jobs to import assets from a signed URL" => LimitName::ConcurrentInProgressJobsToImportAssetsFromASignedUrl,
+"Concurrent in progress jobs to export revisions to Amazon S3" => LimitName::ConcurrentInProgressJobsToExportRevisionsToAmazonS3,
+"Event actions per account" => LimitName::EventActionsPerAccount,
+"Auto export event actions per data set" => LimitName::AutoExportEventActionsPerDataSet,
+"Amazon Redshift datashare assets per import job from Redshift" => LimitName::AmazonRedshiftDatashareAssetsPerImportJobFromRedshift,
+"Concurrent in progress jobs to import assets from Amazon Redshift datashares" => LimitName::ConcurrentInProgressJobsToImportAssetsFromAmazonRedshiftDatashares,
+"Revisions per Amazon Redshift datashare data set" => LimitName::RevisionsPerAmazonRedshiftDatashareDataSet,
+"Amazon Redshift datashare assets per revision" => LimitName::AmazonRedshiftDatashareAssetsPerRevision,
+"Concurrent in progress jobs to import assets from an API Gateway API" => LimitName::ConcurrentInProgressJobsToImportAssetsFromAnApiGatewayApi,
+"Amazon API Gateway API assets per revision" => LimitName::AmazonApiGatewayApiAssetsPerRevision,
+"Revisions per Amazon API Gateway API data set" => LimitName::RevisionsPerAmazonApiGatewayApiDataSet,
+"Concurrent in progress jobs to import assets from an AWS Lake Formation tag policy" => LimitName::ConcurrentInProgressJobsToImportAssetsFromAnAwsLakeFormationTagPolicy,
+"AWS Lake Formation data permission assets per revision" => LimitName::AwsLakeFormationDataPermissionAssetsPerRevision,
+"Revisions per AWS Lake Formation data permission data set" => LimitName::RevisionsPerAwsLakeFormationDataPermissionDataSet,
+"Revisions per Amazon S3 data access data set" => LimitName::RevisionsPerAmazonS3DataAccessDataSet,
+"Amazon S3 data access assets per revision" => LimitName::AmazonS3DataAccessAssetsPerRevision,
+"Concurrent in progress jobs to create Amazon S3 data access assets from S3 buckets" => LimitName::ConcurrentInProgressJobsToCreateAmazonS3DataAccessAssetsFromS3Buckets,
+                    _ => LimitName::Unknown(value),
+                }
+    }
+}
+
+impl ::std::str::FromStr for LimitName {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LimitName, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LimitName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LimitName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LimitName {
+    fn deserialize<D>(deserializer: D) -> Result<LimitName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ListDataSetRevisionsRequest {
@@ -929,6 +1748,99 @@ pub struct ListTagsForResourceResponse {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Origin {
+    /// `OWNED`
+    Owned,
+    /// `ENTITLED`
+    Entitled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Origin {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Origin::Owned => "OWNED",
+            Origin::Entitled => "ENTITLED",
+            Origin::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Origin {
+    fn default() -> Origin {
+        Origin::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Origin {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Origin {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Origin {
+    fn from(value: &'a str) -> Origin {
+        match value {
+            "OWNED" => Origin::Owned,
+            "ENTITLED" => Origin::Entitled,
+            _ => Origin::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Origin {
+    fn from(value: String) -> Origin {
+        match value.as_str() {
+            "OWNED" => Origin::Owned,
+            "ENTITLED" => Origin::Entitled,
+            _ => Origin::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Origin {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Origin, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Origin {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Origin {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Origin {
+    fn deserialize<D>(deserializer: D) -> Result<Origin, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct OriginDetails {
@@ -958,6 +1870,114 @@ pub struct RequestDetails {
     pub import_assets_from_s3: Option<ImportAssetsFromS3RequestDetails>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ResourceType {
+    /// `DATA_SET`
+    DataSet,
+    /// `REVISION`
+    Revision,
+    /// `ASSET`
+    Asset,
+    /// `JOB`
+    Job,
+    /// `EVENT_ACTION`
+    EventAction,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ResourceType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceType::DataSet => "DATA_SET",
+            ResourceType::Revision => "REVISION",
+            ResourceType::Asset => "ASSET",
+            ResourceType::Job => "JOB",
+            ResourceType::EventAction => "EVENT_ACTION",
+            ResourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ResourceType {
+    fn default() -> ResourceType {
+        ResourceType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ResourceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ResourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ResourceType {
+    fn from(value: &'a str) -> ResourceType {
+        match value {
+            "DATA_SET" => ResourceType::DataSet,
+            "REVISION" => ResourceType::Revision,
+            "ASSET" => ResourceType::Asset,
+            "JOB" => ResourceType::Job,
+            "EVENT_ACTION" => ResourceType::EventAction,
+            _ => ResourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceType {
+    fn from(value: String) -> ResourceType {
+        match value.as_str() {
+            "DATA_SET" => ResourceType::DataSet,
+            "REVISION" => ResourceType::Revision,
+            "ASSET" => ResourceType::Asset,
+            "JOB" => ResourceType::Job,
+            "EVENT_ACTION" => ResourceType::EventAction,
+            _ => ResourceType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ResourceType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ResourceType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ResourceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ResourceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ResourceType {
+    fn deserialize<D>(deserializer: D) -> Result<ResourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Details for the response.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -993,7 +2013,12 @@ pub struct RevisionEntry {
     pub comment: Option<String>,
     /// <p>The date and time that the revision was created, in ISO 8601 format.</p>
     #[serde(rename = "CreatedAt")]
-    pub created_at: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
+    pub created_at: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The unique identifier for the data set associated with this revision.</p>
     #[serde(rename = "DataSetId")]
     pub data_set_id: String,
@@ -1010,7 +2035,12 @@ pub struct RevisionEntry {
     pub source_id: Option<String>,
     /// <p>The date and time that the revision was last updated, in ISO 8601 format.</p>
     #[serde(rename = "UpdatedAt")]
-    pub updated_at: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
+    pub updated_at: ::chrono::DateTime<::chrono::Utc>,
 }
 
 /// <p>The S3 object that is the asset.</p>
@@ -1034,6 +2064,119 @@ pub struct StartJobRequest {
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartJobResponse {}
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum State {
+    /// `WAITING`
+    Waiting,
+    /// `IN_PROGRESS`
+    InProgress,
+    /// `ERROR`
+    Error,
+    /// `COMPLETED`
+    Completed,
+    /// `CANCELLED`
+    Cancelled,
+    /// `TIMED_OUT`
+    TimedOut,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl State {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            State::Waiting => "WAITING",
+            State::InProgress => "IN_PROGRESS",
+            State::Error => "ERROR",
+            State::Completed => "COMPLETED",
+            State::Cancelled => "CANCELLED",
+            State::TimedOut => "TIMED_OUT",
+            State::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for State {
+    fn default() -> State {
+        State::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for State {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for State {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for State {
+    fn from(value: &'a str) -> State {
+        match value {
+            "WAITING" => State::Waiting,
+            "IN_PROGRESS" => State::InProgress,
+            "ERROR" => State::Error,
+            "COMPLETED" => State::Completed,
+            "CANCELLED" => State::Cancelled,
+            "TIMED_OUT" => State::TimedOut,
+            _ => State::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for State {
+    fn from(value: String) -> State {
+        match value.as_str() {
+            "WAITING" => State::Waiting,
+            "IN_PROGRESS" => State::InProgress,
+            "ERROR" => State::Error,
+            "COMPLETED" => State::Completed,
+            "CANCELLED" => State::Cancelled,
+            "TIMED_OUT" => State::TimedOut,
+            _ => State::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for State {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<State, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for State {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for State {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for State {
+    fn deserialize<D>(deserializer: D) -> Result<State, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The request body for TagResource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -1046,6 +2189,140 @@ pub struct TagResourceRequest {
     pub tags: ::std::collections::HashMap<String, String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Type {
+    /// `IMPORT_ASSETS_FROM_S3`
+    ImportAssetsFromS3,
+    /// `IMPORT_ASSET_FROM_SIGNED_URL`
+    ImportAssetFromSignedUrl,
+    /// `EXPORT_ASSETS_TO_S3`
+    ExportAssetsToS3,
+    /// `EXPORT_ASSET_TO_SIGNED_URL`
+    ExportAssetToSignedUrl,
+    /// `EXPORT_REVISIONS_TO_S3`
+    ExportRevisionsToS3,
+    /// `IMPORT_ASSETS_FROM_REDSHIFT_DATA_SHARES`
+    ImportAssetsFromRedshiftDataShares,
+    /// `IMPORT_ASSET_FROM_API_GATEWAY_API`
+    ImportAssetFromApiGatewayApi,
+    /// `CREATE_S3_DATA_ACCESS_FROM_S3_BUCKET`
+    CreateS3DataAccessFromS3Bucket,
+    /// `IMPORT_ASSETS_FROM_LAKE_FORMATION_TAG_POLICY`
+    ImportAssetsFromLakeFormationTagPolicy,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Type {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Type::ImportAssetsFromS3 => "IMPORT_ASSETS_FROM_S3",
+            Type::ImportAssetFromSignedUrl => "IMPORT_ASSET_FROM_SIGNED_URL",
+            Type::ExportAssetsToS3 => "EXPORT_ASSETS_TO_S3",
+            Type::ExportAssetToSignedUrl => "EXPORT_ASSET_TO_SIGNED_URL",
+            Type::ExportRevisionsToS3 => "EXPORT_REVISIONS_TO_S3",
+            Type::ImportAssetsFromRedshiftDataShares => "IMPORT_ASSETS_FROM_REDSHIFT_DATA_SHARES",
+            Type::ImportAssetFromApiGatewayApi => "IMPORT_ASSET_FROM_API_GATEWAY_API",
+            Type::CreateS3DataAccessFromS3Bucket => "CREATE_S3_DATA_ACCESS_FROM_S3_BUCKET",
+            Type::ImportAssetsFromLakeFormationTagPolicy => {
+                "IMPORT_ASSETS_FROM_LAKE_FORMATION_TAG_POLICY"
+            }
+            Type::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Type {
+    fn default() -> Type {
+        Type::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Type {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Type {
+    fn from(value: &'a str) -> Type {
+        match value {
+            "IMPORT_ASSETS_FROM_S3" => Type::ImportAssetsFromS3,
+            "IMPORT_ASSET_FROM_SIGNED_URL" => Type::ImportAssetFromSignedUrl,
+            "EXPORT_ASSETS_TO_S3" => Type::ExportAssetsToS3,
+            "EXPORT_ASSET_TO_SIGNED_URL" => Type::ExportAssetToSignedUrl,
+            "EXPORT_REVISIONS_TO_S3" => Type::ExportRevisionsToS3,
+            "IMPORT_ASSETS_FROM_REDSHIFT_DATA_SHARES" => Type::ImportAssetsFromRedshiftDataShares,
+            "IMPORT_ASSET_FROM_API_GATEWAY_API" => Type::ImportAssetFromApiGatewayApi,
+            "CREATE_S3_DATA_ACCESS_FROM_S3_BUCKET" => Type::CreateS3DataAccessFromS3Bucket,
+            "IMPORT_ASSETS_FROM_LAKE_FORMATION_TAG_POLICY" => {
+                Type::ImportAssetsFromLakeFormationTagPolicy
+            }
+            _ => Type::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Type {
+    fn from(value: String) -> Type {
+        match value.as_str() {
+            "IMPORT_ASSETS_FROM_S3" => Type::ImportAssetsFromS3,
+            "IMPORT_ASSET_FROM_SIGNED_URL" => Type::ImportAssetFromSignedUrl,
+            "EXPORT_ASSETS_TO_S3" => Type::ExportAssetsToS3,
+            "EXPORT_ASSET_TO_SIGNED_URL" => Type::ExportAssetToSignedUrl,
+            "EXPORT_REVISIONS_TO_S3" => Type::ExportRevisionsToS3,
+            "IMPORT_ASSETS_FROM_REDSHIFT_DATA_SHARES" => Type::ImportAssetsFromRedshiftDataShares,
+            "IMPORT_ASSET_FROM_API_GATEWAY_API" => Type::ImportAssetFromApiGatewayApi,
+            "CREATE_S3_DATA_ACCESS_FROM_S3_BUCKET" => Type::CreateS3DataAccessFromS3Bucket,
+            "IMPORT_ASSETS_FROM_LAKE_FORMATION_TAG_POLICY" => {
+                Type::ImportAssetsFromLakeFormationTagPolicy
+            }
+            _ => Type::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Type {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Type, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Type {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Type {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Type {
+    fn deserialize<D>(deserializer: D) -> Result<Type, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct UntagResourceRequest {
@@ -1089,11 +2366,16 @@ pub struct UpdateAssetResponse {
     /// <p>The type of file your data is stored in. Currently, the supported asset type is S3_SNAPSHOT.</p>
     #[serde(rename = "AssetType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub asset_type: Option<String>,
+    pub asset_type: Option<AssetType>,
     /// <p>The date and time that the asset was created, in ISO 8601 format.</p>
     #[serde(rename = "CreatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The unique identifier for the data set associated with this asset.</p>
     #[serde(rename = "DataSetId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1116,8 +2398,13 @@ pub struct UpdateAssetResponse {
     pub source_id: Option<String>,
     /// <p>The date and time that the asset was last updated, in ISO 8601 format.</p>
     #[serde(rename = "UpdatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<f64>,
+    pub updated_at: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>The request body for UpdateDataSet.</p>
@@ -1147,11 +2434,16 @@ pub struct UpdateDataSetResponse {
     /// <p>The type of file your data is stored in. Currently, the supported asset type is S3_SNAPSHOT.</p>
     #[serde(rename = "AssetType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub asset_type: Option<String>,
+    pub asset_type: Option<AssetType>,
     /// <p>The date and time that the data set was created, in ISO 8601 format.</p>
     #[serde(rename = "CreatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The description for the data set.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1167,7 +2459,7 @@ pub struct UpdateDataSetResponse {
     /// <p>A property that defines the data set as OWNED by the account (for providers) or ENTITLED to the account (for subscribers).</p>
     #[serde(rename = "Origin")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub origin: Option<String>,
+    pub origin: Option<Origin>,
     /// <p>If the origin of this data set is ENTITLED, includes the details for the product on AWS Marketplace.</p>
     #[serde(rename = "OriginDetails")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1178,8 +2470,13 @@ pub struct UpdateDataSetResponse {
     pub source_id: Option<String>,
     /// <p>The date and time that the data set was last updated, in ISO 8601 format.</p>
     #[serde(rename = "UpdatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<f64>,
+    pub updated_at: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>The request body for UpdateRevision.</p>
@@ -1215,8 +2512,13 @@ pub struct UpdateRevisionResponse {
     pub comment: Option<String>,
     /// <p>The date and time that the revision was created, in ISO 8601 format.</p>
     #[serde(rename = "CreatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The unique identifier for the data set associated with this revision.</p>
     #[serde(rename = "DataSetId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1235,8 +2537,13 @@ pub struct UpdateRevisionResponse {
     pub source_id: Option<String>,
     /// <p>The date and time that the revision was last updated, in ISO 8601 format.</p>
     #[serde(rename = "UpdatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<f64>,
+    pub updated_at: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// Errors returned by CancelJob
@@ -2349,11 +3656,12 @@ pub struct DataExchangeClient {
 impl DataExchangeClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> DataExchangeClient {
         DataExchangeClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "DataExchange"),
         }
     }
 
@@ -2384,6 +3692,7 @@ impl DataExchange for DataExchangeClient {
         let request_uri = format!("/v1/jobs/{job_id}", job_id = input.job_id);
 
         let mut request = SignedRequest::new("DELETE", "dataexchange", &self.region, &request_uri);
+        request.set_operation("CancelJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2410,6 +3719,7 @@ impl DataExchange for DataExchangeClient {
         let request_uri = "/v1/data-sets";
 
         let mut request = SignedRequest::new("POST", "dataexchange", &self.region, &request_uri);
+        request.set_operation("CreateDataSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2440,6 +3750,7 @@ impl DataExchange for DataExchangeClient {
         let request_uri = "/v1/jobs";
 
         let mut request = SignedRequest::new("POST", "dataexchange", &self.region, &request_uri);
+        request.set_operation("CreateJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2473,6 +3784,7 @@ impl DataExchange for DataExchangeClient {
         );
 
         let mut request = SignedRequest::new("POST", "dataexchange", &self.region, &request_uri);
+        request.set_operation("CreateRevision");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2508,6 +3820,7 @@ impl DataExchange for DataExchangeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "dataexchange", &self.region, &request_uri);
+        request.set_operation("DeleteAsset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2537,6 +3850,7 @@ impl DataExchange for DataExchangeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "dataexchange", &self.region, &request_uri);
+        request.set_operation("DeleteDataSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2567,6 +3881,7 @@ impl DataExchange for DataExchangeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "dataexchange", &self.region, &request_uri);
+        request.set_operation("DeleteRevision");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2598,6 +3913,7 @@ impl DataExchange for DataExchangeClient {
         );
 
         let mut request = SignedRequest::new("GET", "dataexchange", &self.region, &request_uri);
+        request.set_operation("GetAsset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2628,6 +3944,7 @@ impl DataExchange for DataExchangeClient {
         );
 
         let mut request = SignedRequest::new("GET", "dataexchange", &self.region, &request_uri);
+        request.set_operation("GetDataSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2655,6 +3972,7 @@ impl DataExchange for DataExchangeClient {
         let request_uri = format!("/v1/jobs/{job_id}", job_id = input.job_id);
 
         let mut request = SignedRequest::new("GET", "dataexchange", &self.region, &request_uri);
+        request.set_operation("GetJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2686,6 +4004,7 @@ impl DataExchange for DataExchangeClient {
         );
 
         let mut request = SignedRequest::new("GET", "dataexchange", &self.region, &request_uri);
+        request.set_operation("GetRevision");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2716,6 +4035,7 @@ impl DataExchange for DataExchangeClient {
         );
 
         let mut request = SignedRequest::new("GET", "dataexchange", &self.region, &request_uri);
+        request.set_operation("ListDataSetRevisions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2752,6 +4072,7 @@ impl DataExchange for DataExchangeClient {
         let request_uri = "/v1/data-sets";
 
         let mut request = SignedRequest::new("GET", "dataexchange", &self.region, &request_uri);
+        request.set_operation("ListDataSets");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2791,6 +4112,7 @@ impl DataExchange for DataExchangeClient {
         let request_uri = "/v1/jobs";
 
         let mut request = SignedRequest::new("GET", "dataexchange", &self.region, &request_uri);
+        request.set_operation("ListJobs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2837,6 +4159,7 @@ impl DataExchange for DataExchangeClient {
         );
 
         let mut request = SignedRequest::new("GET", "dataexchange", &self.region, &request_uri);
+        request.set_operation("ListRevisionAssets");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2873,6 +4196,7 @@ impl DataExchange for DataExchangeClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "dataexchange", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2900,6 +4224,7 @@ impl DataExchange for DataExchangeClient {
         let request_uri = format!("/v1/jobs/{job_id}", job_id = input.job_id);
 
         let mut request = SignedRequest::new("PATCH", "dataexchange", &self.region, &request_uri);
+        request.set_operation("StartJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2927,6 +4252,7 @@ impl DataExchange for DataExchangeClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "dataexchange", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2956,6 +4282,7 @@ impl DataExchange for DataExchangeClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "dataexchange", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2993,6 +4320,7 @@ impl DataExchange for DataExchangeClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "dataexchange", &self.region, &request_uri);
+        request.set_operation("UpdateAsset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3026,6 +4354,7 @@ impl DataExchange for DataExchangeClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "dataexchange", &self.region, &request_uri);
+        request.set_operation("UpdateDataSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3060,6 +4389,7 @@ impl DataExchange for DataExchangeClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "dataexchange", &self.region, &request_uri);
+        request.set_operation("UpdateRevision");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3082,3 +4412,132 @@ impl DataExchange for DataExchangeClient {
         }
     }
 }
+impl DataExchangeClient {
+    /// Auto-paginating version of `list_data_set_revisions`, streaming every page of results.
+    pub fn list_data_set_revisions_pages(
+        &self,
+        input: ListDataSetRevisionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListDataSetRevisionsResponse,
+        ListDataSetRevisionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DataExchangeClient, input: ListDataSetRevisionsRequest| async move {
+                client.list_data_set_revisions(input).await
+            },
+            |input: &ListDataSetRevisionsRequest, output: &ListDataSetRevisionsResponse| {
+                Some(ListDataSetRevisionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_data_set_revisions`, streaming the `Revisions` of every page of results.
+    pub fn list_data_set_revisions_items(
+        &self,
+        input: ListDataSetRevisionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<RevisionEntry, ListDataSetRevisionsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_data_set_revisions_pages(input),
+            |output: ListDataSetRevisionsResponse| output.revisions.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_data_sets`, streaming every page of results.
+    pub fn list_data_sets_pages(
+        &self,
+        input: ListDataSetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListDataSetsResponse, ListDataSetsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DataExchangeClient, input: ListDataSetsRequest| async move {
+                client.list_data_sets(input).await
+            },
+            |input: &ListDataSetsRequest, output: &ListDataSetsResponse| {
+                Some(ListDataSetsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_data_sets`, streaming the `DataSets` of every page of results.
+    pub fn list_data_sets_items(
+        &self,
+        input: ListDataSetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DataSetEntry, ListDataSetsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_data_sets_pages(input),
+            |output: ListDataSetsResponse| output.data_sets.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_jobs`, streaming every page of results.
+    pub fn list_jobs_pages(
+        &self,
+        input: ListJobsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListJobsResponse, ListJobsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DataExchangeClient, input: ListJobsRequest| async move {
+                client.list_jobs(input).await
+            },
+            |input: &ListJobsRequest, output: &ListJobsResponse| {
+                Some(ListJobsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_jobs`, streaming the `Jobs` of every page of results.
+    pub fn list_jobs_items(
+        &self,
+        input: ListJobsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<JobEntry, ListJobsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_jobs_pages(input),
+            |output: ListJobsResponse| output.jobs.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_revision_assets`, streaming every page of results.
+    pub fn list_revision_assets_pages(
+        &self,
+        input: ListRevisionAssetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListRevisionAssetsResponse, ListRevisionAssetsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DataExchangeClient, input: ListRevisionAssetsRequest| async move {
+                client.list_revision_assets(input).await
+            },
+            |input: &ListRevisionAssetsRequest, output: &ListRevisionAssetsResponse| {
+                Some(ListRevisionAssetsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_revision_assets`, streaming the `Assets` of every page of results.
+    pub fn list_revision_assets_items(
+        &self,
+        input: ListRevisionAssetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<AssetEntry, ListRevisionAssetsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_revision_assets_pages(input),
+            |output: ListRevisionAssetsResponse| output.assets.unwrap_or_default(),
+        )
+    }
+}
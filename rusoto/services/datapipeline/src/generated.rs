@@ -37,8 +37,13 @@ pub struct ActivatePipelineInput {
     pub pipeline_id: String,
     /// <p>The date and time to resume the pipeline. By default, the pipeline resumes from the last completed execution.</p>
     #[serde(rename = "startTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_timestamp: Option<f64>,
+    pub start_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>Contains the output of ActivatePipeline.</p>
@@ -293,13 +298,121 @@ pub struct Operator {
     /// <p> The logical operation to be performed: equal (<code>EQ</code>), equal reference (<code>REF_EQ</code>), less than or equal (<code>LE</code>), greater than or equal (<code>GE</code>), or between (<code>BETWEEN</code>). Equal reference (<code>REF_EQ</code>) can be used only with reference fields. The other comparison types can be used only with String fields. The comparison types you can use apply only to certain object fields, as detailed below. </p> <p> The comparison operators EQ and REF_EQ act on the following fields: </p> <ul> <li>name</li> <li>@sphere</li> <li>parent</li> <li>@componentParent</li> <li>@instanceParent</li> <li>@status</li> <li>@scheduledStartTime</li> <li>@scheduledEndTime</li> <li>@actualStartTime</li> <li>@actualEndTime</li> </ul> <p> The comparison operators <code>GE</code>, <code>LE</code>, and <code>BETWEEN</code> act on the following fields: </p> <ul> <li>@scheduledStartTime</li> <li>@scheduledEndTime</li> <li>@actualStartTime</li> <li>@actualEndTime</li> </ul> <p>Note that fields beginning with the at sign (@) are read-only and set by the web service. When you name fields, you should choose names containing only alpha-numeric values, as symbols may be reserved by AWS Data Pipeline. User-defined fields that you add to a pipeline should prefix their name with the string "my".</p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<OperatorType>,
     /// <p>The value that the actual field value will be compared with.</p>
     #[serde(rename = "values")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub values: Option<Vec<String>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OperatorType {
+    /// `EQ`
+    Eq,
+    /// `REF_EQ`
+    RefEq,
+    /// `LE`
+    Le,
+    /// `GE`
+    Ge,
+    /// `BETWEEN`
+    Between,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl OperatorType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OperatorType::Eq => "EQ",
+            OperatorType::RefEq => "REF_EQ",
+            OperatorType::Le => "LE",
+            OperatorType::Ge => "GE",
+            OperatorType::Between => "BETWEEN",
+            OperatorType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for OperatorType {
+    fn default() -> OperatorType {
+        OperatorType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for OperatorType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for OperatorType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for OperatorType {
+    fn from(value: &'a str) -> OperatorType {
+        match value {
+            "EQ" => OperatorType::Eq,
+            "REF_EQ" => OperatorType::RefEq,
+            "LE" => OperatorType::Le,
+            "GE" => OperatorType::Ge,
+            "BETWEEN" => OperatorType::Between,
+            _ => OperatorType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OperatorType {
+    fn from(value: String) -> OperatorType {
+        match value.as_str() {
+            "EQ" => OperatorType::Eq,
+            "REF_EQ" => OperatorType::RefEq,
+            "LE" => OperatorType::Le,
+            "GE" => OperatorType::Ge,
+            "BETWEEN" => OperatorType::Between,
+            _ => OperatorType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for OperatorType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<OperatorType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OperatorType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for OperatorType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OperatorType {
+    fn deserialize<D>(deserializer: D) -> Result<OperatorType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The attributes allowed or specified with a parameter object.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ParameterAttribute {
@@ -614,7 +727,7 @@ pub struct SetTaskStatusInput {
     pub task_id: String,
     /// <p>If <code>FINISHED</code>, the task successfully completed. If <code>FAILED</code>, the task ended unsuccessfully. Preconditions use false.</p>
     #[serde(rename = "taskStatus")]
-    pub task_status: String,
+    pub task_status: TaskStatus,
 }
 
 /// <p>Contains the output of SetTaskStatus.</p>
@@ -655,6 +768,104 @@ pub struct TaskObject {
     pub task_id: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TaskStatus {
+    /// `FINISHED`
+    Finished,
+    /// `FAILED`
+    Failed,
+    /// `FALSE`
+    False,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TaskStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TaskStatus::Finished => "FINISHED",
+            TaskStatus::Failed => "FAILED",
+            TaskStatus::False => "FALSE",
+            TaskStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TaskStatus {
+    fn default() -> TaskStatus {
+        TaskStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TaskStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TaskStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TaskStatus {
+    fn from(value: &'a str) -> TaskStatus {
+        match value {
+            "FINISHED" => TaskStatus::Finished,
+            "FAILED" => TaskStatus::Failed,
+            "FALSE" => TaskStatus::False,
+            _ => TaskStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TaskStatus {
+    fn from(value: String) -> TaskStatus {
+        match value.as_str() {
+            "FINISHED" => TaskStatus::Finished,
+            "FAILED" => TaskStatus::Failed,
+            "FALSE" => TaskStatus::False,
+            _ => TaskStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TaskStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TaskStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TaskStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TaskStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TaskStatus {
+    fn deserialize<D>(deserializer: D) -> Result<TaskStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Contains the parameters for ValidatePipelineDefinition.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -1772,11 +1983,12 @@ pub struct DataPipelineClient {
 impl DataPipelineClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> DataPipelineClient {
         DataPipelineClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Data Pipeline"),
         }
     }
 
@@ -1808,6 +2020,7 @@ impl DataPipeline for DataPipelineClient {
         input: ActivatePipelineInput,
     ) -> Result<ActivatePipelineOutput, RusotoError<ActivatePipelineError>> {
         let mut request = SignedRequest::new("POST", "datapipeline", &self.region, "/");
+        request.set_operation("ActivatePipeline");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DataPipeline.ActivatePipeline");
@@ -1835,6 +2048,7 @@ impl DataPipeline for DataPipelineClient {
         input: AddTagsInput,
     ) -> Result<AddTagsOutput, RusotoError<AddTagsError>> {
         let mut request = SignedRequest::new("POST", "datapipeline", &self.region, "/");
+        request.set_operation("AddTags");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DataPipeline.AddTags");
@@ -1862,6 +2076,7 @@ impl DataPipeline for DataPipelineClient {
         input: CreatePipelineInput,
     ) -> Result<CreatePipelineOutput, RusotoError<CreatePipelineError>> {
         let mut request = SignedRequest::new("POST", "datapipeline", &self.region, "/");
+        request.set_operation("CreatePipeline");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DataPipeline.CreatePipeline");
@@ -1889,6 +2104,7 @@ impl DataPipeline for DataPipelineClient {
         input: DeactivatePipelineInput,
     ) -> Result<DeactivatePipelineOutput, RusotoError<DeactivatePipelineError>> {
         let mut request = SignedRequest::new("POST", "datapipeline", &self.region, "/");
+        request.set_operation("DeactivatePipeline");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DataPipeline.DeactivatePipeline");
@@ -1917,6 +2133,7 @@ impl DataPipeline for DataPipelineClient {
         input: DeletePipelineInput,
     ) -> Result<(), RusotoError<DeletePipelineError>> {
         let mut request = SignedRequest::new("POST", "datapipeline", &self.region, "/");
+        request.set_operation("DeletePipeline");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DataPipeline.DeletePipeline");
@@ -1944,6 +2161,7 @@ impl DataPipeline for DataPipelineClient {
         input: DescribeObjectsInput,
     ) -> Result<DescribeObjectsOutput, RusotoError<DescribeObjectsError>> {
         let mut request = SignedRequest::new("POST", "datapipeline", &self.region, "/");
+        request.set_operation("DescribeObjects");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DataPipeline.DescribeObjects");
@@ -1971,6 +2189,7 @@ impl DataPipeline for DataPipelineClient {
         input: DescribePipelinesInput,
     ) -> Result<DescribePipelinesOutput, RusotoError<DescribePipelinesError>> {
         let mut request = SignedRequest::new("POST", "datapipeline", &self.region, "/");
+        request.set_operation("DescribePipelines");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DataPipeline.DescribePipelines");
@@ -1998,6 +2217,7 @@ impl DataPipeline for DataPipelineClient {
         input: EvaluateExpressionInput,
     ) -> Result<EvaluateExpressionOutput, RusotoError<EvaluateExpressionError>> {
         let mut request = SignedRequest::new("POST", "datapipeline", &self.region, "/");
+        request.set_operation("EvaluateExpression");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DataPipeline.EvaluateExpression");
@@ -2026,6 +2246,7 @@ impl DataPipeline for DataPipelineClient {
         input: GetPipelineDefinitionInput,
     ) -> Result<GetPipelineDefinitionOutput, RusotoError<GetPipelineDefinitionError>> {
         let mut request = SignedRequest::new("POST", "datapipeline", &self.region, "/");
+        request.set_operation("GetPipelineDefinition");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DataPipeline.GetPipelineDefinition");
@@ -2054,6 +2275,7 @@ impl DataPipeline for DataPipelineClient {
         input: ListPipelinesInput,
     ) -> Result<ListPipelinesOutput, RusotoError<ListPipelinesError>> {
         let mut request = SignedRequest::new("POST", "datapipeline", &self.region, "/");
+        request.set_operation("ListPipelines");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DataPipeline.ListPipelines");
@@ -2081,6 +2303,7 @@ impl DataPipeline for DataPipelineClient {
         input: PollForTaskInput,
     ) -> Result<PollForTaskOutput, RusotoError<PollForTaskError>> {
         let mut request = SignedRequest::new("POST", "datapipeline", &self.region, "/");
+        request.set_operation("PollForTask");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DataPipeline.PollForTask");
@@ -2108,6 +2331,7 @@ impl DataPipeline for DataPipelineClient {
         input: PutPipelineDefinitionInput,
     ) -> Result<PutPipelineDefinitionOutput, RusotoError<PutPipelineDefinitionError>> {
         let mut request = SignedRequest::new("POST", "datapipeline", &self.region, "/");
+        request.set_operation("PutPipelineDefinition");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DataPipeline.PutPipelineDefinition");
@@ -2136,6 +2360,7 @@ impl DataPipeline for DataPipelineClient {
         input: QueryObjectsInput,
     ) -> Result<QueryObjectsOutput, RusotoError<QueryObjectsError>> {
         let mut request = SignedRequest::new("POST", "datapipeline", &self.region, "/");
+        request.set_operation("QueryObjects");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DataPipeline.QueryObjects");
@@ -2163,6 +2388,7 @@ impl DataPipeline for DataPipelineClient {
         input: RemoveTagsInput,
     ) -> Result<RemoveTagsOutput, RusotoError<RemoveTagsError>> {
         let mut request = SignedRequest::new("POST", "datapipeline", &self.region, "/");
+        request.set_operation("RemoveTags");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DataPipeline.RemoveTags");
@@ -2190,6 +2416,7 @@ impl DataPipeline for DataPipelineClient {
         input: ReportTaskProgressInput,
     ) -> Result<ReportTaskProgressOutput, RusotoError<ReportTaskProgressError>> {
         let mut request = SignedRequest::new("POST", "datapipeline", &self.region, "/");
+        request.set_operation("ReportTaskProgress");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DataPipeline.ReportTaskProgress");
@@ -2218,6 +2445,7 @@ impl DataPipeline for DataPipelineClient {
         input: ReportTaskRunnerHeartbeatInput,
     ) -> Result<ReportTaskRunnerHeartbeatOutput, RusotoError<ReportTaskRunnerHeartbeatError>> {
         let mut request = SignedRequest::new("POST", "datapipeline", &self.region, "/");
+        request.set_operation("ReportTaskRunnerHeartbeat");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DataPipeline.ReportTaskRunnerHeartbeat");
@@ -2243,6 +2471,7 @@ impl DataPipeline for DataPipelineClient {
     /// <p>Requests that the status of the specified physical or logical pipeline objects be updated in the specified pipeline. This update might not occur immediately, but is eventually consistent. The status that can be set depends on the type of object (for example, DataNode or Activity). You cannot perform this operation on <code>FINISHED</code> pipelines and attempting to do so returns <code>InvalidRequestException</code>.</p>
     async fn set_status(&self, input: SetStatusInput) -> Result<(), RusotoError<SetStatusError>> {
         let mut request = SignedRequest::new("POST", "datapipeline", &self.region, "/");
+        request.set_operation("SetStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DataPipeline.SetStatus");
@@ -2270,6 +2499,7 @@ impl DataPipeline for DataPipelineClient {
         input: SetTaskStatusInput,
     ) -> Result<SetTaskStatusOutput, RusotoError<SetTaskStatusError>> {
         let mut request = SignedRequest::new("POST", "datapipeline", &self.region, "/");
+        request.set_operation("SetTaskStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DataPipeline.SetTaskStatus");
@@ -2298,6 +2528,7 @@ impl DataPipeline for DataPipelineClient {
     ) -> Result<ValidatePipelineDefinitionOutput, RusotoError<ValidatePipelineDefinitionError>>
     {
         let mut request = SignedRequest::new("POST", "datapipeline", &self.region, "/");
+        request.set_operation("ValidatePipelineDefinition");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DataPipeline.ValidatePipelineDefinition");
@@ -2320,3 +2551,105 @@ impl DataPipeline for DataPipelineClient {
         }
     }
 }
+impl DataPipelineClient {
+    /// Auto-paginating version of `describe_objects`, streaming every page of results.
+    pub fn describe_objects_pages(
+        &self,
+        input: DescribeObjectsInput,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeObjectsOutput, DescribeObjectsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DataPipelineClient, input: DescribeObjectsInput| async move {
+                client.describe_objects(input).await
+            },
+            |input: &DescribeObjectsInput, output: &DescribeObjectsOutput| {
+                if !output.has_more_results.unwrap_or(false) {
+                    return None;
+                }
+                Some(DescribeObjectsInput {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_objects`, streaming the `pipelineObjects` of every page of results.
+    pub fn describe_objects_items(
+        &self,
+        input: DescribeObjectsInput,
+    ) -> rusoto_core::pagination::RusotoStream<PipelineObject, DescribeObjectsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_objects_pages(input),
+            |output: DescribeObjectsOutput| output.pipeline_objects,
+        )
+    }
+
+    /// Auto-paginating version of `list_pipelines`, streaming every page of results.
+    pub fn list_pipelines_pages(
+        &self,
+        input: ListPipelinesInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListPipelinesOutput, ListPipelinesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DataPipelineClient, input: ListPipelinesInput| async move {
+                client.list_pipelines(input).await
+            },
+            |_input: &ListPipelinesInput, output: &ListPipelinesOutput| {
+                if !output.has_more_results.unwrap_or(false) {
+                    return None;
+                }
+                Some(ListPipelinesInput {
+                    marker: Some(output.marker.clone()?),
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_pipelines`, streaming the `pipelineIdList` of every page of results.
+    pub fn list_pipelines_items(
+        &self,
+        input: ListPipelinesInput,
+    ) -> rusoto_core::pagination::RusotoStream<PipelineIdName, ListPipelinesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_pipelines_pages(input),
+            |output: ListPipelinesOutput| output.pipeline_id_list,
+        )
+    }
+
+    /// Auto-paginating version of `query_objects`, streaming every page of results.
+    pub fn query_objects_pages(
+        &self,
+        input: QueryObjectsInput,
+    ) -> rusoto_core::pagination::RusotoStream<QueryObjectsOutput, QueryObjectsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DataPipelineClient, input: QueryObjectsInput| async move {
+                client.query_objects(input).await
+            },
+            |input: &QueryObjectsInput, output: &QueryObjectsOutput| {
+                if !output.has_more_results.unwrap_or(false) {
+                    return None;
+                }
+                Some(QueryObjectsInput {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `query_objects`, streaming the `ids` of every page of results.
+    pub fn query_objects_items(
+        &self,
+        input: QueryObjectsInput,
+    ) -> rusoto_core::pagination::RusotoStream<String, QueryObjectsError> {
+        rusoto_core::pagination::paginate_items(
+            self.query_objects_pages(input),
+            |output: QueryObjectsOutput| output.ids.unwrap_or_default(),
+        )
+    }
+}
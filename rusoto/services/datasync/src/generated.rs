@@ -39,9 +39,195 @@ pub struct AgentListEntry {
     /// <p>The status of the agent.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<AgentStatus>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AgentStatus {
+    /// `ONLINE`
+    Online,
+    /// `OFFLINE`
+    Offline,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AgentStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AgentStatus::Online => "ONLINE",
+            AgentStatus::Offline => "OFFLINE",
+            AgentStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AgentStatus {
+    fn default() -> AgentStatus {
+        AgentStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AgentStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AgentStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AgentStatus {
+    fn from(value: &'a str) -> AgentStatus {
+        match value {
+            "ONLINE" => AgentStatus::Online,
+            "OFFLINE" => AgentStatus::Offline,
+            _ => AgentStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AgentStatus {
+    fn from(value: String) -> AgentStatus {
+        match value.as_str() {
+            "ONLINE" => AgentStatus::Online,
+            "OFFLINE" => AgentStatus::Offline,
+            _ => AgentStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AgentStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AgentStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AgentStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AgentStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AgentStatus {
+    fn deserialize<D>(deserializer: D) -> Result<AgentStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Atime {
+    /// `NONE`
+    None,
+    /// `BEST_EFFORT`
+    BestEffort,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Atime {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Atime::None => "NONE",
+            Atime::BestEffort => "BEST_EFFORT",
+            Atime::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Atime {
+    fn default() -> Atime {
+        Atime::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Atime {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Atime {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Atime {
+    fn from(value: &'a str) -> Atime {
+        match value {
+            "NONE" => Atime::None,
+            "BEST_EFFORT" => Atime::BestEffort,
+            _ => Atime::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Atime {
+    fn from(value: String) -> Atime {
+        match value.as_str() {
+            "NONE" => Atime::None,
+            "BEST_EFFORT" => Atime::BestEffort,
+            _ => Atime::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Atime {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Atime, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Atime {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Atime {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Atime {
+    fn deserialize<D>(deserializer: D) -> Result<Atime, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>CancelTaskExecutionRequest</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -207,7 +393,7 @@ pub struct CreateLocationS3Request {
     /// <p>The Amazon S3 storage class that you want to store your files in when this location is used as a task destination. For more information about S3 storage classes, see <a href="https://aws.amazon.com/s3/storage-classes/">Amazon S3 Storage Classes</a> in the <i>Amazon Simple Storage Service Developer Guide</i>. Some storage classes have behaviors that can affect your S3 storage cost. For detailed information, see <a>using-storage-classes</a>.</p>
     #[serde(rename = "S3StorageClass")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub s3_storage_class: Option<String>,
+    pub s3_storage_class: Option<S3StorageClass>,
     /// <p>A subdirectory in the Amazon S3 bucket. This subdirectory in Amazon S3 is used to read data from the S3 source location or write data to the S3 destination.</p>
     #[serde(rename = "Subdirectory")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -375,16 +561,26 @@ pub struct DescribeAgentResponse {
     pub agent_arn: Option<String>,
     /// <p>The time that the agent was activated (that is, created in your account).</p>
     #[serde(rename = "CreationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The type of endpoint that your agent is connected to. If the endpoint is a VPC endpoint, the agent is not accessible over the public Internet. </p>
     #[serde(rename = "EndpointType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub endpoint_type: Option<String>,
+    pub endpoint_type: Option<EndpointType>,
     /// <p>The time that the agent last connected to DataSyc.</p>
     #[serde(rename = "LastConnectionTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_connection_time: Option<f64>,
+    pub last_connection_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the agent.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -396,7 +592,7 @@ pub struct DescribeAgentResponse {
     /// <p>The status of the agent. If the status is ONLINE, then the agent is configured properly and is available to use. The Running status is the normal running status for an agent. If the status is OFFLINE, the agent's VM is turned off or the agent is in an unhealthy state. When the issue that caused the unhealthy state is resolved, the agent returns to ONLINE status.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<AgentStatus>,
 }
 
 /// <p>DescribeLocationEfsRequest</p>
@@ -414,8 +610,13 @@ pub struct DescribeLocationEfsRequest {
 pub struct DescribeLocationEfsResponse {
     /// <p>The time that the EFS location was created.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     #[serde(rename = "Ec2Config")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ec_2_config: Option<Ec2Config>,
@@ -442,8 +643,13 @@ pub struct DescribeLocationFsxWindowsRequest {
 pub struct DescribeLocationFsxWindowsResponse {
     /// <p>The time that the FSx for Windows location was created.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the Windows domain that the FSx for Windows server belongs to.</p>
     #[serde(rename = "Domain")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -481,8 +687,13 @@ pub struct DescribeLocationNfsRequest {
 pub struct DescribeLocationNfsResponse {
     /// <p>The time that the NFS location was created.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The Amazon resource Name (ARN) of the NFS location that was described.</p>
     #[serde(rename = "LocationArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -515,8 +726,13 @@ pub struct DescribeLocationS3Request {
 pub struct DescribeLocationS3Response {
     /// <p>The time that the Amazon S3 bucket location was created.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The Amazon Resource Name (ARN) of the Amazon S3 bucket location.</p>
     #[serde(rename = "LocationArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -531,7 +747,7 @@ pub struct DescribeLocationS3Response {
     /// <p>The Amazon S3 storage class that you chose to store your files in when this location is used as a task destination. For more information about S3 storage classes, see <a href="https://aws.amazon.com/s3/storage-classes/">Amazon S3 Storage Classes</a> in the <i>Amazon Simple Storage Service Developer Guide</i>. Some storage classes have behaviors that can affect your S3 storage cost. For detailed information, see <a>using-storage-classes</a>.</p>
     #[serde(rename = "S3StorageClass")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub s3_storage_class: Option<String>,
+    pub s3_storage_class: Option<S3StorageClass>,
 }
 
 /// <p>DescribeLocationSmbRequest</p>
@@ -553,8 +769,13 @@ pub struct DescribeLocationSmbResponse {
     pub agent_arns: Option<Vec<String>>,
     /// <p>The time that the SMB location was created.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the Windows domain that the SMB server belongs to.</p>
     #[serde(rename = "Domain")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -627,12 +848,17 @@ pub struct DescribeTaskExecutionResponse {
     pub result: Option<TaskExecutionResultDetail>,
     /// <p>The time that the task execution was started.</p>
     #[serde(rename = "StartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The status of the task execution. </p> <p>For detailed information about task execution statuses, see Understanding Task Statuses in the <i>AWS DataSync User Guide.</i> </p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<TaskExecutionStatus>,
     /// <p>The Amazon Resource Name (ARN) of the task execution that was described. <code>TaskExecutionArn</code> is hierarchical and includes <code>TaskArn</code> for the task that was executed. </p> <p>For example, a <code>TaskExecution</code> value with the ARN <code>arn:aws:datasync:us-east-1:111222333444:task/task-0208075f79cedf4a2/execution/exec-08ef1e88ec491019b</code> executed the task with the ARN <code>arn:aws:datasync:us-east-1:111222333444:task/task-0208075f79cedf4a2</code>. </p>
     #[serde(rename = "TaskExecutionArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -658,8 +884,13 @@ pub struct DescribeTaskResponse {
     pub cloud_watch_log_group_arn: Option<String>,
     /// <p>The time that the task was created.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The Amazon Resource Name (ARN) of the task execution that is syncing files.</p>
     #[serde(rename = "CurrentTaskExecutionArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -707,7 +938,7 @@ pub struct DescribeTaskResponse {
     /// <p>The status of the task that was described.</p> <p>For detailed information about task execution statuses, see Understanding Task Statuses in the <i>AWS DataSync User Guide.</i> </p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<TaskStatus>,
     /// <p>The Amazon Resource Name (ARN) of the task that was described.</p>
     #[serde(rename = "TaskArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -725,19 +956,308 @@ pub struct Ec2Config {
     pub subnet_arn: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EndpointType {
+    /// `PUBLIC`
+    Public,
+    /// `PRIVATE_LINK`
+    PrivateLink,
+    /// `FIPS`
+    Fips,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EndpointType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EndpointType::Public => "PUBLIC",
+            EndpointType::PrivateLink => "PRIVATE_LINK",
+            EndpointType::Fips => "FIPS",
+            EndpointType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EndpointType {
+    fn default() -> EndpointType {
+        EndpointType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EndpointType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EndpointType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EndpointType {
+    fn from(value: &'a str) -> EndpointType {
+        match value {
+            "PUBLIC" => EndpointType::Public,
+            "PRIVATE_LINK" => EndpointType::PrivateLink,
+            "FIPS" => EndpointType::Fips,
+            _ => EndpointType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EndpointType {
+    fn from(value: String) -> EndpointType {
+        match value.as_str() {
+            "PUBLIC" => EndpointType::Public,
+            "PRIVATE_LINK" => EndpointType::PrivateLink,
+            "FIPS" => EndpointType::Fips,
+            _ => EndpointType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EndpointType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EndpointType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EndpointType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EndpointType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EndpointType {
+    fn deserialize<D>(deserializer: D) -> Result<EndpointType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Specifies which files, folders and objects to include or exclude when transferring files from source to destination.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FilterRule {
     /// <p>The type of filter rule to apply. AWS DataSync only supports the SIMPLE_PATTERN rule type.</p>
     #[serde(rename = "FilterType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub filter_type: Option<String>,
+    pub filter_type: Option<FilterType>,
     /// <p>A single filter string that consists of the patterns to include or exclude. The patterns are delimited by "|" (that is, a pipe), for example: <code>/folder1|/folder2</code> </p> <p> </p>
     #[serde(rename = "Value")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FilterType {
+    /// `SIMPLE_PATTERN`
+    SimplePattern,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl FilterType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FilterType::SimplePattern => "SIMPLE_PATTERN",
+            FilterType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for FilterType {
+    fn default() -> FilterType {
+        FilterType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for FilterType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FilterType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FilterType {
+    fn from(value: &'a str) -> FilterType {
+        match value {
+            "SIMPLE_PATTERN" => FilterType::SimplePattern,
+            _ => FilterType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FilterType {
+    fn from(value: String) -> FilterType {
+        match value.as_str() {
+            "SIMPLE_PATTERN" => FilterType::SimplePattern,
+            _ => FilterType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for FilterType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<FilterType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FilterType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FilterType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FilterType {
+    fn deserialize<D>(deserializer: D) -> Result<FilterType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Gid {
+    /// `NONE`
+    None,
+    /// `INT_VALUE`
+    IntValue,
+    /// `NAME`
+    Name,
+    /// `BOTH`
+    Both,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Gid {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Gid::None => "NONE",
+            Gid::IntValue => "INT_VALUE",
+            Gid::Name => "NAME",
+            Gid::Both => "BOTH",
+            Gid::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Gid {
+    fn default() -> Gid {
+        Gid::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Gid {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Gid {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Gid {
+    fn from(value: &'a str) -> Gid {
+        match value {
+            "NONE" => Gid::None,
+            "INT_VALUE" => Gid::IntValue,
+            "NAME" => Gid::Name,
+            "BOTH" => Gid::Both,
+            _ => Gid::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Gid {
+    fn from(value: String) -> Gid {
+        match value.as_str() {
+            "NONE" => Gid::None,
+            "INT_VALUE" => Gid::IntValue,
+            "NAME" => Gid::Name,
+            "BOTH" => Gid::Both,
+            _ => Gid::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Gid {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Gid, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Gid {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Gid {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Gid {
+    fn deserialize<D>(deserializer: D) -> Result<Gid, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>ListAgentsRequest</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -899,76 +1419,840 @@ pub struct LocationListEntry {
     pub location_uri: Option<String>,
 }
 
-/// <p>Represents the mount options that are available for DataSync to access an NFS location.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct NfsMountOptions {
-    /// <p><p>The specific NFS version that you want DataSync to use to mount your NFS share. If the server refuses to use the version specified, the sync will fail. If you don&#39;t specify a version, DataSync defaults to <code>AUTOMATIC</code>. That is, DataSync automatically selects a version based on negotiation with the NFS server.</p> <p>You can specify the following NFS versions:</p> <ul> <li> <p> <b> <a href="https://tools.ietf.org/html/rfc1813">NFSv3</a> </b> - stateless protocol version that allows for asynchronous writes on the server.</p> </li> <li> <p> <b> <a href="https://tools.ietf.org/html/rfc3530">NFSv4.0</a> </b> - stateful, firewall-friendly protocol version that supports delegations and pseudo filesystems.</p> </li> <li> <p> <b> <a href="https://tools.ietf.org/html/rfc5661">NFSv4.1</a> </b> - stateful protocol version that supports sessions, directory delegations, and parallel data processing. Version 4.1 also includes all features available in version 4.0.</p> </li> </ul></p>
-    #[serde(rename = "Version")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub version: Option<String>,
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LogLevel {
+    /// `OFF`
+    Off,
+    /// `BASIC`
+    Basic,
+    /// `TRANSFER`
+    Transfer,
+    /// A value not known to this version of the crate
+    Unknown(String),
 }
 
-/// <p>A list of Amazon Resource Names (ARNs) of agents to use for a Network File System (NFS) location.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct OnPremConfig {
-    /// <p>ARNs)of the agents to use for an NFS location.</p>
-    #[serde(rename = "AgentArns")]
-    pub agent_arns: Vec<String>,
+impl LogLevel {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LogLevel::Off => "OFF",
+            LogLevel::Basic => "BASIC",
+            LogLevel::Transfer => "TRANSFER",
+            LogLevel::Unknown(ref value) => value,
+        }
+    }
 }
 
-/// <p>Represents the options that are available to control the behavior of a <a>StartTaskExecution</a> operation. Behavior includes preserving metadata such as user ID (UID), group ID (GID), and file permissions, and also overwriting files in the destination, data integrity verification, and so on.</p> <p>A task has a set of default options associated with it. If you don't specify an option in <a>StartTaskExecution</a>, the default value is used. You can override the defaults options on each task execution by specifying an overriding <code>Options</code> value to <a>StartTaskExecution</a>.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct Options {
-    /// <p><p>A file metadata value that shows the last time a file was accessed (that is, when the file was read or written to). If you set <code>Atime</code> to BEST<em>EFFORT, DataSync attempts to preserve the original <code>Atime</code> attribute on all source files (that is, the version before the PREPARING phase). However, <code>Atime</code>&#39;s behavior is not fully standard across platforms, so AWS DataSync can only do this on a best-effort basis. </p> <p>Default value: BEST</em>EFFORT.</p> <p>BEST<em>EFFORT: Attempt to preserve the per-file <code>Atime</code> value (recommended).</p> <p>NONE: Ignore <code>Atime</code>.</p> <note> <p>If <code>Atime</code> is set to BEST</em>EFFORT, <code>Mtime</code> must be set to PRESERVE. </p> <p>If <code>Atime</code> is set to NONE, <code>Mtime</code> must also be NONE. </p> </note></p>
-    #[serde(rename = "Atime")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub atime: Option<String>,
-    /// <p>A value that limits the bandwidth used by AWS DataSync. For example, if you want AWS DataSync to use a maximum of 1 MB, set this value to <code>1048576</code> (<code>=1024*1024</code>).</p>
-    #[serde(rename = "BytesPerSecond")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub bytes_per_second: Option<i64>,
-    /// <p>The group ID (GID) of the file's owners. </p> <p>Default value: INT_VALUE. This preserves the integer value of the ID.</p> <p>INT_VALUE: Preserve the integer value of user ID (UID) and GID (recommended).</p> <p>NONE: Ignore UID and GID. </p>
-    #[serde(rename = "Gid")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub gid: Option<String>,
-    /// <p>A value that determines the type of logs DataSync will deliver to your AWS CloudWatch Logs file. If set to <code>OFF</code>, no logs will be delivered. <code>BASIC</code> will deliver a few logs per transfer operation and <code>TRANSFER</code> will deliver a verbose log that contains logs for every file that is transferred.</p>
-    #[serde(rename = "LogLevel")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub log_level: Option<String>,
-    /// <p><p>A value that indicates the last time that a file was modified (that is, a file was written to) before the PREPARING phase. </p> <p>Default value: PRESERVE. </p> <p>PRESERVE: Preserve original <code>Mtime</code> (recommended)</p> <p> NONE: Ignore <code>Mtime</code>. </p> <note> <p>If <code>Mtime</code> is set to PRESERVE, <code>Atime</code> must be set to BEST_EFFORT.</p> <p>If <code>Mtime</code> is set to NONE, <code>Atime</code> must also be set to NONE. </p> </note></p>
-    #[serde(rename = "Mtime")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub mtime: Option<String>,
-    /// <p>A value that determines whether files at the destination should be overwritten or preserved when copying files. If set to <code>NEVER</code> a destination file will not be replaced by a source file, even if the destination file differs from the source file. If you modify files in the destination and you sync the files, you can use this value to protect against overwriting those changes. </p> <p>Some storage classes have specific behaviors that can affect your S3 storage cost. For detailed information, see <a>using-storage-classes</a> in the <i>AWS DataSync User Guide</i>.</p>
-    #[serde(rename = "OverwriteMode")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub overwrite_mode: Option<String>,
-    /// <p><p>A value that determines which users or groups can access a file for a specific purpose such as reading, writing, or execution of the file. </p> <p>Default value: PRESERVE.</p> <p>PRESERVE: Preserve POSIX-style permissions (recommended).</p> <p>NONE: Ignore permissions. </p> <note> <p>AWS DataSync can preserve extant permissions of a source location.</p> </note></p>
-    #[serde(rename = "PosixPermissions")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub posix_permissions: Option<String>,
-    /// <p>A value that specifies whether files in the destination that don't exist in the source file system should be preserved. This option can affect your storage cost. If your task deletes objects, you might incur minimum storage duration charges for certain storage classes. For detailed information, see <a>using-storage-classes</a> in the <i>AWS DataSync User Guide</i>.</p> <p>Default value: PRESERVE.</p> <p>PRESERVE: Ignore such destination files (recommended). </p> <p>REMOVE: Delete destination files that aren’t present in the source.</p>
-    #[serde(rename = "PreserveDeletedFiles")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub preserve_deleted_files: Option<String>,
-    /// <p>A value that determines whether AWS DataSync should preserve the metadata of block and character devices in the source file system, and recreate the files with that device name and metadata on the destination.</p> <note> <p>AWS DataSync can't sync the actual contents of such devices, because they are nonterminal and don't return an end-of-file (EOF) marker.</p> </note> <p>Default value: NONE.</p> <p>NONE: Ignore special devices (recommended). </p> <p>PRESERVE: Preserve character and block device metadata. This option isn't currently supported for Amazon EFS. </p>
-    #[serde(rename = "PreserveDevices")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub preserve_devices: Option<String>,
-    /// <p>A value that determines whether tasks should be queued before executing the tasks. If set to <code>ENABLED</code>, the tasks will be queued. The default is <code>ENABLED</code>.</p> <p>If you use the same agent to run multiple tasks you can enable the tasks to run in series. For more information see <a>queue-task-execution</a>.</p>
-    #[serde(rename = "TaskQueueing")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub task_queueing: Option<String>,
-    /// <p>The user ID (UID) of the file's owner. </p> <p>Default value: INT_VALUE. This preserves the integer value of the ID.</p> <p>INT_VALUE: Preserve the integer value of UID and group ID (GID) (recommended).</p> <p>NONE: Ignore UID and GID. </p>
-    #[serde(rename = "Uid")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub uid: Option<String>,
-    /// <p>A value that determines whether a data integrity verification should be performed at the end of a task execution after all data and metadata have been transferred. </p> <p>Default value: POINT_IN_TIME_CONSISTENT.</p> <p>POINT_IN_TIME_CONSISTENT: Perform verification (recommended). </p> <p>ONLY_FILES_TRANSFERRED: Perform verification on only files that were transferred.</p> <p>NONE: Skip verification.</p>
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LogLevel {
+    fn default() -> LogLevel {
+        LogLevel::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LogLevel {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LogLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LogLevel {
+    fn from(value: &'a str) -> LogLevel {
+        match value {
+            "OFF" => LogLevel::Off,
+            "BASIC" => LogLevel::Basic,
+            "TRANSFER" => LogLevel::Transfer,
+            _ => LogLevel::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LogLevel {
+    fn from(value: String) -> LogLevel {
+        match value.as_str() {
+            "OFF" => LogLevel::Off,
+            "BASIC" => LogLevel::Basic,
+            "TRANSFER" => LogLevel::Transfer,
+            _ => LogLevel::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LogLevel {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LogLevel, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LogLevel {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LogLevel {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LogLevel {
+    fn deserialize<D>(deserializer: D) -> Result<LogLevel, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Mtime {
+    /// `NONE`
+    None,
+    /// `PRESERVE`
+    Preserve,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Mtime {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Mtime::None => "NONE",
+            Mtime::Preserve => "PRESERVE",
+            Mtime::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Mtime {
+    fn default() -> Mtime {
+        Mtime::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Mtime {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Mtime {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Mtime {
+    fn from(value: &'a str) -> Mtime {
+        match value {
+            "NONE" => Mtime::None,
+            "PRESERVE" => Mtime::Preserve,
+            _ => Mtime::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Mtime {
+    fn from(value: String) -> Mtime {
+        match value.as_str() {
+            "NONE" => Mtime::None,
+            "PRESERVE" => Mtime::Preserve,
+            _ => Mtime::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Mtime {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Mtime, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Mtime {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Mtime {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Mtime {
+    fn deserialize<D>(deserializer: D) -> Result<Mtime, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Represents the mount options that are available for DataSync to access an NFS location.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NfsMountOptions {
+    /// <p><p>The specific NFS version that you want DataSync to use to mount your NFS share. If the server refuses to use the version specified, the sync will fail. If you don&#39;t specify a version, DataSync defaults to <code>AUTOMATIC</code>. That is, DataSync automatically selects a version based on negotiation with the NFS server.</p> <p>You can specify the following NFS versions:</p> <ul> <li> <p> <b> <a href="https://tools.ietf.org/html/rfc1813">NFSv3</a> </b> - stateless protocol version that allows for asynchronous writes on the server.</p> </li> <li> <p> <b> <a href="https://tools.ietf.org/html/rfc3530">NFSv4.0</a> </b> - stateful, firewall-friendly protocol version that supports delegations and pseudo filesystems.</p> </li> <li> <p> <b> <a href="https://tools.ietf.org/html/rfc5661">NFSv4.1</a> </b> - stateful protocol version that supports sessions, directory delegations, and parallel data processing. Version 4.1 also includes all features available in version 4.0.</p> </li> </ul></p>
+    #[serde(rename = "Version")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<NfsVersion>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum NfsVersion {
+    /// `AUTOMATIC`
+    Automatic,
+    /// `NFS3`
+    Nfs3,
+    /// `NFS4_0`
+    Nfs4_0,
+    /// `NFS4_1`
+    Nfs4_1,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl NfsVersion {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            NfsVersion::Automatic => "AUTOMATIC",
+            NfsVersion::Nfs3 => "NFS3",
+            NfsVersion::Nfs4_0 => "NFS4_0",
+            NfsVersion::Nfs4_1 => "NFS4_1",
+            NfsVersion::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for NfsVersion {
+    fn default() -> NfsVersion {
+        NfsVersion::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for NfsVersion {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for NfsVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for NfsVersion {
+    fn from(value: &'a str) -> NfsVersion {
+        match value {
+            "AUTOMATIC" => NfsVersion::Automatic,
+            "NFS3" => NfsVersion::Nfs3,
+            "NFS4_0" => NfsVersion::Nfs4_0,
+            "NFS4_1" => NfsVersion::Nfs4_1,
+            _ => NfsVersion::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for NfsVersion {
+    fn from(value: String) -> NfsVersion {
+        match value.as_str() {
+            "AUTOMATIC" => NfsVersion::Automatic,
+            "NFS3" => NfsVersion::Nfs3,
+            "NFS4_0" => NfsVersion::Nfs4_0,
+            "NFS4_1" => NfsVersion::Nfs4_1,
+            _ => NfsVersion::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for NfsVersion {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<NfsVersion, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for NfsVersion {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for NfsVersion {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for NfsVersion {
+    fn deserialize<D>(deserializer: D) -> Result<NfsVersion, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>A list of Amazon Resource Names (ARNs) of agents to use for a Network File System (NFS) location.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OnPremConfig {
+    /// <p>ARNs)of the agents to use for an NFS location.</p>
+    #[serde(rename = "AgentArns")]
+    pub agent_arns: Vec<String>,
+}
+
+/// <p>Represents the options that are available to control the behavior of a <a>StartTaskExecution</a> operation. Behavior includes preserving metadata such as user ID (UID), group ID (GID), and file permissions, and also overwriting files in the destination, data integrity verification, and so on.</p> <p>A task has a set of default options associated with it. If you don't specify an option in <a>StartTaskExecution</a>, the default value is used. You can override the defaults options on each task execution by specifying an overriding <code>Options</code> value to <a>StartTaskExecution</a>.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Options {
+    /// <p><p>A file metadata value that shows the last time a file was accessed (that is, when the file was read or written to). If you set <code>Atime</code> to BEST<em>EFFORT, DataSync attempts to preserve the original <code>Atime</code> attribute on all source files (that is, the version before the PREPARING phase). However, <code>Atime</code>&#39;s behavior is not fully standard across platforms, so AWS DataSync can only do this on a best-effort basis. </p> <p>Default value: BEST</em>EFFORT.</p> <p>BEST<em>EFFORT: Attempt to preserve the per-file <code>Atime</code> value (recommended).</p> <p>NONE: Ignore <code>Atime</code>.</p> <note> <p>If <code>Atime</code> is set to BEST</em>EFFORT, <code>Mtime</code> must be set to PRESERVE. </p> <p>If <code>Atime</code> is set to NONE, <code>Mtime</code> must also be NONE. </p> </note></p>
+    #[serde(rename = "Atime")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub atime: Option<Atime>,
+    /// <p>A value that limits the bandwidth used by AWS DataSync. For example, if you want AWS DataSync to use a maximum of 1 MB, set this value to <code>1048576</code> (<code>=1024*1024</code>).</p>
+    #[serde(rename = "BytesPerSecond")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bytes_per_second: Option<i64>,
+    /// <p>The group ID (GID) of the file's owners. </p> <p>Default value: INT_VALUE. This preserves the integer value of the ID.</p> <p>INT_VALUE: Preserve the integer value of user ID (UID) and GID (recommended).</p> <p>NONE: Ignore UID and GID. </p>
+    #[serde(rename = "Gid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gid: Option<Gid>,
+    /// <p>A value that determines the type of logs DataSync will deliver to your AWS CloudWatch Logs file. If set to <code>OFF</code>, no logs will be delivered. <code>BASIC</code> will deliver a few logs per transfer operation and <code>TRANSFER</code> will deliver a verbose log that contains logs for every file that is transferred.</p>
+    #[serde(rename = "LogLevel")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub log_level: Option<LogLevel>,
+    /// <p><p>A value that indicates the last time that a file was modified (that is, a file was written to) before the PREPARING phase. </p> <p>Default value: PRESERVE. </p> <p>PRESERVE: Preserve original <code>Mtime</code> (recommended)</p> <p> NONE: Ignore <code>Mtime</code>. </p> <note> <p>If <code>Mtime</code> is set to PRESERVE, <code>Atime</code> must be set to BEST_EFFORT.</p> <p>If <code>Mtime</code> is set to NONE, <code>Atime</code> must also be set to NONE. </p> </note></p>
+    #[serde(rename = "Mtime")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mtime: Option<Mtime>,
+    /// <p>A value that determines whether files at the destination should be overwritten or preserved when copying files. If set to <code>NEVER</code> a destination file will not be replaced by a source file, even if the destination file differs from the source file. If you modify files in the destination and you sync the files, you can use this value to protect against overwriting those changes. </p> <p>Some storage classes have specific behaviors that can affect your S3 storage cost. For detailed information, see <a>using-storage-classes</a> in the <i>AWS DataSync User Guide</i>.</p>
+    #[serde(rename = "OverwriteMode")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub overwrite_mode: Option<OverwriteMode>,
+    /// <p><p>A value that determines which users or groups can access a file for a specific purpose such as reading, writing, or execution of the file. </p> <p>Default value: PRESERVE.</p> <p>PRESERVE: Preserve POSIX-style permissions (recommended).</p> <p>NONE: Ignore permissions. </p> <note> <p>AWS DataSync can preserve extant permissions of a source location.</p> </note></p>
+    #[serde(rename = "PosixPermissions")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub posix_permissions: Option<PosixPermissions>,
+    /// <p>A value that specifies whether files in the destination that don't exist in the source file system should be preserved. This option can affect your storage cost. If your task deletes objects, you might incur minimum storage duration charges for certain storage classes. For detailed information, see <a>using-storage-classes</a> in the <i>AWS DataSync User Guide</i>.</p> <p>Default value: PRESERVE.</p> <p>PRESERVE: Ignore such destination files (recommended). </p> <p>REMOVE: Delete destination files that aren’t present in the source.</p>
+    #[serde(rename = "PreserveDeletedFiles")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preserve_deleted_files: Option<PreserveDeletedFiles>,
+    /// <p>A value that determines whether AWS DataSync should preserve the metadata of block and character devices in the source file system, and recreate the files with that device name and metadata on the destination.</p> <note> <p>AWS DataSync can't sync the actual contents of such devices, because they are nonterminal and don't return an end-of-file (EOF) marker.</p> </note> <p>Default value: NONE.</p> <p>NONE: Ignore special devices (recommended). </p> <p>PRESERVE: Preserve character and block device metadata. This option isn't currently supported for Amazon EFS. </p>
+    #[serde(rename = "PreserveDevices")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preserve_devices: Option<PreserveDevices>,
+    /// <p>A value that determines whether tasks should be queued before executing the tasks. If set to <code>ENABLED</code>, the tasks will be queued. The default is <code>ENABLED</code>.</p> <p>If you use the same agent to run multiple tasks you can enable the tasks to run in series. For more information see <a>queue-task-execution</a>.</p>
+    #[serde(rename = "TaskQueueing")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub task_queueing: Option<TaskQueueing>,
+    /// <p>The user ID (UID) of the file's owner. </p> <p>Default value: INT_VALUE. This preserves the integer value of the ID.</p> <p>INT_VALUE: Preserve the integer value of UID and group ID (GID) (recommended).</p> <p>NONE: Ignore UID and GID. </p>
+    #[serde(rename = "Uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uid: Option<Uid>,
+    /// <p>A value that determines whether a data integrity verification should be performed at the end of a task execution after all data and metadata have been transferred. </p> <p>Default value: POINT_IN_TIME_CONSISTENT.</p> <p>POINT_IN_TIME_CONSISTENT: Perform verification (recommended). </p> <p>ONLY_FILES_TRANSFERRED: Perform verification on only files that were transferred.</p> <p>NONE: Skip verification.</p>
     #[serde(rename = "VerifyMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub verify_mode: Option<String>,
+    pub verify_mode: Option<VerifyMode>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OverwriteMode {
+    /// `ALWAYS`
+    Always,
+    /// `NEVER`
+    Never,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl OverwriteMode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OverwriteMode::Always => "ALWAYS",
+            OverwriteMode::Never => "NEVER",
+            OverwriteMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for OverwriteMode {
+    fn default() -> OverwriteMode {
+        OverwriteMode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for OverwriteMode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for OverwriteMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for OverwriteMode {
+    fn from(value: &'a str) -> OverwriteMode {
+        match value {
+            "ALWAYS" => OverwriteMode::Always,
+            "NEVER" => OverwriteMode::Never,
+            _ => OverwriteMode::Unknown(value.to_owned()),
+        }
+    }
 }
 
+impl From<String> for OverwriteMode {
+    fn from(value: String) -> OverwriteMode {
+        match value.as_str() {
+            "ALWAYS" => OverwriteMode::Always,
+            "NEVER" => OverwriteMode::Never,
+            _ => OverwriteMode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for OverwriteMode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<OverwriteMode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OverwriteMode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for OverwriteMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OverwriteMode {
+    fn deserialize<D>(deserializer: D) -> Result<OverwriteMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PhaseStatus {
+    /// `PENDING`
+    Pending,
+    /// `SUCCESS`
+    Success,
+    /// `ERROR`
+    Error,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PhaseStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PhaseStatus::Pending => "PENDING",
+            PhaseStatus::Success => "SUCCESS",
+            PhaseStatus::Error => "ERROR",
+            PhaseStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PhaseStatus {
+    fn default() -> PhaseStatus {
+        PhaseStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PhaseStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PhaseStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PhaseStatus {
+    fn from(value: &'a str) -> PhaseStatus {
+        match value {
+            "PENDING" => PhaseStatus::Pending,
+            "SUCCESS" => PhaseStatus::Success,
+            "ERROR" => PhaseStatus::Error,
+            _ => PhaseStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PhaseStatus {
+    fn from(value: String) -> PhaseStatus {
+        match value.as_str() {
+            "PENDING" => PhaseStatus::Pending,
+            "SUCCESS" => PhaseStatus::Success,
+            "ERROR" => PhaseStatus::Error,
+            _ => PhaseStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PhaseStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PhaseStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PhaseStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PhaseStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PhaseStatus {
+    fn deserialize<D>(deserializer: D) -> Result<PhaseStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PosixPermissions {
+    /// `NONE`
+    None,
+    /// `PRESERVE`
+    Preserve,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PosixPermissions {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PosixPermissions::None => "NONE",
+            PosixPermissions::Preserve => "PRESERVE",
+            PosixPermissions::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PosixPermissions {
+    fn default() -> PosixPermissions {
+        PosixPermissions::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PosixPermissions {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PosixPermissions {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PosixPermissions {
+    fn from(value: &'a str) -> PosixPermissions {
+        match value {
+            "NONE" => PosixPermissions::None,
+            "PRESERVE" => PosixPermissions::Preserve,
+            _ => PosixPermissions::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PosixPermissions {
+    fn from(value: String) -> PosixPermissions {
+        match value.as_str() {
+            "NONE" => PosixPermissions::None,
+            "PRESERVE" => PosixPermissions::Preserve,
+            _ => PosixPermissions::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PosixPermissions {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PosixPermissions, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PosixPermissions {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PosixPermissions {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PosixPermissions {
+    fn deserialize<D>(deserializer: D) -> Result<PosixPermissions, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PreserveDeletedFiles {
+    /// `PRESERVE`
+    Preserve,
+    /// `REMOVE`
+    Remove,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PreserveDeletedFiles {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PreserveDeletedFiles::Preserve => "PRESERVE",
+            PreserveDeletedFiles::Remove => "REMOVE",
+            PreserveDeletedFiles::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PreserveDeletedFiles {
+    fn default() -> PreserveDeletedFiles {
+        PreserveDeletedFiles::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PreserveDeletedFiles {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PreserveDeletedFiles {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PreserveDeletedFiles {
+    fn from(value: &'a str) -> PreserveDeletedFiles {
+        match value {
+            "PRESERVE" => PreserveDeletedFiles::Preserve,
+            "REMOVE" => PreserveDeletedFiles::Remove,
+            _ => PreserveDeletedFiles::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PreserveDeletedFiles {
+    fn from(value: String) -> PreserveDeletedFiles {
+        match value.as_str() {
+            "PRESERVE" => PreserveDeletedFiles::Preserve,
+            "REMOVE" => PreserveDeletedFiles::Remove,
+            _ => PreserveDeletedFiles::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PreserveDeletedFiles {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PreserveDeletedFiles, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PreserveDeletedFiles {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PreserveDeletedFiles {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PreserveDeletedFiles {
+    fn deserialize<D>(deserializer: D) -> Result<PreserveDeletedFiles, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PreserveDevices {
+    /// `NONE`
+    None,
+    /// `PRESERVE`
+    Preserve,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PreserveDevices {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PreserveDevices::None => "NONE",
+            PreserveDevices::Preserve => "PRESERVE",
+            PreserveDevices::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PreserveDevices {
+    fn default() -> PreserveDevices {
+        PreserveDevices::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PreserveDevices {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PreserveDevices {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PreserveDevices {
+    fn from(value: &'a str) -> PreserveDevices {
+        match value {
+            "NONE" => PreserveDevices::None,
+            "PRESERVE" => PreserveDevices::Preserve,
+            _ => PreserveDevices::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PreserveDevices {
+    fn from(value: String) -> PreserveDevices {
+        match value.as_str() {
+            "NONE" => PreserveDevices::None,
+            "PRESERVE" => PreserveDevices::Preserve,
+            _ => PreserveDevices::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PreserveDevices {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PreserveDevices, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PreserveDevices {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PreserveDevices {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PreserveDevices {
+    fn deserialize<D>(deserializer: D) -> Result<PreserveDevices, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The VPC endpoint, subnet and security group that an agent uses to access IP addresses in a VPC (Virtual Private Cloud).</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -999,15 +2283,246 @@ pub struct S3Config {
     pub bucket_access_role_arn: String,
 }
 
-/// <p>Represents the mount options that are available for DataSync to access an SMB location.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct SmbMountOptions {
-    /// <p>The specific SMB version that you want DataSync to use to mount your SMB share. If you don't specify a version, DataSync defaults to <code>AUTOMATIC</code>. That is, DataSync automatically selects a version based on negotiation with the SMB server.</p>
-    #[serde(rename = "Version")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub version: Option<String>,
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum S3StorageClass {
+    /// `STANDARD`
+    Standard,
+    /// `STANDARD_IA`
+    StandardIa,
+    /// `ONEZONE_IA`
+    OnezoneIa,
+    /// `INTELLIGENT_TIERING`
+    IntelligentTiering,
+    /// `GLACIER`
+    Glacier,
+    /// `DEEP_ARCHIVE`
+    DeepArchive,
+    /// `OUTPOSTS`
+    Outposts,
+    /// `GLACIER_INSTANT_RETRIEVAL`
+    GlacierInstantRetrieval,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl S3StorageClass {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            S3StorageClass::Standard => "STANDARD",
+            S3StorageClass::StandardIa => "STANDARD_IA",
+            S3StorageClass::OnezoneIa => "ONEZONE_IA",
+            S3StorageClass::IntelligentTiering => "INTELLIGENT_TIERING",
+            S3StorageClass::Glacier => "GLACIER",
+            S3StorageClass::DeepArchive => "DEEP_ARCHIVE",
+            S3StorageClass::Outposts => "OUTPOSTS",
+            S3StorageClass::GlacierInstantRetrieval => "GLACIER_INSTANT_RETRIEVAL",
+            S3StorageClass::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for S3StorageClass {
+    fn default() -> S3StorageClass {
+        S3StorageClass::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for S3StorageClass {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for S3StorageClass {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for S3StorageClass {
+    fn from(value: &'a str) -> S3StorageClass {
+        match value {
+            "STANDARD" => S3StorageClass::Standard,
+            "STANDARD_IA" => S3StorageClass::StandardIa,
+            "ONEZONE_IA" => S3StorageClass::OnezoneIa,
+            "INTELLIGENT_TIERING" => S3StorageClass::IntelligentTiering,
+            "GLACIER" => S3StorageClass::Glacier,
+            "DEEP_ARCHIVE" => S3StorageClass::DeepArchive,
+            "OUTPOSTS" => S3StorageClass::Outposts,
+            "GLACIER_INSTANT_RETRIEVAL" => S3StorageClass::GlacierInstantRetrieval,
+            _ => S3StorageClass::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for S3StorageClass {
+    fn from(value: String) -> S3StorageClass {
+        match value.as_str() {
+            "STANDARD" => S3StorageClass::Standard,
+            "STANDARD_IA" => S3StorageClass::StandardIa,
+            "ONEZONE_IA" => S3StorageClass::OnezoneIa,
+            "INTELLIGENT_TIERING" => S3StorageClass::IntelligentTiering,
+            "GLACIER" => S3StorageClass::Glacier,
+            "DEEP_ARCHIVE" => S3StorageClass::DeepArchive,
+            "OUTPOSTS" => S3StorageClass::Outposts,
+            "GLACIER_INSTANT_RETRIEVAL" => S3StorageClass::GlacierInstantRetrieval,
+            _ => S3StorageClass::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for S3StorageClass {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<S3StorageClass, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for S3StorageClass {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for S3StorageClass {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for S3StorageClass {
+    fn deserialize<D>(deserializer: D) -> Result<S3StorageClass, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Represents the mount options that are available for DataSync to access an SMB location.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SmbMountOptions {
+    /// <p>The specific SMB version that you want DataSync to use to mount your SMB share. If you don't specify a version, DataSync defaults to <code>AUTOMATIC</code>. That is, DataSync automatically selects a version based on negotiation with the SMB server.</p>
+    #[serde(rename = "Version")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<SmbVersion>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SmbVersion {
+    /// `AUTOMATIC`
+    Automatic,
+    /// `SMB2`
+    Smb2,
+    /// `SMB3`
+    Smb3,
+    /// `SMB1`
+    Smb1,
+    /// `SMB2_0`
+    Smb2_0,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SmbVersion {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SmbVersion::Automatic => "AUTOMATIC",
+            SmbVersion::Smb2 => "SMB2",
+            SmbVersion::Smb3 => "SMB3",
+            SmbVersion::Smb1 => "SMB1",
+            SmbVersion::Smb2_0 => "SMB2_0",
+            SmbVersion::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SmbVersion {
+    fn default() -> SmbVersion {
+        SmbVersion::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SmbVersion {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SmbVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SmbVersion {
+    fn from(value: &'a str) -> SmbVersion {
+        match value {
+            "AUTOMATIC" => SmbVersion::Automatic,
+            "SMB2" => SmbVersion::Smb2,
+            "SMB3" => SmbVersion::Smb3,
+            "SMB1" => SmbVersion::Smb1,
+            "SMB2_0" => SmbVersion::Smb2_0,
+            _ => SmbVersion::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SmbVersion {
+    fn from(value: String) -> SmbVersion {
+        match value.as_str() {
+            "AUTOMATIC" => SmbVersion::Automatic,
+            "SMB2" => SmbVersion::Smb2,
+            "SMB3" => SmbVersion::Smb3,
+            "SMB1" => SmbVersion::Smb1,
+            "SMB2_0" => SmbVersion::Smb2_0,
+            _ => SmbVersion::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SmbVersion {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SmbVersion, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SmbVersion {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SmbVersion {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for SmbVersion {
+    fn deserialize<D>(deserializer: D) -> Result<SmbVersion, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>StartTaskExecutionRequest</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -1069,7 +2584,7 @@ pub struct TaskExecutionListEntry {
     /// <p>The status of a task execution.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<TaskExecutionStatus>,
     /// <p>The Amazon Resource Name (ARN) of the task that was executed.</p>
     #[serde(rename = "TaskExecutionArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1095,7 +2610,7 @@ pub struct TaskExecutionResultDetail {
     /// <p>The status of the PREPARING phase.</p>
     #[serde(rename = "PrepareStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub prepare_status: Option<String>,
+    pub prepare_status: Option<PhaseStatus>,
     /// <p>The total time in milliseconds that AWS DataSync took to transfer the file from the source to the destination location.</p>
     #[serde(rename = "TotalDuration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1107,7 +2622,7 @@ pub struct TaskExecutionResultDetail {
     /// <p>The status of the TRANSFERRING Phase.</p>
     #[serde(rename = "TransferStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub transfer_status: Option<String>,
+    pub transfer_status: Option<PhaseStatus>,
     /// <p>The total time in milliseconds that AWS DataSync spent in the VERIFYING phase.</p>
     #[serde(rename = "VerifyDuration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1115,9 +2630,127 @@ pub struct TaskExecutionResultDetail {
     /// <p>The status of the VERIFYING Phase.</p>
     #[serde(rename = "VerifyStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub verify_status: Option<String>,
+    pub verify_status: Option<PhaseStatus>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TaskExecutionStatus {
+    /// `QUEUED`
+    Queued,
+    /// `LAUNCHING`
+    Launching,
+    /// `PREPARING`
+    Preparing,
+    /// `TRANSFERRING`
+    Transferring,
+    /// `VERIFYING`
+    Verifying,
+    /// `SUCCESS`
+    Success,
+    /// `ERROR`
+    Error,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TaskExecutionStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TaskExecutionStatus::Queued => "QUEUED",
+            TaskExecutionStatus::Launching => "LAUNCHING",
+            TaskExecutionStatus::Preparing => "PREPARING",
+            TaskExecutionStatus::Transferring => "TRANSFERRING",
+            TaskExecutionStatus::Verifying => "VERIFYING",
+            TaskExecutionStatus::Success => "SUCCESS",
+            TaskExecutionStatus::Error => "ERROR",
+            TaskExecutionStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TaskExecutionStatus {
+    fn default() -> TaskExecutionStatus {
+        TaskExecutionStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TaskExecutionStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TaskExecutionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TaskExecutionStatus {
+    fn from(value: &'a str) -> TaskExecutionStatus {
+        match value {
+            "QUEUED" => TaskExecutionStatus::Queued,
+            "LAUNCHING" => TaskExecutionStatus::Launching,
+            "PREPARING" => TaskExecutionStatus::Preparing,
+            "TRANSFERRING" => TaskExecutionStatus::Transferring,
+            "VERIFYING" => TaskExecutionStatus::Verifying,
+            "SUCCESS" => TaskExecutionStatus::Success,
+            "ERROR" => TaskExecutionStatus::Error,
+            _ => TaskExecutionStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TaskExecutionStatus {
+    fn from(value: String) -> TaskExecutionStatus {
+        match value.as_str() {
+            "QUEUED" => TaskExecutionStatus::Queued,
+            "LAUNCHING" => TaskExecutionStatus::Launching,
+            "PREPARING" => TaskExecutionStatus::Preparing,
+            "TRANSFERRING" => TaskExecutionStatus::Transferring,
+            "VERIFYING" => TaskExecutionStatus::Verifying,
+            "SUCCESS" => TaskExecutionStatus::Success,
+            "ERROR" => TaskExecutionStatus::Error,
+            _ => TaskExecutionStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TaskExecutionStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TaskExecutionStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TaskExecutionStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TaskExecutionStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for TaskExecutionStatus {
+    fn deserialize<D>(deserializer: D) -> Result<TaskExecutionStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents a single entry in a list of tasks. <code>TaskListEntry</code> returns an array that contains a list of tasks when the <a>ListTasks</a> operation is called. A task includes the source and destination file systems to sync and the options to use for the tasks.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1129,13 +2762,106 @@ pub struct TaskListEntry {
     /// <p>The status of the task.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<TaskStatus>,
     /// <p>The Amazon Resource Name (ARN) of the task.</p>
     #[serde(rename = "TaskArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub task_arn: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TaskQueueing {
+    /// `ENABLED`
+    Enabled,
+    /// `DISABLED`
+    Disabled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TaskQueueing {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TaskQueueing::Enabled => "ENABLED",
+            TaskQueueing::Disabled => "DISABLED",
+            TaskQueueing::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TaskQueueing {
+    fn default() -> TaskQueueing {
+        TaskQueueing::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TaskQueueing {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TaskQueueing {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TaskQueueing {
+    fn from(value: &'a str) -> TaskQueueing {
+        match value {
+            "ENABLED" => TaskQueueing::Enabled,
+            "DISABLED" => TaskQueueing::Disabled,
+            _ => TaskQueueing::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TaskQueueing {
+    fn from(value: String) -> TaskQueueing {
+        match value.as_str() {
+            "ENABLED" => TaskQueueing::Enabled,
+            "DISABLED" => TaskQueueing::Disabled,
+            _ => TaskQueueing::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TaskQueueing {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TaskQueueing, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TaskQueueing {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TaskQueueing {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TaskQueueing {
+    fn deserialize<D>(deserializer: D) -> Result<TaskQueueing, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Specifies the schedule you want your task to use for repeated executions. For more information, see <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/events/ScheduledEvents.html">Schedule Expressions for Rules</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TaskSchedule {
@@ -1144,6 +2870,217 @@ pub struct TaskSchedule {
     pub schedule_expression: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TaskStatus {
+    /// `AVAILABLE`
+    Available,
+    /// `CREATING`
+    Creating,
+    /// `QUEUED`
+    Queued,
+    /// `RUNNING`
+    Running,
+    /// `UNAVAILABLE`
+    Unavailable,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TaskStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TaskStatus::Available => "AVAILABLE",
+            TaskStatus::Creating => "CREATING",
+            TaskStatus::Queued => "QUEUED",
+            TaskStatus::Running => "RUNNING",
+            TaskStatus::Unavailable => "UNAVAILABLE",
+            TaskStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TaskStatus {
+    fn default() -> TaskStatus {
+        TaskStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TaskStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TaskStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TaskStatus {
+    fn from(value: &'a str) -> TaskStatus {
+        match value {
+            "AVAILABLE" => TaskStatus::Available,
+            "CREATING" => TaskStatus::Creating,
+            "QUEUED" => TaskStatus::Queued,
+            "RUNNING" => TaskStatus::Running,
+            "UNAVAILABLE" => TaskStatus::Unavailable,
+            _ => TaskStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TaskStatus {
+    fn from(value: String) -> TaskStatus {
+        match value.as_str() {
+            "AVAILABLE" => TaskStatus::Available,
+            "CREATING" => TaskStatus::Creating,
+            "QUEUED" => TaskStatus::Queued,
+            "RUNNING" => TaskStatus::Running,
+            "UNAVAILABLE" => TaskStatus::Unavailable,
+            _ => TaskStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TaskStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TaskStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TaskStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TaskStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TaskStatus {
+    fn deserialize<D>(deserializer: D) -> Result<TaskStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Uid {
+    /// `NONE`
+    None,
+    /// `INT_VALUE`
+    IntValue,
+    /// `NAME`
+    Name,
+    /// `BOTH`
+    Both,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Uid {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Uid::None => "NONE",
+            Uid::IntValue => "INT_VALUE",
+            Uid::Name => "NAME",
+            Uid::Both => "BOTH",
+            Uid::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Uid {
+    fn default() -> Uid {
+        Uid::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Uid {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Uid {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Uid {
+    fn from(value: &'a str) -> Uid {
+        match value {
+            "NONE" => Uid::None,
+            "INT_VALUE" => Uid::IntValue,
+            "NAME" => Uid::Name,
+            "BOTH" => Uid::Both,
+            _ => Uid::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Uid {
+    fn from(value: String) -> Uid {
+        match value.as_str() {
+            "NONE" => Uid::None,
+            "INT_VALUE" => Uid::IntValue,
+            "NAME" => Uid::Name,
+            "BOTH" => Uid::Both,
+            _ => Uid::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Uid {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Uid, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Uid {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Uid {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Uid {
+    fn deserialize<D>(deserializer: D) -> Result<Uid, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>UntagResourceRequest</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -1209,6 +3146,104 @@ pub struct UpdateTaskRequest {
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateTaskResponse {}
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum VerifyMode {
+    /// `POINT_IN_TIME_CONSISTENT`
+    PointInTimeConsistent,
+    /// `ONLY_FILES_TRANSFERRED`
+    OnlyFilesTransferred,
+    /// `NONE`
+    None,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl VerifyMode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            VerifyMode::PointInTimeConsistent => "POINT_IN_TIME_CONSISTENT",
+            VerifyMode::OnlyFilesTransferred => "ONLY_FILES_TRANSFERRED",
+            VerifyMode::None => "NONE",
+            VerifyMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for VerifyMode {
+    fn default() -> VerifyMode {
+        VerifyMode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for VerifyMode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for VerifyMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for VerifyMode {
+    fn from(value: &'a str) -> VerifyMode {
+        match value {
+            "POINT_IN_TIME_CONSISTENT" => VerifyMode::PointInTimeConsistent,
+            "ONLY_FILES_TRANSFERRED" => VerifyMode::OnlyFilesTransferred,
+            "NONE" => VerifyMode::None,
+            _ => VerifyMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for VerifyMode {
+    fn from(value: String) -> VerifyMode {
+        match value.as_str() {
+            "POINT_IN_TIME_CONSISTENT" => VerifyMode::PointInTimeConsistent,
+            "ONLY_FILES_TRANSFERRED" => VerifyMode::OnlyFilesTransferred,
+            "NONE" => VerifyMode::None,
+            _ => VerifyMode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for VerifyMode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<VerifyMode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for VerifyMode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for VerifyMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for VerifyMode {
+    fn deserialize<D>(deserializer: D) -> Result<VerifyMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// Errors returned by CancelTaskExecution
 #[derive(Debug, PartialEq)]
 pub enum CancelTaskExecutionError {
@@ -2448,11 +4483,12 @@ pub struct DataSyncClient {
 impl DataSyncClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> DataSyncClient {
         DataSyncClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "DataSync"),
         }
     }
 
@@ -2484,6 +4520,7 @@ impl DataSync for DataSyncClient {
         input: CancelTaskExecutionRequest,
     ) -> Result<CancelTaskExecutionResponse, RusotoError<CancelTaskExecutionError>> {
         let mut request = SignedRequest::new("POST", "datasync", &self.region, "/");
+        request.set_operation("CancelTaskExecution");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "FmrsService.CancelTaskExecution");
@@ -2512,6 +4549,7 @@ impl DataSync for DataSyncClient {
         input: CreateAgentRequest,
     ) -> Result<CreateAgentResponse, RusotoError<CreateAgentError>> {
         let mut request = SignedRequest::new("POST", "datasync", &self.region, "/");
+        request.set_operation("CreateAgent");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "FmrsService.CreateAgent");
@@ -2539,6 +4577,7 @@ impl DataSync for DataSyncClient {
         input: CreateLocationEfsRequest,
     ) -> Result<CreateLocationEfsResponse, RusotoError<CreateLocationEfsError>> {
         let mut request = SignedRequest::new("POST", "datasync", &self.region, "/");
+        request.set_operation("CreateLocationEfs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "FmrsService.CreateLocationEfs");
@@ -2567,6 +4606,7 @@ impl DataSync for DataSyncClient {
         input: CreateLocationFsxWindowsRequest,
     ) -> Result<CreateLocationFsxWindowsResponse, RusotoError<CreateLocationFsxWindowsError>> {
         let mut request = SignedRequest::new("POST", "datasync", &self.region, "/");
+        request.set_operation("CreateLocationFsxWindows");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "FmrsService.CreateLocationFsxWindows");
@@ -2595,6 +4635,7 @@ impl DataSync for DataSyncClient {
         input: CreateLocationNfsRequest,
     ) -> Result<CreateLocationNfsResponse, RusotoError<CreateLocationNfsError>> {
         let mut request = SignedRequest::new("POST", "datasync", &self.region, "/");
+        request.set_operation("CreateLocationNfs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "FmrsService.CreateLocationNfs");
@@ -2623,6 +4664,7 @@ impl DataSync for DataSyncClient {
         input: CreateLocationS3Request,
     ) -> Result<CreateLocationS3Response, RusotoError<CreateLocationS3Error>> {
         let mut request = SignedRequest::new("POST", "datasync", &self.region, "/");
+        request.set_operation("CreateLocationS3");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "FmrsService.CreateLocationS3");
@@ -2651,6 +4693,7 @@ impl DataSync for DataSyncClient {
         input: CreateLocationSmbRequest,
     ) -> Result<CreateLocationSmbResponse, RusotoError<CreateLocationSmbError>> {
         let mut request = SignedRequest::new("POST", "datasync", &self.region, "/");
+        request.set_operation("CreateLocationSmb");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "FmrsService.CreateLocationSmb");
@@ -2679,6 +4722,7 @@ impl DataSync for DataSyncClient {
         input: CreateTaskRequest,
     ) -> Result<CreateTaskResponse, RusotoError<CreateTaskError>> {
         let mut request = SignedRequest::new("POST", "datasync", &self.region, "/");
+        request.set_operation("CreateTask");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "FmrsService.CreateTask");
@@ -2706,6 +4750,7 @@ impl DataSync for DataSyncClient {
         input: DeleteAgentRequest,
     ) -> Result<DeleteAgentResponse, RusotoError<DeleteAgentError>> {
         let mut request = SignedRequest::new("POST", "datasync", &self.region, "/");
+        request.set_operation("DeleteAgent");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "FmrsService.DeleteAgent");
@@ -2733,6 +4778,7 @@ impl DataSync for DataSyncClient {
         input: DeleteLocationRequest,
     ) -> Result<DeleteLocationResponse, RusotoError<DeleteLocationError>> {
         let mut request = SignedRequest::new("POST", "datasync", &self.region, "/");
+        request.set_operation("DeleteLocation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "FmrsService.DeleteLocation");
@@ -2760,6 +4806,7 @@ impl DataSync for DataSyncClient {
         input: DeleteTaskRequest,
     ) -> Result<DeleteTaskResponse, RusotoError<DeleteTaskError>> {
         let mut request = SignedRequest::new("POST", "datasync", &self.region, "/");
+        request.set_operation("DeleteTask");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "FmrsService.DeleteTask");
@@ -2787,6 +4834,7 @@ impl DataSync for DataSyncClient {
         input: DescribeAgentRequest,
     ) -> Result<DescribeAgentResponse, RusotoError<DescribeAgentError>> {
         let mut request = SignedRequest::new("POST", "datasync", &self.region, "/");
+        request.set_operation("DescribeAgent");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "FmrsService.DescribeAgent");
@@ -2814,6 +4862,7 @@ impl DataSync for DataSyncClient {
         input: DescribeLocationEfsRequest,
     ) -> Result<DescribeLocationEfsResponse, RusotoError<DescribeLocationEfsError>> {
         let mut request = SignedRequest::new("POST", "datasync", &self.region, "/");
+        request.set_operation("DescribeLocationEfs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "FmrsService.DescribeLocationEfs");
@@ -2843,6 +4892,7 @@ impl DataSync for DataSyncClient {
     ) -> Result<DescribeLocationFsxWindowsResponse, RusotoError<DescribeLocationFsxWindowsError>>
     {
         let mut request = SignedRequest::new("POST", "datasync", &self.region, "/");
+        request.set_operation("DescribeLocationFsxWindows");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "FmrsService.DescribeLocationFsxWindows");
@@ -2871,6 +4921,7 @@ impl DataSync for DataSyncClient {
         input: DescribeLocationNfsRequest,
     ) -> Result<DescribeLocationNfsResponse, RusotoError<DescribeLocationNfsError>> {
         let mut request = SignedRequest::new("POST", "datasync", &self.region, "/");
+        request.set_operation("DescribeLocationNfs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "FmrsService.DescribeLocationNfs");
@@ -2899,6 +4950,7 @@ impl DataSync for DataSyncClient {
         input: DescribeLocationS3Request,
     ) -> Result<DescribeLocationS3Response, RusotoError<DescribeLocationS3Error>> {
         let mut request = SignedRequest::new("POST", "datasync", &self.region, "/");
+        request.set_operation("DescribeLocationS3");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "FmrsService.DescribeLocationS3");
@@ -2927,6 +4979,7 @@ impl DataSync for DataSyncClient {
         input: DescribeLocationSmbRequest,
     ) -> Result<DescribeLocationSmbResponse, RusotoError<DescribeLocationSmbError>> {
         let mut request = SignedRequest::new("POST", "datasync", &self.region, "/");
+        request.set_operation("DescribeLocationSmb");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "FmrsService.DescribeLocationSmb");
@@ -2955,6 +5008,7 @@ impl DataSync for DataSyncClient {
         input: DescribeTaskRequest,
     ) -> Result<DescribeTaskResponse, RusotoError<DescribeTaskError>> {
         let mut request = SignedRequest::new("POST", "datasync", &self.region, "/");
+        request.set_operation("DescribeTask");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "FmrsService.DescribeTask");
@@ -2982,6 +5036,7 @@ impl DataSync for DataSyncClient {
         input: DescribeTaskExecutionRequest,
     ) -> Result<DescribeTaskExecutionResponse, RusotoError<DescribeTaskExecutionError>> {
         let mut request = SignedRequest::new("POST", "datasync", &self.region, "/");
+        request.set_operation("DescribeTaskExecution");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "FmrsService.DescribeTaskExecution");
@@ -3010,6 +5065,7 @@ impl DataSync for DataSyncClient {
         input: ListAgentsRequest,
     ) -> Result<ListAgentsResponse, RusotoError<ListAgentsError>> {
         let mut request = SignedRequest::new("POST", "datasync", &self.region, "/");
+        request.set_operation("ListAgents");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "FmrsService.ListAgents");
@@ -3037,6 +5093,7 @@ impl DataSync for DataSyncClient {
         input: ListLocationsRequest,
     ) -> Result<ListLocationsResponse, RusotoError<ListLocationsError>> {
         let mut request = SignedRequest::new("POST", "datasync", &self.region, "/");
+        request.set_operation("ListLocations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "FmrsService.ListLocations");
@@ -3064,6 +5121,7 @@ impl DataSync for DataSyncClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = SignedRequest::new("POST", "datasync", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "FmrsService.ListTagsForResource");
@@ -3092,6 +5150,7 @@ impl DataSync for DataSyncClient {
         input: ListTaskExecutionsRequest,
     ) -> Result<ListTaskExecutionsResponse, RusotoError<ListTaskExecutionsError>> {
         let mut request = SignedRequest::new("POST", "datasync", &self.region, "/");
+        request.set_operation("ListTaskExecutions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "FmrsService.ListTaskExecutions");
@@ -3120,6 +5179,7 @@ impl DataSync for DataSyncClient {
         input: ListTasksRequest,
     ) -> Result<ListTasksResponse, RusotoError<ListTasksError>> {
         let mut request = SignedRequest::new("POST", "datasync", &self.region, "/");
+        request.set_operation("ListTasks");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "FmrsService.ListTasks");
@@ -3147,6 +5207,7 @@ impl DataSync for DataSyncClient {
         input: StartTaskExecutionRequest,
     ) -> Result<StartTaskExecutionResponse, RusotoError<StartTaskExecutionError>> {
         let mut request = SignedRequest::new("POST", "datasync", &self.region, "/");
+        request.set_operation("StartTaskExecution");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "FmrsService.StartTaskExecution");
@@ -3175,6 +5236,7 @@ impl DataSync for DataSyncClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = SignedRequest::new("POST", "datasync", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "FmrsService.TagResource");
@@ -3202,6 +5264,7 @@ impl DataSync for DataSyncClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = SignedRequest::new("POST", "datasync", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "FmrsService.UntagResource");
@@ -3229,6 +5292,7 @@ impl DataSync for DataSyncClient {
         input: UpdateAgentRequest,
     ) -> Result<UpdateAgentResponse, RusotoError<UpdateAgentError>> {
         let mut request = SignedRequest::new("POST", "datasync", &self.region, "/");
+        request.set_operation("UpdateAgent");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "FmrsService.UpdateAgent");
@@ -3256,6 +5320,7 @@ impl DataSync for DataSyncClient {
         input: UpdateTaskRequest,
     ) -> Result<UpdateTaskResponse, RusotoError<UpdateTaskError>> {
         let mut request = SignedRequest::new("POST", "datasync", &self.region, "/");
+        request.set_operation("UpdateTask");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "FmrsService.UpdateTask");
@@ -3277,3 +5342,162 @@ impl DataSync for DataSyncClient {
         }
     }
 }
+impl DataSyncClient {
+    /// Auto-paginating version of `list_agents`, streaming every page of results.
+    pub fn list_agents_pages(
+        &self,
+        input: ListAgentsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListAgentsResponse, ListAgentsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DataSyncClient, input: ListAgentsRequest| async move {
+                client.list_agents(input).await
+            },
+            |input: &ListAgentsRequest, output: &ListAgentsResponse| {
+                Some(ListAgentsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_agents`, streaming the `Agents` of every page of results.
+    pub fn list_agents_items(
+        &self,
+        input: ListAgentsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<AgentListEntry, ListAgentsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_agents_pages(input),
+            |output: ListAgentsResponse| output.agents.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_locations`, streaming every page of results.
+    pub fn list_locations_pages(
+        &self,
+        input: ListLocationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListLocationsResponse, ListLocationsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DataSyncClient, input: ListLocationsRequest| async move {
+                client.list_locations(input).await
+            },
+            |input: &ListLocationsRequest, output: &ListLocationsResponse| {
+                Some(ListLocationsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_locations`, streaming the `Locations` of every page of results.
+    pub fn list_locations_items(
+        &self,
+        input: ListLocationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<LocationListEntry, ListLocationsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_locations_pages(input),
+            |output: ListLocationsResponse| output.locations.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_tags_for_resource`, streaming every page of results.
+    pub fn list_tags_for_resource_pages(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListTagsForResourceResponse, ListTagsForResourceError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DataSyncClient, input: ListTagsForResourceRequest| async move {
+                client.list_tags_for_resource(input).await
+            },
+            |input: &ListTagsForResourceRequest, output: &ListTagsForResourceResponse| {
+                Some(ListTagsForResourceRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_tags_for_resource`, streaming the `Tags` of every page of results.
+    pub fn list_tags_for_resource_items(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> rusoto_core::pagination::RusotoStream<TagListEntry, ListTagsForResourceError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_tags_for_resource_pages(input),
+            |output: ListTagsForResourceResponse| output.tags.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_task_executions`, streaming every page of results.
+    pub fn list_task_executions_pages(
+        &self,
+        input: ListTaskExecutionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListTaskExecutionsResponse, ListTaskExecutionsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DataSyncClient, input: ListTaskExecutionsRequest| async move {
+                client.list_task_executions(input).await
+            },
+            |input: &ListTaskExecutionsRequest, output: &ListTaskExecutionsResponse| {
+                Some(ListTaskExecutionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_task_executions`, streaming the `TaskExecutions` of every page of results.
+    pub fn list_task_executions_items(
+        &self,
+        input: ListTaskExecutionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<TaskExecutionListEntry, ListTaskExecutionsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_task_executions_pages(input),
+            |output: ListTaskExecutionsResponse| output.task_executions.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_tasks`, streaming every page of results.
+    pub fn list_tasks_pages(
+        &self,
+        input: ListTasksRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListTasksResponse, ListTasksError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DataSyncClient, input: ListTasksRequest| async move {
+                client.list_tasks(input).await
+            },
+            |input: &ListTasksRequest, output: &ListTasksResponse| {
+                Some(ListTasksRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_tasks`, streaming the `Tasks` of every page of results.
+    pub fn list_tasks_items(
+        &self,
+        input: ListTasksRequest,
+    ) -> rusoto_core::pagination::RusotoStream<TaskListEntry, ListTasksError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_tasks_pages(input),
+            |output: ListTasksResponse| output.tasks.unwrap_or_default(),
+        )
+    }
+}
@@ -24,6 +24,99 @@ use rusoto_core::signature::SignedRequest;
 #[allow(unused_imports)]
 use serde::{Deserialize, Serialize};
 use serde_json;
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ChangeType {
+    /// `IMMEDIATE`
+    Immediate,
+    /// `REQUIRES_REBOOT`
+    RequiresReboot,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ChangeType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ChangeType::Immediate => "IMMEDIATE",
+            ChangeType::RequiresReboot => "REQUIRES_REBOOT",
+            ChangeType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ChangeType {
+    fn default() -> ChangeType {
+        ChangeType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ChangeType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ChangeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ChangeType {
+    fn from(value: &'a str) -> ChangeType {
+        match value {
+            "IMMEDIATE" => ChangeType::Immediate,
+            "REQUIRES_REBOOT" => ChangeType::RequiresReboot,
+            _ => ChangeType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ChangeType {
+    fn from(value: String) -> ChangeType {
+        match value.as_str() {
+            "IMMEDIATE" => ChangeType::Immediate,
+            "REQUIRES_REBOOT" => ChangeType::RequiresReboot,
+            _ => ChangeType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ChangeType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ChangeType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ChangeType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ChangeType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ChangeType {
+    fn deserialize<D>(deserializer: D) -> Result<ChangeType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Contains all of the attributes of a specific DAX cluster.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -349,8 +442,13 @@ pub struct DescribeEventsRequest {
     pub duration: Option<i64>,
     /// <p>The end of the time interval for which to retrieve events, specified in ISO 8601 format.</p>
     #[serde(rename = "EndTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The maximum number of results to include in the response. If more results exist than the specified <code>MaxResults</code> value, a token is included in the response so that the remaining results can be retrieved.</p> <p>The value for <code>MaxResults</code> must be between 20 and 100.</p>
     #[serde(rename = "MaxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -366,11 +464,16 @@ pub struct DescribeEventsRequest {
     /// <p>The event source to retrieve events for. If no value is specified, all events are returned.</p>
     #[serde(rename = "SourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub source_type: Option<String>,
+    pub source_type: Option<SourceType>,
     /// <p>The beginning of the time interval to retrieve events for, specified in ISO 8601 format.</p>
     #[serde(rename = "StartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -499,8 +602,13 @@ pub struct Endpoint {
 pub struct Event {
     /// <p>The date and time when the event occurred.</p>
     #[serde(rename = "Date")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub date: Option<f64>,
+    pub date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A user-defined message associated with the event.</p>
     #[serde(rename = "Message")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -512,7 +620,7 @@ pub struct Event {
     /// <p>Specifies the origin of this event - a cluster, a parameter group, a node ID, etc.</p>
     #[serde(rename = "SourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub source_type: Option<String>,
+    pub source_type: Option<SourceType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -539,6 +647,104 @@ pub struct IncreaseReplicationFactorResponse {
     pub cluster: Option<Cluster>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum IsModifiable {
+    /// `TRUE`
+    True,
+    /// `FALSE`
+    False,
+    /// `CONDITIONAL`
+    Conditional,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl IsModifiable {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            IsModifiable::True => "TRUE",
+            IsModifiable::False => "FALSE",
+            IsModifiable::Conditional => "CONDITIONAL",
+            IsModifiable::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for IsModifiable {
+    fn default() -> IsModifiable {
+        IsModifiable::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for IsModifiable {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for IsModifiable {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for IsModifiable {
+    fn from(value: &'a str) -> IsModifiable {
+        match value {
+            "TRUE" => IsModifiable::True,
+            "FALSE" => IsModifiable::False,
+            "CONDITIONAL" => IsModifiable::Conditional,
+            _ => IsModifiable::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for IsModifiable {
+    fn from(value: String) -> IsModifiable {
+        match value.as_str() {
+            "TRUE" => IsModifiable::True,
+            "FALSE" => IsModifiable::False,
+            "CONDITIONAL" => IsModifiable::Conditional,
+            _ => IsModifiable::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for IsModifiable {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<IsModifiable, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for IsModifiable {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for IsModifiable {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for IsModifiable {
+    fn deserialize<D>(deserializer: D) -> Result<IsModifiable, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ListTagsRequest {
@@ -578,8 +784,13 @@ pub struct Node {
     pub endpoint: Option<Endpoint>,
     /// <p>The date and time (in UNIX epoch format) when the node was launched.</p>
     #[serde(rename = "NodeCreateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub node_create_time: Option<f64>,
+    pub node_create_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A system-generated identifier for the node.</p>
     #[serde(rename = "NodeId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -633,7 +844,7 @@ pub struct Parameter {
     /// <p>The conditions under which changes to this parameter can be applied. For example, <code>requires-reboot</code> indicates that a new value for this parameter will only take effect if a node is rebooted.</p>
     #[serde(rename = "ChangeType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub change_type: Option<String>,
+    pub change_type: Option<ChangeType>,
     /// <p>The data type of the parameter. For example, <code>integer</code>:</p>
     #[serde(rename = "DataType")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -645,7 +856,7 @@ pub struct Parameter {
     /// <p>Whether the customer is allowed to modify the parameter.</p>
     #[serde(rename = "IsModifiable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub is_modifiable: Option<String>,
+    pub is_modifiable: Option<IsModifiable>,
     /// <p>A list of node types, and specific parameter values for each node.</p>
     #[serde(rename = "NodeTypeSpecificValues")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -657,7 +868,7 @@ pub struct Parameter {
     /// <p>Determines whether the parameter can be applied to any nodes, or only nodes of a particular type.</p>
     #[serde(rename = "ParameterType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parameter_type: Option<String>,
+    pub parameter_type: Option<ParameterType>,
     /// <p>The value for the parameter.</p>
     #[serde(rename = "ParameterValue")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -714,6 +925,99 @@ pub struct ParameterNameValue {
     pub parameter_value: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ParameterType {
+    /// `DEFAULT`
+    Default,
+    /// `NODE_TYPE_SPECIFIC`
+    NodeTypeSpecific,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ParameterType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ParameterType::Default => "DEFAULT",
+            ParameterType::NodeTypeSpecific => "NODE_TYPE_SPECIFIC",
+            ParameterType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ParameterType {
+    fn default() -> ParameterType {
+        ParameterType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ParameterType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ParameterType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ParameterType {
+    fn from(value: &'a str) -> ParameterType {
+        match value {
+            "DEFAULT" => ParameterType::Default,
+            "NODE_TYPE_SPECIFIC" => ParameterType::NodeTypeSpecific,
+            _ => ParameterType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ParameterType {
+    fn from(value: String) -> ParameterType {
+        match value.as_str() {
+            "DEFAULT" => ParameterType::Default,
+            "NODE_TYPE_SPECIFIC" => ParameterType::NodeTypeSpecific,
+            _ => ParameterType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ParameterType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ParameterType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ParameterType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ParameterType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ParameterType {
+    fn deserialize<D>(deserializer: D) -> Result<ParameterType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct RebootNodeRequest {
@@ -741,7 +1045,7 @@ pub struct SSEDescription {
     /// <p><p>The current state of server-side encryption:</p> <ul> <li> <p> <code>ENABLING</code> - Server-side encryption is being enabled.</p> </li> <li> <p> <code>ENABLED</code> - Server-side encryption is enabled.</p> </li> <li> <p> <code>DISABLING</code> - Server-side encryption is being disabled.</p> </li> <li> <p> <code>DISABLED</code> - Server-side encryption is disabled.</p> </li> </ul></p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<SSEStatus>,
 }
 
 /// <p>Represents the settings used to enable server-side encryption.</p>
@@ -753,6 +1057,109 @@ pub struct SSESpecification {
     pub enabled: bool,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SSEStatus {
+    /// `ENABLING`
+    Enabling,
+    /// `ENABLED`
+    Enabled,
+    /// `DISABLING`
+    Disabling,
+    /// `DISABLED`
+    Disabled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SSEStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SSEStatus::Enabling => "ENABLING",
+            SSEStatus::Enabled => "ENABLED",
+            SSEStatus::Disabling => "DISABLING",
+            SSEStatus::Disabled => "DISABLED",
+            SSEStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SSEStatus {
+    fn default() -> SSEStatus {
+        SSEStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SSEStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SSEStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SSEStatus {
+    fn from(value: &'a str) -> SSEStatus {
+        match value {
+            "ENABLING" => SSEStatus::Enabling,
+            "ENABLED" => SSEStatus::Enabled,
+            "DISABLING" => SSEStatus::Disabling,
+            "DISABLED" => SSEStatus::Disabled,
+            _ => SSEStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SSEStatus {
+    fn from(value: String) -> SSEStatus {
+        match value.as_str() {
+            "ENABLING" => SSEStatus::Enabling,
+            "ENABLED" => SSEStatus::Enabled,
+            "DISABLING" => SSEStatus::Disabling,
+            "DISABLED" => SSEStatus::Disabled,
+            _ => SSEStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SSEStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SSEStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SSEStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SSEStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SSEStatus {
+    fn deserialize<D>(deserializer: D) -> Result<SSEStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An individual VPC security group and its status.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -767,6 +1174,104 @@ pub struct SecurityGroupMembership {
     pub status: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SourceType {
+    /// `CLUSTER`
+    Cluster,
+    /// `PARAMETER_GROUP`
+    ParameterGroup,
+    /// `SUBNET_GROUP`
+    SubnetGroup,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SourceType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SourceType::Cluster => "CLUSTER",
+            SourceType::ParameterGroup => "PARAMETER_GROUP",
+            SourceType::SubnetGroup => "SUBNET_GROUP",
+            SourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SourceType {
+    fn default() -> SourceType {
+        SourceType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SourceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SourceType {
+    fn from(value: &'a str) -> SourceType {
+        match value {
+            "CLUSTER" => SourceType::Cluster,
+            "PARAMETER_GROUP" => SourceType::ParameterGroup,
+            "SUBNET_GROUP" => SourceType::SubnetGroup,
+            _ => SourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SourceType {
+    fn from(value: String) -> SourceType {
+        match value.as_str() {
+            "CLUSTER" => SourceType::Cluster,
+            "PARAMETER_GROUP" => SourceType::ParameterGroup,
+            "SUBNET_GROUP" => SourceType::SubnetGroup,
+            _ => SourceType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SourceType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SourceType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SourceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SourceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SourceType {
+    fn deserialize<D>(deserializer: D) -> Result<SourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents the subnet associated with a DAX cluster. This parameter refers to subnets defined in Amazon Virtual Private Cloud (Amazon VPC) and used with DAX.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -2571,11 +3076,12 @@ pub struct DynamodbAcceleratorClient {
 impl DynamodbAcceleratorClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> DynamodbAcceleratorClient {
         DynamodbAcceleratorClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "DAX"),
         }
     }
 
@@ -2607,6 +3113,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: CreateClusterRequest,
     ) -> Result<CreateClusterResponse, RusotoError<CreateClusterError>> {
         let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
+        request.set_operation("CreateCluster");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDAXV3.CreateCluster");
@@ -2634,6 +3141,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: CreateParameterGroupRequest,
     ) -> Result<CreateParameterGroupResponse, RusotoError<CreateParameterGroupError>> {
         let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
+        request.set_operation("CreateParameterGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDAXV3.CreateParameterGroup");
@@ -2662,6 +3170,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: CreateSubnetGroupRequest,
     ) -> Result<CreateSubnetGroupResponse, RusotoError<CreateSubnetGroupError>> {
         let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
+        request.set_operation("CreateSubnetGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDAXV3.CreateSubnetGroup");
@@ -2691,6 +3200,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
     ) -> Result<DecreaseReplicationFactorResponse, RusotoError<DecreaseReplicationFactorError>>
     {
         let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
+        request.set_operation("DecreaseReplicationFactor");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDAXV3.DecreaseReplicationFactor");
@@ -2719,6 +3229,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: DeleteClusterRequest,
     ) -> Result<DeleteClusterResponse, RusotoError<DeleteClusterError>> {
         let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
+        request.set_operation("DeleteCluster");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDAXV3.DeleteCluster");
@@ -2746,6 +3257,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: DeleteParameterGroupRequest,
     ) -> Result<DeleteParameterGroupResponse, RusotoError<DeleteParameterGroupError>> {
         let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
+        request.set_operation("DeleteParameterGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDAXV3.DeleteParameterGroup");
@@ -2774,6 +3286,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: DeleteSubnetGroupRequest,
     ) -> Result<DeleteSubnetGroupResponse, RusotoError<DeleteSubnetGroupError>> {
         let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
+        request.set_operation("DeleteSubnetGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDAXV3.DeleteSubnetGroup");
@@ -2802,6 +3315,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: DescribeClustersRequest,
     ) -> Result<DescribeClustersResponse, RusotoError<DescribeClustersError>> {
         let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
+        request.set_operation("DescribeClusters");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDAXV3.DescribeClusters");
@@ -2831,6 +3345,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
     ) -> Result<DescribeDefaultParametersResponse, RusotoError<DescribeDefaultParametersError>>
     {
         let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
+        request.set_operation("DescribeDefaultParameters");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDAXV3.DescribeDefaultParameters");
@@ -2859,6 +3374,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: DescribeEventsRequest,
     ) -> Result<DescribeEventsResponse, RusotoError<DescribeEventsError>> {
         let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
+        request.set_operation("DescribeEvents");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDAXV3.DescribeEvents");
@@ -2886,6 +3402,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: DescribeParameterGroupsRequest,
     ) -> Result<DescribeParameterGroupsResponse, RusotoError<DescribeParameterGroupsError>> {
         let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
+        request.set_operation("DescribeParameterGroups");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDAXV3.DescribeParameterGroups");
@@ -2914,6 +3431,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: DescribeParametersRequest,
     ) -> Result<DescribeParametersResponse, RusotoError<DescribeParametersError>> {
         let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
+        request.set_operation("DescribeParameters");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDAXV3.DescribeParameters");
@@ -2942,6 +3460,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: DescribeSubnetGroupsRequest,
     ) -> Result<DescribeSubnetGroupsResponse, RusotoError<DescribeSubnetGroupsError>> {
         let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
+        request.set_operation("DescribeSubnetGroups");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDAXV3.DescribeSubnetGroups");
@@ -2971,6 +3490,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
     ) -> Result<IncreaseReplicationFactorResponse, RusotoError<IncreaseReplicationFactorError>>
     {
         let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
+        request.set_operation("IncreaseReplicationFactor");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDAXV3.IncreaseReplicationFactor");
@@ -2999,6 +3519,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: ListTagsRequest,
     ) -> Result<ListTagsResponse, RusotoError<ListTagsError>> {
         let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
+        request.set_operation("ListTags");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDAXV3.ListTags");
@@ -3026,6 +3547,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: RebootNodeRequest,
     ) -> Result<RebootNodeResponse, RusotoError<RebootNodeError>> {
         let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
+        request.set_operation("RebootNode");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDAXV3.RebootNode");
@@ -3053,6 +3575,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDAXV3.TagResource");
@@ -3080,6 +3603,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDAXV3.UntagResource");
@@ -3107,6 +3631,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: UpdateClusterRequest,
     ) -> Result<UpdateClusterResponse, RusotoError<UpdateClusterError>> {
         let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
+        request.set_operation("UpdateCluster");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDAXV3.UpdateCluster");
@@ -3134,6 +3659,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: UpdateParameterGroupRequest,
     ) -> Result<UpdateParameterGroupResponse, RusotoError<UpdateParameterGroupError>> {
         let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
+        request.set_operation("UpdateParameterGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDAXV3.UpdateParameterGroup");
@@ -3162,6 +3688,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: UpdateSubnetGroupRequest,
     ) -> Result<UpdateSubnetGroupResponse, RusotoError<UpdateSubnetGroupError>> {
         let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
+        request.set_operation("UpdateSubnetGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDAXV3.UpdateSubnetGroup");
@@ -3184,3 +3711,233 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         }
     }
 }
+impl DynamodbAcceleratorClient {
+    /// Auto-paginating version of `describe_clusters`, streaming every page of results.
+    pub fn describe_clusters_pages(
+        &self,
+        input: DescribeClustersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeClustersResponse, DescribeClustersError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DynamodbAcceleratorClient, input: DescribeClustersRequest| async move {
+                client.describe_clusters(input).await
+            },
+            |input: &DescribeClustersRequest, output: &DescribeClustersResponse| {
+                Some(DescribeClustersRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_clusters`, streaming the `Clusters` of every page of results.
+    pub fn describe_clusters_items(
+        &self,
+        input: DescribeClustersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Cluster, DescribeClustersError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_clusters_pages(input),
+            |output: DescribeClustersResponse| output.clusters.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_default_parameters`, streaming every page of results.
+    pub fn describe_default_parameters_pages(
+        &self,
+        input: DescribeDefaultParametersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeDefaultParametersResponse,
+        DescribeDefaultParametersError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DynamodbAcceleratorClient, input: DescribeDefaultParametersRequest| async move {
+                client.describe_default_parameters(input).await
+            },
+            |input: &DescribeDefaultParametersRequest,
+             output: &DescribeDefaultParametersResponse| {
+                Some(DescribeDefaultParametersRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_default_parameters`, streaming the `Parameters` of every page of results.
+    pub fn describe_default_parameters_items(
+        &self,
+        input: DescribeDefaultParametersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Parameter, DescribeDefaultParametersError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_default_parameters_pages(input),
+            |output: DescribeDefaultParametersResponse| output.parameters.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_events`, streaming every page of results.
+    pub fn describe_events_pages(
+        &self,
+        input: DescribeEventsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeEventsResponse, DescribeEventsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DynamodbAcceleratorClient, input: DescribeEventsRequest| async move {
+                client.describe_events(input).await
+            },
+            |input: &DescribeEventsRequest, output: &DescribeEventsResponse| {
+                Some(DescribeEventsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_events`, streaming the `Events` of every page of results.
+    pub fn describe_events_items(
+        &self,
+        input: DescribeEventsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Event, DescribeEventsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_events_pages(input),
+            |output: DescribeEventsResponse| output.events.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_parameter_groups`, streaming every page of results.
+    pub fn describe_parameter_groups_pages(
+        &self,
+        input: DescribeParameterGroupsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeParameterGroupsResponse,
+        DescribeParameterGroupsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DynamodbAcceleratorClient, input: DescribeParameterGroupsRequest| async move {
+                client.describe_parameter_groups(input).await
+            },
+            |input: &DescribeParameterGroupsRequest, output: &DescribeParameterGroupsResponse| {
+                Some(DescribeParameterGroupsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_parameter_groups`, streaming the `ParameterGroups` of every page of results.
+    pub fn describe_parameter_groups_items(
+        &self,
+        input: DescribeParameterGroupsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ParameterGroup, DescribeParameterGroupsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_parameter_groups_pages(input),
+            |output: DescribeParameterGroupsResponse| output.parameter_groups.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_parameters`, streaming every page of results.
+    pub fn describe_parameters_pages(
+        &self,
+        input: DescribeParametersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeParametersResponse, DescribeParametersError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DynamodbAcceleratorClient, input: DescribeParametersRequest| async move {
+                client.describe_parameters(input).await
+            },
+            |input: &DescribeParametersRequest, output: &DescribeParametersResponse| {
+                Some(DescribeParametersRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_parameters`, streaming the `Parameters` of every page of results.
+    pub fn describe_parameters_items(
+        &self,
+        input: DescribeParametersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Parameter, DescribeParametersError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_parameters_pages(input),
+            |output: DescribeParametersResponse| output.parameters.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_subnet_groups`, streaming every page of results.
+    pub fn describe_subnet_groups_pages(
+        &self,
+        input: DescribeSubnetGroupsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeSubnetGroupsResponse,
+        DescribeSubnetGroupsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DynamodbAcceleratorClient, input: DescribeSubnetGroupsRequest| async move {
+                client.describe_subnet_groups(input).await
+            },
+            |input: &DescribeSubnetGroupsRequest, output: &DescribeSubnetGroupsResponse| {
+                Some(DescribeSubnetGroupsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_subnet_groups`, streaming the `SubnetGroups` of every page of results.
+    pub fn describe_subnet_groups_items(
+        &self,
+        input: DescribeSubnetGroupsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<SubnetGroup, DescribeSubnetGroupsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_subnet_groups_pages(input),
+            |output: DescribeSubnetGroupsResponse| output.subnet_groups.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_tags`, streaming every page of results.
+    pub fn list_tags_pages(
+        &self,
+        input: ListTagsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListTagsResponse, ListTagsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DynamodbAcceleratorClient, input: ListTagsRequest| async move {
+                client.list_tags(input).await
+            },
+            |input: &ListTagsRequest, output: &ListTagsResponse| {
+                Some(ListTagsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_tags`, streaming the `Tags` of every page of results.
+    pub fn list_tags_items(
+        &self,
+        input: ListTagsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Tag, ListTagsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_tags_pages(input),
+            |output: ListTagsResponse| output.tags.unwrap_or_default(),
+        )
+    }
+}
@@ -155,8 +155,13 @@ pub struct Graph {
     pub arn: Option<String>,
     /// <p>The date and time that the behavior graph was created. The value is in milliseconds since the epoch.</p>
     #[serde(rename = "CreatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time: Option<f64>,
+    pub created_time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -258,8 +263,13 @@ pub struct MemberDetail {
     pub graph_arn: Option<String>,
     /// <p>The date and time that Detective sent the invitation to the member account. The value is in milliseconds since the epoch.</p>
     #[serde(rename = "InvitedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub invited_time: Option<f64>,
+    pub invited_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The AWS account identifier of the master account for the behavior graph.</p>
     #[serde(rename = "MasterId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -267,13 +277,126 @@ pub struct MemberDetail {
     /// <p>The current membership status of the member account. The status can have one of the following values:</p> <ul> <li> <p> <code>INVITED</code> - Indicates that the member was sent an invitation but has not yet responded.</p> </li> <li> <p> <code>VERIFICATION_IN_PROGRESS</code> - Indicates that Detective is verifying that the account identifier and email address provided for the member account match. If they do match, then Detective sends the invitation. If the email address and account identifier don't match, then the member cannot be added to the behavior graph.</p> </li> <li> <p> <code>VERIFICATION_FAILED</code> - Indicates that the account and email address provided for the member account do not match, and Detective did not send an invitation to the account.</p> </li> <li> <p> <code>ENABLED</code> - Indicates that the member account accepted the invitation to contribute to the behavior graph.</p> </li> </ul> <p>Member accounts that declined an invitation or that were removed from the behavior graph are not included.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<MemberStatus>,
     /// <p>The date and time that the member account was last updated. The value is in milliseconds since the epoch.</p>
     #[serde(rename = "UpdatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_time: Option<f64>,
+    pub updated_time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MemberStatus {
+    /// `INVITED`
+    Invited,
+    /// `VERIFICATION_IN_PROGRESS`
+    VerificationInProgress,
+    /// `VERIFICATION_FAILED`
+    VerificationFailed,
+    /// `ENABLED`
+    Enabled,
+    /// `ACCEPTED_BUT_DISABLED`
+    AcceptedButDisabled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl MemberStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MemberStatus::Invited => "INVITED",
+            MemberStatus::VerificationInProgress => "VERIFICATION_IN_PROGRESS",
+            MemberStatus::VerificationFailed => "VERIFICATION_FAILED",
+            MemberStatus::Enabled => "ENABLED",
+            MemberStatus::AcceptedButDisabled => "ACCEPTED_BUT_DISABLED",
+            MemberStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for MemberStatus {
+    fn default() -> MemberStatus {
+        MemberStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for MemberStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for MemberStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for MemberStatus {
+    fn from(value: &'a str) -> MemberStatus {
+        match value {
+            "INVITED" => MemberStatus::Invited,
+            "VERIFICATION_IN_PROGRESS" => MemberStatus::VerificationInProgress,
+            "VERIFICATION_FAILED" => MemberStatus::VerificationFailed,
+            "ENABLED" => MemberStatus::Enabled,
+            "ACCEPTED_BUT_DISABLED" => MemberStatus::AcceptedButDisabled,
+            _ => MemberStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MemberStatus {
+    fn from(value: String) -> MemberStatus {
+        match value.as_str() {
+            "INVITED" => MemberStatus::Invited,
+            "VERIFICATION_IN_PROGRESS" => MemberStatus::VerificationInProgress,
+            "VERIFICATION_FAILED" => MemberStatus::VerificationFailed,
+            "ENABLED" => MemberStatus::Enabled,
+            "ACCEPTED_BUT_DISABLED" => MemberStatus::AcceptedButDisabled,
+            _ => MemberStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for MemberStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<MemberStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MemberStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for MemberStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MemberStatus {
+    fn deserialize<D>(deserializer: D) -> Result<MemberStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct RejectInvitationRequest {
@@ -790,11 +913,12 @@ pub struct DetectiveClient {
 impl DetectiveClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> DetectiveClient {
         DetectiveClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Detective"),
         }
     }
 
@@ -828,6 +952,7 @@ impl Detective for DetectiveClient {
         let request_uri = "/invitation";
 
         let mut request = SignedRequest::new("PUT", "detective", &self.region, &request_uri);
+        request.set_operation("AcceptInvitation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.detective".to_string());
@@ -855,6 +980,7 @@ impl Detective for DetectiveClient {
         let request_uri = "/graph";
 
         let mut request = SignedRequest::new("POST", "detective", &self.region, &request_uri);
+        request.set_operation("CreateGraph");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.detective".to_string());
@@ -884,6 +1010,7 @@ impl Detective for DetectiveClient {
         let request_uri = "/graph/members";
 
         let mut request = SignedRequest::new("POST", "detective", &self.region, &request_uri);
+        request.set_operation("CreateMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.detective".to_string());
@@ -915,6 +1042,7 @@ impl Detective for DetectiveClient {
         let request_uri = "/graph/removal";
 
         let mut request = SignedRequest::new("POST", "detective", &self.region, &request_uri);
+        request.set_operation("DeleteGraph");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.detective".to_string());
@@ -945,6 +1073,7 @@ impl Detective for DetectiveClient {
         let request_uri = "/graph/members/removal";
 
         let mut request = SignedRequest::new("POST", "detective", &self.region, &request_uri);
+        request.set_operation("DeleteMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.detective".to_string());
@@ -976,6 +1105,7 @@ impl Detective for DetectiveClient {
         let request_uri = "/membership/removal";
 
         let mut request = SignedRequest::new("POST", "detective", &self.region, &request_uri);
+        request.set_operation("DisassociateMembership");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.detective".to_string());
@@ -1006,6 +1136,7 @@ impl Detective for DetectiveClient {
         let request_uri = "/graph/members/get";
 
         let mut request = SignedRequest::new("POST", "detective", &self.region, &request_uri);
+        request.set_operation("GetMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.detective".to_string());
@@ -1037,6 +1168,7 @@ impl Detective for DetectiveClient {
         let request_uri = "/graphs/list";
 
         let mut request = SignedRequest::new("POST", "detective", &self.region, &request_uri);
+        request.set_operation("ListGraphs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.detective".to_string());
@@ -1068,6 +1200,7 @@ impl Detective for DetectiveClient {
         let request_uri = "/invitations/list";
 
         let mut request = SignedRequest::new("POST", "detective", &self.region, &request_uri);
+        request.set_operation("ListInvitations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.detective".to_string());
@@ -1099,6 +1232,7 @@ impl Detective for DetectiveClient {
         let request_uri = "/graph/members/list";
 
         let mut request = SignedRequest::new("POST", "detective", &self.region, &request_uri);
+        request.set_operation("ListMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.detective".to_string());
@@ -1130,6 +1264,7 @@ impl Detective for DetectiveClient {
         let request_uri = "/invitation/removal";
 
         let mut request = SignedRequest::new("POST", "detective", &self.region, &request_uri);
+        request.set_operation("RejectInvitation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.detective".to_string());
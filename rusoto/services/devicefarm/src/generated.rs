@@ -55,11 +55,11 @@ pub struct AccountSettings {
     /// <p>Returns the unmetered devices you have purchased or want to purchase.</p>
     #[serde(rename = "unmeteredDevices")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub unmetered_devices: Option<::std::collections::HashMap<String, i64>>,
+    pub unmetered_devices: Option<::std::collections::HashMap<DevicePlatform, i64>>,
     /// <p>Returns the unmetered remote access devices you have purchased or want to purchase.</p>
     #[serde(rename = "unmeteredRemoteAccessDevices")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub unmetered_remote_access_devices: Option<::std::collections::HashMap<String, i64>>,
+    pub unmetered_remote_access_devices: Option<::std::collections::HashMap<DevicePlatform, i64>>,
 }
 
 /// <p>Represents the output of a test. Examples of artifacts include logs and screenshots.</p>
@@ -81,13 +81,427 @@ pub struct Artifact {
     /// <p><p>The artifact&#39;s type.</p> <p>Allowed values include the following:</p> <ul> <li> <p>UNKNOWN</p> </li> <li> <p>SCREENSHOT</p> </li> <li> <p>DEVICE<em>LOG</p> </li> <li> <p>MESSAGE</em>LOG</p> </li> <li> <p>VIDEO<em>LOG</p> </li> <li> <p>RESULT</em>LOG</p> </li> <li> <p>SERVICE<em>LOG</p> </li> <li> <p>WEBKIT</em>LOG</p> </li> <li> <p>INSTRUMENTATION<em>OUTPUT</p> </li> <li> <p>EXERCISER</em>MONKEY<em>OUTPUT: the artifact (log) generated by an Android fuzz test.</p> </li> <li> <p>CALABASH</em>JSON<em>OUTPUT</p> </li> <li> <p>CALABASH</em>PRETTY<em>OUTPUT</p> </li> <li> <p>CALABASH</em>STANDARD<em>OUTPUT</p> </li> <li> <p>CALABASH</em>JAVA<em>XML</em>OUTPUT</p> </li> <li> <p>AUTOMATION<em>OUTPUT</p> </li> <li> <p>APPIUM</em>SERVER<em>OUTPUT</p> </li> <li> <p>APPIUM</em>JAVA<em>OUTPUT</p> </li> <li> <p>APPIUM</em>JAVA<em>XML</em>OUTPUT</p> </li> <li> <p>APPIUM<em>PYTHON</em>OUTPUT</p> </li> <li> <p>APPIUM<em>PYTHON</em>XML<em>OUTPUT</p> </li> <li> <p>EXPLORER</em>EVENT<em>LOG</p> </li> <li> <p>EXPLORER</em>SUMMARY<em>LOG</p> </li> <li> <p>APPLICATION</em>CRASH<em>REPORT</p> </li> <li> <p>XCTEST</em>LOG</p> </li> <li> <p>VIDEO</p> </li> <li> <p>CUSTOMER<em>ARTIFACT</p> </li> <li> <p>CUSTOMER</em>ARTIFACT<em>LOG</p> </li> <li> <p>TESTSPEC</em>OUTPUT</p> </li> </ul></p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<ArtifactType>,
     /// <p>The presigned Amazon S3 URL that can be used with a GET request to download the artifact's file.</p>
     #[serde(rename = "url")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub url: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ArtifactCategory {
+    /// `SCREENSHOT`
+    Screenshot,
+    /// `FILE`
+    File,
+    /// `LOG`
+    Log,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ArtifactCategory {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ArtifactCategory::Screenshot => "SCREENSHOT",
+            ArtifactCategory::File => "FILE",
+            ArtifactCategory::Log => "LOG",
+            ArtifactCategory::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ArtifactCategory {
+    fn default() -> ArtifactCategory {
+        ArtifactCategory::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ArtifactCategory {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ArtifactCategory {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ArtifactCategory {
+    fn from(value: &'a str) -> ArtifactCategory {
+        match value {
+            "SCREENSHOT" => ArtifactCategory::Screenshot,
+            "FILE" => ArtifactCategory::File,
+            "LOG" => ArtifactCategory::Log,
+            _ => ArtifactCategory::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ArtifactCategory {
+    fn from(value: String) -> ArtifactCategory {
+        match value.as_str() {
+            "SCREENSHOT" => ArtifactCategory::Screenshot,
+            "FILE" => ArtifactCategory::File,
+            "LOG" => ArtifactCategory::Log,
+            _ => ArtifactCategory::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ArtifactCategory {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ArtifactCategory, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ArtifactCategory {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ArtifactCategory {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ArtifactCategory {
+    fn deserialize<D>(deserializer: D) -> Result<ArtifactCategory, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ArtifactType {
+    /// `UNKNOWN`
+    UnknownValue,
+    /// `SCREENSHOT`
+    Screenshot,
+    /// `DEVICE_LOG`
+    DeviceLog,
+    /// `MESSAGE_LOG`
+    MessageLog,
+    /// `VIDEO_LOG`
+    VideoLog,
+    /// `RESULT_LOG`
+    ResultLog,
+    /// `SERVICE_LOG`
+    ServiceLog,
+    /// `WEBKIT_LOG`
+    WebkitLog,
+    /// `INSTRUMENTATION_OUTPUT`
+    InstrumentationOutput,
+    /// `EXERCISER_MONKEY_OUTPUT`
+    ExerciserMonkeyOutput,
+    /// `CALABASH_JSON_OUTPUT`
+    CalabashJsonOutput,
+    /// `CALABASH_PRETTY_OUTPUT`
+    CalabashPrettyOutput,
+    /// `CALABASH_STANDARD_OUTPUT`
+    CalabashStandardOutput,
+    /// `CALABASH_JAVA_XML_OUTPUT`
+    CalabashJavaXmlOutput,
+    /// `AUTOMATION_OUTPUT`
+    AutomationOutput,
+    /// `APPIUM_SERVER_OUTPUT`
+    AppiumServerOutput,
+    /// `APPIUM_JAVA_OUTPUT`
+    AppiumJavaOutput,
+    /// `APPIUM_JAVA_XML_OUTPUT`
+    AppiumJavaXmlOutput,
+    /// `APPIUM_PYTHON_OUTPUT`
+    AppiumPythonOutput,
+    /// `APPIUM_PYTHON_XML_OUTPUT`
+    AppiumPythonXmlOutput,
+    /// `EXPLORER_EVENT_LOG`
+    ExplorerEventLog,
+    /// `EXPLORER_SUMMARY_LOG`
+    ExplorerSummaryLog,
+    /// `APPLICATION_CRASH_REPORT`
+    ApplicationCrashReport,
+    /// `XCTEST_LOG`
+    XctestLog,
+    /// `VIDEO`
+    Video,
+    /// `CUSTOMER_ARTIFACT`
+    CustomerArtifact,
+    /// `CUSTOMER_ARTIFACT_LOG`
+    CustomerArtifactLog,
+    /// `TESTSPEC_OUTPUT`
+    TestspecOutput,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ArtifactType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ArtifactType::UnknownValue => "UNKNOWN",
+            ArtifactType::Screenshot => "SCREENSHOT",
+            ArtifactType::DeviceLog => "DEVICE_LOG",
+            ArtifactType::MessageLog => "MESSAGE_LOG",
+            ArtifactType::VideoLog => "VIDEO_LOG",
+            ArtifactType::ResultLog => "RESULT_LOG",
+            ArtifactType::ServiceLog => "SERVICE_LOG",
+            ArtifactType::WebkitLog => "WEBKIT_LOG",
+            ArtifactType::InstrumentationOutput => "INSTRUMENTATION_OUTPUT",
+            ArtifactType::ExerciserMonkeyOutput => "EXERCISER_MONKEY_OUTPUT",
+            ArtifactType::CalabashJsonOutput => "CALABASH_JSON_OUTPUT",
+            ArtifactType::CalabashPrettyOutput => "CALABASH_PRETTY_OUTPUT",
+            ArtifactType::CalabashStandardOutput => "CALABASH_STANDARD_OUTPUT",
+            ArtifactType::CalabashJavaXmlOutput => "CALABASH_JAVA_XML_OUTPUT",
+            ArtifactType::AutomationOutput => "AUTOMATION_OUTPUT",
+            ArtifactType::AppiumServerOutput => "APPIUM_SERVER_OUTPUT",
+            ArtifactType::AppiumJavaOutput => "APPIUM_JAVA_OUTPUT",
+            ArtifactType::AppiumJavaXmlOutput => "APPIUM_JAVA_XML_OUTPUT",
+            ArtifactType::AppiumPythonOutput => "APPIUM_PYTHON_OUTPUT",
+            ArtifactType::AppiumPythonXmlOutput => "APPIUM_PYTHON_XML_OUTPUT",
+            ArtifactType::ExplorerEventLog => "EXPLORER_EVENT_LOG",
+            ArtifactType::ExplorerSummaryLog => "EXPLORER_SUMMARY_LOG",
+            ArtifactType::ApplicationCrashReport => "APPLICATION_CRASH_REPORT",
+            ArtifactType::XctestLog => "XCTEST_LOG",
+            ArtifactType::Video => "VIDEO",
+            ArtifactType::CustomerArtifact => "CUSTOMER_ARTIFACT",
+            ArtifactType::CustomerArtifactLog => "CUSTOMER_ARTIFACT_LOG",
+            ArtifactType::TestspecOutput => "TESTSPEC_OUTPUT",
+            ArtifactType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ArtifactType {
+    fn default() -> ArtifactType {
+        ArtifactType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ArtifactType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ArtifactType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ArtifactType {
+    fn from(value: &'a str) -> ArtifactType {
+        match value {
+            "UNKNOWN" => ArtifactType::UnknownValue,
+            "SCREENSHOT" => ArtifactType::Screenshot,
+            "DEVICE_LOG" => ArtifactType::DeviceLog,
+            "MESSAGE_LOG" => ArtifactType::MessageLog,
+            "VIDEO_LOG" => ArtifactType::VideoLog,
+            "RESULT_LOG" => ArtifactType::ResultLog,
+            "SERVICE_LOG" => ArtifactType::ServiceLog,
+            "WEBKIT_LOG" => ArtifactType::WebkitLog,
+            "INSTRUMENTATION_OUTPUT" => ArtifactType::InstrumentationOutput,
+            "EXERCISER_MONKEY_OUTPUT" => ArtifactType::ExerciserMonkeyOutput,
+            "CALABASH_JSON_OUTPUT" => ArtifactType::CalabashJsonOutput,
+            "CALABASH_PRETTY_OUTPUT" => ArtifactType::CalabashPrettyOutput,
+            "CALABASH_STANDARD_OUTPUT" => ArtifactType::CalabashStandardOutput,
+            "CALABASH_JAVA_XML_OUTPUT" => ArtifactType::CalabashJavaXmlOutput,
+            "AUTOMATION_OUTPUT" => ArtifactType::AutomationOutput,
+            "APPIUM_SERVER_OUTPUT" => ArtifactType::AppiumServerOutput,
+            "APPIUM_JAVA_OUTPUT" => ArtifactType::AppiumJavaOutput,
+            "APPIUM_JAVA_XML_OUTPUT" => ArtifactType::AppiumJavaXmlOutput,
+            "APPIUM_PYTHON_OUTPUT" => ArtifactType::AppiumPythonOutput,
+            "APPIUM_PYTHON_XML_OUTPUT" => ArtifactType::AppiumPythonXmlOutput,
+            "EXPLORER_EVENT_LOG" => ArtifactType::ExplorerEventLog,
+            "EXPLORER_SUMMARY_LOG" => ArtifactType::ExplorerSummaryLog,
+            "APPLICATION_CRASH_REPORT" => ArtifactType::ApplicationCrashReport,
+            "XCTEST_LOG" => ArtifactType::XctestLog,
+            "VIDEO" => ArtifactType::Video,
+            "CUSTOMER_ARTIFACT" => ArtifactType::CustomerArtifact,
+            "CUSTOMER_ARTIFACT_LOG" => ArtifactType::CustomerArtifactLog,
+            "TESTSPEC_OUTPUT" => ArtifactType::TestspecOutput,
+            _ => ArtifactType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ArtifactType {
+    fn from(value: String) -> ArtifactType {
+        match value.as_str() {
+            "UNKNOWN" => ArtifactType::UnknownValue,
+            "SCREENSHOT" => ArtifactType::Screenshot,
+            "DEVICE_LOG" => ArtifactType::DeviceLog,
+            "MESSAGE_LOG" => ArtifactType::MessageLog,
+            "VIDEO_LOG" => ArtifactType::VideoLog,
+            "RESULT_LOG" => ArtifactType::ResultLog,
+            "SERVICE_LOG" => ArtifactType::ServiceLog,
+            "WEBKIT_LOG" => ArtifactType::WebkitLog,
+            "INSTRUMENTATION_OUTPUT" => ArtifactType::InstrumentationOutput,
+            "EXERCISER_MONKEY_OUTPUT" => ArtifactType::ExerciserMonkeyOutput,
+            "CALABASH_JSON_OUTPUT" => ArtifactType::CalabashJsonOutput,
+            "CALABASH_PRETTY_OUTPUT" => ArtifactType::CalabashPrettyOutput,
+            "CALABASH_STANDARD_OUTPUT" => ArtifactType::CalabashStandardOutput,
+            "CALABASH_JAVA_XML_OUTPUT" => ArtifactType::CalabashJavaXmlOutput,
+            "AUTOMATION_OUTPUT" => ArtifactType::AutomationOutput,
+            "APPIUM_SERVER_OUTPUT" => ArtifactType::AppiumServerOutput,
+            "APPIUM_JAVA_OUTPUT" => ArtifactType::AppiumJavaOutput,
+            "APPIUM_JAVA_XML_OUTPUT" => ArtifactType::AppiumJavaXmlOutput,
+            "APPIUM_PYTHON_OUTPUT" => ArtifactType::AppiumPythonOutput,
+            "APPIUM_PYTHON_XML_OUTPUT" => ArtifactType::AppiumPythonXmlOutput,
+            "EXPLORER_EVENT_LOG" => ArtifactType::ExplorerEventLog,
+            "EXPLORER_SUMMARY_LOG" => ArtifactType::ExplorerSummaryLog,
+            "APPLICATION_CRASH_REPORT" => ArtifactType::ApplicationCrashReport,
+            "XCTEST_LOG" => ArtifactType::XctestLog,
+            "VIDEO" => ArtifactType::Video,
+            "CUSTOMER_ARTIFACT" => ArtifactType::CustomerArtifact,
+            "CUSTOMER_ARTIFACT_LOG" => ArtifactType::CustomerArtifactLog,
+            "TESTSPEC_OUTPUT" => ArtifactType::TestspecOutput,
+            _ => ArtifactType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ArtifactType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ArtifactType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ArtifactType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ArtifactType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ArtifactType {
+    fn deserialize<D>(deserializer: D) -> Result<ArtifactType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BillingMethod {
+    /// `METERED`
+    Metered,
+    /// `UNMETERED`
+    Unmetered,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl BillingMethod {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BillingMethod::Metered => "METERED",
+            BillingMethod::Unmetered => "UNMETERED",
+            BillingMethod::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for BillingMethod {
+    fn default() -> BillingMethod {
+        BillingMethod::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for BillingMethod {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for BillingMethod {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for BillingMethod {
+    fn from(value: &'a str) -> BillingMethod {
+        match value {
+            "METERED" => BillingMethod::Metered,
+            "UNMETERED" => BillingMethod::Unmetered,
+            _ => BillingMethod::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BillingMethod {
+    fn from(value: String) -> BillingMethod {
+        match value.as_str() {
+            "METERED" => BillingMethod::Metered,
+            "UNMETERED" => BillingMethod::Unmetered,
+            _ => BillingMethod::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for BillingMethod {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<BillingMethod, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for BillingMethod {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for BillingMethod {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BillingMethod {
+    fn deserialize<D>(deserializer: D) -> Result<BillingMethod, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents the amount of CPU that an app is using on a physical device. Does not represent system-wide CPU usage.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -238,7 +652,7 @@ pub struct CreateNetworkProfileRequest {
     /// <p>The type of network profile to create. Valid values are listed here.</p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<NetworkProfileType>,
     /// <p>The data throughput rate in bits per second, as an integer from 0 to 104857600.</p>
     #[serde(rename = "uplinkBandwidthBits")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -296,7 +710,7 @@ pub struct CreateRemoteAccessSessionConfiguration {
     /// <p>The billing method for the remote access session.</p>
     #[serde(rename = "billingMethod")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub billing_method: Option<String>,
+    pub billing_method: Option<BillingMethod>,
     /// <p>An array of ARNs included in the VPC endpoint configuration.</p>
     #[serde(rename = "vpceConfigurationArns")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -325,7 +739,7 @@ pub struct CreateRemoteAccessSessionRequest {
     /// <p><p>The interaction mode of the remote access session. Valid values are:</p> <ul> <li> <p>INTERACTIVE: You can interact with the iOS device by viewing, touching, and rotating the screen. You cannot run XCUITest framework-based tests in this mode.</p> </li> <li> <p>NO<em>VIDEO: You are connected to the device, but cannot interact with it or view the screen. This mode has the fastest test execution speed. You can run XCUITest framework-based tests in this mode.</p> </li> <li> <p>VIDEO</em>ONLY: You can view the screen, but cannot touch or rotate it. You can run XCUITest framework-based tests and watch the screen in this mode.</p> </li> </ul></p>
     #[serde(rename = "interactionMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub interaction_mode: Option<String>,
+    pub interaction_mode: Option<InteractionMode>,
     /// <p>The name of the remote access session to create.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -402,8 +816,13 @@ pub struct CreateTestGridUrlRequest {
 pub struct CreateTestGridUrlResult {
     /// <p>The number of seconds the URL from <a>CreateTestGridUrlResult$url</a> stays active.</p>
     #[serde(rename = "expires")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expires: Option<f64>,
+    pub expires: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A signed URL, expiring in <a>CreateTestGridUrlRequest$expiresInSeconds</a> seconds, to be passed to a <code>RemoteWebDriver</code>. </p>
     #[serde(rename = "url")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -426,7 +845,7 @@ pub struct CreateUploadRequest {
     pub project_arn: String,
     /// <p>The upload's upload type.</p> <p>Must be one of the following values:</p> <ul> <li> <p>ANDROID_APP</p> </li> <li> <p>IOS_APP</p> </li> <li> <p>WEB_APP</p> </li> <li> <p>EXTERNAL_DATA</p> </li> <li> <p>APPIUM_JAVA_JUNIT_TEST_PACKAGE</p> </li> <li> <p>APPIUM_JAVA_TESTNG_TEST_PACKAGE</p> </li> <li> <p>APPIUM_PYTHON_TEST_PACKAGE</p> </li> <li> <p>APPIUM_NODE_TEST_PACKAGE</p> </li> <li> <p>APPIUM_RUBY_TEST_PACKAGE</p> </li> <li> <p>APPIUM_WEB_JAVA_JUNIT_TEST_PACKAGE</p> </li> <li> <p>APPIUM_WEB_JAVA_TESTNG_TEST_PACKAGE</p> </li> <li> <p>APPIUM_WEB_PYTHON_TEST_PACKAGE</p> </li> <li> <p>APPIUM_WEB_NODE_TEST_PACKAGE</p> </li> <li> <p>APPIUM_WEB_RUBY_TEST_PACKAGE</p> </li> <li> <p>CALABASH_TEST_PACKAGE</p> </li> <li> <p>INSTRUMENTATION_TEST_PACKAGE</p> </li> <li> <p>UIAUTOMATION_TEST_PACKAGE</p> </li> <li> <p>UIAUTOMATOR_TEST_PACKAGE</p> </li> <li> <p>XCTEST_TEST_PACKAGE</p> </li> <li> <p>XCTEST_UI_TEST_PACKAGE</p> </li> <li> <p>APPIUM_JAVA_JUNIT_TEST_SPEC</p> </li> <li> <p>APPIUM_JAVA_TESTNG_TEST_SPEC</p> </li> <li> <p>APPIUM_PYTHON_TEST_SPEC</p> </li> <li> <p>APPIUM_NODE_TEST_SPEC</p> </li> <li> <p>APPIUM_RUBY_TEST_SPEC</p> </li> <li> <p>APPIUM_WEB_JAVA_JUNIT_TEST_SPEC</p> </li> <li> <p>APPIUM_WEB_JAVA_TESTNG_TEST_SPEC</p> </li> <li> <p>APPIUM_WEB_PYTHON_TEST_SPEC</p> </li> <li> <p>APPIUM_WEB_NODE_TEST_SPEC</p> </li> <li> <p>APPIUM_WEB_RUBY_TEST_SPEC</p> </li> <li> <p>INSTRUMENTATION_TEST_SPEC</p> </li> <li> <p>XCTEST_UI_TEST_SPEC</p> </li> </ul> <p> If you call <code>CreateUpload</code> with <code>WEB_APP</code> specified, AWS Device Farm throws an <code>ArgumentException</code> error.</p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: UploadType,
 }
 
 /// <p>Represents the result of a create upload request.</p>
@@ -466,6 +885,94 @@ pub struct CreateVPCEConfigurationResult {
     pub vpce_configuration: Option<VPCEConfiguration>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CurrencyCode {
+    /// `USD`
+    Usd,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CurrencyCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CurrencyCode::Usd => "USD",
+            CurrencyCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CurrencyCode {
+    fn default() -> CurrencyCode {
+        CurrencyCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CurrencyCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CurrencyCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CurrencyCode {
+    fn from(value: &'a str) -> CurrencyCode {
+        match value {
+            "USD" => CurrencyCode::Usd,
+            _ => CurrencyCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CurrencyCode {
+    fn from(value: String) -> CurrencyCode {
+        match value.as_str() {
+            "USD" => CurrencyCode::Usd,
+            _ => CurrencyCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CurrencyCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CurrencyCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CurrencyCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CurrencyCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CurrencyCode {
+    fn deserialize<D>(deserializer: D) -> Result<CurrencyCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A JSON object that specifies the paths where the artifacts generated by the customer's tests, on the device or in the test environment, are pulled from.</p> <p>Specify <code>deviceHostPaths</code> and optionally specify either <code>iosPaths</code> or <code>androidPaths</code>.</p> <p>For web app tests, you can specify both <code>iosPaths</code> and <code>androidPaths</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CustomerArtifactPaths {
@@ -612,7 +1119,7 @@ pub struct Device {
     /// <p>Indicates how likely a device is available for a test run. Currently available in the <a>ListDevices</a> and GetDevice API methods.</p>
     #[serde(rename = "availability")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub availability: Option<String>,
+    pub availability: Option<DeviceAvailability>,
     /// <p>The device's carrier.</p>
     #[serde(rename = "carrier")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -632,7 +1139,7 @@ pub struct Device {
     /// <p><p>The device&#39;s form factor.</p> <p>Allowed values include:</p> <ul> <li> <p>PHONE</p> </li> <li> <p>TABLET</p> </li> </ul></p>
     #[serde(rename = "formFactor")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub form_factor: Option<String>,
+    pub form_factor: Option<DeviceFormFactor>,
     /// <p>The device's heap size, expressed in bytes.</p>
     #[serde(rename = "heapSize")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -672,7 +1179,7 @@ pub struct Device {
     /// <p><p>The device&#39;s platform.</p> <p>Allowed values include:</p> <ul> <li> <p>ANDROID</p> </li> <li> <p>IOS</p> </li> </ul></p>
     #[serde(rename = "platform")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub platform: Option<String>,
+    pub platform: Option<DevicePlatform>,
     /// <p>The device's radio.</p>
     #[serde(rename = "radio")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -691,72 +1198,652 @@ pub struct Device {
     pub resolution: Option<Resolution>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DeviceAttribute {
+    /// `ARN`
+    Arn,
+    /// `PLATFORM`
+    Platform,
+    /// `FORM_FACTOR`
+    FormFactor,
+    /// `MANUFACTURER`
+    Manufacturer,
+    /// `REMOTE_ACCESS_ENABLED`
+    RemoteAccessEnabled,
+    /// `REMOTE_DEBUG_ENABLED`
+    RemoteDebugEnabled,
+    /// `APPIUM_VERSION`
+    AppiumVersion,
+    /// `INSTANCE_ARN`
+    InstanceArn,
+    /// `INSTANCE_LABELS`
+    InstanceLabels,
+    /// `FLEET_TYPE`
+    FleetType,
+    /// `OS_VERSION`
+    OsVersion,
+    /// `MODEL`
+    Model,
+    /// `AVAILABILITY`
+    Availability,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DeviceAttribute {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeviceAttribute::Arn => "ARN",
+            DeviceAttribute::Platform => "PLATFORM",
+            DeviceAttribute::FormFactor => "FORM_FACTOR",
+            DeviceAttribute::Manufacturer => "MANUFACTURER",
+            DeviceAttribute::RemoteAccessEnabled => "REMOTE_ACCESS_ENABLED",
+            DeviceAttribute::RemoteDebugEnabled => "REMOTE_DEBUG_ENABLED",
+            DeviceAttribute::AppiumVersion => "APPIUM_VERSION",
+            DeviceAttribute::InstanceArn => "INSTANCE_ARN",
+            DeviceAttribute::InstanceLabels => "INSTANCE_LABELS",
+            DeviceAttribute::FleetType => "FLEET_TYPE",
+            DeviceAttribute::OsVersion => "OS_VERSION",
+            DeviceAttribute::Model => "MODEL",
+            DeviceAttribute::Availability => "AVAILABILITY",
+            DeviceAttribute::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DeviceAttribute {
+    fn default() -> DeviceAttribute {
+        DeviceAttribute::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DeviceAttribute {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DeviceAttribute {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DeviceAttribute {
+    fn from(value: &'a str) -> DeviceAttribute {
+        match value {
+            "ARN" => DeviceAttribute::Arn,
+            "PLATFORM" => DeviceAttribute::Platform,
+            "FORM_FACTOR" => DeviceAttribute::FormFactor,
+            "MANUFACTURER" => DeviceAttribute::Manufacturer,
+            "REMOTE_ACCESS_ENABLED" => DeviceAttribute::RemoteAccessEnabled,
+            "REMOTE_DEBUG_ENABLED" => DeviceAttribute::RemoteDebugEnabled,
+            "APPIUM_VERSION" => DeviceAttribute::AppiumVersion,
+            "INSTANCE_ARN" => DeviceAttribute::InstanceArn,
+            "INSTANCE_LABELS" => DeviceAttribute::InstanceLabels,
+            "FLEET_TYPE" => DeviceAttribute::FleetType,
+            "OS_VERSION" => DeviceAttribute::OsVersion,
+            "MODEL" => DeviceAttribute::Model,
+            "AVAILABILITY" => DeviceAttribute::Availability,
+            _ => DeviceAttribute::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeviceAttribute {
+    fn from(value: String) -> DeviceAttribute {
+        match value.as_str() {
+            "ARN" => DeviceAttribute::Arn,
+            "PLATFORM" => DeviceAttribute::Platform,
+            "FORM_FACTOR" => DeviceAttribute::FormFactor,
+            "MANUFACTURER" => DeviceAttribute::Manufacturer,
+            "REMOTE_ACCESS_ENABLED" => DeviceAttribute::RemoteAccessEnabled,
+            "REMOTE_DEBUG_ENABLED" => DeviceAttribute::RemoteDebugEnabled,
+            "APPIUM_VERSION" => DeviceAttribute::AppiumVersion,
+            "INSTANCE_ARN" => DeviceAttribute::InstanceArn,
+            "INSTANCE_LABELS" => DeviceAttribute::InstanceLabels,
+            "FLEET_TYPE" => DeviceAttribute::FleetType,
+            "OS_VERSION" => DeviceAttribute::OsVersion,
+            "MODEL" => DeviceAttribute::Model,
+            "AVAILABILITY" => DeviceAttribute::Availability,
+            _ => DeviceAttribute::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DeviceAttribute {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DeviceAttribute, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DeviceAttribute {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DeviceAttribute {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeviceAttribute {
+    fn deserialize<D>(deserializer: D) -> Result<DeviceAttribute, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DeviceAvailability {
+    /// `TEMPORARY_NOT_AVAILABLE`
+    TemporaryNotAvailable,
+    /// `BUSY`
+    Busy,
+    /// `AVAILABLE`
+    Available,
+    /// `HIGHLY_AVAILABLE`
+    HighlyAvailable,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DeviceAvailability {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeviceAvailability::TemporaryNotAvailable => "TEMPORARY_NOT_AVAILABLE",
+            DeviceAvailability::Busy => "BUSY",
+            DeviceAvailability::Available => "AVAILABLE",
+            DeviceAvailability::HighlyAvailable => "HIGHLY_AVAILABLE",
+            DeviceAvailability::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DeviceAvailability {
+    fn default() -> DeviceAvailability {
+        DeviceAvailability::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DeviceAvailability {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DeviceAvailability {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DeviceAvailability {
+    fn from(value: &'a str) -> DeviceAvailability {
+        match value {
+            "TEMPORARY_NOT_AVAILABLE" => DeviceAvailability::TemporaryNotAvailable,
+            "BUSY" => DeviceAvailability::Busy,
+            "AVAILABLE" => DeviceAvailability::Available,
+            "HIGHLY_AVAILABLE" => DeviceAvailability::HighlyAvailable,
+            _ => DeviceAvailability::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeviceAvailability {
+    fn from(value: String) -> DeviceAvailability {
+        match value.as_str() {
+            "TEMPORARY_NOT_AVAILABLE" => DeviceAvailability::TemporaryNotAvailable,
+            "BUSY" => DeviceAvailability::Busy,
+            "AVAILABLE" => DeviceAvailability::Available,
+            "HIGHLY_AVAILABLE" => DeviceAvailability::HighlyAvailable,
+            _ => DeviceAvailability::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DeviceAvailability {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DeviceAvailability, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DeviceAvailability {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DeviceAvailability {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeviceAvailability {
+    fn deserialize<D>(deserializer: D) -> Result<DeviceAvailability, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents a device filter used to select a set of devices to be included in a test run. This data structure is passed in as the <code>deviceSelectionConfiguration</code> parameter to <code>ScheduleRun</code>. For an example of the JSON request syntax, see <a>ScheduleRun</a>.</p> <p>It is also passed in as the <code>filters</code> parameter to <code>ListDevices</code>. For an example of the JSON request syntax, see <a>ListDevices</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DeviceFilter {
     /// <p><p>The aspect of a device such as platform or model used as the selection criteria in a device filter.</p> <p>The supported operators for each attribute are provided in the following list.</p> <dl> <dt>ARN</dt> <dd> <p>The Amazon Resource Name (ARN) of the device (for example, <code>arn:aws:devicefarm:us-west-2::device:12345Example</code>).</p> <p>Supported operators: <code>EQUALS</code>, <code>IN</code>, <code>NOT<em>IN</code> </p> </dd> <dt>PLATFORM</dt> <dd> <p>The device platform. Valid values are ANDROID or IOS.</p> <p>Supported operators: <code>EQUALS</code> </p> </dd> <dt>OS</em>VERSION</dt> <dd> <p>The operating system version (for example, 10.3.2).</p> <p>Supported operators: <code>EQUALS</code>, <code>GREATER<em>THAN</code>, <code>GREATER</em>THAN<em>OR</em>EQUALS</code>, <code>IN</code>, <code>LESS<em>THAN</code>, <code>LESS</em>THAN<em>OR</em>EQUALS</code>, <code>NOT<em>IN</code> </p> </dd> <dt>MODEL</dt> <dd> <p>The device model (for example, iPad 5th Gen).</p> <p>Supported operators: <code>CONTAINS</code>, <code>EQUALS</code>, <code>IN</code>, <code>NOT</em>IN</code> </p> </dd> <dt>AVAILABILITY</dt> <dd> <p>The current availability of the device. Valid values are AVAILABLE, HIGHLY<em>AVAILABLE, BUSY, or TEMPORARY</em>NOT<em>AVAILABLE.</p> <p>Supported operators: <code>EQUALS</code> </p> </dd> <dt>FORM</em>FACTOR</dt> <dd> <p>The device form factor. Valid values are PHONE or TABLET.</p> <p>Supported operators: <code>EQUALS</code> </p> </dd> <dt>MANUFACTURER</dt> <dd> <p>The device manufacturer (for example, Apple).</p> <p>Supported operators: <code>EQUALS</code>, <code>IN</code>, <code>NOT<em>IN</code> </p> </dd> <dt>REMOTE</em>ACCESS<em>ENABLED</dt> <dd> <p>Whether the device is enabled for remote access. Valid values are TRUE or FALSE.</p> <p>Supported operators: <code>EQUALS</code> </p> </dd> <dt>REMOTE</em>DEBUG<em>ENABLED</dt> <dd> <p>Whether the device is enabled for remote debugging. Valid values are TRUE or FALSE.</p> <p>Supported operators: <code>EQUALS</code> </p> <p>Because remote debugging is <a href="https://docs.aws.amazon.com/devicefarm/latest/developerguide/history.html">no longer supported</a>, this filter is ignored.</p> </dd> <dt>INSTANCE</em>ARN</dt> <dd> <p>The Amazon Resource Name (ARN) of the device instance.</p> <p>Supported operators: <code>EQUALS</code>, <code>IN</code>, <code>NOT<em>IN</code> </p> </dd> <dt>INSTANCE</em>LABELS</dt> <dd> <p>The label of the device instance.</p> <p>Supported operators: <code>CONTAINS</code> </p> </dd> <dt>FLEET_TYPE</dt> <dd> <p>The fleet type. Valid values are PUBLIC or PRIVATE.</p> <p>Supported operators: <code>EQUALS</code> </p> </dd> </dl></p>
     #[serde(rename = "attribute")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub attribute: Option<String>,
+    pub attribute: Option<DeviceFilterAttribute>,
     /// <p>Specifies how Device Farm compares the filter's attribute to the value. See the attribute descriptions.</p>
     #[serde(rename = "operator")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub operator: Option<String>,
+    pub operator: Option<RuleOperator>,
     /// <p><p>An array of one or more filter values used in a device filter.</p> <p class="title"> <b>Operator Values</b> </p> <ul> <li> <p>The IN and NOT<em>IN operators can take a values array that has more than one element.</p> </li> <li> <p>The other operators require an array with a single element.</p> </li> </ul> <p class="title"> <b>Attribute Values</b> </p> <ul> <li> <p>The PLATFORM attribute can be set to ANDROID or IOS.</p> </li> <li> <p>The AVAILABILITY attribute can be set to AVAILABLE, HIGHLY</em>AVAILABLE, BUSY, or TEMPORARY<em>NOT</em>AVAILABLE.</p> </li> <li> <p>The FORM<em>FACTOR attribute can be set to PHONE or TABLET.</p> </li> <li> <p>The FLEET</em>TYPE attribute can be set to PUBLIC or PRIVATE.</p> </li> </ul></p>
     #[serde(rename = "values")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub values: Option<Vec<String>>,
 }
 
-/// <p>Represents the device instance.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct DeviceInstance {
-    /// <p>The Amazon Resource Name (ARN) of the device instance.</p>
-    #[serde(rename = "arn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub arn: Option<String>,
-    /// <p>The ARN of the device.</p>
-    #[serde(rename = "deviceArn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub device_arn: Option<String>,
-    /// <p>A object that contains information about the instance profile.</p>
-    #[serde(rename = "instanceProfile")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub instance_profile: Option<InstanceProfile>,
-    /// <p>An array of strings that describe the device instance.</p>
-    #[serde(rename = "labels")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub labels: Option<Vec<String>>,
-    /// <p>The status of the device instance. Valid values are listed here.</p>
-    #[serde(rename = "status")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
-    /// <p>Unique device identifier for the device instance.</p>
-    #[serde(rename = "udid")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub udid: Option<String>,
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DeviceFilterAttribute {
+    /// `ARN`
+    Arn,
+    /// `PLATFORM`
+    Platform,
+    /// `OS_VERSION`
+    OsVersion,
+    /// `MODEL`
+    Model,
+    /// `AVAILABILITY`
+    Availability,
+    /// `FORM_FACTOR`
+    FormFactor,
+    /// `MANUFACTURER`
+    Manufacturer,
+    /// `REMOTE_ACCESS_ENABLED`
+    RemoteAccessEnabled,
+    /// `REMOTE_DEBUG_ENABLED`
+    RemoteDebugEnabled,
+    /// `INSTANCE_ARN`
+    InstanceArn,
+    /// `INSTANCE_LABELS`
+    InstanceLabels,
+    /// `FLEET_TYPE`
+    FleetType,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DeviceFilterAttribute {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeviceFilterAttribute::Arn => "ARN",
+            DeviceFilterAttribute::Platform => "PLATFORM",
+            DeviceFilterAttribute::OsVersion => "OS_VERSION",
+            DeviceFilterAttribute::Model => "MODEL",
+            DeviceFilterAttribute::Availability => "AVAILABILITY",
+            DeviceFilterAttribute::FormFactor => "FORM_FACTOR",
+            DeviceFilterAttribute::Manufacturer => "MANUFACTURER",
+            DeviceFilterAttribute::RemoteAccessEnabled => "REMOTE_ACCESS_ENABLED",
+            DeviceFilterAttribute::RemoteDebugEnabled => "REMOTE_DEBUG_ENABLED",
+            DeviceFilterAttribute::InstanceArn => "INSTANCE_ARN",
+            DeviceFilterAttribute::InstanceLabels => "INSTANCE_LABELS",
+            DeviceFilterAttribute::FleetType => "FLEET_TYPE",
+            DeviceFilterAttribute::Unknown(ref value) => value,
+        }
+    }
 }
 
-/// <p>Represents the total (metered or unmetered) minutes used by the resource to run tests. Contains the sum of minutes consumed by all children.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct DeviceMinutes {
-    /// <p>When specified, represents only the sum of metered minutes used by the resource to run tests.</p>
-    #[serde(rename = "metered")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub metered: Option<f64>,
-    /// <p>When specified, represents the total minutes used by the resource to run tests.</p>
-    #[serde(rename = "total")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub total: Option<f64>,
-    /// <p>When specified, represents only the sum of unmetered minutes used by the resource to run tests.</p>
-    #[serde(rename = "unmetered")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub unmetered: Option<f64>,
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DeviceFilterAttribute {
+    fn default() -> DeviceFilterAttribute {
+        DeviceFilterAttribute::Unknown(String::new())
+    }
 }
 
-/// <p>Represents a collection of device types.</p>
+impl AsRef<str> for DeviceFilterAttribute {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DeviceFilterAttribute {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DeviceFilterAttribute {
+    fn from(value: &'a str) -> DeviceFilterAttribute {
+        match value {
+            "ARN" => DeviceFilterAttribute::Arn,
+            "PLATFORM" => DeviceFilterAttribute::Platform,
+            "OS_VERSION" => DeviceFilterAttribute::OsVersion,
+            "MODEL" => DeviceFilterAttribute::Model,
+            "AVAILABILITY" => DeviceFilterAttribute::Availability,
+            "FORM_FACTOR" => DeviceFilterAttribute::FormFactor,
+            "MANUFACTURER" => DeviceFilterAttribute::Manufacturer,
+            "REMOTE_ACCESS_ENABLED" => DeviceFilterAttribute::RemoteAccessEnabled,
+            "REMOTE_DEBUG_ENABLED" => DeviceFilterAttribute::RemoteDebugEnabled,
+            "INSTANCE_ARN" => DeviceFilterAttribute::InstanceArn,
+            "INSTANCE_LABELS" => DeviceFilterAttribute::InstanceLabels,
+            "FLEET_TYPE" => DeviceFilterAttribute::FleetType,
+            _ => DeviceFilterAttribute::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeviceFilterAttribute {
+    fn from(value: String) -> DeviceFilterAttribute {
+        match value.as_str() {
+            "ARN" => DeviceFilterAttribute::Arn,
+            "PLATFORM" => DeviceFilterAttribute::Platform,
+            "OS_VERSION" => DeviceFilterAttribute::OsVersion,
+            "MODEL" => DeviceFilterAttribute::Model,
+            "AVAILABILITY" => DeviceFilterAttribute::Availability,
+            "FORM_FACTOR" => DeviceFilterAttribute::FormFactor,
+            "MANUFACTURER" => DeviceFilterAttribute::Manufacturer,
+            "REMOTE_ACCESS_ENABLED" => DeviceFilterAttribute::RemoteAccessEnabled,
+            "REMOTE_DEBUG_ENABLED" => DeviceFilterAttribute::RemoteDebugEnabled,
+            "INSTANCE_ARN" => DeviceFilterAttribute::InstanceArn,
+            "INSTANCE_LABELS" => DeviceFilterAttribute::InstanceLabels,
+            "FLEET_TYPE" => DeviceFilterAttribute::FleetType,
+            _ => DeviceFilterAttribute::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DeviceFilterAttribute {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DeviceFilterAttribute, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DeviceFilterAttribute {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DeviceFilterAttribute {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeviceFilterAttribute {
+    fn deserialize<D>(deserializer: D) -> Result<DeviceFilterAttribute, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DeviceFormFactor {
+    /// `PHONE`
+    Phone,
+    /// `TABLET`
+    Tablet,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DeviceFormFactor {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeviceFormFactor::Phone => "PHONE",
+            DeviceFormFactor::Tablet => "TABLET",
+            DeviceFormFactor::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DeviceFormFactor {
+    fn default() -> DeviceFormFactor {
+        DeviceFormFactor::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DeviceFormFactor {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DeviceFormFactor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DeviceFormFactor {
+    fn from(value: &'a str) -> DeviceFormFactor {
+        match value {
+            "PHONE" => DeviceFormFactor::Phone,
+            "TABLET" => DeviceFormFactor::Tablet,
+            _ => DeviceFormFactor::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeviceFormFactor {
+    fn from(value: String) -> DeviceFormFactor {
+        match value.as_str() {
+            "PHONE" => DeviceFormFactor::Phone,
+            "TABLET" => DeviceFormFactor::Tablet,
+            _ => DeviceFormFactor::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DeviceFormFactor {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DeviceFormFactor, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DeviceFormFactor {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DeviceFormFactor {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeviceFormFactor {
+    fn deserialize<D>(deserializer: D) -> Result<DeviceFormFactor, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Represents the device instance.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct DeviceInstance {
+    /// <p>The Amazon Resource Name (ARN) of the device instance.</p>
+    #[serde(rename = "arn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub arn: Option<String>,
+    /// <p>The ARN of the device.</p>
+    #[serde(rename = "deviceArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device_arn: Option<String>,
+    /// <p>A object that contains information about the instance profile.</p>
+    #[serde(rename = "instanceProfile")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instance_profile: Option<InstanceProfile>,
+    /// <p>An array of strings that describe the device instance.</p>
+    #[serde(rename = "labels")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Vec<String>>,
+    /// <p>The status of the device instance. Valid values are listed here.</p>
+    #[serde(rename = "status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<InstanceStatus>,
+    /// <p>Unique device identifier for the device instance.</p>
+    #[serde(rename = "udid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub udid: Option<String>,
+}
+
+/// <p>Represents the total (metered or unmetered) minutes used by the resource to run tests. Contains the sum of minutes consumed by all children.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct DeviceMinutes {
+    /// <p>When specified, represents only the sum of metered minutes used by the resource to run tests.</p>
+    #[serde(rename = "metered")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metered: Option<f64>,
+    /// <p>When specified, represents the total minutes used by the resource to run tests.</p>
+    #[serde(rename = "total")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total: Option<f64>,
+    /// <p>When specified, represents only the sum of unmetered minutes used by the resource to run tests.</p>
+    #[serde(rename = "unmetered")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unmetered: Option<f64>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DevicePlatform {
+    /// `ANDROID`
+    Android,
+    /// `IOS`
+    Ios,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DevicePlatform {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DevicePlatform::Android => "ANDROID",
+            DevicePlatform::Ios => "IOS",
+            DevicePlatform::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DevicePlatform {
+    fn default() -> DevicePlatform {
+        DevicePlatform::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DevicePlatform {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DevicePlatform {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DevicePlatform {
+    fn from(value: &'a str) -> DevicePlatform {
+        match value {
+            "ANDROID" => DevicePlatform::Android,
+            "IOS" => DevicePlatform::Ios,
+            _ => DevicePlatform::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DevicePlatform {
+    fn from(value: String) -> DevicePlatform {
+        match value.as_str() {
+            "ANDROID" => DevicePlatform::Android,
+            "IOS" => DevicePlatform::Ios,
+            _ => DevicePlatform::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DevicePlatform {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DevicePlatform, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DevicePlatform {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DevicePlatform {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DevicePlatform {
+    fn deserialize<D>(deserializer: D) -> Result<DevicePlatform, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Represents a collection of device types.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DevicePool {
@@ -783,7 +1870,7 @@ pub struct DevicePool {
     /// <p><p>The device pool&#39;s type.</p> <p>Allowed values include:</p> <ul> <li> <p>CURATED: A device pool that is created and managed by AWS Device Farm.</p> </li> <li> <p>PRIVATE: A device pool that is created and managed by the device pool developer.</p> </li> </ul></p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<DevicePoolType>,
 }
 
 /// <p>Represents a device pool compatibility result.</p>
@@ -804,6 +1891,99 @@ pub struct DevicePoolCompatibilityResult {
     pub incompatibility_messages: Option<Vec<IncompatibilityMessage>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DevicePoolType {
+    /// `CURATED`
+    Curated,
+    /// `PRIVATE`
+    Private,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DevicePoolType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DevicePoolType::Curated => "CURATED",
+            DevicePoolType::Private => "PRIVATE",
+            DevicePoolType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DevicePoolType {
+    fn default() -> DevicePoolType {
+        DevicePoolType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DevicePoolType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DevicePoolType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DevicePoolType {
+    fn from(value: &'a str) -> DevicePoolType {
+        match value {
+            "CURATED" => DevicePoolType::Curated,
+            "PRIVATE" => DevicePoolType::Private,
+            _ => DevicePoolType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DevicePoolType {
+    fn from(value: String) -> DevicePoolType {
+        match value.as_str() {
+            "CURATED" => DevicePoolType::Curated,
+            "PRIVATE" => DevicePoolType::Private,
+            _ => DevicePoolType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DevicePoolType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DevicePoolType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DevicePoolType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DevicePoolType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DevicePoolType {
+    fn deserialize<D>(deserializer: D) -> Result<DevicePoolType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents the device filters used in a test run and the maximum number of devices to be included in the run. It is passed in as the <code>deviceSelectionConfiguration</code> request parameter in <a>ScheduleRun</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -860,6 +2040,345 @@ pub struct ExecutionConfiguration {
     pub video_capture: Option<bool>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ExecutionResult {
+    /// `PENDING`
+    Pending,
+    /// `PASSED`
+    Passed,
+    /// `WARNED`
+    Warned,
+    /// `FAILED`
+    Failed,
+    /// `SKIPPED`
+    Skipped,
+    /// `ERRORED`
+    Errored,
+    /// `STOPPED`
+    Stopped,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ExecutionResult {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ExecutionResult::Pending => "PENDING",
+            ExecutionResult::Passed => "PASSED",
+            ExecutionResult::Warned => "WARNED",
+            ExecutionResult::Failed => "FAILED",
+            ExecutionResult::Skipped => "SKIPPED",
+            ExecutionResult::Errored => "ERRORED",
+            ExecutionResult::Stopped => "STOPPED",
+            ExecutionResult::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ExecutionResult {
+    fn default() -> ExecutionResult {
+        ExecutionResult::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ExecutionResult {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ExecutionResult {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ExecutionResult {
+    fn from(value: &'a str) -> ExecutionResult {
+        match value {
+            "PENDING" => ExecutionResult::Pending,
+            "PASSED" => ExecutionResult::Passed,
+            "WARNED" => ExecutionResult::Warned,
+            "FAILED" => ExecutionResult::Failed,
+            "SKIPPED" => ExecutionResult::Skipped,
+            "ERRORED" => ExecutionResult::Errored,
+            "STOPPED" => ExecutionResult::Stopped,
+            _ => ExecutionResult::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ExecutionResult {
+    fn from(value: String) -> ExecutionResult {
+        match value.as_str() {
+            "PENDING" => ExecutionResult::Pending,
+            "PASSED" => ExecutionResult::Passed,
+            "WARNED" => ExecutionResult::Warned,
+            "FAILED" => ExecutionResult::Failed,
+            "SKIPPED" => ExecutionResult::Skipped,
+            "ERRORED" => ExecutionResult::Errored,
+            "STOPPED" => ExecutionResult::Stopped,
+            _ => ExecutionResult::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ExecutionResult {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ExecutionResult, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ExecutionResult {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ExecutionResult {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ExecutionResult {
+    fn deserialize<D>(deserializer: D) -> Result<ExecutionResult, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ExecutionResultCode {
+    /// `PARSING_FAILED`
+    ParsingFailed,
+    /// `VPC_ENDPOINT_SETUP_FAILED`
+    VpcEndpointSetupFailed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ExecutionResultCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ExecutionResultCode::ParsingFailed => "PARSING_FAILED",
+            ExecutionResultCode::VpcEndpointSetupFailed => "VPC_ENDPOINT_SETUP_FAILED",
+            ExecutionResultCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ExecutionResultCode {
+    fn default() -> ExecutionResultCode {
+        ExecutionResultCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ExecutionResultCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ExecutionResultCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ExecutionResultCode {
+    fn from(value: &'a str) -> ExecutionResultCode {
+        match value {
+            "PARSING_FAILED" => ExecutionResultCode::ParsingFailed,
+            "VPC_ENDPOINT_SETUP_FAILED" => ExecutionResultCode::VpcEndpointSetupFailed,
+            _ => ExecutionResultCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ExecutionResultCode {
+    fn from(value: String) -> ExecutionResultCode {
+        match value.as_str() {
+            "PARSING_FAILED" => ExecutionResultCode::ParsingFailed,
+            "VPC_ENDPOINT_SETUP_FAILED" => ExecutionResultCode::VpcEndpointSetupFailed,
+            _ => ExecutionResultCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ExecutionResultCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ExecutionResultCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ExecutionResultCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ExecutionResultCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ExecutionResultCode {
+    fn deserialize<D>(deserializer: D) -> Result<ExecutionResultCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ExecutionStatus {
+    /// `PENDING`
+    Pending,
+    /// `PENDING_CONCURRENCY`
+    PendingConcurrency,
+    /// `PENDING_DEVICE`
+    PendingDevice,
+    /// `PROCESSING`
+    Processing,
+    /// `SCHEDULING`
+    Scheduling,
+    /// `PREPARING`
+    Preparing,
+    /// `RUNNING`
+    Running,
+    /// `COMPLETED`
+    Completed,
+    /// `STOPPING`
+    Stopping,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ExecutionStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ExecutionStatus::Pending => "PENDING",
+            ExecutionStatus::PendingConcurrency => "PENDING_CONCURRENCY",
+            ExecutionStatus::PendingDevice => "PENDING_DEVICE",
+            ExecutionStatus::Processing => "PROCESSING",
+            ExecutionStatus::Scheduling => "SCHEDULING",
+            ExecutionStatus::Preparing => "PREPARING",
+            ExecutionStatus::Running => "RUNNING",
+            ExecutionStatus::Completed => "COMPLETED",
+            ExecutionStatus::Stopping => "STOPPING",
+            ExecutionStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ExecutionStatus {
+    fn default() -> ExecutionStatus {
+        ExecutionStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ExecutionStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ExecutionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ExecutionStatus {
+    fn from(value: &'a str) -> ExecutionStatus {
+        match value {
+            "PENDING" => ExecutionStatus::Pending,
+            "PENDING_CONCURRENCY" => ExecutionStatus::PendingConcurrency,
+            "PENDING_DEVICE" => ExecutionStatus::PendingDevice,
+            "PROCESSING" => ExecutionStatus::Processing,
+            "SCHEDULING" => ExecutionStatus::Scheduling,
+            "PREPARING" => ExecutionStatus::Preparing,
+            "RUNNING" => ExecutionStatus::Running,
+            "COMPLETED" => ExecutionStatus::Completed,
+            "STOPPING" => ExecutionStatus::Stopping,
+            _ => ExecutionStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ExecutionStatus {
+    fn from(value: String) -> ExecutionStatus {
+        match value.as_str() {
+            "PENDING" => ExecutionStatus::Pending,
+            "PENDING_CONCURRENCY" => ExecutionStatus::PendingConcurrency,
+            "PENDING_DEVICE" => ExecutionStatus::PendingDevice,
+            "PROCESSING" => ExecutionStatus::Processing,
+            "SCHEDULING" => ExecutionStatus::Scheduling,
+            "PREPARING" => ExecutionStatus::Preparing,
+            "RUNNING" => ExecutionStatus::Running,
+            "COMPLETED" => ExecutionStatus::Completed,
+            "STOPPING" => ExecutionStatus::Stopping,
+            _ => ExecutionStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ExecutionStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ExecutionStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ExecutionStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ExecutionStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ExecutionStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ExecutionStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents the request sent to retrieve the account settings.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -914,7 +2433,7 @@ pub struct GetDevicePoolCompatibilityRequest {
     /// <p><p>The test type for the specified device pool.</p> <p>Allowed values include the following:</p> <ul> <li> <p>BUILTIN<em>FUZZ.</p> </li> <li> <p>BUILTIN</em>EXPLORER. For Android, an app explorer that traverses an Android app, interacting with it and capturing screenshots at the same time.</p> </li> <li> <p>APPIUM<em>JAVA</em>JUNIT.</p> </li> <li> <p>APPIUM<em>JAVA</em>TESTNG.</p> </li> <li> <p>APPIUM<em>PYTHON.</p> </li> <li> <p>APPIUM</em>NODE.</p> </li> <li> <p>APPIUM<em>RUBY.</p> </li> <li> <p>APPIUM</em>WEB<em>JAVA</em>JUNIT.</p> </li> <li> <p>APPIUM<em>WEB</em>JAVA<em>TESTNG.</p> </li> <li> <p>APPIUM</em>WEB<em>PYTHON.</p> </li> <li> <p>APPIUM</em>WEB<em>NODE.</p> </li> <li> <p>APPIUM</em>WEB<em>RUBY.</p> </li> <li> <p>CALABASH.</p> </li> <li> <p>INSTRUMENTATION.</p> </li> <li> <p>UIAUTOMATION.</p> </li> <li> <p>UIAUTOMATOR.</p> </li> <li> <p>XCTEST.</p> </li> <li> <p>XCTEST</em>UI.</p> </li> </ul></p>
     #[serde(rename = "testType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub test_type: Option<String>,
+    pub test_type: Option<TestType>,
 }
 
 /// <p>Represents the result of describe device pool compatibility request.</p>
@@ -1235,7 +2754,7 @@ pub struct IncompatibilityMessage {
     /// <p><p>The type of incompatibility.</p> <p>Allowed values include:</p> <ul> <li> <p>ARN</p> </li> <li> <p>FORM<em>FACTOR (for example, phone or tablet)</p> </li> <li> <p>MANUFACTURER</p> </li> <li> <p>PLATFORM (for example, Android or iOS)</p> </li> <li> <p>REMOTE</em>ACCESS<em>ENABLED</p> </li> <li> <p>APPIUM</em>VERSION</p> </li> </ul></p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<DeviceAttribute>,
 }
 
 /// <p>Represents the request to install an Android application (in .apk format) or an iOS application (in .ipa format) as part of a remote access session.</p>
@@ -1290,6 +2809,207 @@ pub struct InstanceProfile {
     pub reboot_after_use: Option<bool>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum InstanceStatus {
+    /// `IN_USE`
+    InUse,
+    /// `PREPARING`
+    Preparing,
+    /// `AVAILABLE`
+    Available,
+    /// `NOT_AVAILABLE`
+    NotAvailable,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl InstanceStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InstanceStatus::InUse => "IN_USE",
+            InstanceStatus::Preparing => "PREPARING",
+            InstanceStatus::Available => "AVAILABLE",
+            InstanceStatus::NotAvailable => "NOT_AVAILABLE",
+            InstanceStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for InstanceStatus {
+    fn default() -> InstanceStatus {
+        InstanceStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for InstanceStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for InstanceStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for InstanceStatus {
+    fn from(value: &'a str) -> InstanceStatus {
+        match value {
+            "IN_USE" => InstanceStatus::InUse,
+            "PREPARING" => InstanceStatus::Preparing,
+            "AVAILABLE" => InstanceStatus::Available,
+            "NOT_AVAILABLE" => InstanceStatus::NotAvailable,
+            _ => InstanceStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InstanceStatus {
+    fn from(value: String) -> InstanceStatus {
+        match value.as_str() {
+            "IN_USE" => InstanceStatus::InUse,
+            "PREPARING" => InstanceStatus::Preparing,
+            "AVAILABLE" => InstanceStatus::Available,
+            "NOT_AVAILABLE" => InstanceStatus::NotAvailable,
+            _ => InstanceStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for InstanceStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<InstanceStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for InstanceStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for InstanceStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InstanceStatus {
+    fn deserialize<D>(deserializer: D) -> Result<InstanceStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum InteractionMode {
+    /// `INTERACTIVE`
+    Interactive,
+    /// `NO_VIDEO`
+    NoVideo,
+    /// `VIDEO_ONLY`
+    VideoOnly,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl InteractionMode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InteractionMode::Interactive => "INTERACTIVE",
+            InteractionMode::NoVideo => "NO_VIDEO",
+            InteractionMode::VideoOnly => "VIDEO_ONLY",
+            InteractionMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for InteractionMode {
+    fn default() -> InteractionMode {
+        InteractionMode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for InteractionMode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for InteractionMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for InteractionMode {
+    fn from(value: &'a str) -> InteractionMode {
+        match value {
+            "INTERACTIVE" => InteractionMode::Interactive,
+            "NO_VIDEO" => InteractionMode::NoVideo,
+            "VIDEO_ONLY" => InteractionMode::VideoOnly,
+            _ => InteractionMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InteractionMode {
+    fn from(value: String) -> InteractionMode {
+        match value.as_str() {
+            "INTERACTIVE" => InteractionMode::Interactive,
+            "NO_VIDEO" => InteractionMode::NoVideo,
+            "VIDEO_ONLY" => InteractionMode::VideoOnly,
+            _ => InteractionMode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for InteractionMode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<InteractionMode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for InteractionMode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for InteractionMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InteractionMode {
+    fn deserialize<D>(deserializer: D) -> Result<InteractionMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents a device.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1304,8 +3024,13 @@ pub struct Job {
     pub counters: Option<Counters>,
     /// <p>When the job was created.</p>
     #[serde(rename = "created")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created: Option<f64>,
+    pub created: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The device (phone or tablet).</p>
     #[serde(rename = "device")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1329,23 +3054,33 @@ pub struct Job {
     /// <p><p>The job&#39;s result.</p> <p>Allowed values include:</p> <ul> <li> <p>PENDING</p> </li> <li> <p>PASSED</p> </li> <li> <p>WARNED</p> </li> <li> <p>FAILED</p> </li> <li> <p>SKIPPED</p> </li> <li> <p>ERRORED</p> </li> <li> <p>STOPPED</p> </li> </ul></p>
     #[serde(rename = "result")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub result: Option<String>,
+    pub result: Option<ExecutionResult>,
     /// <p>The job's start time.</p>
     #[serde(rename = "started")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub started: Option<f64>,
+    pub started: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>The job&#39;s status.</p> <p>Allowed values include:</p> <ul> <li> <p>PENDING</p> </li> <li> <p>PENDING<em>CONCURRENCY</p> </li> <li> <p>PENDING</em>DEVICE</p> </li> <li> <p>PROCESSING</p> </li> <li> <p>SCHEDULING</p> </li> <li> <p>PREPARING</p> </li> <li> <p>RUNNING</p> </li> <li> <p>COMPLETED</p> </li> <li> <p>STOPPING</p> </li> </ul></p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ExecutionStatus>,
     /// <p>The job's stop time.</p>
     #[serde(rename = "stopped")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stopped: Option<f64>,
+    pub stopped: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>The job&#39;s type.</p> <p>Allowed values include the following:</p> <ul> <li> <p>BUILTIN<em>FUZZ</p> </li> <li> <p>BUILTIN</em>EXPLORER. For Android, an app explorer that traverses an Android app, interacting with it and capturing screenshots at the same time.</p> </li> <li> <p>APPIUM<em>JAVA</em>JUNIT</p> </li> <li> <p>APPIUM<em>JAVA</em>TESTNG</p> </li> <li> <p>APPIUM<em>PYTHON</p> </li> <li> <p>APPIUM</em>NODE</p> </li> <li> <p>APPIUM<em>RUBY</p> </li> <li> <p>APPIUM</em>WEB<em>JAVA</em>JUNIT</p> </li> <li> <p>APPIUM<em>WEB</em>JAVA<em>TESTNG</p> </li> <li> <p>APPIUM</em>WEB<em>PYTHON</p> </li> <li> <p>APPIUM</em>WEB<em>NODE</p> </li> <li> <p>APPIUM</em>WEB<em>RUBY</p> </li> <li> <p>CALABASH</p> </li> <li> <p>INSTRUMENTATION</p> </li> <li> <p>UIAUTOMATION</p> </li> <li> <p>UIAUTOMATOR</p> </li> <li> <p>XCTEST</p> </li> <li> <p>XCTEST</em>UI</p> </li> </ul></p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<TestType>,
     /// <p>This value is set to true if video capture is enabled. Otherwise, it is set to false.</p>
     #[serde(rename = "videoCapture")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1369,7 +3104,7 @@ pub struct ListArtifactsRequest {
     pub next_token: Option<String>,
     /// <p><p>The artifacts&#39; type.</p> <p>Allowed values include:</p> <ul> <li> <p>FILE</p> </li> <li> <p>LOG</p> </li> <li> <p>SCREENSHOT</p> </li> </ul></p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: ArtifactCategory,
 }
 
 /// <p>Represents the result of a list artifacts operation.</p>
@@ -1426,7 +3161,7 @@ pub struct ListDevicePoolsRequest {
     /// <p><p>The device pools&#39; type.</p> <p>Allowed values include:</p> <ul> <li> <p>CURATED: A device pool that is created and managed by AWS Device Farm.</p> </li> <li> <p>PRIVATE: A device pool that is created and managed by the device pool developer.</p> </li> </ul></p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<DevicePoolType>,
 }
 
 /// <p>Represents the result of a list device pools request.</p>
@@ -1541,7 +3276,7 @@ pub struct ListNetworkProfilesRequest {
     /// <p>The type of network profile to return information about. Valid values are listed here.</p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<NetworkProfileType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1852,7 +3587,7 @@ pub struct ListTestGridSessionArtifactsRequest {
     /// <p>Limit results to a specified type of artifact.</p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<TestGridSessionArtifactCategory>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1873,20 +3608,40 @@ pub struct ListTestGridSessionArtifactsResult {
 pub struct ListTestGridSessionsRequest {
     /// <p>Return only sessions created after this time.</p>
     #[serde(rename = "creationTimeAfter")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time_after: Option<f64>,
+    pub creation_time_after: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Return only sessions created before this time.</p>
     #[serde(rename = "creationTimeBefore")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time_before: Option<f64>,
+    pub creation_time_before: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Return only sessions that ended after this time.</p>
     #[serde(rename = "endTimeAfter")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time_after: Option<f64>,
+    pub end_time_after: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Return only sessions that ended before this time.</p>
     #[serde(rename = "endTimeBefore")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time_before: Option<f64>,
+    pub end_time_before: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Return only this many results at a time.</p>
     #[serde(rename = "maxResult")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1901,7 +3656,7 @@ pub struct ListTestGridSessionsRequest {
     /// <p>Return only sessions in this state.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<TestGridSessionStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1968,7 +3723,7 @@ pub struct ListUniqueProblemsResult {
     /// <p><p>Information about the unique problems.</p> <p>Allowed values include:</p> <ul> <li> <p>PENDING</p> </li> <li> <p>PASSED</p> </li> <li> <p>WARNED</p> </li> <li> <p>FAILED</p> </li> <li> <p>SKIPPED</p> </li> <li> <p>ERRORED</p> </li> <li> <p>STOPPED</p> </li> </ul></p>
     #[serde(rename = "uniqueProblems")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub unique_problems: Option<::std::collections::HashMap<String, Vec<UniqueProblem>>>,
+    pub unique_problems: Option<::std::collections::HashMap<ExecutionResult, Vec<UniqueProblem>>>,
 }
 
 /// <p>Represents a request to the list uploads operation.</p>
@@ -1985,7 +3740,7 @@ pub struct ListUploadsRequest {
     /// <p><p>The type of upload.</p> <p>Must be one of the following values:</p> <ul> <li> <p>ANDROID<em>APP</p> </li> <li> <p>IOS</em>APP</p> </li> <li> <p>WEB<em>APP</p> </li> <li> <p>EXTERNAL</em>DATA</p> </li> <li> <p>APPIUM<em>JAVA</em>JUNIT<em>TEST</em>PACKAGE</p> </li> <li> <p>APPIUM<em>JAVA</em>TESTNG<em>TEST</em>PACKAGE</p> </li> <li> <p>APPIUM<em>PYTHON</em>TEST<em>PACKAGE</p> </li> <li> <p>APPIUM</em>NODE<em>TEST</em>PACKAGE</p> </li> <li> <p>APPIUM<em>RUBY</em>TEST<em>PACKAGE</p> </li> <li> <p>APPIUM</em>WEB<em>JAVA</em>JUNIT<em>TEST</em>PACKAGE</p> </li> <li> <p>APPIUM<em>WEB</em>JAVA<em>TESTNG</em>TEST<em>PACKAGE</p> </li> <li> <p>APPIUM</em>WEB<em>PYTHON</em>TEST<em>PACKAGE</p> </li> <li> <p>APPIUM</em>WEB<em>NODE</em>TEST<em>PACKAGE</p> </li> <li> <p>APPIUM</em>WEB<em>RUBY</em>TEST<em>PACKAGE</p> </li> <li> <p>CALABASH</em>TEST<em>PACKAGE</p> </li> <li> <p>INSTRUMENTATION</em>TEST<em>PACKAGE</p> </li> <li> <p>UIAUTOMATION</em>TEST<em>PACKAGE</p> </li> <li> <p>UIAUTOMATOR</em>TEST<em>PACKAGE</p> </li> <li> <p>XCTEST</em>TEST<em>PACKAGE</p> </li> <li> <p>XCTEST</em>UI<em>TEST</em>PACKAGE</p> </li> <li> <p>APPIUM<em>JAVA</em>JUNIT<em>TEST</em>SPEC</p> </li> <li> <p>APPIUM<em>JAVA</em>TESTNG<em>TEST</em>SPEC</p> </li> <li> <p>APPIUM<em>PYTHON</em>TEST<em>SPEC</p> </li> <li> <p>APPIUM</em>NODE<em>TEST</em>SPEC</p> </li> <li> <p> APPIUM<em>RUBY</em>TEST<em>SPEC</p> </li> <li> <p>APPIUM</em>WEB<em>JAVA</em>JUNIT<em>TEST</em>SPEC</p> </li> <li> <p>APPIUM<em>WEB</em>JAVA<em>TESTNG</em>TEST<em>SPEC</p> </li> <li> <p>APPIUM</em>WEB<em>PYTHON</em>TEST<em>SPEC</p> </li> <li> <p>APPIUM</em>WEB<em>NODE</em>TEST<em>SPEC</p> </li> <li> <p>APPIUM</em>WEB<em>RUBY</em>TEST<em>SPEC</p> </li> <li> <p>INSTRUMENTATION</em>TEST<em>SPEC</p> </li> <li> <p>XCTEST</em>UI<em>TEST</em>SPEC</p> </li> </ul></p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<UploadType>,
 }
 
 /// <p>Represents the result of a list uploads request.</p>
@@ -2050,7 +3805,7 @@ pub struct MonetaryAmount {
     /// <p>The currency code of a monetary amount. For example, <code>USD</code> means U.S. dollars.</p>
     #[serde(rename = "currencyCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub currency_code: Option<String>,
+    pub currency_code: Option<CurrencyCode>,
 }
 
 /// <p>An array of settings that describes characteristics of a network profile.</p>
@@ -2088,7 +3843,7 @@ pub struct NetworkProfile {
     /// <p>The type of network profile. Valid values are listed here.</p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<NetworkProfileType>,
     /// <p>The data throughput rate in bits per second, as an integer from 0 to 104857600.</p>
     #[serde(rename = "uplinkBandwidthBits")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2107,6 +3862,99 @@ pub struct NetworkProfile {
     pub uplink_loss_percent: Option<i64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum NetworkProfileType {
+    /// `CURATED`
+    Curated,
+    /// `PRIVATE`
+    Private,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl NetworkProfileType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            NetworkProfileType::Curated => "CURATED",
+            NetworkProfileType::Private => "PRIVATE",
+            NetworkProfileType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for NetworkProfileType {
+    fn default() -> NetworkProfileType {
+        NetworkProfileType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for NetworkProfileType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for NetworkProfileType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for NetworkProfileType {
+    fn from(value: &'a str) -> NetworkProfileType {
+        match value {
+            "CURATED" => NetworkProfileType::Curated,
+            "PRIVATE" => NetworkProfileType::Private,
+            _ => NetworkProfileType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for NetworkProfileType {
+    fn from(value: String) -> NetworkProfileType {
+        match value.as_str() {
+            "CURATED" => NetworkProfileType::Curated,
+            "PRIVATE" => NetworkProfileType::Private,
+            _ => NetworkProfileType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for NetworkProfileType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<NetworkProfileType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for NetworkProfileType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for NetworkProfileType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for NetworkProfileType {
+    fn deserialize<D>(deserializer: D) -> Result<NetworkProfileType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents the metadata of a device offering.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -2122,7 +3970,7 @@ pub struct Offering {
     /// <p>The platform of the device (for example, <code>ANDROID</code> or <code>IOS</code>).</p>
     #[serde(rename = "platform")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub platform: Option<String>,
+    pub platform: Option<DevicePlatform>,
     /// <p>Specifies whether there are recurring charges for the offering.</p>
     #[serde(rename = "recurringCharges")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2130,7 +3978,7 @@ pub struct Offering {
     /// <p>The type of offering (for example, <code>RECURRING</code>) for a device.</p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<OfferingType>,
 }
 
 /// <p>Represents information about an offering promotion.</p>
@@ -2153,8 +4001,13 @@ pub struct OfferingPromotion {
 pub struct OfferingStatus {
     /// <p>The date on which the offering is effective.</p>
     #[serde(rename = "effectiveOn")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub effective_on: Option<f64>,
+    pub effective_on: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Represents the metadata of an offering status.</p>
     #[serde(rename = "offering")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2166,7 +4019,7 @@ pub struct OfferingStatus {
     /// <p>The type specified for the offering status.</p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<OfferingTransactionType>,
 }
 
 /// <p>Represents the metadata of an offering transaction.</p>
@@ -2179,8 +4032,13 @@ pub struct OfferingTransaction {
     pub cost: Option<MonetaryAmount>,
     /// <p>The date on which an offering transaction was created.</p>
     #[serde(rename = "createdOn")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_on: Option<f64>,
+    pub created_on: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The ID that corresponds to a device offering promotion.</p>
     #[serde(rename = "offeringPromotionId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2195,6 +4053,192 @@ pub struct OfferingTransaction {
     pub transaction_id: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OfferingTransactionType {
+    /// `PURCHASE`
+    Purchase,
+    /// `RENEW`
+    Renew,
+    /// `SYSTEM`
+    System,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl OfferingTransactionType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OfferingTransactionType::Purchase => "PURCHASE",
+            OfferingTransactionType::Renew => "RENEW",
+            OfferingTransactionType::System => "SYSTEM",
+            OfferingTransactionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for OfferingTransactionType {
+    fn default() -> OfferingTransactionType {
+        OfferingTransactionType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for OfferingTransactionType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for OfferingTransactionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for OfferingTransactionType {
+    fn from(value: &'a str) -> OfferingTransactionType {
+        match value {
+            "PURCHASE" => OfferingTransactionType::Purchase,
+            "RENEW" => OfferingTransactionType::Renew,
+            "SYSTEM" => OfferingTransactionType::System,
+            _ => OfferingTransactionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OfferingTransactionType {
+    fn from(value: String) -> OfferingTransactionType {
+        match value.as_str() {
+            "PURCHASE" => OfferingTransactionType::Purchase,
+            "RENEW" => OfferingTransactionType::Renew,
+            "SYSTEM" => OfferingTransactionType::System,
+            _ => OfferingTransactionType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for OfferingTransactionType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<OfferingTransactionType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OfferingTransactionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for OfferingTransactionType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OfferingTransactionType {
+    fn deserialize<D>(deserializer: D) -> Result<OfferingTransactionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OfferingType {
+    /// `RECURRING`
+    Recurring,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl OfferingType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OfferingType::Recurring => "RECURRING",
+            OfferingType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for OfferingType {
+    fn default() -> OfferingType {
+        OfferingType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for OfferingType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for OfferingType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for OfferingType {
+    fn from(value: &'a str) -> OfferingType {
+        match value {
+            "RECURRING" => OfferingType::Recurring,
+            _ => OfferingType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OfferingType {
+    fn from(value: String) -> OfferingType {
+        match value.as_str() {
+            "RECURRING" => OfferingType::Recurring,
+            _ => OfferingType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for OfferingType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<OfferingType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OfferingType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for OfferingType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OfferingType {
+    fn deserialize<D>(deserializer: D) -> Result<OfferingType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents a specific warning or failure.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -2214,7 +4258,7 @@ pub struct Problem {
     /// <p><p>The problem&#39;s result.</p> <p>Allowed values include:</p> <ul> <li> <p>PENDING</p> </li> <li> <p>PASSED</p> </li> <li> <p>WARNED</p> </li> <li> <p>FAILED</p> </li> <li> <p>SKIPPED</p> </li> <li> <p>ERRORED</p> </li> <li> <p>STOPPED</p> </li> </ul></p>
     #[serde(rename = "result")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub result: Option<String>,
+    pub result: Option<ExecutionResult>,
     /// <p>Information about the associated run.</p>
     #[serde(rename = "run")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2253,8 +4297,13 @@ pub struct Project {
     pub arn: Option<String>,
     /// <p>When the project was created.</p>
     #[serde(rename = "created")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created: Option<f64>,
+    pub created: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The default number of minutes (at the project level) a test run executes before it times out. The default value is 150 minutes.</p>
     #[serde(rename = "defaultJobTimeoutMinutes")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2314,20 +4363,108 @@ pub struct Radios {
     pub wifi: Option<bool>,
 }
 
-/// <p>Specifies whether charges for devices are recurring.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct RecurringCharge {
-    /// <p>The cost of the recurring charge.</p>
-    #[serde(rename = "cost")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub cost: Option<MonetaryAmount>,
-    /// <p>The frequency in which charges recur.</p>
-    #[serde(rename = "frequency")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub frequency: Option<String>,
+/// <p>Specifies whether charges for devices are recurring.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct RecurringCharge {
+    /// <p>The cost of the recurring charge.</p>
+    #[serde(rename = "cost")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cost: Option<MonetaryAmount>,
+    /// <p>The frequency in which charges recur.</p>
+    #[serde(rename = "frequency")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub frequency: Option<RecurringChargeFrequency>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RecurringChargeFrequency {
+    /// `MONTHLY`
+    Monthly,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RecurringChargeFrequency {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RecurringChargeFrequency::Monthly => "MONTHLY",
+            RecurringChargeFrequency::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RecurringChargeFrequency {
+    fn default() -> RecurringChargeFrequency {
+        RecurringChargeFrequency::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RecurringChargeFrequency {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RecurringChargeFrequency {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RecurringChargeFrequency {
+    fn from(value: &'a str) -> RecurringChargeFrequency {
+        match value {
+            "MONTHLY" => RecurringChargeFrequency::Monthly,
+            _ => RecurringChargeFrequency::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RecurringChargeFrequency {
+    fn from(value: String) -> RecurringChargeFrequency {
+        match value.as_str() {
+            "MONTHLY" => RecurringChargeFrequency::Monthly,
+            _ => RecurringChargeFrequency::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RecurringChargeFrequency {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RecurringChargeFrequency, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RecurringChargeFrequency {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RecurringChargeFrequency {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RecurringChargeFrequency {
+    fn deserialize<D>(deserializer: D) -> Result<RecurringChargeFrequency, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
 }
-
 /// <p>Represents information about the remote access session.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -2339,15 +4476,20 @@ pub struct RemoteAccessSession {
     /// <p>The billing method of the remote access session. Possible values include <code>METERED</code> or <code>UNMETERED</code>. For more information about metered devices, see <a href="https://docs.aws.amazon.com/devicefarm/latest/developerguide/welcome.html#welcome-terminology">AWS Device Farm terminology</a>.</p>
     #[serde(rename = "billingMethod")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub billing_method: Option<String>,
+    pub billing_method: Option<BillingMethod>,
     /// <p>Unique identifier of your client for the remote access session. Only returned if remote debugging is enabled for the remote access session.</p> <p>Remote debugging is <a href="https://docs.aws.amazon.com/devicefarm/latest/developerguide/history.html">no longer supported</a>.</p>
     #[serde(rename = "clientId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_id: Option<String>,
     /// <p>The date and time the remote access session was created.</p>
     #[serde(rename = "created")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created: Option<f64>,
+    pub created: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The device (phone or tablet) used in the remote access session.</p>
     #[serde(rename = "device")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2375,7 +4517,7 @@ pub struct RemoteAccessSession {
     /// <p><p>The interaction mode of the remote access session. Valid values are:</p> <ul> <li> <p>INTERACTIVE: You can interact with the iOS device by viewing, touching, and rotating the screen. You cannot run XCUITest framework-based tests in this mode.</p> </li> <li> <p>NO<em>VIDEO: You are connected to the device, but cannot interact with it or view the screen. This mode has the fastest test execution speed. You can run XCUITest framework-based tests in this mode.</p> </li> <li> <p>VIDEO</em>ONLY: You can view the screen, but cannot touch or rotate it. You can run XCUITest framework-based tests and watch the screen in this mode.</p> </li> </ul></p>
     #[serde(rename = "interactionMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub interaction_mode: Option<String>,
+    pub interaction_mode: Option<InteractionMode>,
     /// <p>A message about the remote access session.</p>
     #[serde(rename = "message")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2399,23 +4541,33 @@ pub struct RemoteAccessSession {
     /// <p><p>The result of the remote access session. Can be any of the following:</p> <ul> <li> <p>PENDING.</p> </li> <li> <p>PASSED.</p> </li> <li> <p>WARNED.</p> </li> <li> <p>FAILED.</p> </li> <li> <p>SKIPPED.</p> </li> <li> <p>ERRORED.</p> </li> <li> <p>STOPPED.</p> </li> </ul></p>
     #[serde(rename = "result")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub result: Option<String>,
+    pub result: Option<ExecutionResult>,
     /// <p>When set to <code>true</code>, for private devices, Device Farm does not sign your app again. For public devices, Device Farm always signs your apps again.</p> <p>For more information about how Device Farm re-signs your apps, see <a href="https://aws.amazon.com/device-farm/faq/">Do you modify my app?</a> in the <i>AWS Device Farm FAQs</i>.</p>
     #[serde(rename = "skipAppResign")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub skip_app_resign: Option<bool>,
     /// <p>The date and time the remote access session was started.</p>
     #[serde(rename = "started")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub started: Option<f64>,
+    pub started: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>The status of the remote access session. Can be any of the following:</p> <ul> <li> <p>PENDING.</p> </li> <li> <p>PENDING<em>CONCURRENCY.</p> </li> <li> <p>PENDING</em>DEVICE.</p> </li> <li> <p>PROCESSING.</p> </li> <li> <p>SCHEDULING.</p> </li> <li> <p>PREPARING.</p> </li> <li> <p>RUNNING.</p> </li> <li> <p>COMPLETED.</p> </li> <li> <p>STOPPING.</p> </li> </ul></p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ExecutionStatus>,
     /// <p>The date and time the remote access session was stopped.</p>
     #[serde(rename = "stopped")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stopped: Option<f64>,
+    pub stopped: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>A request that represents an offering renewal.</p>
@@ -2462,17 +4614,140 @@ pub struct Rule {
     /// <p><p>The rule&#39;s stringified attribute. For example, specify the value as <code>&quot;&quot;abc&quot;&quot;</code>.</p> <p>The supported operators for each attribute are provided in the following list.</p> <dl> <dt>APPIUM<em>VERSION</dt> <dd> <p>The Appium version for the test.</p> <p>Supported operators: <code>CONTAINS</code> </p> </dd> <dt>ARN</dt> <dd> <p>The Amazon Resource Name (ARN) of the device (for example, <code>arn:aws:devicefarm:us-west-2::device:12345Example</code>.</p> <p>Supported operators: <code>EQUALS</code>, <code>IN</code>, <code>NOT</em>IN</code> </p> </dd> <dt>AVAILABILITY</dt> <dd> <p>The current availability of the device. Valid values are AVAILABLE, HIGHLY<em>AVAILABLE, BUSY, or TEMPORARY</em>NOT<em>AVAILABLE.</p> <p>Supported operators: <code>EQUALS</code> </p> </dd> <dt>FLEET</em>TYPE</dt> <dd> <p>The fleet type. Valid values are PUBLIC or PRIVATE.</p> <p>Supported operators: <code>EQUALS</code> </p> </dd> <dt>FORM<em>FACTOR</dt> <dd> <p>The device form factor. Valid values are PHONE or TABLET.</p> <p>Supported operators: <code>EQUALS</code>, <code>IN</code>, <code>NOT</em>IN</code> </p> </dd> <dt>INSTANCE<em>ARN</dt> <dd> <p>The Amazon Resource Name (ARN) of the device instance.</p> <p>Supported operators: <code>IN</code>, <code>NOT</em>IN</code> </p> </dd> <dt>INSTANCE<em>LABELS</dt> <dd> <p>The label of the device instance.</p> <p>Supported operators: <code>CONTAINS</code> </p> </dd> <dt>MANUFACTURER</dt> <dd> <p>The device manufacturer (for example, Apple).</p> <p>Supported operators: <code>EQUALS</code>, <code>IN</code>, <code>NOT</em>IN</code> </p> </dd> <dt>MODEL</dt> <dd> <p>The device model, such as Apple iPad Air 2 or Google Pixel.</p> <p>Supported operators: <code>CONTAINS</code>, <code>EQUALS</code>, <code>IN</code>, <code>NOT<em>IN</code> </p> </dd> <dt>OS</em>VERSION</dt> <dd> <p>The operating system version (for example, 10.3.2).</p> <p>Supported operators: <code>EQUALS</code>, <code>GREATER<em>THAN</code>, <code>GREATER</em>THAN<em>OR</em>EQUALS</code>, <code>IN</code>, <code>LESS<em>THAN</code>, <code>LESS</em>THAN<em>OR</em>EQUALS</code>, <code>NOT<em>IN</code> </p> </dd> <dt>PLATFORM</dt> <dd> <p>The device platform. Valid values are ANDROID or IOS.</p> <p>Supported operators: <code>EQUALS</code>, <code>IN</code>, <code>NOT</em>IN</code> </p> </dd> <dt>REMOTE<em>ACCESS</em>ENABLED</dt> <dd> <p>Whether the device is enabled for remote access. Valid values are TRUE or FALSE.</p> <p>Supported operators: <code>EQUALS</code> </p> </dd> <dt>REMOTE<em>DEBUG</em>ENABLED</dt> <dd> <p>Whether the device is enabled for remote debugging. Valid values are TRUE or FALSE.</p> <p>Supported operators: <code>EQUALS</code> </p> <p>Because remote debugging is <a href="https://docs.aws.amazon.com/devicefarm/latest/developerguide/history.html">no longer supported</a>, this filter is ignored.</p> </dd> </dl></p>
     #[serde(rename = "attribute")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub attribute: Option<String>,
+    pub attribute: Option<DeviceAttribute>,
     /// <p>Specifies how Device Farm compares the rule's attribute to the value. For the operators that are supported by each attribute, see the attribute descriptions.</p>
     #[serde(rename = "operator")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub operator: Option<String>,
+    pub operator: Option<RuleOperator>,
     /// <p>The rule's value.</p>
     #[serde(rename = "value")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RuleOperator {
+    /// `EQUALS`
+    Equals,
+    /// `LESS_THAN`
+    LessThan,
+    /// `LESS_THAN_OR_EQUALS`
+    LessThanOrEquals,
+    /// `GREATER_THAN`
+    GreaterThan,
+    /// `GREATER_THAN_OR_EQUALS`
+    GreaterThanOrEquals,
+    /// `IN`
+    In,
+    /// `NOT_IN`
+    NotIn,
+    /// `CONTAINS`
+    Contains,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RuleOperator {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RuleOperator::Equals => "EQUALS",
+            RuleOperator::LessThan => "LESS_THAN",
+            RuleOperator::LessThanOrEquals => "LESS_THAN_OR_EQUALS",
+            RuleOperator::GreaterThan => "GREATER_THAN",
+            RuleOperator::GreaterThanOrEquals => "GREATER_THAN_OR_EQUALS",
+            RuleOperator::In => "IN",
+            RuleOperator::NotIn => "NOT_IN",
+            RuleOperator::Contains => "CONTAINS",
+            RuleOperator::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RuleOperator {
+    fn default() -> RuleOperator {
+        RuleOperator::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RuleOperator {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RuleOperator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RuleOperator {
+    fn from(value: &'a str) -> RuleOperator {
+        match value {
+            "EQUALS" => RuleOperator::Equals,
+            "LESS_THAN" => RuleOperator::LessThan,
+            "LESS_THAN_OR_EQUALS" => RuleOperator::LessThanOrEquals,
+            "GREATER_THAN" => RuleOperator::GreaterThan,
+            "GREATER_THAN_OR_EQUALS" => RuleOperator::GreaterThanOrEquals,
+            "IN" => RuleOperator::In,
+            "NOT_IN" => RuleOperator::NotIn,
+            "CONTAINS" => RuleOperator::Contains,
+            _ => RuleOperator::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RuleOperator {
+    fn from(value: String) -> RuleOperator {
+        match value.as_str() {
+            "EQUALS" => RuleOperator::Equals,
+            "LESS_THAN" => RuleOperator::LessThan,
+            "LESS_THAN_OR_EQUALS" => RuleOperator::LessThanOrEquals,
+            "GREATER_THAN" => RuleOperator::GreaterThan,
+            "GREATER_THAN_OR_EQUALS" => RuleOperator::GreaterThanOrEquals,
+            "IN" => RuleOperator::In,
+            "NOT_IN" => RuleOperator::NotIn,
+            "CONTAINS" => RuleOperator::Contains,
+            _ => RuleOperator::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RuleOperator {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RuleOperator, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RuleOperator {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RuleOperator {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RuleOperator {
+    fn deserialize<D>(deserializer: D) -> Result<RuleOperator, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents a test run on a set of devices with a given app package, test parameters, and so on.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -2488,7 +4763,7 @@ pub struct Run {
     /// <p><p>Specifies the billing method for a test run: <code>metered</code> or <code>unmetered</code>. If the parameter is not specified, the default value is <code>metered</code>.</p> <note> <p>If you have unmetered device slots, you must set this to <code>unmetered</code> to use them. Otherwise, the run is counted toward metered device minutes.</p> </note></p>
     #[serde(rename = "billingMethod")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub billing_method: Option<String>,
+    pub billing_method: Option<BillingMethod>,
     /// <p>The total number of completed jobs.</p>
     #[serde(rename = "completedJobs")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2499,8 +4774,13 @@ pub struct Run {
     pub counters: Option<Counters>,
     /// <p>When the run was created.</p>
     #[serde(rename = "created")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created: Option<f64>,
+    pub created: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Output <code>CustomerArtifactPaths</code> object for the test run.</p>
     #[serde(rename = "customerArtifactPaths")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2552,7 +4832,7 @@ pub struct Run {
     /// <p><p>The run&#39;s platform.</p> <p>Allowed values include:</p> <ul> <li> <p>ANDROID</p> </li> <li> <p>IOS</p> </li> </ul></p>
     #[serde(rename = "platform")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub platform: Option<String>,
+    pub platform: Option<DevicePlatform>,
     /// <p>Information about the radio states for the run.</p>
     #[serde(rename = "radios")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2560,11 +4840,11 @@ pub struct Run {
     /// <p><p>The run&#39;s result.</p> <p>Allowed values include:</p> <ul> <li> <p>PENDING</p> </li> <li> <p>PASSED</p> </li> <li> <p>WARNED</p> </li> <li> <p>FAILED</p> </li> <li> <p>SKIPPED</p> </li> <li> <p>ERRORED</p> </li> <li> <p>STOPPED</p> </li> </ul></p>
     #[serde(rename = "result")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub result: Option<String>,
+    pub result: Option<ExecutionResult>,
     /// <p>Supporting field for the result field. Set only if <code>result</code> is <code>SKIPPED</code>. <code>PARSING_FAILED</code> if the result is skipped because of test package parsing failure.</p>
     #[serde(rename = "resultCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub result_code: Option<String>,
+    pub result_code: Option<ExecutionResultCode>,
     /// <p>For fuzz tests, this is a seed to use for randomizing the UI fuzz test. Using the same seed value between tests ensures identical event sequences.</p>
     #[serde(rename = "seed")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2575,16 +4855,26 @@ pub struct Run {
     pub skip_app_resign: Option<bool>,
     /// <p>The run's start time.</p>
     #[serde(rename = "started")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub started: Option<f64>,
+    pub started: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>The run&#39;s status.</p> <p>Allowed values include:</p> <ul> <li> <p>PENDING</p> </li> <li> <p>PENDING<em>CONCURRENCY</p> </li> <li> <p>PENDING</em>DEVICE</p> </li> <li> <p>PROCESSING</p> </li> <li> <p>SCHEDULING</p> </li> <li> <p>PREPARING</p> </li> <li> <p>RUNNING</p> </li> <li> <p>COMPLETED</p> </li> <li> <p>STOPPING</p> </li> </ul></p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ExecutionStatus>,
     /// <p>The run's stop time.</p>
     #[serde(rename = "stopped")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stopped: Option<f64>,
+    pub stopped: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The ARN of the YAML-formatted test specification for the run.</p>
     #[serde(rename = "testSpecArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2596,7 +4886,7 @@ pub struct Run {
     /// <p><p>The run&#39;s type.</p> <p>Must be one of the following values:</p> <ul> <li> <p>BUILTIN<em>FUZZ</p> </li> <li> <p>BUILTIN</em>EXPLORER</p> <note> <p>For Android, an app explorer that traverses an Android app, interacting with it and capturing screenshots at the same time.</p> </note> </li> <li> <p>APPIUM<em>JAVA</em>JUNIT</p> </li> <li> <p>APPIUM<em>JAVA</em>TESTNG</p> </li> <li> <p>APPIUM<em>PYTHON</p> </li> <li> <p>APPIUM</em>NODE</p> </li> <li> <p>APPIUM<em>RUBY</p> </li> <li> <p>APPIUM</em>WEB<em>JAVA</em>JUNIT</p> </li> <li> <p>APPIUM<em>WEB</em>JAVA<em>TESTNG</p> </li> <li> <p>APPIUM</em>WEB<em>PYTHON</p> </li> <li> <p>APPIUM</em>WEB<em>NODE</p> </li> <li> <p>APPIUM</em>WEB<em>RUBY</p> </li> <li> <p>CALABASH</p> </li> <li> <p>INSTRUMENTATION</p> </li> <li> <p>UIAUTOMATION</p> </li> <li> <p>UIAUTOMATOR</p> </li> <li> <p>XCTEST</p> </li> <li> <p>XCTEST</em>UI</p> </li> </ul></p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<TestType>,
     /// <p>The Device Farm console URL for the recording of the run.</p>
     #[serde(rename = "webUrl")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2614,13 +4904,181 @@ pub struct Sample {
     /// <p><p>The sample&#39;s type.</p> <p>Must be one of the following values:</p> <ul> <li> <p>CPU: A CPU sample type. This is expressed as the app processing CPU time (including child processes) as reported by process, as a percentage.</p> </li> <li> <p>MEMORY: A memory usage sample type. This is expressed as the total proportional set size of an app process, in kilobytes.</p> </li> <li> <p>NATIVE<em>AVG</em>DRAWTIME</p> </li> <li> <p>NATIVE<em>FPS</p> </li> <li> <p>NATIVE</em>FRAMES</p> </li> <li> <p>NATIVE<em>MAX</em>DRAWTIME</p> </li> <li> <p>NATIVE<em>MIN</em>DRAWTIME</p> </li> <li> <p>OPENGL<em>AVG</em>DRAWTIME</p> </li> <li> <p>OPENGL<em>FPS</p> </li> <li> <p>OPENGL</em>FRAMES</p> </li> <li> <p>OPENGL<em>MAX</em>DRAWTIME</p> </li> <li> <p>OPENGL<em>MIN</em>DRAWTIME</p> </li> <li> <p>RX</p> </li> <li> <p>RX<em>RATE: The total number of bytes per second (TCP and UDP) that are sent, by app process.</p> </li> <li> <p>THREADS: A threads sample type. This is expressed as the total number of threads per app process.</p> </li> <li> <p>TX</p> </li> <li> <p>TX</em>RATE: The total number of bytes per second (TCP and UDP) that are received, by app process.</p> </li> </ul></p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<SampleType>,
     /// <p>The presigned Amazon S3 URL that can be used with a GET request to download the sample's file.</p>
     #[serde(rename = "url")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub url: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SampleType {
+    /// `CPU`
+    Cpu,
+    /// `MEMORY`
+    Memory,
+    /// `THREADS`
+    Threads,
+    /// `RX_RATE`
+    RxRate,
+    /// `TX_RATE`
+    TxRate,
+    /// `RX`
+    Rx,
+    /// `TX`
+    Tx,
+    /// `NATIVE_FRAMES`
+    NativeFrames,
+    /// `NATIVE_FPS`
+    NativeFps,
+    /// `NATIVE_MIN_DRAWTIME`
+    NativeMinDrawtime,
+    /// `NATIVE_AVG_DRAWTIME`
+    NativeAvgDrawtime,
+    /// `NATIVE_MAX_DRAWTIME`
+    NativeMaxDrawtime,
+    /// `OPENGL_FRAMES`
+    OpenglFrames,
+    /// `OPENGL_FPS`
+    OpenglFps,
+    /// `OPENGL_MIN_DRAWTIME`
+    OpenglMinDrawtime,
+    /// `OPENGL_AVG_DRAWTIME`
+    OpenglAvgDrawtime,
+    /// `OPENGL_MAX_DRAWTIME`
+    OpenglMaxDrawtime,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SampleType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SampleType::Cpu => "CPU",
+            SampleType::Memory => "MEMORY",
+            SampleType::Threads => "THREADS",
+            SampleType::RxRate => "RX_RATE",
+            SampleType::TxRate => "TX_RATE",
+            SampleType::Rx => "RX",
+            SampleType::Tx => "TX",
+            SampleType::NativeFrames => "NATIVE_FRAMES",
+            SampleType::NativeFps => "NATIVE_FPS",
+            SampleType::NativeMinDrawtime => "NATIVE_MIN_DRAWTIME",
+            SampleType::NativeAvgDrawtime => "NATIVE_AVG_DRAWTIME",
+            SampleType::NativeMaxDrawtime => "NATIVE_MAX_DRAWTIME",
+            SampleType::OpenglFrames => "OPENGL_FRAMES",
+            SampleType::OpenglFps => "OPENGL_FPS",
+            SampleType::OpenglMinDrawtime => "OPENGL_MIN_DRAWTIME",
+            SampleType::OpenglAvgDrawtime => "OPENGL_AVG_DRAWTIME",
+            SampleType::OpenglMaxDrawtime => "OPENGL_MAX_DRAWTIME",
+            SampleType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SampleType {
+    fn default() -> SampleType {
+        SampleType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SampleType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SampleType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SampleType {
+    fn from(value: &'a str) -> SampleType {
+        match value {
+            "CPU" => SampleType::Cpu,
+            "MEMORY" => SampleType::Memory,
+            "THREADS" => SampleType::Threads,
+            "RX_RATE" => SampleType::RxRate,
+            "TX_RATE" => SampleType::TxRate,
+            "RX" => SampleType::Rx,
+            "TX" => SampleType::Tx,
+            "NATIVE_FRAMES" => SampleType::NativeFrames,
+            "NATIVE_FPS" => SampleType::NativeFps,
+            "NATIVE_MIN_DRAWTIME" => SampleType::NativeMinDrawtime,
+            "NATIVE_AVG_DRAWTIME" => SampleType::NativeAvgDrawtime,
+            "NATIVE_MAX_DRAWTIME" => SampleType::NativeMaxDrawtime,
+            "OPENGL_FRAMES" => SampleType::OpenglFrames,
+            "OPENGL_FPS" => SampleType::OpenglFps,
+            "OPENGL_MIN_DRAWTIME" => SampleType::OpenglMinDrawtime,
+            "OPENGL_AVG_DRAWTIME" => SampleType::OpenglAvgDrawtime,
+            "OPENGL_MAX_DRAWTIME" => SampleType::OpenglMaxDrawtime,
+            _ => SampleType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SampleType {
+    fn from(value: String) -> SampleType {
+        match value.as_str() {
+            "CPU" => SampleType::Cpu,
+            "MEMORY" => SampleType::Memory,
+            "THREADS" => SampleType::Threads,
+            "RX_RATE" => SampleType::RxRate,
+            "TX_RATE" => SampleType::TxRate,
+            "RX" => SampleType::Rx,
+            "TX" => SampleType::Tx,
+            "NATIVE_FRAMES" => SampleType::NativeFrames,
+            "NATIVE_FPS" => SampleType::NativeFps,
+            "NATIVE_MIN_DRAWTIME" => SampleType::NativeMinDrawtime,
+            "NATIVE_AVG_DRAWTIME" => SampleType::NativeAvgDrawtime,
+            "NATIVE_MAX_DRAWTIME" => SampleType::NativeMaxDrawtime,
+            "OPENGL_FRAMES" => SampleType::OpenglFrames,
+            "OPENGL_FPS" => SampleType::OpenglFps,
+            "OPENGL_MIN_DRAWTIME" => SampleType::OpenglMinDrawtime,
+            "OPENGL_AVG_DRAWTIME" => SampleType::OpenglAvgDrawtime,
+            "OPENGL_MAX_DRAWTIME" => SampleType::OpenglMaxDrawtime,
+            _ => SampleType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SampleType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SampleType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SampleType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SampleType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SampleType {
+    fn deserialize<D>(deserializer: D) -> Result<SampleType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents the settings for a run. Includes things like location, radio states, auxiliary apps, and network profiles.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -2632,7 +5090,7 @@ pub struct ScheduleRunConfiguration {
     /// <p><p>Specifies the billing method for a test run: <code>metered</code> or <code>unmetered</code>. If the parameter is not specified, the default value is <code>metered</code>.</p> <note> <p>If you have purchased unmetered device slots, you must set this parameter to <code>unmetered</code> to make use of them. Otherwise, your run counts against your metered time.</p> </note></p>
     #[serde(rename = "billingMethod")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub billing_method: Option<String>,
+    pub billing_method: Option<BillingMethod>,
     /// <p>Input <code>CustomerArtifactPaths</code> object for the scheduled run configuration.</p>
     #[serde(rename = "customerArtifactPaths")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2731,7 +5189,7 @@ pub struct ScheduleRunTest {
     pub test_spec_arn: Option<String>,
     /// <p><p>The test&#39;s type.</p> <p>Must be one of the following values:</p> <ul> <li> <p>BUILTIN<em>FUZZ</p> </li> <li> <p>BUILTIN</em>EXPLORER. For Android, an app explorer that traverses an Android app, interacting with it and capturing screenshots at the same time.</p> </li> <li> <p>APPIUM<em>JAVA</em>JUNIT</p> </li> <li> <p>APPIUM<em>JAVA</em>TESTNG</p> </li> <li> <p>APPIUM<em>PYTHON</p> </li> <li> <p>APPIUM</em>NODE</p> </li> <li> <p>APPIUM<em>RUBY</p> </li> <li> <p>APPIUM</em>WEB<em>JAVA</em>JUNIT</p> </li> <li> <p>APPIUM<em>WEB</em>JAVA<em>TESTNG</p> </li> <li> <p>APPIUM</em>WEB<em>PYTHON</p> </li> <li> <p>APPIUM</em>WEB<em>NODE</p> </li> <li> <p>APPIUM</em>WEB<em>RUBY</p> </li> <li> <p>CALABASH</p> </li> <li> <p>INSTRUMENTATION</p> </li> <li> <p>UIAUTOMATION</p> </li> <li> <p>UIAUTOMATOR</p> </li> <li> <p>XCTEST</p> </li> <li> <p>XCTEST</em>UI</p> </li> </ul></p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: TestType,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -2803,8 +5261,13 @@ pub struct Suite {
     pub counters: Option<Counters>,
     /// <p>When the suite was created.</p>
     #[serde(rename = "created")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created: Option<f64>,
+    pub created: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Represents the total (metered or unmetered) minutes used by the test suite.</p>
     #[serde(rename = "deviceMinutes")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2820,23 +5283,33 @@ pub struct Suite {
     /// <p><p>The suite&#39;s result.</p> <p>Allowed values include:</p> <ul> <li> <p>PENDING</p> </li> <li> <p>PASSED</p> </li> <li> <p>WARNED</p> </li> <li> <p>FAILED</p> </li> <li> <p>SKIPPED</p> </li> <li> <p>ERRORED</p> </li> <li> <p>STOPPED</p> </li> </ul></p>
     #[serde(rename = "result")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub result: Option<String>,
+    pub result: Option<ExecutionResult>,
     /// <p>The suite's start time.</p>
     #[serde(rename = "started")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub started: Option<f64>,
+    pub started: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>The suite&#39;s status.</p> <p>Allowed values include:</p> <ul> <li> <p>PENDING</p> </li> <li> <p>PENDING<em>CONCURRENCY</p> </li> <li> <p>PENDING</em>DEVICE</p> </li> <li> <p>PROCESSING</p> </li> <li> <p>SCHEDULING</p> </li> <li> <p>PREPARING</p> </li> <li> <p>RUNNING</p> </li> <li> <p>COMPLETED</p> </li> <li> <p>STOPPING</p> </li> </ul></p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ExecutionStatus>,
     /// <p>The suite's stop time.</p>
     #[serde(rename = "stopped")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stopped: Option<f64>,
+    pub stopped: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>The suite&#39;s type.</p> <p>Must be one of the following values:</p> <ul> <li> <p>BUILTIN<em>FUZZ</p> </li> <li> <p>BUILTIN</em>EXPLORER </p> <note> <p>Only available for Android; an app explorer that traverses an Android app, interacting with it and capturing screenshots at the same time.</p> </note> </li> <li> <p>APPIUM<em>JAVA</em>JUNIT</p> </li> <li> <p>APPIUM<em>JAVA</em>TESTNG</p> </li> <li> <p>APPIUM<em>PYTHON</p> </li> <li> <p>APPIUM</em>NODE</p> </li> <li> <p>APPIUM<em>RUBY</p> </li> <li> <p>APPIUM</em>WEB<em>JAVA</em>JUNIT</p> </li> <li> <p>APPIUM<em>WEB</em>JAVA<em>TESTNG</p> </li> <li> <p>APPIUM</em>WEB<em>PYTHON</p> </li> <li> <p>APPIUM</em>WEB<em>NODE</p> </li> <li> <p>APPIUM</em>WEB<em>RUBY</p> </li> <li> <p>CALABASH</p> </li> <li> <p>INSTRUMENTATION</p> </li> <li> <p>UIAUTOMATION</p> </li> <li> <p>UIAUTOMATOR</p> </li> <li> <p>XCTEST</p> </li> <li> <p>XCTEST</em>UI</p> </li> </ul></p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<TestType>,
 }
 
 /// <p>The metadata that you apply to a resource to help you categorize and organize it. Each tag consists of a key and an optional value, both of which you define. Tag keys can have a maximum character length of 128 characters. Tag values can have a maximum length of 256 characters. </p>
@@ -2879,8 +5352,13 @@ pub struct Test {
     pub counters: Option<Counters>,
     /// <p>When the test was created.</p>
     #[serde(rename = "created")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created: Option<f64>,
+    pub created: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Represents the total (metered or unmetered) minutes used by the test.</p>
     #[serde(rename = "deviceMinutes")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2896,23 +5374,33 @@ pub struct Test {
     /// <p><p>The test&#39;s result.</p> <p>Allowed values include:</p> <ul> <li> <p>PENDING</p> </li> <li> <p>PASSED</p> </li> <li> <p>WARNED</p> </li> <li> <p>FAILED</p> </li> <li> <p>SKIPPED</p> </li> <li> <p>ERRORED</p> </li> <li> <p>STOPPED</p> </li> </ul></p>
     #[serde(rename = "result")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub result: Option<String>,
+    pub result: Option<ExecutionResult>,
     /// <p>The test's start time.</p>
     #[serde(rename = "started")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub started: Option<f64>,
+    pub started: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>The test&#39;s status.</p> <p>Allowed values include:</p> <ul> <li> <p>PENDING</p> </li> <li> <p>PENDING<em>CONCURRENCY</p> </li> <li> <p>PENDING</em>DEVICE</p> </li> <li> <p>PROCESSING</p> </li> <li> <p>SCHEDULING</p> </li> <li> <p>PREPARING</p> </li> <li> <p>RUNNING</p> </li> <li> <p>COMPLETED</p> </li> <li> <p>STOPPING</p> </li> </ul></p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ExecutionStatus>,
     /// <p>The test's stop time.</p>
     #[serde(rename = "stopped")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stopped: Option<f64>,
+    pub stopped: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>The test&#39;s type.</p> <p>Must be one of the following values:</p> <ul> <li> <p>BUILTIN<em>FUZZ</p> </li> <li> <p>BUILTIN</em>EXPLORER</p> <note> <p>For Android, an app explorer that traverses an Android app, interacting with it and capturing screenshots at the same time.</p> </note> </li> <li> <p>APPIUM<em>JAVA</em>JUNIT</p> </li> <li> <p>APPIUM<em>JAVA</em>TESTNG</p> </li> <li> <p>APPIUM<em>PYTHON</p> </li> <li> <p>APPIUM</em>NODE</p> </li> <li> <p>APPIUM<em>RUBY</p> </li> <li> <p>APPIUM</em>WEB<em>JAVA</em>JUNIT</p> </li> <li> <p>APPIUM<em>WEB</em>JAVA<em>TESTNG</p> </li> <li> <p>APPIUM</em>WEB<em>PYTHON</p> </li> <li> <p>APPIUM</em>WEB<em>NODE</p> </li> <li> <p>APPIUM</em>WEB<em>RUBY</p> </li> <li> <p>CALABASH</p> </li> <li> <p>INSTRUMENTATION</p> </li> <li> <p>UIAUTOMATION</p> </li> <li> <p>UIAUTOMATOR</p> </li> <li> <p>XCTEST</p> </li> <li> <p>XCTEST</em>UI</p> </li> </ul></p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<TestType>,
 }
 
 /// <p>A Selenium testing project. Projects are used to collect and collate sessions.</p>
@@ -2925,8 +5413,13 @@ pub struct TestGridProject {
     pub arn: Option<String>,
     /// <p>When the project was created.</p>
     #[serde(rename = "created")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created: Option<f64>,
+    pub created: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A human-readable description for the project.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2951,12 +5444,22 @@ pub struct TestGridSession {
     pub billing_minutes: Option<f64>,
     /// <p>The time that the session was started.</p>
     #[serde(rename = "created")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created: Option<f64>,
+    pub created: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The time the session ended.</p>
     #[serde(rename = "ended")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ended: Option<f64>,
+    pub ended: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A JSON object of options and parameters passed to the Selenium WebDriver.</p>
     #[serde(rename = "seleniumProperties")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2964,53 +5467,535 @@ pub struct TestGridSession {
     /// <p>The state of the session.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<TestGridSessionStatus>,
+}
+
+/// <p>An action taken by a <a>TestGridSession</a> browser instance.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct TestGridSessionAction {
+    /// <p>The action taken by the session.</p>
+    #[serde(rename = "action")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    /// <p>The time, in milliseconds, that the action took to complete in the browser.</p>
+    #[serde(rename = "duration")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+    /// <p>HTTP method that the browser used to make the request.</p>
+    #[serde(rename = "requestMethod")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_method: Option<String>,
+    /// <p>The time that the session invoked the action.</p>
+    #[serde(rename = "started")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub started: Option<::chrono::DateTime<::chrono::Utc>>,
+    /// <p>HTTP status code returned to the browser when the action was taken.</p>
+    #[serde(rename = "statusCode")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_code: Option<String>,
+}
+
+/// <p><p>Artifacts are video and other files that are produced in the process of running a browser in an automated context. </p> <note> <p>Video elements might be broken up into multiple artifacts as they grow in size during creation. </p> </note></p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct TestGridSessionArtifact {
+    /// <p>The file name of the artifact.</p>
+    #[serde(rename = "filename")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filename: Option<String>,
+    /// <p>The kind of artifact.</p>
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_: Option<TestGridSessionArtifactType>,
+    /// <p>A semi-stable URL to the content of the object.</p>
+    #[serde(rename = "url")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TestGridSessionArtifactCategory {
+    /// `VIDEO`
+    Video,
+    /// `LOG`
+    Log,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TestGridSessionArtifactCategory {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TestGridSessionArtifactCategory::Video => "VIDEO",
+            TestGridSessionArtifactCategory::Log => "LOG",
+            TestGridSessionArtifactCategory::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TestGridSessionArtifactCategory {
+    fn default() -> TestGridSessionArtifactCategory {
+        TestGridSessionArtifactCategory::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TestGridSessionArtifactCategory {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TestGridSessionArtifactCategory {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TestGridSessionArtifactCategory {
+    fn from(value: &'a str) -> TestGridSessionArtifactCategory {
+        match value {
+            "VIDEO" => TestGridSessionArtifactCategory::Video,
+            "LOG" => TestGridSessionArtifactCategory::Log,
+            _ => TestGridSessionArtifactCategory::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TestGridSessionArtifactCategory {
+    fn from(value: String) -> TestGridSessionArtifactCategory {
+        match value.as_str() {
+            "VIDEO" => TestGridSessionArtifactCategory::Video,
+            "LOG" => TestGridSessionArtifactCategory::Log,
+            _ => TestGridSessionArtifactCategory::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TestGridSessionArtifactCategory {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TestGridSessionArtifactCategory, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TestGridSessionArtifactCategory {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TestGridSessionArtifactCategory {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TestGridSessionArtifactCategory {
+    fn deserialize<D>(deserializer: D) -> Result<TestGridSessionArtifactCategory, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TestGridSessionArtifactType {
+    /// `UNKNOWN`
+    UnknownValue,
+    /// `VIDEO`
+    Video,
+    /// `SELENIUM_LOG`
+    SeleniumLog,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TestGridSessionArtifactType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TestGridSessionArtifactType::UnknownValue => "UNKNOWN",
+            TestGridSessionArtifactType::Video => "VIDEO",
+            TestGridSessionArtifactType::SeleniumLog => "SELENIUM_LOG",
+            TestGridSessionArtifactType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TestGridSessionArtifactType {
+    fn default() -> TestGridSessionArtifactType {
+        TestGridSessionArtifactType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TestGridSessionArtifactType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TestGridSessionArtifactType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TestGridSessionArtifactType {
+    fn from(value: &'a str) -> TestGridSessionArtifactType {
+        match value {
+            "UNKNOWN" => TestGridSessionArtifactType::UnknownValue,
+            "VIDEO" => TestGridSessionArtifactType::Video,
+            "SELENIUM_LOG" => TestGridSessionArtifactType::SeleniumLog,
+            _ => TestGridSessionArtifactType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TestGridSessionArtifactType {
+    fn from(value: String) -> TestGridSessionArtifactType {
+        match value.as_str() {
+            "UNKNOWN" => TestGridSessionArtifactType::UnknownValue,
+            "VIDEO" => TestGridSessionArtifactType::Video,
+            "SELENIUM_LOG" => TestGridSessionArtifactType::SeleniumLog,
+            _ => TestGridSessionArtifactType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TestGridSessionArtifactType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TestGridSessionArtifactType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TestGridSessionArtifactType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TestGridSessionArtifactType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TestGridSessionArtifactType {
+    fn deserialize<D>(deserializer: D) -> Result<TestGridSessionArtifactType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TestGridSessionStatus {
+    /// `ACTIVE`
+    Active,
+    /// `CLOSED`
+    Closed,
+    /// `ERRORED`
+    Errored,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TestGridSessionStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TestGridSessionStatus::Active => "ACTIVE",
+            TestGridSessionStatus::Closed => "CLOSED",
+            TestGridSessionStatus::Errored => "ERRORED",
+            TestGridSessionStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TestGridSessionStatus {
+    fn default() -> TestGridSessionStatus {
+        TestGridSessionStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TestGridSessionStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TestGridSessionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TestGridSessionStatus {
+    fn from(value: &'a str) -> TestGridSessionStatus {
+        match value {
+            "ACTIVE" => TestGridSessionStatus::Active,
+            "CLOSED" => TestGridSessionStatus::Closed,
+            "ERRORED" => TestGridSessionStatus::Errored,
+            _ => TestGridSessionStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TestGridSessionStatus {
+    fn from(value: String) -> TestGridSessionStatus {
+        match value.as_str() {
+            "ACTIVE" => TestGridSessionStatus::Active,
+            "CLOSED" => TestGridSessionStatus::Closed,
+            "ERRORED" => TestGridSessionStatus::Errored,
+            _ => TestGridSessionStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TestGridSessionStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TestGridSessionStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TestGridSessionStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TestGridSessionStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TestGridSessionStatus {
+    fn deserialize<D>(deserializer: D) -> Result<TestGridSessionStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TestType {
+    /// `BUILTIN_FUZZ`
+    BuiltinFuzz,
+    /// `BUILTIN_EXPLORER`
+    BuiltinExplorer,
+    /// `WEB_PERFORMANCE_PROFILE`
+    WebPerformanceProfile,
+    /// `APPIUM_JAVA_JUNIT`
+    AppiumJavaJunit,
+    /// `APPIUM_JAVA_TESTNG`
+    AppiumJavaTestng,
+    /// `APPIUM_PYTHON`
+    AppiumPython,
+    /// `APPIUM_NODE`
+    AppiumNode,
+    /// `APPIUM_RUBY`
+    AppiumRuby,
+    /// `APPIUM_WEB_JAVA_JUNIT`
+    AppiumWebJavaJunit,
+    /// `APPIUM_WEB_JAVA_TESTNG`
+    AppiumWebJavaTestng,
+    /// `APPIUM_WEB_PYTHON`
+    AppiumWebPython,
+    /// `APPIUM_WEB_NODE`
+    AppiumWebNode,
+    /// `APPIUM_WEB_RUBY`
+    AppiumWebRuby,
+    /// `CALABASH`
+    Calabash,
+    /// `INSTRUMENTATION`
+    Instrumentation,
+    /// `UIAUTOMATION`
+    Uiautomation,
+    /// `UIAUTOMATOR`
+    Uiautomator,
+    /// `XCTEST`
+    Xctest,
+    /// `XCTEST_UI`
+    XctestUi,
+    /// `REMOTE_ACCESS_RECORD`
+    RemoteAccessRecord,
+    /// `REMOTE_ACCESS_REPLAY`
+    RemoteAccessReplay,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TestType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TestType::BuiltinFuzz => "BUILTIN_FUZZ",
+            TestType::BuiltinExplorer => "BUILTIN_EXPLORER",
+            TestType::WebPerformanceProfile => "WEB_PERFORMANCE_PROFILE",
+            TestType::AppiumJavaJunit => "APPIUM_JAVA_JUNIT",
+            TestType::AppiumJavaTestng => "APPIUM_JAVA_TESTNG",
+            TestType::AppiumPython => "APPIUM_PYTHON",
+            TestType::AppiumNode => "APPIUM_NODE",
+            TestType::AppiumRuby => "APPIUM_RUBY",
+            TestType::AppiumWebJavaJunit => "APPIUM_WEB_JAVA_JUNIT",
+            TestType::AppiumWebJavaTestng => "APPIUM_WEB_JAVA_TESTNG",
+            TestType::AppiumWebPython => "APPIUM_WEB_PYTHON",
+            TestType::AppiumWebNode => "APPIUM_WEB_NODE",
+            TestType::AppiumWebRuby => "APPIUM_WEB_RUBY",
+            TestType::Calabash => "CALABASH",
+            TestType::Instrumentation => "INSTRUMENTATION",
+            TestType::Uiautomation => "UIAUTOMATION",
+            TestType::Uiautomator => "UIAUTOMATOR",
+            TestType::Xctest => "XCTEST",
+            TestType::XctestUi => "XCTEST_UI",
+            TestType::RemoteAccessRecord => "REMOTE_ACCESS_RECORD",
+            TestType::RemoteAccessReplay => "REMOTE_ACCESS_REPLAY",
+            TestType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TestType {
+    fn default() -> TestType {
+        TestType::Unknown(String::new())
+    }
 }
 
-/// <p>An action taken by a <a>TestGridSession</a> browser instance.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct TestGridSessionAction {
-    /// <p>The action taken by the session.</p>
-    #[serde(rename = "action")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub action: Option<String>,
-    /// <p>The time, in milliseconds, that the action took to complete in the browser.</p>
-    #[serde(rename = "duration")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub duration: Option<i64>,
-    /// <p>HTTP method that the browser used to make the request.</p>
-    #[serde(rename = "requestMethod")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub request_method: Option<String>,
-    /// <p>The time that the session invoked the action.</p>
-    #[serde(rename = "started")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub started: Option<f64>,
-    /// <p>HTTP status code returned to the browser when the action was taken.</p>
-    #[serde(rename = "statusCode")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub status_code: Option<String>,
+impl AsRef<str> for TestType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
 }
 
-/// <p><p>Artifacts are video and other files that are produced in the process of running a browser in an automated context. </p> <note> <p>Video elements might be broken up into multiple artifacts as they grow in size during creation. </p> </note></p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct TestGridSessionArtifact {
-    /// <p>The file name of the artifact.</p>
-    #[serde(rename = "filename")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub filename: Option<String>,
-    /// <p>The kind of artifact.</p>
-    #[serde(rename = "type")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
-    /// <p>A semi-stable URL to the content of the object.</p>
-    #[serde(rename = "url")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub url: Option<String>,
+impl fmt::Display for TestType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TestType {
+    fn from(value: &'a str) -> TestType {
+        match value {
+            "BUILTIN_FUZZ" => TestType::BuiltinFuzz,
+            "BUILTIN_EXPLORER" => TestType::BuiltinExplorer,
+            "WEB_PERFORMANCE_PROFILE" => TestType::WebPerformanceProfile,
+            "APPIUM_JAVA_JUNIT" => TestType::AppiumJavaJunit,
+            "APPIUM_JAVA_TESTNG" => TestType::AppiumJavaTestng,
+            "APPIUM_PYTHON" => TestType::AppiumPython,
+            "APPIUM_NODE" => TestType::AppiumNode,
+            "APPIUM_RUBY" => TestType::AppiumRuby,
+            "APPIUM_WEB_JAVA_JUNIT" => TestType::AppiumWebJavaJunit,
+            "APPIUM_WEB_JAVA_TESTNG" => TestType::AppiumWebJavaTestng,
+            "APPIUM_WEB_PYTHON" => TestType::AppiumWebPython,
+            "APPIUM_WEB_NODE" => TestType::AppiumWebNode,
+            "APPIUM_WEB_RUBY" => TestType::AppiumWebRuby,
+            "CALABASH" => TestType::Calabash,
+            "INSTRUMENTATION" => TestType::Instrumentation,
+            "UIAUTOMATION" => TestType::Uiautomation,
+            "UIAUTOMATOR" => TestType::Uiautomator,
+            "XCTEST" => TestType::Xctest,
+            "XCTEST_UI" => TestType::XctestUi,
+            "REMOTE_ACCESS_RECORD" => TestType::RemoteAccessRecord,
+            "REMOTE_ACCESS_REPLAY" => TestType::RemoteAccessReplay,
+            _ => TestType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TestType {
+    fn from(value: String) -> TestType {
+        match value.as_str() {
+            "BUILTIN_FUZZ" => TestType::BuiltinFuzz,
+            "BUILTIN_EXPLORER" => TestType::BuiltinExplorer,
+            "WEB_PERFORMANCE_PROFILE" => TestType::WebPerformanceProfile,
+            "APPIUM_JAVA_JUNIT" => TestType::AppiumJavaJunit,
+            "APPIUM_JAVA_TESTNG" => TestType::AppiumJavaTestng,
+            "APPIUM_PYTHON" => TestType::AppiumPython,
+            "APPIUM_NODE" => TestType::AppiumNode,
+            "APPIUM_RUBY" => TestType::AppiumRuby,
+            "APPIUM_WEB_JAVA_JUNIT" => TestType::AppiumWebJavaJunit,
+            "APPIUM_WEB_JAVA_TESTNG" => TestType::AppiumWebJavaTestng,
+            "APPIUM_WEB_PYTHON" => TestType::AppiumWebPython,
+            "APPIUM_WEB_NODE" => TestType::AppiumWebNode,
+            "APPIUM_WEB_RUBY" => TestType::AppiumWebRuby,
+            "CALABASH" => TestType::Calabash,
+            "INSTRUMENTATION" => TestType::Instrumentation,
+            "UIAUTOMATION" => TestType::Uiautomation,
+            "UIAUTOMATOR" => TestType::Uiautomator,
+            "XCTEST" => TestType::Xctest,
+            "XCTEST_UI" => TestType::XctestUi,
+            "REMOTE_ACCESS_RECORD" => TestType::RemoteAccessRecord,
+            "REMOTE_ACCESS_REPLAY" => TestType::RemoteAccessReplay,
+            _ => TestType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TestType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TestType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TestType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TestType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for TestType {
+    fn deserialize<D>(deserializer: D) -> Result<TestType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents information about free trial device minutes for an AWS account.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -3188,7 +6173,7 @@ pub struct UpdateNetworkProfileRequest {
     /// <p>The type of network profile to return information about. Valid values are listed here.</p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<NetworkProfileType>,
     /// <p>The data throughput rate in bits per second, as an integer from 0 to 104857600.</p>
     #[serde(rename = "uplinkBandwidthBits")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3341,15 +6326,20 @@ pub struct Upload {
     /// <p><p>The upload&#39;s category. Allowed values include:</p> <ul> <li> <p>CURATED: An upload managed by AWS Device Farm.</p> </li> <li> <p>PRIVATE: An upload managed by the AWS Device Farm customer.</p> </li> </ul></p>
     #[serde(rename = "category")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub category: Option<String>,
+    pub category: Option<UploadCategory>,
     /// <p>The upload's content type (for example, <code>application/octet-stream</code>).</p>
     #[serde(rename = "contentType")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub content_type: Option<String>,
     /// <p>When the upload was created.</p>
     #[serde(rename = "created")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created: Option<f64>,
+    pub created: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A message about the upload's result.</p>
     #[serde(rename = "message")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3365,17 +6355,456 @@ pub struct Upload {
     /// <p><p>The upload&#39;s status.</p> <p>Must be one of the following values:</p> <ul> <li> <p>FAILED</p> </li> <li> <p>INITIALIZED</p> </li> <li> <p>PROCESSING</p> </li> <li> <p>SUCCEEDED</p> </li> </ul></p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<UploadStatus>,
     /// <p><p>The upload&#39;s type.</p> <p>Must be one of the following values:</p> <ul> <li> <p>ANDROID<em>APP</p> </li> <li> <p>IOS</em>APP</p> </li> <li> <p>WEB<em>APP</p> </li> <li> <p>EXTERNAL</em>DATA</p> </li> <li> <p>APPIUM<em>JAVA</em>JUNIT<em>TEST</em>PACKAGE</p> </li> <li> <p>APPIUM<em>JAVA</em>TESTNG<em>TEST</em>PACKAGE</p> </li> <li> <p>APPIUM<em>PYTHON</em>TEST<em>PACKAGE</p> </li> <li> <p>APPIUM</em>NODE<em>TEST</em>PACKAGE</p> </li> <li> <p>APPIUM<em>RUBY</em>TEST<em>PACKAGE</p> </li> <li> <p>APPIUM</em>WEB<em>JAVA</em>JUNIT<em>TEST</em>PACKAGE</p> </li> <li> <p>APPIUM<em>WEB</em>JAVA<em>TESTNG</em>TEST<em>PACKAGE</p> </li> <li> <p>APPIUM</em>WEB<em>PYTHON</em>TEST<em>PACKAGE</p> </li> <li> <p>APPIUM</em>WEB<em>NODE</em>TEST<em>PACKAGE</p> </li> <li> <p>APPIUM</em>WEB<em>RUBY</em>TEST<em>PACKAGE</p> </li> <li> <p>CALABASH</em>TEST<em>PACKAGE</p> </li> <li> <p>INSTRUMENTATION</em>TEST<em>PACKAGE</p> </li> <li> <p>UIAUTOMATION</em>TEST<em>PACKAGE</p> </li> <li> <p>UIAUTOMATOR</em>TEST<em>PACKAGE</p> </li> <li> <p>XCTEST</em>TEST<em>PACKAGE</p> </li> <li> <p>XCTEST</em>UI<em>TEST</em>PACKAGE</p> </li> <li> <p>APPIUM<em>JAVA</em>JUNIT<em>TEST</em>SPEC</p> </li> <li> <p>APPIUM<em>JAVA</em>TESTNG<em>TEST</em>SPEC</p> </li> <li> <p>APPIUM<em>PYTHON</em>TEST<em>SPEC</p> </li> <li> <p>APPIUM</em>NODE<em>TEST</em>SPEC</p> </li> <li> <p>APPIUM<em>RUBY</em>TEST<em>SPEC</p> </li> <li> <p>APPIUM</em>WEB<em>JAVA</em>JUNIT<em>TEST</em>SPEC</p> </li> <li> <p>APPIUM<em>WEB</em>JAVA<em>TESTNG</em>TEST<em>SPEC</p> </li> <li> <p>APPIUM</em>WEB<em>PYTHON</em>TEST<em>SPEC</p> </li> <li> <p>APPIUM</em>WEB<em>NODE</em>TEST<em>SPEC</p> </li> <li> <p>APPIUM</em>WEB<em>RUBY</em>TEST<em>SPEC</p> </li> <li> <p>INSTRUMENTATION</em>TEST<em>SPEC</p> </li> <li> <p>XCTEST</em>UI<em>TEST</em>SPEC</p> </li> </ul></p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<UploadType>,
     /// <p>The presigned Amazon S3 URL that was used to store a file using a PUT request.</p>
     #[serde(rename = "url")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub url: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum UploadCategory {
+    /// `CURATED`
+    Curated,
+    /// `PRIVATE`
+    Private,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl UploadCategory {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            UploadCategory::Curated => "CURATED",
+            UploadCategory::Private => "PRIVATE",
+            UploadCategory::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for UploadCategory {
+    fn default() -> UploadCategory {
+        UploadCategory::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for UploadCategory {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for UploadCategory {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for UploadCategory {
+    fn from(value: &'a str) -> UploadCategory {
+        match value {
+            "CURATED" => UploadCategory::Curated,
+            "PRIVATE" => UploadCategory::Private,
+            _ => UploadCategory::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for UploadCategory {
+    fn from(value: String) -> UploadCategory {
+        match value.as_str() {
+            "CURATED" => UploadCategory::Curated,
+            "PRIVATE" => UploadCategory::Private,
+            _ => UploadCategory::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for UploadCategory {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<UploadCategory, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for UploadCategory {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for UploadCategory {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for UploadCategory {
+    fn deserialize<D>(deserializer: D) -> Result<UploadCategory, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum UploadStatus {
+    /// `INITIALIZED`
+    Initialized,
+    /// `PROCESSING`
+    Processing,
+    /// `SUCCEEDED`
+    Succeeded,
+    /// `FAILED`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl UploadStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            UploadStatus::Initialized => "INITIALIZED",
+            UploadStatus::Processing => "PROCESSING",
+            UploadStatus::Succeeded => "SUCCEEDED",
+            UploadStatus::Failed => "FAILED",
+            UploadStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for UploadStatus {
+    fn default() -> UploadStatus {
+        UploadStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for UploadStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for UploadStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for UploadStatus {
+    fn from(value: &'a str) -> UploadStatus {
+        match value {
+            "INITIALIZED" => UploadStatus::Initialized,
+            "PROCESSING" => UploadStatus::Processing,
+            "SUCCEEDED" => UploadStatus::Succeeded,
+            "FAILED" => UploadStatus::Failed,
+            _ => UploadStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for UploadStatus {
+    fn from(value: String) -> UploadStatus {
+        match value.as_str() {
+            "INITIALIZED" => UploadStatus::Initialized,
+            "PROCESSING" => UploadStatus::Processing,
+            "SUCCEEDED" => UploadStatus::Succeeded,
+            "FAILED" => UploadStatus::Failed,
+            _ => UploadStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for UploadStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<UploadStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for UploadStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for UploadStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for UploadStatus {
+    fn deserialize<D>(deserializer: D) -> Result<UploadStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum UploadType {
+    /// `ANDROID_APP`
+    AndroidApp,
+    /// `IOS_APP`
+    IosApp,
+    /// `WEB_APP`
+    WebApp,
+    /// `EXTERNAL_DATA`
+    ExternalData,
+    /// `APPIUM_JAVA_JUNIT_TEST_PACKAGE`
+    AppiumJavaJunitTestPackage,
+    /// `APPIUM_JAVA_TESTNG_TEST_PACKAGE`
+    AppiumJavaTestngTestPackage,
+    /// `APPIUM_PYTHON_TEST_PACKAGE`
+    AppiumPythonTestPackage,
+    /// `APPIUM_NODE_TEST_PACKAGE`
+    AppiumNodeTestPackage,
+    /// `APPIUM_RUBY_TEST_PACKAGE`
+    AppiumRubyTestPackage,
+    /// `APPIUM_WEB_JAVA_JUNIT_TEST_PACKAGE`
+    AppiumWebJavaJunitTestPackage,
+    /// `APPIUM_WEB_JAVA_TESTNG_TEST_PACKAGE`
+    AppiumWebJavaTestngTestPackage,
+    /// `APPIUM_WEB_PYTHON_TEST_PACKAGE`
+    AppiumWebPythonTestPackage,
+    /// `APPIUM_WEB_NODE_TEST_PACKAGE`
+    AppiumWebNodeTestPackage,
+    /// `APPIUM_WEB_RUBY_TEST_PACKAGE`
+    AppiumWebRubyTestPackage,
+    /// `CALABASH_TEST_PACKAGE`
+    CalabashTestPackage,
+    /// `INSTRUMENTATION_TEST_PACKAGE`
+    InstrumentationTestPackage,
+    /// `UIAUTOMATION_TEST_PACKAGE`
+    UiautomationTestPackage,
+    /// `UIAUTOMATOR_TEST_PACKAGE`
+    UiautomatorTestPackage,
+    /// `XCTEST_TEST_PACKAGE`
+    XctestTestPackage,
+    /// `XCTEST_UI_TEST_PACKAGE`
+    XctestUiTestPackage,
+    /// `APPIUM_JAVA_JUNIT_TEST_SPEC`
+    AppiumJavaJunitTestSpec,
+    /// `APPIUM_JAVA_TESTNG_TEST_SPEC`
+    AppiumJavaTestngTestSpec,
+    /// `APPIUM_PYTHON_TEST_SPEC`
+    AppiumPythonTestSpec,
+    /// `APPIUM_NODE_TEST_SPEC`
+    AppiumNodeTestSpec,
+    /// `APPIUM_RUBY_TEST_SPEC`
+    AppiumRubyTestSpec,
+    /// `APPIUM_WEB_JAVA_JUNIT_TEST_SPEC`
+    AppiumWebJavaJunitTestSpec,
+    /// `APPIUM_WEB_JAVA_TESTNG_TEST_SPEC`
+    AppiumWebJavaTestngTestSpec,
+    /// `APPIUM_WEB_PYTHON_TEST_SPEC`
+    AppiumWebPythonTestSpec,
+    /// `APPIUM_WEB_NODE_TEST_SPEC`
+    AppiumWebNodeTestSpec,
+    /// `APPIUM_WEB_RUBY_TEST_SPEC`
+    AppiumWebRubyTestSpec,
+    /// `INSTRUMENTATION_TEST_SPEC`
+    InstrumentationTestSpec,
+    /// `XCTEST_UI_TEST_SPEC`
+    XctestUiTestSpec,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl UploadType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            UploadType::AndroidApp => "ANDROID_APP",
+            UploadType::IosApp => "IOS_APP",
+            UploadType::WebApp => "WEB_APP",
+            UploadType::ExternalData => "EXTERNAL_DATA",
+            UploadType::AppiumJavaJunitTestPackage => "APPIUM_JAVA_JUNIT_TEST_PACKAGE",
+            UploadType::AppiumJavaTestngTestPackage => "APPIUM_JAVA_TESTNG_TEST_PACKAGE",
+            UploadType::AppiumPythonTestPackage => "APPIUM_PYTHON_TEST_PACKAGE",
+            UploadType::AppiumNodeTestPackage => "APPIUM_NODE_TEST_PACKAGE",
+            UploadType::AppiumRubyTestPackage => "APPIUM_RUBY_TEST_PACKAGE",
+            UploadType::AppiumWebJavaJunitTestPackage => "APPIUM_WEB_JAVA_JUNIT_TEST_PACKAGE",
+            UploadType::AppiumWebJavaTestngTestPackage => "APPIUM_WEB_JAVA_TESTNG_TEST_PACKAGE",
+            UploadType::AppiumWebPythonTestPackage => "APPIUM_WEB_PYTHON_TEST_PACKAGE",
+            UploadType::AppiumWebNodeTestPackage => "APPIUM_WEB_NODE_TEST_PACKAGE",
+            UploadType::AppiumWebRubyTestPackage => "APPIUM_WEB_RUBY_TEST_PACKAGE",
+            UploadType::CalabashTestPackage => "CALABASH_TEST_PACKAGE",
+            UploadType::InstrumentationTestPackage => "INSTRUMENTATION_TEST_PACKAGE",
+            UploadType::UiautomationTestPackage => "UIAUTOMATION_TEST_PACKAGE",
+            UploadType::UiautomatorTestPackage => "UIAUTOMATOR_TEST_PACKAGE",
+            UploadType::XctestTestPackage => "XCTEST_TEST_PACKAGE",
+            UploadType::XctestUiTestPackage => "XCTEST_UI_TEST_PACKAGE",
+            UploadType::AppiumJavaJunitTestSpec => "APPIUM_JAVA_JUNIT_TEST_SPEC",
+            UploadType::AppiumJavaTestngTestSpec => "APPIUM_JAVA_TESTNG_TEST_SPEC",
+            UploadType::AppiumPythonTestSpec => "APPIUM_PYTHON_TEST_SPEC",
+            UploadType::AppiumNodeTestSpec => "APPIUM_NODE_TEST_SPEC",
+            UploadType::AppiumRubyTestSpec => "APPIUM_RUBY_TEST_SPEC",
+            UploadType::AppiumWebJavaJunitTestSpec => "APPIUM_WEB_JAVA_JUNIT_TEST_SPEC",
+            UploadType::AppiumWebJavaTestngTestSpec => "APPIUM_WEB_JAVA_TESTNG_TEST_SPEC",
+            UploadType::AppiumWebPythonTestSpec => "APPIUM_WEB_PYTHON_TEST_SPEC",
+            UploadType::AppiumWebNodeTestSpec => "APPIUM_WEB_NODE_TEST_SPEC",
+            UploadType::AppiumWebRubyTestSpec => "APPIUM_WEB_RUBY_TEST_SPEC",
+            UploadType::InstrumentationTestSpec => "INSTRUMENTATION_TEST_SPEC",
+            UploadType::XctestUiTestSpec => "XCTEST_UI_TEST_SPEC",
+            UploadType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for UploadType {
+    fn default() -> UploadType {
+        UploadType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for UploadType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for UploadType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for UploadType {
+    fn from(value: &'a str) -> UploadType {
+        match value {
+            "ANDROID_APP" => UploadType::AndroidApp,
+            "IOS_APP" => UploadType::IosApp,
+            "WEB_APP" => UploadType::WebApp,
+            "EXTERNAL_DATA" => UploadType::ExternalData,
+            "APPIUM_JAVA_JUNIT_TEST_PACKAGE" => UploadType::AppiumJavaJunitTestPackage,
+            "APPIUM_JAVA_TESTNG_TEST_PACKAGE" => UploadType::AppiumJavaTestngTestPackage,
+            "APPIUM_PYTHON_TEST_PACKAGE" => UploadType::AppiumPythonTestPackage,
+            "APPIUM_NODE_TEST_PACKAGE" => UploadType::AppiumNodeTestPackage,
+            "APPIUM_RUBY_TEST_PACKAGE" => UploadType::AppiumRubyTestPackage,
+            "APPIUM_WEB_JAVA_JUNIT_TEST_PACKAGE" => UploadType::AppiumWebJavaJunitTestPackage,
+            "APPIUM_WEB_JAVA_TESTNG_TEST_PACKAGE" => UploadType::AppiumWebJavaTestngTestPackage,
+            "APPIUM_WEB_PYTHON_TEST_PACKAGE" => UploadType::AppiumWebPythonTestPackage,
+            "APPIUM_WEB_NODE_TEST_PACKAGE" => UploadType::AppiumWebNodeTestPackage,
+            "APPIUM_WEB_RUBY_TEST_PACKAGE" => UploadType::AppiumWebRubyTestPackage,
+            "CALABASH_TEST_PACKAGE" => UploadType::CalabashTestPackage,
+            "INSTRUMENTATION_TEST_PACKAGE" => UploadType::InstrumentationTestPackage,
+            "UIAUTOMATION_TEST_PACKAGE" => UploadType::UiautomationTestPackage,
+            "UIAUTOMATOR_TEST_PACKAGE" => UploadType::UiautomatorTestPackage,
+            "XCTEST_TEST_PACKAGE" => UploadType::XctestTestPackage,
+            "XCTEST_UI_TEST_PACKAGE" => UploadType::XctestUiTestPackage,
+            "APPIUM_JAVA_JUNIT_TEST_SPEC" => UploadType::AppiumJavaJunitTestSpec,
+            "APPIUM_JAVA_TESTNG_TEST_SPEC" => UploadType::AppiumJavaTestngTestSpec,
+            "APPIUM_PYTHON_TEST_SPEC" => UploadType::AppiumPythonTestSpec,
+            "APPIUM_NODE_TEST_SPEC" => UploadType::AppiumNodeTestSpec,
+            "APPIUM_RUBY_TEST_SPEC" => UploadType::AppiumRubyTestSpec,
+            "APPIUM_WEB_JAVA_JUNIT_TEST_SPEC" => UploadType::AppiumWebJavaJunitTestSpec,
+            "APPIUM_WEB_JAVA_TESTNG_TEST_SPEC" => UploadType::AppiumWebJavaTestngTestSpec,
+            "APPIUM_WEB_PYTHON_TEST_SPEC" => UploadType::AppiumWebPythonTestSpec,
+            "APPIUM_WEB_NODE_TEST_SPEC" => UploadType::AppiumWebNodeTestSpec,
+            "APPIUM_WEB_RUBY_TEST_SPEC" => UploadType::AppiumWebRubyTestSpec,
+            "INSTRUMENTATION_TEST_SPEC" => UploadType::InstrumentationTestSpec,
+            "XCTEST_UI_TEST_SPEC" => UploadType::XctestUiTestSpec,
+            _ => UploadType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for UploadType {
+    fn from(value: String) -> UploadType {
+        match value.as_str() {
+            "ANDROID_APP" => UploadType::AndroidApp,
+            "IOS_APP" => UploadType::IosApp,
+            "WEB_APP" => UploadType::WebApp,
+            "EXTERNAL_DATA" => UploadType::ExternalData,
+            "APPIUM_JAVA_JUNIT_TEST_PACKAGE" => UploadType::AppiumJavaJunitTestPackage,
+            "APPIUM_JAVA_TESTNG_TEST_PACKAGE" => UploadType::AppiumJavaTestngTestPackage,
+            "APPIUM_PYTHON_TEST_PACKAGE" => UploadType::AppiumPythonTestPackage,
+            "APPIUM_NODE_TEST_PACKAGE" => UploadType::AppiumNodeTestPackage,
+            "APPIUM_RUBY_TEST_PACKAGE" => UploadType::AppiumRubyTestPackage,
+            "APPIUM_WEB_JAVA_JUNIT_TEST_PACKAGE" => UploadType::AppiumWebJavaJunitTestPackage,
+            "APPIUM_WEB_JAVA_TESTNG_TEST_PACKAGE" => UploadType::AppiumWebJavaTestngTestPackage,
+            "APPIUM_WEB_PYTHON_TEST_PACKAGE" => UploadType::AppiumWebPythonTestPackage,
+            "APPIUM_WEB_NODE_TEST_PACKAGE" => UploadType::AppiumWebNodeTestPackage,
+            "APPIUM_WEB_RUBY_TEST_PACKAGE" => UploadType::AppiumWebRubyTestPackage,
+            "CALABASH_TEST_PACKAGE" => UploadType::CalabashTestPackage,
+            "INSTRUMENTATION_TEST_PACKAGE" => UploadType::InstrumentationTestPackage,
+            "UIAUTOMATION_TEST_PACKAGE" => UploadType::UiautomationTestPackage,
+            "UIAUTOMATOR_TEST_PACKAGE" => UploadType::UiautomatorTestPackage,
+            "XCTEST_TEST_PACKAGE" => UploadType::XctestTestPackage,
+            "XCTEST_UI_TEST_PACKAGE" => UploadType::XctestUiTestPackage,
+            "APPIUM_JAVA_JUNIT_TEST_SPEC" => UploadType::AppiumJavaJunitTestSpec,
+            "APPIUM_JAVA_TESTNG_TEST_SPEC" => UploadType::AppiumJavaTestngTestSpec,
+            "APPIUM_PYTHON_TEST_SPEC" => UploadType::AppiumPythonTestSpec,
+            "APPIUM_NODE_TEST_SPEC" => UploadType::AppiumNodeTestSpec,
+            "APPIUM_RUBY_TEST_SPEC" => UploadType::AppiumRubyTestSpec,
+            "APPIUM_WEB_JAVA_JUNIT_TEST_SPEC" => UploadType::AppiumWebJavaJunitTestSpec,
+            "APPIUM_WEB_JAVA_TESTNG_TEST_SPEC" => UploadType::AppiumWebJavaTestngTestSpec,
+            "APPIUM_WEB_PYTHON_TEST_SPEC" => UploadType::AppiumWebPythonTestSpec,
+            "APPIUM_WEB_NODE_TEST_SPEC" => UploadType::AppiumWebNodeTestSpec,
+            "APPIUM_WEB_RUBY_TEST_SPEC" => UploadType::AppiumWebRubyTestSpec,
+            "INSTRUMENTATION_TEST_SPEC" => UploadType::InstrumentationTestSpec,
+            "XCTEST_UI_TEST_SPEC" => UploadType::XctestUiTestSpec,
+            _ => UploadType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for UploadType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<UploadType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for UploadType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for UploadType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for UploadType {
+    fn deserialize<D>(deserializer: D) -> Result<UploadType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents an Amazon Virtual Private Cloud (VPC) endpoint configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -7594,11 +11023,12 @@ pub struct DeviceFarmClient {
 impl DeviceFarmClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> DeviceFarmClient {
         DeviceFarmClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Device Farm"),
         }
     }
 
@@ -7630,6 +11060,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: CreateDevicePoolRequest,
     ) -> Result<CreateDevicePoolResult, RusotoError<CreateDevicePoolError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("CreateDevicePool");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.CreateDevicePool");
@@ -7657,6 +11088,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: CreateInstanceProfileRequest,
     ) -> Result<CreateInstanceProfileResult, RusotoError<CreateInstanceProfileError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("CreateInstanceProfile");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.CreateInstanceProfile");
@@ -7685,6 +11117,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: CreateNetworkProfileRequest,
     ) -> Result<CreateNetworkProfileResult, RusotoError<CreateNetworkProfileError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("CreateNetworkProfile");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.CreateNetworkProfile");
@@ -7713,6 +11146,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: CreateProjectRequest,
     ) -> Result<CreateProjectResult, RusotoError<CreateProjectError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("CreateProject");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.CreateProject");
@@ -7740,6 +11174,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: CreateRemoteAccessSessionRequest,
     ) -> Result<CreateRemoteAccessSessionResult, RusotoError<CreateRemoteAccessSessionError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("CreateRemoteAccessSession");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7771,6 +11206,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: CreateTestGridProjectRequest,
     ) -> Result<CreateTestGridProjectResult, RusotoError<CreateTestGridProjectError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("CreateTestGridProject");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.CreateTestGridProject");
@@ -7799,6 +11235,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: CreateTestGridUrlRequest,
     ) -> Result<CreateTestGridUrlResult, RusotoError<CreateTestGridUrlError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("CreateTestGridUrl");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.CreateTestGridUrl");
@@ -7826,6 +11263,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: CreateUploadRequest,
     ) -> Result<CreateUploadResult, RusotoError<CreateUploadError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("CreateUpload");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.CreateUpload");
@@ -7853,6 +11291,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: CreateVPCEConfigurationRequest,
     ) -> Result<CreateVPCEConfigurationResult, RusotoError<CreateVPCEConfigurationError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("CreateVPCEConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7884,6 +11323,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: DeleteDevicePoolRequest,
     ) -> Result<DeleteDevicePoolResult, RusotoError<DeleteDevicePoolError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("DeleteDevicePool");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.DeleteDevicePool");
@@ -7911,6 +11351,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: DeleteInstanceProfileRequest,
     ) -> Result<DeleteInstanceProfileResult, RusotoError<DeleteInstanceProfileError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("DeleteInstanceProfile");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.DeleteInstanceProfile");
@@ -7939,6 +11380,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: DeleteNetworkProfileRequest,
     ) -> Result<DeleteNetworkProfileResult, RusotoError<DeleteNetworkProfileError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("DeleteNetworkProfile");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.DeleteNetworkProfile");
@@ -7967,6 +11409,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: DeleteProjectRequest,
     ) -> Result<DeleteProjectResult, RusotoError<DeleteProjectError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("DeleteProject");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.DeleteProject");
@@ -7994,6 +11437,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: DeleteRemoteAccessSessionRequest,
     ) -> Result<DeleteRemoteAccessSessionResult, RusotoError<DeleteRemoteAccessSessionError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("DeleteRemoteAccessSession");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8025,6 +11469,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: DeleteRunRequest,
     ) -> Result<DeleteRunResult, RusotoError<DeleteRunError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("DeleteRun");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.DeleteRun");
@@ -8052,6 +11497,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: DeleteTestGridProjectRequest,
     ) -> Result<DeleteTestGridProjectResult, RusotoError<DeleteTestGridProjectError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("DeleteTestGridProject");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.DeleteTestGridProject");
@@ -8080,6 +11526,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: DeleteUploadRequest,
     ) -> Result<DeleteUploadResult, RusotoError<DeleteUploadError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("DeleteUpload");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.DeleteUpload");
@@ -8107,6 +11554,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: DeleteVPCEConfigurationRequest,
     ) -> Result<DeleteVPCEConfigurationResult, RusotoError<DeleteVPCEConfigurationError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("DeleteVPCEConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8137,6 +11585,7 @@ impl DeviceFarm for DeviceFarmClient {
         &self,
     ) -> Result<GetAccountSettingsResult, RusotoError<GetAccountSettingsError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("GetAccountSettings");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetAccountSettings");
@@ -8164,6 +11613,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: GetDeviceRequest,
     ) -> Result<GetDeviceResult, RusotoError<GetDeviceError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("GetDevice");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetDevice");
@@ -8191,6 +11641,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: GetDeviceInstanceRequest,
     ) -> Result<GetDeviceInstanceResult, RusotoError<GetDeviceInstanceError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("GetDeviceInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetDeviceInstance");
@@ -8218,6 +11669,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: GetDevicePoolRequest,
     ) -> Result<GetDevicePoolResult, RusotoError<GetDevicePoolError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("GetDevicePool");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetDevicePool");
@@ -8246,6 +11698,7 @@ impl DeviceFarm for DeviceFarmClient {
     ) -> Result<GetDevicePoolCompatibilityResult, RusotoError<GetDevicePoolCompatibilityError>>
     {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("GetDevicePoolCompatibility");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8277,6 +11730,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: GetInstanceProfileRequest,
     ) -> Result<GetInstanceProfileResult, RusotoError<GetInstanceProfileError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("GetInstanceProfile");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetInstanceProfile");
@@ -8305,6 +11759,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: GetJobRequest,
     ) -> Result<GetJobResult, RusotoError<GetJobError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("GetJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetJob");
@@ -8332,6 +11787,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: GetNetworkProfileRequest,
     ) -> Result<GetNetworkProfileResult, RusotoError<GetNetworkProfileError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("GetNetworkProfile");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetNetworkProfile");
@@ -8359,6 +11815,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: GetOfferingStatusRequest,
     ) -> Result<GetOfferingStatusResult, RusotoError<GetOfferingStatusError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("GetOfferingStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetOfferingStatus");
@@ -8386,6 +11843,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: GetProjectRequest,
     ) -> Result<GetProjectResult, RusotoError<GetProjectError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("GetProject");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetProject");
@@ -8413,6 +11871,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: GetRemoteAccessSessionRequest,
     ) -> Result<GetRemoteAccessSessionResult, RusotoError<GetRemoteAccessSessionError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("GetRemoteAccessSession");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetRemoteAccessSession");
@@ -8441,6 +11900,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: GetRunRequest,
     ) -> Result<GetRunResult, RusotoError<GetRunError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("GetRun");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetRun");
@@ -8468,6 +11928,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: GetSuiteRequest,
     ) -> Result<GetSuiteResult, RusotoError<GetSuiteError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("GetSuite");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetSuite");
@@ -8495,6 +11956,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: GetTestRequest,
     ) -> Result<GetTestResult, RusotoError<GetTestError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("GetTest");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetTest");
@@ -8522,6 +11984,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: GetTestGridProjectRequest,
     ) -> Result<GetTestGridProjectResult, RusotoError<GetTestGridProjectError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("GetTestGridProject");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetTestGridProject");
@@ -8550,6 +12013,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: GetTestGridSessionRequest,
     ) -> Result<GetTestGridSessionResult, RusotoError<GetTestGridSessionError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("GetTestGridSession");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetTestGridSession");
@@ -8578,6 +12042,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: GetUploadRequest,
     ) -> Result<GetUploadResult, RusotoError<GetUploadError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("GetUpload");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetUpload");
@@ -8605,6 +12070,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: GetVPCEConfigurationRequest,
     ) -> Result<GetVPCEConfigurationResult, RusotoError<GetVPCEConfigurationError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("GetVPCEConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetVPCEConfiguration");
@@ -8634,6 +12100,7 @@ impl DeviceFarm for DeviceFarmClient {
     ) -> Result<InstallToRemoteAccessSessionResult, RusotoError<InstallToRemoteAccessSessionError>>
     {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("InstallToRemoteAccessSession");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8665,6 +12132,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListArtifactsRequest,
     ) -> Result<ListArtifactsResult, RusotoError<ListArtifactsError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListArtifacts");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListArtifacts");
@@ -8692,6 +12160,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListDeviceInstancesRequest,
     ) -> Result<ListDeviceInstancesResult, RusotoError<ListDeviceInstancesError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListDeviceInstances");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListDeviceInstances");
@@ -8720,6 +12189,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListDevicePoolsRequest,
     ) -> Result<ListDevicePoolsResult, RusotoError<ListDevicePoolsError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListDevicePools");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListDevicePools");
@@ -8747,6 +12217,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListDevicesRequest,
     ) -> Result<ListDevicesResult, RusotoError<ListDevicesError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListDevices");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListDevices");
@@ -8774,6 +12245,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListInstanceProfilesRequest,
     ) -> Result<ListInstanceProfilesResult, RusotoError<ListInstanceProfilesError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListInstanceProfiles");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListInstanceProfiles");
@@ -8802,6 +12274,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListJobsRequest,
     ) -> Result<ListJobsResult, RusotoError<ListJobsError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListJobs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListJobs");
@@ -8829,6 +12302,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListNetworkProfilesRequest,
     ) -> Result<ListNetworkProfilesResult, RusotoError<ListNetworkProfilesError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListNetworkProfiles");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListNetworkProfiles");
@@ -8857,6 +12331,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListOfferingPromotionsRequest,
     ) -> Result<ListOfferingPromotionsResult, RusotoError<ListOfferingPromotionsError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListOfferingPromotions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListOfferingPromotions");
@@ -8885,6 +12360,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListOfferingTransactionsRequest,
     ) -> Result<ListOfferingTransactionsResult, RusotoError<ListOfferingTransactionsError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListOfferingTransactions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8916,6 +12392,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListOfferingsRequest,
     ) -> Result<ListOfferingsResult, RusotoError<ListOfferingsError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListOfferings");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListOfferings");
@@ -8943,6 +12420,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListProjectsRequest,
     ) -> Result<ListProjectsResult, RusotoError<ListProjectsError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListProjects");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListProjects");
@@ -8970,6 +12448,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListRemoteAccessSessionsRequest,
     ) -> Result<ListRemoteAccessSessionsResult, RusotoError<ListRemoteAccessSessionsError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListRemoteAccessSessions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9001,6 +12480,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListRunsRequest,
     ) -> Result<ListRunsResult, RusotoError<ListRunsError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListRuns");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListRuns");
@@ -9028,6 +12508,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListSamplesRequest,
     ) -> Result<ListSamplesResult, RusotoError<ListSamplesError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListSamples");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListSamples");
@@ -9055,6 +12536,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListSuitesRequest,
     ) -> Result<ListSuitesResult, RusotoError<ListSuitesError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListSuites");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListSuites");
@@ -9082,6 +12564,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListTagsForResource");
@@ -9110,6 +12593,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListTestGridProjectsRequest,
     ) -> Result<ListTestGridProjectsResult, RusotoError<ListTestGridProjectsError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListTestGridProjects");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListTestGridProjects");
@@ -9139,6 +12623,7 @@ impl DeviceFarm for DeviceFarmClient {
     ) -> Result<ListTestGridSessionActionsResult, RusotoError<ListTestGridSessionActionsError>>
     {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListTestGridSessionActions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9171,6 +12656,7 @@ impl DeviceFarm for DeviceFarmClient {
     ) -> Result<ListTestGridSessionArtifactsResult, RusotoError<ListTestGridSessionArtifactsError>>
     {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListTestGridSessionArtifacts");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9202,6 +12688,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListTestGridSessionsRequest,
     ) -> Result<ListTestGridSessionsResult, RusotoError<ListTestGridSessionsError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListTestGridSessions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListTestGridSessions");
@@ -9230,6 +12717,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListTestsRequest,
     ) -> Result<ListTestsResult, RusotoError<ListTestsError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListTests");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListTests");
@@ -9257,6 +12745,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListUniqueProblemsRequest,
     ) -> Result<ListUniqueProblemsResult, RusotoError<ListUniqueProblemsError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListUniqueProblems");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListUniqueProblems");
@@ -9285,6 +12774,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListUploadsRequest,
     ) -> Result<ListUploadsResult, RusotoError<ListUploadsError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListUploads");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListUploads");
@@ -9312,6 +12802,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListVPCEConfigurationsRequest,
     ) -> Result<ListVPCEConfigurationsResult, RusotoError<ListVPCEConfigurationsError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListVPCEConfigurations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListVPCEConfigurations");
@@ -9340,6 +12831,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: PurchaseOfferingRequest,
     ) -> Result<PurchaseOfferingResult, RusotoError<PurchaseOfferingError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("PurchaseOffering");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.PurchaseOffering");
@@ -9367,6 +12859,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: RenewOfferingRequest,
     ) -> Result<RenewOfferingResult, RusotoError<RenewOfferingError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("RenewOffering");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.RenewOffering");
@@ -9394,6 +12887,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: ScheduleRunRequest,
     ) -> Result<ScheduleRunResult, RusotoError<ScheduleRunError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ScheduleRun");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.ScheduleRun");
@@ -9421,6 +12915,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: StopJobRequest,
     ) -> Result<StopJobResult, RusotoError<StopJobError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("StopJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.StopJob");
@@ -9448,6 +12943,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: StopRemoteAccessSessionRequest,
     ) -> Result<StopRemoteAccessSessionResult, RusotoError<StopRemoteAccessSessionError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("StopRemoteAccessSession");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9479,6 +12975,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: StopRunRequest,
     ) -> Result<StopRunResult, RusotoError<StopRunError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("StopRun");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.StopRun");
@@ -9506,6 +13003,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.TagResource");
@@ -9533,6 +13031,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.UntagResource");
@@ -9560,6 +13059,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: UpdateDeviceInstanceRequest,
     ) -> Result<UpdateDeviceInstanceResult, RusotoError<UpdateDeviceInstanceError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("UpdateDeviceInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.UpdateDeviceInstance");
@@ -9588,6 +13088,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: UpdateDevicePoolRequest,
     ) -> Result<UpdateDevicePoolResult, RusotoError<UpdateDevicePoolError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("UpdateDevicePool");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.UpdateDevicePool");
@@ -9615,6 +13116,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: UpdateInstanceProfileRequest,
     ) -> Result<UpdateInstanceProfileResult, RusotoError<UpdateInstanceProfileError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("UpdateInstanceProfile");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.UpdateInstanceProfile");
@@ -9643,6 +13145,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: UpdateNetworkProfileRequest,
     ) -> Result<UpdateNetworkProfileResult, RusotoError<UpdateNetworkProfileError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("UpdateNetworkProfile");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.UpdateNetworkProfile");
@@ -9671,6 +13174,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: UpdateProjectRequest,
     ) -> Result<UpdateProjectResult, RusotoError<UpdateProjectError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("UpdateProject");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.UpdateProject");
@@ -9698,6 +13202,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: UpdateTestGridProjectRequest,
     ) -> Result<UpdateTestGridProjectResult, RusotoError<UpdateTestGridProjectError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("UpdateTestGridProject");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.UpdateTestGridProject");
@@ -9726,6 +13231,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: UpdateUploadRequest,
     ) -> Result<UpdateUploadResult, RusotoError<UpdateUploadError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("UpdateUpload");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.UpdateUpload");
@@ -9753,6 +13259,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: UpdateVPCEConfigurationRequest,
     ) -> Result<UpdateVPCEConfigurationResult, RusotoError<UpdateVPCEConfigurationError>> {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("UpdateVPCEConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9778,3 +13285,621 @@ impl DeviceFarm for DeviceFarmClient {
         }
     }
 }
+impl DeviceFarmClient {
+    /// Auto-paginating version of `get_offering_status`, streaming every page of results.
+    pub fn get_offering_status_pages(
+        &self,
+        input: GetOfferingStatusRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetOfferingStatusResult, GetOfferingStatusError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DeviceFarmClient, input: GetOfferingStatusRequest| async move {
+                client.get_offering_status(input).await
+            },
+            |_input: &GetOfferingStatusRequest, output: &GetOfferingStatusResult| {
+                Some(GetOfferingStatusRequest {
+                    next_token: Some(output.next_token.clone()?),
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_artifacts`, streaming every page of results.
+    pub fn list_artifacts_pages(
+        &self,
+        input: ListArtifactsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListArtifactsResult, ListArtifactsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DeviceFarmClient, input: ListArtifactsRequest| async move {
+                client.list_artifacts(input).await
+            },
+            |input: &ListArtifactsRequest, output: &ListArtifactsResult| {
+                Some(ListArtifactsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_artifacts`, streaming the `artifacts` of every page of results.
+    pub fn list_artifacts_items(
+        &self,
+        input: ListArtifactsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Artifact, ListArtifactsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_artifacts_pages(input),
+            |output: ListArtifactsResult| output.artifacts.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_device_instances`, streaming every page of results.
+    pub fn list_device_instances_pages(
+        &self,
+        input: ListDeviceInstancesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListDeviceInstancesResult, ListDeviceInstancesError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DeviceFarmClient, input: ListDeviceInstancesRequest| async move {
+                client.list_device_instances(input).await
+            },
+            |input: &ListDeviceInstancesRequest, output: &ListDeviceInstancesResult| {
+                Some(ListDeviceInstancesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_device_instances`, streaming the `deviceInstances` of every page of results.
+    pub fn list_device_instances_items(
+        &self,
+        input: ListDeviceInstancesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DeviceInstance, ListDeviceInstancesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_device_instances_pages(input),
+            |output: ListDeviceInstancesResult| output.device_instances.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_device_pools`, streaming every page of results.
+    pub fn list_device_pools_pages(
+        &self,
+        input: ListDevicePoolsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListDevicePoolsResult, ListDevicePoolsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DeviceFarmClient, input: ListDevicePoolsRequest| async move {
+                client.list_device_pools(input).await
+            },
+            |input: &ListDevicePoolsRequest, output: &ListDevicePoolsResult| {
+                Some(ListDevicePoolsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_device_pools`, streaming the `devicePools` of every page of results.
+    pub fn list_device_pools_items(
+        &self,
+        input: ListDevicePoolsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DevicePool, ListDevicePoolsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_device_pools_pages(input),
+            |output: ListDevicePoolsResult| output.device_pools.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_devices`, streaming every page of results.
+    pub fn list_devices_pages(
+        &self,
+        input: ListDevicesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListDevicesResult, ListDevicesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DeviceFarmClient, input: ListDevicesRequest| async move {
+                client.list_devices(input).await
+            },
+            |input: &ListDevicesRequest, output: &ListDevicesResult| {
+                Some(ListDevicesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_devices`, streaming the `devices` of every page of results.
+    pub fn list_devices_items(
+        &self,
+        input: ListDevicesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Device, ListDevicesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_devices_pages(input),
+            |output: ListDevicesResult| output.devices.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_instance_profiles`, streaming every page of results.
+    pub fn list_instance_profiles_pages(
+        &self,
+        input: ListInstanceProfilesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListInstanceProfilesResult, ListInstanceProfilesError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DeviceFarmClient, input: ListInstanceProfilesRequest| async move {
+                client.list_instance_profiles(input).await
+            },
+            |input: &ListInstanceProfilesRequest, output: &ListInstanceProfilesResult| {
+                Some(ListInstanceProfilesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_instance_profiles`, streaming the `instanceProfiles` of every page of results.
+    pub fn list_instance_profiles_items(
+        &self,
+        input: ListInstanceProfilesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<InstanceProfile, ListInstanceProfilesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_instance_profiles_pages(input),
+            |output: ListInstanceProfilesResult| output.instance_profiles.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_jobs`, streaming every page of results.
+    pub fn list_jobs_pages(
+        &self,
+        input: ListJobsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListJobsResult, ListJobsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DeviceFarmClient, input: ListJobsRequest| async move {
+                client.list_jobs(input).await
+            },
+            |input: &ListJobsRequest, output: &ListJobsResult| {
+                Some(ListJobsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_jobs`, streaming the `jobs` of every page of results.
+    pub fn list_jobs_items(
+        &self,
+        input: ListJobsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Job, ListJobsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_jobs_pages(input),
+            |output: ListJobsResult| output.jobs.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_network_profiles`, streaming every page of results.
+    pub fn list_network_profiles_pages(
+        &self,
+        input: ListNetworkProfilesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListNetworkProfilesResult, ListNetworkProfilesError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DeviceFarmClient, input: ListNetworkProfilesRequest| async move {
+                client.list_network_profiles(input).await
+            },
+            |input: &ListNetworkProfilesRequest, output: &ListNetworkProfilesResult| {
+                Some(ListNetworkProfilesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_network_profiles`, streaming the `networkProfiles` of every page of results.
+    pub fn list_network_profiles_items(
+        &self,
+        input: ListNetworkProfilesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<NetworkProfile, ListNetworkProfilesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_network_profiles_pages(input),
+            |output: ListNetworkProfilesResult| output.network_profiles.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_offering_promotions`, streaming every page of results.
+    pub fn list_offering_promotions_pages(
+        &self,
+        input: ListOfferingPromotionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListOfferingPromotionsResult,
+        ListOfferingPromotionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DeviceFarmClient, input: ListOfferingPromotionsRequest| async move {
+                client.list_offering_promotions(input).await
+            },
+            |_input: &ListOfferingPromotionsRequest, output: &ListOfferingPromotionsResult| {
+                Some(ListOfferingPromotionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_offering_promotions`, streaming the `offeringPromotions` of every page of results.
+    pub fn list_offering_promotions_items(
+        &self,
+        input: ListOfferingPromotionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<OfferingPromotion, ListOfferingPromotionsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_offering_promotions_pages(input),
+            |output: ListOfferingPromotionsResult| output.offering_promotions.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_offering_transactions`, streaming every page of results.
+    pub fn list_offering_transactions_pages(
+        &self,
+        input: ListOfferingTransactionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListOfferingTransactionsResult,
+        ListOfferingTransactionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DeviceFarmClient, input: ListOfferingTransactionsRequest| async move {
+                client.list_offering_transactions(input).await
+            },
+            |_input: &ListOfferingTransactionsRequest, output: &ListOfferingTransactionsResult| {
+                Some(ListOfferingTransactionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_offering_transactions`, streaming the `offeringTransactions` of every page of results.
+    pub fn list_offering_transactions_items(
+        &self,
+        input: ListOfferingTransactionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<OfferingTransaction, ListOfferingTransactionsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_offering_transactions_pages(input),
+            |output: ListOfferingTransactionsResult| {
+                output.offering_transactions.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_offerings`, streaming every page of results.
+    pub fn list_offerings_pages(
+        &self,
+        input: ListOfferingsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListOfferingsResult, ListOfferingsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DeviceFarmClient, input: ListOfferingsRequest| async move {
+                client.list_offerings(input).await
+            },
+            |_input: &ListOfferingsRequest, output: &ListOfferingsResult| {
+                Some(ListOfferingsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_offerings`, streaming the `offerings` of every page of results.
+    pub fn list_offerings_items(
+        &self,
+        input: ListOfferingsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Offering, ListOfferingsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_offerings_pages(input),
+            |output: ListOfferingsResult| output.offerings.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_projects`, streaming every page of results.
+    pub fn list_projects_pages(
+        &self,
+        input: ListProjectsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListProjectsResult, ListProjectsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DeviceFarmClient, input: ListProjectsRequest| async move {
+                client.list_projects(input).await
+            },
+            |input: &ListProjectsRequest, output: &ListProjectsResult| {
+                Some(ListProjectsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_projects`, streaming the `projects` of every page of results.
+    pub fn list_projects_items(
+        &self,
+        input: ListProjectsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Project, ListProjectsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_projects_pages(input),
+            |output: ListProjectsResult| output.projects.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_remote_access_sessions`, streaming every page of results.
+    pub fn list_remote_access_sessions_pages(
+        &self,
+        input: ListRemoteAccessSessionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListRemoteAccessSessionsResult,
+        ListRemoteAccessSessionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DeviceFarmClient, input: ListRemoteAccessSessionsRequest| async move {
+                client.list_remote_access_sessions(input).await
+            },
+            |input: &ListRemoteAccessSessionsRequest, output: &ListRemoteAccessSessionsResult| {
+                Some(ListRemoteAccessSessionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_remote_access_sessions`, streaming the `remoteAccessSessions` of every page of results.
+    pub fn list_remote_access_sessions_items(
+        &self,
+        input: ListRemoteAccessSessionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<RemoteAccessSession, ListRemoteAccessSessionsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_remote_access_sessions_pages(input),
+            |output: ListRemoteAccessSessionsResult| {
+                output.remote_access_sessions.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_runs`, streaming every page of results.
+    pub fn list_runs_pages(
+        &self,
+        input: ListRunsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListRunsResult, ListRunsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DeviceFarmClient, input: ListRunsRequest| async move {
+                client.list_runs(input).await
+            },
+            |input: &ListRunsRequest, output: &ListRunsResult| {
+                Some(ListRunsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_runs`, streaming the `runs` of every page of results.
+    pub fn list_runs_items(
+        &self,
+        input: ListRunsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Run, ListRunsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_runs_pages(input),
+            |output: ListRunsResult| output.runs.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_samples`, streaming every page of results.
+    pub fn list_samples_pages(
+        &self,
+        input: ListSamplesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListSamplesResult, ListSamplesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DeviceFarmClient, input: ListSamplesRequest| async move {
+                client.list_samples(input).await
+            },
+            |input: &ListSamplesRequest, output: &ListSamplesResult| {
+                Some(ListSamplesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_samples`, streaming the `samples` of every page of results.
+    pub fn list_samples_items(
+        &self,
+        input: ListSamplesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Sample, ListSamplesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_samples_pages(input),
+            |output: ListSamplesResult| output.samples.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_suites`, streaming every page of results.
+    pub fn list_suites_pages(
+        &self,
+        input: ListSuitesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListSuitesResult, ListSuitesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DeviceFarmClient, input: ListSuitesRequest| async move {
+                client.list_suites(input).await
+            },
+            |input: &ListSuitesRequest, output: &ListSuitesResult| {
+                Some(ListSuitesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_suites`, streaming the `suites` of every page of results.
+    pub fn list_suites_items(
+        &self,
+        input: ListSuitesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Suite, ListSuitesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_suites_pages(input),
+            |output: ListSuitesResult| output.suites.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_tests`, streaming every page of results.
+    pub fn list_tests_pages(
+        &self,
+        input: ListTestsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListTestsResult, ListTestsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DeviceFarmClient, input: ListTestsRequest| async move {
+                client.list_tests(input).await
+            },
+            |input: &ListTestsRequest, output: &ListTestsResult| {
+                Some(ListTestsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_tests`, streaming the `tests` of every page of results.
+    pub fn list_tests_items(
+        &self,
+        input: ListTestsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Test, ListTestsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_tests_pages(input),
+            |output: ListTestsResult| output.tests.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_unique_problems`, streaming every page of results.
+    pub fn list_unique_problems_pages(
+        &self,
+        input: ListUniqueProblemsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListUniqueProblemsResult, ListUniqueProblemsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DeviceFarmClient, input: ListUniqueProblemsRequest| async move {
+                client.list_unique_problems(input).await
+            },
+            |input: &ListUniqueProblemsRequest, output: &ListUniqueProblemsResult| {
+                Some(ListUniqueProblemsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_uploads`, streaming every page of results.
+    pub fn list_uploads_pages(
+        &self,
+        input: ListUploadsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListUploadsResult, ListUploadsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DeviceFarmClient, input: ListUploadsRequest| async move {
+                client.list_uploads(input).await
+            },
+            |input: &ListUploadsRequest, output: &ListUploadsResult| {
+                Some(ListUploadsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_uploads`, streaming the `uploads` of every page of results.
+    pub fn list_uploads_items(
+        &self,
+        input: ListUploadsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Upload, ListUploadsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_uploads_pages(input),
+            |output: ListUploadsResult| output.uploads.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_vpce_configurations`, streaming every page of results.
+    pub fn list_vpce_configurations_pages(
+        &self,
+        input: ListVPCEConfigurationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListVPCEConfigurationsResult,
+        ListVPCEConfigurationsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DeviceFarmClient, input: ListVPCEConfigurationsRequest| async move {
+                client.list_vpce_configurations(input).await
+            },
+            |input: &ListVPCEConfigurationsRequest, output: &ListVPCEConfigurationsResult| {
+                Some(ListVPCEConfigurationsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_vpce_configurations`, streaming the `vpceConfigurations` of every page of results.
+    pub fn list_vpce_configurations_items(
+        &self,
+        input: ListVPCEConfigurationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<VPCEConfiguration, ListVPCEConfigurationsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_vpce_configurations_pages(input),
+            |output: ListVPCEConfigurationsResult| output.vpce_configurations.unwrap_or_default(),
+        )
+    }
+}
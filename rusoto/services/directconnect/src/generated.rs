@@ -50,6 +50,99 @@ pub struct AcceptDirectConnectGatewayAssociationProposalResult {
     pub direct_connect_gateway_association: Option<DirectConnectGatewayAssociation>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AddressFamily {
+    /// `ipv4`
+    Ipv4,
+    /// `ipv6`
+    Ipv6,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AddressFamily {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AddressFamily::Ipv4 => "ipv4",
+            AddressFamily::Ipv6 => "ipv6",
+            AddressFamily::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AddressFamily {
+    fn default() -> AddressFamily {
+        AddressFamily::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AddressFamily {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AddressFamily {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AddressFamily {
+    fn from(value: &'a str) -> AddressFamily {
+        match value {
+            "ipv4" => AddressFamily::Ipv4,
+            "ipv6" => AddressFamily::Ipv6,
+            _ => AddressFamily::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AddressFamily {
+    fn from(value: String) -> AddressFamily {
+        match value.as_str() {
+            "ipv4" => AddressFamily::Ipv4,
+            "ipv6" => AddressFamily::Ipv6,
+            _ => AddressFamily::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AddressFamily {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AddressFamily, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AddressFamily {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AddressFamily {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AddressFamily {
+    fn deserialize<D>(deserializer: D) -> Result<AddressFamily, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct AllocateConnectionOnInterconnectRequest {
@@ -196,7 +289,7 @@ pub struct AssociatedGateway {
     /// <p>The type of associated gateway.</p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<GatewayType>,
 }
 
 /// <p>Information about a BGP peer.</p>
@@ -206,7 +299,7 @@ pub struct BGPPeer {
     /// <p>The address family for the BGP peer.</p>
     #[serde(rename = "addressFamily")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub address_family: Option<String>,
+    pub address_family: Option<AddressFamily>,
     /// <p>The IP address assigned to the Amazon interface.</p>
     #[serde(rename = "amazonAddress")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -230,17 +323,223 @@ pub struct BGPPeer {
     /// <p><p>The state of the BGP peer. The following are the possible values:</p> <ul> <li> <p> <code>verifying</code>: The BGP peering addresses or ASN require validation before the BGP peer can be created. This state applies only to public virtual interfaces.</p> </li> <li> <p> <code>pending</code>: The BGP peer is created, and remains in this state until it is ready to be established.</p> </li> <li> <p> <code>available</code>: The BGP peer is ready to be established.</p> </li> <li> <p> <code>deleting</code>: The BGP peer is being deleted.</p> </li> <li> <p> <code>deleted</code>: The BGP peer is deleted and cannot be established.</p> </li> </ul></p>
     #[serde(rename = "bgpPeerState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub bgp_peer_state: Option<String>,
+    pub bgp_peer_state: Option<BGPPeerState>,
     /// <p><p>The status of the BGP peer. The following are the possible values:</p> <ul> <li> <p> <code>up</code>: The BGP peer is established. This state does not indicate the state of the routing function. Ensure that you are receiving routes over the BGP session.</p> </li> <li> <p> <code>down</code>: The BGP peer is down.</p> </li> <li> <p> <code>unknown</code>: The BGP peer status is not available.</p> </li> </ul></p>
     #[serde(rename = "bgpStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub bgp_status: Option<String>,
+    pub bgp_status: Option<BGPStatus>,
     /// <p>The IP address assigned to the customer interface.</p>
     #[serde(rename = "customerAddress")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub customer_address: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BGPPeerState {
+    /// `verifying`
+    Verifying,
+    /// `pending`
+    Pending,
+    /// `available`
+    Available,
+    /// `deleting`
+    Deleting,
+    /// `deleted`
+    Deleted,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl BGPPeerState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BGPPeerState::Verifying => "verifying",
+            BGPPeerState::Pending => "pending",
+            BGPPeerState::Available => "available",
+            BGPPeerState::Deleting => "deleting",
+            BGPPeerState::Deleted => "deleted",
+            BGPPeerState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for BGPPeerState {
+    fn default() -> BGPPeerState {
+        BGPPeerState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for BGPPeerState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for BGPPeerState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for BGPPeerState {
+    fn from(value: &'a str) -> BGPPeerState {
+        match value {
+            "verifying" => BGPPeerState::Verifying,
+            "pending" => BGPPeerState::Pending,
+            "available" => BGPPeerState::Available,
+            "deleting" => BGPPeerState::Deleting,
+            "deleted" => BGPPeerState::Deleted,
+            _ => BGPPeerState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BGPPeerState {
+    fn from(value: String) -> BGPPeerState {
+        match value.as_str() {
+            "verifying" => BGPPeerState::Verifying,
+            "pending" => BGPPeerState::Pending,
+            "available" => BGPPeerState::Available,
+            "deleting" => BGPPeerState::Deleting,
+            "deleted" => BGPPeerState::Deleted,
+            _ => BGPPeerState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for BGPPeerState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<BGPPeerState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for BGPPeerState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for BGPPeerState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BGPPeerState {
+    fn deserialize<D>(deserializer: D) -> Result<BGPPeerState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BGPStatus {
+    /// `up`
+    Up,
+    /// `down`
+    Down,
+    /// `unknown`
+    UnknownValue,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl BGPStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BGPStatus::Up => "up",
+            BGPStatus::Down => "down",
+            BGPStatus::UnknownValue => "unknown",
+            BGPStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for BGPStatus {
+    fn default() -> BGPStatus {
+        BGPStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for BGPStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for BGPStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for BGPStatus {
+    fn from(value: &'a str) -> BGPStatus {
+        match value {
+            "up" => BGPStatus::Up,
+            "down" => BGPStatus::Down,
+            "unknown" => BGPStatus::UnknownValue,
+            _ => BGPStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BGPStatus {
+    fn from(value: String) -> BGPStatus {
+        match value.as_str() {
+            "up" => BGPStatus::Up,
+            "down" => BGPStatus::Down,
+            "unknown" => BGPStatus::UnknownValue,
+            _ => BGPStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for BGPStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<BGPStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for BGPStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for BGPStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BGPStatus {
+    fn deserialize<D>(deserializer: D) -> Result<BGPStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ConfirmConnectionRequest {
@@ -255,7 +554,7 @@ pub struct ConfirmConnectionResponse {
     /// <p><p>The state of the connection. The following are the possible values:</p> <ul> <li> <p> <code>ordering</code>: The initial state of a hosted connection provisioned on an interconnect. The connection stays in the ordering state until the owner of the hosted connection confirms or declines the connection order.</p> </li> <li> <p> <code>requested</code>: The initial state of a standard connection. The connection stays in the requested state until the Letter of Authorization (LOA) is sent to the customer.</p> </li> <li> <p> <code>pending</code>: The connection has been approved and is being initialized.</p> </li> <li> <p> <code>available</code>: The network link is up and the connection is ready for use.</p> </li> <li> <p> <code>down</code>: The network link is down.</p> </li> <li> <p> <code>deleting</code>: The connection is being deleted.</p> </li> <li> <p> <code>deleted</code>: The connection has been deleted.</p> </li> <li> <p> <code>rejected</code>: A hosted connection in the <code>ordering</code> state enters the <code>rejected</code> state if it is deleted by the customer.</p> </li> <li> <p> <code>unknown</code>: The state of the connection is not available.</p> </li> </ul></p>
     #[serde(rename = "connectionState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub connection_state: Option<String>,
+    pub connection_state: Option<ConnectionState>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -280,7 +579,7 @@ pub struct ConfirmPrivateVirtualInterfaceResponse {
     /// <p><p>The state of the virtual interface. The following are the possible values:</p> <ul> <li> <p> <code>confirming</code>: The creation of the virtual interface is pending confirmation from the virtual interface owner. If the owner of the virtual interface is different from the owner of the connection on which it is provisioned, then the virtual interface will remain in this state until it is confirmed by the virtual interface owner.</p> </li> <li> <p> <code>verifying</code>: This state only applies to public virtual interfaces. Each public virtual interface needs validation before the virtual interface can be created.</p> </li> <li> <p> <code>pending</code>: A virtual interface is in this state from the time that it is created until the virtual interface is ready to forward traffic.</p> </li> <li> <p> <code>available</code>: A virtual interface that is able to forward traffic.</p> </li> <li> <p> <code>down</code>: A virtual interface that is BGP down.</p> </li> <li> <p> <code>deleting</code>: A virtual interface is in this state immediately after calling <a>DeleteVirtualInterface</a> until it can no longer forward traffic.</p> </li> <li> <p> <code>deleted</code>: A virtual interface that cannot forward traffic.</p> </li> <li> <p> <code>rejected</code>: The virtual interface owner has declined creation of the virtual interface. If a virtual interface in the <code>Confirming</code> state is deleted by the virtual interface owner, the virtual interface enters the <code>Rejected</code> state.</p> </li> <li> <p> <code>unknown</code>: The state of the virtual interface is not available.</p> </li> </ul></p>
     #[serde(rename = "virtualInterfaceState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub virtual_interface_state: Option<String>,
+    pub virtual_interface_state: Option<VirtualInterfaceState>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -297,7 +596,7 @@ pub struct ConfirmPublicVirtualInterfaceResponse {
     /// <p><p>The state of the virtual interface. The following are the possible values:</p> <ul> <li> <p> <code>confirming</code>: The creation of the virtual interface is pending confirmation from the virtual interface owner. If the owner of the virtual interface is different from the owner of the connection on which it is provisioned, then the virtual interface will remain in this state until it is confirmed by the virtual interface owner.</p> </li> <li> <p> <code>verifying</code>: This state only applies to public virtual interfaces. Each public virtual interface needs validation before the virtual interface can be created.</p> </li> <li> <p> <code>pending</code>: A virtual interface is in this state from the time that it is created until the virtual interface is ready to forward traffic.</p> </li> <li> <p> <code>available</code>: A virtual interface that is able to forward traffic.</p> </li> <li> <p> <code>down</code>: A virtual interface that is BGP down.</p> </li> <li> <p> <code>deleting</code>: A virtual interface is in this state immediately after calling <a>DeleteVirtualInterface</a> until it can no longer forward traffic.</p> </li> <li> <p> <code>deleted</code>: A virtual interface that cannot forward traffic.</p> </li> <li> <p> <code>rejected</code>: The virtual interface owner has declined creation of the virtual interface. If a virtual interface in the <code>Confirming</code> state is deleted by the virtual interface owner, the virtual interface enters the <code>Rejected</code> state.</p> </li> <li> <p> <code>unknown</code>: The state of the virtual interface is not available.</p> </li> </ul></p>
     #[serde(rename = "virtualInterfaceState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub virtual_interface_state: Option<String>,
+    pub virtual_interface_state: Option<VirtualInterfaceState>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -317,7 +616,7 @@ pub struct ConfirmTransitVirtualInterfaceResponse {
     /// <p><p>The state of the virtual interface. The following are the possible values:</p> <ul> <li> <p> <code>confirming</code>: The creation of the virtual interface is pending confirmation from the virtual interface owner. If the owner of the virtual interface is different from the owner of the connection on which it is provisioned, then the virtual interface will remain in this state until it is confirmed by the virtual interface owner.</p> </li> <li> <p> <code>verifying</code>: This state only applies to public virtual interfaces. Each public virtual interface needs validation before the virtual interface can be created.</p> </li> <li> <p> <code>pending</code>: A virtual interface is in this state from the time that it is created until the virtual interface is ready to forward traffic.</p> </li> <li> <p> <code>available</code>: A virtual interface that is able to forward traffic.</p> </li> <li> <p> <code>down</code>: A virtual interface that is BGP down.</p> </li> <li> <p> <code>deleting</code>: A virtual interface is in this state immediately after calling <a>DeleteVirtualInterface</a> until it can no longer forward traffic.</p> </li> <li> <p> <code>deleted</code>: A virtual interface that cannot forward traffic.</p> </li> <li> <p> <code>rejected</code>: The virtual interface owner has declined creation of the virtual interface. If a virtual interface in the <code>Confirming</code> state is deleted by the virtual interface owner, the virtual interface enters the <code>Rejected</code> state.</p> </li> <li> <p> <code>unknown</code>: The state of the virtual interface is not available.</p> </li> </ul></p>
     #[serde(rename = "virtualInterfaceState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub virtual_interface_state: Option<String>,
+    pub virtual_interface_state: Option<VirtualInterfaceState>,
 }
 
 /// <p>Information about an AWS Direct Connect connection.</p>
@@ -347,11 +646,11 @@ pub struct Connection {
     /// <p><p>The state of the connection. The following are the possible values:</p> <ul> <li> <p> <code>ordering</code>: The initial state of a hosted connection provisioned on an interconnect. The connection stays in the ordering state until the owner of the hosted connection confirms or declines the connection order.</p> </li> <li> <p> <code>requested</code>: The initial state of a standard connection. The connection stays in the requested state until the Letter of Authorization (LOA) is sent to the customer.</p> </li> <li> <p> <code>pending</code>: The connection has been approved and is being initialized.</p> </li> <li> <p> <code>available</code>: The network link is up and the connection is ready for use.</p> </li> <li> <p> <code>down</code>: The network link is down.</p> </li> <li> <p> <code>deleting</code>: The connection is being deleted.</p> </li> <li> <p> <code>deleted</code>: The connection has been deleted.</p> </li> <li> <p> <code>rejected</code>: A hosted connection in the <code>ordering</code> state enters the <code>rejected</code> state if it is deleted by the customer.</p> </li> <li> <p> <code>unknown</code>: The state of the connection is not available.</p> </li> </ul></p>
     #[serde(rename = "connectionState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub connection_state: Option<String>,
+    pub connection_state: Option<ConnectionState>,
     /// <p>Indicates whether the connection supports a secondary BGP peer in the same address family (IPv4/IPv6).</p>
     #[serde(rename = "hasLogicalRedundancy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub has_logical_redundancy: Option<String>,
+    pub has_logical_redundancy: Option<HasLogicalRedundancy>,
     /// <p>Indicates whether jumbo frames (9001 MTU) are supported.</p>
     #[serde(rename = "jumboFrameCapable")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -362,8 +661,13 @@ pub struct Connection {
     pub lag_id: Option<String>,
     /// <p>The time of the most recent call to <a>DescribeLoa</a> for this connection.</p>
     #[serde(rename = "loaIssueTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub loa_issue_time: Option<f64>,
+    pub loa_issue_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The location of the connection.</p>
     #[serde(rename = "location")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -394,6 +698,134 @@ pub struct Connection {
     pub vlan: Option<i64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ConnectionState {
+    /// `ordering`
+    Ordering,
+    /// `requested`
+    Requested,
+    /// `pending`
+    Pending,
+    /// `available`
+    Available,
+    /// `down`
+    Down,
+    /// `deleting`
+    Deleting,
+    /// `deleted`
+    Deleted,
+    /// `rejected`
+    Rejected,
+    /// `unknown`
+    UnknownValue,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ConnectionState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ConnectionState::Ordering => "ordering",
+            ConnectionState::Requested => "requested",
+            ConnectionState::Pending => "pending",
+            ConnectionState::Available => "available",
+            ConnectionState::Down => "down",
+            ConnectionState::Deleting => "deleting",
+            ConnectionState::Deleted => "deleted",
+            ConnectionState::Rejected => "rejected",
+            ConnectionState::UnknownValue => "unknown",
+            ConnectionState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ConnectionState {
+    fn default() -> ConnectionState {
+        ConnectionState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ConnectionState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ConnectionState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ConnectionState {
+    fn from(value: &'a str) -> ConnectionState {
+        match value {
+            "ordering" => ConnectionState::Ordering,
+            "requested" => ConnectionState::Requested,
+            "pending" => ConnectionState::Pending,
+            "available" => ConnectionState::Available,
+            "down" => ConnectionState::Down,
+            "deleting" => ConnectionState::Deleting,
+            "deleted" => ConnectionState::Deleted,
+            "rejected" => ConnectionState::Rejected,
+            "unknown" => ConnectionState::UnknownValue,
+            _ => ConnectionState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ConnectionState {
+    fn from(value: String) -> ConnectionState {
+        match value.as_str() {
+            "ordering" => ConnectionState::Ordering,
+            "requested" => ConnectionState::Requested,
+            "pending" => ConnectionState::Pending,
+            "available" => ConnectionState::Available,
+            "down" => ConnectionState::Down,
+            "deleting" => ConnectionState::Deleting,
+            "deleted" => ConnectionState::Deleted,
+            "rejected" => ConnectionState::Rejected,
+            "unknown" => ConnectionState::UnknownValue,
+            _ => ConnectionState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ConnectionState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ConnectionState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ConnectionState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ConnectionState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ConnectionState {
+    fn deserialize<D>(deserializer: D) -> Result<ConnectionState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Connections {
@@ -746,7 +1178,7 @@ pub struct DeleteInterconnectResponse {
     /// <p><p>The state of the interconnect. The following are the possible values:</p> <ul> <li> <p> <code>requested</code>: The initial state of an interconnect. The interconnect stays in the requested state until the Letter of Authorization (LOA) is sent to the customer.</p> </li> <li> <p> <code>pending</code>: The interconnect is approved, and is being initialized.</p> </li> <li> <p> <code>available</code>: The network link is up, and the interconnect is ready for use.</p> </li> <li> <p> <code>down</code>: The network link is down.</p> </li> <li> <p> <code>deleting</code>: The interconnect is being deleted.</p> </li> <li> <p> <code>deleted</code>: The interconnect is deleted.</p> </li> <li> <p> <code>unknown</code>: The state of the interconnect is not available.</p> </li> </ul></p>
     #[serde(rename = "interconnectState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub interconnect_state: Option<String>,
+    pub interconnect_state: Option<InterconnectState>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -771,7 +1203,7 @@ pub struct DeleteVirtualInterfaceResponse {
     /// <p><p>The state of the virtual interface. The following are the possible values:</p> <ul> <li> <p> <code>confirming</code>: The creation of the virtual interface is pending confirmation from the virtual interface owner. If the owner of the virtual interface is different from the owner of the connection on which it is provisioned, then the virtual interface will remain in this state until it is confirmed by the virtual interface owner.</p> </li> <li> <p> <code>verifying</code>: This state only applies to public virtual interfaces. Each public virtual interface needs validation before the virtual interface can be created.</p> </li> <li> <p> <code>pending</code>: A virtual interface is in this state from the time that it is created until the virtual interface is ready to forward traffic.</p> </li> <li> <p> <code>available</code>: A virtual interface that is able to forward traffic.</p> </li> <li> <p> <code>down</code>: A virtual interface that is BGP down.</p> </li> <li> <p> <code>deleting</code>: A virtual interface is in this state immediately after calling <a>DeleteVirtualInterface</a> until it can no longer forward traffic.</p> </li> <li> <p> <code>deleted</code>: A virtual interface that cannot forward traffic.</p> </li> <li> <p> <code>rejected</code>: The virtual interface owner has declined creation of the virtual interface. If a virtual interface in the <code>Confirming</code> state is deleted by the virtual interface owner, the virtual interface enters the <code>Rejected</code> state.</p> </li> <li> <p> <code>unknown</code>: The state of the virtual interface is not available.</p> </li> </ul></p>
     #[serde(rename = "virtualInterfaceState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub virtual_interface_state: Option<String>,
+    pub virtual_interface_state: Option<VirtualInterfaceState>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -783,7 +1215,7 @@ pub struct DescribeConnectionLoaRequest {
     /// <p>The standard media type for the LOA-CFA document. The only supported value is application/pdf.</p>
     #[serde(rename = "loaContentType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub loa_content_type: Option<String>,
+    pub loa_content_type: Option<LoaContentType>,
     /// <p>The name of the APN partner or service provider who establishes connectivity on your behalf. If you specify this parameter, the LOA-CFA lists the provider name alongside your company name as the requester of the cross connect.</p>
     #[serde(rename = "providerName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -978,7 +1410,7 @@ pub struct DescribeInterconnectLoaRequest {
     /// <p>The standard media type for the LOA-CFA document. The only supported value is application/pdf.</p>
     #[serde(rename = "loaContentType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub loa_content_type: Option<String>,
+    pub loa_content_type: Option<LoaContentType>,
     /// <p>The name of the service provider who establishes connectivity on your behalf. If you supply this parameter, the LOA-CFA lists the provider name alongside your company name as the requester of the cross connect.</p>
     #[serde(rename = "providerName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1021,7 +1453,7 @@ pub struct DescribeLoaRequest {
     /// <p>The standard media type for the LOA-CFA document. The only supported value is application/pdf.</p>
     #[serde(rename = "loaContentType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub loa_content_type: Option<String>,
+    pub loa_content_type: Option<LoaContentType>,
     /// <p>The name of the service provider who establishes connectivity on your behalf. If you specify this parameter, the LOA-CFA lists the provider name alongside your company name as the requester of the cross connect.</p>
     #[serde(rename = "providerName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1077,7 +1509,7 @@ pub struct DirectConnectGateway {
     /// <p><p>The state of the Direct Connect gateway. The following are the possible values:</p> <ul> <li> <p> <code>pending</code>: The initial state after calling <a>CreateDirectConnectGateway</a>.</p> </li> <li> <p> <code>available</code>: The Direct Connect gateway is ready for use.</p> </li> <li> <p> <code>deleting</code>: The initial state after calling <a>DeleteDirectConnectGateway</a>.</p> </li> <li> <p> <code>deleted</code>: The Direct Connect gateway is deleted and cannot pass traffic.</p> </li> </ul></p>
     #[serde(rename = "directConnectGatewayState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub direct_connect_gateway_state: Option<String>,
+    pub direct_connect_gateway_state: Option<DirectConnectGatewayState>,
     /// <p>The ID of the AWS account that owns the Direct Connect gateway.</p>
     #[serde(rename = "ownerAccount")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1107,7 +1539,7 @@ pub struct DirectConnectGatewayAssociation {
     /// <p><p>The state of the association. The following are the possible values:</p> <ul> <li> <p> <code>associating</code>: The initial state after calling <a>CreateDirectConnectGatewayAssociation</a>.</p> </li> <li> <p> <code>associated</code>: The Direct Connect gateway and virtual private gateway or transit gateway are successfully associated and ready to pass traffic.</p> </li> <li> <p> <code>disassociating</code>: The initial state after calling <a>DeleteDirectConnectGatewayAssociation</a>.</p> </li> <li> <p> <code>disassociated</code>: The virtual private gateway or transit gateway is disassociated from the Direct Connect gateway. Traffic flow between the Direct Connect gateway and virtual private gateway or transit gateway is stopped.</p> </li> </ul></p>
     #[serde(rename = "associationState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub association_state: Option<String>,
+    pub association_state: Option<DirectConnectGatewayAssociationState>,
     /// <p>The ID of the Direct Connect gateway.</p>
     #[serde(rename = "directConnectGatewayId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1161,58 +1593,768 @@ pub struct DirectConnectGatewayAssociationProposal {
     /// <p><p>The state of the proposal. The following are possible values:</p> <ul> <li> <p> <code>accepted</code>: The proposal has been accepted. The Direct Connect gateway association is available to use in this state.</p> </li> <li> <p> <code>deleted</code>: The proposal has been deleted by the owner that made the proposal. The Direct Connect gateway association cannot be used in this state.</p> </li> <li> <p> <code>requested</code>: The proposal has been requested. The Direct Connect gateway association cannot be used in this state.</p> </li> </ul></p>
     #[serde(rename = "proposalState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub proposal_state: Option<String>,
+    pub proposal_state: Option<DirectConnectGatewayAssociationProposalState>,
     /// <p>The Amazon VPC prefixes to advertise to the Direct Connect gateway.</p>
     #[serde(rename = "requestedAllowedPrefixesToDirectConnectGateway")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub requested_allowed_prefixes_to_direct_connect_gateway: Option<Vec<RouteFilterPrefix>>,
 }
 
-/// <p>Information about an attachment between a Direct Connect gateway and a virtual interface.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct DirectConnectGatewayAttachment {
-    /// <p><p>The state of the attachment. The following are the possible values:</p> <ul> <li> <p> <code>attaching</code>: The initial state after a virtual interface is created using the Direct Connect gateway.</p> </li> <li> <p> <code>attached</code>: The Direct Connect gateway and virtual interface are attached and ready to pass traffic.</p> </li> <li> <p> <code>detaching</code>: The initial state after calling <a>DeleteVirtualInterface</a>.</p> </li> <li> <p> <code>detached</code>: The virtual interface is detached from the Direct Connect gateway. Traffic flow between the Direct Connect gateway and virtual interface is stopped.</p> </li> </ul></p>
-    #[serde(rename = "attachmentState")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub attachment_state: Option<String>,
-    /// <p>The type of attachment.</p>
-    #[serde(rename = "attachmentType")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub attachment_type: Option<String>,
-    /// <p>The ID of the Direct Connect gateway.</p>
-    #[serde(rename = "directConnectGatewayId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub direct_connect_gateway_id: Option<String>,
-    /// <p>The error message if the state of an object failed to advance.</p>
-    #[serde(rename = "stateChangeError")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub state_change_error: Option<String>,
-    /// <p>The ID of the virtual interface.</p>
-    #[serde(rename = "virtualInterfaceId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub virtual_interface_id: Option<String>,
-    /// <p>The ID of the AWS account that owns the virtual interface.</p>
-    #[serde(rename = "virtualInterfaceOwnerAccount")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub virtual_interface_owner_account: Option<String>,
-    /// <p>The AWS Region where the virtual interface is located.</p>
-    #[serde(rename = "virtualInterfaceRegion")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub virtual_interface_region: Option<String>,
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DirectConnectGatewayAssociationProposalState {
+    /// `requested`
+    Requested,
+    /// `accepted`
+    Accepted,
+    /// `deleted`
+    Deleted,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DirectConnectGatewayAssociationProposalState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DirectConnectGatewayAssociationProposalState::Requested => "requested",
+            DirectConnectGatewayAssociationProposalState::Accepted => "accepted",
+            DirectConnectGatewayAssociationProposalState::Deleted => "deleted",
+            DirectConnectGatewayAssociationProposalState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DirectConnectGatewayAssociationProposalState {
+    fn default() -> DirectConnectGatewayAssociationProposalState {
+        DirectConnectGatewayAssociationProposalState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DirectConnectGatewayAssociationProposalState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DirectConnectGatewayAssociationProposalState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DirectConnectGatewayAssociationProposalState {
+    fn from(value: &'a str) -> DirectConnectGatewayAssociationProposalState {
+        match value {
+            "requested" => DirectConnectGatewayAssociationProposalState::Requested,
+            "accepted" => DirectConnectGatewayAssociationProposalState::Accepted,
+            "deleted" => DirectConnectGatewayAssociationProposalState::Deleted,
+            _ => DirectConnectGatewayAssociationProposalState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DirectConnectGatewayAssociationProposalState {
+    fn from(value: String) -> DirectConnectGatewayAssociationProposalState {
+        match value.as_str() {
+            "requested" => DirectConnectGatewayAssociationProposalState::Requested,
+            "accepted" => DirectConnectGatewayAssociationProposalState::Accepted,
+            "deleted" => DirectConnectGatewayAssociationProposalState::Deleted,
+            _ => DirectConnectGatewayAssociationProposalState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DirectConnectGatewayAssociationProposalState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DirectConnectGatewayAssociationProposalState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DirectConnectGatewayAssociationProposalState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DirectConnectGatewayAssociationProposalState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DirectConnectGatewayAssociationProposalState {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> Result<DirectConnectGatewayAssociationProposalState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DirectConnectGatewayAssociationState {
+    /// `associating`
+    Associating,
+    /// `associated`
+    Associated,
+    /// `disassociating`
+    Disassociating,
+    /// `disassociated`
+    Disassociated,
+    /// `updating`
+    Updating,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DirectConnectGatewayAssociationState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DirectConnectGatewayAssociationState::Associating => "associating",
+            DirectConnectGatewayAssociationState::Associated => "associated",
+            DirectConnectGatewayAssociationState::Disassociating => "disassociating",
+            DirectConnectGatewayAssociationState::Disassociated => "disassociated",
+            DirectConnectGatewayAssociationState::Updating => "updating",
+            DirectConnectGatewayAssociationState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DirectConnectGatewayAssociationState {
+    fn default() -> DirectConnectGatewayAssociationState {
+        DirectConnectGatewayAssociationState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DirectConnectGatewayAssociationState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DirectConnectGatewayAssociationState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DirectConnectGatewayAssociationState {
+    fn from(value: &'a str) -> DirectConnectGatewayAssociationState {
+        match value {
+            "associating" => DirectConnectGatewayAssociationState::Associating,
+            "associated" => DirectConnectGatewayAssociationState::Associated,
+            "disassociating" => DirectConnectGatewayAssociationState::Disassociating,
+            "disassociated" => DirectConnectGatewayAssociationState::Disassociated,
+            "updating" => DirectConnectGatewayAssociationState::Updating,
+            _ => DirectConnectGatewayAssociationState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DirectConnectGatewayAssociationState {
+    fn from(value: String) -> DirectConnectGatewayAssociationState {
+        match value.as_str() {
+            "associating" => DirectConnectGatewayAssociationState::Associating,
+            "associated" => DirectConnectGatewayAssociationState::Associated,
+            "disassociating" => DirectConnectGatewayAssociationState::Disassociating,
+            "disassociated" => DirectConnectGatewayAssociationState::Disassociated,
+            "updating" => DirectConnectGatewayAssociationState::Updating,
+            _ => DirectConnectGatewayAssociationState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DirectConnectGatewayAssociationState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DirectConnectGatewayAssociationState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DirectConnectGatewayAssociationState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DirectConnectGatewayAssociationState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DirectConnectGatewayAssociationState {
+    fn deserialize<D>(deserializer: D) -> Result<DirectConnectGatewayAssociationState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Information about an attachment between a Direct Connect gateway and a virtual interface.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct DirectConnectGatewayAttachment {
+    /// <p><p>The state of the attachment. The following are the possible values:</p> <ul> <li> <p> <code>attaching</code>: The initial state after a virtual interface is created using the Direct Connect gateway.</p> </li> <li> <p> <code>attached</code>: The Direct Connect gateway and virtual interface are attached and ready to pass traffic.</p> </li> <li> <p> <code>detaching</code>: The initial state after calling <a>DeleteVirtualInterface</a>.</p> </li> <li> <p> <code>detached</code>: The virtual interface is detached from the Direct Connect gateway. Traffic flow between the Direct Connect gateway and virtual interface is stopped.</p> </li> </ul></p>
+    #[serde(rename = "attachmentState")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attachment_state: Option<DirectConnectGatewayAttachmentState>,
+    /// <p>The type of attachment.</p>
+    #[serde(rename = "attachmentType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attachment_type: Option<DirectConnectGatewayAttachmentType>,
+    /// <p>The ID of the Direct Connect gateway.</p>
+    #[serde(rename = "directConnectGatewayId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub direct_connect_gateway_id: Option<String>,
+    /// <p>The error message if the state of an object failed to advance.</p>
+    #[serde(rename = "stateChangeError")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state_change_error: Option<String>,
+    /// <p>The ID of the virtual interface.</p>
+    #[serde(rename = "virtualInterfaceId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub virtual_interface_id: Option<String>,
+    /// <p>The ID of the AWS account that owns the virtual interface.</p>
+    #[serde(rename = "virtualInterfaceOwnerAccount")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub virtual_interface_owner_account: Option<String>,
+    /// <p>The AWS Region where the virtual interface is located.</p>
+    #[serde(rename = "virtualInterfaceRegion")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub virtual_interface_region: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DirectConnectGatewayAttachmentState {
+    /// `attaching`
+    Attaching,
+    /// `attached`
+    Attached,
+    /// `detaching`
+    Detaching,
+    /// `detached`
+    Detached,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DirectConnectGatewayAttachmentState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DirectConnectGatewayAttachmentState::Attaching => "attaching",
+            DirectConnectGatewayAttachmentState::Attached => "attached",
+            DirectConnectGatewayAttachmentState::Detaching => "detaching",
+            DirectConnectGatewayAttachmentState::Detached => "detached",
+            DirectConnectGatewayAttachmentState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DirectConnectGatewayAttachmentState {
+    fn default() -> DirectConnectGatewayAttachmentState {
+        DirectConnectGatewayAttachmentState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DirectConnectGatewayAttachmentState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DirectConnectGatewayAttachmentState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DirectConnectGatewayAttachmentState {
+    fn from(value: &'a str) -> DirectConnectGatewayAttachmentState {
+        match value {
+            "attaching" => DirectConnectGatewayAttachmentState::Attaching,
+            "attached" => DirectConnectGatewayAttachmentState::Attached,
+            "detaching" => DirectConnectGatewayAttachmentState::Detaching,
+            "detached" => DirectConnectGatewayAttachmentState::Detached,
+            _ => DirectConnectGatewayAttachmentState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DirectConnectGatewayAttachmentState {
+    fn from(value: String) -> DirectConnectGatewayAttachmentState {
+        match value.as_str() {
+            "attaching" => DirectConnectGatewayAttachmentState::Attaching,
+            "attached" => DirectConnectGatewayAttachmentState::Attached,
+            "detaching" => DirectConnectGatewayAttachmentState::Detaching,
+            "detached" => DirectConnectGatewayAttachmentState::Detached,
+            _ => DirectConnectGatewayAttachmentState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DirectConnectGatewayAttachmentState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DirectConnectGatewayAttachmentState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DirectConnectGatewayAttachmentState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DirectConnectGatewayAttachmentState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DirectConnectGatewayAttachmentState {
+    fn deserialize<D>(deserializer: D) -> Result<DirectConnectGatewayAttachmentState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DirectConnectGatewayAttachmentType {
+    /// `TransitVirtualInterface`
+    TransitVirtualInterface,
+    /// `PrivateVirtualInterface`
+    PrivateVirtualInterface,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DirectConnectGatewayAttachmentType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DirectConnectGatewayAttachmentType::TransitVirtualInterface => {
+                "TransitVirtualInterface"
+            }
+            DirectConnectGatewayAttachmentType::PrivateVirtualInterface => {
+                "PrivateVirtualInterface"
+            }
+            DirectConnectGatewayAttachmentType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DirectConnectGatewayAttachmentType {
+    fn default() -> DirectConnectGatewayAttachmentType {
+        DirectConnectGatewayAttachmentType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DirectConnectGatewayAttachmentType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DirectConnectGatewayAttachmentType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DirectConnectGatewayAttachmentType {
+    fn from(value: &'a str) -> DirectConnectGatewayAttachmentType {
+        match value {
+            "TransitVirtualInterface" => {
+                DirectConnectGatewayAttachmentType::TransitVirtualInterface
+            }
+            "PrivateVirtualInterface" => {
+                DirectConnectGatewayAttachmentType::PrivateVirtualInterface
+            }
+            _ => DirectConnectGatewayAttachmentType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DirectConnectGatewayAttachmentType {
+    fn from(value: String) -> DirectConnectGatewayAttachmentType {
+        match value.as_str() {
+            "TransitVirtualInterface" => {
+                DirectConnectGatewayAttachmentType::TransitVirtualInterface
+            }
+            "PrivateVirtualInterface" => {
+                DirectConnectGatewayAttachmentType::PrivateVirtualInterface
+            }
+            _ => DirectConnectGatewayAttachmentType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DirectConnectGatewayAttachmentType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DirectConnectGatewayAttachmentType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DirectConnectGatewayAttachmentType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DirectConnectGatewayAttachmentType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DirectConnectGatewayAttachmentType {
+    fn deserialize<D>(deserializer: D) -> Result<DirectConnectGatewayAttachmentType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DirectConnectGatewayState {
+    /// `pending`
+    Pending,
+    /// `available`
+    Available,
+    /// `deleting`
+    Deleting,
+    /// `deleted`
+    Deleted,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DirectConnectGatewayState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DirectConnectGatewayState::Pending => "pending",
+            DirectConnectGatewayState::Available => "available",
+            DirectConnectGatewayState::Deleting => "deleting",
+            DirectConnectGatewayState::Deleted => "deleted",
+            DirectConnectGatewayState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DirectConnectGatewayState {
+    fn default() -> DirectConnectGatewayState {
+        DirectConnectGatewayState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DirectConnectGatewayState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DirectConnectGatewayState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DirectConnectGatewayState {
+    fn from(value: &'a str) -> DirectConnectGatewayState {
+        match value {
+            "pending" => DirectConnectGatewayState::Pending,
+            "available" => DirectConnectGatewayState::Available,
+            "deleting" => DirectConnectGatewayState::Deleting,
+            "deleted" => DirectConnectGatewayState::Deleted,
+            _ => DirectConnectGatewayState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DirectConnectGatewayState {
+    fn from(value: String) -> DirectConnectGatewayState {
+        match value.as_str() {
+            "pending" => DirectConnectGatewayState::Pending,
+            "available" => DirectConnectGatewayState::Available,
+            "deleting" => DirectConnectGatewayState::Deleting,
+            "deleted" => DirectConnectGatewayState::Deleted,
+            _ => DirectConnectGatewayState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DirectConnectGatewayState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DirectConnectGatewayState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DirectConnectGatewayState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DirectConnectGatewayState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DirectConnectGatewayState {
+    fn deserialize<D>(deserializer: D) -> Result<DirectConnectGatewayState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct DisassociateConnectionFromLagRequest {
+    /// <p>The ID of the connection.</p>
+    #[serde(rename = "connectionId")]
+    pub connection_id: String,
+    /// <p>The ID of the LAG.</p>
+    #[serde(rename = "lagId")]
+    pub lag_id: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum GatewayType {
+    /// `virtualPrivateGateway`
+    VirtualPrivateGateway,
+    /// `transitGateway`
+    TransitGateway,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl GatewayType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            GatewayType::VirtualPrivateGateway => "virtualPrivateGateway",
+            GatewayType::TransitGateway => "transitGateway",
+            GatewayType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for GatewayType {
+    fn default() -> GatewayType {
+        GatewayType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for GatewayType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for GatewayType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for GatewayType {
+    fn from(value: &'a str) -> GatewayType {
+        match value {
+            "virtualPrivateGateway" => GatewayType::VirtualPrivateGateway,
+            "transitGateway" => GatewayType::TransitGateway,
+            _ => GatewayType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for GatewayType {
+    fn from(value: String) -> GatewayType {
+        match value.as_str() {
+            "virtualPrivateGateway" => GatewayType::VirtualPrivateGateway,
+            "transitGateway" => GatewayType::TransitGateway,
+            _ => GatewayType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for GatewayType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<GatewayType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for GatewayType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for GatewayType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for GatewayType {
+    fn deserialize<D>(deserializer: D) -> Result<GatewayType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum HasLogicalRedundancy {
+    /// `unknown`
+    UnknownValue,
+    /// `yes`
+    Yes,
+    /// `no`
+    No,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl HasLogicalRedundancy {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            HasLogicalRedundancy::UnknownValue => "unknown",
+            HasLogicalRedundancy::Yes => "yes",
+            HasLogicalRedundancy::No => "no",
+            HasLogicalRedundancy::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for HasLogicalRedundancy {
+    fn default() -> HasLogicalRedundancy {
+        HasLogicalRedundancy::Unknown(String::new())
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct DisassociateConnectionFromLagRequest {
-    /// <p>The ID of the connection.</p>
-    #[serde(rename = "connectionId")]
-    pub connection_id: String,
-    /// <p>The ID of the LAG.</p>
-    #[serde(rename = "lagId")]
-    pub lag_id: String,
+impl AsRef<str> for HasLogicalRedundancy {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for HasLogicalRedundancy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for HasLogicalRedundancy {
+    fn from(value: &'a str) -> HasLogicalRedundancy {
+        match value {
+            "unknown" => HasLogicalRedundancy::UnknownValue,
+            "yes" => HasLogicalRedundancy::Yes,
+            "no" => HasLogicalRedundancy::No,
+            _ => HasLogicalRedundancy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for HasLogicalRedundancy {
+    fn from(value: String) -> HasLogicalRedundancy {
+        match value.as_str() {
+            "unknown" => HasLogicalRedundancy::UnknownValue,
+            "yes" => HasLogicalRedundancy::Yes,
+            "no" => HasLogicalRedundancy::No,
+            _ => HasLogicalRedundancy::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for HasLogicalRedundancy {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<HasLogicalRedundancy, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for HasLogicalRedundancy {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for HasLogicalRedundancy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for HasLogicalRedundancy {
+    fn deserialize<D>(deserializer: D) -> Result<HasLogicalRedundancy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about an interconnect.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1232,7 +2374,7 @@ pub struct Interconnect {
     /// <p>Indicates whether the interconnect supports a secondary BGP in the same address family (IPv4/IPv6).</p>
     #[serde(rename = "hasLogicalRedundancy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub has_logical_redundancy: Option<String>,
+    pub has_logical_redundancy: Option<HasLogicalRedundancy>,
     /// <p>The ID of the interconnect.</p>
     #[serde(rename = "interconnectId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1244,7 +2386,7 @@ pub struct Interconnect {
     /// <p><p>The state of the interconnect. The following are the possible values:</p> <ul> <li> <p> <code>requested</code>: The initial state of an interconnect. The interconnect stays in the requested state until the Letter of Authorization (LOA) is sent to the customer.</p> </li> <li> <p> <code>pending</code>: The interconnect is approved, and is being initialized.</p> </li> <li> <p> <code>available</code>: The network link is up, and the interconnect is ready for use.</p> </li> <li> <p> <code>down</code>: The network link is down.</p> </li> <li> <p> <code>deleting</code>: The interconnect is being deleted.</p> </li> <li> <p> <code>deleted</code>: The interconnect is deleted.</p> </li> <li> <p> <code>unknown</code>: The state of the interconnect is not available.</p> </li> </ul></p>
     #[serde(rename = "interconnectState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub interconnect_state: Option<String>,
+    pub interconnect_state: Option<InterconnectState>,
     /// <p>Indicates whether jumbo frames (9001 MTU) are supported.</p>
     #[serde(rename = "jumboFrameCapable")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1255,8 +2397,13 @@ pub struct Interconnect {
     pub lag_id: Option<String>,
     /// <p>The time of the most recent call to <a>DescribeLoa</a> for this connection.</p>
     #[serde(rename = "loaIssueTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub loa_issue_time: Option<f64>,
+    pub loa_issue_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The location of the connection.</p>
     #[serde(rename = "location")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1275,6 +2422,124 @@ pub struct Interconnect {
     pub tags: Option<Vec<Tag>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum InterconnectState {
+    /// `requested`
+    Requested,
+    /// `pending`
+    Pending,
+    /// `available`
+    Available,
+    /// `down`
+    Down,
+    /// `deleting`
+    Deleting,
+    /// `deleted`
+    Deleted,
+    /// `unknown`
+    UnknownValue,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl InterconnectState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InterconnectState::Requested => "requested",
+            InterconnectState::Pending => "pending",
+            InterconnectState::Available => "available",
+            InterconnectState::Down => "down",
+            InterconnectState::Deleting => "deleting",
+            InterconnectState::Deleted => "deleted",
+            InterconnectState::UnknownValue => "unknown",
+            InterconnectState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for InterconnectState {
+    fn default() -> InterconnectState {
+        InterconnectState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for InterconnectState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for InterconnectState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for InterconnectState {
+    fn from(value: &'a str) -> InterconnectState {
+        match value {
+            "requested" => InterconnectState::Requested,
+            "pending" => InterconnectState::Pending,
+            "available" => InterconnectState::Available,
+            "down" => InterconnectState::Down,
+            "deleting" => InterconnectState::Deleting,
+            "deleted" => InterconnectState::Deleted,
+            "unknown" => InterconnectState::UnknownValue,
+            _ => InterconnectState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InterconnectState {
+    fn from(value: String) -> InterconnectState {
+        match value.as_str() {
+            "requested" => InterconnectState::Requested,
+            "pending" => InterconnectState::Pending,
+            "available" => InterconnectState::Available,
+            "down" => InterconnectState::Down,
+            "deleting" => InterconnectState::Deleting,
+            "deleted" => InterconnectState::Deleted,
+            "unknown" => InterconnectState::UnknownValue,
+            _ => InterconnectState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for InterconnectState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<InterconnectState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for InterconnectState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for InterconnectState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InterconnectState {
+    fn deserialize<D>(deserializer: D) -> Result<InterconnectState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Interconnects {
@@ -1311,7 +2576,7 @@ pub struct Lag {
     /// <p>Indicates whether the LAG supports a secondary BGP peer in the same address family (IPv4/IPv6).</p>
     #[serde(rename = "hasLogicalRedundancy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub has_logical_redundancy: Option<String>,
+    pub has_logical_redundancy: Option<HasLogicalRedundancy>,
     /// <p>Indicates whether jumbo frames (9001 MTU) are supported.</p>
     #[serde(rename = "jumboFrameCapable")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1327,7 +2592,7 @@ pub struct Lag {
     /// <p><p>The state of the LAG. The following are the possible values:</p> <ul> <li> <p> <code>requested</code>: The initial state of a LAG. The LAG stays in the requested state until the Letter of Authorization (LOA) is available.</p> </li> <li> <p> <code>pending</code>: The LAG has been approved and is being initialized.</p> </li> <li> <p> <code>available</code>: The network link is established and the LAG is ready for use.</p> </li> <li> <p> <code>down</code>: The network link is down.</p> </li> <li> <p> <code>deleting</code>: The LAG is being deleted.</p> </li> <li> <p> <code>deleted</code>: The LAG is deleted.</p> </li> <li> <p> <code>unknown</code>: The state of the LAG is not available.</p> </li> </ul></p>
     #[serde(rename = "lagState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub lag_state: Option<String>,
+    pub lag_state: Option<LagState>,
     /// <p>The location of the LAG.</p>
     #[serde(rename = "location")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1358,6 +2623,124 @@ pub struct Lag {
     pub tags: Option<Vec<Tag>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LagState {
+    /// `requested`
+    Requested,
+    /// `pending`
+    Pending,
+    /// `available`
+    Available,
+    /// `down`
+    Down,
+    /// `deleting`
+    Deleting,
+    /// `deleted`
+    Deleted,
+    /// `unknown`
+    UnknownValue,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LagState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LagState::Requested => "requested",
+            LagState::Pending => "pending",
+            LagState::Available => "available",
+            LagState::Down => "down",
+            LagState::Deleting => "deleting",
+            LagState::Deleted => "deleted",
+            LagState::UnknownValue => "unknown",
+            LagState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LagState {
+    fn default() -> LagState {
+        LagState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LagState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LagState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LagState {
+    fn from(value: &'a str) -> LagState {
+        match value {
+            "requested" => LagState::Requested,
+            "pending" => LagState::Pending,
+            "available" => LagState::Available,
+            "down" => LagState::Down,
+            "deleting" => LagState::Deleting,
+            "deleted" => LagState::Deleted,
+            "unknown" => LagState::UnknownValue,
+            _ => LagState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LagState {
+    fn from(value: String) -> LagState {
+        match value.as_str() {
+            "requested" => LagState::Requested,
+            "pending" => LagState::Pending,
+            "available" => LagState::Available,
+            "down" => LagState::Down,
+            "deleting" => LagState::Deleting,
+            "deleted" => LagState::Deleted,
+            "unknown" => LagState::UnknownValue,
+            _ => LagState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LagState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LagState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LagState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LagState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LagState {
+    fn deserialize<D>(deserializer: D) -> Result<LagState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Lags {
@@ -1383,9 +2766,97 @@ pub struct Loa {
     /// <p>The standard media type for the LOA-CFA document. The only supported value is application/pdf.</p>
     #[serde(rename = "loaContentType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub loa_content_type: Option<String>,
+    pub loa_content_type: Option<LoaContentType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LoaContentType {
+    /// `application/pdf`
+    ApplicationPdf,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LoaContentType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LoaContentType::ApplicationPdf => "application/pdf",
+            LoaContentType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LoaContentType {
+    fn default() -> LoaContentType {
+        LoaContentType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LoaContentType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LoaContentType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LoaContentType {
+    fn from(value: &'a str) -> LoaContentType {
+        match value {
+            "application/pdf" => LoaContentType::ApplicationPdf,
+            _ => LoaContentType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LoaContentType {
+    fn from(value: String) -> LoaContentType {
+        match value.as_str() {
+            "application/pdf" => LoaContentType::ApplicationPdf,
+            _ => LoaContentType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LoaContentType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LoaContentType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LoaContentType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
 }
 
+impl ::serde::Serialize for LoaContentType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LoaContentType {
+    fn deserialize<D>(deserializer: D) -> Result<LoaContentType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about an AWS Direct Connect location.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1428,7 +2899,7 @@ pub struct NewBGPPeer {
     /// <p>The address family for the BGP peer.</p>
     #[serde(rename = "addressFamily")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub address_family: Option<String>,
+    pub address_family: Option<AddressFamily>,
     /// <p>The IP address assigned to the Amazon interface.</p>
     #[serde(rename = "amazonAddress")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1454,7 +2925,7 @@ pub struct NewPrivateVirtualInterface {
     /// <p>The address family for the BGP peer.</p>
     #[serde(rename = "addressFamily")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub address_family: Option<String>,
+    pub address_family: Option<AddressFamily>,
     /// <p>The IP address assigned to the Amazon interface.</p>
     #[serde(rename = "amazonAddress")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1501,7 +2972,7 @@ pub struct NewPrivateVirtualInterfaceAllocation {
     /// <p>The address family for the BGP peer.</p>
     #[serde(rename = "addressFamily")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub address_family: Option<String>,
+    pub address_family: Option<AddressFamily>,
     /// <p>The IP address assigned to the Amazon interface.</p>
     #[serde(rename = "amazonAddress")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1540,7 +3011,7 @@ pub struct NewPublicVirtualInterface {
     /// <p>The address family for the BGP peer.</p>
     #[serde(rename = "addressFamily")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub address_family: Option<String>,
+    pub address_family: Option<AddressFamily>,
     /// <p>The IP address assigned to the Amazon interface.</p>
     #[serde(rename = "amazonAddress")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1579,7 +3050,7 @@ pub struct NewPublicVirtualInterfaceAllocation {
     /// <p>The address family for the BGP peer.</p>
     #[serde(rename = "addressFamily")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub address_family: Option<String>,
+    pub address_family: Option<AddressFamily>,
     /// <p>The IP address assigned to the Amazon interface.</p>
     #[serde(rename = "amazonAddress")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1618,7 +3089,7 @@ pub struct NewTransitVirtualInterface {
     /// <p>The address family for the BGP peer.</p>
     #[serde(rename = "addressFamily")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub address_family: Option<String>,
+    pub address_family: Option<AddressFamily>,
     /// <p>The IP address assigned to the Amazon interface.</p>
     #[serde(rename = "amazonAddress")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1664,7 +3135,7 @@ pub struct NewTransitVirtualInterfaceAllocation {
     /// <p>The address family for the BGP peer.</p>
     #[serde(rename = "addressFamily")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub address_family: Option<String>,
+    pub address_family: Option<AddressFamily>,
     /// <p>The IP address assigned to the Amazon interface.</p>
     #[serde(rename = "amazonAddress")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1847,7 +3318,7 @@ pub struct VirtualInterface {
     /// <p>The address family for the BGP peer.</p>
     #[serde(rename = "addressFamily")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub address_family: Option<String>,
+    pub address_family: Option<AddressFamily>,
     /// <p>The IP address assigned to the Amazon interface.</p>
     #[serde(rename = "amazonAddress")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1931,7 +3402,7 @@ pub struct VirtualInterface {
     /// <p><p>The state of the virtual interface. The following are the possible values:</p> <ul> <li> <p> <code>confirming</code>: The creation of the virtual interface is pending confirmation from the virtual interface owner. If the owner of the virtual interface is different from the owner of the connection on which it is provisioned, then the virtual interface will remain in this state until it is confirmed by the virtual interface owner.</p> </li> <li> <p> <code>verifying</code>: This state only applies to public virtual interfaces. Each public virtual interface needs validation before the virtual interface can be created.</p> </li> <li> <p> <code>pending</code>: A virtual interface is in this state from the time that it is created until the virtual interface is ready to forward traffic.</p> </li> <li> <p> <code>available</code>: A virtual interface that is able to forward traffic.</p> </li> <li> <p> <code>down</code>: A virtual interface that is BGP down.</p> </li> <li> <p> <code>deleting</code>: A virtual interface is in this state immediately after calling <a>DeleteVirtualInterface</a> until it can no longer forward traffic.</p> </li> <li> <p> <code>deleted</code>: A virtual interface that cannot forward traffic.</p> </li> <li> <p> <code>rejected</code>: The virtual interface owner has declined creation of the virtual interface. If a virtual interface in the <code>Confirming</code> state is deleted by the virtual interface owner, the virtual interface enters the <code>Rejected</code> state.</p> </li> <li> <p> <code>unknown</code>: The state of the virtual interface is not available.</p> </li> </ul></p>
     #[serde(rename = "virtualInterfaceState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub virtual_interface_state: Option<String>,
+    pub virtual_interface_state: Option<VirtualInterfaceState>,
     /// <p>The type of virtual interface. The possible values are <code>private</code> and <code>public</code>.</p>
     #[serde(rename = "virtualInterfaceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1942,6 +3413,134 @@ pub struct VirtualInterface {
     pub vlan: Option<i64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum VirtualInterfaceState {
+    /// `confirming`
+    Confirming,
+    /// `verifying`
+    Verifying,
+    /// `pending`
+    Pending,
+    /// `available`
+    Available,
+    /// `down`
+    Down,
+    /// `deleting`
+    Deleting,
+    /// `deleted`
+    Deleted,
+    /// `rejected`
+    Rejected,
+    /// `unknown`
+    UnknownValue,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl VirtualInterfaceState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            VirtualInterfaceState::Confirming => "confirming",
+            VirtualInterfaceState::Verifying => "verifying",
+            VirtualInterfaceState::Pending => "pending",
+            VirtualInterfaceState::Available => "available",
+            VirtualInterfaceState::Down => "down",
+            VirtualInterfaceState::Deleting => "deleting",
+            VirtualInterfaceState::Deleted => "deleted",
+            VirtualInterfaceState::Rejected => "rejected",
+            VirtualInterfaceState::UnknownValue => "unknown",
+            VirtualInterfaceState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for VirtualInterfaceState {
+    fn default() -> VirtualInterfaceState {
+        VirtualInterfaceState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for VirtualInterfaceState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for VirtualInterfaceState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for VirtualInterfaceState {
+    fn from(value: &'a str) -> VirtualInterfaceState {
+        match value {
+            "confirming" => VirtualInterfaceState::Confirming,
+            "verifying" => VirtualInterfaceState::Verifying,
+            "pending" => VirtualInterfaceState::Pending,
+            "available" => VirtualInterfaceState::Available,
+            "down" => VirtualInterfaceState::Down,
+            "deleting" => VirtualInterfaceState::Deleting,
+            "deleted" => VirtualInterfaceState::Deleted,
+            "rejected" => VirtualInterfaceState::Rejected,
+            "unknown" => VirtualInterfaceState::UnknownValue,
+            _ => VirtualInterfaceState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for VirtualInterfaceState {
+    fn from(value: String) -> VirtualInterfaceState {
+        match value.as_str() {
+            "confirming" => VirtualInterfaceState::Confirming,
+            "verifying" => VirtualInterfaceState::Verifying,
+            "pending" => VirtualInterfaceState::Pending,
+            "available" => VirtualInterfaceState::Available,
+            "down" => VirtualInterfaceState::Down,
+            "deleting" => VirtualInterfaceState::Deleting,
+            "deleted" => VirtualInterfaceState::Deleted,
+            "rejected" => VirtualInterfaceState::Rejected,
+            "unknown" => VirtualInterfaceState::UnknownValue,
+            _ => VirtualInterfaceState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for VirtualInterfaceState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<VirtualInterfaceState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for VirtualInterfaceState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for VirtualInterfaceState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for VirtualInterfaceState {
+    fn deserialize<D>(deserializer: D) -> Result<VirtualInterfaceState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct VirtualInterfaces {
@@ -4751,11 +6350,12 @@ pub struct DirectConnectClient {
 impl DirectConnectClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> DirectConnectClient {
         DirectConnectClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Direct Connect"),
         }
     }
 
@@ -4790,6 +6390,7 @@ impl DirectConnect for DirectConnectClient {
         RusotoError<AcceptDirectConnectGatewayAssociationProposalError>,
     > {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("AcceptDirectConnectGatewayAssociationProposal");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4821,6 +6422,7 @@ impl DirectConnect for DirectConnectClient {
         input: AllocateConnectionOnInterconnectRequest,
     ) -> Result<Connection, RusotoError<AllocateConnectionOnInterconnectError>> {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("AllocateConnectionOnInterconnect");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4853,6 +6455,7 @@ impl DirectConnect for DirectConnectClient {
         input: AllocateHostedConnectionRequest,
     ) -> Result<Connection, RusotoError<AllocateHostedConnectionError>> {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("AllocateHostedConnection");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OvertureService.AllocateHostedConnection");
@@ -4880,6 +6483,7 @@ impl DirectConnect for DirectConnectClient {
         input: AllocatePrivateVirtualInterfaceRequest,
     ) -> Result<VirtualInterface, RusotoError<AllocatePrivateVirtualInterfaceError>> {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("AllocatePrivateVirtualInterface");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4912,6 +6516,7 @@ impl DirectConnect for DirectConnectClient {
         input: AllocatePublicVirtualInterfaceRequest,
     ) -> Result<VirtualInterface, RusotoError<AllocatePublicVirtualInterfaceError>> {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("AllocatePublicVirtualInterface");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4945,6 +6550,7 @@ impl DirectConnect for DirectConnectClient {
         RusotoError<AllocateTransitVirtualInterfaceError>,
     > {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("AllocateTransitVirtualInterface");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4978,6 +6584,7 @@ impl DirectConnect for DirectConnectClient {
         input: AssociateConnectionWithLagRequest,
     ) -> Result<Connection, RusotoError<AssociateConnectionWithLagError>> {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("AssociateConnectionWithLag");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OvertureService.AssociateConnectionWithLag");
@@ -5005,6 +6612,7 @@ impl DirectConnect for DirectConnectClient {
         input: AssociateHostedConnectionRequest,
     ) -> Result<Connection, RusotoError<AssociateHostedConnectionError>> {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("AssociateHostedConnection");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OvertureService.AssociateHostedConnection");
@@ -5032,6 +6640,7 @@ impl DirectConnect for DirectConnectClient {
         input: AssociateVirtualInterfaceRequest,
     ) -> Result<VirtualInterface, RusotoError<AssociateVirtualInterfaceError>> {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("AssociateVirtualInterface");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OvertureService.AssociateVirtualInterface");
@@ -5059,6 +6668,7 @@ impl DirectConnect for DirectConnectClient {
         input: ConfirmConnectionRequest,
     ) -> Result<ConfirmConnectionResponse, RusotoError<ConfirmConnectionError>> {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("ConfirmConnection");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OvertureService.ConfirmConnection");
@@ -5090,6 +6700,7 @@ impl DirectConnect for DirectConnectClient {
         RusotoError<ConfirmPrivateVirtualInterfaceError>,
     > {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("ConfirmPrivateVirtualInterface");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5124,6 +6735,7 @@ impl DirectConnect for DirectConnectClient {
         RusotoError<ConfirmPublicVirtualInterfaceError>,
     > {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("ConfirmPublicVirtualInterface");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5158,6 +6770,7 @@ impl DirectConnect for DirectConnectClient {
         RusotoError<ConfirmTransitVirtualInterfaceError>,
     > {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("ConfirmTransitVirtualInterface");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5189,6 +6802,7 @@ impl DirectConnect for DirectConnectClient {
         input: CreateBGPPeerRequest,
     ) -> Result<CreateBGPPeerResponse, RusotoError<CreateBGPPeerError>> {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("CreateBGPPeer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OvertureService.CreateBGPPeer");
@@ -5216,6 +6830,7 @@ impl DirectConnect for DirectConnectClient {
         input: CreateConnectionRequest,
     ) -> Result<Connection, RusotoError<CreateConnectionError>> {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("CreateConnection");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OvertureService.CreateConnection");
@@ -5244,6 +6859,7 @@ impl DirectConnect for DirectConnectClient {
     ) -> Result<CreateDirectConnectGatewayResult, RusotoError<CreateDirectConnectGatewayError>>
     {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("CreateDirectConnectGateway");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OvertureService.CreateDirectConnectGateway");
@@ -5275,6 +6891,7 @@ impl DirectConnect for DirectConnectClient {
         RusotoError<CreateDirectConnectGatewayAssociationError>,
     > {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("CreateDirectConnectGatewayAssociation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5311,6 +6928,7 @@ impl DirectConnect for DirectConnectClient {
         RusotoError<CreateDirectConnectGatewayAssociationProposalError>,
     > {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("CreateDirectConnectGatewayAssociationProposal");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5342,6 +6960,7 @@ impl DirectConnect for DirectConnectClient {
         input: CreateInterconnectRequest,
     ) -> Result<Interconnect, RusotoError<CreateInterconnectError>> {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("CreateInterconnect");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OvertureService.CreateInterconnect");
@@ -5369,6 +6988,7 @@ impl DirectConnect for DirectConnectClient {
         input: CreateLagRequest,
     ) -> Result<Lag, RusotoError<CreateLagError>> {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("CreateLag");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OvertureService.CreateLag");
@@ -5396,6 +7016,7 @@ impl DirectConnect for DirectConnectClient {
         input: CreatePrivateVirtualInterfaceRequest,
     ) -> Result<VirtualInterface, RusotoError<CreatePrivateVirtualInterfaceError>> {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("CreatePrivateVirtualInterface");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5426,6 +7047,7 @@ impl DirectConnect for DirectConnectClient {
         input: CreatePublicVirtualInterfaceRequest,
     ) -> Result<VirtualInterface, RusotoError<CreatePublicVirtualInterfaceError>> {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("CreatePublicVirtualInterface");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5457,6 +7079,7 @@ impl DirectConnect for DirectConnectClient {
     ) -> Result<CreateTransitVirtualInterfaceResult, RusotoError<CreateTransitVirtualInterfaceError>>
     {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("CreateTransitVirtualInterface");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5488,6 +7111,7 @@ impl DirectConnect for DirectConnectClient {
         input: DeleteBGPPeerRequest,
     ) -> Result<DeleteBGPPeerResponse, RusotoError<DeleteBGPPeerError>> {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DeleteBGPPeer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OvertureService.DeleteBGPPeer");
@@ -5515,6 +7139,7 @@ impl DirectConnect for DirectConnectClient {
         input: DeleteConnectionRequest,
     ) -> Result<Connection, RusotoError<DeleteConnectionError>> {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DeleteConnection");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OvertureService.DeleteConnection");
@@ -5543,6 +7168,7 @@ impl DirectConnect for DirectConnectClient {
     ) -> Result<DeleteDirectConnectGatewayResult, RusotoError<DeleteDirectConnectGatewayError>>
     {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DeleteDirectConnectGateway");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OvertureService.DeleteDirectConnectGateway");
@@ -5574,6 +7200,7 @@ impl DirectConnect for DirectConnectClient {
         RusotoError<DeleteDirectConnectGatewayAssociationError>,
     > {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DeleteDirectConnectGatewayAssociation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5610,6 +7237,7 @@ impl DirectConnect for DirectConnectClient {
         RusotoError<DeleteDirectConnectGatewayAssociationProposalError>,
     > {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DeleteDirectConnectGatewayAssociationProposal");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5641,6 +7269,7 @@ impl DirectConnect for DirectConnectClient {
         input: DeleteInterconnectRequest,
     ) -> Result<DeleteInterconnectResponse, RusotoError<DeleteInterconnectError>> {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DeleteInterconnect");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OvertureService.DeleteInterconnect");
@@ -5669,6 +7298,7 @@ impl DirectConnect for DirectConnectClient {
         input: DeleteLagRequest,
     ) -> Result<Lag, RusotoError<DeleteLagError>> {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DeleteLag");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OvertureService.DeleteLag");
@@ -5696,6 +7326,7 @@ impl DirectConnect for DirectConnectClient {
         input: DeleteVirtualInterfaceRequest,
     ) -> Result<DeleteVirtualInterfaceResponse, RusotoError<DeleteVirtualInterfaceError>> {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DeleteVirtualInterface");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OvertureService.DeleteVirtualInterface");
@@ -5724,6 +7355,7 @@ impl DirectConnect for DirectConnectClient {
         input: DescribeConnectionLoaRequest,
     ) -> Result<DescribeConnectionLoaResponse, RusotoError<DescribeConnectionLoaError>> {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DescribeConnectionLoa");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OvertureService.DescribeConnectionLoa");
@@ -5752,6 +7384,7 @@ impl DirectConnect for DirectConnectClient {
         input: DescribeConnectionsRequest,
     ) -> Result<Connections, RusotoError<DescribeConnectionsError>> {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DescribeConnections");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OvertureService.DescribeConnections");
@@ -5779,6 +7412,7 @@ impl DirectConnect for DirectConnectClient {
         input: DescribeConnectionsOnInterconnectRequest,
     ) -> Result<Connections, RusotoError<DescribeConnectionsOnInterconnectError>> {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DescribeConnectionsOnInterconnect");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5814,6 +7448,7 @@ impl DirectConnect for DirectConnectClient {
         RusotoError<DescribeDirectConnectGatewayAssociationProposalsError>,
     > {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DescribeDirectConnectGatewayAssociationProposals");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5848,6 +7483,7 @@ impl DirectConnect for DirectConnectClient {
         RusotoError<DescribeDirectConnectGatewayAssociationsError>,
     > {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DescribeDirectConnectGatewayAssociations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5882,6 +7518,7 @@ impl DirectConnect for DirectConnectClient {
         RusotoError<DescribeDirectConnectGatewayAttachmentsError>,
     > {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DescribeDirectConnectGatewayAttachments");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5916,6 +7553,7 @@ impl DirectConnect for DirectConnectClient {
     ) -> Result<DescribeDirectConnectGatewaysResult, RusotoError<DescribeDirectConnectGatewaysError>>
     {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DescribeDirectConnectGateways");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5947,6 +7585,7 @@ impl DirectConnect for DirectConnectClient {
         input: DescribeHostedConnectionsRequest,
     ) -> Result<Connections, RusotoError<DescribeHostedConnectionsError>> {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DescribeHostedConnections");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OvertureService.DescribeHostedConnections");
@@ -5974,6 +7613,7 @@ impl DirectConnect for DirectConnectClient {
         input: DescribeInterconnectLoaRequest,
     ) -> Result<DescribeInterconnectLoaResponse, RusotoError<DescribeInterconnectLoaError>> {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DescribeInterconnectLoa");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OvertureService.DescribeInterconnectLoa");
@@ -6002,6 +7642,7 @@ impl DirectConnect for DirectConnectClient {
         input: DescribeInterconnectsRequest,
     ) -> Result<Interconnects, RusotoError<DescribeInterconnectsError>> {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DescribeInterconnects");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OvertureService.DescribeInterconnects");
@@ -6029,6 +7670,7 @@ impl DirectConnect for DirectConnectClient {
         input: DescribeLagsRequest,
     ) -> Result<Lags, RusotoError<DescribeLagsError>> {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DescribeLags");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OvertureService.DescribeLags");
@@ -6056,6 +7698,7 @@ impl DirectConnect for DirectConnectClient {
         input: DescribeLoaRequest,
     ) -> Result<Loa, RusotoError<DescribeLoaError>> {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DescribeLoa");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OvertureService.DescribeLoa");
@@ -6080,6 +7723,7 @@ impl DirectConnect for DirectConnectClient {
     /// <p>Lists the AWS Direct Connect locations in the current AWS Region. These are the locations that can be selected when calling <a>CreateConnection</a> or <a>CreateInterconnect</a>.</p>
     async fn describe_locations(&self) -> Result<Locations, RusotoError<DescribeLocationsError>> {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DescribeLocations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OvertureService.DescribeLocations");
@@ -6106,6 +7750,7 @@ impl DirectConnect for DirectConnectClient {
         input: DescribeTagsRequest,
     ) -> Result<DescribeTagsResponse, RusotoError<DescribeTagsError>> {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DescribeTags");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OvertureService.DescribeTags");
@@ -6132,6 +7777,7 @@ impl DirectConnect for DirectConnectClient {
         &self,
     ) -> Result<VirtualGateways, RusotoError<DescribeVirtualGatewaysError>> {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DescribeVirtualGateways");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OvertureService.DescribeVirtualGateways");
@@ -6158,6 +7804,7 @@ impl DirectConnect for DirectConnectClient {
         input: DescribeVirtualInterfacesRequest,
     ) -> Result<VirtualInterfaces, RusotoError<DescribeVirtualInterfacesError>> {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DescribeVirtualInterfaces");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OvertureService.DescribeVirtualInterfaces");
@@ -6185,6 +7832,7 @@ impl DirectConnect for DirectConnectClient {
         input: DisassociateConnectionFromLagRequest,
     ) -> Result<Connection, RusotoError<DisassociateConnectionFromLagError>> {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DisassociateConnectionFromLag");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6215,6 +7863,7 @@ impl DirectConnect for DirectConnectClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OvertureService.TagResource");
@@ -6242,6 +7891,7 @@ impl DirectConnect for DirectConnectClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OvertureService.UntagResource");
@@ -6272,6 +7922,7 @@ impl DirectConnect for DirectConnectClient {
         RusotoError<UpdateDirectConnectGatewayAssociationError>,
     > {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("UpdateDirectConnectGatewayAssociation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6305,6 +7956,7 @@ impl DirectConnect for DirectConnectClient {
         input: UpdateLagRequest,
     ) -> Result<Lag, RusotoError<UpdateLagError>> {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("UpdateLag");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OvertureService.UpdateLag");
@@ -6332,6 +7984,7 @@ impl DirectConnect for DirectConnectClient {
         input: UpdateVirtualInterfaceAttributesRequest,
     ) -> Result<VirtualInterface, RusotoError<UpdateVirtualInterfaceAttributesError>> {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("UpdateVirtualInterfaceAttributes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6358,3 +8011,133 @@ impl DirectConnect for DirectConnectClient {
         }
     }
 }
+impl DirectConnectClient {
+    /// Auto-paginating version of `describe_direct_connect_gateway_associations`, streaming every page of results.
+    pub fn describe_direct_connect_gateway_associations_pages(
+        &self,
+        input: DescribeDirectConnectGatewayAssociationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeDirectConnectGatewayAssociationsResult,
+        DescribeDirectConnectGatewayAssociationsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DirectConnectClient,
+             input: DescribeDirectConnectGatewayAssociationsRequest| async move {
+                client
+                    .describe_direct_connect_gateway_associations(input)
+                    .await
+            },
+            |input: &DescribeDirectConnectGatewayAssociationsRequest,
+             output: &DescribeDirectConnectGatewayAssociationsResult| {
+                Some(DescribeDirectConnectGatewayAssociationsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_direct_connect_gateway_associations`, streaming the `directConnectGatewayAssociations` of every page of results.
+    pub fn describe_direct_connect_gateway_associations_items(
+        &self,
+        input: DescribeDirectConnectGatewayAssociationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DirectConnectGatewayAssociation,
+        DescribeDirectConnectGatewayAssociationsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_direct_connect_gateway_associations_pages(input),
+            |output: DescribeDirectConnectGatewayAssociationsResult| {
+                output
+                    .direct_connect_gateway_associations
+                    .unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_direct_connect_gateway_attachments`, streaming every page of results.
+    pub fn describe_direct_connect_gateway_attachments_pages(
+        &self,
+        input: DescribeDirectConnectGatewayAttachmentsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeDirectConnectGatewayAttachmentsResult,
+        DescribeDirectConnectGatewayAttachmentsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DirectConnectClient, input: DescribeDirectConnectGatewayAttachmentsRequest| async move {
+                client
+                    .describe_direct_connect_gateway_attachments(input)
+                    .await
+            },
+            |input: &DescribeDirectConnectGatewayAttachmentsRequest,
+             output: &DescribeDirectConnectGatewayAttachmentsResult| {
+                Some(DescribeDirectConnectGatewayAttachmentsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_direct_connect_gateway_attachments`, streaming the `directConnectGatewayAttachments` of every page of results.
+    pub fn describe_direct_connect_gateway_attachments_items(
+        &self,
+        input: DescribeDirectConnectGatewayAttachmentsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DirectConnectGatewayAttachment,
+        DescribeDirectConnectGatewayAttachmentsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_direct_connect_gateway_attachments_pages(input),
+            |output: DescribeDirectConnectGatewayAttachmentsResult| {
+                output
+                    .direct_connect_gateway_attachments
+                    .unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_direct_connect_gateways`, streaming every page of results.
+    pub fn describe_direct_connect_gateways_pages(
+        &self,
+        input: DescribeDirectConnectGatewaysRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeDirectConnectGatewaysResult,
+        DescribeDirectConnectGatewaysError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DirectConnectClient, input: DescribeDirectConnectGatewaysRequest| async move {
+                client.describe_direct_connect_gateways(input).await
+            },
+            |input: &DescribeDirectConnectGatewaysRequest,
+             output: &DescribeDirectConnectGatewaysResult| {
+                Some(DescribeDirectConnectGatewaysRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_direct_connect_gateways`, streaming the `directConnectGateways` of every page of results.
+    pub fn describe_direct_connect_gateways_items(
+        &self,
+        input: DescribeDirectConnectGatewaysRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DirectConnectGateway,
+        DescribeDirectConnectGatewaysError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_direct_connect_gateways_pages(input),
+            |output: DescribeDirectConnectGatewaysResult| {
+                output.direct_connect_gateways.unwrap_or_default()
+            },
+        )
+    }
+}
@@ -69,7 +69,7 @@ pub struct AgentInfo {
     /// <p>The health of the agent or connector.</p>
     #[serde(rename = "health")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub health: Option<String>,
+    pub health: Option<AgentStatus>,
     /// <p>The name of the host where the agent or connector resides. The host can be a server or virtual machine.</p>
     #[serde(rename = "hostName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -102,6 +102,119 @@ pub struct AgentNetworkInfo {
     pub mac_address: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AgentStatus {
+    /// `HEALTHY`
+    Healthy,
+    /// `UNHEALTHY`
+    Unhealthy,
+    /// `RUNNING`
+    Running,
+    /// `UNKNOWN`
+    UnknownValue,
+    /// `BLACKLISTED`
+    Blacklisted,
+    /// `SHUTDOWN`
+    Shutdown,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AgentStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AgentStatus::Healthy => "HEALTHY",
+            AgentStatus::Unhealthy => "UNHEALTHY",
+            AgentStatus::Running => "RUNNING",
+            AgentStatus::UnknownValue => "UNKNOWN",
+            AgentStatus::Blacklisted => "BLACKLISTED",
+            AgentStatus::Shutdown => "SHUTDOWN",
+            AgentStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AgentStatus {
+    fn default() -> AgentStatus {
+        AgentStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AgentStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AgentStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AgentStatus {
+    fn from(value: &'a str) -> AgentStatus {
+        match value {
+            "HEALTHY" => AgentStatus::Healthy,
+            "UNHEALTHY" => AgentStatus::Unhealthy,
+            "RUNNING" => AgentStatus::Running,
+            "UNKNOWN" => AgentStatus::UnknownValue,
+            "BLACKLISTED" => AgentStatus::Blacklisted,
+            "SHUTDOWN" => AgentStatus::Shutdown,
+            _ => AgentStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AgentStatus {
+    fn from(value: String) -> AgentStatus {
+        match value.as_str() {
+            "HEALTHY" => AgentStatus::Healthy,
+            "UNHEALTHY" => AgentStatus::Unhealthy,
+            "RUNNING" => AgentStatus::Running,
+            "UNKNOWN" => AgentStatus::UnknownValue,
+            "BLACKLISTED" => AgentStatus::Blacklisted,
+            "SHUTDOWN" => AgentStatus::Shutdown,
+            _ => AgentStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AgentStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AgentStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AgentStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AgentStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AgentStatus {
+    fn deserialize<D>(deserializer: D) -> Result<AgentStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct AssociateConfigurationItemsToApplicationRequest {
@@ -124,7 +237,7 @@ pub struct DiscoveryBatchDeleteImportDataError {
     /// <p>The type of error that occurred for a specific import task.</p>
     #[serde(rename = "errorCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub error_code: Option<String>,
+    pub error_code: Option<BatchDeleteImportDataErrorCode>,
     /// <p>The description of the error that occurred for a specific import task.</p>
     #[serde(rename = "errorDescription")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -135,6 +248,104 @@ pub struct DiscoveryBatchDeleteImportDataError {
     pub import_task_id: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BatchDeleteImportDataErrorCode {
+    /// `NOT_FOUND`
+    NotFound,
+    /// `INTERNAL_SERVER_ERROR`
+    InternalServerError,
+    /// `OVER_LIMIT`
+    OverLimit,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl BatchDeleteImportDataErrorCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BatchDeleteImportDataErrorCode::NotFound => "NOT_FOUND",
+            BatchDeleteImportDataErrorCode::InternalServerError => "INTERNAL_SERVER_ERROR",
+            BatchDeleteImportDataErrorCode::OverLimit => "OVER_LIMIT",
+            BatchDeleteImportDataErrorCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for BatchDeleteImportDataErrorCode {
+    fn default() -> BatchDeleteImportDataErrorCode {
+        BatchDeleteImportDataErrorCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for BatchDeleteImportDataErrorCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for BatchDeleteImportDataErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for BatchDeleteImportDataErrorCode {
+    fn from(value: &'a str) -> BatchDeleteImportDataErrorCode {
+        match value {
+            "NOT_FOUND" => BatchDeleteImportDataErrorCode::NotFound,
+            "INTERNAL_SERVER_ERROR" => BatchDeleteImportDataErrorCode::InternalServerError,
+            "OVER_LIMIT" => BatchDeleteImportDataErrorCode::OverLimit,
+            _ => BatchDeleteImportDataErrorCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BatchDeleteImportDataErrorCode {
+    fn from(value: String) -> BatchDeleteImportDataErrorCode {
+        match value.as_str() {
+            "NOT_FOUND" => BatchDeleteImportDataErrorCode::NotFound,
+            "INTERNAL_SERVER_ERROR" => BatchDeleteImportDataErrorCode::InternalServerError,
+            "OVER_LIMIT" => BatchDeleteImportDataErrorCode::OverLimit,
+            _ => BatchDeleteImportDataErrorCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for BatchDeleteImportDataErrorCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<BatchDeleteImportDataErrorCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for BatchDeleteImportDataErrorCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for BatchDeleteImportDataErrorCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BatchDeleteImportDataErrorCode {
+    fn deserialize<D>(deserializer: D) -> Result<BatchDeleteImportDataErrorCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct BatchDeleteImportDataRequest {
@@ -152,6 +363,109 @@ pub struct BatchDeleteImportDataResponse {
     pub errors: Option<Vec<DiscoveryBatchDeleteImportDataError>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ConfigurationItemType {
+    /// `SERVER`
+    Server,
+    /// `PROCESS`
+    Process,
+    /// `CONNECTION`
+    Connection,
+    /// `APPLICATION`
+    Application,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ConfigurationItemType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ConfigurationItemType::Server => "SERVER",
+            ConfigurationItemType::Process => "PROCESS",
+            ConfigurationItemType::Connection => "CONNECTION",
+            ConfigurationItemType::Application => "APPLICATION",
+            ConfigurationItemType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ConfigurationItemType {
+    fn default() -> ConfigurationItemType {
+        ConfigurationItemType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ConfigurationItemType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ConfigurationItemType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ConfigurationItemType {
+    fn from(value: &'a str) -> ConfigurationItemType {
+        match value {
+            "SERVER" => ConfigurationItemType::Server,
+            "PROCESS" => ConfigurationItemType::Process,
+            "CONNECTION" => ConfigurationItemType::Connection,
+            "APPLICATION" => ConfigurationItemType::Application,
+            _ => ConfigurationItemType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ConfigurationItemType {
+    fn from(value: String) -> ConfigurationItemType {
+        match value.as_str() {
+            "SERVER" => ConfigurationItemType::Server,
+            "PROCESS" => ConfigurationItemType::Process,
+            "CONNECTION" => ConfigurationItemType::Connection,
+            "APPLICATION" => ConfigurationItemType::Application,
+            _ => ConfigurationItemType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ConfigurationItemType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ConfigurationItemType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ConfigurationItemType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ConfigurationItemType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ConfigurationItemType {
+    fn deserialize<D>(deserializer: D) -> Result<ConfigurationItemType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Tags for a configuration item. Tags are metadata that help you categorize IT assets.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -163,15 +477,20 @@ pub struct ConfigurationTag {
     /// <p>A type of IT asset to tag.</p>
     #[serde(rename = "configurationType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub configuration_type: Option<String>,
+    pub configuration_type: Option<ConfigurationItemType>,
     /// <p>A type of tag on which to filter. For example, <i>serverType</i>.</p>
     #[serde(rename = "key")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub key: Option<String>,
     /// <p>The time the configuration tag was created in Coordinated Universal Time (UTC).</p>
     #[serde(rename = "timeOfCreation")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub time_of_creation: Option<f64>,
+    pub time_of_creation: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A value on which to filter. For example <i>key = serverType</i> and <i>value = web server</i>.</p>
     #[serde(rename = "value")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -185,7 +504,7 @@ pub struct ContinuousExportDescription {
     /// <p>The type of data collector used to gather this data (currently only offered for AGENT).</p>
     #[serde(rename = "dataSource")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub data_source: Option<String>,
+    pub data_source: Option<DataSource>,
     /// <p>The unique ID assigned to this export.</p>
     #[serde(rename = "exportId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -200,22 +519,150 @@ pub struct ContinuousExportDescription {
     pub schema_storage_config: Option<::std::collections::HashMap<String, String>>,
     /// <p>The timestamp representing when the continuous export was started.</p>
     #[serde(rename = "startTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>Describes the status of the export. Can be one of the following values:</p> <ul> <li> <p>START<em>IN</em>PROGRESS - setting up resources to start continuous export.</p> </li> <li> <p>START<em>FAILED - an error occurred setting up continuous export. To recover, call start-continuous-export again.</p> </li> <li> <p>ACTIVE - data is being exported to the customer bucket.</p> </li> <li> <p>ERROR - an error occurred during export. To fix the issue, call stop-continuous-export and start-continuous-export.</p> </li> <li> <p>STOP</em>IN<em>PROGRESS - stopping the export.</p> </li> <li> <p>STOP</em>FAILED - an error occurred stopping the export. To recover, call stop-continuous-export again.</p> </li> <li> <p>INACTIVE - the continuous export has been stopped. Data is no longer being exported to the customer bucket.</p> </li> </ul></p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ContinuousExportStatus>,
     /// <p><p>Contains information about any errors that have occurred. This data type can have the following values:</p> <ul> <li> <p>ACCESS<em>DENIED - You don’t have permission to start Data Exploration in Amazon Athena. Contact your AWS administrator for help. For more information, see <a href="http://docs.aws.amazon.com/application-discovery/latest/userguide/setting-up.html">Setting Up AWS Application Discovery Service</a> in the Application Discovery Service User Guide.</p> </li> <li> <p>DELIVERY</em>STREAM<em>LIMIT</em>FAILURE - You reached the limit for Amazon Kinesis Data Firehose delivery streams. Reduce the number of streams or request a limit increase and try again. For more information, see <a href="http://docs.aws.amazon.com/streams/latest/dev/service-sizes-and-limits.html">Kinesis Data Streams Limits</a> in the Amazon Kinesis Data Streams Developer Guide.</p> </li> <li> <p>FIREHOSE<em>ROLE</em>MISSING - The Data Exploration feature is in an error state because your IAM User is missing the AWSApplicationDiscoveryServiceFirehose role. Turn on Data Exploration in Amazon Athena and try again. For more information, see <a href="http://docs.aws.amazon.com/application-discovery/latest/userguide/setting-up.html#setting-up-user-policy">Step 3: Provide Application Discovery Service Access to Non-Administrator Users by Attaching Policies</a> in the Application Discovery Service User Guide.</p> </li> <li> <p>FIREHOSE<em>STREAM</em>DOES<em>NOT</em>EXIST - The Data Exploration feature is in an error state because your IAM User is missing one or more of the Kinesis data delivery streams.</p> </li> <li> <p>INTERNAL<em>FAILURE - The Data Exploration feature is in an error state because of an internal failure. Try again later. If this problem persists, contact AWS Support.</p> </li> <li> <p>S3</em>BUCKET<em>LIMIT</em>FAILURE - You reached the limit for Amazon S3 buckets. Reduce the number of Amazon S3 buckets or request a limit increase and try again. For more information, see <a href="http://docs.aws.amazon.com/AmazonS3/latest/dev/BucketRestrictions.html">Bucket Restrictions and Limitations</a> in the Amazon Simple Storage Service Developer Guide.</p> </li> <li> <p>S3<em>NOT</em>SIGNED_UP - Your account is not signed up for the Amazon S3 service. You must sign up before you can use Amazon S3. You can sign up at the following URL: <a href="https://aws.amazon.com/s3">https://aws.amazon.com/s3</a>.</p> </li> </ul></p>
     #[serde(rename = "statusDetail")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status_detail: Option<String>,
     /// <p>The timestamp that represents when this continuous export was stopped.</p>
     #[serde(rename = "stopTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stop_time: Option<f64>,
+    pub stop_time: Option<::chrono::DateTime<::chrono::Utc>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ContinuousExportStatus {
+    /// `START_IN_PROGRESS`
+    StartInProgress,
+    /// `START_FAILED`
+    StartFailed,
+    /// `ACTIVE`
+    Active,
+    /// `ERROR`
+    Error,
+    /// `STOP_IN_PROGRESS`
+    StopInProgress,
+    /// `STOP_FAILED`
+    StopFailed,
+    /// `INACTIVE`
+    Inactive,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ContinuousExportStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ContinuousExportStatus::StartInProgress => "START_IN_PROGRESS",
+            ContinuousExportStatus::StartFailed => "START_FAILED",
+            ContinuousExportStatus::Active => "ACTIVE",
+            ContinuousExportStatus::Error => "ERROR",
+            ContinuousExportStatus::StopInProgress => "STOP_IN_PROGRESS",
+            ContinuousExportStatus::StopFailed => "STOP_FAILED",
+            ContinuousExportStatus::Inactive => "INACTIVE",
+            ContinuousExportStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ContinuousExportStatus {
+    fn default() -> ContinuousExportStatus {
+        ContinuousExportStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ContinuousExportStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ContinuousExportStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ContinuousExportStatus {
+    fn from(value: &'a str) -> ContinuousExportStatus {
+        match value {
+            "START_IN_PROGRESS" => ContinuousExportStatus::StartInProgress,
+            "START_FAILED" => ContinuousExportStatus::StartFailed,
+            "ACTIVE" => ContinuousExportStatus::Active,
+            "ERROR" => ContinuousExportStatus::Error,
+            "STOP_IN_PROGRESS" => ContinuousExportStatus::StopInProgress,
+            "STOP_FAILED" => ContinuousExportStatus::StopFailed,
+            "INACTIVE" => ContinuousExportStatus::Inactive,
+            _ => ContinuousExportStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ContinuousExportStatus {
+    fn from(value: String) -> ContinuousExportStatus {
+        match value.as_str() {
+            "START_IN_PROGRESS" => ContinuousExportStatus::StartInProgress,
+            "START_FAILED" => ContinuousExportStatus::StartFailed,
+            "ACTIVE" => ContinuousExportStatus::Active,
+            "ERROR" => ContinuousExportStatus::Error,
+            "STOP_IN_PROGRESS" => ContinuousExportStatus::StopInProgress,
+            "STOP_FAILED" => ContinuousExportStatus::StopFailed,
+            "INACTIVE" => ContinuousExportStatus::Inactive,
+            _ => ContinuousExportStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ContinuousExportStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ContinuousExportStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ContinuousExportStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ContinuousExportStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for ContinuousExportStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ContinuousExportStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateApplicationRequest {
@@ -306,6 +753,94 @@ pub struct CustomerConnectorInfo {
     pub unknown_connectors: i64,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DataSource {
+    /// `AGENT`
+    Agent,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DataSource {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DataSource::Agent => "AGENT",
+            DataSource::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DataSource {
+    fn default() -> DataSource {
+        DataSource::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DataSource {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DataSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DataSource {
+    fn from(value: &'a str) -> DataSource {
+        match value {
+            "AGENT" => DataSource::Agent,
+            _ => DataSource::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DataSource {
+    fn from(value: String) -> DataSource {
+        match value.as_str() {
+            "AGENT" => DataSource::Agent,
+            _ => DataSource::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DataSource {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DataSource, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DataSource {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DataSource {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DataSource {
+    fn deserialize<D>(deserializer: D) -> Result<DataSource, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct DeleteApplicationsRequest {
@@ -563,6 +1098,94 @@ pub struct ExportConfigurationsResponse {
     pub export_id: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ExportDataFormat {
+    /// `CSV`
+    Csv,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ExportDataFormat {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ExportDataFormat::Csv => "CSV",
+            ExportDataFormat::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ExportDataFormat {
+    fn default() -> ExportDataFormat {
+        ExportDataFormat::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ExportDataFormat {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ExportDataFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ExportDataFormat {
+    fn from(value: &'a str) -> ExportDataFormat {
+        match value {
+            "CSV" => ExportDataFormat::Csv,
+            _ => ExportDataFormat::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ExportDataFormat {
+    fn from(value: String) -> ExportDataFormat {
+        match value.as_str() {
+            "CSV" => ExportDataFormat::Csv,
+            _ => ExportDataFormat::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ExportDataFormat {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ExportDataFormat, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ExportDataFormat {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ExportDataFormat {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ExportDataFormat {
+    fn deserialize<D>(deserializer: D) -> Result<ExportDataFormat, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Used to select which agent's data is to be exported. A single agent ID may be selected for export using the <a href="http://docs.aws.amazon.com/application-discovery/latest/APIReference/API_StartExportTask.html">StartExportTask</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -591,27 +1214,140 @@ pub struct ExportInfo {
     pub export_id: String,
     /// <p>The time that the data export was initiated.</p>
     #[serde(rename = "exportRequestTime")]
-    pub export_request_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub export_request_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The status of the data export job.</p>
     #[serde(rename = "exportStatus")]
-    pub export_status: String,
+    pub export_status: ExportStatus,
     /// <p>If true, the export of agent information exceeded the size limit for a single export and the exported data is incomplete for the requested time range. To address this, select a smaller time range for the export by using <code>startDate</code> and <code>endDate</code>.</p>
     #[serde(rename = "isTruncated")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub is_truncated: Option<bool>,
     /// <p>The <code>endTime</code> used in the <code>StartExportTask</code> request. If no <code>endTime</code> was requested, this result does not appear in <code>ExportInfo</code>.</p>
     #[serde(rename = "requestedEndTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub requested_end_time: Option<f64>,
+    pub requested_end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The value of <code>startTime</code> parameter in the <code>StartExportTask</code> request. If no <code>startTime</code> was requested, this result does not appear in <code>ExportInfo</code>.</p>
     #[serde(rename = "requestedStartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub requested_start_time: Option<f64>,
+    pub requested_start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A status message provided for API callers.</p>
     #[serde(rename = "statusMessage")]
     pub status_message: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ExportStatus {
+    /// `FAILED`
+    Failed,
+    /// `SUCCEEDED`
+    Succeeded,
+    /// `IN_PROGRESS`
+    InProgress,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ExportStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ExportStatus::Failed => "FAILED",
+            ExportStatus::Succeeded => "SUCCEEDED",
+            ExportStatus::InProgress => "IN_PROGRESS",
+            ExportStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ExportStatus {
+    fn default() -> ExportStatus {
+        ExportStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ExportStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ExportStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ExportStatus {
+    fn from(value: &'a str) -> ExportStatus {
+        match value {
+            "FAILED" => ExportStatus::Failed,
+            "SUCCEEDED" => ExportStatus::Succeeded,
+            "IN_PROGRESS" => ExportStatus::InProgress,
+            _ => ExportStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ExportStatus {
+    fn from(value: String) -> ExportStatus {
+        match value.as_str() {
+            "FAILED" => ExportStatus::Failed,
+            "SUCCEEDED" => ExportStatus::Succeeded,
+            "IN_PROGRESS" => ExportStatus::InProgress,
+            _ => ExportStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ExportStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ExportStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ExportStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ExportStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ExportStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ExportStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A filter that can use conditional operators.</p> <p>For more information about filters, see <a href="https://docs.aws.amazon.com/application-discovery/latest/userguide/discovery-api-queries.html">Querying Discovered Configuration Items</a> in the <i>AWS Application Discovery Service User Guide</i>. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -660,6 +1396,144 @@ pub struct GetDiscoverySummaryResponse {
     pub servers_mappedto_tags: Option<i64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ImportStatus {
+    /// `IMPORT_IN_PROGRESS`
+    ImportInProgress,
+    /// `IMPORT_COMPLETE`
+    ImportComplete,
+    /// `IMPORT_COMPLETE_WITH_ERRORS`
+    ImportCompleteWithErrors,
+    /// `IMPORT_FAILED`
+    ImportFailed,
+    /// `IMPORT_FAILED_SERVER_LIMIT_EXCEEDED`
+    ImportFailedServerLimitExceeded,
+    /// `IMPORT_FAILED_RECORD_LIMIT_EXCEEDED`
+    ImportFailedRecordLimitExceeded,
+    /// `DELETE_IN_PROGRESS`
+    DeleteInProgress,
+    /// `DELETE_COMPLETE`
+    DeleteComplete,
+    /// `DELETE_FAILED`
+    DeleteFailed,
+    /// `DELETE_FAILED_LIMIT_EXCEEDED`
+    DeleteFailedLimitExceeded,
+    /// `INTERNAL_ERROR`
+    InternalError,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ImportStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ImportStatus::ImportInProgress => "IMPORT_IN_PROGRESS",
+            ImportStatus::ImportComplete => "IMPORT_COMPLETE",
+            ImportStatus::ImportCompleteWithErrors => "IMPORT_COMPLETE_WITH_ERRORS",
+            ImportStatus::ImportFailed => "IMPORT_FAILED",
+            ImportStatus::ImportFailedServerLimitExceeded => "IMPORT_FAILED_SERVER_LIMIT_EXCEEDED",
+            ImportStatus::ImportFailedRecordLimitExceeded => "IMPORT_FAILED_RECORD_LIMIT_EXCEEDED",
+            ImportStatus::DeleteInProgress => "DELETE_IN_PROGRESS",
+            ImportStatus::DeleteComplete => "DELETE_COMPLETE",
+            ImportStatus::DeleteFailed => "DELETE_FAILED",
+            ImportStatus::DeleteFailedLimitExceeded => "DELETE_FAILED_LIMIT_EXCEEDED",
+            ImportStatus::InternalError => "INTERNAL_ERROR",
+            ImportStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ImportStatus {
+    fn default() -> ImportStatus {
+        ImportStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ImportStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ImportStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ImportStatus {
+    fn from(value: &'a str) -> ImportStatus {
+        match value {
+            "IMPORT_IN_PROGRESS" => ImportStatus::ImportInProgress,
+            "IMPORT_COMPLETE" => ImportStatus::ImportComplete,
+            "IMPORT_COMPLETE_WITH_ERRORS" => ImportStatus::ImportCompleteWithErrors,
+            "IMPORT_FAILED" => ImportStatus::ImportFailed,
+            "IMPORT_FAILED_SERVER_LIMIT_EXCEEDED" => ImportStatus::ImportFailedServerLimitExceeded,
+            "IMPORT_FAILED_RECORD_LIMIT_EXCEEDED" => ImportStatus::ImportFailedRecordLimitExceeded,
+            "DELETE_IN_PROGRESS" => ImportStatus::DeleteInProgress,
+            "DELETE_COMPLETE" => ImportStatus::DeleteComplete,
+            "DELETE_FAILED" => ImportStatus::DeleteFailed,
+            "DELETE_FAILED_LIMIT_EXCEEDED" => ImportStatus::DeleteFailedLimitExceeded,
+            "INTERNAL_ERROR" => ImportStatus::InternalError,
+            _ => ImportStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ImportStatus {
+    fn from(value: String) -> ImportStatus {
+        match value.as_str() {
+            "IMPORT_IN_PROGRESS" => ImportStatus::ImportInProgress,
+            "IMPORT_COMPLETE" => ImportStatus::ImportComplete,
+            "IMPORT_COMPLETE_WITH_ERRORS" => ImportStatus::ImportCompleteWithErrors,
+            "IMPORT_FAILED" => ImportStatus::ImportFailed,
+            "IMPORT_FAILED_SERVER_LIMIT_EXCEEDED" => ImportStatus::ImportFailedServerLimitExceeded,
+            "IMPORT_FAILED_RECORD_LIMIT_EXCEEDED" => ImportStatus::ImportFailedRecordLimitExceeded,
+            "DELETE_IN_PROGRESS" => ImportStatus::DeleteInProgress,
+            "DELETE_COMPLETE" => ImportStatus::DeleteComplete,
+            "DELETE_FAILED" => ImportStatus::DeleteFailed,
+            "DELETE_FAILED_LIMIT_EXCEEDED" => ImportStatus::DeleteFailedLimitExceeded,
+            "INTERNAL_ERROR" => ImportStatus::InternalError,
+            _ => ImportStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ImportStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ImportStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ImportStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ImportStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ImportStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ImportStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An array of information related to the import task request that includes status information, times, IDs, the Amazon S3 Object URL for the import file, and more.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -682,16 +1556,31 @@ pub struct ImportTask {
     pub errors_and_failed_entries_zip: Option<String>,
     /// <p>The time that the import task request finished, presented in the Unix time stamp format.</p>
     #[serde(rename = "importCompletionTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub import_completion_time: Option<f64>,
+    pub import_completion_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The time that the import task request was deleted, presented in the Unix time stamp format.</p>
     #[serde(rename = "importDeletedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub import_deleted_time: Option<f64>,
+    pub import_deleted_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The time that the import task request was made, presented in the Unix time stamp format.</p>
     #[serde(rename = "importRequestTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub import_request_time: Option<f64>,
+    pub import_request_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The unique ID for a specific import task. These IDs aren't globally unique, but they are unique within an AWS account.</p>
     #[serde(rename = "importTaskId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -715,7 +1604,7 @@ pub struct ImportTask {
     /// <p>The status of the import task. An import can have the status of <code>IMPORT_COMPLETE</code> and still have some records fail to import from the overall request. More information can be found in the downloadable archive defined in the <code>errorsAndFailedEntriesZip</code> field, or in the Migration Hub management console.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ImportStatus>,
 }
 
 /// <p><p>A name-values pair of elements you can use to filter the results when querying your import tasks. Currently, wildcards are not supported for filters.</p> <note> <p>When filtering by import status, all other filter values are ignored.</p> </note></p>
@@ -725,19 +1614,117 @@ pub struct ImportTaskFilter {
     /// <p>The name, status, or import task ID for a specific import task.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
+    pub name: Option<ImportTaskFilterName>,
     /// <p>An array of strings that you can provide to match against a specific name, status, or import task ID to filter the results for your import task queries.</p>
     #[serde(rename = "values")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub values: Option<Vec<String>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ImportTaskFilterName {
+    /// `IMPORT_TASK_ID`
+    ImportTaskId,
+    /// `STATUS`
+    Status,
+    /// `NAME`
+    Name,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ImportTaskFilterName {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ImportTaskFilterName::ImportTaskId => "IMPORT_TASK_ID",
+            ImportTaskFilterName::Status => "STATUS",
+            ImportTaskFilterName::Name => "NAME",
+            ImportTaskFilterName::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ImportTaskFilterName {
+    fn default() -> ImportTaskFilterName {
+        ImportTaskFilterName::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ImportTaskFilterName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ImportTaskFilterName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ImportTaskFilterName {
+    fn from(value: &'a str) -> ImportTaskFilterName {
+        match value {
+            "IMPORT_TASK_ID" => ImportTaskFilterName::ImportTaskId,
+            "STATUS" => ImportTaskFilterName::Status,
+            "NAME" => ImportTaskFilterName::Name,
+            _ => ImportTaskFilterName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ImportTaskFilterName {
+    fn from(value: String) -> ImportTaskFilterName {
+        match value.as_str() {
+            "IMPORT_TASK_ID" => ImportTaskFilterName::ImportTaskId,
+            "STATUS" => ImportTaskFilterName::Status,
+            "NAME" => ImportTaskFilterName::Name,
+            _ => ImportTaskFilterName::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ImportTaskFilterName {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ImportTaskFilterName, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ImportTaskFilterName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ImportTaskFilterName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ImportTaskFilterName {
+    fn deserialize<D>(deserializer: D) -> Result<ImportTaskFilterName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ListConfigurationsRequest {
     /// <p>A valid configuration identified by Application Discovery Service. </p>
     #[serde(rename = "configurationType")]
-    pub configuration_type: String,
+    pub configuration_type: ConfigurationItemType,
     /// <p>You can filter the request using various logical operators and a <i>key</i>-<i>value</i> format. For example: </p> <p> <code>{"key": "serverType", "value": "webServer"}</code> </p> <p>For a complete list of filter options and guidance about using them with this action, see <a href="https://docs.aws.amazon.com/application-discovery/latest/userguide/discovery-api-queries.html#ListConfigurations">Using the ListConfigurations Action</a> in the <i>AWS Application Discovery Service User Guide</i>.</p>
     #[serde(rename = "filters")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -842,9 +1829,102 @@ pub struct OrderByElement {
     /// <p>Ordering direction.</p>
     #[serde(rename = "sortOrder")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort_order: Option<String>,
+    pub sort_order: Option<OrderString>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OrderString {
+    /// `ASC`
+    Asc,
+    /// `DESC`
+    Desc,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl OrderString {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OrderString::Asc => "ASC",
+            OrderString::Desc => "DESC",
+            OrderString::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for OrderString {
+    fn default() -> OrderString {
+        OrderString::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for OrderString {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for OrderString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for OrderString {
+    fn from(value: &'a str) -> OrderString {
+        match value {
+            "ASC" => OrderString::Asc,
+            "DESC" => OrderString::Desc,
+            _ => OrderString::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OrderString {
+    fn from(value: String) -> OrderString {
+        match value.as_str() {
+            "ASC" => OrderString::Asc,
+            "DESC" => OrderString::Desc,
+            _ => OrderString::Unknown(value),
+        }
+    }
 }
 
+impl ::std::str::FromStr for OrderString {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<OrderString, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OrderString {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for OrderString {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OrderString {
+    fn deserialize<D>(deserializer: D) -> Result<OrderString, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct StartContinuousExportRequest {}
@@ -855,7 +1935,7 @@ pub struct StartContinuousExportResponse {
     /// <p>The type of data collector used to gather this data (currently only offered for AGENT).</p>
     #[serde(rename = "dataSource")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub data_source: Option<String>,
+    pub data_source: Option<DataSource>,
     /// <p>The unique ID assigned to this export.</p>
     #[serde(rename = "exportId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -870,8 +1950,13 @@ pub struct StartContinuousExportResponse {
     pub schema_storage_config: Option<::std::collections::HashMap<String, String>>,
     /// <p>The timestamp representing when the continuous export was started.</p>
     #[serde(rename = "startTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -896,20 +1981,30 @@ pub struct StartDataCollectionByAgentIdsResponse {
 pub struct StartExportTaskRequest {
     /// <p>The end timestamp for exported data from the single Application Discovery Agent selected in the filters. If no value is specified, exported data includes the most recent data collected by the agent.</p>
     #[serde(rename = "endTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The file format for the returned export data. Default value is <code>CSV</code>. <b>Note:</b> <i>The</i> <code>GRAPHML</code> <i>option has been deprecated.</i> </p>
     #[serde(rename = "exportDataFormat")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub export_data_format: Option<Vec<String>>,
+    pub export_data_format: Option<Vec<ExportDataFormat>>,
     /// <p>If a filter is present, it selects the single <code>agentId</code> of the Application Discovery Agent for which data is exported. The <code>agentId</code> can be found in the results of the <code>DescribeAgents</code> API or CLI. If no filter is present, <code>startTime</code> and <code>endTime</code> are ignored and exported data includes both Agentless Discovery Connector data and summary data from Application Discovery agents. </p>
     #[serde(rename = "filters")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub filters: Option<Vec<ExportFilter>>,
     /// <p>The start timestamp for exported data from the single Application Discovery Agent selected in the filters. If no value is specified, data is exported starting from the first data collected by the agent.</p>
     #[serde(rename = "startTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -958,12 +2053,22 @@ pub struct StopContinuousExportRequest {
 pub struct StopContinuousExportResponse {
     /// <p>Timestamp that represents when this continuous export started collecting data.</p>
     #[serde(rename = "startTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Timestamp that represents when this continuous export was stopped.</p>
     #[serde(rename = "stopTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stop_time: Option<f64>,
+    pub stop_time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -2918,11 +4023,15 @@ pub struct DiscoveryClient {
 impl DiscoveryClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> DiscoveryClient {
         DiscoveryClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(
+                region,
+                "Application Discovery Service",
+            ),
         }
     }
 
@@ -2957,6 +4066,7 @@ impl Discovery for DiscoveryClient {
         RusotoError<AssociateConfigurationItemsToApplicationError>,
     > {
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("AssociateConfigurationItemsToApplication");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2988,6 +4098,7 @@ impl Discovery for DiscoveryClient {
         input: BatchDeleteImportDataRequest,
     ) -> Result<BatchDeleteImportDataResponse, RusotoError<BatchDeleteImportDataError>> {
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("BatchDeleteImportData");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3019,6 +4130,7 @@ impl Discovery for DiscoveryClient {
         input: CreateApplicationRequest,
     ) -> Result<CreateApplicationResponse, RusotoError<CreateApplicationError>> {
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("CreateApplication");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3050,6 +4162,7 @@ impl Discovery for DiscoveryClient {
         input: CreateTagsRequest,
     ) -> Result<CreateTagsResponse, RusotoError<CreateTagsError>> {
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("CreateTags");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSPoseidonService_V2015_11_01.CreateTags");
@@ -3077,6 +4190,7 @@ impl Discovery for DiscoveryClient {
         input: DeleteApplicationsRequest,
     ) -> Result<DeleteApplicationsResponse, RusotoError<DeleteApplicationsError>> {
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("DeleteApplications");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3108,6 +4222,7 @@ impl Discovery for DiscoveryClient {
         input: DeleteTagsRequest,
     ) -> Result<DeleteTagsResponse, RusotoError<DeleteTagsError>> {
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("DeleteTags");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSPoseidonService_V2015_11_01.DeleteTags");
@@ -3135,6 +4250,7 @@ impl Discovery for DiscoveryClient {
         input: DescribeAgentsRequest,
     ) -> Result<DescribeAgentsResponse, RusotoError<DescribeAgentsError>> {
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("DescribeAgents");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3165,6 +4281,7 @@ impl Discovery for DiscoveryClient {
         input: DescribeConfigurationsRequest,
     ) -> Result<DescribeConfigurationsResponse, RusotoError<DescribeConfigurationsError>> {
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("DescribeConfigurations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3197,6 +4314,7 @@ impl Discovery for DiscoveryClient {
     ) -> Result<DescribeContinuousExportsResponse, RusotoError<DescribeContinuousExportsError>>
     {
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("DescribeContinuousExports");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3229,6 +4347,7 @@ impl Discovery for DiscoveryClient {
     ) -> Result<DescribeExportConfigurationsResponse, RusotoError<DescribeExportConfigurationsError>>
     {
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("DescribeExportConfigurations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3260,6 +4379,7 @@ impl Discovery for DiscoveryClient {
         input: DescribeExportTasksRequest,
     ) -> Result<DescribeExportTasksResponse, RusotoError<DescribeExportTasksError>> {
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("DescribeExportTasks");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3291,6 +4411,7 @@ impl Discovery for DiscoveryClient {
         input: DescribeImportTasksRequest,
     ) -> Result<DescribeImportTasksResponse, RusotoError<DescribeImportTasksError>> {
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("DescribeImportTasks");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3322,6 +4443,7 @@ impl Discovery for DiscoveryClient {
         input: DescribeTagsRequest,
     ) -> Result<DescribeTagsResponse, RusotoError<DescribeTagsError>> {
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("DescribeTags");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3355,6 +4477,7 @@ impl Discovery for DiscoveryClient {
         RusotoError<DisassociateConfigurationItemsFromApplicationError>,
     > {
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("DisassociateConfigurationItemsFromApplication");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3385,6 +4508,7 @@ impl Discovery for DiscoveryClient {
         &self,
     ) -> Result<ExportConfigurationsResponse, RusotoError<ExportConfigurationsError>> {
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("ExportConfigurations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3414,6 +4538,7 @@ impl Discovery for DiscoveryClient {
         &self,
     ) -> Result<GetDiscoverySummaryResponse, RusotoError<GetDiscoverySummaryError>> {
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("GetDiscoverySummary");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3444,6 +4569,7 @@ impl Discovery for DiscoveryClient {
         input: ListConfigurationsRequest,
     ) -> Result<ListConfigurationsResponse, RusotoError<ListConfigurationsError>> {
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("ListConfigurations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3475,6 +4601,7 @@ impl Discovery for DiscoveryClient {
         input: ListServerNeighborsRequest,
     ) -> Result<ListServerNeighborsResponse, RusotoError<ListServerNeighborsError>> {
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("ListServerNeighbors");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3505,6 +4632,7 @@ impl Discovery for DiscoveryClient {
         &self,
     ) -> Result<StartContinuousExportResponse, RusotoError<StartContinuousExportError>> {
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("StartContinuousExport");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3538,6 +4666,7 @@ impl Discovery for DiscoveryClient {
         RusotoError<StartDataCollectionByAgentIdsError>,
     > {
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("StartDataCollectionByAgentIds");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3569,6 +4698,7 @@ impl Discovery for DiscoveryClient {
         input: StartExportTaskRequest,
     ) -> Result<StartExportTaskResponse, RusotoError<StartExportTaskError>> {
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("StartExportTask");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3599,6 +4729,7 @@ impl Discovery for DiscoveryClient {
         input: StartImportTaskRequest,
     ) -> Result<StartImportTaskResponse, RusotoError<StartImportTaskError>> {
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("StartImportTask");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3629,6 +4760,7 @@ impl Discovery for DiscoveryClient {
         input: StopContinuousExportRequest,
     ) -> Result<StopContinuousExportResponse, RusotoError<StopContinuousExportError>> {
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("StopContinuousExport");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3661,6 +4793,7 @@ impl Discovery for DiscoveryClient {
     ) -> Result<StopDataCollectionByAgentIdsResponse, RusotoError<StopDataCollectionByAgentIdsError>>
     {
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("StopDataCollectionByAgentIds");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3692,6 +4825,7 @@ impl Discovery for DiscoveryClient {
         input: UpdateApplicationRequest,
     ) -> Result<UpdateApplicationResponse, RusotoError<UpdateApplicationError>> {
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("UpdateApplication");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3717,3 +4851,238 @@ impl Discovery for DiscoveryClient {
         }
     }
 }
+impl DiscoveryClient {
+    /// Auto-paginating version of `describe_agents`, streaming every page of results.
+    pub fn describe_agents_pages(
+        &self,
+        input: DescribeAgentsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeAgentsResponse, DescribeAgentsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DiscoveryClient, input: DescribeAgentsRequest| async move {
+                client.describe_agents(input).await
+            },
+            |input: &DescribeAgentsRequest, output: &DescribeAgentsResponse| {
+                Some(DescribeAgentsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_agents`, streaming the `agentsInfo` of every page of results.
+    pub fn describe_agents_items(
+        &self,
+        input: DescribeAgentsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<AgentInfo, DescribeAgentsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_agents_pages(input),
+            |output: DescribeAgentsResponse| output.agents_info.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_continuous_exports`, streaming every page of results.
+    pub fn describe_continuous_exports_pages(
+        &self,
+        input: DescribeContinuousExportsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeContinuousExportsResponse,
+        DescribeContinuousExportsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DiscoveryClient, input: DescribeContinuousExportsRequest| async move {
+                client.describe_continuous_exports(input).await
+            },
+            |input: &DescribeContinuousExportsRequest,
+             output: &DescribeContinuousExportsResponse| {
+                Some(DescribeContinuousExportsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_continuous_exports`, streaming the `descriptions` of every page of results.
+    pub fn describe_continuous_exports_items(
+        &self,
+        input: DescribeContinuousExportsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ContinuousExportDescription,
+        DescribeContinuousExportsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_continuous_exports_pages(input),
+            |output: DescribeContinuousExportsResponse| output.descriptions.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_export_configurations`, streaming every page of results.
+    pub fn describe_export_configurations_pages(
+        &self,
+        input: DescribeExportConfigurationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeExportConfigurationsResponse,
+        DescribeExportConfigurationsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DiscoveryClient, input: DescribeExportConfigurationsRequest| async move {
+                client.describe_export_configurations(input).await
+            },
+            |input: &DescribeExportConfigurationsRequest,
+             output: &DescribeExportConfigurationsResponse| {
+                Some(DescribeExportConfigurationsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_export_configurations`, streaming the `exportsInfo` of every page of results.
+    pub fn describe_export_configurations_items(
+        &self,
+        input: DescribeExportConfigurationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ExportInfo, DescribeExportConfigurationsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_export_configurations_pages(input),
+            |output: DescribeExportConfigurationsResponse| output.exports_info.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_export_tasks`, streaming every page of results.
+    pub fn describe_export_tasks_pages(
+        &self,
+        input: DescribeExportTasksRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeExportTasksResponse, DescribeExportTasksError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DiscoveryClient, input: DescribeExportTasksRequest| async move {
+                client.describe_export_tasks(input).await
+            },
+            |input: &DescribeExportTasksRequest, output: &DescribeExportTasksResponse| {
+                Some(DescribeExportTasksRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_export_tasks`, streaming the `exportsInfo` of every page of results.
+    pub fn describe_export_tasks_items(
+        &self,
+        input: DescribeExportTasksRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ExportInfo, DescribeExportTasksError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_export_tasks_pages(input),
+            |output: DescribeExportTasksResponse| output.exports_info.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_import_tasks`, streaming every page of results.
+    pub fn describe_import_tasks_pages(
+        &self,
+        input: DescribeImportTasksRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeImportTasksResponse, DescribeImportTasksError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DiscoveryClient, input: DescribeImportTasksRequest| async move {
+                client.describe_import_tasks(input).await
+            },
+            |input: &DescribeImportTasksRequest, output: &DescribeImportTasksResponse| {
+                Some(DescribeImportTasksRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_import_tasks`, streaming the `tasks` of every page of results.
+    pub fn describe_import_tasks_items(
+        &self,
+        input: DescribeImportTasksRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ImportTask, DescribeImportTasksError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_import_tasks_pages(input),
+            |output: DescribeImportTasksResponse| output.tasks.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_tags`, streaming every page of results.
+    pub fn describe_tags_pages(
+        &self,
+        input: DescribeTagsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeTagsResponse, DescribeTagsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DiscoveryClient, input: DescribeTagsRequest| async move {
+                client.describe_tags(input).await
+            },
+            |input: &DescribeTagsRequest, output: &DescribeTagsResponse| {
+                Some(DescribeTagsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_tags`, streaming the `tags` of every page of results.
+    pub fn describe_tags_items(
+        &self,
+        input: DescribeTagsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ConfigurationTag, DescribeTagsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_tags_pages(input),
+            |output: DescribeTagsResponse| output.tags.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_configurations`, streaming every page of results.
+    pub fn list_configurations_pages(
+        &self,
+        input: ListConfigurationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListConfigurationsResponse, ListConfigurationsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DiscoveryClient, input: ListConfigurationsRequest| async move {
+                client.list_configurations(input).await
+            },
+            |input: &ListConfigurationsRequest, output: &ListConfigurationsResponse| {
+                Some(ListConfigurationsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_configurations`, streaming the `configurations` of every page of results.
+    pub fn list_configurations_items(
+        &self,
+        input: ListConfigurationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ::std::collections::HashMap<String, String>,
+        ListConfigurationsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_configurations_pages(input),
+            |output: ListConfigurationsResponse| output.configurations.unwrap_or_default(),
+        )
+    }
+}
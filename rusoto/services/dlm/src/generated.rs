@@ -39,7 +39,7 @@ pub struct CreateLifecyclePolicyRequest {
     pub policy_details: PolicyDetails,
     /// <p>The desired activation state of the lifecycle policy after creation.</p>
     #[serde(rename = "State")]
-    pub state: String,
+    pub state: SettablePolicyStateValues,
     /// <p>The tags to apply to the lifecycle policy during creation.</p>
     #[serde(rename = "Tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -63,7 +63,7 @@ pub struct CreateRule {
     pub interval: i64,
     /// <p>The interval unit.</p>
     #[serde(rename = "IntervalUnit")]
-    pub interval_unit: String,
+    pub interval_unit: IntervalUnitValues,
     /// <p>The time, in UTC, to start the operation. The supported format is hh:mm.</p> <p>The operation occurs within a one-hour window following the specified time.</p>
     #[serde(rename = "Times")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -80,7 +80,7 @@ pub struct CrossRegionCopyRetainRule {
     /// <p>The unit of time for time-based retention.</p>
     #[serde(rename = "IntervalUnit")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub interval_unit: Option<String>,
+    pub interval_unit: Option<RetentionIntervalUnitValues>,
 }
 
 /// <p>Specifies a rule for cross-Region snapshot copies.</p>
@@ -135,7 +135,7 @@ pub struct FastRestoreRule {
     /// <p>The unit of time for enabling fast snapshot restore.</p>
     #[serde(rename = "IntervalUnit")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub interval_unit: Option<String>,
+    pub interval_unit: Option<RetentionIntervalUnitValues>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -148,11 +148,11 @@ pub struct GetLifecyclePoliciesRequest {
     /// <p>The resource type.</p>
     #[serde(rename = "ResourceTypes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_types: Option<Vec<String>>,
+    pub resource_types: Option<Vec<ResourceTypeValues>>,
     /// <p>The activation state.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<GettablePolicyStateValues>,
     /// <p>The tags to add to objects created by the policy.</p> <p>Tags are strings in the format <code>key=value</code>.</p> <p>These user-defined tags are added in addition to the AWS-added lifecycle tags.</p>
     #[serde(rename = "TagsToAdd")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -189,18 +189,214 @@ pub struct GetLifecyclePolicyResponse {
     pub policy: Option<LifecyclePolicy>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum GettablePolicyStateValues {
+    /// `ENABLED`
+    Enabled,
+    /// `DISABLED`
+    Disabled,
+    /// `ERROR`
+    Error,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl GettablePolicyStateValues {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            GettablePolicyStateValues::Enabled => "ENABLED",
+            GettablePolicyStateValues::Disabled => "DISABLED",
+            GettablePolicyStateValues::Error => "ERROR",
+            GettablePolicyStateValues::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for GettablePolicyStateValues {
+    fn default() -> GettablePolicyStateValues {
+        GettablePolicyStateValues::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for GettablePolicyStateValues {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for GettablePolicyStateValues {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for GettablePolicyStateValues {
+    fn from(value: &'a str) -> GettablePolicyStateValues {
+        match value {
+            "ENABLED" => GettablePolicyStateValues::Enabled,
+            "DISABLED" => GettablePolicyStateValues::Disabled,
+            "ERROR" => GettablePolicyStateValues::Error,
+            _ => GettablePolicyStateValues::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for GettablePolicyStateValues {
+    fn from(value: String) -> GettablePolicyStateValues {
+        match value.as_str() {
+            "ENABLED" => GettablePolicyStateValues::Enabled,
+            "DISABLED" => GettablePolicyStateValues::Disabled,
+            "ERROR" => GettablePolicyStateValues::Error,
+            _ => GettablePolicyStateValues::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for GettablePolicyStateValues {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<GettablePolicyStateValues, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for GettablePolicyStateValues {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for GettablePolicyStateValues {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for GettablePolicyStateValues {
+    fn deserialize<D>(deserializer: D) -> Result<GettablePolicyStateValues, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum IntervalUnitValues {
+    /// `HOURS`
+    Hours,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl IntervalUnitValues {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            IntervalUnitValues::Hours => "HOURS",
+            IntervalUnitValues::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for IntervalUnitValues {
+    fn default() -> IntervalUnitValues {
+        IntervalUnitValues::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for IntervalUnitValues {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for IntervalUnitValues {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for IntervalUnitValues {
+    fn from(value: &'a str) -> IntervalUnitValues {
+        match value {
+            "HOURS" => IntervalUnitValues::Hours,
+            _ => IntervalUnitValues::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for IntervalUnitValues {
+    fn from(value: String) -> IntervalUnitValues {
+        match value.as_str() {
+            "HOURS" => IntervalUnitValues::Hours,
+            _ => IntervalUnitValues::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for IntervalUnitValues {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<IntervalUnitValues, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for IntervalUnitValues {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for IntervalUnitValues {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for IntervalUnitValues {
+    fn deserialize<D>(deserializer: D) -> Result<IntervalUnitValues, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Detailed information about a lifecycle policy.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LifecyclePolicy {
     /// <p>The local date and time when the lifecycle policy was created.</p>
     #[serde(rename = "DateCreated")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub date_created: Option<f64>,
+    pub date_created: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The local date and time when the lifecycle policy was last modified.</p>
     #[serde(rename = "DateModified")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub date_modified: Option<f64>,
+    pub date_modified: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The description of the lifecycle policy.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -224,7 +420,7 @@ pub struct LifecyclePolicy {
     /// <p>The activation state of the lifecycle policy.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<GettablePolicyStateValues>,
     /// <p>The description of the status.</p>
     #[serde(rename = "StatusMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -250,7 +446,7 @@ pub struct LifecyclePolicySummary {
     /// <p>The activation state of the lifecycle policy.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<GettablePolicyStateValues>,
     /// <p>The tags.</p>
     #[serde(rename = "Tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -293,11 +489,11 @@ pub struct PolicyDetails {
     /// <p>This field determines the valid target resource types and actions a policy can manage. This field defaults to EBS_SNAPSHOT_MANAGEMENT if not present.</p>
     #[serde(rename = "PolicyType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub policy_type: Option<String>,
+    pub policy_type: Option<PolicyTypeValues>,
     /// <p>The resource type.</p>
     #[serde(rename = "ResourceTypes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_types: Option<Vec<String>>,
+    pub resource_types: Option<Vec<ResourceTypeValues>>,
     /// <p>The schedule of policy-defined actions.</p>
     #[serde(rename = "Schedules")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -308,6 +504,197 @@ pub struct PolicyDetails {
     pub target_tags: Option<Vec<Tag>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PolicyTypeValues {
+    /// `EBS_SNAPSHOT_MANAGEMENT`
+    EbsSnapshotManagement,
+    /// `IMAGE_MANAGEMENT`
+    ImageManagement,
+    /// `EVENT_BASED_POLICY`
+    EventBasedPolicy,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PolicyTypeValues {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PolicyTypeValues::EbsSnapshotManagement => "EBS_SNAPSHOT_MANAGEMENT",
+            PolicyTypeValues::ImageManagement => "IMAGE_MANAGEMENT",
+            PolicyTypeValues::EventBasedPolicy => "EVENT_BASED_POLICY",
+            PolicyTypeValues::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PolicyTypeValues {
+    fn default() -> PolicyTypeValues {
+        PolicyTypeValues::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PolicyTypeValues {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PolicyTypeValues {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PolicyTypeValues {
+    fn from(value: &'a str) -> PolicyTypeValues {
+        match value {
+            "EBS_SNAPSHOT_MANAGEMENT" => PolicyTypeValues::EbsSnapshotManagement,
+            "IMAGE_MANAGEMENT" => PolicyTypeValues::ImageManagement,
+            "EVENT_BASED_POLICY" => PolicyTypeValues::EventBasedPolicy,
+            _ => PolicyTypeValues::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PolicyTypeValues {
+    fn from(value: String) -> PolicyTypeValues {
+        match value.as_str() {
+            "EBS_SNAPSHOT_MANAGEMENT" => PolicyTypeValues::EbsSnapshotManagement,
+            "IMAGE_MANAGEMENT" => PolicyTypeValues::ImageManagement,
+            "EVENT_BASED_POLICY" => PolicyTypeValues::EventBasedPolicy,
+            _ => PolicyTypeValues::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PolicyTypeValues {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PolicyTypeValues, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PolicyTypeValues {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PolicyTypeValues {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PolicyTypeValues {
+    fn deserialize<D>(deserializer: D) -> Result<PolicyTypeValues, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ResourceTypeValues {
+    /// `VOLUME`
+    Volume,
+    /// `INSTANCE`
+    Instance,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ResourceTypeValues {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceTypeValues::Volume => "VOLUME",
+            ResourceTypeValues::Instance => "INSTANCE",
+            ResourceTypeValues::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ResourceTypeValues {
+    fn default() -> ResourceTypeValues {
+        ResourceTypeValues::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ResourceTypeValues {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ResourceTypeValues {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ResourceTypeValues {
+    fn from(value: &'a str) -> ResourceTypeValues {
+        match value {
+            "VOLUME" => ResourceTypeValues::Volume,
+            "INSTANCE" => ResourceTypeValues::Instance,
+            _ => ResourceTypeValues::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceTypeValues {
+    fn from(value: String) -> ResourceTypeValues {
+        match value.as_str() {
+            "VOLUME" => ResourceTypeValues::Volume,
+            "INSTANCE" => ResourceTypeValues::Instance,
+            _ => ResourceTypeValues::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ResourceTypeValues {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ResourceTypeValues, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ResourceTypeValues {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ResourceTypeValues {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ResourceTypeValues {
+    fn deserialize<D>(deserializer: D) -> Result<ResourceTypeValues, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Specifies the retention rule for a lifecycle policy. You can retain snapshots based on either a count or a time interval.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RetainRule {
@@ -322,9 +709,112 @@ pub struct RetainRule {
     /// <p>The unit of time for time-based retention.</p>
     #[serde(rename = "IntervalUnit")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub interval_unit: Option<String>,
+    pub interval_unit: Option<RetentionIntervalUnitValues>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RetentionIntervalUnitValues {
+    /// `DAYS`
+    Days,
+    /// `WEEKS`
+    Weeks,
+    /// `MONTHS`
+    Months,
+    /// `YEARS`
+    Years,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RetentionIntervalUnitValues {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RetentionIntervalUnitValues::Days => "DAYS",
+            RetentionIntervalUnitValues::Weeks => "WEEKS",
+            RetentionIntervalUnitValues::Months => "MONTHS",
+            RetentionIntervalUnitValues::Years => "YEARS",
+            RetentionIntervalUnitValues::Unknown(ref value) => value,
+        }
+    }
 }
 
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RetentionIntervalUnitValues {
+    fn default() -> RetentionIntervalUnitValues {
+        RetentionIntervalUnitValues::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RetentionIntervalUnitValues {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RetentionIntervalUnitValues {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RetentionIntervalUnitValues {
+    fn from(value: &'a str) -> RetentionIntervalUnitValues {
+        match value {
+            "DAYS" => RetentionIntervalUnitValues::Days,
+            "WEEKS" => RetentionIntervalUnitValues::Weeks,
+            "MONTHS" => RetentionIntervalUnitValues::Months,
+            "YEARS" => RetentionIntervalUnitValues::Years,
+            _ => RetentionIntervalUnitValues::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RetentionIntervalUnitValues {
+    fn from(value: String) -> RetentionIntervalUnitValues {
+        match value.as_str() {
+            "DAYS" => RetentionIntervalUnitValues::Days,
+            "WEEKS" => RetentionIntervalUnitValues::Weeks,
+            "MONTHS" => RetentionIntervalUnitValues::Months,
+            "YEARS" => RetentionIntervalUnitValues::Years,
+            _ => RetentionIntervalUnitValues::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RetentionIntervalUnitValues {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RetentionIntervalUnitValues, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RetentionIntervalUnitValues {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RetentionIntervalUnitValues {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RetentionIntervalUnitValues {
+    fn deserialize<D>(deserializer: D) -> Result<RetentionIntervalUnitValues, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Specifies a schedule.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Schedule {
@@ -362,6 +852,99 @@ pub struct Schedule {
     pub variable_tags: Option<Vec<Tag>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SettablePolicyStateValues {
+    /// `ENABLED`
+    Enabled,
+    /// `DISABLED`
+    Disabled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SettablePolicyStateValues {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SettablePolicyStateValues::Enabled => "ENABLED",
+            SettablePolicyStateValues::Disabled => "DISABLED",
+            SettablePolicyStateValues::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SettablePolicyStateValues {
+    fn default() -> SettablePolicyStateValues {
+        SettablePolicyStateValues::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SettablePolicyStateValues {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SettablePolicyStateValues {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SettablePolicyStateValues {
+    fn from(value: &'a str) -> SettablePolicyStateValues {
+        match value {
+            "ENABLED" => SettablePolicyStateValues::Enabled,
+            "DISABLED" => SettablePolicyStateValues::Disabled,
+            _ => SettablePolicyStateValues::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SettablePolicyStateValues {
+    fn from(value: String) -> SettablePolicyStateValues {
+        match value.as_str() {
+            "ENABLED" => SettablePolicyStateValues::Enabled,
+            "DISABLED" => SettablePolicyStateValues::Disabled,
+            _ => SettablePolicyStateValues::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SettablePolicyStateValues {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SettablePolicyStateValues, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SettablePolicyStateValues {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SettablePolicyStateValues {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SettablePolicyStateValues {
+    fn deserialize<D>(deserializer: D) -> Result<SettablePolicyStateValues, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Specifies a tag for a resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Tag {
@@ -424,7 +1007,7 @@ pub struct UpdateLifecyclePolicyRequest {
     /// <p>The desired activation state of the lifecycle policy after creation.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<SettablePolicyStateValues>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -858,11 +1441,12 @@ pub struct DlmClient {
 impl DlmClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> DlmClient {
         DlmClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "DLM"),
         }
     }
 
@@ -896,6 +1480,7 @@ impl Dlm for DlmClient {
         let request_uri = "/policies";
 
         let mut request = SignedRequest::new("POST", "dlm", &self.region, &request_uri);
+        request.set_operation("CreateLifecyclePolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -926,6 +1511,7 @@ impl Dlm for DlmClient {
         let request_uri = format!("/policies/{policy_id}/", policy_id = input.policy_id);
 
         let mut request = SignedRequest::new("DELETE", "dlm", &self.region, &request_uri);
+        request.set_operation("DeleteLifecyclePolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -953,6 +1539,7 @@ impl Dlm for DlmClient {
         let request_uri = "/policies";
 
         let mut request = SignedRequest::new("GET", "dlm", &self.region, &request_uri);
+        request.set_operation("GetLifecyclePolicies");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1006,6 +1593,7 @@ impl Dlm for DlmClient {
         let request_uri = format!("/policies/{policy_id}/", policy_id = input.policy_id);
 
         let mut request = SignedRequest::new("GET", "dlm", &self.region, &request_uri);
+        request.set_operation("GetLifecyclePolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1033,6 +1621,7 @@ impl Dlm for DlmClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "dlm", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1060,6 +1649,7 @@ impl Dlm for DlmClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "dlm", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1090,6 +1680,7 @@ impl Dlm for DlmClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "dlm", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1123,6 +1714,7 @@ impl Dlm for DlmClient {
         let request_uri = format!("/policies/{policy_id}", policy_id = input.policy_id);
 
         let mut request = SignedRequest::new("PATCH", "dlm", &self.region, &request_uri);
+        request.set_operation("UpdateLifecyclePolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -84,6 +84,197 @@ pub struct ApplyPendingMaintenanceActionResponse {
     pub resource_pending_maintenance_actions: Option<ResourcePendingMaintenanceActions>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AuthMechanismValue {
+    /// `default`
+    Default,
+    /// `mongodb_cr`
+    MongodbCr,
+    /// `scram_sha_1`
+    ScramSha1,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AuthMechanismValue {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AuthMechanismValue::Default => "default",
+            AuthMechanismValue::MongodbCr => "mongodb_cr",
+            AuthMechanismValue::ScramSha1 => "scram_sha_1",
+            AuthMechanismValue::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AuthMechanismValue {
+    fn default() -> AuthMechanismValue {
+        AuthMechanismValue::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AuthMechanismValue {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AuthMechanismValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AuthMechanismValue {
+    fn from(value: &'a str) -> AuthMechanismValue {
+        match value {
+            "default" => AuthMechanismValue::Default,
+            "mongodb_cr" => AuthMechanismValue::MongodbCr,
+            "scram_sha_1" => AuthMechanismValue::ScramSha1,
+            _ => AuthMechanismValue::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AuthMechanismValue {
+    fn from(value: String) -> AuthMechanismValue {
+        match value.as_str() {
+            "default" => AuthMechanismValue::Default,
+            "mongodb_cr" => AuthMechanismValue::MongodbCr,
+            "scram_sha_1" => AuthMechanismValue::ScramSha1,
+            _ => AuthMechanismValue::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AuthMechanismValue {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AuthMechanismValue, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AuthMechanismValue {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AuthMechanismValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AuthMechanismValue {
+    fn deserialize<D>(deserializer: D) -> Result<AuthMechanismValue, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AuthTypeValue {
+    /// `no`
+    No,
+    /// `password`
+    Password,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AuthTypeValue {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AuthTypeValue::No => "no",
+            AuthTypeValue::Password => "password",
+            AuthTypeValue::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AuthTypeValue {
+    fn default() -> AuthTypeValue {
+        AuthTypeValue::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AuthTypeValue {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AuthTypeValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AuthTypeValue {
+    fn from(value: &'a str) -> AuthTypeValue {
+        match value {
+            "no" => AuthTypeValue::No,
+            "password" => AuthTypeValue::Password,
+            _ => AuthTypeValue::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AuthTypeValue {
+    fn from(value: String) -> AuthTypeValue {
+        match value.as_str() {
+            "no" => AuthTypeValue::No,
+            "password" => AuthTypeValue::Password,
+            _ => AuthTypeValue::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AuthTypeValue {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AuthTypeValue, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AuthTypeValue {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AuthTypeValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AuthTypeValue {
+    fn deserialize<D>(deserializer: D) -> Result<AuthTypeValue, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -104,8 +295,13 @@ pub struct Certificate {
     pub certificate_arn: Option<String>,
     /// <p>The date that the certificate was created.</p>
     #[serde(rename = "CertificateCreationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub certificate_creation_date: Option<f64>,
+    pub certificate_creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A customer-assigned name for the certificate. Identifiers must begin with a letter; must contain only ASCII letters, digits, and hyphens; and must not end with a hyphen or contain two consecutive hyphens.</p>
     #[serde(rename = "CertificateIdentifier")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -137,14 +333,117 @@ pub struct Certificate {
     pub signing_algorithm: Option<String>,
     /// <p>The beginning date that the certificate is valid.</p>
     #[serde(rename = "ValidFromDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valid_from_date: Option<f64>,
+    pub valid_from_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The final date that the certificate is valid.</p>
     #[serde(rename = "ValidToDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valid_to_date: Option<f64>,
+    pub valid_to_date: Option<::chrono::DateTime<::chrono::Utc>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CompressionTypeValue {
+    /// `none`
+    None,
+    /// `gzip`
+    Gzip,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CompressionTypeValue {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CompressionTypeValue::None => "none",
+            CompressionTypeValue::Gzip => "gzip",
+            CompressionTypeValue::Unknown(ref value) => value,
+        }
+    }
 }
 
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CompressionTypeValue {
+    fn default() -> CompressionTypeValue {
+        CompressionTypeValue::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CompressionTypeValue {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CompressionTypeValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CompressionTypeValue {
+    fn from(value: &'a str) -> CompressionTypeValue {
+        match value {
+            "none" => CompressionTypeValue::None,
+            "gzip" => CompressionTypeValue::Gzip,
+            _ => CompressionTypeValue::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CompressionTypeValue {
+    fn from(value: String) -> CompressionTypeValue {
+        match value.as_str() {
+            "none" => CompressionTypeValue::None,
+            "gzip" => CompressionTypeValue::Gzip,
+            _ => CompressionTypeValue::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CompressionTypeValue {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CompressionTypeValue, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CompressionTypeValue {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CompressionTypeValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CompressionTypeValue {
+    fn deserialize<D>(deserializer: D) -> Result<CompressionTypeValue, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -204,7 +503,7 @@ pub struct CreateEndpointMessage {
     pub endpoint_identifier: String,
     /// <p>The type of endpoint. Valid values are <code>source</code> and <code>target</code>.</p>
     #[serde(rename = "EndpointType")]
-    pub endpoint_type: String,
+    pub endpoint_type: ReplicationEndpointTypeValue,
     /// <p>The type of engine for the endpoint. Valid values, depending on the <code>EndpointType</code> value, include <code>mysql</code>, <code>oracle</code>, <code>postgres</code>, <code>mariadb</code>, <code>aurora</code>, <code>aurora-postgresql</code>, <code>redshift</code>, <code>s3</code>, <code>db2</code>, <code>azuredb</code>, <code>sybase</code>, <code>dynamodb</code>, <code>mongodb</code>, and <code>sqlserver</code>.</p>
     #[serde(rename = "EngineName")]
     pub engine_name: String,
@@ -254,7 +553,7 @@ pub struct CreateEndpointMessage {
     /// <p>The Secure Sockets Layer (SSL) mode to use for the SSL connection. The default is <code>none</code> </p>
     #[serde(rename = "SslMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ssl_mode: Option<String>,
+    pub ssl_mode: Option<DmsSslModeValue>,
     /// <p>One or more tags to be assigned to the endpoint.</p>
     #[serde(rename = "Tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -426,15 +725,20 @@ pub struct CreateReplicationTaskMessage {
     pub cdc_start_position: Option<String>,
     /// <p>Indicates the start time for a change data capture (CDC) operation. Use either CdcStartTime or CdcStartPosition to specify when you want a CDC operation to start. Specifying both values results in an error.</p> <p>Timestamp Example: --cdc-start-time “2018-03-08T12:12:12”</p>
     #[serde(rename = "CdcStartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub cdc_start_time: Option<f64>,
+    pub cdc_start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Indicates when you want a change data capture (CDC) operation to stop. The value can be either server time or commit time.</p> <p>Server time example: --cdc-stop-position “server_time:3018-02-09T12:12:12”</p> <p>Commit time example: --cdc-stop-position “commit_time: 3018-02-09T12:12:12 “</p>
     #[serde(rename = "CdcStopPosition")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cdc_stop_position: Option<String>,
     /// <p>The migration type. Valid values: <code>full-load</code> | <code>cdc</code> | <code>full-load-and-cdc</code> </p>
     #[serde(rename = "MigrationType")]
-    pub migration_type: String,
+    pub migration_type: MigrationTypeValue,
     /// <p>The Amazon Resource Name (ARN) of a replication instance.</p>
     #[serde(rename = "ReplicationInstanceArn")]
     pub replication_instance_arn: String,
@@ -470,6 +774,99 @@ pub struct CreateReplicationTaskResponse {
     pub replication_task: Option<ReplicationTask>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DataFormatValue {
+    /// `csv`
+    Csv,
+    /// `parquet`
+    Parquet,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DataFormatValue {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DataFormatValue::Csv => "csv",
+            DataFormatValue::Parquet => "parquet",
+            DataFormatValue::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DataFormatValue {
+    fn default() -> DataFormatValue {
+        DataFormatValue::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DataFormatValue {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DataFormatValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DataFormatValue {
+    fn from(value: &'a str) -> DataFormatValue {
+        match value {
+            "csv" => DataFormatValue::Csv,
+            "parquet" => DataFormatValue::Parquet,
+            _ => DataFormatValue::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DataFormatValue {
+    fn from(value: String) -> DataFormatValue {
+        match value.as_str() {
+            "csv" => DataFormatValue::Csv,
+            "parquet" => DataFormatValue::Parquet,
+            _ => DataFormatValue::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DataFormatValue {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DataFormatValue, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DataFormatValue {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DataFormatValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DataFormatValue {
+    fn deserialize<D>(deserializer: D) -> Result<DataFormatValue, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct DeleteCertificateMessage {
@@ -814,8 +1211,13 @@ pub struct DescribeEventsMessage {
     pub duration: Option<i64>,
     /// <p>The end time for the events to be listed.</p>
     #[serde(rename = "EndTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A list of event categories for the source type that you've chosen.</p>
     #[serde(rename = "EventCategories")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -839,11 +1241,16 @@ pub struct DescribeEventsMessage {
     /// <p>The type of AWS DMS resource that generates events.</p> <p>Valid values: replication-instance | replication-task</p>
     #[serde(rename = "SourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub source_type: Option<String>,
+    pub source_type: Option<SourceType>,
     /// <p>The start time for the events to be listed.</p>
     #[serde(rename = "StartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p><p/></p>
@@ -1182,6 +1589,109 @@ pub struct DescribeTableStatisticsResponse {
     pub table_statistics: Option<Vec<TableStatistics>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DmsSslModeValue {
+    /// `none`
+    None,
+    /// `require`
+    Require,
+    /// `verify-ca`
+    VerifyCa,
+    /// `verify-full`
+    VerifyFull,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DmsSslModeValue {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DmsSslModeValue::None => "none",
+            DmsSslModeValue::Require => "require",
+            DmsSslModeValue::VerifyCa => "verify-ca",
+            DmsSslModeValue::VerifyFull => "verify-full",
+            DmsSslModeValue::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DmsSslModeValue {
+    fn default() -> DmsSslModeValue {
+        DmsSslModeValue::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DmsSslModeValue {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DmsSslModeValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DmsSslModeValue {
+    fn from(value: &'a str) -> DmsSslModeValue {
+        match value {
+            "none" => DmsSslModeValue::None,
+            "require" => DmsSslModeValue::Require,
+            "verify-ca" => DmsSslModeValue::VerifyCa,
+            "verify-full" => DmsSslModeValue::VerifyFull,
+            _ => DmsSslModeValue::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DmsSslModeValue {
+    fn from(value: String) -> DmsSslModeValue {
+        match value.as_str() {
+            "none" => DmsSslModeValue::None,
+            "require" => DmsSslModeValue::Require,
+            "verify-ca" => DmsSslModeValue::VerifyCa,
+            "verify-full" => DmsSslModeValue::VerifyFull,
+            _ => DmsSslModeValue::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DmsSslModeValue {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DmsSslModeValue, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DmsSslModeValue {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DmsSslModeValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DmsSslModeValue {
+    fn deserialize<D>(deserializer: D) -> Result<DmsSslModeValue, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p> The settings in JSON format for the DMS Transfer type source endpoint. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DmsTransferSettings {
@@ -1222,43 +1732,234 @@ pub struct ElasticsearchSettings {
     pub service_access_role_arn: String,
 }
 
-/// <p><p/></p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct Endpoint {
-    /// <p>The Amazon Resource Name (ARN) used for SSL connection to the endpoint.</p>
-    #[serde(rename = "CertificateArn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub certificate_arn: Option<String>,
-    /// <p>The name of the database at the endpoint.</p>
-    #[serde(rename = "DatabaseName")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub database_name: Option<String>,
-    /// <p>The settings in JSON format for the DMS transfer type of source endpoint. </p> <p>Possible settings include the following:</p> <ul> <li> <p> <code>ServiceAccessRoleArn</code> - The IAM role that has permission to access the Amazon S3 bucket.</p> </li> <li> <p> <code>BucketName</code> - The name of the S3 bucket to use.</p> </li> <li> <p> <code>CompressionType</code> - An optional parameter to use GZIP to compress the target files. To use GZIP, set this value to <code>NONE</code> (the default). To keep the files uncompressed, don't use this value.</p> </li> </ul> <p>Shorthand syntax for these settings is as follows: <code>ServiceAccessRoleArn=string,BucketName=string,CompressionType=string</code> </p> <p>JSON syntax for these settings is as follows: <code>{ "ServiceAccessRoleArn": "string", "BucketName": "string", "CompressionType": "none"|"gzip" } </code> </p>
-    #[serde(rename = "DmsTransferSettings")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub dms_transfer_settings: Option<DmsTransferSettings>,
-    /// <p>The settings for the target DynamoDB database. For more information, see the <code>DynamoDBSettings</code> structure.</p>
-    #[serde(rename = "DynamoDbSettings")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub dynamo_db_settings: Option<DynamoDbSettings>,
-    /// <p>The settings for the Elasticsearch source endpoint. For more information, see the <code>ElasticsearchSettings</code> structure.</p>
-    #[serde(rename = "ElasticsearchSettings")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub elasticsearch_settings: Option<ElasticsearchSettings>,
-    /// <p>The Amazon Resource Name (ARN) string that uniquely identifies the endpoint.</p>
-    #[serde(rename = "EndpointArn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub endpoint_arn: Option<String>,
-    /// <p>The database endpoint identifier. Identifiers must begin with a letter; must contain only ASCII letters, digits, and hyphens; and must not end with a hyphen or contain two consecutive hyphens.</p>
-    #[serde(rename = "EndpointIdentifier")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub endpoint_identifier: Option<String>,
-    /// <p>The type of endpoint. Valid values are <code>source</code> and <code>target</code>.</p>
-    #[serde(rename = "EndpointType")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub endpoint_type: Option<String>,
-    /// <p>The expanded name for the engine name. For example, if the <code>EngineName</code> parameter is "aurora," this value would be "Amazon Aurora MySQL."</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EncodingTypeValue {
+    /// `plain`
+    Plain,
+    /// `plain-dictionary`
+    PlainDictionary,
+    /// `rle-dictionary`
+    RleDictionary,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EncodingTypeValue {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EncodingTypeValue::Plain => "plain",
+            EncodingTypeValue::PlainDictionary => "plain-dictionary",
+            EncodingTypeValue::RleDictionary => "rle-dictionary",
+            EncodingTypeValue::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EncodingTypeValue {
+    fn default() -> EncodingTypeValue {
+        EncodingTypeValue::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EncodingTypeValue {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EncodingTypeValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EncodingTypeValue {
+    fn from(value: &'a str) -> EncodingTypeValue {
+        match value {
+            "plain" => EncodingTypeValue::Plain,
+            "plain-dictionary" => EncodingTypeValue::PlainDictionary,
+            "rle-dictionary" => EncodingTypeValue::RleDictionary,
+            _ => EncodingTypeValue::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EncodingTypeValue {
+    fn from(value: String) -> EncodingTypeValue {
+        match value.as_str() {
+            "plain" => EncodingTypeValue::Plain,
+            "plain-dictionary" => EncodingTypeValue::PlainDictionary,
+            "rle-dictionary" => EncodingTypeValue::RleDictionary,
+            _ => EncodingTypeValue::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EncodingTypeValue {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EncodingTypeValue, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EncodingTypeValue {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EncodingTypeValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EncodingTypeValue {
+    fn deserialize<D>(deserializer: D) -> Result<EncodingTypeValue, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EncryptionModeValue {
+    /// `sse-s3`
+    SseS3,
+    /// `sse-kms`
+    SseKms,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EncryptionModeValue {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EncryptionModeValue::SseS3 => "sse-s3",
+            EncryptionModeValue::SseKms => "sse-kms",
+            EncryptionModeValue::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EncryptionModeValue {
+    fn default() -> EncryptionModeValue {
+        EncryptionModeValue::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EncryptionModeValue {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EncryptionModeValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EncryptionModeValue {
+    fn from(value: &'a str) -> EncryptionModeValue {
+        match value {
+            "sse-s3" => EncryptionModeValue::SseS3,
+            "sse-kms" => EncryptionModeValue::SseKms,
+            _ => EncryptionModeValue::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EncryptionModeValue {
+    fn from(value: String) -> EncryptionModeValue {
+        match value.as_str() {
+            "sse-s3" => EncryptionModeValue::SseS3,
+            "sse-kms" => EncryptionModeValue::SseKms,
+            _ => EncryptionModeValue::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EncryptionModeValue {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EncryptionModeValue, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EncryptionModeValue {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EncryptionModeValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EncryptionModeValue {
+    fn deserialize<D>(deserializer: D) -> Result<EncryptionModeValue, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p><p/></p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct Endpoint {
+    /// <p>The Amazon Resource Name (ARN) used for SSL connection to the endpoint.</p>
+    #[serde(rename = "CertificateArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub certificate_arn: Option<String>,
+    /// <p>The name of the database at the endpoint.</p>
+    #[serde(rename = "DatabaseName")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub database_name: Option<String>,
+    /// <p>The settings in JSON format for the DMS transfer type of source endpoint. </p> <p>Possible settings include the following:</p> <ul> <li> <p> <code>ServiceAccessRoleArn</code> - The IAM role that has permission to access the Amazon S3 bucket.</p> </li> <li> <p> <code>BucketName</code> - The name of the S3 bucket to use.</p> </li> <li> <p> <code>CompressionType</code> - An optional parameter to use GZIP to compress the target files. To use GZIP, set this value to <code>NONE</code> (the default). To keep the files uncompressed, don't use this value.</p> </li> </ul> <p>Shorthand syntax for these settings is as follows: <code>ServiceAccessRoleArn=string,BucketName=string,CompressionType=string</code> </p> <p>JSON syntax for these settings is as follows: <code>{ "ServiceAccessRoleArn": "string", "BucketName": "string", "CompressionType": "none"|"gzip" } </code> </p>
+    #[serde(rename = "DmsTransferSettings")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dms_transfer_settings: Option<DmsTransferSettings>,
+    /// <p>The settings for the target DynamoDB database. For more information, see the <code>DynamoDBSettings</code> structure.</p>
+    #[serde(rename = "DynamoDbSettings")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dynamo_db_settings: Option<DynamoDbSettings>,
+    /// <p>The settings for the Elasticsearch source endpoint. For more information, see the <code>ElasticsearchSettings</code> structure.</p>
+    #[serde(rename = "ElasticsearchSettings")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub elasticsearch_settings: Option<ElasticsearchSettings>,
+    /// <p>The Amazon Resource Name (ARN) string that uniquely identifies the endpoint.</p>
+    #[serde(rename = "EndpointArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub endpoint_arn: Option<String>,
+    /// <p>The database endpoint identifier. Identifiers must begin with a letter; must contain only ASCII letters, digits, and hyphens; and must not end with a hyphen or contain two consecutive hyphens.</p>
+    #[serde(rename = "EndpointIdentifier")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub endpoint_identifier: Option<String>,
+    /// <p>The type of endpoint. Valid values are <code>source</code> and <code>target</code>.</p>
+    #[serde(rename = "EndpointType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub endpoint_type: Option<ReplicationEndpointTypeValue>,
+    /// <p>The expanded name for the engine name. For example, if the <code>EngineName</code> parameter is "aurora," this value would be "Amazon Aurora MySQL."</p>
     #[serde(rename = "EngineDisplayName")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub engine_display_name: Option<String>,
@@ -1313,7 +2014,7 @@ pub struct Endpoint {
     /// <p>The SSL mode used to connect to the endpoint. The default value is <code>none</code>.</p>
     #[serde(rename = "SslMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ssl_mode: Option<String>,
+    pub ssl_mode: Option<DmsSslModeValue>,
     /// <p>The status of the endpoint.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1330,8 +2031,13 @@ pub struct Endpoint {
 pub struct Event {
     /// <p>The date of the event.</p>
     #[serde(rename = "Date")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub date: Option<f64>,
+    pub date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The event categories available for the specified source type.</p>
     #[serde(rename = "EventCategories")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1347,7 +2053,7 @@ pub struct Event {
     /// <p> The type of AWS DMS resource that generates events. </p> <p>Valid values: replication-instance | endpoint | replication-task</p>
     #[serde(rename = "SourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub source_type: Option<String>,
+    pub source_type: Option<SourceType>,
 }
 
 /// <p><p/></p>
@@ -1458,7 +2164,7 @@ pub struct KinesisSettings {
     /// <p>The output format for the records created on the endpoint. The message format is <code>JSON</code>.</p>
     #[serde(rename = "MessageFormat")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub message_format: Option<String>,
+    pub message_format: Option<MessageFormatValue>,
     /// <p>The Amazon Resource Name (ARN) for the IAM role that DMS uses to write to the Amazon Kinesis data stream.</p>
     #[serde(rename = "ServiceAccessRoleArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1488,6 +2194,197 @@ pub struct ListTagsForResourceResponse {
     pub tag_list: Option<Vec<Tag>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MessageFormatValue {
+    /// `json`
+    Json,
+    /// `json-unformatted`
+    JsonUnformatted,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl MessageFormatValue {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MessageFormatValue::Json => "json",
+            MessageFormatValue::JsonUnformatted => "json-unformatted",
+            MessageFormatValue::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for MessageFormatValue {
+    fn default() -> MessageFormatValue {
+        MessageFormatValue::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for MessageFormatValue {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for MessageFormatValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for MessageFormatValue {
+    fn from(value: &'a str) -> MessageFormatValue {
+        match value {
+            "json" => MessageFormatValue::Json,
+            "json-unformatted" => MessageFormatValue::JsonUnformatted,
+            _ => MessageFormatValue::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MessageFormatValue {
+    fn from(value: String) -> MessageFormatValue {
+        match value.as_str() {
+            "json" => MessageFormatValue::Json,
+            "json-unformatted" => MessageFormatValue::JsonUnformatted,
+            _ => MessageFormatValue::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for MessageFormatValue {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<MessageFormatValue, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MessageFormatValue {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for MessageFormatValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MessageFormatValue {
+    fn deserialize<D>(deserializer: D) -> Result<MessageFormatValue, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MigrationTypeValue {
+    /// `full-load`
+    FullLoad,
+    /// `cdc`
+    Cdc,
+    /// `full-load-and-cdc`
+    FullLoadAndCdc,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl MigrationTypeValue {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MigrationTypeValue::FullLoad => "full-load",
+            MigrationTypeValue::Cdc => "cdc",
+            MigrationTypeValue::FullLoadAndCdc => "full-load-and-cdc",
+            MigrationTypeValue::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for MigrationTypeValue {
+    fn default() -> MigrationTypeValue {
+        MigrationTypeValue::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for MigrationTypeValue {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for MigrationTypeValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for MigrationTypeValue {
+    fn from(value: &'a str) -> MigrationTypeValue {
+        match value {
+            "full-load" => MigrationTypeValue::FullLoad,
+            "cdc" => MigrationTypeValue::Cdc,
+            "full-load-and-cdc" => MigrationTypeValue::FullLoadAndCdc,
+            _ => MigrationTypeValue::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MigrationTypeValue {
+    fn from(value: String) -> MigrationTypeValue {
+        match value.as_str() {
+            "full-load" => MigrationTypeValue::FullLoad,
+            "cdc" => MigrationTypeValue::Cdc,
+            "full-load-and-cdc" => MigrationTypeValue::FullLoadAndCdc,
+            _ => MigrationTypeValue::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for MigrationTypeValue {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<MigrationTypeValue, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MigrationTypeValue {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for MigrationTypeValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MigrationTypeValue {
+    fn deserialize<D>(deserializer: D) -> Result<MigrationTypeValue, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -1522,7 +2419,7 @@ pub struct ModifyEndpointMessage {
     /// <p>The type of endpoint. Valid values are <code>source</code> and <code>target</code>.</p>
     #[serde(rename = "EndpointType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub endpoint_type: Option<String>,
+    pub endpoint_type: Option<ReplicationEndpointTypeValue>,
     /// <p>The type of engine for the endpoint. Valid values, depending on the EndpointType, include mysql, oracle, postgres, mariadb, aurora, aurora-postgresql, redshift, s3, db2, azuredb, sybase, dynamodb, mongodb, and sqlserver.</p>
     #[serde(rename = "EngineName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1569,7 +2466,7 @@ pub struct ModifyEndpointMessage {
     /// <p>The SSL mode used to connect to the endpoint. The default value is <code>none</code>.</p>
     #[serde(rename = "SslMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ssl_mode: Option<String>,
+    pub ssl_mode: Option<DmsSslModeValue>,
     /// <p>The user name to be used to login to the endpoint database.</p>
     #[serde(rename = "Username")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1716,8 +2613,13 @@ pub struct ModifyReplicationTaskMessage {
     pub cdc_start_position: Option<String>,
     /// <p>Indicates the start time for a change data capture (CDC) operation. Use either CdcStartTime or CdcStartPosition to specify when you want a CDC operation to start. Specifying both values results in an error.</p> <p>Timestamp Example: --cdc-start-time “2018-03-08T12:12:12”</p>
     #[serde(rename = "CdcStartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub cdc_start_time: Option<f64>,
+    pub cdc_start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Indicates when you want a change data capture (CDC) operation to stop. The value can be either server time or commit time.</p> <p>Server time example: --cdc-stop-position “server_time:3018-02-09T12:12:12”</p> <p>Commit time example: --cdc-stop-position “commit_time: 3018-02-09T12:12:12 “</p>
     #[serde(rename = "CdcStopPosition")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1725,7 +2627,7 @@ pub struct ModifyReplicationTaskMessage {
     /// <p>The migration type. Valid values: <code>full-load</code> | <code>cdc</code> | <code>full-load-and-cdc</code> </p>
     #[serde(rename = "MigrationType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub migration_type: Option<String>,
+    pub migration_type: Option<MigrationTypeValue>,
     /// <p>The Amazon Resource Name (ARN) of the replication task.</p>
     #[serde(rename = "ReplicationTaskArn")]
     pub replication_task_arn: String,
@@ -1759,7 +2661,7 @@ pub struct MongoDbSettings {
     /// <p> The authentication mechanism you use to access the MongoDB source endpoint.</p> <p>Valid values: DEFAULT, MONGODB_CR, SCRAM_SHA_1 </p> <p>DEFAULT – For MongoDB version 2.x, use MONGODB_CR. For MongoDB version 3.x, use SCRAM_SHA_1. This setting is not used when authType=No.</p>
     #[serde(rename = "AuthMechanism")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub auth_mechanism: Option<String>,
+    pub auth_mechanism: Option<AuthMechanismValue>,
     /// <p> The MongoDB database name. This setting is not used when <code>authType=NO</code>. </p> <p>The default is admin.</p>
     #[serde(rename = "AuthSource")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1767,7 +2669,7 @@ pub struct MongoDbSettings {
     /// <p> The authentication type you use to access the MongoDB source endpoint.</p> <p>Valid values: NO, PASSWORD </p> <p>When NO is selected, user name and password parameters are not used and can be empty. </p>
     #[serde(rename = "AuthType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub auth_type: Option<String>,
+    pub auth_type: Option<AuthTypeValue>,
     /// <p> The database name on the MongoDB source endpoint. </p>
     #[serde(rename = "DatabaseName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1787,7 +2689,7 @@ pub struct MongoDbSettings {
     /// <p> Specifies either document or table mode. </p> <p>Valid values: NONE, ONE</p> <p>Default value is NONE. Specify NONE to use document mode. Specify ONE to use table mode.</p>
     #[serde(rename = "NestingLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub nesting_level: Option<String>,
+    pub nesting_level: Option<NestingLevelValue>,
     /// <p> The password for the user account you use to access the MongoDB source endpoint. </p>
     #[serde(rename = "Password")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1806,6 +2708,99 @@ pub struct MongoDbSettings {
     pub username: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum NestingLevelValue {
+    /// `none`
+    None,
+    /// `one`
+    One,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl NestingLevelValue {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            NestingLevelValue::None => "none",
+            NestingLevelValue::One => "one",
+            NestingLevelValue::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for NestingLevelValue {
+    fn default() -> NestingLevelValue {
+        NestingLevelValue::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for NestingLevelValue {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for NestingLevelValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for NestingLevelValue {
+    fn from(value: &'a str) -> NestingLevelValue {
+        match value {
+            "none" => NestingLevelValue::None,
+            "one" => NestingLevelValue::One,
+            _ => NestingLevelValue::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for NestingLevelValue {
+    fn from(value: String) -> NestingLevelValue {
+        match value.as_str() {
+            "none" => NestingLevelValue::None,
+            "one" => NestingLevelValue::One,
+            _ => NestingLevelValue::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for NestingLevelValue {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<NestingLevelValue, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for NestingLevelValue {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for NestingLevelValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for NestingLevelValue {
+    fn deserialize<D>(deserializer: D) -> Result<NestingLevelValue, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1837,7 +2832,7 @@ pub struct OrderableReplicationInstance {
     /// <p><p>The value returned when the specified <code>EngineVersion</code> of the replication instance is in Beta or test mode. This indicates some features might not work as expected.</p> <note> <p>AWS DMS supports the <code>ReleaseStatus</code> parameter in versions 3.1.4 and later.</p> </note></p>
     #[serde(rename = "ReleaseStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub release_status: Option<String>,
+    pub release_status: Option<ReleaseStatusValues>,
     /// <p>The compute and memory capacity of the replication instance.</p> <p> Valid Values: <code>dms.t2.micro | dms.t2.small | dms.t2.medium | dms.t2.large | dms.c4.large | dms.c4.xlarge | dms.c4.2xlarge | dms.c4.4xlarge </code> </p>
     #[serde(rename = "ReplicationInstanceClass")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1848,30 +2843,138 @@ pub struct OrderableReplicationInstance {
     pub storage_type: Option<String>,
 }
 
-/// <p><p/></p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct PendingMaintenanceAction {
-    /// <p>The type of pending maintenance action that is available for the resource.</p>
-    #[serde(rename = "Action")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub action: Option<String>,
-    /// <p>The date of the maintenance window when the action will be applied. The maintenance action will be applied to the resource during its first maintenance window after this date. If this date is specified, any <code>next-maintenance</code> opt-in requests are ignored.</p>
-    #[serde(rename = "AutoAppliedAfterDate")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub auto_applied_after_date: Option<f64>,
-    /// <p>The effective date when the pending maintenance action will be applied to the resource. This date takes into account opt-in requests received from the <code>ApplyPendingMaintenanceAction</code> API, the <code>AutoAppliedAfterDate</code>, and the <code>ForcedApplyDate</code>. This value is blank if an opt-in request has not been received and nothing has been specified as <code>AutoAppliedAfterDate</code> or <code>ForcedApplyDate</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ParquetVersionValue {
+    /// `parquet-1-0`
+    Parquet1_0,
+    /// `parquet-2-0`
+    Parquet2_0,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ParquetVersionValue {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ParquetVersionValue::Parquet1_0 => "parquet-1-0",
+            ParquetVersionValue::Parquet2_0 => "parquet-2-0",
+            ParquetVersionValue::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ParquetVersionValue {
+    fn default() -> ParquetVersionValue {
+        ParquetVersionValue::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ParquetVersionValue {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ParquetVersionValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ParquetVersionValue {
+    fn from(value: &'a str) -> ParquetVersionValue {
+        match value {
+            "parquet-1-0" => ParquetVersionValue::Parquet1_0,
+            "parquet-2-0" => ParquetVersionValue::Parquet2_0,
+            _ => ParquetVersionValue::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ParquetVersionValue {
+    fn from(value: String) -> ParquetVersionValue {
+        match value.as_str() {
+            "parquet-1-0" => ParquetVersionValue::Parquet1_0,
+            "parquet-2-0" => ParquetVersionValue::Parquet2_0,
+            _ => ParquetVersionValue::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ParquetVersionValue {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ParquetVersionValue, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ParquetVersionValue {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ParquetVersionValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ParquetVersionValue {
+    fn deserialize<D>(deserializer: D) -> Result<ParquetVersionValue, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p><p/></p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct PendingMaintenanceAction {
+    /// <p>The type of pending maintenance action that is available for the resource.</p>
+    #[serde(rename = "Action")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    /// <p>The date of the maintenance window when the action will be applied. The maintenance action will be applied to the resource during its first maintenance window after this date. If this date is specified, any <code>next-maintenance</code> opt-in requests are ignored.</p>
+    #[serde(rename = "AutoAppliedAfterDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_applied_after_date: Option<::chrono::DateTime<::chrono::Utc>>,
+    /// <p>The effective date when the pending maintenance action will be applied to the resource. This date takes into account opt-in requests received from the <code>ApplyPendingMaintenanceAction</code> API, the <code>AutoAppliedAfterDate</code>, and the <code>ForcedApplyDate</code>. This value is blank if an opt-in request has not been received and nothing has been specified as <code>AutoAppliedAfterDate</code> or <code>ForcedApplyDate</code>.</p>
     #[serde(rename = "CurrentApplyDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub current_apply_date: Option<f64>,
+    pub current_apply_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A description providing more detail about the maintenance action.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     /// <p>The date when the maintenance action will be automatically applied. The maintenance action will be applied to the resource on this date regardless of the maintenance window for the resource. If this date is specified, any <code>immediate</code> opt-in requests are ignored.</p>
     #[serde(rename = "ForcedApplyDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub forced_apply_date: Option<f64>,
+    pub forced_apply_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Indicates the type of opt-in request that has been received for the resource.</p>
     #[serde(rename = "OptInStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1937,7 +3040,7 @@ pub struct RedshiftSettings {
     /// <p>The type of server-side encryption that you want to use for your data. This encryption type is part of the endpoint settings or the extra connections attributes for Amazon S3. You can choose either <code>SSE_S3</code> (the default) or <code>SSE_KMS</code>. To use <code>SSE_S3</code>, create an AWS Identity and Access Management (IAM) role with a policy that allows <code>"arn:aws:s3:::*"</code> to use the following actions: <code>"s3:PutObject", "s3:ListBucket"</code> </p>
     #[serde(rename = "EncryptionMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub encryption_mode: Option<String>,
+    pub encryption_mode: Option<EncryptionModeValue>,
     /// <p>The number of threads used to upload a single file. This parameter accepts a value from 1 through 64. It defaults to 10.</p>
     #[serde(rename = "FileTransferUploadStreams")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2040,8 +3143,13 @@ pub struct RefreshSchemasStatus {
     pub last_failure_message: Option<String>,
     /// <p>The date the schema was last refreshed.</p>
     #[serde(rename = "LastRefreshDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_refresh_date: Option<f64>,
+    pub last_refresh_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The Amazon Resource Name (ARN) of the replication instance.</p>
     #[serde(rename = "ReplicationInstanceArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2049,16 +3157,300 @@ pub struct RefreshSchemasStatus {
     /// <p>The status of the schema.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<RefreshSchemasStatusTypeValue>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RefreshSchemasStatusTypeValue {
+    /// `successful`
+    Successful,
+    /// `failed`
+    Failed,
+    /// `refreshing`
+    Refreshing,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RefreshSchemasStatusTypeValue {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RefreshSchemasStatusTypeValue::Successful => "successful",
+            RefreshSchemasStatusTypeValue::Failed => "failed",
+            RefreshSchemasStatusTypeValue::Refreshing => "refreshing",
+            RefreshSchemasStatusTypeValue::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RefreshSchemasStatusTypeValue {
+    fn default() -> RefreshSchemasStatusTypeValue {
+        RefreshSchemasStatusTypeValue::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RefreshSchemasStatusTypeValue {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RefreshSchemasStatusTypeValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RefreshSchemasStatusTypeValue {
+    fn from(value: &'a str) -> RefreshSchemasStatusTypeValue {
+        match value {
+            "successful" => RefreshSchemasStatusTypeValue::Successful,
+            "failed" => RefreshSchemasStatusTypeValue::Failed,
+            "refreshing" => RefreshSchemasStatusTypeValue::Refreshing,
+            _ => RefreshSchemasStatusTypeValue::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RefreshSchemasStatusTypeValue {
+    fn from(value: String) -> RefreshSchemasStatusTypeValue {
+        match value.as_str() {
+            "successful" => RefreshSchemasStatusTypeValue::Successful,
+            "failed" => RefreshSchemasStatusTypeValue::Failed,
+            "refreshing" => RefreshSchemasStatusTypeValue::Refreshing,
+            _ => RefreshSchemasStatusTypeValue::Unknown(value),
+        }
+    }
 }
 
+impl ::std::str::FromStr for RefreshSchemasStatusTypeValue {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RefreshSchemasStatusTypeValue, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RefreshSchemasStatusTypeValue {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RefreshSchemasStatusTypeValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RefreshSchemasStatusTypeValue {
+    fn deserialize<D>(deserializer: D) -> Result<RefreshSchemasStatusTypeValue, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ReleaseStatusValues {
+    /// `beta`
+    Beta,
+    /// `prod`
+    Prod,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ReleaseStatusValues {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReleaseStatusValues::Beta => "beta",
+            ReleaseStatusValues::Prod => "prod",
+            ReleaseStatusValues::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ReleaseStatusValues {
+    fn default() -> ReleaseStatusValues {
+        ReleaseStatusValues::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ReleaseStatusValues {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ReleaseStatusValues {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ReleaseStatusValues {
+    fn from(value: &'a str) -> ReleaseStatusValues {
+        match value {
+            "beta" => ReleaseStatusValues::Beta,
+            "prod" => ReleaseStatusValues::Prod,
+            _ => ReleaseStatusValues::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReleaseStatusValues {
+    fn from(value: String) -> ReleaseStatusValues {
+        match value.as_str() {
+            "beta" => ReleaseStatusValues::Beta,
+            "prod" => ReleaseStatusValues::Prod,
+            _ => ReleaseStatusValues::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ReleaseStatusValues {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ReleaseStatusValues, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ReleaseStatusValues {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ReleaseStatusValues {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ReleaseStatusValues {
+    fn deserialize<D>(deserializer: D) -> Result<ReleaseStatusValues, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ReloadOptionValue {
+    /// `data-reload`
+    DataReload,
+    /// `validate-only`
+    ValidateOnly,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ReloadOptionValue {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReloadOptionValue::DataReload => "data-reload",
+            ReloadOptionValue::ValidateOnly => "validate-only",
+            ReloadOptionValue::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ReloadOptionValue {
+    fn default() -> ReloadOptionValue {
+        ReloadOptionValue::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ReloadOptionValue {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ReloadOptionValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ReloadOptionValue {
+    fn from(value: &'a str) -> ReloadOptionValue {
+        match value {
+            "data-reload" => ReloadOptionValue::DataReload,
+            "validate-only" => ReloadOptionValue::ValidateOnly,
+            _ => ReloadOptionValue::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReloadOptionValue {
+    fn from(value: String) -> ReloadOptionValue {
+        match value.as_str() {
+            "data-reload" => ReloadOptionValue::DataReload,
+            "validate-only" => ReloadOptionValue::ValidateOnly,
+            _ => ReloadOptionValue::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ReloadOptionValue {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ReloadOptionValue, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ReloadOptionValue {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ReloadOptionValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ReloadOptionValue {
+    fn deserialize<D>(deserializer: D) -> Result<ReloadOptionValue, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ReloadTablesMessage {
     /// <p>Options for reload. Specify <code>data-reload</code> to reload the data and re-validate it if validation is enabled. Specify <code>validate-only</code> to re-validate the table. This option applies only when validation is enabled for the task. </p> <p>Valid values: data-reload, validate-only</p> <p>Default value is data-reload.</p>
     #[serde(rename = "ReloadOption")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub reload_option: Option<String>,
+    pub reload_option: Option<ReloadOptionValue>,
     /// <p>The Amazon Resource Name (ARN) of the replication task. </p>
     #[serde(rename = "ReplicationTaskArn")]
     pub replication_task_arn: String,
@@ -2093,6 +3485,99 @@ pub struct RemoveTagsFromResourceMessage {
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RemoveTagsFromResourceResponse {}
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ReplicationEndpointTypeValue {
+    /// `source`
+    Source,
+    /// `target`
+    Target,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ReplicationEndpointTypeValue {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReplicationEndpointTypeValue::Source => "source",
+            ReplicationEndpointTypeValue::Target => "target",
+            ReplicationEndpointTypeValue::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ReplicationEndpointTypeValue {
+    fn default() -> ReplicationEndpointTypeValue {
+        ReplicationEndpointTypeValue::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ReplicationEndpointTypeValue {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ReplicationEndpointTypeValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ReplicationEndpointTypeValue {
+    fn from(value: &'a str) -> ReplicationEndpointTypeValue {
+        match value {
+            "source" => ReplicationEndpointTypeValue::Source,
+            "target" => ReplicationEndpointTypeValue::Target,
+            _ => ReplicationEndpointTypeValue::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReplicationEndpointTypeValue {
+    fn from(value: String) -> ReplicationEndpointTypeValue {
+        match value.as_str() {
+            "source" => ReplicationEndpointTypeValue::Source,
+            "target" => ReplicationEndpointTypeValue::Target,
+            _ => ReplicationEndpointTypeValue::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ReplicationEndpointTypeValue {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ReplicationEndpointTypeValue, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ReplicationEndpointTypeValue {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ReplicationEndpointTypeValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ReplicationEndpointTypeValue {
+    fn deserialize<D>(deserializer: D) -> Result<ReplicationEndpointTypeValue, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -2119,12 +3604,22 @@ pub struct ReplicationInstance {
     pub engine_version: Option<String>,
     /// <p> The expiration date of the free replication instance that is part of the Free DMS program. </p>
     #[serde(rename = "FreeUntil")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub free_until: Option<f64>,
+    pub free_until: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The time the replication instance was created.</p>
     #[serde(rename = "InstanceCreateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub instance_create_time: Option<f64>,
+    pub instance_create_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>An AWS KMS key identifier that is used to encrypt the data on the replication instance.</p> <p>If you don't specify a value for the <code>KmsKeyId</code> parameter, then AWS DMS uses your default encryption key.</p> <p>AWS KMS creates the default encryption key for your AWS account. Your AWS account has a different default encryption key for each AWS Region.</p>
     #[serde(rename = "KmsKeyId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2268,7 +3763,7 @@ pub struct ReplicationTask {
     /// <p>The type of migration.</p>
     #[serde(rename = "MigrationType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub migration_type: Option<String>,
+    pub migration_type: Option<MigrationTypeValue>,
     /// <p>Indicates the last checkpoint that occurred during a change data capture (CDC) operation. You can provide this value to the <code>CdcStartPosition</code> parameter to start a CDC operation that begins at that checkpoint.</p>
     #[serde(rename = "RecoveryCheckpoint")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2283,8 +3778,13 @@ pub struct ReplicationTask {
     pub replication_task_arn: Option<String>,
     /// <p>The date the replication task was created.</p>
     #[serde(rename = "ReplicationTaskCreationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub replication_task_creation_date: Option<f64>,
+    pub replication_task_creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>The user-assigned replication task identifier or name.</p> <p>Constraints:</p> <ul> <li> <p>Must contain from 1 to 255 alphanumeric characters or hyphens.</p> </li> <li> <p>First character must be a letter.</p> </li> <li> <p>Cannot end with a hyphen or contain two consecutive hyphens.</p> </li> </ul></p>
     #[serde(rename = "ReplicationTaskIdentifier")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2295,8 +3795,13 @@ pub struct ReplicationTask {
     pub replication_task_settings: Option<String>,
     /// <p>The date the replication task is scheduled to start.</p>
     #[serde(rename = "ReplicationTaskStartDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub replication_task_start_date: Option<f64>,
+    pub replication_task_start_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The statistics for the task, including elapsed time, tables loaded, and table errors.</p>
     #[serde(rename = "ReplicationTaskStats")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2349,8 +3854,13 @@ pub struct ReplicationTaskAssessmentResult {
     pub replication_task_identifier: Option<String>,
     /// <p>The date the task assessment was completed. </p>
     #[serde(rename = "ReplicationTaskLastAssessmentDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub replication_task_last_assessment_date: Option<f64>,
+    pub replication_task_last_assessment_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p> The URL of the S3 object containing the task assessment results. </p>
     #[serde(rename = "S3ObjectUrl")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2367,28 +3877,53 @@ pub struct ReplicationTaskStats {
     pub elapsed_time_millis: Option<i64>,
     /// <p>The date the replication task was started either with a fresh start or a target reload.</p>
     #[serde(rename = "FreshStartDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub fresh_start_date: Option<f64>,
+    pub fresh_start_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The date the replication task full load was completed.</p>
     #[serde(rename = "FullLoadFinishDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub full_load_finish_date: Option<f64>,
+    pub full_load_finish_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The percent complete for the full load migration task.</p>
     #[serde(rename = "FullLoadProgressPercent")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub full_load_progress_percent: Option<i64>,
     /// <p>The date the the replication task full load was started.</p>
     #[serde(rename = "FullLoadStartDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub full_load_start_date: Option<f64>,
+    pub full_load_start_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The date the replication task was started either with a fresh start or a resume. For more information, see <a href="https://docs.aws.amazon.com/dms/latest/APIReference/API_StartReplicationTask.html#DMS-StartReplicationTask-request-StartReplicationTaskType">StartReplicationTaskType</a>.</p>
     #[serde(rename = "StartDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_date: Option<f64>,
+    pub start_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The date the replication task was stopped.</p>
     #[serde(rename = "StopDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stop_date: Option<f64>,
+    pub stop_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The number of errors that have occurred during this task.</p>
     #[serde(rename = "TablesErrored")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2439,7 +3974,7 @@ pub struct S3Settings {
     /// <p> An optional parameter to use GZIP to compress the target files. Set to GZIP to compress the target files. Set to NONE (the default) or do not use to leave the files uncompressed. Applies to both .csv and .parquet file formats. </p>
     #[serde(rename = "CompressionType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compression_type: Option<String>,
+    pub compression_type: Option<CompressionTypeValue>,
     /// <p> The delimiter used to separate columns in the source files. The default is a comma. </p>
     #[serde(rename = "CsvDelimiter")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2451,7 +3986,7 @@ pub struct S3Settings {
     /// <p><p>The format of the data that you want to use for output. You can choose one of the following: </p> <ul> <li> <p> <code>csv</code> : This is a row-based file format with comma-separated values (.csv). </p> </li> <li> <p> <code>parquet</code> : Apache Parquet (.parquet) is a columnar storage file format that features efficient compression and provides faster query response. </p> </li> </ul></p>
     #[serde(rename = "DataFormat")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub data_format: Option<String>,
+    pub data_format: Option<DataFormatValue>,
     /// <p>The size of one data page in bytes. This parameter defaults to 1024 * 1024 bytes (1 MiB). This number is used for .parquet file format only. </p>
     #[serde(rename = "DataPageSize")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2467,11 +4002,11 @@ pub struct S3Settings {
     /// <p><p>The type of encoding you are using: </p> <ul> <li> <p> <code>RLE<em>DICTIONARY</code> uses a combination of bit-packing and run-length encoding to store repeated values more efficiently. This is the default.</p> </li> <li> <p> <code>PLAIN</code> doesn&#39;t use encoding at all. Values are stored as they are.</p> </li> <li> <p> <code>PLAIN</em>DICTIONARY</code> builds a dictionary of the values encountered in a given column. The dictionary is stored in a dictionary page for each column chunk.</p> </li> </ul></p>
     #[serde(rename = "EncodingType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub encoding_type: Option<String>,
+    pub encoding_type: Option<EncodingTypeValue>,
     /// <p><p>The type of server-side encryption that you want to use for your data. This encryption type is part of the endpoint settings or the extra connections attributes for Amazon S3. You can choose either <code>SSE<em>S3</code> (the default) or <code>SSE</em>KMS</code>. To use <code>SSE_S3</code>, you need an AWS Identity and Access Management (IAM) role with permission to allow <code>&quot;arn:aws:s3:::dms-*&quot;</code> to use the following actions:</p> <ul> <li> <p> <code>s3:CreateBucket</code> </p> </li> <li> <p> <code>s3:ListBucket</code> </p> </li> <li> <p> <code>s3:DeleteBucket</code> </p> </li> <li> <p> <code>s3:GetBucketLocation</code> </p> </li> <li> <p> <code>s3:GetObject</code> </p> </li> <li> <p> <code>s3:PutObject</code> </p> </li> <li> <p> <code>s3:DeleteObject</code> </p> </li> <li> <p> <code>s3:GetObjectVersion</code> </p> </li> <li> <p> <code>s3:GetBucketPolicy</code> </p> </li> <li> <p> <code>s3:PutBucketPolicy</code> </p> </li> <li> <p> <code>s3:DeleteBucketPolicy</code> </p> </li> </ul></p>
     #[serde(rename = "EncryptionMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub encryption_mode: Option<String>,
+    pub encryption_mode: Option<EncryptionModeValue>,
     /// <p> The external table definition. </p>
     #[serde(rename = "ExternalTableDefinition")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2487,7 +4022,7 @@ pub struct S3Settings {
     /// <p>The version of the Apache Parquet format that you want to use: <code>parquet_1_0</code> (the default) or <code>parquet_2_0</code>.</p>
     #[serde(rename = "ParquetVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parquet_version: Option<String>,
+    pub parquet_version: Option<ParquetVersionValue>,
     /// <p>The number of rows in a row group. A smaller row group size provides faster reads. But as the number of row groups grows, the slower writes become. This parameter defaults to 10,000 rows. This number is used for .parquet file format only. </p> <p>If you choose a value larger than the maximum, <code>RowGroupLength</code> is set to the max row group length in bytes (64 * 1024 * 1024). </p>
     #[serde(rename = "RowGroupLength")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2506,6 +4041,94 @@ pub struct S3Settings {
     pub timestamp_column_name: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SourceType {
+    /// `replication-instance`
+    ReplicationInstance,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SourceType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SourceType::ReplicationInstance => "replication-instance",
+            SourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SourceType {
+    fn default() -> SourceType {
+        SourceType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SourceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SourceType {
+    fn from(value: &'a str) -> SourceType {
+        match value {
+            "replication-instance" => SourceType::ReplicationInstance,
+            _ => SourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SourceType {
+    fn from(value: String) -> SourceType {
+        match value.as_str() {
+            "replication-instance" => SourceType::ReplicationInstance,
+            _ => SourceType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SourceType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SourceType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SourceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SourceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SourceType {
+    fn deserialize<D>(deserializer: D) -> Result<SourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -2525,40 +4148,143 @@ pub struct StartReplicationTaskAssessmentResponse {
     pub replication_task: Option<ReplicationTask>,
 }
 
-/// <p><p/></p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct StartReplicationTaskMessage {
-    /// <p><p>Indicates when you want a change data capture (CDC) operation to start. Use either CdcStartPosition or CdcStartTime to specify when you want a CDC operation to start. Specifying both values results in an error.</p> <p> The value can be in date, checkpoint, or LSN/SCN format.</p> <p>Date Example: --cdc-start-position “2018-03-08T12:12:12”</p> <p>Checkpoint Example: --cdc-start-position &quot;checkpoint:V1#27#mysql-bin-changelog.157832:1975:-1:2002:677883278264080:mysql-bin-changelog.157832:1876#0#0#*#0#93&quot;</p> <p>LSN Example: --cdc-start-position “mysql-bin-changelog.000024:373”</p> <note> <p>When you use this task setting with a source PostgreSQL database, a logical replication slot should already be created and associated with the source endpoint. You can verify this by setting the <code>slotName</code> extra connection attribute to the name of this logical replication slot. For more information, see <a href="https://docs.aws.amazon.com/dms/latest/userguide/CHAP_Source.PostgreSQL.html#CHAP_Source.PostgreSQL.ConnectionAttrib">Extra Connection Attributes When Using PostgreSQL as a Source for AWS DMS</a>.</p> </note></p>
-    #[serde(rename = "CdcStartPosition")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub cdc_start_position: Option<String>,
-    /// <p>Indicates the start time for a change data capture (CDC) operation. Use either CdcStartTime or CdcStartPosition to specify when you want a CDC operation to start. Specifying both values results in an error.</p> <p>Timestamp Example: --cdc-start-time “2018-03-08T12:12:12”</p>
-    #[serde(rename = "CdcStartTime")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub cdc_start_time: Option<f64>,
-    /// <p>Indicates when you want a change data capture (CDC) operation to stop. The value can be either server time or commit time.</p> <p>Server time example: --cdc-stop-position “server_time:3018-02-09T12:12:12”</p> <p>Commit time example: --cdc-stop-position “commit_time: 3018-02-09T12:12:12 “</p>
-    #[serde(rename = "CdcStopPosition")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub cdc_stop_position: Option<String>,
-    /// <p>The Amazon Resource Name (ARN) of the replication task to be started.</p>
-    #[serde(rename = "ReplicationTaskArn")]
-    pub replication_task_arn: String,
-    /// <p>The type of replication task.</p>
-    #[serde(rename = "StartReplicationTaskType")]
-    pub start_replication_task_type: String,
+/// <p><p/></p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct StartReplicationTaskMessage {
+    /// <p><p>Indicates when you want a change data capture (CDC) operation to start. Use either CdcStartPosition or CdcStartTime to specify when you want a CDC operation to start. Specifying both values results in an error.</p> <p> The value can be in date, checkpoint, or LSN/SCN format.</p> <p>Date Example: --cdc-start-position “2018-03-08T12:12:12”</p> <p>Checkpoint Example: --cdc-start-position &quot;checkpoint:V1#27#mysql-bin-changelog.157832:1975:-1:2002:677883278264080:mysql-bin-changelog.157832:1876#0#0#*#0#93&quot;</p> <p>LSN Example: --cdc-start-position “mysql-bin-changelog.000024:373”</p> <note> <p>When you use this task setting with a source PostgreSQL database, a logical replication slot should already be created and associated with the source endpoint. You can verify this by setting the <code>slotName</code> extra connection attribute to the name of this logical replication slot. For more information, see <a href="https://docs.aws.amazon.com/dms/latest/userguide/CHAP_Source.PostgreSQL.html#CHAP_Source.PostgreSQL.ConnectionAttrib">Extra Connection Attributes When Using PostgreSQL as a Source for AWS DMS</a>.</p> </note></p>
+    #[serde(rename = "CdcStartPosition")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cdc_start_position: Option<String>,
+    /// <p>Indicates the start time for a change data capture (CDC) operation. Use either CdcStartTime or CdcStartPosition to specify when you want a CDC operation to start. Specifying both values results in an error.</p> <p>Timestamp Example: --cdc-start-time “2018-03-08T12:12:12”</p>
+    #[serde(rename = "CdcStartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cdc_start_time: Option<::chrono::DateTime<::chrono::Utc>>,
+    /// <p>Indicates when you want a change data capture (CDC) operation to stop. The value can be either server time or commit time.</p> <p>Server time example: --cdc-stop-position “server_time:3018-02-09T12:12:12”</p> <p>Commit time example: --cdc-stop-position “commit_time: 3018-02-09T12:12:12 “</p>
+    #[serde(rename = "CdcStopPosition")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cdc_stop_position: Option<String>,
+    /// <p>The Amazon Resource Name (ARN) of the replication task to be started.</p>
+    #[serde(rename = "ReplicationTaskArn")]
+    pub replication_task_arn: String,
+    /// <p>The type of replication task.</p>
+    #[serde(rename = "StartReplicationTaskType")]
+    pub start_replication_task_type: StartReplicationTaskTypeValue,
+}
+
+/// <p><p/></p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct StartReplicationTaskResponse {
+    /// <p>The replication task started.</p>
+    #[serde(rename = "ReplicationTask")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replication_task: Option<ReplicationTask>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StartReplicationTaskTypeValue {
+    /// `start-replication`
+    StartReplication,
+    /// `resume-processing`
+    ResumeProcessing,
+    /// `reload-target`
+    ReloadTarget,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl StartReplicationTaskTypeValue {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StartReplicationTaskTypeValue::StartReplication => "start-replication",
+            StartReplicationTaskTypeValue::ResumeProcessing => "resume-processing",
+            StartReplicationTaskTypeValue::ReloadTarget => "reload-target",
+            StartReplicationTaskTypeValue::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for StartReplicationTaskTypeValue {
+    fn default() -> StartReplicationTaskTypeValue {
+        StartReplicationTaskTypeValue::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for StartReplicationTaskTypeValue {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for StartReplicationTaskTypeValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for StartReplicationTaskTypeValue {
+    fn from(value: &'a str) -> StartReplicationTaskTypeValue {
+        match value {
+            "start-replication" => StartReplicationTaskTypeValue::StartReplication,
+            "resume-processing" => StartReplicationTaskTypeValue::ResumeProcessing,
+            "reload-target" => StartReplicationTaskTypeValue::ReloadTarget,
+            _ => StartReplicationTaskTypeValue::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StartReplicationTaskTypeValue {
+    fn from(value: String) -> StartReplicationTaskTypeValue {
+        match value.as_str() {
+            "start-replication" => StartReplicationTaskTypeValue::StartReplication,
+            "resume-processing" => StartReplicationTaskTypeValue::ResumeProcessing,
+            "reload-target" => StartReplicationTaskTypeValue::ReloadTarget,
+            _ => StartReplicationTaskTypeValue::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for StartReplicationTaskTypeValue {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<StartReplicationTaskTypeValue, Self::Err> {
+        Ok(value.into())
+    }
 }
 
-/// <p><p/></p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct StartReplicationTaskResponse {
-    /// <p>The replication task started.</p>
-    #[serde(rename = "ReplicationTask")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub replication_task: Option<ReplicationTask>,
+impl ::rusoto_core::param::ToParam for StartReplicationTaskTypeValue {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for StartReplicationTaskTypeValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for StartReplicationTaskTypeValue {
+    fn deserialize<D>(deserializer: D) -> Result<StartReplicationTaskTypeValue, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -2603,7 +4329,7 @@ pub struct SupportedEndpointType {
     /// <p>The type of endpoint. Valid values are <code>source</code> and <code>target</code>.</p>
     #[serde(rename = "EndpointType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub endpoint_type: Option<String>,
+    pub endpoint_type: Option<ReplicationEndpointTypeValue>,
     /// <p>The expanded name for the engine name. For example, if the <code>EngineName</code> parameter is "aurora," this value would be "Amazon Aurora MySQL."</p>
     #[serde(rename = "EngineDisplayName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2648,8 +4374,13 @@ pub struct TableStatistics {
     pub inserts: Option<i64>,
     /// <p>The last time the table was updated.</p>
     #[serde(rename = "LastUpdateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_update_time: Option<f64>,
+    pub last_update_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The schema name.</p>
     #[serde(rename = "SchemaName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -5352,11 +7083,15 @@ pub struct DatabaseMigrationServiceClient {
 impl DatabaseMigrationServiceClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> DatabaseMigrationServiceClient {
         DatabaseMigrationServiceClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(
+                region,
+                "Database Migration Service",
+            ),
         }
     }
 
@@ -5391,6 +7126,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: AddTagsToResourceMessage,
     ) -> Result<AddTagsToResourceResponse, RusotoError<AddTagsToResourceError>> {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("AddTagsToResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDMSv20160101.AddTagsToResource");
@@ -5422,6 +7158,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         RusotoError<ApplyPendingMaintenanceActionError>,
     > {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("ApplyPendingMaintenanceAction");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5453,6 +7190,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: CreateEndpointMessage,
     ) -> Result<CreateEndpointResponse, RusotoError<CreateEndpointError>> {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("CreateEndpoint");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDMSv20160101.CreateEndpoint");
@@ -5480,6 +7218,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: CreateEventSubscriptionMessage,
     ) -> Result<CreateEventSubscriptionResponse, RusotoError<CreateEventSubscriptionError>> {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("CreateEventSubscription");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDMSv20160101.CreateEventSubscription");
@@ -5509,6 +7248,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
     ) -> Result<CreateReplicationInstanceResponse, RusotoError<CreateReplicationInstanceError>>
     {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("CreateReplicationInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5541,6 +7281,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
     ) -> Result<CreateReplicationSubnetGroupResponse, RusotoError<CreateReplicationSubnetGroupError>>
     {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("CreateReplicationSubnetGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5572,6 +7313,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: CreateReplicationTaskMessage,
     ) -> Result<CreateReplicationTaskResponse, RusotoError<CreateReplicationTaskError>> {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("CreateReplicationTask");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDMSv20160101.CreateReplicationTask");
@@ -5600,6 +7342,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: DeleteCertificateMessage,
     ) -> Result<DeleteCertificateResponse, RusotoError<DeleteCertificateError>> {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DeleteCertificate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDMSv20160101.DeleteCertificate");
@@ -5628,6 +7371,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: DeleteConnectionMessage,
     ) -> Result<DeleteConnectionResponse, RusotoError<DeleteConnectionError>> {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DeleteConnection");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDMSv20160101.DeleteConnection");
@@ -5656,6 +7400,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: DeleteEndpointMessage,
     ) -> Result<DeleteEndpointResponse, RusotoError<DeleteEndpointError>> {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DeleteEndpoint");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDMSv20160101.DeleteEndpoint");
@@ -5683,6 +7428,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: DeleteEventSubscriptionMessage,
     ) -> Result<DeleteEventSubscriptionResponse, RusotoError<DeleteEventSubscriptionError>> {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DeleteEventSubscription");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDMSv20160101.DeleteEventSubscription");
@@ -5712,6 +7458,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
     ) -> Result<DeleteReplicationInstanceResponse, RusotoError<DeleteReplicationInstanceError>>
     {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DeleteReplicationInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5744,6 +7491,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
     ) -> Result<DeleteReplicationSubnetGroupResponse, RusotoError<DeleteReplicationSubnetGroupError>>
     {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DeleteReplicationSubnetGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5775,6 +7523,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: DeleteReplicationTaskMessage,
     ) -> Result<DeleteReplicationTaskResponse, RusotoError<DeleteReplicationTaskError>> {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DeleteReplicationTask");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDMSv20160101.DeleteReplicationTask");
@@ -5803,6 +7552,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
     ) -> Result<DescribeAccountAttributesResponse, RusotoError<DescribeAccountAttributesError>>
     {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DescribeAccountAttributes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5833,6 +7583,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: DescribeCertificatesMessage,
     ) -> Result<DescribeCertificatesResponse, RusotoError<DescribeCertificatesError>> {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DescribeCertificates");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDMSv20160101.DescribeCertificates");
@@ -5861,6 +7612,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: DescribeConnectionsMessage,
     ) -> Result<DescribeConnectionsResponse, RusotoError<DescribeConnectionsError>> {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DescribeConnections");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDMSv20160101.DescribeConnections");
@@ -5889,6 +7641,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: DescribeEndpointTypesMessage,
     ) -> Result<DescribeEndpointTypesResponse, RusotoError<DescribeEndpointTypesError>> {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DescribeEndpointTypes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDMSv20160101.DescribeEndpointTypes");
@@ -5917,6 +7670,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: DescribeEndpointsMessage,
     ) -> Result<DescribeEndpointsResponse, RusotoError<DescribeEndpointsError>> {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DescribeEndpoints");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDMSv20160101.DescribeEndpoints");
@@ -5945,6 +7699,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: DescribeEventCategoriesMessage,
     ) -> Result<DescribeEventCategoriesResponse, RusotoError<DescribeEventCategoriesError>> {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DescribeEventCategories");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDMSv20160101.DescribeEventCategories");
@@ -5974,6 +7729,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
     ) -> Result<DescribeEventSubscriptionsResponse, RusotoError<DescribeEventSubscriptionsError>>
     {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DescribeEventSubscriptions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6005,6 +7761,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: DescribeEventsMessage,
     ) -> Result<DescribeEventsResponse, RusotoError<DescribeEventsError>> {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DescribeEvents");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDMSv20160101.DescribeEvents");
@@ -6035,6 +7792,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         RusotoError<DescribeOrderableReplicationInstancesError>,
     > {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DescribeOrderableReplicationInstances");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6071,6 +7829,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         RusotoError<DescribePendingMaintenanceActionsError>,
     > {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DescribePendingMaintenanceActions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6105,6 +7864,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
     ) -> Result<DescribeRefreshSchemasStatusResponse, RusotoError<DescribeRefreshSchemasStatusError>>
     {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DescribeRefreshSchemasStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6139,6 +7899,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         RusotoError<DescribeReplicationInstanceTaskLogsError>,
     > {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DescribeReplicationInstanceTaskLogs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6173,6 +7934,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
     ) -> Result<DescribeReplicationInstancesResponse, RusotoError<DescribeReplicationInstancesError>>
     {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DescribeReplicationInstances");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6207,6 +7969,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         RusotoError<DescribeReplicationSubnetGroupsError>,
     > {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DescribeReplicationSubnetGroups");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6243,6 +8006,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         RusotoError<DescribeReplicationTaskAssessmentResultsError>,
     > {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DescribeReplicationTaskAssessmentResults");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6274,6 +8038,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: DescribeReplicationTasksMessage,
     ) -> Result<DescribeReplicationTasksResponse, RusotoError<DescribeReplicationTasksError>> {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DescribeReplicationTasks");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6305,6 +8070,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: DescribeSchemasMessage,
     ) -> Result<DescribeSchemasResponse, RusotoError<DescribeSchemasError>> {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DescribeSchemas");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDMSv20160101.DescribeSchemas");
@@ -6332,6 +8098,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: DescribeTableStatisticsMessage,
     ) -> Result<DescribeTableStatisticsResponse, RusotoError<DescribeTableStatisticsError>> {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DescribeTableStatistics");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDMSv20160101.DescribeTableStatistics");
@@ -6360,6 +8127,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: ImportCertificateMessage,
     ) -> Result<ImportCertificateResponse, RusotoError<ImportCertificateError>> {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("ImportCertificate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDMSv20160101.ImportCertificate");
@@ -6388,6 +8156,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: ListTagsForResourceMessage,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDMSv20160101.ListTagsForResource");
@@ -6416,6 +8185,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: ModifyEndpointMessage,
     ) -> Result<ModifyEndpointResponse, RusotoError<ModifyEndpointError>> {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("ModifyEndpoint");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDMSv20160101.ModifyEndpoint");
@@ -6443,6 +8213,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: ModifyEventSubscriptionMessage,
     ) -> Result<ModifyEventSubscriptionResponse, RusotoError<ModifyEventSubscriptionError>> {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("ModifyEventSubscription");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDMSv20160101.ModifyEventSubscription");
@@ -6472,6 +8243,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
     ) -> Result<ModifyReplicationInstanceResponse, RusotoError<ModifyReplicationInstanceError>>
     {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("ModifyReplicationInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6504,6 +8276,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
     ) -> Result<ModifyReplicationSubnetGroupResponse, RusotoError<ModifyReplicationSubnetGroupError>>
     {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("ModifyReplicationSubnetGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6535,6 +8308,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: ModifyReplicationTaskMessage,
     ) -> Result<ModifyReplicationTaskResponse, RusotoError<ModifyReplicationTaskError>> {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("ModifyReplicationTask");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDMSv20160101.ModifyReplicationTask");
@@ -6564,6 +8338,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
     ) -> Result<RebootReplicationInstanceResponse, RusotoError<RebootReplicationInstanceError>>
     {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("RebootReplicationInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6595,6 +8370,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: RefreshSchemasMessage,
     ) -> Result<RefreshSchemasResponse, RusotoError<RefreshSchemasError>> {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("RefreshSchemas");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDMSv20160101.RefreshSchemas");
@@ -6622,6 +8398,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: ReloadTablesMessage,
     ) -> Result<ReloadTablesResponse, RusotoError<ReloadTablesError>> {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("ReloadTables");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDMSv20160101.ReloadTables");
@@ -6649,6 +8426,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: RemoveTagsFromResourceMessage,
     ) -> Result<RemoveTagsFromResourceResponse, RusotoError<RemoveTagsFromResourceError>> {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("RemoveTagsFromResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDMSv20160101.RemoveTagsFromResource");
@@ -6677,6 +8455,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: StartReplicationTaskMessage,
     ) -> Result<StartReplicationTaskResponse, RusotoError<StartReplicationTaskError>> {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("StartReplicationTask");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDMSv20160101.StartReplicationTask");
@@ -6708,6 +8487,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         RusotoError<StartReplicationTaskAssessmentError>,
     > {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("StartReplicationTaskAssessment");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6739,6 +8519,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: StopReplicationTaskMessage,
     ) -> Result<StopReplicationTaskResponse, RusotoError<StopReplicationTaskError>> {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("StopReplicationTask");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDMSv20160101.StopReplicationTask");
@@ -6767,6 +8548,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: TestConnectionMessage,
     ) -> Result<TestConnectionResponse, RusotoError<TestConnectionError>> {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("TestConnection");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDMSv20160101.TestConnection");
@@ -6788,3 +8570,1159 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         }
     }
 }
+impl DatabaseMigrationServiceClient {
+    /// Auto-paginating version of `describe_certificates`, streaming every page of results.
+    pub fn describe_certificates_pages(
+        &self,
+        input: DescribeCertificatesMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeCertificatesResponse,
+        DescribeCertificatesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DatabaseMigrationServiceClient, input: DescribeCertificatesMessage| async move {
+                client.describe_certificates(input).await
+            },
+            |input: &DescribeCertificatesMessage, output: &DescribeCertificatesResponse| {
+                Some(DescribeCertificatesMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_certificates`, streaming the `Certificates` of every page of results.
+    pub fn describe_certificates_items(
+        &self,
+        input: DescribeCertificatesMessage,
+    ) -> rusoto_core::pagination::RusotoStream<Certificate, DescribeCertificatesError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_certificates_pages(input),
+            |output: DescribeCertificatesResponse| output.certificates.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_connections`, streaming every page of results.
+    pub fn describe_connections_pages(
+        &self,
+        input: DescribeConnectionsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeConnectionsResponse, DescribeConnectionsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DatabaseMigrationServiceClient, input: DescribeConnectionsMessage| async move {
+                client.describe_connections(input).await
+            },
+            |input: &DescribeConnectionsMessage, output: &DescribeConnectionsResponse| {
+                Some(DescribeConnectionsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_connections`, streaming the `Connections` of every page of results.
+    pub fn describe_connections_items(
+        &self,
+        input: DescribeConnectionsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<Connection, DescribeConnectionsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_connections_pages(input),
+            |output: DescribeConnectionsResponse| output.connections.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_endpoint_types`, streaming every page of results.
+    pub fn describe_endpoint_types_pages(
+        &self,
+        input: DescribeEndpointTypesMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeEndpointTypesResponse,
+        DescribeEndpointTypesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DatabaseMigrationServiceClient, input: DescribeEndpointTypesMessage| async move {
+                client.describe_endpoint_types(input).await
+            },
+            |input: &DescribeEndpointTypesMessage, output: &DescribeEndpointTypesResponse| {
+                Some(DescribeEndpointTypesMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_endpoint_types`, streaming the `SupportedEndpointTypes` of every page of results.
+    pub fn describe_endpoint_types_items(
+        &self,
+        input: DescribeEndpointTypesMessage,
+    ) -> rusoto_core::pagination::RusotoStream<SupportedEndpointType, DescribeEndpointTypesError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_endpoint_types_pages(input),
+            |output: DescribeEndpointTypesResponse| {
+                output.supported_endpoint_types.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_endpoints`, streaming every page of results.
+    pub fn describe_endpoints_pages(
+        &self,
+        input: DescribeEndpointsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeEndpointsResponse, DescribeEndpointsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DatabaseMigrationServiceClient, input: DescribeEndpointsMessage| async move {
+                client.describe_endpoints(input).await
+            },
+            |input: &DescribeEndpointsMessage, output: &DescribeEndpointsResponse| {
+                Some(DescribeEndpointsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_endpoints`, streaming the `Endpoints` of every page of results.
+    pub fn describe_endpoints_items(
+        &self,
+        input: DescribeEndpointsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<Endpoint, DescribeEndpointsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_endpoints_pages(input),
+            |output: DescribeEndpointsResponse| output.endpoints.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_event_subscriptions`, streaming every page of results.
+    pub fn describe_event_subscriptions_pages(
+        &self,
+        input: DescribeEventSubscriptionsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeEventSubscriptionsResponse,
+        DescribeEventSubscriptionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DatabaseMigrationServiceClient, input: DescribeEventSubscriptionsMessage| async move {
+                client.describe_event_subscriptions(input).await
+            },
+            |input: &DescribeEventSubscriptionsMessage,
+             output: &DescribeEventSubscriptionsResponse| {
+                Some(DescribeEventSubscriptionsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_event_subscriptions`, streaming the `EventSubscriptionsList` of every page of results.
+    pub fn describe_event_subscriptions_items(
+        &self,
+        input: DescribeEventSubscriptionsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<EventSubscription, DescribeEventSubscriptionsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_event_subscriptions_pages(input),
+            |output: DescribeEventSubscriptionsResponse| {
+                output.event_subscriptions_list.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_events`, streaming every page of results.
+    pub fn describe_events_pages(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeEventsResponse, DescribeEventsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DatabaseMigrationServiceClient, input: DescribeEventsMessage| async move {
+                client.describe_events(input).await
+            },
+            |input: &DescribeEventsMessage, output: &DescribeEventsResponse| {
+                Some(DescribeEventsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_events`, streaming the `Events` of every page of results.
+    pub fn describe_events_items(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<Event, DescribeEventsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_events_pages(input),
+            |output: DescribeEventsResponse| output.events.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_orderable_replication_instances`, streaming every page of results.
+    pub fn describe_orderable_replication_instances_pages(
+        &self,
+        input: DescribeOrderableReplicationInstancesMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeOrderableReplicationInstancesResponse,
+        DescribeOrderableReplicationInstancesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DatabaseMigrationServiceClient,
+             input: DescribeOrderableReplicationInstancesMessage| async move {
+                client.describe_orderable_replication_instances(input).await
+            },
+            |input: &DescribeOrderableReplicationInstancesMessage,
+             output: &DescribeOrderableReplicationInstancesResponse| {
+                Some(DescribeOrderableReplicationInstancesMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_orderable_replication_instances`, streaming the `OrderableReplicationInstances` of every page of results.
+    pub fn describe_orderable_replication_instances_items(
+        &self,
+        input: DescribeOrderableReplicationInstancesMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        OrderableReplicationInstance,
+        DescribeOrderableReplicationInstancesError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_orderable_replication_instances_pages(input),
+            |output: DescribeOrderableReplicationInstancesResponse| {
+                output.orderable_replication_instances.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_replication_instances`, streaming every page of results.
+    pub fn describe_replication_instances_pages(
+        &self,
+        input: DescribeReplicationInstancesMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeReplicationInstancesResponse,
+        DescribeReplicationInstancesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DatabaseMigrationServiceClient, input: DescribeReplicationInstancesMessage| async move {
+                client.describe_replication_instances(input).await
+            },
+            |input: &DescribeReplicationInstancesMessage,
+             output: &DescribeReplicationInstancesResponse| {
+                Some(DescribeReplicationInstancesMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_replication_instances`, streaming the `ReplicationInstances` of every page of results.
+    pub fn describe_replication_instances_items(
+        &self,
+        input: DescribeReplicationInstancesMessage,
+    ) -> rusoto_core::pagination::RusotoStream<ReplicationInstance, DescribeReplicationInstancesError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_replication_instances_pages(input),
+            |output: DescribeReplicationInstancesResponse| {
+                output.replication_instances.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_replication_subnet_groups`, streaming every page of results.
+    pub fn describe_replication_subnet_groups_pages(
+        &self,
+        input: DescribeReplicationSubnetGroupsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeReplicationSubnetGroupsResponse,
+        DescribeReplicationSubnetGroupsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DatabaseMigrationServiceClient,
+             input: DescribeReplicationSubnetGroupsMessage| async move {
+                client.describe_replication_subnet_groups(input).await
+            },
+            |input: &DescribeReplicationSubnetGroupsMessage,
+             output: &DescribeReplicationSubnetGroupsResponse| {
+                Some(DescribeReplicationSubnetGroupsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_replication_subnet_groups`, streaming the `ReplicationSubnetGroups` of every page of results.
+    pub fn describe_replication_subnet_groups_items(
+        &self,
+        input: DescribeReplicationSubnetGroupsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ReplicationSubnetGroup,
+        DescribeReplicationSubnetGroupsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_replication_subnet_groups_pages(input),
+            |output: DescribeReplicationSubnetGroupsResponse| {
+                output.replication_subnet_groups.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_replication_task_assessment_results`, streaming every page of results.
+    pub fn describe_replication_task_assessment_results_pages(
+        &self,
+        input: DescribeReplicationTaskAssessmentResultsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeReplicationTaskAssessmentResultsResponse,
+        DescribeReplicationTaskAssessmentResultsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DatabaseMigrationServiceClient,
+             input: DescribeReplicationTaskAssessmentResultsMessage| async move {
+                client
+                    .describe_replication_task_assessment_results(input)
+                    .await
+            },
+            |input: &DescribeReplicationTaskAssessmentResultsMessage,
+             output: &DescribeReplicationTaskAssessmentResultsResponse| {
+                Some(DescribeReplicationTaskAssessmentResultsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_replication_task_assessment_results`, streaming the `ReplicationTaskAssessmentResults` of every page of results.
+    pub fn describe_replication_task_assessment_results_items(
+        &self,
+        input: DescribeReplicationTaskAssessmentResultsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ReplicationTaskAssessmentResult,
+        DescribeReplicationTaskAssessmentResultsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_replication_task_assessment_results_pages(input),
+            |output: DescribeReplicationTaskAssessmentResultsResponse| {
+                output
+                    .replication_task_assessment_results
+                    .unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_replication_tasks`, streaming every page of results.
+    pub fn describe_replication_tasks_pages(
+        &self,
+        input: DescribeReplicationTasksMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeReplicationTasksResponse,
+        DescribeReplicationTasksError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DatabaseMigrationServiceClient, input: DescribeReplicationTasksMessage| async move {
+                client.describe_replication_tasks(input).await
+            },
+            |input: &DescribeReplicationTasksMessage, output: &DescribeReplicationTasksResponse| {
+                Some(DescribeReplicationTasksMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_replication_tasks`, streaming the `ReplicationTasks` of every page of results.
+    pub fn describe_replication_tasks_items(
+        &self,
+        input: DescribeReplicationTasksMessage,
+    ) -> rusoto_core::pagination::RusotoStream<ReplicationTask, DescribeReplicationTasksError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_replication_tasks_pages(input),
+            |output: DescribeReplicationTasksResponse| output.replication_tasks.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_schemas`, streaming every page of results.
+    pub fn describe_schemas_pages(
+        &self,
+        input: DescribeSchemasMessage,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeSchemasResponse, DescribeSchemasError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DatabaseMigrationServiceClient, input: DescribeSchemasMessage| async move {
+                client.describe_schemas(input).await
+            },
+            |input: &DescribeSchemasMessage, output: &DescribeSchemasResponse| {
+                Some(DescribeSchemasMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_schemas`, streaming the `Schemas` of every page of results.
+    pub fn describe_schemas_items(
+        &self,
+        input: DescribeSchemasMessage,
+    ) -> rusoto_core::pagination::RusotoStream<String, DescribeSchemasError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_schemas_pages(input),
+            |output: DescribeSchemasResponse| output.schemas.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_table_statistics`, streaming every page of results.
+    pub fn describe_table_statistics_pages(
+        &self,
+        input: DescribeTableStatisticsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeTableStatisticsResponse,
+        DescribeTableStatisticsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DatabaseMigrationServiceClient, input: DescribeTableStatisticsMessage| async move {
+                client.describe_table_statistics(input).await
+            },
+            |input: &DescribeTableStatisticsMessage, output: &DescribeTableStatisticsResponse| {
+                Some(DescribeTableStatisticsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_table_statistics`, streaming the `TableStatistics` of every page of results.
+    pub fn describe_table_statistics_items(
+        &self,
+        input: DescribeTableStatisticsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<TableStatistics, DescribeTableStatisticsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_table_statistics_pages(input),
+            |output: DescribeTableStatisticsResponse| output.table_statistics.unwrap_or_default(),
+        )
+    }
+}
+impl DatabaseMigrationServiceClient {
+    /// Waits until `describe_endpoints` reports the `EndpointDeleted` state, polling every 5 seconds up to 60 times.
+    pub async fn wait_until_endpoint_deleted(
+        &self,
+        input: DescribeEndpointsMessage,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeEndpointsError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(5), 60);
+        self.wait_until_endpoint_deleted_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_endpoint_deleted`, polling as often and as many times as `config` says.
+    pub async fn wait_until_endpoint_deleted_with_config(
+        &self,
+        input: DescribeEndpointsMessage,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeEndpointsError>> {
+        rusoto_core::waiter::wait(
+            "EndpointDeleted",
+            &config,
+            || self.describe_endpoints(input.clone()),
+            |result: &Result<DescribeEndpointsResponse, RusotoError<DescribeEndpointsError>>| {
+                match result {
+                    Err(RusotoError::Service(DescribeEndpointsError::ResourceNotFoundFault(_))) => {
+                        Some(rusoto_core::waiter::WaiterState::Success)
+                    }
+                    Err(RusotoError::Unknown(response))
+                        if rusoto_core::retry::error_code(response).as_deref()
+                            == Some("ResourceNotFoundFault") =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Success)
+                    }
+                    Ok(output)
+                        if output
+                            .endpoints
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.status.iter())
+                            .any(|v| v == "active") =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    Ok(output)
+                        if output
+                            .endpoints
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.status.iter())
+                            .any(|v| v == "creating") =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    _ => None,
+                }
+            },
+        )
+        .await
+    }
+
+    /// Waits until `describe_replication_instances` reports the `ReplicationInstanceAvailable` state, polling every 60 seconds up to 60 times.
+    pub async fn wait_until_replication_instance_available(
+        &self,
+        input: DescribeReplicationInstancesMessage,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeReplicationInstancesError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(60), 60);
+        self.wait_until_replication_instance_available_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_replication_instance_available`, polling as often and as many times as `config` says.
+    pub async fn wait_until_replication_instance_available_with_config(
+        &self,
+        input: DescribeReplicationInstancesMessage,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeReplicationInstancesError>> {
+        rusoto_core::waiter::wait(
+            "ReplicationInstanceAvailable",
+            &config,
+            || self.describe_replication_instances(input.clone()),
+            |result: &Result<
+                DescribeReplicationInstancesResponse,
+                RusotoError<DescribeReplicationInstancesError>,
+            >| match result {
+                Ok(output)
+                    if rusoto_core::waiter::all_match(
+                        output
+                            .replication_instances
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.replication_instance_status.iter()),
+                        |v| v == "available",
+                    ) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if output
+                        .replication_instances
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.replication_instance_status.iter())
+                        .any(|v| v == "deleting") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .replication_instances
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.replication_instance_status.iter())
+                        .any(|v| v == "incompatible-credentials") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .replication_instances
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.replication_instance_status.iter())
+                        .any(|v| v == "incompatible-network") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .replication_instances
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.replication_instance_status.iter())
+                        .any(|v| v == "inaccessible-encryption-credentials") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+
+    /// Waits until `describe_replication_instances` reports the `ReplicationInstanceDeleted` state, polling every 15 seconds up to 60 times.
+    pub async fn wait_until_replication_instance_deleted(
+        &self,
+        input: DescribeReplicationInstancesMessage,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeReplicationInstancesError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(15), 60);
+        self.wait_until_replication_instance_deleted_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_replication_instance_deleted`, polling as often and as many times as `config` says.
+    pub async fn wait_until_replication_instance_deleted_with_config(
+        &self,
+        input: DescribeReplicationInstancesMessage,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeReplicationInstancesError>> {
+        rusoto_core::waiter::wait(
+            "ReplicationInstanceDeleted",
+            &config,
+            || self.describe_replication_instances(input.clone()),
+            |result: &Result<
+                DescribeReplicationInstancesResponse,
+                RusotoError<DescribeReplicationInstancesError>,
+            >| match result {
+                Ok(output)
+                    if output
+                        .replication_instances
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.replication_instance_status.iter())
+                        .any(|v| v == "available") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Err(RusotoError::Service(
+                    DescribeReplicationInstancesError::ResourceNotFoundFault(_),
+                )) => Some(rusoto_core::waiter::WaiterState::Success),
+                Err(RusotoError::Unknown(response))
+                    if rusoto_core::retry::error_code(response).as_deref()
+                        == Some("ResourceNotFoundFault") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+
+    /// Waits until `describe_replication_tasks` reports the `ReplicationTaskDeleted` state, polling every 15 seconds up to 60 times.
+    pub async fn wait_until_replication_task_deleted(
+        &self,
+        input: DescribeReplicationTasksMessage,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeReplicationTasksError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(15), 60);
+        self.wait_until_replication_task_deleted_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_replication_task_deleted`, polling as often and as many times as `config` says.
+    pub async fn wait_until_replication_task_deleted_with_config(
+        &self,
+        input: DescribeReplicationTasksMessage,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeReplicationTasksError>> {
+        rusoto_core::waiter::wait(
+            "ReplicationTaskDeleted",
+            &config,
+            || self.describe_replication_tasks(input.clone()),
+            |result: &Result<
+                DescribeReplicationTasksResponse,
+                RusotoError<DescribeReplicationTasksError>,
+            >| match result {
+                Ok(output)
+                    if output
+                        .replication_tasks
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| v == "ready") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .replication_tasks
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| v == "creating") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .replication_tasks
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| v == "stopped") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .replication_tasks
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| v == "running") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .replication_tasks
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| v == "failed") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Err(RusotoError::Service(
+                    DescribeReplicationTasksError::ResourceNotFoundFault(_),
+                )) => Some(rusoto_core::waiter::WaiterState::Success),
+                Err(RusotoError::Unknown(response))
+                    if rusoto_core::retry::error_code(response).as_deref()
+                        == Some("ResourceNotFoundFault") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+
+    /// Waits until `describe_replication_tasks` reports the `ReplicationTaskReady` state, polling every 15 seconds up to 60 times.
+    pub async fn wait_until_replication_task_ready(
+        &self,
+        input: DescribeReplicationTasksMessage,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeReplicationTasksError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(15), 60);
+        self.wait_until_replication_task_ready_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_replication_task_ready`, polling as often and as many times as `config` says.
+    pub async fn wait_until_replication_task_ready_with_config(
+        &self,
+        input: DescribeReplicationTasksMessage,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeReplicationTasksError>> {
+        rusoto_core::waiter::wait(
+            "ReplicationTaskReady",
+            &config,
+            || self.describe_replication_tasks(input.clone()),
+            |result: &Result<
+                DescribeReplicationTasksResponse,
+                RusotoError<DescribeReplicationTasksError>,
+            >| match result {
+                Ok(output)
+                    if rusoto_core::waiter::all_match(
+                        output
+                            .replication_tasks
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.status.iter()),
+                        |v| v == "ready",
+                    ) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if output
+                        .replication_tasks
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| v == "starting") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .replication_tasks
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| v == "running") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .replication_tasks
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| v == "stopping") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .replication_tasks
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| v == "stopped") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .replication_tasks
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| v == "failed") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .replication_tasks
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| v == "modifying") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .replication_tasks
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| v == "testing") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .replication_tasks
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| v == "deleting") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+
+    /// Waits until `describe_replication_tasks` reports the `ReplicationTaskRunning` state, polling every 15 seconds up to 60 times.
+    pub async fn wait_until_replication_task_running(
+        &self,
+        input: DescribeReplicationTasksMessage,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeReplicationTasksError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(15), 60);
+        self.wait_until_replication_task_running_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_replication_task_running`, polling as often and as many times as `config` says.
+    pub async fn wait_until_replication_task_running_with_config(
+        &self,
+        input: DescribeReplicationTasksMessage,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeReplicationTasksError>> {
+        rusoto_core::waiter::wait(
+            "ReplicationTaskRunning",
+            &config,
+            || self.describe_replication_tasks(input.clone()),
+            |result: &Result<
+                DescribeReplicationTasksResponse,
+                RusotoError<DescribeReplicationTasksError>,
+            >| match result {
+                Ok(output)
+                    if rusoto_core::waiter::all_match(
+                        output
+                            .replication_tasks
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.status.iter()),
+                        |v| v == "running",
+                    ) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if output
+                        .replication_tasks
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| v == "ready") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .replication_tasks
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| v == "creating") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .replication_tasks
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| v == "stopping") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .replication_tasks
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| v == "stopped") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .replication_tasks
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| v == "failed") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .replication_tasks
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| v == "modifying") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .replication_tasks
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| v == "testing") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .replication_tasks
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| v == "deleting") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+
+    /// Waits until `describe_replication_tasks` reports the `ReplicationTaskStopped` state, polling every 15 seconds up to 60 times.
+    pub async fn wait_until_replication_task_stopped(
+        &self,
+        input: DescribeReplicationTasksMessage,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeReplicationTasksError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(15), 60);
+        self.wait_until_replication_task_stopped_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_replication_task_stopped`, polling as often and as many times as `config` says.
+    pub async fn wait_until_replication_task_stopped_with_config(
+        &self,
+        input: DescribeReplicationTasksMessage,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeReplicationTasksError>> {
+        rusoto_core::waiter::wait(
+            "ReplicationTaskStopped",
+            &config,
+            || self.describe_replication_tasks(input.clone()),
+            |result: &Result<
+                DescribeReplicationTasksResponse,
+                RusotoError<DescribeReplicationTasksError>,
+            >| match result {
+                Ok(output)
+                    if rusoto_core::waiter::all_match(
+                        output
+                            .replication_tasks
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.status.iter()),
+                        |v| v == "stopped",
+                    ) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if output
+                        .replication_tasks
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| v == "ready") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .replication_tasks
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| v == "creating") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .replication_tasks
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| v == "starting") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .replication_tasks
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| v == "failed") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .replication_tasks
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| v == "modifying") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .replication_tasks
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| v == "testing") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .replication_tasks
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| v == "deleting") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+
+    /// Waits until `describe_connections` reports the `TestConnectionSucceeds` state, polling every 5 seconds up to 60 times.
+    pub async fn wait_until_test_connection_succeeds(
+        &self,
+        input: DescribeConnectionsMessage,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeConnectionsError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(5), 60);
+        self.wait_until_test_connection_succeeds_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_test_connection_succeeds`, polling as often and as many times as `config` says.
+    pub async fn wait_until_test_connection_succeeds_with_config(
+        &self,
+        input: DescribeConnectionsMessage,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeConnectionsError>> {
+        rusoto_core::waiter::wait(
+            "TestConnectionSucceeds",
+            &config,
+            || self.describe_connections(input.clone()),
+            |result: &Result<
+                DescribeConnectionsResponse,
+                RusotoError<DescribeConnectionsError>,
+            >| match result {
+                Ok(output)
+                    if rusoto_core::waiter::all_match(
+                        output
+                            .connections
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.status.iter()),
+                        |v| v == "successful",
+                    ) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if output
+                        .connections
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| v == "failed") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+}
@@ -56,12 +56,110 @@ impl AddTagsToResourceMessageSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ApplyMethod {
+    /// `immediate`
+    Immediate,
+    /// `pending-reboot`
+    PendingReboot,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ApplyMethod {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ApplyMethod::Immediate => "immediate",
+            ApplyMethod::PendingReboot => "pending-reboot",
+            ApplyMethod::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ApplyMethod {
+    fn default() -> ApplyMethod {
+        ApplyMethod::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ApplyMethod {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ApplyMethod {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ApplyMethod {
+    fn from(value: &'a str) -> ApplyMethod {
+        match value {
+            "immediate" => ApplyMethod::Immediate,
+            "pending-reboot" => ApplyMethod::PendingReboot,
+            _ => ApplyMethod::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ApplyMethod {
+    fn from(value: String) -> ApplyMethod {
+        match value.as_str() {
+            "immediate" => ApplyMethod::Immediate,
+            "pending-reboot" => ApplyMethod::PendingReboot,
+            _ => ApplyMethod::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ApplyMethod {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ApplyMethod, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ApplyMethod {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ApplyMethod {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ApplyMethod {
+    fn deserialize<D>(deserializer: D) -> Result<ApplyMethod, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct ApplyMethodDeserializer;
 impl ApplyMethodDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ApplyMethod, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -268,9 +366,9 @@ pub struct Certificate {
     /// <p>The thumbprint of the certificate.</p>
     pub thumbprint: Option<String>,
     /// <p>The starting date-time from which the certificate is valid.</p> <p>Example: <code>2019-07-31T17:57:09Z</code> </p>
-    pub valid_from: Option<String>,
+    pub valid_from: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The date-time after which the certificate is no longer valid.</p> <p>Example: <code>2024-07-31T17:57:09Z</code> </p>
-    pub valid_till: Option<String>,
+    pub valid_till: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 struct CertificateDeserializer;
@@ -1022,7 +1120,7 @@ pub struct DBCluster {
     /// <p>Specifies the number of days for which automatic DB snapshots are retained.</p>
     pub backup_retention_period: Option<i64>,
     /// <p>Specifies the time when the DB cluster was created, in Universal Coordinated Time (UTC).</p>
-    pub cluster_create_time: Option<String>,
+    pub cluster_create_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The Amazon Resource Name (ARN) for the DB cluster.</p>
     pub db_cluster_arn: Option<String>,
     /// <p>Contains a user-supplied DB cluster identifier. This identifier is the unique key that identifies a DB cluster.</p>
@@ -1038,7 +1136,7 @@ pub struct DBCluster {
     /// <p>Specifies whether this cluster can be deleted. If <code>DeletionProtection</code> is enabled, the cluster cannot be deleted unless it is modified and <code>DeletionProtection</code> is disabled. <code>DeletionProtection</code> protects clusters from being accidentally deleted.</p>
     pub deletion_protection: Option<bool>,
     /// <p>The earliest time to which a database can be restored with point-in-time restore.</p>
-    pub earliest_restorable_time: Option<String>,
+    pub earliest_restorable_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A list of log types that this DB cluster is configured to export to Amazon CloudWatch Logs.</p>
     pub enabled_cloudwatch_logs_exports: Option<Vec<String>>,
     /// <p>Specifies the connection endpoint for the primary instance of the DB cluster.</p>
@@ -1052,7 +1150,7 @@ pub struct DBCluster {
     /// <p>If <code>StorageEncrypted</code> is <code>true</code>, the AWS KMS key identifier for the encrypted DB cluster.</p>
     pub kms_key_id: Option<String>,
     /// <p>Specifies the latest time to which a database can be restored with point-in-time restore.</p>
-    pub latest_restorable_time: Option<String>,
+    pub latest_restorable_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Contains the master user name for the DB cluster.</p>
     pub master_username: Option<String>,
     /// <p>Specifies whether the DB cluster has instances in multiple Availability Zones.</p>
@@ -1591,7 +1689,7 @@ pub struct DBClusterSnapshot {
     /// <p>Provides the list of Amazon EC2 Availability Zones that instances in the DB cluster snapshot can be restored in.</p>
     pub availability_zones: Option<Vec<String>>,
     /// <p>Specifies the time when the DB cluster was created, in Universal Coordinated Time (UTC).</p>
-    pub cluster_create_time: Option<String>,
+    pub cluster_create_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Specifies the DB cluster identifier of the DB cluster that this DB cluster snapshot was created from.</p>
     pub db_cluster_identifier: Option<String>,
     /// <p>The Amazon Resource Name (ARN) for the DB cluster snapshot.</p>
@@ -1611,7 +1709,7 @@ pub struct DBClusterSnapshot {
     /// <p>Specifies the port that the DB cluster was listening on at the time of the snapshot.</p>
     pub port: Option<i64>,
     /// <p>Provides the time when the snapshot was taken, in UTC.</p>
-    pub snapshot_create_time: Option<String>,
+    pub snapshot_create_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Provides the type of the DB cluster snapshot.</p>
     pub snapshot_type: Option<String>,
     /// <p>If the DB cluster snapshot was copied from a source DB cluster snapshot, the ARN for the source DB cluster snapshot; otherwise, a null value.</p>
@@ -2044,11 +2142,11 @@ pub struct DBInstance {
     /// <p>Indicates the database engine version.</p>
     pub engine_version: Option<String>,
     /// <p>Provides the date and time that the DB instance was created.</p>
-    pub instance_create_time: Option<String>,
+    pub instance_create_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p> If <code>StorageEncrypted</code> is <code>true</code>, the AWS KMS key identifier for the encrypted DB instance. </p>
     pub kms_key_id: Option<String>,
     /// <p>Specifies the latest time to which a database can be restored with point-in-time restore.</p>
-    pub latest_restorable_time: Option<String>,
+    pub latest_restorable_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Specifies that changes to the DB instance are pending. This element is included only when changes are pending. Specific changes are identified by subelements.</p>
     pub pending_modified_values: Option<PendingModifiedValues>,
     /// <p> Specifies the daily time range during which automated backups are created if automated backups are enabled, as determined by the <code>BackupRetentionPeriod</code>. </p>
@@ -3245,7 +3343,7 @@ pub struct DescribeEventsMessage {
     /// <p>The number of minutes to retrieve events for.</p> <p>Default: 60</p>
     pub duration: Option<i64>,
     /// <p> The end of the time interval for which to retrieve events, specified in ISO 8601 format. </p> <p>Example: 2009-07-08T18:00Z</p>
-    pub end_time: Option<String>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A list of event categories that trigger notifications for an event notification subscription.</p>
     pub event_categories: Option<Vec<String>>,
     /// <p>This parameter is not currently supported.</p>
@@ -3257,9 +3355,9 @@ pub struct DescribeEventsMessage {
     /// <p><p>The identifier of the event source for which events are returned. If not specified, then all sources are included in the response.</p> <p>Constraints:</p> <ul> <li> <p>If <code>SourceIdentifier</code> is provided, <code>SourceType</code> must also be provided.</p> </li> <li> <p>If the source type is <code>DBInstance</code>, a <code>DBInstanceIdentifier</code> must be provided.</p> </li> <li> <p>If the source type is <code>DBSecurityGroup</code>, a <code>DBSecurityGroupName</code> must be provided.</p> </li> <li> <p>If the source type is <code>DBParameterGroup</code>, a <code>DBParameterGroupName</code> must be provided.</p> </li> <li> <p>If the source type is <code>DBSnapshot</code>, a <code>DBSnapshotIdentifier</code> must be provided.</p> </li> <li> <p>Cannot end with a hyphen or contain two consecutive hyphens.</p> </li> </ul></p>
     pub source_identifier: Option<String>,
     /// <p>The event source to retrieve events for. If no value is specified, all events are returned.</p>
-    pub source_type: Option<String>,
+    pub source_type: Option<SourceType>,
     /// <p> The beginning of the time interval to retrieve events for, specified in ISO 8601 format. </p> <p>Example: 2009-07-08T18:00Z</p>
-    pub start_time: Option<String>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// Serialize `DescribeEventsMessage` contents to a `SignedRequest`.
@@ -3275,7 +3373,10 @@ impl DescribeEventsMessageSerializer {
             params.put(&format!("{}{}", prefix, "Duration"), &field_value);
         }
         if let Some(ref field_value) = obj.end_time {
-            params.put(&format!("{}{}", prefix, "EndTime"), &field_value);
+            params.put(
+                &format!("{}{}", prefix, "EndTime"),
+                &rusoto_core::serialization::TimestampFormat::Iso8601.format(field_value),
+            );
         }
         if let Some(ref field_value) = obj.event_categories {
             EventCategoriesListSerializer::serialize(
@@ -3304,7 +3405,10 @@ impl DescribeEventsMessageSerializer {
             params.put(&format!("{}{}", prefix, "SourceType"), &field_value);
         }
         if let Some(ref field_value) = obj.start_time {
-            params.put(&format!("{}{}", prefix, "StartTime"), &field_value);
+            params.put(
+                &format!("{}{}", prefix, "StartTime"),
+                &rusoto_core::serialization::TimestampFormat::Iso8601.format(field_value),
+            );
         }
     }
 }
@@ -3494,7 +3598,7 @@ impl EngineDefaultsDeserializer {
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct Event {
     /// <p>Specifies the date and time of the event.</p>
-    pub date: Option<String>,
+    pub date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Specifies the category for the event.</p>
     pub event_categories: Option<Vec<String>>,
     /// <p>Provides the text of this event.</p>
@@ -3504,7 +3608,7 @@ pub struct Event {
     /// <p>Provides the identifier for the source of the event.</p>
     pub source_identifier: Option<String>,
     /// <p>Specifies the source type for this event.</p>
-    pub source_type: Option<String>,
+    pub source_type: Option<SourceType>,
 }
 
 struct EventDeserializer;
@@ -4449,7 +4553,7 @@ pub struct Parameter {
     /// <p>Specifies the valid range of values for the parameter.</p>
     pub allowed_values: Option<String>,
     /// <p>Indicates when to apply parameter updates.</p>
-    pub apply_method: Option<String>,
+    pub apply_method: Option<ApplyMethod>,
     /// <p>Specifies the engine-specific parameters type.</p>
     pub apply_type: Option<String>,
     /// <p>Specifies the valid data type for the parameter.</p>
@@ -4642,13 +4746,13 @@ pub struct PendingMaintenanceAction {
     /// <p>The type of pending maintenance action that is available for the resource.</p>
     pub action: Option<String>,
     /// <p>The date of the maintenance window when the action is applied. The maintenance action is applied to the resource during its first maintenance window after this date. If this date is specified, any <code>next-maintenance</code> opt-in requests are ignored.</p>
-    pub auto_applied_after_date: Option<String>,
+    pub auto_applied_after_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The effective date when the pending maintenance action is applied to the resource.</p>
-    pub current_apply_date: Option<String>,
+    pub current_apply_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A description providing more detail about the maintenance action.</p>
     pub description: Option<String>,
     /// <p>The date when the maintenance action is automatically applied. The maintenance action is applied to the resource on this date regardless of the maintenance window for the resource. If this date is specified, any <code>immediate</code> opt-in requests are ignored.</p>
-    pub forced_apply_date: Option<String>,
+    pub forced_apply_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Indicates the type of opt-in request that has been received for the resource.</p>
     pub opt_in_status: Option<String>,
 }
@@ -5187,7 +5291,7 @@ pub struct RestoreDBClusterToPointInTimeMessage {
     /// <p>The port number on which the new DB cluster accepts connections.</p> <p>Constraints: Must be a value from <code>1150</code> to <code>65535</code>. </p> <p>Default: The default port for the engine.</p>
     pub port: Option<i64>,
     /// <p>The date and time to restore the DB cluster to.</p> <p>Valid values: A time in Universal Coordinated Time (UTC) format.</p> <p>Constraints:</p> <ul> <li> <p>Must be before the latest restorable time for the DB instance.</p> </li> <li> <p>Must be specified if the <code>UseLatestRestorableTime</code> parameter is not provided.</p> </li> <li> <p>Cannot be specified if the <code>UseLatestRestorableTime</code> parameter is <code>true</code>.</p> </li> <li> <p>Cannot be specified if the <code>RestoreType</code> parameter is <code>copy-on-write</code>.</p> </li> </ul> <p>Example: <code>2015-03-07T23:45:00Z</code> </p>
-    pub restore_to_time: Option<String>,
+    pub restore_to_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>The identifier of the source DB cluster from which to restore.</p> <p>Constraints:</p> <ul> <li> <p>Must match the identifier of an existing <code>DBCluster</code>.</p> </li> </ul></p>
     pub source_db_cluster_identifier: String,
     /// <p>The tags to be assigned to the restored DB cluster.</p>
@@ -5231,7 +5335,10 @@ impl RestoreDBClusterToPointInTimeMessageSerializer {
             params.put(&format!("{}{}", prefix, "Port"), &field_value);
         }
         if let Some(ref field_value) = obj.restore_to_time {
-            params.put(&format!("{}{}", prefix, "RestoreToTime"), &field_value);
+            params.put(
+                &format!("{}{}", prefix, "RestoreToTime"),
+                &rusoto_core::serialization::TimestampFormat::Iso8601.format(field_value),
+            );
         }
         params.put(
             &format!("{}{}", prefix, "SourceDBClusterIdentifier"),
@@ -5285,12 +5392,130 @@ impl RestoreDBClusterToPointInTimeResultDeserializer {
         )
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SourceType {
+    /// `db-instance`
+    DbInstance,
+    /// `db-parameter-group`
+    DbParameterGroup,
+    /// `db-security-group`
+    DbSecurityGroup,
+    /// `db-snapshot`
+    DbSnapshot,
+    /// `db-cluster`
+    DbCluster,
+    /// `db-cluster-snapshot`
+    DbClusterSnapshot,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SourceType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SourceType::DbInstance => "db-instance",
+            SourceType::DbParameterGroup => "db-parameter-group",
+            SourceType::DbSecurityGroup => "db-security-group",
+            SourceType::DbSnapshot => "db-snapshot",
+            SourceType::DbCluster => "db-cluster",
+            SourceType::DbClusterSnapshot => "db-cluster-snapshot",
+            SourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SourceType {
+    fn default() -> SourceType {
+        SourceType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SourceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SourceType {
+    fn from(value: &'a str) -> SourceType {
+        match value {
+            "db-instance" => SourceType::DbInstance,
+            "db-parameter-group" => SourceType::DbParameterGroup,
+            "db-security-group" => SourceType::DbSecurityGroup,
+            "db-snapshot" => SourceType::DbSnapshot,
+            "db-cluster" => SourceType::DbCluster,
+            "db-cluster-snapshot" => SourceType::DbClusterSnapshot,
+            _ => SourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SourceType {
+    fn from(value: String) -> SourceType {
+        match value.as_str() {
+            "db-instance" => SourceType::DbInstance,
+            "db-parameter-group" => SourceType::DbParameterGroup,
+            "db-security-group" => SourceType::DbSecurityGroup,
+            "db-snapshot" => SourceType::DbSnapshot,
+            "db-cluster" => SourceType::DbCluster,
+            "db-cluster-snapshot" => SourceType::DbClusterSnapshot,
+            _ => SourceType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SourceType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SourceType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SourceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for SourceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for SourceType {
+    fn deserialize<D>(deserializer: D) -> Result<SourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct SourceTypeDeserializer;
 impl SourceTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<SourceType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -5471,9 +5696,12 @@ impl SubnetListDeserializer {
 struct TStampDeserializer;
 impl TStampDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<::chrono::DateTime<::chrono::Utc>, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::proto::xml::util::timestamp_characters(stack)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -9264,11 +9492,12 @@ pub struct DocdbClient {
 impl DocdbClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> DocdbClient {
         DocdbClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "DocDB"),
         }
     }
 
@@ -9300,6 +9529,7 @@ impl Docdb for DocdbClient {
         input: AddTagsToResourceMessage,
     ) -> Result<(), RusotoError<AddTagsToResourceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("AddTagsToResource");
         let mut params = Params::new();
 
         params.put("Action", "AddTagsToResource");
@@ -9329,6 +9559,7 @@ impl Docdb for DocdbClient {
     ) -> Result<ApplyPendingMaintenanceActionResult, RusotoError<ApplyPendingMaintenanceActionError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ApplyPendingMaintenanceAction");
         let mut params = Params::new();
 
         params.put("Action", "ApplyPendingMaintenanceAction");
@@ -9379,6 +9610,7 @@ impl Docdb for DocdbClient {
     ) -> Result<CopyDBClusterParameterGroupResult, RusotoError<CopyDBClusterParameterGroupError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CopyDBClusterParameterGroup");
         let mut params = Params::new();
 
         params.put("Action", "CopyDBClusterParameterGroup");
@@ -9428,6 +9660,7 @@ impl Docdb for DocdbClient {
         input: CopyDBClusterSnapshotMessage,
     ) -> Result<CopyDBClusterSnapshotResult, RusotoError<CopyDBClusterSnapshotError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CopyDBClusterSnapshot");
         let mut params = Params::new();
 
         params.put("Action", "CopyDBClusterSnapshot");
@@ -9477,6 +9710,7 @@ impl Docdb for DocdbClient {
         input: CreateDBClusterMessage,
     ) -> Result<CreateDBClusterResult, RusotoError<CreateDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateDBCluster");
         let mut params = Params::new();
 
         params.put("Action", "CreateDBCluster");
@@ -9527,6 +9761,7 @@ impl Docdb for DocdbClient {
     ) -> Result<CreateDBClusterParameterGroupResult, RusotoError<CreateDBClusterParameterGroupError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateDBClusterParameterGroup");
         let mut params = Params::new();
 
         params.put("Action", "CreateDBClusterParameterGroup");
@@ -9576,6 +9811,7 @@ impl Docdb for DocdbClient {
         input: CreateDBClusterSnapshotMessage,
     ) -> Result<CreateDBClusterSnapshotResult, RusotoError<CreateDBClusterSnapshotError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateDBClusterSnapshot");
         let mut params = Params::new();
 
         params.put("Action", "CreateDBClusterSnapshot");
@@ -9625,6 +9861,7 @@ impl Docdb for DocdbClient {
         input: CreateDBInstanceMessage,
     ) -> Result<CreateDBInstanceResult, RusotoError<CreateDBInstanceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateDBInstance");
         let mut params = Params::new();
 
         params.put("Action", "CreateDBInstance");
@@ -9674,6 +9911,7 @@ impl Docdb for DocdbClient {
         input: CreateDBSubnetGroupMessage,
     ) -> Result<CreateDBSubnetGroupResult, RusotoError<CreateDBSubnetGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateDBSubnetGroup");
         let mut params = Params::new();
 
         params.put("Action", "CreateDBSubnetGroup");
@@ -9723,6 +9961,7 @@ impl Docdb for DocdbClient {
         input: DeleteDBClusterMessage,
     ) -> Result<DeleteDBClusterResult, RusotoError<DeleteDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteDBCluster");
         let mut params = Params::new();
 
         params.put("Action", "DeleteDBCluster");
@@ -9772,6 +10011,7 @@ impl Docdb for DocdbClient {
         input: DeleteDBClusterParameterGroupMessage,
     ) -> Result<(), RusotoError<DeleteDBClusterParameterGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteDBClusterParameterGroup");
         let mut params = Params::new();
 
         params.put("Action", "DeleteDBClusterParameterGroup");
@@ -9800,6 +10040,7 @@ impl Docdb for DocdbClient {
         input: DeleteDBClusterSnapshotMessage,
     ) -> Result<DeleteDBClusterSnapshotResult, RusotoError<DeleteDBClusterSnapshotError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteDBClusterSnapshot");
         let mut params = Params::new();
 
         params.put("Action", "DeleteDBClusterSnapshot");
@@ -9849,6 +10090,7 @@ impl Docdb for DocdbClient {
         input: DeleteDBInstanceMessage,
     ) -> Result<DeleteDBInstanceResult, RusotoError<DeleteDBInstanceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteDBInstance");
         let mut params = Params::new();
 
         params.put("Action", "DeleteDBInstance");
@@ -9898,6 +10140,7 @@ impl Docdb for DocdbClient {
         input: DeleteDBSubnetGroupMessage,
     ) -> Result<(), RusotoError<DeleteDBSubnetGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteDBSubnetGroup");
         let mut params = Params::new();
 
         params.put("Action", "DeleteDBSubnetGroup");
@@ -9926,6 +10169,7 @@ impl Docdb for DocdbClient {
         input: DescribeCertificatesMessage,
     ) -> Result<CertificateMessage, RusotoError<DescribeCertificatesError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeCertificates");
         let mut params = Params::new();
 
         params.put("Action", "DescribeCertificates");
@@ -9976,6 +10220,7 @@ impl Docdb for DocdbClient {
     ) -> Result<DBClusterParameterGroupsMessage, RusotoError<DescribeDBClusterParameterGroupsError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBClusterParameterGroups");
         let mut params = Params::new();
 
         params.put("Action", "DescribeDBClusterParameterGroups");
@@ -10027,6 +10272,7 @@ impl Docdb for DocdbClient {
         input: DescribeDBClusterParametersMessage,
     ) -> Result<DBClusterParameterGroupDetails, RusotoError<DescribeDBClusterParametersError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBClusterParameters");
         let mut params = Params::new();
 
         params.put("Action", "DescribeDBClusterParameters");
@@ -10079,6 +10325,7 @@ impl Docdb for DocdbClient {
         RusotoError<DescribeDBClusterSnapshotAttributesError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBClusterSnapshotAttributes");
         let mut params = Params::new();
 
         params.put("Action", "DescribeDBClusterSnapshotAttributes");
@@ -10130,6 +10377,7 @@ impl Docdb for DocdbClient {
         input: DescribeDBClusterSnapshotsMessage,
     ) -> Result<DBClusterSnapshotMessage, RusotoError<DescribeDBClusterSnapshotsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBClusterSnapshots");
         let mut params = Params::new();
 
         params.put("Action", "DescribeDBClusterSnapshots");
@@ -10179,6 +10427,7 @@ impl Docdb for DocdbClient {
         input: DescribeDBClustersMessage,
     ) -> Result<DBClusterMessage, RusotoError<DescribeDBClustersError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBClusters");
         let mut params = Params::new();
 
         params.put("Action", "DescribeDBClusters");
@@ -10226,6 +10475,7 @@ impl Docdb for DocdbClient {
         input: DescribeDBEngineVersionsMessage,
     ) -> Result<DBEngineVersionMessage, RusotoError<DescribeDBEngineVersionsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBEngineVersions");
         let mut params = Params::new();
 
         params.put("Action", "DescribeDBEngineVersions");
@@ -10275,6 +10525,7 @@ impl Docdb for DocdbClient {
         input: DescribeDBInstancesMessage,
     ) -> Result<DBInstanceMessage, RusotoError<DescribeDBInstancesError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBInstances");
         let mut params = Params::new();
 
         params.put("Action", "DescribeDBInstances");
@@ -10324,6 +10575,7 @@ impl Docdb for DocdbClient {
         input: DescribeDBSubnetGroupsMessage,
     ) -> Result<DBSubnetGroupMessage, RusotoError<DescribeDBSubnetGroupsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBSubnetGroups");
         let mut params = Params::new();
 
         params.put("Action", "DescribeDBSubnetGroups");
@@ -10376,6 +10628,7 @@ impl Docdb for DocdbClient {
         RusotoError<DescribeEngineDefaultClusterParametersError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeEngineDefaultClusterParameters");
         let mut params = Params::new();
 
         params.put("Action", "DescribeEngineDefaultClusterParameters");
@@ -10427,6 +10680,7 @@ impl Docdb for DocdbClient {
         input: DescribeEventCategoriesMessage,
     ) -> Result<EventCategoriesMessage, RusotoError<DescribeEventCategoriesError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeEventCategories");
         let mut params = Params::new();
 
         params.put("Action", "DescribeEventCategories");
@@ -10476,6 +10730,7 @@ impl Docdb for DocdbClient {
         input: DescribeEventsMessage,
     ) -> Result<EventsMessage, RusotoError<DescribeEventsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeEvents");
         let mut params = Params::new();
 
         params.put("Action", "DescribeEvents");
@@ -10525,6 +10780,7 @@ impl Docdb for DocdbClient {
         RusotoError<DescribeOrderableDBInstanceOptionsError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeOrderableDBInstanceOptions");
         let mut params = Params::new();
 
         params.put("Action", "DescribeOrderableDBInstanceOptions");
@@ -10577,6 +10833,7 @@ impl Docdb for DocdbClient {
     ) -> Result<PendingMaintenanceActionsMessage, RusotoError<DescribePendingMaintenanceActionsError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribePendingMaintenanceActions");
         let mut params = Params::new();
 
         params.put("Action", "DescribePendingMaintenanceActions");
@@ -10628,6 +10885,7 @@ impl Docdb for DocdbClient {
         input: FailoverDBClusterMessage,
     ) -> Result<FailoverDBClusterResult, RusotoError<FailoverDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("FailoverDBCluster");
         let mut params = Params::new();
 
         params.put("Action", "FailoverDBCluster");
@@ -10677,6 +10935,7 @@ impl Docdb for DocdbClient {
         input: ListTagsForResourceMessage,
     ) -> Result<TagListMessage, RusotoError<ListTagsForResourceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ListTagsForResource");
         let mut params = Params::new();
 
         params.put("Action", "ListTagsForResource");
@@ -10724,6 +10983,7 @@ impl Docdb for DocdbClient {
         input: ModifyDBClusterMessage,
     ) -> Result<ModifyDBClusterResult, RusotoError<ModifyDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyDBCluster");
         let mut params = Params::new();
 
         params.put("Action", "ModifyDBCluster");
@@ -10774,6 +11034,7 @@ impl Docdb for DocdbClient {
     ) -> Result<DBClusterParameterGroupNameMessage, RusotoError<ModifyDBClusterParameterGroupError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyDBClusterParameterGroup");
         let mut params = Params::new();
 
         params.put("Action", "ModifyDBClusterParameterGroup");
@@ -10826,6 +11087,7 @@ impl Docdb for DocdbClient {
         RusotoError<ModifyDBClusterSnapshotAttributeError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyDBClusterSnapshotAttribute");
         let mut params = Params::new();
 
         params.put("Action", "ModifyDBClusterSnapshotAttribute");
@@ -10877,6 +11139,7 @@ impl Docdb for DocdbClient {
         input: ModifyDBInstanceMessage,
     ) -> Result<ModifyDBInstanceResult, RusotoError<ModifyDBInstanceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyDBInstance");
         let mut params = Params::new();
 
         params.put("Action", "ModifyDBInstance");
@@ -10926,6 +11189,7 @@ impl Docdb for DocdbClient {
         input: ModifyDBSubnetGroupMessage,
     ) -> Result<ModifyDBSubnetGroupResult, RusotoError<ModifyDBSubnetGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyDBSubnetGroup");
         let mut params = Params::new();
 
         params.put("Action", "ModifyDBSubnetGroup");
@@ -10975,6 +11239,7 @@ impl Docdb for DocdbClient {
         input: RebootDBInstanceMessage,
     ) -> Result<RebootDBInstanceResult, RusotoError<RebootDBInstanceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("RebootDBInstance");
         let mut params = Params::new();
 
         params.put("Action", "RebootDBInstance");
@@ -11024,6 +11289,7 @@ impl Docdb for DocdbClient {
         input: RemoveTagsFromResourceMessage,
     ) -> Result<(), RusotoError<RemoveTagsFromResourceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("RemoveTagsFromResource");
         let mut params = Params::new();
 
         params.put("Action", "RemoveTagsFromResource");
@@ -11053,6 +11319,7 @@ impl Docdb for DocdbClient {
     ) -> Result<DBClusterParameterGroupNameMessage, RusotoError<ResetDBClusterParameterGroupError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ResetDBClusterParameterGroup");
         let mut params = Params::new();
 
         params.put("Action", "ResetDBClusterParameterGroup");
@@ -11103,6 +11370,7 @@ impl Docdb for DocdbClient {
     ) -> Result<RestoreDBClusterFromSnapshotResult, RusotoError<RestoreDBClusterFromSnapshotError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("RestoreDBClusterFromSnapshot");
         let mut params = Params::new();
 
         params.put("Action", "RestoreDBClusterFromSnapshot");
@@ -11153,6 +11421,7 @@ impl Docdb for DocdbClient {
     ) -> Result<RestoreDBClusterToPointInTimeResult, RusotoError<RestoreDBClusterToPointInTimeError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("RestoreDBClusterToPointInTime");
         let mut params = Params::new();
 
         params.put("Action", "RestoreDBClusterToPointInTime");
@@ -11202,6 +11471,7 @@ impl Docdb for DocdbClient {
         input: StartDBClusterMessage,
     ) -> Result<StartDBClusterResult, RusotoError<StartDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("StartDBCluster");
         let mut params = Params::new();
 
         params.put("Action", "StartDBCluster");
@@ -11249,6 +11519,7 @@ impl Docdb for DocdbClient {
         input: StopDBClusterMessage,
     ) -> Result<StopDBClusterResult, RusotoError<StopDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("StopDBCluster");
         let mut params = Params::new();
 
         params.put("Action", "StopDBCluster");
@@ -11290,3 +11561,562 @@ impl Docdb for DocdbClient {
         Ok(result)
     }
 }
+impl DocdbClient {
+    /// Auto-paginating version of `describe_certificates`, streaming every page of results.
+    pub fn describe_certificates_pages(
+        &self,
+        input: DescribeCertificatesMessage,
+    ) -> rusoto_core::pagination::RusotoStream<CertificateMessage, DescribeCertificatesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DocdbClient, input: DescribeCertificatesMessage| async move {
+                client.describe_certificates(input).await
+            },
+            |input: &DescribeCertificatesMessage, output: &CertificateMessage| {
+                Some(DescribeCertificatesMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_certificates`, streaming the `Certificates` of every page of results.
+    pub fn describe_certificates_items(
+        &self,
+        input: DescribeCertificatesMessage,
+    ) -> rusoto_core::pagination::RusotoStream<Certificate, DescribeCertificatesError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_certificates_pages(input),
+            |output: CertificateMessage| output.certificates.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_cluster_parameter_groups`, streaming every page of results.
+    pub fn describe_db_cluster_parameter_groups_pages(
+        &self,
+        input: DescribeDBClusterParameterGroupsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DBClusterParameterGroupsMessage,
+        DescribeDBClusterParameterGroupsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DocdbClient, input: DescribeDBClusterParameterGroupsMessage| async move {
+                client.describe_db_cluster_parameter_groups(input).await
+            },
+            |input: &DescribeDBClusterParameterGroupsMessage,
+             output: &DBClusterParameterGroupsMessage| {
+                Some(DescribeDBClusterParameterGroupsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_cluster_parameter_groups`, streaming the `DBClusterParameterGroups` of every page of results.
+    pub fn describe_db_cluster_parameter_groups_items(
+        &self,
+        input: DescribeDBClusterParameterGroupsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DBClusterParameterGroup,
+        DescribeDBClusterParameterGroupsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_db_cluster_parameter_groups_pages(input),
+            |output: DBClusterParameterGroupsMessage| {
+                output.db_cluster_parameter_groups.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_cluster_parameters`, streaming every page of results.
+    pub fn describe_db_cluster_parameters_pages(
+        &self,
+        input: DescribeDBClusterParametersMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DBClusterParameterGroupDetails,
+        DescribeDBClusterParametersError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DocdbClient, input: DescribeDBClusterParametersMessage| async move {
+                client.describe_db_cluster_parameters(input).await
+            },
+            |input: &DescribeDBClusterParametersMessage,
+             output: &DBClusterParameterGroupDetails| {
+                Some(DescribeDBClusterParametersMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_cluster_parameters`, streaming the `Parameters` of every page of results.
+    pub fn describe_db_cluster_parameters_items(
+        &self,
+        input: DescribeDBClusterParametersMessage,
+    ) -> rusoto_core::pagination::RusotoStream<Parameter, DescribeDBClusterParametersError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_db_cluster_parameters_pages(input),
+            |output: DBClusterParameterGroupDetails| output.parameters.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_cluster_snapshots`, streaming every page of results.
+    pub fn describe_db_cluster_snapshots_pages(
+        &self,
+        input: DescribeDBClusterSnapshotsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DBClusterSnapshotMessage,
+        DescribeDBClusterSnapshotsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DocdbClient, input: DescribeDBClusterSnapshotsMessage| async move {
+                client.describe_db_cluster_snapshots(input).await
+            },
+            |input: &DescribeDBClusterSnapshotsMessage, output: &DBClusterSnapshotMessage| {
+                Some(DescribeDBClusterSnapshotsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_cluster_snapshots`, streaming the `DBClusterSnapshots` of every page of results.
+    pub fn describe_db_cluster_snapshots_items(
+        &self,
+        input: DescribeDBClusterSnapshotsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<DBClusterSnapshot, DescribeDBClusterSnapshotsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_db_cluster_snapshots_pages(input),
+            |output: DBClusterSnapshotMessage| output.db_cluster_snapshots.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_clusters`, streaming every page of results.
+    pub fn describe_db_clusters_pages(
+        &self,
+        input: DescribeDBClustersMessage,
+    ) -> rusoto_core::pagination::RusotoStream<DBClusterMessage, DescribeDBClustersError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DocdbClient, input: DescribeDBClustersMessage| async move {
+                client.describe_db_clusters(input).await
+            },
+            |input: &DescribeDBClustersMessage, output: &DBClusterMessage| {
+                Some(DescribeDBClustersMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_clusters`, streaming the `DBClusters` of every page of results.
+    pub fn describe_db_clusters_items(
+        &self,
+        input: DescribeDBClustersMessage,
+    ) -> rusoto_core::pagination::RusotoStream<DBCluster, DescribeDBClustersError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_db_clusters_pages(input),
+            |output: DBClusterMessage| output.db_clusters.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_engine_versions`, streaming every page of results.
+    pub fn describe_db_engine_versions_pages(
+        &self,
+        input: DescribeDBEngineVersionsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<DBEngineVersionMessage, DescribeDBEngineVersionsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DocdbClient, input: DescribeDBEngineVersionsMessage| async move {
+                client.describe_db_engine_versions(input).await
+            },
+            |input: &DescribeDBEngineVersionsMessage, output: &DBEngineVersionMessage| {
+                Some(DescribeDBEngineVersionsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_engine_versions`, streaming the `DBEngineVersions` of every page of results.
+    pub fn describe_db_engine_versions_items(
+        &self,
+        input: DescribeDBEngineVersionsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<DBEngineVersion, DescribeDBEngineVersionsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_db_engine_versions_pages(input),
+            |output: DBEngineVersionMessage| output.db_engine_versions.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_instances`, streaming every page of results.
+    pub fn describe_db_instances_pages(
+        &self,
+        input: DescribeDBInstancesMessage,
+    ) -> rusoto_core::pagination::RusotoStream<DBInstanceMessage, DescribeDBInstancesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DocdbClient, input: DescribeDBInstancesMessage| async move {
+                client.describe_db_instances(input).await
+            },
+            |input: &DescribeDBInstancesMessage, output: &DBInstanceMessage| {
+                Some(DescribeDBInstancesMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_instances`, streaming the `DBInstances` of every page of results.
+    pub fn describe_db_instances_items(
+        &self,
+        input: DescribeDBInstancesMessage,
+    ) -> rusoto_core::pagination::RusotoStream<DBInstance, DescribeDBInstancesError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_db_instances_pages(input),
+            |output: DBInstanceMessage| output.db_instances.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_subnet_groups`, streaming every page of results.
+    pub fn describe_db_subnet_groups_pages(
+        &self,
+        input: DescribeDBSubnetGroupsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<DBSubnetGroupMessage, DescribeDBSubnetGroupsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DocdbClient, input: DescribeDBSubnetGroupsMessage| async move {
+                client.describe_db_subnet_groups(input).await
+            },
+            |input: &DescribeDBSubnetGroupsMessage, output: &DBSubnetGroupMessage| {
+                Some(DescribeDBSubnetGroupsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_subnet_groups`, streaming the `DBSubnetGroups` of every page of results.
+    pub fn describe_db_subnet_groups_items(
+        &self,
+        input: DescribeDBSubnetGroupsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<DBSubnetGroup, DescribeDBSubnetGroupsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_db_subnet_groups_pages(input),
+            |output: DBSubnetGroupMessage| output.db_subnet_groups.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_events`, streaming every page of results.
+    pub fn describe_events_pages(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<EventsMessage, DescribeEventsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DocdbClient, input: DescribeEventsMessage| async move {
+                client.describe_events(input).await
+            },
+            |input: &DescribeEventsMessage, output: &EventsMessage| {
+                Some(DescribeEventsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_events`, streaming the `Events` of every page of results.
+    pub fn describe_events_items(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<Event, DescribeEventsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_events_pages(input),
+            |output: EventsMessage| output.events.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_orderable_db_instance_options`, streaming every page of results.
+    pub fn describe_orderable_db_instance_options_pages(
+        &self,
+        input: DescribeOrderableDBInstanceOptionsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        OrderableDBInstanceOptionsMessage,
+        DescribeOrderableDBInstanceOptionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DocdbClient, input: DescribeOrderableDBInstanceOptionsMessage| async move {
+                client.describe_orderable_db_instance_options(input).await
+            },
+            |input: &DescribeOrderableDBInstanceOptionsMessage,
+             output: &OrderableDBInstanceOptionsMessage| {
+                Some(DescribeOrderableDBInstanceOptionsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_orderable_db_instance_options`, streaming the `OrderableDBInstanceOptions` of every page of results.
+    pub fn describe_orderable_db_instance_options_items(
+        &self,
+        input: DescribeOrderableDBInstanceOptionsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        OrderableDBInstanceOption,
+        DescribeOrderableDBInstanceOptionsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_orderable_db_instance_options_pages(input),
+            |output: OrderableDBInstanceOptionsMessage| {
+                output.orderable_db_instance_options.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_pending_maintenance_actions`, streaming every page of results.
+    pub fn describe_pending_maintenance_actions_pages(
+        &self,
+        input: DescribePendingMaintenanceActionsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        PendingMaintenanceActionsMessage,
+        DescribePendingMaintenanceActionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DocdbClient, input: DescribePendingMaintenanceActionsMessage| async move {
+                client.describe_pending_maintenance_actions(input).await
+            },
+            |input: &DescribePendingMaintenanceActionsMessage,
+             output: &PendingMaintenanceActionsMessage| {
+                Some(DescribePendingMaintenanceActionsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_pending_maintenance_actions`, streaming the `PendingMaintenanceActions` of every page of results.
+    pub fn describe_pending_maintenance_actions_items(
+        &self,
+        input: DescribePendingMaintenanceActionsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ResourcePendingMaintenanceActions,
+        DescribePendingMaintenanceActionsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_pending_maintenance_actions_pages(input),
+            |output: PendingMaintenanceActionsMessage| {
+                output.pending_maintenance_actions.unwrap_or_default()
+            },
+        )
+    }
+}
+impl DocdbClient {
+    /// Waits until `describe_db_instances` reports the `DBInstanceAvailable` state, polling every 30 seconds up to 60 times.
+    pub async fn wait_until_db_instance_available(
+        &self,
+        input: DescribeDBInstancesMessage,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeDBInstancesError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(30), 60);
+        self.wait_until_db_instance_available_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_db_instance_available`, polling as often and as many times as `config` says.
+    pub async fn wait_until_db_instance_available_with_config(
+        &self,
+        input: DescribeDBInstancesMessage,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeDBInstancesError>> {
+        rusoto_core::waiter::wait(
+            "DBInstanceAvailable",
+            &config,
+            || self.describe_db_instances(input.clone()),
+            |result: &Result<DBInstanceMessage, RusotoError<DescribeDBInstancesError>>| match result
+            {
+                Ok(output)
+                    if rusoto_core::waiter::all_match(
+                        output
+                            .db_instances
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.db_instance_status.iter()),
+                        |v| v == "available",
+                    ) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if output
+                        .db_instances
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.db_instance_status.iter())
+                        .any(|v| v == "deleted") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .db_instances
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.db_instance_status.iter())
+                        .any(|v| v == "deleting") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .db_instances
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.db_instance_status.iter())
+                        .any(|v| v == "failed") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .db_instances
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.db_instance_status.iter())
+                        .any(|v| v == "incompatible-restore") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .db_instances
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.db_instance_status.iter())
+                        .any(|v| v == "incompatible-parameters") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+
+    /// Waits until `describe_db_instances` reports the `DBInstanceDeleted` state, polling every 30 seconds up to 60 times.
+    pub async fn wait_until_db_instance_deleted(
+        &self,
+        input: DescribeDBInstancesMessage,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeDBInstancesError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(30), 60);
+        self.wait_until_db_instance_deleted_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_db_instance_deleted`, polling as often and as many times as `config` says.
+    pub async fn wait_until_db_instance_deleted_with_config(
+        &self,
+        input: DescribeDBInstancesMessage,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeDBInstancesError>> {
+        rusoto_core::waiter::wait(
+            "DBInstanceDeleted",
+            &config,
+            || self.describe_db_instances(input.clone()),
+            |result: &Result<DBInstanceMessage, RusotoError<DescribeDBInstancesError>>| match result
+            {
+                Ok(output)
+                    if rusoto_core::waiter::all_match(
+                        output
+                            .db_instances
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.db_instance_status.iter()),
+                        |v| v == "deleted",
+                    ) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Err(RusotoError::Service(DescribeDBInstancesError::DBInstanceNotFoundFault(_))) => {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Err(RusotoError::Unknown(response))
+                    if rusoto_core::retry::error_code(response).as_deref()
+                        == Some("DBInstanceNotFound") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if output
+                        .db_instances
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.db_instance_status.iter())
+                        .any(|v| v == "creating") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .db_instances
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.db_instance_status.iter())
+                        .any(|v| v == "modifying") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .db_instances
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.db_instance_status.iter())
+                        .any(|v| v == "rebooting") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .db_instances
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.db_instance_status.iter())
+                        .any(|v| v == "resetting-master-credentials") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+}
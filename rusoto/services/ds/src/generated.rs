@@ -117,16 +117,26 @@ pub struct Certificate {
     pub common_name: Option<String>,
     /// <p>The date and time when the certificate will expire.</p>
     #[serde(rename = "ExpiryDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expiry_date_time: Option<f64>,
+    pub expiry_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The date and time that the certificate was registered.</p>
     #[serde(rename = "RegisteredDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub registered_date_time: Option<f64>,
+    pub registered_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The state of the certificate.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<CertificateState>,
     /// <p>Describes a state change for the certificate.</p>
     #[serde(rename = "StateReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -148,9 +158,122 @@ pub struct CertificateInfo {
     /// <p>The state of the certificate.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<CertificateState>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CertificateState {
+    /// `Registering`
+    Registering,
+    /// `Registered`
+    Registered,
+    /// `RegisterFailed`
+    RegisterFailed,
+    /// `Deregistering`
+    Deregistering,
+    /// `Deregistered`
+    Deregistered,
+    /// `DeregisterFailed`
+    DeregisterFailed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CertificateState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CertificateState::Registering => "Registering",
+            CertificateState::Registered => "Registered",
+            CertificateState::RegisterFailed => "RegisterFailed",
+            CertificateState::Deregistering => "Deregistering",
+            CertificateState::Deregistered => "Deregistered",
+            CertificateState::DeregisterFailed => "DeregisterFailed",
+            CertificateState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CertificateState {
+    fn default() -> CertificateState {
+        CertificateState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CertificateState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CertificateState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CertificateState {
+    fn from(value: &'a str) -> CertificateState {
+        match value {
+            "Registering" => CertificateState::Registering,
+            "Registered" => CertificateState::Registered,
+            "RegisterFailed" => CertificateState::RegisterFailed,
+            "Deregistering" => CertificateState::Deregistering,
+            "Deregistered" => CertificateState::Deregistered,
+            "DeregisterFailed" => CertificateState::DeregisterFailed,
+            _ => CertificateState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CertificateState {
+    fn from(value: String) -> CertificateState {
+        match value.as_str() {
+            "Registering" => CertificateState::Registering,
+            "Registered" => CertificateState::Registered,
+            "RegisterFailed" => CertificateState::RegisterFailed,
+            "Deregistering" => CertificateState::Deregistering,
+            "Deregistered" => CertificateState::Deregistered,
+            "DeregisterFailed" => CertificateState::DeregisterFailed,
+            _ => CertificateState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CertificateState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CertificateState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CertificateState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CertificateState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for CertificateState {
+    fn deserialize<D>(deserializer: D) -> Result<CertificateState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Contains information about a computer account in a directory.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -184,7 +307,7 @@ pub struct ConditionalForwarder {
     /// <p>The replication scope of the conditional forwarder. The only allowed value is <code>Domain</code>, which will replicate the conditional forwarder to all of the domain controllers for your AWS directory.</p>
     #[serde(rename = "ReplicationScope")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub replication_scope: Option<String>,
+    pub replication_scope: Option<ReplicationScope>,
 }
 
 /// <p>Contains the inputs for the <a>ConnectDirectory</a> operation.</p>
@@ -210,7 +333,7 @@ pub struct ConnectDirectoryRequest {
     pub short_name: Option<String>,
     /// <p>The size of the directory.</p>
     #[serde(rename = "Size")]
-    pub size: String,
+    pub size: DirectorySize,
     /// <p>The tags to be assigned to AD Connector.</p>
     #[serde(rename = "Tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -326,7 +449,7 @@ pub struct CreateDirectoryRequest {
     pub short_name: Option<String>,
     /// <p>The size of the directory.</p>
     #[serde(rename = "Size")]
-    pub size: String,
+    pub size: DirectorySize,
     /// <p>The tags to be assigned to the Simple AD directory.</p>
     #[serde(rename = "Tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -373,7 +496,7 @@ pub struct CreateMicrosoftADRequest {
     /// <p>AWS Managed Microsoft AD is available in two editions: <code>Standard</code> and <code>Enterprise</code>. <code>Enterprise</code> is the default.</p>
     #[serde(rename = "Edition")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub edition: Option<String>,
+    pub edition: Option<DirectoryEdition>,
     /// <p>The fully qualified domain name for the AWS Managed Microsoft AD directory, such as <code>corp.example.com</code>. This name will resolve inside your VPC only. It does not need to be publicly resolvable.</p>
     #[serde(rename = "Name")]
     pub name: String,
@@ -443,17 +566,17 @@ pub struct CreateTrustRequest {
     /// <p>Optional parameter to enable selective authentication for the trust.</p>
     #[serde(rename = "SelectiveAuth")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub selective_auth: Option<String>,
+    pub selective_auth: Option<SelectiveAuth>,
     /// <p>The direction of the trust relationship.</p>
     #[serde(rename = "TrustDirection")]
-    pub trust_direction: String,
+    pub trust_direction: TrustDirection,
     /// <p>The trust password. The must be the same password that was used when creating the trust relationship on the external domain.</p>
     #[serde(rename = "TrustPassword")]
     pub trust_password: String,
     /// <p>The trust relationship type. <code>Forest</code> is the default.</p>
     #[serde(rename = "TrustType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub trust_type: Option<String>,
+    pub trust_type: Option<TrustType>,
 }
 
 /// <p>The result of a CreateTrust request.</p>
@@ -737,7 +860,7 @@ pub struct DescribeLDAPSSettingsRequest {
     /// <p>The type of LDAP security the customer wants to enable, either server or client. Currently supports only <code>Client</code>, (the default).</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<LDAPSType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -941,11 +1064,16 @@ pub struct DirectoryDescription {
     /// <p>The edition associated with this directory.</p>
     #[serde(rename = "Edition")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub edition: Option<String>,
+    pub edition: Option<DirectoryEdition>,
     /// <p>Specifies when the directory was created.</p>
     #[serde(rename = "LaunchTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub launch_time: Option<f64>,
+    pub launch_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The fully qualified name of the directory.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -961,11 +1089,11 @@ pub struct DirectoryDescription {
     /// <p>The status of the RADIUS MFA server connection.</p>
     #[serde(rename = "RadiusStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub radius_status: Option<String>,
+    pub radius_status: Option<RadiusStatus>,
     /// <p>The method used when sharing a directory to determine whether the directory should be shared within your AWS organization (<code>ORGANIZATIONS</code>) or with any AWS account by sending a shared directory request (<code>HANDSHAKE</code>).</p>
     #[serde(rename = "ShareMethod")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub share_method: Option<String>,
+    pub share_method: Option<ShareMethod>,
     /// <p>A directory share request that is sent by the directory owner to the directory consumer. The request includes a typed message to help the directory consumer administrator determine whether to approve or reject the share invitation.</p>
     #[serde(rename = "ShareNotes")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -973,7 +1101,7 @@ pub struct DirectoryDescription {
     /// <p>Current directory status of the shared AWS Managed Microsoft AD directory.</p>
     #[serde(rename = "ShareStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub share_status: Option<String>,
+    pub share_status: Option<ShareStatus>,
     /// <p>The short name of the directory.</p>
     #[serde(rename = "ShortName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -981,7 +1109,7 @@ pub struct DirectoryDescription {
     /// <p>The directory size.</p>
     #[serde(rename = "Size")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub size: Option<String>,
+    pub size: Option<DirectorySize>,
     /// <p>Indicates if single sign-on is enabled for the directory. For more information, see <a>EnableSso</a> and <a>DisableSso</a>.</p>
     #[serde(rename = "SsoEnabled")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -989,11 +1117,16 @@ pub struct DirectoryDescription {
     /// <p>The current stage of the directory.</p>
     #[serde(rename = "Stage")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stage: Option<String>,
+    pub stage: Option<DirectoryStage>,
     /// <p>The date and time that the stage was last updated.</p>
     #[serde(rename = "StageLastUpdatedDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stage_last_updated_date_time: Option<f64>,
+    pub stage_last_updated_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Additional information about the directory stage.</p>
     #[serde(rename = "StageReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1001,13 +1134,106 @@ pub struct DirectoryDescription {
     /// <p>The directory size.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<DirectoryType>,
     /// <p>A <a>DirectoryVpcSettingsDescription</a> object that contains additional information about a directory. This member is only present if the directory is a Simple AD or Managed AD directory.</p>
     #[serde(rename = "VpcSettings")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub vpc_settings: Option<DirectoryVpcSettingsDescription>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DirectoryEdition {
+    /// `Enterprise`
+    Enterprise,
+    /// `Standard`
+    Standard,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DirectoryEdition {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DirectoryEdition::Enterprise => "Enterprise",
+            DirectoryEdition::Standard => "Standard",
+            DirectoryEdition::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DirectoryEdition {
+    fn default() -> DirectoryEdition {
+        DirectoryEdition::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DirectoryEdition {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DirectoryEdition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DirectoryEdition {
+    fn from(value: &'a str) -> DirectoryEdition {
+        match value {
+            "Enterprise" => DirectoryEdition::Enterprise,
+            "Standard" => DirectoryEdition::Standard,
+            _ => DirectoryEdition::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DirectoryEdition {
+    fn from(value: String) -> DirectoryEdition {
+        match value.as_str() {
+            "Enterprise" => DirectoryEdition::Enterprise,
+            "Standard" => DirectoryEdition::Standard,
+            _ => DirectoryEdition::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DirectoryEdition {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DirectoryEdition, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DirectoryEdition {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DirectoryEdition {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DirectoryEdition {
+    fn deserialize<D>(deserializer: D) -> Result<DirectoryEdition, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Contains directory limit information for a Region.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1050,6 +1276,340 @@ pub struct DirectoryLimits {
     pub connected_directories_limit_reached: Option<bool>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DirectorySize {
+    /// `Small`
+    Small,
+    /// `Large`
+    Large,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DirectorySize {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DirectorySize::Small => "Small",
+            DirectorySize::Large => "Large",
+            DirectorySize::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DirectorySize {
+    fn default() -> DirectorySize {
+        DirectorySize::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DirectorySize {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DirectorySize {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DirectorySize {
+    fn from(value: &'a str) -> DirectorySize {
+        match value {
+            "Small" => DirectorySize::Small,
+            "Large" => DirectorySize::Large,
+            _ => DirectorySize::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DirectorySize {
+    fn from(value: String) -> DirectorySize {
+        match value.as_str() {
+            "Small" => DirectorySize::Small,
+            "Large" => DirectorySize::Large,
+            _ => DirectorySize::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DirectorySize {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DirectorySize, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DirectorySize {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DirectorySize {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DirectorySize {
+    fn deserialize<D>(deserializer: D) -> Result<DirectorySize, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DirectoryStage {
+    /// `Requested`
+    Requested,
+    /// `Creating`
+    Creating,
+    /// `Created`
+    Created,
+    /// `Active`
+    Active,
+    /// `Inoperable`
+    Inoperable,
+    /// `Impaired`
+    Impaired,
+    /// `Restoring`
+    Restoring,
+    /// `RestoreFailed`
+    RestoreFailed,
+    /// `Deleting`
+    Deleting,
+    /// `Deleted`
+    Deleted,
+    /// `Failed`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DirectoryStage {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DirectoryStage::Requested => "Requested",
+            DirectoryStage::Creating => "Creating",
+            DirectoryStage::Created => "Created",
+            DirectoryStage::Active => "Active",
+            DirectoryStage::Inoperable => "Inoperable",
+            DirectoryStage::Impaired => "Impaired",
+            DirectoryStage::Restoring => "Restoring",
+            DirectoryStage::RestoreFailed => "RestoreFailed",
+            DirectoryStage::Deleting => "Deleting",
+            DirectoryStage::Deleted => "Deleted",
+            DirectoryStage::Failed => "Failed",
+            DirectoryStage::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DirectoryStage {
+    fn default() -> DirectoryStage {
+        DirectoryStage::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DirectoryStage {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DirectoryStage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DirectoryStage {
+    fn from(value: &'a str) -> DirectoryStage {
+        match value {
+            "Requested" => DirectoryStage::Requested,
+            "Creating" => DirectoryStage::Creating,
+            "Created" => DirectoryStage::Created,
+            "Active" => DirectoryStage::Active,
+            "Inoperable" => DirectoryStage::Inoperable,
+            "Impaired" => DirectoryStage::Impaired,
+            "Restoring" => DirectoryStage::Restoring,
+            "RestoreFailed" => DirectoryStage::RestoreFailed,
+            "Deleting" => DirectoryStage::Deleting,
+            "Deleted" => DirectoryStage::Deleted,
+            "Failed" => DirectoryStage::Failed,
+            _ => DirectoryStage::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DirectoryStage {
+    fn from(value: String) -> DirectoryStage {
+        match value.as_str() {
+            "Requested" => DirectoryStage::Requested,
+            "Creating" => DirectoryStage::Creating,
+            "Created" => DirectoryStage::Created,
+            "Active" => DirectoryStage::Active,
+            "Inoperable" => DirectoryStage::Inoperable,
+            "Impaired" => DirectoryStage::Impaired,
+            "Restoring" => DirectoryStage::Restoring,
+            "RestoreFailed" => DirectoryStage::RestoreFailed,
+            "Deleting" => DirectoryStage::Deleting,
+            "Deleted" => DirectoryStage::Deleted,
+            "Failed" => DirectoryStage::Failed,
+            _ => DirectoryStage::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DirectoryStage {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DirectoryStage, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DirectoryStage {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DirectoryStage {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DirectoryStage {
+    fn deserialize<D>(deserializer: D) -> Result<DirectoryStage, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DirectoryType {
+    /// `SimpleAD`
+    SimpleAD,
+    /// `ADConnector`
+    ADConnector,
+    /// `MicrosoftAD`
+    MicrosoftAD,
+    /// `SharedMicrosoftAD`
+    SharedMicrosoftAD,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DirectoryType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DirectoryType::SimpleAD => "SimpleAD",
+            DirectoryType::ADConnector => "ADConnector",
+            DirectoryType::MicrosoftAD => "MicrosoftAD",
+            DirectoryType::SharedMicrosoftAD => "SharedMicrosoftAD",
+            DirectoryType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DirectoryType {
+    fn default() -> DirectoryType {
+        DirectoryType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DirectoryType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DirectoryType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DirectoryType {
+    fn from(value: &'a str) -> DirectoryType {
+        match value {
+            "SimpleAD" => DirectoryType::SimpleAD,
+            "ADConnector" => DirectoryType::ADConnector,
+            "MicrosoftAD" => DirectoryType::MicrosoftAD,
+            "SharedMicrosoftAD" => DirectoryType::SharedMicrosoftAD,
+            _ => DirectoryType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DirectoryType {
+    fn from(value: String) -> DirectoryType {
+        match value.as_str() {
+            "SimpleAD" => DirectoryType::SimpleAD,
+            "ADConnector" => DirectoryType::ADConnector,
+            "MicrosoftAD" => DirectoryType::MicrosoftAD,
+            "SharedMicrosoftAD" => DirectoryType::SharedMicrosoftAD,
+            _ => DirectoryType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DirectoryType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DirectoryType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DirectoryType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DirectoryType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DirectoryType {
+    fn deserialize<D>(deserializer: D) -> Result<DirectoryType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Contains VPC information for the <a>CreateDirectory</a> or <a>CreateMicrosoftAD</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -1092,7 +1652,7 @@ pub struct DisableLDAPSRequest {
     pub directory_id: String,
     /// <p>The type of LDAP security that the customer wants to enable. The security can be either server or client, but currently only the default <code>Client</code> is supported.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: LDAPSType,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1157,16 +1717,26 @@ pub struct DomainController {
     pub domain_controller_id: Option<String>,
     /// <p>Specifies when the domain controller was created.</p>
     #[serde(rename = "LaunchTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub launch_time: Option<f64>,
+    pub launch_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The status of the domain controller.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<DomainControllerStatus>,
     /// <p>The date and time that the status was last updated.</p>
     #[serde(rename = "StatusLastUpdatedDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status_last_updated_date_time: Option<f64>,
+    pub status_last_updated_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A description of the domain controller state.</p>
     #[serde(rename = "StatusReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1181,6 +1751,124 @@ pub struct DomainController {
     pub vpc_id: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DomainControllerStatus {
+    /// `Creating`
+    Creating,
+    /// `Active`
+    Active,
+    /// `Impaired`
+    Impaired,
+    /// `Restoring`
+    Restoring,
+    /// `Deleting`
+    Deleting,
+    /// `Deleted`
+    Deleted,
+    /// `Failed`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DomainControllerStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DomainControllerStatus::Creating => "Creating",
+            DomainControllerStatus::Active => "Active",
+            DomainControllerStatus::Impaired => "Impaired",
+            DomainControllerStatus::Restoring => "Restoring",
+            DomainControllerStatus::Deleting => "Deleting",
+            DomainControllerStatus::Deleted => "Deleted",
+            DomainControllerStatus::Failed => "Failed",
+            DomainControllerStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DomainControllerStatus {
+    fn default() -> DomainControllerStatus {
+        DomainControllerStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DomainControllerStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DomainControllerStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DomainControllerStatus {
+    fn from(value: &'a str) -> DomainControllerStatus {
+        match value {
+            "Creating" => DomainControllerStatus::Creating,
+            "Active" => DomainControllerStatus::Active,
+            "Impaired" => DomainControllerStatus::Impaired,
+            "Restoring" => DomainControllerStatus::Restoring,
+            "Deleting" => DomainControllerStatus::Deleting,
+            "Deleted" => DomainControllerStatus::Deleted,
+            "Failed" => DomainControllerStatus::Failed,
+            _ => DomainControllerStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DomainControllerStatus {
+    fn from(value: String) -> DomainControllerStatus {
+        match value.as_str() {
+            "Creating" => DomainControllerStatus::Creating,
+            "Active" => DomainControllerStatus::Active,
+            "Impaired" => DomainControllerStatus::Impaired,
+            "Restoring" => DomainControllerStatus::Restoring,
+            "Deleting" => DomainControllerStatus::Deleting,
+            "Deleted" => DomainControllerStatus::Deleted,
+            "Failed" => DomainControllerStatus::Failed,
+            _ => DomainControllerStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DomainControllerStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DomainControllerStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DomainControllerStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DomainControllerStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DomainControllerStatus {
+    fn deserialize<D>(deserializer: D) -> Result<DomainControllerStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct EnableLDAPSRequest {
@@ -1189,7 +1877,7 @@ pub struct EnableLDAPSRequest {
     pub directory_id: String,
     /// <p>The type of LDAP security the customer wants to enable. The security can be either server or client, but currently only the default <code>Client</code> is supported.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: LDAPSType,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1241,8 +1929,13 @@ pub struct EnableSsoResult {}
 pub struct EventTopic {
     /// <p>The date and time of when you associated your directory with the SNS topic.</p>
     #[serde(rename = "CreatedDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date_time: Option<f64>,
+    pub created_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The Directory ID of an AWS Directory Service directory that will publish status messages to an SNS topic.</p>
     #[serde(rename = "DirectoryId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1250,7 +1943,7 @@ pub struct EventTopic {
     /// <p>The topic registration status.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<TopicStatus>,
     /// <p>The SNS topic ARN (Amazon Resource Name).</p>
     #[serde(rename = "TopicArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1315,8 +2008,13 @@ pub struct IpRoute {
 pub struct IpRouteInfo {
     /// <p>The date and time the address block was added to the directory.</p>
     #[serde(rename = "AddedDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub added_date_time: Option<f64>,
+    pub added_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>IP address block in the <a>IpRoute</a>.</p>
     #[serde(rename = "CidrIp")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1332,38 +2030,347 @@ pub struct IpRouteInfo {
     /// <p>The status of the IP address block.</p>
     #[serde(rename = "IpRouteStatusMsg")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ip_route_status_msg: Option<String>,
+    pub ip_route_status_msg: Option<IpRouteStatusMsg>,
     /// <p>The reason for the IpRouteStatusMsg.</p>
     #[serde(rename = "IpRouteStatusReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ip_route_status_reason: Option<String>,
 }
 
-/// <p>Contains general information about the LDAPS settings.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct LDAPSSettingInfo {
-    /// <p>The state of the LDAPS settings.</p>
-    #[serde(rename = "LDAPSStatus")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub ldaps_status: Option<String>,
-    /// <p>Describes a state change for LDAPS.</p>
-    #[serde(rename = "LDAPSStatusReason")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub ldaps_status_reason: Option<String>,
-    /// <p>The date and time when the LDAPS settings were last updated.</p>
-    #[serde(rename = "LastUpdatedDateTime")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date_time: Option<f64>,
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum IpRouteStatusMsg {
+    /// `Adding`
+    Adding,
+    /// `Added`
+    Added,
+    /// `Removing`
+    Removing,
+    /// `Removed`
+    Removed,
+    /// `AddFailed`
+    AddFailed,
+    /// `RemoveFailed`
+    RemoveFailed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl IpRouteStatusMsg {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            IpRouteStatusMsg::Adding => "Adding",
+            IpRouteStatusMsg::Added => "Added",
+            IpRouteStatusMsg::Removing => "Removing",
+            IpRouteStatusMsg::Removed => "Removed",
+            IpRouteStatusMsg::AddFailed => "AddFailed",
+            IpRouteStatusMsg::RemoveFailed => "RemoveFailed",
+            IpRouteStatusMsg::Unknown(ref value) => value,
+        }
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct ListCertificatesRequest {
-    /// <p>The identifier of the directory.</p>
-    #[serde(rename = "DirectoryId")]
-    pub directory_id: String,
-    /// <p>The number of items that should show up on one page</p>
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for IpRouteStatusMsg {
+    fn default() -> IpRouteStatusMsg {
+        IpRouteStatusMsg::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for IpRouteStatusMsg {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for IpRouteStatusMsg {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for IpRouteStatusMsg {
+    fn from(value: &'a str) -> IpRouteStatusMsg {
+        match value {
+            "Adding" => IpRouteStatusMsg::Adding,
+            "Added" => IpRouteStatusMsg::Added,
+            "Removing" => IpRouteStatusMsg::Removing,
+            "Removed" => IpRouteStatusMsg::Removed,
+            "AddFailed" => IpRouteStatusMsg::AddFailed,
+            "RemoveFailed" => IpRouteStatusMsg::RemoveFailed,
+            _ => IpRouteStatusMsg::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for IpRouteStatusMsg {
+    fn from(value: String) -> IpRouteStatusMsg {
+        match value.as_str() {
+            "Adding" => IpRouteStatusMsg::Adding,
+            "Added" => IpRouteStatusMsg::Added,
+            "Removing" => IpRouteStatusMsg::Removing,
+            "Removed" => IpRouteStatusMsg::Removed,
+            "AddFailed" => IpRouteStatusMsg::AddFailed,
+            "RemoveFailed" => IpRouteStatusMsg::RemoveFailed,
+            _ => IpRouteStatusMsg::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for IpRouteStatusMsg {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<IpRouteStatusMsg, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for IpRouteStatusMsg {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for IpRouteStatusMsg {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for IpRouteStatusMsg {
+    fn deserialize<D>(deserializer: D) -> Result<IpRouteStatusMsg, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Contains general information about the LDAPS settings.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct LDAPSSettingInfo {
+    /// <p>The state of the LDAPS settings.</p>
+    #[serde(rename = "LDAPSStatus")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ldaps_status: Option<LDAPSStatus>,
+    /// <p>Describes a state change for LDAPS.</p>
+    #[serde(rename = "LDAPSStatusReason")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ldaps_status_reason: Option<String>,
+    /// <p>The date and time when the LDAPS settings were last updated.</p>
+    #[serde(rename = "LastUpdatedDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_updated_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LDAPSStatus {
+    /// `Enabling`
+    Enabling,
+    /// `Enabled`
+    Enabled,
+    /// `EnableFailed`
+    EnableFailed,
+    /// `Disabled`
+    Disabled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LDAPSStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LDAPSStatus::Enabling => "Enabling",
+            LDAPSStatus::Enabled => "Enabled",
+            LDAPSStatus::EnableFailed => "EnableFailed",
+            LDAPSStatus::Disabled => "Disabled",
+            LDAPSStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LDAPSStatus {
+    fn default() -> LDAPSStatus {
+        LDAPSStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LDAPSStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LDAPSStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LDAPSStatus {
+    fn from(value: &'a str) -> LDAPSStatus {
+        match value {
+            "Enabling" => LDAPSStatus::Enabling,
+            "Enabled" => LDAPSStatus::Enabled,
+            "EnableFailed" => LDAPSStatus::EnableFailed,
+            "Disabled" => LDAPSStatus::Disabled,
+            _ => LDAPSStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LDAPSStatus {
+    fn from(value: String) -> LDAPSStatus {
+        match value.as_str() {
+            "Enabling" => LDAPSStatus::Enabling,
+            "Enabled" => LDAPSStatus::Enabled,
+            "EnableFailed" => LDAPSStatus::EnableFailed,
+            "Disabled" => LDAPSStatus::Disabled,
+            _ => LDAPSStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LDAPSStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LDAPSStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LDAPSStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LDAPSStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LDAPSStatus {
+    fn deserialize<D>(deserializer: D) -> Result<LDAPSStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LDAPSType {
+    /// `Client`
+    Client,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LDAPSType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LDAPSType::Client => "Client",
+            LDAPSType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LDAPSType {
+    fn default() -> LDAPSType {
+        LDAPSType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LDAPSType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LDAPSType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LDAPSType {
+    fn from(value: &'a str) -> LDAPSType {
+        match value {
+            "Client" => LDAPSType::Client,
+            _ => LDAPSType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LDAPSType {
+    fn from(value: String) -> LDAPSType {
+        match value.as_str() {
+            "Client" => LDAPSType::Client,
+            _ => LDAPSType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LDAPSType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LDAPSType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LDAPSType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LDAPSType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LDAPSType {
+    fn deserialize<D>(deserializer: D) -> Result<LDAPSType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct ListCertificatesRequest {
+    /// <p>The identifier of the directory.</p>
+    #[serde(rename = "DirectoryId")]
+    pub directory_id: String,
+    /// <p>The number of items that should show up on one page</p>
     #[serde(rename = "Limit")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<i64>,
@@ -1517,8 +2524,13 @@ pub struct LogSubscription {
     pub log_group_name: Option<String>,
     /// <p>The date and time that the log subscription was created.</p>
     #[serde(rename = "SubscriptionCreatedDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub subscription_created_date_time: Option<f64>,
+    pub subscription_created_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>Describes the directory owner account details that have been shared to the directory consumer account.</p>
@@ -1544,20 +2556,123 @@ pub struct OwnerDirectoryDescription {
     /// <p>Information about the status of the RADIUS server.</p>
     #[serde(rename = "RadiusStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub radius_status: Option<String>,
+    pub radius_status: Option<RadiusStatus>,
     /// <p>Information about the VPC settings for the directory.</p>
     #[serde(rename = "VpcSettings")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub vpc_settings: Option<DirectoryVpcSettingsDescription>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RadiusAuthenticationProtocol {
+    /// `PAP`
+    Pap,
+    /// `CHAP`
+    Chap,
+    /// `MS-CHAPv1`
+    MsCHAPv1,
+    /// `MS-CHAPv2`
+    MsCHAPv2,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RadiusAuthenticationProtocol {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RadiusAuthenticationProtocol::Pap => "PAP",
+            RadiusAuthenticationProtocol::Chap => "CHAP",
+            RadiusAuthenticationProtocol::MsCHAPv1 => "MS-CHAPv1",
+            RadiusAuthenticationProtocol::MsCHAPv2 => "MS-CHAPv2",
+            RadiusAuthenticationProtocol::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RadiusAuthenticationProtocol {
+    fn default() -> RadiusAuthenticationProtocol {
+        RadiusAuthenticationProtocol::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RadiusAuthenticationProtocol {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RadiusAuthenticationProtocol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RadiusAuthenticationProtocol {
+    fn from(value: &'a str) -> RadiusAuthenticationProtocol {
+        match value {
+            "PAP" => RadiusAuthenticationProtocol::Pap,
+            "CHAP" => RadiusAuthenticationProtocol::Chap,
+            "MS-CHAPv1" => RadiusAuthenticationProtocol::MsCHAPv1,
+            "MS-CHAPv2" => RadiusAuthenticationProtocol::MsCHAPv2,
+            _ => RadiusAuthenticationProtocol::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RadiusAuthenticationProtocol {
+    fn from(value: String) -> RadiusAuthenticationProtocol {
+        match value.as_str() {
+            "PAP" => RadiusAuthenticationProtocol::Pap,
+            "CHAP" => RadiusAuthenticationProtocol::Chap,
+            "MS-CHAPv1" => RadiusAuthenticationProtocol::MsCHAPv1,
+            "MS-CHAPv2" => RadiusAuthenticationProtocol::MsCHAPv2,
+            _ => RadiusAuthenticationProtocol::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RadiusAuthenticationProtocol {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RadiusAuthenticationProtocol, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RadiusAuthenticationProtocol {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RadiusAuthenticationProtocol {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RadiusAuthenticationProtocol {
+    fn deserialize<D>(deserializer: D) -> Result<RadiusAuthenticationProtocol, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Contains information about a Remote Authentication Dial In User Service (RADIUS) server.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RadiusSettings {
     /// <p>The protocol specified for your RADIUS endpoints.</p>
     #[serde(rename = "AuthenticationProtocol")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub authentication_protocol: Option<String>,
+    pub authentication_protocol: Option<RadiusAuthenticationProtocol>,
     /// <p>Not currently used.</p>
     #[serde(rename = "DisplayLabel")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1588,6 +2703,104 @@ pub struct RadiusSettings {
     pub use_same_username: Option<bool>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RadiusStatus {
+    /// `Creating`
+    Creating,
+    /// `Completed`
+    Completed,
+    /// `Failed`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RadiusStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RadiusStatus::Creating => "Creating",
+            RadiusStatus::Completed => "Completed",
+            RadiusStatus::Failed => "Failed",
+            RadiusStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RadiusStatus {
+    fn default() -> RadiusStatus {
+        RadiusStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RadiusStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RadiusStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RadiusStatus {
+    fn from(value: &'a str) -> RadiusStatus {
+        match value {
+            "Creating" => RadiusStatus::Creating,
+            "Completed" => RadiusStatus::Completed,
+            "Failed" => RadiusStatus::Failed,
+            _ => RadiusStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RadiusStatus {
+    fn from(value: String) -> RadiusStatus {
+        match value.as_str() {
+            "Creating" => RadiusStatus::Creating,
+            "Completed" => RadiusStatus::Completed,
+            "Failed" => RadiusStatus::Failed,
+            _ => RadiusStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RadiusStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RadiusStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RadiusStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RadiusStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RadiusStatus {
+    fn deserialize<D>(deserializer: D) -> Result<RadiusStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct RegisterCertificateRequest {
@@ -1672,6 +2885,94 @@ pub struct RemoveTagsFromResourceRequest {
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RemoveTagsFromResourceResult {}
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ReplicationScope {
+    /// `Domain`
+    Domain,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ReplicationScope {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReplicationScope::Domain => "Domain",
+            ReplicationScope::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ReplicationScope {
+    fn default() -> ReplicationScope {
+        ReplicationScope::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ReplicationScope {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ReplicationScope {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ReplicationScope {
+    fn from(value: &'a str) -> ReplicationScope {
+        match value {
+            "Domain" => ReplicationScope::Domain,
+            _ => ReplicationScope::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReplicationScope {
+    fn from(value: String) -> ReplicationScope {
+        match value.as_str() {
+            "Domain" => ReplicationScope::Domain,
+            _ => ReplicationScope::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ReplicationScope {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ReplicationScope, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ReplicationScope {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ReplicationScope {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ReplicationScope {
+    fn deserialize<D>(deserializer: D) -> Result<ReplicationScope, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ResetUserPasswordRequest {
@@ -1718,8 +3019,13 @@ pub struct SchemaExtensionInfo {
     pub directory_id: Option<String>,
     /// <p>The date and time that the schema extension was completed.</p>
     #[serde(rename = "EndDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_date_time: Option<f64>,
+    pub end_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The identifier of the schema extension.</p>
     #[serde(rename = "SchemaExtensionId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1727,17 +3033,243 @@ pub struct SchemaExtensionInfo {
     /// <p>The current status of the schema extension.</p>
     #[serde(rename = "SchemaExtensionStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub schema_extension_status: Option<String>,
+    pub schema_extension_status: Option<SchemaExtensionStatus>,
     /// <p>The reason for the <code>SchemaExtensionStatus</code>.</p>
     #[serde(rename = "SchemaExtensionStatusReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub schema_extension_status_reason: Option<String>,
     /// <p>The date and time that the schema extension started being applied to the directory.</p>
     #[serde(rename = "StartDateTime")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_date_time: Option<f64>,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SchemaExtensionStatus {
+    /// `Initializing`
+    Initializing,
+    /// `CreatingSnapshot`
+    CreatingSnapshot,
+    /// `UpdatingSchema`
+    UpdatingSchema,
+    /// `Replicating`
+    Replicating,
+    /// `CancelInProgress`
+    CancelInProgress,
+    /// `RollbackInProgress`
+    RollbackInProgress,
+    /// `Cancelled`
+    Cancelled,
+    /// `Failed`
+    Failed,
+    /// `Completed`
+    Completed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SchemaExtensionStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SchemaExtensionStatus::Initializing => "Initializing",
+            SchemaExtensionStatus::CreatingSnapshot => "CreatingSnapshot",
+            SchemaExtensionStatus::UpdatingSchema => "UpdatingSchema",
+            SchemaExtensionStatus::Replicating => "Replicating",
+            SchemaExtensionStatus::CancelInProgress => "CancelInProgress",
+            SchemaExtensionStatus::RollbackInProgress => "RollbackInProgress",
+            SchemaExtensionStatus::Cancelled => "Cancelled",
+            SchemaExtensionStatus::Failed => "Failed",
+            SchemaExtensionStatus::Completed => "Completed",
+            SchemaExtensionStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SchemaExtensionStatus {
+    fn default() -> SchemaExtensionStatus {
+        SchemaExtensionStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SchemaExtensionStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SchemaExtensionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SchemaExtensionStatus {
+    fn from(value: &'a str) -> SchemaExtensionStatus {
+        match value {
+            "Initializing" => SchemaExtensionStatus::Initializing,
+            "CreatingSnapshot" => SchemaExtensionStatus::CreatingSnapshot,
+            "UpdatingSchema" => SchemaExtensionStatus::UpdatingSchema,
+            "Replicating" => SchemaExtensionStatus::Replicating,
+            "CancelInProgress" => SchemaExtensionStatus::CancelInProgress,
+            "RollbackInProgress" => SchemaExtensionStatus::RollbackInProgress,
+            "Cancelled" => SchemaExtensionStatus::Cancelled,
+            "Failed" => SchemaExtensionStatus::Failed,
+            "Completed" => SchemaExtensionStatus::Completed,
+            _ => SchemaExtensionStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SchemaExtensionStatus {
+    fn from(value: String) -> SchemaExtensionStatus {
+        match value.as_str() {
+            "Initializing" => SchemaExtensionStatus::Initializing,
+            "CreatingSnapshot" => SchemaExtensionStatus::CreatingSnapshot,
+            "UpdatingSchema" => SchemaExtensionStatus::UpdatingSchema,
+            "Replicating" => SchemaExtensionStatus::Replicating,
+            "CancelInProgress" => SchemaExtensionStatus::CancelInProgress,
+            "RollbackInProgress" => SchemaExtensionStatus::RollbackInProgress,
+            "Cancelled" => SchemaExtensionStatus::Cancelled,
+            "Failed" => SchemaExtensionStatus::Failed,
+            "Completed" => SchemaExtensionStatus::Completed,
+            _ => SchemaExtensionStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SchemaExtensionStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SchemaExtensionStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SchemaExtensionStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SchemaExtensionStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SchemaExtensionStatus {
+    fn deserialize<D>(deserializer: D) -> Result<SchemaExtensionStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SelectiveAuth {
+    /// `Enabled`
+    Enabled,
+    /// `Disabled`
+    Disabled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SelectiveAuth {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SelectiveAuth::Enabled => "Enabled",
+            SelectiveAuth::Disabled => "Disabled",
+            SelectiveAuth::Unknown(ref value) => value,
+        }
+    }
 }
 
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SelectiveAuth {
+    fn default() -> SelectiveAuth {
+        SelectiveAuth::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SelectiveAuth {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SelectiveAuth {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SelectiveAuth {
+    fn from(value: &'a str) -> SelectiveAuth {
+        match value {
+            "Enabled" => SelectiveAuth::Enabled,
+            "Disabled" => SelectiveAuth::Disabled,
+            _ => SelectiveAuth::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SelectiveAuth {
+    fn from(value: String) -> SelectiveAuth {
+        match value.as_str() {
+            "Enabled" => SelectiveAuth::Enabled,
+            "Disabled" => SelectiveAuth::Disabled,
+            _ => SelectiveAuth::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SelectiveAuth {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SelectiveAuth, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SelectiveAuth {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SelectiveAuth {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SelectiveAuth {
+    fn deserialize<D>(deserializer: D) -> Result<SelectiveAuth, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ShareDirectoryRequest {
@@ -1746,7 +3278,7 @@ pub struct ShareDirectoryRequest {
     pub directory_id: String,
     /// <p>The method used when sharing a directory to determine whether the directory should be shared within your AWS organization (<code>ORGANIZATIONS</code>) or with any AWS account by sending a directory sharing request (<code>HANDSHAKE</code>).</p>
     #[serde(rename = "ShareMethod")]
-    pub share_method: String,
+    pub share_method: ShareMethod,
     /// <p>A directory share request that is sent by the directory owner to the directory consumer. The request includes a typed message to help the directory consumer administrator determine whether to approve or reject the share invitation.</p>
     #[serde(rename = "ShareNotes")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1765,6 +3297,227 @@ pub struct ShareDirectoryResult {
     pub shared_directory_id: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ShareMethod {
+    /// `ORGANIZATIONS`
+    Organizations,
+    /// `HANDSHAKE`
+    Handshake,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ShareMethod {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ShareMethod::Organizations => "ORGANIZATIONS",
+            ShareMethod::Handshake => "HANDSHAKE",
+            ShareMethod::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ShareMethod {
+    fn default() -> ShareMethod {
+        ShareMethod::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ShareMethod {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ShareMethod {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ShareMethod {
+    fn from(value: &'a str) -> ShareMethod {
+        match value {
+            "ORGANIZATIONS" => ShareMethod::Organizations,
+            "HANDSHAKE" => ShareMethod::Handshake,
+            _ => ShareMethod::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ShareMethod {
+    fn from(value: String) -> ShareMethod {
+        match value.as_str() {
+            "ORGANIZATIONS" => ShareMethod::Organizations,
+            "HANDSHAKE" => ShareMethod::Handshake,
+            _ => ShareMethod::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ShareMethod {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ShareMethod, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ShareMethod {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ShareMethod {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ShareMethod {
+    fn deserialize<D>(deserializer: D) -> Result<ShareMethod, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ShareStatus {
+    /// `Shared`
+    Shared,
+    /// `PendingAcceptance`
+    PendingAcceptance,
+    /// `Rejected`
+    Rejected,
+    /// `Rejecting`
+    Rejecting,
+    /// `RejectFailed`
+    RejectFailed,
+    /// `Sharing`
+    Sharing,
+    /// `ShareFailed`
+    ShareFailed,
+    /// `Deleted`
+    Deleted,
+    /// `Deleting`
+    Deleting,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ShareStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ShareStatus::Shared => "Shared",
+            ShareStatus::PendingAcceptance => "PendingAcceptance",
+            ShareStatus::Rejected => "Rejected",
+            ShareStatus::Rejecting => "Rejecting",
+            ShareStatus::RejectFailed => "RejectFailed",
+            ShareStatus::Sharing => "Sharing",
+            ShareStatus::ShareFailed => "ShareFailed",
+            ShareStatus::Deleted => "Deleted",
+            ShareStatus::Deleting => "Deleting",
+            ShareStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ShareStatus {
+    fn default() -> ShareStatus {
+        ShareStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ShareStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ShareStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ShareStatus {
+    fn from(value: &'a str) -> ShareStatus {
+        match value {
+            "Shared" => ShareStatus::Shared,
+            "PendingAcceptance" => ShareStatus::PendingAcceptance,
+            "Rejected" => ShareStatus::Rejected,
+            "Rejecting" => ShareStatus::Rejecting,
+            "RejectFailed" => ShareStatus::RejectFailed,
+            "Sharing" => ShareStatus::Sharing,
+            "ShareFailed" => ShareStatus::ShareFailed,
+            "Deleted" => ShareStatus::Deleted,
+            "Deleting" => ShareStatus::Deleting,
+            _ => ShareStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ShareStatus {
+    fn from(value: String) -> ShareStatus {
+        match value.as_str() {
+            "Shared" => ShareStatus::Shared,
+            "PendingAcceptance" => ShareStatus::PendingAcceptance,
+            "Rejected" => ShareStatus::Rejected,
+            "Rejecting" => ShareStatus::Rejecting,
+            "RejectFailed" => ShareStatus::RejectFailed,
+            "Sharing" => ShareStatus::Sharing,
+            "ShareFailed" => ShareStatus::ShareFailed,
+            "Deleted" => ShareStatus::Deleted,
+            "Deleting" => ShareStatus::Deleting,
+            _ => ShareStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ShareStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ShareStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ShareStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ShareStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ShareStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ShareStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Identifier that contains details about the directory consumer account.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -1774,7 +3527,7 @@ pub struct ShareTarget {
     pub id: String,
     /// <p>Type of identifier to be used in the <code>Id</code> field.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: TargetType,
 }
 
 /// <p>Details about the shared directory in the directory owner account for which the share request in the directory consumer account has been accepted.</p>
@@ -1783,12 +3536,22 @@ pub struct ShareTarget {
 pub struct SharedDirectory {
     /// <p>The date and time that the shared directory was created.</p>
     #[serde(rename = "CreatedDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date_time: Option<f64>,
+    pub created_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The date and time that the shared directory was last updated.</p>
     #[serde(rename = "LastUpdatedDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date_time: Option<f64>,
+    pub last_updated_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Identifier of the directory owner account, which contains the directory that has been shared to the consumer account.</p>
     #[serde(rename = "OwnerAccountId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1800,7 +3563,7 @@ pub struct SharedDirectory {
     /// <p>The method used when sharing a directory to determine whether the directory should be shared within your AWS organization (<code>ORGANIZATIONS</code>) or with any AWS account by sending a shared directory request (<code>HANDSHAKE</code>).</p>
     #[serde(rename = "ShareMethod")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub share_method: Option<String>,
+    pub share_method: Option<ShareMethod>,
     /// <p>A directory share request that is sent by the directory owner to the directory consumer. The request includes a typed message to help the directory consumer administrator determine whether to approve or reject the share invitation.</p>
     #[serde(rename = "ShareNotes")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1808,7 +3571,7 @@ pub struct SharedDirectory {
     /// <p>Current directory status of the shared AWS Managed Microsoft AD directory.</p>
     #[serde(rename = "ShareStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub share_status: Option<String>,
+    pub share_status: Option<ShareStatus>,
     /// <p>Identifier of the directory consumer account that has access to the shared directory (<code>OwnerDirectoryId</code>) in the directory owner account.</p>
     #[serde(rename = "SharedAccountId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1837,89 +3600,486 @@ pub struct Snapshot {
     pub snapshot_id: Option<String>,
     /// <p>The date and time that the snapshot was taken.</p>
     #[serde(rename = "StartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The snapshot status.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<SnapshotStatus>,
     /// <p>The snapshot type.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<SnapshotType>,
+}
+
+/// <p>Contains manual snapshot limit information for a directory.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct SnapshotLimits {
+    /// <p>The current number of manual snapshots of the directory.</p>
+    #[serde(rename = "ManualSnapshotsCurrentCount")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub manual_snapshots_current_count: Option<i64>,
+    /// <p>The maximum number of manual snapshots allowed.</p>
+    #[serde(rename = "ManualSnapshotsLimit")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub manual_snapshots_limit: Option<i64>,
+    /// <p>Indicates if the manual snapshot limit has been reached.</p>
+    #[serde(rename = "ManualSnapshotsLimitReached")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub manual_snapshots_limit_reached: Option<bool>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SnapshotStatus {
+    /// `Creating`
+    Creating,
+    /// `Completed`
+    Completed,
+    /// `Failed`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SnapshotStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SnapshotStatus::Creating => "Creating",
+            SnapshotStatus::Completed => "Completed",
+            SnapshotStatus::Failed => "Failed",
+            SnapshotStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SnapshotStatus {
+    fn default() -> SnapshotStatus {
+        SnapshotStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SnapshotStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SnapshotStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SnapshotStatus {
+    fn from(value: &'a str) -> SnapshotStatus {
+        match value {
+            "Creating" => SnapshotStatus::Creating,
+            "Completed" => SnapshotStatus::Completed,
+            "Failed" => SnapshotStatus::Failed,
+            _ => SnapshotStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SnapshotStatus {
+    fn from(value: String) -> SnapshotStatus {
+        match value.as_str() {
+            "Creating" => SnapshotStatus::Creating,
+            "Completed" => SnapshotStatus::Completed,
+            "Failed" => SnapshotStatus::Failed,
+            _ => SnapshotStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SnapshotStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SnapshotStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SnapshotStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SnapshotStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SnapshotStatus {
+    fn deserialize<D>(deserializer: D) -> Result<SnapshotStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SnapshotType {
+    /// `Auto`
+    Auto,
+    /// `Manual`
+    Manual,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SnapshotType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SnapshotType::Auto => "Auto",
+            SnapshotType::Manual => "Manual",
+            SnapshotType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SnapshotType {
+    fn default() -> SnapshotType {
+        SnapshotType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SnapshotType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SnapshotType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SnapshotType {
+    fn from(value: &'a str) -> SnapshotType {
+        match value {
+            "Auto" => SnapshotType::Auto,
+            "Manual" => SnapshotType::Manual,
+            _ => SnapshotType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SnapshotType {
+    fn from(value: String) -> SnapshotType {
+        match value.as_str() {
+            "Auto" => SnapshotType::Auto,
+            "Manual" => SnapshotType::Manual,
+            _ => SnapshotType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SnapshotType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SnapshotType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SnapshotType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SnapshotType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SnapshotType {
+    fn deserialize<D>(deserializer: D) -> Result<SnapshotType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct StartSchemaExtensionRequest {
+    /// <p>If true, creates a snapshot of the directory before applying the schema extension.</p>
+    #[serde(rename = "CreateSnapshotBeforeSchemaExtension")]
+    pub create_snapshot_before_schema_extension: bool,
+    /// <p>A description of the schema extension.</p>
+    #[serde(rename = "Description")]
+    pub description: String,
+    /// <p>The identifier of the directory for which the schema extension will be applied to.</p>
+    #[serde(rename = "DirectoryId")]
+    pub directory_id: String,
+    /// <p>The LDIF file represented as a string. To construct the LdifContent string, precede each line as it would be formatted in an ldif file with \n. See the example request below for more details. The file size can be no larger than 1MB.</p>
+    #[serde(rename = "LdifContent")]
+    pub ldif_content: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct StartSchemaExtensionResult {
+    /// <p>The identifier of the schema extension that will be applied.</p>
+    #[serde(rename = "SchemaExtensionId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema_extension_id: Option<String>,
+}
+
+/// <p>Metadata assigned to a directory consisting of a key-value pair.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Tag {
+    /// <p>Required name of the tag. The string value can be Unicode characters and cannot be prefixed with "aws:". The string can contain only the set of Unicode letters, digits, white-space, '_', '.', '/', '=', '+', '-' (Java regex: "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-]*)$").</p>
+    #[serde(rename = "Key")]
+    pub key: String,
+    /// <p>The optional value of the tag. The string value can be Unicode characters. The string can contain only the set of Unicode letters, digits, white-space, '_', '.', '/', '=', '+', '-' (Java regex: "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-]*)$").</p>
+    #[serde(rename = "Value")]
+    pub value: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TargetType {
+    /// `ACCOUNT`
+    Account,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TargetType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TargetType::Account => "ACCOUNT",
+            TargetType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TargetType {
+    fn default() -> TargetType {
+        TargetType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TargetType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TargetType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TargetType {
+    fn from(value: &'a str) -> TargetType {
+        match value {
+            "ACCOUNT" => TargetType::Account,
+            _ => TargetType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TargetType {
+    fn from(value: String) -> TargetType {
+        match value.as_str() {
+            "ACCOUNT" => TargetType::Account,
+            _ => TargetType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TargetType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TargetType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TargetType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TargetType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TargetType {
+    fn deserialize<D>(deserializer: D) -> Result<TargetType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TopicStatus {
+    /// `Registered`
+    Registered,
+    /// `Topic not found`
+    TopicNotFound,
+    /// `Failed`
+    Failed,
+    /// `Deleted`
+    Deleted,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TopicStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TopicStatus::Registered => "Registered",
+            TopicStatus::TopicNotFound => "Topic not found",
+            TopicStatus::Failed => "Failed",
+            TopicStatus::Deleted => "Deleted",
+            TopicStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TopicStatus {
+    fn default() -> TopicStatus {
+        TopicStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TopicStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TopicStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TopicStatus {
+    fn from(value: &'a str) -> TopicStatus {
+        match value {
+            "Registered" => TopicStatus::Registered,
+            "Topic not found" => TopicStatus::TopicNotFound,
+            "Failed" => TopicStatus::Failed,
+            "Deleted" => TopicStatus::Deleted,
+            _ => TopicStatus::Unknown(value.to_owned()),
+        }
+    }
 }
 
-/// <p>Contains manual snapshot limit information for a directory.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct SnapshotLimits {
-    /// <p>The current number of manual snapshots of the directory.</p>
-    #[serde(rename = "ManualSnapshotsCurrentCount")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub manual_snapshots_current_count: Option<i64>,
-    /// <p>The maximum number of manual snapshots allowed.</p>
-    #[serde(rename = "ManualSnapshotsLimit")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub manual_snapshots_limit: Option<i64>,
-    /// <p>Indicates if the manual snapshot limit has been reached.</p>
-    #[serde(rename = "ManualSnapshotsLimitReached")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub manual_snapshots_limit_reached: Option<bool>,
+impl From<String> for TopicStatus {
+    fn from(value: String) -> TopicStatus {
+        match value.as_str() {
+            "Registered" => TopicStatus::Registered,
+            "Topic not found" => TopicStatus::TopicNotFound,
+            "Failed" => TopicStatus::Failed,
+            "Deleted" => TopicStatus::Deleted,
+            _ => TopicStatus::Unknown(value),
+        }
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct StartSchemaExtensionRequest {
-    /// <p>If true, creates a snapshot of the directory before applying the schema extension.</p>
-    #[serde(rename = "CreateSnapshotBeforeSchemaExtension")]
-    pub create_snapshot_before_schema_extension: bool,
-    /// <p>A description of the schema extension.</p>
-    #[serde(rename = "Description")]
-    pub description: String,
-    /// <p>The identifier of the directory for which the schema extension will be applied to.</p>
-    #[serde(rename = "DirectoryId")]
-    pub directory_id: String,
-    /// <p>The LDIF file represented as a string. To construct the LdifContent string, precede each line as it would be formatted in an ldif file with \n. See the example request below for more details. The file size can be no larger than 1MB.</p>
-    #[serde(rename = "LdifContent")]
-    pub ldif_content: String,
+impl ::std::str::FromStr for TopicStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TopicStatus, Self::Err> {
+        Ok(value.into())
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct StartSchemaExtensionResult {
-    /// <p>The identifier of the schema extension that will be applied.</p>
-    #[serde(rename = "SchemaExtensionId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub schema_extension_id: Option<String>,
+impl ::rusoto_core::param::ToParam for TopicStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
 }
 
-/// <p>Metadata assigned to a directory consisting of a key-value pair.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct Tag {
-    /// <p>Required name of the tag. The string value can be Unicode characters and cannot be prefixed with "aws:". The string can contain only the set of Unicode letters, digits, white-space, '_', '.', '/', '=', '+', '-' (Java regex: "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-]*)$").</p>
-    #[serde(rename = "Key")]
-    pub key: String,
-    /// <p>The optional value of the tag. The string value can be Unicode characters. The string can contain only the set of Unicode letters, digits, white-space, '_', '.', '/', '=', '+', '-' (Java regex: "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-]*)$").</p>
-    #[serde(rename = "Value")]
-    pub value: String,
+impl ::serde::Serialize for TopicStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for TopicStatus {
+    fn deserialize<D>(deserializer: D) -> Result<TopicStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes a trust relationship between an AWS Managed Microsoft AD directory and an external domain.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Trust {
     /// <p>The date and time that the trust relationship was created.</p>
     #[serde(rename = "CreatedDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date_time: Option<f64>,
+    pub created_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The Directory ID of the AWS directory involved in the trust relationship.</p>
     #[serde(rename = "DirectoryId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub directory_id: Option<String>,
     /// <p>The date and time that the trust relationship was last updated.</p>
     #[serde(rename = "LastUpdatedDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date_time: Option<f64>,
+    pub last_updated_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The Fully Qualified Domain Name (FQDN) of the external domain involved in the trust relationship.</p>
     #[serde(rename = "RemoteDomainName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1927,15 +4087,20 @@ pub struct Trust {
     /// <p>Current state of selective authentication for the trust.</p>
     #[serde(rename = "SelectiveAuth")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub selective_auth: Option<String>,
+    pub selective_auth: Option<SelectiveAuth>,
     /// <p>The date and time that the TrustState was last updated.</p>
     #[serde(rename = "StateLastUpdatedDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state_last_updated_date_time: Option<f64>,
+    pub state_last_updated_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The trust relationship direction.</p>
     #[serde(rename = "TrustDirection")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub trust_direction: Option<String>,
+    pub trust_direction: Option<TrustDirection>,
     /// <p>The unique ID of the trust relationship.</p>
     #[serde(rename = "TrustId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1943,7 +4108,7 @@ pub struct Trust {
     /// <p>The trust relationship state.</p>
     #[serde(rename = "TrustState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub trust_state: Option<String>,
+    pub trust_state: Option<TrustState>,
     /// <p>The reason for the TrustState.</p>
     #[serde(rename = "TrustStateReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1951,9 +4116,338 @@ pub struct Trust {
     /// <p>The trust relationship type. <code>Forest</code> is the default.</p>
     #[serde(rename = "TrustType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub trust_type: Option<String>,
+    pub trust_type: Option<TrustType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TrustDirection {
+    /// `One-Way: Outgoing`
+    OneWayOutgoing,
+    /// `One-Way: Incoming`
+    OneWayIncoming,
+    /// `Two-Way`
+    TwoWay,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TrustDirection {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TrustDirection::OneWayOutgoing => "One-Way: Outgoing",
+            TrustDirection::OneWayIncoming => "One-Way: Incoming",
+            TrustDirection::TwoWay => "Two-Way",
+            TrustDirection::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TrustDirection {
+    fn default() -> TrustDirection {
+        TrustDirection::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TrustDirection {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TrustDirection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TrustDirection {
+    fn from(value: &'a str) -> TrustDirection {
+        match value {
+            "One-Way: Outgoing" => TrustDirection::OneWayOutgoing,
+            "One-Way: Incoming" => TrustDirection::OneWayIncoming,
+            "Two-Way" => TrustDirection::TwoWay,
+            _ => TrustDirection::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TrustDirection {
+    fn from(value: String) -> TrustDirection {
+        match value.as_str() {
+            "One-Way: Outgoing" => TrustDirection::OneWayOutgoing,
+            "One-Way: Incoming" => TrustDirection::OneWayIncoming,
+            "Two-Way" => TrustDirection::TwoWay,
+            _ => TrustDirection::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TrustDirection {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TrustDirection, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TrustDirection {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TrustDirection {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TrustDirection {
+    fn deserialize<D>(deserializer: D) -> Result<TrustDirection, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TrustState {
+    /// `Creating`
+    Creating,
+    /// `Created`
+    Created,
+    /// `Verifying`
+    Verifying,
+    /// `VerifyFailed`
+    VerifyFailed,
+    /// `Verified`
+    Verified,
+    /// `Updating`
+    Updating,
+    /// `UpdateFailed`
+    UpdateFailed,
+    /// `Updated`
+    Updated,
+    /// `Deleting`
+    Deleting,
+    /// `Deleted`
+    Deleted,
+    /// `Failed`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TrustState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TrustState::Creating => "Creating",
+            TrustState::Created => "Created",
+            TrustState::Verifying => "Verifying",
+            TrustState::VerifyFailed => "VerifyFailed",
+            TrustState::Verified => "Verified",
+            TrustState::Updating => "Updating",
+            TrustState::UpdateFailed => "UpdateFailed",
+            TrustState::Updated => "Updated",
+            TrustState::Deleting => "Deleting",
+            TrustState::Deleted => "Deleted",
+            TrustState::Failed => "Failed",
+            TrustState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TrustState {
+    fn default() -> TrustState {
+        TrustState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TrustState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TrustState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TrustState {
+    fn from(value: &'a str) -> TrustState {
+        match value {
+            "Creating" => TrustState::Creating,
+            "Created" => TrustState::Created,
+            "Verifying" => TrustState::Verifying,
+            "VerifyFailed" => TrustState::VerifyFailed,
+            "Verified" => TrustState::Verified,
+            "Updating" => TrustState::Updating,
+            "UpdateFailed" => TrustState::UpdateFailed,
+            "Updated" => TrustState::Updated,
+            "Deleting" => TrustState::Deleting,
+            "Deleted" => TrustState::Deleted,
+            "Failed" => TrustState::Failed,
+            _ => TrustState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TrustState {
+    fn from(value: String) -> TrustState {
+        match value.as_str() {
+            "Creating" => TrustState::Creating,
+            "Created" => TrustState::Created,
+            "Verifying" => TrustState::Verifying,
+            "VerifyFailed" => TrustState::VerifyFailed,
+            "Verified" => TrustState::Verified,
+            "Updating" => TrustState::Updating,
+            "UpdateFailed" => TrustState::UpdateFailed,
+            "Updated" => TrustState::Updated,
+            "Deleting" => TrustState::Deleting,
+            "Deleted" => TrustState::Deleted,
+            "Failed" => TrustState::Failed,
+            _ => TrustState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TrustState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TrustState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TrustState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
 }
 
+impl ::serde::Serialize for TrustState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TrustState {
+    fn deserialize<D>(deserializer: D) -> Result<TrustState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TrustType {
+    /// `Forest`
+    Forest,
+    /// `External`
+    External,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TrustType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TrustType::Forest => "Forest",
+            TrustType::External => "External",
+            TrustType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TrustType {
+    fn default() -> TrustType {
+        TrustType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TrustType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TrustType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TrustType {
+    fn from(value: &'a str) -> TrustType {
+        match value {
+            "Forest" => TrustType::Forest,
+            "External" => TrustType::External,
+            _ => TrustType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TrustType {
+    fn from(value: String) -> TrustType {
+        match value.as_str() {
+            "Forest" => TrustType::Forest,
+            "External" => TrustType::External,
+            _ => TrustType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TrustType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TrustType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TrustType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TrustType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TrustType {
+    fn deserialize<D>(deserializer: D) -> Result<TrustType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct UnshareDirectoryRequest {
@@ -1983,7 +4477,7 @@ pub struct UnshareTarget {
     pub id: String,
     /// <p>Type of identifier to be used in the <i>Id</i> field.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: TargetType,
 }
 
 /// <p>Updates a conditional forwarder.</p>
@@ -2044,7 +4538,7 @@ pub struct UpdateTrustRequest {
     /// <p>Updates selective authentication for the trust.</p>
     #[serde(rename = "SelectiveAuth")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub selective_auth: Option<String>,
+    pub selective_auth: Option<SelectiveAuth>,
     /// <p>Identifier of the trust relationship.</p>
     #[serde(rename = "TrustId")]
     pub trust_id: String,
@@ -5884,11 +8378,12 @@ pub struct DirectoryServiceClient {
 impl DirectoryServiceClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> DirectoryServiceClient {
         DirectoryServiceClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Directory Service"),
         }
     }
 
@@ -5920,6 +8415,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: AcceptSharedDirectoryRequest,
     ) -> Result<AcceptSharedDirectoryResult, RusotoError<AcceptSharedDirectoryError>> {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("AcceptSharedDirectory");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5951,6 +8447,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: AddIpRoutesRequest,
     ) -> Result<AddIpRoutesResult, RusotoError<AddIpRoutesError>> {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("AddIpRoutes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DirectoryService_20150416.AddIpRoutes");
@@ -5978,6 +8475,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: AddTagsToResourceRequest,
     ) -> Result<AddTagsToResourceResult, RusotoError<AddTagsToResourceError>> {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("AddTagsToResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6008,6 +8506,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: CancelSchemaExtensionRequest,
     ) -> Result<CancelSchemaExtensionResult, RusotoError<CancelSchemaExtensionError>> {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("CancelSchemaExtension");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6039,6 +8538,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: ConnectDirectoryRequest,
     ) -> Result<ConnectDirectoryResult, RusotoError<ConnectDirectoryError>> {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("ConnectDirectory");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DirectoryService_20150416.ConnectDirectory");
@@ -6066,6 +8566,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: CreateAliasRequest,
     ) -> Result<CreateAliasResult, RusotoError<CreateAliasError>> {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("CreateAlias");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DirectoryService_20150416.CreateAlias");
@@ -6093,6 +8594,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: CreateComputerRequest,
     ) -> Result<CreateComputerResult, RusotoError<CreateComputerError>> {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("CreateComputer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DirectoryService_20150416.CreateComputer");
@@ -6121,6 +8623,7 @@ impl DirectoryService for DirectoryServiceClient {
     ) -> Result<CreateConditionalForwarderResult, RusotoError<CreateConditionalForwarderError>>
     {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("CreateConditionalForwarder");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6152,6 +8655,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: CreateDirectoryRequest,
     ) -> Result<CreateDirectoryResult, RusotoError<CreateDirectoryError>> {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("CreateDirectory");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DirectoryService_20150416.CreateDirectory");
@@ -6179,6 +8683,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: CreateLogSubscriptionRequest,
     ) -> Result<CreateLogSubscriptionResult, RusotoError<CreateLogSubscriptionError>> {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("CreateLogSubscription");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6210,6 +8715,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: CreateMicrosoftADRequest,
     ) -> Result<CreateMicrosoftADResult, RusotoError<CreateMicrosoftADError>> {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("CreateMicrosoftAD");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6240,6 +8746,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: CreateSnapshotRequest,
     ) -> Result<CreateSnapshotResult, RusotoError<CreateSnapshotError>> {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("CreateSnapshot");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DirectoryService_20150416.CreateSnapshot");
@@ -6267,6 +8774,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: CreateTrustRequest,
     ) -> Result<CreateTrustResult, RusotoError<CreateTrustError>> {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("CreateTrust");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DirectoryService_20150416.CreateTrust");
@@ -6295,6 +8803,7 @@ impl DirectoryService for DirectoryServiceClient {
     ) -> Result<DeleteConditionalForwarderResult, RusotoError<DeleteConditionalForwarderError>>
     {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("DeleteConditionalForwarder");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6326,6 +8835,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: DeleteDirectoryRequest,
     ) -> Result<DeleteDirectoryResult, RusotoError<DeleteDirectoryError>> {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("DeleteDirectory");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DirectoryService_20150416.DeleteDirectory");
@@ -6353,6 +8863,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: DeleteLogSubscriptionRequest,
     ) -> Result<DeleteLogSubscriptionResult, RusotoError<DeleteLogSubscriptionError>> {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("DeleteLogSubscription");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6384,6 +8895,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: DeleteSnapshotRequest,
     ) -> Result<DeleteSnapshotResult, RusotoError<DeleteSnapshotError>> {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("DeleteSnapshot");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DirectoryService_20150416.DeleteSnapshot");
@@ -6411,6 +8923,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: DeleteTrustRequest,
     ) -> Result<DeleteTrustResult, RusotoError<DeleteTrustError>> {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("DeleteTrust");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DirectoryService_20150416.DeleteTrust");
@@ -6438,6 +8951,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: DeregisterCertificateRequest,
     ) -> Result<DeregisterCertificateResult, RusotoError<DeregisterCertificateError>> {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("DeregisterCertificate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6469,6 +8983,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: DeregisterEventTopicRequest,
     ) -> Result<DeregisterEventTopicResult, RusotoError<DeregisterEventTopicError>> {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("DeregisterEventTopic");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6500,6 +9015,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: DescribeCertificateRequest,
     ) -> Result<DescribeCertificateResult, RusotoError<DescribeCertificateError>> {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("DescribeCertificate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6532,6 +9048,7 @@ impl DirectoryService for DirectoryServiceClient {
     ) -> Result<DescribeConditionalForwardersResult, RusotoError<DescribeConditionalForwardersError>>
     {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("DescribeConditionalForwarders");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6563,6 +9080,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: DescribeDirectoriesRequest,
     ) -> Result<DescribeDirectoriesResult, RusotoError<DescribeDirectoriesError>> {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("DescribeDirectories");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6594,6 +9112,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: DescribeDomainControllersRequest,
     ) -> Result<DescribeDomainControllersResult, RusotoError<DescribeDomainControllersError>> {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("DescribeDomainControllers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6625,6 +9144,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: DescribeEventTopicsRequest,
     ) -> Result<DescribeEventTopicsResult, RusotoError<DescribeEventTopicsError>> {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("DescribeEventTopics");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6656,6 +9176,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: DescribeLDAPSSettingsRequest,
     ) -> Result<DescribeLDAPSSettingsResult, RusotoError<DescribeLDAPSSettingsError>> {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("DescribeLDAPSSettings");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6687,6 +9208,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: DescribeSharedDirectoriesRequest,
     ) -> Result<DescribeSharedDirectoriesResult, RusotoError<DescribeSharedDirectoriesError>> {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("DescribeSharedDirectories");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6718,6 +9240,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: DescribeSnapshotsRequest,
     ) -> Result<DescribeSnapshotsResult, RusotoError<DescribeSnapshotsError>> {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("DescribeSnapshots");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6748,6 +9271,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: DescribeTrustsRequest,
     ) -> Result<DescribeTrustsResult, RusotoError<DescribeTrustsError>> {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("DescribeTrusts");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DirectoryService_20150416.DescribeTrusts");
@@ -6775,6 +9299,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: DisableLDAPSRequest,
     ) -> Result<DisableLDAPSResult, RusotoError<DisableLDAPSError>> {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("DisableLDAPS");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DirectoryService_20150416.DisableLDAPS");
@@ -6802,6 +9327,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: DisableRadiusRequest,
     ) -> Result<DisableRadiusResult, RusotoError<DisableRadiusError>> {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("DisableRadius");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DirectoryService_20150416.DisableRadius");
@@ -6829,6 +9355,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: DisableSsoRequest,
     ) -> Result<DisableSsoResult, RusotoError<DisableSsoError>> {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("DisableSso");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DirectoryService_20150416.DisableSso");
@@ -6856,6 +9383,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: EnableLDAPSRequest,
     ) -> Result<EnableLDAPSResult, RusotoError<EnableLDAPSError>> {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("EnableLDAPS");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DirectoryService_20150416.EnableLDAPS");
@@ -6883,6 +9411,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: EnableRadiusRequest,
     ) -> Result<EnableRadiusResult, RusotoError<EnableRadiusError>> {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("EnableRadius");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DirectoryService_20150416.EnableRadius");
@@ -6910,6 +9439,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: EnableSsoRequest,
     ) -> Result<EnableSsoResult, RusotoError<EnableSsoError>> {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("EnableSso");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DirectoryService_20150416.EnableSso");
@@ -6936,6 +9466,7 @@ impl DirectoryService for DirectoryServiceClient {
         &self,
     ) -> Result<GetDirectoryLimitsResult, RusotoError<GetDirectoryLimitsError>> {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("GetDirectoryLimits");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6966,6 +9497,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: GetSnapshotLimitsRequest,
     ) -> Result<GetSnapshotLimitsResult, RusotoError<GetSnapshotLimitsError>> {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("GetSnapshotLimits");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6996,6 +9528,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: ListCertificatesRequest,
     ) -> Result<ListCertificatesResult, RusotoError<ListCertificatesError>> {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("ListCertificates");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DirectoryService_20150416.ListCertificates");
@@ -7023,6 +9556,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: ListIpRoutesRequest,
     ) -> Result<ListIpRoutesResult, RusotoError<ListIpRoutesError>> {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("ListIpRoutes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DirectoryService_20150416.ListIpRoutes");
@@ -7050,6 +9584,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: ListLogSubscriptionsRequest,
     ) -> Result<ListLogSubscriptionsResult, RusotoError<ListLogSubscriptionsError>> {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("ListLogSubscriptions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7081,6 +9616,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: ListSchemaExtensionsRequest,
     ) -> Result<ListSchemaExtensionsResult, RusotoError<ListSchemaExtensionsError>> {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("ListSchemaExtensions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7112,6 +9648,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResult, RusotoError<ListTagsForResourceError>> {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7143,6 +9680,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: RegisterCertificateRequest,
     ) -> Result<RegisterCertificateResult, RusotoError<RegisterCertificateError>> {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("RegisterCertificate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7174,6 +9712,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: RegisterEventTopicRequest,
     ) -> Result<RegisterEventTopicResult, RusotoError<RegisterEventTopicError>> {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("RegisterEventTopic");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7205,6 +9744,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: RejectSharedDirectoryRequest,
     ) -> Result<RejectSharedDirectoryResult, RusotoError<RejectSharedDirectoryError>> {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("RejectSharedDirectory");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7236,6 +9776,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: RemoveIpRoutesRequest,
     ) -> Result<RemoveIpRoutesResult, RusotoError<RemoveIpRoutesError>> {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("RemoveIpRoutes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DirectoryService_20150416.RemoveIpRoutes");
@@ -7263,6 +9804,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: RemoveTagsFromResourceRequest,
     ) -> Result<RemoveTagsFromResourceResult, RusotoError<RemoveTagsFromResourceError>> {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("RemoveTagsFromResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7294,6 +9836,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: ResetUserPasswordRequest,
     ) -> Result<ResetUserPasswordResult, RusotoError<ResetUserPasswordError>> {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("ResetUserPassword");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7324,6 +9867,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: RestoreFromSnapshotRequest,
     ) -> Result<RestoreFromSnapshotResult, RusotoError<RestoreFromSnapshotError>> {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("RestoreFromSnapshot");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7355,6 +9899,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: ShareDirectoryRequest,
     ) -> Result<ShareDirectoryResult, RusotoError<ShareDirectoryError>> {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("ShareDirectory");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DirectoryService_20150416.ShareDirectory");
@@ -7382,6 +9927,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: StartSchemaExtensionRequest,
     ) -> Result<StartSchemaExtensionResult, RusotoError<StartSchemaExtensionError>> {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("StartSchemaExtension");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7413,6 +9959,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: UnshareDirectoryRequest,
     ) -> Result<UnshareDirectoryResult, RusotoError<UnshareDirectoryError>> {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("UnshareDirectory");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DirectoryService_20150416.UnshareDirectory");
@@ -7441,6 +9988,7 @@ impl DirectoryService for DirectoryServiceClient {
     ) -> Result<UpdateConditionalForwarderResult, RusotoError<UpdateConditionalForwarderError>>
     {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("UpdateConditionalForwarder");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7475,6 +10023,7 @@ impl DirectoryService for DirectoryServiceClient {
         RusotoError<UpdateNumberOfDomainControllersError>,
     > {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("UpdateNumberOfDomainControllers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7508,6 +10057,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: UpdateRadiusRequest,
     ) -> Result<UpdateRadiusResult, RusotoError<UpdateRadiusError>> {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("UpdateRadius");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DirectoryService_20150416.UpdateRadius");
@@ -7535,6 +10085,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: UpdateTrustRequest,
     ) -> Result<UpdateTrustResult, RusotoError<UpdateTrustError>> {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("UpdateTrust");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DirectoryService_20150416.UpdateTrust");
@@ -7562,6 +10113,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: VerifyTrustRequest,
     ) -> Result<VerifyTrustResult, RusotoError<VerifyTrustError>> {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("VerifyTrust");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DirectoryService_20150416.VerifyTrust");
@@ -7583,3 +10135,361 @@ impl DirectoryService for DirectoryServiceClient {
         }
     }
 }
+impl DirectoryServiceClient {
+    /// Auto-paginating version of `describe_directories`, streaming every page of results.
+    pub fn describe_directories_pages(
+        &self,
+        input: DescribeDirectoriesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeDirectoriesResult, DescribeDirectoriesError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DirectoryServiceClient, input: DescribeDirectoriesRequest| async move {
+                client.describe_directories(input).await
+            },
+            |input: &DescribeDirectoriesRequest, output: &DescribeDirectoriesResult| {
+                Some(DescribeDirectoriesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_directories`, streaming the `DirectoryDescriptions` of every page of results.
+    pub fn describe_directories_items(
+        &self,
+        input: DescribeDirectoriesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DirectoryDescription, DescribeDirectoriesError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_directories_pages(input),
+            |output: DescribeDirectoriesResult| output.directory_descriptions.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_domain_controllers`, streaming every page of results.
+    pub fn describe_domain_controllers_pages(
+        &self,
+        input: DescribeDomainControllersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeDomainControllersResult,
+        DescribeDomainControllersError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DirectoryServiceClient, input: DescribeDomainControllersRequest| async move {
+                client.describe_domain_controllers(input).await
+            },
+            |input: &DescribeDomainControllersRequest, output: &DescribeDomainControllersResult| {
+                Some(DescribeDomainControllersRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_domain_controllers`, streaming the `DomainControllers` of every page of results.
+    pub fn describe_domain_controllers_items(
+        &self,
+        input: DescribeDomainControllersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DomainController, DescribeDomainControllersError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_domain_controllers_pages(input),
+            |output: DescribeDomainControllersResult| output.domain_controllers.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_ldaps_settings`, streaming every page of results.
+    pub fn describe_ldaps_settings_pages(
+        &self,
+        input: DescribeLDAPSSettingsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeLDAPSSettingsResult,
+        DescribeLDAPSSettingsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DirectoryServiceClient, input: DescribeLDAPSSettingsRequest| async move {
+                client.describe_ldaps_settings(input).await
+            },
+            |input: &DescribeLDAPSSettingsRequest, output: &DescribeLDAPSSettingsResult| {
+                Some(DescribeLDAPSSettingsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_ldaps_settings`, streaming the `LDAPSSettingsInfo` of every page of results.
+    pub fn describe_ldaps_settings_items(
+        &self,
+        input: DescribeLDAPSSettingsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<LDAPSSettingInfo, DescribeLDAPSSettingsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_ldaps_settings_pages(input),
+            |output: DescribeLDAPSSettingsResult| output.ldaps_settings_info.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_shared_directories`, streaming every page of results.
+    pub fn describe_shared_directories_pages(
+        &self,
+        input: DescribeSharedDirectoriesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeSharedDirectoriesResult,
+        DescribeSharedDirectoriesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DirectoryServiceClient, input: DescribeSharedDirectoriesRequest| async move {
+                client.describe_shared_directories(input).await
+            },
+            |input: &DescribeSharedDirectoriesRequest, output: &DescribeSharedDirectoriesResult| {
+                Some(DescribeSharedDirectoriesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_shared_directories`, streaming the `SharedDirectories` of every page of results.
+    pub fn describe_shared_directories_items(
+        &self,
+        input: DescribeSharedDirectoriesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<SharedDirectory, DescribeSharedDirectoriesError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_shared_directories_pages(input),
+            |output: DescribeSharedDirectoriesResult| output.shared_directories.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_snapshots`, streaming every page of results.
+    pub fn describe_snapshots_pages(
+        &self,
+        input: DescribeSnapshotsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeSnapshotsResult, DescribeSnapshotsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DirectoryServiceClient, input: DescribeSnapshotsRequest| async move {
+                client.describe_snapshots(input).await
+            },
+            |input: &DescribeSnapshotsRequest, output: &DescribeSnapshotsResult| {
+                Some(DescribeSnapshotsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_snapshots`, streaming the `Snapshots` of every page of results.
+    pub fn describe_snapshots_items(
+        &self,
+        input: DescribeSnapshotsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Snapshot, DescribeSnapshotsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_snapshots_pages(input),
+            |output: DescribeSnapshotsResult| output.snapshots.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_trusts`, streaming every page of results.
+    pub fn describe_trusts_pages(
+        &self,
+        input: DescribeTrustsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeTrustsResult, DescribeTrustsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DirectoryServiceClient, input: DescribeTrustsRequest| async move {
+                client.describe_trusts(input).await
+            },
+            |input: &DescribeTrustsRequest, output: &DescribeTrustsResult| {
+                Some(DescribeTrustsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_trusts`, streaming the `Trusts` of every page of results.
+    pub fn describe_trusts_items(
+        &self,
+        input: DescribeTrustsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Trust, DescribeTrustsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_trusts_pages(input),
+            |output: DescribeTrustsResult| output.trusts.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_certificates`, streaming every page of results.
+    pub fn list_certificates_pages(
+        &self,
+        input: ListCertificatesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListCertificatesResult, ListCertificatesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DirectoryServiceClient, input: ListCertificatesRequest| async move {
+                client.list_certificates(input).await
+            },
+            |input: &ListCertificatesRequest, output: &ListCertificatesResult| {
+                Some(ListCertificatesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_certificates`, streaming the `CertificatesInfo` of every page of results.
+    pub fn list_certificates_items(
+        &self,
+        input: ListCertificatesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<CertificateInfo, ListCertificatesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_certificates_pages(input),
+            |output: ListCertificatesResult| output.certificates_info.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_ip_routes`, streaming every page of results.
+    pub fn list_ip_routes_pages(
+        &self,
+        input: ListIpRoutesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListIpRoutesResult, ListIpRoutesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DirectoryServiceClient, input: ListIpRoutesRequest| async move {
+                client.list_ip_routes(input).await
+            },
+            |input: &ListIpRoutesRequest, output: &ListIpRoutesResult| {
+                Some(ListIpRoutesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_ip_routes`, streaming the `IpRoutesInfo` of every page of results.
+    pub fn list_ip_routes_items(
+        &self,
+        input: ListIpRoutesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<IpRouteInfo, ListIpRoutesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_ip_routes_pages(input),
+            |output: ListIpRoutesResult| output.ip_routes_info.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_log_subscriptions`, streaming every page of results.
+    pub fn list_log_subscriptions_pages(
+        &self,
+        input: ListLogSubscriptionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListLogSubscriptionsResult, ListLogSubscriptionsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DirectoryServiceClient, input: ListLogSubscriptionsRequest| async move {
+                client.list_log_subscriptions(input).await
+            },
+            |input: &ListLogSubscriptionsRequest, output: &ListLogSubscriptionsResult| {
+                Some(ListLogSubscriptionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_log_subscriptions`, streaming the `LogSubscriptions` of every page of results.
+    pub fn list_log_subscriptions_items(
+        &self,
+        input: ListLogSubscriptionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<LogSubscription, ListLogSubscriptionsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_log_subscriptions_pages(input),
+            |output: ListLogSubscriptionsResult| output.log_subscriptions.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_schema_extensions`, streaming every page of results.
+    pub fn list_schema_extensions_pages(
+        &self,
+        input: ListSchemaExtensionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListSchemaExtensionsResult, ListSchemaExtensionsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DirectoryServiceClient, input: ListSchemaExtensionsRequest| async move {
+                client.list_schema_extensions(input).await
+            },
+            |input: &ListSchemaExtensionsRequest, output: &ListSchemaExtensionsResult| {
+                Some(ListSchemaExtensionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_schema_extensions`, streaming the `SchemaExtensionsInfo` of every page of results.
+    pub fn list_schema_extensions_items(
+        &self,
+        input: ListSchemaExtensionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<SchemaExtensionInfo, ListSchemaExtensionsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_schema_extensions_pages(input),
+            |output: ListSchemaExtensionsResult| output.schema_extensions_info.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_tags_for_resource`, streaming every page of results.
+    pub fn list_tags_for_resource_pages(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListTagsForResourceResult, ListTagsForResourceError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DirectoryServiceClient, input: ListTagsForResourceRequest| async move {
+                client.list_tags_for_resource(input).await
+            },
+            |input: &ListTagsForResourceRequest, output: &ListTagsForResourceResult| {
+                Some(ListTagsForResourceRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_tags_for_resource`, streaming the `Tags` of every page of results.
+    pub fn list_tags_for_resource_items(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Tag, ListTagsForResourceError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_tags_for_resource_pages(input),
+            |output: ListTagsForResourceResult| output.tags.unwrap_or_default(),
+        )
+    }
+}
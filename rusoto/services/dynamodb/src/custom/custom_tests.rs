@@ -1,4 +1,4 @@
-use crate::generated::AttributeValue;
+use crate::generated::{AttributeValue, TableDescription, TableStatus};
 
 #[test]
 fn attribute_value_default_is_empty() {
@@ -46,3 +46,16 @@ fn attribute_value_with_binary_set() {
     let serialized = serde_json::to_string(&all_default).unwrap();
     assert_eq!(&serialized, r#"{"BS":["Zm9v","YmFy","YmF6"]}"#);
 }
+
+#[test]
+fn enums_round_trip_unknown_values() {
+    let description: TableDescription =
+        serde_json::from_str(r#"{"TableStatus":"HIBERNATING"}"#).unwrap();
+    assert_eq!(
+        description.table_status,
+        Some(TableStatus::Unknown("HIBERNATING".to_owned()))
+    );
+
+    let serialized = serde_json::to_string(&description).unwrap();
+    assert_eq!(&serialized, r#"{"TableStatus":"HIBERNATING"}"#);
+}
@@ -34,14 +34,117 @@ pub struct ArchivalSummary {
     pub archival_backup_arn: Option<String>,
     /// <p>The date and time when table archival was initiated by DynamoDB, in UNIX epoch time format.</p>
     #[serde(rename = "ArchivalDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub archival_date_time: Option<f64>,
+    pub archival_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>The reason DynamoDB archived the table. Currently, the only possible value is:</p> <ul> <li> <p> <code>INACCESSIBLE<em>ENCRYPTION</em>CREDENTIALS</code> - The table was archived due to the table&#39;s AWS KMS key being inaccessible for more than seven days. An On-Demand backup was created at the archival time.</p> </li> </ul></p>
     #[serde(rename = "ArchivalReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub archival_reason: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AttributeAction {
+    /// `ADD`
+    Add,
+    /// `PUT`
+    Put,
+    /// `DELETE`
+    Delete,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AttributeAction {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AttributeAction::Add => "ADD",
+            AttributeAction::Put => "PUT",
+            AttributeAction::Delete => "DELETE",
+            AttributeAction::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AttributeAction {
+    fn default() -> AttributeAction {
+        AttributeAction::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AttributeAction {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AttributeAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AttributeAction {
+    fn from(value: &'a str) -> AttributeAction {
+        match value {
+            "ADD" => AttributeAction::Add,
+            "PUT" => AttributeAction::Put,
+            "DELETE" => AttributeAction::Delete,
+            _ => AttributeAction::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AttributeAction {
+    fn from(value: String) -> AttributeAction {
+        match value.as_str() {
+            "ADD" => AttributeAction::Add,
+            "PUT" => AttributeAction::Put,
+            "DELETE" => AttributeAction::Delete,
+            _ => AttributeAction::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AttributeAction {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AttributeAction, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AttributeAction {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AttributeAction {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AttributeAction {
+    fn deserialize<D>(deserializer: D) -> Result<AttributeAction, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents an attribute for describing the key schema for the table and indexes.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AttributeDefinition {
@@ -50,7 +153,7 @@ pub struct AttributeDefinition {
     pub attribute_name: String,
     /// <p><p>The data type for the attribute, where:</p> <ul> <li> <p> <code>S</code> - the attribute is of type String</p> </li> <li> <p> <code>N</code> - the attribute is of type Number</p> </li> <li> <p> <code>B</code> - the attribute is of type Binary</p> </li> </ul></p>
     #[serde(rename = "AttributeType")]
-    pub attribute_type: String,
+    pub attribute_type: ScalarAttributeType,
 }
 
 /// <p>Represents the data for an attribute.</p> <p>Each attribute value is described as a name-value pair. The name is the data type, and the value is the data itself.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/HowItWorks.NamingRulesDataTypes.html#HowItWorks.DataTypes">Data Types</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
@@ -115,7 +218,7 @@ pub struct AttributeValueUpdate {
     /// <p><p>Specifies how to perform the update. Valid values are <code>PUT</code> (default), <code>DELETE</code>, and <code>ADD</code>. The behavior depends on whether the specified primary key already exists in the table.</p> <p> <b>If an item with the specified <i>Key</i> is found in the table:</b> </p> <ul> <li> <p> <code>PUT</code> - Adds the specified attribute to the item. If the attribute already exists, it is replaced by the new value. </p> </li> <li> <p> <code>DELETE</code> - If no value is specified, the attribute and its value are removed from the item. The data type of the specified value must match the existing value&#39;s data type.</p> <p>If a <i>set</i> of values is specified, then those values are subtracted from the old set. For example, if the attribute value was the set <code>[a,b,c]</code> and the <code>DELETE</code> action specified <code>[a,c]</code>, then the final attribute value would be <code>[b]</code>. Specifying an empty set is an error.</p> </li> <li> <p> <code>ADD</code> - If the attribute does not already exist, then the attribute and its values are added to the item. If the attribute does exist, then the behavior of <code>ADD</code> depends on the data type of the attribute:</p> <ul> <li> <p>If the existing attribute is a number, and if <code>Value</code> is also a number, then the <code>Value</code> is mathematically added to the existing attribute. If <code>Value</code> is a negative number, then it is subtracted from the existing attribute.</p> <note> <p> If you use <code>ADD</code> to increment or decrement a number value for an item that doesn&#39;t exist before the update, DynamoDB uses 0 as the initial value.</p> <p>In addition, if you use <code>ADD</code> to update an existing item, and intend to increment or decrement an attribute value which does not yet exist, DynamoDB uses <code>0</code> as the initial value. For example, suppose that the item you want to update does not yet have an attribute named <i>itemcount</i>, but you decide to <code>ADD</code> the number <code>3</code> to this attribute anyway, even though it currently does not exist. DynamoDB will create the <i>itemcount</i> attribute, set its initial value to <code>0</code>, and finally add <code>3</code> to it. The result will be a new <i>itemcount</i> attribute in the item, with a value of <code>3</code>.</p> </note> </li> <li> <p>If the existing data type is a set, and if the <code>Value</code> is also a set, then the <code>Value</code> is added to the existing set. (This is a <i>set</i> operation, not mathematical addition.) For example, if the attribute value was the set <code>[1,2]</code>, and the <code>ADD</code> action specified <code>[3]</code>, then the final attribute value would be <code>[1,2,3]</code>. An error occurs if an Add action is specified for a set attribute and the attribute type specified does not match the existing set type. </p> <p>Both sets must have the same primitive data type. For example, if the existing data type is a set of strings, the <code>Value</code> must also be a set of strings. The same holds true for number sets and binary sets.</p> </li> </ul> <p>This action is only valid for an existing attribute whose data type is number or is a set. Do not use <code>ADD</code> for any other data types.</p> </li> </ul> <p> <b>If no item with the specified <i>Key</i> is found:</b> </p> <ul> <li> <p> <code>PUT</code> - DynamoDB creates a new item with the specified primary key, and then adds the attribute. </p> </li> <li> <p> <code>DELETE</code> - Nothing happens; there is no attribute to delete.</p> </li> <li> <p> <code>ADD</code> - DynamoDB creates an item with the supplied primary key and number (or set of numbers) for the attribute value. The only data types allowed are number and number set; no other data types can be specified.</p> </li> </ul></p>
     #[serde(rename = "Action")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub action: Option<String>,
+    pub action: Option<AttributeAction>,
     /// <p>Represents the data for an attribute.</p> <p>Each attribute value is described as a name-value pair. The name is the data type, and the value is the data itself.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/HowItWorks.NamingRulesDataTypes.html#HowItWorks.DataTypes">Data Types</a> in the <i>Amazon DynamoDB Developer Guide</i>. </p>
     #[serde(rename = "Value")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -272,11 +375,21 @@ pub struct BackupDetails {
     pub backup_arn: String,
     /// <p>Time at which the backup was created. This is the request time of the backup. </p>
     #[serde(rename = "BackupCreationDateTime")]
-    pub backup_creation_date_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub backup_creation_date_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>Time at which the automatic on-demand backup created by DynamoDB will expire. This <code>SYSTEM</code> on-demand backup expires automatically 35 days after its creation.</p>
     #[serde(rename = "BackupExpiryDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub backup_expiry_date_time: Option<f64>,
+    pub backup_expiry_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Name of the requested backup.</p>
     #[serde(rename = "BackupName")]
     pub backup_name: String,
@@ -286,12 +399,110 @@ pub struct BackupDetails {
     pub backup_size_bytes: Option<i64>,
     /// <p>Backup can be in one of the following states: CREATING, ACTIVE, DELETED. </p>
     #[serde(rename = "BackupStatus")]
-    pub backup_status: String,
+    pub backup_status: BackupStatus,
     /// <p><p>BackupType:</p> <ul> <li> <p> <code>USER</code> - You create and manage these using the on-demand backup feature.</p> </li> <li> <p> <code>SYSTEM</code> - If you delete a table with point-in-time recovery enabled, a <code>SYSTEM</code> backup is automatically created and is retained for 35 days (at no additional cost). System backups allow you to restore the deleted table to the state it was in just before the point of deletion. </p> </li> <li> <p> <code>AWS_BACKUP</code> - On-demand backup created by you from AWS Backup service.</p> </li> </ul></p>
     #[serde(rename = "BackupType")]
-    pub backup_type: String,
+    pub backup_type: BackupType,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BackupStatus {
+    /// `CREATING`
+    Creating,
+    /// `DELETED`
+    Deleted,
+    /// `AVAILABLE`
+    Available,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl BackupStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BackupStatus::Creating => "CREATING",
+            BackupStatus::Deleted => "DELETED",
+            BackupStatus::Available => "AVAILABLE",
+            BackupStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for BackupStatus {
+    fn default() -> BackupStatus {
+        BackupStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for BackupStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for BackupStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for BackupStatus {
+    fn from(value: &'a str) -> BackupStatus {
+        match value {
+            "CREATING" => BackupStatus::Creating,
+            "DELETED" => BackupStatus::Deleted,
+            "AVAILABLE" => BackupStatus::Available,
+            _ => BackupStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BackupStatus {
+    fn from(value: String) -> BackupStatus {
+        match value.as_str() {
+            "CREATING" => BackupStatus::Creating,
+            "DELETED" => BackupStatus::Deleted,
+            "AVAILABLE" => BackupStatus::Available,
+            _ => BackupStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for BackupStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<BackupStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for BackupStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for BackupStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for BackupStatus {
+    fn deserialize<D>(deserializer: D) -> Result<BackupStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Contains details for the backup.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -302,12 +513,22 @@ pub struct BackupSummary {
     pub backup_arn: Option<String>,
     /// <p>Time at which the backup was created.</p>
     #[serde(rename = "BackupCreationDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub backup_creation_date_time: Option<f64>,
+    pub backup_creation_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Time at which the automatic on-demand backup created by DynamoDB will expire. This <code>SYSTEM</code> on-demand backup expires automatically 35 days after its creation.</p>
     #[serde(rename = "BackupExpiryDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub backup_expiry_date_time: Option<f64>,
+    pub backup_expiry_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Name of the specified backup.</p>
     #[serde(rename = "BackupName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -319,11 +540,11 @@ pub struct BackupSummary {
     /// <p>Backup can be in one of the following states: CREATING, ACTIVE, DELETED.</p>
     #[serde(rename = "BackupStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub backup_status: Option<String>,
+    pub backup_status: Option<BackupStatus>,
     /// <p><p>BackupType:</p> <ul> <li> <p> <code>USER</code> - You create and manage these using the on-demand backup feature.</p> </li> <li> <p> <code>SYSTEM</code> - If you delete a table with point-in-time recovery enabled, a <code>SYSTEM</code> backup is automatically created and is retained for 35 days (at no additional cost). System backups allow you to restore the deleted table to the state it was in just before the point of deletion. </p> </li> <li> <p> <code>AWS_BACKUP</code> - On-demand backup created by you from AWS Backup service.</p> </li> </ul></p>
     #[serde(rename = "BackupType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub backup_type: Option<String>,
+    pub backup_type: Option<BackupType>,
     /// <p>ARN associated with the table.</p>
     #[serde(rename = "TableArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -338,6 +559,207 @@ pub struct BackupSummary {
     pub table_name: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BackupType {
+    /// `USER`
+    User,
+    /// `SYSTEM`
+    System,
+    /// `AWS_BACKUP`
+    AwsBackup,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl BackupType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BackupType::User => "USER",
+            BackupType::System => "SYSTEM",
+            BackupType::AwsBackup => "AWS_BACKUP",
+            BackupType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for BackupType {
+    fn default() -> BackupType {
+        BackupType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for BackupType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for BackupType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for BackupType {
+    fn from(value: &'a str) -> BackupType {
+        match value {
+            "USER" => BackupType::User,
+            "SYSTEM" => BackupType::System,
+            "AWS_BACKUP" => BackupType::AwsBackup,
+            _ => BackupType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BackupType {
+    fn from(value: String) -> BackupType {
+        match value.as_str() {
+            "USER" => BackupType::User,
+            "SYSTEM" => BackupType::System,
+            "AWS_BACKUP" => BackupType::AwsBackup,
+            _ => BackupType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for BackupType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<BackupType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for BackupType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for BackupType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BackupType {
+    fn deserialize<D>(deserializer: D) -> Result<BackupType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BackupTypeFilter {
+    /// `USER`
+    User,
+    /// `SYSTEM`
+    System,
+    /// `AWS_BACKUP`
+    AwsBackup,
+    /// `ALL`
+    All,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl BackupTypeFilter {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BackupTypeFilter::User => "USER",
+            BackupTypeFilter::System => "SYSTEM",
+            BackupTypeFilter::AwsBackup => "AWS_BACKUP",
+            BackupTypeFilter::All => "ALL",
+            BackupTypeFilter::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for BackupTypeFilter {
+    fn default() -> BackupTypeFilter {
+        BackupTypeFilter::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for BackupTypeFilter {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for BackupTypeFilter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for BackupTypeFilter {
+    fn from(value: &'a str) -> BackupTypeFilter {
+        match value {
+            "USER" => BackupTypeFilter::User,
+            "SYSTEM" => BackupTypeFilter::System,
+            "AWS_BACKUP" => BackupTypeFilter::AwsBackup,
+            "ALL" => BackupTypeFilter::All,
+            _ => BackupTypeFilter::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BackupTypeFilter {
+    fn from(value: String) -> BackupTypeFilter {
+        match value.as_str() {
+            "USER" => BackupTypeFilter::User,
+            "SYSTEM" => BackupTypeFilter::System,
+            "AWS_BACKUP" => BackupTypeFilter::AwsBackup,
+            "ALL" => BackupTypeFilter::All,
+            _ => BackupTypeFilter::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for BackupTypeFilter {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<BackupTypeFilter, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for BackupTypeFilter {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for BackupTypeFilter {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BackupTypeFilter {
+    fn deserialize<D>(deserializer: D) -> Result<BackupTypeFilter, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents the input of a <code>BatchGetItem</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -347,7 +769,7 @@ pub struct BatchGetItemInput {
     pub request_items: ::std::collections::HashMap<String, KeysAndAttributes>,
     #[serde(rename = "ReturnConsumedCapacity")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub return_consumed_capacity: Option<String>,
+    pub return_consumed_capacity: Option<ReturnConsumedCapacity>,
 }
 
 /// <p>Represents the output of a <code>BatchGetItem</code> operation.</p>
@@ -382,11 +804,11 @@ pub struct BatchWriteItemInput {
     pub request_items: ::std::collections::HashMap<String, Vec<WriteRequest>>,
     #[serde(rename = "ReturnConsumedCapacity")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub return_consumed_capacity: Option<String>,
+    pub return_consumed_capacity: Option<ReturnConsumedCapacity>,
     /// <p>Determines whether item collection metrics are returned. If set to <code>SIZE</code>, the response includes statistics about item collections, if any, that were modified during the operation are returned in the response. If set to <code>NONE</code> (the default), no statistics are returned.</p>
     #[serde(rename = "ReturnItemCollectionMetrics")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub return_item_collection_metrics: Option<String>,
+    pub return_item_collection_metrics: Option<ReturnItemCollectionMetrics>,
 }
 
 /// <p>Represents the output of a <code>BatchWriteItem</code> operation.</p>
@@ -408,6 +830,99 @@ pub struct BatchWriteItemOutput {
     pub unprocessed_items: Option<::std::collections::HashMap<String, Vec<WriteRequest>>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BillingMode {
+    /// `PROVISIONED`
+    Provisioned,
+    /// `PAY_PER_REQUEST`
+    PayPerRequest,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl BillingMode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BillingMode::Provisioned => "PROVISIONED",
+            BillingMode::PayPerRequest => "PAY_PER_REQUEST",
+            BillingMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for BillingMode {
+    fn default() -> BillingMode {
+        BillingMode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for BillingMode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for BillingMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for BillingMode {
+    fn from(value: &'a str) -> BillingMode {
+        match value {
+            "PROVISIONED" => BillingMode::Provisioned,
+            "PAY_PER_REQUEST" => BillingMode::PayPerRequest,
+            _ => BillingMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BillingMode {
+    fn from(value: String) -> BillingMode {
+        match value.as_str() {
+            "PROVISIONED" => BillingMode::Provisioned,
+            "PAY_PER_REQUEST" => BillingMode::PayPerRequest,
+            _ => BillingMode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for BillingMode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<BillingMode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for BillingMode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for BillingMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BillingMode {
+    fn deserialize<D>(deserializer: D) -> Result<BillingMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Contains the details for the read/write capacity mode.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -415,11 +930,16 @@ pub struct BillingModeSummary {
     /// <p><p>Controls how you are charged for read and write throughput and how you manage capacity. This setting can be changed later.</p> <ul> <li> <p> <code>PROVISIONED</code> - Sets the read/write capacity mode to <code>PROVISIONED</code>. We recommend using <code>PROVISIONED</code> for predictable workloads.</p> </li> <li> <p> <code>PAY<em>PER</em>REQUEST</code> - Sets the read/write capacity mode to <code>PAY<em>PER</em>REQUEST</code>. We recommend using <code>PAY<em>PER</em>REQUEST</code> for unpredictable workloads. </p> </li> </ul></p>
     #[serde(rename = "BillingMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub billing_mode: Option<String>,
+    pub billing_mode: Option<BillingMode>,
     /// <p>Represents the time when <code>PAY_PER_REQUEST</code> was last set as the read/write capacity mode.</p>
     #[serde(rename = "LastUpdateToPayPerRequestDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_update_to_pay_per_request_date_time: Option<f64>,
+    pub last_update_to_pay_per_request_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>An ordered list of errors for each item in the request which caused the transaction to get cancelled. The values of the list are ordered according to the ordering of the <code>TransactWriteItems</code> request parameter. If no error occurred for the associated item an error with a Null code and Null message will be present. </p>
@@ -451,46 +971,287 @@ pub struct Capacity {
     pub write_capacity_units: Option<f64>,
 }
 
-/// <p><p>Represents the selection criteria for a <code>Query</code> or <code>Scan</code> operation:</p> <ul> <li> <p>For a <code>Query</code> operation, <code>Condition</code> is used for specifying the <code>KeyConditions</code> to use when querying a table or an index. For <code>KeyConditions</code>, only the following comparison operators are supported:</p> <p> <code>EQ | LE | LT | GE | GT | BEGINS_WITH | BETWEEN</code> </p> <p> <code>Condition</code> is also used in a <code>QueryFilter</code>, which evaluates the query results and returns only the desired values.</p> </li> <li> <p>For a <code>Scan</code> operation, <code>Condition</code> is used in a <code>ScanFilter</code>, which evaluates the scan results and returns only the desired values.</p> </li> </ul></p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct Condition {
-    /// <p>One or more values to evaluate against the supplied attribute. The number of values in the list depends on the <code>ComparisonOperator</code> being used.</p> <p>For type Number, value comparisons are numeric.</p> <p>String value comparisons for greater than, equals, or less than are based on ASCII character code values. For example, <code>a</code> is greater than <code>A</code>, and <code>a</code> is greater than <code>B</code>. For a list of code values, see <a href="http://en.wikipedia.org/wiki/ASCII#ASCII_printable_characters">http://en.wikipedia.org/wiki/ASCII#ASCII_printable_characters</a>.</p> <p>For Binary, DynamoDB treats each byte of the binary data as unsigned when it compares binary values.</p>
-    #[serde(rename = "AttributeValueList")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub attribute_value_list: Option<Vec<AttributeValue>>,
-    /// <p>A comparator for evaluating attributes. For example, equals, greater than, less than, etc.</p> <p>The following comparison operators are available:</p> <p> <code>EQ | NE | LE | LT | GE | GT | NOT_NULL | NULL | CONTAINS | NOT_CONTAINS | BEGINS_WITH | IN | BETWEEN</code> </p> <p>The following are descriptions of each comparison operator.</p> <ul> <li> <p> <code>EQ</code> : Equal. <code>EQ</code> is supported for all data types, including lists and maps.</p> <p> <code>AttributeValueList</code> can contain only one <code>AttributeValue</code> element of type String, Number, Binary, String Set, Number Set, or Binary Set. If an item contains an <code>AttributeValue</code> element of a different type than the one provided in the request, the value does not match. For example, <code>{"S":"6"}</code> does not equal <code>{"N":"6"}</code>. Also, <code>{"N":"6"}</code> does not equal <code>{"NS":["6", "2", "1"]}</code>.</p> <p/> </li> <li> <p> <code>NE</code> : Not equal. <code>NE</code> is supported for all data types, including lists and maps.</p> <p> <code>AttributeValueList</code> can contain only one <code>AttributeValue</code> of type String, Number, Binary, String Set, Number Set, or Binary Set. If an item contains an <code>AttributeValue</code> of a different type than the one provided in the request, the value does not match. For example, <code>{"S":"6"}</code> does not equal <code>{"N":"6"}</code>. Also, <code>{"N":"6"}</code> does not equal <code>{"NS":["6", "2", "1"]}</code>.</p> <p/> </li> <li> <p> <code>LE</code> : Less than or equal. </p> <p> <code>AttributeValueList</code> can contain only one <code>AttributeValue</code> element of type String, Number, or Binary (not a set type). If an item contains an <code>AttributeValue</code> element of a different type than the one provided in the request, the value does not match. For example, <code>{"S":"6"}</code> does not equal <code>{"N":"6"}</code>. Also, <code>{"N":"6"}</code> does not compare to <code>{"NS":["6", "2", "1"]}</code>.</p> <p/> </li> <li> <p> <code>LT</code> : Less than. </p> <p> <code>AttributeValueList</code> can contain only one <code>AttributeValue</code> of type String, Number, or Binary (not a set type). If an item contains an <code>AttributeValue</code> element of a different type than the one provided in the request, the value does not match. For example, <code>{"S":"6"}</code> does not equal <code>{"N":"6"}</code>. Also, <code>{"N":"6"}</code> does not compare to <code>{"NS":["6", "2", "1"]}</code>.</p> <p/> </li> <li> <p> <code>GE</code> : Greater than or equal. </p> <p> <code>AttributeValueList</code> can contain only one <code>AttributeValue</code> element of type String, Number, or Binary (not a set type). If an item contains an <code>AttributeValue</code> element of a different type than the one provided in the request, the value does not match. For example, <code>{"S":"6"}</code> does not equal <code>{"N":"6"}</code>. Also, <code>{"N":"6"}</code> does not compare to <code>{"NS":["6", "2", "1"]}</code>.</p> <p/> </li> <li> <p> <code>GT</code> : Greater than. </p> <p> <code>AttributeValueList</code> can contain only one <code>AttributeValue</code> element of type String, Number, or Binary (not a set type). If an item contains an <code>AttributeValue</code> element of a different type than the one provided in the request, the value does not match. For example, <code>{"S":"6"}</code> does not equal <code>{"N":"6"}</code>. Also, <code>{"N":"6"}</code> does not compare to <code>{"NS":["6", "2", "1"]}</code>.</p> <p/> </li> <li> <p> <code>NOT_NULL</code> : The attribute exists. <code>NOT_NULL</code> is supported for all data types, including lists and maps.</p> <note> <p>This operator tests for the existence of an attribute, not its data type. If the data type of attribute "<code>a</code>" is null, and you evaluate it using <code>NOT_NULL</code>, the result is a Boolean <code>true</code>. This result is because the attribute "<code>a</code>" exists; its data type is not relevant to the <code>NOT_NULL</code> comparison operator.</p> </note> </li> <li> <p> <code>NULL</code> : The attribute does not exist. <code>NULL</code> is supported for all data types, including lists and maps.</p> <note> <p>This operator tests for the nonexistence of an attribute, not its data type. If the data type of attribute "<code>a</code>" is null, and you evaluate it using <code>NULL</code>, the result is a Boolean <code>false</code>. This is because the attribute "<code>a</code>" exists; its data type is not relevant to the <code>NULL</code> comparison operator.</p> </note> </li> <li> <p> <code>CONTAINS</code> : Checks for a subsequence, or value in a set.</p> <p> <code>AttributeValueList</code> can contain only one <code>AttributeValue</code> element of type String, Number, or Binary (not a set type). If the target attribute of the comparison is of type String, then the operator checks for a substring match. If the target attribute of the comparison is of type Binary, then the operator looks for a subsequence of the target that matches the input. If the target attribute of the comparison is a set ("<code>SS</code>", "<code>NS</code>", or "<code>BS</code>"), then the operator evaluates to true if it finds an exact match with any member of the set.</p> <p>CONTAINS is supported for lists: When evaluating "<code>a CONTAINS b</code>", "<code>a</code>" can be a list; however, "<code>b</code>" cannot be a set, a map, or a list.</p> </li> <li> <p> <code>NOT_CONTAINS</code> : Checks for absence of a subsequence, or absence of a value in a set.</p> <p> <code>AttributeValueList</code> can contain only one <code>AttributeValue</code> element of type String, Number, or Binary (not a set type). If the target attribute of the comparison is a String, then the operator checks for the absence of a substring match. If the target attribute of the comparison is Binary, then the operator checks for the absence of a subsequence of the target that matches the input. If the target attribute of the comparison is a set ("<code>SS</code>", "<code>NS</code>", or "<code>BS</code>"), then the operator evaluates to true if it <i>does not</i> find an exact match with any member of the set.</p> <p>NOT_CONTAINS is supported for lists: When evaluating "<code>a NOT CONTAINS b</code>", "<code>a</code>" can be a list; however, "<code>b</code>" cannot be a set, a map, or a list.</p> </li> <li> <p> <code>BEGINS_WITH</code> : Checks for a prefix. </p> <p> <code>AttributeValueList</code> can contain only one <code>AttributeValue</code> of type String or Binary (not a Number or a set type). The target attribute of the comparison must be of type String or Binary (not a Number or a set type).</p> <p/> </li> <li> <p> <code>IN</code> : Checks for matching elements in a list.</p> <p> <code>AttributeValueList</code> can contain one or more <code>AttributeValue</code> elements of type String, Number, or Binary. These attributes are compared against an existing attribute of an item. If any elements of the input are equal to the item attribute, the expression evaluates to true.</p> </li> <li> <p> <code>BETWEEN</code> : Greater than or equal to the first value, and less than or equal to the second value. </p> <p> <code>AttributeValueList</code> must contain two <code>AttributeValue</code> elements of the same type, either String, Number, or Binary (not a set type). A target attribute matches if the target value is greater than, or equal to, the first element and less than, or equal to, the second element. If an item contains an <code>AttributeValue</code> element of a different type than the one provided in the request, the value does not match. For example, <code>{"S":"6"}</code> does not compare to <code>{"N":"6"}</code>. Also, <code>{"N":"6"}</code> does not compare to <code>{"NS":["6", "2", "1"]}</code> </p> </li> </ul> <p>For usage examples of <code>AttributeValueList</code> and <code>ComparisonOperator</code>, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/LegacyConditionalParameters.html">Legacy Conditional Parameters</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
-    #[serde(rename = "ComparisonOperator")]
-    pub comparison_operator: String,
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ComparisonOperator {
+    /// `EQ`
+    Eq,
+    /// `NE`
+    Ne,
+    /// `IN`
+    In,
+    /// `LE`
+    Le,
+    /// `LT`
+    Lt,
+    /// `GE`
+    Ge,
+    /// `GT`
+    Gt,
+    /// `BETWEEN`
+    Between,
+    /// `NOT_NULL`
+    NotNull,
+    /// `NULL`
+    Null,
+    /// `CONTAINS`
+    Contains,
+    /// `NOT_CONTAINS`
+    NotContains,
+    /// `BEGINS_WITH`
+    BeginsWith,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ComparisonOperator {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ComparisonOperator::Eq => "EQ",
+            ComparisonOperator::Ne => "NE",
+            ComparisonOperator::In => "IN",
+            ComparisonOperator::Le => "LE",
+            ComparisonOperator::Lt => "LT",
+            ComparisonOperator::Ge => "GE",
+            ComparisonOperator::Gt => "GT",
+            ComparisonOperator::Between => "BETWEEN",
+            ComparisonOperator::NotNull => "NOT_NULL",
+            ComparisonOperator::Null => "NULL",
+            ComparisonOperator::Contains => "CONTAINS",
+            ComparisonOperator::NotContains => "NOT_CONTAINS",
+            ComparisonOperator::BeginsWith => "BEGINS_WITH",
+            ComparisonOperator::Unknown(ref value) => value,
+        }
+    }
 }
 
-/// <p>Represents a request to perform a check that an item exists or to check the condition of specific attributes of the item.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct ConditionCheck {
-    /// <p>A condition that must be satisfied in order for a conditional update to succeed.</p>
-    #[serde(rename = "ConditionExpression")]
-    pub condition_expression: String,
-    /// <p>One or more substitution tokens for attribute names in an expression.</p>
-    #[serde(rename = "ExpressionAttributeNames")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub expression_attribute_names: Option<::std::collections::HashMap<String, String>>,
-    /// <p>One or more values that can be substituted in an expression.</p>
-    #[serde(rename = "ExpressionAttributeValues")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub expression_attribute_values: Option<::std::collections::HashMap<String, AttributeValue>>,
-    /// <p>The primary key of the item to be checked. Each element consists of an attribute name and a value for that attribute.</p>
-    #[serde(rename = "Key")]
-    pub key: ::std::collections::HashMap<String, AttributeValue>,
-    /// <p>Use <code>ReturnValuesOnConditionCheckFailure</code> to get the item attributes if the <code>ConditionCheck</code> condition fails. For <code>ReturnValuesOnConditionCheckFailure</code>, the valid values are: NONE and ALL_OLD.</p>
-    #[serde(rename = "ReturnValuesOnConditionCheckFailure")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub return_values_on_condition_check_failure: Option<String>,
-    /// <p>Name of the table for the check item request.</p>
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ComparisonOperator {
+    fn default() -> ComparisonOperator {
+        ComparisonOperator::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ComparisonOperator {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ComparisonOperator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ComparisonOperator {
+    fn from(value: &'a str) -> ComparisonOperator {
+        match value {
+            "EQ" => ComparisonOperator::Eq,
+            "NE" => ComparisonOperator::Ne,
+            "IN" => ComparisonOperator::In,
+            "LE" => ComparisonOperator::Le,
+            "LT" => ComparisonOperator::Lt,
+            "GE" => ComparisonOperator::Ge,
+            "GT" => ComparisonOperator::Gt,
+            "BETWEEN" => ComparisonOperator::Between,
+            "NOT_NULL" => ComparisonOperator::NotNull,
+            "NULL" => ComparisonOperator::Null,
+            "CONTAINS" => ComparisonOperator::Contains,
+            "NOT_CONTAINS" => ComparisonOperator::NotContains,
+            "BEGINS_WITH" => ComparisonOperator::BeginsWith,
+            _ => ComparisonOperator::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ComparisonOperator {
+    fn from(value: String) -> ComparisonOperator {
+        match value.as_str() {
+            "EQ" => ComparisonOperator::Eq,
+            "NE" => ComparisonOperator::Ne,
+            "IN" => ComparisonOperator::In,
+            "LE" => ComparisonOperator::Le,
+            "LT" => ComparisonOperator::Lt,
+            "GE" => ComparisonOperator::Ge,
+            "GT" => ComparisonOperator::Gt,
+            "BETWEEN" => ComparisonOperator::Between,
+            "NOT_NULL" => ComparisonOperator::NotNull,
+            "NULL" => ComparisonOperator::Null,
+            "CONTAINS" => ComparisonOperator::Contains,
+            "NOT_CONTAINS" => ComparisonOperator::NotContains,
+            "BEGINS_WITH" => ComparisonOperator::BeginsWith,
+            _ => ComparisonOperator::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ComparisonOperator {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ComparisonOperator, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ComparisonOperator {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ComparisonOperator {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ComparisonOperator {
+    fn deserialize<D>(deserializer: D) -> Result<ComparisonOperator, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p><p>Represents the selection criteria for a <code>Query</code> or <code>Scan</code> operation:</p> <ul> <li> <p>For a <code>Query</code> operation, <code>Condition</code> is used for specifying the <code>KeyConditions</code> to use when querying a table or an index. For <code>KeyConditions</code>, only the following comparison operators are supported:</p> <p> <code>EQ | LE | LT | GE | GT | BEGINS_WITH | BETWEEN</code> </p> <p> <code>Condition</code> is also used in a <code>QueryFilter</code>, which evaluates the query results and returns only the desired values.</p> </li> <li> <p>For a <code>Scan</code> operation, <code>Condition</code> is used in a <code>ScanFilter</code>, which evaluates the scan results and returns only the desired values.</p> </li> </ul></p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct Condition {
+    /// <p>One or more values to evaluate against the supplied attribute. The number of values in the list depends on the <code>ComparisonOperator</code> being used.</p> <p>For type Number, value comparisons are numeric.</p> <p>String value comparisons for greater than, equals, or less than are based on ASCII character code values. For example, <code>a</code> is greater than <code>A</code>, and <code>a</code> is greater than <code>B</code>. For a list of code values, see <a href="http://en.wikipedia.org/wiki/ASCII#ASCII_printable_characters">http://en.wikipedia.org/wiki/ASCII#ASCII_printable_characters</a>.</p> <p>For Binary, DynamoDB treats each byte of the binary data as unsigned when it compares binary values.</p>
+    #[serde(rename = "AttributeValueList")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attribute_value_list: Option<Vec<AttributeValue>>,
+    /// <p>A comparator for evaluating attributes. For example, equals, greater than, less than, etc.</p> <p>The following comparison operators are available:</p> <p> <code>EQ | NE | LE | LT | GE | GT | NOT_NULL | NULL | CONTAINS | NOT_CONTAINS | BEGINS_WITH | IN | BETWEEN</code> </p> <p>The following are descriptions of each comparison operator.</p> <ul> <li> <p> <code>EQ</code> : Equal. <code>EQ</code> is supported for all data types, including lists and maps.</p> <p> <code>AttributeValueList</code> can contain only one <code>AttributeValue</code> element of type String, Number, Binary, String Set, Number Set, or Binary Set. If an item contains an <code>AttributeValue</code> element of a different type than the one provided in the request, the value does not match. For example, <code>{"S":"6"}</code> does not equal <code>{"N":"6"}</code>. Also, <code>{"N":"6"}</code> does not equal <code>{"NS":["6", "2", "1"]}</code>.</p> <p/> </li> <li> <p> <code>NE</code> : Not equal. <code>NE</code> is supported for all data types, including lists and maps.</p> <p> <code>AttributeValueList</code> can contain only one <code>AttributeValue</code> of type String, Number, Binary, String Set, Number Set, or Binary Set. If an item contains an <code>AttributeValue</code> of a different type than the one provided in the request, the value does not match. For example, <code>{"S":"6"}</code> does not equal <code>{"N":"6"}</code>. Also, <code>{"N":"6"}</code> does not equal <code>{"NS":["6", "2", "1"]}</code>.</p> <p/> </li> <li> <p> <code>LE</code> : Less than or equal. </p> <p> <code>AttributeValueList</code> can contain only one <code>AttributeValue</code> element of type String, Number, or Binary (not a set type). If an item contains an <code>AttributeValue</code> element of a different type than the one provided in the request, the value does not match. For example, <code>{"S":"6"}</code> does not equal <code>{"N":"6"}</code>. Also, <code>{"N":"6"}</code> does not compare to <code>{"NS":["6", "2", "1"]}</code>.</p> <p/> </li> <li> <p> <code>LT</code> : Less than. </p> <p> <code>AttributeValueList</code> can contain only one <code>AttributeValue</code> of type String, Number, or Binary (not a set type). If an item contains an <code>AttributeValue</code> element of a different type than the one provided in the request, the value does not match. For example, <code>{"S":"6"}</code> does not equal <code>{"N":"6"}</code>. Also, <code>{"N":"6"}</code> does not compare to <code>{"NS":["6", "2", "1"]}</code>.</p> <p/> </li> <li> <p> <code>GE</code> : Greater than or equal. </p> <p> <code>AttributeValueList</code> can contain only one <code>AttributeValue</code> element of type String, Number, or Binary (not a set type). If an item contains an <code>AttributeValue</code> element of a different type than the one provided in the request, the value does not match. For example, <code>{"S":"6"}</code> does not equal <code>{"N":"6"}</code>. Also, <code>{"N":"6"}</code> does not compare to <code>{"NS":["6", "2", "1"]}</code>.</p> <p/> </li> <li> <p> <code>GT</code> : Greater than. </p> <p> <code>AttributeValueList</code> can contain only one <code>AttributeValue</code> element of type String, Number, or Binary (not a set type). If an item contains an <code>AttributeValue</code> element of a different type than the one provided in the request, the value does not match. For example, <code>{"S":"6"}</code> does not equal <code>{"N":"6"}</code>. Also, <code>{"N":"6"}</code> does not compare to <code>{"NS":["6", "2", "1"]}</code>.</p> <p/> </li> <li> <p> <code>NOT_NULL</code> : The attribute exists. <code>NOT_NULL</code> is supported for all data types, including lists and maps.</p> <note> <p>This operator tests for the existence of an attribute, not its data type. If the data type of attribute "<code>a</code>" is null, and you evaluate it using <code>NOT_NULL</code>, the result is a Boolean <code>true</code>. This result is because the attribute "<code>a</code>" exists; its data type is not relevant to the <code>NOT_NULL</code> comparison operator.</p> </note> </li> <li> <p> <code>NULL</code> : The attribute does not exist. <code>NULL</code> is supported for all data types, including lists and maps.</p> <note> <p>This operator tests for the nonexistence of an attribute, not its data type. If the data type of attribute "<code>a</code>" is null, and you evaluate it using <code>NULL</code>, the result is a Boolean <code>false</code>. This is because the attribute "<code>a</code>" exists; its data type is not relevant to the <code>NULL</code> comparison operator.</p> </note> </li> <li> <p> <code>CONTAINS</code> : Checks for a subsequence, or value in a set.</p> <p> <code>AttributeValueList</code> can contain only one <code>AttributeValue</code> element of type String, Number, or Binary (not a set type). If the target attribute of the comparison is of type String, then the operator checks for a substring match. If the target attribute of the comparison is of type Binary, then the operator looks for a subsequence of the target that matches the input. If the target attribute of the comparison is a set ("<code>SS</code>", "<code>NS</code>", or "<code>BS</code>"), then the operator evaluates to true if it finds an exact match with any member of the set.</p> <p>CONTAINS is supported for lists: When evaluating "<code>a CONTAINS b</code>", "<code>a</code>" can be a list; however, "<code>b</code>" cannot be a set, a map, or a list.</p> </li> <li> <p> <code>NOT_CONTAINS</code> : Checks for absence of a subsequence, or absence of a value in a set.</p> <p> <code>AttributeValueList</code> can contain only one <code>AttributeValue</code> element of type String, Number, or Binary (not a set type). If the target attribute of the comparison is a String, then the operator checks for the absence of a substring match. If the target attribute of the comparison is Binary, then the operator checks for the absence of a subsequence of the target that matches the input. If the target attribute of the comparison is a set ("<code>SS</code>", "<code>NS</code>", or "<code>BS</code>"), then the operator evaluates to true if it <i>does not</i> find an exact match with any member of the set.</p> <p>NOT_CONTAINS is supported for lists: When evaluating "<code>a NOT CONTAINS b</code>", "<code>a</code>" can be a list; however, "<code>b</code>" cannot be a set, a map, or a list.</p> </li> <li> <p> <code>BEGINS_WITH</code> : Checks for a prefix. </p> <p> <code>AttributeValueList</code> can contain only one <code>AttributeValue</code> of type String or Binary (not a Number or a set type). The target attribute of the comparison must be of type String or Binary (not a Number or a set type).</p> <p/> </li> <li> <p> <code>IN</code> : Checks for matching elements in a list.</p> <p> <code>AttributeValueList</code> can contain one or more <code>AttributeValue</code> elements of type String, Number, or Binary. These attributes are compared against an existing attribute of an item. If any elements of the input are equal to the item attribute, the expression evaluates to true.</p> </li> <li> <p> <code>BETWEEN</code> : Greater than or equal to the first value, and less than or equal to the second value. </p> <p> <code>AttributeValueList</code> must contain two <code>AttributeValue</code> elements of the same type, either String, Number, or Binary (not a set type). A target attribute matches if the target value is greater than, or equal to, the first element and less than, or equal to, the second element. If an item contains an <code>AttributeValue</code> element of a different type than the one provided in the request, the value does not match. For example, <code>{"S":"6"}</code> does not compare to <code>{"N":"6"}</code>. Also, <code>{"N":"6"}</code> does not compare to <code>{"NS":["6", "2", "1"]}</code> </p> </li> </ul> <p>For usage examples of <code>AttributeValueList</code> and <code>ComparisonOperator</code>, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/LegacyConditionalParameters.html">Legacy Conditional Parameters</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
+    #[serde(rename = "ComparisonOperator")]
+    pub comparison_operator: ComparisonOperator,
+}
+
+/// <p>Represents a request to perform a check that an item exists or to check the condition of specific attributes of the item.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct ConditionCheck {
+    /// <p>A condition that must be satisfied in order for a conditional update to succeed.</p>
+    #[serde(rename = "ConditionExpression")]
+    pub condition_expression: String,
+    /// <p>One or more substitution tokens for attribute names in an expression.</p>
+    #[serde(rename = "ExpressionAttributeNames")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expression_attribute_names: Option<::std::collections::HashMap<String, String>>,
+    /// <p>One or more values that can be substituted in an expression.</p>
+    #[serde(rename = "ExpressionAttributeValues")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expression_attribute_values: Option<::std::collections::HashMap<String, AttributeValue>>,
+    /// <p>The primary key of the item to be checked. Each element consists of an attribute name and a value for that attribute.</p>
+    #[serde(rename = "Key")]
+    pub key: ::std::collections::HashMap<String, AttributeValue>,
+    /// <p>Use <code>ReturnValuesOnConditionCheckFailure</code> to get the item attributes if the <code>ConditionCheck</code> condition fails. For <code>ReturnValuesOnConditionCheckFailure</code>, the valid values are: NONE and ALL_OLD.</p>
+    #[serde(rename = "ReturnValuesOnConditionCheckFailure")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub return_values_on_condition_check_failure: Option<ReturnValuesOnConditionCheckFailure>,
+    /// <p>Name of the table for the check item request.</p>
     #[serde(rename = "TableName")]
     pub table_name: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ConditionalOperator {
+    /// `AND`
+    And,
+    /// `OR`
+    Or,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ConditionalOperator {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ConditionalOperator::And => "AND",
+            ConditionalOperator::Or => "OR",
+            ConditionalOperator::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ConditionalOperator {
+    fn default() -> ConditionalOperator {
+        ConditionalOperator::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ConditionalOperator {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ConditionalOperator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ConditionalOperator {
+    fn from(value: &'a str) -> ConditionalOperator {
+        match value {
+            "AND" => ConditionalOperator::And,
+            "OR" => ConditionalOperator::Or,
+            _ => ConditionalOperator::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ConditionalOperator {
+    fn from(value: String) -> ConditionalOperator {
+        match value.as_str() {
+            "AND" => ConditionalOperator::And,
+            "OR" => ConditionalOperator::Or,
+            _ => ConditionalOperator::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ConditionalOperator {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ConditionalOperator, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ConditionalOperator {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ConditionalOperator {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ConditionalOperator {
+    fn deserialize<D>(deserializer: D) -> Result<ConditionalOperator, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The capacity units consumed by an operation. The data returned includes the total provisioned throughput consumed, along with statistics for the table and any indexes involved in the operation. <code>ConsumedCapacity</code> is only returned if the request asked for it. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/ProvisionedThroughputIntro.html">Provisioned Throughput</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -531,13 +1292,307 @@ pub struct ConsumedCapacity {
 pub struct ContinuousBackupsDescription {
     /// <p> <code>ContinuousBackupsStatus</code> can be one of the following states: ENABLED, DISABLED</p>
     #[serde(rename = "ContinuousBackupsStatus")]
-    pub continuous_backups_status: String,
+    pub continuous_backups_status: ContinuousBackupsStatus,
     /// <p>The description of the point in time recovery settings applied to the table.</p>
     #[serde(rename = "PointInTimeRecoveryDescription")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub point_in_time_recovery_description: Option<PointInTimeRecoveryDescription>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ContinuousBackupsStatus {
+    /// `ENABLED`
+    Enabled,
+    /// `DISABLED`
+    Disabled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ContinuousBackupsStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ContinuousBackupsStatus::Enabled => "ENABLED",
+            ContinuousBackupsStatus::Disabled => "DISABLED",
+            ContinuousBackupsStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ContinuousBackupsStatus {
+    fn default() -> ContinuousBackupsStatus {
+        ContinuousBackupsStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ContinuousBackupsStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ContinuousBackupsStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ContinuousBackupsStatus {
+    fn from(value: &'a str) -> ContinuousBackupsStatus {
+        match value {
+            "ENABLED" => ContinuousBackupsStatus::Enabled,
+            "DISABLED" => ContinuousBackupsStatus::Disabled,
+            _ => ContinuousBackupsStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ContinuousBackupsStatus {
+    fn from(value: String) -> ContinuousBackupsStatus {
+        match value.as_str() {
+            "ENABLED" => ContinuousBackupsStatus::Enabled,
+            "DISABLED" => ContinuousBackupsStatus::Disabled,
+            _ => ContinuousBackupsStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ContinuousBackupsStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ContinuousBackupsStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ContinuousBackupsStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ContinuousBackupsStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ContinuousBackupsStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ContinuousBackupsStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ContributorInsightsAction {
+    /// `ENABLE`
+    Enable,
+    /// `DISABLE`
+    Disable,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ContributorInsightsAction {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ContributorInsightsAction::Enable => "ENABLE",
+            ContributorInsightsAction::Disable => "DISABLE",
+            ContributorInsightsAction::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ContributorInsightsAction {
+    fn default() -> ContributorInsightsAction {
+        ContributorInsightsAction::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ContributorInsightsAction {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ContributorInsightsAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ContributorInsightsAction {
+    fn from(value: &'a str) -> ContributorInsightsAction {
+        match value {
+            "ENABLE" => ContributorInsightsAction::Enable,
+            "DISABLE" => ContributorInsightsAction::Disable,
+            _ => ContributorInsightsAction::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ContributorInsightsAction {
+    fn from(value: String) -> ContributorInsightsAction {
+        match value.as_str() {
+            "ENABLE" => ContributorInsightsAction::Enable,
+            "DISABLE" => ContributorInsightsAction::Disable,
+            _ => ContributorInsightsAction::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ContributorInsightsAction {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ContributorInsightsAction, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ContributorInsightsAction {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ContributorInsightsAction {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ContributorInsightsAction {
+    fn deserialize<D>(deserializer: D) -> Result<ContributorInsightsAction, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ContributorInsightsStatus {
+    /// `ENABLING`
+    Enabling,
+    /// `ENABLED`
+    Enabled,
+    /// `DISABLING`
+    Disabling,
+    /// `DISABLED`
+    Disabled,
+    /// `FAILED`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ContributorInsightsStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ContributorInsightsStatus::Enabling => "ENABLING",
+            ContributorInsightsStatus::Enabled => "ENABLED",
+            ContributorInsightsStatus::Disabling => "DISABLING",
+            ContributorInsightsStatus::Disabled => "DISABLED",
+            ContributorInsightsStatus::Failed => "FAILED",
+            ContributorInsightsStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ContributorInsightsStatus {
+    fn default() -> ContributorInsightsStatus {
+        ContributorInsightsStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ContributorInsightsStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ContributorInsightsStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ContributorInsightsStatus {
+    fn from(value: &'a str) -> ContributorInsightsStatus {
+        match value {
+            "ENABLING" => ContributorInsightsStatus::Enabling,
+            "ENABLED" => ContributorInsightsStatus::Enabled,
+            "DISABLING" => ContributorInsightsStatus::Disabling,
+            "DISABLED" => ContributorInsightsStatus::Disabled,
+            "FAILED" => ContributorInsightsStatus::Failed,
+            _ => ContributorInsightsStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ContributorInsightsStatus {
+    fn from(value: String) -> ContributorInsightsStatus {
+        match value.as_str() {
+            "ENABLING" => ContributorInsightsStatus::Enabling,
+            "ENABLED" => ContributorInsightsStatus::Enabled,
+            "DISABLING" => ContributorInsightsStatus::Disabling,
+            "DISABLED" => ContributorInsightsStatus::Disabled,
+            "FAILED" => ContributorInsightsStatus::Failed,
+            _ => ContributorInsightsStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ContributorInsightsStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ContributorInsightsStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ContributorInsightsStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ContributorInsightsStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ContributorInsightsStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ContributorInsightsStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents a Contributor Insights summary entry..</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -545,7 +1600,7 @@ pub struct ContributorInsightsSummary {
     /// <p>Describes the current status for contributor insights for the given table and index, if applicable.</p>
     #[serde(rename = "ContributorInsightsStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub contributor_insights_status: Option<String>,
+    pub contributor_insights_status: Option<ContributorInsightsStatus>,
     /// <p>Name of the index associated with the summary, if any.</p>
     #[serde(rename = "IndexName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -655,7 +1710,7 @@ pub struct CreateTableInput {
     /// <p><p>Controls how you are charged for read and write throughput and how you manage capacity. This setting can be changed later.</p> <ul> <li> <p> <code>PROVISIONED</code> - We recommend using <code>PROVISIONED</code> for predictable workloads. <code>PROVISIONED</code> sets the billing mode to <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/HowItWorks.ReadWriteCapacityMode.html#HowItWorks.ProvisionedThroughput.Manual">Provisioned Mode</a>.</p> </li> <li> <p> <code>PAY<em>PER</em>REQUEST</code> - We recommend using <code>PAY<em>PER</em>REQUEST</code> for unpredictable workloads. <code>PAY<em>PER</em>REQUEST</code> sets the billing mode to <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/HowItWorks.ReadWriteCapacityMode.html#HowItWorks.OnDemand">On-Demand Mode</a>. </p> </li> </ul></p>
     #[serde(rename = "BillingMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub billing_mode: Option<String>,
+    pub billing_mode: Option<BillingMode>,
     /// <p><p>One or more global secondary indexes (the maximum is 20) to be created on the table. Each global secondary index in the array includes the following:</p> <ul> <li> <p> <code>IndexName</code> - The name of the global secondary index. Must be unique only for this table.</p> <p/> </li> <li> <p> <code>KeySchema</code> - Specifies the key schema for the global secondary index.</p> </li> <li> <p> <code>Projection</code> - Specifies attributes that are copied (projected) from the table into the index. These are in addition to the primary key attributes and index key attributes, which are automatically projected. Each attribute specification is composed of:</p> <ul> <li> <p> <code>ProjectionType</code> - One of the following:</p> <ul> <li> <p> <code>KEYS_ONLY</code> - Only the index and primary keys are projected into the index.</p> </li> <li> <p> <code>INCLUDE</code> - Only the specified table attributes are projected into the index. The list of projected attributes is in <code>NonKeyAttributes</code>.</p> </li> <li> <p> <code>ALL</code> - All of the table attributes are projected into the index.</p> </li> </ul> </li> <li> <p> <code>NonKeyAttributes</code> - A list of one or more non-key attribute names that are projected into the secondary index. The total count of attributes provided in <code>NonKeyAttributes</code>, summed across all of the secondary indexes, must not exceed 100. If you project the same attribute into two different indexes, this counts as two distinct attributes when determining the total.</p> </li> </ul> </li> <li> <p> <code>ProvisionedThroughput</code> - The provisioned throughput settings for the global secondary index, consisting of read and write capacity units.</p> </li> </ul></p>
     #[serde(rename = "GlobalSecondaryIndexes")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -720,7 +1775,7 @@ pub struct Delete {
     /// <p>Use <code>ReturnValuesOnConditionCheckFailure</code> to get the item attributes if the <code>Delete</code> condition fails. For <code>ReturnValuesOnConditionCheckFailure</code>, the valid values are: NONE and ALL_OLD.</p>
     #[serde(rename = "ReturnValuesOnConditionCheckFailure")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub return_values_on_condition_check_failure: Option<String>,
+    pub return_values_on_condition_check_failure: Option<ReturnValuesOnConditionCheckFailure>,
     /// <p>Name of the table in which the item to be deleted resides.</p>
     #[serde(rename = "TableName")]
     pub table_name: String,
@@ -763,7 +1818,7 @@ pub struct DeleteItemInput {
     /// <p>This is a legacy parameter. Use <code>ConditionExpression</code> instead. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/LegacyConditionalParameters.ConditionalOperator.html">ConditionalOperator</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
     #[serde(rename = "ConditionalOperator")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub conditional_operator: Option<String>,
+    pub conditional_operator: Option<ConditionalOperator>,
     /// <p>This is a legacy parameter. Use <code>ConditionExpression</code> instead. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/LegacyConditionalParameters.Expected.html">Expected</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
     #[serde(rename = "Expected")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -781,15 +1836,15 @@ pub struct DeleteItemInput {
     pub key: ::std::collections::HashMap<String, AttributeValue>,
     #[serde(rename = "ReturnConsumedCapacity")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub return_consumed_capacity: Option<String>,
+    pub return_consumed_capacity: Option<ReturnConsumedCapacity>,
     /// <p>Determines whether item collection metrics are returned. If set to <code>SIZE</code>, the response includes statistics about item collections, if any, that were modified during the operation are returned in the response. If set to <code>NONE</code> (the default), no statistics are returned.</p>
     #[serde(rename = "ReturnItemCollectionMetrics")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub return_item_collection_metrics: Option<String>,
+    pub return_item_collection_metrics: Option<ReturnItemCollectionMetrics>,
     /// <p><p>Use <code>ReturnValues</code> if you want to get the item attributes as they appeared before they were deleted. For <code>DeleteItem</code>, the valid values are:</p> <ul> <li> <p> <code>NONE</code> - If <code>ReturnValues</code> is not specified, or if its value is <code>NONE</code>, then nothing is returned. (This setting is the default for <code>ReturnValues</code>.)</p> </li> <li> <p> <code>ALL<em>OLD</code> - The content of the old item is returned.</p> </li> </ul> <note> <p>The <code>ReturnValues</code> parameter is used by several DynamoDB operations; however, <code>DeleteItem</code> does not recognize any values other than <code>NONE</code> or <code>ALL</em>OLD</code>.</p> </note></p>
     #[serde(rename = "ReturnValues")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub return_values: Option<String>,
+    pub return_values: Option<ReturnValue>,
     /// <p>The name of the table from which to delete the item.</p>
     #[serde(rename = "TableName")]
     pub table_name: String,
@@ -914,7 +1969,7 @@ pub struct DescribeContributorInsightsOutput {
     /// <p>Current Status contributor insights.</p>
     #[serde(rename = "ContributorInsightsStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub contributor_insights_status: Option<String>,
+    pub contributor_insights_status: Option<ContributorInsightsStatus>,
     /// <p><p>Returns information about the last failure that encountered.</p> <p>The most common exceptions for a FAILED status are:</p> <ul> <li> <p>LimitExceededException - Per-account Amazon CloudWatch Contributor Insights rule limit reached. Please disable Contributor Insights for other tables/indexes OR disable Contributor Insights rules before retrying.</p> </li> <li> <p>AccessDeniedException - Amazon CloudWatch Contributor Insights rules cannot be modified due to insufficient permissions.</p> </li> <li> <p>AccessDeniedException - Failed to create service-linked role for Contributor Insights due to insufficient permissions.</p> </li> <li> <p>InternalServerError - Failed to create Amazon CloudWatch Contributor Insights rules. Please retry request.</p> </li> </ul></p>
     #[serde(rename = "FailureException")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -925,8 +1980,13 @@ pub struct DescribeContributorInsightsOutput {
     pub index_name: Option<String>,
     /// <p>Timestamp of the last time the status was changed.</p>
     #[serde(rename = "LastUpdateDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_update_date_time: Option<f64>,
+    pub last_update_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the table being described.</p>
     #[serde(rename = "TableName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1086,7 +2146,7 @@ pub struct ExpectedAttributeValue {
     /// <p><p>A comparator for evaluating attributes in the <code>AttributeValueList</code>. For example, equals, greater than, less than, etc.</p> <p>The following comparison operators are available:</p> <p> <code>EQ | NE | LE | LT | GE | GT | NOT<em>NULL | NULL | CONTAINS | NOT</em>CONTAINS | BEGINS<em>WITH | IN | BETWEEN</code> </p> <p>The following are descriptions of each comparison operator.</p> <ul> <li> <p> <code>EQ</code> : Equal. <code>EQ</code> is supported for all data types, including lists and maps.</p> <p> <code>AttributeValueList</code> can contain only one <code>AttributeValue</code> element of type String, Number, Binary, String Set, Number Set, or Binary Set. If an item contains an <code>AttributeValue</code> element of a different type than the one provided in the request, the value does not match. For example, <code>{&quot;S&quot;:&quot;6&quot;}</code> does not equal <code>{&quot;N&quot;:&quot;6&quot;}</code>. Also, <code>{&quot;N&quot;:&quot;6&quot;}</code> does not equal <code>{&quot;NS&quot;:[&quot;6&quot;, &quot;2&quot;, &quot;1&quot;]}</code>.</p> <p/> </li> <li> <p> <code>NE</code> : Not equal. <code>NE</code> is supported for all data types, including lists and maps.</p> <p> <code>AttributeValueList</code> can contain only one <code>AttributeValue</code> of type String, Number, Binary, String Set, Number Set, or Binary Set. If an item contains an <code>AttributeValue</code> of a different type than the one provided in the request, the value does not match. For example, <code>{&quot;S&quot;:&quot;6&quot;}</code> does not equal <code>{&quot;N&quot;:&quot;6&quot;}</code>. Also, <code>{&quot;N&quot;:&quot;6&quot;}</code> does not equal <code>{&quot;NS&quot;:[&quot;6&quot;, &quot;2&quot;, &quot;1&quot;]}</code>.</p> <p/> </li> <li> <p> <code>LE</code> : Less than or equal. </p> <p> <code>AttributeValueList</code> can contain only one <code>AttributeValue</code> element of type String, Number, or Binary (not a set type). If an item contains an <code>AttributeValue</code> element of a different type than the one provided in the request, the value does not match. For example, <code>{&quot;S&quot;:&quot;6&quot;}</code> does not equal <code>{&quot;N&quot;:&quot;6&quot;}</code>. Also, <code>{&quot;N&quot;:&quot;6&quot;}</code> does not compare to <code>{&quot;NS&quot;:[&quot;6&quot;, &quot;2&quot;, &quot;1&quot;]}</code>.</p> <p/> </li> <li> <p> <code>LT</code> : Less than. </p> <p> <code>AttributeValueList</code> can contain only one <code>AttributeValue</code> of type String, Number, or Binary (not a set type). If an item contains an <code>AttributeValue</code> element of a different type than the one provided in the request, the value does not match. For example, <code>{&quot;S&quot;:&quot;6&quot;}</code> does not equal <code>{&quot;N&quot;:&quot;6&quot;}</code>. Also, <code>{&quot;N&quot;:&quot;6&quot;}</code> does not compare to <code>{&quot;NS&quot;:[&quot;6&quot;, &quot;2&quot;, &quot;1&quot;]}</code>.</p> <p/> </li> <li> <p> <code>GE</code> : Greater than or equal. </p> <p> <code>AttributeValueList</code> can contain only one <code>AttributeValue</code> element of type String, Number, or Binary (not a set type). If an item contains an <code>AttributeValue</code> element of a different type than the one provided in the request, the value does not match. For example, <code>{&quot;S&quot;:&quot;6&quot;}</code> does not equal <code>{&quot;N&quot;:&quot;6&quot;}</code>. Also, <code>{&quot;N&quot;:&quot;6&quot;}</code> does not compare to <code>{&quot;NS&quot;:[&quot;6&quot;, &quot;2&quot;, &quot;1&quot;]}</code>.</p> <p/> </li> <li> <p> <code>GT</code> : Greater than. </p> <p> <code>AttributeValueList</code> can contain only one <code>AttributeValue</code> element of type String, Number, or Binary (not a set type). If an item contains an <code>AttributeValue</code> element of a different type than the one provided in the request, the value does not match. For example, <code>{&quot;S&quot;:&quot;6&quot;}</code> does not equal <code>{&quot;N&quot;:&quot;6&quot;}</code>. Also, <code>{&quot;N&quot;:&quot;6&quot;}</code> does not compare to <code>{&quot;NS&quot;:[&quot;6&quot;, &quot;2&quot;, &quot;1&quot;]}</code>.</p> <p/> </li> <li> <p> <code>NOT</em>NULL</code> : The attribute exists. <code>NOT<em>NULL</code> is supported for all data types, including lists and maps.</p> <note> <p>This operator tests for the existence of an attribute, not its data type. If the data type of attribute &quot;<code>a</code>&quot; is null, and you evaluate it using <code>NOT</em>NULL</code>, the result is a Boolean <code>true</code>. This result is because the attribute &quot;<code>a</code>&quot; exists; its data type is not relevant to the <code>NOT<em>NULL</code> comparison operator.</p> </note> </li> <li> <p> <code>NULL</code> : The attribute does not exist. <code>NULL</code> is supported for all data types, including lists and maps.</p> <note> <p>This operator tests for the nonexistence of an attribute, not its data type. If the data type of attribute &quot;<code>a</code>&quot; is null, and you evaluate it using <code>NULL</code>, the result is a Boolean <code>false</code>. This is because the attribute &quot;<code>a</code>&quot; exists; its data type is not relevant to the <code>NULL</code> comparison operator.</p> </note> </li> <li> <p> <code>CONTAINS</code> : Checks for a subsequence, or value in a set.</p> <p> <code>AttributeValueList</code> can contain only one <code>AttributeValue</code> element of type String, Number, or Binary (not a set type). If the target attribute of the comparison is of type String, then the operator checks for a substring match. If the target attribute of the comparison is of type Binary, then the operator looks for a subsequence of the target that matches the input. If the target attribute of the comparison is a set (&quot;<code>SS</code>&quot;, &quot;<code>NS</code>&quot;, or &quot;<code>BS</code>&quot;), then the operator evaluates to true if it finds an exact match with any member of the set.</p> <p>CONTAINS is supported for lists: When evaluating &quot;<code>a CONTAINS b</code>&quot;, &quot;<code>a</code>&quot; can be a list; however, &quot;<code>b</code>&quot; cannot be a set, a map, or a list.</p> </li> <li> <p> <code>NOT</em>CONTAINS</code> : Checks for absence of a subsequence, or absence of a value in a set.</p> <p> <code>AttributeValueList</code> can contain only one <code>AttributeValue</code> element of type String, Number, or Binary (not a set type). If the target attribute of the comparison is a String, then the operator checks for the absence of a substring match. If the target attribute of the comparison is Binary, then the operator checks for the absence of a subsequence of the target that matches the input. If the target attribute of the comparison is a set (&quot;<code>SS</code>&quot;, &quot;<code>NS</code>&quot;, or &quot;<code>BS</code>&quot;), then the operator evaluates to true if it <i>does not</i> find an exact match with any member of the set.</p> <p>NOT<em>CONTAINS is supported for lists: When evaluating &quot;<code>a NOT CONTAINS b</code>&quot;, &quot;<code>a</code>&quot; can be a list; however, &quot;<code>b</code>&quot; cannot be a set, a map, or a list.</p> </li> <li> <p> <code>BEGINS</em>WITH</code> : Checks for a prefix. </p> <p> <code>AttributeValueList</code> can contain only one <code>AttributeValue</code> of type String or Binary (not a Number or a set type). The target attribute of the comparison must be of type String or Binary (not a Number or a set type).</p> <p/> </li> <li> <p> <code>IN</code> : Checks for matching elements in a list.</p> <p> <code>AttributeValueList</code> can contain one or more <code>AttributeValue</code> elements of type String, Number, or Binary. These attributes are compared against an existing attribute of an item. If any elements of the input are equal to the item attribute, the expression evaluates to true.</p> </li> <li> <p> <code>BETWEEN</code> : Greater than or equal to the first value, and less than or equal to the second value. </p> <p> <code>AttributeValueList</code> must contain two <code>AttributeValue</code> elements of the same type, either String, Number, or Binary (not a set type). A target attribute matches if the target value is greater than, or equal to, the first element and less than, or equal to, the second element. If an item contains an <code>AttributeValue</code> element of a different type than the one provided in the request, the value does not match. For example, <code>{&quot;S&quot;:&quot;6&quot;}</code> does not compare to <code>{&quot;N&quot;:&quot;6&quot;}</code>. Also, <code>{&quot;N&quot;:&quot;6&quot;}</code> does not compare to <code>{&quot;NS&quot;:[&quot;6&quot;, &quot;2&quot;, &quot;1&quot;]}</code> </p> </li> </ul></p>
     #[serde(rename = "ComparisonOperator")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub comparison_operator: Option<String>,
+    pub comparison_operator: Option<ComparisonOperator>,
     /// <p><p>Causes DynamoDB to evaluate the value before attempting a conditional operation:</p> <ul> <li> <p>If <code>Exists</code> is <code>true</code>, DynamoDB will check to see if that attribute value already exists in the table. If it is found, then the operation succeeds. If it is not found, the operation fails with a <code>ConditionCheckFailedException</code>.</p> </li> <li> <p>If <code>Exists</code> is <code>false</code>, DynamoDB assumes that the attribute value does not exist in the table. If in fact the value does not exist, then the assumption is valid and the operation succeeds. If the value is found, despite the assumption that it does not exist, the operation fails with a <code>ConditionCheckFailedException</code>.</p> </li> </ul> <p>The default setting for <code>Exists</code> is <code>true</code>. If you supply a <code>Value</code> all by itself, DynamoDB assumes the attribute exists: You don&#39;t have to set <code>Exists</code> to <code>true</code>, because it is implied.</p> <p>DynamoDB returns a <code>ValidationException</code> if:</p> <ul> <li> <p> <code>Exists</code> is <code>true</code> but there is no <code>Value</code> to check. (You expect a value to exist, but don&#39;t specify what that value is.)</p> </li> <li> <p> <code>Exists</code> is <code>false</code> but you also provide a <code>Value</code>. (You cannot expect an attribute to have a value, while also expecting it not to exist.)</p> </li> </ul></p>
     #[serde(rename = "Exists")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1156,7 +2216,7 @@ pub struct GetItemInput {
     pub projection_expression: Option<String>,
     #[serde(rename = "ReturnConsumedCapacity")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub return_consumed_capacity: Option<String>,
+    pub return_consumed_capacity: Option<ReturnConsumedCapacity>,
     /// <p>The name of the table containing the requested item.</p>
     #[serde(rename = "TableName")]
     pub table_name: String,
@@ -1231,7 +2291,7 @@ pub struct GlobalSecondaryIndexDescription {
     /// <p><p>The current state of the global secondary index:</p> <ul> <li> <p> <code>CREATING</code> - The index is being created.</p> </li> <li> <p> <code>UPDATING</code> - The index is being updated.</p> </li> <li> <p> <code>DELETING</code> - The index is being deleted.</p> </li> <li> <p> <code>ACTIVE</code> - The index is ready for use.</p> </li> </ul></p>
     #[serde(rename = "IndexStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub index_status: Option<String>,
+    pub index_status: Option<IndexStatus>,
     /// <p>The number of items in the specified index. DynamoDB updates this value approximately every six hours. Recent changes might not be reflected in this value.</p>
     #[serde(rename = "ItemCount")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1310,8 +2370,13 @@ pub struct GlobalTable {
 pub struct GlobalTableDescription {
     /// <p>The creation time of the global table.</p>
     #[serde(rename = "CreationDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date_time: Option<f64>,
+    pub creation_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The unique identifier of the global table.</p>
     #[serde(rename = "GlobalTableArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1323,7 +2388,7 @@ pub struct GlobalTableDescription {
     /// <p><p>The current state of the global table:</p> <ul> <li> <p> <code>CREATING</code> - The global table is being created.</p> </li> <li> <p> <code>UPDATING</code> - The global table is being updated.</p> </li> <li> <p> <code>DELETING</code> - The global table is being deleted.</p> </li> <li> <p> <code>ACTIVE</code> - The global table is ready for use.</p> </li> </ul></p>
     #[serde(rename = "GlobalTableStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub global_table_status: Option<String>,
+    pub global_table_status: Option<GlobalTableStatus>,
     /// <p>The Regions where the global table has replicas.</p>
     #[serde(rename = "ReplicationGroup")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1347,25 +2412,231 @@ pub struct GlobalTableGlobalSecondaryIndexSettingsUpdate {
     pub provisioned_write_capacity_units: Option<i64>,
 }
 
-/// <p>Information about item collections, if any, that were affected by the operation. <code>ItemCollectionMetrics</code> is only returned if the request asked for it. If the table does not have any local secondary indexes, this information is not returned in the response.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct ItemCollectionMetrics {
-    /// <p>The partition key value of the item collection. This value is the same as the partition key value of the item.</p>
-    #[serde(rename = "ItemCollectionKey")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub item_collection_key: Option<::std::collections::HashMap<String, AttributeValue>>,
-    /// <p>An estimate of item collection size, in gigabytes. This value is a two-element array containing a lower bound and an upper bound for the estimate. The estimate includes the size of all the items in the table, plus the size of all attributes projected into all of the local secondary indexes on that table. Use this estimate to measure whether a local secondary index is approaching its size limit.</p> <p>The estimate is subject to change over time; therefore, do not rely on the precision or accuracy of the estimate.</p>
-    #[serde(rename = "SizeEstimateRangeGB")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub size_estimate_range_gb: Option<Vec<f64>>,
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum GlobalTableStatus {
+    /// `CREATING`
+    Creating,
+    /// `ACTIVE`
+    Active,
+    /// `DELETING`
+    Deleting,
+    /// `UPDATING`
+    Updating,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl GlobalTableStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            GlobalTableStatus::Creating => "CREATING",
+            GlobalTableStatus::Active => "ACTIVE",
+            GlobalTableStatus::Deleting => "DELETING",
+            GlobalTableStatus::Updating => "UPDATING",
+            GlobalTableStatus::Unknown(ref value) => value,
+        }
+    }
 }
 
-/// <p>Details for the requested item.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct ItemResponse {
-    /// <p>Map of attribute data consisting of the data type and attribute value.</p>
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for GlobalTableStatus {
+    fn default() -> GlobalTableStatus {
+        GlobalTableStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for GlobalTableStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for GlobalTableStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for GlobalTableStatus {
+    fn from(value: &'a str) -> GlobalTableStatus {
+        match value {
+            "CREATING" => GlobalTableStatus::Creating,
+            "ACTIVE" => GlobalTableStatus::Active,
+            "DELETING" => GlobalTableStatus::Deleting,
+            "UPDATING" => GlobalTableStatus::Updating,
+            _ => GlobalTableStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for GlobalTableStatus {
+    fn from(value: String) -> GlobalTableStatus {
+        match value.as_str() {
+            "CREATING" => GlobalTableStatus::Creating,
+            "ACTIVE" => GlobalTableStatus::Active,
+            "DELETING" => GlobalTableStatus::Deleting,
+            "UPDATING" => GlobalTableStatus::Updating,
+            _ => GlobalTableStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for GlobalTableStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<GlobalTableStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for GlobalTableStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for GlobalTableStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for GlobalTableStatus {
+    fn deserialize<D>(deserializer: D) -> Result<GlobalTableStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum IndexStatus {
+    /// `CREATING`
+    Creating,
+    /// `UPDATING`
+    Updating,
+    /// `DELETING`
+    Deleting,
+    /// `ACTIVE`
+    Active,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl IndexStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            IndexStatus::Creating => "CREATING",
+            IndexStatus::Updating => "UPDATING",
+            IndexStatus::Deleting => "DELETING",
+            IndexStatus::Active => "ACTIVE",
+            IndexStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for IndexStatus {
+    fn default() -> IndexStatus {
+        IndexStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for IndexStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for IndexStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for IndexStatus {
+    fn from(value: &'a str) -> IndexStatus {
+        match value {
+            "CREATING" => IndexStatus::Creating,
+            "UPDATING" => IndexStatus::Updating,
+            "DELETING" => IndexStatus::Deleting,
+            "ACTIVE" => IndexStatus::Active,
+            _ => IndexStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for IndexStatus {
+    fn from(value: String) -> IndexStatus {
+        match value.as_str() {
+            "CREATING" => IndexStatus::Creating,
+            "UPDATING" => IndexStatus::Updating,
+            "DELETING" => IndexStatus::Deleting,
+            "ACTIVE" => IndexStatus::Active,
+            _ => IndexStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for IndexStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<IndexStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for IndexStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for IndexStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for IndexStatus {
+    fn deserialize<D>(deserializer: D) -> Result<IndexStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Information about item collections, if any, that were affected by the operation. <code>ItemCollectionMetrics</code> is only returned if the request asked for it. If the table does not have any local secondary indexes, this information is not returned in the response.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct ItemCollectionMetrics {
+    /// <p>The partition key value of the item collection. This value is the same as the partition key value of the item.</p>
+    #[serde(rename = "ItemCollectionKey")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub item_collection_key: Option<::std::collections::HashMap<String, AttributeValue>>,
+    /// <p>An estimate of item collection size, in gigabytes. This value is a two-element array containing a lower bound and an upper bound for the estimate. The estimate includes the size of all the items in the table, plus the size of all attributes projected into all of the local secondary indexes on that table. Use this estimate to measure whether a local secondary index is approaching its size limit.</p> <p>The estimate is subject to change over time; therefore, do not rely on the precision or accuracy of the estimate.</p>
+    #[serde(rename = "SizeEstimateRangeGB")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size_estimate_range_gb: Option<Vec<f64>>,
+}
+
+/// <p>Details for the requested item.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct ItemResponse {
+    /// <p>Map of attribute data consisting of the data type and attribute value.</p>
     #[serde(rename = "Item")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub item: Option<::std::collections::HashMap<String, AttributeValue>>,
@@ -1379,9 +2650,102 @@ pub struct KeySchemaElement {
     pub attribute_name: String,
     /// <p><p>The role that this key attribute will assume:</p> <ul> <li> <p> <code>HASH</code> - partition key</p> </li> <li> <p> <code>RANGE</code> - sort key</p> </li> </ul> <note> <p>The partition key of an item is also known as its <i>hash attribute</i>. The term &quot;hash attribute&quot; derives from DynamoDB&#39;s usage of an internal hash function to evenly distribute data items across partitions, based on their partition key values.</p> <p>The sort key of an item is also known as its <i>range attribute</i>. The term &quot;range attribute&quot; derives from the way DynamoDB stores items with the same partition key physically close together, in sorted order by the sort key value.</p> </note></p>
     #[serde(rename = "KeyType")]
-    pub key_type: String,
+    pub key_type: KeyType,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum KeyType {
+    /// `HASH`
+    Hash,
+    /// `RANGE`
+    Range,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl KeyType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            KeyType::Hash => "HASH",
+            KeyType::Range => "RANGE",
+            KeyType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for KeyType {
+    fn default() -> KeyType {
+        KeyType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for KeyType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for KeyType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for KeyType {
+    fn from(value: &'a str) -> KeyType {
+        match value {
+            "HASH" => KeyType::Hash,
+            "RANGE" => KeyType::Range,
+            _ => KeyType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for KeyType {
+    fn from(value: String) -> KeyType {
+        match value.as_str() {
+            "HASH" => KeyType::Hash,
+            "RANGE" => KeyType::Range,
+            _ => KeyType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for KeyType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<KeyType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for KeyType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for KeyType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for KeyType {
+    fn deserialize<D>(deserializer: D) -> Result<KeyType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents a set of primary keys and, for each key, the attributes to retrieve from the table.</p> <p>For each primary key, you must provide <i>all</i> of the key attributes. For example, with a simple primary key, you only need to provide the partition key. For a composite primary key, you must provide <i>both</i> the partition key and the sort key.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct KeysAndAttributes {
@@ -1412,7 +2776,7 @@ pub struct ListBackupsInput {
     /// <p><p>The backups from the table specified by <code>BackupType</code> are listed.</p> <p>Where <code>BackupType</code> can be:</p> <ul> <li> <p> <code>USER</code> - On-demand backup created by you.</p> </li> <li> <p> <code>SYSTEM</code> - On-demand backup automatically created by DynamoDB.</p> </li> <li> <p> <code>ALL</code> - All types of on-demand backups (USER and SYSTEM).</p> </li> </ul></p>
     #[serde(rename = "BackupType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub backup_type: Option<String>,
+    pub backup_type: Option<BackupTypeFilter>,
     /// <p> <code>LastEvaluatedBackupArn</code> is the Amazon Resource Name (ARN) of the backup last evaluated when the current page of results was returned, inclusive of the current page of results. This value may be specified as the <code>ExclusiveStartBackupArn</code> of a new <code>ListBackups</code> operation in order to fetch the next page of results. </p>
     #[serde(rename = "ExclusiveStartBackupArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1427,12 +2791,22 @@ pub struct ListBackupsInput {
     pub table_name: Option<String>,
     /// <p>Only backups created after this time are listed. <code>TimeRangeLowerBound</code> is inclusive.</p>
     #[serde(rename = "TimeRangeLowerBound")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub time_range_lower_bound: Option<f64>,
+    pub time_range_lower_bound: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Only backups created before this time are listed. <code>TimeRangeUpperBound</code> is exclusive. </p>
     #[serde(rename = "TimeRangeUpperBound")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub time_range_upper_bound: Option<f64>,
+    pub time_range_upper_bound: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1630,16 +3004,26 @@ pub struct LocalSecondaryIndexInfo {
 pub struct PointInTimeRecoveryDescription {
     /// <p>Specifies the earliest point in time you can restore your table to. You can restore your table to any point in time during the last 35 days. </p>
     #[serde(rename = "EarliestRestorableDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub earliest_restorable_date_time: Option<f64>,
+    pub earliest_restorable_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p> <code>LatestRestorableDateTime</code> is typically 5 minutes before the current time. </p>
     #[serde(rename = "LatestRestorableDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub latest_restorable_date_time: Option<f64>,
+    pub latest_restorable_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>The current state of point in time recovery:</p> <ul> <li> <p> <code>ENABLING</code> - Point in time recovery is being enabled.</p> </li> <li> <p> <code>ENABLED</code> - Point in time recovery is enabled.</p> </li> <li> <p> <code>DISABLED</code> - Point in time recovery is disabled.</p> </li> </ul></p>
     #[serde(rename = "PointInTimeRecoveryStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub point_in_time_recovery_status: Option<String>,
+    pub point_in_time_recovery_status: Option<PointInTimeRecoveryStatus>,
 }
 
 /// <p>Represents the settings used to enable point in time recovery.</p>
@@ -1651,6 +3035,99 @@ pub struct PointInTimeRecoverySpecification {
     pub point_in_time_recovery_enabled: bool,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PointInTimeRecoveryStatus {
+    /// `ENABLED`
+    Enabled,
+    /// `DISABLED`
+    Disabled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PointInTimeRecoveryStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PointInTimeRecoveryStatus::Enabled => "ENABLED",
+            PointInTimeRecoveryStatus::Disabled => "DISABLED",
+            PointInTimeRecoveryStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PointInTimeRecoveryStatus {
+    fn default() -> PointInTimeRecoveryStatus {
+        PointInTimeRecoveryStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PointInTimeRecoveryStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PointInTimeRecoveryStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PointInTimeRecoveryStatus {
+    fn from(value: &'a str) -> PointInTimeRecoveryStatus {
+        match value {
+            "ENABLED" => PointInTimeRecoveryStatus::Enabled,
+            "DISABLED" => PointInTimeRecoveryStatus::Disabled,
+            _ => PointInTimeRecoveryStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PointInTimeRecoveryStatus {
+    fn from(value: String) -> PointInTimeRecoveryStatus {
+        match value.as_str() {
+            "ENABLED" => PointInTimeRecoveryStatus::Enabled,
+            "DISABLED" => PointInTimeRecoveryStatus::Disabled,
+            _ => PointInTimeRecoveryStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PointInTimeRecoveryStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PointInTimeRecoveryStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PointInTimeRecoveryStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PointInTimeRecoveryStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PointInTimeRecoveryStatus {
+    fn deserialize<D>(deserializer: D) -> Result<PointInTimeRecoveryStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents attributes that are copied (projected) from the table into an index. These are in addition to the primary key attributes and index key attributes, which are automatically projected.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Projection {
@@ -1661,9 +3138,107 @@ pub struct Projection {
     /// <p><p>The set of attributes that are projected into the index:</p> <ul> <li> <p> <code>KEYS_ONLY</code> - Only the index and primary keys are projected into the index.</p> </li> <li> <p> <code>INCLUDE</code> - Only the specified table attributes are projected into the index. The list of projected attributes is in <code>NonKeyAttributes</code>.</p> </li> <li> <p> <code>ALL</code> - All of the table attributes are projected into the index.</p> </li> </ul></p>
     #[serde(rename = "ProjectionType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub projection_type: Option<String>,
+    pub projection_type: Option<ProjectionType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ProjectionType {
+    /// `ALL`
+    All,
+    /// `KEYS_ONLY`
+    KeysOnly,
+    /// `INCLUDE`
+    Include,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ProjectionType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ProjectionType::All => "ALL",
+            ProjectionType::KeysOnly => "KEYS_ONLY",
+            ProjectionType::Include => "INCLUDE",
+            ProjectionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ProjectionType {
+    fn default() -> ProjectionType {
+        ProjectionType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ProjectionType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ProjectionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ProjectionType {
+    fn from(value: &'a str) -> ProjectionType {
+        match value {
+            "ALL" => ProjectionType::All,
+            "KEYS_ONLY" => ProjectionType::KeysOnly,
+            "INCLUDE" => ProjectionType::Include,
+            _ => ProjectionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ProjectionType {
+    fn from(value: String) -> ProjectionType {
+        match value.as_str() {
+            "ALL" => ProjectionType::All,
+            "KEYS_ONLY" => ProjectionType::KeysOnly,
+            "INCLUDE" => ProjectionType::Include,
+            _ => ProjectionType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ProjectionType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ProjectionType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ProjectionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ProjectionType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for ProjectionType {
+    fn deserialize<D>(deserializer: D) -> Result<ProjectionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents the provisioned throughput settings for a specified table or index. The settings can be modified using the <code>UpdateTable</code> operation.</p> <p>For current minimum and maximum provisioned throughput values, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Limits.html">Limits</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ProvisionedThroughput {
@@ -1681,12 +3256,22 @@ pub struct ProvisionedThroughput {
 pub struct ProvisionedThroughputDescription {
     /// <p>The date and time of the last provisioned throughput decrease for this table.</p>
     #[serde(rename = "LastDecreaseDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_decrease_date_time: Option<f64>,
+    pub last_decrease_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The date and time of the last provisioned throughput increase for this table.</p>
     #[serde(rename = "LastIncreaseDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_increase_date_time: Option<f64>,
+    pub last_increase_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The number of provisioned throughput decreases for this table during this UTC calendar day. For current maximums on provisioned throughput decreases, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Limits.html">Limits</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
     #[serde(rename = "NumberOfDecreasesToday")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1732,7 +3317,7 @@ pub struct Put {
     /// <p>Use <code>ReturnValuesOnConditionCheckFailure</code> to get the item attributes if the <code>Put</code> condition fails. For <code>ReturnValuesOnConditionCheckFailure</code>, the valid values are: NONE and ALL_OLD.</p>
     #[serde(rename = "ReturnValuesOnConditionCheckFailure")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub return_values_on_condition_check_failure: Option<String>,
+    pub return_values_on_condition_check_failure: Option<ReturnValuesOnConditionCheckFailure>,
     /// <p>Name of the table in which to write the item.</p>
     #[serde(rename = "TableName")]
     pub table_name: String,
@@ -1749,7 +3334,7 @@ pub struct PutItemInput {
     /// <p>This is a legacy parameter. Use <code>ConditionExpression</code> instead. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/LegacyConditionalParameters.ConditionalOperator.html">ConditionalOperator</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
     #[serde(rename = "ConditionalOperator")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub conditional_operator: Option<String>,
+    pub conditional_operator: Option<ConditionalOperator>,
     /// <p>This is a legacy parameter. Use <code>ConditionExpression</code> instead. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/LegacyConditionalParameters.Expected.html">Expected</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
     #[serde(rename = "Expected")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1767,15 +3352,15 @@ pub struct PutItemInput {
     pub item: ::std::collections::HashMap<String, AttributeValue>,
     #[serde(rename = "ReturnConsumedCapacity")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub return_consumed_capacity: Option<String>,
+    pub return_consumed_capacity: Option<ReturnConsumedCapacity>,
     /// <p>Determines whether item collection metrics are returned. If set to <code>SIZE</code>, the response includes statistics about item collections, if any, that were modified during the operation are returned in the response. If set to <code>NONE</code> (the default), no statistics are returned.</p>
     #[serde(rename = "ReturnItemCollectionMetrics")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub return_item_collection_metrics: Option<String>,
+    pub return_item_collection_metrics: Option<ReturnItemCollectionMetrics>,
     /// <p><p>Use <code>ReturnValues</code> if you want to get the item attributes as they appeared before they were updated with the <code>PutItem</code> request. For <code>PutItem</code>, the valid values are:</p> <ul> <li> <p> <code>NONE</code> - If <code>ReturnValues</code> is not specified, or if its value is <code>NONE</code>, then nothing is returned. (This setting is the default for <code>ReturnValues</code>.)</p> </li> <li> <p> <code>ALL<em>OLD</code> - If <code>PutItem</code> overwrote an attribute name-value pair, then the content of the old item is returned.</p> </li> </ul> <note> <p>The <code>ReturnValues</code> parameter is used by several DynamoDB operations; however, <code>PutItem</code> does not recognize any values other than <code>NONE</code> or <code>ALL</em>OLD</code>.</p> </note></p>
     #[serde(rename = "ReturnValues")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub return_values: Option<String>,
+    pub return_values: Option<ReturnValue>,
     /// <p>The name of the table to contain the item.</p>
     #[serde(rename = "TableName")]
     pub table_name: String,
@@ -1818,7 +3403,7 @@ pub struct QueryInput {
     /// <p>This is a legacy parameter. Use <code>FilterExpression</code> instead. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/LegacyConditionalParameters.ConditionalOperator.html">ConditionalOperator</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
     #[serde(rename = "ConditionalOperator")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub conditional_operator: Option<String>,
+    pub conditional_operator: Option<ConditionalOperator>,
     /// <p>Determines the read consistency model: If set to <code>true</code>, then the operation uses strongly consistent reads; otherwise, the operation uses eventually consistent reads.</p> <p>Strongly consistent reads are not supported on global secondary indexes. If you query a global secondary index with <code>ConsistentRead</code> set to <code>true</code>, you will receive a <code>ValidationException</code>.</p>
     #[serde(rename = "ConsistentRead")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1865,7 +3450,7 @@ pub struct QueryInput {
     pub query_filter: Option<::std::collections::HashMap<String, Condition>>,
     #[serde(rename = "ReturnConsumedCapacity")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub return_consumed_capacity: Option<String>,
+    pub return_consumed_capacity: Option<ReturnConsumedCapacity>,
     /// <p>Specifies the order for index traversal: If <code>true</code> (default), the traversal is performed in ascending order; if <code>false</code>, the traversal is performed in descending order. </p> <p>Items with the same partition key value are stored in sorted order by sort key. If the sort key data type is Number, the results are stored in numeric order. For type String, the results are stored in order of UTF-8 bytes. For type Binary, DynamoDB treats each byte of the binary data as unsigned.</p> <p>If <code>ScanIndexForward</code> is <code>true</code>, DynamoDB returns the results in the order in which they are stored (by sort key value). This is the default behavior. If <code>ScanIndexForward</code> is <code>false</code>, DynamoDB reads the results in reverse order by sort key value, and then returns the results to the client.</p>
     #[serde(rename = "ScanIndexForward")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1873,7 +3458,7 @@ pub struct QueryInput {
     /// <p><p>The attributes to be returned in the result. You can retrieve all item attributes, specific item attributes, the count of matching items, or in the case of an index, some or all of the attributes projected into the index.</p> <ul> <li> <p> <code>ALL<em>ATTRIBUTES</code> - Returns all of the item attributes from the specified table or index. If you query a local secondary index, then for each matching item in the index, DynamoDB fetches the entire item from the parent table. If the index is configured to project all item attributes, then all of the data can be obtained from the local secondary index, and no fetching is required.</p> </li> <li> <p> <code>ALL</em>PROJECTED<em>ATTRIBUTES</code> - Allowed only when querying an index. Retrieves all attributes that have been projected into the index. If the index is configured to project all attributes, this return value is equivalent to specifying <code>ALL</em>ATTRIBUTES</code>.</p> </li> <li> <p> <code>COUNT</code> - Returns the number of matching items, rather than the matching items themselves.</p> </li> <li> <p> <code>SPECIFIC<em>ATTRIBUTES</code> - Returns only the attributes listed in <code>AttributesToGet</code>. This return value is equivalent to specifying <code>AttributesToGet</code> without specifying any value for <code>Select</code>.</p> <p>If you query or scan a local secondary index and request only attributes that are projected into that index, the operation will read only the index and not the table. If any of the requested attributes are not projected into the local secondary index, DynamoDB fetches each of these attributes from the parent table. This extra fetching incurs additional throughput cost and latency.</p> <p>If you query or scan a global secondary index, you can only request attributes that are projected into the index. Global secondary index queries cannot fetch attributes from the parent table.</p> </li> </ul> <p>If neither <code>Select</code> nor <code>AttributesToGet</code> are specified, DynamoDB defaults to <code>ALL</em>ATTRIBUTES</code> when accessing a table, and <code>ALL<em>PROJECTED</em>ATTRIBUTES</code> when accessing an index. You cannot use both <code>Select</code> and <code>AttributesToGet</code> together in a single request, unless the value for <code>Select</code> is <code>SPECIFIC<em>ATTRIBUTES</code>. (This usage is equivalent to specifying <code>AttributesToGet</code> without any value for <code>Select</code>.)</p> <note> <p>If you use the <code>ProjectionExpression</code> parameter, then the value for <code>Select</code> can only be <code>SPECIFIC</em>ATTRIBUTES</code>. Any other value for <code>Select</code> will return an error.</p> </note></p>
     #[serde(rename = "Select")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub select: Option<String>,
+    pub select: Option<Select>,
     /// <p>The name of the table containing the requested items.</p>
     #[serde(rename = "TableName")]
     pub table_name: String,
@@ -1937,7 +3522,7 @@ pub struct ReplicaAutoScalingDescription {
     /// <p><p>The current state of the replica:</p> <ul> <li> <p> <code>CREATING</code> - The replica is being created.</p> </li> <li> <p> <code>UPDATING</code> - The replica is being updated.</p> </li> <li> <p> <code>DELETING</code> - The replica is being deleted.</p> </li> <li> <p> <code>ACTIVE</code> - The replica is ready for use.</p> </li> </ul></p>
     #[serde(rename = "ReplicaStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub replica_status: Option<String>,
+    pub replica_status: Option<ReplicaStatus>,
 }
 
 /// <p>Represents the auto scaling settings of a replica that will be modified.</p>
@@ -1980,7 +3565,7 @@ pub struct ReplicaDescription {
     /// <p><p>The current state of the replica:</p> <ul> <li> <p> <code>CREATING</code> - The replica is being created.</p> </li> <li> <p> <code>UPDATING</code> - The replica is being updated.</p> </li> <li> <p> <code>DELETING</code> - The replica is being deleted.</p> </li> <li> <p> <code>ACTIVE</code> - The replica is ready for use.</p> </li> </ul></p>
     #[serde(rename = "ReplicaStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub replica_status: Option<String>,
+    pub replica_status: Option<ReplicaStatus>,
     /// <p>Detailed information about the replica status.</p>
     #[serde(rename = "ReplicaStatusDescription")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2015,7 +3600,7 @@ pub struct ReplicaGlobalSecondaryIndexAutoScalingDescription {
     /// <p><p>The current state of the replica global secondary index:</p> <ul> <li> <p> <code>CREATING</code> - The index is being created.</p> </li> <li> <p> <code>UPDATING</code> - The index is being updated.</p> </li> <li> <p> <code>DELETING</code> - The index is being deleted.</p> </li> <li> <p> <code>ACTIVE</code> - The index is ready for use.</p> </li> </ul></p>
     #[serde(rename = "IndexStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub index_status: Option<String>,
+    pub index_status: Option<IndexStatus>,
     #[serde(rename = "ProvisionedReadCapacityAutoScalingSettings")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub provisioned_read_capacity_auto_scaling_settings: Option<AutoScalingSettingsDescription>,
@@ -2061,7 +3646,7 @@ pub struct ReplicaGlobalSecondaryIndexSettingsDescription {
     /// <p><p> The current status of the global secondary index:</p> <ul> <li> <p> <code>CREATING</code> - The global secondary index is being created.</p> </li> <li> <p> <code>UPDATING</code> - The global secondary index is being updated.</p> </li> <li> <p> <code>DELETING</code> - The global secondary index is being deleted.</p> </li> <li> <p> <code>ACTIVE</code> - The global secondary index is ready for use.</p> </li> </ul></p>
     #[serde(rename = "IndexStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub index_status: Option<String>,
+    pub index_status: Option<IndexStatus>,
     /// <p>Auto scaling settings for a global secondary index replica's read capacity units.</p>
     #[serde(rename = "ProvisionedReadCapacityAutoScalingSettings")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2134,7 +3719,7 @@ pub struct ReplicaSettingsDescription {
     /// <p><p>The current state of the Region:</p> <ul> <li> <p> <code>CREATING</code> - The Region is being created.</p> </li> <li> <p> <code>UPDATING</code> - The Region is being updated.</p> </li> <li> <p> <code>DELETING</code> - The Region is being deleted.</p> </li> <li> <p> <code>ACTIVE</code> - The Region is ready for use.</p> </li> </ul></p>
     #[serde(rename = "ReplicaStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub replica_status: Option<String>,
+    pub replica_status: Option<ReplicaStatus>,
 }
 
 /// <p>Represents the settings for a global table in a Region that will be modified.</p>
@@ -2160,27 +3745,151 @@ pub struct ReplicaSettingsUpdate {
     pub replica_provisioned_read_capacity_units: Option<i64>,
 }
 
-/// <p><p>Represents one of the following:</p> <ul> <li> <p>A new replica to be added to an existing global table.</p> </li> <li> <p>New parameters for an existing replica.</p> </li> <li> <p>An existing replica to be removed from an existing global table.</p> </li> </ul></p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct ReplicaUpdate {
-    /// <p>The parameters required for creating a replica on an existing global table.</p>
-    #[serde(rename = "Create")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub create: Option<CreateReplicaAction>,
-    /// <p>The name of the existing replica to be removed.</p>
-    #[serde(rename = "Delete")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub delete: Option<DeleteReplicaAction>,
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ReplicaStatus {
+    /// `CREATING`
+    Creating,
+    /// `CREATION_FAILED`
+    CreationFailed,
+    /// `UPDATING`
+    Updating,
+    /// `DELETING`
+    Deleting,
+    /// `ACTIVE`
+    Active,
+    /// `REGION_DISABLED`
+    RegionDisabled,
+    /// `INACCESSIBLE_ENCRYPTION_CREDENTIALS`
+    InaccessibleEncryptionCredentials,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ReplicaStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReplicaStatus::Creating => "CREATING",
+            ReplicaStatus::CreationFailed => "CREATION_FAILED",
+            ReplicaStatus::Updating => "UPDATING",
+            ReplicaStatus::Deleting => "DELETING",
+            ReplicaStatus::Active => "ACTIVE",
+            ReplicaStatus::RegionDisabled => "REGION_DISABLED",
+            ReplicaStatus::InaccessibleEncryptionCredentials => {
+                "INACCESSIBLE_ENCRYPTION_CREDENTIALS"
+            }
+            ReplicaStatus::Unknown(ref value) => value,
+        }
+    }
 }
 
-/// <p><p>Represents one of the following:</p> <ul> <li> <p>A new replica to be added to an existing regional table or global table. This request invokes the <code>CreateTableReplica</code> action in the destination Region.</p> </li> <li> <p>New parameters for an existing replica. This request invokes the <code>UpdateTable</code> action in the destination Region.</p> </li> <li> <p>An existing replica to be deleted. The request invokes the <code>DeleteTableReplica</code> action in the destination Region, deleting the replica and all if its items in the destination Region.</p> </li> </ul></p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct ReplicationGroupUpdate {
-    /// <p>The parameters required for creating a replica for the table.</p>
-    #[serde(rename = "Create")]
-    #[serde(skip_serializing_if = "Option::is_none")]
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ReplicaStatus {
+    fn default() -> ReplicaStatus {
+        ReplicaStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ReplicaStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ReplicaStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ReplicaStatus {
+    fn from(value: &'a str) -> ReplicaStatus {
+        match value {
+            "CREATING" => ReplicaStatus::Creating,
+            "CREATION_FAILED" => ReplicaStatus::CreationFailed,
+            "UPDATING" => ReplicaStatus::Updating,
+            "DELETING" => ReplicaStatus::Deleting,
+            "ACTIVE" => ReplicaStatus::Active,
+            "REGION_DISABLED" => ReplicaStatus::RegionDisabled,
+            "INACCESSIBLE_ENCRYPTION_CREDENTIALS" => {
+                ReplicaStatus::InaccessibleEncryptionCredentials
+            }
+            _ => ReplicaStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReplicaStatus {
+    fn from(value: String) -> ReplicaStatus {
+        match value.as_str() {
+            "CREATING" => ReplicaStatus::Creating,
+            "CREATION_FAILED" => ReplicaStatus::CreationFailed,
+            "UPDATING" => ReplicaStatus::Updating,
+            "DELETING" => ReplicaStatus::Deleting,
+            "ACTIVE" => ReplicaStatus::Active,
+            "REGION_DISABLED" => ReplicaStatus::RegionDisabled,
+            "INACCESSIBLE_ENCRYPTION_CREDENTIALS" => {
+                ReplicaStatus::InaccessibleEncryptionCredentials
+            }
+            _ => ReplicaStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ReplicaStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ReplicaStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ReplicaStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ReplicaStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ReplicaStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ReplicaStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p><p>Represents one of the following:</p> <ul> <li> <p>A new replica to be added to an existing global table.</p> </li> <li> <p>New parameters for an existing replica.</p> </li> <li> <p>An existing replica to be removed from an existing global table.</p> </li> </ul></p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct ReplicaUpdate {
+    /// <p>The parameters required for creating a replica on an existing global table.</p>
+    #[serde(rename = "Create")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub create: Option<CreateReplicaAction>,
+    /// <p>The name of the existing replica to be removed.</p>
+    #[serde(rename = "Delete")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delete: Option<DeleteReplicaAction>,
+}
+
+/// <p><p>Represents one of the following:</p> <ul> <li> <p>A new replica to be added to an existing regional table or global table. This request invokes the <code>CreateTableReplica</code> action in the destination Region.</p> </li> <li> <p>New parameters for an existing replica. This request invokes the <code>UpdateTable</code> action in the destination Region.</p> </li> <li> <p>An existing replica to be deleted. The request invokes the <code>DeleteTableReplica</code> action in the destination Region, deleting the replica and all if its items in the destination Region.</p> </li> </ul></p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct ReplicationGroupUpdate {
+    /// <p>The parameters required for creating a replica for the table.</p>
+    #[serde(rename = "Create")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub create: Option<CreateReplicationGroupMemberAction>,
     /// <p>The parameters required for deleting a replica for the table.</p>
     #[serde(rename = "Delete")]
@@ -2198,7 +3907,12 @@ pub struct ReplicationGroupUpdate {
 pub struct RestoreSummary {
     /// <p>Point in time or source backup time.</p>
     #[serde(rename = "RestoreDateTime")]
-    pub restore_date_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub restore_date_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>Indicates if a restore is in progress or not.</p>
     #[serde(rename = "RestoreInProgress")]
     pub restore_in_progress: bool,
@@ -2221,7 +3935,7 @@ pub struct RestoreTableFromBackupInput {
     /// <p>The billing mode of the restored table.</p>
     #[serde(rename = "BillingModeOverride")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub billing_mode_override: Option<String>,
+    pub billing_mode_override: Option<BillingMode>,
     /// <p>List of global secondary indexes for the restored table. The indexes provided should match existing secondary indexes. You can choose to exclude some or all of the indexes at the time of restore.</p>
     #[serde(rename = "GlobalSecondaryIndexOverride")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2254,7 +3968,7 @@ pub struct RestoreTableToPointInTimeInput {
     /// <p>The billing mode of the restored table.</p>
     #[serde(rename = "BillingModeOverride")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub billing_mode_override: Option<String>,
+    pub billing_mode_override: Option<BillingMode>,
     /// <p>List of global secondary indexes for the restored table. The indexes provided should match existing secondary indexes. You can choose to exclude some or all of the indexes at the time of restore.</p>
     #[serde(rename = "GlobalSecondaryIndexOverride")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2269,8 +3983,13 @@ pub struct RestoreTableToPointInTimeInput {
     pub provisioned_throughput_override: Option<ProvisionedThroughput>,
     /// <p>Time in the past to restore the table to.</p>
     #[serde(rename = "RestoreDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub restore_date_time: Option<f64>,
+    pub restore_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Name of the source table that is being restored.</p>
     #[serde(rename = "SourceTableName")]
     pub source_table_name: String,
@@ -2292,14 +4011,412 @@ pub struct RestoreTableToPointInTimeOutput {
     pub table_description: Option<TableDescription>,
 }
 
+/// <p>Determines the level of detail about either provisioned or on-demand throughput consumption that is returned in the response:</p> <ul> <li> <p> <code>INDEXES</code> - The response includes the aggregate <code>ConsumedCapacity</code> for the operation, together with <code>ConsumedCapacity</code> for each table and secondary index that was accessed.</p> <p>Note that some operations, such as <code>GetItem</code> and <code>BatchGetItem</code>, do not access any indexes at all. In these cases, specifying <code>INDEXES</code> will only return <code>ConsumedCapacity</code> information for table(s).</p> </li> <li> <p> <code>TOTAL</code> - The response includes only the aggregate <code>ConsumedCapacity</code> for the operation.</p> </li> <li> <p> <code>NONE</code> - No <code>ConsumedCapacity</code> details are included in the response.</p> </li> </ul>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ReturnConsumedCapacity {
+    /// `INDEXES`
+    Indexes,
+    /// `TOTAL`
+    Total,
+    /// `NONE`
+    None,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ReturnConsumedCapacity {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReturnConsumedCapacity::Indexes => "INDEXES",
+            ReturnConsumedCapacity::Total => "TOTAL",
+            ReturnConsumedCapacity::None => "NONE",
+            ReturnConsumedCapacity::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ReturnConsumedCapacity {
+    fn default() -> ReturnConsumedCapacity {
+        ReturnConsumedCapacity::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ReturnConsumedCapacity {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ReturnConsumedCapacity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ReturnConsumedCapacity {
+    fn from(value: &'a str) -> ReturnConsumedCapacity {
+        match value {
+            "INDEXES" => ReturnConsumedCapacity::Indexes,
+            "TOTAL" => ReturnConsumedCapacity::Total,
+            "NONE" => ReturnConsumedCapacity::None,
+            _ => ReturnConsumedCapacity::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReturnConsumedCapacity {
+    fn from(value: String) -> ReturnConsumedCapacity {
+        match value.as_str() {
+            "INDEXES" => ReturnConsumedCapacity::Indexes,
+            "TOTAL" => ReturnConsumedCapacity::Total,
+            "NONE" => ReturnConsumedCapacity::None,
+            _ => ReturnConsumedCapacity::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ReturnConsumedCapacity {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ReturnConsumedCapacity, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ReturnConsumedCapacity {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ReturnConsumedCapacity {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ReturnConsumedCapacity {
+    fn deserialize<D>(deserializer: D) -> Result<ReturnConsumedCapacity, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ReturnItemCollectionMetrics {
+    /// `SIZE`
+    Size,
+    /// `NONE`
+    None,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ReturnItemCollectionMetrics {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReturnItemCollectionMetrics::Size => "SIZE",
+            ReturnItemCollectionMetrics::None => "NONE",
+            ReturnItemCollectionMetrics::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ReturnItemCollectionMetrics {
+    fn default() -> ReturnItemCollectionMetrics {
+        ReturnItemCollectionMetrics::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ReturnItemCollectionMetrics {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ReturnItemCollectionMetrics {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ReturnItemCollectionMetrics {
+    fn from(value: &'a str) -> ReturnItemCollectionMetrics {
+        match value {
+            "SIZE" => ReturnItemCollectionMetrics::Size,
+            "NONE" => ReturnItemCollectionMetrics::None,
+            _ => ReturnItemCollectionMetrics::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReturnItemCollectionMetrics {
+    fn from(value: String) -> ReturnItemCollectionMetrics {
+        match value.as_str() {
+            "SIZE" => ReturnItemCollectionMetrics::Size,
+            "NONE" => ReturnItemCollectionMetrics::None,
+            _ => ReturnItemCollectionMetrics::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ReturnItemCollectionMetrics {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ReturnItemCollectionMetrics, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ReturnItemCollectionMetrics {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ReturnItemCollectionMetrics {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ReturnItemCollectionMetrics {
+    fn deserialize<D>(deserializer: D) -> Result<ReturnItemCollectionMetrics, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ReturnValue {
+    /// `NONE`
+    None,
+    /// `ALL_OLD`
+    AllOld,
+    /// `UPDATED_OLD`
+    UpdatedOld,
+    /// `ALL_NEW`
+    AllNew,
+    /// `UPDATED_NEW`
+    UpdatedNew,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ReturnValue {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReturnValue::None => "NONE",
+            ReturnValue::AllOld => "ALL_OLD",
+            ReturnValue::UpdatedOld => "UPDATED_OLD",
+            ReturnValue::AllNew => "ALL_NEW",
+            ReturnValue::UpdatedNew => "UPDATED_NEW",
+            ReturnValue::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ReturnValue {
+    fn default() -> ReturnValue {
+        ReturnValue::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ReturnValue {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ReturnValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ReturnValue {
+    fn from(value: &'a str) -> ReturnValue {
+        match value {
+            "NONE" => ReturnValue::None,
+            "ALL_OLD" => ReturnValue::AllOld,
+            "UPDATED_OLD" => ReturnValue::UpdatedOld,
+            "ALL_NEW" => ReturnValue::AllNew,
+            "UPDATED_NEW" => ReturnValue::UpdatedNew,
+            _ => ReturnValue::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReturnValue {
+    fn from(value: String) -> ReturnValue {
+        match value.as_str() {
+            "NONE" => ReturnValue::None,
+            "ALL_OLD" => ReturnValue::AllOld,
+            "UPDATED_OLD" => ReturnValue::UpdatedOld,
+            "ALL_NEW" => ReturnValue::AllNew,
+            "UPDATED_NEW" => ReturnValue::UpdatedNew,
+            _ => ReturnValue::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ReturnValue {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ReturnValue, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ReturnValue {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ReturnValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ReturnValue {
+    fn deserialize<D>(deserializer: D) -> Result<ReturnValue, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ReturnValuesOnConditionCheckFailure {
+    /// `ALL_OLD`
+    AllOld,
+    /// `NONE`
+    None,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ReturnValuesOnConditionCheckFailure {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReturnValuesOnConditionCheckFailure::AllOld => "ALL_OLD",
+            ReturnValuesOnConditionCheckFailure::None => "NONE",
+            ReturnValuesOnConditionCheckFailure::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ReturnValuesOnConditionCheckFailure {
+    fn default() -> ReturnValuesOnConditionCheckFailure {
+        ReturnValuesOnConditionCheckFailure::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ReturnValuesOnConditionCheckFailure {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ReturnValuesOnConditionCheckFailure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ReturnValuesOnConditionCheckFailure {
+    fn from(value: &'a str) -> ReturnValuesOnConditionCheckFailure {
+        match value {
+            "ALL_OLD" => ReturnValuesOnConditionCheckFailure::AllOld,
+            "NONE" => ReturnValuesOnConditionCheckFailure::None,
+            _ => ReturnValuesOnConditionCheckFailure::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReturnValuesOnConditionCheckFailure {
+    fn from(value: String) -> ReturnValuesOnConditionCheckFailure {
+        match value.as_str() {
+            "ALL_OLD" => ReturnValuesOnConditionCheckFailure::AllOld,
+            "NONE" => ReturnValuesOnConditionCheckFailure::None,
+            _ => ReturnValuesOnConditionCheckFailure::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ReturnValuesOnConditionCheckFailure {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ReturnValuesOnConditionCheckFailure, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ReturnValuesOnConditionCheckFailure {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ReturnValuesOnConditionCheckFailure {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ReturnValuesOnConditionCheckFailure {
+    fn deserialize<D>(deserializer: D) -> Result<ReturnValuesOnConditionCheckFailure, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The description of the server-side encryption status on the specified table.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SSEDescription {
     /// <p>Indicates the time, in UNIX epoch date format, when DynamoDB detected that the table's AWS KMS key was inaccessible. This attribute will automatically be cleared when DynamoDB detects that the table's AWS KMS key is accessible again. DynamoDB will initiate the table archival process when table's AWS KMS key remains inaccessible for more than seven days from this date.</p>
     #[serde(rename = "InaccessibleEncryptionDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub inaccessible_encryption_date_time: Option<f64>,
+    pub inaccessible_encryption_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The AWS KMS customer master key (CMK) ARN used for the AWS KMS encryption.</p>
     #[serde(rename = "KMSMasterKeyArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2307,31 +4424,330 @@ pub struct SSEDescription {
     /// <p><p>Server-side encryption type. The only supported value is:</p> <ul> <li> <p> <code>KMS</code> - Server-side encryption that uses AWS Key Management Service. The key is stored in your account and is managed by AWS KMS (AWS KMS charges apply).</p> </li> </ul></p>
     #[serde(rename = "SSEType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sse_type: Option<String>,
+    pub sse_type: Option<SSEType>,
     /// <p><p>Represents the current state of server-side encryption. The only supported values are:</p> <ul> <li> <p> <code>ENABLED</code> - Server-side encryption is enabled.</p> </li> <li> <p> <code>UPDATING</code> - Server-side encryption is being updated.</p> </li> </ul></p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<SSEStatus>,
+}
+
+/// <p>Represents the settings used to enable server-side encryption.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct SSESpecification {
+    /// <p>Indicates whether server-side encryption is done using an AWS managed CMK or an AWS owned CMK. If enabled (true), server-side encryption type is set to <code>KMS</code> and an AWS managed CMK is used (AWS KMS charges apply). If disabled (false) or not specified, server-side encryption is set to AWS owned CMK.</p>
+    #[serde(rename = "Enabled")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    /// <p>The AWS KMS customer master key (CMK) that should be used for the AWS KMS encryption. To specify a CMK, use its key ID, Amazon Resource Name (ARN), alias name, or alias ARN. Note that you should only provide this parameter if the key is different from the default DynamoDB customer master key alias/aws/dynamodb.</p>
+    #[serde(rename = "KMSMasterKeyId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kms_master_key_id: Option<String>,
+    /// <p><p>Server-side encryption type. The only supported value is:</p> <ul> <li> <p> <code>KMS</code> - Server-side encryption that uses AWS Key Management Service. The key is stored in your account and is managed by AWS KMS (AWS KMS charges apply).</p> </li> </ul></p>
+    #[serde(rename = "SSEType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sse_type: Option<SSEType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SSEStatus {
+    /// `ENABLING`
+    Enabling,
+    /// `ENABLED`
+    Enabled,
+    /// `DISABLING`
+    Disabling,
+    /// `DISABLED`
+    Disabled,
+    /// `UPDATING`
+    Updating,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SSEStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SSEStatus::Enabling => "ENABLING",
+            SSEStatus::Enabled => "ENABLED",
+            SSEStatus::Disabling => "DISABLING",
+            SSEStatus::Disabled => "DISABLED",
+            SSEStatus::Updating => "UPDATING",
+            SSEStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SSEStatus {
+    fn default() -> SSEStatus {
+        SSEStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SSEStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SSEStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SSEStatus {
+    fn from(value: &'a str) -> SSEStatus {
+        match value {
+            "ENABLING" => SSEStatus::Enabling,
+            "ENABLED" => SSEStatus::Enabled,
+            "DISABLING" => SSEStatus::Disabling,
+            "DISABLED" => SSEStatus::Disabled,
+            "UPDATING" => SSEStatus::Updating,
+            _ => SSEStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SSEStatus {
+    fn from(value: String) -> SSEStatus {
+        match value.as_str() {
+            "ENABLING" => SSEStatus::Enabling,
+            "ENABLED" => SSEStatus::Enabled,
+            "DISABLING" => SSEStatus::Disabling,
+            "DISABLED" => SSEStatus::Disabled,
+            "UPDATING" => SSEStatus::Updating,
+            _ => SSEStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SSEStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SSEStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SSEStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SSEStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SSEStatus {
+    fn deserialize<D>(deserializer: D) -> Result<SSEStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SSEType {
+    /// `AES256`
+    Aes256,
+    /// `KMS`
+    Kms,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SSEType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SSEType::Aes256 => "AES256",
+            SSEType::Kms => "KMS",
+            SSEType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SSEType {
+    fn default() -> SSEType {
+        SSEType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SSEType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SSEType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SSEType {
+    fn from(value: &'a str) -> SSEType {
+        match value {
+            "AES256" => SSEType::Aes256,
+            "KMS" => SSEType::Kms,
+            _ => SSEType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SSEType {
+    fn from(value: String) -> SSEType {
+        match value.as_str() {
+            "AES256" => SSEType::Aes256,
+            "KMS" => SSEType::Kms,
+            _ => SSEType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SSEType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SSEType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SSEType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SSEType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SSEType {
+    fn deserialize<D>(deserializer: D) -> Result<SSEType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ScalarAttributeType {
+    /// `S`
+    S,
+    /// `N`
+    N,
+    /// `B`
+    B,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ScalarAttributeType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ScalarAttributeType::S => "S",
+            ScalarAttributeType::N => "N",
+            ScalarAttributeType::B => "B",
+            ScalarAttributeType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ScalarAttributeType {
+    fn default() -> ScalarAttributeType {
+        ScalarAttributeType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ScalarAttributeType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ScalarAttributeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ScalarAttributeType {
+    fn from(value: &'a str) -> ScalarAttributeType {
+        match value {
+            "S" => ScalarAttributeType::S,
+            "N" => ScalarAttributeType::N,
+            "B" => ScalarAttributeType::B,
+            _ => ScalarAttributeType::Unknown(value.to_owned()),
+        }
+    }
 }
 
-/// <p>Represents the settings used to enable server-side encryption.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct SSESpecification {
-    /// <p>Indicates whether server-side encryption is done using an AWS managed CMK or an AWS owned CMK. If enabled (true), server-side encryption type is set to <code>KMS</code> and an AWS managed CMK is used (AWS KMS charges apply). If disabled (false) or not specified, server-side encryption is set to AWS owned CMK.</p>
-    #[serde(rename = "Enabled")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub enabled: Option<bool>,
-    /// <p>The AWS KMS customer master key (CMK) that should be used for the AWS KMS encryption. To specify a CMK, use its key ID, Amazon Resource Name (ARN), alias name, or alias ARN. Note that you should only provide this parameter if the key is different from the default DynamoDB customer master key alias/aws/dynamodb.</p>
-    #[serde(rename = "KMSMasterKeyId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub kms_master_key_id: Option<String>,
-    /// <p><p>Server-side encryption type. The only supported value is:</p> <ul> <li> <p> <code>KMS</code> - Server-side encryption that uses AWS Key Management Service. The key is stored in your account and is managed by AWS KMS (AWS KMS charges apply).</p> </li> </ul></p>
-    #[serde(rename = "SSEType")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub sse_type: Option<String>,
+impl From<String> for ScalarAttributeType {
+    fn from(value: String) -> ScalarAttributeType {
+        match value.as_str() {
+            "S" => ScalarAttributeType::S,
+            "N" => ScalarAttributeType::N,
+            "B" => ScalarAttributeType::B,
+            _ => ScalarAttributeType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ScalarAttributeType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ScalarAttributeType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ScalarAttributeType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ScalarAttributeType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for ScalarAttributeType {
+    fn deserialize<D>(deserializer: D) -> Result<ScalarAttributeType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents the input of a <code>Scan</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -2343,7 +4759,7 @@ pub struct ScanInput {
     /// <p>This is a legacy parameter. Use <code>FilterExpression</code> instead. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/LegacyConditionalParameters.ConditionalOperator.html">ConditionalOperator</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
     #[serde(rename = "ConditionalOperator")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub conditional_operator: Option<String>,
+    pub conditional_operator: Option<ConditionalOperator>,
     /// <p>A Boolean value that determines the read consistency model during the scan:</p> <ul> <li> <p>If <code>ConsistentRead</code> is <code>false</code>, then the data returned from <code>Scan</code> might not contain the results from other recently completed write operations (<code>PutItem</code>, <code>UpdateItem</code>, or <code>DeleteItem</code>).</p> </li> <li> <p>If <code>ConsistentRead</code> is <code>true</code>, then all of the write operations that completed before the <code>Scan</code> began are guaranteed to be contained in the <code>Scan</code> response.</p> </li> </ul> <p>The default setting for <code>ConsistentRead</code> is <code>false</code>.</p> <p>The <code>ConsistentRead</code> parameter is not supported on global secondary indexes. If you scan a global secondary index with <code>ConsistentRead</code> set to true, you will receive a <code>ValidationException</code>.</p>
     #[serde(rename = "ConsistentRead")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2378,7 +4794,7 @@ pub struct ScanInput {
     pub projection_expression: Option<String>,
     #[serde(rename = "ReturnConsumedCapacity")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub return_consumed_capacity: Option<String>,
+    pub return_consumed_capacity: Option<ReturnConsumedCapacity>,
     /// <p>This is a legacy parameter. Use <code>FilterExpression</code> instead. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/LegacyConditionalParameters.ScanFilter.html">ScanFilter</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
     #[serde(rename = "ScanFilter")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2390,7 +4806,7 @@ pub struct ScanInput {
     /// <p><p>The attributes to be returned in the result. You can retrieve all item attributes, specific item attributes, the count of matching items, or in the case of an index, some or all of the attributes projected into the index.</p> <ul> <li> <p> <code>ALL<em>ATTRIBUTES</code> - Returns all of the item attributes from the specified table or index. If you query a local secondary index, then for each matching item in the index, DynamoDB fetches the entire item from the parent table. If the index is configured to project all item attributes, then all of the data can be obtained from the local secondary index, and no fetching is required.</p> </li> <li> <p> <code>ALL</em>PROJECTED<em>ATTRIBUTES</code> - Allowed only when querying an index. Retrieves all attributes that have been projected into the index. If the index is configured to project all attributes, this return value is equivalent to specifying <code>ALL</em>ATTRIBUTES</code>.</p> </li> <li> <p> <code>COUNT</code> - Returns the number of matching items, rather than the matching items themselves.</p> </li> <li> <p> <code>SPECIFIC<em>ATTRIBUTES</code> - Returns only the attributes listed in <code>AttributesToGet</code>. This return value is equivalent to specifying <code>AttributesToGet</code> without specifying any value for <code>Select</code>.</p> <p>If you query or scan a local secondary index and request only attributes that are projected into that index, the operation reads only the index and not the table. If any of the requested attributes are not projected into the local secondary index, DynamoDB fetches each of these attributes from the parent table. This extra fetching incurs additional throughput cost and latency.</p> <p>If you query or scan a global secondary index, you can only request attributes that are projected into the index. Global secondary index queries cannot fetch attributes from the parent table.</p> </li> </ul> <p>If neither <code>Select</code> nor <code>AttributesToGet</code> are specified, DynamoDB defaults to <code>ALL</em>ATTRIBUTES</code> when accessing a table, and <code>ALL<em>PROJECTED</em>ATTRIBUTES</code> when accessing an index. You cannot use both <code>Select</code> and <code>AttributesToGet</code> together in a single request, unless the value for <code>Select</code> is <code>SPECIFIC<em>ATTRIBUTES</code>. (This usage is equivalent to specifying <code>AttributesToGet</code> without any value for <code>Select</code>.)</p> <note> <p>If you use the <code>ProjectionExpression</code> parameter, then the value for <code>Select</code> can only be <code>SPECIFIC</em>ATTRIBUTES</code>. Any other value for <code>Select</code> will return an error.</p> </note></p>
     #[serde(rename = "Select")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub select: Option<String>,
+    pub select: Option<Select>,
     /// <p>The name of the table containing the requested items; or, if you provide <code>IndexName</code>, the name of the table to which that index belongs.</p>
     #[serde(rename = "TableName")]
     pub table_name: String,
@@ -2426,6 +4842,109 @@ pub struct ScanOutput {
     pub scanned_count: Option<i64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Select {
+    /// `ALL_ATTRIBUTES`
+    AllAttributes,
+    /// `ALL_PROJECTED_ATTRIBUTES`
+    AllProjectedAttributes,
+    /// `SPECIFIC_ATTRIBUTES`
+    SpecificAttributes,
+    /// `COUNT`
+    Count,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Select {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Select::AllAttributes => "ALL_ATTRIBUTES",
+            Select::AllProjectedAttributes => "ALL_PROJECTED_ATTRIBUTES",
+            Select::SpecificAttributes => "SPECIFIC_ATTRIBUTES",
+            Select::Count => "COUNT",
+            Select::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Select {
+    fn default() -> Select {
+        Select::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Select {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Select {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Select {
+    fn from(value: &'a str) -> Select {
+        match value {
+            "ALL_ATTRIBUTES" => Select::AllAttributes,
+            "ALL_PROJECTED_ATTRIBUTES" => Select::AllProjectedAttributes,
+            "SPECIFIC_ATTRIBUTES" => Select::SpecificAttributes,
+            "COUNT" => Select::Count,
+            _ => Select::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Select {
+    fn from(value: String) -> Select {
+        match value.as_str() {
+            "ALL_ATTRIBUTES" => Select::AllAttributes,
+            "ALL_PROJECTED_ATTRIBUTES" => Select::AllProjectedAttributes,
+            "SPECIFIC_ATTRIBUTES" => Select::SpecificAttributes,
+            "COUNT" => Select::Count,
+            _ => Select::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Select {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Select, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Select {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Select {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Select {
+    fn deserialize<D>(deserializer: D) -> Result<Select, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Contains the details of the table when the backup was created. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -2433,7 +4952,7 @@ pub struct SourceTableDetails {
     /// <p><p>Controls how you are charged for read and write throughput and how you manage capacity. This setting can be changed later.</p> <ul> <li> <p> <code>PROVISIONED</code> - Sets the read/write capacity mode to <code>PROVISIONED</code>. We recommend using <code>PROVISIONED</code> for predictable workloads.</p> </li> <li> <p> <code>PAY<em>PER</em>REQUEST</code> - Sets the read/write capacity mode to <code>PAY<em>PER</em>REQUEST</code>. We recommend using <code>PAY<em>PER</em>REQUEST</code> for unpredictable workloads. </p> </li> </ul></p>
     #[serde(rename = "BillingMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub billing_mode: Option<String>,
+    pub billing_mode: Option<BillingMode>,
     /// <p>Number of items in the table. Note that this is an approximate value. </p>
     #[serde(rename = "ItemCount")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2450,7 +4969,12 @@ pub struct SourceTableDetails {
     pub table_arn: Option<String>,
     /// <p>Time when the source table was created. </p>
     #[serde(rename = "TableCreationDateTime")]
-    pub table_creation_date_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub table_creation_date_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>Unique identifier for the table for which the backup was created. </p>
     #[serde(rename = "TableId")]
     pub table_id: String,
@@ -2498,9 +5022,112 @@ pub struct StreamSpecification {
     /// <p><p> When an item in the table is modified, <code>StreamViewType</code> determines what information is written to the stream for this table. Valid values for <code>StreamViewType</code> are:</p> <ul> <li> <p> <code>KEYS<em>ONLY</code> - Only the key attributes of the modified item are written to the stream.</p> </li> <li> <p> <code>NEW</em>IMAGE</code> - The entire item, as it appears after it was modified, is written to the stream.</p> </li> <li> <p> <code>OLD<em>IMAGE</code> - The entire item, as it appeared before it was modified, is written to the stream.</p> </li> <li> <p> <code>NEW</em>AND<em>OLD</em>IMAGES</code> - Both the new and the old item images of the item are written to the stream.</p> </li> </ul></p>
     #[serde(rename = "StreamViewType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stream_view_type: Option<String>,
+    pub stream_view_type: Option<StreamViewType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StreamViewType {
+    /// `NEW_IMAGE`
+    NewImage,
+    /// `OLD_IMAGE`
+    OldImage,
+    /// `NEW_AND_OLD_IMAGES`
+    NewAndOldImages,
+    /// `KEYS_ONLY`
+    KeysOnly,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl StreamViewType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StreamViewType::NewImage => "NEW_IMAGE",
+            StreamViewType::OldImage => "OLD_IMAGE",
+            StreamViewType::NewAndOldImages => "NEW_AND_OLD_IMAGES",
+            StreamViewType::KeysOnly => "KEYS_ONLY",
+            StreamViewType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for StreamViewType {
+    fn default() -> StreamViewType {
+        StreamViewType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for StreamViewType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for StreamViewType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for StreamViewType {
+    fn from(value: &'a str) -> StreamViewType {
+        match value {
+            "NEW_IMAGE" => StreamViewType::NewImage,
+            "OLD_IMAGE" => StreamViewType::OldImage,
+            "NEW_AND_OLD_IMAGES" => StreamViewType::NewAndOldImages,
+            "KEYS_ONLY" => StreamViewType::KeysOnly,
+            _ => StreamViewType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StreamViewType {
+    fn from(value: String) -> StreamViewType {
+        match value.as_str() {
+            "NEW_IMAGE" => StreamViewType::NewImage,
+            "OLD_IMAGE" => StreamViewType::OldImage,
+            "NEW_AND_OLD_IMAGES" => StreamViewType::NewAndOldImages,
+            "KEYS_ONLY" => StreamViewType::KeysOnly,
+            _ => StreamViewType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for StreamViewType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<StreamViewType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StreamViewType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for StreamViewType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for StreamViewType {
+    fn deserialize<D>(deserializer: D) -> Result<StreamViewType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents the auto scaling configuration for a global table.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -2516,7 +5143,7 @@ pub struct TableAutoScalingDescription {
     /// <p><p>The current state of the table:</p> <ul> <li> <p> <code>CREATING</code> - The table is being created.</p> </li> <li> <p> <code>UPDATING</code> - The table is being updated.</p> </li> <li> <p> <code>DELETING</code> - The table is being deleted.</p> </li> <li> <p> <code>ACTIVE</code> - The table is ready for use.</p> </li> </ul></p>
     #[serde(rename = "TableStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub table_status: Option<String>,
+    pub table_status: Option<TableStatus>,
 }
 
 /// <p>Represents the properties of a table.</p>
@@ -2537,8 +5164,13 @@ pub struct TableDescription {
     pub billing_mode_summary: Option<BillingModeSummary>,
     /// <p>The date and time when the table was created, in <a href="http://www.epochconverter.com/">UNIX epoch time</a> format.</p>
     #[serde(rename = "CreationDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date_time: Option<f64>,
+    pub creation_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The global secondary indexes, if any, on the table. Each index is scoped to a given partition key value. Each element is composed of:</p> <ul> <li> <p> <code>Backfilling</code> - If true, then the index is currently in the backfilling phase. Backfilling occurs only when a new global secondary index is added to the table. It is the process by which DynamoDB populates the new index with data from the table. (This attribute does not appear for indexes that were created during a <code>CreateTable</code> operation.) </p> <p> You can delete an index that is being created during the <code>Backfilling</code> phase when <code>IndexStatus</code> is set to CREATING and <code>Backfilling</code> is true. You can't delete the index that is being created when <code>IndexStatus</code> is set to CREATING and <code>Backfilling</code> is false. (This attribute does not appear for indexes that were created during a <code>CreateTable</code> operation.)</p> </li> <li> <p> <code>IndexName</code> - The name of the global secondary index.</p> </li> <li> <p> <code>IndexSizeBytes</code> - The total size of the global secondary index, in bytes. DynamoDB updates this value approximately every six hours. Recent changes might not be reflected in this value. </p> </li> <li> <p> <code>IndexStatus</code> - The current status of the global secondary index:</p> <ul> <li> <p> <code>CREATING</code> - The index is being created.</p> </li> <li> <p> <code>UPDATING</code> - The index is being updated.</p> </li> <li> <p> <code>DELETING</code> - The index is being deleted.</p> </li> <li> <p> <code>ACTIVE</code> - The index is ready for use.</p> </li> </ul> </li> <li> <p> <code>ItemCount</code> - The number of items in the global secondary index. DynamoDB updates this value approximately every six hours. Recent changes might not be reflected in this value. </p> </li> <li> <p> <code>KeySchema</code> - Specifies the complete index key schema. The attribute names in the key schema must be between 1 and 255 characters (inclusive). The key schema must begin with the same partition key as the table.</p> </li> <li> <p> <code>Projection</code> - Specifies attributes that are copied (projected) from the table into the index. These are in addition to the primary key attributes and index key attributes, which are automatically projected. Each attribute specification is composed of:</p> <ul> <li> <p> <code>ProjectionType</code> - One of the following:</p> <ul> <li> <p> <code>KEYS_ONLY</code> - Only the index and primary keys are projected into the index.</p> </li> <li> <p> <code>INCLUDE</code> - Only the specified table attributes are projected into the index. The list of projected attributes is in <code>NonKeyAttributes</code>.</p> </li> <li> <p> <code>ALL</code> - All of the table attributes are projected into the index.</p> </li> </ul> </li> <li> <p> <code>NonKeyAttributes</code> - A list of one or more non-key attribute names that are projected into the secondary index. The total count of attributes provided in <code>NonKeyAttributes</code>, summed across all of the secondary indexes, must not exceed 20. If you project the same attribute into two different indexes, this counts as two distinct attributes when determining the total.</p> </li> </ul> </li> <li> <p> <code>ProvisionedThroughput</code> - The provisioned throughput settings for the global secondary index, consisting of read and write capacity units, along with data about increases and decreases. </p> </li> </ul> <p>If the table is in the <code>DELETING</code> state, no information about indexes will be returned.</p>
     #[serde(rename = "GlobalSecondaryIndexes")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2606,9 +5238,127 @@ pub struct TableDescription {
     /// <p><p>The current state of the table:</p> <ul> <li> <p> <code>CREATING</code> - The table is being created.</p> </li> <li> <p> <code>UPDATING</code> - The table is being updated.</p> </li> <li> <p> <code>DELETING</code> - The table is being deleted.</p> </li> <li> <p> <code>ACTIVE</code> - The table is ready for use.</p> </li> <li> <p> <code>INACCESSIBLE<em>ENCRYPTION</em>CREDENTIALS</code> - The AWS KMS key used to encrypt the table in inaccessible. Table operations may fail due to failure to use the AWS KMS key. DynamoDB will initiate the table archival process when a table&#39;s AWS KMS key remains inaccessible for more than seven days. </p> </li> <li> <p> <code>ARCHIVING</code> - The table is being archived. Operations are not allowed until archival is complete. </p> </li> <li> <p> <code>ARCHIVED</code> - The table has been archived. See the ArchivalReason for more information. </p> </li> </ul></p>
     #[serde(rename = "TableStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub table_status: Option<String>,
+    pub table_status: Option<TableStatus>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TableStatus {
+    /// `CREATING`
+    Creating,
+    /// `UPDATING`
+    Updating,
+    /// `DELETING`
+    Deleting,
+    /// `ACTIVE`
+    Active,
+    /// `INACCESSIBLE_ENCRYPTION_CREDENTIALS`
+    InaccessibleEncryptionCredentials,
+    /// `ARCHIVING`
+    Archiving,
+    /// `ARCHIVED`
+    Archived,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TableStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TableStatus::Creating => "CREATING",
+            TableStatus::Updating => "UPDATING",
+            TableStatus::Deleting => "DELETING",
+            TableStatus::Active => "ACTIVE",
+            TableStatus::InaccessibleEncryptionCredentials => "INACCESSIBLE_ENCRYPTION_CREDENTIALS",
+            TableStatus::Archiving => "ARCHIVING",
+            TableStatus::Archived => "ARCHIVED",
+            TableStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TableStatus {
+    fn default() -> TableStatus {
+        TableStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TableStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TableStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TableStatus {
+    fn from(value: &'a str) -> TableStatus {
+        match value {
+            "CREATING" => TableStatus::Creating,
+            "UPDATING" => TableStatus::Updating,
+            "DELETING" => TableStatus::Deleting,
+            "ACTIVE" => TableStatus::Active,
+            "INACCESSIBLE_ENCRYPTION_CREDENTIALS" => TableStatus::InaccessibleEncryptionCredentials,
+            "ARCHIVING" => TableStatus::Archiving,
+            "ARCHIVED" => TableStatus::Archived,
+            _ => TableStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TableStatus {
+    fn from(value: String) -> TableStatus {
+        match value.as_str() {
+            "CREATING" => TableStatus::Creating,
+            "UPDATING" => TableStatus::Updating,
+            "DELETING" => TableStatus::Deleting,
+            "ACTIVE" => TableStatus::Active,
+            "INACCESSIBLE_ENCRYPTION_CREDENTIALS" => TableStatus::InaccessibleEncryptionCredentials,
+            "ARCHIVING" => TableStatus::Archiving,
+            "ARCHIVED" => TableStatus::Archived,
+            _ => TableStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TableStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TableStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TableStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TableStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for TableStatus {
+    fn deserialize<D>(deserializer: D) -> Result<TableStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes a tag. A tag is a key-value pair. You can add up to 50 tags to a single DynamoDB table. </p> <p> AWS-assigned tag names and values are automatically assigned the <code>aws:</code> prefix, which the user cannot assign. AWS-assigned tag names do not count towards the tag limit of 50. User-assigned tag names have the prefix <code>user:</code> in the Cost Allocation Report. You cannot backdate the application of a tag. </p> <p>For an overview on tagging DynamoDB resources, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Tagging.html">Tagging for DynamoDB</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Tag {
@@ -2642,7 +5392,7 @@ pub struct TimeToLiveDescription {
     /// <p> The TTL status for the table.</p>
     #[serde(rename = "TimeToLiveStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub time_to_live_status: Option<String>,
+    pub time_to_live_status: Option<TimeToLiveStatus>,
 }
 
 /// <p>Represents the settings used to enable or disable Time to Live (TTL) for the specified table.</p>
@@ -2656,6 +5406,109 @@ pub struct TimeToLiveSpecification {
     pub enabled: bool,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TimeToLiveStatus {
+    /// `ENABLING`
+    Enabling,
+    /// `DISABLING`
+    Disabling,
+    /// `ENABLED`
+    Enabled,
+    /// `DISABLED`
+    Disabled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TimeToLiveStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TimeToLiveStatus::Enabling => "ENABLING",
+            TimeToLiveStatus::Disabling => "DISABLING",
+            TimeToLiveStatus::Enabled => "ENABLED",
+            TimeToLiveStatus::Disabled => "DISABLED",
+            TimeToLiveStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TimeToLiveStatus {
+    fn default() -> TimeToLiveStatus {
+        TimeToLiveStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TimeToLiveStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TimeToLiveStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TimeToLiveStatus {
+    fn from(value: &'a str) -> TimeToLiveStatus {
+        match value {
+            "ENABLING" => TimeToLiveStatus::Enabling,
+            "DISABLING" => TimeToLiveStatus::Disabling,
+            "ENABLED" => TimeToLiveStatus::Enabled,
+            "DISABLED" => TimeToLiveStatus::Disabled,
+            _ => TimeToLiveStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TimeToLiveStatus {
+    fn from(value: String) -> TimeToLiveStatus {
+        match value.as_str() {
+            "ENABLING" => TimeToLiveStatus::Enabling,
+            "DISABLING" => TimeToLiveStatus::Disabling,
+            "ENABLED" => TimeToLiveStatus::Enabled,
+            "DISABLED" => TimeToLiveStatus::Disabled,
+            _ => TimeToLiveStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TimeToLiveStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TimeToLiveStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TimeToLiveStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TimeToLiveStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TimeToLiveStatus {
+    fn deserialize<D>(deserializer: D) -> Result<TimeToLiveStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Specifies an item to be retrieved as part of the transaction.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -2671,7 +5524,7 @@ pub struct TransactGetItemsInput {
     /// <p>A value of <code>TOTAL</code> causes consumed capacity information to be returned, and a value of <code>NONE</code> prevents that information from being returned. No other value is valid.</p>
     #[serde(rename = "ReturnConsumedCapacity")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub return_consumed_capacity: Option<String>,
+    pub return_consumed_capacity: Option<ReturnConsumedCapacity>,
     /// <p>An ordered array of up to 25 <code>TransactGetItem</code> objects, each of which contains a <code>Get</code> structure.</p>
     #[serde(rename = "TransactItems")]
     pub transact_items: Vec<TransactGetItem>,
@@ -2721,11 +5574,11 @@ pub struct TransactWriteItemsInput {
     pub client_request_token: Option<String>,
     #[serde(rename = "ReturnConsumedCapacity")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub return_consumed_capacity: Option<String>,
+    pub return_consumed_capacity: Option<ReturnConsumedCapacity>,
     /// <p>Determines whether item collection metrics are returned. If set to <code>SIZE</code>, the response includes statistics about item collections (if any), that were modified during the operation and are returned in the response. If set to <code>NONE</code> (the default), no statistics are returned. </p>
     #[serde(rename = "ReturnItemCollectionMetrics")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub return_item_collection_metrics: Option<String>,
+    pub return_item_collection_metrics: Option<ReturnItemCollectionMetrics>,
     /// <p>An ordered array of up to 25 <code>TransactWriteItem</code> objects, each of which contains a <code>ConditionCheck</code>, <code>Put</code>, <code>Update</code>, or <code>Delete</code> object. These can operate on items in different tables, but the tables must reside in the same AWS account and Region, and no two of them can operate on the same item. </p>
     #[serde(rename = "TransactItems")]
     pub transact_items: Vec<TransactWriteItem>,
@@ -2778,7 +5631,7 @@ pub struct Update {
     /// <p>Use <code>ReturnValuesOnConditionCheckFailure</code> to get the item attributes if the <code>Update</code> condition fails. For <code>ReturnValuesOnConditionCheckFailure</code>, the valid values are: NONE, ALL_OLD, UPDATED_OLD, ALL_NEW, UPDATED_NEW.</p>
     #[serde(rename = "ReturnValuesOnConditionCheckFailure")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub return_values_on_condition_check_failure: Option<String>,
+    pub return_values_on_condition_check_failure: Option<ReturnValuesOnConditionCheckFailure>,
     /// <p>Name of the table for the <code>UpdateItem</code> request.</p>
     #[serde(rename = "TableName")]
     pub table_name: String,
@@ -2812,7 +5665,7 @@ pub struct UpdateContinuousBackupsOutput {
 pub struct UpdateContributorInsightsInput {
     /// <p>Represents the contributor insights action.</p>
     #[serde(rename = "ContributorInsightsAction")]
-    pub contributor_insights_action: String,
+    pub contributor_insights_action: ContributorInsightsAction,
     /// <p>The global secondary index name, if applicable.</p>
     #[serde(rename = "IndexName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2828,7 +5681,7 @@ pub struct UpdateContributorInsightsOutput {
     /// <p>The status of contributor insights</p>
     #[serde(rename = "ContributorInsightsStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub contributor_insights_status: Option<String>,
+    pub contributor_insights_status: Option<ContributorInsightsStatus>,
     /// <p>The name of the global secondary index, if applicable.</p>
     #[serde(rename = "IndexName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2877,7 +5730,7 @@ pub struct UpdateGlobalTableSettingsInput {
     /// <p><p>The billing mode of the global table. If <code>GlobalTableBillingMode</code> is not specified, the global table defaults to <code>PROVISIONED</code> capacity billing mode.</p> <ul> <li> <p> <code>PROVISIONED</code> - We recommend using <code>PROVISIONED</code> for predictable workloads. <code>PROVISIONED</code> sets the billing mode to <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/HowItWorks.ReadWriteCapacityMode.html#HowItWorks.ProvisionedThroughput.Manual">Provisioned Mode</a>.</p> </li> <li> <p> <code>PAY<em>PER</em>REQUEST</code> - We recommend using <code>PAY<em>PER</em>REQUEST</code> for unpredictable workloads. <code>PAY<em>PER</em>REQUEST</code> sets the billing mode to <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/HowItWorks.ReadWriteCapacityMode.html#HowItWorks.OnDemand">On-Demand Mode</a>. </p> </li> </ul></p>
     #[serde(rename = "GlobalTableBillingMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub global_table_billing_mode: Option<String>,
+    pub global_table_billing_mode: Option<BillingMode>,
     /// <p>Represents the settings of a global secondary index for a global table that will be modified.</p>
     #[serde(rename = "GlobalTableGlobalSecondaryIndexSettingsUpdate")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2929,7 +5782,7 @@ pub struct UpdateItemInput {
     /// <p>This is a legacy parameter. Use <code>ConditionExpression</code> instead. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/LegacyConditionalParameters.ConditionalOperator.html">ConditionalOperator</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
     #[serde(rename = "ConditionalOperator")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub conditional_operator: Option<String>,
+    pub conditional_operator: Option<ConditionalOperator>,
     /// <p>This is a legacy parameter. Use <code>ConditionExpression</code> instead. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/LegacyConditionalParameters.Expected.html">Expected</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
     #[serde(rename = "Expected")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2947,15 +5800,15 @@ pub struct UpdateItemInput {
     pub key: ::std::collections::HashMap<String, AttributeValue>,
     #[serde(rename = "ReturnConsumedCapacity")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub return_consumed_capacity: Option<String>,
+    pub return_consumed_capacity: Option<ReturnConsumedCapacity>,
     /// <p>Determines whether item collection metrics are returned. If set to <code>SIZE</code>, the response includes statistics about item collections, if any, that were modified during the operation are returned in the response. If set to <code>NONE</code> (the default), no statistics are returned.</p>
     #[serde(rename = "ReturnItemCollectionMetrics")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub return_item_collection_metrics: Option<String>,
+    pub return_item_collection_metrics: Option<ReturnItemCollectionMetrics>,
     /// <p>Use <code>ReturnValues</code> if you want to get the item attributes as they appear before or after they are updated. For <code>UpdateItem</code>, the valid values are:</p> <ul> <li> <p> <code>NONE</code> - If <code>ReturnValues</code> is not specified, or if its value is <code>NONE</code>, then nothing is returned. (This setting is the default for <code>ReturnValues</code>.)</p> </li> <li> <p> <code>ALL_OLD</code> - Returns all of the attributes of the item, as they appeared before the UpdateItem operation.</p> </li> <li> <p> <code>UPDATED_OLD</code> - Returns only the updated attributes, as they appeared before the UpdateItem operation.</p> </li> <li> <p> <code>ALL_NEW</code> - Returns all of the attributes of the item, as they appear after the UpdateItem operation.</p> </li> <li> <p> <code>UPDATED_NEW</code> - Returns only the updated attributes, as they appear after the UpdateItem operation.</p> </li> </ul> <p>There is no additional cost associated with requesting a return value aside from the small network and processing overhead of receiving a larger response. No read capacity units are consumed.</p> <p>The values returned are strongly consistent.</p>
     #[serde(rename = "ReturnValues")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub return_values: Option<String>,
+    pub return_values: Option<ReturnValue>,
     /// <p>The name of the table containing the item to update.</p>
     #[serde(rename = "TableName")]
     pub table_name: String,
@@ -3015,7 +5868,7 @@ pub struct UpdateTableInput {
     /// <p><p>Controls how you are charged for read and write throughput and how you manage capacity. When switching from pay-per-request to provisioned capacity, initial provisioned capacity values must be set. The initial provisioned capacity values are estimated based on the consumed read and write capacity of your table and global secondary indexes over the past 30 minutes.</p> <ul> <li> <p> <code>PROVISIONED</code> - We recommend using <code>PROVISIONED</code> for predictable workloads. <code>PROVISIONED</code> sets the billing mode to <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/HowItWorks.ReadWriteCapacityMode.html#HowItWorks.ProvisionedThroughput.Manual">Provisioned Mode</a>.</p> </li> <li> <p> <code>PAY<em>PER</em>REQUEST</code> - We recommend using <code>PAY<em>PER</em>REQUEST</code> for unpredictable workloads. <code>PAY<em>PER</em>REQUEST</code> sets the billing mode to <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/HowItWorks.ReadWriteCapacityMode.html#HowItWorks.OnDemand">On-Demand Mode</a>. </p> </li> </ul></p>
     #[serde(rename = "BillingMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub billing_mode: Option<String>,
+    pub billing_mode: Option<BillingMode>,
     /// <p>An array of one or more global secondary indexes for the table. For each index in the array, you can request one action:</p> <ul> <li> <p> <code>Create</code> - add a new global secondary index to the table.</p> </li> <li> <p> <code>Update</code> - modify the provisioned throughput settings of an existing global secondary index.</p> </li> <li> <p> <code>Delete</code> - remove a global secondary index from the table.</p> </li> </ul> <p>You can create or delete only one global secondary index per <code>UpdateTable</code> operation.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/GSI.OnlineOps.html">Managing Global Secondary Indexes</a> in the <i>Amazon DynamoDB Developer Guide</i>. </p>
     #[serde(rename = "GlobalSecondaryIndexUpdates")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -5417,11 +8270,12 @@ pub struct DynamoDbClient {
 impl DynamoDbClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> DynamoDbClient {
         DynamoDbClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "DynamoDB"),
         }
     }
 
@@ -5453,6 +8307,7 @@ impl DynamoDb for DynamoDbClient {
         input: BatchGetItemInput,
     ) -> Result<BatchGetItemOutput, RusotoError<BatchGetItemError>> {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("BatchGetItem");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.BatchGetItem");
@@ -5480,6 +8335,7 @@ impl DynamoDb for DynamoDbClient {
         input: BatchWriteItemInput,
     ) -> Result<BatchWriteItemOutput, RusotoError<BatchWriteItemError>> {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("BatchWriteItem");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.BatchWriteItem");
@@ -5507,6 +8363,7 @@ impl DynamoDb for DynamoDbClient {
         input: CreateBackupInput,
     ) -> Result<CreateBackupOutput, RusotoError<CreateBackupError>> {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("CreateBackup");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.CreateBackup");
@@ -5534,6 +8391,7 @@ impl DynamoDb for DynamoDbClient {
         input: CreateGlobalTableInput,
     ) -> Result<CreateGlobalTableOutput, RusotoError<CreateGlobalTableError>> {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("CreateGlobalTable");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.CreateGlobalTable");
@@ -5561,6 +8419,7 @@ impl DynamoDb for DynamoDbClient {
         input: CreateTableInput,
     ) -> Result<CreateTableOutput, RusotoError<CreateTableError>> {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("CreateTable");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.CreateTable");
@@ -5588,6 +8447,7 @@ impl DynamoDb for DynamoDbClient {
         input: DeleteBackupInput,
     ) -> Result<DeleteBackupOutput, RusotoError<DeleteBackupError>> {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("DeleteBackup");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.DeleteBackup");
@@ -5615,6 +8475,7 @@ impl DynamoDb for DynamoDbClient {
         input: DeleteItemInput,
     ) -> Result<DeleteItemOutput, RusotoError<DeleteItemError>> {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("DeleteItem");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.DeleteItem");
@@ -5642,6 +8503,7 @@ impl DynamoDb for DynamoDbClient {
         input: DeleteTableInput,
     ) -> Result<DeleteTableOutput, RusotoError<DeleteTableError>> {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("DeleteTable");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.DeleteTable");
@@ -5669,6 +8531,7 @@ impl DynamoDb for DynamoDbClient {
         input: DescribeBackupInput,
     ) -> Result<DescribeBackupOutput, RusotoError<DescribeBackupError>> {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("DescribeBackup");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.DescribeBackup");
@@ -5696,6 +8559,7 @@ impl DynamoDb for DynamoDbClient {
         input: DescribeContinuousBackupsInput,
     ) -> Result<DescribeContinuousBackupsOutput, RusotoError<DescribeContinuousBackupsError>> {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("DescribeContinuousBackups");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -5728,6 +8592,7 @@ impl DynamoDb for DynamoDbClient {
     ) -> Result<DescribeContributorInsightsOutput, RusotoError<DescribeContributorInsightsError>>
     {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("DescribeContributorInsights");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -5758,6 +8623,7 @@ impl DynamoDb for DynamoDbClient {
         &self,
     ) -> Result<DescribeEndpointsResponse, RusotoError<DescribeEndpointsError>> {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("DescribeEndpoints");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.DescribeEndpoints");
@@ -5785,6 +8651,7 @@ impl DynamoDb for DynamoDbClient {
         input: DescribeGlobalTableInput,
     ) -> Result<DescribeGlobalTableOutput, RusotoError<DescribeGlobalTableError>> {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("DescribeGlobalTable");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.DescribeGlobalTable");
@@ -5814,6 +8681,7 @@ impl DynamoDb for DynamoDbClient {
     ) -> Result<DescribeGlobalTableSettingsOutput, RusotoError<DescribeGlobalTableSettingsError>>
     {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("DescribeGlobalTableSettings");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -5844,6 +8712,7 @@ impl DynamoDb for DynamoDbClient {
         &self,
     ) -> Result<DescribeLimitsOutput, RusotoError<DescribeLimitsError>> {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("DescribeLimits");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.DescribeLimits");
@@ -5870,6 +8739,7 @@ impl DynamoDb for DynamoDbClient {
         input: DescribeTableInput,
     ) -> Result<DescribeTableOutput, RusotoError<DescribeTableError>> {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("DescribeTable");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.DescribeTable");
@@ -5900,6 +8770,7 @@ impl DynamoDb for DynamoDbClient {
         RusotoError<DescribeTableReplicaAutoScalingError>,
     > {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("DescribeTableReplicaAutoScaling");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -5933,6 +8804,7 @@ impl DynamoDb for DynamoDbClient {
         input: DescribeTimeToLiveInput,
     ) -> Result<DescribeTimeToLiveOutput, RusotoError<DescribeTimeToLiveError>> {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("DescribeTimeToLive");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.DescribeTimeToLive");
@@ -5961,6 +8833,7 @@ impl DynamoDb for DynamoDbClient {
         input: GetItemInput,
     ) -> Result<GetItemOutput, RusotoError<GetItemError>> {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("GetItem");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.GetItem");
@@ -5988,6 +8861,7 @@ impl DynamoDb for DynamoDbClient {
         input: ListBackupsInput,
     ) -> Result<ListBackupsOutput, RusotoError<ListBackupsError>> {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("ListBackups");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.ListBackups");
@@ -6015,6 +8889,7 @@ impl DynamoDb for DynamoDbClient {
         input: ListContributorInsightsInput,
     ) -> Result<ListContributorInsightsOutput, RusotoError<ListContributorInsightsError>> {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("ListContributorInsights");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.ListContributorInsights");
@@ -6043,6 +8918,7 @@ impl DynamoDb for DynamoDbClient {
         input: ListGlobalTablesInput,
     ) -> Result<ListGlobalTablesOutput, RusotoError<ListGlobalTablesError>> {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("ListGlobalTables");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.ListGlobalTables");
@@ -6070,6 +8946,7 @@ impl DynamoDb for DynamoDbClient {
         input: ListTablesInput,
     ) -> Result<ListTablesOutput, RusotoError<ListTablesError>> {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("ListTables");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.ListTables");
@@ -6097,6 +8974,7 @@ impl DynamoDb for DynamoDbClient {
         input: ListTagsOfResourceInput,
     ) -> Result<ListTagsOfResourceOutput, RusotoError<ListTagsOfResourceError>> {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("ListTagsOfResource");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.ListTagsOfResource");
@@ -6125,6 +9003,7 @@ impl DynamoDb for DynamoDbClient {
         input: PutItemInput,
     ) -> Result<PutItemOutput, RusotoError<PutItemError>> {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("PutItem");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.PutItem");
@@ -6149,6 +9028,7 @@ impl DynamoDb for DynamoDbClient {
     /// <p>The <code>Query</code> operation finds items based on primary key values. You can query any table or secondary index that has a composite primary key (a partition key and a sort key). </p> <p>Use the <code>KeyConditionExpression</code> parameter to provide a specific value for the partition key. The <code>Query</code> operation will return all of the items from the table or index with that partition key value. You can optionally narrow the scope of the <code>Query</code> operation by specifying a sort key value and a comparison operator in <code>KeyConditionExpression</code>. To further refine the <code>Query</code> results, you can optionally provide a <code>FilterExpression</code>. A <code>FilterExpression</code> determines which items within the results should be returned to you. All of the other results are discarded. </p> <p> A <code>Query</code> operation always returns a result set. If no matching items are found, the result set will be empty. Queries that do not return results consume the minimum number of read capacity units for that type of read operation. </p> <note> <p> DynamoDB calculates the number of read capacity units consumed based on item size, not on the amount of data that is returned to an application. The number of capacity units consumed will be the same whether you request all of the attributes (the default behavior) or just some of them (using a projection expression). The number will also be the same whether or not you use a <code>FilterExpression</code>. </p> </note> <p> <code>Query</code> results are always sorted by the sort key value. If the data type of the sort key is Number, the results are returned in numeric order; otherwise, the results are returned in order of UTF-8 bytes. By default, the sort order is ascending. To reverse the order, set the <code>ScanIndexForward</code> parameter to false. </p> <p> A single <code>Query</code> operation will read up to the maximum number of items set (if using the <code>Limit</code> parameter) or a maximum of 1 MB of data and then apply any filtering to the results using <code>FilterExpression</code>. If <code>LastEvaluatedKey</code> is present in the response, you will need to paginate the result set. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Query.html#Query.Pagination">Paginating the Results</a> in the <i>Amazon DynamoDB Developer Guide</i>. </p> <p> <code>FilterExpression</code> is applied after a <code>Query</code> finishes, but before the results are returned. A <code>FilterExpression</code> cannot contain partition key or sort key attributes. You need to specify those attributes in the <code>KeyConditionExpression</code>. </p> <note> <p> A <code>Query</code> operation can return an empty result set and a <code>LastEvaluatedKey</code> if all the items read for the page of results are filtered out. </p> </note> <p>You can query a table, a local secondary index, or a global secondary index. For a query on a table or on a local secondary index, you can set the <code>ConsistentRead</code> parameter to <code>true</code> and obtain a strongly consistent result. Global secondary indexes support eventually consistent reads only, so do not specify <code>ConsistentRead</code> when querying a global secondary index.</p>
     async fn query(&self, input: QueryInput) -> Result<QueryOutput, RusotoError<QueryError>> {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("Query");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.Query");
@@ -6176,6 +9056,7 @@ impl DynamoDb for DynamoDbClient {
         input: RestoreTableFromBackupInput,
     ) -> Result<RestoreTableFromBackupOutput, RusotoError<RestoreTableFromBackupError>> {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("RestoreTableFromBackup");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.RestoreTableFromBackup");
@@ -6204,6 +9085,7 @@ impl DynamoDb for DynamoDbClient {
         input: RestoreTableToPointInTimeInput,
     ) -> Result<RestoreTableToPointInTimeOutput, RusotoError<RestoreTableToPointInTimeError>> {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("RestoreTableToPointInTime");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -6232,6 +9114,7 @@ impl DynamoDb for DynamoDbClient {
     /// <p>The <code>Scan</code> operation returns one or more items and item attributes by accessing every item in a table or a secondary index. To have DynamoDB return fewer items, you can provide a <code>FilterExpression</code> operation.</p> <p>If the total number of scanned items exceeds the maximum dataset size limit of 1 MB, the scan stops and results are returned to the user as a <code>LastEvaluatedKey</code> value to continue the scan in a subsequent operation. The results also include the number of items exceeding the limit. A scan can result in no table data meeting the filter criteria. </p> <p>A single <code>Scan</code> operation reads up to the maximum number of items set (if using the <code>Limit</code> parameter) or a maximum of 1 MB of data and then apply any filtering to the results using <code>FilterExpression</code>. If <code>LastEvaluatedKey</code> is present in the response, you need to paginate the result set. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Scan.html#Scan.Pagination">Paginating the Results</a> in the <i>Amazon DynamoDB Developer Guide</i>. </p> <p> <code>Scan</code> operations proceed sequentially; however, for faster performance on a large table or secondary index, applications can request a parallel <code>Scan</code> operation by providing the <code>Segment</code> and <code>TotalSegments</code> parameters. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Scan.html#Scan.ParallelScan">Parallel Scan</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p> <p> <code>Scan</code> uses eventually consistent reads when accessing the data in a table; therefore, the result set might not include the changes to data in the table immediately before the operation began. If you need a consistent copy of the data, as of the time that the <code>Scan</code> begins, you can set the <code>ConsistentRead</code> parameter to <code>true</code>.</p>
     async fn scan(&self, input: ScanInput) -> Result<ScanOutput, RusotoError<ScanError>> {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("Scan");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.Scan");
@@ -6259,6 +9142,7 @@ impl DynamoDb for DynamoDbClient {
         input: TagResourceInput,
     ) -> Result<(), RusotoError<TagResourceError>> {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.TagResource");
@@ -6286,6 +9170,7 @@ impl DynamoDb for DynamoDbClient {
         input: TransactGetItemsInput,
     ) -> Result<TransactGetItemsOutput, RusotoError<TransactGetItemsError>> {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("TransactGetItems");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.TransactGetItems");
@@ -6313,6 +9198,7 @@ impl DynamoDb for DynamoDbClient {
         input: TransactWriteItemsInput,
     ) -> Result<TransactWriteItemsOutput, RusotoError<TransactWriteItemsError>> {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("TransactWriteItems");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.TransactWriteItems");
@@ -6341,6 +9227,7 @@ impl DynamoDb for DynamoDbClient {
         input: UntagResourceInput,
     ) -> Result<(), RusotoError<UntagResourceError>> {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.UntagResource");
@@ -6368,6 +9255,7 @@ impl DynamoDb for DynamoDbClient {
         input: UpdateContinuousBackupsInput,
     ) -> Result<UpdateContinuousBackupsOutput, RusotoError<UpdateContinuousBackupsError>> {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("UpdateContinuousBackups");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.UpdateContinuousBackups");
@@ -6396,6 +9284,7 @@ impl DynamoDb for DynamoDbClient {
         input: UpdateContributorInsightsInput,
     ) -> Result<UpdateContributorInsightsOutput, RusotoError<UpdateContributorInsightsError>> {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("UpdateContributorInsights");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -6427,6 +9316,7 @@ impl DynamoDb for DynamoDbClient {
         input: UpdateGlobalTableInput,
     ) -> Result<UpdateGlobalTableOutput, RusotoError<UpdateGlobalTableError>> {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("UpdateGlobalTable");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.UpdateGlobalTable");
@@ -6454,6 +9344,7 @@ impl DynamoDb for DynamoDbClient {
         input: UpdateGlobalTableSettingsInput,
     ) -> Result<UpdateGlobalTableSettingsOutput, RusotoError<UpdateGlobalTableSettingsError>> {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("UpdateGlobalTableSettings");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -6485,6 +9376,7 @@ impl DynamoDb for DynamoDbClient {
         input: UpdateItemInput,
     ) -> Result<UpdateItemOutput, RusotoError<UpdateItemError>> {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("UpdateItem");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.UpdateItem");
@@ -6512,6 +9404,7 @@ impl DynamoDb for DynamoDbClient {
         input: UpdateTableInput,
     ) -> Result<UpdateTableOutput, RusotoError<UpdateTableError>> {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("UpdateTable");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.UpdateTable");
@@ -6540,6 +9433,7 @@ impl DynamoDb for DynamoDbClient {
     ) -> Result<UpdateTableReplicaAutoScalingOutput, RusotoError<UpdateTableReplicaAutoScalingError>>
     {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("UpdateTableReplicaAutoScaling");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -6571,6 +9465,7 @@ impl DynamoDb for DynamoDbClient {
         input: UpdateTimeToLiveInput,
     ) -> Result<UpdateTimeToLiveOutput, RusotoError<UpdateTimeToLiveError>> {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("UpdateTimeToLive");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.UpdateTimeToLive");
@@ -6592,3 +9487,242 @@ impl DynamoDb for DynamoDbClient {
         }
     }
 }
+impl DynamoDbClient {
+    /// Auto-paginating version of `list_backups`, streaming every page of results.
+    pub fn list_backups_pages(
+        &self,
+        input: ListBackupsInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListBackupsOutput, ListBackupsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DynamoDbClient, input: ListBackupsInput| async move {
+                client.list_backups(input).await
+            },
+            |input: &ListBackupsInput, output: &ListBackupsOutput| {
+                Some(ListBackupsInput {
+                    exclusive_start_backup_arn: Some(output.last_evaluated_backup_arn.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_backups`, streaming the `BackupSummaries` of every page of results.
+    pub fn list_backups_items(
+        &self,
+        input: ListBackupsInput,
+    ) -> rusoto_core::pagination::RusotoStream<BackupSummary, ListBackupsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_backups_pages(input),
+            |output: ListBackupsOutput| output.backup_summaries.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_tables`, streaming every page of results.
+    pub fn list_tables_pages(
+        &self,
+        input: ListTablesInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListTablesOutput, ListTablesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DynamoDbClient, input: ListTablesInput| async move {
+                client.list_tables(input).await
+            },
+            |input: &ListTablesInput, output: &ListTablesOutput| {
+                Some(ListTablesInput {
+                    exclusive_start_table_name: Some(output.last_evaluated_table_name.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_tables`, streaming the `TableNames` of every page of results.
+    pub fn list_tables_items(
+        &self,
+        input: ListTablesInput,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListTablesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_tables_pages(input),
+            |output: ListTablesOutput| output.table_names.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_tags_of_resource`, streaming every page of results.
+    pub fn list_tags_of_resource_pages(
+        &self,
+        input: ListTagsOfResourceInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListTagsOfResourceOutput, ListTagsOfResourceError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DynamoDbClient, input: ListTagsOfResourceInput| async move {
+                client.list_tags_of_resource(input).await
+            },
+            |input: &ListTagsOfResourceInput, output: &ListTagsOfResourceOutput| {
+                Some(ListTagsOfResourceInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_tags_of_resource`, streaming the `Tags` of every page of results.
+    pub fn list_tags_of_resource_items(
+        &self,
+        input: ListTagsOfResourceInput,
+    ) -> rusoto_core::pagination::RusotoStream<Tag, ListTagsOfResourceError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_tags_of_resource_pages(input),
+            |output: ListTagsOfResourceOutput| output.tags.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `query`, streaming every page of results.
+    pub fn query_pages(
+        &self,
+        input: QueryInput,
+    ) -> rusoto_core::pagination::RusotoStream<QueryOutput, QueryError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DynamoDbClient, input: QueryInput| async move { client.query(input).await },
+            |input: &QueryInput, output: &QueryOutput| {
+                Some(QueryInput {
+                    exclusive_start_key: Some(output.last_evaluated_key.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `query`, streaming the `Items` of every page of results.
+    pub fn query_items(
+        &self,
+        input: QueryInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ::std::collections::HashMap<String, AttributeValue>,
+        QueryError,
+    > {
+        rusoto_core::pagination::paginate_items(self.query_pages(input), |output: QueryOutput| {
+            output.items.unwrap_or_default()
+        })
+    }
+
+    /// Auto-paginating version of `scan`, streaming every page of results.
+    pub fn scan_pages(
+        &self,
+        input: ScanInput,
+    ) -> rusoto_core::pagination::RusotoStream<ScanOutput, ScanError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DynamoDbClient, input: ScanInput| async move { client.scan(input).await },
+            |input: &ScanInput, output: &ScanOutput| {
+                Some(ScanInput {
+                    exclusive_start_key: Some(output.last_evaluated_key.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `scan`, streaming the `Items` of every page of results.
+    pub fn scan_items(
+        &self,
+        input: ScanInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ::std::collections::HashMap<String, AttributeValue>,
+        ScanError,
+    > {
+        rusoto_core::pagination::paginate_items(self.scan_pages(input), |output: ScanOutput| {
+            output.items.unwrap_or_default()
+        })
+    }
+}
+impl DynamoDbClient {
+    /// Waits until `describe_table` reports the `TableExists` state, polling every 20 seconds up to 25 times.
+    pub async fn wait_until_table_exists(
+        &self,
+        input: DescribeTableInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeTableError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(20), 25);
+        self.wait_until_table_exists_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_table_exists`, polling as often and as many times as `config` says.
+    pub async fn wait_until_table_exists_with_config(
+        &self,
+        input: DescribeTableInput,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeTableError>> {
+        rusoto_core::waiter::wait(
+            "TableExists",
+            &config,
+            || self.describe_table(input.clone()),
+            |result: &Result<DescribeTableOutput, RusotoError<DescribeTableError>>| match result {
+                Ok(output)
+                    if output
+                        .table
+                        .iter()
+                        .flat_map(|v| v.table_status.iter())
+                        .any(|v| *v == TableStatus::Active) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Err(RusotoError::Service(DescribeTableError::ResourceNotFound(_))) => {
+                    Some(rusoto_core::waiter::WaiterState::Retry)
+                }
+                Err(RusotoError::Unknown(response))
+                    if rusoto_core::retry::error_code(response).as_deref()
+                        == Some("ResourceNotFoundException") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Retry)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+
+    /// Waits until `describe_table` reports the `TableNotExists` state, polling every 20 seconds up to 25 times.
+    pub async fn wait_until_table_not_exists(
+        &self,
+        input: DescribeTableInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeTableError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(20), 25);
+        self.wait_until_table_not_exists_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_table_not_exists`, polling as often and as many times as `config` says.
+    pub async fn wait_until_table_not_exists_with_config(
+        &self,
+        input: DescribeTableInput,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeTableError>> {
+        rusoto_core::waiter::wait(
+            "TableNotExists",
+            &config,
+            || self.describe_table(input.clone()),
+            |result: &Result<DescribeTableOutput, RusotoError<DescribeTableError>>| match result {
+                Err(RusotoError::Service(DescribeTableError::ResourceNotFound(_))) => {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Err(RusotoError::Unknown(response))
+                    if rusoto_core::retry::error_code(response).as_deref()
+                        == Some("ResourceNotFoundException") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+}
@@ -147,7 +147,7 @@ pub struct GetShardIteratorInput {
     pub shard_id: String,
     /// <p><p>Determines how the shard iterator is used to start reading stream records from the shard:</p> <ul> <li> <p> <code>AT<em>SEQUENCE</em>NUMBER</code> - Start reading exactly from the position denoted by a specific sequence number.</p> </li> <li> <p> <code>AFTER<em>SEQUENCE</em>NUMBER</code> - Start reading right after the position denoted by a specific sequence number.</p> </li> <li> <p> <code>TRIM_HORIZON</code> - Start reading at the last (untrimmed) stream record, which is the oldest record in the shard. In DynamoDB Streams, there is a 24 hour limit on data retention. Stream records whose age exceeds this limit are subject to removal (trimming) from the stream.</p> </li> <li> <p> <code>LATEST</code> - Start reading just after the most recent stream record in the shard, so that you always read the most recent data in the shard.</p> </li> </ul></p>
     #[serde(rename = "ShardIteratorType")]
-    pub shard_iterator_type: String,
+    pub shard_iterator_type: ShardIteratorType,
     /// <p>The Amazon Resource Name (ARN) for the stream.</p>
     #[serde(rename = "StreamArn")]
     pub stream_arn: String,
@@ -186,9 +186,102 @@ pub struct KeySchemaElement {
     pub attribute_name: String,
     /// <p>The attribute data, consisting of the data type and the attribute value itself.</p>
     #[serde(rename = "KeyType")]
-    pub key_type: String,
+    pub key_type: KeyType,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum KeyType {
+    /// `HASH`
+    Hash,
+    /// `RANGE`
+    Range,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl KeyType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            KeyType::Hash => "HASH",
+            KeyType::Range => "RANGE",
+            KeyType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for KeyType {
+    fn default() -> KeyType {
+        KeyType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for KeyType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for KeyType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for KeyType {
+    fn from(value: &'a str) -> KeyType {
+        match value {
+            "HASH" => KeyType::Hash,
+            "RANGE" => KeyType::Range,
+            _ => KeyType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for KeyType {
+    fn from(value: String) -> KeyType {
+        match value.as_str() {
+            "HASH" => KeyType::Hash,
+            "RANGE" => KeyType::Range,
+            _ => KeyType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for KeyType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<KeyType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for KeyType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for KeyType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for KeyType {
+    fn deserialize<D>(deserializer: D) -> Result<KeyType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents the input of a <code>ListStreams</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -221,6 +314,104 @@ pub struct ListStreamsOutput {
     pub streams: Option<Vec<Stream>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OperationType {
+    /// `INSERT`
+    Insert,
+    /// `MODIFY`
+    Modify,
+    /// `REMOVE`
+    Remove,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl OperationType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OperationType::Insert => "INSERT",
+            OperationType::Modify => "MODIFY",
+            OperationType::Remove => "REMOVE",
+            OperationType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for OperationType {
+    fn default() -> OperationType {
+        OperationType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for OperationType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for OperationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for OperationType {
+    fn from(value: &'a str) -> OperationType {
+        match value {
+            "INSERT" => OperationType::Insert,
+            "MODIFY" => OperationType::Modify,
+            "REMOVE" => OperationType::Remove,
+            _ => OperationType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OperationType {
+    fn from(value: String) -> OperationType {
+        match value.as_str() {
+            "INSERT" => OperationType::Insert,
+            "MODIFY" => OperationType::Modify,
+            "REMOVE" => OperationType::Remove,
+            _ => OperationType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for OperationType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<OperationType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OperationType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for OperationType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OperationType {
+    fn deserialize<D>(deserializer: D) -> Result<OperationType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A description of a unique event within a stream.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -240,7 +431,7 @@ pub struct Record {
     /// <p><p>The type of data modification that was performed on the DynamoDB table:</p> <ul> <li> <p> <code>INSERT</code> - a new item was added to the table.</p> </li> <li> <p> <code>MODIFY</code> - one or more of an existing item&#39;s attributes were modified.</p> </li> <li> <p> <code>REMOVE</code> - the item was deleted from the table</p> </li> </ul></p>
     #[serde(rename = "eventName")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub event_name: Option<String>,
+    pub event_name: Option<OperationType>,
     /// <p>The AWS service from which the stream record originated. For DynamoDB Streams, this is <code>aws:dynamodb</code>.</p>
     #[serde(rename = "eventSource")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -287,6 +478,109 @@ pub struct Shard {
     pub shard_id: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ShardIteratorType {
+    /// `TRIM_HORIZON`
+    TrimHorizon,
+    /// `LATEST`
+    Latest,
+    /// `AT_SEQUENCE_NUMBER`
+    AtSequenceNumber,
+    /// `AFTER_SEQUENCE_NUMBER`
+    AfterSequenceNumber,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ShardIteratorType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ShardIteratorType::TrimHorizon => "TRIM_HORIZON",
+            ShardIteratorType::Latest => "LATEST",
+            ShardIteratorType::AtSequenceNumber => "AT_SEQUENCE_NUMBER",
+            ShardIteratorType::AfterSequenceNumber => "AFTER_SEQUENCE_NUMBER",
+            ShardIteratorType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ShardIteratorType {
+    fn default() -> ShardIteratorType {
+        ShardIteratorType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ShardIteratorType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ShardIteratorType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ShardIteratorType {
+    fn from(value: &'a str) -> ShardIteratorType {
+        match value {
+            "TRIM_HORIZON" => ShardIteratorType::TrimHorizon,
+            "LATEST" => ShardIteratorType::Latest,
+            "AT_SEQUENCE_NUMBER" => ShardIteratorType::AtSequenceNumber,
+            "AFTER_SEQUENCE_NUMBER" => ShardIteratorType::AfterSequenceNumber,
+            _ => ShardIteratorType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ShardIteratorType {
+    fn from(value: String) -> ShardIteratorType {
+        match value.as_str() {
+            "TRIM_HORIZON" => ShardIteratorType::TrimHorizon,
+            "LATEST" => ShardIteratorType::Latest,
+            "AT_SEQUENCE_NUMBER" => ShardIteratorType::AtSequenceNumber,
+            "AFTER_SEQUENCE_NUMBER" => ShardIteratorType::AfterSequenceNumber,
+            _ => ShardIteratorType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ShardIteratorType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ShardIteratorType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ShardIteratorType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ShardIteratorType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ShardIteratorType {
+    fn deserialize<D>(deserializer: D) -> Result<ShardIteratorType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents all of the data describing a particular stream.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -311,8 +605,13 @@ pub struct Stream {
 pub struct StreamDescription {
     /// <p>The date and time when the request to create this stream was issued.</p>
     #[serde(rename = "CreationRequestDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_request_date_time: Option<f64>,
+    pub creation_request_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The key attribute(s) of the stream's DynamoDB table.</p>
     #[serde(rename = "KeySchema")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -336,11 +635,11 @@ pub struct StreamDescription {
     /// <p><p>Indicates the current status of the stream:</p> <ul> <li> <p> <code>ENABLING</code> - Streams is currently being enabled on the DynamoDB table.</p> </li> <li> <p> <code>ENABLED</code> - the stream is enabled.</p> </li> <li> <p> <code>DISABLING</code> - Streams is currently being disabled on the DynamoDB table.</p> </li> <li> <p> <code>DISABLED</code> - the stream is disabled.</p> </li> </ul></p>
     #[serde(rename = "StreamStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stream_status: Option<String>,
+    pub stream_status: Option<StreamStatus>,
     /// <p><p>Indicates the format of the records within this stream:</p> <ul> <li> <p> <code>KEYS<em>ONLY</code> - only the key attributes of items that were modified in the DynamoDB table.</p> </li> <li> <p> <code>NEW</em>IMAGE</code> - entire items from the table, as they appeared after they were modified.</p> </li> <li> <p> <code>OLD<em>IMAGE</code> - entire items from the table, as they appeared before they were modified.</p> </li> <li> <p> <code>NEW</em>AND<em>OLD</em>IMAGES</code> - both the new and the old images of the items from the table.</p> </li> </ul></p>
     #[serde(rename = "StreamViewType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stream_view_type: Option<String>,
+    pub stream_view_type: Option<StreamViewType>,
     /// <p>The DynamoDB table with which the stream is associated.</p>
     #[serde(rename = "TableName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -353,8 +652,13 @@ pub struct StreamDescription {
 pub struct StreamRecord {
     /// <p>The approximate date and time when the stream record was created, in <a href="http://www.epochconverter.com/">UNIX epoch time</a> format.</p>
     #[serde(rename = "ApproximateCreationDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub approximate_creation_date_time: Option<f64>,
+    pub approximate_creation_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The primary key attribute(s) for the DynamoDB item that was modified.</p>
     #[serde(rename = "Keys")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -378,9 +682,215 @@ pub struct StreamRecord {
     /// <p><p>The type of data from the modified DynamoDB item that was captured in this stream record:</p> <ul> <li> <p> <code>KEYS<em>ONLY</code> - only the key attributes of the modified item.</p> </li> <li> <p> <code>NEW</em>IMAGE</code> - the entire item, as it appeared after it was modified.</p> </li> <li> <p> <code>OLD<em>IMAGE</code> - the entire item, as it appeared before it was modified.</p> </li> <li> <p> <code>NEW</em>AND<em>OLD</em>IMAGES</code> - both the new and the old item images of the item.</p> </li> </ul></p>
     #[serde(rename = "StreamViewType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stream_view_type: Option<String>,
+    pub stream_view_type: Option<StreamViewType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StreamStatus {
+    /// `ENABLING`
+    Enabling,
+    /// `ENABLED`
+    Enabled,
+    /// `DISABLING`
+    Disabling,
+    /// `DISABLED`
+    Disabled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl StreamStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StreamStatus::Enabling => "ENABLING",
+            StreamStatus::Enabled => "ENABLED",
+            StreamStatus::Disabling => "DISABLING",
+            StreamStatus::Disabled => "DISABLED",
+            StreamStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for StreamStatus {
+    fn default() -> StreamStatus {
+        StreamStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for StreamStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for StreamStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for StreamStatus {
+    fn from(value: &'a str) -> StreamStatus {
+        match value {
+            "ENABLING" => StreamStatus::Enabling,
+            "ENABLED" => StreamStatus::Enabled,
+            "DISABLING" => StreamStatus::Disabling,
+            "DISABLED" => StreamStatus::Disabled,
+            _ => StreamStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StreamStatus {
+    fn from(value: String) -> StreamStatus {
+        match value.as_str() {
+            "ENABLING" => StreamStatus::Enabling,
+            "ENABLED" => StreamStatus::Enabled,
+            "DISABLING" => StreamStatus::Disabling,
+            "DISABLED" => StreamStatus::Disabled,
+            _ => StreamStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for StreamStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<StreamStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StreamStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for StreamStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for StreamStatus {
+    fn deserialize<D>(deserializer: D) -> Result<StreamStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StreamViewType {
+    /// `NEW_IMAGE`
+    NewImage,
+    /// `OLD_IMAGE`
+    OldImage,
+    /// `NEW_AND_OLD_IMAGES`
+    NewAndOldImages,
+    /// `KEYS_ONLY`
+    KeysOnly,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl StreamViewType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StreamViewType::NewImage => "NEW_IMAGE",
+            StreamViewType::OldImage => "OLD_IMAGE",
+            StreamViewType::NewAndOldImages => "NEW_AND_OLD_IMAGES",
+            StreamViewType::KeysOnly => "KEYS_ONLY",
+            StreamViewType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for StreamViewType {
+    fn default() -> StreamViewType {
+        StreamViewType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for StreamViewType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for StreamViewType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for StreamViewType {
+    fn from(value: &'a str) -> StreamViewType {
+        match value {
+            "NEW_IMAGE" => StreamViewType::NewImage,
+            "OLD_IMAGE" => StreamViewType::OldImage,
+            "NEW_AND_OLD_IMAGES" => StreamViewType::NewAndOldImages,
+            "KEYS_ONLY" => StreamViewType::KeysOnly,
+            _ => StreamViewType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StreamViewType {
+    fn from(value: String) -> StreamViewType {
+        match value.as_str() {
+            "NEW_IMAGE" => StreamViewType::NewImage,
+            "OLD_IMAGE" => StreamViewType::OldImage,
+            "NEW_AND_OLD_IMAGES" => StreamViewType::NewAndOldImages,
+            "KEYS_ONLY" => StreamViewType::KeysOnly,
+            _ => StreamViewType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for StreamViewType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<StreamViewType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StreamViewType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for StreamViewType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StreamViewType {
+    fn deserialize<D>(deserializer: D) -> Result<StreamViewType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// Errors returned by DescribeStream
 #[derive(Debug, PartialEq)]
 pub enum DescribeStreamError {
@@ -588,11 +1098,12 @@ pub struct DynamoDbStreamsClient {
 impl DynamoDbStreamsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> DynamoDbStreamsClient {
         DynamoDbStreamsClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "DynamoDB Streams"),
         }
     }
 
@@ -624,6 +1135,7 @@ impl DynamoDbStreams for DynamoDbStreamsClient {
         input: DescribeStreamInput,
     ) -> Result<DescribeStreamOutput, RusotoError<DescribeStreamError>> {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("DescribeStream");
         request.set_endpoint_prefix("streams.dynamodb".to_string());
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDBStreams_20120810.DescribeStream");
@@ -651,6 +1163,7 @@ impl DynamoDbStreams for DynamoDbStreamsClient {
         input: GetRecordsInput,
     ) -> Result<GetRecordsOutput, RusotoError<GetRecordsError>> {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("GetRecords");
         request.set_endpoint_prefix("streams.dynamodb".to_string());
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDBStreams_20120810.GetRecords");
@@ -678,6 +1191,7 @@ impl DynamoDbStreams for DynamoDbStreamsClient {
         input: GetShardIteratorInput,
     ) -> Result<GetShardIteratorOutput, RusotoError<GetShardIteratorError>> {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("GetShardIterator");
         request.set_endpoint_prefix("streams.dynamodb".to_string());
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDBStreams_20120810.GetShardIterator");
@@ -705,6 +1219,7 @@ impl DynamoDbStreams for DynamoDbStreamsClient {
         input: ListStreamsInput,
     ) -> Result<ListStreamsOutput, RusotoError<ListStreamsError>> {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("ListStreams");
         request.set_endpoint_prefix("streams.dynamodb".to_string());
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDBStreams_20120810.ListStreams");
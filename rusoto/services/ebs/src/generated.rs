@@ -57,6 +57,94 @@ pub struct ChangedBlock {
     pub second_block_token: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ChecksumAlgorithm {
+    /// `SHA256`
+    Sha256,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ChecksumAlgorithm {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ChecksumAlgorithm::Sha256 => "SHA256",
+            ChecksumAlgorithm::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ChecksumAlgorithm {
+    fn default() -> ChecksumAlgorithm {
+        ChecksumAlgorithm::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ChecksumAlgorithm {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ChecksumAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ChecksumAlgorithm {
+    fn from(value: &'a str) -> ChecksumAlgorithm {
+        match value {
+            "SHA256" => ChecksumAlgorithm::Sha256,
+            _ => ChecksumAlgorithm::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ChecksumAlgorithm {
+    fn from(value: String) -> ChecksumAlgorithm {
+        match value.as_str() {
+            "SHA256" => ChecksumAlgorithm::Sha256,
+            _ => ChecksumAlgorithm::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ChecksumAlgorithm {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ChecksumAlgorithm, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ChecksumAlgorithm {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ChecksumAlgorithm {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ChecksumAlgorithm {
+    fn deserialize<D>(deserializer: D) -> Result<ChecksumAlgorithm, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct GetSnapshotBlockRequest {
@@ -78,7 +166,7 @@ pub struct GetSnapshotBlockResponse {
     /// <p>The checksum generated for the block.</p>
     pub checksum: Option<String>,
     /// <p>The algorithm used to generate the checksum for the block, such as SHA256.</p>
-    pub checksum_algorithm: Option<String>,
+    pub checksum_algorithm: Option<ChecksumAlgorithm>,
     /// <p>The size of the data in the block.</p>
     pub data_length: Option<i64>,
 }
@@ -120,8 +208,13 @@ pub struct ListChangedBlocksResponse {
     pub changed_blocks: Option<Vec<ChangedBlock>>,
     /// <p>The time when the <code>block token</code> expires.</p>
     #[serde(rename = "ExpiryTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expiry_time: Option<f64>,
+    pub expiry_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The token to use to retrieve the next page of results. This value is null when there are no more results to return.</p>
     #[serde(rename = "NextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -165,8 +258,13 @@ pub struct ListSnapshotBlocksResponse {
     pub blocks: Option<Vec<Block>>,
     /// <p>The time when the <code>block token</code> expires.</p>
     #[serde(rename = "ExpiryTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expiry_time: Option<f64>,
+    pub expiry_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The token to use to retrieve the next page of results. This value is null when there are no more results to return.</p>
     #[serde(rename = "NextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -177,6 +275,115 @@ pub struct ListSnapshotBlocksResponse {
     pub volume_size: Option<i64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ResourceNotFoundExceptionReason {
+    /// `SNAPSHOT_NOT_FOUND`
+    SnapshotNotFound,
+    /// `GRANT_NOT_FOUND`
+    GrantNotFound,
+    /// `DEPENDENCY_RESOURCE_NOT_FOUND`
+    DependencyResourceNotFound,
+    /// `IMAGE_NOT_FOUND`
+    ImageNotFound,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ResourceNotFoundExceptionReason {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceNotFoundExceptionReason::SnapshotNotFound => "SNAPSHOT_NOT_FOUND",
+            ResourceNotFoundExceptionReason::GrantNotFound => "GRANT_NOT_FOUND",
+            ResourceNotFoundExceptionReason::DependencyResourceNotFound => {
+                "DEPENDENCY_RESOURCE_NOT_FOUND"
+            }
+            ResourceNotFoundExceptionReason::ImageNotFound => "IMAGE_NOT_FOUND",
+            ResourceNotFoundExceptionReason::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ResourceNotFoundExceptionReason {
+    fn default() -> ResourceNotFoundExceptionReason {
+        ResourceNotFoundExceptionReason::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ResourceNotFoundExceptionReason {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ResourceNotFoundExceptionReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ResourceNotFoundExceptionReason {
+    fn from(value: &'a str) -> ResourceNotFoundExceptionReason {
+        match value {
+            "SNAPSHOT_NOT_FOUND" => ResourceNotFoundExceptionReason::SnapshotNotFound,
+            "GRANT_NOT_FOUND" => ResourceNotFoundExceptionReason::GrantNotFound,
+            "DEPENDENCY_RESOURCE_NOT_FOUND" => {
+                ResourceNotFoundExceptionReason::DependencyResourceNotFound
+            }
+            "IMAGE_NOT_FOUND" => ResourceNotFoundExceptionReason::ImageNotFound,
+            _ => ResourceNotFoundExceptionReason::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceNotFoundExceptionReason {
+    fn from(value: String) -> ResourceNotFoundExceptionReason {
+        match value.as_str() {
+            "SNAPSHOT_NOT_FOUND" => ResourceNotFoundExceptionReason::SnapshotNotFound,
+            "GRANT_NOT_FOUND" => ResourceNotFoundExceptionReason::GrantNotFound,
+            "DEPENDENCY_RESOURCE_NOT_FOUND" => {
+                ResourceNotFoundExceptionReason::DependencyResourceNotFound
+            }
+            "IMAGE_NOT_FOUND" => ResourceNotFoundExceptionReason::ImageNotFound,
+            _ => ResourceNotFoundExceptionReason::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ResourceNotFoundExceptionReason {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ResourceNotFoundExceptionReason, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ResourceNotFoundExceptionReason {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ResourceNotFoundExceptionReason {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ResourceNotFoundExceptionReason {
+    fn deserialize<D>(deserializer: D) -> Result<ResourceNotFoundExceptionReason, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// Errors returned by GetSnapshotBlock
 #[derive(Debug, PartialEq)]
 pub enum GetSnapshotBlockError {
@@ -298,11 +505,12 @@ pub struct EbsClient {
 impl EbsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> EbsClient {
         EbsClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "EBS"),
         }
     }
 
@@ -340,6 +548,7 @@ impl Ebs for EbsClient {
         );
 
         let mut request = SignedRequest::new("GET", "ebs", &self.region, &request_uri);
+        request.set_operation("GetSnapshotBlock");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -363,7 +572,7 @@ impl Ebs for EbsClient {
             };
             if let Some(checksum_algorithm) = response.headers.get("x-amz-Checksum-Algorithm") {
                 let value = checksum_algorithm.to_owned();
-                result.checksum_algorithm = Some(value)
+                result.checksum_algorithm = Some(value.into())
             };
             if let Some(data_length) = response.headers.get("x-amz-Data-Length") {
                 let value = data_length.to_owned();
@@ -388,6 +597,7 @@ impl Ebs for EbsClient {
         );
 
         let mut request = SignedRequest::new("GET", "ebs", &self.region, &request_uri);
+        request.set_operation("ListChangedBlocks");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -433,6 +643,7 @@ impl Ebs for EbsClient {
         );
 
         let mut request = SignedRequest::new("GET", "ebs", &self.region, &request_uri);
+        request.set_operation("ListSnapshotBlocks");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -129,11 +129,12 @@ pub struct Ec2InstanceConnectClient {
 impl Ec2InstanceConnectClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> Ec2InstanceConnectClient {
         Ec2InstanceConnectClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "EC2 Instance Connect"),
         }
     }
 
@@ -165,6 +166,7 @@ impl Ec2InstanceConnect for Ec2InstanceConnectClient {
         input: SendSSHPublicKeyRequest,
     ) -> Result<SendSSHPublicKeyResponse, RusotoError<SendSSHPublicKeyError>> {
         let mut request = SignedRequest::new("POST", "ec2-instance-connect", &self.region, "/");
+        request.set_operation("SendSSHPublicKey");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
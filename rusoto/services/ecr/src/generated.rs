@@ -47,8 +47,13 @@ pub struct AuthorizationData {
     pub authorization_token: Option<String>,
     /// <p>The Unix time in seconds and milliseconds when the authorization token expires. Authorization tokens are valid for 12 hours.</p>
     #[serde(rename = "expiresAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expires_at: Option<f64>,
+    pub expires_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The registry URL to use for this authorization token in a <code>docker login</code> command. The Amazon ECR registry URL format is <code>https://aws_account_id.dkr.ecr.region.amazonaws.com</code>. For example, <code>https://012345678910.dkr.ecr.us-east-1.amazonaws.com</code>.. </p>
     #[serde(rename = "proxyEndpoint")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -193,7 +198,7 @@ pub struct CreateRepositoryRequest {
     /// <p>The tag mutability setting for the repository. If this parameter is omitted, the default setting of <code>MUTABLE</code> will be used which will allow image tags to be overwritten. If <code>IMMUTABLE</code> is specified, all image tags within the repository will be immutable which will prevent them from being overwritten.</p>
     #[serde(rename = "imageTagMutability")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub image_tag_mutability: Option<String>,
+    pub image_tag_mutability: Option<ImageTagMutability>,
     /// <p>The name to use for the repository. The repository name may be specified on its own (such as <code>nginx-web-app</code>) or it can be prepended with a namespace to group the repository into a category (such as <code>project-a/nginx-web-app</code>).</p>
     #[serde(rename = "repositoryName")]
     pub repository_name: String,
@@ -229,8 +234,13 @@ pub struct DeleteLifecyclePolicyRequest {
 pub struct DeleteLifecyclePolicyResponse {
     /// <p>The time stamp of the last time that the lifecycle policy was run.</p>
     #[serde(rename = "lastEvaluatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_evaluated_at: Option<f64>,
+    pub last_evaluated_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The JSON lifecycle policy text.</p>
     #[serde(rename = "lifecyclePolicyText")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -356,7 +366,7 @@ pub struct DescribeImagesFilter {
     /// <p>The tag status with which to filter your <a>DescribeImages</a> results. You can filter results based on whether they are <code>TAGGED</code> or <code>UNTAGGED</code>.</p>
     #[serde(rename = "tagStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub tag_status: Option<String>,
+    pub tag_status: Option<TagStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -434,6 +444,119 @@ pub struct DescribeRepositoriesResponse {
     pub repositories: Option<Vec<Repository>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FindingSeverity {
+    /// `INFORMATIONAL`
+    Informational,
+    /// `LOW`
+    Low,
+    /// `MEDIUM`
+    Medium,
+    /// `HIGH`
+    High,
+    /// `CRITICAL`
+    Critical,
+    /// `UNDEFINED`
+    Undefined,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl FindingSeverity {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FindingSeverity::Informational => "INFORMATIONAL",
+            FindingSeverity::Low => "LOW",
+            FindingSeverity::Medium => "MEDIUM",
+            FindingSeverity::High => "HIGH",
+            FindingSeverity::Critical => "CRITICAL",
+            FindingSeverity::Undefined => "UNDEFINED",
+            FindingSeverity::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for FindingSeverity {
+    fn default() -> FindingSeverity {
+        FindingSeverity::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for FindingSeverity {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FindingSeverity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FindingSeverity {
+    fn from(value: &'a str) -> FindingSeverity {
+        match value {
+            "INFORMATIONAL" => FindingSeverity::Informational,
+            "LOW" => FindingSeverity::Low,
+            "MEDIUM" => FindingSeverity::Medium,
+            "HIGH" => FindingSeverity::High,
+            "CRITICAL" => FindingSeverity::Critical,
+            "UNDEFINED" => FindingSeverity::Undefined,
+            _ => FindingSeverity::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FindingSeverity {
+    fn from(value: String) -> FindingSeverity {
+        match value.as_str() {
+            "INFORMATIONAL" => FindingSeverity::Informational,
+            "LOW" => FindingSeverity::Low,
+            "MEDIUM" => FindingSeverity::Medium,
+            "HIGH" => FindingSeverity::High,
+            "CRITICAL" => FindingSeverity::Critical,
+            "UNDEFINED" => FindingSeverity::Undefined,
+            _ => FindingSeverity::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for FindingSeverity {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<FindingSeverity, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FindingSeverity {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FindingSeverity {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FindingSeverity {
+    fn deserialize<D>(deserializer: D) -> Result<FindingSeverity, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct GetAuthorizationTokenRequest {
@@ -534,7 +657,7 @@ pub struct GetLifecyclePolicyPreviewResponse {
     /// <p>The status of the lifecycle policy preview request.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<LifecyclePolicyPreviewStatus>,
     /// <p>The list of images that is returned as a result of the action.</p>
     #[serde(rename = "summary")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -558,8 +681,13 @@ pub struct GetLifecyclePolicyRequest {
 pub struct GetLifecyclePolicyResponse {
     /// <p>The time stamp of the last time that the lifecycle policy was run.</p>
     #[serde(rename = "lastEvaluatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_evaluated_at: Option<f64>,
+    pub last_evaluated_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The JSON lifecycle policy text.</p>
     #[serde(rename = "lifecyclePolicyText")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -625,6 +753,94 @@ pub struct Image {
     pub repository_name: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ImageActionType {
+    /// `EXPIRE`
+    Expire,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ImageActionType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ImageActionType::Expire => "EXPIRE",
+            ImageActionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ImageActionType {
+    fn default() -> ImageActionType {
+        ImageActionType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ImageActionType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ImageActionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ImageActionType {
+    fn from(value: &'a str) -> ImageActionType {
+        match value {
+            "EXPIRE" => ImageActionType::Expire,
+            _ => ImageActionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ImageActionType {
+    fn from(value: String) -> ImageActionType {
+        match value.as_str() {
+            "EXPIRE" => ImageActionType::Expire,
+            _ => ImageActionType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ImageActionType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ImageActionType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ImageActionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ImageActionType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ImageActionType {
+    fn deserialize<D>(deserializer: D) -> Result<ImageActionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An object that describes an image returned by a <a>DescribeImages</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -635,8 +851,13 @@ pub struct ImageDetail {
     pub image_digest: Option<String>,
     /// <p>The date and time, expressed in standard JavaScript date format, at which the current image was pushed to the repository. </p>
     #[serde(rename = "imagePushedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub image_pushed_at: Option<f64>,
+    pub image_pushed_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A summary of the last completed image scan.</p>
     #[serde(rename = "imageScanFindingsSummary")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -670,7 +891,7 @@ pub struct ImageFailure {
     /// <p>The code associated with the failure.</p>
     #[serde(rename = "failureCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub failure_code: Option<String>,
+    pub failure_code: Option<ImageFailureCode>,
     /// <p>The reason for the failure.</p>
     #[serde(rename = "failureReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -681,6 +902,139 @@ pub struct ImageFailure {
     pub image_id: Option<ImageIdentifier>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ImageFailureCode {
+    /// `InvalidImageDigest`
+    InvalidImageDigest,
+    /// `InvalidImageTag`
+    InvalidImageTag,
+    /// `ImageTagDoesNotMatchDigest`
+    ImageTagDoesNotMatchDigest,
+    /// `ImageNotFound`
+    ImageNotFound,
+    /// `MissingDigestAndTag`
+    MissingDigestAndTag,
+    /// `ImageReferencedByManifestList`
+    ImageReferencedByManifestList,
+    /// `KmsError`
+    KmsError,
+    /// `UpstreamAccessDenied`
+    UpstreamAccessDenied,
+    /// `UpstreamTooManyRequests`
+    UpstreamTooManyRequests,
+    /// `UpstreamUnavailable`
+    UpstreamUnavailable,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ImageFailureCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ImageFailureCode::InvalidImageDigest => "InvalidImageDigest",
+            ImageFailureCode::InvalidImageTag => "InvalidImageTag",
+            ImageFailureCode::ImageTagDoesNotMatchDigest => "ImageTagDoesNotMatchDigest",
+            ImageFailureCode::ImageNotFound => "ImageNotFound",
+            ImageFailureCode::MissingDigestAndTag => "MissingDigestAndTag",
+            ImageFailureCode::ImageReferencedByManifestList => "ImageReferencedByManifestList",
+            ImageFailureCode::KmsError => "KmsError",
+            ImageFailureCode::UpstreamAccessDenied => "UpstreamAccessDenied",
+            ImageFailureCode::UpstreamTooManyRequests => "UpstreamTooManyRequests",
+            ImageFailureCode::UpstreamUnavailable => "UpstreamUnavailable",
+            ImageFailureCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ImageFailureCode {
+    fn default() -> ImageFailureCode {
+        ImageFailureCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ImageFailureCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ImageFailureCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ImageFailureCode {
+    fn from(value: &'a str) -> ImageFailureCode {
+        match value {
+            "InvalidImageDigest" => ImageFailureCode::InvalidImageDigest,
+            "InvalidImageTag" => ImageFailureCode::InvalidImageTag,
+            "ImageTagDoesNotMatchDigest" => ImageFailureCode::ImageTagDoesNotMatchDigest,
+            "ImageNotFound" => ImageFailureCode::ImageNotFound,
+            "MissingDigestAndTag" => ImageFailureCode::MissingDigestAndTag,
+            "ImageReferencedByManifestList" => ImageFailureCode::ImageReferencedByManifestList,
+            "KmsError" => ImageFailureCode::KmsError,
+            "UpstreamAccessDenied" => ImageFailureCode::UpstreamAccessDenied,
+            "UpstreamTooManyRequests" => ImageFailureCode::UpstreamTooManyRequests,
+            "UpstreamUnavailable" => ImageFailureCode::UpstreamUnavailable,
+            _ => ImageFailureCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ImageFailureCode {
+    fn from(value: String) -> ImageFailureCode {
+        match value.as_str() {
+            "InvalidImageDigest" => ImageFailureCode::InvalidImageDigest,
+            "InvalidImageTag" => ImageFailureCode::InvalidImageTag,
+            "ImageTagDoesNotMatchDigest" => ImageFailureCode::ImageTagDoesNotMatchDigest,
+            "ImageNotFound" => ImageFailureCode::ImageNotFound,
+            "MissingDigestAndTag" => ImageFailureCode::MissingDigestAndTag,
+            "ImageReferencedByManifestList" => ImageFailureCode::ImageReferencedByManifestList,
+            "KmsError" => ImageFailureCode::KmsError,
+            "UpstreamAccessDenied" => ImageFailureCode::UpstreamAccessDenied,
+            "UpstreamTooManyRequests" => ImageFailureCode::UpstreamTooManyRequests,
+            "UpstreamUnavailable" => ImageFailureCode::UpstreamUnavailable,
+            _ => ImageFailureCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ImageFailureCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ImageFailureCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ImageFailureCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ImageFailureCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ImageFailureCode {
+    fn deserialize<D>(deserializer: D) -> Result<ImageFailureCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An object with identifying information for an Amazon ECR image.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ImageIdentifier {
@@ -713,7 +1067,7 @@ pub struct ImageScanFinding {
     /// <p>The finding severity.</p>
     #[serde(rename = "severity")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub severity: Option<String>,
+    pub severity: Option<FindingSeverity>,
     /// <p>A link containing additional details about the security vulnerability.</p>
     #[serde(rename = "uri")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -727,19 +1081,29 @@ pub struct ImageScanFindings {
     /// <p>The image vulnerability counts, sorted by severity.</p>
     #[serde(rename = "findingSeverityCounts")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub finding_severity_counts: Option<::std::collections::HashMap<String, i64>>,
+    pub finding_severity_counts: Option<::std::collections::HashMap<FindingSeverity, i64>>,
     /// <p>The findings from the image scan.</p>
     #[serde(rename = "findings")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub findings: Option<Vec<ImageScanFinding>>,
     /// <p>The time of the last completed image scan.</p>
     #[serde(rename = "imageScanCompletedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub image_scan_completed_at: Option<f64>,
+    pub image_scan_completed_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The time when the vulnerability data was last scanned.</p>
     #[serde(rename = "vulnerabilitySourceUpdatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub vulnerability_source_updated_at: Option<f64>,
+    pub vulnerability_source_updated_at: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>A summary of the last completed image scan.</p>
@@ -749,15 +1113,25 @@ pub struct ImageScanFindingsSummary {
     /// <p>The image vulnerability counts, sorted by severity.</p>
     #[serde(rename = "findingSeverityCounts")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub finding_severity_counts: Option<::std::collections::HashMap<String, i64>>,
+    pub finding_severity_counts: Option<::std::collections::HashMap<FindingSeverity, i64>>,
     /// <p>The time of the last completed image scan.</p>
     #[serde(rename = "imageScanCompletedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub image_scan_completed_at: Option<f64>,
+    pub image_scan_completed_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The time when the vulnerability data was last scanned.</p>
     #[serde(rename = "vulnerabilitySourceUpdatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub vulnerability_source_updated_at: Option<f64>,
+    pub vulnerability_source_updated_at: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>The current status of an image scan.</p>
@@ -771,7 +1145,7 @@ pub struct ImageScanStatus {
     /// <p>The current state of an image scan.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ScanStatus>,
 }
 
 /// <p>The image scanning configuration for a repository.</p>
@@ -783,6 +1157,99 @@ pub struct ImageScanningConfiguration {
     pub scan_on_push: Option<bool>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ImageTagMutability {
+    /// `MUTABLE`
+    Mutable,
+    /// `IMMUTABLE`
+    Immutable,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ImageTagMutability {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ImageTagMutability::Mutable => "MUTABLE",
+            ImageTagMutability::Immutable => "IMMUTABLE",
+            ImageTagMutability::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ImageTagMutability {
+    fn default() -> ImageTagMutability {
+        ImageTagMutability::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ImageTagMutability {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ImageTagMutability {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ImageTagMutability {
+    fn from(value: &'a str) -> ImageTagMutability {
+        match value {
+            "MUTABLE" => ImageTagMutability::Mutable,
+            "IMMUTABLE" => ImageTagMutability::Immutable,
+            _ => ImageTagMutability::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ImageTagMutability {
+    fn from(value: String) -> ImageTagMutability {
+        match value.as_str() {
+            "MUTABLE" => ImageTagMutability::Mutable,
+            "IMMUTABLE" => ImageTagMutability::Immutable,
+            _ => ImageTagMutability::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ImageTagMutability {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ImageTagMutability, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ImageTagMutability {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ImageTagMutability {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ImageTagMutability {
+    fn deserialize<D>(deserializer: D) -> Result<ImageTagMutability, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct InitiateLayerUploadRequest {
@@ -815,7 +1282,7 @@ pub struct Layer {
     /// <p>The availability status of the image layer.</p>
     #[serde(rename = "layerAvailability")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub layer_availability: Option<String>,
+    pub layer_availability: Option<LayerAvailability>,
     /// <p>The <code>sha256</code> digest of the image layer.</p>
     #[serde(rename = "layerDigest")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -830,6 +1297,99 @@ pub struct Layer {
     pub media_type: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LayerAvailability {
+    /// `AVAILABLE`
+    Available,
+    /// `UNAVAILABLE`
+    Unavailable,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LayerAvailability {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LayerAvailability::Available => "AVAILABLE",
+            LayerAvailability::Unavailable => "UNAVAILABLE",
+            LayerAvailability::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LayerAvailability {
+    fn default() -> LayerAvailability {
+        LayerAvailability::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LayerAvailability {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LayerAvailability {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LayerAvailability {
+    fn from(value: &'a str) -> LayerAvailability {
+        match value {
+            "AVAILABLE" => LayerAvailability::Available,
+            "UNAVAILABLE" => LayerAvailability::Unavailable,
+            _ => LayerAvailability::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LayerAvailability {
+    fn from(value: String) -> LayerAvailability {
+        match value.as_str() {
+            "AVAILABLE" => LayerAvailability::Available,
+            "UNAVAILABLE" => LayerAvailability::Unavailable,
+            _ => LayerAvailability::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LayerAvailability {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LayerAvailability, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LayerAvailability {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LayerAvailability {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LayerAvailability {
+    fn deserialize<D>(deserializer: D) -> Result<LayerAvailability, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An object representing an Amazon ECR image layer failure.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -837,7 +1397,7 @@ pub struct LayerFailure {
     /// <p>The failure code associated with the failure.</p>
     #[serde(rename = "failureCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub failure_code: Option<String>,
+    pub failure_code: Option<LayerFailureCode>,
     /// <p>The reason for the failure.</p>
     #[serde(rename = "failureReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -848,6 +1408,99 @@ pub struct LayerFailure {
     pub layer_digest: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LayerFailureCode {
+    /// `InvalidLayerDigest`
+    InvalidLayerDigest,
+    /// `MissingLayerDigest`
+    MissingLayerDigest,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LayerFailureCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LayerFailureCode::InvalidLayerDigest => "InvalidLayerDigest",
+            LayerFailureCode::MissingLayerDigest => "MissingLayerDigest",
+            LayerFailureCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LayerFailureCode {
+    fn default() -> LayerFailureCode {
+        LayerFailureCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LayerFailureCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LayerFailureCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LayerFailureCode {
+    fn from(value: &'a str) -> LayerFailureCode {
+        match value {
+            "InvalidLayerDigest" => LayerFailureCode::InvalidLayerDigest,
+            "MissingLayerDigest" => LayerFailureCode::MissingLayerDigest,
+            _ => LayerFailureCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LayerFailureCode {
+    fn from(value: String) -> LayerFailureCode {
+        match value.as_str() {
+            "InvalidLayerDigest" => LayerFailureCode::InvalidLayerDigest,
+            "MissingLayerDigest" => LayerFailureCode::MissingLayerDigest,
+            _ => LayerFailureCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LayerFailureCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LayerFailureCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LayerFailureCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LayerFailureCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LayerFailureCode {
+    fn deserialize<D>(deserializer: D) -> Result<LayerFailureCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The filter for the lifecycle policy preview.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -855,7 +1508,7 @@ pub struct LifecyclePolicyPreviewFilter {
     /// <p>The tag status of the image.</p>
     #[serde(rename = "tagStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub tag_status: Option<String>,
+    pub tag_status: Option<TagStatus>,
 }
 
 /// <p>The result of the lifecycle policy preview.</p>
@@ -876,14 +1529,122 @@ pub struct LifecyclePolicyPreviewResult {
     pub image_digest: Option<String>,
     /// <p>The date and time, expressed in standard JavaScript date format, at which the current image was pushed to the repository.</p>
     #[serde(rename = "imagePushedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub image_pushed_at: Option<f64>,
+    pub image_pushed_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The list of tags associated with this image.</p>
     #[serde(rename = "imageTags")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub image_tags: Option<Vec<String>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LifecyclePolicyPreviewStatus {
+    /// `IN_PROGRESS`
+    InProgress,
+    /// `COMPLETE`
+    Complete,
+    /// `EXPIRED`
+    Expired,
+    /// `FAILED`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LifecyclePolicyPreviewStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LifecyclePolicyPreviewStatus::InProgress => "IN_PROGRESS",
+            LifecyclePolicyPreviewStatus::Complete => "COMPLETE",
+            LifecyclePolicyPreviewStatus::Expired => "EXPIRED",
+            LifecyclePolicyPreviewStatus::Failed => "FAILED",
+            LifecyclePolicyPreviewStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LifecyclePolicyPreviewStatus {
+    fn default() -> LifecyclePolicyPreviewStatus {
+        LifecyclePolicyPreviewStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LifecyclePolicyPreviewStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LifecyclePolicyPreviewStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LifecyclePolicyPreviewStatus {
+    fn from(value: &'a str) -> LifecyclePolicyPreviewStatus {
+        match value {
+            "IN_PROGRESS" => LifecyclePolicyPreviewStatus::InProgress,
+            "COMPLETE" => LifecyclePolicyPreviewStatus::Complete,
+            "EXPIRED" => LifecyclePolicyPreviewStatus::Expired,
+            "FAILED" => LifecyclePolicyPreviewStatus::Failed,
+            _ => LifecyclePolicyPreviewStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LifecyclePolicyPreviewStatus {
+    fn from(value: String) -> LifecyclePolicyPreviewStatus {
+        match value.as_str() {
+            "IN_PROGRESS" => LifecyclePolicyPreviewStatus::InProgress,
+            "COMPLETE" => LifecyclePolicyPreviewStatus::Complete,
+            "EXPIRED" => LifecyclePolicyPreviewStatus::Expired,
+            "FAILED" => LifecyclePolicyPreviewStatus::Failed,
+            _ => LifecyclePolicyPreviewStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LifecyclePolicyPreviewStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LifecyclePolicyPreviewStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LifecyclePolicyPreviewStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LifecyclePolicyPreviewStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LifecyclePolicyPreviewStatus {
+    fn deserialize<D>(deserializer: D) -> Result<LifecyclePolicyPreviewStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The summary of the lifecycle policy preview request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -901,7 +1662,7 @@ pub struct LifecyclePolicyRuleAction {
     /// <p>The type of action to be taken.</p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<ImageActionType>,
 }
 
 /// <p>An object representing a filter on a <a>ListImages</a> operation.</p>
@@ -911,7 +1672,7 @@ pub struct ListImagesFilter {
     /// <p>The tag status with which to filter your <a>ListImages</a> results. You can filter results based on whether they are <code>TAGGED</code> or <code>UNTAGGED</code>.</p>
     #[serde(rename = "tagStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub tag_status: Option<String>,
+    pub tag_status: Option<TagStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -1033,7 +1794,7 @@ pub struct PutImageScanningConfigurationResponse {
 pub struct PutImageTagMutabilityRequest {
     /// <p>The tag mutability setting for the repository. If <code>MUTABLE</code> is specified, image tags can be overwritten. If <code>IMMUTABLE</code> is specified, all image tags within the repository will be immutable which will prevent them from being overwritten.</p>
     #[serde(rename = "imageTagMutability")]
-    pub image_tag_mutability: String,
+    pub image_tag_mutability: ImageTagMutability,
     /// <p>The AWS account ID associated with the registry that contains the repository in which to update the image tag mutability settings. If you do not specify a registry, the default registry is assumed.</p>
     #[serde(rename = "registryId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1049,7 +1810,7 @@ pub struct PutImageTagMutabilityResponse {
     /// <p>The image tag mutability setting for the repository.</p>
     #[serde(rename = "imageTagMutability")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub image_tag_mutability: Option<String>,
+    pub image_tag_mutability: Option<ImageTagMutability>,
     /// <p>The registry ID associated with the request.</p>
     #[serde(rename = "registryId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1098,15 +1859,20 @@ pub struct PutLifecyclePolicyResponse {
 pub struct Repository {
     /// <p>The date and time, in JavaScript date format, when the repository was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     #[serde(rename = "imageScanningConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub image_scanning_configuration: Option<ImageScanningConfiguration>,
     /// <p>The tag mutability setting for the repository.</p>
     #[serde(rename = "imageTagMutability")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub image_tag_mutability: Option<String>,
+    pub image_tag_mutability: Option<ImageTagMutability>,
     /// <p>The AWS account ID associated with the registry that contains the repository.</p>
     #[serde(rename = "registryId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1125,6 +1891,129 @@ pub struct Repository {
     pub repository_uri: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ScanStatus {
+    /// `IN_PROGRESS`
+    InProgress,
+    /// `COMPLETE`
+    Complete,
+    /// `FAILED`
+    Failed,
+    /// `UNSUPPORTED_IMAGE`
+    UnsupportedImage,
+    /// `ACTIVE`
+    Active,
+    /// `PENDING`
+    Pending,
+    /// `SCAN_ELIGIBILITY_EXPIRED`
+    ScanEligibilityExpired,
+    /// `FINDINGS_UNAVAILABLE`
+    FindingsUnavailable,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ScanStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ScanStatus::InProgress => "IN_PROGRESS",
+            ScanStatus::Complete => "COMPLETE",
+            ScanStatus::Failed => "FAILED",
+            ScanStatus::UnsupportedImage => "UNSUPPORTED_IMAGE",
+            ScanStatus::Active => "ACTIVE",
+            ScanStatus::Pending => "PENDING",
+            ScanStatus::ScanEligibilityExpired => "SCAN_ELIGIBILITY_EXPIRED",
+            ScanStatus::FindingsUnavailable => "FINDINGS_UNAVAILABLE",
+            ScanStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ScanStatus {
+    fn default() -> ScanStatus {
+        ScanStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ScanStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ScanStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ScanStatus {
+    fn from(value: &'a str) -> ScanStatus {
+        match value {
+            "IN_PROGRESS" => ScanStatus::InProgress,
+            "COMPLETE" => ScanStatus::Complete,
+            "FAILED" => ScanStatus::Failed,
+            "UNSUPPORTED_IMAGE" => ScanStatus::UnsupportedImage,
+            "ACTIVE" => ScanStatus::Active,
+            "PENDING" => ScanStatus::Pending,
+            "SCAN_ELIGIBILITY_EXPIRED" => ScanStatus::ScanEligibilityExpired,
+            "FINDINGS_UNAVAILABLE" => ScanStatus::FindingsUnavailable,
+            _ => ScanStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ScanStatus {
+    fn from(value: String) -> ScanStatus {
+        match value.as_str() {
+            "IN_PROGRESS" => ScanStatus::InProgress,
+            "COMPLETE" => ScanStatus::Complete,
+            "FAILED" => ScanStatus::Failed,
+            "UNSUPPORTED_IMAGE" => ScanStatus::UnsupportedImage,
+            "ACTIVE" => ScanStatus::Active,
+            "PENDING" => ScanStatus::Pending,
+            "SCAN_ELIGIBILITY_EXPIRED" => ScanStatus::ScanEligibilityExpired,
+            "FINDINGS_UNAVAILABLE" => ScanStatus::FindingsUnavailable,
+            _ => ScanStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ScanStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ScanStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ScanStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ScanStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ScanStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ScanStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct SetRepositoryPolicyRequest {
@@ -1229,7 +2118,7 @@ pub struct StartLifecyclePolicyPreviewResponse {
     /// <p>The status of the lifecycle policy preview request.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<LifecyclePolicyPreviewStatus>,
 }
 
 /// <p>The metadata that you apply to a resource to help you categorize and organize them. Each tag consists of a key and an optional value, both of which you define. Tag keys can have a maximum character length of 128 characters, and tag values can have a maximum length of 256 characters.</p>
@@ -1260,6 +2149,104 @@ pub struct TagResourceRequest {
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TagResourceResponse {}
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TagStatus {
+    /// `TAGGED`
+    Tagged,
+    /// `UNTAGGED`
+    Untagged,
+    /// `ANY`
+    Any,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TagStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TagStatus::Tagged => "TAGGED",
+            TagStatus::Untagged => "UNTAGGED",
+            TagStatus::Any => "ANY",
+            TagStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TagStatus {
+    fn default() -> TagStatus {
+        TagStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TagStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TagStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TagStatus {
+    fn from(value: &'a str) -> TagStatus {
+        match value {
+            "TAGGED" => TagStatus::Tagged,
+            "UNTAGGED" => TagStatus::Untagged,
+            "ANY" => TagStatus::Any,
+            _ => TagStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TagStatus {
+    fn from(value: String) -> TagStatus {
+        match value.as_str() {
+            "TAGGED" => TagStatus::Tagged,
+            "UNTAGGED" => TagStatus::Untagged,
+            "ANY" => TagStatus::Any,
+            _ => TagStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TagStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TagStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TagStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TagStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TagStatus {
+    fn deserialize<D>(deserializer: D) -> Result<TagStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct UntagResourceRequest {
@@ -3043,11 +4030,12 @@ pub struct EcrClient {
 impl EcrClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> EcrClient {
         EcrClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "ECR"),
         }
     }
 
@@ -3080,6 +4068,7 @@ impl Ecr for EcrClient {
     ) -> Result<BatchCheckLayerAvailabilityResponse, RusotoError<BatchCheckLayerAvailabilityError>>
     {
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("BatchCheckLayerAvailability");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3111,6 +4100,7 @@ impl Ecr for EcrClient {
         input: BatchDeleteImageRequest,
     ) -> Result<BatchDeleteImageResponse, RusotoError<BatchDeleteImageError>> {
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("BatchDeleteImage");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3142,6 +4132,7 @@ impl Ecr for EcrClient {
         input: BatchGetImageRequest,
     ) -> Result<BatchGetImageResponse, RusotoError<BatchGetImageError>> {
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("BatchGetImage");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3172,6 +4163,7 @@ impl Ecr for EcrClient {
         input: CompleteLayerUploadRequest,
     ) -> Result<CompleteLayerUploadResponse, RusotoError<CompleteLayerUploadError>> {
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("CompleteLayerUpload");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3203,6 +4195,7 @@ impl Ecr for EcrClient {
         input: CreateRepositoryRequest,
     ) -> Result<CreateRepositoryResponse, RusotoError<CreateRepositoryError>> {
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("CreateRepository");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3234,6 +4227,7 @@ impl Ecr for EcrClient {
         input: DeleteLifecyclePolicyRequest,
     ) -> Result<DeleteLifecyclePolicyResponse, RusotoError<DeleteLifecyclePolicyError>> {
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("DeleteLifecyclePolicy");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3265,6 +4259,7 @@ impl Ecr for EcrClient {
         input: DeleteRepositoryRequest,
     ) -> Result<DeleteRepositoryResponse, RusotoError<DeleteRepositoryError>> {
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("DeleteRepository");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3296,6 +4291,7 @@ impl Ecr for EcrClient {
         input: DeleteRepositoryPolicyRequest,
     ) -> Result<DeleteRepositoryPolicyResponse, RusotoError<DeleteRepositoryPolicyError>> {
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("DeleteRepositoryPolicy");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3328,6 +4324,7 @@ impl Ecr for EcrClient {
     ) -> Result<DescribeImageScanFindingsResponse, RusotoError<DescribeImageScanFindingsError>>
     {
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("DescribeImageScanFindings");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3359,6 +4356,7 @@ impl Ecr for EcrClient {
         input: DescribeImagesRequest,
     ) -> Result<DescribeImagesResponse, RusotoError<DescribeImagesError>> {
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("DescribeImages");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3389,6 +4387,7 @@ impl Ecr for EcrClient {
         input: DescribeRepositoriesRequest,
     ) -> Result<DescribeRepositoriesResponse, RusotoError<DescribeRepositoriesError>> {
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("DescribeRepositories");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3420,6 +4419,7 @@ impl Ecr for EcrClient {
         input: GetAuthorizationTokenRequest,
     ) -> Result<GetAuthorizationTokenResponse, RusotoError<GetAuthorizationTokenError>> {
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("GetAuthorizationToken");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3451,6 +4451,7 @@ impl Ecr for EcrClient {
         input: GetDownloadUrlForLayerRequest,
     ) -> Result<GetDownloadUrlForLayerResponse, RusotoError<GetDownloadUrlForLayerError>> {
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("GetDownloadUrlForLayer");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3482,6 +4483,7 @@ impl Ecr for EcrClient {
         input: GetLifecyclePolicyRequest,
     ) -> Result<GetLifecyclePolicyResponse, RusotoError<GetLifecyclePolicyError>> {
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("GetLifecyclePolicy");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3514,6 +4516,7 @@ impl Ecr for EcrClient {
     ) -> Result<GetLifecyclePolicyPreviewResponse, RusotoError<GetLifecyclePolicyPreviewError>>
     {
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("GetLifecyclePolicyPreview");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3545,6 +4548,7 @@ impl Ecr for EcrClient {
         input: GetRepositoryPolicyRequest,
     ) -> Result<GetRepositoryPolicyResponse, RusotoError<GetRepositoryPolicyError>> {
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("GetRepositoryPolicy");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3576,6 +4580,7 @@ impl Ecr for EcrClient {
         input: InitiateLayerUploadRequest,
     ) -> Result<InitiateLayerUploadResponse, RusotoError<InitiateLayerUploadError>> {
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("InitiateLayerUpload");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3607,6 +4612,7 @@ impl Ecr for EcrClient {
         input: ListImagesRequest,
     ) -> Result<ListImagesResponse, RusotoError<ListImagesError>> {
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("ListImages");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3637,6 +4643,7 @@ impl Ecr for EcrClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("ListTagsForResource");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3668,6 +4675,7 @@ impl Ecr for EcrClient {
         input: PutImageRequest,
     ) -> Result<PutImageResponse, RusotoError<PutImageError>> {
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("PutImage");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3701,6 +4709,7 @@ impl Ecr for EcrClient {
         RusotoError<PutImageScanningConfigurationError>,
     > {
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("PutImageScanningConfiguration");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3732,6 +4741,7 @@ impl Ecr for EcrClient {
         input: PutImageTagMutabilityRequest,
     ) -> Result<PutImageTagMutabilityResponse, RusotoError<PutImageTagMutabilityError>> {
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("PutImageTagMutability");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3763,6 +4773,7 @@ impl Ecr for EcrClient {
         input: PutLifecyclePolicyRequest,
     ) -> Result<PutLifecyclePolicyResponse, RusotoError<PutLifecyclePolicyError>> {
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("PutLifecyclePolicy");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3794,6 +4805,7 @@ impl Ecr for EcrClient {
         input: SetRepositoryPolicyRequest,
     ) -> Result<SetRepositoryPolicyResponse, RusotoError<SetRepositoryPolicyError>> {
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("SetRepositoryPolicy");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3825,6 +4837,7 @@ impl Ecr for EcrClient {
         input: StartImageScanRequest,
     ) -> Result<StartImageScanResponse, RusotoError<StartImageScanError>> {
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("StartImageScan");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3856,6 +4869,7 @@ impl Ecr for EcrClient {
     ) -> Result<StartLifecyclePolicyPreviewResponse, RusotoError<StartLifecyclePolicyPreviewError>>
     {
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("StartLifecyclePolicyPreview");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3887,6 +4901,7 @@ impl Ecr for EcrClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("TagResource");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3917,6 +4932,7 @@ impl Ecr for EcrClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("UntagResource");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3947,6 +4963,7 @@ impl Ecr for EcrClient {
         input: UploadLayerPartRequest,
     ) -> Result<UploadLayerPartResponse, RusotoError<UploadLayerPartError>> {
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("UploadLayerPart");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3971,3 +4988,231 @@ impl Ecr for EcrClient {
         }
     }
 }
+impl EcrClient {
+    /// Auto-paginating version of `describe_images`, streaming every page of results.
+    pub fn describe_images_pages(
+        &self,
+        input: DescribeImagesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeImagesResponse, DescribeImagesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EcrClient, input: DescribeImagesRequest| async move {
+                client.describe_images(input).await
+            },
+            |input: &DescribeImagesRequest, output: &DescribeImagesResponse| {
+                Some(DescribeImagesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_images`, streaming the `imageDetails` of every page of results.
+    pub fn describe_images_items(
+        &self,
+        input: DescribeImagesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ImageDetail, DescribeImagesError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_images_pages(input),
+            |output: DescribeImagesResponse| output.image_details.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_repositories`, streaming every page of results.
+    pub fn describe_repositories_pages(
+        &self,
+        input: DescribeRepositoriesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeRepositoriesResponse,
+        DescribeRepositoriesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EcrClient, input: DescribeRepositoriesRequest| async move {
+                client.describe_repositories(input).await
+            },
+            |input: &DescribeRepositoriesRequest, output: &DescribeRepositoriesResponse| {
+                Some(DescribeRepositoriesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_repositories`, streaming the `repositories` of every page of results.
+    pub fn describe_repositories_items(
+        &self,
+        input: DescribeRepositoriesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Repository, DescribeRepositoriesError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_repositories_pages(input),
+            |output: DescribeRepositoriesResponse| output.repositories.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_lifecycle_policy_preview`, streaming every page of results.
+    pub fn get_lifecycle_policy_preview_pages(
+        &self,
+        input: GetLifecyclePolicyPreviewRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        GetLifecyclePolicyPreviewResponse,
+        GetLifecyclePolicyPreviewError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EcrClient, input: GetLifecyclePolicyPreviewRequest| async move {
+                client.get_lifecycle_policy_preview(input).await
+            },
+            |input: &GetLifecyclePolicyPreviewRequest,
+             output: &GetLifecyclePolicyPreviewResponse| {
+                Some(GetLifecyclePolicyPreviewRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_lifecycle_policy_preview`, streaming the `previewResults` of every page of results.
+    pub fn get_lifecycle_policy_preview_items(
+        &self,
+        input: GetLifecyclePolicyPreviewRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        LifecyclePolicyPreviewResult,
+        GetLifecyclePolicyPreviewError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.get_lifecycle_policy_preview_pages(input),
+            |output: GetLifecyclePolicyPreviewResponse| output.preview_results.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_images`, streaming every page of results.
+    pub fn list_images_pages(
+        &self,
+        input: ListImagesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListImagesResponse, ListImagesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EcrClient, input: ListImagesRequest| async move { client.list_images(input).await },
+            |input: &ListImagesRequest, output: &ListImagesResponse| {
+                Some(ListImagesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_images`, streaming the `imageIds` of every page of results.
+    pub fn list_images_items(
+        &self,
+        input: ListImagesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ImageIdentifier, ListImagesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_images_pages(input),
+            |output: ListImagesResponse| output.image_ids.unwrap_or_default(),
+        )
+    }
+}
+impl EcrClient {
+    /// Waits until `describe_image_scan_findings` reports the `ImageScanComplete` state, polling every 5 seconds up to 60 times.
+    pub async fn wait_until_image_scan_complete(
+        &self,
+        input: DescribeImageScanFindingsRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeImageScanFindingsError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(5), 60);
+        self.wait_until_image_scan_complete_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_image_scan_complete`, polling as often and as many times as `config` says.
+    pub async fn wait_until_image_scan_complete_with_config(
+        &self,
+        input: DescribeImageScanFindingsRequest,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeImageScanFindingsError>> {
+        rusoto_core::waiter::wait(
+            "ImageScanComplete",
+            &config,
+            || self.describe_image_scan_findings(input.clone()),
+            |result: &Result<
+                DescribeImageScanFindingsResponse,
+                RusotoError<DescribeImageScanFindingsError>,
+            >| match result {
+                Ok(output)
+                    if output
+                        .image_scan_status
+                        .iter()
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| *v == ScanStatus::Complete) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if output
+                        .image_scan_status
+                        .iter()
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| *v == ScanStatus::Failed) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+
+    /// Waits until `get_lifecycle_policy_preview` reports the `LifecyclePolicyPreviewComplete` state, polling every 5 seconds up to 20 times.
+    pub async fn wait_until_lifecycle_policy_preview_complete(
+        &self,
+        input: GetLifecyclePolicyPreviewRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetLifecyclePolicyPreviewError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(5), 20);
+        self.wait_until_lifecycle_policy_preview_complete_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_lifecycle_policy_preview_complete`, polling as often and as many times as `config` says.
+    pub async fn wait_until_lifecycle_policy_preview_complete_with_config(
+        &self,
+        input: GetLifecyclePolicyPreviewRequest,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetLifecyclePolicyPreviewError>> {
+        rusoto_core::waiter::wait(
+            "LifecyclePolicyPreviewComplete",
+            &config,
+            || self.get_lifecycle_policy_preview(input.clone()),
+            |result: &Result<
+                GetLifecyclePolicyPreviewResponse,
+                RusotoError<GetLifecyclePolicyPreviewError>,
+            >| match result {
+                Ok(output)
+                    if output
+                        .status
+                        .iter()
+                        .any(|v| *v == LifecyclePolicyPreviewStatus::Complete) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if output
+                        .status
+                        .iter()
+                        .any(|v| *v == LifecyclePolicyPreviewStatus::Failed) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+}
@@ -24,6 +24,212 @@ use rusoto_core::signature::SignedRequest;
 #[allow(unused_imports)]
 use serde::{Deserialize, Serialize};
 use serde_json;
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AgentUpdateStatus {
+    /// `PENDING`
+    Pending,
+    /// `STAGING`
+    Staging,
+    /// `STAGED`
+    Staged,
+    /// `UPDATING`
+    Updating,
+    /// `UPDATED`
+    Updated,
+    /// `FAILED`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AgentUpdateStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AgentUpdateStatus::Pending => "PENDING",
+            AgentUpdateStatus::Staging => "STAGING",
+            AgentUpdateStatus::Staged => "STAGED",
+            AgentUpdateStatus::Updating => "UPDATING",
+            AgentUpdateStatus::Updated => "UPDATED",
+            AgentUpdateStatus::Failed => "FAILED",
+            AgentUpdateStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AgentUpdateStatus {
+    fn default() -> AgentUpdateStatus {
+        AgentUpdateStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AgentUpdateStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AgentUpdateStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AgentUpdateStatus {
+    fn from(value: &'a str) -> AgentUpdateStatus {
+        match value {
+            "PENDING" => AgentUpdateStatus::Pending,
+            "STAGING" => AgentUpdateStatus::Staging,
+            "STAGED" => AgentUpdateStatus::Staged,
+            "UPDATING" => AgentUpdateStatus::Updating,
+            "UPDATED" => AgentUpdateStatus::Updated,
+            "FAILED" => AgentUpdateStatus::Failed,
+            _ => AgentUpdateStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AgentUpdateStatus {
+    fn from(value: String) -> AgentUpdateStatus {
+        match value.as_str() {
+            "PENDING" => AgentUpdateStatus::Pending,
+            "STAGING" => AgentUpdateStatus::Staging,
+            "STAGED" => AgentUpdateStatus::Staged,
+            "UPDATING" => AgentUpdateStatus::Updating,
+            "UPDATED" => AgentUpdateStatus::Updated,
+            "FAILED" => AgentUpdateStatus::Failed,
+            _ => AgentUpdateStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AgentUpdateStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AgentUpdateStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AgentUpdateStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AgentUpdateStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AgentUpdateStatus {
+    fn deserialize<D>(deserializer: D) -> Result<AgentUpdateStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AssignPublicIp {
+    /// `ENABLED`
+    Enabled,
+    /// `DISABLED`
+    Disabled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AssignPublicIp {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AssignPublicIp::Enabled => "ENABLED",
+            AssignPublicIp::Disabled => "DISABLED",
+            AssignPublicIp::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AssignPublicIp {
+    fn default() -> AssignPublicIp {
+        AssignPublicIp::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AssignPublicIp {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AssignPublicIp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AssignPublicIp {
+    fn from(value: &'a str) -> AssignPublicIp {
+        match value {
+            "ENABLED" => AssignPublicIp::Enabled,
+            "DISABLED" => AssignPublicIp::Disabled,
+            _ => AssignPublicIp::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AssignPublicIp {
+    fn from(value: String) -> AssignPublicIp {
+        match value.as_str() {
+            "ENABLED" => AssignPublicIp::Enabled,
+            "DISABLED" => AssignPublicIp::Disabled,
+            _ => AssignPublicIp::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AssignPublicIp {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AssignPublicIp, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AssignPublicIp {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AssignPublicIp {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AssignPublicIp {
+    fn deserialize<D>(deserializer: D) -> Result<AssignPublicIp, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An object representing a container instance or task attachment.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -71,7 +277,7 @@ pub struct Attribute {
     /// <p>The type of the target with which to attach the attribute. This parameter is required if you use the short form ID for a resource instead of the full ARN.</p>
     #[serde(rename = "targetType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub target_type: Option<String>,
+    pub target_type: Option<TargetType>,
     /// <p>The value of the attribute. Up to 128 letters (uppercase and lowercase), numbers, hyphens, underscores, periods, at signs (@), forward slashes, colons, and spaces are allowed.</p>
     #[serde(rename = "value")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -91,7 +297,7 @@ pub struct AutoScalingGroupProvider {
     /// <p>The managed termination protection setting to use for the Auto Scaling group capacity provider. This determines whether the Auto Scaling group has managed termination protection.</p> <important> <p>When using managed termination protection, managed scaling must also be used otherwise managed termination protection will not work.</p> </important> <p>When managed termination protection is enabled, Amazon ECS prevents the Amazon EC2 instances in an Auto Scaling group that contain tasks from being terminated during a scale-in action. The Auto Scaling group and each instance in the Auto Scaling group must have instance protection from scale-in actions enabled as well. For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/as-instance-termination.html#instance-protection">Instance Protection</a> in the <i>AWS Auto Scaling User Guide</i>.</p> <p>When managed termination protection is disabled, your Amazon EC2 instances are not protected from termination when the Auto Scaling group scales in.</p>
     #[serde(rename = "managedTerminationProtection")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub managed_termination_protection: Option<String>,
+    pub managed_termination_protection: Option<ManagedTerminationProtection>,
 }
 
 /// <p>An object representing the networking details for a task or service.</p>
@@ -100,7 +306,7 @@ pub struct AwsVpcConfiguration {
     /// <p>Whether the task's elastic network interface receives a public IP address. The default value is <code>DISABLED</code>.</p>
     #[serde(rename = "assignPublicIp")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub assign_public_ip: Option<String>,
+    pub assign_public_ip: Option<AssignPublicIp>,
     /// <p><p>The security groups associated with the task or service. If you do not specify a security group, the default security group for the VPC is used. There is a limit of 5 security groups that can be specified per <code>AwsVpcConfiguration</code>.</p> <note> <p>All specified security groups must be from the same VPC.</p> </note></p>
     #[serde(rename = "securityGroups")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -129,13 +335,194 @@ pub struct CapacityProvider {
     /// <p>The current status of the capacity provider. Only capacity providers in an <code>ACTIVE</code> state can be used in a cluster.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<CapacityProviderStatus>,
     /// <p><p>The metadata that you apply to the capacity provider to help you categorize and organize it. Each tag consists of a key and an optional value, both of which you define.</p> <p>The following basic restrictions apply to tags:</p> <ul> <li> <p>Maximum number of tags per resource - 50</p> </li> <li> <p>For each resource, each tag key must be unique, and each tag key can have only one value.</p> </li> <li> <p>Maximum key length - 128 Unicode characters in UTF-8</p> </li> <li> <p>Maximum value length - 256 Unicode characters in UTF-8</p> </li> <li> <p>If your tagging schema is used across multiple services and resources, remember that other services may have restrictions on allowed characters. Generally allowed characters are: letters, numbers, and spaces representable in UTF-8, and the following characters: + - = . _ : / @.</p> </li> <li> <p>Tag keys and values are case-sensitive.</p> </li> <li> <p>Do not use <code>aws:</code>, <code>AWS:</code>, or any upper or lowercase combination of such as a prefix for either keys or values as it is reserved for AWS use. You cannot edit or delete tag keys or values with this prefix. Tags with this prefix do not count against your tags per resource limit.</p> </li> </ul></p>
     #[serde(rename = "tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tags: Option<Vec<Tag>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CapacityProviderField {
+    /// `TAGS`
+    Tags,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CapacityProviderField {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CapacityProviderField::Tags => "TAGS",
+            CapacityProviderField::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CapacityProviderField {
+    fn default() -> CapacityProviderField {
+        CapacityProviderField::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CapacityProviderField {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CapacityProviderField {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CapacityProviderField {
+    fn from(value: &'a str) -> CapacityProviderField {
+        match value {
+            "TAGS" => CapacityProviderField::Tags,
+            _ => CapacityProviderField::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CapacityProviderField {
+    fn from(value: String) -> CapacityProviderField {
+        match value.as_str() {
+            "TAGS" => CapacityProviderField::Tags,
+            _ => CapacityProviderField::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CapacityProviderField {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CapacityProviderField, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CapacityProviderField {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CapacityProviderField {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CapacityProviderField {
+    fn deserialize<D>(deserializer: D) -> Result<CapacityProviderField, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CapacityProviderStatus {
+    /// `ACTIVE`
+    Active,
+    /// `INACTIVE`
+    Inactive,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CapacityProviderStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CapacityProviderStatus::Active => "ACTIVE",
+            CapacityProviderStatus::Inactive => "INACTIVE",
+            CapacityProviderStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CapacityProviderStatus {
+    fn default() -> CapacityProviderStatus {
+        CapacityProviderStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CapacityProviderStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CapacityProviderStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CapacityProviderStatus {
+    fn from(value: &'a str) -> CapacityProviderStatus {
+        match value {
+            "ACTIVE" => CapacityProviderStatus::Active,
+            "INACTIVE" => CapacityProviderStatus::Inactive,
+            _ => CapacityProviderStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CapacityProviderStatus {
+    fn from(value: String) -> CapacityProviderStatus {
+        match value.as_str() {
+            "ACTIVE" => CapacityProviderStatus::Active,
+            "INACTIVE" => CapacityProviderStatus::Inactive,
+            _ => CapacityProviderStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CapacityProviderStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CapacityProviderStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CapacityProviderStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CapacityProviderStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CapacityProviderStatus {
+    fn deserialize<D>(deserializer: D) -> Result<CapacityProviderStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The details of a capacity provider strategy.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CapacityProviderStrategyItem {
@@ -214,73 +601,460 @@ pub struct Cluster {
     pub tags: Option<Vec<Tag>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ClusterField {
+    /// `ATTACHMENTS`
+    Attachments,
+    /// `CONFIGURATIONS`
+    Configurations,
+    /// `SETTINGS`
+    Settings,
+    /// `STATISTICS`
+    Statistics,
+    /// `TAGS`
+    Tags,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ClusterField {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ClusterField::Attachments => "ATTACHMENTS",
+            ClusterField::Configurations => "CONFIGURATIONS",
+            ClusterField::Settings => "SETTINGS",
+            ClusterField::Statistics => "STATISTICS",
+            ClusterField::Tags => "TAGS",
+            ClusterField::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ClusterField {
+    fn default() -> ClusterField {
+        ClusterField::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ClusterField {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ClusterField {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ClusterField {
+    fn from(value: &'a str) -> ClusterField {
+        match value {
+            "ATTACHMENTS" => ClusterField::Attachments,
+            "CONFIGURATIONS" => ClusterField::Configurations,
+            "SETTINGS" => ClusterField::Settings,
+            "STATISTICS" => ClusterField::Statistics,
+            "TAGS" => ClusterField::Tags,
+            _ => ClusterField::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ClusterField {
+    fn from(value: String) -> ClusterField {
+        match value.as_str() {
+            "ATTACHMENTS" => ClusterField::Attachments,
+            "CONFIGURATIONS" => ClusterField::Configurations,
+            "SETTINGS" => ClusterField::Settings,
+            "STATISTICS" => ClusterField::Statistics,
+            "TAGS" => ClusterField::Tags,
+            _ => ClusterField::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ClusterField {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ClusterField, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ClusterField {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ClusterField {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ClusterField {
+    fn deserialize<D>(deserializer: D) -> Result<ClusterField, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The settings to use when creating a cluster. This parameter is used to enable CloudWatch Container Insights for a cluster.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ClusterSetting {
     /// <p>The name of the cluster setting. The only supported value is <code>containerInsights</code>.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
+    pub name: Option<ClusterSettingName>,
     /// <p>The value to set for the cluster setting. The supported values are <code>enabled</code> and <code>disabled</code>. If <code>enabled</code> is specified, CloudWatch Container Insights will be enabled for the cluster, otherwise it will be disabled unless the <code>containerInsights</code> account setting is enabled. If a cluster value is specified, it will override the <code>containerInsights</code> value set with <a>PutAccountSetting</a> or <a>PutAccountSettingDefault</a>.</p>
     #[serde(rename = "value")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<String>,
 }
 
-/// <p>A Docker container that is part of a task.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct Container {
-    /// <p>The Amazon Resource Name (ARN) of the container.</p>
-    #[serde(rename = "containerArn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub container_arn: Option<String>,
-    /// <p>The number of CPU units set for the container. The value will be <code>0</code> if no value was specified in the container definition when the task definition was registered.</p>
-    #[serde(rename = "cpu")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub cpu: Option<String>,
-    /// <p>The exit code returned from the container.</p>
-    #[serde(rename = "exitCode")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub exit_code: Option<i64>,
-    /// <p>The IDs of each GPU assigned to the container.</p>
-    #[serde(rename = "gpuIds")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub gpu_ids: Option<Vec<String>>,
-    /// <p>The health status of the container. If health checks are not configured for this container in its task definition, then it reports the health status as <code>UNKNOWN</code>.</p>
-    #[serde(rename = "healthStatus")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub health_status: Option<String>,
-    /// <p>The image used for the container.</p>
-    #[serde(rename = "image")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub image: Option<String>,
-    /// <p><p>The container image manifest digest.</p> <note> <p>The <code>imageDigest</code> is only returned if the container is using an image hosted in Amazon ECR, otherwise it is omitted.</p> </note></p>
-    #[serde(rename = "imageDigest")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub image_digest: Option<String>,
-    /// <p>The last known status of the container.</p>
-    #[serde(rename = "lastStatus")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_status: Option<String>,
-    /// <p>The hard limit (in MiB) of memory set for the container.</p>
-    #[serde(rename = "memory")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub memory: Option<String>,
-    /// <p>The soft limit (in MiB) of memory set for the container.</p>
-    #[serde(rename = "memoryReservation")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub memory_reservation: Option<String>,
-    /// <p>The name of the container.</p>
-    #[serde(rename = "name")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
-    /// <p>The network bindings associated with the container.</p>
-    #[serde(rename = "networkBindings")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub network_bindings: Option<Vec<NetworkBinding>>,
-    /// <p>The network interfaces associated with the container.</p>
-    #[serde(rename = "networkInterfaces")]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ClusterSettingName {
+    /// `containerInsights`
+    ContainerInsights,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ClusterSettingName {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ClusterSettingName::ContainerInsights => "containerInsights",
+            ClusterSettingName::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ClusterSettingName {
+    fn default() -> ClusterSettingName {
+        ClusterSettingName::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ClusterSettingName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ClusterSettingName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ClusterSettingName {
+    fn from(value: &'a str) -> ClusterSettingName {
+        match value {
+            "containerInsights" => ClusterSettingName::ContainerInsights,
+            _ => ClusterSettingName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ClusterSettingName {
+    fn from(value: String) -> ClusterSettingName {
+        match value.as_str() {
+            "containerInsights" => ClusterSettingName::ContainerInsights,
+            _ => ClusterSettingName::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ClusterSettingName {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ClusterSettingName, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ClusterSettingName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ClusterSettingName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ClusterSettingName {
+    fn deserialize<D>(deserializer: D) -> Result<ClusterSettingName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Compatibility {
+    /// `EC2`
+    Ec2,
+    /// `FARGATE`
+    Fargate,
+    /// `EXTERNAL`
+    External,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Compatibility {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Compatibility::Ec2 => "EC2",
+            Compatibility::Fargate => "FARGATE",
+            Compatibility::External => "EXTERNAL",
+            Compatibility::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Compatibility {
+    fn default() -> Compatibility {
+        Compatibility::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Compatibility {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Compatibility {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Compatibility {
+    fn from(value: &'a str) -> Compatibility {
+        match value {
+            "EC2" => Compatibility::Ec2,
+            "FARGATE" => Compatibility::Fargate,
+            "EXTERNAL" => Compatibility::External,
+            _ => Compatibility::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Compatibility {
+    fn from(value: String) -> Compatibility {
+        match value.as_str() {
+            "EC2" => Compatibility::Ec2,
+            "FARGATE" => Compatibility::Fargate,
+            "EXTERNAL" => Compatibility::External,
+            _ => Compatibility::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Compatibility {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Compatibility, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Compatibility {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Compatibility {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Compatibility {
+    fn deserialize<D>(deserializer: D) -> Result<Compatibility, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Connectivity {
+    /// `CONNECTED`
+    Connected,
+    /// `DISCONNECTED`
+    Disconnected,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Connectivity {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Connectivity::Connected => "CONNECTED",
+            Connectivity::Disconnected => "DISCONNECTED",
+            Connectivity::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Connectivity {
+    fn default() -> Connectivity {
+        Connectivity::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Connectivity {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Connectivity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Connectivity {
+    fn from(value: &'a str) -> Connectivity {
+        match value {
+            "CONNECTED" => Connectivity::Connected,
+            "DISCONNECTED" => Connectivity::Disconnected,
+            _ => Connectivity::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Connectivity {
+    fn from(value: String) -> Connectivity {
+        match value.as_str() {
+            "CONNECTED" => Connectivity::Connected,
+            "DISCONNECTED" => Connectivity::Disconnected,
+            _ => Connectivity::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Connectivity {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Connectivity, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Connectivity {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Connectivity {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Connectivity {
+    fn deserialize<D>(deserializer: D) -> Result<Connectivity, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>A Docker container that is part of a task.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct Container {
+    /// <p>The Amazon Resource Name (ARN) of the container.</p>
+    #[serde(rename = "containerArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub container_arn: Option<String>,
+    /// <p>The number of CPU units set for the container. The value will be <code>0</code> if no value was specified in the container definition when the task definition was registered.</p>
+    #[serde(rename = "cpu")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cpu: Option<String>,
+    /// <p>The exit code returned from the container.</p>
+    #[serde(rename = "exitCode")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exit_code: Option<i64>,
+    /// <p>The IDs of each GPU assigned to the container.</p>
+    #[serde(rename = "gpuIds")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gpu_ids: Option<Vec<String>>,
+    /// <p>The health status of the container. If health checks are not configured for this container in its task definition, then it reports the health status as <code>UNKNOWN</code>.</p>
+    #[serde(rename = "healthStatus")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub health_status: Option<HealthStatus>,
+    /// <p>The image used for the container.</p>
+    #[serde(rename = "image")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+    /// <p><p>The container image manifest digest.</p> <note> <p>The <code>imageDigest</code> is only returned if the container is using an image hosted in Amazon ECR, otherwise it is omitted.</p> </note></p>
+    #[serde(rename = "imageDigest")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_digest: Option<String>,
+    /// <p>The last known status of the container.</p>
+    #[serde(rename = "lastStatus")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_status: Option<String>,
+    /// <p>The hard limit (in MiB) of memory set for the container.</p>
+    #[serde(rename = "memory")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory: Option<String>,
+    /// <p>The soft limit (in MiB) of memory set for the container.</p>
+    #[serde(rename = "memoryReservation")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory_reservation: Option<String>,
+    /// <p>The name of the container.</p>
+    #[serde(rename = "name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// <p>The network bindings associated with the container.</p>
+    #[serde(rename = "networkBindings")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network_bindings: Option<Vec<NetworkBinding>>,
+    /// <p>The network interfaces associated with the container.</p>
+    #[serde(rename = "networkInterfaces")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub network_interfaces: Option<Vec<NetworkInterface>>,
     /// <p>A short (255 max characters) human-readable string to provide additional details about a running or stopped container.</p>
@@ -297,6 +1071,109 @@ pub struct Container {
     pub task_arn: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ContainerCondition {
+    /// `START`
+    Start,
+    /// `COMPLETE`
+    Complete,
+    /// `SUCCESS`
+    Success,
+    /// `HEALTHY`
+    Healthy,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ContainerCondition {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ContainerCondition::Start => "START",
+            ContainerCondition::Complete => "COMPLETE",
+            ContainerCondition::Success => "SUCCESS",
+            ContainerCondition::Healthy => "HEALTHY",
+            ContainerCondition::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ContainerCondition {
+    fn default() -> ContainerCondition {
+        ContainerCondition::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ContainerCondition {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ContainerCondition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ContainerCondition {
+    fn from(value: &'a str) -> ContainerCondition {
+        match value {
+            "START" => ContainerCondition::Start,
+            "COMPLETE" => ContainerCondition::Complete,
+            "SUCCESS" => ContainerCondition::Success,
+            "HEALTHY" => ContainerCondition::Healthy,
+            _ => ContainerCondition::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ContainerCondition {
+    fn from(value: String) -> ContainerCondition {
+        match value.as_str() {
+            "START" => ContainerCondition::Start,
+            "COMPLETE" => ContainerCondition::Complete,
+            "SUCCESS" => ContainerCondition::Success,
+            "HEALTHY" => ContainerCondition::Healthy,
+            _ => ContainerCondition::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ContainerCondition {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ContainerCondition, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ContainerCondition {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ContainerCondition {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ContainerCondition {
+    fn deserialize<D>(deserializer: D) -> Result<ContainerCondition, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Container definitions are used in task definitions to describe the different containers that are launched as part of a task.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ContainerDefinition {
@@ -459,7 +1336,7 @@ pub struct ContainerDefinition {
 pub struct ContainerDependency {
     /// <p><p>The dependency condition of the container. The following are the available conditions and their behavior:</p> <ul> <li> <p> <code>START</code> - This condition emulates the behavior of links and volumes today. It validates that a dependent container is started before permitting other containers to start.</p> </li> <li> <p> <code>COMPLETE</code> - This condition validates that a dependent container runs to completion (exits) before permitting other containers to start. This can be useful for nonessential containers that run a script and then exit.</p> </li> <li> <p> <code>SUCCESS</code> - This condition is the same as <code>COMPLETE</code>, but it also requires that the container exits with a <code>zero</code> status.</p> </li> <li> <p> <code>HEALTHY</code> - This condition validates that the dependent container passes its Docker health check before permitting other containers to start. This requires that the dependent container has health checks configured. This condition is confirmed only at task startup.</p> </li> </ul></p>
     #[serde(rename = "condition")]
-    pub condition: String,
+    pub condition: ContainerCondition,
     /// <p>The name of a container.</p>
     #[serde(rename = "containerName")]
     pub container_name: String,
@@ -476,7 +1353,7 @@ pub struct ContainerInstance {
     /// <p>The status of the most recent agent update. If an update has never been requested, this value is <code>NULL</code>.</p>
     #[serde(rename = "agentUpdateStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub agent_update_status: Option<String>,
+    pub agent_update_status: Option<AgentUpdateStatus>,
     /// <p>The resources attached to a container instance, such as elastic network interfaces.</p>
     #[serde(rename = "attachments")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -503,8 +1380,13 @@ pub struct ContainerInstance {
     pub pending_tasks_count: Option<i64>,
     /// <p>The Unix timestamp for when the container instance was registered.</p>
     #[serde(rename = "registeredAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub registered_at: Option<f64>,
+    pub registered_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>For CPU and memory resource types, this parameter describes the amount of each resource that was available on the container instance when the container agent registered it with Amazon ECS. This value represents the total amount of CPU and memory that can be allocated on this container instance to tasks. For port resource types, this parameter describes the ports that were reserved by the Amazon ECS container agent when it registered the container instance with Amazon ECS.</p>
     #[serde(rename = "registeredResources")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -539,53 +1421,254 @@ pub struct ContainerInstance {
     pub version_info: Option<VersionInfo>,
 }
 
-/// <p>The overrides that should be sent to a container. An empty container override can be passed in. An example of an empty container override would be <code>{"containerOverrides": [ ] }</code>. If a non-empty container override is specified, the <code>name</code> parameter must be included.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct ContainerOverride {
-    /// <p>The command to send to the container that overrides the default command from the Docker image or the task definition. You must also specify a container name.</p>
-    #[serde(rename = "command")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub command: Option<Vec<String>>,
-    /// <p>The number of <code>cpu</code> units reserved for the container, instead of the default value from the task definition. You must also specify a container name.</p>
-    #[serde(rename = "cpu")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub cpu: Option<i64>,
-    /// <p>The environment variables to send to the container. You can add new environment variables, which are added to the container at launch, or you can override the existing environment variables from the Docker image or the task definition. You must also specify a container name.</p>
-    #[serde(rename = "environment")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub environment: Option<Vec<KeyValuePair>>,
-    /// <p>The hard limit (in MiB) of memory to present to the container, instead of the default value from the task definition. If your container attempts to exceed the memory specified here, the container is killed. You must also specify a container name.</p>
-    #[serde(rename = "memory")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub memory: Option<i64>,
-    /// <p>The soft limit (in MiB) of memory to reserve for the container, instead of the default value from the task definition. You must also specify a container name.</p>
-    #[serde(rename = "memoryReservation")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub memory_reservation: Option<i64>,
-    /// <p>The name of the container that receives the override. This parameter is required if any override is specified.</p>
-    #[serde(rename = "name")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
-    /// <p>The type and amount of a resource to assign to a container, instead of the default value from the task definition. The only supported resource is a GPU.</p>
-    #[serde(rename = "resourceRequirements")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_requirements: Option<Vec<ResourceRequirement>>,
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ContainerInstanceField {
+    /// `TAGS`
+    Tags,
+    /// `CONTAINER_INSTANCE_HEALTH`
+    ContainerInstanceHealth,
+    /// A value not known to this version of the crate
+    Unknown(String),
 }
 
-/// <p>An object representing a change in state for a container.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct ContainerStateChange {
-    /// <p>The name of the container.</p>
-    #[serde(rename = "containerName")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub container_name: Option<String>,
-    /// <p>The exit code for the container, if the state change is a result of the container exiting.</p>
-    #[serde(rename = "exitCode")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub exit_code: Option<i64>,
-    /// <p>The container image SHA 256 digest.</p>
-    #[serde(rename = "imageDigest")]
+impl ContainerInstanceField {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ContainerInstanceField::Tags => "TAGS",
+            ContainerInstanceField::ContainerInstanceHealth => "CONTAINER_INSTANCE_HEALTH",
+            ContainerInstanceField::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ContainerInstanceField {
+    fn default() -> ContainerInstanceField {
+        ContainerInstanceField::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ContainerInstanceField {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ContainerInstanceField {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ContainerInstanceField {
+    fn from(value: &'a str) -> ContainerInstanceField {
+        match value {
+            "TAGS" => ContainerInstanceField::Tags,
+            "CONTAINER_INSTANCE_HEALTH" => ContainerInstanceField::ContainerInstanceHealth,
+            _ => ContainerInstanceField::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ContainerInstanceField {
+    fn from(value: String) -> ContainerInstanceField {
+        match value.as_str() {
+            "TAGS" => ContainerInstanceField::Tags,
+            "CONTAINER_INSTANCE_HEALTH" => ContainerInstanceField::ContainerInstanceHealth,
+            _ => ContainerInstanceField::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ContainerInstanceField {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ContainerInstanceField, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ContainerInstanceField {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ContainerInstanceField {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ContainerInstanceField {
+    fn deserialize<D>(deserializer: D) -> Result<ContainerInstanceField, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ContainerInstanceStatus {
+    /// `ACTIVE`
+    Active,
+    /// `DRAINING`
+    Draining,
+    /// `REGISTERING`
+    Registering,
+    /// `DEREGISTERING`
+    Deregistering,
+    /// `REGISTRATION_FAILED`
+    RegistrationFailed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ContainerInstanceStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ContainerInstanceStatus::Active => "ACTIVE",
+            ContainerInstanceStatus::Draining => "DRAINING",
+            ContainerInstanceStatus::Registering => "REGISTERING",
+            ContainerInstanceStatus::Deregistering => "DEREGISTERING",
+            ContainerInstanceStatus::RegistrationFailed => "REGISTRATION_FAILED",
+            ContainerInstanceStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ContainerInstanceStatus {
+    fn default() -> ContainerInstanceStatus {
+        ContainerInstanceStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ContainerInstanceStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ContainerInstanceStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ContainerInstanceStatus {
+    fn from(value: &'a str) -> ContainerInstanceStatus {
+        match value {
+            "ACTIVE" => ContainerInstanceStatus::Active,
+            "DRAINING" => ContainerInstanceStatus::Draining,
+            "REGISTERING" => ContainerInstanceStatus::Registering,
+            "DEREGISTERING" => ContainerInstanceStatus::Deregistering,
+            "REGISTRATION_FAILED" => ContainerInstanceStatus::RegistrationFailed,
+            _ => ContainerInstanceStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ContainerInstanceStatus {
+    fn from(value: String) -> ContainerInstanceStatus {
+        match value.as_str() {
+            "ACTIVE" => ContainerInstanceStatus::Active,
+            "DRAINING" => ContainerInstanceStatus::Draining,
+            "REGISTERING" => ContainerInstanceStatus::Registering,
+            "DEREGISTERING" => ContainerInstanceStatus::Deregistering,
+            "REGISTRATION_FAILED" => ContainerInstanceStatus::RegistrationFailed,
+            _ => ContainerInstanceStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ContainerInstanceStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ContainerInstanceStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ContainerInstanceStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ContainerInstanceStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ContainerInstanceStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ContainerInstanceStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>The overrides that should be sent to a container. An empty container override can be passed in. An example of an empty container override would be <code>{"containerOverrides": [ ] }</code>. If a non-empty container override is specified, the <code>name</code> parameter must be included.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ContainerOverride {
+    /// <p>The command to send to the container that overrides the default command from the Docker image or the task definition. You must also specify a container name.</p>
+    #[serde(rename = "command")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub command: Option<Vec<String>>,
+    /// <p>The number of <code>cpu</code> units reserved for the container, instead of the default value from the task definition. You must also specify a container name.</p>
+    #[serde(rename = "cpu")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cpu: Option<i64>,
+    /// <p>The environment variables to send to the container. You can add new environment variables, which are added to the container at launch, or you can override the existing environment variables from the Docker image or the task definition. You must also specify a container name.</p>
+    #[serde(rename = "environment")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub environment: Option<Vec<KeyValuePair>>,
+    /// <p>The hard limit (in MiB) of memory to present to the container, instead of the default value from the task definition. If your container attempts to exceed the memory specified here, the container is killed. You must also specify a container name.</p>
+    #[serde(rename = "memory")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory: Option<i64>,
+    /// <p>The soft limit (in MiB) of memory to reserve for the container, instead of the default value from the task definition. You must also specify a container name.</p>
+    #[serde(rename = "memoryReservation")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory_reservation: Option<i64>,
+    /// <p>The name of the container that receives the override. This parameter is required if any override is specified.</p>
+    #[serde(rename = "name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// <p>The type and amount of a resource to assign to a container, instead of the default value from the task definition. The only supported resource is a GPU.</p>
+    #[serde(rename = "resourceRequirements")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_requirements: Option<Vec<ResourceRequirement>>,
+}
+
+/// <p>An object representing a change in state for a container.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct ContainerStateChange {
+    /// <p>The name of the container.</p>
+    #[serde(rename = "containerName")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub container_name: Option<String>,
+    /// <p>The exit code for the container, if the state change is a result of the container exiting.</p>
+    #[serde(rename = "exitCode")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exit_code: Option<i64>,
+    /// <p>The container image SHA 256 digest.</p>
+    #[serde(rename = "imageDigest")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub image_digest: Option<String>,
     /// <p>Any network bindings associated with the container.</p>
@@ -702,7 +1785,7 @@ pub struct CreateServiceRequest {
     /// <p>The launch type on which to run your service. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/launch_types.html">Amazon ECS Launch Types</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <p>If a <code>launchType</code> is specified, the <code>capacityProviderStrategy</code> parameter must be omitted.</p>
     #[serde(rename = "launchType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub launch_type: Option<String>,
+    pub launch_type: Option<LaunchType>,
     /// <p>A load balancer object representing the load balancers to use with your service. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/service-load-balancing.html">Service Load Balancing</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <p>If the service is using the rolling update (<code>ECS</code>) deployment controller and using either an Application Load Balancer or Network Load Balancer, you can specify multiple target groups to attach to the service. The service-linked role is required for services that make use of multiple target groups. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/using-service-linked-roles.html">Using Service-Linked Roles for Amazon ECS</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <p>If the service is using the <code>CODE_DEPLOY</code> deployment controller, the service is required to use either an Application Load Balancer or Network Load Balancer. When creating an AWS CodeDeploy deployment group, you specify two target groups (referred to as a <code>targetGroupPair</code>). During a deployment, AWS CodeDeploy determines which task set in your service has the status <code>PRIMARY</code> and associates one target group with it, and then associates the other target group with the replacement task set. The load balancer can also have up to two listeners: a required listener for production traffic and an optional listener that allows you perform validation tests with Lambda functions before routing production traffic to it.</p> <p>After you create a service using the <code>ECS</code> deployment controller, the load balancer name or target group ARN, container name, and container port specified in the service definition are immutable. If you are using the <code>CODE_DEPLOY</code> deployment controller, these values can be changed when updating the service.</p> <p>For Application Load Balancers and Network Load Balancers, this object must contain the load balancer target group ARN, the container name (as it appears in a container definition), and the container port to access from the load balancer. When a task from this service is placed on a container instance, the container instance and port combination is registered as a target in the target group specified here.</p> <p>For Classic Load Balancers, this object must contain the load balancer name, the container name (as it appears in a container definition), and the container port to access from the load balancer. When a task from this service is placed on a container instance, the container instance is registered with the load balancer specified here.</p> <p>Services with tasks that use the <code>awsvpc</code> network mode (for example, those with the Fargate launch type) only support Application Load Balancers and Network Load Balancers. Classic Load Balancers are not supported. Also, when you create any target groups for these services, you must choose <code>ip</code> as the target type, not <code>instance</code>, because tasks that use the <code>awsvpc</code> network mode are associated with an elastic network interface, not an Amazon EC2 instance.</p>
     #[serde(rename = "loadBalancers")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -726,7 +1809,7 @@ pub struct CreateServiceRequest {
     /// <p>Specifies whether to propagate the tags from the task definition or the service to the tasks in the service. If no value is specified, the tags are not propagated. Tags can only be propagated to the tasks within the service during service creation. To add tags to a task after service creation, use the <a>TagResource</a> API action.</p>
     #[serde(rename = "propagateTags")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub propagate_tags: Option<String>,
+    pub propagate_tags: Option<PropagateTags>,
     /// <p>The name or full Amazon Resource Name (ARN) of the IAM role that allows Amazon ECS to make calls to your load balancer on your behalf. This parameter is only permitted if you are using a load balancer with your service and your task definition does not use the <code>awsvpc</code> network mode. If you specify the <code>role</code> parameter, you must also specify a load balancer object with the <code>loadBalancers</code> parameter.</p> <important> <p>If your account has already created the Amazon ECS service-linked role, that role is used by default for your service unless you specify a role here. The service-linked role is required if your task definition uses the <code>awsvpc</code> network mode or if the service is configured to use service discovery, an external deployment controller, multiple target groups, or Elastic Inference accelerators in which case you should not specify a role here. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/using-service-linked-roles.html">Using Service-Linked Roles for Amazon ECS</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> </important> <p>If your specified role has a path other than <code>/</code>, then you must either specify the full role ARN (this is recommended) or prefix the role name with the path. For example, if a role with the name <code>bar</code> has a path of <code>/foo/</code> then you would specify <code>/foo/bar</code> as the role name. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/reference_identifiers.html#identifiers-friendly-names">Friendly Names and Paths</a> in the <i>IAM User Guide</i>.</p>
     #[serde(rename = "role")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -734,7 +1817,7 @@ pub struct CreateServiceRequest {
     /// <p><p>The scheduling strategy to use for the service. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/ecs_services.html">Services</a>.</p> <p>There are two service scheduler strategies available:</p> <ul> <li> <p> <code>REPLICA</code>-The replica scheduling strategy places and maintains the desired number of tasks across your cluster. By default, the service scheduler spreads tasks across Availability Zones. You can use task placement strategies and constraints to customize task placement decisions. This scheduler strategy is required if the service is using the <code>CODE<em>DEPLOY</code> or <code>EXTERNAL</code> deployment controller types.</p> </li> <li> <p> <code>DAEMON</code>-The daemon scheduling strategy deploys exactly one task on each active container instance that meets all of the task placement constraints that you specify in your cluster. When you&#39;re using this strategy, you don&#39;t need to specify a desired number of tasks, a task placement strategy, or use Service Auto Scaling policies.</p> <note> <p>Tasks using the Fargate launch type or the <code>CODE</em>DEPLOY</code> or <code>EXTERNAL</code> deployment controller types don&#39;t support the <code>DAEMON</code> scheduling strategy.</p> </note> </li> </ul></p>
     #[serde(rename = "schedulingStrategy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scheduling_strategy: Option<String>,
+    pub scheduling_strategy: Option<SchedulingStrategy>,
     /// <p>The name of your service. Up to 255 letters (uppercase and lowercase), numbers, and hyphens are allowed. Service names must be unique within a cluster, but you can have similarly named services in multiple clusters within a Region or across multiple Regions.</p>
     #[serde(rename = "serviceName")]
     pub service_name: String,
@@ -782,7 +1865,7 @@ pub struct CreateTaskSetRequest {
     /// <p>The launch type that new tasks in the task set will use. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/launch_types.html">Amazon ECS Launch Types</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <p>If a <code>launchType</code> is specified, the <code>capacityProviderStrategy</code> parameter must be omitted.</p>
     #[serde(rename = "launchType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub launch_type: Option<String>,
+    pub launch_type: Option<LaunchType>,
     /// <p>A load balancer object representing the load balancer to use with the task set. The supported load balancer types are either an Application Load Balancer or a Network Load Balancer.</p>
     #[serde(rename = "loadBalancers")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -826,7 +1909,7 @@ pub struct CreateTaskSetResponse {
 pub struct DeleteAccountSettingRequest {
     /// <p>The resource name for which to disable the account setting. If <code>serviceLongArnFormat</code> is specified, the ARN for your Amazon ECS services is affected. If <code>taskLongArnFormat</code> is specified, the ARN and resource ID for your Amazon ECS tasks is affected. If <code>containerInstanceLongArnFormat</code> is specified, the ARN and resource ID for your Amazon ECS container instances is affected. If <code>awsvpcTrunking</code> is specified, the ENI limit for your Amazon ECS container instances is affected.</p>
     #[serde(rename = "name")]
-    pub name: String,
+    pub name: SettingName,
     /// <p>The ARN of the principal, which can be an IAM user, IAM role, or the root user. If you specify the root user, it disables the account setting for all IAM users, IAM roles, and the root user of the account unless an IAM user or role explicitly overrides these settings. If this field is omitted, the setting is changed only for the authenticated user.</p>
     #[serde(rename = "principalArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -941,8 +2024,13 @@ pub struct Deployment {
     pub capacity_provider_strategy: Option<Vec<CapacityProviderStrategyItem>>,
     /// <p>The Unix timestamp for when the service deployment was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The most recent desired count of tasks that was specified for the service to deploy or maintain.</p>
     #[serde(rename = "desiredCount")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -954,7 +2042,7 @@ pub struct Deployment {
     /// <p>The launch type the tasks in the service are using. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/launch_types.html">Amazon ECS Launch Types</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
     #[serde(rename = "launchType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub launch_type: Option<String>,
+    pub launch_type: Option<LaunchType>,
     /// <p>The VPC subnet and security group configuration for tasks that receive their own elastic network interface by using the <code>awsvpc</code> networking mode.</p>
     #[serde(rename = "networkConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -981,8 +2069,13 @@ pub struct Deployment {
     pub task_definition: Option<String>,
     /// <p>The Unix timestamp for when the service deployment was last updated.</p>
     #[serde(rename = "updatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<f64>,
+    pub updated_at: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>Optional deployment parameters that control how many tasks run during a deployment and the ordering of stopping and starting tasks.</p>
@@ -1003,9 +2096,107 @@ pub struct DeploymentConfiguration {
 pub struct DeploymentController {
     /// <p><p>The deployment controller type to use.</p> <p>There are three deployment controller types available:</p> <dl> <dt>ECS</dt> <dd> <p>The rolling update (<code>ECS</code>) deployment type involves replacing the current running version of the container with the latest version. The number of containers Amazon ECS adds or removes from the service during a rolling update is controlled by adjusting the minimum and maximum number of healthy tasks allowed during a service deployment, as specified in the <a>DeploymentConfiguration</a>.</p> </dd> <dt>CODE<em>DEPLOY</dt> <dd> <p>The blue/green (<code>CODE</em>DEPLOY</code>) deployment type uses the blue/green deployment model powered by AWS CodeDeploy, which allows you to verify a new deployment of a service before sending production traffic to it.</p> </dd> <dt>EXTERNAL</dt> <dd> <p>The external (<code>EXTERNAL</code>) deployment type enables you to use any third-party deployment controller for full control over the deployment process for an Amazon ECS service.</p> </dd> </dl></p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: DeploymentControllerType,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DeploymentControllerType {
+    /// `ECS`
+    Ecs,
+    /// `CODE_DEPLOY`
+    CodeDeploy,
+    /// `EXTERNAL`
+    External,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DeploymentControllerType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeploymentControllerType::Ecs => "ECS",
+            DeploymentControllerType::CodeDeploy => "CODE_DEPLOY",
+            DeploymentControllerType::External => "EXTERNAL",
+            DeploymentControllerType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DeploymentControllerType {
+    fn default() -> DeploymentControllerType {
+        DeploymentControllerType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DeploymentControllerType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DeploymentControllerType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DeploymentControllerType {
+    fn from(value: &'a str) -> DeploymentControllerType {
+        match value {
+            "ECS" => DeploymentControllerType::Ecs,
+            "CODE_DEPLOY" => DeploymentControllerType::CodeDeploy,
+            "EXTERNAL" => DeploymentControllerType::External,
+            _ => DeploymentControllerType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeploymentControllerType {
+    fn from(value: String) -> DeploymentControllerType {
+        match value.as_str() {
+            "ECS" => DeploymentControllerType::Ecs,
+            "CODE_DEPLOY" => DeploymentControllerType::CodeDeploy,
+            "EXTERNAL" => DeploymentControllerType::External,
+            _ => DeploymentControllerType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DeploymentControllerType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DeploymentControllerType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DeploymentControllerType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DeploymentControllerType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for DeploymentControllerType {
+    fn deserialize<D>(deserializer: D) -> Result<DeploymentControllerType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct DeregisterContainerInstanceRequest {
@@ -1058,7 +2249,7 @@ pub struct DescribeCapacityProvidersRequest {
     /// <p>Specifies whether or not you want to see the resource tags for the capacity provider. If <code>TAGS</code> is specified, the tags are included in the response. If this field is omitted, tags are not included in the response.</p>
     #[serde(rename = "include")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub include: Option<Vec<String>>,
+    pub include: Option<Vec<CapacityProviderField>>,
     /// <p>The maximum number of account setting results returned by <code>DescribeCapacityProviders</code> in paginated output. When this parameter is used, <code>DescribeCapacityProviders</code> only returns <code>maxResults</code> results in a single page along with a <code>nextToken</code> response element. The remaining results of the initial request can be seen by sending another <code>DescribeCapacityProviders</code> request with the returned <code>nextToken</code> value. This value can be between 1 and 10. If this parameter is not used, then <code>DescribeCapacityProviders</code> returns up to 10 results and a <code>nextToken</code> value if applicable.</p>
     #[serde(rename = "maxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1096,7 +2287,7 @@ pub struct DescribeClustersRequest {
     /// <p>Whether to include additional information about your clusters in the response. If this field is omitted, the attachments, statistics, and tags are not included.</p> <p>If <code>ATTACHMENTS</code> is specified, the attachments for the container instances or tasks within the cluster are included.</p> <p>If <code>SETTINGS</code> is specified, the settings for the cluster are included.</p> <p>If <code>STATISTICS</code> is specified, the following additional information, separated by launch type, is included:</p> <ul> <li> <p>runningEC2TasksCount</p> </li> <li> <p>runningFargateTasksCount</p> </li> <li> <p>pendingEC2TasksCount</p> </li> <li> <p>pendingFargateTasksCount</p> </li> <li> <p>activeEC2ServiceCount</p> </li> <li> <p>activeFargateServiceCount</p> </li> <li> <p>drainingEC2ServiceCount</p> </li> <li> <p>drainingFargateServiceCount</p> </li> </ul> <p>If <code>TAGS</code> is specified, the metadata tags associated with the cluster are included.</p>
     #[serde(rename = "include")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub include: Option<Vec<String>>,
+    pub include: Option<Vec<ClusterField>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1125,7 +2316,7 @@ pub struct DescribeContainerInstancesRequest {
     /// <p>Specifies whether you want to see the resource tags for the container instance. If <code>TAGS</code> is specified, the tags are included in the response. If this field is omitted, tags are not included in the response.</p>
     #[serde(rename = "include")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub include: Option<Vec<String>>,
+    pub include: Option<Vec<ContainerInstanceField>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1151,7 +2342,7 @@ pub struct DescribeServicesRequest {
     /// <p>Specifies whether you want to see the resource tags for the service. If <code>TAGS</code> is specified, the tags are included in the response. If this field is omitted, tags are not included in the response.</p>
     #[serde(rename = "include")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub include: Option<Vec<String>>,
+    pub include: Option<Vec<ServiceField>>,
     /// <p>A list of services to describe. You may specify up to 10 services to describe in a single operation.</p>
     #[serde(rename = "services")]
     pub services: Vec<String>,
@@ -1176,7 +2367,7 @@ pub struct DescribeTaskDefinitionRequest {
     /// <p>Specifies whether to see the resource tags for the task definition. If <code>TAGS</code> is specified, the tags are included in the response. If this field is omitted, tags are not included in the response.</p>
     #[serde(rename = "include")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub include: Option<Vec<String>>,
+    pub include: Option<Vec<TaskDefinitionField>>,
     /// <p>The <code>family</code> for the latest <code>ACTIVE</code> revision, <code>family</code> and <code>revision</code> (<code>family:revision</code>) for a specific revision in the family, or full Amazon Resource Name (ARN) of the task definition to describe.</p>
     #[serde(rename = "taskDefinition")]
     pub task_definition: String,
@@ -1204,7 +2395,7 @@ pub struct DescribeTaskSetsRequest {
     /// <p>Specifies whether to see the resource tags for the task set. If <code>TAGS</code> is specified, the tags are included in the response. If this field is omitted, tags are not included in the response.</p>
     #[serde(rename = "include")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub include: Option<Vec<String>>,
+    pub include: Option<Vec<TaskSetField>>,
     /// <p>The short name or full Amazon Resource Name (ARN) of the service that the task sets exist in.</p>
     #[serde(rename = "service")]
     pub service: String,
@@ -1237,7 +2428,7 @@ pub struct DescribeTasksRequest {
     /// <p>Specifies whether you want to see the resource tags for the task. If <code>TAGS</code> is specified, the tags are included in the response. If this field is omitted, tags are not included in the response.</p>
     #[serde(rename = "include")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub include: Option<Vec<String>>,
+    pub include: Option<Vec<TaskField>>,
     /// <p>A list of up to 100 task IDs or full ARN entries.</p>
     #[serde(rename = "tasks")]
     pub tasks: Vec<String>,
@@ -1256,6 +2447,104 @@ pub struct DescribeTasksResponse {
     pub tasks: Option<Vec<Task>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DesiredStatus {
+    /// `RUNNING`
+    Running,
+    /// `PENDING`
+    Pending,
+    /// `STOPPED`
+    Stopped,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DesiredStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DesiredStatus::Running => "RUNNING",
+            DesiredStatus::Pending => "PENDING",
+            DesiredStatus::Stopped => "STOPPED",
+            DesiredStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DesiredStatus {
+    fn default() -> DesiredStatus {
+        DesiredStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DesiredStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DesiredStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DesiredStatus {
+    fn from(value: &'a str) -> DesiredStatus {
+        match value {
+            "RUNNING" => DesiredStatus::Running,
+            "PENDING" => DesiredStatus::Pending,
+            "STOPPED" => DesiredStatus::Stopped,
+            _ => DesiredStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DesiredStatus {
+    fn from(value: String) -> DesiredStatus {
+        match value.as_str() {
+            "RUNNING" => DesiredStatus::Running,
+            "PENDING" => DesiredStatus::Pending,
+            "STOPPED" => DesiredStatus::Stopped,
+            _ => DesiredStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DesiredStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DesiredStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DesiredStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DesiredStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DesiredStatus {
+    fn deserialize<D>(deserializer: D) -> Result<DesiredStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An object representing a container instance host device.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Device {
@@ -1269,9 +2558,107 @@ pub struct Device {
     /// <p>The explicit permissions to provide to the container for the device. By default, the container has permissions for <code>read</code>, <code>write</code>, and <code>mknod</code> for the device.</p>
     #[serde(rename = "permissions")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub permissions: Option<Vec<String>>,
+    pub permissions: Option<Vec<DeviceCgroupPermission>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DeviceCgroupPermission {
+    /// `read`
+    Read,
+    /// `write`
+    Write,
+    /// `mknod`
+    Mknod,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DeviceCgroupPermission {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeviceCgroupPermission::Read => "read",
+            DeviceCgroupPermission::Write => "write",
+            DeviceCgroupPermission::Mknod => "mknod",
+            DeviceCgroupPermission::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DeviceCgroupPermission {
+    fn default() -> DeviceCgroupPermission {
+        DeviceCgroupPermission::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DeviceCgroupPermission {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DeviceCgroupPermission {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DeviceCgroupPermission {
+    fn from(value: &'a str) -> DeviceCgroupPermission {
+        match value {
+            "read" => DeviceCgroupPermission::Read,
+            "write" => DeviceCgroupPermission::Write,
+            "mknod" => DeviceCgroupPermission::Mknod,
+            _ => DeviceCgroupPermission::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeviceCgroupPermission {
+    fn from(value: String) -> DeviceCgroupPermission {
+        match value.as_str() {
+            "read" => DeviceCgroupPermission::Read,
+            "write" => DeviceCgroupPermission::Write,
+            "mknod" => DeviceCgroupPermission::Mknod,
+            _ => DeviceCgroupPermission::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DeviceCgroupPermission {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DeviceCgroupPermission, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DeviceCgroupPermission {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DeviceCgroupPermission {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for DeviceCgroupPermission {
+    fn deserialize<D>(deserializer: D) -> Result<DeviceCgroupPermission, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct DiscoverPollEndpointRequest {
@@ -1320,7 +2707,7 @@ pub struct DockerVolumeConfiguration {
     /// <p>The scope for the Docker volume that determines its lifecycle. Docker volumes that are scoped to a <code>task</code> are automatically provisioned when the task starts and destroyed when the task stops. Docker volumes that are scoped as <code>shared</code> persist after the task stops.</p>
     #[serde(rename = "scope")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scope: Option<String>,
+    pub scope: Option<Scope>,
 }
 
 /// <p><p>This parameter is specified when you are using an Amazon Elastic File System (Amazon EFS) file storage. Amazon EFS file systems are only supported when you are using the EC2 launch type.</p> <important> <p> <code>EFSVolumeConfiguration</code> remains in preview and is a Beta Service as defined by and subject to the Beta Service Participation Service Terms located at <a href="https://aws.amazon.com/service-terms">https://aws.amazon.com/service-terms</a> (&quot;Beta Terms&quot;). These Beta Terms apply to your participation in this preview of <code>EFSVolumeConfiguration</code>.</p> </important></p>
@@ -1362,18 +2749,111 @@ pub struct FirelensConfiguration {
     pub options: Option<::std::collections::HashMap<String, String>>,
     /// <p>The log router to use. The valid values are <code>fluentd</code> or <code>fluentbit</code>.</p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: FirelensConfigurationType,
 }
 
-/// <p><p>An object representing a container health check. Health check parameters that are specified in a container definition override any Docker health checks that exist in the container image (such as those specified in a parent image or from the image&#39;s Dockerfile).</p> <p>The following are notes about container health check support:</p> <ul> <li> <p>Container health checks require version 1.17.0 or greater of the Amazon ECS container agent. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/ecs-agent-update.html">Updating the Amazon ECS Container Agent</a>.</p> </li> <li> <p>Container health checks are supported for Fargate tasks if you are using platform version 1.1.0 or greater. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/platform_versions.html">AWS Fargate Platform Versions</a>.</p> </li> <li> <p>Container health checks are not supported for tasks that are part of a service that is configured to use a Classic Load Balancer.</p> </li> </ul></p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct HealthCheck {
-    /// <p>A string array representing the command that the container runs to determine if it is healthy. The string array must start with <code>CMD</code> to execute the command arguments directly, or <code>CMD-SHELL</code> to run the command with the container's default shell. For example:</p> <p> <code>[ "CMD-SHELL", "curl -f http://localhost/ || exit 1" ]</code> </p> <p>An exit code of 0 indicates success, and non-zero exit code indicates failure. For more information, see <code>HealthCheck</code> in the <a href="https://docs.docker.com/engine/api/v1.35/#operation/ContainerCreate">Create a container</a> section of the <a href="https://docs.docker.com/engine/api/v1.35/">Docker Remote API</a>.</p>
-    #[serde(rename = "command")]
-    pub command: Vec<String>,
-    /// <p>The time period in seconds between each health check execution. You may specify between 5 and 300 seconds. The default value is 30 seconds.</p>
-    #[serde(rename = "interval")]
-    #[serde(skip_serializing_if = "Option::is_none")]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FirelensConfigurationType {
+    /// `fluentd`
+    Fluentd,
+    /// `fluentbit`
+    Fluentbit,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl FirelensConfigurationType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FirelensConfigurationType::Fluentd => "fluentd",
+            FirelensConfigurationType::Fluentbit => "fluentbit",
+            FirelensConfigurationType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for FirelensConfigurationType {
+    fn default() -> FirelensConfigurationType {
+        FirelensConfigurationType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for FirelensConfigurationType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FirelensConfigurationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FirelensConfigurationType {
+    fn from(value: &'a str) -> FirelensConfigurationType {
+        match value {
+            "fluentd" => FirelensConfigurationType::Fluentd,
+            "fluentbit" => FirelensConfigurationType::Fluentbit,
+            _ => FirelensConfigurationType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FirelensConfigurationType {
+    fn from(value: String) -> FirelensConfigurationType {
+        match value.as_str() {
+            "fluentd" => FirelensConfigurationType::Fluentd,
+            "fluentbit" => FirelensConfigurationType::Fluentbit,
+            _ => FirelensConfigurationType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for FirelensConfigurationType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<FirelensConfigurationType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FirelensConfigurationType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FirelensConfigurationType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FirelensConfigurationType {
+    fn deserialize<D>(deserializer: D) -> Result<FirelensConfigurationType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p><p>An object representing a container health check. Health check parameters that are specified in a container definition override any Docker health checks that exist in the container image (such as those specified in a parent image or from the image&#39;s Dockerfile).</p> <p>The following are notes about container health check support:</p> <ul> <li> <p>Container health checks require version 1.17.0 or greater of the Amazon ECS container agent. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/ecs-agent-update.html">Updating the Amazon ECS Container Agent</a>.</p> </li> <li> <p>Container health checks are supported for Fargate tasks if you are using platform version 1.1.0 or greater. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/platform_versions.html">AWS Fargate Platform Versions</a>.</p> </li> <li> <p>Container health checks are not supported for tasks that are part of a service that is configured to use a Classic Load Balancer.</p> </li> </ul></p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HealthCheck {
+    /// <p>A string array representing the command that the container runs to determine if it is healthy. The string array must start with <code>CMD</code> to execute the command arguments directly, or <code>CMD-SHELL</code> to run the command with the container's default shell. For example:</p> <p> <code>[ "CMD-SHELL", "curl -f http://localhost/ || exit 1" ]</code> </p> <p>An exit code of 0 indicates success, and non-zero exit code indicates failure. For more information, see <code>HealthCheck</code> in the <a href="https://docs.docker.com/engine/api/v1.35/#operation/ContainerCreate">Create a container</a> section of the <a href="https://docs.docker.com/engine/api/v1.35/">Docker Remote API</a>.</p>
+    #[serde(rename = "command")]
+    pub command: Vec<String>,
+    /// <p>The time period in seconds between each health check execution. You may specify between 5 and 300 seconds. The default value is 30 seconds.</p>
+    #[serde(rename = "interval")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub interval: Option<i64>,
     /// <p>The number of times to retry a failed health check before the container is considered unhealthy. You may specify between 1 and 10 retries. The default value is 3.</p>
     #[serde(rename = "retries")]
@@ -1389,6 +2869,104 @@ pub struct HealthCheck {
     pub timeout: Option<i64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum HealthStatus {
+    /// `HEALTHY`
+    Healthy,
+    /// `UNHEALTHY`
+    Unhealthy,
+    /// `UNKNOWN`
+    UnknownValue,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl HealthStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            HealthStatus::Healthy => "HEALTHY",
+            HealthStatus::Unhealthy => "UNHEALTHY",
+            HealthStatus::UnknownValue => "UNKNOWN",
+            HealthStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for HealthStatus {
+    fn default() -> HealthStatus {
+        HealthStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for HealthStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for HealthStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for HealthStatus {
+    fn from(value: &'a str) -> HealthStatus {
+        match value {
+            "HEALTHY" => HealthStatus::Healthy,
+            "UNHEALTHY" => HealthStatus::Unhealthy,
+            "UNKNOWN" => HealthStatus::UnknownValue,
+            _ => HealthStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for HealthStatus {
+    fn from(value: String) -> HealthStatus {
+        match value.as_str() {
+            "HEALTHY" => HealthStatus::Healthy,
+            "UNHEALTHY" => HealthStatus::Unhealthy,
+            "UNKNOWN" => HealthStatus::UnknownValue,
+            _ => HealthStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for HealthStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<HealthStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for HealthStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for HealthStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for HealthStatus {
+    fn deserialize<D>(deserializer: D) -> Result<HealthStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Hostnames and IP address entries that are added to the <code>/etc/hosts</code> file of a container via the <code>extraHosts</code> parameter of its <a>ContainerDefinition</a>. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct HostEntry {
@@ -1433,6 +3011,104 @@ pub struct InferenceAcceleratorOverride {
     pub device_type: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum IpcMode {
+    /// `host`
+    Host,
+    /// `task`
+    Task,
+    /// `none`
+    None,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl IpcMode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            IpcMode::Host => "host",
+            IpcMode::Task => "task",
+            IpcMode::None => "none",
+            IpcMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for IpcMode {
+    fn default() -> IpcMode {
+        IpcMode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for IpcMode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for IpcMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for IpcMode {
+    fn from(value: &'a str) -> IpcMode {
+        match value {
+            "host" => IpcMode::Host,
+            "task" => IpcMode::Task,
+            "none" => IpcMode::None,
+            _ => IpcMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for IpcMode {
+    fn from(value: String) -> IpcMode {
+        match value.as_str() {
+            "host" => IpcMode::Host,
+            "task" => IpcMode::Task,
+            "none" => IpcMode::None,
+            _ => IpcMode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for IpcMode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<IpcMode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for IpcMode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for IpcMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for IpcMode {
+    fn deserialize<D>(deserializer: D) -> Result<IpcMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The Linux capabilities for the container that are added to or dropped from the default configuration provided by Docker. For more information on the default capabilities and the non-default available capabilities, see <a href="https://docs.docker.com/engine/reference/run/#runtime-privilege-and-linux-capabilities">Runtime privilege and Linux capabilities</a> in the <i>Docker run reference</i>. For more detailed information on these Linux capabilities, see the <a href="http://man7.org/linux/man-pages/man7/capabilities.7.html">capabilities(7)</a> Linux manual page.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct KernelCapabilities {
@@ -1459,6 +3135,104 @@ pub struct KeyValuePair {
     pub value: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LaunchType {
+    /// `EC2`
+    Ec2,
+    /// `FARGATE`
+    Fargate,
+    /// `EXTERNAL`
+    External,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LaunchType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LaunchType::Ec2 => "EC2",
+            LaunchType::Fargate => "FARGATE",
+            LaunchType::External => "EXTERNAL",
+            LaunchType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LaunchType {
+    fn default() -> LaunchType {
+        LaunchType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LaunchType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LaunchType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LaunchType {
+    fn from(value: &'a str) -> LaunchType {
+        match value {
+            "EC2" => LaunchType::Ec2,
+            "FARGATE" => LaunchType::Fargate,
+            "EXTERNAL" => LaunchType::External,
+            _ => LaunchType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LaunchType {
+    fn from(value: String) -> LaunchType {
+        match value.as_str() {
+            "EC2" => LaunchType::Ec2,
+            "FARGATE" => LaunchType::Fargate,
+            "EXTERNAL" => LaunchType::External,
+            _ => LaunchType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LaunchType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LaunchType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LaunchType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LaunchType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LaunchType {
+    fn deserialize<D>(deserializer: D) -> Result<LaunchType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Linux-specific options that are applied to the container, such as Linux <a>KernelCapabilities</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct LinuxParameters {
@@ -1506,7 +3280,7 @@ pub struct ListAccountSettingsRequest {
     /// <p>The resource name you want to list the account settings for.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
+    pub name: Option<SettingName>,
     /// <p><p>The <code>nextToken</code> value returned from a <code>ListAccountSettings</code> request indicating that more results are available to fulfill the request and further calls will be needed. If <code>maxResults</code> was provided, it is possible the number of results to be fewer than <code>maxResults</code>.</p> <note> <p>This token should be treated as an opaque identifier that is only used to retrieve the next items in a list and not for other programmatic purposes.</p> </note></p>
     #[serde(rename = "nextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1559,7 +3333,7 @@ pub struct ListAttributesRequest {
     pub next_token: Option<String>,
     /// <p>The type of the target with which to list attributes.</p>
     #[serde(rename = "targetType")]
-    pub target_type: String,
+    pub target_type: TargetType,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1623,7 +3397,7 @@ pub struct ListContainerInstancesRequest {
     /// <p>Filters the container instances by status. For example, if you specify the <code>DRAINING</code> status, the results include only container instances that have been set to <code>DRAINING</code> using <a>UpdateContainerInstancesState</a>. If you do not specify this parameter, the default is to include container instances set to all states other than <code>INACTIVE</code>.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ContainerInstanceStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1649,7 +3423,7 @@ pub struct ListServicesRequest {
     /// <p>The launch type for the services to list.</p>
     #[serde(rename = "launchType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub launch_type: Option<String>,
+    pub launch_type: Option<LaunchType>,
     /// <p>The maximum number of service results returned by <code>ListServices</code> in paginated output. When this parameter is used, <code>ListServices</code> only returns <code>maxResults</code> results in a single page along with a <code>nextToken</code> response element. The remaining results of the initial request can be seen by sending another <code>ListServices</code> request with the returned <code>nextToken</code> value. This value can be between 1 and 100. If this parameter is not used, then <code>ListServices</code> returns up to 10 results and a <code>nextToken</code> value if applicable.</p>
     #[serde(rename = "maxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1661,7 +3435,7 @@ pub struct ListServicesRequest {
     /// <p>The scheduling strategy for services to list.</p>
     #[serde(rename = "schedulingStrategy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scheduling_strategy: Option<String>,
+    pub scheduling_strategy: Option<SchedulingStrategy>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1712,7 +3486,7 @@ pub struct ListTaskDefinitionFamiliesRequest {
     /// <p>The task definition family status with which to filter the <code>ListTaskDefinitionFamilies</code> results. By default, both <code>ACTIVE</code> and <code>INACTIVE</code> task definition families are listed. If this parameter is set to <code>ACTIVE</code>, only task definition families that have an <code>ACTIVE</code> task definition revision are returned. If this parameter is set to <code>INACTIVE</code>, only task definition families that do not have any <code>ACTIVE</code> task definition revisions are returned. If you paginate the resulting output, be sure to keep the <code>status</code> value constant in each subsequent request.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<TaskDefinitionFamilyStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1746,11 +3520,11 @@ pub struct ListTaskDefinitionsRequest {
     /// <p>The order in which to sort the results. Valid values are <code>ASC</code> and <code>DESC</code>. By default (<code>ASC</code>), task definitions are listed lexicographically by family name and in ascending numerical order by revision so that the newest task definitions in a family are listed last. Setting this parameter to <code>DESC</code> reverses the sort order on family name and revision so that the newest task definitions in a family are listed first.</p>
     #[serde(rename = "sort")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort: Option<String>,
+    pub sort: Option<SortOrder>,
     /// <p>The task definition status with which to filter the <code>ListTaskDefinitions</code> results. By default, only <code>ACTIVE</code> task definitions are listed. By setting this parameter to <code>INACTIVE</code>, you can view task definitions that are <code>INACTIVE</code> as long as an active task or service still references them. If you paginate the resulting output, be sure to keep the <code>status</code> value constant in each subsequent request.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<TaskDefinitionStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1780,7 +3554,7 @@ pub struct ListTasksRequest {
     /// <p><p>The task desired status with which to filter the <code>ListTasks</code> results. Specifying a <code>desiredStatus</code> of <code>STOPPED</code> limits the results to tasks that Amazon ECS has set the desired status to <code>STOPPED</code>. This can be useful for debugging tasks that are not starting properly or have died or finished. The default status filter is <code>RUNNING</code>, which shows tasks that Amazon ECS has set the desired status to <code>RUNNING</code>.</p> <note> <p>Although you can filter results based on a desired status of <code>PENDING</code>, this does not return any results. Amazon ECS never sets the desired status of a task to that value (only a task&#39;s <code>lastStatus</code> may have a value of <code>PENDING</code>).</p> </note></p>
     #[serde(rename = "desiredStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub desired_status: Option<String>,
+    pub desired_status: Option<DesiredStatus>,
     /// <p>The name of the family with which to filter the <code>ListTasks</code> results. Specifying a <code>family</code> limits the results to tasks that belong to that family.</p>
     #[serde(rename = "family")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1788,7 +3562,7 @@ pub struct ListTasksRequest {
     /// <p>The launch type for services to list.</p>
     #[serde(rename = "launchType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub launch_type: Option<String>,
+    pub launch_type: Option<LaunchType>,
     /// <p>The maximum number of task results returned by <code>ListTasks</code> in paginated output. When this parameter is used, <code>ListTasks</code> only returns <code>maxResults</code> results in a single page along with a <code>nextToken</code> response element. The remaining results of the initial request can be seen by sending another <code>ListTasks</code> request with the returned <code>nextToken</code> value. This value can be between 1 and 100. If this parameter is not used, then <code>ListTasks</code> returns up to 100 results and a <code>nextToken</code> value if applicable.</p>
     #[serde(rename = "maxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1846,7 +3620,7 @@ pub struct LoadBalancer {
 pub struct LogConfiguration {
     /// <p><p>The log driver to use for the container. The valid values listed earlier are log drivers that the Amazon ECS container agent can communicate with by default.</p> <p>For tasks using the Fargate launch type, the supported log drivers are <code>awslogs</code>, <code>splunk</code>, and <code>awsfirelens</code>.</p> <p>For tasks using the EC2 launch type, the supported log drivers are <code>awslogs</code>, <code>fluentd</code>, <code>gelf</code>, <code>json-file</code>, <code>journald</code>, <code>logentries</code>,<code>syslog</code>, <code>splunk</code>, and <code>awsfirelens</code>.</p> <p>For more information about using the <code>awslogs</code> log driver, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/using_awslogs.html">Using the awslogs Log Driver</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <p>For more information about using the <code>awsfirelens</code> log driver, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/using_firelens.html">Custom Log Routing</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <note> <p>If you have a custom driver that is not listed, you can fork the Amazon ECS container agent project that is <a href="https://github.com/aws/amazon-ecs-agent">available on GitHub</a> and customize it to work with that driver. We encourage you to submit pull requests for changes that you would like to have included. However, we do not currently provide support for running modified copies of this software.</p> </note></p>
     #[serde(rename = "logDriver")]
-    pub log_driver: String,
+    pub log_driver: LogDriver,
     /// <p>The configuration options to send to the log driver. This parameter requires version 1.19 of the Docker Remote API or greater on your container instance. To check the Docker Remote API version on your container instance, log in to your container instance and run the following command: <code>sudo docker version --format '{{.Server.APIVersion}}'</code> </p>
     #[serde(rename = "options")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1857,6 +3631,129 @@ pub struct LogConfiguration {
     pub secret_options: Option<Vec<Secret>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LogDriver {
+    /// `json-file`
+    JsonFile,
+    /// `syslog`
+    Syslog,
+    /// `journald`
+    Journald,
+    /// `gelf`
+    Gelf,
+    /// `fluentd`
+    Fluentd,
+    /// `awslogs`
+    Awslogs,
+    /// `splunk`
+    Splunk,
+    /// `awsfirelens`
+    Awsfirelens,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LogDriver {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LogDriver::JsonFile => "json-file",
+            LogDriver::Syslog => "syslog",
+            LogDriver::Journald => "journald",
+            LogDriver::Gelf => "gelf",
+            LogDriver::Fluentd => "fluentd",
+            LogDriver::Awslogs => "awslogs",
+            LogDriver::Splunk => "splunk",
+            LogDriver::Awsfirelens => "awsfirelens",
+            LogDriver::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LogDriver {
+    fn default() -> LogDriver {
+        LogDriver::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LogDriver {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LogDriver {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LogDriver {
+    fn from(value: &'a str) -> LogDriver {
+        match value {
+            "json-file" => LogDriver::JsonFile,
+            "syslog" => LogDriver::Syslog,
+            "journald" => LogDriver::Journald,
+            "gelf" => LogDriver::Gelf,
+            "fluentd" => LogDriver::Fluentd,
+            "awslogs" => LogDriver::Awslogs,
+            "splunk" => LogDriver::Splunk,
+            "awsfirelens" => LogDriver::Awsfirelens,
+            _ => LogDriver::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LogDriver {
+    fn from(value: String) -> LogDriver {
+        match value.as_str() {
+            "json-file" => LogDriver::JsonFile,
+            "syslog" => LogDriver::Syslog,
+            "journald" => LogDriver::Journald,
+            "gelf" => LogDriver::Gelf,
+            "fluentd" => LogDriver::Fluentd,
+            "awslogs" => LogDriver::Awslogs,
+            "splunk" => LogDriver::Splunk,
+            "awsfirelens" => LogDriver::Awsfirelens,
+            _ => LogDriver::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LogDriver {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LogDriver, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LogDriver {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LogDriver {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LogDriver {
+    fn deserialize<D>(deserializer: D) -> Result<LogDriver, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The managed scaling settings for the Auto Scaling group capacity provider.</p> <p>When managed scaling is enabled, Amazon ECS manages the scale-in and scale-out actions of the Auto Scaling group. Amazon ECS manages a target tracking scaling policy using an Amazon ECS-managed CloudWatch metric with the specified <code>targetCapacity</code> value as the target value for the metric. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/asg-capacity-providers.html#asg-capacity-providers-managed-scaling">Using Managed Scaling</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <p>If managed scaling is disabled, the user must manage the scaling of the Auto Scaling group.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ManagedScaling {
@@ -1871,53 +3768,239 @@ pub struct ManagedScaling {
     /// <p>Whether or not to enable managed scaling for the capacity provider.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ManagedScalingStatus>,
     /// <p>The target capacity value for the capacity provider. The specified value must be greater than <code>0</code> and less than or equal to <code>100</code>. A value of <code>100</code> will result in the Amazon EC2 instances in your Auto Scaling group being completely utilized.</p>
     #[serde(rename = "targetCapacity")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub target_capacity: Option<i64>,
 }
 
-/// <p>Details on a volume mount point that is used in a container definition.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct MountPoint {
-    /// <p>The path on the container to mount the host volume at.</p>
-    #[serde(rename = "containerPath")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub container_path: Option<String>,
-    /// <p>If this value is <code>true</code>, the container has read-only access to the volume. If this value is <code>false</code>, then the container can write to the volume. The default value is <code>false</code>.</p>
-    #[serde(rename = "readOnly")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub read_only: Option<bool>,
-    /// <p>The name of the volume to mount. Must be a volume name referenced in the <code>name</code> parameter of task definition <code>volume</code>.</p>
-    #[serde(rename = "sourceVolume")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub source_volume: Option<String>,
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ManagedScalingStatus {
+    /// `ENABLED`
+    Enabled,
+    /// `DISABLED`
+    Disabled,
+    /// A value not known to this version of the crate
+    Unknown(String),
 }
 
-/// <p>Details on the network bindings between a container and its host container instance. After a task reaches the <code>RUNNING</code> status, manual and automatic host and container port assignments are visible in the <code>networkBindings</code> section of <a>DescribeTasks</a> API responses.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct NetworkBinding {
-    /// <p>The IP address that the container is bound to on the container instance.</p>
-    #[serde(rename = "bindIP")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub bind_ip: Option<String>,
-    /// <p>The port number on the container that is used with the network binding.</p>
-    #[serde(rename = "containerPort")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub container_port: Option<i64>,
-    /// <p>The port number on the host that is used with the network binding.</p>
-    #[serde(rename = "hostPort")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub host_port: Option<i64>,
-    /// <p>The protocol used for the network binding.</p>
-    #[serde(rename = "protocol")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub protocol: Option<String>,
+impl ManagedScalingStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ManagedScalingStatus::Enabled => "ENABLED",
+            ManagedScalingStatus::Disabled => "DISABLED",
+            ManagedScalingStatus::Unknown(ref value) => value,
+        }
+    }
 }
 
-/// <p>An object representing the network configuration for a task or service.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ManagedScalingStatus {
+    fn default() -> ManagedScalingStatus {
+        ManagedScalingStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ManagedScalingStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ManagedScalingStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ManagedScalingStatus {
+    fn from(value: &'a str) -> ManagedScalingStatus {
+        match value {
+            "ENABLED" => ManagedScalingStatus::Enabled,
+            "DISABLED" => ManagedScalingStatus::Disabled,
+            _ => ManagedScalingStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ManagedScalingStatus {
+    fn from(value: String) -> ManagedScalingStatus {
+        match value.as_str() {
+            "ENABLED" => ManagedScalingStatus::Enabled,
+            "DISABLED" => ManagedScalingStatus::Disabled,
+            _ => ManagedScalingStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ManagedScalingStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ManagedScalingStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ManagedScalingStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ManagedScalingStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ManagedScalingStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ManagedScalingStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ManagedTerminationProtection {
+    /// `ENABLED`
+    Enabled,
+    /// `DISABLED`
+    Disabled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ManagedTerminationProtection {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ManagedTerminationProtection::Enabled => "ENABLED",
+            ManagedTerminationProtection::Disabled => "DISABLED",
+            ManagedTerminationProtection::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ManagedTerminationProtection {
+    fn default() -> ManagedTerminationProtection {
+        ManagedTerminationProtection::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ManagedTerminationProtection {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ManagedTerminationProtection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ManagedTerminationProtection {
+    fn from(value: &'a str) -> ManagedTerminationProtection {
+        match value {
+            "ENABLED" => ManagedTerminationProtection::Enabled,
+            "DISABLED" => ManagedTerminationProtection::Disabled,
+            _ => ManagedTerminationProtection::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ManagedTerminationProtection {
+    fn from(value: String) -> ManagedTerminationProtection {
+        match value.as_str() {
+            "ENABLED" => ManagedTerminationProtection::Enabled,
+            "DISABLED" => ManagedTerminationProtection::Disabled,
+            _ => ManagedTerminationProtection::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ManagedTerminationProtection {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ManagedTerminationProtection, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ManagedTerminationProtection {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ManagedTerminationProtection {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ManagedTerminationProtection {
+    fn deserialize<D>(deserializer: D) -> Result<ManagedTerminationProtection, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Details on a volume mount point that is used in a container definition.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MountPoint {
+    /// <p>The path on the container to mount the host volume at.</p>
+    #[serde(rename = "containerPath")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub container_path: Option<String>,
+    /// <p>If this value is <code>true</code>, the container has read-only access to the volume. If this value is <code>false</code>, then the container can write to the volume. The default value is <code>false</code>.</p>
+    #[serde(rename = "readOnly")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub read_only: Option<bool>,
+    /// <p>The name of the volume to mount. Must be a volume name referenced in the <code>name</code> parameter of task definition <code>volume</code>.</p>
+    #[serde(rename = "sourceVolume")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_volume: Option<String>,
+}
+
+/// <p>Details on the network bindings between a container and its host container instance. After a task reaches the <code>RUNNING</code> status, manual and automatic host and container port assignments are visible in the <code>networkBindings</code> section of <a>DescribeTasks</a> API responses.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NetworkBinding {
+    /// <p>The IP address that the container is bound to on the container instance.</p>
+    #[serde(rename = "bindIP")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bind_ip: Option<String>,
+    /// <p>The port number on the container that is used with the network binding.</p>
+    #[serde(rename = "containerPort")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub container_port: Option<i64>,
+    /// <p>The port number on the host that is used with the network binding.</p>
+    #[serde(rename = "hostPort")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub host_port: Option<i64>,
+    /// <p>The protocol used for the network binding.</p>
+    #[serde(rename = "protocol")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protocol: Option<TransportProtocol>,
+}
+
+/// <p>An object representing the network configuration for a task or service.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct NetworkConfiguration {
     /// <p><p>The VPC subnets and security groups associated with a task.</p> <note> <p>All specified subnets and security groups must be from the same VPC.</p> </note></p>
     #[serde(rename = "awsvpcConfiguration")]
@@ -1943,6 +4026,202 @@ pub struct NetworkInterface {
     pub private_ipv_4_address: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum NetworkMode {
+    /// `bridge`
+    Bridge,
+    /// `host`
+    Host,
+    /// `awsvpc`
+    Awsvpc,
+    /// `none`
+    None,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl NetworkMode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            NetworkMode::Bridge => "bridge",
+            NetworkMode::Host => "host",
+            NetworkMode::Awsvpc => "awsvpc",
+            NetworkMode::None => "none",
+            NetworkMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for NetworkMode {
+    fn default() -> NetworkMode {
+        NetworkMode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for NetworkMode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for NetworkMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for NetworkMode {
+    fn from(value: &'a str) -> NetworkMode {
+        match value {
+            "bridge" => NetworkMode::Bridge,
+            "host" => NetworkMode::Host,
+            "awsvpc" => NetworkMode::Awsvpc,
+            "none" => NetworkMode::None,
+            _ => NetworkMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for NetworkMode {
+    fn from(value: String) -> NetworkMode {
+        match value.as_str() {
+            "bridge" => NetworkMode::Bridge,
+            "host" => NetworkMode::Host,
+            "awsvpc" => NetworkMode::Awsvpc,
+            "none" => NetworkMode::None,
+            _ => NetworkMode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for NetworkMode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<NetworkMode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for NetworkMode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for NetworkMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for NetworkMode {
+    fn deserialize<D>(deserializer: D) -> Result<NetworkMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PidMode {
+    /// `host`
+    Host,
+    /// `task`
+    Task,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PidMode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PidMode::Host => "host",
+            PidMode::Task => "task",
+            PidMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PidMode {
+    fn default() -> PidMode {
+        PidMode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PidMode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PidMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PidMode {
+    fn from(value: &'a str) -> PidMode {
+        match value {
+            "host" => PidMode::Host,
+            "task" => PidMode::Task,
+            _ => PidMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PidMode {
+    fn from(value: String) -> PidMode {
+        match value.as_str() {
+            "host" => PidMode::Host,
+            "task" => PidMode::Task,
+            _ => PidMode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PidMode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PidMode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PidMode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PidMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PidMode {
+    fn deserialize<D>(deserializer: D) -> Result<PidMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p><p>An object representing a constraint on task placement. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/task-placement-constraints.html">Task Placement Constraints</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <note> <p>If you are using the Fargate launch type, task placement constraints are not supported.</p> </note></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PlacementConstraint {
@@ -1953,9 +4232,102 @@ pub struct PlacementConstraint {
     /// <p>The type of constraint. Use <code>distinctInstance</code> to ensure that each task in a particular group is running on a different container instance. Use <code>memberOf</code> to restrict the selection to a group of valid candidates.</p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<PlacementConstraintType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PlacementConstraintType {
+    /// `distinctInstance`
+    DistinctInstance,
+    /// `memberOf`
+    MemberOf,
+    /// A value not known to this version of the crate
+    Unknown(String),
 }
 
+impl PlacementConstraintType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PlacementConstraintType::DistinctInstance => "distinctInstance",
+            PlacementConstraintType::MemberOf => "memberOf",
+            PlacementConstraintType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PlacementConstraintType {
+    fn default() -> PlacementConstraintType {
+        PlacementConstraintType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PlacementConstraintType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PlacementConstraintType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PlacementConstraintType {
+    fn from(value: &'a str) -> PlacementConstraintType {
+        match value {
+            "distinctInstance" => PlacementConstraintType::DistinctInstance,
+            "memberOf" => PlacementConstraintType::MemberOf,
+            _ => PlacementConstraintType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PlacementConstraintType {
+    fn from(value: String) -> PlacementConstraintType {
+        match value.as_str() {
+            "distinctInstance" => PlacementConstraintType::DistinctInstance,
+            "memberOf" => PlacementConstraintType::MemberOf,
+            _ => PlacementConstraintType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PlacementConstraintType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PlacementConstraintType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PlacementConstraintType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PlacementConstraintType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PlacementConstraintType {
+    fn deserialize<D>(deserializer: D) -> Result<PlacementConstraintType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The task placement strategy for a task or service. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/task-placement-strategies.html">Task Placement Strategies</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PlacementStrategy {
@@ -1966,9 +4338,107 @@ pub struct PlacementStrategy {
     /// <p>The type of placement strategy. The <code>random</code> placement strategy randomly places tasks on available candidates. The <code>spread</code> placement strategy spreads placement across available candidates evenly based on the <code>field</code> parameter. The <code>binpack</code> strategy places tasks on available candidates that have the least available amount of the resource that is specified with the <code>field</code> parameter. For example, if you binpack on memory, a task is placed on the instance with the least amount of remaining memory (but still enough to run the task).</p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<PlacementStrategyType>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PlacementStrategyType {
+    /// `random`
+    Random,
+    /// `spread`
+    Spread,
+    /// `binpack`
+    Binpack,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PlacementStrategyType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PlacementStrategyType::Random => "random",
+            PlacementStrategyType::Spread => "spread",
+            PlacementStrategyType::Binpack => "binpack",
+            PlacementStrategyType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PlacementStrategyType {
+    fn default() -> PlacementStrategyType {
+        PlacementStrategyType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PlacementStrategyType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PlacementStrategyType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PlacementStrategyType {
+    fn from(value: &'a str) -> PlacementStrategyType {
+        match value {
+            "random" => PlacementStrategyType::Random,
+            "spread" => PlacementStrategyType::Spread,
+            "binpack" => PlacementStrategyType::Binpack,
+            _ => PlacementStrategyType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PlacementStrategyType {
+    fn from(value: String) -> PlacementStrategyType {
+        match value.as_str() {
+            "random" => PlacementStrategyType::Random,
+            "spread" => PlacementStrategyType::Spread,
+            "binpack" => PlacementStrategyType::Binpack,
+            _ => PlacementStrategyType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PlacementStrategyType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PlacementStrategyType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PlacementStrategyType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PlacementStrategyType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PlacementStrategyType {
+    fn deserialize<D>(deserializer: D) -> Result<PlacementStrategyType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The devices that are available on the container instance. The only supported device type is a GPU.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -1978,9 +4448,97 @@ pub struct PlatformDevice {
     pub id: String,
     /// <p>The type of device that is available on the container instance. The only supported value is <code>GPU</code>.</p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: PlatformDeviceType,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PlatformDeviceType {
+    /// `GPU`
+    Gpu,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PlatformDeviceType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PlatformDeviceType::Gpu => "GPU",
+            PlatformDeviceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PlatformDeviceType {
+    fn default() -> PlatformDeviceType {
+        PlatformDeviceType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PlatformDeviceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PlatformDeviceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PlatformDeviceType {
+    fn from(value: &'a str) -> PlatformDeviceType {
+        match value {
+            "GPU" => PlatformDeviceType::Gpu,
+            _ => PlatformDeviceType::Unknown(value.to_owned()),
+        }
+    }
 }
 
+impl From<String> for PlatformDeviceType {
+    fn from(value: String) -> PlatformDeviceType {
+        match value.as_str() {
+            "GPU" => PlatformDeviceType::Gpu,
+            _ => PlatformDeviceType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PlatformDeviceType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PlatformDeviceType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PlatformDeviceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PlatformDeviceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PlatformDeviceType {
+    fn deserialize<D>(deserializer: D) -> Result<PlatformDeviceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Port mappings allow containers to access ports on the host container instance to send or receive traffic. Port mappings are specified as part of the container definition.</p> <p>If you are using containers in a task with the <code>awsvpc</code> or <code>host</code> network mode, exposed ports should be specified using <code>containerPort</code>. The <code>hostPort</code> can be left blank or it must be the same value as the <code>containerPort</code>.</p> <p>After a task reaches the <code>RUNNING</code> status, manual and automatic host and container port assignments are visible in the <code>networkBindings</code> section of <a>DescribeTasks</a> API responses.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PortMapping {
@@ -1995,9 +4553,107 @@ pub struct PortMapping {
     /// <p>The protocol used for the port mapping. Valid values are <code>tcp</code> and <code>udp</code>. The default is <code>tcp</code>.</p>
     #[serde(rename = "protocol")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub protocol: Option<String>,
+    pub protocol: Option<TransportProtocol>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PropagateTags {
+    /// `TASK_DEFINITION`
+    TaskDefinition,
+    /// `SERVICE`
+    Service,
+    /// `NONE`
+    None,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PropagateTags {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PropagateTags::TaskDefinition => "TASK_DEFINITION",
+            PropagateTags::Service => "SERVICE",
+            PropagateTags::None => "NONE",
+            PropagateTags::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PropagateTags {
+    fn default() -> PropagateTags {
+        PropagateTags::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PropagateTags {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PropagateTags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PropagateTags {
+    fn from(value: &'a str) -> PropagateTags {
+        match value {
+            "TASK_DEFINITION" => PropagateTags::TaskDefinition,
+            "SERVICE" => PropagateTags::Service,
+            "NONE" => PropagateTags::None,
+            _ => PropagateTags::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PropagateTags {
+    fn from(value: String) -> PropagateTags {
+        match value.as_str() {
+            "TASK_DEFINITION" => PropagateTags::TaskDefinition,
+            "SERVICE" => PropagateTags::Service,
+            "NONE" => PropagateTags::None,
+            _ => PropagateTags::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PropagateTags {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PropagateTags, Self::Err> {
+        Ok(value.into())
+    }
 }
 
+impl ::rusoto_core::param::ToParam for PropagateTags {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PropagateTags {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PropagateTags {
+    fn deserialize<D>(deserializer: D) -> Result<PropagateTags, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The configuration details for the App Mesh proxy.</p> <p>For tasks using the EC2 launch type, the container instances require at least version 1.26.0 of the container agent and at least version 1.26.0-1 of the <code>ecs-init</code> package to enable a proxy configuration. If your container instances are launched from the Amazon ECS-optimized AMI version <code>20190301</code> or later, then they contain the required versions of the container agent and <code>ecs-init</code>. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/ecs-optimized_AMI.html">Amazon ECS-optimized Linux AMI</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <p>For tasks using the Fargate launch type, the task or service requires platform version 1.3.0 or later.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ProxyConfiguration {
@@ -2011,15 +4667,103 @@ pub struct ProxyConfiguration {
     /// <p>The proxy type. The only supported value is <code>APPMESH</code>.</p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<ProxyConfigurationType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ProxyConfigurationType {
+    /// `APPMESH`
+    Appmesh,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ProxyConfigurationType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ProxyConfigurationType::Appmesh => "APPMESH",
+            ProxyConfigurationType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ProxyConfigurationType {
+    fn default() -> ProxyConfigurationType {
+        ProxyConfigurationType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ProxyConfigurationType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ProxyConfigurationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ProxyConfigurationType {
+    fn from(value: &'a str) -> ProxyConfigurationType {
+        match value {
+            "APPMESH" => ProxyConfigurationType::Appmesh,
+            _ => ProxyConfigurationType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ProxyConfigurationType {
+    fn from(value: String) -> ProxyConfigurationType {
+        match value.as_str() {
+            "APPMESH" => ProxyConfigurationType::Appmesh,
+            _ => ProxyConfigurationType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ProxyConfigurationType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ProxyConfigurationType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ProxyConfigurationType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ProxyConfigurationType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for ProxyConfigurationType {
+    fn deserialize<D>(deserializer: D) -> Result<ProxyConfigurationType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct PutAccountSettingDefaultRequest {
     /// <p>The resource name for which to modify the account setting. If <code>serviceLongArnFormat</code> is specified, the ARN for your Amazon ECS services is affected. If <code>taskLongArnFormat</code> is specified, the ARN and resource ID for your Amazon ECS tasks is affected. If <code>containerInstanceLongArnFormat</code> is specified, the ARN and resource ID for your Amazon ECS container instances is affected. If <code>awsvpcTrunking</code> is specified, the ENI limit for your Amazon ECS container instances is affected. If <code>containerInsights</code> is specified, the default setting for CloudWatch Container Insights for your clusters is affected.</p>
     #[serde(rename = "name")]
-    pub name: String,
+    pub name: SettingName,
     /// <p>The account setting value for the specified principal ARN. Accepted values are <code>enabled</code> and <code>disabled</code>.</p>
     #[serde(rename = "value")]
     pub value: String,
@@ -2038,7 +4782,7 @@ pub struct PutAccountSettingDefaultResponse {
 pub struct PutAccountSettingRequest {
     /// <p>The Amazon ECS resource name for which to modify the account setting. If <code>serviceLongArnFormat</code> is specified, the ARN for your Amazon ECS services is affected. If <code>taskLongArnFormat</code> is specified, the ARN and resource ID for your Amazon ECS tasks is affected. If <code>containerInstanceLongArnFormat</code> is specified, the ARN and resource ID for your Amazon ECS container instances is affected. If <code>awsvpcTrunking</code> is specified, the elastic network interface (ENI) limit for your Amazon ECS container instances is affected. If <code>containerInsights</code> is specified, the default setting for CloudWatch Container Insights for your clusters is affected.</p>
     #[serde(rename = "name")]
-    pub name: String,
+    pub name: SettingName,
     /// <p>The ARN of the principal, which can be an IAM user, IAM role, or the root user. If you specify the root user, it modifies the account setting for all IAM users, IAM roles, and the root user of the account unless an IAM user or role explicitly overrides these settings. If this field is omitted, the setting is changed only for the authenticated user.</p>
     #[serde(rename = "principalArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2174,7 +4918,7 @@ pub struct RegisterTaskDefinitionRequest {
     /// <p><p>The IPC resource namespace to use for the containers in the task. The valid values are <code>host</code>, <code>task</code>, or <code>none</code>. If <code>host</code> is specified, then all containers within the tasks that specified the <code>host</code> IPC mode on the same container instance share the same IPC resources with the host Amazon EC2 instance. If <code>task</code> is specified, all containers within the specified task share the same IPC resources. If <code>none</code> is specified, then IPC resources within the containers of a task are private and not shared with other containers in a task or on the container instance. If no value is specified, then the IPC resource namespace sharing depends on the Docker daemon setting on the container instance. For more information, see <a href="https://docs.docker.com/engine/reference/run/#ipc-settings---ipc">IPC settings</a> in the <i>Docker run reference</i>.</p> <p>If the <code>host</code> IPC mode is used, be aware that there is a heightened risk of undesired IPC namespace expose. For more information, see <a href="https://docs.docker.com/engine/security/security/">Docker security</a>.</p> <p>If you are setting namespaced kernel parameters using <code>systemControls</code> for the containers in the task, the following will apply to your IPC resource namespace. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/task_definition_parameters.html">System Controls</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <ul> <li> <p>For tasks that use the <code>host</code> IPC mode, IPC namespace related <code>systemControls</code> are not supported.</p> </li> <li> <p>For tasks that use the <code>task</code> IPC mode, IPC namespace related <code>systemControls</code> will apply to all containers within a task.</p> </li> </ul> <note> <p>This parameter is not supported for Windows containers or tasks using the Fargate launch type.</p> </note></p>
     #[serde(rename = "ipcMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ipc_mode: Option<String>,
+    pub ipc_mode: Option<IpcMode>,
     /// <p><p>The amount of memory (in MiB) used by the task. It can be expressed as an integer using MiB, for example <code>1024</code>, or as a string using GB, for example <code>1GB</code> or <code>1 GB</code>, in a task definition. String values are converted to an integer indicating the MiB when the task definition is registered.</p> <note> <p>Task-level CPU and memory parameters are ignored for Windows containers. We recommend specifying container-level resources for Windows containers.</p> </note> <p>If using the EC2 launch type, this field is optional.</p> <p>If using the Fargate launch type, this field is required and you must use one of the following values, which determines your range of supported values for the <code>cpu</code> parameter:</p> <ul> <li> <p>512 (0.5 GB), 1024 (1 GB), 2048 (2 GB) - Available <code>cpu</code> values: 256 (.25 vCPU)</p> </li> <li> <p>1024 (1 GB), 2048 (2 GB), 3072 (3 GB), 4096 (4 GB) - Available <code>cpu</code> values: 512 (.5 vCPU)</p> </li> <li> <p>2048 (2 GB), 3072 (3 GB), 4096 (4 GB), 5120 (5 GB), 6144 (6 GB), 7168 (7 GB), 8192 (8 GB) - Available <code>cpu</code> values: 1024 (1 vCPU)</p> </li> <li> <p>Between 4096 (4 GB) and 16384 (16 GB) in increments of 1024 (1 GB) - Available <code>cpu</code> values: 2048 (2 vCPU)</p> </li> <li> <p>Between 8192 (8 GB) and 30720 (30 GB) in increments of 1024 (1 GB) - Available <code>cpu</code> values: 4096 (4 vCPU)</p> </li> </ul></p>
     #[serde(rename = "memory")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2182,11 +4926,11 @@ pub struct RegisterTaskDefinitionRequest {
     /// <p>The Docker networking mode to use for the containers in the task. The valid values are <code>none</code>, <code>bridge</code>, <code>awsvpc</code>, and <code>host</code>. The default Docker network mode is <code>bridge</code>. If you are using the Fargate launch type, the <code>awsvpc</code> network mode is required. If you are using the EC2 launch type, any network mode can be used. If the network mode is set to <code>none</code>, you cannot specify port mappings in your container definitions, and the tasks containers do not have external connectivity. The <code>host</code> and <code>awsvpc</code> network modes offer the highest networking performance for containers because they use the EC2 network stack instead of the virtualized network stack provided by the <code>bridge</code> mode.</p> <p>With the <code>host</code> and <code>awsvpc</code> network modes, exposed container ports are mapped directly to the corresponding host port (for the <code>host</code> network mode) or the attached elastic network interface port (for the <code>awsvpc</code> network mode), so you cannot take advantage of dynamic host port mappings. </p> <p>If the network mode is <code>awsvpc</code>, the task is allocated an elastic network interface, and you must specify a <a>NetworkConfiguration</a> value when you create a service or run a task with the task definition. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/task-networking.html">Task Networking</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <note> <p>Currently, only Amazon ECS-optimized AMIs, other Amazon Linux variants with the <code>ecs-init</code> package, or AWS Fargate infrastructure support the <code>awsvpc</code> network mode. </p> </note> <p>If the network mode is <code>host</code>, you cannot run multiple instantiations of the same task on a single container instance when port mappings are used.</p> <p>Docker for Windows uses different network modes than Docker for Linux. When you register a task definition with Windows containers, you must not specify a network mode. If you use the console to register a task definition with Windows containers, you must choose the <code>&lt;default&gt;</code> network mode object. </p> <p>For more information, see <a href="https://docs.docker.com/engine/reference/run/#network-settings">Network settings</a> in the <i>Docker run reference</i>.</p>
     #[serde(rename = "networkMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub network_mode: Option<String>,
+    pub network_mode: Option<NetworkMode>,
     /// <p><p>The process namespace to use for the containers in the task. The valid values are <code>host</code> or <code>task</code>. If <code>host</code> is specified, then all containers within the tasks that specified the <code>host</code> PID mode on the same container instance share the same process namespace with the host Amazon EC2 instance. If <code>task</code> is specified, all containers within the specified task share the same process namespace. If no value is specified, the default is a private namespace. For more information, see <a href="https://docs.docker.com/engine/reference/run/#pid-settings---pid">PID settings</a> in the <i>Docker run reference</i>.</p> <p>If the <code>host</code> PID mode is used, be aware that there is a heightened risk of undesired process namespace expose. For more information, see <a href="https://docs.docker.com/engine/security/security/">Docker security</a>.</p> <note> <p>This parameter is not supported for Windows containers or tasks using the Fargate launch type.</p> </note></p>
     #[serde(rename = "pidMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub pid_mode: Option<String>,
+    pub pid_mode: Option<PidMode>,
     /// <p>An array of placement constraint objects to use for the task. You can specify a maximum of 10 constraints per task (this limit includes constraints in the task definition and those specified at runtime).</p>
     #[serde(rename = "placementConstraints")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2197,7 +4941,7 @@ pub struct RegisterTaskDefinitionRequest {
     /// <p>The launch type required by the task. If no value is specified, it defaults to <code>EC2</code>.</p>
     #[serde(rename = "requiresCompatibilities")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub requires_compatibilities: Option<Vec<String>>,
+    pub requires_compatibilities: Option<Vec<Compatibility>>,
     /// <p><p>The metadata that you apply to the task definition to help you categorize and organize them. Each tag consists of a key and an optional value, both of which you define.</p> <p>The following basic restrictions apply to tags:</p> <ul> <li> <p>Maximum number of tags per resource - 50</p> </li> <li> <p>For each resource, each tag key must be unique, and each tag key can have only one value.</p> </li> <li> <p>Maximum key length - 128 Unicode characters in UTF-8</p> </li> <li> <p>Maximum value length - 256 Unicode characters in UTF-8</p> </li> <li> <p>If your tagging schema is used across multiple services and resources, remember that other services may have restrictions on allowed characters. Generally allowed characters are: letters, numbers, and spaces representable in UTF-8, and the following characters: + - = . _ : / @.</p> </li> <li> <p>Tag keys and values are case-sensitive.</p> </li> <li> <p>Do not use <code>aws:</code>, <code>AWS:</code>, or any upper or lowercase combination of such as a prefix for either keys or values as it is reserved for AWS use. You cannot edit or delete tag keys or values with this prefix. Tags with this prefix do not count against your tags per resource limit.</p> </li> </ul></p>
     #[serde(rename = "tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2262,111 +5006,478 @@ pub struct Resource {
     pub type_: Option<String>,
 }
 
-/// <p>The type and amount of a resource to assign to a container. The supported resource types are GPUs and Elastic Inference accelerators. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/ecs-gpu.html">Working with GPUs on Amazon ECS</a> or <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/ecs-eia.html">Working with Amazon Elastic Inference on Amazon ECS</a> in the <i>Amazon Elastic Container Service Developer Guide</i> </p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct ResourceRequirement {
-    /// <p>The type of resource to assign to a container. The supported values are <code>GPU</code> or <code>InferenceAccelerator</code>.</p>
-    #[serde(rename = "type")]
-    pub type_: String,
-    /// <p>The value for the specified resource type.</p> <p>If the <code>GPU</code> type is used, the value is the number of physical <code>GPUs</code> the Amazon ECS container agent will reserve for the container. The number of GPUs reserved for all containers in a task should not exceed the number of available GPUs on the container instance the task is launched on.</p> <p>If the <code>InferenceAccelerator</code> type is used, the <code>value</code> should match the <code>deviceName</code> for an <a>InferenceAccelerator</a> specified in a task definition.</p>
-    #[serde(rename = "value")]
-    pub value: String,
-}
+/// <p>The type and amount of a resource to assign to a container. The supported resource types are GPUs and Elastic Inference accelerators. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/ecs-gpu.html">Working with GPUs on Amazon ECS</a> or <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/ecs-eia.html">Working with Amazon Elastic Inference on Amazon ECS</a> in the <i>Amazon Elastic Container Service Developer Guide</i> </p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResourceRequirement {
+    /// <p>The type of resource to assign to a container. The supported values are <code>GPU</code> or <code>InferenceAccelerator</code>.</p>
+    #[serde(rename = "type")]
+    pub type_: ResourceType,
+    /// <p>The value for the specified resource type.</p> <p>If the <code>GPU</code> type is used, the value is the number of physical <code>GPUs</code> the Amazon ECS container agent will reserve for the container. The number of GPUs reserved for all containers in a task should not exceed the number of available GPUs on the container instance the task is launched on.</p> <p>If the <code>InferenceAccelerator</code> type is used, the <code>value</code> should match the <code>deviceName</code> for an <a>InferenceAccelerator</a> specified in a task definition.</p>
+    #[serde(rename = "value")]
+    pub value: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ResourceType {
+    /// `GPU`
+    Gpu,
+    /// `InferenceAccelerator`
+    InferenceAccelerator,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ResourceType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceType::Gpu => "GPU",
+            ResourceType::InferenceAccelerator => "InferenceAccelerator",
+            ResourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ResourceType {
+    fn default() -> ResourceType {
+        ResourceType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ResourceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ResourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ResourceType {
+    fn from(value: &'a str) -> ResourceType {
+        match value {
+            "GPU" => ResourceType::Gpu,
+            "InferenceAccelerator" => ResourceType::InferenceAccelerator,
+            _ => ResourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceType {
+    fn from(value: String) -> ResourceType {
+        match value.as_str() {
+            "GPU" => ResourceType::Gpu,
+            "InferenceAccelerator" => ResourceType::InferenceAccelerator,
+            _ => ResourceType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ResourceType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ResourceType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ResourceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ResourceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ResourceType {
+    fn deserialize<D>(deserializer: D) -> Result<ResourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct RunTaskRequest {
+    /// <p>The capacity provider strategy to use for the task.</p> <p>A capacity provider strategy consists of one or more capacity providers along with the <code>base</code> and <code>weight</code> to assign to them. A capacity provider must be associated with the cluster to be used in a capacity provider strategy. The <a>PutClusterCapacityProviders</a> API is used to associate a capacity provider with a cluster. Only capacity providers with an <code>ACTIVE</code> or <code>UPDATING</code> status can be used.</p> <p>If a <code>capacityProviderStrategy</code> is specified, the <code>launchType</code> parameter must be omitted. If no <code>capacityProviderStrategy</code> or <code>launchType</code> is specified, the <code>defaultCapacityProviderStrategy</code> for the cluster is used.</p> <p>If specifying a capacity provider that uses an Auto Scaling group, the capacity provider must already be created. New capacity providers can be created with the <a>CreateCapacityProvider</a> API operation.</p> <p>To use a AWS Fargate capacity provider, specify either the <code>FARGATE</code> or <code>FARGATE_SPOT</code> capacity providers. The AWS Fargate capacity providers are available to all accounts and only need to be associated with a cluster to be used.</p> <p>The <a>PutClusterCapacityProviders</a> API operation is used to update the list of available capacity providers for a cluster after the cluster is created.</p>
+    #[serde(rename = "capacityProviderStrategy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capacity_provider_strategy: Option<Vec<CapacityProviderStrategyItem>>,
+    /// <p>The short name or full Amazon Resource Name (ARN) of the cluster on which to run your task. If you do not specify a cluster, the default cluster is assumed.</p>
+    #[serde(rename = "cluster")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cluster: Option<String>,
+    /// <p>The number of instantiations of the specified task to place on your cluster. You can specify up to 10 tasks per call.</p>
+    #[serde(rename = "count")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<i64>,
+    /// <p>Specifies whether to enable Amazon ECS managed tags for the task. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/ecs-using-tags.html">Tagging Your Amazon ECS Resources</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
+    #[serde(rename = "enableECSManagedTags")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_ecs_managed_tags: Option<bool>,
+    /// <p>The name of the task group to associate with the task. The default value is the family name of the task definition (for example, family:my-family-name).</p>
+    #[serde(rename = "group")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
+    /// <p>The launch type on which to run your task. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/launch_types.html">Amazon ECS Launch Types</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <p>If a <code>launchType</code> is specified, the <code>capacityProviderStrategy</code> parameter must be omitted.</p>
+    #[serde(rename = "launchType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub launch_type: Option<LaunchType>,
+    /// <p>The network configuration for the task. This parameter is required for task definitions that use the <code>awsvpc</code> network mode to receive their own elastic network interface, and it is not supported for other network modes. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/task-networking.html">Task Networking</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
+    #[serde(rename = "networkConfiguration")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network_configuration: Option<NetworkConfiguration>,
+    /// <p><p>A list of container overrides in JSON format that specify the name of a container in the specified task definition and the overrides it should receive. You can override the default command for a container (that is specified in the task definition or Docker image) with a <code>command</code> override. You can also override existing environment variables (that are specified in the task definition or Docker image) on a container or add new environment variables to it with an <code>environment</code> override.</p> <note> <p>A total of 8192 characters are allowed for overrides. This limit includes the JSON formatting characters of the override structure.</p> </note></p>
+    #[serde(rename = "overrides")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub overrides: Option<TaskOverride>,
+    /// <p>An array of placement constraint objects to use for the task. You can specify up to 10 constraints per task (including constraints in the task definition and those specified at runtime).</p>
+    #[serde(rename = "placementConstraints")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placement_constraints: Option<Vec<PlacementConstraint>>,
+    /// <p>The placement strategy objects to use for the task. You can specify a maximum of five strategy rules per task.</p>
+    #[serde(rename = "placementStrategy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placement_strategy: Option<Vec<PlacementStrategy>>,
+    /// <p>The platform version the task should run. A platform version is only specified for tasks using the Fargate launch type. If one is not specified, the <code>LATEST</code> platform version is used by default. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/platform_versions.html">AWS Fargate Platform Versions</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
+    #[serde(rename = "platformVersion")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub platform_version: Option<String>,
+    /// <p><p>Specifies whether to propagate the tags from the task definition to the task. If no value is specified, the tags are not propagated. Tags can only be propagated to the task during task creation. To add tags to a task after task creation, use the <a>TagResource</a> API action.</p> <note> <p>An error will be received if you specify the <code>SERVICE</code> option when running a task.</p> </note></p>
+    #[serde(rename = "propagateTags")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub propagate_tags: Option<PropagateTags>,
+    /// <p>The reference ID to use for the task.</p>
+    #[serde(rename = "referenceId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference_id: Option<String>,
+    /// <p>An optional tag specified when a task is started. For example, if you automatically trigger a task to run a batch process job, you could apply a unique identifier for that job to your task with the <code>startedBy</code> parameter. You can then identify which tasks belong to that job by filtering the results of a <a>ListTasks</a> call with the <code>startedBy</code> value. Up to 36 letters (uppercase and lowercase), numbers, hyphens, and underscores are allowed.</p> <p>If a task is started by an Amazon ECS service, then the <code>startedBy</code> parameter contains the deployment ID of the service that starts it.</p>
+    #[serde(rename = "startedBy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub started_by: Option<String>,
+    /// <p><p>The metadata that you apply to the task to help you categorize and organize them. Each tag consists of a key and an optional value, both of which you define.</p> <p>The following basic restrictions apply to tags:</p> <ul> <li> <p>Maximum number of tags per resource - 50</p> </li> <li> <p>For each resource, each tag key must be unique, and each tag key can have only one value.</p> </li> <li> <p>Maximum key length - 128 Unicode characters in UTF-8</p> </li> <li> <p>Maximum value length - 256 Unicode characters in UTF-8</p> </li> <li> <p>If your tagging schema is used across multiple services and resources, remember that other services may have restrictions on allowed characters. Generally allowed characters are: letters, numbers, and spaces representable in UTF-8, and the following characters: + - = . _ : / @.</p> </li> <li> <p>Tag keys and values are case-sensitive.</p> </li> <li> <p>Do not use <code>aws:</code>, <code>AWS:</code>, or any upper or lowercase combination of such as a prefix for either keys or values as it is reserved for AWS use. You cannot edit or delete tag keys or values with this prefix. Tags with this prefix do not count against your tags per resource limit.</p> </li> </ul></p>
+    #[serde(rename = "tags")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<Tag>>,
+    /// <p>The <code>family</code> and <code>revision</code> (<code>family:revision</code>) or full ARN of the task definition to run. If a <code>revision</code> is not specified, the latest <code>ACTIVE</code> revision is used.</p>
+    #[serde(rename = "taskDefinition")]
+    pub task_definition: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct RunTaskResponse {
+    /// <p>Any failures associated with the call.</p>
+    #[serde(rename = "failures")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub failures: Option<Vec<Failure>>,
+    /// <p>A full description of the tasks that were run. The tasks that were successfully placed on your cluster are described here.</p>
+    #[serde(rename = "tasks")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tasks: Option<Vec<Task>>,
+}
+
+/// <p>A floating-point percentage of the desired number of tasks to place and keep running in the task set.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Scale {
+    /// <p>The unit of measure for the scale value.</p>
+    #[serde(rename = "unit")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit: Option<ScaleUnit>,
+    /// <p>The value, specified as a percent total of a service's <code>desiredCount</code>, to scale the task set. Accepted values are numbers between 0 and 100.</p>
+    #[serde(rename = "value")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<f64>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ScaleUnit {
+    /// `PERCENT`
+    Percent,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ScaleUnit {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ScaleUnit::Percent => "PERCENT",
+            ScaleUnit::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ScaleUnit {
+    fn default() -> ScaleUnit {
+        ScaleUnit::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ScaleUnit {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ScaleUnit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ScaleUnit {
+    fn from(value: &'a str) -> ScaleUnit {
+        match value {
+            "PERCENT" => ScaleUnit::Percent,
+            _ => ScaleUnit::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ScaleUnit {
+    fn from(value: String) -> ScaleUnit {
+        match value.as_str() {
+            "PERCENT" => ScaleUnit::Percent,
+            _ => ScaleUnit::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ScaleUnit {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ScaleUnit, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ScaleUnit {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ScaleUnit {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ScaleUnit {
+    fn deserialize<D>(deserializer: D) -> Result<ScaleUnit, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SchedulingStrategy {
+    /// `REPLICA`
+    Replica,
+    /// `DAEMON`
+    Daemon,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SchedulingStrategy {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SchedulingStrategy::Replica => "REPLICA",
+            SchedulingStrategy::Daemon => "DAEMON",
+            SchedulingStrategy::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SchedulingStrategy {
+    fn default() -> SchedulingStrategy {
+        SchedulingStrategy::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SchedulingStrategy {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SchedulingStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SchedulingStrategy {
+    fn from(value: &'a str) -> SchedulingStrategy {
+        match value {
+            "REPLICA" => SchedulingStrategy::Replica,
+            "DAEMON" => SchedulingStrategy::Daemon,
+            _ => SchedulingStrategy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SchedulingStrategy {
+    fn from(value: String) -> SchedulingStrategy {
+        match value.as_str() {
+            "REPLICA" => SchedulingStrategy::Replica,
+            "DAEMON" => SchedulingStrategy::Daemon,
+            _ => SchedulingStrategy::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SchedulingStrategy {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SchedulingStrategy, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SchedulingStrategy {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SchedulingStrategy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SchedulingStrategy {
+    fn deserialize<D>(deserializer: D) -> Result<SchedulingStrategy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Scope {
+    /// `task`
+    Task,
+    /// `shared`
+    Shared,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Scope {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Scope::Task => "task",
+            Scope::Shared => "shared",
+            Scope::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Scope {
+    fn default() -> Scope {
+        Scope::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Scope {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Scope {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Scope {
+    fn from(value: &'a str) -> Scope {
+        match value {
+            "task" => Scope::Task,
+            "shared" => Scope::Shared,
+            _ => Scope::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Scope {
+    fn from(value: String) -> Scope {
+        match value.as_str() {
+            "task" => Scope::Task,
+            "shared" => Scope::Shared,
+            _ => Scope::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Scope {
+    type Err = ::std::convert::Infallible;
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct RunTaskRequest {
-    /// <p>The capacity provider strategy to use for the task.</p> <p>A capacity provider strategy consists of one or more capacity providers along with the <code>base</code> and <code>weight</code> to assign to them. A capacity provider must be associated with the cluster to be used in a capacity provider strategy. The <a>PutClusterCapacityProviders</a> API is used to associate a capacity provider with a cluster. Only capacity providers with an <code>ACTIVE</code> or <code>UPDATING</code> status can be used.</p> <p>If a <code>capacityProviderStrategy</code> is specified, the <code>launchType</code> parameter must be omitted. If no <code>capacityProviderStrategy</code> or <code>launchType</code> is specified, the <code>defaultCapacityProviderStrategy</code> for the cluster is used.</p> <p>If specifying a capacity provider that uses an Auto Scaling group, the capacity provider must already be created. New capacity providers can be created with the <a>CreateCapacityProvider</a> API operation.</p> <p>To use a AWS Fargate capacity provider, specify either the <code>FARGATE</code> or <code>FARGATE_SPOT</code> capacity providers. The AWS Fargate capacity providers are available to all accounts and only need to be associated with a cluster to be used.</p> <p>The <a>PutClusterCapacityProviders</a> API operation is used to update the list of available capacity providers for a cluster after the cluster is created.</p>
-    #[serde(rename = "capacityProviderStrategy")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub capacity_provider_strategy: Option<Vec<CapacityProviderStrategyItem>>,
-    /// <p>The short name or full Amazon Resource Name (ARN) of the cluster on which to run your task. If you do not specify a cluster, the default cluster is assumed.</p>
-    #[serde(rename = "cluster")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub cluster: Option<String>,
-    /// <p>The number of instantiations of the specified task to place on your cluster. You can specify up to 10 tasks per call.</p>
-    #[serde(rename = "count")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub count: Option<i64>,
-    /// <p>Specifies whether to enable Amazon ECS managed tags for the task. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/ecs-using-tags.html">Tagging Your Amazon ECS Resources</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
-    #[serde(rename = "enableECSManagedTags")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub enable_ecs_managed_tags: Option<bool>,
-    /// <p>The name of the task group to associate with the task. The default value is the family name of the task definition (for example, family:my-family-name).</p>
-    #[serde(rename = "group")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub group: Option<String>,
-    /// <p>The launch type on which to run your task. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/launch_types.html">Amazon ECS Launch Types</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <p>If a <code>launchType</code> is specified, the <code>capacityProviderStrategy</code> parameter must be omitted.</p>
-    #[serde(rename = "launchType")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub launch_type: Option<String>,
-    /// <p>The network configuration for the task. This parameter is required for task definitions that use the <code>awsvpc</code> network mode to receive their own elastic network interface, and it is not supported for other network modes. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/task-networking.html">Task Networking</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
-    #[serde(rename = "networkConfiguration")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub network_configuration: Option<NetworkConfiguration>,
-    /// <p><p>A list of container overrides in JSON format that specify the name of a container in the specified task definition and the overrides it should receive. You can override the default command for a container (that is specified in the task definition or Docker image) with a <code>command</code> override. You can also override existing environment variables (that are specified in the task definition or Docker image) on a container or add new environment variables to it with an <code>environment</code> override.</p> <note> <p>A total of 8192 characters are allowed for overrides. This limit includes the JSON formatting characters of the override structure.</p> </note></p>
-    #[serde(rename = "overrides")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub overrides: Option<TaskOverride>,
-    /// <p>An array of placement constraint objects to use for the task. You can specify up to 10 constraints per task (including constraints in the task definition and those specified at runtime).</p>
-    #[serde(rename = "placementConstraints")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub placement_constraints: Option<Vec<PlacementConstraint>>,
-    /// <p>The placement strategy objects to use for the task. You can specify a maximum of five strategy rules per task.</p>
-    #[serde(rename = "placementStrategy")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub placement_strategy: Option<Vec<PlacementStrategy>>,
-    /// <p>The platform version the task should run. A platform version is only specified for tasks using the Fargate launch type. If one is not specified, the <code>LATEST</code> platform version is used by default. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/platform_versions.html">AWS Fargate Platform Versions</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
-    #[serde(rename = "platformVersion")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub platform_version: Option<String>,
-    /// <p><p>Specifies whether to propagate the tags from the task definition to the task. If no value is specified, the tags are not propagated. Tags can only be propagated to the task during task creation. To add tags to a task after task creation, use the <a>TagResource</a> API action.</p> <note> <p>An error will be received if you specify the <code>SERVICE</code> option when running a task.</p> </note></p>
-    #[serde(rename = "propagateTags")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub propagate_tags: Option<String>,
-    /// <p>The reference ID to use for the task.</p>
-    #[serde(rename = "referenceId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub reference_id: Option<String>,
-    /// <p>An optional tag specified when a task is started. For example, if you automatically trigger a task to run a batch process job, you could apply a unique identifier for that job to your task with the <code>startedBy</code> parameter. You can then identify which tasks belong to that job by filtering the results of a <a>ListTasks</a> call with the <code>startedBy</code> value. Up to 36 letters (uppercase and lowercase), numbers, hyphens, and underscores are allowed.</p> <p>If a task is started by an Amazon ECS service, then the <code>startedBy</code> parameter contains the deployment ID of the service that starts it.</p>
-    #[serde(rename = "startedBy")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub started_by: Option<String>,
-    /// <p><p>The metadata that you apply to the task to help you categorize and organize them. Each tag consists of a key and an optional value, both of which you define.</p> <p>The following basic restrictions apply to tags:</p> <ul> <li> <p>Maximum number of tags per resource - 50</p> </li> <li> <p>For each resource, each tag key must be unique, and each tag key can have only one value.</p> </li> <li> <p>Maximum key length - 128 Unicode characters in UTF-8</p> </li> <li> <p>Maximum value length - 256 Unicode characters in UTF-8</p> </li> <li> <p>If your tagging schema is used across multiple services and resources, remember that other services may have restrictions on allowed characters. Generally allowed characters are: letters, numbers, and spaces representable in UTF-8, and the following characters: + - = . _ : / @.</p> </li> <li> <p>Tag keys and values are case-sensitive.</p> </li> <li> <p>Do not use <code>aws:</code>, <code>AWS:</code>, or any upper or lowercase combination of such as a prefix for either keys or values as it is reserved for AWS use. You cannot edit or delete tag keys or values with this prefix. Tags with this prefix do not count against your tags per resource limit.</p> </li> </ul></p>
-    #[serde(rename = "tags")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub tags: Option<Vec<Tag>>,
-    /// <p>The <code>family</code> and <code>revision</code> (<code>family:revision</code>) or full ARN of the task definition to run. If a <code>revision</code> is not specified, the latest <code>ACTIVE</code> revision is used.</p>
-    #[serde(rename = "taskDefinition")]
-    pub task_definition: String,
+    fn from_str(value: &str) -> Result<Scope, Self::Err> {
+        Ok(value.into())
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct RunTaskResponse {
-    /// <p>Any failures associated with the call.</p>
-    #[serde(rename = "failures")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub failures: Option<Vec<Failure>>,
-    /// <p>A full description of the tasks that were run. The tasks that were successfully placed on your cluster are described here.</p>
-    #[serde(rename = "tasks")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub tasks: Option<Vec<Task>>,
+impl ::rusoto_core::param::ToParam for Scope {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
 }
 
-/// <p>A floating-point percentage of the desired number of tasks to place and keep running in the task set.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct Scale {
-    /// <p>The unit of measure for the scale value.</p>
-    #[serde(rename = "unit")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub unit: Option<String>,
-    /// <p>The value, specified as a percent total of a service's <code>desiredCount</code>, to scale the task set. Accepted values are numbers between 0 and 100.</p>
-    #[serde(rename = "value")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub value: Option<f64>,
+impl ::serde::Serialize for Scope {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for Scope {
+    fn deserialize<D>(deserializer: D) -> Result<Scope, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An object representing the secret to expose to your container. Secrets can be exposed to a container in the following ways:</p> <ul> <li> <p>To inject sensitive data into your containers as environment variables, use the <code>secrets</code> container definition parameter.</p> </li> <li> <p>To reference sensitive information in the log configuration of a container, use the <code>secretOptions</code> container definition parameter.</p> </li> </ul> <p>For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/specifying-sensitive-data.html">Specifying Sensitive Data</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Secret {
@@ -2392,8 +5503,13 @@ pub struct Service {
     pub cluster_arn: Option<String>,
     /// <p>The Unix timestamp for when the service was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The principal that created the service.</p>
     #[serde(rename = "createdBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2429,7 +5545,7 @@ pub struct Service {
     /// <p>The launch type on which your service is running. If no value is specified, it will default to <code>EC2</code>. Valid values include <code>EC2</code> and <code>FARGATE</code>. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/launch_types.html">Amazon ECS Launch Types</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
     #[serde(rename = "launchType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub launch_type: Option<String>,
+    pub launch_type: Option<LaunchType>,
     /// <p>A list of Elastic Load Balancing load balancer objects, containing the load balancer name, the container name (as it appears in a container definition), and the container port to access from the load balancer.</p>
     #[serde(rename = "loadBalancers")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2457,7 +5573,7 @@ pub struct Service {
     /// <p>Specifies whether to propagate the tags from the task definition or the service to the task. If no value is specified, the tags are not propagated.</p>
     #[serde(rename = "propagateTags")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub propagate_tags: Option<String>,
+    pub propagate_tags: Option<PropagateTags>,
     /// <p>The ARN of the IAM role associated with the service that allows the Amazon ECS container agent to register container instances with an Elastic Load Balancing load balancer.</p>
     #[serde(rename = "roleArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2469,7 +5585,7 @@ pub struct Service {
     /// <p><p>The scheduling strategy to use for the service. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/ecs_services.html">Services</a>.</p> <p>There are two service scheduler strategies available:</p> <ul> <li> <p> <code>REPLICA</code>-The replica scheduling strategy places and maintains the desired number of tasks across your cluster. By default, the service scheduler spreads tasks across Availability Zones. You can use task placement strategies and constraints to customize task placement decisions.</p> </li> <li> <p> <code>DAEMON</code>-The daemon scheduling strategy deploys exactly one task on each container instance in your cluster. When you are using this strategy, do not specify a desired number of tasks or any task placement strategies.</p> <note> <p>Fargate tasks do not support the <code>DAEMON</code> scheduling strategy.</p> </note> </li> </ul></p>
     #[serde(rename = "schedulingStrategy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scheduling_strategy: Option<String>,
+    pub scheduling_strategy: Option<SchedulingStrategy>,
     /// <p>The ARN that identifies the service. The ARN contains the <code>arn:aws:ecs</code> namespace, followed by the Region of the service, the AWS account ID of the service owner, the <code>service</code> namespace, and then the service name. For example, <code>arn:aws:ecs:region:012345678910:service/my-service</code>.</p>
     #[serde(rename = "serviceArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2506,8 +5622,13 @@ pub struct Service {
 pub struct ServiceEvent {
     /// <p>The Unix timestamp for when the event was triggered.</p>
     #[serde(rename = "createdAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The ID string of the event.</p>
     #[serde(rename = "id")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2518,6 +5639,94 @@ pub struct ServiceEvent {
     pub message: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ServiceField {
+    /// `TAGS`
+    Tags,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ServiceField {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ServiceField::Tags => "TAGS",
+            ServiceField::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ServiceField {
+    fn default() -> ServiceField {
+        ServiceField::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ServiceField {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ServiceField {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ServiceField {
+    fn from(value: &'a str) -> ServiceField {
+        match value {
+            "TAGS" => ServiceField::Tags,
+            _ => ServiceField::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ServiceField {
+    fn from(value: String) -> ServiceField {
+        match value.as_str() {
+            "TAGS" => ServiceField::Tags,
+            _ => ServiceField::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ServiceField {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ServiceField, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ServiceField {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ServiceField {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ServiceField {
+    fn deserialize<D>(deserializer: D) -> Result<ServiceField, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Details of the service registry.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ServiceRegistry {
@@ -2546,7 +5755,7 @@ pub struct Setting {
     /// <p>The Amazon ECS resource name.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
+    pub name: Option<SettingName>,
     /// <p>The ARN of the principal, which can be an IAM user, IAM role, or the root user. If this field is omitted, the authenticated user is assumed.</p>
     #[serde(rename = "principalArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2557,6 +5766,320 @@ pub struct Setting {
     pub value: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SettingName {
+    /// `serviceLongArnFormat`
+    ServiceLongArnFormat,
+    /// `taskLongArnFormat`
+    TaskLongArnFormat,
+    /// `containerInstanceLongArnFormat`
+    ContainerInstanceLongArnFormat,
+    /// `awsvpcTrunking`
+    AwsvpcTrunking,
+    /// `containerInsights`
+    ContainerInsights,
+    /// `fargateFIPSMode`
+    FargateFIPSMode,
+    /// `tagResourceAuthorization`
+    TagResourceAuthorization,
+    /// `fargateTaskRetirementWaitPeriod`
+    FargateTaskRetirementWaitPeriod,
+    /// `guardDutyActivate`
+    GuardDutyActivate,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SettingName {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SettingName::ServiceLongArnFormat => "serviceLongArnFormat",
+            SettingName::TaskLongArnFormat => "taskLongArnFormat",
+            SettingName::ContainerInstanceLongArnFormat => "containerInstanceLongArnFormat",
+            SettingName::AwsvpcTrunking => "awsvpcTrunking",
+            SettingName::ContainerInsights => "containerInsights",
+            SettingName::FargateFIPSMode => "fargateFIPSMode",
+            SettingName::TagResourceAuthorization => "tagResourceAuthorization",
+            SettingName::FargateTaskRetirementWaitPeriod => "fargateTaskRetirementWaitPeriod",
+            SettingName::GuardDutyActivate => "guardDutyActivate",
+            SettingName::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SettingName {
+    fn default() -> SettingName {
+        SettingName::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SettingName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SettingName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SettingName {
+    fn from(value: &'a str) -> SettingName {
+        match value {
+            "serviceLongArnFormat" => SettingName::ServiceLongArnFormat,
+            "taskLongArnFormat" => SettingName::TaskLongArnFormat,
+            "containerInstanceLongArnFormat" => SettingName::ContainerInstanceLongArnFormat,
+            "awsvpcTrunking" => SettingName::AwsvpcTrunking,
+            "containerInsights" => SettingName::ContainerInsights,
+            "fargateFIPSMode" => SettingName::FargateFIPSMode,
+            "tagResourceAuthorization" => SettingName::TagResourceAuthorization,
+            "fargateTaskRetirementWaitPeriod" => SettingName::FargateTaskRetirementWaitPeriod,
+            "guardDutyActivate" => SettingName::GuardDutyActivate,
+            _ => SettingName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SettingName {
+    fn from(value: String) -> SettingName {
+        match value.as_str() {
+            "serviceLongArnFormat" => SettingName::ServiceLongArnFormat,
+            "taskLongArnFormat" => SettingName::TaskLongArnFormat,
+            "containerInstanceLongArnFormat" => SettingName::ContainerInstanceLongArnFormat,
+            "awsvpcTrunking" => SettingName::AwsvpcTrunking,
+            "containerInsights" => SettingName::ContainerInsights,
+            "fargateFIPSMode" => SettingName::FargateFIPSMode,
+            "tagResourceAuthorization" => SettingName::TagResourceAuthorization,
+            "fargateTaskRetirementWaitPeriod" => SettingName::FargateTaskRetirementWaitPeriod,
+            "guardDutyActivate" => SettingName::GuardDutyActivate,
+            _ => SettingName::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SettingName {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SettingName, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SettingName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SettingName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SettingName {
+    fn deserialize<D>(deserializer: D) -> Result<SettingName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SortOrder {
+    /// `ASC`
+    Asc,
+    /// `DESC`
+    Desc,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SortOrder {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SortOrder::Asc => "ASC",
+            SortOrder::Desc => "DESC",
+            SortOrder::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SortOrder {
+    fn default() -> SortOrder {
+        SortOrder::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SortOrder {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SortOrder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SortOrder {
+    fn from(value: &'a str) -> SortOrder {
+        match value {
+            "ASC" => SortOrder::Asc,
+            "DESC" => SortOrder::Desc,
+            _ => SortOrder::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SortOrder {
+    fn from(value: String) -> SortOrder {
+        match value.as_str() {
+            "ASC" => SortOrder::Asc,
+            "DESC" => SortOrder::Desc,
+            _ => SortOrder::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SortOrder {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SortOrder, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SortOrder {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SortOrder {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SortOrder {
+    fn deserialize<D>(deserializer: D) -> Result<SortOrder, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StabilityStatus {
+    /// `STEADY_STATE`
+    SteadyState,
+    /// `STABILIZING`
+    Stabilizing,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl StabilityStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StabilityStatus::SteadyState => "STEADY_STATE",
+            StabilityStatus::Stabilizing => "STABILIZING",
+            StabilityStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for StabilityStatus {
+    fn default() -> StabilityStatus {
+        StabilityStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for StabilityStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for StabilityStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for StabilityStatus {
+    fn from(value: &'a str) -> StabilityStatus {
+        match value {
+            "STEADY_STATE" => StabilityStatus::SteadyState,
+            "STABILIZING" => StabilityStatus::Stabilizing,
+            _ => StabilityStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StabilityStatus {
+    fn from(value: String) -> StabilityStatus {
+        match value.as_str() {
+            "STEADY_STATE" => StabilityStatus::SteadyState,
+            "STABILIZING" => StabilityStatus::Stabilizing,
+            _ => StabilityStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for StabilityStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<StabilityStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StabilityStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for StabilityStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StabilityStatus {
+    fn deserialize<D>(deserializer: D) -> Result<StabilityStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct StartTaskRequest {
@@ -2586,7 +6109,7 @@ pub struct StartTaskRequest {
     /// <p>Specifies whether to propagate the tags from the task definition or the service to the task. If no value is specified, the tags are not propagated.</p>
     #[serde(rename = "propagateTags")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub propagate_tags: Option<String>,
+    pub propagate_tags: Option<PropagateTags>,
     /// <p>The reference ID to use for the task.</p>
     #[serde(rename = "referenceId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2726,16 +6249,31 @@ pub struct SubmitTaskStateChangeRequest {
     pub containers: Option<Vec<ContainerStateChange>>,
     /// <p>The Unix timestamp for when the task execution stopped.</p>
     #[serde(rename = "executionStoppedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub execution_stopped_at: Option<f64>,
+    pub execution_stopped_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The Unix timestamp for when the container image pull began.</p>
     #[serde(rename = "pullStartedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub pull_started_at: Option<f64>,
+    pub pull_started_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The Unix timestamp for when the container image pull completed.</p>
     #[serde(rename = "pullStoppedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub pull_stopped_at: Option<f64>,
+    pub pull_stopped_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The reason for the state change request.</p>
     #[serde(rename = "reason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2800,6 +6338,94 @@ pub struct TagResourceRequest {
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TagResourceResponse {}
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TargetType {
+    /// `container-instance`
+    ContainerInstance,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TargetType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TargetType::ContainerInstance => "container-instance",
+            TargetType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TargetType {
+    fn default() -> TargetType {
+        TargetType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TargetType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TargetType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TargetType {
+    fn from(value: &'a str) -> TargetType {
+        match value {
+            "container-instance" => TargetType::ContainerInstance,
+            _ => TargetType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TargetType {
+    fn from(value: String) -> TargetType {
+        match value.as_str() {
+            "container-instance" => TargetType::ContainerInstance,
+            _ => TargetType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TargetType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TargetType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TargetType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TargetType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TargetType {
+    fn deserialize<D>(deserializer: D) -> Result<TargetType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Details on a task in a cluster.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -2827,11 +6453,16 @@ pub struct Task {
     /// <p>The connectivity status of a task.</p>
     #[serde(rename = "connectivity")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub connectivity: Option<String>,
+    pub connectivity: Option<Connectivity>,
     /// <p>The Unix timestamp for when the task last went into <code>CONNECTED</code> status.</p>
     #[serde(rename = "connectivityAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub connectivity_at: Option<f64>,
+    pub connectivity_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The ARN of the container instances that host the task.</p>
     #[serde(rename = "containerInstanceArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2846,16 +6477,26 @@ pub struct Task {
     pub cpu: Option<String>,
     /// <p>The Unix timestamp for when the task was created (the task entered the <code>PENDING</code> state).</p>
     #[serde(rename = "createdAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The desired status of the task. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/task-lifecycle.html">Task Lifecycle</a>.</p>
     #[serde(rename = "desiredStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub desired_status: Option<String>,
     /// <p>The Unix timestamp for when the task execution stopped.</p>
     #[serde(rename = "executionStoppedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub execution_stopped_at: Option<f64>,
+    pub execution_stopped_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the task group associated with the task.</p>
     #[serde(rename = "group")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2863,7 +6504,7 @@ pub struct Task {
     /// <p><p>The health status for the task, which is determined by the health of the essential containers in the task. If all essential containers in the task are reporting as <code>HEALTHY</code>, then the task status also reports as <code>HEALTHY</code>. If any essential containers in the task are reporting as <code>UNHEALTHY</code> or <code>UNKNOWN</code>, then the task status also reports as <code>UNHEALTHY</code> or <code>UNKNOWN</code>, accordingly.</p> <note> <p>The Amazon ECS container agent does not monitor or report on Docker health checks that are embedded in a container image (such as those specified in a parent image or from the image&#39;s Dockerfile) and not specified in the container definition. Health check parameters that are specified in a container definition override any Docker health checks that exist in the container image.</p> </note></p>
     #[serde(rename = "healthStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub health_status: Option<String>,
+    pub health_status: Option<HealthStatus>,
     /// <p>The Elastic Inference accelerator associated with the task.</p>
     #[serde(rename = "inferenceAccelerators")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2875,7 +6516,7 @@ pub struct Task {
     /// <p>The launch type on which your task is running. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/launch_types.html">Amazon ECS Launch Types</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
     #[serde(rename = "launchType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub launch_type: Option<String>,
+    pub launch_type: Option<LaunchType>,
     /// <p><p>The amount of memory (in MiB) used by the task as expressed in a task definition. It can be expressed as an integer using MiB, for example <code>1024</code>. It can also be expressed as a string using GB, for example <code>1GB</code> or <code>1 GB</code>. String values are converted to an integer indicating the MiB when the task definition is registered.</p> <p>If you are using the EC2 launch type, this field is optional.</p> <p>If you are using the Fargate launch type, this field is required and you must use one of the following values, which determines your range of supported values for the <code>cpu</code> parameter:</p> <ul> <li> <p>512 (0.5 GB), 1024 (1 GB), 2048 (2 GB) - Available <code>cpu</code> values: 256 (.25 vCPU)</p> </li> <li> <p>1024 (1 GB), 2048 (2 GB), 3072 (3 GB), 4096 (4 GB) - Available <code>cpu</code> values: 512 (.5 vCPU)</p> </li> <li> <p>2048 (2 GB), 3072 (3 GB), 4096 (4 GB), 5120 (5 GB), 6144 (6 GB), 7168 (7 GB), 8192 (8 GB) - Available <code>cpu</code> values: 1024 (1 vCPU)</p> </li> <li> <p>Between 4096 (4 GB) and 16384 (16 GB) in increments of 1024 (1 GB) - Available <code>cpu</code> values: 2048 (2 vCPU)</p> </li> <li> <p>Between 8192 (8 GB) and 30720 (30 GB) in increments of 1024 (1 GB) - Available <code>cpu</code> values: 4096 (4 vCPU)</p> </li> </ul></p>
     #[serde(rename = "memory")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2890,16 +6531,31 @@ pub struct Task {
     pub platform_version: Option<String>,
     /// <p>The Unix timestamp for when the container image pull began.</p>
     #[serde(rename = "pullStartedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub pull_started_at: Option<f64>,
+    pub pull_started_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The Unix timestamp for when the container image pull completed.</p>
     #[serde(rename = "pullStoppedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub pull_stopped_at: Option<f64>,
+    pub pull_stopped_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The Unix timestamp for when the task started (the task transitioned from the <code>PENDING</code> state to the <code>RUNNING</code> state).</p>
     #[serde(rename = "startedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub started_at: Option<f64>,
+    pub started_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The tag specified when a task is started. If the task is started by an Amazon ECS service, then the <code>startedBy</code> parameter contains the deployment ID of the service that starts it.</p>
     #[serde(rename = "startedBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2907,19 +6563,29 @@ pub struct Task {
     /// <p>The stop code indicating why a task was stopped. The <code>stoppedReason</code> may contain additional details.</p>
     #[serde(rename = "stopCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stop_code: Option<String>,
+    pub stop_code: Option<TaskStopCode>,
     /// <p>The Unix timestamp for when the task was stopped (the task transitioned from the <code>RUNNING</code> state to the <code>STOPPED</code> state).</p>
     #[serde(rename = "stoppedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stopped_at: Option<f64>,
+    pub stopped_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The reason that the task was stopped.</p>
     #[serde(rename = "stoppedReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub stopped_reason: Option<String>,
     /// <p>The Unix timestamp for when the task stops (transitions from the <code>RUNNING</code> state to <code>STOPPED</code>).</p>
     #[serde(rename = "stoppingAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stopping_at: Option<f64>,
+    pub stopping_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>The metadata that you apply to the task to help you categorize and organize them. Each tag consists of a key and an optional value, both of which you define.</p> <p>The following basic restrictions apply to tags:</p> <ul> <li> <p>Maximum number of tags per resource - 50</p> </li> <li> <p>For each resource, each tag key must be unique, and each tag key can have only one value.</p> </li> <li> <p>Maximum key length - 128 Unicode characters in UTF-8</p> </li> <li> <p>Maximum value length - 256 Unicode characters in UTF-8</p> </li> <li> <p>If your tagging schema is used across multiple services and resources, remember that other services may have restrictions on allowed characters. Generally allowed characters are: letters, numbers, and spaces representable in UTF-8, and the following characters: + - = . _ : / @.</p> </li> <li> <p>Tag keys and values are case-sensitive.</p> </li> <li> <p>Do not use <code>aws:</code>, <code>AWS:</code>, or any upper or lowercase combination of such as a prefix for either keys or values as it is reserved for AWS use. You cannot edit or delete tag keys or values with this prefix. Tags with this prefix do not count against your tags per resource limit.</p> </li> </ul></p>
     #[serde(rename = "tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2945,7 +6611,7 @@ pub struct TaskDefinition {
     /// <p>The launch type to use with your task. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/launch_types.html">Amazon ECS Launch Types</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
     #[serde(rename = "compatibilities")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compatibilities: Option<Vec<String>>,
+    pub compatibilities: Option<Vec<Compatibility>>,
     /// <p>A list of container definitions in JSON format that describe the different containers that make up your task. For more information about container definition parameters and defaults, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/task_defintions.html">Amazon ECS Task Definitions</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
     #[serde(rename = "containerDefinitions")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2969,7 +6635,7 @@ pub struct TaskDefinition {
     /// <p><p>The IPC resource namespace to use for the containers in the task. The valid values are <code>host</code>, <code>task</code>, or <code>none</code>. If <code>host</code> is specified, then all containers within the tasks that specified the <code>host</code> IPC mode on the same container instance share the same IPC resources with the host Amazon EC2 instance. If <code>task</code> is specified, all containers within the specified task share the same IPC resources. If <code>none</code> is specified, then IPC resources within the containers of a task are private and not shared with other containers in a task or on the container instance. If no value is specified, then the IPC resource namespace sharing depends on the Docker daemon setting on the container instance. For more information, see <a href="https://docs.docker.com/engine/reference/run/#ipc-settings---ipc">IPC settings</a> in the <i>Docker run reference</i>.</p> <p>If the <code>host</code> IPC mode is used, be aware that there is a heightened risk of undesired IPC namespace expose. For more information, see <a href="https://docs.docker.com/engine/security/security/">Docker security</a>.</p> <p>If you are setting namespaced kernel parameters using <code>systemControls</code> for the containers in the task, the following will apply to your IPC resource namespace. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/task_definition_parameters.html">System Controls</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <ul> <li> <p>For tasks that use the <code>host</code> IPC mode, IPC namespace related <code>systemControls</code> are not supported.</p> </li> <li> <p>For tasks that use the <code>task</code> IPC mode, IPC namespace related <code>systemControls</code> will apply to all containers within a task.</p> </li> </ul> <note> <p>This parameter is not supported for Windows containers or tasks using the Fargate launch type.</p> </note></p>
     #[serde(rename = "ipcMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ipc_mode: Option<String>,
+    pub ipc_mode: Option<IpcMode>,
     /// <p><p>The amount (in MiB) of memory used by the task.</p> <p>If using the EC2 launch type, this field is optional and any value can be used. If a task-level memory value is specified then the container-level memory value is optional.</p> <p>If using the Fargate launch type, this field is required and you must use one of the following values, which determines your range of valid values for the <code>cpu</code> parameter:</p> <ul> <li> <p>512 (0.5 GB), 1024 (1 GB), 2048 (2 GB) - Available <code>cpu</code> values: 256 (.25 vCPU)</p> </li> <li> <p>1024 (1 GB), 2048 (2 GB), 3072 (3 GB), 4096 (4 GB) - Available <code>cpu</code> values: 512 (.5 vCPU)</p> </li> <li> <p>2048 (2 GB), 3072 (3 GB), 4096 (4 GB), 5120 (5 GB), 6144 (6 GB), 7168 (7 GB), 8192 (8 GB) - Available <code>cpu</code> values: 1024 (1 vCPU)</p> </li> <li> <p>Between 4096 (4 GB) and 16384 (16 GB) in increments of 1024 (1 GB) - Available <code>cpu</code> values: 2048 (2 vCPU)</p> </li> <li> <p>Between 8192 (8 GB) and 30720 (30 GB) in increments of 1024 (1 GB) - Available <code>cpu</code> values: 4096 (4 vCPU)</p> </li> </ul></p>
     #[serde(rename = "memory")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2977,11 +6643,11 @@ pub struct TaskDefinition {
     /// <p>The Docker networking mode to use for the containers in the task. The valid values are <code>none</code>, <code>bridge</code>, <code>awsvpc</code>, and <code>host</code>. The default Docker network mode is <code>bridge</code>. If you are using the Fargate launch type, the <code>awsvpc</code> network mode is required. If you are using the EC2 launch type, any network mode can be used. If the network mode is set to <code>none</code>, you cannot specify port mappings in your container definitions, and the tasks containers do not have external connectivity. The <code>host</code> and <code>awsvpc</code> network modes offer the highest networking performance for containers because they use the EC2 network stack instead of the virtualized network stack provided by the <code>bridge</code> mode.</p> <p>With the <code>host</code> and <code>awsvpc</code> network modes, exposed container ports are mapped directly to the corresponding host port (for the <code>host</code> network mode) or the attached elastic network interface port (for the <code>awsvpc</code> network mode), so you cannot take advantage of dynamic host port mappings. </p> <p>If the network mode is <code>awsvpc</code>, the task is allocated an elastic network interface, and you must specify a <a>NetworkConfiguration</a> value when you create a service or run a task with the task definition. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/task-networking.html">Task Networking</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <note> <p>Currently, only Amazon ECS-optimized AMIs, other Amazon Linux variants with the <code>ecs-init</code> package, or AWS Fargate infrastructure support the <code>awsvpc</code> network mode. </p> </note> <p>If the network mode is <code>host</code>, you cannot run multiple instantiations of the same task on a single container instance when port mappings are used.</p> <p>Docker for Windows uses different network modes than Docker for Linux. When you register a task definition with Windows containers, you must not specify a network mode. If you use the console to register a task definition with Windows containers, you must choose the <code>&lt;default&gt;</code> network mode object. </p> <p>For more information, see <a href="https://docs.docker.com/engine/reference/run/#network-settings">Network settings</a> in the <i>Docker run reference</i>.</p>
     #[serde(rename = "networkMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub network_mode: Option<String>,
+    pub network_mode: Option<NetworkMode>,
     /// <p><p>The process namespace to use for the containers in the task. The valid values are <code>host</code> or <code>task</code>. If <code>host</code> is specified, then all containers within the tasks that specified the <code>host</code> PID mode on the same container instance share the same process namespace with the host Amazon EC2 instance. If <code>task</code> is specified, all containers within the specified task share the same process namespace. If no value is specified, the default is a private namespace. For more information, see <a href="https://docs.docker.com/engine/reference/run/#pid-settings---pid">PID settings</a> in the <i>Docker run reference</i>.</p> <p>If the <code>host</code> PID mode is used, be aware that there is a heightened risk of undesired process namespace expose. For more information, see <a href="https://docs.docker.com/engine/security/security/">Docker security</a>.</p> <note> <p>This parameter is not supported for Windows containers or tasks using the Fargate launch type.</p> </note></p>
     #[serde(rename = "pidMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub pid_mode: Option<String>,
+    pub pid_mode: Option<PidMode>,
     /// <p>An array of placement constraint objects to use for tasks. This field is not valid if you are using the Fargate launch type for your task.</p>
     #[serde(rename = "placementConstraints")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2997,7 +6663,7 @@ pub struct TaskDefinition {
     /// <p>The launch type the task requires. If no value is specified, it will default to <code>EC2</code>. Valid values include <code>EC2</code> and <code>FARGATE</code>.</p>
     #[serde(rename = "requiresCompatibilities")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub requires_compatibilities: Option<Vec<String>>,
+    pub requires_compatibilities: Option<Vec<Compatibility>>,
     /// <p>The revision of the task in a particular family. The revision is a version number of a task definition in a family. When you register a task definition for the first time, the revision is <code>1</code>. Each time that you register a new revision of a task definition in the same family, the revision value always increases by one, even if you have deregistered previous revisions in this family.</p>
     #[serde(rename = "revision")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3005,7 +6671,7 @@ pub struct TaskDefinition {
     /// <p>The status of the task definition.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<TaskDefinitionStatus>,
     /// <p>The full Amazon Resource Name (ARN) of the task definition.</p>
     #[serde(rename = "taskDefinitionArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3020,19 +6686,479 @@ pub struct TaskDefinition {
     pub volumes: Option<Vec<Volume>>,
 }
 
-/// <p><p>An object representing a constraint on task placement in the task definition. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/task-placement-constraints.html">Task Placement Constraints</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <note> <p>If you are using the Fargate launch type, task placement constraints are not supported.</p> </note></p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct TaskDefinitionPlacementConstraint {
-    /// <p>A cluster query language expression to apply to the constraint. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/cluster-query-language.html">Cluster Query Language</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
-    #[serde(rename = "expression")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub expression: Option<String>,
-    /// <p>The type of constraint. The <code>MemberOf</code> constraint restricts selection to be from a group of valid candidates.</p>
-    #[serde(rename = "type")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TaskDefinitionFamilyStatus {
+    /// `ACTIVE`
+    Active,
+    /// `INACTIVE`
+    Inactive,
+    /// `ALL`
+    All,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TaskDefinitionFamilyStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TaskDefinitionFamilyStatus::Active => "ACTIVE",
+            TaskDefinitionFamilyStatus::Inactive => "INACTIVE",
+            TaskDefinitionFamilyStatus::All => "ALL",
+            TaskDefinitionFamilyStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TaskDefinitionFamilyStatus {
+    fn default() -> TaskDefinitionFamilyStatus {
+        TaskDefinitionFamilyStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TaskDefinitionFamilyStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TaskDefinitionFamilyStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TaskDefinitionFamilyStatus {
+    fn from(value: &'a str) -> TaskDefinitionFamilyStatus {
+        match value {
+            "ACTIVE" => TaskDefinitionFamilyStatus::Active,
+            "INACTIVE" => TaskDefinitionFamilyStatus::Inactive,
+            "ALL" => TaskDefinitionFamilyStatus::All,
+            _ => TaskDefinitionFamilyStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TaskDefinitionFamilyStatus {
+    fn from(value: String) -> TaskDefinitionFamilyStatus {
+        match value.as_str() {
+            "ACTIVE" => TaskDefinitionFamilyStatus::Active,
+            "INACTIVE" => TaskDefinitionFamilyStatus::Inactive,
+            "ALL" => TaskDefinitionFamilyStatus::All,
+            _ => TaskDefinitionFamilyStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TaskDefinitionFamilyStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TaskDefinitionFamilyStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TaskDefinitionFamilyStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TaskDefinitionFamilyStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TaskDefinitionFamilyStatus {
+    fn deserialize<D>(deserializer: D) -> Result<TaskDefinitionFamilyStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TaskDefinitionField {
+    /// `TAGS`
+    Tags,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TaskDefinitionField {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TaskDefinitionField::Tags => "TAGS",
+            TaskDefinitionField::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TaskDefinitionField {
+    fn default() -> TaskDefinitionField {
+        TaskDefinitionField::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TaskDefinitionField {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TaskDefinitionField {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TaskDefinitionField {
+    fn from(value: &'a str) -> TaskDefinitionField {
+        match value {
+            "TAGS" => TaskDefinitionField::Tags,
+            _ => TaskDefinitionField::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TaskDefinitionField {
+    fn from(value: String) -> TaskDefinitionField {
+        match value.as_str() {
+            "TAGS" => TaskDefinitionField::Tags,
+            _ => TaskDefinitionField::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TaskDefinitionField {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TaskDefinitionField, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TaskDefinitionField {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TaskDefinitionField {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TaskDefinitionField {
+    fn deserialize<D>(deserializer: D) -> Result<TaskDefinitionField, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p><p>An object representing a constraint on task placement in the task definition. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/task-placement-constraints.html">Task Placement Constraints</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <note> <p>If you are using the Fargate launch type, task placement constraints are not supported.</p> </note></p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaskDefinitionPlacementConstraint {
+    /// <p>A cluster query language expression to apply to the constraint. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/cluster-query-language.html">Cluster Query Language</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
+    #[serde(rename = "expression")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expression: Option<String>,
+    /// <p>The type of constraint. The <code>MemberOf</code> constraint restricts selection to be from a group of valid candidates.</p>
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_: Option<TaskDefinitionPlacementConstraintType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TaskDefinitionPlacementConstraintType {
+    /// `memberOf`
+    MemberOf,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TaskDefinitionPlacementConstraintType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TaskDefinitionPlacementConstraintType::MemberOf => "memberOf",
+            TaskDefinitionPlacementConstraintType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TaskDefinitionPlacementConstraintType {
+    fn default() -> TaskDefinitionPlacementConstraintType {
+        TaskDefinitionPlacementConstraintType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TaskDefinitionPlacementConstraintType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TaskDefinitionPlacementConstraintType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TaskDefinitionPlacementConstraintType {
+    fn from(value: &'a str) -> TaskDefinitionPlacementConstraintType {
+        match value {
+            "memberOf" => TaskDefinitionPlacementConstraintType::MemberOf,
+            _ => TaskDefinitionPlacementConstraintType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TaskDefinitionPlacementConstraintType {
+    fn from(value: String) -> TaskDefinitionPlacementConstraintType {
+        match value.as_str() {
+            "memberOf" => TaskDefinitionPlacementConstraintType::MemberOf,
+            _ => TaskDefinitionPlacementConstraintType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TaskDefinitionPlacementConstraintType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TaskDefinitionPlacementConstraintType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TaskDefinitionPlacementConstraintType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TaskDefinitionPlacementConstraintType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TaskDefinitionPlacementConstraintType {
+    fn deserialize<D>(deserializer: D) -> Result<TaskDefinitionPlacementConstraintType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TaskDefinitionStatus {
+    /// `ACTIVE`
+    Active,
+    /// `INACTIVE`
+    Inactive,
+    /// `DELETE_IN_PROGRESS`
+    DeleteInProgress,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TaskDefinitionStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TaskDefinitionStatus::Active => "ACTIVE",
+            TaskDefinitionStatus::Inactive => "INACTIVE",
+            TaskDefinitionStatus::DeleteInProgress => "DELETE_IN_PROGRESS",
+            TaskDefinitionStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TaskDefinitionStatus {
+    fn default() -> TaskDefinitionStatus {
+        TaskDefinitionStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TaskDefinitionStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TaskDefinitionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TaskDefinitionStatus {
+    fn from(value: &'a str) -> TaskDefinitionStatus {
+        match value {
+            "ACTIVE" => TaskDefinitionStatus::Active,
+            "INACTIVE" => TaskDefinitionStatus::Inactive,
+            "DELETE_IN_PROGRESS" => TaskDefinitionStatus::DeleteInProgress,
+            _ => TaskDefinitionStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TaskDefinitionStatus {
+    fn from(value: String) -> TaskDefinitionStatus {
+        match value.as_str() {
+            "ACTIVE" => TaskDefinitionStatus::Active,
+            "INACTIVE" => TaskDefinitionStatus::Inactive,
+            "DELETE_IN_PROGRESS" => TaskDefinitionStatus::DeleteInProgress,
+            _ => TaskDefinitionStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TaskDefinitionStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TaskDefinitionStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TaskDefinitionStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TaskDefinitionStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TaskDefinitionStatus {
+    fn deserialize<D>(deserializer: D) -> Result<TaskDefinitionStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TaskField {
+    /// `TAGS`
+    Tags,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TaskField {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TaskField::Tags => "TAGS",
+            TaskField::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TaskField {
+    fn default() -> TaskField {
+        TaskField::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TaskField {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TaskField {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TaskField {
+    fn from(value: &'a str) -> TaskField {
+        match value {
+            "TAGS" => TaskField::Tags,
+            _ => TaskField::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TaskField {
+    fn from(value: String) -> TaskField {
+        match value.as_str() {
+            "TAGS" => TaskField::Tags,
+            _ => TaskField::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TaskField {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TaskField, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TaskField {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TaskField {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for TaskField {
+    fn deserialize<D>(deserializer: D) -> Result<TaskField, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The overrides associated with a task.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TaskOverride {
@@ -3080,8 +7206,13 @@ pub struct TaskSet {
     pub computed_desired_count: Option<i64>,
     /// <p>The Unix timestamp for when the task set was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The external ID associated with the task set.</p> <p>If a task set is created by an AWS CodeDeploy deployment, the <code>externalId</code> parameter contains the AWS CodeDeploy deployment ID.</p> <p>If a task set is created for an external deployment and is associated with a service discovery registry, the <code>externalId</code> parameter contains the <code>ECS_TASK_SET_EXTERNAL_ID</code> AWS Cloud Map attribute.</p>
     #[serde(rename = "externalId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3093,7 +7224,7 @@ pub struct TaskSet {
     /// <p>The launch type the tasks in the task set are using. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/launch_types.html">Amazon ECS Launch Types</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
     #[serde(rename = "launchType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub launch_type: Option<String>,
+    pub launch_type: Option<LaunchType>,
     /// <p>Details on a load balancer that is used with a task set.</p>
     #[serde(rename = "loadBalancers")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3129,11 +7260,16 @@ pub struct TaskSet {
     /// <p>The stability status, which indicates whether the task set has reached a steady state. If the following conditions are met, the task set will be in <code>STEADY_STATE</code>:</p> <ul> <li> <p>The task <code>runningCount</code> is equal to the <code>computedDesiredCount</code>.</p> </li> <li> <p>The <code>pendingCount</code> is <code>0</code>.</p> </li> <li> <p>There are no tasks running on container instances in the <code>DRAINING</code> status.</p> </li> <li> <p>All tasks are reporting a healthy status from the load balancers, service discovery, and container health checks.</p> </li> </ul> <p>If any of those conditions are not met, the stability status returns <code>STABILIZING</code>.</p>
     #[serde(rename = "stabilityStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stability_status: Option<String>,
+    pub stability_status: Option<StabilityStatus>,
     /// <p>The Unix timestamp for when the task set stability status was retrieved.</p>
     #[serde(rename = "stabilityStatusAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stability_status_at: Option<f64>,
+    pub stability_status_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The tag specified when a task set is started. If the task set is created by an AWS CodeDeploy deployment, the <code>startedBy</code> parameter is <code>CODE_DEPLOY</code>. For a task set created for an external deployment, the startedBy field isn't used.</p>
     #[serde(rename = "startedBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3156,10 +7292,216 @@ pub struct TaskSet {
     pub task_set_arn: Option<String>,
     /// <p>The Unix timestamp for when the task set was last updated.</p>
     #[serde(rename = "updatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<f64>,
+    pub updated_at: Option<::chrono::DateTime<::chrono::Utc>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TaskSetField {
+    /// `TAGS`
+    Tags,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TaskSetField {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TaskSetField::Tags => "TAGS",
+            TaskSetField::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TaskSetField {
+    fn default() -> TaskSetField {
+        TaskSetField::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TaskSetField {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TaskSetField {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TaskSetField {
+    fn from(value: &'a str) -> TaskSetField {
+        match value {
+            "TAGS" => TaskSetField::Tags,
+            _ => TaskSetField::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TaskSetField {
+    fn from(value: String) -> TaskSetField {
+        match value.as_str() {
+            "TAGS" => TaskSetField::Tags,
+            _ => TaskSetField::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TaskSetField {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TaskSetField, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TaskSetField {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TaskSetField {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TaskSetField {
+    fn deserialize<D>(deserializer: D) -> Result<TaskSetField, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TaskStopCode {
+    /// `TaskFailedToStart`
+    TaskFailedToStart,
+    /// `EssentialContainerExited`
+    EssentialContainerExited,
+    /// `UserInitiated`
+    UserInitiated,
+    /// `ServiceSchedulerInitiated`
+    ServiceSchedulerInitiated,
+    /// `SpotInterruption`
+    SpotInterruption,
+    /// `TerminationNotice`
+    TerminationNotice,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TaskStopCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TaskStopCode::TaskFailedToStart => "TaskFailedToStart",
+            TaskStopCode::EssentialContainerExited => "EssentialContainerExited",
+            TaskStopCode::UserInitiated => "UserInitiated",
+            TaskStopCode::ServiceSchedulerInitiated => "ServiceSchedulerInitiated",
+            TaskStopCode::SpotInterruption => "SpotInterruption",
+            TaskStopCode::TerminationNotice => "TerminationNotice",
+            TaskStopCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TaskStopCode {
+    fn default() -> TaskStopCode {
+        TaskStopCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TaskStopCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TaskStopCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TaskStopCode {
+    fn from(value: &'a str) -> TaskStopCode {
+        match value {
+            "TaskFailedToStart" => TaskStopCode::TaskFailedToStart,
+            "EssentialContainerExited" => TaskStopCode::EssentialContainerExited,
+            "UserInitiated" => TaskStopCode::UserInitiated,
+            "ServiceSchedulerInitiated" => TaskStopCode::ServiceSchedulerInitiated,
+            "SpotInterruption" => TaskStopCode::SpotInterruption,
+            "TerminationNotice" => TaskStopCode::TerminationNotice,
+            _ => TaskStopCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TaskStopCode {
+    fn from(value: String) -> TaskStopCode {
+        match value.as_str() {
+            "TaskFailedToStart" => TaskStopCode::TaskFailedToStart,
+            "EssentialContainerExited" => TaskStopCode::EssentialContainerExited,
+            "UserInitiated" => TaskStopCode::UserInitiated,
+            "ServiceSchedulerInitiated" => TaskStopCode::ServiceSchedulerInitiated,
+            "SpotInterruption" => TaskStopCode::SpotInterruption,
+            "TerminationNotice" => TaskStopCode::TerminationNotice,
+            _ => TaskStopCode::Unknown(value),
+        }
+    }
 }
 
+impl ::std::str::FromStr for TaskStopCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TaskStopCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TaskStopCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TaskStopCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TaskStopCode {
+    fn deserialize<D>(deserializer: D) -> Result<TaskStopCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The container path, mount options, and size of the tmpfs mount.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Tmpfs {
@@ -3175,6 +7517,99 @@ pub struct Tmpfs {
     pub size: i64,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TransportProtocol {
+    /// `tcp`
+    Tcp,
+    /// `udp`
+    Udp,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TransportProtocol {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TransportProtocol::Tcp => "tcp",
+            TransportProtocol::Udp => "udp",
+            TransportProtocol::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TransportProtocol {
+    fn default() -> TransportProtocol {
+        TransportProtocol::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TransportProtocol {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TransportProtocol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TransportProtocol {
+    fn from(value: &'a str) -> TransportProtocol {
+        match value {
+            "tcp" => TransportProtocol::Tcp,
+            "udp" => TransportProtocol::Udp,
+            _ => TransportProtocol::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TransportProtocol {
+    fn from(value: String) -> TransportProtocol {
+        match value.as_str() {
+            "tcp" => TransportProtocol::Tcp,
+            "udp" => TransportProtocol::Udp,
+            _ => TransportProtocol::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TransportProtocol {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TransportProtocol, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TransportProtocol {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TransportProtocol {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TransportProtocol {
+    fn deserialize<D>(deserializer: D) -> Result<TransportProtocol, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The <code>ulimit</code> settings to pass to the container.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Ulimit {
@@ -3183,12 +7618,170 @@ pub struct Ulimit {
     pub hard_limit: i64,
     /// <p>The <code>type</code> of the <code>ulimit</code>.</p>
     #[serde(rename = "name")]
-    pub name: String,
+    pub name: UlimitName,
     /// <p>The soft limit for the ulimit type.</p>
     #[serde(rename = "softLimit")]
     pub soft_limit: i64,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum UlimitName {
+    /// `core`
+    Core,
+    /// `cpu`
+    Cpu,
+    /// `data`
+    Data,
+    /// `fsize`
+    Fsize,
+    /// `locks`
+    Locks,
+    /// `memlock`
+    Memlock,
+    /// `msgqueue`
+    Msgqueue,
+    /// `nice`
+    Nice,
+    /// `nofile`
+    Nofile,
+    /// `nproc`
+    Nproc,
+    /// `rss`
+    Rss,
+    /// `rtprio`
+    Rtprio,
+    /// `rttime`
+    Rttime,
+    /// `sigpending`
+    Sigpending,
+    /// `stack`
+    Stack,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl UlimitName {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            UlimitName::Core => "core",
+            UlimitName::Cpu => "cpu",
+            UlimitName::Data => "data",
+            UlimitName::Fsize => "fsize",
+            UlimitName::Locks => "locks",
+            UlimitName::Memlock => "memlock",
+            UlimitName::Msgqueue => "msgqueue",
+            UlimitName::Nice => "nice",
+            UlimitName::Nofile => "nofile",
+            UlimitName::Nproc => "nproc",
+            UlimitName::Rss => "rss",
+            UlimitName::Rtprio => "rtprio",
+            UlimitName::Rttime => "rttime",
+            UlimitName::Sigpending => "sigpending",
+            UlimitName::Stack => "stack",
+            UlimitName::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for UlimitName {
+    fn default() -> UlimitName {
+        UlimitName::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for UlimitName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for UlimitName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for UlimitName {
+    fn from(value: &'a str) -> UlimitName {
+        match value {
+            "core" => UlimitName::Core,
+            "cpu" => UlimitName::Cpu,
+            "data" => UlimitName::Data,
+            "fsize" => UlimitName::Fsize,
+            "locks" => UlimitName::Locks,
+            "memlock" => UlimitName::Memlock,
+            "msgqueue" => UlimitName::Msgqueue,
+            "nice" => UlimitName::Nice,
+            "nofile" => UlimitName::Nofile,
+            "nproc" => UlimitName::Nproc,
+            "rss" => UlimitName::Rss,
+            "rtprio" => UlimitName::Rtprio,
+            "rttime" => UlimitName::Rttime,
+            "sigpending" => UlimitName::Sigpending,
+            "stack" => UlimitName::Stack,
+            _ => UlimitName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for UlimitName {
+    fn from(value: String) -> UlimitName {
+        match value.as_str() {
+            "core" => UlimitName::Core,
+            "cpu" => UlimitName::Cpu,
+            "data" => UlimitName::Data,
+            "fsize" => UlimitName::Fsize,
+            "locks" => UlimitName::Locks,
+            "memlock" => UlimitName::Memlock,
+            "msgqueue" => UlimitName::Msgqueue,
+            "nice" => UlimitName::Nice,
+            "nofile" => UlimitName::Nofile,
+            "nproc" => UlimitName::Nproc,
+            "rss" => UlimitName::Rss,
+            "rtprio" => UlimitName::Rtprio,
+            "rttime" => UlimitName::Rttime,
+            "sigpending" => UlimitName::Sigpending,
+            "stack" => UlimitName::Stack,
+            _ => UlimitName::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for UlimitName {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<UlimitName, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for UlimitName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for UlimitName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for UlimitName {
+    fn deserialize<D>(deserializer: D) -> Result<UlimitName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct UntagResourceRequest {
@@ -3256,7 +7849,7 @@ pub struct UpdateContainerInstancesStateRequest {
     pub container_instances: Vec<String>,
     /// <p>The container instance state with which to update the container instance. The only valid values for this action are <code>ACTIVE</code> and <code>DRAINING</code>. A container instance can only be updated to <code>DRAINING</code> status once it has reached an <code>ACTIVE</code> state. If a container instance is in <code>REGISTERING</code>, <code>DEREGISTERING</code>, or <code>REGISTRATION_FAILED</code> state you can describe the container instance but will be unable to update the container instance state.</p>
     #[serde(rename = "status")]
-    pub status: String,
+    pub status: ContainerInstanceStatus,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -6339,11 +10932,12 @@ pub struct EcsClient {
 impl EcsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> EcsClient {
         EcsClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "ECS"),
         }
     }
 
@@ -6375,6 +10969,7 @@ impl Ecs for EcsClient {
         input: CreateCapacityProviderRequest,
     ) -> Result<CreateCapacityProviderResponse, RusotoError<CreateCapacityProviderError>> {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("CreateCapacityProvider");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6406,6 +11001,7 @@ impl Ecs for EcsClient {
         input: CreateClusterRequest,
     ) -> Result<CreateClusterResponse, RusotoError<CreateClusterError>> {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("CreateCluster");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6436,6 +11032,7 @@ impl Ecs for EcsClient {
         input: CreateServiceRequest,
     ) -> Result<CreateServiceResponse, RusotoError<CreateServiceError>> {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("CreateService");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6466,6 +11063,7 @@ impl Ecs for EcsClient {
         input: CreateTaskSetRequest,
     ) -> Result<CreateTaskSetResponse, RusotoError<CreateTaskSetError>> {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("CreateTaskSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6496,6 +11094,7 @@ impl Ecs for EcsClient {
         input: DeleteAccountSettingRequest,
     ) -> Result<DeleteAccountSettingResponse, RusotoError<DeleteAccountSettingError>> {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("DeleteAccountSetting");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6527,6 +11126,7 @@ impl Ecs for EcsClient {
         input: DeleteAttributesRequest,
     ) -> Result<DeleteAttributesResponse, RusotoError<DeleteAttributesError>> {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("DeleteAttributes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6558,6 +11158,7 @@ impl Ecs for EcsClient {
         input: DeleteClusterRequest,
     ) -> Result<DeleteClusterResponse, RusotoError<DeleteClusterError>> {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("DeleteCluster");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6588,6 +11189,7 @@ impl Ecs for EcsClient {
         input: DeleteServiceRequest,
     ) -> Result<DeleteServiceResponse, RusotoError<DeleteServiceError>> {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("DeleteService");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6618,6 +11220,7 @@ impl Ecs for EcsClient {
         input: DeleteTaskSetRequest,
     ) -> Result<DeleteTaskSetResponse, RusotoError<DeleteTaskSetError>> {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("DeleteTaskSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6649,6 +11252,7 @@ impl Ecs for EcsClient {
     ) -> Result<DeregisterContainerInstanceResponse, RusotoError<DeregisterContainerInstanceError>>
     {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("DeregisterContainerInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6680,6 +11284,7 @@ impl Ecs for EcsClient {
         input: DeregisterTaskDefinitionRequest,
     ) -> Result<DeregisterTaskDefinitionResponse, RusotoError<DeregisterTaskDefinitionError>> {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("DeregisterTaskDefinition");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6712,6 +11317,7 @@ impl Ecs for EcsClient {
     ) -> Result<DescribeCapacityProvidersResponse, RusotoError<DescribeCapacityProvidersError>>
     {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("DescribeCapacityProviders");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6743,6 +11349,7 @@ impl Ecs for EcsClient {
         input: DescribeClustersRequest,
     ) -> Result<DescribeClustersResponse, RusotoError<DescribeClustersError>> {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("DescribeClusters");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6775,6 +11382,7 @@ impl Ecs for EcsClient {
     ) -> Result<DescribeContainerInstancesResponse, RusotoError<DescribeContainerInstancesError>>
     {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("DescribeContainerInstances");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6806,6 +11414,7 @@ impl Ecs for EcsClient {
         input: DescribeServicesRequest,
     ) -> Result<DescribeServicesResponse, RusotoError<DescribeServicesError>> {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("DescribeServices");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6837,6 +11446,7 @@ impl Ecs for EcsClient {
         input: DescribeTaskDefinitionRequest,
     ) -> Result<DescribeTaskDefinitionResponse, RusotoError<DescribeTaskDefinitionError>> {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("DescribeTaskDefinition");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6868,6 +11478,7 @@ impl Ecs for EcsClient {
         input: DescribeTaskSetsRequest,
     ) -> Result<DescribeTaskSetsResponse, RusotoError<DescribeTaskSetsError>> {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("DescribeTaskSets");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6899,6 +11510,7 @@ impl Ecs for EcsClient {
         input: DescribeTasksRequest,
     ) -> Result<DescribeTasksResponse, RusotoError<DescribeTasksError>> {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("DescribeTasks");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6929,6 +11541,7 @@ impl Ecs for EcsClient {
         input: DiscoverPollEndpointRequest,
     ) -> Result<DiscoverPollEndpointResponse, RusotoError<DiscoverPollEndpointError>> {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("DiscoverPollEndpoint");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6960,6 +11573,7 @@ impl Ecs for EcsClient {
         input: ListAccountSettingsRequest,
     ) -> Result<ListAccountSettingsResponse, RusotoError<ListAccountSettingsError>> {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("ListAccountSettings");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6991,6 +11605,7 @@ impl Ecs for EcsClient {
         input: ListAttributesRequest,
     ) -> Result<ListAttributesResponse, RusotoError<ListAttributesError>> {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("ListAttributes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7021,6 +11636,7 @@ impl Ecs for EcsClient {
         input: ListClustersRequest,
     ) -> Result<ListClustersResponse, RusotoError<ListClustersError>> {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("ListClusters");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7051,6 +11667,7 @@ impl Ecs for EcsClient {
         input: ListContainerInstancesRequest,
     ) -> Result<ListContainerInstancesResponse, RusotoError<ListContainerInstancesError>> {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("ListContainerInstances");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7082,6 +11699,7 @@ impl Ecs for EcsClient {
         input: ListServicesRequest,
     ) -> Result<ListServicesResponse, RusotoError<ListServicesError>> {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("ListServices");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7112,6 +11730,7 @@ impl Ecs for EcsClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7144,6 +11763,7 @@ impl Ecs for EcsClient {
     ) -> Result<ListTaskDefinitionFamiliesResponse, RusotoError<ListTaskDefinitionFamiliesError>>
     {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("ListTaskDefinitionFamilies");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7175,6 +11795,7 @@ impl Ecs for EcsClient {
         input: ListTaskDefinitionsRequest,
     ) -> Result<ListTaskDefinitionsResponse, RusotoError<ListTaskDefinitionsError>> {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("ListTaskDefinitions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7206,6 +11827,7 @@ impl Ecs for EcsClient {
         input: ListTasksRequest,
     ) -> Result<ListTasksResponse, RusotoError<ListTasksError>> {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("ListTasks");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7236,6 +11858,7 @@ impl Ecs for EcsClient {
         input: PutAccountSettingRequest,
     ) -> Result<PutAccountSettingResponse, RusotoError<PutAccountSettingError>> {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("PutAccountSetting");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7267,6 +11890,7 @@ impl Ecs for EcsClient {
         input: PutAccountSettingDefaultRequest,
     ) -> Result<PutAccountSettingDefaultResponse, RusotoError<PutAccountSettingDefaultError>> {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("PutAccountSettingDefault");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7298,6 +11922,7 @@ impl Ecs for EcsClient {
         input: PutAttributesRequest,
     ) -> Result<PutAttributesResponse, RusotoError<PutAttributesError>> {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("PutAttributes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7329,6 +11954,7 @@ impl Ecs for EcsClient {
     ) -> Result<PutClusterCapacityProvidersResponse, RusotoError<PutClusterCapacityProvidersError>>
     {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("PutClusterCapacityProviders");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7361,6 +11987,7 @@ impl Ecs for EcsClient {
     ) -> Result<RegisterContainerInstanceResponse, RusotoError<RegisterContainerInstanceError>>
     {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("RegisterContainerInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7392,6 +12019,7 @@ impl Ecs for EcsClient {
         input: RegisterTaskDefinitionRequest,
     ) -> Result<RegisterTaskDefinitionResponse, RusotoError<RegisterTaskDefinitionError>> {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("RegisterTaskDefinition");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7423,6 +12051,7 @@ impl Ecs for EcsClient {
         input: RunTaskRequest,
     ) -> Result<RunTaskResponse, RusotoError<RunTaskError>> {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("RunTask");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonEC2ContainerServiceV20141113.RunTask");
@@ -7450,6 +12079,7 @@ impl Ecs for EcsClient {
         input: StartTaskRequest,
     ) -> Result<StartTaskResponse, RusotoError<StartTaskError>> {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("StartTask");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7480,6 +12110,7 @@ impl Ecs for EcsClient {
         input: StopTaskRequest,
     ) -> Result<StopTaskResponse, RusotoError<StopTaskError>> {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("StopTask");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7511,6 +12142,7 @@ impl Ecs for EcsClient {
     ) -> Result<SubmitAttachmentStateChangesResponse, RusotoError<SubmitAttachmentStateChangesError>>
     {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("SubmitAttachmentStateChanges");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7543,6 +12175,7 @@ impl Ecs for EcsClient {
     ) -> Result<SubmitContainerStateChangeResponse, RusotoError<SubmitContainerStateChangeError>>
     {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("SubmitContainerStateChange");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7574,6 +12207,7 @@ impl Ecs for EcsClient {
         input: SubmitTaskStateChangeRequest,
     ) -> Result<SubmitTaskStateChangeResponse, RusotoError<SubmitTaskStateChangeError>> {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("SubmitTaskStateChange");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7605,6 +12239,7 @@ impl Ecs for EcsClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7635,6 +12270,7 @@ impl Ecs for EcsClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7665,6 +12301,7 @@ impl Ecs for EcsClient {
         input: UpdateClusterSettingsRequest,
     ) -> Result<UpdateClusterSettingsResponse, RusotoError<UpdateClusterSettingsError>> {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("UpdateClusterSettings");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7696,6 +12333,7 @@ impl Ecs for EcsClient {
         input: UpdateContainerAgentRequest,
     ) -> Result<UpdateContainerAgentResponse, RusotoError<UpdateContainerAgentError>> {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("UpdateContainerAgent");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7730,6 +12368,7 @@ impl Ecs for EcsClient {
         RusotoError<UpdateContainerInstancesStateError>,
     > {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("UpdateContainerInstancesState");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7761,6 +12400,7 @@ impl Ecs for EcsClient {
         input: UpdateServiceRequest,
     ) -> Result<UpdateServiceResponse, RusotoError<UpdateServiceError>> {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("UpdateService");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7792,6 +12432,7 @@ impl Ecs for EcsClient {
     ) -> Result<UpdateServicePrimaryTaskSetResponse, RusotoError<UpdateServicePrimaryTaskSetError>>
     {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("UpdateServicePrimaryTaskSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7823,6 +12464,7 @@ impl Ecs for EcsClient {
         input: UpdateTaskSetRequest,
     ) -> Result<UpdateTaskSetResponse, RusotoError<UpdateTaskSetError>> {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("UpdateTaskSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7847,3 +12489,409 @@ impl Ecs for EcsClient {
         }
     }
 }
+impl EcsClient {
+    /// Auto-paginating version of `list_account_settings`, streaming every page of results.
+    pub fn list_account_settings_pages(
+        &self,
+        input: ListAccountSettingsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListAccountSettingsResponse, ListAccountSettingsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EcsClient, input: ListAccountSettingsRequest| async move {
+                client.list_account_settings(input).await
+            },
+            |input: &ListAccountSettingsRequest, output: &ListAccountSettingsResponse| {
+                Some(ListAccountSettingsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_account_settings`, streaming the `settings` of every page of results.
+    pub fn list_account_settings_items(
+        &self,
+        input: ListAccountSettingsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Setting, ListAccountSettingsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_account_settings_pages(input),
+            |output: ListAccountSettingsResponse| output.settings.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_attributes`, streaming every page of results.
+    pub fn list_attributes_pages(
+        &self,
+        input: ListAttributesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListAttributesResponse, ListAttributesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EcsClient, input: ListAttributesRequest| async move {
+                client.list_attributes(input).await
+            },
+            |input: &ListAttributesRequest, output: &ListAttributesResponse| {
+                Some(ListAttributesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_attributes`, streaming the `attributes` of every page of results.
+    pub fn list_attributes_items(
+        &self,
+        input: ListAttributesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Attribute, ListAttributesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_attributes_pages(input),
+            |output: ListAttributesResponse| output.attributes.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_clusters`, streaming every page of results.
+    pub fn list_clusters_pages(
+        &self,
+        input: ListClustersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListClustersResponse, ListClustersError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EcsClient, input: ListClustersRequest| async move {
+                client.list_clusters(input).await
+            },
+            |input: &ListClustersRequest, output: &ListClustersResponse| {
+                Some(ListClustersRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_clusters`, streaming the `clusterArns` of every page of results.
+    pub fn list_clusters_items(
+        &self,
+        input: ListClustersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListClustersError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_clusters_pages(input),
+            |output: ListClustersResponse| output.cluster_arns.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_container_instances`, streaming every page of results.
+    pub fn list_container_instances_pages(
+        &self,
+        input: ListContainerInstancesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListContainerInstancesResponse,
+        ListContainerInstancesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EcsClient, input: ListContainerInstancesRequest| async move {
+                client.list_container_instances(input).await
+            },
+            |input: &ListContainerInstancesRequest, output: &ListContainerInstancesResponse| {
+                Some(ListContainerInstancesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_container_instances`, streaming the `containerInstanceArns` of every page of results.
+    pub fn list_container_instances_items(
+        &self,
+        input: ListContainerInstancesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListContainerInstancesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_container_instances_pages(input),
+            |output: ListContainerInstancesResponse| {
+                output.container_instance_arns.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_services`, streaming every page of results.
+    pub fn list_services_pages(
+        &self,
+        input: ListServicesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListServicesResponse, ListServicesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EcsClient, input: ListServicesRequest| async move {
+                client.list_services(input).await
+            },
+            |input: &ListServicesRequest, output: &ListServicesResponse| {
+                Some(ListServicesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_services`, streaming the `serviceArns` of every page of results.
+    pub fn list_services_items(
+        &self,
+        input: ListServicesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListServicesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_services_pages(input),
+            |output: ListServicesResponse| output.service_arns.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_task_definition_families`, streaming every page of results.
+    pub fn list_task_definition_families_pages(
+        &self,
+        input: ListTaskDefinitionFamiliesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListTaskDefinitionFamiliesResponse,
+        ListTaskDefinitionFamiliesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EcsClient, input: ListTaskDefinitionFamiliesRequest| async move {
+                client.list_task_definition_families(input).await
+            },
+            |input: &ListTaskDefinitionFamiliesRequest,
+             output: &ListTaskDefinitionFamiliesResponse| {
+                Some(ListTaskDefinitionFamiliesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_task_definition_families`, streaming the `families` of every page of results.
+    pub fn list_task_definition_families_items(
+        &self,
+        input: ListTaskDefinitionFamiliesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListTaskDefinitionFamiliesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_task_definition_families_pages(input),
+            |output: ListTaskDefinitionFamiliesResponse| output.families.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_task_definitions`, streaming every page of results.
+    pub fn list_task_definitions_pages(
+        &self,
+        input: ListTaskDefinitionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListTaskDefinitionsResponse, ListTaskDefinitionsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EcsClient, input: ListTaskDefinitionsRequest| async move {
+                client.list_task_definitions(input).await
+            },
+            |input: &ListTaskDefinitionsRequest, output: &ListTaskDefinitionsResponse| {
+                Some(ListTaskDefinitionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_task_definitions`, streaming the `taskDefinitionArns` of every page of results.
+    pub fn list_task_definitions_items(
+        &self,
+        input: ListTaskDefinitionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListTaskDefinitionsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_task_definitions_pages(input),
+            |output: ListTaskDefinitionsResponse| output.task_definition_arns.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_tasks`, streaming every page of results.
+    pub fn list_tasks_pages(
+        &self,
+        input: ListTasksRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListTasksResponse, ListTasksError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EcsClient, input: ListTasksRequest| async move { client.list_tasks(input).await },
+            |input: &ListTasksRequest, output: &ListTasksResponse| {
+                Some(ListTasksRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_tasks`, streaming the `taskArns` of every page of results.
+    pub fn list_tasks_items(
+        &self,
+        input: ListTasksRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListTasksError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_tasks_pages(input),
+            |output: ListTasksResponse| output.task_arns.unwrap_or_default(),
+        )
+    }
+}
+impl EcsClient {
+    /// Waits until `describe_services` reports the `ServicesInactive` state, polling every 15 seconds up to 40 times.
+    pub async fn wait_until_services_inactive(
+        &self,
+        input: DescribeServicesRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeServicesError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(15), 40);
+        self.wait_until_services_inactive_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_services_inactive`, polling as often and as many times as `config` says.
+    pub async fn wait_until_services_inactive_with_config(
+        &self,
+        input: DescribeServicesRequest,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeServicesError>> {
+        rusoto_core::waiter::wait(
+            "ServicesInactive",
+            &config,
+            || self.describe_services(input.clone()),
+            |result: &Result<DescribeServicesResponse, RusotoError<DescribeServicesError>>| {
+                match result {
+                    Ok(output)
+                        if output
+                            .failures
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.reason.iter())
+                            .any(|v| v == "MISSING") =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    Ok(output)
+                        if output
+                            .services
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.status.iter())
+                            .any(|v| v == "INACTIVE") =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Success)
+                    }
+                    _ => None,
+                }
+            },
+        )
+        .await
+    }
+
+    /// Waits until `describe_tasks` reports the `TasksRunning` state, polling every 6 seconds up to 100 times.
+    pub async fn wait_until_tasks_running(
+        &self,
+        input: DescribeTasksRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeTasksError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(6), 100);
+        self.wait_until_tasks_running_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_tasks_running`, polling as often and as many times as `config` says.
+    pub async fn wait_until_tasks_running_with_config(
+        &self,
+        input: DescribeTasksRequest,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeTasksError>> {
+        rusoto_core::waiter::wait(
+            "TasksRunning",
+            &config,
+            || self.describe_tasks(input.clone()),
+            |result: &Result<DescribeTasksResponse, RusotoError<DescribeTasksError>>| match result {
+                Ok(output)
+                    if output
+                        .tasks
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.last_status.iter())
+                        .any(|v| v == "STOPPED") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .failures
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.reason.iter())
+                        .any(|v| v == "MISSING") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if rusoto_core::waiter::all_match(
+                        output
+                            .tasks
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.last_status.iter()),
+                        |v| v == "RUNNING",
+                    ) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+
+    /// Waits until `describe_tasks` reports the `TasksStopped` state, polling every 6 seconds up to 100 times.
+    pub async fn wait_until_tasks_stopped(
+        &self,
+        input: DescribeTasksRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeTasksError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(6), 100);
+        self.wait_until_tasks_stopped_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_tasks_stopped`, polling as often and as many times as `config` says.
+    pub async fn wait_until_tasks_stopped_with_config(
+        &self,
+        input: DescribeTasksRequest,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeTasksError>> {
+        rusoto_core::waiter::wait(
+            "TasksStopped",
+            &config,
+            || self.describe_tasks(input.clone()),
+            |result: &Result<DescribeTasksResponse, RusotoError<DescribeTasksError>>| match result {
+                Ok(output)
+                    if rusoto_core::waiter::all_match(
+                        output
+                            .tasks
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.last_status.iter()),
+                        |v| v == "STOPPED",
+                    ) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+}
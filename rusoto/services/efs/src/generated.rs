@@ -48,7 +48,7 @@ pub struct AccessPointDescription {
     /// <p>Identifies the lifecycle phase of the access point.</p>
     #[serde(rename = "LifeCycleState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub life_cycle_state: Option<String>,
+    pub life_cycle_state: Option<LifeCycleState>,
     /// <p>The name of the access point. This is the value of the <code>Name</code> tag.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -111,7 +111,7 @@ pub struct CreateFileSystemRequest {
     /// <p>The performance mode of the file system. We recommend <code>generalPurpose</code> performance mode for most file systems. File systems using the <code>maxIO</code> performance mode can scale to higher levels of aggregate throughput and operations per second with a tradeoff of slightly higher latencies for most file operations. The performance mode can't be changed after the file system has been created.</p>
     #[serde(rename = "PerformanceMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub performance_mode: Option<String>,
+    pub performance_mode: Option<PerformanceMode>,
     /// <p>The throughput, measured in MiB/s, that you want to provision for a file system that you're creating. Valid values are 1-1024. Required if <code>ThroughputMode</code> is set to <code>provisioned</code>. The upper limit for throughput is 1024 MiB/s. You can get this limit increased by contacting AWS Support. For more information, see <a href="https://docs.aws.amazon.com/efs/latest/ug/limits.html#soft-limits">Amazon EFS Limits That You Can Increase</a> in the <i>Amazon EFS User Guide.</i> </p>
     #[serde(rename = "ProvisionedThroughputInMibps")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -123,7 +123,7 @@ pub struct CreateFileSystemRequest {
     /// <p>The throughput mode for the file system to be created. There are two throughput modes to choose from for your file system: <code>bursting</code> and <code>provisioned</code>. If you set <code>ThroughputMode</code> to <code>provisioned</code>, you must also set a value for <code>ProvisionedThroughPutInMibps</code>. You can decrease your file system's throughput in Provisioned Throughput mode or change between the throughput modes as long as it’s been more than 24 hours since the last decrease or throughput mode change. For more, see <a href="https://docs.aws.amazon.com/efs/latest/ug/performance.html#provisioned-throughput">Specifying Throughput with Provisioned Mode</a> in the <i>Amazon EFS User Guide.</i> </p>
     #[serde(rename = "ThroughputMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub throughput_mode: Option<String>,
+    pub throughput_mode: Option<ThroughputMode>,
 }
 
 /// <p><p/></p>
@@ -408,7 +408,12 @@ pub struct DescribeTagsResponse {
 pub struct FileSystemDescription {
     /// <p>The time that the file system was created, in seconds (since 1970-01-01T00:00:00Z).</p>
     #[serde(rename = "CreationTime")]
-    pub creation_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub creation_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The opaque string specified in the request.</p>
     #[serde(rename = "CreationToken")]
     pub creation_token: String,
@@ -425,7 +430,7 @@ pub struct FileSystemDescription {
     pub kms_key_id: Option<String>,
     /// <p>The lifecycle phase of the file system.</p>
     #[serde(rename = "LifeCycleState")]
-    pub life_cycle_state: String,
+    pub life_cycle_state: LifeCycleState,
     /// <p>You can add tags to a file system, including a <code>Name</code> tag. For more information, see <a>CreateFileSystem</a>. If the file system has a <code>Name</code> tag, Amazon EFS returns the value in this field. </p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -438,7 +443,7 @@ pub struct FileSystemDescription {
     pub owner_id: String,
     /// <p>The performance mode of the file system.</p>
     #[serde(rename = "PerformanceMode")]
-    pub performance_mode: String,
+    pub performance_mode: PerformanceMode,
     /// <p>The throughput, measured in MiB/s, that you want to provision for a file system. Valid values are 1-1024. Required if <code>ThroughputMode</code> is set to <code>provisioned</code>. The limit on throughput is 1024 MiB/s. You can get these limits increased by contacting AWS Support. For more information, see <a href="https://docs.aws.amazon.com/efs/latest/ug/limits.html#soft-limits">Amazon EFS Limits That You Can Increase</a> in the <i>Amazon EFS User Guide.</i> </p>
     #[serde(rename = "ProvisionedThroughputInMibps")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -452,7 +457,7 @@ pub struct FileSystemDescription {
     /// <p>The throughput mode for a file system. There are two throughput modes to choose from for your file system: <code>bursting</code> and <code>provisioned</code>. If you set <code>ThroughputMode</code> to <code>provisioned</code>, you must also set a value for <code>ProvisionedThroughPutInMibps</code>. You can decrease your file system's throughput in Provisioned Throughput mode or change between the throughput modes as long as it’s been more than 24 hours since the last decrease or throughput mode change. </p>
     #[serde(rename = "ThroughputMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub throughput_mode: Option<String>,
+    pub throughput_mode: Option<ThroughputMode>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -474,8 +479,13 @@ pub struct FileSystemPolicyDescription {
 pub struct FileSystemSize {
     /// <p>The time at which the size of data, returned in the <code>Value</code> field, was determined. The value is the integer number of seconds since 1970-01-01T00:00:00Z.</p>
     #[serde(rename = "Timestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub timestamp: Option<f64>,
+    pub timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The latest known metered size (in bytes) of data stored in the file system.</p>
     #[serde(rename = "Value")]
     pub value: i64,
@@ -489,6 +499,119 @@ pub struct FileSystemSize {
     pub value_in_standard: Option<i64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LifeCycleState {
+    /// `creating`
+    Creating,
+    /// `available`
+    Available,
+    /// `updating`
+    Updating,
+    /// `deleting`
+    Deleting,
+    /// `deleted`
+    Deleted,
+    /// `error`
+    Error,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LifeCycleState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LifeCycleState::Creating => "creating",
+            LifeCycleState::Available => "available",
+            LifeCycleState::Updating => "updating",
+            LifeCycleState::Deleting => "deleting",
+            LifeCycleState::Deleted => "deleted",
+            LifeCycleState::Error => "error",
+            LifeCycleState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LifeCycleState {
+    fn default() -> LifeCycleState {
+        LifeCycleState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LifeCycleState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LifeCycleState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LifeCycleState {
+    fn from(value: &'a str) -> LifeCycleState {
+        match value {
+            "creating" => LifeCycleState::Creating,
+            "available" => LifeCycleState::Available,
+            "updating" => LifeCycleState::Updating,
+            "deleting" => LifeCycleState::Deleting,
+            "deleted" => LifeCycleState::Deleted,
+            "error" => LifeCycleState::Error,
+            _ => LifeCycleState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LifeCycleState {
+    fn from(value: String) -> LifeCycleState {
+        match value.as_str() {
+            "creating" => LifeCycleState::Creating,
+            "available" => LifeCycleState::Available,
+            "updating" => LifeCycleState::Updating,
+            "deleting" => LifeCycleState::Deleting,
+            "deleted" => LifeCycleState::Deleted,
+            "error" => LifeCycleState::Error,
+            _ => LifeCycleState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LifeCycleState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LifeCycleState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LifeCycleState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LifeCycleState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LifeCycleState {
+    fn deserialize<D>(deserializer: D) -> Result<LifeCycleState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LifecycleConfigurationDescription {
@@ -504,7 +627,7 @@ pub struct LifecyclePolicy {
     /// <p> A value that describes the period of time that a file is not accessed, after which it transitions to the IA storage class. Metadata operations such as listing the contents of a directory don't count as file access events.</p>
     #[serde(rename = "TransitionToIA")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub transition_to_ia: Option<String>,
+    pub transition_to_ia: Option<TransitionToIARules>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -570,7 +693,7 @@ pub struct MountTargetDescription {
     pub ip_address: Option<String>,
     /// <p>Lifecycle state of the mount target.</p>
     #[serde(rename = "LifeCycleState")]
-    pub life_cycle_state: String,
+    pub life_cycle_state: LifeCycleState,
     /// <p>System-assigned mount target ID.</p>
     #[serde(rename = "MountTargetId")]
     pub mount_target_id: String,
@@ -587,6 +710,99 @@ pub struct MountTargetDescription {
     pub subnet_id: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PerformanceMode {
+    /// `generalPurpose`
+    GeneralPurpose,
+    /// `maxIO`
+    MaxIO,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PerformanceMode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PerformanceMode::GeneralPurpose => "generalPurpose",
+            PerformanceMode::MaxIO => "maxIO",
+            PerformanceMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PerformanceMode {
+    fn default() -> PerformanceMode {
+        PerformanceMode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PerformanceMode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PerformanceMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PerformanceMode {
+    fn from(value: &'a str) -> PerformanceMode {
+        match value {
+            "generalPurpose" => PerformanceMode::GeneralPurpose,
+            "maxIO" => PerformanceMode::MaxIO,
+            _ => PerformanceMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PerformanceMode {
+    fn from(value: String) -> PerformanceMode {
+        match value.as_str() {
+            "generalPurpose" => PerformanceMode::GeneralPurpose,
+            "maxIO" => PerformanceMode::MaxIO,
+            _ => PerformanceMode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PerformanceMode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PerformanceMode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PerformanceMode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PerformanceMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PerformanceMode {
+    fn deserialize<D>(deserializer: D) -> Result<PerformanceMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The full POSIX identity, including the user ID, group ID, and any secondary group IDs, on the access point that is used for all file system operations performed by NFS clients using the access point.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PosixUser {
@@ -663,6 +879,232 @@ pub struct TagResourceRequest {
     pub tags: Vec<Tag>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ThroughputMode {
+    /// `bursting`
+    Bursting,
+    /// `provisioned`
+    Provisioned,
+    /// `elastic`
+    Elastic,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ThroughputMode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ThroughputMode::Bursting => "bursting",
+            ThroughputMode::Provisioned => "provisioned",
+            ThroughputMode::Elastic => "elastic",
+            ThroughputMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ThroughputMode {
+    fn default() -> ThroughputMode {
+        ThroughputMode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ThroughputMode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ThroughputMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ThroughputMode {
+    fn from(value: &'a str) -> ThroughputMode {
+        match value {
+            "bursting" => ThroughputMode::Bursting,
+            "provisioned" => ThroughputMode::Provisioned,
+            "elastic" => ThroughputMode::Elastic,
+            _ => ThroughputMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ThroughputMode {
+    fn from(value: String) -> ThroughputMode {
+        match value.as_str() {
+            "bursting" => ThroughputMode::Bursting,
+            "provisioned" => ThroughputMode::Provisioned,
+            "elastic" => ThroughputMode::Elastic,
+            _ => ThroughputMode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ThroughputMode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ThroughputMode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ThroughputMode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ThroughputMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ThroughputMode {
+    fn deserialize<D>(deserializer: D) -> Result<ThroughputMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TransitionToIARules {
+    /// `AFTER_7_DAYS`
+    After7Days,
+    /// `AFTER_14_DAYS`
+    After14Days,
+    /// `AFTER_30_DAYS`
+    After30Days,
+    /// `AFTER_60_DAYS`
+    After60Days,
+    /// `AFTER_90_DAYS`
+    After90Days,
+    /// `AFTER_1_DAY`
+    After1Day,
+    /// `AFTER_180_DAYS`
+    After180Days,
+    /// `AFTER_270_DAYS`
+    After270Days,
+    /// `AFTER_365_DAYS`
+    After365Days,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TransitionToIARules {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TransitionToIARules::After7Days => "AFTER_7_DAYS",
+            TransitionToIARules::After14Days => "AFTER_14_DAYS",
+            TransitionToIARules::After30Days => "AFTER_30_DAYS",
+            TransitionToIARules::After60Days => "AFTER_60_DAYS",
+            TransitionToIARules::After90Days => "AFTER_90_DAYS",
+            TransitionToIARules::After1Day => "AFTER_1_DAY",
+            TransitionToIARules::After180Days => "AFTER_180_DAYS",
+            TransitionToIARules::After270Days => "AFTER_270_DAYS",
+            TransitionToIARules::After365Days => "AFTER_365_DAYS",
+            TransitionToIARules::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TransitionToIARules {
+    fn default() -> TransitionToIARules {
+        TransitionToIARules::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TransitionToIARules {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TransitionToIARules {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TransitionToIARules {
+    fn from(value: &'a str) -> TransitionToIARules {
+        match value {
+            "AFTER_7_DAYS" => TransitionToIARules::After7Days,
+            "AFTER_14_DAYS" => TransitionToIARules::After14Days,
+            "AFTER_30_DAYS" => TransitionToIARules::After30Days,
+            "AFTER_60_DAYS" => TransitionToIARules::After60Days,
+            "AFTER_90_DAYS" => TransitionToIARules::After90Days,
+            "AFTER_1_DAY" => TransitionToIARules::After1Day,
+            "AFTER_180_DAYS" => TransitionToIARules::After180Days,
+            "AFTER_270_DAYS" => TransitionToIARules::After270Days,
+            "AFTER_365_DAYS" => TransitionToIARules::After365Days,
+            _ => TransitionToIARules::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TransitionToIARules {
+    fn from(value: String) -> TransitionToIARules {
+        match value.as_str() {
+            "AFTER_7_DAYS" => TransitionToIARules::After7Days,
+            "AFTER_14_DAYS" => TransitionToIARules::After14Days,
+            "AFTER_30_DAYS" => TransitionToIARules::After30Days,
+            "AFTER_60_DAYS" => TransitionToIARules::After60Days,
+            "AFTER_90_DAYS" => TransitionToIARules::After90Days,
+            "AFTER_1_DAY" => TransitionToIARules::After1Day,
+            "AFTER_180_DAYS" => TransitionToIARules::After180Days,
+            "AFTER_270_DAYS" => TransitionToIARules::After270Days,
+            "AFTER_365_DAYS" => TransitionToIARules::After365Days,
+            _ => TransitionToIARules::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TransitionToIARules {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TransitionToIARules, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TransitionToIARules {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TransitionToIARules {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TransitionToIARules {
+    fn deserialize<D>(deserializer: D) -> Result<TransitionToIARules, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct UntagResourceRequest {
@@ -688,7 +1130,7 @@ pub struct UpdateFileSystemRequest {
     /// <p>(Optional) The throughput mode that you want your file system to use. If you're not updating your throughput mode, you don't need to provide this value in your request. If you are changing the <code>ThroughputMode</code> to <code>provisioned</code>, you must also set a value for <code>ProvisionedThroughputInMibps</code>.</p>
     #[serde(rename = "ThroughputMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub throughput_mode: Option<String>,
+    pub throughput_mode: Option<ThroughputMode>,
 }
 
 /// Errors returned by CreateAccessPoint
@@ -2173,11 +2615,12 @@ pub struct EfsClient {
 impl EfsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> EfsClient {
         EfsClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "EFS"),
         }
     }
 
@@ -2212,6 +2655,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("POST", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("CreateAccessPoint");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2243,6 +2687,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("POST", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("CreateFileSystem");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2274,6 +2719,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("POST", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("CreateMountTarget");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2308,6 +2754,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("POST", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("CreateTags");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2341,6 +2788,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("DELETE", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("DeleteAccessPoint");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2371,6 +2819,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("DELETE", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("DeleteFileSystem");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2401,6 +2850,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("DELETE", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("DeleteFileSystemPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2431,6 +2881,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("DELETE", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("DeleteMountTarget");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2461,6 +2912,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("POST", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("DeleteTags");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2491,6 +2943,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("GET", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("DescribeAccessPoints");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2537,6 +2990,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("GET", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("DescribeFileSystemPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2565,6 +3019,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("GET", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("DescribeFileSystems");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2612,6 +3067,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("GET", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("DescribeLifecycleConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2646,6 +3102,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("GET", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("DescribeMountTargetSecurityGroups");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2676,6 +3133,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("GET", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("DescribeMountTargets");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2725,6 +3183,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("GET", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("DescribeTags");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2765,6 +3224,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("GET", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2805,6 +3265,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("PUT", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("ModifyMountTargetSecurityGroups");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2840,6 +3301,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("PUT", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("PutFileSystemPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2875,6 +3337,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("PUT", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("PutLifecycleConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2909,6 +3372,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("POST", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2942,6 +3406,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("DELETE", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2975,6 +3440,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("PUT", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("UpdateFileSystem");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2997,3 +3463,138 @@ impl Efs for EfsClient {
         }
     }
 }
+impl EfsClient {
+    /// Auto-paginating version of `describe_access_points`, streaming every page of results.
+    pub fn describe_access_points_pages(
+        &self,
+        input: DescribeAccessPointsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeAccessPointsResponse,
+        DescribeAccessPointsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EfsClient, input: DescribeAccessPointsRequest| async move {
+                client.describe_access_points(input).await
+            },
+            |input: &DescribeAccessPointsRequest, output: &DescribeAccessPointsResponse| {
+                Some(DescribeAccessPointsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_access_points`, streaming the `AccessPoints` of every page of results.
+    pub fn describe_access_points_items(
+        &self,
+        input: DescribeAccessPointsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<AccessPointDescription, DescribeAccessPointsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_access_points_pages(input),
+            |output: DescribeAccessPointsResponse| output.access_points.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_file_systems`, streaming every page of results.
+    pub fn describe_file_systems_pages(
+        &self,
+        input: DescribeFileSystemsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeFileSystemsResponse, DescribeFileSystemsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EfsClient, input: DescribeFileSystemsRequest| async move {
+                client.describe_file_systems(input).await
+            },
+            |input: &DescribeFileSystemsRequest, output: &DescribeFileSystemsResponse| {
+                Some(DescribeFileSystemsRequest {
+                    marker: Some(output.next_marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_file_systems`, streaming the `FileSystems` of every page of results.
+    pub fn describe_file_systems_items(
+        &self,
+        input: DescribeFileSystemsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<FileSystemDescription, DescribeFileSystemsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_file_systems_pages(input),
+            |output: DescribeFileSystemsResponse| output.file_systems.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_mount_targets`, streaming every page of results.
+    pub fn describe_mount_targets_pages(
+        &self,
+        input: DescribeMountTargetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeMountTargetsResponse,
+        DescribeMountTargetsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EfsClient, input: DescribeMountTargetsRequest| async move {
+                client.describe_mount_targets(input).await
+            },
+            |input: &DescribeMountTargetsRequest, output: &DescribeMountTargetsResponse| {
+                Some(DescribeMountTargetsRequest {
+                    marker: Some(output.next_marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_mount_targets`, streaming the `MountTargets` of every page of results.
+    pub fn describe_mount_targets_items(
+        &self,
+        input: DescribeMountTargetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<MountTargetDescription, DescribeMountTargetsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_mount_targets_pages(input),
+            |output: DescribeMountTargetsResponse| output.mount_targets.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_tags`, streaming every page of results.
+    pub fn describe_tags_pages(
+        &self,
+        input: DescribeTagsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeTagsResponse, DescribeTagsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EfsClient, input: DescribeTagsRequest| async move {
+                client.describe_tags(input).await
+            },
+            |input: &DescribeTagsRequest, output: &DescribeTagsResponse| {
+                Some(DescribeTagsRequest {
+                    marker: Some(output.next_marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_tags`, streaming the `Tags` of every page of results.
+    pub fn describe_tags_items(
+        &self,
+        input: DescribeTagsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Tag, DescribeTagsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_tags_pages(input),
+            |output: DescribeTagsResponse| output.tags,
+        )
+    }
+}
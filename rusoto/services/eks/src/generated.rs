@@ -25,6 +25,149 @@ use rusoto_core::signature::SignedRequest;
 #[allow(unused_imports)]
 use serde::{Deserialize, Serialize};
 use serde_json;
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AMITypes {
+    /// `AL2_x86_64`
+    Al2X86_64,
+    /// `AL2_x86_64_GPU`
+    Al2X86_64Gpu,
+    /// `AL2_ARM_64`
+    Al2Arm64,
+    /// `CUSTOM`
+    Custom,
+    /// `BOTTLEROCKET_ARM_64`
+    BottlerocketArm64,
+    /// `BOTTLEROCKET_x86_64`
+    BottlerocketX86_64,
+    /// `BOTTLEROCKET_ARM_64_NVIDIA`
+    BottlerocketArm64Nvidia,
+    /// `BOTTLEROCKET_x86_64_NVIDIA`
+    BottlerocketX86_64Nvidia,
+    /// `WINDOWS_CORE_2019_x86_64`
+    WindowsCore2019X86_64,
+    /// `WINDOWS_FULL_2019_x86_64`
+    WindowsFull2019X86_64,
+    /// `WINDOWS_CORE_2022_x86_64`
+    WindowsCore2022X86_64,
+    /// `WINDOWS_FULL_2022_x86_64`
+    WindowsFull2022X86_64,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AMITypes {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AMITypes::Al2X86_64 => "AL2_x86_64",
+            AMITypes::Al2X86_64Gpu => "AL2_x86_64_GPU",
+            AMITypes::Al2Arm64 => "AL2_ARM_64",
+            AMITypes::Custom => "CUSTOM",
+            AMITypes::BottlerocketArm64 => "BOTTLEROCKET_ARM_64",
+            AMITypes::BottlerocketX86_64 => "BOTTLEROCKET_x86_64",
+            AMITypes::BottlerocketArm64Nvidia => "BOTTLEROCKET_ARM_64_NVIDIA",
+            AMITypes::BottlerocketX86_64Nvidia => "BOTTLEROCKET_x86_64_NVIDIA",
+            AMITypes::WindowsCore2019X86_64 => "WINDOWS_CORE_2019_x86_64",
+            AMITypes::WindowsFull2019X86_64 => "WINDOWS_FULL_2019_x86_64",
+            AMITypes::WindowsCore2022X86_64 => "WINDOWS_CORE_2022_x86_64",
+            AMITypes::WindowsFull2022X86_64 => "WINDOWS_FULL_2022_x86_64",
+            AMITypes::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AMITypes {
+    fn default() -> AMITypes {
+        AMITypes::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AMITypes {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AMITypes {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AMITypes {
+    fn from(value: &'a str) -> AMITypes {
+        match value {
+            "AL2_x86_64" => AMITypes::Al2X86_64,
+            "AL2_x86_64_GPU" => AMITypes::Al2X86_64Gpu,
+            "AL2_ARM_64" => AMITypes::Al2Arm64,
+            "CUSTOM" => AMITypes::Custom,
+            "BOTTLEROCKET_ARM_64" => AMITypes::BottlerocketArm64,
+            "BOTTLEROCKET_x86_64" => AMITypes::BottlerocketX86_64,
+            "BOTTLEROCKET_ARM_64_NVIDIA" => AMITypes::BottlerocketArm64Nvidia,
+            "BOTTLEROCKET_x86_64_NVIDIA" => AMITypes::BottlerocketX86_64Nvidia,
+            "WINDOWS_CORE_2019_x86_64" => AMITypes::WindowsCore2019X86_64,
+            "WINDOWS_FULL_2019_x86_64" => AMITypes::WindowsFull2019X86_64,
+            "WINDOWS_CORE_2022_x86_64" => AMITypes::WindowsCore2022X86_64,
+            "WINDOWS_FULL_2022_x86_64" => AMITypes::WindowsFull2022X86_64,
+            _ => AMITypes::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AMITypes {
+    fn from(value: String) -> AMITypes {
+        match value.as_str() {
+            "AL2_x86_64" => AMITypes::Al2X86_64,
+            "AL2_x86_64_GPU" => AMITypes::Al2X86_64Gpu,
+            "AL2_ARM_64" => AMITypes::Al2Arm64,
+            "CUSTOM" => AMITypes::Custom,
+            "BOTTLEROCKET_ARM_64" => AMITypes::BottlerocketArm64,
+            "BOTTLEROCKET_x86_64" => AMITypes::BottlerocketX86_64,
+            "BOTTLEROCKET_ARM_64_NVIDIA" => AMITypes::BottlerocketArm64Nvidia,
+            "BOTTLEROCKET_x86_64_NVIDIA" => AMITypes::BottlerocketX86_64Nvidia,
+            "WINDOWS_CORE_2019_x86_64" => AMITypes::WindowsCore2019X86_64,
+            "WINDOWS_FULL_2019_x86_64" => AMITypes::WindowsFull2019X86_64,
+            "WINDOWS_CORE_2022_x86_64" => AMITypes::WindowsCore2022X86_64,
+            "WINDOWS_FULL_2022_x86_64" => AMITypes::WindowsFull2022X86_64,
+            _ => AMITypes::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AMITypes {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AMITypes, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AMITypes {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AMITypes {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AMITypes {
+    fn deserialize<D>(deserializer: D) -> Result<AMITypes, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An Auto Scaling group that is associated with an Amazon EKS managed node group.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -63,8 +206,13 @@ pub struct Cluster {
     pub client_request_token: Option<String>,
     /// <p>The Unix epoch timestamp in seconds for when the cluster was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The endpoint for your Kubernetes API server.</p>
     #[serde(rename = "endpoint")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -96,7 +244,7 @@ pub struct Cluster {
     /// <p>The current status of the cluster.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ClusterStatus>,
     /// <p>The metadata that you apply to the cluster to assist with categorization and organization. Each tag consists of a key and an optional value, both of which you define. Cluster tags do not propagate to any other resources associated with the cluster. </p>
     #[serde(rename = "tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -107,6 +255,119 @@ pub struct Cluster {
     pub version: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ClusterStatus {
+    /// `CREATING`
+    Creating,
+    /// `ACTIVE`
+    Active,
+    /// `DELETING`
+    Deleting,
+    /// `FAILED`
+    Failed,
+    /// `UPDATING`
+    Updating,
+    /// `PENDING`
+    Pending,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ClusterStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ClusterStatus::Creating => "CREATING",
+            ClusterStatus::Active => "ACTIVE",
+            ClusterStatus::Deleting => "DELETING",
+            ClusterStatus::Failed => "FAILED",
+            ClusterStatus::Updating => "UPDATING",
+            ClusterStatus::Pending => "PENDING",
+            ClusterStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ClusterStatus {
+    fn default() -> ClusterStatus {
+        ClusterStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ClusterStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ClusterStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ClusterStatus {
+    fn from(value: &'a str) -> ClusterStatus {
+        match value {
+            "CREATING" => ClusterStatus::Creating,
+            "ACTIVE" => ClusterStatus::Active,
+            "DELETING" => ClusterStatus::Deleting,
+            "FAILED" => ClusterStatus::Failed,
+            "UPDATING" => ClusterStatus::Updating,
+            "PENDING" => ClusterStatus::Pending,
+            _ => ClusterStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ClusterStatus {
+    fn from(value: String) -> ClusterStatus {
+        match value.as_str() {
+            "CREATING" => ClusterStatus::Creating,
+            "ACTIVE" => ClusterStatus::Active,
+            "DELETING" => ClusterStatus::Deleting,
+            "FAILED" => ClusterStatus::Failed,
+            "UPDATING" => ClusterStatus::Updating,
+            "PENDING" => ClusterStatus::Pending,
+            _ => ClusterStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ClusterStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ClusterStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ClusterStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ClusterStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ClusterStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ClusterStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateClusterRequest {
@@ -191,7 +452,7 @@ pub struct CreateNodegroupRequest {
     /// <p>The AMI type for your node group. GPU instance types should use the <code>AL2_x86_64_GPU</code> AMI type, which uses the Amazon EKS-optimized Linux AMI with GPU support. Non-GPU instances should use the <code>AL2_x86_64</code> AMI type, which uses the Amazon EKS-optimized Linux AMI.</p>
     #[serde(rename = "amiType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ami_type: Option<String>,
+    pub ami_type: Option<AMITypes>,
     /// <p>Unique, case-sensitive identifier that you provide to ensure the idempotency of the request.</p>
     #[serde(rename = "clientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -389,6 +650,174 @@ pub struct DescribeUpdateResponse {
     pub update: Option<Update>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorCode {
+    /// `SubnetNotFound`
+    SubnetNotFound,
+    /// `SecurityGroupNotFound`
+    SecurityGroupNotFound,
+    /// `EniLimitReached`
+    EniLimitReached,
+    /// `IpNotAvailable`
+    IpNotAvailable,
+    /// `AccessDenied`
+    AccessDenied,
+    /// `OperationNotPermitted`
+    OperationNotPermitted,
+    /// `VpcIdNotFound`
+    VpcIdNotFound,
+    /// `Unknown`
+    UnknownValue,
+    /// `NodeCreationFailure`
+    NodeCreationFailure,
+    /// `PodEvictionFailure`
+    PodEvictionFailure,
+    /// `InsufficientFreeAddresses`
+    InsufficientFreeAddresses,
+    /// `ClusterUnreachable`
+    ClusterUnreachable,
+    /// `InsufficientNumberOfReplicas`
+    InsufficientNumberOfReplicas,
+    /// `ConfigurationConflict`
+    ConfigurationConflict,
+    /// `AdmissionRequestDenied`
+    AdmissionRequestDenied,
+    /// `UnsupportedAddonModification`
+    UnsupportedAddonModification,
+    /// `K8sResourceNotFound`
+    K8sResourceNotFound,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ErrorCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ErrorCode::SubnetNotFound => "SubnetNotFound",
+            ErrorCode::SecurityGroupNotFound => "SecurityGroupNotFound",
+            ErrorCode::EniLimitReached => "EniLimitReached",
+            ErrorCode::IpNotAvailable => "IpNotAvailable",
+            ErrorCode::AccessDenied => "AccessDenied",
+            ErrorCode::OperationNotPermitted => "OperationNotPermitted",
+            ErrorCode::VpcIdNotFound => "VpcIdNotFound",
+            ErrorCode::UnknownValue => "Unknown",
+            ErrorCode::NodeCreationFailure => "NodeCreationFailure",
+            ErrorCode::PodEvictionFailure => "PodEvictionFailure",
+            ErrorCode::InsufficientFreeAddresses => "InsufficientFreeAddresses",
+            ErrorCode::ClusterUnreachable => "ClusterUnreachable",
+            ErrorCode::InsufficientNumberOfReplicas => "InsufficientNumberOfReplicas",
+            ErrorCode::ConfigurationConflict => "ConfigurationConflict",
+            ErrorCode::AdmissionRequestDenied => "AdmissionRequestDenied",
+            ErrorCode::UnsupportedAddonModification => "UnsupportedAddonModification",
+            ErrorCode::K8sResourceNotFound => "K8sResourceNotFound",
+            ErrorCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ErrorCode {
+    fn default() -> ErrorCode {
+        ErrorCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ErrorCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ErrorCode {
+    fn from(value: &'a str) -> ErrorCode {
+        match value {
+            "SubnetNotFound" => ErrorCode::SubnetNotFound,
+            "SecurityGroupNotFound" => ErrorCode::SecurityGroupNotFound,
+            "EniLimitReached" => ErrorCode::EniLimitReached,
+            "IpNotAvailable" => ErrorCode::IpNotAvailable,
+            "AccessDenied" => ErrorCode::AccessDenied,
+            "OperationNotPermitted" => ErrorCode::OperationNotPermitted,
+            "VpcIdNotFound" => ErrorCode::VpcIdNotFound,
+            "Unknown" => ErrorCode::UnknownValue,
+            "NodeCreationFailure" => ErrorCode::NodeCreationFailure,
+            "PodEvictionFailure" => ErrorCode::PodEvictionFailure,
+            "InsufficientFreeAddresses" => ErrorCode::InsufficientFreeAddresses,
+            "ClusterUnreachable" => ErrorCode::ClusterUnreachable,
+            "InsufficientNumberOfReplicas" => ErrorCode::InsufficientNumberOfReplicas,
+            "ConfigurationConflict" => ErrorCode::ConfigurationConflict,
+            "AdmissionRequestDenied" => ErrorCode::AdmissionRequestDenied,
+            "UnsupportedAddonModification" => ErrorCode::UnsupportedAddonModification,
+            "K8sResourceNotFound" => ErrorCode::K8sResourceNotFound,
+            _ => ErrorCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ErrorCode {
+    fn from(value: String) -> ErrorCode {
+        match value.as_str() {
+            "SubnetNotFound" => ErrorCode::SubnetNotFound,
+            "SecurityGroupNotFound" => ErrorCode::SecurityGroupNotFound,
+            "EniLimitReached" => ErrorCode::EniLimitReached,
+            "IpNotAvailable" => ErrorCode::IpNotAvailable,
+            "AccessDenied" => ErrorCode::AccessDenied,
+            "OperationNotPermitted" => ErrorCode::OperationNotPermitted,
+            "VpcIdNotFound" => ErrorCode::VpcIdNotFound,
+            "Unknown" => ErrorCode::UnknownValue,
+            "NodeCreationFailure" => ErrorCode::NodeCreationFailure,
+            "PodEvictionFailure" => ErrorCode::PodEvictionFailure,
+            "InsufficientFreeAddresses" => ErrorCode::InsufficientFreeAddresses,
+            "ClusterUnreachable" => ErrorCode::ClusterUnreachable,
+            "InsufficientNumberOfReplicas" => ErrorCode::InsufficientNumberOfReplicas,
+            "ConfigurationConflict" => ErrorCode::ConfigurationConflict,
+            "AdmissionRequestDenied" => ErrorCode::AdmissionRequestDenied,
+            "UnsupportedAddonModification" => ErrorCode::UnsupportedAddonModification,
+            "K8sResourceNotFound" => ErrorCode::K8sResourceNotFound,
+            _ => ErrorCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ErrorCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ErrorCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ErrorCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ErrorCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ErrorCode {
+    fn deserialize<D>(deserializer: D) -> Result<ErrorCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An object representing an error when an asynchronous operation fails.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -396,7 +825,7 @@ pub struct ErrorDetail {
     /// <p><p>A brief description of the error. </p> <ul> <li> <p> <b>SubnetNotFound</b>: We couldn&#39;t find one of the subnets associated with the cluster.</p> </li> <li> <p> <b>SecurityGroupNotFound</b>: We couldn&#39;t find one of the security groups associated with the cluster.</p> </li> <li> <p> <b>EniLimitReached</b>: You have reached the elastic network interface limit for your account.</p> </li> <li> <p> <b>IpNotAvailable</b>: A subnet associated with the cluster doesn&#39;t have any free IP addresses.</p> </li> <li> <p> <b>AccessDenied</b>: You don&#39;t have permissions to perform the specified operation.</p> </li> <li> <p> <b>OperationNotPermitted</b>: The service role associated with the cluster doesn&#39;t have the required access permissions for Amazon EKS.</p> </li> <li> <p> <b>VpcIdNotFound</b>: We couldn&#39;t find the VPC associated with the cluster.</p> </li> </ul></p>
     #[serde(rename = "errorCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub error_code: Option<String>,
+    pub error_code: Option<ErrorCode>,
     /// <p>A more complete description of the error.</p>
     #[serde(rename = "errorMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -417,8 +846,13 @@ pub struct FargateProfile {
     pub cluster_name: Option<String>,
     /// <p>The Unix epoch timestamp in seconds for when the Fargate profile was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The full Amazon Resource Name (ARN) of the Fargate profile.</p>
     #[serde(rename = "fargateProfileArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -438,7 +872,7 @@ pub struct FargateProfile {
     /// <p>The current status of the Fargate profile.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<FargateProfileStatus>,
     /// <p>The IDs of subnets to launch pods into.</p>
     #[serde(rename = "subnets")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -462,6 +896,114 @@ pub struct FargateProfileSelector {
     pub namespace: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FargateProfileStatus {
+    /// `CREATING`
+    Creating,
+    /// `ACTIVE`
+    Active,
+    /// `DELETING`
+    Deleting,
+    /// `CREATE_FAILED`
+    CreateFailed,
+    /// `DELETE_FAILED`
+    DeleteFailed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl FargateProfileStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FargateProfileStatus::Creating => "CREATING",
+            FargateProfileStatus::Active => "ACTIVE",
+            FargateProfileStatus::Deleting => "DELETING",
+            FargateProfileStatus::CreateFailed => "CREATE_FAILED",
+            FargateProfileStatus::DeleteFailed => "DELETE_FAILED",
+            FargateProfileStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for FargateProfileStatus {
+    fn default() -> FargateProfileStatus {
+        FargateProfileStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for FargateProfileStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FargateProfileStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FargateProfileStatus {
+    fn from(value: &'a str) -> FargateProfileStatus {
+        match value {
+            "CREATING" => FargateProfileStatus::Creating,
+            "ACTIVE" => FargateProfileStatus::Active,
+            "DELETING" => FargateProfileStatus::Deleting,
+            "CREATE_FAILED" => FargateProfileStatus::CreateFailed,
+            "DELETE_FAILED" => FargateProfileStatus::DeleteFailed,
+            _ => FargateProfileStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FargateProfileStatus {
+    fn from(value: String) -> FargateProfileStatus {
+        match value.as_str() {
+            "CREATING" => FargateProfileStatus::Creating,
+            "ACTIVE" => FargateProfileStatus::Active,
+            "DELETING" => FargateProfileStatus::Deleting,
+            "CREATE_FAILED" => FargateProfileStatus::CreateFailed,
+            "DELETE_FAILED" => FargateProfileStatus::DeleteFailed,
+            _ => FargateProfileStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for FargateProfileStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<FargateProfileStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FargateProfileStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FargateProfileStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FargateProfileStatus {
+    fn deserialize<D>(deserializer: D) -> Result<FargateProfileStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An object representing an identity provider for authentication credentials.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -479,7 +1021,7 @@ pub struct Issue {
     /// <p><p>A brief description of the error.</p> <ul> <li> <p> <b>AutoScalingGroupNotFound</b>: We couldn&#39;t find the Auto Scaling group associated with the managed node group. You may be able to recreate an Auto Scaling group with the same settings to recover.</p> </li> <li> <p> <b>Ec2SecurityGroupNotFound</b>: We couldn&#39;t find the cluster security group for the cluster. You must recreate your cluster.</p> </li> <li> <p> <b>Ec2SecurityGroupDeletionFailure</b>: We could not delete the remote access security group for your managed node group. Remove any dependencies from the security group.</p> </li> <li> <p> <b>Ec2LaunchTemplateNotFound</b>: We couldn&#39;t find the Amazon EC2 launch template for your managed node group. You may be able to recreate a launch template with the same settings to recover.</p> </li> <li> <p> <b>Ec2LaunchTemplateVersionMismatch</b>: The Amazon EC2 launch template version for your managed node group does not match the version that Amazon EKS created. You may be able to revert to the version that Amazon EKS created to recover.</p> </li> <li> <p> <b>IamInstanceProfileNotFound</b>: We couldn&#39;t find the IAM instance profile for your managed node group. You may be able to recreate an instance profile with the same settings to recover.</p> </li> <li> <p> <b>IamNodeRoleNotFound</b>: We couldn&#39;t find the IAM role for your managed node group. You may be able to recreate an IAM role with the same settings to recover.</p> </li> <li> <p> <b>AsgInstanceLaunchFailures</b>: Your Auto Scaling group is experiencing failures while attempting to launch instances.</p> </li> <li> <p> <b>NodeCreationFailure</b>: Your launched instances are unable to register with your Amazon EKS cluster. Common causes of this failure are insufficient <a href="https://docs.aws.amazon.com/eks/latest/userguide/worker_node_IAM_role.html">worker node IAM role</a> permissions or lack of outbound internet access for the nodes. </p> </li> <li> <p> <b>InstanceLimitExceeded</b>: Your AWS account is unable to launch any more instances of the specified instance type. You may be able to request an Amazon EC2 instance limit increase to recover.</p> </li> <li> <p> <b>InsufficientFreeAddresses</b>: One or more of the subnets associated with your managed node group does not have enough available IP addresses for new nodes.</p> </li> <li> <p> <b>AccessDenied</b>: Amazon EKS or one or more of your managed nodes is unable to communicate with your cluster API server.</p> </li> <li> <p> <b>InternalFailure</b>: These errors are usually caused by an Amazon EKS server-side issue.</p> </li> </ul></p>
     #[serde(rename = "code")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub code: Option<String>,
+    pub code: Option<NodegroupIssueCode>,
     /// <p>The error message associated with the issue.</p>
     #[serde(rename = "message")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -634,9 +1176,117 @@ pub struct LogSetup {
     /// <p>The available cluster control plane log types.</p>
     #[serde(rename = "types")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub types: Option<Vec<String>>,
+    pub types: Option<Vec<LogType>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LogType {
+    /// `api`
+    Api,
+    /// `audit`
+    Audit,
+    /// `authenticator`
+    Authenticator,
+    /// `controllerManager`
+    ControllerManager,
+    /// `scheduler`
+    Scheduler,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LogType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LogType::Api => "api",
+            LogType::Audit => "audit",
+            LogType::Authenticator => "authenticator",
+            LogType::ControllerManager => "controllerManager",
+            LogType::Scheduler => "scheduler",
+            LogType::Unknown(ref value) => value,
+        }
+    }
 }
 
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LogType {
+    fn default() -> LogType {
+        LogType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LogType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LogType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LogType {
+    fn from(value: &'a str) -> LogType {
+        match value {
+            "api" => LogType::Api,
+            "audit" => LogType::Audit,
+            "authenticator" => LogType::Authenticator,
+            "controllerManager" => LogType::ControllerManager,
+            "scheduler" => LogType::Scheduler,
+            _ => LogType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LogType {
+    fn from(value: String) -> LogType {
+        match value.as_str() {
+            "api" => LogType::Api,
+            "audit" => LogType::Audit,
+            "authenticator" => LogType::Authenticator,
+            "controllerManager" => LogType::ControllerManager,
+            "scheduler" => LogType::Scheduler,
+            _ => LogType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LogType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LogType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LogType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LogType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LogType {
+    fn deserialize<D>(deserializer: D) -> Result<LogType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An object representing the logging configuration for resources in your cluster.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Logging {
@@ -653,15 +1303,20 @@ pub struct Nodegroup {
     /// <p>The AMI type associated with your node group. GPU instance types should use the <code>AL2_x86_64_GPU</code> AMI type, which uses the Amazon EKS-optimized Linux AMI with GPU support. Non-GPU instances should use the <code>AL2_x86_64</code> AMI type, which uses the Amazon EKS-optimized Linux AMI.</p>
     #[serde(rename = "amiType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ami_type: Option<String>,
+    pub ami_type: Option<AMITypes>,
     /// <p>The name of the cluster that the managed node group resides in.</p>
     #[serde(rename = "clusterName")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cluster_name: Option<String>,
     /// <p>The Unix epoch timestamp in seconds for when the managed node group was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The root device disk size (in GiB) for your node group instances. The default disk size is 20 GiB.</p>
     #[serde(rename = "diskSize")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -680,8 +1335,13 @@ pub struct Nodegroup {
     pub labels: Option<::std::collections::HashMap<String, String>>,
     /// <p>The Unix epoch timestamp in seconds for when the managed node group was last modified.</p>
     #[serde(rename = "modifiedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub modified_at: Option<f64>,
+    pub modified_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The IAM role associated with your node group. The Amazon EKS worker node <code>kubelet</code> daemon makes calls to AWS APIs on your behalf. Worker nodes receive permissions for these API calls through an IAM instance profile and associated policies. Before you can launch worker nodes and register them into a cluster, you must create an IAM role for those worker nodes to use when they are launched. For more information, see <a href="https://docs.aws.amazon.com/eks/latest/userguide/worker_node_IAM_role.html">Amazon EKS Worker Node IAM Role</a> in the <i> <i>Amazon EKS User Guide</i> </i>.</p>
     #[serde(rename = "nodeRole")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -713,7 +1373,7 @@ pub struct Nodegroup {
     /// <p>The current status of the managed node group.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<NodegroupStatus>,
     /// <p>The subnets allowed for the Auto Scaling group that is associated with your node group. These subnets must have the following tag: <code>kubernetes.io/cluster/CLUSTER_NAME</code>, where <code>CLUSTER_NAME</code> is replaced with the name of your cluster.</p>
     #[serde(rename = "subnets")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -737,7 +1397,309 @@ pub struct NodegroupHealth {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub issues: Option<Vec<Issue>>,
 }
-
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum NodegroupIssueCode {
+    /// `AutoScalingGroupNotFound`
+    AutoScalingGroupNotFound,
+    /// `AutoScalingGroupInvalidConfiguration`
+    AutoScalingGroupInvalidConfiguration,
+    /// `Ec2SecurityGroupNotFound`
+    Ec2SecurityGroupNotFound,
+    /// `Ec2SecurityGroupDeletionFailure`
+    Ec2SecurityGroupDeletionFailure,
+    /// `Ec2LaunchTemplateNotFound`
+    Ec2LaunchTemplateNotFound,
+    /// `Ec2LaunchTemplateVersionMismatch`
+    Ec2LaunchTemplateVersionMismatch,
+    /// `Ec2SubnetNotFound`
+    Ec2SubnetNotFound,
+    /// `Ec2SubnetInvalidConfiguration`
+    Ec2SubnetInvalidConfiguration,
+    /// `IamInstanceProfileNotFound`
+    IamInstanceProfileNotFound,
+    /// `Ec2SubnetMissingIpv6Assignment`
+    Ec2SubnetMissingIpv6Assignment,
+    /// `IamLimitExceeded`
+    IamLimitExceeded,
+    /// `IamNodeRoleNotFound`
+    IamNodeRoleNotFound,
+    /// `NodeCreationFailure`
+    NodeCreationFailure,
+    /// `AsgInstanceLaunchFailures`
+    AsgInstanceLaunchFailures,
+    /// `InstanceLimitExceeded`
+    InstanceLimitExceeded,
+    /// `InsufficientFreeAddresses`
+    InsufficientFreeAddresses,
+    /// `AccessDenied`
+    AccessDenied,
+    /// `InternalFailure`
+    InternalFailure,
+    /// `ClusterUnreachable`
+    ClusterUnreachable,
+    /// `AmiIdNotFound`
+    AmiIdNotFound,
+    /// `AutoScalingGroupOptInRequired`
+    AutoScalingGroupOptInRequired,
+    /// `AutoScalingGroupRateLimitExceeded`
+    AutoScalingGroupRateLimitExceeded,
+    /// `Ec2LaunchTemplateDeletionFailure`
+    Ec2LaunchTemplateDeletionFailure,
+    /// `Ec2LaunchTemplateInvalidConfiguration`
+    Ec2LaunchTemplateInvalidConfiguration,
+    /// `Ec2LaunchTemplateMaxLimitExceeded`
+    Ec2LaunchTemplateMaxLimitExceeded,
+    /// `Ec2SubnetListTooLong`
+    Ec2SubnetListTooLong,
+    /// `IamThrottling`
+    IamThrottling,
+    /// `NodeTerminationFailure`
+    NodeTerminationFailure,
+    /// `PodEvictionFailure`
+    PodEvictionFailure,
+    /// `SourceEc2LaunchTemplateNotFound`
+    SourceEc2LaunchTemplateNotFound,
+    /// `LimitExceeded`
+    LimitExceeded,
+    /// `Unknown`
+    UnknownValue,
+    /// `AutoScalingGroupInstanceRefreshActive`
+    AutoScalingGroupInstanceRefreshActive,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl NodegroupIssueCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            NodegroupIssueCode::AutoScalingGroupNotFound => "AutoScalingGroupNotFound",
+            NodegroupIssueCode::AutoScalingGroupInvalidConfiguration => {
+                "AutoScalingGroupInvalidConfiguration"
+            }
+            NodegroupIssueCode::Ec2SecurityGroupNotFound => "Ec2SecurityGroupNotFound",
+            NodegroupIssueCode::Ec2SecurityGroupDeletionFailure => {
+                "Ec2SecurityGroupDeletionFailure"
+            }
+            NodegroupIssueCode::Ec2LaunchTemplateNotFound => "Ec2LaunchTemplateNotFound",
+            NodegroupIssueCode::Ec2LaunchTemplateVersionMismatch => {
+                "Ec2LaunchTemplateVersionMismatch"
+            }
+            NodegroupIssueCode::Ec2SubnetNotFound => "Ec2SubnetNotFound",
+            NodegroupIssueCode::Ec2SubnetInvalidConfiguration => "Ec2SubnetInvalidConfiguration",
+            NodegroupIssueCode::IamInstanceProfileNotFound => "IamInstanceProfileNotFound",
+            NodegroupIssueCode::Ec2SubnetMissingIpv6Assignment => "Ec2SubnetMissingIpv6Assignment",
+            NodegroupIssueCode::IamLimitExceeded => "IamLimitExceeded",
+            NodegroupIssueCode::IamNodeRoleNotFound => "IamNodeRoleNotFound",
+            NodegroupIssueCode::NodeCreationFailure => "NodeCreationFailure",
+            NodegroupIssueCode::AsgInstanceLaunchFailures => "AsgInstanceLaunchFailures",
+            NodegroupIssueCode::InstanceLimitExceeded => "InstanceLimitExceeded",
+            NodegroupIssueCode::InsufficientFreeAddresses => "InsufficientFreeAddresses",
+            NodegroupIssueCode::AccessDenied => "AccessDenied",
+            NodegroupIssueCode::InternalFailure => "InternalFailure",
+            NodegroupIssueCode::ClusterUnreachable => "ClusterUnreachable",
+            NodegroupIssueCode::AmiIdNotFound => "AmiIdNotFound",
+            NodegroupIssueCode::AutoScalingGroupOptInRequired => "AutoScalingGroupOptInRequired",
+            NodegroupIssueCode::AutoScalingGroupRateLimitExceeded => {
+                "AutoScalingGroupRateLimitExceeded"
+            }
+            NodegroupIssueCode::Ec2LaunchTemplateDeletionFailure => {
+                "Ec2LaunchTemplateDeletionFailure"
+            }
+            NodegroupIssueCode::Ec2LaunchTemplateInvalidConfiguration => {
+                "Ec2LaunchTemplateInvalidConfiguration"
+            }
+            NodegroupIssueCode::Ec2LaunchTemplateMaxLimitExceeded => {
+                "Ec2LaunchTemplateMaxLimitExceeded"
+            }
+            NodegroupIssueCode::Ec2SubnetListTooLong => "Ec2SubnetListTooLong",
+            NodegroupIssueCode::IamThrottling => "IamThrottling",
+            NodegroupIssueCode::NodeTerminationFailure => "NodeTerminationFailure",
+            NodegroupIssueCode::PodEvictionFailure => "PodEvictionFailure",
+            NodegroupIssueCode::SourceEc2LaunchTemplateNotFound => {
+                "SourceEc2LaunchTemplateNotFound"
+            }
+            NodegroupIssueCode::LimitExceeded => "LimitExceeded",
+            NodegroupIssueCode::UnknownValue => "Unknown",
+            NodegroupIssueCode::AutoScalingGroupInstanceRefreshActive => {
+                "AutoScalingGroupInstanceRefreshActive"
+            }
+            NodegroupIssueCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for NodegroupIssueCode {
+    fn default() -> NodegroupIssueCode {
+        NodegroupIssueCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for NodegroupIssueCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for NodegroupIssueCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for NodegroupIssueCode {
+    fn from(value: &'a str) -> NodegroupIssueCode {
+        match value {
+            "AutoScalingGroupNotFound" => NodegroupIssueCode::AutoScalingGroupNotFound,
+            "AutoScalingGroupInvalidConfiguration" => {
+                NodegroupIssueCode::AutoScalingGroupInvalidConfiguration
+            }
+            "Ec2SecurityGroupNotFound" => NodegroupIssueCode::Ec2SecurityGroupNotFound,
+            "Ec2SecurityGroupDeletionFailure" => {
+                NodegroupIssueCode::Ec2SecurityGroupDeletionFailure
+            }
+            "Ec2LaunchTemplateNotFound" => NodegroupIssueCode::Ec2LaunchTemplateNotFound,
+            "Ec2LaunchTemplateVersionMismatch" => {
+                NodegroupIssueCode::Ec2LaunchTemplateVersionMismatch
+            }
+            "Ec2SubnetNotFound" => NodegroupIssueCode::Ec2SubnetNotFound,
+            "Ec2SubnetInvalidConfiguration" => NodegroupIssueCode::Ec2SubnetInvalidConfiguration,
+            "IamInstanceProfileNotFound" => NodegroupIssueCode::IamInstanceProfileNotFound,
+            "Ec2SubnetMissingIpv6Assignment" => NodegroupIssueCode::Ec2SubnetMissingIpv6Assignment,
+            "IamLimitExceeded" => NodegroupIssueCode::IamLimitExceeded,
+            "IamNodeRoleNotFound" => NodegroupIssueCode::IamNodeRoleNotFound,
+            "NodeCreationFailure" => NodegroupIssueCode::NodeCreationFailure,
+            "AsgInstanceLaunchFailures" => NodegroupIssueCode::AsgInstanceLaunchFailures,
+            "InstanceLimitExceeded" => NodegroupIssueCode::InstanceLimitExceeded,
+            "InsufficientFreeAddresses" => NodegroupIssueCode::InsufficientFreeAddresses,
+            "AccessDenied" => NodegroupIssueCode::AccessDenied,
+            "InternalFailure" => NodegroupIssueCode::InternalFailure,
+            "ClusterUnreachable" => NodegroupIssueCode::ClusterUnreachable,
+            "AmiIdNotFound" => NodegroupIssueCode::AmiIdNotFound,
+            "AutoScalingGroupOptInRequired" => NodegroupIssueCode::AutoScalingGroupOptInRequired,
+            "AutoScalingGroupRateLimitExceeded" => {
+                NodegroupIssueCode::AutoScalingGroupRateLimitExceeded
+            }
+            "Ec2LaunchTemplateDeletionFailure" => {
+                NodegroupIssueCode::Ec2LaunchTemplateDeletionFailure
+            }
+            "Ec2LaunchTemplateInvalidConfiguration" => {
+                NodegroupIssueCode::Ec2LaunchTemplateInvalidConfiguration
+            }
+            "Ec2LaunchTemplateMaxLimitExceeded" => {
+                NodegroupIssueCode::Ec2LaunchTemplateMaxLimitExceeded
+            }
+            "Ec2SubnetListTooLong" => NodegroupIssueCode::Ec2SubnetListTooLong,
+            "IamThrottling" => NodegroupIssueCode::IamThrottling,
+            "NodeTerminationFailure" => NodegroupIssueCode::NodeTerminationFailure,
+            "PodEvictionFailure" => NodegroupIssueCode::PodEvictionFailure,
+            "SourceEc2LaunchTemplateNotFound" => {
+                NodegroupIssueCode::SourceEc2LaunchTemplateNotFound
+            }
+            "LimitExceeded" => NodegroupIssueCode::LimitExceeded,
+            "Unknown" => NodegroupIssueCode::UnknownValue,
+            "AutoScalingGroupInstanceRefreshActive" => {
+                NodegroupIssueCode::AutoScalingGroupInstanceRefreshActive
+            }
+            _ => NodegroupIssueCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for NodegroupIssueCode {
+    fn from(value: String) -> NodegroupIssueCode {
+        match value.as_str() {
+            "AutoScalingGroupNotFound" => NodegroupIssueCode::AutoScalingGroupNotFound,
+            "AutoScalingGroupInvalidConfiguration" => {
+                NodegroupIssueCode::AutoScalingGroupInvalidConfiguration
+            }
+            "Ec2SecurityGroupNotFound" => NodegroupIssueCode::Ec2SecurityGroupNotFound,
+            "Ec2SecurityGroupDeletionFailure" => {
+                NodegroupIssueCode::Ec2SecurityGroupDeletionFailure
+            }
+            "Ec2LaunchTemplateNotFound" => NodegroupIssueCode::Ec2LaunchTemplateNotFound,
+            "Ec2LaunchTemplateVersionMismatch" => {
+                NodegroupIssueCode::Ec2LaunchTemplateVersionMismatch
+            }
+            "Ec2SubnetNotFound" => NodegroupIssueCode::Ec2SubnetNotFound,
+            "Ec2SubnetInvalidConfiguration" => NodegroupIssueCode::Ec2SubnetInvalidConfiguration,
+            "IamInstanceProfileNotFound" => NodegroupIssueCode::IamInstanceProfileNotFound,
+            "Ec2SubnetMissingIpv6Assignment" => NodegroupIssueCode::Ec2SubnetMissingIpv6Assignment,
+            "IamLimitExceeded" => NodegroupIssueCode::IamLimitExceeded,
+            "IamNodeRoleNotFound" => NodegroupIssueCode::IamNodeRoleNotFound,
+            "NodeCreationFailure" => NodegroupIssueCode::NodeCreationFailure,
+            "AsgInstanceLaunchFailures" => NodegroupIssueCode::AsgInstanceLaunchFailures,
+            "InstanceLimitExceeded" => NodegroupIssueCode::InstanceLimitExceeded,
+            "InsufficientFreeAddresses" => NodegroupIssueCode::InsufficientFreeAddresses,
+            "AccessDenied" => NodegroupIssueCode::AccessDenied,
+            "InternalFailure" => NodegroupIssueCode::InternalFailure,
+            "ClusterUnreachable" => NodegroupIssueCode::ClusterUnreachable,
+            "AmiIdNotFound" => NodegroupIssueCode::AmiIdNotFound,
+            "AutoScalingGroupOptInRequired" => NodegroupIssueCode::AutoScalingGroupOptInRequired,
+            "AutoScalingGroupRateLimitExceeded" => {
+                NodegroupIssueCode::AutoScalingGroupRateLimitExceeded
+            }
+            "Ec2LaunchTemplateDeletionFailure" => {
+                NodegroupIssueCode::Ec2LaunchTemplateDeletionFailure
+            }
+            "Ec2LaunchTemplateInvalidConfiguration" => {
+                NodegroupIssueCode::Ec2LaunchTemplateInvalidConfiguration
+            }
+            "Ec2LaunchTemplateMaxLimitExceeded" => {
+                NodegroupIssueCode::Ec2LaunchTemplateMaxLimitExceeded
+            }
+            "Ec2SubnetListTooLong" => NodegroupIssueCode::Ec2SubnetListTooLong,
+            "IamThrottling" => NodegroupIssueCode::IamThrottling,
+            "NodeTerminationFailure" => NodegroupIssueCode::NodeTerminationFailure,
+            "PodEvictionFailure" => NodegroupIssueCode::PodEvictionFailure,
+            "SourceEc2LaunchTemplateNotFound" => {
+                NodegroupIssueCode::SourceEc2LaunchTemplateNotFound
+            }
+            "LimitExceeded" => NodegroupIssueCode::LimitExceeded,
+            "Unknown" => NodegroupIssueCode::UnknownValue,
+            "AutoScalingGroupInstanceRefreshActive" => {
+                NodegroupIssueCode::AutoScalingGroupInstanceRefreshActive
+            }
+            _ => NodegroupIssueCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for NodegroupIssueCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<NodegroupIssueCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for NodegroupIssueCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for NodegroupIssueCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for NodegroupIssueCode {
+    fn deserialize<D>(deserializer: D) -> Result<NodegroupIssueCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An object representing the resources associated with the node group, such as Auto Scaling groups and security groups for remote access.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -769,6 +1731,124 @@ pub struct NodegroupScalingConfig {
     pub min_size: Option<i64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum NodegroupStatus {
+    /// `CREATING`
+    Creating,
+    /// `ACTIVE`
+    Active,
+    /// `UPDATING`
+    Updating,
+    /// `DELETING`
+    Deleting,
+    /// `CREATE_FAILED`
+    CreateFailed,
+    /// `DELETE_FAILED`
+    DeleteFailed,
+    /// `DEGRADED`
+    Degraded,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl NodegroupStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            NodegroupStatus::Creating => "CREATING",
+            NodegroupStatus::Active => "ACTIVE",
+            NodegroupStatus::Updating => "UPDATING",
+            NodegroupStatus::Deleting => "DELETING",
+            NodegroupStatus::CreateFailed => "CREATE_FAILED",
+            NodegroupStatus::DeleteFailed => "DELETE_FAILED",
+            NodegroupStatus::Degraded => "DEGRADED",
+            NodegroupStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for NodegroupStatus {
+    fn default() -> NodegroupStatus {
+        NodegroupStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for NodegroupStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for NodegroupStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for NodegroupStatus {
+    fn from(value: &'a str) -> NodegroupStatus {
+        match value {
+            "CREATING" => NodegroupStatus::Creating,
+            "ACTIVE" => NodegroupStatus::Active,
+            "UPDATING" => NodegroupStatus::Updating,
+            "DELETING" => NodegroupStatus::Deleting,
+            "CREATE_FAILED" => NodegroupStatus::CreateFailed,
+            "DELETE_FAILED" => NodegroupStatus::DeleteFailed,
+            "DEGRADED" => NodegroupStatus::Degraded,
+            _ => NodegroupStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for NodegroupStatus {
+    fn from(value: String) -> NodegroupStatus {
+        match value.as_str() {
+            "CREATING" => NodegroupStatus::Creating,
+            "ACTIVE" => NodegroupStatus::Active,
+            "UPDATING" => NodegroupStatus::Updating,
+            "DELETING" => NodegroupStatus::Deleting,
+            "CREATE_FAILED" => NodegroupStatus::CreateFailed,
+            "DELETE_FAILED" => NodegroupStatus::DeleteFailed,
+            "DEGRADED" => NodegroupStatus::Degraded,
+            _ => NodegroupStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for NodegroupStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<NodegroupStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for NodegroupStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for NodegroupStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for NodegroupStatus {
+    fn deserialize<D>(deserializer: D) -> Result<NodegroupStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An object representing the <a href="https://openid.net/connect/">OpenID Connect</a> identity provider information for the cluster.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -828,8 +1908,13 @@ pub struct UntagResourceResponse {}
 pub struct Update {
     /// <p>The Unix epoch timestamp in seconds for when the update was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Any errors associated with a <code>Failed</code> update.</p>
     #[serde(rename = "errors")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -845,11 +1930,11 @@ pub struct Update {
     /// <p>The current status of the update.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<UpdateStatus>,
     /// <p>The type of the update.</p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<UpdateType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -990,13 +2075,467 @@ pub struct UpdateParam {
     /// <p>The keys associated with an update request.</p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<UpdateParamType>,
     /// <p>The value of the keys submitted as part of an update request.</p>
     #[serde(rename = "value")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum UpdateParamType {
+    /// `Version`
+    Version,
+    /// `PlatformVersion`
+    PlatformVersion,
+    /// `EndpointPrivateAccess`
+    EndpointPrivateAccess,
+    /// `EndpointPublicAccess`
+    EndpointPublicAccess,
+    /// `ClusterLogging`
+    ClusterLogging,
+    /// `DesiredSize`
+    DesiredSize,
+    /// `LabelsToAdd`
+    LabelsToAdd,
+    /// `LabelsToRemove`
+    LabelsToRemove,
+    /// `TaintsToAdd`
+    TaintsToAdd,
+    /// `TaintsToRemove`
+    TaintsToRemove,
+    /// `MaxSize`
+    MaxSize,
+    /// `MinSize`
+    MinSize,
+    /// `ReleaseVersion`
+    ReleaseVersion,
+    /// `PublicAccessCidrs`
+    PublicAccessCidrs,
+    /// `LaunchTemplateName`
+    LaunchTemplateName,
+    /// `LaunchTemplateVersion`
+    LaunchTemplateVersion,
+    /// `IdentityProviderConfig`
+    IdentityProviderConfig,
+    /// `EncryptionConfig`
+    EncryptionConfig,
+    /// `AddonVersion`
+    AddonVersion,
+    /// `ServiceAccountRoleArn`
+    ServiceAccountRoleArn,
+    /// `ResolveConflicts`
+    ResolveConflicts,
+    /// `MaxUnavailable`
+    MaxUnavailable,
+    /// `MaxUnavailablePercentage`
+    MaxUnavailablePercentage,
+    /// `ConfigurationValues`
+    ConfigurationValues,
+    /// `SecurityGroups`
+    SecurityGroups,
+    /// `Subnets`
+    Subnets,
+    /// `AuthenticationMode`
+    AuthenticationMode,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl UpdateParamType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            UpdateParamType::Version => "Version",
+            UpdateParamType::PlatformVersion => "PlatformVersion",
+            UpdateParamType::EndpointPrivateAccess => "EndpointPrivateAccess",
+            UpdateParamType::EndpointPublicAccess => "EndpointPublicAccess",
+            UpdateParamType::ClusterLogging => "ClusterLogging",
+            UpdateParamType::DesiredSize => "DesiredSize",
+            UpdateParamType::LabelsToAdd => "LabelsToAdd",
+            UpdateParamType::LabelsToRemove => "LabelsToRemove",
+            UpdateParamType::TaintsToAdd => "TaintsToAdd",
+            UpdateParamType::TaintsToRemove => "TaintsToRemove",
+            UpdateParamType::MaxSize => "MaxSize",
+            UpdateParamType::MinSize => "MinSize",
+            UpdateParamType::ReleaseVersion => "ReleaseVersion",
+            UpdateParamType::PublicAccessCidrs => "PublicAccessCidrs",
+            UpdateParamType::LaunchTemplateName => "LaunchTemplateName",
+            UpdateParamType::LaunchTemplateVersion => "LaunchTemplateVersion",
+            UpdateParamType::IdentityProviderConfig => "IdentityProviderConfig",
+            UpdateParamType::EncryptionConfig => "EncryptionConfig",
+            UpdateParamType::AddonVersion => "AddonVersion",
+            UpdateParamType::ServiceAccountRoleArn => "ServiceAccountRoleArn",
+            UpdateParamType::ResolveConflicts => "ResolveConflicts",
+            UpdateParamType::MaxUnavailable => "MaxUnavailable",
+            UpdateParamType::MaxUnavailablePercentage => "MaxUnavailablePercentage",
+            UpdateParamType::ConfigurationValues => "ConfigurationValues",
+            UpdateParamType::SecurityGroups => "SecurityGroups",
+            UpdateParamType::Subnets => "Subnets",
+            UpdateParamType::AuthenticationMode => "AuthenticationMode",
+            UpdateParamType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for UpdateParamType {
+    fn default() -> UpdateParamType {
+        UpdateParamType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for UpdateParamType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for UpdateParamType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for UpdateParamType {
+    fn from(value: &'a str) -> UpdateParamType {
+        match value {
+            "Version" => UpdateParamType::Version,
+            "PlatformVersion" => UpdateParamType::PlatformVersion,
+            "EndpointPrivateAccess" => UpdateParamType::EndpointPrivateAccess,
+            "EndpointPublicAccess" => UpdateParamType::EndpointPublicAccess,
+            "ClusterLogging" => UpdateParamType::ClusterLogging,
+            "DesiredSize" => UpdateParamType::DesiredSize,
+            "LabelsToAdd" => UpdateParamType::LabelsToAdd,
+            "LabelsToRemove" => UpdateParamType::LabelsToRemove,
+            "TaintsToAdd" => UpdateParamType::TaintsToAdd,
+            "TaintsToRemove" => UpdateParamType::TaintsToRemove,
+            "MaxSize" => UpdateParamType::MaxSize,
+            "MinSize" => UpdateParamType::MinSize,
+            "ReleaseVersion" => UpdateParamType::ReleaseVersion,
+            "PublicAccessCidrs" => UpdateParamType::PublicAccessCidrs,
+            "LaunchTemplateName" => UpdateParamType::LaunchTemplateName,
+            "LaunchTemplateVersion" => UpdateParamType::LaunchTemplateVersion,
+            "IdentityProviderConfig" => UpdateParamType::IdentityProviderConfig,
+            "EncryptionConfig" => UpdateParamType::EncryptionConfig,
+            "AddonVersion" => UpdateParamType::AddonVersion,
+            "ServiceAccountRoleArn" => UpdateParamType::ServiceAccountRoleArn,
+            "ResolveConflicts" => UpdateParamType::ResolveConflicts,
+            "MaxUnavailable" => UpdateParamType::MaxUnavailable,
+            "MaxUnavailablePercentage" => UpdateParamType::MaxUnavailablePercentage,
+            "ConfigurationValues" => UpdateParamType::ConfigurationValues,
+            "SecurityGroups" => UpdateParamType::SecurityGroups,
+            "Subnets" => UpdateParamType::Subnets,
+            "AuthenticationMode" => UpdateParamType::AuthenticationMode,
+            _ => UpdateParamType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for UpdateParamType {
+    fn from(value: String) -> UpdateParamType {
+        match value.as_str() {
+            "Version" => UpdateParamType::Version,
+            "PlatformVersion" => UpdateParamType::PlatformVersion,
+            "EndpointPrivateAccess" => UpdateParamType::EndpointPrivateAccess,
+            "EndpointPublicAccess" => UpdateParamType::EndpointPublicAccess,
+            "ClusterLogging" => UpdateParamType::ClusterLogging,
+            "DesiredSize" => UpdateParamType::DesiredSize,
+            "LabelsToAdd" => UpdateParamType::LabelsToAdd,
+            "LabelsToRemove" => UpdateParamType::LabelsToRemove,
+            "TaintsToAdd" => UpdateParamType::TaintsToAdd,
+            "TaintsToRemove" => UpdateParamType::TaintsToRemove,
+            "MaxSize" => UpdateParamType::MaxSize,
+            "MinSize" => UpdateParamType::MinSize,
+            "ReleaseVersion" => UpdateParamType::ReleaseVersion,
+            "PublicAccessCidrs" => UpdateParamType::PublicAccessCidrs,
+            "LaunchTemplateName" => UpdateParamType::LaunchTemplateName,
+            "LaunchTemplateVersion" => UpdateParamType::LaunchTemplateVersion,
+            "IdentityProviderConfig" => UpdateParamType::IdentityProviderConfig,
+            "EncryptionConfig" => UpdateParamType::EncryptionConfig,
+            "AddonVersion" => UpdateParamType::AddonVersion,
+            "ServiceAccountRoleArn" => UpdateParamType::ServiceAccountRoleArn,
+            "ResolveConflicts" => UpdateParamType::ResolveConflicts,
+            "MaxUnavailable" => UpdateParamType::MaxUnavailable,
+            "MaxUnavailablePercentage" => UpdateParamType::MaxUnavailablePercentage,
+            "ConfigurationValues" => UpdateParamType::ConfigurationValues,
+            "SecurityGroups" => UpdateParamType::SecurityGroups,
+            "Subnets" => UpdateParamType::Subnets,
+            "AuthenticationMode" => UpdateParamType::AuthenticationMode,
+            _ => UpdateParamType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for UpdateParamType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<UpdateParamType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for UpdateParamType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for UpdateParamType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for UpdateParamType {
+    fn deserialize<D>(deserializer: D) -> Result<UpdateParamType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum UpdateStatus {
+    /// `InProgress`
+    InProgress,
+    /// `Failed`
+    Failed,
+    /// `Cancelled`
+    Cancelled,
+    /// `Successful`
+    Successful,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl UpdateStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            UpdateStatus::InProgress => "InProgress",
+            UpdateStatus::Failed => "Failed",
+            UpdateStatus::Cancelled => "Cancelled",
+            UpdateStatus::Successful => "Successful",
+            UpdateStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for UpdateStatus {
+    fn default() -> UpdateStatus {
+        UpdateStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for UpdateStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for UpdateStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for UpdateStatus {
+    fn from(value: &'a str) -> UpdateStatus {
+        match value {
+            "InProgress" => UpdateStatus::InProgress,
+            "Failed" => UpdateStatus::Failed,
+            "Cancelled" => UpdateStatus::Cancelled,
+            "Successful" => UpdateStatus::Successful,
+            _ => UpdateStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for UpdateStatus {
+    fn from(value: String) -> UpdateStatus {
+        match value.as_str() {
+            "InProgress" => UpdateStatus::InProgress,
+            "Failed" => UpdateStatus::Failed,
+            "Cancelled" => UpdateStatus::Cancelled,
+            "Successful" => UpdateStatus::Successful,
+            _ => UpdateStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for UpdateStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<UpdateStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for UpdateStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for UpdateStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for UpdateStatus {
+    fn deserialize<D>(deserializer: D) -> Result<UpdateStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum UpdateType {
+    /// `VersionUpdate`
+    VersionUpdate,
+    /// `EndpointAccessUpdate`
+    EndpointAccessUpdate,
+    /// `LoggingUpdate`
+    LoggingUpdate,
+    /// `ConfigUpdate`
+    ConfigUpdate,
+    /// `AssociateIdentityProviderConfig`
+    AssociateIdentityProviderConfig,
+    /// `DisassociateIdentityProviderConfig`
+    DisassociateIdentityProviderConfig,
+    /// `AssociateEncryptionConfig`
+    AssociateEncryptionConfig,
+    /// `AddonUpdate`
+    AddonUpdate,
+    /// `VpcConfigUpdate`
+    VpcConfigUpdate,
+    /// `AccessConfigUpdate`
+    AccessConfigUpdate,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl UpdateType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            UpdateType::VersionUpdate => "VersionUpdate",
+            UpdateType::EndpointAccessUpdate => "EndpointAccessUpdate",
+            UpdateType::LoggingUpdate => "LoggingUpdate",
+            UpdateType::ConfigUpdate => "ConfigUpdate",
+            UpdateType::AssociateIdentityProviderConfig => "AssociateIdentityProviderConfig",
+            UpdateType::DisassociateIdentityProviderConfig => "DisassociateIdentityProviderConfig",
+            UpdateType::AssociateEncryptionConfig => "AssociateEncryptionConfig",
+            UpdateType::AddonUpdate => "AddonUpdate",
+            UpdateType::VpcConfigUpdate => "VpcConfigUpdate",
+            UpdateType::AccessConfigUpdate => "AccessConfigUpdate",
+            UpdateType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for UpdateType {
+    fn default() -> UpdateType {
+        UpdateType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for UpdateType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for UpdateType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for UpdateType {
+    fn from(value: &'a str) -> UpdateType {
+        match value {
+            "VersionUpdate" => UpdateType::VersionUpdate,
+            "EndpointAccessUpdate" => UpdateType::EndpointAccessUpdate,
+            "LoggingUpdate" => UpdateType::LoggingUpdate,
+            "ConfigUpdate" => UpdateType::ConfigUpdate,
+            "AssociateIdentityProviderConfig" => UpdateType::AssociateIdentityProviderConfig,
+            "DisassociateIdentityProviderConfig" => UpdateType::DisassociateIdentityProviderConfig,
+            "AssociateEncryptionConfig" => UpdateType::AssociateEncryptionConfig,
+            "AddonUpdate" => UpdateType::AddonUpdate,
+            "VpcConfigUpdate" => UpdateType::VpcConfigUpdate,
+            "AccessConfigUpdate" => UpdateType::AccessConfigUpdate,
+            _ => UpdateType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for UpdateType {
+    fn from(value: String) -> UpdateType {
+        match value.as_str() {
+            "VersionUpdate" => UpdateType::VersionUpdate,
+            "EndpointAccessUpdate" => UpdateType::EndpointAccessUpdate,
+            "LoggingUpdate" => UpdateType::LoggingUpdate,
+            "ConfigUpdate" => UpdateType::ConfigUpdate,
+            "AssociateIdentityProviderConfig" => UpdateType::AssociateIdentityProviderConfig,
+            "DisassociateIdentityProviderConfig" => UpdateType::DisassociateIdentityProviderConfig,
+            "AssociateEncryptionConfig" => UpdateType::AssociateEncryptionConfig,
+            "AddonUpdate" => UpdateType::AddonUpdate,
+            "VpcConfigUpdate" => UpdateType::VpcConfigUpdate,
+            "AccessConfigUpdate" => UpdateType::AccessConfigUpdate,
+            _ => UpdateType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for UpdateType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<UpdateType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for UpdateType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for UpdateType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for UpdateType {
+    fn deserialize<D>(deserializer: D) -> Result<UpdateType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An object representing the VPC configuration to use for an Amazon EKS cluster.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -2342,11 +3881,12 @@ pub struct EksClient {
 impl EksClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> EksClient {
         EksClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "EKS"),
         }
     }
 
@@ -2380,6 +3920,7 @@ impl Eks for EksClient {
         let request_uri = "/clusters";
 
         let mut request = SignedRequest::new("POST", "eks", &self.region, &request_uri);
+        request.set_operation("CreateCluster");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2413,6 +3954,7 @@ impl Eks for EksClient {
         );
 
         let mut request = SignedRequest::new("POST", "eks", &self.region, &request_uri);
+        request.set_operation("CreateFargateProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2443,6 +3985,7 @@ impl Eks for EksClient {
         let request_uri = format!("/clusters/{name}/node-groups", name = input.cluster_name);
 
         let mut request = SignedRequest::new("POST", "eks", &self.region, &request_uri);
+        request.set_operation("CreateNodegroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2473,6 +4016,7 @@ impl Eks for EksClient {
         let request_uri = format!("/clusters/{name}", name = input.name);
 
         let mut request = SignedRequest::new("DELETE", "eks", &self.region, &request_uri);
+        request.set_operation("DeleteCluster");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2504,6 +4048,7 @@ impl Eks for EksClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "eks", &self.region, &request_uri);
+        request.set_operation("DeleteFargateProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2535,6 +4080,7 @@ impl Eks for EksClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "eks", &self.region, &request_uri);
+        request.set_operation("DeleteNodegroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2562,6 +4108,7 @@ impl Eks for EksClient {
         let request_uri = format!("/clusters/{name}", name = input.name);
 
         let mut request = SignedRequest::new("GET", "eks", &self.region, &request_uri);
+        request.set_operation("DescribeCluster");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2593,6 +4140,7 @@ impl Eks for EksClient {
         );
 
         let mut request = SignedRequest::new("GET", "eks", &self.region, &request_uri);
+        request.set_operation("DescribeFargateProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2624,6 +4172,7 @@ impl Eks for EksClient {
         );
 
         let mut request = SignedRequest::new("GET", "eks", &self.region, &request_uri);
+        request.set_operation("DescribeNodegroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2655,6 +4204,7 @@ impl Eks for EksClient {
         );
 
         let mut request = SignedRequest::new("GET", "eks", &self.region, &request_uri);
+        request.set_operation("DescribeUpdate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2688,6 +4238,7 @@ impl Eks for EksClient {
         let request_uri = "/clusters";
 
         let mut request = SignedRequest::new("GET", "eks", &self.region, &request_uri);
+        request.set_operation("ListClusters");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2727,6 +4278,7 @@ impl Eks for EksClient {
         );
 
         let mut request = SignedRequest::new("GET", "eks", &self.region, &request_uri);
+        request.set_operation("ListFargateProfiles");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2763,6 +4315,7 @@ impl Eks for EksClient {
         let request_uri = format!("/clusters/{name}/node-groups", name = input.cluster_name);
 
         let mut request = SignedRequest::new("GET", "eks", &self.region, &request_uri);
+        request.set_operation("ListNodegroups");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2799,6 +4352,7 @@ impl Eks for EksClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "eks", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2826,6 +4380,7 @@ impl Eks for EksClient {
         let request_uri = format!("/clusters/{name}/updates", name = input.name);
 
         let mut request = SignedRequest::new("GET", "eks", &self.region, &request_uri);
+        request.set_operation("ListUpdates");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2865,6 +4420,7 @@ impl Eks for EksClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "eks", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2895,6 +4451,7 @@ impl Eks for EksClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "eks", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2928,6 +4485,7 @@ impl Eks for EksClient {
         let request_uri = format!("/clusters/{name}/update-config", name = input.name);
 
         let mut request = SignedRequest::new("POST", "eks", &self.region, &request_uri);
+        request.set_operation("UpdateClusterConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2958,6 +4516,7 @@ impl Eks for EksClient {
         let request_uri = format!("/clusters/{name}/updates", name = input.name);
 
         let mut request = SignedRequest::new("POST", "eks", &self.region, &request_uri);
+        request.set_operation("UpdateClusterVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2992,6 +4551,7 @@ impl Eks for EksClient {
         );
 
         let mut request = SignedRequest::new("POST", "eks", &self.region, &request_uri);
+        request.set_operation("UpdateNodegroupConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3026,6 +4586,7 @@ impl Eks for EksClient {
         );
 
         let mut request = SignedRequest::new("POST", "eks", &self.region, &request_uri);
+        request.set_operation("UpdateNodegroupVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3048,3 +4609,441 @@ impl Eks for EksClient {
         }
     }
 }
+impl EksClient {
+    /// Auto-paginating version of `list_clusters`, streaming every page of results.
+    pub fn list_clusters_pages(
+        &self,
+        input: ListClustersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListClustersResponse, ListClustersError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EksClient, input: ListClustersRequest| async move {
+                client.list_clusters(input).await
+            },
+            |input: &ListClustersRequest, output: &ListClustersResponse| {
+                Some(ListClustersRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_clusters`, streaming the `clusters` of every page of results.
+    pub fn list_clusters_items(
+        &self,
+        input: ListClustersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListClustersError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_clusters_pages(input),
+            |output: ListClustersResponse| output.clusters.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_fargate_profiles`, streaming every page of results.
+    pub fn list_fargate_profiles_pages(
+        &self,
+        input: ListFargateProfilesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListFargateProfilesResponse, ListFargateProfilesError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EksClient, input: ListFargateProfilesRequest| async move {
+                client.list_fargate_profiles(input).await
+            },
+            |input: &ListFargateProfilesRequest, output: &ListFargateProfilesResponse| {
+                Some(ListFargateProfilesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_fargate_profiles`, streaming the `fargateProfileNames` of every page of results.
+    pub fn list_fargate_profiles_items(
+        &self,
+        input: ListFargateProfilesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListFargateProfilesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_fargate_profiles_pages(input),
+            |output: ListFargateProfilesResponse| output.fargate_profile_names.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_nodegroups`, streaming every page of results.
+    pub fn list_nodegroups_pages(
+        &self,
+        input: ListNodegroupsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListNodegroupsResponse, ListNodegroupsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EksClient, input: ListNodegroupsRequest| async move {
+                client.list_nodegroups(input).await
+            },
+            |input: &ListNodegroupsRequest, output: &ListNodegroupsResponse| {
+                Some(ListNodegroupsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_nodegroups`, streaming the `nodegroups` of every page of results.
+    pub fn list_nodegroups_items(
+        &self,
+        input: ListNodegroupsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListNodegroupsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_nodegroups_pages(input),
+            |output: ListNodegroupsResponse| output.nodegroups.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_updates`, streaming every page of results.
+    pub fn list_updates_pages(
+        &self,
+        input: ListUpdatesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListUpdatesResponse, ListUpdatesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EksClient, input: ListUpdatesRequest| async move {
+                client.list_updates(input).await
+            },
+            |input: &ListUpdatesRequest, output: &ListUpdatesResponse| {
+                Some(ListUpdatesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_updates`, streaming the `updateIds` of every page of results.
+    pub fn list_updates_items(
+        &self,
+        input: ListUpdatesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListUpdatesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_updates_pages(input),
+            |output: ListUpdatesResponse| output.update_ids.unwrap_or_default(),
+        )
+    }
+}
+impl EksClient {
+    /// Waits until `describe_cluster` reports the `ClusterActive` state, polling every 30 seconds up to 40 times.
+    pub async fn wait_until_cluster_active(
+        &self,
+        input: DescribeClusterRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeClusterError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(30), 40);
+        self.wait_until_cluster_active_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_cluster_active`, polling as often and as many times as `config` says.
+    pub async fn wait_until_cluster_active_with_config(
+        &self,
+        input: DescribeClusterRequest,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeClusterError>> {
+        rusoto_core::waiter::wait(
+            "ClusterActive",
+            &config,
+            || self.describe_cluster(input.clone()),
+            |result: &Result<DescribeClusterResponse, RusotoError<DescribeClusterError>>| {
+                match result {
+                    Ok(output)
+                        if output
+                            .cluster
+                            .iter()
+                            .flat_map(|v| v.status.iter())
+                            .any(|v| *v == ClusterStatus::Deleting) =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    Ok(output)
+                        if output
+                            .cluster
+                            .iter()
+                            .flat_map(|v| v.status.iter())
+                            .any(|v| *v == ClusterStatus::Failed) =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    Ok(output)
+                        if output
+                            .cluster
+                            .iter()
+                            .flat_map(|v| v.status.iter())
+                            .any(|v| *v == ClusterStatus::Active) =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Success)
+                    }
+                    _ => None,
+                }
+            },
+        )
+        .await
+    }
+
+    /// Waits until `describe_cluster` reports the `ClusterDeleted` state, polling every 30 seconds up to 40 times.
+    pub async fn wait_until_cluster_deleted(
+        &self,
+        input: DescribeClusterRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeClusterError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(30), 40);
+        self.wait_until_cluster_deleted_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_cluster_deleted`, polling as often and as many times as `config` says.
+    pub async fn wait_until_cluster_deleted_with_config(
+        &self,
+        input: DescribeClusterRequest,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeClusterError>> {
+        rusoto_core::waiter::wait(
+            "ClusterDeleted",
+            &config,
+            || self.describe_cluster(input.clone()),
+            |result: &Result<DescribeClusterResponse, RusotoError<DescribeClusterError>>| {
+                match result {
+                    Ok(output)
+                        if output
+                            .cluster
+                            .iter()
+                            .flat_map(|v| v.status.iter())
+                            .any(|v| *v == ClusterStatus::Active) =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    Ok(output)
+                        if output
+                            .cluster
+                            .iter()
+                            .flat_map(|v| v.status.iter())
+                            .any(|v| *v == ClusterStatus::Creating) =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    Ok(output)
+                        if output
+                            .cluster
+                            .iter()
+                            .flat_map(|v| v.status.iter())
+                            .any(|v| *v == ClusterStatus::Pending) =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    Err(RusotoError::Service(DescribeClusterError::ResourceNotFound(_))) => {
+                        Some(rusoto_core::waiter::WaiterState::Success)
+                    }
+                    Err(RusotoError::Unknown(response))
+                        if rusoto_core::retry::error_code(response).as_deref()
+                            == Some("ResourceNotFoundException") =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Success)
+                    }
+                    _ => None,
+                }
+            },
+        )
+        .await
+    }
+
+    /// Waits until `describe_fargate_profile` reports the `FargateProfileActive` state, polling every 10 seconds up to 60 times.
+    pub async fn wait_until_fargate_profile_active(
+        &self,
+        input: DescribeFargateProfileRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeFargateProfileError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(10), 60);
+        self.wait_until_fargate_profile_active_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_fargate_profile_active`, polling as often and as many times as `config` says.
+    pub async fn wait_until_fargate_profile_active_with_config(
+        &self,
+        input: DescribeFargateProfileRequest,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeFargateProfileError>> {
+        rusoto_core::waiter::wait(
+            "FargateProfileActive",
+            &config,
+            || self.describe_fargate_profile(input.clone()),
+            |result: &Result<
+                DescribeFargateProfileResponse,
+                RusotoError<DescribeFargateProfileError>,
+            >| match result {
+                Ok(output)
+                    if output
+                        .fargate_profile
+                        .iter()
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| *v == FargateProfileStatus::CreateFailed) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .fargate_profile
+                        .iter()
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| *v == FargateProfileStatus::Active) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+
+    /// Waits until `describe_fargate_profile` reports the `FargateProfileDeleted` state, polling every 30 seconds up to 60 times.
+    pub async fn wait_until_fargate_profile_deleted(
+        &self,
+        input: DescribeFargateProfileRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeFargateProfileError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(30), 60);
+        self.wait_until_fargate_profile_deleted_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_fargate_profile_deleted`, polling as often and as many times as `config` says.
+    pub async fn wait_until_fargate_profile_deleted_with_config(
+        &self,
+        input: DescribeFargateProfileRequest,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeFargateProfileError>> {
+        rusoto_core::waiter::wait(
+            "FargateProfileDeleted",
+            &config,
+            || self.describe_fargate_profile(input.clone()),
+            |result: &Result<
+                DescribeFargateProfileResponse,
+                RusotoError<DescribeFargateProfileError>,
+            >| match result {
+                Ok(output)
+                    if output
+                        .fargate_profile
+                        .iter()
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| *v == FargateProfileStatus::DeleteFailed) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Err(RusotoError::Service(DescribeFargateProfileError::ResourceNotFound(_))) => {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Err(RusotoError::Unknown(response))
+                    if rusoto_core::retry::error_code(response).as_deref()
+                        == Some("ResourceNotFoundException") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+
+    /// Waits until `describe_nodegroup` reports the `NodegroupActive` state, polling every 30 seconds up to 80 times.
+    pub async fn wait_until_nodegroup_active(
+        &self,
+        input: DescribeNodegroupRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeNodegroupError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(30), 80);
+        self.wait_until_nodegroup_active_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_nodegroup_active`, polling as often and as many times as `config` says.
+    pub async fn wait_until_nodegroup_active_with_config(
+        &self,
+        input: DescribeNodegroupRequest,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeNodegroupError>> {
+        rusoto_core::waiter::wait(
+            "NodegroupActive",
+            &config,
+            || self.describe_nodegroup(input.clone()),
+            |result: &Result<DescribeNodegroupResponse, RusotoError<DescribeNodegroupError>>| {
+                match result {
+                    Ok(output)
+                        if output
+                            .nodegroup
+                            .iter()
+                            .flat_map(|v| v.status.iter())
+                            .any(|v| *v == NodegroupStatus::CreateFailed) =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    Ok(output)
+                        if output
+                            .nodegroup
+                            .iter()
+                            .flat_map(|v| v.status.iter())
+                            .any(|v| *v == NodegroupStatus::Active) =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Success)
+                    }
+                    _ => None,
+                }
+            },
+        )
+        .await
+    }
+
+    /// Waits until `describe_nodegroup` reports the `NodegroupDeleted` state, polling every 30 seconds up to 40 times.
+    pub async fn wait_until_nodegroup_deleted(
+        &self,
+        input: DescribeNodegroupRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeNodegroupError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(30), 40);
+        self.wait_until_nodegroup_deleted_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_nodegroup_deleted`, polling as often and as many times as `config` says.
+    pub async fn wait_until_nodegroup_deleted_with_config(
+        &self,
+        input: DescribeNodegroupRequest,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeNodegroupError>> {
+        rusoto_core::waiter::wait(
+            "NodegroupDeleted",
+            &config,
+            || self.describe_nodegroup(input.clone()),
+            |result: &Result<DescribeNodegroupResponse, RusotoError<DescribeNodegroupError>>| {
+                match result {
+                    Ok(output)
+                        if output
+                            .nodegroup
+                            .iter()
+                            .flat_map(|v| v.status.iter())
+                            .any(|v| *v == NodegroupStatus::DeleteFailed) =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    Err(RusotoError::Service(DescribeNodegroupError::ResourceNotFound(_))) => {
+                        Some(rusoto_core::waiter::WaiterState::Success)
+                    }
+                    Err(RusotoError::Unknown(response))
+                        if rusoto_core::retry::error_code(response).as_deref()
+                            == Some("ResourceNotFoundException") =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Success)
+                    }
+                    _ => None,
+                }
+            },
+        )
+        .await
+    }
+}
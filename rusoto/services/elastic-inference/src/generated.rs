@@ -231,11 +231,12 @@ pub struct ElasticInferenceClient {
 impl ElasticInferenceClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> ElasticInferenceClient {
         ElasticInferenceClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Elastic Inference"),
         }
     }
 
@@ -270,6 +271,7 @@ impl ElasticInference for ElasticInferenceClient {
 
         let mut request =
             SignedRequest::new("GET", "elastic-inference", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.elastic-inference".to_string());
@@ -300,6 +302,7 @@ impl ElasticInference for ElasticInferenceClient {
 
         let mut request =
             SignedRequest::new("POST", "elastic-inference", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.elastic-inference".to_string());
@@ -332,6 +335,7 @@ impl ElasticInference for ElasticInferenceClient {
 
         let mut request =
             SignedRequest::new("DELETE", "elastic-inference", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.elastic-inference".to_string());
@@ -32,6 +32,101 @@ use std::str::FromStr;
 use xml::reader::ParserConfig;
 use xml::EventReader;
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AZMode {
+    /// `single-az`
+    SingleAz,
+    /// `cross-az`
+    CrossAz,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AZMode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AZMode::SingleAz => "single-az",
+            AZMode::CrossAz => "cross-az",
+            AZMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AZMode {
+    fn default() -> AZMode {
+        AZMode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AZMode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AZMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AZMode {
+    fn from(value: &'a str) -> AZMode {
+        match value {
+            "single-az" => AZMode::SingleAz,
+            "cross-az" => AZMode::CrossAz,
+            _ => AZMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AZMode {
+    fn from(value: String) -> AZMode {
+        match value.as_str() {
+            "single-az" => AZMode::SingleAz,
+            "cross-az" => AZMode::CrossAz,
+            _ => AZMode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AZMode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AZMode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AZMode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for AZMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for AZMode {
+    fn deserialize<D>(deserializer: D) -> Result<AZMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents the input of an AddTagsToResource operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -106,17 +201,215 @@ impl AllowedNodeTypeModificationsMessageDeserializer {
         )
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AuthTokenUpdateStatus {
+    /// `SETTING`
+    Setting,
+    /// `ROTATING`
+    Rotating,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AuthTokenUpdateStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AuthTokenUpdateStatus::Setting => "SETTING",
+            AuthTokenUpdateStatus::Rotating => "ROTATING",
+            AuthTokenUpdateStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AuthTokenUpdateStatus {
+    fn default() -> AuthTokenUpdateStatus {
+        AuthTokenUpdateStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AuthTokenUpdateStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AuthTokenUpdateStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AuthTokenUpdateStatus {
+    fn from(value: &'a str) -> AuthTokenUpdateStatus {
+        match value {
+            "SETTING" => AuthTokenUpdateStatus::Setting,
+            "ROTATING" => AuthTokenUpdateStatus::Rotating,
+            _ => AuthTokenUpdateStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AuthTokenUpdateStatus {
+    fn from(value: String) -> AuthTokenUpdateStatus {
+        match value.as_str() {
+            "SETTING" => AuthTokenUpdateStatus::Setting,
+            "ROTATING" => AuthTokenUpdateStatus::Rotating,
+            _ => AuthTokenUpdateStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AuthTokenUpdateStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AuthTokenUpdateStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AuthTokenUpdateStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for AuthTokenUpdateStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for AuthTokenUpdateStatus {
+    fn deserialize<D>(deserializer: D) -> Result<AuthTokenUpdateStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct AuthTokenUpdateStatusDeserializer;
 impl AuthTokenUpdateStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<AuthTokenUpdateStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AuthTokenUpdateStrategyType {
+    /// `SET`
+    Set,
+    /// `ROTATE`
+    Rotate,
+    /// `DELETE`
+    Delete,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AuthTokenUpdateStrategyType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AuthTokenUpdateStrategyType::Set => "SET",
+            AuthTokenUpdateStrategyType::Rotate => "ROTATE",
+            AuthTokenUpdateStrategyType::Delete => "DELETE",
+            AuthTokenUpdateStrategyType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AuthTokenUpdateStrategyType {
+    fn default() -> AuthTokenUpdateStrategyType {
+        AuthTokenUpdateStrategyType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AuthTokenUpdateStrategyType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AuthTokenUpdateStrategyType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AuthTokenUpdateStrategyType {
+    fn from(value: &'a str) -> AuthTokenUpdateStrategyType {
+        match value {
+            "SET" => AuthTokenUpdateStrategyType::Set,
+            "ROTATE" => AuthTokenUpdateStrategyType::Rotate,
+            "DELETE" => AuthTokenUpdateStrategyType::Delete,
+            _ => AuthTokenUpdateStrategyType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AuthTokenUpdateStrategyType {
+    fn from(value: String) -> AuthTokenUpdateStrategyType {
+        match value.as_str() {
+            "SET" => AuthTokenUpdateStrategyType::Set,
+            "ROTATE" => AuthTokenUpdateStrategyType::Rotate,
+            "DELETE" => AuthTokenUpdateStrategyType::Delete,
+            _ => AuthTokenUpdateStrategyType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AuthTokenUpdateStrategyType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AuthTokenUpdateStrategyType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AuthTokenUpdateStrategyType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for AuthTokenUpdateStrategyType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for AuthTokenUpdateStrategyType {
+    fn deserialize<D>(deserializer: D) -> Result<AuthTokenUpdateStrategyType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents the input of an AuthorizeCacheSecurityGroupIngress operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -185,12 +478,120 @@ impl AuthorizeCacheSecurityGroupIngressResultDeserializer {
         )
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AutomaticFailoverStatus {
+    /// `enabled`
+    Enabled,
+    /// `disabled`
+    Disabled,
+    /// `enabling`
+    Enabling,
+    /// `disabling`
+    Disabling,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AutomaticFailoverStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AutomaticFailoverStatus::Enabled => "enabled",
+            AutomaticFailoverStatus::Disabled => "disabled",
+            AutomaticFailoverStatus::Enabling => "enabling",
+            AutomaticFailoverStatus::Disabling => "disabling",
+            AutomaticFailoverStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AutomaticFailoverStatus {
+    fn default() -> AutomaticFailoverStatus {
+        AutomaticFailoverStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AutomaticFailoverStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AutomaticFailoverStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AutomaticFailoverStatus {
+    fn from(value: &'a str) -> AutomaticFailoverStatus {
+        match value {
+            "enabled" => AutomaticFailoverStatus::Enabled,
+            "disabled" => AutomaticFailoverStatus::Disabled,
+            "enabling" => AutomaticFailoverStatus::Enabling,
+            "disabling" => AutomaticFailoverStatus::Disabling,
+            _ => AutomaticFailoverStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AutomaticFailoverStatus {
+    fn from(value: String) -> AutomaticFailoverStatus {
+        match value.as_str() {
+            "enabled" => AutomaticFailoverStatus::Enabled,
+            "disabled" => AutomaticFailoverStatus::Disabled,
+            "enabling" => AutomaticFailoverStatus::Enabling,
+            "disabling" => AutomaticFailoverStatus::Disabling,
+            _ => AutomaticFailoverStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AutomaticFailoverStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AutomaticFailoverStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AutomaticFailoverStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for AutomaticFailoverStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for AutomaticFailoverStatus {
+    fn deserialize<D>(deserializer: D) -> Result<AutomaticFailoverStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct AutomaticFailoverStatusDeserializer;
 impl AutomaticFailoverStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<AutomaticFailoverStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -364,11 +765,11 @@ pub struct CacheCluster {
     /// <p>A flag that enables using an <code>AuthToken</code> (password) when issuing Redis commands.</p> <p>Default: <code>false</code> </p>
     pub auth_token_enabled: Option<bool>,
     /// <p>The date the auth token was last modified</p>
-    pub auth_token_last_modified_date: Option<String>,
+    pub auth_token_last_modified_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>This parameter is currently disabled.</p>
     pub auto_minor_version_upgrade: Option<bool>,
     /// <p>The date and time when the cluster was created.</p>
-    pub cache_cluster_create_time: Option<String>,
+    pub cache_cluster_create_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The user-supplied identifier of the cluster. This identifier is a unique key that identifies a cluster.</p>
     pub cache_cluster_id: Option<String>,
     /// <p>The current state of this cluster, one of the following values: <code>available</code>, <code>creating</code>, <code>deleted</code>, <code>deleting</code>, <code>incompatible-network</code>, <code>modifying</code>, <code>rebooting cluster nodes</code>, <code>restore-failed</code>, or <code>snapshotting</code>.</p>
@@ -764,7 +1165,7 @@ impl CacheEngineVersionMessageDeserializer {
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct CacheNode {
     /// <p>The date and time when the cache node was created.</p>
-    pub cache_node_create_time: Option<String>,
+    pub cache_node_create_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The cache node identifier. A node ID is a numeric identifier (0001, 0002, etc.). The combination of cluster ID and node ID uniquely identifies every cache node used in a customer's AWS account.</p>
     pub cache_node_id: Option<String>,
     /// <p>The current state of this cache node.</p>
@@ -882,7 +1283,7 @@ pub struct CacheNodeTypeSpecificParameter {
     /// <p>A list of cache node types and their corresponding values for this parameter.</p>
     pub cache_node_type_specific_values: Option<Vec<CacheNodeTypeSpecificValue>>,
     /// <p>Indicates whether a change to the parameter is applied immediately or requires a reboot for the change to be applied. You can force a reboot or wait until the next maintenance window's reboot. For more information, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/Clusters.Rebooting.html">Rebooting a Cluster</a>.</p>
-    pub change_type: Option<String>,
+    pub change_type: Option<ChangeType>,
     /// <p>The valid data type for the parameter.</p>
     pub data_type: Option<String>,
     /// <p>A description of the parameter.</p>
@@ -1039,19 +1440,19 @@ pub struct CacheNodeUpdateStatus {
     /// <p>The node ID of the cache cluster</p>
     pub cache_node_id: Option<String>,
     /// <p>The deletion date of the node</p>
-    pub node_deletion_date: Option<String>,
+    pub node_deletion_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The end date of the update for a node</p>
-    pub node_update_end_date: Option<String>,
+    pub node_update_end_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Reflects whether the update was initiated by the customer or automatically applied</p>
-    pub node_update_initiated_by: Option<String>,
+    pub node_update_initiated_by: Option<NodeUpdateInitiatedBy>,
     /// <p>The date when the update is triggered</p>
-    pub node_update_initiated_date: Option<String>,
+    pub node_update_initiated_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The start date of the update for a node</p>
-    pub node_update_start_date: Option<String>,
+    pub node_update_start_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The update status of the node</p>
-    pub node_update_status: Option<String>,
+    pub node_update_status: Option<NodeUpdateStatus>,
     /// <p>The date when the NodeUpdateStatus was last modified&gt;</p>
-    pub node_update_status_modified_date: Option<String>,
+    pub node_update_status_modified_date: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 struct CacheNodeUpdateStatusDeserializer;
@@ -1649,12 +2050,110 @@ impl CacheSubnetGroupsDeserializer {
         })
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ChangeType {
+    /// `immediate`
+    Immediate,
+    /// `requires-reboot`
+    RequiresReboot,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ChangeType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ChangeType::Immediate => "immediate",
+            ChangeType::RequiresReboot => "requires-reboot",
+            ChangeType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ChangeType {
+    fn default() -> ChangeType {
+        ChangeType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ChangeType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ChangeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ChangeType {
+    fn from(value: &'a str) -> ChangeType {
+        match value {
+            "immediate" => ChangeType::Immediate,
+            "requires-reboot" => ChangeType::RequiresReboot,
+            _ => ChangeType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ChangeType {
+    fn from(value: String) -> ChangeType {
+        match value.as_str() {
+            "immediate" => ChangeType::Immediate,
+            "requires-reboot" => ChangeType::RequiresReboot,
+            _ => ChangeType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ChangeType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ChangeType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ChangeType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ChangeType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ChangeType {
+    fn deserialize<D>(deserializer: D) -> Result<ChangeType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct ChangeTypeDeserializer;
 impl ChangeTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ChangeType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -1841,7 +2340,7 @@ impl CopySnapshotResultDeserializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateCacheClusterMessage {
     /// <p>Specifies whether the nodes in this Memcached cluster are created in a single Availability Zone or created across multiple Availability Zones in the cluster's region.</p> <p>This parameter is only supported for Memcached clusters.</p> <p>If the <code>AZMode</code> and <code>PreferredAvailabilityZones</code> are not specified, ElastiCache assumes <code>single-az</code> mode.</p>
-    pub az_mode: Option<String>,
+    pub az_mode: Option<AZMode>,
     /// <p> <b>Reserved parameter.</b> The password used to access a password protected server.</p> <p>Password constraints:</p> <ul> <li> <p>Must be only printable ASCII characters.</p> </li> <li> <p>Must be at least 16 characters and no more than 128 characters in length.</p> </li> <li> <p>The only permitted printable special characters are !, &amp;, #, $, ^, &lt;, &gt;, and -. Other printable special characters cannot be used in the AUTH token.</p> </li> </ul> <p>For more information, see <a href="http://redis.io/commands/AUTH">AUTH password</a> at http://redis.io/commands/AUTH.</p>
     pub auth_token: Option<String>,
     /// <p>This parameter is currently disabled.</p>
@@ -3225,7 +3724,7 @@ pub struct DescribeEventsMessage {
     /// <p>The number of minutes worth of events to retrieve.</p>
     pub duration: Option<i64>,
     /// <p>The end of the time interval for which to retrieve events, specified in ISO 8601 format.</p> <p> <b>Example:</b> 2017-03-30T07:03:49.555Z</p>
-    pub end_time: Option<String>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>An optional marker returned from a prior request. Use this marker for pagination of results from this operation. If this parameter is specified, the response includes only records beyond the marker, up to the value specified by <code>MaxRecords</code>.</p>
     pub marker: Option<String>,
     /// <p>The maximum number of records to include in the response. If more records exist than the specified <code>MaxRecords</code> value, a marker is included in the response so that the remaining results can be retrieved.</p> <p>Default: 100</p> <p>Constraints: minimum 20; maximum 100.</p>
@@ -3233,9 +3732,9 @@ pub struct DescribeEventsMessage {
     /// <p>The identifier of the event source for which events are returned. If not specified, all sources are included in the response.</p>
     pub source_identifier: Option<String>,
     /// <p>The event source to retrieve events for. If no value is specified, all events are returned.</p>
-    pub source_type: Option<String>,
+    pub source_type: Option<SourceType>,
     /// <p>The beginning of the time interval to retrieve events for, specified in ISO 8601 format.</p> <p> <b>Example:</b> 2017-03-30T07:03:49.555Z</p>
-    pub start_time: Option<String>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// Serialize `DescribeEventsMessage` contents to a `SignedRequest`.
@@ -3251,7 +3750,10 @@ impl DescribeEventsMessageSerializer {
             params.put(&format!("{}{}", prefix, "Duration"), &field_value);
         }
         if let Some(ref field_value) = obj.end_time {
-            params.put(&format!("{}{}", prefix, "EndTime"), &field_value);
+            params.put(
+                &format!("{}{}", prefix, "EndTime"),
+                &rusoto_core::serialization::TimestampFormat::Iso8601.format(field_value),
+            );
         }
         if let Some(ref field_value) = obj.marker {
             params.put(&format!("{}{}", prefix, "Marker"), &field_value);
@@ -3266,7 +3768,10 @@ impl DescribeEventsMessageSerializer {
             params.put(&format!("{}{}", prefix, "SourceType"), &field_value);
         }
         if let Some(ref field_value) = obj.start_time {
-            params.put(&format!("{}{}", prefix, "StartTime"), &field_value);
+            params.put(
+                &format!("{}{}", prefix, "StartTime"),
+                &rusoto_core::serialization::TimestampFormat::Iso8601.format(field_value),
+            );
         }
     }
 }
@@ -3438,7 +3943,7 @@ pub struct DescribeServiceUpdatesMessage {
     /// <p>The unique ID of the service update</p>
     pub service_update_name: Option<String>,
     /// <p>The status of the service update</p>
-    pub service_update_status: Option<Vec<String>>,
+    pub service_update_status: Option<Vec<ServiceUpdateStatus>>,
 }
 
 /// Serialize `DescribeServiceUpdatesMessage` contents to a `SignedRequest`.
@@ -3578,13 +4083,13 @@ pub struct DescribeUpdateActionsMessage {
     /// <p>The unique ID of the service update</p>
     pub service_update_name: Option<String>,
     /// <p>The status of the service update</p>
-    pub service_update_status: Option<Vec<String>>,
+    pub service_update_status: Option<Vec<ServiceUpdateStatus>>,
     /// <p>The range of time specified to search for service updates that are in available status</p>
     pub service_update_time_range: Option<TimeRangeFilter>,
     /// <p>Dictates whether to include node level update status in the response </p>
     pub show_node_level_update_status: Option<bool>,
     /// <p>The status of the update action.</p>
-    pub update_action_status: Option<Vec<String>>,
+    pub update_action_status: Option<Vec<UpdateActionStatus>>,
 }
 
 /// Serialize `DescribeUpdateActionsMessage` contents to a `SignedRequest`.
@@ -3814,13 +4319,13 @@ impl EngineDefaultsDeserializer {
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct Event {
     /// <p>The date and time when the event occurred.</p>
-    pub date: Option<String>,
+    pub date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The text of the event.</p>
     pub message: Option<String>,
     /// <p>The identifier for the source of the event. For example, if the event occurred at the cluster level, the identifier would be the name of the cluster.</p>
     pub source_identifier: Option<String>,
     /// <p>Specifies the origin of this event - a cluster, a parameter group, a security group, etc.</p>
-    pub source_type: Option<String>,
+    pub source_type: Option<SourceType>,
 }
 
 struct EventDeserializer;
@@ -4061,13 +4566,13 @@ impl ListTagsForResourceMessageSerializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ModifyCacheClusterMessage {
     /// <p><p>Specifies whether the new nodes in this Memcached cluster are all created in a single Availability Zone or created across multiple Availability Zones.</p> <p>Valid values: <code>single-az</code> | <code>cross-az</code>.</p> <p>This option is only supported for Memcached clusters.</p> <note> <p>You cannot specify <code>single-az</code> if the Memcached cluster already has cache nodes in different Availability Zones. If <code>cross-az</code> is specified, existing Memcached nodes remain in their current Availability Zone.</p> <p>Only newly created nodes are located in different Availability Zones. </p> </note></p>
-    pub az_mode: Option<String>,
+    pub az_mode: Option<AZMode>,
     /// <p>If <code>true</code>, this parameter causes the modifications in this request and any pending modifications to be applied, asynchronously and as soon as possible, regardless of the <code>PreferredMaintenanceWindow</code> setting for the cluster.</p> <p>If <code>false</code>, changes to the cluster are applied on the next maintenance reboot, or the next failure reboot, whichever occurs first.</p> <important> <p>If you perform a <code>ModifyCacheCluster</code> before a pending modification is applied, the pending modification is replaced by the newer modification.</p> </important> <p>Valid values: <code>true</code> | <code>false</code> </p> <p>Default: <code>false</code> </p>
     pub apply_immediately: Option<bool>,
     /// <p>Reserved parameter. The password used to access a password protected server. This parameter must be specified with the <code>auth-token-update</code> parameter. Password constraints:</p> <ul> <li> <p>Must be only printable ASCII characters</p> </li> <li> <p>Must be at least 16 characters and no more than 128 characters in length</p> </li> <li> <p>Cannot contain any of the following characters: '/', '"', or '@', '%'</p> </li> </ul> <p> For more information, see AUTH password at <a href="http://redis.io/commands/AUTH">AUTH</a>.</p>
     pub auth_token: Option<String>,
     /// <p>Specifies the strategy to use to update the AUTH token. This parameter must be specified with the <code>auth-token</code> parameter. Possible values:</p> <ul> <li> <p>Rotate</p> </li> <li> <p>Set</p> </li> </ul> <p> For more information, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/auth.html">Authenticating Users with Redis AUTH</a> </p>
-    pub auth_token_update_strategy: Option<String>,
+    pub auth_token_update_strategy: Option<AuthTokenUpdateStrategyType>,
     /// <p>This parameter is currently disabled.</p>
     pub auto_minor_version_upgrade: Option<bool>,
     /// <p>The cluster identifier. This value is stored as a lowercase string.</p>
@@ -4350,7 +4855,7 @@ pub struct ModifyReplicationGroupMessage {
     /// <p>Reserved parameter. The password used to access a password protected server. This parameter must be specified with the <code>auth-token-update-strategy </code> parameter. Password constraints:</p> <ul> <li> <p>Must be only printable ASCII characters</p> </li> <li> <p>Must be at least 16 characters and no more than 128 characters in length</p> </li> <li> <p>Cannot contain any of the following characters: '/', '"', or '@', '%'</p> </li> </ul> <p> For more information, see AUTH password at <a href="http://redis.io/commands/AUTH">AUTH</a>.</p>
     pub auth_token: Option<String>,
     /// <p>Specifies the strategy to use to update the AUTH token. This parameter must be specified with the <code>auth-token</code> parameter. Possible values:</p> <ul> <li> <p>Rotate</p> </li> <li> <p>Set</p> </li> </ul> <p> For more information, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/auth.html">Authenticating Users with Redis AUTH</a> </p>
-    pub auth_token_update_strategy: Option<String>,
+    pub auth_token_update_strategy: Option<AuthTokenUpdateStrategyType>,
     /// <p>This parameter is currently disabled.</p>
     pub auto_minor_version_upgrade: Option<bool>,
     /// <p><p>Determines whether a read replica is automatically promoted to read/write primary if the existing primary encounters a failure.</p> <p>Valid values: <code>true</code> | <code>false</code> </p> <p>Amazon ElastiCache for Redis does not support Multi-AZ with automatic failover on:</p> <ul> <li> <p>Redis versions earlier than 2.8.6.</p> </li> <li> <p>Redis (cluster mode disabled): T1 node types.</p> </li> <li> <p>Redis (cluster mode enabled): T1 node types.</p> </li> </ul></p>
@@ -4884,19 +5389,19 @@ pub struct NodeGroupMemberUpdateStatus {
     /// <p>The node ID of the cache cluster</p>
     pub cache_node_id: Option<String>,
     /// <p>The deletion date of the node</p>
-    pub node_deletion_date: Option<String>,
+    pub node_deletion_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The end date of the update for a node</p>
-    pub node_update_end_date: Option<String>,
+    pub node_update_end_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Reflects whether the update was initiated by the customer or automatically applied</p>
-    pub node_update_initiated_by: Option<String>,
+    pub node_update_initiated_by: Option<NodeUpdateInitiatedBy>,
     /// <p>The date when the update is triggered</p>
-    pub node_update_initiated_date: Option<String>,
+    pub node_update_initiated_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The start date of the update for a node</p>
-    pub node_update_start_date: Option<String>,
+    pub node_update_start_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The update status of the node</p>
-    pub node_update_status: Option<String>,
+    pub node_update_status: Option<NodeUpdateStatus>,
     /// <p>The date when the NodeUpdateStatus was last modified</p>
-    pub node_update_status_modified_date: Option<String>,
+    pub node_update_status_modified_date: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 struct NodeGroupMemberUpdateStatusDeserializer;
@@ -5071,7 +5576,7 @@ pub struct NodeSnapshot {
     /// <p>A unique identifier for the source cluster.</p>
     pub cache_cluster_id: Option<String>,
     /// <p>The date and time when the cache node was created in the source cluster.</p>
-    pub cache_node_create_time: Option<String>,
+    pub cache_node_create_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The cache node identifier for the node in the source cluster.</p>
     pub cache_node_id: Option<String>,
     /// <p>The size of the cache on the source cache node.</p>
@@ -5081,7 +5586,7 @@ pub struct NodeSnapshot {
     /// <p>A unique identifier for the source node group (shard).</p>
     pub node_group_id: Option<String>,
     /// <p>The date and time when the source node's metadata and cache data set was obtained for the snapshot.</p>
-    pub snapshot_create_time: Option<String>,
+    pub snapshot_create_time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 struct NodeSnapshotDeserializer;
@@ -5170,45 +5675,261 @@ impl NodeTypeListDeserializer {
         })
     }
 }
-struct NodeUpdateInitiatedByDeserializer;
-impl NodeUpdateInitiatedByDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
-        start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
-        end_element(tag_name, stack)?;
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum NodeUpdateInitiatedBy {
+    /// `system`
+    System,
+    /// `customer`
+    Customer,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
 
-        Ok(obj)
+impl NodeUpdateInitiatedBy {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            NodeUpdateInitiatedBy::System => "system",
+            NodeUpdateInitiatedBy::Customer => "customer",
+            NodeUpdateInitiatedBy::Unknown(ref value) => value,
+        }
     }
 }
-struct NodeUpdateStatusDeserializer;
-impl NodeUpdateStatusDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
-        start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
-        end_element(tag_name, stack)?;
 
-        Ok(obj)
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for NodeUpdateInitiatedBy {
+    fn default() -> NodeUpdateInitiatedBy {
+        NodeUpdateInitiatedBy::Unknown(String::new())
     }
 }
-/// <p>Describes a notification topic and its status. Notification topics are used for publishing ElastiCache events to subscribers using Amazon Simple Notification Service (SNS).</p>
-#[derive(Default, Debug, Clone, PartialEq)]
-#[cfg_attr(feature = "serialize_structs", derive(Serialize))]
-pub struct NotificationConfiguration {
-    /// <p>The Amazon Resource Name (ARN) that identifies the topic.</p>
-    pub topic_arn: Option<String>,
-    /// <p>The current state of the topic.</p>
-    pub topic_status: Option<String>,
+
+impl AsRef<str> for NodeUpdateInitiatedBy {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
 }
 
-struct NotificationConfigurationDeserializer;
-impl NotificationConfigurationDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
-        tag_name: &str,
-        stack: &mut T,
-    ) -> Result<NotificationConfiguration, XmlParseError> {
+impl fmt::Display for NodeUpdateInitiatedBy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for NodeUpdateInitiatedBy {
+    fn from(value: &'a str) -> NodeUpdateInitiatedBy {
+        match value {
+            "system" => NodeUpdateInitiatedBy::System,
+            "customer" => NodeUpdateInitiatedBy::Customer,
+            _ => NodeUpdateInitiatedBy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for NodeUpdateInitiatedBy {
+    fn from(value: String) -> NodeUpdateInitiatedBy {
+        match value.as_str() {
+            "system" => NodeUpdateInitiatedBy::System,
+            "customer" => NodeUpdateInitiatedBy::Customer,
+            _ => NodeUpdateInitiatedBy::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for NodeUpdateInitiatedBy {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<NodeUpdateInitiatedBy, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for NodeUpdateInitiatedBy {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for NodeUpdateInitiatedBy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for NodeUpdateInitiatedBy {
+    fn deserialize<D>(deserializer: D) -> Result<NodeUpdateInitiatedBy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+struct NodeUpdateInitiatedByDeserializer;
+impl NodeUpdateInitiatedByDeserializer {
+    #[allow(unused_variables)]
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<NodeUpdateInitiatedBy, XmlParseError> {
+        start_element(tag_name, stack)?;
+        let obj = characters(stack)?.into();
+        end_element(tag_name, stack)?;
+
+        Ok(obj)
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum NodeUpdateStatus {
+    /// `not-applied`
+    NotApplied,
+    /// `waiting-to-start`
+    WaitingToStart,
+    /// `in-progress`
+    InProgress,
+    /// `stopping`
+    Stopping,
+    /// `stopped`
+    Stopped,
+    /// `complete`
+    Complete,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl NodeUpdateStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            NodeUpdateStatus::NotApplied => "not-applied",
+            NodeUpdateStatus::WaitingToStart => "waiting-to-start",
+            NodeUpdateStatus::InProgress => "in-progress",
+            NodeUpdateStatus::Stopping => "stopping",
+            NodeUpdateStatus::Stopped => "stopped",
+            NodeUpdateStatus::Complete => "complete",
+            NodeUpdateStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for NodeUpdateStatus {
+    fn default() -> NodeUpdateStatus {
+        NodeUpdateStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for NodeUpdateStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for NodeUpdateStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for NodeUpdateStatus {
+    fn from(value: &'a str) -> NodeUpdateStatus {
+        match value {
+            "not-applied" => NodeUpdateStatus::NotApplied,
+            "waiting-to-start" => NodeUpdateStatus::WaitingToStart,
+            "in-progress" => NodeUpdateStatus::InProgress,
+            "stopping" => NodeUpdateStatus::Stopping,
+            "stopped" => NodeUpdateStatus::Stopped,
+            "complete" => NodeUpdateStatus::Complete,
+            _ => NodeUpdateStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for NodeUpdateStatus {
+    fn from(value: String) -> NodeUpdateStatus {
+        match value.as_str() {
+            "not-applied" => NodeUpdateStatus::NotApplied,
+            "waiting-to-start" => NodeUpdateStatus::WaitingToStart,
+            "in-progress" => NodeUpdateStatus::InProgress,
+            "stopping" => NodeUpdateStatus::Stopping,
+            "stopped" => NodeUpdateStatus::Stopped,
+            "complete" => NodeUpdateStatus::Complete,
+            _ => NodeUpdateStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for NodeUpdateStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<NodeUpdateStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for NodeUpdateStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for NodeUpdateStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for NodeUpdateStatus {
+    fn deserialize<D>(deserializer: D) -> Result<NodeUpdateStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+struct NodeUpdateStatusDeserializer;
+impl NodeUpdateStatusDeserializer {
+    #[allow(unused_variables)]
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<NodeUpdateStatus, XmlParseError> {
+        start_element(tag_name, stack)?;
+        let obj = characters(stack)?.into();
+        end_element(tag_name, stack)?;
+
+        Ok(obj)
+    }
+}
+/// <p>Describes a notification topic and its status. Notification topics are used for publishing ElastiCache events to subscribers using Amazon Simple Notification Service (SNS).</p>
+#[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize_structs", derive(Serialize))]
+pub struct NotificationConfiguration {
+    /// <p>The Amazon Resource Name (ARN) that identifies the topic.</p>
+    pub topic_arn: Option<String>,
+    /// <p>The current state of the topic.</p>
+    pub topic_status: Option<String>,
+}
+
+struct NotificationConfigurationDeserializer;
+impl NotificationConfigurationDeserializer {
+    #[allow(unused_variables)]
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<NotificationConfiguration, XmlParseError> {
         deserialize_elements::<_, NotificationConfiguration, _>(
             tag_name,
             stack,
@@ -5235,7 +5956,7 @@ pub struct Parameter {
     /// <p>The valid range of values for the parameter.</p>
     pub allowed_values: Option<String>,
     /// <p>Indicates whether a change to the parameter is applied immediately or requires a reboot for the change to be applied. You can force a reboot or wait until the next maintenance window's reboot. For more information, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/Clusters.Rebooting.html">Rebooting a Cluster</a>.</p>
-    pub change_type: Option<String>,
+    pub change_type: Option<ChangeType>,
     /// <p>The valid data type for the parameter.</p>
     pub data_type: Option<String>,
     /// <p>A description of the parameter.</p>
@@ -5358,12 +6079,110 @@ impl ParametersListDeserializer {
         })
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PendingAutomaticFailoverStatus {
+    /// `enabled`
+    Enabled,
+    /// `disabled`
+    Disabled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PendingAutomaticFailoverStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PendingAutomaticFailoverStatus::Enabled => "enabled",
+            PendingAutomaticFailoverStatus::Disabled => "disabled",
+            PendingAutomaticFailoverStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PendingAutomaticFailoverStatus {
+    fn default() -> PendingAutomaticFailoverStatus {
+        PendingAutomaticFailoverStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PendingAutomaticFailoverStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PendingAutomaticFailoverStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PendingAutomaticFailoverStatus {
+    fn from(value: &'a str) -> PendingAutomaticFailoverStatus {
+        match value {
+            "enabled" => PendingAutomaticFailoverStatus::Enabled,
+            "disabled" => PendingAutomaticFailoverStatus::Disabled,
+            _ => PendingAutomaticFailoverStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PendingAutomaticFailoverStatus {
+    fn from(value: String) -> PendingAutomaticFailoverStatus {
+        match value.as_str() {
+            "enabled" => PendingAutomaticFailoverStatus::Enabled,
+            "disabled" => PendingAutomaticFailoverStatus::Disabled,
+            _ => PendingAutomaticFailoverStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PendingAutomaticFailoverStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PendingAutomaticFailoverStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PendingAutomaticFailoverStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for PendingAutomaticFailoverStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for PendingAutomaticFailoverStatus {
+    fn deserialize<D>(deserializer: D) -> Result<PendingAutomaticFailoverStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct PendingAutomaticFailoverStatusDeserializer;
 impl PendingAutomaticFailoverStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<PendingAutomaticFailoverStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -5374,7 +6193,7 @@ impl PendingAutomaticFailoverStatusDeserializer {
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct PendingModifiedValues {
     /// <p>The auth token status</p>
-    pub auth_token_status: Option<String>,
+    pub auth_token_status: Option<AuthTokenUpdateStatus>,
     /// <p>A list of cache node IDs that are being removed (or will be removed) from the cluster. A node ID is a 4-digit numeric identifier (0001, 0002, etc.).</p>
     pub cache_node_ids_to_remove: Option<Vec<String>>,
     /// <p>The cache node type that this cluster or replication group is scaled to.</p>
@@ -5448,7 +6267,7 @@ pub struct ProcessedUpdateAction {
     /// <p>The unique ID of the service update</p>
     pub service_update_name: Option<String>,
     /// <p>The status of the update action on the Redis cluster</p>
-    pub update_action_status: Option<String>,
+    pub update_action_status: Option<UpdateActionStatus>,
 }
 
 struct ProcessedUpdateActionDeserializer;
@@ -5749,9 +6568,9 @@ pub struct ReplicationGroup {
     /// <p>A flag that enables using an <code>AuthToken</code> (password) when issuing Redis commands.</p> <p>Default: <code>false</code> </p>
     pub auth_token_enabled: Option<bool>,
     /// <p>The date the auth token was last modified</p>
-    pub auth_token_last_modified_date: Option<String>,
+    pub auth_token_last_modified_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>Indicates the status of Multi-AZ with automatic failover for this Redis replication group.</p> <p>Amazon ElastiCache for Redis does not support Multi-AZ with automatic failover on:</p> <ul> <li> <p>Redis versions earlier than 2.8.6.</p> </li> <li> <p>Redis (cluster mode disabled): T1 node types.</p> </li> <li> <p>Redis (cluster mode enabled): T1 node types.</p> </li> </ul></p>
-    pub automatic_failover: Option<String>,
+    pub automatic_failover: Option<AutomaticFailoverStatus>,
     /// <p>The name of the compute and memory capacity node type for each node in the replication group.</p>
     pub cache_node_type: Option<String>,
     /// <p>A flag indicating whether or not this replication group is cluster enabled; i.e., whether its data can be partitioned across multiple shards (API/CLI: node groups).</p> <p>Valid values: <code>true</code> | <code>false</code> </p>
@@ -5970,9 +6789,9 @@ impl ReplicationGroupMessageDeserializer {
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct ReplicationGroupPendingModifiedValues {
     /// <p>The auth token status</p>
-    pub auth_token_status: Option<String>,
+    pub auth_token_status: Option<AuthTokenUpdateStatus>,
     /// <p><p>Indicates the status of Multi-AZ with automatic failover for this Redis replication group.</p> <p>Amazon ElastiCache for Redis does not support Multi-AZ with automatic failover on:</p> <ul> <li> <p>Redis versions earlier than 2.8.6.</p> </li> <li> <p>Redis (cluster mode disabled): T1 node types.</p> </li> <li> <p>Redis (cluster mode enabled): T1 node types.</p> </li> </ul></p>
-    pub automatic_failover_status: Option<String>,
+    pub automatic_failover_status: Option<PendingAutomaticFailoverStatus>,
     /// <p>The primary cluster ID that is applied immediately (if <code>--apply-immediately</code> was specified), or during the next maintenance window.</p>
     pub primary_cluster_id: Option<String>,
     /// <p>The status of an online resharding operation.</p>
@@ -6047,7 +6866,7 @@ pub struct ReservedCacheNode {
     /// <p>The offering identifier.</p>
     pub reserved_cache_nodes_offering_id: Option<String>,
     /// <p>The time the reservation started.</p>
-    pub start_time: Option<String>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The state of the reserved cache node.</p>
     pub state: Option<String>,
     /// <p>The hourly price charged for this reserved cache node.</p>
@@ -6582,19 +7401,19 @@ pub struct ServiceUpdate {
     /// <p>Provides details of the service update</p>
     pub service_update_description: Option<String>,
     /// <p>The date after which the service update is no longer available</p>
-    pub service_update_end_date: Option<String>,
+    pub service_update_end_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The unique ID of the service update</p>
     pub service_update_name: Option<String>,
     /// <p>The recommendend date to apply the service update in order to ensure compliance. For information on compliance, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/elasticache-compliance.html#elasticache-compliance-self-service">Self-Service Security Updates for Compliance</a>.</p>
-    pub service_update_recommended_apply_by_date: Option<String>,
+    pub service_update_recommended_apply_by_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The date when the service update is initially available</p>
-    pub service_update_release_date: Option<String>,
+    pub service_update_release_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The severity of the service update</p>
-    pub service_update_severity: Option<String>,
+    pub service_update_severity: Option<ServiceUpdateSeverity>,
     /// <p>The status of the service update</p>
-    pub service_update_status: Option<String>,
+    pub service_update_status: Option<ServiceUpdateStatus>,
     /// <p>Reflects the nature of the service update</p>
-    pub service_update_type: Option<String>,
+    pub service_update_type: Option<ServiceUpdateType>,
 }
 
 struct ServiceUpdateDeserializer;
@@ -6700,23 +7519,234 @@ impl ServiceUpdateListDeserializer {
         })
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ServiceUpdateSeverity {
+    /// `critical`
+    Critical,
+    /// `important`
+    Important,
+    /// `medium`
+    Medium,
+    /// `low`
+    Low,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ServiceUpdateSeverity {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ServiceUpdateSeverity::Critical => "critical",
+            ServiceUpdateSeverity::Important => "important",
+            ServiceUpdateSeverity::Medium => "medium",
+            ServiceUpdateSeverity::Low => "low",
+            ServiceUpdateSeverity::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ServiceUpdateSeverity {
+    fn default() -> ServiceUpdateSeverity {
+        ServiceUpdateSeverity::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ServiceUpdateSeverity {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ServiceUpdateSeverity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ServiceUpdateSeverity {
+    fn from(value: &'a str) -> ServiceUpdateSeverity {
+        match value {
+            "critical" => ServiceUpdateSeverity::Critical,
+            "important" => ServiceUpdateSeverity::Important,
+            "medium" => ServiceUpdateSeverity::Medium,
+            "low" => ServiceUpdateSeverity::Low,
+            _ => ServiceUpdateSeverity::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ServiceUpdateSeverity {
+    fn from(value: String) -> ServiceUpdateSeverity {
+        match value.as_str() {
+            "critical" => ServiceUpdateSeverity::Critical,
+            "important" => ServiceUpdateSeverity::Important,
+            "medium" => ServiceUpdateSeverity::Medium,
+            "low" => ServiceUpdateSeverity::Low,
+            _ => ServiceUpdateSeverity::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ServiceUpdateSeverity {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ServiceUpdateSeverity, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ServiceUpdateSeverity {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ServiceUpdateSeverity {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ServiceUpdateSeverity {
+    fn deserialize<D>(deserializer: D) -> Result<ServiceUpdateSeverity, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct ServiceUpdateSeverityDeserializer;
 impl ServiceUpdateSeverityDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ServiceUpdateSeverity, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ServiceUpdateStatus {
+    /// `available`
+    Available,
+    /// `cancelled`
+    Cancelled,
+    /// `expired`
+    Expired,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ServiceUpdateStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ServiceUpdateStatus::Available => "available",
+            ServiceUpdateStatus::Cancelled => "cancelled",
+            ServiceUpdateStatus::Expired => "expired",
+            ServiceUpdateStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ServiceUpdateStatus {
+    fn default() -> ServiceUpdateStatus {
+        ServiceUpdateStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ServiceUpdateStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ServiceUpdateStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ServiceUpdateStatus {
+    fn from(value: &'a str) -> ServiceUpdateStatus {
+        match value {
+            "available" => ServiceUpdateStatus::Available,
+            "cancelled" => ServiceUpdateStatus::Cancelled,
+            "expired" => ServiceUpdateStatus::Expired,
+            _ => ServiceUpdateStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ServiceUpdateStatus {
+    fn from(value: String) -> ServiceUpdateStatus {
+        match value.as_str() {
+            "available" => ServiceUpdateStatus::Available,
+            "cancelled" => ServiceUpdateStatus::Cancelled,
+            "expired" => ServiceUpdateStatus::Expired,
+            _ => ServiceUpdateStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ServiceUpdateStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ServiceUpdateStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ServiceUpdateStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ServiceUpdateStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ServiceUpdateStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ServiceUpdateStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct ServiceUpdateStatusDeserializer;
 impl ServiceUpdateStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ServiceUpdateStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -6726,7 +7756,7 @@ impl ServiceUpdateStatusDeserializer {
 /// Serialize `ServiceUpdateStatusList` contents to a `SignedRequest`.
 struct ServiceUpdateStatusListSerializer;
 impl ServiceUpdateStatusListSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &Vec<String>) {
+    fn serialize(params: &mut Params, name: &str, obj: &Vec<ServiceUpdateStatus>) {
         for (index, obj) in obj.iter().enumerate() {
             let key = format!("{}.member.{}", name, index + 1);
             params.put(&key, &obj);
@@ -6734,12 +7764,105 @@ impl ServiceUpdateStatusListSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ServiceUpdateType {
+    /// `security-update`
+    SecurityUpdate,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ServiceUpdateType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ServiceUpdateType::SecurityUpdate => "security-update",
+            ServiceUpdateType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ServiceUpdateType {
+    fn default() -> ServiceUpdateType {
+        ServiceUpdateType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ServiceUpdateType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ServiceUpdateType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ServiceUpdateType {
+    fn from(value: &'a str) -> ServiceUpdateType {
+        match value {
+            "security-update" => ServiceUpdateType::SecurityUpdate,
+            _ => ServiceUpdateType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ServiceUpdateType {
+    fn from(value: String) -> ServiceUpdateType {
+        match value.as_str() {
+            "security-update" => ServiceUpdateType::SecurityUpdate,
+            _ => ServiceUpdateType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ServiceUpdateType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ServiceUpdateType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ServiceUpdateType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ServiceUpdateType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ServiceUpdateType {
+    fn deserialize<D>(deserializer: D) -> Result<ServiceUpdateType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct ServiceUpdateTypeDeserializer;
 impl ServiceUpdateTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ServiceUpdateType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -6777,12 +7900,112 @@ impl ServiceUpdatesMessageDeserializer {
         })
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SlaMet {
+    /// `yes`
+    Yes,
+    /// `no`
+    No,
+    /// `n/a`
+    NA,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SlaMet {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SlaMet::Yes => "yes",
+            SlaMet::No => "no",
+            SlaMet::NA => "n/a",
+            SlaMet::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SlaMet {
+    fn default() -> SlaMet {
+        SlaMet::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SlaMet {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SlaMet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SlaMet {
+    fn from(value: &'a str) -> SlaMet {
+        match value {
+            "yes" => SlaMet::Yes,
+            "no" => SlaMet::No,
+            "n/a" => SlaMet::NA,
+            _ => SlaMet::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SlaMet {
+    fn from(value: String) -> SlaMet {
+        match value.as_str() {
+            "yes" => SlaMet::Yes,
+            "no" => SlaMet::No,
+            "n/a" => SlaMet::NA,
+            _ => SlaMet::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SlaMet {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SlaMet, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SlaMet {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for SlaMet {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for SlaMet {
+    fn deserialize<D>(deserializer: D) -> Result<SlaMet, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct SlaMetDeserializer;
 impl SlaMetDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<SlaMet, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -6824,9 +8047,9 @@ pub struct Snapshot {
     /// <p>This parameter is currently disabled.</p>
     pub auto_minor_version_upgrade: Option<bool>,
     /// <p><p>Indicates the status of Multi-AZ with automatic failover for the source Redis replication group.</p> <p>Amazon ElastiCache for Redis does not support Multi-AZ with automatic failover on:</p> <ul> <li> <p>Redis versions earlier than 2.8.6.</p> </li> <li> <p>Redis (cluster mode disabled): T1 node types.</p> </li> <li> <p>Redis (cluster mode enabled): T1 node types.</p> </li> </ul></p>
-    pub automatic_failover: Option<String>,
+    pub automatic_failover: Option<AutomaticFailoverStatus>,
     /// <p>The date and time when the source cluster was created.</p>
-    pub cache_cluster_create_time: Option<String>,
+    pub cache_cluster_create_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The user-supplied identifier of the source cluster.</p>
     pub cache_cluster_id: Option<String>,
     /// <p><p>The name of the compute and memory capacity node type for the source cluster.</p> <p>The following node types are supported by ElastiCache. Generally speaking, the current generation types provide more memory and computational power at lower cost when compared to their equivalent previous generation counterparts.</p> <ul> <li> <p>General purpose:</p> <ul> <li> <p>Current generation: </p> <p> <b>M5 node types:</b> <code>cache.m5.large</code>, <code>cache.m5.xlarge</code>, <code>cache.m5.2xlarge</code>, <code>cache.m5.4xlarge</code>, <code>cache.m5.12xlarge</code>, <code>cache.m5.24xlarge</code> </p> <p> <b>M4 node types:</b> <code>cache.m4.large</code>, <code>cache.m4.xlarge</code>, <code>cache.m4.2xlarge</code>, <code>cache.m4.4xlarge</code>, <code>cache.m4.10xlarge</code> </p> <p> <b>T2 node types:</b> <code>cache.t2.micro</code>, <code>cache.t2.small</code>, <code>cache.t2.medium</code> </p> </li> <li> <p>Previous generation: (not recommended)</p> <p> <b>T1 node types:</b> <code>cache.t1.micro</code> </p> <p> <b>M1 node types:</b> <code>cache.m1.small</code>, <code>cache.m1.medium</code>, <code>cache.m1.large</code>, <code>cache.m1.xlarge</code> </p> <p> <b>M3 node types:</b> <code>cache.m3.medium</code>, <code>cache.m3.large</code>, <code>cache.m3.xlarge</code>, <code>cache.m3.2xlarge</code> </p> </li> </ul> </li> <li> <p>Compute optimized:</p> <ul> <li> <p>Previous generation: (not recommended)</p> <p> <b>C1 node types:</b> <code>cache.c1.xlarge</code> </p> </li> </ul> </li> <li> <p>Memory optimized:</p> <ul> <li> <p>Current generation: </p> <p> <b>R5 node types:</b> <code>cache.r5.large</code>, <code>cache.r5.xlarge</code>, <code>cache.r5.2xlarge</code>, <code>cache.r5.4xlarge</code>, <code>cache.r5.12xlarge</code>, <code>cache.r5.24xlarge</code> </p> <p> <b>R4 node types:</b> <code>cache.r4.large</code>, <code>cache.r4.xlarge</code>, <code>cache.r4.2xlarge</code>, <code>cache.r4.4xlarge</code>, <code>cache.r4.8xlarge</code>, <code>cache.r4.16xlarge</code> </p> </li> <li> <p>Previous generation: (not recommended)</p> <p> <b>M2 node types:</b> <code>cache.m2.xlarge</code>, <code>cache.m2.2xlarge</code>, <code>cache.m2.4xlarge</code> </p> <p> <b>R3 node types:</b> <code>cache.r3.large</code>, <code>cache.r3.xlarge</code>, <code>cache.r3.2xlarge</code>, <code>cache.r3.4xlarge</code>, <code>cache.r3.8xlarge</code> </p> </li> </ul> </li> </ul> <p> <b>Additional node type info</b> </p> <ul> <li> <p>All current generation instance types are created in Amazon VPC by default.</p> </li> <li> <p>Redis append-only files (AOF) are not supported for T1 or T2 instances.</p> </li> <li> <p>Redis Multi-AZ with automatic failover is not supported on T1 instances.</p> </li> <li> <p>Redis configuration variables <code>appendonly</code> and <code>appendfsync</code> are not supported on Redis version 2.8.22 and later.</p> </li> </ul></p>
@@ -7038,12 +8261,145 @@ impl SnapshotListDeserializer {
         })
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SourceType {
+    /// `cache-cluster`
+    CacheCluster,
+    /// `cache-parameter-group`
+    CacheParameterGroup,
+    /// `cache-security-group`
+    CacheSecurityGroup,
+    /// `cache-subnet-group`
+    CacheSubnetGroup,
+    /// `replication-group`
+    ReplicationGroup,
+    /// `serverless-cache`
+    ServerlessCache,
+    /// `serverless-cache-snapshot`
+    ServerlessCacheSnapshot,
+    /// `user`
+    User,
+    /// `user-group`
+    UserGroup,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SourceType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SourceType::CacheCluster => "cache-cluster",
+            SourceType::CacheParameterGroup => "cache-parameter-group",
+            SourceType::CacheSecurityGroup => "cache-security-group",
+            SourceType::CacheSubnetGroup => "cache-subnet-group",
+            SourceType::ReplicationGroup => "replication-group",
+            SourceType::ServerlessCache => "serverless-cache",
+            SourceType::ServerlessCacheSnapshot => "serverless-cache-snapshot",
+            SourceType::User => "user",
+            SourceType::UserGroup => "user-group",
+            SourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SourceType {
+    fn default() -> SourceType {
+        SourceType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SourceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SourceType {
+    fn from(value: &'a str) -> SourceType {
+        match value {
+            "cache-cluster" => SourceType::CacheCluster,
+            "cache-parameter-group" => SourceType::CacheParameterGroup,
+            "cache-security-group" => SourceType::CacheSecurityGroup,
+            "cache-subnet-group" => SourceType::CacheSubnetGroup,
+            "replication-group" => SourceType::ReplicationGroup,
+            "serverless-cache" => SourceType::ServerlessCache,
+            "serverless-cache-snapshot" => SourceType::ServerlessCacheSnapshot,
+            "user" => SourceType::User,
+            "user-group" => SourceType::UserGroup,
+            _ => SourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SourceType {
+    fn from(value: String) -> SourceType {
+        match value.as_str() {
+            "cache-cluster" => SourceType::CacheCluster,
+            "cache-parameter-group" => SourceType::CacheParameterGroup,
+            "cache-security-group" => SourceType::CacheSecurityGroup,
+            "cache-subnet-group" => SourceType::CacheSubnetGroup,
+            "replication-group" => SourceType::ReplicationGroup,
+            "serverless-cache" => SourceType::ServerlessCache,
+            "serverless-cache-snapshot" => SourceType::ServerlessCacheSnapshot,
+            "user" => SourceType::User,
+            "user-group" => SourceType::UserGroup,
+            _ => SourceType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SourceType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SourceType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SourceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for SourceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for SourceType {
+    fn deserialize<D>(deserializer: D) -> Result<SourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct SourceTypeDeserializer;
 impl SourceTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<SourceType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -7181,9 +8537,12 @@ impl SubnetListDeserializer {
 struct TStampDeserializer;
 impl TStampDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<::chrono::DateTime<::chrono::Utc>, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::proto::xml::util::timestamp_characters(stack)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -7352,9 +8711,9 @@ impl TestFailoverResultDeserializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct TimeRangeFilter {
     /// <p>The end time of the time range filter</p>
-    pub end_time: Option<String>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The start time of the time range filter</p>
-    pub start_time: Option<String>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// Serialize `TimeRangeFilter` contents to a `SignedRequest`.
@@ -7367,10 +8726,16 @@ impl TimeRangeFilterSerializer {
         }
 
         if let Some(ref field_value) = obj.end_time {
-            params.put(&format!("{}{}", prefix, "EndTime"), &field_value);
+            params.put(
+                &format!("{}{}", prefix, "EndTime"),
+                &rusoto_core::serialization::TimestampFormat::Iso8601.format(field_value),
+            );
         }
         if let Some(ref field_value) = obj.start_time {
-            params.put(&format!("{}{}", prefix, "StartTime"), &field_value);
+            params.put(
+                &format!("{}{}", prefix, "StartTime"),
+                &rusoto_core::serialization::TimestampFormat::Iso8601.format(field_value),
+            );
         }
     }
 }
@@ -7472,23 +8837,23 @@ pub struct UpdateAction {
     /// <p>The unique ID of the service update</p>
     pub service_update_name: Option<String>,
     /// <p>The recommended date to apply the service update to ensure compliance. For information on compliance, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/elasticache-compliance.html#elasticache-compliance-self-service">Self-Service Security Updates for Compliance</a>.</p>
-    pub service_update_recommended_apply_by_date: Option<String>,
+    pub service_update_recommended_apply_by_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The date the update is first available</p>
-    pub service_update_release_date: Option<String>,
+    pub service_update_release_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The severity of the service update</p>
-    pub service_update_severity: Option<String>,
+    pub service_update_severity: Option<ServiceUpdateSeverity>,
     /// <p>The status of the service update</p>
-    pub service_update_status: Option<String>,
+    pub service_update_status: Option<ServiceUpdateStatus>,
     /// <p>Reflects the nature of the service update </p>
-    pub service_update_type: Option<String>,
+    pub service_update_type: Option<ServiceUpdateType>,
     /// <p>If yes, all nodes in the replication group have been updated by the recommended apply-by date. If no, at least one node in the replication group have not been updated by the recommended apply-by date. If N/A, the replication group was created after the recommended apply-by date.</p>
-    pub sla_met: Option<String>,
+    pub sla_met: Option<SlaMet>,
     /// <p>The date that the service update is available to a replication group</p>
-    pub update_action_available_date: Option<String>,
+    pub update_action_available_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The status of the update action</p>
-    pub update_action_status: Option<String>,
+    pub update_action_status: Option<UpdateActionStatus>,
     /// <p>The date when the UpdateActionStatus was last modified</p>
-    pub update_action_status_modified_date: Option<String>,
+    pub update_action_status_modified_date: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 struct UpdateActionDeserializer;
@@ -7666,12 +9031,145 @@ impl UpdateActionResultsMessageDeserializer {
         )
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum UpdateActionStatus {
+    /// `not-applied`
+    NotApplied,
+    /// `waiting-to-start`
+    WaitingToStart,
+    /// `in-progress`
+    InProgress,
+    /// `stopping`
+    Stopping,
+    /// `stopped`
+    Stopped,
+    /// `complete`
+    Complete,
+    /// `scheduling`
+    Scheduling,
+    /// `scheduled`
+    Scheduled,
+    /// `not-applicable`
+    NotApplicable,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl UpdateActionStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            UpdateActionStatus::NotApplied => "not-applied",
+            UpdateActionStatus::WaitingToStart => "waiting-to-start",
+            UpdateActionStatus::InProgress => "in-progress",
+            UpdateActionStatus::Stopping => "stopping",
+            UpdateActionStatus::Stopped => "stopped",
+            UpdateActionStatus::Complete => "complete",
+            UpdateActionStatus::Scheduling => "scheduling",
+            UpdateActionStatus::Scheduled => "scheduled",
+            UpdateActionStatus::NotApplicable => "not-applicable",
+            UpdateActionStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for UpdateActionStatus {
+    fn default() -> UpdateActionStatus {
+        UpdateActionStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for UpdateActionStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for UpdateActionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for UpdateActionStatus {
+    fn from(value: &'a str) -> UpdateActionStatus {
+        match value {
+            "not-applied" => UpdateActionStatus::NotApplied,
+            "waiting-to-start" => UpdateActionStatus::WaitingToStart,
+            "in-progress" => UpdateActionStatus::InProgress,
+            "stopping" => UpdateActionStatus::Stopping,
+            "stopped" => UpdateActionStatus::Stopped,
+            "complete" => UpdateActionStatus::Complete,
+            "scheduling" => UpdateActionStatus::Scheduling,
+            "scheduled" => UpdateActionStatus::Scheduled,
+            "not-applicable" => UpdateActionStatus::NotApplicable,
+            _ => UpdateActionStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for UpdateActionStatus {
+    fn from(value: String) -> UpdateActionStatus {
+        match value.as_str() {
+            "not-applied" => UpdateActionStatus::NotApplied,
+            "waiting-to-start" => UpdateActionStatus::WaitingToStart,
+            "in-progress" => UpdateActionStatus::InProgress,
+            "stopping" => UpdateActionStatus::Stopping,
+            "stopped" => UpdateActionStatus::Stopped,
+            "complete" => UpdateActionStatus::Complete,
+            "scheduling" => UpdateActionStatus::Scheduling,
+            "scheduled" => UpdateActionStatus::Scheduled,
+            "not-applicable" => UpdateActionStatus::NotApplicable,
+            _ => UpdateActionStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for UpdateActionStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<UpdateActionStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for UpdateActionStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for UpdateActionStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for UpdateActionStatus {
+    fn deserialize<D>(deserializer: D) -> Result<UpdateActionStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct UpdateActionStatusDeserializer;
 impl UpdateActionStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<UpdateActionStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -7681,7 +9179,7 @@ impl UpdateActionStatusDeserializer {
 /// Serialize `UpdateActionStatusList` contents to a `SignedRequest`.
 struct UpdateActionStatusListSerializer;
 impl UpdateActionStatusListSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &Vec<String>) {
+    fn serialize(params: &mut Params, name: &str, obj: &Vec<UpdateActionStatus>) {
         for (index, obj) in obj.iter().enumerate() {
             let key = format!("{}.member.{}", name, index + 1);
             params.put(&key, &obj);
@@ -12189,11 +13687,12 @@ pub struct ElastiCacheClient {
 impl ElastiCacheClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> ElastiCacheClient {
         ElastiCacheClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "ElastiCache"),
         }
     }
 
@@ -12225,6 +13724,7 @@ impl ElastiCache for ElastiCacheClient {
         input: AddTagsToResourceMessage,
     ) -> Result<TagListMessage, RusotoError<AddTagsToResourceError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("AddTagsToResource");
         let mut params = Params::new();
 
         params.put("Action", "AddTagsToResource");
@@ -12275,6 +13775,7 @@ impl ElastiCache for ElastiCacheClient {
         RusotoError<AuthorizeCacheSecurityGroupIngressError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("AuthorizeCacheSecurityGroupIngress");
         let mut params = Params::new();
 
         params.put("Action", "AuthorizeCacheSecurityGroupIngress");
@@ -12326,6 +13827,7 @@ impl ElastiCache for ElastiCacheClient {
         input: BatchApplyUpdateActionMessage,
     ) -> Result<UpdateActionResultsMessage, RusotoError<BatchApplyUpdateActionError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("BatchApplyUpdateAction");
         let mut params = Params::new();
 
         params.put("Action", "BatchApplyUpdateAction");
@@ -12375,6 +13877,7 @@ impl ElastiCache for ElastiCacheClient {
         input: BatchStopUpdateActionMessage,
     ) -> Result<UpdateActionResultsMessage, RusotoError<BatchStopUpdateActionError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("BatchStopUpdateAction");
         let mut params = Params::new();
 
         params.put("Action", "BatchStopUpdateAction");
@@ -12424,6 +13927,7 @@ impl ElastiCache for ElastiCacheClient {
         input: CompleteMigrationMessage,
     ) -> Result<CompleteMigrationResponse, RusotoError<CompleteMigrationError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("CompleteMigration");
         let mut params = Params::new();
 
         params.put("Action", "CompleteMigration");
@@ -12473,6 +13977,7 @@ impl ElastiCache for ElastiCacheClient {
         input: CopySnapshotMessage,
     ) -> Result<CopySnapshotResult, RusotoError<CopySnapshotError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("CopySnapshot");
         let mut params = Params::new();
 
         params.put("Action", "CopySnapshot");
@@ -12519,6 +14024,7 @@ impl ElastiCache for ElastiCacheClient {
         input: CreateCacheClusterMessage,
     ) -> Result<CreateCacheClusterResult, RusotoError<CreateCacheClusterError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("CreateCacheCluster");
         let mut params = Params::new();
 
         params.put("Action", "CreateCacheCluster");
@@ -12568,6 +14074,7 @@ impl ElastiCache for ElastiCacheClient {
         input: CreateCacheParameterGroupMessage,
     ) -> Result<CreateCacheParameterGroupResult, RusotoError<CreateCacheParameterGroupError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("CreateCacheParameterGroup");
         let mut params = Params::new();
 
         params.put("Action", "CreateCacheParameterGroup");
@@ -12617,6 +14124,7 @@ impl ElastiCache for ElastiCacheClient {
         input: CreateCacheSecurityGroupMessage,
     ) -> Result<CreateCacheSecurityGroupResult, RusotoError<CreateCacheSecurityGroupError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("CreateCacheSecurityGroup");
         let mut params = Params::new();
 
         params.put("Action", "CreateCacheSecurityGroup");
@@ -12666,6 +14174,7 @@ impl ElastiCache for ElastiCacheClient {
         input: CreateCacheSubnetGroupMessage,
     ) -> Result<CreateCacheSubnetGroupResult, RusotoError<CreateCacheSubnetGroupError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("CreateCacheSubnetGroup");
         let mut params = Params::new();
 
         params.put("Action", "CreateCacheSubnetGroup");
@@ -12715,6 +14224,7 @@ impl ElastiCache for ElastiCacheClient {
         input: CreateReplicationGroupMessage,
     ) -> Result<CreateReplicationGroupResult, RusotoError<CreateReplicationGroupError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("CreateReplicationGroup");
         let mut params = Params::new();
 
         params.put("Action", "CreateReplicationGroup");
@@ -12764,6 +14274,7 @@ impl ElastiCache for ElastiCacheClient {
         input: CreateSnapshotMessage,
     ) -> Result<CreateSnapshotResult, RusotoError<CreateSnapshotError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("CreateSnapshot");
         let mut params = Params::new();
 
         params.put("Action", "CreateSnapshot");
@@ -12811,6 +14322,7 @@ impl ElastiCache for ElastiCacheClient {
         input: DecreaseReplicaCountMessage,
     ) -> Result<DecreaseReplicaCountResult, RusotoError<DecreaseReplicaCountError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DecreaseReplicaCount");
         let mut params = Params::new();
 
         params.put("Action", "DecreaseReplicaCount");
@@ -12860,6 +14372,7 @@ impl ElastiCache for ElastiCacheClient {
         input: DeleteCacheClusterMessage,
     ) -> Result<DeleteCacheClusterResult, RusotoError<DeleteCacheClusterError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DeleteCacheCluster");
         let mut params = Params::new();
 
         params.put("Action", "DeleteCacheCluster");
@@ -12909,6 +14422,7 @@ impl ElastiCache for ElastiCacheClient {
         input: DeleteCacheParameterGroupMessage,
     ) -> Result<(), RusotoError<DeleteCacheParameterGroupError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DeleteCacheParameterGroup");
         let mut params = Params::new();
 
         params.put("Action", "DeleteCacheParameterGroup");
@@ -12937,6 +14451,7 @@ impl ElastiCache for ElastiCacheClient {
         input: DeleteCacheSecurityGroupMessage,
     ) -> Result<(), RusotoError<DeleteCacheSecurityGroupError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DeleteCacheSecurityGroup");
         let mut params = Params::new();
 
         params.put("Action", "DeleteCacheSecurityGroup");
@@ -12965,6 +14480,7 @@ impl ElastiCache for ElastiCacheClient {
         input: DeleteCacheSubnetGroupMessage,
     ) -> Result<(), RusotoError<DeleteCacheSubnetGroupError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DeleteCacheSubnetGroup");
         let mut params = Params::new();
 
         params.put("Action", "DeleteCacheSubnetGroup");
@@ -12993,6 +14509,7 @@ impl ElastiCache for ElastiCacheClient {
         input: DeleteReplicationGroupMessage,
     ) -> Result<DeleteReplicationGroupResult, RusotoError<DeleteReplicationGroupError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DeleteReplicationGroup");
         let mut params = Params::new();
 
         params.put("Action", "DeleteReplicationGroup");
@@ -13042,6 +14559,7 @@ impl ElastiCache for ElastiCacheClient {
         input: DeleteSnapshotMessage,
     ) -> Result<DeleteSnapshotResult, RusotoError<DeleteSnapshotError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DeleteSnapshot");
         let mut params = Params::new();
 
         params.put("Action", "DeleteSnapshot");
@@ -13089,6 +14607,7 @@ impl ElastiCache for ElastiCacheClient {
         input: DescribeCacheClustersMessage,
     ) -> Result<CacheClusterMessage, RusotoError<DescribeCacheClustersError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DescribeCacheClusters");
         let mut params = Params::new();
 
         params.put("Action", "DescribeCacheClusters");
@@ -13138,6 +14657,7 @@ impl ElastiCache for ElastiCacheClient {
         input: DescribeCacheEngineVersionsMessage,
     ) -> Result<CacheEngineVersionMessage, RusotoError<DescribeCacheEngineVersionsError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DescribeCacheEngineVersions");
         let mut params = Params::new();
 
         params.put("Action", "DescribeCacheEngineVersions");
@@ -13187,6 +14707,7 @@ impl ElastiCache for ElastiCacheClient {
         input: DescribeCacheParameterGroupsMessage,
     ) -> Result<CacheParameterGroupsMessage, RusotoError<DescribeCacheParameterGroupsError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DescribeCacheParameterGroups");
         let mut params = Params::new();
 
         params.put("Action", "DescribeCacheParameterGroups");
@@ -13236,6 +14757,7 @@ impl ElastiCache for ElastiCacheClient {
         input: DescribeCacheParametersMessage,
     ) -> Result<CacheParameterGroupDetails, RusotoError<DescribeCacheParametersError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DescribeCacheParameters");
         let mut params = Params::new();
 
         params.put("Action", "DescribeCacheParameters");
@@ -13285,6 +14807,7 @@ impl ElastiCache for ElastiCacheClient {
         input: DescribeCacheSecurityGroupsMessage,
     ) -> Result<CacheSecurityGroupMessage, RusotoError<DescribeCacheSecurityGroupsError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DescribeCacheSecurityGroups");
         let mut params = Params::new();
 
         params.put("Action", "DescribeCacheSecurityGroups");
@@ -13334,6 +14857,7 @@ impl ElastiCache for ElastiCacheClient {
         input: DescribeCacheSubnetGroupsMessage,
     ) -> Result<CacheSubnetGroupMessage, RusotoError<DescribeCacheSubnetGroupsError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DescribeCacheSubnetGroups");
         let mut params = Params::new();
 
         params.put("Action", "DescribeCacheSubnetGroups");
@@ -13386,6 +14910,7 @@ impl ElastiCache for ElastiCacheClient {
         RusotoError<DescribeEngineDefaultParametersError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DescribeEngineDefaultParameters");
         let mut params = Params::new();
 
         params.put("Action", "DescribeEngineDefaultParameters");
@@ -13437,6 +14962,7 @@ impl ElastiCache for ElastiCacheClient {
         input: DescribeEventsMessage,
     ) -> Result<EventsMessage, RusotoError<DescribeEventsError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DescribeEvents");
         let mut params = Params::new();
 
         params.put("Action", "DescribeEvents");
@@ -13483,6 +15009,7 @@ impl ElastiCache for ElastiCacheClient {
         input: DescribeReplicationGroupsMessage,
     ) -> Result<ReplicationGroupMessage, RusotoError<DescribeReplicationGroupsError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DescribeReplicationGroups");
         let mut params = Params::new();
 
         params.put("Action", "DescribeReplicationGroups");
@@ -13532,6 +15059,7 @@ impl ElastiCache for ElastiCacheClient {
         input: DescribeReservedCacheNodesMessage,
     ) -> Result<ReservedCacheNodeMessage, RusotoError<DescribeReservedCacheNodesError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DescribeReservedCacheNodes");
         let mut params = Params::new();
 
         params.put("Action", "DescribeReservedCacheNodes");
@@ -13584,6 +15112,7 @@ impl ElastiCache for ElastiCacheClient {
         RusotoError<DescribeReservedCacheNodesOfferingsError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DescribeReservedCacheNodesOfferings");
         let mut params = Params::new();
 
         params.put("Action", "DescribeReservedCacheNodesOfferings");
@@ -13635,6 +15164,7 @@ impl ElastiCache for ElastiCacheClient {
         input: DescribeServiceUpdatesMessage,
     ) -> Result<ServiceUpdatesMessage, RusotoError<DescribeServiceUpdatesError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DescribeServiceUpdates");
         let mut params = Params::new();
 
         params.put("Action", "DescribeServiceUpdates");
@@ -13684,6 +15214,7 @@ impl ElastiCache for ElastiCacheClient {
         input: DescribeSnapshotsMessage,
     ) -> Result<DescribeSnapshotsListMessage, RusotoError<DescribeSnapshotsError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DescribeSnapshots");
         let mut params = Params::new();
 
         params.put("Action", "DescribeSnapshots");
@@ -13733,6 +15264,7 @@ impl ElastiCache for ElastiCacheClient {
         input: DescribeUpdateActionsMessage,
     ) -> Result<UpdateActionsMessage, RusotoError<DescribeUpdateActionsError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DescribeUpdateActions");
         let mut params = Params::new();
 
         params.put("Action", "DescribeUpdateActions");
@@ -13782,6 +15314,7 @@ impl ElastiCache for ElastiCacheClient {
         input: IncreaseReplicaCountMessage,
     ) -> Result<IncreaseReplicaCountResult, RusotoError<IncreaseReplicaCountError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("IncreaseReplicaCount");
         let mut params = Params::new();
 
         params.put("Action", "IncreaseReplicaCount");
@@ -13834,6 +15367,7 @@ impl ElastiCache for ElastiCacheClient {
         RusotoError<ListAllowedNodeTypeModificationsError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("ListAllowedNodeTypeModifications");
         let mut params = Params::new();
 
         params.put("Action", "ListAllowedNodeTypeModifications");
@@ -13885,6 +15419,7 @@ impl ElastiCache for ElastiCacheClient {
         input: ListTagsForResourceMessage,
     ) -> Result<TagListMessage, RusotoError<ListTagsForResourceError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("ListTagsForResource");
         let mut params = Params::new();
 
         params.put("Action", "ListTagsForResource");
@@ -13932,6 +15467,7 @@ impl ElastiCache for ElastiCacheClient {
         input: ModifyCacheClusterMessage,
     ) -> Result<ModifyCacheClusterResult, RusotoError<ModifyCacheClusterError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("ModifyCacheCluster");
         let mut params = Params::new();
 
         params.put("Action", "ModifyCacheCluster");
@@ -13981,6 +15517,7 @@ impl ElastiCache for ElastiCacheClient {
         input: ModifyCacheParameterGroupMessage,
     ) -> Result<CacheParameterGroupNameMessage, RusotoError<ModifyCacheParameterGroupError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("ModifyCacheParameterGroup");
         let mut params = Params::new();
 
         params.put("Action", "ModifyCacheParameterGroup");
@@ -14030,6 +15567,7 @@ impl ElastiCache for ElastiCacheClient {
         input: ModifyCacheSubnetGroupMessage,
     ) -> Result<ModifyCacheSubnetGroupResult, RusotoError<ModifyCacheSubnetGroupError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("ModifyCacheSubnetGroup");
         let mut params = Params::new();
 
         params.put("Action", "ModifyCacheSubnetGroup");
@@ -14079,6 +15617,7 @@ impl ElastiCache for ElastiCacheClient {
         input: ModifyReplicationGroupMessage,
     ) -> Result<ModifyReplicationGroupResult, RusotoError<ModifyReplicationGroupError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("ModifyReplicationGroup");
         let mut params = Params::new();
 
         params.put("Action", "ModifyReplicationGroup");
@@ -14131,6 +15670,7 @@ impl ElastiCache for ElastiCacheClient {
         RusotoError<ModifyReplicationGroupShardConfigurationError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("ModifyReplicationGroupShardConfiguration");
         let mut params = Params::new();
 
         params.put("Action", "ModifyReplicationGroupShardConfiguration");
@@ -14187,6 +15727,7 @@ impl ElastiCache for ElastiCacheClient {
         RusotoError<PurchaseReservedCacheNodesOfferingError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("PurchaseReservedCacheNodesOffering");
         let mut params = Params::new();
 
         params.put("Action", "PurchaseReservedCacheNodesOffering");
@@ -14238,6 +15779,7 @@ impl ElastiCache for ElastiCacheClient {
         input: RebootCacheClusterMessage,
     ) -> Result<RebootCacheClusterResult, RusotoError<RebootCacheClusterError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("RebootCacheCluster");
         let mut params = Params::new();
 
         params.put("Action", "RebootCacheCluster");
@@ -14287,6 +15829,7 @@ impl ElastiCache for ElastiCacheClient {
         input: RemoveTagsFromResourceMessage,
     ) -> Result<TagListMessage, RusotoError<RemoveTagsFromResourceError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("RemoveTagsFromResource");
         let mut params = Params::new();
 
         params.put("Action", "RemoveTagsFromResource");
@@ -14336,6 +15879,7 @@ impl ElastiCache for ElastiCacheClient {
         input: ResetCacheParameterGroupMessage,
     ) -> Result<CacheParameterGroupNameMessage, RusotoError<ResetCacheParameterGroupError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("ResetCacheParameterGroup");
         let mut params = Params::new();
 
         params.put("Action", "ResetCacheParameterGroup");
@@ -14388,6 +15932,7 @@ impl ElastiCache for ElastiCacheClient {
         RusotoError<RevokeCacheSecurityGroupIngressError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("RevokeCacheSecurityGroupIngress");
         let mut params = Params::new();
 
         params.put("Action", "RevokeCacheSecurityGroupIngress");
@@ -14439,6 +15984,7 @@ impl ElastiCache for ElastiCacheClient {
         input: StartMigrationMessage,
     ) -> Result<StartMigrationResponse, RusotoError<StartMigrationError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("StartMigration");
         let mut params = Params::new();
 
         params.put("Action", "StartMigration");
@@ -14488,6 +16034,7 @@ impl ElastiCache for ElastiCacheClient {
         input: TestFailoverMessage,
     ) -> Result<TestFailoverResult, RusotoError<TestFailoverError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("TestFailover");
         let mut params = Params::new();
 
         params.put("Action", "TestFailover");
@@ -14528,3 +16075,798 @@ impl ElastiCache for ElastiCacheClient {
         Ok(result)
     }
 }
+impl ElastiCacheClient {
+    /// Auto-paginating version of `describe_cache_clusters`, streaming every page of results.
+    pub fn describe_cache_clusters_pages(
+        &self,
+        input: DescribeCacheClustersMessage,
+    ) -> rusoto_core::pagination::RusotoStream<CacheClusterMessage, DescribeCacheClustersError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ElastiCacheClient, input: DescribeCacheClustersMessage| async move {
+                client.describe_cache_clusters(input).await
+            },
+            |input: &DescribeCacheClustersMessage, output: &CacheClusterMessage| {
+                Some(DescribeCacheClustersMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_cache_clusters`, streaming the `CacheClusters` of every page of results.
+    pub fn describe_cache_clusters_items(
+        &self,
+        input: DescribeCacheClustersMessage,
+    ) -> rusoto_core::pagination::RusotoStream<CacheCluster, DescribeCacheClustersError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_cache_clusters_pages(input),
+            |output: CacheClusterMessage| output.cache_clusters.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_cache_engine_versions`, streaming every page of results.
+    pub fn describe_cache_engine_versions_pages(
+        &self,
+        input: DescribeCacheEngineVersionsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        CacheEngineVersionMessage,
+        DescribeCacheEngineVersionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ElastiCacheClient, input: DescribeCacheEngineVersionsMessage| async move {
+                client.describe_cache_engine_versions(input).await
+            },
+            |input: &DescribeCacheEngineVersionsMessage, output: &CacheEngineVersionMessage| {
+                Some(DescribeCacheEngineVersionsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_cache_engine_versions`, streaming the `CacheEngineVersions` of every page of results.
+    pub fn describe_cache_engine_versions_items(
+        &self,
+        input: DescribeCacheEngineVersionsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<CacheEngineVersion, DescribeCacheEngineVersionsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_cache_engine_versions_pages(input),
+            |output: CacheEngineVersionMessage| output.cache_engine_versions.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_cache_parameter_groups`, streaming every page of results.
+    pub fn describe_cache_parameter_groups_pages(
+        &self,
+        input: DescribeCacheParameterGroupsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        CacheParameterGroupsMessage,
+        DescribeCacheParameterGroupsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ElastiCacheClient, input: DescribeCacheParameterGroupsMessage| async move {
+                client.describe_cache_parameter_groups(input).await
+            },
+            |input: &DescribeCacheParameterGroupsMessage, output: &CacheParameterGroupsMessage| {
+                Some(DescribeCacheParameterGroupsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_cache_parameter_groups`, streaming the `CacheParameterGroups` of every page of results.
+    pub fn describe_cache_parameter_groups_items(
+        &self,
+        input: DescribeCacheParameterGroupsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<CacheParameterGroup, DescribeCacheParameterGroupsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_cache_parameter_groups_pages(input),
+            |output: CacheParameterGroupsMessage| output.cache_parameter_groups.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_cache_parameters`, streaming every page of results.
+    pub fn describe_cache_parameters_pages(
+        &self,
+        input: DescribeCacheParametersMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        CacheParameterGroupDetails,
+        DescribeCacheParametersError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ElastiCacheClient, input: DescribeCacheParametersMessage| async move {
+                client.describe_cache_parameters(input).await
+            },
+            |input: &DescribeCacheParametersMessage, output: &CacheParameterGroupDetails| {
+                Some(DescribeCacheParametersMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_cache_parameters`, streaming the `Parameters` of every page of results.
+    pub fn describe_cache_parameters_items(
+        &self,
+        input: DescribeCacheParametersMessage,
+    ) -> rusoto_core::pagination::RusotoStream<Parameter, DescribeCacheParametersError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_cache_parameters_pages(input),
+            |output: CacheParameterGroupDetails| output.parameters.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_cache_security_groups`, streaming every page of results.
+    pub fn describe_cache_security_groups_pages(
+        &self,
+        input: DescribeCacheSecurityGroupsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        CacheSecurityGroupMessage,
+        DescribeCacheSecurityGroupsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ElastiCacheClient, input: DescribeCacheSecurityGroupsMessage| async move {
+                client.describe_cache_security_groups(input).await
+            },
+            |input: &DescribeCacheSecurityGroupsMessage, output: &CacheSecurityGroupMessage| {
+                Some(DescribeCacheSecurityGroupsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_cache_security_groups`, streaming the `CacheSecurityGroups` of every page of results.
+    pub fn describe_cache_security_groups_items(
+        &self,
+        input: DescribeCacheSecurityGroupsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<CacheSecurityGroup, DescribeCacheSecurityGroupsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_cache_security_groups_pages(input),
+            |output: CacheSecurityGroupMessage| output.cache_security_groups.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_cache_subnet_groups`, streaming every page of results.
+    pub fn describe_cache_subnet_groups_pages(
+        &self,
+        input: DescribeCacheSubnetGroupsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        CacheSubnetGroupMessage,
+        DescribeCacheSubnetGroupsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ElastiCacheClient, input: DescribeCacheSubnetGroupsMessage| async move {
+                client.describe_cache_subnet_groups(input).await
+            },
+            |input: &DescribeCacheSubnetGroupsMessage, output: &CacheSubnetGroupMessage| {
+                Some(DescribeCacheSubnetGroupsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_cache_subnet_groups`, streaming the `CacheSubnetGroups` of every page of results.
+    pub fn describe_cache_subnet_groups_items(
+        &self,
+        input: DescribeCacheSubnetGroupsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<CacheSubnetGroup, DescribeCacheSubnetGroupsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_cache_subnet_groups_pages(input),
+            |output: CacheSubnetGroupMessage| output.cache_subnet_groups.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_engine_default_parameters`, streaming every page of results.
+    pub fn describe_engine_default_parameters_pages(
+        &self,
+        input: DescribeEngineDefaultParametersMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeEngineDefaultParametersResult,
+        DescribeEngineDefaultParametersError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ElastiCacheClient, input: DescribeEngineDefaultParametersMessage| async move {
+                client.describe_engine_default_parameters(input).await
+            },
+            |input: &DescribeEngineDefaultParametersMessage,
+             output: &DescribeEngineDefaultParametersResult| {
+                Some(DescribeEngineDefaultParametersMessage {
+                    marker: Some(
+                        output
+                            .engine_defaults
+                            .as_ref()
+                            .and_then(|v| v.marker.clone())?,
+                    ),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_engine_default_parameters`, streaming the `EngineDefaults.Parameters` of every page of results.
+    pub fn describe_engine_default_parameters_items(
+        &self,
+        input: DescribeEngineDefaultParametersMessage,
+    ) -> rusoto_core::pagination::RusotoStream<Parameter, DescribeEngineDefaultParametersError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_engine_default_parameters_pages(input),
+            |output: DescribeEngineDefaultParametersResult| {
+                output
+                    .engine_defaults
+                    .and_then(|v| v.parameters)
+                    .unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_events`, streaming every page of results.
+    pub fn describe_events_pages(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<EventsMessage, DescribeEventsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ElastiCacheClient, input: DescribeEventsMessage| async move {
+                client.describe_events(input).await
+            },
+            |input: &DescribeEventsMessage, output: &EventsMessage| {
+                Some(DescribeEventsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_events`, streaming the `Events` of every page of results.
+    pub fn describe_events_items(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<Event, DescribeEventsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_events_pages(input),
+            |output: EventsMessage| output.events.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_replication_groups`, streaming every page of results.
+    pub fn describe_replication_groups_pages(
+        &self,
+        input: DescribeReplicationGroupsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ReplicationGroupMessage,
+        DescribeReplicationGroupsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ElastiCacheClient, input: DescribeReplicationGroupsMessage| async move {
+                client.describe_replication_groups(input).await
+            },
+            |input: &DescribeReplicationGroupsMessage, output: &ReplicationGroupMessage| {
+                Some(DescribeReplicationGroupsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_replication_groups`, streaming the `ReplicationGroups` of every page of results.
+    pub fn describe_replication_groups_items(
+        &self,
+        input: DescribeReplicationGroupsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<ReplicationGroup, DescribeReplicationGroupsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_replication_groups_pages(input),
+            |output: ReplicationGroupMessage| output.replication_groups.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_reserved_cache_nodes`, streaming every page of results.
+    pub fn describe_reserved_cache_nodes_pages(
+        &self,
+        input: DescribeReservedCacheNodesMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ReservedCacheNodeMessage,
+        DescribeReservedCacheNodesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ElastiCacheClient, input: DescribeReservedCacheNodesMessage| async move {
+                client.describe_reserved_cache_nodes(input).await
+            },
+            |input: &DescribeReservedCacheNodesMessage, output: &ReservedCacheNodeMessage| {
+                Some(DescribeReservedCacheNodesMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_reserved_cache_nodes`, streaming the `ReservedCacheNodes` of every page of results.
+    pub fn describe_reserved_cache_nodes_items(
+        &self,
+        input: DescribeReservedCacheNodesMessage,
+    ) -> rusoto_core::pagination::RusotoStream<ReservedCacheNode, DescribeReservedCacheNodesError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_reserved_cache_nodes_pages(input),
+            |output: ReservedCacheNodeMessage| output.reserved_cache_nodes.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_reserved_cache_nodes_offerings`, streaming every page of results.
+    pub fn describe_reserved_cache_nodes_offerings_pages(
+        &self,
+        input: DescribeReservedCacheNodesOfferingsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ReservedCacheNodesOfferingMessage,
+        DescribeReservedCacheNodesOfferingsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ElastiCacheClient, input: DescribeReservedCacheNodesOfferingsMessage| async move {
+                client.describe_reserved_cache_nodes_offerings(input).await
+            },
+            |input: &DescribeReservedCacheNodesOfferingsMessage,
+             output: &ReservedCacheNodesOfferingMessage| {
+                Some(DescribeReservedCacheNodesOfferingsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_reserved_cache_nodes_offerings`, streaming the `ReservedCacheNodesOfferings` of every page of results.
+    pub fn describe_reserved_cache_nodes_offerings_items(
+        &self,
+        input: DescribeReservedCacheNodesOfferingsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ReservedCacheNodesOffering,
+        DescribeReservedCacheNodesOfferingsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_reserved_cache_nodes_offerings_pages(input),
+            |output: ReservedCacheNodesOfferingMessage| {
+                output.reserved_cache_nodes_offerings.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_service_updates`, streaming every page of results.
+    pub fn describe_service_updates_pages(
+        &self,
+        input: DescribeServiceUpdatesMessage,
+    ) -> rusoto_core::pagination::RusotoStream<ServiceUpdatesMessage, DescribeServiceUpdatesError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ElastiCacheClient, input: DescribeServiceUpdatesMessage| async move {
+                client.describe_service_updates(input).await
+            },
+            |input: &DescribeServiceUpdatesMessage, output: &ServiceUpdatesMessage| {
+                Some(DescribeServiceUpdatesMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_service_updates`, streaming the `ServiceUpdates` of every page of results.
+    pub fn describe_service_updates_items(
+        &self,
+        input: DescribeServiceUpdatesMessage,
+    ) -> rusoto_core::pagination::RusotoStream<ServiceUpdate, DescribeServiceUpdatesError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_service_updates_pages(input),
+            |output: ServiceUpdatesMessage| output.service_updates.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_snapshots`, streaming every page of results.
+    pub fn describe_snapshots_pages(
+        &self,
+        input: DescribeSnapshotsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeSnapshotsListMessage, DescribeSnapshotsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ElastiCacheClient, input: DescribeSnapshotsMessage| async move {
+                client.describe_snapshots(input).await
+            },
+            |input: &DescribeSnapshotsMessage, output: &DescribeSnapshotsListMessage| {
+                Some(DescribeSnapshotsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_snapshots`, streaming the `Snapshots` of every page of results.
+    pub fn describe_snapshots_items(
+        &self,
+        input: DescribeSnapshotsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<Snapshot, DescribeSnapshotsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_snapshots_pages(input),
+            |output: DescribeSnapshotsListMessage| output.snapshots.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_update_actions`, streaming every page of results.
+    pub fn describe_update_actions_pages(
+        &self,
+        input: DescribeUpdateActionsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<UpdateActionsMessage, DescribeUpdateActionsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ElastiCacheClient, input: DescribeUpdateActionsMessage| async move {
+                client.describe_update_actions(input).await
+            },
+            |input: &DescribeUpdateActionsMessage, output: &UpdateActionsMessage| {
+                Some(DescribeUpdateActionsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_update_actions`, streaming the `UpdateActions` of every page of results.
+    pub fn describe_update_actions_items(
+        &self,
+        input: DescribeUpdateActionsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<UpdateAction, DescribeUpdateActionsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_update_actions_pages(input),
+            |output: UpdateActionsMessage| output.update_actions.unwrap_or_default(),
+        )
+    }
+}
+impl ElastiCacheClient {
+    /// Waits until `describe_cache_clusters` reports the `CacheClusterAvailable` state, polling every 15 seconds up to 40 times.
+    pub async fn wait_until_cache_cluster_available(
+        &self,
+        input: DescribeCacheClustersMessage,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeCacheClustersError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(15), 40);
+        self.wait_until_cache_cluster_available_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_cache_cluster_available`, polling as often and as many times as `config` says.
+    pub async fn wait_until_cache_cluster_available_with_config(
+        &self,
+        input: DescribeCacheClustersMessage,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeCacheClustersError>> {
+        rusoto_core::waiter::wait(
+            "CacheClusterAvailable",
+            &config,
+            || self.describe_cache_clusters(input.clone()),
+            |result: &Result<CacheClusterMessage, RusotoError<DescribeCacheClustersError>>| {
+                match result {
+                    Ok(output)
+                        if rusoto_core::waiter::all_match(
+                            output
+                                .cache_clusters
+                                .iter()
+                                .flat_map(|v| v.iter())
+                                .flat_map(|v| v.cache_cluster_status.iter()),
+                            |v| v == "available",
+                        ) =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Success)
+                    }
+                    Ok(output)
+                        if output
+                            .cache_clusters
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.cache_cluster_status.iter())
+                            .any(|v| v == "deleted") =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    Ok(output)
+                        if output
+                            .cache_clusters
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.cache_cluster_status.iter())
+                            .any(|v| v == "deleting") =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    Ok(output)
+                        if output
+                            .cache_clusters
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.cache_cluster_status.iter())
+                            .any(|v| v == "incompatible-network") =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    Ok(output)
+                        if output
+                            .cache_clusters
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.cache_cluster_status.iter())
+                            .any(|v| v == "restore-failed") =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    _ => None,
+                }
+            },
+        )
+        .await
+    }
+
+    /// Waits until `describe_cache_clusters` reports the `CacheClusterDeleted` state, polling every 15 seconds up to 40 times.
+    pub async fn wait_until_cache_cluster_deleted(
+        &self,
+        input: DescribeCacheClustersMessage,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeCacheClustersError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(15), 40);
+        self.wait_until_cache_cluster_deleted_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_cache_cluster_deleted`, polling as often and as many times as `config` says.
+    pub async fn wait_until_cache_cluster_deleted_with_config(
+        &self,
+        input: DescribeCacheClustersMessage,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeCacheClustersError>> {
+        rusoto_core::waiter::wait(
+            "CacheClusterDeleted",
+            &config,
+            || self.describe_cache_clusters(input.clone()),
+            |result: &Result<CacheClusterMessage, RusotoError<DescribeCacheClustersError>>| {
+                match result {
+                    Ok(output)
+                        if rusoto_core::waiter::all_match(
+                            output
+                                .cache_clusters
+                                .iter()
+                                .flat_map(|v| v.iter())
+                                .flat_map(|v| v.cache_cluster_status.iter()),
+                            |v| v == "deleted",
+                        ) =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Success)
+                    }
+                    Err(RusotoError::Service(
+                        DescribeCacheClustersError::CacheClusterNotFoundFault(_),
+                    )) => Some(rusoto_core::waiter::WaiterState::Success),
+                    Err(RusotoError::Unknown(response))
+                        if rusoto_core::retry::error_code(response).as_deref()
+                            == Some("CacheClusterNotFound") =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Success)
+                    }
+                    Ok(output)
+                        if output
+                            .cache_clusters
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.cache_cluster_status.iter())
+                            .any(|v| v == "available") =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    Ok(output)
+                        if output
+                            .cache_clusters
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.cache_cluster_status.iter())
+                            .any(|v| v == "creating") =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    Ok(output)
+                        if output
+                            .cache_clusters
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.cache_cluster_status.iter())
+                            .any(|v| v == "incompatible-network") =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    Ok(output)
+                        if output
+                            .cache_clusters
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.cache_cluster_status.iter())
+                            .any(|v| v == "modifying") =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    Ok(output)
+                        if output
+                            .cache_clusters
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.cache_cluster_status.iter())
+                            .any(|v| v == "restore-failed") =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    Ok(output)
+                        if output
+                            .cache_clusters
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.cache_cluster_status.iter())
+                            .any(|v| v == "snapshotting") =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    _ => None,
+                }
+            },
+        )
+        .await
+    }
+
+    /// Waits until `describe_replication_groups` reports the `ReplicationGroupAvailable` state, polling every 15 seconds up to 40 times.
+    pub async fn wait_until_replication_group_available(
+        &self,
+        input: DescribeReplicationGroupsMessage,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeReplicationGroupsError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(15), 40);
+        self.wait_until_replication_group_available_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_replication_group_available`, polling as often and as many times as `config` says.
+    pub async fn wait_until_replication_group_available_with_config(
+        &self,
+        input: DescribeReplicationGroupsMessage,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeReplicationGroupsError>> {
+        rusoto_core::waiter::wait(
+            "ReplicationGroupAvailable",
+            &config,
+            || self.describe_replication_groups(input.clone()),
+            |result: &Result<
+                ReplicationGroupMessage,
+                RusotoError<DescribeReplicationGroupsError>,
+            >| match result {
+                Ok(output)
+                    if rusoto_core::waiter::all_match(
+                        output
+                            .replication_groups
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.status.iter()),
+                        |v| v == "available",
+                    ) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if output
+                        .replication_groups
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| v == "deleted") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+
+    /// Waits until `describe_replication_groups` reports the `ReplicationGroupDeleted` state, polling every 15 seconds up to 40 times.
+    pub async fn wait_until_replication_group_deleted(
+        &self,
+        input: DescribeReplicationGroupsMessage,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeReplicationGroupsError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(15), 40);
+        self.wait_until_replication_group_deleted_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_replication_group_deleted`, polling as often and as many times as `config` says.
+    pub async fn wait_until_replication_group_deleted_with_config(
+        &self,
+        input: DescribeReplicationGroupsMessage,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeReplicationGroupsError>> {
+        rusoto_core::waiter::wait(
+            "ReplicationGroupDeleted",
+            &config,
+            || self.describe_replication_groups(input.clone()),
+            |result: &Result<
+                ReplicationGroupMessage,
+                RusotoError<DescribeReplicationGroupsError>,
+            >| match result {
+                Ok(output)
+                    if rusoto_core::waiter::all_match(
+                        output
+                            .replication_groups
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.status.iter()),
+                        |v| v == "deleted",
+                    ) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if output
+                        .replication_groups
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| v == "available") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Err(RusotoError::Service(
+                    DescribeReplicationGroupsError::ReplicationGroupNotFoundFault(_),
+                )) => Some(rusoto_core::waiter::WaiterState::Success),
+                Err(RusotoError::Unknown(response))
+                    if rusoto_core::retry::error_code(response).as_deref()
+                        == Some("ReplicationGroupNotFoundFault") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+}
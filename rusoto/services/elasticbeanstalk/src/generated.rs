@@ -82,34 +82,348 @@ impl AbortableOperationInProgressDeserializer {
         Ok(obj)
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ActionHistoryStatus {
+    /// `Completed`
+    Completed,
+    /// `Failed`
+    Failed,
+    /// `Unknown`
+    UnknownValue,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ActionHistoryStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ActionHistoryStatus::Completed => "Completed",
+            ActionHistoryStatus::Failed => "Failed",
+            ActionHistoryStatus::UnknownValue => "Unknown",
+            ActionHistoryStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ActionHistoryStatus {
+    fn default() -> ActionHistoryStatus {
+        ActionHistoryStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ActionHistoryStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ActionHistoryStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ActionHistoryStatus {
+    fn from(value: &'a str) -> ActionHistoryStatus {
+        match value {
+            "Completed" => ActionHistoryStatus::Completed,
+            "Failed" => ActionHistoryStatus::Failed,
+            "Unknown" => ActionHistoryStatus::UnknownValue,
+            _ => ActionHistoryStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ActionHistoryStatus {
+    fn from(value: String) -> ActionHistoryStatus {
+        match value.as_str() {
+            "Completed" => ActionHistoryStatus::Completed,
+            "Failed" => ActionHistoryStatus::Failed,
+            "Unknown" => ActionHistoryStatus::UnknownValue,
+            _ => ActionHistoryStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ActionHistoryStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ActionHistoryStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ActionHistoryStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ActionHistoryStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ActionHistoryStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ActionHistoryStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct ActionHistoryStatusDeserializer;
 impl ActionHistoryStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ActionHistoryStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ActionStatus {
+    /// `Scheduled`
+    Scheduled,
+    /// `Pending`
+    Pending,
+    /// `Running`
+    Running,
+    /// `Unknown`
+    UnknownValue,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ActionStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ActionStatus::Scheduled => "Scheduled",
+            ActionStatus::Pending => "Pending",
+            ActionStatus::Running => "Running",
+            ActionStatus::UnknownValue => "Unknown",
+            ActionStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ActionStatus {
+    fn default() -> ActionStatus {
+        ActionStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ActionStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ActionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ActionStatus {
+    fn from(value: &'a str) -> ActionStatus {
+        match value {
+            "Scheduled" => ActionStatus::Scheduled,
+            "Pending" => ActionStatus::Pending,
+            "Running" => ActionStatus::Running,
+            "Unknown" => ActionStatus::UnknownValue,
+            _ => ActionStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ActionStatus {
+    fn from(value: String) -> ActionStatus {
+        match value.as_str() {
+            "Scheduled" => ActionStatus::Scheduled,
+            "Pending" => ActionStatus::Pending,
+            "Running" => ActionStatus::Running,
+            "Unknown" => ActionStatus::UnknownValue,
+            _ => ActionStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ActionStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ActionStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ActionStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ActionStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ActionStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ActionStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct ActionStatusDeserializer;
 impl ActionStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ActionStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ActionType {
+    /// `InstanceRefresh`
+    InstanceRefresh,
+    /// `PlatformUpdate`
+    PlatformUpdate,
+    /// `Unknown`
+    UnknownValue,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ActionType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ActionType::InstanceRefresh => "InstanceRefresh",
+            ActionType::PlatformUpdate => "PlatformUpdate",
+            ActionType::UnknownValue => "Unknown",
+            ActionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ActionType {
+    fn default() -> ActionType {
+        ActionType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ActionType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ActionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ActionType {
+    fn from(value: &'a str) -> ActionType {
+        match value {
+            "InstanceRefresh" => ActionType::InstanceRefresh,
+            "PlatformUpdate" => ActionType::PlatformUpdate,
+            "Unknown" => ActionType::UnknownValue,
+            _ => ActionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ActionType {
+    fn from(value: String) -> ActionType {
+        match value.as_str() {
+            "InstanceRefresh" => ActionType::InstanceRefresh,
+            "PlatformUpdate" => ActionType::PlatformUpdate,
+            "Unknown" => ActionType::UnknownValue,
+            _ => ActionType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ActionType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ActionType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ActionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ActionType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ActionType {
+    fn deserialize<D>(deserializer: D) -> Result<ActionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct ActionTypeDeserializer;
 impl ActionTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ActionType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -137,9 +451,9 @@ pub struct ApplicationDescription {
     /// <p>The names of the configuration templates associated with this application.</p>
     pub configuration_templates: Option<Vec<String>>,
     /// <p>The date when the application was created.</p>
-    pub date_created: Option<String>,
+    pub date_created: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The date when the application was last modified.</p>
-    pub date_updated: Option<String>,
+    pub date_updated: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>User-defined description of the application.</p>
     pub description: Option<String>,
     /// <p>The lifecycle settings for the application.</p>
@@ -490,9 +804,9 @@ pub struct ApplicationVersionDescription {
     /// <p>Reference to the artifact from the AWS CodeBuild build.</p>
     pub build_arn: Option<String>,
     /// <p>The creation date of the application version.</p>
-    pub date_created: Option<String>,
+    pub date_created: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The last modified date of the application version.</p>
-    pub date_updated: Option<String>,
+    pub date_updated: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The description of the application version.</p>
     pub description: Option<String>,
     /// <p>If the version's source code was retrieved from AWS CodeCommit, the location of the source code for the application version.</p>
@@ -500,7 +814,7 @@ pub struct ApplicationVersionDescription {
     /// <p>The storage location of the application version's source bundle in Amazon S3.</p>
     pub source_bundle: Option<S3Location>,
     /// <p><p>The processing status of the application version. Reflects the state of the application version during its creation. Many of the values are only applicable if you specified <code>True</code> for the <code>Process</code> parameter of the <code>CreateApplicationVersion</code> action. The following list describes the possible values.</p> <ul> <li> <p> <code>Unprocessed</code> – Application version wasn&#39;t pre-processed or validated. Elastic Beanstalk will validate configuration files during deployment of the application version to an environment.</p> </li> <li> <p> <code>Processing</code> – Elastic Beanstalk is currently processing the application version.</p> </li> <li> <p> <code>Building</code> – Application version is currently undergoing an AWS CodeBuild build.</p> </li> <li> <p> <code>Processed</code> – Elastic Beanstalk was successfully pre-processed and validated.</p> </li> <li> <p> <code>Failed</code> – Either the AWS CodeBuild build failed or configuration files didn&#39;t pass validation. This application version isn&#39;t usable.</p> </li> </ul></p>
-    pub status: Option<String>,
+    pub status: Option<ApplicationVersionStatus>,
     /// <p>A unique identifier for the application version.</p>
     pub version_label: Option<String>,
 }
@@ -734,12 +1048,125 @@ impl ApplicationVersionLifecycleConfigSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ApplicationVersionStatus {
+    /// `Processed`
+    Processed,
+    /// `Unprocessed`
+    Unprocessed,
+    /// `Failed`
+    Failed,
+    /// `Processing`
+    Processing,
+    /// `Building`
+    Building,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ApplicationVersionStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ApplicationVersionStatus::Processed => "Processed",
+            ApplicationVersionStatus::Unprocessed => "Unprocessed",
+            ApplicationVersionStatus::Failed => "Failed",
+            ApplicationVersionStatus::Processing => "Processing",
+            ApplicationVersionStatus::Building => "Building",
+            ApplicationVersionStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ApplicationVersionStatus {
+    fn default() -> ApplicationVersionStatus {
+        ApplicationVersionStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ApplicationVersionStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ApplicationVersionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ApplicationVersionStatus {
+    fn from(value: &'a str) -> ApplicationVersionStatus {
+        match value {
+            "Processed" => ApplicationVersionStatus::Processed,
+            "Unprocessed" => ApplicationVersionStatus::Unprocessed,
+            "Failed" => ApplicationVersionStatus::Failed,
+            "Processing" => ApplicationVersionStatus::Processing,
+            "Building" => ApplicationVersionStatus::Building,
+            _ => ApplicationVersionStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ApplicationVersionStatus {
+    fn from(value: String) -> ApplicationVersionStatus {
+        match value.as_str() {
+            "Processed" => ApplicationVersionStatus::Processed,
+            "Unprocessed" => ApplicationVersionStatus::Unprocessed,
+            "Failed" => ApplicationVersionStatus::Failed,
+            "Processing" => ApplicationVersionStatus::Processing,
+            "Building" => ApplicationVersionStatus::Building,
+            _ => ApplicationVersionStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ApplicationVersionStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ApplicationVersionStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ApplicationVersionStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ApplicationVersionStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ApplicationVersionStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ApplicationVersionStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct ApplicationVersionStatusDeserializer;
 impl ApplicationVersionStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ApplicationVersionStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -785,7 +1212,7 @@ pub struct ApplyEnvironmentManagedActionResult {
     /// <p>The action ID of the managed action.</p>
     pub action_id: Option<String>,
     /// <p>The type of managed action.</p>
-    pub action_type: Option<String>,
+    pub action_type: Option<ActionType>,
     /// <p>The status of the managed action.</p>
     pub status: Option<String>,
 }
@@ -933,7 +1360,7 @@ pub struct BuildConfiguration {
     /// <p>The Amazon Resource Name (ARN) of the AWS Identity and Access Management (IAM) role that enables AWS CodeBuild to interact with dependent AWS services on behalf of the AWS account.</p>
     pub code_build_service_role: String,
     /// <p><p>Information about the compute resources the build project will use.</p> <ul> <li> <p> <code>BUILD<em>GENERAL1</em>SMALL: Use up to 3 GB memory and 2 vCPUs for builds</code> </p> </li> <li> <p> <code>BUILD<em>GENERAL1</em>MEDIUM: Use up to 7 GB memory and 4 vCPUs for builds</code> </p> </li> <li> <p> <code>BUILD<em>GENERAL1</em>LARGE: Use up to 15 GB memory and 8 vCPUs for builds</code> </p> </li> </ul></p>
-    pub compute_type: Option<String>,
+    pub compute_type: Option<ComputeType>,
     /// <p>The ID of the Docker image to use for this build project.</p>
     pub image: String,
     /// <p>How long in minutes, from 5 to 480 (8 hours), for AWS CodeBuild to wait until timing out any related build that does not get marked as completed. The default is 60 minutes.</p>
@@ -1194,12 +1621,215 @@ impl ComposeEnvironmentsMessageSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ComputeType {
+    /// `BUILD_GENERAL1_SMALL`
+    BuildGeneral1Small,
+    /// `BUILD_GENERAL1_MEDIUM`
+    BuildGeneral1Medium,
+    /// `BUILD_GENERAL1_LARGE`
+    BuildGeneral1Large,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ComputeType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ComputeType::BuildGeneral1Small => "BUILD_GENERAL1_SMALL",
+            ComputeType::BuildGeneral1Medium => "BUILD_GENERAL1_MEDIUM",
+            ComputeType::BuildGeneral1Large => "BUILD_GENERAL1_LARGE",
+            ComputeType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ComputeType {
+    fn default() -> ComputeType {
+        ComputeType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ComputeType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ComputeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ComputeType {
+    fn from(value: &'a str) -> ComputeType {
+        match value {
+            "BUILD_GENERAL1_SMALL" => ComputeType::BuildGeneral1Small,
+            "BUILD_GENERAL1_MEDIUM" => ComputeType::BuildGeneral1Medium,
+            "BUILD_GENERAL1_LARGE" => ComputeType::BuildGeneral1Large,
+            _ => ComputeType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ComputeType {
+    fn from(value: String) -> ComputeType {
+        match value.as_str() {
+            "BUILD_GENERAL1_SMALL" => ComputeType::BuildGeneral1Small,
+            "BUILD_GENERAL1_MEDIUM" => ComputeType::BuildGeneral1Medium,
+            "BUILD_GENERAL1_LARGE" => ComputeType::BuildGeneral1Large,
+            _ => ComputeType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ComputeType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ComputeType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ComputeType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ComputeType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ComputeType {
+    fn deserialize<D>(deserializer: D) -> Result<ComputeType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ConfigurationDeploymentStatus {
+    /// `deployed`
+    Deployed,
+    /// `pending`
+    Pending,
+    /// `failed`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ConfigurationDeploymentStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ConfigurationDeploymentStatus::Deployed => "deployed",
+            ConfigurationDeploymentStatus::Pending => "pending",
+            ConfigurationDeploymentStatus::Failed => "failed",
+            ConfigurationDeploymentStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ConfigurationDeploymentStatus {
+    fn default() -> ConfigurationDeploymentStatus {
+        ConfigurationDeploymentStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ConfigurationDeploymentStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ConfigurationDeploymentStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ConfigurationDeploymentStatus {
+    fn from(value: &'a str) -> ConfigurationDeploymentStatus {
+        match value {
+            "deployed" => ConfigurationDeploymentStatus::Deployed,
+            "pending" => ConfigurationDeploymentStatus::Pending,
+            "failed" => ConfigurationDeploymentStatus::Failed,
+            _ => ConfigurationDeploymentStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ConfigurationDeploymentStatus {
+    fn from(value: String) -> ConfigurationDeploymentStatus {
+        match value.as_str() {
+            "deployed" => ConfigurationDeploymentStatus::Deployed,
+            "pending" => ConfigurationDeploymentStatus::Pending,
+            "failed" => ConfigurationDeploymentStatus::Failed,
+            _ => ConfigurationDeploymentStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ConfigurationDeploymentStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ConfigurationDeploymentStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ConfigurationDeploymentStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ConfigurationDeploymentStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ConfigurationDeploymentStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ConfigurationDeploymentStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct ConfigurationDeploymentStatusDeserializer;
 impl ConfigurationDeploymentStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ConfigurationDeploymentStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -1241,7 +1871,7 @@ pub struct ConfigurationOptionDescription {
     /// <p>If specified, values for the configuration option are selected from this list.</p>
     pub value_options: Option<Vec<String>>,
     /// <p><p>An indication of which type of values this option has and whether it is allowable to select one or more than one of the possible values:</p> <ul> <li> <p> <code>Scalar</code> : Values for this option are a single selection from the possible values, or an unformatted string, or numeric value governed by the <code>MIN/MAX/Regex</code> constraints.</p> </li> <li> <p> <code>List</code> : Values for this option are multiple selections from the possible values.</p> </li> <li> <p> <code>Boolean</code> : Values for this option are either <code>true</code> or <code>false</code> .</p> </li> <li> <p> <code>Json</code> : Values for this option are a JSON representation of a <code>ConfigDocument</code>.</p> </li> </ul></p>
-    pub value_type: Option<String>,
+    pub value_type: Option<ConfigurationOptionValueType>,
 }
 
 struct ConfigurationOptionDescriptionDeserializer;
@@ -1525,12 +2155,110 @@ impl ConfigurationOptionValueDeserializer {
         Ok(obj)
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ConfigurationOptionValueType {
+    /// `Scalar`
+    Scalar,
+    /// `List`
+    List,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ConfigurationOptionValueType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ConfigurationOptionValueType::Scalar => "Scalar",
+            ConfigurationOptionValueType::List => "List",
+            ConfigurationOptionValueType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ConfigurationOptionValueType {
+    fn default() -> ConfigurationOptionValueType {
+        ConfigurationOptionValueType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ConfigurationOptionValueType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ConfigurationOptionValueType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ConfigurationOptionValueType {
+    fn from(value: &'a str) -> ConfigurationOptionValueType {
+        match value {
+            "Scalar" => ConfigurationOptionValueType::Scalar,
+            "List" => ConfigurationOptionValueType::List,
+            _ => ConfigurationOptionValueType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ConfigurationOptionValueType {
+    fn from(value: String) -> ConfigurationOptionValueType {
+        match value.as_str() {
+            "Scalar" => ConfigurationOptionValueType::Scalar,
+            "List" => ConfigurationOptionValueType::List,
+            _ => ConfigurationOptionValueType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ConfigurationOptionValueType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ConfigurationOptionValueType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ConfigurationOptionValueType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ConfigurationOptionValueType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ConfigurationOptionValueType {
+    fn deserialize<D>(deserializer: D) -> Result<ConfigurationOptionValueType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct ConfigurationOptionValueTypeDeserializer;
 impl ConfigurationOptionValueTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ConfigurationOptionValueType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -1591,11 +2319,11 @@ pub struct ConfigurationSettingsDescription {
     /// <p>The name of the application associated with this configuration set.</p>
     pub application_name: Option<String>,
     /// <p>The date (in UTC time) when this configuration set was created.</p>
-    pub date_created: Option<String>,
+    pub date_created: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The date (in UTC time) when this configuration set was last modified.</p>
-    pub date_updated: Option<String>,
+    pub date_updated: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p> If this configuration set is associated with an environment, the <code>DeploymentStatus</code> parameter indicates the deployment status of this configuration set: </p> <ul> <li> <p> <code>null</code>: This configuration is not associated with a running environment.</p> </li> <li> <p> <code>pending</code>: This is a draft configuration that is not deployed to the associated environment but is in the process of deploying.</p> </li> <li> <p> <code>deployed</code>: This is the configuration that is currently deployed to the associated running environment.</p> </li> <li> <p> <code>failed</code>: This is a draft configuration that failed to successfully deploy.</p> </li> </ul></p>
-    pub deployment_status: Option<String>,
+    pub deployment_status: Option<ConfigurationDeploymentStatus>,
     /// <p>Describes this configuration set.</p>
     pub description: Option<String>,
     /// <p> If not <code>null</code>, the name of the environment for this configuration set. </p>
@@ -2210,9 +2938,12 @@ impl CreateStorageLocationResultMessageDeserializer {
 struct CreationDateDeserializer;
 impl CreationDateDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<::chrono::DateTime<::chrono::Utc>, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::proto::xml::util::timestamp_characters(stack)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -2462,7 +3193,7 @@ pub struct Deployment {
     /// <p>The ID of the deployment. This number increases by one each time that you deploy source code or change instance configuration settings.</p>
     pub deployment_id: Option<i64>,
     /// <p>For in-progress deployments, the time that the deployment started.</p> <p>For completed deployments, the time that the deployment ended.</p>
-    pub deployment_time: Option<String>,
+    pub deployment_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>The status of the deployment:</p> <ul> <li> <p> <code>In Progress</code> : The deployment is in progress.</p> </li> <li> <p> <code>Deployed</code> : The deployment succeeded.</p> </li> <li> <p> <code>Failed</code> : The deployment failed.</p> </li> </ul></p>
     pub status: Option<String>,
     /// <p>The version label of the application version in the deployment.</p>
@@ -2506,9 +3237,12 @@ impl DeploymentDeserializer {
 struct DeploymentTimestampDeserializer;
 impl DeploymentTimestampDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<::chrono::DateTime<::chrono::Utc>, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::proto::xml::util::timestamp_characters(stack)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -2706,7 +3440,7 @@ impl DescribeConfigurationSettingsMessageSerializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct DescribeEnvironmentHealthRequest {
     /// <p>Specify the response elements to return. To retrieve all attributes, set to <code>All</code>. If no attribute names are specified, returns the name of the environment.</p>
-    pub attribute_names: Option<Vec<String>>,
+    pub attribute_names: Option<Vec<EnvironmentHealthAttribute>>,
     /// <p>Specify the environment by ID.</p> <p>You must specify either this or an EnvironmentName, or both.</p>
     pub environment_id: Option<String>,
     /// <p>Specify the environment by name.</p> <p>You must specify either this or an EnvironmentName, or both.</p>
@@ -2755,9 +3489,9 @@ pub struct DescribeEnvironmentHealthResult {
     /// <p>Summary health information for the instances in the environment.</p>
     pub instances_health: Option<InstanceHealthSummary>,
     /// <p>The date and time that the health information was retrieved.</p>
-    pub refreshed_at: Option<String>,
+    pub refreshed_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The environment's operational status. <code>Ready</code>, <code>Launching</code>, <code>Updating</code>, <code>Terminating</code>, or <code>Terminated</code>.</p>
-    pub status: Option<String>,
+    pub status: Option<EnvironmentHealth>,
 }
 
 struct DescribeEnvironmentHealthResultDeserializer;
@@ -2910,7 +3644,7 @@ pub struct DescribeEnvironmentManagedActionsRequest {
     /// <p>The name of the target environment.</p>
     pub environment_name: Option<String>,
     /// <p>To show only actions with a particular status, specify a status.</p>
-    pub status: Option<String>,
+    pub status: Option<ActionStatus>,
 }
 
 /// Serialize `DescribeEnvironmentManagedActionsRequest` contents to a `SignedRequest`.
@@ -3007,7 +3741,7 @@ pub struct DescribeEnvironmentsMessage {
     /// <p>Indicates whether to include deleted environments:</p> <p> <code>true</code>: Environments that have been deleted after <code>IncludedDeletedBackTo</code> are displayed.</p> <p> <code>false</code>: Do not include deleted environments.</p>
     pub include_deleted: Option<bool>,
     /// <p> If specified when <code>IncludeDeleted</code> is set to <code>true</code>, then environments deleted after this date are displayed. </p>
-    pub included_deleted_back_to: Option<String>,
+    pub included_deleted_back_to: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>For a paginated request. Specify a maximum number of environments to include in each response.</p> <p>If no <code>MaxRecords</code> is specified, all available environments are retrieved in a single response.</p>
     pub max_records: Option<i64>,
     /// <p>For a paginated request. Specify a token from a previous response page to retrieve the next response page. All other parameter values must be identical to the ones specified in the initial request.</p> <p>If no <code>NextToken</code> is specified, the first page is retrieved.</p>
@@ -3048,7 +3782,7 @@ impl DescribeEnvironmentsMessageSerializer {
         if let Some(ref field_value) = obj.included_deleted_back_to {
             params.put(
                 &format!("{}{}", prefix, "IncludedDeletedBackTo"),
-                &field_value,
+                &rusoto_core::serialization::TimestampFormat::Iso8601.format(field_value),
             );
         }
         if let Some(ref field_value) = obj.max_records {
@@ -3070,7 +3804,7 @@ pub struct DescribeEventsMessage {
     /// <p>If specified, AWS Elastic Beanstalk restricts the returned descriptions to include only those associated with this application.</p>
     pub application_name: Option<String>,
     /// <p> If specified, AWS Elastic Beanstalk restricts the returned descriptions to those that occur up to, but not including, the <code>EndTime</code>. </p>
-    pub end_time: Option<String>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>If specified, AWS Elastic Beanstalk restricts the returned descriptions to those associated with this environment.</p>
     pub environment_id: Option<String>,
     /// <p>If specified, AWS Elastic Beanstalk restricts the returned descriptions to those associated with this environment.</p>
@@ -3084,9 +3818,9 @@ pub struct DescribeEventsMessage {
     /// <p>If specified, AWS Elastic Beanstalk restricts the described events to include only those associated with this request ID.</p>
     pub request_id: Option<String>,
     /// <p>If specified, limits the events returned from this call to include only those with the specified severity or higher.</p>
-    pub severity: Option<String>,
+    pub severity: Option<EventSeverity>,
     /// <p>If specified, AWS Elastic Beanstalk restricts the returned descriptions to those that occur on or after this time.</p>
-    pub start_time: Option<String>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>If specified, AWS Elastic Beanstalk restricts the returned descriptions to those that are associated with this environment configuration.</p>
     pub template_name: Option<String>,
     /// <p>If specified, AWS Elastic Beanstalk restricts the returned descriptions to those associated with this application version.</p>
@@ -3106,7 +3840,10 @@ impl DescribeEventsMessageSerializer {
             params.put(&format!("{}{}", prefix, "ApplicationName"), &field_value);
         }
         if let Some(ref field_value) = obj.end_time {
-            params.put(&format!("{}{}", prefix, "EndTime"), &field_value);
+            params.put(
+                &format!("{}{}", prefix, "EndTime"),
+                &rusoto_core::serialization::TimestampFormat::Iso8601.format(field_value),
+            );
         }
         if let Some(ref field_value) = obj.environment_id {
             params.put(&format!("{}{}", prefix, "EnvironmentId"), &field_value);
@@ -3130,7 +3867,10 @@ impl DescribeEventsMessageSerializer {
             params.put(&format!("{}{}", prefix, "Severity"), &field_value);
         }
         if let Some(ref field_value) = obj.start_time {
-            params.put(&format!("{}{}", prefix, "StartTime"), &field_value);
+            params.put(
+                &format!("{}{}", prefix, "StartTime"),
+                &rusoto_core::serialization::TimestampFormat::Iso8601.format(field_value),
+            );
         }
         if let Some(ref field_value) = obj.template_name {
             params.put(&format!("{}{}", prefix, "TemplateName"), &field_value);
@@ -3146,7 +3886,7 @@ impl DescribeEventsMessageSerializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct DescribeInstancesHealthRequest {
     /// <p>Specifies the response elements you wish to receive. To retrieve all attributes, set to <code>All</code>. If no attribute names are specified, returns a list of instances.</p>
-    pub attribute_names: Option<Vec<String>>,
+    pub attribute_names: Option<Vec<InstancesHealthAttribute>>,
     /// <p>Specify the AWS Elastic Beanstalk environment by ID.</p>
     pub environment_id: Option<String>,
     /// <p>Specify the AWS Elastic Beanstalk environment by name.</p>
@@ -3192,7 +3932,7 @@ pub struct DescribeInstancesHealthResult {
     /// <p>Pagination token for the next page of results, if available.</p>
     pub next_token: Option<String>,
     /// <p>The date and time that the health information was retrieved.</p>
-    pub refreshed_at: Option<String>,
+    pub refreshed_at: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 struct DescribeInstancesHealthResultDeserializer;
@@ -3340,9 +4080,9 @@ pub struct EnvironmentDescription {
     /// <p>The URL to the CNAME for this environment.</p>
     pub cname: Option<String>,
     /// <p>The creation date for this environment.</p>
-    pub date_created: Option<String>,
+    pub date_created: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The last modified date for this environment.</p>
-    pub date_updated: Option<String>,
+    pub date_updated: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Describes this environment.</p>
     pub description: Option<String>,
     /// <p>For load-balanced, autoscaling environments, the URL to the LoadBalancer. For single-instance environments, the IP address of the instance.</p>
@@ -3356,9 +4096,9 @@ pub struct EnvironmentDescription {
     /// <p>The name of this environment.</p>
     pub environment_name: Option<String>,
     /// <p>Describes the health status of the environment. AWS Elastic Beanstalk indicates the failure levels for a running environment:</p> <ul> <li> <p> <code>Red</code>: Indicates the environment is not responsive. Occurs when three or more consecutive failures occur for an environment.</p> </li> <li> <p> <code>Yellow</code>: Indicates that something is wrong. Occurs when two consecutive failures occur for an environment.</p> </li> <li> <p> <code>Green</code>: Indicates the environment is healthy and fully functional.</p> </li> <li> <p> <code>Grey</code>: Default health for a new environment. The environment is not fully launched and health checks have not started or health checks are suspended during an <code>UpdateEnvironment</code> or <code>RestartEnvironment</code> request.</p> </li> </ul> <p> Default: <code>Grey</code> </p>
-    pub health: Option<String>,
+    pub health: Option<EnvironmentHealth>,
     /// <p>Returns the health status of the application running in your environment. For more information, see <a href="https://docs.aws.amazon.com/elasticbeanstalk/latest/dg/health-enhanced-status.html">Health Colors and Statuses</a>.</p>
-    pub health_status: Option<String>,
+    pub health_status: Option<EnvironmentHealthStatus>,
     /// <p>The ARN of the platform.</p>
     pub platform_arn: Option<String>,
     /// <p>The description of the AWS resources used by this environment.</p>
@@ -3366,7 +4106,7 @@ pub struct EnvironmentDescription {
     /// <p> The name of the <code>SolutionStack</code> deployed with this environment. </p>
     pub solution_stack_name: Option<String>,
     /// <p><p>The current operational status of the environment:</p> <ul> <li> <p> <code>Launching</code>: Environment is in the process of initial deployment.</p> </li> <li> <p> <code>Updating</code>: Environment is in the process of updating its configuration settings or application version.</p> </li> <li> <p> <code>Ready</code>: Environment is available to have an action performed on it, such as update or terminate.</p> </li> <li> <p> <code>Terminating</code>: Environment is in the shut-down process.</p> </li> <li> <p> <code>Terminated</code>: Environment is not running.</p> </li> </ul></p>
-    pub status: Option<String>,
+    pub status: Option<EnvironmentStatus>,
     /// <p>The name of the configuration template used to originally launch this environment.</p>
     pub template_name: Option<String>,
     /// <p>Describes the current tier of this environment.</p>
@@ -3547,22 +4287,255 @@ impl EnvironmentDescriptionsMessageDeserializer {
         )
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EnvironmentHealth {
+    /// `Green`
+    Green,
+    /// `Yellow`
+    Yellow,
+    /// `Red`
+    Red,
+    /// `Grey`
+    Grey,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EnvironmentHealth {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EnvironmentHealth::Green => "Green",
+            EnvironmentHealth::Yellow => "Yellow",
+            EnvironmentHealth::Red => "Red",
+            EnvironmentHealth::Grey => "Grey",
+            EnvironmentHealth::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EnvironmentHealth {
+    fn default() -> EnvironmentHealth {
+        EnvironmentHealth::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EnvironmentHealth {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EnvironmentHealth {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EnvironmentHealth {
+    fn from(value: &'a str) -> EnvironmentHealth {
+        match value {
+            "Green" => EnvironmentHealth::Green,
+            "Yellow" => EnvironmentHealth::Yellow,
+            "Red" => EnvironmentHealth::Red,
+            "Grey" => EnvironmentHealth::Grey,
+            _ => EnvironmentHealth::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EnvironmentHealth {
+    fn from(value: String) -> EnvironmentHealth {
+        match value.as_str() {
+            "Green" => EnvironmentHealth::Green,
+            "Yellow" => EnvironmentHealth::Yellow,
+            "Red" => EnvironmentHealth::Red,
+            "Grey" => EnvironmentHealth::Grey,
+            _ => EnvironmentHealth::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EnvironmentHealth {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EnvironmentHealth, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EnvironmentHealth {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for EnvironmentHealth {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for EnvironmentHealth {
+    fn deserialize<D>(deserializer: D) -> Result<EnvironmentHealth, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct EnvironmentHealthDeserializer;
 impl EnvironmentHealthDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<EnvironmentHealth, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EnvironmentHealthAttribute {
+    /// `Status`
+    Status,
+    /// `Color`
+    Color,
+    /// `Causes`
+    Causes,
+    /// `ApplicationMetrics`
+    ApplicationMetrics,
+    /// `InstancesHealth`
+    InstancesHealth,
+    /// `All`
+    All,
+    /// `HealthStatus`
+    HealthStatus,
+    /// `RefreshedAt`
+    RefreshedAt,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EnvironmentHealthAttribute {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EnvironmentHealthAttribute::Status => "Status",
+            EnvironmentHealthAttribute::Color => "Color",
+            EnvironmentHealthAttribute::Causes => "Causes",
+            EnvironmentHealthAttribute::ApplicationMetrics => "ApplicationMetrics",
+            EnvironmentHealthAttribute::InstancesHealth => "InstancesHealth",
+            EnvironmentHealthAttribute::All => "All",
+            EnvironmentHealthAttribute::HealthStatus => "HealthStatus",
+            EnvironmentHealthAttribute::RefreshedAt => "RefreshedAt",
+            EnvironmentHealthAttribute::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EnvironmentHealthAttribute {
+    fn default() -> EnvironmentHealthAttribute {
+        EnvironmentHealthAttribute::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EnvironmentHealthAttribute {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EnvironmentHealthAttribute {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EnvironmentHealthAttribute {
+    fn from(value: &'a str) -> EnvironmentHealthAttribute {
+        match value {
+            "Status" => EnvironmentHealthAttribute::Status,
+            "Color" => EnvironmentHealthAttribute::Color,
+            "Causes" => EnvironmentHealthAttribute::Causes,
+            "ApplicationMetrics" => EnvironmentHealthAttribute::ApplicationMetrics,
+            "InstancesHealth" => EnvironmentHealthAttribute::InstancesHealth,
+            "All" => EnvironmentHealthAttribute::All,
+            "HealthStatus" => EnvironmentHealthAttribute::HealthStatus,
+            "RefreshedAt" => EnvironmentHealthAttribute::RefreshedAt,
+            _ => EnvironmentHealthAttribute::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EnvironmentHealthAttribute {
+    fn from(value: String) -> EnvironmentHealthAttribute {
+        match value.as_str() {
+            "Status" => EnvironmentHealthAttribute::Status,
+            "Color" => EnvironmentHealthAttribute::Color,
+            "Causes" => EnvironmentHealthAttribute::Causes,
+            "ApplicationMetrics" => EnvironmentHealthAttribute::ApplicationMetrics,
+            "InstancesHealth" => EnvironmentHealthAttribute::InstancesHealth,
+            "All" => EnvironmentHealthAttribute::All,
+            "HealthStatus" => EnvironmentHealthAttribute::HealthStatus,
+            "RefreshedAt" => EnvironmentHealthAttribute::RefreshedAt,
+            _ => EnvironmentHealthAttribute::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EnvironmentHealthAttribute {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EnvironmentHealthAttribute, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EnvironmentHealthAttribute {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for EnvironmentHealthAttribute {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for EnvironmentHealthAttribute {
+    fn deserialize<D>(deserializer: D) -> Result<EnvironmentHealthAttribute, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 
 /// Serialize `EnvironmentHealthAttributes` contents to a `SignedRequest`.
 struct EnvironmentHealthAttributesSerializer;
 impl EnvironmentHealthAttributesSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &Vec<String>) {
+    fn serialize(params: &mut Params, name: &str, obj: &Vec<EnvironmentHealthAttribute>) {
         for (index, obj) in obj.iter().enumerate() {
             let key = format!("{}.member.{}", name, index + 1);
             params.put(&key, &obj);
@@ -3570,12 +4543,145 @@ impl EnvironmentHealthAttributesSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EnvironmentHealthStatus {
+    /// `NoData`
+    NoData,
+    /// `Unknown`
+    UnknownValue,
+    /// `Pending`
+    Pending,
+    /// `Ok`
+    Ok,
+    /// `Info`
+    Info,
+    /// `Warning`
+    Warning,
+    /// `Degraded`
+    Degraded,
+    /// `Severe`
+    Severe,
+    /// `Suspended`
+    Suspended,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EnvironmentHealthStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EnvironmentHealthStatus::NoData => "NoData",
+            EnvironmentHealthStatus::UnknownValue => "Unknown",
+            EnvironmentHealthStatus::Pending => "Pending",
+            EnvironmentHealthStatus::Ok => "Ok",
+            EnvironmentHealthStatus::Info => "Info",
+            EnvironmentHealthStatus::Warning => "Warning",
+            EnvironmentHealthStatus::Degraded => "Degraded",
+            EnvironmentHealthStatus::Severe => "Severe",
+            EnvironmentHealthStatus::Suspended => "Suspended",
+            EnvironmentHealthStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EnvironmentHealthStatus {
+    fn default() -> EnvironmentHealthStatus {
+        EnvironmentHealthStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EnvironmentHealthStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EnvironmentHealthStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EnvironmentHealthStatus {
+    fn from(value: &'a str) -> EnvironmentHealthStatus {
+        match value {
+            "NoData" => EnvironmentHealthStatus::NoData,
+            "Unknown" => EnvironmentHealthStatus::UnknownValue,
+            "Pending" => EnvironmentHealthStatus::Pending,
+            "Ok" => EnvironmentHealthStatus::Ok,
+            "Info" => EnvironmentHealthStatus::Info,
+            "Warning" => EnvironmentHealthStatus::Warning,
+            "Degraded" => EnvironmentHealthStatus::Degraded,
+            "Severe" => EnvironmentHealthStatus::Severe,
+            "Suspended" => EnvironmentHealthStatus::Suspended,
+            _ => EnvironmentHealthStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EnvironmentHealthStatus {
+    fn from(value: String) -> EnvironmentHealthStatus {
+        match value.as_str() {
+            "NoData" => EnvironmentHealthStatus::NoData,
+            "Unknown" => EnvironmentHealthStatus::UnknownValue,
+            "Pending" => EnvironmentHealthStatus::Pending,
+            "Ok" => EnvironmentHealthStatus::Ok,
+            "Info" => EnvironmentHealthStatus::Info,
+            "Warning" => EnvironmentHealthStatus::Warning,
+            "Degraded" => EnvironmentHealthStatus::Degraded,
+            "Severe" => EnvironmentHealthStatus::Severe,
+            "Suspended" => EnvironmentHealthStatus::Suspended,
+            _ => EnvironmentHealthStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EnvironmentHealthStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EnvironmentHealthStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EnvironmentHealthStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for EnvironmentHealthStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for EnvironmentHealthStatus {
+    fn deserialize<D>(deserializer: D) -> Result<EnvironmentHealthStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct EnvironmentHealthStatusDeserializer;
 impl EnvironmentHealthStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<EnvironmentHealthStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -3611,11 +4717,11 @@ pub struct EnvironmentInfoDescription {
     /// <p>The Amazon EC2 Instance ID for this information.</p>
     pub ec_2_instance_id: Option<String>,
     /// <p>The type of information retrieved.</p>
-    pub info_type: Option<String>,
+    pub info_type: Option<EnvironmentInfoType>,
     /// <p>The retrieved information. Currently contains a presigned Amazon S3 URL. The files are deleted after 15 minutes.</p> <p>Anyone in possession of this URL can access the files before they are deleted. Make the URL available only to trusted parties.</p>
     pub message: Option<String>,
     /// <p>The time stamp when this information was retrieved.</p>
-    pub sample_timestamp: Option<String>,
+    pub sample_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 struct EnvironmentInfoDescriptionDeserializer;
@@ -3676,12 +4782,110 @@ impl EnvironmentInfoDescriptionListDeserializer {
         })
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EnvironmentInfoType {
+    /// `tail`
+    Tail,
+    /// `bundle`
+    Bundle,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EnvironmentInfoType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EnvironmentInfoType::Tail => "tail",
+            EnvironmentInfoType::Bundle => "bundle",
+            EnvironmentInfoType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EnvironmentInfoType {
+    fn default() -> EnvironmentInfoType {
+        EnvironmentInfoType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EnvironmentInfoType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EnvironmentInfoType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EnvironmentInfoType {
+    fn from(value: &'a str) -> EnvironmentInfoType {
+        match value {
+            "tail" => EnvironmentInfoType::Tail,
+            "bundle" => EnvironmentInfoType::Bundle,
+            _ => EnvironmentInfoType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EnvironmentInfoType {
+    fn from(value: String) -> EnvironmentInfoType {
+        match value.as_str() {
+            "tail" => EnvironmentInfoType::Tail,
+            "bundle" => EnvironmentInfoType::Bundle,
+            _ => EnvironmentInfoType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EnvironmentInfoType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EnvironmentInfoType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EnvironmentInfoType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for EnvironmentInfoType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for EnvironmentInfoType {
+    fn deserialize<D>(deserializer: D) -> Result<EnvironmentInfoType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct EnvironmentInfoTypeDeserializer;
 impl EnvironmentInfoTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<EnvironmentInfoType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -3914,12 +5118,140 @@ impl EnvironmentResourcesDescriptionDeserializer {
         )
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EnvironmentStatus {
+    /// `Aborting`
+    Aborting,
+    /// `Launching`
+    Launching,
+    /// `Updating`
+    Updating,
+    /// `LinkingFrom`
+    LinkingFrom,
+    /// `LinkingTo`
+    LinkingTo,
+    /// `Ready`
+    Ready,
+    /// `Terminating`
+    Terminating,
+    /// `Terminated`
+    Terminated,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EnvironmentStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EnvironmentStatus::Aborting => "Aborting",
+            EnvironmentStatus::Launching => "Launching",
+            EnvironmentStatus::Updating => "Updating",
+            EnvironmentStatus::LinkingFrom => "LinkingFrom",
+            EnvironmentStatus::LinkingTo => "LinkingTo",
+            EnvironmentStatus::Ready => "Ready",
+            EnvironmentStatus::Terminating => "Terminating",
+            EnvironmentStatus::Terminated => "Terminated",
+            EnvironmentStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EnvironmentStatus {
+    fn default() -> EnvironmentStatus {
+        EnvironmentStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EnvironmentStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EnvironmentStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EnvironmentStatus {
+    fn from(value: &'a str) -> EnvironmentStatus {
+        match value {
+            "Aborting" => EnvironmentStatus::Aborting,
+            "Launching" => EnvironmentStatus::Launching,
+            "Updating" => EnvironmentStatus::Updating,
+            "LinkingFrom" => EnvironmentStatus::LinkingFrom,
+            "LinkingTo" => EnvironmentStatus::LinkingTo,
+            "Ready" => EnvironmentStatus::Ready,
+            "Terminating" => EnvironmentStatus::Terminating,
+            "Terminated" => EnvironmentStatus::Terminated,
+            _ => EnvironmentStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EnvironmentStatus {
+    fn from(value: String) -> EnvironmentStatus {
+        match value.as_str() {
+            "Aborting" => EnvironmentStatus::Aborting,
+            "Launching" => EnvironmentStatus::Launching,
+            "Updating" => EnvironmentStatus::Updating,
+            "LinkingFrom" => EnvironmentStatus::LinkingFrom,
+            "LinkingTo" => EnvironmentStatus::LinkingTo,
+            "Ready" => EnvironmentStatus::Ready,
+            "Terminating" => EnvironmentStatus::Terminating,
+            "Terminated" => EnvironmentStatus::Terminated,
+            _ => EnvironmentStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EnvironmentStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EnvironmentStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EnvironmentStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for EnvironmentStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for EnvironmentStatus {
+    fn deserialize<D>(deserializer: D) -> Result<EnvironmentStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct EnvironmentStatusDeserializer;
 impl EnvironmentStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<EnvironmentStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -3987,9 +5319,12 @@ impl EnvironmentTierSerializer {
 struct EventDateDeserializer;
 impl EventDateDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<::chrono::DateTime<::chrono::Utc>, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::proto::xml::util::timestamp_characters(stack)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -4004,7 +5339,7 @@ pub struct EventDescription {
     /// <p>The name of the environment associated with this event.</p>
     pub environment_name: Option<String>,
     /// <p>The date when the event occurred.</p>
-    pub event_date: Option<String>,
+    pub event_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The event message.</p>
     pub message: Option<String>,
     /// <p>The ARN of the platform.</p>
@@ -4012,7 +5347,7 @@ pub struct EventDescription {
     /// <p>The web service request ID for the activity of this event.</p>
     pub request_id: Option<String>,
     /// <p>The severity level of this event.</p>
-    pub severity: Option<String>,
+    pub severity: Option<EventSeverity>,
     /// <p>The name of the configuration associated with this event.</p>
     pub template_name: Option<String>,
     /// <p>The release label for the application version associated with this event.</p>
@@ -4139,23 +5474,264 @@ impl EventMessageDeserializer {
         Ok(obj)
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EventSeverity {
+    /// `TRACE`
+    Trace,
+    /// `DEBUG`
+    Debug,
+    /// `INFO`
+    Info,
+    /// `WARN`
+    Warn,
+    /// `ERROR`
+    Error,
+    /// `FATAL`
+    Fatal,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EventSeverity {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EventSeverity::Trace => "TRACE",
+            EventSeverity::Debug => "DEBUG",
+            EventSeverity::Info => "INFO",
+            EventSeverity::Warn => "WARN",
+            EventSeverity::Error => "ERROR",
+            EventSeverity::Fatal => "FATAL",
+            EventSeverity::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EventSeverity {
+    fn default() -> EventSeverity {
+        EventSeverity::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EventSeverity {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EventSeverity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EventSeverity {
+    fn from(value: &'a str) -> EventSeverity {
+        match value {
+            "TRACE" => EventSeverity::Trace,
+            "DEBUG" => EventSeverity::Debug,
+            "INFO" => EventSeverity::Info,
+            "WARN" => EventSeverity::Warn,
+            "ERROR" => EventSeverity::Error,
+            "FATAL" => EventSeverity::Fatal,
+            _ => EventSeverity::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EventSeverity {
+    fn from(value: String) -> EventSeverity {
+        match value.as_str() {
+            "TRACE" => EventSeverity::Trace,
+            "DEBUG" => EventSeverity::Debug,
+            "INFO" => EventSeverity::Info,
+            "WARN" => EventSeverity::Warn,
+            "ERROR" => EventSeverity::Error,
+            "FATAL" => EventSeverity::Fatal,
+            _ => EventSeverity::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EventSeverity {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EventSeverity, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EventSeverity {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for EventSeverity {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for EventSeverity {
+    fn deserialize<D>(deserializer: D) -> Result<EventSeverity, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct EventSeverityDeserializer;
 impl EventSeverityDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<EventSeverity, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FailureType {
+    /// `UpdateCancelled`
+    UpdateCancelled,
+    /// `CancellationFailed`
+    CancellationFailed,
+    /// `RollbackFailed`
+    RollbackFailed,
+    /// `RollbackSuccessful`
+    RollbackSuccessful,
+    /// `InternalFailure`
+    InternalFailure,
+    /// `InvalidEnvironmentState`
+    InvalidEnvironmentState,
+    /// `PermissionsError`
+    PermissionsError,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl FailureType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FailureType::UpdateCancelled => "UpdateCancelled",
+            FailureType::CancellationFailed => "CancellationFailed",
+            FailureType::RollbackFailed => "RollbackFailed",
+            FailureType::RollbackSuccessful => "RollbackSuccessful",
+            FailureType::InternalFailure => "InternalFailure",
+            FailureType::InvalidEnvironmentState => "InvalidEnvironmentState",
+            FailureType::PermissionsError => "PermissionsError",
+            FailureType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for FailureType {
+    fn default() -> FailureType {
+        FailureType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for FailureType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FailureType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FailureType {
+    fn from(value: &'a str) -> FailureType {
+        match value {
+            "UpdateCancelled" => FailureType::UpdateCancelled,
+            "CancellationFailed" => FailureType::CancellationFailed,
+            "RollbackFailed" => FailureType::RollbackFailed,
+            "RollbackSuccessful" => FailureType::RollbackSuccessful,
+            "InternalFailure" => FailureType::InternalFailure,
+            "InvalidEnvironmentState" => FailureType::InvalidEnvironmentState,
+            "PermissionsError" => FailureType::PermissionsError,
+            _ => FailureType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FailureType {
+    fn from(value: String) -> FailureType {
+        match value.as_str() {
+            "UpdateCancelled" => FailureType::UpdateCancelled,
+            "CancellationFailed" => FailureType::CancellationFailed,
+            "RollbackFailed" => FailureType::RollbackFailed,
+            "RollbackSuccessful" => FailureType::RollbackSuccessful,
+            "InternalFailure" => FailureType::InternalFailure,
+            "InvalidEnvironmentState" => FailureType::InvalidEnvironmentState,
+            "PermissionsError" => FailureType::PermissionsError,
+            _ => FailureType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for FailureType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<FailureType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FailureType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for FailureType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for FailureType {
+    fn deserialize<D>(deserializer: D) -> Result<FailureType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct FailureTypeDeserializer;
 impl FailureTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<FailureType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -4318,11 +5894,151 @@ impl InstanceListDeserializer {
         })
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum InstancesHealthAttribute {
+    /// `HealthStatus`
+    HealthStatus,
+    /// `Color`
+    Color,
+    /// `Causes`
+    Causes,
+    /// `ApplicationMetrics`
+    ApplicationMetrics,
+    /// `RefreshedAt`
+    RefreshedAt,
+    /// `LaunchedAt`
+    LaunchedAt,
+    /// `System`
+    System,
+    /// `Deployment`
+    Deployment,
+    /// `AvailabilityZone`
+    AvailabilityZone,
+    /// `InstanceType`
+    InstanceType,
+    /// `All`
+    All,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl InstancesHealthAttribute {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InstancesHealthAttribute::HealthStatus => "HealthStatus",
+            InstancesHealthAttribute::Color => "Color",
+            InstancesHealthAttribute::Causes => "Causes",
+            InstancesHealthAttribute::ApplicationMetrics => "ApplicationMetrics",
+            InstancesHealthAttribute::RefreshedAt => "RefreshedAt",
+            InstancesHealthAttribute::LaunchedAt => "LaunchedAt",
+            InstancesHealthAttribute::System => "System",
+            InstancesHealthAttribute::Deployment => "Deployment",
+            InstancesHealthAttribute::AvailabilityZone => "AvailabilityZone",
+            InstancesHealthAttribute::InstanceType => "InstanceType",
+            InstancesHealthAttribute::All => "All",
+            InstancesHealthAttribute::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for InstancesHealthAttribute {
+    fn default() -> InstancesHealthAttribute {
+        InstancesHealthAttribute::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for InstancesHealthAttribute {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for InstancesHealthAttribute {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for InstancesHealthAttribute {
+    fn from(value: &'a str) -> InstancesHealthAttribute {
+        match value {
+            "HealthStatus" => InstancesHealthAttribute::HealthStatus,
+            "Color" => InstancesHealthAttribute::Color,
+            "Causes" => InstancesHealthAttribute::Causes,
+            "ApplicationMetrics" => InstancesHealthAttribute::ApplicationMetrics,
+            "RefreshedAt" => InstancesHealthAttribute::RefreshedAt,
+            "LaunchedAt" => InstancesHealthAttribute::LaunchedAt,
+            "System" => InstancesHealthAttribute::System,
+            "Deployment" => InstancesHealthAttribute::Deployment,
+            "AvailabilityZone" => InstancesHealthAttribute::AvailabilityZone,
+            "InstanceType" => InstancesHealthAttribute::InstanceType,
+            "All" => InstancesHealthAttribute::All,
+            _ => InstancesHealthAttribute::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InstancesHealthAttribute {
+    fn from(value: String) -> InstancesHealthAttribute {
+        match value.as_str() {
+            "HealthStatus" => InstancesHealthAttribute::HealthStatus,
+            "Color" => InstancesHealthAttribute::Color,
+            "Causes" => InstancesHealthAttribute::Causes,
+            "ApplicationMetrics" => InstancesHealthAttribute::ApplicationMetrics,
+            "RefreshedAt" => InstancesHealthAttribute::RefreshedAt,
+            "LaunchedAt" => InstancesHealthAttribute::LaunchedAt,
+            "System" => InstancesHealthAttribute::System,
+            "Deployment" => InstancesHealthAttribute::Deployment,
+            "AvailabilityZone" => InstancesHealthAttribute::AvailabilityZone,
+            "InstanceType" => InstancesHealthAttribute::InstanceType,
+            "All" => InstancesHealthAttribute::All,
+            _ => InstancesHealthAttribute::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for InstancesHealthAttribute {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<InstancesHealthAttribute, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for InstancesHealthAttribute {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for InstancesHealthAttribute {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for InstancesHealthAttribute {
+    fn deserialize<D>(deserializer: D) -> Result<InstancesHealthAttribute, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 
 /// Serialize `InstancesHealthAttributes` contents to a `SignedRequest`.
 struct InstancesHealthAttributesSerializer;
 impl InstancesHealthAttributesSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &Vec<String>) {
+    fn serialize(params: &mut Params, name: &str, obj: &Vec<InstancesHealthAttribute>) {
         for (index, obj) in obj.iter().enumerate() {
             let key = format!("{}.member.{}", name, index + 1);
             params.put(&key, &obj);
@@ -4493,9 +6209,12 @@ impl LaunchTemplateListDeserializer {
 struct LaunchedAtDeserializer;
 impl LaunchedAtDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<::chrono::DateTime<::chrono::Utc>, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::proto::xml::util::timestamp_characters(stack)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -4826,11 +6545,11 @@ pub struct ManagedAction {
     /// <p>A unique identifier for the managed action.</p>
     pub action_id: Option<String>,
     /// <p>The type of managed action.</p>
-    pub action_type: Option<String>,
+    pub action_type: Option<ActionType>,
     /// <p>The status of the managed action. If the action is <code>Scheduled</code>, you can apply it immediately with <a>ApplyEnvironmentManagedAction</a>.</p>
-    pub status: Option<String>,
+    pub status: Option<ActionStatus>,
     /// <p>The start time of the maintenance window in which the managed action will execute.</p>
-    pub window_start_time: Option<String>,
+    pub window_start_time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 struct ManagedActionDeserializer;
@@ -4877,17 +6596,17 @@ pub struct ManagedActionHistoryItem {
     /// <p>A unique identifier for the managed action.</p>
     pub action_id: Option<String>,
     /// <p>The type of the managed action.</p>
-    pub action_type: Option<String>,
+    pub action_type: Option<ActionType>,
     /// <p>The date and time that the action started executing.</p>
-    pub executed_time: Option<String>,
+    pub executed_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>If the action failed, a description of the failure.</p>
     pub failure_description: Option<String>,
     /// <p>If the action failed, the type of failure.</p>
-    pub failure_type: Option<String>,
+    pub failure_type: Option<FailureType>,
     /// <p>The date and time that the action finished executing.</p>
-    pub finished_time: Option<String>,
+    pub finished_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The status of the action.</p>
-    pub status: Option<String>,
+    pub status: Option<ActionHistoryStatus>,
 }
 
 struct ManagedActionHistoryItemDeserializer;
@@ -5325,9 +7044,9 @@ pub struct PlatformDescription {
     /// <p>The custom AMIs supported by the platform.</p>
     pub custom_ami_list: Option<Vec<CustomAmi>>,
     /// <p>The date when the platform was created.</p>
-    pub date_created: Option<String>,
+    pub date_created: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The date when the platform was last updated.</p>
-    pub date_updated: Option<String>,
+    pub date_updated: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The description of the platform.</p>
     pub description: Option<String>,
     /// <p>The frameworks supported by the platform.</p>
@@ -5347,7 +7066,7 @@ pub struct PlatformDescription {
     /// <p>The AWS account ID of the person who created the platform.</p>
     pub platform_owner: Option<String>,
     /// <p>The status of the platform.</p>
-    pub platform_status: Option<String>,
+    pub platform_status: Option<PlatformStatus>,
     /// <p>The version of the platform.</p>
     pub platform_version: Option<String>,
     /// <p>The programming languages supported by the platform.</p>
@@ -5655,12 +7374,125 @@ impl PlatformProgrammingLanguagesDeserializer {
         })
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PlatformStatus {
+    /// `Creating`
+    Creating,
+    /// `Failed`
+    Failed,
+    /// `Ready`
+    Ready,
+    /// `Deleting`
+    Deleting,
+    /// `Deleted`
+    Deleted,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PlatformStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PlatformStatus::Creating => "Creating",
+            PlatformStatus::Failed => "Failed",
+            PlatformStatus::Ready => "Ready",
+            PlatformStatus::Deleting => "Deleting",
+            PlatformStatus::Deleted => "Deleted",
+            PlatformStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PlatformStatus {
+    fn default() -> PlatformStatus {
+        PlatformStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PlatformStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PlatformStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PlatformStatus {
+    fn from(value: &'a str) -> PlatformStatus {
+        match value {
+            "Creating" => PlatformStatus::Creating,
+            "Failed" => PlatformStatus::Failed,
+            "Ready" => PlatformStatus::Ready,
+            "Deleting" => PlatformStatus::Deleting,
+            "Deleted" => PlatformStatus::Deleted,
+            _ => PlatformStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PlatformStatus {
+    fn from(value: String) -> PlatformStatus {
+        match value.as_str() {
+            "Creating" => PlatformStatus::Creating,
+            "Failed" => PlatformStatus::Failed,
+            "Ready" => PlatformStatus::Ready,
+            "Deleting" => PlatformStatus::Deleting,
+            "Deleted" => PlatformStatus::Deleted,
+            _ => PlatformStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PlatformStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PlatformStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PlatformStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for PlatformStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for PlatformStatus {
+    fn deserialize<D>(deserializer: D) -> Result<PlatformStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct PlatformStatusDeserializer;
 impl PlatformStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<PlatformStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -5681,7 +7513,7 @@ pub struct PlatformSummary {
     /// <p>The AWS account ID of the person who created the platform.</p>
     pub platform_owner: Option<String>,
     /// <p>The status of the platform. You can create an environment from the platform once it is ready.</p>
-    pub platform_status: Option<String>,
+    pub platform_status: Option<PlatformStatus>,
     /// <p>The additions associated with the platform.</p>
     pub supported_addon_list: Option<Vec<String>>,
     /// <p>The tiers in which the platform runs.</p>
@@ -5852,9 +7684,12 @@ impl RebuildEnvironmentMessageSerializer {
 struct RefreshedAtDeserializer;
 impl RefreshedAtDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<::chrono::DateTime<::chrono::Utc>, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::proto::xml::util::timestamp_characters(stack)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -5902,7 +7737,7 @@ pub struct RequestEnvironmentInfoMessage {
     /// <p>The name of the environment of the requested data.</p> <p>If no such environment is found, <code>RequestEnvironmentInfo</code> returns an <code>InvalidParameterValue</code> error. </p> <p>Condition: You must specify either this or an EnvironmentId, or both. If you do not specify either, AWS Elastic Beanstalk returns <code>MissingRequiredParameter</code> error. </p>
     pub environment_name: Option<String>,
     /// <p>The type of information to request.</p>
-    pub info_type: String,
+    pub info_type: EnvironmentInfoType,
 }
 
 /// Serialize `RequestEnvironmentInfoMessage` contents to a `SignedRequest`.
@@ -6130,7 +7965,7 @@ pub struct RetrieveEnvironmentInfoMessage {
     /// <p>The name of the data's environment.</p> <p> If no such environment is found, returns an <code>InvalidParameterValue</code> error. </p> <p> Condition: You must specify either this or an EnvironmentId, or both. If you do not specify either, AWS Elastic Beanstalk returns <code>MissingRequiredParameter</code> error. </p>
     pub environment_name: Option<String>,
     /// <p>The type of information to retrieve.</p>
-    pub info_type: String,
+    pub info_type: EnvironmentInfoType,
 }
 
 /// Serialize `RetrieveEnvironmentInfoMessage` contents to a `SignedRequest`.
@@ -6263,9 +8098,12 @@ impl S3LocationSerializer {
 struct SampleTimestampDeserializer;
 impl SampleTimestampDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<::chrono::DateTime<::chrono::Utc>, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::proto::xml::util::timestamp_characters(stack)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -6292,7 +8130,7 @@ pub struct SingleInstanceHealth {
     /// <p>The instance's type.</p>
     pub instance_type: Option<String>,
     /// <p>The time at which the EC2 instance was launched.</p>
-    pub launched_at: Option<String>,
+    pub launched_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Operating system metrics from the instance.</p>
     pub system: Option<SystemStatus>,
 }
@@ -6432,9 +8270,9 @@ pub struct SourceBuildInformation {
     /// <p><p>The location of the source code, as a formatted string, depending on the value of <code>SourceRepository</code> </p> <ul> <li> <p>For <code>CodeCommit</code>, the format is the repository name and commit ID, separated by a forward slash. For example, <code>my-git-repo/265cfa0cf6af46153527f55d6503ec030551f57a</code>.</p> </li> <li> <p>For <code>S3</code>, the format is the S3 bucket name and object key, separated by a forward slash. For example, <code>my-s3-bucket/Folders/my-source-file</code>.</p> </li> </ul></p>
     pub source_location: String,
     /// <p><p>Location where the repository is stored.</p> <ul> <li> <p> <code>CodeCommit</code> </p> </li> <li> <p> <code>S3</code> </p> </li> </ul></p>
-    pub source_repository: String,
+    pub source_repository: SourceRepository,
     /// <p><p>The type of repository.</p> <ul> <li> <p> <code>Git</code> </p> </li> <li> <p> <code>Zip</code> </p> </li> </ul></p>
-    pub source_type: String,
+    pub source_type: SourceType,
 }
 
 struct SourceBuildInformationDeserializer;
@@ -6524,23 +8362,219 @@ impl SourceLocationDeserializer {
         Ok(obj)
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SourceRepository {
+    /// `CodeCommit`
+    CodeCommit,
+    /// `S3`
+    S3,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SourceRepository {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SourceRepository::CodeCommit => "CodeCommit",
+            SourceRepository::S3 => "S3",
+            SourceRepository::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SourceRepository {
+    fn default() -> SourceRepository {
+        SourceRepository::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SourceRepository {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SourceRepository {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SourceRepository {
+    fn from(value: &'a str) -> SourceRepository {
+        match value {
+            "CodeCommit" => SourceRepository::CodeCommit,
+            "S3" => SourceRepository::S3,
+            _ => SourceRepository::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SourceRepository {
+    fn from(value: String) -> SourceRepository {
+        match value.as_str() {
+            "CodeCommit" => SourceRepository::CodeCommit,
+            "S3" => SourceRepository::S3,
+            _ => SourceRepository::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SourceRepository {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SourceRepository, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SourceRepository {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for SourceRepository {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for SourceRepository {
+    fn deserialize<D>(deserializer: D) -> Result<SourceRepository, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct SourceRepositoryDeserializer;
 impl SourceRepositoryDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<SourceRepository, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SourceType {
+    /// `Git`
+    Git,
+    /// `Zip`
+    Zip,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SourceType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SourceType::Git => "Git",
+            SourceType::Zip => "Zip",
+            SourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SourceType {
+    fn default() -> SourceType {
+        SourceType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SourceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SourceType {
+    fn from(value: &'a str) -> SourceType {
+        match value {
+            "Git" => SourceType::Git,
+            "Zip" => SourceType::Zip,
+            _ => SourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SourceType {
+    fn from(value: String) -> SourceType {
+        match value.as_str() {
+            "Git" => SourceType::Git,
+            "Zip" => SourceType::Zip,
+            _ => SourceType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SourceType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SourceType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SourceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for SourceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for SourceType {
+    fn deserialize<D>(deserializer: D) -> Result<SourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct SourceTypeDeserializer;
 impl SourceTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<SourceType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -6916,9 +8950,12 @@ impl TerminateEnvironmentMessageSerializer {
 struct TimestampDeserializer;
 impl TimestampDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<::chrono::DateTime<::chrono::Utc>, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::proto::xml::util::timestamp_characters(stack)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -7122,9 +9159,12 @@ impl UpdateConfigurationTemplateMessageSerializer {
 struct UpdateDateDeserializer;
 impl UpdateDateDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<::chrono::DateTime<::chrono::Utc>, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::proto::xml::util::timestamp_characters(stack)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -7321,7 +9361,7 @@ pub struct ValidationMessage {
     /// <p>The name of the option.</p>
     pub option_name: Option<String>,
     /// <p><p>An indication of the severity of this message:</p> <ul> <li> <p> <code>error</code>: This message indicates that this is not a valid setting for an option.</p> </li> <li> <p> <code>warning</code>: This message is providing information you should take into account.</p> </li> </ul></p>
-    pub severity: Option<String>,
+    pub severity: Option<ValidationSeverity>,
 }
 
 struct ValidationMessageDeserializer;
@@ -7389,12 +9429,110 @@ impl ValidationMessagesListDeserializer {
         })
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ValidationSeverity {
+    /// `error`
+    Error,
+    /// `warning`
+    Warning,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ValidationSeverity {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ValidationSeverity::Error => "error",
+            ValidationSeverity::Warning => "warning",
+            ValidationSeverity::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ValidationSeverity {
+    fn default() -> ValidationSeverity {
+        ValidationSeverity::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ValidationSeverity {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ValidationSeverity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ValidationSeverity {
+    fn from(value: &'a str) -> ValidationSeverity {
+        match value {
+            "error" => ValidationSeverity::Error,
+            "warning" => ValidationSeverity::Warning,
+            _ => ValidationSeverity::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ValidationSeverity {
+    fn from(value: String) -> ValidationSeverity {
+        match value.as_str() {
+            "error" => ValidationSeverity::Error,
+            "warning" => ValidationSeverity::Warning,
+            _ => ValidationSeverity::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ValidationSeverity {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ValidationSeverity, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ValidationSeverity {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ValidationSeverity {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ValidationSeverity {
+    fn deserialize<D>(deserializer: D) -> Result<ValidationSeverity, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct ValidationSeverityDeserializer;
 impl ValidationSeverityDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ValidationSeverity, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -9979,11 +12117,12 @@ pub struct ElasticBeanstalkClient {
 impl ElasticBeanstalkClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> ElasticBeanstalkClient {
         ElasticBeanstalkClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Elastic Beanstalk"),
         }
     }
 
@@ -10015,6 +12154,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: AbortEnvironmentUpdateMessage,
     ) -> Result<(), RusotoError<AbortEnvironmentUpdateError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("AbortEnvironmentUpdate");
         let mut params = Params::new();
 
         params.put("Action", "AbortEnvironmentUpdate");
@@ -10044,6 +12184,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
     ) -> Result<ApplyEnvironmentManagedActionResult, RusotoError<ApplyEnvironmentManagedActionError>>
     {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("ApplyEnvironmentManagedAction");
         let mut params = Params::new();
 
         params.put("Action", "ApplyEnvironmentManagedAction");
@@ -10093,6 +12234,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: CheckDNSAvailabilityMessage,
     ) -> Result<CheckDNSAvailabilityResultMessage, RusotoError<CheckDNSAvailabilityError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("CheckDNSAvailability");
         let mut params = Params::new();
 
         params.put("Action", "CheckDNSAvailability");
@@ -10142,6 +12284,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: ComposeEnvironmentsMessage,
     ) -> Result<EnvironmentDescriptionsMessage, RusotoError<ComposeEnvironmentsError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("ComposeEnvironments");
         let mut params = Params::new();
 
         params.put("Action", "ComposeEnvironments");
@@ -10191,6 +12334,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: CreateApplicationMessage,
     ) -> Result<ApplicationDescriptionMessage, RusotoError<CreateApplicationError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("CreateApplication");
         let mut params = Params::new();
 
         params.put("Action", "CreateApplication");
@@ -10241,6 +12385,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
     ) -> Result<ApplicationVersionDescriptionMessage, RusotoError<CreateApplicationVersionError>>
     {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("CreateApplicationVersion");
         let mut params = Params::new();
 
         params.put("Action", "CreateApplicationVersion");
@@ -10291,6 +12436,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
     ) -> Result<ConfigurationSettingsDescription, RusotoError<CreateConfigurationTemplateError>>
     {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("CreateConfigurationTemplate");
         let mut params = Params::new();
 
         params.put("Action", "CreateConfigurationTemplate");
@@ -10340,6 +12486,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: CreateEnvironmentMessage,
     ) -> Result<EnvironmentDescription, RusotoError<CreateEnvironmentError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("CreateEnvironment");
         let mut params = Params::new();
 
         params.put("Action", "CreateEnvironment");
@@ -10389,6 +12536,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: CreatePlatformVersionRequest,
     ) -> Result<CreatePlatformVersionResult, RusotoError<CreatePlatformVersionError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("CreatePlatformVersion");
         let mut params = Params::new();
 
         params.put("Action", "CreatePlatformVersion");
@@ -10437,6 +12585,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         &self,
     ) -> Result<CreateStorageLocationResultMessage, RusotoError<CreateStorageLocationError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("CreateStorageLocation");
         let mut params = Params::new();
 
         params.put("Action", "CreateStorageLocation");
@@ -10486,6 +12635,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: DeleteApplicationMessage,
     ) -> Result<(), RusotoError<DeleteApplicationError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DeleteApplication");
         let mut params = Params::new();
 
         params.put("Action", "DeleteApplication");
@@ -10514,6 +12664,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: DeleteApplicationVersionMessage,
     ) -> Result<(), RusotoError<DeleteApplicationVersionError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DeleteApplicationVersion");
         let mut params = Params::new();
 
         params.put("Action", "DeleteApplicationVersion");
@@ -10542,6 +12693,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: DeleteConfigurationTemplateMessage,
     ) -> Result<(), RusotoError<DeleteConfigurationTemplateError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DeleteConfigurationTemplate");
         let mut params = Params::new();
 
         params.put("Action", "DeleteConfigurationTemplate");
@@ -10570,6 +12722,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: DeleteEnvironmentConfigurationMessage,
     ) -> Result<(), RusotoError<DeleteEnvironmentConfigurationError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DeleteEnvironmentConfiguration");
         let mut params = Params::new();
 
         params.put("Action", "DeleteEnvironmentConfiguration");
@@ -10598,6 +12751,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: DeletePlatformVersionRequest,
     ) -> Result<DeletePlatformVersionResult, RusotoError<DeletePlatformVersionError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DeletePlatformVersion");
         let mut params = Params::new();
 
         params.put("Action", "DeletePlatformVersion");
@@ -10646,6 +12800,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         &self,
     ) -> Result<DescribeAccountAttributesResult, RusotoError<DescribeAccountAttributesError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DescribeAccountAttributes");
         let mut params = Params::new();
 
         params.put("Action", "DescribeAccountAttributes");
@@ -10696,6 +12851,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
     ) -> Result<ApplicationVersionDescriptionsMessage, RusotoError<DescribeApplicationVersionsError>>
     {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DescribeApplicationVersions");
         let mut params = Params::new();
 
         params.put("Action", "DescribeApplicationVersions");
@@ -10745,6 +12901,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: DescribeApplicationsMessage,
     ) -> Result<ApplicationDescriptionsMessage, RusotoError<DescribeApplicationsError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DescribeApplications");
         let mut params = Params::new();
 
         params.put("Action", "DescribeApplications");
@@ -10795,6 +12952,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
     ) -> Result<ConfigurationOptionsDescription, RusotoError<DescribeConfigurationOptionsError>>
     {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DescribeConfigurationOptions");
         let mut params = Params::new();
 
         params.put("Action", "DescribeConfigurationOptions");
@@ -10845,6 +13003,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
     ) -> Result<ConfigurationSettingsDescriptions, RusotoError<DescribeConfigurationSettingsError>>
     {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DescribeConfigurationSettings");
         let mut params = Params::new();
 
         params.put("Action", "DescribeConfigurationSettings");
@@ -10894,6 +13053,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: DescribeEnvironmentHealthRequest,
     ) -> Result<DescribeEnvironmentHealthResult, RusotoError<DescribeEnvironmentHealthError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DescribeEnvironmentHealth");
         let mut params = Params::new();
 
         params.put("Action", "DescribeEnvironmentHealth");
@@ -10946,6 +13106,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         RusotoError<DescribeEnvironmentManagedActionHistoryError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DescribeEnvironmentManagedActionHistory");
         let mut params = Params::new();
 
         params.put("Action", "DescribeEnvironmentManagedActionHistory");
@@ -11004,6 +13165,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         RusotoError<DescribeEnvironmentManagedActionsError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DescribeEnvironmentManagedActions");
         let mut params = Params::new();
 
         params.put("Action", "DescribeEnvironmentManagedActions");
@@ -11058,6 +13220,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         RusotoError<DescribeEnvironmentResourcesError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DescribeEnvironmentResources");
         let mut params = Params::new();
 
         params.put("Action", "DescribeEnvironmentResources");
@@ -11107,6 +13270,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: DescribeEnvironmentsMessage,
     ) -> Result<EnvironmentDescriptionsMessage, RusotoError<DescribeEnvironmentsError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DescribeEnvironments");
         let mut params = Params::new();
 
         params.put("Action", "DescribeEnvironments");
@@ -11156,6 +13320,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: DescribeEventsMessage,
     ) -> Result<EventDescriptionsMessage, RusotoError<DescribeEventsError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DescribeEvents");
         let mut params = Params::new();
 
         params.put("Action", "DescribeEvents");
@@ -11205,6 +13370,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: DescribeInstancesHealthRequest,
     ) -> Result<DescribeInstancesHealthResult, RusotoError<DescribeInstancesHealthError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DescribeInstancesHealth");
         let mut params = Params::new();
 
         params.put("Action", "DescribeInstancesHealth");
@@ -11254,6 +13420,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: DescribePlatformVersionRequest,
     ) -> Result<DescribePlatformVersionResult, RusotoError<DescribePlatformVersionError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DescribePlatformVersion");
         let mut params = Params::new();
 
         params.put("Action", "DescribePlatformVersion");
@@ -11305,6 +13472,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         RusotoError<ListAvailableSolutionStacksError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("ListAvailableSolutionStacks");
         let mut params = Params::new();
 
         params.put("Action", "ListAvailableSolutionStacks");
@@ -11354,6 +13522,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: ListPlatformVersionsRequest,
     ) -> Result<ListPlatformVersionsResult, RusotoError<ListPlatformVersionsError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("ListPlatformVersions");
         let mut params = Params::new();
 
         params.put("Action", "ListPlatformVersions");
@@ -11403,6 +13572,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: ListTagsForResourceMessage,
     ) -> Result<ResourceTagsDescriptionMessage, RusotoError<ListTagsForResourceError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("ListTagsForResource");
         let mut params = Params::new();
 
         params.put("Action", "ListTagsForResource");
@@ -11452,6 +13622,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: RebuildEnvironmentMessage,
     ) -> Result<(), RusotoError<RebuildEnvironmentError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("RebuildEnvironment");
         let mut params = Params::new();
 
         params.put("Action", "RebuildEnvironment");
@@ -11480,6 +13651,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: RequestEnvironmentInfoMessage,
     ) -> Result<(), RusotoError<RequestEnvironmentInfoError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("RequestEnvironmentInfo");
         let mut params = Params::new();
 
         params.put("Action", "RequestEnvironmentInfo");
@@ -11508,6 +13680,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: RestartAppServerMessage,
     ) -> Result<(), RusotoError<RestartAppServerError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("RestartAppServer");
         let mut params = Params::new();
 
         params.put("Action", "RestartAppServer");
@@ -11537,6 +13710,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
     ) -> Result<RetrieveEnvironmentInfoResultMessage, RusotoError<RetrieveEnvironmentInfoError>>
     {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("RetrieveEnvironmentInfo");
         let mut params = Params::new();
 
         params.put("Action", "RetrieveEnvironmentInfo");
@@ -11586,6 +13760,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: SwapEnvironmentCNAMEsMessage,
     ) -> Result<(), RusotoError<SwapEnvironmentCNAMEsError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("SwapEnvironmentCNAMEs");
         let mut params = Params::new();
 
         params.put("Action", "SwapEnvironmentCNAMEs");
@@ -11614,6 +13789,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: TerminateEnvironmentMessage,
     ) -> Result<EnvironmentDescription, RusotoError<TerminateEnvironmentError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("TerminateEnvironment");
         let mut params = Params::new();
 
         params.put("Action", "TerminateEnvironment");
@@ -11663,6 +13839,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: UpdateApplicationMessage,
     ) -> Result<ApplicationDescriptionMessage, RusotoError<UpdateApplicationError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("UpdateApplication");
         let mut params = Params::new();
 
         params.put("Action", "UpdateApplication");
@@ -11715,6 +13892,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         RusotoError<UpdateApplicationResourceLifecycleError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("UpdateApplicationResourceLifecycle");
         let mut params = Params::new();
 
         params.put("Action", "UpdateApplicationResourceLifecycle");
@@ -11767,6 +13945,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
     ) -> Result<ApplicationVersionDescriptionMessage, RusotoError<UpdateApplicationVersionError>>
     {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("UpdateApplicationVersion");
         let mut params = Params::new();
 
         params.put("Action", "UpdateApplicationVersion");
@@ -11817,6 +13996,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
     ) -> Result<ConfigurationSettingsDescription, RusotoError<UpdateConfigurationTemplateError>>
     {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("UpdateConfigurationTemplate");
         let mut params = Params::new();
 
         params.put("Action", "UpdateConfigurationTemplate");
@@ -11866,6 +14046,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: UpdateEnvironmentMessage,
     ) -> Result<EnvironmentDescription, RusotoError<UpdateEnvironmentError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("UpdateEnvironment");
         let mut params = Params::new();
 
         params.put("Action", "UpdateEnvironment");
@@ -11915,6 +14096,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: UpdateTagsForResourceMessage,
     ) -> Result<(), RusotoError<UpdateTagsForResourceError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("UpdateTagsForResource");
         let mut params = Params::new();
 
         params.put("Action", "UpdateTagsForResource");
@@ -11946,6 +14128,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         RusotoError<ValidateConfigurationSettingsError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("ValidateConfigurationSettings");
         let mut params = Params::new();
 
         params.put("Action", "ValidateConfigurationSettings");
@@ -11989,6 +14172,351 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         Ok(result)
     }
 }
+impl ElasticBeanstalkClient {
+    /// Auto-paginating version of `describe_application_versions`, streaming every page of results.
+    pub fn describe_application_versions_pages(
+        &self,
+        input: DescribeApplicationVersionsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ApplicationVersionDescriptionsMessage,
+        DescribeApplicationVersionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ElasticBeanstalkClient, input: DescribeApplicationVersionsMessage| async move {
+                client.describe_application_versions(input).await
+            },
+            |input: &DescribeApplicationVersionsMessage,
+             output: &ApplicationVersionDescriptionsMessage| {
+                Some(DescribeApplicationVersionsMessage {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_application_versions`, streaming the `ApplicationVersions` of every page of results.
+    pub fn describe_application_versions_items(
+        &self,
+        input: DescribeApplicationVersionsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ApplicationVersionDescription,
+        DescribeApplicationVersionsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_application_versions_pages(input),
+            |output: ApplicationVersionDescriptionsMessage| {
+                output.application_versions.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_environment_managed_action_history`, streaming every page of results.
+    pub fn describe_environment_managed_action_history_pages(
+        &self,
+        input: DescribeEnvironmentManagedActionHistoryRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeEnvironmentManagedActionHistoryResult,
+        DescribeEnvironmentManagedActionHistoryError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ElasticBeanstalkClient,
+             input: DescribeEnvironmentManagedActionHistoryRequest| async move {
+                client
+                    .describe_environment_managed_action_history(input)
+                    .await
+            },
+            |input: &DescribeEnvironmentManagedActionHistoryRequest,
+             output: &DescribeEnvironmentManagedActionHistoryResult| {
+                Some(DescribeEnvironmentManagedActionHistoryRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_environment_managed_action_history`, streaming the `ManagedActionHistoryItems` of every page of results.
+    pub fn describe_environment_managed_action_history_items(
+        &self,
+        input: DescribeEnvironmentManagedActionHistoryRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ManagedActionHistoryItem,
+        DescribeEnvironmentManagedActionHistoryError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_environment_managed_action_history_pages(input),
+            |output: DescribeEnvironmentManagedActionHistoryResult| {
+                output.managed_action_history_items.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_environments`, streaming every page of results.
+    pub fn describe_environments_pages(
+        &self,
+        input: DescribeEnvironmentsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        EnvironmentDescriptionsMessage,
+        DescribeEnvironmentsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ElasticBeanstalkClient, input: DescribeEnvironmentsMessage| async move {
+                client.describe_environments(input).await
+            },
+            |input: &DescribeEnvironmentsMessage, output: &EnvironmentDescriptionsMessage| {
+                Some(DescribeEnvironmentsMessage {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_environments`, streaming the `Environments` of every page of results.
+    pub fn describe_environments_items(
+        &self,
+        input: DescribeEnvironmentsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<EnvironmentDescription, DescribeEnvironmentsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_environments_pages(input),
+            |output: EnvironmentDescriptionsMessage| output.environments.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_events`, streaming every page of results.
+    pub fn describe_events_pages(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<EventDescriptionsMessage, DescribeEventsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ElasticBeanstalkClient, input: DescribeEventsMessage| async move {
+                client.describe_events(input).await
+            },
+            |input: &DescribeEventsMessage, output: &EventDescriptionsMessage| {
+                Some(DescribeEventsMessage {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_events`, streaming the `Events` of every page of results.
+    pub fn describe_events_items(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<EventDescription, DescribeEventsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_events_pages(input),
+            |output: EventDescriptionsMessage| output.events.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_platform_versions`, streaming every page of results.
+    pub fn list_platform_versions_pages(
+        &self,
+        input: ListPlatformVersionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListPlatformVersionsResult, ListPlatformVersionsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ElasticBeanstalkClient, input: ListPlatformVersionsRequest| async move {
+                client.list_platform_versions(input).await
+            },
+            |input: &ListPlatformVersionsRequest, output: &ListPlatformVersionsResult| {
+                Some(ListPlatformVersionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_platform_versions`, streaming the `PlatformSummaryList` of every page of results.
+    pub fn list_platform_versions_items(
+        &self,
+        input: ListPlatformVersionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<PlatformSummary, ListPlatformVersionsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_platform_versions_pages(input),
+            |output: ListPlatformVersionsResult| output.platform_summary_list.unwrap_or_default(),
+        )
+    }
+}
+impl ElasticBeanstalkClient {
+    /// Waits until `describe_environments` reports the `EnvironmentExists` state, polling every 20 seconds up to 20 times.
+    pub async fn wait_until_environment_exists(
+        &self,
+        input: DescribeEnvironmentsMessage,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeEnvironmentsError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(20), 20);
+        self.wait_until_environment_exists_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_environment_exists`, polling as often and as many times as `config` says.
+    pub async fn wait_until_environment_exists_with_config(
+        &self,
+        input: DescribeEnvironmentsMessage,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeEnvironmentsError>> {
+        rusoto_core::waiter::wait(
+            "EnvironmentExists",
+            &config,
+            || self.describe_environments(input.clone()),
+            |result: &Result<
+                EnvironmentDescriptionsMessage,
+                RusotoError<DescribeEnvironmentsError>,
+            >| match result {
+                Ok(output)
+                    if rusoto_core::waiter::all_match(
+                        output
+                            .environments
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.status.iter()),
+                        |v| *v == EnvironmentStatus::Ready,
+                    ) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if rusoto_core::waiter::all_match(
+                        output
+                            .environments
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.status.iter()),
+                        |v| *v == EnvironmentStatus::Launching,
+                    ) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Retry)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+
+    /// Waits until `describe_environments` reports the `EnvironmentTerminated` state, polling every 20 seconds up to 20 times.
+    pub async fn wait_until_environment_terminated(
+        &self,
+        input: DescribeEnvironmentsMessage,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeEnvironmentsError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(20), 20);
+        self.wait_until_environment_terminated_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_environment_terminated`, polling as often and as many times as `config` says.
+    pub async fn wait_until_environment_terminated_with_config(
+        &self,
+        input: DescribeEnvironmentsMessage,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeEnvironmentsError>> {
+        rusoto_core::waiter::wait(
+            "EnvironmentTerminated",
+            &config,
+            || self.describe_environments(input.clone()),
+            |result: &Result<
+                EnvironmentDescriptionsMessage,
+                RusotoError<DescribeEnvironmentsError>,
+            >| match result {
+                Ok(output)
+                    if rusoto_core::waiter::all_match(
+                        output
+                            .environments
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.status.iter()),
+                        |v| *v == EnvironmentStatus::Terminated,
+                    ) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if rusoto_core::waiter::all_match(
+                        output
+                            .environments
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.status.iter()),
+                        |v| *v == EnvironmentStatus::Terminating,
+                    ) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Retry)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+
+    /// Waits until `describe_environments` reports the `EnvironmentUpdated` state, polling every 20 seconds up to 20 times.
+    pub async fn wait_until_environment_updated(
+        &self,
+        input: DescribeEnvironmentsMessage,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeEnvironmentsError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(20), 20);
+        self.wait_until_environment_updated_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_environment_updated`, polling as often and as many times as `config` says.
+    pub async fn wait_until_environment_updated_with_config(
+        &self,
+        input: DescribeEnvironmentsMessage,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeEnvironmentsError>> {
+        rusoto_core::waiter::wait(
+            "EnvironmentUpdated",
+            &config,
+            || self.describe_environments(input.clone()),
+            |result: &Result<
+                EnvironmentDescriptionsMessage,
+                RusotoError<DescribeEnvironmentsError>,
+            >| match result {
+                Ok(output)
+                    if rusoto_core::waiter::all_match(
+                        output
+                            .environments
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.status.iter()),
+                        |v| *v == EnvironmentStatus::Ready,
+                    ) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if rusoto_core::waiter::all_match(
+                        output
+                            .environments
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.status.iter()),
+                        |v| *v == EnvironmentStatus::Updating,
+                    ) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Retry)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+}
 
 #[cfg(test)]
 mod protocol_tests {
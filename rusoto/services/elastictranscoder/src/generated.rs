@@ -2414,11 +2414,12 @@ pub struct EtsClient {
 impl EtsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> EtsClient {
         EtsClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Elastic Transcoder"),
         }
     }
 
@@ -2453,6 +2454,7 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("DELETE", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("CancelJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2481,6 +2483,7 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("POST", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("CreateJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2512,6 +2515,7 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("POST", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("CreatePipeline");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2543,6 +2547,7 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("POST", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("CreatePreset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2574,6 +2579,7 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("DELETE", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("DeletePipeline");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2602,6 +2608,7 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("DELETE", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("DeletePreset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2633,6 +2640,7 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("GET", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("ListJobsByPipeline");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2670,6 +2678,7 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("GET", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("ListJobsByStatus");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2707,6 +2716,7 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("GET", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("ListPipelines");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2744,6 +2754,7 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("GET", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("ListPresets");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2781,6 +2792,7 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("GET", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("ReadJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2809,6 +2821,7 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("GET", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("ReadPipeline");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2837,6 +2850,7 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("GET", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("ReadPreset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2865,6 +2879,7 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("POST", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("TestRole");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2896,6 +2911,7 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("PUT", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("UpdatePipeline");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2928,6 +2944,7 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("POST", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("UpdatePipelineNotifications");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2959,6 +2976,7 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("POST", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("UpdatePipelineStatus");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2981,3 +2999,186 @@ impl Ets for EtsClient {
         }
     }
 }
+impl EtsClient {
+    /// Auto-paginating version of `list_jobs_by_pipeline`, streaming every page of results.
+    pub fn list_jobs_by_pipeline_pages(
+        &self,
+        input: ListJobsByPipelineRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListJobsByPipelineResponse, ListJobsByPipelineError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EtsClient, input: ListJobsByPipelineRequest| async move {
+                client.list_jobs_by_pipeline(input).await
+            },
+            |input: &ListJobsByPipelineRequest, output: &ListJobsByPipelineResponse| {
+                Some(ListJobsByPipelineRequest {
+                    page_token: Some(output.next_page_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_jobs_by_pipeline`, streaming the `Jobs` of every page of results.
+    pub fn list_jobs_by_pipeline_items(
+        &self,
+        input: ListJobsByPipelineRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Job, ListJobsByPipelineError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_jobs_by_pipeline_pages(input),
+            |output: ListJobsByPipelineResponse| output.jobs.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_jobs_by_status`, streaming every page of results.
+    pub fn list_jobs_by_status_pages(
+        &self,
+        input: ListJobsByStatusRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListJobsByStatusResponse, ListJobsByStatusError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EtsClient, input: ListJobsByStatusRequest| async move {
+                client.list_jobs_by_status(input).await
+            },
+            |input: &ListJobsByStatusRequest, output: &ListJobsByStatusResponse| {
+                Some(ListJobsByStatusRequest {
+                    page_token: Some(output.next_page_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_jobs_by_status`, streaming the `Jobs` of every page of results.
+    pub fn list_jobs_by_status_items(
+        &self,
+        input: ListJobsByStatusRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Job, ListJobsByStatusError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_jobs_by_status_pages(input),
+            |output: ListJobsByStatusResponse| output.jobs.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_pipelines`, streaming every page of results.
+    pub fn list_pipelines_pages(
+        &self,
+        input: ListPipelinesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListPipelinesResponse, ListPipelinesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EtsClient, input: ListPipelinesRequest| async move {
+                client.list_pipelines(input).await
+            },
+            |input: &ListPipelinesRequest, output: &ListPipelinesResponse| {
+                Some(ListPipelinesRequest {
+                    page_token: Some(output.next_page_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_pipelines`, streaming the `Pipelines` of every page of results.
+    pub fn list_pipelines_items(
+        &self,
+        input: ListPipelinesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Pipeline, ListPipelinesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_pipelines_pages(input),
+            |output: ListPipelinesResponse| output.pipelines.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_presets`, streaming every page of results.
+    pub fn list_presets_pages(
+        &self,
+        input: ListPresetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListPresetsResponse, ListPresetsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EtsClient, input: ListPresetsRequest| async move {
+                client.list_presets(input).await
+            },
+            |input: &ListPresetsRequest, output: &ListPresetsResponse| {
+                Some(ListPresetsRequest {
+                    page_token: Some(output.next_page_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_presets`, streaming the `Presets` of every page of results.
+    pub fn list_presets_items(
+        &self,
+        input: ListPresetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Preset, ListPresetsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_presets_pages(input),
+            |output: ListPresetsResponse| output.presets.unwrap_or_default(),
+        )
+    }
+}
+impl EtsClient {
+    /// Waits until `read_job` reports the `JobComplete` state, polling every 30 seconds up to 120 times.
+    pub async fn wait_until_job_complete(
+        &self,
+        input: ReadJobRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<ReadJobError>> {
+        let config =
+            rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(30), 120);
+        self.wait_until_job_complete_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_job_complete`, polling as often and as many times as `config` says.
+    pub async fn wait_until_job_complete_with_config(
+        &self,
+        input: ReadJobRequest,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<ReadJobError>> {
+        rusoto_core::waiter::wait(
+            "JobComplete",
+            &config,
+            || self.read_job(input.clone()),
+            |result: &Result<ReadJobResponse, RusotoError<ReadJobError>>| match result {
+                Ok(output)
+                    if output
+                        .job
+                        .iter()
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| v == "Complete") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if output
+                        .job
+                        .iter()
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| v == "Canceled") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .job
+                        .iter()
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| v == "Error") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+}
@@ -1220,9 +1220,12 @@ impl CreateLoadBalancerPolicyOutputDeserializer {
 struct CreatedTimeDeserializer;
 impl CreatedTimeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<::chrono::DateTime<::chrono::Utc>, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::proto::xml::util::timestamp_characters(stack)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -2683,7 +2686,7 @@ pub struct LoadBalancerDescription {
     /// <p>The ID of the Amazon Route 53 hosted zone for the load balancer.</p>
     pub canonical_hosted_zone_name_id: Option<String>,
     /// <p>The date and time the load balancer was created.</p>
-    pub created_time: Option<String>,
+    pub created_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The DNS name of the load balancer.</p>
     pub dns_name: Option<String>,
     /// <p>Information about the health checks conducted on the load balancer.</p>
@@ -6275,11 +6278,15 @@ pub struct ElbClient {
 impl ElbClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> ElbClient {
         ElbClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(
+                region,
+                "Elastic Load Balancing",
+            ),
         }
     }
 
@@ -6311,6 +6318,7 @@ impl Elb for ElbClient {
         input: AddTagsInput,
     ) -> Result<AddTagsOutput, RusotoError<AddTagsError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("AddTags");
         let mut params = Params::new();
 
         params.put("Action", "AddTags");
@@ -6360,6 +6368,7 @@ impl Elb for ElbClient {
         RusotoError<ApplySecurityGroupsToLoadBalancerError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("ApplySecurityGroupsToLoadBalancer");
         let mut params = Params::new();
 
         params.put("Action", "ApplySecurityGroupsToLoadBalancer");
@@ -6412,6 +6421,7 @@ impl Elb for ElbClient {
     ) -> Result<AttachLoadBalancerToSubnetsOutput, RusotoError<AttachLoadBalancerToSubnetsError>>
     {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("AttachLoadBalancerToSubnets");
         let mut params = Params::new();
 
         params.put("Action", "AttachLoadBalancerToSubnets");
@@ -6461,6 +6471,7 @@ impl Elb for ElbClient {
         input: ConfigureHealthCheckInput,
     ) -> Result<ConfigureHealthCheckOutput, RusotoError<ConfigureHealthCheckError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("ConfigureHealthCheck");
         let mut params = Params::new();
 
         params.put("Action", "ConfigureHealthCheck");
@@ -6513,6 +6524,7 @@ impl Elb for ElbClient {
         RusotoError<CreateAppCookieStickinessPolicyError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("CreateAppCookieStickinessPolicy");
         let mut params = Params::new();
 
         params.put("Action", "CreateAppCookieStickinessPolicy");
@@ -6567,6 +6579,7 @@ impl Elb for ElbClient {
         RusotoError<CreateLBCookieStickinessPolicyError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("CreateLBCookieStickinessPolicy");
         let mut params = Params::new();
 
         params.put("Action", "CreateLBCookieStickinessPolicy");
@@ -6616,6 +6629,7 @@ impl Elb for ElbClient {
         input: CreateAccessPointInput,
     ) -> Result<CreateAccessPointOutput, RusotoError<CreateLoadBalancerError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("CreateLoadBalancer");
         let mut params = Params::new();
 
         params.put("Action", "CreateLoadBalancer");
@@ -6666,6 +6680,7 @@ impl Elb for ElbClient {
     ) -> Result<CreateLoadBalancerListenerOutput, RusotoError<CreateLoadBalancerListenersError>>
     {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("CreateLoadBalancerListeners");
         let mut params = Params::new();
 
         params.put("Action", "CreateLoadBalancerListeners");
@@ -6715,6 +6730,7 @@ impl Elb for ElbClient {
         input: CreateLoadBalancerPolicyInput,
     ) -> Result<CreateLoadBalancerPolicyOutput, RusotoError<CreateLoadBalancerPolicyError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("CreateLoadBalancerPolicy");
         let mut params = Params::new();
 
         params.put("Action", "CreateLoadBalancerPolicy");
@@ -6764,6 +6780,7 @@ impl Elb for ElbClient {
         input: DeleteAccessPointInput,
     ) -> Result<DeleteAccessPointOutput, RusotoError<DeleteLoadBalancerError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DeleteLoadBalancer");
         let mut params = Params::new();
 
         params.put("Action", "DeleteLoadBalancer");
@@ -6814,6 +6831,7 @@ impl Elb for ElbClient {
     ) -> Result<DeleteLoadBalancerListenerOutput, RusotoError<DeleteLoadBalancerListenersError>>
     {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DeleteLoadBalancerListeners");
         let mut params = Params::new();
 
         params.put("Action", "DeleteLoadBalancerListeners");
@@ -6863,6 +6881,7 @@ impl Elb for ElbClient {
         input: DeleteLoadBalancerPolicyInput,
     ) -> Result<DeleteLoadBalancerPolicyOutput, RusotoError<DeleteLoadBalancerPolicyError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DeleteLoadBalancerPolicy");
         let mut params = Params::new();
 
         params.put("Action", "DeleteLoadBalancerPolicy");
@@ -6913,6 +6932,7 @@ impl Elb for ElbClient {
     ) -> Result<DeregisterEndPointsOutput, RusotoError<DeregisterInstancesFromLoadBalancerError>>
     {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DeregisterInstancesFromLoadBalancer");
         let mut params = Params::new();
 
         params.put("Action", "DeregisterInstancesFromLoadBalancer");
@@ -6964,6 +6984,7 @@ impl Elb for ElbClient {
         input: DescribeAccountLimitsInput,
     ) -> Result<DescribeAccountLimitsOutput, RusotoError<DescribeAccountLimitsError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeAccountLimits");
         let mut params = Params::new();
 
         params.put("Action", "DescribeAccountLimits");
@@ -7013,6 +7034,7 @@ impl Elb for ElbClient {
         input: DescribeEndPointStateInput,
     ) -> Result<DescribeEndPointStateOutput, RusotoError<DescribeInstanceHealthError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeInstanceHealth");
         let mut params = Params::new();
 
         params.put("Action", "DescribeInstanceHealth");
@@ -7065,6 +7087,7 @@ impl Elb for ElbClient {
         RusotoError<DescribeLoadBalancerAttributesError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeLoadBalancerAttributes");
         let mut params = Params::new();
 
         params.put("Action", "DescribeLoadBalancerAttributes");
@@ -7115,6 +7138,7 @@ impl Elb for ElbClient {
     ) -> Result<DescribeLoadBalancerPoliciesOutput, RusotoError<DescribeLoadBalancerPoliciesError>>
     {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeLoadBalancerPolicies");
         let mut params = Params::new();
 
         params.put("Action", "DescribeLoadBalancerPolicies");
@@ -7167,6 +7191,7 @@ impl Elb for ElbClient {
         RusotoError<DescribeLoadBalancerPolicyTypesError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeLoadBalancerPolicyTypes");
         let mut params = Params::new();
 
         params.put("Action", "DescribeLoadBalancerPolicyTypes");
@@ -7218,6 +7243,7 @@ impl Elb for ElbClient {
         input: DescribeAccessPointsInput,
     ) -> Result<DescribeAccessPointsOutput, RusotoError<DescribeLoadBalancersError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeLoadBalancers");
         let mut params = Params::new();
 
         params.put("Action", "DescribeLoadBalancers");
@@ -7267,6 +7293,7 @@ impl Elb for ElbClient {
         input: DescribeTagsInput,
     ) -> Result<DescribeTagsOutput, RusotoError<DescribeTagsError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeTags");
         let mut params = Params::new();
 
         params.put("Action", "DescribeTags");
@@ -7314,6 +7341,7 @@ impl Elb for ElbClient {
     ) -> Result<DetachLoadBalancerFromSubnetsOutput, RusotoError<DetachLoadBalancerFromSubnetsError>>
     {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DetachLoadBalancerFromSubnets");
         let mut params = Params::new();
 
         params.put("Action", "DetachLoadBalancerFromSubnets");
@@ -7366,6 +7394,7 @@ impl Elb for ElbClient {
         RusotoError<DisableAvailabilityZonesForLoadBalancerError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DisableAvailabilityZonesForLoadBalancer");
         let mut params = Params::new();
 
         params.put("Action", "DisableAvailabilityZonesForLoadBalancer");
@@ -7418,6 +7447,7 @@ impl Elb for ElbClient {
     ) -> Result<AddAvailabilityZonesOutput, RusotoError<EnableAvailabilityZonesForLoadBalancerError>>
     {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("EnableAvailabilityZonesForLoadBalancer");
         let mut params = Params::new();
 
         params.put("Action", "EnableAvailabilityZonesForLoadBalancer");
@@ -7470,6 +7500,7 @@ impl Elb for ElbClient {
     ) -> Result<ModifyLoadBalancerAttributesOutput, RusotoError<ModifyLoadBalancerAttributesError>>
     {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("ModifyLoadBalancerAttributes");
         let mut params = Params::new();
 
         params.put("Action", "ModifyLoadBalancerAttributes");
@@ -7519,6 +7550,7 @@ impl Elb for ElbClient {
         input: RegisterEndPointsInput,
     ) -> Result<RegisterEndPointsOutput, RusotoError<RegisterInstancesWithLoadBalancerError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("RegisterInstancesWithLoadBalancer");
         let mut params = Params::new();
 
         params.put("Action", "RegisterInstancesWithLoadBalancer");
@@ -7570,6 +7602,7 @@ impl Elb for ElbClient {
         input: RemoveTagsInput,
     ) -> Result<RemoveTagsOutput, RusotoError<RemoveTagsError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("RemoveTags");
         let mut params = Params::new();
 
         params.put("Action", "RemoveTags");
@@ -7619,6 +7652,7 @@ impl Elb for ElbClient {
         RusotoError<SetLoadBalancerListenerSSLCertificateError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("SetLoadBalancerListenerSSLCertificate");
         let mut params = Params::new();
 
         params.put("Action", "SetLoadBalancerListenerSSLCertificate");
@@ -7673,6 +7707,7 @@ impl Elb for ElbClient {
         RusotoError<SetLoadBalancerPoliciesForBackendServerError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("SetLoadBalancerPoliciesForBackendServer");
         let mut params = Params::new();
 
         params.put("Action", "SetLoadBalancerPoliciesForBackendServer");
@@ -7727,6 +7762,7 @@ impl Elb for ElbClient {
         RusotoError<SetLoadBalancerPoliciesOfListenerError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("SetLoadBalancerPoliciesOfListener");
         let mut params = Params::new();
 
         params.put("Action", "SetLoadBalancerPoliciesOfListener");
@@ -7772,6 +7808,219 @@ impl Elb for ElbClient {
         Ok(result)
     }
 }
+impl ElbClient {
+    /// Auto-paginating version of `describe_account_limits`, streaming every page of results.
+    pub fn describe_account_limits_pages(
+        &self,
+        input: DescribeAccountLimitsInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeAccountLimitsOutput,
+        DescribeAccountLimitsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ElbClient, input: DescribeAccountLimitsInput| async move {
+                client.describe_account_limits(input).await
+            },
+            |input: &DescribeAccountLimitsInput, output: &DescribeAccountLimitsOutput| {
+                Some(DescribeAccountLimitsInput {
+                    marker: Some(output.next_marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_account_limits`, streaming the `Limits` of every page of results.
+    pub fn describe_account_limits_items(
+        &self,
+        input: DescribeAccountLimitsInput,
+    ) -> rusoto_core::pagination::RusotoStream<Limit, DescribeAccountLimitsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_account_limits_pages(input),
+            |output: DescribeAccountLimitsOutput| output.limits.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_load_balancers`, streaming every page of results.
+    pub fn describe_load_balancers_pages(
+        &self,
+        input: DescribeAccessPointsInput,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeAccessPointsOutput, DescribeLoadBalancersError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ElbClient, input: DescribeAccessPointsInput| async move {
+                client.describe_load_balancers(input).await
+            },
+            |input: &DescribeAccessPointsInput, output: &DescribeAccessPointsOutput| {
+                Some(DescribeAccessPointsInput {
+                    marker: Some(output.next_marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_load_balancers`, streaming the `LoadBalancerDescriptions` of every page of results.
+    pub fn describe_load_balancers_items(
+        &self,
+        input: DescribeAccessPointsInput,
+    ) -> rusoto_core::pagination::RusotoStream<LoadBalancerDescription, DescribeLoadBalancersError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_load_balancers_pages(input),
+            |output: DescribeAccessPointsOutput| {
+                output.load_balancer_descriptions.unwrap_or_default()
+            },
+        )
+    }
+}
+impl ElbClient {
+    /// Waits until `describe_instance_health` reports the `AnyInstanceInService` state, polling every 15 seconds up to 40 times.
+    pub async fn wait_until_any_instance_in_service(
+        &self,
+        input: DescribeEndPointStateInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeInstanceHealthError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(15), 40);
+        self.wait_until_any_instance_in_service_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_any_instance_in_service`, polling as often and as many times as `config` says.
+    pub async fn wait_until_any_instance_in_service_with_config(
+        &self,
+        input: DescribeEndPointStateInput,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeInstanceHealthError>> {
+        rusoto_core::waiter::wait(
+            "AnyInstanceInService",
+            &config,
+            || self.describe_instance_health(input.clone()),
+            |result: &Result<
+                DescribeEndPointStateOutput,
+                RusotoError<DescribeInstanceHealthError>,
+            >| match result {
+                Ok(output)
+                    if output
+                        .instance_states
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.state.iter())
+                        .any(|v| v == "InService") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+
+    /// Waits until `describe_instance_health` reports the `InstanceDeregistered` state, polling every 15 seconds up to 40 times.
+    pub async fn wait_until_instance_deregistered(
+        &self,
+        input: DescribeEndPointStateInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeInstanceHealthError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(15), 40);
+        self.wait_until_instance_deregistered_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_instance_deregistered`, polling as often and as many times as `config` says.
+    pub async fn wait_until_instance_deregistered_with_config(
+        &self,
+        input: DescribeEndPointStateInput,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeInstanceHealthError>> {
+        rusoto_core::waiter::wait(
+            "InstanceDeregistered",
+            &config,
+            || self.describe_instance_health(input.clone()),
+            |result: &Result<
+                DescribeEndPointStateOutput,
+                RusotoError<DescribeInstanceHealthError>,
+            >| match result {
+                Ok(output)
+                    if rusoto_core::waiter::all_match(
+                        output
+                            .instance_states
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.state.iter()),
+                        |v| v == "OutOfService",
+                    ) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Err(RusotoError::Service(DescribeInstanceHealthError::InvalidEndPoint(_))) => {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Err(RusotoError::Unknown(response))
+                    if rusoto_core::retry::error_code(response).as_deref()
+                        == Some("InvalidInstance") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+
+    /// Waits until `describe_instance_health` reports the `InstanceInService` state, polling every 15 seconds up to 40 times.
+    pub async fn wait_until_instance_in_service(
+        &self,
+        input: DescribeEndPointStateInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeInstanceHealthError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(15), 40);
+        self.wait_until_instance_in_service_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_instance_in_service`, polling as often and as many times as `config` says.
+    pub async fn wait_until_instance_in_service_with_config(
+        &self,
+        input: DescribeEndPointStateInput,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeInstanceHealthError>> {
+        rusoto_core::waiter::wait(
+            "InstanceInService",
+            &config,
+            || self.describe_instance_health(input.clone()),
+            |result: &Result<
+                DescribeEndPointStateOutput,
+                RusotoError<DescribeInstanceHealthError>,
+            >| match result {
+                Ok(output)
+                    if rusoto_core::waiter::all_match(
+                        output
+                            .instance_states
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.state.iter()),
+                        |v| v == "InService",
+                    ) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Err(RusotoError::Service(DescribeInstanceHealthError::InvalidEndPoint(_))) => {
+                    Some(rusoto_core::waiter::WaiterState::Retry)
+                }
+                Err(RusotoError::Unknown(response))
+                    if rusoto_core::retry::error_code(response).as_deref()
+                        == Some("InvalidInstance") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Retry)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+}
 
 #[cfg(test)]
 mod protocol_tests {
@@ -52,7 +52,7 @@ pub struct Action {
     /// <p>The Amazon Resource Name (ARN) of the target group. Specify only when <code>Type</code> is <code>forward</code> and you want to route to a single target group. To route to one or more target groups, use <code>ForwardConfig</code> instead.</p>
     pub target_group_arn: Option<String>,
     /// <p>The type of action.</p>
-    pub type_: String,
+    pub type_: ActionTypeEnum,
 }
 
 struct ActionDeserializer;
@@ -178,12 +178,125 @@ impl ActionOrderDeserializer {
         Ok(obj)
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ActionTypeEnum {
+    /// `forward`
+    Forward,
+    /// `authenticate-oidc`
+    AuthenticateOidc,
+    /// `authenticate-cognito`
+    AuthenticateCognito,
+    /// `redirect`
+    Redirect,
+    /// `fixed-response`
+    FixedResponse,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ActionTypeEnum {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ActionTypeEnum::Forward => "forward",
+            ActionTypeEnum::AuthenticateOidc => "authenticate-oidc",
+            ActionTypeEnum::AuthenticateCognito => "authenticate-cognito",
+            ActionTypeEnum::Redirect => "redirect",
+            ActionTypeEnum::FixedResponse => "fixed-response",
+            ActionTypeEnum::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ActionTypeEnum {
+    fn default() -> ActionTypeEnum {
+        ActionTypeEnum::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ActionTypeEnum {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ActionTypeEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ActionTypeEnum {
+    fn from(value: &'a str) -> ActionTypeEnum {
+        match value {
+            "forward" => ActionTypeEnum::Forward,
+            "authenticate-oidc" => ActionTypeEnum::AuthenticateOidc,
+            "authenticate-cognito" => ActionTypeEnum::AuthenticateCognito,
+            "redirect" => ActionTypeEnum::Redirect,
+            "fixed-response" => ActionTypeEnum::FixedResponse,
+            _ => ActionTypeEnum::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ActionTypeEnum {
+    fn from(value: String) -> ActionTypeEnum {
+        match value.as_str() {
+            "forward" => ActionTypeEnum::Forward,
+            "authenticate-oidc" => ActionTypeEnum::AuthenticateOidc,
+            "authenticate-cognito" => ActionTypeEnum::AuthenticateCognito,
+            "redirect" => ActionTypeEnum::Redirect,
+            "fixed-response" => ActionTypeEnum::FixedResponse,
+            _ => ActionTypeEnum::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ActionTypeEnum {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ActionTypeEnum, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ActionTypeEnum {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ActionTypeEnum {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ActionTypeEnum {
+    fn deserialize<D>(deserializer: D) -> Result<ActionTypeEnum, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct ActionTypeEnumDeserializer;
 impl ActionTypeEnumDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ActionTypeEnum, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -402,12 +515,119 @@ impl AuthenticateCognitoActionAuthenticationRequestParamValueDeserializer {
         Ok(obj)
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AuthenticateCognitoActionConditionalBehaviorEnum {
+    /// `deny`
+    Deny,
+    /// `allow`
+    Allow,
+    /// `authenticate`
+    Authenticate,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AuthenticateCognitoActionConditionalBehaviorEnum {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AuthenticateCognitoActionConditionalBehaviorEnum::Deny => "deny",
+            AuthenticateCognitoActionConditionalBehaviorEnum::Allow => "allow",
+            AuthenticateCognitoActionConditionalBehaviorEnum::Authenticate => "authenticate",
+            AuthenticateCognitoActionConditionalBehaviorEnum::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AuthenticateCognitoActionConditionalBehaviorEnum {
+    fn default() -> AuthenticateCognitoActionConditionalBehaviorEnum {
+        AuthenticateCognitoActionConditionalBehaviorEnum::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AuthenticateCognitoActionConditionalBehaviorEnum {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AuthenticateCognitoActionConditionalBehaviorEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AuthenticateCognitoActionConditionalBehaviorEnum {
+    fn from(value: &'a str) -> AuthenticateCognitoActionConditionalBehaviorEnum {
+        match value {
+            "deny" => AuthenticateCognitoActionConditionalBehaviorEnum::Deny,
+            "allow" => AuthenticateCognitoActionConditionalBehaviorEnum::Allow,
+            "authenticate" => AuthenticateCognitoActionConditionalBehaviorEnum::Authenticate,
+            _ => AuthenticateCognitoActionConditionalBehaviorEnum::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AuthenticateCognitoActionConditionalBehaviorEnum {
+    fn from(value: String) -> AuthenticateCognitoActionConditionalBehaviorEnum {
+        match value.as_str() {
+            "deny" => AuthenticateCognitoActionConditionalBehaviorEnum::Deny,
+            "allow" => AuthenticateCognitoActionConditionalBehaviorEnum::Allow,
+            "authenticate" => AuthenticateCognitoActionConditionalBehaviorEnum::Authenticate,
+            _ => AuthenticateCognitoActionConditionalBehaviorEnum::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AuthenticateCognitoActionConditionalBehaviorEnum {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(
+        value: &str,
+    ) -> Result<AuthenticateCognitoActionConditionalBehaviorEnum, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AuthenticateCognitoActionConditionalBehaviorEnum {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for AuthenticateCognitoActionConditionalBehaviorEnum {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for AuthenticateCognitoActionConditionalBehaviorEnum {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> Result<AuthenticateCognitoActionConditionalBehaviorEnum, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct AuthenticateCognitoActionConditionalBehaviorEnumDeserializer;
 impl AuthenticateCognitoActionConditionalBehaviorEnumDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<AuthenticateCognitoActionConditionalBehaviorEnum, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -421,7 +641,7 @@ pub struct AuthenticateCognitoActionConfig {
     /// <p>The query parameters (up to 10) to include in the redirect request to the authorization endpoint.</p>
     pub authentication_request_extra_params: Option<::std::collections::HashMap<String, String>>,
     /// <p><p>The behavior if the user is not authenticated. The following are possible values:</p> <ul> <li> <p>deny<code/> - Return an HTTP 401 Unauthorized error.</p> </li> <li> <p>allow<code/> - Allow the request to be forwarded to the target.</p> </li> <li> <p>authenticate<code/> - Redirect the request to the IdP authorization endpoint. This is the default value.</p> </li> </ul></p>
-    pub on_unauthenticated_request: Option<String>,
+    pub on_unauthenticated_request: Option<AuthenticateCognitoActionConditionalBehaviorEnum>,
     /// <p>The set of user claims to be requested from the IdP. The default is <code>openid</code>.</p> <p>To verify which scope values your IdP supports and how to separate multiple values, see the documentation for your IdP.</p>
     pub scope: Option<String>,
     /// <p>The name of the cookie used to maintain session information. The default is AWSELBAuthSessionCookie.</p>
@@ -714,12 +934,117 @@ impl AuthenticateOidcActionClientSecretDeserializer {
         Ok(obj)
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AuthenticateOidcActionConditionalBehaviorEnum {
+    /// `deny`
+    Deny,
+    /// `allow`
+    Allow,
+    /// `authenticate`
+    Authenticate,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AuthenticateOidcActionConditionalBehaviorEnum {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AuthenticateOidcActionConditionalBehaviorEnum::Deny => "deny",
+            AuthenticateOidcActionConditionalBehaviorEnum::Allow => "allow",
+            AuthenticateOidcActionConditionalBehaviorEnum::Authenticate => "authenticate",
+            AuthenticateOidcActionConditionalBehaviorEnum::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AuthenticateOidcActionConditionalBehaviorEnum {
+    fn default() -> AuthenticateOidcActionConditionalBehaviorEnum {
+        AuthenticateOidcActionConditionalBehaviorEnum::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AuthenticateOidcActionConditionalBehaviorEnum {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AuthenticateOidcActionConditionalBehaviorEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AuthenticateOidcActionConditionalBehaviorEnum {
+    fn from(value: &'a str) -> AuthenticateOidcActionConditionalBehaviorEnum {
+        match value {
+            "deny" => AuthenticateOidcActionConditionalBehaviorEnum::Deny,
+            "allow" => AuthenticateOidcActionConditionalBehaviorEnum::Allow,
+            "authenticate" => AuthenticateOidcActionConditionalBehaviorEnum::Authenticate,
+            _ => AuthenticateOidcActionConditionalBehaviorEnum::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AuthenticateOidcActionConditionalBehaviorEnum {
+    fn from(value: String) -> AuthenticateOidcActionConditionalBehaviorEnum {
+        match value.as_str() {
+            "deny" => AuthenticateOidcActionConditionalBehaviorEnum::Deny,
+            "allow" => AuthenticateOidcActionConditionalBehaviorEnum::Allow,
+            "authenticate" => AuthenticateOidcActionConditionalBehaviorEnum::Authenticate,
+            _ => AuthenticateOidcActionConditionalBehaviorEnum::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AuthenticateOidcActionConditionalBehaviorEnum {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AuthenticateOidcActionConditionalBehaviorEnum, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AuthenticateOidcActionConditionalBehaviorEnum {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for AuthenticateOidcActionConditionalBehaviorEnum {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for AuthenticateOidcActionConditionalBehaviorEnum {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> Result<AuthenticateOidcActionConditionalBehaviorEnum, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct AuthenticateOidcActionConditionalBehaviorEnumDeserializer;
 impl AuthenticateOidcActionConditionalBehaviorEnumDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<AuthenticateOidcActionConditionalBehaviorEnum, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -741,7 +1066,7 @@ pub struct AuthenticateOidcActionConfig {
     /// <p>The OIDC issuer identifier of the IdP. This must be a full URL, including the HTTPS protocol, the domain, and the path.</p>
     pub issuer: String,
     /// <p><p>The behavior if the user is not authenticated. The following are possible values:</p> <ul> <li> <p>deny<code/> - Return an HTTP 401 Unauthorized error.</p> </li> <li> <p>allow<code/> - Allow the request to be forwarded to the target.</p> </li> <li> <p>authenticate<code/> - Redirect the request to the IdP authorization endpoint. This is the default value.</p> </li> </ul></p>
-    pub on_unauthenticated_request: Option<String>,
+    pub on_unauthenticated_request: Option<AuthenticateOidcActionConditionalBehaviorEnum>,
     /// <p>The set of user claims to be requested from the IdP. The default is <code>openid</code>.</p> <p>To verify which scope values your IdP supports and how to separate multiple values, see the documentation for your IdP.</p>
     pub scope: Option<String>,
     /// <p>The name of the cookie used to maintain session information. The default is AWSELBAuthSessionCookie.</p>
@@ -1241,7 +1566,7 @@ pub struct CreateListenerInput {
     /// <p>The port on which the load balancer is listening.</p>
     pub port: i64,
     /// <p>The protocol for connections from clients to the load balancer. For Application Load Balancers, the supported protocols are HTTP and HTTPS. For Network Load Balancers, the supported protocols are TCP, TLS, UDP, and TCP_UDP.</p>
-    pub protocol: String,
+    pub protocol: ProtocolEnum,
     /// <p>[HTTPS and TLS listeners] The security policy that defines which ciphers and protocols are supported. The default is the current predefined security policy.</p>
     pub ssl_policy: Option<String>,
 }
@@ -1310,11 +1635,11 @@ impl CreateListenerOutputDeserializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateLoadBalancerInput {
     /// <p>[Application Load Balancers] The type of IP addresses used by the subnets for your load balancer. The possible values are <code>ipv4</code> (for IPv4 addresses) and <code>dualstack</code> (for IPv4 and IPv6 addresses). Internal load balancers must use <code>ipv4</code>.</p>
-    pub ip_address_type: Option<String>,
+    pub ip_address_type: Option<IpAddressType>,
     /// <p>The name of the load balancer.</p> <p>This name must be unique per region per account, can have a maximum of 32 characters, must contain only alphanumeric characters or hyphens, must not begin or end with a hyphen, and must not begin with "internal-".</p>
     pub name: String,
     /// <p>The nodes of an Internet-facing load balancer have public IP addresses. The DNS name of an Internet-facing load balancer is publicly resolvable to the public IP addresses of the nodes. Therefore, Internet-facing load balancers can route requests from clients over the internet.</p> <p>The nodes of an internal load balancer have only private IP addresses. The DNS name of an internal load balancer is publicly resolvable to the private IP addresses of the nodes. Therefore, internal load balancers can route requests only from clients with access to the VPC for the load balancer.</p> <p>The default is an Internet-facing load balancer.</p>
-    pub scheme: Option<String>,
+    pub scheme: Option<LoadBalancerSchemeEnum>,
     /// <p>[Application Load Balancers] The IDs of the security groups for the load balancer.</p>
     pub security_groups: Option<Vec<String>>,
     /// <p>The IDs of the public subnets. You can specify only one subnet per Availability Zone. You must specify either subnets or subnet mappings.</p> <p>[Application Load Balancers] You must specify subnets from at least two Availability Zones. You cannot specify Elastic IP addresses for your subnets.</p> <p>[Network Load Balancers] You can specify subnets from one or more Availability Zones. You can specify one Elastic IP address per subnet if you need static IP addresses for your internet-facing load balancer. For internal load balancers, you can specify one private IP address per subnet from the IPv4 range of the subnet.</p>
@@ -1324,7 +1649,7 @@ pub struct CreateLoadBalancerInput {
     /// <p>One or more tags to assign to the load balancer.</p>
     pub tags: Option<Vec<Tag>>,
     /// <p>The type of load balancer. The default is <code>application</code>.</p>
-    pub type_: Option<String>,
+    pub type_: Option<LoadBalancerTypeEnum>,
 }
 
 /// Serialize `CreateLoadBalancerInput` contents to a `SignedRequest`.
@@ -1472,7 +1797,7 @@ pub struct CreateTargetGroupInput {
     /// <p>The port the load balancer uses when performing health checks on targets. The default is <code>traffic-port</code>, which is the port on which each target receives traffic from the load balancer.</p>
     pub health_check_port: Option<String>,
     /// <p>The protocol the load balancer uses when performing health checks on targets. For Application Load Balancers, the default is HTTP. For Network Load Balancers, the default is TCP. The TCP protocol is supported for health checks only if the protocol of the target group is TCP, TLS, UDP, or TCP_UDP. The TLS, UDP, and TCP_UDP protocols are not supported for health checks.</p>
-    pub health_check_protocol: Option<String>,
+    pub health_check_protocol: Option<ProtocolEnum>,
     /// <p>The amount of time, in seconds, during which no response from a target means a failed health check. For target groups with a protocol of HTTP or HTTPS, the default is 5 seconds. For target groups with a protocol of TCP or TLS, this value must be 6 seconds for HTTP health checks and 10 seconds for TCP and HTTPS health checks. If the target type is <code>lambda</code>, the default is 30 seconds.</p>
     pub health_check_timeout_seconds: Option<i64>,
     /// <p>The number of consecutive health checks successes required before considering an unhealthy target healthy. For target groups with a protocol of HTTP or HTTPS, the default is 5. For target groups with a protocol of TCP or TLS, the default is 3. If the target type is <code>lambda</code>, the default is 5.</p>
@@ -1484,9 +1809,9 @@ pub struct CreateTargetGroupInput {
     /// <p>The port on which the targets receive traffic. This port is used unless you specify a port override when registering the target. If the target is a Lambda function, this parameter does not apply.</p>
     pub port: Option<i64>,
     /// <p>The protocol to use for routing traffic to the targets. For Application Load Balancers, the supported protocols are HTTP and HTTPS. For Network Load Balancers, the supported protocols are TCP, TLS, UDP, or TCP_UDP. A TCP_UDP listener must be associated with a TCP_UDP target group. If the target is a Lambda function, this parameter does not apply.</p>
-    pub protocol: Option<String>,
+    pub protocol: Option<ProtocolEnum>,
     /// <p><p>The type of target that you must specify when registering targets with this target group. You can&#39;t specify targets for a target group using more than one target type.</p> <ul> <li> <p> <code>instance</code> - Targets are specified by instance ID. This is the default value. If the target group protocol is UDP or TCP_UDP, the target type must be <code>instance</code>.</p> </li> <li> <p> <code>ip</code> - Targets are specified by IP address. You can specify IP addresses from the subnets of the virtual private cloud (VPC) for the target group, the RFC 1918 range (10.0.0.0/8, 172.16.0.0/12, and 192.168.0.0/16), and the RFC 6598 range (100.64.0.0/10). You can&#39;t specify publicly routable IP addresses.</p> </li> <li> <p> <code>lambda</code> - The target groups contains a single Lambda function.</p> </li> </ul></p>
-    pub target_type: Option<String>,
+    pub target_type: Option<TargetTypeEnum>,
     /// <p>The number of consecutive health check failures required before considering a target unhealthy. For target groups with a protocol of HTTP or HTTPS, the default is 2. For target groups with a protocol of TCP or TLS, this value must be the same as the healthy threshold count. If the target type is <code>lambda</code>, the default is 2.</p>
     pub unhealthy_threshold_count: Option<i64>,
     /// <p>The identifier of the virtual private cloud (VPC). If the target is a Lambda function, this parameter does not apply. Otherwise, this parameter is required.</p>
@@ -1594,9 +1919,12 @@ impl CreateTargetGroupOutputDeserializer {
 struct CreatedTimeDeserializer;
 impl CreatedTimeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<::chrono::DateTime<::chrono::Utc>, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::proto::xml::util::timestamp_characters(stack)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -3029,12 +3357,110 @@ impl IpAddressDeserializer {
         Ok(obj)
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum IpAddressType {
+    /// `ipv4`
+    Ipv4,
+    /// `dualstack`
+    Dualstack,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl IpAddressType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            IpAddressType::Ipv4 => "ipv4",
+            IpAddressType::Dualstack => "dualstack",
+            IpAddressType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for IpAddressType {
+    fn default() -> IpAddressType {
+        IpAddressType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for IpAddressType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for IpAddressType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for IpAddressType {
+    fn from(value: &'a str) -> IpAddressType {
+        match value {
+            "ipv4" => IpAddressType::Ipv4,
+            "dualstack" => IpAddressType::Dualstack,
+            _ => IpAddressType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for IpAddressType {
+    fn from(value: String) -> IpAddressType {
+        match value.as_str() {
+            "ipv4" => IpAddressType::Ipv4,
+            "dualstack" => IpAddressType::Dualstack,
+            _ => IpAddressType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for IpAddressType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<IpAddressType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for IpAddressType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for IpAddressType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for IpAddressType {
+    fn deserialize<D>(deserializer: D) -> Result<IpAddressType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct IpAddressTypeDeserializer;
 impl IpAddressTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<IpAddressType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -3140,7 +3566,7 @@ pub struct Listener {
     /// <p>The port on which the load balancer is listening.</p>
     pub port: Option<i64>,
     /// <p>The protocol for connections from clients to the load balancer.</p>
-    pub protocol: Option<String>,
+    pub protocol: Option<ProtocolEnum>,
     /// <p>[HTTPS or TLS listener] The security policy that defines which ciphers and protocols are supported. The default is the current predefined security policy.</p>
     pub ssl_policy: Option<String>,
 }
@@ -3239,23 +3665,23 @@ pub struct LoadBalancer {
     /// <p>The ID of the Amazon Route 53 hosted zone associated with the load balancer.</p>
     pub canonical_hosted_zone_id: Option<String>,
     /// <p>The date and time the load balancer was created.</p>
-    pub created_time: Option<String>,
+    pub created_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The public DNS name of the load balancer.</p>
     pub dns_name: Option<String>,
     /// <p>The type of IP addresses used by the subnets for your load balancer. The possible values are <code>ipv4</code> (for IPv4 addresses) and <code>dualstack</code> (for IPv4 and IPv6 addresses).</p>
-    pub ip_address_type: Option<String>,
+    pub ip_address_type: Option<IpAddressType>,
     /// <p>The Amazon Resource Name (ARN) of the load balancer.</p>
     pub load_balancer_arn: Option<String>,
     /// <p>The name of the load balancer.</p>
     pub load_balancer_name: Option<String>,
     /// <p>The nodes of an Internet-facing load balancer have public IP addresses. The DNS name of an Internet-facing load balancer is publicly resolvable to the public IP addresses of the nodes. Therefore, Internet-facing load balancers can route requests from clients over the internet.</p> <p>The nodes of an internal load balancer have only private IP addresses. The DNS name of an internal load balancer is publicly resolvable to the private IP addresses of the nodes. Therefore, internal load balancers can route requests only from clients with access to the VPC for the load balancer.</p>
-    pub scheme: Option<String>,
+    pub scheme: Option<LoadBalancerSchemeEnum>,
     /// <p>The IDs of the security groups for the load balancer.</p>
     pub security_groups: Option<Vec<String>>,
     /// <p>The state of the load balancer.</p>
     pub state: Option<LoadBalancerState>,
     /// <p>The type of load balancer.</p>
-    pub type_: Option<String>,
+    pub type_: Option<LoadBalancerTypeEnum>,
     /// <p>The ID of the VPC for the load balancer.</p>
     pub vpc_id: Option<String>,
 }
@@ -3565,12 +3991,110 @@ impl LoadBalancerNamesSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LoadBalancerSchemeEnum {
+    /// `internet-facing`
+    InternetFacing,
+    /// `internal`
+    Internal,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LoadBalancerSchemeEnum {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LoadBalancerSchemeEnum::InternetFacing => "internet-facing",
+            LoadBalancerSchemeEnum::Internal => "internal",
+            LoadBalancerSchemeEnum::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LoadBalancerSchemeEnum {
+    fn default() -> LoadBalancerSchemeEnum {
+        LoadBalancerSchemeEnum::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LoadBalancerSchemeEnum {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LoadBalancerSchemeEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LoadBalancerSchemeEnum {
+    fn from(value: &'a str) -> LoadBalancerSchemeEnum {
+        match value {
+            "internet-facing" => LoadBalancerSchemeEnum::InternetFacing,
+            "internal" => LoadBalancerSchemeEnum::Internal,
+            _ => LoadBalancerSchemeEnum::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LoadBalancerSchemeEnum {
+    fn from(value: String) -> LoadBalancerSchemeEnum {
+        match value.as_str() {
+            "internet-facing" => LoadBalancerSchemeEnum::InternetFacing,
+            "internal" => LoadBalancerSchemeEnum::Internal,
+            _ => LoadBalancerSchemeEnum::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LoadBalancerSchemeEnum {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LoadBalancerSchemeEnum, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LoadBalancerSchemeEnum {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for LoadBalancerSchemeEnum {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for LoadBalancerSchemeEnum {
+    fn deserialize<D>(deserializer: D) -> Result<LoadBalancerSchemeEnum, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct LoadBalancerSchemeEnumDeserializer;
 impl LoadBalancerSchemeEnumDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<LoadBalancerSchemeEnum, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -3581,7 +4105,7 @@ impl LoadBalancerSchemeEnumDeserializer {
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct LoadBalancerState {
     /// <p>The state code. The initial state of the load balancer is <code>provisioning</code>. After the load balancer is fully set up and ready to route traffic, its state is <code>active</code>. If the load balancer could not be set up, its state is <code>failed</code>.</p>
-    pub code: Option<String>,
+    pub code: Option<LoadBalancerStateEnum>,
     /// <p>A description of the state.</p>
     pub reason: Option<String>,
 }
@@ -3609,23 +4133,234 @@ impl LoadBalancerStateDeserializer {
         })
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LoadBalancerStateEnum {
+    /// `active`
+    Active,
+    /// `provisioning`
+    Provisioning,
+    /// `active_impaired`
+    ActiveImpaired,
+    /// `failed`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LoadBalancerStateEnum {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LoadBalancerStateEnum::Active => "active",
+            LoadBalancerStateEnum::Provisioning => "provisioning",
+            LoadBalancerStateEnum::ActiveImpaired => "active_impaired",
+            LoadBalancerStateEnum::Failed => "failed",
+            LoadBalancerStateEnum::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LoadBalancerStateEnum {
+    fn default() -> LoadBalancerStateEnum {
+        LoadBalancerStateEnum::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LoadBalancerStateEnum {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LoadBalancerStateEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LoadBalancerStateEnum {
+    fn from(value: &'a str) -> LoadBalancerStateEnum {
+        match value {
+            "active" => LoadBalancerStateEnum::Active,
+            "provisioning" => LoadBalancerStateEnum::Provisioning,
+            "active_impaired" => LoadBalancerStateEnum::ActiveImpaired,
+            "failed" => LoadBalancerStateEnum::Failed,
+            _ => LoadBalancerStateEnum::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LoadBalancerStateEnum {
+    fn from(value: String) -> LoadBalancerStateEnum {
+        match value.as_str() {
+            "active" => LoadBalancerStateEnum::Active,
+            "provisioning" => LoadBalancerStateEnum::Provisioning,
+            "active_impaired" => LoadBalancerStateEnum::ActiveImpaired,
+            "failed" => LoadBalancerStateEnum::Failed,
+            _ => LoadBalancerStateEnum::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LoadBalancerStateEnum {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LoadBalancerStateEnum, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LoadBalancerStateEnum {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for LoadBalancerStateEnum {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for LoadBalancerStateEnum {
+    fn deserialize<D>(deserializer: D) -> Result<LoadBalancerStateEnum, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct LoadBalancerStateEnumDeserializer;
 impl LoadBalancerStateEnumDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<LoadBalancerStateEnum, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LoadBalancerTypeEnum {
+    /// `application`
+    Application,
+    /// `network`
+    Network,
+    /// `gateway`
+    Gateway,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LoadBalancerTypeEnum {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LoadBalancerTypeEnum::Application => "application",
+            LoadBalancerTypeEnum::Network => "network",
+            LoadBalancerTypeEnum::Gateway => "gateway",
+            LoadBalancerTypeEnum::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LoadBalancerTypeEnum {
+    fn default() -> LoadBalancerTypeEnum {
+        LoadBalancerTypeEnum::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LoadBalancerTypeEnum {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LoadBalancerTypeEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LoadBalancerTypeEnum {
+    fn from(value: &'a str) -> LoadBalancerTypeEnum {
+        match value {
+            "application" => LoadBalancerTypeEnum::Application,
+            "network" => LoadBalancerTypeEnum::Network,
+            "gateway" => LoadBalancerTypeEnum::Gateway,
+            _ => LoadBalancerTypeEnum::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LoadBalancerTypeEnum {
+    fn from(value: String) -> LoadBalancerTypeEnum {
+        match value.as_str() {
+            "application" => LoadBalancerTypeEnum::Application,
+            "network" => LoadBalancerTypeEnum::Network,
+            "gateway" => LoadBalancerTypeEnum::Gateway,
+            _ => LoadBalancerTypeEnum::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LoadBalancerTypeEnum {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LoadBalancerTypeEnum, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LoadBalancerTypeEnum {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for LoadBalancerTypeEnum {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for LoadBalancerTypeEnum {
+    fn deserialize<D>(deserializer: D) -> Result<LoadBalancerTypeEnum, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct LoadBalancerTypeEnumDeserializer;
 impl LoadBalancerTypeEnumDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<LoadBalancerTypeEnum, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -3723,7 +4458,7 @@ pub struct ModifyListenerInput {
     /// <p>The port for connections from clients to the load balancer.</p>
     pub port: Option<i64>,
     /// <p>The protocol for connections from clients to the load balancer. Application Load Balancers support the HTTP and HTTPS protocols. Network Load Balancers support the TCP, TLS, UDP, and TCP_UDP protocols.</p>
-    pub protocol: Option<String>,
+    pub protocol: Option<ProtocolEnum>,
     /// <p>[HTTPS and TLS listeners] The security policy that defines which protocols and ciphers are supported. For more information, see <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/application/create-https-listener.html#describe-ssl-policies">Security Policies</a> in the <i>Application Load Balancers Guide</i>.</p>
     pub ssl_policy: Option<String>,
 }
@@ -3986,7 +4721,7 @@ pub struct ModifyTargetGroupInput {
     /// <p>The port the load balancer uses when performing health checks on targets.</p>
     pub health_check_port: Option<String>,
     /// <p>The protocol the load balancer uses when performing health checks on targets. The TCP protocol is supported for health checks only if the protocol of the target group is TCP, TLS, UDP, or TCP_UDP. The TLS, UDP, and TCP_UDP protocols are not supported for health checks.</p> <p>With Network Load Balancers, you can't modify this setting.</p>
-    pub health_check_protocol: Option<String>,
+    pub health_check_protocol: Option<ProtocolEnum>,
     /// <p>[HTTP/HTTPS health checks] The amount of time, in seconds, during which no response means a failed health check.</p> <p>With Network Load Balancers, you can't modify this setting.</p>
     pub health_check_timeout_seconds: Option<i64>,
     /// <p>The number of consecutive health checks successes required before considering an unhealthy target healthy.</p>
@@ -4185,12 +4920,135 @@ impl PrivateIPv4AddressDeserializer {
         Ok(obj)
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ProtocolEnum {
+    /// `HTTP`
+    Http,
+    /// `HTTPS`
+    Https,
+    /// `TCP`
+    Tcp,
+    /// `TLS`
+    Tls,
+    /// `UDP`
+    Udp,
+    /// `TCP_UDP`
+    TcpUdp,
+    /// `GENEVE`
+    Geneve,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ProtocolEnum {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ProtocolEnum::Http => "HTTP",
+            ProtocolEnum::Https => "HTTPS",
+            ProtocolEnum::Tcp => "TCP",
+            ProtocolEnum::Tls => "TLS",
+            ProtocolEnum::Udp => "UDP",
+            ProtocolEnum::TcpUdp => "TCP_UDP",
+            ProtocolEnum::Geneve => "GENEVE",
+            ProtocolEnum::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ProtocolEnum {
+    fn default() -> ProtocolEnum {
+        ProtocolEnum::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ProtocolEnum {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ProtocolEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ProtocolEnum {
+    fn from(value: &'a str) -> ProtocolEnum {
+        match value {
+            "HTTP" => ProtocolEnum::Http,
+            "HTTPS" => ProtocolEnum::Https,
+            "TCP" => ProtocolEnum::Tcp,
+            "TLS" => ProtocolEnum::Tls,
+            "UDP" => ProtocolEnum::Udp,
+            "TCP_UDP" => ProtocolEnum::TcpUdp,
+            "GENEVE" => ProtocolEnum::Geneve,
+            _ => ProtocolEnum::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ProtocolEnum {
+    fn from(value: String) -> ProtocolEnum {
+        match value.as_str() {
+            "HTTP" => ProtocolEnum::Http,
+            "HTTPS" => ProtocolEnum::Https,
+            "TCP" => ProtocolEnum::Tcp,
+            "TLS" => ProtocolEnum::Tls,
+            "UDP" => ProtocolEnum::Udp,
+            "TCP_UDP" => ProtocolEnum::TcpUdp,
+            "GENEVE" => ProtocolEnum::Geneve,
+            _ => ProtocolEnum::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ProtocolEnum {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ProtocolEnum, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ProtocolEnum {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ProtocolEnum {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ProtocolEnum {
+    fn deserialize<D>(deserializer: D) -> Result<ProtocolEnum, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct ProtocolEnumDeserializer;
 impl ProtocolEnumDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ProtocolEnum, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -4351,7 +5209,7 @@ pub struct RedirectActionConfig {
     /// <p>The query parameters, URL-encoded when necessary, but not percent-encoded. Do not include the leading "?", as it is automatically added. You can specify any of the reserved keywords.</p>
     pub query: Option<String>,
     /// <p>The HTTP redirect code. The redirect is either permanent (HTTP 301) or temporary (HTTP 302).</p>
-    pub status_code: String,
+    pub status_code: RedirectActionStatusCodeEnum,
 }
 
 struct RedirectActionConfigDeserializer;
@@ -4476,12 +5334,110 @@ impl RedirectActionQueryDeserializer {
         Ok(obj)
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RedirectActionStatusCodeEnum {
+    /// `HTTP_301`
+    Http301,
+    /// `HTTP_302`
+    Http302,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RedirectActionStatusCodeEnum {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RedirectActionStatusCodeEnum::Http301 => "HTTP_301",
+            RedirectActionStatusCodeEnum::Http302 => "HTTP_302",
+            RedirectActionStatusCodeEnum::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RedirectActionStatusCodeEnum {
+    fn default() -> RedirectActionStatusCodeEnum {
+        RedirectActionStatusCodeEnum::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RedirectActionStatusCodeEnum {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RedirectActionStatusCodeEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RedirectActionStatusCodeEnum {
+    fn from(value: &'a str) -> RedirectActionStatusCodeEnum {
+        match value {
+            "HTTP_301" => RedirectActionStatusCodeEnum::Http301,
+            "HTTP_302" => RedirectActionStatusCodeEnum::Http302,
+            _ => RedirectActionStatusCodeEnum::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RedirectActionStatusCodeEnum {
+    fn from(value: String) -> RedirectActionStatusCodeEnum {
+        match value.as_str() {
+            "HTTP_301" => RedirectActionStatusCodeEnum::Http301,
+            "HTTP_302" => RedirectActionStatusCodeEnum::Http302,
+            _ => RedirectActionStatusCodeEnum::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RedirectActionStatusCodeEnum {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RedirectActionStatusCodeEnum, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RedirectActionStatusCodeEnum {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for RedirectActionStatusCodeEnum {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for RedirectActionStatusCodeEnum {
+    fn deserialize<D>(deserializer: D) -> Result<RedirectActionStatusCodeEnum, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct RedirectActionStatusCodeEnumDeserializer;
 impl RedirectActionStatusCodeEnumDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<RedirectActionStatusCodeEnum, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -5005,7 +5961,7 @@ impl SecurityGroupsSerializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct SetIpAddressTypeInput {
     /// <p>The IP address type. The possible values are <code>ipv4</code> (for IPv4 addresses) and <code>dualstack</code> (for IPv4 and IPv6 addresses). Internal load balancers must use <code>ipv4</code>. Network Load Balancers must use <code>ipv4</code>.</p>
-    pub ip_address_type: String,
+    pub ip_address_type: IpAddressType,
     /// <p>The Amazon Resource Name (ARN) of the load balancer.</p>
     pub load_balancer_arn: String,
 }
@@ -5034,7 +5990,7 @@ impl SetIpAddressTypeInputSerializer {
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct SetIpAddressTypeOutput {
     /// <p>The IP address type.</p>
-    pub ip_address_type: Option<String>,
+    pub ip_address_type: Option<IpAddressType>,
 }
 
 struct SetIpAddressTypeOutputDeserializer;
@@ -5742,7 +6698,7 @@ pub struct TargetGroup {
     /// <p>The port to use to connect with the target.</p>
     pub health_check_port: Option<String>,
     /// <p>The protocol to use to connect with the target.</p>
-    pub health_check_protocol: Option<String>,
+    pub health_check_protocol: Option<ProtocolEnum>,
     /// <p>The amount of time, in seconds, during which no response means a failed health check.</p>
     pub health_check_timeout_seconds: Option<i64>,
     /// <p>The number of consecutive health checks successes required before considering an unhealthy target healthy.</p>
@@ -5754,13 +6710,13 @@ pub struct TargetGroup {
     /// <p>The port on which the targets are listening. Not used if the target is a Lambda function.</p>
     pub port: Option<i64>,
     /// <p>The protocol to use for routing traffic to the targets.</p>
-    pub protocol: Option<String>,
+    pub protocol: Option<ProtocolEnum>,
     /// <p>The Amazon Resource Name (ARN) of the target group.</p>
     pub target_group_arn: Option<String>,
     /// <p>The name of the target group.</p>
     pub target_group_name: Option<String>,
     /// <p>The type of target that you must specify when registering targets with this target group. The possible values are <code>instance</code> (targets are specified by instance ID) or <code>ip</code> (targets are specified by IP address).</p>
-    pub target_type: Option<String>,
+    pub target_type: Option<TargetTypeEnum>,
     /// <p>The number of consecutive health check failures required before considering the target unhealthy.</p>
     pub unhealthy_threshold_count: Option<i64>,
     /// <p>The ID of the VPC for the targets.</p>
@@ -6223,9 +7179,9 @@ pub struct TargetHealth {
     /// <p>A description of the target health that provides additional details. If the state is <code>healthy</code>, a description is not provided.</p>
     pub description: Option<String>,
     /// <p><p>The reason code.</p> <p>If the target state is <code>healthy</code>, a reason code is not provided.</p> <p>If the target state is <code>initial</code>, the reason code can be one of the following values:</p> <ul> <li> <p> <code>Elb.RegistrationInProgress</code> - The target is in the process of being registered with the load balancer.</p> </li> <li> <p> <code>Elb.InitialHealthChecking</code> - The load balancer is still sending the target the minimum number of health checks required to determine its health status.</p> </li> </ul> <p>If the target state is <code>unhealthy</code>, the reason code can be one of the following values:</p> <ul> <li> <p> <code>Target.ResponseCodeMismatch</code> - The health checks did not return an expected HTTP code. Applies only to Application Load Balancers.</p> </li> <li> <p> <code>Target.Timeout</code> - The health check requests timed out. Applies only to Application Load Balancers.</p> </li> <li> <p> <code>Target.FailedHealthChecks</code> - The load balancer received an error while establishing a connection to the target or the target response was malformed.</p> </li> <li> <p> <code>Elb.InternalError</code> - The health checks failed due to an internal error. Applies only to Application Load Balancers.</p> </li> </ul> <p>If the target state is <code>unused</code>, the reason code can be one of the following values:</p> <ul> <li> <p> <code>Target.NotRegistered</code> - The target is not registered with the target group.</p> </li> <li> <p> <code>Target.NotInUse</code> - The target group is not used by any load balancer or the target is in an Availability Zone that is not enabled for its load balancer.</p> </li> <li> <p> <code>Target.InvalidState</code> - The target is in the stopped or terminated state.</p> </li> <li> <p> <code>Target.IpUnusable</code> - The target IP address is reserved for use by a load balancer.</p> </li> </ul> <p>If the target state is <code>draining</code>, the reason code can be the following value:</p> <ul> <li> <p> <code>Target.DeregistrationInProgress</code> - The target is in the process of being deregistered and the deregistration delay period has not expired.</p> </li> </ul> <p>If the target state is <code>unavailable</code>, the reason code can be the following value:</p> <ul> <li> <p> <code>Target.HealthCheckDisabled</code> - Health checks are disabled for the target group. Applies only to Application Load Balancers.</p> </li> <li> <p> <code>Elb.InternalError</code> - Target health is unavailable due to an internal error. Applies only to Network Load Balancers.</p> </li> </ul></p>
-    pub reason: Option<String>,
+    pub reason: Option<TargetHealthReasonEnum>,
     /// <p>The state of the target.</p>
-    pub state: Option<String>,
+    pub state: Option<TargetHealthStateEnum>,
 }
 
 struct TargetHealthDeserializer;
@@ -6323,23 +7279,295 @@ impl TargetHealthDescriptionsDeserializer {
         })
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TargetHealthReasonEnum {
+    /// `Elb.RegistrationInProgress`
+    ElbRegistrationInProgress,
+    /// `Elb.InitialHealthChecking`
+    ElbInitialHealthChecking,
+    /// `Target.ResponseCodeMismatch`
+    TargetResponseCodeMismatch,
+    /// `Target.Timeout`
+    TargetTimeout,
+    /// `Target.FailedHealthChecks`
+    TargetFailedHealthChecks,
+    /// `Target.NotRegistered`
+    TargetNotRegistered,
+    /// `Target.NotInUse`
+    TargetNotInUse,
+    /// `Target.DeregistrationInProgress`
+    TargetDeregistrationInProgress,
+    /// `Target.InvalidState`
+    TargetInvalidState,
+    /// `Target.IpUnusable`
+    TargetIpUnusable,
+    /// `Target.HealthCheckDisabled`
+    TargetHealthCheckDisabled,
+    /// `Elb.InternalError`
+    ElbInternalError,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TargetHealthReasonEnum {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TargetHealthReasonEnum::ElbRegistrationInProgress => "Elb.RegistrationInProgress",
+            TargetHealthReasonEnum::ElbInitialHealthChecking => "Elb.InitialHealthChecking",
+            TargetHealthReasonEnum::TargetResponseCodeMismatch => "Target.ResponseCodeMismatch",
+            TargetHealthReasonEnum::TargetTimeout => "Target.Timeout",
+            TargetHealthReasonEnum::TargetFailedHealthChecks => "Target.FailedHealthChecks",
+            TargetHealthReasonEnum::TargetNotRegistered => "Target.NotRegistered",
+            TargetHealthReasonEnum::TargetNotInUse => "Target.NotInUse",
+            TargetHealthReasonEnum::TargetDeregistrationInProgress => {
+                "Target.DeregistrationInProgress"
+            }
+            TargetHealthReasonEnum::TargetInvalidState => "Target.InvalidState",
+            TargetHealthReasonEnum::TargetIpUnusable => "Target.IpUnusable",
+            TargetHealthReasonEnum::TargetHealthCheckDisabled => "Target.HealthCheckDisabled",
+            TargetHealthReasonEnum::ElbInternalError => "Elb.InternalError",
+            TargetHealthReasonEnum::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TargetHealthReasonEnum {
+    fn default() -> TargetHealthReasonEnum {
+        TargetHealthReasonEnum::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TargetHealthReasonEnum {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TargetHealthReasonEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TargetHealthReasonEnum {
+    fn from(value: &'a str) -> TargetHealthReasonEnum {
+        match value {
+            "Elb.RegistrationInProgress" => TargetHealthReasonEnum::ElbRegistrationInProgress,
+            "Elb.InitialHealthChecking" => TargetHealthReasonEnum::ElbInitialHealthChecking,
+            "Target.ResponseCodeMismatch" => TargetHealthReasonEnum::TargetResponseCodeMismatch,
+            "Target.Timeout" => TargetHealthReasonEnum::TargetTimeout,
+            "Target.FailedHealthChecks" => TargetHealthReasonEnum::TargetFailedHealthChecks,
+            "Target.NotRegistered" => TargetHealthReasonEnum::TargetNotRegistered,
+            "Target.NotInUse" => TargetHealthReasonEnum::TargetNotInUse,
+            "Target.DeregistrationInProgress" => {
+                TargetHealthReasonEnum::TargetDeregistrationInProgress
+            }
+            "Target.InvalidState" => TargetHealthReasonEnum::TargetInvalidState,
+            "Target.IpUnusable" => TargetHealthReasonEnum::TargetIpUnusable,
+            "Target.HealthCheckDisabled" => TargetHealthReasonEnum::TargetHealthCheckDisabled,
+            "Elb.InternalError" => TargetHealthReasonEnum::ElbInternalError,
+            _ => TargetHealthReasonEnum::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TargetHealthReasonEnum {
+    fn from(value: String) -> TargetHealthReasonEnum {
+        match value.as_str() {
+            "Elb.RegistrationInProgress" => TargetHealthReasonEnum::ElbRegistrationInProgress,
+            "Elb.InitialHealthChecking" => TargetHealthReasonEnum::ElbInitialHealthChecking,
+            "Target.ResponseCodeMismatch" => TargetHealthReasonEnum::TargetResponseCodeMismatch,
+            "Target.Timeout" => TargetHealthReasonEnum::TargetTimeout,
+            "Target.FailedHealthChecks" => TargetHealthReasonEnum::TargetFailedHealthChecks,
+            "Target.NotRegistered" => TargetHealthReasonEnum::TargetNotRegistered,
+            "Target.NotInUse" => TargetHealthReasonEnum::TargetNotInUse,
+            "Target.DeregistrationInProgress" => {
+                TargetHealthReasonEnum::TargetDeregistrationInProgress
+            }
+            "Target.InvalidState" => TargetHealthReasonEnum::TargetInvalidState,
+            "Target.IpUnusable" => TargetHealthReasonEnum::TargetIpUnusable,
+            "Target.HealthCheckDisabled" => TargetHealthReasonEnum::TargetHealthCheckDisabled,
+            "Elb.InternalError" => TargetHealthReasonEnum::ElbInternalError,
+            _ => TargetHealthReasonEnum::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TargetHealthReasonEnum {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TargetHealthReasonEnum, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TargetHealthReasonEnum {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for TargetHealthReasonEnum {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for TargetHealthReasonEnum {
+    fn deserialize<D>(deserializer: D) -> Result<TargetHealthReasonEnum, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct TargetHealthReasonEnumDeserializer;
 impl TargetHealthReasonEnumDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<TargetHealthReasonEnum, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TargetHealthStateEnum {
+    /// `initial`
+    Initial,
+    /// `healthy`
+    Healthy,
+    /// `unhealthy`
+    Unhealthy,
+    /// `unused`
+    Unused,
+    /// `draining`
+    Draining,
+    /// `unavailable`
+    Unavailable,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TargetHealthStateEnum {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TargetHealthStateEnum::Initial => "initial",
+            TargetHealthStateEnum::Healthy => "healthy",
+            TargetHealthStateEnum::Unhealthy => "unhealthy",
+            TargetHealthStateEnum::Unused => "unused",
+            TargetHealthStateEnum::Draining => "draining",
+            TargetHealthStateEnum::Unavailable => "unavailable",
+            TargetHealthStateEnum::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TargetHealthStateEnum {
+    fn default() -> TargetHealthStateEnum {
+        TargetHealthStateEnum::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TargetHealthStateEnum {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TargetHealthStateEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TargetHealthStateEnum {
+    fn from(value: &'a str) -> TargetHealthStateEnum {
+        match value {
+            "initial" => TargetHealthStateEnum::Initial,
+            "healthy" => TargetHealthStateEnum::Healthy,
+            "unhealthy" => TargetHealthStateEnum::Unhealthy,
+            "unused" => TargetHealthStateEnum::Unused,
+            "draining" => TargetHealthStateEnum::Draining,
+            "unavailable" => TargetHealthStateEnum::Unavailable,
+            _ => TargetHealthStateEnum::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TargetHealthStateEnum {
+    fn from(value: String) -> TargetHealthStateEnum {
+        match value.as_str() {
+            "initial" => TargetHealthStateEnum::Initial,
+            "healthy" => TargetHealthStateEnum::Healthy,
+            "unhealthy" => TargetHealthStateEnum::Unhealthy,
+            "unused" => TargetHealthStateEnum::Unused,
+            "draining" => TargetHealthStateEnum::Draining,
+            "unavailable" => TargetHealthStateEnum::Unavailable,
+            _ => TargetHealthStateEnum::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TargetHealthStateEnum {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TargetHealthStateEnum, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TargetHealthStateEnum {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for TargetHealthStateEnum {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for TargetHealthStateEnum {
+    fn deserialize<D>(deserializer: D) -> Result<TargetHealthStateEnum, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct TargetHealthStateEnumDeserializer;
 impl TargetHealthStateEnumDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<TargetHealthStateEnum, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -6356,12 +7584,120 @@ impl TargetIdDeserializer {
         Ok(obj)
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TargetTypeEnum {
+    /// `instance`
+    Instance,
+    /// `ip`
+    Ip,
+    /// `lambda`
+    Lambda,
+    /// `alb`
+    Alb,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TargetTypeEnum {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TargetTypeEnum::Instance => "instance",
+            TargetTypeEnum::Ip => "ip",
+            TargetTypeEnum::Lambda => "lambda",
+            TargetTypeEnum::Alb => "alb",
+            TargetTypeEnum::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TargetTypeEnum {
+    fn default() -> TargetTypeEnum {
+        TargetTypeEnum::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TargetTypeEnum {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TargetTypeEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TargetTypeEnum {
+    fn from(value: &'a str) -> TargetTypeEnum {
+        match value {
+            "instance" => TargetTypeEnum::Instance,
+            "ip" => TargetTypeEnum::Ip,
+            "lambda" => TargetTypeEnum::Lambda,
+            "alb" => TargetTypeEnum::Alb,
+            _ => TargetTypeEnum::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TargetTypeEnum {
+    fn from(value: String) -> TargetTypeEnum {
+        match value.as_str() {
+            "instance" => TargetTypeEnum::Instance,
+            "ip" => TargetTypeEnum::Ip,
+            "lambda" => TargetTypeEnum::Lambda,
+            "alb" => TargetTypeEnum::Alb,
+            _ => TargetTypeEnum::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TargetTypeEnum {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TargetTypeEnum, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TargetTypeEnum {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for TargetTypeEnum {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for TargetTypeEnum {
+    fn deserialize<D>(deserializer: D) -> Result<TargetTypeEnum, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct TargetTypeEnumDeserializer;
 impl TargetTypeEnumDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<TargetTypeEnum, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -9055,11 +10391,15 @@ pub struct ElbClient {
 impl ElbClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> ElbClient {
         ElbClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(
+                region,
+                "Elastic Load Balancing v2",
+            ),
         }
     }
 
@@ -9091,6 +10431,7 @@ impl Elb for ElbClient {
         input: AddListenerCertificatesInput,
     ) -> Result<AddListenerCertificatesOutput, RusotoError<AddListenerCertificatesError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("AddListenerCertificates");
         let mut params = Params::new();
 
         params.put("Action", "AddListenerCertificates");
@@ -9140,6 +10481,7 @@ impl Elb for ElbClient {
         input: AddTagsInput,
     ) -> Result<AddTagsOutput, RusotoError<AddTagsError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("AddTags");
         let mut params = Params::new();
 
         params.put("Action", "AddTags");
@@ -9186,6 +10528,7 @@ impl Elb for ElbClient {
         input: CreateListenerInput,
     ) -> Result<CreateListenerOutput, RusotoError<CreateListenerError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("CreateListener");
         let mut params = Params::new();
 
         params.put("Action", "CreateListener");
@@ -9233,6 +10576,7 @@ impl Elb for ElbClient {
         input: CreateLoadBalancerInput,
     ) -> Result<CreateLoadBalancerOutput, RusotoError<CreateLoadBalancerError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("CreateLoadBalancer");
         let mut params = Params::new();
 
         params.put("Action", "CreateLoadBalancer");
@@ -9282,6 +10626,7 @@ impl Elb for ElbClient {
         input: CreateRuleInput,
     ) -> Result<CreateRuleOutput, RusotoError<CreateRuleError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("CreateRule");
         let mut params = Params::new();
 
         params.put("Action", "CreateRule");
@@ -9328,6 +10673,7 @@ impl Elb for ElbClient {
         input: CreateTargetGroupInput,
     ) -> Result<CreateTargetGroupOutput, RusotoError<CreateTargetGroupError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("CreateTargetGroup");
         let mut params = Params::new();
 
         params.put("Action", "CreateTargetGroup");
@@ -9377,6 +10723,7 @@ impl Elb for ElbClient {
         input: DeleteListenerInput,
     ) -> Result<DeleteListenerOutput, RusotoError<DeleteListenerError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DeleteListener");
         let mut params = Params::new();
 
         params.put("Action", "DeleteListener");
@@ -9424,6 +10771,7 @@ impl Elb for ElbClient {
         input: DeleteLoadBalancerInput,
     ) -> Result<DeleteLoadBalancerOutput, RusotoError<DeleteLoadBalancerError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DeleteLoadBalancer");
         let mut params = Params::new();
 
         params.put("Action", "DeleteLoadBalancer");
@@ -9473,6 +10821,7 @@ impl Elb for ElbClient {
         input: DeleteRuleInput,
     ) -> Result<DeleteRuleOutput, RusotoError<DeleteRuleError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DeleteRule");
         let mut params = Params::new();
 
         params.put("Action", "DeleteRule");
@@ -9519,6 +10868,7 @@ impl Elb for ElbClient {
         input: DeleteTargetGroupInput,
     ) -> Result<DeleteTargetGroupOutput, RusotoError<DeleteTargetGroupError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DeleteTargetGroup");
         let mut params = Params::new();
 
         params.put("Action", "DeleteTargetGroup");
@@ -9568,6 +10918,7 @@ impl Elb for ElbClient {
         input: DeregisterTargetsInput,
     ) -> Result<DeregisterTargetsOutput, RusotoError<DeregisterTargetsError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DeregisterTargets");
         let mut params = Params::new();
 
         params.put("Action", "DeregisterTargets");
@@ -9617,6 +10968,7 @@ impl Elb for ElbClient {
         input: DescribeAccountLimitsInput,
     ) -> Result<DescribeAccountLimitsOutput, RusotoError<DescribeAccountLimitsError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeAccountLimits");
         let mut params = Params::new();
 
         params.put("Action", "DescribeAccountLimits");
@@ -9667,6 +11019,7 @@ impl Elb for ElbClient {
     ) -> Result<DescribeListenerCertificatesOutput, RusotoError<DescribeListenerCertificatesError>>
     {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeListenerCertificates");
         let mut params = Params::new();
 
         params.put("Action", "DescribeListenerCertificates");
@@ -9716,6 +11069,7 @@ impl Elb for ElbClient {
         input: DescribeListenersInput,
     ) -> Result<DescribeListenersOutput, RusotoError<DescribeListenersError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeListeners");
         let mut params = Params::new();
 
         params.put("Action", "DescribeListeners");
@@ -9768,6 +11122,7 @@ impl Elb for ElbClient {
         RusotoError<DescribeLoadBalancerAttributesError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeLoadBalancerAttributes");
         let mut params = Params::new();
 
         params.put("Action", "DescribeLoadBalancerAttributes");
@@ -9817,6 +11172,7 @@ impl Elb for ElbClient {
         input: DescribeLoadBalancersInput,
     ) -> Result<DescribeLoadBalancersOutput, RusotoError<DescribeLoadBalancersError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeLoadBalancers");
         let mut params = Params::new();
 
         params.put("Action", "DescribeLoadBalancers");
@@ -9866,6 +11222,7 @@ impl Elb for ElbClient {
         input: DescribeRulesInput,
     ) -> Result<DescribeRulesOutput, RusotoError<DescribeRulesError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeRules");
         let mut params = Params::new();
 
         params.put("Action", "DescribeRules");
@@ -9913,6 +11270,7 @@ impl Elb for ElbClient {
         input: DescribeSSLPoliciesInput,
     ) -> Result<DescribeSSLPoliciesOutput, RusotoError<DescribeSSLPoliciesError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeSSLPolicies");
         let mut params = Params::new();
 
         params.put("Action", "DescribeSSLPolicies");
@@ -9962,6 +11320,7 @@ impl Elb for ElbClient {
         input: DescribeTagsInput,
     ) -> Result<DescribeTagsOutput, RusotoError<DescribeTagsError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeTags");
         let mut params = Params::new();
 
         params.put("Action", "DescribeTags");
@@ -10009,6 +11368,7 @@ impl Elb for ElbClient {
     ) -> Result<DescribeTargetGroupAttributesOutput, RusotoError<DescribeTargetGroupAttributesError>>
     {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeTargetGroupAttributes");
         let mut params = Params::new();
 
         params.put("Action", "DescribeTargetGroupAttributes");
@@ -10058,6 +11418,7 @@ impl Elb for ElbClient {
         input: DescribeTargetGroupsInput,
     ) -> Result<DescribeTargetGroupsOutput, RusotoError<DescribeTargetGroupsError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeTargetGroups");
         let mut params = Params::new();
 
         params.put("Action", "DescribeTargetGroups");
@@ -10107,6 +11468,7 @@ impl Elb for ElbClient {
         input: DescribeTargetHealthInput,
     ) -> Result<DescribeTargetHealthOutput, RusotoError<DescribeTargetHealthError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeTargetHealth");
         let mut params = Params::new();
 
         params.put("Action", "DescribeTargetHealth");
@@ -10156,6 +11518,7 @@ impl Elb for ElbClient {
         input: ModifyListenerInput,
     ) -> Result<ModifyListenerOutput, RusotoError<ModifyListenerError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("ModifyListener");
         let mut params = Params::new();
 
         params.put("Action", "ModifyListener");
@@ -10204,6 +11567,7 @@ impl Elb for ElbClient {
     ) -> Result<ModifyLoadBalancerAttributesOutput, RusotoError<ModifyLoadBalancerAttributesError>>
     {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("ModifyLoadBalancerAttributes");
         let mut params = Params::new();
 
         params.put("Action", "ModifyLoadBalancerAttributes");
@@ -10253,6 +11617,7 @@ impl Elb for ElbClient {
         input: ModifyRuleInput,
     ) -> Result<ModifyRuleOutput, RusotoError<ModifyRuleError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("ModifyRule");
         let mut params = Params::new();
 
         params.put("Action", "ModifyRule");
@@ -10299,6 +11664,7 @@ impl Elb for ElbClient {
         input: ModifyTargetGroupInput,
     ) -> Result<ModifyTargetGroupOutput, RusotoError<ModifyTargetGroupError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("ModifyTargetGroup");
         let mut params = Params::new();
 
         params.put("Action", "ModifyTargetGroup");
@@ -10349,6 +11715,7 @@ impl Elb for ElbClient {
     ) -> Result<ModifyTargetGroupAttributesOutput, RusotoError<ModifyTargetGroupAttributesError>>
     {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("ModifyTargetGroupAttributes");
         let mut params = Params::new();
 
         params.put("Action", "ModifyTargetGroupAttributes");
@@ -10398,6 +11765,7 @@ impl Elb for ElbClient {
         input: RegisterTargetsInput,
     ) -> Result<RegisterTargetsOutput, RusotoError<RegisterTargetsError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("RegisterTargets");
         let mut params = Params::new();
 
         params.put("Action", "RegisterTargets");
@@ -10448,6 +11816,7 @@ impl Elb for ElbClient {
     ) -> Result<RemoveListenerCertificatesOutput, RusotoError<RemoveListenerCertificatesError>>
     {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("RemoveListenerCertificates");
         let mut params = Params::new();
 
         params.put("Action", "RemoveListenerCertificates");
@@ -10497,6 +11866,7 @@ impl Elb for ElbClient {
         input: RemoveTagsInput,
     ) -> Result<RemoveTagsOutput, RusotoError<RemoveTagsError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("RemoveTags");
         let mut params = Params::new();
 
         params.put("Action", "RemoveTags");
@@ -10543,6 +11913,7 @@ impl Elb for ElbClient {
         input: SetIpAddressTypeInput,
     ) -> Result<SetIpAddressTypeOutput, RusotoError<SetIpAddressTypeError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("SetIpAddressType");
         let mut params = Params::new();
 
         params.put("Action", "SetIpAddressType");
@@ -10592,6 +11963,7 @@ impl Elb for ElbClient {
         input: SetRulePrioritiesInput,
     ) -> Result<SetRulePrioritiesOutput, RusotoError<SetRulePrioritiesError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("SetRulePriorities");
         let mut params = Params::new();
 
         params.put("Action", "SetRulePriorities");
@@ -10641,6 +12013,7 @@ impl Elb for ElbClient {
         input: SetSecurityGroupsInput,
     ) -> Result<SetSecurityGroupsOutput, RusotoError<SetSecurityGroupsError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("SetSecurityGroups");
         let mut params = Params::new();
 
         params.put("Action", "SetSecurityGroups");
@@ -10690,6 +12063,7 @@ impl Elb for ElbClient {
         input: SetSubnetsInput,
     ) -> Result<SetSubnetsOutput, RusotoError<SetSubnetsError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("SetSubnets");
         let mut params = Params::new();
 
         params.put("Action", "SetSubnets");
@@ -10730,6 +12104,494 @@ impl Elb for ElbClient {
         Ok(result)
     }
 }
+impl ElbClient {
+    /// Auto-paginating version of `describe_account_limits`, streaming every page of results.
+    pub fn describe_account_limits_pages(
+        &self,
+        input: DescribeAccountLimitsInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeAccountLimitsOutput,
+        DescribeAccountLimitsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ElbClient, input: DescribeAccountLimitsInput| async move {
+                client.describe_account_limits(input).await
+            },
+            |input: &DescribeAccountLimitsInput, output: &DescribeAccountLimitsOutput| {
+                Some(DescribeAccountLimitsInput {
+                    marker: Some(output.next_marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_account_limits`, streaming the `Limits` of every page of results.
+    pub fn describe_account_limits_items(
+        &self,
+        input: DescribeAccountLimitsInput,
+    ) -> rusoto_core::pagination::RusotoStream<Limit, DescribeAccountLimitsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_account_limits_pages(input),
+            |output: DescribeAccountLimitsOutput| output.limits.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_listener_certificates`, streaming every page of results.
+    pub fn describe_listener_certificates_pages(
+        &self,
+        input: DescribeListenerCertificatesInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeListenerCertificatesOutput,
+        DescribeListenerCertificatesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ElbClient, input: DescribeListenerCertificatesInput| async move {
+                client.describe_listener_certificates(input).await
+            },
+            |input: &DescribeListenerCertificatesInput,
+             output: &DescribeListenerCertificatesOutput| {
+                Some(DescribeListenerCertificatesInput {
+                    marker: Some(output.next_marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_listener_certificates`, streaming the `Certificates` of every page of results.
+    pub fn describe_listener_certificates_items(
+        &self,
+        input: DescribeListenerCertificatesInput,
+    ) -> rusoto_core::pagination::RusotoStream<Certificate, DescribeListenerCertificatesError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_listener_certificates_pages(input),
+            |output: DescribeListenerCertificatesOutput| output.certificates.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_listeners`, streaming every page of results.
+    pub fn describe_listeners_pages(
+        &self,
+        input: DescribeListenersInput,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeListenersOutput, DescribeListenersError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ElbClient, input: DescribeListenersInput| async move {
+                client.describe_listeners(input).await
+            },
+            |input: &DescribeListenersInput, output: &DescribeListenersOutput| {
+                Some(DescribeListenersInput {
+                    marker: Some(output.next_marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_listeners`, streaming the `Listeners` of every page of results.
+    pub fn describe_listeners_items(
+        &self,
+        input: DescribeListenersInput,
+    ) -> rusoto_core::pagination::RusotoStream<Listener, DescribeListenersError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_listeners_pages(input),
+            |output: DescribeListenersOutput| output.listeners.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_load_balancers`, streaming every page of results.
+    pub fn describe_load_balancers_pages(
+        &self,
+        input: DescribeLoadBalancersInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeLoadBalancersOutput,
+        DescribeLoadBalancersError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ElbClient, input: DescribeLoadBalancersInput| async move {
+                client.describe_load_balancers(input).await
+            },
+            |input: &DescribeLoadBalancersInput, output: &DescribeLoadBalancersOutput| {
+                Some(DescribeLoadBalancersInput {
+                    marker: Some(output.next_marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_load_balancers`, streaming the `LoadBalancers` of every page of results.
+    pub fn describe_load_balancers_items(
+        &self,
+        input: DescribeLoadBalancersInput,
+    ) -> rusoto_core::pagination::RusotoStream<LoadBalancer, DescribeLoadBalancersError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_load_balancers_pages(input),
+            |output: DescribeLoadBalancersOutput| output.load_balancers.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_rules`, streaming every page of results.
+    pub fn describe_rules_pages(
+        &self,
+        input: DescribeRulesInput,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeRulesOutput, DescribeRulesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ElbClient, input: DescribeRulesInput| async move {
+                client.describe_rules(input).await
+            },
+            |input: &DescribeRulesInput, output: &DescribeRulesOutput| {
+                Some(DescribeRulesInput {
+                    marker: Some(output.next_marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_rules`, streaming the `Rules` of every page of results.
+    pub fn describe_rules_items(
+        &self,
+        input: DescribeRulesInput,
+    ) -> rusoto_core::pagination::RusotoStream<Rule, DescribeRulesError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_rules_pages(input),
+            |output: DescribeRulesOutput| output.rules.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_ssl_policies`, streaming every page of results.
+    pub fn describe_ssl_policies_pages(
+        &self,
+        input: DescribeSSLPoliciesInput,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeSSLPoliciesOutput, DescribeSSLPoliciesError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ElbClient, input: DescribeSSLPoliciesInput| async move {
+                client.describe_ssl_policies(input).await
+            },
+            |input: &DescribeSSLPoliciesInput, output: &DescribeSSLPoliciesOutput| {
+                Some(DescribeSSLPoliciesInput {
+                    marker: Some(output.next_marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_ssl_policies`, streaming the `SslPolicies` of every page of results.
+    pub fn describe_ssl_policies_items(
+        &self,
+        input: DescribeSSLPoliciesInput,
+    ) -> rusoto_core::pagination::RusotoStream<SslPolicy, DescribeSSLPoliciesError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_ssl_policies_pages(input),
+            |output: DescribeSSLPoliciesOutput| output.ssl_policies.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_target_groups`, streaming every page of results.
+    pub fn describe_target_groups_pages(
+        &self,
+        input: DescribeTargetGroupsInput,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeTargetGroupsOutput, DescribeTargetGroupsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ElbClient, input: DescribeTargetGroupsInput| async move {
+                client.describe_target_groups(input).await
+            },
+            |input: &DescribeTargetGroupsInput, output: &DescribeTargetGroupsOutput| {
+                Some(DescribeTargetGroupsInput {
+                    marker: Some(output.next_marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_target_groups`, streaming the `TargetGroups` of every page of results.
+    pub fn describe_target_groups_items(
+        &self,
+        input: DescribeTargetGroupsInput,
+    ) -> rusoto_core::pagination::RusotoStream<TargetGroup, DescribeTargetGroupsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_target_groups_pages(input),
+            |output: DescribeTargetGroupsOutput| output.target_groups.unwrap_or_default(),
+        )
+    }
+}
+impl ElbClient {
+    /// Waits until `describe_load_balancers` reports the `LoadBalancerAvailable` state, polling every 15 seconds up to 40 times.
+    pub async fn wait_until_load_balancer_available(
+        &self,
+        input: DescribeLoadBalancersInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeLoadBalancersError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(15), 40);
+        self.wait_until_load_balancer_available_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_load_balancer_available`, polling as often and as many times as `config` says.
+    pub async fn wait_until_load_balancer_available_with_config(
+        &self,
+        input: DescribeLoadBalancersInput,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeLoadBalancersError>> {
+        rusoto_core::waiter::wait(
+            "LoadBalancerAvailable",
+            &config,
+            || self.describe_load_balancers(input.clone()),
+            |result: &Result<
+                DescribeLoadBalancersOutput,
+                RusotoError<DescribeLoadBalancersError>,
+            >| match result {
+                Ok(output)
+                    if rusoto_core::waiter::all_match(
+                        output
+                            .load_balancers
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.state.iter())
+                            .flat_map(|v| v.code.iter()),
+                        |v| *v == LoadBalancerStateEnum::Active,
+                    ) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if output
+                        .load_balancers
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.state.iter())
+                        .flat_map(|v| v.code.iter())
+                        .any(|v| *v == LoadBalancerStateEnum::Provisioning) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Retry)
+                }
+                Err(RusotoError::Service(DescribeLoadBalancersError::LoadBalancerNotFound(_))) => {
+                    Some(rusoto_core::waiter::WaiterState::Retry)
+                }
+                Err(RusotoError::Unknown(response))
+                    if rusoto_core::retry::error_code(response).as_deref()
+                        == Some("LoadBalancerNotFound") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Retry)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+
+    /// Waits until `describe_load_balancers` reports the `LoadBalancerExists` state, polling every 15 seconds up to 40 times.
+    pub async fn wait_until_load_balancer_exists(
+        &self,
+        input: DescribeLoadBalancersInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeLoadBalancersError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(15), 40);
+        self.wait_until_load_balancer_exists_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_load_balancer_exists`, polling as often and as many times as `config` says.
+    pub async fn wait_until_load_balancer_exists_with_config(
+        &self,
+        input: DescribeLoadBalancersInput,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeLoadBalancersError>> {
+        rusoto_core::waiter::wait(
+            "LoadBalancerExists",
+            &config,
+            || self.describe_load_balancers(input.clone()),
+            |result: &Result<
+                DescribeLoadBalancersOutput,
+                RusotoError<DescribeLoadBalancersError>,
+            >| match result {
+                Ok(_) => Some(rusoto_core::waiter::WaiterState::Success),
+                Err(RusotoError::Service(DescribeLoadBalancersError::LoadBalancerNotFound(_))) => {
+                    Some(rusoto_core::waiter::WaiterState::Retry)
+                }
+                Err(RusotoError::Unknown(response))
+                    if rusoto_core::retry::error_code(response).as_deref()
+                        == Some("LoadBalancerNotFound") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Retry)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+
+    /// Waits until `describe_load_balancers` reports the `LoadBalancersDeleted` state, polling every 15 seconds up to 40 times.
+    pub async fn wait_until_load_balancers_deleted(
+        &self,
+        input: DescribeLoadBalancersInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeLoadBalancersError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(15), 40);
+        self.wait_until_load_balancers_deleted_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_load_balancers_deleted`, polling as often and as many times as `config` says.
+    pub async fn wait_until_load_balancers_deleted_with_config(
+        &self,
+        input: DescribeLoadBalancersInput,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeLoadBalancersError>> {
+        rusoto_core::waiter::wait(
+            "LoadBalancersDeleted",
+            &config,
+            || self.describe_load_balancers(input.clone()),
+            |result: &Result<
+                DescribeLoadBalancersOutput,
+                RusotoError<DescribeLoadBalancersError>,
+            >| match result {
+                Ok(output)
+                    if rusoto_core::waiter::all_match(
+                        output
+                            .load_balancers
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.state.iter())
+                            .flat_map(|v| v.code.iter()),
+                        |v| *v == LoadBalancerStateEnum::Active,
+                    ) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Retry)
+                }
+                Err(RusotoError::Service(DescribeLoadBalancersError::LoadBalancerNotFound(_))) => {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Err(RusotoError::Unknown(response))
+                    if rusoto_core::retry::error_code(response).as_deref()
+                        == Some("LoadBalancerNotFound") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+
+    /// Waits until `describe_target_health` reports the `TargetDeregistered` state, polling every 15 seconds up to 40 times.
+    pub async fn wait_until_target_deregistered(
+        &self,
+        input: DescribeTargetHealthInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeTargetHealthError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(15), 40);
+        self.wait_until_target_deregistered_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_target_deregistered`, polling as often and as many times as `config` says.
+    pub async fn wait_until_target_deregistered_with_config(
+        &self,
+        input: DescribeTargetHealthInput,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeTargetHealthError>> {
+        rusoto_core::waiter::wait(
+            "TargetDeregistered",
+            &config,
+            || self.describe_target_health(input.clone()),
+            |result: &Result<
+                DescribeTargetHealthOutput,
+                RusotoError<DescribeTargetHealthError>,
+            >| match result {
+                Err(RusotoError::Service(DescribeTargetHealthError::InvalidTarget(_))) => {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Err(RusotoError::Unknown(response))
+                    if rusoto_core::retry::error_code(response).as_deref()
+                        == Some("InvalidTarget") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if rusoto_core::waiter::all_match(
+                        output
+                            .target_health_descriptions
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.target_health.iter())
+                            .flat_map(|v| v.state.iter()),
+                        |v| *v == TargetHealthStateEnum::Unused,
+                    ) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+
+    /// Waits until `describe_target_health` reports the `TargetInService` state, polling every 15 seconds up to 40 times.
+    pub async fn wait_until_target_in_service(
+        &self,
+        input: DescribeTargetHealthInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeTargetHealthError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(15), 40);
+        self.wait_until_target_in_service_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_target_in_service`, polling as often and as many times as `config` says.
+    pub async fn wait_until_target_in_service_with_config(
+        &self,
+        input: DescribeTargetHealthInput,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeTargetHealthError>> {
+        rusoto_core::waiter::wait(
+            "TargetInService",
+            &config,
+            || self.describe_target_health(input.clone()),
+            |result: &Result<
+                DescribeTargetHealthOutput,
+                RusotoError<DescribeTargetHealthError>,
+            >| match result {
+                Ok(output)
+                    if rusoto_core::waiter::all_match(
+                        output
+                            .target_health_descriptions
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.target_health.iter())
+                            .flat_map(|v| v.state.iter()),
+                        |v| *v == TargetHealthStateEnum::Healthy,
+                    ) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Err(RusotoError::Unknown(response))
+                    if rusoto_core::retry::error_code(response).as_deref()
+                        == Some("InvalidInstance") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Retry)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+}
 
 #[cfg(test)]
 mod protocol_tests {
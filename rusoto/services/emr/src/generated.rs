@@ -24,6 +24,109 @@ use rusoto_core::signature::SignedRequest;
 #[allow(unused_imports)]
 use serde::{Deserialize, Serialize};
 use serde_json;
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ActionOnFailure {
+    /// `TERMINATE_JOB_FLOW`
+    TerminateJobFlow,
+    /// `TERMINATE_CLUSTER`
+    TerminateCluster,
+    /// `CANCEL_AND_WAIT`
+    CancelAndWait,
+    /// `CONTINUE`
+    Continue,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ActionOnFailure {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ActionOnFailure::TerminateJobFlow => "TERMINATE_JOB_FLOW",
+            ActionOnFailure::TerminateCluster => "TERMINATE_CLUSTER",
+            ActionOnFailure::CancelAndWait => "CANCEL_AND_WAIT",
+            ActionOnFailure::Continue => "CONTINUE",
+            ActionOnFailure::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ActionOnFailure {
+    fn default() -> ActionOnFailure {
+        ActionOnFailure::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ActionOnFailure {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ActionOnFailure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ActionOnFailure {
+    fn from(value: &'a str) -> ActionOnFailure {
+        match value {
+            "TERMINATE_JOB_FLOW" => ActionOnFailure::TerminateJobFlow,
+            "TERMINATE_CLUSTER" => ActionOnFailure::TerminateCluster,
+            "CANCEL_AND_WAIT" => ActionOnFailure::CancelAndWait,
+            "CONTINUE" => ActionOnFailure::Continue,
+            _ => ActionOnFailure::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ActionOnFailure {
+    fn from(value: String) -> ActionOnFailure {
+        match value.as_str() {
+            "TERMINATE_JOB_FLOW" => ActionOnFailure::TerminateJobFlow,
+            "TERMINATE_CLUSTER" => ActionOnFailure::TerminateCluster,
+            "CANCEL_AND_WAIT" => ActionOnFailure::CancelAndWait,
+            "CONTINUE" => ActionOnFailure::Continue,
+            _ => ActionOnFailure::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ActionOnFailure {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ActionOnFailure, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ActionOnFailure {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ActionOnFailure {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ActionOnFailure {
+    fn deserialize<D>(deserializer: D) -> Result<ActionOnFailure, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct AddInstanceFleetInput {
@@ -121,6 +224,104 @@ pub struct AddTagsInput {
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AddTagsOutput {}
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AdjustmentType {
+    /// `CHANGE_IN_CAPACITY`
+    ChangeInCapacity,
+    /// `PERCENT_CHANGE_IN_CAPACITY`
+    PercentChangeInCapacity,
+    /// `EXACT_CAPACITY`
+    ExactCapacity,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AdjustmentType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AdjustmentType::ChangeInCapacity => "CHANGE_IN_CAPACITY",
+            AdjustmentType::PercentChangeInCapacity => "PERCENT_CHANGE_IN_CAPACITY",
+            AdjustmentType::ExactCapacity => "EXACT_CAPACITY",
+            AdjustmentType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AdjustmentType {
+    fn default() -> AdjustmentType {
+        AdjustmentType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AdjustmentType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AdjustmentType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AdjustmentType {
+    fn from(value: &'a str) -> AdjustmentType {
+        match value {
+            "CHANGE_IN_CAPACITY" => AdjustmentType::ChangeInCapacity,
+            "PERCENT_CHANGE_IN_CAPACITY" => AdjustmentType::PercentChangeInCapacity,
+            "EXACT_CAPACITY" => AdjustmentType::ExactCapacity,
+            _ => AdjustmentType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AdjustmentType {
+    fn from(value: String) -> AdjustmentType {
+        match value.as_str() {
+            "CHANGE_IN_CAPACITY" => AdjustmentType::ChangeInCapacity,
+            "PERCENT_CHANGE_IN_CAPACITY" => AdjustmentType::PercentChangeInCapacity,
+            "EXACT_CAPACITY" => AdjustmentType::ExactCapacity,
+            _ => AdjustmentType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AdjustmentType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AdjustmentType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AdjustmentType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AdjustmentType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AdjustmentType {
+    fn deserialize<D>(deserializer: D) -> Result<AdjustmentType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>With Amazon EMR release version 4.0 and later, the only accepted parameter is the application name. To pass arguments to applications, you use configuration classifications specified using configuration JSON objects. For more information, see <a href="https://docs.aws.amazon.com/emr/latest/ReleaseGuide/emr-configure-apps.html">Configuring Applications</a>.</p> <p>With earlier Amazon EMR releases, the application is any Amazon or third-party software that you can add to the cluster. This structure contains a list of strings that indicates the software to use with the cluster and accepts a user argument list. Amazon EMR accepts and forwards the argument list to the corresponding installation script as bootstrap action argument.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Application {
@@ -172,6 +373,119 @@ pub struct AutoScalingPolicyDescription {
     pub status: Option<AutoScalingPolicyStatus>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AutoScalingPolicyState {
+    /// `PENDING`
+    Pending,
+    /// `ATTACHING`
+    Attaching,
+    /// `ATTACHED`
+    Attached,
+    /// `DETACHING`
+    Detaching,
+    /// `DETACHED`
+    Detached,
+    /// `FAILED`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AutoScalingPolicyState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AutoScalingPolicyState::Pending => "PENDING",
+            AutoScalingPolicyState::Attaching => "ATTACHING",
+            AutoScalingPolicyState::Attached => "ATTACHED",
+            AutoScalingPolicyState::Detaching => "DETACHING",
+            AutoScalingPolicyState::Detached => "DETACHED",
+            AutoScalingPolicyState::Failed => "FAILED",
+            AutoScalingPolicyState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AutoScalingPolicyState {
+    fn default() -> AutoScalingPolicyState {
+        AutoScalingPolicyState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AutoScalingPolicyState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AutoScalingPolicyState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AutoScalingPolicyState {
+    fn from(value: &'a str) -> AutoScalingPolicyState {
+        match value {
+            "PENDING" => AutoScalingPolicyState::Pending,
+            "ATTACHING" => AutoScalingPolicyState::Attaching,
+            "ATTACHED" => AutoScalingPolicyState::Attached,
+            "DETACHING" => AutoScalingPolicyState::Detaching,
+            "DETACHED" => AutoScalingPolicyState::Detached,
+            "FAILED" => AutoScalingPolicyState::Failed,
+            _ => AutoScalingPolicyState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AutoScalingPolicyState {
+    fn from(value: String) -> AutoScalingPolicyState {
+        match value.as_str() {
+            "PENDING" => AutoScalingPolicyState::Pending,
+            "ATTACHING" => AutoScalingPolicyState::Attaching,
+            "ATTACHED" => AutoScalingPolicyState::Attached,
+            "DETACHING" => AutoScalingPolicyState::Detaching,
+            "DETACHED" => AutoScalingPolicyState::Detached,
+            "FAILED" => AutoScalingPolicyState::Failed,
+            _ => AutoScalingPolicyState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AutoScalingPolicyState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AutoScalingPolicyState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AutoScalingPolicyState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AutoScalingPolicyState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AutoScalingPolicyState {
+    fn deserialize<D>(deserializer: D) -> Result<AutoScalingPolicyState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The reason for an <a>AutoScalingPolicyStatus</a> change.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -179,13 +493,111 @@ pub struct AutoScalingPolicyStateChangeReason {
     /// <p>The code indicating the reason for the change in status.<code>USER_REQUEST</code> indicates that the scaling policy status was changed by a user. <code>PROVISION_FAILURE</code> indicates that the status change was because the policy failed to provision. <code>CLEANUP_FAILURE</code> indicates an error.</p>
     #[serde(rename = "Code")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub code: Option<String>,
+    pub code: Option<AutoScalingPolicyStateChangeReasonCode>,
     /// <p>A friendly, more verbose message that accompanies an automatic scaling policy state change.</p>
     #[serde(rename = "Message")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub message: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AutoScalingPolicyStateChangeReasonCode {
+    /// `USER_REQUEST`
+    UserRequest,
+    /// `PROVISION_FAILURE`
+    ProvisionFailure,
+    /// `CLEANUP_FAILURE`
+    CleanupFailure,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AutoScalingPolicyStateChangeReasonCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AutoScalingPolicyStateChangeReasonCode::UserRequest => "USER_REQUEST",
+            AutoScalingPolicyStateChangeReasonCode::ProvisionFailure => "PROVISION_FAILURE",
+            AutoScalingPolicyStateChangeReasonCode::CleanupFailure => "CLEANUP_FAILURE",
+            AutoScalingPolicyStateChangeReasonCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AutoScalingPolicyStateChangeReasonCode {
+    fn default() -> AutoScalingPolicyStateChangeReasonCode {
+        AutoScalingPolicyStateChangeReasonCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AutoScalingPolicyStateChangeReasonCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AutoScalingPolicyStateChangeReasonCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AutoScalingPolicyStateChangeReasonCode {
+    fn from(value: &'a str) -> AutoScalingPolicyStateChangeReasonCode {
+        match value {
+            "USER_REQUEST" => AutoScalingPolicyStateChangeReasonCode::UserRequest,
+            "PROVISION_FAILURE" => AutoScalingPolicyStateChangeReasonCode::ProvisionFailure,
+            "CLEANUP_FAILURE" => AutoScalingPolicyStateChangeReasonCode::CleanupFailure,
+            _ => AutoScalingPolicyStateChangeReasonCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AutoScalingPolicyStateChangeReasonCode {
+    fn from(value: String) -> AutoScalingPolicyStateChangeReasonCode {
+        match value.as_str() {
+            "USER_REQUEST" => AutoScalingPolicyStateChangeReasonCode::UserRequest,
+            "PROVISION_FAILURE" => AutoScalingPolicyStateChangeReasonCode::ProvisionFailure,
+            "CLEANUP_FAILURE" => AutoScalingPolicyStateChangeReasonCode::CleanupFailure,
+            _ => AutoScalingPolicyStateChangeReasonCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AutoScalingPolicyStateChangeReasonCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AutoScalingPolicyStateChangeReasonCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AutoScalingPolicyStateChangeReasonCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AutoScalingPolicyStateChangeReasonCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AutoScalingPolicyStateChangeReasonCode {
+    fn deserialize<D>(deserializer: D) -> Result<AutoScalingPolicyStateChangeReasonCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The status of an automatic scaling policy. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -193,7 +605,7 @@ pub struct AutoScalingPolicyStatus {
     /// <p>Indicates the status of the automatic scaling policy.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<AutoScalingPolicyState>,
     /// <p>The reason for a change in status.</p>
     #[serde(rename = "StateChangeReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -221,7 +633,12 @@ pub struct BlockPublicAccessConfigurationMetadata {
     pub created_by_arn: String,
     /// <p>The date and time that the configuration was created.</p>
     #[serde(rename = "CreationDateTime")]
-    pub creation_date_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub creation_date_time: ::chrono::DateTime<::chrono::Utc>,
 }
 
 /// <p>Configuration of a bootstrap action.</p>
@@ -256,7 +673,7 @@ pub struct CancelStepsInfo {
     /// <p>The status of a CancelSteps Request. The value may be SUBMITTED or FAILED.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<CancelStepsRequestStatus>,
     /// <p>The encrypted StepId of a step.</p>
     #[serde(rename = "StepId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -273,7 +690,7 @@ pub struct CancelStepsInput {
     /// <p>The option to choose for cancelling <code>RUNNING</code> steps. By default, the value is <code>SEND_INTERRUPT</code>.</p>
     #[serde(rename = "StepCancellationOption")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub step_cancellation_option: Option<String>,
+    pub step_cancellation_option: Option<StepCancellationOption>,
     /// <p>The list of <code>StepIDs</code> to cancel. Use <a>ListSteps</a> to get steps and their states for the specified cluster.</p>
     #[serde(rename = "StepIds")]
     pub step_ids: Vec<String>,
@@ -289,12 +706,105 @@ pub struct CancelStepsOutput {
     pub cancel_steps_info_list: Option<Vec<CancelStepsInfo>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CancelStepsRequestStatus {
+    /// `SUBMITTED`
+    Submitted,
+    /// `FAILED`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CancelStepsRequestStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CancelStepsRequestStatus::Submitted => "SUBMITTED",
+            CancelStepsRequestStatus::Failed => "FAILED",
+            CancelStepsRequestStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CancelStepsRequestStatus {
+    fn default() -> CancelStepsRequestStatus {
+        CancelStepsRequestStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CancelStepsRequestStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CancelStepsRequestStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CancelStepsRequestStatus {
+    fn from(value: &'a str) -> CancelStepsRequestStatus {
+        match value {
+            "SUBMITTED" => CancelStepsRequestStatus::Submitted,
+            "FAILED" => CancelStepsRequestStatus::Failed,
+            _ => CancelStepsRequestStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CancelStepsRequestStatus {
+    fn from(value: String) -> CancelStepsRequestStatus {
+        match value.as_str() {
+            "SUBMITTED" => CancelStepsRequestStatus::Submitted,
+            "FAILED" => CancelStepsRequestStatus::Failed,
+            _ => CancelStepsRequestStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CancelStepsRequestStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CancelStepsRequestStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CancelStepsRequestStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CancelStepsRequestStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CancelStepsRequestStatus {
+    fn deserialize<D>(deserializer: D) -> Result<CancelStepsRequestStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The definition of a CloudWatch metric alarm, which determines when an automatic scaling activity is triggered. When the defined alarm conditions are satisfied, scaling activity begins.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CloudWatchAlarmDefinition {
     /// <p>Determines how the metric specified by <code>MetricName</code> is compared to the value specified by <code>Threshold</code>.</p>
     #[serde(rename = "ComparisonOperator")]
-    pub comparison_operator: String,
+    pub comparison_operator: ComparisonOperator,
     /// <p>A CloudWatch metric dimension.</p>
     #[serde(rename = "Dimensions")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -316,14 +826,14 @@ pub struct CloudWatchAlarmDefinition {
     /// <p>The statistic to apply to the metric associated with the alarm. The default is <code>AVERAGE</code>.</p>
     #[serde(rename = "Statistic")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub statistic: Option<String>,
+    pub statistic: Option<Statistic>,
     /// <p>The value against which the specified statistic is compared.</p>
     #[serde(rename = "Threshold")]
     pub threshold: f64,
     /// <p>The unit of measure associated with the CloudWatch metric being watched. The value specified for <code>Unit</code> must correspond to the units specified in the CloudWatch metric.</p>
     #[serde(rename = "Unit")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub unit: Option<String>,
+    pub unit: Option<Unit>,
 }
 
 /// <p>The detailed description of the cluster.</p>
@@ -369,7 +879,7 @@ pub struct Cluster {
     /// <p><note> <p>The instance fleet configuration is available only in Amazon EMR versions 4.8.0 and later, excluding 5.0.x versions.</p> </note> <p>The instance group configuration of the cluster. A value of <code>INSTANCE<em>GROUP</code> indicates a uniform instance group configuration. A value of <code>INSTANCE</em>FLEET</code> indicates an instance fleets configuration.</p></p>
     #[serde(rename = "InstanceCollectionType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub instance_collection_type: Option<String>,
+    pub instance_collection_type: Option<InstanceCollectionType>,
     /// <p>Attributes for Kerberos configuration when Kerberos authentication is enabled using a security configuration. For more information see <a href="https://docs.aws.amazon.com/emr/latest/ManagementGuide/emr-kerberos.html">Use Kerberos Authentication</a> in the <i>EMR Management Guide</i>.</p>
     #[serde(rename = "KerberosAttributes")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -401,7 +911,7 @@ pub struct Cluster {
     /// <p>Applies only when <code>CustomAmiID</code> is used. Specifies the type of updates that are applied from the Amazon Linux AMI package repositories when an instance boots using the AMI.</p>
     #[serde(rename = "RepoUpgradeOnBoot")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub repo_upgrade_on_boot: Option<String>,
+    pub repo_upgrade_on_boot: Option<RepoUpgradeOnBoot>,
     /// <p>The AMI version requested for this cluster.</p>
     #[serde(rename = "RequestedAmiVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -413,7 +923,7 @@ pub struct Cluster {
     /// <p>The way that individual Amazon EC2 instances terminate when an automatic scale-in activity occurs or an instance group is resized. <code>TERMINATE_AT_INSTANCE_HOUR</code> indicates that Amazon EMR terminates nodes at the instance-hour boundary, regardless of when the request to terminate the instance was submitted. This option is only available with Amazon EMR 5.1.0 and later and is the default for clusters created using that version. <code>TERMINATE_AT_TASK_COMPLETION</code> indicates that Amazon EMR blacklists and drains tasks from nodes before terminating the Amazon EC2 instances, regardless of the instance-hour boundary. With either behavior, Amazon EMR removes the least active nodes first and blocks instance termination if it could lead to HDFS corruption. <code>TERMINATE_AT_TASK_COMPLETION</code> is available only in Amazon EMR version 4.1.0 and later, and is the default for versions of Amazon EMR earlier than 5.1.0.</p>
     #[serde(rename = "ScaleDownBehavior")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scale_down_behavior: Option<String>,
+    pub scale_down_behavior: Option<ScaleDownBehavior>,
     /// <p>The name of the security configuration applied to the cluster.</p>
     #[serde(rename = "SecurityConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -444,6 +954,124 @@ pub struct Cluster {
     pub visible_to_all_users: Option<bool>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ClusterState {
+    /// `STARTING`
+    Starting,
+    /// `BOOTSTRAPPING`
+    Bootstrapping,
+    /// `RUNNING`
+    Running,
+    /// `WAITING`
+    Waiting,
+    /// `TERMINATING`
+    Terminating,
+    /// `TERMINATED`
+    Terminated,
+    /// `TERMINATED_WITH_ERRORS`
+    TerminatedWithErrors,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ClusterState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ClusterState::Starting => "STARTING",
+            ClusterState::Bootstrapping => "BOOTSTRAPPING",
+            ClusterState::Running => "RUNNING",
+            ClusterState::Waiting => "WAITING",
+            ClusterState::Terminating => "TERMINATING",
+            ClusterState::Terminated => "TERMINATED",
+            ClusterState::TerminatedWithErrors => "TERMINATED_WITH_ERRORS",
+            ClusterState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ClusterState {
+    fn default() -> ClusterState {
+        ClusterState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ClusterState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ClusterState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ClusterState {
+    fn from(value: &'a str) -> ClusterState {
+        match value {
+            "STARTING" => ClusterState::Starting,
+            "BOOTSTRAPPING" => ClusterState::Bootstrapping,
+            "RUNNING" => ClusterState::Running,
+            "WAITING" => ClusterState::Waiting,
+            "TERMINATING" => ClusterState::Terminating,
+            "TERMINATED" => ClusterState::Terminated,
+            "TERMINATED_WITH_ERRORS" => ClusterState::TerminatedWithErrors,
+            _ => ClusterState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ClusterState {
+    fn from(value: String) -> ClusterState {
+        match value.as_str() {
+            "STARTING" => ClusterState::Starting,
+            "BOOTSTRAPPING" => ClusterState::Bootstrapping,
+            "RUNNING" => ClusterState::Running,
+            "WAITING" => ClusterState::Waiting,
+            "TERMINATING" => ClusterState::Terminating,
+            "TERMINATED" => ClusterState::Terminated,
+            "TERMINATED_WITH_ERRORS" => ClusterState::TerminatedWithErrors,
+            _ => ClusterState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ClusterState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ClusterState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ClusterState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ClusterState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ClusterState {
+    fn deserialize<D>(deserializer: D) -> Result<ClusterState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The reason that the cluster changed to its current state.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -451,13 +1079,136 @@ pub struct ClusterStateChangeReason {
     /// <p>The programmatic code for the state change reason.</p>
     #[serde(rename = "Code")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub code: Option<String>,
+    pub code: Option<ClusterStateChangeReasonCode>,
     /// <p>The descriptive message for the state change reason.</p>
     #[serde(rename = "Message")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub message: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ClusterStateChangeReasonCode {
+    /// `INTERNAL_ERROR`
+    InternalError,
+    /// `VALIDATION_ERROR`
+    ValidationError,
+    /// `INSTANCE_FAILURE`
+    InstanceFailure,
+    /// `INSTANCE_FLEET_TIMEOUT`
+    InstanceFleetTimeout,
+    /// `BOOTSTRAP_FAILURE`
+    BootstrapFailure,
+    /// `USER_REQUEST`
+    UserRequest,
+    /// `STEP_FAILURE`
+    StepFailure,
+    /// `ALL_STEPS_COMPLETED`
+    AllStepsCompleted,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ClusterStateChangeReasonCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ClusterStateChangeReasonCode::InternalError => "INTERNAL_ERROR",
+            ClusterStateChangeReasonCode::ValidationError => "VALIDATION_ERROR",
+            ClusterStateChangeReasonCode::InstanceFailure => "INSTANCE_FAILURE",
+            ClusterStateChangeReasonCode::InstanceFleetTimeout => "INSTANCE_FLEET_TIMEOUT",
+            ClusterStateChangeReasonCode::BootstrapFailure => "BOOTSTRAP_FAILURE",
+            ClusterStateChangeReasonCode::UserRequest => "USER_REQUEST",
+            ClusterStateChangeReasonCode::StepFailure => "STEP_FAILURE",
+            ClusterStateChangeReasonCode::AllStepsCompleted => "ALL_STEPS_COMPLETED",
+            ClusterStateChangeReasonCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ClusterStateChangeReasonCode {
+    fn default() -> ClusterStateChangeReasonCode {
+        ClusterStateChangeReasonCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ClusterStateChangeReasonCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ClusterStateChangeReasonCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ClusterStateChangeReasonCode {
+    fn from(value: &'a str) -> ClusterStateChangeReasonCode {
+        match value {
+            "INTERNAL_ERROR" => ClusterStateChangeReasonCode::InternalError,
+            "VALIDATION_ERROR" => ClusterStateChangeReasonCode::ValidationError,
+            "INSTANCE_FAILURE" => ClusterStateChangeReasonCode::InstanceFailure,
+            "INSTANCE_FLEET_TIMEOUT" => ClusterStateChangeReasonCode::InstanceFleetTimeout,
+            "BOOTSTRAP_FAILURE" => ClusterStateChangeReasonCode::BootstrapFailure,
+            "USER_REQUEST" => ClusterStateChangeReasonCode::UserRequest,
+            "STEP_FAILURE" => ClusterStateChangeReasonCode::StepFailure,
+            "ALL_STEPS_COMPLETED" => ClusterStateChangeReasonCode::AllStepsCompleted,
+            _ => ClusterStateChangeReasonCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ClusterStateChangeReasonCode {
+    fn from(value: String) -> ClusterStateChangeReasonCode {
+        match value.as_str() {
+            "INTERNAL_ERROR" => ClusterStateChangeReasonCode::InternalError,
+            "VALIDATION_ERROR" => ClusterStateChangeReasonCode::ValidationError,
+            "INSTANCE_FAILURE" => ClusterStateChangeReasonCode::InstanceFailure,
+            "INSTANCE_FLEET_TIMEOUT" => ClusterStateChangeReasonCode::InstanceFleetTimeout,
+            "BOOTSTRAP_FAILURE" => ClusterStateChangeReasonCode::BootstrapFailure,
+            "USER_REQUEST" => ClusterStateChangeReasonCode::UserRequest,
+            "STEP_FAILURE" => ClusterStateChangeReasonCode::StepFailure,
+            "ALL_STEPS_COMPLETED" => ClusterStateChangeReasonCode::AllStepsCompleted,
+            _ => ClusterStateChangeReasonCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ClusterStateChangeReasonCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ClusterStateChangeReasonCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ClusterStateChangeReasonCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ClusterStateChangeReasonCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ClusterStateChangeReasonCode {
+    fn deserialize<D>(deserializer: D) -> Result<ClusterStateChangeReasonCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The detailed status of the cluster.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -465,7 +1216,7 @@ pub struct ClusterStatus {
     /// <p>The current state of the cluster.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<ClusterState>,
     /// <p>The reason for the cluster status change.</p>
     #[serde(rename = "StateChangeReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -512,36 +1263,154 @@ pub struct ClusterSummary {
 pub struct ClusterTimeline {
     /// <p>The creation date and time of the cluster.</p>
     #[serde(rename = "CreationDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date_time: Option<f64>,
+    pub creation_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The date and time when the cluster was terminated.</p>
     #[serde(rename = "EndDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_date_time: Option<f64>,
+    pub end_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The date and time when the cluster was ready to execute steps.</p>
     #[serde(rename = "ReadyDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ready_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
+}
+
+/// <p>An entity describing an executable that runs on a cluster.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct Command {
+    /// <p>Arguments for Amazon EMR to pass to the command for execution.</p>
+    #[serde(rename = "Args")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub args: Option<Vec<String>>,
+    /// <p>The name of the command.</p>
+    #[serde(rename = "Name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// <p>The Amazon S3 location of the command script.</p>
+    #[serde(rename = "ScriptPath")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ready_date_time: Option<f64>,
+    pub script_path: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ComparisonOperator {
+    /// `GREATER_THAN_OR_EQUAL`
+    GreaterThanOrEqual,
+    /// `GREATER_THAN`
+    GreaterThan,
+    /// `LESS_THAN`
+    LessThan,
+    /// `LESS_THAN_OR_EQUAL`
+    LessThanOrEqual,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ComparisonOperator {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ComparisonOperator::GreaterThanOrEqual => "GREATER_THAN_OR_EQUAL",
+            ComparisonOperator::GreaterThan => "GREATER_THAN",
+            ComparisonOperator::LessThan => "LESS_THAN",
+            ComparisonOperator::LessThanOrEqual => "LESS_THAN_OR_EQUAL",
+            ComparisonOperator::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ComparisonOperator {
+    fn default() -> ComparisonOperator {
+        ComparisonOperator::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ComparisonOperator {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ComparisonOperator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ComparisonOperator {
+    fn from(value: &'a str) -> ComparisonOperator {
+        match value {
+            "GREATER_THAN_OR_EQUAL" => ComparisonOperator::GreaterThanOrEqual,
+            "GREATER_THAN" => ComparisonOperator::GreaterThan,
+            "LESS_THAN" => ComparisonOperator::LessThan,
+            "LESS_THAN_OR_EQUAL" => ComparisonOperator::LessThanOrEqual,
+            _ => ComparisonOperator::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ComparisonOperator {
+    fn from(value: String) -> ComparisonOperator {
+        match value.as_str() {
+            "GREATER_THAN_OR_EQUAL" => ComparisonOperator::GreaterThanOrEqual,
+            "GREATER_THAN" => ComparisonOperator::GreaterThan,
+            "LESS_THAN" => ComparisonOperator::LessThan,
+            "LESS_THAN_OR_EQUAL" => ComparisonOperator::LessThanOrEqual,
+            _ => ComparisonOperator::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ComparisonOperator {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ComparisonOperator, Self::Err> {
+        Ok(value.into())
+    }
 }
 
-/// <p>An entity describing an executable that runs on a cluster.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct Command {
-    /// <p>Arguments for Amazon EMR to pass to the command for execution.</p>
-    #[serde(rename = "Args")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub args: Option<Vec<String>>,
-    /// <p>The name of the command.</p>
-    #[serde(rename = "Name")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
-    /// <p>The Amazon S3 location of the command script.</p>
-    #[serde(rename = "ScriptPath")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub script_path: Option<String>,
+impl ::rusoto_core::param::ToParam for ComparisonOperator {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ComparisonOperator {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for ComparisonOperator {
+    fn deserialize<D>(deserializer: D) -> Result<ComparisonOperator, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p><note> <p>Amazon EMR releases 4.x or later.</p> </note> <p>An optional configuration specification to be used when provisioning cluster instances, which can include configurations for applications and software bundled with Amazon EMR. A configuration consists of a classification, properties, and optional nested configurations. A classification refers to an application-specific configuration file. Properties are the settings you want to change in that file. For more information, see <a href="https://docs.aws.amazon.com/emr/latest/ReleaseGuide/emr-configure-apps.html">Configuring Applications</a>.</p></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Configuration {
@@ -575,7 +1444,12 @@ pub struct CreateSecurityConfigurationInput {
 pub struct CreateSecurityConfigurationOutput {
     /// <p>The date and time the security configuration was created.</p>
     #[serde(rename = "CreationDateTime")]
-    pub creation_date_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub creation_date_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The name of the security configuration.</p>
     #[serde(rename = "Name")]
     pub name: String,
@@ -618,12 +1492,22 @@ pub struct DescribeClusterOutput {
 pub struct DescribeJobFlowsInput {
     /// <p>Return only job flows created after this date and time.</p>
     #[serde(rename = "CreatedAfter")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_after: Option<f64>,
+    pub created_after: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Return only job flows created before this date and time.</p>
     #[serde(rename = "CreatedBefore")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_before: Option<f64>,
+    pub created_before: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Return only job flows whose job flow ID is contained in this list.</p>
     #[serde(rename = "JobFlowIds")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -631,7 +1515,7 @@ pub struct DescribeJobFlowsInput {
     /// <p>Return only job flows whose state is contained in this list.</p>
     #[serde(rename = "JobFlowStates")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_flow_states: Option<Vec<String>>,
+    pub job_flow_states: Option<Vec<JobFlowExecutionState>>,
 }
 
 /// <p> The output for the <a>DescribeJobFlows</a> operation. </p>
@@ -657,8 +1541,13 @@ pub struct DescribeSecurityConfigurationInput {
 pub struct DescribeSecurityConfigurationOutput {
     /// <p>The date and time the security configuration was created</p>
     #[serde(rename = "CreationDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date_time: Option<f64>,
+    pub creation_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the security configuration.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -902,7 +1791,7 @@ pub struct Instance {
     /// <p>The instance purchasing option. Valid values are <code>ON_DEMAND</code> or <code>SPOT</code>. </p>
     #[serde(rename = "Market")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub market: Option<String>,
+    pub market: Option<MarketType>,
     /// <p>The private DNS name of the instance.</p>
     #[serde(rename = "PrivateDnsName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -925,6 +1814,99 @@ pub struct Instance {
     pub status: Option<InstanceStatus>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum InstanceCollectionType {
+    /// `INSTANCE_FLEET`
+    InstanceFleet,
+    /// `INSTANCE_GROUP`
+    InstanceGroup,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl InstanceCollectionType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InstanceCollectionType::InstanceFleet => "INSTANCE_FLEET",
+            InstanceCollectionType::InstanceGroup => "INSTANCE_GROUP",
+            InstanceCollectionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for InstanceCollectionType {
+    fn default() -> InstanceCollectionType {
+        InstanceCollectionType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for InstanceCollectionType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for InstanceCollectionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for InstanceCollectionType {
+    fn from(value: &'a str) -> InstanceCollectionType {
+        match value {
+            "INSTANCE_FLEET" => InstanceCollectionType::InstanceFleet,
+            "INSTANCE_GROUP" => InstanceCollectionType::InstanceGroup,
+            _ => InstanceCollectionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InstanceCollectionType {
+    fn from(value: String) -> InstanceCollectionType {
+        match value.as_str() {
+            "INSTANCE_FLEET" => InstanceCollectionType::InstanceFleet,
+            "INSTANCE_GROUP" => InstanceCollectionType::InstanceGroup,
+            _ => InstanceCollectionType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for InstanceCollectionType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<InstanceCollectionType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for InstanceCollectionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for InstanceCollectionType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InstanceCollectionType {
+    fn deserialize<D>(deserializer: D) -> Result<InstanceCollectionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p><p>Describes an instance fleet, which is a group of EC2 instances that host a particular node type (master, core, or task) in an Amazon EMR cluster. Instance fleets can consist of a mix of instance types and On-Demand and Spot instances, which are provisioned to meet a defined target capacity. </p> <note> <p>The instance fleet configuration is available only in Amazon EMR versions 4.8.0 and later, excluding 5.0.x versions.</p> </note></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -936,7 +1918,7 @@ pub struct InstanceFleet {
     /// <p>The node type that the instance fleet hosts. Valid values are MASTER, CORE, or TASK. </p>
     #[serde(rename = "InstanceFleetType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub instance_fleet_type: Option<String>,
+    pub instance_fleet_type: Option<InstanceFleetType>,
     /// <p>The specification for the instance types that comprise an instance fleet. Up to five unique instance specifications may be defined for each instance fleet. </p>
     #[serde(rename = "InstanceTypeSpecifications")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -977,7 +1959,7 @@ pub struct InstanceFleet {
 pub struct InstanceFleetConfig {
     /// <p>The node type that the instance fleet hosts. Valid values are MASTER,CORE,and TASK.</p>
     #[serde(rename = "InstanceFleetType")]
-    pub instance_fleet_type: String,
+    pub instance_fleet_type: InstanceFleetType,
     /// <p>The instance type configurations that define the EC2 instances in the instance fleet.</p>
     #[serde(rename = "InstanceTypeConfigs")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1025,6 +2007,124 @@ pub struct InstanceFleetProvisioningSpecifications {
     pub spot_specification: SpotProvisioningSpecification,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum InstanceFleetState {
+    /// `PROVISIONING`
+    Provisioning,
+    /// `BOOTSTRAPPING`
+    Bootstrapping,
+    /// `RUNNING`
+    Running,
+    /// `RESIZING`
+    Resizing,
+    /// `SUSPENDED`
+    Suspended,
+    /// `TERMINATING`
+    Terminating,
+    /// `TERMINATED`
+    Terminated,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl InstanceFleetState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InstanceFleetState::Provisioning => "PROVISIONING",
+            InstanceFleetState::Bootstrapping => "BOOTSTRAPPING",
+            InstanceFleetState::Running => "RUNNING",
+            InstanceFleetState::Resizing => "RESIZING",
+            InstanceFleetState::Suspended => "SUSPENDED",
+            InstanceFleetState::Terminating => "TERMINATING",
+            InstanceFleetState::Terminated => "TERMINATED",
+            InstanceFleetState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for InstanceFleetState {
+    fn default() -> InstanceFleetState {
+        InstanceFleetState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for InstanceFleetState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for InstanceFleetState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for InstanceFleetState {
+    fn from(value: &'a str) -> InstanceFleetState {
+        match value {
+            "PROVISIONING" => InstanceFleetState::Provisioning,
+            "BOOTSTRAPPING" => InstanceFleetState::Bootstrapping,
+            "RUNNING" => InstanceFleetState::Running,
+            "RESIZING" => InstanceFleetState::Resizing,
+            "SUSPENDED" => InstanceFleetState::Suspended,
+            "TERMINATING" => InstanceFleetState::Terminating,
+            "TERMINATED" => InstanceFleetState::Terminated,
+            _ => InstanceFleetState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InstanceFleetState {
+    fn from(value: String) -> InstanceFleetState {
+        match value.as_str() {
+            "PROVISIONING" => InstanceFleetState::Provisioning,
+            "BOOTSTRAPPING" => InstanceFleetState::Bootstrapping,
+            "RUNNING" => InstanceFleetState::Running,
+            "RESIZING" => InstanceFleetState::Resizing,
+            "SUSPENDED" => InstanceFleetState::Suspended,
+            "TERMINATING" => InstanceFleetState::Terminating,
+            "TERMINATED" => InstanceFleetState::Terminated,
+            _ => InstanceFleetState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for InstanceFleetState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<InstanceFleetState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for InstanceFleetState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for InstanceFleetState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InstanceFleetState {
+    fn deserialize<D>(deserializer: D) -> Result<InstanceFleetState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p><p>Provides status change reason details for the instance fleet.</p> <note> <p>The instance fleet configuration is available only in Amazon EMR versions 4.8.0 and later, excluding 5.0.x versions.</p> </note></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1032,13 +2132,116 @@ pub struct InstanceFleetStateChangeReason {
     /// <p>A code corresponding to the reason the state change occurred.</p>
     #[serde(rename = "Code")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub code: Option<String>,
+    pub code: Option<InstanceFleetStateChangeReasonCode>,
     /// <p>An explanatory message.</p>
     #[serde(rename = "Message")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub message: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum InstanceFleetStateChangeReasonCode {
+    /// `INTERNAL_ERROR`
+    InternalError,
+    /// `VALIDATION_ERROR`
+    ValidationError,
+    /// `INSTANCE_FAILURE`
+    InstanceFailure,
+    /// `CLUSTER_TERMINATED`
+    ClusterTerminated,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl InstanceFleetStateChangeReasonCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InstanceFleetStateChangeReasonCode::InternalError => "INTERNAL_ERROR",
+            InstanceFleetStateChangeReasonCode::ValidationError => "VALIDATION_ERROR",
+            InstanceFleetStateChangeReasonCode::InstanceFailure => "INSTANCE_FAILURE",
+            InstanceFleetStateChangeReasonCode::ClusterTerminated => "CLUSTER_TERMINATED",
+            InstanceFleetStateChangeReasonCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for InstanceFleetStateChangeReasonCode {
+    fn default() -> InstanceFleetStateChangeReasonCode {
+        InstanceFleetStateChangeReasonCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for InstanceFleetStateChangeReasonCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for InstanceFleetStateChangeReasonCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for InstanceFleetStateChangeReasonCode {
+    fn from(value: &'a str) -> InstanceFleetStateChangeReasonCode {
+        match value {
+            "INTERNAL_ERROR" => InstanceFleetStateChangeReasonCode::InternalError,
+            "VALIDATION_ERROR" => InstanceFleetStateChangeReasonCode::ValidationError,
+            "INSTANCE_FAILURE" => InstanceFleetStateChangeReasonCode::InstanceFailure,
+            "CLUSTER_TERMINATED" => InstanceFleetStateChangeReasonCode::ClusterTerminated,
+            _ => InstanceFleetStateChangeReasonCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InstanceFleetStateChangeReasonCode {
+    fn from(value: String) -> InstanceFleetStateChangeReasonCode {
+        match value.as_str() {
+            "INTERNAL_ERROR" => InstanceFleetStateChangeReasonCode::InternalError,
+            "VALIDATION_ERROR" => InstanceFleetStateChangeReasonCode::ValidationError,
+            "INSTANCE_FAILURE" => InstanceFleetStateChangeReasonCode::InstanceFailure,
+            "CLUSTER_TERMINATED" => InstanceFleetStateChangeReasonCode::ClusterTerminated,
+            _ => InstanceFleetStateChangeReasonCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for InstanceFleetStateChangeReasonCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<InstanceFleetStateChangeReasonCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for InstanceFleetStateChangeReasonCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for InstanceFleetStateChangeReasonCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InstanceFleetStateChangeReasonCode {
+    fn deserialize<D>(deserializer: D) -> Result<InstanceFleetStateChangeReasonCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p><p>The status of the instance fleet.</p> <note> <p>The instance fleet configuration is available only in Amazon EMR versions 4.8.0 and later, excluding 5.0.x versions.</p> </note></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1046,7 +2249,7 @@ pub struct InstanceFleetStatus {
     /// <p><p>A code representing the instance fleet status.</p> <ul> <li> <p> <code>PROVISIONING</code>—The instance fleet is provisioning EC2 resources and is not yet ready to run jobs.</p> </li> <li> <p> <code>BOOTSTRAPPING</code>—EC2 instances and other resources have been provisioned and the bootstrap actions specified for the instances are underway.</p> </li> <li> <p> <code>RUNNING</code>—EC2 instances and other resources are running. They are either executing jobs or waiting to execute jobs.</p> </li> <li> <p> <code>RESIZING</code>—A resize operation is underway. EC2 instances are either being added or removed.</p> </li> <li> <p> <code>SUSPENDED</code>—A resize operation could not complete. Existing EC2 instances are running, but instances can&#39;t be added or removed.</p> </li> <li> <p> <code>TERMINATING</code>—The instance fleet is terminating EC2 instances.</p> </li> <li> <p> <code>TERMINATED</code>—The instance fleet is no longer active, and all EC2 instances have been terminated.</p> </li> </ul></p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<InstanceFleetState>,
     /// <p>Provides status change reason details for the instance fleet.</p>
     #[serde(rename = "StateChangeReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1063,18 +2266,131 @@ pub struct InstanceFleetStatus {
 pub struct InstanceFleetTimeline {
     /// <p>The time and date the instance fleet was created.</p>
     #[serde(rename = "CreationDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date_time: Option<f64>,
+    pub creation_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The time and date the instance fleet terminated.</p>
     #[serde(rename = "EndDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_date_time: Option<f64>,
+    pub end_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The time and date the instance fleet was ready to run jobs.</p>
     #[serde(rename = "ReadyDateTime")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub ready_date_time: Option<f64>,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ready_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum InstanceFleetType {
+    /// `MASTER`
+    Master,
+    /// `CORE`
+    Core,
+    /// `TASK`
+    Task,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl InstanceFleetType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InstanceFleetType::Master => "MASTER",
+            InstanceFleetType::Core => "CORE",
+            InstanceFleetType::Task => "TASK",
+            InstanceFleetType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for InstanceFleetType {
+    fn default() -> InstanceFleetType {
+        InstanceFleetType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for InstanceFleetType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for InstanceFleetType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for InstanceFleetType {
+    fn from(value: &'a str) -> InstanceFleetType {
+        match value {
+            "MASTER" => InstanceFleetType::Master,
+            "CORE" => InstanceFleetType::Core,
+            "TASK" => InstanceFleetType::Task,
+            _ => InstanceFleetType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InstanceFleetType {
+    fn from(value: String) -> InstanceFleetType {
+        match value.as_str() {
+            "MASTER" => InstanceFleetType::Master,
+            "CORE" => InstanceFleetType::Core,
+            "TASK" => InstanceFleetType::Task,
+            _ => InstanceFleetType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for InstanceFleetType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<InstanceFleetType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for InstanceFleetType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for InstanceFleetType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for InstanceFleetType {
+    fn deserialize<D>(deserializer: D) -> Result<InstanceFleetType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>This entity represents an instance group, which is a group of instances that have common purpose. For example, CORE instance group is used for HDFS.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1110,7 +2426,7 @@ pub struct InstanceGroup {
     /// <p>The type of the instance group. Valid values are MASTER, CORE or TASK.</p>
     #[serde(rename = "InstanceGroupType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub instance_group_type: Option<String>,
+    pub instance_group_type: Option<InstanceGroupType>,
     /// <p>The EC2 instance type for all instances in the instance group.</p>
     #[serde(rename = "InstanceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1126,7 +2442,7 @@ pub struct InstanceGroup {
     /// <p>The marketplace to provision instances for this group. Valid values are ON_DEMAND or SPOT.</p>
     #[serde(rename = "Market")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub market: Option<String>,
+    pub market: Option<MarketType>,
     /// <p>The name of the instance group.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1174,14 +2490,14 @@ pub struct InstanceGroupConfig {
     pub instance_count: i64,
     /// <p>The role of the instance group in the cluster.</p>
     #[serde(rename = "InstanceRole")]
-    pub instance_role: String,
+    pub instance_role: InstanceRoleType,
     /// <p>The EC2 instance type for all instances in the instance group.</p>
     #[serde(rename = "InstanceType")]
     pub instance_type: String,
     /// <p>Market type of the EC2 instances used to create a cluster node.</p>
     #[serde(rename = "Market")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub market: Option<String>,
+    pub market: Option<MarketType>,
     /// <p>Friendly name given to the instance group.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1198,11 +2514,21 @@ pub struct InstanceGroupDetail {
     pub bid_price: Option<String>,
     /// <p>The date/time the instance group was created.</p>
     #[serde(rename = "CreationDateTime")]
-    pub creation_date_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub creation_date_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The date/time the instance group was terminated.</p>
     #[serde(rename = "EndDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_date_time: Option<f64>,
+    pub end_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Unique identifier for the instance group.</p>
     #[serde(rename = "InstanceGroupId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1212,7 +2538,7 @@ pub struct InstanceGroupDetail {
     pub instance_request_count: i64,
     /// <p>Instance group role in the cluster</p>
     #[serde(rename = "InstanceRole")]
-    pub instance_role: String,
+    pub instance_role: InstanceRoleType,
     /// <p>Actual count of running instances.</p>
     #[serde(rename = "InstanceRunningCount")]
     pub instance_running_count: i64,
@@ -1225,22 +2551,32 @@ pub struct InstanceGroupDetail {
     pub last_state_change_reason: Option<String>,
     /// <p>Market type of the EC2 instances used to create a cluster node.</p>
     #[serde(rename = "Market")]
-    pub market: String,
+    pub market: MarketType,
     /// <p>Friendly name for the instance group.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
     /// <p>The date/time the instance group was available to the cluster.</p>
     #[serde(rename = "ReadyDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ready_date_time: Option<f64>,
+    pub ready_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The date/time the instance group was started.</p>
     #[serde(rename = "StartDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_date_time: Option<f64>,
+    pub start_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>State of instance group. The following values are deprecated: STARTING, TERMINATED, and FAILED.</p>
     #[serde(rename = "State")]
-    pub state: String,
+    pub state: InstanceGroupState,
 }
 
 /// <p>Modify the size or configurations of an instance group.</p>
@@ -1268,20 +2604,261 @@ pub struct InstanceGroupModifyConfig {
     pub shrink_policy: Option<ShrinkPolicy>,
 }
 
-/// <p>The status change reason details for the instance group.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct InstanceGroupStateChangeReason {
-    /// <p>The programmable code for the state change reason.</p>
-    #[serde(rename = "Code")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub code: Option<String>,
-    /// <p>The status change reason description.</p>
-    #[serde(rename = "Message")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub message: Option<String>,
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum InstanceGroupState {
+    /// `PROVISIONING`
+    Provisioning,
+    /// `BOOTSTRAPPING`
+    Bootstrapping,
+    /// `RUNNING`
+    Running,
+    /// `RECONFIGURING`
+    Reconfiguring,
+    /// `RESIZING`
+    Resizing,
+    /// `SUSPENDED`
+    Suspended,
+    /// `TERMINATING`
+    Terminating,
+    /// `TERMINATED`
+    Terminated,
+    /// `ARRESTED`
+    Arrested,
+    /// `SHUTTING_DOWN`
+    ShuttingDown,
+    /// `ENDED`
+    Ended,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl InstanceGroupState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InstanceGroupState::Provisioning => "PROVISIONING",
+            InstanceGroupState::Bootstrapping => "BOOTSTRAPPING",
+            InstanceGroupState::Running => "RUNNING",
+            InstanceGroupState::Reconfiguring => "RECONFIGURING",
+            InstanceGroupState::Resizing => "RESIZING",
+            InstanceGroupState::Suspended => "SUSPENDED",
+            InstanceGroupState::Terminating => "TERMINATING",
+            InstanceGroupState::Terminated => "TERMINATED",
+            InstanceGroupState::Arrested => "ARRESTED",
+            InstanceGroupState::ShuttingDown => "SHUTTING_DOWN",
+            InstanceGroupState::Ended => "ENDED",
+            InstanceGroupState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for InstanceGroupState {
+    fn default() -> InstanceGroupState {
+        InstanceGroupState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for InstanceGroupState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for InstanceGroupState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for InstanceGroupState {
+    fn from(value: &'a str) -> InstanceGroupState {
+        match value {
+            "PROVISIONING" => InstanceGroupState::Provisioning,
+            "BOOTSTRAPPING" => InstanceGroupState::Bootstrapping,
+            "RUNNING" => InstanceGroupState::Running,
+            "RECONFIGURING" => InstanceGroupState::Reconfiguring,
+            "RESIZING" => InstanceGroupState::Resizing,
+            "SUSPENDED" => InstanceGroupState::Suspended,
+            "TERMINATING" => InstanceGroupState::Terminating,
+            "TERMINATED" => InstanceGroupState::Terminated,
+            "ARRESTED" => InstanceGroupState::Arrested,
+            "SHUTTING_DOWN" => InstanceGroupState::ShuttingDown,
+            "ENDED" => InstanceGroupState::Ended,
+            _ => InstanceGroupState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InstanceGroupState {
+    fn from(value: String) -> InstanceGroupState {
+        match value.as_str() {
+            "PROVISIONING" => InstanceGroupState::Provisioning,
+            "BOOTSTRAPPING" => InstanceGroupState::Bootstrapping,
+            "RUNNING" => InstanceGroupState::Running,
+            "RECONFIGURING" => InstanceGroupState::Reconfiguring,
+            "RESIZING" => InstanceGroupState::Resizing,
+            "SUSPENDED" => InstanceGroupState::Suspended,
+            "TERMINATING" => InstanceGroupState::Terminating,
+            "TERMINATED" => InstanceGroupState::Terminated,
+            "ARRESTED" => InstanceGroupState::Arrested,
+            "SHUTTING_DOWN" => InstanceGroupState::ShuttingDown,
+            "ENDED" => InstanceGroupState::Ended,
+            _ => InstanceGroupState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for InstanceGroupState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<InstanceGroupState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for InstanceGroupState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for InstanceGroupState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InstanceGroupState {
+    fn deserialize<D>(deserializer: D) -> Result<InstanceGroupState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>The status change reason details for the instance group.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct InstanceGroupStateChangeReason {
+    /// <p>The programmable code for the state change reason.</p>
+    #[serde(rename = "Code")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<InstanceGroupStateChangeReasonCode>,
+    /// <p>The status change reason description.</p>
+    #[serde(rename = "Message")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum InstanceGroupStateChangeReasonCode {
+    /// `INTERNAL_ERROR`
+    InternalError,
+    /// `VALIDATION_ERROR`
+    ValidationError,
+    /// `INSTANCE_FAILURE`
+    InstanceFailure,
+    /// `CLUSTER_TERMINATED`
+    ClusterTerminated,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl InstanceGroupStateChangeReasonCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InstanceGroupStateChangeReasonCode::InternalError => "INTERNAL_ERROR",
+            InstanceGroupStateChangeReasonCode::ValidationError => "VALIDATION_ERROR",
+            InstanceGroupStateChangeReasonCode::InstanceFailure => "INSTANCE_FAILURE",
+            InstanceGroupStateChangeReasonCode::ClusterTerminated => "CLUSTER_TERMINATED",
+            InstanceGroupStateChangeReasonCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for InstanceGroupStateChangeReasonCode {
+    fn default() -> InstanceGroupStateChangeReasonCode {
+        InstanceGroupStateChangeReasonCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for InstanceGroupStateChangeReasonCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for InstanceGroupStateChangeReasonCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for InstanceGroupStateChangeReasonCode {
+    fn from(value: &'a str) -> InstanceGroupStateChangeReasonCode {
+        match value {
+            "INTERNAL_ERROR" => InstanceGroupStateChangeReasonCode::InternalError,
+            "VALIDATION_ERROR" => InstanceGroupStateChangeReasonCode::ValidationError,
+            "INSTANCE_FAILURE" => InstanceGroupStateChangeReasonCode::InstanceFailure,
+            "CLUSTER_TERMINATED" => InstanceGroupStateChangeReasonCode::ClusterTerminated,
+            _ => InstanceGroupStateChangeReasonCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InstanceGroupStateChangeReasonCode {
+    fn from(value: String) -> InstanceGroupStateChangeReasonCode {
+        match value.as_str() {
+            "INTERNAL_ERROR" => InstanceGroupStateChangeReasonCode::InternalError,
+            "VALIDATION_ERROR" => InstanceGroupStateChangeReasonCode::ValidationError,
+            "INSTANCE_FAILURE" => InstanceGroupStateChangeReasonCode::InstanceFailure,
+            "CLUSTER_TERMINATED" => InstanceGroupStateChangeReasonCode::ClusterTerminated,
+            _ => InstanceGroupStateChangeReasonCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for InstanceGroupStateChangeReasonCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<InstanceGroupStateChangeReasonCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for InstanceGroupStateChangeReasonCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for InstanceGroupStateChangeReasonCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for InstanceGroupStateChangeReasonCode {
+    fn deserialize<D>(deserializer: D) -> Result<InstanceGroupStateChangeReasonCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The details of the instance group status.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1289,7 +2866,7 @@ pub struct InstanceGroupStatus {
     /// <p>The current state of the instance group.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<InstanceGroupState>,
     /// <p>The status change reason details for the instance group.</p>
     #[serde(rename = "StateChangeReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1306,18 +2883,131 @@ pub struct InstanceGroupStatus {
 pub struct InstanceGroupTimeline {
     /// <p>The creation date and time of the instance group.</p>
     #[serde(rename = "CreationDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date_time: Option<f64>,
+    pub creation_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The date and time when the instance group terminated.</p>
     #[serde(rename = "EndDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_date_time: Option<f64>,
+    pub end_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The date and time when the instance group became ready to perform tasks.</p>
     #[serde(rename = "ReadyDateTime")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub ready_date_time: Option<f64>,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ready_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum InstanceGroupType {
+    /// `MASTER`
+    Master,
+    /// `CORE`
+    Core,
+    /// `TASK`
+    Task,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl InstanceGroupType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InstanceGroupType::Master => "MASTER",
+            InstanceGroupType::Core => "CORE",
+            InstanceGroupType::Task => "TASK",
+            InstanceGroupType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for InstanceGroupType {
+    fn default() -> InstanceGroupType {
+        InstanceGroupType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for InstanceGroupType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for InstanceGroupType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for InstanceGroupType {
+    fn from(value: &'a str) -> InstanceGroupType {
+        match value {
+            "MASTER" => InstanceGroupType::Master,
+            "CORE" => InstanceGroupType::Core,
+            "TASK" => InstanceGroupType::Task,
+            _ => InstanceGroupType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InstanceGroupType {
+    fn from(value: String) -> InstanceGroupType {
+        match value.as_str() {
+            "MASTER" => InstanceGroupType::Master,
+            "CORE" => InstanceGroupType::Core,
+            "TASK" => InstanceGroupType::Task,
+            _ => InstanceGroupType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for InstanceGroupType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<InstanceGroupType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for InstanceGroupType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
 }
 
+impl ::serde::Serialize for InstanceGroupType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InstanceGroupType {
+    fn deserialize<D>(deserializer: D) -> Result<InstanceGroupType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Custom policy for requesting termination protection or termination of specific instances when shrinking an instance group.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct InstanceResizePolicy {
@@ -1335,6 +3025,212 @@ pub struct InstanceResizePolicy {
     pub instances_to_terminate: Option<Vec<String>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum InstanceRoleType {
+    /// `MASTER`
+    Master,
+    /// `CORE`
+    Core,
+    /// `TASK`
+    Task,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl InstanceRoleType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InstanceRoleType::Master => "MASTER",
+            InstanceRoleType::Core => "CORE",
+            InstanceRoleType::Task => "TASK",
+            InstanceRoleType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for InstanceRoleType {
+    fn default() -> InstanceRoleType {
+        InstanceRoleType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for InstanceRoleType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for InstanceRoleType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for InstanceRoleType {
+    fn from(value: &'a str) -> InstanceRoleType {
+        match value {
+            "MASTER" => InstanceRoleType::Master,
+            "CORE" => InstanceRoleType::Core,
+            "TASK" => InstanceRoleType::Task,
+            _ => InstanceRoleType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InstanceRoleType {
+    fn from(value: String) -> InstanceRoleType {
+        match value.as_str() {
+            "MASTER" => InstanceRoleType::Master,
+            "CORE" => InstanceRoleType::Core,
+            "TASK" => InstanceRoleType::Task,
+            _ => InstanceRoleType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for InstanceRoleType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<InstanceRoleType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for InstanceRoleType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for InstanceRoleType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InstanceRoleType {
+    fn deserialize<D>(deserializer: D) -> Result<InstanceRoleType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum InstanceState {
+    /// `AWAITING_FULFILLMENT`
+    AwaitingFulfillment,
+    /// `PROVISIONING`
+    Provisioning,
+    /// `BOOTSTRAPPING`
+    Bootstrapping,
+    /// `RUNNING`
+    Running,
+    /// `TERMINATED`
+    Terminated,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl InstanceState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InstanceState::AwaitingFulfillment => "AWAITING_FULFILLMENT",
+            InstanceState::Provisioning => "PROVISIONING",
+            InstanceState::Bootstrapping => "BOOTSTRAPPING",
+            InstanceState::Running => "RUNNING",
+            InstanceState::Terminated => "TERMINATED",
+            InstanceState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for InstanceState {
+    fn default() -> InstanceState {
+        InstanceState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for InstanceState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for InstanceState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for InstanceState {
+    fn from(value: &'a str) -> InstanceState {
+        match value {
+            "AWAITING_FULFILLMENT" => InstanceState::AwaitingFulfillment,
+            "PROVISIONING" => InstanceState::Provisioning,
+            "BOOTSTRAPPING" => InstanceState::Bootstrapping,
+            "RUNNING" => InstanceState::Running,
+            "TERMINATED" => InstanceState::Terminated,
+            _ => InstanceState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InstanceState {
+    fn from(value: String) -> InstanceState {
+        match value.as_str() {
+            "AWAITING_FULFILLMENT" => InstanceState::AwaitingFulfillment,
+            "PROVISIONING" => InstanceState::Provisioning,
+            "BOOTSTRAPPING" => InstanceState::Bootstrapping,
+            "RUNNING" => InstanceState::Running,
+            "TERMINATED" => InstanceState::Terminated,
+            _ => InstanceState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for InstanceState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<InstanceState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for InstanceState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for InstanceState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InstanceState {
+    fn deserialize<D>(deserializer: D) -> Result<InstanceState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The details of the status change reason for the instance.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1342,13 +3238,121 @@ pub struct InstanceStateChangeReason {
     /// <p>The programmable code for the state change reason.</p>
     #[serde(rename = "Code")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub code: Option<String>,
+    pub code: Option<InstanceStateChangeReasonCode>,
     /// <p>The status change reason description.</p>
     #[serde(rename = "Message")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub message: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum InstanceStateChangeReasonCode {
+    /// `INTERNAL_ERROR`
+    InternalError,
+    /// `VALIDATION_ERROR`
+    ValidationError,
+    /// `INSTANCE_FAILURE`
+    InstanceFailure,
+    /// `BOOTSTRAP_FAILURE`
+    BootstrapFailure,
+    /// `CLUSTER_TERMINATED`
+    ClusterTerminated,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl InstanceStateChangeReasonCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InstanceStateChangeReasonCode::InternalError => "INTERNAL_ERROR",
+            InstanceStateChangeReasonCode::ValidationError => "VALIDATION_ERROR",
+            InstanceStateChangeReasonCode::InstanceFailure => "INSTANCE_FAILURE",
+            InstanceStateChangeReasonCode::BootstrapFailure => "BOOTSTRAP_FAILURE",
+            InstanceStateChangeReasonCode::ClusterTerminated => "CLUSTER_TERMINATED",
+            InstanceStateChangeReasonCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for InstanceStateChangeReasonCode {
+    fn default() -> InstanceStateChangeReasonCode {
+        InstanceStateChangeReasonCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for InstanceStateChangeReasonCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for InstanceStateChangeReasonCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for InstanceStateChangeReasonCode {
+    fn from(value: &'a str) -> InstanceStateChangeReasonCode {
+        match value {
+            "INTERNAL_ERROR" => InstanceStateChangeReasonCode::InternalError,
+            "VALIDATION_ERROR" => InstanceStateChangeReasonCode::ValidationError,
+            "INSTANCE_FAILURE" => InstanceStateChangeReasonCode::InstanceFailure,
+            "BOOTSTRAP_FAILURE" => InstanceStateChangeReasonCode::BootstrapFailure,
+            "CLUSTER_TERMINATED" => InstanceStateChangeReasonCode::ClusterTerminated,
+            _ => InstanceStateChangeReasonCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InstanceStateChangeReasonCode {
+    fn from(value: String) -> InstanceStateChangeReasonCode {
+        match value.as_str() {
+            "INTERNAL_ERROR" => InstanceStateChangeReasonCode::InternalError,
+            "VALIDATION_ERROR" => InstanceStateChangeReasonCode::ValidationError,
+            "INSTANCE_FAILURE" => InstanceStateChangeReasonCode::InstanceFailure,
+            "BOOTSTRAP_FAILURE" => InstanceStateChangeReasonCode::BootstrapFailure,
+            "CLUSTER_TERMINATED" => InstanceStateChangeReasonCode::ClusterTerminated,
+            _ => InstanceStateChangeReasonCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for InstanceStateChangeReasonCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<InstanceStateChangeReasonCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for InstanceStateChangeReasonCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for InstanceStateChangeReasonCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InstanceStateChangeReasonCode {
+    fn deserialize<D>(deserializer: D) -> Result<InstanceStateChangeReasonCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The instance status details.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1356,7 +3360,7 @@ pub struct InstanceStatus {
     /// <p>The current state of the instance.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<InstanceState>,
     /// <p>The details of the status change reason for the instance.</p>
     #[serde(rename = "StateChangeReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1373,16 +3377,31 @@ pub struct InstanceStatus {
 pub struct InstanceTimeline {
     /// <p>The creation date and time of the instance.</p>
     #[serde(rename = "CreationDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date_time: Option<f64>,
+    pub creation_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The date and time when the instance was terminated.</p>
     #[serde(rename = "EndDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_date_time: Option<f64>,
+    pub end_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The date and time when the instance was ready to perform tasks.</p>
     #[serde(rename = "ReadyDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ready_date_time: Option<f64>,
+    pub ready_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p><p>An instance type configuration for each instance type in an instance fleet, which determines the EC2 instances Amazon EMR attempts to provision to fulfill On-Demand and Spot target capacities. There can be a maximum of 5 instance type configurations in a fleet.</p> <note> <p>The instance fleet configuration is available only in Amazon EMR versions 4.8.0 and later, excluding 5.0.x versions.</p> </note></p>
@@ -1487,7 +3506,7 @@ pub struct JobFlowDetail {
     /// <p>The way that individual Amazon EC2 instances terminate when an automatic scale-in activity occurs or an instance group is resized. <code>TERMINATE_AT_INSTANCE_HOUR</code> indicates that Amazon EMR terminates nodes at the instance-hour boundary, regardless of when the request to terminate the instance was submitted. This option is only available with Amazon EMR 5.1.0 and later and is the default for clusters created using that version. <code>TERMINATE_AT_TASK_COMPLETION</code> indicates that Amazon EMR blacklists and drains tasks from nodes before terminating the Amazon EC2 instances, regardless of the instance-hour boundary. With either behavior, Amazon EMR removes the least active nodes first and blocks instance termination if it could lead to HDFS corruption. <code>TERMINATE_AT_TASK_COMPLETION</code> available only in Amazon EMR version 4.1.0 and later, and is the default for versions of Amazon EMR earlier than 5.1.0.</p>
     #[serde(rename = "ScaleDownBehavior")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scale_down_behavior: Option<String>,
+    pub scale_down_behavior: Option<ScaleDownBehavior>,
     /// <p>The IAM role that will be assumed by the Amazon EMR service to access AWS resources on your behalf.</p>
     #[serde(rename = "ServiceRole")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1506,32 +3525,176 @@ pub struct JobFlowDetail {
     pub visible_to_all_users: Option<bool>,
 }
 
+/// <p>The type of instance.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum JobFlowExecutionState {
+    /// `STARTING`
+    Starting,
+    /// `BOOTSTRAPPING`
+    Bootstrapping,
+    /// `RUNNING`
+    Running,
+    /// `WAITING`
+    Waiting,
+    /// `SHUTTING_DOWN`
+    ShuttingDown,
+    /// `TERMINATED`
+    Terminated,
+    /// `COMPLETED`
+    Completed,
+    /// `FAILED`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl JobFlowExecutionState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            JobFlowExecutionState::Starting => "STARTING",
+            JobFlowExecutionState::Bootstrapping => "BOOTSTRAPPING",
+            JobFlowExecutionState::Running => "RUNNING",
+            JobFlowExecutionState::Waiting => "WAITING",
+            JobFlowExecutionState::ShuttingDown => "SHUTTING_DOWN",
+            JobFlowExecutionState::Terminated => "TERMINATED",
+            JobFlowExecutionState::Completed => "COMPLETED",
+            JobFlowExecutionState::Failed => "FAILED",
+            JobFlowExecutionState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for JobFlowExecutionState {
+    fn default() -> JobFlowExecutionState {
+        JobFlowExecutionState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for JobFlowExecutionState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for JobFlowExecutionState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for JobFlowExecutionState {
+    fn from(value: &'a str) -> JobFlowExecutionState {
+        match value {
+            "STARTING" => JobFlowExecutionState::Starting,
+            "BOOTSTRAPPING" => JobFlowExecutionState::Bootstrapping,
+            "RUNNING" => JobFlowExecutionState::Running,
+            "WAITING" => JobFlowExecutionState::Waiting,
+            "SHUTTING_DOWN" => JobFlowExecutionState::ShuttingDown,
+            "TERMINATED" => JobFlowExecutionState::Terminated,
+            "COMPLETED" => JobFlowExecutionState::Completed,
+            "FAILED" => JobFlowExecutionState::Failed,
+            _ => JobFlowExecutionState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for JobFlowExecutionState {
+    fn from(value: String) -> JobFlowExecutionState {
+        match value.as_str() {
+            "STARTING" => JobFlowExecutionState::Starting,
+            "BOOTSTRAPPING" => JobFlowExecutionState::Bootstrapping,
+            "RUNNING" => JobFlowExecutionState::Running,
+            "WAITING" => JobFlowExecutionState::Waiting,
+            "SHUTTING_DOWN" => JobFlowExecutionState::ShuttingDown,
+            "TERMINATED" => JobFlowExecutionState::Terminated,
+            "COMPLETED" => JobFlowExecutionState::Completed,
+            "FAILED" => JobFlowExecutionState::Failed,
+            _ => JobFlowExecutionState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for JobFlowExecutionState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<JobFlowExecutionState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for JobFlowExecutionState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for JobFlowExecutionState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for JobFlowExecutionState {
+    fn deserialize<D>(deserializer: D) -> Result<JobFlowExecutionState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes the status of the cluster (job flow).</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct JobFlowExecutionStatusDetail {
     /// <p>The creation date and time of the job flow.</p>
     #[serde(rename = "CreationDateTime")]
-    pub creation_date_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub creation_date_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The completion date and time of the job flow.</p>
     #[serde(rename = "EndDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_date_time: Option<f64>,
+    pub end_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Description of the job flow last changed state.</p>
     #[serde(rename = "LastStateChangeReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub last_state_change_reason: Option<String>,
     /// <p>The date and time when the job flow was ready to start running bootstrap actions.</p>
     #[serde(rename = "ReadyDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ready_date_time: Option<f64>,
+    pub ready_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The start date and time of the job flow.</p>
     #[serde(rename = "StartDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_date_time: Option<f64>,
+    pub start_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The state of the job flow.</p>
     #[serde(rename = "State")]
-    pub state: String,
+    pub state: JobFlowExecutionState,
 }
 
 /// <p>A description of the Amazon EC2 instance on which the cluster (job flow) runs. A valid JobFlowInstancesConfig must contain either InstanceGroups or InstanceFleets, which is the recommended configuration. They cannot be used together. You may also have MasterInstanceType, SlaveInstanceType, and InstanceCount (all three must be present), but we don't recommend this configuration.</p>
@@ -1733,15 +3896,25 @@ pub struct ListClustersInput {
     /// <p>The cluster state filters to apply when listing clusters.</p>
     #[serde(rename = "ClusterStates")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub cluster_states: Option<Vec<String>>,
+    pub cluster_states: Option<Vec<ClusterState>>,
     /// <p>The creation date and time beginning value filter for listing clusters.</p>
     #[serde(rename = "CreatedAfter")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_after: Option<f64>,
+    pub created_after: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The creation date and time end value filter for listing clusters.</p>
     #[serde(rename = "CreatedBefore")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_before: Option<f64>,
+    pub created_before: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The pagination token that indicates the next set of results to retrieve.</p>
     #[serde(rename = "Marker")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1828,7 +4001,7 @@ pub struct ListInstancesInput {
     /// <p>The node type of the instance fleet. For example MASTER, CORE, or TASK.</p>
     #[serde(rename = "InstanceFleetType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub instance_fleet_type: Option<String>,
+    pub instance_fleet_type: Option<InstanceFleetType>,
     /// <p>The identifier of the instance group for which to list the instances.</p>
     #[serde(rename = "InstanceGroupId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1836,11 +4009,11 @@ pub struct ListInstancesInput {
     /// <p>The type of instance group for which to list the instances.</p>
     #[serde(rename = "InstanceGroupTypes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub instance_group_types: Option<Vec<String>>,
+    pub instance_group_types: Option<Vec<InstanceGroupType>>,
     /// <p>A list of instance states that will filter the instances returned with this request.</p>
     #[serde(rename = "InstanceStates")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub instance_states: Option<Vec<String>>,
+    pub instance_states: Option<Vec<InstanceState>>,
     /// <p>The pagination token that indicates the next set of results to retrieve.</p>
     #[serde(rename = "Marker")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1901,7 +4074,7 @@ pub struct ListStepsInput {
     /// <p>The filter to limit the step list based on certain states.</p>
     #[serde(rename = "StepStates")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub step_states: Option<Vec<String>>,
+    pub step_states: Option<Vec<StepState>>,
 }
 
 /// <p>This output contains the list of steps returned in reverse order. This means that the last step is the first element in the list.</p>
@@ -1918,6 +4091,99 @@ pub struct ListStepsOutput {
     pub steps: Option<Vec<StepSummary>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MarketType {
+    /// `ON_DEMAND`
+    OnDemand,
+    /// `SPOT`
+    Spot,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl MarketType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MarketType::OnDemand => "ON_DEMAND",
+            MarketType::Spot => "SPOT",
+            MarketType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for MarketType {
+    fn default() -> MarketType {
+        MarketType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for MarketType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for MarketType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for MarketType {
+    fn from(value: &'a str) -> MarketType {
+        match value {
+            "ON_DEMAND" => MarketType::OnDemand,
+            "SPOT" => MarketType::Spot,
+            _ => MarketType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MarketType {
+    fn from(value: String) -> MarketType {
+        match value.as_str() {
+            "ON_DEMAND" => MarketType::OnDemand,
+            "SPOT" => MarketType::Spot,
+            _ => MarketType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for MarketType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<MarketType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MarketType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for MarketType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MarketType {
+    fn deserialize<D>(deserializer: D) -> Result<MarketType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A CloudWatch dimension, which is specified using a <code>Key</code> (known as a <code>Name</code> in CloudWatch), <code>Value</code> pair. By default, Amazon EMR uses one dimension whose <code>Key</code> is <code>JobFlowID</code> and <code>Value</code> is a variable representing the cluster ID, which is <code>${emr.clusterId}</code>. This enables the rule to bootstrap when the cluster ID becomes available.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MetricDimension {
@@ -2081,6 +4347,99 @@ pub struct RemoveTagsInput {
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RemoveTagsOutput {}
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RepoUpgradeOnBoot {
+    /// `SECURITY`
+    Security,
+    /// `NONE`
+    None,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RepoUpgradeOnBoot {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RepoUpgradeOnBoot::Security => "SECURITY",
+            RepoUpgradeOnBoot::None => "NONE",
+            RepoUpgradeOnBoot::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RepoUpgradeOnBoot {
+    fn default() -> RepoUpgradeOnBoot {
+        RepoUpgradeOnBoot::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RepoUpgradeOnBoot {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RepoUpgradeOnBoot {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RepoUpgradeOnBoot {
+    fn from(value: &'a str) -> RepoUpgradeOnBoot {
+        match value {
+            "SECURITY" => RepoUpgradeOnBoot::Security,
+            "NONE" => RepoUpgradeOnBoot::None,
+            _ => RepoUpgradeOnBoot::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RepoUpgradeOnBoot {
+    fn from(value: String) -> RepoUpgradeOnBoot {
+        match value.as_str() {
+            "SECURITY" => RepoUpgradeOnBoot::Security,
+            "NONE" => RepoUpgradeOnBoot::None,
+            _ => RepoUpgradeOnBoot::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RepoUpgradeOnBoot {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RepoUpgradeOnBoot, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RepoUpgradeOnBoot {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RepoUpgradeOnBoot {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RepoUpgradeOnBoot {
+    fn deserialize<D>(deserializer: D) -> Result<RepoUpgradeOnBoot, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p> Input to the <a>RunJobFlow</a> operation. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -2146,11 +4505,11 @@ pub struct RunJobFlowInput {
     /// <p>Applies only when <code>CustomAmiID</code> is used. Specifies which updates from the Amazon Linux AMI package repositories to apply automatically when the instance boots using the AMI. If omitted, the default is <code>SECURITY</code>, which indicates that only security updates are applied. If <code>NONE</code> is specified, no updates are applied, and all updates must be applied manually.</p>
     #[serde(rename = "RepoUpgradeOnBoot")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub repo_upgrade_on_boot: Option<String>,
+    pub repo_upgrade_on_boot: Option<RepoUpgradeOnBoot>,
     /// <p>Specifies the way that individual Amazon EC2 instances terminate when an automatic scale-in activity occurs or an instance group is resized. <code>TERMINATE_AT_INSTANCE_HOUR</code> indicates that Amazon EMR terminates nodes at the instance-hour boundary, regardless of when the request to terminate the instance was submitted. This option is only available with Amazon EMR 5.1.0 and later and is the default for clusters created using that version. <code>TERMINATE_AT_TASK_COMPLETION</code> indicates that Amazon EMR blacklists and drains tasks from nodes before terminating the Amazon EC2 instances, regardless of the instance-hour boundary. With either behavior, Amazon EMR removes the least active nodes first and blocks instance termination if it could lead to HDFS corruption. <code>TERMINATE_AT_TASK_COMPLETION</code> available only in Amazon EMR version 4.1.0 and later, and is the default for versions of Amazon EMR earlier than 5.1.0.</p>
     #[serde(rename = "ScaleDownBehavior")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scale_down_behavior: Option<String>,
+    pub scale_down_behavior: Option<ScaleDownBehavior>,
     /// <p>The name of a security configuration to apply to the cluster.</p>
     #[serde(rename = "SecurityConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2195,13 +4554,106 @@ pub struct RunJobFlowOutput {
     pub job_flow_id: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ScaleDownBehavior {
+    /// `TERMINATE_AT_INSTANCE_HOUR`
+    TerminateAtInstanceHour,
+    /// `TERMINATE_AT_TASK_COMPLETION`
+    TerminateAtTaskCompletion,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ScaleDownBehavior {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ScaleDownBehavior::TerminateAtInstanceHour => "TERMINATE_AT_INSTANCE_HOUR",
+            ScaleDownBehavior::TerminateAtTaskCompletion => "TERMINATE_AT_TASK_COMPLETION",
+            ScaleDownBehavior::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ScaleDownBehavior {
+    fn default() -> ScaleDownBehavior {
+        ScaleDownBehavior::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ScaleDownBehavior {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ScaleDownBehavior {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ScaleDownBehavior {
+    fn from(value: &'a str) -> ScaleDownBehavior {
+        match value {
+            "TERMINATE_AT_INSTANCE_HOUR" => ScaleDownBehavior::TerminateAtInstanceHour,
+            "TERMINATE_AT_TASK_COMPLETION" => ScaleDownBehavior::TerminateAtTaskCompletion,
+            _ => ScaleDownBehavior::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ScaleDownBehavior {
+    fn from(value: String) -> ScaleDownBehavior {
+        match value.as_str() {
+            "TERMINATE_AT_INSTANCE_HOUR" => ScaleDownBehavior::TerminateAtInstanceHour,
+            "TERMINATE_AT_TASK_COMPLETION" => ScaleDownBehavior::TerminateAtTaskCompletion,
+            _ => ScaleDownBehavior::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ScaleDownBehavior {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ScaleDownBehavior, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ScaleDownBehavior {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ScaleDownBehavior {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ScaleDownBehavior {
+    fn deserialize<D>(deserializer: D) -> Result<ScaleDownBehavior, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The type of adjustment the automatic scaling activity makes when triggered, and the periodicity of the adjustment.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ScalingAction {
     /// <p>Not available for instance groups. Instance groups use the market type specified for the group.</p>
     #[serde(rename = "Market")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub market: Option<String>,
+    pub market: Option<MarketType>,
     /// <p>The type of adjustment the automatic scaling activity makes when triggered, and the periodicity of the adjustment.</p>
     #[serde(rename = "SimpleScalingPolicyConfiguration")]
     pub simple_scaling_policy_configuration: SimpleScalingPolicyConfiguration,
@@ -2262,8 +4714,13 @@ pub struct ScriptBootstrapActionConfig {
 pub struct SecurityConfigurationSummary {
     /// <p>The date and time the security configuration was created.</p>
     #[serde(rename = "CreationDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date_time: Option<f64>,
+    pub creation_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the security configuration.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2313,7 +4770,7 @@ pub struct SimpleScalingPolicyConfiguration {
     /// <p>The way in which EC2 instances are added (if <code>ScalingAdjustment</code> is a positive number) or terminated (if <code>ScalingAdjustment</code> is a negative number) each time the scaling activity is triggered. <code>CHANGE_IN_CAPACITY</code> is the default. <code>CHANGE_IN_CAPACITY</code> indicates that the EC2 instance count increments or decrements by <code>ScalingAdjustment</code>, which should be expressed as an integer. <code>PERCENT_CHANGE_IN_CAPACITY</code> indicates the instance count increments or decrements by the percentage specified by <code>ScalingAdjustment</code>, which should be expressed as an integer. For example, 20 indicates an increase in 20% increments of cluster capacity. <code>EXACT_CAPACITY</code> indicates the scaling activity results in an instance group with the number of EC2 instances specified by <code>ScalingAdjustment</code>, which should be expressed as a positive integer.</p>
     #[serde(rename = "AdjustmentType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub adjustment_type: Option<String>,
+    pub adjustment_type: Option<AdjustmentType>,
     /// <p>The amount of time, in seconds, after a scaling activity completes before any further trigger-related scaling activities can start. The default value is 0.</p>
     #[serde(rename = "CoolDown")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2332,12 +4789,213 @@ pub struct SpotProvisioningSpecification {
     pub block_duration_minutes: Option<i64>,
     /// <p>The action to take when <code>TargetSpotCapacity</code> has not been fulfilled when the <code>TimeoutDurationMinutes</code> has expired; that is, when all Spot instances could not be provisioned within the Spot provisioning timeout. Valid values are <code>TERMINATE_CLUSTER</code> and <code>SWITCH_TO_ON_DEMAND</code>. SWITCH_TO_ON_DEMAND specifies that if no Spot instances are available, On-Demand Instances should be provisioned to fulfill any remaining Spot capacity.</p>
     #[serde(rename = "TimeoutAction")]
-    pub timeout_action: String,
+    pub timeout_action: SpotProvisioningTimeoutAction,
     /// <p>The spot provisioning timeout period in minutes. If Spot instances are not provisioned within this time period, the <code>TimeOutAction</code> is taken. Minimum value is 5 and maximum value is 1440. The timeout applies only during initial provisioning, when the cluster is first created.</p>
     #[serde(rename = "TimeoutDurationMinutes")]
     pub timeout_duration_minutes: i64,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SpotProvisioningTimeoutAction {
+    /// `SWITCH_TO_ON_DEMAND`
+    SwitchToOnDemand,
+    /// `TERMINATE_CLUSTER`
+    TerminateCluster,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SpotProvisioningTimeoutAction {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SpotProvisioningTimeoutAction::SwitchToOnDemand => "SWITCH_TO_ON_DEMAND",
+            SpotProvisioningTimeoutAction::TerminateCluster => "TERMINATE_CLUSTER",
+            SpotProvisioningTimeoutAction::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SpotProvisioningTimeoutAction {
+    fn default() -> SpotProvisioningTimeoutAction {
+        SpotProvisioningTimeoutAction::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SpotProvisioningTimeoutAction {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SpotProvisioningTimeoutAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SpotProvisioningTimeoutAction {
+    fn from(value: &'a str) -> SpotProvisioningTimeoutAction {
+        match value {
+            "SWITCH_TO_ON_DEMAND" => SpotProvisioningTimeoutAction::SwitchToOnDemand,
+            "TERMINATE_CLUSTER" => SpotProvisioningTimeoutAction::TerminateCluster,
+            _ => SpotProvisioningTimeoutAction::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SpotProvisioningTimeoutAction {
+    fn from(value: String) -> SpotProvisioningTimeoutAction {
+        match value.as_str() {
+            "SWITCH_TO_ON_DEMAND" => SpotProvisioningTimeoutAction::SwitchToOnDemand,
+            "TERMINATE_CLUSTER" => SpotProvisioningTimeoutAction::TerminateCluster,
+            _ => SpotProvisioningTimeoutAction::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SpotProvisioningTimeoutAction {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SpotProvisioningTimeoutAction, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SpotProvisioningTimeoutAction {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SpotProvisioningTimeoutAction {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SpotProvisioningTimeoutAction {
+    fn deserialize<D>(deserializer: D) -> Result<SpotProvisioningTimeoutAction, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Statistic {
+    /// `SAMPLE_COUNT`
+    SampleCount,
+    /// `AVERAGE`
+    Average,
+    /// `SUM`
+    Sum,
+    /// `MINIMUM`
+    Minimum,
+    /// `MAXIMUM`
+    Maximum,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Statistic {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Statistic::SampleCount => "SAMPLE_COUNT",
+            Statistic::Average => "AVERAGE",
+            Statistic::Sum => "SUM",
+            Statistic::Minimum => "MINIMUM",
+            Statistic::Maximum => "MAXIMUM",
+            Statistic::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Statistic {
+    fn default() -> Statistic {
+        Statistic::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Statistic {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Statistic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Statistic {
+    fn from(value: &'a str) -> Statistic {
+        match value {
+            "SAMPLE_COUNT" => Statistic::SampleCount,
+            "AVERAGE" => Statistic::Average,
+            "SUM" => Statistic::Sum,
+            "MINIMUM" => Statistic::Minimum,
+            "MAXIMUM" => Statistic::Maximum,
+            _ => Statistic::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Statistic {
+    fn from(value: String) -> Statistic {
+        match value.as_str() {
+            "SAMPLE_COUNT" => Statistic::SampleCount,
+            "AVERAGE" => Statistic::Average,
+            "SUM" => Statistic::Sum,
+            "MINIMUM" => Statistic::Minimum,
+            "MAXIMUM" => Statistic::Maximum,
+            _ => Statistic::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Statistic {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Statistic, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Statistic {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Statistic {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Statistic {
+    fn deserialize<D>(deserializer: D) -> Result<Statistic, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>This represents a step in a cluster.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -2345,7 +5003,7 @@ pub struct Step {
     /// <p>The action to take when the cluster step fails. Possible values are TERMINATE_CLUSTER, CANCEL_AND_WAIT, and CONTINUE. TERMINATE_JOB_FLOW is provided for backward compatibility. We recommend using TERMINATE_CLUSTER instead.</p>
     #[serde(rename = "ActionOnFailure")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub action_on_failure: Option<String>,
+    pub action_on_failure: Option<ActionOnFailure>,
     /// <p>The Hadoop job configuration of the cluster step.</p>
     #[serde(rename = "Config")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2364,57 +5022,401 @@ pub struct Step {
     pub status: Option<StepStatus>,
 }
 
-/// <p>Specification of a cluster (job flow) step.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct StepConfig {
-    /// <p>The action to take when the cluster step fails. Possible values are TERMINATE_CLUSTER, CANCEL_AND_WAIT, and CONTINUE. TERMINATE_JOB_FLOW is provided for backward compatibility. We recommend using TERMINATE_CLUSTER instead.</p>
-    #[serde(rename = "ActionOnFailure")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub action_on_failure: Option<String>,
-    /// <p>The JAR file used for the step.</p>
-    #[serde(rename = "HadoopJarStep")]
-    pub hadoop_jar_step: HadoopJarStepConfig,
-    /// <p>The name of the step.</p>
-    #[serde(rename = "Name")]
-    pub name: String,
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StepCancellationOption {
+    /// `SEND_INTERRUPT`
+    SendInterrupt,
+    /// `TERMINATE_PROCESS`
+    TerminateProcess,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl StepCancellationOption {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StepCancellationOption::SendInterrupt => "SEND_INTERRUPT",
+            StepCancellationOption::TerminateProcess => "TERMINATE_PROCESS",
+            StepCancellationOption::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for StepCancellationOption {
+    fn default() -> StepCancellationOption {
+        StepCancellationOption::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for StepCancellationOption {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for StepCancellationOption {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for StepCancellationOption {
+    fn from(value: &'a str) -> StepCancellationOption {
+        match value {
+            "SEND_INTERRUPT" => StepCancellationOption::SendInterrupt,
+            "TERMINATE_PROCESS" => StepCancellationOption::TerminateProcess,
+            _ => StepCancellationOption::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StepCancellationOption {
+    fn from(value: String) -> StepCancellationOption {
+        match value.as_str() {
+            "SEND_INTERRUPT" => StepCancellationOption::SendInterrupt,
+            "TERMINATE_PROCESS" => StepCancellationOption::TerminateProcess,
+            _ => StepCancellationOption::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for StepCancellationOption {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<StepCancellationOption, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StepCancellationOption {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for StepCancellationOption {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StepCancellationOption {
+    fn deserialize<D>(deserializer: D) -> Result<StepCancellationOption, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Specification of a cluster (job flow) step.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StepConfig {
+    /// <p>The action to take when the cluster step fails. Possible values are TERMINATE_CLUSTER, CANCEL_AND_WAIT, and CONTINUE. TERMINATE_JOB_FLOW is provided for backward compatibility. We recommend using TERMINATE_CLUSTER instead.</p>
+    #[serde(rename = "ActionOnFailure")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_on_failure: Option<ActionOnFailure>,
+    /// <p>The JAR file used for the step.</p>
+    #[serde(rename = "HadoopJarStep")]
+    pub hadoop_jar_step: HadoopJarStepConfig,
+    /// <p>The name of the step.</p>
+    #[serde(rename = "Name")]
+    pub name: String,
+}
+
+/// <p>Combines the execution state and configuration of a step.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct StepDetail {
+    /// <p>The description of the step status.</p>
+    #[serde(rename = "ExecutionStatusDetail")]
+    pub execution_status_detail: StepExecutionStatusDetail,
+    /// <p>The step configuration.</p>
+    #[serde(rename = "StepConfig")]
+    pub step_config: StepConfig,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StepExecutionState {
+    /// `PENDING`
+    Pending,
+    /// `RUNNING`
+    Running,
+    /// `CONTINUE`
+    Continue,
+    /// `COMPLETED`
+    Completed,
+    /// `CANCELLED`
+    Cancelled,
+    /// `FAILED`
+    Failed,
+    /// `INTERRUPTED`
+    Interrupted,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl StepExecutionState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StepExecutionState::Pending => "PENDING",
+            StepExecutionState::Running => "RUNNING",
+            StepExecutionState::Continue => "CONTINUE",
+            StepExecutionState::Completed => "COMPLETED",
+            StepExecutionState::Cancelled => "CANCELLED",
+            StepExecutionState::Failed => "FAILED",
+            StepExecutionState::Interrupted => "INTERRUPTED",
+            StepExecutionState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for StepExecutionState {
+    fn default() -> StepExecutionState {
+        StepExecutionState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for StepExecutionState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for StepExecutionState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for StepExecutionState {
+    fn from(value: &'a str) -> StepExecutionState {
+        match value {
+            "PENDING" => StepExecutionState::Pending,
+            "RUNNING" => StepExecutionState::Running,
+            "CONTINUE" => StepExecutionState::Continue,
+            "COMPLETED" => StepExecutionState::Completed,
+            "CANCELLED" => StepExecutionState::Cancelled,
+            "FAILED" => StepExecutionState::Failed,
+            "INTERRUPTED" => StepExecutionState::Interrupted,
+            _ => StepExecutionState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StepExecutionState {
+    fn from(value: String) -> StepExecutionState {
+        match value.as_str() {
+            "PENDING" => StepExecutionState::Pending,
+            "RUNNING" => StepExecutionState::Running,
+            "CONTINUE" => StepExecutionState::Continue,
+            "COMPLETED" => StepExecutionState::Completed,
+            "CANCELLED" => StepExecutionState::Cancelled,
+            "FAILED" => StepExecutionState::Failed,
+            "INTERRUPTED" => StepExecutionState::Interrupted,
+            _ => StepExecutionState::Unknown(value),
+        }
+    }
 }
 
-/// <p>Combines the execution state and configuration of a step.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct StepDetail {
-    /// <p>The description of the step status.</p>
-    #[serde(rename = "ExecutionStatusDetail")]
-    pub execution_status_detail: StepExecutionStatusDetail,
-    /// <p>The step configuration.</p>
-    #[serde(rename = "StepConfig")]
-    pub step_config: StepConfig,
+impl ::std::str::FromStr for StepExecutionState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<StepExecutionState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StepExecutionState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for StepExecutionState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for StepExecutionState {
+    fn deserialize<D>(deserializer: D) -> Result<StepExecutionState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The execution state of a step.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StepExecutionStatusDetail {
     /// <p>The creation date and time of the step.</p>
     #[serde(rename = "CreationDateTime")]
-    pub creation_date_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub creation_date_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The completion date and time of the step.</p>
     #[serde(rename = "EndDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_date_time: Option<f64>,
+    pub end_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A description of the step's current state.</p>
     #[serde(rename = "LastStateChangeReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub last_state_change_reason: Option<String>,
     /// <p>The start date and time of the step.</p>
     #[serde(rename = "StartDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_date_time: Option<f64>,
+    pub start_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The state of the step.</p>
     #[serde(rename = "State")]
-    pub state: String,
+    pub state: StepExecutionState,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StepState {
+    /// `PENDING`
+    Pending,
+    /// `CANCEL_PENDING`
+    CancelPending,
+    /// `RUNNING`
+    Running,
+    /// `COMPLETED`
+    Completed,
+    /// `CANCELLED`
+    Cancelled,
+    /// `FAILED`
+    Failed,
+    /// `INTERRUPTED`
+    Interrupted,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl StepState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StepState::Pending => "PENDING",
+            StepState::CancelPending => "CANCEL_PENDING",
+            StepState::Running => "RUNNING",
+            StepState::Completed => "COMPLETED",
+            StepState::Cancelled => "CANCELLED",
+            StepState::Failed => "FAILED",
+            StepState::Interrupted => "INTERRUPTED",
+            StepState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for StepState {
+    fn default() -> StepState {
+        StepState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for StepState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for StepState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for StepState {
+    fn from(value: &'a str) -> StepState {
+        match value {
+            "PENDING" => StepState::Pending,
+            "CANCEL_PENDING" => StepState::CancelPending,
+            "RUNNING" => StepState::Running,
+            "COMPLETED" => StepState::Completed,
+            "CANCELLED" => StepState::Cancelled,
+            "FAILED" => StepState::Failed,
+            "INTERRUPTED" => StepState::Interrupted,
+            _ => StepState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StepState {
+    fn from(value: String) -> StepState {
+        match value.as_str() {
+            "PENDING" => StepState::Pending,
+            "CANCEL_PENDING" => StepState::CancelPending,
+            "RUNNING" => StepState::Running,
+            "COMPLETED" => StepState::Completed,
+            "CANCELLED" => StepState::Cancelled,
+            "FAILED" => StepState::Failed,
+            "INTERRUPTED" => StepState::Interrupted,
+            _ => StepState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for StepState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<StepState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StepState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for StepState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for StepState {
+    fn deserialize<D>(deserializer: D) -> Result<StepState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The details of the step state change reason.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -2422,13 +5424,101 @@ pub struct StepStateChangeReason {
     /// <p>The programmable code for the state change reason. Note: Currently, the service provides no code for the state change.</p>
     #[serde(rename = "Code")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub code: Option<String>,
+    pub code: Option<StepStateChangeReasonCode>,
     /// <p>The descriptive message for the state change reason.</p>
     #[serde(rename = "Message")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub message: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StepStateChangeReasonCode {
+    /// `NONE`
+    None,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl StepStateChangeReasonCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StepStateChangeReasonCode::None => "NONE",
+            StepStateChangeReasonCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for StepStateChangeReasonCode {
+    fn default() -> StepStateChangeReasonCode {
+        StepStateChangeReasonCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for StepStateChangeReasonCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for StepStateChangeReasonCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for StepStateChangeReasonCode {
+    fn from(value: &'a str) -> StepStateChangeReasonCode {
+        match value {
+            "NONE" => StepStateChangeReasonCode::None,
+            _ => StepStateChangeReasonCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StepStateChangeReasonCode {
+    fn from(value: String) -> StepStateChangeReasonCode {
+        match value.as_str() {
+            "NONE" => StepStateChangeReasonCode::None,
+            _ => StepStateChangeReasonCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for StepStateChangeReasonCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<StepStateChangeReasonCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StepStateChangeReasonCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for StepStateChangeReasonCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StepStateChangeReasonCode {
+    fn deserialize<D>(deserializer: D) -> Result<StepStateChangeReasonCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The execution status details of the cluster step.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -2440,7 +5530,7 @@ pub struct StepStatus {
     /// <p>The execution state of the cluster step.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<StepState>,
     /// <p>The reason for the step execution status change.</p>
     #[serde(rename = "StateChangeReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2458,7 +5548,7 @@ pub struct StepSummary {
     /// <p>The action to take when the cluster step fails. Possible values are TERMINATE_CLUSTER, CANCEL_AND_WAIT, and CONTINUE. TERMINATE_JOB_FLOW is available for backward compatibility. We recommend using TERMINATE_CLUSTER instead.</p>
     #[serde(rename = "ActionOnFailure")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub action_on_failure: Option<String>,
+    pub action_on_failure: Option<ActionOnFailure>,
     /// <p>The Hadoop job configuration of the cluster step.</p>
     #[serde(rename = "Config")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2483,16 +5573,31 @@ pub struct StepSummary {
 pub struct StepTimeline {
     /// <p>The date and time when the cluster step was created.</p>
     #[serde(rename = "CreationDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date_time: Option<f64>,
+    pub creation_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The date and time when the cluster step execution completed or failed.</p>
     #[serde(rename = "EndDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_date_time: Option<f64>,
+    pub end_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The date and time when the cluster step execution started.</p>
     #[serde(rename = "StartDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_date_time: Option<f64>,
+    pub start_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>The list of supported product configurations which allow user-supplied arguments. EMR accepts these arguments and forwards them to the corresponding installation script as bootstrap action arguments.</p>
@@ -2531,6 +5636,224 @@ pub struct TerminateJobFlowsInput {
     pub job_flow_ids: Vec<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Unit {
+    /// `NONE`
+    None,
+    /// `SECONDS`
+    Seconds,
+    /// `MICRO_SECONDS`
+    MicroSeconds,
+    /// `MILLI_SECONDS`
+    MilliSeconds,
+    /// `BYTES`
+    Bytes,
+    /// `KILO_BYTES`
+    KiloBytes,
+    /// `MEGA_BYTES`
+    MegaBytes,
+    /// `GIGA_BYTES`
+    GigaBytes,
+    /// `TERA_BYTES`
+    TeraBytes,
+    /// `BITS`
+    Bits,
+    /// `KILO_BITS`
+    KiloBits,
+    /// `MEGA_BITS`
+    MegaBits,
+    /// `GIGA_BITS`
+    GigaBits,
+    /// `TERA_BITS`
+    TeraBits,
+    /// `PERCENT`
+    Percent,
+    /// `COUNT`
+    Count,
+    /// `BYTES_PER_SECOND`
+    BytesPerSecond,
+    /// `KILO_BYTES_PER_SECOND`
+    KiloBytesPerSecond,
+    /// `MEGA_BYTES_PER_SECOND`
+    MegaBytesPerSecond,
+    /// `GIGA_BYTES_PER_SECOND`
+    GigaBytesPerSecond,
+    /// `TERA_BYTES_PER_SECOND`
+    TeraBytesPerSecond,
+    /// `BITS_PER_SECOND`
+    BitsPerSecond,
+    /// `KILO_BITS_PER_SECOND`
+    KiloBitsPerSecond,
+    /// `MEGA_BITS_PER_SECOND`
+    MegaBitsPerSecond,
+    /// `GIGA_BITS_PER_SECOND`
+    GigaBitsPerSecond,
+    /// `TERA_BITS_PER_SECOND`
+    TeraBitsPerSecond,
+    /// `COUNT_PER_SECOND`
+    CountPerSecond,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Unit {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Unit::None => "NONE",
+            Unit::Seconds => "SECONDS",
+            Unit::MicroSeconds => "MICRO_SECONDS",
+            Unit::MilliSeconds => "MILLI_SECONDS",
+            Unit::Bytes => "BYTES",
+            Unit::KiloBytes => "KILO_BYTES",
+            Unit::MegaBytes => "MEGA_BYTES",
+            Unit::GigaBytes => "GIGA_BYTES",
+            Unit::TeraBytes => "TERA_BYTES",
+            Unit::Bits => "BITS",
+            Unit::KiloBits => "KILO_BITS",
+            Unit::MegaBits => "MEGA_BITS",
+            Unit::GigaBits => "GIGA_BITS",
+            Unit::TeraBits => "TERA_BITS",
+            Unit::Percent => "PERCENT",
+            Unit::Count => "COUNT",
+            Unit::BytesPerSecond => "BYTES_PER_SECOND",
+            Unit::KiloBytesPerSecond => "KILO_BYTES_PER_SECOND",
+            Unit::MegaBytesPerSecond => "MEGA_BYTES_PER_SECOND",
+            Unit::GigaBytesPerSecond => "GIGA_BYTES_PER_SECOND",
+            Unit::TeraBytesPerSecond => "TERA_BYTES_PER_SECOND",
+            Unit::BitsPerSecond => "BITS_PER_SECOND",
+            Unit::KiloBitsPerSecond => "KILO_BITS_PER_SECOND",
+            Unit::MegaBitsPerSecond => "MEGA_BITS_PER_SECOND",
+            Unit::GigaBitsPerSecond => "GIGA_BITS_PER_SECOND",
+            Unit::TeraBitsPerSecond => "TERA_BITS_PER_SECOND",
+            Unit::CountPerSecond => "COUNT_PER_SECOND",
+            Unit::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Unit {
+    fn default() -> Unit {
+        Unit::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Unit {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Unit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Unit {
+    fn from(value: &'a str) -> Unit {
+        match value {
+            "NONE" => Unit::None,
+            "SECONDS" => Unit::Seconds,
+            "MICRO_SECONDS" => Unit::MicroSeconds,
+            "MILLI_SECONDS" => Unit::MilliSeconds,
+            "BYTES" => Unit::Bytes,
+            "KILO_BYTES" => Unit::KiloBytes,
+            "MEGA_BYTES" => Unit::MegaBytes,
+            "GIGA_BYTES" => Unit::GigaBytes,
+            "TERA_BYTES" => Unit::TeraBytes,
+            "BITS" => Unit::Bits,
+            "KILO_BITS" => Unit::KiloBits,
+            "MEGA_BITS" => Unit::MegaBits,
+            "GIGA_BITS" => Unit::GigaBits,
+            "TERA_BITS" => Unit::TeraBits,
+            "PERCENT" => Unit::Percent,
+            "COUNT" => Unit::Count,
+            "BYTES_PER_SECOND" => Unit::BytesPerSecond,
+            "KILO_BYTES_PER_SECOND" => Unit::KiloBytesPerSecond,
+            "MEGA_BYTES_PER_SECOND" => Unit::MegaBytesPerSecond,
+            "GIGA_BYTES_PER_SECOND" => Unit::GigaBytesPerSecond,
+            "TERA_BYTES_PER_SECOND" => Unit::TeraBytesPerSecond,
+            "BITS_PER_SECOND" => Unit::BitsPerSecond,
+            "KILO_BITS_PER_SECOND" => Unit::KiloBitsPerSecond,
+            "MEGA_BITS_PER_SECOND" => Unit::MegaBitsPerSecond,
+            "GIGA_BITS_PER_SECOND" => Unit::GigaBitsPerSecond,
+            "TERA_BITS_PER_SECOND" => Unit::TeraBitsPerSecond,
+            "COUNT_PER_SECOND" => Unit::CountPerSecond,
+            _ => Unit::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Unit {
+    fn from(value: String) -> Unit {
+        match value.as_str() {
+            "NONE" => Unit::None,
+            "SECONDS" => Unit::Seconds,
+            "MICRO_SECONDS" => Unit::MicroSeconds,
+            "MILLI_SECONDS" => Unit::MilliSeconds,
+            "BYTES" => Unit::Bytes,
+            "KILO_BYTES" => Unit::KiloBytes,
+            "MEGA_BYTES" => Unit::MegaBytes,
+            "GIGA_BYTES" => Unit::GigaBytes,
+            "TERA_BYTES" => Unit::TeraBytes,
+            "BITS" => Unit::Bits,
+            "KILO_BITS" => Unit::KiloBits,
+            "MEGA_BITS" => Unit::MegaBits,
+            "GIGA_BITS" => Unit::GigaBits,
+            "TERA_BITS" => Unit::TeraBits,
+            "PERCENT" => Unit::Percent,
+            "COUNT" => Unit::Count,
+            "BYTES_PER_SECOND" => Unit::BytesPerSecond,
+            "KILO_BYTES_PER_SECOND" => Unit::KiloBytesPerSecond,
+            "MEGA_BYTES_PER_SECOND" => Unit::MegaBytesPerSecond,
+            "GIGA_BYTES_PER_SECOND" => Unit::GigaBytesPerSecond,
+            "TERA_BYTES_PER_SECOND" => Unit::TeraBytesPerSecond,
+            "BITS_PER_SECOND" => Unit::BitsPerSecond,
+            "KILO_BITS_PER_SECOND" => Unit::KiloBitsPerSecond,
+            "MEGA_BITS_PER_SECOND" => Unit::MegaBitsPerSecond,
+            "GIGA_BITS_PER_SECOND" => Unit::GigaBitsPerSecond,
+            "TERA_BITS_PER_SECOND" => Unit::TeraBitsPerSecond,
+            "COUNT_PER_SECOND" => Unit::CountPerSecond,
+            _ => Unit::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Unit {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Unit, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Unit {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Unit {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Unit {
+    fn deserialize<D>(deserializer: D) -> Result<Unit, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>EBS volume specifications such as volume type, IOPS, and size (GiB) that will be requested for the EBS volume attached to an EC2 instance in the cluster.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct VolumeSpecification {
@@ -3804,11 +7127,12 @@ pub struct EmrClient {
 impl EmrClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> EmrClient {
         EmrClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "EMR"),
         }
     }
 
@@ -3840,6 +7164,7 @@ impl Emr for EmrClient {
         input: AddInstanceFleetInput,
     ) -> Result<AddInstanceFleetOutput, RusotoError<AddInstanceFleetError>> {
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("AddInstanceFleet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ElasticMapReduce.AddInstanceFleet");
@@ -3867,6 +7192,7 @@ impl Emr for EmrClient {
         input: AddInstanceGroupsInput,
     ) -> Result<AddInstanceGroupsOutput, RusotoError<AddInstanceGroupsError>> {
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("AddInstanceGroups");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ElasticMapReduce.AddInstanceGroups");
@@ -3894,6 +7220,7 @@ impl Emr for EmrClient {
         input: AddJobFlowStepsInput,
     ) -> Result<AddJobFlowStepsOutput, RusotoError<AddJobFlowStepsError>> {
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("AddJobFlowSteps");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ElasticMapReduce.AddJobFlowSteps");
@@ -3921,6 +7248,7 @@ impl Emr for EmrClient {
         input: AddTagsInput,
     ) -> Result<AddTagsOutput, RusotoError<AddTagsError>> {
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("AddTags");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ElasticMapReduce.AddTags");
@@ -3948,6 +7276,7 @@ impl Emr for EmrClient {
         input: CancelStepsInput,
     ) -> Result<CancelStepsOutput, RusotoError<CancelStepsError>> {
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("CancelSteps");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ElasticMapReduce.CancelSteps");
@@ -3976,6 +7305,7 @@ impl Emr for EmrClient {
     ) -> Result<CreateSecurityConfigurationOutput, RusotoError<CreateSecurityConfigurationError>>
     {
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("CreateSecurityConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4008,6 +7338,7 @@ impl Emr for EmrClient {
     ) -> Result<DeleteSecurityConfigurationOutput, RusotoError<DeleteSecurityConfigurationError>>
     {
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("DeleteSecurityConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4039,6 +7370,7 @@ impl Emr for EmrClient {
         input: DescribeClusterInput,
     ) -> Result<DescribeClusterOutput, RusotoError<DescribeClusterError>> {
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("DescribeCluster");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ElasticMapReduce.DescribeCluster");
@@ -4066,6 +7398,7 @@ impl Emr for EmrClient {
         input: DescribeJobFlowsInput,
     ) -> Result<DescribeJobFlowsOutput, RusotoError<DescribeJobFlowsError>> {
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("DescribeJobFlows");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ElasticMapReduce.DescribeJobFlows");
@@ -4094,6 +7427,7 @@ impl Emr for EmrClient {
     ) -> Result<DescribeSecurityConfigurationOutput, RusotoError<DescribeSecurityConfigurationError>>
     {
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("DescribeSecurityConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4125,6 +7459,7 @@ impl Emr for EmrClient {
         input: DescribeStepInput,
     ) -> Result<DescribeStepOutput, RusotoError<DescribeStepError>> {
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("DescribeStep");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ElasticMapReduce.DescribeStep");
@@ -4154,6 +7489,7 @@ impl Emr for EmrClient {
         RusotoError<GetBlockPublicAccessConfigurationError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("GetBlockPublicAccessConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4186,6 +7522,7 @@ impl Emr for EmrClient {
         input: ListBootstrapActionsInput,
     ) -> Result<ListBootstrapActionsOutput, RusotoError<ListBootstrapActionsError>> {
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("ListBootstrapActions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ElasticMapReduce.ListBootstrapActions");
@@ -4214,6 +7551,7 @@ impl Emr for EmrClient {
         input: ListClustersInput,
     ) -> Result<ListClustersOutput, RusotoError<ListClustersError>> {
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("ListClusters");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ElasticMapReduce.ListClusters");
@@ -4241,6 +7579,7 @@ impl Emr for EmrClient {
         input: ListInstanceFleetsInput,
     ) -> Result<ListInstanceFleetsOutput, RusotoError<ListInstanceFleetsError>> {
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("ListInstanceFleets");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ElasticMapReduce.ListInstanceFleets");
@@ -4269,6 +7608,7 @@ impl Emr for EmrClient {
         input: ListInstanceGroupsInput,
     ) -> Result<ListInstanceGroupsOutput, RusotoError<ListInstanceGroupsError>> {
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("ListInstanceGroups");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ElasticMapReduce.ListInstanceGroups");
@@ -4297,6 +7637,7 @@ impl Emr for EmrClient {
         input: ListInstancesInput,
     ) -> Result<ListInstancesOutput, RusotoError<ListInstancesError>> {
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("ListInstances");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ElasticMapReduce.ListInstances");
@@ -4325,6 +7666,7 @@ impl Emr for EmrClient {
     ) -> Result<ListSecurityConfigurationsOutput, RusotoError<ListSecurityConfigurationsError>>
     {
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("ListSecurityConfigurations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4356,6 +7698,7 @@ impl Emr for EmrClient {
         input: ListStepsInput,
     ) -> Result<ListStepsOutput, RusotoError<ListStepsError>> {
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("ListSteps");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ElasticMapReduce.ListSteps");
@@ -4383,6 +7726,7 @@ impl Emr for EmrClient {
         input: ModifyClusterInput,
     ) -> Result<ModifyClusterOutput, RusotoError<ModifyClusterError>> {
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("ModifyCluster");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ElasticMapReduce.ModifyCluster");
@@ -4410,6 +7754,7 @@ impl Emr for EmrClient {
         input: ModifyInstanceFleetInput,
     ) -> Result<(), RusotoError<ModifyInstanceFleetError>> {
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("ModifyInstanceFleet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ElasticMapReduce.ModifyInstanceFleet");
@@ -4437,6 +7782,7 @@ impl Emr for EmrClient {
         input: ModifyInstanceGroupsInput,
     ) -> Result<(), RusotoError<ModifyInstanceGroupsError>> {
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("ModifyInstanceGroups");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ElasticMapReduce.ModifyInstanceGroups");
@@ -4464,6 +7810,7 @@ impl Emr for EmrClient {
         input: PutAutoScalingPolicyInput,
     ) -> Result<PutAutoScalingPolicyOutput, RusotoError<PutAutoScalingPolicyError>> {
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("PutAutoScalingPolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ElasticMapReduce.PutAutoScalingPolicy");
@@ -4495,6 +7842,7 @@ impl Emr for EmrClient {
         RusotoError<PutBlockPublicAccessConfigurationError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("PutBlockPublicAccessConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4528,6 +7876,7 @@ impl Emr for EmrClient {
         input: RemoveAutoScalingPolicyInput,
     ) -> Result<RemoveAutoScalingPolicyOutput, RusotoError<RemoveAutoScalingPolicyError>> {
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("RemoveAutoScalingPolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ElasticMapReduce.RemoveAutoScalingPolicy");
@@ -4556,6 +7905,7 @@ impl Emr for EmrClient {
         input: RemoveTagsInput,
     ) -> Result<RemoveTagsOutput, RusotoError<RemoveTagsError>> {
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("RemoveTags");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ElasticMapReduce.RemoveTags");
@@ -4583,6 +7933,7 @@ impl Emr for EmrClient {
         input: RunJobFlowInput,
     ) -> Result<RunJobFlowOutput, RusotoError<RunJobFlowError>> {
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("RunJobFlow");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ElasticMapReduce.RunJobFlow");
@@ -4610,6 +7961,7 @@ impl Emr for EmrClient {
         input: SetTerminationProtectionInput,
     ) -> Result<(), RusotoError<SetTerminationProtectionError>> {
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("SetTerminationProtection");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ElasticMapReduce.SetTerminationProtection");
@@ -4637,6 +7989,7 @@ impl Emr for EmrClient {
         input: SetVisibleToAllUsersInput,
     ) -> Result<(), RusotoError<SetVisibleToAllUsersError>> {
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("SetVisibleToAllUsers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ElasticMapReduce.SetVisibleToAllUsers");
@@ -4664,6 +8017,7 @@ impl Emr for EmrClient {
         input: TerminateJobFlowsInput,
     ) -> Result<(), RusotoError<TerminateJobFlowsError>> {
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("TerminateJobFlows");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ElasticMapReduce.TerminateJobFlows");
@@ -4685,3 +8039,414 @@ impl Emr for EmrClient {
         }
     }
 }
+impl EmrClient {
+    /// Auto-paginating version of `list_bootstrap_actions`, streaming every page of results.
+    pub fn list_bootstrap_actions_pages(
+        &self,
+        input: ListBootstrapActionsInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListBootstrapActionsOutput, ListBootstrapActionsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EmrClient, input: ListBootstrapActionsInput| async move {
+                client.list_bootstrap_actions(input).await
+            },
+            |input: &ListBootstrapActionsInput, output: &ListBootstrapActionsOutput| {
+                Some(ListBootstrapActionsInput {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_bootstrap_actions`, streaming the `BootstrapActions` of every page of results.
+    pub fn list_bootstrap_actions_items(
+        &self,
+        input: ListBootstrapActionsInput,
+    ) -> rusoto_core::pagination::RusotoStream<Command, ListBootstrapActionsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_bootstrap_actions_pages(input),
+            |output: ListBootstrapActionsOutput| output.bootstrap_actions.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_clusters`, streaming every page of results.
+    pub fn list_clusters_pages(
+        &self,
+        input: ListClustersInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListClustersOutput, ListClustersError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EmrClient, input: ListClustersInput| async move {
+                client.list_clusters(input).await
+            },
+            |input: &ListClustersInput, output: &ListClustersOutput| {
+                Some(ListClustersInput {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_clusters`, streaming the `Clusters` of every page of results.
+    pub fn list_clusters_items(
+        &self,
+        input: ListClustersInput,
+    ) -> rusoto_core::pagination::RusotoStream<ClusterSummary, ListClustersError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_clusters_pages(input),
+            |output: ListClustersOutput| output.clusters.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_instance_fleets`, streaming every page of results.
+    pub fn list_instance_fleets_pages(
+        &self,
+        input: ListInstanceFleetsInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListInstanceFleetsOutput, ListInstanceFleetsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EmrClient, input: ListInstanceFleetsInput| async move {
+                client.list_instance_fleets(input).await
+            },
+            |input: &ListInstanceFleetsInput, output: &ListInstanceFleetsOutput| {
+                Some(ListInstanceFleetsInput {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_instance_fleets`, streaming the `InstanceFleets` of every page of results.
+    pub fn list_instance_fleets_items(
+        &self,
+        input: ListInstanceFleetsInput,
+    ) -> rusoto_core::pagination::RusotoStream<InstanceFleet, ListInstanceFleetsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_instance_fleets_pages(input),
+            |output: ListInstanceFleetsOutput| output.instance_fleets.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_instance_groups`, streaming every page of results.
+    pub fn list_instance_groups_pages(
+        &self,
+        input: ListInstanceGroupsInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListInstanceGroupsOutput, ListInstanceGroupsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EmrClient, input: ListInstanceGroupsInput| async move {
+                client.list_instance_groups(input).await
+            },
+            |input: &ListInstanceGroupsInput, output: &ListInstanceGroupsOutput| {
+                Some(ListInstanceGroupsInput {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_instance_groups`, streaming the `InstanceGroups` of every page of results.
+    pub fn list_instance_groups_items(
+        &self,
+        input: ListInstanceGroupsInput,
+    ) -> rusoto_core::pagination::RusotoStream<InstanceGroup, ListInstanceGroupsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_instance_groups_pages(input),
+            |output: ListInstanceGroupsOutput| output.instance_groups.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_instances`, streaming every page of results.
+    pub fn list_instances_pages(
+        &self,
+        input: ListInstancesInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListInstancesOutput, ListInstancesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EmrClient, input: ListInstancesInput| async move {
+                client.list_instances(input).await
+            },
+            |input: &ListInstancesInput, output: &ListInstancesOutput| {
+                Some(ListInstancesInput {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_instances`, streaming the `Instances` of every page of results.
+    pub fn list_instances_items(
+        &self,
+        input: ListInstancesInput,
+    ) -> rusoto_core::pagination::RusotoStream<Instance, ListInstancesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_instances_pages(input),
+            |output: ListInstancesOutput| output.instances.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_security_configurations`, streaming every page of results.
+    pub fn list_security_configurations_pages(
+        &self,
+        input: ListSecurityConfigurationsInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListSecurityConfigurationsOutput,
+        ListSecurityConfigurationsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EmrClient, input: ListSecurityConfigurationsInput| async move {
+                client.list_security_configurations(input).await
+            },
+            |_input: &ListSecurityConfigurationsInput,
+             output: &ListSecurityConfigurationsOutput| {
+                Some(ListSecurityConfigurationsInput {
+                    marker: Some(output.marker.clone()?),
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_security_configurations`, streaming the `SecurityConfigurations` of every page of results.
+    pub fn list_security_configurations_items(
+        &self,
+        input: ListSecurityConfigurationsInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        SecurityConfigurationSummary,
+        ListSecurityConfigurationsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_security_configurations_pages(input),
+            |output: ListSecurityConfigurationsOutput| {
+                output.security_configurations.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_steps`, streaming every page of results.
+    pub fn list_steps_pages(
+        &self,
+        input: ListStepsInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListStepsOutput, ListStepsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EmrClient, input: ListStepsInput| async move { client.list_steps(input).await },
+            |input: &ListStepsInput, output: &ListStepsOutput| {
+                Some(ListStepsInput {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_steps`, streaming the `Steps` of every page of results.
+    pub fn list_steps_items(
+        &self,
+        input: ListStepsInput,
+    ) -> rusoto_core::pagination::RusotoStream<StepSummary, ListStepsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_steps_pages(input),
+            |output: ListStepsOutput| output.steps.unwrap_or_default(),
+        )
+    }
+}
+impl EmrClient {
+    /// Waits until `describe_cluster` reports the `ClusterRunning` state, polling every 30 seconds up to 60 times.
+    pub async fn wait_until_cluster_running(
+        &self,
+        input: DescribeClusterInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeClusterError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(30), 60);
+        self.wait_until_cluster_running_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_cluster_running`, polling as often and as many times as `config` says.
+    pub async fn wait_until_cluster_running_with_config(
+        &self,
+        input: DescribeClusterInput,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeClusterError>> {
+        rusoto_core::waiter::wait(
+            "ClusterRunning",
+            &config,
+            || self.describe_cluster(input.clone()),
+            |result: &Result<DescribeClusterOutput, RusotoError<DescribeClusterError>>| match result
+            {
+                Ok(output)
+                    if output
+                        .cluster
+                        .iter()
+                        .flat_map(|v| v.status.iter())
+                        .flat_map(|v| v.state.iter())
+                        .any(|v| *v == ClusterState::Running) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if output
+                        .cluster
+                        .iter()
+                        .flat_map(|v| v.status.iter())
+                        .flat_map(|v| v.state.iter())
+                        .any(|v| *v == ClusterState::Waiting) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if output
+                        .cluster
+                        .iter()
+                        .flat_map(|v| v.status.iter())
+                        .flat_map(|v| v.state.iter())
+                        .any(|v| *v == ClusterState::Terminating) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .cluster
+                        .iter()
+                        .flat_map(|v| v.status.iter())
+                        .flat_map(|v| v.state.iter())
+                        .any(|v| *v == ClusterState::Terminated) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .cluster
+                        .iter()
+                        .flat_map(|v| v.status.iter())
+                        .flat_map(|v| v.state.iter())
+                        .any(|v| *v == ClusterState::TerminatedWithErrors) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+
+    /// Waits until `describe_cluster` reports the `ClusterTerminated` state, polling every 30 seconds up to 60 times.
+    pub async fn wait_until_cluster_terminated(
+        &self,
+        input: DescribeClusterInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeClusterError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(30), 60);
+        self.wait_until_cluster_terminated_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_cluster_terminated`, polling as often and as many times as `config` says.
+    pub async fn wait_until_cluster_terminated_with_config(
+        &self,
+        input: DescribeClusterInput,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeClusterError>> {
+        rusoto_core::waiter::wait(
+            "ClusterTerminated",
+            &config,
+            || self.describe_cluster(input.clone()),
+            |result: &Result<DescribeClusterOutput, RusotoError<DescribeClusterError>>| match result
+            {
+                Ok(output)
+                    if output
+                        .cluster
+                        .iter()
+                        .flat_map(|v| v.status.iter())
+                        .flat_map(|v| v.state.iter())
+                        .any(|v| *v == ClusterState::Terminated) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if output
+                        .cluster
+                        .iter()
+                        .flat_map(|v| v.status.iter())
+                        .flat_map(|v| v.state.iter())
+                        .any(|v| *v == ClusterState::TerminatedWithErrors) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+
+    /// Waits until `describe_step` reports the `StepComplete` state, polling every 30 seconds up to 60 times.
+    pub async fn wait_until_step_complete(
+        &self,
+        input: DescribeStepInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeStepError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(30), 60);
+        self.wait_until_step_complete_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_step_complete`, polling as often and as many times as `config` says.
+    pub async fn wait_until_step_complete_with_config(
+        &self,
+        input: DescribeStepInput,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeStepError>> {
+        rusoto_core::waiter::wait(
+            "StepComplete",
+            &config,
+            || self.describe_step(input.clone()),
+            |result: &Result<DescribeStepOutput, RusotoError<DescribeStepError>>| match result {
+                Ok(output)
+                    if output
+                        .step
+                        .iter()
+                        .flat_map(|v| v.status.iter())
+                        .flat_map(|v| v.state.iter())
+                        .any(|v| *v == StepState::Completed) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if output
+                        .step
+                        .iter()
+                        .flat_map(|v| v.status.iter())
+                        .flat_map(|v| v.state.iter())
+                        .any(|v| *v == StepState::Failed) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .step
+                        .iter()
+                        .flat_map(|v| v.status.iter())
+                        .flat_map(|v| v.state.iter())
+                        .any(|v| *v == StepState::Cancelled) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+}
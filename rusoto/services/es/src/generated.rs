@@ -181,7 +181,7 @@ pub struct CreateElasticsearchDomainRequest {
     /// <p>Map of <code>LogType</code> and <code>LogPublishingOption</code>, each containing options to publish a given type of Elasticsearch log.</p>
     #[serde(rename = "LogPublishingOptions")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub log_publishing_options: Option<::std::collections::HashMap<String, LogPublishingOption>>,
+    pub log_publishing_options: Option<::std::collections::HashMap<LogType, LogPublishingOption>>,
     /// <p>Specifies the NodeToNodeEncryptionOptions.</p>
     #[serde(rename = "NodeToNodeEncryptionOptions")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -225,6 +225,114 @@ pub struct DeleteElasticsearchDomainResponse {
     pub domain_status: Option<ElasticsearchDomainStatus>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DeploymentStatus {
+    /// `PENDING_UPDATE`
+    PendingUpdate,
+    /// `IN_PROGRESS`
+    InProgress,
+    /// `COMPLETED`
+    Completed,
+    /// `NOT_ELIGIBLE`
+    NotEligible,
+    /// `ELIGIBLE`
+    Eligible,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DeploymentStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeploymentStatus::PendingUpdate => "PENDING_UPDATE",
+            DeploymentStatus::InProgress => "IN_PROGRESS",
+            DeploymentStatus::Completed => "COMPLETED",
+            DeploymentStatus::NotEligible => "NOT_ELIGIBLE",
+            DeploymentStatus::Eligible => "ELIGIBLE",
+            DeploymentStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DeploymentStatus {
+    fn default() -> DeploymentStatus {
+        DeploymentStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DeploymentStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DeploymentStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DeploymentStatus {
+    fn from(value: &'a str) -> DeploymentStatus {
+        match value {
+            "PENDING_UPDATE" => DeploymentStatus::PendingUpdate,
+            "IN_PROGRESS" => DeploymentStatus::InProgress,
+            "COMPLETED" => DeploymentStatus::Completed,
+            "NOT_ELIGIBLE" => DeploymentStatus::NotEligible,
+            "ELIGIBLE" => DeploymentStatus::Eligible,
+            _ => DeploymentStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeploymentStatus {
+    fn from(value: String) -> DeploymentStatus {
+        match value.as_str() {
+            "PENDING_UPDATE" => DeploymentStatus::PendingUpdate,
+            "IN_PROGRESS" => DeploymentStatus::InProgress,
+            "COMPLETED" => DeploymentStatus::Completed,
+            "NOT_ELIGIBLE" => DeploymentStatus::NotEligible,
+            "ELIGIBLE" => DeploymentStatus::Eligible,
+            _ => DeploymentStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DeploymentStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DeploymentStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DeploymentStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DeploymentStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeploymentStatus {
+    fn deserialize<D>(deserializer: D) -> Result<DeploymentStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p> Container for the parameters to the <code>DescribeElasticsearchDomainConfig</code> operation. Specifies the domain name for which you want configuration information.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -292,7 +400,7 @@ pub struct DescribeElasticsearchInstanceTypeLimitsRequest {
     pub elasticsearch_version: String,
     /// <p> The instance type for an Elasticsearch cluster for which Elasticsearch <code> <a>Limits</a> </code> are needed. </p>
     #[serde(rename = "InstanceType")]
-    pub instance_type: String,
+    pub instance_type: ESPartitionInstanceType,
 }
 
 /// <p> Container for the parameters received from <code> <a>DescribeElasticsearchInstanceTypeLimits</a> </code> operation. </p>
@@ -379,7 +487,7 @@ pub struct DomainEndpointOptions {
     /// <p>Specify the TLS security policy that needs to be applied to the HTTPS endpoint of Elasticsearch domain. <br/> It can be one of the following values: <ul> <li><b>Policy-Min-TLS-1-0-2019-07: </b> TLS security policy which supports TLSv1.0 and higher.</li> <li><b>Policy-Min-TLS-1-2-2019-07: </b> TLS security policy which supports only TLSv1.2</li> </ul> </p>
     #[serde(rename = "TLSSecurityPolicy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub tls_security_policy: Option<String>,
+    pub tls_security_policy: Option<TLSSecurityPolicy>,
 }
 
 /// <p>The configured endpoint options for the domain and their current status.</p>
@@ -421,7 +529,7 @@ pub struct EBSOptions {
     /// <p> Specifies the volume type for EBS-based storage.</p>
     #[serde(rename = "VolumeType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub volume_type: Option<String>,
+    pub volume_type: Option<VolumeType>,
 }
 
 /// <p> Status of the EBS options for the specified Elasticsearch domain.</p>
@@ -436,6 +544,496 @@ pub struct EBSOptionsStatus {
     pub status: OptionStatus,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ESPartitionInstanceType {
+    /// `m3.medium.elasticsearch`
+    M3MediumElasticsearch,
+    /// `m3.large.elasticsearch`
+    M3LargeElasticsearch,
+    /// `m3.xlarge.elasticsearch`
+    M3XlargeElasticsearch,
+    /// `m3.2xlarge.elasticsearch`
+    M3_2xlargeElasticsearch,
+    /// `m4.large.elasticsearch`
+    M4LargeElasticsearch,
+    /// `m4.xlarge.elasticsearch`
+    M4XlargeElasticsearch,
+    /// `m4.2xlarge.elasticsearch`
+    M4_2xlargeElasticsearch,
+    /// `m4.4xlarge.elasticsearch`
+    M4_4xlargeElasticsearch,
+    /// `m4.10xlarge.elasticsearch`
+    M4_10xlargeElasticsearch,
+    /// `m5.large.elasticsearch`
+    M5LargeElasticsearch,
+    /// `m5.xlarge.elasticsearch`
+    M5XlargeElasticsearch,
+    /// `m5.2xlarge.elasticsearch`
+    M5_2xlargeElasticsearch,
+    /// `m5.4xlarge.elasticsearch`
+    M5_4xlargeElasticsearch,
+    /// `m5.12xlarge.elasticsearch`
+    M5_12xlargeElasticsearch,
+    /// `r5.large.elasticsearch`
+    R5LargeElasticsearch,
+    /// `r5.xlarge.elasticsearch`
+    R5XlargeElasticsearch,
+    /// `r5.2xlarge.elasticsearch`
+    R5_2xlargeElasticsearch,
+    /// `r5.4xlarge.elasticsearch`
+    R5_4xlargeElasticsearch,
+    /// `r5.12xlarge.elasticsearch`
+    R5_12xlargeElasticsearch,
+    /// `c5.large.elasticsearch`
+    C5LargeElasticsearch,
+    /// `c5.xlarge.elasticsearch`
+    C5XlargeElasticsearch,
+    /// `c5.2xlarge.elasticsearch`
+    C5_2xlargeElasticsearch,
+    /// `c5.4xlarge.elasticsearch`
+    C5_4xlargeElasticsearch,
+    /// `c5.9xlarge.elasticsearch`
+    C5_9xlargeElasticsearch,
+    /// `c5.18xlarge.elasticsearch`
+    C5_18xlargeElasticsearch,
+    /// `ultrawarm1.medium.elasticsearch`
+    Ultrawarm1MediumElasticsearch,
+    /// `ultrawarm1.large.elasticsearch`
+    Ultrawarm1LargeElasticsearch,
+    /// `t2.micro.elasticsearch`
+    T2MicroElasticsearch,
+    /// `t2.small.elasticsearch`
+    T2SmallElasticsearch,
+    /// `t2.medium.elasticsearch`
+    T2MediumElasticsearch,
+    /// `r3.large.elasticsearch`
+    R3LargeElasticsearch,
+    /// `r3.xlarge.elasticsearch`
+    R3XlargeElasticsearch,
+    /// `r3.2xlarge.elasticsearch`
+    R3_2xlargeElasticsearch,
+    /// `r3.4xlarge.elasticsearch`
+    R3_4xlargeElasticsearch,
+    /// `r3.8xlarge.elasticsearch`
+    R3_8xlargeElasticsearch,
+    /// `i2.xlarge.elasticsearch`
+    I2XlargeElasticsearch,
+    /// `i2.2xlarge.elasticsearch`
+    I2_2xlargeElasticsearch,
+    /// `d2.xlarge.elasticsearch`
+    D2XlargeElasticsearch,
+    /// `d2.2xlarge.elasticsearch`
+    D2_2xlargeElasticsearch,
+    /// `d2.4xlarge.elasticsearch`
+    D2_4xlargeElasticsearch,
+    /// `d2.8xlarge.elasticsearch`
+    D2_8xlargeElasticsearch,
+    /// `c4.large.elasticsearch`
+    C4LargeElasticsearch,
+    /// `c4.xlarge.elasticsearch`
+    C4XlargeElasticsearch,
+    /// `c4.2xlarge.elasticsearch`
+    C4_2xlargeElasticsearch,
+    /// `c4.4xlarge.elasticsearch`
+    C4_4xlargeElasticsearch,
+    /// `c4.8xlarge.elasticsearch`
+    C4_8xlargeElasticsearch,
+    /// `r4.large.elasticsearch`
+    R4LargeElasticsearch,
+    /// `r4.xlarge.elasticsearch`
+    R4XlargeElasticsearch,
+    /// `r4.2xlarge.elasticsearch`
+    R4_2xlargeElasticsearch,
+    /// `r4.4xlarge.elasticsearch`
+    R4_4xlargeElasticsearch,
+    /// `r4.8xlarge.elasticsearch`
+    R4_8xlargeElasticsearch,
+    /// `r4.16xlarge.elasticsearch`
+    R4_16xlargeElasticsearch,
+    /// `i3.large.elasticsearch`
+    I3LargeElasticsearch,
+    /// `i3.xlarge.elasticsearch`
+    I3XlargeElasticsearch,
+    /// `i3.2xlarge.elasticsearch`
+    I3_2xlargeElasticsearch,
+    /// `i3.4xlarge.elasticsearch`
+    I3_4xlargeElasticsearch,
+    /// `i3.8xlarge.elasticsearch`
+    I3_8xlargeElasticsearch,
+    /// `i3.16xlarge.elasticsearch`
+    I3_16xlargeElasticsearch,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ESPartitionInstanceType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ESPartitionInstanceType::M3MediumElasticsearch => "m3.medium.elasticsearch",
+            ESPartitionInstanceType::M3LargeElasticsearch => "m3.large.elasticsearch",
+            ESPartitionInstanceType::M3XlargeElasticsearch => "m3.xlarge.elasticsearch",
+            ESPartitionInstanceType::M3_2xlargeElasticsearch => "m3.2xlarge.elasticsearch",
+            ESPartitionInstanceType::M4LargeElasticsearch => "m4.large.elasticsearch",
+            ESPartitionInstanceType::M4XlargeElasticsearch => "m4.xlarge.elasticsearch",
+            ESPartitionInstanceType::M4_2xlargeElasticsearch => "m4.2xlarge.elasticsearch",
+            ESPartitionInstanceType::M4_4xlargeElasticsearch => "m4.4xlarge.elasticsearch",
+            ESPartitionInstanceType::M4_10xlargeElasticsearch => "m4.10xlarge.elasticsearch",
+            ESPartitionInstanceType::M5LargeElasticsearch => "m5.large.elasticsearch",
+            ESPartitionInstanceType::M5XlargeElasticsearch => "m5.xlarge.elasticsearch",
+            ESPartitionInstanceType::M5_2xlargeElasticsearch => "m5.2xlarge.elasticsearch",
+            ESPartitionInstanceType::M5_4xlargeElasticsearch => "m5.4xlarge.elasticsearch",
+            ESPartitionInstanceType::M5_12xlargeElasticsearch => "m5.12xlarge.elasticsearch",
+            ESPartitionInstanceType::R5LargeElasticsearch => "r5.large.elasticsearch",
+            ESPartitionInstanceType::R5XlargeElasticsearch => "r5.xlarge.elasticsearch",
+            ESPartitionInstanceType::R5_2xlargeElasticsearch => "r5.2xlarge.elasticsearch",
+            ESPartitionInstanceType::R5_4xlargeElasticsearch => "r5.4xlarge.elasticsearch",
+            ESPartitionInstanceType::R5_12xlargeElasticsearch => "r5.12xlarge.elasticsearch",
+            ESPartitionInstanceType::C5LargeElasticsearch => "c5.large.elasticsearch",
+            ESPartitionInstanceType::C5XlargeElasticsearch => "c5.xlarge.elasticsearch",
+            ESPartitionInstanceType::C5_2xlargeElasticsearch => "c5.2xlarge.elasticsearch",
+            ESPartitionInstanceType::C5_4xlargeElasticsearch => "c5.4xlarge.elasticsearch",
+            ESPartitionInstanceType::C5_9xlargeElasticsearch => "c5.9xlarge.elasticsearch",
+            ESPartitionInstanceType::C5_18xlargeElasticsearch => "c5.18xlarge.elasticsearch",
+            ESPartitionInstanceType::Ultrawarm1MediumElasticsearch => {
+                "ultrawarm1.medium.elasticsearch"
+            }
+            ESPartitionInstanceType::Ultrawarm1LargeElasticsearch => {
+                "ultrawarm1.large.elasticsearch"
+            }
+            ESPartitionInstanceType::T2MicroElasticsearch => "t2.micro.elasticsearch",
+            ESPartitionInstanceType::T2SmallElasticsearch => "t2.small.elasticsearch",
+            ESPartitionInstanceType::T2MediumElasticsearch => "t2.medium.elasticsearch",
+            ESPartitionInstanceType::R3LargeElasticsearch => "r3.large.elasticsearch",
+            ESPartitionInstanceType::R3XlargeElasticsearch => "r3.xlarge.elasticsearch",
+            ESPartitionInstanceType::R3_2xlargeElasticsearch => "r3.2xlarge.elasticsearch",
+            ESPartitionInstanceType::R3_4xlargeElasticsearch => "r3.4xlarge.elasticsearch",
+            ESPartitionInstanceType::R3_8xlargeElasticsearch => "r3.8xlarge.elasticsearch",
+            ESPartitionInstanceType::I2XlargeElasticsearch => "i2.xlarge.elasticsearch",
+            ESPartitionInstanceType::I2_2xlargeElasticsearch => "i2.2xlarge.elasticsearch",
+            ESPartitionInstanceType::D2XlargeElasticsearch => "d2.xlarge.elasticsearch",
+            ESPartitionInstanceType::D2_2xlargeElasticsearch => "d2.2xlarge.elasticsearch",
+            ESPartitionInstanceType::D2_4xlargeElasticsearch => "d2.4xlarge.elasticsearch",
+            ESPartitionInstanceType::D2_8xlargeElasticsearch => "d2.8xlarge.elasticsearch",
+            ESPartitionInstanceType::C4LargeElasticsearch => "c4.large.elasticsearch",
+            ESPartitionInstanceType::C4XlargeElasticsearch => "c4.xlarge.elasticsearch",
+            ESPartitionInstanceType::C4_2xlargeElasticsearch => "c4.2xlarge.elasticsearch",
+            ESPartitionInstanceType::C4_4xlargeElasticsearch => "c4.4xlarge.elasticsearch",
+            ESPartitionInstanceType::C4_8xlargeElasticsearch => "c4.8xlarge.elasticsearch",
+            ESPartitionInstanceType::R4LargeElasticsearch => "r4.large.elasticsearch",
+            ESPartitionInstanceType::R4XlargeElasticsearch => "r4.xlarge.elasticsearch",
+            ESPartitionInstanceType::R4_2xlargeElasticsearch => "r4.2xlarge.elasticsearch",
+            ESPartitionInstanceType::R4_4xlargeElasticsearch => "r4.4xlarge.elasticsearch",
+            ESPartitionInstanceType::R4_8xlargeElasticsearch => "r4.8xlarge.elasticsearch",
+            ESPartitionInstanceType::R4_16xlargeElasticsearch => "r4.16xlarge.elasticsearch",
+            ESPartitionInstanceType::I3LargeElasticsearch => "i3.large.elasticsearch",
+            ESPartitionInstanceType::I3XlargeElasticsearch => "i3.xlarge.elasticsearch",
+            ESPartitionInstanceType::I3_2xlargeElasticsearch => "i3.2xlarge.elasticsearch",
+            ESPartitionInstanceType::I3_4xlargeElasticsearch => "i3.4xlarge.elasticsearch",
+            ESPartitionInstanceType::I3_8xlargeElasticsearch => "i3.8xlarge.elasticsearch",
+            ESPartitionInstanceType::I3_16xlargeElasticsearch => "i3.16xlarge.elasticsearch",
+            ESPartitionInstanceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ESPartitionInstanceType {
+    fn default() -> ESPartitionInstanceType {
+        ESPartitionInstanceType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ESPartitionInstanceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ESPartitionInstanceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ESPartitionInstanceType {
+    fn from(value: &'a str) -> ESPartitionInstanceType {
+        match value {
+            "m3.medium.elasticsearch" => ESPartitionInstanceType::M3MediumElasticsearch,
+            "m3.large.elasticsearch" => ESPartitionInstanceType::M3LargeElasticsearch,
+            "m3.xlarge.elasticsearch" => ESPartitionInstanceType::M3XlargeElasticsearch,
+            "m3.2xlarge.elasticsearch" => ESPartitionInstanceType::M3_2xlargeElasticsearch,
+            "m4.large.elasticsearch" => ESPartitionInstanceType::M4LargeElasticsearch,
+            "m4.xlarge.elasticsearch" => ESPartitionInstanceType::M4XlargeElasticsearch,
+            "m4.2xlarge.elasticsearch" => ESPartitionInstanceType::M4_2xlargeElasticsearch,
+            "m4.4xlarge.elasticsearch" => ESPartitionInstanceType::M4_4xlargeElasticsearch,
+            "m4.10xlarge.elasticsearch" => ESPartitionInstanceType::M4_10xlargeElasticsearch,
+            "m5.large.elasticsearch" => ESPartitionInstanceType::M5LargeElasticsearch,
+            "m5.xlarge.elasticsearch" => ESPartitionInstanceType::M5XlargeElasticsearch,
+            "m5.2xlarge.elasticsearch" => ESPartitionInstanceType::M5_2xlargeElasticsearch,
+            "m5.4xlarge.elasticsearch" => ESPartitionInstanceType::M5_4xlargeElasticsearch,
+            "m5.12xlarge.elasticsearch" => ESPartitionInstanceType::M5_12xlargeElasticsearch,
+            "r5.large.elasticsearch" => ESPartitionInstanceType::R5LargeElasticsearch,
+            "r5.xlarge.elasticsearch" => ESPartitionInstanceType::R5XlargeElasticsearch,
+            "r5.2xlarge.elasticsearch" => ESPartitionInstanceType::R5_2xlargeElasticsearch,
+            "r5.4xlarge.elasticsearch" => ESPartitionInstanceType::R5_4xlargeElasticsearch,
+            "r5.12xlarge.elasticsearch" => ESPartitionInstanceType::R5_12xlargeElasticsearch,
+            "c5.large.elasticsearch" => ESPartitionInstanceType::C5LargeElasticsearch,
+            "c5.xlarge.elasticsearch" => ESPartitionInstanceType::C5XlargeElasticsearch,
+            "c5.2xlarge.elasticsearch" => ESPartitionInstanceType::C5_2xlargeElasticsearch,
+            "c5.4xlarge.elasticsearch" => ESPartitionInstanceType::C5_4xlargeElasticsearch,
+            "c5.9xlarge.elasticsearch" => ESPartitionInstanceType::C5_9xlargeElasticsearch,
+            "c5.18xlarge.elasticsearch" => ESPartitionInstanceType::C5_18xlargeElasticsearch,
+            "ultrawarm1.medium.elasticsearch" => {
+                ESPartitionInstanceType::Ultrawarm1MediumElasticsearch
+            }
+            "ultrawarm1.large.elasticsearch" => {
+                ESPartitionInstanceType::Ultrawarm1LargeElasticsearch
+            }
+            "t2.micro.elasticsearch" => ESPartitionInstanceType::T2MicroElasticsearch,
+            "t2.small.elasticsearch" => ESPartitionInstanceType::T2SmallElasticsearch,
+            "t2.medium.elasticsearch" => ESPartitionInstanceType::T2MediumElasticsearch,
+            "r3.large.elasticsearch" => ESPartitionInstanceType::R3LargeElasticsearch,
+            "r3.xlarge.elasticsearch" => ESPartitionInstanceType::R3XlargeElasticsearch,
+            "r3.2xlarge.elasticsearch" => ESPartitionInstanceType::R3_2xlargeElasticsearch,
+            "r3.4xlarge.elasticsearch" => ESPartitionInstanceType::R3_4xlargeElasticsearch,
+            "r3.8xlarge.elasticsearch" => ESPartitionInstanceType::R3_8xlargeElasticsearch,
+            "i2.xlarge.elasticsearch" => ESPartitionInstanceType::I2XlargeElasticsearch,
+            "i2.2xlarge.elasticsearch" => ESPartitionInstanceType::I2_2xlargeElasticsearch,
+            "d2.xlarge.elasticsearch" => ESPartitionInstanceType::D2XlargeElasticsearch,
+            "d2.2xlarge.elasticsearch" => ESPartitionInstanceType::D2_2xlargeElasticsearch,
+            "d2.4xlarge.elasticsearch" => ESPartitionInstanceType::D2_4xlargeElasticsearch,
+            "d2.8xlarge.elasticsearch" => ESPartitionInstanceType::D2_8xlargeElasticsearch,
+            "c4.large.elasticsearch" => ESPartitionInstanceType::C4LargeElasticsearch,
+            "c4.xlarge.elasticsearch" => ESPartitionInstanceType::C4XlargeElasticsearch,
+            "c4.2xlarge.elasticsearch" => ESPartitionInstanceType::C4_2xlargeElasticsearch,
+            "c4.4xlarge.elasticsearch" => ESPartitionInstanceType::C4_4xlargeElasticsearch,
+            "c4.8xlarge.elasticsearch" => ESPartitionInstanceType::C4_8xlargeElasticsearch,
+            "r4.large.elasticsearch" => ESPartitionInstanceType::R4LargeElasticsearch,
+            "r4.xlarge.elasticsearch" => ESPartitionInstanceType::R4XlargeElasticsearch,
+            "r4.2xlarge.elasticsearch" => ESPartitionInstanceType::R4_2xlargeElasticsearch,
+            "r4.4xlarge.elasticsearch" => ESPartitionInstanceType::R4_4xlargeElasticsearch,
+            "r4.8xlarge.elasticsearch" => ESPartitionInstanceType::R4_8xlargeElasticsearch,
+            "r4.16xlarge.elasticsearch" => ESPartitionInstanceType::R4_16xlargeElasticsearch,
+            "i3.large.elasticsearch" => ESPartitionInstanceType::I3LargeElasticsearch,
+            "i3.xlarge.elasticsearch" => ESPartitionInstanceType::I3XlargeElasticsearch,
+            "i3.2xlarge.elasticsearch" => ESPartitionInstanceType::I3_2xlargeElasticsearch,
+            "i3.4xlarge.elasticsearch" => ESPartitionInstanceType::I3_4xlargeElasticsearch,
+            "i3.8xlarge.elasticsearch" => ESPartitionInstanceType::I3_8xlargeElasticsearch,
+            "i3.16xlarge.elasticsearch" => ESPartitionInstanceType::I3_16xlargeElasticsearch,
+            _ => ESPartitionInstanceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ESPartitionInstanceType {
+    fn from(value: String) -> ESPartitionInstanceType {
+        match value.as_str() {
+            "m3.medium.elasticsearch" => ESPartitionInstanceType::M3MediumElasticsearch,
+            "m3.large.elasticsearch" => ESPartitionInstanceType::M3LargeElasticsearch,
+            "m3.xlarge.elasticsearch" => ESPartitionInstanceType::M3XlargeElasticsearch,
+            "m3.2xlarge.elasticsearch" => ESPartitionInstanceType::M3_2xlargeElasticsearch,
+            "m4.large.elasticsearch" => ESPartitionInstanceType::M4LargeElasticsearch,
+            "m4.xlarge.elasticsearch" => ESPartitionInstanceType::M4XlargeElasticsearch,
+            "m4.2xlarge.elasticsearch" => ESPartitionInstanceType::M4_2xlargeElasticsearch,
+            "m4.4xlarge.elasticsearch" => ESPartitionInstanceType::M4_4xlargeElasticsearch,
+            "m4.10xlarge.elasticsearch" => ESPartitionInstanceType::M4_10xlargeElasticsearch,
+            "m5.large.elasticsearch" => ESPartitionInstanceType::M5LargeElasticsearch,
+            "m5.xlarge.elasticsearch" => ESPartitionInstanceType::M5XlargeElasticsearch,
+            "m5.2xlarge.elasticsearch" => ESPartitionInstanceType::M5_2xlargeElasticsearch,
+            "m5.4xlarge.elasticsearch" => ESPartitionInstanceType::M5_4xlargeElasticsearch,
+            "m5.12xlarge.elasticsearch" => ESPartitionInstanceType::M5_12xlargeElasticsearch,
+            "r5.large.elasticsearch" => ESPartitionInstanceType::R5LargeElasticsearch,
+            "r5.xlarge.elasticsearch" => ESPartitionInstanceType::R5XlargeElasticsearch,
+            "r5.2xlarge.elasticsearch" => ESPartitionInstanceType::R5_2xlargeElasticsearch,
+            "r5.4xlarge.elasticsearch" => ESPartitionInstanceType::R5_4xlargeElasticsearch,
+            "r5.12xlarge.elasticsearch" => ESPartitionInstanceType::R5_12xlargeElasticsearch,
+            "c5.large.elasticsearch" => ESPartitionInstanceType::C5LargeElasticsearch,
+            "c5.xlarge.elasticsearch" => ESPartitionInstanceType::C5XlargeElasticsearch,
+            "c5.2xlarge.elasticsearch" => ESPartitionInstanceType::C5_2xlargeElasticsearch,
+            "c5.4xlarge.elasticsearch" => ESPartitionInstanceType::C5_4xlargeElasticsearch,
+            "c5.9xlarge.elasticsearch" => ESPartitionInstanceType::C5_9xlargeElasticsearch,
+            "c5.18xlarge.elasticsearch" => ESPartitionInstanceType::C5_18xlargeElasticsearch,
+            "ultrawarm1.medium.elasticsearch" => {
+                ESPartitionInstanceType::Ultrawarm1MediumElasticsearch
+            }
+            "ultrawarm1.large.elasticsearch" => {
+                ESPartitionInstanceType::Ultrawarm1LargeElasticsearch
+            }
+            "t2.micro.elasticsearch" => ESPartitionInstanceType::T2MicroElasticsearch,
+            "t2.small.elasticsearch" => ESPartitionInstanceType::T2SmallElasticsearch,
+            "t2.medium.elasticsearch" => ESPartitionInstanceType::T2MediumElasticsearch,
+            "r3.large.elasticsearch" => ESPartitionInstanceType::R3LargeElasticsearch,
+            "r3.xlarge.elasticsearch" => ESPartitionInstanceType::R3XlargeElasticsearch,
+            "r3.2xlarge.elasticsearch" => ESPartitionInstanceType::R3_2xlargeElasticsearch,
+            "r3.4xlarge.elasticsearch" => ESPartitionInstanceType::R3_4xlargeElasticsearch,
+            "r3.8xlarge.elasticsearch" => ESPartitionInstanceType::R3_8xlargeElasticsearch,
+            "i2.xlarge.elasticsearch" => ESPartitionInstanceType::I2XlargeElasticsearch,
+            "i2.2xlarge.elasticsearch" => ESPartitionInstanceType::I2_2xlargeElasticsearch,
+            "d2.xlarge.elasticsearch" => ESPartitionInstanceType::D2XlargeElasticsearch,
+            "d2.2xlarge.elasticsearch" => ESPartitionInstanceType::D2_2xlargeElasticsearch,
+            "d2.4xlarge.elasticsearch" => ESPartitionInstanceType::D2_4xlargeElasticsearch,
+            "d2.8xlarge.elasticsearch" => ESPartitionInstanceType::D2_8xlargeElasticsearch,
+            "c4.large.elasticsearch" => ESPartitionInstanceType::C4LargeElasticsearch,
+            "c4.xlarge.elasticsearch" => ESPartitionInstanceType::C4XlargeElasticsearch,
+            "c4.2xlarge.elasticsearch" => ESPartitionInstanceType::C4_2xlargeElasticsearch,
+            "c4.4xlarge.elasticsearch" => ESPartitionInstanceType::C4_4xlargeElasticsearch,
+            "c4.8xlarge.elasticsearch" => ESPartitionInstanceType::C4_8xlargeElasticsearch,
+            "r4.large.elasticsearch" => ESPartitionInstanceType::R4LargeElasticsearch,
+            "r4.xlarge.elasticsearch" => ESPartitionInstanceType::R4XlargeElasticsearch,
+            "r4.2xlarge.elasticsearch" => ESPartitionInstanceType::R4_2xlargeElasticsearch,
+            "r4.4xlarge.elasticsearch" => ESPartitionInstanceType::R4_4xlargeElasticsearch,
+            "r4.8xlarge.elasticsearch" => ESPartitionInstanceType::R4_8xlargeElasticsearch,
+            "r4.16xlarge.elasticsearch" => ESPartitionInstanceType::R4_16xlargeElasticsearch,
+            "i3.large.elasticsearch" => ESPartitionInstanceType::I3LargeElasticsearch,
+            "i3.xlarge.elasticsearch" => ESPartitionInstanceType::I3XlargeElasticsearch,
+            "i3.2xlarge.elasticsearch" => ESPartitionInstanceType::I3_2xlargeElasticsearch,
+            "i3.4xlarge.elasticsearch" => ESPartitionInstanceType::I3_4xlargeElasticsearch,
+            "i3.8xlarge.elasticsearch" => ESPartitionInstanceType::I3_8xlargeElasticsearch,
+            "i3.16xlarge.elasticsearch" => ESPartitionInstanceType::I3_16xlargeElasticsearch,
+            _ => ESPartitionInstanceType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ESPartitionInstanceType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ESPartitionInstanceType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ESPartitionInstanceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ESPartitionInstanceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ESPartitionInstanceType {
+    fn deserialize<D>(deserializer: D) -> Result<ESPartitionInstanceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ESWarmPartitionInstanceType {
+    /// `ultrawarm1.medium.elasticsearch`
+    Ultrawarm1MediumElasticsearch,
+    /// `ultrawarm1.large.elasticsearch`
+    Ultrawarm1LargeElasticsearch,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ESWarmPartitionInstanceType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ESWarmPartitionInstanceType::Ultrawarm1MediumElasticsearch => {
+                "ultrawarm1.medium.elasticsearch"
+            }
+            ESWarmPartitionInstanceType::Ultrawarm1LargeElasticsearch => {
+                "ultrawarm1.large.elasticsearch"
+            }
+            ESWarmPartitionInstanceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ESWarmPartitionInstanceType {
+    fn default() -> ESWarmPartitionInstanceType {
+        ESWarmPartitionInstanceType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ESWarmPartitionInstanceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ESWarmPartitionInstanceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ESWarmPartitionInstanceType {
+    fn from(value: &'a str) -> ESWarmPartitionInstanceType {
+        match value {
+            "ultrawarm1.medium.elasticsearch" => {
+                ESWarmPartitionInstanceType::Ultrawarm1MediumElasticsearch
+            }
+            "ultrawarm1.large.elasticsearch" => {
+                ESWarmPartitionInstanceType::Ultrawarm1LargeElasticsearch
+            }
+            _ => ESWarmPartitionInstanceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ESWarmPartitionInstanceType {
+    fn from(value: String) -> ESWarmPartitionInstanceType {
+        match value.as_str() {
+            "ultrawarm1.medium.elasticsearch" => {
+                ESWarmPartitionInstanceType::Ultrawarm1MediumElasticsearch
+            }
+            "ultrawarm1.large.elasticsearch" => {
+                ESWarmPartitionInstanceType::Ultrawarm1LargeElasticsearch
+            }
+            _ => ESWarmPartitionInstanceType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ESWarmPartitionInstanceType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ESWarmPartitionInstanceType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ESWarmPartitionInstanceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ESWarmPartitionInstanceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ESWarmPartitionInstanceType {
+    fn deserialize<D>(deserializer: D) -> Result<ESWarmPartitionInstanceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Specifies the configuration for the domain cluster, such as the type and number of instances.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ElasticsearchClusterConfig {
@@ -450,7 +1048,7 @@ pub struct ElasticsearchClusterConfig {
     /// <p>The instance type for a dedicated master node.</p>
     #[serde(rename = "DedicatedMasterType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub dedicated_master_type: Option<String>,
+    pub dedicated_master_type: Option<ESPartitionInstanceType>,
     /// <p>The number of instances in the specified domain cluster.</p>
     #[serde(rename = "InstanceCount")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -458,7 +1056,7 @@ pub struct ElasticsearchClusterConfig {
     /// <p>The instance type for an Elasticsearch cluster. UltraWarm instance types are not supported for data instances.</p>
     #[serde(rename = "InstanceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub instance_type: Option<String>,
+    pub instance_type: Option<ESPartitionInstanceType>,
     /// <p>The number of warm nodes in the cluster.</p>
     #[serde(rename = "WarmCount")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -470,7 +1068,7 @@ pub struct ElasticsearchClusterConfig {
     /// <p>The instance type for the Elasticsearch cluster's warm nodes.</p>
     #[serde(rename = "WarmType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub warm_type: Option<String>,
+    pub warm_type: Option<ESWarmPartitionInstanceType>,
     /// <p>Specifies the zone awareness configuration for a domain when zone awareness is enabled.</p>
     #[serde(rename = "ZoneAwarenessConfig")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -609,7 +1207,7 @@ pub struct ElasticsearchDomainStatus {
     /// <p>Log publishing options for the given domain.</p>
     #[serde(rename = "LogPublishingOptions")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub log_publishing_options: Option<::std::collections::HashMap<String, LogPublishingOption>>,
+    pub log_publishing_options: Option<::std::collections::HashMap<LogType, LogPublishingOption>>,
     /// <p>Specifies the status of the <code>NodeToNodeEncryptionOptions</code>.</p>
     #[serde(rename = "NodeToNodeEncryptionOptions")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -735,7 +1333,7 @@ pub struct GetUpgradeStatusResponse {
     /// <p> One of 4 statuses that a step can go through returned as part of the <code> <a>GetUpgradeStatusResponse</a> </code> object. The status can take one of the following values: <ul> <li>In Progress</li> <li>Succeeded</li> <li>Succeeded with Issues</li> <li>Failed</li> </ul> </p>
     #[serde(rename = "StepStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub step_status: Option<String>,
+    pub step_status: Option<UpgradeStatus>,
     /// <p>A string that describes the update briefly</p>
     #[serde(rename = "UpgradeName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -743,7 +1341,7 @@ pub struct GetUpgradeStatusResponse {
     /// <p> Represents one of 3 steps that an Upgrade or Upgrade Eligibility Check does through: <ul> <li>PreUpgradeCheck</li> <li>Snapshot</li> <li>Upgrade</li> </ul> </p>
     #[serde(rename = "UpgradeStep")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub upgrade_step: Option<String>,
+    pub upgrade_step: Option<UpgradeStep>,
 }
 
 /// <p> InstanceCountLimits represents the limits on number of instances that be created in Amazon Elasticsearch for given InstanceType. </p>
@@ -822,7 +1420,7 @@ pub struct ListElasticsearchInstanceTypesResponse {
     /// <p> List of instance types supported by Amazon Elasticsearch service for given <code> <a>ElasticsearchVersion</a> </code> </p>
     #[serde(rename = "ElasticsearchInstanceTypes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub elasticsearch_instance_types: Option<Vec<String>>,
+    pub elasticsearch_instance_types: Option<Vec<ESPartitionInstanceType>>,
     /// <p>In case if there are more results available NextToken would be present, make further request to the same API with received NextToken to paginate remaining results. </p>
     #[serde(rename = "NextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -892,13 +1490,117 @@ pub struct LogPublishingOptionsStatus {
     /// <p>The log publishing options configured for the Elasticsearch domain.</p>
     #[serde(rename = "Options")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub options: Option<::std::collections::HashMap<String, LogPublishingOption>>,
+    pub options: Option<::std::collections::HashMap<LogType, LogPublishingOption>>,
     /// <p>The status of the log publishing options for the Elasticsearch domain. See <code>OptionStatus</code> for the status information that's included. </p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status: Option<OptionStatus>,
 }
 
+/// <p>Type of Log File, it can be one of the following: <ul> <li>INDEX_SLOW_LOGS: Index slow logs contain insert requests that took more time than configured index query log threshold to execute.</li> <li>SEARCH_SLOW_LOGS: Search slow logs contain search queries that took more time than configured search query log threshold to execute.</li> <li>ES_APPLICATION_LOGS: Elasticsearch application logs contain information about errors and warnings raised during the operation of the service and can be useful for troubleshooting.</li> <li>AUDIT_LOGS: Audit logs contain records of user requests for access from the domain.</li> </ul> </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LogType {
+    /// `INDEX_SLOW_LOGS`
+    IndexSlowLogs,
+    /// `SEARCH_SLOW_LOGS`
+    SearchSlowLogs,
+    /// `ES_APPLICATION_LOGS`
+    EsApplicationLogs,
+    /// `AUDIT_LOGS`
+    AuditLogs,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LogType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LogType::IndexSlowLogs => "INDEX_SLOW_LOGS",
+            LogType::SearchSlowLogs => "SEARCH_SLOW_LOGS",
+            LogType::EsApplicationLogs => "ES_APPLICATION_LOGS",
+            LogType::AuditLogs => "AUDIT_LOGS",
+            LogType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LogType {
+    fn default() -> LogType {
+        LogType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LogType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LogType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LogType {
+    fn from(value: &'a str) -> LogType {
+        match value {
+            "INDEX_SLOW_LOGS" => LogType::IndexSlowLogs,
+            "SEARCH_SLOW_LOGS" => LogType::SearchSlowLogs,
+            "ES_APPLICATION_LOGS" => LogType::EsApplicationLogs,
+            "AUDIT_LOGS" => LogType::AuditLogs,
+            _ => LogType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LogType {
+    fn from(value: String) -> LogType {
+        match value.as_str() {
+            "INDEX_SLOW_LOGS" => LogType::IndexSlowLogs,
+            "SEARCH_SLOW_LOGS" => LogType::SearchSlowLogs,
+            "ES_APPLICATION_LOGS" => LogType::EsApplicationLogs,
+            "AUDIT_LOGS" => LogType::AuditLogs,
+            _ => LogType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LogType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LogType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LogType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LogType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LogType {
+    fn deserialize<D>(deserializer: D) -> Result<LogType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Specifies the node-to-node encryption options.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct NodeToNodeEncryptionOptions {
@@ -920,23 +1622,132 @@ pub struct NodeToNodeEncryptionOptionsStatus {
     pub status: OptionStatus,
 }
 
+/// <p>The state of a requested change. One of the following:</p> <ul> <li>Processing: The request change is still in-process.</li> <li>Active: The request change is processed and deployed to the Elasticsearch domain.</li> </ul>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OptionState {
+    /// `RequiresIndexDocuments`
+    RequiresIndexDocuments,
+    /// `Processing`
+    Processing,
+    /// `Active`
+    Active,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl OptionState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OptionState::RequiresIndexDocuments => "RequiresIndexDocuments",
+            OptionState::Processing => "Processing",
+            OptionState::Active => "Active",
+            OptionState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for OptionState {
+    fn default() -> OptionState {
+        OptionState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for OptionState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for OptionState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for OptionState {
+    fn from(value: &'a str) -> OptionState {
+        match value {
+            "RequiresIndexDocuments" => OptionState::RequiresIndexDocuments,
+            "Processing" => OptionState::Processing,
+            "Active" => OptionState::Active,
+            _ => OptionState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OptionState {
+    fn from(value: String) -> OptionState {
+        match value.as_str() {
+            "RequiresIndexDocuments" => OptionState::RequiresIndexDocuments,
+            "Processing" => OptionState::Processing,
+            "Active" => OptionState::Active,
+            _ => OptionState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for OptionState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<OptionState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OptionState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for OptionState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OptionState {
+    fn deserialize<D>(deserializer: D) -> Result<OptionState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Provides the current status of the entity.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct OptionStatus {
     /// <p>Timestamp which tells the creation date for the entity.</p>
     #[serde(rename = "CreationDate")]
-    pub creation_date: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub creation_date: ::chrono::DateTime<::chrono::Utc>,
     /// <p>Indicates whether the Elasticsearch domain is being deleted.</p>
     #[serde(rename = "PendingDeletion")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub pending_deletion: Option<bool>,
     /// <p>Provides the <code>OptionState</code> for the Elasticsearch domain.</p>
     #[serde(rename = "State")]
-    pub state: String,
+    pub state: OptionState,
     /// <p>Timestamp which tells the last updated time for the entity.</p>
     #[serde(rename = "UpdateDate")]
-    pub update_date: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub update_date: ::chrono::DateTime<::chrono::Utc>,
     /// <p>Specifies the latest version for the entity.</p>
     #[serde(rename = "UpdateVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1018,7 +1829,7 @@ pub struct ReservedElasticsearchInstance {
     /// <p>The Elasticsearch instance type offered by the reserved instance offering.</p>
     #[serde(rename = "ElasticsearchInstanceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub elasticsearch_instance_type: Option<String>,
+    pub elasticsearch_instance_type: Option<ESPartitionInstanceType>,
     /// <p>The upfront fixed charge you will paid to purchase the specific reserved Elasticsearch instance offering. </p>
     #[serde(rename = "FixedPrice")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1026,7 +1837,7 @@ pub struct ReservedElasticsearchInstance {
     /// <p>The payment option as defined in the reserved Elasticsearch instance offering.</p>
     #[serde(rename = "PaymentOption")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub payment_option: Option<String>,
+    pub payment_option: Option<ReservedElasticsearchInstancePaymentOption>,
     /// <p>The charge to your account regardless of whether you are creating any domains using the instance offering.</p>
     #[serde(rename = "RecurringCharges")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1045,8 +1856,13 @@ pub struct ReservedElasticsearchInstance {
     pub reserved_elasticsearch_instance_offering_id: Option<String>,
     /// <p>The time the reservation started.</p>
     #[serde(rename = "StartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The state of the reserved Elasticsearch instance.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1072,7 +1888,7 @@ pub struct ReservedElasticsearchInstanceOffering {
     /// <p>The Elasticsearch instance type offered by the reserved instance offering.</p>
     #[serde(rename = "ElasticsearchInstanceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub elasticsearch_instance_type: Option<String>,
+    pub elasticsearch_instance_type: Option<ESPartitionInstanceType>,
     /// <p>The upfront fixed charge you will pay to purchase the specific reserved Elasticsearch instance offering. </p>
     #[serde(rename = "FixedPrice")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1080,7 +1896,7 @@ pub struct ReservedElasticsearchInstanceOffering {
     /// <p>Payment option for the reserved Elasticsearch instance offering</p>
     #[serde(rename = "PaymentOption")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub payment_option: Option<String>,
+    pub payment_option: Option<ReservedElasticsearchInstancePaymentOption>,
     /// <p>The charge to your account regardless of whether you are creating any domains using the instance offering.</p>
     #[serde(rename = "RecurringCharges")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1095,14 +1911,119 @@ pub struct ReservedElasticsearchInstanceOffering {
     pub usage_price: Option<f64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ReservedElasticsearchInstancePaymentOption {
+    /// `ALL_UPFRONT`
+    AllUpfront,
+    /// `PARTIAL_UPFRONT`
+    PartialUpfront,
+    /// `NO_UPFRONT`
+    NoUpfront,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ReservedElasticsearchInstancePaymentOption {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReservedElasticsearchInstancePaymentOption::AllUpfront => "ALL_UPFRONT",
+            ReservedElasticsearchInstancePaymentOption::PartialUpfront => "PARTIAL_UPFRONT",
+            ReservedElasticsearchInstancePaymentOption::NoUpfront => "NO_UPFRONT",
+            ReservedElasticsearchInstancePaymentOption::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ReservedElasticsearchInstancePaymentOption {
+    fn default() -> ReservedElasticsearchInstancePaymentOption {
+        ReservedElasticsearchInstancePaymentOption::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ReservedElasticsearchInstancePaymentOption {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ReservedElasticsearchInstancePaymentOption {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ReservedElasticsearchInstancePaymentOption {
+    fn from(value: &'a str) -> ReservedElasticsearchInstancePaymentOption {
+        match value {
+            "ALL_UPFRONT" => ReservedElasticsearchInstancePaymentOption::AllUpfront,
+            "PARTIAL_UPFRONT" => ReservedElasticsearchInstancePaymentOption::PartialUpfront,
+            "NO_UPFRONT" => ReservedElasticsearchInstancePaymentOption::NoUpfront,
+            _ => ReservedElasticsearchInstancePaymentOption::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReservedElasticsearchInstancePaymentOption {
+    fn from(value: String) -> ReservedElasticsearchInstancePaymentOption {
+        match value.as_str() {
+            "ALL_UPFRONT" => ReservedElasticsearchInstancePaymentOption::AllUpfront,
+            "PARTIAL_UPFRONT" => ReservedElasticsearchInstancePaymentOption::PartialUpfront,
+            "NO_UPFRONT" => ReservedElasticsearchInstancePaymentOption::NoUpfront,
+            _ => ReservedElasticsearchInstancePaymentOption::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ReservedElasticsearchInstancePaymentOption {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ReservedElasticsearchInstancePaymentOption, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ReservedElasticsearchInstancePaymentOption {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ReservedElasticsearchInstancePaymentOption {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ReservedElasticsearchInstancePaymentOption {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> Result<ReservedElasticsearchInstancePaymentOption, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The current options of an Elasticsearch domain service software options.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ServiceSoftwareOptions {
     /// <p>Timestamp, in Epoch time, until which you can manually request a service software update. After this date, we automatically update your service software.</p>
     #[serde(rename = "AutomatedUpdateDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub automated_update_date: Option<f64>,
+    pub automated_update_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><code>True</code> if you are able to cancel your service software version update. <code>False</code> if you are not able to cancel your service software version. </p>
     #[serde(rename = "Cancellable")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1126,7 +2047,7 @@ pub struct ServiceSoftwareOptions {
     /// <p>The status of your service software update. This field can take the following values: <code>ELIGIBLE</code>, <code>PENDING_UPDATE</code>, <code>IN_PROGRESS</code>, <code>COMPLETED</code>, and <code>NOT_ELIGIBLE</code>.</p>
     #[serde(rename = "UpdateStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub update_status: Option<String>,
+    pub update_status: Option<DeploymentStatus>,
 }
 
 /// <p>Specifies the time, in UTC format, when the service takes a daily automated snapshot of the specified Elasticsearch domain. Default value is <code>0</code> hours.</p>
@@ -1199,6 +2120,104 @@ pub struct StorageTypeLimit {
     pub limit_values: Option<Vec<String>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TLSSecurityPolicy {
+    /// `Policy-Min-TLS-1-0-2019-07`
+    PolicyMinTls1_0_2019_07,
+    /// `Policy-Min-TLS-1-2-2019-07`
+    PolicyMinTls1_2_2019_07,
+    /// `Policy-Min-TLS-1-2-PFS-2023-10`
+    PolicyMinTls1_2Pfs2023_10,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TLSSecurityPolicy {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TLSSecurityPolicy::PolicyMinTls1_0_2019_07 => "Policy-Min-TLS-1-0-2019-07",
+            TLSSecurityPolicy::PolicyMinTls1_2_2019_07 => "Policy-Min-TLS-1-2-2019-07",
+            TLSSecurityPolicy::PolicyMinTls1_2Pfs2023_10 => "Policy-Min-TLS-1-2-PFS-2023-10",
+            TLSSecurityPolicy::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TLSSecurityPolicy {
+    fn default() -> TLSSecurityPolicy {
+        TLSSecurityPolicy::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TLSSecurityPolicy {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TLSSecurityPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TLSSecurityPolicy {
+    fn from(value: &'a str) -> TLSSecurityPolicy {
+        match value {
+            "Policy-Min-TLS-1-0-2019-07" => TLSSecurityPolicy::PolicyMinTls1_0_2019_07,
+            "Policy-Min-TLS-1-2-2019-07" => TLSSecurityPolicy::PolicyMinTls1_2_2019_07,
+            "Policy-Min-TLS-1-2-PFS-2023-10" => TLSSecurityPolicy::PolicyMinTls1_2Pfs2023_10,
+            _ => TLSSecurityPolicy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TLSSecurityPolicy {
+    fn from(value: String) -> TLSSecurityPolicy {
+        match value.as_str() {
+            "Policy-Min-TLS-1-0-2019-07" => TLSSecurityPolicy::PolicyMinTls1_0_2019_07,
+            "Policy-Min-TLS-1-2-2019-07" => TLSSecurityPolicy::PolicyMinTls1_2_2019_07,
+            "Policy-Min-TLS-1-2-PFS-2023-10" => TLSSecurityPolicy::PolicyMinTls1_2Pfs2023_10,
+            _ => TLSSecurityPolicy::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TLSSecurityPolicy {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TLSSecurityPolicy, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TLSSecurityPolicy {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TLSSecurityPolicy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TLSSecurityPolicy {
+    fn deserialize<D>(deserializer: D) -> Result<TLSSecurityPolicy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Specifies a key value pair for a resource tag.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Tag {
@@ -1244,7 +2263,7 @@ pub struct UpdateElasticsearchDomainConfigRequest {
     /// <p>Map of <code>LogType</code> and <code>LogPublishingOption</code>, each containing options to publish a given type of Elasticsearch log.</p>
     #[serde(rename = "LogPublishingOptions")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub log_publishing_options: Option<::std::collections::HashMap<String, LogPublishingOption>>,
+    pub log_publishing_options: Option<::std::collections::HashMap<LogType, LogPublishingOption>>,
     /// <p>Option to set the time, in UTC format, for the daily automated snapshot. Default value is <code>0</code> hours. </p>
     #[serde(rename = "SnapshotOptions")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1302,8 +2321,13 @@ pub struct UpgradeElasticsearchDomainResponse {
 pub struct UpgradeHistory {
     /// <p>UTC Timestamp at which the Upgrade API call was made in "yyyy-MM-ddTHH:mm:ssZ" format.</p>
     #[serde(rename = "StartTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_timestamp: Option<f64>,
+    pub start_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p> A list of <code> <a>UpgradeStepItem</a> </code> s representing information about each step performed as pard of a specific Upgrade or Upgrade Eligibility Check. </p>
     #[serde(rename = "StepsList")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1315,9 +2339,210 @@ pub struct UpgradeHistory {
     /// <p> The overall status of the update. The status can take one of the following values: <ul> <li>In Progress</li> <li>Succeeded</li> <li>Succeeded with Issues</li> <li>Failed</li> </ul> </p>
     #[serde(rename = "UpgradeStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub upgrade_status: Option<String>,
+    pub upgrade_status: Option<UpgradeStatus>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum UpgradeStatus {
+    /// `IN_PROGRESS`
+    InProgress,
+    /// `SUCCEEDED`
+    Succeeded,
+    /// `SUCCEEDED_WITH_ISSUES`
+    SucceededWithIssues,
+    /// `FAILED`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl UpgradeStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            UpgradeStatus::InProgress => "IN_PROGRESS",
+            UpgradeStatus::Succeeded => "SUCCEEDED",
+            UpgradeStatus::SucceededWithIssues => "SUCCEEDED_WITH_ISSUES",
+            UpgradeStatus::Failed => "FAILED",
+            UpgradeStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for UpgradeStatus {
+    fn default() -> UpgradeStatus {
+        UpgradeStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for UpgradeStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for UpgradeStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for UpgradeStatus {
+    fn from(value: &'a str) -> UpgradeStatus {
+        match value {
+            "IN_PROGRESS" => UpgradeStatus::InProgress,
+            "SUCCEEDED" => UpgradeStatus::Succeeded,
+            "SUCCEEDED_WITH_ISSUES" => UpgradeStatus::SucceededWithIssues,
+            "FAILED" => UpgradeStatus::Failed,
+            _ => UpgradeStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for UpgradeStatus {
+    fn from(value: String) -> UpgradeStatus {
+        match value.as_str() {
+            "IN_PROGRESS" => UpgradeStatus::InProgress,
+            "SUCCEEDED" => UpgradeStatus::Succeeded,
+            "SUCCEEDED_WITH_ISSUES" => UpgradeStatus::SucceededWithIssues,
+            "FAILED" => UpgradeStatus::Failed,
+            _ => UpgradeStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for UpgradeStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<UpgradeStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for UpgradeStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for UpgradeStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for UpgradeStatus {
+    fn deserialize<D>(deserializer: D) -> Result<UpgradeStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum UpgradeStep {
+    /// `PRE_UPGRADE_CHECK`
+    PreUpgradeCheck,
+    /// `SNAPSHOT`
+    Snapshot,
+    /// `UPGRADE`
+    Upgrade,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl UpgradeStep {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            UpgradeStep::PreUpgradeCheck => "PRE_UPGRADE_CHECK",
+            UpgradeStep::Snapshot => "SNAPSHOT",
+            UpgradeStep::Upgrade => "UPGRADE",
+            UpgradeStep::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for UpgradeStep {
+    fn default() -> UpgradeStep {
+        UpgradeStep::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for UpgradeStep {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for UpgradeStep {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
+impl<'a> From<&'a str> for UpgradeStep {
+    fn from(value: &'a str) -> UpgradeStep {
+        match value {
+            "PRE_UPGRADE_CHECK" => UpgradeStep::PreUpgradeCheck,
+            "SNAPSHOT" => UpgradeStep::Snapshot,
+            "UPGRADE" => UpgradeStep::Upgrade,
+            _ => UpgradeStep::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for UpgradeStep {
+    fn from(value: String) -> UpgradeStep {
+        match value.as_str() {
+            "PRE_UPGRADE_CHECK" => UpgradeStep::PreUpgradeCheck,
+            "SNAPSHOT" => UpgradeStep::Snapshot,
+            "UPGRADE" => UpgradeStep::Upgrade,
+            _ => UpgradeStep::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for UpgradeStep {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<UpgradeStep, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for UpgradeStep {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for UpgradeStep {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for UpgradeStep {
+    fn deserialize<D>(deserializer: D) -> Result<UpgradeStep, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents a single step of the Upgrade or Upgrade Eligibility Check workflow.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1333,11 +2558,11 @@ pub struct UpgradeStepItem {
     /// <p> Represents one of 3 steps that an Upgrade or Upgrade Eligibility Check does through: <ul> <li>PreUpgradeCheck</li> <li>Snapshot</li> <li>Upgrade</li> </ul> </p>
     #[serde(rename = "UpgradeStep")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub upgrade_step: Option<String>,
+    pub upgrade_step: Option<UpgradeStep>,
     /// <p> The status of a particular step during an upgrade. The status can take one of the following values: <ul> <li>In Progress</li> <li>Succeeded</li> <li>Succeeded with Issues</li> <li>Failed</li> </ul> </p>
     #[serde(rename = "UpgradeStepStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub upgrade_step_status: Option<String>,
+    pub upgrade_step_status: Option<UpgradeStatus>,
 }
 
 /// <p>Options to specify the subnets and security groups for VPC endpoint. For more information, see <a href="http://docs.aws.amazon.com/elasticsearch-service/latest/developerguide/es-vpc.html" target="_blank"> VPC Endpoints for Amazon Elasticsearch Service Domains</a>.</p>
@@ -1388,6 +2613,110 @@ pub struct VPCOptions {
     pub subnet_ids: Option<Vec<String>>,
 }
 
+/// <p> The type of EBS volume, standard, gp2, gp3 or io1. See <a href="http://docs.aws.amazon.com/elasticsearch-service/latest/developerguide/es-createupdatedomains.html#es-createdomain-configure-ebs" target="_blank">Configuring EBS-based Storage</a>for more information.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum VolumeType {
+    /// `standard`
+    Standard,
+    /// `gp2`
+    Gp2,
+    /// `io1`
+    Io1,
+    /// `gp3`
+    Gp3,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl VolumeType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            VolumeType::Standard => "standard",
+            VolumeType::Gp2 => "gp2",
+            VolumeType::Io1 => "io1",
+            VolumeType::Gp3 => "gp3",
+            VolumeType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for VolumeType {
+    fn default() -> VolumeType {
+        VolumeType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for VolumeType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for VolumeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for VolumeType {
+    fn from(value: &'a str) -> VolumeType {
+        match value {
+            "standard" => VolumeType::Standard,
+            "gp2" => VolumeType::Gp2,
+            "io1" => VolumeType::Io1,
+            "gp3" => VolumeType::Gp3,
+            _ => VolumeType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for VolumeType {
+    fn from(value: String) -> VolumeType {
+        match value.as_str() {
+            "standard" => VolumeType::Standard,
+            "gp2" => VolumeType::Gp2,
+            "io1" => VolumeType::Io1,
+            "gp3" => VolumeType::Gp3,
+            _ => VolumeType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for VolumeType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<VolumeType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for VolumeType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for VolumeType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for VolumeType {
+    fn deserialize<D>(deserializer: D) -> Result<VolumeType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Specifies the zone awareness configuration for the domain cluster, such as the number of availability zones.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ZoneAwarenessConfig {
@@ -2779,11 +4108,15 @@ pub struct EsClient {
 impl EsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> EsClient {
         EsClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(
+                region,
+                "Elasticsearch Service",
+            ),
         }
     }
 
@@ -2814,6 +4147,7 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/tags";
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation("AddTags");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2846,6 +4180,7 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/es/serviceSoftwareUpdate/cancel";
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation("CancelElasticsearchServiceSoftwareUpdate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2877,6 +4212,7 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/es/domain";
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation("CreateElasticsearchDomain");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2911,6 +4247,7 @@ impl Es for EsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "es", &self.region, &request_uri);
+        request.set_operation("DeleteElasticsearchDomain");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2937,6 +4274,7 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/es/role";
 
         let mut request = SignedRequest::new("DELETE", "es", &self.region, &request_uri);
+        request.set_operation("DeleteElasticsearchServiceRole");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2967,6 +4305,7 @@ impl Es for EsClient {
         );
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation("DescribeElasticsearchDomain");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3000,6 +4339,7 @@ impl Es for EsClient {
         );
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation("DescribeElasticsearchDomainConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3030,6 +4370,7 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/es/domain-info";
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation("DescribeElasticsearchDomains");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3067,6 +4408,7 @@ impl Es for EsClient {
         );
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation("DescribeElasticsearchInstanceTypeLimits");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3105,6 +4447,7 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/es/reservedInstanceOfferings";
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation("DescribeReservedElasticsearchInstanceOfferings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3148,6 +4491,7 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/es/reservedInstances";
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation("DescribeReservedElasticsearchInstances");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3192,6 +4536,7 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/es/compatibleVersions";
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation("GetCompatibleElasticsearchVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3230,6 +4575,7 @@ impl Es for EsClient {
         );
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation("GetUpgradeHistory");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3269,6 +4615,7 @@ impl Es for EsClient {
         );
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation("GetUpgradeStatus");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3295,6 +4642,7 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/domain";
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation("ListDomainNames");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3328,6 +4676,7 @@ impl Es for EsClient {
         );
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation("ListElasticsearchInstanceTypes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3368,6 +4717,7 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/es/versions";
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation("ListElasticsearchVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3404,6 +4754,7 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/tags/";
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation("ListTags");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3438,6 +4789,7 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/es/purchaseReservedInstanceOffering";
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation("PurchaseReservedElasticsearchInstanceOffering");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3469,6 +4821,7 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/tags-removal";
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation("RemoveTags");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3501,6 +4854,7 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/es/serviceSoftwareUpdate/start";
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation("StartElasticsearchServiceSoftwareUpdate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3539,6 +4893,7 @@ impl Es for EsClient {
         );
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation("UpdateElasticsearchDomainConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3572,6 +4927,7 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/es/upgradeDomain";
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation("UpgradeElasticsearchDomain");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3594,3 +4950,199 @@ impl Es for EsClient {
         }
     }
 }
+impl EsClient {
+    /// Auto-paginating version of `describe_reserved_elasticsearch_instance_offerings`, streaming every page of results.
+    pub fn describe_reserved_elasticsearch_instance_offerings_pages(
+        &self,
+        input: DescribeReservedElasticsearchInstanceOfferingsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeReservedElasticsearchInstanceOfferingsResponse,
+        DescribeReservedElasticsearchInstanceOfferingsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EsClient, input: DescribeReservedElasticsearchInstanceOfferingsRequest| async move {
+                client
+                    .describe_reserved_elasticsearch_instance_offerings(input)
+                    .await
+            },
+            |input: &DescribeReservedElasticsearchInstanceOfferingsRequest,
+             output: &DescribeReservedElasticsearchInstanceOfferingsResponse| {
+                Some(DescribeReservedElasticsearchInstanceOfferingsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_reserved_elasticsearch_instance_offerings`, streaming the `ReservedElasticsearchInstanceOfferings` of every page of results.
+    pub fn describe_reserved_elasticsearch_instance_offerings_items(
+        &self,
+        input: DescribeReservedElasticsearchInstanceOfferingsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ReservedElasticsearchInstanceOffering,
+        DescribeReservedElasticsearchInstanceOfferingsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_reserved_elasticsearch_instance_offerings_pages(input),
+            |output: DescribeReservedElasticsearchInstanceOfferingsResponse| {
+                output
+                    .reserved_elasticsearch_instance_offerings
+                    .unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_reserved_elasticsearch_instances`, streaming every page of results.
+    pub fn describe_reserved_elasticsearch_instances_pages(
+        &self,
+        input: DescribeReservedElasticsearchInstancesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeReservedElasticsearchInstancesResponse,
+        DescribeReservedElasticsearchInstancesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EsClient, input: DescribeReservedElasticsearchInstancesRequest| async move {
+                client
+                    .describe_reserved_elasticsearch_instances(input)
+                    .await
+            },
+            |input: &DescribeReservedElasticsearchInstancesRequest,
+             output: &DescribeReservedElasticsearchInstancesResponse| {
+                Some(DescribeReservedElasticsearchInstancesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_reserved_elasticsearch_instances`, streaming the `ReservedElasticsearchInstances` of every page of results.
+    pub fn describe_reserved_elasticsearch_instances_items(
+        &self,
+        input: DescribeReservedElasticsearchInstancesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ReservedElasticsearchInstance,
+        DescribeReservedElasticsearchInstancesError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_reserved_elasticsearch_instances_pages(input),
+            |output: DescribeReservedElasticsearchInstancesResponse| {
+                output.reserved_elasticsearch_instances.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_upgrade_history`, streaming every page of results.
+    pub fn get_upgrade_history_pages(
+        &self,
+        input: GetUpgradeHistoryRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetUpgradeHistoryResponse, GetUpgradeHistoryError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EsClient, input: GetUpgradeHistoryRequest| async move {
+                client.get_upgrade_history(input).await
+            },
+            |input: &GetUpgradeHistoryRequest, output: &GetUpgradeHistoryResponse| {
+                Some(GetUpgradeHistoryRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_upgrade_history`, streaming the `UpgradeHistories` of every page of results.
+    pub fn get_upgrade_history_items(
+        &self,
+        input: GetUpgradeHistoryRequest,
+    ) -> rusoto_core::pagination::RusotoStream<UpgradeHistory, GetUpgradeHistoryError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_upgrade_history_pages(input),
+            |output: GetUpgradeHistoryResponse| output.upgrade_histories.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_elasticsearch_instance_types`, streaming every page of results.
+    pub fn list_elasticsearch_instance_types_pages(
+        &self,
+        input: ListElasticsearchInstanceTypesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListElasticsearchInstanceTypesResponse,
+        ListElasticsearchInstanceTypesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EsClient, input: ListElasticsearchInstanceTypesRequest| async move {
+                client.list_elasticsearch_instance_types(input).await
+            },
+            |input: &ListElasticsearchInstanceTypesRequest,
+             output: &ListElasticsearchInstanceTypesResponse| {
+                Some(ListElasticsearchInstanceTypesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_elasticsearch_instance_types`, streaming the `ElasticsearchInstanceTypes` of every page of results.
+    pub fn list_elasticsearch_instance_types_items(
+        &self,
+        input: ListElasticsearchInstanceTypesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ESPartitionInstanceType,
+        ListElasticsearchInstanceTypesError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_elasticsearch_instance_types_pages(input),
+            |output: ListElasticsearchInstanceTypesResponse| {
+                output.elasticsearch_instance_types.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_elasticsearch_versions`, streaming every page of results.
+    pub fn list_elasticsearch_versions_pages(
+        &self,
+        input: ListElasticsearchVersionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListElasticsearchVersionsResponse,
+        ListElasticsearchVersionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EsClient, input: ListElasticsearchVersionsRequest| async move {
+                client.list_elasticsearch_versions(input).await
+            },
+            |input: &ListElasticsearchVersionsRequest,
+             output: &ListElasticsearchVersionsResponse| {
+                Some(ListElasticsearchVersionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_elasticsearch_versions`, streaming the `ElasticsearchVersions` of every page of results.
+    pub fn list_elasticsearch_versions_items(
+        &self,
+        input: ListElasticsearchVersionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListElasticsearchVersionsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_elasticsearch_versions_pages(input),
+            |output: ListElasticsearchVersionsResponse| {
+                output.elasticsearch_versions.unwrap_or_default()
+            },
+        )
+    }
+}
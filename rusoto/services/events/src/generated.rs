@@ -32,13 +32,106 @@ pub struct ActivateEventSourceRequest {
     pub name: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AssignPublicIp {
+    /// `ENABLED`
+    Enabled,
+    /// `DISABLED`
+    Disabled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AssignPublicIp {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AssignPublicIp::Enabled => "ENABLED",
+            AssignPublicIp::Disabled => "DISABLED",
+            AssignPublicIp::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AssignPublicIp {
+    fn default() -> AssignPublicIp {
+        AssignPublicIp::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AssignPublicIp {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AssignPublicIp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AssignPublicIp {
+    fn from(value: &'a str) -> AssignPublicIp {
+        match value {
+            "ENABLED" => AssignPublicIp::Enabled,
+            "DISABLED" => AssignPublicIp::Disabled,
+            _ => AssignPublicIp::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AssignPublicIp {
+    fn from(value: String) -> AssignPublicIp {
+        match value.as_str() {
+            "ENABLED" => AssignPublicIp::Enabled,
+            "DISABLED" => AssignPublicIp::Disabled,
+            _ => AssignPublicIp::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AssignPublicIp {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AssignPublicIp, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AssignPublicIp {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AssignPublicIp {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AssignPublicIp {
+    fn deserialize<D>(deserializer: D) -> Result<AssignPublicIp, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>This structure specifies the VPC subnets and security groups for the task and whether a public IP address is to be used. This structure is relevant only for ECS tasks that use the <code>awsvpc</code> network mode.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AwsVpcConfiguration {
     /// <p>Specifies whether the task's elastic network interface receives a public IP address. You can specify <code>ENABLED</code> only when <code>LaunchType</code> in <code>EcsParameters</code> is set to <code>FARGATE</code>.</p>
     #[serde(rename = "AssignPublicIp")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub assign_public_ip: Option<String>,
+    pub assign_public_ip: Option<AssignPublicIp>,
     /// <p>Specifies the security groups associated with the task. These security groups must all be in the same VPC. You can specify as many as five security groups. If you don't specify a security group, the default security group for the VPC is used.</p>
     #[serde(rename = "SecurityGroups")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -231,12 +324,22 @@ pub struct DescribeEventSourceResponse {
     pub created_by: Option<String>,
     /// <p>The date and time that the event source was created.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The date and time that the event source will expire if you don't create a matching event bus.</p>
     #[serde(rename = "ExpirationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expiration_time: Option<f64>,
+    pub expiration_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the partner event source.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -244,7 +347,7 @@ pub struct DescribeEventSourceResponse {
     /// <p>The state of the event source. If it's <code>ACTIVE</code>, you have already created a matching event bus for this event source, and that event bus is active. If it's <code>PENDING</code>, either you haven't yet created a matching event bus, or that event bus is deactivated. If it's <code>DELETED</code>, you have created a matching event bus, but the event source has since been deleted.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<EventSourceState>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -318,7 +421,7 @@ pub struct DescribeRuleResponse {
     /// <p>Specifies whether the rule is enabled or disabled.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<RuleState>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -343,7 +446,7 @@ pub struct EcsParameters {
     /// <p>Specifies the launch type on which your task is running. The launch type that you specify here must match one of the launch type (compatibilities) of the target task. The <code>FARGATE</code> value is supported only in the Regions where AWS Fargate with Amazon ECS is supported. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/AWS-Fargate.html">AWS Fargate on Amazon ECS</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
     #[serde(rename = "LaunchType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub launch_type: Option<String>,
+    pub launch_type: Option<LaunchType>,
     /// <p>Use this structure if the ECS task uses the <code>awsvpc</code> network mode. This structure specifies the VPC subnets and security groups associated with the task and whether a public IP address is to be used. This structure is required if <code>LaunchType</code> is <code>FARGATE</code> because the <code>awsvpc</code> mode is required for Fargate tasks.</p> <p>If you specify <code>NetworkConfiguration</code> when the target ECS task doesn't use the <code>awsvpc</code> network mode, the task fails.</p>
     #[serde(rename = "NetworkConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -405,12 +508,22 @@ pub struct EventSource {
     pub created_by: Option<String>,
     /// <p>The date and time when the event source was created.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The date and time when the event source will expire if the AWS account doesn't create a matching event bus for it.</p>
     #[serde(rename = "ExpirationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expiration_time: Option<f64>,
+    pub expiration_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the event source.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -418,9 +531,107 @@ pub struct EventSource {
     /// <p>The state of the event source. If it's <code>ACTIVE</code>, you have already created a matching event bus for this event source, and that event bus is active. If it's <code>PENDING</code>, either you haven't yet created a matching event bus, or that event bus is deactivated. If it's <code>DELETED</code>, you have created a matching event bus, but the event source has since been deleted.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<EventSourceState>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EventSourceState {
+    /// `PENDING`
+    Pending,
+    /// `ACTIVE`
+    Active,
+    /// `DELETED`
+    Deleted,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EventSourceState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EventSourceState::Pending => "PENDING",
+            EventSourceState::Active => "ACTIVE",
+            EventSourceState::Deleted => "DELETED",
+            EventSourceState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EventSourceState {
+    fn default() -> EventSourceState {
+        EventSourceState::Unknown(String::new())
+    }
 }
 
+impl AsRef<str> for EventSourceState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EventSourceState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EventSourceState {
+    fn from(value: &'a str) -> EventSourceState {
+        match value {
+            "PENDING" => EventSourceState::Pending,
+            "ACTIVE" => EventSourceState::Active,
+            "DELETED" => EventSourceState::Deleted,
+            _ => EventSourceState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EventSourceState {
+    fn from(value: String) -> EventSourceState {
+        match value.as_str() {
+            "PENDING" => EventSourceState::Pending,
+            "ACTIVE" => EventSourceState::Active,
+            "DELETED" => EventSourceState::Deleted,
+            _ => EventSourceState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EventSourceState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EventSourceState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EventSourceState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EventSourceState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EventSourceState {
+    fn deserialize<D>(deserializer: D) -> Result<EventSourceState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Contains the parameters needed for you to provide custom input to a target based on one or more pieces of data extracted from the event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct InputTransformer {
@@ -441,6 +652,104 @@ pub struct KinesisParameters {
     pub partition_key_path: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LaunchType {
+    /// `EC2`
+    Ec2,
+    /// `FARGATE`
+    Fargate,
+    /// `EXTERNAL`
+    External,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LaunchType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LaunchType::Ec2 => "EC2",
+            LaunchType::Fargate => "FARGATE",
+            LaunchType::External => "EXTERNAL",
+            LaunchType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LaunchType {
+    fn default() -> LaunchType {
+        LaunchType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LaunchType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LaunchType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LaunchType {
+    fn from(value: &'a str) -> LaunchType {
+        match value {
+            "EC2" => LaunchType::Ec2,
+            "FARGATE" => LaunchType::Fargate,
+            "EXTERNAL" => LaunchType::External,
+            _ => LaunchType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LaunchType {
+    fn from(value: String) -> LaunchType {
+        match value.as_str() {
+            "EC2" => LaunchType::Ec2,
+            "FARGATE" => LaunchType::Fargate,
+            "EXTERNAL" => LaunchType::External,
+            _ => LaunchType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LaunchType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LaunchType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LaunchType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LaunchType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LaunchType {
+    fn deserialize<D>(deserializer: D) -> Result<LaunchType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ListEventBusesRequest {
@@ -709,16 +1018,26 @@ pub struct PartnerEventSourceAccount {
     pub account: Option<String>,
     /// <p>The date and time when the event source was created.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The date and time when the event source will expire if the AWS account doesn't create a matching event bus for it.</p>
     #[serde(rename = "ExpirationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expiration_time: Option<f64>,
+    pub expiration_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The state of the event source. If it's <code>ACTIVE</code>, you have already created a matching event bus for this event source, and that event bus is active. If it's <code>PENDING</code>, either you haven't yet created a matching event bus, or that event bus is deactivated. If it's <code>DELETED</code>, you have created a matching event bus, but the event source has since been deleted.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<EventSourceState>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -755,8 +1074,13 @@ pub struct PutEventsRequestEntry {
     pub source: Option<String>,
     /// <p>The timestamp of the event, per <a href="https://www.rfc-editor.org/rfc/rfc3339.txt">RFC3339</a>. If no timestamp is provided, the timestamp of the <a>PutEvents</a> call is used.</p>
     #[serde(rename = "Time")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub time: Option<f64>,
+    pub time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -820,8 +1144,13 @@ pub struct PutPartnerEventsRequestEntry {
     pub source: Option<String>,
     /// <p>The date and time of the event.</p>
     #[serde(rename = "Time")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub time: Option<f64>,
+    pub time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -906,7 +1235,7 @@ pub struct PutRuleRequest {
     /// <p>Indicates whether the rule is enabled or disabled.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<RuleState>,
     /// <p>The list of key-value pairs to associate with the rule.</p>
     #[serde(rename = "Tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1069,9 +1398,113 @@ pub struct Rule {
     /// <p>The state of the rule.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<RuleState>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RuleState {
+    /// `ENABLED`
+    Enabled,
+    /// `DISABLED`
+    Disabled,
+    /// `ENABLED_WITH_ALL_CLOUDTRAIL_MANAGEMENT_EVENTS`
+    EnabledWithAllCloudtrailManagementEvents,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RuleState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RuleState::Enabled => "ENABLED",
+            RuleState::Disabled => "DISABLED",
+            RuleState::EnabledWithAllCloudtrailManagementEvents => {
+                "ENABLED_WITH_ALL_CLOUDTRAIL_MANAGEMENT_EVENTS"
+            }
+            RuleState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RuleState {
+    fn default() -> RuleState {
+        RuleState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RuleState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RuleState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RuleState {
+    fn from(value: &'a str) -> RuleState {
+        match value {
+            "ENABLED" => RuleState::Enabled,
+            "DISABLED" => RuleState::Disabled,
+            "ENABLED_WITH_ALL_CLOUDTRAIL_MANAGEMENT_EVENTS" => {
+                RuleState::EnabledWithAllCloudtrailManagementEvents
+            }
+            _ => RuleState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RuleState {
+    fn from(value: String) -> RuleState {
+        match value.as_str() {
+            "ENABLED" => RuleState::Enabled,
+            "DISABLED" => RuleState::Disabled,
+            "ENABLED_WITH_ALL_CLOUDTRAIL_MANAGEMENT_EVENTS" => {
+                RuleState::EnabledWithAllCloudtrailManagementEvents
+            }
+            _ => RuleState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RuleState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RuleState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RuleState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RuleState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for RuleState {
+    fn deserialize<D>(deserializer: D) -> Result<RuleState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>This parameter contains the criteria (either <code>InstanceIds</code> or a tag) used to specify which EC2 instances are to be sent the command. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RunCommandParameters {
@@ -2710,11 +3143,12 @@ pub struct EventBridgeClient {
 impl EventBridgeClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> EventBridgeClient {
         EventBridgeClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "EventBridge"),
         }
     }
 
@@ -2746,6 +3180,7 @@ impl EventBridge for EventBridgeClient {
         input: ActivateEventSourceRequest,
     ) -> Result<(), RusotoError<ActivateEventSourceError>> {
         let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("ActivateEventSource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSEvents.ActivateEventSource");
@@ -2773,6 +3208,7 @@ impl EventBridge for EventBridgeClient {
         input: CreateEventBusRequest,
     ) -> Result<CreateEventBusResponse, RusotoError<CreateEventBusError>> {
         let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("CreateEventBus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSEvents.CreateEventBus");
@@ -2800,6 +3236,7 @@ impl EventBridge for EventBridgeClient {
         input: CreatePartnerEventSourceRequest,
     ) -> Result<CreatePartnerEventSourceResponse, RusotoError<CreatePartnerEventSourceError>> {
         let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("CreatePartnerEventSource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSEvents.CreatePartnerEventSource");
@@ -2828,6 +3265,7 @@ impl EventBridge for EventBridgeClient {
         input: DeactivateEventSourceRequest,
     ) -> Result<(), RusotoError<DeactivateEventSourceError>> {
         let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("DeactivateEventSource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSEvents.DeactivateEventSource");
@@ -2855,6 +3293,7 @@ impl EventBridge for EventBridgeClient {
         input: DeleteEventBusRequest,
     ) -> Result<(), RusotoError<DeleteEventBusError>> {
         let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("DeleteEventBus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSEvents.DeleteEventBus");
@@ -2882,6 +3321,7 @@ impl EventBridge for EventBridgeClient {
         input: DeletePartnerEventSourceRequest,
     ) -> Result<(), RusotoError<DeletePartnerEventSourceError>> {
         let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("DeletePartnerEventSource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSEvents.DeletePartnerEventSource");
@@ -2909,6 +3349,7 @@ impl EventBridge for EventBridgeClient {
         input: DeleteRuleRequest,
     ) -> Result<(), RusotoError<DeleteRuleError>> {
         let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("DeleteRule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSEvents.DeleteRule");
@@ -2936,6 +3377,7 @@ impl EventBridge for EventBridgeClient {
         input: DescribeEventBusRequest,
     ) -> Result<DescribeEventBusResponse, RusotoError<DescribeEventBusError>> {
         let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("DescribeEventBus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSEvents.DescribeEventBus");
@@ -2964,6 +3406,7 @@ impl EventBridge for EventBridgeClient {
         input: DescribeEventSourceRequest,
     ) -> Result<DescribeEventSourceResponse, RusotoError<DescribeEventSourceError>> {
         let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("DescribeEventSource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSEvents.DescribeEventSource");
@@ -2993,6 +3436,7 @@ impl EventBridge for EventBridgeClient {
     ) -> Result<DescribePartnerEventSourceResponse, RusotoError<DescribePartnerEventSourceError>>
     {
         let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("DescribePartnerEventSource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSEvents.DescribePartnerEventSource");
@@ -3021,6 +3465,7 @@ impl EventBridge for EventBridgeClient {
         input: DescribeRuleRequest,
     ) -> Result<DescribeRuleResponse, RusotoError<DescribeRuleError>> {
         let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("DescribeRule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSEvents.DescribeRule");
@@ -3048,6 +3493,7 @@ impl EventBridge for EventBridgeClient {
         input: DisableRuleRequest,
     ) -> Result<(), RusotoError<DisableRuleError>> {
         let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("DisableRule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSEvents.DisableRule");
@@ -3075,6 +3521,7 @@ impl EventBridge for EventBridgeClient {
         input: EnableRuleRequest,
     ) -> Result<(), RusotoError<EnableRuleError>> {
         let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("EnableRule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSEvents.EnableRule");
@@ -3102,6 +3549,7 @@ impl EventBridge for EventBridgeClient {
         input: ListEventBusesRequest,
     ) -> Result<ListEventBusesResponse, RusotoError<ListEventBusesError>> {
         let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("ListEventBuses");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSEvents.ListEventBuses");
@@ -3129,6 +3577,7 @@ impl EventBridge for EventBridgeClient {
         input: ListEventSourcesRequest,
     ) -> Result<ListEventSourcesResponse, RusotoError<ListEventSourcesError>> {
         let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("ListEventSources");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSEvents.ListEventSources");
@@ -3160,6 +3609,7 @@ impl EventBridge for EventBridgeClient {
         RusotoError<ListPartnerEventSourceAccountsError>,
     > {
         let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("ListPartnerEventSourceAccounts");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSEvents.ListPartnerEventSourceAccounts");
@@ -3188,6 +3638,7 @@ impl EventBridge for EventBridgeClient {
         input: ListPartnerEventSourcesRequest,
     ) -> Result<ListPartnerEventSourcesResponse, RusotoError<ListPartnerEventSourcesError>> {
         let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("ListPartnerEventSources");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSEvents.ListPartnerEventSources");
@@ -3216,6 +3667,7 @@ impl EventBridge for EventBridgeClient {
         input: ListRuleNamesByTargetRequest,
     ) -> Result<ListRuleNamesByTargetResponse, RusotoError<ListRuleNamesByTargetError>> {
         let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("ListRuleNamesByTarget");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSEvents.ListRuleNamesByTarget");
@@ -3244,6 +3696,7 @@ impl EventBridge for EventBridgeClient {
         input: ListRulesRequest,
     ) -> Result<ListRulesResponse, RusotoError<ListRulesError>> {
         let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("ListRules");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSEvents.ListRules");
@@ -3271,6 +3724,7 @@ impl EventBridge for EventBridgeClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSEvents.ListTagsForResource");
@@ -3299,6 +3753,7 @@ impl EventBridge for EventBridgeClient {
         input: ListTargetsByRuleRequest,
     ) -> Result<ListTargetsByRuleResponse, RusotoError<ListTargetsByRuleError>> {
         let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("ListTargetsByRule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSEvents.ListTargetsByRule");
@@ -3327,6 +3782,7 @@ impl EventBridge for EventBridgeClient {
         input: PutEventsRequest,
     ) -> Result<PutEventsResponse, RusotoError<PutEventsError>> {
         let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("PutEvents");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSEvents.PutEvents");
@@ -3354,6 +3810,7 @@ impl EventBridge for EventBridgeClient {
         input: PutPartnerEventsRequest,
     ) -> Result<PutPartnerEventsResponse, RusotoError<PutPartnerEventsError>> {
         let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("PutPartnerEvents");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSEvents.PutPartnerEvents");
@@ -3382,6 +3839,7 @@ impl EventBridge for EventBridgeClient {
         input: PutPermissionRequest,
     ) -> Result<(), RusotoError<PutPermissionError>> {
         let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("PutPermission");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSEvents.PutPermission");
@@ -3409,6 +3867,7 @@ impl EventBridge for EventBridgeClient {
         input: PutRuleRequest,
     ) -> Result<PutRuleResponse, RusotoError<PutRuleError>> {
         let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("PutRule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSEvents.PutRule");
@@ -3436,6 +3895,7 @@ impl EventBridge for EventBridgeClient {
         input: PutTargetsRequest,
     ) -> Result<PutTargetsResponse, RusotoError<PutTargetsError>> {
         let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("PutTargets");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSEvents.PutTargets");
@@ -3463,6 +3923,7 @@ impl EventBridge for EventBridgeClient {
         input: RemovePermissionRequest,
     ) -> Result<(), RusotoError<RemovePermissionError>> {
         let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("RemovePermission");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSEvents.RemovePermission");
@@ -3490,6 +3951,7 @@ impl EventBridge for EventBridgeClient {
         input: RemoveTargetsRequest,
     ) -> Result<RemoveTargetsResponse, RusotoError<RemoveTargetsError>> {
         let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("RemoveTargets");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSEvents.RemoveTargets");
@@ -3517,6 +3979,7 @@ impl EventBridge for EventBridgeClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSEvents.TagResource");
@@ -3544,6 +4007,7 @@ impl EventBridge for EventBridgeClient {
         input: TestEventPatternRequest,
     ) -> Result<TestEventPatternResponse, RusotoError<TestEventPatternError>> {
         let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("TestEventPattern");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSEvents.TestEventPattern");
@@ -3572,6 +4036,7 @@ impl EventBridge for EventBridgeClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSEvents.UntagResource");
@@ -3593,3 +4058,101 @@ impl EventBridge for EventBridgeClient {
         }
     }
 }
+impl EventBridgeClient {
+    /// Auto-paginating version of `list_rule_names_by_target`, streaming every page of results.
+    pub fn list_rule_names_by_target_pages(
+        &self,
+        input: ListRuleNamesByTargetRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListRuleNamesByTargetResponse,
+        ListRuleNamesByTargetError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EventBridgeClient, input: ListRuleNamesByTargetRequest| async move {
+                client.list_rule_names_by_target(input).await
+            },
+            |input: &ListRuleNamesByTargetRequest, output: &ListRuleNamesByTargetResponse| {
+                Some(ListRuleNamesByTargetRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_rule_names_by_target`, streaming the `RuleNames` of every page of results.
+    pub fn list_rule_names_by_target_items(
+        &self,
+        input: ListRuleNamesByTargetRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListRuleNamesByTargetError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_rule_names_by_target_pages(input),
+            |output: ListRuleNamesByTargetResponse| output.rule_names.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_rules`, streaming every page of results.
+    pub fn list_rules_pages(
+        &self,
+        input: ListRulesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListRulesResponse, ListRulesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EventBridgeClient, input: ListRulesRequest| async move {
+                client.list_rules(input).await
+            },
+            |input: &ListRulesRequest, output: &ListRulesResponse| {
+                Some(ListRulesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_rules`, streaming the `Rules` of every page of results.
+    pub fn list_rules_items(
+        &self,
+        input: ListRulesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Rule, ListRulesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_rules_pages(input),
+            |output: ListRulesResponse| output.rules.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_targets_by_rule`, streaming every page of results.
+    pub fn list_targets_by_rule_pages(
+        &self,
+        input: ListTargetsByRuleRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListTargetsByRuleResponse, ListTargetsByRuleError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EventBridgeClient, input: ListTargetsByRuleRequest| async move {
+                client.list_targets_by_rule(input).await
+            },
+            |input: &ListTargetsByRuleRequest, output: &ListTargetsByRuleResponse| {
+                Some(ListTargetsByRuleRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_targets_by_rule`, streaming the `Targets` of every page of results.
+    pub fn list_targets_by_rule_items(
+        &self,
+        input: ListTargetsByRuleRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Target, ListTargetsByRuleError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_targets_by_rule_pages(input),
+            |output: ListTargetsByRuleResponse| output.targets.unwrap_or_default(),
+        )
+    }
+}
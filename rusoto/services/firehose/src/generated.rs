@@ -54,6 +54,114 @@ pub struct CloudWatchLoggingOptions {
     pub log_stream_name: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CompressionFormat {
+    /// `UNCOMPRESSED`
+    Uncompressed,
+    /// `GZIP`
+    Gzip,
+    /// `ZIP`
+    Zip,
+    /// `Snappy`
+    Snappy,
+    /// `HADOOP_SNAPPY`
+    HadoopSnappy,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CompressionFormat {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CompressionFormat::Uncompressed => "UNCOMPRESSED",
+            CompressionFormat::Gzip => "GZIP",
+            CompressionFormat::Zip => "ZIP",
+            CompressionFormat::Snappy => "Snappy",
+            CompressionFormat::HadoopSnappy => "HADOOP_SNAPPY",
+            CompressionFormat::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CompressionFormat {
+    fn default() -> CompressionFormat {
+        CompressionFormat::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CompressionFormat {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CompressionFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CompressionFormat {
+    fn from(value: &'a str) -> CompressionFormat {
+        match value {
+            "UNCOMPRESSED" => CompressionFormat::Uncompressed,
+            "GZIP" => CompressionFormat::Gzip,
+            "ZIP" => CompressionFormat::Zip,
+            "Snappy" => CompressionFormat::Snappy,
+            "HADOOP_SNAPPY" => CompressionFormat::HadoopSnappy,
+            _ => CompressionFormat::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CompressionFormat {
+    fn from(value: String) -> CompressionFormat {
+        match value.as_str() {
+            "UNCOMPRESSED" => CompressionFormat::Uncompressed,
+            "GZIP" => CompressionFormat::Gzip,
+            "ZIP" => CompressionFormat::Zip,
+            "Snappy" => CompressionFormat::Snappy,
+            "HADOOP_SNAPPY" => CompressionFormat::HadoopSnappy,
+            _ => CompressionFormat::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CompressionFormat {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CompressionFormat, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CompressionFormat {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CompressionFormat {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CompressionFormat {
+    fn deserialize<D>(deserializer: D) -> Result<CompressionFormat, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes a <code>COPY</code> command for Amazon Redshift.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CopyCommand {
@@ -84,7 +192,7 @@ pub struct CreateDeliveryStreamInput {
     /// <p><p>The delivery stream type. This parameter can be one of the following values:</p> <ul> <li> <p> <code>DirectPut</code>: Provider applications access the delivery stream directly.</p> </li> <li> <p> <code>KinesisStreamAsSource</code>: The delivery stream uses a Kinesis data stream as a source.</p> </li> </ul></p>
     #[serde(rename = "DeliveryStreamType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub delivery_stream_type: Option<String>,
+    pub delivery_stream_type: Option<DeliveryStreamType>,
     /// <p>The destination in Amazon ES. You can specify only one destination.</p>
     #[serde(rename = "ElasticsearchDestinationConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -163,8 +271,13 @@ pub struct DeleteDeliveryStreamOutput {}
 pub struct DeliveryStreamDescription {
     /// <p>The date and time that the delivery stream was created.</p>
     #[serde(rename = "CreateTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub create_timestamp: Option<f64>,
+    pub create_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The Amazon Resource Name (ARN) of the delivery stream. For more information, see <a href="https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and AWS Service Namespaces</a>.</p>
     #[serde(rename = "DeliveryStreamARN")]
     pub delivery_stream_arn: String,
@@ -177,10 +290,10 @@ pub struct DeliveryStreamDescription {
     pub delivery_stream_name: String,
     /// <p>The status of the delivery stream. If the status of a delivery stream is <code>CREATING_FAILED</code>, this status doesn't change, and you can't invoke <code>CreateDeliveryStream</code> again on it. However, you can invoke the <a>DeleteDeliveryStream</a> operation to delete it.</p>
     #[serde(rename = "DeliveryStreamStatus")]
-    pub delivery_stream_status: String,
+    pub delivery_stream_status: DeliveryStreamStatus,
     /// <p><p>The delivery stream type. This can be one of the following values:</p> <ul> <li> <p> <code>DirectPut</code>: Provider applications access the delivery stream directly.</p> </li> <li> <p> <code>KinesisStreamAsSource</code>: The delivery stream uses a Kinesis data stream as a source.</p> </li> </ul></p>
     #[serde(rename = "DeliveryStreamType")]
-    pub delivery_stream_type: String,
+    pub delivery_stream_type: DeliveryStreamType,
     /// <p>The destinations.</p>
     #[serde(rename = "Destinations")]
     pub destinations: Vec<DestinationDescription>,
@@ -193,8 +306,13 @@ pub struct DeliveryStreamDescription {
     pub has_more_destinations: bool,
     /// <p>The date and time that the delivery stream was last updated.</p>
     #[serde(rename = "LastUpdateTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_update_timestamp: Option<f64>,
+    pub last_update_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>If the <code>DeliveryStreamType</code> parameter is <code>KinesisStreamAsSource</code>, a <a>SourceDescription</a> object describing the source Kinesis data stream.</p>
     #[serde(rename = "Source")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -219,11 +337,11 @@ pub struct DeliveryStreamEncryptionConfiguration {
     /// <p>Indicates the type of customer master key (CMK) that is used for encryption. The default setting is <code>AWS_OWNED_CMK</code>. For more information about CMKs, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#master_keys">Customer Master Keys (CMKs)</a>.</p>
     #[serde(rename = "KeyType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub key_type: Option<String>,
+    pub key_type: Option<KeyType>,
     /// <p>This is the server-side encryption (SSE) status for the delivery stream. For a full description of the different values of this status, see <a>StartDeliveryStreamEncryption</a> and <a>StopDeliveryStreamEncryption</a>. If this status is <code>ENABLING_FAILED</code> or <code>DISABLING_FAILED</code>, it is the status of the most recent attempt to enable or disable SSE, respectively.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<DeliveryStreamEncryptionStatus>,
 }
 
 /// <p>Used to specify the type and Amazon Resource Name (ARN) of the CMK needed for Server-Side Encryption (SSE). </p>
@@ -236,9 +354,486 @@ pub struct DeliveryStreamEncryptionConfigurationInput {
     pub key_arn: Option<String>,
     /// <p>Indicates the type of customer master key (CMK) to use for encryption. The default setting is <code>AWS_OWNED_CMK</code>. For more information about CMKs, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#master_keys">Customer Master Keys (CMKs)</a>. When you invoke <a>CreateDeliveryStream</a> or <a>StartDeliveryStreamEncryption</a> with <code>KeyType</code> set to CUSTOMER_MANAGED_CMK, Kinesis Data Firehose invokes the Amazon KMS operation <a href="https://docs.aws.amazon.com/kms/latest/APIReference/API_CreateGrant.html">CreateGrant</a> to create a grant that allows the Kinesis Data Firehose service to use the customer managed CMK to perform encryption and decryption. Kinesis Data Firehose manages that grant. </p> <p>When you invoke <a>StartDeliveryStreamEncryption</a> to change the CMK for a delivery stream that is already encrypted with a customer managed CMK, Kinesis Data Firehose schedules the grant it had on the old CMK for retirement.</p>
     #[serde(rename = "KeyType")]
-    pub key_type: String,
+    pub key_type: KeyType,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DeliveryStreamEncryptionStatus {
+    /// `ENABLED`
+    Enabled,
+    /// `ENABLING`
+    Enabling,
+    /// `ENABLING_FAILED`
+    EnablingFailed,
+    /// `DISABLED`
+    Disabled,
+    /// `DISABLING`
+    Disabling,
+    /// `DISABLING_FAILED`
+    DisablingFailed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DeliveryStreamEncryptionStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeliveryStreamEncryptionStatus::Enabled => "ENABLED",
+            DeliveryStreamEncryptionStatus::Enabling => "ENABLING",
+            DeliveryStreamEncryptionStatus::EnablingFailed => "ENABLING_FAILED",
+            DeliveryStreamEncryptionStatus::Disabled => "DISABLED",
+            DeliveryStreamEncryptionStatus::Disabling => "DISABLING",
+            DeliveryStreamEncryptionStatus::DisablingFailed => "DISABLING_FAILED",
+            DeliveryStreamEncryptionStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DeliveryStreamEncryptionStatus {
+    fn default() -> DeliveryStreamEncryptionStatus {
+        DeliveryStreamEncryptionStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DeliveryStreamEncryptionStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DeliveryStreamEncryptionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DeliveryStreamEncryptionStatus {
+    fn from(value: &'a str) -> DeliveryStreamEncryptionStatus {
+        match value {
+            "ENABLED" => DeliveryStreamEncryptionStatus::Enabled,
+            "ENABLING" => DeliveryStreamEncryptionStatus::Enabling,
+            "ENABLING_FAILED" => DeliveryStreamEncryptionStatus::EnablingFailed,
+            "DISABLED" => DeliveryStreamEncryptionStatus::Disabled,
+            "DISABLING" => DeliveryStreamEncryptionStatus::Disabling,
+            "DISABLING_FAILED" => DeliveryStreamEncryptionStatus::DisablingFailed,
+            _ => DeliveryStreamEncryptionStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeliveryStreamEncryptionStatus {
+    fn from(value: String) -> DeliveryStreamEncryptionStatus {
+        match value.as_str() {
+            "ENABLED" => DeliveryStreamEncryptionStatus::Enabled,
+            "ENABLING" => DeliveryStreamEncryptionStatus::Enabling,
+            "ENABLING_FAILED" => DeliveryStreamEncryptionStatus::EnablingFailed,
+            "DISABLED" => DeliveryStreamEncryptionStatus::Disabled,
+            "DISABLING" => DeliveryStreamEncryptionStatus::Disabling,
+            "DISABLING_FAILED" => DeliveryStreamEncryptionStatus::DisablingFailed,
+            _ => DeliveryStreamEncryptionStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DeliveryStreamEncryptionStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DeliveryStreamEncryptionStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DeliveryStreamEncryptionStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DeliveryStreamEncryptionStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeliveryStreamEncryptionStatus {
+    fn deserialize<D>(deserializer: D) -> Result<DeliveryStreamEncryptionStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DeliveryStreamFailureType {
+    /// `RETIRE_KMS_GRANT_FAILED`
+    RetireKmsGrantFailed,
+    /// `CREATE_KMS_GRANT_FAILED`
+    CreateKmsGrantFailed,
+    /// `KMS_ACCESS_DENIED`
+    KmsAccessDenied,
+    /// `DISABLED_KMS_KEY`
+    DisabledKmsKey,
+    /// `INVALID_KMS_KEY`
+    InvalidKmsKey,
+    /// `KMS_KEY_NOT_FOUND`
+    KmsKeyNotFound,
+    /// `KMS_OPT_IN_REQUIRED`
+    KmsOptInRequired,
+    /// `CREATE_ENI_FAILED`
+    CreateEniFailed,
+    /// `DELETE_ENI_FAILED`
+    DeleteEniFailed,
+    /// `SUBNET_NOT_FOUND`
+    SubnetNotFound,
+    /// `SECURITY_GROUP_NOT_FOUND`
+    SecurityGroupNotFound,
+    /// `ENI_ACCESS_DENIED`
+    EniAccessDenied,
+    /// `SUBNET_ACCESS_DENIED`
+    SubnetAccessDenied,
+    /// `SECURITY_GROUP_ACCESS_DENIED`
+    SecurityGroupAccessDenied,
+    /// `UNKNOWN_ERROR`
+    UnknownError,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DeliveryStreamFailureType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeliveryStreamFailureType::RetireKmsGrantFailed => "RETIRE_KMS_GRANT_FAILED",
+            DeliveryStreamFailureType::CreateKmsGrantFailed => "CREATE_KMS_GRANT_FAILED",
+            DeliveryStreamFailureType::KmsAccessDenied => "KMS_ACCESS_DENIED",
+            DeliveryStreamFailureType::DisabledKmsKey => "DISABLED_KMS_KEY",
+            DeliveryStreamFailureType::InvalidKmsKey => "INVALID_KMS_KEY",
+            DeliveryStreamFailureType::KmsKeyNotFound => "KMS_KEY_NOT_FOUND",
+            DeliveryStreamFailureType::KmsOptInRequired => "KMS_OPT_IN_REQUIRED",
+            DeliveryStreamFailureType::CreateEniFailed => "CREATE_ENI_FAILED",
+            DeliveryStreamFailureType::DeleteEniFailed => "DELETE_ENI_FAILED",
+            DeliveryStreamFailureType::SubnetNotFound => "SUBNET_NOT_FOUND",
+            DeliveryStreamFailureType::SecurityGroupNotFound => "SECURITY_GROUP_NOT_FOUND",
+            DeliveryStreamFailureType::EniAccessDenied => "ENI_ACCESS_DENIED",
+            DeliveryStreamFailureType::SubnetAccessDenied => "SUBNET_ACCESS_DENIED",
+            DeliveryStreamFailureType::SecurityGroupAccessDenied => "SECURITY_GROUP_ACCESS_DENIED",
+            DeliveryStreamFailureType::UnknownError => "UNKNOWN_ERROR",
+            DeliveryStreamFailureType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DeliveryStreamFailureType {
+    fn default() -> DeliveryStreamFailureType {
+        DeliveryStreamFailureType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DeliveryStreamFailureType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DeliveryStreamFailureType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DeliveryStreamFailureType {
+    fn from(value: &'a str) -> DeliveryStreamFailureType {
+        match value {
+            "RETIRE_KMS_GRANT_FAILED" => DeliveryStreamFailureType::RetireKmsGrantFailed,
+            "CREATE_KMS_GRANT_FAILED" => DeliveryStreamFailureType::CreateKmsGrantFailed,
+            "KMS_ACCESS_DENIED" => DeliveryStreamFailureType::KmsAccessDenied,
+            "DISABLED_KMS_KEY" => DeliveryStreamFailureType::DisabledKmsKey,
+            "INVALID_KMS_KEY" => DeliveryStreamFailureType::InvalidKmsKey,
+            "KMS_KEY_NOT_FOUND" => DeliveryStreamFailureType::KmsKeyNotFound,
+            "KMS_OPT_IN_REQUIRED" => DeliveryStreamFailureType::KmsOptInRequired,
+            "CREATE_ENI_FAILED" => DeliveryStreamFailureType::CreateEniFailed,
+            "DELETE_ENI_FAILED" => DeliveryStreamFailureType::DeleteEniFailed,
+            "SUBNET_NOT_FOUND" => DeliveryStreamFailureType::SubnetNotFound,
+            "SECURITY_GROUP_NOT_FOUND" => DeliveryStreamFailureType::SecurityGroupNotFound,
+            "ENI_ACCESS_DENIED" => DeliveryStreamFailureType::EniAccessDenied,
+            "SUBNET_ACCESS_DENIED" => DeliveryStreamFailureType::SubnetAccessDenied,
+            "SECURITY_GROUP_ACCESS_DENIED" => DeliveryStreamFailureType::SecurityGroupAccessDenied,
+            "UNKNOWN_ERROR" => DeliveryStreamFailureType::UnknownError,
+            _ => DeliveryStreamFailureType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeliveryStreamFailureType {
+    fn from(value: String) -> DeliveryStreamFailureType {
+        match value.as_str() {
+            "RETIRE_KMS_GRANT_FAILED" => DeliveryStreamFailureType::RetireKmsGrantFailed,
+            "CREATE_KMS_GRANT_FAILED" => DeliveryStreamFailureType::CreateKmsGrantFailed,
+            "KMS_ACCESS_DENIED" => DeliveryStreamFailureType::KmsAccessDenied,
+            "DISABLED_KMS_KEY" => DeliveryStreamFailureType::DisabledKmsKey,
+            "INVALID_KMS_KEY" => DeliveryStreamFailureType::InvalidKmsKey,
+            "KMS_KEY_NOT_FOUND" => DeliveryStreamFailureType::KmsKeyNotFound,
+            "KMS_OPT_IN_REQUIRED" => DeliveryStreamFailureType::KmsOptInRequired,
+            "CREATE_ENI_FAILED" => DeliveryStreamFailureType::CreateEniFailed,
+            "DELETE_ENI_FAILED" => DeliveryStreamFailureType::DeleteEniFailed,
+            "SUBNET_NOT_FOUND" => DeliveryStreamFailureType::SubnetNotFound,
+            "SECURITY_GROUP_NOT_FOUND" => DeliveryStreamFailureType::SecurityGroupNotFound,
+            "ENI_ACCESS_DENIED" => DeliveryStreamFailureType::EniAccessDenied,
+            "SUBNET_ACCESS_DENIED" => DeliveryStreamFailureType::SubnetAccessDenied,
+            "SECURITY_GROUP_ACCESS_DENIED" => DeliveryStreamFailureType::SecurityGroupAccessDenied,
+            "UNKNOWN_ERROR" => DeliveryStreamFailureType::UnknownError,
+            _ => DeliveryStreamFailureType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DeliveryStreamFailureType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DeliveryStreamFailureType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DeliveryStreamFailureType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DeliveryStreamFailureType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeliveryStreamFailureType {
+    fn deserialize<D>(deserializer: D) -> Result<DeliveryStreamFailureType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DeliveryStreamStatus {
+    /// `CREATING`
+    Creating,
+    /// `CREATING_FAILED`
+    CreatingFailed,
+    /// `DELETING`
+    Deleting,
+    /// `DELETING_FAILED`
+    DeletingFailed,
+    /// `ACTIVE`
+    Active,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DeliveryStreamStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeliveryStreamStatus::Creating => "CREATING",
+            DeliveryStreamStatus::CreatingFailed => "CREATING_FAILED",
+            DeliveryStreamStatus::Deleting => "DELETING",
+            DeliveryStreamStatus::DeletingFailed => "DELETING_FAILED",
+            DeliveryStreamStatus::Active => "ACTIVE",
+            DeliveryStreamStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DeliveryStreamStatus {
+    fn default() -> DeliveryStreamStatus {
+        DeliveryStreamStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DeliveryStreamStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DeliveryStreamStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DeliveryStreamStatus {
+    fn from(value: &'a str) -> DeliveryStreamStatus {
+        match value {
+            "CREATING" => DeliveryStreamStatus::Creating,
+            "CREATING_FAILED" => DeliveryStreamStatus::CreatingFailed,
+            "DELETING" => DeliveryStreamStatus::Deleting,
+            "DELETING_FAILED" => DeliveryStreamStatus::DeletingFailed,
+            "ACTIVE" => DeliveryStreamStatus::Active,
+            _ => DeliveryStreamStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeliveryStreamStatus {
+    fn from(value: String) -> DeliveryStreamStatus {
+        match value.as_str() {
+            "CREATING" => DeliveryStreamStatus::Creating,
+            "CREATING_FAILED" => DeliveryStreamStatus::CreatingFailed,
+            "DELETING" => DeliveryStreamStatus::Deleting,
+            "DELETING_FAILED" => DeliveryStreamStatus::DeletingFailed,
+            "ACTIVE" => DeliveryStreamStatus::Active,
+            _ => DeliveryStreamStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DeliveryStreamStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DeliveryStreamStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DeliveryStreamStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DeliveryStreamStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeliveryStreamStatus {
+    fn deserialize<D>(deserializer: D) -> Result<DeliveryStreamStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DeliveryStreamType {
+    /// `DirectPut`
+    DirectPut,
+    /// `KinesisStreamAsSource`
+    KinesisStreamAsSource,
+    /// `MSKAsSource`
+    MSKAsSource,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DeliveryStreamType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeliveryStreamType::DirectPut => "DirectPut",
+            DeliveryStreamType::KinesisStreamAsSource => "KinesisStreamAsSource",
+            DeliveryStreamType::MSKAsSource => "MSKAsSource",
+            DeliveryStreamType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DeliveryStreamType {
+    fn default() -> DeliveryStreamType {
+        DeliveryStreamType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DeliveryStreamType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DeliveryStreamType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DeliveryStreamType {
+    fn from(value: &'a str) -> DeliveryStreamType {
+        match value {
+            "DirectPut" => DeliveryStreamType::DirectPut,
+            "KinesisStreamAsSource" => DeliveryStreamType::KinesisStreamAsSource,
+            "MSKAsSource" => DeliveryStreamType::MSKAsSource,
+            _ => DeliveryStreamType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeliveryStreamType {
+    fn from(value: String) -> DeliveryStreamType {
+        match value.as_str() {
+            "DirectPut" => DeliveryStreamType::DirectPut,
+            "KinesisStreamAsSource" => DeliveryStreamType::KinesisStreamAsSource,
+            "MSKAsSource" => DeliveryStreamType::MSKAsSource,
+            _ => DeliveryStreamType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DeliveryStreamType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DeliveryStreamType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DeliveryStreamType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DeliveryStreamType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for DeliveryStreamType {
+    fn deserialize<D>(deserializer: D) -> Result<DeliveryStreamType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct DescribeDeliveryStreamInput {
@@ -344,7 +939,7 @@ pub struct ElasticsearchDestinationConfiguration {
     /// <p>The Elasticsearch index rotation period. Index rotation appends a timestamp to the <code>IndexName</code> to facilitate the expiration of old data. For more information, see <a href="https://docs.aws.amazon.com/firehose/latest/dev/basic-deliver.html#es-index-rotation">Index Rotation for the Amazon ES Destination</a>. The default value is <code>OneDay</code>.</p>
     #[serde(rename = "IndexRotationPeriod")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub index_rotation_period: Option<String>,
+    pub index_rotation_period: Option<ElasticsearchIndexRotationPeriod>,
     /// <p>The data processing configuration.</p>
     #[serde(rename = "ProcessingConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -359,7 +954,7 @@ pub struct ElasticsearchDestinationConfiguration {
     /// <p>Defines how documents should be delivered to Amazon S3. When it is set to <code>FailedDocumentsOnly</code>, Kinesis Data Firehose writes any documents that could not be indexed to the configured Amazon S3 destination, with <code>elasticsearch-failed/</code> appended to the key prefix. When set to <code>AllDocuments</code>, Kinesis Data Firehose delivers all incoming records to Amazon S3, and also writes failed documents with <code>elasticsearch-failed/</code> appended to the prefix. For more information, see <a href="https://docs.aws.amazon.com/firehose/latest/dev/basic-deliver.html#es-s3-backup">Amazon S3 Backup for the Amazon ES Destination</a>. Default value is <code>FailedDocumentsOnly</code>.</p>
     #[serde(rename = "S3BackupMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub s3_backup_mode: Option<String>,
+    pub s3_backup_mode: Option<ElasticsearchS3BackupMode>,
     /// <p>The configuration for the backup Amazon S3 location.</p>
     #[serde(rename = "S3Configuration")]
     pub s3_configuration: S3DestinationConfiguration,
@@ -396,7 +991,7 @@ pub struct ElasticsearchDestinationDescription {
     /// <p>The Elasticsearch index rotation period</p>
     #[serde(rename = "IndexRotationPeriod")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub index_rotation_period: Option<String>,
+    pub index_rotation_period: Option<ElasticsearchIndexRotationPeriod>,
     /// <p>The data processing configuration.</p>
     #[serde(rename = "ProcessingConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -412,7 +1007,7 @@ pub struct ElasticsearchDestinationDescription {
     /// <p>The Amazon S3 backup mode.</p>
     #[serde(rename = "S3BackupMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub s3_backup_mode: Option<String>,
+    pub s3_backup_mode: Option<ElasticsearchS3BackupMode>,
     /// <p>The Amazon S3 destination.</p>
     #[serde(rename = "S3DestinationDescription")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -450,7 +1045,7 @@ pub struct ElasticsearchDestinationUpdate {
     /// <p>The Elasticsearch index rotation period. Index rotation appends a timestamp to <code>IndexName</code> to facilitate the expiration of old data. For more information, see <a href="https://docs.aws.amazon.com/firehose/latest/dev/basic-deliver.html#es-index-rotation">Index Rotation for the Amazon ES Destination</a>. Default value is <code>OneDay</code>.</p>
     #[serde(rename = "IndexRotationPeriod")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub index_rotation_period: Option<String>,
+    pub index_rotation_period: Option<ElasticsearchIndexRotationPeriod>,
     /// <p>The data processing configuration.</p>
     #[serde(rename = "ProcessingConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -473,6 +1068,114 @@ pub struct ElasticsearchDestinationUpdate {
     pub type_name: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ElasticsearchIndexRotationPeriod {
+    /// `NoRotation`
+    NoRotation,
+    /// `OneHour`
+    OneHour,
+    /// `OneDay`
+    OneDay,
+    /// `OneWeek`
+    OneWeek,
+    /// `OneMonth`
+    OneMonth,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ElasticsearchIndexRotationPeriod {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ElasticsearchIndexRotationPeriod::NoRotation => "NoRotation",
+            ElasticsearchIndexRotationPeriod::OneHour => "OneHour",
+            ElasticsearchIndexRotationPeriod::OneDay => "OneDay",
+            ElasticsearchIndexRotationPeriod::OneWeek => "OneWeek",
+            ElasticsearchIndexRotationPeriod::OneMonth => "OneMonth",
+            ElasticsearchIndexRotationPeriod::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ElasticsearchIndexRotationPeriod {
+    fn default() -> ElasticsearchIndexRotationPeriod {
+        ElasticsearchIndexRotationPeriod::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ElasticsearchIndexRotationPeriod {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ElasticsearchIndexRotationPeriod {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ElasticsearchIndexRotationPeriod {
+    fn from(value: &'a str) -> ElasticsearchIndexRotationPeriod {
+        match value {
+            "NoRotation" => ElasticsearchIndexRotationPeriod::NoRotation,
+            "OneHour" => ElasticsearchIndexRotationPeriod::OneHour,
+            "OneDay" => ElasticsearchIndexRotationPeriod::OneDay,
+            "OneWeek" => ElasticsearchIndexRotationPeriod::OneWeek,
+            "OneMonth" => ElasticsearchIndexRotationPeriod::OneMonth,
+            _ => ElasticsearchIndexRotationPeriod::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ElasticsearchIndexRotationPeriod {
+    fn from(value: String) -> ElasticsearchIndexRotationPeriod {
+        match value.as_str() {
+            "NoRotation" => ElasticsearchIndexRotationPeriod::NoRotation,
+            "OneHour" => ElasticsearchIndexRotationPeriod::OneHour,
+            "OneDay" => ElasticsearchIndexRotationPeriod::OneDay,
+            "OneWeek" => ElasticsearchIndexRotationPeriod::OneWeek,
+            "OneMonth" => ElasticsearchIndexRotationPeriod::OneMonth,
+            _ => ElasticsearchIndexRotationPeriod::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ElasticsearchIndexRotationPeriod {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ElasticsearchIndexRotationPeriod, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ElasticsearchIndexRotationPeriod {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ElasticsearchIndexRotationPeriod {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ElasticsearchIndexRotationPeriod {
+    fn deserialize<D>(deserializer: D) -> Result<ElasticsearchIndexRotationPeriod, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Configures retry behavior in case Kinesis Data Firehose is unable to deliver documents to Amazon ES.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ElasticsearchRetryOptions {
@@ -482,17 +1185,110 @@ pub struct ElasticsearchRetryOptions {
     pub duration_in_seconds: Option<i64>,
 }
 
-/// <p>Describes the encryption for a destination in Amazon S3.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct EncryptionConfiguration {
-    /// <p>The encryption key.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ElasticsearchS3BackupMode {
+    /// `FailedDocumentsOnly`
+    FailedDocumentsOnly,
+    /// `AllDocuments`
+    AllDocuments,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ElasticsearchS3BackupMode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ElasticsearchS3BackupMode::FailedDocumentsOnly => "FailedDocumentsOnly",
+            ElasticsearchS3BackupMode::AllDocuments => "AllDocuments",
+            ElasticsearchS3BackupMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ElasticsearchS3BackupMode {
+    fn default() -> ElasticsearchS3BackupMode {
+        ElasticsearchS3BackupMode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ElasticsearchS3BackupMode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ElasticsearchS3BackupMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ElasticsearchS3BackupMode {
+    fn from(value: &'a str) -> ElasticsearchS3BackupMode {
+        match value {
+            "FailedDocumentsOnly" => ElasticsearchS3BackupMode::FailedDocumentsOnly,
+            "AllDocuments" => ElasticsearchS3BackupMode::AllDocuments,
+            _ => ElasticsearchS3BackupMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ElasticsearchS3BackupMode {
+    fn from(value: String) -> ElasticsearchS3BackupMode {
+        match value.as_str() {
+            "FailedDocumentsOnly" => ElasticsearchS3BackupMode::FailedDocumentsOnly,
+            "AllDocuments" => ElasticsearchS3BackupMode::AllDocuments,
+            _ => ElasticsearchS3BackupMode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ElasticsearchS3BackupMode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ElasticsearchS3BackupMode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ElasticsearchS3BackupMode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ElasticsearchS3BackupMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ElasticsearchS3BackupMode {
+    fn deserialize<D>(deserializer: D) -> Result<ElasticsearchS3BackupMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Describes the encryption for a destination in Amazon S3.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EncryptionConfiguration {
+    /// <p>The encryption key.</p>
     #[serde(rename = "KMSEncryptionConfig")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub kms_encryption_config: Option<KMSEncryptionConfig>,
     /// <p>Specifically override existing encryption information to ensure that no encryption is used.</p>
     #[serde(rename = "NoEncryptionConfig")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub no_encryption_config: Option<String>,
+    pub no_encryption_config: Option<NoEncryptionConfig>,
 }
 
 /// <p>Describes the configuration of a destination in Amazon S3.</p>
@@ -513,7 +1309,7 @@ pub struct ExtendedS3DestinationConfiguration {
     /// <p>The compression format. If no value is specified, the default is UNCOMPRESSED.</p>
     #[serde(rename = "CompressionFormat")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compression_format: Option<String>,
+    pub compression_format: Option<CompressionFormat>,
     /// <p>The serializer, deserializer, and schema for converting data from the JSON format to the Parquet or ORC format before writing it to Amazon S3.</p>
     #[serde(rename = "DataFormatConversionConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -544,7 +1340,7 @@ pub struct ExtendedS3DestinationConfiguration {
     /// <p>The Amazon S3 backup mode.</p>
     #[serde(rename = "S3BackupMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub s3_backup_mode: Option<String>,
+    pub s3_backup_mode: Option<S3BackupMode>,
 }
 
 /// <p>Describes a destination in Amazon S3.</p>
@@ -563,7 +1359,7 @@ pub struct ExtendedS3DestinationDescription {
     pub cloud_watch_logging_options: Option<CloudWatchLoggingOptions>,
     /// <p>The compression format. If no value is specified, the default is <code>UNCOMPRESSED</code>.</p>
     #[serde(rename = "CompressionFormat")]
-    pub compression_format: String,
+    pub compression_format: CompressionFormat,
     /// <p>The serializer, deserializer, and schema for converting data from the JSON format to the Parquet or ORC format before writing it to Amazon S3.</p>
     #[serde(rename = "DataFormatConversionConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -593,7 +1389,7 @@ pub struct ExtendedS3DestinationDescription {
     /// <p>The Amazon S3 backup mode.</p>
     #[serde(rename = "S3BackupMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub s3_backup_mode: Option<String>,
+    pub s3_backup_mode: Option<S3BackupMode>,
 }
 
 /// <p>Describes an update for a destination in Amazon S3.</p>
@@ -615,7 +1411,7 @@ pub struct ExtendedS3DestinationUpdate {
     /// <p>The compression format. If no value is specified, the default is <code>UNCOMPRESSED</code>. </p>
     #[serde(rename = "CompressionFormat")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compression_format: Option<String>,
+    pub compression_format: Option<CompressionFormat>,
     /// <p>The serializer, deserializer, and schema for converting data from the JSON format to the Parquet or ORC format before writing it to Amazon S3.</p>
     #[serde(rename = "DataFormatConversionConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -643,7 +1439,7 @@ pub struct ExtendedS3DestinationUpdate {
     /// <p>Enables or disables Amazon S3 backup mode.</p>
     #[serde(rename = "S3BackupMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub s3_backup_mode: Option<String>,
+    pub s3_backup_mode: Option<S3BackupMode>,
     /// <p>The Amazon S3 destination for backup.</p>
     #[serde(rename = "S3BackupUpdate")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -659,9 +1455,102 @@ pub struct FailureDescription {
     pub details: String,
     /// <p>The type of error that caused the failure.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: DeliveryStreamFailureType,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum HECEndpointType {
+    /// `Raw`
+    Raw,
+    /// `Event`
+    Event,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl HECEndpointType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            HECEndpointType::Raw => "Raw",
+            HECEndpointType::Event => "Event",
+            HECEndpointType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for HECEndpointType {
+    fn default() -> HECEndpointType {
+        HECEndpointType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for HECEndpointType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for HECEndpointType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for HECEndpointType {
+    fn from(value: &'a str) -> HECEndpointType {
+        match value {
+            "Raw" => HECEndpointType::Raw,
+            "Event" => HECEndpointType::Event,
+            _ => HECEndpointType::Unknown(value.to_owned()),
+        }
+    }
 }
 
+impl From<String> for HECEndpointType {
+    fn from(value: String) -> HECEndpointType {
+        match value.as_str() {
+            "Raw" => HECEndpointType::Raw,
+            "Event" => HECEndpointType::Event,
+            _ => HECEndpointType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for HECEndpointType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<HECEndpointType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for HECEndpointType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for HECEndpointType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for HECEndpointType {
+    fn deserialize<D>(deserializer: D) -> Result<HECEndpointType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The native Hive / HCatalog JsonSerDe. Used by Kinesis Data Firehose for deserializing data, which means converting it from the JSON format in preparation for serializing it to the Parquet or ORC format. This is one of two deserializers you can choose, depending on which one offers the functionality you need. The other option is the OpenX SerDe.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct HiveJsonSerDe {
@@ -688,6 +1577,99 @@ pub struct KMSEncryptionConfig {
     pub awskms_key_arn: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum KeyType {
+    /// `AWS_OWNED_CMK`
+    AwsOwnedCmk,
+    /// `CUSTOMER_MANAGED_CMK`
+    CustomerManagedCmk,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl KeyType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            KeyType::AwsOwnedCmk => "AWS_OWNED_CMK",
+            KeyType::CustomerManagedCmk => "CUSTOMER_MANAGED_CMK",
+            KeyType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for KeyType {
+    fn default() -> KeyType {
+        KeyType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for KeyType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for KeyType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for KeyType {
+    fn from(value: &'a str) -> KeyType {
+        match value {
+            "AWS_OWNED_CMK" => KeyType::AwsOwnedCmk,
+            "CUSTOMER_MANAGED_CMK" => KeyType::CustomerManagedCmk,
+            _ => KeyType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for KeyType {
+    fn from(value: String) -> KeyType {
+        match value.as_str() {
+            "AWS_OWNED_CMK" => KeyType::AwsOwnedCmk,
+            "CUSTOMER_MANAGED_CMK" => KeyType::CustomerManagedCmk,
+            _ => KeyType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for KeyType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<KeyType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for KeyType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for KeyType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for KeyType {
+    fn deserialize<D>(deserializer: D) -> Result<KeyType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The stream and role Amazon Resource Names (ARNs) for a Kinesis data stream used as the source for a delivery stream.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -706,8 +1688,13 @@ pub struct KinesisStreamSourceConfiguration {
 pub struct KinesisStreamSourceDescription {
     /// <p>Kinesis Data Firehose starts retrieving records from the Kinesis data stream starting with this timestamp.</p>
     #[serde(rename = "DeliveryStartTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub delivery_start_timestamp: Option<f64>,
+    pub delivery_start_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The Amazon Resource Name (ARN) of the source Kinesis data stream. For more information, see <a href="https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html#arn-syntax-kinesis-streams">Amazon Kinesis Data Streams ARN Format</a>.</p>
     #[serde(rename = "KinesisStreamARN")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -724,7 +1711,7 @@ pub struct ListDeliveryStreamsInput {
     /// <p>The delivery stream type. This can be one of the following values:</p> <ul> <li> <p> <code>DirectPut</code>: Provider applications access the delivery stream directly.</p> </li> <li> <p> <code>KinesisStreamAsSource</code>: The delivery stream uses a Kinesis data stream as a source.</p> </li> </ul> <p>This parameter is optional. If this parameter is omitted, delivery streams of all types are returned.</p>
     #[serde(rename = "DeliveryStreamType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub delivery_stream_type: Option<String>,
+    pub delivery_stream_type: Option<DeliveryStreamType>,
     /// <p>The list of delivery streams returned by this call to <code>ListDeliveryStreams</code> will start with the delivery stream whose name comes alphabetically immediately after the name you specify in <code>ExclusiveStartDeliveryStreamName</code>.</p>
     #[serde(rename = "ExclusiveStartDeliveryStreamName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -773,6 +1760,94 @@ pub struct ListTagsForDeliveryStreamOutput {
     pub tags: Vec<Tag>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum NoEncryptionConfig {
+    /// `NoEncryption`
+    NoEncryption,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl NoEncryptionConfig {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            NoEncryptionConfig::NoEncryption => "NoEncryption",
+            NoEncryptionConfig::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for NoEncryptionConfig {
+    fn default() -> NoEncryptionConfig {
+        NoEncryptionConfig::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for NoEncryptionConfig {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for NoEncryptionConfig {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for NoEncryptionConfig {
+    fn from(value: &'a str) -> NoEncryptionConfig {
+        match value {
+            "NoEncryption" => NoEncryptionConfig::NoEncryption,
+            _ => NoEncryptionConfig::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for NoEncryptionConfig {
+    fn from(value: String) -> NoEncryptionConfig {
+        match value.as_str() {
+            "NoEncryption" => NoEncryptionConfig::NoEncryption,
+            _ => NoEncryptionConfig::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for NoEncryptionConfig {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<NoEncryptionConfig, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for NoEncryptionConfig {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for NoEncryptionConfig {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for NoEncryptionConfig {
+    fn deserialize<D>(deserializer: D) -> Result<NoEncryptionConfig, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The OpenX SerDe. Used by Kinesis Data Firehose for deserializing data, which means converting it from the JSON format in preparation for serializing it to the Parquet or ORC format. This is one of two deserializers you can choose, depending on which one offers the functionality you need. The other option is the native Hive / HCatalog JsonSerDe.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct OpenXJsonSerDe {
@@ -790,6 +1865,197 @@ pub struct OpenXJsonSerDe {
     pub convert_dots_in_json_keys_to_underscores: Option<bool>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OrcCompression {
+    /// `NONE`
+    None,
+    /// `ZLIB`
+    Zlib,
+    /// `SNAPPY`
+    Snappy,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl OrcCompression {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OrcCompression::None => "NONE",
+            OrcCompression::Zlib => "ZLIB",
+            OrcCompression::Snappy => "SNAPPY",
+            OrcCompression::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for OrcCompression {
+    fn default() -> OrcCompression {
+        OrcCompression::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for OrcCompression {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for OrcCompression {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for OrcCompression {
+    fn from(value: &'a str) -> OrcCompression {
+        match value {
+            "NONE" => OrcCompression::None,
+            "ZLIB" => OrcCompression::Zlib,
+            "SNAPPY" => OrcCompression::Snappy,
+            _ => OrcCompression::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OrcCompression {
+    fn from(value: String) -> OrcCompression {
+        match value.as_str() {
+            "NONE" => OrcCompression::None,
+            "ZLIB" => OrcCompression::Zlib,
+            "SNAPPY" => OrcCompression::Snappy,
+            _ => OrcCompression::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for OrcCompression {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<OrcCompression, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OrcCompression {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for OrcCompression {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OrcCompression {
+    fn deserialize<D>(deserializer: D) -> Result<OrcCompression, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OrcFormatVersion {
+    /// `V0_11`
+    V0_11,
+    /// `V0_12`
+    V0_12,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl OrcFormatVersion {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OrcFormatVersion::V0_11 => "V0_11",
+            OrcFormatVersion::V0_12 => "V0_12",
+            OrcFormatVersion::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for OrcFormatVersion {
+    fn default() -> OrcFormatVersion {
+        OrcFormatVersion::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for OrcFormatVersion {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for OrcFormatVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for OrcFormatVersion {
+    fn from(value: &'a str) -> OrcFormatVersion {
+        match value {
+            "V0_11" => OrcFormatVersion::V0_11,
+            "V0_12" => OrcFormatVersion::V0_12,
+            _ => OrcFormatVersion::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OrcFormatVersion {
+    fn from(value: String) -> OrcFormatVersion {
+        match value.as_str() {
+            "V0_11" => OrcFormatVersion::V0_11,
+            "V0_12" => OrcFormatVersion::V0_12,
+            _ => OrcFormatVersion::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for OrcFormatVersion {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<OrcFormatVersion, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OrcFormatVersion {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for OrcFormatVersion {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OrcFormatVersion {
+    fn deserialize<D>(deserializer: D) -> Result<OrcFormatVersion, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A serializer to use for converting data to the ORC format before storing it in Amazon S3. For more information, see <a href="https://orc.apache.org/docs/">Apache ORC</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct OrcSerDe {
@@ -808,7 +2074,7 @@ pub struct OrcSerDe {
     /// <p>The compression code to use over data blocks. The default is <code>SNAPPY</code>.</p>
     #[serde(rename = "Compression")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compression: Option<String>,
+    pub compression: Option<OrcCompression>,
     /// <p>Represents the fraction of the total number of non-null rows. To turn off dictionary encoding, set this fraction to a number that is less than the number of distinct keys in a dictionary. To always use dictionary encoding, set this threshold to 1.</p>
     #[serde(rename = "DictionaryKeyThreshold")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -820,7 +2086,7 @@ pub struct OrcSerDe {
     /// <p>The version of the file to write. The possible values are <code>V0_11</code> and <code>V0_12</code>. The default is <code>V0_12</code>.</p>
     #[serde(rename = "FormatVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub format_version: Option<String>,
+    pub format_version: Option<OrcFormatVersion>,
     /// <p>A number between 0 and 1 that defines the tolerance for block padding as a decimal fraction of stripe size. The default value is 0.05, which means 5 percent of stripe size.</p> <p>For the default values of 64 MiB ORC stripes and 256 MiB HDFS blocks, the default block padding tolerance of 5 percent reserves a maximum of 3.2 MiB for padding within the 256 MiB block. In such a case, if the available size within the block is more than 3.2 MiB, a new, smaller stripe is inserted to fit within that space. This ensures that no stripe crosses block boundaries and causes remote reads within a node-local task.</p> <p>Kinesis Data Firehose ignores this parameter when <a>OrcSerDe$EnablePadding</a> is <code>false</code>.</p>
     #[serde(rename = "PaddingTolerance")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -844,6 +2110,104 @@ pub struct OutputFormatConfiguration {
     pub serializer: Option<Serializer>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ParquetCompression {
+    /// `UNCOMPRESSED`
+    Uncompressed,
+    /// `GZIP`
+    Gzip,
+    /// `SNAPPY`
+    Snappy,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ParquetCompression {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ParquetCompression::Uncompressed => "UNCOMPRESSED",
+            ParquetCompression::Gzip => "GZIP",
+            ParquetCompression::Snappy => "SNAPPY",
+            ParquetCompression::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ParquetCompression {
+    fn default() -> ParquetCompression {
+        ParquetCompression::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ParquetCompression {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ParquetCompression {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ParquetCompression {
+    fn from(value: &'a str) -> ParquetCompression {
+        match value {
+            "UNCOMPRESSED" => ParquetCompression::Uncompressed,
+            "GZIP" => ParquetCompression::Gzip,
+            "SNAPPY" => ParquetCompression::Snappy,
+            _ => ParquetCompression::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ParquetCompression {
+    fn from(value: String) -> ParquetCompression {
+        match value.as_str() {
+            "UNCOMPRESSED" => ParquetCompression::Uncompressed,
+            "GZIP" => ParquetCompression::Gzip,
+            "SNAPPY" => ParquetCompression::Snappy,
+            _ => ParquetCompression::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ParquetCompression {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ParquetCompression, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ParquetCompression {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ParquetCompression {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ParquetCompression {
+    fn deserialize<D>(deserializer: D) -> Result<ParquetCompression, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A serializer to use for converting data to the Parquet format before storing it in Amazon S3. For more information, see <a href="https://parquet.apache.org/documentation/latest/">Apache Parquet</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ParquetSerDe {
@@ -854,7 +2218,7 @@ pub struct ParquetSerDe {
     /// <p>The compression code to use over data blocks. The possible values are <code>UNCOMPRESSED</code>, <code>SNAPPY</code>, and <code>GZIP</code>, with the default being <code>SNAPPY</code>. Use <code>SNAPPY</code> for higher decompression speed. Use <code>GZIP</code> if the compression ratio is more important than speed.</p>
     #[serde(rename = "Compression")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compression: Option<String>,
+    pub compression: Option<ParquetCompression>,
     /// <p>Indicates whether to enable dictionary compression.</p>
     #[serde(rename = "EnableDictionaryCompression")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -870,9 +2234,102 @@ pub struct ParquetSerDe {
     /// <p>Indicates the version of row format to output. The possible values are <code>V1</code> and <code>V2</code>. The default is <code>V1</code>.</p>
     #[serde(rename = "WriterVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub writer_version: Option<String>,
+    pub writer_version: Option<ParquetWriterVersion>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ParquetWriterVersion {
+    /// `V1`
+    V1,
+    /// `V2`
+    V2,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ParquetWriterVersion {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ParquetWriterVersion::V1 => "V1",
+            ParquetWriterVersion::V2 => "V2",
+            ParquetWriterVersion::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ParquetWriterVersion {
+    fn default() -> ParquetWriterVersion {
+        ParquetWriterVersion::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ParquetWriterVersion {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ParquetWriterVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ParquetWriterVersion {
+    fn from(value: &'a str) -> ParquetWriterVersion {
+        match value {
+            "V1" => ParquetWriterVersion::V1,
+            "V2" => ParquetWriterVersion::V2,
+            _ => ParquetWriterVersion::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ParquetWriterVersion {
+    fn from(value: String) -> ParquetWriterVersion {
+        match value.as_str() {
+            "V1" => ParquetWriterVersion::V1,
+            "V2" => ParquetWriterVersion::V2,
+            _ => ParquetWriterVersion::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ParquetWriterVersion {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ParquetWriterVersion, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ParquetWriterVersion {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ParquetWriterVersion {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for ParquetWriterVersion {
+    fn deserialize<D>(deserializer: D) -> Result<ParquetWriterVersion, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes a data processing configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ProcessingConfiguration {
@@ -895,7 +2352,7 @@ pub struct Processor {
     pub parameters: Option<Vec<ProcessorParameter>>,
     /// <p>The type of processor.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: ProcessorType,
 }
 
 /// <p>Describes the processor parameter.</p>
@@ -903,12 +2360,253 @@ pub struct Processor {
 pub struct ProcessorParameter {
     /// <p>The name of the parameter.</p>
     #[serde(rename = "ParameterName")]
-    pub parameter_name: String,
+    pub parameter_name: ProcessorParameterName,
     /// <p>The parameter value.</p>
     #[serde(rename = "ParameterValue")]
     pub parameter_value: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ProcessorParameterName {
+    /// `LambdaArn`
+    LambdaArn,
+    /// `NumberOfRetries`
+    NumberOfRetries,
+    /// `MetadataExtractionQuery`
+    MetadataExtractionQuery,
+    /// `JsonParsingEngine`
+    JsonParsingEngine,
+    /// `RoleArn`
+    RoleArn,
+    /// `BufferSizeInMBs`
+    BufferSizeInMBs,
+    /// `BufferIntervalInSeconds`
+    BufferIntervalInSeconds,
+    /// `SubRecordType`
+    SubRecordType,
+    /// `Delimiter`
+    Delimiter,
+    /// `CompressionFormat`
+    CompressionFormat,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ProcessorParameterName {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ProcessorParameterName::LambdaArn => "LambdaArn",
+            ProcessorParameterName::NumberOfRetries => "NumberOfRetries",
+            ProcessorParameterName::MetadataExtractionQuery => "MetadataExtractionQuery",
+            ProcessorParameterName::JsonParsingEngine => "JsonParsingEngine",
+            ProcessorParameterName::RoleArn => "RoleArn",
+            ProcessorParameterName::BufferSizeInMBs => "BufferSizeInMBs",
+            ProcessorParameterName::BufferIntervalInSeconds => "BufferIntervalInSeconds",
+            ProcessorParameterName::SubRecordType => "SubRecordType",
+            ProcessorParameterName::Delimiter => "Delimiter",
+            ProcessorParameterName::CompressionFormat => "CompressionFormat",
+            ProcessorParameterName::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ProcessorParameterName {
+    fn default() -> ProcessorParameterName {
+        ProcessorParameterName::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ProcessorParameterName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ProcessorParameterName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ProcessorParameterName {
+    fn from(value: &'a str) -> ProcessorParameterName {
+        match value {
+            "LambdaArn" => ProcessorParameterName::LambdaArn,
+            "NumberOfRetries" => ProcessorParameterName::NumberOfRetries,
+            "MetadataExtractionQuery" => ProcessorParameterName::MetadataExtractionQuery,
+            "JsonParsingEngine" => ProcessorParameterName::JsonParsingEngine,
+            "RoleArn" => ProcessorParameterName::RoleArn,
+            "BufferSizeInMBs" => ProcessorParameterName::BufferSizeInMBs,
+            "BufferIntervalInSeconds" => ProcessorParameterName::BufferIntervalInSeconds,
+            "SubRecordType" => ProcessorParameterName::SubRecordType,
+            "Delimiter" => ProcessorParameterName::Delimiter,
+            "CompressionFormat" => ProcessorParameterName::CompressionFormat,
+            _ => ProcessorParameterName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ProcessorParameterName {
+    fn from(value: String) -> ProcessorParameterName {
+        match value.as_str() {
+            "LambdaArn" => ProcessorParameterName::LambdaArn,
+            "NumberOfRetries" => ProcessorParameterName::NumberOfRetries,
+            "MetadataExtractionQuery" => ProcessorParameterName::MetadataExtractionQuery,
+            "JsonParsingEngine" => ProcessorParameterName::JsonParsingEngine,
+            "RoleArn" => ProcessorParameterName::RoleArn,
+            "BufferSizeInMBs" => ProcessorParameterName::BufferSizeInMBs,
+            "BufferIntervalInSeconds" => ProcessorParameterName::BufferIntervalInSeconds,
+            "SubRecordType" => ProcessorParameterName::SubRecordType,
+            "Delimiter" => ProcessorParameterName::Delimiter,
+            "CompressionFormat" => ProcessorParameterName::CompressionFormat,
+            _ => ProcessorParameterName::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ProcessorParameterName {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ProcessorParameterName, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ProcessorParameterName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ProcessorParameterName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ProcessorParameterName {
+    fn deserialize<D>(deserializer: D) -> Result<ProcessorParameterName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ProcessorType {
+    /// `RecordDeAggregation`
+    RecordDeAggregation,
+    /// `Decompression`
+    Decompression,
+    /// `Lambda`
+    Lambda,
+    /// `MetadataExtraction`
+    MetadataExtraction,
+    /// `AppendDelimiterToRecord`
+    AppendDelimiterToRecord,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ProcessorType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ProcessorType::RecordDeAggregation => "RecordDeAggregation",
+            ProcessorType::Decompression => "Decompression",
+            ProcessorType::Lambda => "Lambda",
+            ProcessorType::MetadataExtraction => "MetadataExtraction",
+            ProcessorType::AppendDelimiterToRecord => "AppendDelimiterToRecord",
+            ProcessorType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ProcessorType {
+    fn default() -> ProcessorType {
+        ProcessorType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ProcessorType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ProcessorType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ProcessorType {
+    fn from(value: &'a str) -> ProcessorType {
+        match value {
+            "RecordDeAggregation" => ProcessorType::RecordDeAggregation,
+            "Decompression" => ProcessorType::Decompression,
+            "Lambda" => ProcessorType::Lambda,
+            "MetadataExtraction" => ProcessorType::MetadataExtraction,
+            "AppendDelimiterToRecord" => ProcessorType::AppendDelimiterToRecord,
+            _ => ProcessorType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ProcessorType {
+    fn from(value: String) -> ProcessorType {
+        match value.as_str() {
+            "RecordDeAggregation" => ProcessorType::RecordDeAggregation,
+            "Decompression" => ProcessorType::Decompression,
+            "Lambda" => ProcessorType::Lambda,
+            "MetadataExtraction" => ProcessorType::MetadataExtraction,
+            "AppendDelimiterToRecord" => ProcessorType::AppendDelimiterToRecord,
+            _ => ProcessorType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ProcessorType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ProcessorType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ProcessorType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ProcessorType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ProcessorType {
+    fn deserialize<D>(deserializer: D) -> Result<ProcessorType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct PutRecordBatchInput {
@@ -1025,7 +2723,7 @@ pub struct RedshiftDestinationConfiguration {
     /// <p>The Amazon S3 backup mode.</p>
     #[serde(rename = "S3BackupMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub s3_backup_mode: Option<String>,
+    pub s3_backup_mode: Option<RedshiftS3BackupMode>,
     /// <p>The configuration for the intermediate Amazon S3 location from which Amazon Redshift obtains data. Restrictions are described in the topic for <a>CreateDeliveryStream</a>.</p> <p>The compression formats <code>SNAPPY</code> or <code>ZIP</code> cannot be specified in <code>RedshiftDestinationConfiguration.S3Configuration</code> because the Amazon Redshift <code>COPY</code> operation that reads from the S3 bucket doesn't support these compression formats.</p>
     #[serde(rename = "S3Configuration")]
     pub s3_configuration: S3DestinationConfiguration,
@@ -1066,7 +2764,7 @@ pub struct RedshiftDestinationDescription {
     /// <p>The Amazon S3 backup mode.</p>
     #[serde(rename = "S3BackupMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub s3_backup_mode: Option<String>,
+    pub s3_backup_mode: Option<RedshiftS3BackupMode>,
     /// <p>The Amazon S3 destination.</p>
     #[serde(rename = "S3DestinationDescription")]
     pub s3_destination_description: S3DestinationDescription,
@@ -1110,7 +2808,7 @@ pub struct RedshiftDestinationUpdate {
     /// <p>The Amazon S3 backup mode.</p>
     #[serde(rename = "S3BackupMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub s3_backup_mode: Option<String>,
+    pub s3_backup_mode: Option<RedshiftS3BackupMode>,
     /// <p>The Amazon S3 destination for backup.</p>
     #[serde(rename = "S3BackupUpdate")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1134,6 +2832,192 @@ pub struct RedshiftRetryOptions {
     pub duration_in_seconds: Option<i64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RedshiftS3BackupMode {
+    /// `Disabled`
+    Disabled,
+    /// `Enabled`
+    Enabled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RedshiftS3BackupMode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RedshiftS3BackupMode::Disabled => "Disabled",
+            RedshiftS3BackupMode::Enabled => "Enabled",
+            RedshiftS3BackupMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RedshiftS3BackupMode {
+    fn default() -> RedshiftS3BackupMode {
+        RedshiftS3BackupMode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RedshiftS3BackupMode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RedshiftS3BackupMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RedshiftS3BackupMode {
+    fn from(value: &'a str) -> RedshiftS3BackupMode {
+        match value {
+            "Disabled" => RedshiftS3BackupMode::Disabled,
+            "Enabled" => RedshiftS3BackupMode::Enabled,
+            _ => RedshiftS3BackupMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RedshiftS3BackupMode {
+    fn from(value: String) -> RedshiftS3BackupMode {
+        match value.as_str() {
+            "Disabled" => RedshiftS3BackupMode::Disabled,
+            "Enabled" => RedshiftS3BackupMode::Enabled,
+            _ => RedshiftS3BackupMode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RedshiftS3BackupMode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RedshiftS3BackupMode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RedshiftS3BackupMode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RedshiftS3BackupMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RedshiftS3BackupMode {
+    fn deserialize<D>(deserializer: D) -> Result<RedshiftS3BackupMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum S3BackupMode {
+    /// `Disabled`
+    Disabled,
+    /// `Enabled`
+    Enabled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl S3BackupMode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            S3BackupMode::Disabled => "Disabled",
+            S3BackupMode::Enabled => "Enabled",
+            S3BackupMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for S3BackupMode {
+    fn default() -> S3BackupMode {
+        S3BackupMode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for S3BackupMode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for S3BackupMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for S3BackupMode {
+    fn from(value: &'a str) -> S3BackupMode {
+        match value {
+            "Disabled" => S3BackupMode::Disabled,
+            "Enabled" => S3BackupMode::Enabled,
+            _ => S3BackupMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for S3BackupMode {
+    fn from(value: String) -> S3BackupMode {
+        match value.as_str() {
+            "Disabled" => S3BackupMode::Disabled,
+            "Enabled" => S3BackupMode::Enabled,
+            _ => S3BackupMode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for S3BackupMode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<S3BackupMode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for S3BackupMode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for S3BackupMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for S3BackupMode {
+    fn deserialize<D>(deserializer: D) -> Result<S3BackupMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes the configuration of a destination in Amazon S3.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -1152,7 +3036,7 @@ pub struct S3DestinationConfiguration {
     /// <p>The compression format. If no value is specified, the default is <code>UNCOMPRESSED</code>.</p> <p>The compression formats <code>SNAPPY</code> or <code>ZIP</code> cannot be specified for Amazon Redshift destinations because they are not supported by the Amazon Redshift <code>COPY</code> operation that reads from the S3 bucket.</p>
     #[serde(rename = "CompressionFormat")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compression_format: Option<String>,
+    pub compression_format: Option<CompressionFormat>,
     /// <p>The encryption configuration. If no value is specified, the default is no encryption.</p>
     #[serde(rename = "EncryptionConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1186,7 +3070,7 @@ pub struct S3DestinationDescription {
     pub cloud_watch_logging_options: Option<CloudWatchLoggingOptions>,
     /// <p>The compression format. If no value is specified, the default is <code>UNCOMPRESSED</code>.</p>
     #[serde(rename = "CompressionFormat")]
-    pub compression_format: String,
+    pub compression_format: CompressionFormat,
     /// <p>The encryption configuration. If no value is specified, the default is no encryption.</p>
     #[serde(rename = "EncryptionConfiguration")]
     pub encryption_configuration: EncryptionConfiguration,
@@ -1222,7 +3106,7 @@ pub struct S3DestinationUpdate {
     /// <p>The compression format. If no value is specified, the default is <code>UNCOMPRESSED</code>.</p> <p>The compression formats <code>SNAPPY</code> or <code>ZIP</code> cannot be specified for Amazon Redshift destinations because they are not supported by the Amazon Redshift <code>COPY</code> operation that reads from the S3 bucket.</p>
     #[serde(rename = "CompressionFormat")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compression_format: Option<String>,
+    pub compression_format: Option<CompressionFormat>,
     /// <p>The encryption configuration. If no value is specified, the default is no encryption.</p>
     #[serde(rename = "EncryptionConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1310,7 +3194,7 @@ pub struct SplunkDestinationConfiguration {
     pub hec_endpoint: String,
     /// <p>This type can be either "Raw" or "Event."</p>
     #[serde(rename = "HECEndpointType")]
-    pub hec_endpoint_type: String,
+    pub hec_endpoint_type: HECEndpointType,
     /// <p>This is a GUID that you obtain from your Splunk cluster when you create a new HEC endpoint.</p>
     #[serde(rename = "HECToken")]
     pub hec_token: String,
@@ -1325,7 +3209,7 @@ pub struct SplunkDestinationConfiguration {
     /// <p>Defines how documents should be delivered to Amazon S3. When set to <code>FailedDocumentsOnly</code>, Kinesis Data Firehose writes any data that could not be indexed to the configured Amazon S3 destination. When set to <code>AllDocuments</code>, Kinesis Data Firehose delivers all incoming records to Amazon S3, and also writes failed documents to Amazon S3. Default value is <code>FailedDocumentsOnly</code>. </p>
     #[serde(rename = "S3BackupMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub s3_backup_mode: Option<String>,
+    pub s3_backup_mode: Option<SplunkS3BackupMode>,
     /// <p>The configuration for the backup Amazon S3 location.</p>
     #[serde(rename = "S3Configuration")]
     pub s3_configuration: S3DestinationConfiguration,
@@ -1350,7 +3234,7 @@ pub struct SplunkDestinationDescription {
     /// <p>This type can be either "Raw" or "Event."</p>
     #[serde(rename = "HECEndpointType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub hec_endpoint_type: Option<String>,
+    pub hec_endpoint_type: Option<HECEndpointType>,
     /// <p>A GUID you obtain from your Splunk cluster when you create a new HEC endpoint.</p>
     #[serde(rename = "HECToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1366,7 +3250,7 @@ pub struct SplunkDestinationDescription {
     /// <p>Defines how documents should be delivered to Amazon S3. When set to <code>FailedDocumentsOnly</code>, Kinesis Data Firehose writes any data that could not be indexed to the configured Amazon S3 destination. When set to <code>AllDocuments</code>, Kinesis Data Firehose delivers all incoming records to Amazon S3, and also writes failed documents to Amazon S3. Default value is <code>FailedDocumentsOnly</code>. </p>
     #[serde(rename = "S3BackupMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub s3_backup_mode: Option<String>,
+    pub s3_backup_mode: Option<SplunkS3BackupMode>,
     /// <p>The Amazon S3 destination.&gt;</p>
     #[serde(rename = "S3DestinationDescription")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1392,7 +3276,7 @@ pub struct SplunkDestinationUpdate {
     /// <p>This type can be either "Raw" or "Event."</p>
     #[serde(rename = "HECEndpointType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub hec_endpoint_type: Option<String>,
+    pub hec_endpoint_type: Option<HECEndpointType>,
     /// <p>A GUID that you obtain from your Splunk cluster when you create a new HEC endpoint.</p>
     #[serde(rename = "HECToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1408,7 +3292,7 @@ pub struct SplunkDestinationUpdate {
     /// <p>Defines how documents should be delivered to Amazon S3. When set to <code>FailedDocumentsOnly</code>, Kinesis Data Firehose writes any data that could not be indexed to the configured Amazon S3 destination. When set to <code>AllDocuments</code>, Kinesis Data Firehose delivers all incoming records to Amazon S3, and also writes failed documents to Amazon S3. Default value is <code>FailedDocumentsOnly</code>. </p>
     #[serde(rename = "S3BackupMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub s3_backup_mode: Option<String>,
+    pub s3_backup_mode: Option<SplunkS3BackupMode>,
     /// <p>Your update to the configuration of the backup Amazon S3 location.</p>
     #[serde(rename = "S3Update")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1424,6 +3308,99 @@ pub struct SplunkRetryOptions {
     pub duration_in_seconds: Option<i64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SplunkS3BackupMode {
+    /// `FailedEventsOnly`
+    FailedEventsOnly,
+    /// `AllEvents`
+    AllEvents,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SplunkS3BackupMode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SplunkS3BackupMode::FailedEventsOnly => "FailedEventsOnly",
+            SplunkS3BackupMode::AllEvents => "AllEvents",
+            SplunkS3BackupMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SplunkS3BackupMode {
+    fn default() -> SplunkS3BackupMode {
+        SplunkS3BackupMode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SplunkS3BackupMode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SplunkS3BackupMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SplunkS3BackupMode {
+    fn from(value: &'a str) -> SplunkS3BackupMode {
+        match value {
+            "FailedEventsOnly" => SplunkS3BackupMode::FailedEventsOnly,
+            "AllEvents" => SplunkS3BackupMode::AllEvents,
+            _ => SplunkS3BackupMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SplunkS3BackupMode {
+    fn from(value: String) -> SplunkS3BackupMode {
+        match value.as_str() {
+            "FailedEventsOnly" => SplunkS3BackupMode::FailedEventsOnly,
+            "AllEvents" => SplunkS3BackupMode::AllEvents,
+            _ => SplunkS3BackupMode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SplunkS3BackupMode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SplunkS3BackupMode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SplunkS3BackupMode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SplunkS3BackupMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SplunkS3BackupMode {
+    fn deserialize<D>(deserializer: D) -> Result<SplunkS3BackupMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct StartDeliveryStreamEncryptionInput {
@@ -2182,11 +4159,12 @@ pub struct KinesisFirehoseClient {
 impl KinesisFirehoseClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> KinesisFirehoseClient {
         KinesisFirehoseClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Firehose"),
         }
     }
 
@@ -2218,6 +4196,7 @@ impl KinesisFirehose for KinesisFirehoseClient {
         input: CreateDeliveryStreamInput,
     ) -> Result<CreateDeliveryStreamOutput, RusotoError<CreateDeliveryStreamError>> {
         let mut request = SignedRequest::new("POST", "firehose", &self.region, "/");
+        request.set_operation("CreateDeliveryStream");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Firehose_20150804.CreateDeliveryStream");
@@ -2246,6 +4225,7 @@ impl KinesisFirehose for KinesisFirehoseClient {
         input: DeleteDeliveryStreamInput,
     ) -> Result<DeleteDeliveryStreamOutput, RusotoError<DeleteDeliveryStreamError>> {
         let mut request = SignedRequest::new("POST", "firehose", &self.region, "/");
+        request.set_operation("DeleteDeliveryStream");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Firehose_20150804.DeleteDeliveryStream");
@@ -2274,6 +4254,7 @@ impl KinesisFirehose for KinesisFirehoseClient {
         input: DescribeDeliveryStreamInput,
     ) -> Result<DescribeDeliveryStreamOutput, RusotoError<DescribeDeliveryStreamError>> {
         let mut request = SignedRequest::new("POST", "firehose", &self.region, "/");
+        request.set_operation("DescribeDeliveryStream");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Firehose_20150804.DescribeDeliveryStream");
@@ -2302,6 +4283,7 @@ impl KinesisFirehose for KinesisFirehoseClient {
         input: ListDeliveryStreamsInput,
     ) -> Result<ListDeliveryStreamsOutput, RusotoError<ListDeliveryStreamsError>> {
         let mut request = SignedRequest::new("POST", "firehose", &self.region, "/");
+        request.set_operation("ListDeliveryStreams");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Firehose_20150804.ListDeliveryStreams");
@@ -2330,6 +4312,7 @@ impl KinesisFirehose for KinesisFirehoseClient {
         input: ListTagsForDeliveryStreamInput,
     ) -> Result<ListTagsForDeliveryStreamOutput, RusotoError<ListTagsForDeliveryStreamError>> {
         let mut request = SignedRequest::new("POST", "firehose", &self.region, "/");
+        request.set_operation("ListTagsForDeliveryStream");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2361,6 +4344,7 @@ impl KinesisFirehose for KinesisFirehoseClient {
         input: PutRecordInput,
     ) -> Result<PutRecordOutput, RusotoError<PutRecordError>> {
         let mut request = SignedRequest::new("POST", "firehose", &self.region, "/");
+        request.set_operation("PutRecord");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Firehose_20150804.PutRecord");
@@ -2388,6 +4372,7 @@ impl KinesisFirehose for KinesisFirehoseClient {
         input: PutRecordBatchInput,
     ) -> Result<PutRecordBatchOutput, RusotoError<PutRecordBatchError>> {
         let mut request = SignedRequest::new("POST", "firehose", &self.region, "/");
+        request.set_operation("PutRecordBatch");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Firehose_20150804.PutRecordBatch");
@@ -2416,6 +4401,7 @@ impl KinesisFirehose for KinesisFirehoseClient {
     ) -> Result<StartDeliveryStreamEncryptionOutput, RusotoError<StartDeliveryStreamEncryptionError>>
     {
         let mut request = SignedRequest::new("POST", "firehose", &self.region, "/");
+        request.set_operation("StartDeliveryStreamEncryption");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2448,6 +4434,7 @@ impl KinesisFirehose for KinesisFirehoseClient {
     ) -> Result<StopDeliveryStreamEncryptionOutput, RusotoError<StopDeliveryStreamEncryptionError>>
     {
         let mut request = SignedRequest::new("POST", "firehose", &self.region, "/");
+        request.set_operation("StopDeliveryStreamEncryption");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2479,6 +4466,7 @@ impl KinesisFirehose for KinesisFirehoseClient {
         input: TagDeliveryStreamInput,
     ) -> Result<TagDeliveryStreamOutput, RusotoError<TagDeliveryStreamError>> {
         let mut request = SignedRequest::new("POST", "firehose", &self.region, "/");
+        request.set_operation("TagDeliveryStream");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Firehose_20150804.TagDeliveryStream");
@@ -2506,6 +4494,7 @@ impl KinesisFirehose for KinesisFirehoseClient {
         input: UntagDeliveryStreamInput,
     ) -> Result<UntagDeliveryStreamOutput, RusotoError<UntagDeliveryStreamError>> {
         let mut request = SignedRequest::new("POST", "firehose", &self.region, "/");
+        request.set_operation("UntagDeliveryStream");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Firehose_20150804.UntagDeliveryStream");
@@ -2534,6 +4523,7 @@ impl KinesisFirehose for KinesisFirehoseClient {
         input: UpdateDestinationInput,
     ) -> Result<UpdateDestinationOutput, RusotoError<UpdateDestinationError>> {
         let mut request = SignedRequest::new("POST", "firehose", &self.region, "/");
+        request.set_operation("UpdateDestination");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Firehose_20150804.UpdateDestination");
@@ -24,6 +24,114 @@ use rusoto_core::signature::SignedRequest;
 #[allow(unused_imports)]
 use serde::{Deserialize, Serialize};
 use serde_json;
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AccountRoleStatus {
+    /// `READY`
+    Ready,
+    /// `CREATING`
+    Creating,
+    /// `PENDING_DELETION`
+    PendingDeletion,
+    /// `DELETING`
+    Deleting,
+    /// `DELETED`
+    Deleted,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AccountRoleStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AccountRoleStatus::Ready => "READY",
+            AccountRoleStatus::Creating => "CREATING",
+            AccountRoleStatus::PendingDeletion => "PENDING_DELETION",
+            AccountRoleStatus::Deleting => "DELETING",
+            AccountRoleStatus::Deleted => "DELETED",
+            AccountRoleStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AccountRoleStatus {
+    fn default() -> AccountRoleStatus {
+        AccountRoleStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AccountRoleStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AccountRoleStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AccountRoleStatus {
+    fn from(value: &'a str) -> AccountRoleStatus {
+        match value {
+            "READY" => AccountRoleStatus::Ready,
+            "CREATING" => AccountRoleStatus::Creating,
+            "PENDING_DELETION" => AccountRoleStatus::PendingDeletion,
+            "DELETING" => AccountRoleStatus::Deleting,
+            "DELETED" => AccountRoleStatus::Deleted,
+            _ => AccountRoleStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AccountRoleStatus {
+    fn from(value: String) -> AccountRoleStatus {
+        match value.as_str() {
+            "READY" => AccountRoleStatus::Ready,
+            "CREATING" => AccountRoleStatus::Creating,
+            "PENDING_DELETION" => AccountRoleStatus::PendingDeletion,
+            "DELETING" => AccountRoleStatus::Deleting,
+            "DELETED" => AccountRoleStatus::Deleted,
+            _ => AccountRoleStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AccountRoleStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AccountRoleStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AccountRoleStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AccountRoleStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AccountRoleStatus {
+    fn deserialize<D>(deserializer: D) -> Result<AccountRoleStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct AssociateAdminAccountRequest {
@@ -47,9 +155,102 @@ pub struct ComplianceViolator {
     /// <p>The reason that the resource is not protected by the policy.</p>
     #[serde(rename = "ViolationReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub violation_reason: Option<String>,
+    pub violation_reason: Option<ViolationReason>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CustomerPolicyScopeIdType {
+    /// `ACCOUNT`
+    Account,
+    /// `ORG_UNIT`
+    OrgUnit,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CustomerPolicyScopeIdType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CustomerPolicyScopeIdType::Account => "ACCOUNT",
+            CustomerPolicyScopeIdType::OrgUnit => "ORG_UNIT",
+            CustomerPolicyScopeIdType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CustomerPolicyScopeIdType {
+    fn default() -> CustomerPolicyScopeIdType {
+        CustomerPolicyScopeIdType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CustomerPolicyScopeIdType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CustomerPolicyScopeIdType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CustomerPolicyScopeIdType {
+    fn from(value: &'a str) -> CustomerPolicyScopeIdType {
+        match value {
+            "ACCOUNT" => CustomerPolicyScopeIdType::Account,
+            "ORG_UNIT" => CustomerPolicyScopeIdType::OrgUnit,
+            _ => CustomerPolicyScopeIdType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CustomerPolicyScopeIdType {
+    fn from(value: String) -> CustomerPolicyScopeIdType {
+        match value.as_str() {
+            "ACCOUNT" => CustomerPolicyScopeIdType::Account,
+            "ORG_UNIT" => CustomerPolicyScopeIdType::OrgUnit,
+            _ => CustomerPolicyScopeIdType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CustomerPolicyScopeIdType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CustomerPolicyScopeIdType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CustomerPolicyScopeIdType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CustomerPolicyScopeIdType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for CustomerPolicyScopeIdType {
+    fn deserialize<D>(deserializer: D) -> Result<CustomerPolicyScopeIdType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct DeleteNotificationChannelRequest {}
@@ -66,6 +267,109 @@ pub struct DeletePolicyRequest {
     pub policy_id: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DependentServiceName {
+    /// `AWSCONFIG`
+    Awsconfig,
+    /// `AWSWAF`
+    Awswaf,
+    /// `AWSSHIELD_ADVANCED`
+    AwsshieldAdvanced,
+    /// `AWSVPC`
+    Awsvpc,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DependentServiceName {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DependentServiceName::Awsconfig => "AWSCONFIG",
+            DependentServiceName::Awswaf => "AWSWAF",
+            DependentServiceName::AwsshieldAdvanced => "AWSSHIELD_ADVANCED",
+            DependentServiceName::Awsvpc => "AWSVPC",
+            DependentServiceName::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DependentServiceName {
+    fn default() -> DependentServiceName {
+        DependentServiceName::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DependentServiceName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DependentServiceName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DependentServiceName {
+    fn from(value: &'a str) -> DependentServiceName {
+        match value {
+            "AWSCONFIG" => DependentServiceName::Awsconfig,
+            "AWSWAF" => DependentServiceName::Awswaf,
+            "AWSSHIELD_ADVANCED" => DependentServiceName::AwsshieldAdvanced,
+            "AWSVPC" => DependentServiceName::Awsvpc,
+            _ => DependentServiceName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DependentServiceName {
+    fn from(value: String) -> DependentServiceName {
+        match value.as_str() {
+            "AWSCONFIG" => DependentServiceName::Awsconfig,
+            "AWSWAF" => DependentServiceName::Awswaf,
+            "AWSSHIELD_ADVANCED" => DependentServiceName::AwsshieldAdvanced,
+            "AWSVPC" => DependentServiceName::Awsvpc,
+            _ => DependentServiceName::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DependentServiceName {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DependentServiceName, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DependentServiceName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DependentServiceName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DependentServiceName {
+    fn deserialize<D>(deserializer: D) -> Result<DependentServiceName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct DisassociateAdminAccountRequest {}
@@ -77,7 +381,7 @@ pub struct EvaluationResult {
     /// <p>Describes an AWS account's compliance with the AWS Firewall Manager policy.</p>
     #[serde(rename = "ComplianceStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compliance_status: Option<String>,
+    pub compliance_status: Option<PolicyComplianceStatusType>,
     /// <p>Indicates that over 100 resources are noncompliant with the AWS Firewall Manager policy.</p>
     #[serde(rename = "EvaluationLimitExceeded")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -102,7 +406,7 @@ pub struct GetAdminAccountResponse {
     /// <p>The status of the AWS account that you set as the AWS Firewall Manager administrator.</p>
     #[serde(rename = "RoleStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub role_status: Option<String>,
+    pub role_status: Option<AccountRoleStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -168,8 +472,13 @@ pub struct GetPolicyResponse {
 pub struct GetProtectionStatusRequest {
     /// <p>The end of the time period to query for the attacks. This is a <code>timestamp</code> type. The request syntax listing indicates a <code>number</code> type because the default used by AWS Firewall Manager is Unix time in seconds. However, any valid <code>timestamp</code> format is allowed.</p>
     #[serde(rename = "EndTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Specifies the number of objects that you want AWS Firewall Manager to return for this request. If you have more objects than the number that you specify for <code>MaxResults</code>, the response includes a <code>NextToken</code> value that you can use to get another batch of objects.</p>
     #[serde(rename = "MaxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -187,8 +496,13 @@ pub struct GetProtectionStatusRequest {
     pub policy_id: String,
     /// <p>The start of the time period to query for the attacks. This is a <code>timestamp</code> type. The request syntax listing indicates a <code>number</code> type because the default used by AWS Firewall Manager is Unix time in seconds. However, any valid <code>timestamp</code> format is allowed.</p>
     #[serde(rename = "StartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -209,7 +523,7 @@ pub struct GetProtectionStatusResponse {
     /// <p>The service type that is protected by the policy. Currently, this is always <code>SHIELD_ADVANCED</code>.</p>
     #[serde(rename = "ServiceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub service_type: Option<String>,
+    pub service_type: Option<SecurityServiceType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -316,14 +630,14 @@ pub struct Policy {
     /// <p>Specifies the AWS account IDs to exclude from the policy. The <code>IncludeMap</code> values are evaluated first, with all the appropriate account IDs added to the policy. Then the accounts listed in <code>ExcludeMap</code> are removed, resulting in the final list of accounts to add to the policy.</p> <p>The key to the map is <code>ACCOUNT</code>. For example, a valid <code>ExcludeMap</code> would be <code>{“ACCOUNT” : [“accountID1”, “accountID2”]}</code>.</p>
     #[serde(rename = "ExcludeMap")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub exclude_map: Option<::std::collections::HashMap<String, Vec<String>>>,
+    pub exclude_map: Option<::std::collections::HashMap<CustomerPolicyScopeIdType, Vec<String>>>,
     /// <p>If set to <code>True</code>, resources with the tags that are specified in the <code>ResourceTag</code> array are not in scope of the policy. If set to <code>False</code>, and the <code>ResourceTag</code> array is not null, only resources with the specified tags are in scope of the policy.</p>
     #[serde(rename = "ExcludeResourceTags")]
     pub exclude_resource_tags: bool,
     /// <p>Specifies the AWS account IDs to include in the policy. If <code>IncludeMap</code> is null, all accounts in the organization in AWS Organizations are included in the policy. If <code>IncludeMap</code> is not null, only values listed in <code>IncludeMap</code> are included in the policy.</p> <p>The key to the map is <code>ACCOUNT</code>. For example, a valid <code>IncludeMap</code> would be <code>{“ACCOUNT” : [“accountID1”, “accountID2”]}</code>.</p>
     #[serde(rename = "IncludeMap")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub include_map: Option<::std::collections::HashMap<String, Vec<String>>>,
+    pub include_map: Option<::std::collections::HashMap<CustomerPolicyScopeIdType, Vec<String>>>,
     /// <p>The ID of the AWS Firewall Manager policy.</p>
     #[serde(rename = "PolicyId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -364,12 +678,17 @@ pub struct PolicyComplianceDetail {
     pub evaluation_limit_exceeded: Option<bool>,
     /// <p>A timestamp that indicates when the returned information should be considered out of date.</p>
     #[serde(rename = "ExpiredAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expired_at: Option<f64>,
+    pub expired_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Details about problems with dependent services, such as AWS WAF or AWS Config, that are causing a resource to be noncompliant. The details include the name of the dependent service and the error message received that indicates the problem with the service.</p>
     #[serde(rename = "IssueInfoMap")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub issue_info_map: Option<::std::collections::HashMap<String, String>>,
+    pub issue_info_map: Option<::std::collections::HashMap<DependentServiceName, String>>,
     /// <p>The AWS account ID.</p>
     #[serde(rename = "MemberAccount")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -399,11 +718,16 @@ pub struct PolicyComplianceStatus {
     /// <p>Details about problems with dependent services, such as AWS WAF or AWS Config, that are causing a resource to be noncompliant. The details include the name of the dependent service and the error message received that indicates the problem with the service.</p>
     #[serde(rename = "IssueInfoMap")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub issue_info_map: Option<::std::collections::HashMap<String, String>>,
+    pub issue_info_map: Option<::std::collections::HashMap<DependentServiceName, String>>,
     /// <p>Timestamp of the last update to the <code>EvaluationResult</code> objects.</p>
     #[serde(rename = "LastUpdated")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated: Option<f64>,
+    pub last_updated: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The member account ID.</p>
     #[serde(rename = "MemberAccount")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -422,6 +746,99 @@ pub struct PolicyComplianceStatus {
     pub policy_owner: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PolicyComplianceStatusType {
+    /// `COMPLIANT`
+    Compliant,
+    /// `NON_COMPLIANT`
+    NonCompliant,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PolicyComplianceStatusType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PolicyComplianceStatusType::Compliant => "COMPLIANT",
+            PolicyComplianceStatusType::NonCompliant => "NON_COMPLIANT",
+            PolicyComplianceStatusType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PolicyComplianceStatusType {
+    fn default() -> PolicyComplianceStatusType {
+        PolicyComplianceStatusType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PolicyComplianceStatusType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PolicyComplianceStatusType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PolicyComplianceStatusType {
+    fn from(value: &'a str) -> PolicyComplianceStatusType {
+        match value {
+            "COMPLIANT" => PolicyComplianceStatusType::Compliant,
+            "NON_COMPLIANT" => PolicyComplianceStatusType::NonCompliant,
+            _ => PolicyComplianceStatusType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PolicyComplianceStatusType {
+    fn from(value: String) -> PolicyComplianceStatusType {
+        match value.as_str() {
+            "COMPLIANT" => PolicyComplianceStatusType::Compliant,
+            "NON_COMPLIANT" => PolicyComplianceStatusType::NonCompliant,
+            _ => PolicyComplianceStatusType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PolicyComplianceStatusType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PolicyComplianceStatusType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PolicyComplianceStatusType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PolicyComplianceStatusType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PolicyComplianceStatusType {
+    fn deserialize<D>(deserializer: D) -> Result<PolicyComplianceStatusType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Details of the AWS Firewall Manager policy. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -449,7 +866,7 @@ pub struct PolicySummary {
     /// <p>The service that the policy is using to protect the resources. This specifies the type of policy that is created, either an AWS WAF policy, a Shield Advanced policy, or a security group policy.</p>
     #[serde(rename = "SecurityServiceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub security_service_type: Option<String>,
+    pub security_service_type: Option<SecurityServiceType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -509,9 +926,142 @@ pub struct SecurityServicePolicyData {
     pub managed_service_data: Option<String>,
     /// <p>The service that the policy is using to protect the resources. This specifies the type of policy that is created, either an AWS WAF policy, a Shield Advanced policy, or a security group policy. For security group policies, Firewall Manager supports one security group for each common policy and for each content audit policy. This is an adjustable limit that you can increase by contacting AWS Support.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: SecurityServiceType,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SecurityServiceType {
+    /// `WAF`
+    Waf,
+    /// `WAFV2`
+    Wafv2,
+    /// `SHIELD_ADVANCED`
+    ShieldAdvanced,
+    /// `SECURITY_GROUPS_COMMON`
+    SecurityGroupsCommon,
+    /// `SECURITY_GROUPS_CONTENT_AUDIT`
+    SecurityGroupsContentAudit,
+    /// `SECURITY_GROUPS_USAGE_AUDIT`
+    SecurityGroupsUsageAudit,
+    /// `NETWORK_FIREWALL`
+    NetworkFirewall,
+    /// `DNS_FIREWALL`
+    DnsFirewall,
+    /// `THIRD_PARTY_FIREWALL`
+    ThirdPartyFirewall,
+    /// `IMPORT_NETWORK_FIREWALL`
+    ImportNetworkFirewall,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SecurityServiceType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SecurityServiceType::Waf => "WAF",
+            SecurityServiceType::Wafv2 => "WAFV2",
+            SecurityServiceType::ShieldAdvanced => "SHIELD_ADVANCED",
+            SecurityServiceType::SecurityGroupsCommon => "SECURITY_GROUPS_COMMON",
+            SecurityServiceType::SecurityGroupsContentAudit => "SECURITY_GROUPS_CONTENT_AUDIT",
+            SecurityServiceType::SecurityGroupsUsageAudit => "SECURITY_GROUPS_USAGE_AUDIT",
+            SecurityServiceType::NetworkFirewall => "NETWORK_FIREWALL",
+            SecurityServiceType::DnsFirewall => "DNS_FIREWALL",
+            SecurityServiceType::ThirdPartyFirewall => "THIRD_PARTY_FIREWALL",
+            SecurityServiceType::ImportNetworkFirewall => "IMPORT_NETWORK_FIREWALL",
+            SecurityServiceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SecurityServiceType {
+    fn default() -> SecurityServiceType {
+        SecurityServiceType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SecurityServiceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SecurityServiceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SecurityServiceType {
+    fn from(value: &'a str) -> SecurityServiceType {
+        match value {
+            "WAF" => SecurityServiceType::Waf,
+            "WAFV2" => SecurityServiceType::Wafv2,
+            "SHIELD_ADVANCED" => SecurityServiceType::ShieldAdvanced,
+            "SECURITY_GROUPS_COMMON" => SecurityServiceType::SecurityGroupsCommon,
+            "SECURITY_GROUPS_CONTENT_AUDIT" => SecurityServiceType::SecurityGroupsContentAudit,
+            "SECURITY_GROUPS_USAGE_AUDIT" => SecurityServiceType::SecurityGroupsUsageAudit,
+            "NETWORK_FIREWALL" => SecurityServiceType::NetworkFirewall,
+            "DNS_FIREWALL" => SecurityServiceType::DnsFirewall,
+            "THIRD_PARTY_FIREWALL" => SecurityServiceType::ThirdPartyFirewall,
+            "IMPORT_NETWORK_FIREWALL" => SecurityServiceType::ImportNetworkFirewall,
+            _ => SecurityServiceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SecurityServiceType {
+    fn from(value: String) -> SecurityServiceType {
+        match value.as_str() {
+            "WAF" => SecurityServiceType::Waf,
+            "WAFV2" => SecurityServiceType::Wafv2,
+            "SHIELD_ADVANCED" => SecurityServiceType::ShieldAdvanced,
+            "SECURITY_GROUPS_COMMON" => SecurityServiceType::SecurityGroupsCommon,
+            "SECURITY_GROUPS_CONTENT_AUDIT" => SecurityServiceType::SecurityGroupsContentAudit,
+            "SECURITY_GROUPS_USAGE_AUDIT" => SecurityServiceType::SecurityGroupsUsageAudit,
+            "NETWORK_FIREWALL" => SecurityServiceType::NetworkFirewall,
+            "DNS_FIREWALL" => SecurityServiceType::DnsFirewall,
+            "THIRD_PARTY_FIREWALL" => SecurityServiceType::ThirdPartyFirewall,
+            "IMPORT_NETWORK_FIREWALL" => SecurityServiceType::ImportNetworkFirewall,
+            _ => SecurityServiceType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SecurityServiceType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SecurityServiceType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SecurityServiceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SecurityServiceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for SecurityServiceType {
+    fn deserialize<D>(deserializer: D) -> Result<SecurityServiceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A collection of key:value pairs associated with an AWS resource. The key:value pair can be anything you define. Typically, the tag key represents a category (such as "environment") and the tag value represents a specific value within that category (such as "test," "development," or "production"). You can add up to 50 tags to each AWS resource. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Tag {
@@ -553,6 +1103,277 @@ pub struct UntagResourceRequest {
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UntagResourceResponse {}
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ViolationReason {
+    /// `WEB_ACL_MISSING_RULE_GROUP`
+    WebAclMissingRuleGroup,
+    /// `RESOURCE_MISSING_WEB_ACL`
+    ResourceMissingWebAcl,
+    /// `RESOURCE_INCORRECT_WEB_ACL`
+    ResourceIncorrectWebAcl,
+    /// `RESOURCE_MISSING_SHIELD_PROTECTION`
+    ResourceMissingShieldProtection,
+    /// `RESOURCE_MISSING_WEB_ACL_OR_SHIELD_PROTECTION`
+    ResourceMissingWebAclOrShieldProtection,
+    /// `RESOURCE_MISSING_SECURITY_GROUP`
+    ResourceMissingSecurityGroup,
+    /// `RESOURCE_VIOLATES_AUDIT_SECURITY_GROUP`
+    ResourceViolatesAuditSecurityGroup,
+    /// `SECURITY_GROUP_UNUSED`
+    SecurityGroupUnused,
+    /// `SECURITY_GROUP_REDUNDANT`
+    SecurityGroupRedundant,
+    /// `FMS_CREATED_SECURITY_GROUP_EDITED`
+    FmsCreatedSecurityGroupEdited,
+    /// `MISSING_FIREWALL`
+    MissingFirewall,
+    /// `MISSING_FIREWALL_SUBNET_IN_AZ`
+    MissingFirewallSubnetInAz,
+    /// `MISSING_EXPECTED_ROUTE_TABLE`
+    MissingExpectedRouteTable,
+    /// `NETWORK_FIREWALL_POLICY_MODIFIED`
+    NetworkFirewallPolicyModified,
+    /// `FIREWALL_SUBNET_IS_OUT_OF_SCOPE`
+    FirewallSubnetIsOutOfScope,
+    /// `INTERNET_GATEWAY_MISSING_EXPECTED_ROUTE`
+    InternetGatewayMissingExpectedRoute,
+    /// `FIREWALL_SUBNET_MISSING_EXPECTED_ROUTE`
+    FirewallSubnetMissingExpectedRoute,
+    /// `UNEXPECTED_FIREWALL_ROUTES`
+    UnexpectedFirewallRoutes,
+    /// `UNEXPECTED_TARGET_GATEWAY_ROUTES`
+    UnexpectedTargetGatewayRoutes,
+    /// `TRAFFIC_INSPECTION_CROSSES_AZ_BOUNDARY`
+    TrafficInspectionCrossesAzBoundary,
+    /// `INVALID_ROUTE_CONFIGURATION`
+    InvalidRouteConfiguration,
+    /// `MISSING_TARGET_GATEWAY`
+    MissingTargetGateway,
+    /// `INTERNET_TRAFFIC_NOT_INSPECTED`
+    InternetTrafficNotInspected,
+    /// `BLACK_HOLE_ROUTE_DETECTED`
+    BlackHoleRouteDetected,
+    /// `BLACK_HOLE_ROUTE_DETECTED_IN_FIREWALL_SUBNET`
+    BlackHoleRouteDetectedInFirewallSubnet,
+    /// `RESOURCE_MISSING_DNS_FIREWALL`
+    ResourceMissingDnsFirewall,
+    /// `ROUTE_HAS_OUT_OF_SCOPE_ENDPOINT`
+    RouteHasOutOfScopeEndpoint,
+    /// `FIREWALL_SUBNET_MISSING_VPCE_ENDPOINT`
+    FirewallSubnetMissingVpceEndpoint,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ViolationReason {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ViolationReason::WebAclMissingRuleGroup => "WEB_ACL_MISSING_RULE_GROUP",
+            ViolationReason::ResourceMissingWebAcl => "RESOURCE_MISSING_WEB_ACL",
+            ViolationReason::ResourceIncorrectWebAcl => "RESOURCE_INCORRECT_WEB_ACL",
+            ViolationReason::ResourceMissingShieldProtection => {
+                "RESOURCE_MISSING_SHIELD_PROTECTION"
+            }
+            ViolationReason::ResourceMissingWebAclOrShieldProtection => {
+                "RESOURCE_MISSING_WEB_ACL_OR_SHIELD_PROTECTION"
+            }
+            ViolationReason::ResourceMissingSecurityGroup => "RESOURCE_MISSING_SECURITY_GROUP",
+            ViolationReason::ResourceViolatesAuditSecurityGroup => {
+                "RESOURCE_VIOLATES_AUDIT_SECURITY_GROUP"
+            }
+            ViolationReason::SecurityGroupUnused => "SECURITY_GROUP_UNUSED",
+            ViolationReason::SecurityGroupRedundant => "SECURITY_GROUP_REDUNDANT",
+            ViolationReason::FmsCreatedSecurityGroupEdited => "FMS_CREATED_SECURITY_GROUP_EDITED",
+            ViolationReason::MissingFirewall => "MISSING_FIREWALL",
+            ViolationReason::MissingFirewallSubnetInAz => "MISSING_FIREWALL_SUBNET_IN_AZ",
+            ViolationReason::MissingExpectedRouteTable => "MISSING_EXPECTED_ROUTE_TABLE",
+            ViolationReason::NetworkFirewallPolicyModified => "NETWORK_FIREWALL_POLICY_MODIFIED",
+            ViolationReason::FirewallSubnetIsOutOfScope => "FIREWALL_SUBNET_IS_OUT_OF_SCOPE",
+            ViolationReason::InternetGatewayMissingExpectedRoute => {
+                "INTERNET_GATEWAY_MISSING_EXPECTED_ROUTE"
+            }
+            ViolationReason::FirewallSubnetMissingExpectedRoute => {
+                "FIREWALL_SUBNET_MISSING_EXPECTED_ROUTE"
+            }
+            ViolationReason::UnexpectedFirewallRoutes => "UNEXPECTED_FIREWALL_ROUTES",
+            ViolationReason::UnexpectedTargetGatewayRoutes => "UNEXPECTED_TARGET_GATEWAY_ROUTES",
+            ViolationReason::TrafficInspectionCrossesAzBoundary => {
+                "TRAFFIC_INSPECTION_CROSSES_AZ_BOUNDARY"
+            }
+            ViolationReason::InvalidRouteConfiguration => "INVALID_ROUTE_CONFIGURATION",
+            ViolationReason::MissingTargetGateway => "MISSING_TARGET_GATEWAY",
+            ViolationReason::InternetTrafficNotInspected => "INTERNET_TRAFFIC_NOT_INSPECTED",
+            ViolationReason::BlackHoleRouteDetected => "BLACK_HOLE_ROUTE_DETECTED",
+            ViolationReason::BlackHoleRouteDetectedInFirewallSubnet => {
+                "BLACK_HOLE_ROUTE_DETECTED_IN_FIREWALL_SUBNET"
+            }
+            ViolationReason::ResourceMissingDnsFirewall => "RESOURCE_MISSING_DNS_FIREWALL",
+            ViolationReason::RouteHasOutOfScopeEndpoint => "ROUTE_HAS_OUT_OF_SCOPE_ENDPOINT",
+            ViolationReason::FirewallSubnetMissingVpceEndpoint => {
+                "FIREWALL_SUBNET_MISSING_VPCE_ENDPOINT"
+            }
+            ViolationReason::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ViolationReason {
+    fn default() -> ViolationReason {
+        ViolationReason::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ViolationReason {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ViolationReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ViolationReason {
+    fn from(value: &'a str) -> ViolationReason {
+        match value {
+            "WEB_ACL_MISSING_RULE_GROUP" => ViolationReason::WebAclMissingRuleGroup,
+            "RESOURCE_MISSING_WEB_ACL" => ViolationReason::ResourceMissingWebAcl,
+            "RESOURCE_INCORRECT_WEB_ACL" => ViolationReason::ResourceIncorrectWebAcl,
+            "RESOURCE_MISSING_SHIELD_PROTECTION" => {
+                ViolationReason::ResourceMissingShieldProtection
+            }
+            "RESOURCE_MISSING_WEB_ACL_OR_SHIELD_PROTECTION" => {
+                ViolationReason::ResourceMissingWebAclOrShieldProtection
+            }
+            "RESOURCE_MISSING_SECURITY_GROUP" => ViolationReason::ResourceMissingSecurityGroup,
+            "RESOURCE_VIOLATES_AUDIT_SECURITY_GROUP" => {
+                ViolationReason::ResourceViolatesAuditSecurityGroup
+            }
+            "SECURITY_GROUP_UNUSED" => ViolationReason::SecurityGroupUnused,
+            "SECURITY_GROUP_REDUNDANT" => ViolationReason::SecurityGroupRedundant,
+            "FMS_CREATED_SECURITY_GROUP_EDITED" => ViolationReason::FmsCreatedSecurityGroupEdited,
+            "MISSING_FIREWALL" => ViolationReason::MissingFirewall,
+            "MISSING_FIREWALL_SUBNET_IN_AZ" => ViolationReason::MissingFirewallSubnetInAz,
+            "MISSING_EXPECTED_ROUTE_TABLE" => ViolationReason::MissingExpectedRouteTable,
+            "NETWORK_FIREWALL_POLICY_MODIFIED" => ViolationReason::NetworkFirewallPolicyModified,
+            "FIREWALL_SUBNET_IS_OUT_OF_SCOPE" => ViolationReason::FirewallSubnetIsOutOfScope,
+            "INTERNET_GATEWAY_MISSING_EXPECTED_ROUTE" => {
+                ViolationReason::InternetGatewayMissingExpectedRoute
+            }
+            "FIREWALL_SUBNET_MISSING_EXPECTED_ROUTE" => {
+                ViolationReason::FirewallSubnetMissingExpectedRoute
+            }
+            "UNEXPECTED_FIREWALL_ROUTES" => ViolationReason::UnexpectedFirewallRoutes,
+            "UNEXPECTED_TARGET_GATEWAY_ROUTES" => ViolationReason::UnexpectedTargetGatewayRoutes,
+            "TRAFFIC_INSPECTION_CROSSES_AZ_BOUNDARY" => {
+                ViolationReason::TrafficInspectionCrossesAzBoundary
+            }
+            "INVALID_ROUTE_CONFIGURATION" => ViolationReason::InvalidRouteConfiguration,
+            "MISSING_TARGET_GATEWAY" => ViolationReason::MissingTargetGateway,
+            "INTERNET_TRAFFIC_NOT_INSPECTED" => ViolationReason::InternetTrafficNotInspected,
+            "BLACK_HOLE_ROUTE_DETECTED" => ViolationReason::BlackHoleRouteDetected,
+            "BLACK_HOLE_ROUTE_DETECTED_IN_FIREWALL_SUBNET" => {
+                ViolationReason::BlackHoleRouteDetectedInFirewallSubnet
+            }
+            "RESOURCE_MISSING_DNS_FIREWALL" => ViolationReason::ResourceMissingDnsFirewall,
+            "ROUTE_HAS_OUT_OF_SCOPE_ENDPOINT" => ViolationReason::RouteHasOutOfScopeEndpoint,
+            "FIREWALL_SUBNET_MISSING_VPCE_ENDPOINT" => {
+                ViolationReason::FirewallSubnetMissingVpceEndpoint
+            }
+            _ => ViolationReason::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ViolationReason {
+    fn from(value: String) -> ViolationReason {
+        match value.as_str() {
+            "WEB_ACL_MISSING_RULE_GROUP" => ViolationReason::WebAclMissingRuleGroup,
+            "RESOURCE_MISSING_WEB_ACL" => ViolationReason::ResourceMissingWebAcl,
+            "RESOURCE_INCORRECT_WEB_ACL" => ViolationReason::ResourceIncorrectWebAcl,
+            "RESOURCE_MISSING_SHIELD_PROTECTION" => {
+                ViolationReason::ResourceMissingShieldProtection
+            }
+            "RESOURCE_MISSING_WEB_ACL_OR_SHIELD_PROTECTION" => {
+                ViolationReason::ResourceMissingWebAclOrShieldProtection
+            }
+            "RESOURCE_MISSING_SECURITY_GROUP" => ViolationReason::ResourceMissingSecurityGroup,
+            "RESOURCE_VIOLATES_AUDIT_SECURITY_GROUP" => {
+                ViolationReason::ResourceViolatesAuditSecurityGroup
+            }
+            "SECURITY_GROUP_UNUSED" => ViolationReason::SecurityGroupUnused,
+            "SECURITY_GROUP_REDUNDANT" => ViolationReason::SecurityGroupRedundant,
+            "FMS_CREATED_SECURITY_GROUP_EDITED" => ViolationReason::FmsCreatedSecurityGroupEdited,
+            "MISSING_FIREWALL" => ViolationReason::MissingFirewall,
+            "MISSING_FIREWALL_SUBNET_IN_AZ" => ViolationReason::MissingFirewallSubnetInAz,
+            "MISSING_EXPECTED_ROUTE_TABLE" => ViolationReason::MissingExpectedRouteTable,
+            "NETWORK_FIREWALL_POLICY_MODIFIED" => ViolationReason::NetworkFirewallPolicyModified,
+            "FIREWALL_SUBNET_IS_OUT_OF_SCOPE" => ViolationReason::FirewallSubnetIsOutOfScope,
+            "INTERNET_GATEWAY_MISSING_EXPECTED_ROUTE" => {
+                ViolationReason::InternetGatewayMissingExpectedRoute
+            }
+            "FIREWALL_SUBNET_MISSING_EXPECTED_ROUTE" => {
+                ViolationReason::FirewallSubnetMissingExpectedRoute
+            }
+            "UNEXPECTED_FIREWALL_ROUTES" => ViolationReason::UnexpectedFirewallRoutes,
+            "UNEXPECTED_TARGET_GATEWAY_ROUTES" => ViolationReason::UnexpectedTargetGatewayRoutes,
+            "TRAFFIC_INSPECTION_CROSSES_AZ_BOUNDARY" => {
+                ViolationReason::TrafficInspectionCrossesAzBoundary
+            }
+            "INVALID_ROUTE_CONFIGURATION" => ViolationReason::InvalidRouteConfiguration,
+            "MISSING_TARGET_GATEWAY" => ViolationReason::MissingTargetGateway,
+            "INTERNET_TRAFFIC_NOT_INSPECTED" => ViolationReason::InternetTrafficNotInspected,
+            "BLACK_HOLE_ROUTE_DETECTED" => ViolationReason::BlackHoleRouteDetected,
+            "BLACK_HOLE_ROUTE_DETECTED_IN_FIREWALL_SUBNET" => {
+                ViolationReason::BlackHoleRouteDetectedInFirewallSubnet
+            }
+            "RESOURCE_MISSING_DNS_FIREWALL" => ViolationReason::ResourceMissingDnsFirewall,
+            "ROUTE_HAS_OUT_OF_SCOPE_ENDPOINT" => ViolationReason::RouteHasOutOfScopeEndpoint,
+            "FIREWALL_SUBNET_MISSING_VPCE_ENDPOINT" => {
+                ViolationReason::FirewallSubnetMissingVpceEndpoint
+            }
+            _ => ViolationReason::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ViolationReason {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ViolationReason, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ViolationReason {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ViolationReason {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ViolationReason {
+    fn deserialize<D>(deserializer: D) -> Result<ViolationReason, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// Errors returned by AssociateAdminAccount
 #[derive(Debug, PartialEq)]
 pub enum AssociateAdminAccountError {
@@ -1458,11 +2279,12 @@ pub struct FmsClient {
 impl FmsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> FmsClient {
         FmsClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "FMS"),
         }
     }
 
@@ -1494,6 +2316,7 @@ impl Fms for FmsClient {
         input: AssociateAdminAccountRequest,
     ) -> Result<(), RusotoError<AssociateAdminAccountError>> {
         let mut request = SignedRequest::new("POST", "fms", &self.region, "/");
+        request.set_operation("AssociateAdminAccount");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSFMS_20180101.AssociateAdminAccount");
@@ -1520,6 +2343,7 @@ impl Fms for FmsClient {
         &self,
     ) -> Result<(), RusotoError<DeleteNotificationChannelError>> {
         let mut request = SignedRequest::new("POST", "fms", &self.region, "/");
+        request.set_operation("DeleteNotificationChannel");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSFMS_20180101.DeleteNotificationChannel");
@@ -1546,6 +2370,7 @@ impl Fms for FmsClient {
         input: DeletePolicyRequest,
     ) -> Result<(), RusotoError<DeletePolicyError>> {
         let mut request = SignedRequest::new("POST", "fms", &self.region, "/");
+        request.set_operation("DeletePolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSFMS_20180101.DeletePolicy");
@@ -1572,6 +2397,7 @@ impl Fms for FmsClient {
         &self,
     ) -> Result<(), RusotoError<DisassociateAdminAccountError>> {
         let mut request = SignedRequest::new("POST", "fms", &self.region, "/");
+        request.set_operation("DisassociateAdminAccount");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSFMS_20180101.DisassociateAdminAccount");
@@ -1597,6 +2423,7 @@ impl Fms for FmsClient {
         &self,
     ) -> Result<GetAdminAccountResponse, RusotoError<GetAdminAccountError>> {
         let mut request = SignedRequest::new("POST", "fms", &self.region, "/");
+        request.set_operation("GetAdminAccount");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSFMS_20180101.GetAdminAccount");
@@ -1623,6 +2450,7 @@ impl Fms for FmsClient {
         input: GetComplianceDetailRequest,
     ) -> Result<GetComplianceDetailResponse, RusotoError<GetComplianceDetailError>> {
         let mut request = SignedRequest::new("POST", "fms", &self.region, "/");
+        request.set_operation("GetComplianceDetail");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSFMS_20180101.GetComplianceDetail");
@@ -1650,6 +2478,7 @@ impl Fms for FmsClient {
         &self,
     ) -> Result<GetNotificationChannelResponse, RusotoError<GetNotificationChannelError>> {
         let mut request = SignedRequest::new("POST", "fms", &self.region, "/");
+        request.set_operation("GetNotificationChannel");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSFMS_20180101.GetNotificationChannel");
@@ -1677,6 +2506,7 @@ impl Fms for FmsClient {
         input: GetPolicyRequest,
     ) -> Result<GetPolicyResponse, RusotoError<GetPolicyError>> {
         let mut request = SignedRequest::new("POST", "fms", &self.region, "/");
+        request.set_operation("GetPolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSFMS_20180101.GetPolicy");
@@ -1704,6 +2534,7 @@ impl Fms for FmsClient {
         input: GetProtectionStatusRequest,
     ) -> Result<GetProtectionStatusResponse, RusotoError<GetProtectionStatusError>> {
         let mut request = SignedRequest::new("POST", "fms", &self.region, "/");
+        request.set_operation("GetProtectionStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSFMS_20180101.GetProtectionStatus");
@@ -1732,6 +2563,7 @@ impl Fms for FmsClient {
         input: ListComplianceStatusRequest,
     ) -> Result<ListComplianceStatusResponse, RusotoError<ListComplianceStatusError>> {
         let mut request = SignedRequest::new("POST", "fms", &self.region, "/");
+        request.set_operation("ListComplianceStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSFMS_20180101.ListComplianceStatus");
@@ -1760,6 +2592,7 @@ impl Fms for FmsClient {
         input: ListMemberAccountsRequest,
     ) -> Result<ListMemberAccountsResponse, RusotoError<ListMemberAccountsError>> {
         let mut request = SignedRequest::new("POST", "fms", &self.region, "/");
+        request.set_operation("ListMemberAccounts");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSFMS_20180101.ListMemberAccounts");
@@ -1788,6 +2621,7 @@ impl Fms for FmsClient {
         input: ListPoliciesRequest,
     ) -> Result<ListPoliciesResponse, RusotoError<ListPoliciesError>> {
         let mut request = SignedRequest::new("POST", "fms", &self.region, "/");
+        request.set_operation("ListPolicies");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSFMS_20180101.ListPolicies");
@@ -1815,6 +2649,7 @@ impl Fms for FmsClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = SignedRequest::new("POST", "fms", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSFMS_20180101.ListTagsForResource");
@@ -1843,6 +2678,7 @@ impl Fms for FmsClient {
         input: PutNotificationChannelRequest,
     ) -> Result<(), RusotoError<PutNotificationChannelError>> {
         let mut request = SignedRequest::new("POST", "fms", &self.region, "/");
+        request.set_operation("PutNotificationChannel");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSFMS_20180101.PutNotificationChannel");
@@ -1870,6 +2706,7 @@ impl Fms for FmsClient {
         input: PutPolicyRequest,
     ) -> Result<PutPolicyResponse, RusotoError<PutPolicyError>> {
         let mut request = SignedRequest::new("POST", "fms", &self.region, "/");
+        request.set_operation("PutPolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSFMS_20180101.PutPolicy");
@@ -1897,6 +2734,7 @@ impl Fms for FmsClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = SignedRequest::new("POST", "fms", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSFMS_20180101.TagResource");
@@ -1924,6 +2762,7 @@ impl Fms for FmsClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = SignedRequest::new("POST", "fms", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSFMS_20180101.UntagResource");
@@ -1945,3 +2784,104 @@ impl Fms for FmsClient {
         }
     }
 }
+impl FmsClient {
+    /// Auto-paginating version of `list_compliance_status`, streaming every page of results.
+    pub fn list_compliance_status_pages(
+        &self,
+        input: ListComplianceStatusRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListComplianceStatusResponse,
+        ListComplianceStatusError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: FmsClient, input: ListComplianceStatusRequest| async move {
+                client.list_compliance_status(input).await
+            },
+            |input: &ListComplianceStatusRequest, output: &ListComplianceStatusResponse| {
+                Some(ListComplianceStatusRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_compliance_status`, streaming the `PolicyComplianceStatusList` of every page of results.
+    pub fn list_compliance_status_items(
+        &self,
+        input: ListComplianceStatusRequest,
+    ) -> rusoto_core::pagination::RusotoStream<PolicyComplianceStatus, ListComplianceStatusError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_compliance_status_pages(input),
+            |output: ListComplianceStatusResponse| {
+                output.policy_compliance_status_list.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_member_accounts`, streaming every page of results.
+    pub fn list_member_accounts_pages(
+        &self,
+        input: ListMemberAccountsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListMemberAccountsResponse, ListMemberAccountsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: FmsClient, input: ListMemberAccountsRequest| async move {
+                client.list_member_accounts(input).await
+            },
+            |input: &ListMemberAccountsRequest, output: &ListMemberAccountsResponse| {
+                Some(ListMemberAccountsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_member_accounts`, streaming the `MemberAccounts` of every page of results.
+    pub fn list_member_accounts_items(
+        &self,
+        input: ListMemberAccountsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListMemberAccountsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_member_accounts_pages(input),
+            |output: ListMemberAccountsResponse| output.member_accounts.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_policies`, streaming every page of results.
+    pub fn list_policies_pages(
+        &self,
+        input: ListPoliciesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListPoliciesResponse, ListPoliciesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: FmsClient, input: ListPoliciesRequest| async move {
+                client.list_policies(input).await
+            },
+            |input: &ListPoliciesRequest, output: &ListPoliciesResponse| {
+                Some(ListPoliciesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_policies`, streaming the `PolicyList` of every page of results.
+    pub fn list_policies_items(
+        &self,
+        input: ListPoliciesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<PolicySummary, ListPoliciesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_policies_pages(input),
+            |output: ListPoliciesResponse| output.policy_list.unwrap_or_default(),
+        )
+    }
+}
@@ -24,6 +24,114 @@ use rusoto_core::signature::SignedRequest;
 #[allow(unused_imports)]
 use serde::{Deserialize, Serialize};
 use serde_json;
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AttributeType {
+    /// `string`
+    String,
+    /// `integer`
+    Integer,
+    /// `float`
+    Float,
+    /// `timestamp`
+    Timestamp,
+    /// `geolocation`
+    Geolocation,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AttributeType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AttributeType::String => "string",
+            AttributeType::Integer => "integer",
+            AttributeType::Float => "float",
+            AttributeType::Timestamp => "timestamp",
+            AttributeType::Geolocation => "geolocation",
+            AttributeType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AttributeType {
+    fn default() -> AttributeType {
+        AttributeType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AttributeType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AttributeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AttributeType {
+    fn from(value: &'a str) -> AttributeType {
+        match value {
+            "string" => AttributeType::String,
+            "integer" => AttributeType::Integer,
+            "float" => AttributeType::Float,
+            "timestamp" => AttributeType::Timestamp,
+            "geolocation" => AttributeType::Geolocation,
+            _ => AttributeType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AttributeType {
+    fn from(value: String) -> AttributeType {
+        match value.as_str() {
+            "string" => AttributeType::String,
+            "integer" => AttributeType::Integer,
+            "float" => AttributeType::Float,
+            "timestamp" => AttributeType::Timestamp,
+            "geolocation" => AttributeType::Geolocation,
+            _ => AttributeType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AttributeType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AttributeType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AttributeType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AttributeType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AttributeType {
+    fn deserialize<D>(deserializer: D) -> Result<AttributeType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Specifies a categorical hyperparameter and it's range of tunable values. This object is part of the <a>ParameterRanges</a> object.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CategoricalParameterRange {
@@ -50,7 +158,7 @@ pub struct ContinuousParameterRange {
     /// <p>The scale that hyperparameter tuning uses to search the hyperparameter range. Valid values:</p> <dl> <dt>Auto</dt> <dd> <p>Amazon Forecast hyperparameter tuning chooses the best scale for the hyperparameter.</p> </dd> <dt>Linear</dt> <dd> <p>Hyperparameter tuning searches the values in the hyperparameter range by using a linear scale.</p> </dd> <dt>Logarithmic</dt> <dd> <p>Hyperparameter tuning searches the values in the hyperparameter range by using a logarithmic scale.</p> <p>Logarithmic scaling works only for ranges that have values greater than 0.</p> </dd> <dt>ReverseLogarithmic</dt> <dd> <p>hyperparameter tuning searches the values in the hyperparameter range by using a reverse logarithmic scale.</p> <p>Reverse logarithmic scaling works only for ranges that are entirely within the range 0 &lt;= x &lt; 1.0.</p> </dd> </dl> <p>For information about choosing a hyperparameter scale, see <a href="http://docs.aws.amazon.com/sagemaker/latest/dg/automatic-model-tuning-define-ranges.html#scaling-type">Hyperparameter Scaling</a>. One of the following values:</p>
     #[serde(rename = "ScalingType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scaling_type: Option<String>,
+    pub scaling_type: Option<ScalingType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -65,7 +173,7 @@ pub struct CreateDatasetGroupRequest {
     pub dataset_group_name: String,
     /// <p>The domain associated with the dataset group. When you add a dataset to a dataset group, this value and the value specified for the <code>Domain</code> parameter of the <a>CreateDataset</a> operation must match.</p> <p>The <code>Domain</code> and <code>DatasetType</code> that you choose determine the fields that must be present in training data that you import to a dataset. For example, if you choose the <code>RETAIL</code> domain and <code>TARGET_TIME_SERIES</code> as the <code>DatasetType</code>, Amazon Forecast requires that <code>item_id</code>, <code>timestamp</code>, and <code>demand</code> fields are present in your data. For more information, see <a>howitworks-datasets-groups</a>.</p>
     #[serde(rename = "Domain")]
-    pub domain: String,
+    pub domain: Domain,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -116,10 +224,10 @@ pub struct CreateDatasetRequest {
     pub dataset_name: String,
     /// <p>The dataset type. Valid values depend on the chosen <code>Domain</code>.</p>
     #[serde(rename = "DatasetType")]
-    pub dataset_type: String,
+    pub dataset_type: DatasetType,
     /// <p>The domain associated with the dataset. When you add a dataset to a dataset group, this value and the value specified for the <code>Domain</code> parameter of the <a>CreateDatasetGroup</a> operation must match.</p> <p>The <code>Domain</code> and <code>DatasetType</code> that you choose determine the fields that must be present in the training data that you import to the dataset. For example, if you choose the <code>RETAIL</code> domain and <code>TARGET_TIME_SERIES</code> as the <code>DatasetType</code>, Amazon Forecast requires <code>item_id</code>, <code>timestamp</code>, and <code>demand</code> fields to be present in your data. For more information, see <a>howitworks-datasets-groups</a>.</p>
     #[serde(rename = "Domain")]
-    pub domain: String,
+    pub domain: Domain,
     /// <p>An AWS Key Management Service (KMS) key and the AWS Identity and Access Management (IAM) role that Amazon Forecast can assume to access the key.</p>
     #[serde(rename = "EncryptionConfig")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -261,8 +369,13 @@ pub struct DataSource {
 pub struct DatasetGroupSummary {
     /// <p>When the dataset group was created.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The Amazon Resource Name (ARN) of the dataset group.</p>
     #[serde(rename = "DatasetGroupArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -273,8 +386,13 @@ pub struct DatasetGroupSummary {
     pub dataset_group_name: Option<String>,
     /// <p>When the dataset group was created or last updated from a call to the <a>UpdateDatasetGroup</a> operation. While the dataset group is being updated, <code>LastModificationTime</code> is the current time of the <code>ListDatasetGroups</code> call.</p>
     #[serde(rename = "LastModificationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modification_time: Option<f64>,
+    pub last_modification_time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>Provides a summary of the dataset import job properties used in the <a>ListDatasetImportJobs</a> operation. To get the complete set of properties, call the <a>DescribeDatasetImportJob</a> operation, and provide the <code>DatasetImportJobArn</code>.</p>
@@ -283,8 +401,13 @@ pub struct DatasetGroupSummary {
 pub struct DatasetImportJobSummary {
     /// <p>When the dataset import job was created.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The location of the training data to import and an AWS Identity and Access Management (IAM) role that Amazon Forecast can assume to access the data. The training data must be stored in an Amazon S3 bucket.</p> <p>If encryption is used, <code>DataSource</code> includes an AWS Key Management Service (KMS) key.</p>
     #[serde(rename = "DataSource")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -299,8 +422,13 @@ pub struct DatasetImportJobSummary {
     pub dataset_import_job_name: Option<String>,
     /// <p><p>The last time that the dataset was modified. The time depends on the status of the job, as follows:</p> <ul> <li> <p> <code>CREATE<em>PENDING</code> - The same time as <code>CreationTime</code>.</p> </li> <li> <p> <code>CREATE</em>IN<em>PROGRESS</code> - The current timestamp.</p> </li> <li> <p> <code>ACTIVE</code> or <code>CREATE</em>FAILED</code> - When the job finished or failed.</p> </li> </ul></p>
     #[serde(rename = "LastModificationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modification_time: Option<f64>,
+    pub last_modification_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>If an error occurred, an informational message about the error.</p>
     #[serde(rename = "Message")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -317,8 +445,13 @@ pub struct DatasetImportJobSummary {
 pub struct DatasetSummary {
     /// <p>When the dataset was created.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The Amazon Resource Name (ARN) of the dataset.</p>
     #[serde(rename = "DatasetArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -330,17 +463,120 @@ pub struct DatasetSummary {
     /// <p>The dataset type.</p>
     #[serde(rename = "DatasetType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub dataset_type: Option<String>,
+    pub dataset_type: Option<DatasetType>,
     /// <p>The domain associated with the dataset.</p>
     #[serde(rename = "Domain")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub domain: Option<String>,
+    pub domain: Option<Domain>,
     /// <p>When you create a dataset, <code>LastModificationTime</code> is the same as <code>CreationTime</code>. While data is being imported to the dataset, <code>LastModificationTime</code> is the current time of the <code>ListDatasets</code> call. After a <a>CreateDatasetImportJob</a> operation has finished, <code>LastModificationTime</code> is when the import job completed or failed.</p>
     #[serde(rename = "LastModificationTime")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modification_time: Option<f64>,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_modification_time: Option<::chrono::DateTime<::chrono::Utc>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DatasetType {
+    /// `TARGET_TIME_SERIES`
+    TargetTimeSeries,
+    /// `RELATED_TIME_SERIES`
+    RelatedTimeSeries,
+    /// `ITEM_METADATA`
+    ItemMetadata,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DatasetType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DatasetType::TargetTimeSeries => "TARGET_TIME_SERIES",
+            DatasetType::RelatedTimeSeries => "RELATED_TIME_SERIES",
+            DatasetType::ItemMetadata => "ITEM_METADATA",
+            DatasetType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DatasetType {
+    fn default() -> DatasetType {
+        DatasetType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DatasetType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DatasetType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
+impl<'a> From<&'a str> for DatasetType {
+    fn from(value: &'a str) -> DatasetType {
+        match value {
+            "TARGET_TIME_SERIES" => DatasetType::TargetTimeSeries,
+            "RELATED_TIME_SERIES" => DatasetType::RelatedTimeSeries,
+            "ITEM_METADATA" => DatasetType::ItemMetadata,
+            _ => DatasetType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DatasetType {
+    fn from(value: String) -> DatasetType {
+        match value.as_str() {
+            "TARGET_TIME_SERIES" => DatasetType::TargetTimeSeries,
+            "RELATED_TIME_SERIES" => DatasetType::RelatedTimeSeries,
+            "ITEM_METADATA" => DatasetType::ItemMetadata,
+            _ => DatasetType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DatasetType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DatasetType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DatasetType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DatasetType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DatasetType {
+    fn deserialize<D>(deserializer: D) -> Result<DatasetType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct DeleteDatasetGroupRequest {
@@ -402,8 +638,13 @@ pub struct DescribeDatasetGroupRequest {
 pub struct DescribeDatasetGroupResponse {
     /// <p>When the dataset group was created.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>An array of Amazon Resource Names (ARNs) of the datasets contained in the dataset group.</p>
     #[serde(rename = "DatasetArns")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -419,11 +660,16 @@ pub struct DescribeDatasetGroupResponse {
     /// <p>The domain associated with the dataset group.</p>
     #[serde(rename = "Domain")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub domain: Option<String>,
+    pub domain: Option<Domain>,
     /// <p>When the dataset group was created or last updated from a call to the <a>UpdateDatasetGroup</a> operation. While the dataset group is being updated, <code>LastModificationTime</code> is the current time of the <code>DescribeDatasetGroup</code> call.</p>
     #[serde(rename = "LastModificationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modification_time: Option<f64>,
+    pub last_modification_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>The status of the dataset group. States include:</p> <ul> <li> <p> <code>ACTIVE</code> </p> </li> <li> <p> <code>CREATE<em>PENDING</code>, <code>CREATE</em>IN<em>PROGRESS</code>, <code>CREATE</em>FAILED</code> </p> </li> <li> <p> <code>DELETE<em>PENDING</code>, <code>DELETE</em>IN<em>PROGRESS</code>, <code>DELETE</em>FAILED</code> </p> </li> <li> <p> <code>UPDATE<em>PENDING</code>, <code>UPDATE</em>IN<em>PROGRESS</code>, <code>UPDATE</em>FAILED</code> </p> </li> </ul> <p>The <code>UPDATE</code> states apply when you call the <a>UpdateDatasetGroup</a> operation.</p> <note> <p>The <code>Status</code> of the dataset group must be <code>ACTIVE</code> before you can use the dataset group to create a predictor.</p> </note></p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -443,8 +689,13 @@ pub struct DescribeDatasetImportJobRequest {
 pub struct DescribeDatasetImportJobResponse {
     /// <p>When the dataset import job was created.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The size of the dataset in gigabytes (GB) after the import job has finished.</p>
     #[serde(rename = "DataSize")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -471,8 +722,13 @@ pub struct DescribeDatasetImportJobResponse {
     pub field_statistics: Option<::std::collections::HashMap<String, Statistics>>,
     /// <p><p>The last time that the dataset was modified. The time depends on the status of the job, as follows:</p> <ul> <li> <p> <code>CREATE<em>PENDING</code> - The same time as <code>CreationTime</code>.</p> </li> <li> <p> <code>CREATE</em>IN<em>PROGRESS</code> - The current timestamp.</p> </li> <li> <p> <code>ACTIVE</code> or <code>CREATE</em>FAILED</code> - When the job finished or failed.</p> </li> </ul></p>
     #[serde(rename = "LastModificationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modification_time: Option<f64>,
+    pub last_modification_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>If an error occurred, an informational message about the error.</p>
     #[serde(rename = "Message")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -500,8 +756,13 @@ pub struct DescribeDatasetRequest {
 pub struct DescribeDatasetResponse {
     /// <p>When the dataset was created.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The frequency of data collection.</p> <p>Valid intervals are Y (Year), M (Month), W (Week), D (Day), H (Hour), 30min (30 minutes), 15min (15 minutes), 10min (10 minutes), 5min (5 minutes), and 1min (1 minute). For example, "M" indicates every month and "30min" indicates every 30 minutes.</p>
     #[serde(rename = "DataFrequency")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -517,19 +778,24 @@ pub struct DescribeDatasetResponse {
     /// <p>The dataset type.</p>
     #[serde(rename = "DatasetType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub dataset_type: Option<String>,
+    pub dataset_type: Option<DatasetType>,
     /// <p>The domain associated with the dataset.</p>
     #[serde(rename = "Domain")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub domain: Option<String>,
+    pub domain: Option<Domain>,
     /// <p>The AWS Key Management Service (KMS) key and the AWS Identity and Access Management (IAM) role that Amazon Forecast can assume to access the key.</p>
     #[serde(rename = "EncryptionConfig")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub encryption_config: Option<EncryptionConfig>,
     /// <p>When you create a dataset, <code>LastModificationTime</code> is the same as <code>CreationTime</code>. While data is being imported to the dataset, <code>LastModificationTime</code> is the current time of the <code>DescribeDataset</code> call. After a <a>CreateDatasetImportJob</a> operation has finished, <code>LastModificationTime</code> is when the import job completed or failed.</p>
     #[serde(rename = "LastModificationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modification_time: Option<f64>,
+    pub last_modification_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>An array of <code>SchemaAttribute</code> objects that specify the dataset fields. Each <code>SchemaAttribute</code> specifies the name and data type of a field.</p>
     #[serde(rename = "Schema")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -553,8 +819,13 @@ pub struct DescribeForecastExportJobRequest {
 pub struct DescribeForecastExportJobResponse {
     /// <p>When the forecast export job was created.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The path to the Amazon Simple Storage Service (Amazon S3) bucket where the forecast is exported.</p>
     #[serde(rename = "Destination")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -573,8 +844,13 @@ pub struct DescribeForecastExportJobResponse {
     pub forecast_export_job_name: Option<String>,
     /// <p>When the last successful export job finished.</p>
     #[serde(rename = "LastModificationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modification_time: Option<f64>,
+    pub last_modification_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>If an error occurred, an informational message about the error.</p>
     #[serde(rename = "Message")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -598,8 +874,13 @@ pub struct DescribeForecastRequest {
 pub struct DescribeForecastResponse {
     /// <p>When the forecast creation task was created.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The ARN of the dataset group that provided the data used to train the predictor.</p>
     #[serde(rename = "DatasetGroupArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -618,8 +899,13 @@ pub struct DescribeForecastResponse {
     pub forecast_types: Option<Vec<String>>,
     /// <p>Initially, the same as <code>CreationTime</code> (status is <code>CREATE_PENDING</code>). Updated when inference (creating the forecast) starts (status changed to <code>CREATE_IN_PROGRESS</code>), and when inference is complete (status changed to <code>ACTIVE</code>) or fails (status changed to <code>CREATE_FAILED</code>).</p>
     #[serde(rename = "LastModificationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modification_time: Option<f64>,
+    pub last_modification_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>If an error occurred, an informational message about the error.</p>
     #[serde(rename = "Message")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -655,8 +941,13 @@ pub struct DescribePredictorResponse {
     pub auto_ml_algorithm_arns: Option<Vec<String>>,
     /// <p>When the model training task was created.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>An array of the ARNs of the dataset import jobs used to import training data for the predictor.</p>
     #[serde(rename = "DatasetImportJobArns")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -687,8 +978,13 @@ pub struct DescribePredictorResponse {
     pub input_data_config: Option<InputDataConfig>,
     /// <p>Initially, the same as <code>CreationTime</code> (when the status is <code>CREATE_PENDING</code>). This value is updated when training starts (when the status changes to <code>CREATE_IN_PROGRESS</code>), and when training has completed (when the status changes to <code>ACTIVE</code>) or fails (when the status changes to <code>CREATE_FAILED</code>).</p>
     #[serde(rename = "LastModificationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modification_time: Option<f64>,
+    pub last_modification_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>If an error occurred, an informational message about the error.</p>
     #[serde(rename = "Message")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -723,6 +1019,124 @@ pub struct DescribePredictorResponse {
     pub training_parameters: Option<::std::collections::HashMap<String, String>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Domain {
+    /// `RETAIL`
+    Retail,
+    /// `CUSTOM`
+    Custom,
+    /// `INVENTORY_PLANNING`
+    InventoryPlanning,
+    /// `EC2_CAPACITY`
+    Ec2Capacity,
+    /// `WORK_FORCE`
+    WorkForce,
+    /// `WEB_TRAFFIC`
+    WebTraffic,
+    /// `METRICS`
+    Metrics,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Domain {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Domain::Retail => "RETAIL",
+            Domain::Custom => "CUSTOM",
+            Domain::InventoryPlanning => "INVENTORY_PLANNING",
+            Domain::Ec2Capacity => "EC2_CAPACITY",
+            Domain::WorkForce => "WORK_FORCE",
+            Domain::WebTraffic => "WEB_TRAFFIC",
+            Domain::Metrics => "METRICS",
+            Domain::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Domain {
+    fn default() -> Domain {
+        Domain::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Domain {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Domain {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Domain {
+    fn from(value: &'a str) -> Domain {
+        match value {
+            "RETAIL" => Domain::Retail,
+            "CUSTOM" => Domain::Custom,
+            "INVENTORY_PLANNING" => Domain::InventoryPlanning,
+            "EC2_CAPACITY" => Domain::Ec2Capacity,
+            "WORK_FORCE" => Domain::WorkForce,
+            "WEB_TRAFFIC" => Domain::WebTraffic,
+            "METRICS" => Domain::Metrics,
+            _ => Domain::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Domain {
+    fn from(value: String) -> Domain {
+        match value.as_str() {
+            "RETAIL" => Domain::Retail,
+            "CUSTOM" => Domain::Custom,
+            "INVENTORY_PLANNING" => Domain::InventoryPlanning,
+            "EC2_CAPACITY" => Domain::Ec2Capacity,
+            "WORK_FORCE" => Domain::WorkForce,
+            "WEB_TRAFFIC" => Domain::WebTraffic,
+            "METRICS" => Domain::Metrics,
+            _ => Domain::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Domain {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Domain, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Domain {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Domain {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Domain {
+    fn deserialize<D>(deserializer: D) -> Result<Domain, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An AWS Key Management Service (KMS) key and an AWS Identity and Access Management (IAM) role that Amazon Forecast can assume to access the key. You can specify this optional object in the <a>CreateDataset</a> and <a>CreatePredictor</a> requests.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct EncryptionConfig {
@@ -761,6 +1175,99 @@ pub struct EvaluationResult {
     pub test_windows: Option<Vec<WindowSummary>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EvaluationType {
+    /// `SUMMARY`
+    Summary,
+    /// `COMPUTED`
+    Computed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EvaluationType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EvaluationType::Summary => "SUMMARY",
+            EvaluationType::Computed => "COMPUTED",
+            EvaluationType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EvaluationType {
+    fn default() -> EvaluationType {
+        EvaluationType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EvaluationType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EvaluationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EvaluationType {
+    fn from(value: &'a str) -> EvaluationType {
+        match value {
+            "SUMMARY" => EvaluationType::Summary,
+            "COMPUTED" => EvaluationType::Computed,
+            _ => EvaluationType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EvaluationType {
+    fn from(value: String) -> EvaluationType {
+        match value.as_str() {
+            "SUMMARY" => EvaluationType::Summary,
+            "COMPUTED" => EvaluationType::Computed,
+            _ => EvaluationType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EvaluationType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EvaluationType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EvaluationType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EvaluationType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EvaluationType {
+    fn deserialize<D>(deserializer: D) -> Result<EvaluationType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Provides featurization (transformation) information for a dataset field. This object is part of the <a>FeaturizationConfig</a> object.</p> <p>For example:</p> <p> <code>{</code> </p> <p> <code>"AttributeName": "demand",</code> </p> <p> <code>FeaturizationPipeline [ {</code> </p> <p> <code>"FeaturizationMethodName": "filling",</code> </p> <p> <code>"FeaturizationMethodParameters": {"aggregation": "avg", "backfill": "nan"}</code> </p> <p> <code>} ]</code> </p> <p> <code>}</code> </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Featurization {
@@ -794,20 +1301,108 @@ pub struct FeaturizationConfig {
 pub struct FeaturizationMethod {
     /// <p>The name of the method. The "filling" method is the only supported method.</p>
     #[serde(rename = "FeaturizationMethodName")]
-    pub featurization_method_name: String,
+    pub featurization_method_name: FeaturizationMethodName,
     /// <p><p>The method parameters (key-value pairs). Specify these parameters to override the default values. The following list shows the parameters and their valid values. Bold signifies the default value.</p> <ul> <li> <p> <code>aggregation</code>: <b>sum</b>, <code>avg</code>, <code>first</code>, <code>min</code>, <code>max</code> </p> </li> <li> <p> <code>frontfill</code>: <b>none</b> </p> </li> <li> <p> <code>middlefill</code>: <b>zero</b>, <code>nan</code> (not a number)</p> </li> <li> <p> <code>backfill</code>: <b>zero</b>, <code>nan</code> </p> </li> </ul></p>
     #[serde(rename = "FeaturizationMethodParameters")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub featurization_method_parameters: Option<::std::collections::HashMap<String, String>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FeaturizationMethodName {
+    /// `filling`
+    Filling,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl FeaturizationMethodName {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FeaturizationMethodName::Filling => "filling",
+            FeaturizationMethodName::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for FeaturizationMethodName {
+    fn default() -> FeaturizationMethodName {
+        FeaturizationMethodName::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for FeaturizationMethodName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FeaturizationMethodName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FeaturizationMethodName {
+    fn from(value: &'a str) -> FeaturizationMethodName {
+        match value {
+            "filling" => FeaturizationMethodName::Filling,
+            _ => FeaturizationMethodName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FeaturizationMethodName {
+    fn from(value: String) -> FeaturizationMethodName {
+        match value.as_str() {
+            "filling" => FeaturizationMethodName::Filling,
+            _ => FeaturizationMethodName::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for FeaturizationMethodName {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<FeaturizationMethodName, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FeaturizationMethodName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FeaturizationMethodName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FeaturizationMethodName {
+    fn deserialize<D>(deserializer: D) -> Result<FeaturizationMethodName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes a filter for choosing a subset of objects. Each filter consists of a condition and a match statement. The condition is either <code>IS</code> or <code>IS_NOT</code>, which specifies whether to include or exclude the objects that match the statement, respectively. The match statement consists of a key and a value.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct Filter {
     /// <p>The condition to apply. To include the objects that match the statement, specify <code>IS</code>. To exclude matching objects, specify <code>IS_NOT</code>.</p>
     #[serde(rename = "Condition")]
-    pub condition: String,
+    pub condition: FilterConditionString,
     /// <p>The name of the parameter to filter on.</p>
     #[serde(rename = "Key")]
     pub key: String,
@@ -816,14 +1411,112 @@ pub struct Filter {
     pub value: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FilterConditionString {
+    /// `IS`
+    Is,
+    /// `IS_NOT`
+    IsNot,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl FilterConditionString {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FilterConditionString::Is => "IS",
+            FilterConditionString::IsNot => "IS_NOT",
+            FilterConditionString::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for FilterConditionString {
+    fn default() -> FilterConditionString {
+        FilterConditionString::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for FilterConditionString {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FilterConditionString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FilterConditionString {
+    fn from(value: &'a str) -> FilterConditionString {
+        match value {
+            "IS" => FilterConditionString::Is,
+            "IS_NOT" => FilterConditionString::IsNot,
+            _ => FilterConditionString::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FilterConditionString {
+    fn from(value: String) -> FilterConditionString {
+        match value.as_str() {
+            "IS" => FilterConditionString::Is,
+            "IS_NOT" => FilterConditionString::IsNot,
+            _ => FilterConditionString::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for FilterConditionString {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<FilterConditionString, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FilterConditionString {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FilterConditionString {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FilterConditionString {
+    fn deserialize<D>(deserializer: D) -> Result<FilterConditionString, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Provides a summary of the forecast export job properties used in the <a>ListForecastExportJobs</a> operation. To get the complete set of properties, call the <a>DescribeForecastExportJob</a> operation, and provide the listed <code>ForecastExportJobArn</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ForecastExportJobSummary {
     /// <p>When the forecast export job was created.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The path to the Amazon Simple Storage Service (Amazon S3) bucket where the forecast is exported.</p>
     #[serde(rename = "Destination")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -838,8 +1531,13 @@ pub struct ForecastExportJobSummary {
     pub forecast_export_job_name: Option<String>,
     /// <p>When the last successful export job finished.</p>
     #[serde(rename = "LastModificationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modification_time: Option<f64>,
+    pub last_modification_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>If an error occurred, an informational message about the error.</p>
     #[serde(rename = "Message")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -856,8 +1554,13 @@ pub struct ForecastExportJobSummary {
 pub struct ForecastSummary {
     /// <p>When the forecast creation task was created.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The Amazon Resource Name (ARN) of the dataset group that provided the data used to train the predictor.</p>
     #[serde(rename = "DatasetGroupArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -872,8 +1575,13 @@ pub struct ForecastSummary {
     pub forecast_name: Option<String>,
     /// <p>Initially, the same as <code>CreationTime</code> (status is <code>CREATE_PENDING</code>). Updated when inference (creating the forecast) starts (status changed to <code>CREATE_IN_PROGRESS</code>), and when inference is complete (status changed to <code>ACTIVE</code>) or fails (status changed to <code>CREATE_FAILED</code>).</p>
     #[serde(rename = "LastModificationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modification_time: Option<f64>,
+    pub last_modification_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>If an error occurred, an informational message about the error.</p>
     #[serde(rename = "Message")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -941,7 +1649,7 @@ pub struct IntegerParameterRange {
     /// <p>The scale that hyperparameter tuning uses to search the hyperparameter range. Valid values:</p> <dl> <dt>Auto</dt> <dd> <p>Amazon Forecast hyperparameter tuning chooses the best scale for the hyperparameter.</p> </dd> <dt>Linear</dt> <dd> <p>Hyperparameter tuning searches the values in the hyperparameter range by using a linear scale.</p> </dd> <dt>Logarithmic</dt> <dd> <p>Hyperparameter tuning searches the values in the hyperparameter range by using a logarithmic scale.</p> <p>Logarithmic scaling works only for ranges that have values greater than 0.</p> </dd> <dt>ReverseLogarithmic</dt> <dd> <p>Not supported for <code>IntegerParameterRange</code>.</p> <p>Reverse logarithmic scaling works only for ranges that are entirely within the range 0 &lt;= x &lt; 1.0.</p> </dd> </dl> <p>For information about choosing a hyperparameter scale, see <a href="http://docs.aws.amazon.com/sagemaker/latest/dg/automatic-model-tuning-define-ranges.html#scaling-type">Hyperparameter Scaling</a>. One of the following values:</p>
     #[serde(rename = "ScalingType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scaling_type: Option<String>,
+    pub scaling_type: Option<ScalingType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -1177,16 +1885,26 @@ pub struct PredictorExecutionDetails {
 pub struct PredictorSummary {
     /// <p>When the model training task was created.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The Amazon Resource Name (ARN) of the dataset group that contains the data used to train the predictor.</p>
     #[serde(rename = "DatasetGroupArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub dataset_group_arn: Option<String>,
     /// <p>Initially, the same as <code>CreationTime</code> (status is <code>CREATE_PENDING</code>). Updated when training starts (status changed to <code>CREATE_IN_PROGRESS</code>), and when training is complete (status changed to <code>ACTIVE</code>) or fails (status changed to <code>CREATE_FAILED</code>).</p>
     #[serde(rename = "LastModificationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modification_time: Option<f64>,
+    pub last_modification_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>If an error occurred, an informational message about the error.</p>
     #[serde(rename = "Message")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1220,6 +1938,109 @@ pub struct S3Config {
     pub role_arn: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ScalingType {
+    /// `Auto`
+    Auto,
+    /// `Linear`
+    Linear,
+    /// `Logarithmic`
+    Logarithmic,
+    /// `ReverseLogarithmic`
+    ReverseLogarithmic,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ScalingType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ScalingType::Auto => "Auto",
+            ScalingType::Linear => "Linear",
+            ScalingType::Logarithmic => "Logarithmic",
+            ScalingType::ReverseLogarithmic => "ReverseLogarithmic",
+            ScalingType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ScalingType {
+    fn default() -> ScalingType {
+        ScalingType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ScalingType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ScalingType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ScalingType {
+    fn from(value: &'a str) -> ScalingType {
+        match value {
+            "Auto" => ScalingType::Auto,
+            "Linear" => ScalingType::Linear,
+            "Logarithmic" => ScalingType::Logarithmic,
+            "ReverseLogarithmic" => ScalingType::ReverseLogarithmic,
+            _ => ScalingType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ScalingType {
+    fn from(value: String) -> ScalingType {
+        match value.as_str() {
+            "Auto" => ScalingType::Auto,
+            "Linear" => ScalingType::Linear,
+            "Logarithmic" => ScalingType::Logarithmic,
+            "ReverseLogarithmic" => ScalingType::ReverseLogarithmic,
+            _ => ScalingType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ScalingType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ScalingType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ScalingType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ScalingType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ScalingType {
+    fn deserialize<D>(deserializer: D) -> Result<ScalingType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Defines the fields of a dataset. You specify this object in the <a>CreateDataset</a> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Schema {
@@ -1239,7 +2060,7 @@ pub struct SchemaAttribute {
     /// <p>The data type of the field.</p>
     #[serde(rename = "AttributeType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub attribute_type: Option<String>,
+    pub attribute_type: Option<AttributeType>,
 }
 
 /// <p>Provides statistics for each data field imported into to an Amazon Forecast dataset with the <a>CreateDatasetImportJob</a> operation.</p>
@@ -1305,12 +2126,22 @@ pub struct TestWindowSummary {
     pub status: Option<String>,
     /// <p>The time at which the test ended.</p>
     #[serde(rename = "TestWindowEnd")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub test_window_end: Option<f64>,
+    pub test_window_end: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The time at which the test began.</p>
     #[serde(rename = "TestWindowStart")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub test_window_start: Option<f64>,
+    pub test_window_start: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -1349,7 +2180,7 @@ pub struct WindowSummary {
     /// <p><p>The type of evaluation.</p> <ul> <li> <p> <code>SUMMARY</code> - The average metrics across all windows.</p> </li> <li> <p> <code>COMPUTED</code> - The metrics for the specified window.</p> </li> </ul></p>
     #[serde(rename = "EvaluationType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub evaluation_type: Option<String>,
+    pub evaluation_type: Option<EvaluationType>,
     /// <p>The number of data points within the window.</p>
     #[serde(rename = "ItemCount")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1360,12 +2191,22 @@ pub struct WindowSummary {
     pub metrics: Option<Metrics>,
     /// <p>The timestamp that defines the end of the window.</p>
     #[serde(rename = "TestWindowEnd")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub test_window_end: Option<f64>,
+    pub test_window_end: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The timestamp that defines the start of the window.</p>
     #[serde(rename = "TestWindowStart")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub test_window_start: Option<f64>,
+    pub test_window_start: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// Errors returned by CreateDataset
@@ -2655,11 +3496,12 @@ pub struct ForecastClient {
 impl ForecastClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> ForecastClient {
         ForecastClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "forecast"),
         }
     }
 
@@ -2691,6 +3533,7 @@ impl Forecast for ForecastClient {
         input: CreateDatasetRequest,
     ) -> Result<CreateDatasetResponse, RusotoError<CreateDatasetError>> {
         let mut request = SignedRequest::new("POST", "forecast", &self.region, "/");
+        request.set_operation("CreateDataset");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonForecast.CreateDataset");
@@ -2718,6 +3561,7 @@ impl Forecast for ForecastClient {
         input: CreateDatasetGroupRequest,
     ) -> Result<CreateDatasetGroupResponse, RusotoError<CreateDatasetGroupError>> {
         let mut request = SignedRequest::new("POST", "forecast", &self.region, "/");
+        request.set_operation("CreateDatasetGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonForecast.CreateDatasetGroup");
@@ -2746,6 +3590,7 @@ impl Forecast for ForecastClient {
         input: CreateDatasetImportJobRequest,
     ) -> Result<CreateDatasetImportJobResponse, RusotoError<CreateDatasetImportJobError>> {
         let mut request = SignedRequest::new("POST", "forecast", &self.region, "/");
+        request.set_operation("CreateDatasetImportJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonForecast.CreateDatasetImportJob");
@@ -2774,6 +3619,7 @@ impl Forecast for ForecastClient {
         input: CreateForecastRequest,
     ) -> Result<CreateForecastResponse, RusotoError<CreateForecastError>> {
         let mut request = SignedRequest::new("POST", "forecast", &self.region, "/");
+        request.set_operation("CreateForecast");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonForecast.CreateForecast");
@@ -2801,6 +3647,7 @@ impl Forecast for ForecastClient {
         input: CreateForecastExportJobRequest,
     ) -> Result<CreateForecastExportJobResponse, RusotoError<CreateForecastExportJobError>> {
         let mut request = SignedRequest::new("POST", "forecast", &self.region, "/");
+        request.set_operation("CreateForecastExportJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonForecast.CreateForecastExportJob");
@@ -2829,6 +3676,7 @@ impl Forecast for ForecastClient {
         input: CreatePredictorRequest,
     ) -> Result<CreatePredictorResponse, RusotoError<CreatePredictorError>> {
         let mut request = SignedRequest::new("POST", "forecast", &self.region, "/");
+        request.set_operation("CreatePredictor");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonForecast.CreatePredictor");
@@ -2856,6 +3704,7 @@ impl Forecast for ForecastClient {
         input: DeleteDatasetRequest,
     ) -> Result<(), RusotoError<DeleteDatasetError>> {
         let mut request = SignedRequest::new("POST", "forecast", &self.region, "/");
+        request.set_operation("DeleteDataset");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonForecast.DeleteDataset");
@@ -2883,6 +3732,7 @@ impl Forecast for ForecastClient {
         input: DeleteDatasetGroupRequest,
     ) -> Result<(), RusotoError<DeleteDatasetGroupError>> {
         let mut request = SignedRequest::new("POST", "forecast", &self.region, "/");
+        request.set_operation("DeleteDatasetGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonForecast.DeleteDatasetGroup");
@@ -2910,6 +3760,7 @@ impl Forecast for ForecastClient {
         input: DeleteDatasetImportJobRequest,
     ) -> Result<(), RusotoError<DeleteDatasetImportJobError>> {
         let mut request = SignedRequest::new("POST", "forecast", &self.region, "/");
+        request.set_operation("DeleteDatasetImportJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonForecast.DeleteDatasetImportJob");
@@ -2937,6 +3788,7 @@ impl Forecast for ForecastClient {
         input: DeleteForecastRequest,
     ) -> Result<(), RusotoError<DeleteForecastError>> {
         let mut request = SignedRequest::new("POST", "forecast", &self.region, "/");
+        request.set_operation("DeleteForecast");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonForecast.DeleteForecast");
@@ -2964,6 +3816,7 @@ impl Forecast for ForecastClient {
         input: DeleteForecastExportJobRequest,
     ) -> Result<(), RusotoError<DeleteForecastExportJobError>> {
         let mut request = SignedRequest::new("POST", "forecast", &self.region, "/");
+        request.set_operation("DeleteForecastExportJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonForecast.DeleteForecastExportJob");
@@ -2991,6 +3844,7 @@ impl Forecast for ForecastClient {
         input: DeletePredictorRequest,
     ) -> Result<(), RusotoError<DeletePredictorError>> {
         let mut request = SignedRequest::new("POST", "forecast", &self.region, "/");
+        request.set_operation("DeletePredictor");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonForecast.DeletePredictor");
@@ -3018,6 +3872,7 @@ impl Forecast for ForecastClient {
         input: DescribeDatasetRequest,
     ) -> Result<DescribeDatasetResponse, RusotoError<DescribeDatasetError>> {
         let mut request = SignedRequest::new("POST", "forecast", &self.region, "/");
+        request.set_operation("DescribeDataset");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonForecast.DescribeDataset");
@@ -3045,6 +3900,7 @@ impl Forecast for ForecastClient {
         input: DescribeDatasetGroupRequest,
     ) -> Result<DescribeDatasetGroupResponse, RusotoError<DescribeDatasetGroupError>> {
         let mut request = SignedRequest::new("POST", "forecast", &self.region, "/");
+        request.set_operation("DescribeDatasetGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonForecast.DescribeDatasetGroup");
@@ -3073,6 +3929,7 @@ impl Forecast for ForecastClient {
         input: DescribeDatasetImportJobRequest,
     ) -> Result<DescribeDatasetImportJobResponse, RusotoError<DescribeDatasetImportJobError>> {
         let mut request = SignedRequest::new("POST", "forecast", &self.region, "/");
+        request.set_operation("DescribeDatasetImportJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonForecast.DescribeDatasetImportJob");
@@ -3101,6 +3958,7 @@ impl Forecast for ForecastClient {
         input: DescribeForecastRequest,
     ) -> Result<DescribeForecastResponse, RusotoError<DescribeForecastError>> {
         let mut request = SignedRequest::new("POST", "forecast", &self.region, "/");
+        request.set_operation("DescribeForecast");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonForecast.DescribeForecast");
@@ -3130,6 +3988,7 @@ impl Forecast for ForecastClient {
     ) -> Result<DescribeForecastExportJobResponse, RusotoError<DescribeForecastExportJobError>>
     {
         let mut request = SignedRequest::new("POST", "forecast", &self.region, "/");
+        request.set_operation("DescribeForecastExportJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonForecast.DescribeForecastExportJob");
@@ -3158,6 +4017,7 @@ impl Forecast for ForecastClient {
         input: DescribePredictorRequest,
     ) -> Result<DescribePredictorResponse, RusotoError<DescribePredictorError>> {
         let mut request = SignedRequest::new("POST", "forecast", &self.region, "/");
+        request.set_operation("DescribePredictor");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonForecast.DescribePredictor");
@@ -3186,6 +4046,7 @@ impl Forecast for ForecastClient {
         input: GetAccuracyMetricsRequest,
     ) -> Result<GetAccuracyMetricsResponse, RusotoError<GetAccuracyMetricsError>> {
         let mut request = SignedRequest::new("POST", "forecast", &self.region, "/");
+        request.set_operation("GetAccuracyMetrics");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonForecast.GetAccuracyMetrics");
@@ -3214,6 +4075,7 @@ impl Forecast for ForecastClient {
         input: ListDatasetGroupsRequest,
     ) -> Result<ListDatasetGroupsResponse, RusotoError<ListDatasetGroupsError>> {
         let mut request = SignedRequest::new("POST", "forecast", &self.region, "/");
+        request.set_operation("ListDatasetGroups");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonForecast.ListDatasetGroups");
@@ -3242,6 +4104,7 @@ impl Forecast for ForecastClient {
         input: ListDatasetImportJobsRequest,
     ) -> Result<ListDatasetImportJobsResponse, RusotoError<ListDatasetImportJobsError>> {
         let mut request = SignedRequest::new("POST", "forecast", &self.region, "/");
+        request.set_operation("ListDatasetImportJobs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonForecast.ListDatasetImportJobs");
@@ -3270,6 +4133,7 @@ impl Forecast for ForecastClient {
         input: ListDatasetsRequest,
     ) -> Result<ListDatasetsResponse, RusotoError<ListDatasetsError>> {
         let mut request = SignedRequest::new("POST", "forecast", &self.region, "/");
+        request.set_operation("ListDatasets");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonForecast.ListDatasets");
@@ -3297,6 +4161,7 @@ impl Forecast for ForecastClient {
         input: ListForecastExportJobsRequest,
     ) -> Result<ListForecastExportJobsResponse, RusotoError<ListForecastExportJobsError>> {
         let mut request = SignedRequest::new("POST", "forecast", &self.region, "/");
+        request.set_operation("ListForecastExportJobs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonForecast.ListForecastExportJobs");
@@ -3325,6 +4190,7 @@ impl Forecast for ForecastClient {
         input: ListForecastsRequest,
     ) -> Result<ListForecastsResponse, RusotoError<ListForecastsError>> {
         let mut request = SignedRequest::new("POST", "forecast", &self.region, "/");
+        request.set_operation("ListForecasts");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonForecast.ListForecasts");
@@ -3352,6 +4218,7 @@ impl Forecast for ForecastClient {
         input: ListPredictorsRequest,
     ) -> Result<ListPredictorsResponse, RusotoError<ListPredictorsError>> {
         let mut request = SignedRequest::new("POST", "forecast", &self.region, "/");
+        request.set_operation("ListPredictors");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonForecast.ListPredictors");
@@ -3379,6 +4246,7 @@ impl Forecast for ForecastClient {
         input: UpdateDatasetGroupRequest,
     ) -> Result<UpdateDatasetGroupResponse, RusotoError<UpdateDatasetGroupError>> {
         let mut request = SignedRequest::new("POST", "forecast", &self.region, "/");
+        request.set_operation("UpdateDatasetGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonForecast.UpdateDatasetGroup");
@@ -3401,3 +4269,201 @@ impl Forecast for ForecastClient {
         }
     }
 }
+impl ForecastClient {
+    /// Auto-paginating version of `list_dataset_groups`, streaming every page of results.
+    pub fn list_dataset_groups_pages(
+        &self,
+        input: ListDatasetGroupsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListDatasetGroupsResponse, ListDatasetGroupsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ForecastClient, input: ListDatasetGroupsRequest| async move {
+                client.list_dataset_groups(input).await
+            },
+            |input: &ListDatasetGroupsRequest, output: &ListDatasetGroupsResponse| {
+                Some(ListDatasetGroupsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_dataset_groups`, streaming the `DatasetGroups` of every page of results.
+    pub fn list_dataset_groups_items(
+        &self,
+        input: ListDatasetGroupsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DatasetGroupSummary, ListDatasetGroupsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_dataset_groups_pages(input),
+            |output: ListDatasetGroupsResponse| output.dataset_groups.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_dataset_import_jobs`, streaming every page of results.
+    pub fn list_dataset_import_jobs_pages(
+        &self,
+        input: ListDatasetImportJobsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListDatasetImportJobsResponse,
+        ListDatasetImportJobsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ForecastClient, input: ListDatasetImportJobsRequest| async move {
+                client.list_dataset_import_jobs(input).await
+            },
+            |input: &ListDatasetImportJobsRequest, output: &ListDatasetImportJobsResponse| {
+                Some(ListDatasetImportJobsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_dataset_import_jobs`, streaming the `DatasetImportJobs` of every page of results.
+    pub fn list_dataset_import_jobs_items(
+        &self,
+        input: ListDatasetImportJobsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DatasetImportJobSummary, ListDatasetImportJobsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_dataset_import_jobs_pages(input),
+            |output: ListDatasetImportJobsResponse| output.dataset_import_jobs.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_datasets`, streaming every page of results.
+    pub fn list_datasets_pages(
+        &self,
+        input: ListDatasetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListDatasetsResponse, ListDatasetsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ForecastClient, input: ListDatasetsRequest| async move {
+                client.list_datasets(input).await
+            },
+            |input: &ListDatasetsRequest, output: &ListDatasetsResponse| {
+                Some(ListDatasetsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_datasets`, streaming the `Datasets` of every page of results.
+    pub fn list_datasets_items(
+        &self,
+        input: ListDatasetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DatasetSummary, ListDatasetsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_datasets_pages(input),
+            |output: ListDatasetsResponse| output.datasets.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_forecast_export_jobs`, streaming every page of results.
+    pub fn list_forecast_export_jobs_pages(
+        &self,
+        input: ListForecastExportJobsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListForecastExportJobsResponse,
+        ListForecastExportJobsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ForecastClient, input: ListForecastExportJobsRequest| async move {
+                client.list_forecast_export_jobs(input).await
+            },
+            |input: &ListForecastExportJobsRequest, output: &ListForecastExportJobsResponse| {
+                Some(ListForecastExportJobsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_forecast_export_jobs`, streaming the `ForecastExportJobs` of every page of results.
+    pub fn list_forecast_export_jobs_items(
+        &self,
+        input: ListForecastExportJobsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ForecastExportJobSummary, ListForecastExportJobsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_forecast_export_jobs_pages(input),
+            |output: ListForecastExportJobsResponse| {
+                output.forecast_export_jobs.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_forecasts`, streaming every page of results.
+    pub fn list_forecasts_pages(
+        &self,
+        input: ListForecastsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListForecastsResponse, ListForecastsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ForecastClient, input: ListForecastsRequest| async move {
+                client.list_forecasts(input).await
+            },
+            |input: &ListForecastsRequest, output: &ListForecastsResponse| {
+                Some(ListForecastsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_forecasts`, streaming the `Forecasts` of every page of results.
+    pub fn list_forecasts_items(
+        &self,
+        input: ListForecastsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ForecastSummary, ListForecastsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_forecasts_pages(input),
+            |output: ListForecastsResponse| output.forecasts.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_predictors`, streaming every page of results.
+    pub fn list_predictors_pages(
+        &self,
+        input: ListPredictorsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListPredictorsResponse, ListPredictorsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ForecastClient, input: ListPredictorsRequest| async move {
+                client.list_predictors(input).await
+            },
+            |input: &ListPredictorsRequest, output: &ListPredictorsResponse| {
+                Some(ListPredictorsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_predictors`, streaming the `Predictors` of every page of results.
+    pub fn list_predictors_items(
+        &self,
+        input: ListPredictorsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<PredictorSummary, ListPredictorsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_predictors_pages(input),
+            |output: ListPredictorsResponse| output.predictors.unwrap_or_default(),
+        )
+    }
+}
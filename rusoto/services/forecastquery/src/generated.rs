@@ -153,11 +153,12 @@ pub struct ForecastQueryClient {
 impl ForecastQueryClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> ForecastQueryClient {
         ForecastQueryClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "forecastquery"),
         }
     }
 
@@ -189,6 +190,7 @@ impl ForecastQuery for ForecastQueryClient {
         input: QueryForecastRequest,
     ) -> Result<QueryForecastResponse, RusotoError<QueryForecastError>> {
         let mut request = SignedRequest::new("POST", "forecast", &self.region, "/");
+        request.set_operation("QueryForecast");
         request.set_endpoint_prefix("forecastquery".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonForecastRuntime.QueryForecast");
@@ -135,7 +135,7 @@ pub struct CreateDetectorVersionResult {
     /// <p>The status of the detector version.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<DetectorVersionStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -150,7 +150,7 @@ pub struct CreateModelVersionRequest {
     pub model_id: String,
     /// <p>The model type.</p>
     #[serde(rename = "modelType")]
-    pub model_type: String,
+    pub model_type: ModelTypeEnum,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -163,7 +163,7 @@ pub struct CreateModelVersionResult {
     /// <p>The model type.</p>
     #[serde(rename = "modelType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub model_type: Option<String>,
+    pub model_type: Option<ModelTypeEnum>,
     /// <p>The version of the model. </p>
     #[serde(rename = "modelVersionNumber")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -189,7 +189,7 @@ pub struct CreateRuleRequest {
     pub expression: String,
     /// <p>The language of the rule.</p>
     #[serde(rename = "language")]
-    pub language: String,
+    pub language: Language,
     /// <p>The outcome or outcomes returned when the rule expression matches.</p>
     #[serde(rename = "outcomes")]
     pub outcomes: Vec<String>,
@@ -212,10 +212,10 @@ pub struct CreateRuleResult {
 pub struct CreateVariableRequest {
     /// <p>The source of the data.</p>
     #[serde(rename = "dataSource")]
-    pub data_source: String,
+    pub data_source: DataSource,
     /// <p>The data type.</p>
     #[serde(rename = "dataType")]
-    pub data_type: String,
+    pub data_type: DataType,
     /// <p>The default value for the variable when no value is received.</p>
     #[serde(rename = "defaultValue")]
     pub default_value: String,
@@ -236,6 +236,212 @@ pub struct CreateVariableRequest {
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateVariableResult {}
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DataSource {
+    /// `EVENT`
+    Event,
+    /// `MODEL_SCORE`
+    ModelScore,
+    /// `EXTERNAL_MODEL_SCORE`
+    ExternalModelScore,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DataSource {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DataSource::Event => "EVENT",
+            DataSource::ModelScore => "MODEL_SCORE",
+            DataSource::ExternalModelScore => "EXTERNAL_MODEL_SCORE",
+            DataSource::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DataSource {
+    fn default() -> DataSource {
+        DataSource::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DataSource {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DataSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DataSource {
+    fn from(value: &'a str) -> DataSource {
+        match value {
+            "EVENT" => DataSource::Event,
+            "MODEL_SCORE" => DataSource::ModelScore,
+            "EXTERNAL_MODEL_SCORE" => DataSource::ExternalModelScore,
+            _ => DataSource::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DataSource {
+    fn from(value: String) -> DataSource {
+        match value.as_str() {
+            "EVENT" => DataSource::Event,
+            "MODEL_SCORE" => DataSource::ModelScore,
+            "EXTERNAL_MODEL_SCORE" => DataSource::ExternalModelScore,
+            _ => DataSource::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DataSource {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DataSource, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DataSource {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DataSource {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DataSource {
+    fn deserialize<D>(deserializer: D) -> Result<DataSource, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DataType {
+    /// `STRING`
+    String,
+    /// `INTEGER`
+    Integer,
+    /// `FLOAT`
+    Float,
+    /// `BOOLEAN`
+    Boolean,
+    /// `DATETIME`
+    Datetime,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DataType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DataType::String => "STRING",
+            DataType::Integer => "INTEGER",
+            DataType::Float => "FLOAT",
+            DataType::Boolean => "BOOLEAN",
+            DataType::Datetime => "DATETIME",
+            DataType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DataType {
+    fn default() -> DataType {
+        DataType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DataType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DataType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DataType {
+    fn from(value: &'a str) -> DataType {
+        match value {
+            "STRING" => DataType::String,
+            "INTEGER" => DataType::Integer,
+            "FLOAT" => DataType::Float,
+            "BOOLEAN" => DataType::Boolean,
+            "DATETIME" => DataType::Datetime,
+            _ => DataType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DataType {
+    fn from(value: String) -> DataType {
+        match value.as_str() {
+            "STRING" => DataType::String,
+            "INTEGER" => DataType::Integer,
+            "FLOAT" => DataType::Float,
+            "BOOLEAN" => DataType::Boolean,
+            "DATETIME" => DataType::Datetime,
+            _ => DataType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DataType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DataType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DataType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DataType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DataType {
+    fn deserialize<D>(deserializer: D) -> Result<DataType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct DeleteDetectorVersionRequest {
@@ -310,7 +516,7 @@ pub struct DescribeModelVersionsRequest {
     /// <p>The model type.</p>
     #[serde(rename = "modelType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub model_type: Option<String>,
+    pub model_type: Option<ModelTypeEnum>,
     /// <p>The model version. </p>
     #[serde(rename = "modelVersionNumber")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -356,6 +562,104 @@ pub struct Detector {
     pub last_updated_time: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DetectorVersionStatus {
+    /// `DRAFT`
+    Draft,
+    /// `ACTIVE`
+    Active,
+    /// `INACTIVE`
+    Inactive,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DetectorVersionStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DetectorVersionStatus::Draft => "DRAFT",
+            DetectorVersionStatus::Active => "ACTIVE",
+            DetectorVersionStatus::Inactive => "INACTIVE",
+            DetectorVersionStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DetectorVersionStatus {
+    fn default() -> DetectorVersionStatus {
+        DetectorVersionStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DetectorVersionStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DetectorVersionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DetectorVersionStatus {
+    fn from(value: &'a str) -> DetectorVersionStatus {
+        match value {
+            "DRAFT" => DetectorVersionStatus::Draft,
+            "ACTIVE" => DetectorVersionStatus::Active,
+            "INACTIVE" => DetectorVersionStatus::Inactive,
+            _ => DetectorVersionStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DetectorVersionStatus {
+    fn from(value: String) -> DetectorVersionStatus {
+        match value.as_str() {
+            "DRAFT" => DetectorVersionStatus::Draft,
+            "ACTIVE" => DetectorVersionStatus::Active,
+            "INACTIVE" => DetectorVersionStatus::Inactive,
+            _ => DetectorVersionStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DetectorVersionStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DetectorVersionStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DetectorVersionStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DetectorVersionStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DetectorVersionStatus {
+    fn deserialize<D>(deserializer: D) -> Result<DetectorVersionStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The summary of the detector version.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -375,7 +679,7 @@ pub struct DetectorVersionSummary {
     /// <p>The detector version status. </p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<DetectorVersionStatus>,
 }
 
 /// <p>The Amazon SageMaker model.</p>
@@ -401,11 +705,11 @@ pub struct ExternalModel {
     /// <p>The Amazon Fraud Detector status for the external model endpoint</p>
     #[serde(rename = "modelEndpointStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub model_endpoint_status: Option<String>,
+    pub model_endpoint_status: Option<ModelEndpointStatus>,
     /// <p>The source of the model.</p>
     #[serde(rename = "modelSource")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub model_source: Option<String>,
+    pub model_source: Option<ModelSource>,
     /// <p>The output configuration.</p>
     #[serde(rename = "outputConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -465,7 +769,7 @@ pub struct GetDetectorVersionResult {
     /// <p>The status of the detector version.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<DetectorVersionStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -536,7 +840,7 @@ pub struct GetModelVersionRequest {
     pub model_id: String,
     /// <p>The model type. </p>
     #[serde(rename = "modelType")]
-    pub model_type: String,
+    pub model_type: ModelTypeEnum,
     /// <p>The model version. </p>
     #[serde(rename = "modelVersionNumber")]
     pub model_version_number: String,
@@ -556,7 +860,7 @@ pub struct GetModelVersionResult {
     /// <p>The model type. </p>
     #[serde(rename = "modelType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub model_type: Option<String>,
+    pub model_type: Option<ModelTypeEnum>,
     /// <p>The model version. </p>
     #[serde(rename = "modelVersionNumber")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -581,7 +885,7 @@ pub struct GetModelsRequest {
     /// <p>The model type.</p>
     #[serde(rename = "modelType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub model_type: Option<String>,
+    pub model_type: Option<ModelTypeEnum>,
     /// <p>The next token for the request.</p>
     #[serde(rename = "nextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -746,6 +1050,94 @@ pub struct LabelSchema {
     pub label_mapper: ::std::collections::HashMap<String, Vec<String>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Language {
+    /// `DETECTORPL`
+    Detectorpl,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Language {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Language::Detectorpl => "DETECTORPL",
+            Language::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Language {
+    fn default() -> Language {
+        Language::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Language {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Language {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Language {
+    fn from(value: &'a str) -> Language {
+        match value {
+            "DETECTORPL" => Language::Detectorpl,
+            _ => Language::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Language {
+    fn from(value: String) -> Language {
+        match value.as_str() {
+            "DETECTORPL" => Language::Detectorpl,
+            _ => Language::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Language {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Language, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Language {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Language {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Language {
+    fn deserialize<D>(deserializer: D) -> Result<Language, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The model.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -773,7 +1165,7 @@ pub struct Model {
     /// <p>The model type.</p>
     #[serde(rename = "modelType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub model_type: Option<String>,
+    pub model_type: Option<ModelTypeEnum>,
     /// <p>The model input variables.</p>
     #[serde(rename = "modelVariables")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -803,6 +1195,99 @@ pub struct ModelEndpointDataBlob {
     pub content_type: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ModelEndpointStatus {
+    /// `ASSOCIATED`
+    Associated,
+    /// `DISSOCIATED`
+    Dissociated,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ModelEndpointStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ModelEndpointStatus::Associated => "ASSOCIATED",
+            ModelEndpointStatus::Dissociated => "DISSOCIATED",
+            ModelEndpointStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ModelEndpointStatus {
+    fn default() -> ModelEndpointStatus {
+        ModelEndpointStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ModelEndpointStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ModelEndpointStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ModelEndpointStatus {
+    fn from(value: &'a str) -> ModelEndpointStatus {
+        match value {
+            "ASSOCIATED" => ModelEndpointStatus::Associated,
+            "DISSOCIATED" => ModelEndpointStatus::Dissociated,
+            _ => ModelEndpointStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ModelEndpointStatus {
+    fn from(value: String) -> ModelEndpointStatus {
+        match value.as_str() {
+            "ASSOCIATED" => ModelEndpointStatus::Associated,
+            "DISSOCIATED" => ModelEndpointStatus::Dissociated,
+            _ => ModelEndpointStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ModelEndpointStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ModelEndpointStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ModelEndpointStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ModelEndpointStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ModelEndpointStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ModelEndpointStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The model input configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ModelInputConfiguration {
@@ -813,7 +1298,7 @@ pub struct ModelInputConfiguration {
     /// <p> The format of the model input configuration. The format differs depending on if it is passed through to SageMaker or constructed by Amazon Fraud Detector.</p>
     #[serde(rename = "format")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub format: Option<String>,
+    pub format: Option<ModelInputDataFormat>,
     /// <p> For an opaque-model, the input to the model will be a ByteBuffer blob provided in the getPrediction request, and will be passed to SageMaker as-is. For non-opaque models, the input will be constructed by Amazon Fraud Detector based on the model-configuration. </p>
     #[serde(rename = "isOpaque")]
     pub is_opaque: bool,
@@ -823,6 +1308,99 @@ pub struct ModelInputConfiguration {
     pub json_input_template: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ModelInputDataFormat {
+    /// `TEXT_CSV`
+    TextCsv,
+    /// `APPLICATION_JSON`
+    ApplicationJson,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ModelInputDataFormat {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ModelInputDataFormat::TextCsv => "TEXT_CSV",
+            ModelInputDataFormat::ApplicationJson => "APPLICATION_JSON",
+            ModelInputDataFormat::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ModelInputDataFormat {
+    fn default() -> ModelInputDataFormat {
+        ModelInputDataFormat::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ModelInputDataFormat {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ModelInputDataFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ModelInputDataFormat {
+    fn from(value: &'a str) -> ModelInputDataFormat {
+        match value {
+            "TEXT_CSV" => ModelInputDataFormat::TextCsv,
+            "APPLICATION_JSON" => ModelInputDataFormat::ApplicationJson,
+            _ => ModelInputDataFormat::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ModelInputDataFormat {
+    fn from(value: String) -> ModelInputDataFormat {
+        match value.as_str() {
+            "TEXT_CSV" => ModelInputDataFormat::TextCsv,
+            "APPLICATION_JSON" => ModelInputDataFormat::ApplicationJson,
+            _ => ModelInputDataFormat::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ModelInputDataFormat {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ModelInputDataFormat, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ModelInputDataFormat {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ModelInputDataFormat {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ModelInputDataFormat {
+    fn deserialize<D>(deserializer: D) -> Result<ModelInputDataFormat, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Provides the model output configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ModelOutputConfiguration {
@@ -832,13 +1410,106 @@ pub struct ModelOutputConfiguration {
     pub csv_index_to_variable_map: Option<::std::collections::HashMap<String, String>>,
     /// <p>The format of the model output configuration.</p>
     #[serde(rename = "format")]
-    pub format: String,
+    pub format: ModelOutputDataFormat,
     /// <p>A map of JSON keys in response from SageMaker to the Amazon Fraud Detector variables. </p>
     #[serde(rename = "jsonKeyToVariableMap")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub json_key_to_variable_map: Option<::std::collections::HashMap<String, String>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ModelOutputDataFormat {
+    /// `TEXT_CSV`
+    TextCsv,
+    /// `APPLICATION_JSONLINES`
+    ApplicationJsonlines,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ModelOutputDataFormat {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ModelOutputDataFormat::TextCsv => "TEXT_CSV",
+            ModelOutputDataFormat::ApplicationJsonlines => "APPLICATION_JSONLINES",
+            ModelOutputDataFormat::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ModelOutputDataFormat {
+    fn default() -> ModelOutputDataFormat {
+        ModelOutputDataFormat::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ModelOutputDataFormat {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ModelOutputDataFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ModelOutputDataFormat {
+    fn from(value: &'a str) -> ModelOutputDataFormat {
+        match value {
+            "TEXT_CSV" => ModelOutputDataFormat::TextCsv,
+            "APPLICATION_JSONLINES" => ModelOutputDataFormat::ApplicationJsonlines,
+            _ => ModelOutputDataFormat::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ModelOutputDataFormat {
+    fn from(value: String) -> ModelOutputDataFormat {
+        match value.as_str() {
+            "TEXT_CSV" => ModelOutputDataFormat::TextCsv,
+            "APPLICATION_JSONLINES" => ModelOutputDataFormat::ApplicationJsonlines,
+            _ => ModelOutputDataFormat::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ModelOutputDataFormat {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ModelOutputDataFormat, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ModelOutputDataFormat {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ModelOutputDataFormat {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ModelOutputDataFormat {
+    fn deserialize<D>(deserializer: D) -> Result<ModelOutputDataFormat, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The fraud prediction scores.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -853,6 +1524,192 @@ pub struct ModelScores {
     pub scores: Option<::std::collections::HashMap<String, f32>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ModelSource {
+    /// `SAGEMAKER`
+    Sagemaker,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ModelSource {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ModelSource::Sagemaker => "SAGEMAKER",
+            ModelSource::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ModelSource {
+    fn default() -> ModelSource {
+        ModelSource::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ModelSource {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ModelSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ModelSource {
+    fn from(value: &'a str) -> ModelSource {
+        match value {
+            "SAGEMAKER" => ModelSource::Sagemaker,
+            _ => ModelSource::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ModelSource {
+    fn from(value: String) -> ModelSource {
+        match value.as_str() {
+            "SAGEMAKER" => ModelSource::Sagemaker,
+            _ => ModelSource::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ModelSource {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ModelSource, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ModelSource {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ModelSource {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ModelSource {
+    fn deserialize<D>(deserializer: D) -> Result<ModelSource, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ModelTypeEnum {
+    /// `ONLINE_FRAUD_INSIGHTS`
+    OnlineFraudInsights,
+    /// `TRANSACTION_FRAUD_INSIGHTS`
+    TransactionFraudInsights,
+    /// `ACCOUNT_TAKEOVER_INSIGHTS`
+    AccountTakeoverInsights,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ModelTypeEnum {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ModelTypeEnum::OnlineFraudInsights => "ONLINE_FRAUD_INSIGHTS",
+            ModelTypeEnum::TransactionFraudInsights => "TRANSACTION_FRAUD_INSIGHTS",
+            ModelTypeEnum::AccountTakeoverInsights => "ACCOUNT_TAKEOVER_INSIGHTS",
+            ModelTypeEnum::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ModelTypeEnum {
+    fn default() -> ModelTypeEnum {
+        ModelTypeEnum::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ModelTypeEnum {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ModelTypeEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ModelTypeEnum {
+    fn from(value: &'a str) -> ModelTypeEnum {
+        match value {
+            "ONLINE_FRAUD_INSIGHTS" => ModelTypeEnum::OnlineFraudInsights,
+            "TRANSACTION_FRAUD_INSIGHTS" => ModelTypeEnum::TransactionFraudInsights,
+            "ACCOUNT_TAKEOVER_INSIGHTS" => ModelTypeEnum::AccountTakeoverInsights,
+            _ => ModelTypeEnum::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ModelTypeEnum {
+    fn from(value: String) -> ModelTypeEnum {
+        match value.as_str() {
+            "ONLINE_FRAUD_INSIGHTS" => ModelTypeEnum::OnlineFraudInsights,
+            "TRANSACTION_FRAUD_INSIGHTS" => ModelTypeEnum::TransactionFraudInsights,
+            "ACCOUNT_TAKEOVER_INSIGHTS" => ModelTypeEnum::AccountTakeoverInsights,
+            _ => ModelTypeEnum::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ModelTypeEnum {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ModelTypeEnum, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ModelTypeEnum {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ModelTypeEnum {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ModelTypeEnum {
+    fn deserialize<D>(deserializer: D) -> Result<ModelTypeEnum, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The model variable.&gt;</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ModelVariable {
@@ -873,7 +1730,7 @@ pub struct ModelVersion {
     pub model_id: String,
     /// <p>The model type.</p>
     #[serde(rename = "modelType")]
-    pub model_type: String,
+    pub model_type: ModelTypeEnum,
     /// <p>The model version.</p>
     #[serde(rename = "modelVersionNumber")]
     pub model_version_number: String,
@@ -906,7 +1763,7 @@ pub struct ModelVersionDetail {
     /// <p>The model type.</p>
     #[serde(rename = "modelType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub model_type: Option<String>,
+    pub model_type: Option<ModelTypeEnum>,
     /// <p>The model variables.</p>
     #[serde(rename = "modelVariables")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -982,10 +1839,10 @@ pub struct PutExternalModelRequest {
     pub model_endpoint: String,
     /// <p>The model endpoint’s status in Amazon Fraud Detector.</p>
     #[serde(rename = "modelEndpointStatus")]
-    pub model_endpoint_status: String,
+    pub model_endpoint_status: ModelEndpointStatus,
     /// <p>The source of the model.</p>
     #[serde(rename = "modelSource")]
-    pub model_source: String,
+    pub model_source: ModelSource,
     /// <p>The model endpoint output configuration.</p>
     #[serde(rename = "outputConfiguration")]
     pub output_configuration: ModelOutputConfiguration,
@@ -1090,7 +1947,7 @@ pub struct RuleDetail {
     /// <p>The rule language.</p>
     #[serde(rename = "language")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub language: Option<String>,
+    pub language: Option<Language>,
     /// <p>Timestamp of the last time the rule was updated.</p>
     #[serde(rename = "lastUpdatedTime")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1178,7 +2035,7 @@ pub struct UpdateDetectorVersionStatusRequest {
     pub detector_version_id: String,
     /// <p>The new status.</p>
     #[serde(rename = "status")]
-    pub status: String,
+    pub status: DetectorVersionStatus,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1196,7 +2053,7 @@ pub struct UpdateModelVersionRequest {
     pub model_id: String,
     /// <p>The model type.</p>
     #[serde(rename = "modelType")]
-    pub model_type: String,
+    pub model_type: ModelTypeEnum,
     /// <p>The model version.</p>
     #[serde(rename = "modelVersionNumber")]
     pub model_version_number: String,
@@ -1236,7 +2093,7 @@ pub struct UpdateRuleVersionRequest {
     pub expression: String,
     /// <p>The language.</p>
     #[serde(rename = "language")]
-    pub language: String,
+    pub language: Language,
     /// <p>The outcomes.</p>
     #[serde(rename = "outcomes")]
     pub outcomes: Vec<String>,
@@ -1289,11 +2146,11 @@ pub struct Variable {
     /// <p>The data source of the variable.</p>
     #[serde(rename = "dataSource")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub data_source: Option<String>,
+    pub data_source: Option<DataSource>,
     /// <p>The data type of the variable.</p>
     #[serde(rename = "dataType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub data_type: Option<String>,
+    pub data_type: Option<DataType>,
     /// <p>The default value of the variable.</p>
     #[serde(rename = "defaultValue")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2763,11 +3620,12 @@ pub struct FraudDetectorClient {
 impl FraudDetectorClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> FraudDetectorClient {
         FraudDetectorClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "FraudDetector"),
         }
     }
 
@@ -2799,6 +3657,7 @@ impl FraudDetector for FraudDetectorClient {
         input: BatchCreateVariableRequest,
     ) -> Result<BatchCreateVariableResult, RusotoError<BatchCreateVariableError>> {
         let mut request = SignedRequest::new("POST", "frauddetector", &self.region, "/");
+        request.set_operation("BatchCreateVariable");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2830,6 +3689,7 @@ impl FraudDetector for FraudDetectorClient {
         input: BatchGetVariableRequest,
     ) -> Result<BatchGetVariableResult, RusotoError<BatchGetVariableError>> {
         let mut request = SignedRequest::new("POST", "frauddetector", &self.region, "/");
+        request.set_operation("BatchGetVariable");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.BatchGetVariable");
@@ -2857,6 +3717,7 @@ impl FraudDetector for FraudDetectorClient {
         input: CreateDetectorVersionRequest,
     ) -> Result<CreateDetectorVersionResult, RusotoError<CreateDetectorVersionError>> {
         let mut request = SignedRequest::new("POST", "frauddetector", &self.region, "/");
+        request.set_operation("CreateDetectorVersion");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2888,6 +3749,7 @@ impl FraudDetector for FraudDetectorClient {
         input: CreateModelVersionRequest,
     ) -> Result<CreateModelVersionResult, RusotoError<CreateModelVersionError>> {
         let mut request = SignedRequest::new("POST", "frauddetector", &self.region, "/");
+        request.set_operation("CreateModelVersion");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2919,6 +3781,7 @@ impl FraudDetector for FraudDetectorClient {
         input: CreateRuleRequest,
     ) -> Result<CreateRuleResult, RusotoError<CreateRuleError>> {
         let mut request = SignedRequest::new("POST", "frauddetector", &self.region, "/");
+        request.set_operation("CreateRule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.CreateRule");
@@ -2946,6 +3809,7 @@ impl FraudDetector for FraudDetectorClient {
         input: CreateVariableRequest,
     ) -> Result<CreateVariableResult, RusotoError<CreateVariableError>> {
         let mut request = SignedRequest::new("POST", "frauddetector", &self.region, "/");
+        request.set_operation("CreateVariable");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.CreateVariable");
@@ -2973,6 +3837,7 @@ impl FraudDetector for FraudDetectorClient {
         input: DeleteDetectorVersionRequest,
     ) -> Result<DeleteDetectorVersionResult, RusotoError<DeleteDetectorVersionError>> {
         let mut request = SignedRequest::new("POST", "frauddetector", &self.region, "/");
+        request.set_operation("DeleteDetectorVersion");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3004,6 +3869,7 @@ impl FraudDetector for FraudDetectorClient {
         input: DeleteEventRequest,
     ) -> Result<DeleteEventResult, RusotoError<DeleteEventError>> {
         let mut request = SignedRequest::new("POST", "frauddetector", &self.region, "/");
+        request.set_operation("DeleteEvent");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.DeleteEvent");
@@ -3031,6 +3897,7 @@ impl FraudDetector for FraudDetectorClient {
         input: DescribeDetectorRequest,
     ) -> Result<DescribeDetectorResult, RusotoError<DescribeDetectorError>> {
         let mut request = SignedRequest::new("POST", "frauddetector", &self.region, "/");
+        request.set_operation("DescribeDetector");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.DescribeDetector");
@@ -3058,6 +3925,7 @@ impl FraudDetector for FraudDetectorClient {
         input: DescribeModelVersionsRequest,
     ) -> Result<DescribeModelVersionsResult, RusotoError<DescribeModelVersionsError>> {
         let mut request = SignedRequest::new("POST", "frauddetector", &self.region, "/");
+        request.set_operation("DescribeModelVersions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3089,6 +3957,7 @@ impl FraudDetector for FraudDetectorClient {
         input: GetDetectorVersionRequest,
     ) -> Result<GetDetectorVersionResult, RusotoError<GetDetectorVersionError>> {
         let mut request = SignedRequest::new("POST", "frauddetector", &self.region, "/");
+        request.set_operation("GetDetectorVersion");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3120,6 +3989,7 @@ impl FraudDetector for FraudDetectorClient {
         input: GetDetectorsRequest,
     ) -> Result<GetDetectorsResult, RusotoError<GetDetectorsError>> {
         let mut request = SignedRequest::new("POST", "frauddetector", &self.region, "/");
+        request.set_operation("GetDetectors");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.GetDetectors");
@@ -3147,6 +4017,7 @@ impl FraudDetector for FraudDetectorClient {
         input: GetExternalModelsRequest,
     ) -> Result<GetExternalModelsResult, RusotoError<GetExternalModelsError>> {
         let mut request = SignedRequest::new("POST", "frauddetector", &self.region, "/");
+        request.set_operation("GetExternalModels");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3177,6 +4048,7 @@ impl FraudDetector for FraudDetectorClient {
         input: GetModelVersionRequest,
     ) -> Result<GetModelVersionResult, RusotoError<GetModelVersionError>> {
         let mut request = SignedRequest::new("POST", "frauddetector", &self.region, "/");
+        request.set_operation("GetModelVersion");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.GetModelVersion");
@@ -3204,6 +4076,7 @@ impl FraudDetector for FraudDetectorClient {
         input: GetModelsRequest,
     ) -> Result<GetModelsResult, RusotoError<GetModelsError>> {
         let mut request = SignedRequest::new("POST", "frauddetector", &self.region, "/");
+        request.set_operation("GetModels");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.GetModels");
@@ -3231,6 +4104,7 @@ impl FraudDetector for FraudDetectorClient {
         input: GetOutcomesRequest,
     ) -> Result<GetOutcomesResult, RusotoError<GetOutcomesError>> {
         let mut request = SignedRequest::new("POST", "frauddetector", &self.region, "/");
+        request.set_operation("GetOutcomes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.GetOutcomes");
@@ -3258,6 +4132,7 @@ impl FraudDetector for FraudDetectorClient {
         input: GetPredictionRequest,
     ) -> Result<GetPredictionResult, RusotoError<GetPredictionError>> {
         let mut request = SignedRequest::new("POST", "frauddetector", &self.region, "/");
+        request.set_operation("GetPrediction");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.GetPrediction");
@@ -3285,6 +4160,7 @@ impl FraudDetector for FraudDetectorClient {
         input: GetRulesRequest,
     ) -> Result<GetRulesResult, RusotoError<GetRulesError>> {
         let mut request = SignedRequest::new("POST", "frauddetector", &self.region, "/");
+        request.set_operation("GetRules");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.GetRules");
@@ -3312,6 +4188,7 @@ impl FraudDetector for FraudDetectorClient {
         input: GetVariablesRequest,
     ) -> Result<GetVariablesResult, RusotoError<GetVariablesError>> {
         let mut request = SignedRequest::new("POST", "frauddetector", &self.region, "/");
+        request.set_operation("GetVariables");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.GetVariables");
@@ -3339,6 +4216,7 @@ impl FraudDetector for FraudDetectorClient {
         input: PutDetectorRequest,
     ) -> Result<PutDetectorResult, RusotoError<PutDetectorError>> {
         let mut request = SignedRequest::new("POST", "frauddetector", &self.region, "/");
+        request.set_operation("PutDetector");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.PutDetector");
@@ -3366,6 +4244,7 @@ impl FraudDetector for FraudDetectorClient {
         input: PutExternalModelRequest,
     ) -> Result<PutExternalModelResult, RusotoError<PutExternalModelError>> {
         let mut request = SignedRequest::new("POST", "frauddetector", &self.region, "/");
+        request.set_operation("PutExternalModel");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.PutExternalModel");
@@ -3393,6 +4272,7 @@ impl FraudDetector for FraudDetectorClient {
         input: PutModelRequest,
     ) -> Result<PutModelResult, RusotoError<PutModelError>> {
         let mut request = SignedRequest::new("POST", "frauddetector", &self.region, "/");
+        request.set_operation("PutModel");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.PutModel");
@@ -3420,6 +4300,7 @@ impl FraudDetector for FraudDetectorClient {
         input: PutOutcomeRequest,
     ) -> Result<PutOutcomeResult, RusotoError<PutOutcomeError>> {
         let mut request = SignedRequest::new("POST", "frauddetector", &self.region, "/");
+        request.set_operation("PutOutcome");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.PutOutcome");
@@ -3447,6 +4328,7 @@ impl FraudDetector for FraudDetectorClient {
         input: UpdateDetectorVersionRequest,
     ) -> Result<UpdateDetectorVersionResult, RusotoError<UpdateDetectorVersionError>> {
         let mut request = SignedRequest::new("POST", "frauddetector", &self.region, "/");
+        request.set_operation("UpdateDetectorVersion");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3479,6 +4361,7 @@ impl FraudDetector for FraudDetectorClient {
     ) -> Result<UpdateDetectorVersionMetadataResult, RusotoError<UpdateDetectorVersionMetadataError>>
     {
         let mut request = SignedRequest::new("POST", "frauddetector", &self.region, "/");
+        request.set_operation("UpdateDetectorVersionMetadata");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3511,6 +4394,7 @@ impl FraudDetector for FraudDetectorClient {
     ) -> Result<UpdateDetectorVersionStatusResult, RusotoError<UpdateDetectorVersionStatusError>>
     {
         let mut request = SignedRequest::new("POST", "frauddetector", &self.region, "/");
+        request.set_operation("UpdateDetectorVersionStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3542,6 +4426,7 @@ impl FraudDetector for FraudDetectorClient {
         input: UpdateModelVersionRequest,
     ) -> Result<UpdateModelVersionResult, RusotoError<UpdateModelVersionError>> {
         let mut request = SignedRequest::new("POST", "frauddetector", &self.region, "/");
+        request.set_operation("UpdateModelVersion");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3573,6 +4458,7 @@ impl FraudDetector for FraudDetectorClient {
         input: UpdateRuleMetadataRequest,
     ) -> Result<UpdateRuleMetadataResult, RusotoError<UpdateRuleMetadataError>> {
         let mut request = SignedRequest::new("POST", "frauddetector", &self.region, "/");
+        request.set_operation("UpdateRuleMetadata");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3604,6 +4490,7 @@ impl FraudDetector for FraudDetectorClient {
         input: UpdateRuleVersionRequest,
     ) -> Result<UpdateRuleVersionResult, RusotoError<UpdateRuleVersionError>> {
         let mut request = SignedRequest::new("POST", "frauddetector", &self.region, "/");
+        request.set_operation("UpdateRuleVersion");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3634,6 +4521,7 @@ impl FraudDetector for FraudDetectorClient {
         input: UpdateVariableRequest,
     ) -> Result<UpdateVariableResult, RusotoError<UpdateVariableError>> {
         let mut request = SignedRequest::new("POST", "frauddetector", &self.region, "/");
+        request.set_operation("UpdateVariable");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.UpdateVariable");
@@ -38,6 +38,110 @@ pub struct ActiveDirectoryBackupAttributes {
     pub domain_name: Option<String>,
 }
 
+/// <p>The type of error relating to Microsoft Active Directory. NOT_FOUND means that no directory was found by specifying the given directory. INCOMPATIBLE_MODE means that the directory specified is not a Microsoft AD directory. WRONG_VPC means that the specified directory isn't accessible from the specified VPC. WRONG_STAGE means that the specified directory isn't currently in the ACTIVE state.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ActiveDirectoryErrorType {
+    /// `DOMAIN_NOT_FOUND`
+    DomainNotFound,
+    /// `INCOMPATIBLE_DOMAIN_MODE`
+    IncompatibleDomainMode,
+    /// `WRONG_VPC`
+    WrongVpc,
+    /// `INVALID_DOMAIN_STAGE`
+    InvalidDomainStage,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ActiveDirectoryErrorType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ActiveDirectoryErrorType::DomainNotFound => "DOMAIN_NOT_FOUND",
+            ActiveDirectoryErrorType::IncompatibleDomainMode => "INCOMPATIBLE_DOMAIN_MODE",
+            ActiveDirectoryErrorType::WrongVpc => "WRONG_VPC",
+            ActiveDirectoryErrorType::InvalidDomainStage => "INVALID_DOMAIN_STAGE",
+            ActiveDirectoryErrorType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ActiveDirectoryErrorType {
+    fn default() -> ActiveDirectoryErrorType {
+        ActiveDirectoryErrorType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ActiveDirectoryErrorType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ActiveDirectoryErrorType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ActiveDirectoryErrorType {
+    fn from(value: &'a str) -> ActiveDirectoryErrorType {
+        match value {
+            "DOMAIN_NOT_FOUND" => ActiveDirectoryErrorType::DomainNotFound,
+            "INCOMPATIBLE_DOMAIN_MODE" => ActiveDirectoryErrorType::IncompatibleDomainMode,
+            "WRONG_VPC" => ActiveDirectoryErrorType::WrongVpc,
+            "INVALID_DOMAIN_STAGE" => ActiveDirectoryErrorType::InvalidDomainStage,
+            _ => ActiveDirectoryErrorType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ActiveDirectoryErrorType {
+    fn from(value: String) -> ActiveDirectoryErrorType {
+        match value.as_str() {
+            "DOMAIN_NOT_FOUND" => ActiveDirectoryErrorType::DomainNotFound,
+            "INCOMPATIBLE_DOMAIN_MODE" => ActiveDirectoryErrorType::IncompatibleDomainMode,
+            "WRONG_VPC" => ActiveDirectoryErrorType::WrongVpc,
+            "INVALID_DOMAIN_STAGE" => ActiveDirectoryErrorType::InvalidDomainStage,
+            _ => ActiveDirectoryErrorType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ActiveDirectoryErrorType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ActiveDirectoryErrorType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ActiveDirectoryErrorType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ActiveDirectoryErrorType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ActiveDirectoryErrorType {
+    fn deserialize<D>(deserializer: D) -> Result<ActiveDirectoryErrorType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A backup of an Amazon FSx for Windows File Server file system. You can create a new file system from a backup to protect against data loss.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -47,7 +151,12 @@ pub struct Backup {
     pub backup_id: String,
     /// <p>The time when a particular backup was created.</p>
     #[serde(rename = "CreationTime")]
-    pub creation_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub creation_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The configuration of the self-managed Microsoft Active Directory (AD) to which the Windows File Server instance is joined.</p>
     #[serde(rename = "DirectoryInformation")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -65,7 +174,7 @@ pub struct Backup {
     pub kms_key_id: Option<String>,
     /// <p>The lifecycle status of the backup.</p>
     #[serde(rename = "Lifecycle")]
-    pub lifecycle: String,
+    pub lifecycle: BackupLifecycle,
     #[serde(rename = "ProgressPercent")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub progress_percent: Option<i64>,
@@ -79,7 +188,7 @@ pub struct Backup {
     pub tags: Option<Vec<Tag>>,
     /// <p>The type of the backup.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: BackupType,
 }
 
 /// <p>If backup creation fails, this structure contains the details of that failure.</p>
@@ -92,6 +201,224 @@ pub struct BackupFailureDetails {
     pub message: Option<String>,
 }
 
+/// <p>The lifecycle status of the backup.</p> <ul> <li> <p> <code>AVAILABLE</code> - The backup is fully available.</p> </li> <li> <p> <code>PENDING</code> - For user-initiated backups on Lustre file systems only; Amazon FSx hasn't started creating the backup.</p> </li> <li> <p> <code>CREATING</code> - Amazon FSx is creating the new user-initiated backup.</p> </li> <li> <p> <code>TRANSFERRING</code> - For user-initiated backups on Lustre file systems only; Amazon FSx is backing up the file system.</p> </li> <li> <p> <code>COPYING</code> - Amazon FSx is copying the backup.</p> </li> <li> <p> <code>DELETED</code> - Amazon FSx deleted the backup and it's no longer available.</p> </li> <li> <p> <code>FAILED</code> - Amazon FSx couldn't finish the backup.</p> </li> </ul>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BackupLifecycle {
+    /// `AVAILABLE`
+    Available,
+    /// `CREATING`
+    Creating,
+    /// `TRANSFERRING`
+    Transferring,
+    /// `DELETED`
+    Deleted,
+    /// `FAILED`
+    Failed,
+    /// `PENDING`
+    Pending,
+    /// `COPYING`
+    Copying,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl BackupLifecycle {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BackupLifecycle::Available => "AVAILABLE",
+            BackupLifecycle::Creating => "CREATING",
+            BackupLifecycle::Transferring => "TRANSFERRING",
+            BackupLifecycle::Deleted => "DELETED",
+            BackupLifecycle::Failed => "FAILED",
+            BackupLifecycle::Pending => "PENDING",
+            BackupLifecycle::Copying => "COPYING",
+            BackupLifecycle::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for BackupLifecycle {
+    fn default() -> BackupLifecycle {
+        BackupLifecycle::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for BackupLifecycle {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for BackupLifecycle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for BackupLifecycle {
+    fn from(value: &'a str) -> BackupLifecycle {
+        match value {
+            "AVAILABLE" => BackupLifecycle::Available,
+            "CREATING" => BackupLifecycle::Creating,
+            "TRANSFERRING" => BackupLifecycle::Transferring,
+            "DELETED" => BackupLifecycle::Deleted,
+            "FAILED" => BackupLifecycle::Failed,
+            "PENDING" => BackupLifecycle::Pending,
+            "COPYING" => BackupLifecycle::Copying,
+            _ => BackupLifecycle::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BackupLifecycle {
+    fn from(value: String) -> BackupLifecycle {
+        match value.as_str() {
+            "AVAILABLE" => BackupLifecycle::Available,
+            "CREATING" => BackupLifecycle::Creating,
+            "TRANSFERRING" => BackupLifecycle::Transferring,
+            "DELETED" => BackupLifecycle::Deleted,
+            "FAILED" => BackupLifecycle::Failed,
+            "PENDING" => BackupLifecycle::Pending,
+            "COPYING" => BackupLifecycle::Copying,
+            _ => BackupLifecycle::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for BackupLifecycle {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<BackupLifecycle, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for BackupLifecycle {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for BackupLifecycle {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BackupLifecycle {
+    fn deserialize<D>(deserializer: D) -> Result<BackupLifecycle, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>The type of the backup.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BackupType {
+    /// `AUTOMATIC`
+    Automatic,
+    /// `USER_INITIATED`
+    UserInitiated,
+    /// `AWS_BACKUP`
+    AwsBackup,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl BackupType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BackupType::Automatic => "AUTOMATIC",
+            BackupType::UserInitiated => "USER_INITIATED",
+            BackupType::AwsBackup => "AWS_BACKUP",
+            BackupType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for BackupType {
+    fn default() -> BackupType {
+        BackupType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for BackupType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for BackupType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for BackupType {
+    fn from(value: &'a str) -> BackupType {
+        match value {
+            "AUTOMATIC" => BackupType::Automatic,
+            "USER_INITIATED" => BackupType::UserInitiated,
+            "AWS_BACKUP" => BackupType::AwsBackup,
+            _ => BackupType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BackupType {
+    fn from(value: String) -> BackupType {
+        match value.as_str() {
+            "AUTOMATIC" => BackupType::Automatic,
+            "USER_INITIATED" => BackupType::UserInitiated,
+            "AWS_BACKUP" => BackupType::AwsBackup,
+            _ => BackupType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for BackupType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<BackupType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for BackupType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for BackupType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BackupType {
+    fn deserialize<D>(deserializer: D) -> Result<BackupType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Cancels a data repository task.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -107,7 +434,7 @@ pub struct CancelDataRepositoryTaskResponse {
     /// <p><p>The lifecycle status of the data repository task, as follows:</p> <ul> <li> <p> <code>PENDING</code> - Amazon FSx has not started the task.</p> </li> <li> <p> <code>EXECUTING</code> - Amazon FSx is processing the task.</p> </li> <li> <p> <code>FAILED</code> - Amazon FSx was not able to complete the task. For example, there may be files the task failed to process. The <a>DataRepositoryTaskFailureDetails</a> property provides more information about task failures.</p> </li> <li> <p> <code>SUCCEEDED</code> - FSx completed the task successfully.</p> </li> <li> <p> <code>CANCELED</code> - Amazon FSx canceled the task and it did not complete.</p> </li> <li> <p> <code>CANCELING</code> - FSx is in process of canceling the task.</p> </li> </ul></p>
     #[serde(rename = "Lifecycle")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub lifecycle: Option<String>,
+    pub lifecycle: Option<DataRepositoryTaskLifecycle>,
     /// <p>The ID of the task being canceled.</p>
     #[serde(rename = "TaskId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -123,7 +450,7 @@ pub struct CompletionReport {
     /// <p>Required if <code>Enabled</code> is set to <code>true</code>. Specifies the format of the <code>CompletionReport</code>. <code>REPORT_CSV_20191124</code> is the only format currently supported. When <code>Format</code> is set to <code>REPORT_CSV_20191124</code>, the <code>CompletionReport</code> is provided in CSV format, and is delivered to <code>{path}/task-{id}/failures.csv</code>. </p>
     #[serde(rename = "Format")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub format: Option<String>,
+    pub format: Option<ReportFormat>,
     /// <p>Required if <code>Enabled</code> is set to <code>true</code>. Specifies the location of the report on the file system's linked S3 data repository. An absolute path that defines where the completion report will be stored in the destination location. The <code>Path</code> you provide must be located within the file system’s ExportPath. An example <code>Path</code> value is "s3://myBucket/myExportPath/optionalPrefix". The report provides the following information for each file in the report: FilePath, FileStatus, and ErrorCode. To learn more about a file system's <code>ExportPath</code>, see . </p>
     #[serde(rename = "Path")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -131,7 +458,7 @@ pub struct CompletionReport {
     /// <p>Required if <code>Enabled</code> is set to <code>true</code>. Specifies the scope of the <code>CompletionReport</code>; <code>FAILED_FILES_ONLY</code> is the only scope currently supported. When <code>Scope</code> is set to <code>FAILED_FILES_ONLY</code>, the <code>CompletionReport</code> only contains information about files that the data repository task failed to process.</p>
     #[serde(rename = "Scope")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scope: Option<String>,
+    pub scope: Option<ReportScope>,
 }
 
 /// <p>The request object for the <code>CreateBackup</code> operation.</p>
@@ -181,7 +508,7 @@ pub struct CreateDataRepositoryTaskRequest {
     pub tags: Option<Vec<Tag>>,
     /// <p>Specifies the type of data repository task to create.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: DataRepositoryTaskType,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -262,7 +589,7 @@ pub struct CreateFileSystemRequest {
     pub client_request_token: Option<String>,
     /// <p>The type of Amazon FSx file system to create.</p>
     #[serde(rename = "FileSystemType")]
-    pub file_system_type: String,
+    pub file_system_type: FileSystemType,
     #[serde(rename = "KmsKeyId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub kms_key_id: Option<String>,
@@ -322,7 +649,7 @@ pub struct CreateFileSystemWindowsConfiguration {
     /// <p>Specifies the file system deployment type, valid values are the following:</p> <ul> <li> <p>MULTI_AZ_1 - Deploys a high availability file system that is configured for Multi-AZ redundancy to tolerate temporary Availability Zone (AZ) unavailability. You can only deploy a Multi-AZ file system in AWS Regions that have a minimum of three Availability Zones.</p> </li> <li> <p>SINGLE_AZ_1 - (Default) Choose to deploy a file system that is configured for single AZ redundancy.</p> </li> </ul> <p>To learn more about high availability Multi-AZ file systems, see <a href="https://docs.aws.amazon.com/fsx/latest/WindowsGuide/high-availability-multiAZ.html"> High Availability for Amazon FSx for Windows File Server</a>.</p>
     #[serde(rename = "DeploymentType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deployment_type: Option<String>,
+    pub deployment_type: Option<WindowsDeploymentType>,
     /// <p>Required when <code>DeploymentType</code> is set to <code>MULTI_AZ_1</code>. This specifies the subnet in which you want the preferred file server to be located. For in-AWS applications, we recommend that you launch your clients in the same Availability Zone (AZ) as your preferred file server to reduce cross-AZ data transfer costs and minimize latency. </p>
     #[serde(rename = "PreferredSubnetId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -363,11 +690,21 @@ pub struct DataRepositoryConfiguration {
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DataRepositoryTask {
     #[serde(rename = "CreationTime")]
-    pub creation_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub creation_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The time that Amazon FSx completed processing the task, populated after the task is complete.</p>
     #[serde(rename = "EndTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Failure message describing why the task failed, it is populated only when <code>Lifecycle</code> is set to <code>FAILED</code>.</p>
     #[serde(rename = "FailureDetails")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -376,7 +713,7 @@ pub struct DataRepositoryTask {
     pub file_system_id: String,
     /// <p><p>The lifecycle status of the data repository task, as follows:</p> <ul> <li> <p> <code>PENDING</code> - Amazon FSx has not started the task.</p> </li> <li> <p> <code>EXECUTING</code> - Amazon FSx is processing the task.</p> </li> <li> <p> <code>FAILED</code> - Amazon FSx was not able to complete the task. For example, there may be files the task failed to process. The <a>DataRepositoryTaskFailureDetails</a> property provides more information about task failures.</p> </li> <li> <p> <code>SUCCEEDED</code> - FSx completed the task successfully.</p> </li> <li> <p> <code>CANCELED</code> - Amazon FSx canceled the task and it did not complete.</p> </li> <li> <p> <code>CANCELING</code> - FSx is in process of canceling the task.</p> </li> </ul> <note> <p>You cannot delete an FSx for Lustre file system if there are data repository tasks for the file system in the <code>PENDING</code> or <code>EXECUTING</code> states. Please retry when the data repository task is finished (with a status of <code>CANCELED</code>, <code>SUCCEEDED</code>, or <code>FAILED</code>). You can use the DescribeDataRepositoryTask action to monitor the task status. Contact the FSx team if you need to delete your file system immediately.</p> </note></p>
     #[serde(rename = "Lifecycle")]
-    pub lifecycle: String,
+    pub lifecycle: DataRepositoryTaskLifecycle,
     /// <p>An array of paths on the Amazon FSx for Lustre file system that specify the data for the data repository task to process. For example, in an EXPORT_TO_REPOSITORY task, the paths specify which data to export to the linked data repository.</p> <p>(Default) If <code>Paths</code> is not specified, Amazon FSx uses the file system root directory.</p>
     #[serde(rename = "Paths")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -389,8 +726,13 @@ pub struct DataRepositoryTask {
     pub resource_arn: Option<String>,
     /// <p>The time that Amazon FSx began processing the task.</p>
     #[serde(rename = "StartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Provides the status of the number of files that the task has processed successfully and failed to process.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -403,7 +745,7 @@ pub struct DataRepositoryTask {
     pub task_id: String,
     /// <p>The type of data repository task; EXPORT_TO_REPOSITORY is the only type currently supported.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: DataRepositoryTaskType,
 }
 
 /// <p>Provides information about why a data repository task failed. Only populated when the task <code>Lifecycle</code> is set to <code>FAILED</code>.</p>
@@ -422,13 +764,235 @@ pub struct DataRepositoryTaskFilter {
     /// <p><p>Name of the task property to use in filtering the tasks returned in the response.</p> <ul> <li> <p>Use <code>file-system-id</code> to retrieve data repository tasks for specific file systems.</p> </li> <li> <p>Use <code>task-lifecycle</code> to retrieve data repository tasks with one or more specific lifecycle states, as follows: CANCELED, EXECUTING, FAILED, PENDING, and SUCCEEDED.</p> </li> </ul></p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
+    pub name: Option<DataRepositoryTaskFilterName>,
     /// <p>Use Values to include the specific file system IDs and task lifecycle states for the filters you are using.</p>
     #[serde(rename = "Values")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub values: Option<Vec<String>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DataRepositoryTaskFilterName {
+    /// `file-system-id`
+    FileSystemId,
+    /// `task-lifecycle`
+    TaskLifecycle,
+    /// `data-repository-association-id`
+    DataRepositoryAssociationId,
+    /// `file-cache-id`
+    FileCacheId,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DataRepositoryTaskFilterName {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DataRepositoryTaskFilterName::FileSystemId => "file-system-id",
+            DataRepositoryTaskFilterName::TaskLifecycle => "task-lifecycle",
+            DataRepositoryTaskFilterName::DataRepositoryAssociationId => {
+                "data-repository-association-id"
+            }
+            DataRepositoryTaskFilterName::FileCacheId => "file-cache-id",
+            DataRepositoryTaskFilterName::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DataRepositoryTaskFilterName {
+    fn default() -> DataRepositoryTaskFilterName {
+        DataRepositoryTaskFilterName::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DataRepositoryTaskFilterName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DataRepositoryTaskFilterName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DataRepositoryTaskFilterName {
+    fn from(value: &'a str) -> DataRepositoryTaskFilterName {
+        match value {
+            "file-system-id" => DataRepositoryTaskFilterName::FileSystemId,
+            "task-lifecycle" => DataRepositoryTaskFilterName::TaskLifecycle,
+            "data-repository-association-id" => {
+                DataRepositoryTaskFilterName::DataRepositoryAssociationId
+            }
+            "file-cache-id" => DataRepositoryTaskFilterName::FileCacheId,
+            _ => DataRepositoryTaskFilterName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DataRepositoryTaskFilterName {
+    fn from(value: String) -> DataRepositoryTaskFilterName {
+        match value.as_str() {
+            "file-system-id" => DataRepositoryTaskFilterName::FileSystemId,
+            "task-lifecycle" => DataRepositoryTaskFilterName::TaskLifecycle,
+            "data-repository-association-id" => {
+                DataRepositoryTaskFilterName::DataRepositoryAssociationId
+            }
+            "file-cache-id" => DataRepositoryTaskFilterName::FileCacheId,
+            _ => DataRepositoryTaskFilterName::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DataRepositoryTaskFilterName {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DataRepositoryTaskFilterName, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DataRepositoryTaskFilterName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DataRepositoryTaskFilterName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DataRepositoryTaskFilterName {
+    fn deserialize<D>(deserializer: D) -> Result<DataRepositoryTaskFilterName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DataRepositoryTaskLifecycle {
+    /// `PENDING`
+    Pending,
+    /// `EXECUTING`
+    Executing,
+    /// `FAILED`
+    Failed,
+    /// `SUCCEEDED`
+    Succeeded,
+    /// `CANCELED`
+    Canceled,
+    /// `CANCELING`
+    Canceling,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DataRepositoryTaskLifecycle {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DataRepositoryTaskLifecycle::Pending => "PENDING",
+            DataRepositoryTaskLifecycle::Executing => "EXECUTING",
+            DataRepositoryTaskLifecycle::Failed => "FAILED",
+            DataRepositoryTaskLifecycle::Succeeded => "SUCCEEDED",
+            DataRepositoryTaskLifecycle::Canceled => "CANCELED",
+            DataRepositoryTaskLifecycle::Canceling => "CANCELING",
+            DataRepositoryTaskLifecycle::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DataRepositoryTaskLifecycle {
+    fn default() -> DataRepositoryTaskLifecycle {
+        DataRepositoryTaskLifecycle::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DataRepositoryTaskLifecycle {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DataRepositoryTaskLifecycle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DataRepositoryTaskLifecycle {
+    fn from(value: &'a str) -> DataRepositoryTaskLifecycle {
+        match value {
+            "PENDING" => DataRepositoryTaskLifecycle::Pending,
+            "EXECUTING" => DataRepositoryTaskLifecycle::Executing,
+            "FAILED" => DataRepositoryTaskLifecycle::Failed,
+            "SUCCEEDED" => DataRepositoryTaskLifecycle::Succeeded,
+            "CANCELED" => DataRepositoryTaskLifecycle::Canceled,
+            "CANCELING" => DataRepositoryTaskLifecycle::Canceling,
+            _ => DataRepositoryTaskLifecycle::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DataRepositoryTaskLifecycle {
+    fn from(value: String) -> DataRepositoryTaskLifecycle {
+        match value.as_str() {
+            "PENDING" => DataRepositoryTaskLifecycle::Pending,
+            "EXECUTING" => DataRepositoryTaskLifecycle::Executing,
+            "FAILED" => DataRepositoryTaskLifecycle::Failed,
+            "SUCCEEDED" => DataRepositoryTaskLifecycle::Succeeded,
+            "CANCELED" => DataRepositoryTaskLifecycle::Canceled,
+            "CANCELING" => DataRepositoryTaskLifecycle::Canceling,
+            _ => DataRepositoryTaskLifecycle::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DataRepositoryTaskLifecycle {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DataRepositoryTaskLifecycle, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DataRepositoryTaskLifecycle {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DataRepositoryTaskLifecycle {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DataRepositoryTaskLifecycle {
+    fn deserialize<D>(deserializer: D) -> Result<DataRepositoryTaskLifecycle, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Provides the task status showing a running total of the total number of files to be processed, the number successfully processed, and the number of files the task failed to process.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -439,8 +1003,13 @@ pub struct DataRepositoryTaskStatus {
     pub failed_count: Option<i64>,
     /// <p>The time at which the task status was last updated.</p>
     #[serde(rename = "LastUpdatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_time: Option<f64>,
+    pub last_updated_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A running total of the number of files that the task has successfully processed.</p>
     #[serde(rename = "SucceededCount")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -451,6 +1020,115 @@ pub struct DataRepositoryTaskStatus {
     pub total_count: Option<i64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DataRepositoryTaskType {
+    /// `EXPORT_TO_REPOSITORY`
+    ExportToRepository,
+    /// `IMPORT_METADATA_FROM_REPOSITORY`
+    ImportMetadataFromRepository,
+    /// `RELEASE_DATA_FROM_FILESYSTEM`
+    ReleaseDataFromFilesystem,
+    /// `AUTO_RELEASE_DATA`
+    AutoReleaseData,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DataRepositoryTaskType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DataRepositoryTaskType::ExportToRepository => "EXPORT_TO_REPOSITORY",
+            DataRepositoryTaskType::ImportMetadataFromRepository => {
+                "IMPORT_METADATA_FROM_REPOSITORY"
+            }
+            DataRepositoryTaskType::ReleaseDataFromFilesystem => "RELEASE_DATA_FROM_FILESYSTEM",
+            DataRepositoryTaskType::AutoReleaseData => "AUTO_RELEASE_DATA",
+            DataRepositoryTaskType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DataRepositoryTaskType {
+    fn default() -> DataRepositoryTaskType {
+        DataRepositoryTaskType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DataRepositoryTaskType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DataRepositoryTaskType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DataRepositoryTaskType {
+    fn from(value: &'a str) -> DataRepositoryTaskType {
+        match value {
+            "EXPORT_TO_REPOSITORY" => DataRepositoryTaskType::ExportToRepository,
+            "IMPORT_METADATA_FROM_REPOSITORY" => {
+                DataRepositoryTaskType::ImportMetadataFromRepository
+            }
+            "RELEASE_DATA_FROM_FILESYSTEM" => DataRepositoryTaskType::ReleaseDataFromFilesystem,
+            "AUTO_RELEASE_DATA" => DataRepositoryTaskType::AutoReleaseData,
+            _ => DataRepositoryTaskType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DataRepositoryTaskType {
+    fn from(value: String) -> DataRepositoryTaskType {
+        match value.as_str() {
+            "EXPORT_TO_REPOSITORY" => DataRepositoryTaskType::ExportToRepository,
+            "IMPORT_METADATA_FROM_REPOSITORY" => {
+                DataRepositoryTaskType::ImportMetadataFromRepository
+            }
+            "RELEASE_DATA_FROM_FILESYSTEM" => DataRepositoryTaskType::ReleaseDataFromFilesystem,
+            "AUTO_RELEASE_DATA" => DataRepositoryTaskType::AutoReleaseData,
+            _ => DataRepositoryTaskType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DataRepositoryTaskType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DataRepositoryTaskType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DataRepositoryTaskType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DataRepositoryTaskType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DataRepositoryTaskType {
+    fn deserialize<D>(deserializer: D) -> Result<DataRepositoryTaskType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The request object for <code>DeleteBackup</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -475,7 +1153,7 @@ pub struct DeleteBackupResponse {
     /// <p>The lifecycle of the backup. Should be <code>DELETED</code>.</p>
     #[serde(rename = "Lifecycle")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub lifecycle: Option<String>,
+    pub lifecycle: Option<BackupLifecycle>,
 }
 
 /// <p>The request object for <code>DeleteFileSystem</code> operation.</p>
@@ -505,7 +1183,7 @@ pub struct DeleteFileSystemResponse {
     /// <p>The file system lifecycle for the deletion request. Should be <code>DELETING</code>.</p>
     #[serde(rename = "Lifecycle")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub lifecycle: Option<String>,
+    pub lifecycle: Option<FileSystemLifecycle>,
     #[serde(rename = "WindowsResponse")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub windows_response: Option<DeleteFileSystemWindowsResponse>,
@@ -644,8 +1322,13 @@ pub struct DescribeFileSystemsResponse {
 pub struct FileSystem {
     /// <p>The time that the file system was created, in seconds (since 1970-01-01T00:00:00Z), also known as Unix time.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The DNS name for the file system.</p>
     #[serde(rename = "DNSName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -660,7 +1343,7 @@ pub struct FileSystem {
     /// <p>The type of Amazon FSx file system, either <code>LUSTRE</code> or <code>WINDOWS</code>.</p>
     #[serde(rename = "FileSystemType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub file_system_type: Option<String>,
+    pub file_system_type: Option<FileSystemType>,
     /// <p>The ID of the AWS Key Management Service (AWS KMS) key used to encrypt the file system's data for an Amazon FSx for Windows File Server file system. Amazon FSx for Lustre does not support KMS encryption. </p>
     #[serde(rename = "KmsKeyId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -668,7 +1351,7 @@ pub struct FileSystem {
     /// <p><p>The lifecycle status of the file system, following are the possible values and what they mean:</p> <ul> <li> <p> <code>AVAILABLE</code> - The file system is in a healthy state, and is reachable and available for use.</p> </li> <li> <p> <code>CREATING</code> - Amazon FSx is creating the new file system.</p> </li> <li> <p> <code>DELETING</code> - Amazon FSx is deleting an existing file system.</p> </li> <li> <p> <code>FAILED</code> - An existing file system has experienced an unrecoverable failure. When creating a new file system, Amazon FSx was unable to create the file system.</p> </li> <li> <p> <code>MISCONFIGURED</code> indicates that the file system is in a failed but recoverable state.</p> </li> <li> <p> <code>UPDATING</code> indicates that the file system is undergoing a customer initiated update.</p> </li> </ul></p>
     #[serde(rename = "Lifecycle")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub lifecycle: Option<String>,
+    pub lifecycle: Option<FileSystemLifecycle>,
     #[serde(rename = "LustreConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub lustre_configuration: Option<LustreFileSystemConfiguration>,
@@ -706,16 +1389,333 @@ pub struct FileSystem {
     pub windows_configuration: Option<WindowsFileSystemConfiguration>,
 }
 
-/// <p>A structure providing details of any failures that occur when creating the file system has failed.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct FileSystemFailureDetails {
-    /// <p>A message describing any failures that occurred during file system creation.</p>
-    #[serde(rename = "Message")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub message: Option<String>,
+/// <p>A structure providing details of any failures that occur when creating the file system has failed.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct FileSystemFailureDetails {
+    /// <p>A message describing any failures that occurred during file system creation.</p>
+    #[serde(rename = "Message")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// <p>The lifecycle status of the file system.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FileSystemLifecycle {
+    /// `AVAILABLE`
+    Available,
+    /// `CREATING`
+    Creating,
+    /// `FAILED`
+    Failed,
+    /// `DELETING`
+    Deleting,
+    /// `MISCONFIGURED`
+    Misconfigured,
+    /// `UPDATING`
+    Updating,
+    /// `MISCONFIGURED_UNAVAILABLE`
+    MisconfiguredUnavailable,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl FileSystemLifecycle {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FileSystemLifecycle::Available => "AVAILABLE",
+            FileSystemLifecycle::Creating => "CREATING",
+            FileSystemLifecycle::Failed => "FAILED",
+            FileSystemLifecycle::Deleting => "DELETING",
+            FileSystemLifecycle::Misconfigured => "MISCONFIGURED",
+            FileSystemLifecycle::Updating => "UPDATING",
+            FileSystemLifecycle::MisconfiguredUnavailable => "MISCONFIGURED_UNAVAILABLE",
+            FileSystemLifecycle::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for FileSystemLifecycle {
+    fn default() -> FileSystemLifecycle {
+        FileSystemLifecycle::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for FileSystemLifecycle {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FileSystemLifecycle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FileSystemLifecycle {
+    fn from(value: &'a str) -> FileSystemLifecycle {
+        match value {
+            "AVAILABLE" => FileSystemLifecycle::Available,
+            "CREATING" => FileSystemLifecycle::Creating,
+            "FAILED" => FileSystemLifecycle::Failed,
+            "DELETING" => FileSystemLifecycle::Deleting,
+            "MISCONFIGURED" => FileSystemLifecycle::Misconfigured,
+            "UPDATING" => FileSystemLifecycle::Updating,
+            "MISCONFIGURED_UNAVAILABLE" => FileSystemLifecycle::MisconfiguredUnavailable,
+            _ => FileSystemLifecycle::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FileSystemLifecycle {
+    fn from(value: String) -> FileSystemLifecycle {
+        match value.as_str() {
+            "AVAILABLE" => FileSystemLifecycle::Available,
+            "CREATING" => FileSystemLifecycle::Creating,
+            "FAILED" => FileSystemLifecycle::Failed,
+            "DELETING" => FileSystemLifecycle::Deleting,
+            "MISCONFIGURED" => FileSystemLifecycle::Misconfigured,
+            "UPDATING" => FileSystemLifecycle::Updating,
+            "MISCONFIGURED_UNAVAILABLE" => FileSystemLifecycle::MisconfiguredUnavailable,
+            _ => FileSystemLifecycle::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for FileSystemLifecycle {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<FileSystemLifecycle, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FileSystemLifecycle {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FileSystemLifecycle {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FileSystemLifecycle {
+    fn deserialize<D>(deserializer: D) -> Result<FileSystemLifecycle, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>An enumeration specifying the currently ongoing maintenance operation.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FileSystemMaintenanceOperation {
+    /// `PATCHING`
+    Patching,
+    /// `BACKING_UP`
+    BackingUp,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl FileSystemMaintenanceOperation {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FileSystemMaintenanceOperation::Patching => "PATCHING",
+            FileSystemMaintenanceOperation::BackingUp => "BACKING_UP",
+            FileSystemMaintenanceOperation::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for FileSystemMaintenanceOperation {
+    fn default() -> FileSystemMaintenanceOperation {
+        FileSystemMaintenanceOperation::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for FileSystemMaintenanceOperation {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FileSystemMaintenanceOperation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FileSystemMaintenanceOperation {
+    fn from(value: &'a str) -> FileSystemMaintenanceOperation {
+        match value {
+            "PATCHING" => FileSystemMaintenanceOperation::Patching,
+            "BACKING_UP" => FileSystemMaintenanceOperation::BackingUp,
+            _ => FileSystemMaintenanceOperation::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FileSystemMaintenanceOperation {
+    fn from(value: String) -> FileSystemMaintenanceOperation {
+        match value.as_str() {
+            "PATCHING" => FileSystemMaintenanceOperation::Patching,
+            "BACKING_UP" => FileSystemMaintenanceOperation::BackingUp,
+            _ => FileSystemMaintenanceOperation::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for FileSystemMaintenanceOperation {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<FileSystemMaintenanceOperation, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FileSystemMaintenanceOperation {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FileSystemMaintenanceOperation {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FileSystemMaintenanceOperation {
+    fn deserialize<D>(deserializer: D) -> Result<FileSystemMaintenanceOperation, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>The type of file system.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FileSystemType {
+    /// `WINDOWS`
+    Windows,
+    /// `LUSTRE`
+    Lustre,
+    /// `ONTAP`
+    Ontap,
+    /// `OPENZFS`
+    Openzfs,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl FileSystemType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FileSystemType::Windows => "WINDOWS",
+            FileSystemType::Lustre => "LUSTRE",
+            FileSystemType::Ontap => "ONTAP",
+            FileSystemType::Openzfs => "OPENZFS",
+            FileSystemType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for FileSystemType {
+    fn default() -> FileSystemType {
+        FileSystemType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for FileSystemType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FileSystemType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FileSystemType {
+    fn from(value: &'a str) -> FileSystemType {
+        match value {
+            "WINDOWS" => FileSystemType::Windows,
+            "LUSTRE" => FileSystemType::Lustre,
+            "ONTAP" => FileSystemType::Ontap,
+            "OPENZFS" => FileSystemType::Openzfs,
+            _ => FileSystemType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FileSystemType {
+    fn from(value: String) -> FileSystemType {
+        match value.as_str() {
+            "WINDOWS" => FileSystemType::Windows,
+            "LUSTRE" => FileSystemType::Lustre,
+            "ONTAP" => FileSystemType::Ontap,
+            "OPENZFS" => FileSystemType::Openzfs,
+            _ => FileSystemType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for FileSystemType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<FileSystemType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FileSystemType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FileSystemType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for FileSystemType {
+    fn deserialize<D>(deserializer: D) -> Result<FileSystemType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A filter used to restrict the results of describe calls. You can use multiple filters to return results that meet all applied filter requirements.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -723,13 +1723,132 @@ pub struct Filter {
     /// <p>The name for this filter.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
+    pub name: Option<FilterName>,
     /// <p>The values of the filter. These are all the values for any of the applied filters.</p>
     #[serde(rename = "Values")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub values: Option<Vec<String>>,
 }
 
+/// <p>The name for a filter.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FilterName {
+    /// `file-system-id`
+    FileSystemId,
+    /// `backup-type`
+    BackupType,
+    /// `file-system-type`
+    FileSystemType,
+    /// `volume-id`
+    VolumeId,
+    /// `data-repository-type`
+    DataRepositoryType,
+    /// `file-cache-id`
+    FileCacheId,
+    /// `file-cache-type`
+    FileCacheType,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl FilterName {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FilterName::FileSystemId => "file-system-id",
+            FilterName::BackupType => "backup-type",
+            FilterName::FileSystemType => "file-system-type",
+            FilterName::VolumeId => "volume-id",
+            FilterName::DataRepositoryType => "data-repository-type",
+            FilterName::FileCacheId => "file-cache-id",
+            FilterName::FileCacheType => "file-cache-type",
+            FilterName::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for FilterName {
+    fn default() -> FilterName {
+        FilterName::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for FilterName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FilterName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FilterName {
+    fn from(value: &'a str) -> FilterName {
+        match value {
+            "file-system-id" => FilterName::FileSystemId,
+            "backup-type" => FilterName::BackupType,
+            "file-system-type" => FilterName::FileSystemType,
+            "volume-id" => FilterName::VolumeId,
+            "data-repository-type" => FilterName::DataRepositoryType,
+            "file-cache-id" => FilterName::FileCacheId,
+            "file-cache-type" => FilterName::FileCacheType,
+            _ => FilterName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FilterName {
+    fn from(value: String) -> FilterName {
+        match value.as_str() {
+            "file-system-id" => FilterName::FileSystemId,
+            "backup-type" => FilterName::BackupType,
+            "file-system-type" => FilterName::FileSystemType,
+            "volume-id" => FilterName::VolumeId,
+            "data-repository-type" => FilterName::DataRepositoryType,
+            "file-cache-id" => FilterName::FileCacheId,
+            "file-cache-type" => FilterName::FileCacheType,
+            _ => FilterName::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for FilterName {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<FilterName, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FilterName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FilterName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FilterName {
+    fn deserialize<D>(deserializer: D) -> Result<FilterName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The request object for <code>ListTagsForResource</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -774,6 +1893,182 @@ pub struct LustreFileSystemConfiguration {
     pub weekly_maintenance_start_time: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ReportFormat {
+    /// `REPORT_CSV_20191124`
+    ReportCsv20191124,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ReportFormat {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReportFormat::ReportCsv20191124 => "REPORT_CSV_20191124",
+            ReportFormat::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ReportFormat {
+    fn default() -> ReportFormat {
+        ReportFormat::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ReportFormat {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ReportFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ReportFormat {
+    fn from(value: &'a str) -> ReportFormat {
+        match value {
+            "REPORT_CSV_20191124" => ReportFormat::ReportCsv20191124,
+            _ => ReportFormat::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReportFormat {
+    fn from(value: String) -> ReportFormat {
+        match value.as_str() {
+            "REPORT_CSV_20191124" => ReportFormat::ReportCsv20191124,
+            _ => ReportFormat::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ReportFormat {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ReportFormat, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ReportFormat {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ReportFormat {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ReportFormat {
+    fn deserialize<D>(deserializer: D) -> Result<ReportFormat, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ReportScope {
+    /// `FAILED_FILES_ONLY`
+    FailedFilesOnly,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ReportScope {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReportScope::FailedFilesOnly => "FAILED_FILES_ONLY",
+            ReportScope::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ReportScope {
+    fn default() -> ReportScope {
+        ReportScope::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ReportScope {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ReportScope {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ReportScope {
+    fn from(value: &'a str) -> ReportScope {
+        match value {
+            "FAILED_FILES_ONLY" => ReportScope::FailedFilesOnly,
+            _ => ReportScope::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReportScope {
+    fn from(value: String) -> ReportScope {
+        match value.as_str() {
+            "FAILED_FILES_ONLY" => ReportScope::FailedFilesOnly,
+            _ => ReportScope::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ReportScope {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ReportScope, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ReportScope {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ReportScope {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ReportScope {
+    fn deserialize<D>(deserializer: D) -> Result<ReportScope, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The configuration of the self-managed Microsoft Active Directory (AD) directory to which the Windows File Server instance is joined.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -844,6 +2139,146 @@ pub struct SelfManagedActiveDirectoryConfigurationUpdates {
     pub user_name: Option<String>,
 }
 
+/// <p>The types of limits on your service utilization. Limits include file system count, total throughput capacity, total storage, and total user-initiated backups. These limits apply for a specific account in a specific Amazon Web Services Region. You can increase some of them by contacting Amazon Web Services Support.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ServiceLimit {
+    /// `FILE_SYSTEM_COUNT`
+    FileSystemCount,
+    /// `TOTAL_THROUGHPUT_CAPACITY`
+    TotalThroughputCapacity,
+    /// `TOTAL_STORAGE`
+    TotalStorage,
+    /// `TOTAL_USER_INITIATED_BACKUPS`
+    TotalUserInitiatedBackups,
+    /// `TOTAL_USER_TAGS`
+    TotalUserTags,
+    /// `TOTAL_IN_PROGRESS_COPY_BACKUPS`
+    TotalInProgressCopyBackups,
+    /// `STORAGE_VIRTUAL_MACHINES_PER_FILE_SYSTEM`
+    StorageVirtualMachinesPerFileSystem,
+    /// `VOLUMES_PER_FILE_SYSTEM`
+    VolumesPerFileSystem,
+    /// `TOTAL_SSD_IOPS`
+    TotalSsdIops,
+    /// `FILE_CACHE_COUNT`
+    FileCacheCount,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ServiceLimit {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ServiceLimit::FileSystemCount => "FILE_SYSTEM_COUNT",
+            ServiceLimit::TotalThroughputCapacity => "TOTAL_THROUGHPUT_CAPACITY",
+            ServiceLimit::TotalStorage => "TOTAL_STORAGE",
+            ServiceLimit::TotalUserInitiatedBackups => "TOTAL_USER_INITIATED_BACKUPS",
+            ServiceLimit::TotalUserTags => "TOTAL_USER_TAGS",
+            ServiceLimit::TotalInProgressCopyBackups => "TOTAL_IN_PROGRESS_COPY_BACKUPS",
+            ServiceLimit::StorageVirtualMachinesPerFileSystem => {
+                "STORAGE_VIRTUAL_MACHINES_PER_FILE_SYSTEM"
+            }
+            ServiceLimit::VolumesPerFileSystem => "VOLUMES_PER_FILE_SYSTEM",
+            ServiceLimit::TotalSsdIops => "TOTAL_SSD_IOPS",
+            ServiceLimit::FileCacheCount => "FILE_CACHE_COUNT",
+            ServiceLimit::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ServiceLimit {
+    fn default() -> ServiceLimit {
+        ServiceLimit::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ServiceLimit {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ServiceLimit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ServiceLimit {
+    fn from(value: &'a str) -> ServiceLimit {
+        match value {
+            "FILE_SYSTEM_COUNT" => ServiceLimit::FileSystemCount,
+            "TOTAL_THROUGHPUT_CAPACITY" => ServiceLimit::TotalThroughputCapacity,
+            "TOTAL_STORAGE" => ServiceLimit::TotalStorage,
+            "TOTAL_USER_INITIATED_BACKUPS" => ServiceLimit::TotalUserInitiatedBackups,
+            "TOTAL_USER_TAGS" => ServiceLimit::TotalUserTags,
+            "TOTAL_IN_PROGRESS_COPY_BACKUPS" => ServiceLimit::TotalInProgressCopyBackups,
+            "STORAGE_VIRTUAL_MACHINES_PER_FILE_SYSTEM" => {
+                ServiceLimit::StorageVirtualMachinesPerFileSystem
+            }
+            "VOLUMES_PER_FILE_SYSTEM" => ServiceLimit::VolumesPerFileSystem,
+            "TOTAL_SSD_IOPS" => ServiceLimit::TotalSsdIops,
+            "FILE_CACHE_COUNT" => ServiceLimit::FileCacheCount,
+            _ => ServiceLimit::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ServiceLimit {
+    fn from(value: String) -> ServiceLimit {
+        match value.as_str() {
+            "FILE_SYSTEM_COUNT" => ServiceLimit::FileSystemCount,
+            "TOTAL_THROUGHPUT_CAPACITY" => ServiceLimit::TotalThroughputCapacity,
+            "TOTAL_STORAGE" => ServiceLimit::TotalStorage,
+            "TOTAL_USER_INITIATED_BACKUPS" => ServiceLimit::TotalUserInitiatedBackups,
+            "TOTAL_USER_TAGS" => ServiceLimit::TotalUserTags,
+            "TOTAL_IN_PROGRESS_COPY_BACKUPS" => ServiceLimit::TotalInProgressCopyBackups,
+            "STORAGE_VIRTUAL_MACHINES_PER_FILE_SYSTEM" => {
+                ServiceLimit::StorageVirtualMachinesPerFileSystem
+            }
+            "VOLUMES_PER_FILE_SYSTEM" => ServiceLimit::VolumesPerFileSystem,
+            "TOTAL_SSD_IOPS" => ServiceLimit::TotalSsdIops,
+            "FILE_CACHE_COUNT" => ServiceLimit::FileCacheCount,
+            _ => ServiceLimit::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ServiceLimit {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ServiceLimit, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ServiceLimit {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ServiceLimit {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ServiceLimit {
+    fn deserialize<D>(deserializer: D) -> Result<ServiceLimit, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Specifies a key-value pair for a resource tag.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Tag {
@@ -953,6 +2388,104 @@ pub struct UpdateFileSystemWindowsConfiguration {
     pub weekly_maintenance_start_time: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum WindowsDeploymentType {
+    /// `MULTI_AZ_1`
+    MultiAz1,
+    /// `SINGLE_AZ_1`
+    SingleAz1,
+    /// `SINGLE_AZ_2`
+    SingleAz2,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl WindowsDeploymentType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            WindowsDeploymentType::MultiAz1 => "MULTI_AZ_1",
+            WindowsDeploymentType::SingleAz1 => "SINGLE_AZ_1",
+            WindowsDeploymentType::SingleAz2 => "SINGLE_AZ_2",
+            WindowsDeploymentType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for WindowsDeploymentType {
+    fn default() -> WindowsDeploymentType {
+        WindowsDeploymentType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for WindowsDeploymentType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for WindowsDeploymentType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for WindowsDeploymentType {
+    fn from(value: &'a str) -> WindowsDeploymentType {
+        match value {
+            "MULTI_AZ_1" => WindowsDeploymentType::MultiAz1,
+            "SINGLE_AZ_1" => WindowsDeploymentType::SingleAz1,
+            "SINGLE_AZ_2" => WindowsDeploymentType::SingleAz2,
+            _ => WindowsDeploymentType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for WindowsDeploymentType {
+    fn from(value: String) -> WindowsDeploymentType {
+        match value.as_str() {
+            "MULTI_AZ_1" => WindowsDeploymentType::MultiAz1,
+            "SINGLE_AZ_1" => WindowsDeploymentType::SingleAz1,
+            "SINGLE_AZ_2" => WindowsDeploymentType::SingleAz2,
+            _ => WindowsDeploymentType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for WindowsDeploymentType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<WindowsDeploymentType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for WindowsDeploymentType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for WindowsDeploymentType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for WindowsDeploymentType {
+    fn deserialize<D>(deserializer: D) -> Result<WindowsDeploymentType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The configuration for this Microsoft Windows file system.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -976,11 +2509,11 @@ pub struct WindowsFileSystemConfiguration {
     /// <p><p>Specifies the file system deployment type, valid values are the following:</p> <ul> <li> <p> <code>MULTI<em>AZ</em>1</code> - Specifies a high availability file system that is configured for Multi-AZ redundancy to tolerate temporary Availability Zone (AZ) unavailability.</p> </li> <li> <p> <code>SINGLE<em>AZ</em>1</code> - (Default) Specifies a file system that is configured for single AZ redundancy.</p> </li> </ul></p>
     #[serde(rename = "DeploymentType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deployment_type: Option<String>,
+    pub deployment_type: Option<WindowsDeploymentType>,
     /// <p>The list of maintenance operations in progress for this file system.</p>
     #[serde(rename = "MaintenanceOperationsInProgress")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub maintenance_operations_in_progress: Option<Vec<String>>,
+    pub maintenance_operations_in_progress: Option<Vec<FileSystemMaintenanceOperation>>,
     /// <p>For <code>MULTI_AZ_1</code> deployment types, the IP address of the primary, or preferred, file server.</p> <p>Use this IP address when mounting the file system on Linux SMB clients or Windows SMB clients that are not joined to a Microsoft Active Directory. Applicable for both <code>SINGLE_AZ_1</code> and <code>MULTI_AZ_1</code> deployment types. This IP address is temporarily unavailable when the file system is undergoing maintenance. For Linux and Windows SMB clients that are joined to an Active Directory, use the file system's DNSName instead. For more information and instruction on mapping and mounting file shares, see <a href="https://docs.aws.amazon.com/fsx/latest/WindowsGuide/accessing-file-shares.html">https://docs.aws.amazon.com/fsx/latest/WindowsGuide/accessing-file-shares.html</a>.</p>
     #[serde(rename = "PreferredFileServerIp")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2045,11 +3578,12 @@ pub struct FsxClient {
 impl FsxClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> FsxClient {
         FsxClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "FSx"),
         }
     }
 
@@ -2081,6 +3615,7 @@ impl Fsx for FsxClient {
         input: CancelDataRepositoryTaskRequest,
     ) -> Result<CancelDataRepositoryTaskResponse, RusotoError<CancelDataRepositoryTaskError>> {
         let mut request = SignedRequest::new("POST", "fsx", &self.region, "/");
+        request.set_operation("CancelDataRepositoryTask");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2112,6 +3647,7 @@ impl Fsx for FsxClient {
         input: CreateBackupRequest,
     ) -> Result<CreateBackupResponse, RusotoError<CreateBackupError>> {
         let mut request = SignedRequest::new("POST", "fsx", &self.region, "/");
+        request.set_operation("CreateBackup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSSimbaAPIService_v20180301.CreateBackup");
@@ -2139,6 +3675,7 @@ impl Fsx for FsxClient {
         input: CreateDataRepositoryTaskRequest,
     ) -> Result<CreateDataRepositoryTaskResponse, RusotoError<CreateDataRepositoryTaskError>> {
         let mut request = SignedRequest::new("POST", "fsx", &self.region, "/");
+        request.set_operation("CreateDataRepositoryTask");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2170,6 +3707,7 @@ impl Fsx for FsxClient {
         input: CreateFileSystemRequest,
     ) -> Result<CreateFileSystemResponse, RusotoError<CreateFileSystemError>> {
         let mut request = SignedRequest::new("POST", "fsx", &self.region, "/");
+        request.set_operation("CreateFileSystem");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2202,6 +3740,7 @@ impl Fsx for FsxClient {
     ) -> Result<CreateFileSystemFromBackupResponse, RusotoError<CreateFileSystemFromBackupError>>
     {
         let mut request = SignedRequest::new("POST", "fsx", &self.region, "/");
+        request.set_operation("CreateFileSystemFromBackup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2233,6 +3772,7 @@ impl Fsx for FsxClient {
         input: DeleteBackupRequest,
     ) -> Result<DeleteBackupResponse, RusotoError<DeleteBackupError>> {
         let mut request = SignedRequest::new("POST", "fsx", &self.region, "/");
+        request.set_operation("DeleteBackup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSSimbaAPIService_v20180301.DeleteBackup");
@@ -2260,6 +3800,7 @@ impl Fsx for FsxClient {
         input: DeleteFileSystemRequest,
     ) -> Result<DeleteFileSystemResponse, RusotoError<DeleteFileSystemError>> {
         let mut request = SignedRequest::new("POST", "fsx", &self.region, "/");
+        request.set_operation("DeleteFileSystem");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2291,6 +3832,7 @@ impl Fsx for FsxClient {
         input: DescribeBackupsRequest,
     ) -> Result<DescribeBackupsResponse, RusotoError<DescribeBackupsError>> {
         let mut request = SignedRequest::new("POST", "fsx", &self.region, "/");
+        request.set_operation("DescribeBackups");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2322,6 +3864,7 @@ impl Fsx for FsxClient {
     ) -> Result<DescribeDataRepositoryTasksResponse, RusotoError<DescribeDataRepositoryTasksError>>
     {
         let mut request = SignedRequest::new("POST", "fsx", &self.region, "/");
+        request.set_operation("DescribeDataRepositoryTasks");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2353,6 +3896,7 @@ impl Fsx for FsxClient {
         input: DescribeFileSystemsRequest,
     ) -> Result<DescribeFileSystemsResponse, RusotoError<DescribeFileSystemsError>> {
         let mut request = SignedRequest::new("POST", "fsx", &self.region, "/");
+        request.set_operation("DescribeFileSystems");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2384,6 +3928,7 @@ impl Fsx for FsxClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = SignedRequest::new("POST", "fsx", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2415,6 +3960,7 @@ impl Fsx for FsxClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = SignedRequest::new("POST", "fsx", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSSimbaAPIService_v20180301.TagResource");
@@ -2442,6 +3988,7 @@ impl Fsx for FsxClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = SignedRequest::new("POST", "fsx", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSSimbaAPIService_v20180301.UntagResource");
@@ -2469,6 +4016,7 @@ impl Fsx for FsxClient {
         input: UpdateFileSystemRequest,
     ) -> Result<UpdateFileSystemResponse, RusotoError<UpdateFileSystemError>> {
         let mut request = SignedRequest::new("POST", "fsx", &self.region, "/");
+        request.set_operation("UpdateFileSystem");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2494,3 +4042,99 @@ impl Fsx for FsxClient {
         }
     }
 }
+impl FsxClient {
+    /// Auto-paginating version of `describe_backups`, streaming every page of results.
+    pub fn describe_backups_pages(
+        &self,
+        input: DescribeBackupsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeBackupsResponse, DescribeBackupsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: FsxClient, input: DescribeBackupsRequest| async move {
+                client.describe_backups(input).await
+            },
+            |input: &DescribeBackupsRequest, output: &DescribeBackupsResponse| {
+                Some(DescribeBackupsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_backups`, streaming the `Backups` of every page of results.
+    pub fn describe_backups_items(
+        &self,
+        input: DescribeBackupsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Backup, DescribeBackupsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_backups_pages(input),
+            |output: DescribeBackupsResponse| output.backups.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_file_systems`, streaming every page of results.
+    pub fn describe_file_systems_pages(
+        &self,
+        input: DescribeFileSystemsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeFileSystemsResponse, DescribeFileSystemsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: FsxClient, input: DescribeFileSystemsRequest| async move {
+                client.describe_file_systems(input).await
+            },
+            |input: &DescribeFileSystemsRequest, output: &DescribeFileSystemsResponse| {
+                Some(DescribeFileSystemsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_file_systems`, streaming the `FileSystems` of every page of results.
+    pub fn describe_file_systems_items(
+        &self,
+        input: DescribeFileSystemsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<FileSystem, DescribeFileSystemsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_file_systems_pages(input),
+            |output: DescribeFileSystemsResponse| output.file_systems.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_tags_for_resource`, streaming every page of results.
+    pub fn list_tags_for_resource_pages(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListTagsForResourceResponse, ListTagsForResourceError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: FsxClient, input: ListTagsForResourceRequest| async move {
+                client.list_tags_for_resource(input).await
+            },
+            |input: &ListTagsForResourceRequest, output: &ListTagsForResourceResponse| {
+                Some(ListTagsForResourceRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_tags_for_resource`, streaming the `Tags` of every page of results.
+    pub fn list_tags_for_resource_items(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Tag, ListTagsForResourceError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_tags_for_resource_pages(input),
+            |output: ListTagsForResourceResponse| output.tags.unwrap_or_default(),
+        )
+    }
+}
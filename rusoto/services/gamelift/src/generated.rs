@@ -30,7 +30,7 @@ use serde_json;
 pub struct AcceptMatchInput {
     /// <p>Player response to the proposed match.</p>
     #[serde(rename = "AcceptanceType")]
-    pub acceptance_type: String,
+    pub acceptance_type: AcceptanceType,
     /// <p>A unique identifier for a player delivering the response. This parameter can include one or multiple player IDs.</p>
     #[serde(rename = "PlayerIds")]
     pub player_ids: Vec<String>,
@@ -43,6 +43,99 @@ pub struct AcceptMatchInput {
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AcceptMatchOutput {}
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AcceptanceType {
+    /// `ACCEPT`
+    Accept,
+    /// `REJECT`
+    Reject,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AcceptanceType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AcceptanceType::Accept => "ACCEPT",
+            AcceptanceType::Reject => "REJECT",
+            AcceptanceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AcceptanceType {
+    fn default() -> AcceptanceType {
+        AcceptanceType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AcceptanceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AcceptanceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AcceptanceType {
+    fn from(value: &'a str) -> AcceptanceType {
+        match value {
+            "ACCEPT" => AcceptanceType::Accept,
+            "REJECT" => AcceptanceType::Reject,
+            _ => AcceptanceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AcceptanceType {
+    fn from(value: String) -> AcceptanceType {
+        match value.as_str() {
+            "ACCEPT" => AcceptanceType::Accept,
+            "REJECT" => AcceptanceType::Reject,
+            _ => AcceptanceType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AcceptanceType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AcceptanceType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AcceptanceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AcceptanceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AcceptanceType {
+    fn deserialize<D>(deserializer: D) -> Result<AcceptanceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p><p>Properties that describe an alias resource.</p> <ul> <li> <p> <a>CreateAlias</a> </p> </li> <li> <p> <a>ListAliases</a> </p> </li> <li> <p> <a>DescribeAlias</a> </p> </li> <li> <p> <a>UpdateAlias</a> </p> </li> <li> <p> <a>DeleteAlias</a> </p> </li> <li> <p> <a>ResolveAlias</a> </p> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -57,16 +150,26 @@ pub struct Alias {
     pub alias_id: Option<String>,
     /// <p>A time stamp indicating when this data object was created. Format is a number expressed in Unix time as milliseconds (for example "1469498468.057").</p>
     #[serde(rename = "CreationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A human-readable description of an alias.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     /// <p>The time that this data object was last modified. Format is a number expressed in Unix time as milliseconds (for example "1469498468.057").</p>
     #[serde(rename = "LastUpdatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_time: Option<f64>,
+    pub last_updated_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A descriptive label that is associated with an alias. Alias names do not need to be unique.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -116,6 +219,99 @@ pub struct AwsCredentials {
     pub session_token: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BackfillMode {
+    /// `AUTOMATIC`
+    Automatic,
+    /// `MANUAL`
+    Manual,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl BackfillMode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BackfillMode::Automatic => "AUTOMATIC",
+            BackfillMode::Manual => "MANUAL",
+            BackfillMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for BackfillMode {
+    fn default() -> BackfillMode {
+        BackfillMode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for BackfillMode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for BackfillMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for BackfillMode {
+    fn from(value: &'a str) -> BackfillMode {
+        match value {
+            "AUTOMATIC" => BackfillMode::Automatic,
+            "MANUAL" => BackfillMode::Manual,
+            _ => BackfillMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BackfillMode {
+    fn from(value: String) -> BackfillMode {
+        match value.as_str() {
+            "AUTOMATIC" => BackfillMode::Automatic,
+            "MANUAL" => BackfillMode::Manual,
+            _ => BackfillMode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for BackfillMode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<BackfillMode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for BackfillMode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for BackfillMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BackfillMode {
+    fn deserialize<D>(deserializer: D) -> Result<BackfillMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p><p>Properties describing a custom game build.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateBuild</a> </p> </li> <li> <p> <a>ListBuilds</a> </p> </li> <li> <p> <a>DescribeBuild</a> </p> </li> <li> <p> <a>UpdateBuild</a> </p> </li> <li> <p> <a>DeleteBuild</a> </p> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -130,8 +326,13 @@ pub struct Build {
     pub build_id: Option<String>,
     /// <p>Time stamp indicating when this data object was created. Format is a number expressed in Unix time as milliseconds (for example "1469498468.057").</p>
     #[serde(rename = "CreationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A descriptive label that is associated with a build. Build names do not need to be unique. It can be set using <a>CreateBuild</a> or <a>UpdateBuild</a>.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -139,7 +340,7 @@ pub struct Build {
     /// <p>Operating system that the game server binaries are built to run on. This value determines the type of fleet resources that you can use for this build.</p>
     #[serde(rename = "OperatingSystem")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub operating_system: Option<String>,
+    pub operating_system: Option<OperatingSystem>,
     /// <p>File size of the uploaded game build, expressed in bytes. When the build status is <code>INITIALIZED</code>, this value is 0.</p>
     #[serde(rename = "SizeOnDisk")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -147,21 +348,321 @@ pub struct Build {
     /// <p><p>Current status of the build.</p> <p>Possible build statuses include the following:</p> <ul> <li> <p> <b>INITIALIZED</b> -- A new build has been defined, but no files have been uploaded. You cannot create fleets for builds that are in this status. When a build is successfully created, the build status is set to this value. </p> </li> <li> <p> <b>READY</b> -- The game build has been successfully uploaded. You can now create new fleets for this build.</p> </li> <li> <p> <b>FAILED</b> -- The game build upload failed. You cannot create new fleets for this build. </p> </li> </ul></p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<BuildStatus>,
     /// <p>Version information that is associated with a build or script. Version strings do not need to be unique. This value can be set using <a>CreateBuild</a> or <a>UpdateBuild</a>.</p>
     #[serde(rename = "Version")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub version: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BuildStatus {
+    /// `INITIALIZED`
+    Initialized,
+    /// `READY`
+    Ready,
+    /// `FAILED`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl BuildStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BuildStatus::Initialized => "INITIALIZED",
+            BuildStatus::Ready => "READY",
+            BuildStatus::Failed => "FAILED",
+            BuildStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for BuildStatus {
+    fn default() -> BuildStatus {
+        BuildStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for BuildStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for BuildStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for BuildStatus {
+    fn from(value: &'a str) -> BuildStatus {
+        match value {
+            "INITIALIZED" => BuildStatus::Initialized,
+            "READY" => BuildStatus::Ready,
+            "FAILED" => BuildStatus::Failed,
+            _ => BuildStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BuildStatus {
+    fn from(value: String) -> BuildStatus {
+        match value.as_str() {
+            "INITIALIZED" => BuildStatus::Initialized,
+            "READY" => BuildStatus::Ready,
+            "FAILED" => BuildStatus::Failed,
+            _ => BuildStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for BuildStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<BuildStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for BuildStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for BuildStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BuildStatus {
+    fn deserialize<D>(deserializer: D) -> Result<BuildStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about the use of a TLS/SSL certificate for a fleet. TLS certificate generation is enabled at the fleet level, with one certificate generated for the fleet. When this feature is enabled, the certificate can be retrieved using the <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/reference-serversdk.html">GameLift Server SDK</a> call <code>GetInstanceCertificate</code>. All instances in a fleet share the same certificate.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CertificateConfiguration {
     /// <p>Indicates whether a TLS/SSL certificate was generated for a fleet. </p> <p> </p> <p> </p>
     #[serde(rename = "CertificateType")]
-    pub certificate_type: String,
+    pub certificate_type: CertificateType,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CertificateType {
+    /// `DISABLED`
+    Disabled,
+    /// `GENERATED`
+    Generated,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CertificateType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CertificateType::Disabled => "DISABLED",
+            CertificateType::Generated => "GENERATED",
+            CertificateType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CertificateType {
+    fn default() -> CertificateType {
+        CertificateType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CertificateType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CertificateType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CertificateType {
+    fn from(value: &'a str) -> CertificateType {
+        match value {
+            "DISABLED" => CertificateType::Disabled,
+            "GENERATED" => CertificateType::Generated,
+            _ => CertificateType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CertificateType {
+    fn from(value: String) -> CertificateType {
+        match value.as_str() {
+            "DISABLED" => CertificateType::Disabled,
+            "GENERATED" => CertificateType::Generated,
+            _ => CertificateType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CertificateType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CertificateType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CertificateType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CertificateType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CertificateType {
+    fn deserialize<D>(deserializer: D) -> Result<CertificateType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ComparisonOperatorType {
+    /// `GreaterThanOrEqualToThreshold`
+    GreaterThanOrEqualToThreshold,
+    /// `GreaterThanThreshold`
+    GreaterThanThreshold,
+    /// `LessThanThreshold`
+    LessThanThreshold,
+    /// `LessThanOrEqualToThreshold`
+    LessThanOrEqualToThreshold,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ComparisonOperatorType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ComparisonOperatorType::GreaterThanOrEqualToThreshold => {
+                "GreaterThanOrEqualToThreshold"
+            }
+            ComparisonOperatorType::GreaterThanThreshold => "GreaterThanThreshold",
+            ComparisonOperatorType::LessThanThreshold => "LessThanThreshold",
+            ComparisonOperatorType::LessThanOrEqualToThreshold => "LessThanOrEqualToThreshold",
+            ComparisonOperatorType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ComparisonOperatorType {
+    fn default() -> ComparisonOperatorType {
+        ComparisonOperatorType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ComparisonOperatorType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ComparisonOperatorType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ComparisonOperatorType {
+    fn from(value: &'a str) -> ComparisonOperatorType {
+        match value {
+            "GreaterThanOrEqualToThreshold" => {
+                ComparisonOperatorType::GreaterThanOrEqualToThreshold
+            }
+            "GreaterThanThreshold" => ComparisonOperatorType::GreaterThanThreshold,
+            "LessThanThreshold" => ComparisonOperatorType::LessThanThreshold,
+            "LessThanOrEqualToThreshold" => ComparisonOperatorType::LessThanOrEqualToThreshold,
+            _ => ComparisonOperatorType::Unknown(value.to_owned()),
+        }
+    }
 }
 
+impl From<String> for ComparisonOperatorType {
+    fn from(value: String) -> ComparisonOperatorType {
+        match value.as_str() {
+            "GreaterThanOrEqualToThreshold" => {
+                ComparisonOperatorType::GreaterThanOrEqualToThreshold
+            }
+            "GreaterThanThreshold" => ComparisonOperatorType::GreaterThanThreshold,
+            "LessThanThreshold" => ComparisonOperatorType::LessThanThreshold,
+            "LessThanOrEqualToThreshold" => ComparisonOperatorType::LessThanOrEqualToThreshold,
+            _ => ComparisonOperatorType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ComparisonOperatorType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ComparisonOperatorType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ComparisonOperatorType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ComparisonOperatorType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ComparisonOperatorType {
+    fn deserialize<D>(deserializer: D) -> Result<ComparisonOperatorType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -203,7 +704,7 @@ pub struct CreateBuildInput {
     /// <p>The operating system that the game server binaries are built to run on. This value determines the type of fleet resources that you can use for this build. If your game build contains multiple executables, they all must run on the same operating system. If an operating system is not specified when creating a build, Amazon GameLift uses the default value (WINDOWS_2012). This value cannot be changed later.</p>
     #[serde(rename = "OperatingSystem")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub operating_system: Option<String>,
+    pub operating_system: Option<OperatingSystem>,
     /// <p>Information indicating where your game build files are stored. Use this parameter only when creating a build with files stored in an Amazon S3 bucket that you own. The storage location must specify an Amazon S3 bucket name and key. The location must also specify a role ARN that you set up to allow Amazon GameLift to access your Amazon S3 bucket. The S3 bucket and your new build must be in the same Region.</p>
     #[serde(rename = "StorageLocation")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -258,11 +759,11 @@ pub struct CreateFleetInput {
     pub ec2_inbound_permissions: Option<Vec<IpPermission>>,
     /// <p>The name of an EC2 instance type that is supported in Amazon GameLift. A fleet instance type determines the computing resources of each instance in the fleet, including CPU, memory, storage, and networking capacity. Amazon GameLift supports the following EC2 instance types. See <a href="http://aws.amazon.com/ec2/instance-types/">Amazon EC2 Instance Types</a> for detailed descriptions.</p>
     #[serde(rename = "EC2InstanceType")]
-    pub ec2_instance_type: String,
+    pub ec2_instance_type: EC2InstanceType,
     /// <p>Indicates whether to use On-Demand instances or Spot instances for this fleet. If empty, the default is <code>ON_DEMAND</code>. Both categories of instances use identical hardware and configurations based on the instance type selected for this fleet. Learn more about <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/gamelift-ec2-instances.html#gamelift-ec2-instances-spot"> On-Demand versus Spot Instances</a>. </p>
     #[serde(rename = "FleetType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub fleet_type: Option<String>,
+    pub fleet_type: Option<FleetType>,
     /// <p>A unique identifier for an AWS IAM role that manages access to your AWS services. With an instance role ARN set, any application that runs on an instance in this fleet can assume the role, including install scripts, server processes, and daemons (background processes). Create a role or look up a role's ARN from the <a href="https://console.aws.amazon.com/iam/">IAM dashboard</a> in the AWS Management Console. Learn more about using on-box credentials for your game servers at <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/gamelift-sdk-server-resources.html"> Access external resources from a game server</a>.</p>
     #[serde(rename = "InstanceRoleArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -281,7 +782,7 @@ pub struct CreateFleetInput {
     /// <p><p>A game session protection policy to apply to all instances in this fleet. If this parameter is not set, instances in this fleet default to no protection. You can change a fleet&#39;s protection policy using <a>UpdateFleetAttributes</a>, but this change will only affect sessions created after the policy change. You can also set protection for individual instances using <a>UpdateGameSession</a>.</p> <ul> <li> <p> <b>NoProtection</b> - The game session can be terminated during a scale-down event.</p> </li> <li> <p> <b>FullProtection</b> - If the game session is in an <code>ACTIVE</code> status, it cannot be terminated during a scale-down event.</p> </li> </ul></p>
     #[serde(rename = "NewGameSessionProtectionPolicy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub new_game_session_protection_policy: Option<String>,
+    pub new_game_session_protection_policy: Option<ProtectionPolicy>,
     /// <p>A unique identifier for the AWS account with the VPC that you want to peer your Amazon GameLift fleet with. You can find your account ID in the AWS Management Console under account settings. </p>
     #[serde(rename = "PeerVpcAwsAccountId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -430,7 +931,7 @@ pub struct CreateMatchmakingConfigurationInput {
     /// <p>The method used to backfill game sessions that are created with this matchmaking configuration. Specify <code>MANUAL</code> when your game manages backfill requests manually or does not use the match backfill feature. Specify <code>AUTOMATIC</code> to have GameLift create a <a>StartMatchBackfill</a> request whenever a game session has one or more open slots. Learn more about manual and automatic backfill in <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-backfill.html"> Backfill Existing Games with FlexMatch</a>. </p>
     #[serde(rename = "BackfillMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub backfill_mode: Option<String>,
+    pub backfill_mode: Option<BackfillMode>,
     /// <p>Information to be added to all events related to this matchmaking configuration. </p>
     #[serde(rename = "CustomEventData")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -800,7 +1301,7 @@ pub struct DescribeEC2InstanceLimitsInput {
     /// <p>Name of an EC2 instance type that is supported in Amazon GameLift. A fleet instance type determines the computing resources of each instance in the fleet, including CPU, memory, storage, and networking capacity. Amazon GameLift supports the following EC2 instance types. See <a href="http://aws.amazon.com/ec2/instance-types/">Amazon EC2 Instance Types</a> for detailed descriptions. Leave this parameter blank to retrieve limits for all types.</p>
     #[serde(rename = "EC2InstanceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ec2_instance_type: Option<String>,
+    pub ec2_instance_type: Option<EC2InstanceType>,
 }
 
 /// <p>Represents the returned data in response to a request action.</p>
@@ -883,8 +1384,13 @@ pub struct DescribeFleetCapacityOutput {
 pub struct DescribeFleetEventsInput {
     /// <p>Most recent date to retrieve event logs for. If no end time is specified, this call returns entries from the specified start time up to the present. Format is a number expressed in Unix time as milliseconds (ex: "1469498468.057").</p>
     #[serde(rename = "EndTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A unique identifier for a fleet to get event logs for. You can use either the fleet ID or ARN value.</p>
     #[serde(rename = "FleetId")]
     pub fleet_id: String,
@@ -898,8 +1404,13 @@ pub struct DescribeFleetEventsInput {
     pub next_token: Option<String>,
     /// <p>Earliest date to retrieve event logs for. If no start time is specified, this call returns entries starting from when the fleet was created to the specified end time. Format is a number expressed in Unix time as milliseconds (ex: "1469498468.057").</p>
     #[serde(rename = "StartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>Represents the returned data in response to a request action.</p>
@@ -1308,7 +1819,7 @@ pub struct DescribeScalingPoliciesInput {
     /// <p><p>Scaling policy status to filter results on. A scaling policy is only in force when in an <code>ACTIVE</code> status.</p> <ul> <li> <p> <b>ACTIVE</b> -- The scaling policy is currently in force.</p> </li> <li> <p> <b>UPDATEREQUESTED</b> -- A request to update the scaling policy has been received.</p> </li> <li> <p> <b>UPDATING</b> -- A change is being made to the scaling policy.</p> </li> <li> <p> <b>DELETEREQUESTED</b> -- A request to delete the scaling policy has been received.</p> </li> <li> <p> <b>DELETING</b> -- The scaling policy is being deleted.</p> </li> <li> <p> <b>DELETED</b> -- The scaling policy has been deleted.</p> </li> <li> <p> <b>ERROR</b> -- An error occurred in creating the policy. It should be removed and recreated.</p> </li> </ul></p>
     #[serde(rename = "StatusFilter")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status_filter: Option<String>,
+    pub status_filter: Option<ScalingStatusType>,
 }
 
 /// <p>Represents the returned data in response to a request action.</p>
@@ -1434,13 +1945,971 @@ pub struct EC2InstanceLimit {
     /// <p>Name of an EC2 instance type that is supported in Amazon GameLift. A fleet instance type determines the computing resources of each instance in the fleet, including CPU, memory, storage, and networking capacity. Amazon GameLift supports the following EC2 instance types. See <a href="http://aws.amazon.com/ec2/instance-types/">Amazon EC2 Instance Types</a> for detailed descriptions.</p>
     #[serde(rename = "EC2InstanceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ec2_instance_type: Option<String>,
+    pub ec2_instance_type: Option<EC2InstanceType>,
     /// <p>Number of instances allowed.</p>
     #[serde(rename = "InstanceLimit")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub instance_limit: Option<i64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EC2InstanceType {
+    /// `t2.micro`
+    T2Micro,
+    /// `t2.small`
+    T2Small,
+    /// `t2.medium`
+    T2Medium,
+    /// `t2.large`
+    T2Large,
+    /// `c3.large`
+    C3Large,
+    /// `c3.xlarge`
+    C3Xlarge,
+    /// `c3.2xlarge`
+    C3_2xlarge,
+    /// `c3.4xlarge`
+    C3_4xlarge,
+    /// `c3.8xlarge`
+    C3_8xlarge,
+    /// `c4.large`
+    C4Large,
+    /// `c4.xlarge`
+    C4Xlarge,
+    /// `c4.2xlarge`
+    C4_2xlarge,
+    /// `c4.4xlarge`
+    C4_4xlarge,
+    /// `c4.8xlarge`
+    C4_8xlarge,
+    /// `c5.large`
+    C5Large,
+    /// `c5.xlarge`
+    C5Xlarge,
+    /// `c5.2xlarge`
+    C5_2xlarge,
+    /// `c5.4xlarge`
+    C5_4xlarge,
+    /// `c5.9xlarge`
+    C5_9xlarge,
+    /// `c5.12xlarge`
+    C5_12xlarge,
+    /// `c5.18xlarge`
+    C5_18xlarge,
+    /// `c5.24xlarge`
+    C5_24xlarge,
+    /// `c5a.large`
+    C5aLarge,
+    /// `c5a.xlarge`
+    C5aXlarge,
+    /// `c5a.2xlarge`
+    C5a2xlarge,
+    /// `c5a.4xlarge`
+    C5a4xlarge,
+    /// `c5a.8xlarge`
+    C5a8xlarge,
+    /// `c5a.12xlarge`
+    C5a12xlarge,
+    /// `c5a.16xlarge`
+    C5a16xlarge,
+    /// `c5a.24xlarge`
+    C5a24xlarge,
+    /// `r3.large`
+    R3Large,
+    /// `r3.xlarge`
+    R3Xlarge,
+    /// `r3.2xlarge`
+    R3_2xlarge,
+    /// `r3.4xlarge`
+    R3_4xlarge,
+    /// `r3.8xlarge`
+    R3_8xlarge,
+    /// `r4.large`
+    R4Large,
+    /// `r4.xlarge`
+    R4Xlarge,
+    /// `r4.2xlarge`
+    R4_2xlarge,
+    /// `r4.4xlarge`
+    R4_4xlarge,
+    /// `r4.8xlarge`
+    R4_8xlarge,
+    /// `r4.16xlarge`
+    R4_16xlarge,
+    /// `r5.large`
+    R5Large,
+    /// `r5.xlarge`
+    R5Xlarge,
+    /// `r5.2xlarge`
+    R5_2xlarge,
+    /// `r5.4xlarge`
+    R5_4xlarge,
+    /// `r5.8xlarge`
+    R5_8xlarge,
+    /// `r5.12xlarge`
+    R5_12xlarge,
+    /// `r5.16xlarge`
+    R5_16xlarge,
+    /// `r5.24xlarge`
+    R5_24xlarge,
+    /// `r5a.large`
+    R5aLarge,
+    /// `r5a.xlarge`
+    R5aXlarge,
+    /// `r5a.2xlarge`
+    R5a2xlarge,
+    /// `r5a.4xlarge`
+    R5a4xlarge,
+    /// `r5a.8xlarge`
+    R5a8xlarge,
+    /// `r5a.12xlarge`
+    R5a12xlarge,
+    /// `r5a.16xlarge`
+    R5a16xlarge,
+    /// `r5a.24xlarge`
+    R5a24xlarge,
+    /// `m3.medium`
+    M3Medium,
+    /// `m3.large`
+    M3Large,
+    /// `m3.xlarge`
+    M3Xlarge,
+    /// `m3.2xlarge`
+    M3_2xlarge,
+    /// `m4.large`
+    M4Large,
+    /// `m4.xlarge`
+    M4Xlarge,
+    /// `m4.2xlarge`
+    M4_2xlarge,
+    /// `m4.4xlarge`
+    M4_4xlarge,
+    /// `m4.10xlarge`
+    M4_10xlarge,
+    /// `m5.large`
+    M5Large,
+    /// `m5.xlarge`
+    M5Xlarge,
+    /// `m5.2xlarge`
+    M5_2xlarge,
+    /// `m5.4xlarge`
+    M5_4xlarge,
+    /// `m5.8xlarge`
+    M5_8xlarge,
+    /// `m5.12xlarge`
+    M5_12xlarge,
+    /// `m5.16xlarge`
+    M5_16xlarge,
+    /// `m5.24xlarge`
+    M5_24xlarge,
+    /// `m5a.large`
+    M5aLarge,
+    /// `m5a.xlarge`
+    M5aXlarge,
+    /// `m5a.2xlarge`
+    M5a2xlarge,
+    /// `m5a.4xlarge`
+    M5a4xlarge,
+    /// `m5a.8xlarge`
+    M5a8xlarge,
+    /// `m5a.12xlarge`
+    M5a12xlarge,
+    /// `m5a.16xlarge`
+    M5a16xlarge,
+    /// `m5a.24xlarge`
+    M5a24xlarge,
+    /// `c5d.large`
+    C5dLarge,
+    /// `c5d.xlarge`
+    C5dXlarge,
+    /// `c5d.2xlarge`
+    C5d2xlarge,
+    /// `c5d.4xlarge`
+    C5d4xlarge,
+    /// `c5d.9xlarge`
+    C5d9xlarge,
+    /// `c5d.12xlarge`
+    C5d12xlarge,
+    /// `c5d.18xlarge`
+    C5d18xlarge,
+    /// `c5d.24xlarge`
+    C5d24xlarge,
+    /// `c6a.large`
+    C6aLarge,
+    /// `c6a.xlarge`
+    C6aXlarge,
+    /// `c6a.2xlarge`
+    C6a2xlarge,
+    /// `c6a.4xlarge`
+    C6a4xlarge,
+    /// `c6a.8xlarge`
+    C6a8xlarge,
+    /// `c6a.12xlarge`
+    C6a12xlarge,
+    /// `c6a.16xlarge`
+    C6a16xlarge,
+    /// `c6a.24xlarge`
+    C6a24xlarge,
+    /// `c6i.large`
+    C6iLarge,
+    /// `c6i.xlarge`
+    C6iXlarge,
+    /// `c6i.2xlarge`
+    C6i2xlarge,
+    /// `c6i.4xlarge`
+    C6i4xlarge,
+    /// `c6i.8xlarge`
+    C6i8xlarge,
+    /// `c6i.12xlarge`
+    C6i12xlarge,
+    /// `c6i.16xlarge`
+    C6i16xlarge,
+    /// `c6i.24xlarge`
+    C6i24xlarge,
+    /// `r5d.large`
+    R5dLarge,
+    /// `r5d.xlarge`
+    R5dXlarge,
+    /// `r5d.2xlarge`
+    R5d2xlarge,
+    /// `r5d.4xlarge`
+    R5d4xlarge,
+    /// `r5d.8xlarge`
+    R5d8xlarge,
+    /// `r5d.12xlarge`
+    R5d12xlarge,
+    /// `r5d.16xlarge`
+    R5d16xlarge,
+    /// `r5d.24xlarge`
+    R5d24xlarge,
+    /// `m6g.medium`
+    M6gMedium,
+    /// `m6g.large`
+    M6gLarge,
+    /// `m6g.xlarge`
+    M6gXlarge,
+    /// `m6g.2xlarge`
+    M6g2xlarge,
+    /// `m6g.4xlarge`
+    M6g4xlarge,
+    /// `m6g.8xlarge`
+    M6g8xlarge,
+    /// `m6g.12xlarge`
+    M6g12xlarge,
+    /// `m6g.16xlarge`
+    M6g16xlarge,
+    /// `c6g.medium`
+    C6gMedium,
+    /// `c6g.large`
+    C6gLarge,
+    /// `c6g.xlarge`
+    C6gXlarge,
+    /// `c6g.2xlarge`
+    C6g2xlarge,
+    /// `c6g.4xlarge`
+    C6g4xlarge,
+    /// `c6g.8xlarge`
+    C6g8xlarge,
+    /// `c6g.12xlarge`
+    C6g12xlarge,
+    /// `c6g.16xlarge`
+    C6g16xlarge,
+    /// `r6g.medium`
+    R6gMedium,
+    /// `r6g.large`
+    R6gLarge,
+    /// `r6g.xlarge`
+    R6gXlarge,
+    /// `r6g.2xlarge`
+    R6g2xlarge,
+    /// `r6g.4xlarge`
+    R6g4xlarge,
+    /// `r6g.8xlarge`
+    R6g8xlarge,
+    /// `r6g.12xlarge`
+    R6g12xlarge,
+    /// `r6g.16xlarge`
+    R6g16xlarge,
+    /// `c6gn.medium`
+    C6gnMedium,
+    /// `c6gn.large`
+    C6gnLarge,
+    /// `c6gn.xlarge`
+    C6gnXlarge,
+    /// `c6gn.2xlarge`
+    C6gn2xlarge,
+    /// `c6gn.4xlarge`
+    C6gn4xlarge,
+    /// `c6gn.8xlarge`
+    C6gn8xlarge,
+    /// `c6gn.12xlarge`
+    C6gn12xlarge,
+    /// `c6gn.16xlarge`
+    C6gn16xlarge,
+    /// `c7g.medium`
+    C7gMedium,
+    /// `c7g.large`
+    C7gLarge,
+    /// `c7g.xlarge`
+    C7gXlarge,
+    /// `c7g.2xlarge`
+    C7g2xlarge,
+    /// `c7g.4xlarge`
+    C7g4xlarge,
+    /// `c7g.8xlarge`
+    C7g8xlarge,
+    /// `c7g.12xlarge`
+    C7g12xlarge,
+    /// `c7g.16xlarge`
+    C7g16xlarge,
+    /// `r7g.medium`
+    R7gMedium,
+    /// `r7g.large`
+    R7gLarge,
+    /// `r7g.xlarge`
+    R7gXlarge,
+    /// `r7g.2xlarge`
+    R7g2xlarge,
+    /// `r7g.4xlarge`
+    R7g4xlarge,
+    /// `r7g.8xlarge`
+    R7g8xlarge,
+    /// `r7g.12xlarge`
+    R7g12xlarge,
+    /// `r7g.16xlarge`
+    R7g16xlarge,
+    /// `m7g.medium`
+    M7gMedium,
+    /// `m7g.large`
+    M7gLarge,
+    /// `m7g.xlarge`
+    M7gXlarge,
+    /// `m7g.2xlarge`
+    M7g2xlarge,
+    /// `m7g.4xlarge`
+    M7g4xlarge,
+    /// `m7g.8xlarge`
+    M7g8xlarge,
+    /// `m7g.12xlarge`
+    M7g12xlarge,
+    /// `m7g.16xlarge`
+    M7g16xlarge,
+    /// `g5g.xlarge`
+    G5gXlarge,
+    /// `g5g.2xlarge`
+    G5g2xlarge,
+    /// `g5g.4xlarge`
+    G5g4xlarge,
+    /// `g5g.8xlarge`
+    G5g8xlarge,
+    /// `g5g.16xlarge`
+    G5g16xlarge,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EC2InstanceType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EC2InstanceType::T2Micro => "t2.micro",
+            EC2InstanceType::T2Small => "t2.small",
+            EC2InstanceType::T2Medium => "t2.medium",
+            EC2InstanceType::T2Large => "t2.large",
+            EC2InstanceType::C3Large => "c3.large",
+            EC2InstanceType::C3Xlarge => "c3.xlarge",
+            EC2InstanceType::C3_2xlarge => "c3.2xlarge",
+            EC2InstanceType::C3_4xlarge => "c3.4xlarge",
+            EC2InstanceType::C3_8xlarge => "c3.8xlarge",
+            EC2InstanceType::C4Large => "c4.large",
+            EC2InstanceType::C4Xlarge => "c4.xlarge",
+            EC2InstanceType::C4_2xlarge => "c4.2xlarge",
+            EC2InstanceType::C4_4xlarge => "c4.4xlarge",
+            EC2InstanceType::C4_8xlarge => "c4.8xlarge",
+            EC2InstanceType::C5Large => "c5.large",
+            EC2InstanceType::C5Xlarge => "c5.xlarge",
+            EC2InstanceType::C5_2xlarge => "c5.2xlarge",
+            EC2InstanceType::C5_4xlarge => "c5.4xlarge",
+            EC2InstanceType::C5_9xlarge => "c5.9xlarge",
+            EC2InstanceType::C5_12xlarge => "c5.12xlarge",
+            EC2InstanceType::C5_18xlarge => "c5.18xlarge",
+            EC2InstanceType::C5_24xlarge => "c5.24xlarge",
+            EC2InstanceType::C5aLarge => "c5a.large",
+            EC2InstanceType::C5aXlarge => "c5a.xlarge",
+            EC2InstanceType::C5a2xlarge => "c5a.2xlarge",
+            EC2InstanceType::C5a4xlarge => "c5a.4xlarge",
+            EC2InstanceType::C5a8xlarge => "c5a.8xlarge",
+            EC2InstanceType::C5a12xlarge => "c5a.12xlarge",
+            EC2InstanceType::C5a16xlarge => "c5a.16xlarge",
+            EC2InstanceType::C5a24xlarge => "c5a.24xlarge",
+            EC2InstanceType::R3Large => "r3.large",
+            EC2InstanceType::R3Xlarge => "r3.xlarge",
+            EC2InstanceType::R3_2xlarge => "r3.2xlarge",
+            EC2InstanceType::R3_4xlarge => "r3.4xlarge",
+            EC2InstanceType::R3_8xlarge => "r3.8xlarge",
+            EC2InstanceType::R4Large => "r4.large",
+            EC2InstanceType::R4Xlarge => "r4.xlarge",
+            EC2InstanceType::R4_2xlarge => "r4.2xlarge",
+            EC2InstanceType::R4_4xlarge => "r4.4xlarge",
+            EC2InstanceType::R4_8xlarge => "r4.8xlarge",
+            EC2InstanceType::R4_16xlarge => "r4.16xlarge",
+            EC2InstanceType::R5Large => "r5.large",
+            EC2InstanceType::R5Xlarge => "r5.xlarge",
+            EC2InstanceType::R5_2xlarge => "r5.2xlarge",
+            EC2InstanceType::R5_4xlarge => "r5.4xlarge",
+            EC2InstanceType::R5_8xlarge => "r5.8xlarge",
+            EC2InstanceType::R5_12xlarge => "r5.12xlarge",
+            EC2InstanceType::R5_16xlarge => "r5.16xlarge",
+            EC2InstanceType::R5_24xlarge => "r5.24xlarge",
+            EC2InstanceType::R5aLarge => "r5a.large",
+            EC2InstanceType::R5aXlarge => "r5a.xlarge",
+            EC2InstanceType::R5a2xlarge => "r5a.2xlarge",
+            EC2InstanceType::R5a4xlarge => "r5a.4xlarge",
+            EC2InstanceType::R5a8xlarge => "r5a.8xlarge",
+            EC2InstanceType::R5a12xlarge => "r5a.12xlarge",
+            EC2InstanceType::R5a16xlarge => "r5a.16xlarge",
+            EC2InstanceType::R5a24xlarge => "r5a.24xlarge",
+            EC2InstanceType::M3Medium => "m3.medium",
+            EC2InstanceType::M3Large => "m3.large",
+            EC2InstanceType::M3Xlarge => "m3.xlarge",
+            EC2InstanceType::M3_2xlarge => "m3.2xlarge",
+            EC2InstanceType::M4Large => "m4.large",
+            EC2InstanceType::M4Xlarge => "m4.xlarge",
+            EC2InstanceType::M4_2xlarge => "m4.2xlarge",
+            EC2InstanceType::M4_4xlarge => "m4.4xlarge",
+            EC2InstanceType::M4_10xlarge => "m4.10xlarge",
+            EC2InstanceType::M5Large => "m5.large",
+            EC2InstanceType::M5Xlarge => "m5.xlarge",
+            EC2InstanceType::M5_2xlarge => "m5.2xlarge",
+            EC2InstanceType::M5_4xlarge => "m5.4xlarge",
+            EC2InstanceType::M5_8xlarge => "m5.8xlarge",
+            EC2InstanceType::M5_12xlarge => "m5.12xlarge",
+            EC2InstanceType::M5_16xlarge => "m5.16xlarge",
+            EC2InstanceType::M5_24xlarge => "m5.24xlarge",
+            EC2InstanceType::M5aLarge => "m5a.large",
+            EC2InstanceType::M5aXlarge => "m5a.xlarge",
+            EC2InstanceType::M5a2xlarge => "m5a.2xlarge",
+            EC2InstanceType::M5a4xlarge => "m5a.4xlarge",
+            EC2InstanceType::M5a8xlarge => "m5a.8xlarge",
+            EC2InstanceType::M5a12xlarge => "m5a.12xlarge",
+            EC2InstanceType::M5a16xlarge => "m5a.16xlarge",
+            EC2InstanceType::M5a24xlarge => "m5a.24xlarge",
+            EC2InstanceType::C5dLarge => "c5d.large",
+            EC2InstanceType::C5dXlarge => "c5d.xlarge",
+            EC2InstanceType::C5d2xlarge => "c5d.2xlarge",
+            EC2InstanceType::C5d4xlarge => "c5d.4xlarge",
+            EC2InstanceType::C5d9xlarge => "c5d.9xlarge",
+            EC2InstanceType::C5d12xlarge => "c5d.12xlarge",
+            EC2InstanceType::C5d18xlarge => "c5d.18xlarge",
+            EC2InstanceType::C5d24xlarge => "c5d.24xlarge",
+            EC2InstanceType::C6aLarge => "c6a.large",
+            EC2InstanceType::C6aXlarge => "c6a.xlarge",
+            EC2InstanceType::C6a2xlarge => "c6a.2xlarge",
+            EC2InstanceType::C6a4xlarge => "c6a.4xlarge",
+            EC2InstanceType::C6a8xlarge => "c6a.8xlarge",
+            EC2InstanceType::C6a12xlarge => "c6a.12xlarge",
+            EC2InstanceType::C6a16xlarge => "c6a.16xlarge",
+            EC2InstanceType::C6a24xlarge => "c6a.24xlarge",
+            EC2InstanceType::C6iLarge => "c6i.large",
+            EC2InstanceType::C6iXlarge => "c6i.xlarge",
+            EC2InstanceType::C6i2xlarge => "c6i.2xlarge",
+            EC2InstanceType::C6i4xlarge => "c6i.4xlarge",
+            EC2InstanceType::C6i8xlarge => "c6i.8xlarge",
+            EC2InstanceType::C6i12xlarge => "c6i.12xlarge",
+            EC2InstanceType::C6i16xlarge => "c6i.16xlarge",
+            EC2InstanceType::C6i24xlarge => "c6i.24xlarge",
+            EC2InstanceType::R5dLarge => "r5d.large",
+            EC2InstanceType::R5dXlarge => "r5d.xlarge",
+            EC2InstanceType::R5d2xlarge => "r5d.2xlarge",
+            EC2InstanceType::R5d4xlarge => "r5d.4xlarge",
+            EC2InstanceType::R5d8xlarge => "r5d.8xlarge",
+            EC2InstanceType::R5d12xlarge => "r5d.12xlarge",
+            EC2InstanceType::R5d16xlarge => "r5d.16xlarge",
+            EC2InstanceType::R5d24xlarge => "r5d.24xlarge",
+            EC2InstanceType::M6gMedium => "m6g.medium",
+            EC2InstanceType::M6gLarge => "m6g.large",
+            EC2InstanceType::M6gXlarge => "m6g.xlarge",
+            EC2InstanceType::M6g2xlarge => "m6g.2xlarge",
+            EC2InstanceType::M6g4xlarge => "m6g.4xlarge",
+            EC2InstanceType::M6g8xlarge => "m6g.8xlarge",
+            EC2InstanceType::M6g12xlarge => "m6g.12xlarge",
+            EC2InstanceType::M6g16xlarge => "m6g.16xlarge",
+            EC2InstanceType::C6gMedium => "c6g.medium",
+            EC2InstanceType::C6gLarge => "c6g.large",
+            EC2InstanceType::C6gXlarge => "c6g.xlarge",
+            EC2InstanceType::C6g2xlarge => "c6g.2xlarge",
+            EC2InstanceType::C6g4xlarge => "c6g.4xlarge",
+            EC2InstanceType::C6g8xlarge => "c6g.8xlarge",
+            EC2InstanceType::C6g12xlarge => "c6g.12xlarge",
+            EC2InstanceType::C6g16xlarge => "c6g.16xlarge",
+            EC2InstanceType::R6gMedium => "r6g.medium",
+            EC2InstanceType::R6gLarge => "r6g.large",
+            EC2InstanceType::R6gXlarge => "r6g.xlarge",
+            EC2InstanceType::R6g2xlarge => "r6g.2xlarge",
+            EC2InstanceType::R6g4xlarge => "r6g.4xlarge",
+            EC2InstanceType::R6g8xlarge => "r6g.8xlarge",
+            EC2InstanceType::R6g12xlarge => "r6g.12xlarge",
+            EC2InstanceType::R6g16xlarge => "r6g.16xlarge",
+            EC2InstanceType::C6gnMedium => "c6gn.medium",
+            EC2InstanceType::C6gnLarge => "c6gn.large",
+            EC2InstanceType::C6gnXlarge => "c6gn.xlarge",
+            EC2InstanceType::C6gn2xlarge => "c6gn.2xlarge",
+            EC2InstanceType::C6gn4xlarge => "c6gn.4xlarge",
+            EC2InstanceType::C6gn8xlarge => "c6gn.8xlarge",
+            EC2InstanceType::C6gn12xlarge => "c6gn.12xlarge",
+            EC2InstanceType::C6gn16xlarge => "c6gn.16xlarge",
+            EC2InstanceType::C7gMedium => "c7g.medium",
+            EC2InstanceType::C7gLarge => "c7g.large",
+            EC2InstanceType::C7gXlarge => "c7g.xlarge",
+            EC2InstanceType::C7g2xlarge => "c7g.2xlarge",
+            EC2InstanceType::C7g4xlarge => "c7g.4xlarge",
+            EC2InstanceType::C7g8xlarge => "c7g.8xlarge",
+            EC2InstanceType::C7g12xlarge => "c7g.12xlarge",
+            EC2InstanceType::C7g16xlarge => "c7g.16xlarge",
+            EC2InstanceType::R7gMedium => "r7g.medium",
+            EC2InstanceType::R7gLarge => "r7g.large",
+            EC2InstanceType::R7gXlarge => "r7g.xlarge",
+            EC2InstanceType::R7g2xlarge => "r7g.2xlarge",
+            EC2InstanceType::R7g4xlarge => "r7g.4xlarge",
+            EC2InstanceType::R7g8xlarge => "r7g.8xlarge",
+            EC2InstanceType::R7g12xlarge => "r7g.12xlarge",
+            EC2InstanceType::R7g16xlarge => "r7g.16xlarge",
+            EC2InstanceType::M7gMedium => "m7g.medium",
+            EC2InstanceType::M7gLarge => "m7g.large",
+            EC2InstanceType::M7gXlarge => "m7g.xlarge",
+            EC2InstanceType::M7g2xlarge => "m7g.2xlarge",
+            EC2InstanceType::M7g4xlarge => "m7g.4xlarge",
+            EC2InstanceType::M7g8xlarge => "m7g.8xlarge",
+            EC2InstanceType::M7g12xlarge => "m7g.12xlarge",
+            EC2InstanceType::M7g16xlarge => "m7g.16xlarge",
+            EC2InstanceType::G5gXlarge => "g5g.xlarge",
+            EC2InstanceType::G5g2xlarge => "g5g.2xlarge",
+            EC2InstanceType::G5g4xlarge => "g5g.4xlarge",
+            EC2InstanceType::G5g8xlarge => "g5g.8xlarge",
+            EC2InstanceType::G5g16xlarge => "g5g.16xlarge",
+            EC2InstanceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EC2InstanceType {
+    fn default() -> EC2InstanceType {
+        EC2InstanceType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EC2InstanceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EC2InstanceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EC2InstanceType {
+    fn from(value: &'a str) -> EC2InstanceType {
+        match value {
+            "t2.micro" => EC2InstanceType::T2Micro,
+            "t2.small" => EC2InstanceType::T2Small,
+            "t2.medium" => EC2InstanceType::T2Medium,
+            "t2.large" => EC2InstanceType::T2Large,
+            "c3.large" => EC2InstanceType::C3Large,
+            "c3.xlarge" => EC2InstanceType::C3Xlarge,
+            "c3.2xlarge" => EC2InstanceType::C3_2xlarge,
+            "c3.4xlarge" => EC2InstanceType::C3_4xlarge,
+            "c3.8xlarge" => EC2InstanceType::C3_8xlarge,
+            "c4.large" => EC2InstanceType::C4Large,
+            "c4.xlarge" => EC2InstanceType::C4Xlarge,
+            "c4.2xlarge" => EC2InstanceType::C4_2xlarge,
+            "c4.4xlarge" => EC2InstanceType::C4_4xlarge,
+            "c4.8xlarge" => EC2InstanceType::C4_8xlarge,
+            "c5.large" => EC2InstanceType::C5Large,
+            "c5.xlarge" => EC2InstanceType::C5Xlarge,
+            "c5.2xlarge" => EC2InstanceType::C5_2xlarge,
+            "c5.4xlarge" => EC2InstanceType::C5_4xlarge,
+            "c5.9xlarge" => EC2InstanceType::C5_9xlarge,
+            "c5.12xlarge" => EC2InstanceType::C5_12xlarge,
+            "c5.18xlarge" => EC2InstanceType::C5_18xlarge,
+            "c5.24xlarge" => EC2InstanceType::C5_24xlarge,
+            "c5a.large" => EC2InstanceType::C5aLarge,
+            "c5a.xlarge" => EC2InstanceType::C5aXlarge,
+            "c5a.2xlarge" => EC2InstanceType::C5a2xlarge,
+            "c5a.4xlarge" => EC2InstanceType::C5a4xlarge,
+            "c5a.8xlarge" => EC2InstanceType::C5a8xlarge,
+            "c5a.12xlarge" => EC2InstanceType::C5a12xlarge,
+            "c5a.16xlarge" => EC2InstanceType::C5a16xlarge,
+            "c5a.24xlarge" => EC2InstanceType::C5a24xlarge,
+            "r3.large" => EC2InstanceType::R3Large,
+            "r3.xlarge" => EC2InstanceType::R3Xlarge,
+            "r3.2xlarge" => EC2InstanceType::R3_2xlarge,
+            "r3.4xlarge" => EC2InstanceType::R3_4xlarge,
+            "r3.8xlarge" => EC2InstanceType::R3_8xlarge,
+            "r4.large" => EC2InstanceType::R4Large,
+            "r4.xlarge" => EC2InstanceType::R4Xlarge,
+            "r4.2xlarge" => EC2InstanceType::R4_2xlarge,
+            "r4.4xlarge" => EC2InstanceType::R4_4xlarge,
+            "r4.8xlarge" => EC2InstanceType::R4_8xlarge,
+            "r4.16xlarge" => EC2InstanceType::R4_16xlarge,
+            "r5.large" => EC2InstanceType::R5Large,
+            "r5.xlarge" => EC2InstanceType::R5Xlarge,
+            "r5.2xlarge" => EC2InstanceType::R5_2xlarge,
+            "r5.4xlarge" => EC2InstanceType::R5_4xlarge,
+            "r5.8xlarge" => EC2InstanceType::R5_8xlarge,
+            "r5.12xlarge" => EC2InstanceType::R5_12xlarge,
+            "r5.16xlarge" => EC2InstanceType::R5_16xlarge,
+            "r5.24xlarge" => EC2InstanceType::R5_24xlarge,
+            "r5a.large" => EC2InstanceType::R5aLarge,
+            "r5a.xlarge" => EC2InstanceType::R5aXlarge,
+            "r5a.2xlarge" => EC2InstanceType::R5a2xlarge,
+            "r5a.4xlarge" => EC2InstanceType::R5a4xlarge,
+            "r5a.8xlarge" => EC2InstanceType::R5a8xlarge,
+            "r5a.12xlarge" => EC2InstanceType::R5a12xlarge,
+            "r5a.16xlarge" => EC2InstanceType::R5a16xlarge,
+            "r5a.24xlarge" => EC2InstanceType::R5a24xlarge,
+            "m3.medium" => EC2InstanceType::M3Medium,
+            "m3.large" => EC2InstanceType::M3Large,
+            "m3.xlarge" => EC2InstanceType::M3Xlarge,
+            "m3.2xlarge" => EC2InstanceType::M3_2xlarge,
+            "m4.large" => EC2InstanceType::M4Large,
+            "m4.xlarge" => EC2InstanceType::M4Xlarge,
+            "m4.2xlarge" => EC2InstanceType::M4_2xlarge,
+            "m4.4xlarge" => EC2InstanceType::M4_4xlarge,
+            "m4.10xlarge" => EC2InstanceType::M4_10xlarge,
+            "m5.large" => EC2InstanceType::M5Large,
+            "m5.xlarge" => EC2InstanceType::M5Xlarge,
+            "m5.2xlarge" => EC2InstanceType::M5_2xlarge,
+            "m5.4xlarge" => EC2InstanceType::M5_4xlarge,
+            "m5.8xlarge" => EC2InstanceType::M5_8xlarge,
+            "m5.12xlarge" => EC2InstanceType::M5_12xlarge,
+            "m5.16xlarge" => EC2InstanceType::M5_16xlarge,
+            "m5.24xlarge" => EC2InstanceType::M5_24xlarge,
+            "m5a.large" => EC2InstanceType::M5aLarge,
+            "m5a.xlarge" => EC2InstanceType::M5aXlarge,
+            "m5a.2xlarge" => EC2InstanceType::M5a2xlarge,
+            "m5a.4xlarge" => EC2InstanceType::M5a4xlarge,
+            "m5a.8xlarge" => EC2InstanceType::M5a8xlarge,
+            "m5a.12xlarge" => EC2InstanceType::M5a12xlarge,
+            "m5a.16xlarge" => EC2InstanceType::M5a16xlarge,
+            "m5a.24xlarge" => EC2InstanceType::M5a24xlarge,
+            "c5d.large" => EC2InstanceType::C5dLarge,
+            "c5d.xlarge" => EC2InstanceType::C5dXlarge,
+            "c5d.2xlarge" => EC2InstanceType::C5d2xlarge,
+            "c5d.4xlarge" => EC2InstanceType::C5d4xlarge,
+            "c5d.9xlarge" => EC2InstanceType::C5d9xlarge,
+            "c5d.12xlarge" => EC2InstanceType::C5d12xlarge,
+            "c5d.18xlarge" => EC2InstanceType::C5d18xlarge,
+            "c5d.24xlarge" => EC2InstanceType::C5d24xlarge,
+            "c6a.large" => EC2InstanceType::C6aLarge,
+            "c6a.xlarge" => EC2InstanceType::C6aXlarge,
+            "c6a.2xlarge" => EC2InstanceType::C6a2xlarge,
+            "c6a.4xlarge" => EC2InstanceType::C6a4xlarge,
+            "c6a.8xlarge" => EC2InstanceType::C6a8xlarge,
+            "c6a.12xlarge" => EC2InstanceType::C6a12xlarge,
+            "c6a.16xlarge" => EC2InstanceType::C6a16xlarge,
+            "c6a.24xlarge" => EC2InstanceType::C6a24xlarge,
+            "c6i.large" => EC2InstanceType::C6iLarge,
+            "c6i.xlarge" => EC2InstanceType::C6iXlarge,
+            "c6i.2xlarge" => EC2InstanceType::C6i2xlarge,
+            "c6i.4xlarge" => EC2InstanceType::C6i4xlarge,
+            "c6i.8xlarge" => EC2InstanceType::C6i8xlarge,
+            "c6i.12xlarge" => EC2InstanceType::C6i12xlarge,
+            "c6i.16xlarge" => EC2InstanceType::C6i16xlarge,
+            "c6i.24xlarge" => EC2InstanceType::C6i24xlarge,
+            "r5d.large" => EC2InstanceType::R5dLarge,
+            "r5d.xlarge" => EC2InstanceType::R5dXlarge,
+            "r5d.2xlarge" => EC2InstanceType::R5d2xlarge,
+            "r5d.4xlarge" => EC2InstanceType::R5d4xlarge,
+            "r5d.8xlarge" => EC2InstanceType::R5d8xlarge,
+            "r5d.12xlarge" => EC2InstanceType::R5d12xlarge,
+            "r5d.16xlarge" => EC2InstanceType::R5d16xlarge,
+            "r5d.24xlarge" => EC2InstanceType::R5d24xlarge,
+            "m6g.medium" => EC2InstanceType::M6gMedium,
+            "m6g.large" => EC2InstanceType::M6gLarge,
+            "m6g.xlarge" => EC2InstanceType::M6gXlarge,
+            "m6g.2xlarge" => EC2InstanceType::M6g2xlarge,
+            "m6g.4xlarge" => EC2InstanceType::M6g4xlarge,
+            "m6g.8xlarge" => EC2InstanceType::M6g8xlarge,
+            "m6g.12xlarge" => EC2InstanceType::M6g12xlarge,
+            "m6g.16xlarge" => EC2InstanceType::M6g16xlarge,
+            "c6g.medium" => EC2InstanceType::C6gMedium,
+            "c6g.large" => EC2InstanceType::C6gLarge,
+            "c6g.xlarge" => EC2InstanceType::C6gXlarge,
+            "c6g.2xlarge" => EC2InstanceType::C6g2xlarge,
+            "c6g.4xlarge" => EC2InstanceType::C6g4xlarge,
+            "c6g.8xlarge" => EC2InstanceType::C6g8xlarge,
+            "c6g.12xlarge" => EC2InstanceType::C6g12xlarge,
+            "c6g.16xlarge" => EC2InstanceType::C6g16xlarge,
+            "r6g.medium" => EC2InstanceType::R6gMedium,
+            "r6g.large" => EC2InstanceType::R6gLarge,
+            "r6g.xlarge" => EC2InstanceType::R6gXlarge,
+            "r6g.2xlarge" => EC2InstanceType::R6g2xlarge,
+            "r6g.4xlarge" => EC2InstanceType::R6g4xlarge,
+            "r6g.8xlarge" => EC2InstanceType::R6g8xlarge,
+            "r6g.12xlarge" => EC2InstanceType::R6g12xlarge,
+            "r6g.16xlarge" => EC2InstanceType::R6g16xlarge,
+            "c6gn.medium" => EC2InstanceType::C6gnMedium,
+            "c6gn.large" => EC2InstanceType::C6gnLarge,
+            "c6gn.xlarge" => EC2InstanceType::C6gnXlarge,
+            "c6gn.2xlarge" => EC2InstanceType::C6gn2xlarge,
+            "c6gn.4xlarge" => EC2InstanceType::C6gn4xlarge,
+            "c6gn.8xlarge" => EC2InstanceType::C6gn8xlarge,
+            "c6gn.12xlarge" => EC2InstanceType::C6gn12xlarge,
+            "c6gn.16xlarge" => EC2InstanceType::C6gn16xlarge,
+            "c7g.medium" => EC2InstanceType::C7gMedium,
+            "c7g.large" => EC2InstanceType::C7gLarge,
+            "c7g.xlarge" => EC2InstanceType::C7gXlarge,
+            "c7g.2xlarge" => EC2InstanceType::C7g2xlarge,
+            "c7g.4xlarge" => EC2InstanceType::C7g4xlarge,
+            "c7g.8xlarge" => EC2InstanceType::C7g8xlarge,
+            "c7g.12xlarge" => EC2InstanceType::C7g12xlarge,
+            "c7g.16xlarge" => EC2InstanceType::C7g16xlarge,
+            "r7g.medium" => EC2InstanceType::R7gMedium,
+            "r7g.large" => EC2InstanceType::R7gLarge,
+            "r7g.xlarge" => EC2InstanceType::R7gXlarge,
+            "r7g.2xlarge" => EC2InstanceType::R7g2xlarge,
+            "r7g.4xlarge" => EC2InstanceType::R7g4xlarge,
+            "r7g.8xlarge" => EC2InstanceType::R7g8xlarge,
+            "r7g.12xlarge" => EC2InstanceType::R7g12xlarge,
+            "r7g.16xlarge" => EC2InstanceType::R7g16xlarge,
+            "m7g.medium" => EC2InstanceType::M7gMedium,
+            "m7g.large" => EC2InstanceType::M7gLarge,
+            "m7g.xlarge" => EC2InstanceType::M7gXlarge,
+            "m7g.2xlarge" => EC2InstanceType::M7g2xlarge,
+            "m7g.4xlarge" => EC2InstanceType::M7g4xlarge,
+            "m7g.8xlarge" => EC2InstanceType::M7g8xlarge,
+            "m7g.12xlarge" => EC2InstanceType::M7g12xlarge,
+            "m7g.16xlarge" => EC2InstanceType::M7g16xlarge,
+            "g5g.xlarge" => EC2InstanceType::G5gXlarge,
+            "g5g.2xlarge" => EC2InstanceType::G5g2xlarge,
+            "g5g.4xlarge" => EC2InstanceType::G5g4xlarge,
+            "g5g.8xlarge" => EC2InstanceType::G5g8xlarge,
+            "g5g.16xlarge" => EC2InstanceType::G5g16xlarge,
+            _ => EC2InstanceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EC2InstanceType {
+    fn from(value: String) -> EC2InstanceType {
+        match value.as_str() {
+            "t2.micro" => EC2InstanceType::T2Micro,
+            "t2.small" => EC2InstanceType::T2Small,
+            "t2.medium" => EC2InstanceType::T2Medium,
+            "t2.large" => EC2InstanceType::T2Large,
+            "c3.large" => EC2InstanceType::C3Large,
+            "c3.xlarge" => EC2InstanceType::C3Xlarge,
+            "c3.2xlarge" => EC2InstanceType::C3_2xlarge,
+            "c3.4xlarge" => EC2InstanceType::C3_4xlarge,
+            "c3.8xlarge" => EC2InstanceType::C3_8xlarge,
+            "c4.large" => EC2InstanceType::C4Large,
+            "c4.xlarge" => EC2InstanceType::C4Xlarge,
+            "c4.2xlarge" => EC2InstanceType::C4_2xlarge,
+            "c4.4xlarge" => EC2InstanceType::C4_4xlarge,
+            "c4.8xlarge" => EC2InstanceType::C4_8xlarge,
+            "c5.large" => EC2InstanceType::C5Large,
+            "c5.xlarge" => EC2InstanceType::C5Xlarge,
+            "c5.2xlarge" => EC2InstanceType::C5_2xlarge,
+            "c5.4xlarge" => EC2InstanceType::C5_4xlarge,
+            "c5.9xlarge" => EC2InstanceType::C5_9xlarge,
+            "c5.12xlarge" => EC2InstanceType::C5_12xlarge,
+            "c5.18xlarge" => EC2InstanceType::C5_18xlarge,
+            "c5.24xlarge" => EC2InstanceType::C5_24xlarge,
+            "c5a.large" => EC2InstanceType::C5aLarge,
+            "c5a.xlarge" => EC2InstanceType::C5aXlarge,
+            "c5a.2xlarge" => EC2InstanceType::C5a2xlarge,
+            "c5a.4xlarge" => EC2InstanceType::C5a4xlarge,
+            "c5a.8xlarge" => EC2InstanceType::C5a8xlarge,
+            "c5a.12xlarge" => EC2InstanceType::C5a12xlarge,
+            "c5a.16xlarge" => EC2InstanceType::C5a16xlarge,
+            "c5a.24xlarge" => EC2InstanceType::C5a24xlarge,
+            "r3.large" => EC2InstanceType::R3Large,
+            "r3.xlarge" => EC2InstanceType::R3Xlarge,
+            "r3.2xlarge" => EC2InstanceType::R3_2xlarge,
+            "r3.4xlarge" => EC2InstanceType::R3_4xlarge,
+            "r3.8xlarge" => EC2InstanceType::R3_8xlarge,
+            "r4.large" => EC2InstanceType::R4Large,
+            "r4.xlarge" => EC2InstanceType::R4Xlarge,
+            "r4.2xlarge" => EC2InstanceType::R4_2xlarge,
+            "r4.4xlarge" => EC2InstanceType::R4_4xlarge,
+            "r4.8xlarge" => EC2InstanceType::R4_8xlarge,
+            "r4.16xlarge" => EC2InstanceType::R4_16xlarge,
+            "r5.large" => EC2InstanceType::R5Large,
+            "r5.xlarge" => EC2InstanceType::R5Xlarge,
+            "r5.2xlarge" => EC2InstanceType::R5_2xlarge,
+            "r5.4xlarge" => EC2InstanceType::R5_4xlarge,
+            "r5.8xlarge" => EC2InstanceType::R5_8xlarge,
+            "r5.12xlarge" => EC2InstanceType::R5_12xlarge,
+            "r5.16xlarge" => EC2InstanceType::R5_16xlarge,
+            "r5.24xlarge" => EC2InstanceType::R5_24xlarge,
+            "r5a.large" => EC2InstanceType::R5aLarge,
+            "r5a.xlarge" => EC2InstanceType::R5aXlarge,
+            "r5a.2xlarge" => EC2InstanceType::R5a2xlarge,
+            "r5a.4xlarge" => EC2InstanceType::R5a4xlarge,
+            "r5a.8xlarge" => EC2InstanceType::R5a8xlarge,
+            "r5a.12xlarge" => EC2InstanceType::R5a12xlarge,
+            "r5a.16xlarge" => EC2InstanceType::R5a16xlarge,
+            "r5a.24xlarge" => EC2InstanceType::R5a24xlarge,
+            "m3.medium" => EC2InstanceType::M3Medium,
+            "m3.large" => EC2InstanceType::M3Large,
+            "m3.xlarge" => EC2InstanceType::M3Xlarge,
+            "m3.2xlarge" => EC2InstanceType::M3_2xlarge,
+            "m4.large" => EC2InstanceType::M4Large,
+            "m4.xlarge" => EC2InstanceType::M4Xlarge,
+            "m4.2xlarge" => EC2InstanceType::M4_2xlarge,
+            "m4.4xlarge" => EC2InstanceType::M4_4xlarge,
+            "m4.10xlarge" => EC2InstanceType::M4_10xlarge,
+            "m5.large" => EC2InstanceType::M5Large,
+            "m5.xlarge" => EC2InstanceType::M5Xlarge,
+            "m5.2xlarge" => EC2InstanceType::M5_2xlarge,
+            "m5.4xlarge" => EC2InstanceType::M5_4xlarge,
+            "m5.8xlarge" => EC2InstanceType::M5_8xlarge,
+            "m5.12xlarge" => EC2InstanceType::M5_12xlarge,
+            "m5.16xlarge" => EC2InstanceType::M5_16xlarge,
+            "m5.24xlarge" => EC2InstanceType::M5_24xlarge,
+            "m5a.large" => EC2InstanceType::M5aLarge,
+            "m5a.xlarge" => EC2InstanceType::M5aXlarge,
+            "m5a.2xlarge" => EC2InstanceType::M5a2xlarge,
+            "m5a.4xlarge" => EC2InstanceType::M5a4xlarge,
+            "m5a.8xlarge" => EC2InstanceType::M5a8xlarge,
+            "m5a.12xlarge" => EC2InstanceType::M5a12xlarge,
+            "m5a.16xlarge" => EC2InstanceType::M5a16xlarge,
+            "m5a.24xlarge" => EC2InstanceType::M5a24xlarge,
+            "c5d.large" => EC2InstanceType::C5dLarge,
+            "c5d.xlarge" => EC2InstanceType::C5dXlarge,
+            "c5d.2xlarge" => EC2InstanceType::C5d2xlarge,
+            "c5d.4xlarge" => EC2InstanceType::C5d4xlarge,
+            "c5d.9xlarge" => EC2InstanceType::C5d9xlarge,
+            "c5d.12xlarge" => EC2InstanceType::C5d12xlarge,
+            "c5d.18xlarge" => EC2InstanceType::C5d18xlarge,
+            "c5d.24xlarge" => EC2InstanceType::C5d24xlarge,
+            "c6a.large" => EC2InstanceType::C6aLarge,
+            "c6a.xlarge" => EC2InstanceType::C6aXlarge,
+            "c6a.2xlarge" => EC2InstanceType::C6a2xlarge,
+            "c6a.4xlarge" => EC2InstanceType::C6a4xlarge,
+            "c6a.8xlarge" => EC2InstanceType::C6a8xlarge,
+            "c6a.12xlarge" => EC2InstanceType::C6a12xlarge,
+            "c6a.16xlarge" => EC2InstanceType::C6a16xlarge,
+            "c6a.24xlarge" => EC2InstanceType::C6a24xlarge,
+            "c6i.large" => EC2InstanceType::C6iLarge,
+            "c6i.xlarge" => EC2InstanceType::C6iXlarge,
+            "c6i.2xlarge" => EC2InstanceType::C6i2xlarge,
+            "c6i.4xlarge" => EC2InstanceType::C6i4xlarge,
+            "c6i.8xlarge" => EC2InstanceType::C6i8xlarge,
+            "c6i.12xlarge" => EC2InstanceType::C6i12xlarge,
+            "c6i.16xlarge" => EC2InstanceType::C6i16xlarge,
+            "c6i.24xlarge" => EC2InstanceType::C6i24xlarge,
+            "r5d.large" => EC2InstanceType::R5dLarge,
+            "r5d.xlarge" => EC2InstanceType::R5dXlarge,
+            "r5d.2xlarge" => EC2InstanceType::R5d2xlarge,
+            "r5d.4xlarge" => EC2InstanceType::R5d4xlarge,
+            "r5d.8xlarge" => EC2InstanceType::R5d8xlarge,
+            "r5d.12xlarge" => EC2InstanceType::R5d12xlarge,
+            "r5d.16xlarge" => EC2InstanceType::R5d16xlarge,
+            "r5d.24xlarge" => EC2InstanceType::R5d24xlarge,
+            "m6g.medium" => EC2InstanceType::M6gMedium,
+            "m6g.large" => EC2InstanceType::M6gLarge,
+            "m6g.xlarge" => EC2InstanceType::M6gXlarge,
+            "m6g.2xlarge" => EC2InstanceType::M6g2xlarge,
+            "m6g.4xlarge" => EC2InstanceType::M6g4xlarge,
+            "m6g.8xlarge" => EC2InstanceType::M6g8xlarge,
+            "m6g.12xlarge" => EC2InstanceType::M6g12xlarge,
+            "m6g.16xlarge" => EC2InstanceType::M6g16xlarge,
+            "c6g.medium" => EC2InstanceType::C6gMedium,
+            "c6g.large" => EC2InstanceType::C6gLarge,
+            "c6g.xlarge" => EC2InstanceType::C6gXlarge,
+            "c6g.2xlarge" => EC2InstanceType::C6g2xlarge,
+            "c6g.4xlarge" => EC2InstanceType::C6g4xlarge,
+            "c6g.8xlarge" => EC2InstanceType::C6g8xlarge,
+            "c6g.12xlarge" => EC2InstanceType::C6g12xlarge,
+            "c6g.16xlarge" => EC2InstanceType::C6g16xlarge,
+            "r6g.medium" => EC2InstanceType::R6gMedium,
+            "r6g.large" => EC2InstanceType::R6gLarge,
+            "r6g.xlarge" => EC2InstanceType::R6gXlarge,
+            "r6g.2xlarge" => EC2InstanceType::R6g2xlarge,
+            "r6g.4xlarge" => EC2InstanceType::R6g4xlarge,
+            "r6g.8xlarge" => EC2InstanceType::R6g8xlarge,
+            "r6g.12xlarge" => EC2InstanceType::R6g12xlarge,
+            "r6g.16xlarge" => EC2InstanceType::R6g16xlarge,
+            "c6gn.medium" => EC2InstanceType::C6gnMedium,
+            "c6gn.large" => EC2InstanceType::C6gnLarge,
+            "c6gn.xlarge" => EC2InstanceType::C6gnXlarge,
+            "c6gn.2xlarge" => EC2InstanceType::C6gn2xlarge,
+            "c6gn.4xlarge" => EC2InstanceType::C6gn4xlarge,
+            "c6gn.8xlarge" => EC2InstanceType::C6gn8xlarge,
+            "c6gn.12xlarge" => EC2InstanceType::C6gn12xlarge,
+            "c6gn.16xlarge" => EC2InstanceType::C6gn16xlarge,
+            "c7g.medium" => EC2InstanceType::C7gMedium,
+            "c7g.large" => EC2InstanceType::C7gLarge,
+            "c7g.xlarge" => EC2InstanceType::C7gXlarge,
+            "c7g.2xlarge" => EC2InstanceType::C7g2xlarge,
+            "c7g.4xlarge" => EC2InstanceType::C7g4xlarge,
+            "c7g.8xlarge" => EC2InstanceType::C7g8xlarge,
+            "c7g.12xlarge" => EC2InstanceType::C7g12xlarge,
+            "c7g.16xlarge" => EC2InstanceType::C7g16xlarge,
+            "r7g.medium" => EC2InstanceType::R7gMedium,
+            "r7g.large" => EC2InstanceType::R7gLarge,
+            "r7g.xlarge" => EC2InstanceType::R7gXlarge,
+            "r7g.2xlarge" => EC2InstanceType::R7g2xlarge,
+            "r7g.4xlarge" => EC2InstanceType::R7g4xlarge,
+            "r7g.8xlarge" => EC2InstanceType::R7g8xlarge,
+            "r7g.12xlarge" => EC2InstanceType::R7g12xlarge,
+            "r7g.16xlarge" => EC2InstanceType::R7g16xlarge,
+            "m7g.medium" => EC2InstanceType::M7gMedium,
+            "m7g.large" => EC2InstanceType::M7gLarge,
+            "m7g.xlarge" => EC2InstanceType::M7gXlarge,
+            "m7g.2xlarge" => EC2InstanceType::M7g2xlarge,
+            "m7g.4xlarge" => EC2InstanceType::M7g4xlarge,
+            "m7g.8xlarge" => EC2InstanceType::M7g8xlarge,
+            "m7g.12xlarge" => EC2InstanceType::M7g12xlarge,
+            "m7g.16xlarge" => EC2InstanceType::M7g16xlarge,
+            "g5g.xlarge" => EC2InstanceType::G5gXlarge,
+            "g5g.2xlarge" => EC2InstanceType::G5g2xlarge,
+            "g5g.4xlarge" => EC2InstanceType::G5g4xlarge,
+            "g5g.8xlarge" => EC2InstanceType::G5g8xlarge,
+            "g5g.16xlarge" => EC2InstanceType::G5g16xlarge,
+            _ => EC2InstanceType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EC2InstanceType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EC2InstanceType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EC2InstanceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EC2InstanceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EC2InstanceType {
+    fn deserialize<D>(deserializer: D) -> Result<EC2InstanceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Log entry describing an event that involves Amazon GameLift resources (such as a fleet). In addition to tracking activity, event codes and messages can provide additional information for troubleshooting and debugging problems.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1448,15 +2917,20 @@ pub struct Event {
     /// <p><p>The type of event being logged. </p> <p> <b>Fleet creation events (ordered by fleet creation activity):</b> </p> <ul> <li> <p>FLEET<em>CREATED -- A fleet record was successfully created with a status of <code>NEW</code>. Event messaging includes the fleet ID.</p> </li> <li> <p>FLEET</em>STATE<em>DOWNLOADING -- Fleet status changed from <code>NEW</code> to <code>DOWNLOADING</code>. The compressed build has started downloading to a fleet instance for installation.</p> </li> <li> <p> FLEET</em>BINARY<em>DOWNLOAD</em>FAILED -- The build failed to download to the fleet instance.</p> </li> <li> <p>FLEET<em>CREATION</em>EXTRACTING<em>BUILD – The game server build was successfully downloaded to an instance, and the build files are now being extracted from the uploaded build and saved to an instance. Failure at this stage prevents a fleet from moving to <code>ACTIVE</code> status. Logs for this stage display a list of the files that are extracted and saved on the instance. Access the logs by using the URL in <i>PreSignedLogUrl</i>.</p> </li> <li> <p>FLEET</em>CREATION<em>RUNNING</em>INSTALLER – The game server build files were successfully extracted, and the Amazon GameLift is now running the build&#39;s install script (if one is included). Failure in this stage prevents a fleet from moving to <code>ACTIVE</code> status. Logs for this stage list the installation steps and whether or not the install completed successfully. Access the logs by using the URL in <i>PreSignedLogUrl</i>. </p> </li> <li> <p>FLEET<em>CREATION</em>VALIDATING<em>RUNTIME</em>CONFIG -- The build process was successful, and the Amazon GameLift is now verifying that the game server launch paths, which are specified in the fleet&#39;s runtime configuration, exist. If any listed launch path exists, Amazon GameLift tries to launch a game server process and waits for the process to report ready. Failures in this stage prevent a fleet from moving to <code>ACTIVE</code> status. Logs for this stage list the launch paths in the runtime configuration and indicate whether each is found. Access the logs by using the URL in <i>PreSignedLogUrl</i>. </p> </li> <li> <p>FLEET<em>STATE</em>VALIDATING -- Fleet status changed from <code>DOWNLOADING</code> to <code>VALIDATING</code>.</p> </li> <li> <p> FLEET<em>VALIDATION</em>LAUNCH<em>PATH</em>NOT<em>FOUND -- Validation of the runtime configuration failed because the executable specified in a launch path does not exist on the instance.</p> </li> <li> <p>FLEET</em>STATE<em>BUILDING -- Fleet status changed from <code>VALIDATING</code> to <code>BUILDING</code>.</p> </li> <li> <p>FLEET</em>VALIDATION<em>EXECUTABLE</em>RUNTIME<em>FAILURE -- Validation of the runtime configuration failed because the executable specified in a launch path failed to run on the fleet instance.</p> </li> <li> <p>FLEET</em>STATE<em>ACTIVATING -- Fleet status changed from <code>BUILDING</code> to <code>ACTIVATING</code>. </p> </li> <li> <p> FLEET</em>ACTIVATION<em>FAILED - The fleet failed to successfully complete one of the steps in the fleet activation process. This event code indicates that the game build was successfully downloaded to a fleet instance, built, and validated, but was not able to start a server process. Learn more at <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-creating-debug.html#fleets-creating-debug-creation"> Debug Fleet Creation Issues</a> </p> </li> <li> <p>FLEET</em>STATE<em>ACTIVE -- The fleet&#39;s status changed from <code>ACTIVATING</code> to <code>ACTIVE</code>. The fleet is now ready to host game sessions.</p> </li> </ul> <p> <b>VPC peering events:</b> </p> <ul> <li> <p>FLEET</em>VPC<em>PEERING</em>SUCCEEDED -- A VPC peering connection has been established between the VPC for an Amazon GameLift fleet and a VPC in your AWS account.</p> </li> <li> <p>FLEET<em>VPC</em>PEERING<em>FAILED -- A requested VPC peering connection has failed. Event details and status information (see <a>DescribeVpcPeeringConnections</a>) provide additional detail. A common reason for peering failure is that the two VPCs have overlapping CIDR blocks of IPv4 addresses. To resolve this, change the CIDR block for the VPC in your AWS account. For more information on VPC peering failures, see <a href="https://docs.aws.amazon.com/AmazonVPC/latest/PeeringGuide/invalid-peering-configurations.html">https://docs.aws.amazon.com/AmazonVPC/latest/PeeringGuide/invalid-peering-configurations.html</a> </p> </li> <li> <p>FLEET</em>VPC<em>PEERING</em>DELETED -- A VPC peering connection has been successfully deleted.</p> </li> </ul> <p> <b>Spot instance events:</b> </p> <ul> <li> <p> INSTANCE<em>INTERRUPTED -- A spot instance was interrupted by EC2 with a two-minute notification.</p> </li> </ul> <p> <b>Other fleet events:</b> </p> <ul> <li> <p>FLEET</em>SCALING<em>EVENT -- A change was made to the fleet&#39;s capacity settings (desired instances, minimum/maximum scaling limits). Event messaging includes the new capacity settings.</p> </li> <li> <p>FLEET</em>NEW<em>GAME</em>SESSION<em>PROTECTION</em>POLICY<em>UPDATED -- A change was made to the fleet&#39;s game session protection policy setting. Event messaging includes both the old and new policy setting. </p> </li> <li> <p>FLEET</em>DELETED -- A request to delete a fleet was initiated.</p> </li> <li> <p> GENERIC_EVENT -- An unspecified event has occurred.</p> </li> </ul></p>
     #[serde(rename = "EventCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub event_code: Option<String>,
+    pub event_code: Option<EventCode>,
     /// <p>A unique identifier for a fleet event.</p>
     #[serde(rename = "EventId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub event_id: Option<String>,
     /// <p>Time stamp indicating when this event occurred. Format is a number expressed in Unix time as milliseconds (for example "1469498468.057").</p>
     #[serde(rename = "EventTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub event_time: Option<f64>,
+    pub event_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Additional information related to the event.</p>
     #[serde(rename = "Message")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1471,29 +2945,405 @@ pub struct Event {
     pub resource_id: Option<String>,
 }
 
-/// <p><p>General properties describing a fleet.</p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct FleetAttributes {
-    /// <p> The Amazon Resource Name (<a href="https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html">ARN</a>) associated with the GameLift build resource that is deployed on instances in this fleet. In a GameLift build ARN, the resource ID matches the <i>BuildId</i> value.</p>
-    #[serde(rename = "BuildArn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub build_arn: Option<String>,
-    /// <p>A unique identifier for a build.</p>
-    #[serde(rename = "BuildId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub build_id: Option<String>,
-    /// <p>Indicates whether a TLS/SSL certificate was generated for the fleet. </p>
-    #[serde(rename = "CertificateConfiguration")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub certificate_configuration: Option<CertificateConfiguration>,
-    /// <p>Time stamp indicating when this data object was created. Format is a number expressed in Unix time as milliseconds (for example "1469498468.057").</p>
-    #[serde(rename = "CreationTime")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
-    /// <p>Human-readable description of the fleet.</p>
-    #[serde(rename = "Description")]
-    #[serde(skip_serializing_if = "Option::is_none")]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EventCode {
+    /// `GENERIC_EVENT`
+    GenericEvent,
+    /// `FLEET_CREATED`
+    FleetCreated,
+    /// `FLEET_DELETED`
+    FleetDeleted,
+    /// `FLEET_SCALING_EVENT`
+    FleetScalingEvent,
+    /// `FLEET_STATE_DOWNLOADING`
+    FleetStateDownloading,
+    /// `FLEET_STATE_VALIDATING`
+    FleetStateValidating,
+    /// `FLEET_STATE_BUILDING`
+    FleetStateBuilding,
+    /// `FLEET_STATE_ACTIVATING`
+    FleetStateActivating,
+    /// `FLEET_STATE_ACTIVE`
+    FleetStateActive,
+    /// `FLEET_STATE_ERROR`
+    FleetStateError,
+    /// `FLEET_INITIALIZATION_FAILED`
+    FleetInitializationFailed,
+    /// `FLEET_BINARY_DOWNLOAD_FAILED`
+    FleetBinaryDownloadFailed,
+    /// `FLEET_VALIDATION_LAUNCH_PATH_NOT_FOUND`
+    FleetValidationLaunchPathNotFound,
+    /// `FLEET_VALIDATION_EXECUTABLE_RUNTIME_FAILURE`
+    FleetValidationExecutableRuntimeFailure,
+    /// `FLEET_VALIDATION_TIMED_OUT`
+    FleetValidationTimedOut,
+    /// `FLEET_ACTIVATION_FAILED`
+    FleetActivationFailed,
+    /// `FLEET_ACTIVATION_FAILED_NO_INSTANCES`
+    FleetActivationFailedNoInstances,
+    /// `FLEET_NEW_GAME_SESSION_PROTECTION_POLICY_UPDATED`
+    FleetNewGameSessionProtectionPolicyUpdated,
+    /// `SERVER_PROCESS_INVALID_PATH`
+    ServerProcessInvalidPath,
+    /// `SERVER_PROCESS_SDK_INITIALIZATION_TIMEOUT`
+    ServerProcessSdkInitializationTimeout,
+    /// `SERVER_PROCESS_PROCESS_READY_TIMEOUT`
+    ServerProcessProcessReadyTimeout,
+    /// `SERVER_PROCESS_CRASHED`
+    ServerProcessCrashed,
+    /// `SERVER_PROCESS_TERMINATED_UNHEALTHY`
+    ServerProcessTerminatedUnhealthy,
+    /// `SERVER_PROCESS_FORCE_TERMINATED`
+    ServerProcessForceTerminated,
+    /// `SERVER_PROCESS_PROCESS_EXIT_TIMEOUT`
+    ServerProcessProcessExitTimeout,
+    /// `GAME_SESSION_ACTIVATION_TIMEOUT`
+    GameSessionActivationTimeout,
+    /// `FLEET_CREATION_EXTRACTING_BUILD`
+    FleetCreationExtractingBuild,
+    /// `FLEET_CREATION_RUNNING_INSTALLER`
+    FleetCreationRunningInstaller,
+    /// `FLEET_CREATION_VALIDATING_RUNTIME_CONFIG`
+    FleetCreationValidatingRuntimeConfig,
+    /// `FLEET_VPC_PEERING_SUCCEEDED`
+    FleetVpcPeeringSucceeded,
+    /// `FLEET_VPC_PEERING_FAILED`
+    FleetVpcPeeringFailed,
+    /// `FLEET_VPC_PEERING_DELETED`
+    FleetVpcPeeringDeleted,
+    /// `INSTANCE_INTERRUPTED`
+    InstanceInterrupted,
+    /// `INSTANCE_RECYCLED`
+    InstanceRecycled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EventCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EventCode::GenericEvent => "GENERIC_EVENT",
+            EventCode::FleetCreated => "FLEET_CREATED",
+            EventCode::FleetDeleted => "FLEET_DELETED",
+            EventCode::FleetScalingEvent => "FLEET_SCALING_EVENT",
+            EventCode::FleetStateDownloading => "FLEET_STATE_DOWNLOADING",
+            EventCode::FleetStateValidating => "FLEET_STATE_VALIDATING",
+            EventCode::FleetStateBuilding => "FLEET_STATE_BUILDING",
+            EventCode::FleetStateActivating => "FLEET_STATE_ACTIVATING",
+            EventCode::FleetStateActive => "FLEET_STATE_ACTIVE",
+            EventCode::FleetStateError => "FLEET_STATE_ERROR",
+            EventCode::FleetInitializationFailed => "FLEET_INITIALIZATION_FAILED",
+            EventCode::FleetBinaryDownloadFailed => "FLEET_BINARY_DOWNLOAD_FAILED",
+            EventCode::FleetValidationLaunchPathNotFound => {
+                "FLEET_VALIDATION_LAUNCH_PATH_NOT_FOUND"
+            }
+            EventCode::FleetValidationExecutableRuntimeFailure => {
+                "FLEET_VALIDATION_EXECUTABLE_RUNTIME_FAILURE"
+            }
+            EventCode::FleetValidationTimedOut => "FLEET_VALIDATION_TIMED_OUT",
+            EventCode::FleetActivationFailed => "FLEET_ACTIVATION_FAILED",
+            EventCode::FleetActivationFailedNoInstances => "FLEET_ACTIVATION_FAILED_NO_INSTANCES",
+            EventCode::FleetNewGameSessionProtectionPolicyUpdated => {
+                "FLEET_NEW_GAME_SESSION_PROTECTION_POLICY_UPDATED"
+            }
+            EventCode::ServerProcessInvalidPath => "SERVER_PROCESS_INVALID_PATH",
+            EventCode::ServerProcessSdkInitializationTimeout => {
+                "SERVER_PROCESS_SDK_INITIALIZATION_TIMEOUT"
+            }
+            EventCode::ServerProcessProcessReadyTimeout => "SERVER_PROCESS_PROCESS_READY_TIMEOUT",
+            EventCode::ServerProcessCrashed => "SERVER_PROCESS_CRASHED",
+            EventCode::ServerProcessTerminatedUnhealthy => "SERVER_PROCESS_TERMINATED_UNHEALTHY",
+            EventCode::ServerProcessForceTerminated => "SERVER_PROCESS_FORCE_TERMINATED",
+            EventCode::ServerProcessProcessExitTimeout => "SERVER_PROCESS_PROCESS_EXIT_TIMEOUT",
+            EventCode::GameSessionActivationTimeout => "GAME_SESSION_ACTIVATION_TIMEOUT",
+            EventCode::FleetCreationExtractingBuild => "FLEET_CREATION_EXTRACTING_BUILD",
+            EventCode::FleetCreationRunningInstaller => "FLEET_CREATION_RUNNING_INSTALLER",
+            EventCode::FleetCreationValidatingRuntimeConfig => {
+                "FLEET_CREATION_VALIDATING_RUNTIME_CONFIG"
+            }
+            EventCode::FleetVpcPeeringSucceeded => "FLEET_VPC_PEERING_SUCCEEDED",
+            EventCode::FleetVpcPeeringFailed => "FLEET_VPC_PEERING_FAILED",
+            EventCode::FleetVpcPeeringDeleted => "FLEET_VPC_PEERING_DELETED",
+            EventCode::InstanceInterrupted => "INSTANCE_INTERRUPTED",
+            EventCode::InstanceRecycled => "INSTANCE_RECYCLED",
+            EventCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EventCode {
+    fn default() -> EventCode {
+        EventCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EventCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EventCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EventCode {
+    fn from(value: &'a str) -> EventCode {
+        match value {
+            "GENERIC_EVENT" => EventCode::GenericEvent,
+            "FLEET_CREATED" => EventCode::FleetCreated,
+            "FLEET_DELETED" => EventCode::FleetDeleted,
+            "FLEET_SCALING_EVENT" => EventCode::FleetScalingEvent,
+            "FLEET_STATE_DOWNLOADING" => EventCode::FleetStateDownloading,
+            "FLEET_STATE_VALIDATING" => EventCode::FleetStateValidating,
+            "FLEET_STATE_BUILDING" => EventCode::FleetStateBuilding,
+            "FLEET_STATE_ACTIVATING" => EventCode::FleetStateActivating,
+            "FLEET_STATE_ACTIVE" => EventCode::FleetStateActive,
+            "FLEET_STATE_ERROR" => EventCode::FleetStateError,
+            "FLEET_INITIALIZATION_FAILED" => EventCode::FleetInitializationFailed,
+            "FLEET_BINARY_DOWNLOAD_FAILED" => EventCode::FleetBinaryDownloadFailed,
+            "FLEET_VALIDATION_LAUNCH_PATH_NOT_FOUND" => {
+                EventCode::FleetValidationLaunchPathNotFound
+            }
+            "FLEET_VALIDATION_EXECUTABLE_RUNTIME_FAILURE" => {
+                EventCode::FleetValidationExecutableRuntimeFailure
+            }
+            "FLEET_VALIDATION_TIMED_OUT" => EventCode::FleetValidationTimedOut,
+            "FLEET_ACTIVATION_FAILED" => EventCode::FleetActivationFailed,
+            "FLEET_ACTIVATION_FAILED_NO_INSTANCES" => EventCode::FleetActivationFailedNoInstances,
+            "FLEET_NEW_GAME_SESSION_PROTECTION_POLICY_UPDATED" => {
+                EventCode::FleetNewGameSessionProtectionPolicyUpdated
+            }
+            "SERVER_PROCESS_INVALID_PATH" => EventCode::ServerProcessInvalidPath,
+            "SERVER_PROCESS_SDK_INITIALIZATION_TIMEOUT" => {
+                EventCode::ServerProcessSdkInitializationTimeout
+            }
+            "SERVER_PROCESS_PROCESS_READY_TIMEOUT" => EventCode::ServerProcessProcessReadyTimeout,
+            "SERVER_PROCESS_CRASHED" => EventCode::ServerProcessCrashed,
+            "SERVER_PROCESS_TERMINATED_UNHEALTHY" => EventCode::ServerProcessTerminatedUnhealthy,
+            "SERVER_PROCESS_FORCE_TERMINATED" => EventCode::ServerProcessForceTerminated,
+            "SERVER_PROCESS_PROCESS_EXIT_TIMEOUT" => EventCode::ServerProcessProcessExitTimeout,
+            "GAME_SESSION_ACTIVATION_TIMEOUT" => EventCode::GameSessionActivationTimeout,
+            "FLEET_CREATION_EXTRACTING_BUILD" => EventCode::FleetCreationExtractingBuild,
+            "FLEET_CREATION_RUNNING_INSTALLER" => EventCode::FleetCreationRunningInstaller,
+            "FLEET_CREATION_VALIDATING_RUNTIME_CONFIG" => {
+                EventCode::FleetCreationValidatingRuntimeConfig
+            }
+            "FLEET_VPC_PEERING_SUCCEEDED" => EventCode::FleetVpcPeeringSucceeded,
+            "FLEET_VPC_PEERING_FAILED" => EventCode::FleetVpcPeeringFailed,
+            "FLEET_VPC_PEERING_DELETED" => EventCode::FleetVpcPeeringDeleted,
+            "INSTANCE_INTERRUPTED" => EventCode::InstanceInterrupted,
+            "INSTANCE_RECYCLED" => EventCode::InstanceRecycled,
+            _ => EventCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EventCode {
+    fn from(value: String) -> EventCode {
+        match value.as_str() {
+            "GENERIC_EVENT" => EventCode::GenericEvent,
+            "FLEET_CREATED" => EventCode::FleetCreated,
+            "FLEET_DELETED" => EventCode::FleetDeleted,
+            "FLEET_SCALING_EVENT" => EventCode::FleetScalingEvent,
+            "FLEET_STATE_DOWNLOADING" => EventCode::FleetStateDownloading,
+            "FLEET_STATE_VALIDATING" => EventCode::FleetStateValidating,
+            "FLEET_STATE_BUILDING" => EventCode::FleetStateBuilding,
+            "FLEET_STATE_ACTIVATING" => EventCode::FleetStateActivating,
+            "FLEET_STATE_ACTIVE" => EventCode::FleetStateActive,
+            "FLEET_STATE_ERROR" => EventCode::FleetStateError,
+            "FLEET_INITIALIZATION_FAILED" => EventCode::FleetInitializationFailed,
+            "FLEET_BINARY_DOWNLOAD_FAILED" => EventCode::FleetBinaryDownloadFailed,
+            "FLEET_VALIDATION_LAUNCH_PATH_NOT_FOUND" => {
+                EventCode::FleetValidationLaunchPathNotFound
+            }
+            "FLEET_VALIDATION_EXECUTABLE_RUNTIME_FAILURE" => {
+                EventCode::FleetValidationExecutableRuntimeFailure
+            }
+            "FLEET_VALIDATION_TIMED_OUT" => EventCode::FleetValidationTimedOut,
+            "FLEET_ACTIVATION_FAILED" => EventCode::FleetActivationFailed,
+            "FLEET_ACTIVATION_FAILED_NO_INSTANCES" => EventCode::FleetActivationFailedNoInstances,
+            "FLEET_NEW_GAME_SESSION_PROTECTION_POLICY_UPDATED" => {
+                EventCode::FleetNewGameSessionProtectionPolicyUpdated
+            }
+            "SERVER_PROCESS_INVALID_PATH" => EventCode::ServerProcessInvalidPath,
+            "SERVER_PROCESS_SDK_INITIALIZATION_TIMEOUT" => {
+                EventCode::ServerProcessSdkInitializationTimeout
+            }
+            "SERVER_PROCESS_PROCESS_READY_TIMEOUT" => EventCode::ServerProcessProcessReadyTimeout,
+            "SERVER_PROCESS_CRASHED" => EventCode::ServerProcessCrashed,
+            "SERVER_PROCESS_TERMINATED_UNHEALTHY" => EventCode::ServerProcessTerminatedUnhealthy,
+            "SERVER_PROCESS_FORCE_TERMINATED" => EventCode::ServerProcessForceTerminated,
+            "SERVER_PROCESS_PROCESS_EXIT_TIMEOUT" => EventCode::ServerProcessProcessExitTimeout,
+            "GAME_SESSION_ACTIVATION_TIMEOUT" => EventCode::GameSessionActivationTimeout,
+            "FLEET_CREATION_EXTRACTING_BUILD" => EventCode::FleetCreationExtractingBuild,
+            "FLEET_CREATION_RUNNING_INSTALLER" => EventCode::FleetCreationRunningInstaller,
+            "FLEET_CREATION_VALIDATING_RUNTIME_CONFIG" => {
+                EventCode::FleetCreationValidatingRuntimeConfig
+            }
+            "FLEET_VPC_PEERING_SUCCEEDED" => EventCode::FleetVpcPeeringSucceeded,
+            "FLEET_VPC_PEERING_FAILED" => EventCode::FleetVpcPeeringFailed,
+            "FLEET_VPC_PEERING_DELETED" => EventCode::FleetVpcPeeringDeleted,
+            "INSTANCE_INTERRUPTED" => EventCode::InstanceInterrupted,
+            "INSTANCE_RECYCLED" => EventCode::InstanceRecycled,
+            _ => EventCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EventCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EventCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EventCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EventCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EventCode {
+    fn deserialize<D>(deserializer: D) -> Result<EventCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FleetAction {
+    /// `AUTO_SCALING`
+    AutoScaling,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl FleetAction {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FleetAction::AutoScaling => "AUTO_SCALING",
+            FleetAction::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for FleetAction {
+    fn default() -> FleetAction {
+        FleetAction::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for FleetAction {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FleetAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FleetAction {
+    fn from(value: &'a str) -> FleetAction {
+        match value {
+            "AUTO_SCALING" => FleetAction::AutoScaling,
+            _ => FleetAction::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FleetAction {
+    fn from(value: String) -> FleetAction {
+        match value.as_str() {
+            "AUTO_SCALING" => FleetAction::AutoScaling,
+            _ => FleetAction::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for FleetAction {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<FleetAction, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FleetAction {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FleetAction {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FleetAction {
+    fn deserialize<D>(deserializer: D) -> Result<FleetAction, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p><p>General properties describing a fleet.</p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct FleetAttributes {
+    /// <p> The Amazon Resource Name (<a href="https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html">ARN</a>) associated with the GameLift build resource that is deployed on instances in this fleet. In a GameLift build ARN, the resource ID matches the <i>BuildId</i> value.</p>
+    #[serde(rename = "BuildArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub build_arn: Option<String>,
+    /// <p>A unique identifier for a build.</p>
+    #[serde(rename = "BuildId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub build_id: Option<String>,
+    /// <p>Indicates whether a TLS/SSL certificate was generated for the fleet. </p>
+    #[serde(rename = "CertificateConfiguration")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub certificate_configuration: Option<CertificateConfiguration>,
+    /// <p>Time stamp indicating when this data object was created. Format is a number expressed in Unix time as milliseconds (for example "1469498468.057").</p>
+    #[serde(rename = "CreationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
+    /// <p>Human-readable description of the fleet.</p>
+    #[serde(rename = "Description")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     /// <p>The Amazon Resource Name (<a href="https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html">ARN</a>) that is assigned to a GameLift fleet resource and uniquely identifies it. ARNs are unique across all Regions. In a GameLift fleet ARN, the resource ID matches the <i>FleetId</i> value.</p>
     #[serde(rename = "FleetArn")]
@@ -1506,7 +3356,7 @@ pub struct FleetAttributes {
     /// <p>Indicates whether the fleet uses on-demand or spot instances. A spot instance in use may be interrupted with a two-minute notification.</p>
     #[serde(rename = "FleetType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub fleet_type: Option<String>,
+    pub fleet_type: Option<FleetType>,
     /// <p>A unique identifier for an AWS IAM role that manages access to your AWS services. With an instance role ARN set, any application that runs on an instance in this fleet can assume the role, including install scripts, server processes, and daemons (background processes). Create a role or look up a role's ARN from the <a href="https://console.aws.amazon.com/iam/">IAM dashboard</a> in the AWS Management Console. Learn more about using on-box credentials for your game servers at <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/gamelift-sdk-server-resources.html"> Access external resources from a game server</a>.</p>
     #[serde(rename = "InstanceRoleArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1514,7 +3364,7 @@ pub struct FleetAttributes {
     /// <p>EC2 instance type indicating the computing resources of each instance in the fleet, including CPU, memory, storage, and networking capacity. See <a href="http://aws.amazon.com/ec2/instance-types/">Amazon EC2 Instance Types</a> for detailed descriptions.</p>
     #[serde(rename = "InstanceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub instance_type: Option<String>,
+    pub instance_type: Option<EC2InstanceType>,
     /// <p>Location of default log files. When a server process is shut down, Amazon GameLift captures and stores any log files in this location. These logs are in addition to game session logs; see more on game session logs in the <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/gamelift-sdk-server-api.html#gamelift-sdk-server-api-server-code">Amazon GameLift Developer Guide</a>. If no default log path for a fleet is specified, Amazon GameLift automatically uploads logs that are stored on each instance at <code>C:\game\logs</code> (for Windows) or <code>/local/game/logs</code> (for Linux). Use the Amazon GameLift console to access stored logs. </p>
     #[serde(rename = "LogPaths")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1530,11 +3380,11 @@ pub struct FleetAttributes {
     /// <p><p>The type of game session protection to set for all new instances started in the fleet.</p> <ul> <li> <p> <b>NoProtection</b> -- The game session can be terminated during a scale-down event.</p> </li> <li> <p> <b>FullProtection</b> -- If the game session is in an <code>ACTIVE</code> status, it cannot be terminated during a scale-down event.</p> </li> </ul></p>
     #[serde(rename = "NewGameSessionProtectionPolicy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub new_game_session_protection_policy: Option<String>,
+    pub new_game_session_protection_policy: Option<ProtectionPolicy>,
     /// <p>Operating system of the fleet's computing resources. A fleet's operating system depends on the OS specified for the build that is deployed on this fleet.</p>
     #[serde(rename = "OperatingSystem")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub operating_system: Option<String>,
+    pub operating_system: Option<OperatingSystem>,
     /// <p>Fleet policy to limit the number of game sessions an individual player can create over a span of time.</p>
     #[serde(rename = "ResourceCreationLimitPolicy")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1558,15 +3408,20 @@ pub struct FleetAttributes {
     /// <p><p>Current status of the fleet.</p> <p>Possible fleet statuses include the following:</p> <ul> <li> <p> <b>NEW</b> -- A new fleet has been defined and desired instances is set to 1. </p> </li> <li> <p> <b>DOWNLOADING/VALIDATING/BUILDING/ACTIVATING</b> -- Amazon GameLift is setting up the new fleet, creating new instances with the game build or Realtime script and starting server processes.</p> </li> <li> <p> <b>ACTIVE</b> -- Hosts can now accept game sessions.</p> </li> <li> <p> <b>ERROR</b> -- An error occurred when downloading, validating, building, or activating the fleet.</p> </li> <li> <p> <b>DELETING</b> -- Hosts are responding to a delete fleet request.</p> </li> <li> <p> <b>TERMINATED</b> -- The fleet no longer exists.</p> </li> </ul></p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<FleetStatus>,
     /// <p>List of fleet actions that have been suspended using <a>StopFleetActions</a>. This includes auto-scaling.</p>
     #[serde(rename = "StoppedActions")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stopped_actions: Option<Vec<String>>,
+    pub stopped_actions: Option<Vec<FleetAction>>,
     /// <p>Time stamp indicating when this data object was terminated. Format is a number expressed in Unix time as milliseconds (for example "1469498468.057").</p>
     #[serde(rename = "TerminationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub termination_time: Option<f64>,
+    pub termination_time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p><p>Information about the fleet&#39;s capacity. Fleet capacity is measured in EC2 instances. By default, new fleets have a capacity of one instance, but can be updated as needed. The maximum number of instances for a fleet is determined by the fleet&#39;s instance type.</p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
@@ -1584,9 +3439,235 @@ pub struct FleetCapacity {
     /// <p>Name of an EC2 instance type that is supported in Amazon GameLift. A fleet instance type determines the computing resources of each instance in the fleet, including CPU, memory, storage, and networking capacity. Amazon GameLift supports the following EC2 instance types. See <a href="http://aws.amazon.com/ec2/instance-types/">Amazon EC2 Instance Types</a> for detailed descriptions.</p>
     #[serde(rename = "InstanceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub instance_type: Option<String>,
+    pub instance_type: Option<EC2InstanceType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FleetStatus {
+    /// `NEW`
+    New,
+    /// `DOWNLOADING`
+    Downloading,
+    /// `VALIDATING`
+    Validating,
+    /// `BUILDING`
+    Building,
+    /// `ACTIVATING`
+    Activating,
+    /// `ACTIVE`
+    Active,
+    /// `DELETING`
+    Deleting,
+    /// `ERROR`
+    Error,
+    /// `TERMINATED`
+    Terminated,
+    /// `NOT_FOUND`
+    NotFound,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl FleetStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FleetStatus::New => "NEW",
+            FleetStatus::Downloading => "DOWNLOADING",
+            FleetStatus::Validating => "VALIDATING",
+            FleetStatus::Building => "BUILDING",
+            FleetStatus::Activating => "ACTIVATING",
+            FleetStatus::Active => "ACTIVE",
+            FleetStatus::Deleting => "DELETING",
+            FleetStatus::Error => "ERROR",
+            FleetStatus::Terminated => "TERMINATED",
+            FleetStatus::NotFound => "NOT_FOUND",
+            FleetStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for FleetStatus {
+    fn default() -> FleetStatus {
+        FleetStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for FleetStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FleetStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FleetStatus {
+    fn from(value: &'a str) -> FleetStatus {
+        match value {
+            "NEW" => FleetStatus::New,
+            "DOWNLOADING" => FleetStatus::Downloading,
+            "VALIDATING" => FleetStatus::Validating,
+            "BUILDING" => FleetStatus::Building,
+            "ACTIVATING" => FleetStatus::Activating,
+            "ACTIVE" => FleetStatus::Active,
+            "DELETING" => FleetStatus::Deleting,
+            "ERROR" => FleetStatus::Error,
+            "TERMINATED" => FleetStatus::Terminated,
+            "NOT_FOUND" => FleetStatus::NotFound,
+            _ => FleetStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FleetStatus {
+    fn from(value: String) -> FleetStatus {
+        match value.as_str() {
+            "NEW" => FleetStatus::New,
+            "DOWNLOADING" => FleetStatus::Downloading,
+            "VALIDATING" => FleetStatus::Validating,
+            "BUILDING" => FleetStatus::Building,
+            "ACTIVATING" => FleetStatus::Activating,
+            "ACTIVE" => FleetStatus::Active,
+            "DELETING" => FleetStatus::Deleting,
+            "ERROR" => FleetStatus::Error,
+            "TERMINATED" => FleetStatus::Terminated,
+            "NOT_FOUND" => FleetStatus::NotFound,
+            _ => FleetStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for FleetStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<FleetStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FleetStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FleetStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FleetStatus {
+    fn deserialize<D>(deserializer: D) -> Result<FleetStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FleetType {
+    /// `ON_DEMAND`
+    OnDemand,
+    /// `SPOT`
+    Spot,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl FleetType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FleetType::OnDemand => "ON_DEMAND",
+            FleetType::Spot => "SPOT",
+            FleetType::Unknown(ref value) => value,
+        }
+    }
 }
 
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for FleetType {
+    fn default() -> FleetType {
+        FleetType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for FleetType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FleetType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FleetType {
+    fn from(value: &'a str) -> FleetType {
+        match value {
+            "ON_DEMAND" => FleetType::OnDemand,
+            "SPOT" => FleetType::Spot,
+            _ => FleetType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FleetType {
+    fn from(value: String) -> FleetType {
+        match value.as_str() {
+            "ON_DEMAND" => FleetType::OnDemand,
+            "SPOT" => FleetType::Spot,
+            _ => FleetType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for FleetType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<FleetType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FleetType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FleetType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FleetType {
+    fn deserialize<D>(deserializer: D) -> Result<FleetType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p><p>Current status of fleet utilization, including the number of game and player sessions being hosted.</p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1630,8 +3711,13 @@ pub struct GameProperty {
 pub struct GameSession {
     /// <p>Time stamp indicating when this data object was created. Format is a number expressed in Unix time as milliseconds (for example "1469498468.057").</p>
     #[serde(rename = "CreationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A unique identifier for a player. This ID is used to enforce a resource protection policy (if one exists), that limits the number of game sessions a player can create.</p>
     #[serde(rename = "CreatorId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1683,7 +3769,7 @@ pub struct GameSession {
     /// <p>Indicates whether or not the game session is accepting new players.</p>
     #[serde(rename = "PlayerSessionCreationPolicy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub player_session_creation_policy: Option<String>,
+    pub player_session_creation_policy: Option<PlayerSessionCreationPolicy>,
     /// <p>Port number for the game session. To connect to a Amazon GameLift game server, an app needs both the IP address and port number.</p>
     #[serde(rename = "Port")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1691,15 +3777,20 @@ pub struct GameSession {
     /// <p>Current status of the game session. A game session must have an <code>ACTIVE</code> status to have player sessions.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<GameSessionStatus>,
     /// <p>Provides additional information about game session status. <code>INTERRUPTED</code> indicates that the game session was hosted on a spot instance that was reclaimed, causing the active game session to be terminated.</p>
     #[serde(rename = "StatusReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status_reason: Option<String>,
+    pub status_reason: Option<GameSessionStatusReason>,
     /// <p>Time stamp indicating when this data object was terminated. Format is a number expressed in Unix time as milliseconds (for example "1469498468.057").</p>
     #[serde(rename = "TerminationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub termination_time: Option<f64>,
+    pub termination_time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>Connection information for the new game session that is created with matchmaking. (with <a>StartMatchmaking</a>). Once a match is set, the FlexMatch engine places the match and creates a new game session for it. This information, including the game session endpoint and player sessions for each player in the original matchmaking request, is added to the <a>MatchmakingTicket</a>, which can be retrieved by calling <a>DescribeMatchmaking</a>.</p>
@@ -1739,7 +3830,7 @@ pub struct GameSessionDetail {
     /// <p><p>Current status of protection for the game session.</p> <ul> <li> <p> <b>NoProtection</b> -- The game session can be terminated during a scale-down event.</p> </li> <li> <p> <b>FullProtection</b> -- If the game session is in an <code>ACTIVE</code> status, it cannot be terminated during a scale-down event.</p> </li> </ul></p>
     #[serde(rename = "ProtectionPolicy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub protection_policy: Option<String>,
+    pub protection_policy: Option<ProtectionPolicy>,
 }
 
 /// <p><p>Object that describes a <a>StartGameSessionPlacement</a> request. This object includes the full details of the original request plus the current status and start/end time stamps.</p> <p>Game session placement-related operations include:</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul></p>
@@ -1752,8 +3843,13 @@ pub struct GameSessionPlacement {
     pub dns_name: Option<String>,
     /// <p>Time stamp indicating when this request was completed, canceled, or timed out.</p>
     #[serde(rename = "EndTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Set of custom properties for a game session, formatted as key:value pairs. These properties are passed to a game server process in the <a>GameSession</a> object with a request to start a new game session (see <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/gamelift-sdk-server-api.html#gamelift-sdk-server-startsession">Start a Game Session</a>).</p>
     #[serde(rename = "GameProperties")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1812,14 +3908,127 @@ pub struct GameSessionPlacement {
     pub port: Option<i64>,
     /// <p>Time stamp indicating when this request was placed in the queue. Format is a number expressed in Unix time as milliseconds (for example "1469498468.057").</p>
     #[serde(rename = "StartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>Current status of the game session placement request.</p> <ul> <li> <p> <b>PENDING</b> -- The placement request is currently in the queue waiting to be processed.</p> </li> <li> <p> <b>FULFILLED</b> -- A new game session and player sessions (if requested) have been successfully created. Values for <i>GameSessionArn</i> and <i>GameSessionRegion</i> are available. </p> </li> <li> <p> <b>CANCELLED</b> -- The placement request was canceled with a call to <a>StopGameSessionPlacement</a>.</p> </li> <li> <p> <b>TIMED_OUT</b> -- A new game session was not successfully created before the time limit expired. You can resubmit the placement request as needed.</p> </li> <li> <p> <b>FAILED</b> -- GameLift is not able to complete the process of placing the game session. Common reasons are the game session terminated before the placement process was completed, or an unexpected internal error.</p> </li> </ul></p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<GameSessionPlacementState>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum GameSessionPlacementState {
+    /// `PENDING`
+    Pending,
+    /// `FULFILLED`
+    Fulfilled,
+    /// `CANCELLED`
+    Cancelled,
+    /// `TIMED_OUT`
+    TimedOut,
+    /// `FAILED`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl GameSessionPlacementState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            GameSessionPlacementState::Pending => "PENDING",
+            GameSessionPlacementState::Fulfilled => "FULFILLED",
+            GameSessionPlacementState::Cancelled => "CANCELLED",
+            GameSessionPlacementState::TimedOut => "TIMED_OUT",
+            GameSessionPlacementState::Failed => "FAILED",
+            GameSessionPlacementState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for GameSessionPlacementState {
+    fn default() -> GameSessionPlacementState {
+        GameSessionPlacementState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for GameSessionPlacementState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for GameSessionPlacementState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for GameSessionPlacementState {
+    fn from(value: &'a str) -> GameSessionPlacementState {
+        match value {
+            "PENDING" => GameSessionPlacementState::Pending,
+            "FULFILLED" => GameSessionPlacementState::Fulfilled,
+            "CANCELLED" => GameSessionPlacementState::Cancelled,
+            "TIMED_OUT" => GameSessionPlacementState::TimedOut,
+            "FAILED" => GameSessionPlacementState::Failed,
+            _ => GameSessionPlacementState::Unknown(value.to_owned()),
+        }
+    }
 }
 
+impl From<String> for GameSessionPlacementState {
+    fn from(value: String) -> GameSessionPlacementState {
+        match value.as_str() {
+            "PENDING" => GameSessionPlacementState::Pending,
+            "FULFILLED" => GameSessionPlacementState::Fulfilled,
+            "CANCELLED" => GameSessionPlacementState::Cancelled,
+            "TIMED_OUT" => GameSessionPlacementState::TimedOut,
+            "FAILED" => GameSessionPlacementState::Failed,
+            _ => GameSessionPlacementState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for GameSessionPlacementState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<GameSessionPlacementState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for GameSessionPlacementState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for GameSessionPlacementState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for GameSessionPlacementState {
+    fn deserialize<D>(deserializer: D) -> Result<GameSessionPlacementState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p><p>Configuration of a queue that is used to process game session placement requests. The queue configuration identifies several game features:</p> <ul> <li> <p>The destinations where a new game session can potentially be hosted. Amazon GameLift tries these destinations in an order based on either the queue&#39;s default order or player latency information, if provided in a placement request. With latency information, Amazon GameLift can place game sessions where the majority of players are reporting the lowest possible latency. </p> </li> <li> <p>The length of time that placement requests can wait in the queue before timing out. </p> </li> <li> <p>A set of optional latency policies that protect individual players from high latencies, preventing game sessions from being placed where any individual player is reporting latency higher than a policy&#39;s maximum.</p> </li> </ul> <ul> <li> <p> <a>CreateGameSessionQueue</a> </p> </li> <li> <p> <a>DescribeGameSessionQueues</a> </p> </li> <li> <p> <a>UpdateGameSessionQueue</a> </p> </li> <li> <p> <a>DeleteGameSessionQueue</a> </p> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1855,17 +4064,213 @@ pub struct GameSessionQueueDestination {
     pub destination_arn: Option<String>,
 }
 
-/// <p>Represents the input for a request action.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct GetGameSessionLogUrlInput {
-    /// <p>A unique identifier for the game session to get logs for. </p>
-    #[serde(rename = "GameSessionId")]
-    pub game_session_id: String,
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum GameSessionStatus {
+    /// `ACTIVE`
+    Active,
+    /// `ACTIVATING`
+    Activating,
+    /// `TERMINATED`
+    Terminated,
+    /// `TERMINATING`
+    Terminating,
+    /// `ERROR`
+    Error,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl GameSessionStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            GameSessionStatus::Active => "ACTIVE",
+            GameSessionStatus::Activating => "ACTIVATING",
+            GameSessionStatus::Terminated => "TERMINATED",
+            GameSessionStatus::Terminating => "TERMINATING",
+            GameSessionStatus::Error => "ERROR",
+            GameSessionStatus::Unknown(ref value) => value,
+        }
+    }
 }
 
-/// <p>Represents the returned data in response to a request action.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for GameSessionStatus {
+    fn default() -> GameSessionStatus {
+        GameSessionStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for GameSessionStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for GameSessionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for GameSessionStatus {
+    fn from(value: &'a str) -> GameSessionStatus {
+        match value {
+            "ACTIVE" => GameSessionStatus::Active,
+            "ACTIVATING" => GameSessionStatus::Activating,
+            "TERMINATED" => GameSessionStatus::Terminated,
+            "TERMINATING" => GameSessionStatus::Terminating,
+            "ERROR" => GameSessionStatus::Error,
+            _ => GameSessionStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for GameSessionStatus {
+    fn from(value: String) -> GameSessionStatus {
+        match value.as_str() {
+            "ACTIVE" => GameSessionStatus::Active,
+            "ACTIVATING" => GameSessionStatus::Activating,
+            "TERMINATED" => GameSessionStatus::Terminated,
+            "TERMINATING" => GameSessionStatus::Terminating,
+            "ERROR" => GameSessionStatus::Error,
+            _ => GameSessionStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for GameSessionStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<GameSessionStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for GameSessionStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for GameSessionStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for GameSessionStatus {
+    fn deserialize<D>(deserializer: D) -> Result<GameSessionStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum GameSessionStatusReason {
+    /// `INTERRUPTED`
+    Interrupted,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl GameSessionStatusReason {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            GameSessionStatusReason::Interrupted => "INTERRUPTED",
+            GameSessionStatusReason::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for GameSessionStatusReason {
+    fn default() -> GameSessionStatusReason {
+        GameSessionStatusReason::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for GameSessionStatusReason {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for GameSessionStatusReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for GameSessionStatusReason {
+    fn from(value: &'a str) -> GameSessionStatusReason {
+        match value {
+            "INTERRUPTED" => GameSessionStatusReason::Interrupted,
+            _ => GameSessionStatusReason::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for GameSessionStatusReason {
+    fn from(value: String) -> GameSessionStatusReason {
+        match value.as_str() {
+            "INTERRUPTED" => GameSessionStatusReason::Interrupted,
+            _ => GameSessionStatusReason::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for GameSessionStatusReason {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<GameSessionStatusReason, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for GameSessionStatusReason {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for GameSessionStatusReason {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for GameSessionStatusReason {
+    fn deserialize<D>(deserializer: D) -> Result<GameSessionStatusReason, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Represents the input for a request action.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct GetGameSessionLogUrlInput {
+    /// <p>A unique identifier for the game session to get logs for. </p>
+    #[serde(rename = "GameSessionId")]
+    pub game_session_id: String,
+}
+
+/// <p>Represents the returned data in response to a request action.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetGameSessionLogUrlOutput {
     /// <p>Location of the requested game session logs, available for download. This URL is valid for 15 minutes, after which S3 will reject any download request using this URL. You can request a new URL any time within the 14-day period that the logs are retained.</p>
@@ -1902,8 +4307,13 @@ pub struct GetInstanceAccessOutput {
 pub struct Instance {
     /// <p>Time stamp indicating when this data object was created. Format is a number expressed in Unix time as milliseconds (for example "1469498468.057").</p>
     #[serde(rename = "CreationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>DNS identifier assigned to the instance that is running the game session. Values have the following format:</p> <ul> <li> <p>TLS-enabled fleets: <code>&lt;unique identifier&gt;.&lt;region identifier&gt;.amazongamelift.com</code>.</p> </li> <li> <p>Non-TLS-enabled fleets: <code>ec2-&lt;unique identifier&gt;.compute.amazonaws.com</code>. (See <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/using-instance-addressing.html#concepts-public-addresses">Amazon EC2 Instance IP Addressing</a>.)</p> </li> </ul> <p>When connecting to a game session that is running on a TLS-enabled fleet, you must use the DNS name, not the IP address.</p>
     #[serde(rename = "DnsName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1923,15 +4333,15 @@ pub struct Instance {
     /// <p>Operating system that is running on this instance. </p>
     #[serde(rename = "OperatingSystem")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub operating_system: Option<String>,
+    pub operating_system: Option<OperatingSystem>,
     /// <p><p>Current status of the instance. Possible statuses include the following:</p> <ul> <li> <p> <b>PENDING</b> -- The instance is in the process of being created and launching server processes as defined in the fleet&#39;s run-time configuration. </p> </li> <li> <p> <b>ACTIVE</b> -- The instance has been successfully created and at least one server process has successfully launched and reported back to Amazon GameLift that it is ready to host a game session. The instance is now considered ready to host game sessions. </p> </li> <li> <p> <b>TERMINATING</b> -- The instance is in the process of shutting down. This may happen to reduce capacity during a scaling down event or to recycle resources in the event of a problem.</p> </li> </ul></p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<InstanceStatus>,
     /// <p>EC2 instance type that defines the computing resources of this instance. </p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<EC2InstanceType>,
 }
 
 /// <p>Information required to remotely connect to a fleet instance. Access is requested by calling <a>GetInstanceAccess</a>. </p>
@@ -1957,7 +4367,7 @@ pub struct InstanceAccess {
     /// <p>Operating system that is running on the instance.</p>
     #[serde(rename = "OperatingSystem")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub operating_system: Option<String>,
+    pub operating_system: Option<OperatingSystem>,
 }
 
 /// <p>Set of credentials required to remotely access a fleet instance. Access credentials are requested by calling <a>GetInstanceAccess</a> and returned in an <a>InstanceAccess</a> object.</p>
@@ -1974,6 +4384,104 @@ pub struct InstanceCredentials {
     pub user_name: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum InstanceStatus {
+    /// `PENDING`
+    Pending,
+    /// `ACTIVE`
+    Active,
+    /// `TERMINATING`
+    Terminating,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl InstanceStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InstanceStatus::Pending => "PENDING",
+            InstanceStatus::Active => "ACTIVE",
+            InstanceStatus::Terminating => "TERMINATING",
+            InstanceStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for InstanceStatus {
+    fn default() -> InstanceStatus {
+        InstanceStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for InstanceStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for InstanceStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for InstanceStatus {
+    fn from(value: &'a str) -> InstanceStatus {
+        match value {
+            "PENDING" => InstanceStatus::Pending,
+            "ACTIVE" => InstanceStatus::Active,
+            "TERMINATING" => InstanceStatus::Terminating,
+            _ => InstanceStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InstanceStatus {
+    fn from(value: String) -> InstanceStatus {
+        match value.as_str() {
+            "PENDING" => InstanceStatus::Pending,
+            "ACTIVE" => InstanceStatus::Active,
+            "TERMINATING" => InstanceStatus::Terminating,
+            _ => InstanceStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for InstanceStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<InstanceStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for InstanceStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for InstanceStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InstanceStatus {
+    fn deserialize<D>(deserializer: D) -> Result<InstanceStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A range of IP addresses and port settings that allow inbound traffic to connect to server processes on an Amazon GameLift hosting resource. New game sessions that are started on the fleet are assigned an IP address/port number combination, which must fall into the fleet's allowed ranges. For fleets created with a custom game server, the ranges reflect the server's game session assignments. For Realtime Servers fleets, Amazon GameLift automatically opens two port ranges, one for TCP messaging and one for UDP for use by the Realtime servers.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct IpPermission {
@@ -1985,12 +4493,105 @@ pub struct IpPermission {
     pub ip_range: String,
     /// <p>The network communication protocol used by the fleet.</p>
     #[serde(rename = "Protocol")]
-    pub protocol: String,
+    pub protocol: IpProtocol,
     /// <p>An ending value for a range of allowed port numbers. Port numbers are end-inclusive. This value must be higher than <code>FromPort</code>.</p>
     #[serde(rename = "ToPort")]
     pub to_port: i64,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum IpProtocol {
+    /// `TCP`
+    Tcp,
+    /// `UDP`
+    Udp,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl IpProtocol {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            IpProtocol::Tcp => "TCP",
+            IpProtocol::Udp => "UDP",
+            IpProtocol::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for IpProtocol {
+    fn default() -> IpProtocol {
+        IpProtocol::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for IpProtocol {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for IpProtocol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for IpProtocol {
+    fn from(value: &'a str) -> IpProtocol {
+        match value {
+            "TCP" => IpProtocol::Tcp,
+            "UDP" => IpProtocol::Udp,
+            _ => IpProtocol::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for IpProtocol {
+    fn from(value: String) -> IpProtocol {
+        match value.as_str() {
+            "TCP" => IpProtocol::Tcp,
+            "UDP" => IpProtocol::Udp,
+            _ => IpProtocol::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for IpProtocol {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<IpProtocol, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for IpProtocol {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for IpProtocol {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for IpProtocol {
+    fn deserialize<D>(deserializer: D) -> Result<IpProtocol, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -2010,7 +4611,7 @@ pub struct ListAliasesInput {
     /// <p><p>The routing type to filter results on. Use this parameter to retrieve only aliases with a certain routing type. To retrieve all aliases, leave this parameter empty.</p> <p>Possible routing types include the following:</p> <ul> <li> <p> <b>SIMPLE</b> -- The alias resolves to one specific fleet. Use this type when routing to active fleets.</p> </li> <li> <p> <b>TERMINAL</b> -- The alias does not resolve to a fleet but instead can be used to display a message to the user. A terminal alias throws a TerminalRoutingStrategyException with the <a>RoutingStrategy</a> message embedded.</p> </li> </ul></p>
     #[serde(rename = "RoutingStrategyType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub routing_strategy_type: Option<String>,
+    pub routing_strategy_type: Option<RoutingStrategyType>,
 }
 
 /// <p>Represents the returned data in response to a request action.</p>
@@ -2042,7 +4643,7 @@ pub struct ListBuildsInput {
     /// <p><p>Build status to filter results by. To retrieve all builds, leave this parameter empty.</p> <p>Possible build statuses include the following:</p> <ul> <li> <p> <b>INITIALIZED</b> -- A new build has been defined, but no files have been uploaded. You cannot create fleets for builds that are in this status. When a build is successfully created, the build status is set to this value. </p> </li> <li> <p> <b>READY</b> -- The game build has been successfully uploaded. You can now create new fleets for this build.</p> </li> <li> <p> <b>FAILED</b> -- The game build upload failed. You cannot create new fleets for this build. </p> </li> </ul></p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<BuildStatus>,
 }
 
 /// <p>Represents the returned data in response to a request action.</p>
@@ -2171,15 +4772,20 @@ pub struct MatchmakingConfiguration {
     /// <p>The method used to backfill game sessions created with this matchmaking configuration. MANUAL indicates that the game makes backfill requests or does not use the match backfill feature. AUTOMATIC indicates that GameLift creates <a>StartMatchBackfill</a> requests whenever a game session has one or more open slots. Learn more about manual and automatic backfill in <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-backfill.html">Backfill Existing Games with FlexMatch</a>.</p>
     #[serde(rename = "BackfillMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub backfill_mode: Option<String>,
+    pub backfill_mode: Option<BackfillMode>,
     /// <p>Amazon Resource Name (<a href="https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html">ARN</a>) that is assigned to a GameLift matchmaking configuration resource and uniquely identifies it. ARNs are unique across all Regions. In a GameLift configuration ARN, the resource ID matches the <i>Name</i> value.</p>
     #[serde(rename = "ConfigurationArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub configuration_arn: Option<String>,
     /// <p>The time stamp indicating when this data object was created. The format is a number expressed in Unix time as milliseconds (for example "1469498468.057").</p>
     #[serde(rename = "CreationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Information to attach to all events related to the matchmaking configuration. </p>
     #[serde(rename = "CustomEventData")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2222,14 +4828,142 @@ pub struct MatchmakingConfiguration {
     pub rule_set_name: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MatchmakingConfigurationStatus {
+    /// `CANCELLED`
+    Cancelled,
+    /// `COMPLETED`
+    Completed,
+    /// `FAILED`
+    Failed,
+    /// `PLACING`
+    Placing,
+    /// `QUEUED`
+    Queued,
+    /// `REQUIRES_ACCEPTANCE`
+    RequiresAcceptance,
+    /// `SEARCHING`
+    Searching,
+    /// `TIMED_OUT`
+    TimedOut,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl MatchmakingConfigurationStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MatchmakingConfigurationStatus::Cancelled => "CANCELLED",
+            MatchmakingConfigurationStatus::Completed => "COMPLETED",
+            MatchmakingConfigurationStatus::Failed => "FAILED",
+            MatchmakingConfigurationStatus::Placing => "PLACING",
+            MatchmakingConfigurationStatus::Queued => "QUEUED",
+            MatchmakingConfigurationStatus::RequiresAcceptance => "REQUIRES_ACCEPTANCE",
+            MatchmakingConfigurationStatus::Searching => "SEARCHING",
+            MatchmakingConfigurationStatus::TimedOut => "TIMED_OUT",
+            MatchmakingConfigurationStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for MatchmakingConfigurationStatus {
+    fn default() -> MatchmakingConfigurationStatus {
+        MatchmakingConfigurationStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for MatchmakingConfigurationStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for MatchmakingConfigurationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for MatchmakingConfigurationStatus {
+    fn from(value: &'a str) -> MatchmakingConfigurationStatus {
+        match value {
+            "CANCELLED" => MatchmakingConfigurationStatus::Cancelled,
+            "COMPLETED" => MatchmakingConfigurationStatus::Completed,
+            "FAILED" => MatchmakingConfigurationStatus::Failed,
+            "PLACING" => MatchmakingConfigurationStatus::Placing,
+            "QUEUED" => MatchmakingConfigurationStatus::Queued,
+            "REQUIRES_ACCEPTANCE" => MatchmakingConfigurationStatus::RequiresAcceptance,
+            "SEARCHING" => MatchmakingConfigurationStatus::Searching,
+            "TIMED_OUT" => MatchmakingConfigurationStatus::TimedOut,
+            _ => MatchmakingConfigurationStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MatchmakingConfigurationStatus {
+    fn from(value: String) -> MatchmakingConfigurationStatus {
+        match value.as_str() {
+            "CANCELLED" => MatchmakingConfigurationStatus::Cancelled,
+            "COMPLETED" => MatchmakingConfigurationStatus::Completed,
+            "FAILED" => MatchmakingConfigurationStatus::Failed,
+            "PLACING" => MatchmakingConfigurationStatus::Placing,
+            "QUEUED" => MatchmakingConfigurationStatus::Queued,
+            "REQUIRES_ACCEPTANCE" => MatchmakingConfigurationStatus::RequiresAcceptance,
+            "SEARCHING" => MatchmakingConfigurationStatus::Searching,
+            "TIMED_OUT" => MatchmakingConfigurationStatus::TimedOut,
+            _ => MatchmakingConfigurationStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for MatchmakingConfigurationStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<MatchmakingConfigurationStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MatchmakingConfigurationStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for MatchmakingConfigurationStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MatchmakingConfigurationStatus {
+    fn deserialize<D>(deserializer: D) -> Result<MatchmakingConfigurationStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p><p>Set of rule statements, used with FlexMatch, that determine how to build your player matches. Each rule set describes a type of group to be created and defines the parameters for acceptable player matches. Rule sets are used in <a>MatchmakingConfiguration</a> objects.</p> <p>A rule set may define the following elements for a match. For detailed information and examples showing how to construct a rule set, see <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-rulesets.html">Build a FlexMatch Rule Set</a>. </p> <ul> <li> <p>Teams -- Required. A rule set must define one or multiple teams for the match and set minimum and maximum team sizes. For example, a rule set might describe a 4x4 match that requires all eight slots to be filled. </p> </li> <li> <p>Player attributes -- Optional. These attributes specify a set of player characteristics to evaluate when looking for a match. Matchmaking requests that use a rule set with player attributes must provide the corresponding attribute values. For example, an attribute might specify a player&#39;s skill or level.</p> </li> <li> <p>Rules -- Optional. Rules define how to evaluate potential players for a match based on player attributes. A rule might specify minimum requirements for individual players, teams, or entire matches. For example, a rule might require each player to meet a certain skill level, each team to have at least one player in a certain role, or the match to have a minimum average skill level. or may describe an entire group--such as all teams must be evenly matched or have at least one player in a certain role. </p> </li> <li> <p>Expansions -- Optional. Expansions allow you to relax the rules after a period of time when no acceptable matches are found. This feature lets you balance getting players into games in a reasonable amount of time instead of making them wait indefinitely for the best possible match. For example, you might use an expansion to increase the maximum skill variance between players after 30 seconds.</p> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct MatchmakingRuleSet {
     /// <p>The time stamp indicating when this data object was created. The format is a number expressed in Unix time as milliseconds (for example "1469498468.057").</p>
     #[serde(rename = "CreationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Amazon Resource Name (<a href="https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html">ARN</a>) that is assigned to a GameLift matchmaking rule set resource and uniquely identifies it. ARNs are unique across all Regions. In a GameLift rule set ARN, the resource ID matches the <i>RuleSetName</i> value.</p>
     #[serde(rename = "RuleSetArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2257,8 +4991,13 @@ pub struct MatchmakingTicket {
     pub configuration_name: Option<String>,
     /// <p>Time stamp indicating when this matchmaking request stopped being processed due to success, failure, or cancellation. Format is a number expressed in Unix time as milliseconds (for example "1469498468.057").</p>
     #[serde(rename = "EndTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Average amount of time (in seconds) that players are currently waiting for a match. If there is not enough recent data, this property may be empty.</p>
     #[serde(rename = "EstimatedWaitTime")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2273,12 +5012,17 @@ pub struct MatchmakingTicket {
     pub players: Option<Vec<Player>>,
     /// <p>Time stamp indicating when this matchmaking request was received. Format is a number expressed in Unix time as milliseconds (for example "1469498468.057").</p>
     #[serde(rename = "StartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>Current status of the matchmaking request.</p> <ul> <li> <p> <b>QUEUED</b> -- The matchmaking request has been received and is currently waiting to be processed.</p> </li> <li> <p> <b>SEARCHING</b> -- The matchmaking request is currently being processed. </p> </li> <li> <p> <b>REQUIRES<em>ACCEPTANCE</b> -- A match has been proposed and the players must accept the match (see <a>AcceptMatch</a>). This status is used only with requests that use a matchmaking configuration with a player acceptance requirement.</p> </li> <li> <p> <b>PLACING</b> -- The FlexMatch engine has matched players and is in the process of placing a new game session for the match.</p> </li> <li> <p> <b>COMPLETED</b> -- Players have been matched and a game session is ready to host the players. A ticket in this state contains the necessary connection information for players.</p> </li> <li> <p> <b>FAILED</b> -- The matchmaking request was not completed.</p> </li> <li> <p> <b>CANCELLED</b> -- The matchmaking request was canceled. This may be the result of a call to <a>StopMatchmaking</a> or a proposed match that one or more players failed to accept.</p> </li> <li> <p> <b>TIMED</em>OUT</b> -- The matchmaking request was not successful within the duration specified in the matchmaking configuration. </p> </li> </ul> <note> <p>Matchmaking requests that fail to successfully complete (statuses FAILED, CANCELLED, TIMED_OUT) can be resubmitted as new requests with new ticket IDs.</p> </note></p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<MatchmakingConfigurationStatus>,
     /// <p>Additional information about the current status.</p>
     #[serde(rename = "StatusMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2293,6 +5037,257 @@ pub struct MatchmakingTicket {
     pub ticket_id: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MetricName {
+    /// `ActivatingGameSessions`
+    ActivatingGameSessions,
+    /// `ActiveGameSessions`
+    ActiveGameSessions,
+    /// `ActiveInstances`
+    ActiveInstances,
+    /// `AvailableGameSessions`
+    AvailableGameSessions,
+    /// `AvailablePlayerSessions`
+    AvailablePlayerSessions,
+    /// `CurrentPlayerSessions`
+    CurrentPlayerSessions,
+    /// `IdleInstances`
+    IdleInstances,
+    /// `PercentAvailableGameSessions`
+    PercentAvailableGameSessions,
+    /// `PercentIdleInstances`
+    PercentIdleInstances,
+    /// `QueueDepth`
+    QueueDepth,
+    /// `WaitTime`
+    WaitTime,
+    /// `ConcurrentActivatableGameSessions`
+    ConcurrentActivatableGameSessions,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl MetricName {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MetricName::ActivatingGameSessions => "ActivatingGameSessions",
+            MetricName::ActiveGameSessions => "ActiveGameSessions",
+            MetricName::ActiveInstances => "ActiveInstances",
+            MetricName::AvailableGameSessions => "AvailableGameSessions",
+            MetricName::AvailablePlayerSessions => "AvailablePlayerSessions",
+            MetricName::CurrentPlayerSessions => "CurrentPlayerSessions",
+            MetricName::IdleInstances => "IdleInstances",
+            MetricName::PercentAvailableGameSessions => "PercentAvailableGameSessions",
+            MetricName::PercentIdleInstances => "PercentIdleInstances",
+            MetricName::QueueDepth => "QueueDepth",
+            MetricName::WaitTime => "WaitTime",
+            MetricName::ConcurrentActivatableGameSessions => "ConcurrentActivatableGameSessions",
+            MetricName::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for MetricName {
+    fn default() -> MetricName {
+        MetricName::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for MetricName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for MetricName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for MetricName {
+    fn from(value: &'a str) -> MetricName {
+        match value {
+            "ActivatingGameSessions" => MetricName::ActivatingGameSessions,
+            "ActiveGameSessions" => MetricName::ActiveGameSessions,
+            "ActiveInstances" => MetricName::ActiveInstances,
+            "AvailableGameSessions" => MetricName::AvailableGameSessions,
+            "AvailablePlayerSessions" => MetricName::AvailablePlayerSessions,
+            "CurrentPlayerSessions" => MetricName::CurrentPlayerSessions,
+            "IdleInstances" => MetricName::IdleInstances,
+            "PercentAvailableGameSessions" => MetricName::PercentAvailableGameSessions,
+            "PercentIdleInstances" => MetricName::PercentIdleInstances,
+            "QueueDepth" => MetricName::QueueDepth,
+            "WaitTime" => MetricName::WaitTime,
+            "ConcurrentActivatableGameSessions" => MetricName::ConcurrentActivatableGameSessions,
+            _ => MetricName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MetricName {
+    fn from(value: String) -> MetricName {
+        match value.as_str() {
+            "ActivatingGameSessions" => MetricName::ActivatingGameSessions,
+            "ActiveGameSessions" => MetricName::ActiveGameSessions,
+            "ActiveInstances" => MetricName::ActiveInstances,
+            "AvailableGameSessions" => MetricName::AvailableGameSessions,
+            "AvailablePlayerSessions" => MetricName::AvailablePlayerSessions,
+            "CurrentPlayerSessions" => MetricName::CurrentPlayerSessions,
+            "IdleInstances" => MetricName::IdleInstances,
+            "PercentAvailableGameSessions" => MetricName::PercentAvailableGameSessions,
+            "PercentIdleInstances" => MetricName::PercentIdleInstances,
+            "QueueDepth" => MetricName::QueueDepth,
+            "WaitTime" => MetricName::WaitTime,
+            "ConcurrentActivatableGameSessions" => MetricName::ConcurrentActivatableGameSessions,
+            _ => MetricName::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for MetricName {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<MetricName, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MetricName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for MetricName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MetricName {
+    fn deserialize<D>(deserializer: D) -> Result<MetricName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OperatingSystem {
+    /// `WINDOWS_2012`
+    Windows2012,
+    /// `AMAZON_LINUX`
+    AmazonLinux,
+    /// `AMAZON_LINUX_2`
+    AmazonLinux2,
+    /// `WINDOWS_2016`
+    Windows2016,
+    /// `AMAZON_LINUX_2023`
+    AmazonLinux2023,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl OperatingSystem {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OperatingSystem::Windows2012 => "WINDOWS_2012",
+            OperatingSystem::AmazonLinux => "AMAZON_LINUX",
+            OperatingSystem::AmazonLinux2 => "AMAZON_LINUX_2",
+            OperatingSystem::Windows2016 => "WINDOWS_2016",
+            OperatingSystem::AmazonLinux2023 => "AMAZON_LINUX_2023",
+            OperatingSystem::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for OperatingSystem {
+    fn default() -> OperatingSystem {
+        OperatingSystem::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for OperatingSystem {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for OperatingSystem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for OperatingSystem {
+    fn from(value: &'a str) -> OperatingSystem {
+        match value {
+            "WINDOWS_2012" => OperatingSystem::Windows2012,
+            "AMAZON_LINUX" => OperatingSystem::AmazonLinux,
+            "AMAZON_LINUX_2" => OperatingSystem::AmazonLinux2,
+            "WINDOWS_2016" => OperatingSystem::Windows2016,
+            "AMAZON_LINUX_2023" => OperatingSystem::AmazonLinux2023,
+            _ => OperatingSystem::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OperatingSystem {
+    fn from(value: String) -> OperatingSystem {
+        match value.as_str() {
+            "WINDOWS_2012" => OperatingSystem::Windows2012,
+            "AMAZON_LINUX" => OperatingSystem::AmazonLinux,
+            "AMAZON_LINUX_2" => OperatingSystem::AmazonLinux2,
+            "WINDOWS_2016" => OperatingSystem::Windows2016,
+            "AMAZON_LINUX_2023" => OperatingSystem::AmazonLinux2023,
+            _ => OperatingSystem::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for OperatingSystem {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<OperatingSystem, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OperatingSystem {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for OperatingSystem {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OperatingSystem {
+    fn deserialize<D>(deserializer: D) -> Result<OperatingSystem, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p><p>Information about a player session that was created as part of a <a>StartGameSessionPlacement</a> request. This object contains only the player ID and player session ID. To retrieve full details on a player session, call <a>DescribePlayerSessions</a> with the player session ID.</p> <ul> <li> <p> <a>CreatePlayerSession</a> </p> </li> <li> <p> <a>CreatePlayerSessions</a> </p> </li> <li> <p> <a>DescribePlayerSessions</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -2364,8 +5359,13 @@ pub struct PlayerLatencyPolicy {
 pub struct PlayerSession {
     /// <p>Time stamp indicating when this data object was created. Format is a number expressed in Unix time as milliseconds (for example "1469498468.057").</p>
     #[serde(rename = "CreationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>DNS identifier assigned to the instance that is running the game session. Values have the following format:</p> <ul> <li> <p>TLS-enabled fleets: <code>&lt;unique identifier&gt;.&lt;region identifier&gt;.amazongamelift.com</code>.</p> </li> <li> <p>Non-TLS-enabled fleets: <code>ec2-&lt;unique identifier&gt;.compute.amazonaws.com</code>. (See <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/using-instance-addressing.html#concepts-public-addresses">Amazon EC2 Instance IP Addressing</a>.)</p> </li> </ul> <p>When connecting to a game session that is running on a TLS-enabled fleet, you must use the DNS name, not the IP address.</p>
     #[serde(rename = "DnsName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2405,13 +5405,400 @@ pub struct PlayerSession {
     /// <p><p>Current status of the player session.</p> <p>Possible player session statuses include the following:</p> <ul> <li> <p> <b>RESERVED</b> -- The player session request has been received, but the player has not yet connected to the server process and/or been validated. </p> </li> <li> <p> <b>ACTIVE</b> -- The player has been validated by the server process and is currently connected.</p> </li> <li> <p> <b>COMPLETED</b> -- The player connection has been dropped.</p> </li> <li> <p> <b>TIMEDOUT</b> -- A player session request was received, but the player did not connect and/or was not validated within the timeout limit (60 seconds).</p> </li> </ul></p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<PlayerSessionStatus>,
     /// <p>Time stamp indicating when this data object was terminated. Format is a number expressed in Unix time as milliseconds (for example "1469498468.057").</p>
     #[serde(rename = "TerminationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub termination_time: Option<f64>,
+    pub termination_time: Option<::chrono::DateTime<::chrono::Utc>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PlayerSessionCreationPolicy {
+    /// `ACCEPT_ALL`
+    AcceptAll,
+    /// `DENY_ALL`
+    DenyAll,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PlayerSessionCreationPolicy {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PlayerSessionCreationPolicy::AcceptAll => "ACCEPT_ALL",
+            PlayerSessionCreationPolicy::DenyAll => "DENY_ALL",
+            PlayerSessionCreationPolicy::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PlayerSessionCreationPolicy {
+    fn default() -> PlayerSessionCreationPolicy {
+        PlayerSessionCreationPolicy::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PlayerSessionCreationPolicy {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PlayerSessionCreationPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PlayerSessionCreationPolicy {
+    fn from(value: &'a str) -> PlayerSessionCreationPolicy {
+        match value {
+            "ACCEPT_ALL" => PlayerSessionCreationPolicy::AcceptAll,
+            "DENY_ALL" => PlayerSessionCreationPolicy::DenyAll,
+            _ => PlayerSessionCreationPolicy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PlayerSessionCreationPolicy {
+    fn from(value: String) -> PlayerSessionCreationPolicy {
+        match value.as_str() {
+            "ACCEPT_ALL" => PlayerSessionCreationPolicy::AcceptAll,
+            "DENY_ALL" => PlayerSessionCreationPolicy::DenyAll,
+            _ => PlayerSessionCreationPolicy::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PlayerSessionCreationPolicy {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PlayerSessionCreationPolicy, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PlayerSessionCreationPolicy {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PlayerSessionCreationPolicy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PlayerSessionCreationPolicy {
+    fn deserialize<D>(deserializer: D) -> Result<PlayerSessionCreationPolicy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PlayerSessionStatus {
+    /// `RESERVED`
+    Reserved,
+    /// `ACTIVE`
+    Active,
+    /// `COMPLETED`
+    Completed,
+    /// `TIMEDOUT`
+    Timedout,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PlayerSessionStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PlayerSessionStatus::Reserved => "RESERVED",
+            PlayerSessionStatus::Active => "ACTIVE",
+            PlayerSessionStatus::Completed => "COMPLETED",
+            PlayerSessionStatus::Timedout => "TIMEDOUT",
+            PlayerSessionStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PlayerSessionStatus {
+    fn default() -> PlayerSessionStatus {
+        PlayerSessionStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PlayerSessionStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PlayerSessionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PlayerSessionStatus {
+    fn from(value: &'a str) -> PlayerSessionStatus {
+        match value {
+            "RESERVED" => PlayerSessionStatus::Reserved,
+            "ACTIVE" => PlayerSessionStatus::Active,
+            "COMPLETED" => PlayerSessionStatus::Completed,
+            "TIMEDOUT" => PlayerSessionStatus::Timedout,
+            _ => PlayerSessionStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PlayerSessionStatus {
+    fn from(value: String) -> PlayerSessionStatus {
+        match value.as_str() {
+            "RESERVED" => PlayerSessionStatus::Reserved,
+            "ACTIVE" => PlayerSessionStatus::Active,
+            "COMPLETED" => PlayerSessionStatus::Completed,
+            "TIMEDOUT" => PlayerSessionStatus::Timedout,
+            _ => PlayerSessionStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PlayerSessionStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PlayerSessionStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PlayerSessionStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PlayerSessionStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PlayerSessionStatus {
+    fn deserialize<D>(deserializer: D) -> Result<PlayerSessionStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PolicyType {
+    /// `RuleBased`
+    RuleBased,
+    /// `TargetBased`
+    TargetBased,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PolicyType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PolicyType::RuleBased => "RuleBased",
+            PolicyType::TargetBased => "TargetBased",
+            PolicyType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PolicyType {
+    fn default() -> PolicyType {
+        PolicyType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PolicyType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PolicyType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PolicyType {
+    fn from(value: &'a str) -> PolicyType {
+        match value {
+            "RuleBased" => PolicyType::RuleBased,
+            "TargetBased" => PolicyType::TargetBased,
+            _ => PolicyType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PolicyType {
+    fn from(value: String) -> PolicyType {
+        match value.as_str() {
+            "RuleBased" => PolicyType::RuleBased,
+            "TargetBased" => PolicyType::TargetBased,
+            _ => PolicyType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PolicyType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PolicyType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PolicyType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PolicyType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PolicyType {
+    fn deserialize<D>(deserializer: D) -> Result<PolicyType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ProtectionPolicy {
+    /// `NoProtection`
+    NoProtection,
+    /// `FullProtection`
+    FullProtection,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ProtectionPolicy {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ProtectionPolicy::NoProtection => "NoProtection",
+            ProtectionPolicy::FullProtection => "FullProtection",
+            ProtectionPolicy::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ProtectionPolicy {
+    fn default() -> ProtectionPolicy {
+        ProtectionPolicy::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ProtectionPolicy {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ProtectionPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ProtectionPolicy {
+    fn from(value: &'a str) -> ProtectionPolicy {
+        match value {
+            "NoProtection" => ProtectionPolicy::NoProtection,
+            "FullProtection" => ProtectionPolicy::FullProtection,
+            _ => ProtectionPolicy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ProtectionPolicy {
+    fn from(value: String) -> ProtectionPolicy {
+        match value.as_str() {
+            "NoProtection" => ProtectionPolicy::NoProtection,
+            "FullProtection" => ProtectionPolicy::FullProtection,
+            _ => ProtectionPolicy::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ProtectionPolicy {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ProtectionPolicy, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ProtectionPolicy {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ProtectionPolicy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ProtectionPolicy {
+    fn deserialize<D>(deserializer: D) -> Result<ProtectionPolicy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
 }
-
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -2419,7 +5806,7 @@ pub struct PutScalingPolicyInput {
     /// <p>Comparison operator to use when measuring the metric against the threshold value.</p>
     #[serde(rename = "ComparisonOperator")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub comparison_operator: Option<String>,
+    pub comparison_operator: Option<ComparisonOperatorType>,
     /// <p>Length of time (in minutes) the metric must be at or beyond the threshold before a scaling event is triggered.</p>
     #[serde(rename = "EvaluationPeriods")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2429,14 +5816,14 @@ pub struct PutScalingPolicyInput {
     pub fleet_id: String,
     /// <p><p>Name of the Amazon GameLift-defined metric that is used to trigger a scaling adjustment. For detailed descriptions of fleet metrics, see <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/monitoring-cloudwatch.html">Monitor Amazon GameLift with Amazon CloudWatch</a>. </p> <ul> <li> <p> <b>ActivatingGameSessions</b> -- Game sessions in the process of being created.</p> </li> <li> <p> <b>ActiveGameSessions</b> -- Game sessions that are currently running.</p> </li> <li> <p> <b>ActiveInstances</b> -- Fleet instances that are currently running at least one game session.</p> </li> <li> <p> <b>AvailableGameSessions</b> -- Additional game sessions that fleet could host simultaneously, given current capacity.</p> </li> <li> <p> <b>AvailablePlayerSessions</b> -- Empty player slots in currently active game sessions. This includes game sessions that are not currently accepting players. Reserved player slots are not included.</p> </li> <li> <p> <b>CurrentPlayerSessions</b> -- Player slots in active game sessions that are being used by a player or are reserved for a player. </p> </li> <li> <p> <b>IdleInstances</b> -- Active instances that are currently hosting zero game sessions. </p> </li> <li> <p> <b>PercentAvailableGameSessions</b> -- Unused percentage of the total number of game sessions that a fleet could host simultaneously, given current capacity. Use this metric for a target-based scaling policy.</p> </li> <li> <p> <b>PercentIdleInstances</b> -- Percentage of the total number of active instances that are hosting zero game sessions.</p> </li> <li> <p> <b>QueueDepth</b> -- Pending game session placement requests, in any queue, where the current fleet is the top-priority destination.</p> </li> <li> <p> <b>WaitTime</b> -- Current wait time for pending game session placement requests, in any queue, where the current fleet is the top-priority destination. </p> </li> </ul></p>
     #[serde(rename = "MetricName")]
-    pub metric_name: String,
+    pub metric_name: MetricName,
     /// <p>A descriptive label that is associated with a scaling policy. Policy names do not need to be unique. A fleet can have only one scaling policy with the same name.</p>
     #[serde(rename = "Name")]
     pub name: String,
     /// <p>The type of scaling policy to create. For a target-based policy, set the parameter <i>MetricName</i> to 'PercentAvailableGameSessions' and specify a <i>TargetConfiguration</i>. For a rule-based policy set the following parameters: <i>MetricName</i>, <i>ComparisonOperator</i>, <i>Threshold</i>, <i>EvaluationPeriods</i>, <i>ScalingAdjustmentType</i>, and <i>ScalingAdjustment</i>.</p>
     #[serde(rename = "PolicyType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub policy_type: Option<String>,
+    pub policy_type: Option<PolicyType>,
     /// <p>Amount of adjustment to make, based on the scaling adjustment type.</p>
     #[serde(rename = "ScalingAdjustment")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2444,7 +5831,7 @@ pub struct PutScalingPolicyInput {
     /// <p><p>The type of adjustment to make to a fleet&#39;s instance count (see <a>FleetCapacity</a>):</p> <ul> <li> <p> <b>ChangeInCapacity</b> -- add (or subtract) the scaling adjustment value from the current instance count. Positive values scale up while negative values scale down.</p> </li> <li> <p> <b>ExactCapacity</b> -- set the instance count to the scaling adjustment value.</p> </li> <li> <p> <b>PercentChangeInCapacity</b> -- increase or reduce the current instance count by the scaling adjustment, read as a percentage. Positive values scale up while negative values scale down; for example, a value of &quot;-10&quot; scales the fleet down by 10%.</p> </li> </ul></p>
     #[serde(rename = "ScalingAdjustmentType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scaling_adjustment_type: Option<String>,
+    pub scaling_adjustment_type: Option<ScalingAdjustmentType>,
     /// <p>The settings for a target-based scaling policy.</p>
     #[serde(rename = "TargetConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2538,9 +5925,102 @@ pub struct RoutingStrategy {
     /// <p><p>The type of routing strategy for the alias.</p> <p>Possible routing types include the following:</p> <ul> <li> <p> <b>SIMPLE</b> - The alias resolves to one specific fleet. Use this type when routing to active fleets.</p> </li> <li> <p> <b>TERMINAL</b> - The alias does not resolve to a fleet but instead can be used to display a message to the user. A terminal alias throws a TerminalRoutingStrategyException with the <a>RoutingStrategy</a> message embedded.</p> </li> </ul></p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<RoutingStrategyType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RoutingStrategyType {
+    /// `SIMPLE`
+    Simple,
+    /// `TERMINAL`
+    Terminal,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RoutingStrategyType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RoutingStrategyType::Simple => "SIMPLE",
+            RoutingStrategyType::Terminal => "TERMINAL",
+            RoutingStrategyType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RoutingStrategyType {
+    fn default() -> RoutingStrategyType {
+        RoutingStrategyType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RoutingStrategyType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RoutingStrategyType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RoutingStrategyType {
+    fn from(value: &'a str) -> RoutingStrategyType {
+        match value {
+            "SIMPLE" => RoutingStrategyType::Simple,
+            "TERMINAL" => RoutingStrategyType::Terminal,
+            _ => RoutingStrategyType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RoutingStrategyType {
+    fn from(value: String) -> RoutingStrategyType {
+        match value.as_str() {
+            "SIMPLE" => RoutingStrategyType::Simple,
+            "TERMINAL" => RoutingStrategyType::Terminal,
+            _ => RoutingStrategyType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RoutingStrategyType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RoutingStrategyType, Self::Err> {
+        Ok(value.into())
+    }
 }
 
+impl ::rusoto_core::param::ToParam for RoutingStrategyType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RoutingStrategyType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RoutingStrategyType {
+    fn deserialize<D>(deserializer: D) -> Result<RoutingStrategyType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p><p>A collection of server process configurations that describe what processes to run on each instance in a fleet. Server processes run either a custom game build executable or a Realtime Servers script. Each instance in the fleet starts the specified server processes and continues to start new processes as existing processes end. Each instance regularly checks for an updated runtime configuration. </p> <p>The runtime configuration enables the instances in a fleet to run multiple processes simultaneously. Learn more about <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-multiprocess.html"> Running Multiple Processes on a Fleet </a>.</p> <p>A Amazon GameLift instance is limited to 50 processes running simultaneously. To calculate the total number of processes in a runtime configuration, add the values of the <code>ConcurrentExecutions</code> parameter for each <a>ServerProcess</a> object.</p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RuntimeConfiguration {
@@ -2579,6 +6059,104 @@ pub struct S3Location {
     pub role_arn: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ScalingAdjustmentType {
+    /// `ChangeInCapacity`
+    ChangeInCapacity,
+    /// `ExactCapacity`
+    ExactCapacity,
+    /// `PercentChangeInCapacity`
+    PercentChangeInCapacity,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ScalingAdjustmentType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ScalingAdjustmentType::ChangeInCapacity => "ChangeInCapacity",
+            ScalingAdjustmentType::ExactCapacity => "ExactCapacity",
+            ScalingAdjustmentType::PercentChangeInCapacity => "PercentChangeInCapacity",
+            ScalingAdjustmentType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ScalingAdjustmentType {
+    fn default() -> ScalingAdjustmentType {
+        ScalingAdjustmentType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ScalingAdjustmentType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ScalingAdjustmentType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ScalingAdjustmentType {
+    fn from(value: &'a str) -> ScalingAdjustmentType {
+        match value {
+            "ChangeInCapacity" => ScalingAdjustmentType::ChangeInCapacity,
+            "ExactCapacity" => ScalingAdjustmentType::ExactCapacity,
+            "PercentChangeInCapacity" => ScalingAdjustmentType::PercentChangeInCapacity,
+            _ => ScalingAdjustmentType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ScalingAdjustmentType {
+    fn from(value: String) -> ScalingAdjustmentType {
+        match value.as_str() {
+            "ChangeInCapacity" => ScalingAdjustmentType::ChangeInCapacity,
+            "ExactCapacity" => ScalingAdjustmentType::ExactCapacity,
+            "PercentChangeInCapacity" => ScalingAdjustmentType::PercentChangeInCapacity,
+            _ => ScalingAdjustmentType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ScalingAdjustmentType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ScalingAdjustmentType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ScalingAdjustmentType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ScalingAdjustmentType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ScalingAdjustmentType {
+    fn deserialize<D>(deserializer: D) -> Result<ScalingAdjustmentType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p><p>Rule that controls how a fleet is scaled. Scaling policies are uniquely identified by the combination of name and fleet ID.</p> <ul> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p>Manage scaling policies:</p> <ul> <li> <p> <a>PutScalingPolicy</a> (auto-scaling)</p> </li> <li> <p> <a>DescribeScalingPolicies</a> (auto-scaling)</p> </li> <li> <p> <a>DeleteScalingPolicy</a> (auto-scaling)</p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -2586,7 +6164,7 @@ pub struct ScalingPolicy {
     /// <p>Comparison operator to use when measuring a metric against the threshold value.</p>
     #[serde(rename = "ComparisonOperator")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub comparison_operator: Option<String>,
+    pub comparison_operator: Option<ComparisonOperatorType>,
     /// <p>Length of time (in minutes) the metric must be at or beyond the threshold before a scaling event is triggered.</p>
     #[serde(rename = "EvaluationPeriods")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2598,7 +6176,7 @@ pub struct ScalingPolicy {
     /// <p><p>Name of the Amazon GameLift-defined metric that is used to trigger a scaling adjustment. For detailed descriptions of fleet metrics, see <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/monitoring-cloudwatch.html">Monitor Amazon GameLift with Amazon CloudWatch</a>. </p> <ul> <li> <p> <b>ActivatingGameSessions</b> -- Game sessions in the process of being created.</p> </li> <li> <p> <b>ActiveGameSessions</b> -- Game sessions that are currently running.</p> </li> <li> <p> <b>ActiveInstances</b> -- Fleet instances that are currently running at least one game session.</p> </li> <li> <p> <b>AvailableGameSessions</b> -- Additional game sessions that fleet could host simultaneously, given current capacity.</p> </li> <li> <p> <b>AvailablePlayerSessions</b> -- Empty player slots in currently active game sessions. This includes game sessions that are not currently accepting players. Reserved player slots are not included.</p> </li> <li> <p> <b>CurrentPlayerSessions</b> -- Player slots in active game sessions that are being used by a player or are reserved for a player. </p> </li> <li> <p> <b>IdleInstances</b> -- Active instances that are currently hosting zero game sessions. </p> </li> <li> <p> <b>PercentAvailableGameSessions</b> -- Unused percentage of the total number of game sessions that a fleet could host simultaneously, given current capacity. Use this metric for a target-based scaling policy.</p> </li> <li> <p> <b>PercentIdleInstances</b> -- Percentage of the total number of active instances that are hosting zero game sessions.</p> </li> <li> <p> <b>QueueDepth</b> -- Pending game session placement requests, in any queue, where the current fleet is the top-priority destination.</p> </li> <li> <p> <b>WaitTime</b> -- Current wait time for pending game session placement requests, in any queue, where the current fleet is the top-priority destination. </p> </li> </ul></p>
     #[serde(rename = "MetricName")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub metric_name: Option<String>,
+    pub metric_name: Option<MetricName>,
     /// <p>A descriptive label that is associated with a scaling policy. Policy names do not need to be unique.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2606,7 +6184,7 @@ pub struct ScalingPolicy {
     /// <p>The type of scaling policy to create. For a target-based policy, set the parameter <i>MetricName</i> to 'PercentAvailableGameSessions' and specify a <i>TargetConfiguration</i>. For a rule-based policy set the following parameters: <i>MetricName</i>, <i>ComparisonOperator</i>, <i>Threshold</i>, <i>EvaluationPeriods</i>, <i>ScalingAdjustmentType</i>, and <i>ScalingAdjustment</i>.</p>
     #[serde(rename = "PolicyType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub policy_type: Option<String>,
+    pub policy_type: Option<PolicyType>,
     /// <p>Amount of adjustment to make, based on the scaling adjustment type.</p>
     #[serde(rename = "ScalingAdjustment")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2614,11 +6192,11 @@ pub struct ScalingPolicy {
     /// <p><p>The type of adjustment to make to a fleet&#39;s instance count (see <a>FleetCapacity</a>):</p> <ul> <li> <p> <b>ChangeInCapacity</b> -- add (or subtract) the scaling adjustment value from the current instance count. Positive values scale up while negative values scale down.</p> </li> <li> <p> <b>ExactCapacity</b> -- set the instance count to the scaling adjustment value.</p> </li> <li> <p> <b>PercentChangeInCapacity</b> -- increase or reduce the current instance count by the scaling adjustment, read as a percentage. Positive values scale up while negative values scale down.</p> </li> </ul></p>
     #[serde(rename = "ScalingAdjustmentType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scaling_adjustment_type: Option<String>,
+    pub scaling_adjustment_type: Option<ScalingAdjustmentType>,
     /// <p><p>Current status of the scaling policy. The scaling policy can be in force only when in an <code>ACTIVE</code> status. Scaling policies can be suspended for individual fleets (see <a>StopFleetActions</a>; if suspended for a fleet, the policy status does not change. View a fleet&#39;s stopped actions by calling <a>DescribeFleetCapacity</a>.</p> <ul> <li> <p> <b>ACTIVE</b> -- The scaling policy can be used for auto-scaling a fleet.</p> </li> <li> <p> <b>UPDATE<em>REQUESTED</b> -- A request to update the scaling policy has been received.</p> </li> <li> <p> <b>UPDATING</b> -- A change is being made to the scaling policy.</p> </li> <li> <p> <b>DELETE</em>REQUESTED</b> -- A request to delete the scaling policy has been received.</p> </li> <li> <p> <b>DELETING</b> -- The scaling policy is being deleted.</p> </li> <li> <p> <b>DELETED</b> -- The scaling policy has been deleted.</p> </li> <li> <p> <b>ERROR</b> -- An error occurred in creating the policy. It should be removed and recreated.</p> </li> </ul></p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ScalingStatusType>,
     /// <p>The settings for a target-based scaling policy.</p>
     #[serde(rename = "TargetConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2629,14 +6207,137 @@ pub struct ScalingPolicy {
     pub threshold: Option<f64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ScalingStatusType {
+    /// `ACTIVE`
+    Active,
+    /// `UPDATE_REQUESTED`
+    UpdateRequested,
+    /// `UPDATING`
+    Updating,
+    /// `DELETE_REQUESTED`
+    DeleteRequested,
+    /// `DELETING`
+    Deleting,
+    /// `DELETED`
+    Deleted,
+    /// `ERROR`
+    Error,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ScalingStatusType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ScalingStatusType::Active => "ACTIVE",
+            ScalingStatusType::UpdateRequested => "UPDATE_REQUESTED",
+            ScalingStatusType::Updating => "UPDATING",
+            ScalingStatusType::DeleteRequested => "DELETE_REQUESTED",
+            ScalingStatusType::Deleting => "DELETING",
+            ScalingStatusType::Deleted => "DELETED",
+            ScalingStatusType::Error => "ERROR",
+            ScalingStatusType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ScalingStatusType {
+    fn default() -> ScalingStatusType {
+        ScalingStatusType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ScalingStatusType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ScalingStatusType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ScalingStatusType {
+    fn from(value: &'a str) -> ScalingStatusType {
+        match value {
+            "ACTIVE" => ScalingStatusType::Active,
+            "UPDATE_REQUESTED" => ScalingStatusType::UpdateRequested,
+            "UPDATING" => ScalingStatusType::Updating,
+            "DELETE_REQUESTED" => ScalingStatusType::DeleteRequested,
+            "DELETING" => ScalingStatusType::Deleting,
+            "DELETED" => ScalingStatusType::Deleted,
+            "ERROR" => ScalingStatusType::Error,
+            _ => ScalingStatusType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ScalingStatusType {
+    fn from(value: String) -> ScalingStatusType {
+        match value.as_str() {
+            "ACTIVE" => ScalingStatusType::Active,
+            "UPDATE_REQUESTED" => ScalingStatusType::UpdateRequested,
+            "UPDATING" => ScalingStatusType::Updating,
+            "DELETE_REQUESTED" => ScalingStatusType::DeleteRequested,
+            "DELETING" => ScalingStatusType::Deleting,
+            "DELETED" => ScalingStatusType::Deleted,
+            "ERROR" => ScalingStatusType::Error,
+            _ => ScalingStatusType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ScalingStatusType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ScalingStatusType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ScalingStatusType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ScalingStatusType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ScalingStatusType {
+    fn deserialize<D>(deserializer: D) -> Result<ScalingStatusType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p><p>Properties describing a Realtime script.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateScript</a> </p> </li> <li> <p> <a>ListScripts</a> </p> </li> <li> <p> <a>DescribeScript</a> </p> </li> <li> <p> <a>UpdateScript</a> </p> </li> <li> <p> <a>DeleteScript</a> </p> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Script {
     /// <p>A time stamp indicating when this data object was created. The format is a number expressed in Unix time as milliseconds (for example "1469498468.057").</p>
     #[serde(rename = "CreationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A descriptive label that is associated with a script. Script names do not need to be unique.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2726,7 +6427,7 @@ pub struct ServerProcess {
 pub struct StartFleetActionsInput {
     /// <p>List of actions to restart on the fleet.</p>
     #[serde(rename = "Actions")]
-    pub actions: Vec<String>,
+    pub actions: Vec<FleetAction>,
     /// <p>A unique identifier for a fleet to start actions on. You can use either the fleet ID or ARN value.</p>
     #[serde(rename = "FleetId")]
     pub fleet_id: String,
@@ -2841,7 +6542,7 @@ pub struct StartMatchmakingOutput {
 pub struct StopFleetActionsInput {
     /// <p>List of actions to suspend on the fleet. </p>
     #[serde(rename = "Actions")]
-    pub actions: Vec<String>,
+    pub actions: Vec<FleetAction>,
     /// <p>A unique identifier for a fleet to stop actions on. You can use either the fleet ID or ARN value.</p>
     #[serde(rename = "FleetId")]
     pub fleet_id: String,
@@ -3012,7 +6713,7 @@ pub struct UpdateFleetAttributesInput {
     /// <p><p>Game session protection policy to apply to all new instances created in this fleet. Instances that already exist are not affected. You can set protection for individual instances using <a>UpdateGameSession</a>.</p> <ul> <li> <p> <b>NoProtection</b> -- The game session can be terminated during a scale-down event.</p> </li> <li> <p> <b>FullProtection</b> -- If the game session is in an <code>ACTIVE</code> status, it cannot be terminated during a scale-down event.</p> </li> </ul></p>
     #[serde(rename = "NewGameSessionProtectionPolicy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub new_game_session_protection_policy: Option<String>,
+    pub new_game_session_protection_policy: Option<ProtectionPolicy>,
     /// <p>Policy that limits the number of game sessions an individual player can create over a span of time. </p>
     #[serde(rename = "ResourceCreationLimitPolicy")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3105,11 +6806,11 @@ pub struct UpdateGameSessionInput {
     /// <p>Policy determining whether or not the game session accepts new players.</p>
     #[serde(rename = "PlayerSessionCreationPolicy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub player_session_creation_policy: Option<String>,
+    pub player_session_creation_policy: Option<PlayerSessionCreationPolicy>,
     /// <p><p>Game session protection policy to apply to this game session only.</p> <ul> <li> <p> <b>NoProtection</b> -- The game session can be terminated during a scale-down event.</p> </li> <li> <p> <b>FullProtection</b> -- If the game session is in an <code>ACTIVE</code> status, it cannot be terminated during a scale-down event.</p> </li> </ul></p>
     #[serde(rename = "ProtectionPolicy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub protection_policy: Option<String>,
+    pub protection_policy: Option<ProtectionPolicy>,
 }
 
 /// <p>Represents the returned data in response to a request action.</p>
@@ -3172,7 +6873,7 @@ pub struct UpdateMatchmakingConfigurationInput {
     /// <p>The method that is used to backfill game sessions created with this matchmaking configuration. Specify MANUAL when your game manages backfill requests manually or does not use the match backfill feature. Specify AUTOMATIC to have GameLift create a <a>StartMatchBackfill</a> request whenever a game session has one or more open slots. Learn more about manual and automatic backfill in <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-backfill.html">Backfill Existing Games with FlexMatch</a>.</p>
     #[serde(rename = "BackfillMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub backfill_mode: Option<String>,
+    pub backfill_mode: Option<BackfillMode>,
     /// <p>Information to add to all events related to the matchmaking configuration. </p>
     #[serde(rename = "CustomEventData")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3305,12 +7006,22 @@ pub struct ValidateMatchmakingRuleSetOutput {
 pub struct VpcPeeringAuthorization {
     /// <p>Time stamp indicating when this authorization was issued. Format is a number expressed in Unix time as milliseconds (for example "1469498468.057").</p>
     #[serde(rename = "CreationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Time stamp indicating when this authorization expires (24 hours after issuance). Format is a number expressed in Unix time as milliseconds (for example "1469498468.057").</p>
     #[serde(rename = "ExpirationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expiration_time: Option<f64>,
+    pub expiration_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A unique identifier for the AWS account that you use to manage your Amazon GameLift fleet. You can find your Account ID in the AWS Management Console under account settings.</p>
     #[serde(rename = "GameLiftAwsAccountId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -8084,11 +11795,12 @@ pub struct GameLiftClient {
 impl GameLiftClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> GameLiftClient {
         GameLiftClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "GameLift"),
         }
     }
 
@@ -8120,6 +11832,7 @@ impl GameLift for GameLiftClient {
         input: AcceptMatchInput,
     ) -> Result<AcceptMatchOutput, RusotoError<AcceptMatchError>> {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("AcceptMatch");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.AcceptMatch");
@@ -8147,6 +11860,7 @@ impl GameLift for GameLiftClient {
         input: CreateAliasInput,
     ) -> Result<CreateAliasOutput, RusotoError<CreateAliasError>> {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("CreateAlias");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.CreateAlias");
@@ -8174,6 +11888,7 @@ impl GameLift for GameLiftClient {
         input: CreateBuildInput,
     ) -> Result<CreateBuildOutput, RusotoError<CreateBuildError>> {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("CreateBuild");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.CreateBuild");
@@ -8201,6 +11916,7 @@ impl GameLift for GameLiftClient {
         input: CreateFleetInput,
     ) -> Result<CreateFleetOutput, RusotoError<CreateFleetError>> {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("CreateFleet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.CreateFleet");
@@ -8228,6 +11944,7 @@ impl GameLift for GameLiftClient {
         input: CreateGameSessionInput,
     ) -> Result<CreateGameSessionOutput, RusotoError<CreateGameSessionError>> {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("CreateGameSession");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.CreateGameSession");
@@ -8255,6 +11972,7 @@ impl GameLift for GameLiftClient {
         input: CreateGameSessionQueueInput,
     ) -> Result<CreateGameSessionQueueOutput, RusotoError<CreateGameSessionQueueError>> {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("CreateGameSessionQueue");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.CreateGameSessionQueue");
@@ -8286,6 +12004,7 @@ impl GameLift for GameLiftClient {
         RusotoError<CreateMatchmakingConfigurationError>,
     > {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("CreateMatchmakingConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.CreateMatchmakingConfiguration");
@@ -8314,6 +12033,7 @@ impl GameLift for GameLiftClient {
         input: CreateMatchmakingRuleSetInput,
     ) -> Result<CreateMatchmakingRuleSetOutput, RusotoError<CreateMatchmakingRuleSetError>> {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("CreateMatchmakingRuleSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.CreateMatchmakingRuleSet");
@@ -8342,6 +12062,7 @@ impl GameLift for GameLiftClient {
         input: CreatePlayerSessionInput,
     ) -> Result<CreatePlayerSessionOutput, RusotoError<CreatePlayerSessionError>> {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("CreatePlayerSession");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.CreatePlayerSession");
@@ -8370,6 +12091,7 @@ impl GameLift for GameLiftClient {
         input: CreatePlayerSessionsInput,
     ) -> Result<CreatePlayerSessionsOutput, RusotoError<CreatePlayerSessionsError>> {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("CreatePlayerSessions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.CreatePlayerSessions");
@@ -8398,6 +12120,7 @@ impl GameLift for GameLiftClient {
         input: CreateScriptInput,
     ) -> Result<CreateScriptOutput, RusotoError<CreateScriptError>> {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("CreateScript");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.CreateScript");
@@ -8426,6 +12149,7 @@ impl GameLift for GameLiftClient {
     ) -> Result<CreateVpcPeeringAuthorizationOutput, RusotoError<CreateVpcPeeringAuthorizationError>>
     {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("CreateVpcPeeringAuthorization");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.CreateVpcPeeringAuthorization");
@@ -8455,6 +12179,7 @@ impl GameLift for GameLiftClient {
     ) -> Result<CreateVpcPeeringConnectionOutput, RusotoError<CreateVpcPeeringConnectionError>>
     {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("CreateVpcPeeringConnection");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.CreateVpcPeeringConnection");
@@ -8483,6 +12208,7 @@ impl GameLift for GameLiftClient {
         input: DeleteAliasInput,
     ) -> Result<(), RusotoError<DeleteAliasError>> {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DeleteAlias");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DeleteAlias");
@@ -8510,6 +12236,7 @@ impl GameLift for GameLiftClient {
         input: DeleteBuildInput,
     ) -> Result<(), RusotoError<DeleteBuildError>> {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DeleteBuild");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DeleteBuild");
@@ -8537,6 +12264,7 @@ impl GameLift for GameLiftClient {
         input: DeleteFleetInput,
     ) -> Result<(), RusotoError<DeleteFleetError>> {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DeleteFleet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DeleteFleet");
@@ -8564,6 +12292,7 @@ impl GameLift for GameLiftClient {
         input: DeleteGameSessionQueueInput,
     ) -> Result<DeleteGameSessionQueueOutput, RusotoError<DeleteGameSessionQueueError>> {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DeleteGameSessionQueue");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DeleteGameSessionQueue");
@@ -8595,6 +12324,7 @@ impl GameLift for GameLiftClient {
         RusotoError<DeleteMatchmakingConfigurationError>,
     > {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DeleteMatchmakingConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DeleteMatchmakingConfiguration");
@@ -8623,6 +12353,7 @@ impl GameLift for GameLiftClient {
         input: DeleteMatchmakingRuleSetInput,
     ) -> Result<DeleteMatchmakingRuleSetOutput, RusotoError<DeleteMatchmakingRuleSetError>> {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DeleteMatchmakingRuleSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DeleteMatchmakingRuleSet");
@@ -8651,6 +12382,7 @@ impl GameLift for GameLiftClient {
         input: DeleteScalingPolicyInput,
     ) -> Result<(), RusotoError<DeleteScalingPolicyError>> {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DeleteScalingPolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DeleteScalingPolicy");
@@ -8678,6 +12410,7 @@ impl GameLift for GameLiftClient {
         input: DeleteScriptInput,
     ) -> Result<(), RusotoError<DeleteScriptError>> {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DeleteScript");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DeleteScript");
@@ -8706,6 +12439,7 @@ impl GameLift for GameLiftClient {
     ) -> Result<DeleteVpcPeeringAuthorizationOutput, RusotoError<DeleteVpcPeeringAuthorizationError>>
     {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DeleteVpcPeeringAuthorization");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DeleteVpcPeeringAuthorization");
@@ -8735,6 +12469,7 @@ impl GameLift for GameLiftClient {
     ) -> Result<DeleteVpcPeeringConnectionOutput, RusotoError<DeleteVpcPeeringConnectionError>>
     {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DeleteVpcPeeringConnection");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DeleteVpcPeeringConnection");
@@ -8763,6 +12498,7 @@ impl GameLift for GameLiftClient {
         input: DescribeAliasInput,
     ) -> Result<DescribeAliasOutput, RusotoError<DescribeAliasError>> {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribeAlias");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DescribeAlias");
@@ -8790,6 +12526,7 @@ impl GameLift for GameLiftClient {
         input: DescribeBuildInput,
     ) -> Result<DescribeBuildOutput, RusotoError<DescribeBuildError>> {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribeBuild");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DescribeBuild");
@@ -8817,6 +12554,7 @@ impl GameLift for GameLiftClient {
         input: DescribeEC2InstanceLimitsInput,
     ) -> Result<DescribeEC2InstanceLimitsOutput, RusotoError<DescribeEC2InstanceLimitsError>> {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribeEC2InstanceLimits");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DescribeEC2InstanceLimits");
@@ -8845,6 +12583,7 @@ impl GameLift for GameLiftClient {
         input: DescribeFleetAttributesInput,
     ) -> Result<DescribeFleetAttributesOutput, RusotoError<DescribeFleetAttributesError>> {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribeFleetAttributes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DescribeFleetAttributes");
@@ -8873,6 +12612,7 @@ impl GameLift for GameLiftClient {
         input: DescribeFleetCapacityInput,
     ) -> Result<DescribeFleetCapacityOutput, RusotoError<DescribeFleetCapacityError>> {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribeFleetCapacity");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DescribeFleetCapacity");
@@ -8901,6 +12641,7 @@ impl GameLift for GameLiftClient {
         input: DescribeFleetEventsInput,
     ) -> Result<DescribeFleetEventsOutput, RusotoError<DescribeFleetEventsError>> {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribeFleetEvents");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DescribeFleetEvents");
@@ -8929,6 +12670,7 @@ impl GameLift for GameLiftClient {
         input: DescribeFleetPortSettingsInput,
     ) -> Result<DescribeFleetPortSettingsOutput, RusotoError<DescribeFleetPortSettingsError>> {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribeFleetPortSettings");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DescribeFleetPortSettings");
@@ -8957,6 +12699,7 @@ impl GameLift for GameLiftClient {
         input: DescribeFleetUtilizationInput,
     ) -> Result<DescribeFleetUtilizationOutput, RusotoError<DescribeFleetUtilizationError>> {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribeFleetUtilization");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DescribeFleetUtilization");
@@ -8986,6 +12729,7 @@ impl GameLift for GameLiftClient {
     ) -> Result<DescribeGameSessionDetailsOutput, RusotoError<DescribeGameSessionDetailsError>>
     {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribeGameSessionDetails");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DescribeGameSessionDetails");
@@ -9015,6 +12759,7 @@ impl GameLift for GameLiftClient {
     ) -> Result<DescribeGameSessionPlacementOutput, RusotoError<DescribeGameSessionPlacementError>>
     {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribeGameSessionPlacement");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DescribeGameSessionPlacement");
@@ -9043,6 +12788,7 @@ impl GameLift for GameLiftClient {
         input: DescribeGameSessionQueuesInput,
     ) -> Result<DescribeGameSessionQueuesOutput, RusotoError<DescribeGameSessionQueuesError>> {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribeGameSessionQueues");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DescribeGameSessionQueues");
@@ -9071,6 +12817,7 @@ impl GameLift for GameLiftClient {
         input: DescribeGameSessionsInput,
     ) -> Result<DescribeGameSessionsOutput, RusotoError<DescribeGameSessionsError>> {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribeGameSessions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DescribeGameSessions");
@@ -9099,6 +12846,7 @@ impl GameLift for GameLiftClient {
         input: DescribeInstancesInput,
     ) -> Result<DescribeInstancesOutput, RusotoError<DescribeInstancesError>> {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribeInstances");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DescribeInstances");
@@ -9126,6 +12874,7 @@ impl GameLift for GameLiftClient {
         input: DescribeMatchmakingInput,
     ) -> Result<DescribeMatchmakingOutput, RusotoError<DescribeMatchmakingError>> {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribeMatchmaking");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DescribeMatchmaking");
@@ -9157,6 +12906,7 @@ impl GameLift for GameLiftClient {
         RusotoError<DescribeMatchmakingConfigurationsError>,
     > {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribeMatchmakingConfigurations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DescribeMatchmakingConfigurations");
@@ -9188,6 +12938,7 @@ impl GameLift for GameLiftClient {
     ) -> Result<DescribeMatchmakingRuleSetsOutput, RusotoError<DescribeMatchmakingRuleSetsError>>
     {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribeMatchmakingRuleSets");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DescribeMatchmakingRuleSets");
@@ -9216,6 +12967,7 @@ impl GameLift for GameLiftClient {
         input: DescribePlayerSessionsInput,
     ) -> Result<DescribePlayerSessionsOutput, RusotoError<DescribePlayerSessionsError>> {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribePlayerSessions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DescribePlayerSessions");
@@ -9245,6 +12997,7 @@ impl GameLift for GameLiftClient {
     ) -> Result<DescribeRuntimeConfigurationOutput, RusotoError<DescribeRuntimeConfigurationError>>
     {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribeRuntimeConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DescribeRuntimeConfiguration");
@@ -9273,6 +13026,7 @@ impl GameLift for GameLiftClient {
         input: DescribeScalingPoliciesInput,
     ) -> Result<DescribeScalingPoliciesOutput, RusotoError<DescribeScalingPoliciesError>> {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribeScalingPolicies");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DescribeScalingPolicies");
@@ -9301,6 +13055,7 @@ impl GameLift for GameLiftClient {
         input: DescribeScriptInput,
     ) -> Result<DescribeScriptOutput, RusotoError<DescribeScriptError>> {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribeScript");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DescribeScript");
@@ -9330,6 +13085,7 @@ impl GameLift for GameLiftClient {
         RusotoError<DescribeVpcPeeringAuthorizationsError>,
     > {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribeVpcPeeringAuthorizations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DescribeVpcPeeringAuthorizations");
@@ -9360,6 +13116,7 @@ impl GameLift for GameLiftClient {
     ) -> Result<DescribeVpcPeeringConnectionsOutput, RusotoError<DescribeVpcPeeringConnectionsError>>
     {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribeVpcPeeringConnections");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DescribeVpcPeeringConnections");
@@ -9388,6 +13145,7 @@ impl GameLift for GameLiftClient {
         input: GetGameSessionLogUrlInput,
     ) -> Result<GetGameSessionLogUrlOutput, RusotoError<GetGameSessionLogUrlError>> {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("GetGameSessionLogUrl");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.GetGameSessionLogUrl");
@@ -9416,6 +13174,7 @@ impl GameLift for GameLiftClient {
         input: GetInstanceAccessInput,
     ) -> Result<GetInstanceAccessOutput, RusotoError<GetInstanceAccessError>> {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("GetInstanceAccess");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.GetInstanceAccess");
@@ -9443,6 +13202,7 @@ impl GameLift for GameLiftClient {
         input: ListAliasesInput,
     ) -> Result<ListAliasesOutput, RusotoError<ListAliasesError>> {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("ListAliases");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.ListAliases");
@@ -9470,6 +13230,7 @@ impl GameLift for GameLiftClient {
         input: ListBuildsInput,
     ) -> Result<ListBuildsOutput, RusotoError<ListBuildsError>> {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("ListBuilds");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.ListBuilds");
@@ -9497,6 +13258,7 @@ impl GameLift for GameLiftClient {
         input: ListFleetsInput,
     ) -> Result<ListFleetsOutput, RusotoError<ListFleetsError>> {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("ListFleets");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.ListFleets");
@@ -9524,6 +13286,7 @@ impl GameLift for GameLiftClient {
         input: ListScriptsInput,
     ) -> Result<ListScriptsOutput, RusotoError<ListScriptsError>> {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("ListScripts");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.ListScripts");
@@ -9551,6 +13314,7 @@ impl GameLift for GameLiftClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.ListTagsForResource");
@@ -9579,6 +13343,7 @@ impl GameLift for GameLiftClient {
         input: PutScalingPolicyInput,
     ) -> Result<PutScalingPolicyOutput, RusotoError<PutScalingPolicyError>> {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("PutScalingPolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.PutScalingPolicy");
@@ -9606,6 +13371,7 @@ impl GameLift for GameLiftClient {
         input: RequestUploadCredentialsInput,
     ) -> Result<RequestUploadCredentialsOutput, RusotoError<RequestUploadCredentialsError>> {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("RequestUploadCredentials");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.RequestUploadCredentials");
@@ -9634,6 +13400,7 @@ impl GameLift for GameLiftClient {
         input: ResolveAliasInput,
     ) -> Result<ResolveAliasOutput, RusotoError<ResolveAliasError>> {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("ResolveAlias");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.ResolveAlias");
@@ -9661,6 +13428,7 @@ impl GameLift for GameLiftClient {
         input: SearchGameSessionsInput,
     ) -> Result<SearchGameSessionsOutput, RusotoError<SearchGameSessionsError>> {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("SearchGameSessions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.SearchGameSessions");
@@ -9689,6 +13457,7 @@ impl GameLift for GameLiftClient {
         input: StartFleetActionsInput,
     ) -> Result<StartFleetActionsOutput, RusotoError<StartFleetActionsError>> {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("StartFleetActions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.StartFleetActions");
@@ -9716,6 +13485,7 @@ impl GameLift for GameLiftClient {
         input: StartGameSessionPlacementInput,
     ) -> Result<StartGameSessionPlacementOutput, RusotoError<StartGameSessionPlacementError>> {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("StartGameSessionPlacement");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.StartGameSessionPlacement");
@@ -9744,6 +13514,7 @@ impl GameLift for GameLiftClient {
         input: StartMatchBackfillInput,
     ) -> Result<StartMatchBackfillOutput, RusotoError<StartMatchBackfillError>> {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("StartMatchBackfill");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.StartMatchBackfill");
@@ -9772,6 +13543,7 @@ impl GameLift for GameLiftClient {
         input: StartMatchmakingInput,
     ) -> Result<StartMatchmakingOutput, RusotoError<StartMatchmakingError>> {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("StartMatchmaking");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.StartMatchmaking");
@@ -9799,6 +13571,7 @@ impl GameLift for GameLiftClient {
         input: StopFleetActionsInput,
     ) -> Result<StopFleetActionsOutput, RusotoError<StopFleetActionsError>> {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("StopFleetActions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.StopFleetActions");
@@ -9826,6 +13599,7 @@ impl GameLift for GameLiftClient {
         input: StopGameSessionPlacementInput,
     ) -> Result<StopGameSessionPlacementOutput, RusotoError<StopGameSessionPlacementError>> {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("StopGameSessionPlacement");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.StopGameSessionPlacement");
@@ -9854,6 +13628,7 @@ impl GameLift for GameLiftClient {
         input: StopMatchmakingInput,
     ) -> Result<StopMatchmakingOutput, RusotoError<StopMatchmakingError>> {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("StopMatchmaking");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.StopMatchmaking");
@@ -9881,6 +13656,7 @@ impl GameLift for GameLiftClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.TagResource");
@@ -9908,6 +13684,7 @@ impl GameLift for GameLiftClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.UntagResource");
@@ -9935,6 +13712,7 @@ impl GameLift for GameLiftClient {
         input: UpdateAliasInput,
     ) -> Result<UpdateAliasOutput, RusotoError<UpdateAliasError>> {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("UpdateAlias");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.UpdateAlias");
@@ -9962,6 +13740,7 @@ impl GameLift for GameLiftClient {
         input: UpdateBuildInput,
     ) -> Result<UpdateBuildOutput, RusotoError<UpdateBuildError>> {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("UpdateBuild");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.UpdateBuild");
@@ -9989,6 +13768,7 @@ impl GameLift for GameLiftClient {
         input: UpdateFleetAttributesInput,
     ) -> Result<UpdateFleetAttributesOutput, RusotoError<UpdateFleetAttributesError>> {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("UpdateFleetAttributes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.UpdateFleetAttributes");
@@ -10017,6 +13797,7 @@ impl GameLift for GameLiftClient {
         input: UpdateFleetCapacityInput,
     ) -> Result<UpdateFleetCapacityOutput, RusotoError<UpdateFleetCapacityError>> {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("UpdateFleetCapacity");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.UpdateFleetCapacity");
@@ -10045,6 +13826,7 @@ impl GameLift for GameLiftClient {
         input: UpdateFleetPortSettingsInput,
     ) -> Result<UpdateFleetPortSettingsOutput, RusotoError<UpdateFleetPortSettingsError>> {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("UpdateFleetPortSettings");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.UpdateFleetPortSettings");
@@ -10073,6 +13855,7 @@ impl GameLift for GameLiftClient {
         input: UpdateGameSessionInput,
     ) -> Result<UpdateGameSessionOutput, RusotoError<UpdateGameSessionError>> {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("UpdateGameSession");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.UpdateGameSession");
@@ -10100,6 +13883,7 @@ impl GameLift for GameLiftClient {
         input: UpdateGameSessionQueueInput,
     ) -> Result<UpdateGameSessionQueueOutput, RusotoError<UpdateGameSessionQueueError>> {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("UpdateGameSessionQueue");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.UpdateGameSessionQueue");
@@ -10131,6 +13915,7 @@ impl GameLift for GameLiftClient {
         RusotoError<UpdateMatchmakingConfigurationError>,
     > {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("UpdateMatchmakingConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.UpdateMatchmakingConfiguration");
@@ -10160,6 +13945,7 @@ impl GameLift for GameLiftClient {
     ) -> Result<UpdateRuntimeConfigurationOutput, RusotoError<UpdateRuntimeConfigurationError>>
     {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("UpdateRuntimeConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.UpdateRuntimeConfiguration");
@@ -10188,6 +13974,7 @@ impl GameLift for GameLiftClient {
         input: UpdateScriptInput,
     ) -> Result<UpdateScriptOutput, RusotoError<UpdateScriptError>> {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("UpdateScript");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.UpdateScript");
@@ -10216,6 +14003,7 @@ impl GameLift for GameLiftClient {
     ) -> Result<ValidateMatchmakingRuleSetOutput, RusotoError<ValidateMatchmakingRuleSetError>>
     {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("ValidateMatchmakingRuleSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.ValidateMatchmakingRuleSet");
@@ -10238,3 +14026,577 @@ impl GameLift for GameLiftClient {
         }
     }
 }
+impl GameLiftClient {
+    /// Auto-paginating version of `describe_fleet_attributes`, streaming every page of results.
+    pub fn describe_fleet_attributes_pages(
+        &self,
+        input: DescribeFleetAttributesInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeFleetAttributesOutput,
+        DescribeFleetAttributesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GameLiftClient, input: DescribeFleetAttributesInput| async move {
+                client.describe_fleet_attributes(input).await
+            },
+            |input: &DescribeFleetAttributesInput, output: &DescribeFleetAttributesOutput| {
+                Some(DescribeFleetAttributesInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_fleet_attributes`, streaming the `FleetAttributes` of every page of results.
+    pub fn describe_fleet_attributes_items(
+        &self,
+        input: DescribeFleetAttributesInput,
+    ) -> rusoto_core::pagination::RusotoStream<FleetAttributes, DescribeFleetAttributesError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_fleet_attributes_pages(input),
+            |output: DescribeFleetAttributesOutput| output.fleet_attributes.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_fleet_capacity`, streaming every page of results.
+    pub fn describe_fleet_capacity_pages(
+        &self,
+        input: DescribeFleetCapacityInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeFleetCapacityOutput,
+        DescribeFleetCapacityError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GameLiftClient, input: DescribeFleetCapacityInput| async move {
+                client.describe_fleet_capacity(input).await
+            },
+            |input: &DescribeFleetCapacityInput, output: &DescribeFleetCapacityOutput| {
+                Some(DescribeFleetCapacityInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_fleet_capacity`, streaming the `FleetCapacity` of every page of results.
+    pub fn describe_fleet_capacity_items(
+        &self,
+        input: DescribeFleetCapacityInput,
+    ) -> rusoto_core::pagination::RusotoStream<FleetCapacity, DescribeFleetCapacityError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_fleet_capacity_pages(input),
+            |output: DescribeFleetCapacityOutput| output.fleet_capacity.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_fleet_events`, streaming every page of results.
+    pub fn describe_fleet_events_pages(
+        &self,
+        input: DescribeFleetEventsInput,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeFleetEventsOutput, DescribeFleetEventsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GameLiftClient, input: DescribeFleetEventsInput| async move {
+                client.describe_fleet_events(input).await
+            },
+            |input: &DescribeFleetEventsInput, output: &DescribeFleetEventsOutput| {
+                Some(DescribeFleetEventsInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_fleet_events`, streaming the `Events` of every page of results.
+    pub fn describe_fleet_events_items(
+        &self,
+        input: DescribeFleetEventsInput,
+    ) -> rusoto_core::pagination::RusotoStream<Event, DescribeFleetEventsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_fleet_events_pages(input),
+            |output: DescribeFleetEventsOutput| output.events.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_fleet_utilization`, streaming every page of results.
+    pub fn describe_fleet_utilization_pages(
+        &self,
+        input: DescribeFleetUtilizationInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeFleetUtilizationOutput,
+        DescribeFleetUtilizationError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GameLiftClient, input: DescribeFleetUtilizationInput| async move {
+                client.describe_fleet_utilization(input).await
+            },
+            |input: &DescribeFleetUtilizationInput, output: &DescribeFleetUtilizationOutput| {
+                Some(DescribeFleetUtilizationInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_fleet_utilization`, streaming the `FleetUtilization` of every page of results.
+    pub fn describe_fleet_utilization_items(
+        &self,
+        input: DescribeFleetUtilizationInput,
+    ) -> rusoto_core::pagination::RusotoStream<FleetUtilization, DescribeFleetUtilizationError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_fleet_utilization_pages(input),
+            |output: DescribeFleetUtilizationOutput| output.fleet_utilization.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_game_session_details`, streaming every page of results.
+    pub fn describe_game_session_details_pages(
+        &self,
+        input: DescribeGameSessionDetailsInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeGameSessionDetailsOutput,
+        DescribeGameSessionDetailsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GameLiftClient, input: DescribeGameSessionDetailsInput| async move {
+                client.describe_game_session_details(input).await
+            },
+            |input: &DescribeGameSessionDetailsInput, output: &DescribeGameSessionDetailsOutput| {
+                Some(DescribeGameSessionDetailsInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_game_session_details`, streaming the `GameSessionDetails` of every page of results.
+    pub fn describe_game_session_details_items(
+        &self,
+        input: DescribeGameSessionDetailsInput,
+    ) -> rusoto_core::pagination::RusotoStream<GameSessionDetail, DescribeGameSessionDetailsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_game_session_details_pages(input),
+            |output: DescribeGameSessionDetailsOutput| {
+                output.game_session_details.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_game_session_queues`, streaming every page of results.
+    pub fn describe_game_session_queues_pages(
+        &self,
+        input: DescribeGameSessionQueuesInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeGameSessionQueuesOutput,
+        DescribeGameSessionQueuesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GameLiftClient, input: DescribeGameSessionQueuesInput| async move {
+                client.describe_game_session_queues(input).await
+            },
+            |input: &DescribeGameSessionQueuesInput, output: &DescribeGameSessionQueuesOutput| {
+                Some(DescribeGameSessionQueuesInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_game_session_queues`, streaming the `GameSessionQueues` of every page of results.
+    pub fn describe_game_session_queues_items(
+        &self,
+        input: DescribeGameSessionQueuesInput,
+    ) -> rusoto_core::pagination::RusotoStream<GameSessionQueue, DescribeGameSessionQueuesError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_game_session_queues_pages(input),
+            |output: DescribeGameSessionQueuesOutput| {
+                output.game_session_queues.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_game_sessions`, streaming every page of results.
+    pub fn describe_game_sessions_pages(
+        &self,
+        input: DescribeGameSessionsInput,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeGameSessionsOutput, DescribeGameSessionsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GameLiftClient, input: DescribeGameSessionsInput| async move {
+                client.describe_game_sessions(input).await
+            },
+            |input: &DescribeGameSessionsInput, output: &DescribeGameSessionsOutput| {
+                Some(DescribeGameSessionsInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_game_sessions`, streaming the `GameSessions` of every page of results.
+    pub fn describe_game_sessions_items(
+        &self,
+        input: DescribeGameSessionsInput,
+    ) -> rusoto_core::pagination::RusotoStream<GameSession, DescribeGameSessionsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_game_sessions_pages(input),
+            |output: DescribeGameSessionsOutput| output.game_sessions.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_instances`, streaming every page of results.
+    pub fn describe_instances_pages(
+        &self,
+        input: DescribeInstancesInput,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeInstancesOutput, DescribeInstancesError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GameLiftClient, input: DescribeInstancesInput| async move {
+                client.describe_instances(input).await
+            },
+            |input: &DescribeInstancesInput, output: &DescribeInstancesOutput| {
+                Some(DescribeInstancesInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_instances`, streaming the `Instances` of every page of results.
+    pub fn describe_instances_items(
+        &self,
+        input: DescribeInstancesInput,
+    ) -> rusoto_core::pagination::RusotoStream<Instance, DescribeInstancesError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_instances_pages(input),
+            |output: DescribeInstancesOutput| output.instances.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_matchmaking_configurations`, streaming every page of results.
+    pub fn describe_matchmaking_configurations_pages(
+        &self,
+        input: DescribeMatchmakingConfigurationsInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeMatchmakingConfigurationsOutput,
+        DescribeMatchmakingConfigurationsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GameLiftClient, input: DescribeMatchmakingConfigurationsInput| async move {
+                client.describe_matchmaking_configurations(input).await
+            },
+            |input: &DescribeMatchmakingConfigurationsInput,
+             output: &DescribeMatchmakingConfigurationsOutput| {
+                Some(DescribeMatchmakingConfigurationsInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_matchmaking_configurations`, streaming the `Configurations` of every page of results.
+    pub fn describe_matchmaking_configurations_items(
+        &self,
+        input: DescribeMatchmakingConfigurationsInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        MatchmakingConfiguration,
+        DescribeMatchmakingConfigurationsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_matchmaking_configurations_pages(input),
+            |output: DescribeMatchmakingConfigurationsOutput| {
+                output.configurations.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_matchmaking_rule_sets`, streaming every page of results.
+    pub fn describe_matchmaking_rule_sets_pages(
+        &self,
+        input: DescribeMatchmakingRuleSetsInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeMatchmakingRuleSetsOutput,
+        DescribeMatchmakingRuleSetsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GameLiftClient, input: DescribeMatchmakingRuleSetsInput| async move {
+                client.describe_matchmaking_rule_sets(input).await
+            },
+            |input: &DescribeMatchmakingRuleSetsInput,
+             output: &DescribeMatchmakingRuleSetsOutput| {
+                Some(DescribeMatchmakingRuleSetsInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_matchmaking_rule_sets`, streaming the `RuleSets` of every page of results.
+    pub fn describe_matchmaking_rule_sets_items(
+        &self,
+        input: DescribeMatchmakingRuleSetsInput,
+    ) -> rusoto_core::pagination::RusotoStream<MatchmakingRuleSet, DescribeMatchmakingRuleSetsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_matchmaking_rule_sets_pages(input),
+            |output: DescribeMatchmakingRuleSetsOutput| output.rule_sets,
+        )
+    }
+
+    /// Auto-paginating version of `describe_player_sessions`, streaming every page of results.
+    pub fn describe_player_sessions_pages(
+        &self,
+        input: DescribePlayerSessionsInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribePlayerSessionsOutput,
+        DescribePlayerSessionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GameLiftClient, input: DescribePlayerSessionsInput| async move {
+                client.describe_player_sessions(input).await
+            },
+            |input: &DescribePlayerSessionsInput, output: &DescribePlayerSessionsOutput| {
+                Some(DescribePlayerSessionsInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_player_sessions`, streaming the `PlayerSessions` of every page of results.
+    pub fn describe_player_sessions_items(
+        &self,
+        input: DescribePlayerSessionsInput,
+    ) -> rusoto_core::pagination::RusotoStream<PlayerSession, DescribePlayerSessionsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_player_sessions_pages(input),
+            |output: DescribePlayerSessionsOutput| output.player_sessions.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_scaling_policies`, streaming every page of results.
+    pub fn describe_scaling_policies_pages(
+        &self,
+        input: DescribeScalingPoliciesInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeScalingPoliciesOutput,
+        DescribeScalingPoliciesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GameLiftClient, input: DescribeScalingPoliciesInput| async move {
+                client.describe_scaling_policies(input).await
+            },
+            |input: &DescribeScalingPoliciesInput, output: &DescribeScalingPoliciesOutput| {
+                Some(DescribeScalingPoliciesInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_scaling_policies`, streaming the `ScalingPolicies` of every page of results.
+    pub fn describe_scaling_policies_items(
+        &self,
+        input: DescribeScalingPoliciesInput,
+    ) -> rusoto_core::pagination::RusotoStream<ScalingPolicy, DescribeScalingPoliciesError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_scaling_policies_pages(input),
+            |output: DescribeScalingPoliciesOutput| output.scaling_policies.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_aliases`, streaming every page of results.
+    pub fn list_aliases_pages(
+        &self,
+        input: ListAliasesInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListAliasesOutput, ListAliasesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GameLiftClient, input: ListAliasesInput| async move {
+                client.list_aliases(input).await
+            },
+            |input: &ListAliasesInput, output: &ListAliasesOutput| {
+                Some(ListAliasesInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_aliases`, streaming the `Aliases` of every page of results.
+    pub fn list_aliases_items(
+        &self,
+        input: ListAliasesInput,
+    ) -> rusoto_core::pagination::RusotoStream<Alias, ListAliasesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_aliases_pages(input),
+            |output: ListAliasesOutput| output.aliases.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_builds`, streaming every page of results.
+    pub fn list_builds_pages(
+        &self,
+        input: ListBuildsInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListBuildsOutput, ListBuildsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GameLiftClient, input: ListBuildsInput| async move {
+                client.list_builds(input).await
+            },
+            |input: &ListBuildsInput, output: &ListBuildsOutput| {
+                Some(ListBuildsInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_builds`, streaming the `Builds` of every page of results.
+    pub fn list_builds_items(
+        &self,
+        input: ListBuildsInput,
+    ) -> rusoto_core::pagination::RusotoStream<Build, ListBuildsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_builds_pages(input),
+            |output: ListBuildsOutput| output.builds.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_fleets`, streaming every page of results.
+    pub fn list_fleets_pages(
+        &self,
+        input: ListFleetsInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListFleetsOutput, ListFleetsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GameLiftClient, input: ListFleetsInput| async move {
+                client.list_fleets(input).await
+            },
+            |input: &ListFleetsInput, output: &ListFleetsOutput| {
+                Some(ListFleetsInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_fleets`, streaming the `FleetIds` of every page of results.
+    pub fn list_fleets_items(
+        &self,
+        input: ListFleetsInput,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListFleetsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_fleets_pages(input),
+            |output: ListFleetsOutput| output.fleet_ids.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_scripts`, streaming every page of results.
+    pub fn list_scripts_pages(
+        &self,
+        input: ListScriptsInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListScriptsOutput, ListScriptsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GameLiftClient, input: ListScriptsInput| async move {
+                client.list_scripts(input).await
+            },
+            |input: &ListScriptsInput, output: &ListScriptsOutput| {
+                Some(ListScriptsInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_scripts`, streaming the `Scripts` of every page of results.
+    pub fn list_scripts_items(
+        &self,
+        input: ListScriptsInput,
+    ) -> rusoto_core::pagination::RusotoStream<Script, ListScriptsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_scripts_pages(input),
+            |output: ListScriptsOutput| output.scripts.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `search_game_sessions`, streaming every page of results.
+    pub fn search_game_sessions_pages(
+        &self,
+        input: SearchGameSessionsInput,
+    ) -> rusoto_core::pagination::RusotoStream<SearchGameSessionsOutput, SearchGameSessionsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GameLiftClient, input: SearchGameSessionsInput| async move {
+                client.search_game_sessions(input).await
+            },
+            |input: &SearchGameSessionsInput, output: &SearchGameSessionsOutput| {
+                Some(SearchGameSessionsInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `search_game_sessions`, streaming the `GameSessions` of every page of results.
+    pub fn search_game_sessions_items(
+        &self,
+        input: SearchGameSessionsInput,
+    ) -> rusoto_core::pagination::RusotoStream<GameSession, SearchGameSessionsError> {
+        rusoto_core::pagination::paginate_items(
+            self.search_game_sessions_pages(input),
+            |output: SearchGameSessionsOutput| output.game_sessions.unwrap_or_default(),
+        )
+    }
+}
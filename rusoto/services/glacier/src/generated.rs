@@ -52,6 +52,104 @@ pub struct AbortVaultLockInput {
     pub vault_name: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ActionCode {
+    /// `ArchiveRetrieval`
+    ArchiveRetrieval,
+    /// `InventoryRetrieval`
+    InventoryRetrieval,
+    /// `Select`
+    Select,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ActionCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ActionCode::ArchiveRetrieval => "ArchiveRetrieval",
+            ActionCode::InventoryRetrieval => "InventoryRetrieval",
+            ActionCode::Select => "Select",
+            ActionCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ActionCode {
+    fn default() -> ActionCode {
+        ActionCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ActionCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ActionCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ActionCode {
+    fn from(value: &'a str) -> ActionCode {
+        match value {
+            "ArchiveRetrieval" => ActionCode::ArchiveRetrieval,
+            "InventoryRetrieval" => ActionCode::InventoryRetrieval,
+            "Select" => ActionCode::Select,
+            _ => ActionCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ActionCode {
+    fn from(value: String) -> ActionCode {
+        match value.as_str() {
+            "ArchiveRetrieval" => ActionCode::ArchiveRetrieval,
+            "InventoryRetrieval" => ActionCode::InventoryRetrieval,
+            "Select" => ActionCode::Select,
+            _ => ActionCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ActionCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ActionCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ActionCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ActionCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ActionCode {
+    fn deserialize<D>(deserializer: D) -> Result<ActionCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The input values for <code>AddTagsToVault</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -100,7 +198,7 @@ pub struct CSVInput {
     /// <p>Describes the first line of input. Valid values are <code>None</code>, <code>Ignore</code>, and <code>Use</code>.</p>
     #[serde(rename = "FileHeaderInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub file_header_info: Option<String>,
+    pub file_header_info: Option<FileHeaderInfo>,
     /// <p>A value used as an escape character where the field delimiter is part of the value.</p>
     #[serde(rename = "QuoteCharacter")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -133,13 +231,131 @@ pub struct CSVOutput {
     /// <p>A value that indicates whether all output fields should be contained within quotation marks.</p>
     #[serde(rename = "QuoteFields")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub quote_fields: Option<String>,
+    pub quote_fields: Option<QuoteFields>,
     /// <p>A value used to separate individual records from each other.</p>
     #[serde(rename = "RecordDelimiter")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub record_delimiter: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CannedACL {
+    /// `private`
+    Private,
+    /// `public-read`
+    PublicRead,
+    /// `public-read-write`
+    PublicReadWrite,
+    /// `aws-exec-read`
+    AwsExecRead,
+    /// `authenticated-read`
+    AuthenticatedRead,
+    /// `bucket-owner-read`
+    BucketOwnerRead,
+    /// `bucket-owner-full-control`
+    BucketOwnerFullControl,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CannedACL {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CannedACL::Private => "private",
+            CannedACL::PublicRead => "public-read",
+            CannedACL::PublicReadWrite => "public-read-write",
+            CannedACL::AwsExecRead => "aws-exec-read",
+            CannedACL::AuthenticatedRead => "authenticated-read",
+            CannedACL::BucketOwnerRead => "bucket-owner-read",
+            CannedACL::BucketOwnerFullControl => "bucket-owner-full-control",
+            CannedACL::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CannedACL {
+    fn default() -> CannedACL {
+        CannedACL::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CannedACL {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CannedACL {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CannedACL {
+    fn from(value: &'a str) -> CannedACL {
+        match value {
+            "private" => CannedACL::Private,
+            "public-read" => CannedACL::PublicRead,
+            "public-read-write" => CannedACL::PublicReadWrite,
+            "aws-exec-read" => CannedACL::AwsExecRead,
+            "authenticated-read" => CannedACL::AuthenticatedRead,
+            "bucket-owner-read" => CannedACL::BucketOwnerRead,
+            "bucket-owner-full-control" => CannedACL::BucketOwnerFullControl,
+            _ => CannedACL::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CannedACL {
+    fn from(value: String) -> CannedACL {
+        match value.as_str() {
+            "private" => CannedACL::Private,
+            "public-read" => CannedACL::PublicRead,
+            "public-read-write" => CannedACL::PublicReadWrite,
+            "aws-exec-read" => CannedACL::AwsExecRead,
+            "authenticated-read" => CannedACL::AuthenticatedRead,
+            "bucket-owner-read" => CannedACL::BucketOwnerRead,
+            "bucket-owner-full-control" => CannedACL::BucketOwnerFullControl,
+            _ => CannedACL::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CannedACL {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CannedACL, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CannedACL {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CannedACL {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CannedACL {
+    fn deserialize<D>(deserializer: D) -> Result<CannedACL, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Provides options to complete a multipart upload operation. This informs Amazon Glacier that all the archive parts have been uploaded and Amazon S3 Glacier (Glacier) can now assemble the archive from the uploaded parts. After assembling and saving the archive to the vault, Glacier returns the URI path of the newly created archive resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -336,7 +552,7 @@ pub struct Encryption {
     /// <p>The server-side encryption algorithm used when storing job results in Amazon S3, for example <code>AES256</code> or <code>aws:kms</code>.</p>
     #[serde(rename = "EncryptionType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub encryption_type: Option<String>,
+    pub encryption_type: Option<EncryptionType>,
     /// <p>Optional. If the encryption type is <code>aws:kms</code>, you can use this value to specify the encryption context for the job results.</p>
     #[serde(rename = "KMSContext")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -347,6 +563,285 @@ pub struct Encryption {
     pub kms_key_id: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EncryptionType {
+    /// `aws:kms`
+    AwsKms,
+    /// `AES256`
+    Aes256,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EncryptionType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EncryptionType::AwsKms => "aws:kms",
+            EncryptionType::Aes256 => "AES256",
+            EncryptionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EncryptionType {
+    fn default() -> EncryptionType {
+        EncryptionType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EncryptionType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EncryptionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EncryptionType {
+    fn from(value: &'a str) -> EncryptionType {
+        match value {
+            "aws:kms" => EncryptionType::AwsKms,
+            "AES256" => EncryptionType::Aes256,
+            _ => EncryptionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EncryptionType {
+    fn from(value: String) -> EncryptionType {
+        match value.as_str() {
+            "aws:kms" => EncryptionType::AwsKms,
+            "AES256" => EncryptionType::Aes256,
+            _ => EncryptionType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EncryptionType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EncryptionType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EncryptionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EncryptionType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EncryptionType {
+    fn deserialize<D>(deserializer: D) -> Result<EncryptionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ExpressionType {
+    /// `SQL`
+    Sql,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ExpressionType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ExpressionType::Sql => "SQL",
+            ExpressionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ExpressionType {
+    fn default() -> ExpressionType {
+        ExpressionType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ExpressionType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ExpressionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ExpressionType {
+    fn from(value: &'a str) -> ExpressionType {
+        match value {
+            "SQL" => ExpressionType::Sql,
+            _ => ExpressionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ExpressionType {
+    fn from(value: String) -> ExpressionType {
+        match value.as_str() {
+            "SQL" => ExpressionType::Sql,
+            _ => ExpressionType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ExpressionType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ExpressionType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ExpressionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ExpressionType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ExpressionType {
+    fn deserialize<D>(deserializer: D) -> Result<ExpressionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FileHeaderInfo {
+    /// `USE`
+    Use,
+    /// `IGNORE`
+    Ignore,
+    /// `NONE`
+    None,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl FileHeaderInfo {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FileHeaderInfo::Use => "USE",
+            FileHeaderInfo::Ignore => "IGNORE",
+            FileHeaderInfo::None => "NONE",
+            FileHeaderInfo::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for FileHeaderInfo {
+    fn default() -> FileHeaderInfo {
+        FileHeaderInfo::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for FileHeaderInfo {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FileHeaderInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FileHeaderInfo {
+    fn from(value: &'a str) -> FileHeaderInfo {
+        match value {
+            "USE" => FileHeaderInfo::Use,
+            "IGNORE" => FileHeaderInfo::Ignore,
+            "NONE" => FileHeaderInfo::None,
+            _ => FileHeaderInfo::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FileHeaderInfo {
+    fn from(value: String) -> FileHeaderInfo {
+        match value.as_str() {
+            "USE" => FileHeaderInfo::Use,
+            "IGNORE" => FileHeaderInfo::Ignore,
+            "NONE" => FileHeaderInfo::None,
+            _ => FileHeaderInfo::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for FileHeaderInfo {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<FileHeaderInfo, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FileHeaderInfo {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FileHeaderInfo {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FileHeaderInfo {
+    fn deserialize<D>(deserializer: D) -> Result<FileHeaderInfo, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Input for GetDataRetrievalPolicy.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -489,7 +984,7 @@ pub struct GlacierJobDescription {
     /// <p>The job type. This value is either <code>ArchiveRetrieval</code>, <code>InventoryRetrieval</code>, or <code>Select</code>. </p>
     #[serde(rename = "Action")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub action: Option<String>,
+    pub action: Option<ActionCode>,
     /// <p>The archive ID requested for a select job or archive retrieval. Otherwise, this field is null.</p>
     #[serde(rename = "ArchiveId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -557,7 +1052,7 @@ pub struct GlacierJobDescription {
     /// <p>The status code can be <code>InProgress</code>, <code>Succeeded</code>, or <code>Failed</code>, and indicates the status of the job.</p>
     #[serde(rename = "StatusCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status_code: Option<String>,
+    pub status_code: Option<StatusCode>,
     /// <p>A friendly message that describes the job status.</p>
     #[serde(rename = "StatusMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -582,7 +1077,7 @@ pub struct Grant {
     /// <p>Specifies the permission given to the grantee. </p>
     #[serde(rename = "Permission")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub permission: Option<String>,
+    pub permission: Option<Permission>,
 }
 
 /// <p>Contains information about the grantee.</p>
@@ -602,7 +1097,7 @@ pub struct Grantee {
     pub id: Option<String>,
     /// <p>Type of grantee</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: Type,
     /// <p>URI of the grantee group.</p>
     #[serde(rename = "URI")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1041,6 +1536,114 @@ pub struct PartListElement {
     pub sha256_tree_hash: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Permission {
+    /// `FULL_CONTROL`
+    FullControl,
+    /// `WRITE`
+    Write,
+    /// `WRITE_ACP`
+    WriteAcp,
+    /// `READ`
+    Read,
+    /// `READ_ACP`
+    ReadAcp,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Permission {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Permission::FullControl => "FULL_CONTROL",
+            Permission::Write => "WRITE",
+            Permission::WriteAcp => "WRITE_ACP",
+            Permission::Read => "READ",
+            Permission::ReadAcp => "READ_ACP",
+            Permission::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Permission {
+    fn default() -> Permission {
+        Permission::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Permission {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Permission {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Permission {
+    fn from(value: &'a str) -> Permission {
+        match value {
+            "FULL_CONTROL" => Permission::FullControl,
+            "WRITE" => Permission::Write,
+            "WRITE_ACP" => Permission::WriteAcp,
+            "READ" => Permission::Read,
+            "READ_ACP" => Permission::ReadAcp,
+            _ => Permission::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Permission {
+    fn from(value: String) -> Permission {
+        match value.as_str() {
+            "FULL_CONTROL" => Permission::FullControl,
+            "WRITE" => Permission::Write,
+            "WRITE_ACP" => Permission::WriteAcp,
+            "READ" => Permission::Read,
+            "READ_ACP" => Permission::ReadAcp,
+            _ => Permission::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Permission {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Permission, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Permission {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Permission {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Permission {
+    fn deserialize<D>(deserializer: D) -> Result<Permission, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The definition for a provisioned capacity unit.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1076,6 +1679,99 @@ pub struct PurchaseProvisionedCapacityOutput {
     pub capacity_id: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum QuoteFields {
+    /// `ALWAYS`
+    Always,
+    /// `ASNEEDED`
+    Asneeded,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl QuoteFields {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            QuoteFields::Always => "ALWAYS",
+            QuoteFields::Asneeded => "ASNEEDED",
+            QuoteFields::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for QuoteFields {
+    fn default() -> QuoteFields {
+        QuoteFields::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for QuoteFields {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for QuoteFields {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for QuoteFields {
+    fn from(value: &'a str) -> QuoteFields {
+        match value {
+            "ALWAYS" => QuoteFields::Always,
+            "ASNEEDED" => QuoteFields::Asneeded,
+            _ => QuoteFields::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for QuoteFields {
+    fn from(value: String) -> QuoteFields {
+        match value.as_str() {
+            "ALWAYS" => QuoteFields::Always,
+            "ASNEEDED" => QuoteFields::Asneeded,
+            _ => QuoteFields::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for QuoteFields {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<QuoteFields, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for QuoteFields {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for QuoteFields {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for QuoteFields {
+    fn deserialize<D>(deserializer: D) -> Result<QuoteFields, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The input value for <code>RemoveTagsFromVaultInput</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -1106,7 +1802,7 @@ pub struct S3Location {
     /// <p>The canned access control list (ACL) to apply to the job results.</p>
     #[serde(rename = "CannedACL")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub canned_acl: Option<String>,
+    pub canned_acl: Option<CannedACL>,
     /// <p>Contains information about the encryption used to store the job results in Amazon S3.</p>
     #[serde(rename = "Encryption")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1118,7 +1814,7 @@ pub struct S3Location {
     /// <p>The storage class used to store the job results.</p>
     #[serde(rename = "StorageClass")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub storage_class: Option<String>,
+    pub storage_class: Option<StorageClass>,
     /// <p>The tag-set that is applied to the job results.</p>
     #[serde(rename = "Tagging")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1139,7 +1835,7 @@ pub struct SelectParameters {
     /// <p>The type of the provided expression, for example <code>SQL</code>.</p>
     #[serde(rename = "ExpressionType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expression_type: Option<String>,
+    pub expression_type: Option<ExpressionType>,
     /// <p>Describes the serialization format of the object.</p>
     #[serde(rename = "InputSerialization")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1195,6 +1891,300 @@ pub struct SetVaultNotificationsInput {
     pub vault_notification_config: Option<VaultNotificationConfig>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StatusCode {
+    /// `InProgress`
+    InProgress,
+    /// `Succeeded`
+    Succeeded,
+    /// `Failed`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl StatusCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StatusCode::InProgress => "InProgress",
+            StatusCode::Succeeded => "Succeeded",
+            StatusCode::Failed => "Failed",
+            StatusCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for StatusCode {
+    fn default() -> StatusCode {
+        StatusCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for StatusCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for StatusCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for StatusCode {
+    fn from(value: &'a str) -> StatusCode {
+        match value {
+            "InProgress" => StatusCode::InProgress,
+            "Succeeded" => StatusCode::Succeeded,
+            "Failed" => StatusCode::Failed,
+            _ => StatusCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StatusCode {
+    fn from(value: String) -> StatusCode {
+        match value.as_str() {
+            "InProgress" => StatusCode::InProgress,
+            "Succeeded" => StatusCode::Succeeded,
+            "Failed" => StatusCode::Failed,
+            _ => StatusCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for StatusCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<StatusCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StatusCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for StatusCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StatusCode {
+    fn deserialize<D>(deserializer: D) -> Result<StatusCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StorageClass {
+    /// `STANDARD`
+    Standard,
+    /// `REDUCED_REDUNDANCY`
+    ReducedRedundancy,
+    /// `STANDARD_IA`
+    StandardIa,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl StorageClass {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StorageClass::Standard => "STANDARD",
+            StorageClass::ReducedRedundancy => "REDUCED_REDUNDANCY",
+            StorageClass::StandardIa => "STANDARD_IA",
+            StorageClass::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for StorageClass {
+    fn default() -> StorageClass {
+        StorageClass::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for StorageClass {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for StorageClass {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for StorageClass {
+    fn from(value: &'a str) -> StorageClass {
+        match value {
+            "STANDARD" => StorageClass::Standard,
+            "REDUCED_REDUNDANCY" => StorageClass::ReducedRedundancy,
+            "STANDARD_IA" => StorageClass::StandardIa,
+            _ => StorageClass::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StorageClass {
+    fn from(value: String) -> StorageClass {
+        match value.as_str() {
+            "STANDARD" => StorageClass::Standard,
+            "REDUCED_REDUNDANCY" => StorageClass::ReducedRedundancy,
+            "STANDARD_IA" => StorageClass::StandardIa,
+            _ => StorageClass::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for StorageClass {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<StorageClass, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StorageClass {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for StorageClass {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StorageClass {
+    fn deserialize<D>(deserializer: D) -> Result<StorageClass, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Type {
+    /// `AmazonCustomerByEmail`
+    AmazonCustomerByEmail,
+    /// `CanonicalUser`
+    CanonicalUser,
+    /// `Group`
+    Group,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Type {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Type::AmazonCustomerByEmail => "AmazonCustomerByEmail",
+            Type::CanonicalUser => "CanonicalUser",
+            Type::Group => "Group",
+            Type::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Type {
+    fn default() -> Type {
+        Type::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Type {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Type {
+    fn from(value: &'a str) -> Type {
+        match value {
+            "AmazonCustomerByEmail" => Type::AmazonCustomerByEmail,
+            "CanonicalUser" => Type::CanonicalUser,
+            "Group" => Type::Group,
+            _ => Type::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Type {
+    fn from(value: String) -> Type {
+        match value.as_str() {
+            "AmazonCustomerByEmail" => Type::AmazonCustomerByEmail,
+            "CanonicalUser" => Type::CanonicalUser,
+            "Group" => Type::Group,
+            _ => Type::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Type {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Type, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Type {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Type {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Type {
+    fn deserialize<D>(deserializer: D) -> Result<Type, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Provides options to add an archive to a vault.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -3305,11 +4295,12 @@ pub struct GlacierClient {
 impl GlacierClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> GlacierClient {
         GlacierClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Glacier"),
         }
     }
 
@@ -3348,6 +4339,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "glacier", &self.region, &request_uri);
+        request.set_operation("AbortMultipartUpload");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3379,6 +4371,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "glacier", &self.region, &request_uri);
+        request.set_operation("AbortVaultLock");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3410,6 +4403,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
+        request.set_operation("AddTagsToVault");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3449,6 +4443,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
+        request.set_operation("CompleteMultipartUpload");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3502,6 +4497,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
+        request.set_operation("CompleteVaultLock");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3533,6 +4529,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("PUT", "glacier", &self.region, &request_uri);
+        request.set_operation("CreateVault");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3570,6 +4567,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "glacier", &self.region, &request_uri);
+        request.set_operation("DeleteArchive");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3601,6 +4599,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "glacier", &self.region, &request_uri);
+        request.set_operation("DeleteVault");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3632,6 +4631,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "glacier", &self.region, &request_uri);
+        request.set_operation("DeleteVaultAccessPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3663,6 +4663,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "glacier", &self.region, &request_uri);
+        request.set_operation("DeleteVaultNotifications");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3695,6 +4696,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("DescribeJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3727,6 +4729,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("DescribeVault");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3758,6 +4761,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("GetDataRetrievalPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3791,6 +4795,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("GetJobOutput");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3849,6 +4854,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("GetVaultAccessPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3881,6 +4887,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("GetVaultLock");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3913,6 +4920,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("GetVaultNotifications");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3945,6 +4953,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
+        request.set_operation("InitiateJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3992,6 +5001,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
+        request.set_operation("InitiateMultipartUpload");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4043,6 +5053,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
+        request.set_operation("InitiateVaultLock");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4082,6 +5093,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("ListJobs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4129,6 +5141,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("ListMultipartUploads");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4171,6 +5184,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("ListParts");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4211,6 +5225,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("ListProvisionedCapacity");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4243,6 +5258,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("ListTagsForVault");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4271,6 +5287,7 @@ impl Glacier for GlacierClient {
         let request_uri = format!("/{account_id}/vaults", account_id = input.account_id);
 
         let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("ListVaults");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4312,6 +5329,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
+        request.set_operation("PurchaseProvisionedCapacity");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4348,6 +5366,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
+        request.set_operation("RemoveTagsFromVault");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4385,6 +5404,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("PUT", "glacier", &self.region, &request_uri);
+        request.set_operation("SetDataRetrievalPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4419,6 +5439,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("PUT", "glacier", &self.region, &request_uri);
+        request.set_operation("SetVaultAccessPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4453,6 +5474,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("PUT", "glacier", &self.region, &request_uri);
+        request.set_operation("SetVaultNotifications");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4487,6 +5509,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
+        request.set_operation("UploadArchive");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4550,6 +5573,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("PUT", "glacier", &self.region, &request_uri);
+        request.set_operation("UploadMultipartPart");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4589,3 +5613,200 @@ impl Glacier for GlacierClient {
         }
     }
 }
+impl GlacierClient {
+    /// Auto-paginating version of `list_jobs`, streaming every page of results.
+    pub fn list_jobs_pages(
+        &self,
+        input: ListJobsInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListJobsOutput, ListJobsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GlacierClient, input: ListJobsInput| async move { client.list_jobs(input).await },
+            |input: &ListJobsInput, output: &ListJobsOutput| {
+                Some(ListJobsInput {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_jobs`, streaming the `JobList` of every page of results.
+    pub fn list_jobs_items(
+        &self,
+        input: ListJobsInput,
+    ) -> rusoto_core::pagination::RusotoStream<GlacierJobDescription, ListJobsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_jobs_pages(input),
+            |output: ListJobsOutput| output.job_list.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_multipart_uploads`, streaming every page of results.
+    pub fn list_multipart_uploads_pages(
+        &self,
+        input: ListMultipartUploadsInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListMultipartUploadsOutput, ListMultipartUploadsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GlacierClient, input: ListMultipartUploadsInput| async move {
+                client.list_multipart_uploads(input).await
+            },
+            |input: &ListMultipartUploadsInput, output: &ListMultipartUploadsOutput| {
+                Some(ListMultipartUploadsInput {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_multipart_uploads`, streaming the `UploadsList` of every page of results.
+    pub fn list_multipart_uploads_items(
+        &self,
+        input: ListMultipartUploadsInput,
+    ) -> rusoto_core::pagination::RusotoStream<UploadListElement, ListMultipartUploadsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_multipart_uploads_pages(input),
+            |output: ListMultipartUploadsOutput| output.uploads_list.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_parts`, streaming every page of results.
+    pub fn list_parts_pages(
+        &self,
+        input: ListPartsInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListPartsOutput, ListPartsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GlacierClient, input: ListPartsInput| async move { client.list_parts(input).await },
+            |input: &ListPartsInput, output: &ListPartsOutput| {
+                Some(ListPartsInput {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_parts`, streaming the `Parts` of every page of results.
+    pub fn list_parts_items(
+        &self,
+        input: ListPartsInput,
+    ) -> rusoto_core::pagination::RusotoStream<PartListElement, ListPartsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_parts_pages(input),
+            |output: ListPartsOutput| output.parts.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_vaults`, streaming every page of results.
+    pub fn list_vaults_pages(
+        &self,
+        input: ListVaultsInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListVaultsOutput, ListVaultsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GlacierClient, input: ListVaultsInput| async move {
+                client.list_vaults(input).await
+            },
+            |input: &ListVaultsInput, output: &ListVaultsOutput| {
+                Some(ListVaultsInput {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_vaults`, streaming the `VaultList` of every page of results.
+    pub fn list_vaults_items(
+        &self,
+        input: ListVaultsInput,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeVaultOutput, ListVaultsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_vaults_pages(input),
+            |output: ListVaultsOutput| output.vault_list.unwrap_or_default(),
+        )
+    }
+}
+impl GlacierClient {
+    /// Waits until `describe_vault` reports the `VaultExists` state, polling every 3 seconds up to 15 times.
+    pub async fn wait_until_vault_exists(
+        &self,
+        input: DescribeVaultInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeVaultError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(3), 15);
+        self.wait_until_vault_exists_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_vault_exists`, polling as often and as many times as `config` says.
+    pub async fn wait_until_vault_exists_with_config(
+        &self,
+        input: DescribeVaultInput,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeVaultError>> {
+        rusoto_core::waiter::wait(
+            "VaultExists",
+            &config,
+            || self.describe_vault(input.clone()),
+            |result: &Result<DescribeVaultOutput, RusotoError<DescribeVaultError>>| match result {
+                Ok(_) => Some(rusoto_core::waiter::WaiterState::Success),
+                Err(RusotoError::Service(DescribeVaultError::ResourceNotFound(_))) => {
+                    Some(rusoto_core::waiter::WaiterState::Retry)
+                }
+                Err(RusotoError::Unknown(response))
+                    if rusoto_core::retry::error_code(response).as_deref()
+                        == Some("ResourceNotFoundException") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Retry)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+
+    /// Waits until `describe_vault` reports the `VaultNotExists` state, polling every 3 seconds up to 15 times.
+    pub async fn wait_until_vault_not_exists(
+        &self,
+        input: DescribeVaultInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeVaultError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(3), 15);
+        self.wait_until_vault_not_exists_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_vault_not_exists`, polling as often and as many times as `config` says.
+    pub async fn wait_until_vault_not_exists_with_config(
+        &self,
+        input: DescribeVaultInput,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeVaultError>> {
+        rusoto_core::waiter::wait(
+            "VaultNotExists",
+            &config,
+            || self.describe_vault(input.clone()),
+            |result: &Result<DescribeVaultOutput, RusotoError<DescribeVaultError>>| match result {
+                Ok(_) => Some(rusoto_core::waiter::WaiterState::Retry),
+                Err(RusotoError::Service(DescribeVaultError::ResourceNotFound(_))) => {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Err(RusotoError::Unknown(response))
+                    if rusoto_core::retry::error_code(response).as_deref()
+                        == Some("ResourceNotFoundException") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+}
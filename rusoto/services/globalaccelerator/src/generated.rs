@@ -34,8 +34,13 @@ pub struct Accelerator {
     pub accelerator_arn: Option<String>,
     /// <p>The date and time that the accelerator was created.</p>
     #[serde(rename = "CreatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time: Option<f64>,
+    pub created_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The Domain Name System (DNS) name that Global Accelerator creates that points to your accelerator's static IP addresses. </p> <p>The naming convention for the DNS name is: a lower case letter a, followed by a 16-bit random hex string, followed by .awsglobalaccelerator.com. For example: a1234567890abcdef.awsglobalaccelerator.com.</p> <p>For more information about the default DNS name, see <a href="https://docs.aws.amazon.com/global-accelerator/latest/dg/about-accelerators.html#about-accelerators.dns-addressing">Support for DNS Addressing in Global Accelerator</a> in the <i>AWS Global Accelerator Developer Guide</i>.</p>
     #[serde(rename = "DnsName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -47,15 +52,20 @@ pub struct Accelerator {
     /// <p>The value for the address type must be IPv4. </p>
     #[serde(rename = "IpAddressType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ip_address_type: Option<String>,
+    pub ip_address_type: Option<IpAddressType>,
     /// <p>The static IP addresses that Global Accelerator associates with the accelerator.</p>
     #[serde(rename = "IpSets")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ip_sets: Option<Vec<IpSet>>,
     /// <p>The date and time that the accelerator was last modified.</p>
     #[serde(rename = "LastModifiedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_time: Option<f64>,
+    pub last_modified_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the accelerator. The name must contain only alphanumeric characters or hyphens (-), and must not begin or end with a hyphen.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -63,7 +73,7 @@ pub struct Accelerator {
     /// <p>Describes the deployment status of the accelerator.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<AcceleratorStatus>,
 }
 
 /// <p>Attributes of an accelerator.</p>
@@ -84,6 +94,192 @@ pub struct AcceleratorAttributes {
     pub flow_logs_s3_prefix: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AcceleratorStatus {
+    /// `DEPLOYED`
+    Deployed,
+    /// `IN_PROGRESS`
+    InProgress,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AcceleratorStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AcceleratorStatus::Deployed => "DEPLOYED",
+            AcceleratorStatus::InProgress => "IN_PROGRESS",
+            AcceleratorStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AcceleratorStatus {
+    fn default() -> AcceleratorStatus {
+        AcceleratorStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AcceleratorStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AcceleratorStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AcceleratorStatus {
+    fn from(value: &'a str) -> AcceleratorStatus {
+        match value {
+            "DEPLOYED" => AcceleratorStatus::Deployed,
+            "IN_PROGRESS" => AcceleratorStatus::InProgress,
+            _ => AcceleratorStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AcceleratorStatus {
+    fn from(value: String) -> AcceleratorStatus {
+        match value.as_str() {
+            "DEPLOYED" => AcceleratorStatus::Deployed,
+            "IN_PROGRESS" => AcceleratorStatus::InProgress,
+            _ => AcceleratorStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AcceleratorStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AcceleratorStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AcceleratorStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AcceleratorStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AcceleratorStatus {
+    fn deserialize<D>(deserializer: D) -> Result<AcceleratorStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ClientAffinity {
+    /// `NONE`
+    None,
+    /// `SOURCE_IP`
+    SourceIp,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ClientAffinity {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ClientAffinity::None => "NONE",
+            ClientAffinity::SourceIp => "SOURCE_IP",
+            ClientAffinity::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ClientAffinity {
+    fn default() -> ClientAffinity {
+        ClientAffinity::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ClientAffinity {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ClientAffinity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ClientAffinity {
+    fn from(value: &'a str) -> ClientAffinity {
+        match value {
+            "NONE" => ClientAffinity::None,
+            "SOURCE_IP" => ClientAffinity::SourceIp,
+            _ => ClientAffinity::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ClientAffinity {
+    fn from(value: String) -> ClientAffinity {
+        match value.as_str() {
+            "NONE" => ClientAffinity::None,
+            "SOURCE_IP" => ClientAffinity::SourceIp,
+            _ => ClientAffinity::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ClientAffinity {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ClientAffinity, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ClientAffinity {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ClientAffinity {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ClientAffinity {
+    fn deserialize<D>(deserializer: D) -> Result<ClientAffinity, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateAcceleratorRequest {
@@ -97,7 +293,7 @@ pub struct CreateAcceleratorRequest {
     /// <p>The value for the address type must be IPv4. </p>
     #[serde(rename = "IpAddressType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ip_address_type: Option<String>,
+    pub ip_address_type: Option<IpAddressType>,
     /// <p>The name of an accelerator. The name can have a maximum of 32 characters, must contain only alphanumeric characters or hyphens (-), and must not begin or end with a hyphen.</p>
     #[serde(rename = "Name")]
     pub name: String,
@@ -137,7 +333,7 @@ pub struct CreateEndpointGroupRequest {
     /// <p>The protocol that AWS Global Accelerator uses to check the health of endpoints that are part of this endpoint group. The default value is TCP.</p>
     #[serde(rename = "HealthCheckProtocol")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub health_check_protocol: Option<String>,
+    pub health_check_protocol: Option<HealthCheckProtocol>,
     /// <p>A unique, case-sensitive identifier that you provide to ensure the idempotency—that is, the uniqueness—of the request.</p>
     #[serde(rename = "IdempotencyToken")]
     pub idempotency_token: String,
@@ -172,7 +368,7 @@ pub struct CreateListenerRequest {
     /// <p>Client affinity lets you direct all requests from a user to the same endpoint, if you have stateful applications, regardless of the port and protocol of the client request. Clienty affinity gives you control over whether to always route each client to the same specific endpoint.</p> <p>AWS Global Accelerator uses a consistent-flow hashing algorithm to choose the optimal endpoint for a connection. If client affinity is <code>NONE</code>, Global Accelerator uses the "five-tuple" (5-tuple) properties—source IP address, source port, destination IP address, destination port, and protocol—to select the hash value, and then chooses the best endpoint. However, with this setting, if someone uses different ports to connect to Global Accelerator, their connections might not be always routed to the same endpoint because the hash value changes. </p> <p>If you want a given client to always be routed to the same endpoint, set client affinity to <code>SOURCE_IP</code> instead. When you use the <code>SOURCE_IP</code> setting, Global Accelerator uses the "two-tuple" (2-tuple) properties— source (client) IP address and destination IP address—to select the hash value.</p> <p>The default value is <code>NONE</code>.</p>
     #[serde(rename = "ClientAffinity")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub client_affinity: Option<String>,
+    pub client_affinity: Option<ClientAffinity>,
     /// <p>A unique, case-sensitive identifier that you provide to ensure the idempotency—that is, the uniqueness—of the request.</p>
     #[serde(rename = "IdempotencyToken")]
     pub idempotency_token: String,
@@ -181,7 +377,7 @@ pub struct CreateListenerRequest {
     pub port_ranges: Vec<PortRange>,
     /// <p>The protocol for connections from clients to your accelerator.</p>
     #[serde(rename = "Protocol")]
-    pub protocol: String,
+    pub protocol: Protocol,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -322,7 +518,7 @@ pub struct EndpointDescription {
     /// <p>The health status of the endpoint.</p>
     #[serde(rename = "HealthState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub health_state: Option<String>,
+    pub health_state: Option<HealthState>,
     /// <p>The weight associated with the endpoint. When you add weights to endpoints, you configure AWS Global Accelerator to route traffic based on proportions that you specify. For example, you might specify endpoint weights of 4, 5, 5, and 6 (sum=20). The result is that 4/20 of your traffic, on average, is routed to the first endpoint, 5/20 is routed both to the second and third endpoints, and 6/20 is routed to the last endpoint. For more information, see <a href="https://docs.aws.amazon.com/global-accelerator/latest/dg/about-endpoints-endpoint-weights.html">Endpoint Weights</a> in the <i>AWS Global Accelerator Developer Guide</i>. </p>
     #[serde(rename = "Weight")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -360,7 +556,7 @@ pub struct EndpointGroup {
     /// <p>The protocol that Global Accelerator uses to perform health checks on endpoints that are part of this endpoint group. The default value is TCP.</p>
     #[serde(rename = "HealthCheckProtocol")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub health_check_protocol: Option<String>,
+    pub health_check_protocol: Option<HealthCheckProtocol>,
     /// <p>The number of consecutive health checks required to set the state of a healthy endpoint to unhealthy, or to set an unhealthy endpoint to healthy. The default value is 3.</p>
     #[serde(rename = "ThresholdCount")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -371,6 +567,295 @@ pub struct EndpointGroup {
     pub traffic_dial_percentage: Option<f32>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum HealthCheckProtocol {
+    /// `TCP`
+    Tcp,
+    /// `HTTP`
+    Http,
+    /// `HTTPS`
+    Https,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl HealthCheckProtocol {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            HealthCheckProtocol::Tcp => "TCP",
+            HealthCheckProtocol::Http => "HTTP",
+            HealthCheckProtocol::Https => "HTTPS",
+            HealthCheckProtocol::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for HealthCheckProtocol {
+    fn default() -> HealthCheckProtocol {
+        HealthCheckProtocol::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for HealthCheckProtocol {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for HealthCheckProtocol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for HealthCheckProtocol {
+    fn from(value: &'a str) -> HealthCheckProtocol {
+        match value {
+            "TCP" => HealthCheckProtocol::Tcp,
+            "HTTP" => HealthCheckProtocol::Http,
+            "HTTPS" => HealthCheckProtocol::Https,
+            _ => HealthCheckProtocol::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for HealthCheckProtocol {
+    fn from(value: String) -> HealthCheckProtocol {
+        match value.as_str() {
+            "TCP" => HealthCheckProtocol::Tcp,
+            "HTTP" => HealthCheckProtocol::Http,
+            "HTTPS" => HealthCheckProtocol::Https,
+            _ => HealthCheckProtocol::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for HealthCheckProtocol {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<HealthCheckProtocol, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for HealthCheckProtocol {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for HealthCheckProtocol {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for HealthCheckProtocol {
+    fn deserialize<D>(deserializer: D) -> Result<HealthCheckProtocol, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum HealthState {
+    /// `INITIAL`
+    Initial,
+    /// `HEALTHY`
+    Healthy,
+    /// `UNHEALTHY`
+    Unhealthy,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl HealthState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            HealthState::Initial => "INITIAL",
+            HealthState::Healthy => "HEALTHY",
+            HealthState::Unhealthy => "UNHEALTHY",
+            HealthState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for HealthState {
+    fn default() -> HealthState {
+        HealthState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for HealthState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for HealthState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for HealthState {
+    fn from(value: &'a str) -> HealthState {
+        match value {
+            "INITIAL" => HealthState::Initial,
+            "HEALTHY" => HealthState::Healthy,
+            "UNHEALTHY" => HealthState::Unhealthy,
+            _ => HealthState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for HealthState {
+    fn from(value: String) -> HealthState {
+        match value.as_str() {
+            "INITIAL" => HealthState::Initial,
+            "HEALTHY" => HealthState::Healthy,
+            "UNHEALTHY" => HealthState::Unhealthy,
+            _ => HealthState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for HealthState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<HealthState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for HealthState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for HealthState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for HealthState {
+    fn deserialize<D>(deserializer: D) -> Result<HealthState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum IpAddressType {
+    /// `IPV4`
+    Ipv4,
+    /// `DUAL_STACK`
+    DualStack,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl IpAddressType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            IpAddressType::Ipv4 => "IPV4",
+            IpAddressType::DualStack => "DUAL_STACK",
+            IpAddressType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for IpAddressType {
+    fn default() -> IpAddressType {
+        IpAddressType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for IpAddressType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for IpAddressType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for IpAddressType {
+    fn from(value: &'a str) -> IpAddressType {
+        match value {
+            "IPV4" => IpAddressType::Ipv4,
+            "DUAL_STACK" => IpAddressType::DualStack,
+            _ => IpAddressType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for IpAddressType {
+    fn from(value: String) -> IpAddressType {
+        match value.as_str() {
+            "IPV4" => IpAddressType::Ipv4,
+            "DUAL_STACK" => IpAddressType::DualStack,
+            _ => IpAddressType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for IpAddressType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<IpAddressType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for IpAddressType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for IpAddressType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for IpAddressType {
+    fn deserialize<D>(deserializer: D) -> Result<IpAddressType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A complex type for the set of IP addresses for an accelerator.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -476,7 +961,7 @@ pub struct Listener {
     /// <p>Client affinity lets you direct all requests from a user to the same endpoint, if you have stateful applications, regardless of the port and protocol of the client request. Clienty affinity gives you control over whether to always route each client to the same specific endpoint.</p> <p>AWS Global Accelerator uses a consistent-flow hashing algorithm to choose the optimal endpoint for a connection. If client affinity is <code>NONE</code>, Global Accelerator uses the "five-tuple" (5-tuple) properties—source IP address, source port, destination IP address, destination port, and protocol—to select the hash value, and then chooses the best endpoint. However, with this setting, if someone uses different ports to connect to Global Accelerator, their connections might not be always routed to the same endpoint because the hash value changes. </p> <p>If you want a given client to always be routed to the same endpoint, set client affinity to <code>SOURCE_IP</code> instead. When you use the <code>SOURCE_IP</code> setting, Global Accelerator uses the "two-tuple" (2-tuple) properties— source (client) IP address and destination IP address—to select the hash value.</p> <p>The default value is <code>NONE</code>.</p>
     #[serde(rename = "ClientAffinity")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub client_affinity: Option<String>,
+    pub client_affinity: Option<ClientAffinity>,
     /// <p>The Amazon Resource Name (ARN) of the listener.</p>
     #[serde(rename = "ListenerArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -488,7 +973,7 @@ pub struct Listener {
     /// <p>The protocol for the connections from clients to the accelerator.</p>
     #[serde(rename = "Protocol")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub protocol: Option<String>,
+    pub protocol: Option<Protocol>,
 }
 
 /// <p>A complex type for a range of ports for a listener.</p>
@@ -504,6 +989,99 @@ pub struct PortRange {
     pub to_port: Option<i64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Protocol {
+    /// `TCP`
+    Tcp,
+    /// `UDP`
+    Udp,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Protocol {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Protocol::Tcp => "TCP",
+            Protocol::Udp => "UDP",
+            Protocol::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Protocol {
+    fn default() -> Protocol {
+        Protocol::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Protocol {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Protocol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Protocol {
+    fn from(value: &'a str) -> Protocol {
+        match value {
+            "TCP" => Protocol::Tcp,
+            "UDP" => Protocol::Udp,
+            _ => Protocol::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Protocol {
+    fn from(value: String) -> Protocol {
+        match value.as_str() {
+            "TCP" => Protocol::Tcp,
+            "UDP" => Protocol::Udp,
+            _ => Protocol::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Protocol {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Protocol, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Protocol {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Protocol {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Protocol {
+    fn deserialize<D>(deserializer: D) -> Result<Protocol, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct UpdateAcceleratorAttributesRequest {
@@ -546,7 +1124,7 @@ pub struct UpdateAcceleratorRequest {
     /// <p>The value for the address type must be IPv4. </p>
     #[serde(rename = "IpAddressType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ip_address_type: Option<String>,
+    pub ip_address_type: Option<IpAddressType>,
     /// <p>The name of the accelerator. The name can have a maximum of 32 characters, must contain only alphanumeric characters or hyphens (-), and must not begin or end with a hyphen.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -587,7 +1165,7 @@ pub struct UpdateEndpointGroupRequest {
     /// <p>The protocol that AWS Global Accelerator uses to check the health of endpoints that are part of this endpoint group. The default value is TCP.</p>
     #[serde(rename = "HealthCheckProtocol")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub health_check_protocol: Option<String>,
+    pub health_check_protocol: Option<HealthCheckProtocol>,
     /// <p>The number of consecutive health checks required to set the state of a healthy endpoint to unhealthy, or to set an unhealthy endpoint to healthy. The default value is 3.</p>
     #[serde(rename = "ThresholdCount")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -613,7 +1191,7 @@ pub struct UpdateListenerRequest {
     /// <p>Client affinity lets you direct all requests from a user to the same endpoint, if you have stateful applications, regardless of the port and protocol of the client request. Clienty affinity gives you control over whether to always route each client to the same specific endpoint.</p> <p>AWS Global Accelerator uses a consistent-flow hashing algorithm to choose the optimal endpoint for a connection. If client affinity is <code>NONE</code>, Global Accelerator uses the "five-tuple" (5-tuple) properties—source IP address, source port, destination IP address, destination port, and protocol—to select the hash value, and then chooses the best endpoint. However, with this setting, if someone uses different ports to connect to Global Accelerator, their connections might not be always routed to the same endpoint because the hash value changes. </p> <p>If you want a given client to always be routed to the same endpoint, set client affinity to <code>SOURCE_IP</code> instead. When you use the <code>SOURCE_IP</code> setting, Global Accelerator uses the "two-tuple" (2-tuple) properties— source (client) IP address and destination IP address—to select the hash value.</p> <p>The default value is <code>NONE</code>.</p>
     #[serde(rename = "ClientAffinity")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub client_affinity: Option<String>,
+    pub client_affinity: Option<ClientAffinity>,
     /// <p>The Amazon Resource Name (ARN) of the listener to update.</p>
     #[serde(rename = "ListenerArn")]
     pub listener_arn: String,
@@ -624,7 +1202,7 @@ pub struct UpdateListenerRequest {
     /// <p>The updated protocol for the connections from clients to the accelerator.</p>
     #[serde(rename = "Protocol")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub protocol: Option<String>,
+    pub protocol: Option<Protocol>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1642,11 +2220,12 @@ pub struct GlobalAcceleratorClient {
 impl GlobalAcceleratorClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> GlobalAcceleratorClient {
         GlobalAcceleratorClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Global Accelerator"),
         }
     }
 
@@ -1678,6 +2257,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: CreateAcceleratorRequest,
     ) -> Result<CreateAcceleratorResponse, RusotoError<CreateAcceleratorError>> {
         let mut request = SignedRequest::new("POST", "globalaccelerator", &self.region, "/");
+        request.set_operation("CreateAccelerator");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1709,6 +2289,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: CreateEndpointGroupRequest,
     ) -> Result<CreateEndpointGroupResponse, RusotoError<CreateEndpointGroupError>> {
         let mut request = SignedRequest::new("POST", "globalaccelerator", &self.region, "/");
+        request.set_operation("CreateEndpointGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1740,6 +2321,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: CreateListenerRequest,
     ) -> Result<CreateListenerResponse, RusotoError<CreateListenerError>> {
         let mut request = SignedRequest::new("POST", "globalaccelerator", &self.region, "/");
+        request.set_operation("CreateListener");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GlobalAccelerator_V20180706.CreateListener");
@@ -1767,6 +2349,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: DeleteAcceleratorRequest,
     ) -> Result<(), RusotoError<DeleteAcceleratorError>> {
         let mut request = SignedRequest::new("POST", "globalaccelerator", &self.region, "/");
+        request.set_operation("DeleteAccelerator");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1797,6 +2380,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: DeleteEndpointGroupRequest,
     ) -> Result<(), RusotoError<DeleteEndpointGroupError>> {
         let mut request = SignedRequest::new("POST", "globalaccelerator", &self.region, "/");
+        request.set_operation("DeleteEndpointGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1827,6 +2411,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: DeleteListenerRequest,
     ) -> Result<(), RusotoError<DeleteListenerError>> {
         let mut request = SignedRequest::new("POST", "globalaccelerator", &self.region, "/");
+        request.set_operation("DeleteListener");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GlobalAccelerator_V20180706.DeleteListener");
@@ -1854,6 +2439,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: DescribeAcceleratorRequest,
     ) -> Result<DescribeAcceleratorResponse, RusotoError<DescribeAcceleratorError>> {
         let mut request = SignedRequest::new("POST", "globalaccelerator", &self.region, "/");
+        request.set_operation("DescribeAccelerator");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1888,6 +2474,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         RusotoError<DescribeAcceleratorAttributesError>,
     > {
         let mut request = SignedRequest::new("POST", "globalaccelerator", &self.region, "/");
+        request.set_operation("DescribeAcceleratorAttributes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1919,6 +2506,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: DescribeEndpointGroupRequest,
     ) -> Result<DescribeEndpointGroupResponse, RusotoError<DescribeEndpointGroupError>> {
         let mut request = SignedRequest::new("POST", "globalaccelerator", &self.region, "/");
+        request.set_operation("DescribeEndpointGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1950,6 +2538,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: DescribeListenerRequest,
     ) -> Result<DescribeListenerResponse, RusotoError<DescribeListenerError>> {
         let mut request = SignedRequest::new("POST", "globalaccelerator", &self.region, "/");
+        request.set_operation("DescribeListener");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1981,6 +2570,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: ListAcceleratorsRequest,
     ) -> Result<ListAcceleratorsResponse, RusotoError<ListAcceleratorsError>> {
         let mut request = SignedRequest::new("POST", "globalaccelerator", &self.region, "/");
+        request.set_operation("ListAccelerators");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2012,6 +2602,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: ListEndpointGroupsRequest,
     ) -> Result<ListEndpointGroupsResponse, RusotoError<ListEndpointGroupsError>> {
         let mut request = SignedRequest::new("POST", "globalaccelerator", &self.region, "/");
+        request.set_operation("ListEndpointGroups");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2043,6 +2634,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: ListListenersRequest,
     ) -> Result<ListListenersResponse, RusotoError<ListListenersError>> {
         let mut request = SignedRequest::new("POST", "globalaccelerator", &self.region, "/");
+        request.set_operation("ListListeners");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GlobalAccelerator_V20180706.ListListeners");
@@ -2070,6 +2662,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: UpdateAcceleratorRequest,
     ) -> Result<UpdateAcceleratorResponse, RusotoError<UpdateAcceleratorError>> {
         let mut request = SignedRequest::new("POST", "globalaccelerator", &self.region, "/");
+        request.set_operation("UpdateAccelerator");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2102,6 +2695,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
     ) -> Result<UpdateAcceleratorAttributesResponse, RusotoError<UpdateAcceleratorAttributesError>>
     {
         let mut request = SignedRequest::new("POST", "globalaccelerator", &self.region, "/");
+        request.set_operation("UpdateAcceleratorAttributes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2133,6 +2727,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: UpdateEndpointGroupRequest,
     ) -> Result<UpdateEndpointGroupResponse, RusotoError<UpdateEndpointGroupError>> {
         let mut request = SignedRequest::new("POST", "globalaccelerator", &self.region, "/");
+        request.set_operation("UpdateEndpointGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2164,6 +2759,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: UpdateListenerRequest,
     ) -> Result<UpdateListenerResponse, RusotoError<UpdateListenerError>> {
         let mut request = SignedRequest::new("POST", "globalaccelerator", &self.region, "/");
+        request.set_operation("UpdateListener");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GlobalAccelerator_V20180706.UpdateListener");
@@ -2185,3 +2781,99 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         }
     }
 }
+impl GlobalAcceleratorClient {
+    /// Auto-paginating version of `list_accelerators`, streaming every page of results.
+    pub fn list_accelerators_pages(
+        &self,
+        input: ListAcceleratorsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListAcceleratorsResponse, ListAcceleratorsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GlobalAcceleratorClient, input: ListAcceleratorsRequest| async move {
+                client.list_accelerators(input).await
+            },
+            |input: &ListAcceleratorsRequest, output: &ListAcceleratorsResponse| {
+                Some(ListAcceleratorsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_accelerators`, streaming the `Accelerators` of every page of results.
+    pub fn list_accelerators_items(
+        &self,
+        input: ListAcceleratorsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Accelerator, ListAcceleratorsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_accelerators_pages(input),
+            |output: ListAcceleratorsResponse| output.accelerators.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_endpoint_groups`, streaming every page of results.
+    pub fn list_endpoint_groups_pages(
+        &self,
+        input: ListEndpointGroupsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListEndpointGroupsResponse, ListEndpointGroupsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GlobalAcceleratorClient, input: ListEndpointGroupsRequest| async move {
+                client.list_endpoint_groups(input).await
+            },
+            |input: &ListEndpointGroupsRequest, output: &ListEndpointGroupsResponse| {
+                Some(ListEndpointGroupsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_endpoint_groups`, streaming the `EndpointGroups` of every page of results.
+    pub fn list_endpoint_groups_items(
+        &self,
+        input: ListEndpointGroupsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<EndpointGroup, ListEndpointGroupsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_endpoint_groups_pages(input),
+            |output: ListEndpointGroupsResponse| output.endpoint_groups.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_listeners`, streaming every page of results.
+    pub fn list_listeners_pages(
+        &self,
+        input: ListListenersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListListenersResponse, ListListenersError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GlobalAcceleratorClient, input: ListListenersRequest| async move {
+                client.list_listeners(input).await
+            },
+            |input: &ListListenersRequest, output: &ListListenersResponse| {
+                Some(ListListenersRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_listeners`, streaming the `Listeners` of every page of results.
+    pub fn list_listeners_items(
+        &self,
+        input: ListListenersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Listener, ListListenersError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_listeners_pages(input),
+            |output: ListListenersResponse| output.listeners.unwrap_or_default(),
+        )
+    }
+}
@@ -396,7 +396,7 @@ pub struct CancelMLTaskRunResponse {
     /// <p>The status for this run.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<TaskStatusType>,
     /// <p>The unique identifier for the task run.</p>
     #[serde(rename = "TaskRunId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -407,6 +407,99 @@ pub struct CancelMLTaskRunResponse {
     pub transform_id: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CatalogEncryptionMode {
+    /// `DISABLED`
+    Disabled,
+    /// `SSE-KMS`
+    SseKms,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CatalogEncryptionMode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CatalogEncryptionMode::Disabled => "DISABLED",
+            CatalogEncryptionMode::SseKms => "SSE-KMS",
+            CatalogEncryptionMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CatalogEncryptionMode {
+    fn default() -> CatalogEncryptionMode {
+        CatalogEncryptionMode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CatalogEncryptionMode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CatalogEncryptionMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CatalogEncryptionMode {
+    fn from(value: &'a str) -> CatalogEncryptionMode {
+        match value {
+            "DISABLED" => CatalogEncryptionMode::Disabled,
+            "SSE-KMS" => CatalogEncryptionMode::SseKms,
+            _ => CatalogEncryptionMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CatalogEncryptionMode {
+    fn from(value: String) -> CatalogEncryptionMode {
+        match value.as_str() {
+            "DISABLED" => CatalogEncryptionMode::Disabled,
+            "SSE-KMS" => CatalogEncryptionMode::SseKms,
+            _ => CatalogEncryptionMode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CatalogEncryptionMode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CatalogEncryptionMode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CatalogEncryptionMode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CatalogEncryptionMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CatalogEncryptionMode {
+    fn deserialize<D>(deserializer: D) -> Result<CatalogEncryptionMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Specifies a table definition in the AWS Glue Data Catalog.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -429,8 +522,13 @@ pub struct CatalogImportStatus {
     pub import_completed: Option<bool>,
     /// <p>The time that the migration was started.</p>
     #[serde(rename = "ImportTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub import_time: Option<f64>,
+    pub import_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the person who initiated the migration.</p>
     #[serde(rename = "ImportedBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -476,13 +574,106 @@ pub struct CloudWatchEncryption {
     /// <p>The encryption mode to use for CloudWatch data.</p>
     #[serde(rename = "CloudWatchEncryptionMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub cloud_watch_encryption_mode: Option<String>,
+    pub cloud_watch_encryption_mode: Option<CloudWatchEncryptionMode>,
     /// <p>The Amazon Resource Name (ARN) of the KMS key to be used to encrypt the data.</p>
     #[serde(rename = "KmsKeyArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub kms_key_arn: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CloudWatchEncryptionMode {
+    /// `DISABLED`
+    Disabled,
+    /// `SSE-KMS`
+    SseKms,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CloudWatchEncryptionMode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CloudWatchEncryptionMode::Disabled => "DISABLED",
+            CloudWatchEncryptionMode::SseKms => "SSE-KMS",
+            CloudWatchEncryptionMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CloudWatchEncryptionMode {
+    fn default() -> CloudWatchEncryptionMode {
+        CloudWatchEncryptionMode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CloudWatchEncryptionMode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CloudWatchEncryptionMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CloudWatchEncryptionMode {
+    fn from(value: &'a str) -> CloudWatchEncryptionMode {
+        match value {
+            "DISABLED" => CloudWatchEncryptionMode::Disabled,
+            "SSE-KMS" => CloudWatchEncryptionMode::SseKms,
+            _ => CloudWatchEncryptionMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CloudWatchEncryptionMode {
+    fn from(value: String) -> CloudWatchEncryptionMode {
+        match value.as_str() {
+            "DISABLED" => CloudWatchEncryptionMode::Disabled,
+            "SSE-KMS" => CloudWatchEncryptionMode::SseKms,
+            _ => CloudWatchEncryptionMode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CloudWatchEncryptionMode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CloudWatchEncryptionMode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CloudWatchEncryptionMode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CloudWatchEncryptionMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CloudWatchEncryptionMode {
+    fn deserialize<D>(deserializer: D) -> Result<CloudWatchEncryptionMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents a directional edge in a directed acyclic graph (DAG).</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CodeGenEdge {
@@ -551,13 +742,121 @@ pub struct Column {
     pub type_: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Comparator {
+    /// `EQUALS`
+    Equals,
+    /// `GREATER_THAN`
+    GreaterThan,
+    /// `LESS_THAN`
+    LessThan,
+    /// `GREATER_THAN_EQUALS`
+    GreaterThanEquals,
+    /// `LESS_THAN_EQUALS`
+    LessThanEquals,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Comparator {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Comparator::Equals => "EQUALS",
+            Comparator::GreaterThan => "GREATER_THAN",
+            Comparator::LessThan => "LESS_THAN",
+            Comparator::GreaterThanEquals => "GREATER_THAN_EQUALS",
+            Comparator::LessThanEquals => "LESS_THAN_EQUALS",
+            Comparator::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Comparator {
+    fn default() -> Comparator {
+        Comparator::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Comparator {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Comparator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Comparator {
+    fn from(value: &'a str) -> Comparator {
+        match value {
+            "EQUALS" => Comparator::Equals,
+            "GREATER_THAN" => Comparator::GreaterThan,
+            "LESS_THAN" => Comparator::LessThan,
+            "GREATER_THAN_EQUALS" => Comparator::GreaterThanEquals,
+            "LESS_THAN_EQUALS" => Comparator::LessThanEquals,
+            _ => Comparator::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Comparator {
+    fn from(value: String) -> Comparator {
+        match value.as_str() {
+            "EQUALS" => Comparator::Equals,
+            "GREATER_THAN" => Comparator::GreaterThan,
+            "LESS_THAN" => Comparator::LessThan,
+            "GREATER_THAN_EQUALS" => Comparator::GreaterThanEquals,
+            "LESS_THAN_EQUALS" => Comparator::LessThanEquals,
+            _ => Comparator::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Comparator {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Comparator, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Comparator {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Comparator {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Comparator {
+    fn deserialize<D>(deserializer: D) -> Result<Comparator, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Defines a condition under which a trigger fires.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Condition {
     /// <p>The state of the crawler to which this condition applies.</p>
     #[serde(rename = "CrawlState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub crawl_state: Option<String>,
+    pub crawl_state: Option<CrawlState>,
     /// <p>The name of the crawler to which this condition applies.</p>
     #[serde(rename = "CrawlerName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -569,11 +868,11 @@ pub struct Condition {
     /// <p>A logical operator.</p>
     #[serde(rename = "LogicalOperator")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub logical_operator: Option<String>,
+    pub logical_operator: Option<LogicalOperator>,
     /// <p>The condition state. Currently, the values supported are <code>SUCCEEDED</code>, <code>STOPPED</code>, <code>TIMEOUT</code>, and <code>FAILED</code>.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<JobRunState>,
 }
 
 /// <p>The confusion matrix shows you what your transform is predicting accurately and what types of errors it is making.</p> <p>For more information, see <a href="https://en.wikipedia.org/wiki/Confusion_matrix">Confusion matrix</a> in Wikipedia.</p>
@@ -605,15 +904,20 @@ pub struct Connection {
     /// <p><p>These key-value pairs define parameters for the connection:</p> <ul> <li> <p> <code>HOST</code> - The host URI: either the fully qualified domain name (FQDN) or the IPv4 address of the database host.</p> </li> <li> <p> <code>PORT</code> - The port number, between 1024 and 65535, of the port on which the database host is listening for database connections.</p> </li> <li> <p> <code>USER<em>NAME</code> - The name under which to log in to the database. The value string for <code>USER</em>NAME</code> is &quot;<code>USERNAME</code>&quot;.</p> </li> <li> <p> <code>PASSWORD</code> - A password, if one is used, for the user name.</p> </li> <li> <p> <code>ENCRYPTED<em>PASSWORD</code> - When you enable connection password protection by setting <code>ConnectionPasswordEncryption</code> in the Data Catalog encryption settings, this field stores the encrypted password.</p> </li> <li> <p> <code>JDBC</em>DRIVER<em>JAR</em>URI</code> - The Amazon Simple Storage Service (Amazon S3) path of the JAR file that contains the JDBC driver to use.</p> </li> <li> <p> <code>JDBC<em>DRIVER</em>CLASS<em>NAME</code> - The class name of the JDBC driver to use.</p> </li> <li> <p> <code>JDBC</em>ENGINE</code> - The name of the JDBC engine to use.</p> </li> <li> <p> <code>JDBC<em>ENGINE</em>VERSION</code> - The version of the JDBC engine to use.</p> </li> <li> <p> <code>CONFIG<em>FILES</code> - (Reserved for future use.)</p> </li> <li> <p> <code>INSTANCE</em>ID</code> - The instance ID to use.</p> </li> <li> <p> <code>JDBC<em>CONNECTION</em>URL</code> - The URL for the JDBC connection.</p> </li> <li> <p> <code>JDBC<em>ENFORCE</em>SSL</code> - A Boolean string (true, false) specifying whether Secure Sockets Layer (SSL) with hostname matching is enforced for the JDBC connection on the client. The default is false.</p> </li> <li> <p> <code>CUSTOM<em>JDBC</em>CERT</code> - An Amazon S3 location specifying the customer&#39;s root certificate. AWS Glue uses this root certificate to validate the customer’s certificate when connecting to the customer database. AWS Glue only handles X.509 certificates. The certificate provided must be DER-encoded and supplied in Base64 encoding PEM format.</p> </li> <li> <p> <code>SKIP<em>CUSTOM</em>JDBC<em>CERT</em>VALIDATION</code> - By default, this is <code>false</code>. AWS Glue validates the Signature algorithm and Subject Public Key Algorithm for the customer certificate. The only permitted algorithms for the Signature algorithm are SHA256withRSA, SHA384withRSA or SHA512withRSA. For the Subject Public Key Algorithm, the key length must be at least 2048. You can set the value of this property to <code>true</code> to skip AWS Glue’s validation of the customer certificate.</p> </li> <li> <p> <code>CUSTOM<em>JDBC</em>CERT<em>STRING</code> - A custom JDBC certificate string which is used for domain match or distinguished name match to prevent a man-in-the-middle attack. In Oracle database, this is used as the <code>SSL</em>SERVER<em>CERT</em>DN</code>; in Microsoft SQL Server, this is used as the <code>hostNameInCertificate</code>.</p> </li> </ul></p>
     #[serde(rename = "ConnectionProperties")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub connection_properties: Option<::std::collections::HashMap<String, String>>,
+    pub connection_properties: Option<::std::collections::HashMap<ConnectionPropertyKey, String>>,
     /// <p>The type of the connection. Currently, only JDBC is supported; SFTP is not supported.</p>
     #[serde(rename = "ConnectionType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub connection_type: Option<String>,
+    pub connection_type: Option<ConnectionType>,
     /// <p>The time that this connection definition was created.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The description of the connection.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -624,8 +928,13 @@ pub struct Connection {
     pub last_updated_by: Option<String>,
     /// <p>The last time that this connection definition was updated.</p>
     #[serde(rename = "LastUpdatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_time: Option<f64>,
+    pub last_updated_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A list of criteria that can be used in selecting this connection.</p>
     #[serde(rename = "MatchCriteria")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -646,10 +955,10 @@ pub struct Connection {
 pub struct ConnectionInput {
     /// <p>These key-value pairs define parameters for the connection.</p>
     #[serde(rename = "ConnectionProperties")]
-    pub connection_properties: ::std::collections::HashMap<String, String>,
+    pub connection_properties: ::std::collections::HashMap<ConnectionPropertyKey, String>,
     /// <p>The type of the connection. Currently, only JDBC is supported; SFTP is not supported.</p>
     #[serde(rename = "ConnectionType")]
-    pub connection_type: String,
+    pub connection_type: ConnectionType,
     /// <p>The description of the connection.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -679,6 +988,432 @@ pub struct ConnectionPasswordEncryption {
     pub return_connection_password_encrypted: bool,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ConnectionPropertyKey {
+    /// `HOST`
+    Host,
+    /// `PORT`
+    Port,
+    /// `USERNAME`
+    Username,
+    /// `PASSWORD`
+    Password,
+    /// `ENCRYPTED_PASSWORD`
+    EncryptedPassword,
+    /// `JDBC_DRIVER_JAR_URI`
+    JdbcDriverJarUri,
+    /// `JDBC_DRIVER_CLASS_NAME`
+    JdbcDriverClassName,
+    /// `JDBC_ENGINE`
+    JdbcEngine,
+    /// `JDBC_ENGINE_VERSION`
+    JdbcEngineVersion,
+    /// `CONFIG_FILES`
+    ConfigFiles,
+    /// `INSTANCE_ID`
+    InstanceId,
+    /// `JDBC_CONNECTION_URL`
+    JdbcConnectionUrl,
+    /// `JDBC_ENFORCE_SSL`
+    JdbcEnforceSsl,
+    /// `CUSTOM_JDBC_CERT`
+    CustomJdbcCert,
+    /// `SKIP_CUSTOM_JDBC_CERT_VALIDATION`
+    SkipCustomJdbcCertValidation,
+    /// `CUSTOM_JDBC_CERT_STRING`
+    CustomJdbcCertString,
+    /// `CONNECTION_URL`
+    ConnectionUrl,
+    /// `KAFKA_BOOTSTRAP_SERVERS`
+    KafkaBootstrapServers,
+    /// `KAFKA_SSL_ENABLED`
+    KafkaSslEnabled,
+    /// `KAFKA_CUSTOM_CERT`
+    KafkaCustomCert,
+    /// `KAFKA_SKIP_CUSTOM_CERT_VALIDATION`
+    KafkaSkipCustomCertValidation,
+    /// `KAFKA_CLIENT_KEYSTORE`
+    KafkaClientKeystore,
+    /// `KAFKA_CLIENT_KEYSTORE_PASSWORD`
+    KafkaClientKeystorePassword,
+    /// `KAFKA_CLIENT_KEY_PASSWORD`
+    KafkaClientKeyPassword,
+    /// `ENCRYPTED_KAFKA_CLIENT_KEYSTORE_PASSWORD`
+    EncryptedKafkaClientKeystorePassword,
+    /// `ENCRYPTED_KAFKA_CLIENT_KEY_PASSWORD`
+    EncryptedKafkaClientKeyPassword,
+    /// `SECRET_ID`
+    SecretId,
+    /// `CONNECTOR_URL`
+    ConnectorUrl,
+    /// `CONNECTOR_TYPE`
+    ConnectorType,
+    /// `CONNECTOR_CLASS_NAME`
+    ConnectorClassName,
+    /// `KAFKA_SASL_MECHANISM`
+    KafkaSaslMechanism,
+    /// `KAFKA_SASL_SCRAM_USERNAME`
+    KafkaSaslScramUsername,
+    /// `KAFKA_SASL_SCRAM_PASSWORD`
+    KafkaSaslScramPassword,
+    /// `KAFKA_SASL_SCRAM_SECRETS_ARN`
+    KafkaSaslScramSecretsArn,
+    /// `ENCRYPTED_KAFKA_SASL_SCRAM_PASSWORD`
+    EncryptedKafkaSaslScramPassword,
+    /// `KAFKA_SASL_GSSAPI_KEYTAB`
+    KafkaSaslGssapiKeytab,
+    /// `KAFKA_SASL_GSSAPI_KRB5_CONF`
+    KafkaSaslGssapiKrb5Conf,
+    /// `KAFKA_SASL_GSSAPI_SERVICE`
+    KafkaSaslGssapiService,
+    /// `KAFKA_SASL_GSSAPI_PRINCIPAL`
+    KafkaSaslGssapiPrincipal,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ConnectionPropertyKey {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ConnectionPropertyKey::Host => "HOST",
+            ConnectionPropertyKey::Port => "PORT",
+            ConnectionPropertyKey::Username => "USERNAME",
+            ConnectionPropertyKey::Password => "PASSWORD",
+            ConnectionPropertyKey::EncryptedPassword => "ENCRYPTED_PASSWORD",
+            ConnectionPropertyKey::JdbcDriverJarUri => "JDBC_DRIVER_JAR_URI",
+            ConnectionPropertyKey::JdbcDriverClassName => "JDBC_DRIVER_CLASS_NAME",
+            ConnectionPropertyKey::JdbcEngine => "JDBC_ENGINE",
+            ConnectionPropertyKey::JdbcEngineVersion => "JDBC_ENGINE_VERSION",
+            ConnectionPropertyKey::ConfigFiles => "CONFIG_FILES",
+            ConnectionPropertyKey::InstanceId => "INSTANCE_ID",
+            ConnectionPropertyKey::JdbcConnectionUrl => "JDBC_CONNECTION_URL",
+            ConnectionPropertyKey::JdbcEnforceSsl => "JDBC_ENFORCE_SSL",
+            ConnectionPropertyKey::CustomJdbcCert => "CUSTOM_JDBC_CERT",
+            ConnectionPropertyKey::SkipCustomJdbcCertValidation => {
+                "SKIP_CUSTOM_JDBC_CERT_VALIDATION"
+            }
+            ConnectionPropertyKey::CustomJdbcCertString => "CUSTOM_JDBC_CERT_STRING",
+            ConnectionPropertyKey::ConnectionUrl => "CONNECTION_URL",
+            ConnectionPropertyKey::KafkaBootstrapServers => "KAFKA_BOOTSTRAP_SERVERS",
+            ConnectionPropertyKey::KafkaSslEnabled => "KAFKA_SSL_ENABLED",
+            ConnectionPropertyKey::KafkaCustomCert => "KAFKA_CUSTOM_CERT",
+            ConnectionPropertyKey::KafkaSkipCustomCertValidation => {
+                "KAFKA_SKIP_CUSTOM_CERT_VALIDATION"
+            }
+            ConnectionPropertyKey::KafkaClientKeystore => "KAFKA_CLIENT_KEYSTORE",
+            ConnectionPropertyKey::KafkaClientKeystorePassword => "KAFKA_CLIENT_KEYSTORE_PASSWORD",
+            ConnectionPropertyKey::KafkaClientKeyPassword => "KAFKA_CLIENT_KEY_PASSWORD",
+            ConnectionPropertyKey::EncryptedKafkaClientKeystorePassword => {
+                "ENCRYPTED_KAFKA_CLIENT_KEYSTORE_PASSWORD"
+            }
+            ConnectionPropertyKey::EncryptedKafkaClientKeyPassword => {
+                "ENCRYPTED_KAFKA_CLIENT_KEY_PASSWORD"
+            }
+            ConnectionPropertyKey::SecretId => "SECRET_ID",
+            ConnectionPropertyKey::ConnectorUrl => "CONNECTOR_URL",
+            ConnectionPropertyKey::ConnectorType => "CONNECTOR_TYPE",
+            ConnectionPropertyKey::ConnectorClassName => "CONNECTOR_CLASS_NAME",
+            ConnectionPropertyKey::KafkaSaslMechanism => "KAFKA_SASL_MECHANISM",
+            ConnectionPropertyKey::KafkaSaslScramUsername => "KAFKA_SASL_SCRAM_USERNAME",
+            ConnectionPropertyKey::KafkaSaslScramPassword => "KAFKA_SASL_SCRAM_PASSWORD",
+            ConnectionPropertyKey::KafkaSaslScramSecretsArn => "KAFKA_SASL_SCRAM_SECRETS_ARN",
+            ConnectionPropertyKey::EncryptedKafkaSaslScramPassword => {
+                "ENCRYPTED_KAFKA_SASL_SCRAM_PASSWORD"
+            }
+            ConnectionPropertyKey::KafkaSaslGssapiKeytab => "KAFKA_SASL_GSSAPI_KEYTAB",
+            ConnectionPropertyKey::KafkaSaslGssapiKrb5Conf => "KAFKA_SASL_GSSAPI_KRB5_CONF",
+            ConnectionPropertyKey::KafkaSaslGssapiService => "KAFKA_SASL_GSSAPI_SERVICE",
+            ConnectionPropertyKey::KafkaSaslGssapiPrincipal => "KAFKA_SASL_GSSAPI_PRINCIPAL",
+            ConnectionPropertyKey::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ConnectionPropertyKey {
+    fn default() -> ConnectionPropertyKey {
+        ConnectionPropertyKey::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ConnectionPropertyKey {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ConnectionPropertyKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ConnectionPropertyKey {
+    fn from(value: &'a str) -> ConnectionPropertyKey {
+        match value {
+            "HOST" => ConnectionPropertyKey::Host,
+            "PORT" => ConnectionPropertyKey::Port,
+            "USERNAME" => ConnectionPropertyKey::Username,
+            "PASSWORD" => ConnectionPropertyKey::Password,
+            "ENCRYPTED_PASSWORD" => ConnectionPropertyKey::EncryptedPassword,
+            "JDBC_DRIVER_JAR_URI" => ConnectionPropertyKey::JdbcDriverJarUri,
+            "JDBC_DRIVER_CLASS_NAME" => ConnectionPropertyKey::JdbcDriverClassName,
+            "JDBC_ENGINE" => ConnectionPropertyKey::JdbcEngine,
+            "JDBC_ENGINE_VERSION" => ConnectionPropertyKey::JdbcEngineVersion,
+            "CONFIG_FILES" => ConnectionPropertyKey::ConfigFiles,
+            "INSTANCE_ID" => ConnectionPropertyKey::InstanceId,
+            "JDBC_CONNECTION_URL" => ConnectionPropertyKey::JdbcConnectionUrl,
+            "JDBC_ENFORCE_SSL" => ConnectionPropertyKey::JdbcEnforceSsl,
+            "CUSTOM_JDBC_CERT" => ConnectionPropertyKey::CustomJdbcCert,
+            "SKIP_CUSTOM_JDBC_CERT_VALIDATION" => {
+                ConnectionPropertyKey::SkipCustomJdbcCertValidation
+            }
+            "CUSTOM_JDBC_CERT_STRING" => ConnectionPropertyKey::CustomJdbcCertString,
+            "CONNECTION_URL" => ConnectionPropertyKey::ConnectionUrl,
+            "KAFKA_BOOTSTRAP_SERVERS" => ConnectionPropertyKey::KafkaBootstrapServers,
+            "KAFKA_SSL_ENABLED" => ConnectionPropertyKey::KafkaSslEnabled,
+            "KAFKA_CUSTOM_CERT" => ConnectionPropertyKey::KafkaCustomCert,
+            "KAFKA_SKIP_CUSTOM_CERT_VALIDATION" => {
+                ConnectionPropertyKey::KafkaSkipCustomCertValidation
+            }
+            "KAFKA_CLIENT_KEYSTORE" => ConnectionPropertyKey::KafkaClientKeystore,
+            "KAFKA_CLIENT_KEYSTORE_PASSWORD" => ConnectionPropertyKey::KafkaClientKeystorePassword,
+            "KAFKA_CLIENT_KEY_PASSWORD" => ConnectionPropertyKey::KafkaClientKeyPassword,
+            "ENCRYPTED_KAFKA_CLIENT_KEYSTORE_PASSWORD" => {
+                ConnectionPropertyKey::EncryptedKafkaClientKeystorePassword
+            }
+            "ENCRYPTED_KAFKA_CLIENT_KEY_PASSWORD" => {
+                ConnectionPropertyKey::EncryptedKafkaClientKeyPassword
+            }
+            "SECRET_ID" => ConnectionPropertyKey::SecretId,
+            "CONNECTOR_URL" => ConnectionPropertyKey::ConnectorUrl,
+            "CONNECTOR_TYPE" => ConnectionPropertyKey::ConnectorType,
+            "CONNECTOR_CLASS_NAME" => ConnectionPropertyKey::ConnectorClassName,
+            "KAFKA_SASL_MECHANISM" => ConnectionPropertyKey::KafkaSaslMechanism,
+            "KAFKA_SASL_SCRAM_USERNAME" => ConnectionPropertyKey::KafkaSaslScramUsername,
+            "KAFKA_SASL_SCRAM_PASSWORD" => ConnectionPropertyKey::KafkaSaslScramPassword,
+            "KAFKA_SASL_SCRAM_SECRETS_ARN" => ConnectionPropertyKey::KafkaSaslScramSecretsArn,
+            "ENCRYPTED_KAFKA_SASL_SCRAM_PASSWORD" => {
+                ConnectionPropertyKey::EncryptedKafkaSaslScramPassword
+            }
+            "KAFKA_SASL_GSSAPI_KEYTAB" => ConnectionPropertyKey::KafkaSaslGssapiKeytab,
+            "KAFKA_SASL_GSSAPI_KRB5_CONF" => ConnectionPropertyKey::KafkaSaslGssapiKrb5Conf,
+            "KAFKA_SASL_GSSAPI_SERVICE" => ConnectionPropertyKey::KafkaSaslGssapiService,
+            "KAFKA_SASL_GSSAPI_PRINCIPAL" => ConnectionPropertyKey::KafkaSaslGssapiPrincipal,
+            _ => ConnectionPropertyKey::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ConnectionPropertyKey {
+    fn from(value: String) -> ConnectionPropertyKey {
+        match value.as_str() {
+            "HOST" => ConnectionPropertyKey::Host,
+            "PORT" => ConnectionPropertyKey::Port,
+            "USERNAME" => ConnectionPropertyKey::Username,
+            "PASSWORD" => ConnectionPropertyKey::Password,
+            "ENCRYPTED_PASSWORD" => ConnectionPropertyKey::EncryptedPassword,
+            "JDBC_DRIVER_JAR_URI" => ConnectionPropertyKey::JdbcDriverJarUri,
+            "JDBC_DRIVER_CLASS_NAME" => ConnectionPropertyKey::JdbcDriverClassName,
+            "JDBC_ENGINE" => ConnectionPropertyKey::JdbcEngine,
+            "JDBC_ENGINE_VERSION" => ConnectionPropertyKey::JdbcEngineVersion,
+            "CONFIG_FILES" => ConnectionPropertyKey::ConfigFiles,
+            "INSTANCE_ID" => ConnectionPropertyKey::InstanceId,
+            "JDBC_CONNECTION_URL" => ConnectionPropertyKey::JdbcConnectionUrl,
+            "JDBC_ENFORCE_SSL" => ConnectionPropertyKey::JdbcEnforceSsl,
+            "CUSTOM_JDBC_CERT" => ConnectionPropertyKey::CustomJdbcCert,
+            "SKIP_CUSTOM_JDBC_CERT_VALIDATION" => {
+                ConnectionPropertyKey::SkipCustomJdbcCertValidation
+            }
+            "CUSTOM_JDBC_CERT_STRING" => ConnectionPropertyKey::CustomJdbcCertString,
+            "CONNECTION_URL" => ConnectionPropertyKey::ConnectionUrl,
+            "KAFKA_BOOTSTRAP_SERVERS" => ConnectionPropertyKey::KafkaBootstrapServers,
+            "KAFKA_SSL_ENABLED" => ConnectionPropertyKey::KafkaSslEnabled,
+            "KAFKA_CUSTOM_CERT" => ConnectionPropertyKey::KafkaCustomCert,
+            "KAFKA_SKIP_CUSTOM_CERT_VALIDATION" => {
+                ConnectionPropertyKey::KafkaSkipCustomCertValidation
+            }
+            "KAFKA_CLIENT_KEYSTORE" => ConnectionPropertyKey::KafkaClientKeystore,
+            "KAFKA_CLIENT_KEYSTORE_PASSWORD" => ConnectionPropertyKey::KafkaClientKeystorePassword,
+            "KAFKA_CLIENT_KEY_PASSWORD" => ConnectionPropertyKey::KafkaClientKeyPassword,
+            "ENCRYPTED_KAFKA_CLIENT_KEYSTORE_PASSWORD" => {
+                ConnectionPropertyKey::EncryptedKafkaClientKeystorePassword
+            }
+            "ENCRYPTED_KAFKA_CLIENT_KEY_PASSWORD" => {
+                ConnectionPropertyKey::EncryptedKafkaClientKeyPassword
+            }
+            "SECRET_ID" => ConnectionPropertyKey::SecretId,
+            "CONNECTOR_URL" => ConnectionPropertyKey::ConnectorUrl,
+            "CONNECTOR_TYPE" => ConnectionPropertyKey::ConnectorType,
+            "CONNECTOR_CLASS_NAME" => ConnectionPropertyKey::ConnectorClassName,
+            "KAFKA_SASL_MECHANISM" => ConnectionPropertyKey::KafkaSaslMechanism,
+            "KAFKA_SASL_SCRAM_USERNAME" => ConnectionPropertyKey::KafkaSaslScramUsername,
+            "KAFKA_SASL_SCRAM_PASSWORD" => ConnectionPropertyKey::KafkaSaslScramPassword,
+            "KAFKA_SASL_SCRAM_SECRETS_ARN" => ConnectionPropertyKey::KafkaSaslScramSecretsArn,
+            "ENCRYPTED_KAFKA_SASL_SCRAM_PASSWORD" => {
+                ConnectionPropertyKey::EncryptedKafkaSaslScramPassword
+            }
+            "KAFKA_SASL_GSSAPI_KEYTAB" => ConnectionPropertyKey::KafkaSaslGssapiKeytab,
+            "KAFKA_SASL_GSSAPI_KRB5_CONF" => ConnectionPropertyKey::KafkaSaslGssapiKrb5Conf,
+            "KAFKA_SASL_GSSAPI_SERVICE" => ConnectionPropertyKey::KafkaSaslGssapiService,
+            "KAFKA_SASL_GSSAPI_PRINCIPAL" => ConnectionPropertyKey::KafkaSaslGssapiPrincipal,
+            _ => ConnectionPropertyKey::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ConnectionPropertyKey {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ConnectionPropertyKey, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ConnectionPropertyKey {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ConnectionPropertyKey {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ConnectionPropertyKey {
+    fn deserialize<D>(deserializer: D) -> Result<ConnectionPropertyKey, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ConnectionType {
+    /// `JDBC`
+    Jdbc,
+    /// `SFTP`
+    Sftp,
+    /// `MONGODB`
+    Mongodb,
+    /// `KAFKA`
+    Kafka,
+    /// `NETWORK`
+    Network,
+    /// `MARKETPLACE`
+    Marketplace,
+    /// `CUSTOM`
+    Custom,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ConnectionType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ConnectionType::Jdbc => "JDBC",
+            ConnectionType::Sftp => "SFTP",
+            ConnectionType::Mongodb => "MONGODB",
+            ConnectionType::Kafka => "KAFKA",
+            ConnectionType::Network => "NETWORK",
+            ConnectionType::Marketplace => "MARKETPLACE",
+            ConnectionType::Custom => "CUSTOM",
+            ConnectionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ConnectionType {
+    fn default() -> ConnectionType {
+        ConnectionType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ConnectionType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ConnectionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ConnectionType {
+    fn from(value: &'a str) -> ConnectionType {
+        match value {
+            "JDBC" => ConnectionType::Jdbc,
+            "SFTP" => ConnectionType::Sftp,
+            "MONGODB" => ConnectionType::Mongodb,
+            "KAFKA" => ConnectionType::Kafka,
+            "NETWORK" => ConnectionType::Network,
+            "MARKETPLACE" => ConnectionType::Marketplace,
+            "CUSTOM" => ConnectionType::Custom,
+            _ => ConnectionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ConnectionType {
+    fn from(value: String) -> ConnectionType {
+        match value.as_str() {
+            "JDBC" => ConnectionType::Jdbc,
+            "SFTP" => ConnectionType::Sftp,
+            "MONGODB" => ConnectionType::Mongodb,
+            "KAFKA" => ConnectionType::Kafka,
+            "NETWORK" => ConnectionType::Network,
+            "MARKETPLACE" => ConnectionType::Marketplace,
+            "CUSTOM" => ConnectionType::Custom,
+            _ => ConnectionType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ConnectionType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ConnectionType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ConnectionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ConnectionType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ConnectionType {
+    fn deserialize<D>(deserializer: D) -> Result<ConnectionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Specifies the connections used by a job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ConnectionsList {
@@ -694,8 +1429,13 @@ pub struct ConnectionsList {
 pub struct Crawl {
     /// <p>The date and time on which the crawl completed.</p>
     #[serde(rename = "CompletedOn")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub completed_on: Option<f64>,
+    pub completed_on: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The error message associated with the crawl.</p>
     #[serde(rename = "ErrorMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -710,14 +1450,132 @@ pub struct Crawl {
     pub log_stream: Option<String>,
     /// <p>The date and time on which the crawl started.</p>
     #[serde(rename = "StartedOn")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub started_on: Option<f64>,
+    pub started_on: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The state of the crawler.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<CrawlState>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CrawlState {
+    /// `RUNNING`
+    Running,
+    /// `CANCELLING`
+    Cancelling,
+    /// `CANCELLED`
+    Cancelled,
+    /// `SUCCEEDED`
+    Succeeded,
+    /// `FAILED`
+    Failed,
+    /// `ERROR`
+    Error,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CrawlState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CrawlState::Running => "RUNNING",
+            CrawlState::Cancelling => "CANCELLING",
+            CrawlState::Cancelled => "CANCELLED",
+            CrawlState::Succeeded => "SUCCEEDED",
+            CrawlState::Failed => "FAILED",
+            CrawlState::Error => "ERROR",
+            CrawlState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CrawlState {
+    fn default() -> CrawlState {
+        CrawlState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CrawlState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CrawlState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CrawlState {
+    fn from(value: &'a str) -> CrawlState {
+        match value {
+            "RUNNING" => CrawlState::Running,
+            "CANCELLING" => CrawlState::Cancelling,
+            "CANCELLED" => CrawlState::Cancelled,
+            "SUCCEEDED" => CrawlState::Succeeded,
+            "FAILED" => CrawlState::Failed,
+            "ERROR" => CrawlState::Error,
+            _ => CrawlState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CrawlState {
+    fn from(value: String) -> CrawlState {
+        match value.as_str() {
+            "RUNNING" => CrawlState::Running,
+            "CANCELLING" => CrawlState::Cancelling,
+            "CANCELLED" => CrawlState::Cancelled,
+            "SUCCEEDED" => CrawlState::Succeeded,
+            "FAILED" => CrawlState::Failed,
+            "ERROR" => CrawlState::Error,
+            _ => CrawlState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CrawlState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CrawlState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CrawlState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CrawlState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for CrawlState {
+    fn deserialize<D>(deserializer: D) -> Result<CrawlState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Specifies a crawler program that examines a data source and uses classifiers to try to determine its schema. If successful, the crawler records metadata concerning the data source in the AWS Glue Data Catalog.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -740,8 +1598,13 @@ pub struct Crawler {
     pub crawler_security_configuration: Option<String>,
     /// <p>The time that the crawler was created.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the database in which the crawler's output is stored.</p>
     #[serde(rename = "DatabaseName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -756,8 +1619,13 @@ pub struct Crawler {
     pub last_crawl: Option<LastCrawlInfo>,
     /// <p>The time that the crawler was last updated.</p>
     #[serde(rename = "LastUpdated")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated: Option<f64>,
+    pub last_updated: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the crawler.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -777,7 +1645,7 @@ pub struct Crawler {
     /// <p>Indicates whether the crawler is running, or whether a run is pending.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<CrawlerState>,
     /// <p>The prefix added to the names of tables that are created.</p>
     #[serde(rename = "TablePrefix")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -840,19 +1708,117 @@ pub struct CrawlerNodeDetails {
     pub crawls: Option<Vec<Crawl>>,
 }
 
-/// <p>Specifies data stores to crawl.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct CrawlerTargets {
-    /// <p>Specifies AWS Glue Data Catalog targets.</p>
-    #[serde(rename = "CatalogTargets")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub catalog_targets: Option<Vec<CatalogTarget>>,
-    /// <p>Specifies Amazon DynamoDB targets.</p>
-    #[serde(rename = "DynamoDBTargets")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub dynamo_db_targets: Option<Vec<DynamoDBTarget>>,
-    /// <p>Specifies JDBC targets.</p>
-    #[serde(rename = "JdbcTargets")]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CrawlerState {
+    /// `READY`
+    Ready,
+    /// `RUNNING`
+    Running,
+    /// `STOPPING`
+    Stopping,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CrawlerState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CrawlerState::Ready => "READY",
+            CrawlerState::Running => "RUNNING",
+            CrawlerState::Stopping => "STOPPING",
+            CrawlerState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CrawlerState {
+    fn default() -> CrawlerState {
+        CrawlerState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CrawlerState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CrawlerState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CrawlerState {
+    fn from(value: &'a str) -> CrawlerState {
+        match value {
+            "READY" => CrawlerState::Ready,
+            "RUNNING" => CrawlerState::Running,
+            "STOPPING" => CrawlerState::Stopping,
+            _ => CrawlerState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CrawlerState {
+    fn from(value: String) -> CrawlerState {
+        match value.as_str() {
+            "READY" => CrawlerState::Ready,
+            "RUNNING" => CrawlerState::Running,
+            "STOPPING" => CrawlerState::Stopping,
+            _ => CrawlerState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CrawlerState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CrawlerState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CrawlerState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CrawlerState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CrawlerState {
+    fn deserialize<D>(deserializer: D) -> Result<CrawlerState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Specifies data stores to crawl.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CrawlerTargets {
+    /// <p>Specifies AWS Glue Data Catalog targets.</p>
+    #[serde(rename = "CatalogTargets")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub catalog_targets: Option<Vec<CatalogTarget>>,
+    /// <p>Specifies Amazon DynamoDB targets.</p>
+    #[serde(rename = "DynamoDBTargets")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dynamo_db_targets: Option<Vec<DynamoDBTarget>>,
+    /// <p>Specifies JDBC targets.</p>
+    #[serde(rename = "JdbcTargets")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub jdbc_targets: Option<Vec<JdbcTarget>>,
     /// <p>Specifies Amazon Simple Storage Service (Amazon S3) targets.</p>
@@ -967,7 +1933,7 @@ pub struct CreateCsvClassifierRequest {
     /// <p>Indicates whether the CSV file contains a header.</p>
     #[serde(rename = "ContainsHeader")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub contains_header: Option<String>,
+    pub contains_header: Option<CsvHeaderOption>,
     /// <p>A custom symbol to denote what separates each column entry in the row.</p>
     #[serde(rename = "Delimiter")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1065,7 +2031,7 @@ pub struct CreateDevEndpointRequest {
     /// <p>The type of predefined worker that is allocated to the development endpoint. Accepts a value of Standard, G.1X, or G.2X.</p> <ul> <li> <p>For the <code>Standard</code> worker type, each worker provides 4 vCPU, 16 GB of memory and a 50GB disk, and 2 executors per worker.</p> </li> <li> <p>For the <code>G.1X</code> worker type, each worker maps to 1 DPU (4 vCPU, 16 GB of memory, 64 GB disk), and provides 1 executor per worker. We recommend this worker type for memory-intensive jobs.</p> </li> <li> <p>For the <code>G.2X</code> worker type, each worker maps to 2 DPU (8 vCPU, 32 GB of memory, 128 GB disk), and provides 1 executor per worker. We recommend this worker type for memory-intensive jobs.</p> </li> </ul> <p>Known issue: when a development endpoint is created with the <code>G.2X</code> <code>WorkerType</code> configuration, the Spark drivers for the development endpoint will run on 4 vCPU, 16 GB of memory, and a 64 GB disk. </p>
     #[serde(rename = "WorkerType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub worker_type: Option<String>,
+    pub worker_type: Option<WorkerType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1081,8 +2047,13 @@ pub struct CreateDevEndpointResponse {
     pub availability_zone: Option<String>,
     /// <p>The point in time at which this <code>DevEndpoint</code> was created.</p>
     #[serde(rename = "CreatedTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_timestamp: Option<f64>,
+    pub created_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name assigned to the new <code>DevEndpoint</code>.</p>
     #[serde(rename = "EndpointName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1138,7 +2109,7 @@ pub struct CreateDevEndpointResponse {
     /// <p>The type of predefined worker that is allocated to the development endpoint. May be a value of Standard, G.1X, or G.2X.</p>
     #[serde(rename = "WorkerType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub worker_type: Option<String>,
+    pub worker_type: Option<WorkerType>,
     /// <p>The address of the YARN endpoint used by this <code>DevEndpoint</code>.</p>
     #[serde(rename = "YarnEndpointAddress")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1235,7 +2206,7 @@ pub struct CreateJobRequest {
     /// <p><p>The type of predefined worker that is allocated when a job runs. Accepts a value of Standard, G.1X, or G.2X.</p> <ul> <li> <p>For the <code>Standard</code> worker type, each worker provides 4 vCPU, 16 GB of memory and a 50GB disk, and 2 executors per worker.</p> </li> <li> <p>For the <code>G.1X</code> worker type, each worker maps to 1 DPU (4 vCPU, 16 GB of memory, 64 GB disk), and provides 1 executor per worker. We recommend this worker type for memory-intensive jobs.</p> </li> <li> <p>For the <code>G.2X</code> worker type, each worker maps to 2 DPU (8 vCPU, 32 GB of memory, 128 GB disk), and provides 1 executor per worker. We recommend this worker type for memory-intensive jobs.</p> </li> </ul></p>
     #[serde(rename = "WorkerType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub worker_type: Option<String>,
+    pub worker_type: Option<WorkerType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1301,7 +2272,7 @@ pub struct CreateMLTransformRequest {
     /// <p><p>The type of predefined worker that is allocated when this task runs. Accepts a value of Standard, G.1X, or G.2X.</p> <ul> <li> <p>For the <code>Standard</code> worker type, each worker provides 4 vCPU, 16 GB of memory and a 50GB disk, and 2 executors per worker.</p> </li> <li> <p>For the <code>G.1X</code> worker type, each worker provides 4 vCPU, 16 GB of memory and a 64GB disk, and 1 executor per worker.</p> </li> <li> <p>For the <code>G.2X</code> worker type, each worker provides 8 vCPU, 32 GB of memory and a 128GB disk, and 1 executor per worker.</p> </li> </ul> <p> <code>MaxCapacity</code> is a mutually exclusive option with <code>NumberOfWorkers</code> and <code>WorkerType</code>.</p> <ul> <li> <p>If either <code>NumberOfWorkers</code> or <code>WorkerType</code> is set, then <code>MaxCapacity</code> cannot be set.</p> </li> <li> <p>If <code>MaxCapacity</code> is set then neither <code>NumberOfWorkers</code> or <code>WorkerType</code> can be set.</p> </li> <li> <p>If <code>WorkerType</code> is set, then <code>NumberOfWorkers</code> is required (and vice versa).</p> </li> <li> <p> <code>MaxCapacity</code> and <code>NumberOfWorkers</code> must both be at least 1.</p> </li> </ul></p>
     #[serde(rename = "WorkerType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub worker_type: Option<String>,
+    pub worker_type: Option<WorkerType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1349,7 +2320,7 @@ pub struct CreateScriptRequest {
     /// <p>The programming language of the resulting code from the DAG.</p>
     #[serde(rename = "Language")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub language: Option<String>,
+    pub language: Option<Language>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1381,8 +2352,13 @@ pub struct CreateSecurityConfigurationRequest {
 pub struct CreateSecurityConfigurationResponse {
     /// <p>The time at which the new security configuration was created.</p>
     #[serde(rename = "CreatedTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_timestamp: Option<f64>,
+    pub created_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name assigned to the new security configuration.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1439,7 +2415,7 @@ pub struct CreateTriggerRequest {
     pub tags: Option<::std::collections::HashMap<String, String>>,
     /// <p>The type of the new trigger.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: TriggerType,
     /// <p>The name of the workflow associated with the trigger.</p>
     #[serde(rename = "WorkflowName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1530,11 +2506,16 @@ pub struct CsvClassifier {
     /// <p>Indicates whether the CSV file contains a header.</p>
     #[serde(rename = "ContainsHeader")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub contains_header: Option<String>,
+    pub contains_header: Option<CsvHeaderOption>,
     /// <p>The time that this classifier was registered.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A custom symbol to denote what separates each column entry in the row.</p>
     #[serde(rename = "Delimiter")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1549,8 +2530,13 @@ pub struct CsvClassifier {
     pub header: Option<Vec<String>>,
     /// <p>The time that this classifier was last updated.</p>
     #[serde(rename = "LastUpdated")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated: Option<f64>,
+    pub last_updated: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the classifier.</p>
     #[serde(rename = "Name")]
     pub name: String,
@@ -1564,6 +2550,104 @@ pub struct CsvClassifier {
     pub version: Option<i64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CsvHeaderOption {
+    /// `UNKNOWN`
+    UnknownValue,
+    /// `PRESENT`
+    Present,
+    /// `ABSENT`
+    Absent,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CsvHeaderOption {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CsvHeaderOption::UnknownValue => "UNKNOWN",
+            CsvHeaderOption::Present => "PRESENT",
+            CsvHeaderOption::Absent => "ABSENT",
+            CsvHeaderOption::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CsvHeaderOption {
+    fn default() -> CsvHeaderOption {
+        CsvHeaderOption::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CsvHeaderOption {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CsvHeaderOption {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CsvHeaderOption {
+    fn from(value: &'a str) -> CsvHeaderOption {
+        match value {
+            "UNKNOWN" => CsvHeaderOption::UnknownValue,
+            "PRESENT" => CsvHeaderOption::Present,
+            "ABSENT" => CsvHeaderOption::Absent,
+            _ => CsvHeaderOption::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CsvHeaderOption {
+    fn from(value: String) -> CsvHeaderOption {
+        match value.as_str() {
+            "UNKNOWN" => CsvHeaderOption::UnknownValue,
+            "PRESENT" => CsvHeaderOption::Present,
+            "ABSENT" => CsvHeaderOption::Absent,
+            _ => CsvHeaderOption::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CsvHeaderOption {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CsvHeaderOption, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CsvHeaderOption {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CsvHeaderOption {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CsvHeaderOption {
+    fn deserialize<D>(deserializer: D) -> Result<CsvHeaderOption, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Contains configuration information for maintaining Data Catalog security.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DataCatalogEncryptionSettings {
@@ -1596,8 +2680,13 @@ pub struct Database {
     pub create_table_default_permissions: Option<Vec<PrincipalPermissions>>,
     /// <p>The time at which the metadata database was created in the catalog.</p>
     #[serde(rename = "CreateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub create_time: Option<f64>,
+    pub create_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A description of the database.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1640,6 +2729,104 @@ pub struct DatabaseInput {
     pub parameters: Option<::std::collections::HashMap<String, String>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DeleteBehavior {
+    /// `LOG`
+    Log,
+    /// `DELETE_FROM_DATABASE`
+    DeleteFromDatabase,
+    /// `DEPRECATE_IN_DATABASE`
+    DeprecateInDatabase,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DeleteBehavior {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeleteBehavior::Log => "LOG",
+            DeleteBehavior::DeleteFromDatabase => "DELETE_FROM_DATABASE",
+            DeleteBehavior::DeprecateInDatabase => "DEPRECATE_IN_DATABASE",
+            DeleteBehavior::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DeleteBehavior {
+    fn default() -> DeleteBehavior {
+        DeleteBehavior::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DeleteBehavior {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DeleteBehavior {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DeleteBehavior {
+    fn from(value: &'a str) -> DeleteBehavior {
+        match value {
+            "LOG" => DeleteBehavior::Log,
+            "DELETE_FROM_DATABASE" => DeleteBehavior::DeleteFromDatabase,
+            "DEPRECATE_IN_DATABASE" => DeleteBehavior::DeprecateInDatabase,
+            _ => DeleteBehavior::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeleteBehavior {
+    fn from(value: String) -> DeleteBehavior {
+        match value.as_str() {
+            "LOG" => DeleteBehavior::Log,
+            "DELETE_FROM_DATABASE" => DeleteBehavior::DeleteFromDatabase,
+            "DEPRECATE_IN_DATABASE" => DeleteBehavior::DeprecateInDatabase,
+            _ => DeleteBehavior::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DeleteBehavior {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DeleteBehavior, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DeleteBehavior {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DeleteBehavior {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeleteBehavior {
+    fn deserialize<D>(deserializer: D) -> Result<DeleteBehavior, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct DeleteClassifierRequest {
@@ -1897,8 +3084,13 @@ pub struct DevEndpoint {
     pub availability_zone: Option<String>,
     /// <p>The point in time at which this DevEndpoint was created.</p>
     #[serde(rename = "CreatedTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_timestamp: Option<f64>,
+    pub created_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the <code>DevEndpoint</code>.</p>
     #[serde(rename = "EndpointName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1921,8 +3113,13 @@ pub struct DevEndpoint {
     pub glue_version: Option<String>,
     /// <p>The point in time at which this <code>DevEndpoint</code> was last modified.</p>
     #[serde(rename = "LastModifiedTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_timestamp: Option<f64>,
+    pub last_modified_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The status of the last update.</p>
     #[serde(rename = "LastUpdateStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1978,7 +3175,7 @@ pub struct DevEndpoint {
     /// <p>The type of predefined worker that is allocated to the development endpoint. Accepts a value of Standard, G.1X, or G.2X.</p> <ul> <li> <p>For the <code>Standard</code> worker type, each worker provides 4 vCPU, 16 GB of memory and a 50GB disk, and 2 executors per worker.</p> </li> <li> <p>For the <code>G.1X</code> worker type, each worker maps to 1 DPU (4 vCPU, 16 GB of memory, 64 GB disk), and provides 1 executor per worker. We recommend this worker type for memory-intensive jobs.</p> </li> <li> <p>For the <code>G.2X</code> worker type, each worker maps to 2 DPU (8 vCPU, 32 GB of memory, 128 GB disk), and provides 1 executor per worker. We recommend this worker type for memory-intensive jobs.</p> </li> </ul> <p>Known issue: when a development endpoint is created with the <code>G.2X</code> <code>WorkerType</code> configuration, the Spark drivers for the development endpoint will run on 4 vCPU, 16 GB of memory, and a 64 GB disk. </p>
     #[serde(rename = "WorkerType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub worker_type: Option<String>,
+    pub worker_type: Option<WorkerType>,
     /// <p>The YARN endpoint address used by this <code>DevEndpoint</code>.</p>
     #[serde(rename = "YarnEndpointAddress")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2031,7 +3228,7 @@ pub struct Edge {
 pub struct EncryptionAtRest {
     /// <p>The encryption-at-rest mode for encrypting Data Catalog data.</p>
     #[serde(rename = "CatalogEncryptionMode")]
-    pub catalog_encryption_mode: String,
+    pub catalog_encryption_mode: CatalogEncryptionMode,
     /// <p>The ID of the AWS KMS key to use for encryption at rest.</p>
     #[serde(rename = "SseAwsKmsKeyId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2079,7 +3276,7 @@ pub struct EvaluationMetrics {
     pub find_matches_metrics: Option<FindMatchesMetrics>,
     /// <p>The type of machine learning transform.</p>
     #[serde(rename = "TransformType")]
-    pub transform_type: String,
+    pub transform_type: TransformType,
 }
 
 /// <p>An execution property of a job.</p>
@@ -2091,6 +3288,104 @@ pub struct ExecutionProperty {
     pub max_concurrent_runs: Option<i64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ExistCondition {
+    /// `MUST_EXIST`
+    MustExist,
+    /// `NOT_EXIST`
+    NotExist,
+    /// `NONE`
+    None,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ExistCondition {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ExistCondition::MustExist => "MUST_EXIST",
+            ExistCondition::NotExist => "NOT_EXIST",
+            ExistCondition::None => "NONE",
+            ExistCondition::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ExistCondition {
+    fn default() -> ExistCondition {
+        ExistCondition::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ExistCondition {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ExistCondition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ExistCondition {
+    fn from(value: &'a str) -> ExistCondition {
+        match value {
+            "MUST_EXIST" => ExistCondition::MustExist,
+            "NOT_EXIST" => ExistCondition::NotExist,
+            "NONE" => ExistCondition::None,
+            _ => ExistCondition::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ExistCondition {
+    fn from(value: String) -> ExistCondition {
+        match value.as_str() {
+            "MUST_EXIST" => ExistCondition::MustExist,
+            "NOT_EXIST" => ExistCondition::NotExist,
+            "NONE" => ExistCondition::None,
+            _ => ExistCondition::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ExistCondition {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ExistCondition, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ExistCondition {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ExistCondition {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ExistCondition {
+    fn deserialize<D>(deserializer: D) -> Result<ExistCondition, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Specifies configuration properties for an exporting labels task run.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -2259,7 +3554,7 @@ pub struct GetConnectionsFilter {
     /// <p>The type of connections to return. Currently, only JDBC is supported; SFTP is not supported.</p>
     #[serde(rename = "ConnectionType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub connection_type: Option<String>,
+    pub connection_type: Option<ConnectionType>,
     /// <p>A criteria string that must match the criteria recorded in the connection definition for that connection definition to be returned.</p>
     #[serde(rename = "MatchCriteria")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2643,8 +3938,13 @@ pub struct GetMLTaskRunRequest {
 pub struct GetMLTaskRunResponse {
     /// <p>The date and time when this task run was completed.</p>
     #[serde(rename = "CompletedOn")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub completed_on: Option<f64>,
+    pub completed_on: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The error strings that are associated with the task run.</p>
     #[serde(rename = "ErrorString")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2655,8 +3955,13 @@ pub struct GetMLTaskRunResponse {
     pub execution_time: Option<i64>,
     /// <p>The date and time when this task run was last modified.</p>
     #[serde(rename = "LastModifiedOn")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_on: Option<f64>,
+    pub last_modified_on: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The names of the log groups that are associated with the task run.</p>
     #[serde(rename = "LogGroupName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2667,12 +3972,17 @@ pub struct GetMLTaskRunResponse {
     pub properties: Option<TaskRunProperties>,
     /// <p>The date and time when this task run started.</p>
     #[serde(rename = "StartedOn")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub started_on: Option<f64>,
+    pub started_on: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The status for this task run.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<TaskStatusType>,
     /// <p>The unique run identifier associated with this run.</p>
     #[serde(rename = "TaskRunId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2733,8 +4043,13 @@ pub struct GetMLTransformRequest {
 pub struct GetMLTransformResponse {
     /// <p>The date and time when the transform was created.</p>
     #[serde(rename = "CreatedOn")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_on: Option<f64>,
+    pub created_on: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A description of the transform.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2757,8 +4072,13 @@ pub struct GetMLTransformResponse {
     pub label_count: Option<i64>,
     /// <p>The date and time when the transform was last modified.</p>
     #[serde(rename = "LastModifiedOn")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_on: Option<f64>,
+    pub last_modified_on: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The number of AWS Glue data processing units (DPUs) that are allocated to task runs for this transform. You can allocate from 2 to 100 DPUs; the default is 10. A DPU is a relative measure of processing power that consists of 4 vCPUs of compute capacity and 16 GB of memory. For more information, see the <a href="https://aws.amazon.com/glue/pricing/">AWS Glue pricing page</a>. </p> <p>When the <code>WorkerType</code> field is set to a value other than <code>Standard</code>, the <code>MaxCapacity</code> field is set automatically and becomes read-only.</p>
     #[serde(rename = "MaxCapacity")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2790,7 +4110,7 @@ pub struct GetMLTransformResponse {
     /// <p>The last known status of the transform (to indicate whether it can be used or not). One of "NOT_READY", "READY", or "DELETING".</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<TransformStatusType>,
     /// <p>The timeout for a task run for this transform in minutes. This is the maximum time that a task run for this transform can consume resources before it is terminated and enters <code>TIMEOUT</code> status. The default is 2,880 minutes (48 hours).</p>
     #[serde(rename = "Timeout")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2802,7 +4122,7 @@ pub struct GetMLTransformResponse {
     /// <p><p>The type of predefined worker that is allocated when this task runs. Accepts a value of Standard, G.1X, or G.2X.</p> <ul> <li> <p>For the <code>Standard</code> worker type, each worker provides 4 vCPU, 16 GB of memory and a 50GB disk, and 2 executors per worker.</p> </li> <li> <p>For the <code>G.1X</code> worker type, each worker provides 4 vCPU, 16 GB of memory and a 64GB disk, and 1 executor per worker.</p> </li> <li> <p>For the <code>G.2X</code> worker type, each worker provides 8 vCPU, 32 GB of memory and a 128GB disk, and 1 executor per worker.</p> </li> </ul></p>
     #[serde(rename = "WorkerType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub worker_type: Option<String>,
+    pub worker_type: Option<WorkerType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -2939,7 +4259,7 @@ pub struct GetPlanRequest {
     /// <p>The programming language of the code to perform the mapping.</p>
     #[serde(rename = "Language")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub language: Option<String>,
+    pub language: Option<Language>,
     /// <p>The parameters for the mapping.</p>
     #[serde(rename = "Location")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2978,8 +4298,13 @@ pub struct GetResourcePolicyRequest {}
 pub struct GetResourcePolicyResponse {
     /// <p>The date and time at which the policy was created.</p>
     #[serde(rename = "CreateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub create_time: Option<f64>,
+    pub create_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Contains the hash value associated with this policy.</p>
     #[serde(rename = "PolicyHash")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2990,8 +4315,13 @@ pub struct GetResourcePolicyResponse {
     pub policy_in_json: Option<String>,
     /// <p>The date and time at which the policy was last updated.</p>
     #[serde(rename = "UpdateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub update_time: Option<f64>,
+    pub update_time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -3412,8 +4742,13 @@ pub struct GrokClassifier {
     pub classification: String,
     /// <p>The time that this classifier was registered.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Optional custom grok patterns defined by this classifier. For more information, see custom patterns in <a href="http://docs.aws.amazon.com/glue/latest/dg/custom-classifier.html">Writing Custom Classifiers</a>.</p>
     #[serde(rename = "CustomPatterns")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3423,8 +4758,13 @@ pub struct GrokClassifier {
     pub grok_pattern: String,
     /// <p>The time that this classifier was last updated.</p>
     #[serde(rename = "LastUpdated")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated: Option<f64>,
+    pub last_updated: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the classifier.</p>
     #[serde(rename = "Name")]
     pub name: String,
@@ -3492,8 +4832,13 @@ pub struct Job {
     pub connections: Option<ConnectionsList>,
     /// <p>The time and date that this job definition was created.</p>
     #[serde(rename = "CreatedOn")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_on: Option<f64>,
+    pub created_on: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The default arguments for this job, specified as name-value pairs.</p> <p>You can specify arguments here that your own job-execution script consumes, as well as arguments that AWS Glue itself consumes.</p> <p>For information about how to specify and consume your own Job arguments, see the <a href="https://docs.aws.amazon.com/glue/latest/dg/aws-glue-programming-python-calling.html">Calling AWS Glue APIs in Python</a> topic in the developer guide.</p> <p>For information about the key-value pairs that AWS Glue consumes to set up your job, see the <a href="https://docs.aws.amazon.com/glue/latest/dg/aws-glue-programming-etl-glue-arguments.html">Special Parameters Used by AWS Glue</a> topic in the developer guide.</p>
     #[serde(rename = "DefaultArguments")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3512,8 +4857,13 @@ pub struct Job {
     pub glue_version: Option<String>,
     /// <p>The last point in time when this job definition was modified.</p>
     #[serde(rename = "LastModifiedOn")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_on: Option<f64>,
+    pub last_modified_on: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>This field is reserved for future use.</p>
     #[serde(rename = "LogUri")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3553,7 +4903,7 @@ pub struct Job {
     /// <p><p>The type of predefined worker that is allocated when a job runs. Accepts a value of Standard, G.1X, or G.2X.</p> <ul> <li> <p>For the <code>Standard</code> worker type, each worker provides 4 vCPU, 16 GB of memory and a 50GB disk, and 2 executors per worker.</p> </li> <li> <p>For the <code>G.1X</code> worker type, each worker maps to 1 DPU (4 vCPU, 16 GB of memory, 64 GB disk), and provides 1 executor per worker. We recommend this worker type for memory-intensive jobs.</p> </li> <li> <p>For the <code>G.2X</code> worker type, each worker maps to 2 DPU (8 vCPU, 32 GB of memory, 128 GB disk), and provides 1 executor per worker. We recommend this worker type for memory-intensive jobs.</p> </li> </ul></p>
     #[serde(rename = "WorkerType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub worker_type: Option<String>,
+    pub worker_type: Option<WorkerType>,
 }
 
 /// <p>Defines a point that a job can resume processing.</p>
@@ -3596,13 +4946,106 @@ pub struct JobBookmarksEncryption {
     /// <p>The encryption mode to use for job bookmarks data.</p>
     #[serde(rename = "JobBookmarksEncryptionMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_bookmarks_encryption_mode: Option<String>,
+    pub job_bookmarks_encryption_mode: Option<JobBookmarksEncryptionMode>,
     /// <p>The Amazon Resource Name (ARN) of the KMS key to be used to encrypt the data.</p>
     #[serde(rename = "KmsKeyArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub kms_key_arn: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum JobBookmarksEncryptionMode {
+    /// `DISABLED`
+    Disabled,
+    /// `CSE-KMS`
+    CseKms,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl JobBookmarksEncryptionMode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            JobBookmarksEncryptionMode::Disabled => "DISABLED",
+            JobBookmarksEncryptionMode::CseKms => "CSE-KMS",
+            JobBookmarksEncryptionMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for JobBookmarksEncryptionMode {
+    fn default() -> JobBookmarksEncryptionMode {
+        JobBookmarksEncryptionMode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for JobBookmarksEncryptionMode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for JobBookmarksEncryptionMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for JobBookmarksEncryptionMode {
+    fn from(value: &'a str) -> JobBookmarksEncryptionMode {
+        match value {
+            "DISABLED" => JobBookmarksEncryptionMode::Disabled,
+            "CSE-KMS" => JobBookmarksEncryptionMode::CseKms,
+            _ => JobBookmarksEncryptionMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for JobBookmarksEncryptionMode {
+    fn from(value: String) -> JobBookmarksEncryptionMode {
+        match value.as_str() {
+            "DISABLED" => JobBookmarksEncryptionMode::Disabled,
+            "CSE-KMS" => JobBookmarksEncryptionMode::CseKms,
+            _ => JobBookmarksEncryptionMode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for JobBookmarksEncryptionMode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<JobBookmarksEncryptionMode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for JobBookmarksEncryptionMode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for JobBookmarksEncryptionMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for JobBookmarksEncryptionMode {
+    fn deserialize<D>(deserializer: D) -> Result<JobBookmarksEncryptionMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Specifies code executed when a job is run.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct JobCommand {
@@ -3644,8 +5087,13 @@ pub struct JobRun {
     pub attempt: Option<i64>,
     /// <p>The date and time that this job run completed.</p>
     #[serde(rename = "CompletedOn")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub completed_on: Option<f64>,
+    pub completed_on: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>An error message associated with this job run.</p>
     #[serde(rename = "ErrorMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3669,11 +5117,16 @@ pub struct JobRun {
     /// <p>The current state of the job run.</p>
     #[serde(rename = "JobRunState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_run_state: Option<String>,
+    pub job_run_state: Option<JobRunState>,
     /// <p>The last time that this job run was modified.</p>
     #[serde(rename = "LastModifiedOn")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_on: Option<f64>,
+    pub last_modified_on: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the log group for secure logging that can be server-side encrypted in Amazon CloudWatch using AWS KMS. This name can be <code>/aws-glue/jobs/</code>, in which case the default encryption is <code>NONE</code>. If you add a role name and <code>SecurityConfiguration</code> name (in other words, <code>/aws-glue/jobs-yourRoleName-yourSecurityConfigurationName/</code>), then that security configuration is used to encrypt the log group.</p>
     #[serde(rename = "LogGroupName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3704,8 +5157,13 @@ pub struct JobRun {
     pub security_configuration: Option<String>,
     /// <p>The date and time at which this job run was started.</p>
     #[serde(rename = "StartedOn")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub started_on: Option<f64>,
+    pub started_on: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The <code>JobRun</code> timeout in minutes. This is the maximum time that a job run can consume resources before it is terminated and enters <code>TIMEOUT</code> status. The default is 2,880 minutes (48 hours). This overrides the timeout value set in the parent job.</p>
     #[serde(rename = "Timeout")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3717,42 +5175,170 @@ pub struct JobRun {
     /// <p><p>The type of predefined worker that is allocated when a job runs. Accepts a value of Standard, G.1X, or G.2X.</p> <ul> <li> <p>For the <code>Standard</code> worker type, each worker provides 4 vCPU, 16 GB of memory and a 50GB disk, and 2 executors per worker.</p> </li> <li> <p>For the <code>G.1X</code> worker type, each worker provides 4 vCPU, 16 GB of memory and a 64GB disk, and 1 executor per worker.</p> </li> <li> <p>For the <code>G.2X</code> worker type, each worker provides 8 vCPU, 32 GB of memory and a 128GB disk, and 1 executor per worker.</p> </li> </ul></p>
     #[serde(rename = "WorkerType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub worker_type: Option<String>,
+    pub worker_type: Option<WorkerType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum JobRunState {
+    /// `STARTING`
+    Starting,
+    /// `RUNNING`
+    Running,
+    /// `STOPPING`
+    Stopping,
+    /// `STOPPED`
+    Stopped,
+    /// `SUCCEEDED`
+    Succeeded,
+    /// `FAILED`
+    Failed,
+    /// `TIMEOUT`
+    Timeout,
+    /// `ERROR`
+    Error,
+    /// `WAITING`
+    Waiting,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl JobRunState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            JobRunState::Starting => "STARTING",
+            JobRunState::Running => "RUNNING",
+            JobRunState::Stopping => "STOPPING",
+            JobRunState::Stopped => "STOPPED",
+            JobRunState::Succeeded => "SUCCEEDED",
+            JobRunState::Failed => "FAILED",
+            JobRunState::Timeout => "TIMEOUT",
+            JobRunState::Error => "ERROR",
+            JobRunState::Waiting => "WAITING",
+            JobRunState::Unknown(ref value) => value,
+        }
+    }
 }
 
-/// <p>Specifies information used to update an existing job definition. The previous job definition is completely overwritten by this information.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct JobUpdate {
-    /// <p>The <code>JobCommand</code> that executes this job (required).</p>
-    #[serde(rename = "Command")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub command: Option<JobCommand>,
-    /// <p>The connections used for this job.</p>
-    #[serde(rename = "Connections")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub connections: Option<ConnectionsList>,
-    /// <p>The default arguments for this job.</p> <p>You can specify arguments here that your own job-execution script consumes, as well as arguments that AWS Glue itself consumes.</p> <p>For information about how to specify and consume your own Job arguments, see the <a href="https://docs.aws.amazon.com/glue/latest/dg/aws-glue-programming-python-calling.html">Calling AWS Glue APIs in Python</a> topic in the developer guide.</p> <p>For information about the key-value pairs that AWS Glue consumes to set up your job, see the <a href="https://docs.aws.amazon.com/glue/latest/dg/aws-glue-programming-etl-glue-arguments.html">Special Parameters Used by AWS Glue</a> topic in the developer guide.</p>
-    #[serde(rename = "DefaultArguments")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub default_arguments: Option<::std::collections::HashMap<String, String>>,
-    /// <p>Description of the job being defined.</p>
-    #[serde(rename = "Description")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub description: Option<String>,
-    /// <p>An <code>ExecutionProperty</code> specifying the maximum number of concurrent runs allowed for this job.</p>
-    #[serde(rename = "ExecutionProperty")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub execution_property: Option<ExecutionProperty>,
-    /// <p>Glue version determines the versions of Apache Spark and Python that AWS Glue supports. The Python version indicates the version supported for jobs of type Spark. </p> <p>For more information about the available AWS Glue versions and corresponding Spark and Python versions, see <a href="https://docs.aws.amazon.com/glue/latest/dg/add-job.html">Glue version</a> in the developer guide.</p>
-    #[serde(rename = "GlueVersion")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub glue_version: Option<String>,
-    /// <p>This field is reserved for future use.</p>
-    #[serde(rename = "LogUri")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub log_uri: Option<String>,
-    /// <p><p>The number of AWS Glue data processing units (DPUs) that can be allocated when this job runs. A DPU is a relative measure of processing power that consists of 4 vCPUs of compute capacity and 16 GB of memory. For more information, see the <a href="https://aws.amazon.com/glue/pricing/">AWS Glue pricing page</a>.</p> <p>Do not set <code>Max Capacity</code> if using <code>WorkerType</code> and <code>NumberOfWorkers</code>.</p> <p>The value that can be allocated for <code>MaxCapacity</code> depends on whether you are running a Python shell job or an Apache Spark ETL job:</p> <ul> <li> <p>When you specify a Python shell job (<code>JobCommand.Name</code>=&quot;pythonshell&quot;), you can allocate either 0.0625 or 1 DPU. The default is 0.0625 DPU.</p> </li> <li> <p>When you specify an Apache Spark ETL job (<code>JobCommand.Name</code>=&quot;glueetl&quot;), you can allocate from 2 to 100 DPUs. The default is 10 DPUs. This job type cannot have a fractional DPU allocation.</p> </li> </ul></p>
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for JobRunState {
+    fn default() -> JobRunState {
+        JobRunState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for JobRunState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for JobRunState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for JobRunState {
+    fn from(value: &'a str) -> JobRunState {
+        match value {
+            "STARTING" => JobRunState::Starting,
+            "RUNNING" => JobRunState::Running,
+            "STOPPING" => JobRunState::Stopping,
+            "STOPPED" => JobRunState::Stopped,
+            "SUCCEEDED" => JobRunState::Succeeded,
+            "FAILED" => JobRunState::Failed,
+            "TIMEOUT" => JobRunState::Timeout,
+            "ERROR" => JobRunState::Error,
+            "WAITING" => JobRunState::Waiting,
+            _ => JobRunState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for JobRunState {
+    fn from(value: String) -> JobRunState {
+        match value.as_str() {
+            "STARTING" => JobRunState::Starting,
+            "RUNNING" => JobRunState::Running,
+            "STOPPING" => JobRunState::Stopping,
+            "STOPPED" => JobRunState::Stopped,
+            "SUCCEEDED" => JobRunState::Succeeded,
+            "FAILED" => JobRunState::Failed,
+            "TIMEOUT" => JobRunState::Timeout,
+            "ERROR" => JobRunState::Error,
+            "WAITING" => JobRunState::Waiting,
+            _ => JobRunState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for JobRunState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<JobRunState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for JobRunState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for JobRunState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for JobRunState {
+    fn deserialize<D>(deserializer: D) -> Result<JobRunState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Specifies information used to update an existing job definition. The previous job definition is completely overwritten by this information.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct JobUpdate {
+    /// <p>The <code>JobCommand</code> that executes this job (required).</p>
+    #[serde(rename = "Command")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub command: Option<JobCommand>,
+    /// <p>The connections used for this job.</p>
+    #[serde(rename = "Connections")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connections: Option<ConnectionsList>,
+    /// <p>The default arguments for this job.</p> <p>You can specify arguments here that your own job-execution script consumes, as well as arguments that AWS Glue itself consumes.</p> <p>For information about how to specify and consume your own Job arguments, see the <a href="https://docs.aws.amazon.com/glue/latest/dg/aws-glue-programming-python-calling.html">Calling AWS Glue APIs in Python</a> topic in the developer guide.</p> <p>For information about the key-value pairs that AWS Glue consumes to set up your job, see the <a href="https://docs.aws.amazon.com/glue/latest/dg/aws-glue-programming-etl-glue-arguments.html">Special Parameters Used by AWS Glue</a> topic in the developer guide.</p>
+    #[serde(rename = "DefaultArguments")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_arguments: Option<::std::collections::HashMap<String, String>>,
+    /// <p>Description of the job being defined.</p>
+    #[serde(rename = "Description")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// <p>An <code>ExecutionProperty</code> specifying the maximum number of concurrent runs allowed for this job.</p>
+    #[serde(rename = "ExecutionProperty")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub execution_property: Option<ExecutionProperty>,
+    /// <p>Glue version determines the versions of Apache Spark and Python that AWS Glue supports. The Python version indicates the version supported for jobs of type Spark. </p> <p>For more information about the available AWS Glue versions and corresponding Spark and Python versions, see <a href="https://docs.aws.amazon.com/glue/latest/dg/add-job.html">Glue version</a> in the developer guide.</p>
+    #[serde(rename = "GlueVersion")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub glue_version: Option<String>,
+    /// <p>This field is reserved for future use.</p>
+    #[serde(rename = "LogUri")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub log_uri: Option<String>,
+    /// <p><p>The number of AWS Glue data processing units (DPUs) that can be allocated when this job runs. A DPU is a relative measure of processing power that consists of 4 vCPUs of compute capacity and 16 GB of memory. For more information, see the <a href="https://aws.amazon.com/glue/pricing/">AWS Glue pricing page</a>.</p> <p>Do not set <code>Max Capacity</code> if using <code>WorkerType</code> and <code>NumberOfWorkers</code>.</p> <p>The value that can be allocated for <code>MaxCapacity</code> depends on whether you are running a Python shell job or an Apache Spark ETL job:</p> <ul> <li> <p>When you specify a Python shell job (<code>JobCommand.Name</code>=&quot;pythonshell&quot;), you can allocate either 0.0625 or 1 DPU. The default is 0.0625 DPU.</p> </li> <li> <p>When you specify an Apache Spark ETL job (<code>JobCommand.Name</code>=&quot;glueetl&quot;), you can allocate from 2 to 100 DPUs. The default is 10 DPUs. This job type cannot have a fractional DPU allocation.</p> </li> </ul></p>
     #[serde(rename = "MaxCapacity")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub max_capacity: Option<f64>,
@@ -3783,7 +5369,7 @@ pub struct JobUpdate {
     /// <p><p>The type of predefined worker that is allocated when a job runs. Accepts a value of Standard, G.1X, or G.2X.</p> <ul> <li> <p>For the <code>Standard</code> worker type, each worker provides 4 vCPU, 16 GB of memory and a 50GB disk, and 2 executors per worker.</p> </li> <li> <p>For the <code>G.1X</code> worker type, each worker maps to 1 DPU (4 vCPU, 16 GB of memory, 64 GB disk), and provides 1 executor per worker. We recommend this worker type for memory-intensive jobs.</p> </li> <li> <p>For the <code>G.2X</code> worker type, each worker maps to 2 DPU (8 vCPU, 32 GB of memory, 128 GB disk), and provides 1 executor per worker. We recommend this worker type for memory-intensive jobs.</p> </li> </ul></p>
     #[serde(rename = "WorkerType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub worker_type: Option<String>,
+    pub worker_type: Option<WorkerType>,
 }
 
 /// <p>A classifier for <code>JSON</code> content.</p>
@@ -3792,15 +5378,25 @@ pub struct JobUpdate {
 pub struct JsonClassifier {
     /// <p>The time that this classifier was registered.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A <code>JsonPath</code> string defining the JSON data for the classifier to classify. AWS Glue supports a subset of <code>JsonPath</code>, as described in <a href="https://docs.aws.amazon.com/glue/latest/dg/custom-classifier.html#custom-classifier-json">Writing JsonPath Custom Classifiers</a>.</p>
     #[serde(rename = "JsonPath")]
     pub json_path: String,
     /// <p>The time that this classifier was last updated.</p>
     #[serde(rename = "LastUpdated")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated: Option<f64>,
+    pub last_updated: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the classifier.</p>
     #[serde(rename = "Name")]
     pub name: String,
@@ -3820,6 +5416,99 @@ pub struct LabelingSetGenerationTaskRunProperties {
     pub output_s3_path: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Language {
+    /// `PYTHON`
+    Python,
+    /// `SCALA`
+    Scala,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Language {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Language::Python => "PYTHON",
+            Language::Scala => "SCALA",
+            Language::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Language {
+    fn default() -> Language {
+        Language::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Language {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Language {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Language {
+    fn from(value: &'a str) -> Language {
+        match value {
+            "PYTHON" => Language::Python,
+            "SCALA" => Language::Scala,
+            _ => Language::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Language {
+    fn from(value: String) -> Language {
+        match value.as_str() {
+            "PYTHON" => Language::Python,
+            "SCALA" => Language::Scala,
+            _ => Language::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Language {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Language, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Language {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Language {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Language {
+    fn deserialize<D>(deserializer: D) -> Result<Language, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Status and error information about the most recent crawl.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -3842,14 +5531,117 @@ pub struct LastCrawlInfo {
     pub message_prefix: Option<String>,
     /// <p>The time at which the crawl started.</p>
     #[serde(rename = "StartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Status of the last crawl.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<LastCrawlStatus>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LastCrawlStatus {
+    /// `SUCCEEDED`
+    Succeeded,
+    /// `CANCELLED`
+    Cancelled,
+    /// `FAILED`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LastCrawlStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LastCrawlStatus::Succeeded => "SUCCEEDED",
+            LastCrawlStatus::Cancelled => "CANCELLED",
+            LastCrawlStatus::Failed => "FAILED",
+            LastCrawlStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LastCrawlStatus {
+    fn default() -> LastCrawlStatus {
+        LastCrawlStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LastCrawlStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LastCrawlStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LastCrawlStatus {
+    fn from(value: &'a str) -> LastCrawlStatus {
+        match value {
+            "SUCCEEDED" => LastCrawlStatus::Succeeded,
+            "CANCELLED" => LastCrawlStatus::Cancelled,
+            "FAILED" => LastCrawlStatus::Failed,
+            _ => LastCrawlStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LastCrawlStatus {
+    fn from(value: String) -> LastCrawlStatus {
+        match value.as_str() {
+            "SUCCEEDED" => LastCrawlStatus::Succeeded,
+            "CANCELLED" => LastCrawlStatus::Cancelled,
+            "FAILED" => LastCrawlStatus::Failed,
+            _ => LastCrawlStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LastCrawlStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LastCrawlStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LastCrawlStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LastCrawlStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for LastCrawlStatus {
+    fn deserialize<D>(deserializer: D) -> Result<LastCrawlStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ListCrawlersRequest {
@@ -4018,14 +5810,200 @@ pub struct Location {
     pub s3: Option<Vec<CodeGenNodeArg>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Logical {
+    /// `AND`
+    And,
+    /// `ANY`
+    Any,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Logical {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Logical::And => "AND",
+            Logical::Any => "ANY",
+            Logical::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Logical {
+    fn default() -> Logical {
+        Logical::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Logical {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Logical {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Logical {
+    fn from(value: &'a str) -> Logical {
+        match value {
+            "AND" => Logical::And,
+            "ANY" => Logical::Any,
+            _ => Logical::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Logical {
+    fn from(value: String) -> Logical {
+        match value.as_str() {
+            "AND" => Logical::And,
+            "ANY" => Logical::Any,
+            _ => Logical::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Logical {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Logical, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Logical {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Logical {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Logical {
+    fn deserialize<D>(deserializer: D) -> Result<Logical, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LogicalOperator {
+    /// `EQUALS`
+    Equals,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LogicalOperator {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LogicalOperator::Equals => "EQUALS",
+            LogicalOperator::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LogicalOperator {
+    fn default() -> LogicalOperator {
+        LogicalOperator::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LogicalOperator {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LogicalOperator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LogicalOperator {
+    fn from(value: &'a str) -> LogicalOperator {
+        match value {
+            "EQUALS" => LogicalOperator::Equals,
+            _ => LogicalOperator::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LogicalOperator {
+    fn from(value: String) -> LogicalOperator {
+        match value.as_str() {
+            "EQUALS" => LogicalOperator::Equals,
+            _ => LogicalOperator::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LogicalOperator {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LogicalOperator, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LogicalOperator {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LogicalOperator {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LogicalOperator {
+    fn deserialize<D>(deserializer: D) -> Result<LogicalOperator, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A structure for a machine learning transform.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct MLTransform {
     /// <p>A timestamp. The time and date that this machine learning transform was created.</p>
     #[serde(rename = "CreatedOn")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_on: Option<f64>,
+    pub created_on: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A user-defined, long-form description text for the machine learning transform. Descriptions are not guaranteed to be unique and can be changed at any time.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4048,8 +6026,13 @@ pub struct MLTransform {
     pub label_count: Option<i64>,
     /// <p>A timestamp. The last point in time when this machine learning transform was modified.</p>
     #[serde(rename = "LastModifiedOn")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_on: Option<f64>,
+    pub last_modified_on: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The number of AWS Glue data processing units (DPUs) that are allocated to task runs for this transform. You can allocate from 2 to 100 DPUs; the default is 10. A DPU is a relative measure of processing power that consists of 4 vCPUs of compute capacity and 16 GB of memory. For more information, see the <a href="http://aws.amazon.com/glue/pricing/">AWS Glue pricing page</a>. </p> <p> <code>MaxCapacity</code> is a mutually exclusive option with <code>NumberOfWorkers</code> and <code>WorkerType</code>.</p> <ul> <li> <p>If either <code>NumberOfWorkers</code> or <code>WorkerType</code> is set, then <code>MaxCapacity</code> cannot be set.</p> </li> <li> <p>If <code>MaxCapacity</code> is set then neither <code>NumberOfWorkers</code> or <code>WorkerType</code> can be set.</p> </li> <li> <p>If <code>WorkerType</code> is set, then <code>NumberOfWorkers</code> is required (and vice versa).</p> </li> <li> <p> <code>MaxCapacity</code> and <code>NumberOfWorkers</code> must both be at least 1.</p> </li> </ul> <p>When the <code>WorkerType</code> field is set to a value other than <code>Standard</code>, the <code>MaxCapacity</code> field is set automatically and becomes read-only.</p>
     #[serde(rename = "MaxCapacity")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4081,7 +6064,7 @@ pub struct MLTransform {
     /// <p>The current status of the machine learning transform.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<TransformStatusType>,
     /// <p>The timeout in minutes of the machine learning transform.</p>
     #[serde(rename = "Timeout")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4093,7 +6076,7 @@ pub struct MLTransform {
     /// <p><p>The type of predefined worker that is allocated when a task of this transform runs. Accepts a value of Standard, G.1X, or G.2X.</p> <ul> <li> <p>For the <code>Standard</code> worker type, each worker provides 4 vCPU, 16 GB of memory and a 50GB disk, and 2 executors per worker.</p> </li> <li> <p>For the <code>G.1X</code> worker type, each worker provides 4 vCPU, 16 GB of memory and a 64GB disk, and 1 executor per worker.</p> </li> <li> <p>For the <code>G.2X</code> worker type, each worker provides 8 vCPU, 32 GB of memory and a 128GB disk, and 1 executor per worker.</p> </li> </ul> <p> <code>MaxCapacity</code> is a mutually exclusive option with <code>NumberOfWorkers</code> and <code>WorkerType</code>.</p> <ul> <li> <p>If either <code>NumberOfWorkers</code> or <code>WorkerType</code> is set, then <code>MaxCapacity</code> cannot be set.</p> </li> <li> <p>If <code>MaxCapacity</code> is set then neither <code>NumberOfWorkers</code> or <code>WorkerType</code> can be set.</p> </li> <li> <p>If <code>WorkerType</code> is set, then <code>NumberOfWorkers</code> is required (and vice versa).</p> </li> <li> <p> <code>MaxCapacity</code> and <code>NumberOfWorkers</code> must both be at least 1.</p> </li> </ul></p>
     #[serde(rename = "WorkerType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub worker_type: Option<String>,
+    pub worker_type: Option<WorkerType>,
 }
 
 /// <p>Defines a mapping.</p>
@@ -4148,53 +6131,166 @@ pub struct Node {
     /// <p>The type of AWS Glue component represented by the node.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<NodeType>,
     /// <p>The unique Id assigned to the node within the workflow.</p>
     #[serde(rename = "UniqueId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub unique_id: Option<String>,
 }
 
-/// <p>Specifies configuration properties of a notification.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct NotificationProperty {
-    /// <p>After a job run starts, the number of minutes to wait before sending a job run delay notification.</p>
-    #[serde(rename = "NotifyDelayAfter")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub notify_delay_after: Option<i64>,
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum NodeType {
+    /// `CRAWLER`
+    Crawler,
+    /// `JOB`
+    Job,
+    /// `TRIGGER`
+    Trigger,
+    /// A value not known to this version of the crate
+    Unknown(String),
 }
 
-/// <p>Specifies the sort order of a sorted column.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct Order {
-    /// <p>The name of the column.</p>
-    #[serde(rename = "Column")]
-    pub column: String,
-    /// <p>Indicates that the column is sorted in ascending order (<code>== 1</code>), or in descending order (<code>==0</code>).</p>
-    #[serde(rename = "SortOrder")]
-    pub sort_order: i64,
+impl NodeType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            NodeType::Crawler => "CRAWLER",
+            NodeType::Job => "JOB",
+            NodeType::Trigger => "TRIGGER",
+            NodeType::Unknown(ref value) => value,
+        }
+    }
 }
 
-/// <p>Represents a slice of table data.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for NodeType {
+    fn default() -> NodeType {
+        NodeType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for NodeType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for NodeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for NodeType {
+    fn from(value: &'a str) -> NodeType {
+        match value {
+            "CRAWLER" => NodeType::Crawler,
+            "JOB" => NodeType::Job,
+            "TRIGGER" => NodeType::Trigger,
+            _ => NodeType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for NodeType {
+    fn from(value: String) -> NodeType {
+        match value.as_str() {
+            "CRAWLER" => NodeType::Crawler,
+            "JOB" => NodeType::Job,
+            "TRIGGER" => NodeType::Trigger,
+            _ => NodeType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for NodeType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<NodeType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for NodeType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for NodeType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for NodeType {
+    fn deserialize<D>(deserializer: D) -> Result<NodeType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Specifies configuration properties of a notification.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NotificationProperty {
+    /// <p>After a job run starts, the number of minutes to wait before sending a job run delay notification.</p>
+    #[serde(rename = "NotifyDelayAfter")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notify_delay_after: Option<i64>,
+}
+
+/// <p>Specifies the sort order of a sorted column.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Order {
+    /// <p>The name of the column.</p>
+    #[serde(rename = "Column")]
+    pub column: String,
+    /// <p>Indicates that the column is sorted in ascending order (<code>== 1</code>), or in descending order (<code>==0</code>).</p>
+    #[serde(rename = "SortOrder")]
+    pub sort_order: i64,
+}
+
+/// <p>Represents a slice of table data.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Partition {
     /// <p>The time at which the partition was created.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the catalog database in which to create the partition.</p>
     #[serde(rename = "DatabaseName")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub database_name: Option<String>,
     /// <p>The last time at which the partition was accessed.</p>
     #[serde(rename = "LastAccessTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_access_time: Option<f64>,
+    pub last_access_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The last time at which column statistics were computed for this partition.</p>
     #[serde(rename = "LastAnalyzedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_analyzed_time: Option<f64>,
+    pub last_analyzed_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>These key-value pairs define partition parameters.</p>
     #[serde(rename = "Parameters")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4233,12 +6329,22 @@ pub struct PartitionError {
 pub struct PartitionInput {
     /// <p>The last time at which the partition was accessed.</p>
     #[serde(rename = "LastAccessTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_access_time: Option<f64>,
+    pub last_access_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The last time at which column statistics were computed for this partition.</p>
     #[serde(rename = "LastAnalyzedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_analyzed_time: Option<f64>,
+    pub last_analyzed_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>These key-value pairs define partition parameters.</p>
     #[serde(rename = "Parameters")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4261,6 +6367,134 @@ pub struct PartitionValueList {
     pub values: Vec<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Permission {
+    /// `ALL`
+    All,
+    /// `SELECT`
+    Select,
+    /// `ALTER`
+    Alter,
+    /// `DROP`
+    Drop,
+    /// `DELETE`
+    Delete,
+    /// `INSERT`
+    Insert,
+    /// `CREATE_DATABASE`
+    CreateDatabase,
+    /// `CREATE_TABLE`
+    CreateTable,
+    /// `DATA_LOCATION_ACCESS`
+    DataLocationAccess,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Permission {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Permission::All => "ALL",
+            Permission::Select => "SELECT",
+            Permission::Alter => "ALTER",
+            Permission::Drop => "DROP",
+            Permission::Delete => "DELETE",
+            Permission::Insert => "INSERT",
+            Permission::CreateDatabase => "CREATE_DATABASE",
+            Permission::CreateTable => "CREATE_TABLE",
+            Permission::DataLocationAccess => "DATA_LOCATION_ACCESS",
+            Permission::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Permission {
+    fn default() -> Permission {
+        Permission::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Permission {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Permission {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Permission {
+    fn from(value: &'a str) -> Permission {
+        match value {
+            "ALL" => Permission::All,
+            "SELECT" => Permission::Select,
+            "ALTER" => Permission::Alter,
+            "DROP" => Permission::Drop,
+            "DELETE" => Permission::Delete,
+            "INSERT" => Permission::Insert,
+            "CREATE_DATABASE" => Permission::CreateDatabase,
+            "CREATE_TABLE" => Permission::CreateTable,
+            "DATA_LOCATION_ACCESS" => Permission::DataLocationAccess,
+            _ => Permission::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Permission {
+    fn from(value: String) -> Permission {
+        match value.as_str() {
+            "ALL" => Permission::All,
+            "SELECT" => Permission::Select,
+            "ALTER" => Permission::Alter,
+            "DROP" => Permission::Drop,
+            "DELETE" => Permission::Delete,
+            "INSERT" => Permission::Insert,
+            "CREATE_DATABASE" => Permission::CreateDatabase,
+            "CREATE_TABLE" => Permission::CreateTable,
+            "DATA_LOCATION_ACCESS" => Permission::DataLocationAccess,
+            _ => Permission::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Permission {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Permission, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Permission {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Permission {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Permission {
+    fn deserialize<D>(deserializer: D) -> Result<Permission, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Specifies the physical requirements for a connection.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PhysicalConnectionRequirements {
@@ -4302,7 +6536,7 @@ pub struct Predicate {
     /// <p>An optional field if only one condition is listed. If multiple conditions are listed, then this field is required.</p>
     #[serde(rename = "Logical")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub logical: Option<String>,
+    pub logical: Option<Logical>,
 }
 
 /// <p>Permissions granted to a principal.</p>
@@ -4311,13 +6545,111 @@ pub struct PrincipalPermissions {
     /// <p>The permissions that are granted to the principal.</p>
     #[serde(rename = "Permissions")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub permissions: Option<Vec<String>>,
+    pub permissions: Option<Vec<Permission>>,
     /// <p>The principal who is granted permissions.</p>
     #[serde(rename = "Principal")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub principal: Option<DataLakePrincipal>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PrincipalType {
+    /// `USER`
+    User,
+    /// `ROLE`
+    Role,
+    /// `GROUP`
+    Group,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PrincipalType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PrincipalType::User => "USER",
+            PrincipalType::Role => "ROLE",
+            PrincipalType::Group => "GROUP",
+            PrincipalType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PrincipalType {
+    fn default() -> PrincipalType {
+        PrincipalType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PrincipalType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PrincipalType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PrincipalType {
+    fn from(value: &'a str) -> PrincipalType {
+        match value {
+            "USER" => PrincipalType::User,
+            "ROLE" => PrincipalType::Role,
+            "GROUP" => PrincipalType::Group,
+            _ => PrincipalType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PrincipalType {
+    fn from(value: String) -> PrincipalType {
+        match value.as_str() {
+            "USER" => PrincipalType::User,
+            "ROLE" => PrincipalType::Role,
+            "GROUP" => PrincipalType::Group,
+            _ => PrincipalType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PrincipalType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PrincipalType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PrincipalType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PrincipalType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PrincipalType {
+    fn deserialize<D>(deserializer: D) -> Result<PrincipalType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Defines a property predicate.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -4325,7 +6657,7 @@ pub struct PropertyPredicate {
     /// <p>The comparator used to compare this property to others.</p>
     #[serde(rename = "Comparator")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub comparator: Option<String>,
+    pub comparator: Option<Comparator>,
     /// <p>The key of the property.</p>
     #[serde(rename = "Key")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4358,7 +6690,7 @@ pub struct PutResourcePolicyRequest {
     /// <p>A value of <code>MUST_EXIST</code> is used to update a policy. A value of <code>NOT_EXIST</code> is used to create a new policy. If a value of <code>NONE</code> or a null value is used, the call will not depend on the existence of a policy.</p>
     #[serde(rename = "PolicyExistsCondition")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub policy_exists_condition: Option<String>,
+    pub policy_exists_condition: Option<ExistCondition>,
     /// <p>The hash value returned when the previous policy was set using <code>PutResourcePolicy</code>. Its purpose is to prevent concurrent modifications of a policy. Do not use this parameter if no previous policy has been set.</p>
     #[serde(rename = "PolicyHashCondition")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4416,13 +6748,111 @@ pub struct ResetJobBookmarkResponse {
     pub job_bookmark_entry: Option<JobBookmarkEntry>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ResourceType {
+    /// `JAR`
+    Jar,
+    /// `FILE`
+    File,
+    /// `ARCHIVE`
+    Archive,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ResourceType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceType::Jar => "JAR",
+            ResourceType::File => "FILE",
+            ResourceType::Archive => "ARCHIVE",
+            ResourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ResourceType {
+    fn default() -> ResourceType {
+        ResourceType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ResourceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ResourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ResourceType {
+    fn from(value: &'a str) -> ResourceType {
+        match value {
+            "JAR" => ResourceType::Jar,
+            "FILE" => ResourceType::File,
+            "ARCHIVE" => ResourceType::Archive,
+            _ => ResourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceType {
+    fn from(value: String) -> ResourceType {
+        match value.as_str() {
+            "JAR" => ResourceType::Jar,
+            "FILE" => ResourceType::File,
+            "ARCHIVE" => ResourceType::Archive,
+            _ => ResourceType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ResourceType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ResourceType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ResourceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ResourceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ResourceType {
+    fn deserialize<D>(deserializer: D) -> Result<ResourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The URIs for function resources.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ResourceUri {
     /// <p>The type of the resource.</p>
     #[serde(rename = "ResourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ResourceType>,
     /// <p>The URI for accessing the resource.</p>
     #[serde(rename = "Uri")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4439,9 +6869,107 @@ pub struct S3Encryption {
     /// <p>The encryption mode to use for Amazon S3 data.</p>
     #[serde(rename = "S3EncryptionMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub s3_encryption_mode: Option<String>,
+    pub s3_encryption_mode: Option<S3EncryptionMode>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum S3EncryptionMode {
+    /// `DISABLED`
+    Disabled,
+    /// `SSE-KMS`
+    SseKms,
+    /// `SSE-S3`
+    SseS3,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl S3EncryptionMode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            S3EncryptionMode::Disabled => "DISABLED",
+            S3EncryptionMode::SseKms => "SSE-KMS",
+            S3EncryptionMode::SseS3 => "SSE-S3",
+            S3EncryptionMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for S3EncryptionMode {
+    fn default() -> S3EncryptionMode {
+        S3EncryptionMode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for S3EncryptionMode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for S3EncryptionMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for S3EncryptionMode {
+    fn from(value: &'a str) -> S3EncryptionMode {
+        match value {
+            "DISABLED" => S3EncryptionMode::Disabled,
+            "SSE-KMS" => S3EncryptionMode::SseKms,
+            "SSE-S3" => S3EncryptionMode::SseS3,
+            _ => S3EncryptionMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for S3EncryptionMode {
+    fn from(value: String) -> S3EncryptionMode {
+        match value.as_str() {
+            "DISABLED" => S3EncryptionMode::Disabled,
+            "SSE-KMS" => S3EncryptionMode::SseKms,
+            "SSE-S3" => S3EncryptionMode::SseS3,
+            _ => S3EncryptionMode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for S3EncryptionMode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<S3EncryptionMode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for S3EncryptionMode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for S3EncryptionMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for S3EncryptionMode {
+    fn deserialize<D>(deserializer: D) -> Result<S3EncryptionMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Specifies a data store in Amazon Simple Storage Service (Amazon S3).</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct S3Target {
@@ -4466,49 +6994,147 @@ pub struct Schedule {
     /// <p>The state of the schedule.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<ScheduleState>,
 }
 
-/// <p>A policy that specifies update and deletion behaviors for the crawler.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct SchemaChangePolicy {
-    /// <p>The deletion behavior when the crawler finds a deleted object.</p>
-    #[serde(rename = "DeleteBehavior")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub delete_behavior: Option<String>,
-    /// <p>The update behavior when the crawler finds a changed schema.</p>
-    #[serde(rename = "UpdateBehavior")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub update_behavior: Option<String>,
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ScheduleState {
+    /// `SCHEDULED`
+    Scheduled,
+    /// `NOT_SCHEDULED`
+    NotScheduled,
+    /// `TRANSITIONING`
+    Transitioning,
+    /// A value not known to this version of the crate
+    Unknown(String),
 }
 
-/// <p>A key-value pair representing a column and data type that this transform can run against. The <code>Schema</code> parameter of the <code>MLTransform</code> may contain up to 100 of these structures.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct SchemaColumn {
-    /// <p>The type of data in the column.</p>
-    #[serde(rename = "DataType")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub data_type: Option<String>,
-    /// <p>The name of the column.</p>
-    #[serde(rename = "Name")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
+impl ScheduleState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ScheduleState::Scheduled => "SCHEDULED",
+            ScheduleState::NotScheduled => "NOT_SCHEDULED",
+            ScheduleState::Transitioning => "TRANSITIONING",
+            ScheduleState::Unknown(ref value) => value,
+        }
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct SearchTablesRequest {
-    /// <p>A unique identifier, consisting of <code> <i>account_id</i>/datalake</code>.</p>
-    #[serde(rename = "CatalogId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub catalog_id: Option<String>,
-    /// <p>A list of key-value pairs, and a comparator used to filter the search results. Returns all entities matching the predicate.</p>
-    #[serde(rename = "Filters")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub filters: Option<Vec<PropertyPredicate>>,
-    /// <p>The maximum number of tables to return in a single response.</p>
-    #[serde(rename = "MaxResults")]
-    #[serde(skip_serializing_if = "Option::is_none")]
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ScheduleState {
+    fn default() -> ScheduleState {
+        ScheduleState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ScheduleState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ScheduleState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ScheduleState {
+    fn from(value: &'a str) -> ScheduleState {
+        match value {
+            "SCHEDULED" => ScheduleState::Scheduled,
+            "NOT_SCHEDULED" => ScheduleState::NotScheduled,
+            "TRANSITIONING" => ScheduleState::Transitioning,
+            _ => ScheduleState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ScheduleState {
+    fn from(value: String) -> ScheduleState {
+        match value.as_str() {
+            "SCHEDULED" => ScheduleState::Scheduled,
+            "NOT_SCHEDULED" => ScheduleState::NotScheduled,
+            "TRANSITIONING" => ScheduleState::Transitioning,
+            _ => ScheduleState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ScheduleState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ScheduleState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ScheduleState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ScheduleState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ScheduleState {
+    fn deserialize<D>(deserializer: D) -> Result<ScheduleState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>A policy that specifies update and deletion behaviors for the crawler.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SchemaChangePolicy {
+    /// <p>The deletion behavior when the crawler finds a deleted object.</p>
+    #[serde(rename = "DeleteBehavior")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delete_behavior: Option<DeleteBehavior>,
+    /// <p>The update behavior when the crawler finds a changed schema.</p>
+    #[serde(rename = "UpdateBehavior")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub update_behavior: Option<UpdateBehavior>,
+}
+
+/// <p>A key-value pair representing a column and data type that this transform can run against. The <code>Schema</code> parameter of the <code>MLTransform</code> may contain up to 100 of these structures.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SchemaColumn {
+    /// <p>The type of data in the column.</p>
+    #[serde(rename = "DataType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data_type: Option<String>,
+    /// <p>The name of the column.</p>
+    #[serde(rename = "Name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct SearchTablesRequest {
+    /// <p>A unique identifier, consisting of <code> <i>account_id</i>/datalake</code>.</p>
+    #[serde(rename = "CatalogId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub catalog_id: Option<String>,
+    /// <p>A list of key-value pairs, and a comparator used to filter the search results. Returns all entities matching the predicate.</p>
+    #[serde(rename = "Filters")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filters: Option<Vec<PropertyPredicate>>,
+    /// <p>The maximum number of tables to return in a single response.</p>
+    #[serde(rename = "MaxResults")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub max_results: Option<i64>,
     /// <p>A continuation token, included if this is a continuation call.</p>
     #[serde(rename = "NextToken")]
@@ -4543,8 +7169,13 @@ pub struct SearchTablesResponse {
 pub struct SecurityConfiguration {
     /// <p>The time at which this security configuration was created.</p>
     #[serde(rename = "CreatedTimeStamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time_stamp: Option<f64>,
+    pub created_time_stamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The encryption configuration associated with this security configuration.</p>
     #[serde(rename = "EncryptionConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4601,6 +7232,99 @@ pub struct SkewedInfo {
     pub skewed_column_values: Option<Vec<String>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Sort {
+    /// `ASC`
+    Asc,
+    /// `DESC`
+    Desc,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Sort {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Sort::Asc => "ASC",
+            Sort::Desc => "DESC",
+            Sort::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Sort {
+    fn default() -> Sort {
+        Sort::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Sort {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Sort {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Sort {
+    fn from(value: &'a str) -> Sort {
+        match value {
+            "ASC" => Sort::Asc,
+            "DESC" => Sort::Desc,
+            _ => Sort::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Sort {
+    fn from(value: String) -> Sort {
+        match value.as_str() {
+            "ASC" => Sort::Asc,
+            "DESC" => Sort::Desc,
+            _ => Sort::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Sort {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Sort, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Sort {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Sort {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Sort {
+    fn deserialize<D>(deserializer: D) -> Result<Sort, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Specifies a field to sort by and a sort order.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -4612,9 +7336,102 @@ pub struct SortCriterion {
     /// <p>An ascending or descending sort.</p>
     #[serde(rename = "Sort")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort: Option<String>,
+    pub sort: Option<Sort>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SortDirectionType {
+    /// `DESCENDING`
+    Descending,
+    /// `ASCENDING`
+    Ascending,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SortDirectionType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SortDirectionType::Descending => "DESCENDING",
+            SortDirectionType::Ascending => "ASCENDING",
+            SortDirectionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SortDirectionType {
+    fn default() -> SortDirectionType {
+        SortDirectionType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SortDirectionType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SortDirectionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SortDirectionType {
+    fn from(value: &'a str) -> SortDirectionType {
+        match value {
+            "DESCENDING" => SortDirectionType::Descending,
+            "ASCENDING" => SortDirectionType::Ascending,
+            _ => SortDirectionType::Unknown(value.to_owned()),
+        }
+    }
 }
 
+impl From<String> for SortDirectionType {
+    fn from(value: String) -> SortDirectionType {
+        match value.as_str() {
+            "DESCENDING" => SortDirectionType::Descending,
+            "ASCENDING" => SortDirectionType::Ascending,
+            _ => SortDirectionType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SortDirectionType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SortDirectionType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SortDirectionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SortDirectionType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SortDirectionType {
+    fn deserialize<D>(deserializer: D) -> Result<SortDirectionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct StartCrawlerRequest {
@@ -4720,7 +7537,7 @@ pub struct StartJobRunRequest {
     /// <p><p>The type of predefined worker that is allocated when a job runs. Accepts a value of Standard, G.1X, or G.2X.</p> <ul> <li> <p>For the <code>Standard</code> worker type, each worker provides 4 vCPU, 16 GB of memory and a 50GB disk, and 2 executors per worker.</p> </li> <li> <p>For the <code>G.1X</code> worker type, each worker provides 4 vCPU, 16 GB of memory and a 64GB disk, and 1 executor per worker.</p> </li> <li> <p>For the <code>G.2X</code> worker type, each worker provides 8 vCPU, 32 GB of memory and a 128GB disk, and 1 executor per worker.</p> </li> </ul></p>
     #[serde(rename = "WorkerType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub worker_type: Option<String>,
+    pub worker_type: Option<WorkerType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -4903,8 +7720,13 @@ pub struct StorageDescriptor {
 pub struct Table {
     /// <p>The time when the table definition was created in the Data Catalog.</p>
     #[serde(rename = "CreateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub create_time: Option<f64>,
+    pub create_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The person or entity who created the table.</p>
     #[serde(rename = "CreatedBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4923,12 +7745,22 @@ pub struct Table {
     pub is_registered_with_lake_formation: Option<bool>,
     /// <p>The last time that the table was accessed. This is usually taken from HDFS, and might not be reliable.</p>
     #[serde(rename = "LastAccessTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_access_time: Option<f64>,
+    pub last_access_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The last time that column statistics were computed for this table.</p>
     #[serde(rename = "LastAnalyzedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_analyzed_time: Option<f64>,
+    pub last_analyzed_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The table name. For Hive compatibility, this must be entirely lowercase.</p>
     #[serde(rename = "Name")]
     pub name: String,
@@ -4958,8 +7790,13 @@ pub struct Table {
     pub table_type: Option<String>,
     /// <p>The last time that the table was updated.</p>
     #[serde(rename = "UpdateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub update_time: Option<f64>,
+    pub update_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>If the table is a view, the expanded text of the view; otherwise <code>null</code>.</p>
     #[serde(rename = "ViewExpandedText")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4994,12 +7831,22 @@ pub struct TableInput {
     pub description: Option<String>,
     /// <p>The last time that the table was accessed.</p>
     #[serde(rename = "LastAccessTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_access_time: Option<f64>,
+    pub last_access_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The last time that column statistics were computed for this table.</p>
     #[serde(rename = "LastAnalyzedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_analyzed_time: Option<f64>,
+    pub last_analyzed_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The table name. For Hive compatibility, this is folded to lowercase when it is stored.</p>
     #[serde(rename = "Name")]
     pub name: String,
@@ -5090,8 +7937,13 @@ pub struct TagResourceResponse {}
 pub struct TaskRun {
     /// <p>The last point in time that the requested task run was completed.</p>
     #[serde(rename = "CompletedOn")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub completed_on: Option<f64>,
+    pub completed_on: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The list of error strings associated with this task run.</p>
     #[serde(rename = "ErrorString")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -5102,8 +7954,13 @@ pub struct TaskRun {
     pub execution_time: Option<i64>,
     /// <p>The last point in time that the requested task run was updated.</p>
     #[serde(rename = "LastModifiedOn")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_on: Option<f64>,
+    pub last_modified_on: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The names of the log group for secure logging, associated with this task run.</p>
     #[serde(rename = "LogGroupName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -5114,12 +7971,17 @@ pub struct TaskRun {
     pub properties: Option<TaskRunProperties>,
     /// <p>The date and time that this task run started.</p>
     #[serde(rename = "StartedOn")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub started_on: Option<f64>,
+    pub started_on: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The current status of the requested task run.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<TaskStatusType>,
     /// <p>The unique identifier for this task run.</p>
     #[serde(rename = "TaskRunId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -5136,20 +7998,30 @@ pub struct TaskRun {
 pub struct TaskRunFilterCriteria {
     /// <p>Filter on task runs started after this date.</p>
     #[serde(rename = "StartedAfter")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub started_after: Option<f64>,
+    pub started_after: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Filter on task runs started before this date.</p>
     #[serde(rename = "StartedBefore")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub started_before: Option<f64>,
+    pub started_before: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The current status of the task run.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<TaskStatusType>,
     /// <p>The type of task run.</p>
     #[serde(rename = "TaskRunType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub task_run_type: Option<String>,
+    pub task_run_type: Option<TaskType>,
 }
 
 /// <p>The configuration properties for the task run.</p>
@@ -5175,45 +8047,389 @@ pub struct TaskRunProperties {
     /// <p>The type of task run.</p>
     #[serde(rename = "TaskType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub task_type: Option<String>,
+    pub task_type: Option<TaskType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TaskRunSortColumnType {
+    /// `TASK_RUN_TYPE`
+    TaskRunType,
+    /// `STATUS`
+    Status,
+    /// `STARTED`
+    Started,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TaskRunSortColumnType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TaskRunSortColumnType::TaskRunType => "TASK_RUN_TYPE",
+            TaskRunSortColumnType::Status => "STATUS",
+            TaskRunSortColumnType::Started => "STARTED",
+            TaskRunSortColumnType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TaskRunSortColumnType {
+    fn default() -> TaskRunSortColumnType {
+        TaskRunSortColumnType::Unknown(String::new())
+    }
 }
 
+impl AsRef<str> for TaskRunSortColumnType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TaskRunSortColumnType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TaskRunSortColumnType {
+    fn from(value: &'a str) -> TaskRunSortColumnType {
+        match value {
+            "TASK_RUN_TYPE" => TaskRunSortColumnType::TaskRunType,
+            "STATUS" => TaskRunSortColumnType::Status,
+            "STARTED" => TaskRunSortColumnType::Started,
+            _ => TaskRunSortColumnType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TaskRunSortColumnType {
+    fn from(value: String) -> TaskRunSortColumnType {
+        match value.as_str() {
+            "TASK_RUN_TYPE" => TaskRunSortColumnType::TaskRunType,
+            "STATUS" => TaskRunSortColumnType::Status,
+            "STARTED" => TaskRunSortColumnType::Started,
+            _ => TaskRunSortColumnType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TaskRunSortColumnType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TaskRunSortColumnType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TaskRunSortColumnType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TaskRunSortColumnType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TaskRunSortColumnType {
+    fn deserialize<D>(deserializer: D) -> Result<TaskRunSortColumnType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The sorting criteria that are used to sort the list of task runs for the machine learning transform.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct TaskRunSortCriteria {
     /// <p>The column to be used to sort the list of task runs for the machine learning transform.</p>
     #[serde(rename = "Column")]
-    pub column: String,
+    pub column: TaskRunSortColumnType,
     /// <p>The sort direction to be used to sort the list of task runs for the machine learning transform.</p>
     #[serde(rename = "SortDirection")]
-    pub sort_direction: String,
+    pub sort_direction: SortDirectionType,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TaskStatusType {
+    /// `STARTING`
+    Starting,
+    /// `RUNNING`
+    Running,
+    /// `STOPPING`
+    Stopping,
+    /// `STOPPED`
+    Stopped,
+    /// `SUCCEEDED`
+    Succeeded,
+    /// `FAILED`
+    Failed,
+    /// `TIMEOUT`
+    Timeout,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TaskStatusType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TaskStatusType::Starting => "STARTING",
+            TaskStatusType::Running => "RUNNING",
+            TaskStatusType::Stopping => "STOPPING",
+            TaskStatusType::Stopped => "STOPPED",
+            TaskStatusType::Succeeded => "SUCCEEDED",
+            TaskStatusType::Failed => "FAILED",
+            TaskStatusType::Timeout => "TIMEOUT",
+            TaskStatusType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TaskStatusType {
+    fn default() -> TaskStatusType {
+        TaskStatusType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TaskStatusType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TaskStatusType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TaskStatusType {
+    fn from(value: &'a str) -> TaskStatusType {
+        match value {
+            "STARTING" => TaskStatusType::Starting,
+            "RUNNING" => TaskStatusType::Running,
+            "STOPPING" => TaskStatusType::Stopping,
+            "STOPPED" => TaskStatusType::Stopped,
+            "SUCCEEDED" => TaskStatusType::Succeeded,
+            "FAILED" => TaskStatusType::Failed,
+            "TIMEOUT" => TaskStatusType::Timeout,
+            _ => TaskStatusType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TaskStatusType {
+    fn from(value: String) -> TaskStatusType {
+        match value.as_str() {
+            "STARTING" => TaskStatusType::Starting,
+            "RUNNING" => TaskStatusType::Running,
+            "STOPPING" => TaskStatusType::Stopping,
+            "STOPPED" => TaskStatusType::Stopped,
+            "SUCCEEDED" => TaskStatusType::Succeeded,
+            "FAILED" => TaskStatusType::Failed,
+            "TIMEOUT" => TaskStatusType::Timeout,
+            _ => TaskStatusType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TaskStatusType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TaskStatusType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TaskStatusType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TaskStatusType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TaskStatusType {
+    fn deserialize<D>(deserializer: D) -> Result<TaskStatusType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TaskType {
+    /// `EVALUATION`
+    Evaluation,
+    /// `LABELING_SET_GENERATION`
+    LabelingSetGeneration,
+    /// `IMPORT_LABELS`
+    ImportLabels,
+    /// `EXPORT_LABELS`
+    ExportLabels,
+    /// `FIND_MATCHES`
+    FindMatches,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TaskType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TaskType::Evaluation => "EVALUATION",
+            TaskType::LabelingSetGeneration => "LABELING_SET_GENERATION",
+            TaskType::ImportLabels => "IMPORT_LABELS",
+            TaskType::ExportLabels => "EXPORT_LABELS",
+            TaskType::FindMatches => "FIND_MATCHES",
+            TaskType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TaskType {
+    fn default() -> TaskType {
+        TaskType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TaskType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TaskType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TaskType {
+    fn from(value: &'a str) -> TaskType {
+        match value {
+            "EVALUATION" => TaskType::Evaluation,
+            "LABELING_SET_GENERATION" => TaskType::LabelingSetGeneration,
+            "IMPORT_LABELS" => TaskType::ImportLabels,
+            "EXPORT_LABELS" => TaskType::ExportLabels,
+            "FIND_MATCHES" => TaskType::FindMatches,
+            _ => TaskType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TaskType {
+    fn from(value: String) -> TaskType {
+        match value.as_str() {
+            "EVALUATION" => TaskType::Evaluation,
+            "LABELING_SET_GENERATION" => TaskType::LabelingSetGeneration,
+            "IMPORT_LABELS" => TaskType::ImportLabels,
+            "EXPORT_LABELS" => TaskType::ExportLabels,
+            "FIND_MATCHES" => TaskType::FindMatches,
+            _ => TaskType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TaskType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TaskType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TaskType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
 }
 
+impl ::serde::Serialize for TaskType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TaskType {
+    fn deserialize<D>(deserializer: D) -> Result<TaskType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The criteria used to filter the machine learning transforms.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct TransformFilterCriteria {
     /// <p>The time and date after which the transforms were created.</p>
     #[serde(rename = "CreatedAfter")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_after: Option<f64>,
+    pub created_after: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The time and date before which the transforms were created.</p>
     #[serde(rename = "CreatedBefore")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_before: Option<f64>,
+    pub created_before: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>This value determines which version of AWS Glue this machine learning transform is compatible with. Glue 1.0 is recommended for most customers. If the value is not set, the Glue compatibility defaults to Glue 0.9. For more information, see <a href="https://docs.aws.amazon.com/glue/latest/dg/release-notes.html#release-notes-versions">AWS Glue Versions</a> in the developer guide.</p>
     #[serde(rename = "GlueVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub glue_version: Option<String>,
     /// <p>Filter on transforms last modified after this date.</p>
     #[serde(rename = "LastModifiedAfter")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_after: Option<f64>,
+    pub last_modified_after: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Filter on transforms last modified before this date.</p>
     #[serde(rename = "LastModifiedBefore")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_before: Option<f64>,
+    pub last_modified_before: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A unique transform name that is used to filter the machine learning transforms.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -5225,11 +8441,11 @@ pub struct TransformFilterCriteria {
     /// <p>Filters the list of machine learning transforms by the last known status of the transforms (to indicate whether a transform can be used or not). One of "NOT_READY", "READY", or "DELETING".</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<TransformStatusType>,
     /// <p>The type of machine learning transform that is used to filter the machine learning transforms.</p>
     #[serde(rename = "TransformType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub transform_type: Option<String>,
+    pub transform_type: Option<TransformType>,
 }
 
 /// <p>The algorithm-specific parameters that are associated with the machine learning transform.</p>
@@ -5241,21 +8457,315 @@ pub struct TransformParameters {
     pub find_matches_parameters: Option<FindMatchesParameters>,
     /// <p>The type of machine learning transform.</p> <p>For information about the types of machine learning transforms, see <a href="http://docs.aws.amazon.com/glue/latest/dg/add-job-machine-learning-transform.html">Creating Machine Learning Transforms</a>.</p>
     #[serde(rename = "TransformType")]
-    pub transform_type: String,
+    pub transform_type: TransformType,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TransformSortColumnType {
+    /// `NAME`
+    Name,
+    /// `TRANSFORM_TYPE`
+    TransformType,
+    /// `STATUS`
+    Status,
+    /// `CREATED`
+    Created,
+    /// `LAST_MODIFIED`
+    LastModified,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TransformSortColumnType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TransformSortColumnType::Name => "NAME",
+            TransformSortColumnType::TransformType => "TRANSFORM_TYPE",
+            TransformSortColumnType::Status => "STATUS",
+            TransformSortColumnType::Created => "CREATED",
+            TransformSortColumnType::LastModified => "LAST_MODIFIED",
+            TransformSortColumnType::Unknown(ref value) => value,
+        }
+    }
 }
 
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TransformSortColumnType {
+    fn default() -> TransformSortColumnType {
+        TransformSortColumnType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TransformSortColumnType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TransformSortColumnType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TransformSortColumnType {
+    fn from(value: &'a str) -> TransformSortColumnType {
+        match value {
+            "NAME" => TransformSortColumnType::Name,
+            "TRANSFORM_TYPE" => TransformSortColumnType::TransformType,
+            "STATUS" => TransformSortColumnType::Status,
+            "CREATED" => TransformSortColumnType::Created,
+            "LAST_MODIFIED" => TransformSortColumnType::LastModified,
+            _ => TransformSortColumnType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TransformSortColumnType {
+    fn from(value: String) -> TransformSortColumnType {
+        match value.as_str() {
+            "NAME" => TransformSortColumnType::Name,
+            "TRANSFORM_TYPE" => TransformSortColumnType::TransformType,
+            "STATUS" => TransformSortColumnType::Status,
+            "CREATED" => TransformSortColumnType::Created,
+            "LAST_MODIFIED" => TransformSortColumnType::LastModified,
+            _ => TransformSortColumnType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TransformSortColumnType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TransformSortColumnType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TransformSortColumnType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TransformSortColumnType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TransformSortColumnType {
+    fn deserialize<D>(deserializer: D) -> Result<TransformSortColumnType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The sorting criteria that are associated with the machine learning transform.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct TransformSortCriteria {
     /// <p>The column to be used in the sorting criteria that are associated with the machine learning transform.</p>
     #[serde(rename = "Column")]
-    pub column: String,
+    pub column: TransformSortColumnType,
     /// <p>The sort direction to be used in the sorting criteria that are associated with the machine learning transform.</p>
     #[serde(rename = "SortDirection")]
-    pub sort_direction: String,
+    pub sort_direction: SortDirectionType,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TransformStatusType {
+    /// `NOT_READY`
+    NotReady,
+    /// `READY`
+    Ready,
+    /// `DELETING`
+    Deleting,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TransformStatusType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TransformStatusType::NotReady => "NOT_READY",
+            TransformStatusType::Ready => "READY",
+            TransformStatusType::Deleting => "DELETING",
+            TransformStatusType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TransformStatusType {
+    fn default() -> TransformStatusType {
+        TransformStatusType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TransformStatusType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TransformStatusType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TransformStatusType {
+    fn from(value: &'a str) -> TransformStatusType {
+        match value {
+            "NOT_READY" => TransformStatusType::NotReady,
+            "READY" => TransformStatusType::Ready,
+            "DELETING" => TransformStatusType::Deleting,
+            _ => TransformStatusType::Unknown(value.to_owned()),
+        }
+    }
 }
 
+impl From<String> for TransformStatusType {
+    fn from(value: String) -> TransformStatusType {
+        match value.as_str() {
+            "NOT_READY" => TransformStatusType::NotReady,
+            "READY" => TransformStatusType::Ready,
+            "DELETING" => TransformStatusType::Deleting,
+            _ => TransformStatusType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TransformStatusType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TransformStatusType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TransformStatusType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TransformStatusType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TransformStatusType {
+    fn deserialize<D>(deserializer: D) -> Result<TransformStatusType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TransformType {
+    /// `FIND_MATCHES`
+    FindMatches,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TransformType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TransformType::FindMatches => "FIND_MATCHES",
+            TransformType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TransformType {
+    fn default() -> TransformType {
+        TransformType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TransformType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TransformType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TransformType {
+    fn from(value: &'a str) -> TransformType {
+        match value {
+            "FIND_MATCHES" => TransformType::FindMatches,
+            _ => TransformType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TransformType {
+    fn from(value: String) -> TransformType {
+        match value.as_str() {
+            "FIND_MATCHES" => TransformType::FindMatches,
+            _ => TransformType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TransformType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TransformType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TransformType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TransformType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TransformType {
+    fn deserialize<D>(deserializer: D) -> Result<TransformType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about a specific trigger.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -5287,27 +8797,253 @@ pub struct Trigger {
     /// <p>The current state of the trigger.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<TriggerState>,
     /// <p>The type of trigger that this is.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<TriggerType>,
     /// <p>The name of the workflow associated with the trigger.</p>
     #[serde(rename = "WorkflowName")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub workflow_name: Option<String>,
 }
 
-/// <p>The details of a Trigger node present in the workflow.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct TriggerNodeDetails {
-    /// <p>The information of the trigger represented by the trigger node.</p>
-    #[serde(rename = "Trigger")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub trigger: Option<Trigger>,
+/// <p>The details of a Trigger node present in the workflow.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct TriggerNodeDetails {
+    /// <p>The information of the trigger represented by the trigger node.</p>
+    #[serde(rename = "Trigger")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trigger: Option<Trigger>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TriggerState {
+    /// `CREATING`
+    Creating,
+    /// `CREATED`
+    Created,
+    /// `ACTIVATING`
+    Activating,
+    /// `ACTIVATED`
+    Activated,
+    /// `DEACTIVATING`
+    Deactivating,
+    /// `DEACTIVATED`
+    Deactivated,
+    /// `DELETING`
+    Deleting,
+    /// `UPDATING`
+    Updating,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TriggerState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TriggerState::Creating => "CREATING",
+            TriggerState::Created => "CREATED",
+            TriggerState::Activating => "ACTIVATING",
+            TriggerState::Activated => "ACTIVATED",
+            TriggerState::Deactivating => "DEACTIVATING",
+            TriggerState::Deactivated => "DEACTIVATED",
+            TriggerState::Deleting => "DELETING",
+            TriggerState::Updating => "UPDATING",
+            TriggerState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TriggerState {
+    fn default() -> TriggerState {
+        TriggerState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TriggerState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TriggerState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TriggerState {
+    fn from(value: &'a str) -> TriggerState {
+        match value {
+            "CREATING" => TriggerState::Creating,
+            "CREATED" => TriggerState::Created,
+            "ACTIVATING" => TriggerState::Activating,
+            "ACTIVATED" => TriggerState::Activated,
+            "DEACTIVATING" => TriggerState::Deactivating,
+            "DEACTIVATED" => TriggerState::Deactivated,
+            "DELETING" => TriggerState::Deleting,
+            "UPDATING" => TriggerState::Updating,
+            _ => TriggerState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TriggerState {
+    fn from(value: String) -> TriggerState {
+        match value.as_str() {
+            "CREATING" => TriggerState::Creating,
+            "CREATED" => TriggerState::Created,
+            "ACTIVATING" => TriggerState::Activating,
+            "ACTIVATED" => TriggerState::Activated,
+            "DEACTIVATING" => TriggerState::Deactivating,
+            "DEACTIVATED" => TriggerState::Deactivated,
+            "DELETING" => TriggerState::Deleting,
+            "UPDATING" => TriggerState::Updating,
+            _ => TriggerState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TriggerState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TriggerState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TriggerState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TriggerState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TriggerState {
+    fn deserialize<D>(deserializer: D) -> Result<TriggerState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TriggerType {
+    /// `SCHEDULED`
+    Scheduled,
+    /// `CONDITIONAL`
+    Conditional,
+    /// `ON_DEMAND`
+    OnDemand,
+    /// `EVENT`
+    Event,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TriggerType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TriggerType::Scheduled => "SCHEDULED",
+            TriggerType::Conditional => "CONDITIONAL",
+            TriggerType::OnDemand => "ON_DEMAND",
+            TriggerType::Event => "EVENT",
+            TriggerType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TriggerType {
+    fn default() -> TriggerType {
+        TriggerType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TriggerType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TriggerType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TriggerType {
+    fn from(value: &'a str) -> TriggerType {
+        match value {
+            "SCHEDULED" => TriggerType::Scheduled,
+            "CONDITIONAL" => TriggerType::Conditional,
+            "ON_DEMAND" => TriggerType::OnDemand,
+            "EVENT" => TriggerType::Event,
+            _ => TriggerType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TriggerType {
+    fn from(value: String) -> TriggerType {
+        match value.as_str() {
+            "SCHEDULED" => TriggerType::Scheduled,
+            "CONDITIONAL" => TriggerType::Conditional,
+            "ON_DEMAND" => TriggerType::OnDemand,
+            "EVENT" => TriggerType::Event,
+            _ => TriggerType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TriggerType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TriggerType, Self::Err> {
+        Ok(value.into())
+    }
 }
 
+impl ::rusoto_core::param::ToParam for TriggerType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TriggerType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TriggerType {
+    fn deserialize<D>(deserializer: D) -> Result<TriggerType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A structure used to provide information used to update a trigger. This object updates the previous trigger definition by overwriting it completely.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -5349,6 +9085,99 @@ pub struct UntagResourceRequest {
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UntagResourceResponse {}
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum UpdateBehavior {
+    /// `LOG`
+    Log,
+    /// `UPDATE_IN_DATABASE`
+    UpdateInDatabase,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl UpdateBehavior {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            UpdateBehavior::Log => "LOG",
+            UpdateBehavior::UpdateInDatabase => "UPDATE_IN_DATABASE",
+            UpdateBehavior::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for UpdateBehavior {
+    fn default() -> UpdateBehavior {
+        UpdateBehavior::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for UpdateBehavior {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for UpdateBehavior {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for UpdateBehavior {
+    fn from(value: &'a str) -> UpdateBehavior {
+        match value {
+            "LOG" => UpdateBehavior::Log,
+            "UPDATE_IN_DATABASE" => UpdateBehavior::UpdateInDatabase,
+            _ => UpdateBehavior::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for UpdateBehavior {
+    fn from(value: String) -> UpdateBehavior {
+        match value.as_str() {
+            "LOG" => UpdateBehavior::Log,
+            "UPDATE_IN_DATABASE" => UpdateBehavior::UpdateInDatabase,
+            _ => UpdateBehavior::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for UpdateBehavior {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<UpdateBehavior, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for UpdateBehavior {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for UpdateBehavior {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for UpdateBehavior {
+    fn deserialize<D>(deserializer: D) -> Result<UpdateBehavior, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct UpdateClassifierRequest {
@@ -5472,7 +9301,7 @@ pub struct UpdateCsvClassifierRequest {
     /// <p>Indicates whether the CSV file contains a header.</p>
     #[serde(rename = "ContainsHeader")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub contains_header: Option<String>,
+    pub contains_header: Option<CsvHeaderOption>,
     /// <p>A custom symbol to denote what separates each column entry in the row.</p>
     #[serde(rename = "Delimiter")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -5652,7 +9481,7 @@ pub struct UpdateMLTransformRequest {
     /// <p><p>The type of predefined worker that is allocated when this task runs. Accepts a value of Standard, G.1X, or G.2X.</p> <ul> <li> <p>For the <code>Standard</code> worker type, each worker provides 4 vCPU, 16 GB of memory and a 50GB disk, and 2 executors per worker.</p> </li> <li> <p>For the <code>G.1X</code> worker type, each worker provides 4 vCPU, 16 GB of memory and a 64GB disk, and 1 executor per worker.</p> </li> <li> <p>For the <code>G.2X</code> worker type, each worker provides 8 vCPU, 32 GB of memory and a 128GB disk, and 1 executor per worker.</p> </li> </ul></p>
     #[serde(rename = "WorkerType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub worker_type: Option<String>,
+    pub worker_type: Option<WorkerType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -5806,8 +9635,13 @@ pub struct UserDefinedFunction {
     pub class_name: Option<String>,
     /// <p>The time at which the function was created.</p>
     #[serde(rename = "CreateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub create_time: Option<f64>,
+    pub create_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the function.</p>
     #[serde(rename = "FunctionName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -5819,7 +9653,7 @@ pub struct UserDefinedFunction {
     /// <p>The owner type.</p>
     #[serde(rename = "OwnerType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub owner_type: Option<String>,
+    pub owner_type: Option<PrincipalType>,
     /// <p>The resource URIs for the function.</p>
     #[serde(rename = "ResourceUris")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -5845,21 +9679,144 @@ pub struct UserDefinedFunctionInput {
     /// <p>The owner type.</p>
     #[serde(rename = "OwnerType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub owner_type: Option<String>,
+    pub owner_type: Option<PrincipalType>,
     /// <p>The resource URIs for the function.</p>
     #[serde(rename = "ResourceUris")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub resource_uris: Option<Vec<ResourceUri>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum WorkerType {
+    /// `Standard`
+    Standard,
+    /// `G.1X`
+    G1x,
+    /// `G.2X`
+    G2x,
+    /// `G.025X`
+    G025x,
+    /// `G.4X`
+    G4x,
+    /// `G.8X`
+    G8x,
+    /// `Z.2X`
+    Z2x,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl WorkerType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            WorkerType::Standard => "Standard",
+            WorkerType::G1x => "G.1X",
+            WorkerType::G2x => "G.2X",
+            WorkerType::G025x => "G.025X",
+            WorkerType::G4x => "G.4X",
+            WorkerType::G8x => "G.8X",
+            WorkerType::Z2x => "Z.2X",
+            WorkerType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for WorkerType {
+    fn default() -> WorkerType {
+        WorkerType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for WorkerType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for WorkerType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for WorkerType {
+    fn from(value: &'a str) -> WorkerType {
+        match value {
+            "Standard" => WorkerType::Standard,
+            "G.1X" => WorkerType::G1x,
+            "G.2X" => WorkerType::G2x,
+            "G.025X" => WorkerType::G025x,
+            "G.4X" => WorkerType::G4x,
+            "G.8X" => WorkerType::G8x,
+            "Z.2X" => WorkerType::Z2x,
+            _ => WorkerType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for WorkerType {
+    fn from(value: String) -> WorkerType {
+        match value.as_str() {
+            "Standard" => WorkerType::Standard,
+            "G.1X" => WorkerType::G1x,
+            "G.2X" => WorkerType::G2x,
+            "G.025X" => WorkerType::G025x,
+            "G.4X" => WorkerType::G4x,
+            "G.8X" => WorkerType::G8x,
+            "Z.2X" => WorkerType::Z2x,
+            _ => WorkerType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for WorkerType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<WorkerType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for WorkerType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for WorkerType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for WorkerType {
+    fn deserialize<D>(deserializer: D) -> Result<WorkerType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A workflow represents a flow in which AWS Glue components should be executed to complete a logical task.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Workflow {
     /// <p>The date and time when the workflow was created.</p>
     #[serde(rename = "CreatedOn")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_on: Option<f64>,
+    pub created_on: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A collection of properties to be used as part of each execution of the workflow.</p>
     #[serde(rename = "DefaultRunProperties")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -5874,8 +9831,13 @@ pub struct Workflow {
     pub graph: Option<WorkflowGraph>,
     /// <p>The date and time when the workflow was last modified.</p>
     #[serde(rename = "LastModifiedOn")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_on: Option<f64>,
+    pub last_modified_on: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The information about the last execution of the workflow.</p>
     #[serde(rename = "LastRun")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -5906,8 +9868,13 @@ pub struct WorkflowGraph {
 pub struct WorkflowRun {
     /// <p>The date and time when the workflow run completed.</p>
     #[serde(rename = "CompletedOn")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub completed_on: Option<f64>,
+    pub completed_on: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The graph representing all the AWS Glue components that belong to the workflow as nodes and directed connections between them as edges.</p>
     #[serde(rename = "Graph")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -5918,8 +9885,13 @@ pub struct WorkflowRun {
     pub name: Option<String>,
     /// <p>The date and time when the workflow run was started.</p>
     #[serde(rename = "StartedOn")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub started_on: Option<f64>,
+    pub started_on: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The statistics of the run.</p>
     #[serde(rename = "Statistics")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -5927,7 +9899,7 @@ pub struct WorkflowRun {
     /// <p>The status of the workflow run.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<WorkflowRunStatus>,
     /// <p>The ID of this workflow run.</p>
     #[serde(rename = "WorkflowRunId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -5968,6 +9940,114 @@ pub struct WorkflowRunStatistics {
     pub total_actions: Option<i64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum WorkflowRunStatus {
+    /// `RUNNING`
+    Running,
+    /// `COMPLETED`
+    Completed,
+    /// `STOPPING`
+    Stopping,
+    /// `STOPPED`
+    Stopped,
+    /// `ERROR`
+    Error,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl WorkflowRunStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            WorkflowRunStatus::Running => "RUNNING",
+            WorkflowRunStatus::Completed => "COMPLETED",
+            WorkflowRunStatus::Stopping => "STOPPING",
+            WorkflowRunStatus::Stopped => "STOPPED",
+            WorkflowRunStatus::Error => "ERROR",
+            WorkflowRunStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for WorkflowRunStatus {
+    fn default() -> WorkflowRunStatus {
+        WorkflowRunStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for WorkflowRunStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for WorkflowRunStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for WorkflowRunStatus {
+    fn from(value: &'a str) -> WorkflowRunStatus {
+        match value {
+            "RUNNING" => WorkflowRunStatus::Running,
+            "COMPLETED" => WorkflowRunStatus::Completed,
+            "STOPPING" => WorkflowRunStatus::Stopping,
+            "STOPPED" => WorkflowRunStatus::Stopped,
+            "ERROR" => WorkflowRunStatus::Error,
+            _ => WorkflowRunStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for WorkflowRunStatus {
+    fn from(value: String) -> WorkflowRunStatus {
+        match value.as_str() {
+            "RUNNING" => WorkflowRunStatus::Running,
+            "COMPLETED" => WorkflowRunStatus::Completed,
+            "STOPPING" => WorkflowRunStatus::Stopping,
+            "STOPPED" => WorkflowRunStatus::Stopped,
+            "ERROR" => WorkflowRunStatus::Error,
+            _ => WorkflowRunStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for WorkflowRunStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<WorkflowRunStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for WorkflowRunStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for WorkflowRunStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for WorkflowRunStatus {
+    fn deserialize<D>(deserializer: D) -> Result<WorkflowRunStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A classifier for <code>XML</code> content.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -5977,12 +10057,22 @@ pub struct XMLClassifier {
     pub classification: String,
     /// <p>The time that this classifier was registered.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The time that this classifier was last updated.</p>
     #[serde(rename = "LastUpdated")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated: Option<f64>,
+    pub last_updated: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the classifier.</p>
     #[serde(rename = "Name")]
     pub name: String,
@@ -13153,11 +17243,12 @@ pub struct GlueClient {
 impl GlueClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> GlueClient {
         GlueClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Glue"),
         }
     }
 
@@ -13189,6 +17280,7 @@ impl Glue for GlueClient {
         input: BatchCreatePartitionRequest,
     ) -> Result<BatchCreatePartitionResponse, RusotoError<BatchCreatePartitionError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("BatchCreatePartition");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.BatchCreatePartition");
@@ -13217,6 +17309,7 @@ impl Glue for GlueClient {
         input: BatchDeleteConnectionRequest,
     ) -> Result<BatchDeleteConnectionResponse, RusotoError<BatchDeleteConnectionError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("BatchDeleteConnection");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.BatchDeleteConnection");
@@ -13245,6 +17338,7 @@ impl Glue for GlueClient {
         input: BatchDeletePartitionRequest,
     ) -> Result<BatchDeletePartitionResponse, RusotoError<BatchDeletePartitionError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("BatchDeletePartition");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.BatchDeletePartition");
@@ -13273,6 +17367,7 @@ impl Glue for GlueClient {
         input: BatchDeleteTableRequest,
     ) -> Result<BatchDeleteTableResponse, RusotoError<BatchDeleteTableError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("BatchDeleteTable");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.BatchDeleteTable");
@@ -13301,6 +17396,7 @@ impl Glue for GlueClient {
         input: BatchDeleteTableVersionRequest,
     ) -> Result<BatchDeleteTableVersionResponse, RusotoError<BatchDeleteTableVersionError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("BatchDeleteTableVersion");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.BatchDeleteTableVersion");
@@ -13329,6 +17425,7 @@ impl Glue for GlueClient {
         input: BatchGetCrawlersRequest,
     ) -> Result<BatchGetCrawlersResponse, RusotoError<BatchGetCrawlersError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("BatchGetCrawlers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.BatchGetCrawlers");
@@ -13357,6 +17454,7 @@ impl Glue for GlueClient {
         input: BatchGetDevEndpointsRequest,
     ) -> Result<BatchGetDevEndpointsResponse, RusotoError<BatchGetDevEndpointsError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("BatchGetDevEndpoints");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.BatchGetDevEndpoints");
@@ -13385,6 +17483,7 @@ impl Glue for GlueClient {
         input: BatchGetJobsRequest,
     ) -> Result<BatchGetJobsResponse, RusotoError<BatchGetJobsError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("BatchGetJobs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.BatchGetJobs");
@@ -13412,6 +17511,7 @@ impl Glue for GlueClient {
         input: BatchGetPartitionRequest,
     ) -> Result<BatchGetPartitionResponse, RusotoError<BatchGetPartitionError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("BatchGetPartition");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.BatchGetPartition");
@@ -13440,6 +17540,7 @@ impl Glue for GlueClient {
         input: BatchGetTriggersRequest,
     ) -> Result<BatchGetTriggersResponse, RusotoError<BatchGetTriggersError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("BatchGetTriggers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.BatchGetTriggers");
@@ -13468,6 +17569,7 @@ impl Glue for GlueClient {
         input: BatchGetWorkflowsRequest,
     ) -> Result<BatchGetWorkflowsResponse, RusotoError<BatchGetWorkflowsError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("BatchGetWorkflows");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.BatchGetWorkflows");
@@ -13496,6 +17598,7 @@ impl Glue for GlueClient {
         input: BatchStopJobRunRequest,
     ) -> Result<BatchStopJobRunResponse, RusotoError<GlueBatchStopJobRunError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("BatchStopJobRun");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.BatchStopJobRun");
@@ -13523,6 +17626,7 @@ impl Glue for GlueClient {
         input: CancelMLTaskRunRequest,
     ) -> Result<CancelMLTaskRunResponse, RusotoError<CancelMLTaskRunError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("CancelMLTaskRun");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.CancelMLTaskRun");
@@ -13550,6 +17654,7 @@ impl Glue for GlueClient {
         input: CreateClassifierRequest,
     ) -> Result<CreateClassifierResponse, RusotoError<CreateClassifierError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("CreateClassifier");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.CreateClassifier");
@@ -13578,6 +17683,7 @@ impl Glue for GlueClient {
         input: CreateConnectionRequest,
     ) -> Result<CreateConnectionResponse, RusotoError<CreateConnectionError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("CreateConnection");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.CreateConnection");
@@ -13606,6 +17712,7 @@ impl Glue for GlueClient {
         input: CreateCrawlerRequest,
     ) -> Result<CreateCrawlerResponse, RusotoError<CreateCrawlerError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("CreateCrawler");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.CreateCrawler");
@@ -13633,6 +17740,7 @@ impl Glue for GlueClient {
         input: CreateDatabaseRequest,
     ) -> Result<CreateDatabaseResponse, RusotoError<CreateDatabaseError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("CreateDatabase");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.CreateDatabase");
@@ -13660,6 +17768,7 @@ impl Glue for GlueClient {
         input: CreateDevEndpointRequest,
     ) -> Result<CreateDevEndpointResponse, RusotoError<CreateDevEndpointError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("CreateDevEndpoint");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.CreateDevEndpoint");
@@ -13688,6 +17797,7 @@ impl Glue for GlueClient {
         input: CreateJobRequest,
     ) -> Result<CreateJobResponse, RusotoError<CreateJobError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("CreateJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.CreateJob");
@@ -13715,6 +17825,7 @@ impl Glue for GlueClient {
         input: CreateMLTransformRequest,
     ) -> Result<CreateMLTransformResponse, RusotoError<CreateMLTransformError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("CreateMLTransform");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.CreateMLTransform");
@@ -13743,6 +17854,7 @@ impl Glue for GlueClient {
         input: CreatePartitionRequest,
     ) -> Result<CreatePartitionResponse, RusotoError<CreatePartitionError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("CreatePartition");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.CreatePartition");
@@ -13770,6 +17882,7 @@ impl Glue for GlueClient {
         input: CreateScriptRequest,
     ) -> Result<CreateScriptResponse, RusotoError<CreateScriptError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("CreateScript");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.CreateScript");
@@ -13798,6 +17911,7 @@ impl Glue for GlueClient {
     ) -> Result<CreateSecurityConfigurationResponse, RusotoError<CreateSecurityConfigurationError>>
     {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("CreateSecurityConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.CreateSecurityConfiguration");
@@ -13826,6 +17940,7 @@ impl Glue for GlueClient {
         input: CreateTableRequest,
     ) -> Result<CreateTableResponse, RusotoError<CreateTableError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("CreateTable");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.CreateTable");
@@ -13853,6 +17968,7 @@ impl Glue for GlueClient {
         input: CreateTriggerRequest,
     ) -> Result<CreateTriggerResponse, RusotoError<CreateTriggerError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("CreateTrigger");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.CreateTrigger");
@@ -13881,6 +17997,7 @@ impl Glue for GlueClient {
     ) -> Result<CreateUserDefinedFunctionResponse, RusotoError<CreateUserDefinedFunctionError>>
     {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("CreateUserDefinedFunction");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.CreateUserDefinedFunction");
@@ -13909,6 +18026,7 @@ impl Glue for GlueClient {
         input: CreateWorkflowRequest,
     ) -> Result<CreateWorkflowResponse, RusotoError<CreateWorkflowError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("CreateWorkflow");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.CreateWorkflow");
@@ -13936,6 +18054,7 @@ impl Glue for GlueClient {
         input: DeleteClassifierRequest,
     ) -> Result<DeleteClassifierResponse, RusotoError<DeleteClassifierError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("DeleteClassifier");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.DeleteClassifier");
@@ -13964,6 +18083,7 @@ impl Glue for GlueClient {
         input: DeleteConnectionRequest,
     ) -> Result<DeleteConnectionResponse, RusotoError<DeleteConnectionError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("DeleteConnection");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.DeleteConnection");
@@ -13992,6 +18112,7 @@ impl Glue for GlueClient {
         input: DeleteCrawlerRequest,
     ) -> Result<DeleteCrawlerResponse, RusotoError<DeleteCrawlerError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("DeleteCrawler");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.DeleteCrawler");
@@ -14019,6 +18140,7 @@ impl Glue for GlueClient {
         input: DeleteDatabaseRequest,
     ) -> Result<DeleteDatabaseResponse, RusotoError<DeleteDatabaseError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("DeleteDatabase");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.DeleteDatabase");
@@ -14046,6 +18168,7 @@ impl Glue for GlueClient {
         input: DeleteDevEndpointRequest,
     ) -> Result<DeleteDevEndpointResponse, RusotoError<DeleteDevEndpointError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("DeleteDevEndpoint");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.DeleteDevEndpoint");
@@ -14074,6 +18197,7 @@ impl Glue for GlueClient {
         input: DeleteJobRequest,
     ) -> Result<DeleteJobResponse, RusotoError<DeleteJobError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("DeleteJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.DeleteJob");
@@ -14101,6 +18225,7 @@ impl Glue for GlueClient {
         input: DeleteMLTransformRequest,
     ) -> Result<DeleteMLTransformResponse, RusotoError<DeleteMLTransformError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("DeleteMLTransform");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.DeleteMLTransform");
@@ -14129,6 +18254,7 @@ impl Glue for GlueClient {
         input: DeletePartitionRequest,
     ) -> Result<DeletePartitionResponse, RusotoError<DeletePartitionError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("DeletePartition");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.DeletePartition");
@@ -14156,6 +18282,7 @@ impl Glue for GlueClient {
         input: DeleteResourcePolicyRequest,
     ) -> Result<DeleteResourcePolicyResponse, RusotoError<DeleteResourcePolicyError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("DeleteResourcePolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.DeleteResourcePolicy");
@@ -14185,6 +18312,7 @@ impl Glue for GlueClient {
     ) -> Result<DeleteSecurityConfigurationResponse, RusotoError<DeleteSecurityConfigurationError>>
     {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("DeleteSecurityConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.DeleteSecurityConfiguration");
@@ -14213,6 +18341,7 @@ impl Glue for GlueClient {
         input: DeleteTableRequest,
     ) -> Result<DeleteTableResponse, RusotoError<DeleteTableError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("DeleteTable");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.DeleteTable");
@@ -14240,6 +18369,7 @@ impl Glue for GlueClient {
         input: DeleteTableVersionRequest,
     ) -> Result<DeleteTableVersionResponse, RusotoError<DeleteTableVersionError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("DeleteTableVersion");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.DeleteTableVersion");
@@ -14268,6 +18398,7 @@ impl Glue for GlueClient {
         input: DeleteTriggerRequest,
     ) -> Result<DeleteTriggerResponse, RusotoError<DeleteTriggerError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("DeleteTrigger");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.DeleteTrigger");
@@ -14296,6 +18427,7 @@ impl Glue for GlueClient {
     ) -> Result<DeleteUserDefinedFunctionResponse, RusotoError<DeleteUserDefinedFunctionError>>
     {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("DeleteUserDefinedFunction");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.DeleteUserDefinedFunction");
@@ -14324,6 +18456,7 @@ impl Glue for GlueClient {
         input: DeleteWorkflowRequest,
     ) -> Result<DeleteWorkflowResponse, RusotoError<DeleteWorkflowError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("DeleteWorkflow");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.DeleteWorkflow");
@@ -14351,6 +18484,7 @@ impl Glue for GlueClient {
         input: GetCatalogImportStatusRequest,
     ) -> Result<GetCatalogImportStatusResponse, RusotoError<GetCatalogImportStatusError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetCatalogImportStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetCatalogImportStatus");
@@ -14379,6 +18513,7 @@ impl Glue for GlueClient {
         input: GetClassifierRequest,
     ) -> Result<GetClassifierResponse, RusotoError<GetClassifierError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetClassifier");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetClassifier");
@@ -14406,6 +18541,7 @@ impl Glue for GlueClient {
         input: GetClassifiersRequest,
     ) -> Result<GetClassifiersResponse, RusotoError<GetClassifiersError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetClassifiers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetClassifiers");
@@ -14433,6 +18569,7 @@ impl Glue for GlueClient {
         input: GetConnectionRequest,
     ) -> Result<GetConnectionResponse, RusotoError<GetConnectionError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetConnection");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetConnection");
@@ -14460,6 +18597,7 @@ impl Glue for GlueClient {
         input: GetConnectionsRequest,
     ) -> Result<GetConnectionsResponse, RusotoError<GetConnectionsError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetConnections");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetConnections");
@@ -14487,6 +18625,7 @@ impl Glue for GlueClient {
         input: GetCrawlerRequest,
     ) -> Result<GetCrawlerResponse, RusotoError<GetCrawlerError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetCrawler");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetCrawler");
@@ -14514,6 +18653,7 @@ impl Glue for GlueClient {
         input: GetCrawlerMetricsRequest,
     ) -> Result<GetCrawlerMetricsResponse, RusotoError<GetCrawlerMetricsError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetCrawlerMetrics");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetCrawlerMetrics");
@@ -14542,6 +18682,7 @@ impl Glue for GlueClient {
         input: GetCrawlersRequest,
     ) -> Result<GetCrawlersResponse, RusotoError<GetCrawlersError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetCrawlers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetCrawlers");
@@ -14572,6 +18713,7 @@ impl Glue for GlueClient {
         RusotoError<GetDataCatalogEncryptionSettingsError>,
     > {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetDataCatalogEncryptionSettings");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetDataCatalogEncryptionSettings");
@@ -14602,6 +18744,7 @@ impl Glue for GlueClient {
         input: GetDatabaseRequest,
     ) -> Result<GetDatabaseResponse, RusotoError<GetDatabaseError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetDatabase");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetDatabase");
@@ -14629,6 +18772,7 @@ impl Glue for GlueClient {
         input: GetDatabasesRequest,
     ) -> Result<GetDatabasesResponse, RusotoError<GetDatabasesError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetDatabases");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetDatabases");
@@ -14656,6 +18800,7 @@ impl Glue for GlueClient {
         input: GetDataflowGraphRequest,
     ) -> Result<GetDataflowGraphResponse, RusotoError<GetDataflowGraphError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetDataflowGraph");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetDataflowGraph");
@@ -14684,6 +18829,7 @@ impl Glue for GlueClient {
         input: GetDevEndpointRequest,
     ) -> Result<GetDevEndpointResponse, RusotoError<GetDevEndpointError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetDevEndpoint");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetDevEndpoint");
@@ -14711,6 +18857,7 @@ impl Glue for GlueClient {
         input: GetDevEndpointsRequest,
     ) -> Result<GetDevEndpointsResponse, RusotoError<GetDevEndpointsError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetDevEndpoints");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetDevEndpoints");
@@ -14738,6 +18885,7 @@ impl Glue for GlueClient {
         input: GetJobRequest,
     ) -> Result<GetJobResponse, RusotoError<GetJobError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetJob");
@@ -14765,6 +18913,7 @@ impl Glue for GlueClient {
         input: GetJobBookmarkRequest,
     ) -> Result<GetJobBookmarkResponse, RusotoError<GetJobBookmarkError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetJobBookmark");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetJobBookmark");
@@ -14792,6 +18941,7 @@ impl Glue for GlueClient {
         input: GetJobRunRequest,
     ) -> Result<GetJobRunResponse, RusotoError<GetJobRunError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetJobRun");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetJobRun");
@@ -14819,6 +18969,7 @@ impl Glue for GlueClient {
         input: GetJobRunsRequest,
     ) -> Result<GetJobRunsResponse, RusotoError<GetJobRunsError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetJobRuns");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetJobRuns");
@@ -14846,6 +18997,7 @@ impl Glue for GlueClient {
         input: GetJobsRequest,
     ) -> Result<GetJobsResponse, RusotoError<GetJobsError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetJobs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetJobs");
@@ -14873,6 +19025,7 @@ impl Glue for GlueClient {
         input: GetMLTaskRunRequest,
     ) -> Result<GetMLTaskRunResponse, RusotoError<GetMLTaskRunError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetMLTaskRun");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetMLTaskRun");
@@ -14900,6 +19053,7 @@ impl Glue for GlueClient {
         input: GetMLTaskRunsRequest,
     ) -> Result<GetMLTaskRunsResponse, RusotoError<GetMLTaskRunsError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetMLTaskRuns");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetMLTaskRuns");
@@ -14927,6 +19081,7 @@ impl Glue for GlueClient {
         input: GetMLTransformRequest,
     ) -> Result<GetMLTransformResponse, RusotoError<GetMLTransformError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetMLTransform");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetMLTransform");
@@ -14954,6 +19109,7 @@ impl Glue for GlueClient {
         input: GetMLTransformsRequest,
     ) -> Result<GetMLTransformsResponse, RusotoError<GetMLTransformsError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetMLTransforms");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetMLTransforms");
@@ -14981,6 +19137,7 @@ impl Glue for GlueClient {
         input: GetMappingRequest,
     ) -> Result<GetMappingResponse, RusotoError<GetMappingError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetMapping");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetMapping");
@@ -15008,6 +19165,7 @@ impl Glue for GlueClient {
         input: GetPartitionRequest,
     ) -> Result<GetPartitionResponse, RusotoError<GetPartitionError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetPartition");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetPartition");
@@ -15035,6 +19193,7 @@ impl Glue for GlueClient {
         input: GetPartitionsRequest,
     ) -> Result<GetPartitionsResponse, RusotoError<GetPartitionsError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetPartitions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetPartitions");
@@ -15062,6 +19221,7 @@ impl Glue for GlueClient {
         input: GetPlanRequest,
     ) -> Result<GetPlanResponse, RusotoError<GetPlanError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetPlan");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetPlan");
@@ -15088,6 +19248,7 @@ impl Glue for GlueClient {
         &self,
     ) -> Result<GetResourcePolicyResponse, RusotoError<GetResourcePolicyError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetResourcePolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetResourcePolicy");
@@ -15115,6 +19276,7 @@ impl Glue for GlueClient {
         input: GetSecurityConfigurationRequest,
     ) -> Result<GetSecurityConfigurationResponse, RusotoError<GetSecurityConfigurationError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetSecurityConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetSecurityConfiguration");
@@ -15144,6 +19306,7 @@ impl Glue for GlueClient {
     ) -> Result<GetSecurityConfigurationsResponse, RusotoError<GetSecurityConfigurationsError>>
     {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetSecurityConfigurations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetSecurityConfigurations");
@@ -15172,6 +19335,7 @@ impl Glue for GlueClient {
         input: GetTableRequest,
     ) -> Result<GetTableResponse, RusotoError<GetTableError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetTable");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetTable");
@@ -15199,6 +19363,7 @@ impl Glue for GlueClient {
         input: GetTableVersionRequest,
     ) -> Result<GetTableVersionResponse, RusotoError<GetTableVersionError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetTableVersion");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetTableVersion");
@@ -15226,6 +19391,7 @@ impl Glue for GlueClient {
         input: GetTableVersionsRequest,
     ) -> Result<GetTableVersionsResponse, RusotoError<GetTableVersionsError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetTableVersions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetTableVersions");
@@ -15254,6 +19420,7 @@ impl Glue for GlueClient {
         input: GetTablesRequest,
     ) -> Result<GetTablesResponse, RusotoError<GetTablesError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetTables");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetTables");
@@ -15281,6 +19448,7 @@ impl Glue for GlueClient {
         input: GetTagsRequest,
     ) -> Result<GetTagsResponse, RusotoError<GetTagsError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetTags");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetTags");
@@ -15308,6 +19476,7 @@ impl Glue for GlueClient {
         input: GetTriggerRequest,
     ) -> Result<GetTriggerResponse, RusotoError<GetTriggerError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetTrigger");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetTrigger");
@@ -15335,6 +19504,7 @@ impl Glue for GlueClient {
         input: GetTriggersRequest,
     ) -> Result<GetTriggersResponse, RusotoError<GetTriggersError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetTriggers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetTriggers");
@@ -15362,6 +19532,7 @@ impl Glue for GlueClient {
         input: GetUserDefinedFunctionRequest,
     ) -> Result<GetUserDefinedFunctionResponse, RusotoError<GetUserDefinedFunctionError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetUserDefinedFunction");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetUserDefinedFunction");
@@ -15390,6 +19561,7 @@ impl Glue for GlueClient {
         input: GetUserDefinedFunctionsRequest,
     ) -> Result<GetUserDefinedFunctionsResponse, RusotoError<GetUserDefinedFunctionsError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetUserDefinedFunctions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetUserDefinedFunctions");
@@ -15418,6 +19590,7 @@ impl Glue for GlueClient {
         input: GetWorkflowRequest,
     ) -> Result<GetWorkflowResponse, RusotoError<GetWorkflowError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetWorkflow");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetWorkflow");
@@ -15445,6 +19618,7 @@ impl Glue for GlueClient {
         input: GetWorkflowRunRequest,
     ) -> Result<GetWorkflowRunResponse, RusotoError<GetWorkflowRunError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetWorkflowRun");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetWorkflowRun");
@@ -15472,6 +19646,7 @@ impl Glue for GlueClient {
         input: GetWorkflowRunPropertiesRequest,
     ) -> Result<GetWorkflowRunPropertiesResponse, RusotoError<GetWorkflowRunPropertiesError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetWorkflowRunProperties");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetWorkflowRunProperties");
@@ -15500,6 +19675,7 @@ impl Glue for GlueClient {
         input: GetWorkflowRunsRequest,
     ) -> Result<GetWorkflowRunsResponse, RusotoError<GetWorkflowRunsError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetWorkflowRuns");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetWorkflowRuns");
@@ -15527,6 +19703,7 @@ impl Glue for GlueClient {
         input: ImportCatalogToGlueRequest,
     ) -> Result<ImportCatalogToGlueResponse, RusotoError<ImportCatalogToGlueError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("ImportCatalogToGlue");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.ImportCatalogToGlue");
@@ -15555,6 +19732,7 @@ impl Glue for GlueClient {
         input: ListCrawlersRequest,
     ) -> Result<ListCrawlersResponse, RusotoError<ListCrawlersError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("ListCrawlers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.ListCrawlers");
@@ -15582,6 +19760,7 @@ impl Glue for GlueClient {
         input: ListDevEndpointsRequest,
     ) -> Result<ListDevEndpointsResponse, RusotoError<ListDevEndpointsError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("ListDevEndpoints");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.ListDevEndpoints");
@@ -15610,6 +19789,7 @@ impl Glue for GlueClient {
         input: ListJobsRequest,
     ) -> Result<ListJobsResponse, RusotoError<ListJobsError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("ListJobs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.ListJobs");
@@ -15637,6 +19817,7 @@ impl Glue for GlueClient {
         input: ListTriggersRequest,
     ) -> Result<ListTriggersResponse, RusotoError<ListTriggersError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("ListTriggers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.ListTriggers");
@@ -15664,6 +19845,7 @@ impl Glue for GlueClient {
         input: ListWorkflowsRequest,
     ) -> Result<ListWorkflowsResponse, RusotoError<ListWorkflowsError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("ListWorkflows");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.ListWorkflows");
@@ -15694,6 +19876,7 @@ impl Glue for GlueClient {
         RusotoError<PutDataCatalogEncryptionSettingsError>,
     > {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("PutDataCatalogEncryptionSettings");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.PutDataCatalogEncryptionSettings");
@@ -15724,6 +19907,7 @@ impl Glue for GlueClient {
         input: PutResourcePolicyRequest,
     ) -> Result<PutResourcePolicyResponse, RusotoError<PutResourcePolicyError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("PutResourcePolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.PutResourcePolicy");
@@ -15752,6 +19936,7 @@ impl Glue for GlueClient {
         input: PutWorkflowRunPropertiesRequest,
     ) -> Result<PutWorkflowRunPropertiesResponse, RusotoError<PutWorkflowRunPropertiesError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("PutWorkflowRunProperties");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.PutWorkflowRunProperties");
@@ -15780,6 +19965,7 @@ impl Glue for GlueClient {
         input: ResetJobBookmarkRequest,
     ) -> Result<ResetJobBookmarkResponse, RusotoError<ResetJobBookmarkError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("ResetJobBookmark");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.ResetJobBookmark");
@@ -15808,6 +19994,7 @@ impl Glue for GlueClient {
         input: SearchTablesRequest,
     ) -> Result<SearchTablesResponse, RusotoError<SearchTablesError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("SearchTables");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.SearchTables");
@@ -15835,6 +20022,7 @@ impl Glue for GlueClient {
         input: StartCrawlerRequest,
     ) -> Result<StartCrawlerResponse, RusotoError<StartCrawlerError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("StartCrawler");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.StartCrawler");
@@ -15862,6 +20050,7 @@ impl Glue for GlueClient {
         input: StartCrawlerScheduleRequest,
     ) -> Result<StartCrawlerScheduleResponse, RusotoError<StartCrawlerScheduleError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("StartCrawlerSchedule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.StartCrawlerSchedule");
@@ -15890,6 +20079,7 @@ impl Glue for GlueClient {
         input: StartExportLabelsTaskRunRequest,
     ) -> Result<StartExportLabelsTaskRunResponse, RusotoError<StartExportLabelsTaskRunError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("StartExportLabelsTaskRun");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.StartExportLabelsTaskRun");
@@ -15918,6 +20108,7 @@ impl Glue for GlueClient {
         input: StartImportLabelsTaskRunRequest,
     ) -> Result<StartImportLabelsTaskRunResponse, RusotoError<StartImportLabelsTaskRunError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("StartImportLabelsTaskRun");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.StartImportLabelsTaskRun");
@@ -15946,6 +20137,7 @@ impl Glue for GlueClient {
         input: StartJobRunRequest,
     ) -> Result<StartJobRunResponse, RusotoError<StartJobRunError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("StartJobRun");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.StartJobRun");
@@ -15973,6 +20165,7 @@ impl Glue for GlueClient {
         input: StartMLEvaluationTaskRunRequest,
     ) -> Result<StartMLEvaluationTaskRunResponse, RusotoError<StartMLEvaluationTaskRunError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("StartMLEvaluationTaskRun");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.StartMLEvaluationTaskRun");
@@ -16004,6 +20197,7 @@ impl Glue for GlueClient {
         RusotoError<StartMLLabelingSetGenerationTaskRunError>,
     > {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("StartMLLabelingSetGenerationTaskRun");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16037,6 +20231,7 @@ impl Glue for GlueClient {
         input: StartTriggerRequest,
     ) -> Result<StartTriggerResponse, RusotoError<StartTriggerError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("StartTrigger");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.StartTrigger");
@@ -16064,6 +20259,7 @@ impl Glue for GlueClient {
         input: StartWorkflowRunRequest,
     ) -> Result<StartWorkflowRunResponse, RusotoError<StartWorkflowRunError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("StartWorkflowRun");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.StartWorkflowRun");
@@ -16092,6 +20288,7 @@ impl Glue for GlueClient {
         input: StopCrawlerRequest,
     ) -> Result<StopCrawlerResponse, RusotoError<StopCrawlerError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("StopCrawler");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.StopCrawler");
@@ -16119,6 +20316,7 @@ impl Glue for GlueClient {
         input: StopCrawlerScheduleRequest,
     ) -> Result<StopCrawlerScheduleResponse, RusotoError<StopCrawlerScheduleError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("StopCrawlerSchedule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.StopCrawlerSchedule");
@@ -16147,6 +20345,7 @@ impl Glue for GlueClient {
         input: StopTriggerRequest,
     ) -> Result<StopTriggerResponse, RusotoError<StopTriggerError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("StopTrigger");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.StopTrigger");
@@ -16174,6 +20373,7 @@ impl Glue for GlueClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.TagResource");
@@ -16201,6 +20401,7 @@ impl Glue for GlueClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.UntagResource");
@@ -16228,6 +20429,7 @@ impl Glue for GlueClient {
         input: UpdateClassifierRequest,
     ) -> Result<UpdateClassifierResponse, RusotoError<UpdateClassifierError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("UpdateClassifier");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.UpdateClassifier");
@@ -16256,6 +20458,7 @@ impl Glue for GlueClient {
         input: UpdateConnectionRequest,
     ) -> Result<UpdateConnectionResponse, RusotoError<UpdateConnectionError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("UpdateConnection");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.UpdateConnection");
@@ -16284,6 +20487,7 @@ impl Glue for GlueClient {
         input: UpdateCrawlerRequest,
     ) -> Result<UpdateCrawlerResponse, RusotoError<UpdateCrawlerError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("UpdateCrawler");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.UpdateCrawler");
@@ -16311,6 +20515,7 @@ impl Glue for GlueClient {
         input: UpdateCrawlerScheduleRequest,
     ) -> Result<UpdateCrawlerScheduleResponse, RusotoError<UpdateCrawlerScheduleError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("UpdateCrawlerSchedule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.UpdateCrawlerSchedule");
@@ -16339,6 +20544,7 @@ impl Glue for GlueClient {
         input: UpdateDatabaseRequest,
     ) -> Result<UpdateDatabaseResponse, RusotoError<UpdateDatabaseError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("UpdateDatabase");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.UpdateDatabase");
@@ -16366,6 +20572,7 @@ impl Glue for GlueClient {
         input: UpdateDevEndpointRequest,
     ) -> Result<UpdateDevEndpointResponse, RusotoError<UpdateDevEndpointError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("UpdateDevEndpoint");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.UpdateDevEndpoint");
@@ -16394,6 +20601,7 @@ impl Glue for GlueClient {
         input: UpdateJobRequest,
     ) -> Result<UpdateJobResponse, RusotoError<UpdateJobError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("UpdateJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.UpdateJob");
@@ -16421,6 +20629,7 @@ impl Glue for GlueClient {
         input: UpdateMLTransformRequest,
     ) -> Result<UpdateMLTransformResponse, RusotoError<UpdateMLTransformError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("UpdateMLTransform");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.UpdateMLTransform");
@@ -16449,6 +20658,7 @@ impl Glue for GlueClient {
         input: UpdatePartitionRequest,
     ) -> Result<UpdatePartitionResponse, RusotoError<UpdatePartitionError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("UpdatePartition");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.UpdatePartition");
@@ -16476,6 +20686,7 @@ impl Glue for GlueClient {
         input: UpdateTableRequest,
     ) -> Result<UpdateTableResponse, RusotoError<UpdateTableError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("UpdateTable");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.UpdateTable");
@@ -16503,6 +20714,7 @@ impl Glue for GlueClient {
         input: UpdateTriggerRequest,
     ) -> Result<UpdateTriggerResponse, RusotoError<UpdateTriggerError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("UpdateTrigger");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.UpdateTrigger");
@@ -16531,6 +20743,7 @@ impl Glue for GlueClient {
     ) -> Result<UpdateUserDefinedFunctionResponse, RusotoError<UpdateUserDefinedFunctionError>>
     {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("UpdateUserDefinedFunction");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.UpdateUserDefinedFunction");
@@ -16559,6 +20772,7 @@ impl Glue for GlueClient {
         input: UpdateWorkflowRequest,
     ) -> Result<UpdateWorkflowResponse, RusotoError<UpdateWorkflowError>> {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("UpdateWorkflow");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.UpdateWorkflow");
@@ -16580,3 +20794,449 @@ impl Glue for GlueClient {
         }
     }
 }
+impl GlueClient {
+    /// Auto-paginating version of `get_classifiers`, streaming every page of results.
+    pub fn get_classifiers_pages(
+        &self,
+        input: GetClassifiersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetClassifiersResponse, GetClassifiersError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GlueClient, input: GetClassifiersRequest| async move {
+                client.get_classifiers(input).await
+            },
+            |input: &GetClassifiersRequest, output: &GetClassifiersResponse| {
+                Some(GetClassifiersRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_classifiers`, streaming the `Classifiers` of every page of results.
+    pub fn get_classifiers_items(
+        &self,
+        input: GetClassifiersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Classifier, GetClassifiersError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_classifiers_pages(input),
+            |output: GetClassifiersResponse| output.classifiers.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_connections`, streaming every page of results.
+    pub fn get_connections_pages(
+        &self,
+        input: GetConnectionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetConnectionsResponse, GetConnectionsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GlueClient, input: GetConnectionsRequest| async move {
+                client.get_connections(input).await
+            },
+            |input: &GetConnectionsRequest, output: &GetConnectionsResponse| {
+                Some(GetConnectionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_connections`, streaming the `ConnectionList` of every page of results.
+    pub fn get_connections_items(
+        &self,
+        input: GetConnectionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Connection, GetConnectionsError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_connections_pages(input),
+            |output: GetConnectionsResponse| output.connection_list.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_crawler_metrics`, streaming every page of results.
+    pub fn get_crawler_metrics_pages(
+        &self,
+        input: GetCrawlerMetricsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetCrawlerMetricsResponse, GetCrawlerMetricsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GlueClient, input: GetCrawlerMetricsRequest| async move {
+                client.get_crawler_metrics(input).await
+            },
+            |input: &GetCrawlerMetricsRequest, output: &GetCrawlerMetricsResponse| {
+                Some(GetCrawlerMetricsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_crawler_metrics`, streaming the `CrawlerMetricsList` of every page of results.
+    pub fn get_crawler_metrics_items(
+        &self,
+        input: GetCrawlerMetricsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<CrawlerMetrics, GetCrawlerMetricsError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_crawler_metrics_pages(input),
+            |output: GetCrawlerMetricsResponse| output.crawler_metrics_list.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_crawlers`, streaming every page of results.
+    pub fn get_crawlers_pages(
+        &self,
+        input: GetCrawlersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetCrawlersResponse, GetCrawlersError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GlueClient, input: GetCrawlersRequest| async move {
+                client.get_crawlers(input).await
+            },
+            |input: &GetCrawlersRequest, output: &GetCrawlersResponse| {
+                Some(GetCrawlersRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_crawlers`, streaming the `Crawlers` of every page of results.
+    pub fn get_crawlers_items(
+        &self,
+        input: GetCrawlersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Crawler, GetCrawlersError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_crawlers_pages(input),
+            |output: GetCrawlersResponse| output.crawlers.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_databases`, streaming every page of results.
+    pub fn get_databases_pages(
+        &self,
+        input: GetDatabasesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetDatabasesResponse, GetDatabasesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GlueClient, input: GetDatabasesRequest| async move {
+                client.get_databases(input).await
+            },
+            |input: &GetDatabasesRequest, output: &GetDatabasesResponse| {
+                Some(GetDatabasesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_databases`, streaming the `DatabaseList` of every page of results.
+    pub fn get_databases_items(
+        &self,
+        input: GetDatabasesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Database, GetDatabasesError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_databases_pages(input),
+            |output: GetDatabasesResponse| output.database_list,
+        )
+    }
+
+    /// Auto-paginating version of `get_dev_endpoints`, streaming every page of results.
+    pub fn get_dev_endpoints_pages(
+        &self,
+        input: GetDevEndpointsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetDevEndpointsResponse, GetDevEndpointsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GlueClient, input: GetDevEndpointsRequest| async move {
+                client.get_dev_endpoints(input).await
+            },
+            |input: &GetDevEndpointsRequest, output: &GetDevEndpointsResponse| {
+                Some(GetDevEndpointsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_dev_endpoints`, streaming the `DevEndpoints` of every page of results.
+    pub fn get_dev_endpoints_items(
+        &self,
+        input: GetDevEndpointsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DevEndpoint, GetDevEndpointsError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_dev_endpoints_pages(input),
+            |output: GetDevEndpointsResponse| output.dev_endpoints.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_job_runs`, streaming every page of results.
+    pub fn get_job_runs_pages(
+        &self,
+        input: GetJobRunsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetJobRunsResponse, GetJobRunsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GlueClient, input: GetJobRunsRequest| async move {
+                client.get_job_runs(input).await
+            },
+            |input: &GetJobRunsRequest, output: &GetJobRunsResponse| {
+                Some(GetJobRunsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_job_runs`, streaming the `JobRuns` of every page of results.
+    pub fn get_job_runs_items(
+        &self,
+        input: GetJobRunsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<JobRun, GetJobRunsError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_job_runs_pages(input),
+            |output: GetJobRunsResponse| output.job_runs.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_jobs`, streaming every page of results.
+    pub fn get_jobs_pages(
+        &self,
+        input: GetJobsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetJobsResponse, GetJobsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GlueClient, input: GetJobsRequest| async move { client.get_jobs(input).await },
+            |input: &GetJobsRequest, output: &GetJobsResponse| {
+                Some(GetJobsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_jobs`, streaming the `Jobs` of every page of results.
+    pub fn get_jobs_items(
+        &self,
+        input: GetJobsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Job, GetJobsError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_jobs_pages(input),
+            |output: GetJobsResponse| output.jobs.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_partitions`, streaming every page of results.
+    pub fn get_partitions_pages(
+        &self,
+        input: GetPartitionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetPartitionsResponse, GetPartitionsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GlueClient, input: GetPartitionsRequest| async move {
+                client.get_partitions(input).await
+            },
+            |input: &GetPartitionsRequest, output: &GetPartitionsResponse| {
+                Some(GetPartitionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_partitions`, streaming the `Partitions` of every page of results.
+    pub fn get_partitions_items(
+        &self,
+        input: GetPartitionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Partition, GetPartitionsError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_partitions_pages(input),
+            |output: GetPartitionsResponse| output.partitions.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_security_configurations`, streaming every page of results.
+    pub fn get_security_configurations_pages(
+        &self,
+        input: GetSecurityConfigurationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        GetSecurityConfigurationsResponse,
+        GetSecurityConfigurationsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GlueClient, input: GetSecurityConfigurationsRequest| async move {
+                client.get_security_configurations(input).await
+            },
+            |input: &GetSecurityConfigurationsRequest,
+             output: &GetSecurityConfigurationsResponse| {
+                Some(GetSecurityConfigurationsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_security_configurations`, streaming the `SecurityConfigurations` of every page of results.
+    pub fn get_security_configurations_items(
+        &self,
+        input: GetSecurityConfigurationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<SecurityConfiguration, GetSecurityConfigurationsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.get_security_configurations_pages(input),
+            |output: GetSecurityConfigurationsResponse| {
+                output.security_configurations.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_table_versions`, streaming every page of results.
+    pub fn get_table_versions_pages(
+        &self,
+        input: GetTableVersionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetTableVersionsResponse, GetTableVersionsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GlueClient, input: GetTableVersionsRequest| async move {
+                client.get_table_versions(input).await
+            },
+            |input: &GetTableVersionsRequest, output: &GetTableVersionsResponse| {
+                Some(GetTableVersionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_table_versions`, streaming the `TableVersions` of every page of results.
+    pub fn get_table_versions_items(
+        &self,
+        input: GetTableVersionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<TableVersion, GetTableVersionsError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_table_versions_pages(input),
+            |output: GetTableVersionsResponse| output.table_versions.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_tables`, streaming every page of results.
+    pub fn get_tables_pages(
+        &self,
+        input: GetTablesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetTablesResponse, GetTablesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GlueClient, input: GetTablesRequest| async move { client.get_tables(input).await },
+            |input: &GetTablesRequest, output: &GetTablesResponse| {
+                Some(GetTablesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_tables`, streaming the `TableList` of every page of results.
+    pub fn get_tables_items(
+        &self,
+        input: GetTablesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Table, GetTablesError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_tables_pages(input),
+            |output: GetTablesResponse| output.table_list.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_triggers`, streaming every page of results.
+    pub fn get_triggers_pages(
+        &self,
+        input: GetTriggersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetTriggersResponse, GetTriggersError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GlueClient, input: GetTriggersRequest| async move {
+                client.get_triggers(input).await
+            },
+            |input: &GetTriggersRequest, output: &GetTriggersResponse| {
+                Some(GetTriggersRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_triggers`, streaming the `Triggers` of every page of results.
+    pub fn get_triggers_items(
+        &self,
+        input: GetTriggersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Trigger, GetTriggersError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_triggers_pages(input),
+            |output: GetTriggersResponse| output.triggers.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_user_defined_functions`, streaming every page of results.
+    pub fn get_user_defined_functions_pages(
+        &self,
+        input: GetUserDefinedFunctionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        GetUserDefinedFunctionsResponse,
+        GetUserDefinedFunctionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GlueClient, input: GetUserDefinedFunctionsRequest| async move {
+                client.get_user_defined_functions(input).await
+            },
+            |input: &GetUserDefinedFunctionsRequest, output: &GetUserDefinedFunctionsResponse| {
+                Some(GetUserDefinedFunctionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_user_defined_functions`, streaming the `UserDefinedFunctions` of every page of results.
+    pub fn get_user_defined_functions_items(
+        &self,
+        input: GetUserDefinedFunctionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<UserDefinedFunction, GetUserDefinedFunctionsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.get_user_defined_functions_pages(input),
+            |output: GetUserDefinedFunctionsResponse| {
+                output.user_defined_functions.unwrap_or_default()
+            },
+        )
+    }
+}
@@ -121,7 +121,7 @@ pub struct BulkDeploymentResult {
     /// <p>The type of the deployment.</p>
     #[serde(rename = "DeploymentType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deployment_type: Option<String>,
+    pub deployment_type: Option<DeploymentType>,
     /// <p>Details about the error.</p>
     #[serde(rename = "ErrorDetails")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -136,6 +136,120 @@ pub struct BulkDeploymentResult {
     pub group_arn: Option<String>,
 }
 
+/// The current status of the bulk deployment.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BulkDeploymentStatus {
+    /// `Initializing`
+    Initializing,
+    /// `Running`
+    Running,
+    /// `Completed`
+    Completed,
+    /// `Stopping`
+    Stopping,
+    /// `Stopped`
+    Stopped,
+    /// `Failed`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl BulkDeploymentStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BulkDeploymentStatus::Initializing => "Initializing",
+            BulkDeploymentStatus::Running => "Running",
+            BulkDeploymentStatus::Completed => "Completed",
+            BulkDeploymentStatus::Stopping => "Stopping",
+            BulkDeploymentStatus::Stopped => "Stopped",
+            BulkDeploymentStatus::Failed => "Failed",
+            BulkDeploymentStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for BulkDeploymentStatus {
+    fn default() -> BulkDeploymentStatus {
+        BulkDeploymentStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for BulkDeploymentStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for BulkDeploymentStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for BulkDeploymentStatus {
+    fn from(value: &'a str) -> BulkDeploymentStatus {
+        match value {
+            "Initializing" => BulkDeploymentStatus::Initializing,
+            "Running" => BulkDeploymentStatus::Running,
+            "Completed" => BulkDeploymentStatus::Completed,
+            "Stopping" => BulkDeploymentStatus::Stopping,
+            "Stopped" => BulkDeploymentStatus::Stopped,
+            "Failed" => BulkDeploymentStatus::Failed,
+            _ => BulkDeploymentStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BulkDeploymentStatus {
+    fn from(value: String) -> BulkDeploymentStatus {
+        match value.as_str() {
+            "Initializing" => BulkDeploymentStatus::Initializing,
+            "Running" => BulkDeploymentStatus::Running,
+            "Completed" => BulkDeploymentStatus::Completed,
+            "Stopping" => BulkDeploymentStatus::Stopping,
+            "Stopped" => BulkDeploymentStatus::Stopped,
+            "Failed" => BulkDeploymentStatus::Failed,
+            _ => BulkDeploymentStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for BulkDeploymentStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<BulkDeploymentStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for BulkDeploymentStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for BulkDeploymentStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BulkDeploymentStatus {
+    fn deserialize<D>(deserializer: D) -> Result<BulkDeploymentStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about a Greengrass core&#39;s connectivity.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ConnectivityInfo {
@@ -404,7 +518,7 @@ pub struct CreateDeploymentRequest {
     pub deployment_id: Option<String>,
     /// <p>The type of deployment. When used for &#39;&#39;CreateDeployment&#39;&#39;, only &#39;&#39;NewDeployment&#39;&#39; and &#39;&#39;Redeployment&#39;&#39; are valid.</p>
     #[serde(rename = "DeploymentType")]
-    pub deployment_type: String,
+    pub deployment_type: DeploymentType,
     /// <p>The ID of the Greengrass group.</p>
     #[serde(rename = "GroupId")]
     pub group_id: String,
@@ -942,16 +1056,16 @@ pub struct CreateSoftwareUpdateJobRequest {
     #[serde(rename = "S3UrlSignerRole")]
     pub s3_url_signer_role: String,
     #[serde(rename = "SoftwareToUpdate")]
-    pub software_to_update: String,
+    pub software_to_update: SoftwareToUpdate,
     #[serde(rename = "UpdateAgentLogLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub update_agent_log_level: Option<String>,
+    pub update_agent_log_level: Option<UpdateAgentLogLevel>,
     #[serde(rename = "UpdateTargets")]
     pub update_targets: Vec<String>,
     #[serde(rename = "UpdateTargetsArchitecture")]
-    pub update_targets_architecture: String,
+    pub update_targets_architecture: UpdateTargetsArchitecture,
     #[serde(rename = "UpdateTargetsOperatingSystem")]
-    pub update_targets_operating_system: String,
+    pub update_targets_operating_system: UpdateTargetsOperatingSystem,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1215,13 +1329,117 @@ pub struct Deployment {
     /// <p>The type of the deployment.</p>
     #[serde(rename = "DeploymentType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deployment_type: Option<String>,
+    pub deployment_type: Option<DeploymentType>,
     /// <p>The ARN of the group for this deployment.</p>
     #[serde(rename = "GroupArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub group_arn: Option<String>,
 }
 
+/// The type of deployment. When used for ''CreateDeployment'', only ''NewDeployment'' and ''Redeployment'' are valid.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DeploymentType {
+    /// `NewDeployment`
+    NewDeployment,
+    /// `Redeployment`
+    Redeployment,
+    /// `ResetDeployment`
+    ResetDeployment,
+    /// `ForceResetDeployment`
+    ForceResetDeployment,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DeploymentType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeploymentType::NewDeployment => "NewDeployment",
+            DeploymentType::Redeployment => "Redeployment",
+            DeploymentType::ResetDeployment => "ResetDeployment",
+            DeploymentType::ForceResetDeployment => "ForceResetDeployment",
+            DeploymentType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DeploymentType {
+    fn default() -> DeploymentType {
+        DeploymentType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DeploymentType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DeploymentType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DeploymentType {
+    fn from(value: &'a str) -> DeploymentType {
+        match value {
+            "NewDeployment" => DeploymentType::NewDeployment,
+            "Redeployment" => DeploymentType::Redeployment,
+            "ResetDeployment" => DeploymentType::ResetDeployment,
+            "ForceResetDeployment" => DeploymentType::ForceResetDeployment,
+            _ => DeploymentType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeploymentType {
+    fn from(value: String) -> DeploymentType {
+        match value.as_str() {
+            "NewDeployment" => DeploymentType::NewDeployment,
+            "Redeployment" => DeploymentType::Redeployment,
+            "ResetDeployment" => DeploymentType::ResetDeployment,
+            "ForceResetDeployment" => DeploymentType::ForceResetDeployment,
+            _ => DeploymentType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DeploymentType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DeploymentType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DeploymentType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DeploymentType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeploymentType {
+    fn deserialize<D>(deserializer: D) -> Result<DeploymentType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about a device.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Device {
@@ -1279,6 +1497,99 @@ pub struct DisassociateServiceRoleFromAccountResponse {
     pub disassociated_at: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EncodingType {
+    /// `binary`
+    Binary,
+    /// `json`
+    Json,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EncodingType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EncodingType::Binary => "binary",
+            EncodingType::Json => "json",
+            EncodingType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EncodingType {
+    fn default() -> EncodingType {
+        EncodingType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EncodingType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EncodingType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EncodingType {
+    fn from(value: &'a str) -> EncodingType {
+        match value {
+            "binary" => EncodingType::Binary,
+            "json" => EncodingType::Json,
+            _ => EncodingType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EncodingType {
+    fn from(value: String) -> EncodingType {
+        match value.as_str() {
+            "binary" => EncodingType::Binary,
+            "json" => EncodingType::Json,
+            _ => EncodingType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EncodingType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EncodingType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EncodingType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EncodingType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EncodingType {
+    fn deserialize<D>(deserializer: D) -> Result<EncodingType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Details about the error.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1315,7 +1626,7 @@ pub struct FunctionConfiguration {
     /// <p>The expected encoding type of the input payload for the function. The default is &#39;&#39;json&#39;&#39;.</p>
     #[serde(rename = "EncodingType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub encoding_type: Option<String>,
+    pub encoding_type: Option<EncodingType>,
     /// <p>The environment configuration of the function.</p>
     #[serde(rename = "Environment")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1376,7 +1687,7 @@ pub struct FunctionDefaultConfig {
 pub struct FunctionDefaultExecutionConfig {
     #[serde(rename = "IsolationMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub isolation_mode: Option<String>,
+    pub isolation_mode: Option<FunctionIsolationMode>,
     #[serde(rename = "RunAs")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub run_as: Option<FunctionRunAsConfig>,
@@ -1400,12 +1711,106 @@ pub struct FunctionDefinitionVersion {
 pub struct FunctionExecutionConfig {
     #[serde(rename = "IsolationMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub isolation_mode: Option<String>,
+    pub isolation_mode: Option<FunctionIsolationMode>,
     #[serde(rename = "RunAs")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub run_as: Option<FunctionRunAsConfig>,
 }
 
+/// Specifies whether the Lambda function runs in a Greengrass container (default) or without containerization. Unless your scenario requires that you run without containerization, we recommend that you run in a Greengrass container. Omit this value to run the Lambda function with the default containerization for the group.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FunctionIsolationMode {
+    /// `GreengrassContainer`
+    GreengrassContainer,
+    /// `NoContainer`
+    NoContainer,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl FunctionIsolationMode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FunctionIsolationMode::GreengrassContainer => "GreengrassContainer",
+            FunctionIsolationMode::NoContainer => "NoContainer",
+            FunctionIsolationMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for FunctionIsolationMode {
+    fn default() -> FunctionIsolationMode {
+        FunctionIsolationMode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for FunctionIsolationMode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FunctionIsolationMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FunctionIsolationMode {
+    fn from(value: &'a str) -> FunctionIsolationMode {
+        match value {
+            "GreengrassContainer" => FunctionIsolationMode::GreengrassContainer,
+            "NoContainer" => FunctionIsolationMode::NoContainer,
+            _ => FunctionIsolationMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FunctionIsolationMode {
+    fn from(value: String) -> FunctionIsolationMode {
+        match value.as_str() {
+            "GreengrassContainer" => FunctionIsolationMode::GreengrassContainer,
+            "NoContainer" => FunctionIsolationMode::NoContainer,
+            _ => FunctionIsolationMode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for FunctionIsolationMode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<FunctionIsolationMode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FunctionIsolationMode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FunctionIsolationMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FunctionIsolationMode {
+    fn deserialize<D>(deserializer: D) -> Result<FunctionIsolationMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Specifies the user and group whose permissions are used when running the Lambda function. You can specify one or both values to override the default values. We recommend that you avoid running as root unless absolutely necessary to minimize the risk of unintended changes or malicious attacks. To run as root, you must set &#39;&#39;IsolationMode&#39;&#39; to &#39;&#39;NoContainer&#39;&#39; and update config.json in &#39;&#39;greengrass-root/config&#39;&#39; to set &#39;&#39;allowFunctionsToRunAsRoot&#39;&#39; to &#39;&#39;yes&#39;&#39;.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FunctionRunAsConfig {
@@ -1458,7 +1863,7 @@ pub struct GetBulkDeploymentStatusResponse {
     /// <p>The status of the bulk deployment.</p>
     #[serde(rename = "BulkDeploymentStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub bulk_deployment_status: Option<String>,
+    pub bulk_deployment_status: Option<BulkDeploymentStatus>,
     /// <p>The time, in ISO format, when the deployment was created.</p>
     #[serde(rename = "CreatedAt")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1693,7 +2098,7 @@ pub struct GetDeploymentStatusResponse {
     /// <p>The type of the deployment.</p>
     #[serde(rename = "DeploymentType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deployment_type: Option<String>,
+    pub deployment_type: Option<DeploymentType>,
     /// <p>Error details</p>
     #[serde(rename = "ErrorDetails")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2978,22 +3383,115 @@ pub struct LocalVolumeResourceData {
 pub struct Logger {
     /// <p>The component that will be subject to logging.</p>
     #[serde(rename = "Component")]
-    pub component: String,
+    pub component: LoggerComponent,
     /// <p>A descriptive or arbitrary ID for the logger. This value must be unique within the logger definition version. Max length is 128 characters with pattern &#39;&#39;[a-zA-Z0-9:_-]+&#39;&#39;.</p>
     #[serde(rename = "Id")]
     pub id: String,
     /// <p>The level of the logs.</p>
     #[serde(rename = "Level")]
-    pub level: String,
+    pub level: LoggerLevel,
     /// <p>The amount of file space, in KB, to use if the local file system is used for logging purposes.</p>
     #[serde(rename = "Space")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub space: Option<i64>,
     /// <p>The type of log output which will be used.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: LoggerType,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LoggerComponent {
+    /// `GreengrassSystem`
+    GreengrassSystem,
+    /// `Lambda`
+    Lambda,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LoggerComponent {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LoggerComponent::GreengrassSystem => "GreengrassSystem",
+            LoggerComponent::Lambda => "Lambda",
+            LoggerComponent::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LoggerComponent {
+    fn default() -> LoggerComponent {
+        LoggerComponent::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LoggerComponent {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
 }
 
+impl fmt::Display for LoggerComponent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LoggerComponent {
+    fn from(value: &'a str) -> LoggerComponent {
+        match value {
+            "GreengrassSystem" => LoggerComponent::GreengrassSystem,
+            "Lambda" => LoggerComponent::Lambda,
+            _ => LoggerComponent::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LoggerComponent {
+    fn from(value: String) -> LoggerComponent {
+        match value.as_str() {
+            "GreengrassSystem" => LoggerComponent::GreengrassSystem,
+            "Lambda" => LoggerComponent::Lambda,
+            _ => LoggerComponent::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LoggerComponent {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LoggerComponent, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LoggerComponent {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LoggerComponent {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LoggerComponent {
+    fn deserialize<D>(deserializer: D) -> Result<LoggerComponent, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about a logger definition version.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct LoggerDefinitionVersion {
@@ -3003,6 +3501,301 @@ pub struct LoggerDefinitionVersion {
     pub loggers: Option<Vec<Logger>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LoggerLevel {
+    /// `DEBUG`
+    Debug,
+    /// `INFO`
+    Info,
+    /// `WARN`
+    Warn,
+    /// `ERROR`
+    Error,
+    /// `FATAL`
+    Fatal,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LoggerLevel {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LoggerLevel::Debug => "DEBUG",
+            LoggerLevel::Info => "INFO",
+            LoggerLevel::Warn => "WARN",
+            LoggerLevel::Error => "ERROR",
+            LoggerLevel::Fatal => "FATAL",
+            LoggerLevel::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LoggerLevel {
+    fn default() -> LoggerLevel {
+        LoggerLevel::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LoggerLevel {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LoggerLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LoggerLevel {
+    fn from(value: &'a str) -> LoggerLevel {
+        match value {
+            "DEBUG" => LoggerLevel::Debug,
+            "INFO" => LoggerLevel::Info,
+            "WARN" => LoggerLevel::Warn,
+            "ERROR" => LoggerLevel::Error,
+            "FATAL" => LoggerLevel::Fatal,
+            _ => LoggerLevel::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LoggerLevel {
+    fn from(value: String) -> LoggerLevel {
+        match value.as_str() {
+            "DEBUG" => LoggerLevel::Debug,
+            "INFO" => LoggerLevel::Info,
+            "WARN" => LoggerLevel::Warn,
+            "ERROR" => LoggerLevel::Error,
+            "FATAL" => LoggerLevel::Fatal,
+            _ => LoggerLevel::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LoggerLevel {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LoggerLevel, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LoggerLevel {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LoggerLevel {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LoggerLevel {
+    fn deserialize<D>(deserializer: D) -> Result<LoggerLevel, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LoggerType {
+    /// `FileSystem`
+    FileSystem,
+    /// `AWSCloudWatch`
+    AWSCloudWatch,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LoggerType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LoggerType::FileSystem => "FileSystem",
+            LoggerType::AWSCloudWatch => "AWSCloudWatch",
+            LoggerType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LoggerType {
+    fn default() -> LoggerType {
+        LoggerType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LoggerType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LoggerType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LoggerType {
+    fn from(value: &'a str) -> LoggerType {
+        match value {
+            "FileSystem" => LoggerType::FileSystem,
+            "AWSCloudWatch" => LoggerType::AWSCloudWatch,
+            _ => LoggerType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LoggerType {
+    fn from(value: String) -> LoggerType {
+        match value.as_str() {
+            "FileSystem" => LoggerType::FileSystem,
+            "AWSCloudWatch" => LoggerType::AWSCloudWatch,
+            _ => LoggerType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LoggerType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LoggerType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LoggerType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LoggerType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LoggerType {
+    fn deserialize<D>(deserializer: D) -> Result<LoggerType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// The type of permission a function has to access a resource.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Permission {
+    /// `ro`
+    Ro,
+    /// `rw`
+    Rw,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Permission {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Permission::Ro => "ro",
+            Permission::Rw => "rw",
+            Permission::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Permission {
+    fn default() -> Permission {
+        Permission::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Permission {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Permission {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Permission {
+    fn from(value: &'a str) -> Permission {
+        match value {
+            "ro" => Permission::Ro,
+            "rw" => Permission::Rw,
+            _ => Permission::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Permission {
+    fn from(value: String) -> Permission {
+        match value.as_str() {
+            "ro" => Permission::Ro,
+            "rw" => Permission::Rw,
+            _ => Permission::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Permission {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Permission, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Permission {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Permission {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Permission {
+    fn deserialize<D>(deserializer: D) -> Result<Permission, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information needed to reset deployments.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -3053,7 +3846,7 @@ pub struct ResourceAccessPolicy {
     /// <p>The permissions that the Lambda function has to the resource. Can be one of &#39;&#39;rw&#39;&#39; (read/write) or &#39;&#39;ro&#39;&#39; (read-only).</p>
     #[serde(rename = "Permission")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub permission: Option<String>,
+    pub permission: Option<Permission>,
     /// <p>The ID of the resource. (This ID is assigned to the resource when you create the resource definiton.)</p>
     #[serde(rename = "ResourceId")]
     pub resource_id: String,
@@ -3102,7 +3895,7 @@ pub struct ResourceDownloadOwnerSetting {
     pub group_owner: String,
     /// <p>The permissions that the group owner has to the resource. Valid values are &#39;&#39;rw&#39;&#39; (read/write) or &#39;&#39;ro&#39;&#39; (read-only).</p>
     #[serde(rename = "GroupPermission")]
-    pub group_permission: String,
+    pub group_permission: Permission,
 }
 
 /// <p>Attributes that define an Amazon S3 machine learning resource.</p>
@@ -3150,6 +3943,100 @@ pub struct SecretsManagerSecretResourceData {
     pub additional_staging_labels_to_download: Option<Vec<String>>,
 }
 
+/// The piece of software on the Greengrass core that will be updated.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SoftwareToUpdate {
+    /// `core`
+    Core,
+    /// `ota_agent`
+    OtaAgent,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SoftwareToUpdate {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SoftwareToUpdate::Core => "core",
+            SoftwareToUpdate::OtaAgent => "ota_agent",
+            SoftwareToUpdate::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SoftwareToUpdate {
+    fn default() -> SoftwareToUpdate {
+        SoftwareToUpdate::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SoftwareToUpdate {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SoftwareToUpdate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SoftwareToUpdate {
+    fn from(value: &'a str) -> SoftwareToUpdate {
+        match value {
+            "core" => SoftwareToUpdate::Core,
+            "ota_agent" => SoftwareToUpdate::OtaAgent,
+            _ => SoftwareToUpdate::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SoftwareToUpdate {
+    fn from(value: String) -> SoftwareToUpdate {
+        match value.as_str() {
+            "core" => SoftwareToUpdate::Core,
+            "ota_agent" => SoftwareToUpdate::OtaAgent,
+            _ => SoftwareToUpdate::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SoftwareToUpdate {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SoftwareToUpdate, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SoftwareToUpdate {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SoftwareToUpdate {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SoftwareToUpdate {
+    fn deserialize<D>(deserializer: D) -> Result<SoftwareToUpdate, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct StartBulkDeploymentRequest {
@@ -3220,29 +4107,153 @@ pub struct SubscriptionDefinitionVersion {
     pub subscriptions: Option<Vec<Subscription>>,
 }
 
-/// <p>A map of the key-value pairs for the resource tag.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct TagResourceRequest {
-    /// <p>The Amazon Resource Name (ARN) of the resource.</p>
-    #[serde(rename = "ResourceArn")]
-    pub resource_arn: String,
-    #[serde(rename = "tags")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub tags: Option<::std::collections::HashMap<String, String>>,
+/// <p>A map of the key-value pairs for the resource tag.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct TagResourceRequest {
+    /// <p>The Amazon Resource Name (ARN) of the resource.</p>
+    #[serde(rename = "ResourceArn")]
+    pub resource_arn: String,
+    #[serde(rename = "tags")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct UntagResourceRequest {
+    /// <p>The Amazon Resource Name (ARN) of the resource.</p>
+    #[serde(rename = "ResourceArn")]
+    pub resource_arn: String,
+    /// <p>An array of tag keys to delete</p>
+    #[serde(rename = "TagKeys")]
+    pub tag_keys: Vec<String>,
+}
+
+/// The minimum level of log statements that should be logged by the OTA Agent during an update.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum UpdateAgentLogLevel {
+    /// `NONE`
+    None,
+    /// `TRACE`
+    Trace,
+    /// `DEBUG`
+    Debug,
+    /// `VERBOSE`
+    Verbose,
+    /// `INFO`
+    Info,
+    /// `WARN`
+    Warn,
+    /// `ERROR`
+    Error,
+    /// `FATAL`
+    Fatal,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl UpdateAgentLogLevel {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            UpdateAgentLogLevel::None => "NONE",
+            UpdateAgentLogLevel::Trace => "TRACE",
+            UpdateAgentLogLevel::Debug => "DEBUG",
+            UpdateAgentLogLevel::Verbose => "VERBOSE",
+            UpdateAgentLogLevel::Info => "INFO",
+            UpdateAgentLogLevel::Warn => "WARN",
+            UpdateAgentLogLevel::Error => "ERROR",
+            UpdateAgentLogLevel::Fatal => "FATAL",
+            UpdateAgentLogLevel::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for UpdateAgentLogLevel {
+    fn default() -> UpdateAgentLogLevel {
+        UpdateAgentLogLevel::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for UpdateAgentLogLevel {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for UpdateAgentLogLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for UpdateAgentLogLevel {
+    fn from(value: &'a str) -> UpdateAgentLogLevel {
+        match value {
+            "NONE" => UpdateAgentLogLevel::None,
+            "TRACE" => UpdateAgentLogLevel::Trace,
+            "DEBUG" => UpdateAgentLogLevel::Debug,
+            "VERBOSE" => UpdateAgentLogLevel::Verbose,
+            "INFO" => UpdateAgentLogLevel::Info,
+            "WARN" => UpdateAgentLogLevel::Warn,
+            "ERROR" => UpdateAgentLogLevel::Error,
+            "FATAL" => UpdateAgentLogLevel::Fatal,
+            _ => UpdateAgentLogLevel::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for UpdateAgentLogLevel {
+    fn from(value: String) -> UpdateAgentLogLevel {
+        match value.as_str() {
+            "NONE" => UpdateAgentLogLevel::None,
+            "TRACE" => UpdateAgentLogLevel::Trace,
+            "DEBUG" => UpdateAgentLogLevel::Debug,
+            "VERBOSE" => UpdateAgentLogLevel::Verbose,
+            "INFO" => UpdateAgentLogLevel::Info,
+            "WARN" => UpdateAgentLogLevel::Warn,
+            "ERROR" => UpdateAgentLogLevel::Error,
+            "FATAL" => UpdateAgentLogLevel::Fatal,
+            _ => UpdateAgentLogLevel::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for UpdateAgentLogLevel {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<UpdateAgentLogLevel, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for UpdateAgentLogLevel {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct UntagResourceRequest {
-    /// <p>The Amazon Resource Name (ARN) of the resource.</p>
-    #[serde(rename = "ResourceArn")]
-    pub resource_arn: String,
-    /// <p>An array of tag keys to delete</p>
-    #[serde(rename = "TagKeys")]
-    pub tag_keys: Vec<String>,
+impl ::serde::Serialize for UpdateAgentLogLevel {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for UpdateAgentLogLevel {
+    fn deserialize<D>(deserializer: D) -> Result<UpdateAgentLogLevel, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Connectivity information.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -3426,6 +4437,214 @@ pub struct UpdateSubscriptionDefinitionRequest {
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateSubscriptionDefinitionResponse {}
 
+/// The architecture of the cores which are the targets of an update.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum UpdateTargetsArchitecture {
+    /// `armv6l`
+    Armv6l,
+    /// `armv7l`
+    Armv7l,
+    /// `x86_64`
+    X86_64,
+    /// `aarch64`
+    Aarch64,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl UpdateTargetsArchitecture {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            UpdateTargetsArchitecture::Armv6l => "armv6l",
+            UpdateTargetsArchitecture::Armv7l => "armv7l",
+            UpdateTargetsArchitecture::X86_64 => "x86_64",
+            UpdateTargetsArchitecture::Aarch64 => "aarch64",
+            UpdateTargetsArchitecture::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for UpdateTargetsArchitecture {
+    fn default() -> UpdateTargetsArchitecture {
+        UpdateTargetsArchitecture::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for UpdateTargetsArchitecture {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for UpdateTargetsArchitecture {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for UpdateTargetsArchitecture {
+    fn from(value: &'a str) -> UpdateTargetsArchitecture {
+        match value {
+            "armv6l" => UpdateTargetsArchitecture::Armv6l,
+            "armv7l" => UpdateTargetsArchitecture::Armv7l,
+            "x86_64" => UpdateTargetsArchitecture::X86_64,
+            "aarch64" => UpdateTargetsArchitecture::Aarch64,
+            _ => UpdateTargetsArchitecture::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for UpdateTargetsArchitecture {
+    fn from(value: String) -> UpdateTargetsArchitecture {
+        match value.as_str() {
+            "armv6l" => UpdateTargetsArchitecture::Armv6l,
+            "armv7l" => UpdateTargetsArchitecture::Armv7l,
+            "x86_64" => UpdateTargetsArchitecture::X86_64,
+            "aarch64" => UpdateTargetsArchitecture::Aarch64,
+            _ => UpdateTargetsArchitecture::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for UpdateTargetsArchitecture {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<UpdateTargetsArchitecture, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for UpdateTargetsArchitecture {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for UpdateTargetsArchitecture {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for UpdateTargetsArchitecture {
+    fn deserialize<D>(deserializer: D) -> Result<UpdateTargetsArchitecture, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// The operating system of the cores which are the targets of an update.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum UpdateTargetsOperatingSystem {
+    /// `ubuntu`
+    Ubuntu,
+    /// `raspbian`
+    Raspbian,
+    /// `amazon_linux`
+    AmazonLinux,
+    /// `openwrt`
+    Openwrt,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl UpdateTargetsOperatingSystem {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            UpdateTargetsOperatingSystem::Ubuntu => "ubuntu",
+            UpdateTargetsOperatingSystem::Raspbian => "raspbian",
+            UpdateTargetsOperatingSystem::AmazonLinux => "amazon_linux",
+            UpdateTargetsOperatingSystem::Openwrt => "openwrt",
+            UpdateTargetsOperatingSystem::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for UpdateTargetsOperatingSystem {
+    fn default() -> UpdateTargetsOperatingSystem {
+        UpdateTargetsOperatingSystem::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for UpdateTargetsOperatingSystem {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for UpdateTargetsOperatingSystem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for UpdateTargetsOperatingSystem {
+    fn from(value: &'a str) -> UpdateTargetsOperatingSystem {
+        match value {
+            "ubuntu" => UpdateTargetsOperatingSystem::Ubuntu,
+            "raspbian" => UpdateTargetsOperatingSystem::Raspbian,
+            "amazon_linux" => UpdateTargetsOperatingSystem::AmazonLinux,
+            "openwrt" => UpdateTargetsOperatingSystem::Openwrt,
+            _ => UpdateTargetsOperatingSystem::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for UpdateTargetsOperatingSystem {
+    fn from(value: String) -> UpdateTargetsOperatingSystem {
+        match value.as_str() {
+            "ubuntu" => UpdateTargetsOperatingSystem::Ubuntu,
+            "raspbian" => UpdateTargetsOperatingSystem::Raspbian,
+            "amazon_linux" => UpdateTargetsOperatingSystem::AmazonLinux,
+            "openwrt" => UpdateTargetsOperatingSystem::Openwrt,
+            _ => UpdateTargetsOperatingSystem::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for UpdateTargetsOperatingSystem {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<UpdateTargetsOperatingSystem, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for UpdateTargetsOperatingSystem {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for UpdateTargetsOperatingSystem {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for UpdateTargetsOperatingSystem {
+    fn deserialize<D>(deserializer: D) -> Result<UpdateTargetsOperatingSystem, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about a version.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -6948,11 +8167,12 @@ pub struct GreenGrassClient {
 impl GreenGrassClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> GreenGrassClient {
         GreenGrassClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Greengrass"),
         }
     }
 
@@ -6989,6 +8209,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("AssociateRoleToGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7022,6 +8243,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/servicerole";
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("AssociateServiceRoleToAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7053,6 +8275,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/definition/connectors";
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateConnectorDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7093,6 +8316,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateConnectorDefinitionVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7129,6 +8353,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/definition/cores";
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateCoreDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7167,6 +8392,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateCoreDefinitionVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7204,6 +8430,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7238,6 +8465,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/definition/devices";
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateDeviceDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7278,6 +8506,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateDeviceDefinitionVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7312,6 +8541,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/definition/functions";
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateFunctionDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7352,6 +8582,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateFunctionDefinitionVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7388,6 +8619,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/groups";
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7428,6 +8660,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateGroupCertificateAuthority");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         if let Some(ref amzn_client_token) = input.amzn_client_token {
@@ -7464,6 +8697,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateGroupVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7498,6 +8732,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/definition/loggers";
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateLoggerDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7538,6 +8773,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateLoggerDefinitionVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7572,6 +8808,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/definition/resources";
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateResourceDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7612,6 +8849,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateResourceDefinitionVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7648,6 +8886,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/updates";
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateSoftwareUpdateJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7683,6 +8922,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/definition/subscriptions";
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateSubscriptionDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7723,6 +8963,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateSubscriptionDefinitionVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7763,6 +9004,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation("DeleteConnectorDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7793,6 +9035,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation("DeleteCoreDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7823,6 +9066,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation("DeleteDeviceDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7853,6 +9097,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation("DeleteFunctionDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7880,6 +9125,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = format!("/greengrass/groups/{group_id}", group_id = input.group_id);
 
         let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation("DeleteGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7910,6 +9156,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation("DeleteLoggerDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7940,6 +9187,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation("DeleteResourceDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7971,6 +9219,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation("DeleteSubscriptionDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8002,6 +9251,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation("DisassociateRoleFromGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8031,6 +9281,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/servicerole";
 
         let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation("DisassociateServiceRoleFromAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8063,6 +9314,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetAssociatedRole");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8093,6 +9345,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetBulkDeploymentStatus");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8123,6 +9376,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetConnectivityInfo");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8153,6 +9407,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetConnectorDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8183,6 +9438,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = format!("/greengrass/definition/connectors/{connector_definition_id}/versions/{connector_definition_version_id}", connector_definition_id = input.connector_definition_id, connector_definition_version_id = input.connector_definition_version_id);
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetConnectorDefinitionVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8219,6 +9475,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetCoreDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8246,6 +9503,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = format!("/greengrass/definition/cores/{core_definition_id}/versions/{core_definition_version_id}", core_definition_id = input.core_definition_id, core_definition_version_id = input.core_definition_version_id);
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetCoreDefinitionVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8277,6 +9535,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetDeploymentStatus");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8307,6 +9566,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetDeviceDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8335,6 +9595,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = format!("/greengrass/definition/devices/{device_definition_id}/versions/{device_definition_version_id}", device_definition_id = input.device_definition_id, device_definition_version_id = input.device_definition_version_id);
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetDeviceDefinitionVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8371,6 +9632,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetFunctionDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8399,6 +9661,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = format!("/greengrass/definition/functions/{function_definition_id}/versions/{function_definition_version_id}", function_definition_id = input.function_definition_id, function_definition_version_id = input.function_definition_version_id);
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetFunctionDefinitionVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8432,6 +9695,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = format!("/greengrass/groups/{group_id}", group_id = input.group_id);
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8464,6 +9728,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetGroupCertificateAuthority");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8497,6 +9762,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetGroupCertificateConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8530,6 +9796,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetGroupVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8560,6 +9827,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetLoggerDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8588,6 +9856,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = format!("/greengrass/definition/loggers/{logger_definition_id}/versions/{logger_definition_version_id}", logger_definition_id = input.logger_definition_id, logger_definition_version_id = input.logger_definition_version_id);
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetLoggerDefinitionVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8624,6 +9893,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetResourceDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8652,6 +9922,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = format!("/greengrass/definition/resources/{resource_definition_id}/versions/{resource_definition_version_id}", resource_definition_id = input.resource_definition_id, resource_definition_version_id = input.resource_definition_version_id);
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetResourceDefinitionVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8678,6 +9949,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/servicerole";
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetServiceRoleForAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8709,6 +9981,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetSubscriptionDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8739,6 +10012,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = format!("/greengrass/definition/subscriptions/{subscription_definition_id}/versions/{subscription_definition_version_id}", subscription_definition_id = input.subscription_definition_id, subscription_definition_version_id = input.subscription_definition_version_id);
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetSubscriptionDefinitionVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8780,6 +10054,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListBulkDeploymentDetailedReports");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8818,6 +10093,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/bulk/deployments";
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListBulkDeployments");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8860,6 +10136,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListConnectorDefinitionVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8898,6 +10175,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/definition/connectors";
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListConnectorDefinitions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8938,6 +10216,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListCoreDefinitionVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8974,6 +10253,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/definition/cores";
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListCoreDefinitions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9013,6 +10293,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListDeployments");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9053,6 +10334,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListDeviceDefinitionVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9089,6 +10371,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/definition/devices";
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListDeviceDefinitions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9131,6 +10414,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListFunctionDefinitionVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9167,6 +10451,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/definition/functions";
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListFunctionDefinitions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9209,6 +10494,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListGroupCertificateAuthorities");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -9241,6 +10527,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListGroupVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9277,6 +10564,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/groups";
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListGroups");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9317,6 +10605,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListLoggerDefinitionVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9353,6 +10642,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/definition/loggers";
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListLoggerDefinitions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9395,6 +10685,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListResourceDefinitionVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9431,6 +10722,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/definition/resources";
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListResourceDefinitions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9473,6 +10765,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListSubscriptionDefinitionVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9512,6 +10805,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/definition/subscriptions";
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListSubscriptionDefinitions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9548,6 +10842,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -9578,6 +10873,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("ResetDeployments");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9612,6 +10908,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/bulk/deployments";
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("StartBulkDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9649,6 +10946,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("StopBulkDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -9676,6 +10974,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9705,6 +11004,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9740,6 +11040,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("UpdateConnectivityInfo");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9774,6 +11075,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("UpdateConnectorDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9807,6 +11109,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("UpdateCoreDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9840,6 +11143,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("UpdateDeviceDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9873,6 +11177,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("UpdateFunctionDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9903,6 +11208,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = format!("/greengrass/groups/{group_id}", group_id = input.group_id);
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("UpdateGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9939,6 +11245,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("UpdateGroupCertificateConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9974,6 +11281,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("UpdateLoggerDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10007,6 +11315,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("UpdateResourceDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10041,6 +11350,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("UpdateSubscriptionDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10063,3 +11373,678 @@ impl GreenGrass for GreenGrassClient {
         }
     }
 }
+impl GreenGrassClient {
+    /// Auto-paginating version of `list_bulk_deployment_detailed_reports`, streaming every page of results.
+    pub fn list_bulk_deployment_detailed_reports_pages(
+        &self,
+        input: ListBulkDeploymentDetailedReportsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListBulkDeploymentDetailedReportsResponse,
+        ListBulkDeploymentDetailedReportsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GreenGrassClient, input: ListBulkDeploymentDetailedReportsRequest| async move {
+                client.list_bulk_deployment_detailed_reports(input).await
+            },
+            |input: &ListBulkDeploymentDetailedReportsRequest,
+             output: &ListBulkDeploymentDetailedReportsResponse| {
+                Some(ListBulkDeploymentDetailedReportsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_bulk_deployment_detailed_reports`, streaming the `Deployments` of every page of results.
+    pub fn list_bulk_deployment_detailed_reports_items(
+        &self,
+        input: ListBulkDeploymentDetailedReportsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        BulkDeploymentResult,
+        ListBulkDeploymentDetailedReportsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_bulk_deployment_detailed_reports_pages(input),
+            |output: ListBulkDeploymentDetailedReportsResponse| {
+                output.deployments.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_bulk_deployments`, streaming every page of results.
+    pub fn list_bulk_deployments_pages(
+        &self,
+        input: ListBulkDeploymentsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListBulkDeploymentsResponse, ListBulkDeploymentsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GreenGrassClient, input: ListBulkDeploymentsRequest| async move {
+                client.list_bulk_deployments(input).await
+            },
+            |input: &ListBulkDeploymentsRequest, output: &ListBulkDeploymentsResponse| {
+                Some(ListBulkDeploymentsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_bulk_deployments`, streaming the `BulkDeployments` of every page of results.
+    pub fn list_bulk_deployments_items(
+        &self,
+        input: ListBulkDeploymentsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<BulkDeployment, ListBulkDeploymentsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_bulk_deployments_pages(input),
+            |output: ListBulkDeploymentsResponse| output.bulk_deployments.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_connector_definition_versions`, streaming every page of results.
+    pub fn list_connector_definition_versions_pages(
+        &self,
+        input: ListConnectorDefinitionVersionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListConnectorDefinitionVersionsResponse,
+        ListConnectorDefinitionVersionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GreenGrassClient, input: ListConnectorDefinitionVersionsRequest| async move {
+                client.list_connector_definition_versions(input).await
+            },
+            |input: &ListConnectorDefinitionVersionsRequest,
+             output: &ListConnectorDefinitionVersionsResponse| {
+                Some(ListConnectorDefinitionVersionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_connector_definition_versions`, streaming the `Versions` of every page of results.
+    pub fn list_connector_definition_versions_items(
+        &self,
+        input: ListConnectorDefinitionVersionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        VersionInformation,
+        ListConnectorDefinitionVersionsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_connector_definition_versions_pages(input),
+            |output: ListConnectorDefinitionVersionsResponse| output.versions.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_connector_definitions`, streaming every page of results.
+    pub fn list_connector_definitions_pages(
+        &self,
+        input: ListConnectorDefinitionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListConnectorDefinitionsResponse,
+        ListConnectorDefinitionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GreenGrassClient, input: ListConnectorDefinitionsRequest| async move {
+                client.list_connector_definitions(input).await
+            },
+            |input: &ListConnectorDefinitionsRequest, output: &ListConnectorDefinitionsResponse| {
+                Some(ListConnectorDefinitionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_connector_definitions`, streaming the `Definitions` of every page of results.
+    pub fn list_connector_definitions_items(
+        &self,
+        input: ListConnectorDefinitionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DefinitionInformation, ListConnectorDefinitionsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_connector_definitions_pages(input),
+            |output: ListConnectorDefinitionsResponse| output.definitions.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_core_definition_versions`, streaming every page of results.
+    pub fn list_core_definition_versions_pages(
+        &self,
+        input: ListCoreDefinitionVersionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListCoreDefinitionVersionsResponse,
+        ListCoreDefinitionVersionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GreenGrassClient, input: ListCoreDefinitionVersionsRequest| async move {
+                client.list_core_definition_versions(input).await
+            },
+            |input: &ListCoreDefinitionVersionsRequest,
+             output: &ListCoreDefinitionVersionsResponse| {
+                Some(ListCoreDefinitionVersionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_core_definition_versions`, streaming the `Versions` of every page of results.
+    pub fn list_core_definition_versions_items(
+        &self,
+        input: ListCoreDefinitionVersionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<VersionInformation, ListCoreDefinitionVersionsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_core_definition_versions_pages(input),
+            |output: ListCoreDefinitionVersionsResponse| output.versions.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_core_definitions`, streaming every page of results.
+    pub fn list_core_definitions_pages(
+        &self,
+        input: ListCoreDefinitionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListCoreDefinitionsResponse, ListCoreDefinitionsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GreenGrassClient, input: ListCoreDefinitionsRequest| async move {
+                client.list_core_definitions(input).await
+            },
+            |input: &ListCoreDefinitionsRequest, output: &ListCoreDefinitionsResponse| {
+                Some(ListCoreDefinitionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_core_definitions`, streaming the `Definitions` of every page of results.
+    pub fn list_core_definitions_items(
+        &self,
+        input: ListCoreDefinitionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DefinitionInformation, ListCoreDefinitionsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_core_definitions_pages(input),
+            |output: ListCoreDefinitionsResponse| output.definitions.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_deployments`, streaming every page of results.
+    pub fn list_deployments_pages(
+        &self,
+        input: ListDeploymentsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListDeploymentsResponse, ListDeploymentsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GreenGrassClient, input: ListDeploymentsRequest| async move {
+                client.list_deployments(input).await
+            },
+            |input: &ListDeploymentsRequest, output: &ListDeploymentsResponse| {
+                Some(ListDeploymentsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_deployments`, streaming the `Deployments` of every page of results.
+    pub fn list_deployments_items(
+        &self,
+        input: ListDeploymentsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Deployment, ListDeploymentsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_deployments_pages(input),
+            |output: ListDeploymentsResponse| output.deployments.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_device_definition_versions`, streaming every page of results.
+    pub fn list_device_definition_versions_pages(
+        &self,
+        input: ListDeviceDefinitionVersionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListDeviceDefinitionVersionsResponse,
+        ListDeviceDefinitionVersionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GreenGrassClient, input: ListDeviceDefinitionVersionsRequest| async move {
+                client.list_device_definition_versions(input).await
+            },
+            |input: &ListDeviceDefinitionVersionsRequest,
+             output: &ListDeviceDefinitionVersionsResponse| {
+                Some(ListDeviceDefinitionVersionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_device_definition_versions`, streaming the `Versions` of every page of results.
+    pub fn list_device_definition_versions_items(
+        &self,
+        input: ListDeviceDefinitionVersionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<VersionInformation, ListDeviceDefinitionVersionsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_device_definition_versions_pages(input),
+            |output: ListDeviceDefinitionVersionsResponse| output.versions.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_device_definitions`, streaming every page of results.
+    pub fn list_device_definitions_pages(
+        &self,
+        input: ListDeviceDefinitionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListDeviceDefinitionsResponse,
+        ListDeviceDefinitionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GreenGrassClient, input: ListDeviceDefinitionsRequest| async move {
+                client.list_device_definitions(input).await
+            },
+            |input: &ListDeviceDefinitionsRequest, output: &ListDeviceDefinitionsResponse| {
+                Some(ListDeviceDefinitionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_device_definitions`, streaming the `Definitions` of every page of results.
+    pub fn list_device_definitions_items(
+        &self,
+        input: ListDeviceDefinitionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DefinitionInformation, ListDeviceDefinitionsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_device_definitions_pages(input),
+            |output: ListDeviceDefinitionsResponse| output.definitions.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_function_definition_versions`, streaming every page of results.
+    pub fn list_function_definition_versions_pages(
+        &self,
+        input: ListFunctionDefinitionVersionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListFunctionDefinitionVersionsResponse,
+        ListFunctionDefinitionVersionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GreenGrassClient, input: ListFunctionDefinitionVersionsRequest| async move {
+                client.list_function_definition_versions(input).await
+            },
+            |input: &ListFunctionDefinitionVersionsRequest,
+             output: &ListFunctionDefinitionVersionsResponse| {
+                Some(ListFunctionDefinitionVersionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_function_definition_versions`, streaming the `Versions` of every page of results.
+    pub fn list_function_definition_versions_items(
+        &self,
+        input: ListFunctionDefinitionVersionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        VersionInformation,
+        ListFunctionDefinitionVersionsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_function_definition_versions_pages(input),
+            |output: ListFunctionDefinitionVersionsResponse| output.versions.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_function_definitions`, streaming every page of results.
+    pub fn list_function_definitions_pages(
+        &self,
+        input: ListFunctionDefinitionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListFunctionDefinitionsResponse,
+        ListFunctionDefinitionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GreenGrassClient, input: ListFunctionDefinitionsRequest| async move {
+                client.list_function_definitions(input).await
+            },
+            |input: &ListFunctionDefinitionsRequest, output: &ListFunctionDefinitionsResponse| {
+                Some(ListFunctionDefinitionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_function_definitions`, streaming the `Definitions` of every page of results.
+    pub fn list_function_definitions_items(
+        &self,
+        input: ListFunctionDefinitionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DefinitionInformation, ListFunctionDefinitionsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_function_definitions_pages(input),
+            |output: ListFunctionDefinitionsResponse| output.definitions.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_group_versions`, streaming every page of results.
+    pub fn list_group_versions_pages(
+        &self,
+        input: ListGroupVersionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListGroupVersionsResponse, ListGroupVersionsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GreenGrassClient, input: ListGroupVersionsRequest| async move {
+                client.list_group_versions(input).await
+            },
+            |input: &ListGroupVersionsRequest, output: &ListGroupVersionsResponse| {
+                Some(ListGroupVersionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_group_versions`, streaming the `Versions` of every page of results.
+    pub fn list_group_versions_items(
+        &self,
+        input: ListGroupVersionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<VersionInformation, ListGroupVersionsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_group_versions_pages(input),
+            |output: ListGroupVersionsResponse| output.versions.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_groups`, streaming every page of results.
+    pub fn list_groups_pages(
+        &self,
+        input: ListGroupsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListGroupsResponse, ListGroupsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GreenGrassClient, input: ListGroupsRequest| async move {
+                client.list_groups(input).await
+            },
+            |input: &ListGroupsRequest, output: &ListGroupsResponse| {
+                Some(ListGroupsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_groups`, streaming the `Groups` of every page of results.
+    pub fn list_groups_items(
+        &self,
+        input: ListGroupsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GroupInformation, ListGroupsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_groups_pages(input),
+            |output: ListGroupsResponse| output.groups.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_logger_definition_versions`, streaming every page of results.
+    pub fn list_logger_definition_versions_pages(
+        &self,
+        input: ListLoggerDefinitionVersionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListLoggerDefinitionVersionsResponse,
+        ListLoggerDefinitionVersionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GreenGrassClient, input: ListLoggerDefinitionVersionsRequest| async move {
+                client.list_logger_definition_versions(input).await
+            },
+            |input: &ListLoggerDefinitionVersionsRequest,
+             output: &ListLoggerDefinitionVersionsResponse| {
+                Some(ListLoggerDefinitionVersionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_logger_definition_versions`, streaming the `Versions` of every page of results.
+    pub fn list_logger_definition_versions_items(
+        &self,
+        input: ListLoggerDefinitionVersionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<VersionInformation, ListLoggerDefinitionVersionsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_logger_definition_versions_pages(input),
+            |output: ListLoggerDefinitionVersionsResponse| output.versions.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_logger_definitions`, streaming every page of results.
+    pub fn list_logger_definitions_pages(
+        &self,
+        input: ListLoggerDefinitionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListLoggerDefinitionsResponse,
+        ListLoggerDefinitionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GreenGrassClient, input: ListLoggerDefinitionsRequest| async move {
+                client.list_logger_definitions(input).await
+            },
+            |input: &ListLoggerDefinitionsRequest, output: &ListLoggerDefinitionsResponse| {
+                Some(ListLoggerDefinitionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_logger_definitions`, streaming the `Definitions` of every page of results.
+    pub fn list_logger_definitions_items(
+        &self,
+        input: ListLoggerDefinitionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DefinitionInformation, ListLoggerDefinitionsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_logger_definitions_pages(input),
+            |output: ListLoggerDefinitionsResponse| output.definitions.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_resource_definition_versions`, streaming every page of results.
+    pub fn list_resource_definition_versions_pages(
+        &self,
+        input: ListResourceDefinitionVersionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListResourceDefinitionVersionsResponse,
+        ListResourceDefinitionVersionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GreenGrassClient, input: ListResourceDefinitionVersionsRequest| async move {
+                client.list_resource_definition_versions(input).await
+            },
+            |input: &ListResourceDefinitionVersionsRequest,
+             output: &ListResourceDefinitionVersionsResponse| {
+                Some(ListResourceDefinitionVersionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_resource_definition_versions`, streaming the `Versions` of every page of results.
+    pub fn list_resource_definition_versions_items(
+        &self,
+        input: ListResourceDefinitionVersionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        VersionInformation,
+        ListResourceDefinitionVersionsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_resource_definition_versions_pages(input),
+            |output: ListResourceDefinitionVersionsResponse| output.versions.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_resource_definitions`, streaming every page of results.
+    pub fn list_resource_definitions_pages(
+        &self,
+        input: ListResourceDefinitionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListResourceDefinitionsResponse,
+        ListResourceDefinitionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GreenGrassClient, input: ListResourceDefinitionsRequest| async move {
+                client.list_resource_definitions(input).await
+            },
+            |input: &ListResourceDefinitionsRequest, output: &ListResourceDefinitionsResponse| {
+                Some(ListResourceDefinitionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_resource_definitions`, streaming the `Definitions` of every page of results.
+    pub fn list_resource_definitions_items(
+        &self,
+        input: ListResourceDefinitionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DefinitionInformation, ListResourceDefinitionsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_resource_definitions_pages(input),
+            |output: ListResourceDefinitionsResponse| output.definitions.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_subscription_definition_versions`, streaming every page of results.
+    pub fn list_subscription_definition_versions_pages(
+        &self,
+        input: ListSubscriptionDefinitionVersionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListSubscriptionDefinitionVersionsResponse,
+        ListSubscriptionDefinitionVersionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GreenGrassClient, input: ListSubscriptionDefinitionVersionsRequest| async move {
+                client.list_subscription_definition_versions(input).await
+            },
+            |input: &ListSubscriptionDefinitionVersionsRequest,
+             output: &ListSubscriptionDefinitionVersionsResponse| {
+                Some(ListSubscriptionDefinitionVersionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_subscription_definition_versions`, streaming the `Versions` of every page of results.
+    pub fn list_subscription_definition_versions_items(
+        &self,
+        input: ListSubscriptionDefinitionVersionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        VersionInformation,
+        ListSubscriptionDefinitionVersionsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_subscription_definition_versions_pages(input),
+            |output: ListSubscriptionDefinitionVersionsResponse| {
+                output.versions.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_subscription_definitions`, streaming every page of results.
+    pub fn list_subscription_definitions_pages(
+        &self,
+        input: ListSubscriptionDefinitionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListSubscriptionDefinitionsResponse,
+        ListSubscriptionDefinitionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GreenGrassClient, input: ListSubscriptionDefinitionsRequest| async move {
+                client.list_subscription_definitions(input).await
+            },
+            |input: &ListSubscriptionDefinitionsRequest,
+             output: &ListSubscriptionDefinitionsResponse| {
+                Some(ListSubscriptionDefinitionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_subscription_definitions`, streaming the `Definitions` of every page of results.
+    pub fn list_subscription_definitions_items(
+        &self,
+        input: ListSubscriptionDefinitionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DefinitionInformation,
+        ListSubscriptionDefinitionsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_subscription_definitions_pages(input),
+            |output: ListSubscriptionDefinitionsResponse| output.definitions.unwrap_or_default(),
+        )
+    }
+}
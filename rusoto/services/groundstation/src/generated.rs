@@ -25,6 +25,99 @@ use rusoto_core::signature::SignedRequest;
 #[allow(unused_imports)]
 use serde::{Deserialize, Serialize};
 use serde_json;
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AngleUnits {
+    /// `DEGREE_ANGLE`
+    DegreeAngle,
+    /// `RADIAN`
+    Radian,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AngleUnits {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AngleUnits::DegreeAngle => "DEGREE_ANGLE",
+            AngleUnits::Radian => "RADIAN",
+            AngleUnits::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AngleUnits {
+    fn default() -> AngleUnits {
+        AngleUnits::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AngleUnits {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AngleUnits {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AngleUnits {
+    fn from(value: &'a str) -> AngleUnits {
+        match value {
+            "DEGREE_ANGLE" => AngleUnits::DegreeAngle,
+            "RADIAN" => AngleUnits::Radian,
+            _ => AngleUnits::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AngleUnits {
+    fn from(value: String) -> AngleUnits {
+        match value.as_str() {
+            "DEGREE_ANGLE" => AngleUnits::DegreeAngle,
+            "RADIAN" => AngleUnits::Radian,
+            _ => AngleUnits::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AngleUnits {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AngleUnits, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AngleUnits {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AngleUnits {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AngleUnits {
+    fn deserialize<D>(deserializer: D) -> Result<AngleUnits, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about how AWS Ground Station should configure an
 /// antenna for downlink during a contact.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -59,6 +152,104 @@ pub struct AntennaUplinkConfig {
     pub target_eirp: Eirp,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BandwidthUnits {
+    /// `GHz`
+    GHz,
+    /// `MHz`
+    MHz,
+    /// `kHz`
+    KHz,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl BandwidthUnits {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BandwidthUnits::GHz => "GHz",
+            BandwidthUnits::MHz => "MHz",
+            BandwidthUnits::KHz => "kHz",
+            BandwidthUnits::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for BandwidthUnits {
+    fn default() -> BandwidthUnits {
+        BandwidthUnits::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for BandwidthUnits {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for BandwidthUnits {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for BandwidthUnits {
+    fn from(value: &'a str) -> BandwidthUnits {
+        match value {
+            "GHz" => BandwidthUnits::GHz,
+            "MHz" => BandwidthUnits::MHz,
+            "kHz" => BandwidthUnits::KHz,
+            _ => BandwidthUnits::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BandwidthUnits {
+    fn from(value: String) -> BandwidthUnits {
+        match value.as_str() {
+            "GHz" => BandwidthUnits::GHz,
+            "MHz" => BandwidthUnits::MHz,
+            "kHz" => BandwidthUnits::KHz,
+            _ => BandwidthUnits::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for BandwidthUnits {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<BandwidthUnits, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for BandwidthUnits {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for BandwidthUnits {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BandwidthUnits {
+    fn deserialize<D>(deserializer: D) -> Result<BandwidthUnits, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -68,6 +259,124 @@ pub struct CancelContactRequest {
     pub contact_id: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ConfigCapabilityType {
+    /// `antenna-downlink`
+    AntennaDownlink,
+    /// `antenna-downlink-demod-decode`
+    AntennaDownlinkDemodDecode,
+    /// `antenna-uplink`
+    AntennaUplink,
+    /// `dataflow-endpoint`
+    DataflowEndpoint,
+    /// `tracking`
+    Tracking,
+    /// `uplink-echo`
+    UplinkEcho,
+    /// `s3-recording`
+    S3Recording,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ConfigCapabilityType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ConfigCapabilityType::AntennaDownlink => "antenna-downlink",
+            ConfigCapabilityType::AntennaDownlinkDemodDecode => "antenna-downlink-demod-decode",
+            ConfigCapabilityType::AntennaUplink => "antenna-uplink",
+            ConfigCapabilityType::DataflowEndpoint => "dataflow-endpoint",
+            ConfigCapabilityType::Tracking => "tracking",
+            ConfigCapabilityType::UplinkEcho => "uplink-echo",
+            ConfigCapabilityType::S3Recording => "s3-recording",
+            ConfigCapabilityType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ConfigCapabilityType {
+    fn default() -> ConfigCapabilityType {
+        ConfigCapabilityType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ConfigCapabilityType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ConfigCapabilityType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ConfigCapabilityType {
+    fn from(value: &'a str) -> ConfigCapabilityType {
+        match value {
+            "antenna-downlink" => ConfigCapabilityType::AntennaDownlink,
+            "antenna-downlink-demod-decode" => ConfigCapabilityType::AntennaDownlinkDemodDecode,
+            "antenna-uplink" => ConfigCapabilityType::AntennaUplink,
+            "dataflow-endpoint" => ConfigCapabilityType::DataflowEndpoint,
+            "tracking" => ConfigCapabilityType::Tracking,
+            "uplink-echo" => ConfigCapabilityType::UplinkEcho,
+            "s3-recording" => ConfigCapabilityType::S3Recording,
+            _ => ConfigCapabilityType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ConfigCapabilityType {
+    fn from(value: String) -> ConfigCapabilityType {
+        match value.as_str() {
+            "antenna-downlink" => ConfigCapabilityType::AntennaDownlink,
+            "antenna-downlink-demod-decode" => ConfigCapabilityType::AntennaDownlinkDemodDecode,
+            "antenna-uplink" => ConfigCapabilityType::AntennaUplink,
+            "dataflow-endpoint" => ConfigCapabilityType::DataflowEndpoint,
+            "tracking" => ConfigCapabilityType::Tracking,
+            "uplink-echo" => ConfigCapabilityType::UplinkEcho,
+            "s3-recording" => ConfigCapabilityType::S3Recording,
+            _ => ConfigCapabilityType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ConfigCapabilityType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ConfigCapabilityType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ConfigCapabilityType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ConfigCapabilityType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ConfigCapabilityType {
+    fn deserialize<D>(deserializer: D) -> Result<ConfigCapabilityType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -83,7 +392,7 @@ pub struct ConfigIdResponse {
     /// <p>Type of a <code>Config</code>.</p>
     #[serde(rename = "configType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub config_type: Option<String>,
+    pub config_type: Option<ConfigCapabilityType>,
 }
 
 /// <p>An item in a list of <code>Config</code> objects.</p>
@@ -101,7 +410,7 @@ pub struct ConfigListItem {
     /// <p>Type of a <code>Config</code>.</p>
     #[serde(rename = "configType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub config_type: Option<String>,
+    pub config_type: Option<ConfigCapabilityType>,
     /// <p>Name of a <code>Config</code>.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -154,11 +463,16 @@ pub struct ContactData {
     /// <p>Status of a contact.</p>
     #[serde(rename = "contactStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub contact_status: Option<String>,
+    pub contact_status: Option<ContactStatus>,
     /// <p>End time of a contact.</p>
     #[serde(rename = "endTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Error message of a contact.</p>
     #[serde(rename = "errorMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -177,20 +491,35 @@ pub struct ContactData {
     pub mission_profile_arn: Option<String>,
     /// <p>Amount of time after a contact ends that you’d like to receive a CloudWatch event indicating the pass has finished.</p>
     #[serde(rename = "postPassEndTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub post_pass_end_time: Option<f64>,
+    pub post_pass_end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Amount of time prior to contact start you’d like to receive a CloudWatch event indicating an upcoming pass.</p>
     #[serde(rename = "prePassStartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub pre_pass_start_time: Option<f64>,
+    pub pre_pass_start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>ARN of a satellite.</p>
     #[serde(rename = "satelliteArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub satellite_arn: Option<String>,
     /// <p>Start time of a contact.</p>
     #[serde(rename = "startTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Tags assigned to a contact.</p>
     #[serde(rename = "tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -207,6 +536,154 @@ pub struct ContactIdResponse {
     pub contact_id: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ContactStatus {
+    /// `AVAILABLE`
+    Available,
+    /// `AWS_CANCELLED`
+    AwsCancelled,
+    /// `AWS_FAILED`
+    AwsFailed,
+    /// `CANCELLED`
+    Cancelled,
+    /// `CANCELLING`
+    Cancelling,
+    /// `COMPLETED`
+    Completed,
+    /// `FAILED`
+    Failed,
+    /// `FAILED_TO_SCHEDULE`
+    FailedToSchedule,
+    /// `PASS`
+    Pass,
+    /// `POSTPASS`
+    Postpass,
+    /// `PREPASS`
+    Prepass,
+    /// `SCHEDULED`
+    Scheduled,
+    /// `SCHEDULING`
+    Scheduling,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ContactStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ContactStatus::Available => "AVAILABLE",
+            ContactStatus::AwsCancelled => "AWS_CANCELLED",
+            ContactStatus::AwsFailed => "AWS_FAILED",
+            ContactStatus::Cancelled => "CANCELLED",
+            ContactStatus::Cancelling => "CANCELLING",
+            ContactStatus::Completed => "COMPLETED",
+            ContactStatus::Failed => "FAILED",
+            ContactStatus::FailedToSchedule => "FAILED_TO_SCHEDULE",
+            ContactStatus::Pass => "PASS",
+            ContactStatus::Postpass => "POSTPASS",
+            ContactStatus::Prepass => "PREPASS",
+            ContactStatus::Scheduled => "SCHEDULED",
+            ContactStatus::Scheduling => "SCHEDULING",
+            ContactStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ContactStatus {
+    fn default() -> ContactStatus {
+        ContactStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ContactStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ContactStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ContactStatus {
+    fn from(value: &'a str) -> ContactStatus {
+        match value {
+            "AVAILABLE" => ContactStatus::Available,
+            "AWS_CANCELLED" => ContactStatus::AwsCancelled,
+            "AWS_FAILED" => ContactStatus::AwsFailed,
+            "CANCELLED" => ContactStatus::Cancelled,
+            "CANCELLING" => ContactStatus::Cancelling,
+            "COMPLETED" => ContactStatus::Completed,
+            "FAILED" => ContactStatus::Failed,
+            "FAILED_TO_SCHEDULE" => ContactStatus::FailedToSchedule,
+            "PASS" => ContactStatus::Pass,
+            "POSTPASS" => ContactStatus::Postpass,
+            "PREPASS" => ContactStatus::Prepass,
+            "SCHEDULED" => ContactStatus::Scheduled,
+            "SCHEDULING" => ContactStatus::Scheduling,
+            _ => ContactStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ContactStatus {
+    fn from(value: String) -> ContactStatus {
+        match value.as_str() {
+            "AVAILABLE" => ContactStatus::Available,
+            "AWS_CANCELLED" => ContactStatus::AwsCancelled,
+            "AWS_FAILED" => ContactStatus::AwsFailed,
+            "CANCELLED" => ContactStatus::Cancelled,
+            "CANCELLING" => ContactStatus::Cancelling,
+            "COMPLETED" => ContactStatus::Completed,
+            "FAILED" => ContactStatus::Failed,
+            "FAILED_TO_SCHEDULE" => ContactStatus::FailedToSchedule,
+            "PASS" => ContactStatus::Pass,
+            "POSTPASS" => ContactStatus::Postpass,
+            "PREPASS" => ContactStatus::Prepass,
+            "SCHEDULED" => ContactStatus::Scheduled,
+            "SCHEDULING" => ContactStatus::Scheduling,
+            _ => ContactStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ContactStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ContactStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ContactStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ContactStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ContactStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ContactStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -267,6 +744,104 @@ pub struct CreateMissionProfileRequest {
     pub tracking_config_arn: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Criticality {
+    /// `PREFERRED`
+    Preferred,
+    /// `REMOVED`
+    Removed,
+    /// `REQUIRED`
+    Required,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Criticality {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Criticality::Preferred => "PREFERRED",
+            Criticality::Removed => "REMOVED",
+            Criticality::Required => "REQUIRED",
+            Criticality::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Criticality {
+    fn default() -> Criticality {
+        Criticality::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Criticality {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Criticality {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Criticality {
+    fn from(value: &'a str) -> Criticality {
+        match value {
+            "PREFERRED" => Criticality::Preferred,
+            "REMOVED" => Criticality::Removed,
+            "REQUIRED" => Criticality::Required,
+            _ => Criticality::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Criticality {
+    fn from(value: String) -> Criticality {
+        match value.as_str() {
+            "PREFERRED" => Criticality::Preferred,
+            "REMOVED" => Criticality::Removed,
+            "REQUIRED" => Criticality::Required,
+            _ => Criticality::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Criticality {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Criticality, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Criticality {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Criticality {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Criticality {
+    fn deserialize<D>(deserializer: D) -> Result<Criticality, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about a dataflow endpoint.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DataflowEndpoint {
@@ -281,7 +856,7 @@ pub struct DataflowEndpoint {
     /// <p>Status of a dataflow endpoint.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<EndpointStatus>,
 }
 
 /// <p>Information about the dataflow endpoint <code>Config</code>.</p>
@@ -333,7 +908,7 @@ pub struct DeleteConfigRequest {
     pub config_id: String,
     /// <p>Type of a <code>Config</code>.</p>
     #[serde(rename = "configType")]
-    pub config_type: String,
+    pub config_type: ConfigCapabilityType,
 }
 
 /// <p><p/></p>
@@ -382,11 +957,16 @@ pub struct DescribeContactResponse {
     /// <p>Status of a contact.</p>
     #[serde(rename = "contactStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub contact_status: Option<String>,
+    pub contact_status: Option<ContactStatus>,
     /// <p>End time of a contact.</p>
     #[serde(rename = "endTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Error message for a contact.</p>
     #[serde(rename = "errorMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -405,20 +985,35 @@ pub struct DescribeContactResponse {
     pub mission_profile_arn: Option<String>,
     /// <p>Amount of time after a contact ends that you’d like to receive a CloudWatch event indicating the pass has finished.</p>
     #[serde(rename = "postPassEndTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub post_pass_end_time: Option<f64>,
+    pub post_pass_end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Amount of time prior to contact start you’d like to receive a CloudWatch event indicating an upcoming pass.</p>
     #[serde(rename = "prePassStartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub pre_pass_start_time: Option<f64>,
+    pub pre_pass_start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>ARN of a satellite.</p>
     #[serde(rename = "satelliteArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub satellite_arn: Option<String>,
     /// <p>Start time of a contact.</p>
     #[serde(rename = "startTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Tags assigned to a contact.</p>
     #[serde(rename = "tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -430,19 +1025,107 @@ pub struct DescribeContactResponse {
 pub struct Eirp {
     /// <p>Units of an EIRP.</p>
     #[serde(rename = "units")]
-    pub units: String,
+    pub units: EirpUnits,
     /// <p>Value of an EIRP.</p>
     #[serde(rename = "value")]
     pub value: f64,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EirpUnits {
+    /// `dBW`
+    DBW,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EirpUnits {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EirpUnits::DBW => "dBW",
+            EirpUnits::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EirpUnits {
+    fn default() -> EirpUnits {
+        EirpUnits::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EirpUnits {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EirpUnits {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EirpUnits {
+    fn from(value: &'a str) -> EirpUnits {
+        match value {
+            "dBW" => EirpUnits::DBW,
+            _ => EirpUnits::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EirpUnits {
+    fn from(value: String) -> EirpUnits {
+        match value.as_str() {
+            "dBW" => EirpUnits::DBW,
+            _ => EirpUnits::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EirpUnits {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EirpUnits, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EirpUnits {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EirpUnits {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EirpUnits {
+    fn deserialize<D>(deserializer: D) -> Result<EirpUnits, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Elevation angle of the satellite in the sky during a contact.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Elevation {
     /// <p>Elevation angle units.</p>
     #[serde(rename = "unit")]
-    pub unit: String,
+    pub unit: AngleUnits,
     /// <p>Elevation angle value.</p>
     #[serde(rename = "value")]
     pub value: f64,
@@ -461,12 +1144,120 @@ pub struct EndpointDetails {
     pub security_details: Option<SecurityDetails>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EndpointStatus {
+    /// `created`
+    Created,
+    /// `creating`
+    Creating,
+    /// `deleted`
+    Deleted,
+    /// `deleting`
+    Deleting,
+    /// `failed`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EndpointStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EndpointStatus::Created => "created",
+            EndpointStatus::Creating => "creating",
+            EndpointStatus::Deleted => "deleted",
+            EndpointStatus::Deleting => "deleting",
+            EndpointStatus::Failed => "failed",
+            EndpointStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EndpointStatus {
+    fn default() -> EndpointStatus {
+        EndpointStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EndpointStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EndpointStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EndpointStatus {
+    fn from(value: &'a str) -> EndpointStatus {
+        match value {
+            "created" => EndpointStatus::Created,
+            "creating" => EndpointStatus::Creating,
+            "deleted" => EndpointStatus::Deleted,
+            "deleting" => EndpointStatus::Deleting,
+            "failed" => EndpointStatus::Failed,
+            _ => EndpointStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EndpointStatus {
+    fn from(value: String) -> EndpointStatus {
+        match value.as_str() {
+            "created" => EndpointStatus::Created,
+            "creating" => EndpointStatus::Creating,
+            "deleted" => EndpointStatus::Deleted,
+            "deleting" => EndpointStatus::Deleting,
+            "failed" => EndpointStatus::Failed,
+            _ => EndpointStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EndpointStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EndpointStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EndpointStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EndpointStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EndpointStatus {
+    fn deserialize<D>(deserializer: D) -> Result<EndpointStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Object that describes the frequency.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Frequency {
     /// <p>Frequency units.</p>
     #[serde(rename = "units")]
-    pub units: String,
+    pub units: FrequencyUnits,
     /// <p>Frequency value.</p>
     #[serde(rename = "value")]
     pub value: f64,
@@ -477,12 +1268,110 @@ pub struct Frequency {
 pub struct FrequencyBandwidth {
     /// <p>Frequency bandwidth units.</p>
     #[serde(rename = "units")]
-    pub units: String,
+    pub units: BandwidthUnits,
     /// <p>Frequency bandwidth value.</p>
     #[serde(rename = "value")]
     pub value: f64,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FrequencyUnits {
+    /// `GHz`
+    GHz,
+    /// `MHz`
+    MHz,
+    /// `kHz`
+    KHz,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl FrequencyUnits {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FrequencyUnits::GHz => "GHz",
+            FrequencyUnits::MHz => "MHz",
+            FrequencyUnits::KHz => "kHz",
+            FrequencyUnits::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for FrequencyUnits {
+    fn default() -> FrequencyUnits {
+        FrequencyUnits::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for FrequencyUnits {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FrequencyUnits {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FrequencyUnits {
+    fn from(value: &'a str) -> FrequencyUnits {
+        match value {
+            "GHz" => FrequencyUnits::GHz,
+            "MHz" => FrequencyUnits::MHz,
+            "kHz" => FrequencyUnits::KHz,
+            _ => FrequencyUnits::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FrequencyUnits {
+    fn from(value: String) -> FrequencyUnits {
+        match value.as_str() {
+            "GHz" => FrequencyUnits::GHz,
+            "MHz" => FrequencyUnits::MHz,
+            "kHz" => FrequencyUnits::KHz,
+            _ => FrequencyUnits::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for FrequencyUnits {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<FrequencyUnits, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FrequencyUnits {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FrequencyUnits {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FrequencyUnits {
+    fn deserialize<D>(deserializer: D) -> Result<FrequencyUnits, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -492,7 +1381,7 @@ pub struct GetConfigRequest {
     pub config_id: String,
     /// <p>Type of a <code>Config</code>.</p>
     #[serde(rename = "configType")]
-    pub config_type: String,
+    pub config_type: ConfigCapabilityType,
 }
 
 /// <p><p/></p>
@@ -512,7 +1401,7 @@ pub struct GetConfigResponse {
     /// <p>Type of a <code>Config</code>.</p>
     #[serde(rename = "configType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub config_type: Option<String>,
+    pub config_type: Option<ConfigCapabilityType>,
     /// <p>Name of a <code>Config</code>.</p>
     #[serde(rename = "name")]
     pub name: String,
@@ -662,12 +1551,22 @@ pub struct GetSatelliteRequest {
 pub struct GetSatelliteResponse {
     /// <p>When a satellite was created.</p>
     #[serde(rename = "dateCreated")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub date_created: Option<f64>,
+    pub date_created: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>When a satellite was last updated.</p>
     #[serde(rename = "lastUpdated")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated: Option<f64>,
+    pub last_updated: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>NORAD satellite ID number.</p>
     #[serde(rename = "noradSatelliteID")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -738,7 +1637,12 @@ pub struct ListConfigsResponse {
 pub struct ListContactsRequest {
     /// <p>End time of a contact.</p>
     #[serde(rename = "endTime")]
-    pub end_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub end_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>Name of a ground station.</p>
     #[serde(rename = "groundStation")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -761,10 +1665,15 @@ pub struct ListContactsRequest {
     pub satellite_arn: Option<String>,
     /// <p>Start time of a contact.</p>
     #[serde(rename = "startTime")]
-    pub start_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub start_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>Status of a contact reservation.</p>
     #[serde(rename = "statusList")]
-    pub status_list: Vec<String>,
+    pub status_list: Vec<ContactStatus>,
 }
 
 /// <p><p/></p>
@@ -944,13 +1853,116 @@ pub struct MissionProfileListItem {
     pub region: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Polarization {
+    /// `LEFT_HAND`
+    LeftHand,
+    /// `NONE`
+    None,
+    /// `RIGHT_HAND`
+    RightHand,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Polarization {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Polarization::LeftHand => "LEFT_HAND",
+            Polarization::None => "NONE",
+            Polarization::RightHand => "RIGHT_HAND",
+            Polarization::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Polarization {
+    fn default() -> Polarization {
+        Polarization::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Polarization {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Polarization {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Polarization {
+    fn from(value: &'a str) -> Polarization {
+        match value {
+            "LEFT_HAND" => Polarization::LeftHand,
+            "NONE" => Polarization::None,
+            "RIGHT_HAND" => Polarization::RightHand,
+            _ => Polarization::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Polarization {
+    fn from(value: String) -> Polarization {
+        match value.as_str() {
+            "LEFT_HAND" => Polarization::LeftHand,
+            "NONE" => Polarization::None,
+            "RIGHT_HAND" => Polarization::RightHand,
+            _ => Polarization::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Polarization {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Polarization, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Polarization {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Polarization {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Polarization {
+    fn deserialize<D>(deserializer: D) -> Result<Polarization, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ReserveContactRequest {
     /// <p>End time of a contact.</p>
     #[serde(rename = "endTime")]
-    pub end_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub end_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>Name of a ground station.</p>
     #[serde(rename = "groundStation")]
     pub ground_station: String,
@@ -962,7 +1974,12 @@ pub struct ReserveContactRequest {
     pub satellite_arn: String,
     /// <p>Start time of a contact.</p>
     #[serde(rename = "startTime")]
-    pub start_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub start_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>Tags assigned to a contact.</p>
     #[serde(rename = "tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1024,7 +2041,7 @@ pub struct SpectrumConfig {
     /// <p>Polarization of a spectral <code>Config</code>.</p>
     #[serde(rename = "polarization")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub polarization: Option<String>,
+    pub polarization: Option<Polarization>,
 }
 
 /// <p><p/></p>
@@ -1051,7 +2068,7 @@ pub struct TagResourceResponse {}
 pub struct TrackingConfig {
     /// <p>Current setting for autotrack.</p>
     #[serde(rename = "autotrack")]
-    pub autotrack: String,
+    pub autotrack: Criticality,
 }
 
 /// <p><p/></p>
@@ -1083,7 +2100,7 @@ pub struct UpdateConfigRequest {
     pub config_id: String,
     /// <p>Type of a <code>Config</code>.</p>
     #[serde(rename = "configType")]
-    pub config_type: String,
+    pub config_type: ConfigCapabilityType,
     /// <p>Name of a <code>Config</code>.</p>
     #[serde(rename = "name")]
     pub name: String,
@@ -1148,7 +2165,7 @@ pub struct UplinkSpectrumConfig {
     /// <p>Polarization of an uplink spectral <code>Config</code>.</p>
     #[serde(rename = "polarization")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub polarization: Option<String>,
+    pub polarization: Option<Polarization>,
 }
 
 /// Errors returned by CancelContact
@@ -2442,11 +3459,12 @@ pub struct GroundStationClient {
 impl GroundStationClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> GroundStationClient {
         GroundStationClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "GroundStation"),
         }
     }
 
@@ -2480,6 +3498,7 @@ impl GroundStation for GroundStationClient {
         let request_uri = format!("/contact/{contact_id}", contact_id = input.contact_id);
 
         let mut request = SignedRequest::new("DELETE", "groundstation", &self.region, &request_uri);
+        request.set_operation("CancelContact");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2510,6 +3529,7 @@ impl GroundStation for GroundStationClient {
         let request_uri = "/config";
 
         let mut request = SignedRequest::new("POST", "groundstation", &self.region, &request_uri);
+        request.set_operation("CreateConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2547,6 +3567,7 @@ impl GroundStation for GroundStationClient {
         let request_uri = "/dataflowEndpointGroup";
 
         let mut request = SignedRequest::new("POST", "groundstation", &self.region, &request_uri);
+        request.set_operation("CreateDataflowEndpointGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2582,6 +3603,7 @@ impl GroundStation for GroundStationClient {
         let request_uri = "/missionprofile";
 
         let mut request = SignedRequest::new("POST", "groundstation", &self.region, &request_uri);
+        request.set_operation("CreateMissionProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2616,6 +3638,7 @@ impl GroundStation for GroundStationClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "groundstation", &self.region, &request_uri);
+        request.set_operation("DeleteConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2647,6 +3670,7 @@ impl GroundStation for GroundStationClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "groundstation", &self.region, &request_uri);
+        request.set_operation("DeleteDataflowEndpointGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2677,6 +3701,7 @@ impl GroundStation for GroundStationClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "groundstation", &self.region, &request_uri);
+        request.set_operation("DeleteMissionProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2704,6 +3729,7 @@ impl GroundStation for GroundStationClient {
         let request_uri = format!("/contact/{contact_id}", contact_id = input.contact_id);
 
         let mut request = SignedRequest::new("GET", "groundstation", &self.region, &request_uri);
+        request.set_operation("DescribeContact");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2738,6 +3764,7 @@ impl GroundStation for GroundStationClient {
         );
 
         let mut request = SignedRequest::new("GET", "groundstation", &self.region, &request_uri);
+        request.set_operation("GetConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2768,6 +3795,7 @@ impl GroundStation for GroundStationClient {
         );
 
         let mut request = SignedRequest::new("GET", "groundstation", &self.region, &request_uri);
+        request.set_operation("GetDataflowEndpointGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2795,6 +3823,7 @@ impl GroundStation for GroundStationClient {
         let request_uri = "/minute-usage";
 
         let mut request = SignedRequest::new("POST", "groundstation", &self.region, &request_uri);
+        request.set_operation("GetMinuteUsage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2828,6 +3857,7 @@ impl GroundStation for GroundStationClient {
         );
 
         let mut request = SignedRequest::new("GET", "groundstation", &self.region, &request_uri);
+        request.set_operation("GetMissionProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2858,6 +3888,7 @@ impl GroundStation for GroundStationClient {
         );
 
         let mut request = SignedRequest::new("GET", "groundstation", &self.region, &request_uri);
+        request.set_operation("GetSatellite");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2885,6 +3916,7 @@ impl GroundStation for GroundStationClient {
         let request_uri = "/config";
 
         let mut request = SignedRequest::new("GET", "groundstation", &self.region, &request_uri);
+        request.set_operation("ListConfigs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2926,6 +3958,7 @@ impl GroundStation for GroundStationClient {
         let request_uri = "/contacts";
 
         let mut request = SignedRequest::new("POST", "groundstation", &self.region, &request_uri);
+        request.set_operation("ListContacts");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2957,6 +3990,7 @@ impl GroundStation for GroundStationClient {
         let request_uri = "/dataflowEndpointGroup";
 
         let mut request = SignedRequest::new("GET", "groundstation", &self.region, &request_uri);
+        request.set_operation("ListDataflowEndpointGroups");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2993,6 +4027,7 @@ impl GroundStation for GroundStationClient {
         let request_uri = "/groundstation";
 
         let mut request = SignedRequest::new("GET", "groundstation", &self.region, &request_uri);
+        request.set_operation("ListGroundStations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3029,6 +4064,7 @@ impl GroundStation for GroundStationClient {
         let request_uri = "/missionprofile";
 
         let mut request = SignedRequest::new("GET", "groundstation", &self.region, &request_uri);
+        request.set_operation("ListMissionProfiles");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3065,6 +4101,7 @@ impl GroundStation for GroundStationClient {
         let request_uri = "/satellite";
 
         let mut request = SignedRequest::new("GET", "groundstation", &self.region, &request_uri);
+        request.set_operation("ListSatellites");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3101,6 +4138,7 @@ impl GroundStation for GroundStationClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "groundstation", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3128,6 +4166,7 @@ impl GroundStation for GroundStationClient {
         let request_uri = "/contact";
 
         let mut request = SignedRequest::new("POST", "groundstation", &self.region, &request_uri);
+        request.set_operation("ReserveContact");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3158,6 +4197,7 @@ impl GroundStation for GroundStationClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "groundstation", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3188,6 +4228,7 @@ impl GroundStation for GroundStationClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "groundstation", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3229,6 +4270,7 @@ impl GroundStation for GroundStationClient {
         );
 
         let mut request = SignedRequest::new("PUT", "groundstation", &self.region, &request_uri);
+        request.set_operation("UpdateConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3266,6 +4308,7 @@ impl GroundStation for GroundStationClient {
         );
 
         let mut request = SignedRequest::new("PUT", "groundstation", &self.region, &request_uri);
+        request.set_operation("UpdateMissionProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3288,3 +4331,248 @@ impl GroundStation for GroundStationClient {
         }
     }
 }
+impl GroundStationClient {
+    /// Auto-paginating version of `list_configs`, streaming every page of results.
+    pub fn list_configs_pages(
+        &self,
+        input: ListConfigsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListConfigsResponse, ListConfigsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GroundStationClient, input: ListConfigsRequest| async move {
+                client.list_configs(input).await
+            },
+            |input: &ListConfigsRequest, output: &ListConfigsResponse| {
+                Some(ListConfigsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_configs`, streaming the `configList` of every page of results.
+    pub fn list_configs_items(
+        &self,
+        input: ListConfigsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ConfigListItem, ListConfigsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_configs_pages(input),
+            |output: ListConfigsResponse| output.config_list.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_contacts`, streaming every page of results.
+    pub fn list_contacts_pages(
+        &self,
+        input: ListContactsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListContactsResponse, ListContactsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GroundStationClient, input: ListContactsRequest| async move {
+                client.list_contacts(input).await
+            },
+            |input: &ListContactsRequest, output: &ListContactsResponse| {
+                Some(ListContactsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_contacts`, streaming the `contactList` of every page of results.
+    pub fn list_contacts_items(
+        &self,
+        input: ListContactsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ContactData, ListContactsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_contacts_pages(input),
+            |output: ListContactsResponse| output.contact_list.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_dataflow_endpoint_groups`, streaming every page of results.
+    pub fn list_dataflow_endpoint_groups_pages(
+        &self,
+        input: ListDataflowEndpointGroupsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListDataflowEndpointGroupsResponse,
+        ListDataflowEndpointGroupsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GroundStationClient, input: ListDataflowEndpointGroupsRequest| async move {
+                client.list_dataflow_endpoint_groups(input).await
+            },
+            |input: &ListDataflowEndpointGroupsRequest,
+             output: &ListDataflowEndpointGroupsResponse| {
+                Some(ListDataflowEndpointGroupsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_dataflow_endpoint_groups`, streaming the `dataflowEndpointGroupList` of every page of results.
+    pub fn list_dataflow_endpoint_groups_items(
+        &self,
+        input: ListDataflowEndpointGroupsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DataflowEndpointListItem,
+        ListDataflowEndpointGroupsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_dataflow_endpoint_groups_pages(input),
+            |output: ListDataflowEndpointGroupsResponse| {
+                output.dataflow_endpoint_group_list.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_ground_stations`, streaming every page of results.
+    pub fn list_ground_stations_pages(
+        &self,
+        input: ListGroundStationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListGroundStationsResponse, ListGroundStationsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GroundStationClient, input: ListGroundStationsRequest| async move {
+                client.list_ground_stations(input).await
+            },
+            |input: &ListGroundStationsRequest, output: &ListGroundStationsResponse| {
+                Some(ListGroundStationsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_ground_stations`, streaming the `groundStationList` of every page of results.
+    pub fn list_ground_stations_items(
+        &self,
+        input: ListGroundStationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GroundStationData, ListGroundStationsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_ground_stations_pages(input),
+            |output: ListGroundStationsResponse| output.ground_station_list.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_mission_profiles`, streaming every page of results.
+    pub fn list_mission_profiles_pages(
+        &self,
+        input: ListMissionProfilesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListMissionProfilesResponse, ListMissionProfilesError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GroundStationClient, input: ListMissionProfilesRequest| async move {
+                client.list_mission_profiles(input).await
+            },
+            |input: &ListMissionProfilesRequest, output: &ListMissionProfilesResponse| {
+                Some(ListMissionProfilesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_mission_profiles`, streaming the `missionProfileList` of every page of results.
+    pub fn list_mission_profiles_items(
+        &self,
+        input: ListMissionProfilesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<MissionProfileListItem, ListMissionProfilesError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_mission_profiles_pages(input),
+            |output: ListMissionProfilesResponse| output.mission_profile_list.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_satellites`, streaming every page of results.
+    pub fn list_satellites_pages(
+        &self,
+        input: ListSatellitesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListSatellitesResponse, ListSatellitesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GroundStationClient, input: ListSatellitesRequest| async move {
+                client.list_satellites(input).await
+            },
+            |input: &ListSatellitesRequest, output: &ListSatellitesResponse| {
+                Some(ListSatellitesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_satellites`, streaming the `satellites` of every page of results.
+    pub fn list_satellites_items(
+        &self,
+        input: ListSatellitesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<SatelliteListItem, ListSatellitesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_satellites_pages(input),
+            |output: ListSatellitesResponse| output.satellites.unwrap_or_default(),
+        )
+    }
+}
+impl GroundStationClient {
+    /// Waits until `describe_contact` reports the `ContactScheduled` state, polling every 5 seconds up to 180 times.
+    pub async fn wait_until_contact_scheduled(
+        &self,
+        input: DescribeContactRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeContactError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(5), 180);
+        self.wait_until_contact_scheduled_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_contact_scheduled`, polling as often and as many times as `config` says.
+    pub async fn wait_until_contact_scheduled_with_config(
+        &self,
+        input: DescribeContactRequest,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeContactError>> {
+        rusoto_core::waiter::wait(
+            "ContactScheduled",
+            &config,
+            || self.describe_contact(input.clone()),
+            |result: &Result<DescribeContactResponse, RusotoError<DescribeContactError>>| {
+                match result {
+                    Ok(output)
+                        if output
+                            .contact_status
+                            .iter()
+                            .any(|v| *v == ContactStatus::FailedToSchedule) =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    Ok(output)
+                        if output
+                            .contact_status
+                            .iter()
+                            .any(|v| *v == ContactStatus::Scheduled) =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Success)
+                    }
+                    _ => None,
+                }
+            },
+        )
+        .await
+    }
+}
@@ -210,7 +210,7 @@ pub struct CreateDetectorRequest {
     /// <p>A enum value that specifies how frequently customer got Finding updates published.</p>
     #[serde(rename = "FindingPublishingFrequency")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub finding_publishing_frequency: Option<String>,
+    pub finding_publishing_frequency: Option<FindingPublishingFrequency>,
     /// <p>The tags to be added to a new detector resource.</p>
     #[serde(rename = "Tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -232,7 +232,7 @@ pub struct CreateFilterRequest {
     /// <p>Specifies the action that is to be applied to the findings that match the filter.</p>
     #[serde(rename = "Action")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub action: Option<String>,
+    pub action: Option<FilterAction>,
     /// <p>The idempotency token for the create request.</p>
     #[serde(rename = "ClientToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -283,7 +283,7 @@ pub struct CreateIPSetRequest {
     pub detector_id: String,
     /// <p>The format of the file that contains the IPSet.</p>
     #[serde(rename = "Format")]
-    pub format: String,
+    pub format: IpSetFormat,
     /// <p>The URI of the file that contains the IPSet. For example (https://s3.us-west-2.amazonaws.com/my-bucket/my-object-key)</p>
     #[serde(rename = "Location")]
     pub location: String,
@@ -335,7 +335,7 @@ pub struct CreatePublishingDestinationRequest {
     pub destination_properties: DestinationProperties,
     /// <p>The type of resource for the publishing destination. Currently only S3 is supported.</p>
     #[serde(rename = "DestinationType")]
-    pub destination_type: String,
+    pub destination_type: DestinationType,
     /// <p>The ID of the GuardDuty detector associated with the publishing destination.</p>
     #[serde(rename = "DetectorId")]
     pub detector_id: String,
@@ -380,7 +380,7 @@ pub struct CreateThreatIntelSetRequest {
     pub detector_id: String,
     /// <p>The format of the file that contains the ThreatIntelSet.</p>
     #[serde(rename = "Format")]
-    pub format: String,
+    pub format: ThreatIntelSetFormat,
     /// <p>The URI of the file that contains the ThreatIntelSet. For example (https://s3.us-west-2.amazonaws.com/my-bucket/my-object-key).</p>
     #[serde(rename = "Location")]
     pub location: String,
@@ -546,13 +546,13 @@ pub struct DescribePublishingDestinationResponse {
     pub destination_properties: DestinationProperties,
     /// <p>The type of the publishing destination. Currently, only S3 is supported.</p>
     #[serde(rename = "DestinationType")]
-    pub destination_type: String,
+    pub destination_type: DestinationType,
     /// <p>The time, in epoch millisecond format, at which GuardDuty was first unable to publish findings to the destination.</p>
     #[serde(rename = "PublishingFailureStartTimestamp")]
     pub publishing_failure_start_timestamp: i64,
     /// <p>The status of the publishing destination.</p>
     #[serde(rename = "Status")]
-    pub status: String,
+    pub status: PublishingStatus,
 }
 
 /// <p>Contains information about a publishing destination, including the ID, type, and status.</p>
@@ -564,10 +564,10 @@ pub struct Destination {
     pub destination_id: String,
     /// <p>The type of resource used for the publishing destination. Currently, only S3 is supported.</p>
     #[serde(rename = "DestinationType")]
-    pub destination_type: String,
+    pub destination_type: DestinationType,
     /// <p>The status of the publishing destination.</p>
     #[serde(rename = "Status")]
-    pub status: String,
+    pub status: PublishingStatus,
 }
 
 /// <p>Contains the ARN of the resource to publish to, such as an S3 bucket, and the ARN of the KMS key to use to encrypt published findings.</p>
@@ -583,6 +583,187 @@ pub struct DestinationProperties {
     pub kms_key_arn: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DestinationType {
+    /// `S3`
+    S3,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DestinationType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DestinationType::S3 => "S3",
+            DestinationType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DestinationType {
+    fn default() -> DestinationType {
+        DestinationType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DestinationType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DestinationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DestinationType {
+    fn from(value: &'a str) -> DestinationType {
+        match value {
+            "S3" => DestinationType::S3,
+            _ => DestinationType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DestinationType {
+    fn from(value: String) -> DestinationType {
+        match value.as_str() {
+            "S3" => DestinationType::S3,
+            _ => DestinationType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DestinationType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DestinationType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DestinationType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DestinationType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DestinationType {
+    fn deserialize<D>(deserializer: D) -> Result<DestinationType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DetectorStatus {
+    /// `ENABLED`
+    Enabled,
+    /// `DISABLED`
+    Disabled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DetectorStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DetectorStatus::Enabled => "ENABLED",
+            DetectorStatus::Disabled => "DISABLED",
+            DetectorStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DetectorStatus {
+    fn default() -> DetectorStatus {
+        DetectorStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DetectorStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DetectorStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DetectorStatus {
+    fn from(value: &'a str) -> DetectorStatus {
+        match value {
+            "ENABLED" => DetectorStatus::Enabled,
+            "DISABLED" => DetectorStatus::Disabled,
+            _ => DetectorStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DetectorStatus {
+    fn from(value: String) -> DetectorStatus {
+        match value.as_str() {
+            "ENABLED" => DetectorStatus::Enabled,
+            "DISABLED" => DetectorStatus::Disabled,
+            _ => DetectorStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DetectorStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DetectorStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DetectorStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DetectorStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DetectorStatus {
+    fn deserialize<D>(deserializer: D) -> Result<DetectorStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct DisassociateFromMasterAccountRequest {
@@ -644,6 +825,192 @@ pub struct Evidence {
     pub threat_intelligence_details: Option<Vec<ThreatIntelligenceDetail>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Feedback {
+    /// `USEFUL`
+    Useful,
+    /// `NOT_USEFUL`
+    NotUseful,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Feedback {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Feedback::Useful => "USEFUL",
+            Feedback::NotUseful => "NOT_USEFUL",
+            Feedback::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Feedback {
+    fn default() -> Feedback {
+        Feedback::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Feedback {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Feedback {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Feedback {
+    fn from(value: &'a str) -> Feedback {
+        match value {
+            "USEFUL" => Feedback::Useful,
+            "NOT_USEFUL" => Feedback::NotUseful,
+            _ => Feedback::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Feedback {
+    fn from(value: String) -> Feedback {
+        match value.as_str() {
+            "USEFUL" => Feedback::Useful,
+            "NOT_USEFUL" => Feedback::NotUseful,
+            _ => Feedback::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Feedback {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Feedback, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Feedback {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Feedback {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Feedback {
+    fn deserialize<D>(deserializer: D) -> Result<Feedback, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FilterAction {
+    /// `NOOP`
+    Noop,
+    /// `ARCHIVE`
+    Archive,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl FilterAction {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FilterAction::Noop => "NOOP",
+            FilterAction::Archive => "ARCHIVE",
+            FilterAction::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for FilterAction {
+    fn default() -> FilterAction {
+        FilterAction::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for FilterAction {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FilterAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FilterAction {
+    fn from(value: &'a str) -> FilterAction {
+        match value {
+            "NOOP" => FilterAction::Noop,
+            "ARCHIVE" => FilterAction::Archive,
+            _ => FilterAction::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FilterAction {
+    fn from(value: String) -> FilterAction {
+        match value.as_str() {
+            "NOOP" => FilterAction::Noop,
+            "ARCHIVE" => FilterAction::Archive,
+            _ => FilterAction::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for FilterAction {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<FilterAction, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FilterAction {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FilterAction {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FilterAction {
+    fn deserialize<D>(deserializer: D) -> Result<FilterAction, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Contains information about the finding, which is generated when abnormal or suspicious activity is detected.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -707,6 +1074,192 @@ pub struct FindingCriteria {
     pub criterion: Option<::std::collections::HashMap<String, Condition>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FindingPublishingFrequency {
+    /// `FIFTEEN_MINUTES`
+    FifteenMinutes,
+    /// `ONE_HOUR`
+    OneHour,
+    /// `SIX_HOURS`
+    SixHours,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl FindingPublishingFrequency {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FindingPublishingFrequency::FifteenMinutes => "FIFTEEN_MINUTES",
+            FindingPublishingFrequency::OneHour => "ONE_HOUR",
+            FindingPublishingFrequency::SixHours => "SIX_HOURS",
+            FindingPublishingFrequency::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for FindingPublishingFrequency {
+    fn default() -> FindingPublishingFrequency {
+        FindingPublishingFrequency::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for FindingPublishingFrequency {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FindingPublishingFrequency {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FindingPublishingFrequency {
+    fn from(value: &'a str) -> FindingPublishingFrequency {
+        match value {
+            "FIFTEEN_MINUTES" => FindingPublishingFrequency::FifteenMinutes,
+            "ONE_HOUR" => FindingPublishingFrequency::OneHour,
+            "SIX_HOURS" => FindingPublishingFrequency::SixHours,
+            _ => FindingPublishingFrequency::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FindingPublishingFrequency {
+    fn from(value: String) -> FindingPublishingFrequency {
+        match value.as_str() {
+            "FIFTEEN_MINUTES" => FindingPublishingFrequency::FifteenMinutes,
+            "ONE_HOUR" => FindingPublishingFrequency::OneHour,
+            "SIX_HOURS" => FindingPublishingFrequency::SixHours,
+            _ => FindingPublishingFrequency::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for FindingPublishingFrequency {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<FindingPublishingFrequency, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FindingPublishingFrequency {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FindingPublishingFrequency {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FindingPublishingFrequency {
+    fn deserialize<D>(deserializer: D) -> Result<FindingPublishingFrequency, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FindingStatisticType {
+    /// `COUNT_BY_SEVERITY`
+    CountBySeverity,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl FindingStatisticType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FindingStatisticType::CountBySeverity => "COUNT_BY_SEVERITY",
+            FindingStatisticType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for FindingStatisticType {
+    fn default() -> FindingStatisticType {
+        FindingStatisticType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for FindingStatisticType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FindingStatisticType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FindingStatisticType {
+    fn from(value: &'a str) -> FindingStatisticType {
+        match value {
+            "COUNT_BY_SEVERITY" => FindingStatisticType::CountBySeverity,
+            _ => FindingStatisticType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FindingStatisticType {
+    fn from(value: String) -> FindingStatisticType {
+        match value.as_str() {
+            "COUNT_BY_SEVERITY" => FindingStatisticType::CountBySeverity,
+            _ => FindingStatisticType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for FindingStatisticType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<FindingStatisticType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FindingStatisticType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FindingStatisticType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FindingStatisticType {
+    fn deserialize<D>(deserializer: D) -> Result<FindingStatisticType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Contains information about finding statistics.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -749,13 +1302,13 @@ pub struct GetDetectorResponse {
     /// <p>Finding publishing frequency.</p>
     #[serde(rename = "FindingPublishingFrequency")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub finding_publishing_frequency: Option<String>,
+    pub finding_publishing_frequency: Option<FindingPublishingFrequency>,
     /// <p>The GuardDuty service role.</p>
     #[serde(rename = "ServiceRole")]
     pub service_role: String,
     /// <p>The detector status.</p>
     #[serde(rename = "Status")]
-    pub status: String,
+    pub status: DetectorStatus,
     /// <p>The tags of the detector resource.</p>
     #[serde(rename = "Tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -782,7 +1335,7 @@ pub struct GetFilterRequest {
 pub struct GetFilterResponse {
     /// <p>Specifies the action that is to be applied to the findings that match the filter.</p>
     #[serde(rename = "Action")]
-    pub action: String,
+    pub action: FilterAction,
     /// <p>The description of the filter.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -838,7 +1391,7 @@ pub struct GetFindingsStatisticsRequest {
     pub finding_criteria: Option<FindingCriteria>,
     /// <p>Types of finding statistics to retrieve.</p>
     #[serde(rename = "FindingStatisticTypes")]
-    pub finding_statistic_types: Vec<String>,
+    pub finding_statistic_types: Vec<FindingStatisticType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -865,7 +1418,7 @@ pub struct GetIPSetRequest {
 pub struct GetIPSetResponse {
     /// <p>The format of the file that contains the IPSet.</p>
     #[serde(rename = "Format")]
-    pub format: String,
+    pub format: IpSetFormat,
     /// <p>The URI of the file that contains the IPSet. For example (https://s3.us-west-2.amazonaws.com/my-bucket/my-object-key)</p>
     #[serde(rename = "Location")]
     pub location: String,
@@ -874,7 +1427,7 @@ pub struct GetIPSetResponse {
     pub name: String,
     /// <p>The status of ipSet file uploaded.</p>
     #[serde(rename = "Status")]
-    pub status: String,
+    pub status: IpSetStatus,
     /// <p>The tags of the IP set resource.</p>
     #[serde(rename = "Tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -948,7 +1501,7 @@ pub struct GetThreatIntelSetRequest {
 pub struct GetThreatIntelSetResponse {
     /// <p>The format of the threatIntelSet.</p>
     #[serde(rename = "Format")]
-    pub format: String,
+    pub format: ThreatIntelSetFormat,
     /// <p>The URI of the file that contains the ThreatIntelSet. For example (https://s3.us-west-2.amazonaws.com/my-bucket/my-object-key).</p>
     #[serde(rename = "Location")]
     pub location: String,
@@ -957,7 +1510,7 @@ pub struct GetThreatIntelSetResponse {
     pub name: String,
     /// <p>The status of threatIntelSet file uploaded.</p>
     #[serde(rename = "Status")]
-    pub status: String,
+    pub status: ThreatIntelSetStatus,
     /// <p>The tags of the Threat List resource.</p>
     #[serde(rename = "Tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1032,55 +1585,286 @@ pub struct InstanceDetails {
     pub tags: Option<Vec<Tag>>,
 }
 
-/// <p>Contains information about the invitation to become a member account.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct Invitation {
-    /// <p>The ID of the account from which the invitations was sent.</p>
-    #[serde(rename = "AccountId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub account_id: Option<String>,
-    /// <p>The ID of the invitation. This value is used to validate the inviter account to the member account.</p>
-    #[serde(rename = "InvitationId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub invitation_id: Option<String>,
-    /// <p>Timestamp at which the invitation was sent.</p>
-    #[serde(rename = "InvitedAt")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub invited_at: Option<String>,
-    /// <p>The status of the relationship between the inviter and invitee accounts.</p>
-    #[serde(rename = "RelationshipStatus")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub relationship_status: Option<String>,
+/// <p>Contains information about the invitation to become a member account.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct Invitation {
+    /// <p>The ID of the account from which the invitations was sent.</p>
+    #[serde(rename = "AccountId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_id: Option<String>,
+    /// <p>The ID of the invitation. This value is used to validate the inviter account to the member account.</p>
+    #[serde(rename = "InvitationId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invitation_id: Option<String>,
+    /// <p>Timestamp at which the invitation was sent.</p>
+    #[serde(rename = "InvitedAt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invited_at: Option<String>,
+    /// <p>The status of the relationship between the inviter and invitee accounts.</p>
+    #[serde(rename = "RelationshipStatus")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relationship_status: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct InviteMembersRequest {
+    /// <p>A list of account IDs of the accounts that you want to invite to GuardDuty as members.</p>
+    #[serde(rename = "AccountIds")]
+    pub account_ids: Vec<String>,
+    /// <p>The unique ID of the detector of the GuardDuty account with which you want to invite members.</p>
+    #[serde(rename = "DetectorId")]
+    pub detector_id: String,
+    /// <p>A boolean value that specifies whether you want to disable email notification to the accounts that you’re inviting to GuardDuty as members.</p>
+    #[serde(rename = "DisableEmailNotification")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_email_notification: Option<bool>,
+    /// <p>The invitation message that you want to send to the accounts that you’re inviting to GuardDuty as members.</p>
+    #[serde(rename = "Message")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct InviteMembersResponse {
+    /// <p>A list of objects containing the unprocessed account and a result string explaining why it was unprocessed.</p>
+    #[serde(rename = "UnprocessedAccounts")]
+    pub unprocessed_accounts: Vec<UnprocessedAccount>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum IpSetFormat {
+    /// `TXT`
+    Txt,
+    /// `STIX`
+    Stix,
+    /// `OTX_CSV`
+    OtxCsv,
+    /// `ALIEN_VAULT`
+    AlienVault,
+    /// `PROOF_POINT`
+    ProofPoint,
+    /// `FIRE_EYE`
+    FireEye,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl IpSetFormat {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            IpSetFormat::Txt => "TXT",
+            IpSetFormat::Stix => "STIX",
+            IpSetFormat::OtxCsv => "OTX_CSV",
+            IpSetFormat::AlienVault => "ALIEN_VAULT",
+            IpSetFormat::ProofPoint => "PROOF_POINT",
+            IpSetFormat::FireEye => "FIRE_EYE",
+            IpSetFormat::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for IpSetFormat {
+    fn default() -> IpSetFormat {
+        IpSetFormat::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for IpSetFormat {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for IpSetFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for IpSetFormat {
+    fn from(value: &'a str) -> IpSetFormat {
+        match value {
+            "TXT" => IpSetFormat::Txt,
+            "STIX" => IpSetFormat::Stix,
+            "OTX_CSV" => IpSetFormat::OtxCsv,
+            "ALIEN_VAULT" => IpSetFormat::AlienVault,
+            "PROOF_POINT" => IpSetFormat::ProofPoint,
+            "FIRE_EYE" => IpSetFormat::FireEye,
+            _ => IpSetFormat::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for IpSetFormat {
+    fn from(value: String) -> IpSetFormat {
+        match value.as_str() {
+            "TXT" => IpSetFormat::Txt,
+            "STIX" => IpSetFormat::Stix,
+            "OTX_CSV" => IpSetFormat::OtxCsv,
+            "ALIEN_VAULT" => IpSetFormat::AlienVault,
+            "PROOF_POINT" => IpSetFormat::ProofPoint,
+            "FIRE_EYE" => IpSetFormat::FireEye,
+            _ => IpSetFormat::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for IpSetFormat {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<IpSetFormat, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for IpSetFormat {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for IpSetFormat {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for IpSetFormat {
+    fn deserialize<D>(deserializer: D) -> Result<IpSetFormat, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum IpSetStatus {
+    /// `INACTIVE`
+    Inactive,
+    /// `ACTIVATING`
+    Activating,
+    /// `ACTIVE`
+    Active,
+    /// `DEACTIVATING`
+    Deactivating,
+    /// `ERROR`
+    Error,
+    /// `DELETE_PENDING`
+    DeletePending,
+    /// `DELETED`
+    Deleted,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl IpSetStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            IpSetStatus::Inactive => "INACTIVE",
+            IpSetStatus::Activating => "ACTIVATING",
+            IpSetStatus::Active => "ACTIVE",
+            IpSetStatus::Deactivating => "DEACTIVATING",
+            IpSetStatus::Error => "ERROR",
+            IpSetStatus::DeletePending => "DELETE_PENDING",
+            IpSetStatus::Deleted => "DELETED",
+            IpSetStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for IpSetStatus {
+    fn default() -> IpSetStatus {
+        IpSetStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for IpSetStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for IpSetStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for IpSetStatus {
+    fn from(value: &'a str) -> IpSetStatus {
+        match value {
+            "INACTIVE" => IpSetStatus::Inactive,
+            "ACTIVATING" => IpSetStatus::Activating,
+            "ACTIVE" => IpSetStatus::Active,
+            "DEACTIVATING" => IpSetStatus::Deactivating,
+            "ERROR" => IpSetStatus::Error,
+            "DELETE_PENDING" => IpSetStatus::DeletePending,
+            "DELETED" => IpSetStatus::Deleted,
+            _ => IpSetStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for IpSetStatus {
+    fn from(value: String) -> IpSetStatus {
+        match value.as_str() {
+            "INACTIVE" => IpSetStatus::Inactive,
+            "ACTIVATING" => IpSetStatus::Activating,
+            "ACTIVE" => IpSetStatus::Active,
+            "DEACTIVATING" => IpSetStatus::Deactivating,
+            "ERROR" => IpSetStatus::Error,
+            "DELETE_PENDING" => IpSetStatus::DeletePending,
+            "DELETED" => IpSetStatus::Deleted,
+            _ => IpSetStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for IpSetStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<IpSetStatus, Self::Err> {
+        Ok(value.into())
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct InviteMembersRequest {
-    /// <p>A list of account IDs of the accounts that you want to invite to GuardDuty as members.</p>
-    #[serde(rename = "AccountIds")]
-    pub account_ids: Vec<String>,
-    /// <p>The unique ID of the detector of the GuardDuty account with which you want to invite members.</p>
-    #[serde(rename = "DetectorId")]
-    pub detector_id: String,
-    /// <p>A boolean value that specifies whether you want to disable email notification to the accounts that you’re inviting to GuardDuty as members.</p>
-    #[serde(rename = "DisableEmailNotification")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub disable_email_notification: Option<bool>,
-    /// <p>The invitation message that you want to send to the accounts that you’re inviting to GuardDuty as members.</p>
-    #[serde(rename = "Message")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub message: Option<String>,
+impl ::rusoto_core::param::ToParam for IpSetStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct InviteMembersResponse {
-    /// <p>A list of objects containing the unprocessed account and a result string explaining why it was unprocessed.</p>
-    #[serde(rename = "UnprocessedAccounts")]
-    pub unprocessed_accounts: Vec<UnprocessedAccount>,
+impl ::serde::Serialize for IpSetStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for IpSetStatus {
+    fn deserialize<D>(deserializer: D) -> Result<IpSetStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ListDetectorsRequest {
@@ -1471,6 +2255,99 @@ pub struct NetworkInterface {
     pub vpc_id: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OrderBy {
+    /// `ASC`
+    Asc,
+    /// `DESC`
+    Desc,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl OrderBy {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OrderBy::Asc => "ASC",
+            OrderBy::Desc => "DESC",
+            OrderBy::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for OrderBy {
+    fn default() -> OrderBy {
+        OrderBy::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for OrderBy {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for OrderBy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for OrderBy {
+    fn from(value: &'a str) -> OrderBy {
+        match value {
+            "ASC" => OrderBy::Asc,
+            "DESC" => OrderBy::Desc,
+            _ => OrderBy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OrderBy {
+    fn from(value: String) -> OrderBy {
+        match value.as_str() {
+            "ASC" => OrderBy::Asc,
+            "DESC" => OrderBy::Desc,
+            _ => OrderBy::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for OrderBy {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<OrderBy, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OrderBy {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for OrderBy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OrderBy {
+    fn deserialize<D>(deserializer: D) -> Result<OrderBy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Continas information about the ISP organization of the remote IP address.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1549,6 +2426,115 @@ pub struct ProductCode {
     pub product_type: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PublishingStatus {
+    /// `PENDING_VERIFICATION`
+    PendingVerification,
+    /// `PUBLISHING`
+    Publishing,
+    /// `UNABLE_TO_PUBLISH_FIX_DESTINATION_PROPERTY`
+    UnableToPublishFixDestinationProperty,
+    /// `STOPPED`
+    Stopped,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PublishingStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PublishingStatus::PendingVerification => "PENDING_VERIFICATION",
+            PublishingStatus::Publishing => "PUBLISHING",
+            PublishingStatus::UnableToPublishFixDestinationProperty => {
+                "UNABLE_TO_PUBLISH_FIX_DESTINATION_PROPERTY"
+            }
+            PublishingStatus::Stopped => "STOPPED",
+            PublishingStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PublishingStatus {
+    fn default() -> PublishingStatus {
+        PublishingStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PublishingStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PublishingStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PublishingStatus {
+    fn from(value: &'a str) -> PublishingStatus {
+        match value {
+            "PENDING_VERIFICATION" => PublishingStatus::PendingVerification,
+            "PUBLISHING" => PublishingStatus::Publishing,
+            "UNABLE_TO_PUBLISH_FIX_DESTINATION_PROPERTY" => {
+                PublishingStatus::UnableToPublishFixDestinationProperty
+            }
+            "STOPPED" => PublishingStatus::Stopped,
+            _ => PublishingStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PublishingStatus {
+    fn from(value: String) -> PublishingStatus {
+        match value.as_str() {
+            "PENDING_VERIFICATION" => PublishingStatus::PendingVerification,
+            "PUBLISHING" => PublishingStatus::Publishing,
+            "UNABLE_TO_PUBLISH_FIX_DESTINATION_PROPERTY" => {
+                PublishingStatus::UnableToPublishFixDestinationProperty
+            }
+            "STOPPED" => PublishingStatus::Stopped,
+            _ => PublishingStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PublishingStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PublishingStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PublishingStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PublishingStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PublishingStatus {
+    fn deserialize<D>(deserializer: D) -> Result<PublishingStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Continas information about the remote IP address of the connection.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1678,7 +2664,7 @@ pub struct SortCriteria {
     /// <p>Order by which the sorted findings are to be displayed.</p>
     #[serde(rename = "OrderBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub order_by: Option<String>,
+    pub order_by: Option<OrderBy>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -1748,6 +2734,237 @@ pub struct TagResourceRequest {
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TagResourceResponse {}
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ThreatIntelSetFormat {
+    /// `TXT`
+    Txt,
+    /// `STIX`
+    Stix,
+    /// `OTX_CSV`
+    OtxCsv,
+    /// `ALIEN_VAULT`
+    AlienVault,
+    /// `PROOF_POINT`
+    ProofPoint,
+    /// `FIRE_EYE`
+    FireEye,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ThreatIntelSetFormat {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ThreatIntelSetFormat::Txt => "TXT",
+            ThreatIntelSetFormat::Stix => "STIX",
+            ThreatIntelSetFormat::OtxCsv => "OTX_CSV",
+            ThreatIntelSetFormat::AlienVault => "ALIEN_VAULT",
+            ThreatIntelSetFormat::ProofPoint => "PROOF_POINT",
+            ThreatIntelSetFormat::FireEye => "FIRE_EYE",
+            ThreatIntelSetFormat::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ThreatIntelSetFormat {
+    fn default() -> ThreatIntelSetFormat {
+        ThreatIntelSetFormat::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ThreatIntelSetFormat {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ThreatIntelSetFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ThreatIntelSetFormat {
+    fn from(value: &'a str) -> ThreatIntelSetFormat {
+        match value {
+            "TXT" => ThreatIntelSetFormat::Txt,
+            "STIX" => ThreatIntelSetFormat::Stix,
+            "OTX_CSV" => ThreatIntelSetFormat::OtxCsv,
+            "ALIEN_VAULT" => ThreatIntelSetFormat::AlienVault,
+            "PROOF_POINT" => ThreatIntelSetFormat::ProofPoint,
+            "FIRE_EYE" => ThreatIntelSetFormat::FireEye,
+            _ => ThreatIntelSetFormat::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ThreatIntelSetFormat {
+    fn from(value: String) -> ThreatIntelSetFormat {
+        match value.as_str() {
+            "TXT" => ThreatIntelSetFormat::Txt,
+            "STIX" => ThreatIntelSetFormat::Stix,
+            "OTX_CSV" => ThreatIntelSetFormat::OtxCsv,
+            "ALIEN_VAULT" => ThreatIntelSetFormat::AlienVault,
+            "PROOF_POINT" => ThreatIntelSetFormat::ProofPoint,
+            "FIRE_EYE" => ThreatIntelSetFormat::FireEye,
+            _ => ThreatIntelSetFormat::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ThreatIntelSetFormat {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ThreatIntelSetFormat, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ThreatIntelSetFormat {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ThreatIntelSetFormat {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ThreatIntelSetFormat {
+    fn deserialize<D>(deserializer: D) -> Result<ThreatIntelSetFormat, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ThreatIntelSetStatus {
+    /// `INACTIVE`
+    Inactive,
+    /// `ACTIVATING`
+    Activating,
+    /// `ACTIVE`
+    Active,
+    /// `DEACTIVATING`
+    Deactivating,
+    /// `ERROR`
+    Error,
+    /// `DELETE_PENDING`
+    DeletePending,
+    /// `DELETED`
+    Deleted,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ThreatIntelSetStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ThreatIntelSetStatus::Inactive => "INACTIVE",
+            ThreatIntelSetStatus::Activating => "ACTIVATING",
+            ThreatIntelSetStatus::Active => "ACTIVE",
+            ThreatIntelSetStatus::Deactivating => "DEACTIVATING",
+            ThreatIntelSetStatus::Error => "ERROR",
+            ThreatIntelSetStatus::DeletePending => "DELETE_PENDING",
+            ThreatIntelSetStatus::Deleted => "DELETED",
+            ThreatIntelSetStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ThreatIntelSetStatus {
+    fn default() -> ThreatIntelSetStatus {
+        ThreatIntelSetStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ThreatIntelSetStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ThreatIntelSetStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ThreatIntelSetStatus {
+    fn from(value: &'a str) -> ThreatIntelSetStatus {
+        match value {
+            "INACTIVE" => ThreatIntelSetStatus::Inactive,
+            "ACTIVATING" => ThreatIntelSetStatus::Activating,
+            "ACTIVE" => ThreatIntelSetStatus::Active,
+            "DEACTIVATING" => ThreatIntelSetStatus::Deactivating,
+            "ERROR" => ThreatIntelSetStatus::Error,
+            "DELETE_PENDING" => ThreatIntelSetStatus::DeletePending,
+            "DELETED" => ThreatIntelSetStatus::Deleted,
+            _ => ThreatIntelSetStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ThreatIntelSetStatus {
+    fn from(value: String) -> ThreatIntelSetStatus {
+        match value.as_str() {
+            "INACTIVE" => ThreatIntelSetStatus::Inactive,
+            "ACTIVATING" => ThreatIntelSetStatus::Activating,
+            "ACTIVE" => ThreatIntelSetStatus::Active,
+            "DEACTIVATING" => ThreatIntelSetStatus::Deactivating,
+            "ERROR" => ThreatIntelSetStatus::Error,
+            "DELETE_PENDING" => ThreatIntelSetStatus::DeletePending,
+            "DELETED" => ThreatIntelSetStatus::Deleted,
+            _ => ThreatIntelSetStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ThreatIntelSetStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ThreatIntelSetStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ThreatIntelSetStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ThreatIntelSetStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ThreatIntelSetStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ThreatIntelSetStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An instance of a threat intelligence detail that constitutes evidence for the finding.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1817,7 +3034,7 @@ pub struct UpdateDetectorRequest {
     /// <p>A enum value that specifies how frequently findings are exported, such as to CloudWatch Events.</p>
     #[serde(rename = "FindingPublishingFrequency")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub finding_publishing_frequency: Option<String>,
+    pub finding_publishing_frequency: Option<FindingPublishingFrequency>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1830,7 +3047,7 @@ pub struct UpdateFilterRequest {
     /// <p>Specifies the action that is to be applied to the findings that match the filter.</p>
     #[serde(rename = "Action")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub action: Option<String>,
+    pub action: Option<FilterAction>,
     /// <p>The description of the filter.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1871,7 +3088,7 @@ pub struct UpdateFindingsFeedbackRequest {
     pub detector_id: String,
     /// <p>The feedback for the finding.</p>
     #[serde(rename = "Feedback")]
-    pub feedback: String,
+    pub feedback: Feedback,
     /// <p>IDs of the findings that you want to mark as useful or not useful.</p>
     #[serde(rename = "FindingIds")]
     pub finding_ids: Vec<String>,
@@ -4156,11 +5373,12 @@ pub struct GuardDutyClient {
 impl GuardDutyClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> GuardDutyClient {
         GuardDutyClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "GuardDuty"),
         }
     }
 
@@ -4197,6 +5415,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("AcceptInvitation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4230,6 +5449,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("ArchiveFindings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4260,6 +5480,7 @@ impl GuardDuty for GuardDutyClient {
         let request_uri = "/detector";
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("CreateDetector");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4293,6 +5514,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("CreateFilter");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4326,6 +5548,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("CreateIPSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4359,6 +5582,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("CreateMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4393,6 +5617,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("CreatePublishingDestination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4426,6 +5651,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("CreateSampleFindings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4459,6 +5685,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("CreateThreatIntelSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4489,6 +5716,7 @@ impl GuardDuty for GuardDutyClient {
         let request_uri = "/invitation/decline";
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("DeclineInvitations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4519,6 +5747,7 @@ impl GuardDuty for GuardDutyClient {
         let request_uri = format!("/detector/{detector_id}", detector_id = input.detector_id);
 
         let mut request = SignedRequest::new("DELETE", "guardduty", &self.region, &request_uri);
+        request.set_operation("DeleteDetector");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4550,6 +5779,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "guardduty", &self.region, &request_uri);
+        request.set_operation("DeleteFilter");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4581,6 +5811,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "guardduty", &self.region, &request_uri);
+        request.set_operation("DeleteIPSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4608,6 +5839,7 @@ impl GuardDuty for GuardDutyClient {
         let request_uri = "/invitation/delete";
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("DeleteInvitations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4641,6 +5873,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("DeleteMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4676,6 +5909,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "guardduty", &self.region, &request_uri);
+        request.set_operation("DeletePublishingDestination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4707,6 +5941,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "guardduty", &self.region, &request_uri);
+        request.set_operation("DeleteThreatIntelSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4741,6 +5976,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("DescribePublishingDestination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4774,6 +6010,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("DisassociateFromMasterAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4804,6 +6041,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("DisassociateMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4834,6 +6072,7 @@ impl GuardDuty for GuardDutyClient {
         let request_uri = format!("/detector/{detector_id}", detector_id = input.detector_id);
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("GetDetector");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4865,6 +6104,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("GetFilter");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4895,6 +6135,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("GetFindings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4928,6 +6169,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("GetFindingsStatistics");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4962,6 +6204,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("GetIPSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4988,6 +6231,7 @@ impl GuardDuty for GuardDutyClient {
         let request_uri = "/invitation/count";
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("GetInvitationsCount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5018,6 +6262,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("GetMasterAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5048,6 +6293,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("GetMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5082,6 +6328,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("GetThreatIntelSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5112,6 +6359,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("InviteMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5142,6 +6390,7 @@ impl GuardDuty for GuardDutyClient {
         let request_uri = "/detector";
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("ListDetectors");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5181,6 +6430,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("ListFilters");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5220,6 +6470,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("ListFindings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5253,6 +6504,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("ListIPSets");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5289,6 +6541,7 @@ impl GuardDuty for GuardDutyClient {
         let request_uri = "/invitation";
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("ListInvitations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5328,6 +6581,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("ListMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5371,6 +6625,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("ListPublishingDestinations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5407,6 +6662,7 @@ impl GuardDuty for GuardDutyClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5437,6 +6693,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("ListThreatIntelSets");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5476,6 +6733,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("StartMonitoringMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5509,6 +6767,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("StopMonitoringMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5539,6 +6798,7 @@ impl GuardDuty for GuardDutyClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5572,6 +6832,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("UnarchiveFindings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5602,6 +6863,7 @@ impl GuardDuty for GuardDutyClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "guardduty", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5635,6 +6897,7 @@ impl GuardDuty for GuardDutyClient {
         let request_uri = format!("/detector/{detector_id}", detector_id = input.detector_id);
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("UpdateDetector");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5669,6 +6932,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("UpdateFilter");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5702,6 +6966,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("UpdateFindingsFeedback");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5736,6 +7001,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("UpdateIPSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5771,6 +7037,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("UpdatePublishingDestination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5805,6 +7072,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("UpdateThreatIntelSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5827,3 +7095,222 @@ impl GuardDuty for GuardDutyClient {
         }
     }
 }
+impl GuardDutyClient {
+    /// Auto-paginating version of `list_detectors`, streaming every page of results.
+    pub fn list_detectors_pages(
+        &self,
+        input: ListDetectorsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListDetectorsResponse, ListDetectorsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GuardDutyClient, input: ListDetectorsRequest| async move {
+                client.list_detectors(input).await
+            },
+            |input: &ListDetectorsRequest, output: &ListDetectorsResponse| {
+                Some(ListDetectorsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_detectors`, streaming the `DetectorIds` of every page of results.
+    pub fn list_detectors_items(
+        &self,
+        input: ListDetectorsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListDetectorsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_detectors_pages(input),
+            |output: ListDetectorsResponse| output.detector_ids,
+        )
+    }
+
+    /// Auto-paginating version of `list_filters`, streaming every page of results.
+    pub fn list_filters_pages(
+        &self,
+        input: ListFiltersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListFiltersResponse, ListFiltersError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GuardDutyClient, input: ListFiltersRequest| async move {
+                client.list_filters(input).await
+            },
+            |input: &ListFiltersRequest, output: &ListFiltersResponse| {
+                Some(ListFiltersRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_filters`, streaming the `FilterNames` of every page of results.
+    pub fn list_filters_items(
+        &self,
+        input: ListFiltersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListFiltersError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_filters_pages(input),
+            |output: ListFiltersResponse| output.filter_names,
+        )
+    }
+
+    /// Auto-paginating version of `list_findings`, streaming every page of results.
+    pub fn list_findings_pages(
+        &self,
+        input: ListFindingsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListFindingsResponse, ListFindingsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GuardDutyClient, input: ListFindingsRequest| async move {
+                client.list_findings(input).await
+            },
+            |input: &ListFindingsRequest, output: &ListFindingsResponse| {
+                Some(ListFindingsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_findings`, streaming the `FindingIds` of every page of results.
+    pub fn list_findings_items(
+        &self,
+        input: ListFindingsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListFindingsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_findings_pages(input),
+            |output: ListFindingsResponse| output.finding_ids,
+        )
+    }
+
+    /// Auto-paginating version of `list_ip_sets`, streaming every page of results.
+    pub fn list_ip_sets_pages(
+        &self,
+        input: ListIPSetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListIPSetsResponse, ListIPSetsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GuardDutyClient, input: ListIPSetsRequest| async move {
+                client.list_ip_sets(input).await
+            },
+            |input: &ListIPSetsRequest, output: &ListIPSetsResponse| {
+                Some(ListIPSetsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_ip_sets`, streaming the `IpSetIds` of every page of results.
+    pub fn list_ip_sets_items(
+        &self,
+        input: ListIPSetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListIPSetsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_ip_sets_pages(input),
+            |output: ListIPSetsResponse| output.ip_set_ids,
+        )
+    }
+
+    /// Auto-paginating version of `list_invitations`, streaming every page of results.
+    pub fn list_invitations_pages(
+        &self,
+        input: ListInvitationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListInvitationsResponse, ListInvitationsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GuardDutyClient, input: ListInvitationsRequest| async move {
+                client.list_invitations(input).await
+            },
+            |input: &ListInvitationsRequest, output: &ListInvitationsResponse| {
+                Some(ListInvitationsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_invitations`, streaming the `Invitations` of every page of results.
+    pub fn list_invitations_items(
+        &self,
+        input: ListInvitationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Invitation, ListInvitationsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_invitations_pages(input),
+            |output: ListInvitationsResponse| output.invitations.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_members`, streaming every page of results.
+    pub fn list_members_pages(
+        &self,
+        input: ListMembersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListMembersResponse, ListMembersError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GuardDutyClient, input: ListMembersRequest| async move {
+                client.list_members(input).await
+            },
+            |input: &ListMembersRequest, output: &ListMembersResponse| {
+                Some(ListMembersRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_members`, streaming the `Members` of every page of results.
+    pub fn list_members_items(
+        &self,
+        input: ListMembersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Member, ListMembersError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_members_pages(input),
+            |output: ListMembersResponse| output.members.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_threat_intel_sets`, streaming every page of results.
+    pub fn list_threat_intel_sets_pages(
+        &self,
+        input: ListThreatIntelSetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListThreatIntelSetsResponse, ListThreatIntelSetsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GuardDutyClient, input: ListThreatIntelSetsRequest| async move {
+                client.list_threat_intel_sets(input).await
+            },
+            |input: &ListThreatIntelSetsRequest, output: &ListThreatIntelSetsResponse| {
+                Some(ListThreatIntelSetsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_threat_intel_sets`, streaming the `ThreatIntelSetIds` of every page of results.
+    pub fn list_threat_intel_sets_items(
+        &self,
+        input: ListThreatIntelSetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListThreatIntelSetsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_threat_intel_sets_pages(input),
+            |output: ListThreatIntelSetsResponse| output.threat_intel_set_ids,
+        )
+    }
+}
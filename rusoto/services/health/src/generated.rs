@@ -50,12 +50,17 @@ pub struct AffectedEntity {
     pub event_arn: Option<String>,
     /// <p>The most recent time that the entity was updated.</p>
     #[serde(rename = "lastUpdatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_time: Option<f64>,
+    pub last_updated_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The most recent status of the entity affected by the event. The possible values are <code>IMPAIRED</code>, <code>UNIMPAIRED</code>, and <code>UNKNOWN</code>.</p>
     #[serde(rename = "statusCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status_code: Option<String>,
+    pub status_code: Option<EntityStatusCode>,
     /// <p>A map of entity tags attached to the affected entity.</p>
     #[serde(rename = "tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -68,12 +73,22 @@ pub struct AffectedEntity {
 pub struct DateTimeRange {
     /// <p>The starting date and time of a time range.</p>
     #[serde(rename = "from")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub from: Option<f64>,
+    pub from: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The ending date and time of a time range.</p>
     #[serde(rename = "to")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub to: Option<f64>,
+    pub to: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -198,7 +213,7 @@ pub struct DescribeEntityAggregatesResponse {
 pub struct DescribeEventAggregatesRequest {
     /// <p>The only currently supported value is <code>eventTypeCategory</code>.</p>
     #[serde(rename = "aggregateField")]
-    pub aggregate_field: String,
+    pub aggregate_field: EventAggregateField,
     /// <p>Values to narrow the results returned.</p>
     #[serde(rename = "filter")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -423,13 +438,121 @@ pub struct EntityFilter {
     /// <p>A list of entity status codes (<code>IMPAIRED</code>, <code>UNIMPAIRED</code>, or <code>UNKNOWN</code>).</p>
     #[serde(rename = "statusCodes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status_codes: Option<Vec<String>>,
+    pub status_codes: Option<Vec<EntityStatusCode>>,
     /// <p>A map of entity tags attached to the affected entity.</p>
     #[serde(rename = "tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tags: Option<Vec<::std::collections::HashMap<String, String>>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EntityStatusCode {
+    /// `IMPAIRED`
+    Impaired,
+    /// `UNIMPAIRED`
+    Unimpaired,
+    /// `UNKNOWN`
+    UnknownValue,
+    /// `PENDING`
+    Pending,
+    /// `RESOLVED`
+    Resolved,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EntityStatusCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EntityStatusCode::Impaired => "IMPAIRED",
+            EntityStatusCode::Unimpaired => "UNIMPAIRED",
+            EntityStatusCode::UnknownValue => "UNKNOWN",
+            EntityStatusCode::Pending => "PENDING",
+            EntityStatusCode::Resolved => "RESOLVED",
+            EntityStatusCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EntityStatusCode {
+    fn default() -> EntityStatusCode {
+        EntityStatusCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EntityStatusCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EntityStatusCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EntityStatusCode {
+    fn from(value: &'a str) -> EntityStatusCode {
+        match value {
+            "IMPAIRED" => EntityStatusCode::Impaired,
+            "UNIMPAIRED" => EntityStatusCode::Unimpaired,
+            "UNKNOWN" => EntityStatusCode::UnknownValue,
+            "PENDING" => EntityStatusCode::Pending,
+            "RESOLVED" => EntityStatusCode::Resolved,
+            _ => EntityStatusCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EntityStatusCode {
+    fn from(value: String) -> EntityStatusCode {
+        match value.as_str() {
+            "IMPAIRED" => EntityStatusCode::Impaired,
+            "UNIMPAIRED" => EntityStatusCode::Unimpaired,
+            "UNKNOWN" => EntityStatusCode::UnknownValue,
+            "PENDING" => EntityStatusCode::Pending,
+            "RESOLVED" => EntityStatusCode::Resolved,
+            _ => EntityStatusCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EntityStatusCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EntityStatusCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EntityStatusCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EntityStatusCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EntityStatusCode {
+    fn deserialize<D>(deserializer: D) -> Result<EntityStatusCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Summary information about an AWS Health event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -444,20 +567,30 @@ pub struct Event {
     pub availability_zone: Option<String>,
     /// <p>The date and time that the event ended.</p>
     #[serde(rename = "endTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The category of the event. Possible values are <code>issue</code>, <code>scheduledChange</code>, and <code>accountNotification</code>.</p>
     #[serde(rename = "eventTypeCategory")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub event_type_category: Option<String>,
+    pub event_type_category: Option<EventTypeCategory>,
     /// <p>The unique identifier for the event type. The format is <code>AWS_<i>SERVICE</i>_<i>DESCRIPTION</i> </code>; for example, <code>AWS_EC2_SYSTEM_MAINTENANCE_EVENT</code>.</p>
     #[serde(rename = "eventTypeCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub event_type_code: Option<String>,
     /// <p>The most recent date and time that the event was updated.</p>
     #[serde(rename = "lastUpdatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_time: Option<f64>,
+    pub last_updated_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The AWS region name of the event.</p>
     #[serde(rename = "region")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -468,12 +601,17 @@ pub struct Event {
     pub service: Option<String>,
     /// <p>The date and time that the event began.</p>
     #[serde(rename = "startTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The most recent status of the event. Possible values are <code>open</code>, <code>closed</code>, and <code>upcoming</code>.</p>
     #[serde(rename = "statusCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status_code: Option<String>,
+    pub status_code: Option<EventStatusCode>,
 }
 
 /// <p>The values used to filter results from the <a>DescribeEventDetailsForOrganization</a> and <a>DescribeAffectedEntitiesForOrganization</a> operations.</p>
@@ -502,6 +640,94 @@ pub struct EventAggregate {
     pub count: Option<i64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EventAggregateField {
+    /// `eventTypeCategory`
+    EventTypeCategory,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EventAggregateField {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EventAggregateField::EventTypeCategory => "eventTypeCategory",
+            EventAggregateField::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EventAggregateField {
+    fn default() -> EventAggregateField {
+        EventAggregateField::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EventAggregateField {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EventAggregateField {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EventAggregateField {
+    fn from(value: &'a str) -> EventAggregateField {
+        match value {
+            "eventTypeCategory" => EventAggregateField::EventTypeCategory,
+            _ => EventAggregateField::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EventAggregateField {
+    fn from(value: String) -> EventAggregateField {
+        match value.as_str() {
+            "eventTypeCategory" => EventAggregateField::EventTypeCategory,
+            _ => EventAggregateField::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EventAggregateField {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EventAggregateField, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EventAggregateField {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EventAggregateField {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EventAggregateField {
+    fn deserialize<D>(deserializer: D) -> Result<EventAggregateField, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Detailed information about an event. A combination of an <a>Event</a> object, an <a>EventDescription</a> object, and additional metadata about the event. Returned by the <a>DescribeEventDetails</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -565,11 +791,11 @@ pub struct EventFilter {
     /// <p>A list of event status codes.</p>
     #[serde(rename = "eventStatusCodes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub event_status_codes: Option<Vec<String>>,
+    pub event_status_codes: Option<Vec<EventStatusCode>>,
     /// <p>A list of event type category codes (<code>issue</code>, <code>scheduledChange</code>, or <code>accountNotification</code>).</p>
     #[serde(rename = "eventTypeCategories")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub event_type_categories: Option<Vec<String>>,
+    pub event_type_categories: Option<Vec<EventTypeCategory>>,
     /// <p>A list of unique identifiers for event types. For example, <code>"AWS_EC2_SYSTEM_MAINTENANCE_EVENT","AWS_RDS_MAINTENANCE_SCHEDULED".</code> </p>
     #[serde(rename = "eventTypeCodes")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -596,6 +822,207 @@ pub struct EventFilter {
     pub tags: Option<Vec<::std::collections::HashMap<String, String>>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EventStatusCode {
+    /// `open`
+    Open,
+    /// `closed`
+    Closed,
+    /// `upcoming`
+    Upcoming,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EventStatusCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EventStatusCode::Open => "open",
+            EventStatusCode::Closed => "closed",
+            EventStatusCode::Upcoming => "upcoming",
+            EventStatusCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EventStatusCode {
+    fn default() -> EventStatusCode {
+        EventStatusCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EventStatusCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EventStatusCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EventStatusCode {
+    fn from(value: &'a str) -> EventStatusCode {
+        match value {
+            "open" => EventStatusCode::Open,
+            "closed" => EventStatusCode::Closed,
+            "upcoming" => EventStatusCode::Upcoming,
+            _ => EventStatusCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EventStatusCode {
+    fn from(value: String) -> EventStatusCode {
+        match value.as_str() {
+            "open" => EventStatusCode::Open,
+            "closed" => EventStatusCode::Closed,
+            "upcoming" => EventStatusCode::Upcoming,
+            _ => EventStatusCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EventStatusCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EventStatusCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EventStatusCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EventStatusCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EventStatusCode {
+    fn deserialize<D>(deserializer: D) -> Result<EventStatusCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EventTypeCategory {
+    /// `issue`
+    Issue,
+    /// `accountNotification`
+    AccountNotification,
+    /// `scheduledChange`
+    ScheduledChange,
+    /// `investigation`
+    Investigation,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EventTypeCategory {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EventTypeCategory::Issue => "issue",
+            EventTypeCategory::AccountNotification => "accountNotification",
+            EventTypeCategory::ScheduledChange => "scheduledChange",
+            EventTypeCategory::Investigation => "investigation",
+            EventTypeCategory::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EventTypeCategory {
+    fn default() -> EventTypeCategory {
+        EventTypeCategory::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EventTypeCategory {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EventTypeCategory {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EventTypeCategory {
+    fn from(value: &'a str) -> EventTypeCategory {
+        match value {
+            "issue" => EventTypeCategory::Issue,
+            "accountNotification" => EventTypeCategory::AccountNotification,
+            "scheduledChange" => EventTypeCategory::ScheduledChange,
+            "investigation" => EventTypeCategory::Investigation,
+            _ => EventTypeCategory::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EventTypeCategory {
+    fn from(value: String) -> EventTypeCategory {
+        match value.as_str() {
+            "issue" => EventTypeCategory::Issue,
+            "accountNotification" => EventTypeCategory::AccountNotification,
+            "scheduledChange" => EventTypeCategory::ScheduledChange,
+            "investigation" => EventTypeCategory::Investigation,
+            _ => EventTypeCategory::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EventTypeCategory {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EventTypeCategory, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EventTypeCategory {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EventTypeCategory {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EventTypeCategory {
+    fn deserialize<D>(deserializer: D) -> Result<EventTypeCategory, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The values to use to filter results from the <a>DescribeEventTypes</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -603,7 +1030,7 @@ pub struct EventTypeFilter {
     /// <p>A list of event type category codes (<code>issue</code>, <code>scheduledChange</code>, or <code>accountNotification</code>).</p>
     #[serde(rename = "eventTypeCategories")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub event_type_categories: Option<Vec<String>>,
+    pub event_type_categories: Option<Vec<EventTypeCategory>>,
     /// <p>A list of event type codes.</p>
     #[serde(rename = "eventTypeCodes")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -646,20 +1073,30 @@ pub struct OrganizationEvent {
     pub arn: Option<String>,
     /// <p>The date and time that the event ended.</p>
     #[serde(rename = "endTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The category of the event type.</p>
     #[serde(rename = "eventTypeCategory")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub event_type_category: Option<String>,
+    pub event_type_category: Option<EventTypeCategory>,
     /// <p>The unique identifier for the event type. The format is <code>AWS_SERVICE_DESCRIPTION</code>. For example, <code>AWS_EC2_SYSTEM_MAINTENANCE_EVENT</code>.</p>
     #[serde(rename = "eventTypeCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub event_type_code: Option<String>,
     /// <p>The most recent date and time that the event was updated.</p>
     #[serde(rename = "lastUpdatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_time: Option<f64>,
+    pub last_updated_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The AWS Region name of the event.</p>
     #[serde(rename = "region")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -670,12 +1107,17 @@ pub struct OrganizationEvent {
     pub service: Option<String>,
     /// <p>The date and time that the event began.</p>
     #[serde(rename = "startTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The most recent status of the event. Possible values are <code>open</code>, <code>closed</code>, and <code>upcoming</code>.</p>
     #[serde(rename = "statusCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status_code: Option<String>,
+    pub status_code: Option<EventStatusCode>,
 }
 
 /// <p>Detailed information about an event. A combination of an <a>Event</a> object, an <a>EventDescription</a> object, and additional metadata about the event. Returned by the <a>DescribeEventDetailsForOrganization</a> operation.</p>
@@ -742,11 +1184,11 @@ pub struct OrganizationEventFilter {
     /// <p>A list of event status codes.</p>
     #[serde(rename = "eventStatusCodes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub event_status_codes: Option<Vec<String>>,
+    pub event_status_codes: Option<Vec<EventStatusCode>>,
     /// <p>REPLACEME</p>
     #[serde(rename = "eventTypeCategories")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub event_type_categories: Option<Vec<String>>,
+    pub event_type_categories: Option<Vec<EventTypeCategory>>,
     /// <p>A list of unique identifiers for event types. For example, <code>"AWS_EC2_SYSTEM_MAINTENANCE_EVENT","AWS_RDS_MAINTENANCE_SCHEDULED".</code> </p>
     #[serde(rename = "eventTypeCodes")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1346,11 +1788,12 @@ pub struct AWSHealthClient {
 impl AWSHealthClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> AWSHealthClient {
         AWSHealthClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Health"),
         }
     }
 
@@ -1385,6 +1828,7 @@ impl AWSHealth for AWSHealthClient {
         RusotoError<DescribeAffectedAccountsForOrganizationError>,
     > {
         let mut request = SignedRequest::new("POST", "health", &self.region, "/");
+        request.set_operation("DescribeAffectedAccountsForOrganization");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1418,6 +1862,7 @@ impl AWSHealth for AWSHealthClient {
         input: DescribeAffectedEntitiesRequest,
     ) -> Result<DescribeAffectedEntitiesResponse, RusotoError<DescribeAffectedEntitiesError>> {
         let mut request = SignedRequest::new("POST", "health", &self.region, "/");
+        request.set_operation("DescribeAffectedEntities");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1452,6 +1897,7 @@ impl AWSHealth for AWSHealthClient {
         RusotoError<DescribeAffectedEntitiesForOrganizationError>,
     > {
         let mut request = SignedRequest::new("POST", "health", &self.region, "/");
+        request.set_operation("DescribeAffectedEntitiesForOrganization");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1485,6 +1931,7 @@ impl AWSHealth for AWSHealthClient {
         input: DescribeEntityAggregatesRequest,
     ) -> Result<DescribeEntityAggregatesResponse, RusotoError<DescribeEntityAggregatesError>> {
         let mut request = SignedRequest::new("POST", "health", &self.region, "/");
+        request.set_operation("DescribeEntityAggregates");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1516,6 +1963,7 @@ impl AWSHealth for AWSHealthClient {
         input: DescribeEventAggregatesRequest,
     ) -> Result<DescribeEventAggregatesResponse, RusotoError<DescribeEventAggregatesError>> {
         let mut request = SignedRequest::new("POST", "health", &self.region, "/");
+        request.set_operation("DescribeEventAggregates");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSHealth_20160804.DescribeEventAggregates");
@@ -1544,6 +1992,7 @@ impl AWSHealth for AWSHealthClient {
         input: DescribeEventDetailsRequest,
     ) -> Result<DescribeEventDetailsResponse, RusotoError<DescribeEventDetailsError>> {
         let mut request = SignedRequest::new("POST", "health", &self.region, "/");
+        request.set_operation("DescribeEventDetails");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSHealth_20160804.DescribeEventDetails");
@@ -1575,6 +2024,7 @@ impl AWSHealth for AWSHealthClient {
         RusotoError<DescribeEventDetailsForOrganizationError>,
     > {
         let mut request = SignedRequest::new("POST", "health", &self.region, "/");
+        request.set_operation("DescribeEventDetailsForOrganization");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1608,6 +2058,7 @@ impl AWSHealth for AWSHealthClient {
         input: DescribeEventTypesRequest,
     ) -> Result<DescribeEventTypesResponse, RusotoError<DescribeEventTypesError>> {
         let mut request = SignedRequest::new("POST", "health", &self.region, "/");
+        request.set_operation("DescribeEventTypes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSHealth_20160804.DescribeEventTypes");
@@ -1636,6 +2087,7 @@ impl AWSHealth for AWSHealthClient {
         input: DescribeEventsRequest,
     ) -> Result<DescribeEventsResponse, RusotoError<DescribeEventsError>> {
         let mut request = SignedRequest::new("POST", "health", &self.region, "/");
+        request.set_operation("DescribeEvents");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSHealth_20160804.DescribeEvents");
@@ -1666,6 +2118,7 @@ impl AWSHealth for AWSHealthClient {
         RusotoError<DescribeEventsForOrganizationError>,
     > {
         let mut request = SignedRequest::new("POST", "health", &self.region, "/");
+        request.set_operation("DescribeEventsForOrganization");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1699,6 +2152,7 @@ impl AWSHealth for AWSHealthClient {
         RusotoError<DescribeHealthServiceStatusForOrganizationError>,
     > {
         let mut request = SignedRequest::new("POST", "health", &self.region, "/");
+        request.set_operation("DescribeHealthServiceStatusForOrganization");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1728,6 +2182,7 @@ impl AWSHealth for AWSHealthClient {
         &self,
     ) -> Result<(), RusotoError<DisableHealthServiceAccessForOrganizationError>> {
         let mut request = SignedRequest::new("POST", "health", &self.region, "/");
+        request.set_operation("DisableHealthServiceAccessForOrganization");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1756,6 +2211,7 @@ impl AWSHealth for AWSHealthClient {
         &self,
     ) -> Result<(), RusotoError<EnableHealthServiceAccessForOrganizationError>> {
         let mut request = SignedRequest::new("POST", "health", &self.region, "/");
+        request.set_operation("EnableHealthServiceAccessForOrganization");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1779,3 +2235,253 @@ impl AWSHealth for AWSHealthClient {
         }
     }
 }
+impl AWSHealthClient {
+    /// Auto-paginating version of `describe_affected_accounts_for_organization`, streaming every page of results.
+    pub fn describe_affected_accounts_for_organization_pages(
+        &self,
+        input: DescribeAffectedAccountsForOrganizationRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeAffectedAccountsForOrganizationResponse,
+        DescribeAffectedAccountsForOrganizationError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AWSHealthClient, input: DescribeAffectedAccountsForOrganizationRequest| async move {
+                client
+                    .describe_affected_accounts_for_organization(input)
+                    .await
+            },
+            |input: &DescribeAffectedAccountsForOrganizationRequest,
+             output: &DescribeAffectedAccountsForOrganizationResponse| {
+                Some(DescribeAffectedAccountsForOrganizationRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_affected_accounts_for_organization`, streaming the `affectedAccounts` of every page of results.
+    pub fn describe_affected_accounts_for_organization_items(
+        &self,
+        input: DescribeAffectedAccountsForOrganizationRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, DescribeAffectedAccountsForOrganizationError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_affected_accounts_for_organization_pages(input),
+            |output: DescribeAffectedAccountsForOrganizationResponse| {
+                output.affected_accounts.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_affected_entities`, streaming every page of results.
+    pub fn describe_affected_entities_pages(
+        &self,
+        input: DescribeAffectedEntitiesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeAffectedEntitiesResponse,
+        DescribeAffectedEntitiesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AWSHealthClient, input: DescribeAffectedEntitiesRequest| async move {
+                client.describe_affected_entities(input).await
+            },
+            |input: &DescribeAffectedEntitiesRequest, output: &DescribeAffectedEntitiesResponse| {
+                Some(DescribeAffectedEntitiesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_affected_entities`, streaming the `entities` of every page of results.
+    pub fn describe_affected_entities_items(
+        &self,
+        input: DescribeAffectedEntitiesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<AffectedEntity, DescribeAffectedEntitiesError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_affected_entities_pages(input),
+            |output: DescribeAffectedEntitiesResponse| output.entities.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_affected_entities_for_organization`, streaming every page of results.
+    pub fn describe_affected_entities_for_organization_pages(
+        &self,
+        input: DescribeAffectedEntitiesForOrganizationRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeAffectedEntitiesForOrganizationResponse,
+        DescribeAffectedEntitiesForOrganizationError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AWSHealthClient, input: DescribeAffectedEntitiesForOrganizationRequest| async move {
+                client
+                    .describe_affected_entities_for_organization(input)
+                    .await
+            },
+            |input: &DescribeAffectedEntitiesForOrganizationRequest,
+             output: &DescribeAffectedEntitiesForOrganizationResponse| {
+                Some(DescribeAffectedEntitiesForOrganizationRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_affected_entities_for_organization`, streaming the `entities` of every page of results.
+    pub fn describe_affected_entities_for_organization_items(
+        &self,
+        input: DescribeAffectedEntitiesForOrganizationRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        AffectedEntity,
+        DescribeAffectedEntitiesForOrganizationError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_affected_entities_for_organization_pages(input),
+            |output: DescribeAffectedEntitiesForOrganizationResponse| {
+                output.entities.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_event_aggregates`, streaming every page of results.
+    pub fn describe_event_aggregates_pages(
+        &self,
+        input: DescribeEventAggregatesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeEventAggregatesResponse,
+        DescribeEventAggregatesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AWSHealthClient, input: DescribeEventAggregatesRequest| async move {
+                client.describe_event_aggregates(input).await
+            },
+            |input: &DescribeEventAggregatesRequest, output: &DescribeEventAggregatesResponse| {
+                Some(DescribeEventAggregatesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_event_aggregates`, streaming the `eventAggregates` of every page of results.
+    pub fn describe_event_aggregates_items(
+        &self,
+        input: DescribeEventAggregatesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<EventAggregate, DescribeEventAggregatesError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_event_aggregates_pages(input),
+            |output: DescribeEventAggregatesResponse| output.event_aggregates.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_event_types`, streaming every page of results.
+    pub fn describe_event_types_pages(
+        &self,
+        input: DescribeEventTypesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeEventTypesResponse, DescribeEventTypesError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AWSHealthClient, input: DescribeEventTypesRequest| async move {
+                client.describe_event_types(input).await
+            },
+            |input: &DescribeEventTypesRequest, output: &DescribeEventTypesResponse| {
+                Some(DescribeEventTypesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_event_types`, streaming the `eventTypes` of every page of results.
+    pub fn describe_event_types_items(
+        &self,
+        input: DescribeEventTypesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, DescribeEventTypesError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_event_types_pages(input),
+            |output: DescribeEventTypesResponse| output.event_types.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_events`, streaming every page of results.
+    pub fn describe_events_pages(
+        &self,
+        input: DescribeEventsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeEventsResponse, DescribeEventsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AWSHealthClient, input: DescribeEventsRequest| async move {
+                client.describe_events(input).await
+            },
+            |input: &DescribeEventsRequest, output: &DescribeEventsResponse| {
+                Some(DescribeEventsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_events`, streaming the `events` of every page of results.
+    pub fn describe_events_items(
+        &self,
+        input: DescribeEventsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Event, DescribeEventsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_events_pages(input),
+            |output: DescribeEventsResponse| output.events.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_events_for_organization`, streaming every page of results.
+    pub fn describe_events_for_organization_pages(
+        &self,
+        input: DescribeEventsForOrganizationRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeEventsForOrganizationResponse,
+        DescribeEventsForOrganizationError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AWSHealthClient, input: DescribeEventsForOrganizationRequest| async move {
+                client.describe_events_for_organization(input).await
+            },
+            |input: &DescribeEventsForOrganizationRequest,
+             output: &DescribeEventsForOrganizationResponse| {
+                Some(DescribeEventsForOrganizationRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_events_for_organization`, streaming the `events` of every page of results.
+    pub fn describe_events_for_organization_items(
+        &self,
+        input: DescribeEventsForOrganizationRequest,
+    ) -> rusoto_core::pagination::RusotoStream<OrganizationEvent, DescribeEventsForOrganizationError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_events_for_organization_pages(input),
+            |output: DescribeEventsForOrganizationResponse| output.events.unwrap_or_default(),
+        )
+    }
+}
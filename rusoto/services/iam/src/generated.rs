@@ -39,7 +39,7 @@ pub struct AccessDetail {
     /// <p>The path of the Organizations entity (root, organizational unit, or account) from which an authenticated principal last attempted to access the service. AWS does not report unauthenticated requests.</p> <p>This field is null if no principals (IAM users, IAM roles, or root users) in the reported Organizations entity attempted to access the service within the <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/access_policies_access-advisor.html#service-last-accessed-reporting-period">reporting period</a>.</p>
     pub entity_path: Option<String>,
     /// <p>The date and time, in <a href="http://www.iso.org/iso/iso8601">ISO 8601 date-time format</a>, when an authenticated principal most recently attempted to access the service. AWS does not report unauthenticated requests.</p> <p>This field is null if no principals in the reported Organizations entity attempted to access the service within the <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/access_policies_access-advisor.html#service-last-accessed-reporting-period">reporting period</a>.</p>
-    pub last_authenticated_time: Option<String>,
+    pub last_authenticated_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The Region where the last service access attempt occurred.</p> <p>This field is null if no principals in the reported Organizations entity attempted to access the service within the <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/access_policies_access-advisor.html#service-last-accessed-reporting-period">reporting period</a>.</p>
     pub region: Option<String>,
     /// <p>The name of the service in which access was attempted.</p>
@@ -118,11 +118,11 @@ pub struct AccessKey {
     /// <p>The ID for this access key.</p>
     pub access_key_id: String,
     /// <p>The date when the access key was created.</p>
-    pub create_date: Option<String>,
+    pub create_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The secret key used to sign requests.</p>
     pub secret_access_key: String,
     /// <p>The status of the access key. <code>Active</code> means that the key is valid for API calls, while <code>Inactive</code> means it is not. </p>
-    pub status: String,
+    pub status: StatusType,
     /// <p>The name of the IAM user that the access key is associated with.</p>
     pub user_name: String,
 }
@@ -175,7 +175,7 @@ impl AccessKeyIdTypeDeserializer {
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct AccessKeyLastUsed {
     /// <p><p>The date and time, in <a href="http://www.iso.org/iso/iso8601">ISO 8601 date-time format</a>, when the access key was most recently used. This field is null in the following situations:</p> <ul> <li> <p>The user does not have an access key.</p> </li> <li> <p>An access key exists but has not been used since IAM began tracking this information.</p> </li> <li> <p>There is no sign-in data associated with the user.</p> </li> </ul></p>
-    pub last_used_date: String,
+    pub last_used_date: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The AWS Region where this access key was most recently used. The value for this field is "N/A" in the following situations:</p> <ul> <li> <p>The user does not have an access key.</p> </li> <li> <p>An access key exists but has not been used since IAM began tracking this information.</p> </li> <li> <p>There is no sign-in data associated with the user.</p> </li> </ul> <p>For more information about AWS Regions, see <a href="https://docs.aws.amazon.com/general/latest/gr/rande.html">Regions and Endpoints</a> in the Amazon Web Services General Reference.</p>
     pub region: String,
     /// <p><p>The name of the AWS service with which this access key was most recently used. The value of this field is &quot;N/A&quot; in the following situations:</p> <ul> <li> <p>The user does not have an access key.</p> </li> <li> <p>An access key exists but has not been used since IAM started tracking this information.</p> </li> <li> <p>There is no sign-in data associated with the user.</p> </li> </ul></p>
@@ -213,9 +213,9 @@ pub struct AccessKeyMetadata {
     /// <p>The ID for this access key.</p>
     pub access_key_id: Option<String>,
     /// <p>The date when the access key was created.</p>
-    pub create_date: Option<String>,
+    pub create_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The status of the access key. <code>Active</code> means that the key is valid for API calls; <code>Inactive</code> means it is not.</p>
-    pub status: Option<String>,
+    pub status: Option<StatusType>,
     /// <p>The name of the IAM user that the key is associated with.</p>
     pub user_name: Option<String>,
 }
@@ -432,6 +432,106 @@ impl ArnTypeDeserializer {
         Ok(obj)
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AssignmentStatusType {
+    /// `Assigned`
+    Assigned,
+    /// `Unassigned`
+    Unassigned,
+    /// `Any`
+    Any,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AssignmentStatusType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AssignmentStatusType::Assigned => "Assigned",
+            AssignmentStatusType::Unassigned => "Unassigned",
+            AssignmentStatusType::Any => "Any",
+            AssignmentStatusType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AssignmentStatusType {
+    fn default() -> AssignmentStatusType {
+        AssignmentStatusType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AssignmentStatusType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AssignmentStatusType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AssignmentStatusType {
+    fn from(value: &'a str) -> AssignmentStatusType {
+        match value {
+            "Assigned" => AssignmentStatusType::Assigned,
+            "Unassigned" => AssignmentStatusType::Unassigned,
+            "Any" => AssignmentStatusType::Any,
+            _ => AssignmentStatusType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AssignmentStatusType {
+    fn from(value: String) -> AssignmentStatusType {
+        match value.as_str() {
+            "Assigned" => AssignmentStatusType::Assigned,
+            "Unassigned" => AssignmentStatusType::Unassigned,
+            "Any" => AssignmentStatusType::Any,
+            _ => AssignmentStatusType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AssignmentStatusType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AssignmentStatusType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AssignmentStatusType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for AssignmentStatusType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for AssignmentStatusType {
+    fn deserialize<D>(deserializer: D) -> Result<AssignmentStatusType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct AttachGroupPolicyRequest {
@@ -508,7 +608,7 @@ pub struct AttachedPermissionsBoundary {
     /// <p> The ARN of the policy used to set the permissions boundary for the user or role.</p>
     pub permissions_boundary_arn: Option<String>,
     /// <p> The permissions boundary usage type that indicates what type of IAM resource is used as the permissions boundary for an entity. This data type can only have a value of <code>Policy</code>.</p>
-    pub permissions_boundary_type: Option<String>,
+    pub permissions_boundary_type: Option<PermissionsBoundaryAttachmentType>,
 }
 
 struct AttachedPermissionsBoundaryDeserializer;
@@ -773,7 +873,7 @@ pub struct ContextEntry {
     /// <p>The full name of a condition context key, including the service prefix. For example, <code>aws:SourceIp</code> or <code>s3:VersionId</code>.</p>
     pub context_key_name: Option<String>,
     /// <p>The data type of the value (or values) specified in the <code>ContextKeyValues</code> parameter.</p>
-    pub context_key_type: Option<String>,
+    pub context_key_type: Option<ContextKeyTypeEnum>,
     /// <p>The value (or values, if the condition context key supports multiple values) to provide to the simulation when the key is referenced by a <code>Condition</code> element in an input policy.</p>
     pub context_key_values: Option<Vec<String>>,
 }
@@ -844,6 +944,151 @@ impl ContextKeyNamesResultListTypeDeserializer {
         })
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ContextKeyTypeEnum {
+    /// `string`
+    String,
+    /// `stringList`
+    StringList,
+    /// `numeric`
+    Numeric,
+    /// `numericList`
+    NumericList,
+    /// `boolean`
+    Boolean,
+    /// `booleanList`
+    BooleanList,
+    /// `ip`
+    Ip,
+    /// `ipList`
+    IpList,
+    /// `binary`
+    Binary,
+    /// `binaryList`
+    BinaryList,
+    /// `date`
+    Date,
+    /// `dateList`
+    DateList,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ContextKeyTypeEnum {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ContextKeyTypeEnum::String => "string",
+            ContextKeyTypeEnum::StringList => "stringList",
+            ContextKeyTypeEnum::Numeric => "numeric",
+            ContextKeyTypeEnum::NumericList => "numericList",
+            ContextKeyTypeEnum::Boolean => "boolean",
+            ContextKeyTypeEnum::BooleanList => "booleanList",
+            ContextKeyTypeEnum::Ip => "ip",
+            ContextKeyTypeEnum::IpList => "ipList",
+            ContextKeyTypeEnum::Binary => "binary",
+            ContextKeyTypeEnum::BinaryList => "binaryList",
+            ContextKeyTypeEnum::Date => "date",
+            ContextKeyTypeEnum::DateList => "dateList",
+            ContextKeyTypeEnum::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ContextKeyTypeEnum {
+    fn default() -> ContextKeyTypeEnum {
+        ContextKeyTypeEnum::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ContextKeyTypeEnum {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ContextKeyTypeEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ContextKeyTypeEnum {
+    fn from(value: &'a str) -> ContextKeyTypeEnum {
+        match value {
+            "string" => ContextKeyTypeEnum::String,
+            "stringList" => ContextKeyTypeEnum::StringList,
+            "numeric" => ContextKeyTypeEnum::Numeric,
+            "numericList" => ContextKeyTypeEnum::NumericList,
+            "boolean" => ContextKeyTypeEnum::Boolean,
+            "booleanList" => ContextKeyTypeEnum::BooleanList,
+            "ip" => ContextKeyTypeEnum::Ip,
+            "ipList" => ContextKeyTypeEnum::IpList,
+            "binary" => ContextKeyTypeEnum::Binary,
+            "binaryList" => ContextKeyTypeEnum::BinaryList,
+            "date" => ContextKeyTypeEnum::Date,
+            "dateList" => ContextKeyTypeEnum::DateList,
+            _ => ContextKeyTypeEnum::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ContextKeyTypeEnum {
+    fn from(value: String) -> ContextKeyTypeEnum {
+        match value.as_str() {
+            "string" => ContextKeyTypeEnum::String,
+            "stringList" => ContextKeyTypeEnum::StringList,
+            "numeric" => ContextKeyTypeEnum::Numeric,
+            "numericList" => ContextKeyTypeEnum::NumericList,
+            "boolean" => ContextKeyTypeEnum::Boolean,
+            "booleanList" => ContextKeyTypeEnum::BooleanList,
+            "ip" => ContextKeyTypeEnum::Ip,
+            "ipList" => ContextKeyTypeEnum::IpList,
+            "binary" => ContextKeyTypeEnum::Binary,
+            "binaryList" => ContextKeyTypeEnum::BinaryList,
+            "date" => ContextKeyTypeEnum::Date,
+            "dateList" => ContextKeyTypeEnum::DateList,
+            _ => ContextKeyTypeEnum::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ContextKeyTypeEnum {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ContextKeyTypeEnum, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ContextKeyTypeEnum {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ContextKeyTypeEnum {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ContextKeyTypeEnum {
+    fn deserialize<D>(deserializer: D) -> Result<ContextKeyTypeEnum, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 
 /// Serialize `ContextKeyValueListType` contents to a `SignedRequest`.
 struct ContextKeyValueListTypeSerializer;
@@ -1676,9 +1921,12 @@ impl CreateVirtualMFADeviceResponseDeserializer {
 struct DateTypeDeserializer;
 impl DateTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<::chrono::DateTime<::chrono::Utc>, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::proto::xml::util::timestamp_characters(stack)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -2276,12 +2524,120 @@ impl DeletionTaskIdTypeDeserializer {
         Ok(obj)
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DeletionTaskStatusType {
+    /// `SUCCEEDED`
+    Succeeded,
+    /// `IN_PROGRESS`
+    InProgress,
+    /// `FAILED`
+    Failed,
+    /// `NOT_STARTED`
+    NotStarted,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DeletionTaskStatusType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeletionTaskStatusType::Succeeded => "SUCCEEDED",
+            DeletionTaskStatusType::InProgress => "IN_PROGRESS",
+            DeletionTaskStatusType::Failed => "FAILED",
+            DeletionTaskStatusType::NotStarted => "NOT_STARTED",
+            DeletionTaskStatusType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DeletionTaskStatusType {
+    fn default() -> DeletionTaskStatusType {
+        DeletionTaskStatusType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DeletionTaskStatusType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DeletionTaskStatusType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DeletionTaskStatusType {
+    fn from(value: &'a str) -> DeletionTaskStatusType {
+        match value {
+            "SUCCEEDED" => DeletionTaskStatusType::Succeeded,
+            "IN_PROGRESS" => DeletionTaskStatusType::InProgress,
+            "FAILED" => DeletionTaskStatusType::Failed,
+            "NOT_STARTED" => DeletionTaskStatusType::NotStarted,
+            _ => DeletionTaskStatusType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeletionTaskStatusType {
+    fn from(value: String) -> DeletionTaskStatusType {
+        match value.as_str() {
+            "SUCCEEDED" => DeletionTaskStatusType::Succeeded,
+            "IN_PROGRESS" => DeletionTaskStatusType::InProgress,
+            "FAILED" => DeletionTaskStatusType::Failed,
+            "NOT_STARTED" => DeletionTaskStatusType::NotStarted,
+            _ => DeletionTaskStatusType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DeletionTaskStatusType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DeletionTaskStatusType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DeletionTaskStatusType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for DeletionTaskStatusType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for DeletionTaskStatusType {
+    fn deserialize<D>(deserializer: D) -> Result<DeletionTaskStatusType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct DeletionTaskStatusTypeDeserializer;
 impl DeletionTaskStatusTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<DeletionTaskStatusType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -2391,6 +2747,101 @@ impl EnableMFADeviceRequestSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EncodingType {
+    /// `SSH`
+    Ssh,
+    /// `PEM`
+    Pem,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EncodingType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EncodingType::Ssh => "SSH",
+            EncodingType::Pem => "PEM",
+            EncodingType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EncodingType {
+    fn default() -> EncodingType {
+        EncodingType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EncodingType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EncodingType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EncodingType {
+    fn from(value: &'a str) -> EncodingType {
+        match value {
+            "SSH" => EncodingType::Ssh,
+            "PEM" => EncodingType::Pem,
+            _ => EncodingType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EncodingType {
+    fn from(value: String) -> EncodingType {
+        match value.as_str() {
+            "SSH" => EncodingType::Ssh,
+            "PEM" => EncodingType::Pem,
+            _ => EncodingType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EncodingType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EncodingType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EncodingType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for EncodingType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for EncodingType {
+    fn deserialize<D>(deserializer: D) -> Result<EncodingType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An object that contains details about when the IAM entities (users or roles) were last used in an attempt to access the specified AWS service.</p> <p>This data type is a response element in the <a>GetServiceLastAccessedDetailsWithEntities</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
@@ -2398,7 +2849,7 @@ pub struct EntityDetails {
     /// <p>The <code>EntityInfo</code> object that contains details about the entity (user or role).</p>
     pub entity_info: EntityInfo,
     /// <p>The date and time, in <a href="http://www.iso.org/iso/iso8601">ISO 8601 date-time format</a>, when the authenticated entity last attempted to access AWS. AWS does not report unauthenticated requests.</p> <p>This field is null if no IAM entities attempted to access the service within the <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/access_policies_access-advisor.html#service-last-accessed-reporting-period">reporting period</a>.</p>
-    pub last_authenticated: Option<String>,
+    pub last_authenticated: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 struct EntityDetailsDeserializer;
@@ -2454,7 +2905,7 @@ pub struct EntityInfo {
     /// <p>The path to the entity (user or role). For more information about paths, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/Using_Identifiers.html">IAM Identifiers</a> in the <i>IAM User Guide</i>. </p>
     pub path: Option<String>,
     /// <p>The type of entity (user or role).</p>
-    pub type_: String,
+    pub type_: PolicyOwnerEntityType,
 }
 
 struct EntityInfoDeserializer;
@@ -2491,7 +2942,7 @@ impl EntityInfoDeserializer {
 /// Serialize `EntityListType` contents to a `SignedRequest`.
 struct EntityListTypeSerializer;
 impl EntityListTypeSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &Vec<String>) {
+    fn serialize(params: &mut Params, name: &str, obj: &Vec<EntityType>) {
         for (index, obj) in obj.iter().enumerate() {
             let key = format!("{}.member.{}", name, index + 1);
             params.put(&key, &obj);
@@ -2510,6 +2961,116 @@ impl EntityNameTypeDeserializer {
         Ok(obj)
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EntityType {
+    /// `User`
+    User,
+    /// `Role`
+    Role,
+    /// `Group`
+    Group,
+    /// `LocalManagedPolicy`
+    LocalManagedPolicy,
+    /// `AWSManagedPolicy`
+    AWSManagedPolicy,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EntityType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EntityType::User => "User",
+            EntityType::Role => "Role",
+            EntityType::Group => "Group",
+            EntityType::LocalManagedPolicy => "LocalManagedPolicy",
+            EntityType::AWSManagedPolicy => "AWSManagedPolicy",
+            EntityType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EntityType {
+    fn default() -> EntityType {
+        EntityType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EntityType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EntityType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EntityType {
+    fn from(value: &'a str) -> EntityType {
+        match value {
+            "User" => EntityType::User,
+            "Role" => EntityType::Role,
+            "Group" => EntityType::Group,
+            "LocalManagedPolicy" => EntityType::LocalManagedPolicy,
+            "AWSManagedPolicy" => EntityType::AWSManagedPolicy,
+            _ => EntityType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EntityType {
+    fn from(value: String) -> EntityType {
+        match value.as_str() {
+            "User" => EntityType::User,
+            "Role" => EntityType::Role,
+            "Group" => EntityType::Group,
+            "LocalManagedPolicy" => EntityType::LocalManagedPolicy,
+            "AWSManagedPolicy" => EntityType::AWSManagedPolicy,
+            _ => EntityType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EntityType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EntityType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EntityType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for EntityType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for EntityType {
+    fn deserialize<D>(deserializer: D) -> Result<EntityType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Contains information about the reason that the operation failed.</p> <p>This data type is used as a response element in the <a>GetOrganizationsAccessReport</a>, <a>GetServiceLastAccessedDetails</a>, and <a>GetServiceLastAccessedDetailsWithEntities</a> operations.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
@@ -2547,7 +3108,8 @@ impl EvalDecisionDetailsTypeDeserializer {
     fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
-    ) -> Result<::std::collections::HashMap<String, String>, XmlParseError> {
+    ) -> Result<::std::collections::HashMap<String, PolicyEvaluationDecisionType>, XmlParseError>
+    {
         start_element(tag_name, stack)?;
 
         let mut obj = ::std::collections::HashMap::new();
@@ -2582,9 +3144,10 @@ pub struct EvaluationResult {
     /// <p>The name of the API operation tested on the indicated resource.</p>
     pub eval_action_name: String,
     /// <p>The result of the simulation.</p>
-    pub eval_decision: String,
+    pub eval_decision: PolicyEvaluationDecisionType,
     /// <p>Additional details about the results of the cross-account evaluation decision. This parameter is populated for only cross-account simulations. It contains a brief summary of how each policy type contributes to the final evaluation decision.</p> <p>If the simulation evaluates policies within the same account and includes a resource ARN, then the parameter is present but the response is empty. If the simulation evaluates policies within the same account and specifies all resources (<code>*</code>), then the parameter is not returned.</p> <p>When you make a cross-account request, AWS evaluates the request in the trusting account and the trusted account. The request is allowed only if both evaluations return <code>true</code>. For more information about how policies are evaluated, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/reference_policies_evaluation-logic.html#policy-eval-basics">Evaluating Policies Within a Single Account</a>.</p> <p>If an AWS Organizations SCP included in the evaluation denies access, the simulation ends. In this case, policy evaluation does not proceed any further and this parameter is not returned.</p>
-    pub eval_decision_details: Option<::std::collections::HashMap<String, String>>,
+    pub eval_decision_details:
+        Option<::std::collections::HashMap<String, PolicyEvaluationDecisionType>>,
     /// <p>The ARN of the resource that the indicated API operation was tested on.</p>
     pub eval_resource_name: Option<String>,
     /// <p>A list of the statements in the input policies that determine the result for this scenario. Remember that even if multiple statements allow the operation on the resource, if only one statement denies that operation, then the explicit deny overrides any allow. In addition, the deny statement is the only entry included in the result.</p>
@@ -2707,7 +3270,7 @@ pub struct GenerateCredentialReportResponse {
     /// <p>Information about the credential report.</p>
     pub description: Option<String>,
     /// <p>Information about the state of the credential report.</p>
-    pub state: Option<String>,
+    pub state: Option<ReportStateType>,
 }
 
 struct GenerateCredentialReportResponseDeserializer;
@@ -2908,7 +3471,7 @@ impl GetAccessKeyLastUsedResponseDeserializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct GetAccountAuthorizationDetailsRequest {
     /// <p>A list of entity types used to filter the results. Only the entities that match the types you specify are included in the output. Use the value <code>LocalManagedPolicy</code> to include customer managed policies.</p> <p>The format for this parameter is a comma-separated (if more than one) list of strings. Each string value in the list must be one of the valid values listed below.</p>
-    pub filter: Option<Vec<String>>,
+    pub filter: Option<Vec<EntityType>>,
     /// <p>Use this parameter only when paginating results and only after you receive a response indicating that the results are truncated. Set it to the value of the <code>Marker</code> element in the response that you received to indicate where the next call should start.</p>
     pub marker: Option<String>,
     /// <p>Use this only when paginating results to indicate the maximum number of items you want in the response. If additional items exist beyond the maximum you specify, the <code>IsTruncated</code> response element is <code>true</code>.</p> <p>If you do not include this parameter, the number of items defaults to 100. Note that IAM might return fewer results, even when there are more results available. In that case, the <code>IsTruncated</code> response element returns <code>true</code>, and <code>Marker</code> contains a value to include in the subsequent call that tells the service where to continue from.</p>
@@ -3044,7 +3607,7 @@ impl GetAccountPasswordPolicyResponseDeserializer {
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct GetAccountSummaryResponse {
     /// <p>A set of key–value pairs containing information about IAM entity usage and IAM quotas.</p>
-    pub summary_map: Option<::std::collections::HashMap<String, i64>>,
+    pub summary_map: Option<::std::collections::HashMap<SummaryKeyType, i64>>,
 }
 
 struct GetAccountSummaryResponseDeserializer;
@@ -3170,9 +3733,9 @@ pub struct GetCredentialReportResponse {
     /// <p>Contains the credential report. The report is Base64-encoded.</p>
     pub content: Option<bytes::Bytes>,
     /// <p> The date and time when the credential report was created, in <a href="http://www.iso.org/iso/iso8601">ISO 8601 date-time format</a>.</p>
-    pub generated_time: Option<String>,
+    pub generated_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The format (MIME type) of the credential report.</p>
-    pub report_format: Option<String>,
+    pub report_format: Option<ReportFormatType>,
 }
 
 struct GetCredentialReportResponseDeserializer;
@@ -3479,7 +4042,7 @@ pub struct GetOpenIDConnectProviderResponse {
     /// <p>A list of client IDs (also known as audiences) that are associated with the specified IAM OIDC provider resource object. For more information, see <a>CreateOpenIDConnectProvider</a>.</p>
     pub client_id_list: Option<Vec<String>>,
     /// <p>The date and time when the IAM OIDC provider resource object was created in the AWS account.</p>
-    pub create_date: Option<String>,
+    pub create_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A list of certificate thumbprints that are associated with the specified IAM OIDC provider resource object. For more information, see <a>CreateOpenIDConnectProvider</a>. </p>
     pub thumbprint_list: Option<Vec<String>>,
     /// <p>The URL that the IAM OIDC provider resource object is associated with. For more information, see <a>CreateOpenIDConnectProvider</a>.</p>
@@ -3534,7 +4097,7 @@ pub struct GetOrganizationsAccessReportRequest {
     /// <p>Use this only when paginating results to indicate the maximum number of items you want in the response. If additional items exist beyond the maximum you specify, the <code>IsTruncated</code> response element is <code>true</code>.</p> <p>If you do not include this parameter, the number of items defaults to 100. Note that IAM might return fewer results, even when there are more results available. In that case, the <code>IsTruncated</code> response element returns <code>true</code>, and <code>Marker</code> contains a value to include in the subsequent call that tells the service where to continue from.</p>
     pub max_items: Option<i64>,
     /// <p>The key that is used to sort the results. If you choose the namespace key, the results are returned in alphabetical order. If you choose the time key, the results are sorted numerically by the date and time.</p>
-    pub sort_key: Option<String>,
+    pub sort_key: Option<SortKeyType>,
 }
 
 /// Serialize `GetOrganizationsAccessReportRequest` contents to a `SignedRequest`.
@@ -3568,11 +4131,11 @@ pub struct GetOrganizationsAccessReportResponse {
     /// <p>A flag that indicates whether there are more items to return. If your results were truncated, you can make a subsequent pagination request using the <code>Marker</code> request parameter to retrieve more items. Note that IAM might return fewer than the <code>MaxItems</code> number of results even when there are more results available. We recommend that you check <code>IsTruncated</code> after every call to ensure that you receive all your results.</p>
     pub is_truncated: Option<bool>,
     /// <p>The date and time, in <a href="http://www.iso.org/iso/iso8601">ISO 8601 date-time format</a>, when the generated report job was completed or failed.</p> <p>This field is null if the job is still in progress, as indicated by a job status value of <code>IN_PROGRESS</code>.</p>
-    pub job_completion_date: Option<String>,
+    pub job_completion_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The date and time, in <a href="http://www.iso.org/iso/iso8601">ISO 8601 date-time format</a>, when the report job was created.</p>
-    pub job_creation_date: String,
+    pub job_creation_date: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The status of the job.</p>
-    pub job_status: String,
+    pub job_status: JobStatusType,
     /// <p>When <code>IsTruncated</code> is <code>true</code>, this element is present and contains the value to use for the <code>Marker</code> parameter in a subsequent pagination request.</p>
     pub marker: Option<String>,
     /// <p>The number of services that the applicable SCPs allow account principals to access.</p>
@@ -3882,11 +4445,11 @@ impl GetSAMLProviderRequestSerializer {
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct GetSAMLProviderResponse {
     /// <p>The date and time when the SAML provider was created.</p>
-    pub create_date: Option<String>,
+    pub create_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The XML metadata document that includes information about an identity provider.</p>
     pub saml_metadata_document: Option<String>,
     /// <p>The expiration date and time for the SAML provider.</p>
-    pub valid_until: Option<String>,
+    pub valid_until: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 struct GetSAMLProviderResponseDeserializer;
@@ -3927,7 +4490,7 @@ impl GetSAMLProviderResponseDeserializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct GetSSHPublicKeyRequest {
     /// <p>Specifies the public key encoding format to use in the response. To retrieve the public key in ssh-rsa format, use <code>SSH</code>. To retrieve the public key in PEM format, use <code>PEM</code>.</p>
-    pub encoding: String,
+    pub encoding: EncodingType,
     /// <p>The unique identifier for the SSH public key.</p> <p>This parameter allows (through its <a href="http://wikipedia.org/wiki/regex">regex pattern</a>) a string of characters that can consist of any upper or lowercased letter or digit.</p>
     pub ssh_public_key_id: String,
     /// <p>The name of the IAM user associated with the SSH public key.</p> <p>This parameter allows (through its <a href="http://wikipedia.org/wiki/regex">regex pattern</a>) a string of characters consisting of upper and lowercase alphanumeric characters with no spaces. You can also include any of the following characters: _+=,.@-</p>
@@ -4077,11 +4640,11 @@ pub struct GetServiceLastAccessedDetailsResponse {
     /// <p><p/> <p>A flag that indicates whether there are more items to return. If your results were truncated, you can make a subsequent pagination request using the <code>Marker</code> request parameter to retrieve more items. Note that IAM might return fewer than the <code>MaxItems</code> number of results even when there are more results available. We recommend that you check <code>IsTruncated</code> after every call to ensure that you receive all your results.</p></p>
     pub is_truncated: Option<bool>,
     /// <p>The date and time, in <a href="http://www.iso.org/iso/iso8601">ISO 8601 date-time format</a>, when the generated report job was completed or failed.</p> <p>This field is null if the job is still in progress, as indicated by a job status value of <code>IN_PROGRESS</code>.</p>
-    pub job_completion_date: String,
+    pub job_completion_date: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The date and time, in <a href="http://www.iso.org/iso/iso8601">ISO 8601 date-time format</a>, when the report job was created.</p>
-    pub job_creation_date: String,
+    pub job_creation_date: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The status of the job.</p>
-    pub job_status: String,
+    pub job_status: JobStatusType,
     /// <p>When <code>IsTruncated</code> is <code>true</code>, this element is present and contains the value to use for the <code>Marker</code> parameter in a subsequent pagination request.</p>
     pub marker: Option<String>,
     /// <p> A <code>ServiceLastAccessed</code> object that contains details about the most recent attempt to access the service.</p>
@@ -4189,11 +4752,11 @@ pub struct GetServiceLastAccessedDetailsWithEntitiesResponse {
     /// <p>A flag that indicates whether there are more items to return. If your results were truncated, you can make a subsequent pagination request using the <code>Marker</code> request parameter to retrieve more items. Note that IAM might return fewer than the <code>MaxItems</code> number of results even when there are more results available. We recommend that you check <code>IsTruncated</code> after every call to ensure that you receive all your results.</p>
     pub is_truncated: Option<bool>,
     /// <p>The date and time, in <a href="http://www.iso.org/iso/iso8601">ISO 8601 date-time format</a>, when the generated report job was completed or failed.</p> <p>This field is null if the job is still in progress, as indicated by a job status value of <code>IN_PROGRESS</code>.</p>
-    pub job_completion_date: String,
+    pub job_completion_date: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The date and time, in <a href="http://www.iso.org/iso/iso8601">ISO 8601 date-time format</a>, when the report job was created.</p>
-    pub job_creation_date: String,
+    pub job_creation_date: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The status of the job.</p>
-    pub job_status: String,
+    pub job_status: JobStatusType,
     /// <p>When <code>IsTruncated</code> is <code>true</code>, this element is present and contains the value to use for the <code>Marker</code> parameter in a subsequent pagination request.</p>
     pub marker: Option<String>,
 }
@@ -4278,7 +4841,7 @@ pub struct GetServiceLinkedRoleDeletionStatusResponse {
     /// <p>An object that contains details about the reason the deletion failed.</p>
     pub reason: Option<DeletionTaskFailureReasonType>,
     /// <p>The status of the deletion.</p>
-    pub status: String,
+    pub status: DeletionTaskStatusType,
 }
 
 struct GetServiceLinkedRoleDeletionStatusResponseDeserializer;
@@ -4418,6 +4981,101 @@ impl GetUserResponseDeserializer {
         })
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum GlobalEndpointTokenVersion {
+    /// `v1Token`
+    V1Token,
+    /// `v2Token`
+    V2Token,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl GlobalEndpointTokenVersion {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            GlobalEndpointTokenVersion::V1Token => "v1Token",
+            GlobalEndpointTokenVersion::V2Token => "v2Token",
+            GlobalEndpointTokenVersion::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for GlobalEndpointTokenVersion {
+    fn default() -> GlobalEndpointTokenVersion {
+        GlobalEndpointTokenVersion::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for GlobalEndpointTokenVersion {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for GlobalEndpointTokenVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for GlobalEndpointTokenVersion {
+    fn from(value: &'a str) -> GlobalEndpointTokenVersion {
+        match value {
+            "v1Token" => GlobalEndpointTokenVersion::V1Token,
+            "v2Token" => GlobalEndpointTokenVersion::V2Token,
+            _ => GlobalEndpointTokenVersion::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for GlobalEndpointTokenVersion {
+    fn from(value: String) -> GlobalEndpointTokenVersion {
+        match value.as_str() {
+            "v1Token" => GlobalEndpointTokenVersion::V1Token,
+            "v2Token" => GlobalEndpointTokenVersion::V2Token,
+            _ => GlobalEndpointTokenVersion::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for GlobalEndpointTokenVersion {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<GlobalEndpointTokenVersion, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for GlobalEndpointTokenVersion {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for GlobalEndpointTokenVersion {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for GlobalEndpointTokenVersion {
+    fn deserialize<D>(deserializer: D) -> Result<GlobalEndpointTokenVersion, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p><p>Contains information about an IAM group entity.</p> <p>This data type is used as a response element in the following operations:</p> <ul> <li> <p> <a>CreateGroup</a> </p> </li> <li> <p> <a>GetGroup</a> </p> </li> <li> <p> <a>ListGroups</a> </p> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
@@ -4425,7 +5083,7 @@ pub struct Group {
     /// <p> The Amazon Resource Name (ARN) specifying the group. For more information about ARNs and how to use them in policies, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/Using_Identifiers.html">IAM Identifiers</a> in the <i>IAM User Guide</i>. </p>
     pub arn: String,
     /// <p>The date and time, in <a href="http://www.iso.org/iso/iso8601">ISO 8601 date-time format</a>, when the group was created.</p>
-    pub create_date: String,
+    pub create_date: ::chrono::DateTime<::chrono::Utc>,
     /// <p> The stable and unique string identifying the group. For more information about IDs, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/Using_Identifiers.html">IAM Identifiers</a> in the <i>IAM User Guide</i>. </p>
     pub group_id: String,
     /// <p>The friendly name that identifies the group.</p>
@@ -4469,7 +5127,7 @@ pub struct GroupDetail {
     /// <p>A list of the managed policies attached to the group.</p>
     pub attached_managed_policies: Option<Vec<AttachedPolicy>>,
     /// <p>The date and time, in <a href="http://www.iso.org/iso/iso8601">ISO 8601 date-time format</a>, when the group was created.</p>
-    pub create_date: Option<String>,
+    pub create_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The stable and unique string identifying the group. For more information about IDs, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/Using_Identifiers.html">IAM Identifiers</a> in the <i>IAM User Guide</i>.</p>
     pub group_id: Option<String>,
     /// <p>The friendly name that identifies the group.</p>
@@ -4604,7 +5262,7 @@ pub struct InstanceProfile {
     /// <p> The Amazon Resource Name (ARN) specifying the instance profile. For more information about ARNs and how to use them in policies, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/Using_Identifiers.html">IAM Identifiers</a> in the <i>IAM User Guide</i>. </p>
     pub arn: String,
     /// <p>The date when the instance profile was created.</p>
-    pub create_date: String,
+    pub create_date: ::chrono::DateTime<::chrono::Utc>,
     /// <p> The stable and unique string identifying the instance profile. For more information about IDs, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/Using_Identifiers.html">IAM Identifiers</a> in the <i>IAM User Guide</i>. </p>
     pub instance_profile_id: String,
     /// <p>The name identifying the instance profile.</p>
@@ -4703,12 +5361,115 @@ impl JobIDTypeDeserializer {
         Ok(obj)
     }
 }
-struct JobStatusTypeDeserializer;
-impl JobStatusTypeDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum JobStatusType {
+    /// `IN_PROGRESS`
+    InProgress,
+    /// `COMPLETED`
+    Completed,
+    /// `FAILED`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl JobStatusType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            JobStatusType::InProgress => "IN_PROGRESS",
+            JobStatusType::Completed => "COMPLETED",
+            JobStatusType::Failed => "FAILED",
+            JobStatusType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for JobStatusType {
+    fn default() -> JobStatusType {
+        JobStatusType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for JobStatusType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for JobStatusType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for JobStatusType {
+    fn from(value: &'a str) -> JobStatusType {
+        match value {
+            "IN_PROGRESS" => JobStatusType::InProgress,
+            "COMPLETED" => JobStatusType::Completed,
+            "FAILED" => JobStatusType::Failed,
+            _ => JobStatusType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for JobStatusType {
+    fn from(value: String) -> JobStatusType {
+        match value.as_str() {
+            "IN_PROGRESS" => JobStatusType::InProgress,
+            "COMPLETED" => JobStatusType::Completed,
+            "FAILED" => JobStatusType::Failed,
+            _ => JobStatusType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for JobStatusType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<JobStatusType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for JobStatusType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for JobStatusType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for JobStatusType {
+    fn deserialize<D>(deserializer: D) -> Result<JobStatusType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+struct JobStatusTypeDeserializer;
+impl JobStatusTypeDeserializer {
+    #[allow(unused_variables)]
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<JobStatusType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -5128,7 +5889,7 @@ impl ListAttachedUserPoliciesResponseDeserializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ListEntitiesForPolicyRequest {
     /// <p>The entity type to use for filtering the results.</p> <p>For example, when <code>EntityFilter</code> is <code>Role</code>, only the roles that are attached to the specified policy are returned. This parameter is optional. If it is not included, all attached entities (users, groups, and roles) are returned. The argument for this parameter must be one of the valid values listed below.</p>
-    pub entity_filter: Option<String>,
+    pub entity_filter: Option<EntityType>,
     /// <p>Use this parameter only when paginating results and only after you receive a response indicating that the results are truncated. Set it to the value of the <code>Marker</code> element in the response that you received to indicate where the next call should start.</p>
     pub marker: Option<String>,
     /// <p>Use this only when paginating results to indicate the maximum number of items you want in the response. If additional items exist beyond the maximum you specify, the <code>IsTruncated</code> response element is <code>true</code>.</p> <p>If you do not include this parameter, the number of items defaults to 100. Note that IAM might return fewer results, even when there are more results available. In that case, the <code>IsTruncated</code> response element returns <code>true</code>, and <code>Marker</code> contains a value to include in the subsequent call that tells the service where to continue from.</p>
@@ -5138,7 +5899,7 @@ pub struct ListEntitiesForPolicyRequest {
     /// <p>The Amazon Resource Name (ARN) of the IAM policy for which you want the versions.</p> <p>For more information about ARNs, see <a href="https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and AWS Service Namespaces</a> in the <i>AWS General Reference</i>.</p>
     pub policy_arn: String,
     /// <p>The policy usage method to use for filtering the results.</p> <p>To list only permissions policies, set <code>PolicyUsageFilter</code> to <code>PermissionsPolicy</code>. To list only the policies used to set permissions boundaries, set the value to <code>PermissionsBoundary</code>.</p> <p>This parameter is optional. If it is not included, all policies are returned. </p>
-    pub policy_usage_filter: Option<String>,
+    pub policy_usage_filter: Option<PolicyUsageType>,
 }
 
 /// Serialize `ListEntitiesForPolicyRequest` contents to a `SignedRequest`.
@@ -5863,9 +6624,9 @@ pub struct ListPoliciesRequest {
     /// <p>The path prefix for filtering the results. This parameter is optional. If it is not included, it defaults to a slash (/), listing all policies. This parameter allows (through its <a href="http://wikipedia.org/wiki/regex">regex pattern</a>) a string of characters consisting of either a forward slash (/) by itself or a string that must begin and end with forward slashes. In addition, it can contain any ASCII character from the ! (<code>\u0021</code>) through the DEL character (<code>\u007F</code>), including most punctuation characters, digits, and upper and lowercased letters.</p>
     pub path_prefix: Option<String>,
     /// <p>The policy usage method to use for filtering the results.</p> <p>To list only permissions policies, set <code>PolicyUsageFilter</code> to <code>PermissionsPolicy</code>. To list only the policies used to set permissions boundaries, set the value to <code>PermissionsBoundary</code>.</p> <p>This parameter is optional. If it is not included, all policies are returned. </p>
-    pub policy_usage_filter: Option<String>,
+    pub policy_usage_filter: Option<PolicyUsageType>,
     /// <p>The scope to use for filtering the results.</p> <p>To list only AWS managed policies, set <code>Scope</code> to <code>AWS</code>. To list only the customer managed policies in your AWS account, set <code>Scope</code> to <code>Local</code>.</p> <p>This parameter is optional. If it is not included, or if it is set to <code>All</code>, all policies are returned.</p>
-    pub scope: Option<String>,
+    pub scope: Option<PolicyScopeType>,
 }
 
 /// Serialize `ListPoliciesRequest` contents to a `SignedRequest`.
@@ -6824,7 +7585,7 @@ impl ListUsersResponseDeserializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ListVirtualMFADevicesRequest {
     /// <p> The status (<code>Unassigned</code> or <code>Assigned</code>) of the devices to list. If you do not specify an <code>AssignmentStatus</code>, the operation defaults to <code>Any</code>, which lists both assigned and unassigned virtual MFA devices.,</p>
-    pub assignment_status: Option<String>,
+    pub assignment_status: Option<AssignmentStatusType>,
     /// <p>Use this parameter only when paginating results and only after you receive a response indicating that the results are truncated. Set it to the value of the <code>Marker</code> element in the response that you received to indicate where the next call should start.</p>
     pub marker: Option<String>,
     /// <p>Use this only when paginating results to indicate the maximum number of items you want in the response. If additional items exist beyond the maximum you specify, the <code>IsTruncated</code> response element is <code>true</code>.</p> <p>If you do not include this parameter, the number of items defaults to 100. Note that IAM might return fewer results, even when there are more results available. In that case, the <code>IsTruncated</code> response element returns <code>true</code>, and <code>Marker</code> contains a value to include in the subsequent call that tells the service where to continue from.</p>
@@ -6905,7 +7666,7 @@ impl ListVirtualMFADevicesResponseDeserializer {
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct LoginProfile {
     /// <p>The date when the password for the user was created.</p>
-    pub create_date: String,
+    pub create_date: ::chrono::DateTime<::chrono::Utc>,
     /// <p>Specifies whether the user is required to set a new password on next sign-in.</p>
     pub password_reset_required: Option<bool>,
     /// <p>The name of the user, which can be used for signing in to the AWS Management Console.</p>
@@ -6944,7 +7705,7 @@ impl LoginProfileDeserializer {
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct MFADevice {
     /// <p>The date when the MFA device was enabled for the user.</p>
-    pub enable_date: String,
+    pub enable_date: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The serial number that uniquely identifies the MFA device. For virtual MFA devices, the serial number is the device ARN.</p>
     pub serial_number: String,
     /// <p>The user with whom the MFA device is associated.</p>
@@ -6984,7 +7745,7 @@ pub struct ManagedPolicyDetail {
     /// <p>The number of principal entities (users, groups, and roles) that the policy is attached to.</p>
     pub attachment_count: Option<i64>,
     /// <p>The date and time, in <a href="http://www.iso.org/iso/iso8601">ISO 8601 date-time format</a>, when the policy was created.</p>
-    pub create_date: Option<String>,
+    pub create_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The identifier for the version of the policy that is set as the default (operative) version.</p> <p>For more information about policy versions, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/policies-managed-versions.html">Versioning for Managed Policies</a> in the <i>IAM User Guide</i>. </p>
     pub default_version_id: Option<String>,
     /// <p>A friendly description of the policy.</p>
@@ -7002,7 +7763,7 @@ pub struct ManagedPolicyDetail {
     /// <p>A list containing information about the versions of the policy.</p>
     pub policy_version_list: Option<Vec<PolicyVersion>>,
     /// <p>The date and time, in <a href="http://www.iso.org/iso/iso8601">ISO 8601 date-time format</a>, when the policy was last updated.</p> <p>When a policy has only one version, this field contains the date and time when the policy was created. When a policy has more than one version, this field contains the date and time when the most recent policy version was created.</p>
-    pub update_date: Option<String>,
+    pub update_date: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 struct ManagedPolicyDetailDeserializer;
@@ -7374,12 +8135,111 @@ impl PathTypeDeserializer {
         Ok(obj)
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PermissionsBoundaryAttachmentType {
+    /// `PermissionsBoundaryPolicy`
+    PermissionsBoundaryPolicy,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PermissionsBoundaryAttachmentType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PermissionsBoundaryAttachmentType::PermissionsBoundaryPolicy => {
+                "PermissionsBoundaryPolicy"
+            }
+            PermissionsBoundaryAttachmentType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PermissionsBoundaryAttachmentType {
+    fn default() -> PermissionsBoundaryAttachmentType {
+        PermissionsBoundaryAttachmentType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PermissionsBoundaryAttachmentType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PermissionsBoundaryAttachmentType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PermissionsBoundaryAttachmentType {
+    fn from(value: &'a str) -> PermissionsBoundaryAttachmentType {
+        match value {
+            "PermissionsBoundaryPolicy" => {
+                PermissionsBoundaryAttachmentType::PermissionsBoundaryPolicy
+            }
+            _ => PermissionsBoundaryAttachmentType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PermissionsBoundaryAttachmentType {
+    fn from(value: String) -> PermissionsBoundaryAttachmentType {
+        match value.as_str() {
+            "PermissionsBoundaryPolicy" => {
+                PermissionsBoundaryAttachmentType::PermissionsBoundaryPolicy
+            }
+            _ => PermissionsBoundaryAttachmentType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PermissionsBoundaryAttachmentType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PermissionsBoundaryAttachmentType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PermissionsBoundaryAttachmentType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for PermissionsBoundaryAttachmentType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for PermissionsBoundaryAttachmentType {
+    fn deserialize<D>(deserializer: D) -> Result<PermissionsBoundaryAttachmentType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct PermissionsBoundaryAttachmentTypeDeserializer;
 impl PermissionsBoundaryAttachmentTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<PermissionsBoundaryAttachmentType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -7427,7 +8287,7 @@ pub struct Policy {
     /// <p>The number of entities (users, groups, and roles) that the policy is attached to.</p>
     pub attachment_count: Option<i64>,
     /// <p>The date and time, in <a href="http://www.iso.org/iso/iso8601">ISO 8601 date-time format</a>, when the policy was created.</p>
-    pub create_date: Option<String>,
+    pub create_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The identifier for the version of the policy that is set as the default version.</p>
     pub default_version_id: Option<String>,
     /// <p>A friendly description of the policy.</p> <p>This element is included in the response to the <a>GetPolicy</a> operation. It is not included in the response to the <a>ListPolicies</a> operation. </p>
@@ -7443,7 +8303,7 @@ pub struct Policy {
     /// <p>The friendly name (not ARN) identifying the policy.</p>
     pub policy_name: Option<String>,
     /// <p>The date and time, in <a href="http://www.iso.org/iso/iso8601">ISO 8601 date-time format</a>, when the policy was last updated.</p> <p>When a policy has only one version, this field contains the date and time when the policy was created. When a policy has more than one version, this field contains the date and time when the most recent policy version was created.</p>
-    pub update_date: Option<String>,
+    pub update_date: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 struct PolicyDeserializer;
@@ -7601,12 +8461,115 @@ impl PolicyDocumentVersionListTypeDeserializer {
         })
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PolicyEvaluationDecisionType {
+    /// `allowed`
+    Allowed,
+    /// `explicitDeny`
+    ExplicitDeny,
+    /// `implicitDeny`
+    ImplicitDeny,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PolicyEvaluationDecisionType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PolicyEvaluationDecisionType::Allowed => "allowed",
+            PolicyEvaluationDecisionType::ExplicitDeny => "explicitDeny",
+            PolicyEvaluationDecisionType::ImplicitDeny => "implicitDeny",
+            PolicyEvaluationDecisionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PolicyEvaluationDecisionType {
+    fn default() -> PolicyEvaluationDecisionType {
+        PolicyEvaluationDecisionType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PolicyEvaluationDecisionType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PolicyEvaluationDecisionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PolicyEvaluationDecisionType {
+    fn from(value: &'a str) -> PolicyEvaluationDecisionType {
+        match value {
+            "allowed" => PolicyEvaluationDecisionType::Allowed,
+            "explicitDeny" => PolicyEvaluationDecisionType::ExplicitDeny,
+            "implicitDeny" => PolicyEvaluationDecisionType::ImplicitDeny,
+            _ => PolicyEvaluationDecisionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PolicyEvaluationDecisionType {
+    fn from(value: String) -> PolicyEvaluationDecisionType {
+        match value.as_str() {
+            "allowed" => PolicyEvaluationDecisionType::Allowed,
+            "explicitDeny" => PolicyEvaluationDecisionType::ExplicitDeny,
+            "implicitDeny" => PolicyEvaluationDecisionType::ImplicitDeny,
+            _ => PolicyEvaluationDecisionType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PolicyEvaluationDecisionType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PolicyEvaluationDecisionType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PolicyEvaluationDecisionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for PolicyEvaluationDecisionType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for PolicyEvaluationDecisionType {
+    fn deserialize<D>(deserializer: D) -> Result<PolicyEvaluationDecisionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct PolicyEvaluationDecisionTypeDeserializer;
 impl PolicyEvaluationDecisionTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<PolicyEvaluationDecisionType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -7619,12 +8582,12 @@ pub struct PolicyGrantingServiceAccess {
     /// <p>The name of the entity (user or role) to which the inline policy is attached.</p> <p>This field is null for managed policies. For more information about these policy types, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/access_policies_managed-vs-inline.html">Managed Policies and Inline Policies</a> in the <i>IAM User Guide</i>.</p>
     pub entity_name: Option<String>,
     /// <p>The type of entity (user or role) that used the policy to access the service to which the inline policy is attached.</p> <p>This field is null for managed policies. For more information about these policy types, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/access_policies_managed-vs-inline.html">Managed Policies and Inline Policies</a> in the <i>IAM User Guide</i>.</p>
-    pub entity_type: Option<String>,
+    pub entity_type: Option<PolicyOwnerEntityType>,
     pub policy_arn: Option<String>,
     /// <p>The policy name.</p>
     pub policy_name: String,
     /// <p>The policy type. For more information about these policy types, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/access_policies_managed-vs-inline.html">Managed Policies and Inline Policies</a> in the <i>IAM User Guide</i>.</p>
-    pub policy_type: String,
+    pub policy_type: PolicyType,
 }
 
 struct PolicyGrantingServiceAccessDeserializer;
@@ -7793,12 +8756,115 @@ impl PolicyNameTypeDeserializer {
         Ok(obj)
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PolicyOwnerEntityType {
+    /// `USER`
+    User,
+    /// `ROLE`
+    Role,
+    /// `GROUP`
+    Group,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PolicyOwnerEntityType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PolicyOwnerEntityType::User => "USER",
+            PolicyOwnerEntityType::Role => "ROLE",
+            PolicyOwnerEntityType::Group => "GROUP",
+            PolicyOwnerEntityType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PolicyOwnerEntityType {
+    fn default() -> PolicyOwnerEntityType {
+        PolicyOwnerEntityType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PolicyOwnerEntityType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PolicyOwnerEntityType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PolicyOwnerEntityType {
+    fn from(value: &'a str) -> PolicyOwnerEntityType {
+        match value {
+            "USER" => PolicyOwnerEntityType::User,
+            "ROLE" => PolicyOwnerEntityType::Role,
+            "GROUP" => PolicyOwnerEntityType::Group,
+            _ => PolicyOwnerEntityType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PolicyOwnerEntityType {
+    fn from(value: String) -> PolicyOwnerEntityType {
+        match value.as_str() {
+            "USER" => PolicyOwnerEntityType::User,
+            "ROLE" => PolicyOwnerEntityType::Role,
+            "GROUP" => PolicyOwnerEntityType::Group,
+            _ => PolicyOwnerEntityType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PolicyOwnerEntityType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PolicyOwnerEntityType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PolicyOwnerEntityType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for PolicyOwnerEntityType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for PolicyOwnerEntityType {
+    fn deserialize<D>(deserializer: D) -> Result<PolicyOwnerEntityType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct PolicyOwnerEntityTypeDeserializer;
 impl PolicyOwnerEntityTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<PolicyOwnerEntityType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -7863,72 +8929,489 @@ impl PolicyRoleListTypeDeserializer {
         })
     }
 }
-struct PolicySourceTypeDeserializer;
-impl PolicySourceTypeDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
-        start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
-        end_element(tag_name, stack)?;
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PolicyScopeType {
+    /// `All`
+    All,
+    /// `AWS`
+    Aws,
+    /// `Local`
+    Local,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
 
-        Ok(obj)
+impl PolicyScopeType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PolicyScopeType::All => "All",
+            PolicyScopeType::Aws => "AWS",
+            PolicyScopeType::Local => "Local",
+            PolicyScopeType::Unknown(ref value) => value,
+        }
     }
 }
-struct PolicyTypeDeserializer;
-impl PolicyTypeDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
-        start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
-        end_element(tag_name, stack)?;
 
-        Ok(obj)
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PolicyScopeType {
+    fn default() -> PolicyScopeType {
+        PolicyScopeType::Unknown(String::new())
     }
 }
-/// <p>Contains information about a user that a managed policy is attached to.</p> <p>This data type is used as a response element in the <a>ListEntitiesForPolicy</a> operation. </p> <p>For more information about managed policies, refer to <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/policies-managed-vs-inline.html">Managed Policies and Inline Policies</a> in the <i>IAM User Guide</i>. </p>
-#[derive(Default, Debug, Clone, PartialEq)]
-#[cfg_attr(feature = "serialize_structs", derive(Serialize))]
-pub struct PolicyUser {
-    /// <p>The stable and unique string identifying the user. For more information about IDs, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/reference_identifiers.html">IAM Identifiers</a> in the <i>IAM User Guide</i>.</p>
-    pub user_id: Option<String>,
-    /// <p>The name (friendly name, not ARN) identifying the user.</p>
-    pub user_name: Option<String>,
+
+impl AsRef<str> for PolicyScopeType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
 }
 
-struct PolicyUserDeserializer;
-impl PolicyUserDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
-        tag_name: &str,
-        stack: &mut T,
-    ) -> Result<PolicyUser, XmlParseError> {
-        deserialize_elements::<_, PolicyUser, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "UserId" => {
-                    obj.user_id = Some(IdTypeDeserializer::deserialize("UserId", stack)?);
-                }
-                "UserName" => {
-                    obj.user_name = Some(UserNameTypeDeserializer::deserialize("UserName", stack)?);
-                }
-                _ => skip_tree(stack),
-            }
-            Ok(())
-        })
+impl fmt::Display for PolicyScopeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
     }
 }
-struct PolicyUserListTypeDeserializer;
-impl PolicyUserListTypeDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
-        tag_name: &str,
-        stack: &mut T,
-    ) -> Result<Vec<PolicyUser>, XmlParseError> {
-        deserialize_elements::<_, Vec<_>, _>(tag_name, stack, |name, stack, obj| {
-            if name == "member" {
-                obj.push(PolicyUserDeserializer::deserialize("member", stack)?);
-            } else {
-                skip_tree(stack);
-            }
+
+impl<'a> From<&'a str> for PolicyScopeType {
+    fn from(value: &'a str) -> PolicyScopeType {
+        match value {
+            "All" => PolicyScopeType::All,
+            "AWS" => PolicyScopeType::Aws,
+            "Local" => PolicyScopeType::Local,
+            _ => PolicyScopeType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PolicyScopeType {
+    fn from(value: String) -> PolicyScopeType {
+        match value.as_str() {
+            "All" => PolicyScopeType::All,
+            "AWS" => PolicyScopeType::Aws,
+            "Local" => PolicyScopeType::Local,
+            _ => PolicyScopeType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PolicyScopeType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PolicyScopeType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PolicyScopeType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for PolicyScopeType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for PolicyScopeType {
+    fn deserialize<D>(deserializer: D) -> Result<PolicyScopeType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PolicySourceType {
+    /// `user`
+    User,
+    /// `group`
+    Group,
+    /// `role`
+    Role,
+    /// `aws-managed`
+    AwsManaged,
+    /// `user-managed`
+    UserManaged,
+    /// `resource`
+    Resource,
+    /// `none`
+    None,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PolicySourceType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PolicySourceType::User => "user",
+            PolicySourceType::Group => "group",
+            PolicySourceType::Role => "role",
+            PolicySourceType::AwsManaged => "aws-managed",
+            PolicySourceType::UserManaged => "user-managed",
+            PolicySourceType::Resource => "resource",
+            PolicySourceType::None => "none",
+            PolicySourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PolicySourceType {
+    fn default() -> PolicySourceType {
+        PolicySourceType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PolicySourceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PolicySourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PolicySourceType {
+    fn from(value: &'a str) -> PolicySourceType {
+        match value {
+            "user" => PolicySourceType::User,
+            "group" => PolicySourceType::Group,
+            "role" => PolicySourceType::Role,
+            "aws-managed" => PolicySourceType::AwsManaged,
+            "user-managed" => PolicySourceType::UserManaged,
+            "resource" => PolicySourceType::Resource,
+            "none" => PolicySourceType::None,
+            _ => PolicySourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PolicySourceType {
+    fn from(value: String) -> PolicySourceType {
+        match value.as_str() {
+            "user" => PolicySourceType::User,
+            "group" => PolicySourceType::Group,
+            "role" => PolicySourceType::Role,
+            "aws-managed" => PolicySourceType::AwsManaged,
+            "user-managed" => PolicySourceType::UserManaged,
+            "resource" => PolicySourceType::Resource,
+            "none" => PolicySourceType::None,
+            _ => PolicySourceType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PolicySourceType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PolicySourceType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PolicySourceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for PolicySourceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for PolicySourceType {
+    fn deserialize<D>(deserializer: D) -> Result<PolicySourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+struct PolicySourceTypeDeserializer;
+impl PolicySourceTypeDeserializer {
+    #[allow(unused_variables)]
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<PolicySourceType, XmlParseError> {
+        start_element(tag_name, stack)?;
+        let obj = characters(stack)?.into();
+        end_element(tag_name, stack)?;
+
+        Ok(obj)
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PolicyType {
+    /// `INLINE`
+    Inline,
+    /// `MANAGED`
+    Managed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PolicyType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PolicyType::Inline => "INLINE",
+            PolicyType::Managed => "MANAGED",
+            PolicyType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PolicyType {
+    fn default() -> PolicyType {
+        PolicyType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PolicyType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PolicyType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PolicyType {
+    fn from(value: &'a str) -> PolicyType {
+        match value {
+            "INLINE" => PolicyType::Inline,
+            "MANAGED" => PolicyType::Managed,
+            _ => PolicyType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PolicyType {
+    fn from(value: String) -> PolicyType {
+        match value.as_str() {
+            "INLINE" => PolicyType::Inline,
+            "MANAGED" => PolicyType::Managed,
+            _ => PolicyType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PolicyType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PolicyType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PolicyType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for PolicyType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for PolicyType {
+    fn deserialize<D>(deserializer: D) -> Result<PolicyType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+struct PolicyTypeDeserializer;
+impl PolicyTypeDeserializer {
+    #[allow(unused_variables)]
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<PolicyType, XmlParseError> {
+        start_element(tag_name, stack)?;
+        let obj = characters(stack)?.into();
+        end_element(tag_name, stack)?;
+
+        Ok(obj)
+    }
+}
+/// <p>The policy usage type that indicates whether the policy is used as a permissions policy or as the permissions boundary for an entity.</p> <p>For more information about permissions boundaries, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/access_policies_boundaries.html">Permissions boundaries for IAM identities </a> in the <i>IAM User Guide</i>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PolicyUsageType {
+    /// `PermissionsPolicy`
+    PermissionsPolicy,
+    /// `PermissionsBoundary`
+    PermissionsBoundary,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PolicyUsageType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PolicyUsageType::PermissionsPolicy => "PermissionsPolicy",
+            PolicyUsageType::PermissionsBoundary => "PermissionsBoundary",
+            PolicyUsageType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PolicyUsageType {
+    fn default() -> PolicyUsageType {
+        PolicyUsageType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PolicyUsageType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PolicyUsageType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PolicyUsageType {
+    fn from(value: &'a str) -> PolicyUsageType {
+        match value {
+            "PermissionsPolicy" => PolicyUsageType::PermissionsPolicy,
+            "PermissionsBoundary" => PolicyUsageType::PermissionsBoundary,
+            _ => PolicyUsageType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PolicyUsageType {
+    fn from(value: String) -> PolicyUsageType {
+        match value.as_str() {
+            "PermissionsPolicy" => PolicyUsageType::PermissionsPolicy,
+            "PermissionsBoundary" => PolicyUsageType::PermissionsBoundary,
+            _ => PolicyUsageType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PolicyUsageType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PolicyUsageType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PolicyUsageType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for PolicyUsageType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for PolicyUsageType {
+    fn deserialize<D>(deserializer: D) -> Result<PolicyUsageType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Contains information about a user that a managed policy is attached to.</p> <p>This data type is used as a response element in the <a>ListEntitiesForPolicy</a> operation. </p> <p>For more information about managed policies, refer to <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/policies-managed-vs-inline.html">Managed Policies and Inline Policies</a> in the <i>IAM User Guide</i>. </p>
+#[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize_structs", derive(Serialize))]
+pub struct PolicyUser {
+    /// <p>The stable and unique string identifying the user. For more information about IDs, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/reference_identifiers.html">IAM Identifiers</a> in the <i>IAM User Guide</i>.</p>
+    pub user_id: Option<String>,
+    /// <p>The name (friendly name, not ARN) identifying the user.</p>
+    pub user_name: Option<String>,
+}
+
+struct PolicyUserDeserializer;
+impl PolicyUserDeserializer {
+    #[allow(unused_variables)]
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<PolicyUser, XmlParseError> {
+        deserialize_elements::<_, PolicyUser, _>(tag_name, stack, |name, stack, obj| {
+            match name {
+                "UserId" => {
+                    obj.user_id = Some(IdTypeDeserializer::deserialize("UserId", stack)?);
+                }
+                "UserName" => {
+                    obj.user_name = Some(UserNameTypeDeserializer::deserialize("UserName", stack)?);
+                }
+                _ => skip_tree(stack),
+            }
+            Ok(())
+        })
+    }
+}
+struct PolicyUserListTypeDeserializer;
+impl PolicyUserListTypeDeserializer {
+    #[allow(unused_variables)]
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Vec<PolicyUser>, XmlParseError> {
+        deserialize_elements::<_, Vec<_>, _>(tag_name, stack, |name, stack, obj| {
+            if name == "member" {
+                obj.push(PolicyUserDeserializer::deserialize("member", stack)?);
+            } else {
+                skip_tree(stack);
+            }
             Ok(())
         })
     }
@@ -7938,7 +9421,7 @@ impl PolicyUserListTypeDeserializer {
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct PolicyVersion {
     /// <p>The date and time, in <a href="http://www.iso.org/iso/iso8601">ISO 8601 date-time format</a>, when the policy version was created.</p>
-    pub create_date: Option<String>,
+    pub create_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The policy document.</p> <p>The policy document is returned in the response to the <a>GetPolicyVersion</a> and <a>GetAccountAuthorizationDetails</a> operations. It is not returned in the response to the <a>CreatePolicyVersion</a> or <a>ListPolicyVersions</a> operations. </p> <p>The policy document returned in this structure is URL-encoded compliant with <a href="https://tools.ietf.org/html/rfc3986">RFC 3986</a>. You can use a URL decoding method to convert the policy back to plain JSON text. For example, if you use Java, you can use the <code>decode</code> method of the <code>java.net.URLDecoder</code> utility class in the Java SDK. Other languages and SDKs provide similar functionality.</p>
     pub document: Option<String>,
     /// <p>Specifies whether the policy version is set as the policy's default version.</p>
@@ -8311,53 +9794,249 @@ impl ReportContentTypeDeserializer {
         Ok(obj)
     }
 }
-struct ReportFormatTypeDeserializer;
-impl ReportFormatTypeDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
-        start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
-        end_element(tag_name, stack)?;
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ReportFormatType {
+    /// `text/csv`
+    TextCsv,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
 
-        Ok(obj)
+impl ReportFormatType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReportFormatType::TextCsv => "text/csv",
+            ReportFormatType::Unknown(ref value) => value,
+        }
     }
 }
-struct ReportStateDescriptionTypeDeserializer;
-impl ReportStateDescriptionTypeDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
-        start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
-        end_element(tag_name, stack)?;
 
-        Ok(obj)
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ReportFormatType {
+    fn default() -> ReportFormatType {
+        ReportFormatType::Unknown(String::new())
     }
 }
-struct ReportStateTypeDeserializer;
-impl ReportStateTypeDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
-        start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
-        end_element(tag_name, stack)?;
 
-        Ok(obj)
+impl AsRef<str> for ReportFormatType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
     }
 }
-#[derive(Default, Debug, Clone, PartialEq)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct ResetServiceSpecificCredentialRequest {
-    /// <p>The unique identifier of the service-specific credential.</p> <p>This parameter allows (through its <a href="http://wikipedia.org/wiki/regex">regex pattern</a>) a string of characters that can consist of any upper or lowercased letter or digit.</p>
-    pub service_specific_credential_id: String,
-    /// <p>The name of the IAM user associated with the service-specific credential. If this value is not specified, then the operation assumes the user whose credentials are used to call the operation.</p> <p>This parameter allows (through its <a href="http://wikipedia.org/wiki/regex">regex pattern</a>) a string of characters consisting of upper and lowercase alphanumeric characters with no spaces. You can also include any of the following characters: _+=,.@-</p>
-    pub user_name: Option<String>,
+
+impl fmt::Display for ReportFormatType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
-/// Serialize `ResetServiceSpecificCredentialRequest` contents to a `SignedRequest`.
-struct ResetServiceSpecificCredentialRequestSerializer;
-impl ResetServiceSpecificCredentialRequestSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &ResetServiceSpecificCredentialRequest) {
-        let mut prefix = name.to_string();
+impl<'a> From<&'a str> for ReportFormatType {
+    fn from(value: &'a str) -> ReportFormatType {
+        match value {
+            "text/csv" => ReportFormatType::TextCsv,
+            _ => ReportFormatType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReportFormatType {
+    fn from(value: String) -> ReportFormatType {
+        match value.as_str() {
+            "text/csv" => ReportFormatType::TextCsv,
+            _ => ReportFormatType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ReportFormatType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ReportFormatType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ReportFormatType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ReportFormatType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ReportFormatType {
+    fn deserialize<D>(deserializer: D) -> Result<ReportFormatType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+struct ReportFormatTypeDeserializer;
+impl ReportFormatTypeDeserializer {
+    #[allow(unused_variables)]
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ReportFormatType, XmlParseError> {
+        start_element(tag_name, stack)?;
+        let obj = characters(stack)?.into();
+        end_element(tag_name, stack)?;
+
+        Ok(obj)
+    }
+}
+struct ReportStateDescriptionTypeDeserializer;
+impl ReportStateDescriptionTypeDeserializer {
+    #[allow(unused_variables)]
+    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+        start_element(tag_name, stack)?;
+        let obj = characters(stack)?;
+        end_element(tag_name, stack)?;
+
+        Ok(obj)
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ReportStateType {
+    /// `STARTED`
+    Started,
+    /// `INPROGRESS`
+    Inprogress,
+    /// `COMPLETE`
+    Complete,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ReportStateType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReportStateType::Started => "STARTED",
+            ReportStateType::Inprogress => "INPROGRESS",
+            ReportStateType::Complete => "COMPLETE",
+            ReportStateType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ReportStateType {
+    fn default() -> ReportStateType {
+        ReportStateType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ReportStateType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ReportStateType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ReportStateType {
+    fn from(value: &'a str) -> ReportStateType {
+        match value {
+            "STARTED" => ReportStateType::Started,
+            "INPROGRESS" => ReportStateType::Inprogress,
+            "COMPLETE" => ReportStateType::Complete,
+            _ => ReportStateType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReportStateType {
+    fn from(value: String) -> ReportStateType {
+        match value.as_str() {
+            "STARTED" => ReportStateType::Started,
+            "INPROGRESS" => ReportStateType::Inprogress,
+            "COMPLETE" => ReportStateType::Complete,
+            _ => ReportStateType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ReportStateType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ReportStateType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ReportStateType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ReportStateType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ReportStateType {
+    fn deserialize<D>(deserializer: D) -> Result<ReportStateType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+struct ReportStateTypeDeserializer;
+impl ReportStateTypeDeserializer {
+    #[allow(unused_variables)]
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ReportStateType, XmlParseError> {
+        start_element(tag_name, stack)?;
+        let obj = characters(stack)?.into();
+        end_element(tag_name, stack)?;
+
+        Ok(obj)
+    }
+}
+#[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct ResetServiceSpecificCredentialRequest {
+    /// <p>The unique identifier of the service-specific credential.</p> <p>This parameter allows (through its <a href="http://wikipedia.org/wiki/regex">regex pattern</a>) a string of characters that can consist of any upper or lowercased letter or digit.</p>
+    pub service_specific_credential_id: String,
+    /// <p>The name of the IAM user associated with the service-specific credential. If this value is not specified, then the operation assumes the user whose credentials are used to call the operation.</p> <p>This parameter allows (through its <a href="http://wikipedia.org/wiki/regex">regex pattern</a>) a string of characters consisting of upper and lowercase alphanumeric characters with no spaces. You can also include any of the following characters: _+=,.@-</p>
+    pub user_name: Option<String>,
+}
+
+/// Serialize `ResetServiceSpecificCredentialRequest` contents to a `SignedRequest`.
+struct ResetServiceSpecificCredentialRequestSerializer;
+impl ResetServiceSpecificCredentialRequestSerializer {
+    fn serialize(params: &mut Params, name: &str, obj: &ResetServiceSpecificCredentialRequest) {
+        let mut prefix = name.to_string();
         if prefix != "" {
             prefix.push_str(".");
         }
@@ -8433,9 +10112,10 @@ impl ResourceNameTypeDeserializer {
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct ResourceSpecificResult {
     /// <p>Additional details about the results of the evaluation decision on a single resource. This parameter is returned only for cross-account simulations. This parameter explains how each policy type contributes to the resource-specific evaluation decision.</p>
-    pub eval_decision_details: Option<::std::collections::HashMap<String, String>>,
+    pub eval_decision_details:
+        Option<::std::collections::HashMap<String, PolicyEvaluationDecisionType>>,
     /// <p>The result of the simulation of the simulated API operation on the resource specified in <code>EvalResourceName</code>.</p>
-    pub eval_resource_decision: String,
+    pub eval_resource_decision: PolicyEvaluationDecisionType,
     /// <p>The name of the simulated resource, in Amazon Resource Name (ARN) format.</p>
     pub eval_resource_name: String,
     /// <p>A list of the statements in the input policies that determine the result for this part of the simulation. Remember that even if multiple statements allow the operation on the resource, if <i>any</i> statement denies that operation, then the explicit deny overrides any allow. In addition, the deny statement is the only entry included in the result.</p>
@@ -8573,7 +10253,7 @@ pub struct Role {
     /// <p>The policy that grants an entity permission to assume the role.</p>
     pub assume_role_policy_document: Option<String>,
     /// <p>The date and time, in <a href="http://www.iso.org/iso/iso8601">ISO 8601 date-time format</a>, when the role was created.</p>
-    pub create_date: String,
+    pub create_date: ::chrono::DateTime<::chrono::Utc>,
     /// <p>A description of the role that you provide.</p>
     pub description: Option<String>,
     /// <p>The maximum session duration (in seconds) for the specified role. Anyone who uses the AWS CLI, or API to assume the role can specify the duration using the optional <code>DurationSeconds</code> API parameter or <code>duration-seconds</code> CLI parameter.</p>
@@ -8678,7 +10358,7 @@ pub struct RoleDetail {
     /// <p>A list of managed policies attached to the role. These policies are the role's access (permissions) policies.</p>
     pub attached_managed_policies: Option<Vec<AttachedPolicy>>,
     /// <p>The date and time, in <a href="http://www.iso.org/iso/iso8601">ISO 8601 date-time format</a>, when the role was created.</p>
-    pub create_date: Option<String>,
+    pub create_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A list of instance profiles that contain this role.</p>
     pub instance_profile_list: Option<Vec<InstanceProfile>>,
     /// <p>The path to the role. For more information about paths, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/Using_Identifiers.html">IAM Identifiers</a> in the <i>IAM User Guide</i>.</p>
@@ -8795,7 +10475,7 @@ impl RoleDetailListTypeDeserializer {
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct RoleLastUsed {
     /// <p>The date and time, in <a href="http://www.iso.org/iso/iso8601">ISO 8601 date-time format</a> that the role was last used.</p> <p>This field is null if the role has not been used within the IAM tracking period. For more information about the tracking period, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/access_policies_access-advisor.html#access-advisor_tracking-period">Regions Where Data Is Tracked</a> in the <i>IAM User Guide</i>. </p>
-    pub last_used_date: Option<String>,
+    pub last_used_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the AWS Region in which the role was last used.</p>
     pub region: Option<String>,
 }
@@ -8929,9 +10609,9 @@ pub struct SAMLProviderListEntry {
     /// <p>The Amazon Resource Name (ARN) of the SAML provider.</p>
     pub arn: Option<String>,
     /// <p>The date and time when the SAML provider was created.</p>
-    pub create_date: Option<String>,
+    pub create_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The expiration date and time for the SAML provider.</p>
-    pub valid_until: Option<String>,
+    pub valid_until: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 struct SAMLProviderListEntryDeserializer;
@@ -8988,9 +10668,9 @@ pub struct SSHPublicKey {
     /// <p>The unique identifier for the SSH public key.</p>
     pub ssh_public_key_id: String,
     /// <p>The status of the SSH public key. <code>Active</code> means that the key can be used for authentication with an AWS CodeCommit repository. <code>Inactive</code> means that the key cannot be used.</p>
-    pub status: String,
+    pub status: StatusType,
     /// <p>The date and time, in <a href="http://www.iso.org/iso/iso8601">ISO 8601 date-time format</a>, when the SSH public key was uploaded.</p>
-    pub upload_date: Option<String>,
+    pub upload_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the IAM user associated with the SSH public key.</p>
     pub user_name: String,
 }
@@ -9057,9 +10737,9 @@ pub struct SSHPublicKeyMetadata {
     /// <p>The unique identifier for the SSH public key.</p>
     pub ssh_public_key_id: String,
     /// <p>The status of the SSH public key. <code>Active</code> means that the key can be used for authentication with an AWS CodeCommit repository. <code>Inactive</code> means that the key cannot be used.</p>
-    pub status: String,
+    pub status: StatusType,
     /// <p>The date and time, in <a href="http://www.iso.org/iso/iso8601">ISO 8601 date-time format</a>, when the SSH public key was uploaded.</p>
-    pub upload_date: String,
+    pub upload_date: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The name of the IAM user associated with the SSH public key.</p>
     pub user_name: String,
 }
@@ -9154,7 +10834,7 @@ pub struct ServerCertificateMetadata {
     /// <p> The Amazon Resource Name (ARN) specifying the server certificate. For more information about ARNs and how to use them in policies, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/Using_Identifiers.html">IAM Identifiers</a> in the <i>IAM User Guide</i>. </p>
     pub arn: String,
     /// <p>The date on which the certificate is set to expire.</p>
-    pub expiration: Option<String>,
+    pub expiration: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p> The path to the server certificate. For more information about paths, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/Using_Identifiers.html">IAM Identifiers</a> in the <i>IAM User Guide</i>. </p>
     pub path: String,
     /// <p> The stable and unique string identifying the server certificate. For more information about IDs, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/Using_Identifiers.html">IAM Identifiers</a> in the <i>IAM User Guide</i>. </p>
@@ -9162,7 +10842,7 @@ pub struct ServerCertificateMetadata {
     /// <p>The name that identifies the server certificate.</p>
     pub server_certificate_name: String,
     /// <p>The date when the server certificate was uploaded.</p>
-    pub upload_date: Option<String>,
+    pub upload_date: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 struct ServerCertificateMetadataDeserializer;
@@ -9244,7 +10924,7 @@ impl ServerCertificateNameTypeDeserializer {
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct ServiceLastAccessed {
     /// <p>The date and time, in <a href="http://www.iso.org/iso/iso8601">ISO 8601 date-time format</a>, when an authenticated entity most recently attempted to access the service. AWS does not report unauthenticated requests.</p> <p>This field is null if no IAM entities attempted to access the service within the <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/access_policies_access-advisor.html#service-last-accessed-reporting-period">reporting period</a>.</p>
-    pub last_authenticated: Option<String>,
+    pub last_authenticated: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The ARN of the authenticated entity (user or role) that last attempted to access the service. AWS does not report unauthenticated requests.</p> <p>This field is null if no IAM entities attempted to access the service within the <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/access_policies_access-advisor.html#service-last-accessed-reporting-period">reporting period</a>.</p>
     pub last_authenticated_entity: Option<String>,
     /// <p>The name of the service in which access was attempted.</p>
@@ -9357,7 +11037,7 @@ impl ServicePasswordDeserializer {
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct ServiceSpecificCredential {
     /// <p>The date and time, in <a href="http://www.iso.org/iso/iso8601">ISO 8601 date-time format</a>, when the service-specific credential were created.</p>
-    pub create_date: String,
+    pub create_date: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The name of the service associated with the service-specific credential.</p>
     pub service_name: String,
     /// <p>The generated password for the service-specific credential.</p>
@@ -9367,7 +11047,7 @@ pub struct ServiceSpecificCredential {
     /// <p>The generated user name for the service-specific credential. This value is generated by combining the IAM user's name combined with the ID number of the AWS account, as in <code>jane-at-123456789012</code>, for example. This value cannot be configured by the user.</p>
     pub service_user_name: String,
     /// <p>The status of the service-specific credential. <code>Active</code> means that the key is valid for API calls, while <code>Inactive</code> means it is not.</p>
-    pub status: String,
+    pub status: StatusType,
     /// <p>The name of the IAM user associated with the service-specific credential.</p>
     pub user_name: String,
 }
@@ -9435,7 +11115,7 @@ impl ServiceSpecificCredentialIdDeserializer {
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct ServiceSpecificCredentialMetadata {
     /// <p>The date and time, in <a href="http://www.iso.org/iso/iso8601">ISO 8601 date-time format</a>, when the service-specific credential were created.</p>
-    pub create_date: String,
+    pub create_date: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The name of the service associated with the service-specific credential.</p>
     pub service_name: String,
     /// <p>The unique identifier for the service-specific credential.</p>
@@ -9443,7 +11123,7 @@ pub struct ServiceSpecificCredentialMetadata {
     /// <p>The generated user name for the service-specific credential.</p>
     pub service_user_name: String,
     /// <p>The status of the service-specific credential. <code>Active</code> means that the key is valid for API calls, while <code>Inactive</code> means it is not.</p>
-    pub status: String,
+    pub status: StatusType,
     /// <p>The name of the IAM user associated with the service-specific credential.</p>
     pub user_name: String,
 }
@@ -9567,7 +11247,7 @@ impl SetDefaultPolicyVersionRequestSerializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct SetSecurityTokenServicePreferencesRequest {
     /// <p>The version of the global endpoint token. Version 1 tokens are valid only in AWS Regions that are available by default. These tokens do not work in manually enabled Regions, such as Asia Pacific (Hong Kong). Version 2 tokens are valid in all Regions. However, version 2 tokens are longer and might affect systems where you temporarily store tokens.</p> <p>For information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_credentials_temp_enable-regions.html">Activating and Deactivating STS in an AWS Region</a> in the <i>IAM User Guide</i>.</p>
-    pub global_endpoint_token_version: String,
+    pub global_endpoint_token_version: GlobalEndpointTokenVersion,
 }
 
 /// Serialize `SetSecurityTokenServicePreferencesRequest` contents to a `SignedRequest`.
@@ -9595,9 +11275,9 @@ pub struct SigningCertificate {
     /// <p>The ID for the signing certificate.</p>
     pub certificate_id: String,
     /// <p>The status of the signing certificate. <code>Active</code> means that the key is valid for API calls, while <code>Inactive</code> means it is not.</p>
-    pub status: String,
+    pub status: StatusType,
     /// <p>The date when the signing certificate was uploaded.</p>
-    pub upload_date: Option<String>,
+    pub upload_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the user the signing certificate is associated with.</p>
     pub user_name: String,
 }
@@ -9879,6 +11559,111 @@ impl SimulationPolicyListTypeSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SortKeyType {
+    /// `SERVICE_NAMESPACE_ASCENDING`
+    ServiceNamespaceAscending,
+    /// `SERVICE_NAMESPACE_DESCENDING`
+    ServiceNamespaceDescending,
+    /// `LAST_AUTHENTICATED_TIME_ASCENDING`
+    LastAuthenticatedTimeAscending,
+    /// `LAST_AUTHENTICATED_TIME_DESCENDING`
+    LastAuthenticatedTimeDescending,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SortKeyType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SortKeyType::ServiceNamespaceAscending => "SERVICE_NAMESPACE_ASCENDING",
+            SortKeyType::ServiceNamespaceDescending => "SERVICE_NAMESPACE_DESCENDING",
+            SortKeyType::LastAuthenticatedTimeAscending => "LAST_AUTHENTICATED_TIME_ASCENDING",
+            SortKeyType::LastAuthenticatedTimeDescending => "LAST_AUTHENTICATED_TIME_DESCENDING",
+            SortKeyType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SortKeyType {
+    fn default() -> SortKeyType {
+        SortKeyType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SortKeyType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SortKeyType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SortKeyType {
+    fn from(value: &'a str) -> SortKeyType {
+        match value {
+            "SERVICE_NAMESPACE_ASCENDING" => SortKeyType::ServiceNamespaceAscending,
+            "SERVICE_NAMESPACE_DESCENDING" => SortKeyType::ServiceNamespaceDescending,
+            "LAST_AUTHENTICATED_TIME_ASCENDING" => SortKeyType::LastAuthenticatedTimeAscending,
+            "LAST_AUTHENTICATED_TIME_DESCENDING" => SortKeyType::LastAuthenticatedTimeDescending,
+            _ => SortKeyType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SortKeyType {
+    fn from(value: String) -> SortKeyType {
+        match value.as_str() {
+            "SERVICE_NAMESPACE_ASCENDING" => SortKeyType::ServiceNamespaceAscending,
+            "SERVICE_NAMESPACE_DESCENDING" => SortKeyType::ServiceNamespaceDescending,
+            "LAST_AUTHENTICATED_TIME_ASCENDING" => SortKeyType::LastAuthenticatedTimeAscending,
+            "LAST_AUTHENTICATED_TIME_DESCENDING" => SortKeyType::LastAuthenticatedTimeDescending,
+            _ => SortKeyType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SortKeyType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SortKeyType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SortKeyType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for SortKeyType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for SortKeyType {
+    fn deserialize<D>(deserializer: D) -> Result<SortKeyType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Contains a reference to a <code>Statement</code> element in a policy document that determines the result of the simulation.</p> <p>This data type is used by the <code>MatchedStatements</code> member of the <code> <a>EvaluationResult</a> </code> type.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
@@ -9888,7 +11673,7 @@ pub struct Statement {
     /// <p>The identifier of the policy that was provided as an input.</p>
     pub source_policy_id: Option<String>,
     /// <p>The type of the policy.</p>
-    pub source_policy_type: Option<String>,
+    pub source_policy_type: Option<PolicySourceType>,
     /// <p>The row and column of the beginning of the <code>Statement</code> in an IAM policy.</p>
     pub start_position: Option<Position>,
 }
@@ -9945,12 +11730,110 @@ impl StatementListTypeDeserializer {
         })
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StatusType {
+    /// `Active`
+    Active,
+    /// `Inactive`
+    Inactive,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl StatusType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StatusType::Active => "Active",
+            StatusType::Inactive => "Inactive",
+            StatusType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for StatusType {
+    fn default() -> StatusType {
+        StatusType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for StatusType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for StatusType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for StatusType {
+    fn from(value: &'a str) -> StatusType {
+        match value {
+            "Active" => StatusType::Active,
+            "Inactive" => StatusType::Inactive,
+            _ => StatusType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StatusType {
+    fn from(value: String) -> StatusType {
+        match value.as_str() {
+            "Active" => StatusType::Active,
+            "Inactive" => StatusType::Inactive,
+            _ => StatusType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for StatusType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<StatusType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StatusType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for StatusType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for StatusType {
+    fn deserialize<D>(deserializer: D) -> Result<StatusType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct StatusTypeDeserializer;
 impl StatusTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<StatusType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -9967,12 +11850,236 @@ impl StringTypeDeserializer {
         Ok(obj)
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SummaryKeyType {
+    /// `Users`
+    Users,
+    /// `UsersQuota`
+    UsersQuota,
+    /// `Groups`
+    Groups,
+    /// `GroupsQuota`
+    GroupsQuota,
+    /// `ServerCertificates`
+    ServerCertificates,
+    /// `ServerCertificatesQuota`
+    ServerCertificatesQuota,
+    /// `UserPolicySizeQuota`
+    UserPolicySizeQuota,
+    /// `GroupPolicySizeQuota`
+    GroupPolicySizeQuota,
+    /// `GroupsPerUserQuota`
+    GroupsPerUserQuota,
+    /// `SigningCertificatesPerUserQuota`
+    SigningCertificatesPerUserQuota,
+    /// `AccessKeysPerUserQuota`
+    AccessKeysPerUserQuota,
+    /// `MFADevices`
+    MFADevices,
+    /// `MFADevicesInUse`
+    MFADevicesInUse,
+    /// `AccountMFAEnabled`
+    AccountMFAEnabled,
+    /// `AccountAccessKeysPresent`
+    AccountAccessKeysPresent,
+    /// `AccountSigningCertificatesPresent`
+    AccountSigningCertificatesPresent,
+    /// `AttachedPoliciesPerGroupQuota`
+    AttachedPoliciesPerGroupQuota,
+    /// `AttachedPoliciesPerRoleQuota`
+    AttachedPoliciesPerRoleQuota,
+    /// `AttachedPoliciesPerUserQuota`
+    AttachedPoliciesPerUserQuota,
+    /// `Policies`
+    Policies,
+    /// `PoliciesQuota`
+    PoliciesQuota,
+    /// `PolicySizeQuota`
+    PolicySizeQuota,
+    /// `PolicyVersionsInUse`
+    PolicyVersionsInUse,
+    /// `PolicyVersionsInUseQuota`
+    PolicyVersionsInUseQuota,
+    /// `VersionsPerPolicyQuota`
+    VersionsPerPolicyQuota,
+    /// `GlobalEndpointTokenVersion`
+    GlobalEndpointTokenVersion,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SummaryKeyType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SummaryKeyType::Users => "Users",
+            SummaryKeyType::UsersQuota => "UsersQuota",
+            SummaryKeyType::Groups => "Groups",
+            SummaryKeyType::GroupsQuota => "GroupsQuota",
+            SummaryKeyType::ServerCertificates => "ServerCertificates",
+            SummaryKeyType::ServerCertificatesQuota => "ServerCertificatesQuota",
+            SummaryKeyType::UserPolicySizeQuota => "UserPolicySizeQuota",
+            SummaryKeyType::GroupPolicySizeQuota => "GroupPolicySizeQuota",
+            SummaryKeyType::GroupsPerUserQuota => "GroupsPerUserQuota",
+            SummaryKeyType::SigningCertificatesPerUserQuota => "SigningCertificatesPerUserQuota",
+            SummaryKeyType::AccessKeysPerUserQuota => "AccessKeysPerUserQuota",
+            SummaryKeyType::MFADevices => "MFADevices",
+            SummaryKeyType::MFADevicesInUse => "MFADevicesInUse",
+            SummaryKeyType::AccountMFAEnabled => "AccountMFAEnabled",
+            SummaryKeyType::AccountAccessKeysPresent => "AccountAccessKeysPresent",
+            SummaryKeyType::AccountSigningCertificatesPresent => {
+                "AccountSigningCertificatesPresent"
+            }
+            SummaryKeyType::AttachedPoliciesPerGroupQuota => "AttachedPoliciesPerGroupQuota",
+            SummaryKeyType::AttachedPoliciesPerRoleQuota => "AttachedPoliciesPerRoleQuota",
+            SummaryKeyType::AttachedPoliciesPerUserQuota => "AttachedPoliciesPerUserQuota",
+            SummaryKeyType::Policies => "Policies",
+            SummaryKeyType::PoliciesQuota => "PoliciesQuota",
+            SummaryKeyType::PolicySizeQuota => "PolicySizeQuota",
+            SummaryKeyType::PolicyVersionsInUse => "PolicyVersionsInUse",
+            SummaryKeyType::PolicyVersionsInUseQuota => "PolicyVersionsInUseQuota",
+            SummaryKeyType::VersionsPerPolicyQuota => "VersionsPerPolicyQuota",
+            SummaryKeyType::GlobalEndpointTokenVersion => "GlobalEndpointTokenVersion",
+            SummaryKeyType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SummaryKeyType {
+    fn default() -> SummaryKeyType {
+        SummaryKeyType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SummaryKeyType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SummaryKeyType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SummaryKeyType {
+    fn from(value: &'a str) -> SummaryKeyType {
+        match value {
+            "Users" => SummaryKeyType::Users,
+            "UsersQuota" => SummaryKeyType::UsersQuota,
+            "Groups" => SummaryKeyType::Groups,
+            "GroupsQuota" => SummaryKeyType::GroupsQuota,
+            "ServerCertificates" => SummaryKeyType::ServerCertificates,
+            "ServerCertificatesQuota" => SummaryKeyType::ServerCertificatesQuota,
+            "UserPolicySizeQuota" => SummaryKeyType::UserPolicySizeQuota,
+            "GroupPolicySizeQuota" => SummaryKeyType::GroupPolicySizeQuota,
+            "GroupsPerUserQuota" => SummaryKeyType::GroupsPerUserQuota,
+            "SigningCertificatesPerUserQuota" => SummaryKeyType::SigningCertificatesPerUserQuota,
+            "AccessKeysPerUserQuota" => SummaryKeyType::AccessKeysPerUserQuota,
+            "MFADevices" => SummaryKeyType::MFADevices,
+            "MFADevicesInUse" => SummaryKeyType::MFADevicesInUse,
+            "AccountMFAEnabled" => SummaryKeyType::AccountMFAEnabled,
+            "AccountAccessKeysPresent" => SummaryKeyType::AccountAccessKeysPresent,
+            "AccountSigningCertificatesPresent" => {
+                SummaryKeyType::AccountSigningCertificatesPresent
+            }
+            "AttachedPoliciesPerGroupQuota" => SummaryKeyType::AttachedPoliciesPerGroupQuota,
+            "AttachedPoliciesPerRoleQuota" => SummaryKeyType::AttachedPoliciesPerRoleQuota,
+            "AttachedPoliciesPerUserQuota" => SummaryKeyType::AttachedPoliciesPerUserQuota,
+            "Policies" => SummaryKeyType::Policies,
+            "PoliciesQuota" => SummaryKeyType::PoliciesQuota,
+            "PolicySizeQuota" => SummaryKeyType::PolicySizeQuota,
+            "PolicyVersionsInUse" => SummaryKeyType::PolicyVersionsInUse,
+            "PolicyVersionsInUseQuota" => SummaryKeyType::PolicyVersionsInUseQuota,
+            "VersionsPerPolicyQuota" => SummaryKeyType::VersionsPerPolicyQuota,
+            "GlobalEndpointTokenVersion" => SummaryKeyType::GlobalEndpointTokenVersion,
+            _ => SummaryKeyType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SummaryKeyType {
+    fn from(value: String) -> SummaryKeyType {
+        match value.as_str() {
+            "Users" => SummaryKeyType::Users,
+            "UsersQuota" => SummaryKeyType::UsersQuota,
+            "Groups" => SummaryKeyType::Groups,
+            "GroupsQuota" => SummaryKeyType::GroupsQuota,
+            "ServerCertificates" => SummaryKeyType::ServerCertificates,
+            "ServerCertificatesQuota" => SummaryKeyType::ServerCertificatesQuota,
+            "UserPolicySizeQuota" => SummaryKeyType::UserPolicySizeQuota,
+            "GroupPolicySizeQuota" => SummaryKeyType::GroupPolicySizeQuota,
+            "GroupsPerUserQuota" => SummaryKeyType::GroupsPerUserQuota,
+            "SigningCertificatesPerUserQuota" => SummaryKeyType::SigningCertificatesPerUserQuota,
+            "AccessKeysPerUserQuota" => SummaryKeyType::AccessKeysPerUserQuota,
+            "MFADevices" => SummaryKeyType::MFADevices,
+            "MFADevicesInUse" => SummaryKeyType::MFADevicesInUse,
+            "AccountMFAEnabled" => SummaryKeyType::AccountMFAEnabled,
+            "AccountAccessKeysPresent" => SummaryKeyType::AccountAccessKeysPresent,
+            "AccountSigningCertificatesPresent" => {
+                SummaryKeyType::AccountSigningCertificatesPresent
+            }
+            "AttachedPoliciesPerGroupQuota" => SummaryKeyType::AttachedPoliciesPerGroupQuota,
+            "AttachedPoliciesPerRoleQuota" => SummaryKeyType::AttachedPoliciesPerRoleQuota,
+            "AttachedPoliciesPerUserQuota" => SummaryKeyType::AttachedPoliciesPerUserQuota,
+            "Policies" => SummaryKeyType::Policies,
+            "PoliciesQuota" => SummaryKeyType::PoliciesQuota,
+            "PolicySizeQuota" => SummaryKeyType::PolicySizeQuota,
+            "PolicyVersionsInUse" => SummaryKeyType::PolicyVersionsInUse,
+            "PolicyVersionsInUseQuota" => SummaryKeyType::PolicyVersionsInUseQuota,
+            "VersionsPerPolicyQuota" => SummaryKeyType::VersionsPerPolicyQuota,
+            "GlobalEndpointTokenVersion" => SummaryKeyType::GlobalEndpointTokenVersion,
+            _ => SummaryKeyType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SummaryKeyType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SummaryKeyType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SummaryKeyType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for SummaryKeyType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for SummaryKeyType {
+    fn deserialize<D>(deserializer: D) -> Result<SummaryKeyType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct SummaryKeyTypeDeserializer;
 impl SummaryKeyTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<SummaryKeyType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -9984,7 +12091,7 @@ impl SummaryMapTypeDeserializer {
     fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
-    ) -> Result<::std::collections::HashMap<String, i64>, XmlParseError> {
+    ) -> Result<::std::collections::HashMap<SummaryKeyType, i64>, XmlParseError> {
         start_element(tag_name, stack)?;
 
         let mut obj = ::std::collections::HashMap::new();
@@ -10264,7 +12371,7 @@ pub struct UpdateAccessKeyRequest {
     /// <p>The access key ID of the secret access key you want to update.</p> <p>This parameter allows (through its <a href="http://wikipedia.org/wiki/regex">regex pattern</a>) a string of characters that can consist of any upper or lowercased letter or digit.</p>
     pub access_key_id: String,
     /// <p> The status you want to assign to the secret access key. <code>Active</code> means that the key can be used for API calls to AWS, while <code>Inactive</code> means that the key cannot be used.</p>
-    pub status: String,
+    pub status: StatusType,
     /// <p>The name of the user whose key you want to update.</p> <p>This parameter allows (through its <a href="http://wikipedia.org/wiki/regex">regex pattern</a>) a string of characters consisting of upper and lowercase alphanumeric characters with no spaces. You can also include any of the following characters: _+=,.@-</p>
     pub user_name: Option<String>,
 }
@@ -10654,7 +12761,7 @@ pub struct UpdateSSHPublicKeyRequest {
     /// <p>The unique identifier for the SSH public key.</p> <p>This parameter allows (through its <a href="http://wikipedia.org/wiki/regex">regex pattern</a>) a string of characters that can consist of any upper or lowercased letter or digit.</p>
     pub ssh_public_key_id: String,
     /// <p>The status to assign to the SSH public key. <code>Active</code> means that the key can be used for authentication with an AWS CodeCommit repository. <code>Inactive</code> means that the key cannot be used.</p>
-    pub status: String,
+    pub status: StatusType,
     /// <p>The name of the IAM user associated with the SSH public key.</p> <p>This parameter allows (through its <a href="http://wikipedia.org/wiki/regex">regex pattern</a>) a string of characters consisting of upper and lowercase alphanumeric characters with no spaces. You can also include any of the following characters: _+=,.@-</p>
     pub user_name: String,
 }
@@ -10719,7 +12826,7 @@ pub struct UpdateServiceSpecificCredentialRequest {
     /// <p>The unique identifier of the service-specific credential.</p> <p>This parameter allows (through its <a href="http://wikipedia.org/wiki/regex">regex pattern</a>) a string of characters that can consist of any upper or lowercased letter or digit.</p>
     pub service_specific_credential_id: String,
     /// <p>The status to be assigned to the service-specific credential.</p>
-    pub status: String,
+    pub status: StatusType,
     /// <p>The name of the IAM user associated with the service-specific credential. If you do not specify this value, then the operation assumes the user whose credentials are used to call the operation.</p> <p>This parameter allows (through its <a href="http://wikipedia.org/wiki/regex">regex pattern</a>) a string of characters consisting of upper and lowercase alphanumeric characters with no spaces. You can also include any of the following characters: _+=,.@-</p>
     pub user_name: Option<String>,
 }
@@ -10750,7 +12857,7 @@ pub struct UpdateSigningCertificateRequest {
     /// <p>The ID of the signing certificate you want to update.</p> <p>This parameter allows (through its <a href="http://wikipedia.org/wiki/regex">regex pattern</a>) a string of characters that can consist of any upper or lowercased letter or digit.</p>
     pub certificate_id: String,
     /// <p> The status you want to assign to the certificate. <code>Active</code> means that the certificate can be used for API calls to AWS <code>Inactive</code> means that the certificate cannot be used.</p>
-    pub status: String,
+    pub status: StatusType,
     /// <p>The name of the IAM user the signing certificate belongs to.</p> <p>This parameter allows (through its <a href="http://wikipedia.org/wiki/regex">regex pattern</a>) a string of characters consisting of upper and lowercase alphanumeric characters with no spaces. You can also include any of the following characters: _+=,.@-</p>
     pub user_name: Option<String>,
 }
@@ -11006,9 +13113,9 @@ pub struct User {
     /// <p>The Amazon Resource Name (ARN) that identifies the user. For more information about ARNs and how to use ARNs in policies, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/Using_Identifiers.html">IAM Identifiers</a> in the <i>IAM User Guide</i>. </p>
     pub arn: String,
     /// <p>The date and time, in <a href="http://www.iso.org/iso/iso8601">ISO 8601 date-time format</a>, when the user was created.</p>
-    pub create_date: String,
+    pub create_date: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The date and time, in <a href="http://www.iso.org/iso/iso8601">ISO 8601 date-time format</a>, when the user's password was last used to sign in to an AWS website. For a list of AWS websites that capture a user's last sign-in time, see the <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/credential-reports.html">Credential Reports</a> topic in the <i>IAM User Guide</i>. If a password is used more than once in a five-minute span, only the first use is returned in this field. If the field is null (no value), then it indicates that they never signed in with a password. This can be because:</p> <ul> <li> <p>The user never had a password.</p> </li> <li> <p>A password exists but has not been used since IAM started tracking this information on October 20, 2014.</p> </li> </ul> <p>A null value does not mean that the user <i>never</i> had a password. Also, if the user does not currently have a password but had one in the past, then this field contains the date and time the most recent password was used.</p> <p>This value is returned only in the <a>GetUser</a> and <a>ListUsers</a> operations. </p>
-    pub password_last_used: Option<String>,
+    pub password_last_used: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The path to the user. For more information about paths, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/Using_Identifiers.html">IAM Identifiers</a> in the <i>IAM User Guide</i>.</p>
     pub path: String,
     /// <p>The ARN of the policy used to set the permissions boundary for the user.</p> <p>For more information about permissions boundaries, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/access_policies_boundaries.html">Permissions Boundaries for IAM Identities </a> in the <i>IAM User Guide</i>.</p>
@@ -11074,7 +13181,7 @@ pub struct UserDetail {
     /// <p>A list of the managed policies attached to the user.</p>
     pub attached_managed_policies: Option<Vec<AttachedPolicy>>,
     /// <p>The date and time, in <a href="http://www.iso.org/iso/iso8601">ISO 8601 date-time format</a>, when the user was created.</p>
-    pub create_date: Option<String>,
+    pub create_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A list of IAM groups that the user is in.</p>
     pub group_list: Option<Vec<String>>,
     /// <p>The path to the user. For more information about paths, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/Using_Identifiers.html">IAM Identifiers</a> in the <i>IAM User Guide</i>.</p>
@@ -11203,7 +13310,7 @@ pub struct VirtualMFADevice {
     /// <p> The base32 seed defined as specified in <a href="https://tools.ietf.org/html/rfc3548.txt">RFC3548</a>. The <code>Base32StringSeed</code> is base64-encoded. </p>
     pub base_32_string_seed: Option<bytes::Bytes>,
     /// <p>The date and time on which the virtual MFA device was enabled.</p>
-    pub enable_date: Option<String>,
+    pub enable_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p> A QR code PNG image that encodes <code>otpauth://totp/$virtualMFADeviceName@$AccountName?secret=$Base32String</code> where <code>$virtualMFADeviceName</code> is one of the create call arguments. <code>AccountName</code> is the user name if set (otherwise, the account ID otherwise), and <code>Base32String</code> is the seed in base32 format. The <code>Base32String</code> value is base64-encoded. </p>
     pub qr_code_png: Option<bytes::Bytes>,
     /// <p>The serial number associated with <code>VirtualMFADevice</code>.</p>
@@ -20856,11 +22963,12 @@ pub struct IamClient {
 impl IamClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> IamClient {
         IamClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "IAM"),
         }
     }
 
@@ -20892,6 +23000,7 @@ impl Iam for IamClient {
         input: AddClientIDToOpenIDConnectProviderRequest,
     ) -> Result<(), RusotoError<AddClientIDToOpenIDConnectProviderError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("AddClientIDToOpenIDConnectProvider");
         let mut params = Params::new();
 
         params.put("Action", "AddClientIDToOpenIDConnectProvider");
@@ -20922,6 +23031,7 @@ impl Iam for IamClient {
         input: AddRoleToInstanceProfileRequest,
     ) -> Result<(), RusotoError<AddRoleToInstanceProfileError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("AddRoleToInstanceProfile");
         let mut params = Params::new();
 
         params.put("Action", "AddRoleToInstanceProfile");
@@ -20950,6 +23060,7 @@ impl Iam for IamClient {
         input: AddUserToGroupRequest,
     ) -> Result<(), RusotoError<AddUserToGroupError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("AddUserToGroup");
         let mut params = Params::new();
 
         params.put("Action", "AddUserToGroup");
@@ -20978,6 +23089,7 @@ impl Iam for IamClient {
         input: AttachGroupPolicyRequest,
     ) -> Result<(), RusotoError<AttachGroupPolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("AttachGroupPolicy");
         let mut params = Params::new();
 
         params.put("Action", "AttachGroupPolicy");
@@ -21006,6 +23118,7 @@ impl Iam for IamClient {
         input: AttachRolePolicyRequest,
     ) -> Result<(), RusotoError<AttachRolePolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("AttachRolePolicy");
         let mut params = Params::new();
 
         params.put("Action", "AttachRolePolicy");
@@ -21034,6 +23147,7 @@ impl Iam for IamClient {
         input: AttachUserPolicyRequest,
     ) -> Result<(), RusotoError<AttachUserPolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("AttachUserPolicy");
         let mut params = Params::new();
 
         params.put("Action", "AttachUserPolicy");
@@ -21062,6 +23176,7 @@ impl Iam for IamClient {
         input: ChangePasswordRequest,
     ) -> Result<(), RusotoError<ChangePasswordError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("ChangePassword");
         let mut params = Params::new();
 
         params.put("Action", "ChangePassword");
@@ -21090,6 +23205,7 @@ impl Iam for IamClient {
         input: CreateAccessKeyRequest,
     ) -> Result<CreateAccessKeyResponse, RusotoError<CreateAccessKeyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("CreateAccessKey");
         let mut params = Params::new();
 
         params.put("Action", "CreateAccessKey");
@@ -21139,6 +23255,7 @@ impl Iam for IamClient {
         input: CreateAccountAliasRequest,
     ) -> Result<(), RusotoError<CreateAccountAliasError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("CreateAccountAlias");
         let mut params = Params::new();
 
         params.put("Action", "CreateAccountAlias");
@@ -21167,6 +23284,7 @@ impl Iam for IamClient {
         input: CreateGroupRequest,
     ) -> Result<CreateGroupResponse, RusotoError<CreateGroupError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("CreateGroup");
         let mut params = Params::new();
 
         params.put("Action", "CreateGroup");
@@ -21213,6 +23331,7 @@ impl Iam for IamClient {
         input: CreateInstanceProfileRequest,
     ) -> Result<CreateInstanceProfileResponse, RusotoError<CreateInstanceProfileError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("CreateInstanceProfile");
         let mut params = Params::new();
 
         params.put("Action", "CreateInstanceProfile");
@@ -21262,6 +23381,7 @@ impl Iam for IamClient {
         input: CreateLoginProfileRequest,
     ) -> Result<CreateLoginProfileResponse, RusotoError<CreateLoginProfileError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("CreateLoginProfile");
         let mut params = Params::new();
 
         params.put("Action", "CreateLoginProfile");
@@ -21312,6 +23432,7 @@ impl Iam for IamClient {
     ) -> Result<CreateOpenIDConnectProviderResponse, RusotoError<CreateOpenIDConnectProviderError>>
     {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("CreateOpenIDConnectProvider");
         let mut params = Params::new();
 
         params.put("Action", "CreateOpenIDConnectProvider");
@@ -21361,6 +23482,7 @@ impl Iam for IamClient {
         input: CreatePolicyRequest,
     ) -> Result<CreatePolicyResponse, RusotoError<CreatePolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("CreatePolicy");
         let mut params = Params::new();
 
         params.put("Action", "CreatePolicy");
@@ -21408,6 +23530,7 @@ impl Iam for IamClient {
         input: CreatePolicyVersionRequest,
     ) -> Result<CreatePolicyVersionResponse, RusotoError<CreatePolicyVersionError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("CreatePolicyVersion");
         let mut params = Params::new();
 
         params.put("Action", "CreatePolicyVersion");
@@ -21457,6 +23580,7 @@ impl Iam for IamClient {
         input: CreateRoleRequest,
     ) -> Result<CreateRoleResponse, RusotoError<CreateRoleError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("CreateRole");
         let mut params = Params::new();
 
         params.put("Action", "CreateRole");
@@ -21503,6 +23627,7 @@ impl Iam for IamClient {
         input: CreateSAMLProviderRequest,
     ) -> Result<CreateSAMLProviderResponse, RusotoError<CreateSAMLProviderError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("CreateSAMLProvider");
         let mut params = Params::new();
 
         params.put("Action", "CreateSAMLProvider");
@@ -21552,6 +23677,7 @@ impl Iam for IamClient {
         input: CreateServiceLinkedRoleRequest,
     ) -> Result<CreateServiceLinkedRoleResponse, RusotoError<CreateServiceLinkedRoleError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("CreateServiceLinkedRole");
         let mut params = Params::new();
 
         params.put("Action", "CreateServiceLinkedRole");
@@ -21604,6 +23730,7 @@ impl Iam for IamClient {
         RusotoError<CreateServiceSpecificCredentialError>,
     > {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("CreateServiceSpecificCredential");
         let mut params = Params::new();
 
         params.put("Action", "CreateServiceSpecificCredential");
@@ -21655,6 +23782,7 @@ impl Iam for IamClient {
         input: CreateUserRequest,
     ) -> Result<CreateUserResponse, RusotoError<CreateUserError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("CreateUser");
         let mut params = Params::new();
 
         params.put("Action", "CreateUser");
@@ -21701,6 +23829,7 @@ impl Iam for IamClient {
         input: CreateVirtualMFADeviceRequest,
     ) -> Result<CreateVirtualMFADeviceResponse, RusotoError<CreateVirtualMFADeviceError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("CreateVirtualMFADevice");
         let mut params = Params::new();
 
         params.put("Action", "CreateVirtualMFADevice");
@@ -21750,6 +23879,7 @@ impl Iam for IamClient {
         input: DeactivateMFADeviceRequest,
     ) -> Result<(), RusotoError<DeactivateMFADeviceError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("DeactivateMFADevice");
         let mut params = Params::new();
 
         params.put("Action", "DeactivateMFADevice");
@@ -21778,6 +23908,7 @@ impl Iam for IamClient {
         input: DeleteAccessKeyRequest,
     ) -> Result<(), RusotoError<DeleteAccessKeyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("DeleteAccessKey");
         let mut params = Params::new();
 
         params.put("Action", "DeleteAccessKey");
@@ -21806,6 +23937,7 @@ impl Iam for IamClient {
         input: DeleteAccountAliasRequest,
     ) -> Result<(), RusotoError<DeleteAccountAliasError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("DeleteAccountAlias");
         let mut params = Params::new();
 
         params.put("Action", "DeleteAccountAlias");
@@ -21833,6 +23965,7 @@ impl Iam for IamClient {
         &self,
     ) -> Result<(), RusotoError<DeleteAccountPasswordPolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("DeleteAccountPasswordPolicy");
         let mut params = Params::new();
 
         params.put("Action", "DeleteAccountPasswordPolicy");
@@ -21861,6 +23994,7 @@ impl Iam for IamClient {
         input: DeleteGroupRequest,
     ) -> Result<(), RusotoError<DeleteGroupError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("DeleteGroup");
         let mut params = Params::new();
 
         params.put("Action", "DeleteGroup");
@@ -21889,6 +24023,7 @@ impl Iam for IamClient {
         input: DeleteGroupPolicyRequest,
     ) -> Result<(), RusotoError<DeleteGroupPolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("DeleteGroupPolicy");
         let mut params = Params::new();
 
         params.put("Action", "DeleteGroupPolicy");
@@ -21917,6 +24052,7 @@ impl Iam for IamClient {
         input: DeleteInstanceProfileRequest,
     ) -> Result<(), RusotoError<DeleteInstanceProfileError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("DeleteInstanceProfile");
         let mut params = Params::new();
 
         params.put("Action", "DeleteInstanceProfile");
@@ -21945,6 +24081,7 @@ impl Iam for IamClient {
         input: DeleteLoginProfileRequest,
     ) -> Result<(), RusotoError<DeleteLoginProfileError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("DeleteLoginProfile");
         let mut params = Params::new();
 
         params.put("Action", "DeleteLoginProfile");
@@ -21973,6 +24110,7 @@ impl Iam for IamClient {
         input: DeleteOpenIDConnectProviderRequest,
     ) -> Result<(), RusotoError<DeleteOpenIDConnectProviderError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("DeleteOpenIDConnectProvider");
         let mut params = Params::new();
 
         params.put("Action", "DeleteOpenIDConnectProvider");
@@ -22001,6 +24139,7 @@ impl Iam for IamClient {
         input: DeletePolicyRequest,
     ) -> Result<(), RusotoError<DeletePolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("DeletePolicy");
         let mut params = Params::new();
 
         params.put("Action", "DeletePolicy");
@@ -22029,6 +24168,7 @@ impl Iam for IamClient {
         input: DeletePolicyVersionRequest,
     ) -> Result<(), RusotoError<DeletePolicyVersionError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("DeletePolicyVersion");
         let mut params = Params::new();
 
         params.put("Action", "DeletePolicyVersion");
@@ -22057,6 +24197,7 @@ impl Iam for IamClient {
         input: DeleteRoleRequest,
     ) -> Result<(), RusotoError<DeleteRoleError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("DeleteRole");
         let mut params = Params::new();
 
         params.put("Action", "DeleteRole");
@@ -22085,6 +24226,7 @@ impl Iam for IamClient {
         input: DeleteRolePermissionsBoundaryRequest,
     ) -> Result<(), RusotoError<DeleteRolePermissionsBoundaryError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("DeleteRolePermissionsBoundary");
         let mut params = Params::new();
 
         params.put("Action", "DeleteRolePermissionsBoundary");
@@ -22113,6 +24255,7 @@ impl Iam for IamClient {
         input: DeleteRolePolicyRequest,
     ) -> Result<(), RusotoError<DeleteRolePolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("DeleteRolePolicy");
         let mut params = Params::new();
 
         params.put("Action", "DeleteRolePolicy");
@@ -22141,6 +24284,7 @@ impl Iam for IamClient {
         input: DeleteSAMLProviderRequest,
     ) -> Result<(), RusotoError<DeleteSAMLProviderError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("DeleteSAMLProvider");
         let mut params = Params::new();
 
         params.put("Action", "DeleteSAMLProvider");
@@ -22169,6 +24313,7 @@ impl Iam for IamClient {
         input: DeleteSSHPublicKeyRequest,
     ) -> Result<(), RusotoError<DeleteSSHPublicKeyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("DeleteSSHPublicKey");
         let mut params = Params::new();
 
         params.put("Action", "DeleteSSHPublicKey");
@@ -22197,6 +24342,7 @@ impl Iam for IamClient {
         input: DeleteServerCertificateRequest,
     ) -> Result<(), RusotoError<DeleteServerCertificateError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("DeleteServerCertificate");
         let mut params = Params::new();
 
         params.put("Action", "DeleteServerCertificate");
@@ -22225,6 +24371,7 @@ impl Iam for IamClient {
         input: DeleteServiceLinkedRoleRequest,
     ) -> Result<DeleteServiceLinkedRoleResponse, RusotoError<DeleteServiceLinkedRoleError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("DeleteServiceLinkedRole");
         let mut params = Params::new();
 
         params.put("Action", "DeleteServiceLinkedRole");
@@ -22274,6 +24421,7 @@ impl Iam for IamClient {
         input: DeleteServiceSpecificCredentialRequest,
     ) -> Result<(), RusotoError<DeleteServiceSpecificCredentialError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("DeleteServiceSpecificCredential");
         let mut params = Params::new();
 
         params.put("Action", "DeleteServiceSpecificCredential");
@@ -22304,6 +24452,7 @@ impl Iam for IamClient {
         input: DeleteSigningCertificateRequest,
     ) -> Result<(), RusotoError<DeleteSigningCertificateError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("DeleteSigningCertificate");
         let mut params = Params::new();
 
         params.put("Action", "DeleteSigningCertificate");
@@ -22332,6 +24481,7 @@ impl Iam for IamClient {
         input: DeleteUserRequest,
     ) -> Result<(), RusotoError<DeleteUserError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("DeleteUser");
         let mut params = Params::new();
 
         params.put("Action", "DeleteUser");
@@ -22360,6 +24510,7 @@ impl Iam for IamClient {
         input: DeleteUserPermissionsBoundaryRequest,
     ) -> Result<(), RusotoError<DeleteUserPermissionsBoundaryError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("DeleteUserPermissionsBoundary");
         let mut params = Params::new();
 
         params.put("Action", "DeleteUserPermissionsBoundary");
@@ -22388,6 +24539,7 @@ impl Iam for IamClient {
         input: DeleteUserPolicyRequest,
     ) -> Result<(), RusotoError<DeleteUserPolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("DeleteUserPolicy");
         let mut params = Params::new();
 
         params.put("Action", "DeleteUserPolicy");
@@ -22416,6 +24568,7 @@ impl Iam for IamClient {
         input: DeleteVirtualMFADeviceRequest,
     ) -> Result<(), RusotoError<DeleteVirtualMFADeviceError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("DeleteVirtualMFADevice");
         let mut params = Params::new();
 
         params.put("Action", "DeleteVirtualMFADevice");
@@ -22444,6 +24597,7 @@ impl Iam for IamClient {
         input: DetachGroupPolicyRequest,
     ) -> Result<(), RusotoError<DetachGroupPolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("DetachGroupPolicy");
         let mut params = Params::new();
 
         params.put("Action", "DetachGroupPolicy");
@@ -22472,6 +24626,7 @@ impl Iam for IamClient {
         input: DetachRolePolicyRequest,
     ) -> Result<(), RusotoError<DetachRolePolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("DetachRolePolicy");
         let mut params = Params::new();
 
         params.put("Action", "DetachRolePolicy");
@@ -22500,6 +24655,7 @@ impl Iam for IamClient {
         input: DetachUserPolicyRequest,
     ) -> Result<(), RusotoError<DetachUserPolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("DetachUserPolicy");
         let mut params = Params::new();
 
         params.put("Action", "DetachUserPolicy");
@@ -22528,6 +24684,7 @@ impl Iam for IamClient {
         input: EnableMFADeviceRequest,
     ) -> Result<(), RusotoError<EnableMFADeviceError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("EnableMFADevice");
         let mut params = Params::new();
 
         params.put("Action", "EnableMFADevice");
@@ -22555,6 +24712,7 @@ impl Iam for IamClient {
         &self,
     ) -> Result<GenerateCredentialReportResponse, RusotoError<GenerateCredentialReportError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("GenerateCredentialReport");
         let mut params = Params::new();
 
         params.put("Action", "GenerateCredentialReport");
@@ -22607,6 +24765,7 @@ impl Iam for IamClient {
         RusotoError<GenerateOrganizationsAccessReportError>,
     > {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("GenerateOrganizationsAccessReport");
         let mut params = Params::new();
 
         params.put("Action", "GenerateOrganizationsAccessReport");
@@ -22661,6 +24820,7 @@ impl Iam for IamClient {
         RusotoError<GenerateServiceLastAccessedDetailsError>,
     > {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("GenerateServiceLastAccessedDetails");
         let mut params = Params::new();
 
         params.put("Action", "GenerateServiceLastAccessedDetails");
@@ -22712,6 +24872,7 @@ impl Iam for IamClient {
         input: GetAccessKeyLastUsedRequest,
     ) -> Result<GetAccessKeyLastUsedResponse, RusotoError<GetAccessKeyLastUsedError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("GetAccessKeyLastUsed");
         let mut params = Params::new();
 
         params.put("Action", "GetAccessKeyLastUsed");
@@ -22764,6 +24925,7 @@ impl Iam for IamClient {
         RusotoError<GetAccountAuthorizationDetailsError>,
     > {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("GetAccountAuthorizationDetails");
         let mut params = Params::new();
 
         params.put("Action", "GetAccountAuthorizationDetails");
@@ -22812,6 +24974,7 @@ impl Iam for IamClient {
         &self,
     ) -> Result<GetAccountPasswordPolicyResponse, RusotoError<GetAccountPasswordPolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("GetAccountPasswordPolicy");
         let mut params = Params::new();
 
         params.put("Action", "GetAccountPasswordPolicy");
@@ -22860,6 +25023,7 @@ impl Iam for IamClient {
         &self,
     ) -> Result<GetAccountSummaryResponse, RusotoError<GetAccountSummaryError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("GetAccountSummary");
         let mut params = Params::new();
 
         params.put("Action", "GetAccountSummary");
@@ -22910,6 +25074,7 @@ impl Iam for IamClient {
     ) -> Result<GetContextKeysForPolicyResponse, RusotoError<GetContextKeysForCustomPolicyError>>
     {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("GetContextKeysForCustomPolicy");
         let mut params = Params::new();
 
         params.put("Action", "GetContextKeysForCustomPolicy");
@@ -22960,6 +25125,7 @@ impl Iam for IamClient {
     ) -> Result<GetContextKeysForPolicyResponse, RusotoError<GetContextKeysForPrincipalPolicyError>>
     {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("GetContextKeysForPrincipalPolicy");
         let mut params = Params::new();
 
         params.put("Action", "GetContextKeysForPrincipalPolicy");
@@ -23010,6 +25176,7 @@ impl Iam for IamClient {
         &self,
     ) -> Result<GetCredentialReportResponse, RusotoError<GetCredentialReportError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("GetCredentialReport");
         let mut params = Params::new();
 
         params.put("Action", "GetCredentialReport");
@@ -23059,6 +25226,7 @@ impl Iam for IamClient {
         input: GetGroupRequest,
     ) -> Result<GetGroupResponse, RusotoError<GetGroupError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("GetGroup");
         let mut params = Params::new();
 
         params.put("Action", "GetGroup");
@@ -23105,6 +25273,7 @@ impl Iam for IamClient {
         input: GetGroupPolicyRequest,
     ) -> Result<GetGroupPolicyResponse, RusotoError<GetGroupPolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("GetGroupPolicy");
         let mut params = Params::new();
 
         params.put("Action", "GetGroupPolicy");
@@ -23154,6 +25323,7 @@ impl Iam for IamClient {
         input: GetInstanceProfileRequest,
     ) -> Result<GetInstanceProfileResponse, RusotoError<GetInstanceProfileError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("GetInstanceProfile");
         let mut params = Params::new();
 
         params.put("Action", "GetInstanceProfile");
@@ -23203,6 +25373,7 @@ impl Iam for IamClient {
         input: GetLoginProfileRequest,
     ) -> Result<GetLoginProfileResponse, RusotoError<GetLoginProfileError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("GetLoginProfile");
         let mut params = Params::new();
 
         params.put("Action", "GetLoginProfile");
@@ -23252,6 +25423,7 @@ impl Iam for IamClient {
         input: GetOpenIDConnectProviderRequest,
     ) -> Result<GetOpenIDConnectProviderResponse, RusotoError<GetOpenIDConnectProviderError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("GetOpenIDConnectProvider");
         let mut params = Params::new();
 
         params.put("Action", "GetOpenIDConnectProvider");
@@ -23302,6 +25474,7 @@ impl Iam for IamClient {
     ) -> Result<GetOrganizationsAccessReportResponse, RusotoError<GetOrganizationsAccessReportError>>
     {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("GetOrganizationsAccessReport");
         let mut params = Params::new();
 
         params.put("Action", "GetOrganizationsAccessReport");
@@ -23351,6 +25524,7 @@ impl Iam for IamClient {
         input: GetPolicyRequest,
     ) -> Result<GetPolicyResponse, RusotoError<GetPolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("GetPolicy");
         let mut params = Params::new();
 
         params.put("Action", "GetPolicy");
@@ -23397,6 +25571,7 @@ impl Iam for IamClient {
         input: GetPolicyVersionRequest,
     ) -> Result<GetPolicyVersionResponse, RusotoError<GetPolicyVersionError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("GetPolicyVersion");
         let mut params = Params::new();
 
         params.put("Action", "GetPolicyVersion");
@@ -23446,6 +25621,7 @@ impl Iam for IamClient {
         input: GetRoleRequest,
     ) -> Result<GetRoleResponse, RusotoError<GetRoleError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("GetRole");
         let mut params = Params::new();
 
         params.put("Action", "GetRole");
@@ -23492,6 +25668,7 @@ impl Iam for IamClient {
         input: GetRolePolicyRequest,
     ) -> Result<GetRolePolicyResponse, RusotoError<GetRolePolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("GetRolePolicy");
         let mut params = Params::new();
 
         params.put("Action", "GetRolePolicy");
@@ -23539,6 +25716,7 @@ impl Iam for IamClient {
         input: GetSAMLProviderRequest,
     ) -> Result<GetSAMLProviderResponse, RusotoError<GetSAMLProviderError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("GetSAMLProvider");
         let mut params = Params::new();
 
         params.put("Action", "GetSAMLProvider");
@@ -23588,6 +25766,7 @@ impl Iam for IamClient {
         input: GetSSHPublicKeyRequest,
     ) -> Result<GetSSHPublicKeyResponse, RusotoError<GetSSHPublicKeyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("GetSSHPublicKey");
         let mut params = Params::new();
 
         params.put("Action", "GetSSHPublicKey");
@@ -23637,6 +25816,7 @@ impl Iam for IamClient {
         input: GetServerCertificateRequest,
     ) -> Result<GetServerCertificateResponse, RusotoError<GetServerCertificateError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("GetServerCertificate");
         let mut params = Params::new();
 
         params.put("Action", "GetServerCertificate");
@@ -23689,6 +25869,7 @@ impl Iam for IamClient {
         RusotoError<GetServiceLastAccessedDetailsError>,
     > {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("GetServiceLastAccessedDetails");
         let mut params = Params::new();
 
         params.put("Action", "GetServiceLastAccessedDetails");
@@ -23741,6 +25922,7 @@ impl Iam for IamClient {
         RusotoError<GetServiceLastAccessedDetailsWithEntitiesError>,
     > {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("GetServiceLastAccessedDetailsWithEntities");
         let mut params = Params::new();
 
         params.put("Action", "GetServiceLastAccessedDetailsWithEntities");
@@ -23797,6 +25979,7 @@ impl Iam for IamClient {
         RusotoError<GetServiceLinkedRoleDeletionStatusError>,
     > {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("GetServiceLinkedRoleDeletionStatus");
         let mut params = Params::new();
 
         params.put("Action", "GetServiceLinkedRoleDeletionStatus");
@@ -23848,6 +26031,7 @@ impl Iam for IamClient {
         input: GetUserRequest,
     ) -> Result<GetUserResponse, RusotoError<GetUserError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("GetUser");
         let mut params = Params::new();
 
         params.put("Action", "GetUser");
@@ -23894,6 +26078,7 @@ impl Iam for IamClient {
         input: GetUserPolicyRequest,
     ) -> Result<GetUserPolicyResponse, RusotoError<GetUserPolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("GetUserPolicy");
         let mut params = Params::new();
 
         params.put("Action", "GetUserPolicy");
@@ -23941,6 +26126,7 @@ impl Iam for IamClient {
         input: ListAccessKeysRequest,
     ) -> Result<ListAccessKeysResponse, RusotoError<ListAccessKeysError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("ListAccessKeys");
         let mut params = Params::new();
 
         params.put("Action", "ListAccessKeys");
@@ -23990,6 +26176,7 @@ impl Iam for IamClient {
         input: ListAccountAliasesRequest,
     ) -> Result<ListAccountAliasesResponse, RusotoError<ListAccountAliasesError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("ListAccountAliases");
         let mut params = Params::new();
 
         params.put("Action", "ListAccountAliases");
@@ -24040,6 +26227,7 @@ impl Iam for IamClient {
     ) -> Result<ListAttachedGroupPoliciesResponse, RusotoError<ListAttachedGroupPoliciesError>>
     {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("ListAttachedGroupPolicies");
         let mut params = Params::new();
 
         params.put("Action", "ListAttachedGroupPolicies");
@@ -24089,6 +26277,7 @@ impl Iam for IamClient {
         input: ListAttachedRolePoliciesRequest,
     ) -> Result<ListAttachedRolePoliciesResponse, RusotoError<ListAttachedRolePoliciesError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("ListAttachedRolePolicies");
         let mut params = Params::new();
 
         params.put("Action", "ListAttachedRolePolicies");
@@ -24138,6 +26327,7 @@ impl Iam for IamClient {
         input: ListAttachedUserPoliciesRequest,
     ) -> Result<ListAttachedUserPoliciesResponse, RusotoError<ListAttachedUserPoliciesError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("ListAttachedUserPolicies");
         let mut params = Params::new();
 
         params.put("Action", "ListAttachedUserPolicies");
@@ -24187,6 +26377,7 @@ impl Iam for IamClient {
         input: ListEntitiesForPolicyRequest,
     ) -> Result<ListEntitiesForPolicyResponse, RusotoError<ListEntitiesForPolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("ListEntitiesForPolicy");
         let mut params = Params::new();
 
         params.put("Action", "ListEntitiesForPolicy");
@@ -24236,6 +26427,7 @@ impl Iam for IamClient {
         input: ListGroupPoliciesRequest,
     ) -> Result<ListGroupPoliciesResponse, RusotoError<ListGroupPoliciesError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("ListGroupPolicies");
         let mut params = Params::new();
 
         params.put("Action", "ListGroupPolicies");
@@ -24285,6 +26477,7 @@ impl Iam for IamClient {
         input: ListGroupsRequest,
     ) -> Result<ListGroupsResponse, RusotoError<ListGroupsError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("ListGroups");
         let mut params = Params::new();
 
         params.put("Action", "ListGroups");
@@ -24331,6 +26524,7 @@ impl Iam for IamClient {
         input: ListGroupsForUserRequest,
     ) -> Result<ListGroupsForUserResponse, RusotoError<ListGroupsForUserError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("ListGroupsForUser");
         let mut params = Params::new();
 
         params.put("Action", "ListGroupsForUser");
@@ -24380,6 +26574,7 @@ impl Iam for IamClient {
         input: ListInstanceProfilesRequest,
     ) -> Result<ListInstanceProfilesResponse, RusotoError<ListInstanceProfilesError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("ListInstanceProfiles");
         let mut params = Params::new();
 
         params.put("Action", "ListInstanceProfiles");
@@ -24430,6 +26625,7 @@ impl Iam for IamClient {
     ) -> Result<ListInstanceProfilesForRoleResponse, RusotoError<ListInstanceProfilesForRoleError>>
     {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("ListInstanceProfilesForRole");
         let mut params = Params::new();
 
         params.put("Action", "ListInstanceProfilesForRole");
@@ -24479,6 +26675,7 @@ impl Iam for IamClient {
         input: ListMFADevicesRequest,
     ) -> Result<ListMFADevicesResponse, RusotoError<ListMFADevicesError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("ListMFADevices");
         let mut params = Params::new();
 
         params.put("Action", "ListMFADevices");
@@ -24529,6 +26726,7 @@ impl Iam for IamClient {
     ) -> Result<ListOpenIDConnectProvidersResponse, RusotoError<ListOpenIDConnectProvidersError>>
     {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("ListOpenIDConnectProviders");
         let mut params = Params::new();
 
         params.put("Action", "ListOpenIDConnectProviders");
@@ -24578,6 +26776,7 @@ impl Iam for IamClient {
         input: ListPoliciesRequest,
     ) -> Result<ListPoliciesResponse, RusotoError<ListPoliciesError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("ListPolicies");
         let mut params = Params::new();
 
         params.put("Action", "ListPolicies");
@@ -24628,6 +26827,7 @@ impl Iam for IamClient {
         RusotoError<ListPoliciesGrantingServiceAccessError>,
     > {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("ListPoliciesGrantingServiceAccess");
         let mut params = Params::new();
 
         params.put("Action", "ListPoliciesGrantingServiceAccess");
@@ -24679,6 +26879,7 @@ impl Iam for IamClient {
         input: ListPolicyVersionsRequest,
     ) -> Result<ListPolicyVersionsResponse, RusotoError<ListPolicyVersionsError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("ListPolicyVersions");
         let mut params = Params::new();
 
         params.put("Action", "ListPolicyVersions");
@@ -24728,6 +26929,7 @@ impl Iam for IamClient {
         input: ListRolePoliciesRequest,
     ) -> Result<ListRolePoliciesResponse, RusotoError<ListRolePoliciesError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("ListRolePolicies");
         let mut params = Params::new();
 
         params.put("Action", "ListRolePolicies");
@@ -24777,6 +26979,7 @@ impl Iam for IamClient {
         input: ListRoleTagsRequest,
     ) -> Result<ListRoleTagsResponse, RusotoError<ListRoleTagsError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("ListRoleTags");
         let mut params = Params::new();
 
         params.put("Action", "ListRoleTags");
@@ -24824,6 +27027,7 @@ impl Iam for IamClient {
         input: ListRolesRequest,
     ) -> Result<ListRolesResponse, RusotoError<ListRolesError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("ListRoles");
         let mut params = Params::new();
 
         params.put("Action", "ListRoles");
@@ -24870,6 +27074,7 @@ impl Iam for IamClient {
         input: ListSAMLProvidersRequest,
     ) -> Result<ListSAMLProvidersResponse, RusotoError<ListSAMLProvidersError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("ListSAMLProviders");
         let mut params = Params::new();
 
         params.put("Action", "ListSAMLProviders");
@@ -24919,6 +27124,7 @@ impl Iam for IamClient {
         input: ListSSHPublicKeysRequest,
     ) -> Result<ListSSHPublicKeysResponse, RusotoError<ListSSHPublicKeysError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("ListSSHPublicKeys");
         let mut params = Params::new();
 
         params.put("Action", "ListSSHPublicKeys");
@@ -24968,6 +27174,7 @@ impl Iam for IamClient {
         input: ListServerCertificatesRequest,
     ) -> Result<ListServerCertificatesResponse, RusotoError<ListServerCertificatesError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("ListServerCertificates");
         let mut params = Params::new();
 
         params.put("Action", "ListServerCertificates");
@@ -25020,6 +27227,7 @@ impl Iam for IamClient {
         RusotoError<ListServiceSpecificCredentialsError>,
     > {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("ListServiceSpecificCredentials");
         let mut params = Params::new();
 
         params.put("Action", "ListServiceSpecificCredentials");
@@ -25069,6 +27277,7 @@ impl Iam for IamClient {
         input: ListSigningCertificatesRequest,
     ) -> Result<ListSigningCertificatesResponse, RusotoError<ListSigningCertificatesError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("ListSigningCertificates");
         let mut params = Params::new();
 
         params.put("Action", "ListSigningCertificates");
@@ -25118,6 +27327,7 @@ impl Iam for IamClient {
         input: ListUserPoliciesRequest,
     ) -> Result<ListUserPoliciesResponse, RusotoError<ListUserPoliciesError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("ListUserPolicies");
         let mut params = Params::new();
 
         params.put("Action", "ListUserPolicies");
@@ -25167,6 +27377,7 @@ impl Iam for IamClient {
         input: ListUserTagsRequest,
     ) -> Result<ListUserTagsResponse, RusotoError<ListUserTagsError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("ListUserTags");
         let mut params = Params::new();
 
         params.put("Action", "ListUserTags");
@@ -25214,6 +27425,7 @@ impl Iam for IamClient {
         input: ListUsersRequest,
     ) -> Result<ListUsersResponse, RusotoError<ListUsersError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("ListUsers");
         let mut params = Params::new();
 
         params.put("Action", "ListUsers");
@@ -25260,6 +27472,7 @@ impl Iam for IamClient {
         input: ListVirtualMFADevicesRequest,
     ) -> Result<ListVirtualMFADevicesResponse, RusotoError<ListVirtualMFADevicesError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("ListVirtualMFADevices");
         let mut params = Params::new();
 
         params.put("Action", "ListVirtualMFADevices");
@@ -25309,6 +27522,7 @@ impl Iam for IamClient {
         input: PutGroupPolicyRequest,
     ) -> Result<(), RusotoError<PutGroupPolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("PutGroupPolicy");
         let mut params = Params::new();
 
         params.put("Action", "PutGroupPolicy");
@@ -25337,6 +27551,7 @@ impl Iam for IamClient {
         input: PutRolePermissionsBoundaryRequest,
     ) -> Result<(), RusotoError<PutRolePermissionsBoundaryError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("PutRolePermissionsBoundary");
         let mut params = Params::new();
 
         params.put("Action", "PutRolePermissionsBoundary");
@@ -25365,6 +27580,7 @@ impl Iam for IamClient {
         input: PutRolePolicyRequest,
     ) -> Result<(), RusotoError<PutRolePolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("PutRolePolicy");
         let mut params = Params::new();
 
         params.put("Action", "PutRolePolicy");
@@ -25393,6 +27609,7 @@ impl Iam for IamClient {
         input: PutUserPermissionsBoundaryRequest,
     ) -> Result<(), RusotoError<PutUserPermissionsBoundaryError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("PutUserPermissionsBoundary");
         let mut params = Params::new();
 
         params.put("Action", "PutUserPermissionsBoundary");
@@ -25421,6 +27638,7 @@ impl Iam for IamClient {
         input: PutUserPolicyRequest,
     ) -> Result<(), RusotoError<PutUserPolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("PutUserPolicy");
         let mut params = Params::new();
 
         params.put("Action", "PutUserPolicy");
@@ -25449,6 +27667,7 @@ impl Iam for IamClient {
         input: RemoveClientIDFromOpenIDConnectProviderRequest,
     ) -> Result<(), RusotoError<RemoveClientIDFromOpenIDConnectProviderError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("RemoveClientIDFromOpenIDConnectProvider");
         let mut params = Params::new();
 
         params.put("Action", "RemoveClientIDFromOpenIDConnectProvider");
@@ -25483,6 +27702,7 @@ impl Iam for IamClient {
         input: RemoveRoleFromInstanceProfileRequest,
     ) -> Result<(), RusotoError<RemoveRoleFromInstanceProfileError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("RemoveRoleFromInstanceProfile");
         let mut params = Params::new();
 
         params.put("Action", "RemoveRoleFromInstanceProfile");
@@ -25511,6 +27731,7 @@ impl Iam for IamClient {
         input: RemoveUserFromGroupRequest,
     ) -> Result<(), RusotoError<RemoveUserFromGroupError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("RemoveUserFromGroup");
         let mut params = Params::new();
 
         params.put("Action", "RemoveUserFromGroup");
@@ -25542,6 +27763,7 @@ impl Iam for IamClient {
         RusotoError<ResetServiceSpecificCredentialError>,
     > {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("ResetServiceSpecificCredential");
         let mut params = Params::new();
 
         params.put("Action", "ResetServiceSpecificCredential");
@@ -25591,6 +27813,7 @@ impl Iam for IamClient {
         input: ResyncMFADeviceRequest,
     ) -> Result<(), RusotoError<ResyncMFADeviceError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("ResyncMFADevice");
         let mut params = Params::new();
 
         params.put("Action", "ResyncMFADevice");
@@ -25619,6 +27842,7 @@ impl Iam for IamClient {
         input: SetDefaultPolicyVersionRequest,
     ) -> Result<(), RusotoError<SetDefaultPolicyVersionError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("SetDefaultPolicyVersion");
         let mut params = Params::new();
 
         params.put("Action", "SetDefaultPolicyVersion");
@@ -25647,6 +27871,7 @@ impl Iam for IamClient {
         input: SetSecurityTokenServicePreferencesRequest,
     ) -> Result<(), RusotoError<SetSecurityTokenServicePreferencesError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("SetSecurityTokenServicePreferences");
         let mut params = Params::new();
 
         params.put("Action", "SetSecurityTokenServicePreferences");
@@ -25677,6 +27902,7 @@ impl Iam for IamClient {
         input: SimulateCustomPolicyRequest,
     ) -> Result<SimulatePolicyResponse, RusotoError<SimulateCustomPolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("SimulateCustomPolicy");
         let mut params = Params::new();
 
         params.put("Action", "SimulateCustomPolicy");
@@ -25726,6 +27952,7 @@ impl Iam for IamClient {
         input: SimulatePrincipalPolicyRequest,
     ) -> Result<SimulatePolicyResponse, RusotoError<SimulatePrincipalPolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("SimulatePrincipalPolicy");
         let mut params = Params::new();
 
         params.put("Action", "SimulatePrincipalPolicy");
@@ -25772,6 +27999,7 @@ impl Iam for IamClient {
     /// <p>Adds one or more tags to an IAM role. The role can be a regular role or a service-linked role. If a tag with the same key name already exists, then that tag is overwritten with the new value.</p> <p>A tag consists of a key name and an associated value. By assigning tags to your resources, you can do the following:</p> <ul> <li> <p> <b>Administrative grouping and discovery</b> - Attach tags to resources to aid in organization and search. For example, you could search for all resources with the key name <i>Project</i> and the value <i>MyImportantProject</i>. Or search for all resources with the key name <i>Cost Center</i> and the value <i>41200</i>. </p> </li> <li> <p> <b>Access control</b> - Reference tags in IAM user-based and resource-based policies. You can use tags to restrict access to only an IAM user or role that has a specified tag attached. You can also restrict access to only those resources that have a certain tag attached. For examples of policies that show how to use tags to control access, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/access_tags.html">Control Access Using IAM Tags</a> in the <i>IAM User Guide</i>.</p> </li> <li> <p> <b>Cost allocation</b> - Use tags to help track which individuals and teams are using which AWS resources.</p> </li> </ul> <note> <ul> <li> <p>Make sure that you have no invalid tags and that you do not exceed the allowed number of tags per role. In either case, the entire request fails and <i>no</i> tags are added to the role.</p> </li> <li> <p>AWS always interprets the tag <code>Value</code> as a single string. If you need to store an array, you can store comma-separated values in the string. However, you must interpret the value in your code.</p> </li> </ul> </note> <p>For more information about tagging, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_tags.html">Tagging IAM Identities</a> in the <i>IAM User Guide</i>.</p>
     async fn tag_role(&self, input: TagRoleRequest) -> Result<(), RusotoError<TagRoleError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("TagRole");
         let mut params = Params::new();
 
         params.put("Action", "TagRole");
@@ -25797,6 +28025,7 @@ impl Iam for IamClient {
     /// <p>Adds one or more tags to an IAM user. If a tag with the same key name already exists, then that tag is overwritten with the new value.</p> <p>A tag consists of a key name and an associated value. By assigning tags to your resources, you can do the following:</p> <ul> <li> <p> <b>Administrative grouping and discovery</b> - Attach tags to resources to aid in organization and search. For example, you could search for all resources with the key name <i>Project</i> and the value <i>MyImportantProject</i>. Or search for all resources with the key name <i>Cost Center</i> and the value <i>41200</i>. </p> </li> <li> <p> <b>Access control</b> - Reference tags in IAM user-based and resource-based policies. You can use tags to restrict access to only an IAM requesting user or to a role that has a specified tag attached. You can also restrict access to only those resources that have a certain tag attached. For examples of policies that show how to use tags to control access, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/access_tags.html">Control Access Using IAM Tags</a> in the <i>IAM User Guide</i>.</p> </li> <li> <p> <b>Cost allocation</b> - Use tags to help track which individuals and teams are using which AWS resources.</p> </li> </ul> <note> <ul> <li> <p>Make sure that you have no invalid tags and that you do not exceed the allowed number of tags per role. In either case, the entire request fails and <i>no</i> tags are added to the role.</p> </li> <li> <p>AWS always interprets the tag <code>Value</code> as a single string. If you need to store an array, you can store comma-separated values in the string. However, you must interpret the value in your code.</p> </li> </ul> </note> <p>For more information about tagging, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_tags.html">Tagging IAM Identities</a> in the <i>IAM User Guide</i>.</p>
     async fn tag_user(&self, input: TagUserRequest) -> Result<(), RusotoError<TagUserError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("TagUser");
         let mut params = Params::new();
 
         params.put("Action", "TagUser");
@@ -25822,6 +28051,7 @@ impl Iam for IamClient {
     /// <p>Removes the specified tags from the role. For more information about tagging, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_tags.html">Tagging IAM Identities</a> in the <i>IAM User Guide</i>.</p>
     async fn untag_role(&self, input: UntagRoleRequest) -> Result<(), RusotoError<UntagRoleError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("UntagRole");
         let mut params = Params::new();
 
         params.put("Action", "UntagRole");
@@ -25847,6 +28077,7 @@ impl Iam for IamClient {
     /// <p>Removes the specified tags from the user. For more information about tagging, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_tags.html">Tagging IAM Identities</a> in the <i>IAM User Guide</i>.</p>
     async fn untag_user(&self, input: UntagUserRequest) -> Result<(), RusotoError<UntagUserError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("UntagUser");
         let mut params = Params::new();
 
         params.put("Action", "UntagUser");
@@ -25875,6 +28106,7 @@ impl Iam for IamClient {
         input: UpdateAccessKeyRequest,
     ) -> Result<(), RusotoError<UpdateAccessKeyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("UpdateAccessKey");
         let mut params = Params::new();
 
         params.put("Action", "UpdateAccessKey");
@@ -25903,6 +28135,7 @@ impl Iam for IamClient {
         input: UpdateAccountPasswordPolicyRequest,
     ) -> Result<(), RusotoError<UpdateAccountPasswordPolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("UpdateAccountPasswordPolicy");
         let mut params = Params::new();
 
         params.put("Action", "UpdateAccountPasswordPolicy");
@@ -25931,6 +28164,7 @@ impl Iam for IamClient {
         input: UpdateAssumeRolePolicyRequest,
     ) -> Result<(), RusotoError<UpdateAssumeRolePolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("UpdateAssumeRolePolicy");
         let mut params = Params::new();
 
         params.put("Action", "UpdateAssumeRolePolicy");
@@ -25959,6 +28193,7 @@ impl Iam for IamClient {
         input: UpdateGroupRequest,
     ) -> Result<(), RusotoError<UpdateGroupError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("UpdateGroup");
         let mut params = Params::new();
 
         params.put("Action", "UpdateGroup");
@@ -25987,6 +28222,7 @@ impl Iam for IamClient {
         input: UpdateLoginProfileRequest,
     ) -> Result<(), RusotoError<UpdateLoginProfileError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("UpdateLoginProfile");
         let mut params = Params::new();
 
         params.put("Action", "UpdateLoginProfile");
@@ -26015,6 +28251,7 @@ impl Iam for IamClient {
         input: UpdateOpenIDConnectProviderThumbprintRequest,
     ) -> Result<(), RusotoError<UpdateOpenIDConnectProviderThumbprintError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("UpdateOpenIDConnectProviderThumbprint");
         let mut params = Params::new();
 
         params.put("Action", "UpdateOpenIDConnectProviderThumbprint");
@@ -26045,6 +28282,7 @@ impl Iam for IamClient {
         input: UpdateRoleRequest,
     ) -> Result<UpdateRoleResponse, RusotoError<UpdateRoleError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("UpdateRole");
         let mut params = Params::new();
 
         params.put("Action", "UpdateRole");
@@ -26091,6 +28329,7 @@ impl Iam for IamClient {
         input: UpdateRoleDescriptionRequest,
     ) -> Result<UpdateRoleDescriptionResponse, RusotoError<UpdateRoleDescriptionError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("UpdateRoleDescription");
         let mut params = Params::new();
 
         params.put("Action", "UpdateRoleDescription");
@@ -26140,6 +28379,7 @@ impl Iam for IamClient {
         input: UpdateSAMLProviderRequest,
     ) -> Result<UpdateSAMLProviderResponse, RusotoError<UpdateSAMLProviderError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("UpdateSAMLProvider");
         let mut params = Params::new();
 
         params.put("Action", "UpdateSAMLProvider");
@@ -26189,6 +28429,7 @@ impl Iam for IamClient {
         input: UpdateSSHPublicKeyRequest,
     ) -> Result<(), RusotoError<UpdateSSHPublicKeyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("UpdateSSHPublicKey");
         let mut params = Params::new();
 
         params.put("Action", "UpdateSSHPublicKey");
@@ -26217,6 +28458,7 @@ impl Iam for IamClient {
         input: UpdateServerCertificateRequest,
     ) -> Result<(), RusotoError<UpdateServerCertificateError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("UpdateServerCertificate");
         let mut params = Params::new();
 
         params.put("Action", "UpdateServerCertificate");
@@ -26245,6 +28487,7 @@ impl Iam for IamClient {
         input: UpdateServiceSpecificCredentialRequest,
     ) -> Result<(), RusotoError<UpdateServiceSpecificCredentialError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("UpdateServiceSpecificCredential");
         let mut params = Params::new();
 
         params.put("Action", "UpdateServiceSpecificCredential");
@@ -26275,6 +28518,7 @@ impl Iam for IamClient {
         input: UpdateSigningCertificateRequest,
     ) -> Result<(), RusotoError<UpdateSigningCertificateError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("UpdateSigningCertificate");
         let mut params = Params::new();
 
         params.put("Action", "UpdateSigningCertificate");
@@ -26303,6 +28547,7 @@ impl Iam for IamClient {
         input: UpdateUserRequest,
     ) -> Result<(), RusotoError<UpdateUserError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("UpdateUser");
         let mut params = Params::new();
 
         params.put("Action", "UpdateUser");
@@ -26331,6 +28576,7 @@ impl Iam for IamClient {
         input: UploadSSHPublicKeyRequest,
     ) -> Result<UploadSSHPublicKeyResponse, RusotoError<UploadSSHPublicKeyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("UploadSSHPublicKey");
         let mut params = Params::new();
 
         params.put("Action", "UploadSSHPublicKey");
@@ -26380,6 +28626,7 @@ impl Iam for IamClient {
         input: UploadServerCertificateRequest,
     ) -> Result<UploadServerCertificateResponse, RusotoError<UploadServerCertificateError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("UploadServerCertificate");
         let mut params = Params::new();
 
         params.put("Action", "UploadServerCertificate");
@@ -26429,6 +28676,7 @@ impl Iam for IamClient {
         input: UploadSigningCertificateRequest,
     ) -> Result<UploadSigningCertificateResponse, RusotoError<UploadSigningCertificateError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("UploadSigningCertificate");
         let mut params = Params::new();
 
         params.put("Action", "UploadSigningCertificate");
@@ -26472,6 +28720,1147 @@ impl Iam for IamClient {
         Ok(result)
     }
 }
+impl IamClient {
+    /// Auto-paginating version of `get_account_authorization_details`, streaming every page of results.
+    pub fn get_account_authorization_details_pages(
+        &self,
+        input: GetAccountAuthorizationDetailsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        GetAccountAuthorizationDetailsResponse,
+        GetAccountAuthorizationDetailsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IamClient, input: GetAccountAuthorizationDetailsRequest| async move {
+                client.get_account_authorization_details(input).await
+            },
+            |input: &GetAccountAuthorizationDetailsRequest,
+             output: &GetAccountAuthorizationDetailsResponse| {
+                if !output.is_truncated.unwrap_or(false) {
+                    return None;
+                }
+                Some(GetAccountAuthorizationDetailsRequest {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_account_authorization_details`, streaming the `UserDetailList` of every page of results.
+    pub fn get_account_authorization_details_items(
+        &self,
+        input: GetAccountAuthorizationDetailsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<UserDetail, GetAccountAuthorizationDetailsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.get_account_authorization_details_pages(input),
+            |output: GetAccountAuthorizationDetailsResponse| {
+                output.user_detail_list.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_group`, streaming every page of results.
+    pub fn get_group_pages(
+        &self,
+        input: GetGroupRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetGroupResponse, GetGroupError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IamClient, input: GetGroupRequest| async move { client.get_group(input).await },
+            |input: &GetGroupRequest, output: &GetGroupResponse| {
+                if !output.is_truncated.unwrap_or(false) {
+                    return None;
+                }
+                Some(GetGroupRequest {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_group`, streaming the `Users` of every page of results.
+    pub fn get_group_items(
+        &self,
+        input: GetGroupRequest,
+    ) -> rusoto_core::pagination::RusotoStream<User, GetGroupError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_group_pages(input),
+            |output: GetGroupResponse| output.users,
+        )
+    }
+
+    /// Auto-paginating version of `list_access_keys`, streaming every page of results.
+    pub fn list_access_keys_pages(
+        &self,
+        input: ListAccessKeysRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListAccessKeysResponse, ListAccessKeysError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IamClient, input: ListAccessKeysRequest| async move {
+                client.list_access_keys(input).await
+            },
+            |input: &ListAccessKeysRequest, output: &ListAccessKeysResponse| {
+                if !output.is_truncated.unwrap_or(false) {
+                    return None;
+                }
+                Some(ListAccessKeysRequest {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_access_keys`, streaming the `AccessKeyMetadata` of every page of results.
+    pub fn list_access_keys_items(
+        &self,
+        input: ListAccessKeysRequest,
+    ) -> rusoto_core::pagination::RusotoStream<AccessKeyMetadata, ListAccessKeysError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_access_keys_pages(input),
+            |output: ListAccessKeysResponse| output.access_key_metadata,
+        )
+    }
+
+    /// Auto-paginating version of `list_account_aliases`, streaming every page of results.
+    pub fn list_account_aliases_pages(
+        &self,
+        input: ListAccountAliasesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListAccountAliasesResponse, ListAccountAliasesError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IamClient, input: ListAccountAliasesRequest| async move {
+                client.list_account_aliases(input).await
+            },
+            |input: &ListAccountAliasesRequest, output: &ListAccountAliasesResponse| {
+                if !output.is_truncated.unwrap_or(false) {
+                    return None;
+                }
+                Some(ListAccountAliasesRequest {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_account_aliases`, streaming the `AccountAliases` of every page of results.
+    pub fn list_account_aliases_items(
+        &self,
+        input: ListAccountAliasesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListAccountAliasesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_account_aliases_pages(input),
+            |output: ListAccountAliasesResponse| output.account_aliases,
+        )
+    }
+
+    /// Auto-paginating version of `list_attached_group_policies`, streaming every page of results.
+    pub fn list_attached_group_policies_pages(
+        &self,
+        input: ListAttachedGroupPoliciesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListAttachedGroupPoliciesResponse,
+        ListAttachedGroupPoliciesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IamClient, input: ListAttachedGroupPoliciesRequest| async move {
+                client.list_attached_group_policies(input).await
+            },
+            |input: &ListAttachedGroupPoliciesRequest,
+             output: &ListAttachedGroupPoliciesResponse| {
+                if !output.is_truncated.unwrap_or(false) {
+                    return None;
+                }
+                Some(ListAttachedGroupPoliciesRequest {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_attached_group_policies`, streaming the `AttachedPolicies` of every page of results.
+    pub fn list_attached_group_policies_items(
+        &self,
+        input: ListAttachedGroupPoliciesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<AttachedPolicy, ListAttachedGroupPoliciesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_attached_group_policies_pages(input),
+            |output: ListAttachedGroupPoliciesResponse| {
+                output.attached_policies.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_attached_role_policies`, streaming every page of results.
+    pub fn list_attached_role_policies_pages(
+        &self,
+        input: ListAttachedRolePoliciesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListAttachedRolePoliciesResponse,
+        ListAttachedRolePoliciesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IamClient, input: ListAttachedRolePoliciesRequest| async move {
+                client.list_attached_role_policies(input).await
+            },
+            |input: &ListAttachedRolePoliciesRequest, output: &ListAttachedRolePoliciesResponse| {
+                if !output.is_truncated.unwrap_or(false) {
+                    return None;
+                }
+                Some(ListAttachedRolePoliciesRequest {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_attached_role_policies`, streaming the `AttachedPolicies` of every page of results.
+    pub fn list_attached_role_policies_items(
+        &self,
+        input: ListAttachedRolePoliciesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<AttachedPolicy, ListAttachedRolePoliciesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_attached_role_policies_pages(input),
+            |output: ListAttachedRolePoliciesResponse| output.attached_policies.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_attached_user_policies`, streaming every page of results.
+    pub fn list_attached_user_policies_pages(
+        &self,
+        input: ListAttachedUserPoliciesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListAttachedUserPoliciesResponse,
+        ListAttachedUserPoliciesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IamClient, input: ListAttachedUserPoliciesRequest| async move {
+                client.list_attached_user_policies(input).await
+            },
+            |input: &ListAttachedUserPoliciesRequest, output: &ListAttachedUserPoliciesResponse| {
+                if !output.is_truncated.unwrap_or(false) {
+                    return None;
+                }
+                Some(ListAttachedUserPoliciesRequest {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_attached_user_policies`, streaming the `AttachedPolicies` of every page of results.
+    pub fn list_attached_user_policies_items(
+        &self,
+        input: ListAttachedUserPoliciesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<AttachedPolicy, ListAttachedUserPoliciesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_attached_user_policies_pages(input),
+            |output: ListAttachedUserPoliciesResponse| output.attached_policies.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_entities_for_policy`, streaming every page of results.
+    pub fn list_entities_for_policy_pages(
+        &self,
+        input: ListEntitiesForPolicyRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListEntitiesForPolicyResponse,
+        ListEntitiesForPolicyError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IamClient, input: ListEntitiesForPolicyRequest| async move {
+                client.list_entities_for_policy(input).await
+            },
+            |input: &ListEntitiesForPolicyRequest, output: &ListEntitiesForPolicyResponse| {
+                if !output.is_truncated.unwrap_or(false) {
+                    return None;
+                }
+                Some(ListEntitiesForPolicyRequest {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_entities_for_policy`, streaming the `PolicyGroups` of every page of results.
+    pub fn list_entities_for_policy_items(
+        &self,
+        input: ListEntitiesForPolicyRequest,
+    ) -> rusoto_core::pagination::RusotoStream<PolicyGroup, ListEntitiesForPolicyError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_entities_for_policy_pages(input),
+            |output: ListEntitiesForPolicyResponse| output.policy_groups.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_group_policies`, streaming every page of results.
+    pub fn list_group_policies_pages(
+        &self,
+        input: ListGroupPoliciesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListGroupPoliciesResponse, ListGroupPoliciesError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IamClient, input: ListGroupPoliciesRequest| async move {
+                client.list_group_policies(input).await
+            },
+            |input: &ListGroupPoliciesRequest, output: &ListGroupPoliciesResponse| {
+                if !output.is_truncated.unwrap_or(false) {
+                    return None;
+                }
+                Some(ListGroupPoliciesRequest {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_group_policies`, streaming the `PolicyNames` of every page of results.
+    pub fn list_group_policies_items(
+        &self,
+        input: ListGroupPoliciesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListGroupPoliciesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_group_policies_pages(input),
+            |output: ListGroupPoliciesResponse| output.policy_names,
+        )
+    }
+
+    /// Auto-paginating version of `list_groups`, streaming every page of results.
+    pub fn list_groups_pages(
+        &self,
+        input: ListGroupsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListGroupsResponse, ListGroupsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IamClient, input: ListGroupsRequest| async move { client.list_groups(input).await },
+            |input: &ListGroupsRequest, output: &ListGroupsResponse| {
+                if !output.is_truncated.unwrap_or(false) {
+                    return None;
+                }
+                Some(ListGroupsRequest {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_groups`, streaming the `Groups` of every page of results.
+    pub fn list_groups_items(
+        &self,
+        input: ListGroupsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Group, ListGroupsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_groups_pages(input),
+            |output: ListGroupsResponse| output.groups,
+        )
+    }
+
+    /// Auto-paginating version of `list_groups_for_user`, streaming every page of results.
+    pub fn list_groups_for_user_pages(
+        &self,
+        input: ListGroupsForUserRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListGroupsForUserResponse, ListGroupsForUserError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IamClient, input: ListGroupsForUserRequest| async move {
+                client.list_groups_for_user(input).await
+            },
+            |input: &ListGroupsForUserRequest, output: &ListGroupsForUserResponse| {
+                if !output.is_truncated.unwrap_or(false) {
+                    return None;
+                }
+                Some(ListGroupsForUserRequest {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_groups_for_user`, streaming the `Groups` of every page of results.
+    pub fn list_groups_for_user_items(
+        &self,
+        input: ListGroupsForUserRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Group, ListGroupsForUserError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_groups_for_user_pages(input),
+            |output: ListGroupsForUserResponse| output.groups,
+        )
+    }
+
+    /// Auto-paginating version of `list_instance_profiles`, streaming every page of results.
+    pub fn list_instance_profiles_pages(
+        &self,
+        input: ListInstanceProfilesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListInstanceProfilesResponse,
+        ListInstanceProfilesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IamClient, input: ListInstanceProfilesRequest| async move {
+                client.list_instance_profiles(input).await
+            },
+            |input: &ListInstanceProfilesRequest, output: &ListInstanceProfilesResponse| {
+                if !output.is_truncated.unwrap_or(false) {
+                    return None;
+                }
+                Some(ListInstanceProfilesRequest {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_instance_profiles`, streaming the `InstanceProfiles` of every page of results.
+    pub fn list_instance_profiles_items(
+        &self,
+        input: ListInstanceProfilesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<InstanceProfile, ListInstanceProfilesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_instance_profiles_pages(input),
+            |output: ListInstanceProfilesResponse| output.instance_profiles,
+        )
+    }
+
+    /// Auto-paginating version of `list_instance_profiles_for_role`, streaming every page of results.
+    pub fn list_instance_profiles_for_role_pages(
+        &self,
+        input: ListInstanceProfilesForRoleRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListInstanceProfilesForRoleResponse,
+        ListInstanceProfilesForRoleError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IamClient, input: ListInstanceProfilesForRoleRequest| async move {
+                client.list_instance_profiles_for_role(input).await
+            },
+            |input: &ListInstanceProfilesForRoleRequest,
+             output: &ListInstanceProfilesForRoleResponse| {
+                if !output.is_truncated.unwrap_or(false) {
+                    return None;
+                }
+                Some(ListInstanceProfilesForRoleRequest {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_instance_profiles_for_role`, streaming the `InstanceProfiles` of every page of results.
+    pub fn list_instance_profiles_for_role_items(
+        &self,
+        input: ListInstanceProfilesForRoleRequest,
+    ) -> rusoto_core::pagination::RusotoStream<InstanceProfile, ListInstanceProfilesForRoleError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_instance_profiles_for_role_pages(input),
+            |output: ListInstanceProfilesForRoleResponse| output.instance_profiles,
+        )
+    }
+
+    /// Auto-paginating version of `list_mfa_devices`, streaming every page of results.
+    pub fn list_mfa_devices_pages(
+        &self,
+        input: ListMFADevicesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListMFADevicesResponse, ListMFADevicesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IamClient, input: ListMFADevicesRequest| async move {
+                client.list_mfa_devices(input).await
+            },
+            |input: &ListMFADevicesRequest, output: &ListMFADevicesResponse| {
+                if !output.is_truncated.unwrap_or(false) {
+                    return None;
+                }
+                Some(ListMFADevicesRequest {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_mfa_devices`, streaming the `MFADevices` of every page of results.
+    pub fn list_mfa_devices_items(
+        &self,
+        input: ListMFADevicesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<MFADevice, ListMFADevicesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_mfa_devices_pages(input),
+            |output: ListMFADevicesResponse| output.mfa_devices,
+        )
+    }
+
+    /// Auto-paginating version of `list_policies`, streaming every page of results.
+    pub fn list_policies_pages(
+        &self,
+        input: ListPoliciesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListPoliciesResponse, ListPoliciesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IamClient, input: ListPoliciesRequest| async move {
+                client.list_policies(input).await
+            },
+            |input: &ListPoliciesRequest, output: &ListPoliciesResponse| {
+                if !output.is_truncated.unwrap_or(false) {
+                    return None;
+                }
+                Some(ListPoliciesRequest {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_policies`, streaming the `Policies` of every page of results.
+    pub fn list_policies_items(
+        &self,
+        input: ListPoliciesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Policy, ListPoliciesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_policies_pages(input),
+            |output: ListPoliciesResponse| output.policies.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_policy_versions`, streaming every page of results.
+    pub fn list_policy_versions_pages(
+        &self,
+        input: ListPolicyVersionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListPolicyVersionsResponse, ListPolicyVersionsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IamClient, input: ListPolicyVersionsRequest| async move {
+                client.list_policy_versions(input).await
+            },
+            |input: &ListPolicyVersionsRequest, output: &ListPolicyVersionsResponse| {
+                if !output.is_truncated.unwrap_or(false) {
+                    return None;
+                }
+                Some(ListPolicyVersionsRequest {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_policy_versions`, streaming the `Versions` of every page of results.
+    pub fn list_policy_versions_items(
+        &self,
+        input: ListPolicyVersionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<PolicyVersion, ListPolicyVersionsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_policy_versions_pages(input),
+            |output: ListPolicyVersionsResponse| output.versions.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_role_policies`, streaming every page of results.
+    pub fn list_role_policies_pages(
+        &self,
+        input: ListRolePoliciesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListRolePoliciesResponse, ListRolePoliciesError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IamClient, input: ListRolePoliciesRequest| async move {
+                client.list_role_policies(input).await
+            },
+            |input: &ListRolePoliciesRequest, output: &ListRolePoliciesResponse| {
+                if !output.is_truncated.unwrap_or(false) {
+                    return None;
+                }
+                Some(ListRolePoliciesRequest {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_role_policies`, streaming the `PolicyNames` of every page of results.
+    pub fn list_role_policies_items(
+        &self,
+        input: ListRolePoliciesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListRolePoliciesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_role_policies_pages(input),
+            |output: ListRolePoliciesResponse| output.policy_names,
+        )
+    }
+
+    /// Auto-paginating version of `list_role_tags`, streaming every page of results.
+    pub fn list_role_tags_pages(
+        &self,
+        input: ListRoleTagsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListRoleTagsResponse, ListRoleTagsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IamClient, input: ListRoleTagsRequest| async move {
+                client.list_role_tags(input).await
+            },
+            |input: &ListRoleTagsRequest, output: &ListRoleTagsResponse| {
+                if !output.is_truncated.unwrap_or(false) {
+                    return None;
+                }
+                Some(ListRoleTagsRequest {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_role_tags`, streaming the `Tags` of every page of results.
+    pub fn list_role_tags_items(
+        &self,
+        input: ListRoleTagsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Tag, ListRoleTagsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_role_tags_pages(input),
+            |output: ListRoleTagsResponse| output.tags,
+        )
+    }
+
+    /// Auto-paginating version of `list_roles`, streaming every page of results.
+    pub fn list_roles_pages(
+        &self,
+        input: ListRolesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListRolesResponse, ListRolesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IamClient, input: ListRolesRequest| async move { client.list_roles(input).await },
+            |input: &ListRolesRequest, output: &ListRolesResponse| {
+                if !output.is_truncated.unwrap_or(false) {
+                    return None;
+                }
+                Some(ListRolesRequest {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_roles`, streaming the `Roles` of every page of results.
+    pub fn list_roles_items(
+        &self,
+        input: ListRolesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Role, ListRolesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_roles_pages(input),
+            |output: ListRolesResponse| output.roles,
+        )
+    }
+
+    /// Auto-paginating version of `list_ssh_public_keys`, streaming every page of results.
+    pub fn list_ssh_public_keys_pages(
+        &self,
+        input: ListSSHPublicKeysRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListSSHPublicKeysResponse, ListSSHPublicKeysError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IamClient, input: ListSSHPublicKeysRequest| async move {
+                client.list_ssh_public_keys(input).await
+            },
+            |input: &ListSSHPublicKeysRequest, output: &ListSSHPublicKeysResponse| {
+                if !output.is_truncated.unwrap_or(false) {
+                    return None;
+                }
+                Some(ListSSHPublicKeysRequest {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_ssh_public_keys`, streaming the `SSHPublicKeys` of every page of results.
+    pub fn list_ssh_public_keys_items(
+        &self,
+        input: ListSSHPublicKeysRequest,
+    ) -> rusoto_core::pagination::RusotoStream<SSHPublicKeyMetadata, ListSSHPublicKeysError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_ssh_public_keys_pages(input),
+            |output: ListSSHPublicKeysResponse| output.ssh_public_keys.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_server_certificates`, streaming every page of results.
+    pub fn list_server_certificates_pages(
+        &self,
+        input: ListServerCertificatesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListServerCertificatesResponse,
+        ListServerCertificatesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IamClient, input: ListServerCertificatesRequest| async move {
+                client.list_server_certificates(input).await
+            },
+            |input: &ListServerCertificatesRequest, output: &ListServerCertificatesResponse| {
+                if !output.is_truncated.unwrap_or(false) {
+                    return None;
+                }
+                Some(ListServerCertificatesRequest {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_server_certificates`, streaming the `ServerCertificateMetadataList` of every page of results.
+    pub fn list_server_certificates_items(
+        &self,
+        input: ListServerCertificatesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ServerCertificateMetadata, ListServerCertificatesError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_server_certificates_pages(input),
+            |output: ListServerCertificatesResponse| output.server_certificate_metadata_list,
+        )
+    }
+
+    /// Auto-paginating version of `list_signing_certificates`, streaming every page of results.
+    pub fn list_signing_certificates_pages(
+        &self,
+        input: ListSigningCertificatesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListSigningCertificatesResponse,
+        ListSigningCertificatesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IamClient, input: ListSigningCertificatesRequest| async move {
+                client.list_signing_certificates(input).await
+            },
+            |input: &ListSigningCertificatesRequest, output: &ListSigningCertificatesResponse| {
+                if !output.is_truncated.unwrap_or(false) {
+                    return None;
+                }
+                Some(ListSigningCertificatesRequest {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_signing_certificates`, streaming the `Certificates` of every page of results.
+    pub fn list_signing_certificates_items(
+        &self,
+        input: ListSigningCertificatesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<SigningCertificate, ListSigningCertificatesError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_signing_certificates_pages(input),
+            |output: ListSigningCertificatesResponse| output.certificates,
+        )
+    }
+
+    /// Auto-paginating version of `list_user_policies`, streaming every page of results.
+    pub fn list_user_policies_pages(
+        &self,
+        input: ListUserPoliciesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListUserPoliciesResponse, ListUserPoliciesError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IamClient, input: ListUserPoliciesRequest| async move {
+                client.list_user_policies(input).await
+            },
+            |input: &ListUserPoliciesRequest, output: &ListUserPoliciesResponse| {
+                if !output.is_truncated.unwrap_or(false) {
+                    return None;
+                }
+                Some(ListUserPoliciesRequest {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_user_policies`, streaming the `PolicyNames` of every page of results.
+    pub fn list_user_policies_items(
+        &self,
+        input: ListUserPoliciesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListUserPoliciesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_user_policies_pages(input),
+            |output: ListUserPoliciesResponse| output.policy_names,
+        )
+    }
+
+    /// Auto-paginating version of `list_user_tags`, streaming every page of results.
+    pub fn list_user_tags_pages(
+        &self,
+        input: ListUserTagsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListUserTagsResponse, ListUserTagsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IamClient, input: ListUserTagsRequest| async move {
+                client.list_user_tags(input).await
+            },
+            |input: &ListUserTagsRequest, output: &ListUserTagsResponse| {
+                if !output.is_truncated.unwrap_or(false) {
+                    return None;
+                }
+                Some(ListUserTagsRequest {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_user_tags`, streaming the `Tags` of every page of results.
+    pub fn list_user_tags_items(
+        &self,
+        input: ListUserTagsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Tag, ListUserTagsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_user_tags_pages(input),
+            |output: ListUserTagsResponse| output.tags,
+        )
+    }
+
+    /// Auto-paginating version of `list_users`, streaming every page of results.
+    pub fn list_users_pages(
+        &self,
+        input: ListUsersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListUsersResponse, ListUsersError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IamClient, input: ListUsersRequest| async move { client.list_users(input).await },
+            |input: &ListUsersRequest, output: &ListUsersResponse| {
+                if !output.is_truncated.unwrap_or(false) {
+                    return None;
+                }
+                Some(ListUsersRequest {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_users`, streaming the `Users` of every page of results.
+    pub fn list_users_items(
+        &self,
+        input: ListUsersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<User, ListUsersError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_users_pages(input),
+            |output: ListUsersResponse| output.users,
+        )
+    }
+
+    /// Auto-paginating version of `list_virtual_mfa_devices`, streaming every page of results.
+    pub fn list_virtual_mfa_devices_pages(
+        &self,
+        input: ListVirtualMFADevicesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListVirtualMFADevicesResponse,
+        ListVirtualMFADevicesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IamClient, input: ListVirtualMFADevicesRequest| async move {
+                client.list_virtual_mfa_devices(input).await
+            },
+            |input: &ListVirtualMFADevicesRequest, output: &ListVirtualMFADevicesResponse| {
+                if !output.is_truncated.unwrap_or(false) {
+                    return None;
+                }
+                Some(ListVirtualMFADevicesRequest {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_virtual_mfa_devices`, streaming the `VirtualMFADevices` of every page of results.
+    pub fn list_virtual_mfa_devices_items(
+        &self,
+        input: ListVirtualMFADevicesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<VirtualMFADevice, ListVirtualMFADevicesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_virtual_mfa_devices_pages(input),
+            |output: ListVirtualMFADevicesResponse| output.virtual_mfa_devices,
+        )
+    }
+
+    /// Auto-paginating version of `simulate_custom_policy`, streaming every page of results.
+    pub fn simulate_custom_policy_pages(
+        &self,
+        input: SimulateCustomPolicyRequest,
+    ) -> rusoto_core::pagination::RusotoStream<SimulatePolicyResponse, SimulateCustomPolicyError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IamClient, input: SimulateCustomPolicyRequest| async move {
+                client.simulate_custom_policy(input).await
+            },
+            |input: &SimulateCustomPolicyRequest, output: &SimulatePolicyResponse| {
+                if !output.is_truncated.unwrap_or(false) {
+                    return None;
+                }
+                Some(SimulateCustomPolicyRequest {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `simulate_custom_policy`, streaming the `EvaluationResults` of every page of results.
+    pub fn simulate_custom_policy_items(
+        &self,
+        input: SimulateCustomPolicyRequest,
+    ) -> rusoto_core::pagination::RusotoStream<EvaluationResult, SimulateCustomPolicyError> {
+        rusoto_core::pagination::paginate_items(
+            self.simulate_custom_policy_pages(input),
+            |output: SimulatePolicyResponse| output.evaluation_results.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `simulate_principal_policy`, streaming every page of results.
+    pub fn simulate_principal_policy_pages(
+        &self,
+        input: SimulatePrincipalPolicyRequest,
+    ) -> rusoto_core::pagination::RusotoStream<SimulatePolicyResponse, SimulatePrincipalPolicyError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IamClient, input: SimulatePrincipalPolicyRequest| async move {
+                client.simulate_principal_policy(input).await
+            },
+            |input: &SimulatePrincipalPolicyRequest, output: &SimulatePolicyResponse| {
+                if !output.is_truncated.unwrap_or(false) {
+                    return None;
+                }
+                Some(SimulatePrincipalPolicyRequest {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `simulate_principal_policy`, streaming the `EvaluationResults` of every page of results.
+    pub fn simulate_principal_policy_items(
+        &self,
+        input: SimulatePrincipalPolicyRequest,
+    ) -> rusoto_core::pagination::RusotoStream<EvaluationResult, SimulatePrincipalPolicyError> {
+        rusoto_core::pagination::paginate_items(
+            self.simulate_principal_policy_pages(input),
+            |output: SimulatePolicyResponse| output.evaluation_results.unwrap_or_default(),
+        )
+    }
+}
+impl IamClient {
+    /// Waits until `get_instance_profile` reports the `InstanceProfileExists` state, polling every 1 seconds up to 40 times.
+    pub async fn wait_until_instance_profile_exists(
+        &self,
+        input: GetInstanceProfileRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetInstanceProfileError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(1), 40);
+        self.wait_until_instance_profile_exists_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_instance_profile_exists`, polling as often and as many times as `config` says.
+    pub async fn wait_until_instance_profile_exists_with_config(
+        &self,
+        input: GetInstanceProfileRequest,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetInstanceProfileError>> {
+        rusoto_core::waiter::wait(
+            "InstanceProfileExists",
+            &config,
+            || self.get_instance_profile(input.clone()),
+            |result: &Result<GetInstanceProfileResponse, RusotoError<GetInstanceProfileError>>| {
+                match result {
+                    Ok(_) => Some(rusoto_core::waiter::WaiterState::Success),
+                    Err(RusotoError::Service(GetInstanceProfileError::NoSuchEntity(_))) => {
+                        Some(rusoto_core::waiter::WaiterState::Retry)
+                    }
+                    Err(RusotoError::Unknown(response)) if response.status.as_u16() == 404 => {
+                        Some(rusoto_core::waiter::WaiterState::Retry)
+                    }
+                    _ => None,
+                }
+            },
+        )
+        .await
+    }
+
+    /// Waits until `get_policy` reports the `PolicyExists` state, polling every 1 seconds up to 20 times.
+    pub async fn wait_until_policy_exists(
+        &self,
+        input: GetPolicyRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetPolicyError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(1), 20);
+        self.wait_until_policy_exists_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_policy_exists`, polling as often and as many times as `config` says.
+    pub async fn wait_until_policy_exists_with_config(
+        &self,
+        input: GetPolicyRequest,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetPolicyError>> {
+        rusoto_core::waiter::wait(
+            "PolicyExists",
+            &config,
+            || self.get_policy(input.clone()),
+            |result: &Result<GetPolicyResponse, RusotoError<GetPolicyError>>| match result {
+                Ok(_) => Some(rusoto_core::waiter::WaiterState::Success),
+                Err(RusotoError::Service(GetPolicyError::NoSuchEntity(_))) => {
+                    Some(rusoto_core::waiter::WaiterState::Retry)
+                }
+                Err(RusotoError::Unknown(response))
+                    if rusoto_core::retry::error_code(response).as_deref()
+                        == Some("NoSuchEntity") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Retry)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+
+    /// Waits until `get_role` reports the `RoleExists` state, polling every 1 seconds up to 20 times.
+    pub async fn wait_until_role_exists(
+        &self,
+        input: GetRoleRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetRoleError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(1), 20);
+        self.wait_until_role_exists_with_config(input, config).await
+    }
+
+    /// Same as `wait_until_role_exists`, polling as often and as many times as `config` says.
+    pub async fn wait_until_role_exists_with_config(
+        &self,
+        input: GetRoleRequest,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetRoleError>> {
+        rusoto_core::waiter::wait(
+            "RoleExists",
+            &config,
+            || self.get_role(input.clone()),
+            |result: &Result<GetRoleResponse, RusotoError<GetRoleError>>| match result {
+                Ok(_) => Some(rusoto_core::waiter::WaiterState::Success),
+                Err(RusotoError::Service(GetRoleError::NoSuchEntity(_))) => {
+                    Some(rusoto_core::waiter::WaiterState::Retry)
+                }
+                Err(RusotoError::Unknown(response))
+                    if rusoto_core::retry::error_code(response).as_deref()
+                        == Some("NoSuchEntity") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Retry)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+
+    /// Waits until `get_user` reports the `UserExists` state, polling every 1 seconds up to 20 times.
+    pub async fn wait_until_user_exists(
+        &self,
+        input: GetUserRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetUserError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(1), 20);
+        self.wait_until_user_exists_with_config(input, config).await
+    }
+
+    /// Same as `wait_until_user_exists`, polling as often and as many times as `config` says.
+    pub async fn wait_until_user_exists_with_config(
+        &self,
+        input: GetUserRequest,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetUserError>> {
+        rusoto_core::waiter::wait(
+            "UserExists",
+            &config,
+            || self.get_user(input.clone()),
+            |result: &Result<GetUserResponse, RusotoError<GetUserError>>| match result {
+                Ok(_) => Some(rusoto_core::waiter::WaiterState::Success),
+                Err(RusotoError::Service(GetUserError::NoSuchEntity(_))) => {
+                    Some(rusoto_core::waiter::WaiterState::Retry)
+                }
+                Err(RusotoError::Unknown(response))
+                    if rusoto_core::retry::error_code(response).as_deref()
+                        == Some("NoSuchEntity") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Retry)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+}
 
 #[cfg(test)]
 mod protocol_tests {
@@ -142,7 +142,7 @@ pub struct Component {
     /// <p>The platform of the component.</p>
     #[serde(rename = "platform")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub platform: Option<String>,
+    pub platform: Option<Platform>,
     /// <p>The tags associated with the component.</p>
     #[serde(rename = "tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -150,7 +150,7 @@ pub struct Component {
     /// <p>The type of the component denotes whether the component is used to build the image or only to test it.</p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<ComponentType>,
     /// <p>The version of the component.</p>
     #[serde(rename = "version")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -165,6 +165,94 @@ pub struct ComponentConfiguration {
     pub component_arn: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ComponentFormat {
+    /// `SHELL`
+    Shell,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ComponentFormat {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ComponentFormat::Shell => "SHELL",
+            ComponentFormat::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ComponentFormat {
+    fn default() -> ComponentFormat {
+        ComponentFormat::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ComponentFormat {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ComponentFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ComponentFormat {
+    fn from(value: &'a str) -> ComponentFormat {
+        match value {
+            "SHELL" => ComponentFormat::Shell,
+            _ => ComponentFormat::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ComponentFormat {
+    fn from(value: String) -> ComponentFormat {
+        match value.as_str() {
+            "SHELL" => ComponentFormat::Shell,
+            _ => ComponentFormat::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ComponentFormat {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ComponentFormat, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ComponentFormat {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ComponentFormat {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ComponentFormat {
+    fn deserialize<D>(deserializer: D) -> Result<ComponentFormat, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A high level summary of a component.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -196,7 +284,7 @@ pub struct ComponentSummary {
     /// <p>The platform of the component.</p>
     #[serde(rename = "platform")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub platform: Option<String>,
+    pub platform: Option<Platform>,
     /// <p>The tags associated with the component.</p>
     #[serde(rename = "tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -204,13 +292,106 @@ pub struct ComponentSummary {
     /// <p>The type of the component denotes whether the component is used to build the image or only to test it.</p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<ComponentType>,
     /// <p>The version of the component.</p>
     #[serde(rename = "version")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub version: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ComponentType {
+    /// `BUILD`
+    Build,
+    /// `TEST`
+    Test,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ComponentType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ComponentType::Build => "BUILD",
+            ComponentType::Test => "TEST",
+            ComponentType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ComponentType {
+    fn default() -> ComponentType {
+        ComponentType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ComponentType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ComponentType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ComponentType {
+    fn from(value: &'a str) -> ComponentType {
+        match value {
+            "BUILD" => ComponentType::Build,
+            "TEST" => ComponentType::Test,
+            _ => ComponentType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ComponentType {
+    fn from(value: String) -> ComponentType {
+        match value.as_str() {
+            "BUILD" => ComponentType::Build,
+            "TEST" => ComponentType::Test,
+            _ => ComponentType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ComponentType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ComponentType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ComponentType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ComponentType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ComponentType {
+    fn deserialize<D>(deserializer: D) -> Result<ComponentType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A high level overview of a component semantic version.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -238,11 +419,11 @@ pub struct ComponentVersion {
     /// <p>The platform of the component.</p>
     #[serde(rename = "platform")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub platform: Option<String>,
+    pub platform: Option<Platform>,
     /// <p>The type of the component denotes whether the component is used to build the image or only to test it.</p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<ComponentType>,
     /// <p>The semantic version of the component.</p>
     #[serde(rename = "version")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -276,7 +457,7 @@ pub struct CreateComponentRequest {
     pub name: String,
     /// <p>CThe platform of the component.</p>
     #[serde(rename = "platform")]
-    pub platform: String,
+    pub platform: Platform,
     /// <p>The semantic version of the component. This version to follow the semantic version syntax. i.e. major.minor.patch. This could be versioned like software 2.0.1 or date like 2019.12.01.</p>
     #[serde(rename = "semanticVersion")]
     pub semantic_version: String,
@@ -380,7 +561,7 @@ pub struct CreateImagePipelineRequest {
     /// <p> The status of the image pipeline. </p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<PipelineStatus>,
     /// <p> The tags of the image pipeline. </p>
     #[serde(rename = "tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -802,9 +983,127 @@ pub struct EbsInstanceBlockDeviceSpecification {
     /// <p>Use to override the device's volume type.</p>
     #[serde(rename = "volumeType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub volume_type: Option<String>,
+    pub volume_type: Option<EbsVolumeType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EbsVolumeType {
+    /// `standard`
+    Standard,
+    /// `io1`
+    Io1,
+    /// `io2`
+    Io2,
+    /// `gp2`
+    Gp2,
+    /// `gp3`
+    Gp3,
+    /// `sc1`
+    Sc1,
+    /// `st1`
+    St1,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EbsVolumeType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EbsVolumeType::Standard => "standard",
+            EbsVolumeType::Io1 => "io1",
+            EbsVolumeType::Io2 => "io2",
+            EbsVolumeType::Gp2 => "gp2",
+            EbsVolumeType::Gp3 => "gp3",
+            EbsVolumeType::Sc1 => "sc1",
+            EbsVolumeType::St1 => "st1",
+            EbsVolumeType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EbsVolumeType {
+    fn default() -> EbsVolumeType {
+        EbsVolumeType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EbsVolumeType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EbsVolumeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EbsVolumeType {
+    fn from(value: &'a str) -> EbsVolumeType {
+        match value {
+            "standard" => EbsVolumeType::Standard,
+            "io1" => EbsVolumeType::Io1,
+            "io2" => EbsVolumeType::Io2,
+            "gp2" => EbsVolumeType::Gp2,
+            "gp3" => EbsVolumeType::Gp3,
+            "sc1" => EbsVolumeType::Sc1,
+            "st1" => EbsVolumeType::St1,
+            _ => EbsVolumeType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EbsVolumeType {
+    fn from(value: String) -> EbsVolumeType {
+        match value.as_str() {
+            "standard" => EbsVolumeType::Standard,
+            "io1" => EbsVolumeType::Io1,
+            "io2" => EbsVolumeType::Io2,
+            "gp2" => EbsVolumeType::Gp2,
+            "gp3" => EbsVolumeType::Gp3,
+            "sc1" => EbsVolumeType::Sc1,
+            "st1" => EbsVolumeType::St1,
+            _ => EbsVolumeType::Unknown(value),
+        }
+    }
 }
 
+impl ::std::str::FromStr for EbsVolumeType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EbsVolumeType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EbsVolumeType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EbsVolumeType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EbsVolumeType {
+    fn deserialize<D>(deserializer: D) -> Result<EbsVolumeType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p> </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -1049,7 +1348,7 @@ pub struct Image {
     /// <p>The platform of the image.</p>
     #[serde(rename = "platform")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub platform: Option<String>,
+    pub platform: Option<Platform>,
     /// <p>The Amazon Resource Name (ARN) of the image pipeline that created this image.</p>
     #[serde(rename = "sourcePipelineArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1123,7 +1422,7 @@ pub struct ImagePipeline {
     /// <p>The platform of the image pipeline.</p>
     #[serde(rename = "platform")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub platform: Option<String>,
+    pub platform: Option<Platform>,
     /// <p>The schedule of the image pipeline.</p>
     #[serde(rename = "schedule")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1131,7 +1430,7 @@ pub struct ImagePipeline {
     /// <p>The status of the image pipeline.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<PipelineStatus>,
     /// <p>The tags of this image pipeline.</p>
     #[serde(rename = "tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1177,7 +1476,7 @@ pub struct ImageRecipe {
     /// <p>The platform of the image recipe.</p>
     #[serde(rename = "platform")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub platform: Option<String>,
+    pub platform: Option<Platform>,
     /// <p>The tags of the image recipe.</p>
     #[serde(rename = "tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1215,7 +1514,7 @@ pub struct ImageRecipeSummary {
     /// <p>The platform of the image recipe.</p>
     #[serde(rename = "platform")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub platform: Option<String>,
+    pub platform: Option<Platform>,
     /// <p>The tags of the image recipe.</p>
     #[serde(rename = "tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1233,9 +1532,152 @@ pub struct ImageState {
     /// <p> The status of the image. </p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ImageStatus>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ImageStatus {
+    /// `PENDING`
+    Pending,
+    /// `CREATING`
+    Creating,
+    /// `BUILDING`
+    Building,
+    /// `TESTING`
+    Testing,
+    /// `DISTRIBUTING`
+    Distributing,
+    /// `INTEGRATING`
+    Integrating,
+    /// `AVAILABLE`
+    Available,
+    /// `CANCELLED`
+    Cancelled,
+    /// `FAILED`
+    Failed,
+    /// `DEPRECATED`
+    Deprecated,
+    /// `DELETED`
+    Deleted,
+    /// `DISABLED`
+    Disabled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ImageStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ImageStatus::Pending => "PENDING",
+            ImageStatus::Creating => "CREATING",
+            ImageStatus::Building => "BUILDING",
+            ImageStatus::Testing => "TESTING",
+            ImageStatus::Distributing => "DISTRIBUTING",
+            ImageStatus::Integrating => "INTEGRATING",
+            ImageStatus::Available => "AVAILABLE",
+            ImageStatus::Cancelled => "CANCELLED",
+            ImageStatus::Failed => "FAILED",
+            ImageStatus::Deprecated => "DEPRECATED",
+            ImageStatus::Deleted => "DELETED",
+            ImageStatus::Disabled => "DISABLED",
+            ImageStatus::Unknown(ref value) => value,
+        }
+    }
 }
 
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ImageStatus {
+    fn default() -> ImageStatus {
+        ImageStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ImageStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ImageStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ImageStatus {
+    fn from(value: &'a str) -> ImageStatus {
+        match value {
+            "PENDING" => ImageStatus::Pending,
+            "CREATING" => ImageStatus::Creating,
+            "BUILDING" => ImageStatus::Building,
+            "TESTING" => ImageStatus::Testing,
+            "DISTRIBUTING" => ImageStatus::Distributing,
+            "INTEGRATING" => ImageStatus::Integrating,
+            "AVAILABLE" => ImageStatus::Available,
+            "CANCELLED" => ImageStatus::Cancelled,
+            "FAILED" => ImageStatus::Failed,
+            "DEPRECATED" => ImageStatus::Deprecated,
+            "DELETED" => ImageStatus::Deleted,
+            "DISABLED" => ImageStatus::Disabled,
+            _ => ImageStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ImageStatus {
+    fn from(value: String) -> ImageStatus {
+        match value.as_str() {
+            "PENDING" => ImageStatus::Pending,
+            "CREATING" => ImageStatus::Creating,
+            "BUILDING" => ImageStatus::Building,
+            "TESTING" => ImageStatus::Testing,
+            "DISTRIBUTING" => ImageStatus::Distributing,
+            "INTEGRATING" => ImageStatus::Integrating,
+            "AVAILABLE" => ImageStatus::Available,
+            "CANCELLED" => ImageStatus::Cancelled,
+            "FAILED" => ImageStatus::Failed,
+            "DEPRECATED" => ImageStatus::Deprecated,
+            "DELETED" => ImageStatus::Deleted,
+            "DISABLED" => ImageStatus::Disabled,
+            _ => ImageStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ImageStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ImageStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ImageStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ImageStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ImageStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ImageStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An image summary.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1263,7 +1705,7 @@ pub struct ImageSummary {
     /// <p>The platform of the image.</p>
     #[serde(rename = "platform")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub platform: Option<String>,
+    pub platform: Option<Platform>,
     /// <p>The state of the image.</p>
     #[serde(rename = "state")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1314,7 +1756,7 @@ pub struct ImageVersion {
     /// <p>The platform of the image semantic version.</p>
     #[serde(rename = "platform")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub platform: Option<String>,
+    pub platform: Option<Platform>,
     /// <p>The semantic version of the image semantic version.</p>
     #[serde(rename = "version")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1341,7 +1783,7 @@ pub struct ImportComponentRequest {
     pub description: Option<String>,
     /// <p> The format of the resource that you wish to import as a component. </p>
     #[serde(rename = "format")]
-    pub format: String,
+    pub format: ComponentFormat,
     /// <p> The ID of the KMS key that should be used to encrypt this component. </p>
     #[serde(rename = "kmsKeyId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1351,7 +1793,7 @@ pub struct ImportComponentRequest {
     pub name: String,
     /// <p> The platform of the component. </p>
     #[serde(rename = "platform")]
-    pub platform: String,
+    pub platform: Platform,
     /// <p> The semantic version of the component. This version to follow the semantic version syntax. i.e. major.minor.patch. This could be versioned like software 2.0.1 or date like 2019.12.01. </p>
     #[serde(rename = "semanticVersion")]
     pub semantic_version: String,
@@ -1361,7 +1803,7 @@ pub struct ImportComponentRequest {
     pub tags: Option<::std::collections::HashMap<String, String>>,
     /// <p> The type of the component denotes whether the component is used to build the image or only to test it. </p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: ComponentType,
     /// <p> The uri of the component. </p>
     #[serde(rename = "uri")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1562,7 +2004,7 @@ pub struct ListComponentsRequest {
     /// <p> The owner defines whose components you wish to list. By default this request will only show components owned by your account. You may use this field to specify if you wish to view components owned by yourself, Amazon, or those components that have been shared with you by other customers. </p>
     #[serde(rename = "owner")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub owner: Option<String>,
+    pub owner: Option<Ownership>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1743,7 +2185,7 @@ pub struct ListImageRecipesRequest {
     /// <p> The owner defines whose image recipes you wish to list. By default this request will only show image recipes owned by your account. You may use this field to specify if you wish to view image recipes owned by yourself, Amazon, or those image recipes that have been shared with you by other customers. </p>
     #[serde(rename = "owner")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub owner: Option<String>,
+    pub owner: Option<Ownership>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1781,7 +2223,7 @@ pub struct ListImagesRequest {
     /// <p> The owner defines whose images you wish to list. By default this request will only show images owned by your account. You may use this field to specify if you wish to view images owned by yourself, Amazon, or those images that have been shared with you by other customers. </p>
     #[serde(rename = "owner")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub owner: Option<String>,
+    pub owner: Option<Ownership>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1871,6 +2313,394 @@ pub struct OutputResources {
     pub amis: Option<Vec<Ami>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Ownership {
+    /// `Self`
+    SelfValue,
+    /// `Shared`
+    Shared,
+    /// `Amazon`
+    Amazon,
+    /// `ThirdParty`
+    ThirdParty,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Ownership {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Ownership::SelfValue => "Self",
+            Ownership::Shared => "Shared",
+            Ownership::Amazon => "Amazon",
+            Ownership::ThirdParty => "ThirdParty",
+            Ownership::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Ownership {
+    fn default() -> Ownership {
+        Ownership::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Ownership {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Ownership {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Ownership {
+    fn from(value: &'a str) -> Ownership {
+        match value {
+            "Self" => Ownership::SelfValue,
+            "Shared" => Ownership::Shared,
+            "Amazon" => Ownership::Amazon,
+            "ThirdParty" => Ownership::ThirdParty,
+            _ => Ownership::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Ownership {
+    fn from(value: String) -> Ownership {
+        match value.as_str() {
+            "Self" => Ownership::SelfValue,
+            "Shared" => Ownership::Shared,
+            "Amazon" => Ownership::Amazon,
+            "ThirdParty" => Ownership::ThirdParty,
+            _ => Ownership::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Ownership {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Ownership, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Ownership {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Ownership {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Ownership {
+    fn deserialize<D>(deserializer: D) -> Result<Ownership, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PipelineExecutionStartCondition {
+    /// `EXPRESSION_MATCH_ONLY`
+    ExpressionMatchOnly,
+    /// `EXPRESSION_MATCH_AND_DEPENDENCY_UPDATES_AVAILABLE`
+    ExpressionMatchAndDependencyUpdatesAvailable,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PipelineExecutionStartCondition {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PipelineExecutionStartCondition::ExpressionMatchOnly => "EXPRESSION_MATCH_ONLY",
+            PipelineExecutionStartCondition::ExpressionMatchAndDependencyUpdatesAvailable => {
+                "EXPRESSION_MATCH_AND_DEPENDENCY_UPDATES_AVAILABLE"
+            }
+            PipelineExecutionStartCondition::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PipelineExecutionStartCondition {
+    fn default() -> PipelineExecutionStartCondition {
+        PipelineExecutionStartCondition::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PipelineExecutionStartCondition {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PipelineExecutionStartCondition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PipelineExecutionStartCondition {
+    fn from(value: &'a str) -> PipelineExecutionStartCondition {
+        match value {
+            "EXPRESSION_MATCH_ONLY" => PipelineExecutionStartCondition::ExpressionMatchOnly,
+            "EXPRESSION_MATCH_AND_DEPENDENCY_UPDATES_AVAILABLE" => {
+                PipelineExecutionStartCondition::ExpressionMatchAndDependencyUpdatesAvailable
+            }
+            _ => PipelineExecutionStartCondition::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PipelineExecutionStartCondition {
+    fn from(value: String) -> PipelineExecutionStartCondition {
+        match value.as_str() {
+            "EXPRESSION_MATCH_ONLY" => PipelineExecutionStartCondition::ExpressionMatchOnly,
+            "EXPRESSION_MATCH_AND_DEPENDENCY_UPDATES_AVAILABLE" => {
+                PipelineExecutionStartCondition::ExpressionMatchAndDependencyUpdatesAvailable
+            }
+            _ => PipelineExecutionStartCondition::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PipelineExecutionStartCondition {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PipelineExecutionStartCondition, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PipelineExecutionStartCondition {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PipelineExecutionStartCondition {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PipelineExecutionStartCondition {
+    fn deserialize<D>(deserializer: D) -> Result<PipelineExecutionStartCondition, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PipelineStatus {
+    /// `DISABLED`
+    Disabled,
+    /// `ENABLED`
+    Enabled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PipelineStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PipelineStatus::Disabled => "DISABLED",
+            PipelineStatus::Enabled => "ENABLED",
+            PipelineStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PipelineStatus {
+    fn default() -> PipelineStatus {
+        PipelineStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PipelineStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PipelineStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PipelineStatus {
+    fn from(value: &'a str) -> PipelineStatus {
+        match value {
+            "DISABLED" => PipelineStatus::Disabled,
+            "ENABLED" => PipelineStatus::Enabled,
+            _ => PipelineStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PipelineStatus {
+    fn from(value: String) -> PipelineStatus {
+        match value.as_str() {
+            "DISABLED" => PipelineStatus::Disabled,
+            "ENABLED" => PipelineStatus::Enabled,
+            _ => PipelineStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PipelineStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PipelineStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PipelineStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PipelineStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PipelineStatus {
+    fn deserialize<D>(deserializer: D) -> Result<PipelineStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Platform {
+    /// `Windows`
+    Windows,
+    /// `Linux`
+    Linux,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Platform {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Platform::Windows => "Windows",
+            Platform::Linux => "Linux",
+            Platform::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Platform {
+    fn default() -> Platform {
+        Platform::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Platform {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Platform {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Platform {
+    fn from(value: &'a str) -> Platform {
+        match value {
+            "Windows" => Platform::Windows,
+            "Linux" => Platform::Linux,
+            _ => Platform::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Platform {
+    fn from(value: String) -> Platform {
+        match value.as_str() {
+            "Windows" => Platform::Windows,
+            "Linux" => Platform::Linux,
+            _ => Platform::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Platform {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Platform, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Platform {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Platform {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Platform {
+    fn deserialize<D>(deserializer: D) -> Result<Platform, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct PutComponentPolicyRequest {
@@ -1962,7 +2792,7 @@ pub struct Schedule {
     /// <p> The condition configures when the pipeline should trigger a new image build. </p>
     #[serde(rename = "pipelineExecutionStartCondition")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub pipeline_execution_start_condition: Option<String>,
+    pub pipeline_execution_start_condition: Option<PipelineExecutionStartCondition>,
     /// <p> The expression determines how often a pipeline starts the creation of new images. </p>
     #[serde(rename = "scheduleExpression")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2099,7 +2929,7 @@ pub struct UpdateImagePipelineRequest {
     /// <p> The status of the image pipeline. </p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<PipelineStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -5733,11 +6563,12 @@ pub struct ImageBuilderClient {
 impl ImageBuilderClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> ImageBuilderClient {
         ImageBuilderClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "imagebuilder"),
         }
     }
 
@@ -5771,6 +6602,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/CancelImageCreation";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("CancelImageCreation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5801,6 +6633,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/CreateComponent";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("CreateComponent");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5834,6 +6667,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/CreateDistributionConfiguration";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("CreateDistributionConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5866,6 +6700,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/CreateImage";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("CreateImage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5896,6 +6731,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/CreateImagePipeline";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("CreateImagePipeline");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5926,6 +6762,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/CreateImageRecipe";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("CreateImageRecipe");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5959,6 +6796,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/CreateInfrastructureConfiguration";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("CreateInfrastructureConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5991,6 +6829,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/DeleteComponent";
 
         let mut request = SignedRequest::new("DELETE", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("DeleteComponent");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6028,6 +6867,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/DeleteDistributionConfiguration";
 
         let mut request = SignedRequest::new("DELETE", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("DeleteDistributionConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6064,6 +6904,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/DeleteImage";
 
         let mut request = SignedRequest::new("DELETE", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("DeleteImage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6095,6 +6936,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/DeleteImagePipeline";
 
         let mut request = SignedRequest::new("DELETE", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("DeleteImagePipeline");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6126,6 +6968,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/DeleteImageRecipe";
 
         let mut request = SignedRequest::new("DELETE", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("DeleteImageRecipe");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6160,6 +7003,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/DeleteInfrastructureConfiguration";
 
         let mut request = SignedRequest::new("DELETE", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("DeleteInfrastructureConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6196,6 +7040,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/GetComponent";
 
         let mut request = SignedRequest::new("GET", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("GetComponent");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6230,6 +7075,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/GetComponentPolicy";
 
         let mut request = SignedRequest::new("GET", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("GetComponentPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6262,6 +7108,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/GetDistributionConfiguration";
 
         let mut request = SignedRequest::new("GET", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("GetDistributionConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6296,6 +7143,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/GetImage";
 
         let mut request = SignedRequest::new("GET", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("GetImage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6327,6 +7175,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/GetImagePipeline";
 
         let mut request = SignedRequest::new("GET", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("GetImagePipeline");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6358,6 +7207,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/GetImagePolicy";
 
         let mut request = SignedRequest::new("GET", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("GetImagePolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6389,6 +7239,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/GetImageRecipe";
 
         let mut request = SignedRequest::new("GET", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("GetImageRecipe");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6420,6 +7271,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/GetImageRecipePolicy";
 
         let mut request = SignedRequest::new("GET", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("GetImageRecipePolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6454,6 +7306,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/GetInfrastructureConfiguration";
 
         let mut request = SignedRequest::new("GET", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("GetInfrastructureConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6488,6 +7341,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/ImportComponent";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("ImportComponent");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6519,6 +7373,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/ListComponentBuildVersions";
 
         let mut request = SignedRequest::new("POST", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("ListComponentBuildVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6549,6 +7404,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/ListComponents";
 
         let mut request = SignedRequest::new("POST", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("ListComponents");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6582,6 +7438,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/ListDistributionConfigurations";
 
         let mut request = SignedRequest::new("POST", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("ListDistributionConfigurations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6612,6 +7469,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/ListImageBuildVersions";
 
         let mut request = SignedRequest::new("POST", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("ListImageBuildVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6642,6 +7500,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/ListImagePipelineImages";
 
         let mut request = SignedRequest::new("POST", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("ListImagePipelineImages");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6672,6 +7531,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/ListImagePipelines";
 
         let mut request = SignedRequest::new("POST", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("ListImagePipelines");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6702,6 +7562,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/ListImageRecipes";
 
         let mut request = SignedRequest::new("POST", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("ListImageRecipes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6732,6 +7593,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/ListImages";
 
         let mut request = SignedRequest::new("POST", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("ListImages");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6765,6 +7627,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/ListInfrastructureConfigurations";
 
         let mut request = SignedRequest::new("POST", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("ListInfrastructureConfigurations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6797,6 +7660,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -6824,6 +7688,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/PutComponentPolicy";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("PutComponentPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6854,6 +7719,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/PutImagePolicy";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("PutImagePolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6884,6 +7750,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/PutImageRecipePolicy";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("PutImageRecipePolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6915,6 +7782,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/StartImagePipelineExecution";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("StartImagePipelineExecution");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6945,6 +7813,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6975,6 +7844,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7011,6 +7881,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/UpdateDistributionConfiguration";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("UpdateDistributionConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7043,6 +7914,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/UpdateImagePipeline";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("UpdateImagePipeline");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7076,6 +7948,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/UpdateInfrastructureConfiguration";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("UpdateInfrastructureConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -144,7 +144,7 @@ impl CarrierDeserializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateJobInput {
     pub api_version: Option<String>,
-    pub job_type: String,
+    pub job_type: JobType,
     pub manifest: String,
     pub manifest_addendum: Option<String>,
     pub validate_only: bool,
@@ -177,7 +177,7 @@ impl CreateJobInputSerializer {
 pub struct CreateJobOutput {
     pub artifact_list: Option<Vec<Artifact>>,
     pub job_id: Option<String>,
-    pub job_type: Option<String>,
+    pub job_type: Option<JobType>,
     pub signature: Option<String>,
     pub signature_file_contents: Option<String>,
     pub warning_message: Option<String>,
@@ -228,9 +228,12 @@ impl CreateJobOutputDeserializer {
 struct CreationDateDeserializer;
 impl CreationDateDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<::chrono::DateTime<::chrono::Utc>, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::proto::xml::util::timestamp_characters(stack)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -404,11 +407,11 @@ impl GetStatusInputSerializer {
 pub struct GetStatusOutput {
     pub artifact_list: Option<Vec<Artifact>>,
     pub carrier: Option<String>,
-    pub creation_date: Option<String>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     pub current_manifest: Option<String>,
     pub error_count: Option<i64>,
     pub job_id: Option<String>,
-    pub job_type: Option<String>,
+    pub job_type: Option<JobType>,
     pub location_code: Option<String>,
     pub location_message: Option<String>,
     pub log_bucket: Option<String>,
@@ -536,10 +539,10 @@ impl IsTruncatedDeserializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct Job {
-    pub creation_date: Option<String>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     pub is_canceled: Option<bool>,
     pub job_id: Option<String>,
-    pub job_type: Option<String>,
+    pub job_type: Option<JobType>,
 }
 
 struct JobDeserializer;
@@ -593,12 +596,111 @@ impl JobIdListSerializer {
     }
 }
 
+/// Specifies whether the job to initiate is an import or export job.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum JobType {
+    /// `Import`
+    Import,
+    /// `Export`
+    Export,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl JobType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            JobType::Import => "Import",
+            JobType::Export => "Export",
+            JobType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for JobType {
+    fn default() -> JobType {
+        JobType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for JobType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for JobType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for JobType {
+    fn from(value: &'a str) -> JobType {
+        match value {
+            "Import" => JobType::Import,
+            "Export" => JobType::Export,
+            _ => JobType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for JobType {
+    fn from(value: String) -> JobType {
+        match value.as_str() {
+            "Import" => JobType::Import,
+            "Export" => JobType::Export,
+            _ => JobType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for JobType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<JobType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for JobType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for JobType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for JobType {
+    fn deserialize<D>(deserializer: D) -> Result<JobType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct JobTypeDeserializer;
 impl JobTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<JobType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -810,7 +912,7 @@ impl URLDeserializer {
 pub struct UpdateJobInput {
     pub api_version: Option<String>,
     pub job_id: String,
-    pub job_type: String,
+    pub job_type: JobType,
     pub manifest: String,
     pub validate_only: bool,
 }
@@ -1588,11 +1690,12 @@ pub struct ImportExportClient {
 impl ImportExportClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> ImportExportClient {
         ImportExportClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "ImportExport"),
         }
     }
 
@@ -1629,6 +1732,7 @@ impl ImportExport for ImportExportClient {
             &self.region,
             "/?Operation=CancelJob",
         );
+        request.set_operation("CancelJob");
         let mut params = Params::new();
 
         params.put("Action", "CancelJob");
@@ -1680,6 +1784,7 @@ impl ImportExport for ImportExportClient {
             &self.region,
             "/?Operation=CreateJob",
         );
+        request.set_operation("CreateJob");
         let mut params = Params::new();
 
         params.put("Action", "CreateJob");
@@ -1731,6 +1836,7 @@ impl ImportExport for ImportExportClient {
             &self.region,
             "/?Operation=GetShippingLabel",
         );
+        request.set_operation("GetShippingLabel");
         let mut params = Params::new();
 
         params.put("Action", "GetShippingLabel");
@@ -1785,6 +1891,7 @@ impl ImportExport for ImportExportClient {
             &self.region,
             "/?Operation=GetStatus",
         );
+        request.set_operation("GetStatus");
         let mut params = Params::new();
 
         params.put("Action", "GetStatus");
@@ -1832,6 +1939,7 @@ impl ImportExport for ImportExportClient {
     ) -> Result<ListJobsOutput, RusotoError<ListJobsError>> {
         let mut request =
             SignedRequest::new("POST", "importexport", &self.region, "/?Operation=ListJobs");
+        request.set_operation("ListJobs");
         let mut params = Params::new();
 
         params.put("Action", "ListJobs");
@@ -1883,6 +1991,7 @@ impl ImportExport for ImportExportClient {
             &self.region,
             "/?Operation=UpdateJob",
         );
+        request.set_operation("UpdateJob");
         let mut params = Params::new();
 
         params.put("Action", "UpdateJob");
@@ -1923,6 +2032,47 @@ impl ImportExport for ImportExportClient {
         Ok(result)
     }
 }
+impl ImportExportClient {
+    /// Auto-paginating version of `list_jobs`, streaming every page of results.
+    pub fn list_jobs_pages(
+        &self,
+        input: ListJobsInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListJobsOutput, ListJobsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ImportExportClient, input: ListJobsInput| async move {
+                client.list_jobs(input).await
+            },
+            |input: &ListJobsInput, output: &ListJobsOutput| {
+                if !output.is_truncated.unwrap_or(false) {
+                    return None;
+                }
+                Some(ListJobsInput {
+                    marker: Some(
+                        output
+                            .jobs
+                            .as_ref()
+                            .and_then(|v| v.last())
+                            .and_then(|v| v.job_id.clone())?,
+                    ),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_jobs`, streaming the `Jobs` of every page of results.
+    pub fn list_jobs_items(
+        &self,
+        input: ListJobsInput,
+    ) -> rusoto_core::pagination::RusotoStream<Job, ListJobsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_jobs_pages(input),
+            |output: ListJobsOutput| output.jobs.unwrap_or_default(),
+        )
+    }
+}
 
 #[cfg(test)]
 mod protocol_tests {
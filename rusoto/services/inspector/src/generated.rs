@@ -24,6 +24,141 @@ use rusoto_core::signature::SignedRequest;
 #[allow(unused_imports)]
 use serde::{Deserialize, Serialize};
 use serde_json;
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AccessDeniedErrorCode {
+    /// `ACCESS_DENIED_TO_ASSESSMENT_TARGET`
+    AccessDeniedToAssessmentTarget,
+    /// `ACCESS_DENIED_TO_ASSESSMENT_TEMPLATE`
+    AccessDeniedToAssessmentTemplate,
+    /// `ACCESS_DENIED_TO_ASSESSMENT_RUN`
+    AccessDeniedToAssessmentRun,
+    /// `ACCESS_DENIED_TO_FINDING`
+    AccessDeniedToFinding,
+    /// `ACCESS_DENIED_TO_RESOURCE_GROUP`
+    AccessDeniedToResourceGroup,
+    /// `ACCESS_DENIED_TO_RULES_PACKAGE`
+    AccessDeniedToRulesPackage,
+    /// `ACCESS_DENIED_TO_SNS_TOPIC`
+    AccessDeniedToSnsTopic,
+    /// `ACCESS_DENIED_TO_IAM_ROLE`
+    AccessDeniedToIamRole,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AccessDeniedErrorCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AccessDeniedErrorCode::AccessDeniedToAssessmentTarget => {
+                "ACCESS_DENIED_TO_ASSESSMENT_TARGET"
+            }
+            AccessDeniedErrorCode::AccessDeniedToAssessmentTemplate => {
+                "ACCESS_DENIED_TO_ASSESSMENT_TEMPLATE"
+            }
+            AccessDeniedErrorCode::AccessDeniedToAssessmentRun => "ACCESS_DENIED_TO_ASSESSMENT_RUN",
+            AccessDeniedErrorCode::AccessDeniedToFinding => "ACCESS_DENIED_TO_FINDING",
+            AccessDeniedErrorCode::AccessDeniedToResourceGroup => "ACCESS_DENIED_TO_RESOURCE_GROUP",
+            AccessDeniedErrorCode::AccessDeniedToRulesPackage => "ACCESS_DENIED_TO_RULES_PACKAGE",
+            AccessDeniedErrorCode::AccessDeniedToSnsTopic => "ACCESS_DENIED_TO_SNS_TOPIC",
+            AccessDeniedErrorCode::AccessDeniedToIamRole => "ACCESS_DENIED_TO_IAM_ROLE",
+            AccessDeniedErrorCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AccessDeniedErrorCode {
+    fn default() -> AccessDeniedErrorCode {
+        AccessDeniedErrorCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AccessDeniedErrorCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AccessDeniedErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AccessDeniedErrorCode {
+    fn from(value: &'a str) -> AccessDeniedErrorCode {
+        match value {
+            "ACCESS_DENIED_TO_ASSESSMENT_TARGET" => {
+                AccessDeniedErrorCode::AccessDeniedToAssessmentTarget
+            }
+            "ACCESS_DENIED_TO_ASSESSMENT_TEMPLATE" => {
+                AccessDeniedErrorCode::AccessDeniedToAssessmentTemplate
+            }
+            "ACCESS_DENIED_TO_ASSESSMENT_RUN" => AccessDeniedErrorCode::AccessDeniedToAssessmentRun,
+            "ACCESS_DENIED_TO_FINDING" => AccessDeniedErrorCode::AccessDeniedToFinding,
+            "ACCESS_DENIED_TO_RESOURCE_GROUP" => AccessDeniedErrorCode::AccessDeniedToResourceGroup,
+            "ACCESS_DENIED_TO_RULES_PACKAGE" => AccessDeniedErrorCode::AccessDeniedToRulesPackage,
+            "ACCESS_DENIED_TO_SNS_TOPIC" => AccessDeniedErrorCode::AccessDeniedToSnsTopic,
+            "ACCESS_DENIED_TO_IAM_ROLE" => AccessDeniedErrorCode::AccessDeniedToIamRole,
+            _ => AccessDeniedErrorCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AccessDeniedErrorCode {
+    fn from(value: String) -> AccessDeniedErrorCode {
+        match value.as_str() {
+            "ACCESS_DENIED_TO_ASSESSMENT_TARGET" => {
+                AccessDeniedErrorCode::AccessDeniedToAssessmentTarget
+            }
+            "ACCESS_DENIED_TO_ASSESSMENT_TEMPLATE" => {
+                AccessDeniedErrorCode::AccessDeniedToAssessmentTemplate
+            }
+            "ACCESS_DENIED_TO_ASSESSMENT_RUN" => AccessDeniedErrorCode::AccessDeniedToAssessmentRun,
+            "ACCESS_DENIED_TO_FINDING" => AccessDeniedErrorCode::AccessDeniedToFinding,
+            "ACCESS_DENIED_TO_RESOURCE_GROUP" => AccessDeniedErrorCode::AccessDeniedToResourceGroup,
+            "ACCESS_DENIED_TO_RULES_PACKAGE" => AccessDeniedErrorCode::AccessDeniedToRulesPackage,
+            "ACCESS_DENIED_TO_SNS_TOPIC" => AccessDeniedErrorCode::AccessDeniedToSnsTopic,
+            "ACCESS_DENIED_TO_IAM_ROLE" => AccessDeniedErrorCode::AccessDeniedToIamRole,
+            _ => AccessDeniedErrorCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AccessDeniedErrorCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AccessDeniedErrorCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AccessDeniedErrorCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AccessDeniedErrorCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AccessDeniedErrorCode {
+    fn deserialize<D>(deserializer: D) -> Result<AccessDeniedErrorCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct AddAttributesToFindingsRequest {
@@ -58,12 +193,223 @@ pub struct AgentAlreadyRunningAssessment {
 pub struct AgentFilter {
     /// <p>The detailed health state of the agent. Values can be set to <b>IDLE</b>, <b>RUNNING</b>, <b>SHUTDOWN</b>, <b>UNHEALTHY</b>, <b>THROTTLED</b>, and <b>UNKNOWN</b>. </p>
     #[serde(rename = "agentHealthCodes")]
-    pub agent_health_codes: Vec<String>,
+    pub agent_health_codes: Vec<AgentHealthCode>,
     /// <p>The current health state of the agent. Values can be set to <b>HEALTHY</b> or <b>UNHEALTHY</b>.</p>
     #[serde(rename = "agentHealths")]
-    pub agent_healths: Vec<String>,
+    pub agent_healths: Vec<AgentHealth>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AgentHealth {
+    /// `HEALTHY`
+    Healthy,
+    /// `UNHEALTHY`
+    Unhealthy,
+    /// `UNKNOWN`
+    UnknownValue,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AgentHealth {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AgentHealth::Healthy => "HEALTHY",
+            AgentHealth::Unhealthy => "UNHEALTHY",
+            AgentHealth::UnknownValue => "UNKNOWN",
+            AgentHealth::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AgentHealth {
+    fn default() -> AgentHealth {
+        AgentHealth::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AgentHealth {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AgentHealth {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AgentHealth {
+    fn from(value: &'a str) -> AgentHealth {
+        match value {
+            "HEALTHY" => AgentHealth::Healthy,
+            "UNHEALTHY" => AgentHealth::Unhealthy,
+            "UNKNOWN" => AgentHealth::UnknownValue,
+            _ => AgentHealth::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AgentHealth {
+    fn from(value: String) -> AgentHealth {
+        match value.as_str() {
+            "HEALTHY" => AgentHealth::Healthy,
+            "UNHEALTHY" => AgentHealth::Unhealthy,
+            "UNKNOWN" => AgentHealth::UnknownValue,
+            _ => AgentHealth::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AgentHealth {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AgentHealth, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AgentHealth {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AgentHealth {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AgentHealth {
+    fn deserialize<D>(deserializer: D) -> Result<AgentHealth, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AgentHealthCode {
+    /// `IDLE`
+    Idle,
+    /// `RUNNING`
+    Running,
+    /// `SHUTDOWN`
+    Shutdown,
+    /// `UNHEALTHY`
+    Unhealthy,
+    /// `THROTTLED`
+    Throttled,
+    /// `UNKNOWN`
+    UnknownValue,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AgentHealthCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AgentHealthCode::Idle => "IDLE",
+            AgentHealthCode::Running => "RUNNING",
+            AgentHealthCode::Shutdown => "SHUTDOWN",
+            AgentHealthCode::Unhealthy => "UNHEALTHY",
+            AgentHealthCode::Throttled => "THROTTLED",
+            AgentHealthCode::UnknownValue => "UNKNOWN",
+            AgentHealthCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AgentHealthCode {
+    fn default() -> AgentHealthCode {
+        AgentHealthCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AgentHealthCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AgentHealthCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AgentHealthCode {
+    fn from(value: &'a str) -> AgentHealthCode {
+        match value {
+            "IDLE" => AgentHealthCode::Idle,
+            "RUNNING" => AgentHealthCode::Running,
+            "SHUTDOWN" => AgentHealthCode::Shutdown,
+            "UNHEALTHY" => AgentHealthCode::Unhealthy,
+            "THROTTLED" => AgentHealthCode::Throttled,
+            "UNKNOWN" => AgentHealthCode::UnknownValue,
+            _ => AgentHealthCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AgentHealthCode {
+    fn from(value: String) -> AgentHealthCode {
+        match value.as_str() {
+            "IDLE" => AgentHealthCode::Idle,
+            "RUNNING" => AgentHealthCode::Running,
+            "SHUTDOWN" => AgentHealthCode::Shutdown,
+            "UNHEALTHY" => AgentHealthCode::Unhealthy,
+            "THROTTLED" => AgentHealthCode::Throttled,
+            "UNKNOWN" => AgentHealthCode::UnknownValue,
+            _ => AgentHealthCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AgentHealthCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AgentHealthCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AgentHealthCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AgentHealthCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for AgentHealthCode {
+    fn deserialize<D>(deserializer: D) -> Result<AgentHealthCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Used as a response element in the <a>PreviewAgents</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -71,7 +417,7 @@ pub struct AgentPreview {
     /// <p>The health status of the Amazon Inspector Agent.</p>
     #[serde(rename = "agentHealth")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub agent_health: Option<String>,
+    pub agent_health: Option<AgentHealth>,
     /// <p>The ID of the EC2 instance where the agent is installed.</p>
     #[serde(rename = "agentId")]
     pub agent_id: String,
@@ -113,11 +459,21 @@ pub struct AssessmentRun {
     pub assessment_template_arn: String,
     /// <p>The assessment run completion time that corresponds to the rules packages evaluation completion time or failure.</p>
     #[serde(rename = "completedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub completed_at: Option<f64>,
+    pub completed_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The time when <a>StartAssessmentRun</a> was called.</p>
     #[serde(rename = "createdAt")]
-    pub created_at: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub created_at: ::chrono::DateTime<::chrono::Utc>,
     /// <p>A Boolean value (true or false) that specifies whether the process of collecting data from the agents is completed.</p>
     #[serde(rename = "dataCollected")]
     pub data_collected: bool,
@@ -126,7 +482,7 @@ pub struct AssessmentRun {
     pub duration_in_seconds: i64,
     /// <p>Provides a total count of generated findings per severity.</p>
     #[serde(rename = "findingCounts")]
-    pub finding_counts: ::std::collections::HashMap<String, i64>,
+    pub finding_counts: ::std::collections::HashMap<Severity, i64>,
     /// <p>The auto-generated name for the assessment run.</p>
     #[serde(rename = "name")]
     pub name: String,
@@ -138,14 +494,24 @@ pub struct AssessmentRun {
     pub rules_package_arns: Vec<String>,
     /// <p>The time when <a>StartAssessmentRun</a> was called.</p>
     #[serde(rename = "startedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub started_at: Option<f64>,
+    pub started_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The state of the assessment run.</p>
     #[serde(rename = "state")]
-    pub state: String,
+    pub state: AssessmentRunState,
     /// <p>The last time when the assessment run's state changed.</p>
     #[serde(rename = "stateChangedAt")]
-    pub state_changed_at: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub state_changed_at: ::chrono::DateTime<::chrono::Utc>,
     /// <p>A list of the assessment run state changes.</p>
     #[serde(rename = "stateChanges")]
     pub state_changes: Vec<AssessmentRunStateChange>,
@@ -160,10 +526,10 @@ pub struct AssessmentRun {
 pub struct AssessmentRunAgent {
     /// <p>The current health state of the agent.</p>
     #[serde(rename = "agentHealth")]
-    pub agent_health: String,
+    pub agent_health: AgentHealth,
     /// <p>The detailed health state of the agent.</p>
     #[serde(rename = "agentHealthCode")]
-    pub agent_health_code: String,
+    pub agent_health_code: AgentHealthCode,
     /// <p>The description for the agent health code.</p>
     #[serde(rename = "agentHealthDetails")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -214,7 +580,7 @@ pub struct AssessmentRunFilter {
     /// <p>For a record to match a filter, one of the values specified for this data type property must be the exact match of the value of the <b>assessmentRunState</b> property of the <a>AssessmentRun</a> data type.</p>
     #[serde(rename = "states")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub states: Option<Vec<String>>,
+    pub states: Option<Vec<AssessmentRunState>>,
 }
 
 /// <p>Used as one of the elements of the <a>AssessmentRun</a> data type.</p>
@@ -223,13 +589,18 @@ pub struct AssessmentRunFilter {
 pub struct AssessmentRunNotification {
     /// <p>The date of the notification.</p>
     #[serde(rename = "date")]
-    pub date: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub date: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The Boolean value that specifies whether the notification represents an error.</p>
     #[serde(rename = "error")]
     pub error: bool,
     /// <p>The event for which a notification is sent.</p>
     #[serde(rename = "event")]
-    pub event: String,
+    pub event: InspectorEvent,
     /// <p>The message included in the notification.</p>
     #[serde(rename = "message")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -237,23 +608,285 @@ pub struct AssessmentRunNotification {
     /// <p>The status code of the SNS notification.</p>
     #[serde(rename = "snsPublishStatusCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sns_publish_status_code: Option<String>,
+    pub sns_publish_status_code: Option<AssessmentRunNotificationSnsStatusCode>,
     /// <p>The SNS topic to which the SNS notification is sent.</p>
     #[serde(rename = "snsTopicArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sns_topic_arn: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AssessmentRunNotificationSnsStatusCode {
+    /// `SUCCESS`
+    Success,
+    /// `TOPIC_DOES_NOT_EXIST`
+    TopicDoesNotExist,
+    /// `ACCESS_DENIED`
+    AccessDenied,
+    /// `INTERNAL_ERROR`
+    InternalError,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AssessmentRunNotificationSnsStatusCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AssessmentRunNotificationSnsStatusCode::Success => "SUCCESS",
+            AssessmentRunNotificationSnsStatusCode::TopicDoesNotExist => "TOPIC_DOES_NOT_EXIST",
+            AssessmentRunNotificationSnsStatusCode::AccessDenied => "ACCESS_DENIED",
+            AssessmentRunNotificationSnsStatusCode::InternalError => "INTERNAL_ERROR",
+            AssessmentRunNotificationSnsStatusCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AssessmentRunNotificationSnsStatusCode {
+    fn default() -> AssessmentRunNotificationSnsStatusCode {
+        AssessmentRunNotificationSnsStatusCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AssessmentRunNotificationSnsStatusCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AssessmentRunNotificationSnsStatusCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AssessmentRunNotificationSnsStatusCode {
+    fn from(value: &'a str) -> AssessmentRunNotificationSnsStatusCode {
+        match value {
+            "SUCCESS" => AssessmentRunNotificationSnsStatusCode::Success,
+            "TOPIC_DOES_NOT_EXIST" => AssessmentRunNotificationSnsStatusCode::TopicDoesNotExist,
+            "ACCESS_DENIED" => AssessmentRunNotificationSnsStatusCode::AccessDenied,
+            "INTERNAL_ERROR" => AssessmentRunNotificationSnsStatusCode::InternalError,
+            _ => AssessmentRunNotificationSnsStatusCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AssessmentRunNotificationSnsStatusCode {
+    fn from(value: String) -> AssessmentRunNotificationSnsStatusCode {
+        match value.as_str() {
+            "SUCCESS" => AssessmentRunNotificationSnsStatusCode::Success,
+            "TOPIC_DOES_NOT_EXIST" => AssessmentRunNotificationSnsStatusCode::TopicDoesNotExist,
+            "ACCESS_DENIED" => AssessmentRunNotificationSnsStatusCode::AccessDenied,
+            "INTERNAL_ERROR" => AssessmentRunNotificationSnsStatusCode::InternalError,
+            _ => AssessmentRunNotificationSnsStatusCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AssessmentRunNotificationSnsStatusCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AssessmentRunNotificationSnsStatusCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AssessmentRunNotificationSnsStatusCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AssessmentRunNotificationSnsStatusCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AssessmentRunNotificationSnsStatusCode {
+    fn deserialize<D>(deserializer: D) -> Result<AssessmentRunNotificationSnsStatusCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AssessmentRunState {
+    /// `CREATED`
+    Created,
+    /// `START_DATA_COLLECTION_PENDING`
+    StartDataCollectionPending,
+    /// `START_DATA_COLLECTION_IN_PROGRESS`
+    StartDataCollectionInProgress,
+    /// `COLLECTING_DATA`
+    CollectingData,
+    /// `STOP_DATA_COLLECTION_PENDING`
+    StopDataCollectionPending,
+    /// `DATA_COLLECTED`
+    DataCollected,
+    /// `START_EVALUATING_RULES_PENDING`
+    StartEvaluatingRulesPending,
+    /// `EVALUATING_RULES`
+    EvaluatingRules,
+    /// `FAILED`
+    Failed,
+    /// `ERROR`
+    Error,
+    /// `COMPLETED`
+    Completed,
+    /// `COMPLETED_WITH_ERRORS`
+    CompletedWithErrors,
+    /// `CANCELED`
+    Canceled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AssessmentRunState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AssessmentRunState::Created => "CREATED",
+            AssessmentRunState::StartDataCollectionPending => "START_DATA_COLLECTION_PENDING",
+            AssessmentRunState::StartDataCollectionInProgress => {
+                "START_DATA_COLLECTION_IN_PROGRESS"
+            }
+            AssessmentRunState::CollectingData => "COLLECTING_DATA",
+            AssessmentRunState::StopDataCollectionPending => "STOP_DATA_COLLECTION_PENDING",
+            AssessmentRunState::DataCollected => "DATA_COLLECTED",
+            AssessmentRunState::StartEvaluatingRulesPending => "START_EVALUATING_RULES_PENDING",
+            AssessmentRunState::EvaluatingRules => "EVALUATING_RULES",
+            AssessmentRunState::Failed => "FAILED",
+            AssessmentRunState::Error => "ERROR",
+            AssessmentRunState::Completed => "COMPLETED",
+            AssessmentRunState::CompletedWithErrors => "COMPLETED_WITH_ERRORS",
+            AssessmentRunState::Canceled => "CANCELED",
+            AssessmentRunState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AssessmentRunState {
+    fn default() -> AssessmentRunState {
+        AssessmentRunState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AssessmentRunState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AssessmentRunState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AssessmentRunState {
+    fn from(value: &'a str) -> AssessmentRunState {
+        match value {
+            "CREATED" => AssessmentRunState::Created,
+            "START_DATA_COLLECTION_PENDING" => AssessmentRunState::StartDataCollectionPending,
+            "START_DATA_COLLECTION_IN_PROGRESS" => {
+                AssessmentRunState::StartDataCollectionInProgress
+            }
+            "COLLECTING_DATA" => AssessmentRunState::CollectingData,
+            "STOP_DATA_COLLECTION_PENDING" => AssessmentRunState::StopDataCollectionPending,
+            "DATA_COLLECTED" => AssessmentRunState::DataCollected,
+            "START_EVALUATING_RULES_PENDING" => AssessmentRunState::StartEvaluatingRulesPending,
+            "EVALUATING_RULES" => AssessmentRunState::EvaluatingRules,
+            "FAILED" => AssessmentRunState::Failed,
+            "ERROR" => AssessmentRunState::Error,
+            "COMPLETED" => AssessmentRunState::Completed,
+            "COMPLETED_WITH_ERRORS" => AssessmentRunState::CompletedWithErrors,
+            "CANCELED" => AssessmentRunState::Canceled,
+            _ => AssessmentRunState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AssessmentRunState {
+    fn from(value: String) -> AssessmentRunState {
+        match value.as_str() {
+            "CREATED" => AssessmentRunState::Created,
+            "START_DATA_COLLECTION_PENDING" => AssessmentRunState::StartDataCollectionPending,
+            "START_DATA_COLLECTION_IN_PROGRESS" => {
+                AssessmentRunState::StartDataCollectionInProgress
+            }
+            "COLLECTING_DATA" => AssessmentRunState::CollectingData,
+            "STOP_DATA_COLLECTION_PENDING" => AssessmentRunState::StopDataCollectionPending,
+            "DATA_COLLECTED" => AssessmentRunState::DataCollected,
+            "START_EVALUATING_RULES_PENDING" => AssessmentRunState::StartEvaluatingRulesPending,
+            "EVALUATING_RULES" => AssessmentRunState::EvaluatingRules,
+            "FAILED" => AssessmentRunState::Failed,
+            "ERROR" => AssessmentRunState::Error,
+            "COMPLETED" => AssessmentRunState::Completed,
+            "COMPLETED_WITH_ERRORS" => AssessmentRunState::CompletedWithErrors,
+            "CANCELED" => AssessmentRunState::Canceled,
+            _ => AssessmentRunState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AssessmentRunState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AssessmentRunState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AssessmentRunState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AssessmentRunState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AssessmentRunState {
+    fn deserialize<D>(deserializer: D) -> Result<AssessmentRunState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Used as one of the elements of the <a>AssessmentRun</a> data type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AssessmentRunStateChange {
     /// <p>The assessment run state.</p>
     #[serde(rename = "state")]
-    pub state: String,
+    pub state: AssessmentRunState,
     /// <p>The last time the assessment run state changed.</p>
     #[serde(rename = "stateChangedAt")]
-    pub state_changed_at: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub state_changed_at: ::chrono::DateTime<::chrono::Utc>,
 }
 
 /// <p>Contains information about an Amazon Inspector application. This data type is used as the response element in the <a>DescribeAssessmentTargets</a> action.</p>
@@ -265,7 +898,12 @@ pub struct AssessmentTarget {
     pub arn: String,
     /// <p>The time at which the assessment target is created.</p>
     #[serde(rename = "createdAt")]
-    pub created_at: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub created_at: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The name of the Amazon Inspector assessment target.</p>
     #[serde(rename = "name")]
     pub name: String,
@@ -275,7 +913,12 @@ pub struct AssessmentTarget {
     pub resource_group_arn: Option<String>,
     /// <p>The time at which <a>UpdateAssessmentTarget</a> is called.</p>
     #[serde(rename = "updatedAt")]
-    pub updated_at: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub updated_at: ::chrono::DateTime<::chrono::Utc>,
 }
 
 /// <p>Used as the request parameter in the <a>ListAssessmentTargets</a> action.</p>
@@ -303,7 +946,12 @@ pub struct AssessmentTemplate {
     pub assessment_target_arn: String,
     /// <p>The time at which the assessment template is created.</p>
     #[serde(rename = "createdAt")]
-    pub created_at: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub created_at: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The duration in seconds specified for this assessment template. The default value is 3600 seconds (one hour). The maximum value is 86400 seconds (one day).</p>
     #[serde(rename = "durationInSeconds")]
     pub duration_in_seconds: i64,
@@ -377,6 +1025,94 @@ pub struct AssetAttributes {
     pub tags: Option<Vec<Tag>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AssetType {
+    /// `ec2-instance`
+    Ec2Instance,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AssetType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AssetType::Ec2Instance => "ec2-instance",
+            AssetType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AssetType {
+    fn default() -> AssetType {
+        AssetType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AssetType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AssetType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AssetType {
+    fn from(value: &'a str) -> AssetType {
+        match value {
+            "ec2-instance" => AssetType::Ec2Instance,
+            _ => AssetType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AssetType {
+    fn from(value: String) -> AssetType {
+        match value.as_str() {
+            "ec2-instance" => AssetType::Ec2Instance,
+            _ => AssetType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AssetType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AssetType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AssetType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AssetType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AssetType {
+    fn deserialize<D>(deserializer: D) -> Result<AssetType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>This data type is used as a request parameter in the <a>AddAttributesToFindings</a> and <a>CreateAssessmentTemplate</a> actions.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Attribute {
@@ -555,7 +1291,12 @@ pub struct DescribeAssessmentTemplatesResponse {
 pub struct DescribeCrossAccountAccessRoleResponse {
     /// <p>The date when the cross-account access role was registered.</p>
     #[serde(rename = "registeredAt")]
-    pub registered_at: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub registered_at: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The ARN that specifies the IAM role that Amazon Inspector uses to access your AWS account.</p>
     #[serde(rename = "roleArn")]
     pub role_arn: String,
@@ -573,7 +1314,7 @@ pub struct DescribeExclusionsRequest {
     /// <p>The locale into which you want to translate the exclusion's title, description, and recommendation.</p>
     #[serde(rename = "locale")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub locale: Option<String>,
+    pub locale: Option<Locale>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -596,7 +1337,7 @@ pub struct DescribeFindingsRequest {
     /// <p>The locale into which you want to translate a finding description, recommendation, and the short description that identifies the finding.</p>
     #[serde(rename = "locale")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub locale: Option<String>,
+    pub locale: Option<Locale>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -635,7 +1376,7 @@ pub struct DescribeRulesPackagesRequest {
     /// <p>The locale that you want to translate a rules package description into.</p>
     #[serde(rename = "locale")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub locale: Option<String>,
+    pub locale: Option<Locale>,
     /// <p>The ARN that specifies the rules package that you want to describe.</p>
     #[serde(rename = "rulesPackageArns")]
     pub rules_package_arns: Vec<String>,
@@ -672,10 +1413,15 @@ pub struct DurationRange {
 pub struct EventSubscription {
     /// <p>The event for which Amazon Simple Notification Service (SNS) notifications are sent.</p>
     #[serde(rename = "event")]
-    pub event: String,
+    pub event: InspectorEvent,
     /// <p>The time at which <a>SubscribeToEvent</a> is called.</p>
     #[serde(rename = "subscribedAt")]
-    pub subscribed_at: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub subscribed_at: ::chrono::DateTime<::chrono::Utc>,
 }
 
 /// <p>Contains information about what was excluded from an assessment run.</p>
@@ -731,27 +1477,140 @@ pub struct ExclusionPreview {
 pub struct FailedItemDetails {
     /// <p>The status code of a failed item.</p>
     #[serde(rename = "failureCode")]
-    pub failure_code: String,
+    pub failure_code: FailedItemErrorCode,
     /// <p>Indicates whether you can immediately retry a request for this item for a specified resource.</p>
     #[serde(rename = "retryable")]
     pub retryable: bool,
 }
 
-/// <p>Contains information about an Amazon Inspector finding. This data type is used as the response element in the <a>DescribeFindings</a> action.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct Finding {
-    /// <p>The ARN that specifies the finding.</p>
-    #[serde(rename = "arn")]
-    pub arn: String,
-    /// <p>A collection of attributes of the host from which the finding is generated.</p>
-    #[serde(rename = "assetAttributes")]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FailedItemErrorCode {
+    /// `INVALID_ARN`
+    InvalidArn,
+    /// `DUPLICATE_ARN`
+    DuplicateArn,
+    /// `ITEM_DOES_NOT_EXIST`
+    ItemDoesNotExist,
+    /// `ACCESS_DENIED`
+    AccessDenied,
+    /// `LIMIT_EXCEEDED`
+    LimitExceeded,
+    /// `INTERNAL_ERROR`
+    InternalError,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl FailedItemErrorCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FailedItemErrorCode::InvalidArn => "INVALID_ARN",
+            FailedItemErrorCode::DuplicateArn => "DUPLICATE_ARN",
+            FailedItemErrorCode::ItemDoesNotExist => "ITEM_DOES_NOT_EXIST",
+            FailedItemErrorCode::AccessDenied => "ACCESS_DENIED",
+            FailedItemErrorCode::LimitExceeded => "LIMIT_EXCEEDED",
+            FailedItemErrorCode::InternalError => "INTERNAL_ERROR",
+            FailedItemErrorCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for FailedItemErrorCode {
+    fn default() -> FailedItemErrorCode {
+        FailedItemErrorCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for FailedItemErrorCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FailedItemErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FailedItemErrorCode {
+    fn from(value: &'a str) -> FailedItemErrorCode {
+        match value {
+            "INVALID_ARN" => FailedItemErrorCode::InvalidArn,
+            "DUPLICATE_ARN" => FailedItemErrorCode::DuplicateArn,
+            "ITEM_DOES_NOT_EXIST" => FailedItemErrorCode::ItemDoesNotExist,
+            "ACCESS_DENIED" => FailedItemErrorCode::AccessDenied,
+            "LIMIT_EXCEEDED" => FailedItemErrorCode::LimitExceeded,
+            "INTERNAL_ERROR" => FailedItemErrorCode::InternalError,
+            _ => FailedItemErrorCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FailedItemErrorCode {
+    fn from(value: String) -> FailedItemErrorCode {
+        match value.as_str() {
+            "INVALID_ARN" => FailedItemErrorCode::InvalidArn,
+            "DUPLICATE_ARN" => FailedItemErrorCode::DuplicateArn,
+            "ITEM_DOES_NOT_EXIST" => FailedItemErrorCode::ItemDoesNotExist,
+            "ACCESS_DENIED" => FailedItemErrorCode::AccessDenied,
+            "LIMIT_EXCEEDED" => FailedItemErrorCode::LimitExceeded,
+            "INTERNAL_ERROR" => FailedItemErrorCode::InternalError,
+            _ => FailedItemErrorCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for FailedItemErrorCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<FailedItemErrorCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FailedItemErrorCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FailedItemErrorCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FailedItemErrorCode {
+    fn deserialize<D>(deserializer: D) -> Result<FailedItemErrorCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Contains information about an Amazon Inspector finding. This data type is used as the response element in the <a>DescribeFindings</a> action.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct Finding {
+    /// <p>The ARN that specifies the finding.</p>
+    #[serde(rename = "arn")]
+    pub arn: String,
+    /// <p>A collection of attributes of the host from which the finding is generated.</p>
+    #[serde(rename = "assetAttributes")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub asset_attributes: Option<AssetAttributes>,
     /// <p>The type of the host from which the finding is generated.</p>
     #[serde(rename = "assetType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub asset_type: Option<String>,
+    pub asset_type: Option<AssetType>,
     /// <p>The system-defined attributes for the finding.</p>
     #[serde(rename = "attributes")]
     pub attributes: Vec<Attribute>,
@@ -761,7 +1620,12 @@ pub struct Finding {
     pub confidence: Option<i64>,
     /// <p>The time when the finding was generated.</p>
     #[serde(rename = "createdAt")]
-    pub created_at: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub created_at: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The description of the finding.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -797,14 +1661,19 @@ pub struct Finding {
     /// <p>The finding severity. Values can be set to High, Medium, Low, and Informational.</p>
     #[serde(rename = "severity")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub severity: Option<String>,
+    pub severity: Option<Severity>,
     /// <p>The name of the finding.</p>
     #[serde(rename = "title")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
     /// <p>The time when <a>AddAttributesToFindings</a> is called.</p>
     #[serde(rename = "updatedAt")]
-    pub updated_at: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub updated_at: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The user-defined attributes that are assigned to the finding.</p>
     #[serde(rename = "userAttributes")]
     pub user_attributes: Vec<Attribute>,
@@ -841,7 +1710,7 @@ pub struct FindingFilter {
     /// <p>For a record to match a filter, one of the values that is specified for this data type property must be the exact match of the value of the <b>severity</b> property of the <a>Finding</a> data type.</p>
     #[serde(rename = "severities")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub severities: Option<Vec<String>>,
+    pub severities: Option<Vec<Severity>>,
     /// <p>For a record to match a filter, the value that is specified for this data type property must be contained in the list of values of the <b>userAttributes</b> property of the <a>Finding</a> data type.</p>
     #[serde(rename = "userAttributes")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -856,10 +1725,10 @@ pub struct GetAssessmentReportRequest {
     pub assessment_run_arn: String,
     /// <p>Specifies the file format (html or pdf) of the assessment report that you want to generate.</p>
     #[serde(rename = "reportFileFormat")]
-    pub report_file_format: String,
+    pub report_file_format: ReportFileFormat,
     /// <p>Specifies the type of the assessment report that you want to generate. There are two types of assessment reports: a finding report and a full report. For more information, see <a href="https://docs.aws.amazon.com/inspector/latest/userguide/inspector_reports.html">Assessment Reports</a>. </p>
     #[serde(rename = "reportType")]
-    pub report_type: String,
+    pub report_type: ReportType,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -867,7 +1736,7 @@ pub struct GetAssessmentReportRequest {
 pub struct GetAssessmentReportResponse {
     /// <p>Specifies the status of the request to generate an assessment report. </p>
     #[serde(rename = "status")]
-    pub status: String,
+    pub status: ReportStatus,
     /// <p>Specifies the URL where you can find the generated assessment report. This parameter is only returned if the report is successfully generated.</p>
     #[serde(rename = "url")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -883,7 +1752,7 @@ pub struct GetExclusionsPreviewRequest {
     /// <p>The locale into which you want to translate the exclusion's title, description, and recommendation.</p>
     #[serde(rename = "locale")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub locale: Option<String>,
+    pub locale: Option<Locale>,
     /// <p>You can use this parameter to indicate the maximum number of items you want in the response. The default value is 100. The maximum value is 500.</p>
     #[serde(rename = "maxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -910,7 +1779,7 @@ pub struct GetExclusionsPreviewResponse {
     pub next_token: Option<String>,
     /// <p>Specifies the status of the request to generate an exclusions preview.</p>
     #[serde(rename = "previewStatus")]
-    pub preview_status: String,
+    pub preview_status: PreviewStatus,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -929,6 +1798,114 @@ pub struct GetTelemetryMetadataResponse {
     pub telemetry_metadata: Vec<TelemetryMetadata>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum InspectorEvent {
+    /// `ASSESSMENT_RUN_STARTED`
+    AssessmentRunStarted,
+    /// `ASSESSMENT_RUN_COMPLETED`
+    AssessmentRunCompleted,
+    /// `ASSESSMENT_RUN_STATE_CHANGED`
+    AssessmentRunStateChanged,
+    /// `FINDING_REPORTED`
+    FindingReported,
+    /// `OTHER`
+    Other,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl InspectorEvent {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InspectorEvent::AssessmentRunStarted => "ASSESSMENT_RUN_STARTED",
+            InspectorEvent::AssessmentRunCompleted => "ASSESSMENT_RUN_COMPLETED",
+            InspectorEvent::AssessmentRunStateChanged => "ASSESSMENT_RUN_STATE_CHANGED",
+            InspectorEvent::FindingReported => "FINDING_REPORTED",
+            InspectorEvent::Other => "OTHER",
+            InspectorEvent::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for InspectorEvent {
+    fn default() -> InspectorEvent {
+        InspectorEvent::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for InspectorEvent {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for InspectorEvent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for InspectorEvent {
+    fn from(value: &'a str) -> InspectorEvent {
+        match value {
+            "ASSESSMENT_RUN_STARTED" => InspectorEvent::AssessmentRunStarted,
+            "ASSESSMENT_RUN_COMPLETED" => InspectorEvent::AssessmentRunCompleted,
+            "ASSESSMENT_RUN_STATE_CHANGED" => InspectorEvent::AssessmentRunStateChanged,
+            "FINDING_REPORTED" => InspectorEvent::FindingReported,
+            "OTHER" => InspectorEvent::Other,
+            _ => InspectorEvent::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InspectorEvent {
+    fn from(value: String) -> InspectorEvent {
+        match value.as_str() {
+            "ASSESSMENT_RUN_STARTED" => InspectorEvent::AssessmentRunStarted,
+            "ASSESSMENT_RUN_COMPLETED" => InspectorEvent::AssessmentRunCompleted,
+            "ASSESSMENT_RUN_STATE_CHANGED" => InspectorEvent::AssessmentRunStateChanged,
+            "FINDING_REPORTED" => InspectorEvent::FindingReported,
+            "OTHER" => InspectorEvent::Other,
+            _ => InspectorEvent::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for InspectorEvent {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<InspectorEvent, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for InspectorEvent {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for InspectorEvent {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InspectorEvent {
+    fn deserialize<D>(deserializer: D) -> Result<InspectorEvent, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>This data type is used in the <a>Finding</a> data type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -946,172 +1923,888 @@ pub struct InspectorServiceAttributes {
     pub schema_version: i64,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct ListAssessmentRunAgentsRequest {
-    /// <p>The ARN that specifies the assessment run whose agents you want to list.</p>
-    #[serde(rename = "assessmentRunArn")]
-    pub assessment_run_arn: String,
-    /// <p>You can use this parameter to specify a subset of data to be included in the action's response.</p> <p>For a record to match a filter, all specified filter attributes must match. When multiple values are specified for a filter attribute, any of the values can match.</p>
-    #[serde(rename = "filter")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub filter: Option<AgentFilter>,
-    /// <p>You can use this parameter to indicate the maximum number of items that you want in the response. The default value is 10. The maximum value is 500.</p>
-    #[serde(rename = "maxResults")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub max_results: Option<i64>,
-    /// <p>You can use this parameter when paginating results. Set the value of this parameter to null on your first call to the <b>ListAssessmentRunAgents</b> action. Subsequent calls to the action fill <b>nextToken</b> in the request with the value of <b>NextToken</b> from the previous response to continue listing data.</p>
-    #[serde(rename = "nextToken")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_token: Option<String>,
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum InvalidCrossAccountRoleErrorCode {
+    /// `ROLE_DOES_NOT_EXIST_OR_INVALID_TRUST_RELATIONSHIP`
+    RoleDoesNotExistOrInvalidTrustRelationship,
+    /// `ROLE_DOES_NOT_HAVE_CORRECT_POLICY`
+    RoleDoesNotHaveCorrectPolicy,
+    /// A value not known to this version of the crate
+    Unknown(String),
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct ListAssessmentRunAgentsResponse {
-    /// <p>A list of ARNs that specifies the agents returned by the action.</p>
-    #[serde(rename = "assessmentRunAgents")]
-    pub assessment_run_agents: Vec<AssessmentRunAgent>,
-    /// <p> When a response is generated, if there is more data to be listed, this parameter is present in the response and contains the value to use for the <b>nextToken</b> parameter in a subsequent pagination request. If there is no more data to be listed, this parameter is set to null.</p>
-    #[serde(rename = "nextToken")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_token: Option<String>,
+impl InvalidCrossAccountRoleErrorCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InvalidCrossAccountRoleErrorCode::RoleDoesNotExistOrInvalidTrustRelationship => {
+                "ROLE_DOES_NOT_EXIST_OR_INVALID_TRUST_RELATIONSHIP"
+            }
+            InvalidCrossAccountRoleErrorCode::RoleDoesNotHaveCorrectPolicy => {
+                "ROLE_DOES_NOT_HAVE_CORRECT_POLICY"
+            }
+            InvalidCrossAccountRoleErrorCode::Unknown(ref value) => value,
+        }
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct ListAssessmentRunsRequest {
-    /// <p>The ARNs that specify the assessment templates whose assessment runs you want to list.</p>
-    #[serde(rename = "assessmentTemplateArns")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub assessment_template_arns: Option<Vec<String>>,
-    /// <p>You can use this parameter to specify a subset of data to be included in the action's response.</p> <p>For a record to match a filter, all specified filter attributes must match. When multiple values are specified for a filter attribute, any of the values can match.</p>
-    #[serde(rename = "filter")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub filter: Option<AssessmentRunFilter>,
-    /// <p>You can use this parameter to indicate the maximum number of items that you want in the response. The default value is 10. The maximum value is 500.</p>
-    #[serde(rename = "maxResults")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub max_results: Option<i64>,
-    /// <p>You can use this parameter when paginating results. Set the value of this parameter to null on your first call to the <b>ListAssessmentRuns</b> action. Subsequent calls to the action fill <b>nextToken</b> in the request with the value of <b>NextToken</b> from the previous response to continue listing data.</p>
-    #[serde(rename = "nextToken")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_token: Option<String>,
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for InvalidCrossAccountRoleErrorCode {
+    fn default() -> InvalidCrossAccountRoleErrorCode {
+        InvalidCrossAccountRoleErrorCode::Unknown(String::new())
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct ListAssessmentRunsResponse {
-    /// <p>A list of ARNs that specifies the assessment runs that are returned by the action.</p>
-    #[serde(rename = "assessmentRunArns")]
-    pub assessment_run_arns: Vec<String>,
-    /// <p> When a response is generated, if there is more data to be listed, this parameter is present in the response and contains the value to use for the <b>nextToken</b> parameter in a subsequent pagination request. If there is no more data to be listed, this parameter is set to null.</p>
-    #[serde(rename = "nextToken")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_token: Option<String>,
+impl AsRef<str> for InvalidCrossAccountRoleErrorCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct ListAssessmentTargetsRequest {
-    /// <p>You can use this parameter to specify a subset of data to be included in the action's response.</p> <p>For a record to match a filter, all specified filter attributes must match. When multiple values are specified for a filter attribute, any of the values can match.</p>
-    #[serde(rename = "filter")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub filter: Option<AssessmentTargetFilter>,
-    /// <p>You can use this parameter to indicate the maximum number of items you want in the response. The default value is 10. The maximum value is 500.</p>
-    #[serde(rename = "maxResults")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub max_results: Option<i64>,
-    /// <p>You can use this parameter when paginating results. Set the value of this parameter to null on your first call to the <b>ListAssessmentTargets</b> action. Subsequent calls to the action fill <b>nextToken</b> in the request with the value of <b>NextToken</b> from the previous response to continue listing data.</p>
-    #[serde(rename = "nextToken")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_token: Option<String>,
+impl fmt::Display for InvalidCrossAccountRoleErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct ListAssessmentTargetsResponse {
-    /// <p>A list of ARNs that specifies the assessment targets that are returned by the action.</p>
-    #[serde(rename = "assessmentTargetArns")]
-    pub assessment_target_arns: Vec<String>,
-    /// <p> When a response is generated, if there is more data to be listed, this parameter is present in the response and contains the value to use for the <b>nextToken</b> parameter in a subsequent pagination request. If there is no more data to be listed, this parameter is set to null.</p>
-    #[serde(rename = "nextToken")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_token: Option<String>,
+impl<'a> From<&'a str> for InvalidCrossAccountRoleErrorCode {
+    fn from(value: &'a str) -> InvalidCrossAccountRoleErrorCode {
+        match value {
+            "ROLE_DOES_NOT_EXIST_OR_INVALID_TRUST_RELATIONSHIP" => {
+                InvalidCrossAccountRoleErrorCode::RoleDoesNotExistOrInvalidTrustRelationship
+            }
+            "ROLE_DOES_NOT_HAVE_CORRECT_POLICY" => {
+                InvalidCrossAccountRoleErrorCode::RoleDoesNotHaveCorrectPolicy
+            }
+            _ => InvalidCrossAccountRoleErrorCode::Unknown(value.to_owned()),
+        }
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct ListAssessmentTemplatesRequest {
-    /// <p>A list of ARNs that specifies the assessment targets whose assessment templates you want to list.</p>
-    #[serde(rename = "assessmentTargetArns")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub assessment_target_arns: Option<Vec<String>>,
-    /// <p>You can use this parameter to specify a subset of data to be included in the action's response.</p> <p>For a record to match a filter, all specified filter attributes must match. When multiple values are specified for a filter attribute, any of the values can match.</p>
-    #[serde(rename = "filter")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub filter: Option<AssessmentTemplateFilter>,
-    /// <p>You can use this parameter to indicate the maximum number of items you want in the response. The default value is 10. The maximum value is 500.</p>
-    #[serde(rename = "maxResults")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub max_results: Option<i64>,
-    /// <p>You can use this parameter when paginating results. Set the value of this parameter to null on your first call to the <b>ListAssessmentTemplates</b> action. Subsequent calls to the action fill <b>nextToken</b> in the request with the value of <b>NextToken</b> from the previous response to continue listing data.</p>
-    #[serde(rename = "nextToken")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_token: Option<String>,
+impl From<String> for InvalidCrossAccountRoleErrorCode {
+    fn from(value: String) -> InvalidCrossAccountRoleErrorCode {
+        match value.as_str() {
+            "ROLE_DOES_NOT_EXIST_OR_INVALID_TRUST_RELATIONSHIP" => {
+                InvalidCrossAccountRoleErrorCode::RoleDoesNotExistOrInvalidTrustRelationship
+            }
+            "ROLE_DOES_NOT_HAVE_CORRECT_POLICY" => {
+                InvalidCrossAccountRoleErrorCode::RoleDoesNotHaveCorrectPolicy
+            }
+            _ => InvalidCrossAccountRoleErrorCode::Unknown(value),
+        }
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct ListAssessmentTemplatesResponse {
-    /// <p>A list of ARNs that specifies the assessment templates returned by the action.</p>
-    #[serde(rename = "assessmentTemplateArns")]
-    pub assessment_template_arns: Vec<String>,
-    /// <p> When a response is generated, if there is more data to be listed, this parameter is present in the response and contains the value to use for the <b>nextToken</b> parameter in a subsequent pagination request. If there is no more data to be listed, this parameter is set to null.</p>
-    #[serde(rename = "nextToken")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_token: Option<String>,
+impl ::std::str::FromStr for InvalidCrossAccountRoleErrorCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<InvalidCrossAccountRoleErrorCode, Self::Err> {
+        Ok(value.into())
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct ListEventSubscriptionsRequest {
-    /// <p>You can use this parameter to indicate the maximum number of items you want in the response. The default value is 10. The maximum value is 500.</p>
-    #[serde(rename = "maxResults")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub max_results: Option<i64>,
-    /// <p>You can use this parameter when paginating results. Set the value of this parameter to null on your first call to the <b>ListEventSubscriptions</b> action. Subsequent calls to the action fill <b>nextToken</b> in the request with the value of <b>NextToken</b> from the previous response to continue listing data.</p>
-    #[serde(rename = "nextToken")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_token: Option<String>,
-    /// <p>The ARN of the assessment template for which you want to list the existing event subscriptions.</p>
-    #[serde(rename = "resourceArn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_arn: Option<String>,
+impl ::rusoto_core::param::ToParam for InvalidCrossAccountRoleErrorCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct ListEventSubscriptionsResponse {
-    /// <p> When a response is generated, if there is more data to be listed, this parameter is present in the response and contains the value to use for the <b>nextToken</b> parameter in a subsequent pagination request. If there is no more data to be listed, this parameter is set to null.</p>
-    #[serde(rename = "nextToken")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_token: Option<String>,
-    /// <p>Details of the returned event subscriptions.</p>
-    #[serde(rename = "subscriptions")]
-    pub subscriptions: Vec<Subscription>,
+impl ::serde::Serialize for InvalidCrossAccountRoleErrorCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct ListExclusionsRequest {
-    /// <p>The ARN of the assessment run that generated the exclusions that you want to list.</p>
-    #[serde(rename = "assessmentRunArn")]
-    pub assessment_run_arn: String,
-    /// <p>You can use this parameter to indicate the maximum number of items you want in the response. The default value is 100. The maximum value is 500.</p>
-    #[serde(rename = "maxResults")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub max_results: Option<i64>,
+impl<'de> ::serde::Deserialize<'de> for InvalidCrossAccountRoleErrorCode {
+    fn deserialize<D>(deserializer: D) -> Result<InvalidCrossAccountRoleErrorCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum InvalidInputErrorCode {
+    /// `INVALID_ASSESSMENT_TARGET_ARN`
+    InvalidAssessmentTargetArn,
+    /// `INVALID_ASSESSMENT_TEMPLATE_ARN`
+    InvalidAssessmentTemplateArn,
+    /// `INVALID_ASSESSMENT_RUN_ARN`
+    InvalidAssessmentRunArn,
+    /// `INVALID_FINDING_ARN`
+    InvalidFindingArn,
+    /// `INVALID_RESOURCE_GROUP_ARN`
+    InvalidResourceGroupArn,
+    /// `INVALID_RULES_PACKAGE_ARN`
+    InvalidRulesPackageArn,
+    /// `INVALID_RESOURCE_ARN`
+    InvalidResourceArn,
+    /// `INVALID_SNS_TOPIC_ARN`
+    InvalidSnsTopicArn,
+    /// `INVALID_IAM_ROLE_ARN`
+    InvalidIamRoleArn,
+    /// `INVALID_ASSESSMENT_TARGET_NAME`
+    InvalidAssessmentTargetName,
+    /// `INVALID_ASSESSMENT_TARGET_NAME_PATTERN`
+    InvalidAssessmentTargetNamePattern,
+    /// `INVALID_ASSESSMENT_TEMPLATE_NAME`
+    InvalidAssessmentTemplateName,
+    /// `INVALID_ASSESSMENT_TEMPLATE_NAME_PATTERN`
+    InvalidAssessmentTemplateNamePattern,
+    /// `INVALID_ASSESSMENT_TEMPLATE_DURATION`
+    InvalidAssessmentTemplateDuration,
+    /// `INVALID_ASSESSMENT_TEMPLATE_DURATION_RANGE`
+    InvalidAssessmentTemplateDurationRange,
+    /// `INVALID_ASSESSMENT_RUN_DURATION_RANGE`
+    InvalidAssessmentRunDurationRange,
+    /// `INVALID_ASSESSMENT_RUN_START_TIME_RANGE`
+    InvalidAssessmentRunStartTimeRange,
+    /// `INVALID_ASSESSMENT_RUN_COMPLETION_TIME_RANGE`
+    InvalidAssessmentRunCompletionTimeRange,
+    /// `INVALID_ASSESSMENT_RUN_STATE_CHANGE_TIME_RANGE`
+    InvalidAssessmentRunStateChangeTimeRange,
+    /// `INVALID_ASSESSMENT_RUN_STATE`
+    InvalidAssessmentRunState,
+    /// `INVALID_TAG`
+    InvalidTag,
+    /// `INVALID_TAG_KEY`
+    InvalidTagKey,
+    /// `INVALID_TAG_VALUE`
+    InvalidTagValue,
+    /// `INVALID_RESOURCE_GROUP_TAG_KEY`
+    InvalidResourceGroupTagKey,
+    /// `INVALID_RESOURCE_GROUP_TAG_VALUE`
+    InvalidResourceGroupTagValue,
+    /// `INVALID_ATTRIBUTE`
+    InvalidAttribute,
+    /// `INVALID_USER_ATTRIBUTE`
+    InvalidUserAttribute,
+    /// `INVALID_USER_ATTRIBUTE_KEY`
+    InvalidUserAttributeKey,
+    /// `INVALID_USER_ATTRIBUTE_VALUE`
+    InvalidUserAttributeValue,
+    /// `INVALID_PAGINATION_TOKEN`
+    InvalidPaginationToken,
+    /// `INVALID_MAX_RESULTS`
+    InvalidMaxResults,
+    /// `INVALID_AGENT_ID`
+    InvalidAgentId,
+    /// `INVALID_AUTO_SCALING_GROUP`
+    InvalidAutoScalingGroup,
+    /// `INVALID_RULE_NAME`
+    InvalidRuleName,
+    /// `INVALID_SEVERITY`
+    InvalidSeverity,
+    /// `INVALID_LOCALE`
+    InvalidLocale,
+    /// `INVALID_EVENT`
+    InvalidEvent,
+    /// `ASSESSMENT_TARGET_NAME_ALREADY_TAKEN`
+    AssessmentTargetNameAlreadyTaken,
+    /// `ASSESSMENT_TEMPLATE_NAME_ALREADY_TAKEN`
+    AssessmentTemplateNameAlreadyTaken,
+    /// `INVALID_NUMBER_OF_ASSESSMENT_TARGET_ARNS`
+    InvalidNumberOfAssessmentTargetArns,
+    /// `INVALID_NUMBER_OF_ASSESSMENT_TEMPLATE_ARNS`
+    InvalidNumberOfAssessmentTemplateArns,
+    /// `INVALID_NUMBER_OF_ASSESSMENT_RUN_ARNS`
+    InvalidNumberOfAssessmentRunArns,
+    /// `INVALID_NUMBER_OF_FINDING_ARNS`
+    InvalidNumberOfFindingArns,
+    /// `INVALID_NUMBER_OF_RESOURCE_GROUP_ARNS`
+    InvalidNumberOfResourceGroupArns,
+    /// `INVALID_NUMBER_OF_RULES_PACKAGE_ARNS`
+    InvalidNumberOfRulesPackageArns,
+    /// `INVALID_NUMBER_OF_ASSESSMENT_RUN_STATES`
+    InvalidNumberOfAssessmentRunStates,
+    /// `INVALID_NUMBER_OF_TAGS`
+    InvalidNumberOfTags,
+    /// `INVALID_NUMBER_OF_RESOURCE_GROUP_TAGS`
+    InvalidNumberOfResourceGroupTags,
+    /// `INVALID_NUMBER_OF_ATTRIBUTES`
+    InvalidNumberOfAttributes,
+    /// `INVALID_NUMBER_OF_USER_ATTRIBUTES`
+    InvalidNumberOfUserAttributes,
+    /// `INVALID_NUMBER_OF_AGENT_IDS`
+    InvalidNumberOfAgentIds,
+    /// `INVALID_NUMBER_OF_AUTO_SCALING_GROUPS`
+    InvalidNumberOfAutoScalingGroups,
+    /// `INVALID_NUMBER_OF_RULE_NAMES`
+    InvalidNumberOfRuleNames,
+    /// `INVALID_NUMBER_OF_SEVERITIES`
+    InvalidNumberOfSeverities,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl InvalidInputErrorCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InvalidInputErrorCode::InvalidAssessmentTargetArn => "INVALID_ASSESSMENT_TARGET_ARN",
+            InvalidInputErrorCode::InvalidAssessmentTemplateArn => {
+                "INVALID_ASSESSMENT_TEMPLATE_ARN"
+            }
+            InvalidInputErrorCode::InvalidAssessmentRunArn => "INVALID_ASSESSMENT_RUN_ARN",
+            InvalidInputErrorCode::InvalidFindingArn => "INVALID_FINDING_ARN",
+            InvalidInputErrorCode::InvalidResourceGroupArn => "INVALID_RESOURCE_GROUP_ARN",
+            InvalidInputErrorCode::InvalidRulesPackageArn => "INVALID_RULES_PACKAGE_ARN",
+            InvalidInputErrorCode::InvalidResourceArn => "INVALID_RESOURCE_ARN",
+            InvalidInputErrorCode::InvalidSnsTopicArn => "INVALID_SNS_TOPIC_ARN",
+            InvalidInputErrorCode::InvalidIamRoleArn => "INVALID_IAM_ROLE_ARN",
+            InvalidInputErrorCode::InvalidAssessmentTargetName => "INVALID_ASSESSMENT_TARGET_NAME",
+            InvalidInputErrorCode::InvalidAssessmentTargetNamePattern => {
+                "INVALID_ASSESSMENT_TARGET_NAME_PATTERN"
+            }
+            InvalidInputErrorCode::InvalidAssessmentTemplateName => {
+                "INVALID_ASSESSMENT_TEMPLATE_NAME"
+            }
+            InvalidInputErrorCode::InvalidAssessmentTemplateNamePattern => {
+                "INVALID_ASSESSMENT_TEMPLATE_NAME_PATTERN"
+            }
+            InvalidInputErrorCode::InvalidAssessmentTemplateDuration => {
+                "INVALID_ASSESSMENT_TEMPLATE_DURATION"
+            }
+            InvalidInputErrorCode::InvalidAssessmentTemplateDurationRange => {
+                "INVALID_ASSESSMENT_TEMPLATE_DURATION_RANGE"
+            }
+            InvalidInputErrorCode::InvalidAssessmentRunDurationRange => {
+                "INVALID_ASSESSMENT_RUN_DURATION_RANGE"
+            }
+            InvalidInputErrorCode::InvalidAssessmentRunStartTimeRange => {
+                "INVALID_ASSESSMENT_RUN_START_TIME_RANGE"
+            }
+            InvalidInputErrorCode::InvalidAssessmentRunCompletionTimeRange => {
+                "INVALID_ASSESSMENT_RUN_COMPLETION_TIME_RANGE"
+            }
+            InvalidInputErrorCode::InvalidAssessmentRunStateChangeTimeRange => {
+                "INVALID_ASSESSMENT_RUN_STATE_CHANGE_TIME_RANGE"
+            }
+            InvalidInputErrorCode::InvalidAssessmentRunState => "INVALID_ASSESSMENT_RUN_STATE",
+            InvalidInputErrorCode::InvalidTag => "INVALID_TAG",
+            InvalidInputErrorCode::InvalidTagKey => "INVALID_TAG_KEY",
+            InvalidInputErrorCode::InvalidTagValue => "INVALID_TAG_VALUE",
+            InvalidInputErrorCode::InvalidResourceGroupTagKey => "INVALID_RESOURCE_GROUP_TAG_KEY",
+            InvalidInputErrorCode::InvalidResourceGroupTagValue => {
+                "INVALID_RESOURCE_GROUP_TAG_VALUE"
+            }
+            InvalidInputErrorCode::InvalidAttribute => "INVALID_ATTRIBUTE",
+            InvalidInputErrorCode::InvalidUserAttribute => "INVALID_USER_ATTRIBUTE",
+            InvalidInputErrorCode::InvalidUserAttributeKey => "INVALID_USER_ATTRIBUTE_KEY",
+            InvalidInputErrorCode::InvalidUserAttributeValue => "INVALID_USER_ATTRIBUTE_VALUE",
+            InvalidInputErrorCode::InvalidPaginationToken => "INVALID_PAGINATION_TOKEN",
+            InvalidInputErrorCode::InvalidMaxResults => "INVALID_MAX_RESULTS",
+            InvalidInputErrorCode::InvalidAgentId => "INVALID_AGENT_ID",
+            InvalidInputErrorCode::InvalidAutoScalingGroup => "INVALID_AUTO_SCALING_GROUP",
+            InvalidInputErrorCode::InvalidRuleName => "INVALID_RULE_NAME",
+            InvalidInputErrorCode::InvalidSeverity => "INVALID_SEVERITY",
+            InvalidInputErrorCode::InvalidLocale => "INVALID_LOCALE",
+            InvalidInputErrorCode::InvalidEvent => "INVALID_EVENT",
+            InvalidInputErrorCode::AssessmentTargetNameAlreadyTaken => {
+                "ASSESSMENT_TARGET_NAME_ALREADY_TAKEN"
+            }
+            InvalidInputErrorCode::AssessmentTemplateNameAlreadyTaken => {
+                "ASSESSMENT_TEMPLATE_NAME_ALREADY_TAKEN"
+            }
+            InvalidInputErrorCode::InvalidNumberOfAssessmentTargetArns => {
+                "INVALID_NUMBER_OF_ASSESSMENT_TARGET_ARNS"
+            }
+            InvalidInputErrorCode::InvalidNumberOfAssessmentTemplateArns => {
+                "INVALID_NUMBER_OF_ASSESSMENT_TEMPLATE_ARNS"
+            }
+            InvalidInputErrorCode::InvalidNumberOfAssessmentRunArns => {
+                "INVALID_NUMBER_OF_ASSESSMENT_RUN_ARNS"
+            }
+            InvalidInputErrorCode::InvalidNumberOfFindingArns => "INVALID_NUMBER_OF_FINDING_ARNS",
+            InvalidInputErrorCode::InvalidNumberOfResourceGroupArns => {
+                "INVALID_NUMBER_OF_RESOURCE_GROUP_ARNS"
+            }
+            InvalidInputErrorCode::InvalidNumberOfRulesPackageArns => {
+                "INVALID_NUMBER_OF_RULES_PACKAGE_ARNS"
+            }
+            InvalidInputErrorCode::InvalidNumberOfAssessmentRunStates => {
+                "INVALID_NUMBER_OF_ASSESSMENT_RUN_STATES"
+            }
+            InvalidInputErrorCode::InvalidNumberOfTags => "INVALID_NUMBER_OF_TAGS",
+            InvalidInputErrorCode::InvalidNumberOfResourceGroupTags => {
+                "INVALID_NUMBER_OF_RESOURCE_GROUP_TAGS"
+            }
+            InvalidInputErrorCode::InvalidNumberOfAttributes => "INVALID_NUMBER_OF_ATTRIBUTES",
+            InvalidInputErrorCode::InvalidNumberOfUserAttributes => {
+                "INVALID_NUMBER_OF_USER_ATTRIBUTES"
+            }
+            InvalidInputErrorCode::InvalidNumberOfAgentIds => "INVALID_NUMBER_OF_AGENT_IDS",
+            InvalidInputErrorCode::InvalidNumberOfAutoScalingGroups => {
+                "INVALID_NUMBER_OF_AUTO_SCALING_GROUPS"
+            }
+            InvalidInputErrorCode::InvalidNumberOfRuleNames => "INVALID_NUMBER_OF_RULE_NAMES",
+            InvalidInputErrorCode::InvalidNumberOfSeverities => "INVALID_NUMBER_OF_SEVERITIES",
+            InvalidInputErrorCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for InvalidInputErrorCode {
+    fn default() -> InvalidInputErrorCode {
+        InvalidInputErrorCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for InvalidInputErrorCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for InvalidInputErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for InvalidInputErrorCode {
+    fn from(value: &'a str) -> InvalidInputErrorCode {
+        match value {
+            "INVALID_ASSESSMENT_TARGET_ARN" => InvalidInputErrorCode::InvalidAssessmentTargetArn,
+            "INVALID_ASSESSMENT_TEMPLATE_ARN" => {
+                InvalidInputErrorCode::InvalidAssessmentTemplateArn
+            }
+            "INVALID_ASSESSMENT_RUN_ARN" => InvalidInputErrorCode::InvalidAssessmentRunArn,
+            "INVALID_FINDING_ARN" => InvalidInputErrorCode::InvalidFindingArn,
+            "INVALID_RESOURCE_GROUP_ARN" => InvalidInputErrorCode::InvalidResourceGroupArn,
+            "INVALID_RULES_PACKAGE_ARN" => InvalidInputErrorCode::InvalidRulesPackageArn,
+            "INVALID_RESOURCE_ARN" => InvalidInputErrorCode::InvalidResourceArn,
+            "INVALID_SNS_TOPIC_ARN" => InvalidInputErrorCode::InvalidSnsTopicArn,
+            "INVALID_IAM_ROLE_ARN" => InvalidInputErrorCode::InvalidIamRoleArn,
+            "INVALID_ASSESSMENT_TARGET_NAME" => InvalidInputErrorCode::InvalidAssessmentTargetName,
+            "INVALID_ASSESSMENT_TARGET_NAME_PATTERN" => {
+                InvalidInputErrorCode::InvalidAssessmentTargetNamePattern
+            }
+            "INVALID_ASSESSMENT_TEMPLATE_NAME" => {
+                InvalidInputErrorCode::InvalidAssessmentTemplateName
+            }
+            "INVALID_ASSESSMENT_TEMPLATE_NAME_PATTERN" => {
+                InvalidInputErrorCode::InvalidAssessmentTemplateNamePattern
+            }
+            "INVALID_ASSESSMENT_TEMPLATE_DURATION" => {
+                InvalidInputErrorCode::InvalidAssessmentTemplateDuration
+            }
+            "INVALID_ASSESSMENT_TEMPLATE_DURATION_RANGE" => {
+                InvalidInputErrorCode::InvalidAssessmentTemplateDurationRange
+            }
+            "INVALID_ASSESSMENT_RUN_DURATION_RANGE" => {
+                InvalidInputErrorCode::InvalidAssessmentRunDurationRange
+            }
+            "INVALID_ASSESSMENT_RUN_START_TIME_RANGE" => {
+                InvalidInputErrorCode::InvalidAssessmentRunStartTimeRange
+            }
+            "INVALID_ASSESSMENT_RUN_COMPLETION_TIME_RANGE" => {
+                InvalidInputErrorCode::InvalidAssessmentRunCompletionTimeRange
+            }
+            "INVALID_ASSESSMENT_RUN_STATE_CHANGE_TIME_RANGE" => {
+                InvalidInputErrorCode::InvalidAssessmentRunStateChangeTimeRange
+            }
+            "INVALID_ASSESSMENT_RUN_STATE" => InvalidInputErrorCode::InvalidAssessmentRunState,
+            "INVALID_TAG" => InvalidInputErrorCode::InvalidTag,
+            "INVALID_TAG_KEY" => InvalidInputErrorCode::InvalidTagKey,
+            "INVALID_TAG_VALUE" => InvalidInputErrorCode::InvalidTagValue,
+            "INVALID_RESOURCE_GROUP_TAG_KEY" => InvalidInputErrorCode::InvalidResourceGroupTagKey,
+            "INVALID_RESOURCE_GROUP_TAG_VALUE" => {
+                InvalidInputErrorCode::InvalidResourceGroupTagValue
+            }
+            "INVALID_ATTRIBUTE" => InvalidInputErrorCode::InvalidAttribute,
+            "INVALID_USER_ATTRIBUTE" => InvalidInputErrorCode::InvalidUserAttribute,
+            "INVALID_USER_ATTRIBUTE_KEY" => InvalidInputErrorCode::InvalidUserAttributeKey,
+            "INVALID_USER_ATTRIBUTE_VALUE" => InvalidInputErrorCode::InvalidUserAttributeValue,
+            "INVALID_PAGINATION_TOKEN" => InvalidInputErrorCode::InvalidPaginationToken,
+            "INVALID_MAX_RESULTS" => InvalidInputErrorCode::InvalidMaxResults,
+            "INVALID_AGENT_ID" => InvalidInputErrorCode::InvalidAgentId,
+            "INVALID_AUTO_SCALING_GROUP" => InvalidInputErrorCode::InvalidAutoScalingGroup,
+            "INVALID_RULE_NAME" => InvalidInputErrorCode::InvalidRuleName,
+            "INVALID_SEVERITY" => InvalidInputErrorCode::InvalidSeverity,
+            "INVALID_LOCALE" => InvalidInputErrorCode::InvalidLocale,
+            "INVALID_EVENT" => InvalidInputErrorCode::InvalidEvent,
+            "ASSESSMENT_TARGET_NAME_ALREADY_TAKEN" => {
+                InvalidInputErrorCode::AssessmentTargetNameAlreadyTaken
+            }
+            "ASSESSMENT_TEMPLATE_NAME_ALREADY_TAKEN" => {
+                InvalidInputErrorCode::AssessmentTemplateNameAlreadyTaken
+            }
+            "INVALID_NUMBER_OF_ASSESSMENT_TARGET_ARNS" => {
+                InvalidInputErrorCode::InvalidNumberOfAssessmentTargetArns
+            }
+            "INVALID_NUMBER_OF_ASSESSMENT_TEMPLATE_ARNS" => {
+                InvalidInputErrorCode::InvalidNumberOfAssessmentTemplateArns
+            }
+            "INVALID_NUMBER_OF_ASSESSMENT_RUN_ARNS" => {
+                InvalidInputErrorCode::InvalidNumberOfAssessmentRunArns
+            }
+            "INVALID_NUMBER_OF_FINDING_ARNS" => InvalidInputErrorCode::InvalidNumberOfFindingArns,
+            "INVALID_NUMBER_OF_RESOURCE_GROUP_ARNS" => {
+                InvalidInputErrorCode::InvalidNumberOfResourceGroupArns
+            }
+            "INVALID_NUMBER_OF_RULES_PACKAGE_ARNS" => {
+                InvalidInputErrorCode::InvalidNumberOfRulesPackageArns
+            }
+            "INVALID_NUMBER_OF_ASSESSMENT_RUN_STATES" => {
+                InvalidInputErrorCode::InvalidNumberOfAssessmentRunStates
+            }
+            "INVALID_NUMBER_OF_TAGS" => InvalidInputErrorCode::InvalidNumberOfTags,
+            "INVALID_NUMBER_OF_RESOURCE_GROUP_TAGS" => {
+                InvalidInputErrorCode::InvalidNumberOfResourceGroupTags
+            }
+            "INVALID_NUMBER_OF_ATTRIBUTES" => InvalidInputErrorCode::InvalidNumberOfAttributes,
+            "INVALID_NUMBER_OF_USER_ATTRIBUTES" => {
+                InvalidInputErrorCode::InvalidNumberOfUserAttributes
+            }
+            "INVALID_NUMBER_OF_AGENT_IDS" => InvalidInputErrorCode::InvalidNumberOfAgentIds,
+            "INVALID_NUMBER_OF_AUTO_SCALING_GROUPS" => {
+                InvalidInputErrorCode::InvalidNumberOfAutoScalingGroups
+            }
+            "INVALID_NUMBER_OF_RULE_NAMES" => InvalidInputErrorCode::InvalidNumberOfRuleNames,
+            "INVALID_NUMBER_OF_SEVERITIES" => InvalidInputErrorCode::InvalidNumberOfSeverities,
+            _ => InvalidInputErrorCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InvalidInputErrorCode {
+    fn from(value: String) -> InvalidInputErrorCode {
+        match value.as_str() {
+            "INVALID_ASSESSMENT_TARGET_ARN" => InvalidInputErrorCode::InvalidAssessmentTargetArn,
+            "INVALID_ASSESSMENT_TEMPLATE_ARN" => {
+                InvalidInputErrorCode::InvalidAssessmentTemplateArn
+            }
+            "INVALID_ASSESSMENT_RUN_ARN" => InvalidInputErrorCode::InvalidAssessmentRunArn,
+            "INVALID_FINDING_ARN" => InvalidInputErrorCode::InvalidFindingArn,
+            "INVALID_RESOURCE_GROUP_ARN" => InvalidInputErrorCode::InvalidResourceGroupArn,
+            "INVALID_RULES_PACKAGE_ARN" => InvalidInputErrorCode::InvalidRulesPackageArn,
+            "INVALID_RESOURCE_ARN" => InvalidInputErrorCode::InvalidResourceArn,
+            "INVALID_SNS_TOPIC_ARN" => InvalidInputErrorCode::InvalidSnsTopicArn,
+            "INVALID_IAM_ROLE_ARN" => InvalidInputErrorCode::InvalidIamRoleArn,
+            "INVALID_ASSESSMENT_TARGET_NAME" => InvalidInputErrorCode::InvalidAssessmentTargetName,
+            "INVALID_ASSESSMENT_TARGET_NAME_PATTERN" => {
+                InvalidInputErrorCode::InvalidAssessmentTargetNamePattern
+            }
+            "INVALID_ASSESSMENT_TEMPLATE_NAME" => {
+                InvalidInputErrorCode::InvalidAssessmentTemplateName
+            }
+            "INVALID_ASSESSMENT_TEMPLATE_NAME_PATTERN" => {
+                InvalidInputErrorCode::InvalidAssessmentTemplateNamePattern
+            }
+            "INVALID_ASSESSMENT_TEMPLATE_DURATION" => {
+                InvalidInputErrorCode::InvalidAssessmentTemplateDuration
+            }
+            "INVALID_ASSESSMENT_TEMPLATE_DURATION_RANGE" => {
+                InvalidInputErrorCode::InvalidAssessmentTemplateDurationRange
+            }
+            "INVALID_ASSESSMENT_RUN_DURATION_RANGE" => {
+                InvalidInputErrorCode::InvalidAssessmentRunDurationRange
+            }
+            "INVALID_ASSESSMENT_RUN_START_TIME_RANGE" => {
+                InvalidInputErrorCode::InvalidAssessmentRunStartTimeRange
+            }
+            "INVALID_ASSESSMENT_RUN_COMPLETION_TIME_RANGE" => {
+                InvalidInputErrorCode::InvalidAssessmentRunCompletionTimeRange
+            }
+            "INVALID_ASSESSMENT_RUN_STATE_CHANGE_TIME_RANGE" => {
+                InvalidInputErrorCode::InvalidAssessmentRunStateChangeTimeRange
+            }
+            "INVALID_ASSESSMENT_RUN_STATE" => InvalidInputErrorCode::InvalidAssessmentRunState,
+            "INVALID_TAG" => InvalidInputErrorCode::InvalidTag,
+            "INVALID_TAG_KEY" => InvalidInputErrorCode::InvalidTagKey,
+            "INVALID_TAG_VALUE" => InvalidInputErrorCode::InvalidTagValue,
+            "INVALID_RESOURCE_GROUP_TAG_KEY" => InvalidInputErrorCode::InvalidResourceGroupTagKey,
+            "INVALID_RESOURCE_GROUP_TAG_VALUE" => {
+                InvalidInputErrorCode::InvalidResourceGroupTagValue
+            }
+            "INVALID_ATTRIBUTE" => InvalidInputErrorCode::InvalidAttribute,
+            "INVALID_USER_ATTRIBUTE" => InvalidInputErrorCode::InvalidUserAttribute,
+            "INVALID_USER_ATTRIBUTE_KEY" => InvalidInputErrorCode::InvalidUserAttributeKey,
+            "INVALID_USER_ATTRIBUTE_VALUE" => InvalidInputErrorCode::InvalidUserAttributeValue,
+            "INVALID_PAGINATION_TOKEN" => InvalidInputErrorCode::InvalidPaginationToken,
+            "INVALID_MAX_RESULTS" => InvalidInputErrorCode::InvalidMaxResults,
+            "INVALID_AGENT_ID" => InvalidInputErrorCode::InvalidAgentId,
+            "INVALID_AUTO_SCALING_GROUP" => InvalidInputErrorCode::InvalidAutoScalingGroup,
+            "INVALID_RULE_NAME" => InvalidInputErrorCode::InvalidRuleName,
+            "INVALID_SEVERITY" => InvalidInputErrorCode::InvalidSeverity,
+            "INVALID_LOCALE" => InvalidInputErrorCode::InvalidLocale,
+            "INVALID_EVENT" => InvalidInputErrorCode::InvalidEvent,
+            "ASSESSMENT_TARGET_NAME_ALREADY_TAKEN" => {
+                InvalidInputErrorCode::AssessmentTargetNameAlreadyTaken
+            }
+            "ASSESSMENT_TEMPLATE_NAME_ALREADY_TAKEN" => {
+                InvalidInputErrorCode::AssessmentTemplateNameAlreadyTaken
+            }
+            "INVALID_NUMBER_OF_ASSESSMENT_TARGET_ARNS" => {
+                InvalidInputErrorCode::InvalidNumberOfAssessmentTargetArns
+            }
+            "INVALID_NUMBER_OF_ASSESSMENT_TEMPLATE_ARNS" => {
+                InvalidInputErrorCode::InvalidNumberOfAssessmentTemplateArns
+            }
+            "INVALID_NUMBER_OF_ASSESSMENT_RUN_ARNS" => {
+                InvalidInputErrorCode::InvalidNumberOfAssessmentRunArns
+            }
+            "INVALID_NUMBER_OF_FINDING_ARNS" => InvalidInputErrorCode::InvalidNumberOfFindingArns,
+            "INVALID_NUMBER_OF_RESOURCE_GROUP_ARNS" => {
+                InvalidInputErrorCode::InvalidNumberOfResourceGroupArns
+            }
+            "INVALID_NUMBER_OF_RULES_PACKAGE_ARNS" => {
+                InvalidInputErrorCode::InvalidNumberOfRulesPackageArns
+            }
+            "INVALID_NUMBER_OF_ASSESSMENT_RUN_STATES" => {
+                InvalidInputErrorCode::InvalidNumberOfAssessmentRunStates
+            }
+            "INVALID_NUMBER_OF_TAGS" => InvalidInputErrorCode::InvalidNumberOfTags,
+            "INVALID_NUMBER_OF_RESOURCE_GROUP_TAGS" => {
+                InvalidInputErrorCode::InvalidNumberOfResourceGroupTags
+            }
+            "INVALID_NUMBER_OF_ATTRIBUTES" => InvalidInputErrorCode::InvalidNumberOfAttributes,
+            "INVALID_NUMBER_OF_USER_ATTRIBUTES" => {
+                InvalidInputErrorCode::InvalidNumberOfUserAttributes
+            }
+            "INVALID_NUMBER_OF_AGENT_IDS" => InvalidInputErrorCode::InvalidNumberOfAgentIds,
+            "INVALID_NUMBER_OF_AUTO_SCALING_GROUPS" => {
+                InvalidInputErrorCode::InvalidNumberOfAutoScalingGroups
+            }
+            "INVALID_NUMBER_OF_RULE_NAMES" => InvalidInputErrorCode::InvalidNumberOfRuleNames,
+            "INVALID_NUMBER_OF_SEVERITIES" => InvalidInputErrorCode::InvalidNumberOfSeverities,
+            _ => InvalidInputErrorCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for InvalidInputErrorCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<InvalidInputErrorCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for InvalidInputErrorCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for InvalidInputErrorCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InvalidInputErrorCode {
+    fn deserialize<D>(deserializer: D) -> Result<InvalidInputErrorCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LimitExceededErrorCode {
+    /// `ASSESSMENT_TARGET_LIMIT_EXCEEDED`
+    AssessmentTargetLimitExceeded,
+    /// `ASSESSMENT_TEMPLATE_LIMIT_EXCEEDED`
+    AssessmentTemplateLimitExceeded,
+    /// `ASSESSMENT_RUN_LIMIT_EXCEEDED`
+    AssessmentRunLimitExceeded,
+    /// `RESOURCE_GROUP_LIMIT_EXCEEDED`
+    ResourceGroupLimitExceeded,
+    /// `EVENT_SUBSCRIPTION_LIMIT_EXCEEDED`
+    EventSubscriptionLimitExceeded,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LimitExceededErrorCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LimitExceededErrorCode::AssessmentTargetLimitExceeded => {
+                "ASSESSMENT_TARGET_LIMIT_EXCEEDED"
+            }
+            LimitExceededErrorCode::AssessmentTemplateLimitExceeded => {
+                "ASSESSMENT_TEMPLATE_LIMIT_EXCEEDED"
+            }
+            LimitExceededErrorCode::AssessmentRunLimitExceeded => "ASSESSMENT_RUN_LIMIT_EXCEEDED",
+            LimitExceededErrorCode::ResourceGroupLimitExceeded => "RESOURCE_GROUP_LIMIT_EXCEEDED",
+            LimitExceededErrorCode::EventSubscriptionLimitExceeded => {
+                "EVENT_SUBSCRIPTION_LIMIT_EXCEEDED"
+            }
+            LimitExceededErrorCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LimitExceededErrorCode {
+    fn default() -> LimitExceededErrorCode {
+        LimitExceededErrorCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LimitExceededErrorCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LimitExceededErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LimitExceededErrorCode {
+    fn from(value: &'a str) -> LimitExceededErrorCode {
+        match value {
+            "ASSESSMENT_TARGET_LIMIT_EXCEEDED" => {
+                LimitExceededErrorCode::AssessmentTargetLimitExceeded
+            }
+            "ASSESSMENT_TEMPLATE_LIMIT_EXCEEDED" => {
+                LimitExceededErrorCode::AssessmentTemplateLimitExceeded
+            }
+            "ASSESSMENT_RUN_LIMIT_EXCEEDED" => LimitExceededErrorCode::AssessmentRunLimitExceeded,
+            "RESOURCE_GROUP_LIMIT_EXCEEDED" => LimitExceededErrorCode::ResourceGroupLimitExceeded,
+            "EVENT_SUBSCRIPTION_LIMIT_EXCEEDED" => {
+                LimitExceededErrorCode::EventSubscriptionLimitExceeded
+            }
+            _ => LimitExceededErrorCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LimitExceededErrorCode {
+    fn from(value: String) -> LimitExceededErrorCode {
+        match value.as_str() {
+            "ASSESSMENT_TARGET_LIMIT_EXCEEDED" => {
+                LimitExceededErrorCode::AssessmentTargetLimitExceeded
+            }
+            "ASSESSMENT_TEMPLATE_LIMIT_EXCEEDED" => {
+                LimitExceededErrorCode::AssessmentTemplateLimitExceeded
+            }
+            "ASSESSMENT_RUN_LIMIT_EXCEEDED" => LimitExceededErrorCode::AssessmentRunLimitExceeded,
+            "RESOURCE_GROUP_LIMIT_EXCEEDED" => LimitExceededErrorCode::ResourceGroupLimitExceeded,
+            "EVENT_SUBSCRIPTION_LIMIT_EXCEEDED" => {
+                LimitExceededErrorCode::EventSubscriptionLimitExceeded
+            }
+            _ => LimitExceededErrorCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LimitExceededErrorCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LimitExceededErrorCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LimitExceededErrorCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LimitExceededErrorCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LimitExceededErrorCode {
+    fn deserialize<D>(deserializer: D) -> Result<LimitExceededErrorCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct ListAssessmentRunAgentsRequest {
+    /// <p>The ARN that specifies the assessment run whose agents you want to list.</p>
+    #[serde(rename = "assessmentRunArn")]
+    pub assessment_run_arn: String,
+    /// <p>You can use this parameter to specify a subset of data to be included in the action's response.</p> <p>For a record to match a filter, all specified filter attributes must match. When multiple values are specified for a filter attribute, any of the values can match.</p>
+    #[serde(rename = "filter")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<AgentFilter>,
+    /// <p>You can use this parameter to indicate the maximum number of items that you want in the response. The default value is 10. The maximum value is 500.</p>
+    #[serde(rename = "maxResults")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_results: Option<i64>,
+    /// <p>You can use this parameter when paginating results. Set the value of this parameter to null on your first call to the <b>ListAssessmentRunAgents</b> action. Subsequent calls to the action fill <b>nextToken</b> in the request with the value of <b>NextToken</b> from the previous response to continue listing data.</p>
+    #[serde(rename = "nextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct ListAssessmentRunAgentsResponse {
+    /// <p>A list of ARNs that specifies the agents returned by the action.</p>
+    #[serde(rename = "assessmentRunAgents")]
+    pub assessment_run_agents: Vec<AssessmentRunAgent>,
+    /// <p> When a response is generated, if there is more data to be listed, this parameter is present in the response and contains the value to use for the <b>nextToken</b> parameter in a subsequent pagination request. If there is no more data to be listed, this parameter is set to null.</p>
+    #[serde(rename = "nextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct ListAssessmentRunsRequest {
+    /// <p>The ARNs that specify the assessment templates whose assessment runs you want to list.</p>
+    #[serde(rename = "assessmentTemplateArns")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assessment_template_arns: Option<Vec<String>>,
+    /// <p>You can use this parameter to specify a subset of data to be included in the action's response.</p> <p>For a record to match a filter, all specified filter attributes must match. When multiple values are specified for a filter attribute, any of the values can match.</p>
+    #[serde(rename = "filter")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<AssessmentRunFilter>,
+    /// <p>You can use this parameter to indicate the maximum number of items that you want in the response. The default value is 10. The maximum value is 500.</p>
+    #[serde(rename = "maxResults")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_results: Option<i64>,
+    /// <p>You can use this parameter when paginating results. Set the value of this parameter to null on your first call to the <b>ListAssessmentRuns</b> action. Subsequent calls to the action fill <b>nextToken</b> in the request with the value of <b>NextToken</b> from the previous response to continue listing data.</p>
+    #[serde(rename = "nextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct ListAssessmentRunsResponse {
+    /// <p>A list of ARNs that specifies the assessment runs that are returned by the action.</p>
+    #[serde(rename = "assessmentRunArns")]
+    pub assessment_run_arns: Vec<String>,
+    /// <p> When a response is generated, if there is more data to be listed, this parameter is present in the response and contains the value to use for the <b>nextToken</b> parameter in a subsequent pagination request. If there is no more data to be listed, this parameter is set to null.</p>
+    #[serde(rename = "nextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct ListAssessmentTargetsRequest {
+    /// <p>You can use this parameter to specify a subset of data to be included in the action's response.</p> <p>For a record to match a filter, all specified filter attributes must match. When multiple values are specified for a filter attribute, any of the values can match.</p>
+    #[serde(rename = "filter")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<AssessmentTargetFilter>,
+    /// <p>You can use this parameter to indicate the maximum number of items you want in the response. The default value is 10. The maximum value is 500.</p>
+    #[serde(rename = "maxResults")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_results: Option<i64>,
+    /// <p>You can use this parameter when paginating results. Set the value of this parameter to null on your first call to the <b>ListAssessmentTargets</b> action. Subsequent calls to the action fill <b>nextToken</b> in the request with the value of <b>NextToken</b> from the previous response to continue listing data.</p>
+    #[serde(rename = "nextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct ListAssessmentTargetsResponse {
+    /// <p>A list of ARNs that specifies the assessment targets that are returned by the action.</p>
+    #[serde(rename = "assessmentTargetArns")]
+    pub assessment_target_arns: Vec<String>,
+    /// <p> When a response is generated, if there is more data to be listed, this parameter is present in the response and contains the value to use for the <b>nextToken</b> parameter in a subsequent pagination request. If there is no more data to be listed, this parameter is set to null.</p>
+    #[serde(rename = "nextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct ListAssessmentTemplatesRequest {
+    /// <p>A list of ARNs that specifies the assessment targets whose assessment templates you want to list.</p>
+    #[serde(rename = "assessmentTargetArns")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assessment_target_arns: Option<Vec<String>>,
+    /// <p>You can use this parameter to specify a subset of data to be included in the action's response.</p> <p>For a record to match a filter, all specified filter attributes must match. When multiple values are specified for a filter attribute, any of the values can match.</p>
+    #[serde(rename = "filter")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<AssessmentTemplateFilter>,
+    /// <p>You can use this parameter to indicate the maximum number of items you want in the response. The default value is 10. The maximum value is 500.</p>
+    #[serde(rename = "maxResults")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_results: Option<i64>,
+    /// <p>You can use this parameter when paginating results. Set the value of this parameter to null on your first call to the <b>ListAssessmentTemplates</b> action. Subsequent calls to the action fill <b>nextToken</b> in the request with the value of <b>NextToken</b> from the previous response to continue listing data.</p>
+    #[serde(rename = "nextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct ListAssessmentTemplatesResponse {
+    /// <p>A list of ARNs that specifies the assessment templates returned by the action.</p>
+    #[serde(rename = "assessmentTemplateArns")]
+    pub assessment_template_arns: Vec<String>,
+    /// <p> When a response is generated, if there is more data to be listed, this parameter is present in the response and contains the value to use for the <b>nextToken</b> parameter in a subsequent pagination request. If there is no more data to be listed, this parameter is set to null.</p>
+    #[serde(rename = "nextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct ListEventSubscriptionsRequest {
+    /// <p>You can use this parameter to indicate the maximum number of items you want in the response. The default value is 10. The maximum value is 500.</p>
+    #[serde(rename = "maxResults")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_results: Option<i64>,
+    /// <p>You can use this parameter when paginating results. Set the value of this parameter to null on your first call to the <b>ListEventSubscriptions</b> action. Subsequent calls to the action fill <b>nextToken</b> in the request with the value of <b>NextToken</b> from the previous response to continue listing data.</p>
+    #[serde(rename = "nextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+    /// <p>The ARN of the assessment template for which you want to list the existing event subscriptions.</p>
+    #[serde(rename = "resourceArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_arn: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct ListEventSubscriptionsResponse {
+    /// <p> When a response is generated, if there is more data to be listed, this parameter is present in the response and contains the value to use for the <b>nextToken</b> parameter in a subsequent pagination request. If there is no more data to be listed, this parameter is set to null.</p>
+    #[serde(rename = "nextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+    /// <p>Details of the returned event subscriptions.</p>
+    #[serde(rename = "subscriptions")]
+    pub subscriptions: Vec<Subscription>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct ListExclusionsRequest {
+    /// <p>The ARN of the assessment run that generated the exclusions that you want to list.</p>
+    #[serde(rename = "assessmentRunArn")]
+    pub assessment_run_arn: String,
+    /// <p>You can use this parameter to indicate the maximum number of items you want in the response. The default value is 100. The maximum value is 500.</p>
+    #[serde(rename = "maxResults")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_results: Option<i64>,
     /// <p>You can use this parameter when paginating results. Set the value of this parameter to null on your first call to the ListExclusionsRequest action. Subsequent calls to the action fill nextToken in the request with the value of nextToken from the previous response to continue listing data.</p>
     #[serde(rename = "nextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1176,34 +2869,122 @@ pub struct ListRulesPackagesRequest {
     pub next_token: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct ListRulesPackagesResponse {
-    /// <p> When a response is generated, if there is more data to be listed, this parameter is present in the response and contains the value to use for the <b>nextToken</b> parameter in a subsequent pagination request. If there is no more data to be listed, this parameter is set to null.</p>
-    #[serde(rename = "nextToken")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_token: Option<String>,
-    /// <p>The list of ARNs that specifies the rules packages returned by the action.</p>
-    #[serde(rename = "rulesPackageArns")]
-    pub rules_package_arns: Vec<String>,
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct ListRulesPackagesResponse {
+    /// <p> When a response is generated, if there is more data to be listed, this parameter is present in the response and contains the value to use for the <b>nextToken</b> parameter in a subsequent pagination request. If there is no more data to be listed, this parameter is set to null.</p>
+    #[serde(rename = "nextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+    /// <p>The list of ARNs that specifies the rules packages returned by the action.</p>
+    #[serde(rename = "rulesPackageArns")]
+    pub rules_package_arns: Vec<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct ListTagsForResourceRequest {
+    /// <p>The ARN that specifies the assessment template whose tags you want to list.</p>
+    #[serde(rename = "resourceArn")]
+    pub resource_arn: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct ListTagsForResourceResponse {
+    /// <p>A collection of key and value pairs.</p>
+    #[serde(rename = "tags")]
+    pub tags: Vec<Tag>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Locale {
+    /// `EN_US`
+    EnUs,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Locale {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Locale::EnUs => "EN_US",
+            Locale::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Locale {
+    fn default() -> Locale {
+        Locale::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Locale {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Locale {
+    fn from(value: &'a str) -> Locale {
+        match value {
+            "EN_US" => Locale::EnUs,
+            _ => Locale::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Locale {
+    fn from(value: String) -> Locale {
+        match value.as_str() {
+            "EN_US" => Locale::EnUs,
+            _ => Locale::Unknown(value),
+        }
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct ListTagsForResourceRequest {
-    /// <p>The ARN that specifies the assessment template whose tags you want to list.</p>
-    #[serde(rename = "resourceArn")]
-    pub resource_arn: String,
+impl ::std::str::FromStr for Locale {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Locale, Self::Err> {
+        Ok(value.into())
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct ListTagsForResourceResponse {
-    /// <p>A collection of key and value pairs.</p>
-    #[serde(rename = "tags")]
-    pub tags: Vec<Tag>,
+impl ::rusoto_core::param::ToParam for Locale {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Locale {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for Locale {
+    fn deserialize<D>(deserializer: D) -> Result<Locale, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Contains information about the network interfaces interacting with an EC2 instance. This data type is used as one of the elements of the <a>AssetAttributes</a> data type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1250,6 +3031,141 @@ pub struct NetworkInterface {
     pub vpc_id: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum NoSuchEntityErrorCode {
+    /// `ASSESSMENT_TARGET_DOES_NOT_EXIST`
+    AssessmentTargetDoesNotExist,
+    /// `ASSESSMENT_TEMPLATE_DOES_NOT_EXIST`
+    AssessmentTemplateDoesNotExist,
+    /// `ASSESSMENT_RUN_DOES_NOT_EXIST`
+    AssessmentRunDoesNotExist,
+    /// `FINDING_DOES_NOT_EXIST`
+    FindingDoesNotExist,
+    /// `RESOURCE_GROUP_DOES_NOT_EXIST`
+    ResourceGroupDoesNotExist,
+    /// `RULES_PACKAGE_DOES_NOT_EXIST`
+    RulesPackageDoesNotExist,
+    /// `SNS_TOPIC_DOES_NOT_EXIST`
+    SnsTopicDoesNotExist,
+    /// `IAM_ROLE_DOES_NOT_EXIST`
+    IamRoleDoesNotExist,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl NoSuchEntityErrorCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            NoSuchEntityErrorCode::AssessmentTargetDoesNotExist => {
+                "ASSESSMENT_TARGET_DOES_NOT_EXIST"
+            }
+            NoSuchEntityErrorCode::AssessmentTemplateDoesNotExist => {
+                "ASSESSMENT_TEMPLATE_DOES_NOT_EXIST"
+            }
+            NoSuchEntityErrorCode::AssessmentRunDoesNotExist => "ASSESSMENT_RUN_DOES_NOT_EXIST",
+            NoSuchEntityErrorCode::FindingDoesNotExist => "FINDING_DOES_NOT_EXIST",
+            NoSuchEntityErrorCode::ResourceGroupDoesNotExist => "RESOURCE_GROUP_DOES_NOT_EXIST",
+            NoSuchEntityErrorCode::RulesPackageDoesNotExist => "RULES_PACKAGE_DOES_NOT_EXIST",
+            NoSuchEntityErrorCode::SnsTopicDoesNotExist => "SNS_TOPIC_DOES_NOT_EXIST",
+            NoSuchEntityErrorCode::IamRoleDoesNotExist => "IAM_ROLE_DOES_NOT_EXIST",
+            NoSuchEntityErrorCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for NoSuchEntityErrorCode {
+    fn default() -> NoSuchEntityErrorCode {
+        NoSuchEntityErrorCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for NoSuchEntityErrorCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for NoSuchEntityErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for NoSuchEntityErrorCode {
+    fn from(value: &'a str) -> NoSuchEntityErrorCode {
+        match value {
+            "ASSESSMENT_TARGET_DOES_NOT_EXIST" => {
+                NoSuchEntityErrorCode::AssessmentTargetDoesNotExist
+            }
+            "ASSESSMENT_TEMPLATE_DOES_NOT_EXIST" => {
+                NoSuchEntityErrorCode::AssessmentTemplateDoesNotExist
+            }
+            "ASSESSMENT_RUN_DOES_NOT_EXIST" => NoSuchEntityErrorCode::AssessmentRunDoesNotExist,
+            "FINDING_DOES_NOT_EXIST" => NoSuchEntityErrorCode::FindingDoesNotExist,
+            "RESOURCE_GROUP_DOES_NOT_EXIST" => NoSuchEntityErrorCode::ResourceGroupDoesNotExist,
+            "RULES_PACKAGE_DOES_NOT_EXIST" => NoSuchEntityErrorCode::RulesPackageDoesNotExist,
+            "SNS_TOPIC_DOES_NOT_EXIST" => NoSuchEntityErrorCode::SnsTopicDoesNotExist,
+            "IAM_ROLE_DOES_NOT_EXIST" => NoSuchEntityErrorCode::IamRoleDoesNotExist,
+            _ => NoSuchEntityErrorCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for NoSuchEntityErrorCode {
+    fn from(value: String) -> NoSuchEntityErrorCode {
+        match value.as_str() {
+            "ASSESSMENT_TARGET_DOES_NOT_EXIST" => {
+                NoSuchEntityErrorCode::AssessmentTargetDoesNotExist
+            }
+            "ASSESSMENT_TEMPLATE_DOES_NOT_EXIST" => {
+                NoSuchEntityErrorCode::AssessmentTemplateDoesNotExist
+            }
+            "ASSESSMENT_RUN_DOES_NOT_EXIST" => NoSuchEntityErrorCode::AssessmentRunDoesNotExist,
+            "FINDING_DOES_NOT_EXIST" => NoSuchEntityErrorCode::FindingDoesNotExist,
+            "RESOURCE_GROUP_DOES_NOT_EXIST" => NoSuchEntityErrorCode::ResourceGroupDoesNotExist,
+            "RULES_PACKAGE_DOES_NOT_EXIST" => NoSuchEntityErrorCode::RulesPackageDoesNotExist,
+            "SNS_TOPIC_DOES_NOT_EXIST" => NoSuchEntityErrorCode::SnsTopicDoesNotExist,
+            "IAM_ROLE_DOES_NOT_EXIST" => NoSuchEntityErrorCode::IamRoleDoesNotExist,
+            _ => NoSuchEntityErrorCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for NoSuchEntityErrorCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<NoSuchEntityErrorCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for NoSuchEntityErrorCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for NoSuchEntityErrorCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for NoSuchEntityErrorCode {
+    fn deserialize<D>(deserializer: D) -> Result<NoSuchEntityErrorCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct PreviewAgentsRequest {
@@ -1278,47 +3194,424 @@ pub struct PreviewAgentsResponse {
     pub next_token: Option<String>,
 }
 
-/// <p>Contains information about a private IP address associated with a network interface. This data type is used as a response element in the <a>DescribeFindings</a> action.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct PrivateIp {
-    /// <p>The DNS name of the private IP address.</p>
-    #[serde(rename = "privateDnsName")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub private_dns_name: Option<String>,
-    /// <p>The full IP address of the network inteface.</p>
-    #[serde(rename = "privateIpAddress")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub private_ip_address: Option<String>,
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PreviewStatus {
+    /// `WORK_IN_PROGRESS`
+    WorkInProgress,
+    /// `COMPLETED`
+    Completed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PreviewStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PreviewStatus::WorkInProgress => "WORK_IN_PROGRESS",
+            PreviewStatus::Completed => "COMPLETED",
+            PreviewStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PreviewStatus {
+    fn default() -> PreviewStatus {
+        PreviewStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PreviewStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PreviewStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PreviewStatus {
+    fn from(value: &'a str) -> PreviewStatus {
+        match value {
+            "WORK_IN_PROGRESS" => PreviewStatus::WorkInProgress,
+            "COMPLETED" => PreviewStatus::Completed,
+            _ => PreviewStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PreviewStatus {
+    fn from(value: String) -> PreviewStatus {
+        match value.as_str() {
+            "WORK_IN_PROGRESS" => PreviewStatus::WorkInProgress,
+            "COMPLETED" => PreviewStatus::Completed,
+            _ => PreviewStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PreviewStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PreviewStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PreviewStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PreviewStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PreviewStatus {
+    fn deserialize<D>(deserializer: D) -> Result<PreviewStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Contains information about a private IP address associated with a network interface. This data type is used as a response element in the <a>DescribeFindings</a> action.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct PrivateIp {
+    /// <p>The DNS name of the private IP address.</p>
+    #[serde(rename = "privateDnsName")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private_dns_name: Option<String>,
+    /// <p>The full IP address of the network inteface.</p>
+    #[serde(rename = "privateIpAddress")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private_ip_address: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct RegisterCrossAccountAccessRoleRequest {
+    /// <p>The ARN of the IAM role that grants Amazon Inspector access to AWS Services needed to perform security assessments. </p>
+    #[serde(rename = "roleArn")]
+    pub role_arn: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct RemoveAttributesFromFindingsRequest {
+    /// <p>The array of attribute keys that you want to remove from specified findings.</p>
+    #[serde(rename = "attributeKeys")]
+    pub attribute_keys: Vec<String>,
+    /// <p>The ARNs that specify the findings that you want to remove attributes from.</p>
+    #[serde(rename = "findingArns")]
+    pub finding_arns: Vec<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct RemoveAttributesFromFindingsResponse {
+    /// <p>Attributes details that cannot be described. An error code is provided for each failed item.</p>
+    #[serde(rename = "failedItems")]
+    pub failed_items: ::std::collections::HashMap<String, FailedItemDetails>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ReportFileFormat {
+    /// `HTML`
+    Html,
+    /// `PDF`
+    Pdf,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ReportFileFormat {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReportFileFormat::Html => "HTML",
+            ReportFileFormat::Pdf => "PDF",
+            ReportFileFormat::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ReportFileFormat {
+    fn default() -> ReportFileFormat {
+        ReportFileFormat::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ReportFileFormat {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ReportFileFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ReportFileFormat {
+    fn from(value: &'a str) -> ReportFileFormat {
+        match value {
+            "HTML" => ReportFileFormat::Html,
+            "PDF" => ReportFileFormat::Pdf,
+            _ => ReportFileFormat::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReportFileFormat {
+    fn from(value: String) -> ReportFileFormat {
+        match value.as_str() {
+            "HTML" => ReportFileFormat::Html,
+            "PDF" => ReportFileFormat::Pdf,
+            _ => ReportFileFormat::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ReportFileFormat {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ReportFileFormat, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ReportFileFormat {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ReportFileFormat {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ReportFileFormat {
+    fn deserialize<D>(deserializer: D) -> Result<ReportFileFormat, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ReportStatus {
+    /// `WORK_IN_PROGRESS`
+    WorkInProgress,
+    /// `FAILED`
+    Failed,
+    /// `COMPLETED`
+    Completed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ReportStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReportStatus::WorkInProgress => "WORK_IN_PROGRESS",
+            ReportStatus::Failed => "FAILED",
+            ReportStatus::Completed => "COMPLETED",
+            ReportStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ReportStatus {
+    fn default() -> ReportStatus {
+        ReportStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ReportStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ReportStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ReportStatus {
+    fn from(value: &'a str) -> ReportStatus {
+        match value {
+            "WORK_IN_PROGRESS" => ReportStatus::WorkInProgress,
+            "FAILED" => ReportStatus::Failed,
+            "COMPLETED" => ReportStatus::Completed,
+            _ => ReportStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReportStatus {
+    fn from(value: String) -> ReportStatus {
+        match value.as_str() {
+            "WORK_IN_PROGRESS" => ReportStatus::WorkInProgress,
+            "FAILED" => ReportStatus::Failed,
+            "COMPLETED" => ReportStatus::Completed,
+            _ => ReportStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ReportStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ReportStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ReportStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ReportStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ReportStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ReportStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ReportType {
+    /// `FINDING`
+    Finding,
+    /// `FULL`
+    Full,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ReportType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReportType::Finding => "FINDING",
+            ReportType::Full => "FULL",
+            ReportType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ReportType {
+    fn default() -> ReportType {
+        ReportType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ReportType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ReportType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct RegisterCrossAccountAccessRoleRequest {
-    /// <p>The ARN of the IAM role that grants Amazon Inspector access to AWS Services needed to perform security assessments. </p>
-    #[serde(rename = "roleArn")]
-    pub role_arn: String,
+impl<'a> From<&'a str> for ReportType {
+    fn from(value: &'a str) -> ReportType {
+        match value {
+            "FINDING" => ReportType::Finding,
+            "FULL" => ReportType::Full,
+            _ => ReportType::Unknown(value.to_owned()),
+        }
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct RemoveAttributesFromFindingsRequest {
-    /// <p>The array of attribute keys that you want to remove from specified findings.</p>
-    #[serde(rename = "attributeKeys")]
-    pub attribute_keys: Vec<String>,
-    /// <p>The ARNs that specify the findings that you want to remove attributes from.</p>
-    #[serde(rename = "findingArns")]
-    pub finding_arns: Vec<String>,
+impl From<String> for ReportType {
+    fn from(value: String) -> ReportType {
+        match value.as_str() {
+            "FINDING" => ReportType::Finding,
+            "FULL" => ReportType::Full,
+            _ => ReportType::Unknown(value),
+        }
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct RemoveAttributesFromFindingsResponse {
-    /// <p>Attributes details that cannot be described. An error code is provided for each failed item.</p>
-    #[serde(rename = "failedItems")]
-    pub failed_items: ::std::collections::HashMap<String, FailedItemDetails>,
+impl ::std::str::FromStr for ReportType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ReportType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ReportType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ReportType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for ReportType {
+    fn deserialize<D>(deserializer: D) -> Result<ReportType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Contains information about a resource group. The resource group defines a set of tags that, when queried, identify the AWS resources that make up the assessment target. This data type is used as the response element in the <a>DescribeResourceGroups</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1328,7 +3621,12 @@ pub struct ResourceGroup {
     pub arn: String,
     /// <p>The time at which resource group is created.</p>
     #[serde(rename = "createdAt")]
-    pub created_at: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub created_at: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The tags (key and value pairs) of the resource group. This data type property is used in the <a>CreateResourceGroup</a> action.</p>
     #[serde(rename = "tags")]
     pub tags: Vec<ResourceGroupTag>,
@@ -1375,13 +3673,106 @@ pub struct Scope {
     /// <p>The type of the scope.</p>
     #[serde(rename = "key")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub key: Option<String>,
+    pub key: Option<ScopeType>,
     /// <p>The resource identifier for the specified scope type.</p>
     #[serde(rename = "value")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ScopeType {
+    /// `INSTANCE_ID`
+    InstanceId,
+    /// `RULES_PACKAGE_ARN`
+    RulesPackageArn,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ScopeType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ScopeType::InstanceId => "INSTANCE_ID",
+            ScopeType::RulesPackageArn => "RULES_PACKAGE_ARN",
+            ScopeType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ScopeType {
+    fn default() -> ScopeType {
+        ScopeType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ScopeType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ScopeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ScopeType {
+    fn from(value: &'a str) -> ScopeType {
+        match value {
+            "INSTANCE_ID" => ScopeType::InstanceId,
+            "RULES_PACKAGE_ARN" => ScopeType::RulesPackageArn,
+            _ => ScopeType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ScopeType {
+    fn from(value: String) -> ScopeType {
+        match value.as_str() {
+            "INSTANCE_ID" => ScopeType::InstanceId,
+            "RULES_PACKAGE_ARN" => ScopeType::RulesPackageArn,
+            _ => ScopeType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ScopeType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ScopeType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ScopeType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ScopeType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ScopeType {
+    fn deserialize<D>(deserializer: D) -> Result<ScopeType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Contains information about a security group associated with a network interface. This data type is used as one of the elements of the <a>NetworkInterface</a> data type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1408,6 +3799,114 @@ pub struct SetTagsForResourceRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Severity {
+    /// `Low`
+    Low,
+    /// `Medium`
+    Medium,
+    /// `High`
+    High,
+    /// `Informational`
+    Informational,
+    /// `Undefined`
+    Undefined,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Severity {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Severity::Low => "Low",
+            Severity::Medium => "Medium",
+            Severity::High => "High",
+            Severity::Informational => "Informational",
+            Severity::Undefined => "Undefined",
+            Severity::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Severity {
+    fn default() -> Severity {
+        Severity::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Severity {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Severity {
+    fn from(value: &'a str) -> Severity {
+        match value {
+            "Low" => Severity::Low,
+            "Medium" => Severity::Medium,
+            "High" => Severity::High,
+            "Informational" => Severity::Informational,
+            "Undefined" => Severity::Undefined,
+            _ => Severity::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Severity {
+    fn from(value: String) -> Severity {
+        match value.as_str() {
+            "Low" => Severity::Low,
+            "Medium" => Severity::Medium,
+            "High" => Severity::High,
+            "Informational" => Severity::Informational,
+            "Undefined" => Severity::Undefined,
+            _ => Severity::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Severity {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Severity, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Severity {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Severity {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Severity {
+    fn deserialize<D>(deserializer: D) -> Result<Severity, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct StartAssessmentRunRequest {
@@ -1428,6 +3927,99 @@ pub struct StartAssessmentRunResponse {
     pub assessment_run_arn: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StopAction {
+    /// `START_EVALUATION`
+    StartEvaluation,
+    /// `SKIP_EVALUATION`
+    SkipEvaluation,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl StopAction {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StopAction::StartEvaluation => "START_EVALUATION",
+            StopAction::SkipEvaluation => "SKIP_EVALUATION",
+            StopAction::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for StopAction {
+    fn default() -> StopAction {
+        StopAction::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for StopAction {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for StopAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for StopAction {
+    fn from(value: &'a str) -> StopAction {
+        match value {
+            "START_EVALUATION" => StopAction::StartEvaluation,
+            "SKIP_EVALUATION" => StopAction::SkipEvaluation,
+            _ => StopAction::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StopAction {
+    fn from(value: String) -> StopAction {
+        match value.as_str() {
+            "START_EVALUATION" => StopAction::StartEvaluation,
+            "SKIP_EVALUATION" => StopAction::SkipEvaluation,
+            _ => StopAction::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for StopAction {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<StopAction, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StopAction {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for StopAction {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StopAction {
+    fn deserialize<D>(deserializer: D) -> Result<StopAction, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct StopAssessmentRunRequest {
@@ -1437,7 +4029,7 @@ pub struct StopAssessmentRunRequest {
     /// <p>An input option that can be set to either START_EVALUATION or SKIP_EVALUATION. START_EVALUATION (the default value), stops the AWS agent from collecting data and begins the results evaluation and the findings generation process. SKIP_EVALUATION cancels the assessment run immediately, after which no findings are generated.</p>
     #[serde(rename = "stopAction")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stop_action: Option<String>,
+    pub stop_action: Option<StopAction>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -1445,7 +4037,7 @@ pub struct StopAssessmentRunRequest {
 pub struct SubscribeToEventRequest {
     /// <p>The event for which you want to receive SNS notifications.</p>
     #[serde(rename = "event")]
-    pub event: String,
+    pub event: InspectorEvent,
     /// <p>The ARN of the assessment template that is used during the event for which you want to receive SNS notifications.</p>
     #[serde(rename = "resourceArn")]
     pub resource_arn: String,
@@ -1503,12 +4095,22 @@ pub struct TelemetryMetadata {
 pub struct TimestampRange {
     /// <p>The minimum value of the timestamp range.</p>
     #[serde(rename = "beginDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub begin_date: Option<f64>,
+    pub begin_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The maximum value of the timestamp range.</p>
     #[serde(rename = "endDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_date: Option<f64>,
+    pub end_date: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -1516,7 +4118,7 @@ pub struct TimestampRange {
 pub struct UnsubscribeFromEventRequest {
     /// <p>The event for which you want to stop receiving SNS notifications.</p>
     #[serde(rename = "event")]
-    pub event: String,
+    pub event: InspectorEvent,
     /// <p>The ARN of the assessment template that is used during the event for which you want to stop receiving SNS notifications.</p>
     #[serde(rename = "resourceArn")]
     pub resource_arn: String,
@@ -3797,11 +6399,12 @@ pub struct InspectorClient {
 impl InspectorClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> InspectorClient {
         InspectorClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Inspector"),
         }
     }
 
@@ -3833,6 +6436,7 @@ impl Inspector for InspectorClient {
         input: AddAttributesToFindingsRequest,
     ) -> Result<AddAttributesToFindingsResponse, RusotoError<AddAttributesToFindingsError>> {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("AddAttributesToFindings");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.AddAttributesToFindings");
@@ -3861,6 +6465,7 @@ impl Inspector for InspectorClient {
         input: CreateAssessmentTargetRequest,
     ) -> Result<CreateAssessmentTargetResponse, RusotoError<CreateAssessmentTargetError>> {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("CreateAssessmentTarget");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.CreateAssessmentTarget");
@@ -3889,6 +6494,7 @@ impl Inspector for InspectorClient {
         input: CreateAssessmentTemplateRequest,
     ) -> Result<CreateAssessmentTemplateResponse, RusotoError<CreateAssessmentTemplateError>> {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("CreateAssessmentTemplate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.CreateAssessmentTemplate");
@@ -3917,6 +6523,7 @@ impl Inspector for InspectorClient {
         input: CreateExclusionsPreviewRequest,
     ) -> Result<CreateExclusionsPreviewResponse, RusotoError<CreateExclusionsPreviewError>> {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("CreateExclusionsPreview");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.CreateExclusionsPreview");
@@ -3945,6 +6552,7 @@ impl Inspector for InspectorClient {
         input: CreateResourceGroupRequest,
     ) -> Result<CreateResourceGroupResponse, RusotoError<CreateResourceGroupError>> {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("CreateResourceGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.CreateResourceGroup");
@@ -3973,6 +6581,7 @@ impl Inspector for InspectorClient {
         input: DeleteAssessmentRunRequest,
     ) -> Result<(), RusotoError<DeleteAssessmentRunError>> {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("DeleteAssessmentRun");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.DeleteAssessmentRun");
@@ -4000,6 +6609,7 @@ impl Inspector for InspectorClient {
         input: DeleteAssessmentTargetRequest,
     ) -> Result<(), RusotoError<DeleteAssessmentTargetError>> {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("DeleteAssessmentTarget");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.DeleteAssessmentTarget");
@@ -4027,6 +6637,7 @@ impl Inspector for InspectorClient {
         input: DeleteAssessmentTemplateRequest,
     ) -> Result<(), RusotoError<DeleteAssessmentTemplateError>> {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("DeleteAssessmentTemplate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.DeleteAssessmentTemplate");
@@ -4054,6 +6665,7 @@ impl Inspector for InspectorClient {
         input: DescribeAssessmentRunsRequest,
     ) -> Result<DescribeAssessmentRunsResponse, RusotoError<DescribeAssessmentRunsError>> {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("DescribeAssessmentRuns");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.DescribeAssessmentRuns");
@@ -4083,6 +6695,7 @@ impl Inspector for InspectorClient {
     ) -> Result<DescribeAssessmentTargetsResponse, RusotoError<DescribeAssessmentTargetsError>>
     {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("DescribeAssessmentTargets");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.DescribeAssessmentTargets");
@@ -4112,6 +6725,7 @@ impl Inspector for InspectorClient {
     ) -> Result<DescribeAssessmentTemplatesResponse, RusotoError<DescribeAssessmentTemplatesError>>
     {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("DescribeAssessmentTemplates");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4145,6 +6759,7 @@ impl Inspector for InspectorClient {
         RusotoError<DescribeCrossAccountAccessRoleError>,
     > {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("DescribeCrossAccountAccessRole");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4175,6 +6790,7 @@ impl Inspector for InspectorClient {
         input: DescribeExclusionsRequest,
     ) -> Result<DescribeExclusionsResponse, RusotoError<DescribeExclusionsError>> {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("DescribeExclusions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.DescribeExclusions");
@@ -4203,6 +6819,7 @@ impl Inspector for InspectorClient {
         input: DescribeFindingsRequest,
     ) -> Result<DescribeFindingsResponse, RusotoError<DescribeFindingsError>> {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("DescribeFindings");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.DescribeFindings");
@@ -4231,6 +6848,7 @@ impl Inspector for InspectorClient {
         input: DescribeResourceGroupsRequest,
     ) -> Result<DescribeResourceGroupsResponse, RusotoError<DescribeResourceGroupsError>> {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("DescribeResourceGroups");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.DescribeResourceGroups");
@@ -4259,6 +6877,7 @@ impl Inspector for InspectorClient {
         input: DescribeRulesPackagesRequest,
     ) -> Result<DescribeRulesPackagesResponse, RusotoError<DescribeRulesPackagesError>> {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("DescribeRulesPackages");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.DescribeRulesPackages");
@@ -4287,6 +6906,7 @@ impl Inspector for InspectorClient {
         input: GetAssessmentReportRequest,
     ) -> Result<GetAssessmentReportResponse, RusotoError<GetAssessmentReportError>> {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("GetAssessmentReport");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.GetAssessmentReport");
@@ -4315,6 +6935,7 @@ impl Inspector for InspectorClient {
         input: GetExclusionsPreviewRequest,
     ) -> Result<GetExclusionsPreviewResponse, RusotoError<GetExclusionsPreviewError>> {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("GetExclusionsPreview");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.GetExclusionsPreview");
@@ -4343,6 +6964,7 @@ impl Inspector for InspectorClient {
         input: GetTelemetryMetadataRequest,
     ) -> Result<GetTelemetryMetadataResponse, RusotoError<GetTelemetryMetadataError>> {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("GetTelemetryMetadata");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.GetTelemetryMetadata");
@@ -4371,6 +6993,7 @@ impl Inspector for InspectorClient {
         input: ListAssessmentRunAgentsRequest,
     ) -> Result<ListAssessmentRunAgentsResponse, RusotoError<ListAssessmentRunAgentsError>> {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("ListAssessmentRunAgents");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.ListAssessmentRunAgents");
@@ -4399,6 +7022,7 @@ impl Inspector for InspectorClient {
         input: ListAssessmentRunsRequest,
     ) -> Result<ListAssessmentRunsResponse, RusotoError<ListAssessmentRunsError>> {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("ListAssessmentRuns");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.ListAssessmentRuns");
@@ -4427,6 +7051,7 @@ impl Inspector for InspectorClient {
         input: ListAssessmentTargetsRequest,
     ) -> Result<ListAssessmentTargetsResponse, RusotoError<ListAssessmentTargetsError>> {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("ListAssessmentTargets");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.ListAssessmentTargets");
@@ -4455,6 +7080,7 @@ impl Inspector for InspectorClient {
         input: ListAssessmentTemplatesRequest,
     ) -> Result<ListAssessmentTemplatesResponse, RusotoError<ListAssessmentTemplatesError>> {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("ListAssessmentTemplates");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.ListAssessmentTemplates");
@@ -4483,6 +7109,7 @@ impl Inspector for InspectorClient {
         input: ListEventSubscriptionsRequest,
     ) -> Result<ListEventSubscriptionsResponse, RusotoError<ListEventSubscriptionsError>> {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("ListEventSubscriptions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.ListEventSubscriptions");
@@ -4511,6 +7138,7 @@ impl Inspector for InspectorClient {
         input: ListExclusionsRequest,
     ) -> Result<ListExclusionsResponse, RusotoError<ListExclusionsError>> {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("ListExclusions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.ListExclusions");
@@ -4538,6 +7166,7 @@ impl Inspector for InspectorClient {
         input: ListFindingsRequest,
     ) -> Result<ListFindingsResponse, RusotoError<ListFindingsError>> {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("ListFindings");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.ListFindings");
@@ -4565,6 +7194,7 @@ impl Inspector for InspectorClient {
         input: ListRulesPackagesRequest,
     ) -> Result<ListRulesPackagesResponse, RusotoError<ListRulesPackagesError>> {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("ListRulesPackages");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.ListRulesPackages");
@@ -4593,6 +7223,7 @@ impl Inspector for InspectorClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.ListTagsForResource");
@@ -4621,6 +7252,7 @@ impl Inspector for InspectorClient {
         input: PreviewAgentsRequest,
     ) -> Result<PreviewAgentsResponse, RusotoError<PreviewAgentsError>> {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("PreviewAgents");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.PreviewAgents");
@@ -4648,6 +7280,7 @@ impl Inspector for InspectorClient {
         input: RegisterCrossAccountAccessRoleRequest,
     ) -> Result<(), RusotoError<RegisterCrossAccountAccessRoleError>> {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("RegisterCrossAccountAccessRole");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4679,6 +7312,7 @@ impl Inspector for InspectorClient {
     ) -> Result<RemoveAttributesFromFindingsResponse, RusotoError<RemoveAttributesFromFindingsError>>
     {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("RemoveAttributesFromFindings");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4710,6 +7344,7 @@ impl Inspector for InspectorClient {
         input: SetTagsForResourceRequest,
     ) -> Result<(), RusotoError<SetTagsForResourceError>> {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("SetTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.SetTagsForResource");
@@ -4737,6 +7372,7 @@ impl Inspector for InspectorClient {
         input: StartAssessmentRunRequest,
     ) -> Result<StartAssessmentRunResponse, RusotoError<StartAssessmentRunError>> {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("StartAssessmentRun");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.StartAssessmentRun");
@@ -4765,6 +7401,7 @@ impl Inspector for InspectorClient {
         input: StopAssessmentRunRequest,
     ) -> Result<(), RusotoError<StopAssessmentRunError>> {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("StopAssessmentRun");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.StopAssessmentRun");
@@ -4792,6 +7429,7 @@ impl Inspector for InspectorClient {
         input: SubscribeToEventRequest,
     ) -> Result<(), RusotoError<SubscribeToEventError>> {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("SubscribeToEvent");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.SubscribeToEvent");
@@ -4819,6 +7457,7 @@ impl Inspector for InspectorClient {
         input: UnsubscribeFromEventRequest,
     ) -> Result<(), RusotoError<UnsubscribeFromEventError>> {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("UnsubscribeFromEvent");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.UnsubscribeFromEvent");
@@ -4846,6 +7485,7 @@ impl Inspector for InspectorClient {
         input: UpdateAssessmentTargetRequest,
     ) -> Result<(), RusotoError<UpdateAssessmentTargetError>> {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("UpdateAssessmentTarget");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.UpdateAssessmentTarget");
@@ -4867,3 +7507,298 @@ impl Inspector for InspectorClient {
         }
     }
 }
+impl InspectorClient {
+    /// Auto-paginating version of `list_assessment_run_agents`, streaming every page of results.
+    pub fn list_assessment_run_agents_pages(
+        &self,
+        input: ListAssessmentRunAgentsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListAssessmentRunAgentsResponse,
+        ListAssessmentRunAgentsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: InspectorClient, input: ListAssessmentRunAgentsRequest| async move {
+                client.list_assessment_run_agents(input).await
+            },
+            |input: &ListAssessmentRunAgentsRequest, output: &ListAssessmentRunAgentsResponse| {
+                Some(ListAssessmentRunAgentsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_assessment_run_agents`, streaming the `assessmentRunAgents` of every page of results.
+    pub fn list_assessment_run_agents_items(
+        &self,
+        input: ListAssessmentRunAgentsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<AssessmentRunAgent, ListAssessmentRunAgentsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_assessment_run_agents_pages(input),
+            |output: ListAssessmentRunAgentsResponse| output.assessment_run_agents,
+        )
+    }
+
+    /// Auto-paginating version of `list_assessment_runs`, streaming every page of results.
+    pub fn list_assessment_runs_pages(
+        &self,
+        input: ListAssessmentRunsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListAssessmentRunsResponse, ListAssessmentRunsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: InspectorClient, input: ListAssessmentRunsRequest| async move {
+                client.list_assessment_runs(input).await
+            },
+            |input: &ListAssessmentRunsRequest, output: &ListAssessmentRunsResponse| {
+                Some(ListAssessmentRunsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_assessment_runs`, streaming the `assessmentRunArns` of every page of results.
+    pub fn list_assessment_runs_items(
+        &self,
+        input: ListAssessmentRunsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListAssessmentRunsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_assessment_runs_pages(input),
+            |output: ListAssessmentRunsResponse| output.assessment_run_arns,
+        )
+    }
+
+    /// Auto-paginating version of `list_assessment_targets`, streaming every page of results.
+    pub fn list_assessment_targets_pages(
+        &self,
+        input: ListAssessmentTargetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListAssessmentTargetsResponse,
+        ListAssessmentTargetsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: InspectorClient, input: ListAssessmentTargetsRequest| async move {
+                client.list_assessment_targets(input).await
+            },
+            |input: &ListAssessmentTargetsRequest, output: &ListAssessmentTargetsResponse| {
+                Some(ListAssessmentTargetsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_assessment_targets`, streaming the `assessmentTargetArns` of every page of results.
+    pub fn list_assessment_targets_items(
+        &self,
+        input: ListAssessmentTargetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListAssessmentTargetsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_assessment_targets_pages(input),
+            |output: ListAssessmentTargetsResponse| output.assessment_target_arns,
+        )
+    }
+
+    /// Auto-paginating version of `list_assessment_templates`, streaming every page of results.
+    pub fn list_assessment_templates_pages(
+        &self,
+        input: ListAssessmentTemplatesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListAssessmentTemplatesResponse,
+        ListAssessmentTemplatesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: InspectorClient, input: ListAssessmentTemplatesRequest| async move {
+                client.list_assessment_templates(input).await
+            },
+            |input: &ListAssessmentTemplatesRequest, output: &ListAssessmentTemplatesResponse| {
+                Some(ListAssessmentTemplatesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_assessment_templates`, streaming the `assessmentTemplateArns` of every page of results.
+    pub fn list_assessment_templates_items(
+        &self,
+        input: ListAssessmentTemplatesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListAssessmentTemplatesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_assessment_templates_pages(input),
+            |output: ListAssessmentTemplatesResponse| output.assessment_template_arns,
+        )
+    }
+
+    /// Auto-paginating version of `list_event_subscriptions`, streaming every page of results.
+    pub fn list_event_subscriptions_pages(
+        &self,
+        input: ListEventSubscriptionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListEventSubscriptionsResponse,
+        ListEventSubscriptionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: InspectorClient, input: ListEventSubscriptionsRequest| async move {
+                client.list_event_subscriptions(input).await
+            },
+            |input: &ListEventSubscriptionsRequest, output: &ListEventSubscriptionsResponse| {
+                Some(ListEventSubscriptionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_event_subscriptions`, streaming the `subscriptions` of every page of results.
+    pub fn list_event_subscriptions_items(
+        &self,
+        input: ListEventSubscriptionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Subscription, ListEventSubscriptionsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_event_subscriptions_pages(input),
+            |output: ListEventSubscriptionsResponse| output.subscriptions,
+        )
+    }
+
+    /// Auto-paginating version of `list_exclusions`, streaming every page of results.
+    pub fn list_exclusions_pages(
+        &self,
+        input: ListExclusionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListExclusionsResponse, ListExclusionsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: InspectorClient, input: ListExclusionsRequest| async move {
+                client.list_exclusions(input).await
+            },
+            |input: &ListExclusionsRequest, output: &ListExclusionsResponse| {
+                Some(ListExclusionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_exclusions`, streaming the `exclusionArns` of every page of results.
+    pub fn list_exclusions_items(
+        &self,
+        input: ListExclusionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListExclusionsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_exclusions_pages(input),
+            |output: ListExclusionsResponse| output.exclusion_arns,
+        )
+    }
+
+    /// Auto-paginating version of `list_findings`, streaming every page of results.
+    pub fn list_findings_pages(
+        &self,
+        input: ListFindingsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListFindingsResponse, ListFindingsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: InspectorClient, input: ListFindingsRequest| async move {
+                client.list_findings(input).await
+            },
+            |input: &ListFindingsRequest, output: &ListFindingsResponse| {
+                Some(ListFindingsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_findings`, streaming the `findingArns` of every page of results.
+    pub fn list_findings_items(
+        &self,
+        input: ListFindingsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListFindingsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_findings_pages(input),
+            |output: ListFindingsResponse| output.finding_arns,
+        )
+    }
+
+    /// Auto-paginating version of `list_rules_packages`, streaming every page of results.
+    pub fn list_rules_packages_pages(
+        &self,
+        input: ListRulesPackagesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListRulesPackagesResponse, ListRulesPackagesError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: InspectorClient, input: ListRulesPackagesRequest| async move {
+                client.list_rules_packages(input).await
+            },
+            |input: &ListRulesPackagesRequest, output: &ListRulesPackagesResponse| {
+                Some(ListRulesPackagesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_rules_packages`, streaming the `rulesPackageArns` of every page of results.
+    pub fn list_rules_packages_items(
+        &self,
+        input: ListRulesPackagesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListRulesPackagesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_rules_packages_pages(input),
+            |output: ListRulesPackagesResponse| output.rules_package_arns,
+        )
+    }
+
+    /// Auto-paginating version of `preview_agents`, streaming every page of results.
+    pub fn preview_agents_pages(
+        &self,
+        input: PreviewAgentsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<PreviewAgentsResponse, PreviewAgentsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: InspectorClient, input: PreviewAgentsRequest| async move {
+                client.preview_agents(input).await
+            },
+            |input: &PreviewAgentsRequest, output: &PreviewAgentsResponse| {
+                Some(PreviewAgentsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `preview_agents`, streaming the `agentPreviews` of every page of results.
+    pub fn preview_agents_items(
+        &self,
+        input: PreviewAgentsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<AgentPreview, PreviewAgentsError> {
+        rusoto_core::pagination::paginate_items(
+            self.preview_agents_pages(input),
+            |output: PreviewAgentsResponse| output.agent_previews,
+        )
+    }
+}
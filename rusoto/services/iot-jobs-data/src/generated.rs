@@ -109,7 +109,7 @@ pub struct JobExecution {
     /// <p>The status of the job execution. Can be one of: "QUEUED", "IN_PROGRESS", "FAILED", "SUCCESS", "CANCELED", "REJECTED", or "REMOVED".</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<JobExecutionStatus>,
     /// <p>A collection of name/value pairs that describe the status of the job execution.</p>
     #[serde(rename = "statusDetails")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -131,7 +131,7 @@ pub struct JobExecutionState {
     /// <p>The status of the job execution. Can be one of: "QUEUED", "IN_PROGRESS", "FAILED", "SUCCESS", "CANCELED", "REJECTED", or "REMOVED".</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<JobExecutionStatus>,
     /// <p>A collection of name/value pairs that describe the status of the job execution.</p>
     #[serde(rename = "statusDetails")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -142,6 +142,129 @@ pub struct JobExecutionState {
     pub version_number: Option<i64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum JobExecutionStatus {
+    /// `QUEUED`
+    Queued,
+    /// `IN_PROGRESS`
+    InProgress,
+    /// `SUCCEEDED`
+    Succeeded,
+    /// `FAILED`
+    Failed,
+    /// `TIMED_OUT`
+    TimedOut,
+    /// `REJECTED`
+    Rejected,
+    /// `REMOVED`
+    Removed,
+    /// `CANCELED`
+    Canceled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl JobExecutionStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            JobExecutionStatus::Queued => "QUEUED",
+            JobExecutionStatus::InProgress => "IN_PROGRESS",
+            JobExecutionStatus::Succeeded => "SUCCEEDED",
+            JobExecutionStatus::Failed => "FAILED",
+            JobExecutionStatus::TimedOut => "TIMED_OUT",
+            JobExecutionStatus::Rejected => "REJECTED",
+            JobExecutionStatus::Removed => "REMOVED",
+            JobExecutionStatus::Canceled => "CANCELED",
+            JobExecutionStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for JobExecutionStatus {
+    fn default() -> JobExecutionStatus {
+        JobExecutionStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for JobExecutionStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for JobExecutionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for JobExecutionStatus {
+    fn from(value: &'a str) -> JobExecutionStatus {
+        match value {
+            "QUEUED" => JobExecutionStatus::Queued,
+            "IN_PROGRESS" => JobExecutionStatus::InProgress,
+            "SUCCEEDED" => JobExecutionStatus::Succeeded,
+            "FAILED" => JobExecutionStatus::Failed,
+            "TIMED_OUT" => JobExecutionStatus::TimedOut,
+            "REJECTED" => JobExecutionStatus::Rejected,
+            "REMOVED" => JobExecutionStatus::Removed,
+            "CANCELED" => JobExecutionStatus::Canceled,
+            _ => JobExecutionStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for JobExecutionStatus {
+    fn from(value: String) -> JobExecutionStatus {
+        match value.as_str() {
+            "QUEUED" => JobExecutionStatus::Queued,
+            "IN_PROGRESS" => JobExecutionStatus::InProgress,
+            "SUCCEEDED" => JobExecutionStatus::Succeeded,
+            "FAILED" => JobExecutionStatus::Failed,
+            "TIMED_OUT" => JobExecutionStatus::TimedOut,
+            "REJECTED" => JobExecutionStatus::Rejected,
+            "REMOVED" => JobExecutionStatus::Removed,
+            "CANCELED" => JobExecutionStatus::Canceled,
+            _ => JobExecutionStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for JobExecutionStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<JobExecutionStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for JobExecutionStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for JobExecutionStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for JobExecutionStatus {
+    fn deserialize<D>(deserializer: D) -> Result<JobExecutionStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Contains a subset of information about a job execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -221,7 +344,7 @@ pub struct UpdateJobExecutionRequest {
     pub job_id: String,
     /// <p>The new status for the job execution (IN_PROGRESS, FAILED, SUCCESS, or REJECTED). This must be specified on every update.</p>
     #[serde(rename = "status")]
-    pub status: String,
+    pub status: JobExecutionStatus,
     /// <p> Optional. A collection of name/value pairs that describe the status of the job execution. If not specified, the statusDetails are unchanged.</p>
     #[serde(rename = "statusDetails")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -553,11 +676,12 @@ pub struct IotJobsDataClient {
 impl IotJobsDataClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> IotJobsDataClient {
         IotJobsDataClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "IoT Jobs Data Plane"),
         }
     }
 
@@ -595,6 +719,7 @@ impl IotJobsData for IotJobsDataClient {
         );
 
         let mut request = SignedRequest::new("GET", "iot-jobs-data", &self.region, &request_uri);
+        request.set_operation("DescribeJobExecution");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("data.jobs.iot".to_string());
@@ -633,6 +758,7 @@ impl IotJobsData for IotJobsDataClient {
         let request_uri = format!("/things/{thing_name}/jobs", thing_name = input.thing_name);
 
         let mut request = SignedRequest::new("GET", "iot-jobs-data", &self.region, &request_uri);
+        request.set_operation("GetPendingJobExecutions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("data.jobs.iot".to_string());
@@ -666,6 +792,7 @@ impl IotJobsData for IotJobsDataClient {
         );
 
         let mut request = SignedRequest::new("PUT", "iot-jobs-data", &self.region, &request_uri);
+        request.set_operation("StartNextPendingJobExecution");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("data.jobs.iot".to_string());
@@ -701,6 +828,7 @@ impl IotJobsData for IotJobsDataClient {
         );
 
         let mut request = SignedRequest::new("POST", "iot-jobs-data", &self.region, &request_uri);
+        request.set_operation("UpdateJobExecution");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("data.jobs.iot".to_string());
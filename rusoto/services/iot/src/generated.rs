@@ -25,6 +25,94 @@ use rusoto_core::signature::SignedRequest;
 #[allow(unused_imports)]
 use serde::{Deserialize, Serialize};
 use serde_json;
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AbortAction {
+    /// `CANCEL`
+    Cancel,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AbortAction {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AbortAction::Cancel => "CANCEL",
+            AbortAction::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AbortAction {
+    fn default() -> AbortAction {
+        AbortAction::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AbortAction {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AbortAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AbortAction {
+    fn from(value: &'a str) -> AbortAction {
+        match value {
+            "CANCEL" => AbortAction::Cancel,
+            _ => AbortAction::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AbortAction {
+    fn from(value: String) -> AbortAction {
+        match value.as_str() {
+            "CANCEL" => AbortAction::Cancel,
+            _ => AbortAction::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AbortAction {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AbortAction, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AbortAction {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AbortAction {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AbortAction {
+    fn deserialize<D>(deserializer: D) -> Result<AbortAction, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Details of abort criteria to abort the job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AbortConfig {
@@ -38,10 +126,10 @@ pub struct AbortConfig {
 pub struct AbortCriteria {
     /// <p>The type of abort action to initiate a job abort.</p>
     #[serde(rename = "action")]
-    pub action: String,
+    pub action: AbortAction,
     /// <p>The type of job execution failure to define a rule to initiate a job abort.</p>
     #[serde(rename = "failureType")]
-    pub failure_type: String,
+    pub failure_type: JobExecutionFailureType,
     /// <p>Minimum number of executed things before evaluating an abort rule.</p>
     #[serde(rename = "minNumberOfExecutedThings")]
     pub min_number_of_executed_things: i64,
@@ -140,6 +228,109 @@ pub struct Action {
     pub step_functions: Option<StepFunctionsAction>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ActionType {
+    /// `PUBLISH`
+    Publish,
+    /// `SUBSCRIBE`
+    Subscribe,
+    /// `RECEIVE`
+    Receive,
+    /// `CONNECT`
+    Connect,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ActionType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ActionType::Publish => "PUBLISH",
+            ActionType::Subscribe => "SUBSCRIBE",
+            ActionType::Receive => "RECEIVE",
+            ActionType::Connect => "CONNECT",
+            ActionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ActionType {
+    fn default() -> ActionType {
+        ActionType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ActionType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ActionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ActionType {
+    fn from(value: &'a str) -> ActionType {
+        match value {
+            "PUBLISH" => ActionType::Publish,
+            "SUBSCRIBE" => ActionType::Subscribe,
+            "RECEIVE" => ActionType::Receive,
+            "CONNECT" => ActionType::Connect,
+            _ => ActionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ActionType {
+    fn from(value: String) -> ActionType {
+        match value.as_str() {
+            "PUBLISH" => ActionType::Publish,
+            "SUBSCRIBE" => ActionType::Subscribe,
+            "RECEIVE" => ActionType::Receive,
+            "CONNECT" => ActionType::Connect,
+            _ => ActionType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ActionType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ActionType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ActionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ActionType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ActionType {
+    fn deserialize<D>(deserializer: D) -> Result<ActionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about an active Device Defender security profile behavior violation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -150,8 +341,13 @@ pub struct ActiveViolation {
     pub behavior: Option<Behavior>,
     /// <p>The time the most recent violation occurred.</p>
     #[serde(rename = "lastViolationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_violation_time: Option<f64>,
+    pub last_violation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The value of the metric (the measurement) which caused the most recent violation.</p>
     #[serde(rename = "lastViolationValue")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -170,8 +366,13 @@ pub struct ActiveViolation {
     pub violation_id: Option<String>,
     /// <p>The time the violation started.</p>
     #[serde(rename = "violationStartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub violation_start_time: Option<f64>,
+    pub violation_start_time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -251,6 +452,95 @@ pub struct AlertTarget {
     pub role_arn: String,
 }
 
+/// <p>The type of alert target: one of "SNS".</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AlertTargetType {
+    /// `SNS`
+    Sns,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AlertTargetType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AlertTargetType::Sns => "SNS",
+            AlertTargetType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AlertTargetType {
+    fn default() -> AlertTargetType {
+        AlertTargetType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AlertTargetType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AlertTargetType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AlertTargetType {
+    fn from(value: &'a str) -> AlertTargetType {
+        match value {
+            "SNS" => AlertTargetType::Sns,
+            _ => AlertTargetType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AlertTargetType {
+    fn from(value: String) -> AlertTargetType {
+        match value.as_str() {
+            "SNS" => AlertTargetType::Sns,
+            _ => AlertTargetType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AlertTargetType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AlertTargetType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AlertTargetType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AlertTargetType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AlertTargetType {
+    fn deserialize<D>(deserializer: D) -> Result<AlertTargetType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Contains information that allowed the authorization.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -429,7 +719,7 @@ pub struct AuditCheckDetails {
     /// <p>The completion status of this check. One of "IN_PROGRESS", "WAITING_FOR_DATA_COLLECTION", "CANCELED", "COMPLETED_COMPLIANT", "COMPLETED_NON_COMPLIANT", or "FAILED".</p>
     #[serde(rename = "checkRunStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub check_run_status: Option<String>,
+    pub check_run_status: Option<AuditCheckRunStatus>,
     /// <p>The code of any error encountered when this check is performed during this audit. One of "INSUFFICIENT_PERMISSIONS" or "AUDIT_CHECK_DISABLED".</p>
     #[serde(rename = "errorCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -448,6 +738,119 @@ pub struct AuditCheckDetails {
     pub total_resources_count: Option<i64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AuditCheckRunStatus {
+    /// `IN_PROGRESS`
+    InProgress,
+    /// `WAITING_FOR_DATA_COLLECTION`
+    WaitingForDataCollection,
+    /// `CANCELED`
+    Canceled,
+    /// `COMPLETED_COMPLIANT`
+    CompletedCompliant,
+    /// `COMPLETED_NON_COMPLIANT`
+    CompletedNonCompliant,
+    /// `FAILED`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AuditCheckRunStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AuditCheckRunStatus::InProgress => "IN_PROGRESS",
+            AuditCheckRunStatus::WaitingForDataCollection => "WAITING_FOR_DATA_COLLECTION",
+            AuditCheckRunStatus::Canceled => "CANCELED",
+            AuditCheckRunStatus::CompletedCompliant => "COMPLETED_COMPLIANT",
+            AuditCheckRunStatus::CompletedNonCompliant => "COMPLETED_NON_COMPLIANT",
+            AuditCheckRunStatus::Failed => "FAILED",
+            AuditCheckRunStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AuditCheckRunStatus {
+    fn default() -> AuditCheckRunStatus {
+        AuditCheckRunStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AuditCheckRunStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AuditCheckRunStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AuditCheckRunStatus {
+    fn from(value: &'a str) -> AuditCheckRunStatus {
+        match value {
+            "IN_PROGRESS" => AuditCheckRunStatus::InProgress,
+            "WAITING_FOR_DATA_COLLECTION" => AuditCheckRunStatus::WaitingForDataCollection,
+            "CANCELED" => AuditCheckRunStatus::Canceled,
+            "COMPLETED_COMPLIANT" => AuditCheckRunStatus::CompletedCompliant,
+            "COMPLETED_NON_COMPLIANT" => AuditCheckRunStatus::CompletedNonCompliant,
+            "FAILED" => AuditCheckRunStatus::Failed,
+            _ => AuditCheckRunStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AuditCheckRunStatus {
+    fn from(value: String) -> AuditCheckRunStatus {
+        match value.as_str() {
+            "IN_PROGRESS" => AuditCheckRunStatus::InProgress,
+            "WAITING_FOR_DATA_COLLECTION" => AuditCheckRunStatus::WaitingForDataCollection,
+            "CANCELED" => AuditCheckRunStatus::Canceled,
+            "COMPLETED_COMPLIANT" => AuditCheckRunStatus::CompletedCompliant,
+            "COMPLETED_NON_COMPLIANT" => AuditCheckRunStatus::CompletedNonCompliant,
+            "FAILED" => AuditCheckRunStatus::Failed,
+            _ => AuditCheckRunStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AuditCheckRunStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AuditCheckRunStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AuditCheckRunStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AuditCheckRunStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AuditCheckRunStatus {
+    fn deserialize<D>(deserializer: D) -> Result<AuditCheckRunStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The findings (results) of the audit.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -462,8 +865,13 @@ pub struct AuditFinding {
     pub finding_id: Option<String>,
     /// <p>The time the result (finding) was discovered.</p>
     #[serde(rename = "findingTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub finding_time: Option<f64>,
+    pub finding_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The resource that was found to be noncompliant with the audit check.</p>
     #[serde(rename = "nonCompliantResource")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -483,17 +891,228 @@ pub struct AuditFinding {
     /// <p>The severity of the result (finding).</p>
     #[serde(rename = "severity")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub severity: Option<String>,
+    pub severity: Option<AuditFindingSeverity>,
     /// <p>The ID of the audit that generated this result (finding).</p>
     #[serde(rename = "taskId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub task_id: Option<String>,
     /// <p>The time the audit started.</p>
     #[serde(rename = "taskStartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub task_start_time: Option<f64>,
+    pub task_start_time: Option<::chrono::DateTime<::chrono::Utc>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AuditFindingSeverity {
+    /// `CRITICAL`
+    Critical,
+    /// `HIGH`
+    High,
+    /// `MEDIUM`
+    Medium,
+    /// `LOW`
+    Low,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AuditFindingSeverity {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AuditFindingSeverity::Critical => "CRITICAL",
+            AuditFindingSeverity::High => "HIGH",
+            AuditFindingSeverity::Medium => "MEDIUM",
+            AuditFindingSeverity::Low => "LOW",
+            AuditFindingSeverity::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AuditFindingSeverity {
+    fn default() -> AuditFindingSeverity {
+        AuditFindingSeverity::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AuditFindingSeverity {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AuditFindingSeverity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AuditFindingSeverity {
+    fn from(value: &'a str) -> AuditFindingSeverity {
+        match value {
+            "CRITICAL" => AuditFindingSeverity::Critical,
+            "HIGH" => AuditFindingSeverity::High,
+            "MEDIUM" => AuditFindingSeverity::Medium,
+            "LOW" => AuditFindingSeverity::Low,
+            _ => AuditFindingSeverity::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AuditFindingSeverity {
+    fn from(value: String) -> AuditFindingSeverity {
+        match value.as_str() {
+            "CRITICAL" => AuditFindingSeverity::Critical,
+            "HIGH" => AuditFindingSeverity::High,
+            "MEDIUM" => AuditFindingSeverity::Medium,
+            "LOW" => AuditFindingSeverity::Low,
+            _ => AuditFindingSeverity::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AuditFindingSeverity {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AuditFindingSeverity, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AuditFindingSeverity {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AuditFindingSeverity {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AuditFindingSeverity {
+    fn deserialize<D>(deserializer: D) -> Result<AuditFindingSeverity, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AuditFrequency {
+    /// `DAILY`
+    Daily,
+    /// `WEEKLY`
+    Weekly,
+    /// `BIWEEKLY`
+    Biweekly,
+    /// `MONTHLY`
+    Monthly,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AuditFrequency {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AuditFrequency::Daily => "DAILY",
+            AuditFrequency::Weekly => "WEEKLY",
+            AuditFrequency::Biweekly => "BIWEEKLY",
+            AuditFrequency::Monthly => "MONTHLY",
+            AuditFrequency::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AuditFrequency {
+    fn default() -> AuditFrequency {
+        AuditFrequency::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AuditFrequency {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AuditFrequency {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
+impl<'a> From<&'a str> for AuditFrequency {
+    fn from(value: &'a str) -> AuditFrequency {
+        match value {
+            "DAILY" => AuditFrequency::Daily,
+            "WEEKLY" => AuditFrequency::Weekly,
+            "BIWEEKLY" => AuditFrequency::Biweekly,
+            "MONTHLY" => AuditFrequency::Monthly,
+            _ => AuditFrequency::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AuditFrequency {
+    fn from(value: String) -> AuditFrequency {
+        match value.as_str() {
+            "DAILY" => AuditFrequency::Daily,
+            "WEEKLY" => AuditFrequency::Weekly,
+            "BIWEEKLY" => AuditFrequency::Biweekly,
+            "MONTHLY" => AuditFrequency::Monthly,
+            _ => AuditFrequency::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AuditFrequency {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AuditFrequency, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AuditFrequency {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AuditFrequency {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AuditFrequency {
+    fn deserialize<D>(deserializer: D) -> Result<AuditFrequency, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Returned by ListAuditMitigationActionsTask, this object contains information that describes a mitigation action that has been started.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -508,8 +1127,13 @@ pub struct AuditMitigationActionExecutionMetadata {
     pub action_name: Option<String>,
     /// <p>The date and time when the task was completed or canceled. Blank if the task is still running.</p>
     #[serde(rename = "endTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>If an error occurred, the code that indicates which type of error occurred.</p>
     #[serde(rename = "errorCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -524,26 +1148,149 @@ pub struct AuditMitigationActionExecutionMetadata {
     pub message: Option<String>,
     /// <p>The date and time when the task was started.</p>
     #[serde(rename = "startTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The current status of the task being executed.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<AuditMitigationActionsExecutionStatus>,
     /// <p>The unique identifier for the task that applies the mitigation action.</p>
     #[serde(rename = "taskId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub task_id: Option<String>,
 }
 
-/// <p>Information about an audit mitigation actions task that is returned by <code>ListAuditMitigationActionsTasks</code>.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AuditMitigationActionsExecutionStatus {
+    /// `IN_PROGRESS`
+    InProgress,
+    /// `COMPLETED`
+    Completed,
+    /// `FAILED`
+    Failed,
+    /// `CANCELED`
+    Canceled,
+    /// `SKIPPED`
+    Skipped,
+    /// `PENDING`
+    Pending,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AuditMitigationActionsExecutionStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AuditMitigationActionsExecutionStatus::InProgress => "IN_PROGRESS",
+            AuditMitigationActionsExecutionStatus::Completed => "COMPLETED",
+            AuditMitigationActionsExecutionStatus::Failed => "FAILED",
+            AuditMitigationActionsExecutionStatus::Canceled => "CANCELED",
+            AuditMitigationActionsExecutionStatus::Skipped => "SKIPPED",
+            AuditMitigationActionsExecutionStatus::Pending => "PENDING",
+            AuditMitigationActionsExecutionStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AuditMitigationActionsExecutionStatus {
+    fn default() -> AuditMitigationActionsExecutionStatus {
+        AuditMitigationActionsExecutionStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AuditMitigationActionsExecutionStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AuditMitigationActionsExecutionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AuditMitigationActionsExecutionStatus {
+    fn from(value: &'a str) -> AuditMitigationActionsExecutionStatus {
+        match value {
+            "IN_PROGRESS" => AuditMitigationActionsExecutionStatus::InProgress,
+            "COMPLETED" => AuditMitigationActionsExecutionStatus::Completed,
+            "FAILED" => AuditMitigationActionsExecutionStatus::Failed,
+            "CANCELED" => AuditMitigationActionsExecutionStatus::Canceled,
+            "SKIPPED" => AuditMitigationActionsExecutionStatus::Skipped,
+            "PENDING" => AuditMitigationActionsExecutionStatus::Pending,
+            _ => AuditMitigationActionsExecutionStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AuditMitigationActionsExecutionStatus {
+    fn from(value: String) -> AuditMitigationActionsExecutionStatus {
+        match value.as_str() {
+            "IN_PROGRESS" => AuditMitigationActionsExecutionStatus::InProgress,
+            "COMPLETED" => AuditMitigationActionsExecutionStatus::Completed,
+            "FAILED" => AuditMitigationActionsExecutionStatus::Failed,
+            "CANCELED" => AuditMitigationActionsExecutionStatus::Canceled,
+            "SKIPPED" => AuditMitigationActionsExecutionStatus::Skipped,
+            "PENDING" => AuditMitigationActionsExecutionStatus::Pending,
+            _ => AuditMitigationActionsExecutionStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AuditMitigationActionsExecutionStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AuditMitigationActionsExecutionStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AuditMitigationActionsExecutionStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AuditMitigationActionsExecutionStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AuditMitigationActionsExecutionStatus {
+    fn deserialize<D>(deserializer: D) -> Result<AuditMitigationActionsExecutionStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Information about an audit mitigation actions task that is returned by <code>ListAuditMitigationActionsTasks</code>.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AuditMitigationActionsTaskMetadata {
     /// <p>The time at which the audit mitigation actions task was started.</p>
     #[serde(rename = "startTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The unique identifier for the task.</p>
     #[serde(rename = "taskId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -551,9 +1298,112 @@ pub struct AuditMitigationActionsTaskMetadata {
     /// <p>The current state of the audit mitigation actions task.</p>
     #[serde(rename = "taskStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub task_status: Option<String>,
+    pub task_status: Option<AuditMitigationActionsTaskStatus>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AuditMitigationActionsTaskStatus {
+    /// `IN_PROGRESS`
+    InProgress,
+    /// `COMPLETED`
+    Completed,
+    /// `FAILED`
+    Failed,
+    /// `CANCELED`
+    Canceled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AuditMitigationActionsTaskStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AuditMitigationActionsTaskStatus::InProgress => "IN_PROGRESS",
+            AuditMitigationActionsTaskStatus::Completed => "COMPLETED",
+            AuditMitigationActionsTaskStatus::Failed => "FAILED",
+            AuditMitigationActionsTaskStatus::Canceled => "CANCELED",
+            AuditMitigationActionsTaskStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AuditMitigationActionsTaskStatus {
+    fn default() -> AuditMitigationActionsTaskStatus {
+        AuditMitigationActionsTaskStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AuditMitigationActionsTaskStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AuditMitigationActionsTaskStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AuditMitigationActionsTaskStatus {
+    fn from(value: &'a str) -> AuditMitigationActionsTaskStatus {
+        match value {
+            "IN_PROGRESS" => AuditMitigationActionsTaskStatus::InProgress,
+            "COMPLETED" => AuditMitigationActionsTaskStatus::Completed,
+            "FAILED" => AuditMitigationActionsTaskStatus::Failed,
+            "CANCELED" => AuditMitigationActionsTaskStatus::Canceled,
+            _ => AuditMitigationActionsTaskStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AuditMitigationActionsTaskStatus {
+    fn from(value: String) -> AuditMitigationActionsTaskStatus {
+        match value.as_str() {
+            "IN_PROGRESS" => AuditMitigationActionsTaskStatus::InProgress,
+            "COMPLETED" => AuditMitigationActionsTaskStatus::Completed,
+            "FAILED" => AuditMitigationActionsTaskStatus::Failed,
+            "CANCELED" => AuditMitigationActionsTaskStatus::Canceled,
+            _ => AuditMitigationActionsTaskStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AuditMitigationActionsTaskStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AuditMitigationActionsTaskStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AuditMitigationActionsTaskStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AuditMitigationActionsTaskStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for AuditMitigationActionsTaskStatus {
+    fn deserialize<D>(deserializer: D) -> Result<AuditMitigationActionsTaskStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Used in MitigationActionParams, this information identifies the target findings to which the mitigation actions are applied. Only one entry appears.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AuditMitigationActionsTaskTarget {
@@ -588,6 +1438,94 @@ pub struct AuditNotificationTarget {
     pub target_arn: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AuditNotificationType {
+    /// `SNS`
+    Sns,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AuditNotificationType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AuditNotificationType::Sns => "SNS",
+            AuditNotificationType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AuditNotificationType {
+    fn default() -> AuditNotificationType {
+        AuditNotificationType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AuditNotificationType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AuditNotificationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AuditNotificationType {
+    fn from(value: &'a str) -> AuditNotificationType {
+        match value {
+            "SNS" => AuditNotificationType::Sns,
+            _ => AuditNotificationType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AuditNotificationType {
+    fn from(value: String) -> AuditNotificationType {
+        match value.as_str() {
+            "SNS" => AuditNotificationType::Sns,
+            _ => AuditNotificationType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AuditNotificationType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AuditNotificationType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AuditNotificationType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AuditNotificationType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AuditNotificationType {
+    fn deserialize<D>(deserializer: D) -> Result<AuditNotificationType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The audits that were performed.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -599,20 +1537,314 @@ pub struct AuditTaskMetadata {
     /// <p>The status of this audit. One of "IN_PROGRESS", "COMPLETED", "FAILED", or "CANCELED".</p>
     #[serde(rename = "taskStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub task_status: Option<String>,
+    pub task_status: Option<AuditTaskStatus>,
     /// <p>The type of this audit. One of "ON_DEMAND_AUDIT_TASK" or "SCHEDULED_AUDIT_TASK".</p>
     #[serde(rename = "taskType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub task_type: Option<String>,
+    pub task_type: Option<AuditTaskType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AuditTaskStatus {
+    /// `IN_PROGRESS`
+    InProgress,
+    /// `COMPLETED`
+    Completed,
+    /// `FAILED`
+    Failed,
+    /// `CANCELED`
+    Canceled,
+    /// A value not known to this version of the crate
+    Unknown(String),
 }
 
+impl AuditTaskStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AuditTaskStatus::InProgress => "IN_PROGRESS",
+            AuditTaskStatus::Completed => "COMPLETED",
+            AuditTaskStatus::Failed => "FAILED",
+            AuditTaskStatus::Canceled => "CANCELED",
+            AuditTaskStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AuditTaskStatus {
+    fn default() -> AuditTaskStatus {
+        AuditTaskStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AuditTaskStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AuditTaskStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AuditTaskStatus {
+    fn from(value: &'a str) -> AuditTaskStatus {
+        match value {
+            "IN_PROGRESS" => AuditTaskStatus::InProgress,
+            "COMPLETED" => AuditTaskStatus::Completed,
+            "FAILED" => AuditTaskStatus::Failed,
+            "CANCELED" => AuditTaskStatus::Canceled,
+            _ => AuditTaskStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AuditTaskStatus {
+    fn from(value: String) -> AuditTaskStatus {
+        match value.as_str() {
+            "IN_PROGRESS" => AuditTaskStatus::InProgress,
+            "COMPLETED" => AuditTaskStatus::Completed,
+            "FAILED" => AuditTaskStatus::Failed,
+            "CANCELED" => AuditTaskStatus::Canceled,
+            _ => AuditTaskStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AuditTaskStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AuditTaskStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AuditTaskStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AuditTaskStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AuditTaskStatus {
+    fn deserialize<D>(deserializer: D) -> Result<AuditTaskStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AuditTaskType {
+    /// `ON_DEMAND_AUDIT_TASK`
+    OnDemandAuditTask,
+    /// `SCHEDULED_AUDIT_TASK`
+    ScheduledAuditTask,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AuditTaskType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AuditTaskType::OnDemandAuditTask => "ON_DEMAND_AUDIT_TASK",
+            AuditTaskType::ScheduledAuditTask => "SCHEDULED_AUDIT_TASK",
+            AuditTaskType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AuditTaskType {
+    fn default() -> AuditTaskType {
+        AuditTaskType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AuditTaskType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AuditTaskType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AuditTaskType {
+    fn from(value: &'a str) -> AuditTaskType {
+        match value {
+            "ON_DEMAND_AUDIT_TASK" => AuditTaskType::OnDemandAuditTask,
+            "SCHEDULED_AUDIT_TASK" => AuditTaskType::ScheduledAuditTask,
+            _ => AuditTaskType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AuditTaskType {
+    fn from(value: String) -> AuditTaskType {
+        match value.as_str() {
+            "ON_DEMAND_AUDIT_TASK" => AuditTaskType::OnDemandAuditTask,
+            "SCHEDULED_AUDIT_TASK" => AuditTaskType::ScheduledAuditTask,
+            _ => AuditTaskType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AuditTaskType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AuditTaskType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AuditTaskType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AuditTaskType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AuditTaskType {
+    fn deserialize<D>(deserializer: D) -> Result<AuditTaskType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AuthDecision {
+    /// `ALLOWED`
+    Allowed,
+    /// `EXPLICIT_DENY`
+    ExplicitDeny,
+    /// `IMPLICIT_DENY`
+    ImplicitDeny,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AuthDecision {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AuthDecision::Allowed => "ALLOWED",
+            AuthDecision::ExplicitDeny => "EXPLICIT_DENY",
+            AuthDecision::ImplicitDeny => "IMPLICIT_DENY",
+            AuthDecision::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AuthDecision {
+    fn default() -> AuthDecision {
+        AuthDecision::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AuthDecision {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AuthDecision {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AuthDecision {
+    fn from(value: &'a str) -> AuthDecision {
+        match value {
+            "ALLOWED" => AuthDecision::Allowed,
+            "EXPLICIT_DENY" => AuthDecision::ExplicitDeny,
+            "IMPLICIT_DENY" => AuthDecision::ImplicitDeny,
+            _ => AuthDecision::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AuthDecision {
+    fn from(value: String) -> AuthDecision {
+        match value.as_str() {
+            "ALLOWED" => AuthDecision::Allowed,
+            "EXPLICIT_DENY" => AuthDecision::ExplicitDeny,
+            "IMPLICIT_DENY" => AuthDecision::ImplicitDeny,
+            _ => AuthDecision::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AuthDecision {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AuthDecision, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AuthDecision {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AuthDecision {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AuthDecision {
+    fn deserialize<D>(deserializer: D) -> Result<AuthDecision, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A collection of authorization information.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AuthInfo {
     /// <p>The type of action for which the principal is being authorized.</p>
     #[serde(rename = "actionType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub action_type: Option<String>,
+    pub action_type: Option<ActionType>,
     /// <p>The resources for which the principal is being authorized to perform the specified action.</p>
     #[serde(rename = "resources")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -630,7 +1862,7 @@ pub struct AuthResult {
     /// <p>The final authorization decision of this scenario. Multiple statements are taken into account when determining the authorization decision. An explicit deny statement can override multiple allow statements.</p>
     #[serde(rename = "authDecision")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub auth_decision: Option<String>,
+    pub auth_decision: Option<AuthDecision>,
     /// <p>Authorization information.</p>
     #[serde(rename = "authInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -676,12 +1908,22 @@ pub struct AuthorizerDescription {
     pub authorizer_name: Option<String>,
     /// <p>The UNIX timestamp of when the authorizer was created.</p>
     #[serde(rename = "creationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The UNIX timestamp of when the authorizer was last updated.</p>
     #[serde(rename = "lastModifiedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_date: Option<f64>,
+    pub last_modified_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Specifies whether AWS IoT validates the token signature in an authorization request.</p>
     #[serde(rename = "signingDisabled")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -689,7 +1931,7 @@ pub struct AuthorizerDescription {
     /// <p>The status of the authorizer.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<AuthorizerStatus>,
     /// <p>The key used to extract the token from the HTTP headers.</p>
     #[serde(rename = "tokenKeyName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -700,11 +1942,104 @@ pub struct AuthorizerDescription {
     pub token_signing_public_keys: Option<::std::collections::HashMap<String, String>>,
 }
 
-/// <p>The authorizer summary.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct AuthorizerSummary {
-    /// <p>The authorizer ARN.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AuthorizerStatus {
+    /// `ACTIVE`
+    Active,
+    /// `INACTIVE`
+    Inactive,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AuthorizerStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AuthorizerStatus::Active => "ACTIVE",
+            AuthorizerStatus::Inactive => "INACTIVE",
+            AuthorizerStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AuthorizerStatus {
+    fn default() -> AuthorizerStatus {
+        AuthorizerStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AuthorizerStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AuthorizerStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AuthorizerStatus {
+    fn from(value: &'a str) -> AuthorizerStatus {
+        match value {
+            "ACTIVE" => AuthorizerStatus::Active,
+            "INACTIVE" => AuthorizerStatus::Inactive,
+            _ => AuthorizerStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AuthorizerStatus {
+    fn from(value: String) -> AuthorizerStatus {
+        match value.as_str() {
+            "ACTIVE" => AuthorizerStatus::Active,
+            "INACTIVE" => AuthorizerStatus::Inactive,
+            _ => AuthorizerStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AuthorizerStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AuthorizerStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AuthorizerStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AuthorizerStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AuthorizerStatus {
+    fn deserialize<D>(deserializer: D) -> Result<AuthorizerStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>The authorizer summary.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct AuthorizerSummary {
+    /// <p>The authorizer ARN.</p>
     #[serde(rename = "authorizerArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub authorizer_arn: Option<String>,
@@ -714,6 +2049,99 @@ pub struct AuthorizerSummary {
     pub authorizer_name: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AutoRegistrationStatus {
+    /// `ENABLE`
+    Enable,
+    /// `DISABLE`
+    Disable,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AutoRegistrationStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AutoRegistrationStatus::Enable => "ENABLE",
+            AutoRegistrationStatus::Disable => "DISABLE",
+            AutoRegistrationStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AutoRegistrationStatus {
+    fn default() -> AutoRegistrationStatus {
+        AutoRegistrationStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AutoRegistrationStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AutoRegistrationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AutoRegistrationStatus {
+    fn from(value: &'a str) -> AutoRegistrationStatus {
+        match value {
+            "ENABLE" => AutoRegistrationStatus::Enable,
+            "DISABLE" => AutoRegistrationStatus::Disable,
+            _ => AutoRegistrationStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AutoRegistrationStatus {
+    fn from(value: String) -> AutoRegistrationStatus {
+        match value.as_str() {
+            "ENABLE" => AutoRegistrationStatus::Enable,
+            "DISABLE" => AutoRegistrationStatus::Disable,
+            _ => AutoRegistrationStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AutoRegistrationStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AutoRegistrationStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AutoRegistrationStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AutoRegistrationStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AutoRegistrationStatus {
+    fn deserialize<D>(deserializer: D) -> Result<AutoRegistrationStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Configuration for the rollout of OTA updates.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AwsJobExecutionsRolloutConfig {
@@ -754,7 +2182,7 @@ pub struct BehaviorCriteria {
     /// <p>The operator that relates the thing measured (<code>metric</code>) to the criteria (containing a <code>value</code> or <code>statisticalThreshold</code>).</p>
     #[serde(rename = "comparisonOperator")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub comparison_operator: Option<String>,
+    pub comparison_operator: Option<ComparisonOperator>,
     /// <p>If a device is in violation of the behavior for the specified number of consecutive datapoints, an alarm occurs. If not specified, the default is 1.</p>
     #[serde(rename = "consecutiveDatapointsToAlarm")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -783,8 +2211,13 @@ pub struct BehaviorCriteria {
 pub struct BillingGroupMetadata {
     /// <p>The date the billing group was created.</p>
     #[serde(rename = "creationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>The properties of a billing group.</p>
@@ -810,12 +2243,17 @@ pub struct CACertificate {
     pub certificate_id: Option<String>,
     /// <p>The date the CA certificate was created.</p>
     #[serde(rename = "creationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The status of the CA certificate.</p> <p>The status value REGISTER_INACTIVE is deprecated and should not be used.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<CACertificateStatus>,
 }
 
 /// <p>Describes a CA certificate.</p>
@@ -825,7 +2263,7 @@ pub struct CACertificateDescription {
     /// <p>Whether the CA certificate configured for auto registration of device certificates. Valid values are "ENABLE" and "DISABLE"</p>
     #[serde(rename = "autoRegistrationStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub auto_registration_status: Option<String>,
+    pub auto_registration_status: Option<AutoRegistrationStatus>,
     /// <p>The CA certificate ARN.</p>
     #[serde(rename = "certificateArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -840,8 +2278,13 @@ pub struct CACertificateDescription {
     pub certificate_pem: Option<String>,
     /// <p>The date the CA certificate was created.</p>
     #[serde(rename = "creationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The customer version of the CA certificate.</p>
     #[serde(rename = "customerVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -852,8 +2295,13 @@ pub struct CACertificateDescription {
     pub generation_id: Option<String>,
     /// <p>The date the CA certificate was last modified.</p>
     #[serde(rename = "lastModifiedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_date: Option<f64>,
+    pub last_modified_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The owner of the CA certificate.</p>
     #[serde(rename = "ownedBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -861,13 +2309,194 @@ pub struct CACertificateDescription {
     /// <p>The status of a CA certificate.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<CACertificateStatus>,
     /// <p>When the CA certificate is valid.</p>
     #[serde(rename = "validity")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub validity: Option<CertificateValidity>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CACertificateStatus {
+    /// `ACTIVE`
+    Active,
+    /// `INACTIVE`
+    Inactive,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CACertificateStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CACertificateStatus::Active => "ACTIVE",
+            CACertificateStatus::Inactive => "INACTIVE",
+            CACertificateStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CACertificateStatus {
+    fn default() -> CACertificateStatus {
+        CACertificateStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CACertificateStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CACertificateStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CACertificateStatus {
+    fn from(value: &'a str) -> CACertificateStatus {
+        match value {
+            "ACTIVE" => CACertificateStatus::Active,
+            "INACTIVE" => CACertificateStatus::Inactive,
+            _ => CACertificateStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CACertificateStatus {
+    fn from(value: String) -> CACertificateStatus {
+        match value.as_str() {
+            "ACTIVE" => CACertificateStatus::Active,
+            "INACTIVE" => CACertificateStatus::Inactive,
+            _ => CACertificateStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CACertificateStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CACertificateStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CACertificateStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CACertificateStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CACertificateStatus {
+    fn deserialize<D>(deserializer: D) -> Result<CACertificateStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CACertificateUpdateAction {
+    /// `DEACTIVATE`
+    Deactivate,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CACertificateUpdateAction {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CACertificateUpdateAction::Deactivate => "DEACTIVATE",
+            CACertificateUpdateAction::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CACertificateUpdateAction {
+    fn default() -> CACertificateUpdateAction {
+        CACertificateUpdateAction::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CACertificateUpdateAction {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CACertificateUpdateAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CACertificateUpdateAction {
+    fn from(value: &'a str) -> CACertificateUpdateAction {
+        match value {
+            "DEACTIVATE" => CACertificateUpdateAction::Deactivate,
+            _ => CACertificateUpdateAction::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CACertificateUpdateAction {
+    fn from(value: String) -> CACertificateUpdateAction {
+        match value.as_str() {
+            "DEACTIVATE" => CACertificateUpdateAction::Deactivate,
+            _ => CACertificateUpdateAction::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CACertificateUpdateAction {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CACertificateUpdateAction, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CACertificateUpdateAction {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CACertificateUpdateAction {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CACertificateUpdateAction {
+    fn deserialize<D>(deserializer: D) -> Result<CACertificateUpdateAction, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CancelAuditMitigationActionsTaskRequest {
@@ -961,6 +2590,129 @@ pub struct CancelJobResponse {
     pub job_id: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CannedAccessControlList {
+    /// `private`
+    Private,
+    /// `public-read`
+    PublicRead,
+    /// `public-read-write`
+    PublicReadWrite,
+    /// `aws-exec-read`
+    AwsExecRead,
+    /// `authenticated-read`
+    AuthenticatedRead,
+    /// `bucket-owner-read`
+    BucketOwnerRead,
+    /// `bucket-owner-full-control`
+    BucketOwnerFullControl,
+    /// `log-delivery-write`
+    LogDeliveryWrite,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CannedAccessControlList {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CannedAccessControlList::Private => "private",
+            CannedAccessControlList::PublicRead => "public-read",
+            CannedAccessControlList::PublicReadWrite => "public-read-write",
+            CannedAccessControlList::AwsExecRead => "aws-exec-read",
+            CannedAccessControlList::AuthenticatedRead => "authenticated-read",
+            CannedAccessControlList::BucketOwnerRead => "bucket-owner-read",
+            CannedAccessControlList::BucketOwnerFullControl => "bucket-owner-full-control",
+            CannedAccessControlList::LogDeliveryWrite => "log-delivery-write",
+            CannedAccessControlList::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CannedAccessControlList {
+    fn default() -> CannedAccessControlList {
+        CannedAccessControlList::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CannedAccessControlList {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CannedAccessControlList {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CannedAccessControlList {
+    fn from(value: &'a str) -> CannedAccessControlList {
+        match value {
+            "private" => CannedAccessControlList::Private,
+            "public-read" => CannedAccessControlList::PublicRead,
+            "public-read-write" => CannedAccessControlList::PublicReadWrite,
+            "aws-exec-read" => CannedAccessControlList::AwsExecRead,
+            "authenticated-read" => CannedAccessControlList::AuthenticatedRead,
+            "bucket-owner-read" => CannedAccessControlList::BucketOwnerRead,
+            "bucket-owner-full-control" => CannedAccessControlList::BucketOwnerFullControl,
+            "log-delivery-write" => CannedAccessControlList::LogDeliveryWrite,
+            _ => CannedAccessControlList::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CannedAccessControlList {
+    fn from(value: String) -> CannedAccessControlList {
+        match value.as_str() {
+            "private" => CannedAccessControlList::Private,
+            "public-read" => CannedAccessControlList::PublicRead,
+            "public-read-write" => CannedAccessControlList::PublicReadWrite,
+            "aws-exec-read" => CannedAccessControlList::AwsExecRead,
+            "authenticated-read" => CannedAccessControlList::AuthenticatedRead,
+            "bucket-owner-read" => CannedAccessControlList::BucketOwnerRead,
+            "bucket-owner-full-control" => CannedAccessControlList::BucketOwnerFullControl,
+            "log-delivery-write" => CannedAccessControlList::LogDeliveryWrite,
+            _ => CannedAccessControlList::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CannedAccessControlList {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CannedAccessControlList, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CannedAccessControlList {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CannedAccessControlList {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CannedAccessControlList {
+    fn deserialize<D>(deserializer: D) -> Result<CannedAccessControlList, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about a certificate.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -975,12 +2727,17 @@ pub struct Certificate {
     pub certificate_id: Option<String>,
     /// <p>The date and time the certificate was created.</p>
     #[serde(rename = "creationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The status of the certificate.</p> <p>The status value REGISTER_INACTIVE is deprecated and should not be used.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<CertificateStatus>,
 }
 
 /// <p>Describes a certificate.</p>
@@ -1005,8 +2762,13 @@ pub struct CertificateDescription {
     pub certificate_pem: Option<String>,
     /// <p>The date and time the certificate was created.</p>
     #[serde(rename = "creationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The customer version of the certificate.</p>
     #[serde(rename = "customerVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1017,8 +2779,13 @@ pub struct CertificateDescription {
     pub generation_id: Option<String>,
     /// <p>The date and time the certificate was last modified.</p>
     #[serde(rename = "lastModifiedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_date: Option<f64>,
+    pub last_modified_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The ID of the AWS account that owns the certificate.</p>
     #[serde(rename = "ownedBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1030,7 +2797,7 @@ pub struct CertificateDescription {
     /// <p>The status of the certificate.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<CertificateStatus>,
     /// <p>The transfer data.</p>
     #[serde(rename = "transferData")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1041,32 +2808,155 @@ pub struct CertificateDescription {
     pub validity: Option<CertificateValidity>,
 }
 
-/// <p>When the certificate is valid.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct CertificateValidity {
-    /// <p>The certificate is not valid after this date.</p>
-    #[serde(rename = "notAfter")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub not_after: Option<f64>,
-    /// <p>The certificate is not valid before this date.</p>
-    #[serde(rename = "notBefore")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub not_before: Option<f64>,
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CertificateStatus {
+    /// `ACTIVE`
+    Active,
+    /// `INACTIVE`
+    Inactive,
+    /// `REVOKED`
+    Revoked,
+    /// `PENDING_TRANSFER`
+    PendingTransfer,
+    /// `REGISTER_INACTIVE`
+    RegisterInactive,
+    /// `PENDING_ACTIVATION`
+    PendingActivation,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CertificateStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CertificateStatus::Active => "ACTIVE",
+            CertificateStatus::Inactive => "INACTIVE",
+            CertificateStatus::Revoked => "REVOKED",
+            CertificateStatus::PendingTransfer => "PENDING_TRANSFER",
+            CertificateStatus::RegisterInactive => "REGISTER_INACTIVE",
+            CertificateStatus::PendingActivation => "PENDING_ACTIVATION",
+            CertificateStatus::Unknown(ref value) => value,
+        }
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct ClearDefaultAuthorizerRequest {}
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CertificateStatus {
+    fn default() -> CertificateStatus {
+        CertificateStatus::Unknown(String::new())
+    }
+}
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct ClearDefaultAuthorizerResponse {}
+impl AsRef<str> for CertificateStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
 
-/// <p>Describes an action that updates a CloudWatch alarm.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct CloudwatchAlarmAction {
-    /// <p>The CloudWatch alarm name.</p>
+impl fmt::Display for CertificateStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CertificateStatus {
+    fn from(value: &'a str) -> CertificateStatus {
+        match value {
+            "ACTIVE" => CertificateStatus::Active,
+            "INACTIVE" => CertificateStatus::Inactive,
+            "REVOKED" => CertificateStatus::Revoked,
+            "PENDING_TRANSFER" => CertificateStatus::PendingTransfer,
+            "REGISTER_INACTIVE" => CertificateStatus::RegisterInactive,
+            "PENDING_ACTIVATION" => CertificateStatus::PendingActivation,
+            _ => CertificateStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CertificateStatus {
+    fn from(value: String) -> CertificateStatus {
+        match value.as_str() {
+            "ACTIVE" => CertificateStatus::Active,
+            "INACTIVE" => CertificateStatus::Inactive,
+            "REVOKED" => CertificateStatus::Revoked,
+            "PENDING_TRANSFER" => CertificateStatus::PendingTransfer,
+            "REGISTER_INACTIVE" => CertificateStatus::RegisterInactive,
+            "PENDING_ACTIVATION" => CertificateStatus::PendingActivation,
+            _ => CertificateStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CertificateStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CertificateStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CertificateStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CertificateStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CertificateStatus {
+    fn deserialize<D>(deserializer: D) -> Result<CertificateStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>When the certificate is valid.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct CertificateValidity {
+    /// <p>The certificate is not valid after this date.</p>
+    #[serde(rename = "notAfter")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub not_after: Option<::chrono::DateTime<::chrono::Utc>>,
+    /// <p>The certificate is not valid before this date.</p>
+    #[serde(rename = "notBefore")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub not_before: Option<::chrono::DateTime<::chrono::Utc>>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct ClearDefaultAuthorizerRequest {}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct ClearDefaultAuthorizerResponse {}
+
+/// <p>Describes an action that updates a CloudWatch alarm.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CloudwatchAlarmAction {
+    /// <p>The CloudWatch alarm name.</p>
     #[serde(rename = "alarmName")]
     pub alarm_name: String,
     /// <p>The IAM role that allows access to the CloudWatch alarm.</p>
@@ -1148,6 +3038,139 @@ pub struct CodeSigningSignature {
     pub inline_document: Option<bytes::Bytes>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ComparisonOperator {
+    /// `less-than`
+    LessThan,
+    /// `less-than-equals`
+    LessThanEquals,
+    /// `greater-than`
+    GreaterThan,
+    /// `greater-than-equals`
+    GreaterThanEquals,
+    /// `in-cidr-set`
+    InCidrSet,
+    /// `not-in-cidr-set`
+    NotInCidrSet,
+    /// `in-port-set`
+    InPortSet,
+    /// `not-in-port-set`
+    NotInPortSet,
+    /// `in-set`
+    InSet,
+    /// `not-in-set`
+    NotInSet,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ComparisonOperator {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ComparisonOperator::LessThan => "less-than",
+            ComparisonOperator::LessThanEquals => "less-than-equals",
+            ComparisonOperator::GreaterThan => "greater-than",
+            ComparisonOperator::GreaterThanEquals => "greater-than-equals",
+            ComparisonOperator::InCidrSet => "in-cidr-set",
+            ComparisonOperator::NotInCidrSet => "not-in-cidr-set",
+            ComparisonOperator::InPortSet => "in-port-set",
+            ComparisonOperator::NotInPortSet => "not-in-port-set",
+            ComparisonOperator::InSet => "in-set",
+            ComparisonOperator::NotInSet => "not-in-set",
+            ComparisonOperator::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ComparisonOperator {
+    fn default() -> ComparisonOperator {
+        ComparisonOperator::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ComparisonOperator {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ComparisonOperator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ComparisonOperator {
+    fn from(value: &'a str) -> ComparisonOperator {
+        match value {
+            "less-than" => ComparisonOperator::LessThan,
+            "less-than-equals" => ComparisonOperator::LessThanEquals,
+            "greater-than" => ComparisonOperator::GreaterThan,
+            "greater-than-equals" => ComparisonOperator::GreaterThanEquals,
+            "in-cidr-set" => ComparisonOperator::InCidrSet,
+            "not-in-cidr-set" => ComparisonOperator::NotInCidrSet,
+            "in-port-set" => ComparisonOperator::InPortSet,
+            "not-in-port-set" => ComparisonOperator::NotInPortSet,
+            "in-set" => ComparisonOperator::InSet,
+            "not-in-set" => ComparisonOperator::NotInSet,
+            _ => ComparisonOperator::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ComparisonOperator {
+    fn from(value: String) -> ComparisonOperator {
+        match value.as_str() {
+            "less-than" => ComparisonOperator::LessThan,
+            "less-than-equals" => ComparisonOperator::LessThanEquals,
+            "greater-than" => ComparisonOperator::GreaterThan,
+            "greater-than-equals" => ComparisonOperator::GreaterThanEquals,
+            "in-cidr-set" => ComparisonOperator::InCidrSet,
+            "not-in-cidr-set" => ComparisonOperator::NotInCidrSet,
+            "in-port-set" => ComparisonOperator::InPortSet,
+            "not-in-port-set" => ComparisonOperator::NotInPortSet,
+            "in-set" => ComparisonOperator::InSet,
+            "not-in-set" => ComparisonOperator::NotInSet,
+            _ => ComparisonOperator::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ComparisonOperator {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ComparisonOperator, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ComparisonOperator {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ComparisonOperator {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ComparisonOperator {
+    fn deserialize<D>(deserializer: D) -> Result<ComparisonOperator, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Configuration {
@@ -1185,7 +3208,7 @@ pub struct CreateAuthorizerRequest {
     /// <p>The status of the create authorizer request.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<AuthorizerStatus>,
     /// <p>The name of the token key used to extract the token from the HTTP headers.</p>
     #[serde(rename = "tokenKeyName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1294,7 +3317,7 @@ pub struct CreateDomainConfigurationRequest {
     /// <p>The type of service delivered by the endpoint.</p>
     #[serde(rename = "serviceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub service_type: Option<String>,
+    pub service_type: Option<ServiceType>,
     /// <p>The certificate used to validate the server certificate and prove domain name ownership. This certificate must be signed by a public certificate authority. This value is not required for AWS-managed domains.</p>
     #[serde(rename = "validationCertificateArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1407,7 +3430,7 @@ pub struct CreateJobRequest {
     /// <p>Specifies whether the job will continue to run (CONTINUOUS), or will be complete after all those things specified as targets have completed the job (SNAPSHOT). If continuous, the job may also be run on a thing when a change is detected in a target. For example, a job will run on a thing when the thing is added to a target group, even after the job was completed by all things originally in the group.</p>
     #[serde(rename = "targetSelection")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub target_selection: Option<String>,
+    pub target_selection: Option<TargetSelection>,
     /// <p>A list of things and thing groups to which the job should be sent.</p>
     #[serde(rename = "targets")]
     pub targets: Vec<String>,
@@ -1525,7 +3548,7 @@ pub struct CreateOTAUpdateRequest {
     /// <p>The protocol used to transfer the OTA update image. Valid values are [HTTP], [MQTT], [HTTP, MQTT]. When both HTTP and MQTT are specified, the target device can choose the protocol.</p>
     #[serde(rename = "protocols")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub protocols: Option<Vec<String>>,
+    pub protocols: Option<Vec<Protocol>>,
     /// <p>The IAM role that allows access to the AWS IoT Jobs service.</p>
     #[serde(rename = "roleArn")]
     pub role_arn: String,
@@ -1536,7 +3559,7 @@ pub struct CreateOTAUpdateRequest {
     /// <p>Specifies whether the update will continue to run (CONTINUOUS), or will be complete after all the things specified as targets have completed the update (SNAPSHOT). If continuous, the update may also be run on a thing when a change is detected in a target. For example, an update will run on a thing when the thing is added to a target group, even after the update was completed by all things originally in the group. Valid values: CONTINUOUS | SNAPSHOT.</p>
     #[serde(rename = "targetSelection")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub target_selection: Option<String>,
+    pub target_selection: Option<TargetSelection>,
     /// <p>The targeted devices to receive OTA updates.</p>
     #[serde(rename = "targets")]
     pub targets: Vec<String>,
@@ -1564,7 +3587,7 @@ pub struct CreateOTAUpdateResponse {
     /// <p>The OTA update status.</p>
     #[serde(rename = "otaUpdateStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ota_update_status: Option<String>,
+    pub ota_update_status: Option<OTAUpdateStatus>,
 }
 
 /// <p>The input for the CreatePolicy operation.</p>
@@ -1660,8 +3683,13 @@ pub struct CreateProvisioningClaimResponse {
     pub certificate_pem: Option<String>,
     /// <p>The provisioning claim expiration time.</p>
     #[serde(rename = "expiration")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expiration: Option<f64>,
+    pub expiration: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The provisioning claim key pair.</p>
     #[serde(rename = "keyPair")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1785,10 +3813,10 @@ pub struct CreateScheduledAuditRequest {
     /// <p>The day of the week on which the scheduled audit takes place. Can be one of "SUN", "MON", "TUE", "WED", "THU", "FRI", or "SAT". This field is required if the "frequency" parameter is set to "WEEKLY" or "BIWEEKLY".</p>
     #[serde(rename = "dayOfWeek")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub day_of_week: Option<String>,
+    pub day_of_week: Option<DayOfWeek>,
     /// <p>How often the scheduled audit takes place. Can be one of "DAILY", "WEEKLY", "BIWEEKLY" or "MONTHLY". The start time of each audit is determined by the system.</p>
     #[serde(rename = "frequency")]
-    pub frequency: String,
+    pub frequency: AuditFrequency,
     /// <p>The name you want to give to the scheduled audit. (Max. 128 chars)</p>
     #[serde(rename = "scheduledAuditName")]
     pub scheduled_audit_name: String,
@@ -1820,7 +3848,7 @@ pub struct CreateSecurityProfileRequest {
     /// <p>Specifies the destinations to which alerts are sent. (Alerts are always sent to the console.) Alerts are generated when a device (thing) violates a behavior.</p>
     #[serde(rename = "alertTargets")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub alert_targets: Option<::std::collections::HashMap<String, AlertTarget>>,
+    pub alert_targets: Option<::std::collections::HashMap<AlertTargetType, AlertTarget>>,
     /// <p>Specifies the behaviors that, when violated by a device (thing), cause an alert.</p>
     #[serde(rename = "behaviors")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2059,6 +4087,124 @@ pub struct CustomCodeSigning {
     pub signature_algorithm: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DayOfWeek {
+    /// `SUN`
+    Sun,
+    /// `MON`
+    Mon,
+    /// `TUE`
+    Tue,
+    /// `WED`
+    Wed,
+    /// `THU`
+    Thu,
+    /// `FRI`
+    Fri,
+    /// `SAT`
+    Sat,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DayOfWeek {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DayOfWeek::Sun => "SUN",
+            DayOfWeek::Mon => "MON",
+            DayOfWeek::Tue => "TUE",
+            DayOfWeek::Wed => "WED",
+            DayOfWeek::Thu => "THU",
+            DayOfWeek::Fri => "FRI",
+            DayOfWeek::Sat => "SAT",
+            DayOfWeek::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DayOfWeek {
+    fn default() -> DayOfWeek {
+        DayOfWeek::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DayOfWeek {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DayOfWeek {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DayOfWeek {
+    fn from(value: &'a str) -> DayOfWeek {
+        match value {
+            "SUN" => DayOfWeek::Sun,
+            "MON" => DayOfWeek::Mon,
+            "TUE" => DayOfWeek::Tue,
+            "WED" => DayOfWeek::Wed,
+            "THU" => DayOfWeek::Thu,
+            "FRI" => DayOfWeek::Fri,
+            "SAT" => DayOfWeek::Sat,
+            _ => DayOfWeek::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DayOfWeek {
+    fn from(value: String) -> DayOfWeek {
+        match value.as_str() {
+            "SUN" => DayOfWeek::Sun,
+            "MON" => DayOfWeek::Mon,
+            "TUE" => DayOfWeek::Tue,
+            "WED" => DayOfWeek::Wed,
+            "THU" => DayOfWeek::Thu,
+            "FRI" => DayOfWeek::Fri,
+            "SAT" => DayOfWeek::Sat,
+            _ => DayOfWeek::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DayOfWeek {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DayOfWeek, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DayOfWeek {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DayOfWeek {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DayOfWeek {
+    fn deserialize<D>(deserializer: D) -> Result<DayOfWeek, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct DeleteAccountAuditConfigurationRequest {
@@ -2404,7 +4550,7 @@ pub struct DeleteV2LoggingLevelRequest {
     pub target_name: String,
     /// <p>The type of resource for which you are configuring logging. Must be <code>THING_Group</code>.</p>
     #[serde(rename = "targetType")]
-    pub target_type: String,
+    pub target_type: LogTargetType,
 }
 
 /// <p>Contains information that denied the authorization.</p>
@@ -2455,7 +4601,7 @@ pub struct DescribeAccountAuditConfigurationResponse {
     #[serde(rename = "auditNotificationTargetConfigurations")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub audit_notification_target_configurations:
-        Option<::std::collections::HashMap<String, AuditNotificationTarget>>,
+        Option<::std::collections::HashMap<AuditNotificationType, AuditNotificationTarget>>,
     /// <p>The ARN of the role that grants permission to AWS IoT to access information about your devices, policies, certificates, and other items as required when performing an audit.</p> <p>On the first call to <code>UpdateAccountAuditConfiguration</code>, this parameter is required.</p>
     #[serde(rename = "roleArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2499,12 +4645,22 @@ pub struct DescribeAuditMitigationActionsTaskResponse {
     pub audit_check_to_actions_mapping: Option<::std::collections::HashMap<String, Vec<String>>>,
     /// <p>The date and time when the task was completed or canceled.</p>
     #[serde(rename = "endTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The date and time when the task was started.</p>
     #[serde(rename = "startTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Identifies the findings to which the mitigation actions are applied. This can be by audit checks, by audit task, or a set of findings.</p>
     #[serde(rename = "target")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2516,7 +4672,7 @@ pub struct DescribeAuditMitigationActionsTaskResponse {
     /// <p>The current status of the task.</p>
     #[serde(rename = "taskStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub task_status: Option<String>,
+    pub task_status: Option<AuditMitigationActionsTaskStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -2540,8 +4696,13 @@ pub struct DescribeAuditTaskResponse {
     pub scheduled_audit_name: Option<String>,
     /// <p>The time the audit started.</p>
     #[serde(rename = "taskStartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub task_start_time: Option<f64>,
+    pub task_start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Statistical information about the audit.</p>
     #[serde(rename = "taskStatistics")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2549,11 +4710,11 @@ pub struct DescribeAuditTaskResponse {
     /// <p>The status of the audit: one of "IN_PROGRESS", "COMPLETED", "FAILED", or "CANCELED".</p>
     #[serde(rename = "taskStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub task_status: Option<String>,
+    pub task_status: Option<AuditTaskStatus>,
     /// <p>The type of audit: "ON_DEMAND_AUDIT_TASK" or "SCHEDULED_AUDIT_TASK".</p>
     #[serde(rename = "taskType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub task_type: Option<String>,
+    pub task_type: Option<AuditTaskType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -2691,7 +4852,7 @@ pub struct DescribeDomainConfigurationResponse {
     /// <p>A Boolean value that specifies the current state of the domain configuration.</p>
     #[serde(rename = "domainConfigurationStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub domain_configuration_status: Option<String>,
+    pub domain_configuration_status: Option<DomainConfigurationStatus>,
     /// <p>The name of the domain.</p>
     #[serde(rename = "domainName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2699,7 +4860,7 @@ pub struct DescribeDomainConfigurationResponse {
     /// <p>The type of the domain.</p>
     #[serde(rename = "domainType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub domain_type: Option<String>,
+    pub domain_type: Option<DomainType>,
     /// <p>A list containing summary information about the server certificate included in the domain configuration.</p>
     #[serde(rename = "serverCertificates")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2707,7 +4868,7 @@ pub struct DescribeDomainConfigurationResponse {
     /// <p>The type of service delivered by the endpoint.</p>
     #[serde(rename = "serviceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub service_type: Option<String>,
+    pub service_type: Option<ServiceType>,
 }
 
 /// <p>The input for the DescribeEndpoint operation.</p>
@@ -2739,16 +4900,26 @@ pub struct DescribeEventConfigurationsRequest {}
 pub struct DescribeEventConfigurationsResponse {
     /// <p>The creation date of the event configuration.</p>
     #[serde(rename = "creationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The event configurations.</p>
     #[serde(rename = "eventConfigurations")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub event_configurations: Option<::std::collections::HashMap<String, Configuration>>,
+    pub event_configurations: Option<::std::collections::HashMap<EventType, Configuration>>,
     /// <p>The date the event configurations were last modified.</p>
     #[serde(rename = "lastModifiedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_date: Option<f64>,
+    pub last_modified_date: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -2769,7 +4940,7 @@ pub struct DescribeIndexResponse {
     /// <p>The index status.</p>
     #[serde(rename = "indexStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub index_status: Option<String>,
+    pub index_status: Option<IndexStatus>,
     /// <p><p>Contains a value that specifies the type of indexing performed. Valid values are:</p> <ul> <li> <p>REGISTRY – Your thing index contains only registry data.</p> </li> <li> <p>REGISTRY<em>AND</em>SHADOW - Your thing index contains registry data and shadow data.</p> </li> <li> <p>REGISTRY<em>AND</em>CONNECTIVITY<em>STATUS - Your thing index contains registry data and thing connectivity status data.</p> </li> <li> <p>REGISTRY</em>AND<em>SHADOW</em>AND<em>CONNECTIVITY</em>STATUS - Your thing index contains registry data, shadow data, and thing connectivity status data.</p> </li> </ul></p>
     #[serde(rename = "schema")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2851,15 +5022,25 @@ pub struct DescribeMitigationActionResponse {
     /// <p>The type of mitigation action.</p>
     #[serde(rename = "actionType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub action_type: Option<String>,
+    pub action_type: Option<MitigationActionType>,
     /// <p>The date and time when the mitigation action was added to your AWS account.</p>
     #[serde(rename = "creationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The date and time when the mitigation action was last changed.</p>
     #[serde(rename = "lastModifiedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_date: Option<f64>,
+    pub last_modified_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The ARN of the IAM role used to apply this action.</p>
     #[serde(rename = "roleArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2879,8 +5060,13 @@ pub struct DescribeProvisioningTemplateRequest {
 pub struct DescribeProvisioningTemplateResponse {
     /// <p>The date when the fleet provisioning template was created.</p>
     #[serde(rename = "creationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The default fleet template version ID.</p>
     #[serde(rename = "defaultVersionId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2895,8 +5081,13 @@ pub struct DescribeProvisioningTemplateResponse {
     pub enabled: Option<bool>,
     /// <p>The date when the fleet provisioning template was last modified.</p>
     #[serde(rename = "lastModifiedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_date: Option<f64>,
+    pub last_modified_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The ARN of the role associated with the provisioning template. This IoT role grants permission to provision a device.</p>
     #[serde(rename = "provisioningRoleArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2931,8 +5122,13 @@ pub struct DescribeProvisioningTemplateVersionRequest {
 pub struct DescribeProvisioningTemplateVersionResponse {
     /// <p>The date when the fleet provisioning template version was created.</p>
     #[serde(rename = "creationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>True if the fleet provisioning template version is the default version.</p>
     #[serde(rename = "isDefaultVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2982,11 +5178,11 @@ pub struct DescribeScheduledAuditResponse {
     /// <p>The day of the week on which the scheduled audit takes place. One of "SUN", "MON", "TUE", "WED", "THU", "FRI", or "SAT".</p>
     #[serde(rename = "dayOfWeek")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub day_of_week: Option<String>,
+    pub day_of_week: Option<DayOfWeek>,
     /// <p>How often the scheduled audit takes place. One of "DAILY", "WEEKLY", "BIWEEKLY", or "MONTHLY". The start time of each audit is determined by the system.</p>
     #[serde(rename = "frequency")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub frequency: Option<String>,
+    pub frequency: Option<AuditFrequency>,
     /// <p>The ARN of the scheduled audit.</p>
     #[serde(rename = "scheduledAuditArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3019,19 +5215,29 @@ pub struct DescribeSecurityProfileResponse {
     /// <p>Where the alerts are sent. (Alerts are always sent to the console.)</p>
     #[serde(rename = "alertTargets")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub alert_targets: Option<::std::collections::HashMap<String, AlertTarget>>,
+    pub alert_targets: Option<::std::collections::HashMap<AlertTargetType, AlertTarget>>,
     /// <p>Specifies the behaviors that, when violated by a device (thing), cause an alert.</p>
     #[serde(rename = "behaviors")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub behaviors: Option<Vec<Behavior>>,
     /// <p>The time the security profile was created.</p>
     #[serde(rename = "creationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The time the security profile was last modified.</p>
     #[serde(rename = "lastModifiedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_date: Option<f64>,
+    pub last_modified_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The ARN of the security profile.</p>
     #[serde(rename = "securityProfileArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3093,7 +5299,7 @@ pub struct DescribeThingGroupResponse {
     /// <p>The dynamic thing group status.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<DynamicGroupStatus>,
     /// <p>The thing group ARN.</p>
     #[serde(rename = "thingGroupArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3133,8 +5339,13 @@ pub struct DescribeThingRegistrationTaskRequest {
 pub struct DescribeThingRegistrationTaskResponse {
     /// <p>The task creation date.</p>
     #[serde(rename = "creationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The number of things that failed to be provisioned.</p>
     #[serde(rename = "failureCount")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3149,8 +5360,13 @@ pub struct DescribeThingRegistrationTaskResponse {
     pub input_file_key: Option<String>,
     /// <p>The date when the task was last modified.</p>
     #[serde(rename = "lastModifiedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_date: Option<f64>,
+    pub last_modified_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The message.</p>
     #[serde(rename = "message")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3166,7 +5382,7 @@ pub struct DescribeThingRegistrationTaskResponse {
     /// <p>The status of the bulk thing provisioning task.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<Status>,
     /// <p>The number of things successfully provisioned.</p>
     #[serde(rename = "successCount")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3327,6 +5543,94 @@ pub struct DetachThingPrincipalRequest {
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DetachThingPrincipalResponse {}
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DeviceCertificateUpdateAction {
+    /// `DEACTIVATE`
+    Deactivate,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DeviceCertificateUpdateAction {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeviceCertificateUpdateAction::Deactivate => "DEACTIVATE",
+            DeviceCertificateUpdateAction::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DeviceCertificateUpdateAction {
+    fn default() -> DeviceCertificateUpdateAction {
+        DeviceCertificateUpdateAction::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DeviceCertificateUpdateAction {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DeviceCertificateUpdateAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DeviceCertificateUpdateAction {
+    fn from(value: &'a str) -> DeviceCertificateUpdateAction {
+        match value {
+            "DEACTIVATE" => DeviceCertificateUpdateAction::Deactivate,
+            _ => DeviceCertificateUpdateAction::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeviceCertificateUpdateAction {
+    fn from(value: String) -> DeviceCertificateUpdateAction {
+        match value.as_str() {
+            "DEACTIVATE" => DeviceCertificateUpdateAction::Deactivate,
+            _ => DeviceCertificateUpdateAction::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DeviceCertificateUpdateAction {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DeviceCertificateUpdateAction, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DeviceCertificateUpdateAction {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DeviceCertificateUpdateAction {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeviceCertificateUpdateAction {
+    fn deserialize<D>(deserializer: D) -> Result<DeviceCertificateUpdateAction, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The input for the DisableTopicRuleRequest operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -3336,44 +5640,333 @@ pub struct DisableTopicRuleRequest {
     pub rule_name: String,
 }
 
-/// <p><p>The summary of a domain configuration. A domain configuration specifies custom IoT-specific information about a domain. A domain configuration can be associated with an AWS-managed domain (for example, dbc123defghijk.iot.us-west-2.amazonaws.com), a customer managed domain, or a default endpoint.</p> <ul> <li> <p>Data</p> </li> <li> <p>Jobs</p> </li> <li> <p>CredentialProvider</p> </li> </ul> <note> <p>The domain configuration feature is in public preview and is subject to change.</p> </note></p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct DomainConfigurationSummary {
-    /// <p>The ARN of the domain configuration.</p>
-    #[serde(rename = "domainConfigurationArn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub domain_configuration_arn: Option<String>,
-    /// <p>The name of the domain configuration. This value must be unique to a region.</p>
-    #[serde(rename = "domainConfigurationName")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub domain_configuration_name: Option<String>,
-    /// <p>The type of service delivered by the endpoint.</p>
-    #[serde(rename = "serviceType")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub service_type: Option<String>,
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DomainConfigurationStatus {
+    /// `ENABLED`
+    Enabled,
+    /// `DISABLED`
+    Disabled,
+    /// A value not known to this version of the crate
+    Unknown(String),
 }
 
-/// <p>Describes an action to write to a DynamoDB table.</p> <p>The <code>tableName</code>, <code>hashKeyField</code>, and <code>rangeKeyField</code> values must match the values used when you created the table.</p> <p>The <code>hashKeyValue</code> and <code>rangeKeyvalue</code> fields use a substitution template syntax. These templates provide data at runtime. The syntax is as follows: ${<i>sql-expression</i>}.</p> <p>You can specify any valid expression in a WHERE or SELECT clause, including JSON properties, comparisons, calculations, and functions. For example, the following field uses the third level of the topic:</p> <p> <code>"hashKeyValue": "${topic(3)}"</code> </p> <p>The following field uses the timestamp:</p> <p> <code>"rangeKeyValue": "${timestamp()}"</code> </p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct DynamoDBAction {
-    /// <p>The hash key name.</p>
-    #[serde(rename = "hashKeyField")]
-    pub hash_key_field: String,
-    /// <p>The hash key type. Valid values are "STRING" or "NUMBER"</p>
-    #[serde(rename = "hashKeyType")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub hash_key_type: Option<String>,
-    /// <p>The hash key value.</p>
-    #[serde(rename = "hashKeyValue")]
-    pub hash_key_value: String,
-    /// <p>The type of operation to be performed. This follows the substitution template, so it can be <code>${operation}</code>, but the substitution must result in one of the following: <code>INSERT</code>, <code>UPDATE</code>, or <code>DELETE</code>.</p>
-    #[serde(rename = "operation")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub operation: Option<String>,
-    /// <p>The action payload. This name can be customized.</p>
-    #[serde(rename = "payloadField")]
-    #[serde(skip_serializing_if = "Option::is_none")]
+impl DomainConfigurationStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DomainConfigurationStatus::Enabled => "ENABLED",
+            DomainConfigurationStatus::Disabled => "DISABLED",
+            DomainConfigurationStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DomainConfigurationStatus {
+    fn default() -> DomainConfigurationStatus {
+        DomainConfigurationStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DomainConfigurationStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DomainConfigurationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DomainConfigurationStatus {
+    fn from(value: &'a str) -> DomainConfigurationStatus {
+        match value {
+            "ENABLED" => DomainConfigurationStatus::Enabled,
+            "DISABLED" => DomainConfigurationStatus::Disabled,
+            _ => DomainConfigurationStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DomainConfigurationStatus {
+    fn from(value: String) -> DomainConfigurationStatus {
+        match value.as_str() {
+            "ENABLED" => DomainConfigurationStatus::Enabled,
+            "DISABLED" => DomainConfigurationStatus::Disabled,
+            _ => DomainConfigurationStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DomainConfigurationStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DomainConfigurationStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DomainConfigurationStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DomainConfigurationStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DomainConfigurationStatus {
+    fn deserialize<D>(deserializer: D) -> Result<DomainConfigurationStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p><p>The summary of a domain configuration. A domain configuration specifies custom IoT-specific information about a domain. A domain configuration can be associated with an AWS-managed domain (for example, dbc123defghijk.iot.us-west-2.amazonaws.com), a customer managed domain, or a default endpoint.</p> <ul> <li> <p>Data</p> </li> <li> <p>Jobs</p> </li> <li> <p>CredentialProvider</p> </li> </ul> <note> <p>The domain configuration feature is in public preview and is subject to change.</p> </note></p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct DomainConfigurationSummary {
+    /// <p>The ARN of the domain configuration.</p>
+    #[serde(rename = "domainConfigurationArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub domain_configuration_arn: Option<String>,
+    /// <p>The name of the domain configuration. This value must be unique to a region.</p>
+    #[serde(rename = "domainConfigurationName")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub domain_configuration_name: Option<String>,
+    /// <p>The type of service delivered by the endpoint.</p>
+    #[serde(rename = "serviceType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service_type: Option<ServiceType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DomainType {
+    /// `ENDPOINT`
+    Endpoint,
+    /// `AWS_MANAGED`
+    AwsManaged,
+    /// `CUSTOMER_MANAGED`
+    CustomerManaged,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DomainType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DomainType::Endpoint => "ENDPOINT",
+            DomainType::AwsManaged => "AWS_MANAGED",
+            DomainType::CustomerManaged => "CUSTOMER_MANAGED",
+            DomainType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DomainType {
+    fn default() -> DomainType {
+        DomainType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DomainType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DomainType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DomainType {
+    fn from(value: &'a str) -> DomainType {
+        match value {
+            "ENDPOINT" => DomainType::Endpoint,
+            "AWS_MANAGED" => DomainType::AwsManaged,
+            "CUSTOMER_MANAGED" => DomainType::CustomerManaged,
+            _ => DomainType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DomainType {
+    fn from(value: String) -> DomainType {
+        match value.as_str() {
+            "ENDPOINT" => DomainType::Endpoint,
+            "AWS_MANAGED" => DomainType::AwsManaged,
+            "CUSTOMER_MANAGED" => DomainType::CustomerManaged,
+            _ => DomainType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DomainType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DomainType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DomainType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DomainType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DomainType {
+    fn deserialize<D>(deserializer: D) -> Result<DomainType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DynamicGroupStatus {
+    /// `ACTIVE`
+    Active,
+    /// `BUILDING`
+    Building,
+    /// `REBUILDING`
+    Rebuilding,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DynamicGroupStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DynamicGroupStatus::Active => "ACTIVE",
+            DynamicGroupStatus::Building => "BUILDING",
+            DynamicGroupStatus::Rebuilding => "REBUILDING",
+            DynamicGroupStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DynamicGroupStatus {
+    fn default() -> DynamicGroupStatus {
+        DynamicGroupStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DynamicGroupStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DynamicGroupStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DynamicGroupStatus {
+    fn from(value: &'a str) -> DynamicGroupStatus {
+        match value {
+            "ACTIVE" => DynamicGroupStatus::Active,
+            "BUILDING" => DynamicGroupStatus::Building,
+            "REBUILDING" => DynamicGroupStatus::Rebuilding,
+            _ => DynamicGroupStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DynamicGroupStatus {
+    fn from(value: String) -> DynamicGroupStatus {
+        match value.as_str() {
+            "ACTIVE" => DynamicGroupStatus::Active,
+            "BUILDING" => DynamicGroupStatus::Building,
+            "REBUILDING" => DynamicGroupStatus::Rebuilding,
+            _ => DynamicGroupStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DynamicGroupStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DynamicGroupStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DynamicGroupStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DynamicGroupStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DynamicGroupStatus {
+    fn deserialize<D>(deserializer: D) -> Result<DynamicGroupStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Describes an action to write to a DynamoDB table.</p> <p>The <code>tableName</code>, <code>hashKeyField</code>, and <code>rangeKeyField</code> values must match the values used when you created the table.</p> <p>The <code>hashKeyValue</code> and <code>rangeKeyvalue</code> fields use a substitution template syntax. These templates provide data at runtime. The syntax is as follows: ${<i>sql-expression</i>}.</p> <p>You can specify any valid expression in a WHERE or SELECT clause, including JSON properties, comparisons, calculations, and functions. For example, the following field uses the third level of the topic:</p> <p> <code>"hashKeyValue": "${topic(3)}"</code> </p> <p>The following field uses the timestamp:</p> <p> <code>"rangeKeyValue": "${timestamp()}"</code> </p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DynamoDBAction {
+    /// <p>The hash key name.</p>
+    #[serde(rename = "hashKeyField")]
+    pub hash_key_field: String,
+    /// <p>The hash key type. Valid values are "STRING" or "NUMBER"</p>
+    #[serde(rename = "hashKeyType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hash_key_type: Option<DynamoKeyType>,
+    /// <p>The hash key value.</p>
+    #[serde(rename = "hashKeyValue")]
+    pub hash_key_value: String,
+    /// <p>The type of operation to be performed. This follows the substitution template, so it can be <code>${operation}</code>, but the substitution must result in one of the following: <code>INSERT</code>, <code>UPDATE</code>, or <code>DELETE</code>.</p>
+    #[serde(rename = "operation")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub operation: Option<String>,
+    /// <p>The action payload. This name can be customized.</p>
+    #[serde(rename = "payloadField")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub payload_field: Option<String>,
     /// <p>The range key name.</p>
     #[serde(rename = "rangeKeyField")]
@@ -3382,7 +5975,7 @@ pub struct DynamoDBAction {
     /// <p>The range key type. Valid values are "STRING" or "NUMBER"</p>
     #[serde(rename = "rangeKeyType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub range_key_type: Option<String>,
+    pub range_key_type: Option<DynamoKeyType>,
     /// <p>The range key value.</p>
     #[serde(rename = "rangeKeyValue")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3406,6 +5999,99 @@ pub struct DynamoDBv2Action {
     pub role_arn: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DynamoKeyType {
+    /// `STRING`
+    String,
+    /// `NUMBER`
+    Number,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DynamoKeyType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DynamoKeyType::String => "STRING",
+            DynamoKeyType::Number => "NUMBER",
+            DynamoKeyType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DynamoKeyType {
+    fn default() -> DynamoKeyType {
+        DynamoKeyType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DynamoKeyType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DynamoKeyType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DynamoKeyType {
+    fn from(value: &'a str) -> DynamoKeyType {
+        match value {
+            "STRING" => DynamoKeyType::String,
+            "NUMBER" => DynamoKeyType::Number,
+            _ => DynamoKeyType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DynamoKeyType {
+    fn from(value: String) -> DynamoKeyType {
+        match value.as_str() {
+            "STRING" => DynamoKeyType::String,
+            "NUMBER" => DynamoKeyType::Number,
+            _ => DynamoKeyType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DynamoKeyType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DynamoKeyType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DynamoKeyType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DynamoKeyType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DynamoKeyType {
+    fn deserialize<D>(deserializer: D) -> Result<DynamoKeyType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The policy that has the effect on the authorization results.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -3449,7 +6135,7 @@ pub struct ElasticsearchAction {
 pub struct EnableIoTLoggingParams {
     /// <p>Specifies the types of information to be logged.</p>
     #[serde(rename = "logLevel")]
-    pub log_level: String,
+    pub log_level: LogLevel,
     /// <p>The ARN of the IAM role used for logging.</p>
     #[serde(rename = "roleArnForLogging")]
     pub role_arn_for_logging: String,
@@ -3478,6 +6164,144 @@ pub struct ErrorInfo {
     pub message: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EventType {
+    /// `THING`
+    Thing,
+    /// `THING_GROUP`
+    ThingGroup,
+    /// `THING_TYPE`
+    ThingType,
+    /// `THING_GROUP_MEMBERSHIP`
+    ThingGroupMembership,
+    /// `THING_GROUP_HIERARCHY`
+    ThingGroupHierarchy,
+    /// `THING_TYPE_ASSOCIATION`
+    ThingTypeAssociation,
+    /// `JOB`
+    Job,
+    /// `JOB_EXECUTION`
+    JobExecution,
+    /// `POLICY`
+    Policy,
+    /// `CERTIFICATE`
+    Certificate,
+    /// `CA_CERTIFICATE`
+    CaCertificate,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EventType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EventType::Thing => "THING",
+            EventType::ThingGroup => "THING_GROUP",
+            EventType::ThingType => "THING_TYPE",
+            EventType::ThingGroupMembership => "THING_GROUP_MEMBERSHIP",
+            EventType::ThingGroupHierarchy => "THING_GROUP_HIERARCHY",
+            EventType::ThingTypeAssociation => "THING_TYPE_ASSOCIATION",
+            EventType::Job => "JOB",
+            EventType::JobExecution => "JOB_EXECUTION",
+            EventType::Policy => "POLICY",
+            EventType::Certificate => "CERTIFICATE",
+            EventType::CaCertificate => "CA_CERTIFICATE",
+            EventType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EventType {
+    fn default() -> EventType {
+        EventType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EventType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EventType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EventType {
+    fn from(value: &'a str) -> EventType {
+        match value {
+            "THING" => EventType::Thing,
+            "THING_GROUP" => EventType::ThingGroup,
+            "THING_TYPE" => EventType::ThingType,
+            "THING_GROUP_MEMBERSHIP" => EventType::ThingGroupMembership,
+            "THING_GROUP_HIERARCHY" => EventType::ThingGroupHierarchy,
+            "THING_TYPE_ASSOCIATION" => EventType::ThingTypeAssociation,
+            "JOB" => EventType::Job,
+            "JOB_EXECUTION" => EventType::JobExecution,
+            "POLICY" => EventType::Policy,
+            "CERTIFICATE" => EventType::Certificate,
+            "CA_CERTIFICATE" => EventType::CaCertificate,
+            _ => EventType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EventType {
+    fn from(value: String) -> EventType {
+        match value.as_str() {
+            "THING" => EventType::Thing,
+            "THING_GROUP" => EventType::ThingGroup,
+            "THING_TYPE" => EventType::ThingType,
+            "THING_GROUP_MEMBERSHIP" => EventType::ThingGroupMembership,
+            "THING_GROUP_HIERARCHY" => EventType::ThingGroupHierarchy,
+            "THING_TYPE_ASSOCIATION" => EventType::ThingTypeAssociation,
+            "JOB" => EventType::Job,
+            "JOB_EXECUTION" => EventType::JobExecution,
+            "POLICY" => EventType::Policy,
+            "CERTIFICATE" => EventType::Certificate,
+            "CA_CERTIFICATE" => EventType::CaCertificate,
+            _ => EventType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EventType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EventType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EventType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EventType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EventType {
+    fn deserialize<D>(deserializer: D) -> Result<EventType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information that explicitly denies authorization.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -3512,34 +6336,132 @@ pub struct Field {
     /// <p>The datatype of the field.</p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<FieldType>,
 }
 
-/// <p>The location of the OTA update.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct FileLocation {
-    /// <p>The location of the updated firmware in S3.</p>
-    #[serde(rename = "s3Location")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub s_3_location: Option<S3Location>,
-    /// <p>The stream that contains the OTA update.</p>
-    #[serde(rename = "stream")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub stream: Option<Stream>,
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FieldType {
+    /// `Number`
+    Number,
+    /// `String`
+    String,
+    /// `Boolean`
+    Boolean,
+    /// A value not known to this version of the crate
+    Unknown(String),
 }
 
-/// <p>Describes an action that writes data to an Amazon Kinesis Firehose stream.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct FirehoseAction {
-    /// <p>The delivery stream name.</p>
-    #[serde(rename = "deliveryStreamName")]
-    pub delivery_stream_name: String,
-    /// <p>The IAM role that grants access to the Amazon Kinesis Firehose stream.</p>
-    #[serde(rename = "roleArn")]
-    pub role_arn: String,
-    /// <p>A character separator that will be used to separate records written to the Firehose stream. Valid values are: '\n' (newline), '\t' (tab), '\r\n' (Windows newline), ',' (comma).</p>
-    #[serde(rename = "separator")]
-    #[serde(skip_serializing_if = "Option::is_none")]
+impl FieldType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FieldType::Number => "Number",
+            FieldType::String => "String",
+            FieldType::Boolean => "Boolean",
+            FieldType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for FieldType {
+    fn default() -> FieldType {
+        FieldType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for FieldType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FieldType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FieldType {
+    fn from(value: &'a str) -> FieldType {
+        match value {
+            "Number" => FieldType::Number,
+            "String" => FieldType::String,
+            "Boolean" => FieldType::Boolean,
+            _ => FieldType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FieldType {
+    fn from(value: String) -> FieldType {
+        match value.as_str() {
+            "Number" => FieldType::Number,
+            "String" => FieldType::String,
+            "Boolean" => FieldType::Boolean,
+            _ => FieldType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for FieldType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<FieldType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FieldType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FieldType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FieldType {
+    fn deserialize<D>(deserializer: D) -> Result<FieldType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>The location of the OTA update.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FileLocation {
+    /// <p>The location of the updated firmware in S3.</p>
+    #[serde(rename = "s3Location")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub s_3_location: Option<S3Location>,
+    /// <p>The stream that contains the OTA update.</p>
+    #[serde(rename = "stream")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stream: Option<Stream>,
+}
+
+/// <p>Describes an action that writes data to an Amazon Kinesis Firehose stream.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FirehoseAction {
+    /// <p>The delivery stream name.</p>
+    #[serde(rename = "deliveryStreamName")]
+    pub delivery_stream_name: String,
+    /// <p>The IAM role that grants access to the Amazon Kinesis Firehose stream.</p>
+    #[serde(rename = "roleArn")]
+    pub role_arn: String,
+    /// <p>A character separator that will be used to separate records written to the Firehose stream. Valid values are: '\n' (newline), '\t' (tab), '\r\n' (Windows newline), ',' (comma).</p>
+    #[serde(rename = "separator")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub separator: Option<String>,
 }
 
@@ -3644,7 +6566,7 @@ pub struct GetLoggingOptionsResponse {
     /// <p>The logging level.</p>
     #[serde(rename = "logLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub log_level: Option<String>,
+    pub log_level: Option<LogLevel>,
     /// <p>The ARN of the IAM role that grants access.</p>
     #[serde(rename = "roleArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3716,8 +6638,13 @@ pub struct GetPolicyRequest {
 pub struct GetPolicyResponse {
     /// <p>The date the policy was created.</p>
     #[serde(rename = "creationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The default policy version ID.</p>
     #[serde(rename = "defaultVersionId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3728,8 +6655,13 @@ pub struct GetPolicyResponse {
     pub generation_id: Option<String>,
     /// <p>The date the policy was last modified.</p>
     #[serde(rename = "lastModifiedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_date: Option<f64>,
+    pub last_modified_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The policy ARN.</p>
     #[serde(rename = "policyArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3762,8 +6694,13 @@ pub struct GetPolicyVersionRequest {
 pub struct GetPolicyVersionResponse {
     /// <p>The date the policy was created.</p>
     #[serde(rename = "creationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The generation ID of the policy version.</p>
     #[serde(rename = "generationId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3774,8 +6711,13 @@ pub struct GetPolicyVersionResponse {
     pub is_default_version: Option<bool>,
     /// <p>The date the policy was last modified.</p>
     #[serde(rename = "lastModifiedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_date: Option<f64>,
+    pub last_modified_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The policy ARN.</p>
     #[serde(rename = "policyArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3888,7 +6830,7 @@ pub struct GetV2LoggingOptionsResponse {
     /// <p>The default log level.</p>
     #[serde(rename = "defaultLogLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub default_log_level: Option<String>,
+    pub default_log_level: Option<LogLevel>,
     /// <p>Disables all logs.</p>
     #[serde(rename = "disableAllLogs")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4006,6 +6948,104 @@ pub struct ImplicitDeny {
     pub policies: Option<Vec<Policy>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum IndexStatus {
+    /// `ACTIVE`
+    Active,
+    /// `BUILDING`
+    Building,
+    /// `REBUILDING`
+    Rebuilding,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl IndexStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            IndexStatus::Active => "ACTIVE",
+            IndexStatus::Building => "BUILDING",
+            IndexStatus::Rebuilding => "REBUILDING",
+            IndexStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for IndexStatus {
+    fn default() -> IndexStatus {
+        IndexStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for IndexStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for IndexStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for IndexStatus {
+    fn from(value: &'a str) -> IndexStatus {
+        match value {
+            "ACTIVE" => IndexStatus::Active,
+            "BUILDING" => IndexStatus::Building,
+            "REBUILDING" => IndexStatus::Rebuilding,
+            _ => IndexStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for IndexStatus {
+    fn from(value: String) -> IndexStatus {
+        match value.as_str() {
+            "ACTIVE" => IndexStatus::Active,
+            "BUILDING" => IndexStatus::Building,
+            "REBUILDING" => IndexStatus::Rebuilding,
+            _ => IndexStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for IndexStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<IndexStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for IndexStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for IndexStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for IndexStatus {
+    fn deserialize<D>(deserializer: D) -> Result<IndexStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Sends message data to an AWS IoT Analytics channel.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct IotAnalyticsAction {
@@ -4063,12 +7103,22 @@ pub struct Job {
     pub comment: Option<String>,
     /// <p>The time, in seconds since the epoch, when the job was completed.</p>
     #[serde(rename = "completedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub completed_at: Option<f64>,
+    pub completed_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The time, in seconds since the epoch, when the job was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A short text description of the job.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4095,8 +7145,13 @@ pub struct Job {
     pub job_process_details: Option<JobProcessDetails>,
     /// <p>The time, in seconds since the epoch, when the job was last updated.</p>
     #[serde(rename = "lastUpdatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_at: Option<f64>,
+    pub last_updated_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Configuration for pre-signed S3 URLs.</p>
     #[serde(rename = "presignedUrlConfig")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4108,11 +7163,11 @@ pub struct Job {
     /// <p>The status of the job, one of <code>IN_PROGRESS</code>, <code>CANCELED</code>, <code>DELETION_IN_PROGRESS</code> or <code>COMPLETED</code>. </p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<JobStatus>,
     /// <p>Specifies whether the job will continue to run (CONTINUOUS), or will be complete after all those things specified as targets have completed the job (SNAPSHOT). If continuous, the job may also be run on a thing when a change is detected in a target. For example, a job will run on a device when the thing representing the device is added to a target group, even after the job was completed by all things originally in the group. </p>
     #[serde(rename = "targetSelection")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub target_selection: Option<String>,
+    pub target_selection: Option<TargetSelection>,
     /// <p>A list of IoT things and thing groups to which the job should be sent.</p>
     #[serde(rename = "targets")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4145,20 +7200,35 @@ pub struct JobExecution {
     pub job_id: Option<String>,
     /// <p>The time, in seconds since the epoch, when the job execution was last updated.</p>
     #[serde(rename = "lastUpdatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_at: Option<f64>,
+    pub last_updated_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The time, in seconds since the epoch, when the job execution was queued.</p>
     #[serde(rename = "queuedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub queued_at: Option<f64>,
+    pub queued_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The time, in seconds since the epoch, when the job execution started.</p>
     #[serde(rename = "startedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub started_at: Option<f64>,
+    pub started_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The status of the job execution (IN_PROGRESS, QUEUED, FAILED, SUCCEEDED, TIMED_OUT, CANCELED, or REJECTED).</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<JobExecutionStatus>,
     /// <p>A collection of name/value pairs that describe the status of the job execution.</p>
     #[serde(rename = "statusDetails")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4173,6 +7243,232 @@ pub struct JobExecution {
     pub version_number: Option<i64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum JobExecutionFailureType {
+    /// `FAILED`
+    Failed,
+    /// `REJECTED`
+    Rejected,
+    /// `TIMED_OUT`
+    TimedOut,
+    /// `ALL`
+    All,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl JobExecutionFailureType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            JobExecutionFailureType::Failed => "FAILED",
+            JobExecutionFailureType::Rejected => "REJECTED",
+            JobExecutionFailureType::TimedOut => "TIMED_OUT",
+            JobExecutionFailureType::All => "ALL",
+            JobExecutionFailureType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for JobExecutionFailureType {
+    fn default() -> JobExecutionFailureType {
+        JobExecutionFailureType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for JobExecutionFailureType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for JobExecutionFailureType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for JobExecutionFailureType {
+    fn from(value: &'a str) -> JobExecutionFailureType {
+        match value {
+            "FAILED" => JobExecutionFailureType::Failed,
+            "REJECTED" => JobExecutionFailureType::Rejected,
+            "TIMED_OUT" => JobExecutionFailureType::TimedOut,
+            "ALL" => JobExecutionFailureType::All,
+            _ => JobExecutionFailureType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for JobExecutionFailureType {
+    fn from(value: String) -> JobExecutionFailureType {
+        match value.as_str() {
+            "FAILED" => JobExecutionFailureType::Failed,
+            "REJECTED" => JobExecutionFailureType::Rejected,
+            "TIMED_OUT" => JobExecutionFailureType::TimedOut,
+            "ALL" => JobExecutionFailureType::All,
+            _ => JobExecutionFailureType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for JobExecutionFailureType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<JobExecutionFailureType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for JobExecutionFailureType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for JobExecutionFailureType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for JobExecutionFailureType {
+    fn deserialize<D>(deserializer: D) -> Result<JobExecutionFailureType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum JobExecutionStatus {
+    /// `QUEUED`
+    Queued,
+    /// `IN_PROGRESS`
+    InProgress,
+    /// `SUCCEEDED`
+    Succeeded,
+    /// `FAILED`
+    Failed,
+    /// `TIMED_OUT`
+    TimedOut,
+    /// `REJECTED`
+    Rejected,
+    /// `REMOVED`
+    Removed,
+    /// `CANCELED`
+    Canceled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl JobExecutionStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            JobExecutionStatus::Queued => "QUEUED",
+            JobExecutionStatus::InProgress => "IN_PROGRESS",
+            JobExecutionStatus::Succeeded => "SUCCEEDED",
+            JobExecutionStatus::Failed => "FAILED",
+            JobExecutionStatus::TimedOut => "TIMED_OUT",
+            JobExecutionStatus::Rejected => "REJECTED",
+            JobExecutionStatus::Removed => "REMOVED",
+            JobExecutionStatus::Canceled => "CANCELED",
+            JobExecutionStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for JobExecutionStatus {
+    fn default() -> JobExecutionStatus {
+        JobExecutionStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for JobExecutionStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for JobExecutionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for JobExecutionStatus {
+    fn from(value: &'a str) -> JobExecutionStatus {
+        match value {
+            "QUEUED" => JobExecutionStatus::Queued,
+            "IN_PROGRESS" => JobExecutionStatus::InProgress,
+            "SUCCEEDED" => JobExecutionStatus::Succeeded,
+            "FAILED" => JobExecutionStatus::Failed,
+            "TIMED_OUT" => JobExecutionStatus::TimedOut,
+            "REJECTED" => JobExecutionStatus::Rejected,
+            "REMOVED" => JobExecutionStatus::Removed,
+            "CANCELED" => JobExecutionStatus::Canceled,
+            _ => JobExecutionStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for JobExecutionStatus {
+    fn from(value: String) -> JobExecutionStatus {
+        match value.as_str() {
+            "QUEUED" => JobExecutionStatus::Queued,
+            "IN_PROGRESS" => JobExecutionStatus::InProgress,
+            "SUCCEEDED" => JobExecutionStatus::Succeeded,
+            "FAILED" => JobExecutionStatus::Failed,
+            "TIMED_OUT" => JobExecutionStatus::TimedOut,
+            "REJECTED" => JobExecutionStatus::Rejected,
+            "REMOVED" => JobExecutionStatus::Removed,
+            "CANCELED" => JobExecutionStatus::Canceled,
+            _ => JobExecutionStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for JobExecutionStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<JobExecutionStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for JobExecutionStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for JobExecutionStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for JobExecutionStatus {
+    fn deserialize<D>(deserializer: D) -> Result<JobExecutionStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Details of the job execution status.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -4193,20 +7489,35 @@ pub struct JobExecutionSummary {
     pub execution_number: Option<i64>,
     /// <p>The time, in seconds since the epoch, when the job execution was last updated.</p>
     #[serde(rename = "lastUpdatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_at: Option<f64>,
+    pub last_updated_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The time, in seconds since the epoch, when the job execution was queued.</p>
     #[serde(rename = "queuedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub queued_at: Option<f64>,
+    pub queued_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The time, in seconds since the epoch, when the job execution started.</p>
     #[serde(rename = "startedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub started_at: Option<f64>,
+    pub started_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The status of the job execution.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<JobExecutionStatus>,
 }
 
 /// <p>Contains a summary of information about job executions for a specific job.</p>
@@ -4292,46 +7603,169 @@ pub struct JobProcessDetails {
     pub processing_targets: Option<Vec<String>>,
 }
 
-/// <p>The job summary.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct JobSummary {
-    /// <p>The time, in seconds since the epoch, when the job completed.</p>
-    #[serde(rename = "completedAt")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub completed_at: Option<f64>,
-    /// <p>The time, in seconds since the epoch, when the job was created.</p>
-    #[serde(rename = "createdAt")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
-    /// <p>The job ARN.</p>
-    #[serde(rename = "jobArn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_arn: Option<String>,
-    /// <p>The unique identifier you assigned to this job when it was created.</p>
-    #[serde(rename = "jobId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_id: Option<String>,
-    /// <p>The time, in seconds since the epoch, when the job was last updated.</p>
-    #[serde(rename = "lastUpdatedAt")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_at: Option<f64>,
-    /// <p>The job summary status.</p>
-    #[serde(rename = "status")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
-    /// <p>Specifies whether the job will continue to run (CONTINUOUS), or will be complete after all those things specified as targets have completed the job (SNAPSHOT). If continuous, the job may also be run on a thing when a change is detected in a target. For example, a job will run on a thing when the thing is added to a target group, even after the job was completed by all things originally in the group.</p>
-    #[serde(rename = "targetSelection")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub target_selection: Option<String>,
-    /// <p>The ID of the thing group.</p>
-    #[serde(rename = "thingGroupId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub thing_group_id: Option<String>,
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum JobStatus {
+    /// `IN_PROGRESS`
+    InProgress,
+    /// `CANCELED`
+    Canceled,
+    /// `COMPLETED`
+    Completed,
+    /// `DELETION_IN_PROGRESS`
+    DeletionInProgress,
+    /// `SCHEDULED`
+    Scheduled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl JobStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            JobStatus::InProgress => "IN_PROGRESS",
+            JobStatus::Canceled => "CANCELED",
+            JobStatus::Completed => "COMPLETED",
+            JobStatus::DeletionInProgress => "DELETION_IN_PROGRESS",
+            JobStatus::Scheduled => "SCHEDULED",
+            JobStatus::Unknown(ref value) => value,
+        }
+    }
 }
 
-/// <p>Describes a key pair.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for JobStatus {
+    fn default() -> JobStatus {
+        JobStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for JobStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for JobStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for JobStatus {
+    fn from(value: &'a str) -> JobStatus {
+        match value {
+            "IN_PROGRESS" => JobStatus::InProgress,
+            "CANCELED" => JobStatus::Canceled,
+            "COMPLETED" => JobStatus::Completed,
+            "DELETION_IN_PROGRESS" => JobStatus::DeletionInProgress,
+            "SCHEDULED" => JobStatus::Scheduled,
+            _ => JobStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for JobStatus {
+    fn from(value: String) -> JobStatus {
+        match value.as_str() {
+            "IN_PROGRESS" => JobStatus::InProgress,
+            "CANCELED" => JobStatus::Canceled,
+            "COMPLETED" => JobStatus::Completed,
+            "DELETION_IN_PROGRESS" => JobStatus::DeletionInProgress,
+            "SCHEDULED" => JobStatus::Scheduled,
+            _ => JobStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for JobStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<JobStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for JobStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for JobStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for JobStatus {
+    fn deserialize<D>(deserializer: D) -> Result<JobStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>The job summary.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct JobSummary {
+    /// <p>The time, in seconds since the epoch, when the job completed.</p>
+    #[serde(rename = "completedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<::chrono::DateTime<::chrono::Utc>>,
+    /// <p>The time, in seconds since the epoch, when the job was created.</p>
+    #[serde(rename = "createdAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
+    /// <p>The job ARN.</p>
+    #[serde(rename = "jobArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub job_arn: Option<String>,
+    /// <p>The unique identifier you assigned to this job when it was created.</p>
+    #[serde(rename = "jobId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub job_id: Option<String>,
+    /// <p>The time, in seconds since the epoch, when the job was last updated.</p>
+    #[serde(rename = "lastUpdatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_updated_at: Option<::chrono::DateTime<::chrono::Utc>>,
+    /// <p>The job summary status.</p>
+    #[serde(rename = "status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<JobStatus>,
+    /// <p>Specifies whether the job will continue to run (CONTINUOUS), or will be complete after all those things specified as targets have completed the job (SNAPSHOT). If continuous, the job may also be run on a thing when a change is detected in a target. For example, a job will run on a thing when the thing is added to a target group, even after the job was completed by all things originally in the group.</p>
+    #[serde(rename = "targetSelection")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_selection: Option<TargetSelection>,
+    /// <p>The ID of the thing group.</p>
+    #[serde(rename = "thingGroupId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thing_group_id: Option<String>,
+}
+
+/// <p>Describes a key pair.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct KeyPair {
     /// <p>The private key.</p>
@@ -4443,8 +7877,13 @@ pub struct ListAuditFindingsRequest {
     pub check_name: Option<String>,
     /// <p>A filter to limit results to those found before the specified time. You must specify either the startTime and endTime or the taskId, but not both.</p>
     #[serde(rename = "endTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The maximum number of results to return at one time. The default is 25.</p>
     #[serde(rename = "maxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4459,8 +7898,13 @@ pub struct ListAuditFindingsRequest {
     pub resource_identifier: Option<ResourceIdentifier>,
     /// <p>A filter to limit results to those found after the specified time. You must specify either the startTime and endTime or the taskId, but not both.</p>
     #[serde(rename = "startTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A filter to limit results to the audit with the specified ID. You must specify either the taskId or the startTime and endTime, but not both.</p>
     #[serde(rename = "taskId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4486,7 +7930,7 @@ pub struct ListAuditMitigationActionsExecutionsRequest {
     /// <p>Specify this filter to limit results to those with a specific status.</p>
     #[serde(rename = "actionStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub action_status: Option<String>,
+    pub action_status: Option<AuditMitigationActionsExecutionStatus>,
     /// <p>Specify this filter to limit results to those that were applied to a specific audit finding.</p>
     #[serde(rename = "findingId")]
     pub finding_id: String,
@@ -4525,7 +7969,12 @@ pub struct ListAuditMitigationActionsTasksRequest {
     pub audit_task_id: Option<String>,
     /// <p>Specify this filter to limit results to tasks that were completed or canceled on or before a specific date and time.</p>
     #[serde(rename = "endTime")]
-    pub end_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
+    pub end_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>Specify this filter to limit results to tasks that were applied to a specific audit finding.</p>
     #[serde(rename = "findingId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4540,11 +7989,16 @@ pub struct ListAuditMitigationActionsTasksRequest {
     pub next_token: Option<String>,
     /// <p>Specify this filter to limit results to tasks that began on or after a specific date and time.</p>
     #[serde(rename = "startTime")]
-    pub start_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
+    pub start_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>Specify this filter to limit results to tasks that are in a specific state.</p>
     #[serde(rename = "taskStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub task_status: Option<String>,
+    pub task_status: Option<AuditMitigationActionsTaskStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -4565,7 +8019,12 @@ pub struct ListAuditMitigationActionsTasksResponse {
 pub struct ListAuditTasksRequest {
     /// <p>The end of the time period.</p>
     #[serde(rename = "endTime")]
-    pub end_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
+    pub end_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The maximum number of results to return at one time. The default is 25.</p>
     #[serde(rename = "maxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4576,15 +8035,20 @@ pub struct ListAuditTasksRequest {
     pub next_token: Option<String>,
     /// <p>The beginning of the time period. Audit information is retained for a limited time (180 days). Requesting a start time prior to what is retained results in an "InvalidRequestException".</p>
     #[serde(rename = "startTime")]
-    pub start_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
+    pub start_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>A filter to limit the output to audits with the specified completion status: can be one of "IN_PROGRESS", "COMPLETED", "FAILED", or "CANCELED".</p>
     #[serde(rename = "taskStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub task_status: Option<String>,
+    pub task_status: Option<AuditTaskStatus>,
     /// <p>A filter to limit the output to the specified type of audit: can be one of "ON_DEMAND_AUDIT_TASK" or "SCHEDULED__AUDIT_TASK".</p>
     #[serde(rename = "taskType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub task_type: Option<String>,
+    pub task_type: Option<AuditTaskType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -4618,7 +8082,7 @@ pub struct ListAuthorizersRequest {
     /// <p>The status of the list authorizers request.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<AuthorizerStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -4777,7 +8241,7 @@ pub struct ListDomainConfigurationsRequest {
     /// <p>The type of service delivered by the endpoint.</p>
     #[serde(rename = "serviceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub service_type: Option<String>,
+    pub service_type: Option<ServiceType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -4836,7 +8300,7 @@ pub struct ListJobExecutionsForJobRequest {
     /// <p>The status of the job.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<JobExecutionStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -4866,7 +8330,7 @@ pub struct ListJobExecutionsForThingRequest {
     /// <p>An optional filter that lets you search for jobs that have the specified status.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<JobExecutionStatus>,
     /// <p>The thing name.</p>
     #[serde(rename = "thingName")]
     pub thing_name: String,
@@ -4899,11 +8363,11 @@ pub struct ListJobsRequest {
     /// <p>An optional filter that lets you search for jobs that have the specified status.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<JobStatus>,
     /// <p>Specifies whether the job will continue to run (CONTINUOUS), or will be complete after all those things specified as targets have completed the job (SNAPSHOT). If continuous, the job may also be run on a thing when a change is detected in a target. For example, a job will run on a thing when the thing is added to a target group, even after the job was completed by all things originally in the group. </p>
     #[serde(rename = "targetSelection")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub target_selection: Option<String>,
+    pub target_selection: Option<TargetSelection>,
     /// <p>A filter that limits the returned jobs to those for the specified group.</p>
     #[serde(rename = "thingGroupId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4933,7 +8397,7 @@ pub struct ListMitigationActionsRequest {
     /// <p>Specify a value to limit the result to mitigation actions with a specific action type.</p>
     #[serde(rename = "actionType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub action_type: Option<String>,
+    pub action_type: Option<MitigationActionType>,
     /// <p>The maximum number of results to return at one time. The default is 25.</p>
     #[serde(rename = "maxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4971,7 +8435,7 @@ pub struct ListOTAUpdatesRequest {
     /// <p>The OTA update job status.</p>
     #[serde(rename = "otaUpdateStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ota_update_status: Option<String>,
+    pub ota_update_status: Option<OTAUpdateStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -5553,7 +9017,7 @@ pub struct ListThingRegistrationTaskReportsRequest {
     pub next_token: Option<String>,
     /// <p>The type of task report.</p>
     #[serde(rename = "reportType")]
-    pub report_type: String,
+    pub report_type: ReportType,
     /// <p>The id of the task.</p>
     #[serde(rename = "taskId")]
     pub task_id: String,
@@ -5569,7 +9033,7 @@ pub struct ListThingRegistrationTaskReportsResponse {
     /// <p>The type of task report.</p>
     #[serde(rename = "reportType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub report_type: Option<String>,
+    pub report_type: Option<ReportType>,
     /// <p>Links to the task resources.</p>
     #[serde(rename = "resourceLinks")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -5590,7 +9054,7 @@ pub struct ListThingRegistrationTasksRequest {
     /// <p>The status of the bulk thing provisioning task.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<Status>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -5816,7 +9280,7 @@ pub struct ListV2LoggingLevelsRequest {
     /// <p>The type of resource for which you are configuring logging. Must be <code>THING_Group</code>.</p>
     #[serde(rename = "targetType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub target_type: Option<String>,
+    pub target_type: Option<LogTargetType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -5837,7 +9301,12 @@ pub struct ListV2LoggingLevelsResponse {
 pub struct ListViolationEventsRequest {
     /// <p>The end time for the alerts to be listed.</p>
     #[serde(rename = "endTime")]
-    pub end_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
+    pub end_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The maximum number of results to return at one time.</p>
     #[serde(rename = "maxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -5852,7 +9321,12 @@ pub struct ListViolationEventsRequest {
     pub security_profile_name: Option<String>,
     /// <p>The start time for the alerts to be listed.</p>
     #[serde(rename = "startTime")]
-    pub start_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
+    pub start_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>A filter to limit results to those alerts caused by the specified thing.</p>
     #[serde(rename = "thingName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -5872,6 +9346,114 @@ pub struct ListViolationEventsResponse {
     pub violation_events: Option<Vec<ViolationEvent>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LogLevel {
+    /// `DEBUG`
+    Debug,
+    /// `INFO`
+    Info,
+    /// `ERROR`
+    Error,
+    /// `WARN`
+    Warn,
+    /// `DISABLED`
+    Disabled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LogLevel {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LogLevel::Debug => "DEBUG",
+            LogLevel::Info => "INFO",
+            LogLevel::Error => "ERROR",
+            LogLevel::Warn => "WARN",
+            LogLevel::Disabled => "DISABLED",
+            LogLevel::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LogLevel {
+    fn default() -> LogLevel {
+        LogLevel::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LogLevel {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LogLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LogLevel {
+    fn from(value: &'a str) -> LogLevel {
+        match value {
+            "DEBUG" => LogLevel::Debug,
+            "INFO" => LogLevel::Info,
+            "ERROR" => LogLevel::Error,
+            "WARN" => LogLevel::Warn,
+            "DISABLED" => LogLevel::Disabled,
+            _ => LogLevel::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LogLevel {
+    fn from(value: String) -> LogLevel {
+        match value.as_str() {
+            "DEBUG" => LogLevel::Debug,
+            "INFO" => LogLevel::Info,
+            "ERROR" => LogLevel::Error,
+            "WARN" => LogLevel::Warn,
+            "DISABLED" => LogLevel::Disabled,
+            _ => LogLevel::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LogLevel {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LogLevel, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LogLevel {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LogLevel {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LogLevel {
+    fn deserialize<D>(deserializer: D) -> Result<LogLevel, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A log target.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct LogTarget {
@@ -5881,7 +9463,7 @@ pub struct LogTarget {
     pub target_name: Option<String>,
     /// <p>The target type.</p>
     #[serde(rename = "targetType")]
-    pub target_type: String,
+    pub target_type: LogTargetType,
 }
 
 /// <p>The target configuration.</p>
@@ -5891,13 +9473,121 @@ pub struct LogTargetConfiguration {
     /// <p>The logging level.</p>
     #[serde(rename = "logLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub log_level: Option<String>,
+    pub log_level: Option<LogLevel>,
     /// <p>A log target</p>
     #[serde(rename = "logTarget")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub log_target: Option<LogTarget>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LogTargetType {
+    /// `DEFAULT`
+    Default,
+    /// `THING_GROUP`
+    ThingGroup,
+    /// `CLIENT_ID`
+    ClientId,
+    /// `SOURCE_IP`
+    SourceIp,
+    /// `PRINCIPAL_ID`
+    PrincipalId,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LogTargetType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LogTargetType::Default => "DEFAULT",
+            LogTargetType::ThingGroup => "THING_GROUP",
+            LogTargetType::ClientId => "CLIENT_ID",
+            LogTargetType::SourceIp => "SOURCE_IP",
+            LogTargetType::PrincipalId => "PRINCIPAL_ID",
+            LogTargetType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LogTargetType {
+    fn default() -> LogTargetType {
+        LogTargetType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LogTargetType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LogTargetType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LogTargetType {
+    fn from(value: &'a str) -> LogTargetType {
+        match value {
+            "DEFAULT" => LogTargetType::Default,
+            "THING_GROUP" => LogTargetType::ThingGroup,
+            "CLIENT_ID" => LogTargetType::ClientId,
+            "SOURCE_IP" => LogTargetType::SourceIp,
+            "PRINCIPAL_ID" => LogTargetType::PrincipalId,
+            _ => LogTargetType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LogTargetType {
+    fn from(value: String) -> LogTargetType {
+        match value.as_str() {
+            "DEFAULT" => LogTargetType::Default,
+            "THING_GROUP" => LogTargetType::ThingGroup,
+            "CLIENT_ID" => LogTargetType::ClientId,
+            "SOURCE_IP" => LogTargetType::SourceIp,
+            "PRINCIPAL_ID" => LogTargetType::PrincipalId,
+            _ => LogTargetType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LogTargetType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LogTargetType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LogTargetType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LogTargetType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LogTargetType {
+    fn deserialize<D>(deserializer: D) -> Result<LogTargetType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes the logging options payload.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -5905,12 +9595,105 @@ pub struct LoggingOptionsPayload {
     /// <p>The log level.</p>
     #[serde(rename = "logLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub log_level: Option<String>,
+    pub log_level: Option<LogLevel>,
     /// <p>The ARN of the IAM role that grants access.</p>
     #[serde(rename = "roleArn")]
     pub role_arn: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MessageFormat {
+    /// `RAW`
+    Raw,
+    /// `JSON`
+    Json,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl MessageFormat {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MessageFormat::Raw => "RAW",
+            MessageFormat::Json => "JSON",
+            MessageFormat::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for MessageFormat {
+    fn default() -> MessageFormat {
+        MessageFormat::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for MessageFormat {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for MessageFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for MessageFormat {
+    fn from(value: &'a str) -> MessageFormat {
+        match value {
+            "RAW" => MessageFormat::Raw,
+            "JSON" => MessageFormat::Json,
+            _ => MessageFormat::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MessageFormat {
+    fn from(value: String) -> MessageFormat {
+        match value.as_str() {
+            "RAW" => MessageFormat::Raw,
+            "JSON" => MessageFormat::Json,
+            _ => MessageFormat::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for MessageFormat {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<MessageFormat, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MessageFormat {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for MessageFormat {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MessageFormat {
+    fn deserialize<D>(deserializer: D) -> Result<MessageFormat, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The value to be compared with the <code>metric</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MetricValue {
@@ -5964,8 +9747,13 @@ pub struct MitigationActionIdentifier {
     pub action_name: Option<String>,
     /// <p>The date when this mitigation action was created.</p>
     #[serde(rename = "creationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>The set of parameters for this mitigation action. You can specify only one type of parameter (in other words, you can apply only one action for each defined mitigation action).</p>
@@ -5997,37 +9785,150 @@ pub struct MitigationActionParams {
     pub update_device_certificate_params: Option<UpdateDeviceCertificateParams>,
 }
 
-/// <p>Specifies the MQTT context to use for the test authorizer request</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct MqttContext {
-    /// <p>The value of the <code>clientId</code> key in an MQTT authorization request.</p>
-    #[serde(rename = "clientId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub client_id: Option<String>,
-    /// <p>The value of the <code>password</code> key in an MQTT authorization request.</p>
-    #[serde(rename = "password")]
-    #[serde(
-        deserialize_with = "::rusoto_core::serialization::SerdeBlob::deserialize_blob",
-        serialize_with = "::rusoto_core::serialization::SerdeBlob::serialize_blob",
-        default
-    )]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub password: Option<bytes::Bytes>,
-    /// <p>The value of the <code>username</code> key in an MQTT authorization request.</p>
-    #[serde(rename = "username")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub username: Option<String>,
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MitigationActionType {
+    /// `UPDATE_DEVICE_CERTIFICATE`
+    UpdateDeviceCertificate,
+    /// `UPDATE_CA_CERTIFICATE`
+    UpdateCaCertificate,
+    /// `ADD_THINGS_TO_THING_GROUP`
+    AddThingsToThingGroup,
+    /// `REPLACE_DEFAULT_POLICY_VERSION`
+    ReplaceDefaultPolicyVersion,
+    /// `ENABLE_IOT_LOGGING`
+    EnableIotLogging,
+    /// `PUBLISH_FINDING_TO_SNS`
+    PublishFindingToSns,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl MitigationActionType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MitigationActionType::UpdateDeviceCertificate => "UPDATE_DEVICE_CERTIFICATE",
+            MitigationActionType::UpdateCaCertificate => "UPDATE_CA_CERTIFICATE",
+            MitigationActionType::AddThingsToThingGroup => "ADD_THINGS_TO_THING_GROUP",
+            MitigationActionType::ReplaceDefaultPolicyVersion => "REPLACE_DEFAULT_POLICY_VERSION",
+            MitigationActionType::EnableIotLogging => "ENABLE_IOT_LOGGING",
+            MitigationActionType::PublishFindingToSns => "PUBLISH_FINDING_TO_SNS",
+            MitigationActionType::Unknown(ref value) => value,
+        }
+    }
 }
 
-/// <p>Information about the resource that was noncompliant with the audit check.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct NonCompliantResource {
-    /// <p>Other information about the noncompliant resource.</p>
-    #[serde(rename = "additionalInfo")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub additional_info: Option<::std::collections::HashMap<String, String>>,
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for MitigationActionType {
+    fn default() -> MitigationActionType {
+        MitigationActionType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for MitigationActionType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for MitigationActionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for MitigationActionType {
+    fn from(value: &'a str) -> MitigationActionType {
+        match value {
+            "UPDATE_DEVICE_CERTIFICATE" => MitigationActionType::UpdateDeviceCertificate,
+            "UPDATE_CA_CERTIFICATE" => MitigationActionType::UpdateCaCertificate,
+            "ADD_THINGS_TO_THING_GROUP" => MitigationActionType::AddThingsToThingGroup,
+            "REPLACE_DEFAULT_POLICY_VERSION" => MitigationActionType::ReplaceDefaultPolicyVersion,
+            "ENABLE_IOT_LOGGING" => MitigationActionType::EnableIotLogging,
+            "PUBLISH_FINDING_TO_SNS" => MitigationActionType::PublishFindingToSns,
+            _ => MitigationActionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MitigationActionType {
+    fn from(value: String) -> MitigationActionType {
+        match value.as_str() {
+            "UPDATE_DEVICE_CERTIFICATE" => MitigationActionType::UpdateDeviceCertificate,
+            "UPDATE_CA_CERTIFICATE" => MitigationActionType::UpdateCaCertificate,
+            "ADD_THINGS_TO_THING_GROUP" => MitigationActionType::AddThingsToThingGroup,
+            "REPLACE_DEFAULT_POLICY_VERSION" => MitigationActionType::ReplaceDefaultPolicyVersion,
+            "ENABLE_IOT_LOGGING" => MitigationActionType::EnableIotLogging,
+            "PUBLISH_FINDING_TO_SNS" => MitigationActionType::PublishFindingToSns,
+            _ => MitigationActionType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for MitigationActionType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<MitigationActionType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MitigationActionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for MitigationActionType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MitigationActionType {
+    fn deserialize<D>(deserializer: D) -> Result<MitigationActionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Specifies the MQTT context to use for the test authorizer request</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct MqttContext {
+    /// <p>The value of the <code>clientId</code> key in an MQTT authorization request.</p>
+    #[serde(rename = "clientId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+    /// <p>The value of the <code>password</code> key in an MQTT authorization request.</p>
+    #[serde(rename = "password")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeBlob::deserialize_blob",
+        serialize_with = "::rusoto_core::serialization::SerdeBlob::serialize_blob",
+        default
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<bytes::Bytes>,
+    /// <p>The value of the <code>username</code> key in an MQTT authorization request.</p>
+    #[serde(rename = "username")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+}
+
+/// <p>Information about the resource that was noncompliant with the audit check.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct NonCompliantResource {
+    /// <p>Other information about the noncompliant resource.</p>
+    #[serde(rename = "additionalInfo")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub additional_info: Option<::std::collections::HashMap<String, String>>,
     /// <p>Information that identifies the noncompliant resource.</p>
     #[serde(rename = "resourceIdentifier")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -6035,7 +9936,7 @@ pub struct NonCompliantResource {
     /// <p>The type of the noncompliant resource.</p>
     #[serde(rename = "resourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ResourceType>,
 }
 
 /// <p>Describes a file to be associated with an OTA update.</p>
@@ -6089,8 +9990,13 @@ pub struct OTAUpdateInfo {
     pub aws_job_presigned_url_config: Option<AwsJobPresignedUrlConfig>,
     /// <p>The date when the OTA update was created.</p>
     #[serde(rename = "creationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A description of the OTA update.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -6101,8 +10007,13 @@ pub struct OTAUpdateInfo {
     pub error_info: Option<ErrorInfo>,
     /// <p>The date when the OTA update was last updated.</p>
     #[serde(rename = "lastModifiedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_date: Option<f64>,
+    pub last_modified_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The OTA update ARN.</p>
     #[serde(rename = "otaUpdateArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -6118,29 +10029,147 @@ pub struct OTAUpdateInfo {
     /// <p>The status of the OTA update.</p>
     #[serde(rename = "otaUpdateStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ota_update_status: Option<String>,
+    pub ota_update_status: Option<OTAUpdateStatus>,
     /// <p>The protocol used to transfer the OTA update image. Valid values are [HTTP], [MQTT], [HTTP, MQTT]. When both HTTP and MQTT are specified, the target device can choose the protocol.</p>
     #[serde(rename = "protocols")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub protocols: Option<Vec<String>>,
+    pub protocols: Option<Vec<Protocol>>,
     /// <p>Specifies whether the OTA update will continue to run (CONTINUOUS), or will be complete after all those things specified as targets have completed the OTA update (SNAPSHOT). If continuous, the OTA update may also be run on a thing when a change is detected in a target. For example, an OTA update will run on a thing when the thing is added to a target group, even after the OTA update was completed by all things originally in the group. </p>
     #[serde(rename = "targetSelection")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub target_selection: Option<String>,
+    pub target_selection: Option<TargetSelection>,
     /// <p>The targets of the OTA update.</p>
     #[serde(rename = "targets")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub targets: Option<Vec<String>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OTAUpdateStatus {
+    /// `CREATE_PENDING`
+    CreatePending,
+    /// `CREATE_IN_PROGRESS`
+    CreateInProgress,
+    /// `CREATE_COMPLETE`
+    CreateComplete,
+    /// `CREATE_FAILED`
+    CreateFailed,
+    /// `DELETE_IN_PROGRESS`
+    DeleteInProgress,
+    /// `DELETE_FAILED`
+    DeleteFailed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl OTAUpdateStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OTAUpdateStatus::CreatePending => "CREATE_PENDING",
+            OTAUpdateStatus::CreateInProgress => "CREATE_IN_PROGRESS",
+            OTAUpdateStatus::CreateComplete => "CREATE_COMPLETE",
+            OTAUpdateStatus::CreateFailed => "CREATE_FAILED",
+            OTAUpdateStatus::DeleteInProgress => "DELETE_IN_PROGRESS",
+            OTAUpdateStatus::DeleteFailed => "DELETE_FAILED",
+            OTAUpdateStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for OTAUpdateStatus {
+    fn default() -> OTAUpdateStatus {
+        OTAUpdateStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for OTAUpdateStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for OTAUpdateStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for OTAUpdateStatus {
+    fn from(value: &'a str) -> OTAUpdateStatus {
+        match value {
+            "CREATE_PENDING" => OTAUpdateStatus::CreatePending,
+            "CREATE_IN_PROGRESS" => OTAUpdateStatus::CreateInProgress,
+            "CREATE_COMPLETE" => OTAUpdateStatus::CreateComplete,
+            "CREATE_FAILED" => OTAUpdateStatus::CreateFailed,
+            "DELETE_IN_PROGRESS" => OTAUpdateStatus::DeleteInProgress,
+            "DELETE_FAILED" => OTAUpdateStatus::DeleteFailed,
+            _ => OTAUpdateStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OTAUpdateStatus {
+    fn from(value: String) -> OTAUpdateStatus {
+        match value.as_str() {
+            "CREATE_PENDING" => OTAUpdateStatus::CreatePending,
+            "CREATE_IN_PROGRESS" => OTAUpdateStatus::CreateInProgress,
+            "CREATE_COMPLETE" => OTAUpdateStatus::CreateComplete,
+            "CREATE_FAILED" => OTAUpdateStatus::CreateFailed,
+            "DELETE_IN_PROGRESS" => OTAUpdateStatus::DeleteInProgress,
+            "DELETE_FAILED" => OTAUpdateStatus::DeleteFailed,
+            _ => OTAUpdateStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for OTAUpdateStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<OTAUpdateStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OTAUpdateStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for OTAUpdateStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OTAUpdateStatus {
+    fn deserialize<D>(deserializer: D) -> Result<OTAUpdateStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An OTA update summary.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct OTAUpdateSummary {
     /// <p>The date when the OTA update was created.</p>
     #[serde(rename = "creationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The OTA update ARN.</p>
     #[serde(rename = "otaUpdateArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -6165,12 +10194,22 @@ pub struct OutgoingCertificate {
     pub certificate_id: Option<String>,
     /// <p>The certificate creation date.</p>
     #[serde(rename = "creationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The date the transfer was initiated.</p>
     #[serde(rename = "transferDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub transfer_date: Option<f64>,
+    pub transfer_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The transfer message.</p>
     #[serde(rename = "transferMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -6209,14 +10248,107 @@ pub struct Policy {
     pub policy_name: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PolicyTemplateName {
+    /// `BLANK_POLICY`
+    BlankPolicy,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PolicyTemplateName {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PolicyTemplateName::BlankPolicy => "BLANK_POLICY",
+            PolicyTemplateName::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PolicyTemplateName {
+    fn default() -> PolicyTemplateName {
+        PolicyTemplateName::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PolicyTemplateName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PolicyTemplateName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PolicyTemplateName {
+    fn from(value: &'a str) -> PolicyTemplateName {
+        match value {
+            "BLANK_POLICY" => PolicyTemplateName::BlankPolicy,
+            _ => PolicyTemplateName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PolicyTemplateName {
+    fn from(value: String) -> PolicyTemplateName {
+        match value.as_str() {
+            "BLANK_POLICY" => PolicyTemplateName::BlankPolicy,
+            _ => PolicyTemplateName::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PolicyTemplateName {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PolicyTemplateName, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PolicyTemplateName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PolicyTemplateName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PolicyTemplateName {
+    fn deserialize<D>(deserializer: D) -> Result<PolicyTemplateName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes a policy version.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PolicyVersion {
     /// <p>The date and time the policy was created.</p>
     #[serde(rename = "createDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub create_date: Option<f64>,
+    pub create_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Specifies whether the policy version is the default.</p>
     #[serde(rename = "isDefaultVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -6253,14 +10385,112 @@ pub struct PresignedUrlConfig {
     pub role_arn: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Protocol {
+    /// `MQTT`
+    Mqtt,
+    /// `HTTP`
+    Http,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Protocol {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Protocol::Mqtt => "MQTT",
+            Protocol::Http => "HTTP",
+            Protocol::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Protocol {
+    fn default() -> Protocol {
+        Protocol::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Protocol {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Protocol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Protocol {
+    fn from(value: &'a str) -> Protocol {
+        match value {
+            "MQTT" => Protocol::Mqtt,
+            "HTTP" => Protocol::Http,
+            _ => Protocol::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Protocol {
+    fn from(value: String) -> Protocol {
+        match value.as_str() {
+            "MQTT" => Protocol::Mqtt,
+            "HTTP" => Protocol::Http,
+            _ => Protocol::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Protocol {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Protocol, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Protocol {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Protocol {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Protocol {
+    fn deserialize<D>(deserializer: D) -> Result<Protocol, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A summary of information about a fleet provisioning template.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ProvisioningTemplateSummary {
     /// <p>The date when the fleet provisioning template summary was created.</p>
     #[serde(rename = "creationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The description of the fleet provisioning template.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -6271,8 +10501,13 @@ pub struct ProvisioningTemplateSummary {
     pub enabled: Option<bool>,
     /// <p>The date when the fleet provisioning template summary was last modified.</p>
     #[serde(rename = "lastModifiedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_date: Option<f64>,
+    pub last_modified_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The ARN of the fleet provisioning template.</p>
     #[serde(rename = "templateArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -6289,8 +10524,13 @@ pub struct ProvisioningTemplateSummary {
 pub struct ProvisioningTemplateVersionSummary {
     /// <p>The date when the fleet provisioning template version was created</p>
     #[serde(rename = "creationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>True if the fleet provisioning template version is the default version, otherwise false.</p>
     #[serde(rename = "isDefaultVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -6406,7 +10646,7 @@ pub struct RegisterCertificateRequest {
     /// <p>The status of the register certificate request.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<CertificateStatus>,
 }
 
 /// <p>The output from the RegisterCertificate operation.</p>
@@ -6489,7 +10729,7 @@ pub struct RelatedResource {
     /// <p>The type of resource.</p>
     #[serde(rename = "resourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ResourceType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -6547,7 +10787,7 @@ pub struct RemoveThingFromThingGroupResponse {}
 pub struct ReplaceDefaultPolicyVersionParams {
     /// <p>The name of the template to be applied. The only supported value is <code>BLANK_POLICY</code>.</p>
     #[serde(rename = "templateName")]
-    pub template_name: String,
+    pub template_name: PolicyTemplateName,
 }
 
 /// <p>The input for the ReplaceTopicRule operation.</p>
@@ -6562,6 +10802,99 @@ pub struct ReplaceTopicRuleRequest {
     pub topic_rule_payload: TopicRulePayload,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ReportType {
+    /// `ERRORS`
+    Errors,
+    /// `RESULTS`
+    Results,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ReportType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReportType::Errors => "ERRORS",
+            ReportType::Results => "RESULTS",
+            ReportType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ReportType {
+    fn default() -> ReportType {
+        ReportType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ReportType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ReportType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ReportType {
+    fn from(value: &'a str) -> ReportType {
+        match value {
+            "ERRORS" => ReportType::Errors,
+            "RESULTS" => ReportType::Results,
+            _ => ReportType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReportType {
+    fn from(value: String) -> ReportType {
+        match value.as_str() {
+            "ERRORS" => ReportType::Errors,
+            "RESULTS" => ReportType::Results,
+            _ => ReportType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ReportType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ReportType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ReportType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ReportType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ReportType {
+    fn deserialize<D>(deserializer: D) -> Result<ReportType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes an action to republish to another topic.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RepublishAction {
@@ -6614,22 +10947,160 @@ pub struct ResourceIdentifier {
     pub role_alias_arn: Option<String>,
 }
 
-/// <p>Role alias description.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ResourceType {
+    /// `DEVICE_CERTIFICATE`
+    DeviceCertificate,
+    /// `CA_CERTIFICATE`
+    CaCertificate,
+    /// `IOT_POLICY`
+    IotPolicy,
+    /// `COGNITO_IDENTITY_POOL`
+    CognitoIdentityPool,
+    /// `CLIENT_ID`
+    ClientId,
+    /// `ACCOUNT_SETTINGS`
+    AccountSettings,
+    /// `ROLE_ALIAS`
+    RoleAlias,
+    /// `IAM_ROLE`
+    IamRole,
+    /// `ISSUER_CERTIFICATE`
+    IssuerCertificate,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ResourceType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceType::DeviceCertificate => "DEVICE_CERTIFICATE",
+            ResourceType::CaCertificate => "CA_CERTIFICATE",
+            ResourceType::IotPolicy => "IOT_POLICY",
+            ResourceType::CognitoIdentityPool => "COGNITO_IDENTITY_POOL",
+            ResourceType::ClientId => "CLIENT_ID",
+            ResourceType::AccountSettings => "ACCOUNT_SETTINGS",
+            ResourceType::RoleAlias => "ROLE_ALIAS",
+            ResourceType::IamRole => "IAM_ROLE",
+            ResourceType::IssuerCertificate => "ISSUER_CERTIFICATE",
+            ResourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ResourceType {
+    fn default() -> ResourceType {
+        ResourceType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ResourceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ResourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ResourceType {
+    fn from(value: &'a str) -> ResourceType {
+        match value {
+            "DEVICE_CERTIFICATE" => ResourceType::DeviceCertificate,
+            "CA_CERTIFICATE" => ResourceType::CaCertificate,
+            "IOT_POLICY" => ResourceType::IotPolicy,
+            "COGNITO_IDENTITY_POOL" => ResourceType::CognitoIdentityPool,
+            "CLIENT_ID" => ResourceType::ClientId,
+            "ACCOUNT_SETTINGS" => ResourceType::AccountSettings,
+            "ROLE_ALIAS" => ResourceType::RoleAlias,
+            "IAM_ROLE" => ResourceType::IamRole,
+            "ISSUER_CERTIFICATE" => ResourceType::IssuerCertificate,
+            _ => ResourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceType {
+    fn from(value: String) -> ResourceType {
+        match value.as_str() {
+            "DEVICE_CERTIFICATE" => ResourceType::DeviceCertificate,
+            "CA_CERTIFICATE" => ResourceType::CaCertificate,
+            "IOT_POLICY" => ResourceType::IotPolicy,
+            "COGNITO_IDENTITY_POOL" => ResourceType::CognitoIdentityPool,
+            "CLIENT_ID" => ResourceType::ClientId,
+            "ACCOUNT_SETTINGS" => ResourceType::AccountSettings,
+            "ROLE_ALIAS" => ResourceType::RoleAlias,
+            "IAM_ROLE" => ResourceType::IamRole,
+            "ISSUER_CERTIFICATE" => ResourceType::IssuerCertificate,
+            _ => ResourceType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ResourceType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ResourceType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ResourceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ResourceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ResourceType {
+    fn deserialize<D>(deserializer: D) -> Result<ResourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Role alias description.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RoleAliasDescription {
     /// <p>The UNIX timestamp of when the role alias was created.</p>
     #[serde(rename = "creationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The number of seconds for which the credential is valid.</p>
     #[serde(rename = "credentialDurationSeconds")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub credential_duration_seconds: Option<i64>,
     /// <p>The UNIX timestamp of when the role alias was last modified.</p>
     #[serde(rename = "lastModifiedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_date: Option<f64>,
+    pub last_modified_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The role alias owner.</p>
     #[serde(rename = "owner")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -6657,7 +11128,7 @@ pub struct S3Action {
     /// <p>The Amazon S3 canned ACL that controls access to the object identified by the object key. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/acl-overview.html#canned-acl">S3 canned ACLs</a>.</p>
     #[serde(rename = "cannedAcl")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub canned_acl: Option<String>,
+    pub canned_acl: Option<CannedAccessControlList>,
     /// <p>The object key.</p>
     #[serde(rename = "key")]
     pub key: String,
@@ -6718,11 +11189,11 @@ pub struct ScheduledAuditMetadata {
     /// <p>The day of the week on which the scheduled audit is run (if the <code>frequency</code> is "WEEKLY" or "BIWEEKLY").</p>
     #[serde(rename = "dayOfWeek")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub day_of_week: Option<String>,
+    pub day_of_week: Option<DayOfWeek>,
     /// <p>How often the scheduled audit occurs.</p>
     #[serde(rename = "frequency")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub frequency: Option<String>,
+    pub frequency: Option<AuditFrequency>,
     /// <p>The ARN of the scheduled audit.</p>
     #[serde(rename = "scheduledAuditArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -6809,6 +11280,99 @@ pub struct SecurityProfileTargetMapping {
     pub target: Option<SecurityProfileTarget>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ServerCertificateStatus {
+    /// `INVALID`
+    Invalid,
+    /// `VALID`
+    Valid,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ServerCertificateStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ServerCertificateStatus::Invalid => "INVALID",
+            ServerCertificateStatus::Valid => "VALID",
+            ServerCertificateStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ServerCertificateStatus {
+    fn default() -> ServerCertificateStatus {
+        ServerCertificateStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ServerCertificateStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ServerCertificateStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ServerCertificateStatus {
+    fn from(value: &'a str) -> ServerCertificateStatus {
+        match value {
+            "INVALID" => ServerCertificateStatus::Invalid,
+            "VALID" => ServerCertificateStatus::Valid,
+            _ => ServerCertificateStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ServerCertificateStatus {
+    fn from(value: String) -> ServerCertificateStatus {
+        match value.as_str() {
+            "INVALID" => ServerCertificateStatus::Invalid,
+            "VALID" => ServerCertificateStatus::Valid,
+            _ => ServerCertificateStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ServerCertificateStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ServerCertificateStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ServerCertificateStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ServerCertificateStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ServerCertificateStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ServerCertificateStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An object that contains information about a server certificate.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -6820,13 +11384,111 @@ pub struct ServerCertificateSummary {
     /// <p>The status of the server certificate.</p>
     #[serde(rename = "serverCertificateStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub server_certificate_status: Option<String>,
+    pub server_certificate_status: Option<ServerCertificateStatus>,
     /// <p>Details that explain the status of the server certificate.</p>
     #[serde(rename = "serverCertificateStatusDetail")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub server_certificate_status_detail: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ServiceType {
+    /// `DATA`
+    Data,
+    /// `CREDENTIAL_PROVIDER`
+    CredentialProvider,
+    /// `JOBS`
+    Jobs,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ServiceType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ServiceType::Data => "DATA",
+            ServiceType::CredentialProvider => "CREDENTIAL_PROVIDER",
+            ServiceType::Jobs => "JOBS",
+            ServiceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ServiceType {
+    fn default() -> ServiceType {
+        ServiceType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ServiceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ServiceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ServiceType {
+    fn from(value: &'a str) -> ServiceType {
+        match value {
+            "DATA" => ServiceType::Data,
+            "CREDENTIAL_PROVIDER" => ServiceType::CredentialProvider,
+            "JOBS" => ServiceType::Jobs,
+            _ => ServiceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ServiceType {
+    fn from(value: String) -> ServiceType {
+        match value.as_str() {
+            "DATA" => ServiceType::Data,
+            "CREDENTIAL_PROVIDER" => ServiceType::CredentialProvider,
+            "JOBS" => ServiceType::Jobs,
+            _ => ServiceType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ServiceType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ServiceType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ServiceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ServiceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ServiceType {
+    fn deserialize<D>(deserializer: D) -> Result<ServiceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct SetDefaultAuthorizerRequest {
@@ -6874,7 +11536,7 @@ pub struct SetLoggingOptionsRequest {
 pub struct SetV2LoggingLevelRequest {
     /// <p>The log level.</p>
     #[serde(rename = "logLevel")]
-    pub log_level: String,
+    pub log_level: LogLevel,
     /// <p>The log target.</p>
     #[serde(rename = "logTarget")]
     pub log_target: LogTarget,
@@ -6886,7 +11548,7 @@ pub struct SetV2LoggingOptionsRequest {
     /// <p>The default logging level.</p>
     #[serde(rename = "defaultLogLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub default_log_level: Option<String>,
+    pub default_log_level: Option<LogLevel>,
     /// <p>If true all logs are disabled. The default is false.</p>
     #[serde(rename = "disableAllLogs")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -6934,7 +11596,7 @@ pub struct SnsAction {
     /// <p>(Optional) The message format of the message to publish. Accepted values are "JSON" and "RAW". The default value of the attribute is "RAW". SNS uses this setting to determine if the payload should be parsed and relevant platform-specific bits of the payload should be extracted. To read more about SNS message formats, see <a href="https://docs.aws.amazon.com/sns/latest/dg/json-formats.html">https://docs.aws.amazon.com/sns/latest/dg/json-formats.html</a> refer to their official documentation.</p>
     #[serde(rename = "messageFormat")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub message_format: Option<String>,
+    pub message_format: Option<MessageFormat>,
     /// <p>The ARN of the IAM role that grants access.</p>
     #[serde(rename = "roleArn")]
     pub role_arn: String,
@@ -7091,6 +11753,114 @@ pub struct Statistics {
     pub variance: Option<f64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Status {
+    /// `InProgress`
+    InProgress,
+    /// `Completed`
+    Completed,
+    /// `Failed`
+    Failed,
+    /// `Cancelled`
+    Cancelled,
+    /// `Cancelling`
+    Cancelling,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Status {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Status::InProgress => "InProgress",
+            Status::Completed => "Completed",
+            Status::Failed => "Failed",
+            Status::Cancelled => "Cancelled",
+            Status::Cancelling => "Cancelling",
+            Status::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Status {
+    fn default() -> Status {
+        Status::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Status {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Status {
+    fn from(value: &'a str) -> Status {
+        match value {
+            "InProgress" => Status::InProgress,
+            "Completed" => Status::Completed,
+            "Failed" => Status::Failed,
+            "Cancelled" => Status::Cancelled,
+            "Cancelling" => Status::Cancelling,
+            _ => Status::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Status {
+    fn from(value: String) -> Status {
+        match value.as_str() {
+            "InProgress" => Status::InProgress,
+            "Completed" => Status::Completed,
+            "Failed" => Status::Failed,
+            "Cancelled" => Status::Cancelled,
+            "Cancelling" => Status::Cancelling,
+            _ => Status::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Status {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Status, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Status {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Status {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Status {
+    fn deserialize<D>(deserializer: D) -> Result<Status, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Starts execution of a Step Functions state machine.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct StepFunctionsAction {
@@ -7150,8 +11920,13 @@ pub struct StreamFile {
 pub struct StreamInfo {
     /// <p>The date when the stream was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The description of the stream.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -7162,8 +11937,13 @@ pub struct StreamInfo {
     pub files: Option<Vec<StreamFile>>,
     /// <p>The date when the stream was last updated.</p>
     #[serde(rename = "lastUpdatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_at: Option<f64>,
+    pub last_updated_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>An IAM role AWS IoT assumes to access your S3 files.</p>
     #[serde(rename = "roleArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -7232,6 +12012,99 @@ pub struct TagResourceRequest {
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TagResourceResponse {}
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TargetSelection {
+    /// `CONTINUOUS`
+    Continuous,
+    /// `SNAPSHOT`
+    Snapshot,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TargetSelection {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TargetSelection::Continuous => "CONTINUOUS",
+            TargetSelection::Snapshot => "SNAPSHOT",
+            TargetSelection::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TargetSelection {
+    fn default() -> TargetSelection {
+        TargetSelection::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TargetSelection {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TargetSelection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TargetSelection {
+    fn from(value: &'a str) -> TargetSelection {
+        match value {
+            "CONTINUOUS" => TargetSelection::Continuous,
+            "SNAPSHOT" => TargetSelection::Snapshot,
+            _ => TargetSelection::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TargetSelection {
+    fn from(value: String) -> TargetSelection {
+        match value.as_str() {
+            "CONTINUOUS" => TargetSelection::Continuous,
+            "SNAPSHOT" => TargetSelection::Snapshot,
+            _ => TargetSelection::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TargetSelection {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TargetSelection, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TargetSelection {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TargetSelection {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TargetSelection {
+    fn deserialize<D>(deserializer: D) -> Result<TargetSelection, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Statistics for the checks performed during the audit.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -7422,6 +12295,99 @@ pub struct ThingConnectivity {
     pub timestamp: Option<i64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ThingConnectivityIndexingMode {
+    /// `OFF`
+    Off,
+    /// `STATUS`
+    Status,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ThingConnectivityIndexingMode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ThingConnectivityIndexingMode::Off => "OFF",
+            ThingConnectivityIndexingMode::Status => "STATUS",
+            ThingConnectivityIndexingMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ThingConnectivityIndexingMode {
+    fn default() -> ThingConnectivityIndexingMode {
+        ThingConnectivityIndexingMode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ThingConnectivityIndexingMode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ThingConnectivityIndexingMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ThingConnectivityIndexingMode {
+    fn from(value: &'a str) -> ThingConnectivityIndexingMode {
+        match value {
+            "OFF" => ThingConnectivityIndexingMode::Off,
+            "STATUS" => ThingConnectivityIndexingMode::Status,
+            _ => ThingConnectivityIndexingMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ThingConnectivityIndexingMode {
+    fn from(value: String) -> ThingConnectivityIndexingMode {
+        match value.as_str() {
+            "OFF" => ThingConnectivityIndexingMode::Off,
+            "STATUS" => ThingConnectivityIndexingMode::Status,
+            _ => ThingConnectivityIndexingMode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ThingConnectivityIndexingMode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ThingConnectivityIndexingMode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ThingConnectivityIndexingMode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ThingConnectivityIndexingMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ThingConnectivityIndexingMode {
+    fn deserialize<D>(deserializer: D) -> Result<ThingConnectivityIndexingMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The thing search index document.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -7495,17 +12461,115 @@ pub struct ThingGroupIndexingConfiguration {
     pub managed_fields: Option<Vec<Field>>,
     /// <p>Thing group indexing mode.</p>
     #[serde(rename = "thingGroupIndexingMode")]
-    pub thing_group_indexing_mode: String,
+    pub thing_group_indexing_mode: ThingGroupIndexingMode,
 }
 
-/// <p>Thing group metadata.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ThingGroupIndexingMode {
+    /// `OFF`
+    Off,
+    /// `ON`
+    On,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ThingGroupIndexingMode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ThingGroupIndexingMode::Off => "OFF",
+            ThingGroupIndexingMode::On => "ON",
+            ThingGroupIndexingMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ThingGroupIndexingMode {
+    fn default() -> ThingGroupIndexingMode {
+        ThingGroupIndexingMode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ThingGroupIndexingMode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ThingGroupIndexingMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ThingGroupIndexingMode {
+    fn from(value: &'a str) -> ThingGroupIndexingMode {
+        match value {
+            "OFF" => ThingGroupIndexingMode::Off,
+            "ON" => ThingGroupIndexingMode::On,
+            _ => ThingGroupIndexingMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ThingGroupIndexingMode {
+    fn from(value: String) -> ThingGroupIndexingMode {
+        match value.as_str() {
+            "OFF" => ThingGroupIndexingMode::Off,
+            "ON" => ThingGroupIndexingMode::On,
+            _ => ThingGroupIndexingMode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ThingGroupIndexingMode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ThingGroupIndexingMode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ThingGroupIndexingMode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ThingGroupIndexingMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ThingGroupIndexingMode {
+    fn deserialize<D>(deserializer: D) -> Result<ThingGroupIndexingMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Thing group metadata.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ThingGroupMetadata {
     /// <p>The UNIX timestamp of when the thing group was created.</p>
     #[serde(rename = "creationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The parent thing group name.</p>
     #[serde(rename = "parentGroupName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -7543,12 +12607,110 @@ pub struct ThingIndexingConfiguration {
     /// <p><p>Thing connectivity indexing mode. Valid values are: </p> <ul> <li> <p>STATUS – Your thing index contains connectivity status. To enable thing connectivity indexing, thingIndexMode must not be set to OFF.</p> </li> <li> <p>OFF - Thing connectivity status indexing is disabled.</p> </li> </ul></p>
     #[serde(rename = "thingConnectivityIndexingMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub thing_connectivity_indexing_mode: Option<String>,
+    pub thing_connectivity_indexing_mode: Option<ThingConnectivityIndexingMode>,
     /// <p><p>Thing indexing mode. Valid values are:</p> <ul> <li> <p>REGISTRY – Your thing index contains registry data only.</p> </li> <li> <p>REGISTRY<em>AND</em>SHADOW - Your thing index contains registry and shadow data.</p> </li> <li> <p>OFF - Thing indexing is disabled.</p> </li> </ul></p>
     #[serde(rename = "thingIndexingMode")]
-    pub thing_indexing_mode: String,
+    pub thing_indexing_mode: ThingIndexingMode,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ThingIndexingMode {
+    /// `OFF`
+    Off,
+    /// `REGISTRY`
+    Registry,
+    /// `REGISTRY_AND_SHADOW`
+    RegistryAndShadow,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ThingIndexingMode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ThingIndexingMode::Off => "OFF",
+            ThingIndexingMode::Registry => "REGISTRY",
+            ThingIndexingMode::RegistryAndShadow => "REGISTRY_AND_SHADOW",
+            ThingIndexingMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ThingIndexingMode {
+    fn default() -> ThingIndexingMode {
+        ThingIndexingMode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ThingIndexingMode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ThingIndexingMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ThingIndexingMode {
+    fn from(value: &'a str) -> ThingIndexingMode {
+        match value {
+            "OFF" => ThingIndexingMode::Off,
+            "REGISTRY" => ThingIndexingMode::Registry,
+            "REGISTRY_AND_SHADOW" => ThingIndexingMode::RegistryAndShadow,
+            _ => ThingIndexingMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ThingIndexingMode {
+    fn from(value: String) -> ThingIndexingMode {
+        match value.as_str() {
+            "OFF" => ThingIndexingMode::Off,
+            "REGISTRY" => ThingIndexingMode::Registry,
+            "REGISTRY_AND_SHADOW" => ThingIndexingMode::RegistryAndShadow,
+            _ => ThingIndexingMode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ThingIndexingMode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ThingIndexingMode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ThingIndexingMode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ThingIndexingMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for ThingIndexingMode {
+    fn deserialize<D>(deserializer: D) -> Result<ThingIndexingMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The definition of the thing type, including thing type name and description.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -7577,16 +12739,26 @@ pub struct ThingTypeDefinition {
 pub struct ThingTypeMetadata {
     /// <p>The date and time when the thing type was created.</p>
     #[serde(rename = "creationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Whether the thing type is deprecated. If <b>true</b>, no new things could be associated with this type.</p>
     #[serde(rename = "deprecated")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub deprecated: Option<bool>,
     /// <p>The date and time when the thing type was deprecated.</p>
     #[serde(rename = "deprecationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deprecation_date: Option<f64>,
+    pub deprecation_date: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>The ThingTypeProperties contains information about the thing type including: a thing type description, and a list of searchable thing attribute names.</p>
@@ -7635,8 +12807,13 @@ pub struct TopicRule {
     pub aws_iot_sql_version: Option<String>,
     /// <p>The date and time the rule was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The description of the rule.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -7674,7 +12851,7 @@ pub struct TopicRuleDestination {
     /// <p><p>The status of the topic rule destination. Valid values are:</p> <dl> <dt>IN<em>PROGRESS</dt> <dd> <p>A topic rule destination was created but has not been confirmed. You can set <code>status</code> to <code>IN</em>PROGRESS</code> by calling <code>UpdateTopicRuleDestination</code>. Calling <code>UpdateTopicRuleDestination</code> causes a new confirmation challenge to be sent to your confirmation endpoint.</p> </dd> <dt>ENABLED</dt> <dd> <p>Confirmation was completed, and traffic to this destination is allowed. You can set <code>status</code> to <code>DISABLED</code> by calling <code>UpdateTopicRuleDestination</code>.</p> </dd> <dt>DISABLED</dt> <dd> <p>Confirmation was completed, and traffic to this destination is not allowed. You can set <code>status</code> to <code>ENABLED</code> by calling <code>UpdateTopicRuleDestination</code>.</p> </dd> <dt>ERROR</dt> <dd> <p>Confirmation could not be completed, for example if the confirmation timed out. You can call <code>GetTopicRuleDestination</code> for details about the error. You can set <code>status</code> to <code>IN_PROGRESS</code> by calling <code>UpdateTopicRuleDestination</code>. Calling <code>UpdateTopicRuleDestination</code> causes a new confirmation challenge to be sent to your confirmation endpoint.</p> </dd> </dl></p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<TopicRuleDestinationStatus>,
     /// <p>Additional details or reason why the topic rule destination is in the current status.</p>
     #[serde(rename = "statusReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -7691,6 +12868,114 @@ pub struct TopicRuleDestinationConfiguration {
     pub http_url_configuration: Option<HttpUrlDestinationConfiguration>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TopicRuleDestinationStatus {
+    /// `ENABLED`
+    Enabled,
+    /// `IN_PROGRESS`
+    InProgress,
+    /// `DISABLED`
+    Disabled,
+    /// `ERROR`
+    Error,
+    /// `DELETING`
+    Deleting,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TopicRuleDestinationStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TopicRuleDestinationStatus::Enabled => "ENABLED",
+            TopicRuleDestinationStatus::InProgress => "IN_PROGRESS",
+            TopicRuleDestinationStatus::Disabled => "DISABLED",
+            TopicRuleDestinationStatus::Error => "ERROR",
+            TopicRuleDestinationStatus::Deleting => "DELETING",
+            TopicRuleDestinationStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TopicRuleDestinationStatus {
+    fn default() -> TopicRuleDestinationStatus {
+        TopicRuleDestinationStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TopicRuleDestinationStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TopicRuleDestinationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TopicRuleDestinationStatus {
+    fn from(value: &'a str) -> TopicRuleDestinationStatus {
+        match value {
+            "ENABLED" => TopicRuleDestinationStatus::Enabled,
+            "IN_PROGRESS" => TopicRuleDestinationStatus::InProgress,
+            "DISABLED" => TopicRuleDestinationStatus::Disabled,
+            "ERROR" => TopicRuleDestinationStatus::Error,
+            "DELETING" => TopicRuleDestinationStatus::Deleting,
+            _ => TopicRuleDestinationStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TopicRuleDestinationStatus {
+    fn from(value: String) -> TopicRuleDestinationStatus {
+        match value.as_str() {
+            "ENABLED" => TopicRuleDestinationStatus::Enabled,
+            "IN_PROGRESS" => TopicRuleDestinationStatus::InProgress,
+            "DISABLED" => TopicRuleDestinationStatus::Disabled,
+            "ERROR" => TopicRuleDestinationStatus::Error,
+            "DELETING" => TopicRuleDestinationStatus::Deleting,
+            _ => TopicRuleDestinationStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TopicRuleDestinationStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TopicRuleDestinationStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TopicRuleDestinationStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TopicRuleDestinationStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TopicRuleDestinationStatus {
+    fn deserialize<D>(deserializer: D) -> Result<TopicRuleDestinationStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about the topic rule destination.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -7706,7 +12991,7 @@ pub struct TopicRuleDestinationSummary {
     /// <p><p>The status of the topic rule destination. Valid values are:</p> <dl> <dt>IN<em>PROGRESS</dt> <dd> <p>A topic rule destination was created but has not been confirmed. You can set <code>status</code> to <code>IN</em>PROGRESS</code> by calling <code>UpdateTopicRuleDestination</code>. Calling <code>UpdateTopicRuleDestination</code> causes a new confirmation challenge to be sent to your confirmation endpoint.</p> </dd> <dt>ENABLED</dt> <dd> <p>Confirmation was completed, and traffic to this destination is allowed. You can set <code>status</code> to <code>DISABLED</code> by calling <code>UpdateTopicRuleDestination</code>.</p> </dd> <dt>DISABLED</dt> <dd> <p>Confirmation was completed, and traffic to this destination is not allowed. You can set <code>status</code> to <code>ENABLED</code> by calling <code>UpdateTopicRuleDestination</code>.</p> </dd> <dt>ERROR</dt> <dd> <p>Confirmation could not be completed, for example if the confirmation timed out. You can call <code>GetTopicRuleDestination</code> for details about the error. You can set <code>status</code> to <code>IN_PROGRESS</code> by calling <code>UpdateTopicRuleDestination</code>. Calling <code>UpdateTopicRuleDestination</code> causes a new confirmation challenge to be sent to your confirmation endpoint.</p> </dd> </dl></p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<TopicRuleDestinationStatus>,
     /// <p>The reason the topic rule destination is in the current status.</p>
     #[serde(rename = "statusReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -7719,8 +13004,13 @@ pub struct TopicRuleDestinationSummary {
 pub struct TopicRuleListItem {
     /// <p>The date and time the rule was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The rule ARN.</p>
     #[serde(rename = "ruleArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -7799,20 +13089,35 @@ pub struct TransferCertificateResponse {
 pub struct TransferData {
     /// <p>The date the transfer was accepted.</p>
     #[serde(rename = "acceptDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub accept_date: Option<f64>,
+    pub accept_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The date the transfer was rejected.</p>
     #[serde(rename = "rejectDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub reject_date: Option<f64>,
+    pub reject_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The reason why the transfer was rejected.</p>
     #[serde(rename = "rejectReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reject_reason: Option<String>,
     /// <p>The date the transfer took place.</p>
     #[serde(rename = "transferDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub transfer_date: Option<f64>,
+    pub transfer_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The transfer message.</p>
     #[serde(rename = "transferMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -7846,7 +13151,7 @@ pub struct UpdateAccountAuditConfigurationRequest {
     #[serde(rename = "auditNotificationTargetConfigurations")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub audit_notification_target_configurations:
-        Option<::std::collections::HashMap<String, AuditNotificationTarget>>,
+        Option<::std::collections::HashMap<AuditNotificationType, AuditNotificationTarget>>,
     /// <p>The ARN of the role that grants permission to AWS IoT to access information about your devices, policies, certificates and other items as required when performing an audit.</p>
     #[serde(rename = "roleArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -7870,7 +13175,7 @@ pub struct UpdateAuthorizerRequest {
     /// <p>The status of the update authorizer request.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<AuthorizerStatus>,
     /// <p>The key used to extract the token from the HTTP headers. </p>
     #[serde(rename = "tokenKeyName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -7923,7 +13228,7 @@ pub struct UpdateBillingGroupResponse {
 pub struct UpdateCACertificateParams {
     /// <p>The action that you want to apply to the CA cerrtificate. The only supported value is <code>DEACTIVATE</code>.</p>
     #[serde(rename = "action")]
-    pub action: String,
+    pub action: CACertificateUpdateAction,
 }
 
 /// <p>The input to the UpdateCACertificate operation.</p>
@@ -7936,11 +13241,11 @@ pub struct UpdateCACertificateRequest {
     /// <p>The new value for the auto registration status. Valid values are: "ENABLE" or "DISABLE".</p>
     #[serde(rename = "newAutoRegistrationStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub new_auto_registration_status: Option<String>,
+    pub new_auto_registration_status: Option<AutoRegistrationStatus>,
     /// <p>The updated status of the CA certificate.</p> <p> <b>Note:</b> The status value REGISTER_INACTIVE is deprecated and should not be used.</p>
     #[serde(rename = "newStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub new_status: Option<String>,
+    pub new_status: Option<CACertificateStatus>,
     /// <p>Information about the registration configuration.</p>
     #[serde(rename = "registrationConfig")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -7960,7 +13265,7 @@ pub struct UpdateCertificateRequest {
     pub certificate_id: String,
     /// <p>The new status.</p> <p> <b>Note:</b> Setting the status to PENDING_TRANSFER will result in an exception being thrown. PENDING_TRANSFER is a status used internally by AWS IoT. It is not intended for developer use.</p> <p> <b>Note:</b> The status value REGISTER_INACTIVE is deprecated and should not be used.</p>
     #[serde(rename = "newStatus")]
-    pub new_status: String,
+    pub new_status: CertificateStatus,
 }
 
 /// <p>Parameters to define a mitigation action that changes the state of the device certificate to inactive.</p>
@@ -7968,7 +13273,7 @@ pub struct UpdateCertificateRequest {
 pub struct UpdateDeviceCertificateParams {
     /// <p>The action that you want to apply to the device cerrtificate. The only supported value is <code>DEACTIVATE</code>.</p>
     #[serde(rename = "action")]
-    pub action: String,
+    pub action: DeviceCertificateUpdateAction,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -7984,7 +13289,7 @@ pub struct UpdateDomainConfigurationRequest {
     /// <p>The status to which the domain configuration should be updated.</p>
     #[serde(rename = "domainConfigurationStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub domain_configuration_status: Option<String>,
+    pub domain_configuration_status: Option<DomainConfigurationStatus>,
     /// <p>Removes the authorization configuration from a domain.</p>
     #[serde(rename = "removeAuthorizerConfig")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -8046,7 +13351,7 @@ pub struct UpdateEventConfigurationsRequest {
     /// <p>The new event configuration values.</p>
     #[serde(rename = "eventConfigurations")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub event_configurations: Option<::std::collections::HashMap<String, Configuration>>,
+    pub event_configurations: Option<::std::collections::HashMap<EventType, Configuration>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -8194,11 +13499,11 @@ pub struct UpdateScheduledAuditRequest {
     /// <p>The day of the week on which the scheduled audit takes place. Can be one of "SUN", "MON", "TUE", "WED", "THU", "FRI", or "SAT". This field is required if the "frequency" parameter is set to "WEEKLY" or "BIWEEKLY".</p>
     #[serde(rename = "dayOfWeek")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub day_of_week: Option<String>,
+    pub day_of_week: Option<DayOfWeek>,
     /// <p>How often the scheduled audit takes place. Can be one of "DAILY", "WEEKLY", "BIWEEKLY", or "MONTHLY". The start time of each audit is determined by the system.</p>
     #[serde(rename = "frequency")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub frequency: Option<String>,
+    pub frequency: Option<AuditFrequency>,
     /// <p>The name of the scheduled audit. (Max. 128 chars)</p>
     #[serde(rename = "scheduledAuditName")]
     pub scheduled_audit_name: String,
@@ -8227,7 +13532,7 @@ pub struct UpdateSecurityProfileRequest {
     /// <p>Where the alerts are sent. (Alerts are always sent to the console.)</p>
     #[serde(rename = "alertTargets")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub alert_targets: Option<::std::collections::HashMap<String, AlertTarget>>,
+    pub alert_targets: Option<::std::collections::HashMap<AlertTargetType, AlertTarget>>,
     /// <p>Specifies the behaviors that, when violated by a device (thing), cause an alert.</p>
     #[serde(rename = "behaviors")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -8267,19 +13572,29 @@ pub struct UpdateSecurityProfileResponse {
     /// <p>Where the alerts are sent. (Alerts are always sent to the console.)</p>
     #[serde(rename = "alertTargets")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub alert_targets: Option<::std::collections::HashMap<String, AlertTarget>>,
+    pub alert_targets: Option<::std::collections::HashMap<AlertTargetType, AlertTarget>>,
     /// <p>Specifies the behaviors that, when violated by a device (thing), cause an alert.</p>
     #[serde(rename = "behaviors")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub behaviors: Option<Vec<Behavior>>,
     /// <p>The time the security profile was created.</p>
     #[serde(rename = "creationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The time the security profile was last modified.</p>
     #[serde(rename = "lastModifiedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_date: Option<f64>,
+    pub last_modified_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The ARN of the security profile that was updated.</p>
     #[serde(rename = "securityProfileArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -8426,7 +13741,7 @@ pub struct UpdateTopicRuleDestinationRequest {
     pub arn: String,
     /// <p><p>The status of the topic rule destination. Valid values are:</p> <dl> <dt>IN<em>PROGRESS</dt> <dd> <p>A topic rule destination was created but has not been confirmed. You can set <code>status</code> to <code>IN</em>PROGRESS</code> by calling <code>UpdateTopicRuleDestination</code>. Calling <code>UpdateTopicRuleDestination</code> causes a new confirmation challenge to be sent to your confirmation endpoint.</p> </dd> <dt>ENABLED</dt> <dd> <p>Confirmation was completed, and traffic to this destination is allowed. You can set <code>status</code> to <code>DISABLED</code> by calling <code>UpdateTopicRuleDestination</code>.</p> </dd> <dt>DISABLED</dt> <dd> <p>Confirmation was completed, and traffic to this destination is not allowed. You can set <code>status</code> to <code>ENABLED</code> by calling <code>UpdateTopicRuleDestination</code>.</p> </dd> <dt>ERROR</dt> <dd> <p>Confirmation could not be completed, for example if the confirmation timed out. You can call <code>GetTopicRuleDestination</code> for details about the error. You can set <code>status</code> to <code>IN_PROGRESS</code> by calling <code>UpdateTopicRuleDestination</code>. Calling <code>UpdateTopicRuleDestination</code> causes a new confirmation challenge to be sent to your confirmation endpoint.</p> </dd> </dl></p>
     #[serde(rename = "status")]
-    pub status: String,
+    pub status: TopicRuleDestinationStatus,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -8486,18 +13801,121 @@ pub struct ViolationEvent {
     pub thing_name: Option<String>,
     /// <p>The time the violation event occurred.</p>
     #[serde(rename = "violationEventTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub violation_event_time: Option<f64>,
+    pub violation_event_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The type of violation event.</p>
     #[serde(rename = "violationEventType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub violation_event_type: Option<String>,
+    pub violation_event_type: Option<ViolationEventType>,
     /// <p>The ID of the violation event.</p>
     #[serde(rename = "violationId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub violation_id: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ViolationEventType {
+    /// `in-alarm`
+    InAlarm,
+    /// `alarm-cleared`
+    AlarmCleared,
+    /// `alarm-invalidated`
+    AlarmInvalidated,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ViolationEventType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ViolationEventType::InAlarm => "in-alarm",
+            ViolationEventType::AlarmCleared => "alarm-cleared",
+            ViolationEventType::AlarmInvalidated => "alarm-invalidated",
+            ViolationEventType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ViolationEventType {
+    fn default() -> ViolationEventType {
+        ViolationEventType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ViolationEventType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ViolationEventType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ViolationEventType {
+    fn from(value: &'a str) -> ViolationEventType {
+        match value {
+            "in-alarm" => ViolationEventType::InAlarm,
+            "alarm-cleared" => ViolationEventType::AlarmCleared,
+            "alarm-invalidated" => ViolationEventType::AlarmInvalidated,
+            _ => ViolationEventType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ViolationEventType {
+    fn from(value: String) -> ViolationEventType {
+        match value.as_str() {
+            "in-alarm" => ViolationEventType::InAlarm,
+            "alarm-cleared" => ViolationEventType::AlarmCleared,
+            "alarm-invalidated" => ViolationEventType::AlarmInvalidated,
+            _ => ViolationEventType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ViolationEventType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ViolationEventType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ViolationEventType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ViolationEventType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ViolationEventType {
+    fn deserialize<D>(deserializer: D) -> Result<ViolationEventType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// Errors returned by AcceptCertificateTransfer
 #[derive(Debug, PartialEq)]
 pub enum AcceptCertificateTransferError {
@@ -21965,11 +27383,12 @@ pub struct IotClient {
 impl IotClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> IotClient {
         IotClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "IoT"),
         }
     }
 
@@ -22006,6 +27425,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "execute-api", &self.region, &request_uri);
+        request.set_operation("AcceptCertificateTransfer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -22040,6 +27460,7 @@ impl Iot for IotClient {
         let request_uri = "/billing-groups/addThingToBillingGroup";
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation("AddThingToBillingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -22071,6 +27492,7 @@ impl Iot for IotClient {
         let request_uri = "/thing-groups/addThingToThingGroup";
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation("AddThingToThingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -22102,6 +27524,7 @@ impl Iot for IotClient {
         let request_uri = format!("/jobs/{job_id}/targets", job_id = input.job_id);
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("AssociateTargetsWithJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -22136,6 +27559,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation("AttachPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -22169,6 +27593,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation("AttachPrincipalPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -22202,6 +27627,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation("AttachSecurityProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -22241,6 +27667,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation("AttachThingPrincipal");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -22278,6 +27705,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation("CancelAuditMitigationActionsTask");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -22309,6 +27737,7 @@ impl Iot for IotClient {
         let request_uri = format!("/audit/tasks/{task_id}/cancel", task_id = input.task_id);
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation("CancelAuditTask");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -22341,6 +27770,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "execute-api", &self.region, &request_uri);
+        request.set_operation("CancelCertificateTransfer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -22369,6 +27799,7 @@ impl Iot for IotClient {
         let request_uri = format!("/jobs/{job_id}/cancel", job_id = input.job_id);
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation("CancelJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -22410,6 +27841,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation("CancelJobExecution");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -22445,6 +27877,7 @@ impl Iot for IotClient {
         let request_uri = "/default-authorizer";
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("ClearDefaultAuthorizer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -22478,6 +27911,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ConfirmTopicRuleDestination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -22510,6 +27944,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("CreateAuthorizer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -22544,6 +27979,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("CreateBillingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -22575,6 +28011,7 @@ impl Iot for IotClient {
         let request_uri = "/certificates";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("CreateCertificateFromCsr");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -22616,6 +28053,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("CreateDomainConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -22650,6 +28088,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("CreateDynamicThingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -22681,6 +28120,7 @@ impl Iot for IotClient {
         let request_uri = format!("/jobs/{job_id}", job_id = input.job_id);
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation("CreateJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -22712,6 +28152,7 @@ impl Iot for IotClient {
         let request_uri = "/keys-and-certificate";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("CreateKeysAndCertificate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -22750,6 +28191,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("CreateMitigationAction");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -22784,6 +28226,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("CreateOTAUpdate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -22815,6 +28258,7 @@ impl Iot for IotClient {
         let request_uri = format!("/policies/{policy_name}", policy_name = input.policy_name);
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("CreatePolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -22849,6 +28293,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("CreatePolicyVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -22889,6 +28334,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("CreateProvisioningClaim");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -22919,6 +28365,7 @@ impl Iot for IotClient {
         let request_uri = "/provisioning-templates";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("CreateProvisioningTemplate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -22956,6 +28403,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("CreateProvisioningTemplateVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -22995,6 +28443,7 @@ impl Iot for IotClient {
         let request_uri = format!("/role-aliases/{role_alias}", role_alias = input.role_alias);
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("CreateRoleAlias");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -23029,6 +28478,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("CreateScheduledAudit");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -23063,6 +28513,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("CreateSecurityProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -23094,6 +28545,7 @@ impl Iot for IotClient {
         let request_uri = format!("/streams/{stream_id}", stream_id = input.stream_id);
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("CreateStream");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -23125,6 +28577,7 @@ impl Iot for IotClient {
         let request_uri = format!("/things/{thing_name}", thing_name = input.thing_name);
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("CreateThing");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -23159,6 +28612,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("CreateThingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -23193,6 +28647,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("CreateThingType");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -23224,6 +28679,7 @@ impl Iot for IotClient {
         let request_uri = format!("/rules/{rule_name}", rule_name = input.rule_name);
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("CreateTopicRule");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -23259,6 +28715,7 @@ impl Iot for IotClient {
         let request_uri = "/destinations";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("CreateTopicRuleDestination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -23293,6 +28750,7 @@ impl Iot for IotClient {
         let request_uri = "/audit/configuration";
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DeleteAccountAuditConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -23333,6 +28791,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DeleteAuthorizer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -23365,6 +28824,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DeleteBillingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -23403,6 +28863,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DeleteCACertificate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -23435,6 +28896,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DeleteCertificate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -23473,6 +28935,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DeleteDomainConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -23505,6 +28968,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DeleteDynamicThingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -23537,6 +29001,7 @@ impl Iot for IotClient {
         let request_uri = format!("/jobs/{job_id}", job_id = input.job_id);
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DeleteJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -23576,6 +29041,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DeleteJobExecution");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -23613,6 +29079,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DeleteMitigationAction");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -23645,6 +29112,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DeleteOTAUpdate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -23683,6 +29151,7 @@ impl Iot for IotClient {
         let request_uri = format!("/policies/{policy_name}", policy_name = input.policy_name);
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DeletePolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -23715,6 +29184,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DeletePolicyVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -23747,6 +29217,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DeleteProvisioningTemplate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -23783,6 +29254,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DeleteProvisioningTemplateVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -23813,6 +29285,7 @@ impl Iot for IotClient {
         let request_uri = "/registrationcode";
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DeleteRegistrationCode");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -23842,6 +29315,7 @@ impl Iot for IotClient {
         let request_uri = format!("/role-aliases/{role_alias}", role_alias = input.role_alias);
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DeleteRoleAlias");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -23874,6 +29348,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DeleteScheduledAudit");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -23906,6 +29381,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DeleteSecurityProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -23941,6 +29417,7 @@ impl Iot for IotClient {
         let request_uri = format!("/streams/{stream_id}", stream_id = input.stream_id);
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DeleteStream");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -23970,6 +29447,7 @@ impl Iot for IotClient {
         let request_uri = format!("/things/{thing_name}", thing_name = input.thing_name);
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DeleteThing");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24008,6 +29486,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DeleteThingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24046,6 +29525,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DeleteThingType");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24075,6 +29555,7 @@ impl Iot for IotClient {
         let request_uri = format!("/rules/{rule_name}", rule_name = input.rule_name);
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DeleteTopicRule");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24104,6 +29585,7 @@ impl Iot for IotClient {
         let request_uri = format!("/destinations/{arn}", arn = input.arn);
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DeleteTopicRuleDestination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24133,6 +29615,7 @@ impl Iot for IotClient {
         let request_uri = "/v2LoggingLevel";
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DeleteV2LoggingLevel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24169,6 +29652,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("DeprecateThingType");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24202,6 +29686,7 @@ impl Iot for IotClient {
         let request_uri = "/audit/configuration";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("DescribeAccountAuditConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24236,6 +29721,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("DescribeAuditFinding");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24271,6 +29757,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("DescribeAuditMitigationActionsTask");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24302,6 +29789,7 @@ impl Iot for IotClient {
         let request_uri = format!("/audit/tasks/{task_id}", task_id = input.task_id);
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("DescribeAuditTask");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24334,6 +29822,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("DescribeAuthorizer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24366,6 +29855,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("DescribeBillingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24398,6 +29888,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("DescribeCACertificate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24430,6 +29921,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("DescribeCertificate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24459,6 +29951,7 @@ impl Iot for IotClient {
         let request_uri = "/default-authorizer";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("DescribeDefaultAuthorizer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24492,6 +29985,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("DescribeDomainConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24521,6 +30015,7 @@ impl Iot for IotClient {
         let request_uri = "/endpoint";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("DescribeEndpoint");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24556,6 +30051,7 @@ impl Iot for IotClient {
         let request_uri = "/event-configurations";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("DescribeEventConfigurations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24585,6 +30081,7 @@ impl Iot for IotClient {
         let request_uri = format!("/indices/{index_name}", index_name = input.index_name);
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("DescribeIndex");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24614,6 +30111,7 @@ impl Iot for IotClient {
         let request_uri = format!("/jobs/{job_id}", job_id = input.job_id);
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("DescribeJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24647,6 +30145,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("DescribeJobExecution");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24685,6 +30184,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("DescribeMitigationAction");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24718,6 +30218,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("DescribeProvisioningTemplate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24754,6 +30255,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("DescribeProvisioningTemplateVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24785,6 +30287,7 @@ impl Iot for IotClient {
         let request_uri = format!("/role-aliases/{role_alias}", role_alias = input.role_alias);
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("DescribeRoleAlias");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24817,6 +30320,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("DescribeScheduledAudit");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24849,6 +30353,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("DescribeSecurityProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24878,6 +30383,7 @@ impl Iot for IotClient {
         let request_uri = format!("/streams/{stream_id}", stream_id = input.stream_id);
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("DescribeStream");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24907,6 +30413,7 @@ impl Iot for IotClient {
         let request_uri = format!("/things/{thing_name}", thing_name = input.thing_name);
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("DescribeThing");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24939,6 +30446,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("DescribeThingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24974,6 +30482,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("DescribeThingRegistrationTask");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25006,6 +30515,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("DescribeThingType");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25038,6 +30548,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("DetachPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25071,6 +30582,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DetachPrincipalPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25104,6 +30616,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DetachSecurityProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25143,6 +30656,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DetachThingPrincipal");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25174,6 +30688,7 @@ impl Iot for IotClient {
         let request_uri = format!("/rules/{rule_name}/disable", rule_name = input.rule_name);
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("DisableTopicRule");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25202,6 +30717,7 @@ impl Iot for IotClient {
         let request_uri = format!("/rules/{rule_name}/enable", rule_name = input.rule_name);
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("EnableTopicRule");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25230,6 +30746,7 @@ impl Iot for IotClient {
         let request_uri = "/indices/cardinality";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("GetCardinality");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25261,6 +30778,7 @@ impl Iot for IotClient {
         let request_uri = "/effective-policies";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("GetEffectivePolicies");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25297,6 +30815,7 @@ impl Iot for IotClient {
         let request_uri = "/indexing/config";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("GetIndexingConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25326,6 +30845,7 @@ impl Iot for IotClient {
         let request_uri = format!("/jobs/{job_id}/job-document", job_id = input.job_id);
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("GetJobDocument");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25354,6 +30874,7 @@ impl Iot for IotClient {
         let request_uri = "/loggingOptions";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("GetLoggingOptions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25386,6 +30907,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("GetOTAUpdate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25415,6 +30937,7 @@ impl Iot for IotClient {
         let request_uri = "/indices/percentiles";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("GetPercentiles");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25446,6 +30969,7 @@ impl Iot for IotClient {
         let request_uri = format!("/policies/{policy_name}", policy_name = input.policy_name);
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("GetPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25479,6 +31003,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("GetPolicyVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25507,6 +31032,7 @@ impl Iot for IotClient {
         let request_uri = "/registrationcode";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("GetRegistrationCode");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25536,6 +31062,7 @@ impl Iot for IotClient {
         let request_uri = "/indices/statistics";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("GetStatistics");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25567,6 +31094,7 @@ impl Iot for IotClient {
         let request_uri = format!("/rules/{rule_name}", rule_name = input.rule_name);
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("GetTopicRule");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25596,6 +31124,7 @@ impl Iot for IotClient {
         let request_uri = format!("/destinations/{arn}", arn = input.arn);
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("GetTopicRuleDestination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25624,6 +31153,7 @@ impl Iot for IotClient {
         let request_uri = "/v2LoggingOptions";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("GetV2LoggingOptions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25653,6 +31183,7 @@ impl Iot for IotClient {
         let request_uri = "/active-violations";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListActiveViolations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25697,6 +31228,7 @@ impl Iot for IotClient {
         let request_uri = format!("/attached-policies/{target}", target = input.target);
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListAttachedPolicies");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25738,6 +31270,7 @@ impl Iot for IotClient {
         let request_uri = "/audit/findings";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListAuditFindings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25772,6 +31305,7 @@ impl Iot for IotClient {
         let request_uri = "/audit/mitigationactions/executions";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListAuditMitigationActionsExecutions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25820,6 +31354,7 @@ impl Iot for IotClient {
         let request_uri = "/audit/mitigationactions/tasks";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListAuditMitigationActionsTasks");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25828,7 +31363,10 @@ impl Iot for IotClient {
         if let Some(ref x) = input.audit_task_id {
             params.put("auditTaskId", x);
         }
-        params.put("endTime", &input.end_time);
+        params.put(
+            "endTime",
+            &rusoto_core::serialization::TimestampFormat::Iso8601.format(&input.end_time),
+        );
         if let Some(ref x) = input.finding_id {
             params.put("findingId", x);
         }
@@ -25838,7 +31376,10 @@ impl Iot for IotClient {
         if let Some(ref x) = input.next_token {
             params.put("nextToken", x);
         }
-        params.put("startTime", &input.start_time);
+        params.put(
+            "startTime",
+            &rusoto_core::serialization::TimestampFormat::Iso8601.format(&input.start_time),
+        );
         if let Some(ref x) = input.task_status {
             params.put("taskStatus", x);
         }
@@ -25871,19 +31412,26 @@ impl Iot for IotClient {
         let request_uri = "/audit/tasks";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListAuditTasks");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
 
         let mut params = Params::new();
-        params.put("endTime", &input.end_time);
+        params.put(
+            "endTime",
+            &rusoto_core::serialization::TimestampFormat::Iso8601.format(&input.end_time),
+        );
         if let Some(ref x) = input.max_results {
             params.put("maxResults", x);
         }
         if let Some(ref x) = input.next_token {
             params.put("nextToken", x);
         }
-        params.put("startTime", &input.start_time);
+        params.put(
+            "startTime",
+            &rusoto_core::serialization::TimestampFormat::Iso8601.format(&input.start_time),
+        );
         if let Some(ref x) = input.task_status {
             params.put("taskStatus", x);
         }
@@ -25917,6 +31465,7 @@ impl Iot for IotClient {
         let request_uri = "/authorizers/";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListAuthorizers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25961,6 +31510,7 @@ impl Iot for IotClient {
         let request_uri = "/billing-groups";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListBillingGroups");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26002,6 +31552,7 @@ impl Iot for IotClient {
         let request_uri = "/cacertificates";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListCACertificates");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26043,6 +31594,7 @@ impl Iot for IotClient {
         let request_uri = "/certificates";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListCertificates");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26087,6 +31639,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListCertificatesByCA");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26128,6 +31681,7 @@ impl Iot for IotClient {
         let request_uri = "/domainConfigurations";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListDomainConfigurations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26169,6 +31723,7 @@ impl Iot for IotClient {
         let request_uri = "/indices";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListIndices");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26207,6 +31762,7 @@ impl Iot for IotClient {
         let request_uri = format!("/jobs/{job_id}/things", job_id = input.job_id);
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListJobExecutionsForJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26249,6 +31805,7 @@ impl Iot for IotClient {
         let request_uri = format!("/things/{thing_name}/jobs", thing_name = input.thing_name);
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListJobExecutionsForThing");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26290,6 +31847,7 @@ impl Iot for IotClient {
         let request_uri = "/jobs";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListJobs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26340,6 +31898,7 @@ impl Iot for IotClient {
         let request_uri = "/mitigationactions/actions";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListMitigationActions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26381,6 +31940,7 @@ impl Iot for IotClient {
         let request_uri = "/otaUpdates";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListOTAUpdates");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26422,6 +31982,7 @@ impl Iot for IotClient {
         let request_uri = "/certificates-out-going";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListOutgoingCertificates");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26463,6 +32024,7 @@ impl Iot for IotClient {
         let request_uri = "/policies";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListPolicies");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26504,6 +32066,7 @@ impl Iot for IotClient {
         let request_uri = "/policy-principals";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListPolicyPrincipals");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26549,6 +32112,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListPolicyVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26578,6 +32142,7 @@ impl Iot for IotClient {
         let request_uri = "/principal-policies";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListPrincipalPolicies");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26620,6 +32185,7 @@ impl Iot for IotClient {
         let request_uri = "/principals/things";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListPrincipalThings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26665,6 +32231,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListProvisioningTemplateVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26706,6 +32273,7 @@ impl Iot for IotClient {
         let request_uri = "/provisioning-templates";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListProvisioningTemplates");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26744,6 +32312,7 @@ impl Iot for IotClient {
         let request_uri = "/role-aliases";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListRoleAliases");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26785,6 +32354,7 @@ impl Iot for IotClient {
         let request_uri = "/audit/scheduledaudits";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListScheduledAudits");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26823,6 +32393,7 @@ impl Iot for IotClient {
         let request_uri = "/security-profiles";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListSecurityProfiles");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26864,6 +32435,7 @@ impl Iot for IotClient {
         let request_uri = "/security-profiles-for-target";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListSecurityProfilesForTarget");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26909,6 +32481,7 @@ impl Iot for IotClient {
         let request_uri = "/streams";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListStreams");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26950,6 +32523,7 @@ impl Iot for IotClient {
         let request_uri = "/tags";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26989,6 +32563,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListTargetsForPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27033,6 +32608,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListTargetsForSecurityProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27071,6 +32647,7 @@ impl Iot for IotClient {
         let request_uri = "/thing-groups";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListThingGroups");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27121,6 +32698,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListThingGroupsForThing");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27162,6 +32740,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListThingPrincipals");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27197,6 +32776,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListThingRegistrationTaskReports");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27239,6 +32819,7 @@ impl Iot for IotClient {
         let request_uri = "/thing-registration-tasks";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListThingRegistrationTasks");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27280,6 +32861,7 @@ impl Iot for IotClient {
         let request_uri = "/thing-types";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListThingTypes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27321,6 +32903,7 @@ impl Iot for IotClient {
         let request_uri = "/things";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListThings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27371,6 +32954,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListThingsInBillingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27412,6 +32996,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListThingsInThingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27454,6 +33039,7 @@ impl Iot for IotClient {
         let request_uri = "/destinations";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListTopicRuleDestinations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27492,6 +33078,7 @@ impl Iot for IotClient {
         let request_uri = "/rules";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListTopicRules");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27536,6 +33123,7 @@ impl Iot for IotClient {
         let request_uri = "/v2LoggingLevel";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListV2LoggingLevels");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27577,12 +33165,16 @@ impl Iot for IotClient {
         let request_uri = "/violation-events";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListViolationEvents");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
 
         let mut params = Params::new();
-        params.put("endTime", &input.end_time);
+        params.put(
+            "endTime",
+            &rusoto_core::serialization::TimestampFormat::Iso8601.format(&input.end_time),
+        );
         if let Some(ref x) = input.max_results {
             params.put("maxResults", x);
         }
@@ -27592,7 +33184,10 @@ impl Iot for IotClient {
         if let Some(ref x) = input.security_profile_name {
             params.put("securityProfileName", x);
         }
-        params.put("startTime", &input.start_time);
+        params.put(
+            "startTime",
+            &rusoto_core::serialization::TimestampFormat::Iso8601.format(&input.start_time),
+        );
         if let Some(ref x) = input.thing_name {
             params.put("thingName", x);
         }
@@ -27623,6 +33218,7 @@ impl Iot for IotClient {
         let request_uri = "/cacertificate";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("RegisterCACertificate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27663,6 +33259,7 @@ impl Iot for IotClient {
         let request_uri = "/certificate/register";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("RegisterCertificate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27694,6 +33291,7 @@ impl Iot for IotClient {
         let request_uri = "/things";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("RegisterThing");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27728,6 +33326,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "execute-api", &self.region, &request_uri);
+        request.set_operation("RejectCertificateTransfer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27759,6 +33358,7 @@ impl Iot for IotClient {
         let request_uri = "/billing-groups/removeThingFromBillingGroup";
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation("RemoveThingFromBillingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27791,6 +33391,7 @@ impl Iot for IotClient {
         let request_uri = "/thing-groups/removeThingFromThingGroup";
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation("RemoveThingFromThingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27822,6 +33423,7 @@ impl Iot for IotClient {
         let request_uri = format!("/rules/{rule_name}", rule_name = input.rule_name);
 
         let mut request = SignedRequest::new("PATCH", "execute-api", &self.region, &request_uri);
+        request.set_operation("ReplaceTopicRule");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27852,6 +33454,7 @@ impl Iot for IotClient {
         let request_uri = "/indices/search";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("SearchIndex");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27883,6 +33486,7 @@ impl Iot for IotClient {
         let request_uri = "/default-authorizer";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("SetDefaultAuthorizer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27918,6 +33522,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "execute-api", &self.region, &request_uri);
+        request.set_operation("SetDefaultPolicyVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27946,6 +33551,7 @@ impl Iot for IotClient {
         let request_uri = "/loggingOptions";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("SetLoggingOptions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27976,6 +33582,7 @@ impl Iot for IotClient {
         let request_uri = "/v2LoggingLevel";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("SetV2LoggingLevel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28006,6 +33613,7 @@ impl Iot for IotClient {
         let request_uri = "/v2LoggingOptions";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("SetV2LoggingOptions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28042,6 +33650,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("StartAuditMitigationActionsTask");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28075,6 +33684,7 @@ impl Iot for IotClient {
         let request_uri = "/audit/tasks";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("StartOnDemandAuditTask");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28107,6 +33717,7 @@ impl Iot for IotClient {
         let request_uri = "/thing-registration-tasks";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("StartThingRegistrationTask");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28142,6 +33753,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation("StopThingRegistrationTask");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28171,6 +33783,7 @@ impl Iot for IotClient {
         let request_uri = "/tags";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28202,6 +33815,7 @@ impl Iot for IotClient {
         let request_uri = "/test-authorization";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("TestAuthorization");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28242,6 +33856,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("TestInvokeAuthorizer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28276,6 +33891,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "execute-api", &self.region, &request_uri);
+        request.set_operation("TransferCertificate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28311,6 +33927,7 @@ impl Iot for IotClient {
         let request_uri = "/untag";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28345,6 +33962,7 @@ impl Iot for IotClient {
         let request_uri = "/audit/configuration";
 
         let mut request = SignedRequest::new("PATCH", "execute-api", &self.region, &request_uri);
+        request.set_operation("UpdateAccountAuditConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28381,6 +33999,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation("UpdateAuthorizer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28415,6 +34034,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "execute-api", &self.region, &request_uri);
+        request.set_operation("UpdateBillingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28449,6 +34069,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation("UpdateCACertificate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28491,6 +34112,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation("UpdateCertificate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28527,6 +34149,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation("UpdateDomainConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28561,6 +34184,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "execute-api", &self.region, &request_uri);
+        request.set_operation("UpdateDynamicThingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28593,6 +34217,7 @@ impl Iot for IotClient {
         let request_uri = "/event-configurations";
 
         let mut request = SignedRequest::new("PATCH", "execute-api", &self.region, &request_uri);
+        request.set_operation("UpdateEventConfigurations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28625,6 +34250,7 @@ impl Iot for IotClient {
         let request_uri = "/indexing/config";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("UpdateIndexingConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28653,6 +34279,7 @@ impl Iot for IotClient {
         let request_uri = format!("/jobs/{job_id}", job_id = input.job_id);
 
         let mut request = SignedRequest::new("PATCH", "execute-api", &self.region, &request_uri);
+        request.set_operation("UpdateJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28686,6 +34313,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "execute-api", &self.region, &request_uri);
+        request.set_operation("UpdateMitigationAction");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28721,6 +34349,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "execute-api", &self.region, &request_uri);
+        request.set_operation("UpdateProvisioningTemplate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28752,6 +34381,7 @@ impl Iot for IotClient {
         let request_uri = format!("/role-aliases/{role_alias}", role_alias = input.role_alias);
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation("UpdateRoleAlias");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28786,6 +34416,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "execute-api", &self.region, &request_uri);
+        request.set_operation("UpdateScheduledAudit");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28820,6 +34451,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "execute-api", &self.region, &request_uri);
+        request.set_operation("UpdateSecurityProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28857,6 +34489,7 @@ impl Iot for IotClient {
         let request_uri = format!("/streams/{stream_id}", stream_id = input.stream_id);
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation("UpdateStream");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28888,6 +34521,7 @@ impl Iot for IotClient {
         let request_uri = format!("/things/{thing_name}", thing_name = input.thing_name);
 
         let mut request = SignedRequest::new("PATCH", "execute-api", &self.region, &request_uri);
+        request.set_operation("UpdateThing");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28922,6 +34556,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "execute-api", &self.region, &request_uri);
+        request.set_operation("UpdateThingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28954,6 +34589,7 @@ impl Iot for IotClient {
         let request_uri = "/thing-groups/updateThingGroupsForThing";
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation("UpdateThingGroupsForThing");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28986,6 +34622,7 @@ impl Iot for IotClient {
         let request_uri = "/destinations";
 
         let mut request = SignedRequest::new("PATCH", "execute-api", &self.region, &request_uri);
+        request.set_operation("UpdateTopicRuleDestination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -29020,6 +34657,7 @@ impl Iot for IotClient {
         let request_uri = "/security-profile-behaviors/validate";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("ValidateSecurityProfileBehaviors");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -29045,3 +34683,1543 @@ impl Iot for IotClient {
         }
     }
 }
+impl IotClient {
+    /// Auto-paginating version of `list_active_violations`, streaming every page of results.
+    pub fn list_active_violations_pages(
+        &self,
+        input: ListActiveViolationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListActiveViolationsResponse,
+        ListActiveViolationsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotClient, input: ListActiveViolationsRequest| async move {
+                client.list_active_violations(input).await
+            },
+            |input: &ListActiveViolationsRequest, output: &ListActiveViolationsResponse| {
+                Some(ListActiveViolationsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_active_violations`, streaming the `activeViolations` of every page of results.
+    pub fn list_active_violations_items(
+        &self,
+        input: ListActiveViolationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ActiveViolation, ListActiveViolationsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_active_violations_pages(input),
+            |output: ListActiveViolationsResponse| output.active_violations.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_attached_policies`, streaming every page of results.
+    pub fn list_attached_policies_pages(
+        &self,
+        input: ListAttachedPoliciesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListAttachedPoliciesResponse,
+        ListAttachedPoliciesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotClient, input: ListAttachedPoliciesRequest| async move {
+                client.list_attached_policies(input).await
+            },
+            |input: &ListAttachedPoliciesRequest, output: &ListAttachedPoliciesResponse| {
+                Some(ListAttachedPoliciesRequest {
+                    marker: Some(output.next_marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_attached_policies`, streaming the `policies` of every page of results.
+    pub fn list_attached_policies_items(
+        &self,
+        input: ListAttachedPoliciesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Policy, ListAttachedPoliciesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_attached_policies_pages(input),
+            |output: ListAttachedPoliciesResponse| output.policies.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_audit_findings`, streaming every page of results.
+    pub fn list_audit_findings_pages(
+        &self,
+        input: ListAuditFindingsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListAuditFindingsResponse, ListAuditFindingsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotClient, input: ListAuditFindingsRequest| async move {
+                client.list_audit_findings(input).await
+            },
+            |input: &ListAuditFindingsRequest, output: &ListAuditFindingsResponse| {
+                Some(ListAuditFindingsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_audit_findings`, streaming the `findings` of every page of results.
+    pub fn list_audit_findings_items(
+        &self,
+        input: ListAuditFindingsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<AuditFinding, ListAuditFindingsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_audit_findings_pages(input),
+            |output: ListAuditFindingsResponse| output.findings.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_audit_mitigation_actions_executions`, streaming every page of results.
+    pub fn list_audit_mitigation_actions_executions_pages(
+        &self,
+        input: ListAuditMitigationActionsExecutionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListAuditMitigationActionsExecutionsResponse,
+        ListAuditMitigationActionsExecutionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotClient, input: ListAuditMitigationActionsExecutionsRequest| async move {
+                client.list_audit_mitigation_actions_executions(input).await
+            },
+            |input: &ListAuditMitigationActionsExecutionsRequest,
+             output: &ListAuditMitigationActionsExecutionsResponse| {
+                Some(ListAuditMitigationActionsExecutionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_audit_mitigation_actions_executions`, streaming the `actionsExecutions` of every page of results.
+    pub fn list_audit_mitigation_actions_executions_items(
+        &self,
+        input: ListAuditMitigationActionsExecutionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        AuditMitigationActionExecutionMetadata,
+        ListAuditMitigationActionsExecutionsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_audit_mitigation_actions_executions_pages(input),
+            |output: ListAuditMitigationActionsExecutionsResponse| {
+                output.actions_executions.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_audit_mitigation_actions_tasks`, streaming every page of results.
+    pub fn list_audit_mitigation_actions_tasks_pages(
+        &self,
+        input: ListAuditMitigationActionsTasksRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListAuditMitigationActionsTasksResponse,
+        ListAuditMitigationActionsTasksError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotClient, input: ListAuditMitigationActionsTasksRequest| async move {
+                client.list_audit_mitigation_actions_tasks(input).await
+            },
+            |input: &ListAuditMitigationActionsTasksRequest,
+             output: &ListAuditMitigationActionsTasksResponse| {
+                Some(ListAuditMitigationActionsTasksRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_audit_mitigation_actions_tasks`, streaming the `tasks` of every page of results.
+    pub fn list_audit_mitigation_actions_tasks_items(
+        &self,
+        input: ListAuditMitigationActionsTasksRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        AuditMitigationActionsTaskMetadata,
+        ListAuditMitigationActionsTasksError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_audit_mitigation_actions_tasks_pages(input),
+            |output: ListAuditMitigationActionsTasksResponse| output.tasks.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_audit_tasks`, streaming every page of results.
+    pub fn list_audit_tasks_pages(
+        &self,
+        input: ListAuditTasksRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListAuditTasksResponse, ListAuditTasksError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotClient, input: ListAuditTasksRequest| async move {
+                client.list_audit_tasks(input).await
+            },
+            |input: &ListAuditTasksRequest, output: &ListAuditTasksResponse| {
+                Some(ListAuditTasksRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_audit_tasks`, streaming the `tasks` of every page of results.
+    pub fn list_audit_tasks_items(
+        &self,
+        input: ListAuditTasksRequest,
+    ) -> rusoto_core::pagination::RusotoStream<AuditTaskMetadata, ListAuditTasksError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_audit_tasks_pages(input),
+            |output: ListAuditTasksResponse| output.tasks.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_authorizers`, streaming every page of results.
+    pub fn list_authorizers_pages(
+        &self,
+        input: ListAuthorizersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListAuthorizersResponse, ListAuthorizersError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotClient, input: ListAuthorizersRequest| async move {
+                client.list_authorizers(input).await
+            },
+            |input: &ListAuthorizersRequest, output: &ListAuthorizersResponse| {
+                Some(ListAuthorizersRequest {
+                    marker: Some(output.next_marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_authorizers`, streaming the `authorizers` of every page of results.
+    pub fn list_authorizers_items(
+        &self,
+        input: ListAuthorizersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<AuthorizerSummary, ListAuthorizersError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_authorizers_pages(input),
+            |output: ListAuthorizersResponse| output.authorizers.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_billing_groups`, streaming every page of results.
+    pub fn list_billing_groups_pages(
+        &self,
+        input: ListBillingGroupsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListBillingGroupsResponse, ListBillingGroupsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotClient, input: ListBillingGroupsRequest| async move {
+                client.list_billing_groups(input).await
+            },
+            |input: &ListBillingGroupsRequest, output: &ListBillingGroupsResponse| {
+                Some(ListBillingGroupsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_billing_groups`, streaming the `billingGroups` of every page of results.
+    pub fn list_billing_groups_items(
+        &self,
+        input: ListBillingGroupsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GroupNameAndArn, ListBillingGroupsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_billing_groups_pages(input),
+            |output: ListBillingGroupsResponse| output.billing_groups.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_ca_certificates`, streaming every page of results.
+    pub fn list_ca_certificates_pages(
+        &self,
+        input: ListCACertificatesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListCACertificatesResponse, ListCACertificatesError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotClient, input: ListCACertificatesRequest| async move {
+                client.list_ca_certificates(input).await
+            },
+            |input: &ListCACertificatesRequest, output: &ListCACertificatesResponse| {
+                Some(ListCACertificatesRequest {
+                    marker: Some(output.next_marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_ca_certificates`, streaming the `certificates` of every page of results.
+    pub fn list_ca_certificates_items(
+        &self,
+        input: ListCACertificatesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<CACertificate, ListCACertificatesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_ca_certificates_pages(input),
+            |output: ListCACertificatesResponse| output.certificates.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_certificates`, streaming every page of results.
+    pub fn list_certificates_pages(
+        &self,
+        input: ListCertificatesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListCertificatesResponse, ListCertificatesError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotClient, input: ListCertificatesRequest| async move {
+                client.list_certificates(input).await
+            },
+            |input: &ListCertificatesRequest, output: &ListCertificatesResponse| {
+                Some(ListCertificatesRequest {
+                    marker: Some(output.next_marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_certificates`, streaming the `certificates` of every page of results.
+    pub fn list_certificates_items(
+        &self,
+        input: ListCertificatesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Certificate, ListCertificatesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_certificates_pages(input),
+            |output: ListCertificatesResponse| output.certificates.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_certificates_by_ca`, streaming every page of results.
+    pub fn list_certificates_by_ca_pages(
+        &self,
+        input: ListCertificatesByCARequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListCertificatesByCAResponse,
+        ListCertificatesByCAError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotClient, input: ListCertificatesByCARequest| async move {
+                client.list_certificates_by_ca(input).await
+            },
+            |input: &ListCertificatesByCARequest, output: &ListCertificatesByCAResponse| {
+                Some(ListCertificatesByCARequest {
+                    marker: Some(output.next_marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_certificates_by_ca`, streaming the `certificates` of every page of results.
+    pub fn list_certificates_by_ca_items(
+        &self,
+        input: ListCertificatesByCARequest,
+    ) -> rusoto_core::pagination::RusotoStream<Certificate, ListCertificatesByCAError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_certificates_by_ca_pages(input),
+            |output: ListCertificatesByCAResponse| output.certificates.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_domain_configurations`, streaming every page of results.
+    pub fn list_domain_configurations_pages(
+        &self,
+        input: ListDomainConfigurationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListDomainConfigurationsResponse,
+        ListDomainConfigurationsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotClient, input: ListDomainConfigurationsRequest| async move {
+                client.list_domain_configurations(input).await
+            },
+            |input: &ListDomainConfigurationsRequest, output: &ListDomainConfigurationsResponse| {
+                Some(ListDomainConfigurationsRequest {
+                    marker: Some(output.next_marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_domain_configurations`, streaming the `domainConfigurations` of every page of results.
+    pub fn list_domain_configurations_items(
+        &self,
+        input: ListDomainConfigurationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DomainConfigurationSummary,
+        ListDomainConfigurationsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_domain_configurations_pages(input),
+            |output: ListDomainConfigurationsResponse| {
+                output.domain_configurations.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_indices`, streaming every page of results.
+    pub fn list_indices_pages(
+        &self,
+        input: ListIndicesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListIndicesResponse, ListIndicesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotClient, input: ListIndicesRequest| async move {
+                client.list_indices(input).await
+            },
+            |input: &ListIndicesRequest, output: &ListIndicesResponse| {
+                Some(ListIndicesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_indices`, streaming the `indexNames` of every page of results.
+    pub fn list_indices_items(
+        &self,
+        input: ListIndicesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListIndicesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_indices_pages(input),
+            |output: ListIndicesResponse| output.index_names.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_job_executions_for_job`, streaming every page of results.
+    pub fn list_job_executions_for_job_pages(
+        &self,
+        input: ListJobExecutionsForJobRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListJobExecutionsForJobResponse,
+        ListJobExecutionsForJobError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotClient, input: ListJobExecutionsForJobRequest| async move {
+                client.list_job_executions_for_job(input).await
+            },
+            |input: &ListJobExecutionsForJobRequest, output: &ListJobExecutionsForJobResponse| {
+                Some(ListJobExecutionsForJobRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_job_executions_for_job`, streaming the `executionSummaries` of every page of results.
+    pub fn list_job_executions_for_job_items(
+        &self,
+        input: ListJobExecutionsForJobRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        JobExecutionSummaryForJob,
+        ListJobExecutionsForJobError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_job_executions_for_job_pages(input),
+            |output: ListJobExecutionsForJobResponse| {
+                output.execution_summaries.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_job_executions_for_thing`, streaming every page of results.
+    pub fn list_job_executions_for_thing_pages(
+        &self,
+        input: ListJobExecutionsForThingRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListJobExecutionsForThingResponse,
+        ListJobExecutionsForThingError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotClient, input: ListJobExecutionsForThingRequest| async move {
+                client.list_job_executions_for_thing(input).await
+            },
+            |input: &ListJobExecutionsForThingRequest,
+             output: &ListJobExecutionsForThingResponse| {
+                Some(ListJobExecutionsForThingRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_job_executions_for_thing`, streaming the `executionSummaries` of every page of results.
+    pub fn list_job_executions_for_thing_items(
+        &self,
+        input: ListJobExecutionsForThingRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        JobExecutionSummaryForThing,
+        ListJobExecutionsForThingError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_job_executions_for_thing_pages(input),
+            |output: ListJobExecutionsForThingResponse| {
+                output.execution_summaries.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_jobs`, streaming every page of results.
+    pub fn list_jobs_pages(
+        &self,
+        input: ListJobsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListJobsResponse, ListJobsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotClient, input: ListJobsRequest| async move { client.list_jobs(input).await },
+            |input: &ListJobsRequest, output: &ListJobsResponse| {
+                Some(ListJobsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_jobs`, streaming the `jobs` of every page of results.
+    pub fn list_jobs_items(
+        &self,
+        input: ListJobsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<JobSummary, ListJobsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_jobs_pages(input),
+            |output: ListJobsResponse| output.jobs.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_mitigation_actions`, streaming every page of results.
+    pub fn list_mitigation_actions_pages(
+        &self,
+        input: ListMitigationActionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListMitigationActionsResponse,
+        ListMitigationActionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotClient, input: ListMitigationActionsRequest| async move {
+                client.list_mitigation_actions(input).await
+            },
+            |input: &ListMitigationActionsRequest, output: &ListMitigationActionsResponse| {
+                Some(ListMitigationActionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_mitigation_actions`, streaming the `actionIdentifiers` of every page of results.
+    pub fn list_mitigation_actions_items(
+        &self,
+        input: ListMitigationActionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<MitigationActionIdentifier, ListMitigationActionsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_mitigation_actions_pages(input),
+            |output: ListMitigationActionsResponse| output.action_identifiers.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_ota_updates`, streaming every page of results.
+    pub fn list_ota_updates_pages(
+        &self,
+        input: ListOTAUpdatesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListOTAUpdatesResponse, ListOTAUpdatesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotClient, input: ListOTAUpdatesRequest| async move {
+                client.list_ota_updates(input).await
+            },
+            |input: &ListOTAUpdatesRequest, output: &ListOTAUpdatesResponse| {
+                Some(ListOTAUpdatesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_ota_updates`, streaming the `otaUpdates` of every page of results.
+    pub fn list_ota_updates_items(
+        &self,
+        input: ListOTAUpdatesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<OTAUpdateSummary, ListOTAUpdatesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_ota_updates_pages(input),
+            |output: ListOTAUpdatesResponse| output.ota_updates.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_outgoing_certificates`, streaming every page of results.
+    pub fn list_outgoing_certificates_pages(
+        &self,
+        input: ListOutgoingCertificatesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListOutgoingCertificatesResponse,
+        ListOutgoingCertificatesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotClient, input: ListOutgoingCertificatesRequest| async move {
+                client.list_outgoing_certificates(input).await
+            },
+            |input: &ListOutgoingCertificatesRequest, output: &ListOutgoingCertificatesResponse| {
+                Some(ListOutgoingCertificatesRequest {
+                    marker: Some(output.next_marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_outgoing_certificates`, streaming the `outgoingCertificates` of every page of results.
+    pub fn list_outgoing_certificates_items(
+        &self,
+        input: ListOutgoingCertificatesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<OutgoingCertificate, ListOutgoingCertificatesError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_outgoing_certificates_pages(input),
+            |output: ListOutgoingCertificatesResponse| {
+                output.outgoing_certificates.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_policies`, streaming every page of results.
+    pub fn list_policies_pages(
+        &self,
+        input: ListPoliciesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListPoliciesResponse, ListPoliciesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotClient, input: ListPoliciesRequest| async move {
+                client.list_policies(input).await
+            },
+            |input: &ListPoliciesRequest, output: &ListPoliciesResponse| {
+                Some(ListPoliciesRequest {
+                    marker: Some(output.next_marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_policies`, streaming the `policies` of every page of results.
+    pub fn list_policies_items(
+        &self,
+        input: ListPoliciesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Policy, ListPoliciesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_policies_pages(input),
+            |output: ListPoliciesResponse| output.policies.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_policy_principals`, streaming every page of results.
+    pub fn list_policy_principals_pages(
+        &self,
+        input: ListPolicyPrincipalsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListPolicyPrincipalsResponse,
+        ListPolicyPrincipalsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotClient, input: ListPolicyPrincipalsRequest| async move {
+                client.list_policy_principals(input).await
+            },
+            |input: &ListPolicyPrincipalsRequest, output: &ListPolicyPrincipalsResponse| {
+                Some(ListPolicyPrincipalsRequest {
+                    marker: Some(output.next_marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_policy_principals`, streaming the `principals` of every page of results.
+    pub fn list_policy_principals_items(
+        &self,
+        input: ListPolicyPrincipalsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListPolicyPrincipalsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_policy_principals_pages(input),
+            |output: ListPolicyPrincipalsResponse| output.principals.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_principal_policies`, streaming every page of results.
+    pub fn list_principal_policies_pages(
+        &self,
+        input: ListPrincipalPoliciesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListPrincipalPoliciesResponse,
+        ListPrincipalPoliciesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotClient, input: ListPrincipalPoliciesRequest| async move {
+                client.list_principal_policies(input).await
+            },
+            |input: &ListPrincipalPoliciesRequest, output: &ListPrincipalPoliciesResponse| {
+                Some(ListPrincipalPoliciesRequest {
+                    marker: Some(output.next_marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_principal_policies`, streaming the `policies` of every page of results.
+    pub fn list_principal_policies_items(
+        &self,
+        input: ListPrincipalPoliciesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Policy, ListPrincipalPoliciesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_principal_policies_pages(input),
+            |output: ListPrincipalPoliciesResponse| output.policies.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_principal_things`, streaming every page of results.
+    pub fn list_principal_things_pages(
+        &self,
+        input: ListPrincipalThingsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListPrincipalThingsResponse, ListPrincipalThingsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotClient, input: ListPrincipalThingsRequest| async move {
+                client.list_principal_things(input).await
+            },
+            |input: &ListPrincipalThingsRequest, output: &ListPrincipalThingsResponse| {
+                Some(ListPrincipalThingsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_principal_things`, streaming the `things` of every page of results.
+    pub fn list_principal_things_items(
+        &self,
+        input: ListPrincipalThingsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListPrincipalThingsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_principal_things_pages(input),
+            |output: ListPrincipalThingsResponse| output.things.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_provisioning_template_versions`, streaming every page of results.
+    pub fn list_provisioning_template_versions_pages(
+        &self,
+        input: ListProvisioningTemplateVersionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListProvisioningTemplateVersionsResponse,
+        ListProvisioningTemplateVersionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotClient, input: ListProvisioningTemplateVersionsRequest| async move {
+                client.list_provisioning_template_versions(input).await
+            },
+            |input: &ListProvisioningTemplateVersionsRequest,
+             output: &ListProvisioningTemplateVersionsResponse| {
+                Some(ListProvisioningTemplateVersionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_provisioning_template_versions`, streaming the `versions` of every page of results.
+    pub fn list_provisioning_template_versions_items(
+        &self,
+        input: ListProvisioningTemplateVersionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ProvisioningTemplateVersionSummary,
+        ListProvisioningTemplateVersionsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_provisioning_template_versions_pages(input),
+            |output: ListProvisioningTemplateVersionsResponse| output.versions.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_provisioning_templates`, streaming every page of results.
+    pub fn list_provisioning_templates_pages(
+        &self,
+        input: ListProvisioningTemplatesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListProvisioningTemplatesResponse,
+        ListProvisioningTemplatesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotClient, input: ListProvisioningTemplatesRequest| async move {
+                client.list_provisioning_templates(input).await
+            },
+            |input: &ListProvisioningTemplatesRequest,
+             output: &ListProvisioningTemplatesResponse| {
+                Some(ListProvisioningTemplatesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_provisioning_templates`, streaming the `templates` of every page of results.
+    pub fn list_provisioning_templates_items(
+        &self,
+        input: ListProvisioningTemplatesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ProvisioningTemplateSummary,
+        ListProvisioningTemplatesError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_provisioning_templates_pages(input),
+            |output: ListProvisioningTemplatesResponse| output.templates.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_role_aliases`, streaming every page of results.
+    pub fn list_role_aliases_pages(
+        &self,
+        input: ListRoleAliasesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListRoleAliasesResponse, ListRoleAliasesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotClient, input: ListRoleAliasesRequest| async move {
+                client.list_role_aliases(input).await
+            },
+            |input: &ListRoleAliasesRequest, output: &ListRoleAliasesResponse| {
+                Some(ListRoleAliasesRequest {
+                    marker: Some(output.next_marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_role_aliases`, streaming the `roleAliases` of every page of results.
+    pub fn list_role_aliases_items(
+        &self,
+        input: ListRoleAliasesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListRoleAliasesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_role_aliases_pages(input),
+            |output: ListRoleAliasesResponse| output.role_aliases.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_scheduled_audits`, streaming every page of results.
+    pub fn list_scheduled_audits_pages(
+        &self,
+        input: ListScheduledAuditsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListScheduledAuditsResponse, ListScheduledAuditsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotClient, input: ListScheduledAuditsRequest| async move {
+                client.list_scheduled_audits(input).await
+            },
+            |input: &ListScheduledAuditsRequest, output: &ListScheduledAuditsResponse| {
+                Some(ListScheduledAuditsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_scheduled_audits`, streaming the `scheduledAudits` of every page of results.
+    pub fn list_scheduled_audits_items(
+        &self,
+        input: ListScheduledAuditsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ScheduledAuditMetadata, ListScheduledAuditsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_scheduled_audits_pages(input),
+            |output: ListScheduledAuditsResponse| output.scheduled_audits.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_security_profiles`, streaming every page of results.
+    pub fn list_security_profiles_pages(
+        &self,
+        input: ListSecurityProfilesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListSecurityProfilesResponse,
+        ListSecurityProfilesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotClient, input: ListSecurityProfilesRequest| async move {
+                client.list_security_profiles(input).await
+            },
+            |input: &ListSecurityProfilesRequest, output: &ListSecurityProfilesResponse| {
+                Some(ListSecurityProfilesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_security_profiles`, streaming the `securityProfileIdentifiers` of every page of results.
+    pub fn list_security_profiles_items(
+        &self,
+        input: ListSecurityProfilesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<SecurityProfileIdentifier, ListSecurityProfilesError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_security_profiles_pages(input),
+            |output: ListSecurityProfilesResponse| {
+                output.security_profile_identifiers.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_security_profiles_for_target`, streaming every page of results.
+    pub fn list_security_profiles_for_target_pages(
+        &self,
+        input: ListSecurityProfilesForTargetRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListSecurityProfilesForTargetResponse,
+        ListSecurityProfilesForTargetError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotClient, input: ListSecurityProfilesForTargetRequest| async move {
+                client.list_security_profiles_for_target(input).await
+            },
+            |input: &ListSecurityProfilesForTargetRequest,
+             output: &ListSecurityProfilesForTargetResponse| {
+                Some(ListSecurityProfilesForTargetRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_security_profiles_for_target`, streaming the `securityProfileTargetMappings` of every page of results.
+    pub fn list_security_profiles_for_target_items(
+        &self,
+        input: ListSecurityProfilesForTargetRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        SecurityProfileTargetMapping,
+        ListSecurityProfilesForTargetError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_security_profiles_for_target_pages(input),
+            |output: ListSecurityProfilesForTargetResponse| {
+                output.security_profile_target_mappings.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_streams`, streaming every page of results.
+    pub fn list_streams_pages(
+        &self,
+        input: ListStreamsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListStreamsResponse, ListStreamsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotClient, input: ListStreamsRequest| async move {
+                client.list_streams(input).await
+            },
+            |input: &ListStreamsRequest, output: &ListStreamsResponse| {
+                Some(ListStreamsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_streams`, streaming the `streams` of every page of results.
+    pub fn list_streams_items(
+        &self,
+        input: ListStreamsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<StreamSummary, ListStreamsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_streams_pages(input),
+            |output: ListStreamsResponse| output.streams.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_tags_for_resource`, streaming every page of results.
+    pub fn list_tags_for_resource_pages(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListTagsForResourceResponse, ListTagsForResourceError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotClient, input: ListTagsForResourceRequest| async move {
+                client.list_tags_for_resource(input).await
+            },
+            |input: &ListTagsForResourceRequest, output: &ListTagsForResourceResponse| {
+                Some(ListTagsForResourceRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_tags_for_resource`, streaming the `tags` of every page of results.
+    pub fn list_tags_for_resource_items(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Tag, ListTagsForResourceError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_tags_for_resource_pages(input),
+            |output: ListTagsForResourceResponse| output.tags.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_targets_for_policy`, streaming every page of results.
+    pub fn list_targets_for_policy_pages(
+        &self,
+        input: ListTargetsForPolicyRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListTargetsForPolicyResponse,
+        ListTargetsForPolicyError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotClient, input: ListTargetsForPolicyRequest| async move {
+                client.list_targets_for_policy(input).await
+            },
+            |input: &ListTargetsForPolicyRequest, output: &ListTargetsForPolicyResponse| {
+                Some(ListTargetsForPolicyRequest {
+                    marker: Some(output.next_marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_targets_for_policy`, streaming the `targets` of every page of results.
+    pub fn list_targets_for_policy_items(
+        &self,
+        input: ListTargetsForPolicyRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListTargetsForPolicyError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_targets_for_policy_pages(input),
+            |output: ListTargetsForPolicyResponse| output.targets.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_targets_for_security_profile`, streaming every page of results.
+    pub fn list_targets_for_security_profile_pages(
+        &self,
+        input: ListTargetsForSecurityProfileRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListTargetsForSecurityProfileResponse,
+        ListTargetsForSecurityProfileError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotClient, input: ListTargetsForSecurityProfileRequest| async move {
+                client.list_targets_for_security_profile(input).await
+            },
+            |input: &ListTargetsForSecurityProfileRequest,
+             output: &ListTargetsForSecurityProfileResponse| {
+                Some(ListTargetsForSecurityProfileRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_targets_for_security_profile`, streaming the `securityProfileTargets` of every page of results.
+    pub fn list_targets_for_security_profile_items(
+        &self,
+        input: ListTargetsForSecurityProfileRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        SecurityProfileTarget,
+        ListTargetsForSecurityProfileError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_targets_for_security_profile_pages(input),
+            |output: ListTargetsForSecurityProfileResponse| {
+                output.security_profile_targets.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_thing_groups`, streaming every page of results.
+    pub fn list_thing_groups_pages(
+        &self,
+        input: ListThingGroupsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListThingGroupsResponse, ListThingGroupsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotClient, input: ListThingGroupsRequest| async move {
+                client.list_thing_groups(input).await
+            },
+            |input: &ListThingGroupsRequest, output: &ListThingGroupsResponse| {
+                Some(ListThingGroupsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_thing_groups`, streaming the `thingGroups` of every page of results.
+    pub fn list_thing_groups_items(
+        &self,
+        input: ListThingGroupsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GroupNameAndArn, ListThingGroupsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_thing_groups_pages(input),
+            |output: ListThingGroupsResponse| output.thing_groups.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_thing_groups_for_thing`, streaming every page of results.
+    pub fn list_thing_groups_for_thing_pages(
+        &self,
+        input: ListThingGroupsForThingRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListThingGroupsForThingResponse,
+        ListThingGroupsForThingError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotClient, input: ListThingGroupsForThingRequest| async move {
+                client.list_thing_groups_for_thing(input).await
+            },
+            |input: &ListThingGroupsForThingRequest, output: &ListThingGroupsForThingResponse| {
+                Some(ListThingGroupsForThingRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_thing_groups_for_thing`, streaming the `thingGroups` of every page of results.
+    pub fn list_thing_groups_for_thing_items(
+        &self,
+        input: ListThingGroupsForThingRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GroupNameAndArn, ListThingGroupsForThingError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_thing_groups_for_thing_pages(input),
+            |output: ListThingGroupsForThingResponse| output.thing_groups.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_thing_registration_task_reports`, streaming every page of results.
+    pub fn list_thing_registration_task_reports_pages(
+        &self,
+        input: ListThingRegistrationTaskReportsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListThingRegistrationTaskReportsResponse,
+        ListThingRegistrationTaskReportsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotClient, input: ListThingRegistrationTaskReportsRequest| async move {
+                client.list_thing_registration_task_reports(input).await
+            },
+            |input: &ListThingRegistrationTaskReportsRequest,
+             output: &ListThingRegistrationTaskReportsResponse| {
+                Some(ListThingRegistrationTaskReportsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_thing_registration_task_reports`, streaming the `resourceLinks` of every page of results.
+    pub fn list_thing_registration_task_reports_items(
+        &self,
+        input: ListThingRegistrationTaskReportsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListThingRegistrationTaskReportsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_thing_registration_task_reports_pages(input),
+            |output: ListThingRegistrationTaskReportsResponse| {
+                output.resource_links.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_thing_registration_tasks`, streaming every page of results.
+    pub fn list_thing_registration_tasks_pages(
+        &self,
+        input: ListThingRegistrationTasksRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListThingRegistrationTasksResponse,
+        ListThingRegistrationTasksError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotClient, input: ListThingRegistrationTasksRequest| async move {
+                client.list_thing_registration_tasks(input).await
+            },
+            |input: &ListThingRegistrationTasksRequest,
+             output: &ListThingRegistrationTasksResponse| {
+                Some(ListThingRegistrationTasksRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_thing_registration_tasks`, streaming the `taskIds` of every page of results.
+    pub fn list_thing_registration_tasks_items(
+        &self,
+        input: ListThingRegistrationTasksRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListThingRegistrationTasksError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_thing_registration_tasks_pages(input),
+            |output: ListThingRegistrationTasksResponse| output.task_ids.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_thing_types`, streaming every page of results.
+    pub fn list_thing_types_pages(
+        &self,
+        input: ListThingTypesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListThingTypesResponse, ListThingTypesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotClient, input: ListThingTypesRequest| async move {
+                client.list_thing_types(input).await
+            },
+            |input: &ListThingTypesRequest, output: &ListThingTypesResponse| {
+                Some(ListThingTypesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_thing_types`, streaming the `thingTypes` of every page of results.
+    pub fn list_thing_types_items(
+        &self,
+        input: ListThingTypesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ThingTypeDefinition, ListThingTypesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_thing_types_pages(input),
+            |output: ListThingTypesResponse| output.thing_types.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_things`, streaming every page of results.
+    pub fn list_things_pages(
+        &self,
+        input: ListThingsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListThingsResponse, ListThingsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotClient, input: ListThingsRequest| async move { client.list_things(input).await },
+            |input: &ListThingsRequest, output: &ListThingsResponse| {
+                Some(ListThingsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_things`, streaming the `things` of every page of results.
+    pub fn list_things_items(
+        &self,
+        input: ListThingsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ThingAttribute, ListThingsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_things_pages(input),
+            |output: ListThingsResponse| output.things.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_things_in_billing_group`, streaming every page of results.
+    pub fn list_things_in_billing_group_pages(
+        &self,
+        input: ListThingsInBillingGroupRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListThingsInBillingGroupResponse,
+        ListThingsInBillingGroupError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotClient, input: ListThingsInBillingGroupRequest| async move {
+                client.list_things_in_billing_group(input).await
+            },
+            |input: &ListThingsInBillingGroupRequest, output: &ListThingsInBillingGroupResponse| {
+                Some(ListThingsInBillingGroupRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_things_in_billing_group`, streaming the `things` of every page of results.
+    pub fn list_things_in_billing_group_items(
+        &self,
+        input: ListThingsInBillingGroupRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListThingsInBillingGroupError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_things_in_billing_group_pages(input),
+            |output: ListThingsInBillingGroupResponse| output.things.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_things_in_thing_group`, streaming every page of results.
+    pub fn list_things_in_thing_group_pages(
+        &self,
+        input: ListThingsInThingGroupRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListThingsInThingGroupResponse,
+        ListThingsInThingGroupError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotClient, input: ListThingsInThingGroupRequest| async move {
+                client.list_things_in_thing_group(input).await
+            },
+            |input: &ListThingsInThingGroupRequest, output: &ListThingsInThingGroupResponse| {
+                Some(ListThingsInThingGroupRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_things_in_thing_group`, streaming the `things` of every page of results.
+    pub fn list_things_in_thing_group_items(
+        &self,
+        input: ListThingsInThingGroupRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListThingsInThingGroupError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_things_in_thing_group_pages(input),
+            |output: ListThingsInThingGroupResponse| output.things.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_topic_rule_destinations`, streaming every page of results.
+    pub fn list_topic_rule_destinations_pages(
+        &self,
+        input: ListTopicRuleDestinationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListTopicRuleDestinationsResponse,
+        ListTopicRuleDestinationsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotClient, input: ListTopicRuleDestinationsRequest| async move {
+                client.list_topic_rule_destinations(input).await
+            },
+            |input: &ListTopicRuleDestinationsRequest,
+             output: &ListTopicRuleDestinationsResponse| {
+                Some(ListTopicRuleDestinationsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_topic_rule_destinations`, streaming the `destinationSummaries` of every page of results.
+    pub fn list_topic_rule_destinations_items(
+        &self,
+        input: ListTopicRuleDestinationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        TopicRuleDestinationSummary,
+        ListTopicRuleDestinationsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_topic_rule_destinations_pages(input),
+            |output: ListTopicRuleDestinationsResponse| {
+                output.destination_summaries.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_topic_rules`, streaming every page of results.
+    pub fn list_topic_rules_pages(
+        &self,
+        input: ListTopicRulesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListTopicRulesResponse, ListTopicRulesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotClient, input: ListTopicRulesRequest| async move {
+                client.list_topic_rules(input).await
+            },
+            |input: &ListTopicRulesRequest, output: &ListTopicRulesResponse| {
+                Some(ListTopicRulesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_topic_rules`, streaming the `rules` of every page of results.
+    pub fn list_topic_rules_items(
+        &self,
+        input: ListTopicRulesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<TopicRuleListItem, ListTopicRulesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_topic_rules_pages(input),
+            |output: ListTopicRulesResponse| output.rules.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_v2_logging_levels`, streaming every page of results.
+    pub fn list_v2_logging_levels_pages(
+        &self,
+        input: ListV2LoggingLevelsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListV2LoggingLevelsResponse, ListV2LoggingLevelsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotClient, input: ListV2LoggingLevelsRequest| async move {
+                client.list_v2_logging_levels(input).await
+            },
+            |input: &ListV2LoggingLevelsRequest, output: &ListV2LoggingLevelsResponse| {
+                Some(ListV2LoggingLevelsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_v2_logging_levels`, streaming the `logTargetConfigurations` of every page of results.
+    pub fn list_v2_logging_levels_items(
+        &self,
+        input: ListV2LoggingLevelsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<LogTargetConfiguration, ListV2LoggingLevelsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_v2_logging_levels_pages(input),
+            |output: ListV2LoggingLevelsResponse| {
+                output.log_target_configurations.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_violation_events`, streaming every page of results.
+    pub fn list_violation_events_pages(
+        &self,
+        input: ListViolationEventsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListViolationEventsResponse, ListViolationEventsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotClient, input: ListViolationEventsRequest| async move {
+                client.list_violation_events(input).await
+            },
+            |input: &ListViolationEventsRequest, output: &ListViolationEventsResponse| {
+                Some(ListViolationEventsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_violation_events`, streaming the `violationEvents` of every page of results.
+    pub fn list_violation_events_items(
+        &self,
+        input: ListViolationEventsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ViolationEvent, ListViolationEventsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_violation_events_pages(input),
+            |output: ListViolationEventsResponse| output.violation_events.unwrap_or_default(),
+        )
+    }
+}
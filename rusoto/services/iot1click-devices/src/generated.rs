@@ -239,7 +239,12 @@ pub struct ListDeviceEventsRequest {
     /// 2018-03-28T15:45:12.880Z
     /// </p>
     #[serde(rename = "FromTimeStamp")]
-    pub from_time_stamp: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
+    pub from_time_stamp: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The maximum number of results to return per request. If not set, a default value of
     /// 100 is used.</p>
     #[serde(rename = "MaxResults")]
@@ -253,7 +258,12 @@ pub struct ListDeviceEventsRequest {
     /// 2018-03-28T15:45:12.880Z
     /// </p>
     #[serde(rename = "ToTimeStamp")]
-    pub to_time_stamp: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
+    pub to_time_stamp: ::chrono::DateTime<::chrono::Utc>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1081,11 +1091,15 @@ pub struct Iot1ClickDevicesClient {
 impl Iot1ClickDevicesClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> Iot1ClickDevicesClient {
         Iot1ClickDevicesClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(
+                region,
+                "IoT 1Click Devices Service",
+            ),
         }
     }
 
@@ -1120,6 +1134,7 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         let request_uri = format!("/claims/{claim_code}", claim_code = input.claim_code);
 
         let mut request = SignedRequest::new("PUT", "iot1click", &self.region, &request_uri);
+        request.set_operation("ClaimDevicesByClaimCode");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1150,6 +1165,7 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         let request_uri = format!("/devices/{device_id}", device_id = input.device_id);
 
         let mut request = SignedRequest::new("GET", "iot1click", &self.region, &request_uri);
+        request.set_operation("DescribeDevice");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1187,6 +1203,7 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         );
 
         let mut request = SignedRequest::new("PUT", "iot1click", &self.region, &request_uri);
+        request.set_operation("FinalizeDeviceClaim");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1218,6 +1235,7 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         let request_uri = format!("/devices/{device_id}/methods", device_id = input.device_id);
 
         let mut request = SignedRequest::new("GET", "iot1click", &self.region, &request_uri);
+        request.set_operation("GetDeviceMethods");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1255,6 +1273,7 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         );
 
         let mut request = SignedRequest::new("PUT", "iot1click", &self.region, &request_uri);
+        request.set_operation("InitiateDeviceClaim");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1285,6 +1304,7 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         let request_uri = format!("/devices/{device_id}/methods", device_id = input.device_id);
 
         let mut request = SignedRequest::new("POST", "iot1click", &self.region, &request_uri);
+        request.set_operation("InvokeDeviceMethod");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1317,19 +1337,26 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         let request_uri = format!("/devices/{device_id}/events", device_id = input.device_id);
 
         let mut request = SignedRequest::new("GET", "iot1click", &self.region, &request_uri);
+        request.set_operation("ListDeviceEvents");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
 
         let mut params = Params::new();
-        params.put("fromTimeStamp", &input.from_time_stamp);
+        params.put(
+            "fromTimeStamp",
+            &rusoto_core::serialization::TimestampFormat::Iso8601.format(&input.from_time_stamp),
+        );
         if let Some(ref x) = input.max_results {
             params.put("maxResults", x);
         }
         if let Some(ref x) = input.next_token {
             params.put("nextToken", x);
         }
-        params.put("toTimeStamp", &input.to_time_stamp);
+        params.put(
+            "toTimeStamp",
+            &rusoto_core::serialization::TimestampFormat::Iso8601.format(&input.to_time_stamp),
+        );
         request.set_params(params);
 
         let mut response = self
@@ -1357,6 +1384,7 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         let request_uri = "/devices";
 
         let mut request = SignedRequest::new("GET", "iot1click", &self.region, &request_uri);
+        request.set_operation("ListDevices");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1398,6 +1426,7 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "iot1click", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1428,6 +1457,7 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "iot1click", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1458,6 +1488,7 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         let request_uri = format!("/devices/{device_id}/unclaim", device_id = input.device_id);
 
         let mut request = SignedRequest::new("PUT", "iot1click", &self.region, &request_uri);
+        request.set_operation("UnclaimDevice");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1488,6 +1519,7 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "iot1click", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1523,6 +1555,7 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         let request_uri = format!("/devices/{device_id}/state", device_id = input.device_id);
 
         let mut request = SignedRequest::new("PUT", "iot1click", &self.region, &request_uri);
+        request.set_operation("UpdateDeviceState");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1546,3 +1579,67 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         }
     }
 }
+impl Iot1ClickDevicesClient {
+    /// Auto-paginating version of `list_device_events`, streaming every page of results.
+    pub fn list_device_events_pages(
+        &self,
+        input: ListDeviceEventsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListDeviceEventsResponse, ListDeviceEventsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: Iot1ClickDevicesClient, input: ListDeviceEventsRequest| async move {
+                client.list_device_events(input).await
+            },
+            |input: &ListDeviceEventsRequest, output: &ListDeviceEventsResponse| {
+                Some(ListDeviceEventsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_device_events`, streaming the `Events` of every page of results.
+    pub fn list_device_events_items(
+        &self,
+        input: ListDeviceEventsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DeviceEvent, ListDeviceEventsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_device_events_pages(input),
+            |output: ListDeviceEventsResponse| output.events.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_devices`, streaming every page of results.
+    pub fn list_devices_pages(
+        &self,
+        input: ListDevicesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListDevicesResponse, ListDevicesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: Iot1ClickDevicesClient, input: ListDevicesRequest| async move {
+                client.list_devices(input).await
+            },
+            |input: &ListDevicesRequest, output: &ListDevicesResponse| {
+                Some(ListDevicesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_devices`, streaming the `Devices` of every page of results.
+    pub fn list_devices_items(
+        &self,
+        input: ListDevicesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DeviceDescription, ListDevicesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_devices_pages(input),
+            |output: ListDevicesResponse| output.devices.unwrap_or_default(),
+        )
+    }
+}
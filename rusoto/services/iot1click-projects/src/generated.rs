@@ -280,7 +280,12 @@ pub struct PlacementDescription {
     pub attributes: ::std::collections::HashMap<String, String>,
     /// <p>The date when the placement was initially created, in UNIX epoch time format.</p>
     #[serde(rename = "createdDate")]
-    pub created_date: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub created_date: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The name of the placement.</p>
     #[serde(rename = "placementName")]
     pub placement_name: String,
@@ -289,7 +294,12 @@ pub struct PlacementDescription {
     pub project_name: String,
     /// <p>The date when the placement was last updated, in UNIX epoch time format. If the placement was not updated, then <code>createdDate</code> and <code>updatedDate</code> are the same.</p>
     #[serde(rename = "updatedDate")]
-    pub updated_date: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub updated_date: ::chrono::DateTime<::chrono::Utc>,
 }
 
 /// <p>An object providing summary information for a particular placement.</p>
@@ -298,7 +308,12 @@ pub struct PlacementDescription {
 pub struct PlacementSummary {
     /// <p>The date when the placement was originally created, in UNIX epoch time format.</p>
     #[serde(rename = "createdDate")]
-    pub created_date: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub created_date: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The name of the placement being summarized.</p>
     #[serde(rename = "placementName")]
     pub placement_name: String,
@@ -307,7 +322,12 @@ pub struct PlacementSummary {
     pub project_name: String,
     /// <p>The date when the placement was last updated, in UNIX epoch time format. If the placement was not updated, then <code>createdDate</code> and <code>updatedDate</code> are the same.</p>
     #[serde(rename = "updatedDate")]
-    pub updated_date: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub updated_date: ::chrono::DateTime<::chrono::Utc>,
 }
 
 /// <p>An object defining the template for a placement.</p>
@@ -333,7 +353,12 @@ pub struct ProjectDescription {
     pub arn: Option<String>,
     /// <p>The date when the project was originally created, in UNIX epoch time format.</p>
     #[serde(rename = "createdDate")]
-    pub created_date: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub created_date: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The description of the project.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -351,7 +376,12 @@ pub struct ProjectDescription {
     pub tags: Option<::std::collections::HashMap<String, String>>,
     /// <p>The date when the project was last updated, in UNIX epoch time format. If the project was not updated, then <code>createdDate</code> and <code>updatedDate</code> are the same.</p>
     #[serde(rename = "updatedDate")]
-    pub updated_date: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub updated_date: ::chrono::DateTime<::chrono::Utc>,
 }
 
 /// <p>An object providing summary information for a particular project for an associated AWS account and region.</p>
@@ -364,7 +394,12 @@ pub struct ProjectSummary {
     pub arn: Option<String>,
     /// <p>The date when the project was originally created, in UNIX epoch time format.</p>
     #[serde(rename = "createdDate")]
-    pub created_date: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub created_date: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The name of the project being summarized.</p>
     #[serde(rename = "projectName")]
     pub project_name: String,
@@ -374,7 +409,12 @@ pub struct ProjectSummary {
     pub tags: Option<::std::collections::HashMap<String, String>>,
     /// <p>The date when the project was last updated, in UNIX epoch time format. If the project was not updated, then <code>createdDate</code> and <code>updatedDate</code> are the same.</p>
     #[serde(rename = "updatedDate")]
-    pub updated_date: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub updated_date: ::chrono::DateTime<::chrono::Utc>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -1306,11 +1346,12 @@ pub struct Iot1ClickProjectsClient {
 impl Iot1ClickProjectsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> Iot1ClickProjectsClient {
         Iot1ClickProjectsClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "IoT 1Click Projects"),
         }
     }
 
@@ -1350,6 +1391,7 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
         );
 
         let mut request = SignedRequest::new("PUT", "iot1click", &self.region, &request_uri);
+        request.set_operation("AssociateDeviceWithPlacement");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("projects.iot1click".to_string());
@@ -1384,6 +1426,7 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
         );
 
         let mut request = SignedRequest::new("POST", "iot1click", &self.region, &request_uri);
+        request.set_operation("CreatePlacement");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("projects.iot1click".to_string());
@@ -1415,6 +1458,7 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
         let request_uri = "/projects";
 
         let mut request = SignedRequest::new("POST", "iot1click", &self.region, &request_uri);
+        request.set_operation("CreateProject");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("projects.iot1click".to_string());
@@ -1450,6 +1494,7 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "iot1click", &self.region, &request_uri);
+        request.set_operation("DeletePlacement");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("projects.iot1click".to_string());
@@ -1482,6 +1527,7 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "iot1click", &self.region, &request_uri);
+        request.set_operation("DeleteProject");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("projects.iot1click".to_string());
@@ -1515,6 +1561,7 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
         );
 
         let mut request = SignedRequest::new("GET", "iot1click", &self.region, &request_uri);
+        request.set_operation("DescribePlacement");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("projects.iot1click".to_string());
@@ -1547,6 +1594,7 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
         );
 
         let mut request = SignedRequest::new("GET", "iot1click", &self.region, &request_uri);
+        request.set_operation("DescribeProject");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("projects.iot1click".to_string());
@@ -1584,6 +1632,7 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "iot1click", &self.region, &request_uri);
+        request.set_operation("DisassociateDeviceFromPlacement");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("projects.iot1click".to_string());
@@ -1619,6 +1668,7 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
         );
 
         let mut request = SignedRequest::new("GET", "iot1click", &self.region, &request_uri);
+        request.set_operation("GetDevicesInPlacement");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("projects.iot1click".to_string());
@@ -1651,6 +1701,7 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
         );
 
         let mut request = SignedRequest::new("GET", "iot1click", &self.region, &request_uri);
+        request.set_operation("ListPlacements");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("projects.iot1click".to_string());
@@ -1689,6 +1740,7 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
         let request_uri = "/projects";
 
         let mut request = SignedRequest::new("GET", "iot1click", &self.region, &request_uri);
+        request.set_operation("ListProjects");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("projects.iot1click".to_string());
@@ -1727,6 +1779,7 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "iot1click", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("projects.iot1click".to_string());
@@ -1756,6 +1809,7 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "iot1click", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("projects.iot1click".to_string());
@@ -1787,6 +1841,7 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "iot1click", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("projects.iot1click".to_string());
@@ -1826,6 +1881,7 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
         );
 
         let mut request = SignedRequest::new("PUT", "iot1click", &self.region, &request_uri);
+        request.set_operation("UpdatePlacement");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("projects.iot1click".to_string());
@@ -1860,6 +1916,7 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
         );
 
         let mut request = SignedRequest::new("PUT", "iot1click", &self.region, &request_uri);
+        request.set_operation("UpdateProject");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("projects.iot1click".to_string());
@@ -1883,3 +1940,66 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
         }
     }
 }
+impl Iot1ClickProjectsClient {
+    /// Auto-paginating version of `list_placements`, streaming every page of results.
+    pub fn list_placements_pages(
+        &self,
+        input: ListPlacementsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListPlacementsResponse, ListPlacementsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: Iot1ClickProjectsClient, input: ListPlacementsRequest| async move {
+                client.list_placements(input).await
+            },
+            |input: &ListPlacementsRequest, output: &ListPlacementsResponse| {
+                Some(ListPlacementsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_placements`, streaming the `placements` of every page of results.
+    pub fn list_placements_items(
+        &self,
+        input: ListPlacementsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<PlacementSummary, ListPlacementsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_placements_pages(input),
+            |output: ListPlacementsResponse| output.placements,
+        )
+    }
+
+    /// Auto-paginating version of `list_projects`, streaming every page of results.
+    pub fn list_projects_pages(
+        &self,
+        input: ListProjectsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListProjectsResponse, ListProjectsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: Iot1ClickProjectsClient, input: ListProjectsRequest| async move {
+                client.list_projects(input).await
+            },
+            |input: &ListProjectsRequest, output: &ListProjectsResponse| {
+                Some(ListProjectsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_projects`, streaming the `projects` of every page of results.
+    pub fn list_projects_items(
+        &self,
+        input: ListProjectsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ProjectSummary, ListProjectsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_projects_pages(input),
+            |output: ListProjectsResponse| output.projects,
+        )
+    }
+}
@@ -103,12 +103,22 @@ pub struct Channel {
     pub arn: Option<String>,
     /// <p>When the channel was created.</p>
     #[serde(rename = "creationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>When the channel was last updated.</p>
     #[serde(rename = "lastUpdateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_update_time: Option<f64>,
+    pub last_update_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the channel.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -120,7 +130,7 @@ pub struct Channel {
     /// <p>The status of the channel.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ChannelStatus>,
     /// <p>Where channel data is stored. You may choose one of "serviceManagedS3" or "customerManagedS3" storage. If not specified, the default is "serviceManagedS3". This cannot be changed after creation of the channel.</p>
     #[serde(rename = "storage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -152,6 +162,104 @@ pub struct ChannelStatistics {
     pub size: Option<EstimatedResourceSize>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ChannelStatus {
+    /// `CREATING`
+    Creating,
+    /// `ACTIVE`
+    Active,
+    /// `DELETING`
+    Deleting,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ChannelStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ChannelStatus::Creating => "CREATING",
+            ChannelStatus::Active => "ACTIVE",
+            ChannelStatus::Deleting => "DELETING",
+            ChannelStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ChannelStatus {
+    fn default() -> ChannelStatus {
+        ChannelStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ChannelStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ChannelStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ChannelStatus {
+    fn from(value: &'a str) -> ChannelStatus {
+        match value {
+            "CREATING" => ChannelStatus::Creating,
+            "ACTIVE" => ChannelStatus::Active,
+            "DELETING" => ChannelStatus::Deleting,
+            _ => ChannelStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ChannelStatus {
+    fn from(value: String) -> ChannelStatus {
+        match value.as_str() {
+            "CREATING" => ChannelStatus::Creating,
+            "ACTIVE" => ChannelStatus::Active,
+            "DELETING" => ChannelStatus::Deleting,
+            _ => ChannelStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ChannelStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ChannelStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ChannelStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ChannelStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ChannelStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ChannelStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Where channel data is stored. You may choose one of "serviceManagedS3" or "customerManagedS3" storage. If not specified, the default is "serviceManagedS3". This cannot be changed after creation of the channel.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ChannelStorage {
@@ -193,18 +301,121 @@ pub struct ChannelSummary {
     pub channel_storage: Option<ChannelStorageSummary>,
     /// <p>When the channel was created.</p>
     #[serde(rename = "creationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The last time the channel was updated.</p>
     #[serde(rename = "lastUpdateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_update_time: Option<f64>,
+    pub last_update_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The status of the channel.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ChannelStatus>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ComputeType {
+    /// `ACU_1`
+    Acu1,
+    /// `ACU_2`
+    Acu2,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ComputeType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ComputeType::Acu1 => "ACU_1",
+            ComputeType::Acu2 => "ACU_2",
+            ComputeType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ComputeType {
+    fn default() -> ComputeType {
+        ComputeType::Unknown(String::new())
+    }
 }
 
+impl AsRef<str> for ComputeType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ComputeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ComputeType {
+    fn from(value: &'a str) -> ComputeType {
+        match value {
+            "ACU_1" => ComputeType::Acu1,
+            "ACU_2" => ComputeType::Acu2,
+            _ => ComputeType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ComputeType {
+    fn from(value: String) -> ComputeType {
+        match value.as_str() {
+            "ACU_1" => ComputeType::Acu1,
+            "ACU_2" => ComputeType::Acu2,
+            _ => ComputeType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ComputeType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ComputeType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ComputeType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ComputeType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ComputeType {
+    fn deserialize<D>(deserializer: D) -> Result<ComputeType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information needed to run the "containerAction" to produce data set contents.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ContainerDatasetAction {
@@ -474,12 +685,22 @@ pub struct Dataset {
     pub content_delivery_rules: Option<Vec<DatasetContentDeliveryRule>>,
     /// <p>When the data set was created.</p>
     #[serde(rename = "creationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The last time the data set was updated.</p>
     #[serde(rename = "lastUpdateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_update_time: Option<f64>,
+    pub last_update_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the data set.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -491,7 +712,7 @@ pub struct Dataset {
     /// <p>The status of the data set.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<DatasetStatus>,
     /// <p>The "DatasetTrigger" objects that specify when the data set is automatically updated.</p>
     #[serde(rename = "triggers")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -530,9 +751,102 @@ pub struct DatasetActionSummary {
     /// <p>The type of action by which the data set's contents are automatically created.</p>
     #[serde(rename = "actionType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub action_type: Option<String>,
+    pub action_type: Option<DatasetActionType>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DatasetActionType {
+    /// `QUERY`
+    Query,
+    /// `CONTAINER`
+    Container,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DatasetActionType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DatasetActionType::Query => "QUERY",
+            DatasetActionType::Container => "CONTAINER",
+            DatasetActionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DatasetActionType {
+    fn default() -> DatasetActionType {
+        DatasetActionType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DatasetActionType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DatasetActionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DatasetActionType {
+    fn from(value: &'a str) -> DatasetActionType {
+        match value {
+            "QUERY" => DatasetActionType::Query,
+            "CONTAINER" => DatasetActionType::Container,
+            _ => DatasetActionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DatasetActionType {
+    fn from(value: String) -> DatasetActionType {
+        match value.as_str() {
+            "QUERY" => DatasetActionType::Query,
+            "CONTAINER" => DatasetActionType::Container,
+            _ => DatasetActionType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DatasetActionType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DatasetActionType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DatasetActionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DatasetActionType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DatasetActionType {
+    fn deserialize<D>(deserializer: D) -> Result<DatasetActionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The destination to which data set contents are delivered.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DatasetContentDeliveryDestination {
@@ -558,6 +872,104 @@ pub struct DatasetContentDeliveryRule {
     pub entry_name: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DatasetContentState {
+    /// `CREATING`
+    Creating,
+    /// `SUCCEEDED`
+    Succeeded,
+    /// `FAILED`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DatasetContentState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DatasetContentState::Creating => "CREATING",
+            DatasetContentState::Succeeded => "SUCCEEDED",
+            DatasetContentState::Failed => "FAILED",
+            DatasetContentState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DatasetContentState {
+    fn default() -> DatasetContentState {
+        DatasetContentState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DatasetContentState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DatasetContentState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DatasetContentState {
+    fn from(value: &'a str) -> DatasetContentState {
+        match value {
+            "CREATING" => DatasetContentState::Creating,
+            "SUCCEEDED" => DatasetContentState::Succeeded,
+            "FAILED" => DatasetContentState::Failed,
+            _ => DatasetContentState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DatasetContentState {
+    fn from(value: String) -> DatasetContentState {
+        match value.as_str() {
+            "CREATING" => DatasetContentState::Creating,
+            "SUCCEEDED" => DatasetContentState::Succeeded,
+            "FAILED" => DatasetContentState::Failed,
+            _ => DatasetContentState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DatasetContentState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DatasetContentState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DatasetContentState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DatasetContentState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DatasetContentState {
+    fn deserialize<D>(deserializer: D) -> Result<DatasetContentState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The state of the data set contents and the reason they are in this state.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -569,7 +981,7 @@ pub struct DatasetContentStatus {
     /// <p>The state of the data set contents. Can be one of "READY", "CREATING", "SUCCEEDED" or "FAILED".</p>
     #[serde(rename = "state")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<DatasetContentState>,
 }
 
 /// <p>Summary information about data set contents.</p>
@@ -578,16 +990,31 @@ pub struct DatasetContentStatus {
 pub struct DatasetContentSummary {
     /// <p>The time the dataset content status was updated to SUCCEEDED or FAILED.</p>
     #[serde(rename = "completionTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub completion_time: Option<f64>,
+    pub completion_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The actual time the creation of the data set contents was started.</p>
     #[serde(rename = "creationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The time the creation of the data set contents was scheduled to start.</p>
     #[serde(rename = "scheduleTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub schedule_time: Option<f64>,
+    pub schedule_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The status of the data set contents.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -620,6 +1047,104 @@ pub struct DatasetEntry {
     pub entry_name: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DatasetStatus {
+    /// `CREATING`
+    Creating,
+    /// `ACTIVE`
+    Active,
+    /// `DELETING`
+    Deleting,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DatasetStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DatasetStatus::Creating => "CREATING",
+            DatasetStatus::Active => "ACTIVE",
+            DatasetStatus::Deleting => "DELETING",
+            DatasetStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DatasetStatus {
+    fn default() -> DatasetStatus {
+        DatasetStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DatasetStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DatasetStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DatasetStatus {
+    fn from(value: &'a str) -> DatasetStatus {
+        match value {
+            "CREATING" => DatasetStatus::Creating,
+            "ACTIVE" => DatasetStatus::Active,
+            "DELETING" => DatasetStatus::Deleting,
+            _ => DatasetStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DatasetStatus {
+    fn from(value: String) -> DatasetStatus {
+        match value.as_str() {
+            "CREATING" => DatasetStatus::Creating,
+            "ACTIVE" => DatasetStatus::Active,
+            "DELETING" => DatasetStatus::Deleting,
+            _ => DatasetStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DatasetStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DatasetStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DatasetStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DatasetStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DatasetStatus {
+    fn deserialize<D>(deserializer: D) -> Result<DatasetStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A summary of information about a data set.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -630,20 +1155,30 @@ pub struct DatasetSummary {
     pub actions: Option<Vec<DatasetActionSummary>>,
     /// <p>The time the data set was created.</p>
     #[serde(rename = "creationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the data set.</p>
     #[serde(rename = "datasetName")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub dataset_name: Option<String>,
     /// <p>The last time the data set was updated.</p>
     #[serde(rename = "lastUpdateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_update_time: Option<f64>,
+    pub last_update_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The status of the data set.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<DatasetStatus>,
     /// <p>A list of triggers. A trigger causes data set content to be populated at a specified time interval or when another data set is populated. The list of triggers can be empty or contain up to five DataSetTrigger objects</p>
     #[serde(rename = "triggers")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -673,12 +1208,22 @@ pub struct Datastore {
     pub arn: Option<String>,
     /// <p>When the data store was created.</p>
     #[serde(rename = "creationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The last time the data store was updated.</p>
     #[serde(rename = "lastUpdateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_update_time: Option<f64>,
+    pub last_update_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the data store.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -690,7 +1235,7 @@ pub struct Datastore {
     /// <p><p>The status of a data store:</p> <dl> <dt>CREATING</dt> <dd> <p>The data store is being created.</p> </dd> <dt>ACTIVE</dt> <dd> <p>The data store has been created and can be used.</p> </dd> <dt>DELETING</dt> <dd> <p>The data store is being deleted.</p> </dd> </dl></p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<DatastoreStatus>,
     /// <p>Where data store data is stored. You may choose one of "serviceManagedS3" or "customerManagedS3" storage. If not specified, the default is "serviceManagedS3". This cannot be changed after the data store is created.</p>
     #[serde(rename = "storage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -718,6 +1263,104 @@ pub struct DatastoreStatistics {
     pub size: Option<EstimatedResourceSize>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DatastoreStatus {
+    /// `CREATING`
+    Creating,
+    /// `ACTIVE`
+    Active,
+    /// `DELETING`
+    Deleting,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DatastoreStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DatastoreStatus::Creating => "CREATING",
+            DatastoreStatus::Active => "ACTIVE",
+            DatastoreStatus::Deleting => "DELETING",
+            DatastoreStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DatastoreStatus {
+    fn default() -> DatastoreStatus {
+        DatastoreStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DatastoreStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DatastoreStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DatastoreStatus {
+    fn from(value: &'a str) -> DatastoreStatus {
+        match value {
+            "CREATING" => DatastoreStatus::Creating,
+            "ACTIVE" => DatastoreStatus::Active,
+            "DELETING" => DatastoreStatus::Deleting,
+            _ => DatastoreStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DatastoreStatus {
+    fn from(value: String) -> DatastoreStatus {
+        match value.as_str() {
+            "CREATING" => DatastoreStatus::Creating,
+            "ACTIVE" => DatastoreStatus::Active,
+            "DELETING" => DatastoreStatus::Deleting,
+            _ => DatastoreStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DatastoreStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DatastoreStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DatastoreStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DatastoreStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DatastoreStatus {
+    fn deserialize<D>(deserializer: D) -> Result<DatastoreStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Where data store data is stored. You may choose one of "serviceManagedS3" or "customerManagedS3" storage. If not specified, the default is "serviceManagedS3". This cannot be changed after the data store is created.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DatastoreStorage {
@@ -751,8 +1394,13 @@ pub struct DatastoreStorageSummary {
 pub struct DatastoreSummary {
     /// <p>When the data store was created.</p>
     #[serde(rename = "creationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the data store.</p>
     #[serde(rename = "datastoreName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -763,12 +1411,17 @@ pub struct DatastoreSummary {
     pub datastore_storage: Option<DatastoreStorageSummary>,
     /// <p>The last time the data store was updated.</p>
     #[serde(rename = "lastUpdateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_update_time: Option<f64>,
+    pub last_update_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The status of the data store.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<DatastoreStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -971,8 +1624,13 @@ pub struct DeviceShadowEnrichActivity {
 pub struct EstimatedResourceSize {
     /// <p>The time when the estimate of the size of the resource was made.</p>
     #[serde(rename = "estimatedOn")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub estimated_on: Option<f64>,
+    pub estimated_on: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The estimated size of the resource in bytes.</p>
     #[serde(rename = "estimatedSizeInBytes")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1019,8 +1677,13 @@ pub struct GetDatasetContentResponse {
     pub status: Option<DatasetContentStatus>,
     /// <p>The time when the request was made.</p>
     #[serde(rename = "timestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub timestamp: Option<f64>,
+    pub timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>Configuration information for coordination with the AWS Glue ETL (extract, transform and load) service.</p>
@@ -1105,12 +1768,22 @@ pub struct ListDatasetContentsRequest {
     pub next_token: Option<String>,
     /// <p>A filter to limit results to those data set contents whose creation is scheduled before the given time. See the field <code>triggers.schedule</code> in the CreateDataset request. (timestamp)</p>
     #[serde(rename = "scheduledBefore")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scheduled_before: Option<f64>,
+    pub scheduled_before: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A filter to limit results to those data set contents whose creation is scheduled on or after the given time. See the field <code>triggers.schedule</code> in the CreateDataset request. (timestamp)</p>
     #[serde(rename = "scheduledOnOrAfter")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scheduled_on_or_after: Option<f64>,
+    pub scheduled_on_or_after: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1221,6 +1894,94 @@ pub struct ListTagsForResourceResponse {
     pub tags: Option<Vec<Tag>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LoggingLevel {
+    /// `ERROR`
+    Error,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LoggingLevel {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LoggingLevel::Error => "ERROR",
+            LoggingLevel::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LoggingLevel {
+    fn default() -> LoggingLevel {
+        LoggingLevel::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LoggingLevel {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LoggingLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LoggingLevel {
+    fn from(value: &'a str) -> LoggingLevel {
+        match value {
+            "ERROR" => LoggingLevel::Error,
+            _ => LoggingLevel::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LoggingLevel {
+    fn from(value: String) -> LoggingLevel {
+        match value.as_str() {
+            "ERROR" => LoggingLevel::Error,
+            _ => LoggingLevel::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LoggingLevel {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LoggingLevel, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LoggingLevel {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LoggingLevel {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LoggingLevel {
+    fn deserialize<D>(deserializer: D) -> Result<LoggingLevel, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about logging options.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct LoggingOptions {
@@ -1229,7 +1990,7 @@ pub struct LoggingOptions {
     pub enabled: bool,
     /// <p>The logging level. Currently, only "ERROR" is supported.</p>
     #[serde(rename = "level")]
-    pub level: String,
+    pub level: LoggingLevel,
     /// <p>The ARN of the role that grants permission to AWS IoT Analytics to perform logging.</p>
     #[serde(rename = "roleArn")]
     pub role_arn: String,
@@ -1292,12 +2053,22 @@ pub struct Pipeline {
     pub arn: Option<String>,
     /// <p>When the pipeline was created.</p>
     #[serde(rename = "creationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The last time the pipeline was updated.</p>
     #[serde(rename = "lastUpdateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_update_time: Option<f64>,
+    pub last_update_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the pipeline.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1359,12 +2130,22 @@ pub struct PipelineActivity {
 pub struct PipelineSummary {
     /// <p>When the pipeline was created.</p>
     #[serde(rename = "creationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>When the pipeline was last updated.</p>
     #[serde(rename = "lastUpdateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_update_time: Option<f64>,
+    pub last_update_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the pipeline.</p>
     #[serde(rename = "pipelineName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1407,14 +2188,122 @@ pub struct RemoveAttributesActivity {
     pub next: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ReprocessingStatus {
+    /// `RUNNING`
+    Running,
+    /// `SUCCEEDED`
+    Succeeded,
+    /// `CANCELLED`
+    Cancelled,
+    /// `FAILED`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ReprocessingStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReprocessingStatus::Running => "RUNNING",
+            ReprocessingStatus::Succeeded => "SUCCEEDED",
+            ReprocessingStatus::Cancelled => "CANCELLED",
+            ReprocessingStatus::Failed => "FAILED",
+            ReprocessingStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ReprocessingStatus {
+    fn default() -> ReprocessingStatus {
+        ReprocessingStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ReprocessingStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ReprocessingStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ReprocessingStatus {
+    fn from(value: &'a str) -> ReprocessingStatus {
+        match value {
+            "RUNNING" => ReprocessingStatus::Running,
+            "SUCCEEDED" => ReprocessingStatus::Succeeded,
+            "CANCELLED" => ReprocessingStatus::Cancelled,
+            "FAILED" => ReprocessingStatus::Failed,
+            _ => ReprocessingStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReprocessingStatus {
+    fn from(value: String) -> ReprocessingStatus {
+        match value.as_str() {
+            "RUNNING" => ReprocessingStatus::Running,
+            "SUCCEEDED" => ReprocessingStatus::Succeeded,
+            "CANCELLED" => ReprocessingStatus::Cancelled,
+            "FAILED" => ReprocessingStatus::Failed,
+            _ => ReprocessingStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ReprocessingStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ReprocessingStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ReprocessingStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ReprocessingStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ReprocessingStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ReprocessingStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about pipeline reprocessing.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ReprocessingSummary {
     /// <p>The time the pipeline reprocessing was created.</p>
     #[serde(rename = "creationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The 'reprocessingId' returned by "StartPipelineReprocessing".</p>
     #[serde(rename = "id")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1422,7 +2311,7 @@ pub struct ReprocessingSummary {
     /// <p>The status of the pipeline reprocessing.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ReprocessingStatus>,
 }
 
 /// <p>The configuration of the resource used to execute the "containerAction".</p>
@@ -1430,7 +2319,7 @@ pub struct ReprocessingSummary {
 pub struct ResourceConfiguration {
     /// <p>The type of the compute resource used to execute the "containerAction". Possible values are: ACU_1 (vCPU=4, memory=16GiB) or ACU_2 (vCPU=8, memory=32GiB).</p>
     #[serde(rename = "computeType")]
-    pub compute_type: String,
+    pub compute_type: ComputeType,
     /// <p>The size (in GB) of the persistent storage available to the resource instance used to execute the "containerAction" (min: 1, max: 50).</p>
     #[serde(rename = "volumeSizeInGB")]
     pub volume_size_in_gb: i64,
@@ -1509,16 +2398,26 @@ pub struct SampleChannelDataRequest {
     pub channel_name: String,
     /// <p>The end of the time window from which sample messages are retrieved.</p>
     #[serde(rename = "endTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The number of sample messages to be retrieved. The limit is 10, the default is also 10.</p>
     #[serde(rename = "maxMessages")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub max_messages: Option<i64>,
     /// <p>The start of the time window from which sample messages are retrieved.</p>
     #[serde(rename = "startTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1594,15 +2493,25 @@ pub struct SqlQueryDatasetAction {
 pub struct StartPipelineReprocessingRequest {
     /// <p>The end time (exclusive) of raw message data that is reprocessed.</p>
     #[serde(rename = "endTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the pipeline on which to start reprocessing.</p>
     #[serde(rename = "pipelineName")]
     pub pipeline_name: String,
     /// <p>The start time (inclusive) of raw message data that is reprocessed.</p>
     #[serde(rename = "startTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -3910,11 +4819,12 @@ pub struct IotAnalyticsClient {
 impl IotAnalyticsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> IotAnalyticsClient {
         IotAnalyticsClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "IoTAnalytics"),
         }
     }
 
@@ -3948,6 +4858,7 @@ impl IotAnalytics for IotAnalyticsClient {
         let request_uri = "/messages/batch";
 
         let mut request = SignedRequest::new("POST", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("BatchPutMessage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3983,6 +4894,7 @@ impl IotAnalytics for IotAnalyticsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("CancelPipelineReprocessing");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4010,6 +4922,7 @@ impl IotAnalytics for IotAnalyticsClient {
         let request_uri = "/channels";
 
         let mut request = SignedRequest::new("POST", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("CreateChannel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4040,6 +4953,7 @@ impl IotAnalytics for IotAnalyticsClient {
         let request_uri = "/datasets";
 
         let mut request = SignedRequest::new("POST", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("CreateDataset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4073,6 +4987,7 @@ impl IotAnalytics for IotAnalyticsClient {
         );
 
         let mut request = SignedRequest::new("POST", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("CreateDatasetContent");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4100,6 +5015,7 @@ impl IotAnalytics for IotAnalyticsClient {
         let request_uri = "/datastores";
 
         let mut request = SignedRequest::new("POST", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("CreateDatastore");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4130,6 +5046,7 @@ impl IotAnalytics for IotAnalyticsClient {
         let request_uri = "/pipelines";
 
         let mut request = SignedRequest::new("POST", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("CreatePipeline");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4163,6 +5080,7 @@ impl IotAnalytics for IotAnalyticsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("DeleteChannel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4192,6 +5110,7 @@ impl IotAnalytics for IotAnalyticsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("DeleteDataset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4221,6 +5140,7 @@ impl IotAnalytics for IotAnalyticsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("DeleteDatasetContent");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4256,6 +5176,7 @@ impl IotAnalytics for IotAnalyticsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("DeleteDatastore");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4285,6 +5206,7 @@ impl IotAnalytics for IotAnalyticsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("DeletePipeline");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4314,6 +5236,7 @@ impl IotAnalytics for IotAnalyticsClient {
         );
 
         let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("DescribeChannel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4350,6 +5273,7 @@ impl IotAnalytics for IotAnalyticsClient {
         );
 
         let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("DescribeDataset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4380,6 +5304,7 @@ impl IotAnalytics for IotAnalyticsClient {
         );
 
         let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("DescribeDatastore");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4412,6 +5337,7 @@ impl IotAnalytics for IotAnalyticsClient {
         let request_uri = "/logging";
 
         let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("DescribeLoggingOptions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4442,6 +5368,7 @@ impl IotAnalytics for IotAnalyticsClient {
         );
 
         let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("DescribePipeline");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4472,6 +5399,7 @@ impl IotAnalytics for IotAnalyticsClient {
         );
 
         let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("GetDatasetContent");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4505,6 +5433,7 @@ impl IotAnalytics for IotAnalyticsClient {
         let request_uri = "/channels";
 
         let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("ListChannels");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4544,6 +5473,7 @@ impl IotAnalytics for IotAnalyticsClient {
         );
 
         let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("ListDatasetContents");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4554,10 +5484,16 @@ impl IotAnalytics for IotAnalyticsClient {
             params.put("nextToken", x);
         }
         if let Some(ref x) = input.scheduled_before {
-            params.put("scheduledBefore", x);
+            params.put(
+                "scheduledBefore",
+                &rusoto_core::serialization::TimestampFormat::Iso8601.format(x),
+            );
         }
         if let Some(ref x) = input.scheduled_on_or_after {
-            params.put("scheduledOnOrAfter", x);
+            params.put(
+                "scheduledOnOrAfter",
+                &rusoto_core::serialization::TimestampFormat::Iso8601.format(x),
+            );
         }
         request.set_params(params);
 
@@ -4586,6 +5522,7 @@ impl IotAnalytics for IotAnalyticsClient {
         let request_uri = "/datasets";
 
         let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("ListDatasets");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4622,6 +5559,7 @@ impl IotAnalytics for IotAnalyticsClient {
         let request_uri = "/datastores";
 
         let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("ListDatastores");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4658,6 +5596,7 @@ impl IotAnalytics for IotAnalyticsClient {
         let request_uri = "/pipelines";
 
         let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("ListPipelines");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4694,6 +5633,7 @@ impl IotAnalytics for IotAnalyticsClient {
         let request_uri = "/tags";
 
         let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4725,6 +5665,7 @@ impl IotAnalytics for IotAnalyticsClient {
         let request_uri = "/logging";
 
         let mut request = SignedRequest::new("PUT", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("PutLoggingOptions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4754,6 +5695,7 @@ impl IotAnalytics for IotAnalyticsClient {
         let request_uri = "/pipelineactivities/run";
 
         let mut request = SignedRequest::new("POST", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("RunPipelineActivity");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4787,17 +5729,24 @@ impl IotAnalytics for IotAnalyticsClient {
         );
 
         let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("SampleChannelData");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
         if let Some(ref x) = input.end_time {
-            params.put("endTime", x);
+            params.put(
+                "endTime",
+                &rusoto_core::serialization::TimestampFormat::Iso8601.format(x),
+            );
         }
         if let Some(ref x) = input.max_messages {
             params.put("maxMessages", x);
         }
         if let Some(ref x) = input.start_time {
-            params.put("startTime", x);
+            params.put(
+                "startTime",
+                &rusoto_core::serialization::TimestampFormat::Iso8601.format(x),
+            );
         }
         request.set_params(params);
 
@@ -4830,6 +5779,7 @@ impl IotAnalytics for IotAnalyticsClient {
         );
 
         let mut request = SignedRequest::new("POST", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("StartPipelineReprocessing");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4860,6 +5810,7 @@ impl IotAnalytics for IotAnalyticsClient {
         let request_uri = "/tags";
 
         let mut request = SignedRequest::new("POST", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4894,6 +5845,7 @@ impl IotAnalytics for IotAnalyticsClient {
         let request_uri = "/tags";
 
         let mut request = SignedRequest::new("DELETE", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4931,6 +5883,7 @@ impl IotAnalytics for IotAnalyticsClient {
         );
 
         let mut request = SignedRequest::new("PUT", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("UpdateChannel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4963,6 +5916,7 @@ impl IotAnalytics for IotAnalyticsClient {
         );
 
         let mut request = SignedRequest::new("PUT", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("UpdateDataset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4995,6 +5949,7 @@ impl IotAnalytics for IotAnalyticsClient {
         );
 
         let mut request = SignedRequest::new("PUT", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("UpdateDatastore");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5027,6 +5982,7 @@ impl IotAnalytics for IotAnalyticsClient {
         );
 
         let mut request = SignedRequest::new("PUT", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("UpdatePipeline");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5048,3 +6004,163 @@ impl IotAnalytics for IotAnalyticsClient {
         }
     }
 }
+impl IotAnalyticsClient {
+    /// Auto-paginating version of `list_channels`, streaming every page of results.
+    pub fn list_channels_pages(
+        &self,
+        input: ListChannelsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListChannelsResponse, ListChannelsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotAnalyticsClient, input: ListChannelsRequest| async move {
+                client.list_channels(input).await
+            },
+            |input: &ListChannelsRequest, output: &ListChannelsResponse| {
+                Some(ListChannelsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_channels`, streaming the `channelSummaries` of every page of results.
+    pub fn list_channels_items(
+        &self,
+        input: ListChannelsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ChannelSummary, ListChannelsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_channels_pages(input),
+            |output: ListChannelsResponse| output.channel_summaries.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_dataset_contents`, streaming every page of results.
+    pub fn list_dataset_contents_pages(
+        &self,
+        input: ListDatasetContentsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListDatasetContentsResponse, ListDatasetContentsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotAnalyticsClient, input: ListDatasetContentsRequest| async move {
+                client.list_dataset_contents(input).await
+            },
+            |input: &ListDatasetContentsRequest, output: &ListDatasetContentsResponse| {
+                Some(ListDatasetContentsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_dataset_contents`, streaming the `datasetContentSummaries` of every page of results.
+    pub fn list_dataset_contents_items(
+        &self,
+        input: ListDatasetContentsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DatasetContentSummary, ListDatasetContentsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_dataset_contents_pages(input),
+            |output: ListDatasetContentsResponse| {
+                output.dataset_content_summaries.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_datasets`, streaming every page of results.
+    pub fn list_datasets_pages(
+        &self,
+        input: ListDatasetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListDatasetsResponse, ListDatasetsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotAnalyticsClient, input: ListDatasetsRequest| async move {
+                client.list_datasets(input).await
+            },
+            |input: &ListDatasetsRequest, output: &ListDatasetsResponse| {
+                Some(ListDatasetsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_datasets`, streaming the `datasetSummaries` of every page of results.
+    pub fn list_datasets_items(
+        &self,
+        input: ListDatasetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DatasetSummary, ListDatasetsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_datasets_pages(input),
+            |output: ListDatasetsResponse| output.dataset_summaries.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_datastores`, streaming every page of results.
+    pub fn list_datastores_pages(
+        &self,
+        input: ListDatastoresRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListDatastoresResponse, ListDatastoresError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotAnalyticsClient, input: ListDatastoresRequest| async move {
+                client.list_datastores(input).await
+            },
+            |input: &ListDatastoresRequest, output: &ListDatastoresResponse| {
+                Some(ListDatastoresRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_datastores`, streaming the `datastoreSummaries` of every page of results.
+    pub fn list_datastores_items(
+        &self,
+        input: ListDatastoresRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DatastoreSummary, ListDatastoresError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_datastores_pages(input),
+            |output: ListDatastoresResponse| output.datastore_summaries.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_pipelines`, streaming every page of results.
+    pub fn list_pipelines_pages(
+        &self,
+        input: ListPipelinesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListPipelinesResponse, ListPipelinesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotAnalyticsClient, input: ListPipelinesRequest| async move {
+                client.list_pipelines(input).await
+            },
+            |input: &ListPipelinesRequest, output: &ListPipelinesResponse| {
+                Some(ListPipelinesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_pipelines`, streaming the `pipelineSummaries` of every page of results.
+    pub fn list_pipelines_items(
+        &self,
+        input: ListPipelinesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<PipelineSummary, ListPipelinesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_pipelines_pages(input),
+            |output: ListPipelinesResponse| output.pipeline_summaries.unwrap_or_default(),
+        )
+    }
+}
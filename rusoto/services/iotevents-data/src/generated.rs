@@ -32,7 +32,7 @@ pub struct BatchPutMessageErrorEntry {
     /// <p>The code associated with the error.</p>
     #[serde(rename = "errorCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub error_code: Option<String>,
+    pub error_code: Option<ErrorCode>,
     /// <p>More information about the error.</p>
     #[serde(rename = "errorMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -67,7 +67,7 @@ pub struct BatchUpdateDetectorErrorEntry {
     /// <p>The code of the error.</p>
     #[serde(rename = "errorCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub error_code: Option<String>,
+    pub error_code: Option<ErrorCode>,
     /// <p>A message describing the error.</p>
     #[serde(rename = "errorMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -122,8 +122,13 @@ pub struct DescribeDetectorResponse {
 pub struct Detector {
     /// <p>The time the detector (instance) was created.</p>
     #[serde(rename = "creationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the detector model that created this detector (instance).</p>
     #[serde(rename = "detectorModelName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -138,8 +143,13 @@ pub struct Detector {
     pub key_value: Option<String>,
     /// <p>The time the detector (instance) was last updated.</p>
     #[serde(rename = "lastUpdateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_update_time: Option<f64>,
+    pub last_update_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The current state of the detector (instance).</p>
     #[serde(rename = "state")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -192,8 +202,13 @@ pub struct DetectorStateSummary {
 pub struct DetectorSummary {
     /// <p>The time the detector (instance) was created.</p>
     #[serde(rename = "creationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the detector model that created this detector (instance).</p>
     #[serde(rename = "detectorModelName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -208,14 +223,127 @@ pub struct DetectorSummary {
     pub key_value: Option<String>,
     /// <p>The time the detector (instance) was last updated.</p>
     #[serde(rename = "lastUpdateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_update_time: Option<f64>,
+    pub last_update_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The current state of the detector (instance).</p>
     #[serde(rename = "state")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub state: Option<DetectorStateSummary>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorCode {
+    /// `ResourceNotFoundException`
+    ResourceNotFoundException,
+    /// `InvalidRequestException`
+    InvalidRequestException,
+    /// `InternalFailureException`
+    InternalFailureException,
+    /// `ServiceUnavailableException`
+    ServiceUnavailableException,
+    /// `ThrottlingException`
+    ThrottlingException,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ErrorCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ErrorCode::ResourceNotFoundException => "ResourceNotFoundException",
+            ErrorCode::InvalidRequestException => "InvalidRequestException",
+            ErrorCode::InternalFailureException => "InternalFailureException",
+            ErrorCode::ServiceUnavailableException => "ServiceUnavailableException",
+            ErrorCode::ThrottlingException => "ThrottlingException",
+            ErrorCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ErrorCode {
+    fn default() -> ErrorCode {
+        ErrorCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ErrorCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ErrorCode {
+    fn from(value: &'a str) -> ErrorCode {
+        match value {
+            "ResourceNotFoundException" => ErrorCode::ResourceNotFoundException,
+            "InvalidRequestException" => ErrorCode::InvalidRequestException,
+            "InternalFailureException" => ErrorCode::InternalFailureException,
+            "ServiceUnavailableException" => ErrorCode::ServiceUnavailableException,
+            "ThrottlingException" => ErrorCode::ThrottlingException,
+            _ => ErrorCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ErrorCode {
+    fn from(value: String) -> ErrorCode {
+        match value.as_str() {
+            "ResourceNotFoundException" => ErrorCode::ResourceNotFoundException,
+            "InvalidRequestException" => ErrorCode::InvalidRequestException,
+            "InternalFailureException" => ErrorCode::InternalFailureException,
+            "ServiceUnavailableException" => ErrorCode::ServiceUnavailableException,
+            "ThrottlingException" => ErrorCode::ThrottlingException,
+            _ => ErrorCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ErrorCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ErrorCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ErrorCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ErrorCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ErrorCode {
+    fn deserialize<D>(deserializer: D) -> Result<ErrorCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ListDetectorsRequest {
@@ -278,7 +406,12 @@ pub struct Timer {
     pub name: String,
     /// <p>The number of seconds which have elapsed on the timer.</p>
     #[serde(rename = "timestamp")]
-    pub timestamp: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub timestamp: ::chrono::DateTime<::chrono::Utc>,
 }
 
 /// <p>The new setting of a timer.</p>
@@ -579,11 +712,12 @@ pub struct IotEventsDataClient {
 impl IotEventsDataClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> IotEventsDataClient {
         IotEventsDataClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "IoT Events Data"),
         }
     }
 
@@ -617,6 +751,7 @@ impl IotEventsData for IotEventsDataClient {
         let request_uri = "/inputs/messages";
 
         let mut request = SignedRequest::new("POST", "ioteventsdata", &self.region, &request_uri);
+        request.set_operation("BatchPutMessage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("data.iotevents".to_string());
@@ -648,6 +783,7 @@ impl IotEventsData for IotEventsDataClient {
         let request_uri = "/detectors";
 
         let mut request = SignedRequest::new("POST", "ioteventsdata", &self.region, &request_uri);
+        request.set_operation("BatchUpdateDetector");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("data.iotevents".to_string());
@@ -682,6 +818,7 @@ impl IotEventsData for IotEventsDataClient {
         );
 
         let mut request = SignedRequest::new("GET", "ioteventsdata", &self.region, &request_uri);
+        request.set_operation("DescribeDetector");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("data.iotevents".to_string());
@@ -720,6 +857,7 @@ impl IotEventsData for IotEventsDataClient {
         );
 
         let mut request = SignedRequest::new("GET", "ioteventsdata", &self.region, &request_uri);
+        request.set_operation("ListDetectors");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("data.iotevents".to_string());
@@ -102,7 +102,7 @@ pub struct CreateDetectorModelRequest {
     /// <p>Information about the order in which events are evaluated and how actions are executed. </p>
     #[serde(rename = "evaluationMethod")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub evaluation_method: Option<String>,
+    pub evaluation_method: Option<EvaluationMethod>,
     /// <p>The input attribute key used to identify a device or system in order to create a detector (an instance of the detector model) and then to route each input received to the appropriate detector (instance). This parameter uses a JSON-path expression to specify the attribute-value pair in the message payload of each input that is used to identify the device associated with the input.</p>
     #[serde(rename = "key")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -260,8 +260,13 @@ pub struct DetectorModel {
 pub struct DetectorModelConfiguration {
     /// <p>The time the detector model was created.</p>
     #[serde(rename = "creationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The ARN of the detector model.</p>
     #[serde(rename = "detectorModelArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -281,15 +286,20 @@ pub struct DetectorModelConfiguration {
     /// <p>Information about the order in which events are evaluated and how actions are executed. </p>
     #[serde(rename = "evaluationMethod")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub evaluation_method: Option<String>,
+    pub evaluation_method: Option<EvaluationMethod>,
     /// <p>The input attribute key used to identify a device or system in order to create a detector (an instance of the detector model) and then to route each input received to the appropriate detector (instance). This parameter uses a JSON-path expression to specify the attribute-value pair in the message payload of each input that is used to identify the device associated with the input.</p>
     #[serde(rename = "key")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub key: Option<String>,
     /// <p>The time the detector model was last updated.</p>
     #[serde(rename = "lastUpdateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_update_time: Option<f64>,
+    pub last_update_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The ARN of the role that grants permission to AWS IoT Events to perform its operations.</p>
     #[serde(rename = "roleArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -297,7 +307,7 @@ pub struct DetectorModelConfiguration {
     /// <p>The status of the detector model.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<DetectorModelVersionStatus>,
 }
 
 /// <p>Information that defines how a detector operates.</p>
@@ -317,8 +327,13 @@ pub struct DetectorModelDefinition {
 pub struct DetectorModelSummary {
     /// <p>The time the detector model was created.</p>
     #[serde(rename = "creationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A brief description of the detector model.</p>
     #[serde(rename = "detectorModelDescription")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -329,14 +344,137 @@ pub struct DetectorModelSummary {
     pub detector_model_name: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DetectorModelVersionStatus {
+    /// `ACTIVE`
+    Active,
+    /// `ACTIVATING`
+    Activating,
+    /// `INACTIVE`
+    Inactive,
+    /// `DEPRECATED`
+    Deprecated,
+    /// `DRAFT`
+    Draft,
+    /// `PAUSED`
+    Paused,
+    /// `FAILED`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DetectorModelVersionStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DetectorModelVersionStatus::Active => "ACTIVE",
+            DetectorModelVersionStatus::Activating => "ACTIVATING",
+            DetectorModelVersionStatus::Inactive => "INACTIVE",
+            DetectorModelVersionStatus::Deprecated => "DEPRECATED",
+            DetectorModelVersionStatus::Draft => "DRAFT",
+            DetectorModelVersionStatus::Paused => "PAUSED",
+            DetectorModelVersionStatus::Failed => "FAILED",
+            DetectorModelVersionStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DetectorModelVersionStatus {
+    fn default() -> DetectorModelVersionStatus {
+        DetectorModelVersionStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DetectorModelVersionStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DetectorModelVersionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DetectorModelVersionStatus {
+    fn from(value: &'a str) -> DetectorModelVersionStatus {
+        match value {
+            "ACTIVE" => DetectorModelVersionStatus::Active,
+            "ACTIVATING" => DetectorModelVersionStatus::Activating,
+            "INACTIVE" => DetectorModelVersionStatus::Inactive,
+            "DEPRECATED" => DetectorModelVersionStatus::Deprecated,
+            "DRAFT" => DetectorModelVersionStatus::Draft,
+            "PAUSED" => DetectorModelVersionStatus::Paused,
+            "FAILED" => DetectorModelVersionStatus::Failed,
+            _ => DetectorModelVersionStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DetectorModelVersionStatus {
+    fn from(value: String) -> DetectorModelVersionStatus {
+        match value.as_str() {
+            "ACTIVE" => DetectorModelVersionStatus::Active,
+            "ACTIVATING" => DetectorModelVersionStatus::Activating,
+            "INACTIVE" => DetectorModelVersionStatus::Inactive,
+            "DEPRECATED" => DetectorModelVersionStatus::Deprecated,
+            "DRAFT" => DetectorModelVersionStatus::Draft,
+            "PAUSED" => DetectorModelVersionStatus::Paused,
+            "FAILED" => DetectorModelVersionStatus::Failed,
+            _ => DetectorModelVersionStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DetectorModelVersionStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DetectorModelVersionStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DetectorModelVersionStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DetectorModelVersionStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DetectorModelVersionStatus {
+    fn deserialize<D>(deserializer: D) -> Result<DetectorModelVersionStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about the detector model version.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DetectorModelVersionSummary {
     /// <p>The time the detector model version was created.</p>
     #[serde(rename = "creationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The ARN of the detector model version.</p>
     #[serde(rename = "detectorModelArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -352,11 +490,16 @@ pub struct DetectorModelVersionSummary {
     /// <p>Information about the order in which events are evaluated and how actions are executed. </p>
     #[serde(rename = "evaluationMethod")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub evaluation_method: Option<String>,
+    pub evaluation_method: Option<EvaluationMethod>,
     /// <p>The last time the detector model version was updated.</p>
     #[serde(rename = "lastUpdateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_update_time: Option<f64>,
+    pub last_update_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The ARN of the role that grants the detector model permission to perform its tasks.</p>
     #[serde(rename = "roleArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -364,9 +507,102 @@ pub struct DetectorModelVersionSummary {
     /// <p>The status of the detector model version.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<DetectorModelVersionStatus>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EvaluationMethod {
+    /// `BATCH`
+    Batch,
+    /// `SERIAL`
+    Serial,
+    /// A value not known to this version of the crate
+    Unknown(String),
 }
 
+impl EvaluationMethod {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EvaluationMethod::Batch => "BATCH",
+            EvaluationMethod::Serial => "SERIAL",
+            EvaluationMethod::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EvaluationMethod {
+    fn default() -> EvaluationMethod {
+        EvaluationMethod::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EvaluationMethod {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EvaluationMethod {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EvaluationMethod {
+    fn from(value: &'a str) -> EvaluationMethod {
+        match value {
+            "BATCH" => EvaluationMethod::Batch,
+            "SERIAL" => EvaluationMethod::Serial,
+            _ => EvaluationMethod::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EvaluationMethod {
+    fn from(value: String) -> EvaluationMethod {
+        match value.as_str() {
+            "BATCH" => EvaluationMethod::Batch,
+            "SERIAL" => EvaluationMethod::Serial,
+            _ => EvaluationMethod::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EvaluationMethod {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EvaluationMethod, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EvaluationMethod {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EvaluationMethod {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EvaluationMethod {
+    fn deserialize<D>(deserializer: D) -> Result<EvaluationMethod, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Specifies the <code>"actions"</code> to be performed when the <code>"condition"</code> evaluates to TRUE.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Event {
@@ -415,7 +651,12 @@ pub struct Input {
 pub struct InputConfiguration {
     /// <p>The time the input was created.</p>
     #[serde(rename = "creationTime")]
-    pub creation_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub creation_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The ARN of the input.</p>
     #[serde(rename = "inputArn")]
     pub input_arn: String,
@@ -428,10 +669,15 @@ pub struct InputConfiguration {
     pub input_name: String,
     /// <p>The last time the input was updated.</p>
     #[serde(rename = "lastUpdateTime")]
-    pub last_update_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub last_update_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The status of the input.</p>
     #[serde(rename = "status")]
-    pub status: String,
+    pub status: InputStatus,
 }
 
 /// <p>The definition of the input.</p>
@@ -442,14 +688,122 @@ pub struct InputDefinition {
     pub attributes: Vec<Attribute>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum InputStatus {
+    /// `CREATING`
+    Creating,
+    /// `UPDATING`
+    Updating,
+    /// `ACTIVE`
+    Active,
+    /// `DELETING`
+    Deleting,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl InputStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InputStatus::Creating => "CREATING",
+            InputStatus::Updating => "UPDATING",
+            InputStatus::Active => "ACTIVE",
+            InputStatus::Deleting => "DELETING",
+            InputStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for InputStatus {
+    fn default() -> InputStatus {
+        InputStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for InputStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for InputStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for InputStatus {
+    fn from(value: &'a str) -> InputStatus {
+        match value {
+            "CREATING" => InputStatus::Creating,
+            "UPDATING" => InputStatus::Updating,
+            "ACTIVE" => InputStatus::Active,
+            "DELETING" => InputStatus::Deleting,
+            _ => InputStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InputStatus {
+    fn from(value: String) -> InputStatus {
+        match value.as_str() {
+            "CREATING" => InputStatus::Creating,
+            "UPDATING" => InputStatus::Updating,
+            "ACTIVE" => InputStatus::Active,
+            "DELETING" => InputStatus::Deleting,
+            _ => InputStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for InputStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<InputStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for InputStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for InputStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InputStatus {
+    fn deserialize<D>(deserializer: D) -> Result<InputStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about the input.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InputSummary {
     /// <p>The time the input was created.</p>
     #[serde(rename = "creationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The ARN of the input.</p>
     #[serde(rename = "inputArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -464,12 +818,17 @@ pub struct InputSummary {
     pub input_name: Option<String>,
     /// <p>The last time the input was updated.</p>
     #[serde(rename = "lastUpdateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_update_time: Option<f64>,
+    pub last_update_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The status of the input.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<InputStatus>,
 }
 
 /// <p>Sends an AWS IoT Events input, passing in information about the detector model instance and the event that triggered the action.</p>
@@ -594,6 +953,104 @@ pub struct ListTagsForResourceResponse {
     pub tags: Option<Vec<Tag>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LoggingLevel {
+    /// `ERROR`
+    Error,
+    /// `INFO`
+    Info,
+    /// `DEBUG`
+    Debug,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LoggingLevel {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LoggingLevel::Error => "ERROR",
+            LoggingLevel::Info => "INFO",
+            LoggingLevel::Debug => "DEBUG",
+            LoggingLevel::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LoggingLevel {
+    fn default() -> LoggingLevel {
+        LoggingLevel::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LoggingLevel {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LoggingLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LoggingLevel {
+    fn from(value: &'a str) -> LoggingLevel {
+        match value {
+            "ERROR" => LoggingLevel::Error,
+            "INFO" => LoggingLevel::Info,
+            "DEBUG" => LoggingLevel::Debug,
+            _ => LoggingLevel::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LoggingLevel {
+    fn from(value: String) -> LoggingLevel {
+        match value.as_str() {
+            "ERROR" => LoggingLevel::Error,
+            "INFO" => LoggingLevel::Info,
+            "DEBUG" => LoggingLevel::Debug,
+            _ => LoggingLevel::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LoggingLevel {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LoggingLevel, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LoggingLevel {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LoggingLevel {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LoggingLevel {
+    fn deserialize<D>(deserializer: D) -> Result<LoggingLevel, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The values of the AWS IoT Events logging options.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct LoggingOptions {
@@ -606,7 +1063,7 @@ pub struct LoggingOptions {
     pub enabled: bool,
     /// <p>The logging level.</p>
     #[serde(rename = "level")]
-    pub level: String,
+    pub level: LoggingLevel,
     /// <p>The ARN of the role that grants permission to AWS IoT Events to perform logging.</p>
     #[serde(rename = "roleArn")]
     pub role_arn: String,
@@ -796,7 +1253,7 @@ pub struct UpdateDetectorModelRequest {
     /// <p>Information about the order in which events are evaluated and how actions are executed. </p>
     #[serde(rename = "evaluationMethod")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub evaluation_method: Option<String>,
+    pub evaluation_method: Option<EvaluationMethod>,
     /// <p>The ARN of the role that grants permission to AWS IoT Events to perform its operations.</p>
     #[serde(rename = "roleArn")]
     pub role_arn: String,
@@ -1897,11 +2354,12 @@ pub struct IotEventsClient {
 impl IotEventsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> IotEventsClient {
         IotEventsClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "IoT Events"),
         }
     }
 
@@ -1935,6 +2393,7 @@ impl IotEvents for IotEventsClient {
         let request_uri = "/detector-models";
 
         let mut request = SignedRequest::new("POST", "iotevents", &self.region, &request_uri);
+        request.set_operation("CreateDetectorModel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1965,6 +2424,7 @@ impl IotEvents for IotEventsClient {
         let request_uri = "/inputs";
 
         let mut request = SignedRequest::new("POST", "iotevents", &self.region, &request_uri);
+        request.set_operation("CreateInput");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1998,6 +2458,7 @@ impl IotEvents for IotEventsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "iotevents", &self.region, &request_uri);
+        request.set_operation("DeleteDetectorModel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2025,6 +2486,7 @@ impl IotEvents for IotEventsClient {
         let request_uri = format!("/inputs/{input_name}", input_name = input.input_name);
 
         let mut request = SignedRequest::new("DELETE", "iotevents", &self.region, &request_uri);
+        request.set_operation("DeleteInput");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2055,6 +2517,7 @@ impl IotEvents for IotEventsClient {
         );
 
         let mut request = SignedRequest::new("GET", "iotevents", &self.region, &request_uri);
+        request.set_operation("DescribeDetectorModel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2088,6 +2551,7 @@ impl IotEvents for IotEventsClient {
         let request_uri = format!("/inputs/{input_name}", input_name = input.input_name);
 
         let mut request = SignedRequest::new("GET", "iotevents", &self.region, &request_uri);
+        request.set_operation("DescribeInput");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2114,6 +2578,7 @@ impl IotEvents for IotEventsClient {
         let request_uri = "/logging";
 
         let mut request = SignedRequest::new("GET", "iotevents", &self.region, &request_uri);
+        request.set_operation("DescribeLoggingOptions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2145,6 +2610,7 @@ impl IotEvents for IotEventsClient {
         );
 
         let mut request = SignedRequest::new("GET", "iotevents", &self.region, &request_uri);
+        request.set_operation("ListDetectorModelVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2181,6 +2647,7 @@ impl IotEvents for IotEventsClient {
         let request_uri = "/detector-models";
 
         let mut request = SignedRequest::new("GET", "iotevents", &self.region, &request_uri);
+        request.set_operation("ListDetectorModels");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2217,6 +2684,7 @@ impl IotEvents for IotEventsClient {
         let request_uri = "/inputs";
 
         let mut request = SignedRequest::new("GET", "iotevents", &self.region, &request_uri);
+        request.set_operation("ListInputs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2253,6 +2721,7 @@ impl IotEvents for IotEventsClient {
         let request_uri = "/tags";
 
         let mut request = SignedRequest::new("GET", "iotevents", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2284,6 +2753,7 @@ impl IotEvents for IotEventsClient {
         let request_uri = "/logging";
 
         let mut request = SignedRequest::new("PUT", "iotevents", &self.region, &request_uri);
+        request.set_operation("PutLoggingOptions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2313,6 +2783,7 @@ impl IotEvents for IotEventsClient {
         let request_uri = "/tags";
 
         let mut request = SignedRequest::new("POST", "iotevents", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2347,6 +2818,7 @@ impl IotEvents for IotEventsClient {
         let request_uri = "/tags";
 
         let mut request = SignedRequest::new("DELETE", "iotevents", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2384,6 +2856,7 @@ impl IotEvents for IotEventsClient {
         );
 
         let mut request = SignedRequest::new("POST", "iotevents", &self.region, &request_uri);
+        request.set_operation("UpdateDetectorModel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2414,6 +2887,7 @@ impl IotEvents for IotEventsClient {
         let request_uri = format!("/inputs/{input_name}", input_name = input.input_name);
 
         let mut request = SignedRequest::new("PUT", "iotevents", &self.region, &request_uri);
+        request.set_operation("UpdateInput");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -46,14 +46,112 @@ pub struct CloseTunnelResponse {}
 pub struct ConnectionState {
     /// <p>The last time the connection status was updated.</p>
     #[serde(rename = "lastUpdatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_at: Option<f64>,
+    pub last_updated_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The connection status of the tunnel. Valid values are <code>CONNECTED</code> and <code>DISCONNECTED</code>.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ConnectionStatus>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ConnectionStatus {
+    /// `CONNECTED`
+    Connected,
+    /// `DISCONNECTED`
+    Disconnected,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ConnectionStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ConnectionStatus::Connected => "CONNECTED",
+            ConnectionStatus::Disconnected => "DISCONNECTED",
+            ConnectionStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ConnectionStatus {
+    fn default() -> ConnectionStatus {
+        ConnectionStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ConnectionStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ConnectionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ConnectionStatus {
+    fn from(value: &'a str) -> ConnectionStatus {
+        match value {
+            "CONNECTED" => ConnectionStatus::Connected,
+            "DISCONNECTED" => ConnectionStatus::Disconnected,
+            _ => ConnectionStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ConnectionStatus {
+    fn from(value: String) -> ConnectionStatus {
+        match value.as_str() {
+            "CONNECTED" => ConnectionStatus::Connected,
+            "DISCONNECTED" => ConnectionStatus::Disconnected,
+            _ => ConnectionStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ConnectionStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ConnectionStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ConnectionStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ConnectionStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ConnectionStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ConnectionStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct DescribeTunnelRequest {
@@ -212,8 +310,13 @@ pub struct TimeoutConfig {
 pub struct Tunnel {
     /// <p>The time when the tunnel was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A description of the tunnel.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -228,8 +331,13 @@ pub struct Tunnel {
     pub destination_connection_state: Option<ConnectionState>,
     /// <p>The last time the tunnel was updated.</p>
     #[serde(rename = "lastUpdatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_at: Option<f64>,
+    pub last_updated_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The connection state of the source application.</p>
     #[serde(rename = "sourceConnectionState")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -237,7 +345,7 @@ pub struct Tunnel {
     /// <p>The status of a tunnel. Valid values are: Open and Closed.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<TunnelStatus>,
     /// <p>A list of tag metadata associated with the secure tunnel.</p>
     #[serde(rename = "tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -256,26 +364,129 @@ pub struct Tunnel {
     pub tunnel_id: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TunnelStatus {
+    /// `OPEN`
+    Open,
+    /// `CLOSED`
+    Closed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TunnelStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TunnelStatus::Open => "OPEN",
+            TunnelStatus::Closed => "CLOSED",
+            TunnelStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TunnelStatus {
+    fn default() -> TunnelStatus {
+        TunnelStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TunnelStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TunnelStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TunnelStatus {
+    fn from(value: &'a str) -> TunnelStatus {
+        match value {
+            "OPEN" => TunnelStatus::Open,
+            "CLOSED" => TunnelStatus::Closed,
+            _ => TunnelStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TunnelStatus {
+    fn from(value: String) -> TunnelStatus {
+        match value.as_str() {
+            "OPEN" => TunnelStatus::Open,
+            "CLOSED" => TunnelStatus::Closed,
+            _ => TunnelStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TunnelStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TunnelStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TunnelStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TunnelStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TunnelStatus {
+    fn deserialize<D>(deserializer: D) -> Result<TunnelStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about the tunnel.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TunnelSummary {
     /// <p>The time the tunnel was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A description of the tunnel.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     /// <p>The time the tunnel was last updated.</p>
     #[serde(rename = "lastUpdatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_at: Option<f64>,
+    pub last_updated_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The status of a tunnel. Valid values are: Open and Closed.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<TunnelStatus>,
     /// <p>The Amazon Resource Name of the tunnel. The tunnel ARN format is <code>arn:aws:tunnel:&lt;region&gt;:&lt;account-id&gt;:tunnel/&lt;tunnel-id&gt;</code> </p>
     #[serde(rename = "tunnelArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -560,11 +771,12 @@ pub struct IoTSecureTunnelingClient {
 impl IoTSecureTunnelingClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> IoTSecureTunnelingClient {
         IoTSecureTunnelingClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "IoTSecureTunneling"),
         }
     }
 
@@ -596,6 +808,7 @@ impl IoTSecureTunneling for IoTSecureTunnelingClient {
         input: CloseTunnelRequest,
     ) -> Result<CloseTunnelResponse, RusotoError<CloseTunnelError>> {
         let mut request = SignedRequest::new("POST", "IoTSecuredTunneling", &self.region, "/");
+        request.set_operation("CloseTunnel");
         request.set_endpoint_prefix("api.tunneling.iot".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "IoTSecuredTunneling.CloseTunnel");
@@ -623,6 +836,7 @@ impl IoTSecureTunneling for IoTSecureTunnelingClient {
         input: DescribeTunnelRequest,
     ) -> Result<DescribeTunnelResponse, RusotoError<DescribeTunnelError>> {
         let mut request = SignedRequest::new("POST", "IoTSecuredTunneling", &self.region, "/");
+        request.set_operation("DescribeTunnel");
         request.set_endpoint_prefix("api.tunneling.iot".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "IoTSecuredTunneling.DescribeTunnel");
@@ -650,6 +864,7 @@ impl IoTSecureTunneling for IoTSecureTunnelingClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = SignedRequest::new("POST", "IoTSecuredTunneling", &self.region, "/");
+        request.set_operation("ListTagsForResource");
         request.set_endpoint_prefix("api.tunneling.iot".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "IoTSecuredTunneling.ListTagsForResource");
@@ -678,6 +893,7 @@ impl IoTSecureTunneling for IoTSecureTunnelingClient {
         input: ListTunnelsRequest,
     ) -> Result<ListTunnelsResponse, RusotoError<ListTunnelsError>> {
         let mut request = SignedRequest::new("POST", "IoTSecuredTunneling", &self.region, "/");
+        request.set_operation("ListTunnels");
         request.set_endpoint_prefix("api.tunneling.iot".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "IoTSecuredTunneling.ListTunnels");
@@ -705,6 +921,7 @@ impl IoTSecureTunneling for IoTSecureTunnelingClient {
         input: OpenTunnelRequest,
     ) -> Result<OpenTunnelResponse, RusotoError<OpenTunnelError>> {
         let mut request = SignedRequest::new("POST", "IoTSecuredTunneling", &self.region, "/");
+        request.set_operation("OpenTunnel");
         request.set_endpoint_prefix("api.tunneling.iot".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "IoTSecuredTunneling.OpenTunnel");
@@ -732,6 +949,7 @@ impl IoTSecureTunneling for IoTSecureTunnelingClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = SignedRequest::new("POST", "IoTSecuredTunneling", &self.region, "/");
+        request.set_operation("TagResource");
         request.set_endpoint_prefix("api.tunneling.iot".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "IoTSecuredTunneling.TagResource");
@@ -759,6 +977,7 @@ impl IoTSecureTunneling for IoTSecureTunnelingClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = SignedRequest::new("POST", "IoTSecuredTunneling", &self.region, "/");
+        request.set_operation("UntagResource");
         request.set_endpoint_prefix("api.tunneling.iot".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "IoTSecuredTunneling.UntagResource");
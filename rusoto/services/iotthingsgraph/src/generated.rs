@@ -90,7 +90,7 @@ pub struct CreateSystemInstanceRequest {
     pub tags: Option<Vec<Tag>>,
     /// <p>The target type of the deployment. Valid values are <code>GREENGRASS</code> and <code>CLOUD</code>.</p>
     #[serde(rename = "target")]
-    pub target: String,
+    pub target: DeploymentTarget,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -128,12 +128,100 @@ pub struct CreateSystemTemplateResponse {
 pub struct DefinitionDocument {
     /// <p>The language used to define the entity. <code>GRAPHQL</code> is the only valid value.</p>
     #[serde(rename = "language")]
-    pub language: String,
+    pub language: DefinitionLanguage,
     /// <p>The GraphQL text that defines the entity.</p>
     #[serde(rename = "text")]
     pub text: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DefinitionLanguage {
+    /// `GRAPHQL`
+    Graphql,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DefinitionLanguage {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DefinitionLanguage::Graphql => "GRAPHQL",
+            DefinitionLanguage::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DefinitionLanguage {
+    fn default() -> DefinitionLanguage {
+        DefinitionLanguage::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DefinitionLanguage {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DefinitionLanguage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DefinitionLanguage {
+    fn from(value: &'a str) -> DefinitionLanguage {
+        match value {
+            "GRAPHQL" => DefinitionLanguage::Graphql,
+            _ => DefinitionLanguage::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DefinitionLanguage {
+    fn from(value: String) -> DefinitionLanguage {
+        match value.as_str() {
+            "GRAPHQL" => DefinitionLanguage::Graphql,
+            _ => DefinitionLanguage::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DefinitionLanguage {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DefinitionLanguage, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DefinitionLanguage {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DefinitionLanguage {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DefinitionLanguage {
+    fn deserialize<D>(deserializer: D) -> Result<DefinitionLanguage, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct DeleteFlowTemplateRequest {
@@ -223,6 +311,99 @@ pub struct DeploySystemInstanceResponse {
     pub summary: SystemInstanceSummary,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DeploymentTarget {
+    /// `GREENGRASS`
+    Greengrass,
+    /// `CLOUD`
+    Cloud,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DeploymentTarget {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeploymentTarget::Greengrass => "GREENGRASS",
+            DeploymentTarget::Cloud => "CLOUD",
+            DeploymentTarget::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DeploymentTarget {
+    fn default() -> DeploymentTarget {
+        DeploymentTarget::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DeploymentTarget {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DeploymentTarget {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DeploymentTarget {
+    fn from(value: &'a str) -> DeploymentTarget {
+        match value {
+            "GREENGRASS" => DeploymentTarget::Greengrass,
+            "CLOUD" => DeploymentTarget::Cloud,
+            _ => DeploymentTarget::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeploymentTarget {
+    fn from(value: String) -> DeploymentTarget {
+        match value.as_str() {
+            "GREENGRASS" => DeploymentTarget::Greengrass,
+            "CLOUD" => DeploymentTarget::Cloud,
+            _ => DeploymentTarget::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DeploymentTarget {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DeploymentTarget, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DeploymentTarget {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DeploymentTarget {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeploymentTarget {
+    fn deserialize<D>(deserializer: D) -> Result<DeploymentTarget, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct DeprecateFlowTemplateRequest {
@@ -286,7 +467,7 @@ pub struct DescribeNamespaceResponse {
 pub struct DissociateEntityFromThingRequest {
     /// <p>The entity type from which to disassociate the thing.</p>
     #[serde(rename = "entityType")]
-    pub entity_type: String,
+    pub entity_type: EntityType,
     /// <p>The name of the thing to disassociate.</p>
     #[serde(rename = "thingName")]
     pub thing_name: String,
@@ -306,8 +487,13 @@ pub struct EntityDescription {
     pub arn: Option<String>,
     /// <p>The time at which the entity was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The definition document of the entity.</p>
     #[serde(rename = "definition")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -319,7 +505,7 @@ pub struct EntityDescription {
     /// <p>The entity type.</p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<EntityType>,
 }
 
 /// <p>An object that filters an entity search. Multiple filters function as OR criteria in the search. For example a search that includes a <code>NAMESPACE</code> and a <code>REFERENCED_ENTITY_ID</code> filter searches for entities in the specified namespace that use the entity specified by the value of <code>REFERENCED_ENTITY_ID</code>.</p>
@@ -329,13 +515,417 @@ pub struct EntityFilter {
     /// <p>The name of the entity search filter field. <code>REFERENCED_ENTITY_ID</code> filters on entities that are used by the entity in the result set. For example, you can filter on the ID of a property that is used in a state.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
+    pub name: Option<EntityFilterName>,
     /// <p>An array of string values for the search filter field. Multiple values function as AND criteria in the search.</p>
     #[serde(rename = "value")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<Vec<String>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EntityFilterName {
+    /// `NAME`
+    Name,
+    /// `NAMESPACE`
+    Namespace,
+    /// `SEMANTIC_TYPE_PATH`
+    SemanticTypePath,
+    /// `REFERENCED_ENTITY_ID`
+    ReferencedEntityId,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EntityFilterName {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EntityFilterName::Name => "NAME",
+            EntityFilterName::Namespace => "NAMESPACE",
+            EntityFilterName::SemanticTypePath => "SEMANTIC_TYPE_PATH",
+            EntityFilterName::ReferencedEntityId => "REFERENCED_ENTITY_ID",
+            EntityFilterName::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EntityFilterName {
+    fn default() -> EntityFilterName {
+        EntityFilterName::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EntityFilterName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EntityFilterName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EntityFilterName {
+    fn from(value: &'a str) -> EntityFilterName {
+        match value {
+            "NAME" => EntityFilterName::Name,
+            "NAMESPACE" => EntityFilterName::Namespace,
+            "SEMANTIC_TYPE_PATH" => EntityFilterName::SemanticTypePath,
+            "REFERENCED_ENTITY_ID" => EntityFilterName::ReferencedEntityId,
+            _ => EntityFilterName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EntityFilterName {
+    fn from(value: String) -> EntityFilterName {
+        match value.as_str() {
+            "NAME" => EntityFilterName::Name,
+            "NAMESPACE" => EntityFilterName::Namespace,
+            "SEMANTIC_TYPE_PATH" => EntityFilterName::SemanticTypePath,
+            "REFERENCED_ENTITY_ID" => EntityFilterName::ReferencedEntityId,
+            _ => EntityFilterName::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EntityFilterName {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EntityFilterName, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EntityFilterName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EntityFilterName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EntityFilterName {
+    fn deserialize<D>(deserializer: D) -> Result<EntityFilterName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EntityType {
+    /// `DEVICE`
+    Device,
+    /// `SERVICE`
+    Service,
+    /// `DEVICE_MODEL`
+    DeviceModel,
+    /// `CAPABILITY`
+    Capability,
+    /// `STATE`
+    State,
+    /// `ACTION`
+    Action,
+    /// `EVENT`
+    Event,
+    /// `PROPERTY`
+    Property,
+    /// `MAPPING`
+    Mapping,
+    /// `ENUM`
+    Enum,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EntityType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EntityType::Device => "DEVICE",
+            EntityType::Service => "SERVICE",
+            EntityType::DeviceModel => "DEVICE_MODEL",
+            EntityType::Capability => "CAPABILITY",
+            EntityType::State => "STATE",
+            EntityType::Action => "ACTION",
+            EntityType::Event => "EVENT",
+            EntityType::Property => "PROPERTY",
+            EntityType::Mapping => "MAPPING",
+            EntityType::Enum => "ENUM",
+            EntityType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EntityType {
+    fn default() -> EntityType {
+        EntityType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EntityType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EntityType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EntityType {
+    fn from(value: &'a str) -> EntityType {
+        match value {
+            "DEVICE" => EntityType::Device,
+            "SERVICE" => EntityType::Service,
+            "DEVICE_MODEL" => EntityType::DeviceModel,
+            "CAPABILITY" => EntityType::Capability,
+            "STATE" => EntityType::State,
+            "ACTION" => EntityType::Action,
+            "EVENT" => EntityType::Event,
+            "PROPERTY" => EntityType::Property,
+            "MAPPING" => EntityType::Mapping,
+            "ENUM" => EntityType::Enum,
+            _ => EntityType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EntityType {
+    fn from(value: String) -> EntityType {
+        match value.as_str() {
+            "DEVICE" => EntityType::Device,
+            "SERVICE" => EntityType::Service,
+            "DEVICE_MODEL" => EntityType::DeviceModel,
+            "CAPABILITY" => EntityType::Capability,
+            "STATE" => EntityType::State,
+            "ACTION" => EntityType::Action,
+            "EVENT" => EntityType::Event,
+            "PROPERTY" => EntityType::Property,
+            "MAPPING" => EntityType::Mapping,
+            "ENUM" => EntityType::Enum,
+            _ => EntityType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EntityType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EntityType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EntityType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EntityType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EntityType {
+    fn deserialize<D>(deserializer: D) -> Result<EntityType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FlowExecutionEventType {
+    /// `EXECUTION_STARTED`
+    ExecutionStarted,
+    /// `EXECUTION_FAILED`
+    ExecutionFailed,
+    /// `EXECUTION_ABORTED`
+    ExecutionAborted,
+    /// `EXECUTION_SUCCEEDED`
+    ExecutionSucceeded,
+    /// `STEP_STARTED`
+    StepStarted,
+    /// `STEP_FAILED`
+    StepFailed,
+    /// `STEP_SUCCEEDED`
+    StepSucceeded,
+    /// `ACTIVITY_SCHEDULED`
+    ActivityScheduled,
+    /// `ACTIVITY_STARTED`
+    ActivityStarted,
+    /// `ACTIVITY_FAILED`
+    ActivityFailed,
+    /// `ACTIVITY_SUCCEEDED`
+    ActivitySucceeded,
+    /// `START_FLOW_EXECUTION_TASK`
+    StartFlowExecutionTask,
+    /// `SCHEDULE_NEXT_READY_STEPS_TASK`
+    ScheduleNextReadyStepsTask,
+    /// `THING_ACTION_TASK`
+    ThingActionTask,
+    /// `THING_ACTION_TASK_FAILED`
+    ThingActionTaskFailed,
+    /// `THING_ACTION_TASK_SUCCEEDED`
+    ThingActionTaskSucceeded,
+    /// `ACKNOWLEDGE_TASK_MESSAGE`
+    AcknowledgeTaskMessage,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl FlowExecutionEventType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FlowExecutionEventType::ExecutionStarted => "EXECUTION_STARTED",
+            FlowExecutionEventType::ExecutionFailed => "EXECUTION_FAILED",
+            FlowExecutionEventType::ExecutionAborted => "EXECUTION_ABORTED",
+            FlowExecutionEventType::ExecutionSucceeded => "EXECUTION_SUCCEEDED",
+            FlowExecutionEventType::StepStarted => "STEP_STARTED",
+            FlowExecutionEventType::StepFailed => "STEP_FAILED",
+            FlowExecutionEventType::StepSucceeded => "STEP_SUCCEEDED",
+            FlowExecutionEventType::ActivityScheduled => "ACTIVITY_SCHEDULED",
+            FlowExecutionEventType::ActivityStarted => "ACTIVITY_STARTED",
+            FlowExecutionEventType::ActivityFailed => "ACTIVITY_FAILED",
+            FlowExecutionEventType::ActivitySucceeded => "ACTIVITY_SUCCEEDED",
+            FlowExecutionEventType::StartFlowExecutionTask => "START_FLOW_EXECUTION_TASK",
+            FlowExecutionEventType::ScheduleNextReadyStepsTask => "SCHEDULE_NEXT_READY_STEPS_TASK",
+            FlowExecutionEventType::ThingActionTask => "THING_ACTION_TASK",
+            FlowExecutionEventType::ThingActionTaskFailed => "THING_ACTION_TASK_FAILED",
+            FlowExecutionEventType::ThingActionTaskSucceeded => "THING_ACTION_TASK_SUCCEEDED",
+            FlowExecutionEventType::AcknowledgeTaskMessage => "ACKNOWLEDGE_TASK_MESSAGE",
+            FlowExecutionEventType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for FlowExecutionEventType {
+    fn default() -> FlowExecutionEventType {
+        FlowExecutionEventType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for FlowExecutionEventType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FlowExecutionEventType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FlowExecutionEventType {
+    fn from(value: &'a str) -> FlowExecutionEventType {
+        match value {
+            "EXECUTION_STARTED" => FlowExecutionEventType::ExecutionStarted,
+            "EXECUTION_FAILED" => FlowExecutionEventType::ExecutionFailed,
+            "EXECUTION_ABORTED" => FlowExecutionEventType::ExecutionAborted,
+            "EXECUTION_SUCCEEDED" => FlowExecutionEventType::ExecutionSucceeded,
+            "STEP_STARTED" => FlowExecutionEventType::StepStarted,
+            "STEP_FAILED" => FlowExecutionEventType::StepFailed,
+            "STEP_SUCCEEDED" => FlowExecutionEventType::StepSucceeded,
+            "ACTIVITY_SCHEDULED" => FlowExecutionEventType::ActivityScheduled,
+            "ACTIVITY_STARTED" => FlowExecutionEventType::ActivityStarted,
+            "ACTIVITY_FAILED" => FlowExecutionEventType::ActivityFailed,
+            "ACTIVITY_SUCCEEDED" => FlowExecutionEventType::ActivitySucceeded,
+            "START_FLOW_EXECUTION_TASK" => FlowExecutionEventType::StartFlowExecutionTask,
+            "SCHEDULE_NEXT_READY_STEPS_TASK" => FlowExecutionEventType::ScheduleNextReadyStepsTask,
+            "THING_ACTION_TASK" => FlowExecutionEventType::ThingActionTask,
+            "THING_ACTION_TASK_FAILED" => FlowExecutionEventType::ThingActionTaskFailed,
+            "THING_ACTION_TASK_SUCCEEDED" => FlowExecutionEventType::ThingActionTaskSucceeded,
+            "ACKNOWLEDGE_TASK_MESSAGE" => FlowExecutionEventType::AcknowledgeTaskMessage,
+            _ => FlowExecutionEventType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FlowExecutionEventType {
+    fn from(value: String) -> FlowExecutionEventType {
+        match value.as_str() {
+            "EXECUTION_STARTED" => FlowExecutionEventType::ExecutionStarted,
+            "EXECUTION_FAILED" => FlowExecutionEventType::ExecutionFailed,
+            "EXECUTION_ABORTED" => FlowExecutionEventType::ExecutionAborted,
+            "EXECUTION_SUCCEEDED" => FlowExecutionEventType::ExecutionSucceeded,
+            "STEP_STARTED" => FlowExecutionEventType::StepStarted,
+            "STEP_FAILED" => FlowExecutionEventType::StepFailed,
+            "STEP_SUCCEEDED" => FlowExecutionEventType::StepSucceeded,
+            "ACTIVITY_SCHEDULED" => FlowExecutionEventType::ActivityScheduled,
+            "ACTIVITY_STARTED" => FlowExecutionEventType::ActivityStarted,
+            "ACTIVITY_FAILED" => FlowExecutionEventType::ActivityFailed,
+            "ACTIVITY_SUCCEEDED" => FlowExecutionEventType::ActivitySucceeded,
+            "START_FLOW_EXECUTION_TASK" => FlowExecutionEventType::StartFlowExecutionTask,
+            "SCHEDULE_NEXT_READY_STEPS_TASK" => FlowExecutionEventType::ScheduleNextReadyStepsTask,
+            "THING_ACTION_TASK" => FlowExecutionEventType::ThingActionTask,
+            "THING_ACTION_TASK_FAILED" => FlowExecutionEventType::ThingActionTaskFailed,
+            "THING_ACTION_TASK_SUCCEEDED" => FlowExecutionEventType::ThingActionTaskSucceeded,
+            "ACKNOWLEDGE_TASK_MESSAGE" => FlowExecutionEventType::AcknowledgeTaskMessage,
+            _ => FlowExecutionEventType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for FlowExecutionEventType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<FlowExecutionEventType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FlowExecutionEventType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FlowExecutionEventType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FlowExecutionEventType {
+    fn deserialize<D>(deserializer: D) -> Result<FlowExecutionEventType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An object that contains information about a flow event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -343,7 +933,7 @@ pub struct FlowExecutionMessage {
     /// <p>The type of flow event .</p>
     #[serde(rename = "eventType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub event_type: Option<String>,
+    pub event_type: Option<FlowExecutionEventType>,
     /// <p>The unique identifier of the message.</p>
     #[serde(rename = "messageId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -354,18 +944,131 @@ pub struct FlowExecutionMessage {
     pub payload: Option<String>,
     /// <p>The date and time when the message was last updated.</p>
     #[serde(rename = "timestamp")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub timestamp: Option<f64>,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FlowExecutionStatus {
+    /// `RUNNING`
+    Running,
+    /// `ABORTED`
+    Aborted,
+    /// `SUCCEEDED`
+    Succeeded,
+    /// `FAILED`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl FlowExecutionStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FlowExecutionStatus::Running => "RUNNING",
+            FlowExecutionStatus::Aborted => "ABORTED",
+            FlowExecutionStatus::Succeeded => "SUCCEEDED",
+            FlowExecutionStatus::Failed => "FAILED",
+            FlowExecutionStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for FlowExecutionStatus {
+    fn default() -> FlowExecutionStatus {
+        FlowExecutionStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for FlowExecutionStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FlowExecutionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FlowExecutionStatus {
+    fn from(value: &'a str) -> FlowExecutionStatus {
+        match value {
+            "RUNNING" => FlowExecutionStatus::Running,
+            "ABORTED" => FlowExecutionStatus::Aborted,
+            "SUCCEEDED" => FlowExecutionStatus::Succeeded,
+            "FAILED" => FlowExecutionStatus::Failed,
+            _ => FlowExecutionStatus::Unknown(value.to_owned()),
+        }
+    }
 }
 
+impl From<String> for FlowExecutionStatus {
+    fn from(value: String) -> FlowExecutionStatus {
+        match value.as_str() {
+            "RUNNING" => FlowExecutionStatus::Running,
+            "ABORTED" => FlowExecutionStatus::Aborted,
+            "SUCCEEDED" => FlowExecutionStatus::Succeeded,
+            "FAILED" => FlowExecutionStatus::Failed,
+            _ => FlowExecutionStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for FlowExecutionStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<FlowExecutionStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FlowExecutionStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FlowExecutionStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FlowExecutionStatus {
+    fn deserialize<D>(deserializer: D) -> Result<FlowExecutionStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An object that contains summary information about a flow execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct FlowExecutionSummary {
     /// <p>The date and time when the flow execution summary was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The ID of the flow execution.</p>
     #[serde(rename = "flowExecutionId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -377,15 +1080,20 @@ pub struct FlowExecutionSummary {
     /// <p>The current status of the flow execution.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<FlowExecutionStatus>,
     /// <p>The ID of the system instance that contains the flow.</p>
     #[serde(rename = "systemInstanceId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub system_instance_id: Option<String>,
     /// <p>The date and time when the flow execution summary was last updated.</p>
     #[serde(rename = "updatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<f64>,
+    pub updated_at: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>An object that contains a workflow's definition and summary information.</p>
@@ -412,12 +1120,100 @@ pub struct FlowTemplateDescription {
 pub struct FlowTemplateFilter {
     /// <p>The name of the search filter field.</p>
     #[serde(rename = "name")]
-    pub name: String,
+    pub name: FlowTemplateFilterName,
     /// <p>An array of string values for the search filter field. Multiple values function as AND criteria in the search.</p>
     #[serde(rename = "value")]
     pub value: Vec<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FlowTemplateFilterName {
+    /// `DEVICE_MODEL_ID`
+    DeviceModelId,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl FlowTemplateFilterName {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FlowTemplateFilterName::DeviceModelId => "DEVICE_MODEL_ID",
+            FlowTemplateFilterName::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for FlowTemplateFilterName {
+    fn default() -> FlowTemplateFilterName {
+        FlowTemplateFilterName::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for FlowTemplateFilterName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FlowTemplateFilterName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FlowTemplateFilterName {
+    fn from(value: &'a str) -> FlowTemplateFilterName {
+        match value {
+            "DEVICE_MODEL_ID" => FlowTemplateFilterName::DeviceModelId,
+            _ => FlowTemplateFilterName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FlowTemplateFilterName {
+    fn from(value: String) -> FlowTemplateFilterName {
+        match value.as_str() {
+            "DEVICE_MODEL_ID" => FlowTemplateFilterName::DeviceModelId,
+            _ => FlowTemplateFilterName::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for FlowTemplateFilterName {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<FlowTemplateFilterName, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FlowTemplateFilterName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FlowTemplateFilterName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FlowTemplateFilterName {
+    fn deserialize<D>(deserializer: D) -> Result<FlowTemplateFilterName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An object that contains summary information about a workflow.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -428,8 +1224,13 @@ pub struct FlowTemplateSummary {
     pub arn: Option<String>,
     /// <p>The date when the workflow was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The ID of the workflow.</p>
     #[serde(rename = "id")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -521,7 +1322,7 @@ pub struct GetNamespaceDeletionStatusResponse {
     /// <p>An error code returned by the namespace deletion task.</p>
     #[serde(rename = "errorCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub error_code: Option<String>,
+    pub error_code: Option<NamespaceDeletionStatusErrorCodes>,
     /// <p>An error code returned by the namespace deletion task.</p>
     #[serde(rename = "errorMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -537,7 +1338,7 @@ pub struct GetNamespaceDeletionStatusResponse {
     /// <p>The status of the deletion request.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<NamespaceDeletionStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -620,7 +1421,12 @@ pub struct GetUploadStatusRequest {
 pub struct GetUploadStatusResponse {
     /// <p>The date at which the upload was created.</p>
     #[serde(rename = "createdDate")]
-    pub created_date: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub created_date: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The reason for an upload failure.</p>
     #[serde(rename = "failureReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -642,7 +1448,7 @@ pub struct GetUploadStatusResponse {
     pub upload_id: String,
     /// <p>The status of the upload. The initial status is <code>IN_PROGRESS</code>. The response show all validation failures if the upload fails.</p>
     #[serde(rename = "uploadStatus")]
-    pub upload_status: String,
+    pub upload_status: UploadStatus,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -716,12 +1522,198 @@ pub struct MetricsConfiguration {
     pub metric_rule_role_arn: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum NamespaceDeletionStatus {
+    /// `IN_PROGRESS`
+    InProgress,
+    /// `SUCCEEDED`
+    Succeeded,
+    /// `FAILED`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl NamespaceDeletionStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            NamespaceDeletionStatus::InProgress => "IN_PROGRESS",
+            NamespaceDeletionStatus::Succeeded => "SUCCEEDED",
+            NamespaceDeletionStatus::Failed => "FAILED",
+            NamespaceDeletionStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for NamespaceDeletionStatus {
+    fn default() -> NamespaceDeletionStatus {
+        NamespaceDeletionStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for NamespaceDeletionStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for NamespaceDeletionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for NamespaceDeletionStatus {
+    fn from(value: &'a str) -> NamespaceDeletionStatus {
+        match value {
+            "IN_PROGRESS" => NamespaceDeletionStatus::InProgress,
+            "SUCCEEDED" => NamespaceDeletionStatus::Succeeded,
+            "FAILED" => NamespaceDeletionStatus::Failed,
+            _ => NamespaceDeletionStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for NamespaceDeletionStatus {
+    fn from(value: String) -> NamespaceDeletionStatus {
+        match value.as_str() {
+            "IN_PROGRESS" => NamespaceDeletionStatus::InProgress,
+            "SUCCEEDED" => NamespaceDeletionStatus::Succeeded,
+            "FAILED" => NamespaceDeletionStatus::Failed,
+            _ => NamespaceDeletionStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for NamespaceDeletionStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<NamespaceDeletionStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for NamespaceDeletionStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for NamespaceDeletionStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for NamespaceDeletionStatus {
+    fn deserialize<D>(deserializer: D) -> Result<NamespaceDeletionStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum NamespaceDeletionStatusErrorCodes {
+    /// `VALIDATION_FAILED`
+    ValidationFailed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl NamespaceDeletionStatusErrorCodes {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            NamespaceDeletionStatusErrorCodes::ValidationFailed => "VALIDATION_FAILED",
+            NamespaceDeletionStatusErrorCodes::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for NamespaceDeletionStatusErrorCodes {
+    fn default() -> NamespaceDeletionStatusErrorCodes {
+        NamespaceDeletionStatusErrorCodes::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for NamespaceDeletionStatusErrorCodes {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for NamespaceDeletionStatusErrorCodes {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for NamespaceDeletionStatusErrorCodes {
+    fn from(value: &'a str) -> NamespaceDeletionStatusErrorCodes {
+        match value {
+            "VALIDATION_FAILED" => NamespaceDeletionStatusErrorCodes::ValidationFailed,
+            _ => NamespaceDeletionStatusErrorCodes::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for NamespaceDeletionStatusErrorCodes {
+    fn from(value: String) -> NamespaceDeletionStatusErrorCodes {
+        match value.as_str() {
+            "VALIDATION_FAILED" => NamespaceDeletionStatusErrorCodes::ValidationFailed,
+            _ => NamespaceDeletionStatusErrorCodes::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for NamespaceDeletionStatusErrorCodes {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<NamespaceDeletionStatusErrorCodes, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for NamespaceDeletionStatusErrorCodes {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for NamespaceDeletionStatusErrorCodes {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for NamespaceDeletionStatusErrorCodes {
+    fn deserialize<D>(deserializer: D) -> Result<NamespaceDeletionStatusErrorCodes, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct SearchEntitiesRequest {
     /// <p>The entity types for which to search.</p>
     #[serde(rename = "entityTypes")]
-    pub entity_types: Vec<String>,
+    pub entity_types: Vec<EntityType>,
     /// <p>Optional filter to apply to the search. Valid filters are <code>NAME</code> <code>NAMESPACE</code>, <code>SEMANTIC_TYPE_PATH</code> and <code>REFERENCED_ENTITY_ID</code>. <code>REFERENCED_ENTITY_ID</code> filters on entities that are used by the entity in the result set. For example, you can filter on the ID of a property that is used in a state.</p> <p>Multiple filters function as OR criteria in the query. Multiple values passed inside the filter function as AND criteria.</p>
     #[serde(rename = "filters")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -758,8 +1750,13 @@ pub struct SearchEntitiesResponse {
 pub struct SearchFlowExecutionsRequest {
     /// <p>The date and time of the latest flow execution to return.</p>
     #[serde(rename = "endTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The ID of a flow execution.</p>
     #[serde(rename = "flowExecutionId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -774,8 +1771,13 @@ pub struct SearchFlowExecutionsRequest {
     pub next_token: Option<String>,
     /// <p>The date and time of the earliest flow execution to return.</p>
     #[serde(rename = "startTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The ID of the system instance that contains the flow.</p>
     #[serde(rename = "systemInstanceId")]
     pub system_instance_id: String,
@@ -917,6 +1919,129 @@ pub struct SearchThingsResponse {
     pub things: Option<Vec<Thing>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SystemInstanceDeploymentStatus {
+    /// `NOT_DEPLOYED`
+    NotDeployed,
+    /// `BOOTSTRAP`
+    Bootstrap,
+    /// `DEPLOY_IN_PROGRESS`
+    DeployInProgress,
+    /// `DEPLOYED_IN_TARGET`
+    DeployedInTarget,
+    /// `UNDEPLOY_IN_PROGRESS`
+    UndeployInProgress,
+    /// `FAILED`
+    Failed,
+    /// `PENDING_DELETE`
+    PendingDelete,
+    /// `DELETED_IN_TARGET`
+    DeletedInTarget,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SystemInstanceDeploymentStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SystemInstanceDeploymentStatus::NotDeployed => "NOT_DEPLOYED",
+            SystemInstanceDeploymentStatus::Bootstrap => "BOOTSTRAP",
+            SystemInstanceDeploymentStatus::DeployInProgress => "DEPLOY_IN_PROGRESS",
+            SystemInstanceDeploymentStatus::DeployedInTarget => "DEPLOYED_IN_TARGET",
+            SystemInstanceDeploymentStatus::UndeployInProgress => "UNDEPLOY_IN_PROGRESS",
+            SystemInstanceDeploymentStatus::Failed => "FAILED",
+            SystemInstanceDeploymentStatus::PendingDelete => "PENDING_DELETE",
+            SystemInstanceDeploymentStatus::DeletedInTarget => "DELETED_IN_TARGET",
+            SystemInstanceDeploymentStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SystemInstanceDeploymentStatus {
+    fn default() -> SystemInstanceDeploymentStatus {
+        SystemInstanceDeploymentStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SystemInstanceDeploymentStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SystemInstanceDeploymentStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SystemInstanceDeploymentStatus {
+    fn from(value: &'a str) -> SystemInstanceDeploymentStatus {
+        match value {
+            "NOT_DEPLOYED" => SystemInstanceDeploymentStatus::NotDeployed,
+            "BOOTSTRAP" => SystemInstanceDeploymentStatus::Bootstrap,
+            "DEPLOY_IN_PROGRESS" => SystemInstanceDeploymentStatus::DeployInProgress,
+            "DEPLOYED_IN_TARGET" => SystemInstanceDeploymentStatus::DeployedInTarget,
+            "UNDEPLOY_IN_PROGRESS" => SystemInstanceDeploymentStatus::UndeployInProgress,
+            "FAILED" => SystemInstanceDeploymentStatus::Failed,
+            "PENDING_DELETE" => SystemInstanceDeploymentStatus::PendingDelete,
+            "DELETED_IN_TARGET" => SystemInstanceDeploymentStatus::DeletedInTarget,
+            _ => SystemInstanceDeploymentStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SystemInstanceDeploymentStatus {
+    fn from(value: String) -> SystemInstanceDeploymentStatus {
+        match value.as_str() {
+            "NOT_DEPLOYED" => SystemInstanceDeploymentStatus::NotDeployed,
+            "BOOTSTRAP" => SystemInstanceDeploymentStatus::Bootstrap,
+            "DEPLOY_IN_PROGRESS" => SystemInstanceDeploymentStatus::DeployInProgress,
+            "DEPLOYED_IN_TARGET" => SystemInstanceDeploymentStatus::DeployedInTarget,
+            "UNDEPLOY_IN_PROGRESS" => SystemInstanceDeploymentStatus::UndeployInProgress,
+            "FAILED" => SystemInstanceDeploymentStatus::Failed,
+            "PENDING_DELETE" => SystemInstanceDeploymentStatus::PendingDelete,
+            "DELETED_IN_TARGET" => SystemInstanceDeploymentStatus::DeletedInTarget,
+            _ => SystemInstanceDeploymentStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SystemInstanceDeploymentStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SystemInstanceDeploymentStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SystemInstanceDeploymentStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SystemInstanceDeploymentStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SystemInstanceDeploymentStatus {
+    fn deserialize<D>(deserializer: D) -> Result<SystemInstanceDeploymentStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An object that contains a system instance definition and summary information.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -956,13 +2081,111 @@ pub struct SystemInstanceFilter {
     /// <p>The name of the search filter field.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
+    pub name: Option<SystemInstanceFilterName>,
     /// <p>An array of string values for the search filter field. Multiple values function as AND criteria in the search. </p>
     #[serde(rename = "value")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<Vec<String>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SystemInstanceFilterName {
+    /// `SYSTEM_TEMPLATE_ID`
+    SystemTemplateId,
+    /// `STATUS`
+    Status,
+    /// `GREENGRASS_GROUP_NAME`
+    GreengrassGroupName,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SystemInstanceFilterName {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SystemInstanceFilterName::SystemTemplateId => "SYSTEM_TEMPLATE_ID",
+            SystemInstanceFilterName::Status => "STATUS",
+            SystemInstanceFilterName::GreengrassGroupName => "GREENGRASS_GROUP_NAME",
+            SystemInstanceFilterName::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SystemInstanceFilterName {
+    fn default() -> SystemInstanceFilterName {
+        SystemInstanceFilterName::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SystemInstanceFilterName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SystemInstanceFilterName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SystemInstanceFilterName {
+    fn from(value: &'a str) -> SystemInstanceFilterName {
+        match value {
+            "SYSTEM_TEMPLATE_ID" => SystemInstanceFilterName::SystemTemplateId,
+            "STATUS" => SystemInstanceFilterName::Status,
+            "GREENGRASS_GROUP_NAME" => SystemInstanceFilterName::GreengrassGroupName,
+            _ => SystemInstanceFilterName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SystemInstanceFilterName {
+    fn from(value: String) -> SystemInstanceFilterName {
+        match value.as_str() {
+            "SYSTEM_TEMPLATE_ID" => SystemInstanceFilterName::SystemTemplateId,
+            "STATUS" => SystemInstanceFilterName::Status,
+            "GREENGRASS_GROUP_NAME" => SystemInstanceFilterName::GreengrassGroupName,
+            _ => SystemInstanceFilterName::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SystemInstanceFilterName {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SystemInstanceFilterName, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SystemInstanceFilterName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SystemInstanceFilterName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SystemInstanceFilterName {
+    fn deserialize<D>(deserializer: D) -> Result<SystemInstanceFilterName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An object that contains summary information about a system instance.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -973,8 +2196,13 @@ pub struct SystemInstanceSummary {
     pub arn: Option<String>,
     /// <p>The date when the system instance was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The ID of the Greengrass group where the system instance is deployed.</p>
     #[serde(rename = "greengrassGroupId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -994,15 +2222,20 @@ pub struct SystemInstanceSummary {
     /// <p>The status of the system instance.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<SystemInstanceDeploymentStatus>,
     /// <p>The target of the system instance.</p>
     #[serde(rename = "target")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub target: Option<String>,
+    pub target: Option<DeploymentTarget>,
     /// <p> The date and time when the system instance was last updated.</p>
     #[serde(rename = "updatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<f64>,
+    pub updated_at: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>An object that contains a system's definition document and summary information.</p>
@@ -1029,12 +2262,100 @@ pub struct SystemTemplateDescription {
 pub struct SystemTemplateFilter {
     /// <p>The name of the system search filter field.</p>
     #[serde(rename = "name")]
-    pub name: String,
+    pub name: SystemTemplateFilterName,
     /// <p>An array of string values for the search filter field. Multiple values function as AND criteria in the search.</p>
     #[serde(rename = "value")]
     pub value: Vec<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SystemTemplateFilterName {
+    /// `FLOW_TEMPLATE_ID`
+    FlowTemplateId,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SystemTemplateFilterName {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SystemTemplateFilterName::FlowTemplateId => "FLOW_TEMPLATE_ID",
+            SystemTemplateFilterName::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SystemTemplateFilterName {
+    fn default() -> SystemTemplateFilterName {
+        SystemTemplateFilterName::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SystemTemplateFilterName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SystemTemplateFilterName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SystemTemplateFilterName {
+    fn from(value: &'a str) -> SystemTemplateFilterName {
+        match value {
+            "FLOW_TEMPLATE_ID" => SystemTemplateFilterName::FlowTemplateId,
+            _ => SystemTemplateFilterName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SystemTemplateFilterName {
+    fn from(value: String) -> SystemTemplateFilterName {
+        match value.as_str() {
+            "FLOW_TEMPLATE_ID" => SystemTemplateFilterName::FlowTemplateId,
+            _ => SystemTemplateFilterName::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SystemTemplateFilterName {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SystemTemplateFilterName, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SystemTemplateFilterName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SystemTemplateFilterName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SystemTemplateFilterName {
+    fn deserialize<D>(deserializer: D) -> Result<SystemTemplateFilterName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An object that contains information about a system.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1045,8 +2366,13 @@ pub struct SystemTemplateSummary {
     pub arn: Option<String>,
     /// <p>The date when the system was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The ID of the system.</p>
     #[serde(rename = "id")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1203,6 +2529,104 @@ pub struct UploadEntityDefinitionsResponse {
     pub upload_id: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum UploadStatus {
+    /// `IN_PROGRESS`
+    InProgress,
+    /// `SUCCEEDED`
+    Succeeded,
+    /// `FAILED`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl UploadStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            UploadStatus::InProgress => "IN_PROGRESS",
+            UploadStatus::Succeeded => "SUCCEEDED",
+            UploadStatus::Failed => "FAILED",
+            UploadStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for UploadStatus {
+    fn default() -> UploadStatus {
+        UploadStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for UploadStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for UploadStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for UploadStatus {
+    fn from(value: &'a str) -> UploadStatus {
+        match value {
+            "IN_PROGRESS" => UploadStatus::InProgress,
+            "SUCCEEDED" => UploadStatus::Succeeded,
+            "FAILED" => UploadStatus::Failed,
+            _ => UploadStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for UploadStatus {
+    fn from(value: String) -> UploadStatus {
+        match value.as_str() {
+            "IN_PROGRESS" => UploadStatus::InProgress,
+            "SUCCEEDED" => UploadStatus::Succeeded,
+            "FAILED" => UploadStatus::Failed,
+            _ => UploadStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for UploadStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<UploadStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for UploadStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for UploadStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for UploadStatus {
+    fn deserialize<D>(deserializer: D) -> Result<UploadStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// Errors returned by AssociateEntityToThing
 #[derive(Debug, PartialEq)]
 pub enum AssociateEntityToThingError {
@@ -3186,11 +4610,12 @@ pub struct IotThingsGraphClient {
 impl IotThingsGraphClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> IotThingsGraphClient {
         IotThingsGraphClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "IoTThingsGraph"),
         }
     }
 
@@ -3222,6 +4647,7 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: AssociateEntityToThingRequest,
     ) -> Result<AssociateEntityToThingResponse, RusotoError<AssociateEntityToThingError>> {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("AssociateEntityToThing");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3253,6 +4679,7 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: CreateFlowTemplateRequest,
     ) -> Result<CreateFlowTemplateResponse, RusotoError<CreateFlowTemplateError>> {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("CreateFlowTemplate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3284,6 +4711,7 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: CreateSystemInstanceRequest,
     ) -> Result<CreateSystemInstanceResponse, RusotoError<CreateSystemInstanceError>> {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("CreateSystemInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3315,6 +4743,7 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: CreateSystemTemplateRequest,
     ) -> Result<CreateSystemTemplateResponse, RusotoError<CreateSystemTemplateError>> {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("CreateSystemTemplate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3346,6 +4775,7 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: DeleteFlowTemplateRequest,
     ) -> Result<DeleteFlowTemplateResponse, RusotoError<DeleteFlowTemplateError>> {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("DeleteFlowTemplate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3376,6 +4806,7 @@ impl IotThingsGraph for IotThingsGraphClient {
         &self,
     ) -> Result<DeleteNamespaceResponse, RusotoError<DeleteNamespaceError>> {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("DeleteNamespace");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3405,6 +4836,7 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: DeleteSystemInstanceRequest,
     ) -> Result<DeleteSystemInstanceResponse, RusotoError<DeleteSystemInstanceError>> {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("DeleteSystemInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3436,6 +4868,7 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: DeleteSystemTemplateRequest,
     ) -> Result<DeleteSystemTemplateResponse, RusotoError<DeleteSystemTemplateError>> {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("DeleteSystemTemplate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3467,6 +4900,7 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: DeploySystemInstanceRequest,
     ) -> Result<DeploySystemInstanceResponse, RusotoError<DeploySystemInstanceError>> {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("DeploySystemInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3498,6 +4932,7 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: DeprecateFlowTemplateRequest,
     ) -> Result<DeprecateFlowTemplateResponse, RusotoError<DeprecateFlowTemplateError>> {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("DeprecateFlowTemplate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3529,6 +4964,7 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: DeprecateSystemTemplateRequest,
     ) -> Result<DeprecateSystemTemplateResponse, RusotoError<DeprecateSystemTemplateError>> {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("DeprecateSystemTemplate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3560,6 +4996,7 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: DescribeNamespaceRequest,
     ) -> Result<DescribeNamespaceResponse, RusotoError<DescribeNamespaceError>> {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("DescribeNamespace");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3592,6 +5029,7 @@ impl IotThingsGraph for IotThingsGraphClient {
     ) -> Result<DissociateEntityFromThingResponse, RusotoError<DissociateEntityFromThingError>>
     {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("DissociateEntityFromThing");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3623,6 +5061,7 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: GetEntitiesRequest,
     ) -> Result<GetEntitiesResponse, RusotoError<GetEntitiesError>> {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("GetEntities");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "IotThingsGraphFrontEndService.GetEntities");
@@ -3650,6 +5089,7 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: GetFlowTemplateRequest,
     ) -> Result<GetFlowTemplateResponse, RusotoError<GetFlowTemplateError>> {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("GetFlowTemplate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3680,6 +5120,7 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: GetFlowTemplateRevisionsRequest,
     ) -> Result<GetFlowTemplateRevisionsResponse, RusotoError<GetFlowTemplateRevisionsError>> {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("GetFlowTemplateRevisions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3711,6 +5152,7 @@ impl IotThingsGraph for IotThingsGraphClient {
     ) -> Result<GetNamespaceDeletionStatusResponse, RusotoError<GetNamespaceDeletionStatusError>>
     {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("GetNamespaceDeletionStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3741,6 +5183,7 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: GetSystemInstanceRequest,
     ) -> Result<GetSystemInstanceResponse, RusotoError<GetSystemInstanceError>> {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("GetSystemInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3772,6 +5215,7 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: GetSystemTemplateRequest,
     ) -> Result<GetSystemTemplateResponse, RusotoError<GetSystemTemplateError>> {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("GetSystemTemplate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3804,6 +5248,7 @@ impl IotThingsGraph for IotThingsGraphClient {
     ) -> Result<GetSystemTemplateRevisionsResponse, RusotoError<GetSystemTemplateRevisionsError>>
     {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("GetSystemTemplateRevisions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3835,6 +5280,7 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: GetUploadStatusRequest,
     ) -> Result<GetUploadStatusResponse, RusotoError<GetUploadStatusError>> {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("GetUploadStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3866,6 +5312,7 @@ impl IotThingsGraph for IotThingsGraphClient {
     ) -> Result<ListFlowExecutionMessagesResponse, RusotoError<ListFlowExecutionMessagesError>>
     {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("ListFlowExecutionMessages");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3897,6 +5344,7 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3928,6 +5376,7 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: SearchEntitiesRequest,
     ) -> Result<SearchEntitiesResponse, RusotoError<SearchEntitiesError>> {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("SearchEntities");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3958,6 +5407,7 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: SearchFlowExecutionsRequest,
     ) -> Result<SearchFlowExecutionsResponse, RusotoError<SearchFlowExecutionsError>> {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("SearchFlowExecutions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3989,6 +5439,7 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: SearchFlowTemplatesRequest,
     ) -> Result<SearchFlowTemplatesResponse, RusotoError<SearchFlowTemplatesError>> {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("SearchFlowTemplates");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4020,6 +5471,7 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: SearchSystemInstancesRequest,
     ) -> Result<SearchSystemInstancesResponse, RusotoError<SearchSystemInstancesError>> {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("SearchSystemInstances");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4051,6 +5503,7 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: SearchSystemTemplatesRequest,
     ) -> Result<SearchSystemTemplatesResponse, RusotoError<SearchSystemTemplatesError>> {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("SearchSystemTemplates");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4082,6 +5535,7 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: SearchThingsRequest,
     ) -> Result<SearchThingsResponse, RusotoError<SearchThingsError>> {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("SearchThings");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "IotThingsGraphFrontEndService.SearchThings");
@@ -4109,6 +5563,7 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "IotThingsGraphFrontEndService.TagResource");
@@ -4136,6 +5591,7 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: UndeploySystemInstanceRequest,
     ) -> Result<UndeploySystemInstanceResponse, RusotoError<UndeploySystemInstanceError>> {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("UndeploySystemInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4167,6 +5623,7 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4197,6 +5654,7 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: UpdateFlowTemplateRequest,
     ) -> Result<UpdateFlowTemplateResponse, RusotoError<UpdateFlowTemplateError>> {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("UpdateFlowTemplate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4228,6 +5686,7 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: UpdateSystemTemplateRequest,
     ) -> Result<UpdateSystemTemplateResponse, RusotoError<UpdateSystemTemplateError>> {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("UpdateSystemTemplate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4259,6 +5718,7 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: UploadEntityDefinitionsRequest,
     ) -> Result<UploadEntityDefinitionsResponse, RusotoError<UploadEntityDefinitionsError>> {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("UploadEntityDefinitions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4284,3 +5744,342 @@ impl IotThingsGraph for IotThingsGraphClient {
         }
     }
 }
+impl IotThingsGraphClient {
+    /// Auto-paginating version of `get_flow_template_revisions`, streaming every page of results.
+    pub fn get_flow_template_revisions_pages(
+        &self,
+        input: GetFlowTemplateRevisionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        GetFlowTemplateRevisionsResponse,
+        GetFlowTemplateRevisionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotThingsGraphClient, input: GetFlowTemplateRevisionsRequest| async move {
+                client.get_flow_template_revisions(input).await
+            },
+            |input: &GetFlowTemplateRevisionsRequest, output: &GetFlowTemplateRevisionsResponse| {
+                Some(GetFlowTemplateRevisionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_flow_template_revisions`, streaming the `summaries` of every page of results.
+    pub fn get_flow_template_revisions_items(
+        &self,
+        input: GetFlowTemplateRevisionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<FlowTemplateSummary, GetFlowTemplateRevisionsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.get_flow_template_revisions_pages(input),
+            |output: GetFlowTemplateRevisionsResponse| output.summaries.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_system_template_revisions`, streaming every page of results.
+    pub fn get_system_template_revisions_pages(
+        &self,
+        input: GetSystemTemplateRevisionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        GetSystemTemplateRevisionsResponse,
+        GetSystemTemplateRevisionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotThingsGraphClient, input: GetSystemTemplateRevisionsRequest| async move {
+                client.get_system_template_revisions(input).await
+            },
+            |input: &GetSystemTemplateRevisionsRequest,
+             output: &GetSystemTemplateRevisionsResponse| {
+                Some(GetSystemTemplateRevisionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_system_template_revisions`, streaming the `summaries` of every page of results.
+    pub fn get_system_template_revisions_items(
+        &self,
+        input: GetSystemTemplateRevisionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<SystemTemplateSummary, GetSystemTemplateRevisionsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.get_system_template_revisions_pages(input),
+            |output: GetSystemTemplateRevisionsResponse| output.summaries.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_flow_execution_messages`, streaming every page of results.
+    pub fn list_flow_execution_messages_pages(
+        &self,
+        input: ListFlowExecutionMessagesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListFlowExecutionMessagesResponse,
+        ListFlowExecutionMessagesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotThingsGraphClient, input: ListFlowExecutionMessagesRequest| async move {
+                client.list_flow_execution_messages(input).await
+            },
+            |input: &ListFlowExecutionMessagesRequest,
+             output: &ListFlowExecutionMessagesResponse| {
+                Some(ListFlowExecutionMessagesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_flow_execution_messages`, streaming the `messages` of every page of results.
+    pub fn list_flow_execution_messages_items(
+        &self,
+        input: ListFlowExecutionMessagesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<FlowExecutionMessage, ListFlowExecutionMessagesError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_flow_execution_messages_pages(input),
+            |output: ListFlowExecutionMessagesResponse| output.messages.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_tags_for_resource`, streaming every page of results.
+    pub fn list_tags_for_resource_pages(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListTagsForResourceResponse, ListTagsForResourceError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotThingsGraphClient, input: ListTagsForResourceRequest| async move {
+                client.list_tags_for_resource(input).await
+            },
+            |input: &ListTagsForResourceRequest, output: &ListTagsForResourceResponse| {
+                Some(ListTagsForResourceRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_tags_for_resource`, streaming the `tags` of every page of results.
+    pub fn list_tags_for_resource_items(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Tag, ListTagsForResourceError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_tags_for_resource_pages(input),
+            |output: ListTagsForResourceResponse| output.tags.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `search_entities`, streaming every page of results.
+    pub fn search_entities_pages(
+        &self,
+        input: SearchEntitiesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<SearchEntitiesResponse, SearchEntitiesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotThingsGraphClient, input: SearchEntitiesRequest| async move {
+                client.search_entities(input).await
+            },
+            |input: &SearchEntitiesRequest, output: &SearchEntitiesResponse| {
+                Some(SearchEntitiesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `search_entities`, streaming the `descriptions` of every page of results.
+    pub fn search_entities_items(
+        &self,
+        input: SearchEntitiesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<EntityDescription, SearchEntitiesError> {
+        rusoto_core::pagination::paginate_items(
+            self.search_entities_pages(input),
+            |output: SearchEntitiesResponse| output.descriptions.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `search_flow_executions`, streaming every page of results.
+    pub fn search_flow_executions_pages(
+        &self,
+        input: SearchFlowExecutionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        SearchFlowExecutionsResponse,
+        SearchFlowExecutionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotThingsGraphClient, input: SearchFlowExecutionsRequest| async move {
+                client.search_flow_executions(input).await
+            },
+            |input: &SearchFlowExecutionsRequest, output: &SearchFlowExecutionsResponse| {
+                Some(SearchFlowExecutionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `search_flow_executions`, streaming the `summaries` of every page of results.
+    pub fn search_flow_executions_items(
+        &self,
+        input: SearchFlowExecutionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<FlowExecutionSummary, SearchFlowExecutionsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.search_flow_executions_pages(input),
+            |output: SearchFlowExecutionsResponse| output.summaries.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `search_flow_templates`, streaming every page of results.
+    pub fn search_flow_templates_pages(
+        &self,
+        input: SearchFlowTemplatesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<SearchFlowTemplatesResponse, SearchFlowTemplatesError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotThingsGraphClient, input: SearchFlowTemplatesRequest| async move {
+                client.search_flow_templates(input).await
+            },
+            |input: &SearchFlowTemplatesRequest, output: &SearchFlowTemplatesResponse| {
+                Some(SearchFlowTemplatesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `search_flow_templates`, streaming the `summaries` of every page of results.
+    pub fn search_flow_templates_items(
+        &self,
+        input: SearchFlowTemplatesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<FlowTemplateSummary, SearchFlowTemplatesError> {
+        rusoto_core::pagination::paginate_items(
+            self.search_flow_templates_pages(input),
+            |output: SearchFlowTemplatesResponse| output.summaries.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `search_system_instances`, streaming every page of results.
+    pub fn search_system_instances_pages(
+        &self,
+        input: SearchSystemInstancesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        SearchSystemInstancesResponse,
+        SearchSystemInstancesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotThingsGraphClient, input: SearchSystemInstancesRequest| async move {
+                client.search_system_instances(input).await
+            },
+            |input: &SearchSystemInstancesRequest, output: &SearchSystemInstancesResponse| {
+                Some(SearchSystemInstancesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `search_system_instances`, streaming the `summaries` of every page of results.
+    pub fn search_system_instances_items(
+        &self,
+        input: SearchSystemInstancesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<SystemInstanceSummary, SearchSystemInstancesError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.search_system_instances_pages(input),
+            |output: SearchSystemInstancesResponse| output.summaries.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `search_system_templates`, streaming every page of results.
+    pub fn search_system_templates_pages(
+        &self,
+        input: SearchSystemTemplatesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        SearchSystemTemplatesResponse,
+        SearchSystemTemplatesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotThingsGraphClient, input: SearchSystemTemplatesRequest| async move {
+                client.search_system_templates(input).await
+            },
+            |input: &SearchSystemTemplatesRequest, output: &SearchSystemTemplatesResponse| {
+                Some(SearchSystemTemplatesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `search_system_templates`, streaming the `summaries` of every page of results.
+    pub fn search_system_templates_items(
+        &self,
+        input: SearchSystemTemplatesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<SystemTemplateSummary, SearchSystemTemplatesError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.search_system_templates_pages(input),
+            |output: SearchSystemTemplatesResponse| output.summaries.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `search_things`, streaming every page of results.
+    pub fn search_things_pages(
+        &self,
+        input: SearchThingsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<SearchThingsResponse, SearchThingsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotThingsGraphClient, input: SearchThingsRequest| async move {
+                client.search_things(input).await
+            },
+            |input: &SearchThingsRequest, output: &SearchThingsResponse| {
+                Some(SearchThingsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `search_things`, streaming the `things` of every page of results.
+    pub fn search_things_items(
+        &self,
+        input: SearchThingsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Thing, SearchThingsError> {
+        rusoto_core::pagination::paginate_items(
+            self.search_things_pages(input),
+            |output: SearchThingsResponse| output.things.unwrap_or_default(),
+        )
+    }
+}
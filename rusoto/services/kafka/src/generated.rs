@@ -25,6 +25,98 @@ use rusoto_core::signature::SignedRequest;
 #[allow(unused_imports)]
 use serde::{Deserialize, Serialize};
 use serde_json;
+///
+/// <p>The distribution of broker nodes across Availability Zones. This is an optional parameter. If you don't specify it, Amazon MSK gives it the value DEFAULT. You can also explicitly set this parameter to the value DEFAULT. No other values are currently allowed.</p>
+/// <p>Amazon MSK distributes the broker nodes evenly across the Availability Zones that correspond to the subnets you provide when you create the cluster.</p>
+///
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BrokerAZDistribution {
+    /// `DEFAULT`
+    Default,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl BrokerAZDistribution {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BrokerAZDistribution::Default => "DEFAULT",
+            BrokerAZDistribution::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for BrokerAZDistribution {
+    fn default() -> BrokerAZDistribution {
+        BrokerAZDistribution::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for BrokerAZDistribution {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for BrokerAZDistribution {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for BrokerAZDistribution {
+    fn from(value: &'a str) -> BrokerAZDistribution {
+        match value {
+            "DEFAULT" => BrokerAZDistribution::Default,
+            _ => BrokerAZDistribution::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BrokerAZDistribution {
+    fn from(value: String) -> BrokerAZDistribution {
+        match value.as_str() {
+            "DEFAULT" => BrokerAZDistribution::Default,
+            _ => BrokerAZDistribution::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for BrokerAZDistribution {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<BrokerAZDistribution, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for BrokerAZDistribution {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for BrokerAZDistribution {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BrokerAZDistribution {
+    fn deserialize<D>(deserializer: D) -> Result<BrokerAZDistribution, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <pre><code>        &lt;p&gt;Specifies the EBS volume upgrade information. The broker identifier must be set to the keyword ALL. This means the changes apply to all the brokers in the cluster.&lt;/p&gt;
 /// </code></pre>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -48,7 +140,7 @@ pub struct BrokerNodeGroupInfo {
     /// </code></pre>
     #[serde(rename = "BrokerAZDistribution")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub broker_az_distribution: Option<String>,
+    pub broker_az_distribution: Option<BrokerAZDistribution>,
     /// <pre><code>        &lt;p&gt;The list of subnets to connect to in the client virtual private cloud (VPC). AWS creates elastic network interfaces inside these subnets. Client applications use elastic network interfaces to produce and consume data. Client subnets can&#39;t be in Availability Zone us-east-1e.&lt;/p&gt;
     /// </code></pre>
     #[serde(rename = "ClientSubnets")]
@@ -141,6 +233,107 @@ pub struct ClientAuthentication {
     pub tls: Option<Tls>,
 }
 
+///
+/// <p>Client-broker encryption in transit setting.</p>
+///
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ClientBroker {
+    /// `TLS`
+    Tls,
+    /// `TLS_PLAINTEXT`
+    TlsPlaintext,
+    /// `PLAINTEXT`
+    Plaintext,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ClientBroker {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ClientBroker::Tls => "TLS",
+            ClientBroker::TlsPlaintext => "TLS_PLAINTEXT",
+            ClientBroker::Plaintext => "PLAINTEXT",
+            ClientBroker::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ClientBroker {
+    fn default() -> ClientBroker {
+        ClientBroker::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ClientBroker {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ClientBroker {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ClientBroker {
+    fn from(value: &'a str) -> ClientBroker {
+        match value {
+            "TLS" => ClientBroker::Tls,
+            "TLS_PLAINTEXT" => ClientBroker::TlsPlaintext,
+            "PLAINTEXT" => ClientBroker::Plaintext,
+            _ => ClientBroker::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ClientBroker {
+    fn from(value: String) -> ClientBroker {
+        match value.as_str() {
+            "TLS" => ClientBroker::Tls,
+            "TLS_PLAINTEXT" => ClientBroker::TlsPlaintext,
+            "PLAINTEXT" => ClientBroker::Plaintext,
+            _ => ClientBroker::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ClientBroker {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ClientBroker, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ClientBroker {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ClientBroker {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ClientBroker {
+    fn deserialize<D>(deserializer: D) -> Result<ClientBroker, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <pre><code>        &lt;p&gt;Returns information about a cluster.&lt;/p&gt;
 /// </code></pre>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -174,8 +367,13 @@ pub struct ClusterInfo {
     /// <pre><code>        &lt;p&gt;The time when the cluster was created.&lt;/p&gt;
     /// </code></pre>
     #[serde(rename = "CreationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <pre><code>        &lt;p&gt;Information about the version of software currently deployed on the Kafka brokers in the cluster.&lt;/p&gt;
     /// </code></pre>
     #[serde(rename = "CurrentBrokerSoftwareInfo")]
@@ -195,7 +393,7 @@ pub struct ClusterInfo {
     /// </code></pre>
     #[serde(rename = "EnhancedMonitoring")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub enhanced_monitoring: Option<String>,
+    pub enhanced_monitoring: Option<EnhancedMonitoring>,
     /// <pre><code>        &lt;p&gt;The number of broker nodes in the cluster.&lt;/p&gt;
     /// </code></pre>
     #[serde(rename = "NumberOfBrokerNodes")]
@@ -210,7 +408,7 @@ pub struct ClusterInfo {
     /// </code></pre>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<ClusterState>,
     /// <pre><code>        &lt;p&gt;Tags attached to the cluster.&lt;/p&gt;
     /// </code></pre>
     #[serde(rename = "Tags")]
@@ -241,13 +439,23 @@ pub struct ClusterOperationInfo {
     /// <pre><code>        &lt;p&gt;The time that the operation was created.&lt;/p&gt;
     /// </code></pre>
     #[serde(rename = "CreationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <pre><code>        &lt;p&gt;The time at which the operation finished.&lt;/p&gt;
     /// </code></pre>
     #[serde(rename = "EndTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <pre><code>        &lt;p&gt;Describes the error if the operation fails.&lt;/p&gt;
     /// </code></pre>
     #[serde(rename = "ErrorInfo")]
@@ -280,6 +488,132 @@ pub struct ClusterOperationInfo {
     pub target_cluster_info: Option<MutableClusterInfo>,
 }
 
+///
+/// <p>The state of the Apache Kafka cluster.</p>
+///
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ClusterState {
+    /// `ACTIVE`
+    Active,
+    /// `CREATING`
+    Creating,
+    /// `DELETING`
+    Deleting,
+    /// `FAILED`
+    Failed,
+    /// `HEALING`
+    Healing,
+    /// `MAINTENANCE`
+    Maintenance,
+    /// `REBOOTING_BROKER`
+    RebootingBroker,
+    /// `UPDATING`
+    Updating,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ClusterState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ClusterState::Active => "ACTIVE",
+            ClusterState::Creating => "CREATING",
+            ClusterState::Deleting => "DELETING",
+            ClusterState::Failed => "FAILED",
+            ClusterState::Healing => "HEALING",
+            ClusterState::Maintenance => "MAINTENANCE",
+            ClusterState::RebootingBroker => "REBOOTING_BROKER",
+            ClusterState::Updating => "UPDATING",
+            ClusterState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ClusterState {
+    fn default() -> ClusterState {
+        ClusterState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ClusterState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ClusterState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ClusterState {
+    fn from(value: &'a str) -> ClusterState {
+        match value {
+            "ACTIVE" => ClusterState::Active,
+            "CREATING" => ClusterState::Creating,
+            "DELETING" => ClusterState::Deleting,
+            "FAILED" => ClusterState::Failed,
+            "HEALING" => ClusterState::Healing,
+            "MAINTENANCE" => ClusterState::Maintenance,
+            "REBOOTING_BROKER" => ClusterState::RebootingBroker,
+            "UPDATING" => ClusterState::Updating,
+            _ => ClusterState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ClusterState {
+    fn from(value: String) -> ClusterState {
+        match value.as_str() {
+            "ACTIVE" => ClusterState::Active,
+            "CREATING" => ClusterState::Creating,
+            "DELETING" => ClusterState::Deleting,
+            "FAILED" => ClusterState::Failed,
+            "HEALING" => ClusterState::Healing,
+            "MAINTENANCE" => ClusterState::Maintenance,
+            "REBOOTING_BROKER" => ClusterState::RebootingBroker,
+            "UPDATING" => ClusterState::Updating,
+            _ => ClusterState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ClusterState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ClusterState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ClusterState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ClusterState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ClusterState {
+    fn deserialize<D>(deserializer: D) -> Result<ClusterState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <pre><code>        &lt;p&gt;Represents an MSK Configuration.&lt;/p&gt;
 /// </code></pre>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -292,7 +626,12 @@ pub struct Configuration {
     /// <pre><code>        &lt;p&gt;The time when the configuration was created.&lt;/p&gt;
     /// </code></pre>
     #[serde(rename = "CreationTime")]
-    pub creation_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
+    pub creation_time: ::chrono::DateTime<::chrono::Utc>,
     /// <pre><code>        &lt;p&gt;The description of the configuration.&lt;/p&gt;
     /// </code></pre>
     #[serde(rename = "Description")]
@@ -333,7 +672,12 @@ pub struct ConfigurationRevision {
     /// <pre><code>        &lt;p&gt;The time when the configuration revision was created.&lt;/p&gt;
     /// </code></pre>
     #[serde(rename = "CreationTime")]
-    pub creation_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
+    pub creation_time: ::chrono::DateTime<::chrono::Utc>,
     /// <pre><code>        &lt;p&gt;The description of the configuration revision.&lt;/p&gt;
     /// </code></pre>
     #[serde(rename = "Description")]
@@ -375,7 +719,7 @@ pub struct CreateClusterRequest {
     /// </code></pre>
     #[serde(rename = "EnhancedMonitoring")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub enhanced_monitoring: Option<String>,
+    pub enhanced_monitoring: Option<EnhancedMonitoring>,
     /// <pre><code>        &lt;p&gt;The version of Apache Kafka.&lt;/p&gt;
     /// </code></pre>
     #[serde(rename = "KafkaVersion")]
@@ -413,7 +757,7 @@ pub struct CreateClusterResponse {
     /// </code></pre>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<ClusterState>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -455,8 +799,13 @@ pub struct CreateConfigurationResponse {
     /// <pre><code>        &lt;p&gt;The time when the configuration was created.&lt;/p&gt;
     /// </code></pre>
     #[serde(rename = "CreationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <pre><code>        &lt;p&gt;Latest revision of the configuration.&lt;/p&gt;
     /// </code></pre>
     #[serde(rename = "LatestRevision")]
@@ -495,7 +844,7 @@ pub struct DeleteClusterResponse {
     /// </code></pre>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<ClusterState>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -556,8 +905,13 @@ pub struct DescribeConfigurationResponse {
     /// <pre><code>        &lt;p&gt;The time when the configuration was created.&lt;/p&gt;
     /// </code></pre>
     #[serde(rename = "CreationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <pre><code>        &lt;p&gt;The description of the configuration.&lt;/p&gt;
     /// </code></pre>
     #[serde(rename = "Description")]
@@ -604,8 +958,13 @@ pub struct DescribeConfigurationRevisionResponse {
     /// <pre><code>        &lt;p&gt;The time when the configuration was created.&lt;/p&gt;
     /// </code></pre>
     #[serde(rename = "CreationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <pre><code>        &lt;p&gt;The description of the configuration.&lt;/p&gt;
     /// </code></pre>
     #[serde(rename = "Description")]
@@ -665,7 +1024,7 @@ pub struct EncryptionInTransit {
     /// </code></pre>
     #[serde(rename = "ClientBroker")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub client_broker: Option<String>,
+    pub client_broker: Option<ClientBroker>,
     /// <pre><code>        &lt;p&gt;When set to true, it indicates that data communication among the broker nodes of the cluster is encrypted. When set to false, the communication happens in plaintext.&lt;/p&gt;
     /// &lt;p&gt;The default value is true.&lt;/p&gt;
     /// </code></pre>
@@ -690,6 +1049,112 @@ pub struct EncryptionInfo {
     pub encryption_in_transit: Option<EncryptionInTransit>,
 }
 
+///
+/// <p>Specifies which metrics are gathered for the MSK cluster. This property has the following possible values: DEFAULT, PER_BROKER, PER_TOPIC_PER_BROKER, and PER_TOPIC_PER_PARTITION. For a list of the metrics associated with each of these levels of monitoring, see <a href="https://docs.aws.amazon.com/msk/latest/developerguide/monitoring.html">Monitoring</a>.</p>
+///
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EnhancedMonitoring {
+    /// `DEFAULT`
+    Default,
+    /// `PER_BROKER`
+    PerBroker,
+    /// `PER_TOPIC_PER_BROKER`
+    PerTopicPerBroker,
+    /// `PER_TOPIC_PER_PARTITION`
+    PerTopicPerPartition,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EnhancedMonitoring {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EnhancedMonitoring::Default => "DEFAULT",
+            EnhancedMonitoring::PerBroker => "PER_BROKER",
+            EnhancedMonitoring::PerTopicPerBroker => "PER_TOPIC_PER_BROKER",
+            EnhancedMonitoring::PerTopicPerPartition => "PER_TOPIC_PER_PARTITION",
+            EnhancedMonitoring::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EnhancedMonitoring {
+    fn default() -> EnhancedMonitoring {
+        EnhancedMonitoring::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EnhancedMonitoring {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EnhancedMonitoring {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EnhancedMonitoring {
+    fn from(value: &'a str) -> EnhancedMonitoring {
+        match value {
+            "DEFAULT" => EnhancedMonitoring::Default,
+            "PER_BROKER" => EnhancedMonitoring::PerBroker,
+            "PER_TOPIC_PER_BROKER" => EnhancedMonitoring::PerTopicPerBroker,
+            "PER_TOPIC_PER_PARTITION" => EnhancedMonitoring::PerTopicPerPartition,
+            _ => EnhancedMonitoring::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EnhancedMonitoring {
+    fn from(value: String) -> EnhancedMonitoring {
+        match value.as_str() {
+            "DEFAULT" => EnhancedMonitoring::Default,
+            "PER_BROKER" => EnhancedMonitoring::PerBroker,
+            "PER_TOPIC_PER_BROKER" => EnhancedMonitoring::PerTopicPerBroker,
+            "PER_TOPIC_PER_PARTITION" => EnhancedMonitoring::PerTopicPerPartition,
+            _ => EnhancedMonitoring::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EnhancedMonitoring {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EnhancedMonitoring, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EnhancedMonitoring {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EnhancedMonitoring {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EnhancedMonitoring {
+    fn deserialize<D>(deserializer: D) -> Result<EnhancedMonitoring, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <pre><code>        &lt;p&gt;Returns information about an error state of the cluster.&lt;/p&gt;
 /// </code></pre>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -966,7 +1431,7 @@ pub struct MutableClusterInfo {
     /// </code></pre>
     #[serde(rename = "EnhancedMonitoring")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub enhanced_monitoring: Option<String>,
+    pub enhanced_monitoring: Option<EnhancedMonitoring>,
     /// <pre><code>        &lt;p&gt;The number of broker nodes in the cluster.&lt;/p&gt;
     /// </code></pre>
     #[serde(rename = "NumberOfBrokerNodes")]
@@ -1030,7 +1495,7 @@ pub struct NodeInfo {
     /// </code></pre>
     #[serde(rename = "NodeType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub node_type: Option<String>,
+    pub node_type: Option<NodeType>,
     /// <pre><code>        &lt;p&gt;The ZookeeperNodeInfo.&lt;/p&gt;
     /// </code></pre>
     #[serde(rename = "ZookeeperNodeInfo")]
@@ -1038,6 +1503,97 @@ pub struct NodeInfo {
     pub zookeeper_node_info: Option<ZookeeperNodeInfo>,
 }
 
+///
+/// <p>The broker or Zookeeper node.</p>
+///
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum NodeType {
+    /// `BROKER`
+    Broker,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl NodeType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            NodeType::Broker => "BROKER",
+            NodeType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for NodeType {
+    fn default() -> NodeType {
+        NodeType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for NodeType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for NodeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for NodeType {
+    fn from(value: &'a str) -> NodeType {
+        match value {
+            "BROKER" => NodeType::Broker,
+            _ => NodeType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for NodeType {
+    fn from(value: String) -> NodeType {
+        match value.as_str() {
+            "BROKER" => NodeType::Broker,
+            _ => NodeType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for NodeType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<NodeType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for NodeType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for NodeType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for NodeType {
+    fn deserialize<D>(deserializer: D) -> Result<NodeType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <pre><code>        &lt;p&gt;JMX and Node monitoring for the MSK cluster.&lt;/p&gt;
 /// </code></pre>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1273,7 +1829,7 @@ pub struct UpdateMonitoringRequest {
     /// </code></pre>
     #[serde(rename = "EnhancedMonitoring")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub enhanced_monitoring: Option<String>,
+    pub enhanced_monitoring: Option<EnhancedMonitoring>,
     /// <pre><code>        &lt;p&gt;The settings for open monitoring.&lt;/p&gt;
     /// </code></pre>
     #[serde(rename = "OpenMonitoring")]
@@ -2732,11 +3288,12 @@ pub struct KafkaClient {
 impl KafkaClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> KafkaClient {
         KafkaClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Kafka"),
         }
     }
 
@@ -2771,6 +3328,7 @@ impl Kafka for KafkaClient {
         let request_uri = "/v1/clusters";
 
         let mut request = SignedRequest::new("POST", "kafka", &self.region, &request_uri);
+        request.set_operation("CreateCluster");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2802,6 +3360,7 @@ impl Kafka for KafkaClient {
         let request_uri = "/v1/configurations";
 
         let mut request = SignedRequest::new("POST", "kafka", &self.region, &request_uri);
+        request.set_operation("CreateConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2836,6 +3395,7 @@ impl Kafka for KafkaClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "kafka", &self.region, &request_uri);
+        request.set_operation("DeleteCluster");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2873,6 +3433,7 @@ impl Kafka for KafkaClient {
         );
 
         let mut request = SignedRequest::new("GET", "kafka", &self.region, &request_uri);
+        request.set_operation("DescribeCluster");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2904,6 +3465,7 @@ impl Kafka for KafkaClient {
         );
 
         let mut request = SignedRequest::new("GET", "kafka", &self.region, &request_uri);
+        request.set_operation("DescribeClusterOperation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2932,6 +3494,7 @@ impl Kafka for KafkaClient {
         let request_uri = format!("/v1/configurations/{arn}", arn = input.arn);
 
         let mut request = SignedRequest::new("GET", "kafka", &self.region, &request_uri);
+        request.set_operation("DescribeConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2967,6 +3530,7 @@ impl Kafka for KafkaClient {
         );
 
         let mut request = SignedRequest::new("GET", "kafka", &self.region, &request_uri);
+        request.set_operation("DescribeConfigurationRevision");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2998,6 +3562,7 @@ impl Kafka for KafkaClient {
         );
 
         let mut request = SignedRequest::new("GET", "kafka", &self.region, &request_uri);
+        request.set_operation("GetBootstrapBrokers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3029,6 +3594,7 @@ impl Kafka for KafkaClient {
         );
 
         let mut request = SignedRequest::new("GET", "kafka", &self.region, &request_uri);
+        request.set_operation("ListClusterOperations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3066,6 +3632,7 @@ impl Kafka for KafkaClient {
         let request_uri = "/v1/clusters";
 
         let mut request = SignedRequest::new("GET", "kafka", &self.region, &request_uri);
+        request.set_operation("ListClusters");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3107,6 +3674,7 @@ impl Kafka for KafkaClient {
         let request_uri = format!("/v1/configurations/{arn}/revisions", arn = input.arn);
 
         let mut request = SignedRequest::new("GET", "kafka", &self.region, &request_uri);
+        request.set_operation("ListConfigurationRevisions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3144,6 +3712,7 @@ impl Kafka for KafkaClient {
         let request_uri = "/v1/configurations";
 
         let mut request = SignedRequest::new("GET", "kafka", &self.region, &request_uri);
+        request.set_operation("ListConfigurations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3184,6 +3753,7 @@ impl Kafka for KafkaClient {
         );
 
         let mut request = SignedRequest::new("GET", "kafka", &self.region, &request_uri);
+        request.set_operation("ListNodes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3221,6 +3791,7 @@ impl Kafka for KafkaClient {
         let request_uri = format!("/v1/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "kafka", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3249,6 +3820,7 @@ impl Kafka for KafkaClient {
         let request_uri = format!("/v1/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "kafka", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3279,6 +3851,7 @@ impl Kafka for KafkaClient {
         let request_uri = format!("/v1/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "kafka", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3315,6 +3888,7 @@ impl Kafka for KafkaClient {
         );
 
         let mut request = SignedRequest::new("PUT", "kafka", &self.region, &request_uri);
+        request.set_operation("UpdateBrokerCount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3349,6 +3923,7 @@ impl Kafka for KafkaClient {
         );
 
         let mut request = SignedRequest::new("PUT", "kafka", &self.region, &request_uri);
+        request.set_operation("UpdateBrokerStorage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3384,6 +3959,7 @@ impl Kafka for KafkaClient {
         );
 
         let mut request = SignedRequest::new("PUT", "kafka", &self.region, &request_uri);
+        request.set_operation("UpdateClusterConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3418,6 +3994,7 @@ impl Kafka for KafkaClient {
         );
 
         let mut request = SignedRequest::new("PUT", "kafka", &self.region, &request_uri);
+        request.set_operation("UpdateMonitoring");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3440,3 +4017,169 @@ impl Kafka for KafkaClient {
         }
     }
 }
+impl KafkaClient {
+    /// Auto-paginating version of `list_cluster_operations`, streaming every page of results.
+    pub fn list_cluster_operations_pages(
+        &self,
+        input: ListClusterOperationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListClusterOperationsResponse,
+        ListClusterOperationsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: KafkaClient, input: ListClusterOperationsRequest| async move {
+                client.list_cluster_operations(input).await
+            },
+            |input: &ListClusterOperationsRequest, output: &ListClusterOperationsResponse| {
+                Some(ListClusterOperationsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_cluster_operations`, streaming the `ClusterOperationInfoList` of every page of results.
+    pub fn list_cluster_operations_items(
+        &self,
+        input: ListClusterOperationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ClusterOperationInfo, ListClusterOperationsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_cluster_operations_pages(input),
+            |output: ListClusterOperationsResponse| {
+                output.cluster_operation_info_list.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_clusters`, streaming every page of results.
+    pub fn list_clusters_pages(
+        &self,
+        input: ListClustersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListClustersResponse, ListClustersError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: KafkaClient, input: ListClustersRequest| async move {
+                client.list_clusters(input).await
+            },
+            |input: &ListClustersRequest, output: &ListClustersResponse| {
+                Some(ListClustersRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_clusters`, streaming the `ClusterInfoList` of every page of results.
+    pub fn list_clusters_items(
+        &self,
+        input: ListClustersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ClusterInfo, ListClustersError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_clusters_pages(input),
+            |output: ListClustersResponse| output.cluster_info_list.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_configuration_revisions`, streaming every page of results.
+    pub fn list_configuration_revisions_pages(
+        &self,
+        input: ListConfigurationRevisionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListConfigurationRevisionsResponse,
+        ListConfigurationRevisionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: KafkaClient, input: ListConfigurationRevisionsRequest| async move {
+                client.list_configuration_revisions(input).await
+            },
+            |input: &ListConfigurationRevisionsRequest,
+             output: &ListConfigurationRevisionsResponse| {
+                Some(ListConfigurationRevisionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_configuration_revisions`, streaming the `Revisions` of every page of results.
+    pub fn list_configuration_revisions_items(
+        &self,
+        input: ListConfigurationRevisionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ConfigurationRevision, ListConfigurationRevisionsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_configuration_revisions_pages(input),
+            |output: ListConfigurationRevisionsResponse| output.revisions.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_configurations`, streaming every page of results.
+    pub fn list_configurations_pages(
+        &self,
+        input: ListConfigurationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListConfigurationsResponse, ListConfigurationsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: KafkaClient, input: ListConfigurationsRequest| async move {
+                client.list_configurations(input).await
+            },
+            |input: &ListConfigurationsRequest, output: &ListConfigurationsResponse| {
+                Some(ListConfigurationsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_configurations`, streaming the `Configurations` of every page of results.
+    pub fn list_configurations_items(
+        &self,
+        input: ListConfigurationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Configuration, ListConfigurationsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_configurations_pages(input),
+            |output: ListConfigurationsResponse| output.configurations.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_nodes`, streaming every page of results.
+    pub fn list_nodes_pages(
+        &self,
+        input: ListNodesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListNodesResponse, ListNodesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: KafkaClient, input: ListNodesRequest| async move { client.list_nodes(input).await },
+            |input: &ListNodesRequest, output: &ListNodesResponse| {
+                Some(ListNodesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_nodes`, streaming the `NodeInfoList` of every page of results.
+    pub fn list_nodes_items(
+        &self,
+        input: ListNodesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<NodeInfo, ListNodesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_nodes_pages(input),
+            |output: ListNodesResponse| output.node_info_list.unwrap_or_default(),
+        )
+    }
+}
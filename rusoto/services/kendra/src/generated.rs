@@ -53,7 +53,7 @@ pub struct AdditionalResultAttribute {
     pub value: AdditionalResultAttributeValue,
     /// <p><p/></p>
     #[serde(rename = "ValueType")]
-    pub value_type: String,
+    pub value_type: AdditionalResultAttributeValueType,
 }
 
 /// <p>An attribute returned with a document from a search.</p>
@@ -66,6 +66,100 @@ pub struct AdditionalResultAttributeValue {
     pub text_with_highlights_value: Option<TextWithHighlights>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AdditionalResultAttributeValueType {
+    /// `TEXT_WITH_HIGHLIGHTS_VALUE`
+    TextWithHighlightsValue,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AdditionalResultAttributeValueType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AdditionalResultAttributeValueType::TextWithHighlightsValue => {
+                "TEXT_WITH_HIGHLIGHTS_VALUE"
+            }
+            AdditionalResultAttributeValueType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AdditionalResultAttributeValueType {
+    fn default() -> AdditionalResultAttributeValueType {
+        AdditionalResultAttributeValueType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AdditionalResultAttributeValueType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AdditionalResultAttributeValueType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AdditionalResultAttributeValueType {
+    fn from(value: &'a str) -> AdditionalResultAttributeValueType {
+        match value {
+            "TEXT_WITH_HIGHLIGHTS_VALUE" => {
+                AdditionalResultAttributeValueType::TextWithHighlightsValue
+            }
+            _ => AdditionalResultAttributeValueType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AdditionalResultAttributeValueType {
+    fn from(value: String) -> AdditionalResultAttributeValueType {
+        match value.as_str() {
+            "TEXT_WITH_HIGHLIGHTS_VALUE" => {
+                AdditionalResultAttributeValueType::TextWithHighlightsValue
+            }
+            _ => AdditionalResultAttributeValueType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AdditionalResultAttributeValueType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AdditionalResultAttributeValueType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AdditionalResultAttributeValueType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AdditionalResultAttributeValueType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AdditionalResultAttributeValueType {
+    fn deserialize<D>(deserializer: D) -> Result<AdditionalResultAttributeValueType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Provides filtering the query results based on document attributes.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -139,7 +233,7 @@ pub struct BatchDeleteDocumentResponseFailedDocument {
     /// <p>The error code for why the document couldn't be removed from the index.</p>
     #[serde(rename = "ErrorCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub error_code: Option<String>,
+    pub error_code: Option<ErrorCode>,
     /// <p>An explanation for why the document couldn't be removed from the index.</p>
     #[serde(rename = "ErrorMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -181,7 +275,7 @@ pub struct BatchPutDocumentResponseFailedDocument {
     /// <p>The type of error that caused the document to fail to be indexed.</p>
     #[serde(rename = "ErrorCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub error_code: Option<String>,
+    pub error_code: Option<ErrorCode>,
     /// <p>A description of the reason why the document could not be indexed.</p>
     #[serde(rename = "ErrorMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -198,7 +292,12 @@ pub struct BatchPutDocumentResponseFailedDocument {
 pub struct ClickFeedback {
     /// <p>The Unix timestamp of the data and time that the result was clicked.</p>
     #[serde(rename = "ClickTime")]
-    pub click_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub click_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The unique identifier of the search result that was clicked.</p>
     #[serde(rename = "ResultId")]
     pub result_id: String,
@@ -246,6 +345,149 @@ pub struct ConnectionConfiguration {
     pub table_name: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ContentType {
+    /// `PDF`
+    Pdf,
+    /// `HTML`
+    Html,
+    /// `MS_WORD`
+    MsWord,
+    /// `PLAIN_TEXT`
+    PlainText,
+    /// `PPT`
+    Ppt,
+    /// `RTF`
+    Rtf,
+    /// `XML`
+    Xml,
+    /// `XSLT`
+    Xslt,
+    /// `MS_EXCEL`
+    MsExcel,
+    /// `CSV`
+    Csv,
+    /// `JSON`
+    Json,
+    /// `MD`
+    Md,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ContentType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ContentType::Pdf => "PDF",
+            ContentType::Html => "HTML",
+            ContentType::MsWord => "MS_WORD",
+            ContentType::PlainText => "PLAIN_TEXT",
+            ContentType::Ppt => "PPT",
+            ContentType::Rtf => "RTF",
+            ContentType::Xml => "XML",
+            ContentType::Xslt => "XSLT",
+            ContentType::MsExcel => "MS_EXCEL",
+            ContentType::Csv => "CSV",
+            ContentType::Json => "JSON",
+            ContentType::Md => "MD",
+            ContentType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ContentType {
+    fn default() -> ContentType {
+        ContentType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ContentType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ContentType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ContentType {
+    fn from(value: &'a str) -> ContentType {
+        match value {
+            "PDF" => ContentType::Pdf,
+            "HTML" => ContentType::Html,
+            "MS_WORD" => ContentType::MsWord,
+            "PLAIN_TEXT" => ContentType::PlainText,
+            "PPT" => ContentType::Ppt,
+            "RTF" => ContentType::Rtf,
+            "XML" => ContentType::Xml,
+            "XSLT" => ContentType::Xslt,
+            "MS_EXCEL" => ContentType::MsExcel,
+            "CSV" => ContentType::Csv,
+            "JSON" => ContentType::Json,
+            "MD" => ContentType::Md,
+            _ => ContentType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ContentType {
+    fn from(value: String) -> ContentType {
+        match value.as_str() {
+            "PDF" => ContentType::Pdf,
+            "HTML" => ContentType::Html,
+            "MS_WORD" => ContentType::MsWord,
+            "PLAIN_TEXT" => ContentType::PlainText,
+            "PPT" => ContentType::Ppt,
+            "RTF" => ContentType::Rtf,
+            "XML" => ContentType::Xml,
+            "XSLT" => ContentType::Xslt,
+            "MS_EXCEL" => ContentType::MsExcel,
+            "CSV" => ContentType::Csv,
+            "JSON" => ContentType::Json,
+            "MD" => ContentType::Md,
+            _ => ContentType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ContentType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ContentType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ContentType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ContentType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ContentType {
+    fn deserialize<D>(deserializer: D) -> Result<ContentType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateDataSourceRequest {
@@ -271,7 +513,7 @@ pub struct CreateDataSourceRequest {
     pub schedule: Option<String>,
     /// <p>The type of repository that contains the data source.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: DataSourceType,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -357,14 +599,127 @@ pub struct DataSourceConfiguration {
     pub share_point_configuration: Option<SharePointConfiguration>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DataSourceStatus {
+    /// `CREATING`
+    Creating,
+    /// `DELETING`
+    Deleting,
+    /// `FAILED`
+    Failed,
+    /// `UPDATING`
+    Updating,
+    /// `ACTIVE`
+    Active,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DataSourceStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DataSourceStatus::Creating => "CREATING",
+            DataSourceStatus::Deleting => "DELETING",
+            DataSourceStatus::Failed => "FAILED",
+            DataSourceStatus::Updating => "UPDATING",
+            DataSourceStatus::Active => "ACTIVE",
+            DataSourceStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DataSourceStatus {
+    fn default() -> DataSourceStatus {
+        DataSourceStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DataSourceStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DataSourceStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DataSourceStatus {
+    fn from(value: &'a str) -> DataSourceStatus {
+        match value {
+            "CREATING" => DataSourceStatus::Creating,
+            "DELETING" => DataSourceStatus::Deleting,
+            "FAILED" => DataSourceStatus::Failed,
+            "UPDATING" => DataSourceStatus::Updating,
+            "ACTIVE" => DataSourceStatus::Active,
+            _ => DataSourceStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DataSourceStatus {
+    fn from(value: String) -> DataSourceStatus {
+        match value.as_str() {
+            "CREATING" => DataSourceStatus::Creating,
+            "DELETING" => DataSourceStatus::Deleting,
+            "FAILED" => DataSourceStatus::Failed,
+            "UPDATING" => DataSourceStatus::Updating,
+            "ACTIVE" => DataSourceStatus::Active,
+            _ => DataSourceStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DataSourceStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DataSourceStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DataSourceStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DataSourceStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DataSourceStatus {
+    fn deserialize<D>(deserializer: D) -> Result<DataSourceStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Summary information for a Amazon Kendra data source. Returned in a call to .</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DataSourceSummary {
     /// <p>The UNIX datetime that the data source was created.</p>
     #[serde(rename = "CreatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The unique identifier for the data source.</p>
     #[serde(rename = "Id")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -376,15 +731,20 @@ pub struct DataSourceSummary {
     /// <p>The status of the data source. When the status is <code>ATIVE</code> the data source is ready to use.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<DataSourceStatus>,
     /// <p>The type of the data source.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<DataSourceType>,
     /// <p>The UNIX datetime that the data source was lasted updated. </p>
     #[serde(rename = "UpdatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<f64>,
+    pub updated_at: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>Provides information about a synchronization job.</p>
@@ -397,12 +757,17 @@ pub struct DataSourceSyncJob {
     pub data_source_error_code: Option<String>,
     /// <p>The UNIX datetime that the synchronization job was completed.</p>
     #[serde(rename = "EndTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>If the <code>Status</code> field is set to <code>FAILED</code>, the <code>ErrorCode</code> field contains a the reason that the synchronization failed.</p>
     #[serde(rename = "ErrorCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub error_code: Option<String>,
+    pub error_code: Option<ErrorCode>,
     /// <p>If the <code>Status</code> field is set to <code>ERROR</code>, the <code>ErrorMessage</code> field contains a description of the error that caused the synchronization to fail.</p>
     #[serde(rename = "ErrorMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -413,14 +778,137 @@ pub struct DataSourceSyncJob {
     pub execution_id: Option<String>,
     /// <p>The UNIX datetime that the synchronization job was started.</p>
     #[serde(rename = "StartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The execution status of the synchronization job. When the <code>Status</code> field is set to <code>SUCCEEDED</code>, the synchronization job is done. If the status code is set to <code>FAILED</code>, the <code>ErrorCode</code> and <code>ErrorMessage</code> fields give you the reason for the failure.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<DataSourceSyncJobStatus>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DataSourceSyncJobStatus {
+    /// `FAILED`
+    Failed,
+    /// `SUCCEEDED`
+    Succeeded,
+    /// `SYNCING`
+    Syncing,
+    /// `INCOMPLETE`
+    Incomplete,
+    /// `STOPPING`
+    Stopping,
+    /// `ABORTED`
+    Aborted,
+    /// `SYNCING_INDEXING`
+    SyncingIndexing,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DataSourceSyncJobStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DataSourceSyncJobStatus::Failed => "FAILED",
+            DataSourceSyncJobStatus::Succeeded => "SUCCEEDED",
+            DataSourceSyncJobStatus::Syncing => "SYNCING",
+            DataSourceSyncJobStatus::Incomplete => "INCOMPLETE",
+            DataSourceSyncJobStatus::Stopping => "STOPPING",
+            DataSourceSyncJobStatus::Aborted => "ABORTED",
+            DataSourceSyncJobStatus::SyncingIndexing => "SYNCING_INDEXING",
+            DataSourceSyncJobStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DataSourceSyncJobStatus {
+    fn default() -> DataSourceSyncJobStatus {
+        DataSourceSyncJobStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DataSourceSyncJobStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DataSourceSyncJobStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DataSourceSyncJobStatus {
+    fn from(value: &'a str) -> DataSourceSyncJobStatus {
+        match value {
+            "FAILED" => DataSourceSyncJobStatus::Failed,
+            "SUCCEEDED" => DataSourceSyncJobStatus::Succeeded,
+            "SYNCING" => DataSourceSyncJobStatus::Syncing,
+            "INCOMPLETE" => DataSourceSyncJobStatus::Incomplete,
+            "STOPPING" => DataSourceSyncJobStatus::Stopping,
+            "ABORTED" => DataSourceSyncJobStatus::Aborted,
+            "SYNCING_INDEXING" => DataSourceSyncJobStatus::SyncingIndexing,
+            _ => DataSourceSyncJobStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DataSourceSyncJobStatus {
+    fn from(value: String) -> DataSourceSyncJobStatus {
+        match value.as_str() {
+            "FAILED" => DataSourceSyncJobStatus::Failed,
+            "SUCCEEDED" => DataSourceSyncJobStatus::Succeeded,
+            "SYNCING" => DataSourceSyncJobStatus::Syncing,
+            "INCOMPLETE" => DataSourceSyncJobStatus::Incomplete,
+            "STOPPING" => DataSourceSyncJobStatus::Stopping,
+            "ABORTED" => DataSourceSyncJobStatus::Aborted,
+            "SYNCING_INDEXING" => DataSourceSyncJobStatus::SyncingIndexing,
+            _ => DataSourceSyncJobStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DataSourceSyncJobStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DataSourceSyncJobStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DataSourceSyncJobStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
 }
 
+impl ::serde::Serialize for DataSourceSyncJobStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DataSourceSyncJobStatus {
+    fn deserialize<D>(deserializer: D) -> Result<DataSourceSyncJobStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Maps a column or attribute in the data source to an index field. You must first create the fields in the index using the <a>UpdateIndex</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DataSourceToIndexFieldMapping {
@@ -436,6 +924,184 @@ pub struct DataSourceToIndexFieldMapping {
     pub index_field_name: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DataSourceType {
+    /// `S3`
+    S3,
+    /// `SHAREPOINT`
+    Sharepoint,
+    /// `DATABASE`
+    Database,
+    /// `SALESFORCE`
+    Salesforce,
+    /// `ONEDRIVE`
+    Onedrive,
+    /// `SERVICENOW`
+    Servicenow,
+    /// `CUSTOM`
+    Custom,
+    /// `CONFLUENCE`
+    Confluence,
+    /// `GOOGLEDRIVE`
+    Googledrive,
+    /// `WEBCRAWLER`
+    Webcrawler,
+    /// `WORKDOCS`
+    Workdocs,
+    /// `FSX`
+    Fsx,
+    /// `SLACK`
+    Slack,
+    /// `BOX`
+    Box,
+    /// `QUIP`
+    Quip,
+    /// `JIRA`
+    Jira,
+    /// `GITHUB`
+    Github,
+    /// `ALFRESCO`
+    Alfresco,
+    /// `TEMPLATE`
+    Template,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DataSourceType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DataSourceType::S3 => "S3",
+            DataSourceType::Sharepoint => "SHAREPOINT",
+            DataSourceType::Database => "DATABASE",
+            DataSourceType::Salesforce => "SALESFORCE",
+            DataSourceType::Onedrive => "ONEDRIVE",
+            DataSourceType::Servicenow => "SERVICENOW",
+            DataSourceType::Custom => "CUSTOM",
+            DataSourceType::Confluence => "CONFLUENCE",
+            DataSourceType::Googledrive => "GOOGLEDRIVE",
+            DataSourceType::Webcrawler => "WEBCRAWLER",
+            DataSourceType::Workdocs => "WORKDOCS",
+            DataSourceType::Fsx => "FSX",
+            DataSourceType::Slack => "SLACK",
+            DataSourceType::Box => "BOX",
+            DataSourceType::Quip => "QUIP",
+            DataSourceType::Jira => "JIRA",
+            DataSourceType::Github => "GITHUB",
+            DataSourceType::Alfresco => "ALFRESCO",
+            DataSourceType::Template => "TEMPLATE",
+            DataSourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DataSourceType {
+    fn default() -> DataSourceType {
+        DataSourceType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DataSourceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DataSourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DataSourceType {
+    fn from(value: &'a str) -> DataSourceType {
+        match value {
+            "S3" => DataSourceType::S3,
+            "SHAREPOINT" => DataSourceType::Sharepoint,
+            "DATABASE" => DataSourceType::Database,
+            "SALESFORCE" => DataSourceType::Salesforce,
+            "ONEDRIVE" => DataSourceType::Onedrive,
+            "SERVICENOW" => DataSourceType::Servicenow,
+            "CUSTOM" => DataSourceType::Custom,
+            "CONFLUENCE" => DataSourceType::Confluence,
+            "GOOGLEDRIVE" => DataSourceType::Googledrive,
+            "WEBCRAWLER" => DataSourceType::Webcrawler,
+            "WORKDOCS" => DataSourceType::Workdocs,
+            "FSX" => DataSourceType::Fsx,
+            "SLACK" => DataSourceType::Slack,
+            "BOX" => DataSourceType::Box,
+            "QUIP" => DataSourceType::Quip,
+            "JIRA" => DataSourceType::Jira,
+            "GITHUB" => DataSourceType::Github,
+            "ALFRESCO" => DataSourceType::Alfresco,
+            "TEMPLATE" => DataSourceType::Template,
+            _ => DataSourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DataSourceType {
+    fn from(value: String) -> DataSourceType {
+        match value.as_str() {
+            "S3" => DataSourceType::S3,
+            "SHAREPOINT" => DataSourceType::Sharepoint,
+            "DATABASE" => DataSourceType::Database,
+            "SALESFORCE" => DataSourceType::Salesforce,
+            "ONEDRIVE" => DataSourceType::Onedrive,
+            "SERVICENOW" => DataSourceType::Servicenow,
+            "CUSTOM" => DataSourceType::Custom,
+            "CONFLUENCE" => DataSourceType::Confluence,
+            "GOOGLEDRIVE" => DataSourceType::Googledrive,
+            "WEBCRAWLER" => DataSourceType::Webcrawler,
+            "WORKDOCS" => DataSourceType::Workdocs,
+            "FSX" => DataSourceType::Fsx,
+            "SLACK" => DataSourceType::Slack,
+            "BOX" => DataSourceType::Box,
+            "QUIP" => DataSourceType::Quip,
+            "JIRA" => DataSourceType::Jira,
+            "GITHUB" => DataSourceType::Github,
+            "ALFRESCO" => DataSourceType::Alfresco,
+            "TEMPLATE" => DataSourceType::Template,
+            _ => DataSourceType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DataSourceType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DataSourceType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DataSourceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DataSourceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DataSourceType {
+    fn deserialize<D>(deserializer: D) -> Result<DataSourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Provides information for connecting to an Amazon VPC.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DataSourceVpcConfiguration {
@@ -462,12 +1128,115 @@ pub struct DatabaseConfiguration {
     pub connection_configuration: ConnectionConfiguration,
     /// <p>The type of database engine that runs the database.</p>
     #[serde(rename = "DatabaseEngineType")]
-    pub database_engine_type: String,
+    pub database_engine_type: DatabaseEngineType,
     #[serde(rename = "VpcConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub vpc_configuration: Option<DataSourceVpcConfiguration>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DatabaseEngineType {
+    /// `RDS_AURORA_MYSQL`
+    RdsAuroraMysql,
+    /// `RDS_AURORA_POSTGRESQL`
+    RdsAuroraPostgresql,
+    /// `RDS_MYSQL`
+    RdsMysql,
+    /// `RDS_POSTGRESQL`
+    RdsPostgresql,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DatabaseEngineType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DatabaseEngineType::RdsAuroraMysql => "RDS_AURORA_MYSQL",
+            DatabaseEngineType::RdsAuroraPostgresql => "RDS_AURORA_POSTGRESQL",
+            DatabaseEngineType::RdsMysql => "RDS_MYSQL",
+            DatabaseEngineType::RdsPostgresql => "RDS_POSTGRESQL",
+            DatabaseEngineType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DatabaseEngineType {
+    fn default() -> DatabaseEngineType {
+        DatabaseEngineType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DatabaseEngineType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DatabaseEngineType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DatabaseEngineType {
+    fn from(value: &'a str) -> DatabaseEngineType {
+        match value {
+            "RDS_AURORA_MYSQL" => DatabaseEngineType::RdsAuroraMysql,
+            "RDS_AURORA_POSTGRESQL" => DatabaseEngineType::RdsAuroraPostgresql,
+            "RDS_MYSQL" => DatabaseEngineType::RdsMysql,
+            "RDS_POSTGRESQL" => DatabaseEngineType::RdsPostgresql,
+            _ => DatabaseEngineType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DatabaseEngineType {
+    fn from(value: String) -> DatabaseEngineType {
+        match value.as_str() {
+            "RDS_AURORA_MYSQL" => DatabaseEngineType::RdsAuroraMysql,
+            "RDS_AURORA_POSTGRESQL" => DatabaseEngineType::RdsAuroraPostgresql,
+            "RDS_MYSQL" => DatabaseEngineType::RdsMysql,
+            "RDS_POSTGRESQL" => DatabaseEngineType::RdsPostgresql,
+            _ => DatabaseEngineType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DatabaseEngineType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DatabaseEngineType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DatabaseEngineType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DatabaseEngineType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DatabaseEngineType {
+    fn deserialize<D>(deserializer: D) -> Result<DatabaseEngineType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct DeleteFaqRequest {
@@ -507,8 +1276,13 @@ pub struct DescribeDataSourceResponse {
     pub configuration: Option<DataSourceConfiguration>,
     /// <p>The Unix timestamp of when the data source was created.</p>
     #[serde(rename = "CreatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The description of the data source.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -540,15 +1314,20 @@ pub struct DescribeDataSourceResponse {
     /// <p>The current status of the data source. When the status is <code>ACTIVE</code> the data source is ready to use. When the status is <code>FAILED</code>, the <code>ErrorMessage</code> field contains the reason that the data source failed.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<DataSourceStatus>,
     /// <p>The type of the data source.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<DataSourceType>,
     /// <p>The Unix timestamp of when the data source was last updated.</p>
     #[serde(rename = "UpdatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<f64>,
+    pub updated_at: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -567,8 +1346,13 @@ pub struct DescribeFaqRequest {
 pub struct DescribeFaqResponse {
     /// <p>The date and time that the FAQ was created.</p>
     #[serde(rename = "CreatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The description of the FAQ that you provided when it was created.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -599,11 +1383,16 @@ pub struct DescribeFaqResponse {
     /// <p>The status of the FAQ. It is ready to use when the status is <code>ACTIVE</code>.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<FaqStatus>,
     /// <p>The date and time that the FAQ was last updated.</p>
     #[serde(rename = "UpdatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<f64>,
+    pub updated_at: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -619,8 +1408,13 @@ pub struct DescribeIndexRequest {
 pub struct DescribeIndexResponse {
     /// <p>The Unix datetime that the index was created.</p>
     #[serde(rename = "CreatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The description of the index.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -656,11 +1450,16 @@ pub struct DescribeIndexResponse {
     /// <p>The current status of the index. When the value is <code>ACTIVE</code>, the index is ready for use. If the <code>Status</code> field value is <code>FAILED</code>, the <code>ErrorMessage</code> field contains a message that explains why.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<IndexStatus>,
     /// <p>The Unix datetime that the index was last updated.</p>
     #[serde(rename = "UpdatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<f64>,
+    pub updated_at: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>A document in an index.</p>
@@ -687,7 +1486,7 @@ pub struct Document {
     /// <p>The file type of the document in the <code>Blob</code> field.</p>
     #[serde(rename = "ContentType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub content_type: Option<String>,
+    pub content_type: Option<ContentType>,
     /// <p>A unique identifier of the document in the index.</p>
     #[serde(rename = "Id")]
     pub id: String,
@@ -716,8 +1515,13 @@ pub struct DocumentAttribute {
 pub struct DocumentAttributeValue {
     /// <p>A date value expressed as seconds from the Unix epoch.</p>
     #[serde(rename = "DateValue")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub date_value: Option<f64>,
+    pub date_value: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A long integer value.</p>
     #[serde(rename = "LongValue")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -746,6 +1550,109 @@ pub struct DocumentAttributeValueCountPair {
     pub document_attribute_value: Option<DocumentAttributeValue>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DocumentAttributeValueType {
+    /// `STRING_VALUE`
+    StringValue,
+    /// `STRING_LIST_VALUE`
+    StringListValue,
+    /// `LONG_VALUE`
+    LongValue,
+    /// `DATE_VALUE`
+    DateValue,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DocumentAttributeValueType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DocumentAttributeValueType::StringValue => "STRING_VALUE",
+            DocumentAttributeValueType::StringListValue => "STRING_LIST_VALUE",
+            DocumentAttributeValueType::LongValue => "LONG_VALUE",
+            DocumentAttributeValueType::DateValue => "DATE_VALUE",
+            DocumentAttributeValueType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DocumentAttributeValueType {
+    fn default() -> DocumentAttributeValueType {
+        DocumentAttributeValueType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DocumentAttributeValueType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DocumentAttributeValueType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DocumentAttributeValueType {
+    fn from(value: &'a str) -> DocumentAttributeValueType {
+        match value {
+            "STRING_VALUE" => DocumentAttributeValueType::StringValue,
+            "STRING_LIST_VALUE" => DocumentAttributeValueType::StringListValue,
+            "LONG_VALUE" => DocumentAttributeValueType::LongValue,
+            "DATE_VALUE" => DocumentAttributeValueType::DateValue,
+            _ => DocumentAttributeValueType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DocumentAttributeValueType {
+    fn from(value: String) -> DocumentAttributeValueType {
+        match value.as_str() {
+            "STRING_VALUE" => DocumentAttributeValueType::StringValue,
+            "STRING_LIST_VALUE" => DocumentAttributeValueType::StringListValue,
+            "LONG_VALUE" => DocumentAttributeValueType::LongValue,
+            "DATE_VALUE" => DocumentAttributeValueType::DateValue,
+            _ => DocumentAttributeValueType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DocumentAttributeValueType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DocumentAttributeValueType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DocumentAttributeValueType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DocumentAttributeValueType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DocumentAttributeValueType {
+    fn deserialize<D>(deserializer: D) -> Result<DocumentAttributeValueType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Specifies the properties of a custom index field.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DocumentMetadataConfiguration {
@@ -762,7 +1669,7 @@ pub struct DocumentMetadataConfiguration {
     pub search: Option<Search>,
     /// <p>The data type of the index field. </p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: DocumentAttributeValueType,
 }
 
 /// <p>Document metadata files that contain information such as the document access control information, source URI, document author, and custom attributes. Each metadata file contains metadata about a single document.</p>
@@ -774,6 +1681,99 @@ pub struct DocumentsMetadataConfiguration {
     pub s3_prefix: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorCode {
+    /// `InternalError`
+    InternalError,
+    /// `InvalidRequest`
+    InvalidRequest,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ErrorCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ErrorCode::InternalError => "InternalError",
+            ErrorCode::InvalidRequest => "InvalidRequest",
+            ErrorCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ErrorCode {
+    fn default() -> ErrorCode {
+        ErrorCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ErrorCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ErrorCode {
+    fn from(value: &'a str) -> ErrorCode {
+        match value {
+            "InternalError" => ErrorCode::InternalError,
+            "InvalidRequest" => ErrorCode::InvalidRequest,
+            _ => ErrorCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ErrorCode {
+    fn from(value: String) -> ErrorCode {
+        match value.as_str() {
+            "InternalError" => ErrorCode::InternalError,
+            "InvalidRequest" => ErrorCode::InvalidRequest,
+            _ => ErrorCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ErrorCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ErrorCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ErrorCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ErrorCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ErrorCode {
+    fn deserialize<D>(deserializer: D) -> Result<ErrorCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information a document attribute</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -807,14 +1807,127 @@ pub struct FaqStatistics {
     pub indexed_question_answers_count: i64,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FaqStatus {
+    /// `CREATING`
+    Creating,
+    /// `UPDATING`
+    Updating,
+    /// `ACTIVE`
+    Active,
+    /// `DELETING`
+    Deleting,
+    /// `FAILED`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl FaqStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FaqStatus::Creating => "CREATING",
+            FaqStatus::Updating => "UPDATING",
+            FaqStatus::Active => "ACTIVE",
+            FaqStatus::Deleting => "DELETING",
+            FaqStatus::Failed => "FAILED",
+            FaqStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for FaqStatus {
+    fn default() -> FaqStatus {
+        FaqStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for FaqStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FaqStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FaqStatus {
+    fn from(value: &'a str) -> FaqStatus {
+        match value {
+            "CREATING" => FaqStatus::Creating,
+            "UPDATING" => FaqStatus::Updating,
+            "ACTIVE" => FaqStatus::Active,
+            "DELETING" => FaqStatus::Deleting,
+            "FAILED" => FaqStatus::Failed,
+            _ => FaqStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FaqStatus {
+    fn from(value: String) -> FaqStatus {
+        match value.as_str() {
+            "CREATING" => FaqStatus::Creating,
+            "UPDATING" => FaqStatus::Updating,
+            "ACTIVE" => FaqStatus::Active,
+            "DELETING" => FaqStatus::Deleting,
+            "FAILED" => FaqStatus::Failed,
+            _ => FaqStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for FaqStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<FaqStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FaqStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FaqStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FaqStatus {
+    fn deserialize<D>(deserializer: D) -> Result<FaqStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Provides information about a frequently asked questions and answer contained in an index.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct FaqSummary {
     /// <p>The UNIX datetime that the FAQ was added to the index.</p>
     #[serde(rename = "CreatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The unique identifier of the FAQ.</p>
     #[serde(rename = "Id")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -826,11 +1939,16 @@ pub struct FaqSummary {
     /// <p>The current status of the FAQ. When the status is <code>ACTIVE</code> the FAQ is ready for use.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<FaqStatus>,
     /// <p>The UNIX datetime that the FAQ was last updated.</p>
     #[serde(rename = "UpdatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<f64>,
+    pub updated_at: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>Provides information that you can use to highlight a search result so that your users can quickly identify terms in the response.</p>
@@ -855,7 +1973,12 @@ pub struct Highlight {
 pub struct IndexConfigurationSummary {
     /// <p>The Unix timestamp when the index was created.</p>
     #[serde(rename = "CreatedAt")]
-    pub created_at: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub created_at: ::chrono::DateTime<::chrono::Utc>,
     /// <p>A unique identifier for the index. Use this to identify the index when you are using operations such as <code>Query</code>, <code>DescribeIndex</code>, <code>UpdateIndex</code>, and <code>DeleteIndex</code>.</p>
     #[serde(rename = "Id")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -866,10 +1989,15 @@ pub struct IndexConfigurationSummary {
     pub name: Option<String>,
     /// <p>The current status of the index. When the status is <code>ACTIVE</code>, the index is ready to search.</p>
     #[serde(rename = "Status")]
-    pub status: String,
+    pub status: IndexStatus,
     /// <p>The Unix timestamp when the index was last updated by the <code>UpdateIndex</code> operation.</p>
     #[serde(rename = "UpdatedAt")]
-    pub updated_at: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub updated_at: ::chrono::DateTime<::chrono::Utc>,
 }
 
 /// <p>Provides information about the number of documents and the number of questions and answers in an index.</p>
@@ -884,6 +2012,119 @@ pub struct IndexStatistics {
     pub text_document_statistics: TextDocumentStatistics,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum IndexStatus {
+    /// `CREATING`
+    Creating,
+    /// `ACTIVE`
+    Active,
+    /// `DELETING`
+    Deleting,
+    /// `FAILED`
+    Failed,
+    /// `UPDATING`
+    Updating,
+    /// `SYSTEM_UPDATING`
+    SystemUpdating,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl IndexStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            IndexStatus::Creating => "CREATING",
+            IndexStatus::Active => "ACTIVE",
+            IndexStatus::Deleting => "DELETING",
+            IndexStatus::Failed => "FAILED",
+            IndexStatus::Updating => "UPDATING",
+            IndexStatus::SystemUpdating => "SYSTEM_UPDATING",
+            IndexStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for IndexStatus {
+    fn default() -> IndexStatus {
+        IndexStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for IndexStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for IndexStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for IndexStatus {
+    fn from(value: &'a str) -> IndexStatus {
+        match value {
+            "CREATING" => IndexStatus::Creating,
+            "ACTIVE" => IndexStatus::Active,
+            "DELETING" => IndexStatus::Deleting,
+            "FAILED" => IndexStatus::Failed,
+            "UPDATING" => IndexStatus::Updating,
+            "SYSTEM_UPDATING" => IndexStatus::SystemUpdating,
+            _ => IndexStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for IndexStatus {
+    fn from(value: String) -> IndexStatus {
+        match value.as_str() {
+            "CREATING" => IndexStatus::Creating,
+            "ACTIVE" => IndexStatus::Active,
+            "DELETING" => IndexStatus::Deleting,
+            "FAILED" => IndexStatus::Failed,
+            "UPDATING" => IndexStatus::Updating,
+            "SYSTEM_UPDATING" => IndexStatus::SystemUpdating,
+            _ => IndexStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for IndexStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<IndexStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for IndexStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for IndexStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for IndexStatus {
+    fn deserialize<D>(deserializer: D) -> Result<IndexStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ListDataSourceSyncJobsRequest {
@@ -908,7 +2149,7 @@ pub struct ListDataSourceSyncJobsRequest {
     /// <p>When specified, only returns synchronization jobs with the <code>Status</code> field equal to the specified status.</p>
     #[serde(rename = "StatusFilter")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status_filter: Option<String>,
+    pub status_filter: Option<DataSourceSyncJobStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1008,21 +2249,207 @@ pub struct ListIndicesResponse {
     pub next_token: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Order {
+    /// `ASCENDING`
+    Ascending,
+    /// `DESCENDING`
+    Descending,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Order {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Order::Ascending => "ASCENDING",
+            Order::Descending => "DESCENDING",
+            Order::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Order {
+    fn default() -> Order {
+        Order::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Order {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Order {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Order {
+    fn from(value: &'a str) -> Order {
+        match value {
+            "ASCENDING" => Order::Ascending,
+            "DESCENDING" => Order::Descending,
+            _ => Order::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Order {
+    fn from(value: String) -> Order {
+        match value.as_str() {
+            "ASCENDING" => Order::Ascending,
+            "DESCENDING" => Order::Descending,
+            _ => Order::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Order {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Order, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Order {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Order {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Order {
+    fn deserialize<D>(deserializer: D) -> Result<Order, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Provides user and group information for document access filtering.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct Principal {
     /// <p>Whether to allow or deny access to the principal.</p>
     #[serde(rename = "Access")]
-    pub access: String,
+    pub access: ReadAccessType,
     /// <p>The name of the user or group.</p>
     #[serde(rename = "Name")]
     pub name: String,
     /// <p>The type of principal.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: PrincipalType,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PrincipalType {
+    /// `USER`
+    User,
+    /// `GROUP`
+    Group,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PrincipalType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PrincipalType::User => "USER",
+            PrincipalType::Group => "GROUP",
+            PrincipalType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PrincipalType {
+    fn default() -> PrincipalType {
+        PrincipalType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PrincipalType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PrincipalType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PrincipalType {
+    fn from(value: &'a str) -> PrincipalType {
+        match value {
+            "USER" => PrincipalType::User,
+            "GROUP" => PrincipalType::Group,
+            _ => PrincipalType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PrincipalType {
+    fn from(value: String) -> PrincipalType {
+        match value.as_str() {
+            "USER" => PrincipalType::User,
+            "GROUP" => PrincipalType::Group,
+            _ => PrincipalType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PrincipalType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PrincipalType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PrincipalType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PrincipalType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for PrincipalType {
+    fn deserialize<D>(deserializer: D) -> Result<PrincipalType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct QueryRequest {
@@ -1048,7 +2475,7 @@ pub struct QueryRequest {
     /// <p>Sets the type of query. Only results for the specified query type are returned.</p>
     #[serde(rename = "QueryResultTypeFilter")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub query_result_type_filter: Option<String>,
+    pub query_result_type_filter: Option<QueryResultType>,
     /// <p>The text to search for.</p>
     #[serde(rename = "QueryText")]
     pub query_text: String,
@@ -1114,9 +2541,200 @@ pub struct QueryResultItem {
     /// <p>The type of document. </p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<QueryResultType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum QueryResultType {
+    /// `DOCUMENT`
+    Document,
+    /// `QUESTION_ANSWER`
+    QuestionAnswer,
+    /// `ANSWER`
+    Answer,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl QueryResultType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            QueryResultType::Document => "DOCUMENT",
+            QueryResultType::QuestionAnswer => "QUESTION_ANSWER",
+            QueryResultType::Answer => "ANSWER",
+            QueryResultType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for QueryResultType {
+    fn default() -> QueryResultType {
+        QueryResultType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for QueryResultType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for QueryResultType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for QueryResultType {
+    fn from(value: &'a str) -> QueryResultType {
+        match value {
+            "DOCUMENT" => QueryResultType::Document,
+            "QUESTION_ANSWER" => QueryResultType::QuestionAnswer,
+            "ANSWER" => QueryResultType::Answer,
+            _ => QueryResultType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for QueryResultType {
+    fn from(value: String) -> QueryResultType {
+        match value.as_str() {
+            "DOCUMENT" => QueryResultType::Document,
+            "QUESTION_ANSWER" => QueryResultType::QuestionAnswer,
+            "ANSWER" => QueryResultType::Answer,
+            _ => QueryResultType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for QueryResultType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<QueryResultType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for QueryResultType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for QueryResultType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for QueryResultType {
+    fn deserialize<D>(deserializer: D) -> Result<QueryResultType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ReadAccessType {
+    /// `ALLOW`
+    Allow,
+    /// `DENY`
+    Deny,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ReadAccessType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReadAccessType::Allow => "ALLOW",
+            ReadAccessType::Deny => "DENY",
+            ReadAccessType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ReadAccessType {
+    fn default() -> ReadAccessType {
+        ReadAccessType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ReadAccessType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ReadAccessType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ReadAccessType {
+    fn from(value: &'a str) -> ReadAccessType {
+        match value {
+            "ALLOW" => ReadAccessType::Allow,
+            "DENY" => ReadAccessType::Deny,
+            _ => ReadAccessType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReadAccessType {
+    fn from(value: String) -> ReadAccessType {
+        match value.as_str() {
+            "ALLOW" => ReadAccessType::Allow,
+            "DENY" => ReadAccessType::Deny,
+            _ => ReadAccessType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ReadAccessType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ReadAccessType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ReadAccessType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
 }
 
+impl ::serde::Serialize for ReadAccessType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ReadAccessType {
+    fn deserialize<D>(deserializer: D) -> Result<ReadAccessType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Provides information for manually tuning the relevance of a field in a search. When a query includes terms that match the field, the results are given a boost in the response based on these tuning parameters.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Relevance {
@@ -1135,7 +2753,7 @@ pub struct Relevance {
     /// <p>Determines how values should be interpreted.</p> <p>When the <code>RankOrder</code> field is <code>ASCENDING</code>, higher numbers are better. For example, a document with a rating score of 10 is higher ranking than a document with a rating score of 1.</p> <p>When the <code>RankOrder</code> field is <code>DESCENDING</code>, lower numbers are better. For example, in a task tracking application, a priority 1 task is more important than a priority 5 task.</p> <p>Only applies to <code>LONG</code> and <code>DOUBLE</code> fields.</p>
     #[serde(rename = "RankOrder")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub rank_order: Option<String>,
+    pub rank_order: Option<Order>,
     /// <p>A list of values that should be given a different boost when they appear in the result list. For example, if you are boosting a field called "department," query terms that match the department field are boosted in the result. However, you can add entries from the department field to boost documents with those values higher. </p> <p>For example, you can add entries to the map with names of departments. If you add "HR",5 and "Legal",3 those departments are given special attention when they appear in the metadata of a document. When those terms appear they are given the specified importance instead of the regular importance for the boost.</p>
     #[serde(rename = "ValueImportanceMap")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1148,12 +2766,105 @@ pub struct Relevance {
 pub struct RelevanceFeedback {
     /// <p>Whether to document was relevant or not relevant to the search.</p>
     #[serde(rename = "RelevanceValue")]
-    pub relevance_value: String,
+    pub relevance_value: RelevanceType,
     /// <p>The unique identifier of the search result that the user provided relevance feedback for.</p>
     #[serde(rename = "ResultId")]
     pub result_id: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RelevanceType {
+    /// `RELEVANT`
+    Relevant,
+    /// `NOT_RELEVANT`
+    NotRelevant,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RelevanceType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RelevanceType::Relevant => "RELEVANT",
+            RelevanceType::NotRelevant => "NOT_RELEVANT",
+            RelevanceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RelevanceType {
+    fn default() -> RelevanceType {
+        RelevanceType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RelevanceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RelevanceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RelevanceType {
+    fn from(value: &'a str) -> RelevanceType {
+        match value {
+            "RELEVANT" => RelevanceType::Relevant,
+            "NOT_RELEVANT" => RelevanceType::NotRelevant,
+            _ => RelevanceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RelevanceType {
+    fn from(value: String) -> RelevanceType {
+        match value.as_str() {
+            "RELEVANT" => RelevanceType::Relevant,
+            "NOT_RELEVANT" => RelevanceType::NotRelevant,
+            _ => RelevanceType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RelevanceType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RelevanceType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RelevanceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RelevanceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RelevanceType {
+    fn deserialize<D>(deserializer: D) -> Result<RelevanceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Provides configuration information for a data source to index documents in an Amazon S3 bucket.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct S3DataSourceConfiguration {
@@ -1234,7 +2945,7 @@ pub struct SharePointConfiguration {
     pub secret_arn: String,
     /// <p>The version of Microsoft SharePoint that you are using as a data source.</p>
     #[serde(rename = "SharePointVersion")]
-    pub share_point_version: String,
+    pub share_point_version: SharePointVersion,
     /// <p>The URLs of the Microsoft SharePoint site that contains the documents that should be indexed.</p>
     #[serde(rename = "Urls")]
     pub urls: Vec<String>,
@@ -1243,6 +2954,109 @@ pub struct SharePointConfiguration {
     pub vpc_configuration: Option<DataSourceVpcConfiguration>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SharePointVersion {
+    /// `SHAREPOINT_2013`
+    Sharepoint2013,
+    /// `SHAREPOINT_2016`
+    Sharepoint2016,
+    /// `SHAREPOINT_ONLINE`
+    SharepointOnline,
+    /// `SHAREPOINT_2019`
+    Sharepoint2019,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SharePointVersion {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SharePointVersion::Sharepoint2013 => "SHAREPOINT_2013",
+            SharePointVersion::Sharepoint2016 => "SHAREPOINT_2016",
+            SharePointVersion::SharepointOnline => "SHAREPOINT_ONLINE",
+            SharePointVersion::Sharepoint2019 => "SHAREPOINT_2019",
+            SharePointVersion::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SharePointVersion {
+    fn default() -> SharePointVersion {
+        SharePointVersion::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SharePointVersion {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SharePointVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SharePointVersion {
+    fn from(value: &'a str) -> SharePointVersion {
+        match value {
+            "SHAREPOINT_2013" => SharePointVersion::Sharepoint2013,
+            "SHAREPOINT_2016" => SharePointVersion::Sharepoint2016,
+            "SHAREPOINT_ONLINE" => SharePointVersion::SharepointOnline,
+            "SHAREPOINT_2019" => SharePointVersion::Sharepoint2019,
+            _ => SharePointVersion::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SharePointVersion {
+    fn from(value: String) -> SharePointVersion {
+        match value.as_str() {
+            "SHAREPOINT_2013" => SharePointVersion::Sharepoint2013,
+            "SHAREPOINT_2016" => SharePointVersion::Sharepoint2016,
+            "SHAREPOINT_ONLINE" => SharePointVersion::SharepointOnline,
+            "SHAREPOINT_2019" => SharePointVersion::Sharepoint2019,
+            _ => SharePointVersion::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SharePointVersion {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SharePointVersion, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SharePointVersion {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SharePointVersion {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SharePointVersion {
+    fn deserialize<D>(deserializer: D) -> Result<SharePointVersion, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct StartDataSourceSyncJobRequest {
@@ -1322,12 +3136,22 @@ pub struct TextWithHighlights {
 pub struct TimeRange {
     /// <p>The UNIX datetime of the end of the time range.</p>
     #[serde(rename = "EndTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The UNIX datetime of the beginning of the time range.</p>
     #[serde(rename = "StartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -2593,11 +4417,12 @@ pub struct KendraClient {
 impl KendraClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> KendraClient {
         KendraClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "kendra"),
         }
     }
 
@@ -2629,6 +4454,7 @@ impl Kendra for KendraClient {
         input: BatchDeleteDocumentRequest,
     ) -> Result<BatchDeleteDocumentResponse, RusotoError<BatchDeleteDocumentError>> {
         let mut request = SignedRequest::new("POST", "kendra", &self.region, "/");
+        request.set_operation("BatchDeleteDocument");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2660,6 +4486,7 @@ impl Kendra for KendraClient {
         input: BatchPutDocumentRequest,
     ) -> Result<BatchPutDocumentResponse, RusotoError<BatchPutDocumentError>> {
         let mut request = SignedRequest::new("POST", "kendra", &self.region, "/");
+        request.set_operation("BatchPutDocument");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSKendraFrontendService.BatchPutDocument");
@@ -2688,6 +4515,7 @@ impl Kendra for KendraClient {
         input: CreateDataSourceRequest,
     ) -> Result<CreateDataSourceResponse, RusotoError<CreateDataSourceError>> {
         let mut request = SignedRequest::new("POST", "kendra", &self.region, "/");
+        request.set_operation("CreateDataSource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSKendraFrontendService.CreateDataSource");
@@ -2716,6 +4544,7 @@ impl Kendra for KendraClient {
         input: CreateFaqRequest,
     ) -> Result<CreateFaqResponse, RusotoError<CreateFaqError>> {
         let mut request = SignedRequest::new("POST", "kendra", &self.region, "/");
+        request.set_operation("CreateFaq");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSKendraFrontendService.CreateFaq");
@@ -2743,6 +4572,7 @@ impl Kendra for KendraClient {
         input: CreateIndexRequest,
     ) -> Result<CreateIndexResponse, RusotoError<CreateIndexError>> {
         let mut request = SignedRequest::new("POST", "kendra", &self.region, "/");
+        request.set_operation("CreateIndex");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSKendraFrontendService.CreateIndex");
@@ -2767,6 +4597,7 @@ impl Kendra for KendraClient {
     /// <p>Removes an FAQ from an index.</p>
     async fn delete_faq(&self, input: DeleteFaqRequest) -> Result<(), RusotoError<DeleteFaqError>> {
         let mut request = SignedRequest::new("POST", "kendra", &self.region, "/");
+        request.set_operation("DeleteFaq");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSKendraFrontendService.DeleteFaq");
@@ -2794,6 +4625,7 @@ impl Kendra for KendraClient {
         input: DeleteIndexRequest,
     ) -> Result<(), RusotoError<DeleteIndexError>> {
         let mut request = SignedRequest::new("POST", "kendra", &self.region, "/");
+        request.set_operation("DeleteIndex");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSKendraFrontendService.DeleteIndex");
@@ -2821,6 +4653,7 @@ impl Kendra for KendraClient {
         input: DescribeDataSourceRequest,
     ) -> Result<DescribeDataSourceResponse, RusotoError<DescribeDataSourceError>> {
         let mut request = SignedRequest::new("POST", "kendra", &self.region, "/");
+        request.set_operation("DescribeDataSource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2852,6 +4685,7 @@ impl Kendra for KendraClient {
         input: DescribeFaqRequest,
     ) -> Result<DescribeFaqResponse, RusotoError<DescribeFaqError>> {
         let mut request = SignedRequest::new("POST", "kendra", &self.region, "/");
+        request.set_operation("DescribeFaq");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSKendraFrontendService.DescribeFaq");
@@ -2879,6 +4713,7 @@ impl Kendra for KendraClient {
         input: DescribeIndexRequest,
     ) -> Result<DescribeIndexResponse, RusotoError<DescribeIndexError>> {
         let mut request = SignedRequest::new("POST", "kendra", &self.region, "/");
+        request.set_operation("DescribeIndex");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSKendraFrontendService.DescribeIndex");
@@ -2906,6 +4741,7 @@ impl Kendra for KendraClient {
         input: ListDataSourceSyncJobsRequest,
     ) -> Result<ListDataSourceSyncJobsResponse, RusotoError<ListDataSourceSyncJobsError>> {
         let mut request = SignedRequest::new("POST", "kendra", &self.region, "/");
+        request.set_operation("ListDataSourceSyncJobs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2937,6 +4773,7 @@ impl Kendra for KendraClient {
         input: ListDataSourcesRequest,
     ) -> Result<ListDataSourcesResponse, RusotoError<ListDataSourcesError>> {
         let mut request = SignedRequest::new("POST", "kendra", &self.region, "/");
+        request.set_operation("ListDataSources");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSKendraFrontendService.ListDataSources");
@@ -2964,6 +4801,7 @@ impl Kendra for KendraClient {
         input: ListFaqsRequest,
     ) -> Result<ListFaqsResponse, RusotoError<ListFaqsError>> {
         let mut request = SignedRequest::new("POST", "kendra", &self.region, "/");
+        request.set_operation("ListFaqs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSKendraFrontendService.ListFaqs");
@@ -2991,6 +4829,7 @@ impl Kendra for KendraClient {
         input: ListIndicesRequest,
     ) -> Result<ListIndicesResponse, RusotoError<ListIndicesError>> {
         let mut request = SignedRequest::new("POST", "kendra", &self.region, "/");
+        request.set_operation("ListIndices");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSKendraFrontendService.ListIndices");
@@ -3015,6 +4854,7 @@ impl Kendra for KendraClient {
     /// <p>Searches an active index. Use this API to search your documents using query. The <code>Query</code> operation enables to do faceted search and to filter results based on document attributes.</p> <p>It also enables you to provide user context that Amazon Kendra uses to enforce document access control in the search results. </p> <p>Amazon Kendra searches your index for text content and question and answer (FAQ) content. By default the response contains three types of results.</p> <ul> <li> <p>Relevant passages</p> </li> <li> <p>Matching FAQs</p> </li> <li> <p>Relevant documents</p> </li> </ul> <p>You can specify that the query return only one type of result using the <code>QueryResultTypeConfig</code> parameter.</p>
     async fn query(&self, input: QueryRequest) -> Result<QueryResult, RusotoError<QueryError>> {
         let mut request = SignedRequest::new("POST", "kendra", &self.region, "/");
+        request.set_operation("Query");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSKendraFrontendService.Query");
@@ -3042,6 +4882,7 @@ impl Kendra for KendraClient {
         input: StartDataSourceSyncJobRequest,
     ) -> Result<StartDataSourceSyncJobResponse, RusotoError<StartDataSourceSyncJobError>> {
         let mut request = SignedRequest::new("POST", "kendra", &self.region, "/");
+        request.set_operation("StartDataSourceSyncJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3073,6 +4914,7 @@ impl Kendra for KendraClient {
         input: StopDataSourceSyncJobRequest,
     ) -> Result<(), RusotoError<StopDataSourceSyncJobError>> {
         let mut request = SignedRequest::new("POST", "kendra", &self.region, "/");
+        request.set_operation("StopDataSourceSyncJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3103,6 +4945,7 @@ impl Kendra for KendraClient {
         input: SubmitFeedbackRequest,
     ) -> Result<(), RusotoError<SubmitFeedbackError>> {
         let mut request = SignedRequest::new("POST", "kendra", &self.region, "/");
+        request.set_operation("SubmitFeedback");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSKendraFrontendService.SubmitFeedback");
@@ -3130,6 +4973,7 @@ impl Kendra for KendraClient {
         input: UpdateDataSourceRequest,
     ) -> Result<(), RusotoError<UpdateDataSourceError>> {
         let mut request = SignedRequest::new("POST", "kendra", &self.region, "/");
+        request.set_operation("UpdateDataSource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSKendraFrontendService.UpdateDataSource");
@@ -3157,6 +5001,7 @@ impl Kendra for KendraClient {
         input: UpdateIndexRequest,
     ) -> Result<(), RusotoError<UpdateIndexError>> {
         let mut request = SignedRequest::new("POST", "kendra", &self.region, "/");
+        request.set_operation("UpdateIndex");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSKendraFrontendService.UpdateIndex");
@@ -24,6 +24,285 @@ use rusoto_core::signature::SignedRequest;
 #[allow(unused_imports)]
 use serde::{Deserialize, Serialize};
 use serde_json;
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ContainerFormat {
+    /// `FRAGMENTED_MP4`
+    FragmentedMp4,
+    /// `MPEG_TS`
+    MpegTs,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ContainerFormat {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ContainerFormat::FragmentedMp4 => "FRAGMENTED_MP4",
+            ContainerFormat::MpegTs => "MPEG_TS",
+            ContainerFormat::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ContainerFormat {
+    fn default() -> ContainerFormat {
+        ContainerFormat::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ContainerFormat {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ContainerFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ContainerFormat {
+    fn from(value: &'a str) -> ContainerFormat {
+        match value {
+            "FRAGMENTED_MP4" => ContainerFormat::FragmentedMp4,
+            "MPEG_TS" => ContainerFormat::MpegTs,
+            _ => ContainerFormat::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ContainerFormat {
+    fn from(value: String) -> ContainerFormat {
+        match value.as_str() {
+            "FRAGMENTED_MP4" => ContainerFormat::FragmentedMp4,
+            "MPEG_TS" => ContainerFormat::MpegTs,
+            _ => ContainerFormat::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ContainerFormat {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ContainerFormat, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ContainerFormat {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ContainerFormat {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ContainerFormat {
+    fn deserialize<D>(deserializer: D) -> Result<ContainerFormat, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DASHDisplayFragmentNumber {
+    /// `ALWAYS`
+    Always,
+    /// `NEVER`
+    Never,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DASHDisplayFragmentNumber {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DASHDisplayFragmentNumber::Always => "ALWAYS",
+            DASHDisplayFragmentNumber::Never => "NEVER",
+            DASHDisplayFragmentNumber::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DASHDisplayFragmentNumber {
+    fn default() -> DASHDisplayFragmentNumber {
+        DASHDisplayFragmentNumber::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DASHDisplayFragmentNumber {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DASHDisplayFragmentNumber {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DASHDisplayFragmentNumber {
+    fn from(value: &'a str) -> DASHDisplayFragmentNumber {
+        match value {
+            "ALWAYS" => DASHDisplayFragmentNumber::Always,
+            "NEVER" => DASHDisplayFragmentNumber::Never,
+            _ => DASHDisplayFragmentNumber::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DASHDisplayFragmentNumber {
+    fn from(value: String) -> DASHDisplayFragmentNumber {
+        match value.as_str() {
+            "ALWAYS" => DASHDisplayFragmentNumber::Always,
+            "NEVER" => DASHDisplayFragmentNumber::Never,
+            _ => DASHDisplayFragmentNumber::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DASHDisplayFragmentNumber {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DASHDisplayFragmentNumber, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DASHDisplayFragmentNumber {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DASHDisplayFragmentNumber {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DASHDisplayFragmentNumber {
+    fn deserialize<D>(deserializer: D) -> Result<DASHDisplayFragmentNumber, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DASHDisplayFragmentTimestamp {
+    /// `ALWAYS`
+    Always,
+    /// `NEVER`
+    Never,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DASHDisplayFragmentTimestamp {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DASHDisplayFragmentTimestamp::Always => "ALWAYS",
+            DASHDisplayFragmentTimestamp::Never => "NEVER",
+            DASHDisplayFragmentTimestamp::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DASHDisplayFragmentTimestamp {
+    fn default() -> DASHDisplayFragmentTimestamp {
+        DASHDisplayFragmentTimestamp::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DASHDisplayFragmentTimestamp {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DASHDisplayFragmentTimestamp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DASHDisplayFragmentTimestamp {
+    fn from(value: &'a str) -> DASHDisplayFragmentTimestamp {
+        match value {
+            "ALWAYS" => DASHDisplayFragmentTimestamp::Always,
+            "NEVER" => DASHDisplayFragmentTimestamp::Never,
+            _ => DASHDisplayFragmentTimestamp::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DASHDisplayFragmentTimestamp {
+    fn from(value: String) -> DASHDisplayFragmentTimestamp {
+        match value.as_str() {
+            "ALWAYS" => DASHDisplayFragmentTimestamp::Always,
+            "NEVER" => DASHDisplayFragmentTimestamp::Never,
+            _ => DASHDisplayFragmentTimestamp::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DASHDisplayFragmentTimestamp {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DASHDisplayFragmentTimestamp, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DASHDisplayFragmentTimestamp {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DASHDisplayFragmentTimestamp {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DASHDisplayFragmentTimestamp {
+    fn deserialize<D>(deserializer: D) -> Result<DASHDisplayFragmentTimestamp, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Contains the range of timestamps for the requested media, and the source of the timestamps. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -31,25 +310,226 @@ pub struct DASHFragmentSelector {
     /// <p>The source of the timestamps for the requested media.</p> <p>When <code>FragmentSelectorType</code> is set to <code>PRODUCER_TIMESTAMP</code> and <a>GetDASHStreamingSessionURLInput$PlaybackMode</a> is <code>ON_DEMAND</code> or <code>LIVE_REPLAY</code>, the first fragment ingested with a producer timestamp within the specified <a>FragmentSelector$TimestampRange</a> is included in the media playlist. In addition, the fragments with producer timestamps within the <code>TimestampRange</code> ingested immediately following the first fragment (up to the <a>GetDASHStreamingSessionURLInput$MaxManifestFragmentResults</a> value) are included. </p> <p>Fragments that have duplicate producer timestamps are deduplicated. This means that if producers are producing a stream of fragments with producer timestamps that are approximately equal to the true clock time, the MPEG-DASH manifest will contain all of the fragments within the requested timestamp range. If some fragments are ingested within the same time range and very different points in time, only the oldest ingested collection of fragments are returned.</p> <p>When <code>FragmentSelectorType</code> is set to <code>PRODUCER_TIMESTAMP</code> and <a>GetDASHStreamingSessionURLInput$PlaybackMode</a> is <code>LIVE</code>, the producer timestamps are used in the MP4 fragments and for deduplication. But the most recently ingested fragments based on server timestamps are included in the MPEG-DASH manifest. This means that even if fragments ingested in the past have producer timestamps with values now, they are not included in the HLS media playlist.</p> <p>The default is <code>SERVER_TIMESTAMP</code>.</p>
     #[serde(rename = "FragmentSelectorType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub fragment_selector_type: Option<String>,
+    pub fragment_selector_type: Option<DASHFragmentSelectorType>,
     /// <p>The start and end of the timestamp range for the requested media.</p> <p>This value should not be present if <code>PlaybackType</code> is <code>LIVE</code>.</p>
     #[serde(rename = "TimestampRange")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub timestamp_range: Option<DASHTimestampRange>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DASHFragmentSelectorType {
+    /// `PRODUCER_TIMESTAMP`
+    ProducerTimestamp,
+    /// `SERVER_TIMESTAMP`
+    ServerTimestamp,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DASHFragmentSelectorType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DASHFragmentSelectorType::ProducerTimestamp => "PRODUCER_TIMESTAMP",
+            DASHFragmentSelectorType::ServerTimestamp => "SERVER_TIMESTAMP",
+            DASHFragmentSelectorType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DASHFragmentSelectorType {
+    fn default() -> DASHFragmentSelectorType {
+        DASHFragmentSelectorType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DASHFragmentSelectorType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DASHFragmentSelectorType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DASHFragmentSelectorType {
+    fn from(value: &'a str) -> DASHFragmentSelectorType {
+        match value {
+            "PRODUCER_TIMESTAMP" => DASHFragmentSelectorType::ProducerTimestamp,
+            "SERVER_TIMESTAMP" => DASHFragmentSelectorType::ServerTimestamp,
+            _ => DASHFragmentSelectorType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DASHFragmentSelectorType {
+    fn from(value: String) -> DASHFragmentSelectorType {
+        match value.as_str() {
+            "PRODUCER_TIMESTAMP" => DASHFragmentSelectorType::ProducerTimestamp,
+            "SERVER_TIMESTAMP" => DASHFragmentSelectorType::ServerTimestamp,
+            _ => DASHFragmentSelectorType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DASHFragmentSelectorType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DASHFragmentSelectorType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DASHFragmentSelectorType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DASHFragmentSelectorType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DASHFragmentSelectorType {
+    fn deserialize<D>(deserializer: D) -> Result<DASHFragmentSelectorType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DASHPlaybackMode {
+    /// `LIVE`
+    Live,
+    /// `LIVE_REPLAY`
+    LiveReplay,
+    /// `ON_DEMAND`
+    OnDemand,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DASHPlaybackMode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DASHPlaybackMode::Live => "LIVE",
+            DASHPlaybackMode::LiveReplay => "LIVE_REPLAY",
+            DASHPlaybackMode::OnDemand => "ON_DEMAND",
+            DASHPlaybackMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DASHPlaybackMode {
+    fn default() -> DASHPlaybackMode {
+        DASHPlaybackMode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DASHPlaybackMode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DASHPlaybackMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DASHPlaybackMode {
+    fn from(value: &'a str) -> DASHPlaybackMode {
+        match value {
+            "LIVE" => DASHPlaybackMode::Live,
+            "LIVE_REPLAY" => DASHPlaybackMode::LiveReplay,
+            "ON_DEMAND" => DASHPlaybackMode::OnDemand,
+            _ => DASHPlaybackMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DASHPlaybackMode {
+    fn from(value: String) -> DASHPlaybackMode {
+        match value.as_str() {
+            "LIVE" => DASHPlaybackMode::Live,
+            "LIVE_REPLAY" => DASHPlaybackMode::LiveReplay,
+            "ON_DEMAND" => DASHPlaybackMode::OnDemand,
+            _ => DASHPlaybackMode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DASHPlaybackMode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DASHPlaybackMode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DASHPlaybackMode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DASHPlaybackMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DASHPlaybackMode {
+    fn deserialize<D>(deserializer: D) -> Result<DASHPlaybackMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p><p>The start and end of the timestamp range for the requested media.</p> <p>This value should not be present if <code>PlaybackType</code> is <code>LIVE</code>.</p> <note> <p>The values in the <code>DASHimestampRange</code> are inclusive. Fragments that begin before the start time but continue past it, or fragments that begin before the end time but continue past it, are included in the session.</p> </note></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct DASHTimestampRange {
     /// <p><p>The end of the timestamp range for the requested media. This value must be within 3 hours of the specified <code>StartTimestamp</code>, and it must be later than the <code>StartTimestamp</code> value.</p> <p>If <code>FragmentSelectorType</code> for the request is <code>SERVER<em>TIMESTAMP</code>, this value must be in the past.</p> <p>The <code>EndTimestamp</code> value is required for <code>ON</em>DEMAND</code> mode, but optional for <code>LIVE<em>REPLAY</code> mode. If the <code>EndTimestamp</code> is not set for <code>LIVE</em>REPLAY</code> mode then the session will continue to include newly ingested fragments until the session expires.</p> <note> <p>This value is inclusive. The <code>EndTimestamp</code> is compared to the (starting) timestamp of the fragment. Fragments that start before the <code>EndTimestamp</code> value and continue past it are included in the session.</p> </note></p>
     #[serde(rename = "EndTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_timestamp: Option<f64>,
+    pub end_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>The start of the timestamp range for the requested media.</p> <p>If the <code>DASHTimestampRange</code> value is specified, the <code>StartTimestamp</code> value is required.</p> <note> <p>This value is inclusive. Fragments that start before the <code>StartTimestamp</code> and continue past it are included in the session. If <code>FragmentSelectorType</code> is <code>SERVER_TIMESTAMP</code>, the <code>StartTimestamp</code> must be later than the stream head.</p> </note></p>
     #[serde(rename = "StartTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_timestamp: Option<f64>,
+    pub start_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>Represents a segment of video or other time-delimited data.</p>
@@ -70,12 +550,22 @@ pub struct Fragment {
     pub fragment_size_in_bytes: Option<i64>,
     /// <p>The timestamp from the producer corresponding to the fragment.</p>
     #[serde(rename = "ProducerTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub producer_timestamp: Option<f64>,
+    pub producer_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The timestamp from the AWS server corresponding to the fragment.</p>
     #[serde(rename = "ServerTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub server_timestamp: Option<f64>,
+    pub server_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>Describes the timestamp range and timestamp origin of a range of fragments.</p> <p>Only fragments with a start timestamp greater than or equal to the given start time and less than or equal to the end time are returned. For example, if a stream contains fragments with the following start timestamps: </p> <ul> <li> <p>00:00:00</p> </li> <li> <p>00:00:02</p> </li> <li> <p>00:00:04</p> </li> <li> <p>00:00:06</p> </li> </ul> <p> A fragment selector range with a start time of 00:00:01 and end time of 00:00:04 would return the fragments with start times of 00:00:02 and 00:00:04. </p>
@@ -84,12 +574,105 @@ pub struct Fragment {
 pub struct FragmentSelector {
     /// <p>The origin of the timestamps to use (Server or Producer).</p>
     #[serde(rename = "FragmentSelectorType")]
-    pub fragment_selector_type: String,
+    pub fragment_selector_type: FragmentSelectorType,
     /// <p>The range of timestamps to return.</p>
     #[serde(rename = "TimestampRange")]
     pub timestamp_range: TimestampRange,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FragmentSelectorType {
+    /// `PRODUCER_TIMESTAMP`
+    ProducerTimestamp,
+    /// `SERVER_TIMESTAMP`
+    ServerTimestamp,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl FragmentSelectorType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FragmentSelectorType::ProducerTimestamp => "PRODUCER_TIMESTAMP",
+            FragmentSelectorType::ServerTimestamp => "SERVER_TIMESTAMP",
+            FragmentSelectorType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for FragmentSelectorType {
+    fn default() -> FragmentSelectorType {
+        FragmentSelectorType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for FragmentSelectorType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FragmentSelectorType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FragmentSelectorType {
+    fn from(value: &'a str) -> FragmentSelectorType {
+        match value {
+            "PRODUCER_TIMESTAMP" => FragmentSelectorType::ProducerTimestamp,
+            "SERVER_TIMESTAMP" => FragmentSelectorType::ServerTimestamp,
+            _ => FragmentSelectorType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FragmentSelectorType {
+    fn from(value: String) -> FragmentSelectorType {
+        match value.as_str() {
+            "PRODUCER_TIMESTAMP" => FragmentSelectorType::ProducerTimestamp,
+            "SERVER_TIMESTAMP" => FragmentSelectorType::ServerTimestamp,
+            _ => FragmentSelectorType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for FragmentSelectorType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<FragmentSelectorType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FragmentSelectorType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FragmentSelectorType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FragmentSelectorType {
+    fn deserialize<D>(deserializer: D) -> Result<FragmentSelectorType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct GetDASHStreamingSessionURLInput {
@@ -100,11 +683,11 @@ pub struct GetDASHStreamingSessionURLInput {
     /// <p>Fragments are identified in the manifest file based on their sequence number in the session. If DisplayFragmentNumber is set to <code>ALWAYS</code>, the Kinesis Video Streams fragment number is added to each S element in the manifest file with the attribute name “kvs:fn”. These fragment numbers can be used for logging or for use with other APIs (e.g. <code>GetMedia</code> and <code>GetMediaForFragmentList</code>). A custom MPEG-DASH media player is necessary to leverage these this custom attribute.</p> <p>The default value is <code>NEVER</code>.</p>
     #[serde(rename = "DisplayFragmentNumber")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub display_fragment_number: Option<String>,
+    pub display_fragment_number: Option<DASHDisplayFragmentNumber>,
     /// <p>Per the MPEG-DASH specification, the wall-clock time of fragments in the manifest file can be derived using attributes in the manifest itself. However, typically, MPEG-DASH compatible media players do not properly handle gaps in the media timeline. Kinesis Video Streams adjusts the media timeline in the manifest file to enable playback of media with discontinuities. Therefore, the wall-clock time derived from the manifest file may be inaccurate. If DisplayFragmentTimestamp is set to <code>ALWAYS</code>, the accurate fragment timestamp is added to each S element in the manifest file with the attribute name “kvs:ts”. A custom MPEG-DASH media player is necessary to leverage this custom attribute.</p> <p>The default value is <code>NEVER</code>. When <a>DASHFragmentSelector</a> is <code>SERVER_TIMESTAMP</code>, the timestamps will be the server start timestamps. Similarly, when <a>DASHFragmentSelector</a> is <code>PRODUCER_TIMESTAMP</code>, the timestamps will be the producer start timestamps. </p>
     #[serde(rename = "DisplayFragmentTimestamp")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub display_fragment_timestamp: Option<String>,
+    pub display_fragment_timestamp: Option<DASHDisplayFragmentTimestamp>,
     /// <p>The time in seconds until the requested session expires. This value can be between 300 (5 minutes) and 43200 (12 hours).</p> <p>When a session expires, no new calls to <code>GetDashManifest</code>, <code>GetMP4InitFragment</code>, or <code>GetMP4MediaFragment</code> can be made for that session.</p> <p>The default is 300 (5 minutes).</p>
     #[serde(rename = "Expires")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -116,7 +699,7 @@ pub struct GetDASHStreamingSessionURLInput {
     /// <p>Whether to retrieve live, live replay, or archived, on-demand data.</p> <p>Features of the three types of sessions include the following:</p> <ul> <li> <p> <b> <code>LIVE</code> </b>: For sessions of this type, the MPEG-DASH manifest is continually updated with the latest fragments as they become available. We recommend that the media player retrieve a new manifest on a one-second interval. When this type of session is played in a media player, the user interface typically displays a "live" notification, with no scrubber control for choosing the position in the playback window to display.</p> <note> <p>In <code>LIVE</code> mode, the newest available fragments are included in an MPEG-DASH manifest, even if there is a gap between fragments (that is, if a fragment is missing). A gap like this might cause a media player to halt or cause a jump in playback. In this mode, fragments are not added to the MPEG-DASH manifest if they are older than the newest fragment in the playlist. If the missing fragment becomes available after a subsequent fragment is added to the manifest, the older fragment is not added, and the gap is not filled.</p> </note> </li> <li> <p> <b> <code>LIVE_REPLAY</code> </b>: For sessions of this type, the MPEG-DASH manifest is updated similarly to how it is updated for <code>LIVE</code> mode except that it starts by including fragments from a given start time. Instead of fragments being added as they are ingested, fragments are added as the duration of the next fragment elapses. For example, if the fragments in the session are two seconds long, then a new fragment is added to the manifest every two seconds. This mode is useful to be able to start playback from when an event is detected and continue live streaming media that has not yet been ingested as of the time of the session creation. This mode is also useful to stream previously archived media without being limited by the 1,000 fragment limit in the <code>ON_DEMAND</code> mode. </p> </li> <li> <p> <b> <code>ON_DEMAND</code> </b>: For sessions of this type, the MPEG-DASH manifest contains all the fragments for the session, up to the number that is specified in <code>MaxMediaPlaylistFragmentResults</code>. The manifest must be retrieved only once for each session. When this type of session is played in a media player, the user interface typically displays a scrubber control for choosing the position in the playback window to display.</p> </li> </ul> <p>In all playback modes, if <code>FragmentSelectorType</code> is <code>PRODUCER_TIMESTAMP</code>, and if there are multiple fragments with the same start timestamp, the fragment that has the larger fragment number (that is, the newer fragment) is included in the MPEG-DASH manifest. The other fragments are not included. Fragments that have different timestamps but have overlapping durations are still included in the MPEG-DASH manifest. This can lead to unexpected behavior in the media player.</p> <p>The default is <code>LIVE</code>.</p>
     #[serde(rename = "PlaybackMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub playback_mode: Option<String>,
+    pub playback_mode: Option<DASHPlaybackMode>,
     /// <p>The Amazon Resource Name (ARN) of the stream for which to retrieve the MPEG-DASH manifest URL.</p> <p>You must specify either the <code>StreamName</code> or the <code>StreamARN</code>.</p>
     #[serde(rename = "StreamARN")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -142,15 +725,15 @@ pub struct GetHLSStreamingSessionURLInput {
     /// <p>Specifies which format should be used for packaging the media. Specifying the <code>FRAGMENTED_MP4</code> container format packages the media into MP4 fragments (fMP4 or CMAF). This is the recommended packaging because there is minimal packaging overhead. The other container format option is <code>MPEG_TS</code>. HLS has supported MPEG TS chunks since it was released and is sometimes the only supported packaging on older HLS players. MPEG TS typically has a 5-25 percent packaging overhead. This means MPEG TS typically requires 5-25 percent more bandwidth and cost than fMP4.</p> <p>The default is <code>FRAGMENTED_MP4</code>.</p>
     #[serde(rename = "ContainerFormat")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub container_format: Option<String>,
+    pub container_format: Option<ContainerFormat>,
     /// <p>Specifies when flags marking discontinuities between fragments are added to the media playlists.</p> <p>Media players typically build a timeline of media content to play, based on the timestamps of each fragment. This means that if there is any overlap or gap between fragments (as is typical if <a>HLSFragmentSelector</a> is set to <code>SERVER_TIMESTAMP</code>), the media player timeline will also have small gaps between fragments in some places, and will overwrite frames in other places. Gaps in the media player timeline can cause playback to stall and overlaps can cause playback to be jittery. When there are discontinuity flags between fragments, the media player is expected to reset the timeline, resulting in the next fragment being played immediately after the previous fragment. </p> <p>The following modes are supported:</p> <ul> <li> <p> <code>ALWAYS</code>: a discontinuity marker is placed between every fragment in the HLS media playlist. It is recommended to use a value of <code>ALWAYS</code> if the fragment timestamps are not accurate.</p> </li> <li> <p> <code>NEVER</code>: no discontinuity markers are placed anywhere. It is recommended to use a value of <code>NEVER</code> to ensure the media player timeline most accurately maps to the producer timestamps. </p> </li> <li> <p> <code>ON_DISCONTIUNITY</code>: a discontinuity marker is placed between fragments that have a gap or overlap of more than 50 milliseconds. For most playback scenarios, it is recommended to use a value of <code>ON_DISCONTINUITY</code> so that the media player timeline is only reset when there is a significant issue with the media timeline (e.g. a missing fragment).</p> </li> </ul> <p>The default is <code>ALWAYS</code> when <a>HLSFragmentSelector</a> is set to <code>SERVER_TIMESTAMP</code>, and <code>NEVER</code> when it is set to <code>PRODUCER_TIMESTAMP</code>.</p>
     #[serde(rename = "DiscontinuityMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub discontinuity_mode: Option<String>,
+    pub discontinuity_mode: Option<HLSDiscontinuityMode>,
     /// <p>Specifies when the fragment start timestamps should be included in the HLS media playlist. Typically, media players report the playhead position as a time relative to the start of the first fragment in the playback session. However, when the start timestamps are included in the HLS media playlist, some media players might report the current playhead as an absolute time based on the fragment timestamps. This can be useful for creating a playback experience that shows viewers the wall-clock time of the media.</p> <p>The default is <code>NEVER</code>. When <a>HLSFragmentSelector</a> is <code>SERVER_TIMESTAMP</code>, the timestamps will be the server start timestamps. Similarly, when <a>HLSFragmentSelector</a> is <code>PRODUCER_TIMESTAMP</code>, the timestamps will be the producer start timestamps. </p>
     #[serde(rename = "DisplayFragmentTimestamp")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub display_fragment_timestamp: Option<String>,
+    pub display_fragment_timestamp: Option<HLSDisplayFragmentTimestamp>,
     /// <p>The time in seconds until the requested session expires. This value can be between 300 (5 minutes) and 43200 (12 hours).</p> <p>When a session expires, no new calls to <code>GetHLSMasterPlaylist</code>, <code>GetHLSMediaPlaylist</code>, <code>GetMP4InitFragment</code>, <code>GetMP4MediaFragment</code>, or <code>GetTSFragment</code> can be made for that session.</p> <p>The default is 300 (5 minutes).</p>
     #[serde(rename = "Expires")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -166,7 +749,7 @@ pub struct GetHLSStreamingSessionURLInput {
     /// <p>Whether to retrieve live, live replay, or archived, on-demand data.</p> <p>Features of the three types of sessions include the following:</p> <ul> <li> <p> <b> <code>LIVE</code> </b>: For sessions of this type, the HLS media playlist is continually updated with the latest fragments as they become available. We recommend that the media player retrieve a new playlist on a one-second interval. When this type of session is played in a media player, the user interface typically displays a "live" notification, with no scrubber control for choosing the position in the playback window to display.</p> <note> <p>In <code>LIVE</code> mode, the newest available fragments are included in an HLS media playlist, even if there is a gap between fragments (that is, if a fragment is missing). A gap like this might cause a media player to halt or cause a jump in playback. In this mode, fragments are not added to the HLS media playlist if they are older than the newest fragment in the playlist. If the missing fragment becomes available after a subsequent fragment is added to the playlist, the older fragment is not added, and the gap is not filled.</p> </note> </li> <li> <p> <b> <code>LIVE_REPLAY</code> </b>: For sessions of this type, the HLS media playlist is updated similarly to how it is updated for <code>LIVE</code> mode except that it starts by including fragments from a given start time. Instead of fragments being added as they are ingested, fragments are added as the duration of the next fragment elapses. For example, if the fragments in the session are two seconds long, then a new fragment is added to the media playlist every two seconds. This mode is useful to be able to start playback from when an event is detected and continue live streaming media that has not yet been ingested as of the time of the session creation. This mode is also useful to stream previously archived media without being limited by the 1,000 fragment limit in the <code>ON_DEMAND</code> mode. </p> </li> <li> <p> <b> <code>ON_DEMAND</code> </b>: For sessions of this type, the HLS media playlist contains all the fragments for the session, up to the number that is specified in <code>MaxMediaPlaylistFragmentResults</code>. The playlist must be retrieved only once for each session. When this type of session is played in a media player, the user interface typically displays a scrubber control for choosing the position in the playback window to display.</p> </li> </ul> <p>In all playback modes, if <code>FragmentSelectorType</code> is <code>PRODUCER_TIMESTAMP</code>, and if there are multiple fragments with the same start timestamp, the fragment that has the larger fragment number (that is, the newer fragment) is included in the HLS media playlist. The other fragments are not included. Fragments that have different timestamps but have overlapping durations are still included in the HLS media playlist. This can lead to unexpected behavior in the media player.</p> <p>The default is <code>LIVE</code>.</p>
     #[serde(rename = "PlaybackMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub playback_mode: Option<String>,
+    pub playback_mode: Option<HLSPlaybackMode>,
     /// <p>The Amazon Resource Name (ARN) of the stream for which to retrieve the HLS master playlist URL.</p> <p>You must specify either the <code>StreamName</code> or the <code>StreamARN</code>.</p>
     #[serde(rename = "StreamARN")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -205,6 +788,197 @@ pub struct GetMediaForFragmentListOutput {
     pub payload: Option<bytes::Bytes>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum HLSDiscontinuityMode {
+    /// `ALWAYS`
+    Always,
+    /// `NEVER`
+    Never,
+    /// `ON_DISCONTINUITY`
+    OnDiscontinuity,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl HLSDiscontinuityMode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            HLSDiscontinuityMode::Always => "ALWAYS",
+            HLSDiscontinuityMode::Never => "NEVER",
+            HLSDiscontinuityMode::OnDiscontinuity => "ON_DISCONTINUITY",
+            HLSDiscontinuityMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for HLSDiscontinuityMode {
+    fn default() -> HLSDiscontinuityMode {
+        HLSDiscontinuityMode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for HLSDiscontinuityMode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for HLSDiscontinuityMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for HLSDiscontinuityMode {
+    fn from(value: &'a str) -> HLSDiscontinuityMode {
+        match value {
+            "ALWAYS" => HLSDiscontinuityMode::Always,
+            "NEVER" => HLSDiscontinuityMode::Never,
+            "ON_DISCONTINUITY" => HLSDiscontinuityMode::OnDiscontinuity,
+            _ => HLSDiscontinuityMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for HLSDiscontinuityMode {
+    fn from(value: String) -> HLSDiscontinuityMode {
+        match value.as_str() {
+            "ALWAYS" => HLSDiscontinuityMode::Always,
+            "NEVER" => HLSDiscontinuityMode::Never,
+            "ON_DISCONTINUITY" => HLSDiscontinuityMode::OnDiscontinuity,
+            _ => HLSDiscontinuityMode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for HLSDiscontinuityMode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<HLSDiscontinuityMode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for HLSDiscontinuityMode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for HLSDiscontinuityMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for HLSDiscontinuityMode {
+    fn deserialize<D>(deserializer: D) -> Result<HLSDiscontinuityMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum HLSDisplayFragmentTimestamp {
+    /// `ALWAYS`
+    Always,
+    /// `NEVER`
+    Never,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl HLSDisplayFragmentTimestamp {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            HLSDisplayFragmentTimestamp::Always => "ALWAYS",
+            HLSDisplayFragmentTimestamp::Never => "NEVER",
+            HLSDisplayFragmentTimestamp::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for HLSDisplayFragmentTimestamp {
+    fn default() -> HLSDisplayFragmentTimestamp {
+        HLSDisplayFragmentTimestamp::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for HLSDisplayFragmentTimestamp {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for HLSDisplayFragmentTimestamp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for HLSDisplayFragmentTimestamp {
+    fn from(value: &'a str) -> HLSDisplayFragmentTimestamp {
+        match value {
+            "ALWAYS" => HLSDisplayFragmentTimestamp::Always,
+            "NEVER" => HLSDisplayFragmentTimestamp::Never,
+            _ => HLSDisplayFragmentTimestamp::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for HLSDisplayFragmentTimestamp {
+    fn from(value: String) -> HLSDisplayFragmentTimestamp {
+        match value.as_str() {
+            "ALWAYS" => HLSDisplayFragmentTimestamp::Always,
+            "NEVER" => HLSDisplayFragmentTimestamp::Never,
+            _ => HLSDisplayFragmentTimestamp::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for HLSDisplayFragmentTimestamp {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<HLSDisplayFragmentTimestamp, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for HLSDisplayFragmentTimestamp {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for HLSDisplayFragmentTimestamp {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for HLSDisplayFragmentTimestamp {
+    fn deserialize<D>(deserializer: D) -> Result<HLSDisplayFragmentTimestamp, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Contains the range of timestamps for the requested media, and the source of the timestamps.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -212,25 +986,226 @@ pub struct HLSFragmentSelector {
     /// <p>The source of the timestamps for the requested media.</p> <p>When <code>FragmentSelectorType</code> is set to <code>PRODUCER_TIMESTAMP</code> and <a>GetHLSStreamingSessionURLInput$PlaybackMode</a> is <code>ON_DEMAND</code> or <code>LIVE_REPLAY</code>, the first fragment ingested with a producer timestamp within the specified <a>FragmentSelector$TimestampRange</a> is included in the media playlist. In addition, the fragments with producer timestamps within the <code>TimestampRange</code> ingested immediately following the first fragment (up to the <a>GetHLSStreamingSessionURLInput$MaxMediaPlaylistFragmentResults</a> value) are included. </p> <p>Fragments that have duplicate producer timestamps are deduplicated. This means that if producers are producing a stream of fragments with producer timestamps that are approximately equal to the true clock time, the HLS media playlists will contain all of the fragments within the requested timestamp range. If some fragments are ingested within the same time range and very different points in time, only the oldest ingested collection of fragments are returned.</p> <p>When <code>FragmentSelectorType</code> is set to <code>PRODUCER_TIMESTAMP</code> and <a>GetHLSStreamingSessionURLInput$PlaybackMode</a> is <code>LIVE</code>, the producer timestamps are used in the MP4 fragments and for deduplication. But the most recently ingested fragments based on server timestamps are included in the HLS media playlist. This means that even if fragments ingested in the past have producer timestamps with values now, they are not included in the HLS media playlist.</p> <p>The default is <code>SERVER_TIMESTAMP</code>.</p>
     #[serde(rename = "FragmentSelectorType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub fragment_selector_type: Option<String>,
+    pub fragment_selector_type: Option<HLSFragmentSelectorType>,
     /// <p>The start and end of the timestamp range for the requested media.</p> <p>This value should not be present if <code>PlaybackType</code> is <code>LIVE</code>.</p>
     #[serde(rename = "TimestampRange")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub timestamp_range: Option<HLSTimestampRange>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum HLSFragmentSelectorType {
+    /// `PRODUCER_TIMESTAMP`
+    ProducerTimestamp,
+    /// `SERVER_TIMESTAMP`
+    ServerTimestamp,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl HLSFragmentSelectorType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            HLSFragmentSelectorType::ProducerTimestamp => "PRODUCER_TIMESTAMP",
+            HLSFragmentSelectorType::ServerTimestamp => "SERVER_TIMESTAMP",
+            HLSFragmentSelectorType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for HLSFragmentSelectorType {
+    fn default() -> HLSFragmentSelectorType {
+        HLSFragmentSelectorType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for HLSFragmentSelectorType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for HLSFragmentSelectorType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for HLSFragmentSelectorType {
+    fn from(value: &'a str) -> HLSFragmentSelectorType {
+        match value {
+            "PRODUCER_TIMESTAMP" => HLSFragmentSelectorType::ProducerTimestamp,
+            "SERVER_TIMESTAMP" => HLSFragmentSelectorType::ServerTimestamp,
+            _ => HLSFragmentSelectorType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for HLSFragmentSelectorType {
+    fn from(value: String) -> HLSFragmentSelectorType {
+        match value.as_str() {
+            "PRODUCER_TIMESTAMP" => HLSFragmentSelectorType::ProducerTimestamp,
+            "SERVER_TIMESTAMP" => HLSFragmentSelectorType::ServerTimestamp,
+            _ => HLSFragmentSelectorType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for HLSFragmentSelectorType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<HLSFragmentSelectorType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for HLSFragmentSelectorType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for HLSFragmentSelectorType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for HLSFragmentSelectorType {
+    fn deserialize<D>(deserializer: D) -> Result<HLSFragmentSelectorType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum HLSPlaybackMode {
+    /// `LIVE`
+    Live,
+    /// `LIVE_REPLAY`
+    LiveReplay,
+    /// `ON_DEMAND`
+    OnDemand,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl HLSPlaybackMode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            HLSPlaybackMode::Live => "LIVE",
+            HLSPlaybackMode::LiveReplay => "LIVE_REPLAY",
+            HLSPlaybackMode::OnDemand => "ON_DEMAND",
+            HLSPlaybackMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for HLSPlaybackMode {
+    fn default() -> HLSPlaybackMode {
+        HLSPlaybackMode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for HLSPlaybackMode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for HLSPlaybackMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for HLSPlaybackMode {
+    fn from(value: &'a str) -> HLSPlaybackMode {
+        match value {
+            "LIVE" => HLSPlaybackMode::Live,
+            "LIVE_REPLAY" => HLSPlaybackMode::LiveReplay,
+            "ON_DEMAND" => HLSPlaybackMode::OnDemand,
+            _ => HLSPlaybackMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for HLSPlaybackMode {
+    fn from(value: String) -> HLSPlaybackMode {
+        match value.as_str() {
+            "LIVE" => HLSPlaybackMode::Live,
+            "LIVE_REPLAY" => HLSPlaybackMode::LiveReplay,
+            "ON_DEMAND" => HLSPlaybackMode::OnDemand,
+            _ => HLSPlaybackMode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for HLSPlaybackMode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<HLSPlaybackMode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for HLSPlaybackMode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for HLSPlaybackMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for HLSPlaybackMode {
+    fn deserialize<D>(deserializer: D) -> Result<HLSPlaybackMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p><p>The start and end of the timestamp range for the requested media.</p> <p>This value should not be present if <code>PlaybackType</code> is <code>LIVE</code>.</p> <note> <p>The values in the <code>HLSTimestampRange</code> are inclusive. Fragments that begin before the start time but continue past it, or fragments that begin before the end time but continue past it, are included in the session.</p> </note></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct HLSTimestampRange {
     /// <p><p>The end of the timestamp range for the requested media. This value must be within 3 hours of the specified <code>StartTimestamp</code>, and it must be later than the <code>StartTimestamp</code> value.</p> <p>If <code>FragmentSelectorType</code> for the request is <code>SERVER<em>TIMESTAMP</code>, this value must be in the past.</p> <p>The <code>EndTimestamp</code> value is required for <code>ON</em>DEMAND</code> mode, but optional for <code>LIVE<em>REPLAY</code> mode. If the <code>EndTimestamp</code> is not set for <code>LIVE</em>REPLAY</code> mode then the session will continue to include newly ingested fragments until the session expires.</p> <note> <p>This value is inclusive. The <code>EndTimestamp</code> is compared to the (starting) timestamp of the fragment. Fragments that start before the <code>EndTimestamp</code> value and continue past it are included in the session.</p> </note></p>
     #[serde(rename = "EndTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_timestamp: Option<f64>,
+    pub end_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>The start of the timestamp range for the requested media.</p> <p>If the <code>HLSTimestampRange</code> value is specified, the <code>StartTimestamp</code> value is required.</p> <note> <p>This value is inclusive. Fragments that start before the <code>StartTimestamp</code> and continue past it are included in the session. If <code>FragmentSelectorType</code> is <code>SERVER_TIMESTAMP</code>, the <code>StartTimestamp</code> must be later than the stream head.</p> </note></p>
     #[serde(rename = "StartTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_timestamp: Option<f64>,
+    pub start_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -272,10 +1247,20 @@ pub struct ListFragmentsOutput {
 pub struct TimestampRange {
     /// <p>The ending timestamp in the range of timestamps for which to return fragments.</p>
     #[serde(rename = "EndTimestamp")]
-    pub end_timestamp: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub end_timestamp: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The starting timestamp in the range of timestamps for which to return fragments.</p>
     #[serde(rename = "StartTimestamp")]
-    pub start_timestamp: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub start_timestamp: ::chrono::DateTime<::chrono::Utc>,
 }
 
 /// Errors returned by GetDASHStreamingSessionURL
@@ -613,11 +1598,15 @@ pub struct KinesisVideoArchivedMediaClient {
 impl KinesisVideoArchivedMediaClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> KinesisVideoArchivedMediaClient {
         KinesisVideoArchivedMediaClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(
+                region,
+                "Kinesis Video Archived Media",
+            ),
         }
     }
 
@@ -655,6 +1644,7 @@ impl KinesisVideoArchivedMedia for KinesisVideoArchivedMediaClient {
         let request_uri = "/getDASHStreamingSessionURL";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("GetDASHStreamingSessionURL");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -685,6 +1675,7 @@ impl KinesisVideoArchivedMedia for KinesisVideoArchivedMediaClient {
         let request_uri = "/getHLSStreamingSessionURL";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("GetHLSStreamingSessionURL");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -715,6 +1706,7 @@ impl KinesisVideoArchivedMedia for KinesisVideoArchivedMediaClient {
         let request_uri = "/getMediaForFragmentList";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("GetMediaForFragmentList");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -751,6 +1743,7 @@ impl KinesisVideoArchivedMedia for KinesisVideoArchivedMediaClient {
         let request_uri = "/listFragments";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("ListFragments");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -773,3 +1766,35 @@ impl KinesisVideoArchivedMedia for KinesisVideoArchivedMediaClient {
         }
     }
 }
+impl KinesisVideoArchivedMediaClient {
+    /// Auto-paginating version of `list_fragments`, streaming every page of results.
+    pub fn list_fragments_pages(
+        &self,
+        input: ListFragmentsInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListFragmentsOutput, ListFragmentsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: KinesisVideoArchivedMediaClient, input: ListFragmentsInput| async move {
+                client.list_fragments(input).await
+            },
+            |input: &ListFragmentsInput, output: &ListFragmentsOutput| {
+                Some(ListFragmentsInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_fragments`, streaming the `Fragments` of every page of results.
+    pub fn list_fragments_items(
+        &self,
+        input: ListFragmentsInput,
+    ) -> rusoto_core::pagination::RusotoStream<Fragment, ListFragmentsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_fragments_pages(input),
+            |output: ListFragmentsOutput| output.fragments.unwrap_or_default(),
+        )
+    }
+}
@@ -62,13 +62,131 @@ pub struct StartSelector {
     pub continuation_token: Option<String>,
     /// <p><p>Identifies the fragment on the Kinesis video stream where you want to start getting the data from.</p> <ul> <li> <p>NOW - Start with the latest chunk on the stream.</p> </li> <li> <p>EARLIEST - Start with earliest available chunk on the stream.</p> </li> <li> <p>FRAGMENT<em>NUMBER - Start with the chunk after a specific fragment. You must also specify the <code>AfterFragmentNumber</code> parameter.</p> </li> <li> <p>PRODUCER</em>TIMESTAMP or SERVER<em>TIMESTAMP - Start with the chunk containing a fragment with the specified producer or server timestamp. You specify the timestamp by adding <code>StartTimestamp</code>.</p> </li> <li> <p> CONTINUATION</em>TOKEN - Read using the specified continuation token. </p> </li> </ul> <note> <p>If you choose the NOW, EARLIEST, or CONTINUATION_TOKEN as the <code>startSelectorType</code>, you don&#39;t provide any additional information in the <code>startSelector</code>.</p> </note></p>
     #[serde(rename = "StartSelectorType")]
-    pub start_selector_type: String,
+    pub start_selector_type: StartSelectorType,
     /// <p>A timestamp value. This value is required if you choose the PRODUCER_TIMESTAMP or the SERVER_TIMESTAMP as the <code>startSelectorType</code>. The <code>GetMedia</code> API then starts with the chunk containing the fragment that has the specified timestamp.</p>
     #[serde(rename = "StartTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_timestamp: Option<f64>,
+    pub start_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StartSelectorType {
+    /// `FRAGMENT_NUMBER`
+    FragmentNumber,
+    /// `SERVER_TIMESTAMP`
+    ServerTimestamp,
+    /// `PRODUCER_TIMESTAMP`
+    ProducerTimestamp,
+    /// `NOW`
+    Now,
+    /// `EARLIEST`
+    Earliest,
+    /// `CONTINUATION_TOKEN`
+    ContinuationToken,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl StartSelectorType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StartSelectorType::FragmentNumber => "FRAGMENT_NUMBER",
+            StartSelectorType::ServerTimestamp => "SERVER_TIMESTAMP",
+            StartSelectorType::ProducerTimestamp => "PRODUCER_TIMESTAMP",
+            StartSelectorType::Now => "NOW",
+            StartSelectorType::Earliest => "EARLIEST",
+            StartSelectorType::ContinuationToken => "CONTINUATION_TOKEN",
+            StartSelectorType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for StartSelectorType {
+    fn default() -> StartSelectorType {
+        StartSelectorType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for StartSelectorType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for StartSelectorType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for StartSelectorType {
+    fn from(value: &'a str) -> StartSelectorType {
+        match value {
+            "FRAGMENT_NUMBER" => StartSelectorType::FragmentNumber,
+            "SERVER_TIMESTAMP" => StartSelectorType::ServerTimestamp,
+            "PRODUCER_TIMESTAMP" => StartSelectorType::ProducerTimestamp,
+            "NOW" => StartSelectorType::Now,
+            "EARLIEST" => StartSelectorType::Earliest,
+            "CONTINUATION_TOKEN" => StartSelectorType::ContinuationToken,
+            _ => StartSelectorType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StartSelectorType {
+    fn from(value: String) -> StartSelectorType {
+        match value.as_str() {
+            "FRAGMENT_NUMBER" => StartSelectorType::FragmentNumber,
+            "SERVER_TIMESTAMP" => StartSelectorType::ServerTimestamp,
+            "PRODUCER_TIMESTAMP" => StartSelectorType::ProducerTimestamp,
+            "NOW" => StartSelectorType::Now,
+            "EARLIEST" => StartSelectorType::Earliest,
+            "CONTINUATION_TOKEN" => StartSelectorType::ContinuationToken,
+            _ => StartSelectorType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for StartSelectorType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<StartSelectorType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StartSelectorType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for StartSelectorType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StartSelectorType {
+    fn deserialize<D>(deserializer: D) -> Result<StartSelectorType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// Errors returned by GetMedia
 #[derive(Debug, PartialEq)]
 pub enum GetMediaError {
@@ -148,11 +266,12 @@ pub struct KinesisVideoMediaClient {
 impl KinesisVideoMediaClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> KinesisVideoMediaClient {
         KinesisVideoMediaClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Kinesis Video Media"),
         }
     }
 
@@ -186,6 +305,7 @@ impl KinesisVideoMedia for KinesisVideoMediaClient {
         let request_uri = "/getMedia";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("GetMedia");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -37,7 +37,7 @@ pub struct GetIceServerConfigRequest {
     /// <p>Specifies the desired service. Currently, <code>TURN</code> is the only valid value.</p>
     #[serde(rename = "Service")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub service: Option<String>,
+    pub service: Option<Service>,
     /// <p>An optional user ID to be associated with the credentials.</p>
     #[serde(rename = "Username")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -98,6 +98,94 @@ pub struct SendAlexaOfferToMasterResponse {
     pub answer: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Service {
+    /// `TURN`
+    Turn,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Service {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Service::Turn => "TURN",
+            Service::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Service {
+    fn default() -> Service {
+        Service::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Service {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Service {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Service {
+    fn from(value: &'a str) -> Service {
+        match value {
+            "TURN" => Service::Turn,
+            _ => Service::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Service {
+    fn from(value: String) -> Service {
+        match value.as_str() {
+            "TURN" => Service::Turn,
+            _ => Service::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Service {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Service, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Service {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Service {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Service {
+    fn deserialize<D>(deserializer: D) -> Result<Service, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// Errors returned by GetIceServerConfig
 #[derive(Debug, PartialEq)]
 pub enum GetIceServerConfigError {
@@ -241,11 +329,15 @@ pub struct KinesisVideoSignalingClient {
 impl KinesisVideoSignalingClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> KinesisVideoSignalingClient {
         KinesisVideoSignalingClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(
+                region,
+                "Kinesis Video Signaling",
+            ),
         }
     }
 
@@ -279,6 +371,7 @@ impl KinesisVideoSignaling for KinesisVideoSignalingClient {
         let request_uri = "/v1/get-ice-server-config";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("GetIceServerConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -309,6 +402,7 @@ impl KinesisVideoSignaling for KinesisVideoSignalingClient {
         let request_uri = "/v1/send-alexa-offer-to-master";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("SendAlexaOfferToMaster");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
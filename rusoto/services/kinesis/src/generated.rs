@@ -45,13 +45,18 @@ pub struct Consumer {
     pub consumer_arn: String,
     /// <p><p/></p>
     #[serde(rename = "ConsumerCreationTimestamp")]
-    pub consumer_creation_timestamp: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub consumer_creation_timestamp: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The name of the consumer is something you choose when you register the consumer.</p>
     #[serde(rename = "ConsumerName")]
     pub consumer_name: String,
     /// <p>A consumer can't read data while in the <code>CREATING</code> or <code>DELETING</code> states.</p>
     #[serde(rename = "ConsumerStatus")]
-    pub consumer_status: String,
+    pub consumer_status: ConsumerStatus,
 }
 
 /// <p>An object that represents the details of a registered consumer.</p>
@@ -63,18 +68,121 @@ pub struct ConsumerDescription {
     pub consumer_arn: String,
     /// <p><p/></p>
     #[serde(rename = "ConsumerCreationTimestamp")]
-    pub consumer_creation_timestamp: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub consumer_creation_timestamp: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The name of the consumer is something you choose when you register the consumer.</p>
     #[serde(rename = "ConsumerName")]
     pub consumer_name: String,
     /// <p>A consumer can't read data while in the <code>CREATING</code> or <code>DELETING</code> states.</p>
     #[serde(rename = "ConsumerStatus")]
-    pub consumer_status: String,
+    pub consumer_status: ConsumerStatus,
     /// <p>The ARN of the stream with which you registered the consumer.</p>
     #[serde(rename = "StreamARN")]
     pub stream_arn: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ConsumerStatus {
+    /// `CREATING`
+    Creating,
+    /// `DELETING`
+    Deleting,
+    /// `ACTIVE`
+    Active,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ConsumerStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ConsumerStatus::Creating => "CREATING",
+            ConsumerStatus::Deleting => "DELETING",
+            ConsumerStatus::Active => "ACTIVE",
+            ConsumerStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ConsumerStatus {
+    fn default() -> ConsumerStatus {
+        ConsumerStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ConsumerStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ConsumerStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ConsumerStatus {
+    fn from(value: &'a str) -> ConsumerStatus {
+        match value {
+            "CREATING" => ConsumerStatus::Creating,
+            "DELETING" => ConsumerStatus::Deleting,
+            "ACTIVE" => ConsumerStatus::Active,
+            _ => ConsumerStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ConsumerStatus {
+    fn from(value: String) -> ConsumerStatus {
+        match value.as_str() {
+            "CREATING" => ConsumerStatus::Creating,
+            "DELETING" => ConsumerStatus::Deleting,
+            "ACTIVE" => ConsumerStatus::Active,
+            _ => ConsumerStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ConsumerStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ConsumerStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ConsumerStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ConsumerStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ConsumerStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ConsumerStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents the input for <code>CreateStream</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -217,7 +325,7 @@ pub struct DescribeStreamSummaryOutput {
 pub struct DisableEnhancedMonitoringInput {
     /// <p>List of shard-level metrics to disable.</p> <p>The following are the valid shard-level metrics. The value "<code>ALL</code>" disables every metric.</p> <ul> <li> <p> <code>IncomingBytes</code> </p> </li> <li> <p> <code>IncomingRecords</code> </p> </li> <li> <p> <code>OutgoingBytes</code> </p> </li> <li> <p> <code>OutgoingRecords</code> </p> </li> <li> <p> <code>WriteProvisionedThroughputExceeded</code> </p> </li> <li> <p> <code>ReadProvisionedThroughputExceeded</code> </p> </li> <li> <p> <code>IteratorAgeMilliseconds</code> </p> </li> <li> <p> <code>ALL</code> </p> </li> </ul> <p>For more information, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/monitoring-with-cloudwatch.html">Monitoring the Amazon Kinesis Data Streams Service with Amazon CloudWatch</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>.</p>
     #[serde(rename = "ShardLevelMetrics")]
-    pub shard_level_metrics: Vec<String>,
+    pub shard_level_metrics: Vec<MetricsName>,
     /// <p>The name of the Kinesis data stream for which to disable enhanced monitoring.</p>
     #[serde(rename = "StreamName")]
     pub stream_name: String,
@@ -229,12 +337,105 @@ pub struct DisableEnhancedMonitoringInput {
 pub struct EnableEnhancedMonitoringInput {
     /// <p>List of shard-level metrics to enable.</p> <p>The following are the valid shard-level metrics. The value "<code>ALL</code>" enables every metric.</p> <ul> <li> <p> <code>IncomingBytes</code> </p> </li> <li> <p> <code>IncomingRecords</code> </p> </li> <li> <p> <code>OutgoingBytes</code> </p> </li> <li> <p> <code>OutgoingRecords</code> </p> </li> <li> <p> <code>WriteProvisionedThroughputExceeded</code> </p> </li> <li> <p> <code>ReadProvisionedThroughputExceeded</code> </p> </li> <li> <p> <code>IteratorAgeMilliseconds</code> </p> </li> <li> <p> <code>ALL</code> </p> </li> </ul> <p>For more information, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/monitoring-with-cloudwatch.html">Monitoring the Amazon Kinesis Data Streams Service with Amazon CloudWatch</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>.</p>
     #[serde(rename = "ShardLevelMetrics")]
-    pub shard_level_metrics: Vec<String>,
+    pub shard_level_metrics: Vec<MetricsName>,
     /// <p>The name of the stream for which to enable enhanced monitoring.</p>
     #[serde(rename = "StreamName")]
     pub stream_name: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EncryptionType {
+    /// `NONE`
+    None,
+    /// `KMS`
+    Kms,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EncryptionType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EncryptionType::None => "NONE",
+            EncryptionType::Kms => "KMS",
+            EncryptionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EncryptionType {
+    fn default() -> EncryptionType {
+        EncryptionType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EncryptionType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EncryptionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EncryptionType {
+    fn from(value: &'a str) -> EncryptionType {
+        match value {
+            "NONE" => EncryptionType::None,
+            "KMS" => EncryptionType::Kms,
+            _ => EncryptionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EncryptionType {
+    fn from(value: String) -> EncryptionType {
+        match value.as_str() {
+            "NONE" => EncryptionType::None,
+            "KMS" => EncryptionType::Kms,
+            _ => EncryptionType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EncryptionType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EncryptionType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EncryptionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EncryptionType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EncryptionType {
+    fn deserialize<D>(deserializer: D) -> Result<EncryptionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents enhanced metrics types.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -242,7 +443,7 @@ pub struct EnhancedMetrics {
     /// <p>List of shard-level metrics.</p> <p>The following are the valid shard-level metrics. The value "<code>ALL</code>" enhances every metric.</p> <ul> <li> <p> <code>IncomingBytes</code> </p> </li> <li> <p> <code>IncomingRecords</code> </p> </li> <li> <p> <code>OutgoingBytes</code> </p> </li> <li> <p> <code>OutgoingRecords</code> </p> </li> <li> <p> <code>WriteProvisionedThroughputExceeded</code> </p> </li> <li> <p> <code>ReadProvisionedThroughputExceeded</code> </p> </li> <li> <p> <code>IteratorAgeMilliseconds</code> </p> </li> <li> <p> <code>ALL</code> </p> </li> </ul> <p>For more information, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/monitoring-with-cloudwatch.html">Monitoring the Amazon Kinesis Data Streams Service with Amazon CloudWatch</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>.</p>
     #[serde(rename = "ShardLevelMetrics")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub shard_level_metrics: Option<Vec<String>>,
+    pub shard_level_metrics: Option<Vec<MetricsName>>,
 }
 
 /// <p>Represents the output for <a>EnableEnhancedMonitoring</a> and <a>DisableEnhancedMonitoring</a>.</p>
@@ -252,11 +453,11 @@ pub struct EnhancedMonitoringOutput {
     /// <p>Represents the current state of the metrics that are in the enhanced state before the operation.</p>
     #[serde(rename = "CurrentShardLevelMetrics")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub current_shard_level_metrics: Option<Vec<String>>,
+    pub current_shard_level_metrics: Option<Vec<MetricsName>>,
     /// <p>Represents the list of all the metrics that would be in the enhanced state after the operation.</p>
     #[serde(rename = "DesiredShardLevelMetrics")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub desired_shard_level_metrics: Option<Vec<String>>,
+    pub desired_shard_level_metrics: Option<Vec<MetricsName>>,
     /// <p>The name of the Kinesis data stream.</p>
     #[serde(rename = "StreamName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -315,7 +516,7 @@ pub struct GetShardIteratorInput {
     pub shard_id: String,
     /// <p><p>Determines how the shard iterator is used to start reading data records from the shard.</p> <p>The following are the valid Amazon Kinesis shard iterator types:</p> <ul> <li> <p>AT<em>SEQUENCE</em>NUMBER - Start reading from the position denoted by a specific sequence number, provided in the value <code>StartingSequenceNumber</code>.</p> </li> <li> <p>AFTER<em>SEQUENCE</em>NUMBER - Start reading right after the position denoted by a specific sequence number, provided in the value <code>StartingSequenceNumber</code>.</p> </li> <li> <p>AT<em>TIMESTAMP - Start reading from the position denoted by a specific time stamp, provided in the value <code>Timestamp</code>.</p> </li> <li> <p>TRIM</em>HORIZON - Start reading at the last untrimmed record in the shard in the system, which is the oldest data record in the shard.</p> </li> <li> <p>LATEST - Start reading just after the most recent record in the shard, so that you always read the most recent data in the shard.</p> </li> </ul></p>
     #[serde(rename = "ShardIteratorType")]
-    pub shard_iterator_type: String,
+    pub shard_iterator_type: ShardIteratorType,
     /// <p>The sequence number of the data record in the shard from which to start reading. Used with shard iterator type AT_SEQUENCE_NUMBER and AFTER_SEQUENCE_NUMBER.</p>
     #[serde(rename = "StartingSequenceNumber")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -325,8 +526,13 @@ pub struct GetShardIteratorInput {
     pub stream_name: String,
     /// <p>The time stamp of the data record from which to start reading. Used with shard iterator type AT_TIMESTAMP. A time stamp is the Unix epoch date with precision in milliseconds. For example, <code>2016-04-04T19:58:46.480-00:00</code> or <code>1459799926.480</code>. If a record with this exact time stamp does not exist, the iterator returned is for the next (later) record. If the time stamp is older than the current trim horizon, the iterator returned is for the oldest untrimmed data record (TRIM_HORIZON).</p>
     #[serde(rename = "Timestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub timestamp: Option<f64>,
+    pub timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>Represents the output for <code>GetShardIterator</code>.</p>
@@ -462,8 +668,13 @@ pub struct ListShardsInput {
     pub next_token: Option<String>,
     /// <p>Specify this input parameter to distinguish data streams that have the same name. For example, if you create a data stream and then delete it, and you later create another data stream with the same name, you can use this input parameter to specify which of the two streams you want to list the shards for.</p> <p>You cannot specify this parameter if you specify the <code>NextToken</code> parameter.</p>
     #[serde(rename = "StreamCreationTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stream_creation_timestamp: Option<f64>,
+    pub stream_creation_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the data stream whose shards you want to list. </p> <p>You cannot specify this parameter if you specify the <code>NextToken</code> parameter.</p>
     #[serde(rename = "StreamName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -499,8 +710,13 @@ pub struct ListStreamConsumersInput {
     pub stream_arn: String,
     /// <p>Specify this input parameter to distinguish data streams that have the same name. For example, if you create a data stream and then delete it, and you later create another data stream with the same name, you can use this input parameter to specify which of the two streams you want to list the consumers for. </p> <p>You can't specify this parameter if you specify the NextToken parameter. </p>
     #[serde(rename = "StreamCreationTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stream_creation_timestamp: Option<f64>,
+    pub stream_creation_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -586,6 +802,129 @@ pub struct MergeShardsInput {
     pub stream_name: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MetricsName {
+    /// `IncomingBytes`
+    IncomingBytes,
+    /// `IncomingRecords`
+    IncomingRecords,
+    /// `OutgoingBytes`
+    OutgoingBytes,
+    /// `OutgoingRecords`
+    OutgoingRecords,
+    /// `WriteProvisionedThroughputExceeded`
+    WriteProvisionedThroughputExceeded,
+    /// `ReadProvisionedThroughputExceeded`
+    ReadProvisionedThroughputExceeded,
+    /// `IteratorAgeMilliseconds`
+    IteratorAgeMilliseconds,
+    /// `ALL`
+    All,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl MetricsName {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MetricsName::IncomingBytes => "IncomingBytes",
+            MetricsName::IncomingRecords => "IncomingRecords",
+            MetricsName::OutgoingBytes => "OutgoingBytes",
+            MetricsName::OutgoingRecords => "OutgoingRecords",
+            MetricsName::WriteProvisionedThroughputExceeded => "WriteProvisionedThroughputExceeded",
+            MetricsName::ReadProvisionedThroughputExceeded => "ReadProvisionedThroughputExceeded",
+            MetricsName::IteratorAgeMilliseconds => "IteratorAgeMilliseconds",
+            MetricsName::All => "ALL",
+            MetricsName::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for MetricsName {
+    fn default() -> MetricsName {
+        MetricsName::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for MetricsName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for MetricsName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for MetricsName {
+    fn from(value: &'a str) -> MetricsName {
+        match value {
+            "IncomingBytes" => MetricsName::IncomingBytes,
+            "IncomingRecords" => MetricsName::IncomingRecords,
+            "OutgoingBytes" => MetricsName::OutgoingBytes,
+            "OutgoingRecords" => MetricsName::OutgoingRecords,
+            "WriteProvisionedThroughputExceeded" => MetricsName::WriteProvisionedThroughputExceeded,
+            "ReadProvisionedThroughputExceeded" => MetricsName::ReadProvisionedThroughputExceeded,
+            "IteratorAgeMilliseconds" => MetricsName::IteratorAgeMilliseconds,
+            "ALL" => MetricsName::All,
+            _ => MetricsName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MetricsName {
+    fn from(value: String) -> MetricsName {
+        match value.as_str() {
+            "IncomingBytes" => MetricsName::IncomingBytes,
+            "IncomingRecords" => MetricsName::IncomingRecords,
+            "OutgoingBytes" => MetricsName::OutgoingBytes,
+            "OutgoingRecords" => MetricsName::OutgoingRecords,
+            "WriteProvisionedThroughputExceeded" => MetricsName::WriteProvisionedThroughputExceeded,
+            "ReadProvisionedThroughputExceeded" => MetricsName::ReadProvisionedThroughputExceeded,
+            "IteratorAgeMilliseconds" => MetricsName::IteratorAgeMilliseconds,
+            "ALL" => MetricsName::All,
+            _ => MetricsName::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for MetricsName {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<MetricsName, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MetricsName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for MetricsName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MetricsName {
+    fn deserialize<D>(deserializer: D) -> Result<MetricsName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The request rate for the stream is too high, or the requested data is too large for the available throughput. Reduce the frequency or size of your requests. For more information, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/service-sizes-and-limits.html">Streams Limits</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>, and <a href="http://docs.aws.amazon.com/general/latest/gr/api-retries.html">Error Retries and Exponential Backoff in AWS</a> in the <i>AWS General Reference</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct ProvisionedThroughputExceededException {
@@ -628,7 +967,7 @@ pub struct PutRecordOutput {
     /// <p><p>The encryption type to use on the record. This parameter can be one of the following values:</p> <ul> <li> <p> <code>NONE</code>: Do not encrypt the records in the stream.</p> </li> <li> <p> <code>KMS</code>: Use server-side encryption on the records in the stream using a customer-managed AWS KMS key.</p> </li> </ul></p>
     #[serde(rename = "EncryptionType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub encryption_type: Option<String>,
+    pub encryption_type: Option<EncryptionType>,
     /// <p>The sequence number identifier that was assigned to the put data record. The sequence number for the record is unique across all records in the stream. A sequence number is the identifier associated with every record put into the stream.</p>
     #[serde(rename = "SequenceNumber")]
     pub sequence_number: String,
@@ -656,7 +995,7 @@ pub struct PutRecordsOutput {
     /// <p><p>The encryption type used on the records. This parameter can be one of the following values:</p> <ul> <li> <p> <code>NONE</code>: Do not encrypt the records.</p> </li> <li> <p> <code>KMS</code>: Use server-side encryption on the records using a customer-managed AWS KMS key.</p> </li> </ul></p>
     #[serde(rename = "EncryptionType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub encryption_type: Option<String>,
+    pub encryption_type: Option<EncryptionType>,
     /// <p>The number of unsuccessfully processed records in a <code>PutRecords</code> request.</p>
     #[serde(rename = "FailedRecordCount")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -715,8 +1054,13 @@ pub struct PutRecordsResultEntry {
 pub struct Record {
     /// <p>The approximate time that the record was inserted into the stream.</p>
     #[serde(rename = "ApproximateArrivalTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub approximate_arrival_timestamp: Option<f64>,
+    pub approximate_arrival_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The data blob. The data in the blob is both opaque and immutable to Kinesis Data Streams, which does not inspect, interpret, or change the data in the blob in any way. When the data blob (the payload before base64-encoding) is added to the partition key size, the total size must not exceed the maximum record size (1 MB).</p>
     #[serde(rename = "Data")]
     #[serde(
@@ -728,7 +1072,7 @@ pub struct Record {
     /// <p><p>The encryption type used on the record. This parameter can be one of the following values:</p> <ul> <li> <p> <code>NONE</code>: Do not encrypt the records in the stream.</p> </li> <li> <p> <code>KMS</code>: Use server-side encryption on the records in the stream using a customer-managed AWS KMS key.</p> </li> </ul></p>
     #[serde(rename = "EncryptionType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub encryption_type: Option<String>,
+    pub encryption_type: Option<EncryptionType>,
     /// <p>Identifies which shard in the stream the data record is assigned to.</p>
     #[serde(rename = "PartitionKey")]
     pub partition_key: String,
@@ -788,6 +1132,94 @@ pub struct ResourceNotFoundException {
     pub message: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ScalingType {
+    /// `UNIFORM_SCALING`
+    UniformScaling,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ScalingType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ScalingType::UniformScaling => "UNIFORM_SCALING",
+            ScalingType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ScalingType {
+    fn default() -> ScalingType {
+        ScalingType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ScalingType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ScalingType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ScalingType {
+    fn from(value: &'a str) -> ScalingType {
+        match value {
+            "UNIFORM_SCALING" => ScalingType::UniformScaling,
+            _ => ScalingType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ScalingType {
+    fn from(value: String) -> ScalingType {
+        match value.as_str() {
+            "UNIFORM_SCALING" => ScalingType::UniformScaling,
+            _ => ScalingType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ScalingType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ScalingType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ScalingType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ScalingType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ScalingType {
+    fn deserialize<D>(deserializer: D) -> Result<ScalingType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The range of possible sequence numbers for the shard.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -824,6 +1256,114 @@ pub struct Shard {
     pub shard_id: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ShardIteratorType {
+    /// `AT_SEQUENCE_NUMBER`
+    AtSequenceNumber,
+    /// `AFTER_SEQUENCE_NUMBER`
+    AfterSequenceNumber,
+    /// `TRIM_HORIZON`
+    TrimHorizon,
+    /// `LATEST`
+    Latest,
+    /// `AT_TIMESTAMP`
+    AtTimestamp,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ShardIteratorType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ShardIteratorType::AtSequenceNumber => "AT_SEQUENCE_NUMBER",
+            ShardIteratorType::AfterSequenceNumber => "AFTER_SEQUENCE_NUMBER",
+            ShardIteratorType::TrimHorizon => "TRIM_HORIZON",
+            ShardIteratorType::Latest => "LATEST",
+            ShardIteratorType::AtTimestamp => "AT_TIMESTAMP",
+            ShardIteratorType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ShardIteratorType {
+    fn default() -> ShardIteratorType {
+        ShardIteratorType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ShardIteratorType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ShardIteratorType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ShardIteratorType {
+    fn from(value: &'a str) -> ShardIteratorType {
+        match value {
+            "AT_SEQUENCE_NUMBER" => ShardIteratorType::AtSequenceNumber,
+            "AFTER_SEQUENCE_NUMBER" => ShardIteratorType::AfterSequenceNumber,
+            "TRIM_HORIZON" => ShardIteratorType::TrimHorizon,
+            "LATEST" => ShardIteratorType::Latest,
+            "AT_TIMESTAMP" => ShardIteratorType::AtTimestamp,
+            _ => ShardIteratorType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ShardIteratorType {
+    fn from(value: String) -> ShardIteratorType {
+        match value.as_str() {
+            "AT_SEQUENCE_NUMBER" => ShardIteratorType::AtSequenceNumber,
+            "AFTER_SEQUENCE_NUMBER" => ShardIteratorType::AfterSequenceNumber,
+            "TRIM_HORIZON" => ShardIteratorType::TrimHorizon,
+            "LATEST" => ShardIteratorType::Latest,
+            "AT_TIMESTAMP" => ShardIteratorType::AtTimestamp,
+            _ => ShardIteratorType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ShardIteratorType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ShardIteratorType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ShardIteratorType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ShardIteratorType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ShardIteratorType {
+    fn deserialize<D>(deserializer: D) -> Result<ShardIteratorType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents the input for <code>SplitShard</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -844,7 +1384,7 @@ pub struct SplitShardInput {
 pub struct StartStreamEncryptionInput {
     /// <p>The encryption type to use. The only valid value is <code>KMS</code>.</p>
     #[serde(rename = "EncryptionType")]
-    pub encryption_type: String,
+    pub encryption_type: EncryptionType,
     /// <p><p>The GUID for the customer-managed AWS KMS key to use for encryption. This value can be a globally unique identifier, a fully specified Amazon Resource Name (ARN) to either an alias or a key, or an alias name prefixed by &quot;alias/&quot;.You can also use a master key owned by Kinesis Data Streams by specifying the alias <code>aws/kinesis</code>.</p> <ul> <li> <p>Key ARN example: <code>arn:aws:kms:us-east-1:123456789012:key/12345678-1234-1234-1234-123456789012</code> </p> </li> <li> <p>Alias ARN example: <code>arn:aws:kms:us-east-1:123456789012:alias/MyAliasName</code> </p> </li> <li> <p>Globally unique key ID example: <code>12345678-1234-1234-1234-123456789012</code> </p> </li> <li> <p>Alias name example: <code>alias/MyAliasName</code> </p> </li> <li> <p>Master key owned by Kinesis Data Streams: <code>alias/aws/kinesis</code> </p> </li> </ul></p>
     #[serde(rename = "KeyId")]
     pub key_id: String,
@@ -860,10 +1400,15 @@ pub struct StartingPosition {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sequence_number: Option<String>,
     #[serde(rename = "Timestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub timestamp: Option<f64>,
+    pub timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: ShardIteratorType,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -871,7 +1416,7 @@ pub struct StartingPosition {
 pub struct StopStreamEncryptionInput {
     /// <p>The encryption type. The only valid value is <code>KMS</code>.</p>
     #[serde(rename = "EncryptionType")]
-    pub encryption_type: String,
+    pub encryption_type: EncryptionType,
     /// <p><p>The GUID for the customer-managed AWS KMS key to use for encryption. This value can be a globally unique identifier, a fully specified Amazon Resource Name (ARN) to either an alias or a key, or an alias name prefixed by &quot;alias/&quot;.You can also use a master key owned by Kinesis Data Streams by specifying the alias <code>aws/kinesis</code>.</p> <ul> <li> <p>Key ARN example: <code>arn:aws:kms:us-east-1:123456789012:key/12345678-1234-1234-1234-123456789012</code> </p> </li> <li> <p>Alias ARN example: <code>arn:aws:kms:us-east-1:123456789012:alias/MyAliasName</code> </p> </li> <li> <p>Globally unique key ID example: <code>12345678-1234-1234-1234-123456789012</code> </p> </li> <li> <p>Alias name example: <code>alias/MyAliasName</code> </p> </li> <li> <p>Master key owned by Kinesis Data Streams: <code>alias/aws/kinesis</code> </p> </li> </ul></p>
     #[serde(rename = "KeyId")]
     pub key_id: String,
@@ -887,7 +1432,7 @@ pub struct StreamDescription {
     /// <p><p>The server-side encryption type used on the stream. This parameter can be one of the following values:</p> <ul> <li> <p> <code>NONE</code>: Do not encrypt the records in the stream.</p> </li> <li> <p> <code>KMS</code>: Use server-side encryption on the records in the stream using a customer-managed AWS KMS key.</p> </li> </ul></p>
     #[serde(rename = "EncryptionType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub encryption_type: Option<String>,
+    pub encryption_type: Option<EncryptionType>,
     /// <p>Represents the current enhanced monitoring settings of the stream.</p>
     #[serde(rename = "EnhancedMonitoring")]
     pub enhanced_monitoring: Vec<EnhancedMetrics>,
@@ -909,13 +1454,18 @@ pub struct StreamDescription {
     pub stream_arn: String,
     /// <p>The approximate time that the stream was created.</p>
     #[serde(rename = "StreamCreationTimestamp")]
-    pub stream_creation_timestamp: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub stream_creation_timestamp: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The name of the stream being described.</p>
     #[serde(rename = "StreamName")]
     pub stream_name: String,
     /// <p><p>The current status of the stream being described. The stream status is one of the following states:</p> <ul> <li> <p> <code>CREATING</code> - The stream is being created. Kinesis Data Streams immediately returns and sets <code>StreamStatus</code> to <code>CREATING</code>.</p> </li> <li> <p> <code>DELETING</code> - The stream is being deleted. The specified stream is in the <code>DELETING</code> state until Kinesis Data Streams completes the deletion.</p> </li> <li> <p> <code>ACTIVE</code> - The stream exists and is ready for read and write operations or deletion. You should perform read and write operations only on an <code>ACTIVE</code> stream.</p> </li> <li> <p> <code>UPDATING</code> - Shards in the stream are being merged or split. Read and write operations continue to work while the stream is in the <code>UPDATING</code> state.</p> </li> </ul></p>
     #[serde(rename = "StreamStatus")]
-    pub stream_status: String,
+    pub stream_status: StreamStatus,
 }
 
 /// <p>Represents the output for <a>DescribeStreamSummary</a> </p>
@@ -929,7 +1479,7 @@ pub struct StreamDescriptionSummary {
     /// <p><p>The encryption type used. This value is one of the following:</p> <ul> <li> <p> <code>KMS</code> </p> </li> <li> <p> <code>NONE</code> </p> </li> </ul></p>
     #[serde(rename = "EncryptionType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub encryption_type: Option<String>,
+    pub encryption_type: Option<EncryptionType>,
     /// <p>Represents the current enhanced monitoring settings of the stream.</p>
     #[serde(rename = "EnhancedMonitoring")]
     pub enhanced_monitoring: Vec<EnhancedMetrics>,
@@ -948,15 +1498,123 @@ pub struct StreamDescriptionSummary {
     pub stream_arn: String,
     /// <p>The approximate time that the stream was created.</p>
     #[serde(rename = "StreamCreationTimestamp")]
-    pub stream_creation_timestamp: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub stream_creation_timestamp: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The name of the stream being described.</p>
     #[serde(rename = "StreamName")]
     pub stream_name: String,
     /// <p><p>The current status of the stream being described. The stream status is one of the following states:</p> <ul> <li> <p> <code>CREATING</code> - The stream is being created. Kinesis Data Streams immediately returns and sets <code>StreamStatus</code> to <code>CREATING</code>.</p> </li> <li> <p> <code>DELETING</code> - The stream is being deleted. The specified stream is in the <code>DELETING</code> state until Kinesis Data Streams completes the deletion.</p> </li> <li> <p> <code>ACTIVE</code> - The stream exists and is ready for read and write operations or deletion. You should perform read and write operations only on an <code>ACTIVE</code> stream.</p> </li> <li> <p> <code>UPDATING</code> - Shards in the stream are being merged or split. Read and write operations continue to work while the stream is in the <code>UPDATING</code> state.</p> </li> </ul></p>
     #[serde(rename = "StreamStatus")]
-    pub stream_status: String,
+    pub stream_status: StreamStatus,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StreamStatus {
+    /// `CREATING`
+    Creating,
+    /// `DELETING`
+    Deleting,
+    /// `ACTIVE`
+    Active,
+    /// `UPDATING`
+    Updating,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl StreamStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StreamStatus::Creating => "CREATING",
+            StreamStatus::Deleting => "DELETING",
+            StreamStatus::Active => "ACTIVE",
+            StreamStatus::Updating => "UPDATING",
+            StreamStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for StreamStatus {
+    fn default() -> StreamStatus {
+        StreamStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for StreamStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
 }
 
+impl fmt::Display for StreamStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for StreamStatus {
+    fn from(value: &'a str) -> StreamStatus {
+        match value {
+            "CREATING" => StreamStatus::Creating,
+            "DELETING" => StreamStatus::Deleting,
+            "ACTIVE" => StreamStatus::Active,
+            "UPDATING" => StreamStatus::Updating,
+            _ => StreamStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StreamStatus {
+    fn from(value: String) -> StreamStatus {
+        match value.as_str() {
+            "CREATING" => StreamStatus::Creating,
+            "DELETING" => StreamStatus::Deleting,
+            "ACTIVE" => StreamStatus::Active,
+            "UPDATING" => StreamStatus::Updating,
+            _ => StreamStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for StreamStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<StreamStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StreamStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for StreamStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StreamStatus {
+    fn deserialize<D>(deserializer: D) -> Result<StreamStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>After you call <a>SubscribeToShard</a>, Kinesis Data Streams sends events of this type to your consumer. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -972,38 +1630,9 @@ pub struct SubscribeToShardEvent {
     pub records: Vec<Record>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[derive(Default, Debug, Clone, PartialEq)]
 pub struct SubscribeToShardEventStream {
-    #[serde(rename = "InternalFailureException")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub internal_failure_exception: Option<InternalFailureException>,
-    #[serde(rename = "KMSAccessDeniedException")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub kms_access_denied_exception: Option<KMSAccessDeniedException>,
-    #[serde(rename = "KMSDisabledException")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub kms_disabled_exception: Option<KMSDisabledException>,
-    #[serde(rename = "KMSInvalidStateException")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub kms_invalid_state_exception: Option<KMSInvalidStateException>,
-    #[serde(rename = "KMSNotFoundException")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub kms_not_found_exception: Option<KMSNotFoundException>,
-    #[serde(rename = "KMSOptInRequired")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub kms_opt_in_required: Option<KMSOptInRequired>,
-    #[serde(rename = "KMSThrottlingException")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub kms_throttling_exception: Option<KMSThrottlingException>,
-    #[serde(rename = "ResourceInUseException")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_in_use_exception: Option<ResourceInUseException>,
-    #[serde(rename = "ResourceNotFoundException")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_not_found_exception: Option<ResourceNotFoundException>,
-    #[serde(rename = "SubscribeToShardEvent")]
-    pub subscribe_to_shard_event: SubscribeToShardEvent,
+    pub subscribe_to_shard_event: Option<SubscribeToShardEvent>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -1019,12 +1648,10 @@ pub struct SubscribeToShardInput {
     pub starting_position: StartingPosition,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[derive(Default, Debug)]
 pub struct SubscribeToShardOutput {
     /// <p>The event stream that your consumer can use to read records from the shard.</p>
-    #[serde(rename = "EventStream")]
-    pub event_stream: SubscribeToShardEventStream,
+    pub event_stream: Option<::rusoto_core::event_stream::EventStream<SubscribeToShardEventStream>>,
 }
 
 /// <p>Metadata assigned to the stream, consisting of a key-value pair.</p>
@@ -1045,7 +1672,7 @@ pub struct Tag {
 pub struct UpdateShardCountInput {
     /// <p>The scaling type. Uniform scaling creates shards of equal size.</p>
     #[serde(rename = "ScalingType")]
-    pub scaling_type: String,
+    pub scaling_type: ScalingType,
     /// <p>The name of the stream.</p>
     #[serde(rename = "StreamName")]
     pub stream_name: String,
@@ -2561,6 +3188,30 @@ impl fmt::Display for UpdateShardCountError {
     }
 }
 impl Error for UpdateShardCountError {}
+impl rusoto_core::event_stream::DeserializeEvent for SubscribeToShardEventStream {
+    type Error = SubscribeToShardError;
+
+    #[allow(unused_variables)]
+    fn deserialize_event(
+        event_type: &str,
+        message: &rusoto_core::event_stream::Message,
+    ) -> Result<SubscribeToShardEventStream, rusoto_core::event_stream::EventStreamError> {
+        let mut event = SubscribeToShardEventStream::default();
+        match event_type {
+            "SubscribeToShardEvent" => {
+                event.subscribe_to_shard_event = Some(serde_json::from_slice(&message.payload)?);
+            }
+            _ => {}
+        }
+        Ok(event)
+    }
+
+    fn deserialize_error(
+        error: rusoto_core::event_stream::ErrorMessage,
+    ) -> RusotoError<SubscribeToShardError> {
+        SubscribeToShardError::from_response(error.into_json_response())
+    }
+}
 /// Trait representing the capabilities of the Kinesis API. Kinesis clients implement this trait.
 #[async_trait]
 pub trait Kinesis {
@@ -2739,11 +3390,12 @@ pub struct KinesisClient {
 impl KinesisClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> KinesisClient {
         KinesisClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Kinesis"),
         }
     }
 
@@ -2775,6 +3427,7 @@ impl Kinesis for KinesisClient {
         input: AddTagsToStreamInput,
     ) -> Result<(), RusotoError<AddTagsToStreamError>> {
         let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("AddTagsToStream");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Kinesis_20131202.AddTagsToStream");
@@ -2802,6 +3455,7 @@ impl Kinesis for KinesisClient {
         input: CreateStreamInput,
     ) -> Result<(), RusotoError<CreateStreamError>> {
         let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("CreateStream");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Kinesis_20131202.CreateStream");
@@ -2829,6 +3483,7 @@ impl Kinesis for KinesisClient {
         input: DecreaseStreamRetentionPeriodInput,
     ) -> Result<(), RusotoError<DecreaseStreamRetentionPeriodError>> {
         let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("DecreaseStreamRetentionPeriod");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2859,6 +3514,7 @@ impl Kinesis for KinesisClient {
         input: DeleteStreamInput,
     ) -> Result<(), RusotoError<DeleteStreamError>> {
         let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("DeleteStream");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Kinesis_20131202.DeleteStream");
@@ -2886,6 +3542,7 @@ impl Kinesis for KinesisClient {
         input: DeregisterStreamConsumerInput,
     ) -> Result<(), RusotoError<DeregisterStreamConsumerError>> {
         let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("DeregisterStreamConsumer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Kinesis_20131202.DeregisterStreamConsumer");
@@ -2912,6 +3569,7 @@ impl Kinesis for KinesisClient {
         &self,
     ) -> Result<DescribeLimitsOutput, RusotoError<DescribeLimitsError>> {
         let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("DescribeLimits");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Kinesis_20131202.DescribeLimits");
@@ -2938,6 +3596,7 @@ impl Kinesis for KinesisClient {
         input: DescribeStreamInput,
     ) -> Result<DescribeStreamOutput, RusotoError<DescribeStreamError>> {
         let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("DescribeStream");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Kinesis_20131202.DescribeStream");
@@ -2965,6 +3624,7 @@ impl Kinesis for KinesisClient {
         input: DescribeStreamConsumerInput,
     ) -> Result<DescribeStreamConsumerOutput, RusotoError<DescribeStreamConsumerError>> {
         let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("DescribeStreamConsumer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Kinesis_20131202.DescribeStreamConsumer");
@@ -2993,6 +3653,7 @@ impl Kinesis for KinesisClient {
         input: DescribeStreamSummaryInput,
     ) -> Result<DescribeStreamSummaryOutput, RusotoError<DescribeStreamSummaryError>> {
         let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("DescribeStreamSummary");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Kinesis_20131202.DescribeStreamSummary");
@@ -3021,6 +3682,7 @@ impl Kinesis for KinesisClient {
         input: DisableEnhancedMonitoringInput,
     ) -> Result<EnhancedMonitoringOutput, RusotoError<DisableEnhancedMonitoringError>> {
         let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("DisableEnhancedMonitoring");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Kinesis_20131202.DisableEnhancedMonitoring");
@@ -3049,6 +3711,7 @@ impl Kinesis for KinesisClient {
         input: EnableEnhancedMonitoringInput,
     ) -> Result<EnhancedMonitoringOutput, RusotoError<EnableEnhancedMonitoringError>> {
         let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("EnableEnhancedMonitoring");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Kinesis_20131202.EnableEnhancedMonitoring");
@@ -3077,6 +3740,7 @@ impl Kinesis for KinesisClient {
         input: GetRecordsInput,
     ) -> Result<GetRecordsOutput, RusotoError<GetRecordsError>> {
         let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("GetRecords");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Kinesis_20131202.GetRecords");
@@ -3104,6 +3768,7 @@ impl Kinesis for KinesisClient {
         input: GetShardIteratorInput,
     ) -> Result<GetShardIteratorOutput, RusotoError<GetShardIteratorError>> {
         let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("GetShardIterator");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Kinesis_20131202.GetShardIterator");
@@ -3131,6 +3796,7 @@ impl Kinesis for KinesisClient {
         input: IncreaseStreamRetentionPeriodInput,
     ) -> Result<(), RusotoError<IncreaseStreamRetentionPeriodError>> {
         let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("IncreaseStreamRetentionPeriod");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3161,6 +3827,7 @@ impl Kinesis for KinesisClient {
         input: ListShardsInput,
     ) -> Result<ListShardsOutput, RusotoError<ListShardsError>> {
         let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("ListShards");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Kinesis_20131202.ListShards");
@@ -3188,6 +3855,7 @@ impl Kinesis for KinesisClient {
         input: ListStreamConsumersInput,
     ) -> Result<ListStreamConsumersOutput, RusotoError<ListStreamConsumersError>> {
         let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("ListStreamConsumers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Kinesis_20131202.ListStreamConsumers");
@@ -3216,6 +3884,7 @@ impl Kinesis for KinesisClient {
         input: ListStreamsInput,
     ) -> Result<ListStreamsOutput, RusotoError<ListStreamsError>> {
         let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("ListStreams");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Kinesis_20131202.ListStreams");
@@ -3243,6 +3912,7 @@ impl Kinesis for KinesisClient {
         input: ListTagsForStreamInput,
     ) -> Result<ListTagsForStreamOutput, RusotoError<ListTagsForStreamError>> {
         let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("ListTagsForStream");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Kinesis_20131202.ListTagsForStream");
@@ -3270,6 +3940,7 @@ impl Kinesis for KinesisClient {
         input: MergeShardsInput,
     ) -> Result<(), RusotoError<MergeShardsError>> {
         let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("MergeShards");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Kinesis_20131202.MergeShards");
@@ -3297,6 +3968,7 @@ impl Kinesis for KinesisClient {
         input: PutRecordInput,
     ) -> Result<PutRecordOutput, RusotoError<PutRecordError>> {
         let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("PutRecord");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Kinesis_20131202.PutRecord");
@@ -3324,6 +3996,7 @@ impl Kinesis for KinesisClient {
         input: PutRecordsInput,
     ) -> Result<PutRecordsOutput, RusotoError<PutRecordsError>> {
         let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("PutRecords");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Kinesis_20131202.PutRecords");
@@ -3351,6 +4024,7 @@ impl Kinesis for KinesisClient {
         input: RegisterStreamConsumerInput,
     ) -> Result<RegisterStreamConsumerOutput, RusotoError<RegisterStreamConsumerError>> {
         let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("RegisterStreamConsumer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Kinesis_20131202.RegisterStreamConsumer");
@@ -3379,6 +4053,7 @@ impl Kinesis for KinesisClient {
         input: RemoveTagsFromStreamInput,
     ) -> Result<(), RusotoError<RemoveTagsFromStreamError>> {
         let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("RemoveTagsFromStream");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Kinesis_20131202.RemoveTagsFromStream");
@@ -3406,6 +4081,7 @@ impl Kinesis for KinesisClient {
         input: SplitShardInput,
     ) -> Result<(), RusotoError<SplitShardError>> {
         let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("SplitShard");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Kinesis_20131202.SplitShard");
@@ -3433,6 +4109,7 @@ impl Kinesis for KinesisClient {
         input: StartStreamEncryptionInput,
     ) -> Result<(), RusotoError<StartStreamEncryptionError>> {
         let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("StartStreamEncryption");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Kinesis_20131202.StartStreamEncryption");
@@ -3460,6 +4137,7 @@ impl Kinesis for KinesisClient {
         input: StopStreamEncryptionInput,
     ) -> Result<(), RusotoError<StopStreamEncryptionError>> {
         let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("StopStreamEncryption");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Kinesis_20131202.StopStreamEncryption");
@@ -3487,6 +4165,7 @@ impl Kinesis for KinesisClient {
         input: SubscribeToShardInput,
     ) -> Result<SubscribeToShardOutput, RusotoError<SubscribeToShardError>> {
         let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("SubscribeToShard");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Kinesis_20131202.SubscribeToShard");
@@ -3499,8 +4178,9 @@ impl Kinesis for KinesisClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<SubscribeToShardOutput, _>()
+            let mut result = SubscribeToShardOutput::default();
+            result.event_stream = Some(rusoto_core::event_stream::EventStream::new(response.body));
+            Ok(result)
         } else {
             let try_response = response.buffer().await;
             let response = try_response.map_err(RusotoError::HttpDispatch)?;
@@ -3514,6 +4194,7 @@ impl Kinesis for KinesisClient {
         input: UpdateShardCountInput,
     ) -> Result<UpdateShardCountOutput, RusotoError<UpdateShardCountError>> {
         let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("UpdateShardCount");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Kinesis_20131202.UpdateShardCount");
@@ -3535,3 +4216,178 @@ impl Kinesis for KinesisClient {
         }
     }
 }
+impl KinesisClient {
+    /// Auto-paginating version of `describe_stream`, streaming every page of results.
+    pub fn describe_stream_pages(
+        &self,
+        input: DescribeStreamInput,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeStreamOutput, DescribeStreamError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: KinesisClient, input: DescribeStreamInput| async move {
+                client.describe_stream(input).await
+            },
+            |input: &DescribeStreamInput, output: &DescribeStreamOutput| {
+                if !output.stream_description.has_more_shards {
+                    return None;
+                }
+                Some(DescribeStreamInput {
+                    exclusive_start_shard_id: Some(
+                        output
+                            .stream_description
+                            .shards
+                            .last()
+                            .map(|v| v.shard_id.clone())?,
+                    ),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_stream`, streaming the `StreamDescription.Shards` of every page of results.
+    pub fn describe_stream_items(
+        &self,
+        input: DescribeStreamInput,
+    ) -> rusoto_core::pagination::RusotoStream<Shard, DescribeStreamError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_stream_pages(input),
+            |output: DescribeStreamOutput| output.stream_description.shards,
+        )
+    }
+
+    /// Auto-paginating version of `list_shards`, streaming every page of results.
+    pub fn list_shards_pages(
+        &self,
+        input: ListShardsInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListShardsOutput, ListShardsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: KinesisClient, input: ListShardsInput| async move {
+                client.list_shards(input).await
+            },
+            |input: &ListShardsInput, output: &ListShardsOutput| {
+                Some(ListShardsInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_shards`, streaming the `Shards` of every page of results.
+    pub fn list_shards_items(
+        &self,
+        input: ListShardsInput,
+    ) -> rusoto_core::pagination::RusotoStream<Shard, ListShardsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_shards_pages(input),
+            |output: ListShardsOutput| output.shards.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_stream_consumers`, streaming every page of results.
+    pub fn list_stream_consumers_pages(
+        &self,
+        input: ListStreamConsumersInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListStreamConsumersOutput, ListStreamConsumersError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: KinesisClient, input: ListStreamConsumersInput| async move {
+                client.list_stream_consumers(input).await
+            },
+            |input: &ListStreamConsumersInput, output: &ListStreamConsumersOutput| {
+                Some(ListStreamConsumersInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_stream_consumers`, streaming the `Consumers` of every page of results.
+    pub fn list_stream_consumers_items(
+        &self,
+        input: ListStreamConsumersInput,
+    ) -> rusoto_core::pagination::RusotoStream<Consumer, ListStreamConsumersError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_stream_consumers_pages(input),
+            |output: ListStreamConsumersOutput| output.consumers.unwrap_or_default(),
+        )
+    }
+}
+impl KinesisClient {
+    /// Waits until `describe_stream` reports the `StreamExists` state, polling every 10 seconds up to 18 times.
+    pub async fn wait_until_stream_exists(
+        &self,
+        input: DescribeStreamInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeStreamError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(10), 18);
+        self.wait_until_stream_exists_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_stream_exists`, polling as often and as many times as `config` says.
+    pub async fn wait_until_stream_exists_with_config(
+        &self,
+        input: DescribeStreamInput,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeStreamError>> {
+        rusoto_core::waiter::wait(
+            "StreamExists",
+            &config,
+            || self.describe_stream(input.clone()),
+            |result: &Result<DescribeStreamOutput, RusotoError<DescribeStreamError>>| match result {
+                Ok(output)
+                    if std::iter::once(&output.stream_description)
+                        .map(|v| &v.stream_status)
+                        .any(|v| *v == StreamStatus::Active) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+
+    /// Waits until `describe_stream` reports the `StreamNotExists` state, polling every 10 seconds up to 18 times.
+    pub async fn wait_until_stream_not_exists(
+        &self,
+        input: DescribeStreamInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeStreamError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(10), 18);
+        self.wait_until_stream_not_exists_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_stream_not_exists`, polling as often and as many times as `config` says.
+    pub async fn wait_until_stream_not_exists_with_config(
+        &self,
+        input: DescribeStreamInput,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeStreamError>> {
+        rusoto_core::waiter::wait(
+            "StreamNotExists",
+            &config,
+            || self.describe_stream(input.clone()),
+            |result: &Result<DescribeStreamOutput, RusotoError<DescribeStreamError>>| match result {
+                Err(RusotoError::Service(DescribeStreamError::ResourceNotFound(_))) => {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Err(RusotoError::Unknown(response))
+                    if rusoto_core::retry::error_code(response).as_deref()
+                        == Some("ResourceNotFoundException") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+}
@@ -143,7 +143,7 @@ pub struct ApplicationDetail {
     pub application_name: String,
     /// <p>Status of the application.</p>
     #[serde(rename = "ApplicationStatus")]
-    pub application_status: String,
+    pub application_status: ApplicationStatus,
     /// <p>Provides the current application version.</p>
     #[serde(rename = "ApplicationVersionId")]
     pub application_version_id: i64,
@@ -153,16 +153,26 @@ pub struct ApplicationDetail {
     pub cloud_watch_logging_option_descriptions: Option<Vec<CloudWatchLoggingOptionDescription>>,
     /// <p>Time stamp when the application version was created.</p>
     #[serde(rename = "CreateTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub create_timestamp: Option<f64>,
+    pub create_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Describes the application input configuration. For more information, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/how-it-works-input.html">Configuring Application Input</a>. </p>
     #[serde(rename = "InputDescriptions")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub input_descriptions: Option<Vec<InputDescription>>,
     /// <p>Time stamp when the application was last updated.</p>
     #[serde(rename = "LastUpdateTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_update_timestamp: Option<f64>,
+    pub last_update_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Describes the application output configuration. For more information, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/how-it-works-output.html">Configuring Application Output</a>. </p>
     #[serde(rename = "OutputDescriptions")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -173,6 +183,119 @@ pub struct ApplicationDetail {
     pub reference_data_source_descriptions: Option<Vec<ReferenceDataSourceDescription>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ApplicationStatus {
+    /// `DELETING`
+    Deleting,
+    /// `STARTING`
+    Starting,
+    /// `STOPPING`
+    Stopping,
+    /// `READY`
+    Ready,
+    /// `RUNNING`
+    Running,
+    /// `UPDATING`
+    Updating,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ApplicationStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ApplicationStatus::Deleting => "DELETING",
+            ApplicationStatus::Starting => "STARTING",
+            ApplicationStatus::Stopping => "STOPPING",
+            ApplicationStatus::Ready => "READY",
+            ApplicationStatus::Running => "RUNNING",
+            ApplicationStatus::Updating => "UPDATING",
+            ApplicationStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ApplicationStatus {
+    fn default() -> ApplicationStatus {
+        ApplicationStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ApplicationStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ApplicationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ApplicationStatus {
+    fn from(value: &'a str) -> ApplicationStatus {
+        match value {
+            "DELETING" => ApplicationStatus::Deleting,
+            "STARTING" => ApplicationStatus::Starting,
+            "STOPPING" => ApplicationStatus::Stopping,
+            "READY" => ApplicationStatus::Ready,
+            "RUNNING" => ApplicationStatus::Running,
+            "UPDATING" => ApplicationStatus::Updating,
+            _ => ApplicationStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ApplicationStatus {
+    fn from(value: String) -> ApplicationStatus {
+        match value.as_str() {
+            "DELETING" => ApplicationStatus::Deleting,
+            "STARTING" => ApplicationStatus::Starting,
+            "STOPPING" => ApplicationStatus::Stopping,
+            "READY" => ApplicationStatus::Ready,
+            "RUNNING" => ApplicationStatus::Running,
+            "UPDATING" => ApplicationStatus::Updating,
+            _ => ApplicationStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ApplicationStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ApplicationStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ApplicationStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ApplicationStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ApplicationStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ApplicationStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p>Provides application summary information, including the application Amazon Resource Name (ARN), name, and status.</p></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -185,7 +308,7 @@ pub struct ApplicationSummary {
     pub application_name: String,
     /// <p>Status of the application.</p>
     #[serde(rename = "ApplicationStatus")]
-    pub application_status: String,
+    pub application_status: ApplicationStatus,
 }
 
 /// <p>Describes updates to apply to an existing Amazon Kinesis Analytics application.</p>
@@ -395,7 +518,12 @@ pub struct DeleteApplicationRequest {
     pub application_name: String,
     /// <p> You can use the <code>DescribeApplication</code> operation to get this value. </p>
     #[serde(rename = "CreateTimestamp")]
-    pub create_timestamp: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub create_timestamp: ::chrono::DateTime<::chrono::Utc>,
 }
 
 /// <p><p/></p>
@@ -426,7 +554,7 @@ pub struct DescribeApplicationResponse {
 pub struct DestinationSchema {
     /// <p>Specifies the format of the records on the output stream.</p>
     #[serde(rename = "RecordFormatType")]
-    pub record_format_type: String,
+    pub record_format_type: RecordFormatType,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -663,13 +791,111 @@ pub struct InputSchemaUpdate {
     pub record_format_update: Option<RecordFormat>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum InputStartingPosition {
+    /// `NOW`
+    Now,
+    /// `TRIM_HORIZON`
+    TrimHorizon,
+    /// `LAST_STOPPED_POINT`
+    LastStoppedPoint,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl InputStartingPosition {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InputStartingPosition::Now => "NOW",
+            InputStartingPosition::TrimHorizon => "TRIM_HORIZON",
+            InputStartingPosition::LastStoppedPoint => "LAST_STOPPED_POINT",
+            InputStartingPosition::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for InputStartingPosition {
+    fn default() -> InputStartingPosition {
+        InputStartingPosition::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for InputStartingPosition {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for InputStartingPosition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for InputStartingPosition {
+    fn from(value: &'a str) -> InputStartingPosition {
+        match value {
+            "NOW" => InputStartingPosition::Now,
+            "TRIM_HORIZON" => InputStartingPosition::TrimHorizon,
+            "LAST_STOPPED_POINT" => InputStartingPosition::LastStoppedPoint,
+            _ => InputStartingPosition::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InputStartingPosition {
+    fn from(value: String) -> InputStartingPosition {
+        match value.as_str() {
+            "NOW" => InputStartingPosition::Now,
+            "TRIM_HORIZON" => InputStartingPosition::TrimHorizon,
+            "LAST_STOPPED_POINT" => InputStartingPosition::LastStoppedPoint,
+            _ => InputStartingPosition::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for InputStartingPosition {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<InputStartingPosition, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for InputStartingPosition {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for InputStartingPosition {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InputStartingPosition {
+    fn deserialize<D>(deserializer: D) -> Result<InputStartingPosition, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes the point at which the application reads from the streaming source.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct InputStartingPositionConfiguration {
     /// <p><p>The starting position on the stream.</p> <ul> <li> <p> <code>NOW</code> - Start reading just after the most recent record in the stream, start at the request time stamp that the customer issued.</p> </li> <li> <p> <code>TRIM<em>HORIZON</code> - Start reading at the last untrimmed record in the stream, which is the oldest record available in the stream. This option is not available for an Amazon Kinesis Firehose delivery stream.</p> </li> <li> <p> <code>LAST</em>STOPPED_POINT</code> - Resume reading from where the application last stopped reading.</p> </li> </ul></p>
     #[serde(rename = "InputStartingPosition")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub input_starting_position: Option<String>,
+    pub input_starting_position: Option<InputStartingPosition>,
 }
 
 /// <p>Describes updates to a specific input configuration (identified by the <code>InputId</code> of an application). </p>
@@ -1076,9 +1302,102 @@ pub struct RecordFormat {
     pub mapping_parameters: Option<MappingParameters>,
     /// <p>The type of record format.</p>
     #[serde(rename = "RecordFormatType")]
-    pub record_format_type: String,
+    pub record_format_type: RecordFormatType,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RecordFormatType {
+    /// `JSON`
+    Json,
+    /// `CSV`
+    Csv,
+    /// A value not known to this version of the crate
+    Unknown(String),
 }
 
+impl RecordFormatType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RecordFormatType::Json => "JSON",
+            RecordFormatType::Csv => "CSV",
+            RecordFormatType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RecordFormatType {
+    fn default() -> RecordFormatType {
+        RecordFormatType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RecordFormatType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RecordFormatType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RecordFormatType {
+    fn from(value: &'a str) -> RecordFormatType {
+        match value {
+            "JSON" => RecordFormatType::Json,
+            "CSV" => RecordFormatType::Csv,
+            _ => RecordFormatType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RecordFormatType {
+    fn from(value: String) -> RecordFormatType {
+        match value.as_str() {
+            "JSON" => RecordFormatType::Json,
+            "CSV" => RecordFormatType::Csv,
+            _ => RecordFormatType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RecordFormatType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RecordFormatType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RecordFormatType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RecordFormatType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RecordFormatType {
+    fn deserialize<D>(deserializer: D) -> Result<RecordFormatType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes the reference data source by providing the source information (S3 bucket name and object key name), the resulting in-application table name that is created, and the necessary schema to map the data elements in the Amazon S3 object to the in-application table.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -2677,11 +2996,12 @@ pub struct KinesisAnalyticsClient {
 impl KinesisAnalyticsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> KinesisAnalyticsClient {
         KinesisAnalyticsClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Kinesis Analytics"),
         }
     }
 
@@ -2716,6 +3036,7 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         RusotoError<AddApplicationCloudWatchLoggingOptionError>,
     > {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("AddApplicationCloudWatchLoggingOption");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2749,6 +3070,7 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         input: AddApplicationInputRequest,
     ) -> Result<AddApplicationInputResponse, RusotoError<AddApplicationInputError>> {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("AddApplicationInput");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2783,6 +3105,7 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         RusotoError<AddApplicationInputProcessingConfigurationError>,
     > {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("AddApplicationInputProcessingConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2814,6 +3137,7 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         input: AddApplicationOutputRequest,
     ) -> Result<AddApplicationOutputResponse, RusotoError<AddApplicationOutputError>> {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("AddApplicationOutput");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2848,6 +3172,7 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         RusotoError<AddApplicationReferenceDataSourceError>,
     > {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("AddApplicationReferenceDataSource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2881,6 +3206,7 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         input: CreateApplicationRequest,
     ) -> Result<CreateApplicationResponse, RusotoError<CreateApplicationError>> {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("CreateApplication");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2912,6 +3238,7 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         input: DeleteApplicationRequest,
     ) -> Result<DeleteApplicationResponse, RusotoError<DeleteApplicationError>> {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("DeleteApplication");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2946,6 +3273,7 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         RusotoError<DeleteApplicationCloudWatchLoggingOptionError>,
     > {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("DeleteApplicationCloudWatchLoggingOption");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2980,6 +3308,7 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         RusotoError<DeleteApplicationInputProcessingConfigurationError>,
     > {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("DeleteApplicationInputProcessingConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3011,6 +3340,7 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         input: DeleteApplicationOutputRequest,
     ) -> Result<DeleteApplicationOutputResponse, RusotoError<DeleteApplicationOutputError>> {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("DeleteApplicationOutput");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3045,6 +3375,7 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         RusotoError<DeleteApplicationReferenceDataSourceError>,
     > {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("DeleteApplicationReferenceDataSource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3078,6 +3409,7 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         input: DescribeApplicationRequest,
     ) -> Result<DescribeApplicationResponse, RusotoError<DescribeApplicationError>> {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("DescribeApplication");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3109,6 +3441,7 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         input: DiscoverInputSchemaRequest,
     ) -> Result<DiscoverInputSchemaResponse, RusotoError<DiscoverInputSchemaError>> {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("DiscoverInputSchema");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3140,6 +3473,7 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         input: ListApplicationsRequest,
     ) -> Result<ListApplicationsResponse, RusotoError<ListApplicationsError>> {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("ListApplications");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "KinesisAnalytics_20150814.ListApplications");
@@ -3168,6 +3502,7 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3199,6 +3534,7 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         input: StartApplicationRequest,
     ) -> Result<StartApplicationResponse, RusotoError<StartApplicationError>> {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("StartApplication");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "KinesisAnalytics_20150814.StartApplication");
@@ -3227,6 +3563,7 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         input: StopApplicationRequest,
     ) -> Result<StopApplicationResponse, RusotoError<StopApplicationError>> {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("StopApplication");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "KinesisAnalytics_20150814.StopApplication");
@@ -3254,6 +3591,7 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "KinesisAnalytics_20150814.TagResource");
@@ -3281,6 +3619,7 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "KinesisAnalytics_20150814.UntagResource");
@@ -3308,6 +3647,7 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         input: UpdateApplicationRequest,
     ) -> Result<UpdateApplicationResponse, RusotoError<UpdateApplicationError>> {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("UpdateApplication");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
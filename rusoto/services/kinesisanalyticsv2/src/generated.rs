@@ -227,7 +227,7 @@ pub struct ApplicationCodeConfiguration {
     pub code_content: Option<CodeContent>,
     /// <p>Specifies whether the code content is in text or zip format.</p>
     #[serde(rename = "CodeContentType")]
-    pub code_content_type: String,
+    pub code_content_type: CodeContentType,
 }
 
 /// <p>Describes code configuration for a Java-based Kinesis Data Analytics application.</p>
@@ -240,7 +240,7 @@ pub struct ApplicationCodeConfigurationDescription {
     pub code_content_description: Option<CodeContentDescription>,
     /// <p>Specifies whether the code content is in text or zip format.</p>
     #[serde(rename = "CodeContentType")]
-    pub code_content_type: String,
+    pub code_content_type: CodeContentType,
 }
 
 /// <p>Describes updates to a Java-based Amazon Kinesis Data Analytics application.</p>
@@ -250,7 +250,7 @@ pub struct ApplicationCodeConfigurationUpdate {
     /// <p>Describes updates to the code content type.</p>
     #[serde(rename = "CodeContentTypeUpdate")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub code_content_type_update: Option<String>,
+    pub code_content_type_update: Option<CodeContentType>,
     /// <p>Describes updates to the code content of an application.</p>
     #[serde(rename = "CodeContentUpdate")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -372,7 +372,7 @@ pub struct ApplicationDetail {
     pub application_name: String,
     /// <p>The status of the application.</p>
     #[serde(rename = "ApplicationStatus")]
-    pub application_status: String,
+    pub application_status: ApplicationStatus,
     /// <p>Provides the current application version. Kinesis Data Analytics updates the <code>ApplicationVersionId</code> each time you update the application.</p>
     #[serde(rename = "ApplicationVersionId")]
     pub application_version_id: i64,
@@ -382,15 +382,25 @@ pub struct ApplicationDetail {
     pub cloud_watch_logging_option_descriptions: Option<Vec<CloudWatchLoggingOptionDescription>>,
     /// <p>The current timestamp when the application was created.</p>
     #[serde(rename = "CreateTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub create_timestamp: Option<f64>,
+    pub create_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The current timestamp when the application was last updated.</p>
     #[serde(rename = "LastUpdateTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_update_timestamp: Option<f64>,
+    pub last_update_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The runtime environment for the application (<code>SQL-1.0</code> or <code>FLINK-1_6</code>).</p>
     #[serde(rename = "RuntimeEnvironment")]
-    pub runtime_environment: String,
+    pub runtime_environment: RuntimeEnvironment,
     /// <p>Specifies the IAM role that the application uses to access external resources.</p>
     #[serde(rename = "ServiceExecutionRole")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -402,13 +412,111 @@ pub struct ApplicationDetail {
 pub struct ApplicationRestoreConfiguration {
     /// <p>Specifies how the application should be restored.</p>
     #[serde(rename = "ApplicationRestoreType")]
-    pub application_restore_type: String,
+    pub application_restore_type: ApplicationRestoreType,
     /// <p>The identifier of an existing snapshot of application state to use to restart an application. The application uses this value if <code>RESTORE_FROM_CUSTOM_SNAPSHOT</code> is specified for the <code>ApplicationRestoreType</code>.</p>
     #[serde(rename = "SnapshotName")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub snapshot_name: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ApplicationRestoreType {
+    /// `SKIP_RESTORE_FROM_SNAPSHOT`
+    SkipRestoreFromSnapshot,
+    /// `RESTORE_FROM_LATEST_SNAPSHOT`
+    RestoreFromLatestSnapshot,
+    /// `RESTORE_FROM_CUSTOM_SNAPSHOT`
+    RestoreFromCustomSnapshot,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ApplicationRestoreType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ApplicationRestoreType::SkipRestoreFromSnapshot => "SKIP_RESTORE_FROM_SNAPSHOT",
+            ApplicationRestoreType::RestoreFromLatestSnapshot => "RESTORE_FROM_LATEST_SNAPSHOT",
+            ApplicationRestoreType::RestoreFromCustomSnapshot => "RESTORE_FROM_CUSTOM_SNAPSHOT",
+            ApplicationRestoreType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ApplicationRestoreType {
+    fn default() -> ApplicationRestoreType {
+        ApplicationRestoreType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ApplicationRestoreType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ApplicationRestoreType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ApplicationRestoreType {
+    fn from(value: &'a str) -> ApplicationRestoreType {
+        match value {
+            "SKIP_RESTORE_FROM_SNAPSHOT" => ApplicationRestoreType::SkipRestoreFromSnapshot,
+            "RESTORE_FROM_LATEST_SNAPSHOT" => ApplicationRestoreType::RestoreFromLatestSnapshot,
+            "RESTORE_FROM_CUSTOM_SNAPSHOT" => ApplicationRestoreType::RestoreFromCustomSnapshot,
+            _ => ApplicationRestoreType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ApplicationRestoreType {
+    fn from(value: String) -> ApplicationRestoreType {
+        match value.as_str() {
+            "SKIP_RESTORE_FROM_SNAPSHOT" => ApplicationRestoreType::SkipRestoreFromSnapshot,
+            "RESTORE_FROM_LATEST_SNAPSHOT" => ApplicationRestoreType::RestoreFromLatestSnapshot,
+            "RESTORE_FROM_CUSTOM_SNAPSHOT" => ApplicationRestoreType::RestoreFromCustomSnapshot,
+            _ => ApplicationRestoreType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ApplicationRestoreType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ApplicationRestoreType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ApplicationRestoreType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ApplicationRestoreType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ApplicationRestoreType {
+    fn deserialize<D>(deserializer: D) -> Result<ApplicationRestoreType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes whether snapshots are enabled for a Java-based Kinesis Data Analytics application.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -436,6 +544,144 @@ pub struct ApplicationSnapshotConfigurationUpdate {
     pub snapshots_enabled_update: bool,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ApplicationStatus {
+    /// `DELETING`
+    Deleting,
+    /// `STARTING`
+    Starting,
+    /// `STOPPING`
+    Stopping,
+    /// `READY`
+    Ready,
+    /// `RUNNING`
+    Running,
+    /// `UPDATING`
+    Updating,
+    /// `AUTOSCALING`
+    Autoscaling,
+    /// `FORCE_STOPPING`
+    ForceStopping,
+    /// `ROLLING_BACK`
+    RollingBack,
+    /// `MAINTENANCE`
+    Maintenance,
+    /// `ROLLED_BACK`
+    RolledBack,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ApplicationStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ApplicationStatus::Deleting => "DELETING",
+            ApplicationStatus::Starting => "STARTING",
+            ApplicationStatus::Stopping => "STOPPING",
+            ApplicationStatus::Ready => "READY",
+            ApplicationStatus::Running => "RUNNING",
+            ApplicationStatus::Updating => "UPDATING",
+            ApplicationStatus::Autoscaling => "AUTOSCALING",
+            ApplicationStatus::ForceStopping => "FORCE_STOPPING",
+            ApplicationStatus::RollingBack => "ROLLING_BACK",
+            ApplicationStatus::Maintenance => "MAINTENANCE",
+            ApplicationStatus::RolledBack => "ROLLED_BACK",
+            ApplicationStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ApplicationStatus {
+    fn default() -> ApplicationStatus {
+        ApplicationStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ApplicationStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ApplicationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ApplicationStatus {
+    fn from(value: &'a str) -> ApplicationStatus {
+        match value {
+            "DELETING" => ApplicationStatus::Deleting,
+            "STARTING" => ApplicationStatus::Starting,
+            "STOPPING" => ApplicationStatus::Stopping,
+            "READY" => ApplicationStatus::Ready,
+            "RUNNING" => ApplicationStatus::Running,
+            "UPDATING" => ApplicationStatus::Updating,
+            "AUTOSCALING" => ApplicationStatus::Autoscaling,
+            "FORCE_STOPPING" => ApplicationStatus::ForceStopping,
+            "ROLLING_BACK" => ApplicationStatus::RollingBack,
+            "MAINTENANCE" => ApplicationStatus::Maintenance,
+            "ROLLED_BACK" => ApplicationStatus::RolledBack,
+            _ => ApplicationStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ApplicationStatus {
+    fn from(value: String) -> ApplicationStatus {
+        match value.as_str() {
+            "DELETING" => ApplicationStatus::Deleting,
+            "STARTING" => ApplicationStatus::Starting,
+            "STOPPING" => ApplicationStatus::Stopping,
+            "READY" => ApplicationStatus::Ready,
+            "RUNNING" => ApplicationStatus::Running,
+            "UPDATING" => ApplicationStatus::Updating,
+            "AUTOSCALING" => ApplicationStatus::Autoscaling,
+            "FORCE_STOPPING" => ApplicationStatus::ForceStopping,
+            "ROLLING_BACK" => ApplicationStatus::RollingBack,
+            "MAINTENANCE" => ApplicationStatus::Maintenance,
+            "ROLLED_BACK" => ApplicationStatus::RolledBack,
+            _ => ApplicationStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ApplicationStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ApplicationStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ApplicationStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ApplicationStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ApplicationStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ApplicationStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Provides application summary information, including the application Amazon Resource Name (ARN), name, and status.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -448,13 +694,13 @@ pub struct ApplicationSummary {
     pub application_name: String,
     /// <p>The status of the application.</p>
     #[serde(rename = "ApplicationStatus")]
-    pub application_status: String,
+    pub application_status: ApplicationStatus,
     /// <p>Provides the current application version.</p>
     #[serde(rename = "ApplicationVersionId")]
     pub application_version_id: i64,
     /// <p>The runtime environment for the application (<code>SQL-1.0</code> or <code>FLINK-1_6</code>).</p>
     #[serde(rename = "RuntimeEnvironment")]
-    pub runtime_environment: String,
+    pub runtime_environment: RuntimeEnvironment,
 }
 
 /// <p>For an SQL-based application, provides additional mapping information when the record format uses delimiters, such as CSV. For example, the following sample records use CSV format, where the records use the <i>'\n'</i> as the row delimiter and a comma (",") as the column delimiter: </p> <p> <code>"name1", "address1"</code> </p> <p> <code>"name2", "address2"</code> </p>
@@ -482,7 +728,7 @@ pub struct CheckpointConfiguration {
     pub checkpointing_enabled: Option<bool>,
     /// <p><p>Describes whether the application uses Amazon Kinesis Data Analytics&#39; default checkpointing behavior. You must set this property to <code>CUSTOM</code> in order to set the <code>CheckpointingEnabled</code>, <code>CheckpointInterval</code>, or <code>MinPauseBetweenCheckpoints</code> parameters.</p> <note> <p>If this value is set to <code>DEFAULT</code>, the application will use the following values, even if they are set to other values using APIs or application code:</p> <ul> <li> <p> <b>CheckpointingEnabled:</b> true</p> </li> <li> <p> <b>CheckpointInterval:</b> 60000</p> </li> <li> <p> <b>MinPauseBetweenCheckpoints:</b> 5000</p> </li> </ul> </note></p>
     #[serde(rename = "ConfigurationType")]
-    pub configuration_type: String,
+    pub configuration_type: ConfigurationType,
     /// <p><p>Describes the minimum time in milliseconds after a checkpoint operation completes that a new checkpoint operation can start. If a checkpoint operation takes longer than the <code>CheckpointInterval</code>, the application otherwise performs continual checkpoint operations. For more information, see <a href="https://ci.apache.org/projects/flink/flink-docs-stable/ops/state/large_state_tuning.html#tuning-checkpointing"> Tuning Checkpointing</a> in the <a href="https://ci.apache.org/projects/flink/flink-docs-release-1.6/">Apache Flink Documentation</a>.</p> <note> <p>If <code>CheckpointConfiguration.ConfigurationType</code> is <code>DEFAULT</code>, the application will use a <code>MinPauseBetweenCheckpoints</code> value of 5000, even if this value is set using this API or in application code.</p> </note></p>
     #[serde(rename = "MinPauseBetweenCheckpoints")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -504,7 +750,7 @@ pub struct CheckpointConfigurationDescription {
     /// <p><p>Describes whether the application uses the default checkpointing behavior in Kinesis Data Analytics. </p> <note> <p>If this value is set to <code>DEFAULT</code>, the application will use the following values, even if they are set to other values using APIs or application code:</p> <ul> <li> <p> <b>CheckpointingEnabled:</b> true</p> </li> <li> <p> <b>CheckpointInterval:</b> 60000</p> </li> <li> <p> <b>MinPauseBetweenCheckpoints:</b> 5000</p> </li> </ul> </note></p>
     #[serde(rename = "ConfigurationType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub configuration_type: Option<String>,
+    pub configuration_type: Option<ConfigurationType>,
     /// <p><p>Describes the minimum time in milliseconds after a checkpoint operation completes that a new checkpoint operation can start. </p> <note> <p>If <code>CheckpointConfiguration.ConfigurationType</code> is <code>DEFAULT</code>, the application will use a <code>MinPauseBetweenCheckpoints</code> value of 5000, even if this value is set using this API or in application code.</p> </note></p>
     #[serde(rename = "MinPauseBetweenCheckpoints")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -526,7 +772,7 @@ pub struct CheckpointConfigurationUpdate {
     /// <p><p>Describes updates to whether the application uses the default checkpointing behavior of Kinesis Data Analytics. You must set this property to <code>CUSTOM</code> in order to set the <code>CheckpointingEnabled</code>, <code>CheckpointInterval</code>, or <code>MinPauseBetweenCheckpoints</code> parameters. </p> <note> <p>If this value is set to <code>DEFAULT</code>, the application will use the following values, even if they are set to other values using APIs or application code:</p> <ul> <li> <p> <b>CheckpointingEnabled:</b> true</p> </li> <li> <p> <b>CheckpointInterval:</b> 60000</p> </li> <li> <p> <b>MinPauseBetweenCheckpoints:</b> 5000</p> </li> </ul> </note></p>
     #[serde(rename = "ConfigurationTypeUpdate")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub configuration_type_update: Option<String>,
+    pub configuration_type_update: Option<ConfigurationType>,
     /// <p><p>Describes updates to the minimum time in milliseconds after a checkpoint operation completes that a new checkpoint operation can start.</p> <note> <p>If <code>CheckpointConfiguration.ConfigurationType</code> is <code>DEFAULT</code>, the application will use a <code>MinPauseBetweenCheckpoints</code> value of 5000, even if this value is set using this API or in application code.</p> </note></p>
     #[serde(rename = "MinPauseBetweenCheckpointsUpdate")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -617,6 +863,99 @@ pub struct CodeContentDescription {
     pub text_content: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CodeContentType {
+    /// `PLAINTEXT`
+    Plaintext,
+    /// `ZIPFILE`
+    Zipfile,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CodeContentType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CodeContentType::Plaintext => "PLAINTEXT",
+            CodeContentType::Zipfile => "ZIPFILE",
+            CodeContentType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CodeContentType {
+    fn default() -> CodeContentType {
+        CodeContentType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CodeContentType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CodeContentType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CodeContentType {
+    fn from(value: &'a str) -> CodeContentType {
+        match value {
+            "PLAINTEXT" => CodeContentType::Plaintext,
+            "ZIPFILE" => CodeContentType::Zipfile,
+            _ => CodeContentType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CodeContentType {
+    fn from(value: String) -> CodeContentType {
+        match value.as_str() {
+            "PLAINTEXT" => CodeContentType::Plaintext,
+            "ZIPFILE" => CodeContentType::Zipfile,
+            _ => CodeContentType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CodeContentType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CodeContentType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CodeContentType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CodeContentType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CodeContentType {
+    fn deserialize<D>(deserializer: D) -> Result<CodeContentType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes an update to the code of a Java-based Kinesis Data Analytics application.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -640,6 +979,99 @@ pub struct CodeContentUpdate {
     pub zip_file_content_update: Option<bytes::Bytes>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ConfigurationType {
+    /// `DEFAULT`
+    Default,
+    /// `CUSTOM`
+    Custom,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ConfigurationType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ConfigurationType::Default => "DEFAULT",
+            ConfigurationType::Custom => "CUSTOM",
+            ConfigurationType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ConfigurationType {
+    fn default() -> ConfigurationType {
+        ConfigurationType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ConfigurationType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ConfigurationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ConfigurationType {
+    fn from(value: &'a str) -> ConfigurationType {
+        match value {
+            "DEFAULT" => ConfigurationType::Default,
+            "CUSTOM" => ConfigurationType::Custom,
+            _ => ConfigurationType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ConfigurationType {
+    fn from(value: String) -> ConfigurationType {
+        match value.as_str() {
+            "DEFAULT" => ConfigurationType::Default,
+            "CUSTOM" => ConfigurationType::Custom,
+            _ => ConfigurationType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ConfigurationType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ConfigurationType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ConfigurationType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ConfigurationType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ConfigurationType {
+    fn deserialize<D>(deserializer: D) -> Result<ConfigurationType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateApplicationRequest {
@@ -660,7 +1092,7 @@ pub struct CreateApplicationRequest {
     pub cloud_watch_logging_options: Option<Vec<CloudWatchLoggingOption>>,
     /// <p>The runtime environment for the application (<code>SQL-1.0</code> or <code>FLINK-1_6</code>).</p>
     #[serde(rename = "RuntimeEnvironment")]
-    pub runtime_environment: String,
+    pub runtime_environment: RuntimeEnvironment,
     /// <p>The IAM role used by the application to access Kinesis data streams, Kinesis Data Firehose delivery streams, Amazon S3 objects, and other external resources.</p>
     #[serde(rename = "ServiceExecutionRole")]
     pub service_execution_role: String,
@@ -813,7 +1245,12 @@ pub struct DeleteApplicationRequest {
     pub application_name: String,
     /// <p>Use the <code>DescribeApplication</code> operation to get this value.</p>
     #[serde(rename = "CreateTimestamp")]
-    pub create_timestamp: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub create_timestamp: ::chrono::DateTime<::chrono::Utc>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -828,7 +1265,12 @@ pub struct DeleteApplicationSnapshotRequest {
     pub application_name: String,
     /// <p>The creation timestamp of the application snapshot to delete. You can retrieve this value using or .</p>
     #[serde(rename = "SnapshotCreationTimestamp")]
-    pub snapshot_creation_timestamp: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub snapshot_creation_timestamp: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The identifier for the snapshot delete.</p>
     #[serde(rename = "SnapshotName")]
     pub snapshot_name: String,
@@ -909,7 +1351,7 @@ pub struct DescribeApplicationSnapshotResponse {
 pub struct DestinationSchema {
     /// <p>Specifies the format of the records on the output stream.</p>
     #[serde(rename = "RecordFormatType")]
-    pub record_format_type: String,
+    pub record_format_type: RecordFormatType,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -1218,13 +1660,111 @@ pub struct InputSchemaUpdate {
     pub record_format_update: Option<RecordFormat>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum InputStartingPosition {
+    /// `NOW`
+    Now,
+    /// `TRIM_HORIZON`
+    TrimHorizon,
+    /// `LAST_STOPPED_POINT`
+    LastStoppedPoint,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl InputStartingPosition {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InputStartingPosition::Now => "NOW",
+            InputStartingPosition::TrimHorizon => "TRIM_HORIZON",
+            InputStartingPosition::LastStoppedPoint => "LAST_STOPPED_POINT",
+            InputStartingPosition::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for InputStartingPosition {
+    fn default() -> InputStartingPosition {
+        InputStartingPosition::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for InputStartingPosition {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for InputStartingPosition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for InputStartingPosition {
+    fn from(value: &'a str) -> InputStartingPosition {
+        match value {
+            "NOW" => InputStartingPosition::Now,
+            "TRIM_HORIZON" => InputStartingPosition::TrimHorizon,
+            "LAST_STOPPED_POINT" => InputStartingPosition::LastStoppedPoint,
+            _ => InputStartingPosition::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InputStartingPosition {
+    fn from(value: String) -> InputStartingPosition {
+        match value.as_str() {
+            "NOW" => InputStartingPosition::Now,
+            "TRIM_HORIZON" => InputStartingPosition::TrimHorizon,
+            "LAST_STOPPED_POINT" => InputStartingPosition::LastStoppedPoint,
+            _ => InputStartingPosition::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for InputStartingPosition {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<InputStartingPosition, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for InputStartingPosition {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for InputStartingPosition {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InputStartingPosition {
+    fn deserialize<D>(deserializer: D) -> Result<InputStartingPosition, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes the point at which the application reads from the streaming source.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct InputStartingPositionConfiguration {
     /// <p><p>The starting position on the stream.</p> <ul> <li> <p> <code>NOW</code> - Start reading just after the most recent record in the stream, and start at the request timestamp that the customer issued.</p> </li> <li> <p> <code>TRIM<em>HORIZON</code> - Start reading at the last untrimmed record in the stream, which is the oldest record available in the stream. This option is not available for an Amazon Kinesis Data Firehose delivery stream.</p> </li> <li> <p> <code>LAST</em>STOPPED_POINT</code> - Resume reading from where the application last stopped reading.</p> </li> </ul></p>
     #[serde(rename = "InputStartingPosition")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub input_starting_position: Option<String>,
+    pub input_starting_position: Option<InputStartingPosition>,
 }
 
 /// <p>For an SQL-based Amazon Kinesis Data Analytics application, describes updates to a specific input configuration (identified by the <code>InputId</code> of an application). </p>
@@ -1494,6 +2034,109 @@ pub struct ListTagsForResourceResponse {
     pub tags: Option<Vec<Tag>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LogLevel {
+    /// `INFO`
+    Info,
+    /// `WARN`
+    Warn,
+    /// `ERROR`
+    Error,
+    /// `DEBUG`
+    Debug,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LogLevel {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LogLevel::Info => "INFO",
+            LogLevel::Warn => "WARN",
+            LogLevel::Error => "ERROR",
+            LogLevel::Debug => "DEBUG",
+            LogLevel::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LogLevel {
+    fn default() -> LogLevel {
+        LogLevel::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LogLevel {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LogLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LogLevel {
+    fn from(value: &'a str) -> LogLevel {
+        match value {
+            "INFO" => LogLevel::Info,
+            "WARN" => LogLevel::Warn,
+            "ERROR" => LogLevel::Error,
+            "DEBUG" => LogLevel::Debug,
+            _ => LogLevel::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LogLevel {
+    fn from(value: String) -> LogLevel {
+        match value.as_str() {
+            "INFO" => LogLevel::Info,
+            "WARN" => LogLevel::Warn,
+            "ERROR" => LogLevel::Error,
+            "DEBUG" => LogLevel::Debug,
+            _ => LogLevel::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LogLevel {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LogLevel, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LogLevel {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LogLevel {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LogLevel {
+    fn deserialize<D>(deserializer: D) -> Result<LogLevel, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>When you configure an SQL-based Amazon Kinesis Data Analytics application's input at the time of creating or updating an application, provides additional mapping information specific to the record format (such as JSON, CSV, or record fields delimited by some delimiter) on the streaming source.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MappingParameters {
@@ -1507,21 +2150,124 @@ pub struct MappingParameters {
     pub json_mapping_parameters: Option<JSONMappingParameters>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MetricsLevel {
+    /// `APPLICATION`
+    Application,
+    /// `TASK`
+    Task,
+    /// `OPERATOR`
+    Operator,
+    /// `PARALLELISM`
+    Parallelism,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl MetricsLevel {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MetricsLevel::Application => "APPLICATION",
+            MetricsLevel::Task => "TASK",
+            MetricsLevel::Operator => "OPERATOR",
+            MetricsLevel::Parallelism => "PARALLELISM",
+            MetricsLevel::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for MetricsLevel {
+    fn default() -> MetricsLevel {
+        MetricsLevel::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for MetricsLevel {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for MetricsLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for MetricsLevel {
+    fn from(value: &'a str) -> MetricsLevel {
+        match value {
+            "APPLICATION" => MetricsLevel::Application,
+            "TASK" => MetricsLevel::Task,
+            "OPERATOR" => MetricsLevel::Operator,
+            "PARALLELISM" => MetricsLevel::Parallelism,
+            _ => MetricsLevel::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MetricsLevel {
+    fn from(value: String) -> MetricsLevel {
+        match value.as_str() {
+            "APPLICATION" => MetricsLevel::Application,
+            "TASK" => MetricsLevel::Task,
+            "OPERATOR" => MetricsLevel::Operator,
+            "PARALLELISM" => MetricsLevel::Parallelism,
+            _ => MetricsLevel::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for MetricsLevel {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<MetricsLevel, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MetricsLevel {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for MetricsLevel {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MetricsLevel {
+    fn deserialize<D>(deserializer: D) -> Result<MetricsLevel, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes configuration parameters for Amazon CloudWatch logging for a Java-based Kinesis Data Analytics application. For more information about CloudWatch logging, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/java/monitoring-overview.html">Monitoring</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct MonitoringConfiguration {
     /// <p>Describes whether to use the default CloudWatch logging configuration for an application. You must set this property to <code>CUSTOM</code> in order to set the <code>LogLevel</code> or <code>MetricsLevel</code> parameters.</p>
     #[serde(rename = "ConfigurationType")]
-    pub configuration_type: String,
+    pub configuration_type: ConfigurationType,
     /// <p>Describes the verbosity of the CloudWatch Logs for an application.</p>
     #[serde(rename = "LogLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub log_level: Option<String>,
+    pub log_level: Option<LogLevel>,
     /// <p>Describes the granularity of the CloudWatch Logs for an application.</p>
     #[serde(rename = "MetricsLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub metrics_level: Option<String>,
+    pub metrics_level: Option<MetricsLevel>,
 }
 
 /// <p>Describes configuration parameters for CloudWatch logging for a Java-based Kinesis Data Analytics application.</p>
@@ -1531,15 +2277,15 @@ pub struct MonitoringConfigurationDescription {
     /// <p>Describes whether to use the default CloudWatch logging configuration for an application.</p>
     #[serde(rename = "ConfigurationType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub configuration_type: Option<String>,
+    pub configuration_type: Option<ConfigurationType>,
     /// <p>Describes the verbosity of the CloudWatch Logs for an application.</p>
     #[serde(rename = "LogLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub log_level: Option<String>,
+    pub log_level: Option<LogLevel>,
     /// <p>Describes the granularity of the CloudWatch Logs for an application.</p>
     #[serde(rename = "MetricsLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub metrics_level: Option<String>,
+    pub metrics_level: Option<MetricsLevel>,
 }
 
 /// <p>Describes updates to configuration parameters for Amazon CloudWatch logging for a Java-based Kinesis Data Analytics application.</p>
@@ -1549,15 +2295,15 @@ pub struct MonitoringConfigurationUpdate {
     /// <p>Describes updates to whether to use the default CloudWatch logging configuration for an application. You must set this property to <code>CUSTOM</code> in order to set the <code>LogLevel</code> or <code>MetricsLevel</code> parameters.</p>
     #[serde(rename = "ConfigurationTypeUpdate")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub configuration_type_update: Option<String>,
+    pub configuration_type_update: Option<ConfigurationType>,
     /// <p>Describes updates to the verbosity of the CloudWatch Logs for an application.</p>
     #[serde(rename = "LogLevelUpdate")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub log_level_update: Option<String>,
+    pub log_level_update: Option<LogLevel>,
     /// <p>Describes updates to the granularity of the CloudWatch Logs for an application.</p>
     #[serde(rename = "MetricsLevelUpdate")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub metrics_level_update: Option<String>,
+    pub metrics_level_update: Option<MetricsLevel>,
 }
 
 /// <p><p> Describes an SQL-based Amazon Kinesis Data Analytics application&#39;s output configuration, in which you identify an in-application stream and a destination where you want the in-application stream data to be written. The destination can be a Kinesis data stream or a Kinesis Data Firehose delivery stream. </p> <p/></p>
@@ -1653,7 +2399,7 @@ pub struct ParallelismConfiguration {
     pub auto_scaling_enabled: Option<bool>,
     /// <p>Describes whether the application uses the default parallelism for the Kinesis Data Analytics service. You must set this property to <code>CUSTOM</code> in order to change your application's <code>AutoScalingEnabled</code>, <code>Parallelism</code>, or <code>ParallelismPerKPU</code> properties.</p>
     #[serde(rename = "ConfigurationType")]
-    pub configuration_type: String,
+    pub configuration_type: ConfigurationType,
     /// <p>Describes the initial number of parallel tasks that a Java-based Kinesis Data Analytics application can perform. If <code>AutoScalingEnabled</code> is set to True, Kinesis Data Analytics increases the <code>CurrentParallelism</code> value in response to application load. The service can increase the <code>CurrentParallelism</code> value up to the maximum parallelism, which is <code>ParalellismPerKPU</code> times the maximum KPUs for the application. The maximum KPUs for an application is 32 by default, and can be increased by requesting a limit increase. If application load is reduced, the service can reduce the <code>CurrentParallelism</code> value down to the <code>Parallelism</code> setting.</p>
     #[serde(rename = "Parallelism")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1675,7 +2421,7 @@ pub struct ParallelismConfigurationDescription {
     /// <p>Describes whether the application uses the default parallelism for the Kinesis Data Analytics service. </p>
     #[serde(rename = "ConfigurationType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub configuration_type: Option<String>,
+    pub configuration_type: Option<ConfigurationType>,
     /// <p>Describes the current number of parallel tasks that a Java-based Kinesis Data Analytics application can perform. If <code>AutoScalingEnabled</code> is set to True, Kinesis Data Analytics can increase this value in response to application load. The service can increase this value up to the maximum parallelism, which is <code>ParalellismPerKPU</code> times the maximum KPUs for the application. The maximum KPUs for an application is 32 by default, and can be increased by requesting a limit increase. If application load is reduced, the service can reduce the <code>CurrentParallelism</code> value down to the <code>Parallelism</code> setting.</p>
     #[serde(rename = "CurrentParallelism")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1701,7 +2447,7 @@ pub struct ParallelismConfigurationUpdate {
     /// <p>Describes updates to whether the application uses the default parallelism for the Kinesis Data Analytics service, or if a custom parallelism is used. You must set this property to <code>CUSTOM</code> in order to change your application's <code>AutoScalingEnabled</code>, <code>Parallelism</code>, or <code>ParallelismPerKPU</code> properties.</p>
     #[serde(rename = "ConfigurationTypeUpdate")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub configuration_type_update: Option<String>,
+    pub configuration_type_update: Option<ConfigurationType>,
     /// <p>Describes updates to the number of parallel tasks an application can perform per Kinesis Processing Unit (KPU) used by the application.</p>
     #[serde(rename = "ParallelismPerKPUUpdate")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1747,9 +2493,102 @@ pub struct RecordFormat {
     pub mapping_parameters: Option<MappingParameters>,
     /// <p>The type of record format.</p>
     #[serde(rename = "RecordFormatType")]
-    pub record_format_type: String,
+    pub record_format_type: RecordFormatType,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RecordFormatType {
+    /// `JSON`
+    Json,
+    /// `CSV`
+    Csv,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RecordFormatType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RecordFormatType::Json => "JSON",
+            RecordFormatType::Csv => "CSV",
+            RecordFormatType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RecordFormatType {
+    fn default() -> RecordFormatType {
+        RecordFormatType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RecordFormatType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RecordFormatType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RecordFormatType {
+    fn from(value: &'a str) -> RecordFormatType {
+        match value {
+            "JSON" => RecordFormatType::Json,
+            "CSV" => RecordFormatType::Csv,
+            _ => RecordFormatType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RecordFormatType {
+    fn from(value: String) -> RecordFormatType {
+        match value.as_str() {
+            "JSON" => RecordFormatType::Json,
+            "CSV" => RecordFormatType::Csv,
+            _ => RecordFormatType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RecordFormatType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RecordFormatType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RecordFormatType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
 }
 
+impl ::serde::Serialize for RecordFormatType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RecordFormatType {
+    fn deserialize<D>(deserializer: D) -> Result<RecordFormatType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>For an SQL-based Amazon Kinesis Data Analytics application, describes the reference data source by providing the source information (Amazon S3 bucket name and object key name), the resulting in-application table name that is created, and the necessary schema to map the data elements in the Amazon S3 object to the in-application table.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -1848,6 +2687,134 @@ pub struct RunConfigurationUpdate {
     pub flink_run_configuration: Option<FlinkRunConfiguration>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RuntimeEnvironment {
+    /// `SQL-1_0`
+    Sql1_0,
+    /// `FLINK-1_6`
+    Flink1_6,
+    /// `FLINK-1_8`
+    Flink1_8,
+    /// `ZEPPELIN-FLINK-1_0`
+    ZeppelinFlink1_0,
+    /// `FLINK-1_11`
+    Flink1_11,
+    /// `FLINK-1_13`
+    Flink1_13,
+    /// `ZEPPELIN-FLINK-2_0`
+    ZeppelinFlink2_0,
+    /// `FLINK-1_15`
+    Flink1_15,
+    /// `ZEPPELIN-FLINK-3_0`
+    ZeppelinFlink3_0,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RuntimeEnvironment {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RuntimeEnvironment::Sql1_0 => "SQL-1_0",
+            RuntimeEnvironment::Flink1_6 => "FLINK-1_6",
+            RuntimeEnvironment::Flink1_8 => "FLINK-1_8",
+            RuntimeEnvironment::ZeppelinFlink1_0 => "ZEPPELIN-FLINK-1_0",
+            RuntimeEnvironment::Flink1_11 => "FLINK-1_11",
+            RuntimeEnvironment::Flink1_13 => "FLINK-1_13",
+            RuntimeEnvironment::ZeppelinFlink2_0 => "ZEPPELIN-FLINK-2_0",
+            RuntimeEnvironment::Flink1_15 => "FLINK-1_15",
+            RuntimeEnvironment::ZeppelinFlink3_0 => "ZEPPELIN-FLINK-3_0",
+            RuntimeEnvironment::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RuntimeEnvironment {
+    fn default() -> RuntimeEnvironment {
+        RuntimeEnvironment::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RuntimeEnvironment {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RuntimeEnvironment {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RuntimeEnvironment {
+    fn from(value: &'a str) -> RuntimeEnvironment {
+        match value {
+            "SQL-1_0" => RuntimeEnvironment::Sql1_0,
+            "FLINK-1_6" => RuntimeEnvironment::Flink1_6,
+            "FLINK-1_8" => RuntimeEnvironment::Flink1_8,
+            "ZEPPELIN-FLINK-1_0" => RuntimeEnvironment::ZeppelinFlink1_0,
+            "FLINK-1_11" => RuntimeEnvironment::Flink1_11,
+            "FLINK-1_13" => RuntimeEnvironment::Flink1_13,
+            "ZEPPELIN-FLINK-2_0" => RuntimeEnvironment::ZeppelinFlink2_0,
+            "FLINK-1_15" => RuntimeEnvironment::Flink1_15,
+            "ZEPPELIN-FLINK-3_0" => RuntimeEnvironment::ZeppelinFlink3_0,
+            _ => RuntimeEnvironment::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RuntimeEnvironment {
+    fn from(value: String) -> RuntimeEnvironment {
+        match value.as_str() {
+            "SQL-1_0" => RuntimeEnvironment::Sql1_0,
+            "FLINK-1_6" => RuntimeEnvironment::Flink1_6,
+            "FLINK-1_8" => RuntimeEnvironment::Flink1_8,
+            "ZEPPELIN-FLINK-1_0" => RuntimeEnvironment::ZeppelinFlink1_0,
+            "FLINK-1_11" => RuntimeEnvironment::Flink1_11,
+            "FLINK-1_13" => RuntimeEnvironment::Flink1_13,
+            "ZEPPELIN-FLINK-2_0" => RuntimeEnvironment::ZeppelinFlink2_0,
+            "FLINK-1_15" => RuntimeEnvironment::Flink1_15,
+            "ZEPPELIN-FLINK-3_0" => RuntimeEnvironment::ZeppelinFlink3_0,
+            _ => RuntimeEnvironment::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RuntimeEnvironment {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RuntimeEnvironment, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RuntimeEnvironment {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RuntimeEnvironment {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RuntimeEnvironment {
+    fn deserialize<D>(deserializer: D) -> Result<RuntimeEnvironment, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes the location of a Java-based Amazon Kinesis Data Analytics application's code stored in an S3 bucket.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1963,16 +2930,124 @@ pub struct SnapshotDetails {
     pub application_version_id: i64,
     /// <p>The timestamp of the application snapshot.</p>
     #[serde(rename = "SnapshotCreationTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub snapshot_creation_timestamp: Option<f64>,
+    pub snapshot_creation_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The identifier for the application snapshot.</p>
     #[serde(rename = "SnapshotName")]
     pub snapshot_name: String,
     /// <p>The status of the application snapshot.</p>
     #[serde(rename = "SnapshotStatus")]
-    pub snapshot_status: String,
+    pub snapshot_status: SnapshotStatus,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SnapshotStatus {
+    /// `CREATING`
+    Creating,
+    /// `READY`
+    Ready,
+    /// `DELETING`
+    Deleting,
+    /// `FAILED`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SnapshotStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SnapshotStatus::Creating => "CREATING",
+            SnapshotStatus::Ready => "READY",
+            SnapshotStatus::Deleting => "DELETING",
+            SnapshotStatus::Failed => "FAILED",
+            SnapshotStatus::Unknown(ref value) => value,
+        }
+    }
 }
 
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SnapshotStatus {
+    fn default() -> SnapshotStatus {
+        SnapshotStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SnapshotStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SnapshotStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SnapshotStatus {
+    fn from(value: &'a str) -> SnapshotStatus {
+        match value {
+            "CREATING" => SnapshotStatus::Creating,
+            "READY" => SnapshotStatus::Ready,
+            "DELETING" => SnapshotStatus::Deleting,
+            "FAILED" => SnapshotStatus::Failed,
+            _ => SnapshotStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SnapshotStatus {
+    fn from(value: String) -> SnapshotStatus {
+        match value.as_str() {
+            "CREATING" => SnapshotStatus::Creating,
+            "READY" => SnapshotStatus::Ready,
+            "DELETING" => SnapshotStatus::Deleting,
+            "FAILED" => SnapshotStatus::Failed,
+            _ => SnapshotStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SnapshotStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SnapshotStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SnapshotStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SnapshotStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SnapshotStatus {
+    fn deserialize<D>(deserializer: D) -> Result<SnapshotStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>For an SQL-based Amazon Kinesis Data Analytics application, describes the format of the data in the streaming source, and how each data element maps to corresponding columns created in the in-application stream. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SourceSchema {
@@ -4054,11 +5129,12 @@ pub struct KinesisAnalyticsV2Client {
 impl KinesisAnalyticsV2Client {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> KinesisAnalyticsV2Client {
         KinesisAnalyticsV2Client {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Kinesis Analytics V2"),
         }
     }
 
@@ -4093,6 +5169,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         RusotoError<AddApplicationCloudWatchLoggingOptionError>,
     > {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("AddApplicationCloudWatchLoggingOption");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4126,6 +5203,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         input: AddApplicationInputRequest,
     ) -> Result<AddApplicationInputResponse, RusotoError<AddApplicationInputError>> {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("AddApplicationInput");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4160,6 +5238,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         RusotoError<AddApplicationInputProcessingConfigurationError>,
     > {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("AddApplicationInputProcessingConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4191,6 +5270,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         input: AddApplicationOutputRequest,
     ) -> Result<AddApplicationOutputResponse, RusotoError<AddApplicationOutputError>> {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("AddApplicationOutput");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4225,6 +5305,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         RusotoError<AddApplicationReferenceDataSourceError>,
     > {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("AddApplicationReferenceDataSource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4261,6 +5342,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         RusotoError<AddApplicationVpcConfigurationError>,
     > {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("AddApplicationVpcConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4292,6 +5374,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         input: CreateApplicationRequest,
     ) -> Result<CreateApplicationResponse, RusotoError<CreateApplicationError>> {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("CreateApplication");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4324,6 +5407,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
     ) -> Result<CreateApplicationSnapshotResponse, RusotoError<CreateApplicationSnapshotError>>
     {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("CreateApplicationSnapshot");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4355,6 +5439,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         input: DeleteApplicationRequest,
     ) -> Result<DeleteApplicationResponse, RusotoError<DeleteApplicationError>> {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("DeleteApplication");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4389,6 +5474,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         RusotoError<DeleteApplicationCloudWatchLoggingOptionError>,
     > {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("DeleteApplicationCloudWatchLoggingOption");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4423,6 +5509,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         RusotoError<DeleteApplicationInputProcessingConfigurationError>,
     > {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("DeleteApplicationInputProcessingConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4454,6 +5541,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         input: DeleteApplicationOutputRequest,
     ) -> Result<DeleteApplicationOutputResponse, RusotoError<DeleteApplicationOutputError>> {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("DeleteApplicationOutput");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4488,6 +5576,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         RusotoError<DeleteApplicationReferenceDataSourceError>,
     > {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("DeleteApplicationReferenceDataSource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4522,6 +5611,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
     ) -> Result<DeleteApplicationSnapshotResponse, RusotoError<DeleteApplicationSnapshotError>>
     {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("DeleteApplicationSnapshot");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4556,6 +5646,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         RusotoError<DeleteApplicationVpcConfigurationError>,
     > {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("DeleteApplicationVpcConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4589,6 +5680,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         input: DescribeApplicationRequest,
     ) -> Result<DescribeApplicationResponse, RusotoError<DescribeApplicationError>> {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("DescribeApplication");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4621,6 +5713,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
     ) -> Result<DescribeApplicationSnapshotResponse, RusotoError<DescribeApplicationSnapshotError>>
     {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("DescribeApplicationSnapshot");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4652,6 +5745,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         input: DiscoverInputSchemaRequest,
     ) -> Result<DiscoverInputSchemaResponse, RusotoError<DiscoverInputSchemaError>> {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("DiscoverInputSchema");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4683,6 +5777,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         input: ListApplicationSnapshotsRequest,
     ) -> Result<ListApplicationSnapshotsResponse, RusotoError<ListApplicationSnapshotsError>> {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("ListApplicationSnapshots");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4714,6 +5809,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         input: ListApplicationsRequest,
     ) -> Result<ListApplicationsResponse, RusotoError<ListApplicationsError>> {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("ListApplications");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "KinesisAnalytics_20180523.ListApplications");
@@ -4742,6 +5838,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4773,6 +5870,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         input: StartApplicationRequest,
     ) -> Result<StartApplicationResponse, RusotoError<StartApplicationError>> {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("StartApplication");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "KinesisAnalytics_20180523.StartApplication");
@@ -4801,6 +5899,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         input: StopApplicationRequest,
     ) -> Result<StopApplicationResponse, RusotoError<StopApplicationError>> {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("StopApplication");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "KinesisAnalytics_20180523.StopApplication");
@@ -4828,6 +5927,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "KinesisAnalytics_20180523.TagResource");
@@ -4855,6 +5955,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "KinesisAnalytics_20180523.UntagResource");
@@ -4882,6 +5983,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         input: UpdateApplicationRequest,
     ) -> Result<UpdateApplicationResponse, RusotoError<UpdateApplicationError>> {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("UpdateApplication");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4907,3 +6009,72 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         }
     }
 }
+impl KinesisAnalyticsV2Client {
+    /// Auto-paginating version of `list_application_snapshots`, streaming every page of results.
+    pub fn list_application_snapshots_pages(
+        &self,
+        input: ListApplicationSnapshotsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListApplicationSnapshotsResponse,
+        ListApplicationSnapshotsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: KinesisAnalyticsV2Client, input: ListApplicationSnapshotsRequest| async move {
+                client.list_application_snapshots(input).await
+            },
+            |input: &ListApplicationSnapshotsRequest, output: &ListApplicationSnapshotsResponse| {
+                Some(ListApplicationSnapshotsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_application_snapshots`, streaming the `SnapshotSummaries` of every page of results.
+    pub fn list_application_snapshots_items(
+        &self,
+        input: ListApplicationSnapshotsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<SnapshotDetails, ListApplicationSnapshotsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_application_snapshots_pages(input),
+            |output: ListApplicationSnapshotsResponse| {
+                output.snapshot_summaries.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_applications`, streaming every page of results.
+    pub fn list_applications_pages(
+        &self,
+        input: ListApplicationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListApplicationsResponse, ListApplicationsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: KinesisAnalyticsV2Client, input: ListApplicationsRequest| async move {
+                client.list_applications(input).await
+            },
+            |input: &ListApplicationsRequest, output: &ListApplicationsResponse| {
+                Some(ListApplicationsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_applications`, streaming the `ApplicationSummaries` of every page of results.
+    pub fn list_applications_items(
+        &self,
+        input: ListApplicationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ApplicationSummary, ListApplicationsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_applications_pages(input),
+            |output: ListApplicationsResponse| output.application_summaries,
+        )
+    }
+}
@@ -24,6 +24,129 @@ use rusoto_core::signature::SignedRequest;
 #[allow(unused_imports)]
 use serde::{Deserialize, Serialize};
 use serde_json;
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum APIName {
+    /// `PUT_MEDIA`
+    PutMedia,
+    /// `GET_MEDIA`
+    GetMedia,
+    /// `LIST_FRAGMENTS`
+    ListFragments,
+    /// `GET_MEDIA_FOR_FRAGMENT_LIST`
+    GetMediaForFragmentList,
+    /// `GET_HLS_STREAMING_SESSION_URL`
+    GetHlsStreamingSessionUrl,
+    /// `GET_DASH_STREAMING_SESSION_URL`
+    GetDashStreamingSessionUrl,
+    /// `GET_CLIP`
+    GetClip,
+    /// `GET_IMAGES`
+    GetImages,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl APIName {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            APIName::PutMedia => "PUT_MEDIA",
+            APIName::GetMedia => "GET_MEDIA",
+            APIName::ListFragments => "LIST_FRAGMENTS",
+            APIName::GetMediaForFragmentList => "GET_MEDIA_FOR_FRAGMENT_LIST",
+            APIName::GetHlsStreamingSessionUrl => "GET_HLS_STREAMING_SESSION_URL",
+            APIName::GetDashStreamingSessionUrl => "GET_DASH_STREAMING_SESSION_URL",
+            APIName::GetClip => "GET_CLIP",
+            APIName::GetImages => "GET_IMAGES",
+            APIName::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for APIName {
+    fn default() -> APIName {
+        APIName::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for APIName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for APIName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for APIName {
+    fn from(value: &'a str) -> APIName {
+        match value {
+            "PUT_MEDIA" => APIName::PutMedia,
+            "GET_MEDIA" => APIName::GetMedia,
+            "LIST_FRAGMENTS" => APIName::ListFragments,
+            "GET_MEDIA_FOR_FRAGMENT_LIST" => APIName::GetMediaForFragmentList,
+            "GET_HLS_STREAMING_SESSION_URL" => APIName::GetHlsStreamingSessionUrl,
+            "GET_DASH_STREAMING_SESSION_URL" => APIName::GetDashStreamingSessionUrl,
+            "GET_CLIP" => APIName::GetClip,
+            "GET_IMAGES" => APIName::GetImages,
+            _ => APIName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for APIName {
+    fn from(value: String) -> APIName {
+        match value.as_str() {
+            "PUT_MEDIA" => APIName::PutMedia,
+            "GET_MEDIA" => APIName::GetMedia,
+            "LIST_FRAGMENTS" => APIName::ListFragments,
+            "GET_MEDIA_FOR_FRAGMENT_LIST" => APIName::GetMediaForFragmentList,
+            "GET_HLS_STREAMING_SESSION_URL" => APIName::GetHlsStreamingSessionUrl,
+            "GET_DASH_STREAMING_SESSION_URL" => APIName::GetDashStreamingSessionUrl,
+            "GET_CLIP" => APIName::GetClip,
+            "GET_IMAGES" => APIName::GetImages,
+            _ => APIName::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for APIName {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<APIName, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for APIName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for APIName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for APIName {
+    fn deserialize<D>(deserializer: D) -> Result<APIName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A structure that encapsulates a signaling channel's metadata and properties.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -39,15 +162,20 @@ pub struct ChannelInfo {
     /// <p>Current status of the signaling channel.</p>
     #[serde(rename = "ChannelStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub channel_status: Option<String>,
+    pub channel_status: Option<Status>,
     /// <p>The type of the signaling channel.</p>
     #[serde(rename = "ChannelType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub channel_type: Option<String>,
+    pub channel_type: Option<ChannelType>,
     /// <p>The time at which the signaling channel was created.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A structure that contains the configuration for the <code>SINGLE_MASTER</code> channel type.</p>
     #[serde(rename = "SingleMasterConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -65,13 +193,385 @@ pub struct ChannelNameCondition {
     /// <p>A comparison operator. Currently, you can only specify the <code>BEGINS_WITH</code> operator, which finds signaling channels whose names begin with a given prefix.</p>
     #[serde(rename = "ComparisonOperator")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub comparison_operator: Option<String>,
+    pub comparison_operator: Option<ComparisonOperator>,
     /// <p>A value to compare.</p>
     #[serde(rename = "ComparisonValue")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub comparison_value: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ChannelProtocol {
+    /// `WSS`
+    Wss,
+    /// `HTTPS`
+    Https,
+    /// `WEBRTC`
+    Webrtc,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ChannelProtocol {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ChannelProtocol::Wss => "WSS",
+            ChannelProtocol::Https => "HTTPS",
+            ChannelProtocol::Webrtc => "WEBRTC",
+            ChannelProtocol::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ChannelProtocol {
+    fn default() -> ChannelProtocol {
+        ChannelProtocol::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ChannelProtocol {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ChannelProtocol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ChannelProtocol {
+    fn from(value: &'a str) -> ChannelProtocol {
+        match value {
+            "WSS" => ChannelProtocol::Wss,
+            "HTTPS" => ChannelProtocol::Https,
+            "WEBRTC" => ChannelProtocol::Webrtc,
+            _ => ChannelProtocol::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ChannelProtocol {
+    fn from(value: String) -> ChannelProtocol {
+        match value.as_str() {
+            "WSS" => ChannelProtocol::Wss,
+            "HTTPS" => ChannelProtocol::Https,
+            "WEBRTC" => ChannelProtocol::Webrtc,
+            _ => ChannelProtocol::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ChannelProtocol {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ChannelProtocol, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ChannelProtocol {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ChannelProtocol {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ChannelProtocol {
+    fn deserialize<D>(deserializer: D) -> Result<ChannelProtocol, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ChannelRole {
+    /// `MASTER`
+    Master,
+    /// `VIEWER`
+    Viewer,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ChannelRole {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ChannelRole::Master => "MASTER",
+            ChannelRole::Viewer => "VIEWER",
+            ChannelRole::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ChannelRole {
+    fn default() -> ChannelRole {
+        ChannelRole::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ChannelRole {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ChannelRole {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ChannelRole {
+    fn from(value: &'a str) -> ChannelRole {
+        match value {
+            "MASTER" => ChannelRole::Master,
+            "VIEWER" => ChannelRole::Viewer,
+            _ => ChannelRole::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ChannelRole {
+    fn from(value: String) -> ChannelRole {
+        match value.as_str() {
+            "MASTER" => ChannelRole::Master,
+            "VIEWER" => ChannelRole::Viewer,
+            _ => ChannelRole::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ChannelRole {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ChannelRole, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ChannelRole {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ChannelRole {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ChannelRole {
+    fn deserialize<D>(deserializer: D) -> Result<ChannelRole, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ChannelType {
+    /// `SINGLE_MASTER`
+    SingleMaster,
+    /// `FULL_MESH`
+    FullMesh,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ChannelType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ChannelType::SingleMaster => "SINGLE_MASTER",
+            ChannelType::FullMesh => "FULL_MESH",
+            ChannelType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ChannelType {
+    fn default() -> ChannelType {
+        ChannelType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ChannelType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ChannelType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ChannelType {
+    fn from(value: &'a str) -> ChannelType {
+        match value {
+            "SINGLE_MASTER" => ChannelType::SingleMaster,
+            "FULL_MESH" => ChannelType::FullMesh,
+            _ => ChannelType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ChannelType {
+    fn from(value: String) -> ChannelType {
+        match value.as_str() {
+            "SINGLE_MASTER" => ChannelType::SingleMaster,
+            "FULL_MESH" => ChannelType::FullMesh,
+            _ => ChannelType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ChannelType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ChannelType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ChannelType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ChannelType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ChannelType {
+    fn deserialize<D>(deserializer: D) -> Result<ChannelType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ComparisonOperator {
+    /// `BEGINS_WITH`
+    BeginsWith,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ComparisonOperator {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ComparisonOperator::BeginsWith => "BEGINS_WITH",
+            ComparisonOperator::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ComparisonOperator {
+    fn default() -> ComparisonOperator {
+        ComparisonOperator::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ComparisonOperator {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ComparisonOperator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ComparisonOperator {
+    fn from(value: &'a str) -> ComparisonOperator {
+        match value {
+            "BEGINS_WITH" => ComparisonOperator::BeginsWith,
+            _ => ComparisonOperator::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ComparisonOperator {
+    fn from(value: String) -> ComparisonOperator {
+        match value.as_str() {
+            "BEGINS_WITH" => ComparisonOperator::BeginsWith,
+            _ => ComparisonOperator::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ComparisonOperator {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ComparisonOperator, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ComparisonOperator {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ComparisonOperator {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ComparisonOperator {
+    fn deserialize<D>(deserializer: D) -> Result<ComparisonOperator, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateSignalingChannelInput {
@@ -81,7 +581,7 @@ pub struct CreateSignalingChannelInput {
     /// <p>A type of the signaling channel that you are creating. Currently, <code>SINGLE_MASTER</code> is the only supported channel type. </p>
     #[serde(rename = "ChannelType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub channel_type: Option<String>,
+    pub channel_type: Option<ChannelType>,
     /// <p>A structure containing the configuration for the <code>SINGLE_MASTER</code> channel type. </p>
     #[serde(rename = "SingleMasterConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -219,7 +719,7 @@ pub struct DescribeStreamOutput {
 pub struct GetDataEndpointInput {
     /// <p>The name of the API action for which to get an endpoint.</p>
     #[serde(rename = "APIName")]
-    pub api_name: String,
+    pub api_name: APIName,
     /// <p>The Amazon Resource Name (ARN) of the stream that you want to get the endpoint for. You must specify either this parameter or a <code>StreamName</code> in the request. </p>
     #[serde(rename = "StreamARN")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -383,7 +883,7 @@ pub struct ResourceEndpointListItem {
     /// <p>The protocol of the signaling channel returned by the <code>GetSignalingChannelEndpoint</code> API.</p>
     #[serde(rename = "Protocol")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub protocol: Option<String>,
+    pub protocol: Option<ChannelProtocol>,
     /// <p>The endpoint of the signaling channel returned by the <code>GetSignalingChannelEndpoint</code> API.</p>
     #[serde(rename = "ResourceEndpoint")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -397,11 +897,11 @@ pub struct SingleMasterChannelEndpointConfiguration {
     /// <p>This property is used to determine the nature of communication over this <code>SINGLE_MASTER</code> signaling channel. If <code>WSS</code> is specified, this API returns a websocket endpoint. If <code>HTTPS</code> is specified, this API returns an <code>HTTPS</code> endpoint.</p>
     #[serde(rename = "Protocols")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub protocols: Option<Vec<String>>,
+    pub protocols: Option<Vec<ChannelProtocol>>,
     /// <p>This property is used to determine messaging permissions in this <code>SINGLE_MASTER</code> signaling channel. If <code>MASTER</code> is specified, this API returns an endpoint that a client can use to receive offers from and send answers to any of the viewers on this signaling channel. If <code>VIEWER</code> is specified, this API returns an endpoint that a client can use only to send offers to another <code>MASTER</code> client on this signaling channel. </p>
     #[serde(rename = "Role")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub role: Option<String>,
+    pub role: Option<ChannelRole>,
 }
 
 /// <p>A structure that contains the configuration for the <code>SINGLE_MASTER</code> channel type.</p>
@@ -413,14 +913,122 @@ pub struct SingleMasterConfiguration {
     pub message_ttl_seconds: Option<i64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Status {
+    /// `CREATING`
+    Creating,
+    /// `ACTIVE`
+    Active,
+    /// `UPDATING`
+    Updating,
+    /// `DELETING`
+    Deleting,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Status {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Status::Creating => "CREATING",
+            Status::Active => "ACTIVE",
+            Status::Updating => "UPDATING",
+            Status::Deleting => "DELETING",
+            Status::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Status {
+    fn default() -> Status {
+        Status::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Status {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Status {
+    fn from(value: &'a str) -> Status {
+        match value {
+            "CREATING" => Status::Creating,
+            "ACTIVE" => Status::Active,
+            "UPDATING" => Status::Updating,
+            "DELETING" => Status::Deleting,
+            _ => Status::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Status {
+    fn from(value: String) -> Status {
+        match value.as_str() {
+            "CREATING" => Status::Creating,
+            "ACTIVE" => Status::Active,
+            "UPDATING" => Status::Updating,
+            "DELETING" => Status::Deleting,
+            _ => Status::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Status {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Status, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Status {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Status {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Status {
+    fn deserialize<D>(deserializer: D) -> Result<Status, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An object describing a Kinesis video stream.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StreamInfo {
     /// <p>A time stamp that indicates when the stream was created.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>How long the stream retains data, in hours.</p>
     #[serde(rename = "DataRetentionInHours")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -440,7 +1048,7 @@ pub struct StreamInfo {
     /// <p>The status of the stream.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<Status>,
     /// <p>The Amazon Resource Name (ARN) of the stream.</p>
     #[serde(rename = "StreamARN")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -462,7 +1070,7 @@ pub struct StreamNameCondition {
     /// <p>A comparison operator. Currently, you can specify only the <code>BEGINS_WITH</code> operator, which finds streams whose names start with a given prefix.</p>
     #[serde(rename = "ComparisonOperator")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub comparison_operator: Option<String>,
+    pub comparison_operator: Option<ComparisonOperator>,
     /// <p>A value to compare.</p>
     #[serde(rename = "ComparisonValue")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -562,7 +1170,7 @@ pub struct UpdateDataRetentionInput {
     pub data_retention_change_in_hours: i64,
     /// <p>Indicates whether you want to increase or decrease the retention period.</p>
     #[serde(rename = "Operation")]
-    pub operation: String,
+    pub operation: UpdateDataRetentionOperation,
     /// <p>The Amazon Resource Name (ARN) of the stream whose retention period you want to change.</p>
     #[serde(rename = "StreamARN")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -573,6 +1181,99 @@ pub struct UpdateDataRetentionInput {
     pub stream_name: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum UpdateDataRetentionOperation {
+    /// `INCREASE_DATA_RETENTION`
+    IncreaseDataRetention,
+    /// `DECREASE_DATA_RETENTION`
+    DecreaseDataRetention,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl UpdateDataRetentionOperation {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            UpdateDataRetentionOperation::IncreaseDataRetention => "INCREASE_DATA_RETENTION",
+            UpdateDataRetentionOperation::DecreaseDataRetention => "DECREASE_DATA_RETENTION",
+            UpdateDataRetentionOperation::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for UpdateDataRetentionOperation {
+    fn default() -> UpdateDataRetentionOperation {
+        UpdateDataRetentionOperation::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for UpdateDataRetentionOperation {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for UpdateDataRetentionOperation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for UpdateDataRetentionOperation {
+    fn from(value: &'a str) -> UpdateDataRetentionOperation {
+        match value {
+            "INCREASE_DATA_RETENTION" => UpdateDataRetentionOperation::IncreaseDataRetention,
+            "DECREASE_DATA_RETENTION" => UpdateDataRetentionOperation::DecreaseDataRetention,
+            _ => UpdateDataRetentionOperation::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for UpdateDataRetentionOperation {
+    fn from(value: String) -> UpdateDataRetentionOperation {
+        match value.as_str() {
+            "INCREASE_DATA_RETENTION" => UpdateDataRetentionOperation::IncreaseDataRetention,
+            "DECREASE_DATA_RETENTION" => UpdateDataRetentionOperation::DecreaseDataRetention,
+            _ => UpdateDataRetentionOperation::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for UpdateDataRetentionOperation {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<UpdateDataRetentionOperation, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for UpdateDataRetentionOperation {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for UpdateDataRetentionOperation {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for UpdateDataRetentionOperation {
+    fn deserialize<D>(deserializer: D) -> Result<UpdateDataRetentionOperation, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateDataRetentionOutput {}
@@ -1839,11 +2540,12 @@ pub struct KinesisVideoClient {
 impl KinesisVideoClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> KinesisVideoClient {
         KinesisVideoClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Kinesis Video"),
         }
     }
 
@@ -1877,6 +2579,7 @@ impl KinesisVideo for KinesisVideoClient {
         let request_uri = "/createSignalingChannel";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("CreateSignalingChannel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1907,6 +2610,7 @@ impl KinesisVideo for KinesisVideoClient {
         let request_uri = "/createStream";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("CreateStream");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1937,6 +2641,7 @@ impl KinesisVideo for KinesisVideoClient {
         let request_uri = "/deleteSignalingChannel";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("DeleteSignalingChannel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1967,6 +2672,7 @@ impl KinesisVideo for KinesisVideoClient {
         let request_uri = "/deleteStream";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("DeleteStream");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1997,6 +2703,7 @@ impl KinesisVideo for KinesisVideoClient {
         let request_uri = "/describeSignalingChannel";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("DescribeSignalingChannel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2027,6 +2734,7 @@ impl KinesisVideo for KinesisVideoClient {
         let request_uri = "/describeStream";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("DescribeStream");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2057,6 +2765,7 @@ impl KinesisVideo for KinesisVideoClient {
         let request_uri = "/getDataEndpoint";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("GetDataEndpoint");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2088,6 +2797,7 @@ impl KinesisVideo for KinesisVideoClient {
         let request_uri = "/getSignalingChannelEndpoint";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("GetSignalingChannelEndpoint");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2118,6 +2828,7 @@ impl KinesisVideo for KinesisVideoClient {
         let request_uri = "/listSignalingChannels";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("ListSignalingChannels");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2148,6 +2859,7 @@ impl KinesisVideo for KinesisVideoClient {
         let request_uri = "/listStreams";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("ListStreams");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2178,6 +2890,7 @@ impl KinesisVideo for KinesisVideoClient {
         let request_uri = "/ListTagsForResource";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2208,6 +2921,7 @@ impl KinesisVideo for KinesisVideoClient {
         let request_uri = "/listTagsForStream";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("ListTagsForStream");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2238,6 +2952,7 @@ impl KinesisVideo for KinesisVideoClient {
         let request_uri = "/TagResource";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2268,6 +2983,7 @@ impl KinesisVideo for KinesisVideoClient {
         let request_uri = "/tagStream";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("TagStream");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2298,6 +3014,7 @@ impl KinesisVideo for KinesisVideoClient {
         let request_uri = "/UntagResource";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2328,6 +3045,7 @@ impl KinesisVideo for KinesisVideoClient {
         let request_uri = "/untagStream";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("UntagStream");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2358,6 +3076,7 @@ impl KinesisVideo for KinesisVideoClient {
         let request_uri = "/updateDataRetention";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("UpdateDataRetention");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2388,6 +3107,7 @@ impl KinesisVideo for KinesisVideoClient {
         let request_uri = "/updateSignalingChannel";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("UpdateSignalingChannel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2418,6 +3138,7 @@ impl KinesisVideo for KinesisVideoClient {
         let request_uri = "/updateStream";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("UpdateStream");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2440,3 +3161,69 @@ impl KinesisVideo for KinesisVideoClient {
         }
     }
 }
+impl KinesisVideoClient {
+    /// Auto-paginating version of `list_signaling_channels`, streaming every page of results.
+    pub fn list_signaling_channels_pages(
+        &self,
+        input: ListSignalingChannelsInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListSignalingChannelsOutput,
+        ListSignalingChannelsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: KinesisVideoClient, input: ListSignalingChannelsInput| async move {
+                client.list_signaling_channels(input).await
+            },
+            |input: &ListSignalingChannelsInput, output: &ListSignalingChannelsOutput| {
+                Some(ListSignalingChannelsInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_signaling_channels`, streaming the `ChannelInfoList` of every page of results.
+    pub fn list_signaling_channels_items(
+        &self,
+        input: ListSignalingChannelsInput,
+    ) -> rusoto_core::pagination::RusotoStream<ChannelInfo, ListSignalingChannelsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_signaling_channels_pages(input),
+            |output: ListSignalingChannelsOutput| output.channel_info_list.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_streams`, streaming every page of results.
+    pub fn list_streams_pages(
+        &self,
+        input: ListStreamsInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListStreamsOutput, ListStreamsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: KinesisVideoClient, input: ListStreamsInput| async move {
+                client.list_streams(input).await
+            },
+            |input: &ListStreamsInput, output: &ListStreamsOutput| {
+                Some(ListStreamsInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_streams`, streaming the `StreamInfoList` of every page of results.
+    pub fn list_streams_items(
+        &self,
+        input: ListStreamsInput,
+    ) -> rusoto_core::pagination::RusotoStream<StreamInfo, ListStreamsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_streams_pages(input),
+            |output: ListStreamsOutput| output.stream_info_list.unwrap_or_default(),
+        )
+    }
+}
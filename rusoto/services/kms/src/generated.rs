@@ -24,6 +24,114 @@ use rusoto_core::signature::SignedRequest;
 #[allow(unused_imports)]
 use serde::{Deserialize, Serialize};
 use serde_json;
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AlgorithmSpec {
+    /// `RSAES_PKCS1_V1_5`
+    RsaesPkcs1V1_5,
+    /// `RSAES_OAEP_SHA_1`
+    RsaesOaepSha1,
+    /// `RSAES_OAEP_SHA_256`
+    RsaesOaepSha256,
+    /// `RSA_AES_KEY_WRAP_SHA_1`
+    RsaAesKeyWrapSha1,
+    /// `RSA_AES_KEY_WRAP_SHA_256`
+    RsaAesKeyWrapSha256,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AlgorithmSpec {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AlgorithmSpec::RsaesPkcs1V1_5 => "RSAES_PKCS1_V1_5",
+            AlgorithmSpec::RsaesOaepSha1 => "RSAES_OAEP_SHA_1",
+            AlgorithmSpec::RsaesOaepSha256 => "RSAES_OAEP_SHA_256",
+            AlgorithmSpec::RsaAesKeyWrapSha1 => "RSA_AES_KEY_WRAP_SHA_1",
+            AlgorithmSpec::RsaAesKeyWrapSha256 => "RSA_AES_KEY_WRAP_SHA_256",
+            AlgorithmSpec::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AlgorithmSpec {
+    fn default() -> AlgorithmSpec {
+        AlgorithmSpec::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AlgorithmSpec {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AlgorithmSpec {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AlgorithmSpec {
+    fn from(value: &'a str) -> AlgorithmSpec {
+        match value {
+            "RSAES_PKCS1_V1_5" => AlgorithmSpec::RsaesPkcs1V1_5,
+            "RSAES_OAEP_SHA_1" => AlgorithmSpec::RsaesOaepSha1,
+            "RSAES_OAEP_SHA_256" => AlgorithmSpec::RsaesOaepSha256,
+            "RSA_AES_KEY_WRAP_SHA_1" => AlgorithmSpec::RsaAesKeyWrapSha1,
+            "RSA_AES_KEY_WRAP_SHA_256" => AlgorithmSpec::RsaAesKeyWrapSha256,
+            _ => AlgorithmSpec::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AlgorithmSpec {
+    fn from(value: String) -> AlgorithmSpec {
+        match value.as_str() {
+            "RSAES_PKCS1_V1_5" => AlgorithmSpec::RsaesPkcs1V1_5,
+            "RSAES_OAEP_SHA_1" => AlgorithmSpec::RsaesOaepSha1,
+            "RSAES_OAEP_SHA_256" => AlgorithmSpec::RsaesOaepSha256,
+            "RSA_AES_KEY_WRAP_SHA_1" => AlgorithmSpec::RsaAesKeyWrapSha1,
+            "RSA_AES_KEY_WRAP_SHA_256" => AlgorithmSpec::RsaAesKeyWrapSha256,
+            _ => AlgorithmSpec::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AlgorithmSpec {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AlgorithmSpec, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AlgorithmSpec {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AlgorithmSpec {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AlgorithmSpec {
+    fn deserialize<D>(deserializer: D) -> Result<AlgorithmSpec, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Contains information about an alias.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -71,6 +179,317 @@ pub struct ConnectCustomKeyStoreRequest {
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ConnectCustomKeyStoreResponse {}
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ConnectionErrorCodeType {
+    /// `INVALID_CREDENTIALS`
+    InvalidCredentials,
+    /// `CLUSTER_NOT_FOUND`
+    ClusterNotFound,
+    /// `NETWORK_ERRORS`
+    NetworkErrors,
+    /// `INTERNAL_ERROR`
+    InternalError,
+    /// `INSUFFICIENT_CLOUDHSM_HSMS`
+    InsufficientCloudhsmHsms,
+    /// `USER_LOCKED_OUT`
+    UserLockedOut,
+    /// `USER_NOT_FOUND`
+    UserNotFound,
+    /// `USER_LOGGED_IN`
+    UserLoggedIn,
+    /// `SUBNET_NOT_FOUND`
+    SubnetNotFound,
+    /// `INSUFFICIENT_FREE_ADDRESSES_IN_SUBNET`
+    InsufficientFreeAddressesInSubnet,
+    /// `XKS_PROXY_ACCESS_DENIED`
+    XksProxyAccessDenied,
+    /// `XKS_PROXY_NOT_REACHABLE`
+    XksProxyNotReachable,
+    /// `XKS_VPC_ENDPOINT_SERVICE_NOT_FOUND`
+    XksVpcEndpointServiceNotFound,
+    /// `XKS_PROXY_INVALID_RESPONSE`
+    XksProxyInvalidResponse,
+    /// `XKS_PROXY_INVALID_CONFIGURATION`
+    XksProxyInvalidConfiguration,
+    /// `XKS_VPC_ENDPOINT_SERVICE_INVALID_CONFIGURATION`
+    XksVpcEndpointServiceInvalidConfiguration,
+    /// `XKS_PROXY_TIMED_OUT`
+    XksProxyTimedOut,
+    /// `XKS_PROXY_INVALID_TLS_CONFIGURATION`
+    XksProxyInvalidTlsConfiguration,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ConnectionErrorCodeType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ConnectionErrorCodeType::InvalidCredentials => "INVALID_CREDENTIALS",
+            ConnectionErrorCodeType::ClusterNotFound => "CLUSTER_NOT_FOUND",
+            ConnectionErrorCodeType::NetworkErrors => "NETWORK_ERRORS",
+            ConnectionErrorCodeType::InternalError => "INTERNAL_ERROR",
+            ConnectionErrorCodeType::InsufficientCloudhsmHsms => "INSUFFICIENT_CLOUDHSM_HSMS",
+            ConnectionErrorCodeType::UserLockedOut => "USER_LOCKED_OUT",
+            ConnectionErrorCodeType::UserNotFound => "USER_NOT_FOUND",
+            ConnectionErrorCodeType::UserLoggedIn => "USER_LOGGED_IN",
+            ConnectionErrorCodeType::SubnetNotFound => "SUBNET_NOT_FOUND",
+            ConnectionErrorCodeType::InsufficientFreeAddressesInSubnet => {
+                "INSUFFICIENT_FREE_ADDRESSES_IN_SUBNET"
+            }
+            ConnectionErrorCodeType::XksProxyAccessDenied => "XKS_PROXY_ACCESS_DENIED",
+            ConnectionErrorCodeType::XksProxyNotReachable => "XKS_PROXY_NOT_REACHABLE",
+            ConnectionErrorCodeType::XksVpcEndpointServiceNotFound => {
+                "XKS_VPC_ENDPOINT_SERVICE_NOT_FOUND"
+            }
+            ConnectionErrorCodeType::XksProxyInvalidResponse => "XKS_PROXY_INVALID_RESPONSE",
+            ConnectionErrorCodeType::XksProxyInvalidConfiguration => {
+                "XKS_PROXY_INVALID_CONFIGURATION"
+            }
+            ConnectionErrorCodeType::XksVpcEndpointServiceInvalidConfiguration => {
+                "XKS_VPC_ENDPOINT_SERVICE_INVALID_CONFIGURATION"
+            }
+            ConnectionErrorCodeType::XksProxyTimedOut => "XKS_PROXY_TIMED_OUT",
+            ConnectionErrorCodeType::XksProxyInvalidTlsConfiguration => {
+                "XKS_PROXY_INVALID_TLS_CONFIGURATION"
+            }
+            ConnectionErrorCodeType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ConnectionErrorCodeType {
+    fn default() -> ConnectionErrorCodeType {
+        ConnectionErrorCodeType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ConnectionErrorCodeType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ConnectionErrorCodeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ConnectionErrorCodeType {
+    fn from(value: &'a str) -> ConnectionErrorCodeType {
+        match value {
+            "INVALID_CREDENTIALS" => ConnectionErrorCodeType::InvalidCredentials,
+            "CLUSTER_NOT_FOUND" => ConnectionErrorCodeType::ClusterNotFound,
+            "NETWORK_ERRORS" => ConnectionErrorCodeType::NetworkErrors,
+            "INTERNAL_ERROR" => ConnectionErrorCodeType::InternalError,
+            "INSUFFICIENT_CLOUDHSM_HSMS" => ConnectionErrorCodeType::InsufficientCloudhsmHsms,
+            "USER_LOCKED_OUT" => ConnectionErrorCodeType::UserLockedOut,
+            "USER_NOT_FOUND" => ConnectionErrorCodeType::UserNotFound,
+            "USER_LOGGED_IN" => ConnectionErrorCodeType::UserLoggedIn,
+            "SUBNET_NOT_FOUND" => ConnectionErrorCodeType::SubnetNotFound,
+            "INSUFFICIENT_FREE_ADDRESSES_IN_SUBNET" => {
+                ConnectionErrorCodeType::InsufficientFreeAddressesInSubnet
+            }
+            "XKS_PROXY_ACCESS_DENIED" => ConnectionErrorCodeType::XksProxyAccessDenied,
+            "XKS_PROXY_NOT_REACHABLE" => ConnectionErrorCodeType::XksProxyNotReachable,
+            "XKS_VPC_ENDPOINT_SERVICE_NOT_FOUND" => {
+                ConnectionErrorCodeType::XksVpcEndpointServiceNotFound
+            }
+            "XKS_PROXY_INVALID_RESPONSE" => ConnectionErrorCodeType::XksProxyInvalidResponse,
+            "XKS_PROXY_INVALID_CONFIGURATION" => {
+                ConnectionErrorCodeType::XksProxyInvalidConfiguration
+            }
+            "XKS_VPC_ENDPOINT_SERVICE_INVALID_CONFIGURATION" => {
+                ConnectionErrorCodeType::XksVpcEndpointServiceInvalidConfiguration
+            }
+            "XKS_PROXY_TIMED_OUT" => ConnectionErrorCodeType::XksProxyTimedOut,
+            "XKS_PROXY_INVALID_TLS_CONFIGURATION" => {
+                ConnectionErrorCodeType::XksProxyInvalidTlsConfiguration
+            }
+            _ => ConnectionErrorCodeType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ConnectionErrorCodeType {
+    fn from(value: String) -> ConnectionErrorCodeType {
+        match value.as_str() {
+            "INVALID_CREDENTIALS" => ConnectionErrorCodeType::InvalidCredentials,
+            "CLUSTER_NOT_FOUND" => ConnectionErrorCodeType::ClusterNotFound,
+            "NETWORK_ERRORS" => ConnectionErrorCodeType::NetworkErrors,
+            "INTERNAL_ERROR" => ConnectionErrorCodeType::InternalError,
+            "INSUFFICIENT_CLOUDHSM_HSMS" => ConnectionErrorCodeType::InsufficientCloudhsmHsms,
+            "USER_LOCKED_OUT" => ConnectionErrorCodeType::UserLockedOut,
+            "USER_NOT_FOUND" => ConnectionErrorCodeType::UserNotFound,
+            "USER_LOGGED_IN" => ConnectionErrorCodeType::UserLoggedIn,
+            "SUBNET_NOT_FOUND" => ConnectionErrorCodeType::SubnetNotFound,
+            "INSUFFICIENT_FREE_ADDRESSES_IN_SUBNET" => {
+                ConnectionErrorCodeType::InsufficientFreeAddressesInSubnet
+            }
+            "XKS_PROXY_ACCESS_DENIED" => ConnectionErrorCodeType::XksProxyAccessDenied,
+            "XKS_PROXY_NOT_REACHABLE" => ConnectionErrorCodeType::XksProxyNotReachable,
+            "XKS_VPC_ENDPOINT_SERVICE_NOT_FOUND" => {
+                ConnectionErrorCodeType::XksVpcEndpointServiceNotFound
+            }
+            "XKS_PROXY_INVALID_RESPONSE" => ConnectionErrorCodeType::XksProxyInvalidResponse,
+            "XKS_PROXY_INVALID_CONFIGURATION" => {
+                ConnectionErrorCodeType::XksProxyInvalidConfiguration
+            }
+            "XKS_VPC_ENDPOINT_SERVICE_INVALID_CONFIGURATION" => {
+                ConnectionErrorCodeType::XksVpcEndpointServiceInvalidConfiguration
+            }
+            "XKS_PROXY_TIMED_OUT" => ConnectionErrorCodeType::XksProxyTimedOut,
+            "XKS_PROXY_INVALID_TLS_CONFIGURATION" => {
+                ConnectionErrorCodeType::XksProxyInvalidTlsConfiguration
+            }
+            _ => ConnectionErrorCodeType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ConnectionErrorCodeType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ConnectionErrorCodeType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ConnectionErrorCodeType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ConnectionErrorCodeType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ConnectionErrorCodeType {
+    fn deserialize<D>(deserializer: D) -> Result<ConnectionErrorCodeType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ConnectionStateType {
+    /// `CONNECTED`
+    Connected,
+    /// `CONNECTING`
+    Connecting,
+    /// `FAILED`
+    Failed,
+    /// `DISCONNECTED`
+    Disconnected,
+    /// `DISCONNECTING`
+    Disconnecting,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ConnectionStateType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ConnectionStateType::Connected => "CONNECTED",
+            ConnectionStateType::Connecting => "CONNECTING",
+            ConnectionStateType::Failed => "FAILED",
+            ConnectionStateType::Disconnected => "DISCONNECTED",
+            ConnectionStateType::Disconnecting => "DISCONNECTING",
+            ConnectionStateType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ConnectionStateType {
+    fn default() -> ConnectionStateType {
+        ConnectionStateType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ConnectionStateType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ConnectionStateType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ConnectionStateType {
+    fn from(value: &'a str) -> ConnectionStateType {
+        match value {
+            "CONNECTED" => ConnectionStateType::Connected,
+            "CONNECTING" => ConnectionStateType::Connecting,
+            "FAILED" => ConnectionStateType::Failed,
+            "DISCONNECTED" => ConnectionStateType::Disconnected,
+            "DISCONNECTING" => ConnectionStateType::Disconnecting,
+            _ => ConnectionStateType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ConnectionStateType {
+    fn from(value: String) -> ConnectionStateType {
+        match value.as_str() {
+            "CONNECTED" => ConnectionStateType::Connected,
+            "CONNECTING" => ConnectionStateType::Connecting,
+            "FAILED" => ConnectionStateType::Failed,
+            "DISCONNECTED" => ConnectionStateType::Disconnected,
+            "DISCONNECTING" => ConnectionStateType::Disconnecting,
+            _ => ConnectionStateType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ConnectionStateType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ConnectionStateType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ConnectionStateType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ConnectionStateType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ConnectionStateType {
+    fn deserialize<D>(deserializer: D) -> Result<ConnectionStateType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateAliasRequest {
@@ -131,7 +550,7 @@ pub struct CreateGrantRequest {
     pub name: Option<String>,
     /// <p>A list of operations that the grant permits.</p>
     #[serde(rename = "Operations")]
-    pub operations: Vec<String>,
+    pub operations: Vec<GrantOperation>,
     /// <p>The principal that is given permission to retire the grant by using <a>RetireGrant</a> operation.</p> <p>To specify the principal, use the <a href="https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Name (ARN)</a> of an AWS principal. Valid AWS principals include AWS accounts (root), IAM users, federated users, and assumed role users. For examples of the ARN syntax to use for specifying a principal, see <a href="https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html#arn-syntax-iam">AWS Identity and Access Management (IAM)</a> in the Example ARNs section of the <i>AWS General Reference</i>.</p>
     #[serde(rename = "RetiringPrincipal")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -165,7 +584,7 @@ pub struct CreateKeyRequest {
     /// <p><p>Specifies the type of CMK to create. The default value, <code>SYMMETRIC<em>DEFAULT</code>, creates a CMK with a 256-bit symmetric key for encryption and decryption. For help choosing a key spec for your CMK, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/symm-asymm-choose.html">How to Choose Your CMK Configuration</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>The <code>CustomerMasterKeySpec</code> determines whether the CMK contains a symmetric key or an asymmetric key pair. It also determines the encryption algorithms or signing algorithms that the CMK supports. You can&#39;t change the <code>CustomerMasterKeySpec</code> after the CMK is created. To further restrict the algorithms that can be used with the CMK, use a condition key in its key policy or IAM policy. For more information, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/policy-conditions.html#conditions-kms-encryption-algorithm">kms:EncryptionAlgorithm</a> or <a href="https://docs.aws.amazon.com/kms/latest/developerguide/policy-conditions.html#conditions-kms-signing-algorithm">kms:Signing Algorithm</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <important> <p> &lt;a href=&quot;http://aws.amazon.com/kms/features/#AWS</em>Service<em>Integration&quot;&gt;AWS services that are integrated with AWS KMS</a> use symmetric CMKs to protect your data. These services do not support asymmetric CMKs. For help determining whether a CMK is symmetric or asymmetric, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/find-symm-asymm.html">Identifying Symmetric and Asymmetric CMKs</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> </important> <p>AWS KMS supports the following key specs for CMKs:</p> <ul> <li> <p>Symmetric key (default)</p> <ul> <li> <p> <code>SYMMETRIC</em>DEFAULT</code> (AES-256-GCM)</p> </li> </ul> </li> <li> <p>Asymmetric RSA key pairs</p> <ul> <li> <p> <code>RSA<em>2048</code> </p> </li> <li> <p> <code>RSA</em>3072</code> </p> </li> <li> <p> <code>RSA<em>4096</code> </p> </li> </ul> </li> <li> <p>Asymmetric NIST-recommended elliptic curve key pairs</p> <ul> <li> <p> <code>ECC</em>NIST<em>P256</code> (secp256r1)</p> </li> <li> <p> <code>ECC</em>NIST<em>P384</code> (secp384r1)</p> </li> <li> <p> <code>ECC</em>NIST<em>P521</code> (secp521r1)</p> </li> </ul> </li> <li> <p>Other asymmetric elliptic curve key pairs</p> <ul> <li> <p> <code>ECC</em>SECG_P256K1</code> (secp256k1), commonly used for cryptocurrencies.</p> </li> </ul> </li> </ul></p>
     #[serde(rename = "CustomerMasterKeySpec")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub customer_master_key_spec: Option<String>,
+    pub customer_master_key_spec: Option<CustomerMasterKeySpec>,
     /// <p>A description of the CMK.</p> <p>Use a description that helps you decide whether the CMK is appropriate for a task.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -173,11 +592,11 @@ pub struct CreateKeyRequest {
     /// <p><p>Determines the cryptographic operations for which you can use the CMK. The default value is <code>ENCRYPT<em>DECRYPT</code>. This parameter is required only for asymmetric CMKs. You can&#39;t change the <code>KeyUsage</code> value after the CMK is created.</p> <p>Select only one valid value.</p> <ul> <li> <p>For symmetric CMKs, omit the parameter or specify <code>ENCRYPT</em>DECRYPT</code>.</p> </li> <li> <p>For asymmetric CMKs with RSA key material, specify <code>ENCRYPT<em>DECRYPT</code> or <code>SIGN</em>VERIFY</code>.</p> </li> <li> <p>For asymmetric CMKs with ECC key material, specify <code>SIGN_VERIFY</code>.</p> </li> </ul></p>
     #[serde(rename = "KeyUsage")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub key_usage: Option<String>,
+    pub key_usage: Option<KeyUsageType>,
     /// <p>The source of the key material for the CMK. You cannot change the origin after you create the CMK. The default is <code>AWS_KMS</code>, which means AWS KMS creates the key material.</p> <p>When the parameter value is <code>EXTERNAL</code>, AWS KMS creates a CMK without key material so that you can import key material from your existing key management infrastructure. For more information about importing key material into AWS KMS, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/importing-keys.html">Importing Key Material</a> in the <i>AWS Key Management Service Developer Guide</i>. This value is valid only for symmetric CMKs.</p> <p>When the parameter value is <code>AWS_CLOUDHSM</code>, AWS KMS creates the CMK in an AWS KMS <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">custom key store</a> and creates its key material in the associated AWS CloudHSM cluster. You must also use the <code>CustomKeyStoreId</code> parameter to identify the custom key store. This value is valid only for symmetric CMKs.</p>
     #[serde(rename = "Origin")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub origin: Option<String>,
+    pub origin: Option<OriginType>,
     /// <p>The key policy to attach to the CMK.</p> <p>If you provide a key policy, it must meet the following criteria:</p> <ul> <li> <p>If you don't set <code>BypassPolicyLockoutSafetyCheck</code> to true, the key policy must allow the principal that is making the <code>CreateKey</code> request to make a subsequent <a>PutKeyPolicy</a> request on the CMK. This reduces the risk that the CMK becomes unmanageable. For more information, refer to the scenario in the <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-policies.html#key-policy-default-allow-root-enable-iam">Default Key Policy</a> section of the <i> <i>AWS Key Management Service Developer Guide</i> </i>.</p> </li> <li> <p>Each statement in the key policy must contain one or more principals. The principals in the key policy must exist and be visible to AWS KMS. When you create a new AWS principal (for example, an IAM user or role), you might need to enforce a delay before including the new principal in a key policy because the new principal might not be immediately visible to AWS KMS. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/troubleshoot_general.html#troubleshoot_general_eventual-consistency">Changes that I make are not always immediately visible</a> in the <i>AWS Identity and Access Management User Guide</i>.</p> </li> </ul> <p>If you do not provide a key policy, AWS KMS attaches a default key policy to the CMK. For more information, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-policies.html#key-policy-default">Default Key Policy</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>The key policy size quota is 32 kilobytes (32768 bytes).</p>
     #[serde(rename = "Policy")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -208,15 +627,20 @@ pub struct CustomKeyStoresListEntry {
     /// <p><p>Describes the connection error. This field appears in the response only when the <code>ConnectionState</code> is <code>FAILED</code>. For help resolving these errors, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/fix-keystore.html#fix-keystore-failed">How to Fix a Connection Failure</a> in <i>AWS Key Management Service Developer Guide</i>.</p> <p>Valid values are:</p> <ul> <li> <p> <code>CLUSTER<em>NOT</em>FOUND</code> - AWS KMS cannot find the AWS CloudHSM cluster with the specified cluster ID.</p> </li> <li> <p> <code>INSUFFICIENT<em>CLOUDHSM</em>HSMS</code> - The associated AWS CloudHSM cluster does not contain any active HSMs. To connect a custom key store to its AWS CloudHSM cluster, the cluster must contain at least one active HSM.</p> </li> <li> <p> <code>INTERNAL<em>ERROR</code> - AWS KMS could not complete the request due to an internal error. Retry the request. For <code>ConnectCustomKeyStore</code> requests, disconnect the custom key store before trying to connect again.</p> </li> <li> <p> <code>INVALID</em>CREDENTIALS</code> - AWS KMS does not have the correct password for the <code>kmsuser</code> crypto user in the AWS CloudHSM cluster. Before you can connect your custom key store to its AWS CloudHSM cluster, you must change the <code>kmsuser</code> account password and update the key store password value for the custom key store.</p> </li> <li> <p> <code>NETWORK<em>ERRORS</code> - Network errors are preventing AWS KMS from connecting to the custom key store.</p> </li> <li> <p> <code>USER</em>LOCKED<em>OUT</code> - The <code>kmsuser</code> CU account is locked out of the associated AWS CloudHSM cluster due to too many failed password attempts. Before you can connect your custom key store to its AWS CloudHSM cluster, you must change the <code>kmsuser</code> account password and update the key store password value for the custom key store.</p> </li> <li> <p> <code>USER</em>LOGGED<em>IN</code> - The <code>kmsuser</code> CU account is logged into the the associated AWS CloudHSM cluster. This prevents AWS KMS from rotating the <code>kmsuser</code> account password and logging into the cluster. Before you can connect your custom key store to its AWS CloudHSM cluster, you must log the <code>kmsuser</code> CU out of the cluster. If you changed the <code>kmsuser</code> password to log into the cluster, you must also and update the key store password value for the custom key store. For help, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/fix-keystore.html#login-kmsuser-2">How to Log Out and Reconnect</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> </li> <li> <p> <code>USER</em>NOT_FOUND</code> - AWS KMS cannot find a <code>kmsuser</code> CU account in the associated AWS CloudHSM cluster. Before you can connect your custom key store to its AWS CloudHSM cluster, you must create a <code>kmsuser</code> CU account in the cluster, and then update the key store password value for the custom key store.</p> </li> </ul></p>
     #[serde(rename = "ConnectionErrorCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub connection_error_code: Option<String>,
+    pub connection_error_code: Option<ConnectionErrorCodeType>,
     /// <p>Indicates whether the custom key store is connected to its AWS CloudHSM cluster.</p> <p>You can create and use CMKs in your custom key stores only when its connection state is <code>CONNECTED</code>.</p> <p>The value is <code>DISCONNECTED</code> if the key store has never been connected or you use the <a>DisconnectCustomKeyStore</a> operation to disconnect it. If the value is <code>CONNECTED</code> but you are having trouble using the custom key store, make sure that its associated AWS CloudHSM cluster is active and contains at least one active HSM.</p> <p>A value of <code>FAILED</code> indicates that an attempt to connect was unsuccessful. The <code>ConnectionErrorCode</code> field in the response indicates the cause of the failure. For help resolving a connection failure, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/fix-keystore.html">Troubleshooting a Custom Key Store</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
     #[serde(rename = "ConnectionState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub connection_state: Option<String>,
+    pub connection_state: Option<ConnectionStateType>,
     /// <p>The date and time when the custom key store was created.</p>
     #[serde(rename = "CreationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A unique identifier for the custom key store.</p>
     #[serde(rename = "CustomKeyStoreId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -231,6 +655,370 @@ pub struct CustomKeyStoresListEntry {
     pub trust_anchor_certificate: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CustomerMasterKeySpec {
+    /// `RSA_2048`
+    Rsa2048,
+    /// `RSA_3072`
+    Rsa3072,
+    /// `RSA_4096`
+    Rsa4096,
+    /// `ECC_NIST_P256`
+    EccNistP256,
+    /// `ECC_NIST_P384`
+    EccNistP384,
+    /// `ECC_NIST_P521`
+    EccNistP521,
+    /// `ECC_SECG_P256K1`
+    EccSecgP256k1,
+    /// `SYMMETRIC_DEFAULT`
+    SymmetricDefault,
+    /// `HMAC_224`
+    Hmac224,
+    /// `HMAC_256`
+    Hmac256,
+    /// `HMAC_384`
+    Hmac384,
+    /// `HMAC_512`
+    Hmac512,
+    /// `SM2`
+    Sm2,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CustomerMasterKeySpec {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CustomerMasterKeySpec::Rsa2048 => "RSA_2048",
+            CustomerMasterKeySpec::Rsa3072 => "RSA_3072",
+            CustomerMasterKeySpec::Rsa4096 => "RSA_4096",
+            CustomerMasterKeySpec::EccNistP256 => "ECC_NIST_P256",
+            CustomerMasterKeySpec::EccNistP384 => "ECC_NIST_P384",
+            CustomerMasterKeySpec::EccNistP521 => "ECC_NIST_P521",
+            CustomerMasterKeySpec::EccSecgP256k1 => "ECC_SECG_P256K1",
+            CustomerMasterKeySpec::SymmetricDefault => "SYMMETRIC_DEFAULT",
+            CustomerMasterKeySpec::Hmac224 => "HMAC_224",
+            CustomerMasterKeySpec::Hmac256 => "HMAC_256",
+            CustomerMasterKeySpec::Hmac384 => "HMAC_384",
+            CustomerMasterKeySpec::Hmac512 => "HMAC_512",
+            CustomerMasterKeySpec::Sm2 => "SM2",
+            CustomerMasterKeySpec::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CustomerMasterKeySpec {
+    fn default() -> CustomerMasterKeySpec {
+        CustomerMasterKeySpec::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CustomerMasterKeySpec {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CustomerMasterKeySpec {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CustomerMasterKeySpec {
+    fn from(value: &'a str) -> CustomerMasterKeySpec {
+        match value {
+            "RSA_2048" => CustomerMasterKeySpec::Rsa2048,
+            "RSA_3072" => CustomerMasterKeySpec::Rsa3072,
+            "RSA_4096" => CustomerMasterKeySpec::Rsa4096,
+            "ECC_NIST_P256" => CustomerMasterKeySpec::EccNistP256,
+            "ECC_NIST_P384" => CustomerMasterKeySpec::EccNistP384,
+            "ECC_NIST_P521" => CustomerMasterKeySpec::EccNistP521,
+            "ECC_SECG_P256K1" => CustomerMasterKeySpec::EccSecgP256k1,
+            "SYMMETRIC_DEFAULT" => CustomerMasterKeySpec::SymmetricDefault,
+            "HMAC_224" => CustomerMasterKeySpec::Hmac224,
+            "HMAC_256" => CustomerMasterKeySpec::Hmac256,
+            "HMAC_384" => CustomerMasterKeySpec::Hmac384,
+            "HMAC_512" => CustomerMasterKeySpec::Hmac512,
+            "SM2" => CustomerMasterKeySpec::Sm2,
+            _ => CustomerMasterKeySpec::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CustomerMasterKeySpec {
+    fn from(value: String) -> CustomerMasterKeySpec {
+        match value.as_str() {
+            "RSA_2048" => CustomerMasterKeySpec::Rsa2048,
+            "RSA_3072" => CustomerMasterKeySpec::Rsa3072,
+            "RSA_4096" => CustomerMasterKeySpec::Rsa4096,
+            "ECC_NIST_P256" => CustomerMasterKeySpec::EccNistP256,
+            "ECC_NIST_P384" => CustomerMasterKeySpec::EccNistP384,
+            "ECC_NIST_P521" => CustomerMasterKeySpec::EccNistP521,
+            "ECC_SECG_P256K1" => CustomerMasterKeySpec::EccSecgP256k1,
+            "SYMMETRIC_DEFAULT" => CustomerMasterKeySpec::SymmetricDefault,
+            "HMAC_224" => CustomerMasterKeySpec::Hmac224,
+            "HMAC_256" => CustomerMasterKeySpec::Hmac256,
+            "HMAC_384" => CustomerMasterKeySpec::Hmac384,
+            "HMAC_512" => CustomerMasterKeySpec::Hmac512,
+            "SM2" => CustomerMasterKeySpec::Sm2,
+            _ => CustomerMasterKeySpec::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CustomerMasterKeySpec {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CustomerMasterKeySpec, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CustomerMasterKeySpec {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CustomerMasterKeySpec {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CustomerMasterKeySpec {
+    fn deserialize<D>(deserializer: D) -> Result<CustomerMasterKeySpec, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DataKeyPairSpec {
+    /// `RSA_2048`
+    Rsa2048,
+    /// `RSA_3072`
+    Rsa3072,
+    /// `RSA_4096`
+    Rsa4096,
+    /// `ECC_NIST_P256`
+    EccNistP256,
+    /// `ECC_NIST_P384`
+    EccNistP384,
+    /// `ECC_NIST_P521`
+    EccNistP521,
+    /// `ECC_SECG_P256K1`
+    EccSecgP256k1,
+    /// `SM2`
+    Sm2,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DataKeyPairSpec {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DataKeyPairSpec::Rsa2048 => "RSA_2048",
+            DataKeyPairSpec::Rsa3072 => "RSA_3072",
+            DataKeyPairSpec::Rsa4096 => "RSA_4096",
+            DataKeyPairSpec::EccNistP256 => "ECC_NIST_P256",
+            DataKeyPairSpec::EccNistP384 => "ECC_NIST_P384",
+            DataKeyPairSpec::EccNistP521 => "ECC_NIST_P521",
+            DataKeyPairSpec::EccSecgP256k1 => "ECC_SECG_P256K1",
+            DataKeyPairSpec::Sm2 => "SM2",
+            DataKeyPairSpec::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DataKeyPairSpec {
+    fn default() -> DataKeyPairSpec {
+        DataKeyPairSpec::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DataKeyPairSpec {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DataKeyPairSpec {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DataKeyPairSpec {
+    fn from(value: &'a str) -> DataKeyPairSpec {
+        match value {
+            "RSA_2048" => DataKeyPairSpec::Rsa2048,
+            "RSA_3072" => DataKeyPairSpec::Rsa3072,
+            "RSA_4096" => DataKeyPairSpec::Rsa4096,
+            "ECC_NIST_P256" => DataKeyPairSpec::EccNistP256,
+            "ECC_NIST_P384" => DataKeyPairSpec::EccNistP384,
+            "ECC_NIST_P521" => DataKeyPairSpec::EccNistP521,
+            "ECC_SECG_P256K1" => DataKeyPairSpec::EccSecgP256k1,
+            "SM2" => DataKeyPairSpec::Sm2,
+            _ => DataKeyPairSpec::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DataKeyPairSpec {
+    fn from(value: String) -> DataKeyPairSpec {
+        match value.as_str() {
+            "RSA_2048" => DataKeyPairSpec::Rsa2048,
+            "RSA_3072" => DataKeyPairSpec::Rsa3072,
+            "RSA_4096" => DataKeyPairSpec::Rsa4096,
+            "ECC_NIST_P256" => DataKeyPairSpec::EccNistP256,
+            "ECC_NIST_P384" => DataKeyPairSpec::EccNistP384,
+            "ECC_NIST_P521" => DataKeyPairSpec::EccNistP521,
+            "ECC_SECG_P256K1" => DataKeyPairSpec::EccSecgP256k1,
+            "SM2" => DataKeyPairSpec::Sm2,
+            _ => DataKeyPairSpec::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DataKeyPairSpec {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DataKeyPairSpec, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DataKeyPairSpec {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DataKeyPairSpec {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DataKeyPairSpec {
+    fn deserialize<D>(deserializer: D) -> Result<DataKeyPairSpec, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DataKeySpec {
+    /// `AES_256`
+    Aes256,
+    /// `AES_128`
+    Aes128,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DataKeySpec {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DataKeySpec::Aes256 => "AES_256",
+            DataKeySpec::Aes128 => "AES_128",
+            DataKeySpec::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DataKeySpec {
+    fn default() -> DataKeySpec {
+        DataKeySpec::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DataKeySpec {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DataKeySpec {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DataKeySpec {
+    fn from(value: &'a str) -> DataKeySpec {
+        match value {
+            "AES_256" => DataKeySpec::Aes256,
+            "AES_128" => DataKeySpec::Aes128,
+            _ => DataKeySpec::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DataKeySpec {
+    fn from(value: String) -> DataKeySpec {
+        match value.as_str() {
+            "AES_256" => DataKeySpec::Aes256,
+            "AES_128" => DataKeySpec::Aes128,
+            _ => DataKeySpec::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DataKeySpec {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DataKeySpec, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DataKeySpec {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DataKeySpec {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DataKeySpec {
+    fn deserialize<D>(deserializer: D) -> Result<DataKeySpec, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct DecryptRequest {
@@ -245,7 +1033,7 @@ pub struct DecryptRequest {
     /// <p>Specifies the encryption algorithm that will be used to decrypt the ciphertext. Specify the same algorithm that was used to encrypt the data. If you specify a different algorithm, the <code>Decrypt</code> operation fails.</p> <p>This parameter is required only when the ciphertext was encrypted under an asymmetric CMK. The default value, <code>SYMMETRIC_DEFAULT</code>, represents the only supported algorithm that is valid for symmetric CMKs.</p>
     #[serde(rename = "EncryptionAlgorithm")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub encryption_algorithm: Option<String>,
+    pub encryption_algorithm: Option<EncryptionAlgorithmSpec>,
     /// <p>Specifies the encryption context to use when decrypting the data. An encryption context is valid only for cryptographic operations with a symmetric CMK. The standard asymmetric encryption algorithms that AWS KMS uses do not support an encryption context.</p> <p>An <i>encryption context</i> is a collection of non-secret key-value pairs that represents additional authenticated data. When you use an encryption context to encrypt data, you must specify the same (an exact case-sensitive match) encryption context to decrypt the data. An encryption context is optional when encrypting with a symmetric CMK, but it is highly recommended.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#encrypt_context">Encryption Context</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
     #[serde(rename = "EncryptionContext")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -266,7 +1054,7 @@ pub struct DecryptResponse {
     /// <p>The encryption algorithm that was used to decrypt the ciphertext.</p>
     #[serde(rename = "EncryptionAlgorithm")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub encryption_algorithm: Option<String>,
+    pub encryption_algorithm: Option<EncryptionAlgorithmSpec>,
     /// <p>The ARN of the customer master key that was used to perform the decryption.</p>
     #[serde(rename = "KeyId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -419,7 +1207,7 @@ pub struct EncryptRequest {
     /// <p>Specifies the encryption algorithm that AWS KMS will use to encrypt the plaintext message. The algorithm must be compatible with the CMK that you specify.</p> <p>This parameter is required only for asymmetric CMKs. The default value, <code>SYMMETRIC_DEFAULT</code>, is the algorithm used for symmetric CMKs. If you are using an asymmetric CMK, we recommend RSAES_OAEP_SHA_256.</p>
     #[serde(rename = "EncryptionAlgorithm")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub encryption_algorithm: Option<String>,
+    pub encryption_algorithm: Option<EncryptionAlgorithmSpec>,
     /// <p>Specifies the encryption context that will be used to encrypt the data. An encryption context is valid only for cryptographic operations with a symmetric CMK. The standard asymmetric encryption algorithms that AWS KMS uses do not support an encryption context. </p> <p>An <i>encryption context</i> is a collection of non-secret key-value pairs that represents additional authenticated data. When you use an encryption context to encrypt data, you must specify the same (an exact case-sensitive match) encryption context to decrypt the data. An encryption context is optional when encrypting with a symmetric CMK, but it is highly recommended.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#encrypt_context">Encryption Context</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
     #[serde(rename = "EncryptionContext")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -456,13 +1244,209 @@ pub struct EncryptResponse {
     /// <p>The encryption algorithm that was used to encrypt the plaintext.</p>
     #[serde(rename = "EncryptionAlgorithm")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub encryption_algorithm: Option<String>,
+    pub encryption_algorithm: Option<EncryptionAlgorithmSpec>,
     /// <p>The ID of the key used during encryption.</p>
     #[serde(rename = "KeyId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub key_id: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EncryptionAlgorithmSpec {
+    /// `SYMMETRIC_DEFAULT`
+    SymmetricDefault,
+    /// `RSAES_OAEP_SHA_1`
+    RsaesOaepSha1,
+    /// `RSAES_OAEP_SHA_256`
+    RsaesOaepSha256,
+    /// `SM2PKE`
+    Sm2pke,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EncryptionAlgorithmSpec {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EncryptionAlgorithmSpec::SymmetricDefault => "SYMMETRIC_DEFAULT",
+            EncryptionAlgorithmSpec::RsaesOaepSha1 => "RSAES_OAEP_SHA_1",
+            EncryptionAlgorithmSpec::RsaesOaepSha256 => "RSAES_OAEP_SHA_256",
+            EncryptionAlgorithmSpec::Sm2pke => "SM2PKE",
+            EncryptionAlgorithmSpec::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EncryptionAlgorithmSpec {
+    fn default() -> EncryptionAlgorithmSpec {
+        EncryptionAlgorithmSpec::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EncryptionAlgorithmSpec {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EncryptionAlgorithmSpec {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EncryptionAlgorithmSpec {
+    fn from(value: &'a str) -> EncryptionAlgorithmSpec {
+        match value {
+            "SYMMETRIC_DEFAULT" => EncryptionAlgorithmSpec::SymmetricDefault,
+            "RSAES_OAEP_SHA_1" => EncryptionAlgorithmSpec::RsaesOaepSha1,
+            "RSAES_OAEP_SHA_256" => EncryptionAlgorithmSpec::RsaesOaepSha256,
+            "SM2PKE" => EncryptionAlgorithmSpec::Sm2pke,
+            _ => EncryptionAlgorithmSpec::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EncryptionAlgorithmSpec {
+    fn from(value: String) -> EncryptionAlgorithmSpec {
+        match value.as_str() {
+            "SYMMETRIC_DEFAULT" => EncryptionAlgorithmSpec::SymmetricDefault,
+            "RSAES_OAEP_SHA_1" => EncryptionAlgorithmSpec::RsaesOaepSha1,
+            "RSAES_OAEP_SHA_256" => EncryptionAlgorithmSpec::RsaesOaepSha256,
+            "SM2PKE" => EncryptionAlgorithmSpec::Sm2pke,
+            _ => EncryptionAlgorithmSpec::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EncryptionAlgorithmSpec {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EncryptionAlgorithmSpec, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EncryptionAlgorithmSpec {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EncryptionAlgorithmSpec {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EncryptionAlgorithmSpec {
+    fn deserialize<D>(deserializer: D) -> Result<EncryptionAlgorithmSpec, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ExpirationModelType {
+    /// `KEY_MATERIAL_EXPIRES`
+    KeyMaterialExpires,
+    /// `KEY_MATERIAL_DOES_NOT_EXPIRE`
+    KeyMaterialDoesNotExpire,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ExpirationModelType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ExpirationModelType::KeyMaterialExpires => "KEY_MATERIAL_EXPIRES",
+            ExpirationModelType::KeyMaterialDoesNotExpire => "KEY_MATERIAL_DOES_NOT_EXPIRE",
+            ExpirationModelType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ExpirationModelType {
+    fn default() -> ExpirationModelType {
+        ExpirationModelType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ExpirationModelType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ExpirationModelType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ExpirationModelType {
+    fn from(value: &'a str) -> ExpirationModelType {
+        match value {
+            "KEY_MATERIAL_EXPIRES" => ExpirationModelType::KeyMaterialExpires,
+            "KEY_MATERIAL_DOES_NOT_EXPIRE" => ExpirationModelType::KeyMaterialDoesNotExpire,
+            _ => ExpirationModelType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ExpirationModelType {
+    fn from(value: String) -> ExpirationModelType {
+        match value.as_str() {
+            "KEY_MATERIAL_EXPIRES" => ExpirationModelType::KeyMaterialExpires,
+            "KEY_MATERIAL_DOES_NOT_EXPIRE" => ExpirationModelType::KeyMaterialDoesNotExpire,
+            _ => ExpirationModelType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ExpirationModelType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ExpirationModelType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ExpirationModelType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ExpirationModelType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ExpirationModelType {
+    fn deserialize<D>(deserializer: D) -> Result<ExpirationModelType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct GenerateDataKeyPairRequest {
@@ -479,7 +1463,7 @@ pub struct GenerateDataKeyPairRequest {
     pub key_id: String,
     /// <p>Determines the type of data key pair that is generated. </p> <p>The AWS KMS rule that restricts the use of asymmetric RSA CMKs to encrypt and decrypt or to sign and verify (but not both), and the rule that permits you to use ECC CMKs only to sign and verify, are not effective outside of AWS KMS.</p>
     #[serde(rename = "KeyPairSpec")]
-    pub key_pair_spec: String,
+    pub key_pair_spec: DataKeyPairSpec,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -492,7 +1476,7 @@ pub struct GenerateDataKeyPairResponse {
     /// <p>The type of data key pair that was generated.</p>
     #[serde(rename = "KeyPairSpec")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub key_pair_spec: Option<String>,
+    pub key_pair_spec: Option<DataKeyPairSpec>,
     /// <p>The encrypted copy of the private key. When you use the HTTP API or the AWS CLI, the value is Base64-encoded. Otherwise, it is not Base64-encoded.</p>
     #[serde(rename = "PrivateKeyCiphertextBlob")]
     #[serde(
@@ -538,7 +1522,7 @@ pub struct GenerateDataKeyPairWithoutPlaintextRequest {
     pub key_id: String,
     /// <p>Determines the type of data key pair that is generated.</p> <p>The AWS KMS rule that restricts the use of asymmetric RSA CMKs to encrypt and decrypt or to sign and verify (but not both), and the rule that permits you to use ECC CMKs only to sign and verify, are not effective outside of AWS KMS.</p>
     #[serde(rename = "KeyPairSpec")]
-    pub key_pair_spec: String,
+    pub key_pair_spec: DataKeyPairSpec,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -551,7 +1535,7 @@ pub struct GenerateDataKeyPairWithoutPlaintextResponse {
     /// <p>The type of data key pair that was generated.</p>
     #[serde(rename = "KeyPairSpec")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub key_pair_spec: Option<String>,
+    pub key_pair_spec: Option<DataKeyPairSpec>,
     /// <p>The encrypted copy of the private key. When you use the HTTP API or the AWS CLI, the value is Base64-encoded. Otherwise, it is not Base64-encoded.</p>
     #[serde(rename = "PrivateKeyCiphertextBlob")]
     #[serde(
@@ -589,7 +1573,7 @@ pub struct GenerateDataKeyRequest {
     /// <p>Specifies the length of the data key. Use <code>AES_128</code> to generate a 128-bit symmetric key, or <code>AES_256</code> to generate a 256-bit symmetric key.</p> <p>You must specify either the <code>KeySpec</code> or the <code>NumberOfBytes</code> parameter (but not both) in every <code>GenerateDataKey</code> request.</p>
     #[serde(rename = "KeySpec")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub key_spec: Option<String>,
+    pub key_spec: Option<DataKeySpec>,
     /// <p>Specifies the length of the data key in bytes. For example, use the value 64 to generate a 512-bit data key (64 bytes is 512 bits). For 128-bit (16-byte) and 256-bit (32-byte) data keys, use the <code>KeySpec</code> parameter.</p> <p>You must specify either the <code>KeySpec</code> or the <code>NumberOfBytes</code> parameter (but not both) in every <code>GenerateDataKey</code> request.</p>
     #[serde(rename = "NumberOfBytes")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -640,7 +1624,7 @@ pub struct GenerateDataKeyWithoutPlaintextRequest {
     /// <p>The length of the data key. Use <code>AES_128</code> to generate a 128-bit symmetric key, or <code>AES_256</code> to generate a 256-bit symmetric key.</p>
     #[serde(rename = "KeySpec")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub key_spec: Option<String>,
+    pub key_spec: Option<DataKeySpec>,
     /// <p>The length of the data key in bytes. For example, use the value 64 to generate a 512-bit data key (64 bytes is 512 bits). For common key lengths (128-bit and 256-bit symmetric keys), we recommend that you use the <code>KeySpec</code> field instead of this one.</p>
     #[serde(rename = "NumberOfBytes")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -737,10 +1721,10 @@ pub struct GetParametersForImportRequest {
     pub key_id: String,
     /// <p>The algorithm you will use to encrypt the key material before importing it with <a>ImportKeyMaterial</a>. For more information, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/importing-keys-encrypt-key-material.html">Encrypt the Key Material</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
     #[serde(rename = "WrappingAlgorithm")]
-    pub wrapping_algorithm: String,
+    pub wrapping_algorithm: AlgorithmSpec,
     /// <p>The type of wrapping key (public key) to return in the response. Only 2048-bit RSA public keys are supported.</p>
     #[serde(rename = "WrappingKeySpec")]
-    pub wrapping_key_spec: String,
+    pub wrapping_key_spec: WrappingKeySpec,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -761,8 +1745,13 @@ pub struct GetParametersForImportResponse {
     pub key_id: Option<String>,
     /// <p>The time at which the import token and public key are no longer valid. After this time, you cannot use them to make an <a>ImportKeyMaterial</a> request and you must send another <code>GetParametersForImport</code> request to get new ones.</p>
     #[serde(rename = "ParametersValidTo")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parameters_valid_to: Option<f64>,
+    pub parameters_valid_to: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The public key to use to encrypt the key material before importing it with <a>ImportKeyMaterial</a>.</p>
     #[serde(rename = "PublicKey")]
     #[serde(
@@ -792,11 +1781,11 @@ pub struct GetPublicKeyResponse {
     /// <p>The type of the of the public key that was downloaded.</p>
     #[serde(rename = "CustomerMasterKeySpec")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub customer_master_key_spec: Option<String>,
+    pub customer_master_key_spec: Option<CustomerMasterKeySpec>,
     /// <p>The encryption algorithms that AWS KMS supports for this key. </p> <p>This information is critical. If a public key encrypts data outside of AWS KMS by using an unsupported encryption algorithm, the ciphertext cannot be decrypted. </p> <p>This field appears in the response only when the <code>KeyUsage</code> of the public key is <code>ENCRYPT_DECRYPT</code>.</p>
     #[serde(rename = "EncryptionAlgorithms")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub encryption_algorithms: Option<Vec<String>>,
+    pub encryption_algorithms: Option<Vec<EncryptionAlgorithmSpec>>,
     /// <p>The identifier of the asymmetric CMK from which the public key was downloaded.</p>
     #[serde(rename = "KeyId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -804,7 +1793,7 @@ pub struct GetPublicKeyResponse {
     /// <p>The permitted use of the public key. Valid values are <code>ENCRYPT_DECRYPT</code> or <code>SIGN_VERIFY</code>. </p> <p>This information is critical. If a public key with <code>SIGN_VERIFY</code> key usage encrypts data outside of AWS KMS, the ciphertext cannot be decrypted. </p>
     #[serde(rename = "KeyUsage")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub key_usage: Option<String>,
+    pub key_usage: Option<KeyUsageType>,
     /// <p>The exported public key. </p> <p>This value is returned as a binary <a href="https://www.itu.int/ITU-T/studygroups/com17/languages/X.690-0207.pdf">Distinguished Encoding Rules</a> (DER)-encoded object. To decode it, use an ASN.1 parsing tool, such as <a href="https://www.openssl.org/docs/man1.0.2/man1/asn1parse.html">OpenSSL asn1parse</a>.</p>
     #[serde(rename = "PublicKey")]
     #[serde(
@@ -817,7 +1806,7 @@ pub struct GetPublicKeyResponse {
     /// <p>The signing algorithms that AWS KMS supports for this key.</p> <p>This field appears in the response only when the <code>KeyUsage</code> of the public key is <code>SIGN_VERIFY</code>.</p>
     #[serde(rename = "SigningAlgorithms")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub signing_algorithms: Option<Vec<String>>,
+    pub signing_algorithms: Option<Vec<SigningAlgorithmSpec>>,
 }
 
 /// <p><p>Use this structure to allow cryptographic operations in the grant only when the operation request includes the specified <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#encrypt_context">encryption context</a>.</p> <p>AWS KMS applies the grant constraints only when the grant allows a cryptographic operation that accepts an encryption context as input, such as the following.</p> <ul> <li> <p> <a>Encrypt</a> </p> </li> <li> <p> <a>Decrypt</a> </p> </li> <li> <p> <a>GenerateDataKey</a> </p> </li> <li> <p> <a>GenerateDataKeyWithoutPlaintext</a> </p> </li> <li> <p> <a>ReEncrypt</a> </p> </li> </ul> <p>AWS KMS does not apply the grant constraints to other operations, such as <a>DescribeKey</a> or <a>ScheduleKeyDeletion</a>.</p> <important> <p>In a cryptographic operation, the encryption context in the decryption operation must be an exact, case-sensitive match for the keys and values in the encryption context of the encryption operation. Only the order of the pairs can vary.</p> <p>However, in a grant constraint, the key in each key-value pair is not case sensitive, but the value is case sensitive.</p> <p>To avoid confusion, do not use multiple encryption context pairs that differ only by case. To require a fully case-sensitive encryption context, use the <code>kms:EncryptionContext:</code> and <code>kms:EncryptionContextKeys</code> conditions in an IAM or key policy. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/policy-conditions.html#conditions-kms-encryption-context">kms:EncryptionContext:</a> in the <i> <i>AWS Key Management Service Developer Guide</i> </i>.</p> </important></p>
@@ -843,8 +1832,13 @@ pub struct GrantListEntry {
     pub constraints: Option<GrantConstraints>,
     /// <p>The date and time when the grant was created.</p>
     #[serde(rename = "CreationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The unique identifier for the grant.</p>
     #[serde(rename = "GrantId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -868,13 +1862,182 @@ pub struct GrantListEntry {
     /// <p>The list of operations permitted by the grant.</p>
     #[serde(rename = "Operations")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub operations: Option<Vec<String>>,
+    pub operations: Option<Vec<GrantOperation>>,
     /// <p>The principal that can retire the grant.</p>
     #[serde(rename = "RetiringPrincipal")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub retiring_principal: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum GrantOperation {
+    /// `Decrypt`
+    Decrypt,
+    /// `Encrypt`
+    Encrypt,
+    /// `GenerateDataKey`
+    GenerateDataKey,
+    /// `GenerateDataKeyWithoutPlaintext`
+    GenerateDataKeyWithoutPlaintext,
+    /// `ReEncryptFrom`
+    ReEncryptFrom,
+    /// `ReEncryptTo`
+    ReEncryptTo,
+    /// `Sign`
+    Sign,
+    /// `Verify`
+    Verify,
+    /// `GetPublicKey`
+    GetPublicKey,
+    /// `CreateGrant`
+    CreateGrant,
+    /// `RetireGrant`
+    RetireGrant,
+    /// `DescribeKey`
+    DescribeKey,
+    /// `GenerateDataKeyPair`
+    GenerateDataKeyPair,
+    /// `GenerateDataKeyPairWithoutPlaintext`
+    GenerateDataKeyPairWithoutPlaintext,
+    /// `GenerateMac`
+    GenerateMac,
+    /// `VerifyMac`
+    VerifyMac,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl GrantOperation {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            GrantOperation::Decrypt => "Decrypt",
+            GrantOperation::Encrypt => "Encrypt",
+            GrantOperation::GenerateDataKey => "GenerateDataKey",
+            GrantOperation::GenerateDataKeyWithoutPlaintext => "GenerateDataKeyWithoutPlaintext",
+            GrantOperation::ReEncryptFrom => "ReEncryptFrom",
+            GrantOperation::ReEncryptTo => "ReEncryptTo",
+            GrantOperation::Sign => "Sign",
+            GrantOperation::Verify => "Verify",
+            GrantOperation::GetPublicKey => "GetPublicKey",
+            GrantOperation::CreateGrant => "CreateGrant",
+            GrantOperation::RetireGrant => "RetireGrant",
+            GrantOperation::DescribeKey => "DescribeKey",
+            GrantOperation::GenerateDataKeyPair => "GenerateDataKeyPair",
+            GrantOperation::GenerateDataKeyPairWithoutPlaintext => {
+                "GenerateDataKeyPairWithoutPlaintext"
+            }
+            GrantOperation::GenerateMac => "GenerateMac",
+            GrantOperation::VerifyMac => "VerifyMac",
+            GrantOperation::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for GrantOperation {
+    fn default() -> GrantOperation {
+        GrantOperation::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for GrantOperation {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for GrantOperation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for GrantOperation {
+    fn from(value: &'a str) -> GrantOperation {
+        match value {
+            "Decrypt" => GrantOperation::Decrypt,
+            "Encrypt" => GrantOperation::Encrypt,
+            "GenerateDataKey" => GrantOperation::GenerateDataKey,
+            "GenerateDataKeyWithoutPlaintext" => GrantOperation::GenerateDataKeyWithoutPlaintext,
+            "ReEncryptFrom" => GrantOperation::ReEncryptFrom,
+            "ReEncryptTo" => GrantOperation::ReEncryptTo,
+            "Sign" => GrantOperation::Sign,
+            "Verify" => GrantOperation::Verify,
+            "GetPublicKey" => GrantOperation::GetPublicKey,
+            "CreateGrant" => GrantOperation::CreateGrant,
+            "RetireGrant" => GrantOperation::RetireGrant,
+            "DescribeKey" => GrantOperation::DescribeKey,
+            "GenerateDataKeyPair" => GrantOperation::GenerateDataKeyPair,
+            "GenerateDataKeyPairWithoutPlaintext" => {
+                GrantOperation::GenerateDataKeyPairWithoutPlaintext
+            }
+            "GenerateMac" => GrantOperation::GenerateMac,
+            "VerifyMac" => GrantOperation::VerifyMac,
+            _ => GrantOperation::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for GrantOperation {
+    fn from(value: String) -> GrantOperation {
+        match value.as_str() {
+            "Decrypt" => GrantOperation::Decrypt,
+            "Encrypt" => GrantOperation::Encrypt,
+            "GenerateDataKey" => GrantOperation::GenerateDataKey,
+            "GenerateDataKeyWithoutPlaintext" => GrantOperation::GenerateDataKeyWithoutPlaintext,
+            "ReEncryptFrom" => GrantOperation::ReEncryptFrom,
+            "ReEncryptTo" => GrantOperation::ReEncryptTo,
+            "Sign" => GrantOperation::Sign,
+            "Verify" => GrantOperation::Verify,
+            "GetPublicKey" => GrantOperation::GetPublicKey,
+            "CreateGrant" => GrantOperation::CreateGrant,
+            "RetireGrant" => GrantOperation::RetireGrant,
+            "DescribeKey" => GrantOperation::DescribeKey,
+            "GenerateDataKeyPair" => GrantOperation::GenerateDataKeyPair,
+            "GenerateDataKeyPairWithoutPlaintext" => {
+                GrantOperation::GenerateDataKeyPairWithoutPlaintext
+            }
+            "GenerateMac" => GrantOperation::GenerateMac,
+            "VerifyMac" => GrantOperation::VerifyMac,
+            _ => GrantOperation::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for GrantOperation {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<GrantOperation, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for GrantOperation {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for GrantOperation {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for GrantOperation {
+    fn deserialize<D>(deserializer: D) -> Result<GrantOperation, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ImportKeyMaterialRequest {
@@ -889,7 +2052,7 @@ pub struct ImportKeyMaterialRequest {
     /// <p>Specifies whether the key material expires. The default is <code>KEY_MATERIAL_EXPIRES</code>, in which case you must include the <code>ValidTo</code> parameter. When this parameter is set to <code>KEY_MATERIAL_DOES_NOT_EXPIRE</code>, you must omit the <code>ValidTo</code> parameter.</p>
     #[serde(rename = "ExpirationModel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expiration_model: Option<String>,
+    pub expiration_model: Option<ExpirationModelType>,
     /// <p>The import token that you received in the response to a previous <a>GetParametersForImport</a> request. It must be from the same response that contained the public key that you used to encrypt the key material.</p>
     #[serde(rename = "ImportToken")]
     #[serde(
@@ -903,8 +2066,13 @@ pub struct ImportKeyMaterialRequest {
     pub key_id: String,
     /// <p>The time at which the imported key material expires. When the key material expires, AWS KMS deletes the key material and the CMK becomes unusable. You must omit this parameter when the <code>ExpirationModel</code> parameter is set to <code>KEY_MATERIAL_DOES_NOT_EXPIRE</code>. Otherwise it is required.</p>
     #[serde(rename = "ValidTo")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valid_to: Option<f64>,
+    pub valid_to: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -925,6 +2093,99 @@ pub struct KeyListEntry {
     pub key_id: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum KeyManagerType {
+    /// `AWS`
+    Aws,
+    /// `CUSTOMER`
+    Customer,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl KeyManagerType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            KeyManagerType::Aws => "AWS",
+            KeyManagerType::Customer => "CUSTOMER",
+            KeyManagerType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for KeyManagerType {
+    fn default() -> KeyManagerType {
+        KeyManagerType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for KeyManagerType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for KeyManagerType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for KeyManagerType {
+    fn from(value: &'a str) -> KeyManagerType {
+        match value {
+            "AWS" => KeyManagerType::Aws,
+            "CUSTOMER" => KeyManagerType::Customer,
+            _ => KeyManagerType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for KeyManagerType {
+    fn from(value: String) -> KeyManagerType {
+        match value.as_str() {
+            "AWS" => KeyManagerType::Aws,
+            "CUSTOMER" => KeyManagerType::Customer,
+            _ => KeyManagerType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for KeyManagerType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<KeyManagerType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for KeyManagerType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for KeyManagerType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for KeyManagerType {
+    fn deserialize<D>(deserializer: D) -> Result<KeyManagerType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Contains metadata about a customer master key (CMK).</p> <p>This data type is used as a response element for the <a>CreateKey</a> and <a>DescribeKey</a> operations.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -943,8 +2204,13 @@ pub struct KeyMetadata {
     pub cloud_hsm_cluster_id: Option<String>,
     /// <p>The date and time when the CMK was created.</p>
     #[serde(rename = "CreationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A unique identifier for the <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">custom key store</a> that contains the CMK. This value is present only when the CMK is created in a custom key store.</p>
     #[serde(rename = "CustomKeyStoreId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -952,11 +2218,16 @@ pub struct KeyMetadata {
     /// <p>Describes the type of key material in the CMK.</p>
     #[serde(rename = "CustomerMasterKeySpec")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub customer_master_key_spec: Option<String>,
+    pub customer_master_key_spec: Option<CustomerMasterKeySpec>,
     /// <p>The date and time after which AWS KMS deletes the CMK. This value is present only when <code>KeyState</code> is <code>PendingDeletion</code>.</p>
     #[serde(rename = "DeletionDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deletion_date: Option<f64>,
+    pub deletion_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The description of the CMK.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -968,40 +2239,266 @@ pub struct KeyMetadata {
     /// <p>A list of encryption algorithms that the CMK supports. You cannot use the CMK with other encryption algorithms within AWS KMS.</p> <p>This field appears only when the <code>KeyUsage</code> of the CMK is <code>ENCRYPT_DECRYPT</code>.</p>
     #[serde(rename = "EncryptionAlgorithms")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub encryption_algorithms: Option<Vec<String>>,
+    pub encryption_algorithms: Option<Vec<EncryptionAlgorithmSpec>>,
     /// <p>Specifies whether the CMK's key material expires. This value is present only when <code>Origin</code> is <code>EXTERNAL</code>, otherwise this value is omitted.</p>
     #[serde(rename = "ExpirationModel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expiration_model: Option<String>,
+    pub expiration_model: Option<ExpirationModelType>,
     /// <p>The globally unique identifier for the CMK.</p>
     #[serde(rename = "KeyId")]
     pub key_id: String,
     /// <p>The manager of the CMK. CMKs in your AWS account are either customer managed or AWS managed. For more information about the difference, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#master_keys">Customer Master Keys</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
     #[serde(rename = "KeyManager")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub key_manager: Option<String>,
+    pub key_manager: Option<KeyManagerType>,
     /// <p>The state of the CMK.</p> <p>For more information about how key state affects the use of a CMK, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects the Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
     #[serde(rename = "KeyState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub key_state: Option<String>,
+    pub key_state: Option<KeyState>,
     /// <p>The cryptographic operations for which you can use the CMK.</p>
     #[serde(rename = "KeyUsage")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub key_usage: Option<String>,
+    pub key_usage: Option<KeyUsageType>,
     /// <p>The source of the CMK's key material. When this value is <code>AWS_KMS</code>, AWS KMS created the key material. When this value is <code>EXTERNAL</code>, the key material was imported from your existing key management infrastructure or the CMK lacks key material. When this value is <code>AWS_CLOUDHSM</code>, the key material was created in the AWS CloudHSM cluster associated with a custom key store.</p>
     #[serde(rename = "Origin")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub origin: Option<String>,
+    pub origin: Option<OriginType>,
     /// <p>A list of signing algorithms that the CMK supports. You cannot use the CMK with other signing algorithms within AWS KMS.</p> <p>This field appears only when the <code>KeyUsage</code> of the CMK is <code>SIGN_VERIFY</code>.</p>
     #[serde(rename = "SigningAlgorithms")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub signing_algorithms: Option<Vec<String>>,
+    pub signing_algorithms: Option<Vec<SigningAlgorithmSpec>>,
     /// <p>The time at which the imported key material expires. When the key material expires, AWS KMS deletes the key material and the CMK becomes unusable. This value is present only for CMKs whose <code>Origin</code> is <code>EXTERNAL</code> and whose <code>ExpirationModel</code> is <code>KEY_MATERIAL_EXPIRES</code>, otherwise this value is omitted.</p>
     #[serde(rename = "ValidTo")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valid_to: Option<f64>,
+    pub valid_to: Option<::chrono::DateTime<::chrono::Utc>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum KeyState {
+    /// `Creating`
+    Creating,
+    /// `Enabled`
+    Enabled,
+    /// `Disabled`
+    Disabled,
+    /// `PendingDeletion`
+    PendingDeletion,
+    /// `PendingImport`
+    PendingImport,
+    /// `PendingReplicaDeletion`
+    PendingReplicaDeletion,
+    /// `Unavailable`
+    Unavailable,
+    /// `Updating`
+    Updating,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl KeyState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            KeyState::Creating => "Creating",
+            KeyState::Enabled => "Enabled",
+            KeyState::Disabled => "Disabled",
+            KeyState::PendingDeletion => "PendingDeletion",
+            KeyState::PendingImport => "PendingImport",
+            KeyState::PendingReplicaDeletion => "PendingReplicaDeletion",
+            KeyState::Unavailable => "Unavailable",
+            KeyState::Updating => "Updating",
+            KeyState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for KeyState {
+    fn default() -> KeyState {
+        KeyState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for KeyState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for KeyState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for KeyState {
+    fn from(value: &'a str) -> KeyState {
+        match value {
+            "Creating" => KeyState::Creating,
+            "Enabled" => KeyState::Enabled,
+            "Disabled" => KeyState::Disabled,
+            "PendingDeletion" => KeyState::PendingDeletion,
+            "PendingImport" => KeyState::PendingImport,
+            "PendingReplicaDeletion" => KeyState::PendingReplicaDeletion,
+            "Unavailable" => KeyState::Unavailable,
+            "Updating" => KeyState::Updating,
+            _ => KeyState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for KeyState {
+    fn from(value: String) -> KeyState {
+        match value.as_str() {
+            "Creating" => KeyState::Creating,
+            "Enabled" => KeyState::Enabled,
+            "Disabled" => KeyState::Disabled,
+            "PendingDeletion" => KeyState::PendingDeletion,
+            "PendingImport" => KeyState::PendingImport,
+            "PendingReplicaDeletion" => KeyState::PendingReplicaDeletion,
+            "Unavailable" => KeyState::Unavailable,
+            "Updating" => KeyState::Updating,
+            _ => KeyState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for KeyState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<KeyState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for KeyState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for KeyState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for KeyState {
+    fn deserialize<D>(deserializer: D) -> Result<KeyState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum KeyUsageType {
+    /// `SIGN_VERIFY`
+    SignVerify,
+    /// `ENCRYPT_DECRYPT`
+    EncryptDecrypt,
+    /// `GENERATE_VERIFY_MAC`
+    GenerateVerifyMac,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl KeyUsageType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            KeyUsageType::SignVerify => "SIGN_VERIFY",
+            KeyUsageType::EncryptDecrypt => "ENCRYPT_DECRYPT",
+            KeyUsageType::GenerateVerifyMac => "GENERATE_VERIFY_MAC",
+            KeyUsageType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for KeyUsageType {
+    fn default() -> KeyUsageType {
+        KeyUsageType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for KeyUsageType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for KeyUsageType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for KeyUsageType {
+    fn from(value: &'a str) -> KeyUsageType {
+        match value {
+            "SIGN_VERIFY" => KeyUsageType::SignVerify,
+            "ENCRYPT_DECRYPT" => KeyUsageType::EncryptDecrypt,
+            "GENERATE_VERIFY_MAC" => KeyUsageType::GenerateVerifyMac,
+            _ => KeyUsageType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for KeyUsageType {
+    fn from(value: String) -> KeyUsageType {
+        match value.as_str() {
+            "SIGN_VERIFY" => KeyUsageType::SignVerify,
+            "ENCRYPT_DECRYPT" => KeyUsageType::EncryptDecrypt,
+            "GENERATE_VERIFY_MAC" => KeyUsageType::GenerateVerifyMac,
+            _ => KeyUsageType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for KeyUsageType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<KeyUsageType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for KeyUsageType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for KeyUsageType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for KeyUsageType {
+    fn deserialize<D>(deserializer: D) -> Result<KeyUsageType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ListAliasesRequest {
@@ -1181,6 +2678,202 @@ pub struct ListRetirableGrantsRequest {
     pub retiring_principal: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MessageType {
+    /// `RAW`
+    Raw,
+    /// `DIGEST`
+    Digest,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl MessageType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MessageType::Raw => "RAW",
+            MessageType::Digest => "DIGEST",
+            MessageType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for MessageType {
+    fn default() -> MessageType {
+        MessageType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for MessageType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for MessageType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for MessageType {
+    fn from(value: &'a str) -> MessageType {
+        match value {
+            "RAW" => MessageType::Raw,
+            "DIGEST" => MessageType::Digest,
+            _ => MessageType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MessageType {
+    fn from(value: String) -> MessageType {
+        match value.as_str() {
+            "RAW" => MessageType::Raw,
+            "DIGEST" => MessageType::Digest,
+            _ => MessageType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for MessageType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<MessageType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MessageType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for MessageType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MessageType {
+    fn deserialize<D>(deserializer: D) -> Result<MessageType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OriginType {
+    /// `AWS_KMS`
+    AwsKms,
+    /// `EXTERNAL`
+    External,
+    /// `AWS_CLOUDHSM`
+    AwsCloudhsm,
+    /// `EXTERNAL_KEY_STORE`
+    ExternalKeyStore,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl OriginType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OriginType::AwsKms => "AWS_KMS",
+            OriginType::External => "EXTERNAL",
+            OriginType::AwsCloudhsm => "AWS_CLOUDHSM",
+            OriginType::ExternalKeyStore => "EXTERNAL_KEY_STORE",
+            OriginType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for OriginType {
+    fn default() -> OriginType {
+        OriginType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for OriginType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for OriginType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for OriginType {
+    fn from(value: &'a str) -> OriginType {
+        match value {
+            "AWS_KMS" => OriginType::AwsKms,
+            "EXTERNAL" => OriginType::External,
+            "AWS_CLOUDHSM" => OriginType::AwsCloudhsm,
+            "EXTERNAL_KEY_STORE" => OriginType::ExternalKeyStore,
+            _ => OriginType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OriginType {
+    fn from(value: String) -> OriginType {
+        match value.as_str() {
+            "AWS_KMS" => OriginType::AwsKms,
+            "EXTERNAL" => OriginType::External,
+            "AWS_CLOUDHSM" => OriginType::AwsCloudhsm,
+            "EXTERNAL_KEY_STORE" => OriginType::ExternalKeyStore,
+            _ => OriginType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for OriginType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<OriginType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OriginType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for OriginType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OriginType {
+    fn deserialize<D>(deserializer: D) -> Result<OriginType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct PutKeyPolicyRequest {
@@ -1213,7 +2906,7 @@ pub struct ReEncryptRequest {
     /// <p>Specifies the encryption algorithm that AWS KMS will use to reecrypt the data after it has decrypted it. The default value, <code>SYMMETRIC_DEFAULT</code>, represents the encryption algorithm used for symmetric CMKs.</p> <p>This parameter is required only when the destination CMK is an asymmetric CMK.</p>
     #[serde(rename = "DestinationEncryptionAlgorithm")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub destination_encryption_algorithm: Option<String>,
+    pub destination_encryption_algorithm: Option<EncryptionAlgorithmSpec>,
     /// <p>Specifies that encryption context to use when the reencrypting the data.</p> <p>A destination encryption context is valid only when the destination CMK is a symmetric CMK. The standard ciphertext format for asymmetric CMKs does not include fields for metadata.</p> <p>An <i>encryption context</i> is a collection of non-secret key-value pairs that represents additional authenticated data. When you use an encryption context to encrypt data, you must specify the same (an exact case-sensitive match) encryption context to decrypt the data. An encryption context is optional when encrypting with a symmetric CMK, but it is highly recommended.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#encrypt_context">Encryption Context</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
     #[serde(rename = "DestinationEncryptionContext")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1228,7 +2921,7 @@ pub struct ReEncryptRequest {
     /// <p>Specifies the encryption algorithm that AWS KMS will use to decrypt the ciphertext before it is reencrypted. The default value, <code>SYMMETRIC_DEFAULT</code>, represents the algorithm used for symmetric CMKs.</p> <p>Specify the same algorithm that was used to encrypt the ciphertext. If you specify a different algorithm, the decrypt attempt fails.</p> <p>This parameter is required only when the ciphertext was encrypted under an asymmetric CMK.</p>
     #[serde(rename = "SourceEncryptionAlgorithm")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub source_encryption_algorithm: Option<String>,
+    pub source_encryption_algorithm: Option<EncryptionAlgorithmSpec>,
     /// <p>Specifies the encryption context to use to decrypt the ciphertext. Enter the same encryption context that was used to encrypt the ciphertext.</p> <p>An <i>encryption context</i> is a collection of non-secret key-value pairs that represents additional authenticated data. When you use an encryption context to encrypt data, you must specify the same (an exact case-sensitive match) encryption context to decrypt the data. An encryption context is optional when encrypting with a symmetric CMK, but it is highly recommended.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#encrypt_context">Encryption Context</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
     #[serde(rename = "SourceEncryptionContext")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1254,7 +2947,7 @@ pub struct ReEncryptResponse {
     /// <p>The encryption algorithm that was used to reencrypt the data.</p>
     #[serde(rename = "DestinationEncryptionAlgorithm")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub destination_encryption_algorithm: Option<String>,
+    pub destination_encryption_algorithm: Option<EncryptionAlgorithmSpec>,
     /// <p>Unique identifier of the CMK used to reencrypt the data.</p>
     #[serde(rename = "KeyId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1262,7 +2955,7 @@ pub struct ReEncryptResponse {
     /// <p>The encryption algorithm that was used to decrypt the ciphertext before it was reencrypted.</p>
     #[serde(rename = "SourceEncryptionAlgorithm")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub source_encryption_algorithm: Option<String>,
+    pub source_encryption_algorithm: Option<EncryptionAlgorithmSpec>,
     /// <p>Unique identifier of the CMK used to originally encrypt the data.</p>
     #[serde(rename = "SourceKeyId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1314,8 +3007,13 @@ pub struct ScheduleKeyDeletionRequest {
 pub struct ScheduleKeyDeletionResponse {
     /// <p>The date and time after which AWS KMS deletes the customer master key (CMK).</p>
     #[serde(rename = "DeletionDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deletion_date: Option<f64>,
+    pub deletion_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The unique identifier of the customer master key (CMK) for which deletion is scheduled.</p>
     #[serde(rename = "KeyId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1343,10 +3041,10 @@ pub struct SignRequest {
     /// <p>Tells AWS KMS whether the value of the <code>Message</code> parameter is a message or message digest. To indicate a message, enter <code>RAW</code>. To indicate a message digest, enter <code>DIGEST</code>.</p>
     #[serde(rename = "MessageType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub message_type: Option<String>,
+    pub message_type: Option<MessageType>,
     /// <p>Specifies the signing algorithm to use when signing the message. </p> <p>Choose an algorithm that is compatible with the type and size of the specified asymmetric CMK.</p>
     #[serde(rename = "SigningAlgorithm")]
-    pub signing_algorithm: String,
+    pub signing_algorithm: SigningAlgorithmSpec,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1368,9 +3066,142 @@ pub struct SignResponse {
     /// <p>The signing algorithm that was used to sign the message.</p>
     #[serde(rename = "SigningAlgorithm")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub signing_algorithm: Option<String>,
+    pub signing_algorithm: Option<SigningAlgorithmSpec>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SigningAlgorithmSpec {
+    /// `RSASSA_PSS_SHA_256`
+    RsassaPssSha256,
+    /// `RSASSA_PSS_SHA_384`
+    RsassaPssSha384,
+    /// `RSASSA_PSS_SHA_512`
+    RsassaPssSha512,
+    /// `RSASSA_PKCS1_V1_5_SHA_256`
+    RsassaPkcs1V1_5Sha256,
+    /// `RSASSA_PKCS1_V1_5_SHA_384`
+    RsassaPkcs1V1_5Sha384,
+    /// `RSASSA_PKCS1_V1_5_SHA_512`
+    RsassaPkcs1V1_5Sha512,
+    /// `ECDSA_SHA_256`
+    EcdsaSha256,
+    /// `ECDSA_SHA_384`
+    EcdsaSha384,
+    /// `ECDSA_SHA_512`
+    EcdsaSha512,
+    /// `SM2DSA`
+    Sm2dsa,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SigningAlgorithmSpec {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SigningAlgorithmSpec::RsassaPssSha256 => "RSASSA_PSS_SHA_256",
+            SigningAlgorithmSpec::RsassaPssSha384 => "RSASSA_PSS_SHA_384",
+            SigningAlgorithmSpec::RsassaPssSha512 => "RSASSA_PSS_SHA_512",
+            SigningAlgorithmSpec::RsassaPkcs1V1_5Sha256 => "RSASSA_PKCS1_V1_5_SHA_256",
+            SigningAlgorithmSpec::RsassaPkcs1V1_5Sha384 => "RSASSA_PKCS1_V1_5_SHA_384",
+            SigningAlgorithmSpec::RsassaPkcs1V1_5Sha512 => "RSASSA_PKCS1_V1_5_SHA_512",
+            SigningAlgorithmSpec::EcdsaSha256 => "ECDSA_SHA_256",
+            SigningAlgorithmSpec::EcdsaSha384 => "ECDSA_SHA_384",
+            SigningAlgorithmSpec::EcdsaSha512 => "ECDSA_SHA_512",
+            SigningAlgorithmSpec::Sm2dsa => "SM2DSA",
+            SigningAlgorithmSpec::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SigningAlgorithmSpec {
+    fn default() -> SigningAlgorithmSpec {
+        SigningAlgorithmSpec::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SigningAlgorithmSpec {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SigningAlgorithmSpec {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SigningAlgorithmSpec {
+    fn from(value: &'a str) -> SigningAlgorithmSpec {
+        match value {
+            "RSASSA_PSS_SHA_256" => SigningAlgorithmSpec::RsassaPssSha256,
+            "RSASSA_PSS_SHA_384" => SigningAlgorithmSpec::RsassaPssSha384,
+            "RSASSA_PSS_SHA_512" => SigningAlgorithmSpec::RsassaPssSha512,
+            "RSASSA_PKCS1_V1_5_SHA_256" => SigningAlgorithmSpec::RsassaPkcs1V1_5Sha256,
+            "RSASSA_PKCS1_V1_5_SHA_384" => SigningAlgorithmSpec::RsassaPkcs1V1_5Sha384,
+            "RSASSA_PKCS1_V1_5_SHA_512" => SigningAlgorithmSpec::RsassaPkcs1V1_5Sha512,
+            "ECDSA_SHA_256" => SigningAlgorithmSpec::EcdsaSha256,
+            "ECDSA_SHA_384" => SigningAlgorithmSpec::EcdsaSha384,
+            "ECDSA_SHA_512" => SigningAlgorithmSpec::EcdsaSha512,
+            "SM2DSA" => SigningAlgorithmSpec::Sm2dsa,
+            _ => SigningAlgorithmSpec::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SigningAlgorithmSpec {
+    fn from(value: String) -> SigningAlgorithmSpec {
+        match value.as_str() {
+            "RSASSA_PSS_SHA_256" => SigningAlgorithmSpec::RsassaPssSha256,
+            "RSASSA_PSS_SHA_384" => SigningAlgorithmSpec::RsassaPssSha384,
+            "RSASSA_PSS_SHA_512" => SigningAlgorithmSpec::RsassaPssSha512,
+            "RSASSA_PKCS1_V1_5_SHA_256" => SigningAlgorithmSpec::RsassaPkcs1V1_5Sha256,
+            "RSASSA_PKCS1_V1_5_SHA_384" => SigningAlgorithmSpec::RsassaPkcs1V1_5Sha384,
+            "RSASSA_PKCS1_V1_5_SHA_512" => SigningAlgorithmSpec::RsassaPkcs1V1_5Sha512,
+            "ECDSA_SHA_256" => SigningAlgorithmSpec::EcdsaSha256,
+            "ECDSA_SHA_384" => SigningAlgorithmSpec::EcdsaSha384,
+            "ECDSA_SHA_512" => SigningAlgorithmSpec::EcdsaSha512,
+            "SM2DSA" => SigningAlgorithmSpec::Sm2dsa,
+            _ => SigningAlgorithmSpec::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SigningAlgorithmSpec {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SigningAlgorithmSpec, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SigningAlgorithmSpec {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SigningAlgorithmSpec {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for SigningAlgorithmSpec {
+    fn deserialize<D>(deserializer: D) -> Result<SigningAlgorithmSpec, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A key-value pair. A tag consists of a tag key and a tag value. Tag keys and tag values are both required, but tag values can be empty (null) strings.</p> <p>For information about the rules that apply to tag keys and tag values, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/allocation-tag-restrictions.html">User-Defined Tag Restrictions</a> in the <i>AWS Billing and Cost Management User Guide</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Tag {
@@ -1471,7 +3302,7 @@ pub struct VerifyRequest {
     /// <p><p>Tells AWS KMS whether the value of the <code>Message</code> parameter is a message or message digest. The default value, RAW, indicates a message. To indicate a message digest, enter <code>DIGEST</code>.</p> <important> <p>Use the <code>DIGEST</code> value only when the value of the <code>Message</code> parameter is a message digest. If you use the <code>DIGEST</code> value with a raw message, the security of the verification operation can be compromised.</p> </important></p>
     #[serde(rename = "MessageType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub message_type: Option<String>,
+    pub message_type: Option<MessageType>,
     /// <p>The signature that the <code>Sign</code> operation generated.</p>
     #[serde(rename = "Signature")]
     #[serde(
@@ -1482,7 +3313,7 @@ pub struct VerifyRequest {
     pub signature: bytes::Bytes,
     /// <p>The signing algorithm that was used to sign the message. If you submit a different algorithm, the signature verification fails.</p>
     #[serde(rename = "SigningAlgorithm")]
-    pub signing_algorithm: String,
+    pub signing_algorithm: SigningAlgorithmSpec,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1499,9 +3330,107 @@ pub struct VerifyResponse {
     /// <p>The signing algorithm that was used to verify the signature.</p>
     #[serde(rename = "SigningAlgorithm")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub signing_algorithm: Option<String>,
+    pub signing_algorithm: Option<SigningAlgorithmSpec>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum WrappingKeySpec {
+    /// `RSA_2048`
+    Rsa2048,
+    /// `RSA_3072`
+    Rsa3072,
+    /// `RSA_4096`
+    Rsa4096,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl WrappingKeySpec {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            WrappingKeySpec::Rsa2048 => "RSA_2048",
+            WrappingKeySpec::Rsa3072 => "RSA_3072",
+            WrappingKeySpec::Rsa4096 => "RSA_4096",
+            WrappingKeySpec::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for WrappingKeySpec {
+    fn default() -> WrappingKeySpec {
+        WrappingKeySpec::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for WrappingKeySpec {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for WrappingKeySpec {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for WrappingKeySpec {
+    fn from(value: &'a str) -> WrappingKeySpec {
+        match value {
+            "RSA_2048" => WrappingKeySpec::Rsa2048,
+            "RSA_3072" => WrappingKeySpec::Rsa3072,
+            "RSA_4096" => WrappingKeySpec::Rsa4096,
+            _ => WrappingKeySpec::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for WrappingKeySpec {
+    fn from(value: String) -> WrappingKeySpec {
+        match value.as_str() {
+            "RSA_2048" => WrappingKeySpec::Rsa2048,
+            "RSA_3072" => WrappingKeySpec::Rsa3072,
+            "RSA_4096" => WrappingKeySpec::Rsa4096,
+            _ => WrappingKeySpec::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for WrappingKeySpec {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<WrappingKeySpec, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for WrappingKeySpec {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
 }
 
+impl ::serde::Serialize for WrappingKeySpec {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for WrappingKeySpec {
+    fn deserialize<D>(deserializer: D) -> Result<WrappingKeySpec, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// Errors returned by CancelKeyDeletion
 #[derive(Debug, PartialEq)]
 pub enum CancelKeyDeletionError {
@@ -4815,11 +6744,12 @@ pub struct KmsClient {
 impl KmsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> KmsClient {
         KmsClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "KMS"),
         }
     }
 
@@ -4851,6 +6781,7 @@ impl Kms for KmsClient {
         input: CancelKeyDeletionRequest,
     ) -> Result<CancelKeyDeletionResponse, RusotoError<CancelKeyDeletionError>> {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("CancelKeyDeletion");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.CancelKeyDeletion");
@@ -4879,6 +6810,7 @@ impl Kms for KmsClient {
         input: ConnectCustomKeyStoreRequest,
     ) -> Result<ConnectCustomKeyStoreResponse, RusotoError<ConnectCustomKeyStoreError>> {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("ConnectCustomKeyStore");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.ConnectCustomKeyStore");
@@ -4907,6 +6839,7 @@ impl Kms for KmsClient {
         input: CreateAliasRequest,
     ) -> Result<(), RusotoError<CreateAliasError>> {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("CreateAlias");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.CreateAlias");
@@ -4934,6 +6867,7 @@ impl Kms for KmsClient {
         input: CreateCustomKeyStoreRequest,
     ) -> Result<CreateCustomKeyStoreResponse, RusotoError<CreateCustomKeyStoreError>> {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("CreateCustomKeyStore");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.CreateCustomKeyStore");
@@ -4962,6 +6896,7 @@ impl Kms for KmsClient {
         input: CreateGrantRequest,
     ) -> Result<CreateGrantResponse, RusotoError<CreateGrantError>> {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("CreateGrant");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.CreateGrant");
@@ -4989,6 +6924,7 @@ impl Kms for KmsClient {
         input: CreateKeyRequest,
     ) -> Result<CreateKeyResponse, RusotoError<CreateKeyError>> {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("CreateKey");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.CreateKey");
@@ -5016,6 +6952,7 @@ impl Kms for KmsClient {
         input: DecryptRequest,
     ) -> Result<DecryptResponse, RusotoError<DecryptError>> {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("Decrypt");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.Decrypt");
@@ -5043,6 +6980,7 @@ impl Kms for KmsClient {
         input: DeleteAliasRequest,
     ) -> Result<(), RusotoError<DeleteAliasError>> {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("DeleteAlias");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.DeleteAlias");
@@ -5070,6 +7008,7 @@ impl Kms for KmsClient {
         input: DeleteCustomKeyStoreRequest,
     ) -> Result<DeleteCustomKeyStoreResponse, RusotoError<DeleteCustomKeyStoreError>> {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("DeleteCustomKeyStore");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.DeleteCustomKeyStore");
@@ -5098,6 +7037,7 @@ impl Kms for KmsClient {
         input: DeleteImportedKeyMaterialRequest,
     ) -> Result<(), RusotoError<DeleteImportedKeyMaterialError>> {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("DeleteImportedKeyMaterial");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.DeleteImportedKeyMaterial");
@@ -5125,6 +7065,7 @@ impl Kms for KmsClient {
         input: DescribeCustomKeyStoresRequest,
     ) -> Result<DescribeCustomKeyStoresResponse, RusotoError<DescribeCustomKeyStoresError>> {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("DescribeCustomKeyStores");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.DescribeCustomKeyStores");
@@ -5153,6 +7094,7 @@ impl Kms for KmsClient {
         input: DescribeKeyRequest,
     ) -> Result<DescribeKeyResponse, RusotoError<DescribeKeyError>> {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("DescribeKey");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.DescribeKey");
@@ -5180,6 +7122,7 @@ impl Kms for KmsClient {
         input: DisableKeyRequest,
     ) -> Result<(), RusotoError<DisableKeyError>> {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("DisableKey");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.DisableKey");
@@ -5207,6 +7150,7 @@ impl Kms for KmsClient {
         input: DisableKeyRotationRequest,
     ) -> Result<(), RusotoError<DisableKeyRotationError>> {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("DisableKeyRotation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.DisableKeyRotation");
@@ -5234,6 +7178,7 @@ impl Kms for KmsClient {
         input: DisconnectCustomKeyStoreRequest,
     ) -> Result<DisconnectCustomKeyStoreResponse, RusotoError<DisconnectCustomKeyStoreError>> {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("DisconnectCustomKeyStore");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.DisconnectCustomKeyStore");
@@ -5259,6 +7204,7 @@ impl Kms for KmsClient {
     /// <p>Sets the key state of a customer master key (CMK) to enabled. This allows you to use the CMK for cryptographic operations. You cannot perform this operation on a CMK in a different AWS account.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
     async fn enable_key(&self, input: EnableKeyRequest) -> Result<(), RusotoError<EnableKeyError>> {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("EnableKey");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.EnableKey");
@@ -5286,6 +7232,7 @@ impl Kms for KmsClient {
         input: EnableKeyRotationRequest,
     ) -> Result<(), RusotoError<EnableKeyRotationError>> {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("EnableKeyRotation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.EnableKeyRotation");
@@ -5313,6 +7260,7 @@ impl Kms for KmsClient {
         input: EncryptRequest,
     ) -> Result<EncryptResponse, RusotoError<EncryptError>> {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("Encrypt");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.Encrypt");
@@ -5340,6 +7288,7 @@ impl Kms for KmsClient {
         input: GenerateDataKeyRequest,
     ) -> Result<GenerateDataKeyResponse, RusotoError<GenerateDataKeyError>> {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("GenerateDataKey");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.GenerateDataKey");
@@ -5367,6 +7316,7 @@ impl Kms for KmsClient {
         input: GenerateDataKeyPairRequest,
     ) -> Result<GenerateDataKeyPairResponse, RusotoError<GenerateDataKeyPairError>> {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("GenerateDataKeyPair");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.GenerateDataKeyPair");
@@ -5398,6 +7348,7 @@ impl Kms for KmsClient {
         RusotoError<GenerateDataKeyPairWithoutPlaintextError>,
     > {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("GenerateDataKeyPairWithoutPlaintext");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5434,6 +7385,7 @@ impl Kms for KmsClient {
         RusotoError<GenerateDataKeyWithoutPlaintextError>,
     > {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("GenerateDataKeyWithoutPlaintext");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5467,6 +7419,7 @@ impl Kms for KmsClient {
         input: GenerateRandomRequest,
     ) -> Result<GenerateRandomResponse, RusotoError<GenerateRandomError>> {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("GenerateRandom");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.GenerateRandom");
@@ -5494,6 +7447,7 @@ impl Kms for KmsClient {
         input: GetKeyPolicyRequest,
     ) -> Result<GetKeyPolicyResponse, RusotoError<GetKeyPolicyError>> {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("GetKeyPolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.GetKeyPolicy");
@@ -5521,6 +7475,7 @@ impl Kms for KmsClient {
         input: GetKeyRotationStatusRequest,
     ) -> Result<GetKeyRotationStatusResponse, RusotoError<GetKeyRotationStatusError>> {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("GetKeyRotationStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.GetKeyRotationStatus");
@@ -5549,6 +7504,7 @@ impl Kms for KmsClient {
         input: GetParametersForImportRequest,
     ) -> Result<GetParametersForImportResponse, RusotoError<GetParametersForImportError>> {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("GetParametersForImport");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.GetParametersForImport");
@@ -5577,6 +7533,7 @@ impl Kms for KmsClient {
         input: GetPublicKeyRequest,
     ) -> Result<GetPublicKeyResponse, RusotoError<GetPublicKeyError>> {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("GetPublicKey");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.GetPublicKey");
@@ -5604,6 +7561,7 @@ impl Kms for KmsClient {
         input: ImportKeyMaterialRequest,
     ) -> Result<ImportKeyMaterialResponse, RusotoError<ImportKeyMaterialError>> {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("ImportKeyMaterial");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.ImportKeyMaterial");
@@ -5632,6 +7590,7 @@ impl Kms for KmsClient {
         input: ListAliasesRequest,
     ) -> Result<ListAliasesResponse, RusotoError<ListAliasesError>> {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("ListAliases");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.ListAliases");
@@ -5659,6 +7618,7 @@ impl Kms for KmsClient {
         input: ListGrantsRequest,
     ) -> Result<ListGrantsResponse, RusotoError<ListGrantsError>> {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("ListGrants");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.ListGrants");
@@ -5686,6 +7646,7 @@ impl Kms for KmsClient {
         input: ListKeyPoliciesRequest,
     ) -> Result<ListKeyPoliciesResponse, RusotoError<ListKeyPoliciesError>> {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("ListKeyPolicies");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.ListKeyPolicies");
@@ -5713,6 +7674,7 @@ impl Kms for KmsClient {
         input: ListKeysRequest,
     ) -> Result<ListKeysResponse, RusotoError<ListKeysError>> {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("ListKeys");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.ListKeys");
@@ -5740,6 +7702,7 @@ impl Kms for KmsClient {
         input: ListResourceTagsRequest,
     ) -> Result<ListResourceTagsResponse, RusotoError<ListResourceTagsError>> {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("ListResourceTags");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.ListResourceTags");
@@ -5768,6 +7731,7 @@ impl Kms for KmsClient {
         input: ListRetirableGrantsRequest,
     ) -> Result<ListGrantsResponse, RusotoError<ListRetirableGrantsError>> {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("ListRetirableGrants");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.ListRetirableGrants");
@@ -5795,6 +7759,7 @@ impl Kms for KmsClient {
         input: PutKeyPolicyRequest,
     ) -> Result<(), RusotoError<PutKeyPolicyError>> {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("PutKeyPolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.PutKeyPolicy");
@@ -5822,6 +7787,7 @@ impl Kms for KmsClient {
         input: ReEncryptRequest,
     ) -> Result<ReEncryptResponse, RusotoError<ReEncryptError>> {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("ReEncrypt");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.ReEncrypt");
@@ -5849,6 +7815,7 @@ impl Kms for KmsClient {
         input: RetireGrantRequest,
     ) -> Result<(), RusotoError<RetireGrantError>> {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("RetireGrant");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.RetireGrant");
@@ -5876,6 +7843,7 @@ impl Kms for KmsClient {
         input: RevokeGrantRequest,
     ) -> Result<(), RusotoError<RevokeGrantError>> {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("RevokeGrant");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.RevokeGrant");
@@ -5903,6 +7871,7 @@ impl Kms for KmsClient {
         input: ScheduleKeyDeletionRequest,
     ) -> Result<ScheduleKeyDeletionResponse, RusotoError<ScheduleKeyDeletionError>> {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("ScheduleKeyDeletion");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.ScheduleKeyDeletion");
@@ -5928,6 +7897,7 @@ impl Kms for KmsClient {
     /// <p>Creates a <a href="https://en.wikipedia.org/wiki/Digital_signature">digital signature</a> for a message or message digest by using the private key in an asymmetric CMK. To verify the signature, use the <a>Verify</a> operation, or use the public key in the same asymmetric CMK outside of AWS KMS. For information about symmetric and asymmetric CMKs, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/symmetric-asymmetric.html">Using Symmetric and Asymmetric CMKs</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>Digital signatures are generated and verified by using asymmetric key pair, such as an RSA or ECC pair that is represented by an asymmetric customer master key (CMK). The key owner (or an authorized user) uses their private key to sign a message. Anyone with the public key can verify that the message was signed with that particular private key and that the message hasn't changed since it was signed. </p> <p>To use the <code>Sign</code> operation, provide the following information:</p> <ul> <li> <p>Use the <code>KeyId</code> parameter to identify an asymmetric CMK with a <code>KeyUsage</code> value of <code>SIGN_VERIFY</code>. To get the <code>KeyUsage</code> value of a CMK, use the <a>DescribeKey</a> operation. The caller must have <code>kms:Sign</code> permission on the CMK.</p> </li> <li> <p>Use the <code>Message</code> parameter to specify the message or message digest to sign. You can submit messages of up to 4096 bytes. To sign a larger message, generate a hash digest of the message, and then provide the hash digest in the <code>Message</code> parameter. To indicate whether the message is a full message or a digest, use the <code>MessageType</code> parameter.</p> </li> <li> <p>Choose a signing algorithm that is compatible with the CMK. </p> </li> </ul> <important> <p>When signing a message, be sure to record the CMK and the signing algorithm. This information is required to verify the signature.</p> </important> <p>To verify the signature that this operation generates, use the <a>Verify</a> operation. Or use the <a>GetPublicKey</a> operation to download the public key and then use the public key to verify the signature outside of AWS KMS. </p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
     async fn sign(&self, input: SignRequest) -> Result<SignResponse, RusotoError<SignError>> {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("Sign");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.Sign");
@@ -5955,6 +7925,7 @@ impl Kms for KmsClient {
         input: TagResourceRequest,
     ) -> Result<(), RusotoError<TagResourceError>> {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.TagResource");
@@ -5982,6 +7953,7 @@ impl Kms for KmsClient {
         input: UntagResourceRequest,
     ) -> Result<(), RusotoError<UntagResourceError>> {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.UntagResource");
@@ -6009,6 +7981,7 @@ impl Kms for KmsClient {
         input: UpdateAliasRequest,
     ) -> Result<(), RusotoError<UpdateAliasError>> {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("UpdateAlias");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.UpdateAlias");
@@ -6036,6 +8009,7 @@ impl Kms for KmsClient {
         input: UpdateCustomKeyStoreRequest,
     ) -> Result<UpdateCustomKeyStoreResponse, RusotoError<UpdateCustomKeyStoreError>> {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("UpdateCustomKeyStore");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.UpdateCustomKeyStore");
@@ -6064,6 +8038,7 @@ impl Kms for KmsClient {
         input: UpdateKeyDescriptionRequest,
     ) -> Result<(), RusotoError<UpdateKeyDescriptionError>> {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("UpdateKeyDescription");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.UpdateKeyDescription");
@@ -6091,6 +8066,7 @@ impl Kms for KmsClient {
         input: VerifyRequest,
     ) -> Result<VerifyResponse, RusotoError<VerifyError>> {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("Verify");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.Verify");
@@ -6112,3 +8088,243 @@ impl Kms for KmsClient {
         }
     }
 }
+impl KmsClient {
+    /// Auto-paginating version of `describe_custom_key_stores`, streaming every page of results.
+    pub fn describe_custom_key_stores_pages(
+        &self,
+        input: DescribeCustomKeyStoresRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeCustomKeyStoresResponse,
+        DescribeCustomKeyStoresError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: KmsClient, input: DescribeCustomKeyStoresRequest| async move {
+                client.describe_custom_key_stores(input).await
+            },
+            |input: &DescribeCustomKeyStoresRequest, output: &DescribeCustomKeyStoresResponse| {
+                if !output.truncated.unwrap_or(false) {
+                    return None;
+                }
+                Some(DescribeCustomKeyStoresRequest {
+                    marker: Some(output.next_marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_custom_key_stores`, streaming the `CustomKeyStores` of every page of results.
+    pub fn describe_custom_key_stores_items(
+        &self,
+        input: DescribeCustomKeyStoresRequest,
+    ) -> rusoto_core::pagination::RusotoStream<CustomKeyStoresListEntry, DescribeCustomKeyStoresError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_custom_key_stores_pages(input),
+            |output: DescribeCustomKeyStoresResponse| output.custom_key_stores.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_aliases`, streaming every page of results.
+    pub fn list_aliases_pages(
+        &self,
+        input: ListAliasesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListAliasesResponse, ListAliasesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: KmsClient, input: ListAliasesRequest| async move {
+                client.list_aliases(input).await
+            },
+            |input: &ListAliasesRequest, output: &ListAliasesResponse| {
+                if !output.truncated.unwrap_or(false) {
+                    return None;
+                }
+                Some(ListAliasesRequest {
+                    marker: Some(output.next_marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_aliases`, streaming the `Aliases` of every page of results.
+    pub fn list_aliases_items(
+        &self,
+        input: ListAliasesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<AliasListEntry, ListAliasesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_aliases_pages(input),
+            |output: ListAliasesResponse| output.aliases.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_grants`, streaming every page of results.
+    pub fn list_grants_pages(
+        &self,
+        input: ListGrantsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListGrantsResponse, ListGrantsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: KmsClient, input: ListGrantsRequest| async move { client.list_grants(input).await },
+            |input: &ListGrantsRequest, output: &ListGrantsResponse| {
+                if !output.truncated.unwrap_or(false) {
+                    return None;
+                }
+                Some(ListGrantsRequest {
+                    marker: Some(output.next_marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_grants`, streaming the `Grants` of every page of results.
+    pub fn list_grants_items(
+        &self,
+        input: ListGrantsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GrantListEntry, ListGrantsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_grants_pages(input),
+            |output: ListGrantsResponse| output.grants.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_key_policies`, streaming every page of results.
+    pub fn list_key_policies_pages(
+        &self,
+        input: ListKeyPoliciesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListKeyPoliciesResponse, ListKeyPoliciesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: KmsClient, input: ListKeyPoliciesRequest| async move {
+                client.list_key_policies(input).await
+            },
+            |input: &ListKeyPoliciesRequest, output: &ListKeyPoliciesResponse| {
+                if !output.truncated.unwrap_or(false) {
+                    return None;
+                }
+                Some(ListKeyPoliciesRequest {
+                    marker: Some(output.next_marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_key_policies`, streaming the `PolicyNames` of every page of results.
+    pub fn list_key_policies_items(
+        &self,
+        input: ListKeyPoliciesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListKeyPoliciesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_key_policies_pages(input),
+            |output: ListKeyPoliciesResponse| output.policy_names.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_keys`, streaming every page of results.
+    pub fn list_keys_pages(
+        &self,
+        input: ListKeysRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListKeysResponse, ListKeysError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: KmsClient, input: ListKeysRequest| async move { client.list_keys(input).await },
+            |input: &ListKeysRequest, output: &ListKeysResponse| {
+                if !output.truncated.unwrap_or(false) {
+                    return None;
+                }
+                Some(ListKeysRequest {
+                    marker: Some(output.next_marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_keys`, streaming the `Keys` of every page of results.
+    pub fn list_keys_items(
+        &self,
+        input: ListKeysRequest,
+    ) -> rusoto_core::pagination::RusotoStream<KeyListEntry, ListKeysError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_keys_pages(input),
+            |output: ListKeysResponse| output.keys.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_resource_tags`, streaming every page of results.
+    pub fn list_resource_tags_pages(
+        &self,
+        input: ListResourceTagsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListResourceTagsResponse, ListResourceTagsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: KmsClient, input: ListResourceTagsRequest| async move {
+                client.list_resource_tags(input).await
+            },
+            |input: &ListResourceTagsRequest, output: &ListResourceTagsResponse| {
+                if !output.truncated.unwrap_or(false) {
+                    return None;
+                }
+                Some(ListResourceTagsRequest {
+                    marker: Some(output.next_marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_resource_tags`, streaming the `Tags` of every page of results.
+    pub fn list_resource_tags_items(
+        &self,
+        input: ListResourceTagsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Tag, ListResourceTagsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_resource_tags_pages(input),
+            |output: ListResourceTagsResponse| output.tags.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_retirable_grants`, streaming every page of results.
+    pub fn list_retirable_grants_pages(
+        &self,
+        input: ListRetirableGrantsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListGrantsResponse, ListRetirableGrantsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: KmsClient, input: ListRetirableGrantsRequest| async move {
+                client.list_retirable_grants(input).await
+            },
+            |input: &ListRetirableGrantsRequest, output: &ListGrantsResponse| {
+                if !output.truncated.unwrap_or(false) {
+                    return None;
+                }
+                Some(ListRetirableGrantsRequest {
+                    marker: Some(output.next_marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_retirable_grants`, streaming the `Grants` of every page of results.
+    pub fn list_retirable_grants_items(
+        &self,
+        input: ListRetirableGrantsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GrantListEntry, ListRetirableGrantsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_retirable_grants_pages(input),
+            |output: ListGrantsResponse| output.grants.unwrap_or_default(),
+        )
+    }
+}
@@ -68,11 +68,11 @@ pub struct BatchPermissionsRequestEntry {
     /// <p>The permissions to be granted.</p>
     #[serde(rename = "Permissions")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub permissions: Option<Vec<String>>,
+    pub permissions: Option<Vec<Permission>>,
     /// <p>Indicates if the option to pass permissions is granted.</p>
     #[serde(rename = "PermissionsWithGrantOption")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub permissions_with_grant_option: Option<Vec<String>>,
+    pub permissions_with_grant_option: Option<Vec<Permission>>,
     /// <p>The principal to be granted a permission.</p>
     #[serde(rename = "Principal")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -117,6 +117,144 @@ pub struct ColumnWildcard {
     pub excluded_column_names: Option<Vec<String>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ComparisonOperator {
+    /// `EQ`
+    Eq,
+    /// `NE`
+    Ne,
+    /// `LE`
+    Le,
+    /// `LT`
+    Lt,
+    /// `GE`
+    Ge,
+    /// `GT`
+    Gt,
+    /// `CONTAINS`
+    Contains,
+    /// `NOT_CONTAINS`
+    NotContains,
+    /// `BEGINS_WITH`
+    BeginsWith,
+    /// `IN`
+    In,
+    /// `BETWEEN`
+    Between,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ComparisonOperator {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ComparisonOperator::Eq => "EQ",
+            ComparisonOperator::Ne => "NE",
+            ComparisonOperator::Le => "LE",
+            ComparisonOperator::Lt => "LT",
+            ComparisonOperator::Ge => "GE",
+            ComparisonOperator::Gt => "GT",
+            ComparisonOperator::Contains => "CONTAINS",
+            ComparisonOperator::NotContains => "NOT_CONTAINS",
+            ComparisonOperator::BeginsWith => "BEGINS_WITH",
+            ComparisonOperator::In => "IN",
+            ComparisonOperator::Between => "BETWEEN",
+            ComparisonOperator::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ComparisonOperator {
+    fn default() -> ComparisonOperator {
+        ComparisonOperator::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ComparisonOperator {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ComparisonOperator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ComparisonOperator {
+    fn from(value: &'a str) -> ComparisonOperator {
+        match value {
+            "EQ" => ComparisonOperator::Eq,
+            "NE" => ComparisonOperator::Ne,
+            "LE" => ComparisonOperator::Le,
+            "LT" => ComparisonOperator::Lt,
+            "GE" => ComparisonOperator::Ge,
+            "GT" => ComparisonOperator::Gt,
+            "CONTAINS" => ComparisonOperator::Contains,
+            "NOT_CONTAINS" => ComparisonOperator::NotContains,
+            "BEGINS_WITH" => ComparisonOperator::BeginsWith,
+            "IN" => ComparisonOperator::In,
+            "BETWEEN" => ComparisonOperator::Between,
+            _ => ComparisonOperator::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ComparisonOperator {
+    fn from(value: String) -> ComparisonOperator {
+        match value.as_str() {
+            "EQ" => ComparisonOperator::Eq,
+            "NE" => ComparisonOperator::Ne,
+            "LE" => ComparisonOperator::Le,
+            "LT" => ComparisonOperator::Lt,
+            "GE" => ComparisonOperator::Ge,
+            "GT" => ComparisonOperator::Gt,
+            "CONTAINS" => ComparisonOperator::Contains,
+            "NOT_CONTAINS" => ComparisonOperator::NotContains,
+            "BEGINS_WITH" => ComparisonOperator::BeginsWith,
+            "IN" => ComparisonOperator::In,
+            "BETWEEN" => ComparisonOperator::Between,
+            _ => ComparisonOperator::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ComparisonOperator {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ComparisonOperator, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ComparisonOperator {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ComparisonOperator {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ComparisonOperator {
+    fn deserialize<D>(deserializer: D) -> Result<ComparisonOperator, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The AWS Lake Formation principal.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DataLakePrincipal {
@@ -126,6 +264,129 @@ pub struct DataLakePrincipal {
     pub data_lake_principal_identifier: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DataLakeResourceType {
+    /// `CATALOG`
+    Catalog,
+    /// `DATABASE`
+    Database,
+    /// `TABLE`
+    Table,
+    /// `DATA_LOCATION`
+    DataLocation,
+    /// `LF_TAG`
+    LfTag,
+    /// `LF_TAG_POLICY`
+    LfTagPolicy,
+    /// `LF_TAG_POLICY_DATABASE`
+    LfTagPolicyDatabase,
+    /// `LF_TAG_POLICY_TABLE`
+    LfTagPolicyTable,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DataLakeResourceType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DataLakeResourceType::Catalog => "CATALOG",
+            DataLakeResourceType::Database => "DATABASE",
+            DataLakeResourceType::Table => "TABLE",
+            DataLakeResourceType::DataLocation => "DATA_LOCATION",
+            DataLakeResourceType::LfTag => "LF_TAG",
+            DataLakeResourceType::LfTagPolicy => "LF_TAG_POLICY",
+            DataLakeResourceType::LfTagPolicyDatabase => "LF_TAG_POLICY_DATABASE",
+            DataLakeResourceType::LfTagPolicyTable => "LF_TAG_POLICY_TABLE",
+            DataLakeResourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DataLakeResourceType {
+    fn default() -> DataLakeResourceType {
+        DataLakeResourceType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DataLakeResourceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DataLakeResourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DataLakeResourceType {
+    fn from(value: &'a str) -> DataLakeResourceType {
+        match value {
+            "CATALOG" => DataLakeResourceType::Catalog,
+            "DATABASE" => DataLakeResourceType::Database,
+            "TABLE" => DataLakeResourceType::Table,
+            "DATA_LOCATION" => DataLakeResourceType::DataLocation,
+            "LF_TAG" => DataLakeResourceType::LfTag,
+            "LF_TAG_POLICY" => DataLakeResourceType::LfTagPolicy,
+            "LF_TAG_POLICY_DATABASE" => DataLakeResourceType::LfTagPolicyDatabase,
+            "LF_TAG_POLICY_TABLE" => DataLakeResourceType::LfTagPolicyTable,
+            _ => DataLakeResourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DataLakeResourceType {
+    fn from(value: String) -> DataLakeResourceType {
+        match value.as_str() {
+            "CATALOG" => DataLakeResourceType::Catalog,
+            "DATABASE" => DataLakeResourceType::Database,
+            "TABLE" => DataLakeResourceType::Table,
+            "DATA_LOCATION" => DataLakeResourceType::DataLocation,
+            "LF_TAG" => DataLakeResourceType::LfTag,
+            "LF_TAG_POLICY" => DataLakeResourceType::LfTagPolicy,
+            "LF_TAG_POLICY_DATABASE" => DataLakeResourceType::LfTagPolicyDatabase,
+            "LF_TAG_POLICY_TABLE" => DataLakeResourceType::LfTagPolicyTable,
+            _ => DataLakeResourceType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DataLakeResourceType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DataLakeResourceType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DataLakeResourceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DataLakeResourceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DataLakeResourceType {
+    fn deserialize<D>(deserializer: D) -> Result<DataLakeResourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The AWS Lake Formation principal.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DataLakeSettings {
@@ -202,6 +463,104 @@ pub struct ErrorDetail {
     pub error_message: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FieldNameString {
+    /// `RESOURCE_ARN`
+    ResourceArn,
+    /// `ROLE_ARN`
+    RoleArn,
+    /// `LAST_MODIFIED`
+    LastModified,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl FieldNameString {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FieldNameString::ResourceArn => "RESOURCE_ARN",
+            FieldNameString::RoleArn => "ROLE_ARN",
+            FieldNameString::LastModified => "LAST_MODIFIED",
+            FieldNameString::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for FieldNameString {
+    fn default() -> FieldNameString {
+        FieldNameString::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for FieldNameString {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FieldNameString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FieldNameString {
+    fn from(value: &'a str) -> FieldNameString {
+        match value {
+            "RESOURCE_ARN" => FieldNameString::ResourceArn,
+            "ROLE_ARN" => FieldNameString::RoleArn,
+            "LAST_MODIFIED" => FieldNameString::LastModified,
+            _ => FieldNameString::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FieldNameString {
+    fn from(value: String) -> FieldNameString {
+        match value.as_str() {
+            "RESOURCE_ARN" => FieldNameString::ResourceArn,
+            "ROLE_ARN" => FieldNameString::RoleArn,
+            "LAST_MODIFIED" => FieldNameString::LastModified,
+            _ => FieldNameString::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for FieldNameString {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<FieldNameString, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FieldNameString {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FieldNameString {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FieldNameString {
+    fn deserialize<D>(deserializer: D) -> Result<FieldNameString, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>This structure describes the filtering of columns in a table based on a filter condition.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -209,11 +568,11 @@ pub struct FilterCondition {
     /// <p>The comparison operator used in the filter condition.</p>
     #[serde(rename = "ComparisonOperator")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub comparison_operator: Option<String>,
+    pub comparison_operator: Option<ComparisonOperator>,
     /// <p>The field to filter in the filter condition.</p>
     #[serde(rename = "Field")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub field: Option<String>,
+    pub field: Option<FieldNameString>,
     /// <p>A string with values used in evaluating the filter condition.</p>
     #[serde(rename = "StringValueList")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -280,11 +639,11 @@ pub struct GrantPermissionsRequest {
     pub catalog_id: Option<String>,
     /// <p>The permissions granted to the principal on the resource. AWS Lake Formation defines privileges to grant and revoke access to metadata in the Data Catalog and data organized in underlying data storage such as Amazon S3. AWS Lake Formation requires that each principal be authorized to perform a specific task on AWS Lake Formation resources. </p>
     #[serde(rename = "Permissions")]
-    pub permissions: Vec<String>,
+    pub permissions: Vec<Permission>,
     /// <p>Indicates a list of the granted permissions that the principal may pass to other users. These permissions may only be a subset of the permissions granted in the <code>Privileges</code>.</p>
     #[serde(rename = "PermissionsWithGrantOption")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub permissions_with_grant_option: Option<Vec<String>>,
+    pub permissions_with_grant_option: Option<Vec<Permission>>,
     /// <p>The principal to be granted the permissions on the resource. Supported principals are IAM users or IAM roles, and they are defined by their principal type and their ARN.</p> <p>Note that if you define a resource with a particular ARN, then later delete, and recreate a resource with that same ARN, the resource maintains the permissions already granted. </p>
     #[serde(rename = "Principal")]
     pub principal: DataLakePrincipal,
@@ -323,7 +682,7 @@ pub struct ListPermissionsRequest {
     /// <p>Specifies a resource type to filter the permissions returned.</p>
     #[serde(rename = "ResourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<DataLakeResourceType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -369,13 +728,161 @@ pub struct ListResourcesResponse {
     pub resource_info_list: Option<Vec<ResourceInfo>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Permission {
+    /// `ALL`
+    All,
+    /// `SELECT`
+    Select,
+    /// `ALTER`
+    Alter,
+    /// `DROP`
+    Drop,
+    /// `DELETE`
+    Delete,
+    /// `INSERT`
+    Insert,
+    /// `DESCRIBE`
+    Describe,
+    /// `CREATE_DATABASE`
+    CreateDatabase,
+    /// `CREATE_TABLE`
+    CreateTable,
+    /// `DATA_LOCATION_ACCESS`
+    DataLocationAccess,
+    /// `CREATE_LF_TAG`
+    CreateLfTag,
+    /// `ASSOCIATE`
+    Associate,
+    /// `GRANT_WITH_LF_TAG_EXPRESSION`
+    GrantWithLfTagExpression,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Permission {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Permission::All => "ALL",
+            Permission::Select => "SELECT",
+            Permission::Alter => "ALTER",
+            Permission::Drop => "DROP",
+            Permission::Delete => "DELETE",
+            Permission::Insert => "INSERT",
+            Permission::Describe => "DESCRIBE",
+            Permission::CreateDatabase => "CREATE_DATABASE",
+            Permission::CreateTable => "CREATE_TABLE",
+            Permission::DataLocationAccess => "DATA_LOCATION_ACCESS",
+            Permission::CreateLfTag => "CREATE_LF_TAG",
+            Permission::Associate => "ASSOCIATE",
+            Permission::GrantWithLfTagExpression => "GRANT_WITH_LF_TAG_EXPRESSION",
+            Permission::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Permission {
+    fn default() -> Permission {
+        Permission::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Permission {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Permission {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Permission {
+    fn from(value: &'a str) -> Permission {
+        match value {
+            "ALL" => Permission::All,
+            "SELECT" => Permission::Select,
+            "ALTER" => Permission::Alter,
+            "DROP" => Permission::Drop,
+            "DELETE" => Permission::Delete,
+            "INSERT" => Permission::Insert,
+            "DESCRIBE" => Permission::Describe,
+            "CREATE_DATABASE" => Permission::CreateDatabase,
+            "CREATE_TABLE" => Permission::CreateTable,
+            "DATA_LOCATION_ACCESS" => Permission::DataLocationAccess,
+            "CREATE_LF_TAG" => Permission::CreateLfTag,
+            "ASSOCIATE" => Permission::Associate,
+            "GRANT_WITH_LF_TAG_EXPRESSION" => Permission::GrantWithLfTagExpression,
+            _ => Permission::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Permission {
+    fn from(value: String) -> Permission {
+        match value.as_str() {
+            "ALL" => Permission::All,
+            "SELECT" => Permission::Select,
+            "ALTER" => Permission::Alter,
+            "DROP" => Permission::Drop,
+            "DELETE" => Permission::Delete,
+            "INSERT" => Permission::Insert,
+            "DESCRIBE" => Permission::Describe,
+            "CREATE_DATABASE" => Permission::CreateDatabase,
+            "CREATE_TABLE" => Permission::CreateTable,
+            "DATA_LOCATION_ACCESS" => Permission::DataLocationAccess,
+            "CREATE_LF_TAG" => Permission::CreateLfTag,
+            "ASSOCIATE" => Permission::Associate,
+            "GRANT_WITH_LF_TAG_EXPRESSION" => Permission::GrantWithLfTagExpression,
+            _ => Permission::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Permission {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Permission, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Permission {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Permission {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Permission {
+    fn deserialize<D>(deserializer: D) -> Result<Permission, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Permissions granted to a principal.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PrincipalPermissions {
     /// <p>The permissions that are granted to the principal.</p>
     #[serde(rename = "Permissions")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub permissions: Option<Vec<String>>,
+    pub permissions: Option<Vec<Permission>>,
     /// <p>The principal who is granted permissions.</p>
     #[serde(rename = "Principal")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -389,11 +896,11 @@ pub struct PrincipalResourcePermissions {
     /// <p>The permissions to be granted or revoked on the resource.</p>
     #[serde(rename = "Permissions")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub permissions: Option<Vec<String>>,
+    pub permissions: Option<Vec<Permission>>,
     /// <p>Indicates whether to grant the ability to grant permissions (as a subset of permissions granted).</p>
     #[serde(rename = "PermissionsWithGrantOption")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub permissions_with_grant_option: Option<Vec<String>>,
+    pub permissions_with_grant_option: Option<Vec<Permission>>,
     /// <p>The Data Lake principal to be granted or revoked permissions.</p>
     #[serde(rename = "Principal")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -471,8 +978,13 @@ pub struct Resource {
 pub struct ResourceInfo {
     /// <p>The date and time the resource was last modified.</p>
     #[serde(rename = "LastModified")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified: Option<f64>,
+    pub last_modified: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The Amazon Resource Name (ARN) of the resource.</p>
     #[serde(rename = "ResourceArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -492,11 +1004,11 @@ pub struct RevokePermissionsRequest {
     pub catalog_id: Option<String>,
     /// <p>The permissions revoked to the principal on the resource. For information about permissions, see <a href="https://docs-aws.amazon.com/michigan/latest/dg/security-data-access.html">Security and Access Control to Metadata and Data</a>.</p>
     #[serde(rename = "Permissions")]
-    pub permissions: Vec<String>,
+    pub permissions: Vec<Permission>,
     /// <p>Indicates a list of permissions for which to revoke the grant option allowing the principal to pass permissions to other principals.</p>
     #[serde(rename = "PermissionsWithGrantOption")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub permissions_with_grant_option: Option<Vec<String>>,
+    pub permissions_with_grant_option: Option<Vec<Permission>>,
     /// <p>The principal to be revoked permissions on the resource.</p>
     #[serde(rename = "Principal")]
     pub principal: DataLakePrincipal,
@@ -1232,11 +1744,12 @@ pub struct LakeFormationClient {
 impl LakeFormationClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> LakeFormationClient {
         LakeFormationClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "LakeFormation"),
         }
     }
 
@@ -1268,6 +1781,7 @@ impl LakeFormation for LakeFormationClient {
         input: BatchGrantPermissionsRequest,
     ) -> Result<BatchGrantPermissionsResponse, RusotoError<BatchGrantPermissionsError>> {
         let mut request = SignedRequest::new("POST", "lakeformation", &self.region, "/");
+        request.set_operation("BatchGrantPermissions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSLakeFormation.BatchGrantPermissions");
@@ -1296,6 +1810,7 @@ impl LakeFormation for LakeFormationClient {
         input: BatchRevokePermissionsRequest,
     ) -> Result<BatchRevokePermissionsResponse, RusotoError<BatchRevokePermissionsError>> {
         let mut request = SignedRequest::new("POST", "lakeformation", &self.region, "/");
+        request.set_operation("BatchRevokePermissions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSLakeFormation.BatchRevokePermissions");
@@ -1324,6 +1839,7 @@ impl LakeFormation for LakeFormationClient {
         input: DeregisterResourceRequest,
     ) -> Result<DeregisterResourceResponse, RusotoError<DeregisterResourceError>> {
         let mut request = SignedRequest::new("POST", "lakeformation", &self.region, "/");
+        request.set_operation("DeregisterResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSLakeFormation.DeregisterResource");
@@ -1352,6 +1868,7 @@ impl LakeFormation for LakeFormationClient {
         input: DescribeResourceRequest,
     ) -> Result<DescribeResourceResponse, RusotoError<DescribeResourceError>> {
         let mut request = SignedRequest::new("POST", "lakeformation", &self.region, "/");
+        request.set_operation("DescribeResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSLakeFormation.DescribeResource");
@@ -1380,6 +1897,7 @@ impl LakeFormation for LakeFormationClient {
         input: GetDataLakeSettingsRequest,
     ) -> Result<GetDataLakeSettingsResponse, RusotoError<GetDataLakeSettingsError>> {
         let mut request = SignedRequest::new("POST", "lakeformation", &self.region, "/");
+        request.set_operation("GetDataLakeSettings");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSLakeFormation.GetDataLakeSettings");
@@ -1411,6 +1929,7 @@ impl LakeFormation for LakeFormationClient {
         RusotoError<GetEffectivePermissionsForPathError>,
     > {
         let mut request = SignedRequest::new("POST", "lakeformation", &self.region, "/");
+        request.set_operation("GetEffectivePermissionsForPath");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1442,6 +1961,7 @@ impl LakeFormation for LakeFormationClient {
         input: GrantPermissionsRequest,
     ) -> Result<GrantPermissionsResponse, RusotoError<GrantPermissionsError>> {
         let mut request = SignedRequest::new("POST", "lakeformation", &self.region, "/");
+        request.set_operation("GrantPermissions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSLakeFormation.GrantPermissions");
@@ -1470,6 +1990,7 @@ impl LakeFormation for LakeFormationClient {
         input: ListPermissionsRequest,
     ) -> Result<ListPermissionsResponse, RusotoError<ListPermissionsError>> {
         let mut request = SignedRequest::new("POST", "lakeformation", &self.region, "/");
+        request.set_operation("ListPermissions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSLakeFormation.ListPermissions");
@@ -1497,6 +2018,7 @@ impl LakeFormation for LakeFormationClient {
         input: ListResourcesRequest,
     ) -> Result<ListResourcesResponse, RusotoError<ListResourcesError>> {
         let mut request = SignedRequest::new("POST", "lakeformation", &self.region, "/");
+        request.set_operation("ListResources");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSLakeFormation.ListResources");
@@ -1524,6 +2046,7 @@ impl LakeFormation for LakeFormationClient {
         input: PutDataLakeSettingsRequest,
     ) -> Result<PutDataLakeSettingsResponse, RusotoError<PutDataLakeSettingsError>> {
         let mut request = SignedRequest::new("POST", "lakeformation", &self.region, "/");
+        request.set_operation("PutDataLakeSettings");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSLakeFormation.PutDataLakeSettings");
@@ -1552,6 +2075,7 @@ impl LakeFormation for LakeFormationClient {
         input: RegisterResourceRequest,
     ) -> Result<RegisterResourceResponse, RusotoError<RegisterResourceError>> {
         let mut request = SignedRequest::new("POST", "lakeformation", &self.region, "/");
+        request.set_operation("RegisterResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSLakeFormation.RegisterResource");
@@ -1580,6 +2104,7 @@ impl LakeFormation for LakeFormationClient {
         input: RevokePermissionsRequest,
     ) -> Result<RevokePermissionsResponse, RusotoError<RevokePermissionsError>> {
         let mut request = SignedRequest::new("POST", "lakeformation", &self.region, "/");
+        request.set_operation("RevokePermissions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSLakeFormation.RevokePermissions");
@@ -1608,6 +2133,7 @@ impl LakeFormation for LakeFormationClient {
         input: UpdateResourceRequest,
     ) -> Result<UpdateResourceResponse, RusotoError<UpdateResourceError>> {
         let mut request = SignedRequest::new("POST", "lakeformation", &self.region, "/");
+        request.set_operation("UpdateResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSLakeFormation.UpdateResource");
@@ -266,11 +266,16 @@ pub struct CreateEventSourceMappingRequest {
     /// <p>The position in a stream from which to start reading. Required for Amazon Kinesis and Amazon DynamoDB Streams sources. <code>AT_TIMESTAMP</code> is only supported for Amazon Kinesis streams.</p>
     #[serde(rename = "StartingPosition")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub starting_position: Option<String>,
+    pub starting_position: Option<EventSourcePosition>,
     /// <p>With <code>StartingPosition</code> set to <code>AT_TIMESTAMP</code>, the time from which to start reading.</p>
     #[serde(rename = "StartingPositionTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub starting_position_timestamp: Option<f64>,
+    pub starting_position_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -318,7 +323,7 @@ pub struct CreateFunctionRequest {
     pub role: String,
     /// <p>The identifier of the function's <a href="https://docs.aws.amazon.com/lambda/latest/dg/lambda-runtimes.html">runtime</a>.</p>
     #[serde(rename = "Runtime")]
-    pub runtime: String,
+    pub runtime: Runtime,
     /// <p>A list of <a href="https://docs.aws.amazon.com/lambda/latest/dg/tagging.html">tags</a> to apply to the function.</p>
     #[serde(rename = "Tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -496,8 +501,13 @@ pub struct EventSourceMappingConfiguration {
     pub function_arn: Option<String>,
     /// <p>The date that the event source mapping was last updated, or its state changed.</p>
     #[serde(rename = "LastModified")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified: Option<f64>,
+    pub last_modified: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The result of the last AWS Lambda invocation of your Lambda function.</p>
     #[serde(rename = "LastProcessingResult")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -532,6 +542,104 @@ pub struct EventSourceMappingConfiguration {
     pub uuid: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EventSourcePosition {
+    /// `TRIM_HORIZON`
+    TrimHorizon,
+    /// `LATEST`
+    Latest,
+    /// `AT_TIMESTAMP`
+    AtTimestamp,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EventSourcePosition {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EventSourcePosition::TrimHorizon => "TRIM_HORIZON",
+            EventSourcePosition::Latest => "LATEST",
+            EventSourcePosition::AtTimestamp => "AT_TIMESTAMP",
+            EventSourcePosition::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EventSourcePosition {
+    fn default() -> EventSourcePosition {
+        EventSourcePosition::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EventSourcePosition {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EventSourcePosition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EventSourcePosition {
+    fn from(value: &'a str) -> EventSourcePosition {
+        match value {
+            "TRIM_HORIZON" => EventSourcePosition::TrimHorizon,
+            "LATEST" => EventSourcePosition::Latest,
+            "AT_TIMESTAMP" => EventSourcePosition::AtTimestamp,
+            _ => EventSourcePosition::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EventSourcePosition {
+    fn from(value: String) -> EventSourcePosition {
+        match value.as_str() {
+            "TRIM_HORIZON" => EventSourcePosition::TrimHorizon,
+            "LATEST" => EventSourcePosition::Latest,
+            "AT_TIMESTAMP" => EventSourcePosition::AtTimestamp,
+            _ => EventSourcePosition::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EventSourcePosition {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EventSourcePosition, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EventSourcePosition {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EventSourcePosition {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EventSourcePosition {
+    fn deserialize<D>(deserializer: D) -> Result<EventSourcePosition, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The code for the Lambda function. You can specify either an object in Amazon S3, or upload a deployment package directly.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -620,7 +728,7 @@ pub struct FunctionConfiguration {
     /// <p>The status of the last update that was performed on the function. This is first set to <code>Successful</code> after function creation completes.</p>
     #[serde(rename = "LastUpdateStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_update_status: Option<String>,
+    pub last_update_status: Option<LastUpdateStatus>,
     /// <p>The reason for the last update that was performed on the function.</p>
     #[serde(rename = "LastUpdateStatusReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -628,7 +736,7 @@ pub struct FunctionConfiguration {
     /// <p>The reason code for the last update that was performed on the function.</p>
     #[serde(rename = "LastUpdateStatusReasonCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_update_status_reason_code: Option<String>,
+    pub last_update_status_reason_code: Option<LastUpdateStatusReasonCode>,
     /// <p>The function's <a href="https://docs.aws.amazon.com/lambda/latest/dg/configuration-layers.html"> layers</a>.</p>
     #[serde(rename = "Layers")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -652,11 +760,11 @@ pub struct FunctionConfiguration {
     /// <p>The runtime environment for the Lambda function.</p>
     #[serde(rename = "Runtime")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub runtime: Option<String>,
+    pub runtime: Option<Runtime>,
     /// <p>The current state of the function. When the state is <code>Inactive</code>, you can reactivate the function by invoking it.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<State>,
     /// <p>The reason for the function's current state.</p>
     #[serde(rename = "StateReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -664,7 +772,7 @@ pub struct FunctionConfiguration {
     /// <p>The reason code for the function's current state. When the code is <code>Creating</code>, you can't invoke or modify the function.</p>
     #[serde(rename = "StateReasonCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state_reason_code: Option<String>,
+    pub state_reason_code: Option<StateReasonCode>,
     /// <p>The amount of time that Lambda allows a function to run before stopping it.</p>
     #[serde(rename = "Timeout")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -696,8 +804,13 @@ pub struct FunctionEventInvokeConfig {
     pub function_arn: Option<String>,
     /// <p>The date and time that the configuration was last updated.</p>
     #[serde(rename = "LastModified")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified: Option<f64>,
+    pub last_modified: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The maximum age of a request that Lambda sends to a function for processing.</p>
     #[serde(rename = "MaximumEventAgeInSeconds")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -708,6 +821,94 @@ pub struct FunctionEventInvokeConfig {
     pub maximum_retry_attempts: Option<i64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FunctionVersion {
+    /// `ALL`
+    All,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl FunctionVersion {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FunctionVersion::All => "ALL",
+            FunctionVersion::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for FunctionVersion {
+    fn default() -> FunctionVersion {
+        FunctionVersion::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for FunctionVersion {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FunctionVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FunctionVersion {
+    fn from(value: &'a str) -> FunctionVersion {
+        match value {
+            "ALL" => FunctionVersion::All,
+            _ => FunctionVersion::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FunctionVersion {
+    fn from(value: String) -> FunctionVersion {
+        match value.as_str() {
+            "ALL" => FunctionVersion::All,
+            _ => FunctionVersion::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for FunctionVersion {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<FunctionVersion, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FunctionVersion {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FunctionVersion {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FunctionVersion {
+    fn deserialize<D>(deserializer: D) -> Result<FunctionVersion, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct GetAccountSettingsRequest {}
@@ -867,7 +1068,7 @@ pub struct GetLayerVersionResponse {
     /// <p>The layer's compatible runtimes.</p>
     #[serde(rename = "CompatibleRuntimes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compatible_runtimes: Option<Vec<String>>,
+    pub compatible_runtimes: Option<Vec<Runtime>>,
     /// <p>Details about the layer version.</p>
     #[serde(rename = "Content")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -956,7 +1157,7 @@ pub struct GetProvisionedConcurrencyConfigResponse {
     /// <p>The status of the allocation process.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ProvisionedConcurrencyStatusEnum>,
     /// <p>For failed allocations, the reason that provisioned concurrency could not be allocated.</p>
     #[serde(rename = "StatusReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -976,11 +1177,11 @@ pub struct InvocationRequest {
     /// <p><p>Choose from the following options.</p> <ul> <li> <p> <code>RequestResponse</code> (default) - Invoke the function synchronously. Keep the connection open until the function returns a response or times out. The API response includes the function response and additional data.</p> </li> <li> <p> <code>Event</code> - Invoke the function asynchronously. Send events that fail multiple times to the function&#39;s dead-letter queue (if it&#39;s configured). The API response only includes a status code.</p> </li> <li> <p> <code>DryRun</code> - Validate parameter values and verify that the user or role has permission to invoke the function.</p> </li> </ul></p>
     #[serde(rename = "InvocationType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub invocation_type: Option<String>,
+    pub invocation_type: Option<InvocationType>,
     /// <p>Set to <code>Tail</code> to include the execution log in the response.</p>
     #[serde(rename = "LogType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub log_type: Option<String>,
+    pub log_type: Option<LogType>,
     /// <p>The JSON that you want to provide to your Lambda function as input.</p>
     #[serde(rename = "Payload")]
     #[serde(
@@ -1010,6 +1211,104 @@ pub struct InvocationResponse {
     pub status_code: Option<i64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum InvocationType {
+    /// `Event`
+    Event,
+    /// `RequestResponse`
+    RequestResponse,
+    /// `DryRun`
+    DryRun,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl InvocationType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InvocationType::Event => "Event",
+            InvocationType::RequestResponse => "RequestResponse",
+            InvocationType::DryRun => "DryRun",
+            InvocationType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for InvocationType {
+    fn default() -> InvocationType {
+        InvocationType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for InvocationType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for InvocationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for InvocationType {
+    fn from(value: &'a str) -> InvocationType {
+        match value {
+            "Event" => InvocationType::Event,
+            "RequestResponse" => InvocationType::RequestResponse,
+            "DryRun" => InvocationType::DryRun,
+            _ => InvocationType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InvocationType {
+    fn from(value: String) -> InvocationType {
+        match value.as_str() {
+            "Event" => InvocationType::Event,
+            "RequestResponse" => InvocationType::RequestResponse,
+            "DryRun" => InvocationType::DryRun,
+            _ => InvocationType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for InvocationType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<InvocationType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for InvocationType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for InvocationType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InvocationType {
+    fn deserialize<D>(deserializer: D) -> Result<InvocationType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct InvokeAsyncRequest {
@@ -1036,6 +1335,298 @@ pub struct InvokeAsyncResponse {
     pub status: Option<i64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LastUpdateStatus {
+    /// `Successful`
+    Successful,
+    /// `Failed`
+    Failed,
+    /// `InProgress`
+    InProgress,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LastUpdateStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LastUpdateStatus::Successful => "Successful",
+            LastUpdateStatus::Failed => "Failed",
+            LastUpdateStatus::InProgress => "InProgress",
+            LastUpdateStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LastUpdateStatus {
+    fn default() -> LastUpdateStatus {
+        LastUpdateStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LastUpdateStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LastUpdateStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LastUpdateStatus {
+    fn from(value: &'a str) -> LastUpdateStatus {
+        match value {
+            "Successful" => LastUpdateStatus::Successful,
+            "Failed" => LastUpdateStatus::Failed,
+            "InProgress" => LastUpdateStatus::InProgress,
+            _ => LastUpdateStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LastUpdateStatus {
+    fn from(value: String) -> LastUpdateStatus {
+        match value.as_str() {
+            "Successful" => LastUpdateStatus::Successful,
+            "Failed" => LastUpdateStatus::Failed,
+            "InProgress" => LastUpdateStatus::InProgress,
+            _ => LastUpdateStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LastUpdateStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LastUpdateStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LastUpdateStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LastUpdateStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LastUpdateStatus {
+    fn deserialize<D>(deserializer: D) -> Result<LastUpdateStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LastUpdateStatusReasonCode {
+    /// `EniLimitExceeded`
+    EniLimitExceeded,
+    /// `InsufficientRolePermissions`
+    InsufficientRolePermissions,
+    /// `InvalidConfiguration`
+    InvalidConfiguration,
+    /// `InternalError`
+    InternalError,
+    /// `SubnetOutOfIPAddresses`
+    SubnetOutOfIPAddresses,
+    /// `InvalidSubnet`
+    InvalidSubnet,
+    /// `InvalidSecurityGroup`
+    InvalidSecurityGroup,
+    /// `ImageDeleted`
+    ImageDeleted,
+    /// `ImageAccessDenied`
+    ImageAccessDenied,
+    /// `InvalidImage`
+    InvalidImage,
+    /// `KMSKeyAccessDenied`
+    KMSKeyAccessDenied,
+    /// `KMSKeyNotFound`
+    KMSKeyNotFound,
+    /// `InvalidStateKMSKey`
+    InvalidStateKMSKey,
+    /// `DisabledKMSKey`
+    DisabledKMSKey,
+    /// `EFSIOError`
+    EFSIOError,
+    /// `EFSMountConnectivityError`
+    EFSMountConnectivityError,
+    /// `EFSMountFailure`
+    EFSMountFailure,
+    /// `EFSMountTimeout`
+    EFSMountTimeout,
+    /// `InvalidRuntime`
+    InvalidRuntime,
+    /// `InvalidZipFileException`
+    InvalidZipFileException,
+    /// `FunctionError`
+    FunctionError,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LastUpdateStatusReasonCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LastUpdateStatusReasonCode::EniLimitExceeded => "EniLimitExceeded",
+            LastUpdateStatusReasonCode::InsufficientRolePermissions => {
+                "InsufficientRolePermissions"
+            }
+            LastUpdateStatusReasonCode::InvalidConfiguration => "InvalidConfiguration",
+            LastUpdateStatusReasonCode::InternalError => "InternalError",
+            LastUpdateStatusReasonCode::SubnetOutOfIPAddresses => "SubnetOutOfIPAddresses",
+            LastUpdateStatusReasonCode::InvalidSubnet => "InvalidSubnet",
+            LastUpdateStatusReasonCode::InvalidSecurityGroup => "InvalidSecurityGroup",
+            LastUpdateStatusReasonCode::ImageDeleted => "ImageDeleted",
+            LastUpdateStatusReasonCode::ImageAccessDenied => "ImageAccessDenied",
+            LastUpdateStatusReasonCode::InvalidImage => "InvalidImage",
+            LastUpdateStatusReasonCode::KMSKeyAccessDenied => "KMSKeyAccessDenied",
+            LastUpdateStatusReasonCode::KMSKeyNotFound => "KMSKeyNotFound",
+            LastUpdateStatusReasonCode::InvalidStateKMSKey => "InvalidStateKMSKey",
+            LastUpdateStatusReasonCode::DisabledKMSKey => "DisabledKMSKey",
+            LastUpdateStatusReasonCode::EFSIOError => "EFSIOError",
+            LastUpdateStatusReasonCode::EFSMountConnectivityError => "EFSMountConnectivityError",
+            LastUpdateStatusReasonCode::EFSMountFailure => "EFSMountFailure",
+            LastUpdateStatusReasonCode::EFSMountTimeout => "EFSMountTimeout",
+            LastUpdateStatusReasonCode::InvalidRuntime => "InvalidRuntime",
+            LastUpdateStatusReasonCode::InvalidZipFileException => "InvalidZipFileException",
+            LastUpdateStatusReasonCode::FunctionError => "FunctionError",
+            LastUpdateStatusReasonCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LastUpdateStatusReasonCode {
+    fn default() -> LastUpdateStatusReasonCode {
+        LastUpdateStatusReasonCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LastUpdateStatusReasonCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LastUpdateStatusReasonCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LastUpdateStatusReasonCode {
+    fn from(value: &'a str) -> LastUpdateStatusReasonCode {
+        match value {
+            "EniLimitExceeded" => LastUpdateStatusReasonCode::EniLimitExceeded,
+            "InsufficientRolePermissions" => {
+                LastUpdateStatusReasonCode::InsufficientRolePermissions
+            }
+            "InvalidConfiguration" => LastUpdateStatusReasonCode::InvalidConfiguration,
+            "InternalError" => LastUpdateStatusReasonCode::InternalError,
+            "SubnetOutOfIPAddresses" => LastUpdateStatusReasonCode::SubnetOutOfIPAddresses,
+            "InvalidSubnet" => LastUpdateStatusReasonCode::InvalidSubnet,
+            "InvalidSecurityGroup" => LastUpdateStatusReasonCode::InvalidSecurityGroup,
+            "ImageDeleted" => LastUpdateStatusReasonCode::ImageDeleted,
+            "ImageAccessDenied" => LastUpdateStatusReasonCode::ImageAccessDenied,
+            "InvalidImage" => LastUpdateStatusReasonCode::InvalidImage,
+            "KMSKeyAccessDenied" => LastUpdateStatusReasonCode::KMSKeyAccessDenied,
+            "KMSKeyNotFound" => LastUpdateStatusReasonCode::KMSKeyNotFound,
+            "InvalidStateKMSKey" => LastUpdateStatusReasonCode::InvalidStateKMSKey,
+            "DisabledKMSKey" => LastUpdateStatusReasonCode::DisabledKMSKey,
+            "EFSIOError" => LastUpdateStatusReasonCode::EFSIOError,
+            "EFSMountConnectivityError" => LastUpdateStatusReasonCode::EFSMountConnectivityError,
+            "EFSMountFailure" => LastUpdateStatusReasonCode::EFSMountFailure,
+            "EFSMountTimeout" => LastUpdateStatusReasonCode::EFSMountTimeout,
+            "InvalidRuntime" => LastUpdateStatusReasonCode::InvalidRuntime,
+            "InvalidZipFileException" => LastUpdateStatusReasonCode::InvalidZipFileException,
+            "FunctionError" => LastUpdateStatusReasonCode::FunctionError,
+            _ => LastUpdateStatusReasonCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LastUpdateStatusReasonCode {
+    fn from(value: String) -> LastUpdateStatusReasonCode {
+        match value.as_str() {
+            "EniLimitExceeded" => LastUpdateStatusReasonCode::EniLimitExceeded,
+            "InsufficientRolePermissions" => {
+                LastUpdateStatusReasonCode::InsufficientRolePermissions
+            }
+            "InvalidConfiguration" => LastUpdateStatusReasonCode::InvalidConfiguration,
+            "InternalError" => LastUpdateStatusReasonCode::InternalError,
+            "SubnetOutOfIPAddresses" => LastUpdateStatusReasonCode::SubnetOutOfIPAddresses,
+            "InvalidSubnet" => LastUpdateStatusReasonCode::InvalidSubnet,
+            "InvalidSecurityGroup" => LastUpdateStatusReasonCode::InvalidSecurityGroup,
+            "ImageDeleted" => LastUpdateStatusReasonCode::ImageDeleted,
+            "ImageAccessDenied" => LastUpdateStatusReasonCode::ImageAccessDenied,
+            "InvalidImage" => LastUpdateStatusReasonCode::InvalidImage,
+            "KMSKeyAccessDenied" => LastUpdateStatusReasonCode::KMSKeyAccessDenied,
+            "KMSKeyNotFound" => LastUpdateStatusReasonCode::KMSKeyNotFound,
+            "InvalidStateKMSKey" => LastUpdateStatusReasonCode::InvalidStateKMSKey,
+            "DisabledKMSKey" => LastUpdateStatusReasonCode::DisabledKMSKey,
+            "EFSIOError" => LastUpdateStatusReasonCode::EFSIOError,
+            "EFSMountConnectivityError" => LastUpdateStatusReasonCode::EFSMountConnectivityError,
+            "EFSMountFailure" => LastUpdateStatusReasonCode::EFSMountFailure,
+            "EFSMountTimeout" => LastUpdateStatusReasonCode::EFSMountTimeout,
+            "InvalidRuntime" => LastUpdateStatusReasonCode::InvalidRuntime,
+            "InvalidZipFileException" => LastUpdateStatusReasonCode::InvalidZipFileException,
+            "FunctionError" => LastUpdateStatusReasonCode::FunctionError,
+            _ => LastUpdateStatusReasonCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LastUpdateStatusReasonCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LastUpdateStatusReasonCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LastUpdateStatusReasonCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LastUpdateStatusReasonCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LastUpdateStatusReasonCode {
+    fn deserialize<D>(deserializer: D) -> Result<LastUpdateStatusReasonCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An <a href="https://docs.aws.amazon.com/lambda/latest/dg/configuration-layers.html">AWS Lambda layer</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1102,7 +1693,7 @@ pub struct LayerVersionsListItem {
     /// <p>The layer's compatible runtimes.</p>
     #[serde(rename = "CompatibleRuntimes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compatible_runtimes: Option<Vec<String>>,
+    pub compatible_runtimes: Option<Vec<Runtime>>,
     /// <p>The date that the version was created, in ISO 8601 format. For example, <code>2018-11-27T15:10:45.123+0000</code>.</p>
     #[serde(rename = "CreatedDate")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1245,7 +1836,7 @@ pub struct ListFunctionsRequest {
     /// <p>Set to <code>ALL</code> to include entries for all published versions of each function.</p>
     #[serde(rename = "FunctionVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub function_version: Option<String>,
+    pub function_version: Option<FunctionVersion>,
     /// <p>Specify the pagination token that's returned by a previous request to retrieve the next page of results.</p>
     #[serde(rename = "Marker")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1280,7 +1871,7 @@ pub struct ListLayerVersionsRequest {
     /// <p>A runtime identifier. For example, <code>go1.x</code>.</p>
     #[serde(rename = "CompatibleRuntime")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compatible_runtime: Option<String>,
+    pub compatible_runtime: Option<Runtime>,
     /// <p>The name or Amazon Resource Name (ARN) of the layer.</p>
     #[serde(rename = "LayerName")]
     pub layer_name: String,
@@ -1313,7 +1904,7 @@ pub struct ListLayersRequest {
     /// <p>A runtime identifier. For example, <code>go1.x</code>.</p>
     #[serde(rename = "CompatibleRuntime")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compatible_runtime: Option<String>,
+    pub compatible_runtime: Option<Runtime>,
     /// <p>A pagination token returned by a previous call.</p>
     #[serde(rename = "Marker")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1412,6 +2003,99 @@ pub struct ListVersionsByFunctionResponse {
     pub versions: Option<Vec<FunctionConfiguration>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LogType {
+    /// `None`
+    None,
+    /// `Tail`
+    Tail,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LogType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LogType::None => "None",
+            LogType::Tail => "Tail",
+            LogType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LogType {
+    fn default() -> LogType {
+        LogType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LogType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LogType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LogType {
+    fn from(value: &'a str) -> LogType {
+        match value {
+            "None" => LogType::None,
+            "Tail" => LogType::Tail,
+            _ => LogType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LogType {
+    fn from(value: String) -> LogType {
+        match value.as_str() {
+            "None" => LogType::None,
+            "Tail" => LogType::Tail,
+            _ => LogType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LogType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LogType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LogType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LogType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LogType {
+    fn deserialize<D>(deserializer: D) -> Result<LogType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A destination for events that failed processing.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct OnFailure {
@@ -1457,20 +2141,118 @@ pub struct ProvisionedConcurrencyConfigListItem {
     /// <p>The status of the allocation process.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ProvisionedConcurrencyStatusEnum>,
     /// <p>For failed allocations, the reason that provisioned concurrency could not be allocated.</p>
     #[serde(rename = "StatusReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status_reason: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ProvisionedConcurrencyStatusEnum {
+    /// `IN_PROGRESS`
+    InProgress,
+    /// `READY`
+    Ready,
+    /// `FAILED`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ProvisionedConcurrencyStatusEnum {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ProvisionedConcurrencyStatusEnum::InProgress => "IN_PROGRESS",
+            ProvisionedConcurrencyStatusEnum::Ready => "READY",
+            ProvisionedConcurrencyStatusEnum::Failed => "FAILED",
+            ProvisionedConcurrencyStatusEnum::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ProvisionedConcurrencyStatusEnum {
+    fn default() -> ProvisionedConcurrencyStatusEnum {
+        ProvisionedConcurrencyStatusEnum::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ProvisionedConcurrencyStatusEnum {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ProvisionedConcurrencyStatusEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ProvisionedConcurrencyStatusEnum {
+    fn from(value: &'a str) -> ProvisionedConcurrencyStatusEnum {
+        match value {
+            "IN_PROGRESS" => ProvisionedConcurrencyStatusEnum::InProgress,
+            "READY" => ProvisionedConcurrencyStatusEnum::Ready,
+            "FAILED" => ProvisionedConcurrencyStatusEnum::Failed,
+            _ => ProvisionedConcurrencyStatusEnum::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ProvisionedConcurrencyStatusEnum {
+    fn from(value: String) -> ProvisionedConcurrencyStatusEnum {
+        match value.as_str() {
+            "IN_PROGRESS" => ProvisionedConcurrencyStatusEnum::InProgress,
+            "READY" => ProvisionedConcurrencyStatusEnum::Ready,
+            "FAILED" => ProvisionedConcurrencyStatusEnum::Failed,
+            _ => ProvisionedConcurrencyStatusEnum::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ProvisionedConcurrencyStatusEnum {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ProvisionedConcurrencyStatusEnum, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ProvisionedConcurrencyStatusEnum {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ProvisionedConcurrencyStatusEnum {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ProvisionedConcurrencyStatusEnum {
+    fn deserialize<D>(deserializer: D) -> Result<ProvisionedConcurrencyStatusEnum, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct PublishLayerVersionRequest {
     /// <p>A list of compatible <a href="https://docs.aws.amazon.com/lambda/latest/dg/lambda-runtimes.html">function runtimes</a>. Used for filtering with <a>ListLayers</a> and <a>ListLayerVersions</a>.</p>
     #[serde(rename = "CompatibleRuntimes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compatible_runtimes: Option<Vec<String>>,
+    pub compatible_runtimes: Option<Vec<Runtime>>,
     /// <p>The function layer archive.</p>
     #[serde(rename = "Content")]
     pub content: LayerVersionContentInput,
@@ -1493,7 +2275,7 @@ pub struct PublishLayerVersionResponse {
     /// <p>The layer's compatible runtimes.</p>
     #[serde(rename = "CompatibleRuntimes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compatible_runtimes: Option<Vec<String>>,
+    pub compatible_runtimes: Option<Vec<Runtime>>,
     /// <p>Details about the layer version.</p>
     #[serde(rename = "Content")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1615,50 +2397,619 @@ pub struct PutProvisionedConcurrencyConfigResponse {
     /// <p>The status of the allocation process.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ProvisionedConcurrencyStatusEnum>,
     /// <p>For failed allocations, the reason that provisioned concurrency could not be allocated.</p>
     #[serde(rename = "StatusReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status_reason: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct RemoveLayerVersionPermissionRequest {
-    /// <p>The name or Amazon Resource Name (ARN) of the layer.</p>
-    #[serde(rename = "LayerName")]
-    pub layer_name: String,
-    /// <p>Only update the policy if the revision ID matches the ID specified. Use this option to avoid modifying a policy that has changed since you last read it.</p>
-    #[serde(rename = "RevisionId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub revision_id: Option<String>,
-    /// <p>The identifier that was specified when the statement was added.</p>
-    #[serde(rename = "StatementId")]
-    pub statement_id: String,
-    /// <p>The version number.</p>
-    #[serde(rename = "VersionNumber")]
-    pub version_number: i64,
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct RemoveLayerVersionPermissionRequest {
+    /// <p>The name or Amazon Resource Name (ARN) of the layer.</p>
+    #[serde(rename = "LayerName")]
+    pub layer_name: String,
+    /// <p>Only update the policy if the revision ID matches the ID specified. Use this option to avoid modifying a policy that has changed since you last read it.</p>
+    #[serde(rename = "RevisionId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revision_id: Option<String>,
+    /// <p>The identifier that was specified when the statement was added.</p>
+    #[serde(rename = "StatementId")]
+    pub statement_id: String,
+    /// <p>The version number.</p>
+    #[serde(rename = "VersionNumber")]
+    pub version_number: i64,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct RemovePermissionRequest {
+    /// <p>The name of the Lambda function, version, or alias.</p> <p class="title"> <b>Name formats</b> </p> <ul> <li> <p> <b>Function name</b> - <code>my-function</code> (name-only), <code>my-function:v1</code> (with alias).</p> </li> <li> <p> <b>Function ARN</b> - <code>arn:aws:lambda:us-west-2:123456789012:function:my-function</code>.</p> </li> <li> <p> <b>Partial ARN</b> - <code>123456789012:function:my-function</code>.</p> </li> </ul> <p>You can append a version number or alias to any of the formats. The length constraint applies only to the full ARN. If you specify only the function name, it is limited to 64 characters in length.</p>
+    #[serde(rename = "FunctionName")]
+    pub function_name: String,
+    /// <p>Specify a version or alias to remove permissions from a published version of the function.</p>
+    #[serde(rename = "Qualifier")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub qualifier: Option<String>,
+    /// <p>Only update the policy if the revision ID matches the ID that's specified. Use this option to avoid modifying a policy that has changed since you last read it.</p>
+    #[serde(rename = "RevisionId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revision_id: Option<String>,
+    /// <p>Statement ID of the permission to remove.</p>
+    #[serde(rename = "StatementId")]
+    pub statement_id: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Runtime {
+    /// `nodejs`
+    Nodejs,
+    /// `nodejs4.3`
+    Nodejs4_3,
+    /// `nodejs6.10`
+    Nodejs6_10,
+    /// `nodejs8.10`
+    Nodejs8_10,
+    /// `nodejs10.x`
+    Nodejs10X,
+    /// `nodejs12.x`
+    Nodejs12X,
+    /// `nodejs14.x`
+    Nodejs14X,
+    /// `nodejs16.x`
+    Nodejs16X,
+    /// `java8`
+    Java8,
+    /// `java8.al2`
+    Java8Al2,
+    /// `java11`
+    Java11,
+    /// `python2.7`
+    Python2_7,
+    /// `python3.6`
+    Python3_6,
+    /// `python3.7`
+    Python3_7,
+    /// `python3.8`
+    Python3_8,
+    /// `python3.9`
+    Python3_9,
+    /// `dotnetcore1.0`
+    Dotnetcore1_0,
+    /// `dotnetcore2.0`
+    Dotnetcore2_0,
+    /// `dotnetcore2.1`
+    Dotnetcore2_1,
+    /// `dotnetcore3.1`
+    Dotnetcore3_1,
+    /// `dotnet6`
+    Dotnet6,
+    /// `nodejs4.3-edge`
+    Nodejs4_3Edge,
+    /// `go1.x`
+    Go1X,
+    /// `ruby2.5`
+    Ruby2_5,
+    /// `ruby2.7`
+    Ruby2_7,
+    /// `provided`
+    Provided,
+    /// `provided.al2`
+    ProvidedAl2,
+    /// `nodejs18.x`
+    Nodejs18X,
+    /// `python3.10`
+    Python3_10,
+    /// `java17`
+    Java17,
+    /// `ruby3.2`
+    Ruby3_2,
+    /// `python3.11`
+    Python3_11,
+    /// `nodejs20.x`
+    Nodejs20X,
+    /// `provided.al2023`
+    ProvidedAl2023,
+    /// `python3.12`
+    Python3_12,
+    /// `java21`
+    Java21,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Runtime {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Runtime::Nodejs => "nodejs",
+            Runtime::Nodejs4_3 => "nodejs4.3",
+            Runtime::Nodejs6_10 => "nodejs6.10",
+            Runtime::Nodejs8_10 => "nodejs8.10",
+            Runtime::Nodejs10X => "nodejs10.x",
+            Runtime::Nodejs12X => "nodejs12.x",
+            Runtime::Nodejs14X => "nodejs14.x",
+            Runtime::Nodejs16X => "nodejs16.x",
+            Runtime::Java8 => "java8",
+            Runtime::Java8Al2 => "java8.al2",
+            Runtime::Java11 => "java11",
+            Runtime::Python2_7 => "python2.7",
+            Runtime::Python3_6 => "python3.6",
+            Runtime::Python3_7 => "python3.7",
+            Runtime::Python3_8 => "python3.8",
+            Runtime::Python3_9 => "python3.9",
+            Runtime::Dotnetcore1_0 => "dotnetcore1.0",
+            Runtime::Dotnetcore2_0 => "dotnetcore2.0",
+            Runtime::Dotnetcore2_1 => "dotnetcore2.1",
+            Runtime::Dotnetcore3_1 => "dotnetcore3.1",
+            Runtime::Dotnet6 => "dotnet6",
+            Runtime::Nodejs4_3Edge => "nodejs4.3-edge",
+            Runtime::Go1X => "go1.x",
+            Runtime::Ruby2_5 => "ruby2.5",
+            Runtime::Ruby2_7 => "ruby2.7",
+            Runtime::Provided => "provided",
+            Runtime::ProvidedAl2 => "provided.al2",
+            Runtime::Nodejs18X => "nodejs18.x",
+            Runtime::Python3_10 => "python3.10",
+            Runtime::Java17 => "java17",
+            Runtime::Ruby3_2 => "ruby3.2",
+            Runtime::Python3_11 => "python3.11",
+            Runtime::Nodejs20X => "nodejs20.x",
+            Runtime::ProvidedAl2023 => "provided.al2023",
+            Runtime::Python3_12 => "python3.12",
+            Runtime::Java21 => "java21",
+            Runtime::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Runtime {
+    fn default() -> Runtime {
+        Runtime::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Runtime {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Runtime {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Runtime {
+    fn from(value: &'a str) -> Runtime {
+        match value {
+            "nodejs" => Runtime::Nodejs,
+            "nodejs4.3" => Runtime::Nodejs4_3,
+            "nodejs6.10" => Runtime::Nodejs6_10,
+            "nodejs8.10" => Runtime::Nodejs8_10,
+            "nodejs10.x" => Runtime::Nodejs10X,
+            "nodejs12.x" => Runtime::Nodejs12X,
+            "nodejs14.x" => Runtime::Nodejs14X,
+            "nodejs16.x" => Runtime::Nodejs16X,
+            "java8" => Runtime::Java8,
+            "java8.al2" => Runtime::Java8Al2,
+            "java11" => Runtime::Java11,
+            "python2.7" => Runtime::Python2_7,
+            "python3.6" => Runtime::Python3_6,
+            "python3.7" => Runtime::Python3_7,
+            "python3.8" => Runtime::Python3_8,
+            "python3.9" => Runtime::Python3_9,
+            "dotnetcore1.0" => Runtime::Dotnetcore1_0,
+            "dotnetcore2.0" => Runtime::Dotnetcore2_0,
+            "dotnetcore2.1" => Runtime::Dotnetcore2_1,
+            "dotnetcore3.1" => Runtime::Dotnetcore3_1,
+            "dotnet6" => Runtime::Dotnet6,
+            "nodejs4.3-edge" => Runtime::Nodejs4_3Edge,
+            "go1.x" => Runtime::Go1X,
+            "ruby2.5" => Runtime::Ruby2_5,
+            "ruby2.7" => Runtime::Ruby2_7,
+            "provided" => Runtime::Provided,
+            "provided.al2" => Runtime::ProvidedAl2,
+            "nodejs18.x" => Runtime::Nodejs18X,
+            "python3.10" => Runtime::Python3_10,
+            "java17" => Runtime::Java17,
+            "ruby3.2" => Runtime::Ruby3_2,
+            "python3.11" => Runtime::Python3_11,
+            "nodejs20.x" => Runtime::Nodejs20X,
+            "provided.al2023" => Runtime::ProvidedAl2023,
+            "python3.12" => Runtime::Python3_12,
+            "java21" => Runtime::Java21,
+            _ => Runtime::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Runtime {
+    fn from(value: String) -> Runtime {
+        match value.as_str() {
+            "nodejs" => Runtime::Nodejs,
+            "nodejs4.3" => Runtime::Nodejs4_3,
+            "nodejs6.10" => Runtime::Nodejs6_10,
+            "nodejs8.10" => Runtime::Nodejs8_10,
+            "nodejs10.x" => Runtime::Nodejs10X,
+            "nodejs12.x" => Runtime::Nodejs12X,
+            "nodejs14.x" => Runtime::Nodejs14X,
+            "nodejs16.x" => Runtime::Nodejs16X,
+            "java8" => Runtime::Java8,
+            "java8.al2" => Runtime::Java8Al2,
+            "java11" => Runtime::Java11,
+            "python2.7" => Runtime::Python2_7,
+            "python3.6" => Runtime::Python3_6,
+            "python3.7" => Runtime::Python3_7,
+            "python3.8" => Runtime::Python3_8,
+            "python3.9" => Runtime::Python3_9,
+            "dotnetcore1.0" => Runtime::Dotnetcore1_0,
+            "dotnetcore2.0" => Runtime::Dotnetcore2_0,
+            "dotnetcore2.1" => Runtime::Dotnetcore2_1,
+            "dotnetcore3.1" => Runtime::Dotnetcore3_1,
+            "dotnet6" => Runtime::Dotnet6,
+            "nodejs4.3-edge" => Runtime::Nodejs4_3Edge,
+            "go1.x" => Runtime::Go1X,
+            "ruby2.5" => Runtime::Ruby2_5,
+            "ruby2.7" => Runtime::Ruby2_7,
+            "provided" => Runtime::Provided,
+            "provided.al2" => Runtime::ProvidedAl2,
+            "nodejs18.x" => Runtime::Nodejs18X,
+            "python3.10" => Runtime::Python3_10,
+            "java17" => Runtime::Java17,
+            "ruby3.2" => Runtime::Ruby3_2,
+            "python3.11" => Runtime::Python3_11,
+            "nodejs20.x" => Runtime::Nodejs20X,
+            "provided.al2023" => Runtime::ProvidedAl2023,
+            "python3.12" => Runtime::Python3_12,
+            "java21" => Runtime::Java21,
+            _ => Runtime::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Runtime {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Runtime, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Runtime {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Runtime {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Runtime {
+    fn deserialize<D>(deserializer: D) -> Result<Runtime, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum State {
+    /// `Pending`
+    Pending,
+    /// `Active`
+    Active,
+    /// `Inactive`
+    Inactive,
+    /// `Failed`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl State {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            State::Pending => "Pending",
+            State::Active => "Active",
+            State::Inactive => "Inactive",
+            State::Failed => "Failed",
+            State::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for State {
+    fn default() -> State {
+        State::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for State {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for State {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for State {
+    fn from(value: &'a str) -> State {
+        match value {
+            "Pending" => State::Pending,
+            "Active" => State::Active,
+            "Inactive" => State::Inactive,
+            "Failed" => State::Failed,
+            _ => State::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for State {
+    fn from(value: String) -> State {
+        match value.as_str() {
+            "Pending" => State::Pending,
+            "Active" => State::Active,
+            "Inactive" => State::Inactive,
+            "Failed" => State::Failed,
+            _ => State::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for State {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<State, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for State {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for State {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for State {
+    fn deserialize<D>(deserializer: D) -> Result<State, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StateReasonCode {
+    /// `Idle`
+    Idle,
+    /// `Creating`
+    Creating,
+    /// `Restoring`
+    Restoring,
+    /// `EniLimitExceeded`
+    EniLimitExceeded,
+    /// `InsufficientRolePermissions`
+    InsufficientRolePermissions,
+    /// `InvalidConfiguration`
+    InvalidConfiguration,
+    /// `InternalError`
+    InternalError,
+    /// `SubnetOutOfIPAddresses`
+    SubnetOutOfIPAddresses,
+    /// `InvalidSubnet`
+    InvalidSubnet,
+    /// `InvalidSecurityGroup`
+    InvalidSecurityGroup,
+    /// `ImageDeleted`
+    ImageDeleted,
+    /// `ImageAccessDenied`
+    ImageAccessDenied,
+    /// `InvalidImage`
+    InvalidImage,
+    /// `KMSKeyAccessDenied`
+    KMSKeyAccessDenied,
+    /// `KMSKeyNotFound`
+    KMSKeyNotFound,
+    /// `InvalidStateKMSKey`
+    InvalidStateKMSKey,
+    /// `DisabledKMSKey`
+    DisabledKMSKey,
+    /// `EFSIOError`
+    EFSIOError,
+    /// `EFSMountConnectivityError`
+    EFSMountConnectivityError,
+    /// `EFSMountFailure`
+    EFSMountFailure,
+    /// `EFSMountTimeout`
+    EFSMountTimeout,
+    /// `InvalidRuntime`
+    InvalidRuntime,
+    /// `InvalidZipFileException`
+    InvalidZipFileException,
+    /// `FunctionError`
+    FunctionError,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl StateReasonCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StateReasonCode::Idle => "Idle",
+            StateReasonCode::Creating => "Creating",
+            StateReasonCode::Restoring => "Restoring",
+            StateReasonCode::EniLimitExceeded => "EniLimitExceeded",
+            StateReasonCode::InsufficientRolePermissions => "InsufficientRolePermissions",
+            StateReasonCode::InvalidConfiguration => "InvalidConfiguration",
+            StateReasonCode::InternalError => "InternalError",
+            StateReasonCode::SubnetOutOfIPAddresses => "SubnetOutOfIPAddresses",
+            StateReasonCode::InvalidSubnet => "InvalidSubnet",
+            StateReasonCode::InvalidSecurityGroup => "InvalidSecurityGroup",
+            StateReasonCode::ImageDeleted => "ImageDeleted",
+            StateReasonCode::ImageAccessDenied => "ImageAccessDenied",
+            StateReasonCode::InvalidImage => "InvalidImage",
+            StateReasonCode::KMSKeyAccessDenied => "KMSKeyAccessDenied",
+            StateReasonCode::KMSKeyNotFound => "KMSKeyNotFound",
+            StateReasonCode::InvalidStateKMSKey => "InvalidStateKMSKey",
+            StateReasonCode::DisabledKMSKey => "DisabledKMSKey",
+            StateReasonCode::EFSIOError => "EFSIOError",
+            StateReasonCode::EFSMountConnectivityError => "EFSMountConnectivityError",
+            StateReasonCode::EFSMountFailure => "EFSMountFailure",
+            StateReasonCode::EFSMountTimeout => "EFSMountTimeout",
+            StateReasonCode::InvalidRuntime => "InvalidRuntime",
+            StateReasonCode::InvalidZipFileException => "InvalidZipFileException",
+            StateReasonCode::FunctionError => "FunctionError",
+            StateReasonCode::Unknown(ref value) => value,
+        }
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct RemovePermissionRequest {
-    /// <p>The name of the Lambda function, version, or alias.</p> <p class="title"> <b>Name formats</b> </p> <ul> <li> <p> <b>Function name</b> - <code>my-function</code> (name-only), <code>my-function:v1</code> (with alias).</p> </li> <li> <p> <b>Function ARN</b> - <code>arn:aws:lambda:us-west-2:123456789012:function:my-function</code>.</p> </li> <li> <p> <b>Partial ARN</b> - <code>123456789012:function:my-function</code>.</p> </li> </ul> <p>You can append a version number or alias to any of the formats. The length constraint applies only to the full ARN. If you specify only the function name, it is limited to 64 characters in length.</p>
-    #[serde(rename = "FunctionName")]
-    pub function_name: String,
-    /// <p>Specify a version or alias to remove permissions from a published version of the function.</p>
-    #[serde(rename = "Qualifier")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub qualifier: Option<String>,
-    /// <p>Only update the policy if the revision ID matches the ID that's specified. Use this option to avoid modifying a policy that has changed since you last read it.</p>
-    #[serde(rename = "RevisionId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub revision_id: Option<String>,
-    /// <p>Statement ID of the permission to remove.</p>
-    #[serde(rename = "StatementId")]
-    pub statement_id: String,
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for StateReasonCode {
+    fn default() -> StateReasonCode {
+        StateReasonCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for StateReasonCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for StateReasonCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for StateReasonCode {
+    fn from(value: &'a str) -> StateReasonCode {
+        match value {
+            "Idle" => StateReasonCode::Idle,
+            "Creating" => StateReasonCode::Creating,
+            "Restoring" => StateReasonCode::Restoring,
+            "EniLimitExceeded" => StateReasonCode::EniLimitExceeded,
+            "InsufficientRolePermissions" => StateReasonCode::InsufficientRolePermissions,
+            "InvalidConfiguration" => StateReasonCode::InvalidConfiguration,
+            "InternalError" => StateReasonCode::InternalError,
+            "SubnetOutOfIPAddresses" => StateReasonCode::SubnetOutOfIPAddresses,
+            "InvalidSubnet" => StateReasonCode::InvalidSubnet,
+            "InvalidSecurityGroup" => StateReasonCode::InvalidSecurityGroup,
+            "ImageDeleted" => StateReasonCode::ImageDeleted,
+            "ImageAccessDenied" => StateReasonCode::ImageAccessDenied,
+            "InvalidImage" => StateReasonCode::InvalidImage,
+            "KMSKeyAccessDenied" => StateReasonCode::KMSKeyAccessDenied,
+            "KMSKeyNotFound" => StateReasonCode::KMSKeyNotFound,
+            "InvalidStateKMSKey" => StateReasonCode::InvalidStateKMSKey,
+            "DisabledKMSKey" => StateReasonCode::DisabledKMSKey,
+            "EFSIOError" => StateReasonCode::EFSIOError,
+            "EFSMountConnectivityError" => StateReasonCode::EFSMountConnectivityError,
+            "EFSMountFailure" => StateReasonCode::EFSMountFailure,
+            "EFSMountTimeout" => StateReasonCode::EFSMountTimeout,
+            "InvalidRuntime" => StateReasonCode::InvalidRuntime,
+            "InvalidZipFileException" => StateReasonCode::InvalidZipFileException,
+            "FunctionError" => StateReasonCode::FunctionError,
+            _ => StateReasonCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StateReasonCode {
+    fn from(value: String) -> StateReasonCode {
+        match value.as_str() {
+            "Idle" => StateReasonCode::Idle,
+            "Creating" => StateReasonCode::Creating,
+            "Restoring" => StateReasonCode::Restoring,
+            "EniLimitExceeded" => StateReasonCode::EniLimitExceeded,
+            "InsufficientRolePermissions" => StateReasonCode::InsufficientRolePermissions,
+            "InvalidConfiguration" => StateReasonCode::InvalidConfiguration,
+            "InternalError" => StateReasonCode::InternalError,
+            "SubnetOutOfIPAddresses" => StateReasonCode::SubnetOutOfIPAddresses,
+            "InvalidSubnet" => StateReasonCode::InvalidSubnet,
+            "InvalidSecurityGroup" => StateReasonCode::InvalidSecurityGroup,
+            "ImageDeleted" => StateReasonCode::ImageDeleted,
+            "ImageAccessDenied" => StateReasonCode::ImageAccessDenied,
+            "InvalidImage" => StateReasonCode::InvalidImage,
+            "KMSKeyAccessDenied" => StateReasonCode::KMSKeyAccessDenied,
+            "KMSKeyNotFound" => StateReasonCode::KMSKeyNotFound,
+            "InvalidStateKMSKey" => StateReasonCode::InvalidStateKMSKey,
+            "DisabledKMSKey" => StateReasonCode::DisabledKMSKey,
+            "EFSIOError" => StateReasonCode::EFSIOError,
+            "EFSMountConnectivityError" => StateReasonCode::EFSMountConnectivityError,
+            "EFSMountFailure" => StateReasonCode::EFSMountFailure,
+            "EFSMountTimeout" => StateReasonCode::EFSMountTimeout,
+            "InvalidRuntime" => StateReasonCode::InvalidRuntime,
+            "InvalidZipFileException" => StateReasonCode::InvalidZipFileException,
+            "FunctionError" => StateReasonCode::FunctionError,
+            _ => StateReasonCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for StateReasonCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<StateReasonCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StateReasonCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for StateReasonCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for StateReasonCode {
+    fn deserialize<D>(deserializer: D) -> Result<StateReasonCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct TagResourceRequest {
@@ -1670,6 +3021,149 @@ pub struct TagResourceRequest {
     pub tags: ::std::collections::HashMap<String, String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ThrottleReason {
+    /// `ConcurrentInvocationLimitExceeded`
+    ConcurrentInvocationLimitExceeded,
+    /// `FunctionInvocationRateLimitExceeded`
+    FunctionInvocationRateLimitExceeded,
+    /// `ReservedFunctionConcurrentInvocationLimitExceeded`
+    ReservedFunctionConcurrentInvocationLimitExceeded,
+    /// `ReservedFunctionInvocationRateLimitExceeded`
+    ReservedFunctionInvocationRateLimitExceeded,
+    /// `CallerRateLimitExceeded`
+    CallerRateLimitExceeded,
+    /// `ConcurrentSnapshotCreateLimitExceeded`
+    ConcurrentSnapshotCreateLimitExceeded,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ThrottleReason {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ThrottleReason::ConcurrentInvocationLimitExceeded => {
+                "ConcurrentInvocationLimitExceeded"
+            }
+            ThrottleReason::FunctionInvocationRateLimitExceeded => {
+                "FunctionInvocationRateLimitExceeded"
+            }
+            ThrottleReason::ReservedFunctionConcurrentInvocationLimitExceeded => {
+                "ReservedFunctionConcurrentInvocationLimitExceeded"
+            }
+            ThrottleReason::ReservedFunctionInvocationRateLimitExceeded => {
+                "ReservedFunctionInvocationRateLimitExceeded"
+            }
+            ThrottleReason::CallerRateLimitExceeded => "CallerRateLimitExceeded",
+            ThrottleReason::ConcurrentSnapshotCreateLimitExceeded => {
+                "ConcurrentSnapshotCreateLimitExceeded"
+            }
+            ThrottleReason::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ThrottleReason {
+    fn default() -> ThrottleReason {
+        ThrottleReason::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ThrottleReason {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ThrottleReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ThrottleReason {
+    fn from(value: &'a str) -> ThrottleReason {
+        match value {
+            "ConcurrentInvocationLimitExceeded" => {
+                ThrottleReason::ConcurrentInvocationLimitExceeded
+            }
+            "FunctionInvocationRateLimitExceeded" => {
+                ThrottleReason::FunctionInvocationRateLimitExceeded
+            }
+            "ReservedFunctionConcurrentInvocationLimitExceeded" => {
+                ThrottleReason::ReservedFunctionConcurrentInvocationLimitExceeded
+            }
+            "ReservedFunctionInvocationRateLimitExceeded" => {
+                ThrottleReason::ReservedFunctionInvocationRateLimitExceeded
+            }
+            "CallerRateLimitExceeded" => ThrottleReason::CallerRateLimitExceeded,
+            "ConcurrentSnapshotCreateLimitExceeded" => {
+                ThrottleReason::ConcurrentSnapshotCreateLimitExceeded
+            }
+            _ => ThrottleReason::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ThrottleReason {
+    fn from(value: String) -> ThrottleReason {
+        match value.as_str() {
+            "ConcurrentInvocationLimitExceeded" => {
+                ThrottleReason::ConcurrentInvocationLimitExceeded
+            }
+            "FunctionInvocationRateLimitExceeded" => {
+                ThrottleReason::FunctionInvocationRateLimitExceeded
+            }
+            "ReservedFunctionConcurrentInvocationLimitExceeded" => {
+                ThrottleReason::ReservedFunctionConcurrentInvocationLimitExceeded
+            }
+            "ReservedFunctionInvocationRateLimitExceeded" => {
+                ThrottleReason::ReservedFunctionInvocationRateLimitExceeded
+            }
+            "CallerRateLimitExceeded" => ThrottleReason::CallerRateLimitExceeded,
+            "ConcurrentSnapshotCreateLimitExceeded" => {
+                ThrottleReason::ConcurrentSnapshotCreateLimitExceeded
+            }
+            _ => ThrottleReason::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ThrottleReason {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ThrottleReason, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ThrottleReason {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ThrottleReason {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ThrottleReason {
+    fn deserialize<D>(deserializer: D) -> Result<ThrottleReason, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The function's AWS X-Ray tracing configuration. To sample and record incoming requests, set <code>Mode</code> to <code>Active</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -1677,7 +3171,7 @@ pub struct TracingConfig {
     /// <p>The tracing mode.</p>
     #[serde(rename = "Mode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub mode: Option<String>,
+    pub mode: Option<TracingMode>,
 }
 
 /// <p>The function's AWS X-Ray tracing configuration.</p>
@@ -1687,9 +3181,102 @@ pub struct TracingConfigResponse {
     /// <p>The tracing mode.</p>
     #[serde(rename = "Mode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub mode: Option<String>,
+    pub mode: Option<TracingMode>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TracingMode {
+    /// `Active`
+    Active,
+    /// `PassThrough`
+    PassThrough,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TracingMode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TracingMode::Active => "Active",
+            TracingMode::PassThrough => "PassThrough",
+            TracingMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TracingMode {
+    fn default() -> TracingMode {
+        TracingMode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TracingMode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TracingMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TracingMode {
+    fn from(value: &'a str) -> TracingMode {
+        match value {
+            "Active" => TracingMode::Active,
+            "PassThrough" => TracingMode::PassThrough,
+            _ => TracingMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TracingMode {
+    fn from(value: String) -> TracingMode {
+        match value.as_str() {
+            "Active" => TracingMode::Active,
+            "PassThrough" => TracingMode::PassThrough,
+            _ => TracingMode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TracingMode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TracingMode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TracingMode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TracingMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for TracingMode {
+    fn deserialize<D>(deserializer: D) -> Result<TracingMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct UntagResourceRequest {
@@ -1858,7 +3445,7 @@ pub struct UpdateFunctionConfigurationRequest {
     /// <p>The identifier of the function's <a href="https://docs.aws.amazon.com/lambda/latest/dg/lambda-runtimes.html">runtime</a>.</p>
     #[serde(rename = "Runtime")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub runtime: Option<String>,
+    pub runtime: Option<Runtime>,
     /// <p>The amount of time that Lambda allows a function to run before stopping it. The default is 3 seconds. The maximum allowed value is 900 seconds.</p>
     #[serde(rename = "Timeout")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -5199,11 +6786,12 @@ pub struct LambdaClient {
 impl LambdaClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> LambdaClient {
         LambdaClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Lambda"),
         }
     }
 
@@ -5242,6 +6830,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation("AddLayerVersionPermission");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5281,6 +6870,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation("AddPermission");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5320,6 +6910,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation("CreateAlias");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5350,6 +6941,7 @@ impl Lambda for LambdaClient {
         let request_uri = "/2015-03-31/event-source-mappings/";
 
         let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation("CreateEventSourceMapping");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5380,6 +6972,7 @@ impl Lambda for LambdaClient {
         let request_uri = "/2015-03-31/functions";
 
         let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation("CreateFunction");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5414,6 +7007,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
+        request.set_operation("DeleteAlias");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5443,6 +7037,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
+        request.set_operation("DeleteEventSourceMapping");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5473,6 +7068,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
+        request.set_operation("DeleteFunction");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5508,6 +7104,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
+        request.set_operation("DeleteFunctionConcurrency");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5537,6 +7134,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
+        request.set_operation("DeleteFunctionEventInvokeConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5575,6 +7173,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
+        request.set_operation("DeleteLayerVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5604,6 +7203,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
+        request.set_operation("DeleteProvisionedConcurrencyConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5635,6 +7235,7 @@ impl Lambda for LambdaClient {
         let request_uri = "/2016-08-19/account-settings/";
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("GetAccountSettings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5666,6 +7267,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("GetAlias");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5696,6 +7298,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("GetEventSourceMapping");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5726,6 +7329,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("GetFunction");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5762,6 +7366,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("GetFunctionConcurrency");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5792,6 +7397,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("GetFunctionConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5828,6 +7434,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("GetFunctionEventInvokeConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5865,6 +7472,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("GetLayerVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5892,6 +7500,7 @@ impl Lambda for LambdaClient {
         let request_uri = "/2018-10-31/layers";
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("GetLayerVersionByArn");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5928,6 +7537,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("GetLayerVersionPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5958,6 +7568,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("GetPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5997,6 +7608,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("GetProvisionedConcurrencyConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6033,6 +7645,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation("Invoke");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = if let Some(ref payload) = input.payload {
@@ -6101,6 +7714,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation("InvokeAsync");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(input.invoke_args.to_owned());
@@ -6135,6 +7749,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("ListAliases");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6174,6 +7789,7 @@ impl Lambda for LambdaClient {
         let request_uri = "/2015-03-31/event-source-mappings/";
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("ListEventSourceMappings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6222,6 +7838,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("ListFunctionEventInvokeConfigs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6258,6 +7875,7 @@ impl Lambda for LambdaClient {
         let request_uri = "/2015-03-31/functions/";
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("ListFunctions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6303,6 +7921,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("ListLayerVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6342,6 +7961,7 @@ impl Lambda for LambdaClient {
         let request_uri = "/2018-10-31/layers";
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("ListLayers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6387,6 +8007,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("ListProvisionedConcurrencyConfigs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6426,6 +8047,7 @@ impl Lambda for LambdaClient {
         let request_uri = format!("/2017-03-31/tags/{arn}", arn = input.resource);
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("ListTags");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -6456,6 +8078,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("ListVersionsByFunction");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6495,6 +8118,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation("PublishLayerVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6528,6 +8152,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation("PublishVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6561,6 +8186,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("PUT", "lambda", &self.region, &request_uri);
+        request.set_operation("PutFunctionConcurrency");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6594,6 +8220,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("PUT", "lambda", &self.region, &request_uri);
+        request.set_operation("PutFunctionEventInvokeConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6636,6 +8263,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("PUT", "lambda", &self.region, &request_uri);
+        request.set_operation("PutProvisionedConcurrencyConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6677,6 +8305,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
+        request.set_operation("RemoveLayerVersionPermission");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6713,6 +8342,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
+        request.set_operation("RemovePermission");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6748,6 +8378,7 @@ impl Lambda for LambdaClient {
         let request_uri = format!("/2017-03-31/tags/{arn}", arn = input.resource);
 
         let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6777,6 +8408,7 @@ impl Lambda for LambdaClient {
         let request_uri = format!("/2017-03-31/tags/{arn}", arn = input.resource);
 
         let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6813,6 +8445,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("PUT", "lambda", &self.region, &request_uri);
+        request.set_operation("UpdateAlias");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6846,6 +8479,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("PUT", "lambda", &self.region, &request_uri);
+        request.set_operation("UpdateEventSourceMapping");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6879,6 +8513,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("PUT", "lambda", &self.region, &request_uri);
+        request.set_operation("UpdateFunctionCode");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6912,6 +8547,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("PUT", "lambda", &self.region, &request_uri);
+        request.set_operation("UpdateFunctionConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6945,6 +8581,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation("UpdateFunctionEventInvokeConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6975,3 +8612,558 @@ impl Lambda for LambdaClient {
         }
     }
 }
+impl LambdaClient {
+    /// Auto-paginating version of `list_aliases`, streaming every page of results.
+    pub fn list_aliases_pages(
+        &self,
+        input: ListAliasesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListAliasesResponse, ListAliasesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LambdaClient, input: ListAliasesRequest| async move {
+                client.list_aliases(input).await
+            },
+            |input: &ListAliasesRequest, output: &ListAliasesResponse| {
+                Some(ListAliasesRequest {
+                    marker: Some(output.next_marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_aliases`, streaming the `Aliases` of every page of results.
+    pub fn list_aliases_items(
+        &self,
+        input: ListAliasesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<AliasConfiguration, ListAliasesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_aliases_pages(input),
+            |output: ListAliasesResponse| output.aliases.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_event_source_mappings`, streaming every page of results.
+    pub fn list_event_source_mappings_pages(
+        &self,
+        input: ListEventSourceMappingsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListEventSourceMappingsResponse,
+        ListEventSourceMappingsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LambdaClient, input: ListEventSourceMappingsRequest| async move {
+                client.list_event_source_mappings(input).await
+            },
+            |input: &ListEventSourceMappingsRequest, output: &ListEventSourceMappingsResponse| {
+                Some(ListEventSourceMappingsRequest {
+                    marker: Some(output.next_marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_event_source_mappings`, streaming the `EventSourceMappings` of every page of results.
+    pub fn list_event_source_mappings_items(
+        &self,
+        input: ListEventSourceMappingsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        EventSourceMappingConfiguration,
+        ListEventSourceMappingsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_event_source_mappings_pages(input),
+            |output: ListEventSourceMappingsResponse| {
+                output.event_source_mappings.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_function_event_invoke_configs`, streaming every page of results.
+    pub fn list_function_event_invoke_configs_pages(
+        &self,
+        input: ListFunctionEventInvokeConfigsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListFunctionEventInvokeConfigsResponse,
+        ListFunctionEventInvokeConfigsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LambdaClient, input: ListFunctionEventInvokeConfigsRequest| async move {
+                client.list_function_event_invoke_configs(input).await
+            },
+            |input: &ListFunctionEventInvokeConfigsRequest,
+             output: &ListFunctionEventInvokeConfigsResponse| {
+                Some(ListFunctionEventInvokeConfigsRequest {
+                    marker: Some(output.next_marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_function_event_invoke_configs`, streaming the `FunctionEventInvokeConfigs` of every page of results.
+    pub fn list_function_event_invoke_configs_items(
+        &self,
+        input: ListFunctionEventInvokeConfigsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        FunctionEventInvokeConfig,
+        ListFunctionEventInvokeConfigsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_function_event_invoke_configs_pages(input),
+            |output: ListFunctionEventInvokeConfigsResponse| {
+                output.function_event_invoke_configs.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_functions`, streaming every page of results.
+    pub fn list_functions_pages(
+        &self,
+        input: ListFunctionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListFunctionsResponse, ListFunctionsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LambdaClient, input: ListFunctionsRequest| async move {
+                client.list_functions(input).await
+            },
+            |input: &ListFunctionsRequest, output: &ListFunctionsResponse| {
+                Some(ListFunctionsRequest {
+                    marker: Some(output.next_marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_functions`, streaming the `Functions` of every page of results.
+    pub fn list_functions_items(
+        &self,
+        input: ListFunctionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<FunctionConfiguration, ListFunctionsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_functions_pages(input),
+            |output: ListFunctionsResponse| output.functions.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_layer_versions`, streaming every page of results.
+    pub fn list_layer_versions_pages(
+        &self,
+        input: ListLayerVersionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListLayerVersionsResponse, ListLayerVersionsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LambdaClient, input: ListLayerVersionsRequest| async move {
+                client.list_layer_versions(input).await
+            },
+            |input: &ListLayerVersionsRequest, output: &ListLayerVersionsResponse| {
+                Some(ListLayerVersionsRequest {
+                    marker: Some(output.next_marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_layer_versions`, streaming the `LayerVersions` of every page of results.
+    pub fn list_layer_versions_items(
+        &self,
+        input: ListLayerVersionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<LayerVersionsListItem, ListLayerVersionsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_layer_versions_pages(input),
+            |output: ListLayerVersionsResponse| output.layer_versions.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_layers`, streaming every page of results.
+    pub fn list_layers_pages(
+        &self,
+        input: ListLayersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListLayersResponse, ListLayersError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LambdaClient, input: ListLayersRequest| async move {
+                client.list_layers(input).await
+            },
+            |input: &ListLayersRequest, output: &ListLayersResponse| {
+                Some(ListLayersRequest {
+                    marker: Some(output.next_marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_layers`, streaming the `Layers` of every page of results.
+    pub fn list_layers_items(
+        &self,
+        input: ListLayersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<LayersListItem, ListLayersError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_layers_pages(input),
+            |output: ListLayersResponse| output.layers.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_provisioned_concurrency_configs`, streaming every page of results.
+    pub fn list_provisioned_concurrency_configs_pages(
+        &self,
+        input: ListProvisionedConcurrencyConfigsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListProvisionedConcurrencyConfigsResponse,
+        ListProvisionedConcurrencyConfigsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LambdaClient, input: ListProvisionedConcurrencyConfigsRequest| async move {
+                client.list_provisioned_concurrency_configs(input).await
+            },
+            |input: &ListProvisionedConcurrencyConfigsRequest,
+             output: &ListProvisionedConcurrencyConfigsResponse| {
+                Some(ListProvisionedConcurrencyConfigsRequest {
+                    marker: Some(output.next_marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_provisioned_concurrency_configs`, streaming the `ProvisionedConcurrencyConfigs` of every page of results.
+    pub fn list_provisioned_concurrency_configs_items(
+        &self,
+        input: ListProvisionedConcurrencyConfigsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ProvisionedConcurrencyConfigListItem,
+        ListProvisionedConcurrencyConfigsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_provisioned_concurrency_configs_pages(input),
+            |output: ListProvisionedConcurrencyConfigsResponse| {
+                output.provisioned_concurrency_configs.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_versions_by_function`, streaming every page of results.
+    pub fn list_versions_by_function_pages(
+        &self,
+        input: ListVersionsByFunctionRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListVersionsByFunctionResponse,
+        ListVersionsByFunctionError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LambdaClient, input: ListVersionsByFunctionRequest| async move {
+                client.list_versions_by_function(input).await
+            },
+            |input: &ListVersionsByFunctionRequest, output: &ListVersionsByFunctionResponse| {
+                Some(ListVersionsByFunctionRequest {
+                    marker: Some(output.next_marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_versions_by_function`, streaming the `Versions` of every page of results.
+    pub fn list_versions_by_function_items(
+        &self,
+        input: ListVersionsByFunctionRequest,
+    ) -> rusoto_core::pagination::RusotoStream<FunctionConfiguration, ListVersionsByFunctionError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_versions_by_function_pages(input),
+            |output: ListVersionsByFunctionResponse| output.versions.unwrap_or_default(),
+        )
+    }
+}
+impl LambdaClient {
+    /// Waits until `get_function_configuration` reports the `FunctionActive` state, polling every 5 seconds up to 60 times.
+    pub async fn wait_until_function_active(
+        &self,
+        input: GetFunctionConfigurationRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetFunctionConfigurationError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(5), 60);
+        self.wait_until_function_active_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_function_active`, polling as often and as many times as `config` says.
+    pub async fn wait_until_function_active_with_config(
+        &self,
+        input: GetFunctionConfigurationRequest,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetFunctionConfigurationError>> {
+        rusoto_core::waiter::wait(
+            "FunctionActive",
+            &config,
+            || self.get_function_configuration(input.clone()),
+            |result: &Result<FunctionConfiguration, RusotoError<GetFunctionConfigurationError>>| {
+                match result {
+                    Ok(output) if output.state.iter().any(|v| *v == State::Active) => {
+                        Some(rusoto_core::waiter::WaiterState::Success)
+                    }
+                    Ok(output) if output.state.iter().any(|v| *v == State::Failed) => {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    Ok(output) if output.state.iter().any(|v| *v == State::Pending) => {
+                        Some(rusoto_core::waiter::WaiterState::Retry)
+                    }
+                    _ => None,
+                }
+            },
+        )
+        .await
+    }
+
+    /// Waits until `get_function` reports the `FunctionActiveV2` state, polling every 1 seconds up to 300 times.
+    pub async fn wait_until_function_active_v2(
+        &self,
+        input: GetFunctionRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetFunctionError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(1), 300);
+        self.wait_until_function_active_v2_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_function_active_v2`, polling as often and as many times as `config` says.
+    pub async fn wait_until_function_active_v2_with_config(
+        &self,
+        input: GetFunctionRequest,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetFunctionError>> {
+        rusoto_core::waiter::wait(
+            "FunctionActiveV2",
+            &config,
+            || self.get_function(input.clone()),
+            |result: &Result<GetFunctionResponse, RusotoError<GetFunctionError>>| match result {
+                Ok(output)
+                    if output
+                        .configuration
+                        .iter()
+                        .flat_map(|v| v.state.iter())
+                        .any(|v| *v == State::Active) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if output
+                        .configuration
+                        .iter()
+                        .flat_map(|v| v.state.iter())
+                        .any(|v| *v == State::Failed) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .configuration
+                        .iter()
+                        .flat_map(|v| v.state.iter())
+                        .any(|v| *v == State::Pending) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Retry)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+
+    /// Waits until `get_function` reports the `FunctionExists` state, polling every 1 seconds up to 20 times.
+    pub async fn wait_until_function_exists(
+        &self,
+        input: GetFunctionRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetFunctionError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(1), 20);
+        self.wait_until_function_exists_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_function_exists`, polling as often and as many times as `config` says.
+    pub async fn wait_until_function_exists_with_config(
+        &self,
+        input: GetFunctionRequest,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetFunctionError>> {
+        rusoto_core::waiter::wait(
+            "FunctionExists",
+            &config,
+            || self.get_function(input.clone()),
+            |result: &Result<GetFunctionResponse, RusotoError<GetFunctionError>>| match result {
+                Ok(_) => Some(rusoto_core::waiter::WaiterState::Success),
+                Err(RusotoError::Service(GetFunctionError::ResourceNotFound(_))) => {
+                    Some(rusoto_core::waiter::WaiterState::Retry)
+                }
+                Err(RusotoError::Unknown(response))
+                    if rusoto_core::retry::error_code(response).as_deref()
+                        == Some("ResourceNotFoundException") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Retry)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+
+    /// Waits until `get_function_configuration` reports the `FunctionUpdated` state, polling every 5 seconds up to 60 times.
+    pub async fn wait_until_function_updated(
+        &self,
+        input: GetFunctionConfigurationRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetFunctionConfigurationError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(5), 60);
+        self.wait_until_function_updated_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_function_updated`, polling as often and as many times as `config` says.
+    pub async fn wait_until_function_updated_with_config(
+        &self,
+        input: GetFunctionConfigurationRequest,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetFunctionConfigurationError>> {
+        rusoto_core::waiter::wait(
+            "FunctionUpdated",
+            &config,
+            || self.get_function_configuration(input.clone()),
+            |result: &Result<FunctionConfiguration, RusotoError<GetFunctionConfigurationError>>| {
+                match result {
+                    Ok(output)
+                        if output
+                            .last_update_status
+                            .iter()
+                            .any(|v| *v == LastUpdateStatus::Successful) =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Success)
+                    }
+                    Ok(output)
+                        if output
+                            .last_update_status
+                            .iter()
+                            .any(|v| *v == LastUpdateStatus::Failed) =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    Ok(output)
+                        if output
+                            .last_update_status
+                            .iter()
+                            .any(|v| *v == LastUpdateStatus::InProgress) =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Retry)
+                    }
+                    _ => None,
+                }
+            },
+        )
+        .await
+    }
+
+    /// Waits until `get_function` reports the `FunctionUpdatedV2` state, polling every 1 seconds up to 300 times.
+    pub async fn wait_until_function_updated_v2(
+        &self,
+        input: GetFunctionRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetFunctionError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(1), 300);
+        self.wait_until_function_updated_v2_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_function_updated_v2`, polling as often and as many times as `config` says.
+    pub async fn wait_until_function_updated_v2_with_config(
+        &self,
+        input: GetFunctionRequest,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetFunctionError>> {
+        rusoto_core::waiter::wait(
+            "FunctionUpdatedV2",
+            &config,
+            || self.get_function(input.clone()),
+            |result: &Result<GetFunctionResponse, RusotoError<GetFunctionError>>| match result {
+                Ok(output)
+                    if output
+                        .configuration
+                        .iter()
+                        .flat_map(|v| v.last_update_status.iter())
+                        .any(|v| *v == LastUpdateStatus::Successful) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if output
+                        .configuration
+                        .iter()
+                        .flat_map(|v| v.last_update_status.iter())
+                        .any(|v| *v == LastUpdateStatus::Failed) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .configuration
+                        .iter()
+                        .flat_map(|v| v.last_update_status.iter())
+                        .any(|v| *v == LastUpdateStatus::InProgress) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Retry)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+
+    /// Waits until `get_function_configuration` reports the `PublishedVersionActive` state, polling every 5 seconds up to 312 times.
+    pub async fn wait_until_published_version_active(
+        &self,
+        input: GetFunctionConfigurationRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetFunctionConfigurationError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(5), 312);
+        self.wait_until_published_version_active_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_published_version_active`, polling as often and as many times as `config` says.
+    pub async fn wait_until_published_version_active_with_config(
+        &self,
+        input: GetFunctionConfigurationRequest,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetFunctionConfigurationError>> {
+        rusoto_core::waiter::wait(
+            "PublishedVersionActive",
+            &config,
+            || self.get_function_configuration(input.clone()),
+            |result: &Result<FunctionConfiguration, RusotoError<GetFunctionConfigurationError>>| {
+                match result {
+                    Ok(output) if output.state.iter().any(|v| *v == State::Active) => {
+                        Some(rusoto_core::waiter::WaiterState::Success)
+                    }
+                    Ok(output) if output.state.iter().any(|v| *v == State::Failed) => {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    Ok(output) if output.state.iter().any(|v| *v == State::Pending) => {
+                        Some(rusoto_core::waiter::WaiterState::Retry)
+                    }
+                    _ => None,
+                }
+            },
+        )
+        .await
+    }
+}
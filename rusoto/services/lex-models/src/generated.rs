@@ -47,16 +47,26 @@ pub struct BotAliasMetadata {
     pub conversation_logs: Option<ConversationLogsResponse>,
     /// <p>The date that the bot alias was created.</p>
     #[serde(rename = "createdDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A description of the bot alias.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     /// <p>The date that the bot alias was updated. When you create a resource, the creation date and last updated date are the same.</p>
     #[serde(rename = "lastUpdatedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date: Option<f64>,
+    pub last_updated_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the bot alias.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -81,8 +91,13 @@ pub struct BotChannelAssociation {
     pub bot_name: Option<String>,
     /// <p>The date that the association between the Amazon Lex bot and the channel was created. </p>
     #[serde(rename = "createdDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A text description of the association you are creating. </p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -98,11 +113,11 @@ pub struct BotChannelAssociation {
     /// <p><p>The status of the bot channel. </p> <ul> <li> <p> <code>CREATED</code> - The channel has been created and is ready for use.</p> </li> <li> <p> <code>IN_PROGRESS</code> - Channel creation is in progress.</p> </li> <li> <p> <code>FAILED</code> - There was an error creating the channel. For information about the reason for the failure, see the <code>failureReason</code> field.</p> </li> </ul></p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ChannelStatus>,
     /// <p>Specifies the type of association by indicating the type of channel being established between the Amazon Lex bot and the external messaging platform.</p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<ChannelType>,
 }
 
 /// <p>Provides information about a bot. .</p>
@@ -111,16 +126,26 @@ pub struct BotChannelAssociation {
 pub struct BotMetadata {
     /// <p>The date that the bot was created.</p>
     #[serde(rename = "createdDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A description of the bot.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     /// <p>The date that the bot was updated. When you create a bot, the creation date and last updated date are the same. </p>
     #[serde(rename = "lastUpdatedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date: Option<f64>,
+    pub last_updated_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the bot. </p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -128,7 +153,7 @@ pub struct BotMetadata {
     /// <p>The status of the bot.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<Status>,
     /// <p>The version of the bot. For a new bot, the version is always <code>$LATEST</code>.</p>
     #[serde(rename = "version")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -146,7 +171,7 @@ pub struct BuiltinIntentMetadata {
     /// <p>A list of identifiers for the locales that the intent supports.</p>
     #[serde(rename = "supportedLocales")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub supported_locales: Option<Vec<String>>,
+    pub supported_locales: Option<Vec<Locale>>,
 }
 
 /// <p>Provides information about a slot used in a built-in intent.</p>
@@ -170,9 +195,210 @@ pub struct BuiltinSlotTypeMetadata {
     /// <p>A list of target locales for the slot. </p>
     #[serde(rename = "supportedLocales")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub supported_locales: Option<Vec<String>>,
+    pub supported_locales: Option<Vec<Locale>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ChannelStatus {
+    /// `IN_PROGRESS`
+    InProgress,
+    /// `CREATED`
+    Created,
+    /// `FAILED`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ChannelStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ChannelStatus::InProgress => "IN_PROGRESS",
+            ChannelStatus::Created => "CREATED",
+            ChannelStatus::Failed => "FAILED",
+            ChannelStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ChannelStatus {
+    fn default() -> ChannelStatus {
+        ChannelStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ChannelStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ChannelStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ChannelStatus {
+    fn from(value: &'a str) -> ChannelStatus {
+        match value {
+            "IN_PROGRESS" => ChannelStatus::InProgress,
+            "CREATED" => ChannelStatus::Created,
+            "FAILED" => ChannelStatus::Failed,
+            _ => ChannelStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ChannelStatus {
+    fn from(value: String) -> ChannelStatus {
+        match value.as_str() {
+            "IN_PROGRESS" => ChannelStatus::InProgress,
+            "CREATED" => ChannelStatus::Created,
+            "FAILED" => ChannelStatus::Failed,
+            _ => ChannelStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ChannelStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ChannelStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ChannelStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ChannelStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ChannelStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ChannelStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ChannelType {
+    /// `Facebook`
+    Facebook,
+    /// `Slack`
+    Slack,
+    /// `Twilio-Sms`
+    TwilioSms,
+    /// `Kik`
+    Kik,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ChannelType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ChannelType::Facebook => "Facebook",
+            ChannelType::Slack => "Slack",
+            ChannelType::TwilioSms => "Twilio-Sms",
+            ChannelType::Kik => "Kik",
+            ChannelType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ChannelType {
+    fn default() -> ChannelType {
+        ChannelType::Unknown(String::new())
+    }
 }
 
+impl AsRef<str> for ChannelType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ChannelType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ChannelType {
+    fn from(value: &'a str) -> ChannelType {
+        match value {
+            "Facebook" => ChannelType::Facebook,
+            "Slack" => ChannelType::Slack,
+            "Twilio-Sms" => ChannelType::TwilioSms,
+            "Kik" => ChannelType::Kik,
+            _ => ChannelType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ChannelType {
+    fn from(value: String) -> ChannelType {
+        match value.as_str() {
+            "Facebook" => ChannelType::Facebook,
+            "Slack" => ChannelType::Slack,
+            "Twilio-Sms" => ChannelType::TwilioSms,
+            "Kik" => ChannelType::Kik,
+            _ => ChannelType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ChannelType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ChannelType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ChannelType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ChannelType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ChannelType {
+    fn deserialize<D>(deserializer: D) -> Result<ChannelType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Specifies a Lambda function that verifies requests to a bot or fulfills the user's request to a bot..</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CodeHook {
@@ -184,6 +410,104 @@ pub struct CodeHook {
     pub uri: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ContentType {
+    /// `PlainText`
+    PlainText,
+    /// `SSML`
+    Ssml,
+    /// `CustomPayload`
+    CustomPayload,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ContentType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ContentType::PlainText => "PlainText",
+            ContentType::Ssml => "SSML",
+            ContentType::CustomPayload => "CustomPayload",
+            ContentType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ContentType {
+    fn default() -> ContentType {
+        ContentType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ContentType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ContentType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ContentType {
+    fn from(value: &'a str) -> ContentType {
+        match value {
+            "PlainText" => ContentType::PlainText,
+            "SSML" => ContentType::Ssml,
+            "CustomPayload" => ContentType::CustomPayload,
+            _ => ContentType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ContentType {
+    fn from(value: String) -> ContentType {
+        match value.as_str() {
+            "PlainText" => ContentType::PlainText,
+            "SSML" => ContentType::Ssml,
+            "CustomPayload" => ContentType::CustomPayload,
+            _ => ContentType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ContentType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ContentType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ContentType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ContentType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ContentType {
+    fn deserialize<D>(deserializer: D) -> Result<ContentType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Provides the settings needed for conversation logs.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -243,8 +567,13 @@ pub struct CreateBotVersionResponse {
     pub clarification_prompt: Option<Prompt>,
     /// <p>The date when the bot version was created.</p>
     #[serde(rename = "createdDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A description of the bot.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -267,12 +596,17 @@ pub struct CreateBotVersionResponse {
     pub intents: Option<Vec<Intent>>,
     /// <p>The date when the <code>$LATEST</code> version of this bot was updated. </p>
     #[serde(rename = "lastUpdatedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date: Option<f64>,
+    pub last_updated_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p> Specifies the target locale for the bot. </p>
     #[serde(rename = "locale")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub locale: Option<String>,
+    pub locale: Option<Locale>,
     /// <p>The name of the bot.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -280,7 +614,7 @@ pub struct CreateBotVersionResponse {
     /// <p> When you send a request to create or update a bot, Amazon Lex sets the <code>status</code> response element to <code>BUILDING</code>. After Amazon Lex builds the bot, it sets <code>status</code> to <code>READY</code>. If Amazon Lex can't build the bot, it sets <code>status</code> to <code>FAILED</code>. Amazon Lex returns the reason for the failure in the <code>failureReason</code> response element. </p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<Status>,
     /// <p>The version of the bot. </p>
     #[serde(rename = "version")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -320,8 +654,13 @@ pub struct CreateIntentVersionResponse {
     pub confirmation_prompt: Option<Prompt>,
     /// <p>The date that the intent was created.</p>
     #[serde(rename = "createdDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A description of the intent.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -340,8 +679,13 @@ pub struct CreateIntentVersionResponse {
     pub fulfillment_activity: Option<FulfillmentActivity>,
     /// <p>The date that the intent was updated. </p>
     #[serde(rename = "lastUpdatedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date: Option<f64>,
+    pub last_updated_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the intent.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -389,8 +733,13 @@ pub struct CreateSlotTypeVersionResponse {
     pub checksum: Option<String>,
     /// <p>The date that the slot type was created.</p>
     #[serde(rename = "createdDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A description of the slot type.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -401,8 +750,13 @@ pub struct CreateSlotTypeVersionResponse {
     pub enumeration_values: Option<Vec<EnumerationValue>>,
     /// <p>The date that the slot type was updated. When you create a resource, the creation date and last update date are the same.</p>
     #[serde(rename = "lastUpdatedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date: Option<f64>,
+    pub last_updated_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the slot type.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -410,7 +764,7 @@ pub struct CreateSlotTypeVersionResponse {
     /// <p>The strategy that Amazon Lex uses to determine the value of the slot. For more information, see <a>PutSlotType</a>.</p>
     #[serde(rename = "valueSelectionStrategy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub value_selection_strategy: Option<String>,
+    pub value_selection_strategy: Option<SlotValueSelectionStrategy>,
     /// <p>The version assigned to the new slot type version. </p>
     #[serde(rename = "version")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -510,6 +864,99 @@ pub struct DeleteUtterancesRequest {
     pub user_id: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Destination {
+    /// `CLOUDWATCH_LOGS`
+    CloudwatchLogs,
+    /// `S3`
+    S3,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Destination {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Destination::CloudwatchLogs => "CLOUDWATCH_LOGS",
+            Destination::S3 => "S3",
+            Destination::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Destination {
+    fn default() -> Destination {
+        Destination::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Destination {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Destination {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Destination {
+    fn from(value: &'a str) -> Destination {
+        match value {
+            "CLOUDWATCH_LOGS" => Destination::CloudwatchLogs,
+            "S3" => Destination::S3,
+            _ => Destination::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Destination {
+    fn from(value: String) -> Destination {
+        match value.as_str() {
+            "CLOUDWATCH_LOGS" => Destination::CloudwatchLogs,
+            "S3" => Destination::S3,
+            _ => Destination::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Destination {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Destination, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Destination {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Destination {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Destination {
+    fn deserialize<D>(deserializer: D) -> Result<Destination, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p><p>Each slot type can have a set of values. Each enumeration value represents a value the slot type can take. </p> <p>For example, a pizza ordering bot could have a slot type that specifies the type of crust that the pizza should have. The slot type could include the values </p> <ul> <li> <p>thick</p> </li> <li> <p>thin</p> </li> <li> <p>stuffed</p> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct EnumerationValue {
@@ -522,72 +969,366 @@ pub struct EnumerationValue {
     pub value: String,
 }
 
-/// <p>A prompt for additional activity after an intent is fulfilled. For example, after the <code>OrderPizza</code> intent is fulfilled, you might prompt the user to find out whether the user wants to order drinks.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct FollowUpPrompt {
-    /// <p>Prompts for information from the user. </p>
-    #[serde(rename = "prompt")]
-    pub prompt: Prompt,
-    /// <p>If the user answers "no" to the question defined in the <code>prompt</code> field, Amazon Lex responds with this statement to acknowledge that the intent was canceled. </p>
-    #[serde(rename = "rejectionStatement")]
-    pub rejection_statement: Statement,
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ExportStatus {
+    /// `IN_PROGRESS`
+    InProgress,
+    /// `READY`
+    Ready,
+    /// `FAILED`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
 }
 
-/// <p><p> Describes how the intent is fulfilled after the user provides all of the information required for the intent. You can provide a Lambda function to process the intent, or you can return the intent information to the client application. We recommend that you use a Lambda function so that the relevant logic lives in the Cloud and limit the client-side code primarily to presentation. If you need to update the logic, you only update the Lambda function; you don&#39;t need to upgrade your client application. </p> <p>Consider the following examples:</p> <ul> <li> <p>In a pizza ordering application, after the user provides all of the information for placing an order, you use a Lambda function to place an order with a pizzeria. </p> </li> <li> <p>In a gaming application, when a user says &quot;pick up a rock,&quot; this information must go back to the client application so that it can perform the operation and update the graphics. In this case, you want Amazon Lex to return the intent data to the client. </p> </li> </ul></p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct FulfillmentActivity {
-    /// <p> A description of the Lambda function that is run to fulfill the intent. </p>
-    #[serde(rename = "codeHook")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub code_hook: Option<CodeHook>,
-    /// <p> How the intent should be fulfilled, either by running a Lambda function or by returning the slot data to the client application. </p>
-    #[serde(rename = "type")]
-    pub type_: String,
+impl ExportStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ExportStatus::InProgress => "IN_PROGRESS",
+            ExportStatus::Ready => "READY",
+            ExportStatus::Failed => "FAILED",
+            ExportStatus::Unknown(ref value) => value,
+        }
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct GetBotAliasRequest {
-    /// <p>The name of the bot.</p>
-    #[serde(rename = "botName")]
-    pub bot_name: String,
-    /// <p>The name of the bot alias. The name is case sensitive.</p>
-    #[serde(rename = "name")]
-    pub name: String,
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ExportStatus {
+    fn default() -> ExportStatus {
+        ExportStatus::Unknown(String::new())
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct GetBotAliasResponse {
-    /// <p>The name of the bot that the alias points to.</p>
-    #[serde(rename = "botName")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub bot_name: Option<String>,
-    /// <p>The version of the bot that the alias points to.</p>
-    #[serde(rename = "botVersion")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub bot_version: Option<String>,
-    /// <p>Checksum of the bot alias.</p>
-    #[serde(rename = "checksum")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub checksum: Option<String>,
-    /// <p>The settings that determine how Amazon Lex uses conversation logs for the alias.</p>
-    #[serde(rename = "conversationLogs")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub conversation_logs: Option<ConversationLogsResponse>,
-    /// <p>The date that the bot alias was created.</p>
-    #[serde(rename = "createdDate")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
-    /// <p>A description of the bot alias.</p>
-    #[serde(rename = "description")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub description: Option<String>,
-    /// <p>The date that the bot alias was updated. When you create a resource, the creation date and the last updated date are the same.</p>
-    #[serde(rename = "lastUpdatedDate")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date: Option<f64>,
-    /// <p>The name of the bot alias.</p>
+impl AsRef<str> for ExportStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ExportStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ExportStatus {
+    fn from(value: &'a str) -> ExportStatus {
+        match value {
+            "IN_PROGRESS" => ExportStatus::InProgress,
+            "READY" => ExportStatus::Ready,
+            "FAILED" => ExportStatus::Failed,
+            _ => ExportStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ExportStatus {
+    fn from(value: String) -> ExportStatus {
+        match value.as_str() {
+            "IN_PROGRESS" => ExportStatus::InProgress,
+            "READY" => ExportStatus::Ready,
+            "FAILED" => ExportStatus::Failed,
+            _ => ExportStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ExportStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ExportStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ExportStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ExportStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ExportStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ExportStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ExportType {
+    /// `ALEXA_SKILLS_KIT`
+    AlexaSkillsKit,
+    /// `LEX`
+    Lex,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ExportType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ExportType::AlexaSkillsKit => "ALEXA_SKILLS_KIT",
+            ExportType::Lex => "LEX",
+            ExportType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ExportType {
+    fn default() -> ExportType {
+        ExportType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ExportType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ExportType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ExportType {
+    fn from(value: &'a str) -> ExportType {
+        match value {
+            "ALEXA_SKILLS_KIT" => ExportType::AlexaSkillsKit,
+            "LEX" => ExportType::Lex,
+            _ => ExportType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ExportType {
+    fn from(value: String) -> ExportType {
+        match value.as_str() {
+            "ALEXA_SKILLS_KIT" => ExportType::AlexaSkillsKit,
+            "LEX" => ExportType::Lex,
+            _ => ExportType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ExportType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ExportType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ExportType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ExportType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ExportType {
+    fn deserialize<D>(deserializer: D) -> Result<ExportType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>A prompt for additional activity after an intent is fulfilled. For example, after the <code>OrderPizza</code> intent is fulfilled, you might prompt the user to find out whether the user wants to order drinks.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FollowUpPrompt {
+    /// <p>Prompts for information from the user. </p>
+    #[serde(rename = "prompt")]
+    pub prompt: Prompt,
+    /// <p>If the user answers "no" to the question defined in the <code>prompt</code> field, Amazon Lex responds with this statement to acknowledge that the intent was canceled. </p>
+    #[serde(rename = "rejectionStatement")]
+    pub rejection_statement: Statement,
+}
+
+/// <p><p> Describes how the intent is fulfilled after the user provides all of the information required for the intent. You can provide a Lambda function to process the intent, or you can return the intent information to the client application. We recommend that you use a Lambda function so that the relevant logic lives in the Cloud and limit the client-side code primarily to presentation. If you need to update the logic, you only update the Lambda function; you don&#39;t need to upgrade your client application. </p> <p>Consider the following examples:</p> <ul> <li> <p>In a pizza ordering application, after the user provides all of the information for placing an order, you use a Lambda function to place an order with a pizzeria. </p> </li> <li> <p>In a gaming application, when a user says &quot;pick up a rock,&quot; this information must go back to the client application so that it can perform the operation and update the graphics. In this case, you want Amazon Lex to return the intent data to the client. </p> </li> </ul></p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FulfillmentActivity {
+    /// <p> A description of the Lambda function that is run to fulfill the intent. </p>
+    #[serde(rename = "codeHook")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code_hook: Option<CodeHook>,
+    /// <p> How the intent should be fulfilled, either by running a Lambda function or by returning the slot data to the client application. </p>
+    #[serde(rename = "type")]
+    pub type_: FulfillmentActivityType,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FulfillmentActivityType {
+    /// `ReturnIntent`
+    ReturnIntent,
+    /// `CodeHook`
+    CodeHook,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl FulfillmentActivityType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FulfillmentActivityType::ReturnIntent => "ReturnIntent",
+            FulfillmentActivityType::CodeHook => "CodeHook",
+            FulfillmentActivityType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for FulfillmentActivityType {
+    fn default() -> FulfillmentActivityType {
+        FulfillmentActivityType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for FulfillmentActivityType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FulfillmentActivityType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FulfillmentActivityType {
+    fn from(value: &'a str) -> FulfillmentActivityType {
+        match value {
+            "ReturnIntent" => FulfillmentActivityType::ReturnIntent,
+            "CodeHook" => FulfillmentActivityType::CodeHook,
+            _ => FulfillmentActivityType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FulfillmentActivityType {
+    fn from(value: String) -> FulfillmentActivityType {
+        match value.as_str() {
+            "ReturnIntent" => FulfillmentActivityType::ReturnIntent,
+            "CodeHook" => FulfillmentActivityType::CodeHook,
+            _ => FulfillmentActivityType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for FulfillmentActivityType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<FulfillmentActivityType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FulfillmentActivityType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FulfillmentActivityType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FulfillmentActivityType {
+    fn deserialize<D>(deserializer: D) -> Result<FulfillmentActivityType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct GetBotAliasRequest {
+    /// <p>The name of the bot.</p>
+    #[serde(rename = "botName")]
+    pub bot_name: String,
+    /// <p>The name of the bot alias. The name is case sensitive.</p>
+    #[serde(rename = "name")]
+    pub name: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct GetBotAliasResponse {
+    /// <p>The name of the bot that the alias points to.</p>
+    #[serde(rename = "botName")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bot_name: Option<String>,
+    /// <p>The version of the bot that the alias points to.</p>
+    #[serde(rename = "botVersion")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bot_version: Option<String>,
+    /// <p>Checksum of the bot alias.</p>
+    #[serde(rename = "checksum")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub checksum: Option<String>,
+    /// <p>The settings that determine how Amazon Lex uses conversation logs for the alias.</p>
+    #[serde(rename = "conversationLogs")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conversation_logs: Option<ConversationLogsResponse>,
+    /// <p>The date that the bot alias was created.</p>
+    #[serde(rename = "createdDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_date: Option<::chrono::DateTime<::chrono::Utc>>,
+    /// <p>A description of the bot alias.</p>
+    #[serde(rename = "description")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// <p>The date that the bot alias was updated. When you create a resource, the creation date and the last updated date are the same.</p>
+    #[serde(rename = "lastUpdatedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_updated_date: Option<::chrono::DateTime<::chrono::Utc>>,
+    /// <p>The name of the bot alias.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
@@ -657,8 +1398,13 @@ pub struct GetBotChannelAssociationResponse {
     pub bot_name: Option<String>,
     /// <p>The date that the association between the bot and the channel was created.</p>
     #[serde(rename = "createdDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A description of the association between the bot and the channel.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -674,11 +1420,11 @@ pub struct GetBotChannelAssociationResponse {
     /// <p><p>The status of the bot channel. </p> <ul> <li> <p> <code>CREATED</code> - The channel has been created and is ready for use.</p> </li> <li> <p> <code>IN_PROGRESS</code> - Channel creation is in progress.</p> </li> <li> <p> <code>FAILED</code> - There was an error creating the channel. For information about the reason for the failure, see the <code>failureReason</code> field.</p> </li> </ul></p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ChannelStatus>,
     /// <p>The type of the messaging platform.</p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<ChannelType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -749,8 +1495,13 @@ pub struct GetBotResponse {
     pub clarification_prompt: Option<Prompt>,
     /// <p>The date that the bot was created.</p>
     #[serde(rename = "createdDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A description of the bot.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -773,12 +1524,17 @@ pub struct GetBotResponse {
     pub intents: Option<Vec<Intent>>,
     /// <p>The date that the bot was updated. When you create a resource, the creation date and last updated date are the same. </p>
     #[serde(rename = "lastUpdatedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date: Option<f64>,
+    pub last_updated_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p> The target locale for the bot. </p>
     #[serde(rename = "locale")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub locale: Option<String>,
+    pub locale: Option<Locale>,
     /// <p>The name of the bot.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -786,7 +1542,7 @@ pub struct GetBotResponse {
     /// <p>The status of the bot. </p> <p>When the status is <code>BUILDING</code> Amazon Lex is building the bot for testing and use.</p> <p>If the status of the bot is <code>READY_BASIC_TESTING</code>, you can test the bot using the exact utterances specified in the bot's intents. When the bot is ready for full testing or to run, the status is <code>READY</code>.</p> <p>If there was a problem with building the bot, the status is <code>FAILED</code> and the <code>failureReason</code> field explains why the bot did not build.</p> <p>If the bot was saved but not built, the status is <code>NOT_BUILT</code>.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<Status>,
     /// <p>The version of the bot. For a new bot, the version is always <code>$LATEST</code>.</p>
     #[serde(rename = "version")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -878,7 +1634,7 @@ pub struct GetBuiltinIntentResponse {
     /// <p>A list of locales that the intent supports.</p>
     #[serde(rename = "supportedLocales")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub supported_locales: Option<Vec<String>>,
+    pub supported_locales: Option<Vec<Locale>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -887,7 +1643,7 @@ pub struct GetBuiltinIntentsRequest {
     /// <p>A list of locales that the intent supports.</p>
     #[serde(rename = "locale")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub locale: Option<String>,
+    pub locale: Option<Locale>,
     /// <p>The maximum number of intents to return in the response. The default is 10.</p>
     #[serde(rename = "maxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -921,7 +1677,7 @@ pub struct GetBuiltinSlotTypesRequest {
     /// <p>A list of locales that the slot type supports.</p>
     #[serde(rename = "locale")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub locale: Option<String>,
+    pub locale: Option<Locale>,
     /// <p>The maximum number of slot types to return in the response. The default is 10.</p>
     #[serde(rename = "maxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -954,13 +1710,13 @@ pub struct GetBuiltinSlotTypesResponse {
 pub struct GetExportRequest {
     /// <p>The format of the exported data.</p>
     #[serde(rename = "exportType")]
-    pub export_type: String,
+    pub export_type: ExportType,
     /// <p>The name of the bot to export.</p>
     #[serde(rename = "name")]
     pub name: String,
     /// <p>The type of resource to export. </p>
     #[serde(rename = "resourceType")]
-    pub resource_type: String,
+    pub resource_type: ResourceType,
     /// <p>The version of the bot to export.</p>
     #[serde(rename = "version")]
     pub version: String,
@@ -972,11 +1728,11 @@ pub struct GetExportResponse {
     /// <p><p>The status of the export. </p> <ul> <li> <p> <code>IN_PROGRESS</code> - The export is in progress.</p> </li> <li> <p> <code>READY</code> - The export is complete.</p> </li> <li> <p> <code>FAILED</code> - The export could not be completed.</p> </li> </ul></p>
     #[serde(rename = "exportStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub export_status: Option<String>,
+    pub export_status: Option<ExportStatus>,
     /// <p>The format of the exported data.</p>
     #[serde(rename = "exportType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub export_type: Option<String>,
+    pub export_type: Option<ExportType>,
     /// <p>If <code>status</code> is <code>FAILED</code>, Amazon Lex provides the reason that it failed to export the resource.</p>
     #[serde(rename = "failureReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -988,7 +1744,7 @@ pub struct GetExportResponse {
     /// <p>The type of the exported resource.</p>
     #[serde(rename = "resourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ResourceType>,
     /// <p>An S3 pre-signed URL that provides the location of the exported resource. The exported resource is a ZIP archive that contains the exported resource in JSON format. The structure of the archive may change. Your code should not rely on the archive structure.</p>
     #[serde(rename = "url")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1012,8 +1768,13 @@ pub struct GetImportRequest {
 pub struct GetImportResponse {
     /// <p>A timestamp for the date and time that the import job was created.</p>
     #[serde(rename = "createdDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A string that describes why an import job failed to complete.</p>
     #[serde(rename = "failureReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1025,11 +1786,11 @@ pub struct GetImportResponse {
     /// <p>The status of the import job. If the status is <code>FAILED</code>, you can get the reason for the failure from the <code>failureReason</code> field.</p>
     #[serde(rename = "importStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub import_status: Option<String>,
+    pub import_status: Option<ImportStatus>,
     /// <p>The action taken when there was a conflict between an existing resource and a resource in the import file.</p>
     #[serde(rename = "mergeStrategy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub merge_strategy: Option<String>,
+    pub merge_strategy: Option<MergeStrategy>,
     /// <p>The name given to the import job.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1037,7 +1798,7 @@ pub struct GetImportResponse {
     /// <p>The type of resource imported.</p>
     #[serde(rename = "resourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ResourceType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -1068,8 +1829,13 @@ pub struct GetIntentResponse {
     pub confirmation_prompt: Option<Prompt>,
     /// <p>The date that the intent was created.</p>
     #[serde(rename = "createdDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A description of the intent.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1088,8 +1854,13 @@ pub struct GetIntentResponse {
     pub fulfillment_activity: Option<FulfillmentActivity>,
     /// <p>The date that the intent was updated. When you create a resource, the creation date and the last updated date are the same. </p>
     #[serde(rename = "lastUpdatedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date: Option<f64>,
+    pub last_updated_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the intent.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1195,8 +1966,13 @@ pub struct GetSlotTypeResponse {
     pub checksum: Option<String>,
     /// <p>The date that the slot type was created.</p>
     #[serde(rename = "createdDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A description of the slot type.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1207,8 +1983,13 @@ pub struct GetSlotTypeResponse {
     pub enumeration_values: Option<Vec<EnumerationValue>>,
     /// <p>The date that the slot type was updated. When you create a resource, the creation date and last update date are the same.</p>
     #[serde(rename = "lastUpdatedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date: Option<f64>,
+    pub last_updated_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the slot type.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1216,7 +1997,7 @@ pub struct GetSlotTypeResponse {
     /// <p>The strategy that Amazon Lex uses to determine the value of the slot. For more information, see <a>PutSlotType</a>.</p>
     #[serde(rename = "valueSelectionStrategy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub value_selection_strategy: Option<String>,
+    pub value_selection_strategy: Option<SlotValueSelectionStrategy>,
     /// <p>The version of the slot type.</p>
     #[serde(rename = "version")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1293,7 +2074,7 @@ pub struct GetUtterancesViewRequest {
     pub bot_versions: Vec<String>,
     /// <p>To return utterances that were recognized and handled, use <code>Detected</code>. To return utterances that were not recognized, use <code>Missed</code>.</p>
     #[serde(rename = "statusType")]
-    pub status_type: String,
+    pub status_type: StatusType,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1309,6 +2090,104 @@ pub struct GetUtterancesViewResponse {
     pub utterances: Option<Vec<UtteranceList>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ImportStatus {
+    /// `IN_PROGRESS`
+    InProgress,
+    /// `COMPLETE`
+    Complete,
+    /// `FAILED`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ImportStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ImportStatus::InProgress => "IN_PROGRESS",
+            ImportStatus::Complete => "COMPLETE",
+            ImportStatus::Failed => "FAILED",
+            ImportStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ImportStatus {
+    fn default() -> ImportStatus {
+        ImportStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ImportStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ImportStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ImportStatus {
+    fn from(value: &'a str) -> ImportStatus {
+        match value {
+            "IN_PROGRESS" => ImportStatus::InProgress,
+            "COMPLETE" => ImportStatus::Complete,
+            "FAILED" => ImportStatus::Failed,
+            _ => ImportStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ImportStatus {
+    fn from(value: String) -> ImportStatus {
+        match value.as_str() {
+            "IN_PROGRESS" => ImportStatus::InProgress,
+            "COMPLETE" => ImportStatus::Complete,
+            "FAILED" => ImportStatus::Failed,
+            _ => ImportStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ImportStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ImportStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ImportStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ImportStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ImportStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ImportStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Identifies the specific version of an intent.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Intent {
@@ -1326,16 +2205,26 @@ pub struct Intent {
 pub struct IntentMetadata {
     /// <p>The date that the intent was created.</p>
     #[serde(rename = "createdDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A description of the intent.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     /// <p>The date that the intent was updated. When you create an intent, the creation date and last updated date are the same.</p>
     #[serde(rename = "lastUpdatedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date: Option<f64>,
+    pub last_updated_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the intent.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1346,20 +2235,168 @@ pub struct IntentMetadata {
     pub version: Option<String>,
 }
 
-/// <p>Settings used to configure delivery mode and destination for conversation logs.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Locale {
+    /// `de-DE`
+    DeDe,
+    /// `en-AU`
+    EnAu,
+    /// `en-GB`
+    EnGb,
+    /// `en-IN`
+    EnIn,
+    /// `en-US`
+    EnUs,
+    /// `es-419`
+    Es419,
+    /// `es-ES`
+    EsEs,
+    /// `es-US`
+    EsUs,
+    /// `fr-FR`
+    FrFr,
+    /// `fr-CA`
+    FrCa,
+    /// `it-IT`
+    ItIt,
+    /// `ja-JP`
+    JaJp,
+    /// `ko-KR`
+    KoKr,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Locale {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Locale::DeDe => "de-DE",
+            Locale::EnAu => "en-AU",
+            Locale::EnGb => "en-GB",
+            Locale::EnIn => "en-IN",
+            Locale::EnUs => "en-US",
+            Locale::Es419 => "es-419",
+            Locale::EsEs => "es-ES",
+            Locale::EsUs => "es-US",
+            Locale::FrFr => "fr-FR",
+            Locale::FrCa => "fr-CA",
+            Locale::ItIt => "it-IT",
+            Locale::JaJp => "ja-JP",
+            Locale::KoKr => "ko-KR",
+            Locale::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Locale {
+    fn default() -> Locale {
+        Locale::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Locale {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Locale {
+    fn from(value: &'a str) -> Locale {
+        match value {
+            "de-DE" => Locale::DeDe,
+            "en-AU" => Locale::EnAu,
+            "en-GB" => Locale::EnGb,
+            "en-IN" => Locale::EnIn,
+            "en-US" => Locale::EnUs,
+            "es-419" => Locale::Es419,
+            "es-ES" => Locale::EsEs,
+            "es-US" => Locale::EsUs,
+            "fr-FR" => Locale::FrFr,
+            "fr-CA" => Locale::FrCa,
+            "it-IT" => Locale::ItIt,
+            "ja-JP" => Locale::JaJp,
+            "ko-KR" => Locale::KoKr,
+            _ => Locale::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Locale {
+    fn from(value: String) -> Locale {
+        match value.as_str() {
+            "de-DE" => Locale::DeDe,
+            "en-AU" => Locale::EnAu,
+            "en-GB" => Locale::EnGb,
+            "en-IN" => Locale::EnIn,
+            "en-US" => Locale::EnUs,
+            "es-419" => Locale::Es419,
+            "es-ES" => Locale::EsEs,
+            "es-US" => Locale::EsUs,
+            "fr-FR" => Locale::FrFr,
+            "fr-CA" => Locale::FrCa,
+            "it-IT" => Locale::ItIt,
+            "ja-JP" => Locale::JaJp,
+            "ko-KR" => Locale::KoKr,
+            _ => Locale::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Locale {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Locale, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Locale {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Locale {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Locale {
+    fn deserialize<D>(deserializer: D) -> Result<Locale, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Settings used to configure delivery mode and destination for conversation logs.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct LogSettingsRequest {
     /// <p>Where the logs will be delivered. Text logs are delivered to a CloudWatch Logs log group. Audio logs are delivered to an S3 bucket.</p>
     #[serde(rename = "destination")]
-    pub destination: String,
+    pub destination: Destination,
     /// <p>The Amazon Resource Name (ARN) of the AWS KMS customer managed key for encrypting audio logs delivered to an S3 bucket. The key does not apply to CloudWatch Logs and is optional for S3 buckets.</p>
     #[serde(rename = "kmsKeyArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub kms_key_arn: Option<String>,
     /// <p>The type of logging to enable. Text logs are delivered to a CloudWatch Logs log group. Audio logs are delivered to an S3 bucket.</p>
     #[serde(rename = "logType")]
-    pub log_type: String,
+    pub log_type: LogType,
     /// <p>The Amazon Resource Name (ARN) of the CloudWatch Logs log group or S3 bucket where the logs should be delivered.</p>
     #[serde(rename = "resourceArn")]
     pub resource_arn: String,
@@ -1372,7 +2409,7 @@ pub struct LogSettingsResponse {
     /// <p>The destination where logs are delivered.</p>
     #[serde(rename = "destination")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub destination: Option<String>,
+    pub destination: Option<Destination>,
     /// <p>The Amazon Resource Name (ARN) of the key used to encrypt audio logs in an S3 bucket.</p>
     #[serde(rename = "kmsKeyArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1380,7 +2417,7 @@ pub struct LogSettingsResponse {
     /// <p>The type of logging that is enabled.</p>
     #[serde(rename = "logType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub log_type: Option<String>,
+    pub log_type: Option<LogType>,
     /// <p>The Amazon Resource Name (ARN) of the CloudWatch Logs log group or S3 bucket where the logs are delivered.</p>
     #[serde(rename = "resourceArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1391,6 +2428,192 @@ pub struct LogSettingsResponse {
     pub resource_prefix: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LogType {
+    /// `AUDIO`
+    Audio,
+    /// `TEXT`
+    Text,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LogType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LogType::Audio => "AUDIO",
+            LogType::Text => "TEXT",
+            LogType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LogType {
+    fn default() -> LogType {
+        LogType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LogType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LogType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LogType {
+    fn from(value: &'a str) -> LogType {
+        match value {
+            "AUDIO" => LogType::Audio,
+            "TEXT" => LogType::Text,
+            _ => LogType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LogType {
+    fn from(value: String) -> LogType {
+        match value.as_str() {
+            "AUDIO" => LogType::Audio,
+            "TEXT" => LogType::Text,
+            _ => LogType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LogType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LogType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LogType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LogType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LogType {
+    fn deserialize<D>(deserializer: D) -> Result<LogType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MergeStrategy {
+    /// `OVERWRITE_LATEST`
+    OverwriteLatest,
+    /// `FAIL_ON_CONFLICT`
+    FailOnConflict,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl MergeStrategy {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MergeStrategy::OverwriteLatest => "OVERWRITE_LATEST",
+            MergeStrategy::FailOnConflict => "FAIL_ON_CONFLICT",
+            MergeStrategy::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for MergeStrategy {
+    fn default() -> MergeStrategy {
+        MergeStrategy::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for MergeStrategy {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for MergeStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for MergeStrategy {
+    fn from(value: &'a str) -> MergeStrategy {
+        match value {
+            "OVERWRITE_LATEST" => MergeStrategy::OverwriteLatest,
+            "FAIL_ON_CONFLICT" => MergeStrategy::FailOnConflict,
+            _ => MergeStrategy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MergeStrategy {
+    fn from(value: String) -> MergeStrategy {
+        match value.as_str() {
+            "OVERWRITE_LATEST" => MergeStrategy::OverwriteLatest,
+            "FAIL_ON_CONFLICT" => MergeStrategy::FailOnConflict,
+            _ => MergeStrategy::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for MergeStrategy {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<MergeStrategy, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MergeStrategy {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for MergeStrategy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MergeStrategy {
+    fn deserialize<D>(deserializer: D) -> Result<MergeStrategy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The message object that provides the message text and its type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Message {
@@ -1399,13 +2622,199 @@ pub struct Message {
     pub content: String,
     /// <p>The content type of the message string.</p>
     #[serde(rename = "contentType")]
-    pub content_type: String,
+    pub content_type: ContentType,
     /// <p>Identifies the message group that the message belongs to. When a group is assigned to a message, Amazon Lex returns one message from each group in the response.</p>
     #[serde(rename = "groupNumber")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub group_number: Option<i64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ObfuscationSetting {
+    /// `NONE`
+    None,
+    /// `DEFAULT_OBFUSCATION`
+    DefaultObfuscation,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ObfuscationSetting {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ObfuscationSetting::None => "NONE",
+            ObfuscationSetting::DefaultObfuscation => "DEFAULT_OBFUSCATION",
+            ObfuscationSetting::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ObfuscationSetting {
+    fn default() -> ObfuscationSetting {
+        ObfuscationSetting::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ObfuscationSetting {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ObfuscationSetting {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ObfuscationSetting {
+    fn from(value: &'a str) -> ObfuscationSetting {
+        match value {
+            "NONE" => ObfuscationSetting::None,
+            "DEFAULT_OBFUSCATION" => ObfuscationSetting::DefaultObfuscation,
+            _ => ObfuscationSetting::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ObfuscationSetting {
+    fn from(value: String) -> ObfuscationSetting {
+        match value.as_str() {
+            "NONE" => ObfuscationSetting::None,
+            "DEFAULT_OBFUSCATION" => ObfuscationSetting::DefaultObfuscation,
+            _ => ObfuscationSetting::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ObfuscationSetting {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ObfuscationSetting, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ObfuscationSetting {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ObfuscationSetting {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ObfuscationSetting {
+    fn deserialize<D>(deserializer: D) -> Result<ObfuscationSetting, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ProcessBehavior {
+    /// `SAVE`
+    Save,
+    /// `BUILD`
+    Build,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ProcessBehavior {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ProcessBehavior::Save => "SAVE",
+            ProcessBehavior::Build => "BUILD",
+            ProcessBehavior::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ProcessBehavior {
+    fn default() -> ProcessBehavior {
+        ProcessBehavior::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ProcessBehavior {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ProcessBehavior {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ProcessBehavior {
+    fn from(value: &'a str) -> ProcessBehavior {
+        match value {
+            "SAVE" => ProcessBehavior::Save,
+            "BUILD" => ProcessBehavior::Build,
+            _ => ProcessBehavior::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ProcessBehavior {
+    fn from(value: String) -> ProcessBehavior {
+        match value.as_str() {
+            "SAVE" => ProcessBehavior::Save,
+            "BUILD" => ProcessBehavior::Build,
+            _ => ProcessBehavior::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ProcessBehavior {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ProcessBehavior, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ProcessBehavior {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ProcessBehavior {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ProcessBehavior {
+    fn deserialize<D>(deserializer: D) -> Result<ProcessBehavior, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Obtains information from the user. To define a prompt, provide one or more messages and specify the number of attempts to get information from the user. If you provide more than one message, Amazon Lex chooses one of the messages to use to prompt the user. For more information, see <a>how-it-works</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Prompt {
@@ -1468,16 +2877,26 @@ pub struct PutBotAliasResponse {
     pub conversation_logs: Option<ConversationLogsResponse>,
     /// <p>The date that the bot alias was created.</p>
     #[serde(rename = "createdDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A description of the alias.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     /// <p>The date that the bot alias was updated. When you create a resource, the creation date and the last updated date are the same.</p>
     #[serde(rename = "lastUpdatedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date: Option<f64>,
+    pub last_updated_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the alias.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1524,14 +2943,14 @@ pub struct PutBotRequest {
     pub intents: Option<Vec<Intent>>,
     /// <p> Specifies the target locale for the bot. Any intent used in the bot must be compatible with the locale of the bot. </p> <p>The default is <code>en-US</code>.</p>
     #[serde(rename = "locale")]
-    pub locale: String,
+    pub locale: Locale,
     /// <p>The name of the bot. The name is <i>not</i> case sensitive. </p>
     #[serde(rename = "name")]
     pub name: String,
     /// <p>If you set the <code>processBehavior</code> element to <code>BUILD</code>, Amazon Lex builds the bot so that it can be run. If you set the element to <code>SAVE</code> Amazon Lex saves the bot, but doesn't build it. </p> <p>If you don't specify this value, the default value is <code>BUILD</code>.</p>
     #[serde(rename = "processBehavior")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub process_behavior: Option<String>,
+    pub process_behavior: Option<ProcessBehavior>,
     /// <p>The Amazon Polly voice ID that you want Amazon Lex to use for voice interactions with the user. The locale configured for the voice must match the locale of the bot. For more information, see <a href="https://docs.aws.amazon.com/polly/latest/dg/voicelist.html">Voices in Amazon Polly</a> in the <i>Amazon Polly Developer Guide</i>.</p>
     #[serde(rename = "voiceId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1563,8 +2982,13 @@ pub struct PutBotResponse {
     pub create_version: Option<bool>,
     /// <p>The date that the bot was created.</p>
     #[serde(rename = "createdDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A description of the bot.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1587,12 +3011,17 @@ pub struct PutBotResponse {
     pub intents: Option<Vec<Intent>>,
     /// <p>The date that the bot was updated. When you create a resource, the creation date and last updated date are the same.</p>
     #[serde(rename = "lastUpdatedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date: Option<f64>,
+    pub last_updated_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p> The target locale for the bot. </p>
     #[serde(rename = "locale")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub locale: Option<String>,
+    pub locale: Option<Locale>,
     /// <p>The name of the bot.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1600,7 +3029,7 @@ pub struct PutBotResponse {
     /// <p> When you send a request to create a bot with <code>processBehavior</code> set to <code>BUILD</code>, Amazon Lex sets the <code>status</code> response element to <code>BUILDING</code>.</p> <p>In the <code>READY_BASIC_TESTING</code> state you can test the bot with user inputs that exactly match the utterances configured for the bot's intents and values in the slot types.</p> <p>If Amazon Lex can't build the bot, Amazon Lex sets <code>status</code> to <code>FAILED</code>. Amazon Lex returns the reason for the failure in the <code>failureReason</code> response element. </p> <p>When you set <code>processBehavior</code> to <code>SAVE</code>, Amazon Lex sets the status code to <code>NOT BUILT</code>.</p> <p>When the bot is in the <code>READY</code> state you can test and publish the bot.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<Status>,
     /// <p>The version of the bot. For a new bot, the version is always <code>$LATEST</code>.</p>
     #[serde(rename = "version")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1688,8 +3117,13 @@ pub struct PutIntentResponse {
     pub create_version: Option<bool>,
     /// <p>The date that the intent was created.</p>
     #[serde(rename = "createdDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A description of the intent.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1708,8 +3142,13 @@ pub struct PutIntentResponse {
     pub fulfillment_activity: Option<FulfillmentActivity>,
     /// <p>The date that the intent was updated. When you create a resource, the creation date and last update dates are the same.</p>
     #[serde(rename = "lastUpdatedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date: Option<f64>,
+    pub last_updated_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the intent.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1761,7 +3200,7 @@ pub struct PutSlotTypeRequest {
     /// <p>Determines the slot resolution strategy that Amazon Lex uses to return slot type values. The field can be set to one of the following values:</p> <ul> <li> <p> <code>ORIGINAL_VALUE</code> - Returns the value entered by the user, if the user value is similar to the slot value.</p> </li> <li> <p> <code>TOP_RESOLUTION</code> - If there is a resolution list for the slot, return the first value in the resolution list as the slot type value. If there is no resolution list, null is returned.</p> </li> </ul> <p>If you don't specify the <code>valueSelectionStrategy</code>, the default is <code>ORIGINAL_VALUE</code>.</p>
     #[serde(rename = "valueSelectionStrategy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub value_selection_strategy: Option<String>,
+    pub value_selection_strategy: Option<SlotValueSelectionStrategy>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1777,8 +3216,13 @@ pub struct PutSlotTypeResponse {
     pub create_version: Option<bool>,
     /// <p>The date that the slot type was created.</p>
     #[serde(rename = "createdDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A description of the slot type.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1789,8 +3233,13 @@ pub struct PutSlotTypeResponse {
     pub enumeration_values: Option<Vec<EnumerationValue>>,
     /// <p>The date that the slot type was updated. When you create a slot type, the creation date and last update date are the same.</p>
     #[serde(rename = "lastUpdatedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date: Option<f64>,
+    pub last_updated_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the slot type.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1798,13 +3247,116 @@ pub struct PutSlotTypeResponse {
     /// <p>The slot resolution strategy that Amazon Lex uses to determine the value of the slot. For more information, see <a>PutSlotType</a>.</p>
     #[serde(rename = "valueSelectionStrategy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub value_selection_strategy: Option<String>,
+    pub value_selection_strategy: Option<SlotValueSelectionStrategy>,
     /// <p>The version of the slot type. For a new slot type, the version is always <code>$LATEST</code>. </p>
     #[serde(rename = "version")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub version: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ReferenceType {
+    /// `Intent`
+    Intent,
+    /// `Bot`
+    Bot,
+    /// `BotAlias`
+    BotAlias,
+    /// `BotChannel`
+    BotChannel,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ReferenceType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReferenceType::Intent => "Intent",
+            ReferenceType::Bot => "Bot",
+            ReferenceType::BotAlias => "BotAlias",
+            ReferenceType::BotChannel => "BotChannel",
+            ReferenceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ReferenceType {
+    fn default() -> ReferenceType {
+        ReferenceType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ReferenceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ReferenceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ReferenceType {
+    fn from(value: &'a str) -> ReferenceType {
+        match value {
+            "Intent" => ReferenceType::Intent,
+            "Bot" => ReferenceType::Bot,
+            "BotAlias" => ReferenceType::BotAlias,
+            "BotChannel" => ReferenceType::BotChannel,
+            _ => ReferenceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReferenceType {
+    fn from(value: String) -> ReferenceType {
+        match value.as_str() {
+            "Intent" => ReferenceType::Intent,
+            "Bot" => ReferenceType::Bot,
+            "BotAlias" => ReferenceType::BotAlias,
+            "BotChannel" => ReferenceType::BotChannel,
+            _ => ReferenceType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ReferenceType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ReferenceType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ReferenceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ReferenceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ReferenceType {
+    fn deserialize<D>(deserializer: D) -> Result<ReferenceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes the resource that refers to the resource that you are attempting to delete. This object is returned as part of the <code>ResourceInUseException</code> exception. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct ResourceReference {
@@ -1814,6 +3366,104 @@ pub struct ResourceReference {
     pub version: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ResourceType {
+    /// `BOT`
+    Bot,
+    /// `INTENT`
+    Intent,
+    /// `SLOT_TYPE`
+    SlotType,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ResourceType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceType::Bot => "BOT",
+            ResourceType::Intent => "INTENT",
+            ResourceType::SlotType => "SLOT_TYPE",
+            ResourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ResourceType {
+    fn default() -> ResourceType {
+        ResourceType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ResourceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ResourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ResourceType {
+    fn from(value: &'a str) -> ResourceType {
+        match value {
+            "BOT" => ResourceType::Bot,
+            "INTENT" => ResourceType::Intent,
+            "SLOT_TYPE" => ResourceType::SlotType,
+            _ => ResourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceType {
+    fn from(value: String) -> ResourceType {
+        match value.as_str() {
+            "BOT" => ResourceType::Bot,
+            "INTENT" => ResourceType::Intent,
+            "SLOT_TYPE" => ResourceType::SlotType,
+            _ => ResourceType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ResourceType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ResourceType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ResourceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ResourceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ResourceType {
+    fn deserialize<D>(deserializer: D) -> Result<ResourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Identifies the version of a specific slot.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Slot {
@@ -1827,7 +3477,7 @@ pub struct Slot {
     /// <p>Determines whether a slot is obfuscated in conversation logs and stored utterances. When you obfuscate a slot, the value is replaced by the slot name in curly braces ({}). For example, if the slot name is "full_name", obfuscated values are replaced with "{full_name}". For more information, see <a href="https://docs.aws.amazon.com/lex/latest/dg/how-obfuscate.html"> Slot Obfuscation </a>. </p>
     #[serde(rename = "obfuscationSetting")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub obfuscation_setting: Option<String>,
+    pub obfuscation_setting: Option<ObfuscationSetting>,
     /// <p> Directs Lex the order in which to elicit this slot value from the user. For example, if the intent has two slots with priorities 1 and 2, AWS Lex first elicits a value for the slot with priority 1.</p> <p>If multiple slots share the same priority, the order in which Lex elicits values is arbitrary.</p>
     #[serde(rename = "priority")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1842,7 +3492,7 @@ pub struct Slot {
     pub sample_utterances: Option<Vec<String>>,
     /// <p>Specifies whether the slot is required or optional. </p>
     #[serde(rename = "slotConstraint")]
-    pub slot_constraint: String,
+    pub slot_constraint: SlotConstraint,
     /// <p>The type of the slot, either a custom slot type that you defined or one of the built-in slot types.</p>
     #[serde(rename = "slotType")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1857,22 +3507,125 @@ pub struct Slot {
     pub value_elicitation_prompt: Option<Prompt>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SlotConstraint {
+    /// `Required`
+    Required,
+    /// `Optional`
+    Optional,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SlotConstraint {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SlotConstraint::Required => "Required",
+            SlotConstraint::Optional => "Optional",
+            SlotConstraint::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SlotConstraint {
+    fn default() -> SlotConstraint {
+        SlotConstraint::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SlotConstraint {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SlotConstraint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SlotConstraint {
+    fn from(value: &'a str) -> SlotConstraint {
+        match value {
+            "Required" => SlotConstraint::Required,
+            "Optional" => SlotConstraint::Optional,
+            _ => SlotConstraint::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SlotConstraint {
+    fn from(value: String) -> SlotConstraint {
+        match value.as_str() {
+            "Required" => SlotConstraint::Required,
+            "Optional" => SlotConstraint::Optional,
+            _ => SlotConstraint::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SlotConstraint {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SlotConstraint, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SlotConstraint {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SlotConstraint {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SlotConstraint {
+    fn deserialize<D>(deserializer: D) -> Result<SlotConstraint, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Provides information about a slot type..</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SlotTypeMetadata {
     /// <p>The date that the slot type was created.</p>
     #[serde(rename = "createdDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A description of the slot type.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     /// <p>The date that the slot type was updated. When you create a resource, the creation date and last updated date are the same. </p>
     #[serde(rename = "lastUpdatedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date: Option<f64>,
+    pub last_updated_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the slot type.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1883,12 +3636,105 @@ pub struct SlotTypeMetadata {
     pub version: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SlotValueSelectionStrategy {
+    /// `ORIGINAL_VALUE`
+    OriginalValue,
+    /// `TOP_RESOLUTION`
+    TopResolution,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SlotValueSelectionStrategy {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SlotValueSelectionStrategy::OriginalValue => "ORIGINAL_VALUE",
+            SlotValueSelectionStrategy::TopResolution => "TOP_RESOLUTION",
+            SlotValueSelectionStrategy::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SlotValueSelectionStrategy {
+    fn default() -> SlotValueSelectionStrategy {
+        SlotValueSelectionStrategy::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SlotValueSelectionStrategy {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SlotValueSelectionStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SlotValueSelectionStrategy {
+    fn from(value: &'a str) -> SlotValueSelectionStrategy {
+        match value {
+            "ORIGINAL_VALUE" => SlotValueSelectionStrategy::OriginalValue,
+            "TOP_RESOLUTION" => SlotValueSelectionStrategy::TopResolution,
+            _ => SlotValueSelectionStrategy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SlotValueSelectionStrategy {
+    fn from(value: String) -> SlotValueSelectionStrategy {
+        match value.as_str() {
+            "ORIGINAL_VALUE" => SlotValueSelectionStrategy::OriginalValue,
+            "TOP_RESOLUTION" => SlotValueSelectionStrategy::TopResolution,
+            _ => SlotValueSelectionStrategy::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SlotValueSelectionStrategy {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SlotValueSelectionStrategy, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SlotValueSelectionStrategy {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SlotValueSelectionStrategy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SlotValueSelectionStrategy {
+    fn deserialize<D>(deserializer: D) -> Result<SlotValueSelectionStrategy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct StartImportRequest {
     /// <p><p>Specifies the action that the <code>StartImport</code> operation should take when there is an existing resource with the same name.</p> <ul> <li> <p>FAIL<em>ON</em>CONFLICT - The import operation is stopped on the first conflict between a resource in the import file and an existing resource. The name of the resource causing the conflict is in the <code>failureReason</code> field of the response to the <code>GetImport</code> operation.</p> <p>OVERWRITE_LATEST - The import operation proceeds even if there is a conflict with an existing resource. The $LASTEST version of the existing resource is overwritten with the data from the import file.</p> </li> </ul></p>
     #[serde(rename = "mergeStrategy")]
-    pub merge_strategy: String,
+    pub merge_strategy: MergeStrategy,
     /// <p>A zip archive in binary format. The archive should contain one file, a JSON file containing the resource to import. The resource should match the type specified in the <code>resourceType</code> field.</p>
     #[serde(rename = "payload")]
     #[serde(
@@ -1899,7 +3745,7 @@ pub struct StartImportRequest {
     pub payload: bytes::Bytes,
     /// <p><p>Specifies the type of resource to export. Each resource also exports any resources that it depends on. </p> <ul> <li> <p>A bot exports dependent intents.</p> </li> <li> <p>An intent exports dependent slot types.</p> </li> </ul></p>
     #[serde(rename = "resourceType")]
-    pub resource_type: String,
+    pub resource_type: ResourceType,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1907,8 +3753,13 @@ pub struct StartImportRequest {
 pub struct StartImportResponse {
     /// <p>A timestamp for the date and time that the import job was requested.</p>
     #[serde(rename = "createdDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The identifier for the specific import job.</p>
     #[serde(rename = "importId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1916,11 +3767,11 @@ pub struct StartImportResponse {
     /// <p>The status of the import job. If the status is <code>FAILED</code>, you can get the reason for the failure using the <code>GetImport</code> operation.</p>
     #[serde(rename = "importStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub import_status: Option<String>,
+    pub import_status: Option<ImportStatus>,
     /// <p>The action to take when there is a merge conflict.</p>
     #[serde(rename = "mergeStrategy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub merge_strategy: Option<String>,
+    pub merge_strategy: Option<MergeStrategy>,
     /// <p>The name given to the import job.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1928,7 +3779,7 @@ pub struct StartImportResponse {
     /// <p>The type of resource to import.</p>
     #[serde(rename = "resourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ResourceType>,
 }
 
 /// <p>A collection of messages that convey information to the user. At runtime, Amazon Lex selects the message to convey. </p>
@@ -1943,6 +3794,207 @@ pub struct Statement {
     pub response_card: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Status {
+    /// `BUILDING`
+    Building,
+    /// `READY`
+    Ready,
+    /// `READY_BASIC_TESTING`
+    ReadyBasicTesting,
+    /// `FAILED`
+    Failed,
+    /// `NOT_BUILT`
+    NotBuilt,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Status {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Status::Building => "BUILDING",
+            Status::Ready => "READY",
+            Status::ReadyBasicTesting => "READY_BASIC_TESTING",
+            Status::Failed => "FAILED",
+            Status::NotBuilt => "NOT_BUILT",
+            Status::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Status {
+    fn default() -> Status {
+        Status::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Status {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Status {
+    fn from(value: &'a str) -> Status {
+        match value {
+            "BUILDING" => Status::Building,
+            "READY" => Status::Ready,
+            "READY_BASIC_TESTING" => Status::ReadyBasicTesting,
+            "FAILED" => Status::Failed,
+            "NOT_BUILT" => Status::NotBuilt,
+            _ => Status::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Status {
+    fn from(value: String) -> Status {
+        match value.as_str() {
+            "BUILDING" => Status::Building,
+            "READY" => Status::Ready,
+            "READY_BASIC_TESTING" => Status::ReadyBasicTesting,
+            "FAILED" => Status::Failed,
+            "NOT_BUILT" => Status::NotBuilt,
+            _ => Status::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Status {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Status, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Status {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Status {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Status {
+    fn deserialize<D>(deserializer: D) -> Result<Status, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StatusType {
+    /// `Detected`
+    Detected,
+    /// `Missed`
+    Missed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl StatusType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StatusType::Detected => "Detected",
+            StatusType::Missed => "Missed",
+            StatusType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for StatusType {
+    fn default() -> StatusType {
+        StatusType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for StatusType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for StatusType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for StatusType {
+    fn from(value: &'a str) -> StatusType {
+        match value {
+            "Detected" => StatusType::Detected,
+            "Missed" => StatusType::Missed,
+            _ => StatusType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StatusType {
+    fn from(value: String) -> StatusType {
+        match value.as_str() {
+            "Detected" => StatusType::Detected,
+            "Missed" => StatusType::Missed,
+            _ => StatusType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for StatusType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<StatusType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StatusType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for StatusType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StatusType {
+    fn deserialize<D>(deserializer: D) -> Result<StatusType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Provides information about a single utterance that was made to your bot. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1957,12 +4009,22 @@ pub struct UtteranceData {
     pub distinct_users: Option<i64>,
     /// <p>The date that the utterance was first recorded.</p>
     #[serde(rename = "firstUtteredDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub first_uttered_date: Option<f64>,
+    pub first_uttered_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The date that the utterance was last recorded.</p>
     #[serde(rename = "lastUtteredDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_uttered_date: Option<f64>,
+    pub last_uttered_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The text that was entered by the user or the text representation of an audio clip.</p>
     #[serde(rename = "utteranceString")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4077,11 +6139,15 @@ pub struct LexModelsClient {
 impl LexModelsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> LexModelsClient {
         LexModelsClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(
+                region,
+                "Lex Model Building Service",
+            ),
         }
     }
 
@@ -4115,6 +6181,7 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/bots/{name}/versions", name = input.name);
 
         let mut request = SignedRequest::new("POST", "lex", &self.region, &request_uri);
+        request.set_operation("CreateBotVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4146,6 +6213,7 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/intents/{name}/versions", name = input.name);
 
         let mut request = SignedRequest::new("POST", "lex", &self.region, &request_uri);
+        request.set_operation("CreateIntentVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4177,6 +6245,7 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/slottypes/{name}/versions", name = input.name);
 
         let mut request = SignedRequest::new("POST", "lex", &self.region, &request_uri);
+        request.set_operation("CreateSlotTypeVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4205,6 +6274,7 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/bots/{name}", name = input.name);
 
         let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
+        request.set_operation("DeleteBot");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4237,6 +6307,7 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
+        request.set_operation("DeleteBotAlias");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4270,6 +6341,7 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
+        request.set_operation("DeleteBotChannelAssociation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4302,6 +6374,7 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
+        request.set_operation("DeleteBotVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4330,6 +6403,7 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/intents/{name}", name = input.name);
 
         let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
+        request.set_operation("DeleteIntent");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4362,6 +6436,7 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
+        request.set_operation("DeleteIntentVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4390,6 +6465,7 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/slottypes/{name}", name = input.name);
 
         let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
+        request.set_operation("DeleteSlotType");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4422,6 +6498,7 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
+        request.set_operation("DeleteSlotTypeVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4454,6 +6531,7 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
+        request.set_operation("DeleteUtterances");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4486,6 +6564,7 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetBot");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4519,6 +6598,7 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetBotAlias");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4548,6 +6628,7 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/bots/{bot_name}/aliases/", bot_name = input.bot_name);
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetBotAliases");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4594,6 +6675,7 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetBotChannelAssociation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4628,6 +6710,7 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetBotChannelAssociations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4669,6 +6752,7 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/bots/{name}/versions/", name = input.name);
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetBotVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4707,6 +6791,7 @@ impl LexModels for LexModelsClient {
         let request_uri = "/bots/";
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetBots");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4748,6 +6833,7 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/builtins/intents/{signature}", signature = input.signature);
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetBuiltinIntent");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4777,6 +6863,7 @@ impl LexModels for LexModelsClient {
         let request_uri = "/builtins/intents/";
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetBuiltinIntents");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4821,6 +6908,7 @@ impl LexModels for LexModelsClient {
         let request_uri = "/builtins/slottypes/";
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetBuiltinSlotTypes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4865,6 +6953,7 @@ impl LexModels for LexModelsClient {
         let request_uri = "/exports/";
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetExport");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4901,6 +6990,7 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/imports/{import_id}", import_id = input.import_id);
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetImport");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4934,6 +7024,7 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetIntent");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4963,6 +7054,7 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/intents/{name}/versions/", name = input.name);
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetIntentVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5001,6 +7093,7 @@ impl LexModels for LexModelsClient {
         let request_uri = "/intents/";
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetIntents");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5046,6 +7139,7 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetSlotType");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5075,6 +7169,7 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/slottypes/{name}/versions/", name = input.name);
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetSlotTypeVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5113,6 +7208,7 @@ impl LexModels for LexModelsClient {
         let request_uri = "/slottypes/";
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetSlotTypes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5154,6 +7250,7 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/bots/{botname}/utterances", botname = input.bot_name);
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetUtterancesView");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5191,6 +7288,7 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/bots/{name}/versions/$LATEST", name = input.name);
 
         let mut request = SignedRequest::new("PUT", "lex", &self.region, &request_uri);
+        request.set_operation("PutBot");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5226,6 +7324,7 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("PUT", "lex", &self.region, &request_uri);
+        request.set_operation("PutBotAlias");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5257,6 +7356,7 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/intents/{name}/versions/$LATEST", name = input.name);
 
         let mut request = SignedRequest::new("PUT", "lex", &self.region, &request_uri);
+        request.set_operation("PutIntent");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5288,6 +7388,7 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/slottypes/{name}/versions/$LATEST", name = input.name);
 
         let mut request = SignedRequest::new("PUT", "lex", &self.region, &request_uri);
+        request.set_operation("PutSlotType");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5319,6 +7420,7 @@ impl LexModels for LexModelsClient {
         let request_uri = "/imports/";
 
         let mut request = SignedRequest::new("POST", "lex", &self.region, &request_uri);
+        request.set_operation("StartImport");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5342,3 +7444,324 @@ impl LexModels for LexModelsClient {
         }
     }
 }
+impl LexModelsClient {
+    /// Auto-paginating version of `get_bot_aliases`, streaming every page of results.
+    pub fn get_bot_aliases_pages(
+        &self,
+        input: GetBotAliasesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetBotAliasesResponse, GetBotAliasesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LexModelsClient, input: GetBotAliasesRequest| async move {
+                client.get_bot_aliases(input).await
+            },
+            |input: &GetBotAliasesRequest, output: &GetBotAliasesResponse| {
+                Some(GetBotAliasesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_bot_aliases`, streaming the `BotAliases` of every page of results.
+    pub fn get_bot_aliases_items(
+        &self,
+        input: GetBotAliasesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<BotAliasMetadata, GetBotAliasesError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_bot_aliases_pages(input),
+            |output: GetBotAliasesResponse| output.bot_aliases.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_bot_channel_associations`, streaming every page of results.
+    pub fn get_bot_channel_associations_pages(
+        &self,
+        input: GetBotChannelAssociationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        GetBotChannelAssociationsResponse,
+        GetBotChannelAssociationsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LexModelsClient, input: GetBotChannelAssociationsRequest| async move {
+                client.get_bot_channel_associations(input).await
+            },
+            |input: &GetBotChannelAssociationsRequest,
+             output: &GetBotChannelAssociationsResponse| {
+                Some(GetBotChannelAssociationsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_bot_channel_associations`, streaming the `botChannelAssociations` of every page of results.
+    pub fn get_bot_channel_associations_items(
+        &self,
+        input: GetBotChannelAssociationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<BotChannelAssociation, GetBotChannelAssociationsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.get_bot_channel_associations_pages(input),
+            |output: GetBotChannelAssociationsResponse| {
+                output.bot_channel_associations.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_bot_versions`, streaming every page of results.
+    pub fn get_bot_versions_pages(
+        &self,
+        input: GetBotVersionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetBotVersionsResponse, GetBotVersionsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LexModelsClient, input: GetBotVersionsRequest| async move {
+                client.get_bot_versions(input).await
+            },
+            |input: &GetBotVersionsRequest, output: &GetBotVersionsResponse| {
+                Some(GetBotVersionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_bot_versions`, streaming the `bots` of every page of results.
+    pub fn get_bot_versions_items(
+        &self,
+        input: GetBotVersionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<BotMetadata, GetBotVersionsError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_bot_versions_pages(input),
+            |output: GetBotVersionsResponse| output.bots.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_bots`, streaming every page of results.
+    pub fn get_bots_pages(
+        &self,
+        input: GetBotsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetBotsResponse, GetBotsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LexModelsClient, input: GetBotsRequest| async move { client.get_bots(input).await },
+            |input: &GetBotsRequest, output: &GetBotsResponse| {
+                Some(GetBotsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_bots`, streaming the `bots` of every page of results.
+    pub fn get_bots_items(
+        &self,
+        input: GetBotsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<BotMetadata, GetBotsError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_bots_pages(input),
+            |output: GetBotsResponse| output.bots.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_builtin_intents`, streaming every page of results.
+    pub fn get_builtin_intents_pages(
+        &self,
+        input: GetBuiltinIntentsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetBuiltinIntentsResponse, GetBuiltinIntentsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LexModelsClient, input: GetBuiltinIntentsRequest| async move {
+                client.get_builtin_intents(input).await
+            },
+            |input: &GetBuiltinIntentsRequest, output: &GetBuiltinIntentsResponse| {
+                Some(GetBuiltinIntentsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_builtin_intents`, streaming the `intents` of every page of results.
+    pub fn get_builtin_intents_items(
+        &self,
+        input: GetBuiltinIntentsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<BuiltinIntentMetadata, GetBuiltinIntentsError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_builtin_intents_pages(input),
+            |output: GetBuiltinIntentsResponse| output.intents.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_builtin_slot_types`, streaming every page of results.
+    pub fn get_builtin_slot_types_pages(
+        &self,
+        input: GetBuiltinSlotTypesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetBuiltinSlotTypesResponse, GetBuiltinSlotTypesError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LexModelsClient, input: GetBuiltinSlotTypesRequest| async move {
+                client.get_builtin_slot_types(input).await
+            },
+            |input: &GetBuiltinSlotTypesRequest, output: &GetBuiltinSlotTypesResponse| {
+                Some(GetBuiltinSlotTypesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_builtin_slot_types`, streaming the `slotTypes` of every page of results.
+    pub fn get_builtin_slot_types_items(
+        &self,
+        input: GetBuiltinSlotTypesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<BuiltinSlotTypeMetadata, GetBuiltinSlotTypesError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.get_builtin_slot_types_pages(input),
+            |output: GetBuiltinSlotTypesResponse| output.slot_types.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_intent_versions`, streaming every page of results.
+    pub fn get_intent_versions_pages(
+        &self,
+        input: GetIntentVersionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetIntentVersionsResponse, GetIntentVersionsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LexModelsClient, input: GetIntentVersionsRequest| async move {
+                client.get_intent_versions(input).await
+            },
+            |input: &GetIntentVersionsRequest, output: &GetIntentVersionsResponse| {
+                Some(GetIntentVersionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_intent_versions`, streaming the `intents` of every page of results.
+    pub fn get_intent_versions_items(
+        &self,
+        input: GetIntentVersionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<IntentMetadata, GetIntentVersionsError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_intent_versions_pages(input),
+            |output: GetIntentVersionsResponse| output.intents.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_intents`, streaming every page of results.
+    pub fn get_intents_pages(
+        &self,
+        input: GetIntentsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetIntentsResponse, GetIntentsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LexModelsClient, input: GetIntentsRequest| async move {
+                client.get_intents(input).await
+            },
+            |input: &GetIntentsRequest, output: &GetIntentsResponse| {
+                Some(GetIntentsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_intents`, streaming the `intents` of every page of results.
+    pub fn get_intents_items(
+        &self,
+        input: GetIntentsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<IntentMetadata, GetIntentsError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_intents_pages(input),
+            |output: GetIntentsResponse| output.intents.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_slot_type_versions`, streaming every page of results.
+    pub fn get_slot_type_versions_pages(
+        &self,
+        input: GetSlotTypeVersionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetSlotTypeVersionsResponse, GetSlotTypeVersionsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LexModelsClient, input: GetSlotTypeVersionsRequest| async move {
+                client.get_slot_type_versions(input).await
+            },
+            |input: &GetSlotTypeVersionsRequest, output: &GetSlotTypeVersionsResponse| {
+                Some(GetSlotTypeVersionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_slot_type_versions`, streaming the `slotTypes` of every page of results.
+    pub fn get_slot_type_versions_items(
+        &self,
+        input: GetSlotTypeVersionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<SlotTypeMetadata, GetSlotTypeVersionsError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_slot_type_versions_pages(input),
+            |output: GetSlotTypeVersionsResponse| output.slot_types.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_slot_types`, streaming every page of results.
+    pub fn get_slot_types_pages(
+        &self,
+        input: GetSlotTypesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetSlotTypesResponse, GetSlotTypesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LexModelsClient, input: GetSlotTypesRequest| async move {
+                client.get_slot_types(input).await
+            },
+            |input: &GetSlotTypesRequest, output: &GetSlotTypesResponse| {
+                Some(GetSlotTypesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_slot_types`, streaming the `slotTypes` of every page of results.
+    pub fn get_slot_types_items(
+        &self,
+        input: GetSlotTypesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<SlotTypeMetadata, GetSlotTypesError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_slot_types_pages(input),
+            |output: GetSlotTypesResponse| output.slot_types.unwrap_or_default(),
+        )
+    }
+}
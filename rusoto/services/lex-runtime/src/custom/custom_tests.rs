@@ -1,6 +1,8 @@
 extern crate rusoto_mock;
 
-use crate::generated::{LexRuntime, LexRuntimeClient, PostTextRequest, PostTextResponse};
+use crate::generated::{
+    DialogState, LexRuntime, LexRuntimeClient, MessageFormatType, PostTextRequest, PostTextResponse,
+};
 use rusoto_core::Region;
 use std::collections::HashMap;
 
@@ -37,10 +39,10 @@ async fn test_post_text_resposnse_serialization() {
     slots.insert("PickUpCity".to_owned(), Some("Boston".to_owned()));
 
     let expected = PostTextResponse {
-        dialog_state: Some("ElicitSlot".to_owned()),
+        dialog_state: Some(DialogState::ElicitSlot),
         intent_name: Some("BookCar".to_owned()),
         message: Some("In what city do you need to rent a car?".to_owned()),
-        message_format: Some("PlainText".to_owned()),
+        message_format: Some(MessageFormatType::PlainText),
         slot_to_elicit: Some("PickUpCity".to_owned()),
         slots: Some(slots),
         response_card: None,
@@ -37,6 +37,198 @@ pub struct Button {
     pub value: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ConfirmationStatus {
+    /// `None`
+    None,
+    /// `Confirmed`
+    Confirmed,
+    /// `Denied`
+    Denied,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ConfirmationStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ConfirmationStatus::None => "None",
+            ConfirmationStatus::Confirmed => "Confirmed",
+            ConfirmationStatus::Denied => "Denied",
+            ConfirmationStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ConfirmationStatus {
+    fn default() -> ConfirmationStatus {
+        ConfirmationStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ConfirmationStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ConfirmationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ConfirmationStatus {
+    fn from(value: &'a str) -> ConfirmationStatus {
+        match value {
+            "None" => ConfirmationStatus::None,
+            "Confirmed" => ConfirmationStatus::Confirmed,
+            "Denied" => ConfirmationStatus::Denied,
+            _ => ConfirmationStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ConfirmationStatus {
+    fn from(value: String) -> ConfirmationStatus {
+        match value.as_str() {
+            "None" => ConfirmationStatus::None,
+            "Confirmed" => ConfirmationStatus::Confirmed,
+            "Denied" => ConfirmationStatus::Denied,
+            _ => ConfirmationStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ConfirmationStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ConfirmationStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ConfirmationStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ConfirmationStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ConfirmationStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ConfirmationStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ContentType {
+    /// `application/vnd.amazonaws.card.generic`
+    ApplicationVndAmazonawsCardGeneric,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ContentType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ContentType::ApplicationVndAmazonawsCardGeneric => {
+                "application/vnd.amazonaws.card.generic"
+            }
+            ContentType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ContentType {
+    fn default() -> ContentType {
+        ContentType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ContentType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ContentType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ContentType {
+    fn from(value: &'a str) -> ContentType {
+        match value {
+            "application/vnd.amazonaws.card.generic" => {
+                ContentType::ApplicationVndAmazonawsCardGeneric
+            }
+            _ => ContentType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ContentType {
+    fn from(value: String) -> ContentType {
+        match value.as_str() {
+            "application/vnd.amazonaws.card.generic" => {
+                ContentType::ApplicationVndAmazonawsCardGeneric
+            }
+            _ => ContentType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ContentType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ContentType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ContentType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ContentType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ContentType {
+    fn deserialize<D>(deserializer: D) -> Result<ContentType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct DeleteSessionRequest {
@@ -78,7 +270,7 @@ pub struct DialogAction {
     /// <p><p>The fulfillment state of the intent. The possible values are:</p> <ul> <li> <p> <code>Failed</code> - The Lambda function associated with the intent failed to fulfill the intent.</p> </li> <li> <p> <code>Fulfilled</code> - The intent has fulfilled by the Lambda function associated with the intent. </p> </li> <li> <p> <code>ReadyForFulfillment</code> - All of the information necessary for the intent is present and the intent ready to be fulfilled by the client application.</p> </li> </ul></p>
     #[serde(rename = "fulfillmentState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub fulfillment_state: Option<String>,
+    pub fulfillment_state: Option<FulfillmentState>,
     /// <p>The name of the intent.</p>
     #[serde(rename = "intentName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -90,7 +282,7 @@ pub struct DialogAction {
     /// <ul> <li> <p> <code>PlainText</code> - The message contains plain UTF-8 text.</p> </li> <li> <p> <code>CustomPayload</code> - The message is a custom format for the client.</p> </li> <li> <p> <code>SSML</code> - The message contains text formatted for voice output.</p> </li> <li> <p> <code>Composite</code> - The message contains an escaped JSON object containing one or more messages. For more information, see <a href="https://docs.aws.amazon.com/lex/latest/dg/howitworks-manage-prompts.html">Message Groups</a>. </p> </li> </ul>
     #[serde(rename = "messageFormat")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub message_format: Option<String>,
+    pub message_format: Option<MessageFormatType>,
     /// <p>The name of the slot that should be elicited from the user.</p>
     #[serde(rename = "slotToElicit")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -101,9 +293,328 @@ pub struct DialogAction {
     pub slots: Option<::std::collections::HashMap<String, String>>,
     /// <p><p>The next action that the bot should take in its interaction with the user. The possible values are:</p> <ul> <li> <p> <code>ConfirmIntent</code> - The next action is asking the user if the intent is complete and ready to be fulfilled. This is a yes/no question such as &quot;Place the order?&quot;</p> </li> <li> <p> <code>Close</code> - Indicates that the there will not be a response from the user. For example, the statement &quot;Your order has been placed&quot; does not require a response.</p> </li> <li> <p> <code>Delegate</code> - The next action is determined by Amazon Lex.</p> </li> <li> <p> <code>ElicitIntent</code> - The next action is to determine the intent that the user wants to fulfill.</p> </li> <li> <p> <code>ElicitSlot</code> - The next action is to elicit a slot value from the user.</p> </li> </ul></p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: DialogActionType,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DialogActionType {
+    /// `ElicitIntent`
+    ElicitIntent,
+    /// `ConfirmIntent`
+    ConfirmIntent,
+    /// `ElicitSlot`
+    ElicitSlot,
+    /// `Close`
+    Close,
+    /// `Delegate`
+    Delegate,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DialogActionType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DialogActionType::ElicitIntent => "ElicitIntent",
+            DialogActionType::ConfirmIntent => "ConfirmIntent",
+            DialogActionType::ElicitSlot => "ElicitSlot",
+            DialogActionType::Close => "Close",
+            DialogActionType::Delegate => "Delegate",
+            DialogActionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DialogActionType {
+    fn default() -> DialogActionType {
+        DialogActionType::Unknown(String::new())
+    }
 }
 
+impl AsRef<str> for DialogActionType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DialogActionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DialogActionType {
+    fn from(value: &'a str) -> DialogActionType {
+        match value {
+            "ElicitIntent" => DialogActionType::ElicitIntent,
+            "ConfirmIntent" => DialogActionType::ConfirmIntent,
+            "ElicitSlot" => DialogActionType::ElicitSlot,
+            "Close" => DialogActionType::Close,
+            "Delegate" => DialogActionType::Delegate,
+            _ => DialogActionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DialogActionType {
+    fn from(value: String) -> DialogActionType {
+        match value.as_str() {
+            "ElicitIntent" => DialogActionType::ElicitIntent,
+            "ConfirmIntent" => DialogActionType::ConfirmIntent,
+            "ElicitSlot" => DialogActionType::ElicitSlot,
+            "Close" => DialogActionType::Close,
+            "Delegate" => DialogActionType::Delegate,
+            _ => DialogActionType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DialogActionType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DialogActionType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DialogActionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DialogActionType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DialogActionType {
+    fn deserialize<D>(deserializer: D) -> Result<DialogActionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DialogState {
+    /// `ElicitIntent`
+    ElicitIntent,
+    /// `ConfirmIntent`
+    ConfirmIntent,
+    /// `ElicitSlot`
+    ElicitSlot,
+    /// `Fulfilled`
+    Fulfilled,
+    /// `ReadyForFulfillment`
+    ReadyForFulfillment,
+    /// `Failed`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DialogState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DialogState::ElicitIntent => "ElicitIntent",
+            DialogState::ConfirmIntent => "ConfirmIntent",
+            DialogState::ElicitSlot => "ElicitSlot",
+            DialogState::Fulfilled => "Fulfilled",
+            DialogState::ReadyForFulfillment => "ReadyForFulfillment",
+            DialogState::Failed => "Failed",
+            DialogState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DialogState {
+    fn default() -> DialogState {
+        DialogState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DialogState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DialogState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DialogState {
+    fn from(value: &'a str) -> DialogState {
+        match value {
+            "ElicitIntent" => DialogState::ElicitIntent,
+            "ConfirmIntent" => DialogState::ConfirmIntent,
+            "ElicitSlot" => DialogState::ElicitSlot,
+            "Fulfilled" => DialogState::Fulfilled,
+            "ReadyForFulfillment" => DialogState::ReadyForFulfillment,
+            "Failed" => DialogState::Failed,
+            _ => DialogState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DialogState {
+    fn from(value: String) -> DialogState {
+        match value.as_str() {
+            "ElicitIntent" => DialogState::ElicitIntent,
+            "ConfirmIntent" => DialogState::ConfirmIntent,
+            "ElicitSlot" => DialogState::ElicitSlot,
+            "Fulfilled" => DialogState::Fulfilled,
+            "ReadyForFulfillment" => DialogState::ReadyForFulfillment,
+            "Failed" => DialogState::Failed,
+            _ => DialogState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DialogState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DialogState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DialogState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DialogState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DialogState {
+    fn deserialize<D>(deserializer: D) -> Result<DialogState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FulfillmentState {
+    /// `Fulfilled`
+    Fulfilled,
+    /// `Failed`
+    Failed,
+    /// `ReadyForFulfillment`
+    ReadyForFulfillment,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl FulfillmentState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FulfillmentState::Fulfilled => "Fulfilled",
+            FulfillmentState::Failed => "Failed",
+            FulfillmentState::ReadyForFulfillment => "ReadyForFulfillment",
+            FulfillmentState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for FulfillmentState {
+    fn default() -> FulfillmentState {
+        FulfillmentState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for FulfillmentState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FulfillmentState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FulfillmentState {
+    fn from(value: &'a str) -> FulfillmentState {
+        match value {
+            "Fulfilled" => FulfillmentState::Fulfilled,
+            "Failed" => FulfillmentState::Failed,
+            "ReadyForFulfillment" => FulfillmentState::ReadyForFulfillment,
+            _ => FulfillmentState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FulfillmentState {
+    fn from(value: String) -> FulfillmentState {
+        match value.as_str() {
+            "Fulfilled" => FulfillmentState::Fulfilled,
+            "Failed" => FulfillmentState::Failed,
+            "ReadyForFulfillment" => FulfillmentState::ReadyForFulfillment,
+            _ => FulfillmentState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for FulfillmentState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<FulfillmentState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FulfillmentState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FulfillmentState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FulfillmentState {
+    fn deserialize<D>(deserializer: D) -> Result<FulfillmentState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents an option rendered to the user when a prompt is shown. It could be an image, a button, a link, or text. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -179,14 +690,14 @@ pub struct IntentSummary {
     /// <p><p>The status of the intent after the user responds to the confirmation prompt. If the user confirms the intent, Amazon Lex sets this field to <code>Confirmed</code>. If the user denies the intent, Amazon Lex sets this value to <code>Denied</code>. The possible values are:</p> <ul> <li> <p> <code>Confirmed</code> - The user has responded &quot;Yes&quot; to the confirmation prompt, confirming that the intent is complete and that it is ready to be fulfilled.</p> </li> <li> <p> <code>Denied</code> - The user has responded &quot;No&quot; to the confirmation prompt.</p> </li> <li> <p> <code>None</code> - The user has never been prompted for confirmation; or, the user was prompted but did not confirm or deny the prompt.</p> </li> </ul></p>
     #[serde(rename = "confirmationStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub confirmation_status: Option<String>,
+    pub confirmation_status: Option<ConfirmationStatus>,
     /// <p><p>The next action that the bot should take in its interaction with the user. The possible values are:</p> <ul> <li> <p> <code>ConfirmIntent</code> - The next action is asking the user if the intent is complete and ready to be fulfilled. This is a yes/no question such as &quot;Place the order?&quot;</p> </li> <li> <p> <code>Close</code> - Indicates that the there will not be a response from the user. For example, the statement &quot;Your order has been placed&quot; does not require a response.</p> </li> <li> <p> <code>ElicitIntent</code> - The next action is to determine the intent that the user wants to fulfill.</p> </li> <li> <p> <code>ElicitSlot</code> - The next action is to elicit a slot value from the user.</p> </li> </ul></p>
     #[serde(rename = "dialogActionType")]
-    pub dialog_action_type: String,
+    pub dialog_action_type: DialogActionType,
     /// <p><p>The fulfillment state of the intent. The possible values are:</p> <ul> <li> <p> <code>Failed</code> - The Lambda function associated with the intent failed to fulfill the intent.</p> </li> <li> <p> <code>Fulfilled</code> - The intent has fulfilled by the Lambda function associated with the intent. </p> </li> <li> <p> <code>ReadyForFulfillment</code> - All of the information necessary for the intent is present and the intent ready to be fulfilled by the client application.</p> </li> </ul></p>
     #[serde(rename = "fulfillmentState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub fulfillment_state: Option<String>,
+    pub fulfillment_state: Option<FulfillmentState>,
     /// <p>The name of the intent.</p>
     #[serde(rename = "intentName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -201,6 +712,109 @@ pub struct IntentSummary {
     pub slots: Option<::std::collections::HashMap<String, String>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MessageFormatType {
+    /// `PlainText`
+    PlainText,
+    /// `CustomPayload`
+    CustomPayload,
+    /// `SSML`
+    Ssml,
+    /// `Composite`
+    Composite,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl MessageFormatType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MessageFormatType::PlainText => "PlainText",
+            MessageFormatType::CustomPayload => "CustomPayload",
+            MessageFormatType::Ssml => "SSML",
+            MessageFormatType::Composite => "Composite",
+            MessageFormatType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for MessageFormatType {
+    fn default() -> MessageFormatType {
+        MessageFormatType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for MessageFormatType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for MessageFormatType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for MessageFormatType {
+    fn from(value: &'a str) -> MessageFormatType {
+        match value {
+            "PlainText" => MessageFormatType::PlainText,
+            "CustomPayload" => MessageFormatType::CustomPayload,
+            "SSML" => MessageFormatType::Ssml,
+            "Composite" => MessageFormatType::Composite,
+            _ => MessageFormatType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MessageFormatType {
+    fn from(value: String) -> MessageFormatType {
+        match value.as_str() {
+            "PlainText" => MessageFormatType::PlainText,
+            "CustomPayload" => MessageFormatType::CustomPayload,
+            "SSML" => MessageFormatType::Ssml,
+            "Composite" => MessageFormatType::Composite,
+            _ => MessageFormatType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for MessageFormatType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<MessageFormatType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MessageFormatType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for MessageFormatType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MessageFormatType {
+    fn deserialize<D>(deserializer: D) -> Result<MessageFormatType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct PostContentRequest {
@@ -245,7 +859,7 @@ pub struct PostContentResponse {
     /// <p>Content type as specified in the <code>Accept</code> HTTP header in the request.</p>
     pub content_type: Option<String>,
     /// <p><p>Identifies the current state of the user interaction. Amazon Lex returns one of the following values as <code>dialogState</code>. The client can optionally use this information to customize the user interface. </p> <ul> <li> <p> <code>ElicitIntent</code> - Amazon Lex wants to elicit the user&#39;s intent. Consider the following examples: </p> <p> For example, a user might utter an intent (&quot;I want to order a pizza&quot;). If Amazon Lex cannot infer the user intent from this utterance, it will return this dialog state. </p> </li> <li> <p> <code>ConfirmIntent</code> - Amazon Lex is expecting a &quot;yes&quot; or &quot;no&quot; response. </p> <p>For example, Amazon Lex wants user confirmation before fulfilling an intent. Instead of a simple &quot;yes&quot; or &quot;no&quot; response, a user might respond with additional information. For example, &quot;yes, but make it a thick crust pizza&quot; or &quot;no, I want to order a drink.&quot; Amazon Lex can process such additional information (in these examples, update the crust type slot or change the intent from OrderPizza to OrderDrink). </p> </li> <li> <p> <code>ElicitSlot</code> - Amazon Lex is expecting the value of a slot for the current intent. </p> <p> For example, suppose that in the response Amazon Lex sends this message: &quot;What size pizza would you like?&quot;. A user might reply with the slot value (e.g., &quot;medium&quot;). The user might also provide additional information in the response (e.g., &quot;medium thick crust pizza&quot;). Amazon Lex can process such additional information appropriately. </p> </li> <li> <p> <code>Fulfilled</code> - Conveys that the Lambda function has successfully fulfilled the intent. </p> </li> <li> <p> <code>ReadyForFulfillment</code> - Conveys that the client has to fulfill the request. </p> </li> <li> <p> <code>Failed</code> - Conveys that the conversation with the user failed. </p> <p> This can happen for various reasons, including that the user does not provide an appropriate response to prompts from the service (you can configure how many times Amazon Lex can prompt a user for specific information), or if the Lambda function fails to fulfill the intent. </p> </li> </ul></p>
-    pub dialog_state: Option<String>,
+    pub dialog_state: Option<DialogState>,
     /// <p>The text used to process the request.</p> <p>If the input was an audio stream, the <code>inputTranscript</code> field contains the text extracted from the audio stream. This is the text that is actually processed to recognize intents and slot values. You can use this information to determine if Amazon Lex is correctly processing the audio that you send.</p>
     pub input_transcript: Option<String>,
     /// <p>Current user intent that Amazon Lex is aware of.</p>
@@ -253,7 +867,7 @@ pub struct PostContentResponse {
     /// <p>The message to convey to the user. The message can come from the bot's configuration or from a Lambda function.</p> <p>If the intent is not configured with a Lambda function, or if the Lambda function returned <code>Delegate</code> as the <code>dialogAction.type</code> in its response, Amazon Lex decides on the next course of action and selects an appropriate message from the bot's configuration based on the current interaction context. For example, if Amazon Lex isn't able to understand user input, it uses a clarification prompt message.</p> <p>When you create an intent you can assign messages to groups. When messages are assigned to groups Amazon Lex returns one message from each group in the response. The message field is an escaped JSON string containing the messages. For more information about the structure of the JSON string returned, see <a>msg-prompts-formats</a>.</p> <p>If the Lambda function returns a message, Amazon Lex passes it to the client in its response.</p>
     pub message: Option<String>,
     /// <p><p>The format of the response message. One of the following values:</p> <ul> <li> <p> <code>PlainText</code> - The message contains plain UTF-8 text.</p> </li> <li> <p> <code>CustomPayload</code> - The message is a custom format for the client.</p> </li> <li> <p> <code>SSML</code> - The message contains text formatted for voice output.</p> </li> <li> <p> <code>Composite</code> - The message contains an escaped JSON object containing one or more messages from the groups that messages were assigned to when the intent was created.</p> </li> </ul></p>
-    pub message_format: Option<String>,
+    pub message_format: Option<MessageFormatType>,
     /// <p>The sentiment expressed in and utterance.</p> <p>When the bot is configured to send utterances to Amazon Comprehend for sentiment analysis, this field contains the result of the analysis.</p>
     pub sentiment_response: Option<String>,
     /// <p> Map of key/value pairs representing the session-specific context information. </p>
@@ -297,7 +911,7 @@ pub struct PostTextResponse {
     /// <p><p> Identifies the current state of the user interaction. Amazon Lex returns one of the following values as <code>dialogState</code>. The client can optionally use this information to customize the user interface. </p> <ul> <li> <p> <code>ElicitIntent</code> - Amazon Lex wants to elicit user intent. </p> <p>For example, a user might utter an intent (&quot;I want to order a pizza&quot;). If Amazon Lex cannot infer the user intent from this utterance, it will return this dialogState.</p> </li> <li> <p> <code>ConfirmIntent</code> - Amazon Lex is expecting a &quot;yes&quot; or &quot;no&quot; response. </p> <p> For example, Amazon Lex wants user confirmation before fulfilling an intent. </p> <p>Instead of a simple &quot;yes&quot; or &quot;no,&quot; a user might respond with additional information. For example, &quot;yes, but make it thick crust pizza&quot; or &quot;no, I want to order a drink&quot;. Amazon Lex can process such additional information (in these examples, update the crust type slot value, or change intent from OrderPizza to OrderDrink).</p> </li> <li> <p> <code>ElicitSlot</code> - Amazon Lex is expecting a slot value for the current intent. </p> <p>For example, suppose that in the response Amazon Lex sends this message: &quot;What size pizza would you like?&quot;. A user might reply with the slot value (e.g., &quot;medium&quot;). The user might also provide additional information in the response (e.g., &quot;medium thick crust pizza&quot;). Amazon Lex can process such additional information appropriately. </p> </li> <li> <p> <code>Fulfilled</code> - Conveys that the Lambda function configured for the intent has successfully fulfilled the intent. </p> </li> <li> <p> <code>ReadyForFulfillment</code> - Conveys that the client has to fulfill the intent. </p> </li> <li> <p> <code>Failed</code> - Conveys that the conversation with the user failed. </p> <p> This can happen for various reasons including that the user did not provide an appropriate response to prompts from the service (you can configure how many times Amazon Lex can prompt a user for specific information), or the Lambda function failed to fulfill the intent. </p> </li> </ul></p>
     #[serde(rename = "dialogState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub dialog_state: Option<String>,
+    pub dialog_state: Option<DialogState>,
     /// <p>The current user intent that Amazon Lex is aware of.</p>
     #[serde(rename = "intentName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -309,7 +923,7 @@ pub struct PostTextResponse {
     /// <p><p>The format of the response message. One of the following values:</p> <ul> <li> <p> <code>PlainText</code> - The message contains plain UTF-8 text.</p> </li> <li> <p> <code>CustomPayload</code> - The message is a custom format defined by the Lambda function.</p> </li> <li> <p> <code>SSML</code> - The message contains text formatted for voice output.</p> </li> <li> <p> <code>Composite</code> - The message contains an escaped JSON object containing one or more messages from the groups that messages were assigned to when the intent was created.</p> </li> </ul></p>
     #[serde(rename = "messageFormat")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub message_format: Option<String>,
+    pub message_format: Option<MessageFormatType>,
     /// <p>Represents the options that the user has to respond to the current prompt. Response Card can come from the bot configuration (in the Amazon Lex console, choose the settings button next to a slot) or from a code hook (Lambda function). </p>
     #[serde(rename = "responseCard")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -373,13 +987,13 @@ pub struct PutSessionResponse {
     /// <p>Content type as specified in the <code>Accept</code> HTTP header in the request.</p>
     pub content_type: Option<String>,
     /// <p><p/> <ul> <li> <p> <code>ConfirmIntent</code> - Amazon Lex is expecting a &quot;yes&quot; or &quot;no&quot; response to confirm the intent before fulfilling an intent.</p> </li> <li> <p> <code>ElicitIntent</code> - Amazon Lex wants to elicit the user&#39;s intent.</p> </li> <li> <p> <code>ElicitSlot</code> - Amazon Lex is expecting the value of a slot for the current intent.</p> </li> <li> <p> <code>Failed</code> - Conveys that the conversation with the user has failed. This can happen for various reasons, including the user does not provide an appropriate response to prompts from the service, or if the Lambda function fails to fulfill the intent.</p> </li> <li> <p> <code>Fulfilled</code> - Conveys that the Lambda function has sucessfully fulfilled the intent.</p> </li> <li> <p> <code>ReadyForFulfillment</code> - Conveys that the client has to fulfill the intent.</p> </li> </ul></p>
-    pub dialog_state: Option<String>,
+    pub dialog_state: Option<DialogState>,
     /// <p>The name of the current intent.</p>
     pub intent_name: Option<String>,
     /// <p>The next message that should be presented to the user.</p>
     pub message: Option<String>,
     /// <p><p>The format of the response message. One of the following values:</p> <ul> <li> <p> <code>PlainText</code> - The message contains plain UTF-8 text.</p> </li> <li> <p> <code>CustomPayload</code> - The message is a custom format for the client.</p> </li> <li> <p> <code>SSML</code> - The message contains text formatted for voice output.</p> </li> <li> <p> <code>Composite</code> - The message contains an escaped JSON object containing one or more messages from the groups that messages were assigned to when the intent was created.</p> </li> </ul></p>
-    pub message_format: Option<String>,
+    pub message_format: Option<MessageFormatType>,
     /// <p>Map of key/value pairs representing session-specific context information.</p>
     pub session_attributes: Option<String>,
     /// <p>A unique identifier for the session.</p>
@@ -397,7 +1011,7 @@ pub struct ResponseCard {
     /// <p>The content type of the response.</p>
     #[serde(rename = "contentType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub content_type: Option<String>,
+    pub content_type: Option<ContentType>,
     /// <p>An array of attachment objects representing options.</p>
     #[serde(rename = "genericAttachments")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -801,11 +1415,12 @@ pub struct LexRuntimeClient {
 impl LexRuntimeClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> LexRuntimeClient {
         LexRuntimeClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Lex Runtime Service"),
         }
     }
 
@@ -844,6 +1459,7 @@ impl LexRuntime for LexRuntimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
+        request.set_operation("DeleteSession");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("runtime.lex".to_string());
@@ -878,6 +1494,7 @@ impl LexRuntime for LexRuntimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetSession");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("runtime.lex".to_string());
@@ -918,6 +1535,7 @@ impl LexRuntime for LexRuntimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "lex", &self.region, &request_uri);
+        request.set_operation("PostContent");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("runtime.lex".to_string());
@@ -960,7 +1578,7 @@ impl LexRuntime for LexRuntimeClient {
             };
             if let Some(dialog_state) = response.headers.get("x-amz-lex-dialog-state") {
                 let value = dialog_state.to_owned();
-                result.dialog_state = Some(value)
+                result.dialog_state = Some(value.into())
             };
             if let Some(input_transcript) = response.headers.get("x-amz-lex-input-transcript") {
                 let value = input_transcript.to_owned();
@@ -976,7 +1594,7 @@ impl LexRuntime for LexRuntimeClient {
             };
             if let Some(message_format) = response.headers.get("x-amz-lex-message-format") {
                 let value = message_format.to_owned();
-                result.message_format = Some(value)
+                result.message_format = Some(value.into())
             };
             if let Some(sentiment_response) = response.headers.get("x-amz-lex-sentiment") {
                 let value = sentiment_response.to_owned();
@@ -1019,6 +1637,7 @@ impl LexRuntime for LexRuntimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "lex", &self.region, &request_uri);
+        request.set_operation("PostText");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("runtime.lex".to_string());
@@ -1055,6 +1674,7 @@ impl LexRuntime for LexRuntimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "lex", &self.region, &request_uri);
+        request.set_operation("PutSession");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("runtime.lex".to_string());
@@ -1082,7 +1702,7 @@ impl LexRuntime for LexRuntimeClient {
             };
             if let Some(dialog_state) = response.headers.get("x-amz-lex-dialog-state") {
                 let value = dialog_state.to_owned();
-                result.dialog_state = Some(value)
+                result.dialog_state = Some(value.into())
             };
             if let Some(intent_name) = response.headers.get("x-amz-lex-intent-name") {
                 let value = intent_name.to_owned();
@@ -1094,7 +1714,7 @@ impl LexRuntime for LexRuntimeClient {
             };
             if let Some(message_format) = response.headers.get("x-amz-lex-message-format") {
                 let value = message_format.to_owned();
-                result.message_format = Some(value)
+                result.message_format = Some(value.into())
             };
             if let Some(session_attributes) = response.headers.get("x-amz-lex-session-attributes") {
                 let value = session_attributes.to_owned();
@@ -30,8 +30,13 @@ use serde_json;
 pub struct AutomatedDiscoveryInformation {
     /// <p>Time that automated discovery last ran.</p>
     #[serde(rename = "LastRunTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_run_time: Option<f64>,
+    pub last_run_time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>Details about license consumption.</p>
@@ -45,7 +50,7 @@ pub struct ConsumedLicenseSummary {
     /// <p>Resource type of the resource consuming a license.</p>
     #[serde(rename = "ResourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ResourceType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -65,7 +70,7 @@ pub struct CreateLicenseConfigurationRequest {
     pub license_count_hard_limit: Option<bool>,
     /// <p>Dimension used to track the license inventory.</p>
     #[serde(rename = "LicenseCountingType")]
-    pub license_counting_type: String,
+    pub license_counting_type: LicenseCountingType,
     /// <p><p>License rules. The syntax is #name=value (for example, #allowedTenancy=EC2-DedicatedHost). Available rules vary by dimension.</p> <ul> <li> <p> <code>Cores</code> dimension: <code>allowedTenancy</code> | <code>maximumCores</code> | <code>minimumCores</code> </p> </li> <li> <p> <code>Instances</code> dimension: <code>allowedTenancy</code> | <code>maximumCores</code> | <code>minimumCores</code> | <code>maximumSockets</code> | <code>minimumSockets</code> | <code>maximumVcpus</code> | <code>minimumVcpus</code> </p> </li> <li> <p> <code>Sockets</code> dimension: <code>allowedTenancy</code> | <code>maximumSockets</code> | <code>minimumSockets</code> </p> </li> <li> <p> <code>vCPUs</code> dimension: <code>allowedTenancy</code> | <code>honorVcpuOptimization</code> | <code>maximumVcpus</code> | <code>minimumVcpus</code> </p> </li> </ul></p>
     #[serde(rename = "LicenseRules")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -164,7 +169,7 @@ pub struct GetLicenseConfigurationResponse {
     /// <p>Dimension on which the licenses are counted.</p>
     #[serde(rename = "LicenseCountingType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub license_counting_type: Option<String>,
+    pub license_counting_type: Option<LicenseCountingType>,
     /// <p>License rules.</p>
     #[serde(rename = "LicenseRules")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -230,7 +235,7 @@ pub struct GetServiceSettingsResponse {
 pub struct InventoryFilter {
     /// <p>Condition of the filter.</p>
     #[serde(rename = "Condition")]
-    pub condition: String,
+    pub condition: InventoryFilterCondition,
     /// <p>Name of the filter.</p>
     #[serde(rename = "Name")]
     pub name: String,
@@ -240,6 +245,109 @@ pub struct InventoryFilter {
     pub value: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum InventoryFilterCondition {
+    /// `EQUALS`
+    Equals,
+    /// `NOT_EQUALS`
+    NotEquals,
+    /// `BEGINS_WITH`
+    BeginsWith,
+    /// `CONTAINS`
+    Contains,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl InventoryFilterCondition {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InventoryFilterCondition::Equals => "EQUALS",
+            InventoryFilterCondition::NotEquals => "NOT_EQUALS",
+            InventoryFilterCondition::BeginsWith => "BEGINS_WITH",
+            InventoryFilterCondition::Contains => "CONTAINS",
+            InventoryFilterCondition::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for InventoryFilterCondition {
+    fn default() -> InventoryFilterCondition {
+        InventoryFilterCondition::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for InventoryFilterCondition {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for InventoryFilterCondition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for InventoryFilterCondition {
+    fn from(value: &'a str) -> InventoryFilterCondition {
+        match value {
+            "EQUALS" => InventoryFilterCondition::Equals,
+            "NOT_EQUALS" => InventoryFilterCondition::NotEquals,
+            "BEGINS_WITH" => InventoryFilterCondition::BeginsWith,
+            "CONTAINS" => InventoryFilterCondition::Contains,
+            _ => InventoryFilterCondition::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InventoryFilterCondition {
+    fn from(value: String) -> InventoryFilterCondition {
+        match value.as_str() {
+            "EQUALS" => InventoryFilterCondition::Equals,
+            "NOT_EQUALS" => InventoryFilterCondition::NotEquals,
+            "BEGINS_WITH" => InventoryFilterCondition::BeginsWith,
+            "CONTAINS" => InventoryFilterCondition::Contains,
+            _ => InventoryFilterCondition::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for InventoryFilterCondition {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<InventoryFilterCondition, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for InventoryFilterCondition {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for InventoryFilterCondition {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InventoryFilterCondition {
+    fn deserialize<D>(deserializer: D) -> Result<InventoryFilterCondition, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A license configuration is an abstraction of a customer license agreement that can be consumed and enforced by License Manager. Components include specifications for the license type (licensing by instance, socket, CPU, or vCPU), allowed tenancy (shared tenancy, Dedicated Instance, Dedicated Host, or all of these), host affinity (how long a VM must be associated with a host), and the number of licenses purchased and used.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -279,7 +387,7 @@ pub struct LicenseConfiguration {
     /// <p>Dimension to use to track the license inventory.</p>
     #[serde(rename = "LicenseCountingType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub license_counting_type: Option<String>,
+    pub license_counting_type: Option<LicenseCountingType>,
     /// <p>License rules.</p>
     #[serde(rename = "LicenseRules")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -312,8 +420,13 @@ pub struct LicenseConfiguration {
 pub struct LicenseConfigurationAssociation {
     /// <p>Time when the license configuration was associated with the resource.</p>
     #[serde(rename = "AssociationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub association_time: Option<f64>,
+    pub association_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Amazon Resource Name (ARN) of the resource.</p>
     #[serde(rename = "ResourceArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -325,17 +438,115 @@ pub struct LicenseConfigurationAssociation {
     /// <p>Type of server resource.</p>
     #[serde(rename = "ResourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ResourceType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LicenseConfigurationStatus {
+    /// `AVAILABLE`
+    Available,
+    /// `DISABLED`
+    Disabled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LicenseConfigurationStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LicenseConfigurationStatus::Available => "AVAILABLE",
+            LicenseConfigurationStatus::Disabled => "DISABLED",
+            LicenseConfigurationStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LicenseConfigurationStatus {
+    fn default() -> LicenseConfigurationStatus {
+        LicenseConfigurationStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LicenseConfigurationStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LicenseConfigurationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LicenseConfigurationStatus {
+    fn from(value: &'a str) -> LicenseConfigurationStatus {
+        match value {
+            "AVAILABLE" => LicenseConfigurationStatus::Available,
+            "DISABLED" => LicenseConfigurationStatus::Disabled,
+            _ => LicenseConfigurationStatus::Unknown(value.to_owned()),
+        }
+    }
 }
 
+impl From<String> for LicenseConfigurationStatus {
+    fn from(value: String) -> LicenseConfigurationStatus {
+        match value.as_str() {
+            "AVAILABLE" => LicenseConfigurationStatus::Available,
+            "DISABLED" => LicenseConfigurationStatus::Disabled,
+            _ => LicenseConfigurationStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LicenseConfigurationStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LicenseConfigurationStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LicenseConfigurationStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LicenseConfigurationStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LicenseConfigurationStatus {
+    fn deserialize<D>(deserializer: D) -> Result<LicenseConfigurationStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Details about the usage of a resource associated with a license configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LicenseConfigurationUsage {
     /// <p>Time when the license configuration was initially associated with the resource.</p>
     #[serde(rename = "AssociationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub association_time: Option<f64>,
+    pub association_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Number of licenses consumed by the resource.</p>
     #[serde(rename = "ConsumedLicenses")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -355,9 +566,112 @@ pub struct LicenseConfigurationUsage {
     /// <p>Type of resource.</p>
     #[serde(rename = "ResourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ResourceType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LicenseCountingType {
+    /// `vCPU`
+    VCPU,
+    /// `Instance`
+    Instance,
+    /// `Core`
+    Core,
+    /// `Socket`
+    Socket,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LicenseCountingType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LicenseCountingType::VCPU => "vCPU",
+            LicenseCountingType::Instance => "Instance",
+            LicenseCountingType::Core => "Core",
+            LicenseCountingType::Socket => "Socket",
+            LicenseCountingType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LicenseCountingType {
+    fn default() -> LicenseCountingType {
+        LicenseCountingType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LicenseCountingType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LicenseCountingType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LicenseCountingType {
+    fn from(value: &'a str) -> LicenseCountingType {
+        match value {
+            "vCPU" => LicenseCountingType::VCPU,
+            "Instance" => LicenseCountingType::Instance,
+            "Core" => LicenseCountingType::Core,
+            "Socket" => LicenseCountingType::Socket,
+            _ => LicenseCountingType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LicenseCountingType {
+    fn from(value: String) -> LicenseCountingType {
+        match value.as_str() {
+            "vCPU" => LicenseCountingType::VCPU,
+            "Instance" => LicenseCountingType::Instance,
+            "Core" => LicenseCountingType::Core,
+            "Socket" => LicenseCountingType::Socket,
+            _ => LicenseCountingType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LicenseCountingType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LicenseCountingType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LicenseCountingType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LicenseCountingType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for LicenseCountingType {
+    fn deserialize<D>(deserializer: D) -> Result<LicenseCountingType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes the failure of a license operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -368,8 +682,13 @@ pub struct LicenseOperationFailure {
     pub error_message: Option<String>,
     /// <p>Failure time.</p>
     #[serde(rename = "FailureTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub failure_time: Option<f64>,
+    pub failure_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Reserved.</p>
     #[serde(rename = "MetadataList")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -393,7 +712,7 @@ pub struct LicenseOperationFailure {
     /// <p>Resource type.</p>
     #[serde(rename = "ResourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ResourceType>,
 }
 
 /// <p>Details for associating a license configuration with a resource.</p>
@@ -616,7 +935,7 @@ pub struct ManagedResourceSummary {
     /// <p>Type of resource associated with a license.</p>
     #[serde(rename = "ResourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ResourceType>,
 }
 
 /// <p>Reserved.</p>
@@ -693,9 +1012,117 @@ pub struct ResourceInventory {
     /// <p>Type of resource.</p>
     #[serde(rename = "ResourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ResourceType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ResourceType {
+    /// `EC2_INSTANCE`
+    Ec2Instance,
+    /// `EC2_HOST`
+    Ec2Host,
+    /// `EC2_AMI`
+    Ec2Ami,
+    /// `RDS`
+    Rds,
+    /// `SYSTEMS_MANAGER_MANAGED_INSTANCE`
+    SystemsManagerManagedInstance,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ResourceType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceType::Ec2Instance => "EC2_INSTANCE",
+            ResourceType::Ec2Host => "EC2_HOST",
+            ResourceType::Ec2Ami => "EC2_AMI",
+            ResourceType::Rds => "RDS",
+            ResourceType::SystemsManagerManagedInstance => "SYSTEMS_MANAGER_MANAGED_INSTANCE",
+            ResourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ResourceType {
+    fn default() -> ResourceType {
+        ResourceType::Unknown(String::new())
+    }
 }
 
+impl AsRef<str> for ResourceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ResourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ResourceType {
+    fn from(value: &'a str) -> ResourceType {
+        match value {
+            "EC2_INSTANCE" => ResourceType::Ec2Instance,
+            "EC2_HOST" => ResourceType::Ec2Host,
+            "EC2_AMI" => ResourceType::Ec2Ami,
+            "RDS" => ResourceType::Rds,
+            "SYSTEMS_MANAGER_MANAGED_INSTANCE" => ResourceType::SystemsManagerManagedInstance,
+            _ => ResourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceType {
+    fn from(value: String) -> ResourceType {
+        match value.as_str() {
+            "EC2_INSTANCE" => ResourceType::Ec2Instance,
+            "EC2_HOST" => ResourceType::Ec2Host,
+            "EC2_AMI" => ResourceType::Ec2Ami,
+            "RDS" => ResourceType::Rds,
+            "SYSTEMS_MANAGER_MANAGED_INSTANCE" => ResourceType::SystemsManagerManagedInstance,
+            _ => ResourceType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ResourceType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ResourceType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ResourceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ResourceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ResourceType {
+    fn deserialize<D>(deserializer: D) -> Result<ResourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Details about a tag for a license configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Tag {
@@ -752,7 +1179,7 @@ pub struct UpdateLicenseConfigurationRequest {
     /// <p>New status of the license configuration.</p>
     #[serde(rename = "LicenseConfigurationStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub license_configuration_status: Option<String>,
+    pub license_configuration_status: Option<LicenseConfigurationStatus>,
     /// <p>New number of licenses managed by the license configuration.</p>
     #[serde(rename = "LicenseCount")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2081,11 +2508,12 @@ pub struct LicenseManagerClient {
 impl LicenseManagerClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> LicenseManagerClient {
         LicenseManagerClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "License Manager"),
         }
     }
 
@@ -2118,6 +2546,7 @@ impl LicenseManager for LicenseManagerClient {
     ) -> Result<CreateLicenseConfigurationResponse, RusotoError<CreateLicenseConfigurationError>>
     {
         let mut request = SignedRequest::new("POST", "license-manager", &self.region, "/");
+        request.set_operation("CreateLicenseConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2150,6 +2579,7 @@ impl LicenseManager for LicenseManagerClient {
     ) -> Result<DeleteLicenseConfigurationResponse, RusotoError<DeleteLicenseConfigurationError>>
     {
         let mut request = SignedRequest::new("POST", "license-manager", &self.region, "/");
+        request.set_operation("DeleteLicenseConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2181,6 +2611,7 @@ impl LicenseManager for LicenseManagerClient {
         input: GetLicenseConfigurationRequest,
     ) -> Result<GetLicenseConfigurationResponse, RusotoError<GetLicenseConfigurationError>> {
         let mut request = SignedRequest::new("POST", "license-manager", &self.region, "/");
+        request.set_operation("GetLicenseConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSLicenseManager.GetLicenseConfiguration");
@@ -2208,6 +2639,7 @@ impl LicenseManager for LicenseManagerClient {
         &self,
     ) -> Result<GetServiceSettingsResponse, RusotoError<GetServiceSettingsError>> {
         let mut request = SignedRequest::new("POST", "license-manager", &self.region, "/");
+        request.set_operation("GetServiceSettings");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSLicenseManager.GetServiceSettings");
@@ -2238,6 +2670,7 @@ impl LicenseManager for LicenseManagerClient {
         RusotoError<ListAssociationsForLicenseConfigurationError>,
     > {
         let mut request = SignedRequest::new("POST", "license-manager", &self.region, "/");
+        request.set_operation("ListAssociationsForLicenseConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2274,6 +2707,7 @@ impl LicenseManager for LicenseManagerClient {
         RusotoError<ListFailuresForLicenseConfigurationOperationsError>,
     > {
         let mut request = SignedRequest::new("POST", "license-manager", &self.region, "/");
+        request.set_operation("ListFailuresForLicenseConfigurationOperations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2306,6 +2740,7 @@ impl LicenseManager for LicenseManagerClient {
     ) -> Result<ListLicenseConfigurationsResponse, RusotoError<ListLicenseConfigurationsError>>
     {
         let mut request = SignedRequest::new("POST", "license-manager", &self.region, "/");
+        request.set_operation("ListLicenseConfigurations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2340,6 +2775,7 @@ impl LicenseManager for LicenseManagerClient {
         RusotoError<ListLicenseSpecificationsForResourceError>,
     > {
         let mut request = SignedRequest::new("POST", "license-manager", &self.region, "/");
+        request.set_operation("ListLicenseSpecificationsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2373,6 +2809,7 @@ impl LicenseManager for LicenseManagerClient {
         input: ListResourceInventoryRequest,
     ) -> Result<ListResourceInventoryResponse, RusotoError<ListResourceInventoryError>> {
         let mut request = SignedRequest::new("POST", "license-manager", &self.region, "/");
+        request.set_operation("ListResourceInventory");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSLicenseManager.ListResourceInventory");
@@ -2401,6 +2838,7 @@ impl LicenseManager for LicenseManagerClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = SignedRequest::new("POST", "license-manager", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSLicenseManager.ListTagsForResource");
@@ -2432,6 +2870,7 @@ impl LicenseManager for LicenseManagerClient {
         RusotoError<ListUsageForLicenseConfigurationError>,
     > {
         let mut request = SignedRequest::new("POST", "license-manager", &self.region, "/");
+        request.set_operation("ListUsageForLicenseConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2465,6 +2904,7 @@ impl LicenseManager for LicenseManagerClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = SignedRequest::new("POST", "license-manager", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSLicenseManager.TagResource");
@@ -2492,6 +2932,7 @@ impl LicenseManager for LicenseManagerClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = SignedRequest::new("POST", "license-manager", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSLicenseManager.UntagResource");
@@ -2520,6 +2961,7 @@ impl LicenseManager for LicenseManagerClient {
     ) -> Result<UpdateLicenseConfigurationResponse, RusotoError<UpdateLicenseConfigurationError>>
     {
         let mut request = SignedRequest::new("POST", "license-manager", &self.region, "/");
+        request.set_operation("UpdateLicenseConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2554,6 +2996,7 @@ impl LicenseManager for LicenseManagerClient {
         RusotoError<UpdateLicenseSpecificationsForResourceError>,
     > {
         let mut request = SignedRequest::new("POST", "license-manager", &self.region, "/");
+        request.set_operation("UpdateLicenseSpecificationsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2587,6 +3030,7 @@ impl LicenseManager for LicenseManagerClient {
         input: UpdateServiceSettingsRequest,
     ) -> Result<UpdateServiceSettingsResponse, RusotoError<UpdateServiceSettingsError>> {
         let mut request = SignedRequest::new("POST", "license-manager", &self.region, "/");
+        request.set_operation("UpdateServiceSettings");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSLicenseManager.UpdateServiceSettings");
@@ -2609,3 +3053,203 @@ impl LicenseManager for LicenseManagerClient {
         }
     }
 }
+impl LicenseManagerClient {
+    /// Auto-paginating version of `list_associations_for_license_configuration`, streaming every page of results.
+    pub fn list_associations_for_license_configuration_pages(
+        &self,
+        input: ListAssociationsForLicenseConfigurationRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListAssociationsForLicenseConfigurationResponse,
+        ListAssociationsForLicenseConfigurationError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LicenseManagerClient,
+             input: ListAssociationsForLicenseConfigurationRequest| async move {
+                client
+                    .list_associations_for_license_configuration(input)
+                    .await
+            },
+            |input: &ListAssociationsForLicenseConfigurationRequest,
+             output: &ListAssociationsForLicenseConfigurationResponse| {
+                Some(ListAssociationsForLicenseConfigurationRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_associations_for_license_configuration`, streaming the `LicenseConfigurationAssociations` of every page of results.
+    pub fn list_associations_for_license_configuration_items(
+        &self,
+        input: ListAssociationsForLicenseConfigurationRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        LicenseConfigurationAssociation,
+        ListAssociationsForLicenseConfigurationError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_associations_for_license_configuration_pages(input),
+            |output: ListAssociationsForLicenseConfigurationResponse| {
+                output
+                    .license_configuration_associations
+                    .unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_license_configurations`, streaming every page of results.
+    pub fn list_license_configurations_pages(
+        &self,
+        input: ListLicenseConfigurationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListLicenseConfigurationsResponse,
+        ListLicenseConfigurationsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LicenseManagerClient, input: ListLicenseConfigurationsRequest| async move {
+                client.list_license_configurations(input).await
+            },
+            |input: &ListLicenseConfigurationsRequest,
+             output: &ListLicenseConfigurationsResponse| {
+                Some(ListLicenseConfigurationsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_license_configurations`, streaming the `LicenseConfigurations` of every page of results.
+    pub fn list_license_configurations_items(
+        &self,
+        input: ListLicenseConfigurationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<LicenseConfiguration, ListLicenseConfigurationsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_license_configurations_pages(input),
+            |output: ListLicenseConfigurationsResponse| {
+                output.license_configurations.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_license_specifications_for_resource`, streaming every page of results.
+    pub fn list_license_specifications_for_resource_pages(
+        &self,
+        input: ListLicenseSpecificationsForResourceRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListLicenseSpecificationsForResourceResponse,
+        ListLicenseSpecificationsForResourceError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LicenseManagerClient, input: ListLicenseSpecificationsForResourceRequest| async move {
+                client.list_license_specifications_for_resource(input).await
+            },
+            |input: &ListLicenseSpecificationsForResourceRequest,
+             output: &ListLicenseSpecificationsForResourceResponse| {
+                Some(ListLicenseSpecificationsForResourceRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_license_specifications_for_resource`, streaming the `LicenseSpecifications` of every page of results.
+    pub fn list_license_specifications_for_resource_items(
+        &self,
+        input: ListLicenseSpecificationsForResourceRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        LicenseSpecification,
+        ListLicenseSpecificationsForResourceError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_license_specifications_for_resource_pages(input),
+            |output: ListLicenseSpecificationsForResourceResponse| {
+                output.license_specifications.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_resource_inventory`, streaming every page of results.
+    pub fn list_resource_inventory_pages(
+        &self,
+        input: ListResourceInventoryRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListResourceInventoryResponse,
+        ListResourceInventoryError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LicenseManagerClient, input: ListResourceInventoryRequest| async move {
+                client.list_resource_inventory(input).await
+            },
+            |input: &ListResourceInventoryRequest, output: &ListResourceInventoryResponse| {
+                Some(ListResourceInventoryRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_resource_inventory`, streaming the `ResourceInventoryList` of every page of results.
+    pub fn list_resource_inventory_items(
+        &self,
+        input: ListResourceInventoryRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ResourceInventory, ListResourceInventoryError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_resource_inventory_pages(input),
+            |output: ListResourceInventoryResponse| {
+                output.resource_inventory_list.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_usage_for_license_configuration`, streaming every page of results.
+    pub fn list_usage_for_license_configuration_pages(
+        &self,
+        input: ListUsageForLicenseConfigurationRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListUsageForLicenseConfigurationResponse,
+        ListUsageForLicenseConfigurationError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LicenseManagerClient, input: ListUsageForLicenseConfigurationRequest| async move {
+                client.list_usage_for_license_configuration(input).await
+            },
+            |input: &ListUsageForLicenseConfigurationRequest,
+             output: &ListUsageForLicenseConfigurationResponse| {
+                Some(ListUsageForLicenseConfigurationRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_usage_for_license_configuration`, streaming the `LicenseConfigurationUsageList` of every page of results.
+    pub fn list_usage_for_license_configuration_items(
+        &self,
+        input: ListUsageForLicenseConfigurationRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        LicenseConfigurationUsage,
+        ListUsageForLicenseConfigurationError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_usage_for_license_configuration_pages(input),
+            |output: ListUsageForLicenseConfigurationResponse| {
+                output.license_configuration_usage_list.unwrap_or_default()
+            },
+        )
+    }
+}
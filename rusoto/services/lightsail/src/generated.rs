@@ -24,6 +24,99 @@ use rusoto_core::signature::SignedRequest;
 #[allow(unused_imports)]
 use serde::{Deserialize, Serialize};
 use serde_json;
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AccessDirection {
+    /// `inbound`
+    Inbound,
+    /// `outbound`
+    Outbound,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AccessDirection {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AccessDirection::Inbound => "inbound",
+            AccessDirection::Outbound => "outbound",
+            AccessDirection::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AccessDirection {
+    fn default() -> AccessDirection {
+        AccessDirection::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AccessDirection {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AccessDirection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AccessDirection {
+    fn from(value: &'a str) -> AccessDirection {
+        match value {
+            "inbound" => AccessDirection::Inbound,
+            "outbound" => AccessDirection::Outbound,
+            _ => AccessDirection::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AccessDirection {
+    fn from(value: String) -> AccessDirection {
+        match value.as_str() {
+            "inbound" => AccessDirection::Inbound,
+            "outbound" => AccessDirection::Outbound,
+            _ => AccessDirection::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AccessDirection {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AccessDirection, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AccessDirection {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AccessDirection {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AccessDirection {
+    fn deserialize<D>(deserializer: D) -> Result<AccessDirection, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes an add-on that is enabled for an Amazon Lightsail resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -52,13 +145,106 @@ pub struct AddOn {
 pub struct AddOnRequest {
     /// <p>The add-on type.</p>
     #[serde(rename = "addOnType")]
-    pub add_on_type: String,
+    pub add_on_type: AddOnType,
     /// <p>An object that represents additional parameters when enabling or modifying the automatic snapshot add-on.</p>
     #[serde(rename = "autoSnapshotAddOnRequest")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub auto_snapshot_add_on_request: Option<AutoSnapshotAddOnRequest>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AddOnType {
+    /// `AutoSnapshot`
+    AutoSnapshot,
+    /// `StopInstanceOnIdle`
+    StopInstanceOnIdle,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AddOnType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AddOnType::AutoSnapshot => "AutoSnapshot",
+            AddOnType::StopInstanceOnIdle => "StopInstanceOnIdle",
+            AddOnType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AddOnType {
+    fn default() -> AddOnType {
+        AddOnType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AddOnType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AddOnType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AddOnType {
+    fn from(value: &'a str) -> AddOnType {
+        match value {
+            "AutoSnapshot" => AddOnType::AutoSnapshot,
+            "StopInstanceOnIdle" => AddOnType::StopInstanceOnIdle,
+            _ => AddOnType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AddOnType {
+    fn from(value: String) -> AddOnType {
+        match value.as_str() {
+            "AutoSnapshot" => AddOnType::AutoSnapshot,
+            "StopInstanceOnIdle" => AddOnType::StopInstanceOnIdle,
+            _ => AddOnType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AddOnType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AddOnType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AddOnType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AddOnType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AddOnType {
+    fn deserialize<D>(deserializer: D) -> Result<AddOnType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct AllocateStaticIpRequest {
@@ -189,8 +375,13 @@ pub struct AutoSnapshotAddOnRequest {
 pub struct AutoSnapshotDetails {
     /// <p>The timestamp when the automatic snapshot was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The date of the automatic snapshot in <code>YYYY-MM-DD</code> format.</p>
     #[serde(rename = "date")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -202,9 +393,112 @@ pub struct AutoSnapshotDetails {
     /// <p>The status of the automatic snapshot.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<AutoSnapshotStatus>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AutoSnapshotStatus {
+    /// `Success`
+    Success,
+    /// `Failed`
+    Failed,
+    /// `InProgress`
+    InProgress,
+    /// `NotFound`
+    NotFound,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AutoSnapshotStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AutoSnapshotStatus::Success => "Success",
+            AutoSnapshotStatus::Failed => "Failed",
+            AutoSnapshotStatus::InProgress => "InProgress",
+            AutoSnapshotStatus::NotFound => "NotFound",
+            AutoSnapshotStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AutoSnapshotStatus {
+    fn default() -> AutoSnapshotStatus {
+        AutoSnapshotStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AutoSnapshotStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AutoSnapshotStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AutoSnapshotStatus {
+    fn from(value: &'a str) -> AutoSnapshotStatus {
+        match value {
+            "Success" => AutoSnapshotStatus::Success,
+            "Failed" => AutoSnapshotStatus::Failed,
+            "InProgress" => AutoSnapshotStatus::InProgress,
+            "NotFound" => AutoSnapshotStatus::NotFound,
+            _ => AutoSnapshotStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AutoSnapshotStatus {
+    fn from(value: String) -> AutoSnapshotStatus {
+        match value.as_str() {
+            "Success" => AutoSnapshotStatus::Success,
+            "Failed" => AutoSnapshotStatus::Failed,
+            "InProgress" => AutoSnapshotStatus::InProgress,
+            "NotFound" => AutoSnapshotStatus::NotFound,
+            _ => AutoSnapshotStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AutoSnapshotStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AutoSnapshotStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AutoSnapshotStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AutoSnapshotStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for AutoSnapshotStatus {
+    fn deserialize<D>(deserializer: D) -> Result<AutoSnapshotStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes an Availability Zone.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -254,7 +548,7 @@ pub struct Blueprint {
     /// <p>The operating system platform (either Linux/Unix-based or Windows Server-based) of the blueprint.</p>
     #[serde(rename = "platform")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub platform: Option<String>,
+    pub platform: Option<InstancePlatform>,
     /// <p>The product URL to learn more about the image or blueprint.</p>
     #[serde(rename = "productUrl")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -262,7 +556,7 @@ pub struct Blueprint {
     /// <p>The type of the blueprint (e.g., <code>os</code> or <code>app</code>).</p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<BlueprintType>,
     /// <p>The version number of the operating system, application, or stack (e.g., <code>2016.03.0</code>).</p>
     #[serde(rename = "version")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -273,6 +567,99 @@ pub struct Blueprint {
     pub version_code: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BlueprintType {
+    /// `os`
+    Os,
+    /// `app`
+    App,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl BlueprintType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BlueprintType::Os => "os",
+            BlueprintType::App => "app",
+            BlueprintType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for BlueprintType {
+    fn default() -> BlueprintType {
+        BlueprintType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for BlueprintType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for BlueprintType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for BlueprintType {
+    fn from(value: &'a str) -> BlueprintType {
+        match value {
+            "os" => BlueprintType::Os,
+            "app" => BlueprintType::App,
+            _ => BlueprintType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BlueprintType {
+    fn from(value: String) -> BlueprintType {
+        match value.as_str() {
+            "os" => BlueprintType::Os,
+            "app" => BlueprintType::App,
+            _ => BlueprintType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for BlueprintType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<BlueprintType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for BlueprintType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for BlueprintType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BlueprintType {
+    fn deserialize<D>(deserializer: D) -> Result<BlueprintType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes a bundle, which is a set of specs describing your virtual private server (or <i>instance</i>).</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -316,7 +703,7 @@ pub struct Bundle {
     /// <p>The operating system platform (Linux/Unix-based or Windows Server-based) that the bundle supports. You can only launch a <code>WINDOWS</code> bundle on a blueprint that supports the <code>WINDOWS</code> platform. <code>LINUX_UNIX</code> blueprints require a <code>LINUX_UNIX</code> bundle.</p>
     #[serde(rename = "supportedPlatforms")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub supported_platforms: Option<Vec<String>>,
+    pub supported_platforms: Option<Vec<InstancePlatform>>,
     /// <p>The data transfer rate per month in GB (e.g., <code>2000</code>).</p>
     #[serde(rename = "transferPerMonthInGb")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -353,8 +740,13 @@ pub struct CloudFormationStackRecord {
     pub arn: Option<String>,
     /// <p>The date when the CloudFormation stack record was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A list of objects describing the destination service, which is AWS CloudFormation, and the Amazon Resource Name (ARN) of the AWS CloudFormation stack.</p>
     #[serde(rename = "destinationInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -370,7 +762,7 @@ pub struct CloudFormationStackRecord {
     /// <p>The Lightsail resource type (e.g., <code>CloudFormationStackRecord</code>).</p>
     #[serde(rename = "resourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ResourceType>,
     /// <p>A list of objects describing the source of the CloudFormation stack record.</p>
     #[serde(rename = "sourceInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -378,7 +770,7 @@ pub struct CloudFormationStackRecord {
     /// <p>The current state of the CloudFormation stack record.</p>
     #[serde(rename = "state")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<RecordState>,
 }
 
 /// <p>Describes the source of a CloudFormation stack record (i.e., the export snapshot record).</p>
@@ -396,9 +788,97 @@ pub struct CloudFormationStackRecordSourceInfo {
     /// <p>The Lightsail resource type (e.g., <code>ExportSnapshotRecord</code>).</p>
     #[serde(rename = "resourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<CloudFormationStackRecordSourceType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CloudFormationStackRecordSourceType {
+    /// `ExportSnapshotRecord`
+    ExportSnapshotRecord,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CloudFormationStackRecordSourceType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CloudFormationStackRecordSourceType::ExportSnapshotRecord => "ExportSnapshotRecord",
+            CloudFormationStackRecordSourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CloudFormationStackRecordSourceType {
+    fn default() -> CloudFormationStackRecordSourceType {
+        CloudFormationStackRecordSourceType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CloudFormationStackRecordSourceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CloudFormationStackRecordSourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CloudFormationStackRecordSourceType {
+    fn from(value: &'a str) -> CloudFormationStackRecordSourceType {
+        match value {
+            "ExportSnapshotRecord" => CloudFormationStackRecordSourceType::ExportSnapshotRecord,
+            _ => CloudFormationStackRecordSourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CloudFormationStackRecordSourceType {
+    fn from(value: String) -> CloudFormationStackRecordSourceType {
+        match value.as_str() {
+            "ExportSnapshotRecord" => CloudFormationStackRecordSourceType::ExportSnapshotRecord,
+            _ => CloudFormationStackRecordSourceType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CloudFormationStackRecordSourceType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CloudFormationStackRecordSourceType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CloudFormationStackRecordSourceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CloudFormationStackRecordSourceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for CloudFormationStackRecordSourceType {
+    fn deserialize<D>(deserializer: D) -> Result<CloudFormationStackRecordSourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CopySnapshotRequest {
@@ -408,7 +888,7 @@ pub struct CopySnapshotRequest {
     pub restore_date: Option<String>,
     /// <p>The AWS Region where the source manual or automatic snapshot is located.</p>
     #[serde(rename = "sourceRegion")]
-    pub source_region: String,
+    pub source_region: RegionName,
     /// <p><p>The name of the source instance or disk from which the source automatic snapshot was created.</p> <p>Constraint:</p> <ul> <li> <p>Define this parameter only when copying an automatic snapshot as a manual snapshot. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en_us/articles/amazon-lightsail-keeping-automatic-snapshots">Lightsail Dev Guide</a>.</p> </li> </ul></p>
     #[serde(rename = "sourceResourceName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -853,8 +1333,13 @@ pub struct CreateRelationalDatabaseFromSnapshotRequest {
     pub relational_database_snapshot_name: Option<String>,
     /// <p><p>The date and time to restore your database from.</p> <p>Constraints:</p> <ul> <li> <p>Must be before the latest restorable time for the database.</p> </li> <li> <p>Cannot be specified if the <code>use latest restorable time</code> parameter is <code>true</code>.</p> </li> <li> <p>Specified in Coordinated Universal Time (UTC).</p> </li> <li> <p>Specified in the Unix time format.</p> <p>For example, if you wish to use a restore time of October 1, 2018, at 8 PM UTC, then you input <code>1538424000</code> as the restore time.</p> </li> </ul></p>
     #[serde(rename = "restoreTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub restore_time: Option<f64>,
+    pub restore_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the source database.</p>
     #[serde(rename = "sourceRelationalDatabaseName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1278,7 +1763,7 @@ pub struct DetachStaticIpResult {
 pub struct DisableAddOnRequest {
     /// <p>The add-on type to disable.</p>
     #[serde(rename = "addOnType")]
-    pub add_on_type: String,
+    pub add_on_type: AddOnType,
     /// <p>The name of the source resource for which to disable the add-on.</p>
     #[serde(rename = "resourceName")]
     pub resource_name: String,
@@ -1311,8 +1796,13 @@ pub struct Disk {
     pub attached_to: Option<String>,
     /// <p>The date when the disk was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The input/output operations per second (IOPS) of the disk.</p>
     #[serde(rename = "iops")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1340,7 +1830,7 @@ pub struct Disk {
     /// <p>The Lightsail resource type (e.g., <code>Disk</code>).</p>
     #[serde(rename = "resourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ResourceType>,
     /// <p>The size of the disk in GB.</p>
     #[serde(rename = "sizeInGb")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1348,7 +1838,7 @@ pub struct Disk {
     /// <p>Describes the status of the disk.</p>
     #[serde(rename = "state")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<DiskState>,
     /// <p>The support code. Include this code in your email to support when you have questions about an instance or another resource in Lightsail. This code enables our support team to look up your Lightsail information more easily.</p>
     #[serde(rename = "supportCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1405,8 +1895,13 @@ pub struct DiskSnapshot {
     pub arn: Option<String>,
     /// <p>The date when the disk snapshot was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The Amazon Resource Name (ARN) of the source disk from which the disk snapshot was created.</p>
     #[serde(rename = "fromDiskArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1442,7 +1937,7 @@ pub struct DiskSnapshot {
     /// <p>The Lightsail resource type (e.g., <code>DiskSnapshot</code>).</p>
     #[serde(rename = "resourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ResourceType>,
     /// <p>The size of the disk in GB.</p>
     #[serde(rename = "sizeInGb")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1450,7 +1945,7 @@ pub struct DiskSnapshot {
     /// <p>The status of the disk snapshot operation.</p>
     #[serde(rename = "state")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<DiskSnapshotState>,
     /// <p>The support code. Include this code in your email to support when you have questions about an instance or another resource in Lightsail. This code enables our support team to look up your Lightsail information more easily.</p>
     #[serde(rename = "supportCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1471,55 +1966,271 @@ pub struct DiskSnapshotInfo {
     pub size_in_gb: Option<i64>,
 }
 
-/// <p>Describes a domain where you are storing recordsets in Lightsail.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct Domain {
-    /// <p>The Amazon Resource Name (ARN) of the domain recordset (e.g., <code>arn:aws:lightsail:global:123456789101:Domain/824cede0-abc7-4f84-8dbc-12345EXAMPLE</code>).</p>
-    #[serde(rename = "arn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub arn: Option<String>,
-    /// <p>The date when the domain recordset was created.</p>
-    #[serde(rename = "createdAt")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
-    /// <p>An array of key-value pairs containing information about the domain entries.</p>
-    #[serde(rename = "domainEntries")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub domain_entries: Option<Vec<DomainEntry>>,
-    /// <p>The AWS Region and Availability Zones where the domain recordset was created.</p>
-    #[serde(rename = "location")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub location: Option<ResourceLocation>,
-    /// <p>The name of the domain.</p>
-    #[serde(rename = "name")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
-    /// <p>The resource type. </p>
-    #[serde(rename = "resourceType")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
-    /// <p>The support code. Include this code in your email to support when you have questions about an instance or another resource in Lightsail. This code enables our support team to look up your Lightsail information more easily.</p>
-    #[serde(rename = "supportCode")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub support_code: Option<String>,
-    /// <p>The tag keys and optional values for the resource. For more information about tags in Lightsail, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-tags">Lightsail Dev Guide</a>.</p>
-    #[serde(rename = "tags")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub tags: Option<Vec<Tag>>,
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DiskSnapshotState {
+    /// `pending`
+    Pending,
+    /// `completed`
+    Completed,
+    /// `error`
+    Error,
+    /// `unknown`
+    UnknownValue,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DiskSnapshotState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DiskSnapshotState::Pending => "pending",
+            DiskSnapshotState::Completed => "completed",
+            DiskSnapshotState::Error => "error",
+            DiskSnapshotState::UnknownValue => "unknown",
+            DiskSnapshotState::Unknown(ref value) => value,
+        }
+    }
 }
 
-/// <p>Describes a domain recordset entry.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct DomainEntry {
-    /// <p>The ID of the domain recordset entry.</p>
-    #[serde(rename = "id")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
-    /// <p>When <code>true</code>, specifies whether the domain entry is an alias used by the Lightsail load balancer. You can include an alias (A type) record in your request, which points to a load balancer DNS name and routes traffic to your load balancer</p>
-    #[serde(rename = "isAlias")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub is_alias: Option<bool>,
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DiskSnapshotState {
+    fn default() -> DiskSnapshotState {
+        DiskSnapshotState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DiskSnapshotState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DiskSnapshotState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DiskSnapshotState {
+    fn from(value: &'a str) -> DiskSnapshotState {
+        match value {
+            "pending" => DiskSnapshotState::Pending,
+            "completed" => DiskSnapshotState::Completed,
+            "error" => DiskSnapshotState::Error,
+            "unknown" => DiskSnapshotState::UnknownValue,
+            _ => DiskSnapshotState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DiskSnapshotState {
+    fn from(value: String) -> DiskSnapshotState {
+        match value.as_str() {
+            "pending" => DiskSnapshotState::Pending,
+            "completed" => DiskSnapshotState::Completed,
+            "error" => DiskSnapshotState::Error,
+            "unknown" => DiskSnapshotState::UnknownValue,
+            _ => DiskSnapshotState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DiskSnapshotState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DiskSnapshotState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DiskSnapshotState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DiskSnapshotState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DiskSnapshotState {
+    fn deserialize<D>(deserializer: D) -> Result<DiskSnapshotState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DiskState {
+    /// `pending`
+    Pending,
+    /// `error`
+    Error,
+    /// `available`
+    Available,
+    /// `in-use`
+    InUse,
+    /// `unknown`
+    UnknownValue,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DiskState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DiskState::Pending => "pending",
+            DiskState::Error => "error",
+            DiskState::Available => "available",
+            DiskState::InUse => "in-use",
+            DiskState::UnknownValue => "unknown",
+            DiskState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DiskState {
+    fn default() -> DiskState {
+        DiskState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DiskState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DiskState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DiskState {
+    fn from(value: &'a str) -> DiskState {
+        match value {
+            "pending" => DiskState::Pending,
+            "error" => DiskState::Error,
+            "available" => DiskState::Available,
+            "in-use" => DiskState::InUse,
+            "unknown" => DiskState::UnknownValue,
+            _ => DiskState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DiskState {
+    fn from(value: String) -> DiskState {
+        match value.as_str() {
+            "pending" => DiskState::Pending,
+            "error" => DiskState::Error,
+            "available" => DiskState::Available,
+            "in-use" => DiskState::InUse,
+            "unknown" => DiskState::UnknownValue,
+            _ => DiskState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DiskState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DiskState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DiskState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DiskState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DiskState {
+    fn deserialize<D>(deserializer: D) -> Result<DiskState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Describes a domain where you are storing recordsets in Lightsail.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct Domain {
+    /// <p>The Amazon Resource Name (ARN) of the domain recordset (e.g., <code>arn:aws:lightsail:global:123456789101:Domain/824cede0-abc7-4f84-8dbc-12345EXAMPLE</code>).</p>
+    #[serde(rename = "arn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub arn: Option<String>,
+    /// <p>The date when the domain recordset was created.</p>
+    #[serde(rename = "createdAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
+    /// <p>An array of key-value pairs containing information about the domain entries.</p>
+    #[serde(rename = "domainEntries")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub domain_entries: Option<Vec<DomainEntry>>,
+    /// <p>The AWS Region and Availability Zones where the domain recordset was created.</p>
+    #[serde(rename = "location")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<ResourceLocation>,
+    /// <p>The name of the domain.</p>
+    #[serde(rename = "name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// <p>The resource type. </p>
+    #[serde(rename = "resourceType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_type: Option<ResourceType>,
+    /// <p>The support code. Include this code in your email to support when you have questions about an instance or another resource in Lightsail. This code enables our support team to look up your Lightsail information more easily.</p>
+    #[serde(rename = "supportCode")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub support_code: Option<String>,
+    /// <p>The tag keys and optional values for the resource. For more information about tags in Lightsail, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-tags">Lightsail Dev Guide</a>.</p>
+    #[serde(rename = "tags")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<Tag>>,
+}
+
+/// <p>Describes a domain recordset entry.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DomainEntry {
+    /// <p>The ID of the domain recordset entry.</p>
+    #[serde(rename = "id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// <p>When <code>true</code>, specifies whether the domain entry is an alias used by the Lightsail load balancer. You can include an alias (A type) record in your request, which points to a load balancer DNS name and routes traffic to your load balancer</p>
+    #[serde(rename = "isAlias")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_alias: Option<bool>,
     /// <p>The name of the domain.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1581,8 +2292,13 @@ pub struct ExportSnapshotRecord {
     pub arn: Option<String>,
     /// <p>The date when the export snapshot record was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A list of objects describing the destination of the export snapshot record.</p>
     #[serde(rename = "destinationInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1598,7 +2314,7 @@ pub struct ExportSnapshotRecord {
     /// <p>The Lightsail resource type (e.g., <code>ExportSnapshotRecord</code>).</p>
     #[serde(rename = "resourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ResourceType>,
     /// <p>A list of objects describing the source of the export snapshot record.</p>
     #[serde(rename = "sourceInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1606,7 +2322,7 @@ pub struct ExportSnapshotRecord {
     /// <p>The state of the export snapshot record.</p>
     #[serde(rename = "state")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<RecordState>,
 }
 
 /// <p>Describes the source of an export snapshot record.</p>
@@ -1619,8 +2335,13 @@ pub struct ExportSnapshotRecordSourceInfo {
     pub arn: Option<String>,
     /// <p>The date when the source instance or disk snapshot was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A list of objects describing a disk snapshot.</p>
     #[serde(rename = "diskSnapshotInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1644,9 +2365,102 @@ pub struct ExportSnapshotRecordSourceInfo {
     /// <p>The Lightsail resource type (e.g., <code>InstanceSnapshot</code> or <code>DiskSnapshot</code>).</p>
     #[serde(rename = "resourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ExportSnapshotRecordSourceType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ExportSnapshotRecordSourceType {
+    /// `InstanceSnapshot`
+    InstanceSnapshot,
+    /// `DiskSnapshot`
+    DiskSnapshot,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ExportSnapshotRecordSourceType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ExportSnapshotRecordSourceType::InstanceSnapshot => "InstanceSnapshot",
+            ExportSnapshotRecordSourceType::DiskSnapshot => "DiskSnapshot",
+            ExportSnapshotRecordSourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ExportSnapshotRecordSourceType {
+    fn default() -> ExportSnapshotRecordSourceType {
+        ExportSnapshotRecordSourceType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ExportSnapshotRecordSourceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ExportSnapshotRecordSourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ExportSnapshotRecordSourceType {
+    fn from(value: &'a str) -> ExportSnapshotRecordSourceType {
+        match value {
+            "InstanceSnapshot" => ExportSnapshotRecordSourceType::InstanceSnapshot,
+            "DiskSnapshot" => ExportSnapshotRecordSourceType::DiskSnapshot,
+            _ => ExportSnapshotRecordSourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ExportSnapshotRecordSourceType {
+    fn from(value: String) -> ExportSnapshotRecordSourceType {
+        match value.as_str() {
+            "InstanceSnapshot" => ExportSnapshotRecordSourceType::InstanceSnapshot,
+            "DiskSnapshot" => ExportSnapshotRecordSourceType::DiskSnapshot,
+            _ => ExportSnapshotRecordSourceType::Unknown(value),
+        }
+    }
 }
 
+impl ::std::str::FromStr for ExportSnapshotRecordSourceType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ExportSnapshotRecordSourceType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ExportSnapshotRecordSourceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ExportSnapshotRecordSourceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ExportSnapshotRecordSourceType {
+    fn deserialize<D>(deserializer: D) -> Result<ExportSnapshotRecordSourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ExportSnapshotRequest {
@@ -1708,7 +2522,7 @@ pub struct GetAutoSnapshotsResult {
     /// <p>The resource type (e.g., <code>Instance</code> or <code>Disk</code>).</p>
     #[serde(rename = "resourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ResourceType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -1933,7 +2747,7 @@ pub struct GetInstanceAccessDetailsRequest {
     /// <p>The protocol to use to connect to your instance. Defaults to <code>ssh</code>.</p>
     #[serde(rename = "protocol")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub protocol: Option<String>,
+    pub protocol: Option<InstanceAccessProtocol>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1950,25 +2764,35 @@ pub struct GetInstanceAccessDetailsResult {
 pub struct GetInstanceMetricDataRequest {
     /// <p>The end time of the time period.</p>
     #[serde(rename = "endTime")]
-    pub end_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub end_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The name of the instance for which you want to get metrics data.</p>
     #[serde(rename = "instanceName")]
     pub instance_name: String,
     /// <p>The metric name to get data about. </p>
     #[serde(rename = "metricName")]
-    pub metric_name: String,
+    pub metric_name: InstanceMetricName,
     /// <p>The granularity, in seconds, of the returned data points.</p>
     #[serde(rename = "period")]
     pub period: i64,
     /// <p>The start time of the time period.</p>
     #[serde(rename = "startTime")]
-    pub start_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub start_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The instance statistics. </p>
     #[serde(rename = "statistics")]
-    pub statistics: Vec<String>,
+    pub statistics: Vec<MetricStatistic>,
     /// <p>The unit. The list of valid values is below.</p>
     #[serde(rename = "unit")]
-    pub unit: String,
+    pub unit: MetricUnit,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1981,7 +2805,7 @@ pub struct GetInstanceMetricDataResult {
     /// <p>The metric name to return data for.</p>
     #[serde(rename = "metricName")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub metric_name: Option<String>,
+    pub metric_name: Option<InstanceMetricName>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -2140,25 +2964,35 @@ pub struct GetKeyPairsResult {
 pub struct GetLoadBalancerMetricDataRequest {
     /// <p>The end time of the period.</p>
     #[serde(rename = "endTime")]
-    pub end_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub end_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The name of the load balancer.</p>
     #[serde(rename = "loadBalancerName")]
     pub load_balancer_name: String,
     /// <p><p>The metric about which you want to return information. Valid values are listed below, along with the most useful <code>statistics</code> to include in your request.</p> <ul> <li> <p> <b> <code>ClientTLSNegotiationErrorCount</code> </b> - The number of TLS connections initiated by the client that did not establish a session with the load balancer. Possible causes include a mismatch of ciphers or protocols.</p> <p> <code>Statistics</code>: The most useful statistic is <code>Sum</code>.</p> </li> <li> <p> <b> <code>HealthyHostCount</code> </b> - The number of target instances that are considered healthy.</p> <p> <code>Statistics</code>: The most useful statistic are <code>Average</code>, <code>Minimum</code>, and <code>Maximum</code>.</p> </li> <li> <p> <b> <code>UnhealthyHostCount</code> </b> - The number of target instances that are considered unhealthy.</p> <p> <code>Statistics</code>: The most useful statistic are <code>Average</code>, <code>Minimum</code>, and <code>Maximum</code>.</p> </li> <li> <p> <b> <code>HTTPCode<em>LB</em>4XX<em>Count</code> </b> - The number of HTTP 4XX client error codes that originate from the load balancer. Client errors are generated when requests are malformed or incomplete. These requests have not been received by the target instance. This count does not include any response codes generated by the target instances.</p> <p> <code>Statistics</code>: The most useful statistic is <code>Sum</code>. Note that <code>Minimum</code>, <code>Maximum</code>, and <code>Average</code> all return <code>1</code>.</p> </li> <li> <p> <b> <code>HTTPCode</em>LB<em>5XX</em>Count</code> </b> - The number of HTTP 5XX server error codes that originate from the load balancer. This count does not include any response codes generated by the target instances.</p> <p> <code>Statistics</code>: The most useful statistic is <code>Sum</code>. Note that <code>Minimum</code>, <code>Maximum</code>, and <code>Average</code> all return <code>1</code>. Note that <code>Minimum</code>, <code>Maximum</code>, and <code>Average</code> all return <code>1</code>.</p> </li> <li> <p> <b> <code>HTTPCode<em>Instance</em>2XX<em>Count</code> </b> - The number of HTTP response codes generated by the target instances. This does not include any response codes generated by the load balancer.</p> <p> <code>Statistics</code>: The most useful statistic is <code>Sum</code>. Note that <code>Minimum</code>, <code>Maximum</code>, and <code>Average</code> all return <code>1</code>.</p> </li> <li> <p> <b> <code>HTTPCode</em>Instance<em>3XX</em>Count</code> </b> - The number of HTTP response codes generated by the target instances. This does not include any response codes generated by the load balancer. </p> <p> <code>Statistics</code>: The most useful statistic is <code>Sum</code>. Note that <code>Minimum</code>, <code>Maximum</code>, and <code>Average</code> all return <code>1</code>.</p> </li> <li> <p> <b> <code>HTTPCode<em>Instance</em>4XX<em>Count</code> </b> - The number of HTTP response codes generated by the target instances. This does not include any response codes generated by the load balancer.</p> <p> <code>Statistics</code>: The most useful statistic is <code>Sum</code>. Note that <code>Minimum</code>, <code>Maximum</code>, and <code>Average</code> all return <code>1</code>.</p> </li> <li> <p> <b> <code>HTTPCode</em>Instance<em>5XX</em>Count</code> </b> - The number of HTTP response codes generated by the target instances. This does not include any response codes generated by the load balancer.</p> <p> <code>Statistics</code>: The most useful statistic is <code>Sum</code>. Note that <code>Minimum</code>, <code>Maximum</code>, and <code>Average</code> all return <code>1</code>.</p> </li> <li> <p> <b> <code>InstanceResponseTime</code> </b> - The time elapsed, in seconds, after the request leaves the load balancer until a response from the target instance is received.</p> <p> <code>Statistics</code>: The most useful statistic is <code>Average</code>.</p> </li> <li> <p> <b> <code>RejectedConnectionCount</code> </b> - The number of connections that were rejected because the load balancer had reached its maximum number of connections.</p> <p> <code>Statistics</code>: The most useful statistic is <code>Sum</code>.</p> </li> <li> <p> <b> <code>RequestCount</code> </b> - The number of requests processed over IPv4. This count includes only the requests with a response generated by a target instance of the load balancer.</p> <p> <code>Statistics</code>: The most useful statistic is <code>Sum</code>. Note that <code>Minimum</code>, <code>Maximum</code>, and <code>Average</code> all return <code>1</code>.</p> </li> </ul></p>
     #[serde(rename = "metricName")]
-    pub metric_name: String,
+    pub metric_name: LoadBalancerMetricName,
     /// <p>The granularity, in seconds, of the returned data points.</p>
     #[serde(rename = "period")]
     pub period: i64,
     /// <p>The start time of the period.</p>
     #[serde(rename = "startTime")]
-    pub start_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub start_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p><p>An array of statistics that you want to request metrics for. Valid values are listed below.</p> <ul> <li> <p> <b> <code>SampleCount</code> </b> - The count (number) of data points used for the statistical calculation.</p> </li> <li> <p> <b> <code>Average</code> </b> - The value of Sum / SampleCount during the specified period. By comparing this statistic with the Minimum and Maximum, you can determine the full scope of a metric and how close the average use is to the Minimum and Maximum. This comparison helps you to know when to increase or decrease your resources as needed.</p> </li> <li> <p> <b> <code>Sum</code> </b> - All values submitted for the matching metric added together. This statistic can be useful for determining the total volume of a metric.</p> </li> <li> <p> <b> <code>Minimum</code> </b> - The lowest value observed during the specified period. You can use this value to determine low volumes of activity for your application.</p> </li> <li> <p> <b> <code>Maximum</code> </b> - The highest value observed during the specified period. You can use this value to determine high volumes of activity for your application.</p> </li> </ul></p>
     #[serde(rename = "statistics")]
-    pub statistics: Vec<String>,
+    pub statistics: Vec<MetricStatistic>,
     /// <p>The unit for the time period request. Valid values are listed below.</p>
     #[serde(rename = "unit")]
-    pub unit: String,
+    pub unit: MetricUnit,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -2171,7 +3005,7 @@ pub struct GetLoadBalancerMetricDataResult {
     /// <p><p>The metric about which you are receiving information. Valid values are listed below, along with the most useful <code>statistics</code> to include in your request.</p> <ul> <li> <p> <b> <code>ClientTLSNegotiationErrorCount</code> </b> - The number of TLS connections initiated by the client that did not establish a session with the load balancer. Possible causes include a mismatch of ciphers or protocols.</p> <p> <code>Statistics</code>: The most useful statistic is <code>Sum</code>.</p> </li> <li> <p> <b> <code>HealthyHostCount</code> </b> - The number of target instances that are considered healthy.</p> <p> <code>Statistics</code>: The most useful statistic are <code>Average</code>, <code>Minimum</code>, and <code>Maximum</code>.</p> </li> <li> <p> <b> <code>UnhealthyHostCount</code> </b> - The number of target instances that are considered unhealthy.</p> <p> <code>Statistics</code>: The most useful statistic are <code>Average</code>, <code>Minimum</code>, and <code>Maximum</code>.</p> </li> <li> <p> <b> <code>HTTPCode<em>LB</em>4XX<em>Count</code> </b> - The number of HTTP 4XX client error codes that originate from the load balancer. Client errors are generated when requests are malformed or incomplete. These requests have not been received by the target instance. This count does not include any response codes generated by the target instances.</p> <p> <code>Statistics</code>: The most useful statistic is <code>Sum</code>. Note that <code>Minimum</code>, <code>Maximum</code>, and <code>Average</code> all return <code>1</code>.</p> </li> <li> <p> <b> <code>HTTPCode</em>LB<em>5XX</em>Count</code> </b> - The number of HTTP 5XX server error codes that originate from the load balancer. This count does not include any response codes generated by the target instances.</p> <p> <code>Statistics</code>: The most useful statistic is <code>Sum</code>. Note that <code>Minimum</code>, <code>Maximum</code>, and <code>Average</code> all return <code>1</code>. Note that <code>Minimum</code>, <code>Maximum</code>, and <code>Average</code> all return <code>1</code>.</p> </li> <li> <p> <b> <code>HTTPCode<em>Instance</em>2XX<em>Count</code> </b> - The number of HTTP response codes generated by the target instances. This does not include any response codes generated by the load balancer.</p> <p> <code>Statistics</code>: The most useful statistic is <code>Sum</code>. Note that <code>Minimum</code>, <code>Maximum</code>, and <code>Average</code> all return <code>1</code>.</p> </li> <li> <p> <b> <code>HTTPCode</em>Instance<em>3XX</em>Count</code> </b> - The number of HTTP response codes generated by the target instances. This does not include any response codes generated by the load balancer. </p> <p> <code>Statistics</code>: The most useful statistic is <code>Sum</code>. Note that <code>Minimum</code>, <code>Maximum</code>, and <code>Average</code> all return <code>1</code>.</p> </li> <li> <p> <b> <code>HTTPCode<em>Instance</em>4XX<em>Count</code> </b> - The number of HTTP response codes generated by the target instances. This does not include any response codes generated by the load balancer.</p> <p> <code>Statistics</code>: The most useful statistic is <code>Sum</code>. Note that <code>Minimum</code>, <code>Maximum</code>, and <code>Average</code> all return <code>1</code>.</p> </li> <li> <p> <b> <code>HTTPCode</em>Instance<em>5XX</em>Count</code> </b> - The number of HTTP response codes generated by the target instances. This does not include any response codes generated by the load balancer.</p> <p> <code>Statistics</code>: The most useful statistic is <code>Sum</code>. Note that <code>Minimum</code>, <code>Maximum</code>, and <code>Average</code> all return <code>1</code>.</p> </li> <li> <p> <b> <code>InstanceResponseTime</code> </b> - The time elapsed, in seconds, after the request leaves the load balancer until a response from the target instance is received.</p> <p> <code>Statistics</code>: The most useful statistic is <code>Average</code>.</p> </li> <li> <p> <b> <code>RejectedConnectionCount</code> </b> - The number of connections that were rejected because the load balancer had reached its maximum number of connections.</p> <p> <code>Statistics</code>: The most useful statistic is <code>Sum</code>.</p> </li> <li> <p> <b> <code>RequestCount</code> </b> - The number of requests processed over IPv4. This count includes only the requests with a response generated by a target instance of the load balancer.</p> <p> <code>Statistics</code>: The most useful statistic is <code>Sum</code>. Note that <code>Minimum</code>, <code>Maximum</code>, and <code>Average</code> all return <code>1</code>.</p> </li> </ul></p>
     #[serde(rename = "metricName")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub metric_name: Option<String>,
+    pub metric_name: Option<LoadBalancerMetricName>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -2394,8 +3228,13 @@ pub struct GetRelationalDatabaseEventsResult {
 pub struct GetRelationalDatabaseLogEventsRequest {
     /// <p><p>The end of the time interval from which to get log events.</p> <p>Constraints:</p> <ul> <li> <p>Specified in Coordinated Universal Time (UTC).</p> </li> <li> <p>Specified in the Unix time format.</p> <p>For example, if you wish to use an end time of October 1, 2018, at 8 PM UTC, then you input <code>1538424000</code> as the end time.</p> </li> </ul></p>
     #[serde(rename = "endTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the log stream.</p> <p>Use the <code>get relational database log streams</code> operation to get a list of available log streams.</p>
     #[serde(rename = "logStreamName")]
     pub log_stream_name: String,
@@ -2412,8 +3251,13 @@ pub struct GetRelationalDatabaseLogEventsRequest {
     pub start_from_head: Option<bool>,
     /// <p><p>The start of the time interval from which to get log events.</p> <p>Constraints:</p> <ul> <li> <p>Specified in Coordinated Universal Time (UTC).</p> </li> <li> <p>Specified in the Unix time format.</p> <p>For example, if you wish to use a start time of October 1, 2018, at 8 PM UTC, then you input <code>1538424000</code> as the start time.</p> </li> </ul></p>
     #[serde(rename = "startTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -2456,7 +3300,7 @@ pub struct GetRelationalDatabaseMasterUserPasswordRequest {
     /// <p>The password version to return.</p> <p>Specifying <code>CURRENT</code> or <code>PREVIOUS</code> returns the current or previous passwords respectively. Specifying <code>PENDING</code> returns the newest version of the password that will rotate to <code>CURRENT</code>. After the <code>PENDING</code> password rotates to <code>CURRENT</code>, the <code>PENDING</code> password is no longer available.</p> <p>Default: <code>CURRENT</code> </p>
     #[serde(rename = "passwordVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub password_version: Option<String>,
+    pub password_version: Option<RelationalDatabasePasswordVersion>,
     /// <p>The name of your database for which to get the master user password.</p>
     #[serde(rename = "relationalDatabaseName")]
     pub relational_database_name: String,
@@ -2467,8 +3311,13 @@ pub struct GetRelationalDatabaseMasterUserPasswordRequest {
 pub struct GetRelationalDatabaseMasterUserPasswordResult {
     /// <p>The timestamp when the specified version of the master user password was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The master user password for the <code>password version</code> specified.</p>
     #[serde(rename = "masterUserPassword")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2480,10 +3329,15 @@ pub struct GetRelationalDatabaseMasterUserPasswordResult {
 pub struct GetRelationalDatabaseMetricDataRequest {
     /// <p><p>The end of the time interval from which to get metric data.</p> <p>Constraints:</p> <ul> <li> <p>Specified in Coordinated Universal Time (UTC).</p> </li> <li> <p>Specified in the Unix time format.</p> <p>For example, if you wish to use an end time of October 1, 2018, at 8 PM UTC, then you input <code>1538424000</code> as the end time.</p> </li> </ul></p>
     #[serde(rename = "endTime")]
-    pub end_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub end_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The name of the metric data to return.</p>
     #[serde(rename = "metricName")]
-    pub metric_name: String,
+    pub metric_name: RelationalDatabaseMetricName,
     /// <p>The granularity, in seconds, of the returned data points.</p>
     #[serde(rename = "period")]
     pub period: i64,
@@ -2492,13 +3346,18 @@ pub struct GetRelationalDatabaseMetricDataRequest {
     pub relational_database_name: String,
     /// <p><p>The start of the time interval from which to get metric data.</p> <p>Constraints:</p> <ul> <li> <p>Specified in Coordinated Universal Time (UTC).</p> </li> <li> <p>Specified in the Unix time format.</p> <p>For example, if you wish to use a start time of October 1, 2018, at 8 PM UTC, then you input <code>1538424000</code> as the start time.</p> </li> </ul></p>
     #[serde(rename = "startTime")]
-    pub start_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub start_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The array of statistics for your metric data request.</p>
     #[serde(rename = "statistics")]
-    pub statistics: Vec<String>,
+    pub statistics: Vec<MetricStatistic>,
     /// <p>The unit for the metric data request.</p>
     #[serde(rename = "unit")]
-    pub unit: String,
+    pub unit: MetricUnit,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -2511,7 +3370,7 @@ pub struct GetRelationalDatabaseMetricDataResult {
     /// <p>The name of the metric.</p>
     #[serde(rename = "metricName")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub metric_name: Option<String>,
+    pub metric_name: Option<RelationalDatabaseMetricName>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -2674,20 +3533,35 @@ pub struct HostKeyAttributes {
     pub fingerprint_sha256: Option<String>,
     /// <p>The returned RDP certificate is not valid after this point in time.</p> <p>This value is listed only for RDP certificates.</p>
     #[serde(rename = "notValidAfter")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub not_valid_after: Option<f64>,
+    pub not_valid_after: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The returned RDP certificate is valid after this point in time.</p> <p>This value is listed only for RDP certificates.</p>
     #[serde(rename = "notValidBefore")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub not_valid_before: Option<f64>,
+    pub not_valid_before: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The public SSH host key or the RDP certificate.</p>
     #[serde(rename = "publicKey")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub public_key: Option<String>,
     /// <p>The time that the SSH host key or RDP certificate was recorded by Lightsail.</p>
     #[serde(rename = "witnessedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub witnessed_at: Option<f64>,
+    pub witnessed_at: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -2736,8 +3610,13 @@ pub struct Instance {
     pub bundle_id: Option<String>,
     /// <p>The timestamp when the instance was created (e.g., <code>1479734909.17</code>).</p>
     #[serde(rename = "createdAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The size of the vCPU and the amount of RAM for the instance.</p>
     #[serde(rename = "hardware")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2773,7 +3652,7 @@ pub struct Instance {
     /// <p>The type of resource (usually <code>Instance</code>).</p>
     #[serde(rename = "resourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ResourceType>,
     /// <p>The name of the SSH key being used to connect to the instance (e.g., <code>LightsailDefaultKeyPair</code>).</p>
     #[serde(rename = "sshKeyName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2806,8 +3685,13 @@ pub struct InstanceAccessDetails {
     pub cert_key: Option<String>,
     /// <p>For SSH access, the date on which the temporary keys expire.</p>
     #[serde(rename = "expiresAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expires_at: Option<f64>,
+    pub expires_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Describes the public SSH host keys or the RDP certificate.</p>
     #[serde(rename = "hostKeys")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2835,13 +3719,106 @@ pub struct InstanceAccessDetails {
     /// <p>The protocol for these Amazon Lightsail instance access details.</p>
     #[serde(rename = "protocol")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub protocol: Option<String>,
+    pub protocol: Option<InstanceAccessProtocol>,
     /// <p>The user name to use when logging in to the Amazon Lightsail instance.</p>
     #[serde(rename = "username")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub username: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum InstanceAccessProtocol {
+    /// `ssh`
+    Ssh,
+    /// `rdp`
+    Rdp,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl InstanceAccessProtocol {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InstanceAccessProtocol::Ssh => "ssh",
+            InstanceAccessProtocol::Rdp => "rdp",
+            InstanceAccessProtocol::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for InstanceAccessProtocol {
+    fn default() -> InstanceAccessProtocol {
+        InstanceAccessProtocol::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for InstanceAccessProtocol {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for InstanceAccessProtocol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for InstanceAccessProtocol {
+    fn from(value: &'a str) -> InstanceAccessProtocol {
+        match value {
+            "ssh" => InstanceAccessProtocol::Ssh,
+            "rdp" => InstanceAccessProtocol::Rdp,
+            _ => InstanceAccessProtocol::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InstanceAccessProtocol {
+    fn from(value: String) -> InstanceAccessProtocol {
+        match value.as_str() {
+            "ssh" => InstanceAccessProtocol::Ssh,
+            "rdp" => InstanceAccessProtocol::Rdp,
+            _ => InstanceAccessProtocol::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for InstanceAccessProtocol {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<InstanceAccessProtocol, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for InstanceAccessProtocol {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for InstanceAccessProtocol {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InstanceAccessProtocol {
+    fn deserialize<D>(deserializer: D) -> Result<InstanceAccessProtocol, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes the Amazon Elastic Compute Cloud instance and related resources to be created using the <code>create cloud formation stack</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -2854,7 +3831,7 @@ pub struct InstanceEntry {
     pub instance_type: String,
     /// <p><p>The port configuration to use for the new Amazon EC2 instance.</p> <p>The following configuration options are available:</p> <ul> <li> <p>DEFAULT — Use the default firewall settings from the image.</p> </li> <li> <p>INSTANCE — Use the firewall settings from the source Lightsail instance.</p> </li> <li> <p>NONE — Default to Amazon EC2.</p> </li> <li> <p>CLOSED — All ports closed.</p> </li> </ul></p>
     #[serde(rename = "portInfoSource")]
-    pub port_info_source: String,
+    pub port_info_source: PortInfoSourceType,
     /// <p>The name of the export snapshot record, which contains the exported Lightsail instance snapshot that will be used as the source of the new Amazon EC2 instance.</p> <p>Use the <code>get export snapshot records</code> operation to get a list of export snapshot records that you can use to create a CloudFormation stack.</p>
     #[serde(rename = "sourceName")]
     pub source_name: String,
@@ -2882,66 +3859,544 @@ pub struct InstanceHardware {
     pub ram_size_in_gb: Option<f32>,
 }
 
-/// <p>Describes information about the health of the instance.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct InstanceHealthSummary {
-    /// <p>Describes the overall instance health. Valid values are below.</p>
-    #[serde(rename = "instanceHealth")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub instance_health: Option<String>,
-    /// <p><p>More information about the instance health. If the <code>instanceHealth</code> is <code>healthy</code>, then an <code>instanceHealthReason</code> value is not provided.</p> <p>If <b> <code>instanceHealth</code> </b> is <code>initial</code>, the <b> <code>instanceHealthReason</code> </b> value can be one of the following:</p> <ul> <li> <p> <b> <code>Lb.RegistrationInProgress</code> </b> - The target instance is in the process of being registered with the load balancer.</p> </li> <li> <p> <b> <code>Lb.InitialHealthChecking</code> </b> - The Lightsail load balancer is still sending the target instance the minimum number of health checks required to determine its health status.</p> </li> </ul> <p>If <b> <code>instanceHealth</code> </b> is <code>unhealthy</code>, the <b> <code>instanceHealthReason</code> </b> value can be one of the following:</p> <ul> <li> <p> <b> <code>Instance.ResponseCodeMismatch</code> </b> - The health checks did not return an expected HTTP code.</p> </li> <li> <p> <b> <code>Instance.Timeout</code> </b> - The health check requests timed out.</p> </li> <li> <p> <b> <code>Instance.FailedHealthChecks</code> </b> - The health checks failed because the connection to the target instance timed out, the target instance response was malformed, or the target instance failed the health check for an unknown reason.</p> </li> <li> <p> <b> <code>Lb.InternalError</code> </b> - The health checks failed due to an internal error.</p> </li> </ul> <p>If <b> <code>instanceHealth</code> </b> is <code>unused</code>, the <b> <code>instanceHealthReason</code> </b> value can be one of the following:</p> <ul> <li> <p> <b> <code>Instance.NotRegistered</code> </b> - The target instance is not registered with the target group.</p> </li> <li> <p> <b> <code>Instance.NotInUse</code> </b> - The target group is not used by any load balancer, or the target instance is in an Availability Zone that is not enabled for its load balancer.</p> </li> <li> <p> <b> <code>Instance.IpUnusable</code> </b> - The target IP address is reserved for use by a Lightsail load balancer.</p> </li> <li> <p> <b> <code>Instance.InvalidState</code> </b> - The target is in the stopped or terminated state.</p> </li> </ul> <p>If <b> <code>instanceHealth</code> </b> is <code>draining</code>, the <b> <code>instanceHealthReason</code> </b> value can be one of the following:</p> <ul> <li> <p> <b> <code>Instance.DeregistrationInProgress</code> </b> - The target instance is in the process of being deregistered and the deregistration delay period has not expired.</p> </li> </ul></p>
-    #[serde(rename = "instanceHealthReason")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub instance_health_reason: Option<String>,
-    /// <p>The name of the Lightsail instance for which you are requesting health check data.</p>
-    #[serde(rename = "instanceName")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub instance_name: Option<String>,
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum InstanceHealthReason {
+    /// `Lb.RegistrationInProgress`
+    LbRegistrationInProgress,
+    /// `Lb.InitialHealthChecking`
+    LbInitialHealthChecking,
+    /// `Lb.InternalError`
+    LbInternalError,
+    /// `Instance.ResponseCodeMismatch`
+    InstanceResponseCodeMismatch,
+    /// `Instance.Timeout`
+    InstanceTimeout,
+    /// `Instance.FailedHealthChecks`
+    InstanceFailedHealthChecks,
+    /// `Instance.NotRegistered`
+    InstanceNotRegistered,
+    /// `Instance.NotInUse`
+    InstanceNotInUse,
+    /// `Instance.DeregistrationInProgress`
+    InstanceDeregistrationInProgress,
+    /// `Instance.InvalidState`
+    InstanceInvalidState,
+    /// `Instance.IpUnusable`
+    InstanceIpUnusable,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl InstanceHealthReason {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InstanceHealthReason::LbRegistrationInProgress => "Lb.RegistrationInProgress",
+            InstanceHealthReason::LbInitialHealthChecking => "Lb.InitialHealthChecking",
+            InstanceHealthReason::LbInternalError => "Lb.InternalError",
+            InstanceHealthReason::InstanceResponseCodeMismatch => "Instance.ResponseCodeMismatch",
+            InstanceHealthReason::InstanceTimeout => "Instance.Timeout",
+            InstanceHealthReason::InstanceFailedHealthChecks => "Instance.FailedHealthChecks",
+            InstanceHealthReason::InstanceNotRegistered => "Instance.NotRegistered",
+            InstanceHealthReason::InstanceNotInUse => "Instance.NotInUse",
+            InstanceHealthReason::InstanceDeregistrationInProgress => {
+                "Instance.DeregistrationInProgress"
+            }
+            InstanceHealthReason::InstanceInvalidState => "Instance.InvalidState",
+            InstanceHealthReason::InstanceIpUnusable => "Instance.IpUnusable",
+            InstanceHealthReason::Unknown(ref value) => value,
+        }
+    }
 }
 
-/// <p>Describes monthly data transfer rates and port information for an instance.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct InstanceNetworking {
-    /// <p>The amount of data in GB allocated for monthly data transfers.</p>
-    #[serde(rename = "monthlyTransfer")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub monthly_transfer: Option<MonthlyTransfer>,
-    /// <p>An array of key-value pairs containing information about the ports on the instance.</p>
-    #[serde(rename = "ports")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub ports: Option<Vec<InstancePortInfo>>,
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for InstanceHealthReason {
+    fn default() -> InstanceHealthReason {
+        InstanceHealthReason::Unknown(String::new())
+    }
 }
 
-/// <p>Describes information about the instance ports.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct InstancePortInfo {
-    /// <p>The access direction (<code>inbound</code> or <code>outbound</code>).</p>
-    #[serde(rename = "accessDirection")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub access_direction: Option<String>,
-    /// <p>The location from which access is allowed (e.g., <code>Anywhere (0.0.0.0/0)</code>).</p>
-    #[serde(rename = "accessFrom")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub access_from: Option<String>,
-    /// <p>The type of access (<code>Public</code> or <code>Private</code>).</p>
-    #[serde(rename = "accessType")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub access_type: Option<String>,
-    /// <p>The common name.</p>
-    #[serde(rename = "commonName")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub common_name: Option<String>,
-    /// <p>The first port in the range.</p>
-    #[serde(rename = "fromPort")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub from_port: Option<i64>,
-    /// <p><p>The protocol being used. Can be one of the following.</p> <ul> <li> <p> <code>tcp</code> - Transmission Control Protocol (TCP) provides reliable, ordered, and error-checked delivery of streamed data between applications running on hosts communicating by an IP network. If you have an application that doesn&#39;t require reliable data stream service, use UDP instead.</p> </li> <li> <p> <code>all</code> - All transport layer protocol types. For more general information, see <a href="https://en.wikipedia.org/wiki/Transport_layer">Transport layer</a> on Wikipedia.</p> </li> <li> <p> <code>udp</code> - With User Datagram Protocol (UDP), computer applications can send messages (or datagrams) to other hosts on an Internet Protocol (IP) network. Prior communications are not required to set up transmission channels or data paths. Applications that don&#39;t require reliable data stream service can use UDP, which provides a connectionless datagram service that emphasizes reduced latency over reliability. If you do require reliable data stream service, use TCP instead.</p> </li> </ul></p>
-    #[serde(rename = "protocol")]
+impl AsRef<str> for InstanceHealthReason {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for InstanceHealthReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for InstanceHealthReason {
+    fn from(value: &'a str) -> InstanceHealthReason {
+        match value {
+            "Lb.RegistrationInProgress" => InstanceHealthReason::LbRegistrationInProgress,
+            "Lb.InitialHealthChecking" => InstanceHealthReason::LbInitialHealthChecking,
+            "Lb.InternalError" => InstanceHealthReason::LbInternalError,
+            "Instance.ResponseCodeMismatch" => InstanceHealthReason::InstanceResponseCodeMismatch,
+            "Instance.Timeout" => InstanceHealthReason::InstanceTimeout,
+            "Instance.FailedHealthChecks" => InstanceHealthReason::InstanceFailedHealthChecks,
+            "Instance.NotRegistered" => InstanceHealthReason::InstanceNotRegistered,
+            "Instance.NotInUse" => InstanceHealthReason::InstanceNotInUse,
+            "Instance.DeregistrationInProgress" => {
+                InstanceHealthReason::InstanceDeregistrationInProgress
+            }
+            "Instance.InvalidState" => InstanceHealthReason::InstanceInvalidState,
+            "Instance.IpUnusable" => InstanceHealthReason::InstanceIpUnusable,
+            _ => InstanceHealthReason::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InstanceHealthReason {
+    fn from(value: String) -> InstanceHealthReason {
+        match value.as_str() {
+            "Lb.RegistrationInProgress" => InstanceHealthReason::LbRegistrationInProgress,
+            "Lb.InitialHealthChecking" => InstanceHealthReason::LbInitialHealthChecking,
+            "Lb.InternalError" => InstanceHealthReason::LbInternalError,
+            "Instance.ResponseCodeMismatch" => InstanceHealthReason::InstanceResponseCodeMismatch,
+            "Instance.Timeout" => InstanceHealthReason::InstanceTimeout,
+            "Instance.FailedHealthChecks" => InstanceHealthReason::InstanceFailedHealthChecks,
+            "Instance.NotRegistered" => InstanceHealthReason::InstanceNotRegistered,
+            "Instance.NotInUse" => InstanceHealthReason::InstanceNotInUse,
+            "Instance.DeregistrationInProgress" => {
+                InstanceHealthReason::InstanceDeregistrationInProgress
+            }
+            "Instance.InvalidState" => InstanceHealthReason::InstanceInvalidState,
+            "Instance.IpUnusable" => InstanceHealthReason::InstanceIpUnusable,
+            _ => InstanceHealthReason::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for InstanceHealthReason {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<InstanceHealthReason, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for InstanceHealthReason {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for InstanceHealthReason {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InstanceHealthReason {
+    fn deserialize<D>(deserializer: D) -> Result<InstanceHealthReason, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum InstanceHealthState {
+    /// `initial`
+    Initial,
+    /// `healthy`
+    Healthy,
+    /// `unhealthy`
+    Unhealthy,
+    /// `unused`
+    Unused,
+    /// `draining`
+    Draining,
+    /// `unavailable`
+    Unavailable,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl InstanceHealthState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InstanceHealthState::Initial => "initial",
+            InstanceHealthState::Healthy => "healthy",
+            InstanceHealthState::Unhealthy => "unhealthy",
+            InstanceHealthState::Unused => "unused",
+            InstanceHealthState::Draining => "draining",
+            InstanceHealthState::Unavailable => "unavailable",
+            InstanceHealthState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for InstanceHealthState {
+    fn default() -> InstanceHealthState {
+        InstanceHealthState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for InstanceHealthState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for InstanceHealthState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for InstanceHealthState {
+    fn from(value: &'a str) -> InstanceHealthState {
+        match value {
+            "initial" => InstanceHealthState::Initial,
+            "healthy" => InstanceHealthState::Healthy,
+            "unhealthy" => InstanceHealthState::Unhealthy,
+            "unused" => InstanceHealthState::Unused,
+            "draining" => InstanceHealthState::Draining,
+            "unavailable" => InstanceHealthState::Unavailable,
+            _ => InstanceHealthState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InstanceHealthState {
+    fn from(value: String) -> InstanceHealthState {
+        match value.as_str() {
+            "initial" => InstanceHealthState::Initial,
+            "healthy" => InstanceHealthState::Healthy,
+            "unhealthy" => InstanceHealthState::Unhealthy,
+            "unused" => InstanceHealthState::Unused,
+            "draining" => InstanceHealthState::Draining,
+            "unavailable" => InstanceHealthState::Unavailable,
+            _ => InstanceHealthState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for InstanceHealthState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<InstanceHealthState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for InstanceHealthState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for InstanceHealthState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InstanceHealthState {
+    fn deserialize<D>(deserializer: D) -> Result<InstanceHealthState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Describes information about the health of the instance.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct InstanceHealthSummary {
+    /// <p>Describes the overall instance health. Valid values are below.</p>
+    #[serde(rename = "instanceHealth")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instance_health: Option<InstanceHealthState>,
+    /// <p><p>More information about the instance health. If the <code>instanceHealth</code> is <code>healthy</code>, then an <code>instanceHealthReason</code> value is not provided.</p> <p>If <b> <code>instanceHealth</code> </b> is <code>initial</code>, the <b> <code>instanceHealthReason</code> </b> value can be one of the following:</p> <ul> <li> <p> <b> <code>Lb.RegistrationInProgress</code> </b> - The target instance is in the process of being registered with the load balancer.</p> </li> <li> <p> <b> <code>Lb.InitialHealthChecking</code> </b> - The Lightsail load balancer is still sending the target instance the minimum number of health checks required to determine its health status.</p> </li> </ul> <p>If <b> <code>instanceHealth</code> </b> is <code>unhealthy</code>, the <b> <code>instanceHealthReason</code> </b> value can be one of the following:</p> <ul> <li> <p> <b> <code>Instance.ResponseCodeMismatch</code> </b> - The health checks did not return an expected HTTP code.</p> </li> <li> <p> <b> <code>Instance.Timeout</code> </b> - The health check requests timed out.</p> </li> <li> <p> <b> <code>Instance.FailedHealthChecks</code> </b> - The health checks failed because the connection to the target instance timed out, the target instance response was malformed, or the target instance failed the health check for an unknown reason.</p> </li> <li> <p> <b> <code>Lb.InternalError</code> </b> - The health checks failed due to an internal error.</p> </li> </ul> <p>If <b> <code>instanceHealth</code> </b> is <code>unused</code>, the <b> <code>instanceHealthReason</code> </b> value can be one of the following:</p> <ul> <li> <p> <b> <code>Instance.NotRegistered</code> </b> - The target instance is not registered with the target group.</p> </li> <li> <p> <b> <code>Instance.NotInUse</code> </b> - The target group is not used by any load balancer, or the target instance is in an Availability Zone that is not enabled for its load balancer.</p> </li> <li> <p> <b> <code>Instance.IpUnusable</code> </b> - The target IP address is reserved for use by a Lightsail load balancer.</p> </li> <li> <p> <b> <code>Instance.InvalidState</code> </b> - The target is in the stopped or terminated state.</p> </li> </ul> <p>If <b> <code>instanceHealth</code> </b> is <code>draining</code>, the <b> <code>instanceHealthReason</code> </b> value can be one of the following:</p> <ul> <li> <p> <b> <code>Instance.DeregistrationInProgress</code> </b> - The target instance is in the process of being deregistered and the deregistration delay period has not expired.</p> </li> </ul></p>
+    #[serde(rename = "instanceHealthReason")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instance_health_reason: Option<InstanceHealthReason>,
+    /// <p>The name of the Lightsail instance for which you are requesting health check data.</p>
+    #[serde(rename = "instanceName")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instance_name: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum InstanceMetricName {
+    /// `CPUUtilization`
+    CPUUtilization,
+    /// `NetworkIn`
+    NetworkIn,
+    /// `NetworkOut`
+    NetworkOut,
+    /// `StatusCheckFailed`
+    StatusCheckFailed,
+    /// `StatusCheckFailed_Instance`
+    StatusCheckFailedInstance,
+    /// `StatusCheckFailed_System`
+    StatusCheckFailedSystem,
+    /// `BurstCapacityTime`
+    BurstCapacityTime,
+    /// `BurstCapacityPercentage`
+    BurstCapacityPercentage,
+    /// `MetadataNoToken`
+    MetadataNoToken,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl InstanceMetricName {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InstanceMetricName::CPUUtilization => "CPUUtilization",
+            InstanceMetricName::NetworkIn => "NetworkIn",
+            InstanceMetricName::NetworkOut => "NetworkOut",
+            InstanceMetricName::StatusCheckFailed => "StatusCheckFailed",
+            InstanceMetricName::StatusCheckFailedInstance => "StatusCheckFailed_Instance",
+            InstanceMetricName::StatusCheckFailedSystem => "StatusCheckFailed_System",
+            InstanceMetricName::BurstCapacityTime => "BurstCapacityTime",
+            InstanceMetricName::BurstCapacityPercentage => "BurstCapacityPercentage",
+            InstanceMetricName::MetadataNoToken => "MetadataNoToken",
+            InstanceMetricName::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for InstanceMetricName {
+    fn default() -> InstanceMetricName {
+        InstanceMetricName::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for InstanceMetricName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for InstanceMetricName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for InstanceMetricName {
+    fn from(value: &'a str) -> InstanceMetricName {
+        match value {
+            "CPUUtilization" => InstanceMetricName::CPUUtilization,
+            "NetworkIn" => InstanceMetricName::NetworkIn,
+            "NetworkOut" => InstanceMetricName::NetworkOut,
+            "StatusCheckFailed" => InstanceMetricName::StatusCheckFailed,
+            "StatusCheckFailed_Instance" => InstanceMetricName::StatusCheckFailedInstance,
+            "StatusCheckFailed_System" => InstanceMetricName::StatusCheckFailedSystem,
+            "BurstCapacityTime" => InstanceMetricName::BurstCapacityTime,
+            "BurstCapacityPercentage" => InstanceMetricName::BurstCapacityPercentage,
+            "MetadataNoToken" => InstanceMetricName::MetadataNoToken,
+            _ => InstanceMetricName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InstanceMetricName {
+    fn from(value: String) -> InstanceMetricName {
+        match value.as_str() {
+            "CPUUtilization" => InstanceMetricName::CPUUtilization,
+            "NetworkIn" => InstanceMetricName::NetworkIn,
+            "NetworkOut" => InstanceMetricName::NetworkOut,
+            "StatusCheckFailed" => InstanceMetricName::StatusCheckFailed,
+            "StatusCheckFailed_Instance" => InstanceMetricName::StatusCheckFailedInstance,
+            "StatusCheckFailed_System" => InstanceMetricName::StatusCheckFailedSystem,
+            "BurstCapacityTime" => InstanceMetricName::BurstCapacityTime,
+            "BurstCapacityPercentage" => InstanceMetricName::BurstCapacityPercentage,
+            "MetadataNoToken" => InstanceMetricName::MetadataNoToken,
+            _ => InstanceMetricName::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for InstanceMetricName {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<InstanceMetricName, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for InstanceMetricName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for InstanceMetricName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InstanceMetricName {
+    fn deserialize<D>(deserializer: D) -> Result<InstanceMetricName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Describes monthly data transfer rates and port information for an instance.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct InstanceNetworking {
+    /// <p>The amount of data in GB allocated for monthly data transfers.</p>
+    #[serde(rename = "monthlyTransfer")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub monthly_transfer: Option<MonthlyTransfer>,
+    /// <p>An array of key-value pairs containing information about the ports on the instance.</p>
+    #[serde(rename = "ports")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub protocol: Option<String>,
+    pub ports: Option<Vec<InstancePortInfo>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum InstancePlatform {
+    /// `LINUX_UNIX`
+    LinuxUnix,
+    /// `WINDOWS`
+    Windows,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl InstancePlatform {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InstancePlatform::LinuxUnix => "LINUX_UNIX",
+            InstancePlatform::Windows => "WINDOWS",
+            InstancePlatform::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for InstancePlatform {
+    fn default() -> InstancePlatform {
+        InstancePlatform::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for InstancePlatform {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for InstancePlatform {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for InstancePlatform {
+    fn from(value: &'a str) -> InstancePlatform {
+        match value {
+            "LINUX_UNIX" => InstancePlatform::LinuxUnix,
+            "WINDOWS" => InstancePlatform::Windows,
+            _ => InstancePlatform::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InstancePlatform {
+    fn from(value: String) -> InstancePlatform {
+        match value.as_str() {
+            "LINUX_UNIX" => InstancePlatform::LinuxUnix,
+            "WINDOWS" => InstancePlatform::Windows,
+            _ => InstancePlatform::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for InstancePlatform {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<InstancePlatform, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for InstancePlatform {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for InstancePlatform {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InstancePlatform {
+    fn deserialize<D>(deserializer: D) -> Result<InstancePlatform, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Describes information about the instance ports.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct InstancePortInfo {
+    /// <p>The access direction (<code>inbound</code> or <code>outbound</code>).</p>
+    #[serde(rename = "accessDirection")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub access_direction: Option<AccessDirection>,
+    /// <p>The location from which access is allowed (e.g., <code>Anywhere (0.0.0.0/0)</code>).</p>
+    #[serde(rename = "accessFrom")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub access_from: Option<String>,
+    /// <p>The type of access (<code>Public</code> or <code>Private</code>).</p>
+    #[serde(rename = "accessType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub access_type: Option<PortAccessType>,
+    /// <p>The common name.</p>
+    #[serde(rename = "commonName")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub common_name: Option<String>,
+    /// <p>The first port in the range.</p>
+    #[serde(rename = "fromPort")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_port: Option<i64>,
+    /// <p><p>The protocol being used. Can be one of the following.</p> <ul> <li> <p> <code>tcp</code> - Transmission Control Protocol (TCP) provides reliable, ordered, and error-checked delivery of streamed data between applications running on hosts communicating by an IP network. If you have an application that doesn&#39;t require reliable data stream service, use UDP instead.</p> </li> <li> <p> <code>all</code> - All transport layer protocol types. For more general information, see <a href="https://en.wikipedia.org/wiki/Transport_layer">Transport layer</a> on Wikipedia.</p> </li> <li> <p> <code>udp</code> - With User Datagram Protocol (UDP), computer applications can send messages (or datagrams) to other hosts on an Internet Protocol (IP) network. Prior communications are not required to set up transmission channels or data paths. Applications that don&#39;t require reliable data stream service can use UDP, which provides a connectionless datagram service that emphasizes reduced latency over reliability. If you do require reliable data stream service, use TCP instead.</p> </li> </ul></p>
+    #[serde(rename = "protocol")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protocol: Option<NetworkProtocol>,
     /// <p>The last port in the range.</p>
     #[serde(rename = "toPort")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2959,11 +4414,11 @@ pub struct InstancePortState {
     /// <p><p>The protocol being used. Can be one of the following.</p> <ul> <li> <p> <code>tcp</code> - Transmission Control Protocol (TCP) provides reliable, ordered, and error-checked delivery of streamed data between applications running on hosts communicating by an IP network. If you have an application that doesn&#39;t require reliable data stream service, use UDP instead.</p> </li> <li> <p> <code>all</code> - All transport layer protocol types. For more general information, see <a href="https://en.wikipedia.org/wiki/Transport_layer">Transport layer</a> on Wikipedia.</p> </li> <li> <p> <code>udp</code> - With User Datagram Protocol (UDP), computer applications can send messages (or datagrams) to other hosts on an Internet Protocol (IP) network. Prior communications are not required to set up transmission channels or data paths. Applications that don&#39;t require reliable data stream service can use UDP, which provides a connectionless datagram service that emphasizes reduced latency over reliability. If you do require reliable data stream service, use TCP instead.</p> </li> </ul></p>
     #[serde(rename = "protocol")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub protocol: Option<String>,
+    pub protocol: Option<NetworkProtocol>,
     /// <p>Specifies whether the instance port is <code>open</code> or <code>closed</code>.</p>
     #[serde(rename = "state")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<PortState>,
     /// <p>The last port in the range.</p>
     #[serde(rename = "toPort")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2980,8 +4435,13 @@ pub struct InstanceSnapshot {
     pub arn: Option<String>,
     /// <p>The timestamp when the snapshot was created (e.g., <code>1479907467.024</code>).</p>
     #[serde(rename = "createdAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>An array of disk objects containing information about all block storage disks.</p>
     #[serde(rename = "fromAttachedDisks")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3021,7 +4481,7 @@ pub struct InstanceSnapshot {
     /// <p>The type of resource (usually <code>InstanceSnapshot</code>).</p>
     #[serde(rename = "resourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ResourceType>,
     /// <p>The size in GB of the SSD.</p>
     #[serde(rename = "sizeInGb")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3029,7 +4489,7 @@ pub struct InstanceSnapshot {
     /// <p>The state the snapshot is in.</p>
     #[serde(rename = "state")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<InstanceSnapshotState>,
     /// <p>The support code. Include this code in your email to support when you have questions about an instance or another resource in Lightsail. This code enables our support team to look up your Lightsail information more easily.</p>
     #[serde(rename = "supportCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3058,6 +4518,104 @@ pub struct InstanceSnapshotInfo {
     pub from_disk_info: Option<Vec<DiskInfo>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum InstanceSnapshotState {
+    /// `pending`
+    Pending,
+    /// `error`
+    Error,
+    /// `available`
+    Available,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl InstanceSnapshotState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InstanceSnapshotState::Pending => "pending",
+            InstanceSnapshotState::Error => "error",
+            InstanceSnapshotState::Available => "available",
+            InstanceSnapshotState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for InstanceSnapshotState {
+    fn default() -> InstanceSnapshotState {
+        InstanceSnapshotState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for InstanceSnapshotState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for InstanceSnapshotState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for InstanceSnapshotState {
+    fn from(value: &'a str) -> InstanceSnapshotState {
+        match value {
+            "pending" => InstanceSnapshotState::Pending,
+            "error" => InstanceSnapshotState::Error,
+            "available" => InstanceSnapshotState::Available,
+            _ => InstanceSnapshotState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InstanceSnapshotState {
+    fn from(value: String) -> InstanceSnapshotState {
+        match value.as_str() {
+            "pending" => InstanceSnapshotState::Pending,
+            "error" => InstanceSnapshotState::Error,
+            "available" => InstanceSnapshotState::Available,
+            _ => InstanceSnapshotState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for InstanceSnapshotState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<InstanceSnapshotState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for InstanceSnapshotState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for InstanceSnapshotState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InstanceSnapshotState {
+    fn deserialize<D>(deserializer: D) -> Result<InstanceSnapshotState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes the virtual private server (or <i>instance</i>) status.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -3095,8 +4653,13 @@ pub struct KeyPair {
     pub arn: Option<String>,
     /// <p>The timestamp when the key pair was created (e.g., <code>1479816991.349</code>).</p>
     #[serde(rename = "createdAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The RSA fingerprint of the key pair.</p>
     #[serde(rename = "fingerprint")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3112,7 +4675,7 @@ pub struct KeyPair {
     /// <p>The resource type (usually <code>KeyPair</code>).</p>
     #[serde(rename = "resourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ResourceType>,
     /// <p>The support code. Include this code in your email to support when you have questions about an instance or another resource in Lightsail. This code enables our support team to look up your Lightsail information more easily.</p>
     #[serde(rename = "supportCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3134,11 +4697,17 @@ pub struct LoadBalancer {
     /// <p>A string to string map of the configuration options for your load balancer. Valid values are listed below.</p>
     #[serde(rename = "configurationOptions")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub configuration_options: Option<::std::collections::HashMap<String, String>>,
+    pub configuration_options:
+        Option<::std::collections::HashMap<LoadBalancerAttributeName, String>>,
     /// <p>The date when your load balancer was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The DNS name of your Lightsail load balancer.</p>
     #[serde(rename = "dnsName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3166,7 +4735,7 @@ pub struct LoadBalancer {
     /// <p>The protocol you have enabled for your load balancer. Valid values are below.</p> <p>You can't just have <code>HTTP_HTTPS</code>, but you can have just <code>HTTP</code>.</p>
     #[serde(rename = "protocol")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub protocol: Option<String>,
+    pub protocol: Option<LoadBalancerProtocol>,
     /// <p>An array of public port settings for your load balancer. For HTTP, use port 80. For HTTPS, use port 443.</p>
     #[serde(rename = "publicPorts")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3174,11 +4743,11 @@ pub struct LoadBalancer {
     /// <p>The resource type (e.g., <code>LoadBalancer</code>.</p>
     #[serde(rename = "resourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ResourceType>,
     /// <p>The status of your load balancer. Valid values are below.</p>
     #[serde(rename = "state")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<LoadBalancerState>,
     /// <p>The support code. Include this code in your email to support when you have questions about your Lightsail load balancer. This code enables our support team to look up your Lightsail information more easily.</p>
     #[serde(rename = "supportCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3193,22 +4762,491 @@ pub struct LoadBalancer {
     pub tls_certificate_summaries: Option<Vec<LoadBalancerTlsCertificateSummary>>,
 }
 
-/// <p>Describes a load balancer SSL/TLS certificate.</p> <p>TLS is just an updated, more secure version of Secure Socket Layer (SSL).</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct LoadBalancerTlsCertificate {
-    /// <p>The Amazon Resource Name (ARN) of the SSL/TLS certificate.</p>
-    #[serde(rename = "arn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub arn: Option<String>,
-    /// <p>The time when you created your SSL/TLS certificate.</p>
-    #[serde(rename = "createdAt")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
-    /// <p>The domain name for your SSL/TLS certificate.</p>
-    #[serde(rename = "domainName")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub domain_name: Option<String>,
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LoadBalancerAttributeName {
+    /// `HealthCheckPath`
+    HealthCheckPath,
+    /// `SessionStickinessEnabled`
+    SessionStickinessEnabled,
+    /// `SessionStickiness_LB_CookieDurationSeconds`
+    SessionStickinessLbCookieDurationSeconds,
+    /// `HttpsRedirectionEnabled`
+    HttpsRedirectionEnabled,
+    /// `TlsPolicyName`
+    TlsPolicyName,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LoadBalancerAttributeName {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LoadBalancerAttributeName::HealthCheckPath => "HealthCheckPath",
+            LoadBalancerAttributeName::SessionStickinessEnabled => "SessionStickinessEnabled",
+            LoadBalancerAttributeName::SessionStickinessLbCookieDurationSeconds => {
+                "SessionStickiness_LB_CookieDurationSeconds"
+            }
+            LoadBalancerAttributeName::HttpsRedirectionEnabled => "HttpsRedirectionEnabled",
+            LoadBalancerAttributeName::TlsPolicyName => "TlsPolicyName",
+            LoadBalancerAttributeName::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LoadBalancerAttributeName {
+    fn default() -> LoadBalancerAttributeName {
+        LoadBalancerAttributeName::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LoadBalancerAttributeName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LoadBalancerAttributeName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LoadBalancerAttributeName {
+    fn from(value: &'a str) -> LoadBalancerAttributeName {
+        match value {
+            "HealthCheckPath" => LoadBalancerAttributeName::HealthCheckPath,
+            "SessionStickinessEnabled" => LoadBalancerAttributeName::SessionStickinessEnabled,
+            "SessionStickiness_LB_CookieDurationSeconds" => {
+                LoadBalancerAttributeName::SessionStickinessLbCookieDurationSeconds
+            }
+            "HttpsRedirectionEnabled" => LoadBalancerAttributeName::HttpsRedirectionEnabled,
+            "TlsPolicyName" => LoadBalancerAttributeName::TlsPolicyName,
+            _ => LoadBalancerAttributeName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LoadBalancerAttributeName {
+    fn from(value: String) -> LoadBalancerAttributeName {
+        match value.as_str() {
+            "HealthCheckPath" => LoadBalancerAttributeName::HealthCheckPath,
+            "SessionStickinessEnabled" => LoadBalancerAttributeName::SessionStickinessEnabled,
+            "SessionStickiness_LB_CookieDurationSeconds" => {
+                LoadBalancerAttributeName::SessionStickinessLbCookieDurationSeconds
+            }
+            "HttpsRedirectionEnabled" => LoadBalancerAttributeName::HttpsRedirectionEnabled,
+            "TlsPolicyName" => LoadBalancerAttributeName::TlsPolicyName,
+            _ => LoadBalancerAttributeName::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LoadBalancerAttributeName {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LoadBalancerAttributeName, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LoadBalancerAttributeName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LoadBalancerAttributeName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LoadBalancerAttributeName {
+    fn deserialize<D>(deserializer: D) -> Result<LoadBalancerAttributeName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LoadBalancerMetricName {
+    /// `ClientTLSNegotiationErrorCount`
+    ClientTLSNegotiationErrorCount,
+    /// `HealthyHostCount`
+    HealthyHostCount,
+    /// `UnhealthyHostCount`
+    UnhealthyHostCount,
+    /// `HTTPCode_LB_4XX_Count`
+    HTTPCodeLb4xxCount,
+    /// `HTTPCode_LB_5XX_Count`
+    HTTPCodeLb5xxCount,
+    /// `HTTPCode_Instance_2XX_Count`
+    HTTPCodeInstance2xxCount,
+    /// `HTTPCode_Instance_3XX_Count`
+    HTTPCodeInstance3xxCount,
+    /// `HTTPCode_Instance_4XX_Count`
+    HTTPCodeInstance4xxCount,
+    /// `HTTPCode_Instance_5XX_Count`
+    HTTPCodeInstance5xxCount,
+    /// `InstanceResponseTime`
+    InstanceResponseTime,
+    /// `RejectedConnectionCount`
+    RejectedConnectionCount,
+    /// `RequestCount`
+    RequestCount,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LoadBalancerMetricName {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LoadBalancerMetricName::ClientTLSNegotiationErrorCount => {
+                "ClientTLSNegotiationErrorCount"
+            }
+            LoadBalancerMetricName::HealthyHostCount => "HealthyHostCount",
+            LoadBalancerMetricName::UnhealthyHostCount => "UnhealthyHostCount",
+            LoadBalancerMetricName::HTTPCodeLb4xxCount => "HTTPCode_LB_4XX_Count",
+            LoadBalancerMetricName::HTTPCodeLb5xxCount => "HTTPCode_LB_5XX_Count",
+            LoadBalancerMetricName::HTTPCodeInstance2xxCount => "HTTPCode_Instance_2XX_Count",
+            LoadBalancerMetricName::HTTPCodeInstance3xxCount => "HTTPCode_Instance_3XX_Count",
+            LoadBalancerMetricName::HTTPCodeInstance4xxCount => "HTTPCode_Instance_4XX_Count",
+            LoadBalancerMetricName::HTTPCodeInstance5xxCount => "HTTPCode_Instance_5XX_Count",
+            LoadBalancerMetricName::InstanceResponseTime => "InstanceResponseTime",
+            LoadBalancerMetricName::RejectedConnectionCount => "RejectedConnectionCount",
+            LoadBalancerMetricName::RequestCount => "RequestCount",
+            LoadBalancerMetricName::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LoadBalancerMetricName {
+    fn default() -> LoadBalancerMetricName {
+        LoadBalancerMetricName::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LoadBalancerMetricName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LoadBalancerMetricName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LoadBalancerMetricName {
+    fn from(value: &'a str) -> LoadBalancerMetricName {
+        match value {
+            "ClientTLSNegotiationErrorCount" => {
+                LoadBalancerMetricName::ClientTLSNegotiationErrorCount
+            }
+            "HealthyHostCount" => LoadBalancerMetricName::HealthyHostCount,
+            "UnhealthyHostCount" => LoadBalancerMetricName::UnhealthyHostCount,
+            "HTTPCode_LB_4XX_Count" => LoadBalancerMetricName::HTTPCodeLb4xxCount,
+            "HTTPCode_LB_5XX_Count" => LoadBalancerMetricName::HTTPCodeLb5xxCount,
+            "HTTPCode_Instance_2XX_Count" => LoadBalancerMetricName::HTTPCodeInstance2xxCount,
+            "HTTPCode_Instance_3XX_Count" => LoadBalancerMetricName::HTTPCodeInstance3xxCount,
+            "HTTPCode_Instance_4XX_Count" => LoadBalancerMetricName::HTTPCodeInstance4xxCount,
+            "HTTPCode_Instance_5XX_Count" => LoadBalancerMetricName::HTTPCodeInstance5xxCount,
+            "InstanceResponseTime" => LoadBalancerMetricName::InstanceResponseTime,
+            "RejectedConnectionCount" => LoadBalancerMetricName::RejectedConnectionCount,
+            "RequestCount" => LoadBalancerMetricName::RequestCount,
+            _ => LoadBalancerMetricName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LoadBalancerMetricName {
+    fn from(value: String) -> LoadBalancerMetricName {
+        match value.as_str() {
+            "ClientTLSNegotiationErrorCount" => {
+                LoadBalancerMetricName::ClientTLSNegotiationErrorCount
+            }
+            "HealthyHostCount" => LoadBalancerMetricName::HealthyHostCount,
+            "UnhealthyHostCount" => LoadBalancerMetricName::UnhealthyHostCount,
+            "HTTPCode_LB_4XX_Count" => LoadBalancerMetricName::HTTPCodeLb4xxCount,
+            "HTTPCode_LB_5XX_Count" => LoadBalancerMetricName::HTTPCodeLb5xxCount,
+            "HTTPCode_Instance_2XX_Count" => LoadBalancerMetricName::HTTPCodeInstance2xxCount,
+            "HTTPCode_Instance_3XX_Count" => LoadBalancerMetricName::HTTPCodeInstance3xxCount,
+            "HTTPCode_Instance_4XX_Count" => LoadBalancerMetricName::HTTPCodeInstance4xxCount,
+            "HTTPCode_Instance_5XX_Count" => LoadBalancerMetricName::HTTPCodeInstance5xxCount,
+            "InstanceResponseTime" => LoadBalancerMetricName::InstanceResponseTime,
+            "RejectedConnectionCount" => LoadBalancerMetricName::RejectedConnectionCount,
+            "RequestCount" => LoadBalancerMetricName::RequestCount,
+            _ => LoadBalancerMetricName::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LoadBalancerMetricName {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LoadBalancerMetricName, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LoadBalancerMetricName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LoadBalancerMetricName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LoadBalancerMetricName {
+    fn deserialize<D>(deserializer: D) -> Result<LoadBalancerMetricName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LoadBalancerProtocol {
+    /// `HTTP_HTTPS`
+    HttpHttps,
+    /// `HTTP`
+    Http,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LoadBalancerProtocol {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LoadBalancerProtocol::HttpHttps => "HTTP_HTTPS",
+            LoadBalancerProtocol::Http => "HTTP",
+            LoadBalancerProtocol::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LoadBalancerProtocol {
+    fn default() -> LoadBalancerProtocol {
+        LoadBalancerProtocol::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LoadBalancerProtocol {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LoadBalancerProtocol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LoadBalancerProtocol {
+    fn from(value: &'a str) -> LoadBalancerProtocol {
+        match value {
+            "HTTP_HTTPS" => LoadBalancerProtocol::HttpHttps,
+            "HTTP" => LoadBalancerProtocol::Http,
+            _ => LoadBalancerProtocol::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LoadBalancerProtocol {
+    fn from(value: String) -> LoadBalancerProtocol {
+        match value.as_str() {
+            "HTTP_HTTPS" => LoadBalancerProtocol::HttpHttps,
+            "HTTP" => LoadBalancerProtocol::Http,
+            _ => LoadBalancerProtocol::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LoadBalancerProtocol {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LoadBalancerProtocol, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LoadBalancerProtocol {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LoadBalancerProtocol {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LoadBalancerProtocol {
+    fn deserialize<D>(deserializer: D) -> Result<LoadBalancerProtocol, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LoadBalancerState {
+    /// `active`
+    Active,
+    /// `provisioning`
+    Provisioning,
+    /// `active_impaired`
+    ActiveImpaired,
+    /// `failed`
+    Failed,
+    /// `unknown`
+    UnknownValue,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LoadBalancerState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LoadBalancerState::Active => "active",
+            LoadBalancerState::Provisioning => "provisioning",
+            LoadBalancerState::ActiveImpaired => "active_impaired",
+            LoadBalancerState::Failed => "failed",
+            LoadBalancerState::UnknownValue => "unknown",
+            LoadBalancerState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LoadBalancerState {
+    fn default() -> LoadBalancerState {
+        LoadBalancerState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LoadBalancerState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LoadBalancerState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LoadBalancerState {
+    fn from(value: &'a str) -> LoadBalancerState {
+        match value {
+            "active" => LoadBalancerState::Active,
+            "provisioning" => LoadBalancerState::Provisioning,
+            "active_impaired" => LoadBalancerState::ActiveImpaired,
+            "failed" => LoadBalancerState::Failed,
+            "unknown" => LoadBalancerState::UnknownValue,
+            _ => LoadBalancerState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LoadBalancerState {
+    fn from(value: String) -> LoadBalancerState {
+        match value.as_str() {
+            "active" => LoadBalancerState::Active,
+            "provisioning" => LoadBalancerState::Provisioning,
+            "active_impaired" => LoadBalancerState::ActiveImpaired,
+            "failed" => LoadBalancerState::Failed,
+            "unknown" => LoadBalancerState::UnknownValue,
+            _ => LoadBalancerState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LoadBalancerState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LoadBalancerState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LoadBalancerState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LoadBalancerState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LoadBalancerState {
+    fn deserialize<D>(deserializer: D) -> Result<LoadBalancerState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Describes a load balancer SSL/TLS certificate.</p> <p>TLS is just an updated, more secure version of Secure Socket Layer (SSL).</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct LoadBalancerTlsCertificate {
+    /// <p>The Amazon Resource Name (ARN) of the SSL/TLS certificate.</p>
+    #[serde(rename = "arn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub arn: Option<String>,
+    /// <p>The time when you created your SSL/TLS certificate.</p>
+    #[serde(rename = "createdAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
+    /// <p>The domain name for your SSL/TLS certificate.</p>
+    #[serde(rename = "domainName")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub domain_name: Option<String>,
     /// <p>An array of LoadBalancerTlsCertificateDomainValidationRecord objects describing the records.</p>
     #[serde(rename = "domainValidationRecords")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3216,15 +5254,20 @@ pub struct LoadBalancerTlsCertificate {
     /// <p>The reason for the SSL/TLS certificate validation failure.</p>
     #[serde(rename = "failureReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub failure_reason: Option<String>,
+    pub failure_reason: Option<LoadBalancerTlsCertificateFailureReason>,
     /// <p>When <code>true</code>, the SSL/TLS certificate is attached to the Lightsail load balancer.</p>
     #[serde(rename = "isAttached")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub is_attached: Option<bool>,
     /// <p>The time when the SSL/TLS certificate was issued.</p>
     #[serde(rename = "issuedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub issued_at: Option<f64>,
+    pub issued_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The issuer of the certificate.</p>
     #[serde(rename = "issuer")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3247,12 +5290,22 @@ pub struct LoadBalancerTlsCertificate {
     pub name: Option<String>,
     /// <p>The timestamp when the SSL/TLS certificate expires.</p>
     #[serde(rename = "notAfter")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub not_after: Option<f64>,
+    pub not_after: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The timestamp when the SSL/TLS certificate is first valid.</p>
     #[serde(rename = "notBefore")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub not_before: Option<f64>,
+    pub not_before: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>An object containing information about the status of Lightsail's managed renewal for the certificate.</p>
     #[serde(rename = "renewalSummary")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3260,15 +5313,20 @@ pub struct LoadBalancerTlsCertificate {
     /// <p><p>The resource type (e.g., <code>LoadBalancerTlsCertificate</code>).</p> <ul> <li> <p> <b> <code>Instance</code> </b> - A Lightsail instance (a virtual private server)</p> </li> <li> <p> <b> <code>StaticIp</code> </b> - A static IP address</p> </li> <li> <p> <b> <code>KeyPair</code> </b> - The key pair used to connect to a Lightsail instance</p> </li> <li> <p> <b> <code>InstanceSnapshot</code> </b> - A Lightsail instance snapshot</p> </li> <li> <p> <b> <code>Domain</code> </b> - A DNS zone</p> </li> <li> <p> <b> <code>PeeredVpc</code> </b> - A peered VPC</p> </li> <li> <p> <b> <code>LoadBalancer</code> </b> - A Lightsail load balancer</p> </li> <li> <p> <b> <code>LoadBalancerTlsCertificate</code> </b> - An SSL/TLS certificate associated with a Lightsail load balancer</p> </li> <li> <p> <b> <code>Disk</code> </b> - A Lightsail block storage disk</p> </li> <li> <p> <b> <code>DiskSnapshot</code> </b> - A block storage disk snapshot</p> </li> </ul></p>
     #[serde(rename = "resourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ResourceType>,
     /// <p>The reason the certificate was revoked. Valid values are below.</p>
     #[serde(rename = "revocationReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub revocation_reason: Option<String>,
+    pub revocation_reason: Option<LoadBalancerTlsCertificateRevocationReason>,
     /// <p>The timestamp when the SSL/TLS certificate was revoked.</p>
     #[serde(rename = "revokedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub revoked_at: Option<f64>,
+    pub revoked_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The serial number of the certificate.</p>
     #[serde(rename = "serial")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3280,7 +5338,7 @@ pub struct LoadBalancerTlsCertificate {
     /// <p>The status of the SSL/TLS certificate. Valid values are below.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<LoadBalancerTlsCertificateStatus>,
     /// <p>The name of the entity that is associated with the public key contained in the certificate.</p>
     #[serde(rename = "subject")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3299,6 +5357,104 @@ pub struct LoadBalancerTlsCertificate {
     pub tags: Option<Vec<Tag>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LoadBalancerTlsCertificateDomainStatus {
+    /// `PENDING_VALIDATION`
+    PendingValidation,
+    /// `FAILED`
+    Failed,
+    /// `SUCCESS`
+    Success,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LoadBalancerTlsCertificateDomainStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LoadBalancerTlsCertificateDomainStatus::PendingValidation => "PENDING_VALIDATION",
+            LoadBalancerTlsCertificateDomainStatus::Failed => "FAILED",
+            LoadBalancerTlsCertificateDomainStatus::Success => "SUCCESS",
+            LoadBalancerTlsCertificateDomainStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LoadBalancerTlsCertificateDomainStatus {
+    fn default() -> LoadBalancerTlsCertificateDomainStatus {
+        LoadBalancerTlsCertificateDomainStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LoadBalancerTlsCertificateDomainStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LoadBalancerTlsCertificateDomainStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LoadBalancerTlsCertificateDomainStatus {
+    fn from(value: &'a str) -> LoadBalancerTlsCertificateDomainStatus {
+        match value {
+            "PENDING_VALIDATION" => LoadBalancerTlsCertificateDomainStatus::PendingValidation,
+            "FAILED" => LoadBalancerTlsCertificateDomainStatus::Failed,
+            "SUCCESS" => LoadBalancerTlsCertificateDomainStatus::Success,
+            _ => LoadBalancerTlsCertificateDomainStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LoadBalancerTlsCertificateDomainStatus {
+    fn from(value: String) -> LoadBalancerTlsCertificateDomainStatus {
+        match value.as_str() {
+            "PENDING_VALIDATION" => LoadBalancerTlsCertificateDomainStatus::PendingValidation,
+            "FAILED" => LoadBalancerTlsCertificateDomainStatus::Failed,
+            "SUCCESS" => LoadBalancerTlsCertificateDomainStatus::Success,
+            _ => LoadBalancerTlsCertificateDomainStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LoadBalancerTlsCertificateDomainStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LoadBalancerTlsCertificateDomainStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LoadBalancerTlsCertificateDomainStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LoadBalancerTlsCertificateDomainStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LoadBalancerTlsCertificateDomainStatus {
+    fn deserialize<D>(deserializer: D) -> Result<LoadBalancerTlsCertificateDomainStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Contains information about the domain names on an SSL/TLS certificate that you will use to validate domain ownership.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -3310,7 +5466,7 @@ pub struct LoadBalancerTlsCertificateDomainValidationOption {
     /// <p>The status of the domain validation. Valid values are listed below.</p>
     #[serde(rename = "validationStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub validation_status: Option<String>,
+    pub validation_status: Option<LoadBalancerTlsCertificateDomainStatus>,
 }
 
 /// <p>Describes the validation record of each domain name in the SSL/TLS certificate.</p>
@@ -3332,13 +5488,230 @@ pub struct LoadBalancerTlsCertificateDomainValidationRecord {
     /// <p>The validation status. Valid values are listed below.</p>
     #[serde(rename = "validationStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub validation_status: Option<String>,
+    pub validation_status: Option<LoadBalancerTlsCertificateDomainStatus>,
     /// <p>The value for that type.</p>
     #[serde(rename = "value")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LoadBalancerTlsCertificateFailureReason {
+    /// `NO_AVAILABLE_CONTACTS`
+    NoAvailableContacts,
+    /// `ADDITIONAL_VERIFICATION_REQUIRED`
+    AdditionalVerificationRequired,
+    /// `DOMAIN_NOT_ALLOWED`
+    DomainNotAllowed,
+    /// `INVALID_PUBLIC_DOMAIN`
+    InvalidPublicDomain,
+    /// `OTHER`
+    Other,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LoadBalancerTlsCertificateFailureReason {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LoadBalancerTlsCertificateFailureReason::NoAvailableContacts => "NO_AVAILABLE_CONTACTS",
+            LoadBalancerTlsCertificateFailureReason::AdditionalVerificationRequired => {
+                "ADDITIONAL_VERIFICATION_REQUIRED"
+            }
+            LoadBalancerTlsCertificateFailureReason::DomainNotAllowed => "DOMAIN_NOT_ALLOWED",
+            LoadBalancerTlsCertificateFailureReason::InvalidPublicDomain => "INVALID_PUBLIC_DOMAIN",
+            LoadBalancerTlsCertificateFailureReason::Other => "OTHER",
+            LoadBalancerTlsCertificateFailureReason::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LoadBalancerTlsCertificateFailureReason {
+    fn default() -> LoadBalancerTlsCertificateFailureReason {
+        LoadBalancerTlsCertificateFailureReason::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LoadBalancerTlsCertificateFailureReason {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LoadBalancerTlsCertificateFailureReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LoadBalancerTlsCertificateFailureReason {
+    fn from(value: &'a str) -> LoadBalancerTlsCertificateFailureReason {
+        match value {
+            "NO_AVAILABLE_CONTACTS" => LoadBalancerTlsCertificateFailureReason::NoAvailableContacts,
+            "ADDITIONAL_VERIFICATION_REQUIRED" => {
+                LoadBalancerTlsCertificateFailureReason::AdditionalVerificationRequired
+            }
+            "DOMAIN_NOT_ALLOWED" => LoadBalancerTlsCertificateFailureReason::DomainNotAllowed,
+            "INVALID_PUBLIC_DOMAIN" => LoadBalancerTlsCertificateFailureReason::InvalidPublicDomain,
+            "OTHER" => LoadBalancerTlsCertificateFailureReason::Other,
+            _ => LoadBalancerTlsCertificateFailureReason::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LoadBalancerTlsCertificateFailureReason {
+    fn from(value: String) -> LoadBalancerTlsCertificateFailureReason {
+        match value.as_str() {
+            "NO_AVAILABLE_CONTACTS" => LoadBalancerTlsCertificateFailureReason::NoAvailableContacts,
+            "ADDITIONAL_VERIFICATION_REQUIRED" => {
+                LoadBalancerTlsCertificateFailureReason::AdditionalVerificationRequired
+            }
+            "DOMAIN_NOT_ALLOWED" => LoadBalancerTlsCertificateFailureReason::DomainNotAllowed,
+            "INVALID_PUBLIC_DOMAIN" => LoadBalancerTlsCertificateFailureReason::InvalidPublicDomain,
+            "OTHER" => LoadBalancerTlsCertificateFailureReason::Other,
+            _ => LoadBalancerTlsCertificateFailureReason::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LoadBalancerTlsCertificateFailureReason {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LoadBalancerTlsCertificateFailureReason, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LoadBalancerTlsCertificateFailureReason {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LoadBalancerTlsCertificateFailureReason {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LoadBalancerTlsCertificateFailureReason {
+    fn deserialize<D>(deserializer: D) -> Result<LoadBalancerTlsCertificateFailureReason, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LoadBalancerTlsCertificateRenewalStatus {
+    /// `PENDING_AUTO_RENEWAL`
+    PendingAutoRenewal,
+    /// `PENDING_VALIDATION`
+    PendingValidation,
+    /// `SUCCESS`
+    Success,
+    /// `FAILED`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LoadBalancerTlsCertificateRenewalStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LoadBalancerTlsCertificateRenewalStatus::PendingAutoRenewal => "PENDING_AUTO_RENEWAL",
+            LoadBalancerTlsCertificateRenewalStatus::PendingValidation => "PENDING_VALIDATION",
+            LoadBalancerTlsCertificateRenewalStatus::Success => "SUCCESS",
+            LoadBalancerTlsCertificateRenewalStatus::Failed => "FAILED",
+            LoadBalancerTlsCertificateRenewalStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LoadBalancerTlsCertificateRenewalStatus {
+    fn default() -> LoadBalancerTlsCertificateRenewalStatus {
+        LoadBalancerTlsCertificateRenewalStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LoadBalancerTlsCertificateRenewalStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LoadBalancerTlsCertificateRenewalStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LoadBalancerTlsCertificateRenewalStatus {
+    fn from(value: &'a str) -> LoadBalancerTlsCertificateRenewalStatus {
+        match value {
+            "PENDING_AUTO_RENEWAL" => LoadBalancerTlsCertificateRenewalStatus::PendingAutoRenewal,
+            "PENDING_VALIDATION" => LoadBalancerTlsCertificateRenewalStatus::PendingValidation,
+            "SUCCESS" => LoadBalancerTlsCertificateRenewalStatus::Success,
+            "FAILED" => LoadBalancerTlsCertificateRenewalStatus::Failed,
+            _ => LoadBalancerTlsCertificateRenewalStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LoadBalancerTlsCertificateRenewalStatus {
+    fn from(value: String) -> LoadBalancerTlsCertificateRenewalStatus {
+        match value.as_str() {
+            "PENDING_AUTO_RENEWAL" => LoadBalancerTlsCertificateRenewalStatus::PendingAutoRenewal,
+            "PENDING_VALIDATION" => LoadBalancerTlsCertificateRenewalStatus::PendingValidation,
+            "SUCCESS" => LoadBalancerTlsCertificateRenewalStatus::Success,
+            "FAILED" => LoadBalancerTlsCertificateRenewalStatus::Failed,
+            _ => LoadBalancerTlsCertificateRenewalStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LoadBalancerTlsCertificateRenewalStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LoadBalancerTlsCertificateRenewalStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LoadBalancerTlsCertificateRenewalStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LoadBalancerTlsCertificateRenewalStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LoadBalancerTlsCertificateRenewalStatus {
+    fn deserialize<D>(deserializer: D) -> Result<LoadBalancerTlsCertificateRenewalStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Contains information about the status of Lightsail's managed renewal for the certificate.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -3350,9 +5723,273 @@ pub struct LoadBalancerTlsCertificateRenewalSummary {
     /// <p>The status of Lightsail's managed renewal of the certificate. Valid values are listed below.</p>
     #[serde(rename = "renewalStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub renewal_status: Option<String>,
+    pub renewal_status: Option<LoadBalancerTlsCertificateRenewalStatus>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LoadBalancerTlsCertificateRevocationReason {
+    /// `UNSPECIFIED`
+    Unspecified,
+    /// `KEY_COMPROMISE`
+    KeyCompromise,
+    /// `CA_COMPROMISE`
+    CaCompromise,
+    /// `AFFILIATION_CHANGED`
+    AffiliationChanged,
+    /// `SUPERCEDED`
+    Superceded,
+    /// `CESSATION_OF_OPERATION`
+    CessationOfOperation,
+    /// `CERTIFICATE_HOLD`
+    CertificateHold,
+    /// `REMOVE_FROM_CRL`
+    RemoveFromCrl,
+    /// `PRIVILEGE_WITHDRAWN`
+    PrivilegeWithdrawn,
+    /// `A_A_COMPROMISE`
+    AACompromise,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LoadBalancerTlsCertificateRevocationReason {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LoadBalancerTlsCertificateRevocationReason::Unspecified => "UNSPECIFIED",
+            LoadBalancerTlsCertificateRevocationReason::KeyCompromise => "KEY_COMPROMISE",
+            LoadBalancerTlsCertificateRevocationReason::CaCompromise => "CA_COMPROMISE",
+            LoadBalancerTlsCertificateRevocationReason::AffiliationChanged => "AFFILIATION_CHANGED",
+            LoadBalancerTlsCertificateRevocationReason::Superceded => "SUPERCEDED",
+            LoadBalancerTlsCertificateRevocationReason::CessationOfOperation => {
+                "CESSATION_OF_OPERATION"
+            }
+            LoadBalancerTlsCertificateRevocationReason::CertificateHold => "CERTIFICATE_HOLD",
+            LoadBalancerTlsCertificateRevocationReason::RemoveFromCrl => "REMOVE_FROM_CRL",
+            LoadBalancerTlsCertificateRevocationReason::PrivilegeWithdrawn => "PRIVILEGE_WITHDRAWN",
+            LoadBalancerTlsCertificateRevocationReason::AACompromise => "A_A_COMPROMISE",
+            LoadBalancerTlsCertificateRevocationReason::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LoadBalancerTlsCertificateRevocationReason {
+    fn default() -> LoadBalancerTlsCertificateRevocationReason {
+        LoadBalancerTlsCertificateRevocationReason::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LoadBalancerTlsCertificateRevocationReason {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LoadBalancerTlsCertificateRevocationReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LoadBalancerTlsCertificateRevocationReason {
+    fn from(value: &'a str) -> LoadBalancerTlsCertificateRevocationReason {
+        match value {
+            "UNSPECIFIED" => LoadBalancerTlsCertificateRevocationReason::Unspecified,
+            "KEY_COMPROMISE" => LoadBalancerTlsCertificateRevocationReason::KeyCompromise,
+            "CA_COMPROMISE" => LoadBalancerTlsCertificateRevocationReason::CaCompromise,
+            "AFFILIATION_CHANGED" => LoadBalancerTlsCertificateRevocationReason::AffiliationChanged,
+            "SUPERCEDED" => LoadBalancerTlsCertificateRevocationReason::Superceded,
+            "CESSATION_OF_OPERATION" => {
+                LoadBalancerTlsCertificateRevocationReason::CessationOfOperation
+            }
+            "CERTIFICATE_HOLD" => LoadBalancerTlsCertificateRevocationReason::CertificateHold,
+            "REMOVE_FROM_CRL" => LoadBalancerTlsCertificateRevocationReason::RemoveFromCrl,
+            "PRIVILEGE_WITHDRAWN" => LoadBalancerTlsCertificateRevocationReason::PrivilegeWithdrawn,
+            "A_A_COMPROMISE" => LoadBalancerTlsCertificateRevocationReason::AACompromise,
+            _ => LoadBalancerTlsCertificateRevocationReason::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LoadBalancerTlsCertificateRevocationReason {
+    fn from(value: String) -> LoadBalancerTlsCertificateRevocationReason {
+        match value.as_str() {
+            "UNSPECIFIED" => LoadBalancerTlsCertificateRevocationReason::Unspecified,
+            "KEY_COMPROMISE" => LoadBalancerTlsCertificateRevocationReason::KeyCompromise,
+            "CA_COMPROMISE" => LoadBalancerTlsCertificateRevocationReason::CaCompromise,
+            "AFFILIATION_CHANGED" => LoadBalancerTlsCertificateRevocationReason::AffiliationChanged,
+            "SUPERCEDED" => LoadBalancerTlsCertificateRevocationReason::Superceded,
+            "CESSATION_OF_OPERATION" => {
+                LoadBalancerTlsCertificateRevocationReason::CessationOfOperation
+            }
+            "CERTIFICATE_HOLD" => LoadBalancerTlsCertificateRevocationReason::CertificateHold,
+            "REMOVE_FROM_CRL" => LoadBalancerTlsCertificateRevocationReason::RemoveFromCrl,
+            "PRIVILEGE_WITHDRAWN" => LoadBalancerTlsCertificateRevocationReason::PrivilegeWithdrawn,
+            "A_A_COMPROMISE" => LoadBalancerTlsCertificateRevocationReason::AACompromise,
+            _ => LoadBalancerTlsCertificateRevocationReason::Unknown(value),
+        }
+    }
 }
 
+impl ::std::str::FromStr for LoadBalancerTlsCertificateRevocationReason {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LoadBalancerTlsCertificateRevocationReason, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LoadBalancerTlsCertificateRevocationReason {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LoadBalancerTlsCertificateRevocationReason {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LoadBalancerTlsCertificateRevocationReason {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> Result<LoadBalancerTlsCertificateRevocationReason, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LoadBalancerTlsCertificateStatus {
+    /// `PENDING_VALIDATION`
+    PendingValidation,
+    /// `ISSUED`
+    Issued,
+    /// `INACTIVE`
+    Inactive,
+    /// `EXPIRED`
+    Expired,
+    /// `VALIDATION_TIMED_OUT`
+    ValidationTimedOut,
+    /// `REVOKED`
+    Revoked,
+    /// `FAILED`
+    Failed,
+    /// `UNKNOWN`
+    UnknownValue,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LoadBalancerTlsCertificateStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LoadBalancerTlsCertificateStatus::PendingValidation => "PENDING_VALIDATION",
+            LoadBalancerTlsCertificateStatus::Issued => "ISSUED",
+            LoadBalancerTlsCertificateStatus::Inactive => "INACTIVE",
+            LoadBalancerTlsCertificateStatus::Expired => "EXPIRED",
+            LoadBalancerTlsCertificateStatus::ValidationTimedOut => "VALIDATION_TIMED_OUT",
+            LoadBalancerTlsCertificateStatus::Revoked => "REVOKED",
+            LoadBalancerTlsCertificateStatus::Failed => "FAILED",
+            LoadBalancerTlsCertificateStatus::UnknownValue => "UNKNOWN",
+            LoadBalancerTlsCertificateStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LoadBalancerTlsCertificateStatus {
+    fn default() -> LoadBalancerTlsCertificateStatus {
+        LoadBalancerTlsCertificateStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LoadBalancerTlsCertificateStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LoadBalancerTlsCertificateStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LoadBalancerTlsCertificateStatus {
+    fn from(value: &'a str) -> LoadBalancerTlsCertificateStatus {
+        match value {
+            "PENDING_VALIDATION" => LoadBalancerTlsCertificateStatus::PendingValidation,
+            "ISSUED" => LoadBalancerTlsCertificateStatus::Issued,
+            "INACTIVE" => LoadBalancerTlsCertificateStatus::Inactive,
+            "EXPIRED" => LoadBalancerTlsCertificateStatus::Expired,
+            "VALIDATION_TIMED_OUT" => LoadBalancerTlsCertificateStatus::ValidationTimedOut,
+            "REVOKED" => LoadBalancerTlsCertificateStatus::Revoked,
+            "FAILED" => LoadBalancerTlsCertificateStatus::Failed,
+            "UNKNOWN" => LoadBalancerTlsCertificateStatus::UnknownValue,
+            _ => LoadBalancerTlsCertificateStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LoadBalancerTlsCertificateStatus {
+    fn from(value: String) -> LoadBalancerTlsCertificateStatus {
+        match value.as_str() {
+            "PENDING_VALIDATION" => LoadBalancerTlsCertificateStatus::PendingValidation,
+            "ISSUED" => LoadBalancerTlsCertificateStatus::Issued,
+            "INACTIVE" => LoadBalancerTlsCertificateStatus::Inactive,
+            "EXPIRED" => LoadBalancerTlsCertificateStatus::Expired,
+            "VALIDATION_TIMED_OUT" => LoadBalancerTlsCertificateStatus::ValidationTimedOut,
+            "REVOKED" => LoadBalancerTlsCertificateStatus::Revoked,
+            "FAILED" => LoadBalancerTlsCertificateStatus::Failed,
+            "UNKNOWN" => LoadBalancerTlsCertificateStatus::UnknownValue,
+            _ => LoadBalancerTlsCertificateStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LoadBalancerTlsCertificateStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LoadBalancerTlsCertificateStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LoadBalancerTlsCertificateStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LoadBalancerTlsCertificateStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LoadBalancerTlsCertificateStatus {
+    fn deserialize<D>(deserializer: D) -> Result<LoadBalancerTlsCertificateStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Provides a summary of SSL/TLS certificate metadata.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -3373,8 +6010,13 @@ pub struct LoadBalancerTlsCertificateSummary {
 pub struct LogEvent {
     /// <p>The timestamp when the database log event was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The message of the database log event.</p>
     #[serde(rename = "message")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3407,14 +6049,345 @@ pub struct MetricDatapoint {
     pub sum: Option<f64>,
     /// <p>The timestamp (e.g., <code>1479816991.349</code>).</p>
     #[serde(rename = "timestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub timestamp: Option<f64>,
+    pub timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The unit. </p>
     #[serde(rename = "unit")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub unit: Option<String>,
+    pub unit: Option<MetricUnit>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MetricStatistic {
+    /// `Minimum`
+    Minimum,
+    /// `Maximum`
+    Maximum,
+    /// `Sum`
+    Sum,
+    /// `Average`
+    Average,
+    /// `SampleCount`
+    SampleCount,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl MetricStatistic {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MetricStatistic::Minimum => "Minimum",
+            MetricStatistic::Maximum => "Maximum",
+            MetricStatistic::Sum => "Sum",
+            MetricStatistic::Average => "Average",
+            MetricStatistic::SampleCount => "SampleCount",
+            MetricStatistic::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for MetricStatistic {
+    fn default() -> MetricStatistic {
+        MetricStatistic::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for MetricStatistic {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for MetricStatistic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for MetricStatistic {
+    fn from(value: &'a str) -> MetricStatistic {
+        match value {
+            "Minimum" => MetricStatistic::Minimum,
+            "Maximum" => MetricStatistic::Maximum,
+            "Sum" => MetricStatistic::Sum,
+            "Average" => MetricStatistic::Average,
+            "SampleCount" => MetricStatistic::SampleCount,
+            _ => MetricStatistic::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MetricStatistic {
+    fn from(value: String) -> MetricStatistic {
+        match value.as_str() {
+            "Minimum" => MetricStatistic::Minimum,
+            "Maximum" => MetricStatistic::Maximum,
+            "Sum" => MetricStatistic::Sum,
+            "Average" => MetricStatistic::Average,
+            "SampleCount" => MetricStatistic::SampleCount,
+            _ => MetricStatistic::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for MetricStatistic {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<MetricStatistic, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MetricStatistic {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for MetricStatistic {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MetricStatistic {
+    fn deserialize<D>(deserializer: D) -> Result<MetricStatistic, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MetricUnit {
+    /// `Seconds`
+    Seconds,
+    /// `Microseconds`
+    Microseconds,
+    /// `Milliseconds`
+    Milliseconds,
+    /// `Bytes`
+    Bytes,
+    /// `Kilobytes`
+    Kilobytes,
+    /// `Megabytes`
+    Megabytes,
+    /// `Gigabytes`
+    Gigabytes,
+    /// `Terabytes`
+    Terabytes,
+    /// `Bits`
+    Bits,
+    /// `Kilobits`
+    Kilobits,
+    /// `Megabits`
+    Megabits,
+    /// `Gigabits`
+    Gigabits,
+    /// `Terabits`
+    Terabits,
+    /// `Percent`
+    Percent,
+    /// `Count`
+    Count,
+    /// `Bytes/Second`
+    BytesSecond,
+    /// `Kilobytes/Second`
+    KilobytesSecond,
+    /// `Megabytes/Second`
+    MegabytesSecond,
+    /// `Gigabytes/Second`
+    GigabytesSecond,
+    /// `Terabytes/Second`
+    TerabytesSecond,
+    /// `Bits/Second`
+    BitsSecond,
+    /// `Kilobits/Second`
+    KilobitsSecond,
+    /// `Megabits/Second`
+    MegabitsSecond,
+    /// `Gigabits/Second`
+    GigabitsSecond,
+    /// `Terabits/Second`
+    TerabitsSecond,
+    /// `Count/Second`
+    CountSecond,
+    /// `None`
+    None,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl MetricUnit {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MetricUnit::Seconds => "Seconds",
+            MetricUnit::Microseconds => "Microseconds",
+            MetricUnit::Milliseconds => "Milliseconds",
+            MetricUnit::Bytes => "Bytes",
+            MetricUnit::Kilobytes => "Kilobytes",
+            MetricUnit::Megabytes => "Megabytes",
+            MetricUnit::Gigabytes => "Gigabytes",
+            MetricUnit::Terabytes => "Terabytes",
+            MetricUnit::Bits => "Bits",
+            MetricUnit::Kilobits => "Kilobits",
+            MetricUnit::Megabits => "Megabits",
+            MetricUnit::Gigabits => "Gigabits",
+            MetricUnit::Terabits => "Terabits",
+            MetricUnit::Percent => "Percent",
+            MetricUnit::Count => "Count",
+            MetricUnit::BytesSecond => "Bytes/Second",
+            MetricUnit::KilobytesSecond => "Kilobytes/Second",
+            MetricUnit::MegabytesSecond => "Megabytes/Second",
+            MetricUnit::GigabytesSecond => "Gigabytes/Second",
+            MetricUnit::TerabytesSecond => "Terabytes/Second",
+            MetricUnit::BitsSecond => "Bits/Second",
+            MetricUnit::KilobitsSecond => "Kilobits/Second",
+            MetricUnit::MegabitsSecond => "Megabits/Second",
+            MetricUnit::GigabitsSecond => "Gigabits/Second",
+            MetricUnit::TerabitsSecond => "Terabits/Second",
+            MetricUnit::CountSecond => "Count/Second",
+            MetricUnit::None => "None",
+            MetricUnit::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for MetricUnit {
+    fn default() -> MetricUnit {
+        MetricUnit::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for MetricUnit {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for MetricUnit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for MetricUnit {
+    fn from(value: &'a str) -> MetricUnit {
+        match value {
+            "Seconds" => MetricUnit::Seconds,
+            "Microseconds" => MetricUnit::Microseconds,
+            "Milliseconds" => MetricUnit::Milliseconds,
+            "Bytes" => MetricUnit::Bytes,
+            "Kilobytes" => MetricUnit::Kilobytes,
+            "Megabytes" => MetricUnit::Megabytes,
+            "Gigabytes" => MetricUnit::Gigabytes,
+            "Terabytes" => MetricUnit::Terabytes,
+            "Bits" => MetricUnit::Bits,
+            "Kilobits" => MetricUnit::Kilobits,
+            "Megabits" => MetricUnit::Megabits,
+            "Gigabits" => MetricUnit::Gigabits,
+            "Terabits" => MetricUnit::Terabits,
+            "Percent" => MetricUnit::Percent,
+            "Count" => MetricUnit::Count,
+            "Bytes/Second" => MetricUnit::BytesSecond,
+            "Kilobytes/Second" => MetricUnit::KilobytesSecond,
+            "Megabytes/Second" => MetricUnit::MegabytesSecond,
+            "Gigabytes/Second" => MetricUnit::GigabytesSecond,
+            "Terabytes/Second" => MetricUnit::TerabytesSecond,
+            "Bits/Second" => MetricUnit::BitsSecond,
+            "Kilobits/Second" => MetricUnit::KilobitsSecond,
+            "Megabits/Second" => MetricUnit::MegabitsSecond,
+            "Gigabits/Second" => MetricUnit::GigabitsSecond,
+            "Terabits/Second" => MetricUnit::TerabitsSecond,
+            "Count/Second" => MetricUnit::CountSecond,
+            "None" => MetricUnit::None,
+            _ => MetricUnit::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MetricUnit {
+    fn from(value: String) -> MetricUnit {
+        match value.as_str() {
+            "Seconds" => MetricUnit::Seconds,
+            "Microseconds" => MetricUnit::Microseconds,
+            "Milliseconds" => MetricUnit::Milliseconds,
+            "Bytes" => MetricUnit::Bytes,
+            "Kilobytes" => MetricUnit::Kilobytes,
+            "Megabytes" => MetricUnit::Megabytes,
+            "Gigabytes" => MetricUnit::Gigabytes,
+            "Terabytes" => MetricUnit::Terabytes,
+            "Bits" => MetricUnit::Bits,
+            "Kilobits" => MetricUnit::Kilobits,
+            "Megabits" => MetricUnit::Megabits,
+            "Gigabits" => MetricUnit::Gigabits,
+            "Terabits" => MetricUnit::Terabits,
+            "Percent" => MetricUnit::Percent,
+            "Count" => MetricUnit::Count,
+            "Bytes/Second" => MetricUnit::BytesSecond,
+            "Kilobytes/Second" => MetricUnit::KilobytesSecond,
+            "Megabytes/Second" => MetricUnit::MegabytesSecond,
+            "Gigabytes/Second" => MetricUnit::GigabytesSecond,
+            "Terabytes/Second" => MetricUnit::TerabytesSecond,
+            "Bits/Second" => MetricUnit::BitsSecond,
+            "Kilobits/Second" => MetricUnit::KilobitsSecond,
+            "Megabits/Second" => MetricUnit::MegabitsSecond,
+            "Gigabits/Second" => MetricUnit::GigabitsSecond,
+            "Terabits/Second" => MetricUnit::TerabitsSecond,
+            "Count/Second" => MetricUnit::CountSecond,
+            "None" => MetricUnit::None,
+            _ => MetricUnit::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for MetricUnit {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<MetricUnit, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MetricUnit {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for MetricUnit {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for MetricUnit {
+    fn deserialize<D>(deserializer: D) -> Result<MetricUnit, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes the monthly data transfer in and out of your virtual private server (or <i>instance</i>).</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -3425,6 +6398,109 @@ pub struct MonthlyTransfer {
     pub gb_per_month_allocated: Option<i64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum NetworkProtocol {
+    /// `tcp`
+    Tcp,
+    /// `all`
+    All,
+    /// `udp`
+    Udp,
+    /// `icmp`
+    Icmp,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl NetworkProtocol {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            NetworkProtocol::Tcp => "tcp",
+            NetworkProtocol::All => "all",
+            NetworkProtocol::Udp => "udp",
+            NetworkProtocol::Icmp => "icmp",
+            NetworkProtocol::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for NetworkProtocol {
+    fn default() -> NetworkProtocol {
+        NetworkProtocol::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for NetworkProtocol {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for NetworkProtocol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for NetworkProtocol {
+    fn from(value: &'a str) -> NetworkProtocol {
+        match value {
+            "tcp" => NetworkProtocol::Tcp,
+            "all" => NetworkProtocol::All,
+            "udp" => NetworkProtocol::Udp,
+            "icmp" => NetworkProtocol::Icmp,
+            _ => NetworkProtocol::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for NetworkProtocol {
+    fn from(value: String) -> NetworkProtocol {
+        match value.as_str() {
+            "tcp" => NetworkProtocol::Tcp,
+            "all" => NetworkProtocol::All,
+            "udp" => NetworkProtocol::Udp,
+            "icmp" => NetworkProtocol::Icmp,
+            _ => NetworkProtocol::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for NetworkProtocol {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<NetworkProtocol, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for NetworkProtocol {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for NetworkProtocol {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for NetworkProtocol {
+    fn deserialize<D>(deserializer: D) -> Result<NetworkProtocol, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct OpenInstancePublicPortsRequest {
@@ -3451,8 +6527,13 @@ pub struct OpenInstancePublicPortsResult {
 pub struct Operation {
     /// <p>The timestamp when the operation was initialized (e.g., <code>1479816991.349</code>).</p>
     #[serde(rename = "createdAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The error code.</p>
     #[serde(rename = "errorCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3480,7 +6561,7 @@ pub struct Operation {
     /// <p>The type of operation. </p>
     #[serde(rename = "operationType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub operation_type: Option<String>,
+    pub operation_type: Option<OperationType>,
     /// <p>The resource name.</p>
     #[serde(rename = "resourceName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3488,17 +6569,646 @@ pub struct Operation {
     /// <p>The resource type. </p>
     #[serde(rename = "resourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ResourceType>,
     /// <p>The status of the operation. </p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<OperationStatus>,
     /// <p>The timestamp when the status was changed (e.g., <code>1479816991.349</code>).</p>
     #[serde(rename = "statusChangedAt")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub status_changed_at: Option<f64>,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_changed_at: Option<::chrono::DateTime<::chrono::Utc>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OperationStatus {
+    /// `NotStarted`
+    NotStarted,
+    /// `Started`
+    Started,
+    /// `Failed`
+    Failed,
+    /// `Completed`
+    Completed,
+    /// `Succeeded`
+    Succeeded,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl OperationStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OperationStatus::NotStarted => "NotStarted",
+            OperationStatus::Started => "Started",
+            OperationStatus::Failed => "Failed",
+            OperationStatus::Completed => "Completed",
+            OperationStatus::Succeeded => "Succeeded",
+            OperationStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for OperationStatus {
+    fn default() -> OperationStatus {
+        OperationStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for OperationStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for OperationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for OperationStatus {
+    fn from(value: &'a str) -> OperationStatus {
+        match value {
+            "NotStarted" => OperationStatus::NotStarted,
+            "Started" => OperationStatus::Started,
+            "Failed" => OperationStatus::Failed,
+            "Completed" => OperationStatus::Completed,
+            "Succeeded" => OperationStatus::Succeeded,
+            _ => OperationStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OperationStatus {
+    fn from(value: String) -> OperationStatus {
+        match value.as_str() {
+            "NotStarted" => OperationStatus::NotStarted,
+            "Started" => OperationStatus::Started,
+            "Failed" => OperationStatus::Failed,
+            "Completed" => OperationStatus::Completed,
+            "Succeeded" => OperationStatus::Succeeded,
+            _ => OperationStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for OperationStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<OperationStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OperationStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for OperationStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OperationStatus {
+    fn deserialize<D>(deserializer: D) -> Result<OperationStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OperationType {
+    /// `DeleteKnownHostKeys`
+    DeleteKnownHostKeys,
+    /// `DeleteInstance`
+    DeleteInstance,
+    /// `CreateInstance`
+    CreateInstance,
+    /// `StopInstance`
+    StopInstance,
+    /// `StartInstance`
+    StartInstance,
+    /// `RebootInstance`
+    RebootInstance,
+    /// `OpenInstancePublicPorts`
+    OpenInstancePublicPorts,
+    /// `PutInstancePublicPorts`
+    PutInstancePublicPorts,
+    /// `CloseInstancePublicPorts`
+    CloseInstancePublicPorts,
+    /// `AllocateStaticIp`
+    AllocateStaticIp,
+    /// `ReleaseStaticIp`
+    ReleaseStaticIp,
+    /// `AttachStaticIp`
+    AttachStaticIp,
+    /// `DetachStaticIp`
+    DetachStaticIp,
+    /// `UpdateDomainEntry`
+    UpdateDomainEntry,
+    /// `DeleteDomainEntry`
+    DeleteDomainEntry,
+    /// `CreateDomain`
+    CreateDomain,
+    /// `DeleteDomain`
+    DeleteDomain,
+    /// `CreateInstanceSnapshot`
+    CreateInstanceSnapshot,
+    /// `DeleteInstanceSnapshot`
+    DeleteInstanceSnapshot,
+    /// `CreateInstancesFromSnapshot`
+    CreateInstancesFromSnapshot,
+    /// `CreateLoadBalancer`
+    CreateLoadBalancer,
+    /// `DeleteLoadBalancer`
+    DeleteLoadBalancer,
+    /// `AttachInstancesToLoadBalancer`
+    AttachInstancesToLoadBalancer,
+    /// `DetachInstancesFromLoadBalancer`
+    DetachInstancesFromLoadBalancer,
+    /// `UpdateLoadBalancerAttribute`
+    UpdateLoadBalancerAttribute,
+    /// `CreateLoadBalancerTlsCertificate`
+    CreateLoadBalancerTlsCertificate,
+    /// `DeleteLoadBalancerTlsCertificate`
+    DeleteLoadBalancerTlsCertificate,
+    /// `AttachLoadBalancerTlsCertificate`
+    AttachLoadBalancerTlsCertificate,
+    /// `CreateDisk`
+    CreateDisk,
+    /// `DeleteDisk`
+    DeleteDisk,
+    /// `AttachDisk`
+    AttachDisk,
+    /// `DetachDisk`
+    DetachDisk,
+    /// `CreateDiskSnapshot`
+    CreateDiskSnapshot,
+    /// `DeleteDiskSnapshot`
+    DeleteDiskSnapshot,
+    /// `CreateDiskFromSnapshot`
+    CreateDiskFromSnapshot,
+    /// `CreateRelationalDatabase`
+    CreateRelationalDatabase,
+    /// `UpdateRelationalDatabase`
+    UpdateRelationalDatabase,
+    /// `DeleteRelationalDatabase`
+    DeleteRelationalDatabase,
+    /// `CreateRelationalDatabaseFromSnapshot`
+    CreateRelationalDatabaseFromSnapshot,
+    /// `CreateRelationalDatabaseSnapshot`
+    CreateRelationalDatabaseSnapshot,
+    /// `DeleteRelationalDatabaseSnapshot`
+    DeleteRelationalDatabaseSnapshot,
+    /// `UpdateRelationalDatabaseParameters`
+    UpdateRelationalDatabaseParameters,
+    /// `StartRelationalDatabase`
+    StartRelationalDatabase,
+    /// `RebootRelationalDatabase`
+    RebootRelationalDatabase,
+    /// `StopRelationalDatabase`
+    StopRelationalDatabase,
+    /// `EnableAddOn`
+    EnableAddOn,
+    /// `DisableAddOn`
+    DisableAddOn,
+    /// `PutAlarm`
+    PutAlarm,
+    /// `GetAlarms`
+    GetAlarms,
+    /// `DeleteAlarm`
+    DeleteAlarm,
+    /// `TestAlarm`
+    TestAlarm,
+    /// `CreateContactMethod`
+    CreateContactMethod,
+    /// `GetContactMethods`
+    GetContactMethods,
+    /// `SendContactMethodVerification`
+    SendContactMethodVerification,
+    /// `DeleteContactMethod`
+    DeleteContactMethod,
+    /// `CreateDistribution`
+    CreateDistribution,
+    /// `UpdateDistribution`
+    UpdateDistribution,
+    /// `DeleteDistribution`
+    DeleteDistribution,
+    /// `ResetDistributionCache`
+    ResetDistributionCache,
+    /// `AttachCertificateToDistribution`
+    AttachCertificateToDistribution,
+    /// `DetachCertificateFromDistribution`
+    DetachCertificateFromDistribution,
+    /// `UpdateDistributionBundle`
+    UpdateDistributionBundle,
+    /// `SetIpAddressType`
+    SetIpAddressType,
+    /// `CreateCertificate`
+    CreateCertificate,
+    /// `DeleteCertificate`
+    DeleteCertificate,
+    /// `CreateContainerService`
+    CreateContainerService,
+    /// `UpdateContainerService`
+    UpdateContainerService,
+    /// `DeleteContainerService`
+    DeleteContainerService,
+    /// `CreateContainerServiceDeployment`
+    CreateContainerServiceDeployment,
+    /// `CreateContainerServiceRegistryLogin`
+    CreateContainerServiceRegistryLogin,
+    /// `RegisterContainerImage`
+    RegisterContainerImage,
+    /// `DeleteContainerImage`
+    DeleteContainerImage,
+    /// `CreateBucket`
+    CreateBucket,
+    /// `DeleteBucket`
+    DeleteBucket,
+    /// `CreateBucketAccessKey`
+    CreateBucketAccessKey,
+    /// `DeleteBucketAccessKey`
+    DeleteBucketAccessKey,
+    /// `UpdateBucketBundle`
+    UpdateBucketBundle,
+    /// `UpdateBucket`
+    UpdateBucket,
+    /// `SetResourceAccessForBucket`
+    SetResourceAccessForBucket,
+    /// `UpdateInstanceMetadataOptions`
+    UpdateInstanceMetadataOptions,
+    /// `StartGUISession`
+    StartGUISession,
+    /// `StopGUISession`
+    StopGUISession,
+    /// `SetupInstanceHttps`
+    SetupInstanceHttps,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl OperationType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OperationType::DeleteKnownHostKeys => "DeleteKnownHostKeys",
+            OperationType::DeleteInstance => "DeleteInstance",
+            OperationType::CreateInstance => "CreateInstance",
+            OperationType::StopInstance => "StopInstance",
+            OperationType::StartInstance => "StartInstance",
+            OperationType::RebootInstance => "RebootInstance",
+            OperationType::OpenInstancePublicPorts => "OpenInstancePublicPorts",
+            OperationType::PutInstancePublicPorts => "PutInstancePublicPorts",
+            OperationType::CloseInstancePublicPorts => "CloseInstancePublicPorts",
+            OperationType::AllocateStaticIp => "AllocateStaticIp",
+            OperationType::ReleaseStaticIp => "ReleaseStaticIp",
+            OperationType::AttachStaticIp => "AttachStaticIp",
+            OperationType::DetachStaticIp => "DetachStaticIp",
+            OperationType::UpdateDomainEntry => "UpdateDomainEntry",
+            OperationType::DeleteDomainEntry => "DeleteDomainEntry",
+            OperationType::CreateDomain => "CreateDomain",
+            OperationType::DeleteDomain => "DeleteDomain",
+            OperationType::CreateInstanceSnapshot => "CreateInstanceSnapshot",
+            OperationType::DeleteInstanceSnapshot => "DeleteInstanceSnapshot",
+            OperationType::CreateInstancesFromSnapshot => "CreateInstancesFromSnapshot",
+            OperationType::CreateLoadBalancer => "CreateLoadBalancer",
+            OperationType::DeleteLoadBalancer => "DeleteLoadBalancer",
+            OperationType::AttachInstancesToLoadBalancer => "AttachInstancesToLoadBalancer",
+            OperationType::DetachInstancesFromLoadBalancer => "DetachInstancesFromLoadBalancer",
+            OperationType::UpdateLoadBalancerAttribute => "UpdateLoadBalancerAttribute",
+            OperationType::CreateLoadBalancerTlsCertificate => "CreateLoadBalancerTlsCertificate",
+            OperationType::DeleteLoadBalancerTlsCertificate => "DeleteLoadBalancerTlsCertificate",
+            OperationType::AttachLoadBalancerTlsCertificate => "AttachLoadBalancerTlsCertificate",
+            OperationType::CreateDisk => "CreateDisk",
+            OperationType::DeleteDisk => "DeleteDisk",
+            OperationType::AttachDisk => "AttachDisk",
+            OperationType::DetachDisk => "DetachDisk",
+            OperationType::CreateDiskSnapshot => "CreateDiskSnapshot",
+            OperationType::DeleteDiskSnapshot => "DeleteDiskSnapshot",
+            OperationType::CreateDiskFromSnapshot => "CreateDiskFromSnapshot",
+            OperationType::CreateRelationalDatabase => "CreateRelationalDatabase",
+            OperationType::UpdateRelationalDatabase => "UpdateRelationalDatabase",
+            OperationType::DeleteRelationalDatabase => "DeleteRelationalDatabase",
+            OperationType::CreateRelationalDatabaseFromSnapshot => {
+                "CreateRelationalDatabaseFromSnapshot"
+            }
+            OperationType::CreateRelationalDatabaseSnapshot => "CreateRelationalDatabaseSnapshot",
+            OperationType::DeleteRelationalDatabaseSnapshot => "DeleteRelationalDatabaseSnapshot",
+            OperationType::UpdateRelationalDatabaseParameters => {
+                "UpdateRelationalDatabaseParameters"
+            }
+            OperationType::StartRelationalDatabase => "StartRelationalDatabase",
+            OperationType::RebootRelationalDatabase => "RebootRelationalDatabase",
+            OperationType::StopRelationalDatabase => "StopRelationalDatabase",
+            OperationType::EnableAddOn => "EnableAddOn",
+            OperationType::DisableAddOn => "DisableAddOn",
+            OperationType::PutAlarm => "PutAlarm",
+            OperationType::GetAlarms => "GetAlarms",
+            OperationType::DeleteAlarm => "DeleteAlarm",
+            OperationType::TestAlarm => "TestAlarm",
+            OperationType::CreateContactMethod => "CreateContactMethod",
+            OperationType::GetContactMethods => "GetContactMethods",
+            OperationType::SendContactMethodVerification => "SendContactMethodVerification",
+            OperationType::DeleteContactMethod => "DeleteContactMethod",
+            OperationType::CreateDistribution => "CreateDistribution",
+            OperationType::UpdateDistribution => "UpdateDistribution",
+            OperationType::DeleteDistribution => "DeleteDistribution",
+            OperationType::ResetDistributionCache => "ResetDistributionCache",
+            OperationType::AttachCertificateToDistribution => "AttachCertificateToDistribution",
+            OperationType::DetachCertificateFromDistribution => "DetachCertificateFromDistribution",
+            OperationType::UpdateDistributionBundle => "UpdateDistributionBundle",
+            OperationType::SetIpAddressType => "SetIpAddressType",
+            OperationType::CreateCertificate => "CreateCertificate",
+            OperationType::DeleteCertificate => "DeleteCertificate",
+            OperationType::CreateContainerService => "CreateContainerService",
+            OperationType::UpdateContainerService => "UpdateContainerService",
+            OperationType::DeleteContainerService => "DeleteContainerService",
+            OperationType::CreateContainerServiceDeployment => "CreateContainerServiceDeployment",
+            OperationType::CreateContainerServiceRegistryLogin => {
+                "CreateContainerServiceRegistryLogin"
+            }
+            OperationType::RegisterContainerImage => "RegisterContainerImage",
+            OperationType::DeleteContainerImage => "DeleteContainerImage",
+            OperationType::CreateBucket => "CreateBucket",
+            OperationType::DeleteBucket => "DeleteBucket",
+            OperationType::CreateBucketAccessKey => "CreateBucketAccessKey",
+            OperationType::DeleteBucketAccessKey => "DeleteBucketAccessKey",
+            OperationType::UpdateBucketBundle => "UpdateBucketBundle",
+            OperationType::UpdateBucket => "UpdateBucket",
+            OperationType::SetResourceAccessForBucket => "SetResourceAccessForBucket",
+            OperationType::UpdateInstanceMetadataOptions => "UpdateInstanceMetadataOptions",
+            OperationType::StartGUISession => "StartGUISession",
+            OperationType::StopGUISession => "StopGUISession",
+            OperationType::SetupInstanceHttps => "SetupInstanceHttps",
+            OperationType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for OperationType {
+    fn default() -> OperationType {
+        OperationType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for OperationType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
 }
 
+impl fmt::Display for OperationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for OperationType {
+    fn from(value: &'a str) -> OperationType {
+        match value {
+            "DeleteKnownHostKeys" => OperationType::DeleteKnownHostKeys,
+            "DeleteInstance" => OperationType::DeleteInstance,
+            "CreateInstance" => OperationType::CreateInstance,
+            "StopInstance" => OperationType::StopInstance,
+            "StartInstance" => OperationType::StartInstance,
+            "RebootInstance" => OperationType::RebootInstance,
+            "OpenInstancePublicPorts" => OperationType::OpenInstancePublicPorts,
+            "PutInstancePublicPorts" => OperationType::PutInstancePublicPorts,
+            "CloseInstancePublicPorts" => OperationType::CloseInstancePublicPorts,
+            "AllocateStaticIp" => OperationType::AllocateStaticIp,
+            "ReleaseStaticIp" => OperationType::ReleaseStaticIp,
+            "AttachStaticIp" => OperationType::AttachStaticIp,
+            "DetachStaticIp" => OperationType::DetachStaticIp,
+            "UpdateDomainEntry" => OperationType::UpdateDomainEntry,
+            "DeleteDomainEntry" => OperationType::DeleteDomainEntry,
+            "CreateDomain" => OperationType::CreateDomain,
+            "DeleteDomain" => OperationType::DeleteDomain,
+            "CreateInstanceSnapshot" => OperationType::CreateInstanceSnapshot,
+            "DeleteInstanceSnapshot" => OperationType::DeleteInstanceSnapshot,
+            "CreateInstancesFromSnapshot" => OperationType::CreateInstancesFromSnapshot,
+            "CreateLoadBalancer" => OperationType::CreateLoadBalancer,
+            "DeleteLoadBalancer" => OperationType::DeleteLoadBalancer,
+            "AttachInstancesToLoadBalancer" => OperationType::AttachInstancesToLoadBalancer,
+            "DetachInstancesFromLoadBalancer" => OperationType::DetachInstancesFromLoadBalancer,
+            "UpdateLoadBalancerAttribute" => OperationType::UpdateLoadBalancerAttribute,
+            "CreateLoadBalancerTlsCertificate" => OperationType::CreateLoadBalancerTlsCertificate,
+            "DeleteLoadBalancerTlsCertificate" => OperationType::DeleteLoadBalancerTlsCertificate,
+            "AttachLoadBalancerTlsCertificate" => OperationType::AttachLoadBalancerTlsCertificate,
+            "CreateDisk" => OperationType::CreateDisk,
+            "DeleteDisk" => OperationType::DeleteDisk,
+            "AttachDisk" => OperationType::AttachDisk,
+            "DetachDisk" => OperationType::DetachDisk,
+            "CreateDiskSnapshot" => OperationType::CreateDiskSnapshot,
+            "DeleteDiskSnapshot" => OperationType::DeleteDiskSnapshot,
+            "CreateDiskFromSnapshot" => OperationType::CreateDiskFromSnapshot,
+            "CreateRelationalDatabase" => OperationType::CreateRelationalDatabase,
+            "UpdateRelationalDatabase" => OperationType::UpdateRelationalDatabase,
+            "DeleteRelationalDatabase" => OperationType::DeleteRelationalDatabase,
+            "CreateRelationalDatabaseFromSnapshot" => {
+                OperationType::CreateRelationalDatabaseFromSnapshot
+            }
+            "CreateRelationalDatabaseSnapshot" => OperationType::CreateRelationalDatabaseSnapshot,
+            "DeleteRelationalDatabaseSnapshot" => OperationType::DeleteRelationalDatabaseSnapshot,
+            "UpdateRelationalDatabaseParameters" => {
+                OperationType::UpdateRelationalDatabaseParameters
+            }
+            "StartRelationalDatabase" => OperationType::StartRelationalDatabase,
+            "RebootRelationalDatabase" => OperationType::RebootRelationalDatabase,
+            "StopRelationalDatabase" => OperationType::StopRelationalDatabase,
+            "EnableAddOn" => OperationType::EnableAddOn,
+            "DisableAddOn" => OperationType::DisableAddOn,
+            "PutAlarm" => OperationType::PutAlarm,
+            "GetAlarms" => OperationType::GetAlarms,
+            "DeleteAlarm" => OperationType::DeleteAlarm,
+            "TestAlarm" => OperationType::TestAlarm,
+            "CreateContactMethod" => OperationType::CreateContactMethod,
+            "GetContactMethods" => OperationType::GetContactMethods,
+            "SendContactMethodVerification" => OperationType::SendContactMethodVerification,
+            "DeleteContactMethod" => OperationType::DeleteContactMethod,
+            "CreateDistribution" => OperationType::CreateDistribution,
+            "UpdateDistribution" => OperationType::UpdateDistribution,
+            "DeleteDistribution" => OperationType::DeleteDistribution,
+            "ResetDistributionCache" => OperationType::ResetDistributionCache,
+            "AttachCertificateToDistribution" => OperationType::AttachCertificateToDistribution,
+            "DetachCertificateFromDistribution" => OperationType::DetachCertificateFromDistribution,
+            "UpdateDistributionBundle" => OperationType::UpdateDistributionBundle,
+            "SetIpAddressType" => OperationType::SetIpAddressType,
+            "CreateCertificate" => OperationType::CreateCertificate,
+            "DeleteCertificate" => OperationType::DeleteCertificate,
+            "CreateContainerService" => OperationType::CreateContainerService,
+            "UpdateContainerService" => OperationType::UpdateContainerService,
+            "DeleteContainerService" => OperationType::DeleteContainerService,
+            "CreateContainerServiceDeployment" => OperationType::CreateContainerServiceDeployment,
+            "CreateContainerServiceRegistryLogin" => {
+                OperationType::CreateContainerServiceRegistryLogin
+            }
+            "RegisterContainerImage" => OperationType::RegisterContainerImage,
+            "DeleteContainerImage" => OperationType::DeleteContainerImage,
+            "CreateBucket" => OperationType::CreateBucket,
+            "DeleteBucket" => OperationType::DeleteBucket,
+            "CreateBucketAccessKey" => OperationType::CreateBucketAccessKey,
+            "DeleteBucketAccessKey" => OperationType::DeleteBucketAccessKey,
+            "UpdateBucketBundle" => OperationType::UpdateBucketBundle,
+            "UpdateBucket" => OperationType::UpdateBucket,
+            "SetResourceAccessForBucket" => OperationType::SetResourceAccessForBucket,
+            "UpdateInstanceMetadataOptions" => OperationType::UpdateInstanceMetadataOptions,
+            "StartGUISession" => OperationType::StartGUISession,
+            "StopGUISession" => OperationType::StopGUISession,
+            "SetupInstanceHttps" => OperationType::SetupInstanceHttps,
+            _ => OperationType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OperationType {
+    fn from(value: String) -> OperationType {
+        match value.as_str() {
+            "DeleteKnownHostKeys" => OperationType::DeleteKnownHostKeys,
+            "DeleteInstance" => OperationType::DeleteInstance,
+            "CreateInstance" => OperationType::CreateInstance,
+            "StopInstance" => OperationType::StopInstance,
+            "StartInstance" => OperationType::StartInstance,
+            "RebootInstance" => OperationType::RebootInstance,
+            "OpenInstancePublicPorts" => OperationType::OpenInstancePublicPorts,
+            "PutInstancePublicPorts" => OperationType::PutInstancePublicPorts,
+            "CloseInstancePublicPorts" => OperationType::CloseInstancePublicPorts,
+            "AllocateStaticIp" => OperationType::AllocateStaticIp,
+            "ReleaseStaticIp" => OperationType::ReleaseStaticIp,
+            "AttachStaticIp" => OperationType::AttachStaticIp,
+            "DetachStaticIp" => OperationType::DetachStaticIp,
+            "UpdateDomainEntry" => OperationType::UpdateDomainEntry,
+            "DeleteDomainEntry" => OperationType::DeleteDomainEntry,
+            "CreateDomain" => OperationType::CreateDomain,
+            "DeleteDomain" => OperationType::DeleteDomain,
+            "CreateInstanceSnapshot" => OperationType::CreateInstanceSnapshot,
+            "DeleteInstanceSnapshot" => OperationType::DeleteInstanceSnapshot,
+            "CreateInstancesFromSnapshot" => OperationType::CreateInstancesFromSnapshot,
+            "CreateLoadBalancer" => OperationType::CreateLoadBalancer,
+            "DeleteLoadBalancer" => OperationType::DeleteLoadBalancer,
+            "AttachInstancesToLoadBalancer" => OperationType::AttachInstancesToLoadBalancer,
+            "DetachInstancesFromLoadBalancer" => OperationType::DetachInstancesFromLoadBalancer,
+            "UpdateLoadBalancerAttribute" => OperationType::UpdateLoadBalancerAttribute,
+            "CreateLoadBalancerTlsCertificate" => OperationType::CreateLoadBalancerTlsCertificate,
+            "DeleteLoadBalancerTlsCertificate" => OperationType::DeleteLoadBalancerTlsCertificate,
+            "AttachLoadBalancerTlsCertificate" => OperationType::AttachLoadBalancerTlsCertificate,
+            "CreateDisk" => OperationType::CreateDisk,
+            "DeleteDisk" => OperationType::DeleteDisk,
+            "AttachDisk" => OperationType::AttachDisk,
+            "DetachDisk" => OperationType::DetachDisk,
+            "CreateDiskSnapshot" => OperationType::CreateDiskSnapshot,
+            "DeleteDiskSnapshot" => OperationType::DeleteDiskSnapshot,
+            "CreateDiskFromSnapshot" => OperationType::CreateDiskFromSnapshot,
+            "CreateRelationalDatabase" => OperationType::CreateRelationalDatabase,
+            "UpdateRelationalDatabase" => OperationType::UpdateRelationalDatabase,
+            "DeleteRelationalDatabase" => OperationType::DeleteRelationalDatabase,
+            "CreateRelationalDatabaseFromSnapshot" => {
+                OperationType::CreateRelationalDatabaseFromSnapshot
+            }
+            "CreateRelationalDatabaseSnapshot" => OperationType::CreateRelationalDatabaseSnapshot,
+            "DeleteRelationalDatabaseSnapshot" => OperationType::DeleteRelationalDatabaseSnapshot,
+            "UpdateRelationalDatabaseParameters" => {
+                OperationType::UpdateRelationalDatabaseParameters
+            }
+            "StartRelationalDatabase" => OperationType::StartRelationalDatabase,
+            "RebootRelationalDatabase" => OperationType::RebootRelationalDatabase,
+            "StopRelationalDatabase" => OperationType::StopRelationalDatabase,
+            "EnableAddOn" => OperationType::EnableAddOn,
+            "DisableAddOn" => OperationType::DisableAddOn,
+            "PutAlarm" => OperationType::PutAlarm,
+            "GetAlarms" => OperationType::GetAlarms,
+            "DeleteAlarm" => OperationType::DeleteAlarm,
+            "TestAlarm" => OperationType::TestAlarm,
+            "CreateContactMethod" => OperationType::CreateContactMethod,
+            "GetContactMethods" => OperationType::GetContactMethods,
+            "SendContactMethodVerification" => OperationType::SendContactMethodVerification,
+            "DeleteContactMethod" => OperationType::DeleteContactMethod,
+            "CreateDistribution" => OperationType::CreateDistribution,
+            "UpdateDistribution" => OperationType::UpdateDistribution,
+            "DeleteDistribution" => OperationType::DeleteDistribution,
+            "ResetDistributionCache" => OperationType::ResetDistributionCache,
+            "AttachCertificateToDistribution" => OperationType::AttachCertificateToDistribution,
+            "DetachCertificateFromDistribution" => OperationType::DetachCertificateFromDistribution,
+            "UpdateDistributionBundle" => OperationType::UpdateDistributionBundle,
+            "SetIpAddressType" => OperationType::SetIpAddressType,
+            "CreateCertificate" => OperationType::CreateCertificate,
+            "DeleteCertificate" => OperationType::DeleteCertificate,
+            "CreateContainerService" => OperationType::CreateContainerService,
+            "UpdateContainerService" => OperationType::UpdateContainerService,
+            "DeleteContainerService" => OperationType::DeleteContainerService,
+            "CreateContainerServiceDeployment" => OperationType::CreateContainerServiceDeployment,
+            "CreateContainerServiceRegistryLogin" => {
+                OperationType::CreateContainerServiceRegistryLogin
+            }
+            "RegisterContainerImage" => OperationType::RegisterContainerImage,
+            "DeleteContainerImage" => OperationType::DeleteContainerImage,
+            "CreateBucket" => OperationType::CreateBucket,
+            "DeleteBucket" => OperationType::DeleteBucket,
+            "CreateBucketAccessKey" => OperationType::CreateBucketAccessKey,
+            "DeleteBucketAccessKey" => OperationType::DeleteBucketAccessKey,
+            "UpdateBucketBundle" => OperationType::UpdateBucketBundle,
+            "UpdateBucket" => OperationType::UpdateBucket,
+            "SetResourceAccessForBucket" => OperationType::SetResourceAccessForBucket,
+            "UpdateInstanceMetadataOptions" => OperationType::UpdateInstanceMetadataOptions,
+            "StartGUISession" => OperationType::StartGUISession,
+            "StopGUISession" => OperationType::StopGUISession,
+            "SetupInstanceHttps" => OperationType::SetupInstanceHttps,
+            _ => OperationType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for OperationType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<OperationType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OperationType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for OperationType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OperationType {
+    fn deserialize<D>(deserializer: D) -> Result<OperationType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The password data for the Windows Server-based instance, including the ciphertext and the key pair name.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -3536,8 +7246,13 @@ pub struct PendingMaintenanceAction {
     pub action: Option<String>,
     /// <p>The effective date of the pending database maintenance action.</p>
     #[serde(rename = "currentApplyDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub current_apply_date: Option<f64>,
+    pub current_apply_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Additional detail about the pending database maintenance action.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3562,6 +7277,99 @@ pub struct PendingModifiedRelationalDatabaseValues {
     pub master_user_password: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PortAccessType {
+    /// `Public`
+    Public,
+    /// `Private`
+    Private,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PortAccessType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PortAccessType::Public => "Public",
+            PortAccessType::Private => "Private",
+            PortAccessType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PortAccessType {
+    fn default() -> PortAccessType {
+        PortAccessType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PortAccessType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PortAccessType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PortAccessType {
+    fn from(value: &'a str) -> PortAccessType {
+        match value {
+            "Public" => PortAccessType::Public,
+            "Private" => PortAccessType::Private,
+            _ => PortAccessType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PortAccessType {
+    fn from(value: String) -> PortAccessType {
+        match value.as_str() {
+            "Public" => PortAccessType::Public,
+            "Private" => PortAccessType::Private,
+            _ => PortAccessType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PortAccessType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PortAccessType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PortAccessType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PortAccessType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PortAccessType {
+    fn deserialize<D>(deserializer: D) -> Result<PortAccessType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes information about the ports on your virtual private server (or <i>instance</i>).</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -3573,13 +7381,209 @@ pub struct PortInfo {
     /// <p>The protocol. </p>
     #[serde(rename = "protocol")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub protocol: Option<String>,
+    pub protocol: Option<NetworkProtocol>,
     /// <p>The last port in the range.</p>
     #[serde(rename = "toPort")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub to_port: Option<i64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PortInfoSourceType {
+    /// `DEFAULT`
+    Default,
+    /// `INSTANCE`
+    Instance,
+    /// `NONE`
+    None,
+    /// `CLOSED`
+    Closed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PortInfoSourceType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PortInfoSourceType::Default => "DEFAULT",
+            PortInfoSourceType::Instance => "INSTANCE",
+            PortInfoSourceType::None => "NONE",
+            PortInfoSourceType::Closed => "CLOSED",
+            PortInfoSourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PortInfoSourceType {
+    fn default() -> PortInfoSourceType {
+        PortInfoSourceType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PortInfoSourceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PortInfoSourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PortInfoSourceType {
+    fn from(value: &'a str) -> PortInfoSourceType {
+        match value {
+            "DEFAULT" => PortInfoSourceType::Default,
+            "INSTANCE" => PortInfoSourceType::Instance,
+            "NONE" => PortInfoSourceType::None,
+            "CLOSED" => PortInfoSourceType::Closed,
+            _ => PortInfoSourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PortInfoSourceType {
+    fn from(value: String) -> PortInfoSourceType {
+        match value.as_str() {
+            "DEFAULT" => PortInfoSourceType::Default,
+            "INSTANCE" => PortInfoSourceType::Instance,
+            "NONE" => PortInfoSourceType::None,
+            "CLOSED" => PortInfoSourceType::Closed,
+            _ => PortInfoSourceType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PortInfoSourceType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PortInfoSourceType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PortInfoSourceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PortInfoSourceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PortInfoSourceType {
+    fn deserialize<D>(deserializer: D) -> Result<PortInfoSourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PortState {
+    /// `open`
+    Open,
+    /// `closed`
+    Closed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PortState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PortState::Open => "open",
+            PortState::Closed => "closed",
+            PortState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PortState {
+    fn default() -> PortState {
+        PortState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PortState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PortState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PortState {
+    fn from(value: &'a str) -> PortState {
+        match value {
+            "open" => PortState::Open,
+            "closed" => PortState::Closed,
+            _ => PortState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PortState {
+    fn from(value: String) -> PortState {
+        match value.as_str() {
+            "open" => PortState::Open,
+            "closed" => PortState::Closed,
+            _ => PortState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PortState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PortState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PortState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PortState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PortState {
+    fn deserialize<D>(deserializer: D) -> Result<PortState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct PutInstancePublicPortsRequest {
@@ -3634,6 +7638,104 @@ pub struct RebootRelationalDatabaseResult {
     pub operations: Option<Vec<Operation>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RecordState {
+    /// `Started`
+    Started,
+    /// `Succeeded`
+    Succeeded,
+    /// `Failed`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RecordState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RecordState::Started => "Started",
+            RecordState::Succeeded => "Succeeded",
+            RecordState::Failed => "Failed",
+            RecordState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RecordState {
+    fn default() -> RecordState {
+        RecordState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RecordState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RecordState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RecordState {
+    fn from(value: &'a str) -> RecordState {
+        match value {
+            "Started" => RecordState::Started,
+            "Succeeded" => RecordState::Succeeded,
+            "Failed" => RecordState::Failed,
+            _ => RecordState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RecordState {
+    fn from(value: String) -> RecordState {
+        match value.as_str() {
+            "Started" => RecordState::Started,
+            "Succeeded" => RecordState::Succeeded,
+            "Failed" => RecordState::Failed,
+            _ => RecordState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RecordState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RecordState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RecordState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RecordState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RecordState {
+    fn deserialize<D>(deserializer: D) -> Result<RecordState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes the AWS Region.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -3657,13 +7759,171 @@ pub struct Region {
     /// <p>The region name (e.g., <code>us-east-2</code>).</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
+    pub name: Option<RegionName>,
     /// <p>The Availability Zones for databases. Follows the format <code>us-east-2a</code> (case-sensitive).</p>
     #[serde(rename = "relationalDatabaseAvailabilityZones")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub relational_database_availability_zones: Option<Vec<AvailabilityZone>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RegionName {
+    /// `us-east-1`
+    UsEast1,
+    /// `us-east-2`
+    UsEast2,
+    /// `us-west-1`
+    UsWest1,
+    /// `us-west-2`
+    UsWest2,
+    /// `eu-west-1`
+    EuWest1,
+    /// `eu-west-2`
+    EuWest2,
+    /// `eu-west-3`
+    EuWest3,
+    /// `eu-central-1`
+    EuCentral1,
+    /// `ca-central-1`
+    CaCentral1,
+    /// `ap-south-1`
+    ApSouth1,
+    /// `ap-southeast-1`
+    ApSoutheast1,
+    /// `ap-southeast-2`
+    ApSoutheast2,
+    /// `ap-northeast-1`
+    ApNortheast1,
+    /// `ap-northeast-2`
+    ApNortheast2,
+    /// `eu-north-1`
+    EuNorth1,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RegionName {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RegionName::UsEast1 => "us-east-1",
+            RegionName::UsEast2 => "us-east-2",
+            RegionName::UsWest1 => "us-west-1",
+            RegionName::UsWest2 => "us-west-2",
+            RegionName::EuWest1 => "eu-west-1",
+            RegionName::EuWest2 => "eu-west-2",
+            RegionName::EuWest3 => "eu-west-3",
+            RegionName::EuCentral1 => "eu-central-1",
+            RegionName::CaCentral1 => "ca-central-1",
+            RegionName::ApSouth1 => "ap-south-1",
+            RegionName::ApSoutheast1 => "ap-southeast-1",
+            RegionName::ApSoutheast2 => "ap-southeast-2",
+            RegionName::ApNortheast1 => "ap-northeast-1",
+            RegionName::ApNortheast2 => "ap-northeast-2",
+            RegionName::EuNorth1 => "eu-north-1",
+            RegionName::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RegionName {
+    fn default() -> RegionName {
+        RegionName::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RegionName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RegionName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RegionName {
+    fn from(value: &'a str) -> RegionName {
+        match value {
+            "us-east-1" => RegionName::UsEast1,
+            "us-east-2" => RegionName::UsEast2,
+            "us-west-1" => RegionName::UsWest1,
+            "us-west-2" => RegionName::UsWest2,
+            "eu-west-1" => RegionName::EuWest1,
+            "eu-west-2" => RegionName::EuWest2,
+            "eu-west-3" => RegionName::EuWest3,
+            "eu-central-1" => RegionName::EuCentral1,
+            "ca-central-1" => RegionName::CaCentral1,
+            "ap-south-1" => RegionName::ApSouth1,
+            "ap-southeast-1" => RegionName::ApSoutheast1,
+            "ap-southeast-2" => RegionName::ApSoutheast2,
+            "ap-northeast-1" => RegionName::ApNortheast1,
+            "ap-northeast-2" => RegionName::ApNortheast2,
+            "eu-north-1" => RegionName::EuNorth1,
+            _ => RegionName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RegionName {
+    fn from(value: String) -> RegionName {
+        match value.as_str() {
+            "us-east-1" => RegionName::UsEast1,
+            "us-east-2" => RegionName::UsEast2,
+            "us-west-1" => RegionName::UsWest1,
+            "us-west-2" => RegionName::UsWest2,
+            "eu-west-1" => RegionName::EuWest1,
+            "eu-west-2" => RegionName::EuWest2,
+            "eu-west-3" => RegionName::EuWest3,
+            "eu-central-1" => RegionName::EuCentral1,
+            "ca-central-1" => RegionName::CaCentral1,
+            "ap-south-1" => RegionName::ApSouth1,
+            "ap-southeast-1" => RegionName::ApSoutheast1,
+            "ap-southeast-2" => RegionName::ApSoutheast2,
+            "ap-northeast-1" => RegionName::ApNortheast1,
+            "ap-northeast-2" => RegionName::ApNortheast2,
+            "eu-north-1" => RegionName::EuNorth1,
+            _ => RegionName::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RegionName {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RegionName, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RegionName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RegionName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RegionName {
+    fn deserialize<D>(deserializer: D) -> Result<RegionName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes a database.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -3682,8 +7942,13 @@ pub struct RelationalDatabase {
     pub ca_certificate_identifier: Option<String>,
     /// <p>The timestamp when the database was created. Formatted in Unix time.</p>
     #[serde(rename = "createdAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The database software (for example, <code>MySQL</code>).</p>
     #[serde(rename = "engine")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3698,8 +7963,13 @@ pub struct RelationalDatabase {
     pub hardware: Option<RelationalDatabaseHardware>,
     /// <p>The latest point in time to which the database can be restored. Formatted in Unix time.</p>
     #[serde(rename = "latestRestorableTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub latest_restorable_time: Option<f64>,
+    pub latest_restorable_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The Region name and Availability Zone where the database is located.</p>
     #[serde(rename = "location")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3755,7 +8025,7 @@ pub struct RelationalDatabase {
     /// <p>The Lightsail resource type for the database (for example, <code>RelationalDatabase</code>).</p>
     #[serde(rename = "resourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ResourceType>,
     /// <p>Describes the secondary Availability Zone of a high availability database.</p> <p>The secondary database is used for failover support of a high availability database.</p>
     #[serde(rename = "secondaryAvailabilityZone")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3785,7 +8055,7 @@ pub struct RelationalDatabaseBlueprint {
     /// <p>The database software of the database blueprint (for example, <code>MySQL</code>).</p>
     #[serde(rename = "engine")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub engine: Option<String>,
+    pub engine: Option<RelationalDatabaseEngine>,
     /// <p>The description of the database engine for the database blueprint.</p>
     #[serde(rename = "engineDescription")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3860,14 +8130,107 @@ pub struct RelationalDatabaseEndpoint {
     pub port: Option<i64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RelationalDatabaseEngine {
+    /// `mysql`
+    Mysql,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RelationalDatabaseEngine {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RelationalDatabaseEngine::Mysql => "mysql",
+            RelationalDatabaseEngine::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RelationalDatabaseEngine {
+    fn default() -> RelationalDatabaseEngine {
+        RelationalDatabaseEngine::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RelationalDatabaseEngine {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RelationalDatabaseEngine {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RelationalDatabaseEngine {
+    fn from(value: &'a str) -> RelationalDatabaseEngine {
+        match value {
+            "mysql" => RelationalDatabaseEngine::Mysql,
+            _ => RelationalDatabaseEngine::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RelationalDatabaseEngine {
+    fn from(value: String) -> RelationalDatabaseEngine {
+        match value.as_str() {
+            "mysql" => RelationalDatabaseEngine::Mysql,
+            _ => RelationalDatabaseEngine::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RelationalDatabaseEngine {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RelationalDatabaseEngine, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RelationalDatabaseEngine {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RelationalDatabaseEngine {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RelationalDatabaseEngine {
+    fn deserialize<D>(deserializer: D) -> Result<RelationalDatabaseEngine, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes an event for a database.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RelationalDatabaseEvent {
     /// <p>The timestamp when the database event was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The category that the database event belongs to.</p>
     #[serde(rename = "eventCategories")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3900,6 +8263,119 @@ pub struct RelationalDatabaseHardware {
     pub ram_size_in_gb: Option<f32>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RelationalDatabaseMetricName {
+    /// `CPUUtilization`
+    CPUUtilization,
+    /// `DatabaseConnections`
+    DatabaseConnections,
+    /// `DiskQueueDepth`
+    DiskQueueDepth,
+    /// `FreeStorageSpace`
+    FreeStorageSpace,
+    /// `NetworkReceiveThroughput`
+    NetworkReceiveThroughput,
+    /// `NetworkTransmitThroughput`
+    NetworkTransmitThroughput,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RelationalDatabaseMetricName {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RelationalDatabaseMetricName::CPUUtilization => "CPUUtilization",
+            RelationalDatabaseMetricName::DatabaseConnections => "DatabaseConnections",
+            RelationalDatabaseMetricName::DiskQueueDepth => "DiskQueueDepth",
+            RelationalDatabaseMetricName::FreeStorageSpace => "FreeStorageSpace",
+            RelationalDatabaseMetricName::NetworkReceiveThroughput => "NetworkReceiveThroughput",
+            RelationalDatabaseMetricName::NetworkTransmitThroughput => "NetworkTransmitThroughput",
+            RelationalDatabaseMetricName::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RelationalDatabaseMetricName {
+    fn default() -> RelationalDatabaseMetricName {
+        RelationalDatabaseMetricName::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RelationalDatabaseMetricName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RelationalDatabaseMetricName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RelationalDatabaseMetricName {
+    fn from(value: &'a str) -> RelationalDatabaseMetricName {
+        match value {
+            "CPUUtilization" => RelationalDatabaseMetricName::CPUUtilization,
+            "DatabaseConnections" => RelationalDatabaseMetricName::DatabaseConnections,
+            "DiskQueueDepth" => RelationalDatabaseMetricName::DiskQueueDepth,
+            "FreeStorageSpace" => RelationalDatabaseMetricName::FreeStorageSpace,
+            "NetworkReceiveThroughput" => RelationalDatabaseMetricName::NetworkReceiveThroughput,
+            "NetworkTransmitThroughput" => RelationalDatabaseMetricName::NetworkTransmitThroughput,
+            _ => RelationalDatabaseMetricName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RelationalDatabaseMetricName {
+    fn from(value: String) -> RelationalDatabaseMetricName {
+        match value.as_str() {
+            "CPUUtilization" => RelationalDatabaseMetricName::CPUUtilization,
+            "DatabaseConnections" => RelationalDatabaseMetricName::DatabaseConnections,
+            "DiskQueueDepth" => RelationalDatabaseMetricName::DiskQueueDepth,
+            "FreeStorageSpace" => RelationalDatabaseMetricName::FreeStorageSpace,
+            "NetworkReceiveThroughput" => RelationalDatabaseMetricName::NetworkReceiveThroughput,
+            "NetworkTransmitThroughput" => RelationalDatabaseMetricName::NetworkTransmitThroughput,
+            _ => RelationalDatabaseMetricName::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RelationalDatabaseMetricName {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RelationalDatabaseMetricName, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RelationalDatabaseMetricName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RelationalDatabaseMetricName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RelationalDatabaseMetricName {
+    fn deserialize<D>(deserializer: D) -> Result<RelationalDatabaseMetricName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes the parameters of a database.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RelationalDatabaseParameter {
@@ -3937,6 +8413,104 @@ pub struct RelationalDatabaseParameter {
     pub parameter_value: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RelationalDatabasePasswordVersion {
+    /// `CURRENT`
+    Current,
+    /// `PREVIOUS`
+    Previous,
+    /// `PENDING`
+    Pending,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RelationalDatabasePasswordVersion {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RelationalDatabasePasswordVersion::Current => "CURRENT",
+            RelationalDatabasePasswordVersion::Previous => "PREVIOUS",
+            RelationalDatabasePasswordVersion::Pending => "PENDING",
+            RelationalDatabasePasswordVersion::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RelationalDatabasePasswordVersion {
+    fn default() -> RelationalDatabasePasswordVersion {
+        RelationalDatabasePasswordVersion::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RelationalDatabasePasswordVersion {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RelationalDatabasePasswordVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RelationalDatabasePasswordVersion {
+    fn from(value: &'a str) -> RelationalDatabasePasswordVersion {
+        match value {
+            "CURRENT" => RelationalDatabasePasswordVersion::Current,
+            "PREVIOUS" => RelationalDatabasePasswordVersion::Previous,
+            "PENDING" => RelationalDatabasePasswordVersion::Pending,
+            _ => RelationalDatabasePasswordVersion::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RelationalDatabasePasswordVersion {
+    fn from(value: String) -> RelationalDatabasePasswordVersion {
+        match value.as_str() {
+            "CURRENT" => RelationalDatabasePasswordVersion::Current,
+            "PREVIOUS" => RelationalDatabasePasswordVersion::Previous,
+            "PENDING" => RelationalDatabasePasswordVersion::Pending,
+            _ => RelationalDatabasePasswordVersion::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RelationalDatabasePasswordVersion {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RelationalDatabasePasswordVersion, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RelationalDatabasePasswordVersion {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RelationalDatabasePasswordVersion {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RelationalDatabasePasswordVersion {
+    fn deserialize<D>(deserializer: D) -> Result<RelationalDatabasePasswordVersion, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes a database snapshot.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -3947,8 +8521,13 @@ pub struct RelationalDatabaseSnapshot {
     pub arn: Option<String>,
     /// <p>The timestamp when the database snapshot was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The software of the database snapshot (for example, <code>MySQL</code>)</p>
     #[serde(rename = "engine")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3984,7 +8563,7 @@ pub struct RelationalDatabaseSnapshot {
     /// <p>The Lightsail resource type.</p>
     #[serde(rename = "resourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ResourceType>,
     /// <p>The size of the disk in GB (for example, <code>32</code>) for the database snapshot.</p>
     #[serde(rename = "sizeInGb")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4031,9 +8610,192 @@ pub struct ResourceLocation {
     /// <p>The AWS Region name.</p>
     #[serde(rename = "regionName")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub region_name: Option<String>,
+    pub region_name: Option<RegionName>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ResourceType {
+    /// `ContainerService`
+    ContainerService,
+    /// `Instance`
+    Instance,
+    /// `StaticIp`
+    StaticIp,
+    /// `KeyPair`
+    KeyPair,
+    /// `InstanceSnapshot`
+    InstanceSnapshot,
+    /// `Domain`
+    Domain,
+    /// `PeeredVpc`
+    PeeredVpc,
+    /// `LoadBalancer`
+    LoadBalancer,
+    /// `LoadBalancerTlsCertificate`
+    LoadBalancerTlsCertificate,
+    /// `Disk`
+    Disk,
+    /// `DiskSnapshot`
+    DiskSnapshot,
+    /// `RelationalDatabase`
+    RelationalDatabase,
+    /// `RelationalDatabaseSnapshot`
+    RelationalDatabaseSnapshot,
+    /// `ExportSnapshotRecord`
+    ExportSnapshotRecord,
+    /// `CloudFormationStackRecord`
+    CloudFormationStackRecord,
+    /// `Alarm`
+    Alarm,
+    /// `ContactMethod`
+    ContactMethod,
+    /// `Distribution`
+    Distribution,
+    /// `Certificate`
+    Certificate,
+    /// `Bucket`
+    Bucket,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ResourceType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceType::ContainerService => "ContainerService",
+            ResourceType::Instance => "Instance",
+            ResourceType::StaticIp => "StaticIp",
+            ResourceType::KeyPair => "KeyPair",
+            ResourceType::InstanceSnapshot => "InstanceSnapshot",
+            ResourceType::Domain => "Domain",
+            ResourceType::PeeredVpc => "PeeredVpc",
+            ResourceType::LoadBalancer => "LoadBalancer",
+            ResourceType::LoadBalancerTlsCertificate => "LoadBalancerTlsCertificate",
+            ResourceType::Disk => "Disk",
+            ResourceType::DiskSnapshot => "DiskSnapshot",
+            ResourceType::RelationalDatabase => "RelationalDatabase",
+            ResourceType::RelationalDatabaseSnapshot => "RelationalDatabaseSnapshot",
+            ResourceType::ExportSnapshotRecord => "ExportSnapshotRecord",
+            ResourceType::CloudFormationStackRecord => "CloudFormationStackRecord",
+            ResourceType::Alarm => "Alarm",
+            ResourceType::ContactMethod => "ContactMethod",
+            ResourceType::Distribution => "Distribution",
+            ResourceType::Certificate => "Certificate",
+            ResourceType::Bucket => "Bucket",
+            ResourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ResourceType {
+    fn default() -> ResourceType {
+        ResourceType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ResourceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
 }
 
+impl fmt::Display for ResourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ResourceType {
+    fn from(value: &'a str) -> ResourceType {
+        match value {
+            "ContainerService" => ResourceType::ContainerService,
+            "Instance" => ResourceType::Instance,
+            "StaticIp" => ResourceType::StaticIp,
+            "KeyPair" => ResourceType::KeyPair,
+            "InstanceSnapshot" => ResourceType::InstanceSnapshot,
+            "Domain" => ResourceType::Domain,
+            "PeeredVpc" => ResourceType::PeeredVpc,
+            "LoadBalancer" => ResourceType::LoadBalancer,
+            "LoadBalancerTlsCertificate" => ResourceType::LoadBalancerTlsCertificate,
+            "Disk" => ResourceType::Disk,
+            "DiskSnapshot" => ResourceType::DiskSnapshot,
+            "RelationalDatabase" => ResourceType::RelationalDatabase,
+            "RelationalDatabaseSnapshot" => ResourceType::RelationalDatabaseSnapshot,
+            "ExportSnapshotRecord" => ResourceType::ExportSnapshotRecord,
+            "CloudFormationStackRecord" => ResourceType::CloudFormationStackRecord,
+            "Alarm" => ResourceType::Alarm,
+            "ContactMethod" => ResourceType::ContactMethod,
+            "Distribution" => ResourceType::Distribution,
+            "Certificate" => ResourceType::Certificate,
+            "Bucket" => ResourceType::Bucket,
+            _ => ResourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceType {
+    fn from(value: String) -> ResourceType {
+        match value.as_str() {
+            "ContainerService" => ResourceType::ContainerService,
+            "Instance" => ResourceType::Instance,
+            "StaticIp" => ResourceType::StaticIp,
+            "KeyPair" => ResourceType::KeyPair,
+            "InstanceSnapshot" => ResourceType::InstanceSnapshot,
+            "Domain" => ResourceType::Domain,
+            "PeeredVpc" => ResourceType::PeeredVpc,
+            "LoadBalancer" => ResourceType::LoadBalancer,
+            "LoadBalancerTlsCertificate" => ResourceType::LoadBalancerTlsCertificate,
+            "Disk" => ResourceType::Disk,
+            "DiskSnapshot" => ResourceType::DiskSnapshot,
+            "RelationalDatabase" => ResourceType::RelationalDatabase,
+            "RelationalDatabaseSnapshot" => ResourceType::RelationalDatabaseSnapshot,
+            "ExportSnapshotRecord" => ResourceType::ExportSnapshotRecord,
+            "CloudFormationStackRecord" => ResourceType::CloudFormationStackRecord,
+            "Alarm" => ResourceType::Alarm,
+            "ContactMethod" => ResourceType::ContactMethod,
+            "Distribution" => ResourceType::Distribution,
+            "Certificate" => ResourceType::Certificate,
+            "Bucket" => ResourceType::Bucket,
+            _ => ResourceType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ResourceType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ResourceType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ResourceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ResourceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ResourceType {
+    fn deserialize<D>(deserializer: D) -> Result<ResourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct StartInstanceRequest {
@@ -4082,8 +8844,13 @@ pub struct StaticIp {
     pub attached_to: Option<String>,
     /// <p>The timestamp when the static IP was created (e.g., <code>1479735304.222</code>).</p>
     #[serde(rename = "createdAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The static IP address.</p>
     #[serde(rename = "ipAddress")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4103,7 +8870,7 @@ pub struct StaticIp {
     /// <p>The resource type (usually <code>StaticIp</code>).</p>
     #[serde(rename = "resourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ResourceType>,
     /// <p>The support code. Include this code in your email to support when you have questions about an instance or another resource in Lightsail. This code enables our support team to look up your Lightsail information more easily.</p>
     #[serde(rename = "supportCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4251,7 +9018,7 @@ pub struct UpdateDomainEntryResult {
 pub struct UpdateLoadBalancerAttributeRequest {
     /// <p>The name of the attribute you want to update. Valid values are below.</p>
     #[serde(rename = "attributeName")]
-    pub attribute_name: String,
+    pub attribute_name: LoadBalancerAttributeName,
     /// <p>The value that you want to specify for the attribute name.</p>
     #[serde(rename = "attributeValue")]
     pub attribute_value: String,
@@ -12737,11 +17504,12 @@ pub struct LightsailClient {
 impl LightsailClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> LightsailClient {
         LightsailClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Lightsail"),
         }
     }
 
@@ -12773,6 +17541,7 @@ impl Lightsail for LightsailClient {
         input: AllocateStaticIpRequest,
     ) -> Result<AllocateStaticIpResult, RusotoError<AllocateStaticIpError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("AllocateStaticIp");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.AllocateStaticIp");
@@ -12800,6 +17569,7 @@ impl Lightsail for LightsailClient {
         input: AttachDiskRequest,
     ) -> Result<AttachDiskResult, RusotoError<AttachDiskError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("AttachDisk");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.AttachDisk");
@@ -12828,6 +17598,7 @@ impl Lightsail for LightsailClient {
     ) -> Result<AttachInstancesToLoadBalancerResult, RusotoError<AttachInstancesToLoadBalancerError>>
     {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("AttachInstancesToLoadBalancer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -12862,6 +17633,7 @@ impl Lightsail for LightsailClient {
         RusotoError<AttachLoadBalancerTlsCertificateError>,
     > {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("AttachLoadBalancerTlsCertificate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -12895,6 +17667,7 @@ impl Lightsail for LightsailClient {
         input: AttachStaticIpRequest,
     ) -> Result<AttachStaticIpResult, RusotoError<AttachStaticIpError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("AttachStaticIp");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.AttachStaticIp");
@@ -12922,6 +17695,7 @@ impl Lightsail for LightsailClient {
         input: CloseInstancePublicPortsRequest,
     ) -> Result<CloseInstancePublicPortsResult, RusotoError<CloseInstancePublicPortsError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("CloseInstancePublicPorts");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -12953,6 +17727,7 @@ impl Lightsail for LightsailClient {
         input: CopySnapshotRequest,
     ) -> Result<CopySnapshotResult, RusotoError<CopySnapshotError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("CopySnapshot");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.CopySnapshot");
@@ -12980,6 +17755,7 @@ impl Lightsail for LightsailClient {
         input: CreateCloudFormationStackRequest,
     ) -> Result<CreateCloudFormationStackResult, RusotoError<CreateCloudFormationStackError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("CreateCloudFormationStack");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13011,6 +17787,7 @@ impl Lightsail for LightsailClient {
         input: CreateDiskRequest,
     ) -> Result<CreateDiskResult, RusotoError<CreateDiskError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("CreateDisk");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.CreateDisk");
@@ -13038,6 +17815,7 @@ impl Lightsail for LightsailClient {
         input: CreateDiskFromSnapshotRequest,
     ) -> Result<CreateDiskFromSnapshotResult, RusotoError<CreateDiskFromSnapshotError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("CreateDiskFromSnapshot");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.CreateDiskFromSnapshot");
@@ -13066,6 +17844,7 @@ impl Lightsail for LightsailClient {
         input: CreateDiskSnapshotRequest,
     ) -> Result<CreateDiskSnapshotResult, RusotoError<CreateDiskSnapshotError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("CreateDiskSnapshot");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.CreateDiskSnapshot");
@@ -13094,6 +17873,7 @@ impl Lightsail for LightsailClient {
         input: CreateDomainRequest,
     ) -> Result<CreateDomainResult, RusotoError<CreateDomainError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("CreateDomain");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.CreateDomain");
@@ -13121,6 +17901,7 @@ impl Lightsail for LightsailClient {
         input: CreateDomainEntryRequest,
     ) -> Result<CreateDomainEntryResult, RusotoError<CreateDomainEntryError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("CreateDomainEntry");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.CreateDomainEntry");
@@ -13148,6 +17929,7 @@ impl Lightsail for LightsailClient {
         input: CreateInstanceSnapshotRequest,
     ) -> Result<CreateInstanceSnapshotResult, RusotoError<CreateInstanceSnapshotError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("CreateInstanceSnapshot");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.CreateInstanceSnapshot");
@@ -13176,6 +17958,7 @@ impl Lightsail for LightsailClient {
         input: CreateInstancesRequest,
     ) -> Result<CreateInstancesResult, RusotoError<CreateInstancesError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("CreateInstances");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.CreateInstances");
@@ -13204,6 +17987,7 @@ impl Lightsail for LightsailClient {
     ) -> Result<CreateInstancesFromSnapshotResult, RusotoError<CreateInstancesFromSnapshotError>>
     {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("CreateInstancesFromSnapshot");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13235,6 +18019,7 @@ impl Lightsail for LightsailClient {
         input: CreateKeyPairRequest,
     ) -> Result<CreateKeyPairResult, RusotoError<CreateKeyPairError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("CreateKeyPair");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.CreateKeyPair");
@@ -13262,6 +18047,7 @@ impl Lightsail for LightsailClient {
         input: CreateLoadBalancerRequest,
     ) -> Result<CreateLoadBalancerResult, RusotoError<CreateLoadBalancerError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("CreateLoadBalancer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.CreateLoadBalancer");
@@ -13293,6 +18079,7 @@ impl Lightsail for LightsailClient {
         RusotoError<CreateLoadBalancerTlsCertificateError>,
     > {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("CreateLoadBalancerTlsCertificate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13326,6 +18113,7 @@ impl Lightsail for LightsailClient {
         input: CreateRelationalDatabaseRequest,
     ) -> Result<CreateRelationalDatabaseResult, RusotoError<CreateRelationalDatabaseError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("CreateRelationalDatabase");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13360,6 +18148,7 @@ impl Lightsail for LightsailClient {
         RusotoError<CreateRelationalDatabaseFromSnapshotError>,
     > {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("CreateRelationalDatabaseFromSnapshot");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13396,6 +18185,7 @@ impl Lightsail for LightsailClient {
         RusotoError<CreateRelationalDatabaseSnapshotError>,
     > {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("CreateRelationalDatabaseSnapshot");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13429,6 +18219,7 @@ impl Lightsail for LightsailClient {
         input: DeleteAutoSnapshotRequest,
     ) -> Result<DeleteAutoSnapshotResult, RusotoError<DeleteAutoSnapshotError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("DeleteAutoSnapshot");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.DeleteAutoSnapshot");
@@ -13457,6 +18248,7 @@ impl Lightsail for LightsailClient {
         input: DeleteDiskRequest,
     ) -> Result<DeleteDiskResult, RusotoError<DeleteDiskError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("DeleteDisk");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.DeleteDisk");
@@ -13484,6 +18276,7 @@ impl Lightsail for LightsailClient {
         input: DeleteDiskSnapshotRequest,
     ) -> Result<DeleteDiskSnapshotResult, RusotoError<DeleteDiskSnapshotError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("DeleteDiskSnapshot");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.DeleteDiskSnapshot");
@@ -13512,6 +18305,7 @@ impl Lightsail for LightsailClient {
         input: DeleteDomainRequest,
     ) -> Result<DeleteDomainResult, RusotoError<DeleteDomainError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("DeleteDomain");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.DeleteDomain");
@@ -13539,6 +18333,7 @@ impl Lightsail for LightsailClient {
         input: DeleteDomainEntryRequest,
     ) -> Result<DeleteDomainEntryResult, RusotoError<DeleteDomainEntryError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("DeleteDomainEntry");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.DeleteDomainEntry");
@@ -13566,6 +18361,7 @@ impl Lightsail for LightsailClient {
         input: DeleteInstanceRequest,
     ) -> Result<DeleteInstanceResult, RusotoError<DeleteInstanceError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("DeleteInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.DeleteInstance");
@@ -13593,6 +18389,7 @@ impl Lightsail for LightsailClient {
         input: DeleteInstanceSnapshotRequest,
     ) -> Result<DeleteInstanceSnapshotResult, RusotoError<DeleteInstanceSnapshotError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("DeleteInstanceSnapshot");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.DeleteInstanceSnapshot");
@@ -13621,6 +18418,7 @@ impl Lightsail for LightsailClient {
         input: DeleteKeyPairRequest,
     ) -> Result<DeleteKeyPairResult, RusotoError<DeleteKeyPairError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("DeleteKeyPair");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.DeleteKeyPair");
@@ -13648,6 +18446,7 @@ impl Lightsail for LightsailClient {
         input: DeleteKnownHostKeysRequest,
     ) -> Result<DeleteKnownHostKeysResult, RusotoError<DeleteKnownHostKeysError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("DeleteKnownHostKeys");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.DeleteKnownHostKeys");
@@ -13676,6 +18475,7 @@ impl Lightsail for LightsailClient {
         input: DeleteLoadBalancerRequest,
     ) -> Result<DeleteLoadBalancerResult, RusotoError<DeleteLoadBalancerError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("DeleteLoadBalancer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.DeleteLoadBalancer");
@@ -13707,6 +18507,7 @@ impl Lightsail for LightsailClient {
         RusotoError<DeleteLoadBalancerTlsCertificateError>,
     > {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("DeleteLoadBalancerTlsCertificate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13740,6 +18541,7 @@ impl Lightsail for LightsailClient {
         input: DeleteRelationalDatabaseRequest,
     ) -> Result<DeleteRelationalDatabaseResult, RusotoError<DeleteRelationalDatabaseError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("DeleteRelationalDatabase");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13774,6 +18576,7 @@ impl Lightsail for LightsailClient {
         RusotoError<DeleteRelationalDatabaseSnapshotError>,
     > {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("DeleteRelationalDatabaseSnapshot");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13807,6 +18610,7 @@ impl Lightsail for LightsailClient {
         input: DetachDiskRequest,
     ) -> Result<DetachDiskResult, RusotoError<DetachDiskError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("DetachDisk");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.DetachDisk");
@@ -13837,6 +18641,7 @@ impl Lightsail for LightsailClient {
         RusotoError<DetachInstancesFromLoadBalancerError>,
     > {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("DetachInstancesFromLoadBalancer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13870,6 +18675,7 @@ impl Lightsail for LightsailClient {
         input: DetachStaticIpRequest,
     ) -> Result<DetachStaticIpResult, RusotoError<DetachStaticIpError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("DetachStaticIp");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.DetachStaticIp");
@@ -13897,6 +18703,7 @@ impl Lightsail for LightsailClient {
         input: DisableAddOnRequest,
     ) -> Result<DisableAddOnResult, RusotoError<DisableAddOnError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("DisableAddOn");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.DisableAddOn");
@@ -13923,6 +18730,7 @@ impl Lightsail for LightsailClient {
         &self,
     ) -> Result<DownloadDefaultKeyPairResult, RusotoError<DownloadDefaultKeyPairError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("DownloadDefaultKeyPair");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.DownloadDefaultKeyPair");
@@ -13950,6 +18758,7 @@ impl Lightsail for LightsailClient {
         input: EnableAddOnRequest,
     ) -> Result<EnableAddOnResult, RusotoError<EnableAddOnError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("EnableAddOn");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.EnableAddOn");
@@ -13977,6 +18786,7 @@ impl Lightsail for LightsailClient {
         input: ExportSnapshotRequest,
     ) -> Result<ExportSnapshotResult, RusotoError<ExportSnapshotError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("ExportSnapshot");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.ExportSnapshot");
@@ -14004,6 +18814,7 @@ impl Lightsail for LightsailClient {
         input: GetActiveNamesRequest,
     ) -> Result<GetActiveNamesResult, RusotoError<GetActiveNamesError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetActiveNames");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.GetActiveNames");
@@ -14031,6 +18842,7 @@ impl Lightsail for LightsailClient {
         input: GetAutoSnapshotsRequest,
     ) -> Result<GetAutoSnapshotsResult, RusotoError<GetAutoSnapshotsError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetAutoSnapshots");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.GetAutoSnapshots");
@@ -14058,6 +18870,7 @@ impl Lightsail for LightsailClient {
         input: GetBlueprintsRequest,
     ) -> Result<GetBlueprintsResult, RusotoError<GetBlueprintsError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetBlueprints");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.GetBlueprints");
@@ -14085,6 +18898,7 @@ impl Lightsail for LightsailClient {
         input: GetBundlesRequest,
     ) -> Result<GetBundlesResult, RusotoError<GetBundlesError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetBundles");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.GetBundles");
@@ -14113,6 +18927,7 @@ impl Lightsail for LightsailClient {
     ) -> Result<GetCloudFormationStackRecordsResult, RusotoError<GetCloudFormationStackRecordsError>>
     {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetCloudFormationStackRecords");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14144,6 +18959,7 @@ impl Lightsail for LightsailClient {
         input: GetDiskRequest,
     ) -> Result<GetDiskResult, RusotoError<GetDiskError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetDisk");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.GetDisk");
@@ -14171,6 +18987,7 @@ impl Lightsail for LightsailClient {
         input: GetDiskSnapshotRequest,
     ) -> Result<GetDiskSnapshotResult, RusotoError<GetDiskSnapshotError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetDiskSnapshot");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.GetDiskSnapshot");
@@ -14198,6 +19015,7 @@ impl Lightsail for LightsailClient {
         input: GetDiskSnapshotsRequest,
     ) -> Result<GetDiskSnapshotsResult, RusotoError<GetDiskSnapshotsError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetDiskSnapshots");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.GetDiskSnapshots");
@@ -14225,6 +19043,7 @@ impl Lightsail for LightsailClient {
         input: GetDisksRequest,
     ) -> Result<GetDisksResult, RusotoError<GetDisksError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetDisks");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.GetDisks");
@@ -14252,6 +19071,7 @@ impl Lightsail for LightsailClient {
         input: GetDomainRequest,
     ) -> Result<GetDomainResult, RusotoError<GetDomainError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetDomain");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.GetDomain");
@@ -14279,6 +19099,7 @@ impl Lightsail for LightsailClient {
         input: GetDomainsRequest,
     ) -> Result<GetDomainsResult, RusotoError<GetDomainsError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetDomains");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.GetDomains");
@@ -14306,6 +19127,7 @@ impl Lightsail for LightsailClient {
         input: GetExportSnapshotRecordsRequest,
     ) -> Result<GetExportSnapshotRecordsResult, RusotoError<GetExportSnapshotRecordsError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetExportSnapshotRecords");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14337,6 +19159,7 @@ impl Lightsail for LightsailClient {
         input: GetInstanceRequest,
     ) -> Result<GetInstanceResult, RusotoError<GetInstanceError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.GetInstance");
@@ -14364,6 +19187,7 @@ impl Lightsail for LightsailClient {
         input: GetInstanceAccessDetailsRequest,
     ) -> Result<GetInstanceAccessDetailsResult, RusotoError<GetInstanceAccessDetailsError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetInstanceAccessDetails");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14395,6 +19219,7 @@ impl Lightsail for LightsailClient {
         input: GetInstanceMetricDataRequest,
     ) -> Result<GetInstanceMetricDataResult, RusotoError<GetInstanceMetricDataError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetInstanceMetricData");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.GetInstanceMetricData");
@@ -14423,6 +19248,7 @@ impl Lightsail for LightsailClient {
         input: GetInstancePortStatesRequest,
     ) -> Result<GetInstancePortStatesResult, RusotoError<GetInstancePortStatesError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetInstancePortStates");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.GetInstancePortStates");
@@ -14451,6 +19277,7 @@ impl Lightsail for LightsailClient {
         input: GetInstanceSnapshotRequest,
     ) -> Result<GetInstanceSnapshotResult, RusotoError<GetInstanceSnapshotError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetInstanceSnapshot");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.GetInstanceSnapshot");
@@ -14479,6 +19306,7 @@ impl Lightsail for LightsailClient {
         input: GetInstanceSnapshotsRequest,
     ) -> Result<GetInstanceSnapshotsResult, RusotoError<GetInstanceSnapshotsError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetInstanceSnapshots");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.GetInstanceSnapshots");
@@ -14507,6 +19335,7 @@ impl Lightsail for LightsailClient {
         input: GetInstanceStateRequest,
     ) -> Result<GetInstanceStateResult, RusotoError<GetInstanceStateError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetInstanceState");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.GetInstanceState");
@@ -14534,6 +19363,7 @@ impl Lightsail for LightsailClient {
         input: GetInstancesRequest,
     ) -> Result<GetInstancesResult, RusotoError<GetInstancesError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetInstances");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.GetInstances");
@@ -14561,6 +19391,7 @@ impl Lightsail for LightsailClient {
         input: GetKeyPairRequest,
     ) -> Result<GetKeyPairResult, RusotoError<GetKeyPairError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetKeyPair");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.GetKeyPair");
@@ -14588,6 +19419,7 @@ impl Lightsail for LightsailClient {
         input: GetKeyPairsRequest,
     ) -> Result<GetKeyPairsResult, RusotoError<GetKeyPairsError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetKeyPairs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.GetKeyPairs");
@@ -14615,6 +19447,7 @@ impl Lightsail for LightsailClient {
         input: GetLoadBalancerRequest,
     ) -> Result<GetLoadBalancerResult, RusotoError<GetLoadBalancerError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetLoadBalancer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.GetLoadBalancer");
@@ -14642,6 +19475,7 @@ impl Lightsail for LightsailClient {
         input: GetLoadBalancerMetricDataRequest,
     ) -> Result<GetLoadBalancerMetricDataResult, RusotoError<GetLoadBalancerMetricDataError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetLoadBalancerMetricData");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14676,6 +19510,7 @@ impl Lightsail for LightsailClient {
         RusotoError<GetLoadBalancerTlsCertificatesError>,
     > {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetLoadBalancerTlsCertificates");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14707,6 +19542,7 @@ impl Lightsail for LightsailClient {
         input: GetLoadBalancersRequest,
     ) -> Result<GetLoadBalancersResult, RusotoError<GetLoadBalancersError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetLoadBalancers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.GetLoadBalancers");
@@ -14734,6 +19570,7 @@ impl Lightsail for LightsailClient {
         input: GetOperationRequest,
     ) -> Result<GetOperationResult, RusotoError<GetOperationError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetOperation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.GetOperation");
@@ -14761,6 +19598,7 @@ impl Lightsail for LightsailClient {
         input: GetOperationsRequest,
     ) -> Result<GetOperationsResult, RusotoError<GetOperationsError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetOperations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.GetOperations");
@@ -14788,6 +19626,7 @@ impl Lightsail for LightsailClient {
         input: GetOperationsForResourceRequest,
     ) -> Result<GetOperationsForResourceResult, RusotoError<GetOperationsForResourceError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetOperationsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14819,6 +19658,7 @@ impl Lightsail for LightsailClient {
         input: GetRegionsRequest,
     ) -> Result<GetRegionsResult, RusotoError<GetRegionsError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetRegions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.GetRegions");
@@ -14846,6 +19686,7 @@ impl Lightsail for LightsailClient {
         input: GetRelationalDatabaseRequest,
     ) -> Result<GetRelationalDatabaseResult, RusotoError<GetRelationalDatabaseError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetRelationalDatabase");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.GetRelationalDatabase");
@@ -14877,6 +19718,7 @@ impl Lightsail for LightsailClient {
         RusotoError<GetRelationalDatabaseBlueprintsError>,
     > {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetRelationalDatabaseBlueprints");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14911,6 +19753,7 @@ impl Lightsail for LightsailClient {
     ) -> Result<GetRelationalDatabaseBundlesResult, RusotoError<GetRelationalDatabaseBundlesError>>
     {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetRelationalDatabaseBundles");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14943,6 +19786,7 @@ impl Lightsail for LightsailClient {
     ) -> Result<GetRelationalDatabaseEventsResult, RusotoError<GetRelationalDatabaseEventsError>>
     {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetRelationalDatabaseEvents");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14977,6 +19821,7 @@ impl Lightsail for LightsailClient {
         RusotoError<GetRelationalDatabaseLogEventsError>,
     > {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetRelationalDatabaseLogEvents");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15011,6 +19856,7 @@ impl Lightsail for LightsailClient {
         RusotoError<GetRelationalDatabaseLogStreamsError>,
     > {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetRelationalDatabaseLogStreams");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15047,6 +19893,7 @@ impl Lightsail for LightsailClient {
         RusotoError<GetRelationalDatabaseMasterUserPasswordError>,
     > {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetRelationalDatabaseMasterUserPassword");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15083,6 +19930,7 @@ impl Lightsail for LightsailClient {
         RusotoError<GetRelationalDatabaseMetricDataError>,
     > {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetRelationalDatabaseMetricData");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15119,6 +19967,7 @@ impl Lightsail for LightsailClient {
         RusotoError<GetRelationalDatabaseParametersError>,
     > {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetRelationalDatabaseParameters");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15153,6 +20002,7 @@ impl Lightsail for LightsailClient {
     ) -> Result<GetRelationalDatabaseSnapshotResult, RusotoError<GetRelationalDatabaseSnapshotError>>
     {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetRelationalDatabaseSnapshot");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15187,6 +20037,7 @@ impl Lightsail for LightsailClient {
         RusotoError<GetRelationalDatabaseSnapshotsError>,
     > {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetRelationalDatabaseSnapshots");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15218,6 +20069,7 @@ impl Lightsail for LightsailClient {
         input: GetRelationalDatabasesRequest,
     ) -> Result<GetRelationalDatabasesResult, RusotoError<GetRelationalDatabasesError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetRelationalDatabases");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.GetRelationalDatabases");
@@ -15246,6 +20098,7 @@ impl Lightsail for LightsailClient {
         input: GetStaticIpRequest,
     ) -> Result<GetStaticIpResult, RusotoError<GetStaticIpError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetStaticIp");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.GetStaticIp");
@@ -15273,6 +20126,7 @@ impl Lightsail for LightsailClient {
         input: GetStaticIpsRequest,
     ) -> Result<GetStaticIpsResult, RusotoError<GetStaticIpsError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetStaticIps");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.GetStaticIps");
@@ -15300,6 +20154,7 @@ impl Lightsail for LightsailClient {
         input: ImportKeyPairRequest,
     ) -> Result<ImportKeyPairResult, RusotoError<ImportKeyPairError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("ImportKeyPair");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.ImportKeyPair");
@@ -15324,6 +20179,7 @@ impl Lightsail for LightsailClient {
     /// <p>Returns a Boolean value indicating whether your Lightsail VPC is peered.</p>
     async fn is_vpc_peered(&self) -> Result<IsVpcPeeredResult, RusotoError<IsVpcPeeredError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("IsVpcPeered");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.IsVpcPeered");
@@ -15350,6 +20206,7 @@ impl Lightsail for LightsailClient {
         input: OpenInstancePublicPortsRequest,
     ) -> Result<OpenInstancePublicPortsResult, RusotoError<OpenInstancePublicPortsError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("OpenInstancePublicPorts");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.OpenInstancePublicPorts");
@@ -15375,6 +20232,7 @@ impl Lightsail for LightsailClient {
     /// <p>Tries to peer the Lightsail VPC with the user's default VPC.</p>
     async fn peer_vpc(&self) -> Result<PeerVpcResult, RusotoError<PeerVpcError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("PeerVpc");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.PeerVpc");
@@ -15401,6 +20259,7 @@ impl Lightsail for LightsailClient {
         input: PutInstancePublicPortsRequest,
     ) -> Result<PutInstancePublicPortsResult, RusotoError<PutInstancePublicPortsError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("PutInstancePublicPorts");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.PutInstancePublicPorts");
@@ -15429,6 +20288,7 @@ impl Lightsail for LightsailClient {
         input: RebootInstanceRequest,
     ) -> Result<RebootInstanceResult, RusotoError<RebootInstanceError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("RebootInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.RebootInstance");
@@ -15456,6 +20316,7 @@ impl Lightsail for LightsailClient {
         input: RebootRelationalDatabaseRequest,
     ) -> Result<RebootRelationalDatabaseResult, RusotoError<RebootRelationalDatabaseError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("RebootRelationalDatabase");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15487,6 +20348,7 @@ impl Lightsail for LightsailClient {
         input: ReleaseStaticIpRequest,
     ) -> Result<ReleaseStaticIpResult, RusotoError<ReleaseStaticIpError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("ReleaseStaticIp");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.ReleaseStaticIp");
@@ -15514,6 +20376,7 @@ impl Lightsail for LightsailClient {
         input: StartInstanceRequest,
     ) -> Result<StartInstanceResult, RusotoError<StartInstanceError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("StartInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.StartInstance");
@@ -15541,6 +20404,7 @@ impl Lightsail for LightsailClient {
         input: StartRelationalDatabaseRequest,
     ) -> Result<StartRelationalDatabaseResult, RusotoError<StartRelationalDatabaseError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("StartRelationalDatabase");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.StartRelationalDatabase");
@@ -15569,6 +20433,7 @@ impl Lightsail for LightsailClient {
         input: StopInstanceRequest,
     ) -> Result<StopInstanceResult, RusotoError<StopInstanceError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("StopInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.StopInstance");
@@ -15596,6 +20461,7 @@ impl Lightsail for LightsailClient {
         input: StopRelationalDatabaseRequest,
     ) -> Result<StopRelationalDatabaseResult, RusotoError<StopRelationalDatabaseError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("StopRelationalDatabase");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.StopRelationalDatabase");
@@ -15624,6 +20490,7 @@ impl Lightsail for LightsailClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResult, RusotoError<TagResourceError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.TagResource");
@@ -15648,6 +20515,7 @@ impl Lightsail for LightsailClient {
     /// <p>Attempts to unpeer the Lightsail VPC from the user's default VPC.</p>
     async fn unpeer_vpc(&self) -> Result<UnpeerVpcResult, RusotoError<UnpeerVpcError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("UnpeerVpc");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.UnpeerVpc");
@@ -15674,6 +20542,7 @@ impl Lightsail for LightsailClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResult, RusotoError<UntagResourceError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.UntagResource");
@@ -15701,6 +20570,7 @@ impl Lightsail for LightsailClient {
         input: UpdateDomainEntryRequest,
     ) -> Result<UpdateDomainEntryResult, RusotoError<UpdateDomainEntryError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("UpdateDomainEntry");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.UpdateDomainEntry");
@@ -15729,6 +20599,7 @@ impl Lightsail for LightsailClient {
     ) -> Result<UpdateLoadBalancerAttributeResult, RusotoError<UpdateLoadBalancerAttributeError>>
     {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("UpdateLoadBalancerAttribute");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15760,6 +20631,7 @@ impl Lightsail for LightsailClient {
         input: UpdateRelationalDatabaseRequest,
     ) -> Result<UpdateRelationalDatabaseResult, RusotoError<UpdateRelationalDatabaseError>> {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("UpdateRelationalDatabase");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15794,6 +20666,7 @@ impl Lightsail for LightsailClient {
         RusotoError<UpdateRelationalDatabaseParametersError>,
     > {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("UpdateRelationalDatabaseParameters");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15821,3 +20694,667 @@ impl Lightsail for LightsailClient {
         }
     }
 }
+impl LightsailClient {
+    /// Auto-paginating version of `get_active_names`, streaming every page of results.
+    pub fn get_active_names_pages(
+        &self,
+        input: GetActiveNamesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetActiveNamesResult, GetActiveNamesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LightsailClient, input: GetActiveNamesRequest| async move {
+                client.get_active_names(input).await
+            },
+            |_input: &GetActiveNamesRequest, output: &GetActiveNamesResult| {
+                Some(GetActiveNamesRequest {
+                    page_token: Some(output.next_page_token.clone()?),
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_active_names`, streaming the `activeNames` of every page of results.
+    pub fn get_active_names_items(
+        &self,
+        input: GetActiveNamesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, GetActiveNamesError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_active_names_pages(input),
+            |output: GetActiveNamesResult| output.active_names.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_blueprints`, streaming every page of results.
+    pub fn get_blueprints_pages(
+        &self,
+        input: GetBlueprintsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetBlueprintsResult, GetBlueprintsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LightsailClient, input: GetBlueprintsRequest| async move {
+                client.get_blueprints(input).await
+            },
+            |input: &GetBlueprintsRequest, output: &GetBlueprintsResult| {
+                Some(GetBlueprintsRequest {
+                    page_token: Some(output.next_page_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_blueprints`, streaming the `blueprints` of every page of results.
+    pub fn get_blueprints_items(
+        &self,
+        input: GetBlueprintsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Blueprint, GetBlueprintsError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_blueprints_pages(input),
+            |output: GetBlueprintsResult| output.blueprints.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_bundles`, streaming every page of results.
+    pub fn get_bundles_pages(
+        &self,
+        input: GetBundlesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetBundlesResult, GetBundlesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LightsailClient, input: GetBundlesRequest| async move {
+                client.get_bundles(input).await
+            },
+            |input: &GetBundlesRequest, output: &GetBundlesResult| {
+                Some(GetBundlesRequest {
+                    page_token: Some(output.next_page_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_bundles`, streaming the `bundles` of every page of results.
+    pub fn get_bundles_items(
+        &self,
+        input: GetBundlesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Bundle, GetBundlesError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_bundles_pages(input),
+            |output: GetBundlesResult| output.bundles.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_cloud_formation_stack_records`, streaming every page of results.
+    pub fn get_cloud_formation_stack_records_pages(
+        &self,
+        input: GetCloudFormationStackRecordsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        GetCloudFormationStackRecordsResult,
+        GetCloudFormationStackRecordsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LightsailClient, input: GetCloudFormationStackRecordsRequest| async move {
+                client.get_cloud_formation_stack_records(input).await
+            },
+            |_input: &GetCloudFormationStackRecordsRequest,
+             output: &GetCloudFormationStackRecordsResult| {
+                Some(GetCloudFormationStackRecordsRequest {
+                    page_token: Some(output.next_page_token.clone()?),
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_cloud_formation_stack_records`, streaming the `cloudFormationStackRecords` of every page of results.
+    pub fn get_cloud_formation_stack_records_items(
+        &self,
+        input: GetCloudFormationStackRecordsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        CloudFormationStackRecord,
+        GetCloudFormationStackRecordsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.get_cloud_formation_stack_records_pages(input),
+            |output: GetCloudFormationStackRecordsResult| {
+                output.cloud_formation_stack_records.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_disk_snapshots`, streaming every page of results.
+    pub fn get_disk_snapshots_pages(
+        &self,
+        input: GetDiskSnapshotsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetDiskSnapshotsResult, GetDiskSnapshotsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LightsailClient, input: GetDiskSnapshotsRequest| async move {
+                client.get_disk_snapshots(input).await
+            },
+            |_input: &GetDiskSnapshotsRequest, output: &GetDiskSnapshotsResult| {
+                Some(GetDiskSnapshotsRequest {
+                    page_token: Some(output.next_page_token.clone()?),
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_disk_snapshots`, streaming the `diskSnapshots` of every page of results.
+    pub fn get_disk_snapshots_items(
+        &self,
+        input: GetDiskSnapshotsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DiskSnapshot, GetDiskSnapshotsError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_disk_snapshots_pages(input),
+            |output: GetDiskSnapshotsResult| output.disk_snapshots.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_disks`, streaming every page of results.
+    pub fn get_disks_pages(
+        &self,
+        input: GetDisksRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetDisksResult, GetDisksError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LightsailClient, input: GetDisksRequest| async move {
+                client.get_disks(input).await
+            },
+            |_input: &GetDisksRequest, output: &GetDisksResult| {
+                Some(GetDisksRequest {
+                    page_token: Some(output.next_page_token.clone()?),
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_disks`, streaming the `disks` of every page of results.
+    pub fn get_disks_items(
+        &self,
+        input: GetDisksRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Disk, GetDisksError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_disks_pages(input),
+            |output: GetDisksResult| output.disks.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_domains`, streaming every page of results.
+    pub fn get_domains_pages(
+        &self,
+        input: GetDomainsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetDomainsResult, GetDomainsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LightsailClient, input: GetDomainsRequest| async move {
+                client.get_domains(input).await
+            },
+            |_input: &GetDomainsRequest, output: &GetDomainsResult| {
+                Some(GetDomainsRequest {
+                    page_token: Some(output.next_page_token.clone()?),
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_domains`, streaming the `domains` of every page of results.
+    pub fn get_domains_items(
+        &self,
+        input: GetDomainsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Domain, GetDomainsError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_domains_pages(input),
+            |output: GetDomainsResult| output.domains.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_export_snapshot_records`, streaming every page of results.
+    pub fn get_export_snapshot_records_pages(
+        &self,
+        input: GetExportSnapshotRecordsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        GetExportSnapshotRecordsResult,
+        GetExportSnapshotRecordsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LightsailClient, input: GetExportSnapshotRecordsRequest| async move {
+                client.get_export_snapshot_records(input).await
+            },
+            |_input: &GetExportSnapshotRecordsRequest, output: &GetExportSnapshotRecordsResult| {
+                Some(GetExportSnapshotRecordsRequest {
+                    page_token: Some(output.next_page_token.clone()?),
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_export_snapshot_records`, streaming the `exportSnapshotRecords` of every page of results.
+    pub fn get_export_snapshot_records_items(
+        &self,
+        input: GetExportSnapshotRecordsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ExportSnapshotRecord, GetExportSnapshotRecordsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.get_export_snapshot_records_pages(input),
+            |output: GetExportSnapshotRecordsResult| {
+                output.export_snapshot_records.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_instance_snapshots`, streaming every page of results.
+    pub fn get_instance_snapshots_pages(
+        &self,
+        input: GetInstanceSnapshotsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetInstanceSnapshotsResult, GetInstanceSnapshotsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LightsailClient, input: GetInstanceSnapshotsRequest| async move {
+                client.get_instance_snapshots(input).await
+            },
+            |_input: &GetInstanceSnapshotsRequest, output: &GetInstanceSnapshotsResult| {
+                Some(GetInstanceSnapshotsRequest {
+                    page_token: Some(output.next_page_token.clone()?),
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_instance_snapshots`, streaming the `instanceSnapshots` of every page of results.
+    pub fn get_instance_snapshots_items(
+        &self,
+        input: GetInstanceSnapshotsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<InstanceSnapshot, GetInstanceSnapshotsError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_instance_snapshots_pages(input),
+            |output: GetInstanceSnapshotsResult| output.instance_snapshots.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_instances`, streaming every page of results.
+    pub fn get_instances_pages(
+        &self,
+        input: GetInstancesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetInstancesResult, GetInstancesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LightsailClient, input: GetInstancesRequest| async move {
+                client.get_instances(input).await
+            },
+            |_input: &GetInstancesRequest, output: &GetInstancesResult| {
+                Some(GetInstancesRequest {
+                    page_token: Some(output.next_page_token.clone()?),
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_instances`, streaming the `instances` of every page of results.
+    pub fn get_instances_items(
+        &self,
+        input: GetInstancesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Instance, GetInstancesError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_instances_pages(input),
+            |output: GetInstancesResult| output.instances.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_key_pairs`, streaming every page of results.
+    pub fn get_key_pairs_pages(
+        &self,
+        input: GetKeyPairsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetKeyPairsResult, GetKeyPairsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LightsailClient, input: GetKeyPairsRequest| async move {
+                client.get_key_pairs(input).await
+            },
+            |_input: &GetKeyPairsRequest, output: &GetKeyPairsResult| {
+                Some(GetKeyPairsRequest {
+                    page_token: Some(output.next_page_token.clone()?),
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_key_pairs`, streaming the `keyPairs` of every page of results.
+    pub fn get_key_pairs_items(
+        &self,
+        input: GetKeyPairsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<KeyPair, GetKeyPairsError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_key_pairs_pages(input),
+            |output: GetKeyPairsResult| output.key_pairs.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_load_balancers`, streaming every page of results.
+    pub fn get_load_balancers_pages(
+        &self,
+        input: GetLoadBalancersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetLoadBalancersResult, GetLoadBalancersError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LightsailClient, input: GetLoadBalancersRequest| async move {
+                client.get_load_balancers(input).await
+            },
+            |_input: &GetLoadBalancersRequest, output: &GetLoadBalancersResult| {
+                Some(GetLoadBalancersRequest {
+                    page_token: Some(output.next_page_token.clone()?),
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_load_balancers`, streaming the `loadBalancers` of every page of results.
+    pub fn get_load_balancers_items(
+        &self,
+        input: GetLoadBalancersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<LoadBalancer, GetLoadBalancersError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_load_balancers_pages(input),
+            |output: GetLoadBalancersResult| output.load_balancers.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_operations`, streaming every page of results.
+    pub fn get_operations_pages(
+        &self,
+        input: GetOperationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetOperationsResult, GetOperationsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LightsailClient, input: GetOperationsRequest| async move {
+                client.get_operations(input).await
+            },
+            |_input: &GetOperationsRequest, output: &GetOperationsResult| {
+                Some(GetOperationsRequest {
+                    page_token: Some(output.next_page_token.clone()?),
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_operations`, streaming the `operations` of every page of results.
+    pub fn get_operations_items(
+        &self,
+        input: GetOperationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Operation, GetOperationsError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_operations_pages(input),
+            |output: GetOperationsResult| output.operations.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_relational_database_blueprints`, streaming every page of results.
+    pub fn get_relational_database_blueprints_pages(
+        &self,
+        input: GetRelationalDatabaseBlueprintsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        GetRelationalDatabaseBlueprintsResult,
+        GetRelationalDatabaseBlueprintsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LightsailClient, input: GetRelationalDatabaseBlueprintsRequest| async move {
+                client.get_relational_database_blueprints(input).await
+            },
+            |_input: &GetRelationalDatabaseBlueprintsRequest,
+             output: &GetRelationalDatabaseBlueprintsResult| {
+                Some(GetRelationalDatabaseBlueprintsRequest {
+                    page_token: Some(output.next_page_token.clone()?),
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_relational_database_blueprints`, streaming the `blueprints` of every page of results.
+    pub fn get_relational_database_blueprints_items(
+        &self,
+        input: GetRelationalDatabaseBlueprintsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        RelationalDatabaseBlueprint,
+        GetRelationalDatabaseBlueprintsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.get_relational_database_blueprints_pages(input),
+            |output: GetRelationalDatabaseBlueprintsResult| output.blueprints.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_relational_database_bundles`, streaming every page of results.
+    pub fn get_relational_database_bundles_pages(
+        &self,
+        input: GetRelationalDatabaseBundlesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        GetRelationalDatabaseBundlesResult,
+        GetRelationalDatabaseBundlesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LightsailClient, input: GetRelationalDatabaseBundlesRequest| async move {
+                client.get_relational_database_bundles(input).await
+            },
+            |_input: &GetRelationalDatabaseBundlesRequest,
+             output: &GetRelationalDatabaseBundlesResult| {
+                Some(GetRelationalDatabaseBundlesRequest {
+                    page_token: Some(output.next_page_token.clone()?),
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_relational_database_bundles`, streaming the `bundles` of every page of results.
+    pub fn get_relational_database_bundles_items(
+        &self,
+        input: GetRelationalDatabaseBundlesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        RelationalDatabaseBundle,
+        GetRelationalDatabaseBundlesError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.get_relational_database_bundles_pages(input),
+            |output: GetRelationalDatabaseBundlesResult| output.bundles.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_relational_database_events`, streaming every page of results.
+    pub fn get_relational_database_events_pages(
+        &self,
+        input: GetRelationalDatabaseEventsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        GetRelationalDatabaseEventsResult,
+        GetRelationalDatabaseEventsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LightsailClient, input: GetRelationalDatabaseEventsRequest| async move {
+                client.get_relational_database_events(input).await
+            },
+            |input: &GetRelationalDatabaseEventsRequest,
+             output: &GetRelationalDatabaseEventsResult| {
+                Some(GetRelationalDatabaseEventsRequest {
+                    page_token: Some(output.next_page_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_relational_database_events`, streaming the `relationalDatabaseEvents` of every page of results.
+    pub fn get_relational_database_events_items(
+        &self,
+        input: GetRelationalDatabaseEventsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        RelationalDatabaseEvent,
+        GetRelationalDatabaseEventsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.get_relational_database_events_pages(input),
+            |output: GetRelationalDatabaseEventsResult| {
+                output.relational_database_events.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_relational_database_parameters`, streaming every page of results.
+    pub fn get_relational_database_parameters_pages(
+        &self,
+        input: GetRelationalDatabaseParametersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        GetRelationalDatabaseParametersResult,
+        GetRelationalDatabaseParametersError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LightsailClient, input: GetRelationalDatabaseParametersRequest| async move {
+                client.get_relational_database_parameters(input).await
+            },
+            |input: &GetRelationalDatabaseParametersRequest,
+             output: &GetRelationalDatabaseParametersResult| {
+                Some(GetRelationalDatabaseParametersRequest {
+                    page_token: Some(output.next_page_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_relational_database_parameters`, streaming the `parameters` of every page of results.
+    pub fn get_relational_database_parameters_items(
+        &self,
+        input: GetRelationalDatabaseParametersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        RelationalDatabaseParameter,
+        GetRelationalDatabaseParametersError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.get_relational_database_parameters_pages(input),
+            |output: GetRelationalDatabaseParametersResult| output.parameters.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_relational_database_snapshots`, streaming every page of results.
+    pub fn get_relational_database_snapshots_pages(
+        &self,
+        input: GetRelationalDatabaseSnapshotsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        GetRelationalDatabaseSnapshotsResult,
+        GetRelationalDatabaseSnapshotsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LightsailClient, input: GetRelationalDatabaseSnapshotsRequest| async move {
+                client.get_relational_database_snapshots(input).await
+            },
+            |_input: &GetRelationalDatabaseSnapshotsRequest,
+             output: &GetRelationalDatabaseSnapshotsResult| {
+                Some(GetRelationalDatabaseSnapshotsRequest {
+                    page_token: Some(output.next_page_token.clone()?),
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_relational_database_snapshots`, streaming the `relationalDatabaseSnapshots` of every page of results.
+    pub fn get_relational_database_snapshots_items(
+        &self,
+        input: GetRelationalDatabaseSnapshotsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        RelationalDatabaseSnapshot,
+        GetRelationalDatabaseSnapshotsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.get_relational_database_snapshots_pages(input),
+            |output: GetRelationalDatabaseSnapshotsResult| {
+                output.relational_database_snapshots.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_relational_databases`, streaming every page of results.
+    pub fn get_relational_databases_pages(
+        &self,
+        input: GetRelationalDatabasesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        GetRelationalDatabasesResult,
+        GetRelationalDatabasesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LightsailClient, input: GetRelationalDatabasesRequest| async move {
+                client.get_relational_databases(input).await
+            },
+            |_input: &GetRelationalDatabasesRequest, output: &GetRelationalDatabasesResult| {
+                Some(GetRelationalDatabasesRequest {
+                    page_token: Some(output.next_page_token.clone()?),
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_relational_databases`, streaming the `relationalDatabases` of every page of results.
+    pub fn get_relational_databases_items(
+        &self,
+        input: GetRelationalDatabasesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<RelationalDatabase, GetRelationalDatabasesError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.get_relational_databases_pages(input),
+            |output: GetRelationalDatabasesResult| output.relational_databases.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_static_ips`, streaming every page of results.
+    pub fn get_static_ips_pages(
+        &self,
+        input: GetStaticIpsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetStaticIpsResult, GetStaticIpsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LightsailClient, input: GetStaticIpsRequest| async move {
+                client.get_static_ips(input).await
+            },
+            |_input: &GetStaticIpsRequest, output: &GetStaticIpsResult| {
+                Some(GetStaticIpsRequest {
+                    page_token: Some(output.next_page_token.clone()?),
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_static_ips`, streaming the `staticIps` of every page of results.
+    pub fn get_static_ips_items(
+        &self,
+        input: GetStaticIpsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<StaticIp, GetStaticIpsError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_static_ips_pages(input),
+            |output: GetStaticIpsResult| output.static_ips.unwrap_or_default(),
+        )
+    }
+}
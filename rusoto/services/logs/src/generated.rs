@@ -217,7 +217,7 @@ pub struct DescribeExportTasksRequest {
     /// <p>The status code of the export task. Specifying a status code filters the results to zero or more export tasks.</p>
     #[serde(rename = "statusCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status_code: Option<String>,
+    pub status_code: Option<ExportTaskStatusCode>,
     /// <p>The ID of the export task. Specifying a task ID filters the results to zero or one export tasks.</p>
     #[serde(rename = "taskId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -290,7 +290,7 @@ pub struct DescribeLogStreamsRequest {
     /// <p>If the value is <code>LogStreamName</code>, the results are ordered by log stream name. If the value is <code>LastEventTime</code>, the results are ordered by the event time. The default value is <code>LogStreamName</code>.</p> <p>If you order the results by event time, you cannot specify the <code>logStreamNamePrefix</code> parameter.</p> <p>lastEventTimestamp represents the time of the most recent log event in the log stream in CloudWatch Logs. This number is expressed as the number of milliseconds after Jan 1, 1970 00:00:00 UTC. lastEventTimeStamp updates on an eventual consistency basis. It typically updates in less than an hour from ingestion, but may take longer in some rare situations.</p>
     #[serde(rename = "orderBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub order_by: Option<String>,
+    pub order_by: Option<OrderBy>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -363,7 +363,7 @@ pub struct DescribeQueriesRequest {
     /// <p>Limits the returned queries to only those that have the specified status. Valid values are <code>Cancelled</code>, <code>Complete</code>, <code>Failed</code>, <code>Running</code>, and <code>Scheduled</code>.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<QueryStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -472,6 +472,100 @@ pub struct DisassociateKmsKeyRequest {
     pub log_group_name: String,
 }
 
+/// <p>The method used to distribute log data to the destination, which can be either random or grouped by log stream.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Distribution {
+    /// `Random`
+    Random,
+    /// `ByLogStream`
+    ByLogStream,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Distribution {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Distribution::Random => "Random",
+            Distribution::ByLogStream => "ByLogStream",
+            Distribution::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Distribution {
+    fn default() -> Distribution {
+        Distribution::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Distribution {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Distribution {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Distribution {
+    fn from(value: &'a str) -> Distribution {
+        match value {
+            "Random" => Distribution::Random,
+            "ByLogStream" => Distribution::ByLogStream,
+            _ => Distribution::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Distribution {
+    fn from(value: String) -> Distribution {
+        match value.as_str() {
+            "Random" => Distribution::Random,
+            "ByLogStream" => Distribution::ByLogStream,
+            _ => Distribution::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Distribution {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Distribution, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Distribution {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Distribution {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Distribution {
+    fn deserialize<D>(deserializer: D) -> Result<Distribution, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents an export task.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -535,13 +629,126 @@ pub struct ExportTaskStatus {
     /// <p>The status code of the export task.</p>
     #[serde(rename = "code")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub code: Option<String>,
+    pub code: Option<ExportTaskStatusCode>,
     /// <p>The status message related to the status code.</p>
     #[serde(rename = "message")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub message: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ExportTaskStatusCode {
+    /// `CANCELLED`
+    Cancelled,
+    /// `COMPLETED`
+    Completed,
+    /// `FAILED`
+    Failed,
+    /// `PENDING`
+    Pending,
+    /// `PENDING_CANCEL`
+    PendingCancel,
+    /// `RUNNING`
+    Running,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ExportTaskStatusCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ExportTaskStatusCode::Cancelled => "CANCELLED",
+            ExportTaskStatusCode::Completed => "COMPLETED",
+            ExportTaskStatusCode::Failed => "FAILED",
+            ExportTaskStatusCode::Pending => "PENDING",
+            ExportTaskStatusCode::PendingCancel => "PENDING_CANCEL",
+            ExportTaskStatusCode::Running => "RUNNING",
+            ExportTaskStatusCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ExportTaskStatusCode {
+    fn default() -> ExportTaskStatusCode {
+        ExportTaskStatusCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ExportTaskStatusCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ExportTaskStatusCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ExportTaskStatusCode {
+    fn from(value: &'a str) -> ExportTaskStatusCode {
+        match value {
+            "CANCELLED" => ExportTaskStatusCode::Cancelled,
+            "COMPLETED" => ExportTaskStatusCode::Completed,
+            "FAILED" => ExportTaskStatusCode::Failed,
+            "PENDING" => ExportTaskStatusCode::Pending,
+            "PENDING_CANCEL" => ExportTaskStatusCode::PendingCancel,
+            "RUNNING" => ExportTaskStatusCode::Running,
+            _ => ExportTaskStatusCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ExportTaskStatusCode {
+    fn from(value: String) -> ExportTaskStatusCode {
+        match value.as_str() {
+            "CANCELLED" => ExportTaskStatusCode::Cancelled,
+            "COMPLETED" => ExportTaskStatusCode::Completed,
+            "FAILED" => ExportTaskStatusCode::Failed,
+            "PENDING" => ExportTaskStatusCode::Pending,
+            "PENDING_CANCEL" => ExportTaskStatusCode::PendingCancel,
+            "RUNNING" => ExportTaskStatusCode::Running,
+            _ => ExportTaskStatusCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ExportTaskStatusCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ExportTaskStatusCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ExportTaskStatusCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ExportTaskStatusCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ExportTaskStatusCode {
+    fn deserialize<D>(deserializer: D) -> Result<ExportTaskStatusCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct FilterLogEventsRequest {
@@ -729,7 +936,7 @@ pub struct GetQueryResultsResponse {
     /// <p>The status of the most recent running of the query. Possible values are <code>Cancelled</code>, <code>Complete</code>, <code>Failed</code>, <code>Running</code>, <code>Scheduled</code>, <code>Timeout</code>, and <code>Unknown</code>.</p> <p>Queries time out after 15 minutes of execution. To avoid having your queries time out, reduce the time range being searched, or partition your query into a number of queries.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<QueryStatus>,
 }
 
 /// <p>Represents a log event, which is a record of activity that was recorded by the application or resource being monitored.</p>
@@ -903,6 +1110,99 @@ pub struct MetricTransformation {
     pub metric_value: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OrderBy {
+    /// `LogStreamName`
+    LogStreamName,
+    /// `LastEventTime`
+    LastEventTime,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl OrderBy {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OrderBy::LogStreamName => "LogStreamName",
+            OrderBy::LastEventTime => "LastEventTime",
+            OrderBy::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for OrderBy {
+    fn default() -> OrderBy {
+        OrderBy::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for OrderBy {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for OrderBy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for OrderBy {
+    fn from(value: &'a str) -> OrderBy {
+        match value {
+            "LogStreamName" => OrderBy::LogStreamName,
+            "LastEventTime" => OrderBy::LastEventTime,
+            _ => OrderBy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OrderBy {
+    fn from(value: String) -> OrderBy {
+        match value.as_str() {
+            "LogStreamName" => OrderBy::LogStreamName,
+            "LastEventTime" => OrderBy::LastEventTime,
+            _ => OrderBy::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for OrderBy {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<OrderBy, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OrderBy {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for OrderBy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OrderBy {
+    fn deserialize<D>(deserializer: D) -> Result<OrderBy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents a log event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1044,7 +1344,7 @@ pub struct PutSubscriptionFilterRequest {
     /// <p>The method used to distribute log data to the destination. By default log data is grouped by log stream, but the grouping can be set to random for a more even distribution. This property is only applicable when the destination is an Amazon Kinesis stream. </p>
     #[serde(rename = "distribution")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub distribution: Option<String>,
+    pub distribution: Option<Distribution>,
     /// <p>A name for the subscription filter. If you are updating an existing filter, you must specify the correct name in <code>filterName</code>. Otherwise, the call fails because you cannot associate a second filter with a log group. To find the name of the filter currently associated with a log group, use <a>DescribeSubscriptionFilters</a>.</p>
     #[serde(rename = "filterName")]
     pub filter_name: String,
@@ -1101,7 +1401,7 @@ pub struct QueryInfo {
     /// <p>The status of this query. Possible values are <code>Cancelled</code>, <code>Complete</code>, <code>Failed</code>, <code>Running</code>, <code>Scheduled</code>, and <code>Unknown</code>.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<QueryStatus>,
 }
 
 /// <p>Contains the number of log events scanned by the query, the number of log events that matched the query criteria, and the total number of bytes in the log events that were scanned.</p>
@@ -1122,6 +1422,124 @@ pub struct QueryStatistics {
     pub records_scanned: Option<f64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum QueryStatus {
+    /// `Scheduled`
+    Scheduled,
+    /// `Running`
+    Running,
+    /// `Complete`
+    Complete,
+    /// `Failed`
+    Failed,
+    /// `Cancelled`
+    Cancelled,
+    /// `Timeout`
+    Timeout,
+    /// `Unknown`
+    UnknownValue,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl QueryStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            QueryStatus::Scheduled => "Scheduled",
+            QueryStatus::Running => "Running",
+            QueryStatus::Complete => "Complete",
+            QueryStatus::Failed => "Failed",
+            QueryStatus::Cancelled => "Cancelled",
+            QueryStatus::Timeout => "Timeout",
+            QueryStatus::UnknownValue => "Unknown",
+            QueryStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for QueryStatus {
+    fn default() -> QueryStatus {
+        QueryStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for QueryStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for QueryStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for QueryStatus {
+    fn from(value: &'a str) -> QueryStatus {
+        match value {
+            "Scheduled" => QueryStatus::Scheduled,
+            "Running" => QueryStatus::Running,
+            "Complete" => QueryStatus::Complete,
+            "Failed" => QueryStatus::Failed,
+            "Cancelled" => QueryStatus::Cancelled,
+            "Timeout" => QueryStatus::Timeout,
+            "Unknown" => QueryStatus::UnknownValue,
+            _ => QueryStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for QueryStatus {
+    fn from(value: String) -> QueryStatus {
+        match value.as_str() {
+            "Scheduled" => QueryStatus::Scheduled,
+            "Running" => QueryStatus::Running,
+            "Complete" => QueryStatus::Complete,
+            "Failed" => QueryStatus::Failed,
+            "Cancelled" => QueryStatus::Cancelled,
+            "Timeout" => QueryStatus::Timeout,
+            "Unknown" => QueryStatus::UnknownValue,
+            _ => QueryStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for QueryStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<QueryStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for QueryStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for QueryStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for QueryStatus {
+    fn deserialize<D>(deserializer: D) -> Result<QueryStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents the rejected events.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1252,7 +1670,7 @@ pub struct SubscriptionFilter {
     pub destination_arn: Option<String>,
     #[serde(rename = "distribution")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub distribution: Option<String>,
+    pub distribution: Option<Distribution>,
     /// <p>The name of the subscription filter.</p>
     #[serde(rename = "filterName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3390,11 +3808,12 @@ pub struct CloudWatchLogsClient {
 impl CloudWatchLogsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> CloudWatchLogsClient {
         CloudWatchLogsClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "CloudWatch Logs"),
         }
     }
 
@@ -3426,6 +3845,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: AssociateKmsKeyRequest,
     ) -> Result<(), RusotoError<AssociateKmsKeyError>> {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("AssociateKmsKey");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.AssociateKmsKey");
@@ -3453,6 +3873,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: CancelExportTaskRequest,
     ) -> Result<(), RusotoError<CancelExportTaskError>> {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("CancelExportTask");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.CancelExportTask");
@@ -3480,6 +3901,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: CreateExportTaskRequest,
     ) -> Result<CreateExportTaskResponse, RusotoError<CreateExportTaskError>> {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("CreateExportTask");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.CreateExportTask");
@@ -3508,6 +3930,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: CreateLogGroupRequest,
     ) -> Result<(), RusotoError<CreateLogGroupError>> {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("CreateLogGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.CreateLogGroup");
@@ -3535,6 +3958,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: CreateLogStreamRequest,
     ) -> Result<(), RusotoError<CreateLogStreamError>> {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("CreateLogStream");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.CreateLogStream");
@@ -3562,6 +3986,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: DeleteDestinationRequest,
     ) -> Result<(), RusotoError<DeleteDestinationError>> {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("DeleteDestination");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.DeleteDestination");
@@ -3589,6 +4014,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: DeleteLogGroupRequest,
     ) -> Result<(), RusotoError<DeleteLogGroupError>> {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("DeleteLogGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.DeleteLogGroup");
@@ -3616,6 +4042,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: DeleteLogStreamRequest,
     ) -> Result<(), RusotoError<DeleteLogStreamError>> {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("DeleteLogStream");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.DeleteLogStream");
@@ -3643,6 +4070,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: DeleteMetricFilterRequest,
     ) -> Result<(), RusotoError<DeleteMetricFilterError>> {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("DeleteMetricFilter");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.DeleteMetricFilter");
@@ -3670,6 +4098,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: DeleteResourcePolicyRequest,
     ) -> Result<(), RusotoError<DeleteResourcePolicyError>> {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("DeleteResourcePolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.DeleteResourcePolicy");
@@ -3697,6 +4126,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: DeleteRetentionPolicyRequest,
     ) -> Result<(), RusotoError<DeleteRetentionPolicyError>> {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("DeleteRetentionPolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.DeleteRetentionPolicy");
@@ -3724,6 +4154,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: DeleteSubscriptionFilterRequest,
     ) -> Result<(), RusotoError<DeleteSubscriptionFilterError>> {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("DeleteSubscriptionFilter");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.DeleteSubscriptionFilter");
@@ -3751,6 +4182,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: DescribeDestinationsRequest,
     ) -> Result<DescribeDestinationsResponse, RusotoError<DescribeDestinationsError>> {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("DescribeDestinations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.DescribeDestinations");
@@ -3779,6 +4211,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: DescribeExportTasksRequest,
     ) -> Result<DescribeExportTasksResponse, RusotoError<DescribeExportTasksError>> {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("DescribeExportTasks");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.DescribeExportTasks");
@@ -3807,6 +4240,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: DescribeLogGroupsRequest,
     ) -> Result<DescribeLogGroupsResponse, RusotoError<DescribeLogGroupsError>> {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("DescribeLogGroups");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.DescribeLogGroups");
@@ -3835,6 +4269,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: DescribeLogStreamsRequest,
     ) -> Result<DescribeLogStreamsResponse, RusotoError<DescribeLogStreamsError>> {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("DescribeLogStreams");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.DescribeLogStreams");
@@ -3863,6 +4298,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: DescribeMetricFiltersRequest,
     ) -> Result<DescribeMetricFiltersResponse, RusotoError<DescribeMetricFiltersError>> {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("DescribeMetricFilters");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.DescribeMetricFilters");
@@ -3891,6 +4327,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: DescribeQueriesRequest,
     ) -> Result<DescribeQueriesResponse, RusotoError<DescribeQueriesError>> {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("DescribeQueries");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.DescribeQueries");
@@ -3918,6 +4355,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: DescribeResourcePoliciesRequest,
     ) -> Result<DescribeResourcePoliciesResponse, RusotoError<DescribeResourcePoliciesError>> {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("DescribeResourcePolicies");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.DescribeResourcePolicies");
@@ -3947,6 +4385,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
     ) -> Result<DescribeSubscriptionFiltersResponse, RusotoError<DescribeSubscriptionFiltersError>>
     {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("DescribeSubscriptionFilters");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.DescribeSubscriptionFilters");
@@ -3975,6 +4414,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: DisassociateKmsKeyRequest,
     ) -> Result<(), RusotoError<DisassociateKmsKeyError>> {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("DisassociateKmsKey");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.DisassociateKmsKey");
@@ -4002,6 +4442,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: FilterLogEventsRequest,
     ) -> Result<FilterLogEventsResponse, RusotoError<FilterLogEventsError>> {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("FilterLogEvents");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.FilterLogEvents");
@@ -4029,6 +4470,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: GetLogEventsRequest,
     ) -> Result<GetLogEventsResponse, RusotoError<GetLogEventsError>> {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("GetLogEvents");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.GetLogEvents");
@@ -4056,6 +4498,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: GetLogGroupFieldsRequest,
     ) -> Result<GetLogGroupFieldsResponse, RusotoError<GetLogGroupFieldsError>> {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("GetLogGroupFields");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.GetLogGroupFields");
@@ -4084,6 +4527,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: GetLogRecordRequest,
     ) -> Result<GetLogRecordResponse, RusotoError<GetLogRecordError>> {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("GetLogRecord");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.GetLogRecord");
@@ -4111,6 +4555,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: GetQueryResultsRequest,
     ) -> Result<GetQueryResultsResponse, RusotoError<GetQueryResultsError>> {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("GetQueryResults");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.GetQueryResults");
@@ -4138,6 +4583,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: ListTagsLogGroupRequest,
     ) -> Result<ListTagsLogGroupResponse, RusotoError<ListTagsLogGroupError>> {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("ListTagsLogGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.ListTagsLogGroup");
@@ -4166,6 +4612,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: PutDestinationRequest,
     ) -> Result<PutDestinationResponse, RusotoError<PutDestinationError>> {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("PutDestination");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.PutDestination");
@@ -4193,6 +4640,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: PutDestinationPolicyRequest,
     ) -> Result<(), RusotoError<PutDestinationPolicyError>> {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("PutDestinationPolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.PutDestinationPolicy");
@@ -4220,6 +4668,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: PutLogEventsRequest,
     ) -> Result<PutLogEventsResponse, RusotoError<PutLogEventsError>> {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("PutLogEvents");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.PutLogEvents");
@@ -4247,6 +4696,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: PutMetricFilterRequest,
     ) -> Result<(), RusotoError<PutMetricFilterError>> {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("PutMetricFilter");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.PutMetricFilter");
@@ -4274,6 +4724,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: PutResourcePolicyRequest,
     ) -> Result<PutResourcePolicyResponse, RusotoError<PutResourcePolicyError>> {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("PutResourcePolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.PutResourcePolicy");
@@ -4302,6 +4753,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: PutRetentionPolicyRequest,
     ) -> Result<(), RusotoError<PutRetentionPolicyError>> {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("PutRetentionPolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.PutRetentionPolicy");
@@ -4329,6 +4781,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: PutSubscriptionFilterRequest,
     ) -> Result<(), RusotoError<PutSubscriptionFilterError>> {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("PutSubscriptionFilter");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.PutSubscriptionFilter");
@@ -4356,6 +4809,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: StartQueryRequest,
     ) -> Result<StartQueryResponse, RusotoError<StartQueryError>> {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("StartQuery");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.StartQuery");
@@ -4383,6 +4837,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: StopQueryRequest,
     ) -> Result<StopQueryResponse, RusotoError<StopQueryError>> {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("StopQuery");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.StopQuery");
@@ -4410,6 +4865,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: TagLogGroupRequest,
     ) -> Result<(), RusotoError<TagLogGroupError>> {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("TagLogGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.TagLogGroup");
@@ -4437,6 +4893,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: TestMetricFilterRequest,
     ) -> Result<TestMetricFilterResponse, RusotoError<TestMetricFilterError>> {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("TestMetricFilter");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.TestMetricFilter");
@@ -4465,6 +4922,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: UntagLogGroupRequest,
     ) -> Result<(), RusotoError<UntagLogGroupError>> {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("UntagLogGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.UntagLogGroup");
@@ -4486,3 +4944,302 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         }
     }
 }
+impl CloudWatchLogsClient {
+    /// Auto-paginating version of `describe_destinations`, streaming every page of results.
+    pub fn describe_destinations_pages(
+        &self,
+        input: DescribeDestinationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeDestinationsResponse,
+        DescribeDestinationsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudWatchLogsClient, input: DescribeDestinationsRequest| async move {
+                client.describe_destinations(input).await
+            },
+            |input: &DescribeDestinationsRequest, output: &DescribeDestinationsResponse| {
+                Some(DescribeDestinationsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_destinations`, streaming the `destinations` of every page of results.
+    pub fn describe_destinations_items(
+        &self,
+        input: DescribeDestinationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Destination, DescribeDestinationsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_destinations_pages(input),
+            |output: DescribeDestinationsResponse| output.destinations.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_export_tasks`, streaming every page of results.
+    pub fn describe_export_tasks_pages(
+        &self,
+        input: DescribeExportTasksRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeExportTasksResponse, DescribeExportTasksError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudWatchLogsClient, input: DescribeExportTasksRequest| async move {
+                client.describe_export_tasks(input).await
+            },
+            |input: &DescribeExportTasksRequest, output: &DescribeExportTasksResponse| {
+                Some(DescribeExportTasksRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_export_tasks`, streaming the `exportTasks` of every page of results.
+    pub fn describe_export_tasks_items(
+        &self,
+        input: DescribeExportTasksRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ExportTask, DescribeExportTasksError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_export_tasks_pages(input),
+            |output: DescribeExportTasksResponse| output.export_tasks.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_log_groups`, streaming every page of results.
+    pub fn describe_log_groups_pages(
+        &self,
+        input: DescribeLogGroupsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeLogGroupsResponse, DescribeLogGroupsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudWatchLogsClient, input: DescribeLogGroupsRequest| async move {
+                client.describe_log_groups(input).await
+            },
+            |input: &DescribeLogGroupsRequest, output: &DescribeLogGroupsResponse| {
+                Some(DescribeLogGroupsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_log_groups`, streaming the `logGroups` of every page of results.
+    pub fn describe_log_groups_items(
+        &self,
+        input: DescribeLogGroupsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<LogGroup, DescribeLogGroupsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_log_groups_pages(input),
+            |output: DescribeLogGroupsResponse| output.log_groups.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_log_streams`, streaming every page of results.
+    pub fn describe_log_streams_pages(
+        &self,
+        input: DescribeLogStreamsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeLogStreamsResponse, DescribeLogStreamsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudWatchLogsClient, input: DescribeLogStreamsRequest| async move {
+                client.describe_log_streams(input).await
+            },
+            |input: &DescribeLogStreamsRequest, output: &DescribeLogStreamsResponse| {
+                Some(DescribeLogStreamsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_log_streams`, streaming the `logStreams` of every page of results.
+    pub fn describe_log_streams_items(
+        &self,
+        input: DescribeLogStreamsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<LogStream, DescribeLogStreamsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_log_streams_pages(input),
+            |output: DescribeLogStreamsResponse| output.log_streams.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_metric_filters`, streaming every page of results.
+    pub fn describe_metric_filters_pages(
+        &self,
+        input: DescribeMetricFiltersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeMetricFiltersResponse,
+        DescribeMetricFiltersError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudWatchLogsClient, input: DescribeMetricFiltersRequest| async move {
+                client.describe_metric_filters(input).await
+            },
+            |input: &DescribeMetricFiltersRequest, output: &DescribeMetricFiltersResponse| {
+                Some(DescribeMetricFiltersRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_metric_filters`, streaming the `metricFilters` of every page of results.
+    pub fn describe_metric_filters_items(
+        &self,
+        input: DescribeMetricFiltersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<MetricFilter, DescribeMetricFiltersError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_metric_filters_pages(input),
+            |output: DescribeMetricFiltersResponse| output.metric_filters.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_queries`, streaming every page of results.
+    pub fn describe_queries_pages(
+        &self,
+        input: DescribeQueriesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeQueriesResponse, DescribeQueriesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudWatchLogsClient, input: DescribeQueriesRequest| async move {
+                client.describe_queries(input).await
+            },
+            |input: &DescribeQueriesRequest, output: &DescribeQueriesResponse| {
+                Some(DescribeQueriesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_queries`, streaming the `queries` of every page of results.
+    pub fn describe_queries_items(
+        &self,
+        input: DescribeQueriesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<QueryInfo, DescribeQueriesError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_queries_pages(input),
+            |output: DescribeQueriesResponse| output.queries.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_resource_policies`, streaming every page of results.
+    pub fn describe_resource_policies_pages(
+        &self,
+        input: DescribeResourcePoliciesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeResourcePoliciesResponse,
+        DescribeResourcePoliciesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudWatchLogsClient, input: DescribeResourcePoliciesRequest| async move {
+                client.describe_resource_policies(input).await
+            },
+            |input: &DescribeResourcePoliciesRequest, output: &DescribeResourcePoliciesResponse| {
+                Some(DescribeResourcePoliciesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_resource_policies`, streaming the `resourcePolicies` of every page of results.
+    pub fn describe_resource_policies_items(
+        &self,
+        input: DescribeResourcePoliciesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ResourcePolicy, DescribeResourcePoliciesError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_resource_policies_pages(input),
+            |output: DescribeResourcePoliciesResponse| output.resource_policies.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_subscription_filters`, streaming every page of results.
+    pub fn describe_subscription_filters_pages(
+        &self,
+        input: DescribeSubscriptionFiltersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeSubscriptionFiltersResponse,
+        DescribeSubscriptionFiltersError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudWatchLogsClient, input: DescribeSubscriptionFiltersRequest| async move {
+                client.describe_subscription_filters(input).await
+            },
+            |input: &DescribeSubscriptionFiltersRequest,
+             output: &DescribeSubscriptionFiltersResponse| {
+                Some(DescribeSubscriptionFiltersRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_subscription_filters`, streaming the `subscriptionFilters` of every page of results.
+    pub fn describe_subscription_filters_items(
+        &self,
+        input: DescribeSubscriptionFiltersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<SubscriptionFilter, DescribeSubscriptionFiltersError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_subscription_filters_pages(input),
+            |output: DescribeSubscriptionFiltersResponse| {
+                output.subscription_filters.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `filter_log_events`, streaming every page of results.
+    pub fn filter_log_events_pages(
+        &self,
+        input: FilterLogEventsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<FilterLogEventsResponse, FilterLogEventsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudWatchLogsClient, input: FilterLogEventsRequest| async move {
+                client.filter_log_events(input).await
+            },
+            |input: &FilterLogEventsRequest, output: &FilterLogEventsResponse| {
+                Some(FilterLogEventsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `filter_log_events`, streaming the `events` of every page of results.
+    pub fn filter_log_events_items(
+        &self,
+        input: FilterLogEventsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<FilteredLogEvent, FilterLogEventsError> {
+        rusoto_core::pagination::paginate_items(
+            self.filter_log_events_pages(input),
+            |output: FilterLogEventsResponse| output.events.unwrap_or_default(),
+        )
+    }
+}
@@ -32,7 +32,7 @@ pub struct AddTagsInput {
     pub resource_id: String,
     /// <p>The type of the ML object to tag. </p>
     #[serde(rename = "ResourceType")]
-    pub resource_type: String,
+    pub resource_type: TaggableResourceType,
     /// <p>The key-value pairs to use to create tags. If you specify a key without specifying a value, Amazon ML creates a tag with the specified key and a value of null.</p>
     #[serde(rename = "Tags")]
     pub tags: Vec<Tag>,
@@ -49,9 +49,98 @@ pub struct AddTagsOutput {
     /// <p>The type of the ML object that was tagged.</p>
     #[serde(rename = "ResourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<TaggableResourceType>,
 }
 
+/// <p>The function used to train an <code>MLModel</code>. Training choices supported by Amazon ML include the following:</p> <ul> <li> <p> <code>SGD</code> - Stochastic Gradient Descent.</p> </li> <li> <p> <code>RandomForest</code> - Random forest of decision trees.</p> </li> </ul>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Algorithm {
+    /// `sgd`
+    Sgd,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Algorithm {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Algorithm::Sgd => "sgd",
+            Algorithm::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Algorithm {
+    fn default() -> Algorithm {
+        Algorithm::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Algorithm {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Algorithm {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Algorithm {
+    fn from(value: &'a str) -> Algorithm {
+        match value {
+            "sgd" => Algorithm::Sgd,
+            _ => Algorithm::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Algorithm {
+    fn from(value: String) -> Algorithm {
+        match value.as_str() {
+            "sgd" => Algorithm::Sgd,
+            _ => Algorithm::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Algorithm {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Algorithm, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Algorithm {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Algorithm {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Algorithm {
+    fn deserialize<D>(deserializer: D) -> Result<Algorithm, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p> Represents the output of a <code>GetBatchPrediction</code> operation.</p> <p> The content consists of the detailed metadata, the status, and the data file information of a <code>Batch Prediction</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -69,15 +158,25 @@ pub struct BatchPrediction {
     pub compute_time: Option<i64>,
     /// <p>The time that the <code>BatchPrediction</code> was created. The time is expressed in epoch time.</p>
     #[serde(rename = "CreatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The AWS user account that invoked the <code>BatchPrediction</code>. The account type can be either an AWS root account or an AWS Identity and Access Management (IAM) user account.</p>
     #[serde(rename = "CreatedByIamUser")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub created_by_iam_user: Option<String>,
     #[serde(rename = "FinishedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub finished_at: Option<f64>,
+    pub finished_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The location of the data file or directory in Amazon Simple Storage Service (Amazon S3).</p>
     #[serde(rename = "InputDataLocationS3")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -87,8 +186,13 @@ pub struct BatchPrediction {
     pub invalid_record_count: Option<i64>,
     /// <p>The time of the most recent edit to the <code>BatchPrediction</code>. The time is expressed in epoch time.</p>
     #[serde(rename = "LastUpdatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_at: Option<f64>,
+    pub last_updated_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The ID of the <code>MLModel</code> that generated predictions for the <code>BatchPrediction</code> request.</p>
     #[serde(rename = "MLModelId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -106,17 +210,146 @@ pub struct BatchPrediction {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub output_uri: Option<String>,
     #[serde(rename = "StartedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub started_at: Option<f64>,
+    pub started_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>The status of the <code>BatchPrediction</code>. This element can have one of the following values:</p> <ul> <li> <code>PENDING</code> - Amazon Machine Learning (Amazon ML) submitted a request to generate predictions for a batch of observations.</li> <li> <code>INPROGRESS</code> - The process is underway.</li> <li> <code>FAILED</code> - The request to perform a batch prediction did not run to completion. It is not usable.</li> <li> <code>COMPLETED</code> - The batch prediction process completed successfully.</li> <li> <code>DELETED</code> - The <code>BatchPrediction</code> is marked as deleted. It is not usable.</li> </ul></p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<EntityStatus>,
     #[serde(rename = "TotalRecordCount")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub total_record_count: Option<i64>,
 }
 
+/// <p>A list of the variables to use in searching or filtering <code>BatchPrediction</code>.</p> <ul> <li> <p> <code>CreatedAt</code> - Sets the search criteria to <code>BatchPrediction</code> creation date.</p> </li> <li> <p> <code>Status</code> - Sets the search criteria to <code>BatchPrediction</code> status.</p> </li> <li> <p> <code>Name</code> - Sets the search criteria to the contents of <code>BatchPrediction</code> <code>Name</code>.</p> </li> <li> <p> <code>IAMUser</code> - Sets the search criteria to the user account that invoked the <code>BatchPrediction</code> creation.</p> </li> <li> <p> <code>MLModelId</code> - Sets the search criteria to the <code>MLModel</code> used in the <code>BatchPrediction</code>.</p> </li> <li> <p> <code>DataSourceId</code> - Sets the search criteria to the <code>DataSource</code> used in the <code>BatchPrediction</code>.</p> </li> <li> <p> <code>DataURI</code> - Sets the search criteria to the data file(s) used in the <code>BatchPrediction</code>. The URL can identify either a file or an Amazon Simple Storage Service (Amazon S3) bucket or directory.</p> </li> </ul>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BatchPredictionFilterVariable {
+    /// `CreatedAt`
+    CreatedAt,
+    /// `LastUpdatedAt`
+    LastUpdatedAt,
+    /// `Status`
+    Status,
+    /// `Name`
+    Name,
+    /// `IAMUser`
+    IAMUser,
+    /// `MLModelId`
+    MLModelId,
+    /// `DataSourceId`
+    DataSourceId,
+    /// `DataURI`
+    DataURI,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl BatchPredictionFilterVariable {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BatchPredictionFilterVariable::CreatedAt => "CreatedAt",
+            BatchPredictionFilterVariable::LastUpdatedAt => "LastUpdatedAt",
+            BatchPredictionFilterVariable::Status => "Status",
+            BatchPredictionFilterVariable::Name => "Name",
+            BatchPredictionFilterVariable::IAMUser => "IAMUser",
+            BatchPredictionFilterVariable::MLModelId => "MLModelId",
+            BatchPredictionFilterVariable::DataSourceId => "DataSourceId",
+            BatchPredictionFilterVariable::DataURI => "DataURI",
+            BatchPredictionFilterVariable::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for BatchPredictionFilterVariable {
+    fn default() -> BatchPredictionFilterVariable {
+        BatchPredictionFilterVariable::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for BatchPredictionFilterVariable {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for BatchPredictionFilterVariable {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for BatchPredictionFilterVariable {
+    fn from(value: &'a str) -> BatchPredictionFilterVariable {
+        match value {
+            "CreatedAt" => BatchPredictionFilterVariable::CreatedAt,
+            "LastUpdatedAt" => BatchPredictionFilterVariable::LastUpdatedAt,
+            "Status" => BatchPredictionFilterVariable::Status,
+            "Name" => BatchPredictionFilterVariable::Name,
+            "IAMUser" => BatchPredictionFilterVariable::IAMUser,
+            "MLModelId" => BatchPredictionFilterVariable::MLModelId,
+            "DataSourceId" => BatchPredictionFilterVariable::DataSourceId,
+            "DataURI" => BatchPredictionFilterVariable::DataURI,
+            _ => BatchPredictionFilterVariable::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BatchPredictionFilterVariable {
+    fn from(value: String) -> BatchPredictionFilterVariable {
+        match value.as_str() {
+            "CreatedAt" => BatchPredictionFilterVariable::CreatedAt,
+            "LastUpdatedAt" => BatchPredictionFilterVariable::LastUpdatedAt,
+            "Status" => BatchPredictionFilterVariable::Status,
+            "Name" => BatchPredictionFilterVariable::Name,
+            "IAMUser" => BatchPredictionFilterVariable::IAMUser,
+            "MLModelId" => BatchPredictionFilterVariable::MLModelId,
+            "DataSourceId" => BatchPredictionFilterVariable::DataSourceId,
+            "DataURI" => BatchPredictionFilterVariable::DataURI,
+            _ => BatchPredictionFilterVariable::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for BatchPredictionFilterVariable {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<BatchPredictionFilterVariable, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for BatchPredictionFilterVariable {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for BatchPredictionFilterVariable {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BatchPredictionFilterVariable {
+    fn deserialize<D>(deserializer: D) -> Result<BatchPredictionFilterVariable, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateBatchPredictionInput {
@@ -281,7 +514,7 @@ pub struct CreateMLModelInput {
     pub ml_model_name: Option<String>,
     /// <p>The category of supervised learning that this <code>MLModel</code> will address. Choose from the following types:</p> <ul> <li>Choose <code>REGRESSION</code> if the <code>MLModel</code> will be used to predict a numeric value.</li> <li>Choose <code>BINARY</code> if the <code>MLModel</code> result has two possible values.</li> <li>Choose <code>MULTICLASS</code> if the <code>MLModel</code> result has a limited number of values. </li> </ul> <p> For more information, see the <a href="http://docs.aws.amazon.com/machine-learning/latest/dg">Amazon Machine Learning Developer Guide</a>.</p>
     #[serde(rename = "MLModelType")]
-    pub ml_model_type: String,
+    pub ml_model_type: MLModelType,
     /// <p><p>A list of the training parameters in the <code>MLModel</code>. The list is implemented as a map of key-value pairs.</p> <p>The following is the current set of training parameters: </p> <ul> <li> <p><code>sgd.maxMLModelSizeInBytes</code> - The maximum allowed size of the model. Depending on the input data, the size of the model might affect its performance.</p> <p> The value is an integer that ranges from <code>100000</code> to <code>2147483648</code>. The default value is <code>33554432</code>.</p> </li> <li><p><code>sgd.maxPasses</code> - The number of times that the training process traverses the observations to build the <code>MLModel</code>. The value is an integer that ranges from <code>1</code> to <code>10000</code>. The default value is <code>10</code>.</p></li> <li> <p><code>sgd.shuffleType</code> - Whether Amazon ML shuffles the training data. Shuffling the data improves a model&#39;s ability to find the optimal solution for a variety of data types. The valid values are <code>auto</code> and <code>none</code>. The default value is <code>none</code>. We &lt;?oxy<em>insert</em>start author=&quot;laurama&quot; timestamp=&quot;20160329T131121-0700&quot;&gt;strongly recommend that you shuffle your data.&lt;?oxy<em>insert</em>end&gt;</p> </li> <li> <p><code>sgd.l1RegularizationAmount</code> - The coefficient regularization L1 norm. It controls overfitting the data by penalizing large coefficients. This tends to drive coefficients to zero, resulting in a sparse feature set. If you use this parameter, start by specifying a small value, such as <code>1.0E-08</code>.</p> <p>The value is a double that ranges from <code>0</code> to <code>MAX<em>DOUBLE</code>. The default is to not use L1 normalization. This parameter can&#39;t be used when <code>L2</code> is specified. Use this parameter sparingly.</p> </li> <li> <p><code>sgd.l2RegularizationAmount</code> - The coefficient regularization L2 norm. It controls overfitting the data by penalizing large coefficients. This tends to drive coefficients to small, nonzero values. If you use this parameter, start by specifying a small value, such as <code>1.0E-08</code>.</p> <p>The value is a double that ranges from <code>0</code> to <code>MAX</em>DOUBLE</code>. The default is to not use L2 normalization. This parameter can&#39;t be used when <code>L1</code> is specified. Use this parameter sparingly.</p> </li> </ul></p>
     #[serde(rename = "Parameters")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -344,8 +577,13 @@ pub struct DataSource {
     pub compute_time: Option<i64>,
     /// <p>The time that the <code>DataSource</code> was created. The time is expressed in epoch time.</p>
     #[serde(rename = "CreatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The AWS user account from which the <code>DataSource</code> was created. The account type can be either an AWS root account or an AWS Identity and Access Management (IAM) user account.</p>
     #[serde(rename = "CreatedByIamUser")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -367,12 +605,22 @@ pub struct DataSource {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub data_source_id: Option<String>,
     #[serde(rename = "FinishedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub finished_at: Option<f64>,
+    pub finished_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The time of the most recent edit to the <code>BatchPrediction</code>. The time is expressed in epoch time.</p>
     #[serde(rename = "LastUpdatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_at: Option<f64>,
+    pub last_updated_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A description of the most recent details about creating the <code>DataSource</code>.</p>
     #[serde(rename = "Message")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -395,14 +643,133 @@ pub struct DataSource {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub role_arn: Option<String>,
     #[serde(rename = "StartedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub started_at: Option<f64>,
+    pub started_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>The current status of the <code>DataSource</code>. This element can have one of the following values: </p> <ul> <li>PENDING - Amazon Machine Learning (Amazon ML) submitted a request to create a <code>DataSource</code>.</li> <li>INPROGRESS - The creation process is underway.</li> <li>FAILED - The request to create a <code>DataSource</code> did not run to completion. It is not usable.</li> <li>COMPLETED - The creation process completed successfully.</li> <li>DELETED - The <code>DataSource</code> is marked as deleted. It is not usable.</li> </ul></p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<EntityStatus>,
+}
+
+/// <p>A list of the variables to use in searching or filtering <code>DataSource</code>.</p> <ul> <li> <p> <code>CreatedAt</code> - Sets the search criteria to <code>DataSource</code> creation date.</p> </li> <li> <p> <code>Status</code> - Sets the search criteria to <code>DataSource</code> status.</p> </li> <li> <p> <code>Name</code> - Sets the search criteria to the contents of <code>DataSource</code> <code>Name</code>.</p> </li> <li> <p> <code>DataUri</code> - Sets the search criteria to the URI of data files used to create the <code>DataSource</code>. The URI can identify either a file or an Amazon Simple Storage Service (Amazon S3) bucket or directory.</p> </li> <li> <p> <code>IAMUser</code> - Sets the search criteria to the user account that invoked the <code>DataSource</code> creation.</p> </li> </ul> <p> <b>Note:</b> The variable names should match the variable names in the <code>DataSource</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DataSourceFilterVariable {
+    /// `CreatedAt`
+    CreatedAt,
+    /// `LastUpdatedAt`
+    LastUpdatedAt,
+    /// `Status`
+    Status,
+    /// `Name`
+    Name,
+    /// `DataLocationS3`
+    DataLocationS3,
+    /// `IAMUser`
+    IAMUser,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DataSourceFilterVariable {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DataSourceFilterVariable::CreatedAt => "CreatedAt",
+            DataSourceFilterVariable::LastUpdatedAt => "LastUpdatedAt",
+            DataSourceFilterVariable::Status => "Status",
+            DataSourceFilterVariable::Name => "Name",
+            DataSourceFilterVariable::DataLocationS3 => "DataLocationS3",
+            DataSourceFilterVariable::IAMUser => "IAMUser",
+            DataSourceFilterVariable::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DataSourceFilterVariable {
+    fn default() -> DataSourceFilterVariable {
+        DataSourceFilterVariable::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DataSourceFilterVariable {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DataSourceFilterVariable {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DataSourceFilterVariable {
+    fn from(value: &'a str) -> DataSourceFilterVariable {
+        match value {
+            "CreatedAt" => DataSourceFilterVariable::CreatedAt,
+            "LastUpdatedAt" => DataSourceFilterVariable::LastUpdatedAt,
+            "Status" => DataSourceFilterVariable::Status,
+            "Name" => DataSourceFilterVariable::Name,
+            "DataLocationS3" => DataSourceFilterVariable::DataLocationS3,
+            "IAMUser" => DataSourceFilterVariable::IAMUser,
+            _ => DataSourceFilterVariable::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DataSourceFilterVariable {
+    fn from(value: String) -> DataSourceFilterVariable {
+        match value.as_str() {
+            "CreatedAt" => DataSourceFilterVariable::CreatedAt,
+            "LastUpdatedAt" => DataSourceFilterVariable::LastUpdatedAt,
+            "Status" => DataSourceFilterVariable::Status,
+            "Name" => DataSourceFilterVariable::Name,
+            "DataLocationS3" => DataSourceFilterVariable::DataLocationS3,
+            "IAMUser" => DataSourceFilterVariable::IAMUser,
+            _ => DataSourceFilterVariable::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DataSourceFilterVariable {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DataSourceFilterVariable, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DataSourceFilterVariable {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DataSourceFilterVariable {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for DataSourceFilterVariable {
+    fn deserialize<D>(deserializer: D) -> Result<DataSourceFilterVariable, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct DeleteBatchPredictionInput {
@@ -505,7 +872,7 @@ pub struct DeleteTagsInput {
     pub resource_id: String,
     /// <p>The type of the tagged ML object.</p>
     #[serde(rename = "ResourceType")]
-    pub resource_type: String,
+    pub resource_type: TaggableResourceType,
     /// <p>One or more tags to delete.</p>
     #[serde(rename = "TagKeys")]
     pub tag_keys: Vec<String>,
@@ -522,7 +889,7 @@ pub struct DeleteTagsOutput {
     /// <p>The type of the ML object from which tags were deleted.</p>
     #[serde(rename = "ResourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<TaggableResourceType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -535,7 +902,7 @@ pub struct DescribeBatchPredictionsInput {
     /// <p><p>Use one of the following variables to filter a list of <code>BatchPrediction</code>:</p> <ul> <li> <code>CreatedAt</code> - Sets the search criteria to the <code>BatchPrediction</code> creation date.</li> <li> <code>Status</code> - Sets the search criteria to the <code>BatchPrediction</code> status.</li> <li> <code>Name</code> - Sets the search criteria to the contents of the <code>BatchPrediction</code><b> </b> <code>Name</code>.</li> <li> <code>IAMUser</code> - Sets the search criteria to the user account that invoked the <code>BatchPrediction</code> creation.</li> <li> <code>MLModelId</code> - Sets the search criteria to the <code>MLModel</code> used in the <code>BatchPrediction</code>.</li> <li> <code>DataSourceId</code> - Sets the search criteria to the <code>DataSource</code> used in the <code>BatchPrediction</code>.</li> <li> <code>DataURI</code> - Sets the search criteria to the data file(s) used in the <code>BatchPrediction</code>. The URL can identify either a file or an Amazon Simple Storage Solution (Amazon S3) bucket or directory.</li> </ul></p>
     #[serde(rename = "FilterVariable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub filter_variable: Option<String>,
+    pub filter_variable: Option<BatchPredictionFilterVariable>,
     /// <p>The greater than or equal to operator. The <code>BatchPrediction</code> results will have <code>FilterVariable</code> values that are greater than or equal to the value specified with <code>GE</code>. </p>
     #[serde(rename = "GE")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -571,7 +938,7 @@ pub struct DescribeBatchPredictionsInput {
     /// <p>A two-value parameter that determines the sequence of the resulting list of <code>MLModel</code>s.</p> <ul> <li> <code>asc</code> - Arranges the list in ascending order (A-Z, 0-9).</li> <li> <code>dsc</code> - Arranges the list in descending order (Z-A, 9-0).</li> </ul> <p>Results are sorted by <code>FilterVariable</code>.</p>
     #[serde(rename = "SortOrder")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort_order: Option<String>,
+    pub sort_order: Option<SortOrder>,
 }
 
 /// <p>Represents the output of a <code>DescribeBatchPredictions</code> operation. The content is essentially a list of <code>BatchPrediction</code>s.</p>
@@ -598,7 +965,7 @@ pub struct DescribeDataSourcesInput {
     /// <p><p>Use one of the following variables to filter a list of <code>DataSource</code>:</p> <ul> <li> <code>CreatedAt</code> - Sets the search criteria to <code>DataSource</code> creation dates.</li> <li> <code>Status</code> - Sets the search criteria to <code>DataSource</code> statuses.</li> <li> <code>Name</code> - Sets the search criteria to the contents of <code>DataSource</code> <b> </b> <code>Name</code>.</li> <li> <code>DataUri</code> - Sets the search criteria to the URI of data files used to create the <code>DataSource</code>. The URI can identify either a file or an Amazon Simple Storage Service (Amazon S3) bucket or directory.</li> <li> <code>IAMUser</code> - Sets the search criteria to the user account that invoked the <code>DataSource</code> creation.</li> </ul></p>
     #[serde(rename = "FilterVariable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub filter_variable: Option<String>,
+    pub filter_variable: Option<DataSourceFilterVariable>,
     /// <p>The greater than or equal to operator. The <code>DataSource</code> results will have <code>FilterVariable</code> values that are greater than or equal to the value specified with <code>GE</code>. </p>
     #[serde(rename = "GE")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -634,7 +1001,7 @@ pub struct DescribeDataSourcesInput {
     /// <p>A two-value parameter that determines the sequence of the resulting list of <code>DataSource</code>.</p> <ul> <li> <code>asc</code> - Arranges the list in ascending order (A-Z, 0-9).</li> <li> <code>dsc</code> - Arranges the list in descending order (Z-A, 9-0).</li> </ul> <p>Results are sorted by <code>FilterVariable</code>.</p>
     #[serde(rename = "SortOrder")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort_order: Option<String>,
+    pub sort_order: Option<SortOrder>,
 }
 
 /// <p>Represents the query results from a <a>DescribeDataSources</a> operation. The content is essentially a list of <code>DataSource</code>.</p>
@@ -661,7 +1028,7 @@ pub struct DescribeEvaluationsInput {
     /// <p><p>Use one of the following variable to filter a list of <code>Evaluation</code> objects:</p> <ul> <li> <code>CreatedAt</code> - Sets the search criteria to the <code>Evaluation</code> creation date.</li> <li> <code>Status</code> - Sets the search criteria to the <code>Evaluation</code> status.</li> <li> <code>Name</code> - Sets the search criteria to the contents of <code>Evaluation</code> <b> </b> <code>Name</code>.</li> <li> <code>IAMUser</code> - Sets the search criteria to the user account that invoked an <code>Evaluation</code>.</li> <li> <code>MLModelId</code> - Sets the search criteria to the <code>MLModel</code> that was evaluated.</li> <li> <code>DataSourceId</code> - Sets the search criteria to the <code>DataSource</code> used in <code>Evaluation</code>.</li> <li> <code>DataUri</code> - Sets the search criteria to the data file(s) used in <code>Evaluation</code>. The URL can identify either a file or an Amazon Simple Storage Solution (Amazon S3) bucket or directory.</li> </ul></p>
     #[serde(rename = "FilterVariable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub filter_variable: Option<String>,
+    pub filter_variable: Option<EvaluationFilterVariable>,
     /// <p>The greater than or equal to operator. The <code>Evaluation</code> results will have <code>FilterVariable</code> values that are greater than or equal to the value specified with <code>GE</code>. </p>
     #[serde(rename = "GE")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -697,7 +1064,7 @@ pub struct DescribeEvaluationsInput {
     /// <p>A two-value parameter that determines the sequence of the resulting list of <code>Evaluation</code>.</p> <ul> <li> <code>asc</code> - Arranges the list in ascending order (A-Z, 0-9).</li> <li> <code>dsc</code> - Arranges the list in descending order (Z-A, 9-0).</li> </ul> <p>Results are sorted by <code>FilterVariable</code>.</p>
     #[serde(rename = "SortOrder")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort_order: Option<String>,
+    pub sort_order: Option<SortOrder>,
 }
 
 /// <p>Represents the query results from a <code>DescribeEvaluations</code> operation. The content is essentially a list of <code>Evaluation</code>.</p>
@@ -724,7 +1091,7 @@ pub struct DescribeMLModelsInput {
     /// <p><p>Use one of the following variables to filter a list of <code>MLModel</code>:</p> <ul> <li> <code>CreatedAt</code> - Sets the search criteria to <code>MLModel</code> creation date.</li> <li> <code>Status</code> - Sets the search criteria to <code>MLModel</code> status.</li> <li> <code>Name</code> - Sets the search criteria to the contents of <code>MLModel</code><b> </b> <code>Name</code>.</li> <li> <code>IAMUser</code> - Sets the search criteria to the user account that invoked the <code>MLModel</code> creation.</li> <li> <code>TrainingDataSourceId</code> - Sets the search criteria to the <code>DataSource</code> used to train one or more <code>MLModel</code>.</li> <li> <code>RealtimeEndpointStatus</code> - Sets the search criteria to the <code>MLModel</code> real-time endpoint status.</li> <li> <code>MLModelType</code> - Sets the search criteria to <code>MLModel</code> type: binary, regression, or multi-class.</li> <li> <code>Algorithm</code> - Sets the search criteria to the algorithm that the <code>MLModel</code> uses.</li> <li> <code>TrainingDataURI</code> - Sets the search criteria to the data file(s) used in training a <code>MLModel</code>. The URL can identify either a file or an Amazon Simple Storage Service (Amazon S3) bucket or directory.</li> </ul></p>
     #[serde(rename = "FilterVariable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub filter_variable: Option<String>,
+    pub filter_variable: Option<MLModelFilterVariable>,
     /// <p>The greater than or equal to operator. The <code>MLModel</code> results will have <code>FilterVariable</code> values that are greater than or equal to the value specified with <code>GE</code>. </p>
     #[serde(rename = "GE")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -760,7 +1127,7 @@ pub struct DescribeMLModelsInput {
     /// <p>A two-value parameter that determines the sequence of the resulting list of <code>MLModel</code>.</p> <ul> <li> <code>asc</code> - Arranges the list in ascending order (A-Z, 0-9).</li> <li> <code>dsc</code> - Arranges the list in descending order (Z-A, 9-0).</li> </ul> <p>Results are sorted by <code>FilterVariable</code>.</p>
     #[serde(rename = "SortOrder")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort_order: Option<String>,
+    pub sort_order: Option<SortOrder>,
 }
 
 /// <p>Represents the output of a <code>DescribeMLModels</code> operation. The content is essentially a list of <code>MLModel</code>.</p>
@@ -785,7 +1152,7 @@ pub struct DescribeTagsInput {
     pub resource_id: String,
     /// <p>The type of the ML object.</p>
     #[serde(rename = "ResourceType")]
-    pub resource_type: String,
+    pub resource_type: TaggableResourceType,
 }
 
 /// <p>Amazon ML returns the following elements. </p>
@@ -799,13 +1166,216 @@ pub struct DescribeTagsOutput {
     /// <p>The type of the tagged ML object.</p>
     #[serde(rename = "ResourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<TaggableResourceType>,
     /// <p>A list of tags associated with the ML object.</p>
     #[serde(rename = "Tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tags: Option<Vec<Tag>>,
 }
 
+/// <p>Contains the key values of <code>DetailsMap</code>:</p> <ul> <li> <p> <code>PredictiveModelType</code> - Indicates the type of the <code>MLModel</code>.</p> </li> <li> <p> <code>Algorithm</code> - Indicates the algorithm that was used for the <code>MLModel</code>.</p> </li> </ul>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DetailsAttributes {
+    /// `PredictiveModelType`
+    PredictiveModelType,
+    /// `Algorithm`
+    Algorithm,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DetailsAttributes {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DetailsAttributes::PredictiveModelType => "PredictiveModelType",
+            DetailsAttributes::Algorithm => "Algorithm",
+            DetailsAttributes::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DetailsAttributes {
+    fn default() -> DetailsAttributes {
+        DetailsAttributes::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DetailsAttributes {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DetailsAttributes {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DetailsAttributes {
+    fn from(value: &'a str) -> DetailsAttributes {
+        match value {
+            "PredictiveModelType" => DetailsAttributes::PredictiveModelType,
+            "Algorithm" => DetailsAttributes::Algorithm,
+            _ => DetailsAttributes::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DetailsAttributes {
+    fn from(value: String) -> DetailsAttributes {
+        match value.as_str() {
+            "PredictiveModelType" => DetailsAttributes::PredictiveModelType,
+            "Algorithm" => DetailsAttributes::Algorithm,
+            _ => DetailsAttributes::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DetailsAttributes {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DetailsAttributes, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DetailsAttributes {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DetailsAttributes {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DetailsAttributes {
+    fn deserialize<D>(deserializer: D) -> Result<DetailsAttributes, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Object status with the following possible values:</p> <ul> <li> <p> <code>PENDING</code> </p> </li> <li> <p> <code>INPROGRESS</code> </p> </li> <li> <p> <code>FAILED</code> </p> </li> <li> <p> <code>COMPLETED</code> </p> </li> <li> <p> <code>DELETED</code> </p> </li> </ul>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EntityStatus {
+    /// `PENDING`
+    Pending,
+    /// `INPROGRESS`
+    Inprogress,
+    /// `FAILED`
+    Failed,
+    /// `COMPLETED`
+    Completed,
+    /// `DELETED`
+    Deleted,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EntityStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EntityStatus::Pending => "PENDING",
+            EntityStatus::Inprogress => "INPROGRESS",
+            EntityStatus::Failed => "FAILED",
+            EntityStatus::Completed => "COMPLETED",
+            EntityStatus::Deleted => "DELETED",
+            EntityStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EntityStatus {
+    fn default() -> EntityStatus {
+        EntityStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EntityStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EntityStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EntityStatus {
+    fn from(value: &'a str) -> EntityStatus {
+        match value {
+            "PENDING" => EntityStatus::Pending,
+            "INPROGRESS" => EntityStatus::Inprogress,
+            "FAILED" => EntityStatus::Failed,
+            "COMPLETED" => EntityStatus::Completed,
+            "DELETED" => EntityStatus::Deleted,
+            _ => EntityStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EntityStatus {
+    fn from(value: String) -> EntityStatus {
+        match value.as_str() {
+            "PENDING" => EntityStatus::Pending,
+            "INPROGRESS" => EntityStatus::Inprogress,
+            "FAILED" => EntityStatus::Failed,
+            "COMPLETED" => EntityStatus::Completed,
+            "DELETED" => EntityStatus::Deleted,
+            _ => EntityStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EntityStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EntityStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EntityStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EntityStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EntityStatus {
+    fn deserialize<D>(deserializer: D) -> Result<EntityStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p> Represents the output of <code>GetEvaluation</code> operation. </p> <p>The content consists of the detailed metadata and data file information and the current status of the <code>Evaluation</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -815,8 +1385,13 @@ pub struct Evaluation {
     pub compute_time: Option<i64>,
     /// <p>The time that the <code>Evaluation</code> was created. The time is expressed in epoch time.</p>
     #[serde(rename = "CreatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The AWS user account that invoked the evaluation. The account type can be either an AWS root account or an AWS Identity and Access Management (IAM) user account.</p>
     #[serde(rename = "CreatedByIamUser")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -830,16 +1405,26 @@ pub struct Evaluation {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub evaluation_id: Option<String>,
     #[serde(rename = "FinishedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub finished_at: Option<f64>,
+    pub finished_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The location and name of the data in Amazon Simple Storage Server (Amazon S3) that is used in the evaluation.</p>
     #[serde(rename = "InputDataLocationS3")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub input_data_location_s3: Option<String>,
     /// <p>The time of the most recent edit to the <code>Evaluation</code>. The time is expressed in epoch time.</p>
     #[serde(rename = "LastUpdatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_at: Option<f64>,
+    pub last_updated_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The ID of the <code>MLModel</code> that is the focus of the evaluation.</p>
     #[serde(rename = "MLModelId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -857,14 +1442,143 @@ pub struct Evaluation {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub performance_metrics: Option<PerformanceMetrics>,
     #[serde(rename = "StartedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub started_at: Option<f64>,
+    pub started_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>The status of the evaluation. This element can have one of the following values:</p> <ul> <li> <code>PENDING</code> - Amazon Machine Learning (Amazon ML) submitted a request to evaluate an <code>MLModel</code>.</li> <li> <code>INPROGRESS</code> - The evaluation is underway.</li> <li> <code>FAILED</code> - The request to evaluate an <code>MLModel</code> did not run to completion. It is not usable.</li> <li> <code>COMPLETED</code> - The evaluation process completed successfully.</li> <li> <code>DELETED</code> - The <code>Evaluation</code> is marked as deleted. It is not usable.</li> </ul></p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<EntityStatus>,
+}
+
+/// <p>A list of the variables to use in searching or filtering <code>Evaluation</code>.</p> <ul> <li> <p> <code>CreatedAt</code> - Sets the search criteria to <code>Evaluation</code> creation date.</p> </li> <li> <p> <code>Status</code> - Sets the search criteria to <code>Evaluation</code> status.</p> </li> <li> <p> <code>Name</code> - Sets the search criteria to the contents of <code>Evaluation</code> <b> </b> <code>Name</code>.</p> </li> <li> <p> <code>IAMUser</code> - Sets the search criteria to the user account that invoked an evaluation.</p> </li> <li> <p> <code>MLModelId</code> - Sets the search criteria to the <code>Predictor</code> that was evaluated.</p> </li> <li> <p> <code>DataSourceId</code> - Sets the search criteria to the <code>DataSource</code> used in evaluation.</p> </li> <li> <p> <code>DataUri</code> - Sets the search criteria to the data file(s) used in evaluation. The URL can identify either a file or an Amazon Simple Storage Service (Amazon S3) bucket or directory.</p> </li> </ul>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EvaluationFilterVariable {
+    /// `CreatedAt`
+    CreatedAt,
+    /// `LastUpdatedAt`
+    LastUpdatedAt,
+    /// `Status`
+    Status,
+    /// `Name`
+    Name,
+    /// `IAMUser`
+    IAMUser,
+    /// `MLModelId`
+    MLModelId,
+    /// `DataSourceId`
+    DataSourceId,
+    /// `DataURI`
+    DataURI,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EvaluationFilterVariable {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EvaluationFilterVariable::CreatedAt => "CreatedAt",
+            EvaluationFilterVariable::LastUpdatedAt => "LastUpdatedAt",
+            EvaluationFilterVariable::Status => "Status",
+            EvaluationFilterVariable::Name => "Name",
+            EvaluationFilterVariable::IAMUser => "IAMUser",
+            EvaluationFilterVariable::MLModelId => "MLModelId",
+            EvaluationFilterVariable::DataSourceId => "DataSourceId",
+            EvaluationFilterVariable::DataURI => "DataURI",
+            EvaluationFilterVariable::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EvaluationFilterVariable {
+    fn default() -> EvaluationFilterVariable {
+        EvaluationFilterVariable::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EvaluationFilterVariable {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EvaluationFilterVariable {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EvaluationFilterVariable {
+    fn from(value: &'a str) -> EvaluationFilterVariable {
+        match value {
+            "CreatedAt" => EvaluationFilterVariable::CreatedAt,
+            "LastUpdatedAt" => EvaluationFilterVariable::LastUpdatedAt,
+            "Status" => EvaluationFilterVariable::Status,
+            "Name" => EvaluationFilterVariable::Name,
+            "IAMUser" => EvaluationFilterVariable::IAMUser,
+            "MLModelId" => EvaluationFilterVariable::MLModelId,
+            "DataSourceId" => EvaluationFilterVariable::DataSourceId,
+            "DataURI" => EvaluationFilterVariable::DataURI,
+            _ => EvaluationFilterVariable::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EvaluationFilterVariable {
+    fn from(value: String) -> EvaluationFilterVariable {
+        match value.as_str() {
+            "CreatedAt" => EvaluationFilterVariable::CreatedAt,
+            "LastUpdatedAt" => EvaluationFilterVariable::LastUpdatedAt,
+            "Status" => EvaluationFilterVariable::Status,
+            "Name" => EvaluationFilterVariable::Name,
+            "IAMUser" => EvaluationFilterVariable::IAMUser,
+            "MLModelId" => EvaluationFilterVariable::MLModelId,
+            "DataSourceId" => EvaluationFilterVariable::DataSourceId,
+            "DataURI" => EvaluationFilterVariable::DataURI,
+            _ => EvaluationFilterVariable::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EvaluationFilterVariable {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EvaluationFilterVariable, Self::Err> {
+        Ok(value.into())
+    }
 }
 
+impl ::rusoto_core::param::ToParam for EvaluationFilterVariable {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EvaluationFilterVariable {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EvaluationFilterVariable {
+    fn deserialize<D>(deserializer: D) -> Result<EvaluationFilterVariable, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct GetBatchPredictionInput {
@@ -891,16 +1605,26 @@ pub struct GetBatchPredictionOutput {
     pub compute_time: Option<i64>,
     /// <p>The time when the <code>BatchPrediction</code> was created. The time is expressed in epoch time.</p>
     #[serde(rename = "CreatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The AWS user account that invoked the <code>BatchPrediction</code>. The account type can be either an AWS root account or an AWS Identity and Access Management (IAM) user account.</p>
     #[serde(rename = "CreatedByIamUser")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub created_by_iam_user: Option<String>,
     /// <p>The epoch time when Amazon Machine Learning marked the <code>BatchPrediction</code> as <code>COMPLETED</code> or <code>FAILED</code>. <code>FinishedAt</code> is only available when the <code>BatchPrediction</code> is in the <code>COMPLETED</code> or <code>FAILED</code> state.</p>
     #[serde(rename = "FinishedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub finished_at: Option<f64>,
+    pub finished_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The location of the data file or directory in Amazon Simple Storage Service (Amazon S3).</p>
     #[serde(rename = "InputDataLocationS3")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -911,8 +1635,13 @@ pub struct GetBatchPredictionOutput {
     pub invalid_record_count: Option<i64>,
     /// <p>The time of the most recent edit to <code>BatchPrediction</code>. The time is expressed in epoch time.</p>
     #[serde(rename = "LastUpdatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_at: Option<f64>,
+    pub last_updated_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A link to the file that contains logs of the <code>CreateBatchPrediction</code> operation.</p>
     #[serde(rename = "LogUri")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -935,12 +1664,17 @@ pub struct GetBatchPredictionOutput {
     pub output_uri: Option<String>,
     /// <p>The epoch time when Amazon Machine Learning marked the <code>BatchPrediction</code> as <code>INPROGRESS</code>. <code>StartedAt</code> isn't available if the <code>BatchPrediction</code> is in the <code>PENDING</code> state.</p>
     #[serde(rename = "StartedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub started_at: Option<f64>,
+    pub started_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>The status of the <code>BatchPrediction</code>, which can be one of the following values:</p> <ul> <li> <code>PENDING</code> - Amazon Machine Learning (Amazon ML) submitted a request to generate batch predictions.</li> <li> <code>INPROGRESS</code> - The batch predictions are in progress.</li> <li> <code>FAILED</code> - The request to perform a batch prediction did not run to completion. It is not usable.</li> <li> <code>COMPLETED</code> - The batch prediction process completed successfully.</li> <li> <code>DELETED</code> - The <code>BatchPrediction</code> is marked as deleted. It is not usable.</li> </ul></p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<EntityStatus>,
     /// <p>The number of total records that Amazon Machine Learning saw while processing the <code>BatchPrediction</code>.</p>
     #[serde(rename = "TotalRecordCount")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -973,8 +1707,13 @@ pub struct GetDataSourceOutput {
     pub compute_time: Option<i64>,
     /// <p>The time that the <code>DataSource</code> was created. The time is expressed in epoch time.</p>
     #[serde(rename = "CreatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The AWS user account from which the <code>DataSource</code> was created. The account type can be either an AWS root account or an AWS Identity and Access Management (IAM) user account.</p>
     #[serde(rename = "CreatedByIamUser")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1001,12 +1740,22 @@ pub struct GetDataSourceOutput {
     pub data_source_schema: Option<String>,
     /// <p>The epoch time when Amazon Machine Learning marked the <code>DataSource</code> as <code>COMPLETED</code> or <code>FAILED</code>. <code>FinishedAt</code> is only available when the <code>DataSource</code> is in the <code>COMPLETED</code> or <code>FAILED</code> state.</p>
     #[serde(rename = "FinishedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub finished_at: Option<f64>,
+    pub finished_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The time of the most recent edit to the <code>DataSource</code>. The time is expressed in epoch time.</p>
     #[serde(rename = "LastUpdatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_at: Option<f64>,
+    pub last_updated_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A link to the file containing logs of <code>CreateDataSourceFrom*</code> operations.</p>
     #[serde(rename = "LogUri")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1034,12 +1783,17 @@ pub struct GetDataSourceOutput {
     pub role_arn: Option<String>,
     /// <p>The epoch time when Amazon Machine Learning marked the <code>DataSource</code> as <code>INPROGRESS</code>. <code>StartedAt</code> isn't available if the <code>DataSource</code> is in the <code>PENDING</code> state.</p>
     #[serde(rename = "StartedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub started_at: Option<f64>,
+    pub started_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>The current status of the <code>DataSource</code>. This element can have one of the following values:</p> <ul> <li> <code>PENDING</code> - Amazon ML submitted a request to create a <code>DataSource</code>.</li> <li> <code>INPROGRESS</code> - The creation process is underway.</li> <li> <code>FAILED</code> - The request to create a <code>DataSource</code> did not run to completion. It is not usable.</li> <li> <code>COMPLETED</code> - The creation process completed successfully.</li> <li> <code>DELETED</code> - The <code>DataSource</code> is marked as deleted. It is not usable.</li> </ul></p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<EntityStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -1060,8 +1814,13 @@ pub struct GetEvaluationOutput {
     pub compute_time: Option<i64>,
     /// <p>The time that the <code>Evaluation</code> was created. The time is expressed in epoch time.</p>
     #[serde(rename = "CreatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The AWS user account that invoked the evaluation. The account type can be either an AWS root account or an AWS Identity and Access Management (IAM) user account.</p>
     #[serde(rename = "CreatedByIamUser")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1076,16 +1835,26 @@ pub struct GetEvaluationOutput {
     pub evaluation_id: Option<String>,
     /// <p>The epoch time when Amazon Machine Learning marked the <code>Evaluation</code> as <code>COMPLETED</code> or <code>FAILED</code>. <code>FinishedAt</code> is only available when the <code>Evaluation</code> is in the <code>COMPLETED</code> or <code>FAILED</code> state.</p>
     #[serde(rename = "FinishedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub finished_at: Option<f64>,
+    pub finished_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The location of the data file or directory in Amazon Simple Storage Service (Amazon S3).</p>
     #[serde(rename = "InputDataLocationS3")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub input_data_location_s3: Option<String>,
     /// <p>The time of the most recent edit to the <code>Evaluation</code>. The time is expressed in epoch time.</p>
     #[serde(rename = "LastUpdatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_at: Option<f64>,
+    pub last_updated_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A link to the file that contains logs of the <code>CreateEvaluation</code> operation.</p>
     #[serde(rename = "LogUri")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1108,12 +1877,17 @@ pub struct GetEvaluationOutput {
     pub performance_metrics: Option<PerformanceMetrics>,
     /// <p>The epoch time when Amazon Machine Learning marked the <code>Evaluation</code> as <code>INPROGRESS</code>. <code>StartedAt</code> isn't available if the <code>Evaluation</code> is in the <code>PENDING</code> state.</p>
     #[serde(rename = "StartedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub started_at: Option<f64>,
+    pub started_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>The status of the evaluation. This element can have one of the following values:</p> <ul> <li> <code>PENDING</code> - Amazon Machine Language (Amazon ML) submitted a request to evaluate an <code>MLModel</code>.</li> <li> <code>INPROGRESS</code> - The evaluation is underway.</li> <li> <code>FAILED</code> - The request to evaluate an <code>MLModel</code> did not run to completion. It is not usable.</li> <li> <code>COMPLETED</code> - The evaluation process completed successfully.</li> <li> <code>DELETED</code> - The <code>Evaluation</code> is marked as deleted. It is not usable.</li> </ul></p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<EntityStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -1138,8 +1912,13 @@ pub struct GetMLModelOutput {
     pub compute_time: Option<i64>,
     /// <p>The time that the <code>MLModel</code> was created. The time is expressed in epoch time.</p>
     #[serde(rename = "CreatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The AWS user account from which the <code>MLModel</code> was created. The account type can be either an AWS root account or an AWS Identity and Access Management (IAM) user account.</p>
     #[serde(rename = "CreatedByIamUser")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1150,16 +1929,26 @@ pub struct GetMLModelOutput {
     pub endpoint_info: Option<RealtimeEndpointInfo>,
     /// <p>The epoch time when Amazon Machine Learning marked the <code>MLModel</code> as <code>COMPLETED</code> or <code>FAILED</code>. <code>FinishedAt</code> is only available when the <code>MLModel</code> is in the <code>COMPLETED</code> or <code>FAILED</code> state.</p>
     #[serde(rename = "FinishedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub finished_at: Option<f64>,
+    pub finished_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The location of the data file or directory in Amazon Simple Storage Service (Amazon S3).</p>
     #[serde(rename = "InputDataLocationS3")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub input_data_location_s3: Option<String>,
     /// <p>The time of the most recent edit to the <code>MLModel</code>. The time is expressed in epoch time.</p>
     #[serde(rename = "LastUpdatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_at: Option<f64>,
+    pub last_updated_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A link to the file that contains logs of the <code>CreateMLModel</code> operation.</p>
     #[serde(rename = "LogUri")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1171,7 +1960,7 @@ pub struct GetMLModelOutput {
     /// <p><p>Identifies the <code>MLModel</code> category. The following are the available types: </p> <ul> <li>REGRESSION -- Produces a numeric result. For example, &quot;What price should a house be listed at?&quot;</li> <li>BINARY -- Produces one of two possible results. For example, &quot;Is this an e-commerce website?&quot;</li> <li>MULTICLASS -- Produces one of several possible results. For example, &quot;Is this a HIGH, LOW or MEDIUM risk trade?&quot;</li> </ul></p>
     #[serde(rename = "MLModelType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ml_model_type: Option<String>,
+    pub ml_model_type: Option<MLModelType>,
     /// <p>A description of the most recent details about accessing the <code>MLModel</code>.</p>
     #[serde(rename = "Message")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1194,19 +1983,29 @@ pub struct GetMLModelOutput {
     pub score_threshold: Option<f32>,
     /// <p>The time of the most recent edit to the <code>ScoreThreshold</code>. The time is expressed in epoch time.</p>
     #[serde(rename = "ScoreThresholdLastUpdatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub score_threshold_last_updated_at: Option<f64>,
+    pub score_threshold_last_updated_at: Option<::chrono::DateTime<::chrono::Utc>>,
     #[serde(rename = "SizeInBytes")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub size_in_bytes: Option<i64>,
     /// <p>The epoch time when Amazon Machine Learning marked the <code>MLModel</code> as <code>INPROGRESS</code>. <code>StartedAt</code> isn't available if the <code>MLModel</code> is in the <code>PENDING</code> state.</p>
     #[serde(rename = "StartedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub started_at: Option<f64>,
+    pub started_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>The current status of the <code>MLModel</code>. This element can have one of the following values:</p> <ul> <li> <code>PENDING</code> - Amazon Machine Learning (Amazon ML) submitted a request to describe a <code>MLModel</code>.</li> <li> <code>INPROGRESS</code> - The request is processing.</li> <li> <code>FAILED</code> - The request did not run to completion. The ML model isn&#39;t usable.</li> <li> <code>COMPLETED</code> - The request completed successfully.</li> <li> <code>DELETED</code> - The <code>MLModel</code> is marked as deleted. It isn&#39;t usable.</li> </ul></p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<EntityStatus>,
     /// <p>The ID of the training <code>DataSource</code>.</p>
     #[serde(rename = "TrainingDataSourceId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1224,14 +2023,19 @@ pub struct MLModel {
     /// <p><p>The algorithm used to train the <code>MLModel</code>. The following algorithm is supported:</p> <ul> <li> <code>SGD</code> -- Stochastic gradient descent. The goal of <code>SGD</code> is to minimize the gradient of the loss function. </li> </ul></p>
     #[serde(rename = "Algorithm")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub algorithm: Option<String>,
+    pub algorithm: Option<Algorithm>,
     #[serde(rename = "ComputeTime")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub compute_time: Option<i64>,
     /// <p>The time that the <code>MLModel</code> was created. The time is expressed in epoch time.</p>
     #[serde(rename = "CreatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The AWS user account from which the <code>MLModel</code> was created. The account type can be either an AWS root account or an AWS Identity and Access Management (IAM) user account.</p>
     #[serde(rename = "CreatedByIamUser")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1241,16 +2045,26 @@ pub struct MLModel {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub endpoint_info: Option<RealtimeEndpointInfo>,
     #[serde(rename = "FinishedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub finished_at: Option<f64>,
+    pub finished_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The location of the data file or directory in Amazon Simple Storage Service (Amazon S3).</p>
     #[serde(rename = "InputDataLocationS3")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub input_data_location_s3: Option<String>,
     /// <p>The time of the most recent edit to the <code>MLModel</code>. The time is expressed in epoch time.</p>
     #[serde(rename = "LastUpdatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_at: Option<f64>,
+    pub last_updated_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The ID assigned to the <code>MLModel</code> at creation.</p>
     #[serde(rename = "MLModelId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1258,7 +2072,7 @@ pub struct MLModel {
     /// <p><p>Identifies the <code>MLModel</code> category. The following are the available types:</p> <ul> <li> <code>REGRESSION</code> - Produces a numeric result. For example, &quot;What price should a house be listed at?&quot;</li> <li> <code>BINARY</code> - Produces one of two possible results. For example, &quot;Is this a child-friendly web site?&quot;.</li> <li> <code>MULTICLASS</code> - Produces one of several possible results. For example, &quot;Is this a HIGH-, LOW-, or MEDIUM&lt;?oxy<em>delete author=&quot;annbech&quot; timestamp=&quot;20160328T175050-0700&quot; content=&quot; &quot;&gt;&lt;?oxy</em>insert<em>start author=&quot;annbech&quot; timestamp=&quot;20160328T175050-0700&quot;&gt;-&lt;?oxy</em>insert_end&gt;risk trade?&quot;.</li> </ul></p>
     #[serde(rename = "MLModelType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ml_model_type: Option<String>,
+    pub ml_model_type: Option<MLModelType>,
     /// <p>A description of the most recent details about accessing the <code>MLModel</code>.</p>
     #[serde(rename = "Message")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1272,18 +2086,28 @@ pub struct MLModel {
     pub score_threshold: Option<f32>,
     /// <p>The time of the most recent edit to the <code>ScoreThreshold</code>. The time is expressed in epoch time.</p>
     #[serde(rename = "ScoreThresholdLastUpdatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub score_threshold_last_updated_at: Option<f64>,
+    pub score_threshold_last_updated_at: Option<::chrono::DateTime<::chrono::Utc>>,
     #[serde(rename = "SizeInBytes")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub size_in_bytes: Option<i64>,
     #[serde(rename = "StartedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub started_at: Option<f64>,
+    pub started_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>The current status of an <code>MLModel</code>. This element can have one of the following values: </p> <ul> <li> <code>PENDING</code> - Amazon Machine Learning (Amazon ML) submitted a request to create an <code>MLModel</code>.</li> <li> <code>INPROGRESS</code> - The creation process is underway.</li> <li> <code>FAILED</code> - The request to create an <code>MLModel</code> didn&#39;t run to completion. The model isn&#39;t usable.</li> <li> <code>COMPLETED</code> - The creation process completed successfully.</li> <li> <code>DELETED</code> - The <code>MLModel</code> is marked as deleted. It isn&#39;t usable.</li> </ul></p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<EntityStatus>,
     /// <p>The ID of the training <code>DataSource</code>. The <code>CreateMLModel</code> operation uses the <code>TrainingDataSourceId</code>.</p>
     #[serde(rename = "TrainingDataSourceId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1294,6 +2118,237 @@ pub struct MLModel {
     pub training_parameters: Option<::std::collections::HashMap<String, String>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MLModelFilterVariable {
+    /// `CreatedAt`
+    CreatedAt,
+    /// `LastUpdatedAt`
+    LastUpdatedAt,
+    /// `Status`
+    Status,
+    /// `Name`
+    Name,
+    /// `IAMUser`
+    IAMUser,
+    /// `TrainingDataSourceId`
+    TrainingDataSourceId,
+    /// `RealtimeEndpointStatus`
+    RealtimeEndpointStatus,
+    /// `MLModelType`
+    MLModelType,
+    /// `Algorithm`
+    Algorithm,
+    /// `TrainingDataURI`
+    TrainingDataURI,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl MLModelFilterVariable {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MLModelFilterVariable::CreatedAt => "CreatedAt",
+            MLModelFilterVariable::LastUpdatedAt => "LastUpdatedAt",
+            MLModelFilterVariable::Status => "Status",
+            MLModelFilterVariable::Name => "Name",
+            MLModelFilterVariable::IAMUser => "IAMUser",
+            MLModelFilterVariable::TrainingDataSourceId => "TrainingDataSourceId",
+            MLModelFilterVariable::RealtimeEndpointStatus => "RealtimeEndpointStatus",
+            MLModelFilterVariable::MLModelType => "MLModelType",
+            MLModelFilterVariable::Algorithm => "Algorithm",
+            MLModelFilterVariable::TrainingDataURI => "TrainingDataURI",
+            MLModelFilterVariable::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for MLModelFilterVariable {
+    fn default() -> MLModelFilterVariable {
+        MLModelFilterVariable::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for MLModelFilterVariable {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for MLModelFilterVariable {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for MLModelFilterVariable {
+    fn from(value: &'a str) -> MLModelFilterVariable {
+        match value {
+            "CreatedAt" => MLModelFilterVariable::CreatedAt,
+            "LastUpdatedAt" => MLModelFilterVariable::LastUpdatedAt,
+            "Status" => MLModelFilterVariable::Status,
+            "Name" => MLModelFilterVariable::Name,
+            "IAMUser" => MLModelFilterVariable::IAMUser,
+            "TrainingDataSourceId" => MLModelFilterVariable::TrainingDataSourceId,
+            "RealtimeEndpointStatus" => MLModelFilterVariable::RealtimeEndpointStatus,
+            "MLModelType" => MLModelFilterVariable::MLModelType,
+            "Algorithm" => MLModelFilterVariable::Algorithm,
+            "TrainingDataURI" => MLModelFilterVariable::TrainingDataURI,
+            _ => MLModelFilterVariable::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MLModelFilterVariable {
+    fn from(value: String) -> MLModelFilterVariable {
+        match value.as_str() {
+            "CreatedAt" => MLModelFilterVariable::CreatedAt,
+            "LastUpdatedAt" => MLModelFilterVariable::LastUpdatedAt,
+            "Status" => MLModelFilterVariable::Status,
+            "Name" => MLModelFilterVariable::Name,
+            "IAMUser" => MLModelFilterVariable::IAMUser,
+            "TrainingDataSourceId" => MLModelFilterVariable::TrainingDataSourceId,
+            "RealtimeEndpointStatus" => MLModelFilterVariable::RealtimeEndpointStatus,
+            "MLModelType" => MLModelFilterVariable::MLModelType,
+            "Algorithm" => MLModelFilterVariable::Algorithm,
+            "TrainingDataURI" => MLModelFilterVariable::TrainingDataURI,
+            _ => MLModelFilterVariable::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for MLModelFilterVariable {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<MLModelFilterVariable, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MLModelFilterVariable {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for MLModelFilterVariable {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MLModelFilterVariable {
+    fn deserialize<D>(deserializer: D) -> Result<MLModelFilterVariable, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MLModelType {
+    /// `REGRESSION`
+    Regression,
+    /// `BINARY`
+    Binary,
+    /// `MULTICLASS`
+    Multiclass,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl MLModelType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MLModelType::Regression => "REGRESSION",
+            MLModelType::Binary => "BINARY",
+            MLModelType::Multiclass => "MULTICLASS",
+            MLModelType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for MLModelType {
+    fn default() -> MLModelType {
+        MLModelType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for MLModelType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for MLModelType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for MLModelType {
+    fn from(value: &'a str) -> MLModelType {
+        match value {
+            "REGRESSION" => MLModelType::Regression,
+            "BINARY" => MLModelType::Binary,
+            "MULTICLASS" => MLModelType::Multiclass,
+            _ => MLModelType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MLModelType {
+    fn from(value: String) -> MLModelType {
+        match value.as_str() {
+            "REGRESSION" => MLModelType::Regression,
+            "BINARY" => MLModelType::Binary,
+            "MULTICLASS" => MLModelType::Multiclass,
+            _ => MLModelType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for MLModelType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<MLModelType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MLModelType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for MLModelType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MLModelType {
+    fn deserialize<D>(deserializer: D) -> Result<MLModelType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Measurements of how well the <code>MLModel</code> performed on known observations. One of the following metrics is returned, based on the type of the <code>MLModel</code>: </p> <ul> <li> <p>BinaryAUC: The binary <code>MLModel</code> uses the Area Under the Curve (AUC) technique to measure performance. </p> </li> <li> <p>RegressionRMSE: The regression <code>MLModel</code> uses the Root Mean Square Error (RMSE) technique to measure performance. RMSE measures the difference between predicted and actual values for a single variable.</p> </li> <li> <p>MulticlassAvgFScore: The multiclass <code>MLModel</code> uses the F1 score technique to measure performance. </p> </li> </ul> <p> For more information about performance metrics, please see the <a href="http://docs.aws.amazon.com/machine-learning/latest/dg">Amazon Machine Learning Developer Guide</a>. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1329,7 +2384,7 @@ pub struct PredictOutput {
 pub struct Prediction {
     #[serde(rename = "details")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub details: Option<::std::collections::HashMap<String, String>>,
+    pub details: Option<::std::collections::HashMap<DetailsAttributes, String>>,
     /// <p>The prediction label for either a <code>BINARY</code> or <code>MULTICLASS</code> <code>MLModel</code>.</p>
     #[serde(rename = "predictedLabel")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1440,12 +2495,17 @@ pub struct RDSMetadata {
 pub struct RealtimeEndpointInfo {
     /// <p>The time that the request to create the real-time endpoint for the <code>MLModel</code> was received. The time is expressed in epoch time.</p>
     #[serde(rename = "CreatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p> The current status of the real-time endpoint for the <code>MLModel</code>. This element can have one of the following values: </p> <ul> <li> <code>NONE</code> - Endpoint does not exist or was previously deleted.</li> <li> <code>READY</code> - Endpoint is ready to be used for real-time predictions.</li> <li> <code>UPDATING</code> - Updating/creating the endpoint. </li> </ul></p>
     #[serde(rename = "EndpointStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub endpoint_status: Option<String>,
+    pub endpoint_status: Option<RealtimeEndpointStatus>,
     /// <p><p>The URI that specifies where to send real-time prediction requests for the <code>MLModel</code>.</p> <note><title>Note</title> <p>The application must wait until the real-time endpoint is ready before using this URI.</p> </note></p>
     #[serde(rename = "EndpointUrl")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1456,6 +2516,109 @@ pub struct RealtimeEndpointInfo {
     pub peak_requests_per_second: Option<i64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RealtimeEndpointStatus {
+    /// `NONE`
+    None,
+    /// `READY`
+    Ready,
+    /// `UPDATING`
+    Updating,
+    /// `FAILED`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RealtimeEndpointStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RealtimeEndpointStatus::None => "NONE",
+            RealtimeEndpointStatus::Ready => "READY",
+            RealtimeEndpointStatus::Updating => "UPDATING",
+            RealtimeEndpointStatus::Failed => "FAILED",
+            RealtimeEndpointStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RealtimeEndpointStatus {
+    fn default() -> RealtimeEndpointStatus {
+        RealtimeEndpointStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RealtimeEndpointStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RealtimeEndpointStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RealtimeEndpointStatus {
+    fn from(value: &'a str) -> RealtimeEndpointStatus {
+        match value {
+            "NONE" => RealtimeEndpointStatus::None,
+            "READY" => RealtimeEndpointStatus::Ready,
+            "UPDATING" => RealtimeEndpointStatus::Updating,
+            "FAILED" => RealtimeEndpointStatus::Failed,
+            _ => RealtimeEndpointStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RealtimeEndpointStatus {
+    fn from(value: String) -> RealtimeEndpointStatus {
+        match value.as_str() {
+            "NONE" => RealtimeEndpointStatus::None,
+            "READY" => RealtimeEndpointStatus::Ready,
+            "UPDATING" => RealtimeEndpointStatus::Updating,
+            "FAILED" => RealtimeEndpointStatus::Failed,
+            _ => RealtimeEndpointStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RealtimeEndpointStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RealtimeEndpointStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RealtimeEndpointStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RealtimeEndpointStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RealtimeEndpointStatus {
+    fn deserialize<D>(deserializer: D) -> Result<RealtimeEndpointStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes the data specification of an Amazon Redshift <code>DataSource</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -1542,6 +2705,100 @@ pub struct S3DataSpec {
     pub data_schema_location_s3: Option<String>,
 }
 
+/// <p>The sort order specified in a listing condition. Possible values include the following:</p> <ul> <li> <p> <code>asc</code> - Present the information in ascending order (from A-Z).</p> </li> <li> <p> <code>dsc</code> - Present the information in descending order (from Z-A).</p> </li> </ul>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SortOrder {
+    /// `asc`
+    Asc,
+    /// `dsc`
+    Dsc,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SortOrder {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SortOrder::Asc => "asc",
+            SortOrder::Dsc => "dsc",
+            SortOrder::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SortOrder {
+    fn default() -> SortOrder {
+        SortOrder::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SortOrder {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SortOrder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SortOrder {
+    fn from(value: &'a str) -> SortOrder {
+        match value {
+            "asc" => SortOrder::Asc,
+            "dsc" => SortOrder::Dsc,
+            _ => SortOrder::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SortOrder {
+    fn from(value: String) -> SortOrder {
+        match value.as_str() {
+            "asc" => SortOrder::Asc,
+            "dsc" => SortOrder::Dsc,
+            _ => SortOrder::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SortOrder {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SortOrder, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SortOrder {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SortOrder {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SortOrder {
+    fn deserialize<D>(deserializer: D) -> Result<SortOrder, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A custom key-value pair associated with an ML object, such as an ML model.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Tag {
@@ -1555,6 +2812,109 @@ pub struct Tag {
     pub value: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TaggableResourceType {
+    /// `BatchPrediction`
+    BatchPrediction,
+    /// `DataSource`
+    DataSource,
+    /// `Evaluation`
+    Evaluation,
+    /// `MLModel`
+    MLModel,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TaggableResourceType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TaggableResourceType::BatchPrediction => "BatchPrediction",
+            TaggableResourceType::DataSource => "DataSource",
+            TaggableResourceType::Evaluation => "Evaluation",
+            TaggableResourceType::MLModel => "MLModel",
+            TaggableResourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TaggableResourceType {
+    fn default() -> TaggableResourceType {
+        TaggableResourceType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TaggableResourceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TaggableResourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TaggableResourceType {
+    fn from(value: &'a str) -> TaggableResourceType {
+        match value {
+            "BatchPrediction" => TaggableResourceType::BatchPrediction,
+            "DataSource" => TaggableResourceType::DataSource,
+            "Evaluation" => TaggableResourceType::Evaluation,
+            "MLModel" => TaggableResourceType::MLModel,
+            _ => TaggableResourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TaggableResourceType {
+    fn from(value: String) -> TaggableResourceType {
+        match value.as_str() {
+            "BatchPrediction" => TaggableResourceType::BatchPrediction,
+            "DataSource" => TaggableResourceType::DataSource,
+            "Evaluation" => TaggableResourceType::Evaluation,
+            "MLModel" => TaggableResourceType::MLModel,
+            _ => TaggableResourceType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TaggableResourceType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TaggableResourceType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TaggableResourceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TaggableResourceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TaggableResourceType {
+    fn deserialize<D>(deserializer: D) -> Result<TaggableResourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct UpdateBatchPredictionInput {
@@ -3061,11 +4421,12 @@ pub struct MachineLearningClient {
 impl MachineLearningClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> MachineLearningClient {
         MachineLearningClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Machine Learning"),
         }
     }
 
@@ -3097,6 +4458,7 @@ impl MachineLearning for MachineLearningClient {
         input: AddTagsInput,
     ) -> Result<AddTagsOutput, RusotoError<AddTagsError>> {
         let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("AddTags");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonML_20141212.AddTags");
@@ -3124,6 +4486,7 @@ impl MachineLearning for MachineLearningClient {
         input: CreateBatchPredictionInput,
     ) -> Result<CreateBatchPredictionOutput, RusotoError<CreateBatchPredictionError>> {
         let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("CreateBatchPrediction");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonML_20141212.CreateBatchPrediction");
@@ -3152,6 +4515,7 @@ impl MachineLearning for MachineLearningClient {
         input: CreateDataSourceFromRDSInput,
     ) -> Result<CreateDataSourceFromRDSOutput, RusotoError<CreateDataSourceFromRDSError>> {
         let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("CreateDataSourceFromRDS");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonML_20141212.CreateDataSourceFromRDS");
@@ -3181,6 +4545,7 @@ impl MachineLearning for MachineLearningClient {
     ) -> Result<CreateDataSourceFromRedshiftOutput, RusotoError<CreateDataSourceFromRedshiftError>>
     {
         let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("CreateDataSourceFromRedshift");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3212,6 +4577,7 @@ impl MachineLearning for MachineLearningClient {
         input: CreateDataSourceFromS3Input,
     ) -> Result<CreateDataSourceFromS3Output, RusotoError<CreateDataSourceFromS3Error>> {
         let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("CreateDataSourceFromS3");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonML_20141212.CreateDataSourceFromS3");
@@ -3240,6 +4606,7 @@ impl MachineLearning for MachineLearningClient {
         input: CreateEvaluationInput,
     ) -> Result<CreateEvaluationOutput, RusotoError<CreateEvaluationError>> {
         let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("CreateEvaluation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonML_20141212.CreateEvaluation");
@@ -3267,6 +4634,7 @@ impl MachineLearning for MachineLearningClient {
         input: CreateMLModelInput,
     ) -> Result<CreateMLModelOutput, RusotoError<CreateMLModelError>> {
         let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("CreateMLModel");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonML_20141212.CreateMLModel");
@@ -3294,6 +4662,7 @@ impl MachineLearning for MachineLearningClient {
         input: CreateRealtimeEndpointInput,
     ) -> Result<CreateRealtimeEndpointOutput, RusotoError<CreateRealtimeEndpointError>> {
         let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("CreateRealtimeEndpoint");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonML_20141212.CreateRealtimeEndpoint");
@@ -3322,6 +4691,7 @@ impl MachineLearning for MachineLearningClient {
         input: DeleteBatchPredictionInput,
     ) -> Result<DeleteBatchPredictionOutput, RusotoError<DeleteBatchPredictionError>> {
         let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("DeleteBatchPrediction");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonML_20141212.DeleteBatchPrediction");
@@ -3350,6 +4720,7 @@ impl MachineLearning for MachineLearningClient {
         input: DeleteDataSourceInput,
     ) -> Result<DeleteDataSourceOutput, RusotoError<DeleteDataSourceError>> {
         let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("DeleteDataSource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonML_20141212.DeleteDataSource");
@@ -3377,6 +4748,7 @@ impl MachineLearning for MachineLearningClient {
         input: DeleteEvaluationInput,
     ) -> Result<DeleteEvaluationOutput, RusotoError<DeleteEvaluationError>> {
         let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("DeleteEvaluation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonML_20141212.DeleteEvaluation");
@@ -3404,6 +4776,7 @@ impl MachineLearning for MachineLearningClient {
         input: DeleteMLModelInput,
     ) -> Result<DeleteMLModelOutput, RusotoError<DeleteMLModelError>> {
         let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("DeleteMLModel");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonML_20141212.DeleteMLModel");
@@ -3431,6 +4804,7 @@ impl MachineLearning for MachineLearningClient {
         input: DeleteRealtimeEndpointInput,
     ) -> Result<DeleteRealtimeEndpointOutput, RusotoError<DeleteRealtimeEndpointError>> {
         let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("DeleteRealtimeEndpoint");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonML_20141212.DeleteRealtimeEndpoint");
@@ -3459,6 +4833,7 @@ impl MachineLearning for MachineLearningClient {
         input: DeleteTagsInput,
     ) -> Result<DeleteTagsOutput, RusotoError<DeleteTagsError>> {
         let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("DeleteTags");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonML_20141212.DeleteTags");
@@ -3486,6 +4861,7 @@ impl MachineLearning for MachineLearningClient {
         input: DescribeBatchPredictionsInput,
     ) -> Result<DescribeBatchPredictionsOutput, RusotoError<DescribeBatchPredictionsError>> {
         let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("DescribeBatchPredictions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonML_20141212.DescribeBatchPredictions");
@@ -3514,6 +4890,7 @@ impl MachineLearning for MachineLearningClient {
         input: DescribeDataSourcesInput,
     ) -> Result<DescribeDataSourcesOutput, RusotoError<DescribeDataSourcesError>> {
         let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("DescribeDataSources");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonML_20141212.DescribeDataSources");
@@ -3542,6 +4919,7 @@ impl MachineLearning for MachineLearningClient {
         input: DescribeEvaluationsInput,
     ) -> Result<DescribeEvaluationsOutput, RusotoError<DescribeEvaluationsError>> {
         let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("DescribeEvaluations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonML_20141212.DescribeEvaluations");
@@ -3570,6 +4948,7 @@ impl MachineLearning for MachineLearningClient {
         input: DescribeMLModelsInput,
     ) -> Result<DescribeMLModelsOutput, RusotoError<DescribeMLModelsError>> {
         let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("DescribeMLModels");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonML_20141212.DescribeMLModels");
@@ -3597,6 +4976,7 @@ impl MachineLearning for MachineLearningClient {
         input: DescribeTagsInput,
     ) -> Result<DescribeTagsOutput, RusotoError<DescribeTagsError>> {
         let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("DescribeTags");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonML_20141212.DescribeTags");
@@ -3624,6 +5004,7 @@ impl MachineLearning for MachineLearningClient {
         input: GetBatchPredictionInput,
     ) -> Result<GetBatchPredictionOutput, RusotoError<GetBatchPredictionError>> {
         let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("GetBatchPrediction");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonML_20141212.GetBatchPrediction");
@@ -3652,6 +5033,7 @@ impl MachineLearning for MachineLearningClient {
         input: GetDataSourceInput,
     ) -> Result<GetDataSourceOutput, RusotoError<GetDataSourceError>> {
         let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("GetDataSource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonML_20141212.GetDataSource");
@@ -3679,6 +5061,7 @@ impl MachineLearning for MachineLearningClient {
         input: GetEvaluationInput,
     ) -> Result<GetEvaluationOutput, RusotoError<GetEvaluationError>> {
         let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("GetEvaluation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonML_20141212.GetEvaluation");
@@ -3706,6 +5089,7 @@ impl MachineLearning for MachineLearningClient {
         input: GetMLModelInput,
     ) -> Result<GetMLModelOutput, RusotoError<GetMLModelError>> {
         let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("GetMLModel");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonML_20141212.GetMLModel");
@@ -3733,6 +5117,7 @@ impl MachineLearning for MachineLearningClient {
         input: PredictInput,
     ) -> Result<PredictOutput, RusotoError<PredictError>> {
         let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("Predict");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonML_20141212.Predict");
@@ -3760,6 +5145,7 @@ impl MachineLearning for MachineLearningClient {
         input: UpdateBatchPredictionInput,
     ) -> Result<UpdateBatchPredictionOutput, RusotoError<UpdateBatchPredictionError>> {
         let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("UpdateBatchPrediction");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonML_20141212.UpdateBatchPrediction");
@@ -3788,6 +5174,7 @@ impl MachineLearning for MachineLearningClient {
         input: UpdateDataSourceInput,
     ) -> Result<UpdateDataSourceOutput, RusotoError<UpdateDataSourceError>> {
         let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("UpdateDataSource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonML_20141212.UpdateDataSource");
@@ -3815,6 +5202,7 @@ impl MachineLearning for MachineLearningClient {
         input: UpdateEvaluationInput,
     ) -> Result<UpdateEvaluationOutput, RusotoError<UpdateEvaluationError>> {
         let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("UpdateEvaluation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonML_20141212.UpdateEvaluation");
@@ -3842,6 +5230,7 @@ impl MachineLearning for MachineLearningClient {
         input: UpdateMLModelInput,
     ) -> Result<UpdateMLModelOutput, RusotoError<UpdateMLModelError>> {
         let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("UpdateMLModel");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonML_20141212.UpdateMLModel");
@@ -3863,3 +5252,339 @@ impl MachineLearning for MachineLearningClient {
         }
     }
 }
+impl MachineLearningClient {
+    /// Auto-paginating version of `describe_batch_predictions`, streaming every page of results.
+    pub fn describe_batch_predictions_pages(
+        &self,
+        input: DescribeBatchPredictionsInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeBatchPredictionsOutput,
+        DescribeBatchPredictionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MachineLearningClient, input: DescribeBatchPredictionsInput| async move {
+                client.describe_batch_predictions(input).await
+            },
+            |input: &DescribeBatchPredictionsInput, output: &DescribeBatchPredictionsOutput| {
+                Some(DescribeBatchPredictionsInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_batch_predictions`, streaming the `Results` of every page of results.
+    pub fn describe_batch_predictions_items(
+        &self,
+        input: DescribeBatchPredictionsInput,
+    ) -> rusoto_core::pagination::RusotoStream<BatchPrediction, DescribeBatchPredictionsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_batch_predictions_pages(input),
+            |output: DescribeBatchPredictionsOutput| output.results.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_data_sources`, streaming every page of results.
+    pub fn describe_data_sources_pages(
+        &self,
+        input: DescribeDataSourcesInput,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeDataSourcesOutput, DescribeDataSourcesError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MachineLearningClient, input: DescribeDataSourcesInput| async move {
+                client.describe_data_sources(input).await
+            },
+            |input: &DescribeDataSourcesInput, output: &DescribeDataSourcesOutput| {
+                Some(DescribeDataSourcesInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_data_sources`, streaming the `Results` of every page of results.
+    pub fn describe_data_sources_items(
+        &self,
+        input: DescribeDataSourcesInput,
+    ) -> rusoto_core::pagination::RusotoStream<DataSource, DescribeDataSourcesError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_data_sources_pages(input),
+            |output: DescribeDataSourcesOutput| output.results.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_evaluations`, streaming every page of results.
+    pub fn describe_evaluations_pages(
+        &self,
+        input: DescribeEvaluationsInput,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeEvaluationsOutput, DescribeEvaluationsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MachineLearningClient, input: DescribeEvaluationsInput| async move {
+                client.describe_evaluations(input).await
+            },
+            |input: &DescribeEvaluationsInput, output: &DescribeEvaluationsOutput| {
+                Some(DescribeEvaluationsInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_evaluations`, streaming the `Results` of every page of results.
+    pub fn describe_evaluations_items(
+        &self,
+        input: DescribeEvaluationsInput,
+    ) -> rusoto_core::pagination::RusotoStream<Evaluation, DescribeEvaluationsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_evaluations_pages(input),
+            |output: DescribeEvaluationsOutput| output.results.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_ml_models`, streaming every page of results.
+    pub fn describe_ml_models_pages(
+        &self,
+        input: DescribeMLModelsInput,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeMLModelsOutput, DescribeMLModelsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MachineLearningClient, input: DescribeMLModelsInput| async move {
+                client.describe_ml_models(input).await
+            },
+            |input: &DescribeMLModelsInput, output: &DescribeMLModelsOutput| {
+                Some(DescribeMLModelsInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_ml_models`, streaming the `Results` of every page of results.
+    pub fn describe_ml_models_items(
+        &self,
+        input: DescribeMLModelsInput,
+    ) -> rusoto_core::pagination::RusotoStream<MLModel, DescribeMLModelsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_ml_models_pages(input),
+            |output: DescribeMLModelsOutput| output.results.unwrap_or_default(),
+        )
+    }
+}
+impl MachineLearningClient {
+    /// Waits until `describe_batch_predictions` reports the `BatchPredictionAvailable` state, polling every 30 seconds up to 60 times.
+    pub async fn wait_until_batch_prediction_available(
+        &self,
+        input: DescribeBatchPredictionsInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeBatchPredictionsError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(30), 60);
+        self.wait_until_batch_prediction_available_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_batch_prediction_available`, polling as often and as many times as `config` says.
+    pub async fn wait_until_batch_prediction_available_with_config(
+        &self,
+        input: DescribeBatchPredictionsInput,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeBatchPredictionsError>> {
+        rusoto_core::waiter::wait(
+            "BatchPredictionAvailable",
+            &config,
+            || self.describe_batch_predictions(input.clone()),
+            |result: &Result<
+                DescribeBatchPredictionsOutput,
+                RusotoError<DescribeBatchPredictionsError>,
+            >| match result {
+                Ok(output)
+                    if rusoto_core::waiter::all_match(
+                        output
+                            .results
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.status.iter()),
+                        |v| *v == EntityStatus::Completed,
+                    ) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if output
+                        .results
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| *v == EntityStatus::Failed) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+
+    /// Waits until `describe_data_sources` reports the `DataSourceAvailable` state, polling every 30 seconds up to 60 times.
+    pub async fn wait_until_data_source_available(
+        &self,
+        input: DescribeDataSourcesInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeDataSourcesError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(30), 60);
+        self.wait_until_data_source_available_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_data_source_available`, polling as often and as many times as `config` says.
+    pub async fn wait_until_data_source_available_with_config(
+        &self,
+        input: DescribeDataSourcesInput,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeDataSourcesError>> {
+        rusoto_core::waiter::wait(
+            "DataSourceAvailable",
+            &config,
+            || self.describe_data_sources(input.clone()),
+            |result: &Result<DescribeDataSourcesOutput, RusotoError<DescribeDataSourcesError>>| {
+                match result {
+                    Ok(output)
+                        if rusoto_core::waiter::all_match(
+                            output
+                                .results
+                                .iter()
+                                .flat_map(|v| v.iter())
+                                .flat_map(|v| v.status.iter()),
+                            |v| *v == EntityStatus::Completed,
+                        ) =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Success)
+                    }
+                    Ok(output)
+                        if output
+                            .results
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.status.iter())
+                            .any(|v| *v == EntityStatus::Failed) =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    _ => None,
+                }
+            },
+        )
+        .await
+    }
+
+    /// Waits until `describe_evaluations` reports the `EvaluationAvailable` state, polling every 30 seconds up to 60 times.
+    pub async fn wait_until_evaluation_available(
+        &self,
+        input: DescribeEvaluationsInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeEvaluationsError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(30), 60);
+        self.wait_until_evaluation_available_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_evaluation_available`, polling as often and as many times as `config` says.
+    pub async fn wait_until_evaluation_available_with_config(
+        &self,
+        input: DescribeEvaluationsInput,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeEvaluationsError>> {
+        rusoto_core::waiter::wait(
+            "EvaluationAvailable",
+            &config,
+            || self.describe_evaluations(input.clone()),
+            |result: &Result<DescribeEvaluationsOutput, RusotoError<DescribeEvaluationsError>>| {
+                match result {
+                    Ok(output)
+                        if rusoto_core::waiter::all_match(
+                            output
+                                .results
+                                .iter()
+                                .flat_map(|v| v.iter())
+                                .flat_map(|v| v.status.iter()),
+                            |v| *v == EntityStatus::Completed,
+                        ) =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Success)
+                    }
+                    Ok(output)
+                        if output
+                            .results
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.status.iter())
+                            .any(|v| *v == EntityStatus::Failed) =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    _ => None,
+                }
+            },
+        )
+        .await
+    }
+
+    /// Waits until `describe_ml_models` reports the `MLModelAvailable` state, polling every 30 seconds up to 60 times.
+    pub async fn wait_until_ml_model_available(
+        &self,
+        input: DescribeMLModelsInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeMLModelsError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(30), 60);
+        self.wait_until_ml_model_available_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_ml_model_available`, polling as often and as many times as `config` says.
+    pub async fn wait_until_ml_model_available_with_config(
+        &self,
+        input: DescribeMLModelsInput,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeMLModelsError>> {
+        rusoto_core::waiter::wait(
+            "MLModelAvailable",
+            &config,
+            || self.describe_ml_models(input.clone()),
+            |result: &Result<DescribeMLModelsOutput, RusotoError<DescribeMLModelsError>>| {
+                match result {
+                    Ok(output)
+                        if rusoto_core::waiter::all_match(
+                            output
+                                .results
+                                .iter()
+                                .flat_map(|v| v.iter())
+                                .flat_map(|v| v.status.iter()),
+                            |v| *v == EntityStatus::Completed,
+                        ) =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Success)
+                    }
+                    Ok(output)
+                        if output
+                            .results
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.status.iter())
+                            .any(|v| *v == EntityStatus::Failed) =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    _ => None,
+                }
+            },
+        )
+        .await
+    }
+}
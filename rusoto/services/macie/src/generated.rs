@@ -58,10 +58,10 @@ pub struct AssociateS3ResourcesResult {
 pub struct ClassificationType {
     /// <p>A continuous classification of the objects that are added to a specified S3 bucket. Amazon Macie begins performing continuous classification after a bucket is successfully associated with Amazon Macie. </p>
     #[serde(rename = "continuous")]
-    pub continuous: String,
+    pub continuous: S3ContinuousClassificationType,
     /// <p>A one-time classification of all of the existing objects in a specified S3 bucket. </p>
     #[serde(rename = "oneTime")]
-    pub one_time: String,
+    pub one_time: S3OneTimeClassificationType,
 }
 
 /// <p>The classification type that Amazon Macie applies to the associated S3 resources. At least one of the classification types (oneTime or continuous) must be specified. </p>
@@ -71,11 +71,11 @@ pub struct ClassificationTypeUpdate {
     /// <p>A continuous classification of the objects that are added to a specified S3 bucket. Amazon Macie begins performing continuous classification after a bucket is successfully associated with Amazon Macie. </p>
     #[serde(rename = "continuous")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub continuous: Option<String>,
+    pub continuous: Option<S3ContinuousClassificationType>,
     /// <p>A one-time classification of all of the existing objects in a specified S3 bucket. </p>
     #[serde(rename = "oneTime")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub one_time: Option<String>,
+    pub one_time: Option<S3OneTimeClassificationType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -191,6 +191,187 @@ pub struct MemberAccount {
     pub account_id: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum S3ContinuousClassificationType {
+    /// `FULL`
+    Full,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl S3ContinuousClassificationType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            S3ContinuousClassificationType::Full => "FULL",
+            S3ContinuousClassificationType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for S3ContinuousClassificationType {
+    fn default() -> S3ContinuousClassificationType {
+        S3ContinuousClassificationType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for S3ContinuousClassificationType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for S3ContinuousClassificationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for S3ContinuousClassificationType {
+    fn from(value: &'a str) -> S3ContinuousClassificationType {
+        match value {
+            "FULL" => S3ContinuousClassificationType::Full,
+            _ => S3ContinuousClassificationType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for S3ContinuousClassificationType {
+    fn from(value: String) -> S3ContinuousClassificationType {
+        match value.as_str() {
+            "FULL" => S3ContinuousClassificationType::Full,
+            _ => S3ContinuousClassificationType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for S3ContinuousClassificationType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<S3ContinuousClassificationType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for S3ContinuousClassificationType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for S3ContinuousClassificationType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for S3ContinuousClassificationType {
+    fn deserialize<D>(deserializer: D) -> Result<S3ContinuousClassificationType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum S3OneTimeClassificationType {
+    /// `FULL`
+    Full,
+    /// `NONE`
+    None,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl S3OneTimeClassificationType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            S3OneTimeClassificationType::Full => "FULL",
+            S3OneTimeClassificationType::None => "NONE",
+            S3OneTimeClassificationType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for S3OneTimeClassificationType {
+    fn default() -> S3OneTimeClassificationType {
+        S3OneTimeClassificationType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for S3OneTimeClassificationType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for S3OneTimeClassificationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for S3OneTimeClassificationType {
+    fn from(value: &'a str) -> S3OneTimeClassificationType {
+        match value {
+            "FULL" => S3OneTimeClassificationType::Full,
+            "NONE" => S3OneTimeClassificationType::None,
+            _ => S3OneTimeClassificationType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for S3OneTimeClassificationType {
+    fn from(value: String) -> S3OneTimeClassificationType {
+        match value.as_str() {
+            "FULL" => S3OneTimeClassificationType::Full,
+            "NONE" => S3OneTimeClassificationType::None,
+            _ => S3OneTimeClassificationType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for S3OneTimeClassificationType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<S3OneTimeClassificationType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for S3OneTimeClassificationType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for S3OneTimeClassificationType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for S3OneTimeClassificationType {
+    fn deserialize<D>(deserializer: D) -> Result<S3OneTimeClassificationType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Contains information about the S3 resource. This data type is used as a request parameter in the DisassociateS3Resources action and can be used as a response parameter in the AssociateS3Resources and UpdateS3Resources actions. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct S3Resource {
@@ -606,11 +787,12 @@ pub struct MacieClient {
 impl MacieClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> MacieClient {
         MacieClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Macie"),
         }
     }
 
@@ -642,6 +824,7 @@ impl Macie for MacieClient {
         input: AssociateMemberAccountRequest,
     ) -> Result<(), RusotoError<AssociateMemberAccountError>> {
         let mut request = SignedRequest::new("POST", "macie", &self.region, "/");
+        request.set_operation("AssociateMemberAccount");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MacieService.AssociateMemberAccount");
@@ -669,6 +852,7 @@ impl Macie for MacieClient {
         input: AssociateS3ResourcesRequest,
     ) -> Result<AssociateS3ResourcesResult, RusotoError<AssociateS3ResourcesError>> {
         let mut request = SignedRequest::new("POST", "macie", &self.region, "/");
+        request.set_operation("AssociateS3Resources");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MacieService.AssociateS3Resources");
@@ -697,6 +881,7 @@ impl Macie for MacieClient {
         input: DisassociateMemberAccountRequest,
     ) -> Result<(), RusotoError<DisassociateMemberAccountError>> {
         let mut request = SignedRequest::new("POST", "macie", &self.region, "/");
+        request.set_operation("DisassociateMemberAccount");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MacieService.DisassociateMemberAccount");
@@ -724,6 +909,7 @@ impl Macie for MacieClient {
         input: DisassociateS3ResourcesRequest,
     ) -> Result<DisassociateS3ResourcesResult, RusotoError<DisassociateS3ResourcesError>> {
         let mut request = SignedRequest::new("POST", "macie", &self.region, "/");
+        request.set_operation("DisassociateS3Resources");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MacieService.DisassociateS3Resources");
@@ -752,6 +938,7 @@ impl Macie for MacieClient {
         input: ListMemberAccountsRequest,
     ) -> Result<ListMemberAccountsResult, RusotoError<ListMemberAccountsError>> {
         let mut request = SignedRequest::new("POST", "macie", &self.region, "/");
+        request.set_operation("ListMemberAccounts");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MacieService.ListMemberAccounts");
@@ -780,6 +967,7 @@ impl Macie for MacieClient {
         input: ListS3ResourcesRequest,
     ) -> Result<ListS3ResourcesResult, RusotoError<ListS3ResourcesError>> {
         let mut request = SignedRequest::new("POST", "macie", &self.region, "/");
+        request.set_operation("ListS3Resources");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MacieService.ListS3Resources");
@@ -807,6 +995,7 @@ impl Macie for MacieClient {
         input: UpdateS3ResourcesRequest,
     ) -> Result<UpdateS3ResourcesResult, RusotoError<UpdateS3ResourcesError>> {
         let mut request = SignedRequest::new("POST", "macie", &self.region, "/");
+        request.set_operation("UpdateS3Resources");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MacieService.UpdateS3Resources");
@@ -828,3 +1017,67 @@ impl Macie for MacieClient {
         }
     }
 }
+impl MacieClient {
+    /// Auto-paginating version of `list_member_accounts`, streaming every page of results.
+    pub fn list_member_accounts_pages(
+        &self,
+        input: ListMemberAccountsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListMemberAccountsResult, ListMemberAccountsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MacieClient, input: ListMemberAccountsRequest| async move {
+                client.list_member_accounts(input).await
+            },
+            |input: &ListMemberAccountsRequest, output: &ListMemberAccountsResult| {
+                Some(ListMemberAccountsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_member_accounts`, streaming the `memberAccounts` of every page of results.
+    pub fn list_member_accounts_items(
+        &self,
+        input: ListMemberAccountsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<MemberAccount, ListMemberAccountsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_member_accounts_pages(input),
+            |output: ListMemberAccountsResult| output.member_accounts.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_s3_resources`, streaming every page of results.
+    pub fn list_s3_resources_pages(
+        &self,
+        input: ListS3ResourcesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListS3ResourcesResult, ListS3ResourcesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MacieClient, input: ListS3ResourcesRequest| async move {
+                client.list_s3_resources(input).await
+            },
+            |input: &ListS3ResourcesRequest, output: &ListS3ResourcesResult| {
+                Some(ListS3ResourcesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_s3_resources`, streaming the `s3Resources` of every page of results.
+    pub fn list_s3_resources_items(
+        &self,
+        input: ListS3ResourcesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<S3ResourceClassification, ListS3ResourcesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_s3_resources_pages(input),
+            |output: ListS3ResourcesResult| output.s_3_resources.unwrap_or_default(),
+        )
+    }
+}
@@ -35,7 +35,7 @@ pub struct ApprovalThresholdPolicy {
     /// <p>Determines whether the vote percentage must be greater than the <code>ThresholdPercentage</code> or must be greater than or equal to the <code>ThreholdPercentage</code> to be approved.</p>
     #[serde(rename = "ThresholdComparator")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub threshold_comparator: Option<String>,
+    pub threshold_comparator: Option<ThresholdComparator>,
     /// <p>The percentage of votes among all members that must be <code>YES</code> for a proposal to be approved. For example, a <code>ThresholdPercentage</code> value of <code>50</code> indicates 50%. The <code>ThresholdComparator</code> determines the precise comparison. If a <code>ThresholdPercentage</code> value of <code>50</code> is specified on a network with 10 members, along with a <code>ThresholdComparator</code> value of <code>GREATER_THAN</code>, this indicates that 6 <code>YES</code> votes are required for the proposal to be approved.</p>
     #[serde(rename = "ThresholdPercentage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -80,7 +80,7 @@ pub struct CreateNetworkInput {
     pub description: Option<String>,
     /// <p>The blockchain framework that the network uses.</p>
     #[serde(rename = "Framework")]
-    pub framework: String,
+    pub framework: Framework,
     /// <p> Configuration properties of the blockchain framework relevant to the network configuration. </p>
     #[serde(rename = "FrameworkConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -201,6 +201,192 @@ pub struct DeleteNodeInput {
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteNodeOutput {}
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Edition {
+    /// `STARTER`
+    Starter,
+    /// `STANDARD`
+    Standard,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Edition {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Edition::Starter => "STARTER",
+            Edition::Standard => "STANDARD",
+            Edition::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Edition {
+    fn default() -> Edition {
+        Edition::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Edition {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Edition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Edition {
+    fn from(value: &'a str) -> Edition {
+        match value {
+            "STARTER" => Edition::Starter,
+            "STANDARD" => Edition::Standard,
+            _ => Edition::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Edition {
+    fn from(value: String) -> Edition {
+        match value.as_str() {
+            "STARTER" => Edition::Starter,
+            "STANDARD" => Edition::Standard,
+            _ => Edition::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Edition {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Edition, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Edition {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Edition {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Edition {
+    fn deserialize<D>(deserializer: D) -> Result<Edition, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Framework {
+    /// `HYPERLEDGER_FABRIC`
+    HyperledgerFabric,
+    /// `ETHEREUM`
+    Ethereum,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Framework {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Framework::HyperledgerFabric => "HYPERLEDGER_FABRIC",
+            Framework::Ethereum => "ETHEREUM",
+            Framework::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Framework {
+    fn default() -> Framework {
+        Framework::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Framework {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Framework {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Framework {
+    fn from(value: &'a str) -> Framework {
+        match value {
+            "HYPERLEDGER_FABRIC" => Framework::HyperledgerFabric,
+            "ETHEREUM" => Framework::Ethereum,
+            _ => Framework::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Framework {
+    fn from(value: String) -> Framework {
+        match value.as_str() {
+            "HYPERLEDGER_FABRIC" => Framework::HyperledgerFabric,
+            "ETHEREUM" => Framework::Ethereum,
+            _ => Framework::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Framework {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Framework, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Framework {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Framework {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Framework {
+    fn deserialize<D>(deserializer: D) -> Result<Framework, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct GetMemberInput {
@@ -287,12 +473,22 @@ pub struct GetProposalOutput {
 pub struct Invitation {
     /// <p>The date and time that the invitation was created.</p>
     #[serde(rename = "CreationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The date and time that the invitation expires. This is the <code>CreationDate</code> plus the <code>ProposalDurationInHours</code> that is specified in the <code>ProposalThresholdPolicy</code>. After this date and time, the invitee can no longer create a member and join the network using this <code>InvitationId</code>.</p>
     #[serde(rename = "ExpirationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expiration_date: Option<f64>,
+    pub expiration_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The unique identifier for the invitation.</p>
     #[serde(rename = "InvitationId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -303,9 +499,117 @@ pub struct Invitation {
     /// <p><p>The status of the invitation:</p> <ul> <li> <p> <code>PENDING</code> - The invitee has not created a member to join the network, and the invitation has not yet expired.</p> </li> <li> <p> <code>ACCEPTING</code> - The invitee has begun creating a member, and creation has not yet completed.</p> </li> <li> <p> <code>ACCEPTED</code> - The invitee created a member and joined the network using the <code>InvitationID</code>.</p> </li> <li> <p> <code>REJECTED</code> - The invitee rejected the invitation.</p> </li> <li> <p> <code>EXPIRED</code> - The invitee neither created a member nor rejected the invitation before the <code>ExpirationDate</code>.</p> </li> </ul></p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<InvitationStatus>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum InvitationStatus {
+    /// `PENDING`
+    Pending,
+    /// `ACCEPTED`
+    Accepted,
+    /// `ACCEPTING`
+    Accepting,
+    /// `REJECTED`
+    Rejected,
+    /// `EXPIRED`
+    Expired,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl InvitationStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InvitationStatus::Pending => "PENDING",
+            InvitationStatus::Accepted => "ACCEPTED",
+            InvitationStatus::Accepting => "ACCEPTING",
+            InvitationStatus::Rejected => "REJECTED",
+            InvitationStatus::Expired => "EXPIRED",
+            InvitationStatus::Unknown(ref value) => value,
+        }
+    }
 }
 
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for InvitationStatus {
+    fn default() -> InvitationStatus {
+        InvitationStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for InvitationStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for InvitationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for InvitationStatus {
+    fn from(value: &'a str) -> InvitationStatus {
+        match value {
+            "PENDING" => InvitationStatus::Pending,
+            "ACCEPTED" => InvitationStatus::Accepted,
+            "ACCEPTING" => InvitationStatus::Accepting,
+            "REJECTED" => InvitationStatus::Rejected,
+            "EXPIRED" => InvitationStatus::Expired,
+            _ => InvitationStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InvitationStatus {
+    fn from(value: String) -> InvitationStatus {
+        match value.as_str() {
+            "PENDING" => InvitationStatus::Pending,
+            "ACCEPTED" => InvitationStatus::Accepted,
+            "ACCEPTING" => InvitationStatus::Accepting,
+            "REJECTED" => InvitationStatus::Rejected,
+            "EXPIRED" => InvitationStatus::Expired,
+            _ => InvitationStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for InvitationStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<InvitationStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for InvitationStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for InvitationStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InvitationStatus {
+    fn deserialize<D>(deserializer: D) -> Result<InvitationStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An action to invite a specific AWS account to create a member and join the network. The <code>InviteAction</code> is carried out when a <code>Proposal</code> is <code>APPROVED</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct InviteAction {
@@ -365,7 +669,7 @@ pub struct ListMembersInput {
     /// <p>An optional status specifier. If provided, only members currently in this status are listed.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<MemberStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -387,7 +691,7 @@ pub struct ListNetworksInput {
     /// <p>An optional framework specifier. If provided, only networks of this framework type are listed.</p>
     #[serde(rename = "Framework")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub framework: Option<String>,
+    pub framework: Option<Framework>,
     /// <p>The maximum number of networks to list.</p>
     #[serde(rename = "MaxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -403,7 +707,7 @@ pub struct ListNetworksInput {
     /// <p>An optional status specifier. If provided, only networks currently in this status are listed.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<NetworkStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -439,7 +743,7 @@ pub struct ListNodesInput {
     /// <p>An optional status specifier. If provided, only nodes currently in this status are listed.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<NodeStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -522,8 +826,13 @@ pub struct ListProposalsOutput {
 pub struct Member {
     /// <p>The date and time that the member was created.</p>
     #[serde(rename = "CreationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>An optional description for the member.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -547,7 +856,7 @@ pub struct Member {
     /// <p><p>The status of a member.</p> <ul> <li> <p> <code>CREATING</code> - The AWS account is in the process of creating a member.</p> </li> <li> <p> <code>AVAILABLE</code> - The member has been created and can participate in the network.</p> </li> <li> <p> <code>CREATE_FAILED</code> - The AWS account attempted to create a member and creation failed.</p> </li> <li> <p> <code>DELETING</code> - The member and all associated resources are in the process of being deleted. Either the AWS account that owns the member deleted it, or the member is being deleted as the result of an <code>APPROVED</code> <code>PROPOSAL</code> to remove the member.</p> </li> <li> <p> <code>DELETED</code> - The member can no longer participate on the network and all associated resources are deleted. Either the AWS account that owns the member deleted it, or the member is being deleted as the result of an <code>APPROVED</code> <code>PROPOSAL</code> to remove the member.</p> </li> </ul></p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<MemberStatus>,
 }
 
 /// <p>Configuration properties of the member.</p>
@@ -612,14 +921,137 @@ pub struct MemberFrameworkConfiguration {
     pub fabric: Option<MemberFabricConfiguration>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MemberStatus {
+    /// `CREATING`
+    Creating,
+    /// `AVAILABLE`
+    Available,
+    /// `CREATE_FAILED`
+    CreateFailed,
+    /// `UPDATING`
+    Updating,
+    /// `DELETING`
+    Deleting,
+    /// `DELETED`
+    Deleted,
+    /// `INACCESSIBLE_ENCRYPTION_KEY`
+    InaccessibleEncryptionKey,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl MemberStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MemberStatus::Creating => "CREATING",
+            MemberStatus::Available => "AVAILABLE",
+            MemberStatus::CreateFailed => "CREATE_FAILED",
+            MemberStatus::Updating => "UPDATING",
+            MemberStatus::Deleting => "DELETING",
+            MemberStatus::Deleted => "DELETED",
+            MemberStatus::InaccessibleEncryptionKey => "INACCESSIBLE_ENCRYPTION_KEY",
+            MemberStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for MemberStatus {
+    fn default() -> MemberStatus {
+        MemberStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for MemberStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for MemberStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for MemberStatus {
+    fn from(value: &'a str) -> MemberStatus {
+        match value {
+            "CREATING" => MemberStatus::Creating,
+            "AVAILABLE" => MemberStatus::Available,
+            "CREATE_FAILED" => MemberStatus::CreateFailed,
+            "UPDATING" => MemberStatus::Updating,
+            "DELETING" => MemberStatus::Deleting,
+            "DELETED" => MemberStatus::Deleted,
+            "INACCESSIBLE_ENCRYPTION_KEY" => MemberStatus::InaccessibleEncryptionKey,
+            _ => MemberStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MemberStatus {
+    fn from(value: String) -> MemberStatus {
+        match value.as_str() {
+            "CREATING" => MemberStatus::Creating,
+            "AVAILABLE" => MemberStatus::Available,
+            "CREATE_FAILED" => MemberStatus::CreateFailed,
+            "UPDATING" => MemberStatus::Updating,
+            "DELETING" => MemberStatus::Deleting,
+            "DELETED" => MemberStatus::Deleted,
+            "INACCESSIBLE_ENCRYPTION_KEY" => MemberStatus::InaccessibleEncryptionKey,
+            _ => MemberStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for MemberStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<MemberStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MemberStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for MemberStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MemberStatus {
+    fn deserialize<D>(deserializer: D) -> Result<MemberStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A summary of configuration properties for a member.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct MemberSummary {
     /// <p>The date and time that the member was created.</p>
     #[serde(rename = "CreationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>An optional description of the member.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -639,7 +1071,7 @@ pub struct MemberSummary {
     /// <p><p>The status of the member.</p> <ul> <li> <p> <code>CREATING</code> - The AWS account is in the process of creating a member.</p> </li> <li> <p> <code>AVAILABLE</code> - The member has been created and can participate in the network.</p> </li> <li> <p> <code>CREATE_FAILED</code> - The AWS account attempted to create a member and creation failed.</p> </li> <li> <p> <code>DELETING</code> - The member and all associated resources are in the process of being deleted. Either the AWS account that owns the member deleted it, or the member is being deleted as the result of an <code>APPROVED</code> <code>PROPOSAL</code> to remove the member.</p> </li> <li> <p> <code>DELETED</code> - The member can no longer participate on the network and all associated resources are deleted. Either the AWS account that owns the member deleted it, or the member is being deleted as the result of an <code>APPROVED</code> <code>PROPOSAL</code> to remove the member.</p> </li> </ul></p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<MemberStatus>,
 }
 
 /// <p>Network configuration properties.</p>
@@ -648,8 +1080,13 @@ pub struct MemberSummary {
 pub struct Network {
     /// <p>The date and time that the network was created.</p>
     #[serde(rename = "CreationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Attributes of the blockchain framework for the network.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -657,7 +1094,7 @@ pub struct Network {
     /// <p>The blockchain framework that the network uses.</p>
     #[serde(rename = "Framework")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub framework: Option<String>,
+    pub framework: Option<Framework>,
     /// <p>Attributes of the blockchain framework that the network uses.</p>
     #[serde(rename = "FrameworkAttributes")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -677,7 +1114,7 @@ pub struct Network {
     /// <p>The current status of the network.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<NetworkStatus>,
     /// <p>The voting rules for the network to decide if a proposal is accepted.</p>
     #[serde(rename = "VotingPolicy")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -695,7 +1132,7 @@ pub struct NetworkFabricAttributes {
     /// <p>The edition of Amazon Managed Blockchain that Hyperledger Fabric uses. For more information, see <a href="https://aws.amazon.com/managed-blockchain/pricing/">Amazon Managed Blockchain Pricing</a>.</p>
     #[serde(rename = "Edition")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub edition: Option<String>,
+    pub edition: Option<Edition>,
     /// <p>The endpoint of the ordering service for the network.</p>
     #[serde(rename = "OrderingServiceEndpoint")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -708,7 +1145,7 @@ pub struct NetworkFabricAttributes {
 pub struct NetworkFabricConfiguration {
     /// <p>The edition of Amazon Managed Blockchain that the network uses. For more information, see <a href="https://aws.amazon.com/managed-blockchain/pricing/">Amazon Managed Blockchain Pricing</a>.</p>
     #[serde(rename = "Edition")]
-    pub edition: String,
+    pub edition: Edition,
 }
 
 /// <p>Attributes relevant to the network for the blockchain framework that the network uses.</p>
@@ -731,14 +1168,127 @@ pub struct NetworkFrameworkConfiguration {
     pub fabric: Option<NetworkFabricConfiguration>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum NetworkStatus {
+    /// `CREATING`
+    Creating,
+    /// `AVAILABLE`
+    Available,
+    /// `CREATE_FAILED`
+    CreateFailed,
+    /// `DELETING`
+    Deleting,
+    /// `DELETED`
+    Deleted,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl NetworkStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            NetworkStatus::Creating => "CREATING",
+            NetworkStatus::Available => "AVAILABLE",
+            NetworkStatus::CreateFailed => "CREATE_FAILED",
+            NetworkStatus::Deleting => "DELETING",
+            NetworkStatus::Deleted => "DELETED",
+            NetworkStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for NetworkStatus {
+    fn default() -> NetworkStatus {
+        NetworkStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for NetworkStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for NetworkStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for NetworkStatus {
+    fn from(value: &'a str) -> NetworkStatus {
+        match value {
+            "CREATING" => NetworkStatus::Creating,
+            "AVAILABLE" => NetworkStatus::Available,
+            "CREATE_FAILED" => NetworkStatus::CreateFailed,
+            "DELETING" => NetworkStatus::Deleting,
+            "DELETED" => NetworkStatus::Deleted,
+            _ => NetworkStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for NetworkStatus {
+    fn from(value: String) -> NetworkStatus {
+        match value.as_str() {
+            "CREATING" => NetworkStatus::Creating,
+            "AVAILABLE" => NetworkStatus::Available,
+            "CREATE_FAILED" => NetworkStatus::CreateFailed,
+            "DELETING" => NetworkStatus::Deleting,
+            "DELETED" => NetworkStatus::Deleted,
+            _ => NetworkStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for NetworkStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<NetworkStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for NetworkStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for NetworkStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for NetworkStatus {
+    fn deserialize<D>(deserializer: D) -> Result<NetworkStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A summary of network configuration properties.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct NetworkSummary {
     /// <p>The date and time that the network was created.</p>
     #[serde(rename = "CreationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>An optional description of the network.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -746,7 +1296,7 @@ pub struct NetworkSummary {
     /// <p>The blockchain framework that the network uses.</p>
     #[serde(rename = "Framework")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub framework: Option<String>,
+    pub framework: Option<Framework>,
     /// <p>The version of the blockchain framework that the network uses.</p>
     #[serde(rename = "FrameworkVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -762,7 +1312,7 @@ pub struct NetworkSummary {
     /// <p>The current status of the network.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<NetworkStatus>,
 }
 
 /// <p>Configuration properties of a peer node.</p>
@@ -775,8 +1325,13 @@ pub struct Node {
     pub availability_zone: Option<String>,
     /// <p>The date and time that the node was created.</p>
     #[serde(rename = "CreationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Attributes of the blockchain framework being used.</p>
     #[serde(rename = "FrameworkAttributes")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -800,7 +1355,7 @@ pub struct Node {
     /// <p>The status of the node.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<NodeStatus>,
 }
 
 /// <p>Configuration properties of a peer node.</p>
@@ -839,6 +1394,134 @@ pub struct NodeFrameworkAttributes {
     pub fabric: Option<NodeFabricAttributes>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum NodeStatus {
+    /// `CREATING`
+    Creating,
+    /// `AVAILABLE`
+    Available,
+    /// `UNHEALTHY`
+    Unhealthy,
+    /// `CREATE_FAILED`
+    CreateFailed,
+    /// `UPDATING`
+    Updating,
+    /// `DELETING`
+    Deleting,
+    /// `DELETED`
+    Deleted,
+    /// `FAILED`
+    Failed,
+    /// `INACCESSIBLE_ENCRYPTION_KEY`
+    InaccessibleEncryptionKey,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl NodeStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            NodeStatus::Creating => "CREATING",
+            NodeStatus::Available => "AVAILABLE",
+            NodeStatus::Unhealthy => "UNHEALTHY",
+            NodeStatus::CreateFailed => "CREATE_FAILED",
+            NodeStatus::Updating => "UPDATING",
+            NodeStatus::Deleting => "DELETING",
+            NodeStatus::Deleted => "DELETED",
+            NodeStatus::Failed => "FAILED",
+            NodeStatus::InaccessibleEncryptionKey => "INACCESSIBLE_ENCRYPTION_KEY",
+            NodeStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for NodeStatus {
+    fn default() -> NodeStatus {
+        NodeStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for NodeStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for NodeStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for NodeStatus {
+    fn from(value: &'a str) -> NodeStatus {
+        match value {
+            "CREATING" => NodeStatus::Creating,
+            "AVAILABLE" => NodeStatus::Available,
+            "UNHEALTHY" => NodeStatus::Unhealthy,
+            "CREATE_FAILED" => NodeStatus::CreateFailed,
+            "UPDATING" => NodeStatus::Updating,
+            "DELETING" => NodeStatus::Deleting,
+            "DELETED" => NodeStatus::Deleted,
+            "FAILED" => NodeStatus::Failed,
+            "INACCESSIBLE_ENCRYPTION_KEY" => NodeStatus::InaccessibleEncryptionKey,
+            _ => NodeStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for NodeStatus {
+    fn from(value: String) -> NodeStatus {
+        match value.as_str() {
+            "CREATING" => NodeStatus::Creating,
+            "AVAILABLE" => NodeStatus::Available,
+            "UNHEALTHY" => NodeStatus::Unhealthy,
+            "CREATE_FAILED" => NodeStatus::CreateFailed,
+            "UPDATING" => NodeStatus::Updating,
+            "DELETING" => NodeStatus::Deleting,
+            "DELETED" => NodeStatus::Deleted,
+            "FAILED" => NodeStatus::Failed,
+            "INACCESSIBLE_ENCRYPTION_KEY" => NodeStatus::InaccessibleEncryptionKey,
+            _ => NodeStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for NodeStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<NodeStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for NodeStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for NodeStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for NodeStatus {
+    fn deserialize<D>(deserializer: D) -> Result<NodeStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A summary of configuration properties for a peer node.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -849,8 +1532,13 @@ pub struct NodeSummary {
     pub availability_zone: Option<String>,
     /// <p>The date and time that the node was created.</p>
     #[serde(rename = "CreationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The unique identifier of the node.</p>
     #[serde(rename = "Id")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -862,7 +1550,7 @@ pub struct NodeSummary {
     /// <p>The status of the node.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<NodeStatus>,
 }
 
 /// <p>Properties of a proposal on a Managed Blockchain network.</p>
@@ -875,16 +1563,26 @@ pub struct Proposal {
     pub actions: Option<ProposalActions>,
     /// <p> The date and time that the proposal was created. </p>
     #[serde(rename = "CreationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The description of the proposal.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     /// <p> The date and time that the proposal expires. This is the <code>CreationDate</code> plus the <code>ProposalDurationInHours</code> that is specified in the <code>ProposalThresholdPolicy</code>. After this date and time, if members have not cast enough votes to determine the outcome according to the voting policy, the proposal is <code>EXPIRED</code> and <code>Actions</code> are not carried out. </p>
     #[serde(rename = "ExpirationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expiration_date: Option<f64>,
+    pub expiration_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The unique identifier of the network for which the proposal is made.</p>
     #[serde(rename = "NetworkId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -912,7 +1610,7 @@ pub struct Proposal {
     /// <p><p>The status of the proposal. Values are as follows:</p> <ul> <li> <p> <code>IN<em>PROGRESS</code> - The proposal is active and open for member voting.</p> </li> <li> <p> <code>APPROVED</code> - The proposal was approved with sufficient <code>YES</code> votes among members according to the <code>VotingPolicy</code> specified for the <code>Network</code>. The specified proposal actions are carried out.</p> </li> <li> <p> <code>REJECTED</code> - The proposal was rejected with insufficient <code>YES</code> votes among members according to the <code>VotingPolicy</code> specified for the <code>Network</code>. The specified <code>ProposalActions</code> are not carried out.</p> </li> <li> <p> <code>EXPIRED</code> - Members did not cast the number of votes required to determine the proposal outcome before the proposal expired. The specified <code>ProposalActions</code> are not carried out.</p> </li> <li> <p> <code>ACTION</em>FAILED</code> - One or more of the specified <code>ProposalActions</code> in a proposal that was approved could not be completed because of an error.</p> </li> </ul></p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ProposalStatus>,
     /// <p> The current total of <code>YES</code> votes cast on the proposal by members. </p>
     #[serde(rename = "YesVoteCount")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -932,22 +1630,140 @@ pub struct ProposalActions {
     pub removals: Option<Vec<RemoveAction>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ProposalStatus {
+    /// `IN_PROGRESS`
+    InProgress,
+    /// `APPROVED`
+    Approved,
+    /// `REJECTED`
+    Rejected,
+    /// `EXPIRED`
+    Expired,
+    /// `ACTION_FAILED`
+    ActionFailed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ProposalStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ProposalStatus::InProgress => "IN_PROGRESS",
+            ProposalStatus::Approved => "APPROVED",
+            ProposalStatus::Rejected => "REJECTED",
+            ProposalStatus::Expired => "EXPIRED",
+            ProposalStatus::ActionFailed => "ACTION_FAILED",
+            ProposalStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ProposalStatus {
+    fn default() -> ProposalStatus {
+        ProposalStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ProposalStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ProposalStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ProposalStatus {
+    fn from(value: &'a str) -> ProposalStatus {
+        match value {
+            "IN_PROGRESS" => ProposalStatus::InProgress,
+            "APPROVED" => ProposalStatus::Approved,
+            "REJECTED" => ProposalStatus::Rejected,
+            "EXPIRED" => ProposalStatus::Expired,
+            "ACTION_FAILED" => ProposalStatus::ActionFailed,
+            _ => ProposalStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ProposalStatus {
+    fn from(value: String) -> ProposalStatus {
+        match value.as_str() {
+            "IN_PROGRESS" => ProposalStatus::InProgress,
+            "APPROVED" => ProposalStatus::Approved,
+            "REJECTED" => ProposalStatus::Rejected,
+            "EXPIRED" => ProposalStatus::Expired,
+            "ACTION_FAILED" => ProposalStatus::ActionFailed,
+            _ => ProposalStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ProposalStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ProposalStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ProposalStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ProposalStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ProposalStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ProposalStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Properties of a proposal.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ProposalSummary {
     /// <p> The date and time that the proposal was created. </p>
     #[serde(rename = "CreationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p> The description of the proposal. </p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     /// <p> The date and time that the proposal expires. This is the <code>CreationDate</code> plus the <code>ProposalDurationInHours</code> that is specified in the <code>ProposalThresholdPolicy</code>. After this date and time, if members have not cast enough votes to determine the outcome according to the voting policy, the proposal is <code>EXPIRED</code> and <code>Actions</code> are not carried out. </p>
     #[serde(rename = "ExpirationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expiration_date: Option<f64>,
+    pub expiration_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p> The unique identifier of the proposal. </p>
     #[serde(rename = "ProposalId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -963,7 +1779,7 @@ pub struct ProposalSummary {
     /// <p><p>The status of the proposal. Values are as follows:</p> <ul> <li> <p> <code>IN<em>PROGRESS</code> - The proposal is active and open for member voting.</p> </li> <li> <p> <code>APPROVED</code> - The proposal was approved with sufficient <code>YES</code> votes among members according to the <code>VotingPolicy</code> specified for the <code>Network</code>. The specified proposal actions are carried out.</p> </li> <li> <p> <code>REJECTED</code> - The proposal was rejected with insufficient <code>YES</code> votes among members according to the <code>VotingPolicy</code> specified for the <code>Network</code>. The specified <code>ProposalActions</code> are not carried out.</p> </li> <li> <p> <code>EXPIRED</code> - Members did not cast the number of votes required to determine the proposal outcome before the proposal expired. The specified <code>ProposalActions</code> are not carried out.</p> </li> <li> <p> <code>ACTION</em>FAILED</code> - One or more of the specified <code>ProposalActions</code> in a proposal that was approved could not be completed because of an error.</p> </li> </ul></p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ProposalStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -986,6 +1802,99 @@ pub struct RemoveAction {
     pub member_id: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ThresholdComparator {
+    /// `GREATER_THAN`
+    GreaterThan,
+    /// `GREATER_THAN_OR_EQUAL_TO`
+    GreaterThanOrEqualTo,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ThresholdComparator {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ThresholdComparator::GreaterThan => "GREATER_THAN",
+            ThresholdComparator::GreaterThanOrEqualTo => "GREATER_THAN_OR_EQUAL_TO",
+            ThresholdComparator::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ThresholdComparator {
+    fn default() -> ThresholdComparator {
+        ThresholdComparator::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ThresholdComparator {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ThresholdComparator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ThresholdComparator {
+    fn from(value: &'a str) -> ThresholdComparator {
+        match value {
+            "GREATER_THAN" => ThresholdComparator::GreaterThan,
+            "GREATER_THAN_OR_EQUAL_TO" => ThresholdComparator::GreaterThanOrEqualTo,
+            _ => ThresholdComparator::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ThresholdComparator {
+    fn from(value: String) -> ThresholdComparator {
+        match value.as_str() {
+            "GREATER_THAN" => ThresholdComparator::GreaterThan,
+            "GREATER_THAN_OR_EQUAL_TO" => ThresholdComparator::GreaterThanOrEqualTo,
+            _ => ThresholdComparator::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ThresholdComparator {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ThresholdComparator, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ThresholdComparator {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ThresholdComparator {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ThresholdComparator {
+    fn deserialize<D>(deserializer: D) -> Result<ThresholdComparator, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct VoteOnProposalInput {
@@ -997,7 +1906,7 @@ pub struct VoteOnProposalInput {
     pub proposal_id: String,
     /// <p> The value of the vote. </p>
     #[serde(rename = "Vote")]
-    pub vote: String,
+    pub vote: VoteValue,
     /// <p>The unique identifier of the member casting the vote. </p>
     #[serde(rename = "VoterMemberId")]
     pub voter_member_id: String,
@@ -1022,9 +1931,102 @@ pub struct VoteSummary {
     /// <p> The vote value, either <code>YES</code> or <code>NO</code>. </p>
     #[serde(rename = "Vote")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub vote: Option<String>,
+    pub vote: Option<VoteValue>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum VoteValue {
+    /// `YES`
+    Yes,
+    /// `NO`
+    No,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl VoteValue {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            VoteValue::Yes => "YES",
+            VoteValue::No => "NO",
+            VoteValue::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for VoteValue {
+    fn default() -> VoteValue {
+        VoteValue::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for VoteValue {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for VoteValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for VoteValue {
+    fn from(value: &'a str) -> VoteValue {
+        match value {
+            "YES" => VoteValue::Yes,
+            "NO" => VoteValue::No,
+            _ => VoteValue::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for VoteValue {
+    fn from(value: String) -> VoteValue {
+        match value.as_str() {
+            "YES" => VoteValue::Yes,
+            "NO" => VoteValue::No,
+            _ => VoteValue::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for VoteValue {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<VoteValue, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for VoteValue {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for VoteValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for VoteValue {
+    fn deserialize<D>(deserializer: D) -> Result<VoteValue, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p> The voting rules for the network to decide if a proposal is accepted </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct VotingPolicy {
@@ -2189,11 +3191,12 @@ pub struct ManagedBlockchainClient {
 impl ManagedBlockchainClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> ManagedBlockchainClient {
         ManagedBlockchainClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "ManagedBlockchain"),
         }
     }
 
@@ -2231,6 +3234,7 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("POST", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("CreateMember");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2262,6 +3266,7 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("POST", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("CreateNetwork");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2297,6 +3302,7 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("POST", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("CreateNode");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2331,6 +3337,7 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("POST", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("CreateProposal");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2366,6 +3373,7 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("DELETE", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("DeleteMember");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2399,6 +3407,7 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("DELETE", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("DeleteNode");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2431,6 +3440,7 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("GET", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("GetMember");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2459,6 +3469,7 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("GET", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("GetNetwork");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2492,6 +3503,7 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("GET", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("GetNode");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2524,6 +3536,7 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("GET", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("GetProposal");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2552,6 +3565,7 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("GET", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("ListInvitations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2592,6 +3606,7 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("GET", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("ListMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2638,6 +3653,7 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("GET", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("ListNetworks");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2688,6 +3704,7 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("GET", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("ListNodes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2732,6 +3749,7 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("GET", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("ListProposalVotes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2772,6 +3790,7 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("GET", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("ListProposals");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2812,6 +3831,7 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("DELETE", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("RejectInvitation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2844,6 +3864,7 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("POST", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("VoteOnProposal");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -95,9 +95,117 @@ pub struct ChangeSetSummaryListItem {
     /// <p>The current status of the change set.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ChangeStatus>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ChangeStatus {
+    /// `PREPARING`
+    Preparing,
+    /// `APPLYING`
+    Applying,
+    /// `SUCCEEDED`
+    Succeeded,
+    /// `CANCELLED`
+    Cancelled,
+    /// `FAILED`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ChangeStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ChangeStatus::Preparing => "PREPARING",
+            ChangeStatus::Applying => "APPLYING",
+            ChangeStatus::Succeeded => "SUCCEEDED",
+            ChangeStatus::Cancelled => "CANCELLED",
+            ChangeStatus::Failed => "FAILED",
+            ChangeStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ChangeStatus {
+    fn default() -> ChangeStatus {
+        ChangeStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ChangeStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ChangeStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ChangeStatus {
+    fn from(value: &'a str) -> ChangeStatus {
+        match value {
+            "PREPARING" => ChangeStatus::Preparing,
+            "APPLYING" => ChangeStatus::Applying,
+            "SUCCEEDED" => ChangeStatus::Succeeded,
+            "CANCELLED" => ChangeStatus::Cancelled,
+            "FAILED" => ChangeStatus::Failed,
+            _ => ChangeStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ChangeStatus {
+    fn from(value: String) -> ChangeStatus {
+        match value.as_str() {
+            "PREPARING" => ChangeStatus::Preparing,
+            "APPLYING" => ChangeStatus::Applying,
+            "SUCCEEDED" => ChangeStatus::Succeeded,
+            "CANCELLED" => ChangeStatus::Cancelled,
+            "FAILED" => ChangeStatus::Failed,
+            _ => ChangeStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ChangeStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ChangeStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ChangeStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ChangeStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ChangeStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ChangeStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>This object is a container for common summary information about the change. The summary doesn't contain the whole change structure.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -161,7 +269,7 @@ pub struct DescribeChangeSetResponse {
     /// <p>The status of the change request.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ChangeStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -358,9 +466,102 @@ pub struct Sort {
     /// <p>The sorting order. Can be <code>ASCENDING</code> or <code>DESCENDING</code>. The default value is <code>DESCENDING</code>.</p>
     #[serde(rename = "SortOrder")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort_order: Option<String>,
+    pub sort_order: Option<SortOrder>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SortOrder {
+    /// `ASCENDING`
+    Ascending,
+    /// `DESCENDING`
+    Descending,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SortOrder {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SortOrder::Ascending => "ASCENDING",
+            SortOrder::Descending => "DESCENDING",
+            SortOrder::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SortOrder {
+    fn default() -> SortOrder {
+        SortOrder::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SortOrder {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SortOrder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SortOrder {
+    fn from(value: &'a str) -> SortOrder {
+        match value {
+            "ASCENDING" => SortOrder::Ascending,
+            "DESCENDING" => SortOrder::Descending,
+            _ => SortOrder::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SortOrder {
+    fn from(value: String) -> SortOrder {
+        match value.as_str() {
+            "ASCENDING" => SortOrder::Ascending,
+            "DESCENDING" => SortOrder::Descending,
+            _ => SortOrder::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SortOrder {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SortOrder, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SortOrder {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SortOrder {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for SortOrder {
+    fn deserialize<D>(deserializer: D) -> Result<SortOrder, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct StartChangeSetRequest {
@@ -748,11 +949,12 @@ pub struct MarketplaceCatalogClient {
 impl MarketplaceCatalogClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> MarketplaceCatalogClient {
         MarketplaceCatalogClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Marketplace Catalog"),
         }
     }
 
@@ -787,6 +989,7 @@ impl MarketplaceCatalog for MarketplaceCatalogClient {
 
         let mut request =
             SignedRequest::new("PATCH", "aws-marketplace", &self.region, &request_uri);
+        request.set_operation("CancelChangeSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("catalog.marketplace".to_string());
@@ -821,6 +1024,7 @@ impl MarketplaceCatalog for MarketplaceCatalogClient {
         let request_uri = "/DescribeChangeSet";
 
         let mut request = SignedRequest::new("GET", "aws-marketplace", &self.region, &request_uri);
+        request.set_operation("DescribeChangeSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("catalog.marketplace".to_string());
@@ -855,6 +1059,7 @@ impl MarketplaceCatalog for MarketplaceCatalogClient {
         let request_uri = "/DescribeEntity";
 
         let mut request = SignedRequest::new("GET", "aws-marketplace", &self.region, &request_uri);
+        request.set_operation("DescribeEntity");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("catalog.marketplace".to_string());
@@ -889,6 +1094,7 @@ impl MarketplaceCatalog for MarketplaceCatalogClient {
         let request_uri = "/ListChangeSets";
 
         let mut request = SignedRequest::new("POST", "aws-marketplace", &self.region, &request_uri);
+        request.set_operation("ListChangeSets");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("catalog.marketplace".to_string());
@@ -920,6 +1126,7 @@ impl MarketplaceCatalog for MarketplaceCatalogClient {
         let request_uri = "/ListEntities";
 
         let mut request = SignedRequest::new("POST", "aws-marketplace", &self.region, &request_uri);
+        request.set_operation("ListEntities");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("catalog.marketplace".to_string());
@@ -951,6 +1158,7 @@ impl MarketplaceCatalog for MarketplaceCatalogClient {
         let request_uri = "/StartChangeSet";
 
         let mut request = SignedRequest::new("POST", "aws-marketplace", &self.region, &request_uri);
+        request.set_operation("StartChangeSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("catalog.marketplace".to_string());
@@ -974,3 +1182,66 @@ impl MarketplaceCatalog for MarketplaceCatalogClient {
         }
     }
 }
+impl MarketplaceCatalogClient {
+    /// Auto-paginating version of `list_change_sets`, streaming every page of results.
+    pub fn list_change_sets_pages(
+        &self,
+        input: ListChangeSetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListChangeSetsResponse, ListChangeSetsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MarketplaceCatalogClient, input: ListChangeSetsRequest| async move {
+                client.list_change_sets(input).await
+            },
+            |input: &ListChangeSetsRequest, output: &ListChangeSetsResponse| {
+                Some(ListChangeSetsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_change_sets`, streaming the `ChangeSetSummaryList` of every page of results.
+    pub fn list_change_sets_items(
+        &self,
+        input: ListChangeSetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ChangeSetSummaryListItem, ListChangeSetsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_change_sets_pages(input),
+            |output: ListChangeSetsResponse| output.change_set_summary_list.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_entities`, streaming every page of results.
+    pub fn list_entities_pages(
+        &self,
+        input: ListEntitiesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListEntitiesResponse, ListEntitiesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MarketplaceCatalogClient, input: ListEntitiesRequest| async move {
+                client.list_entities(input).await
+            },
+            |input: &ListEntitiesRequest, output: &ListEntitiesResponse| {
+                Some(ListEntitiesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_entities`, streaming the `EntitySummaryList` of every page of results.
+    pub fn list_entities_items(
+        &self,
+        input: ListEntitiesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<EntitySummary, ListEntitiesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_entities_pages(input),
+            |output: ListEntitiesResponse| output.entity_summary_list.unwrap_or_default(),
+        )
+    }
+}
@@ -38,8 +38,13 @@ pub struct Entitlement {
     pub dimension: Option<String>,
     /// <p>The expiration date represents the minimum date through which this entitlement is expected to remain valid. For contractual products listed on AWS Marketplace, the expiration date is the date at which the customer will renew or cancel their contract. Customers who are opting to renew their contract will still have entitlements with an expiration date.</p>
     #[serde(rename = "ExpirationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expiration_date: Option<f64>,
+    pub expiration_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The product code for which the given entitlement applies. Product codes are provided by AWS Marketplace when the product listing is created.</p>
     #[serde(rename = "ProductCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -72,6 +77,99 @@ pub struct EntitlementValue {
     pub string_value: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum GetEntitlementFilterName {
+    /// `CUSTOMER_IDENTIFIER`
+    CustomerIdentifier,
+    /// `DIMENSION`
+    Dimension,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl GetEntitlementFilterName {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            GetEntitlementFilterName::CustomerIdentifier => "CUSTOMER_IDENTIFIER",
+            GetEntitlementFilterName::Dimension => "DIMENSION",
+            GetEntitlementFilterName::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for GetEntitlementFilterName {
+    fn default() -> GetEntitlementFilterName {
+        GetEntitlementFilterName::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for GetEntitlementFilterName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for GetEntitlementFilterName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for GetEntitlementFilterName {
+    fn from(value: &'a str) -> GetEntitlementFilterName {
+        match value {
+            "CUSTOMER_IDENTIFIER" => GetEntitlementFilterName::CustomerIdentifier,
+            "DIMENSION" => GetEntitlementFilterName::Dimension,
+            _ => GetEntitlementFilterName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for GetEntitlementFilterName {
+    fn from(value: String) -> GetEntitlementFilterName {
+        match value.as_str() {
+            "CUSTOMER_IDENTIFIER" => GetEntitlementFilterName::CustomerIdentifier,
+            "DIMENSION" => GetEntitlementFilterName::Dimension,
+            _ => GetEntitlementFilterName::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for GetEntitlementFilterName {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<GetEntitlementFilterName, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for GetEntitlementFilterName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for GetEntitlementFilterName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for GetEntitlementFilterName {
+    fn deserialize<D>(deserializer: D) -> Result<GetEntitlementFilterName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The GetEntitlementsRequest contains parameters for the GetEntitlements operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -79,7 +177,7 @@ pub struct GetEntitlementsRequest {
     /// <p>Filter is used to return entitlements for a specific customer or for a specific dimension. Filters are described as keys mapped to a lists of values. Filtered requests are <i>unioned</i> for each value in the value list, and then <i>intersected</i> for each filter key.</p>
     #[serde(rename = "Filter")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub filter: Option<::std::collections::HashMap<String, Vec<String>>>,
+    pub filter: Option<::std::collections::HashMap<GetEntitlementFilterName, Vec<String>>>,
     /// <p>The maximum number of items to retrieve from the GetEntitlements operation. For pagination, use the NextToken field in subsequent calls to GetEntitlements.</p>
     #[serde(rename = "MaxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -170,11 +268,15 @@ pub struct MarketplaceEntitlementClient {
 impl MarketplaceEntitlementClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> MarketplaceEntitlementClient {
         MarketplaceEntitlementClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(
+                region,
+                "Marketplace Entitlement Service",
+            ),
         }
     }
 
@@ -206,6 +308,7 @@ impl MarketplaceEntitlement for MarketplaceEntitlementClient {
         input: GetEntitlementsRequest,
     ) -> Result<GetEntitlementsResult, RusotoError<GetEntitlementsError>> {
         let mut request = SignedRequest::new("POST", "aws-marketplace", &self.region, "/");
+        request.set_operation("GetEntitlements");
         request.set_endpoint_prefix("entitlement.marketplace".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSMPEntitlementService.GetEntitlements");
@@ -227,3 +330,35 @@ impl MarketplaceEntitlement for MarketplaceEntitlementClient {
         }
     }
 }
+impl MarketplaceEntitlementClient {
+    /// Auto-paginating version of `get_entitlements`, streaming every page of results.
+    pub fn get_entitlements_pages(
+        &self,
+        input: GetEntitlementsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetEntitlementsResult, GetEntitlementsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MarketplaceEntitlementClient, input: GetEntitlementsRequest| async move {
+                client.get_entitlements(input).await
+            },
+            |input: &GetEntitlementsRequest, output: &GetEntitlementsResult| {
+                Some(GetEntitlementsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_entitlements`, streaming the `Entitlements` of every page of results.
+    pub fn get_entitlements_items(
+        &self,
+        input: GetEntitlementsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Entitlement, GetEntitlementsError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_entitlements_pages(input),
+            |output: GetEntitlementsResult| output.entitlements.unwrap_or_default(),
+        )
+    }
+}
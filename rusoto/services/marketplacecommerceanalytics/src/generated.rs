@@ -24,6 +24,304 @@ use rusoto_core::signature::SignedRequest;
 #[allow(unused_imports)]
 use serde::{Deserialize, Serialize};
 use serde_json;
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DataSetType {
+    /// `customer_subscriber_hourly_monthly_subscriptions`
+    CustomerSubscriberHourlyMonthlySubscriptions,
+    /// `customer_subscriber_annual_subscriptions`
+    CustomerSubscriberAnnualSubscriptions,
+    /// `daily_business_usage_by_instance_type`
+    DailyBusinessUsageByInstanceType,
+    /// `daily_business_fees`
+    DailyBusinessFees,
+    /// `daily_business_free_trial_conversions`
+    DailyBusinessFreeTrialConversions,
+    /// `daily_business_new_instances`
+    DailyBusinessNewInstances,
+    /// `daily_business_new_product_subscribers`
+    DailyBusinessNewProductSubscribers,
+    /// `daily_business_canceled_product_subscribers`
+    DailyBusinessCanceledProductSubscribers,
+    /// `monthly_revenue_billing_and_revenue_data`
+    MonthlyRevenueBillingAndRevenueData,
+    /// `monthly_revenue_annual_subscriptions`
+    MonthlyRevenueAnnualSubscriptions,
+    /// `monthly_revenue_field_demonstration_usage`
+    MonthlyRevenueFieldDemonstrationUsage,
+    /// `monthly_revenue_flexible_payment_schedule`
+    MonthlyRevenueFlexiblePaymentSchedule,
+    /// `disbursed_amount_by_product`
+    DisbursedAmountByProduct,
+    /// `disbursed_amount_by_product_with_uncollected_funds`
+    DisbursedAmountByProductWithUncollectedFunds,
+    /// `disbursed_amount_by_instance_hours`
+    DisbursedAmountByInstanceHours,
+    /// `disbursed_amount_by_customer_geo`
+    DisbursedAmountByCustomerGeo,
+    /// `disbursed_amount_by_age_of_uncollected_funds`
+    DisbursedAmountByAgeOfUncollectedFunds,
+    /// `disbursed_amount_by_age_of_disbursed_funds`
+    DisbursedAmountByAgeOfDisbursedFunds,
+    /// `disbursed_amount_by_age_of_past_due_funds`
+    DisbursedAmountByAgeOfPastDueFunds,
+    /// `disbursed_amount_by_uncollected_funds_breakdown`
+    DisbursedAmountByUncollectedFundsBreakdown,
+    /// `customer_profile_by_industry`
+    CustomerProfileByIndustry,
+    /// `customer_profile_by_revenue`
+    CustomerProfileByRevenue,
+    /// `customer_profile_by_geography`
+    CustomerProfileByGeography,
+    /// `sales_compensation_billed_revenue`
+    SalesCompensationBilledRevenue,
+    /// `us_sales_and_use_tax_records`
+    UsSalesAndUseTaxRecords,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DataSetType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DataSetType::CustomerSubscriberHourlyMonthlySubscriptions => {
+                "customer_subscriber_hourly_monthly_subscriptions"
+            }
+            DataSetType::CustomerSubscriberAnnualSubscriptions => {
+                "customer_subscriber_annual_subscriptions"
+            }
+            DataSetType::DailyBusinessUsageByInstanceType => {
+                "daily_business_usage_by_instance_type"
+            }
+            DataSetType::DailyBusinessFees => "daily_business_fees",
+            DataSetType::DailyBusinessFreeTrialConversions => {
+                "daily_business_free_trial_conversions"
+            }
+            DataSetType::DailyBusinessNewInstances => "daily_business_new_instances",
+            DataSetType::DailyBusinessNewProductSubscribers => {
+                "daily_business_new_product_subscribers"
+            }
+            DataSetType::DailyBusinessCanceledProductSubscribers => {
+                "daily_business_canceled_product_subscribers"
+            }
+            DataSetType::MonthlyRevenueBillingAndRevenueData => {
+                "monthly_revenue_billing_and_revenue_data"
+            }
+            DataSetType::MonthlyRevenueAnnualSubscriptions => {
+                "monthly_revenue_annual_subscriptions"
+            }
+            DataSetType::MonthlyRevenueFieldDemonstrationUsage => {
+                "monthly_revenue_field_demonstration_usage"
+            }
+            DataSetType::MonthlyRevenueFlexiblePaymentSchedule => {
+                "monthly_revenue_flexible_payment_schedule"
+            }
+            DataSetType::DisbursedAmountByProduct => "disbursed_amount_by_product",
+            DataSetType::DisbursedAmountByProductWithUncollectedFunds => {
+                "disbursed_amount_by_product_with_uncollected_funds"
+            }
+            DataSetType::DisbursedAmountByInstanceHours => "disbursed_amount_by_instance_hours",
+            DataSetType::DisbursedAmountByCustomerGeo => "disbursed_amount_by_customer_geo",
+            DataSetType::DisbursedAmountByAgeOfUncollectedFunds => {
+                "disbursed_amount_by_age_of_uncollected_funds"
+            }
+            DataSetType::DisbursedAmountByAgeOfDisbursedFunds => {
+                "disbursed_amount_by_age_of_disbursed_funds"
+            }
+            DataSetType::DisbursedAmountByAgeOfPastDueFunds => {
+                "disbursed_amount_by_age_of_past_due_funds"
+            }
+            DataSetType::DisbursedAmountByUncollectedFundsBreakdown => {
+                "disbursed_amount_by_uncollected_funds_breakdown"
+            }
+            DataSetType::CustomerProfileByIndustry => "customer_profile_by_industry",
+            DataSetType::CustomerProfileByRevenue => "customer_profile_by_revenue",
+            DataSetType::CustomerProfileByGeography => "customer_profile_by_geography",
+            DataSetType::SalesCompensationBilledRevenue => "sales_compensation_billed_revenue",
+            DataSetType::UsSalesAndUseTaxRecords => "us_sales_and_use_tax_records",
+            DataSetType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DataSetType {
+    fn default() -> DataSetType {
+        DataSetType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DataSetType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DataSetType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DataSetType {
+    fn from(value: &'a str) -> DataSetType {
+        match value {
+            "customer_subscriber_hourly_monthly_subscriptions" => {
+                DataSetType::CustomerSubscriberHourlyMonthlySubscriptions
+            }
+            "customer_subscriber_annual_subscriptions" => {
+                DataSetType::CustomerSubscriberAnnualSubscriptions
+            }
+            "daily_business_usage_by_instance_type" => {
+                DataSetType::DailyBusinessUsageByInstanceType
+            }
+            "daily_business_fees" => DataSetType::DailyBusinessFees,
+            "daily_business_free_trial_conversions" => {
+                DataSetType::DailyBusinessFreeTrialConversions
+            }
+            "daily_business_new_instances" => DataSetType::DailyBusinessNewInstances,
+            "daily_business_new_product_subscribers" => {
+                DataSetType::DailyBusinessNewProductSubscribers
+            }
+            "daily_business_canceled_product_subscribers" => {
+                DataSetType::DailyBusinessCanceledProductSubscribers
+            }
+            "monthly_revenue_billing_and_revenue_data" => {
+                DataSetType::MonthlyRevenueBillingAndRevenueData
+            }
+            "monthly_revenue_annual_subscriptions" => {
+                DataSetType::MonthlyRevenueAnnualSubscriptions
+            }
+            "monthly_revenue_field_demonstration_usage" => {
+                DataSetType::MonthlyRevenueFieldDemonstrationUsage
+            }
+            "monthly_revenue_flexible_payment_schedule" => {
+                DataSetType::MonthlyRevenueFlexiblePaymentSchedule
+            }
+            "disbursed_amount_by_product" => DataSetType::DisbursedAmountByProduct,
+            "disbursed_amount_by_product_with_uncollected_funds" => {
+                DataSetType::DisbursedAmountByProductWithUncollectedFunds
+            }
+            "disbursed_amount_by_instance_hours" => DataSetType::DisbursedAmountByInstanceHours,
+            "disbursed_amount_by_customer_geo" => DataSetType::DisbursedAmountByCustomerGeo,
+            "disbursed_amount_by_age_of_uncollected_funds" => {
+                DataSetType::DisbursedAmountByAgeOfUncollectedFunds
+            }
+            "disbursed_amount_by_age_of_disbursed_funds" => {
+                DataSetType::DisbursedAmountByAgeOfDisbursedFunds
+            }
+            "disbursed_amount_by_age_of_past_due_funds" => {
+                DataSetType::DisbursedAmountByAgeOfPastDueFunds
+            }
+            "disbursed_amount_by_uncollected_funds_breakdown" => {
+                DataSetType::DisbursedAmountByUncollectedFundsBreakdown
+            }
+            "customer_profile_by_industry" => DataSetType::CustomerProfileByIndustry,
+            "customer_profile_by_revenue" => DataSetType::CustomerProfileByRevenue,
+            "customer_profile_by_geography" => DataSetType::CustomerProfileByGeography,
+            "sales_compensation_billed_revenue" => DataSetType::SalesCompensationBilledRevenue,
+            "us_sales_and_use_tax_records" => DataSetType::UsSalesAndUseTaxRecords,
+            _ => DataSetType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DataSetType {
+    fn from(value: String) -> DataSetType {
+        match value.as_str() {
+            "customer_subscriber_hourly_monthly_subscriptions" => {
+                DataSetType::CustomerSubscriberHourlyMonthlySubscriptions
+            }
+            "customer_subscriber_annual_subscriptions" => {
+                DataSetType::CustomerSubscriberAnnualSubscriptions
+            }
+            "daily_business_usage_by_instance_type" => {
+                DataSetType::DailyBusinessUsageByInstanceType
+            }
+            "daily_business_fees" => DataSetType::DailyBusinessFees,
+            "daily_business_free_trial_conversions" => {
+                DataSetType::DailyBusinessFreeTrialConversions
+            }
+            "daily_business_new_instances" => DataSetType::DailyBusinessNewInstances,
+            "daily_business_new_product_subscribers" => {
+                DataSetType::DailyBusinessNewProductSubscribers
+            }
+            "daily_business_canceled_product_subscribers" => {
+                DataSetType::DailyBusinessCanceledProductSubscribers
+            }
+            "monthly_revenue_billing_and_revenue_data" => {
+                DataSetType::MonthlyRevenueBillingAndRevenueData
+            }
+            "monthly_revenue_annual_subscriptions" => {
+                DataSetType::MonthlyRevenueAnnualSubscriptions
+            }
+            "monthly_revenue_field_demonstration_usage" => {
+                DataSetType::MonthlyRevenueFieldDemonstrationUsage
+            }
+            "monthly_revenue_flexible_payment_schedule" => {
+                DataSetType::MonthlyRevenueFlexiblePaymentSchedule
+            }
+            "disbursed_amount_by_product" => DataSetType::DisbursedAmountByProduct,
+            "disbursed_amount_by_product_with_uncollected_funds" => {
+                DataSetType::DisbursedAmountByProductWithUncollectedFunds
+            }
+            "disbursed_amount_by_instance_hours" => DataSetType::DisbursedAmountByInstanceHours,
+            "disbursed_amount_by_customer_geo" => DataSetType::DisbursedAmountByCustomerGeo,
+            "disbursed_amount_by_age_of_uncollected_funds" => {
+                DataSetType::DisbursedAmountByAgeOfUncollectedFunds
+            }
+            "disbursed_amount_by_age_of_disbursed_funds" => {
+                DataSetType::DisbursedAmountByAgeOfDisbursedFunds
+            }
+            "disbursed_amount_by_age_of_past_due_funds" => {
+                DataSetType::DisbursedAmountByAgeOfPastDueFunds
+            }
+            "disbursed_amount_by_uncollected_funds_breakdown" => {
+                DataSetType::DisbursedAmountByUncollectedFundsBreakdown
+            }
+            "customer_profile_by_industry" => DataSetType::CustomerProfileByIndustry,
+            "customer_profile_by_revenue" => DataSetType::CustomerProfileByRevenue,
+            "customer_profile_by_geography" => DataSetType::CustomerProfileByGeography,
+            "sales_compensation_billed_revenue" => DataSetType::SalesCompensationBilledRevenue,
+            "us_sales_and_use_tax_records" => DataSetType::UsSalesAndUseTaxRecords,
+            _ => DataSetType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DataSetType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DataSetType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DataSetType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DataSetType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DataSetType {
+    fn deserialize<D>(deserializer: D) -> Result<DataSetType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Container for the parameters to the GenerateDataSet operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -34,10 +332,15 @@ pub struct GenerateDataSetRequest {
     pub customer_defined_values: Option<::std::collections::HashMap<String, String>>,
     /// <p>The date a data set was published. For daily data sets, provide a date with day-level granularity for the desired day. For weekly data sets, provide a date with day-level granularity within the desired week (the day value will be ignored). For monthly data sets, provide a date with month-level granularity for the desired month (the day value will be ignored).</p>
     #[serde(rename = "dataSetPublicationDate")]
-    pub data_set_publication_date: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub data_set_publication_date: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The desired data set type.</p> <p> <ul> <li> <strong>customer_subscriber_hourly_monthly_subscriptions</strong> <p>From 2017-09-15 to present: Available daily by 24:00 UTC.</p> </li> <li> <strong>customer_subscriber_annual_subscriptions</strong> <p>From 2017-09-15 to present: Available daily by 24:00 UTC.</p> </li> <li> <strong>daily_business_usage_by_instance_type</strong> <p>From 2017-09-15 to present: Available daily by 24:00 UTC.</p> </li> <li> <strong>daily_business_fees</strong> <p>From 2017-09-15 to present: Available daily by 24:00 UTC.</p> </li> <li> <strong>daily_business_free_trial_conversions</strong> <p>From 2017-09-15 to present: Available daily by 24:00 UTC.</p> </li> <li> <strong>daily_business_new_instances</strong> <p>From 2017-09-15 to present: Available daily by 24:00 UTC.</p> </li> <li> <strong>daily_business_new_product_subscribers</strong> <p>From 2017-09-15 to present: Available daily by 24:00 UTC.</p> </li> <li> <strong>daily_business_canceled_product_subscribers</strong> <p>From 2017-09-15 to present: Available daily by 24:00 UTC.</p> </li> <li> <strong>monthly_revenue_billing_and_revenue_data</strong> <p>From 2017-09-15 to present: Available monthly on the 15th day of the month by 24:00 UTC. Data includes metered transactions (e.g. hourly) from one month prior.</p> </li> <li> <strong>monthly_revenue_annual_subscriptions</strong> <p>From 2017-09-15 to present: Available monthly on the 15th day of the month by 24:00 UTC. Data includes up-front software charges (e.g. annual) from one month prior.</p> </li> <li> <strong>monthly_revenue_field_demonstration_usage</strong> <p>From 2018-03-15 to present: Available monthly on the 15th day of the month by 24:00 UTC.</p> </li> <li> <strong>monthly_revenue_flexible_payment_schedule</strong> <p>From 2018-11-15 to present: Available monthly on the 15th day of the month by 24:00 UTC.</p> </li> <li> <strong>disbursed_amount_by_product</strong> <p>From 2017-09-15 to present: Available every 30 days by 24:00 UTC.</p> </li> <li> <strong>disbursed_amount_by_instance_hours</strong> <p>From 2017-09-15 to present: Available every 30 days by 24:00 UTC.</p> </li> <li> <strong>disbursed_amount_by_customer_geo</strong> <p>From 2017-09-15 to present: Available every 30 days by 24:00 UTC.</p> </li> <li> <strong>disbursed_amount_by_age_of_uncollected_funds</strong> <p>From 2017-09-15 to present: Available every 30 days by 24:00 UTC.</p> </li> <li> <strong>disbursed_amount_by_age_of_disbursed_funds</strong> <p>From 2017-09-15 to present: Available every 30 days by 24:00 UTC.</p> </li> <li> <strong>disbursed_amount_by_age_of_past_due_funds</strong> <p>From 2018-04-07 to present: Available every 30 days by 24:00 UTC.</p> </li> <li> <strong>disbursed_amount_by_uncollected_funds_breakdown</strong> <p>From 2019-10-04 to present: Available every 30 days by 24:00 UTC.</p> </li> <li> <strong>sales_compensation_billed_revenue</strong> <p>From 2017-09-15 to present: Available monthly on the 15th day of the month by 24:00 UTC. Data includes metered transactions (e.g. hourly) from one month prior, and up-front software charges (e.g. annual) from one month prior.</p> </li> <li> <strong>us_sales_and_use_tax_records</strong> <p>From 2017-09-15 to present: Available monthly on the 15th day of the month by 24:00 UTC.</p> </li> </ul> </p>
     #[serde(rename = "dataSetType")]
-    pub data_set_type: String,
+    pub data_set_type: DataSetType,
     /// <p>The name (friendly name, not ARN) of the destination S3 bucket.</p>
     #[serde(rename = "destinationS3BucketName")]
     pub destination_s3_bucket_name: String,
@@ -73,7 +376,7 @@ pub struct StartSupportDataExportRequest {
     pub customer_defined_values: Option<::std::collections::HashMap<String, String>>,
     /// <p> Specifies the data set type to be written to the output csv file. The data set types customer_support_contacts_data and test_customer_support_contacts_data both result in a csv file containing the following fields: Product Id, Product Code, Customer Guid, Subscription Guid, Subscription Start Date, Organization, AWS Account Id, Given Name, Surname, Telephone Number, Email, Title, Country Code, ZIP Code, Operation Type, and Operation Time. </p> <p> <ul> <li><i>customer_support_contacts_data</i> Customer support contact data. The data set will contain all changes (Creates, Updates, and Deletes) to customer support contact data from the date specified in the from_date parameter.</li> <li><i>test_customer_support_contacts_data</i> An example data set containing static test data in the same format as customer_support_contacts_data</li> </ul> </p>
     #[serde(rename = "dataSetType")]
-    pub data_set_type: String,
+    pub data_set_type: SupportDataSetType,
     /// <p>The name (friendly name, not ARN) of the destination S3 bucket.</p>
     #[serde(rename = "destinationS3BucketName")]
     pub destination_s3_bucket_name: String,
@@ -83,7 +386,12 @@ pub struct StartSupportDataExportRequest {
     pub destination_s3_prefix: Option<String>,
     /// <p>The start date from which to retrieve the data set in UTC. This parameter only affects the customer<em>support</em>contacts_data data set type.</p>
     #[serde(rename = "fromDate")]
-    pub from_date: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub from_date: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The Amazon Resource Name (ARN) of the Role with an attached permissions policy to interact with the provided AWS services.</p>
     #[serde(rename = "roleNameArn")]
     pub role_name_arn: String,
@@ -102,6 +410,105 @@ pub struct StartSupportDataExportResult {
     pub data_set_request_id: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SupportDataSetType {
+    /// `customer_support_contacts_data`
+    CustomerSupportContactsData,
+    /// `test_customer_support_contacts_data`
+    TestCustomerSupportContactsData,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SupportDataSetType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SupportDataSetType::CustomerSupportContactsData => "customer_support_contacts_data",
+            SupportDataSetType::TestCustomerSupportContactsData => {
+                "test_customer_support_contacts_data"
+            }
+            SupportDataSetType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SupportDataSetType {
+    fn default() -> SupportDataSetType {
+        SupportDataSetType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SupportDataSetType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SupportDataSetType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SupportDataSetType {
+    fn from(value: &'a str) -> SupportDataSetType {
+        match value {
+            "customer_support_contacts_data" => SupportDataSetType::CustomerSupportContactsData,
+            "test_customer_support_contacts_data" => {
+                SupportDataSetType::TestCustomerSupportContactsData
+            }
+            _ => SupportDataSetType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SupportDataSetType {
+    fn from(value: String) -> SupportDataSetType {
+        match value.as_str() {
+            "customer_support_contacts_data" => SupportDataSetType::CustomerSupportContactsData,
+            "test_customer_support_contacts_data" => {
+                SupportDataSetType::TestCustomerSupportContactsData
+            }
+            _ => SupportDataSetType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SupportDataSetType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SupportDataSetType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SupportDataSetType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SupportDataSetType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SupportDataSetType {
+    fn deserialize<D>(deserializer: D) -> Result<SupportDataSetType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// Errors returned by GenerateDataSet
 #[derive(Debug, PartialEq)]
 pub enum GenerateDataSetError {
@@ -193,11 +600,15 @@ pub struct MarketplaceCommerceAnalyticsClient {
 impl MarketplaceCommerceAnalyticsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> MarketplaceCommerceAnalyticsClient {
         MarketplaceCommerceAnalyticsClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(
+                region,
+                "Marketplace Commerce Analytics",
+            ),
         }
     }
 
@@ -233,6 +644,7 @@ impl MarketplaceCommerceAnalytics for MarketplaceCommerceAnalyticsClient {
     ) -> Result<GenerateDataSetResult, RusotoError<GenerateDataSetError>> {
         let mut request =
             SignedRequest::new("POST", "marketplacecommerceanalytics", &self.region, "/");
+        request.set_operation("GenerateDataSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -264,6 +676,7 @@ impl MarketplaceCommerceAnalytics for MarketplaceCommerceAnalyticsClient {
     ) -> Result<StartSupportDataExportResult, RusotoError<StartSupportDataExportError>> {
         let mut request =
             SignedRequest::new("POST", "marketplacecommerceanalytics", &self.region, "/");
+        request.set_operation("StartSupportDataExport");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -84,7 +84,7 @@ pub struct AddOutputRequest {
     pub port: Option<i64>,
     /// <p>The protocol to use for the output.</p>
     #[serde(rename = "Protocol")]
-    pub protocol: String,
+    pub protocol: Protocol,
     /// <p>The remote ID for the Zixi-pull output stream.</p>
     #[serde(rename = "RemoteId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -99,6 +99,104 @@ pub struct AddOutputRequest {
     pub stream_id: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Algorithm {
+    /// `aes128`
+    Aes128,
+    /// `aes192`
+    Aes192,
+    /// `aes256`
+    Aes256,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Algorithm {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Algorithm::Aes128 => "aes128",
+            Algorithm::Aes192 => "aes192",
+            Algorithm::Aes256 => "aes256",
+            Algorithm::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Algorithm {
+    fn default() -> Algorithm {
+        Algorithm::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Algorithm {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Algorithm {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Algorithm {
+    fn from(value: &'a str) -> Algorithm {
+        match value {
+            "aes128" => Algorithm::Aes128,
+            "aes192" => Algorithm::Aes192,
+            "aes256" => Algorithm::Aes256,
+            _ => Algorithm::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Algorithm {
+    fn from(value: String) -> Algorithm {
+        match value.as_str() {
+            "aes128" => Algorithm::Aes128,
+            "aes192" => Algorithm::Aes192,
+            "aes256" => Algorithm::Aes256,
+            _ => Algorithm::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Algorithm {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Algorithm, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Algorithm {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Algorithm {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Algorithm {
+    fn deserialize<D>(deserializer: D) -> Result<Algorithm, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Creates a new flow. The request must include one source. The request optionally can include outputs (up to 20) and entitlements (up to 50).</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -148,7 +246,7 @@ pub struct DeleteFlowResponse {
     /// <p>The status of the flow when the DeleteFlow process begins.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<Status>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -175,7 +273,7 @@ pub struct DescribeFlowResponse {
 pub struct Encryption {
     /// <p>The type of algorithm that is used for the encryption (such as aes128, aes192, or aes256).</p>
     #[serde(rename = "Algorithm")]
-    pub algorithm: String,
+    pub algorithm: Algorithm,
     /// <p>A 128-bit, 16-byte hex value represented by a 32-character string, to be used with the key for encrypting content. This parameter is not valid for static key encryption.</p>
     #[serde(rename = "ConstantInitializationVector")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -187,7 +285,7 @@ pub struct Encryption {
     /// <p>The type of key that is used for the encryption. If no keyType is provided, the service will use the default setting (static-key).</p>
     #[serde(rename = "KeyType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub key_type: Option<String>,
+    pub key_type: Option<KeyType>,
     /// <p>The AWS Region that the API Gateway proxy endpoint was created in. This parameter is required for SPEKE encryption and is not valid for static key encryption.</p>
     #[serde(rename = "Region")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -267,7 +365,7 @@ pub struct Flow {
     pub source: Source,
     /// <p>The current status of the flow.</p>
     #[serde(rename = "Status")]
-    pub status: String,
+    pub status: Status,
 }
 
 /// <p>The entitlements that you want to grant on a flow.</p>
@@ -320,6 +418,104 @@ pub struct GrantFlowEntitlementsResponse {
     pub flow_arn: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum KeyType {
+    /// `speke`
+    Speke,
+    /// `static-key`
+    StaticKey,
+    /// `srt-password`
+    SrtPassword,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl KeyType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            KeyType::Speke => "speke",
+            KeyType::StaticKey => "static-key",
+            KeyType::SrtPassword => "srt-password",
+            KeyType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for KeyType {
+    fn default() -> KeyType {
+        KeyType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for KeyType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for KeyType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for KeyType {
+    fn from(value: &'a str) -> KeyType {
+        match value {
+            "speke" => KeyType::Speke,
+            "static-key" => KeyType::StaticKey,
+            "srt-password" => KeyType::SrtPassword,
+            _ => KeyType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for KeyType {
+    fn from(value: String) -> KeyType {
+        match value.as_str() {
+            "speke" => KeyType::Speke,
+            "static-key" => KeyType::StaticKey,
+            "srt-password" => KeyType::SrtPassword,
+            _ => KeyType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for KeyType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<KeyType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for KeyType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for KeyType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for KeyType {
+    fn deserialize<D>(deserializer: D) -> Result<KeyType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ListEntitlementsRequest {
@@ -423,10 +619,10 @@ pub struct ListedFlow {
     pub name: String,
     /// <p>The type of source. This value is either owned (originated somewhere other than an AWS Elemental MediaConnect flow owned by another AWS account) or entitled (originated at an AWS Elemental MediaConnect flow owned by another AWS account).</p>
     #[serde(rename = "SourceType")]
-    pub source_type: String,
+    pub source_type: SourceType,
     /// <p>The current status of the flow.</p>
     #[serde(rename = "Status")]
-    pub status: String,
+    pub status: Status,
 }
 
 /// <p>Messages that provide the state of the flow.</p>
@@ -482,6 +678,144 @@ pub struct Output {
     pub transport: Option<Transport>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Protocol {
+    /// `zixi-push`
+    ZixiPush,
+    /// `rtp-fec`
+    RtpFec,
+    /// `rtp`
+    Rtp,
+    /// `zixi-pull`
+    ZixiPull,
+    /// `rist`
+    Rist,
+    /// `st2110-jpegxs`
+    St2110Jpegxs,
+    /// `cdi`
+    Cdi,
+    /// `srt-listener`
+    SrtListener,
+    /// `srt-caller`
+    SrtCaller,
+    /// `fujitsu-qos`
+    FujitsuQos,
+    /// `udp`
+    Udp,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Protocol {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Protocol::ZixiPush => "zixi-push",
+            Protocol::RtpFec => "rtp-fec",
+            Protocol::Rtp => "rtp",
+            Protocol::ZixiPull => "zixi-pull",
+            Protocol::Rist => "rist",
+            Protocol::St2110Jpegxs => "st2110-jpegxs",
+            Protocol::Cdi => "cdi",
+            Protocol::SrtListener => "srt-listener",
+            Protocol::SrtCaller => "srt-caller",
+            Protocol::FujitsuQos => "fujitsu-qos",
+            Protocol::Udp => "udp",
+            Protocol::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Protocol {
+    fn default() -> Protocol {
+        Protocol::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Protocol {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Protocol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Protocol {
+    fn from(value: &'a str) -> Protocol {
+        match value {
+            "zixi-push" => Protocol::ZixiPush,
+            "rtp-fec" => Protocol::RtpFec,
+            "rtp" => Protocol::Rtp,
+            "zixi-pull" => Protocol::ZixiPull,
+            "rist" => Protocol::Rist,
+            "st2110-jpegxs" => Protocol::St2110Jpegxs,
+            "cdi" => Protocol::Cdi,
+            "srt-listener" => Protocol::SrtListener,
+            "srt-caller" => Protocol::SrtCaller,
+            "fujitsu-qos" => Protocol::FujitsuQos,
+            "udp" => Protocol::Udp,
+            _ => Protocol::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Protocol {
+    fn from(value: String) -> Protocol {
+        match value.as_str() {
+            "zixi-push" => Protocol::ZixiPush,
+            "rtp-fec" => Protocol::RtpFec,
+            "rtp" => Protocol::Rtp,
+            "zixi-pull" => Protocol::ZixiPull,
+            "rist" => Protocol::Rist,
+            "st2110-jpegxs" => Protocol::St2110Jpegxs,
+            "cdi" => Protocol::Cdi,
+            "srt-listener" => Protocol::SrtListener,
+            "srt-caller" => Protocol::SrtCaller,
+            "fujitsu-qos" => Protocol::FujitsuQos,
+            "udp" => Protocol::Udp,
+            _ => Protocol::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Protocol {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Protocol, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Protocol {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Protocol {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Protocol {
+    fn deserialize<D>(deserializer: D) -> Result<Protocol, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct RemoveFlowOutputRequest {
@@ -565,7 +899,7 @@ pub struct SetSourceRequest {
     /// <p>The protocol that is used by the source.</p>
     #[serde(rename = "Protocol")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub protocol: Option<String>,
+    pub protocol: Option<Protocol>,
     /// <p>The stream ID that you want to use for this transport. This parameter applies only to Zixi-based streams.</p>
     #[serde(rename = "StreamId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -620,6 +954,99 @@ pub struct Source {
     pub whitelist_cidr: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SourceType {
+    /// `OWNED`
+    Owned,
+    /// `ENTITLED`
+    Entitled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SourceType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SourceType::Owned => "OWNED",
+            SourceType::Entitled => "ENTITLED",
+            SourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SourceType {
+    fn default() -> SourceType {
+        SourceType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SourceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SourceType {
+    fn from(value: &'a str) -> SourceType {
+        match value {
+            "OWNED" => SourceType::Owned,
+            "ENTITLED" => SourceType::Entitled,
+            _ => SourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SourceType {
+    fn from(value: String) -> SourceType {
+        match value.as_str() {
+            "OWNED" => SourceType::Owned,
+            "ENTITLED" => SourceType::Entitled,
+            _ => SourceType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SourceType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SourceType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SourceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SourceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SourceType {
+    fn deserialize<D>(deserializer: D) -> Result<SourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct StartFlowRequest {
@@ -638,9 +1065,127 @@ pub struct StartFlowResponse {
     /// <p>The status of the flow when the StartFlow process begins.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<Status>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Status {
+    /// `STANDBY`
+    Standby,
+    /// `ACTIVE`
+    Active,
+    /// `UPDATING`
+    Updating,
+    /// `DELETING`
+    Deleting,
+    /// `STARTING`
+    Starting,
+    /// `STOPPING`
+    Stopping,
+    /// `ERROR`
+    Error,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Status {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Status::Standby => "STANDBY",
+            Status::Active => "ACTIVE",
+            Status::Updating => "UPDATING",
+            Status::Deleting => "DELETING",
+            Status::Starting => "STARTING",
+            Status::Stopping => "STOPPING",
+            Status::Error => "ERROR",
+            Status::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Status {
+    fn default() -> Status {
+        Status::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Status {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Status {
+    fn from(value: &'a str) -> Status {
+        match value {
+            "STANDBY" => Status::Standby,
+            "ACTIVE" => Status::Active,
+            "UPDATING" => Status::Updating,
+            "DELETING" => Status::Deleting,
+            "STARTING" => Status::Starting,
+            "STOPPING" => Status::Stopping,
+            "ERROR" => Status::Error,
+            _ => Status::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Status {
+    fn from(value: String) -> Status {
+        match value.as_str() {
+            "STANDBY" => Status::Standby,
+            "ACTIVE" => Status::Active,
+            "UPDATING" => Status::Updating,
+            "DELETING" => Status::Deleting,
+            "STARTING" => Status::Starting,
+            "STOPPING" => Status::Stopping,
+            "ERROR" => Status::Error,
+            _ => Status::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Status {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Status, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Status {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Status {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for Status {
+    fn deserialize<D>(deserializer: D) -> Result<Status, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct StopFlowRequest {
@@ -659,7 +1204,7 @@ pub struct StopFlowResponse {
     /// <p>The status of the flow when the StopFlow process begins.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<Status>,
 }
 
 /// <p>The tags to add to the resource. A tag is an array of key-value pairs. Tag keys can have a maximum character length of 128 characters, and tag values can have a maximum length of 256 characters.</p>
@@ -692,7 +1237,7 @@ pub struct Transport {
     pub max_latency: Option<i64>,
     /// <p>The protocol that is used by the source or output.</p>
     #[serde(rename = "Protocol")]
-    pub protocol: String,
+    pub protocol: Protocol,
     /// <p>The remote ID for the Zixi-pull stream.</p>
     #[serde(rename = "RemoteId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -725,7 +1270,7 @@ pub struct UpdateEncryption {
     /// <p>The type of algorithm that is used for the encryption (such as aes128, aes192, or aes256).</p>
     #[serde(rename = "Algorithm")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub algorithm: Option<String>,
+    pub algorithm: Option<Algorithm>,
     /// <p>A 128-bit, 16-byte hex value represented by a 32-character string, to be used with the key for encrypting content. This parameter is not valid for static key encryption.</p>
     #[serde(rename = "ConstantInitializationVector")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -737,7 +1282,7 @@ pub struct UpdateEncryption {
     /// <p>The type of key that is used for the encryption. If no keyType is provided, the service will use the default setting (static-key).</p>
     #[serde(rename = "KeyType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub key_type: Option<String>,
+    pub key_type: Option<KeyType>,
     /// <p>The AWS Region that the API Gateway proxy endpoint was created in. This parameter is required for SPEKE encryption and is not valid for static key encryption.</p>
     #[serde(rename = "Region")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -833,7 +1378,7 @@ pub struct UpdateFlowOutputRequest {
     /// <p>The protocol to use for the output.</p>
     #[serde(rename = "Protocol")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub protocol: Option<String>,
+    pub protocol: Option<Protocol>,
     /// <p>The remote ID for the Zixi-pull stream.</p>
     #[serde(rename = "RemoteId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -894,7 +1439,7 @@ pub struct UpdateFlowSourceRequest {
     /// <p>The protocol that is used by the source.</p>
     #[serde(rename = "Protocol")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub protocol: Option<String>,
+    pub protocol: Option<Protocol>,
     /// <p>The ARN of the source that you want to update.</p>
     #[serde(rename = "SourceArn")]
     pub source_arn: String,
@@ -2022,11 +2567,12 @@ pub struct MediaConnectClient {
 impl MediaConnectClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> MediaConnectClient {
         MediaConnectClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "MediaConnect"),
         }
     }
 
@@ -2060,6 +2606,7 @@ impl MediaConnect for MediaConnectClient {
         let request_uri = format!("/v1/flows/{flow_arn}/outputs", flow_arn = input.flow_arn);
 
         let mut request = SignedRequest::new("POST", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("AddFlowOutputs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2090,6 +2637,7 @@ impl MediaConnect for MediaConnectClient {
         let request_uri = "/v1/flows";
 
         let mut request = SignedRequest::new("POST", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("CreateFlow");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2120,6 +2668,7 @@ impl MediaConnect for MediaConnectClient {
         let request_uri = format!("/v1/flows/{flow_arn}", flow_arn = input.flow_arn);
 
         let mut request = SignedRequest::new("DELETE", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("DeleteFlow");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2147,6 +2696,7 @@ impl MediaConnect for MediaConnectClient {
         let request_uri = format!("/v1/flows/{flow_arn}", flow_arn = input.flow_arn);
 
         let mut request = SignedRequest::new("GET", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("DescribeFlow");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2177,6 +2727,7 @@ impl MediaConnect for MediaConnectClient {
         );
 
         let mut request = SignedRequest::new("POST", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("GrantFlowEntitlements");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2207,6 +2758,7 @@ impl MediaConnect for MediaConnectClient {
         let request_uri = "/v1/entitlements";
 
         let mut request = SignedRequest::new("GET", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("ListEntitlements");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2243,6 +2795,7 @@ impl MediaConnect for MediaConnectClient {
         let request_uri = "/v1/flows";
 
         let mut request = SignedRequest::new("GET", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("ListFlows");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2279,6 +2832,7 @@ impl MediaConnect for MediaConnectClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2310,6 +2864,7 @@ impl MediaConnect for MediaConnectClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("RemoveFlowOutput");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2341,6 +2896,7 @@ impl MediaConnect for MediaConnectClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("RevokeFlowEntitlement");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2368,6 +2924,7 @@ impl MediaConnect for MediaConnectClient {
         let request_uri = format!("/v1/flows/start/{flow_arn}", flow_arn = input.flow_arn);
 
         let mut request = SignedRequest::new("POST", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("StartFlow");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2395,6 +2952,7 @@ impl MediaConnect for MediaConnectClient {
         let request_uri = format!("/v1/flows/stop/{flow_arn}", flow_arn = input.flow_arn);
 
         let mut request = SignedRequest::new("POST", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("StopFlow");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2422,6 +2980,7 @@ impl MediaConnect for MediaConnectClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2451,6 +3010,7 @@ impl MediaConnect for MediaConnectClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2487,6 +3047,7 @@ impl MediaConnect for MediaConnectClient {
         );
 
         let mut request = SignedRequest::new("PUT", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("UpdateFlowEntitlement");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2521,6 +3082,7 @@ impl MediaConnect for MediaConnectClient {
         );
 
         let mut request = SignedRequest::new("PUT", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("UpdateFlowOutput");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2555,6 +3117,7 @@ impl MediaConnect for MediaConnectClient {
         );
 
         let mut request = SignedRequest::new("PUT", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("UpdateFlowSource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2577,3 +3140,271 @@ impl MediaConnect for MediaConnectClient {
         }
     }
 }
+impl MediaConnectClient {
+    /// Auto-paginating version of `list_entitlements`, streaming every page of results.
+    pub fn list_entitlements_pages(
+        &self,
+        input: ListEntitlementsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListEntitlementsResponse, ListEntitlementsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MediaConnectClient, input: ListEntitlementsRequest| async move {
+                client.list_entitlements(input).await
+            },
+            |input: &ListEntitlementsRequest, output: &ListEntitlementsResponse| {
+                Some(ListEntitlementsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_entitlements`, streaming the `Entitlements` of every page of results.
+    pub fn list_entitlements_items(
+        &self,
+        input: ListEntitlementsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListedEntitlement, ListEntitlementsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_entitlements_pages(input),
+            |output: ListEntitlementsResponse| output.entitlements.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_flows`, streaming every page of results.
+    pub fn list_flows_pages(
+        &self,
+        input: ListFlowsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListFlowsResponse, ListFlowsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MediaConnectClient, input: ListFlowsRequest| async move {
+                client.list_flows(input).await
+            },
+            |input: &ListFlowsRequest, output: &ListFlowsResponse| {
+                Some(ListFlowsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_flows`, streaming the `Flows` of every page of results.
+    pub fn list_flows_items(
+        &self,
+        input: ListFlowsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListedFlow, ListFlowsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_flows_pages(input),
+            |output: ListFlowsResponse| output.flows.unwrap_or_default(),
+        )
+    }
+}
+impl MediaConnectClient {
+    /// Waits until `describe_flow` reports the `FlowActive` state, polling every 3 seconds up to 40 times.
+    pub async fn wait_until_flow_active(
+        &self,
+        input: DescribeFlowRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeFlowError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(3), 40);
+        self.wait_until_flow_active_with_config(input, config).await
+    }
+
+    /// Same as `wait_until_flow_active`, polling as often and as many times as `config` says.
+    pub async fn wait_until_flow_active_with_config(
+        &self,
+        input: DescribeFlowRequest,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeFlowError>> {
+        rusoto_core::waiter::wait(
+            "FlowActive",
+            &config,
+            || self.describe_flow(input.clone()),
+            |result: &Result<DescribeFlowResponse, RusotoError<DescribeFlowError>>| match result {
+                Ok(output)
+                    if output
+                        .flow
+                        .iter()
+                        .map(|v| &v.status)
+                        .any(|v| *v == Status::Active) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if output
+                        .flow
+                        .iter()
+                        .map(|v| &v.status)
+                        .any(|v| *v == Status::Starting) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Retry)
+                }
+                Ok(output)
+                    if output
+                        .flow
+                        .iter()
+                        .map(|v| &v.status)
+                        .any(|v| *v == Status::Updating) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Retry)
+                }
+                Err(RusotoError::Service(DescribeFlowError::InternalServerError(_))) => {
+                    Some(rusoto_core::waiter::WaiterState::Retry)
+                }
+                Err(RusotoError::Unknown(response)) if response.status.as_u16() == 500 => {
+                    Some(rusoto_core::waiter::WaiterState::Retry)
+                }
+                Err(RusotoError::Service(DescribeFlowError::ServiceUnavailable(_))) => {
+                    Some(rusoto_core::waiter::WaiterState::Retry)
+                }
+                Err(RusotoError::Unknown(response)) if response.status.as_u16() == 503 => {
+                    Some(rusoto_core::waiter::WaiterState::Retry)
+                }
+                Ok(output)
+                    if output
+                        .flow
+                        .iter()
+                        .map(|v| &v.status)
+                        .any(|v| *v == Status::Error) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+
+    /// Waits until `describe_flow` reports the `FlowDeleted` state, polling every 3 seconds up to 40 times.
+    pub async fn wait_until_flow_deleted(
+        &self,
+        input: DescribeFlowRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeFlowError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(3), 40);
+        self.wait_until_flow_deleted_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_flow_deleted`, polling as often and as many times as `config` says.
+    pub async fn wait_until_flow_deleted_with_config(
+        &self,
+        input: DescribeFlowRequest,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeFlowError>> {
+        rusoto_core::waiter::wait(
+            "FlowDeleted",
+            &config,
+            || self.describe_flow(input.clone()),
+            |result: &Result<DescribeFlowResponse, RusotoError<DescribeFlowError>>| match result {
+                Err(RusotoError::Service(DescribeFlowError::NotFound(_))) => {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Err(RusotoError::Unknown(response)) if response.status.as_u16() == 404 => {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if output
+                        .flow
+                        .iter()
+                        .map(|v| &v.status)
+                        .any(|v| *v == Status::Deleting) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Retry)
+                }
+                Err(RusotoError::Service(DescribeFlowError::InternalServerError(_))) => {
+                    Some(rusoto_core::waiter::WaiterState::Retry)
+                }
+                Err(RusotoError::Unknown(response)) if response.status.as_u16() == 500 => {
+                    Some(rusoto_core::waiter::WaiterState::Retry)
+                }
+                Err(RusotoError::Service(DescribeFlowError::ServiceUnavailable(_))) => {
+                    Some(rusoto_core::waiter::WaiterState::Retry)
+                }
+                Err(RusotoError::Unknown(response)) if response.status.as_u16() == 503 => {
+                    Some(rusoto_core::waiter::WaiterState::Retry)
+                }
+                Ok(output)
+                    if output
+                        .flow
+                        .iter()
+                        .map(|v| &v.status)
+                        .any(|v| *v == Status::Error) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+
+    /// Waits until `describe_flow` reports the `FlowStandby` state, polling every 3 seconds up to 40 times.
+    pub async fn wait_until_flow_standby(
+        &self,
+        input: DescribeFlowRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeFlowError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(3), 40);
+        self.wait_until_flow_standby_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_flow_standby`, polling as often and as many times as `config` says.
+    pub async fn wait_until_flow_standby_with_config(
+        &self,
+        input: DescribeFlowRequest,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeFlowError>> {
+        rusoto_core::waiter::wait(
+            "FlowStandby",
+            &config,
+            || self.describe_flow(input.clone()),
+            |result: &Result<DescribeFlowResponse, RusotoError<DescribeFlowError>>| match result {
+                Ok(output)
+                    if output
+                        .flow
+                        .iter()
+                        .map(|v| &v.status)
+                        .any(|v| *v == Status::Standby) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if output
+                        .flow
+                        .iter()
+                        .map(|v| &v.status)
+                        .any(|v| *v == Status::Stopping) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Retry)
+                }
+                Err(RusotoError::Service(DescribeFlowError::InternalServerError(_))) => {
+                    Some(rusoto_core::waiter::WaiterState::Retry)
+                }
+                Err(RusotoError::Unknown(response)) if response.status.as_u16() == 500 => {
+                    Some(rusoto_core::waiter::WaiterState::Retry)
+                }
+                Err(RusotoError::Service(DescribeFlowError::ServiceUnavailable(_))) => {
+                    Some(rusoto_core::waiter::WaiterState::Retry)
+                }
+                Err(RusotoError::Unknown(response)) if response.status.as_u16() == 503 => {
+                    Some(rusoto_core::waiter::WaiterState::Retry)
+                }
+                Ok(output)
+                    if output
+                        .flow
+                        .iter()
+                        .map(|v| &v.status)
+                        .any(|v| *v == Status::Error) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+}
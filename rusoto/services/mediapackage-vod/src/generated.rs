@@ -25,6 +25,104 @@ use rusoto_core::signature::SignedRequest;
 #[allow(unused_imports)]
 use serde::{Deserialize, Serialize};
 use serde_json;
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AdMarkers {
+    /// `NONE`
+    None,
+    /// `SCTE35_ENHANCED`
+    Scte35Enhanced,
+    /// `PASSTHROUGH`
+    Passthrough,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AdMarkers {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AdMarkers::None => "NONE",
+            AdMarkers::Scte35Enhanced => "SCTE35_ENHANCED",
+            AdMarkers::Passthrough => "PASSTHROUGH",
+            AdMarkers::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AdMarkers {
+    fn default() -> AdMarkers {
+        AdMarkers::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AdMarkers {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AdMarkers {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AdMarkers {
+    fn from(value: &'a str) -> AdMarkers {
+        match value {
+            "NONE" => AdMarkers::None,
+            "SCTE35_ENHANCED" => AdMarkers::Scte35Enhanced,
+            "PASSTHROUGH" => AdMarkers::Passthrough,
+            _ => AdMarkers::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AdMarkers {
+    fn from(value: String) -> AdMarkers {
+        match value.as_str() {
+            "NONE" => AdMarkers::None,
+            "SCTE35_ENHANCED" => AdMarkers::Scte35Enhanced,
+            "PASSTHROUGH" => AdMarkers::Passthrough,
+            _ => AdMarkers::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AdMarkers {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AdMarkers, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AdMarkers {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AdMarkers {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AdMarkers {
+    fn deserialize<D>(deserializer: D) -> Result<AdMarkers, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A MediaPackage VOD Asset resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -241,7 +339,7 @@ pub struct DashManifest {
     /// <p>The Dynamic Adaptive Streaming over HTTP (DASH) profile type.  When set to &quot;HBBTV<em>1</em>5&quot;, HbbTV 1.5 compliant output is enabled.</p>
     #[serde(rename = "Profile")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub profile: Option<String>,
+    pub profile: Option<Profile>,
     #[serde(rename = "StreamSelection")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub stream_selection: Option<StreamSelection>,
@@ -420,6 +518,99 @@ pub struct EgressEndpoint {
     pub url: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EncryptionMethod {
+    /// `AES_128`
+    Aes128,
+    /// `SAMPLE_AES`
+    SampleAes,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EncryptionMethod {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EncryptionMethod::Aes128 => "AES_128",
+            EncryptionMethod::SampleAes => "SAMPLE_AES",
+            EncryptionMethod::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EncryptionMethod {
+    fn default() -> EncryptionMethod {
+        EncryptionMethod::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EncryptionMethod {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EncryptionMethod {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EncryptionMethod {
+    fn from(value: &'a str) -> EncryptionMethod {
+        match value {
+            "AES_128" => EncryptionMethod::Aes128,
+            "SAMPLE_AES" => EncryptionMethod::SampleAes,
+            _ => EncryptionMethod::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EncryptionMethod {
+    fn from(value: String) -> EncryptionMethod {
+        match value.as_str() {
+            "AES_128" => EncryptionMethod::Aes128,
+            "SAMPLE_AES" => EncryptionMethod::SampleAes,
+            _ => EncryptionMethod::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EncryptionMethod {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EncryptionMethod, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EncryptionMethod {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EncryptionMethod {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EncryptionMethod {
+    fn deserialize<D>(deserializer: D) -> Result<EncryptionMethod, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An HTTP Live Streaming (HLS) encryption configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct HlsEncryption {
@@ -431,7 +622,7 @@ pub struct HlsEncryption {
     /// <p>The encryption method to use.</p>
     #[serde(rename = "EncryptionMethod")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub encryption_method: Option<String>,
+    pub encryption_method: Option<EncryptionMethod>,
     #[serde(rename = "SpekeKeyProvider")]
     pub speke_key_provider: SpekeKeyProvider,
 }
@@ -447,7 +638,7 @@ pub struct HlsManifest {
     /// messages in the input source.</p>
     #[serde(rename = "AdMarkers")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ad_markers: Option<String>,
+    pub ad_markers: Option<AdMarkers>,
     /// <p>When enabled, an I-Frame only stream will be included in the output.</p>
     #[serde(rename = "IncludeIframeOnlyStream")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -665,6 +856,99 @@ pub struct PackagingGroup {
     pub id: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Profile {
+    /// `NONE`
+    None,
+    /// `HBBTV_1_5`
+    Hbbtv1_5,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Profile {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Profile::None => "NONE",
+            Profile::Hbbtv1_5 => "HBBTV_1_5",
+            Profile::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Profile {
+    fn default() -> Profile {
+        Profile::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Profile {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Profile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Profile {
+    fn from(value: &'a str) -> Profile {
+        match value {
+            "NONE" => Profile::None,
+            "HBBTV_1_5" => Profile::Hbbtv1_5,
+            _ => Profile::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Profile {
+    fn from(value: String) -> Profile {
+        match value.as_str() {
+            "NONE" => Profile::None,
+            "HBBTV_1_5" => Profile::Hbbtv1_5,
+            _ => Profile::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Profile {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Profile, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Profile {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Profile {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Profile {
+    fn deserialize<D>(deserializer: D) -> Result<Profile, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A configuration for accessing an external Secure Packager and Encoder Key Exchange (SPEKE) service that will provide encryption keys.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SpekeKeyProvider {
@@ -680,6 +964,104 @@ pub struct SpekeKeyProvider {
     pub url: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StreamOrder {
+    /// `ORIGINAL`
+    Original,
+    /// `VIDEO_BITRATE_ASCENDING`
+    VideoBitrateAscending,
+    /// `VIDEO_BITRATE_DESCENDING`
+    VideoBitrateDescending,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl StreamOrder {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StreamOrder::Original => "ORIGINAL",
+            StreamOrder::VideoBitrateAscending => "VIDEO_BITRATE_ASCENDING",
+            StreamOrder::VideoBitrateDescending => "VIDEO_BITRATE_DESCENDING",
+            StreamOrder::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for StreamOrder {
+    fn default() -> StreamOrder {
+        StreamOrder::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for StreamOrder {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for StreamOrder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for StreamOrder {
+    fn from(value: &'a str) -> StreamOrder {
+        match value {
+            "ORIGINAL" => StreamOrder::Original,
+            "VIDEO_BITRATE_ASCENDING" => StreamOrder::VideoBitrateAscending,
+            "VIDEO_BITRATE_DESCENDING" => StreamOrder::VideoBitrateDescending,
+            _ => StreamOrder::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StreamOrder {
+    fn from(value: String) -> StreamOrder {
+        match value.as_str() {
+            "ORIGINAL" => StreamOrder::Original,
+            "VIDEO_BITRATE_ASCENDING" => StreamOrder::VideoBitrateAscending,
+            "VIDEO_BITRATE_DESCENDING" => StreamOrder::VideoBitrateDescending,
+            _ => StreamOrder::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for StreamOrder {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<StreamOrder, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StreamOrder {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for StreamOrder {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StreamOrder {
+    fn deserialize<D>(deserializer: D) -> Result<StreamOrder, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A StreamSelection configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct StreamSelection {
@@ -694,7 +1076,7 @@ pub struct StreamSelection {
     /// <p>A directive that determines the order of streams in the output.</p>
     #[serde(rename = "StreamOrder")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stream_order: Option<String>,
+    pub stream_order: Option<StreamOrder>,
 }
 
 /// Errors returned by CreateAsset
@@ -1617,11 +1999,12 @@ pub struct MediaPackageVodClient {
 impl MediaPackageVodClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> MediaPackageVodClient {
         MediaPackageVodClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "MediaPackage Vod"),
         }
     }
 
@@ -1656,6 +2039,7 @@ impl MediaPackageVod for MediaPackageVodClient {
 
         let mut request =
             SignedRequest::new("POST", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation("CreateAsset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1688,6 +2072,7 @@ impl MediaPackageVod for MediaPackageVodClient {
 
         let mut request =
             SignedRequest::new("POST", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation("CreatePackagingConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1719,6 +2104,7 @@ impl MediaPackageVod for MediaPackageVodClient {
 
         let mut request =
             SignedRequest::new("POST", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation("CreatePackagingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1750,6 +2136,7 @@ impl MediaPackageVod for MediaPackageVodClient {
 
         let mut request =
             SignedRequest::new("DELETE", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation("DeleteAsset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1779,6 +2166,7 @@ impl MediaPackageVod for MediaPackageVodClient {
 
         let mut request =
             SignedRequest::new("DELETE", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation("DeletePackagingConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1807,6 +2195,7 @@ impl MediaPackageVod for MediaPackageVodClient {
 
         let mut request =
             SignedRequest::new("DELETE", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation("DeletePackagingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1834,6 +2223,7 @@ impl MediaPackageVod for MediaPackageVodClient {
         let request_uri = format!("/assets/{id}", id = input.id);
 
         let mut request = SignedRequest::new("GET", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation("DescribeAsset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1864,6 +2254,7 @@ impl MediaPackageVod for MediaPackageVodClient {
         let request_uri = format!("/packaging_configurations/{id}", id = input.id);
 
         let mut request = SignedRequest::new("GET", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation("DescribePackagingConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1891,6 +2282,7 @@ impl MediaPackageVod for MediaPackageVodClient {
         let request_uri = format!("/packaging_groups/{id}", id = input.id);
 
         let mut request = SignedRequest::new("GET", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation("DescribePackagingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1918,6 +2310,7 @@ impl MediaPackageVod for MediaPackageVodClient {
         let request_uri = "/assets";
 
         let mut request = SignedRequest::new("GET", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation("ListAssets");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1958,6 +2351,7 @@ impl MediaPackageVod for MediaPackageVodClient {
         let request_uri = "/packaging_configurations";
 
         let mut request = SignedRequest::new("GET", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation("ListPackagingConfigurations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1997,6 +2391,7 @@ impl MediaPackageVod for MediaPackageVodClient {
         let request_uri = "/packaging_groups";
 
         let mut request = SignedRequest::new("GET", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation("ListPackagingGroups");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2025,3 +2420,107 @@ impl MediaPackageVod for MediaPackageVodClient {
         }
     }
 }
+impl MediaPackageVodClient {
+    /// Auto-paginating version of `list_assets`, streaming every page of results.
+    pub fn list_assets_pages(
+        &self,
+        input: ListAssetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListAssetsResponse, ListAssetsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MediaPackageVodClient, input: ListAssetsRequest| async move {
+                client.list_assets(input).await
+            },
+            |input: &ListAssetsRequest, output: &ListAssetsResponse| {
+                Some(ListAssetsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_assets`, streaming the `Assets` of every page of results.
+    pub fn list_assets_items(
+        &self,
+        input: ListAssetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<AssetShallow, ListAssetsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_assets_pages(input),
+            |output: ListAssetsResponse| output.assets.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_packaging_configurations`, streaming every page of results.
+    pub fn list_packaging_configurations_pages(
+        &self,
+        input: ListPackagingConfigurationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListPackagingConfigurationsResponse,
+        ListPackagingConfigurationsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MediaPackageVodClient, input: ListPackagingConfigurationsRequest| async move {
+                client.list_packaging_configurations(input).await
+            },
+            |input: &ListPackagingConfigurationsRequest,
+             output: &ListPackagingConfigurationsResponse| {
+                Some(ListPackagingConfigurationsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_packaging_configurations`, streaming the `PackagingConfigurations` of every page of results.
+    pub fn list_packaging_configurations_items(
+        &self,
+        input: ListPackagingConfigurationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        PackagingConfiguration,
+        ListPackagingConfigurationsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_packaging_configurations_pages(input),
+            |output: ListPackagingConfigurationsResponse| {
+                output.packaging_configurations.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_packaging_groups`, streaming every page of results.
+    pub fn list_packaging_groups_pages(
+        &self,
+        input: ListPackagingGroupsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListPackagingGroupsResponse, ListPackagingGroupsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MediaPackageVodClient, input: ListPackagingGroupsRequest| async move {
+                client.list_packaging_groups(input).await
+            },
+            |input: &ListPackagingGroupsRequest, output: &ListPackagingGroupsResponse| {
+                Some(ListPackagingGroupsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_packaging_groups`, streaming the `PackagingGroups` of every page of results.
+    pub fn list_packaging_groups_items(
+        &self,
+        input: ListPackagingGroupsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<PackagingGroup, ListPackagingGroupsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_packaging_groups_pages(input),
+            |output: ListPackagingGroupsResponse| output.packaging_groups.unwrap_or_default(),
+        )
+    }
+}
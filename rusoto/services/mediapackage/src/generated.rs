@@ -25,6 +25,220 @@ use rusoto_core::signature::SignedRequest;
 #[allow(unused_imports)]
 use serde::{Deserialize, Serialize};
 use serde_json;
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AdMarkers {
+    /// `NONE`
+    None,
+    /// `SCTE35_ENHANCED`
+    Scte35Enhanced,
+    /// `PASSTHROUGH`
+    Passthrough,
+    /// `DATERANGE`
+    Daterange,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AdMarkers {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AdMarkers::None => "NONE",
+            AdMarkers::Scte35Enhanced => "SCTE35_ENHANCED",
+            AdMarkers::Passthrough => "PASSTHROUGH",
+            AdMarkers::Daterange => "DATERANGE",
+            AdMarkers::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AdMarkers {
+    fn default() -> AdMarkers {
+        AdMarkers::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AdMarkers {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AdMarkers {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AdMarkers {
+    fn from(value: &'a str) -> AdMarkers {
+        match value {
+            "NONE" => AdMarkers::None,
+            "SCTE35_ENHANCED" => AdMarkers::Scte35Enhanced,
+            "PASSTHROUGH" => AdMarkers::Passthrough,
+            "DATERANGE" => AdMarkers::Daterange,
+            _ => AdMarkers::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AdMarkers {
+    fn from(value: String) -> AdMarkers {
+        match value.as_str() {
+            "NONE" => AdMarkers::None,
+            "SCTE35_ENHANCED" => AdMarkers::Scte35Enhanced,
+            "PASSTHROUGH" => AdMarkers::Passthrough,
+            "DATERANGE" => AdMarkers::Daterange,
+            _ => AdMarkers::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AdMarkers {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AdMarkers, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AdMarkers {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AdMarkers {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AdMarkers {
+    fn deserialize<D>(deserializer: D) -> Result<AdMarkers, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// This setting allows the delivery restriction flags on SCTE-35 segmentation descriptors to
+/// determine whether a message signals an ad.  Choosing "NONE" means no SCTE-35 messages become
+/// ads.  Choosing "RESTRICTED" means SCTE-35 messages of the types specified in AdTriggers that
+/// contain delivery restrictions will be treated as ads.  Choosing "UNRESTRICTED" means SCTE-35
+/// messages of the types specified in AdTriggers that do not contain delivery restrictions will
+/// be treated as ads.  Choosing "BOTH" means all SCTE-35 messages of the types specified in
+/// AdTriggers will be treated as ads.  Note that Splice Insert messages do not have these flags
+/// and are always treated as ads if specified in AdTriggers.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AdsOnDeliveryRestrictions {
+    /// `NONE`
+    None,
+    /// `RESTRICTED`
+    Restricted,
+    /// `UNRESTRICTED`
+    Unrestricted,
+    /// `BOTH`
+    Both,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AdsOnDeliveryRestrictions {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AdsOnDeliveryRestrictions::None => "NONE",
+            AdsOnDeliveryRestrictions::Restricted => "RESTRICTED",
+            AdsOnDeliveryRestrictions::Unrestricted => "UNRESTRICTED",
+            AdsOnDeliveryRestrictions::Both => "BOTH",
+            AdsOnDeliveryRestrictions::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AdsOnDeliveryRestrictions {
+    fn default() -> AdsOnDeliveryRestrictions {
+        AdsOnDeliveryRestrictions::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AdsOnDeliveryRestrictions {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AdsOnDeliveryRestrictions {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AdsOnDeliveryRestrictions {
+    fn from(value: &'a str) -> AdsOnDeliveryRestrictions {
+        match value {
+            "NONE" => AdsOnDeliveryRestrictions::None,
+            "RESTRICTED" => AdsOnDeliveryRestrictions::Restricted,
+            "UNRESTRICTED" => AdsOnDeliveryRestrictions::Unrestricted,
+            "BOTH" => AdsOnDeliveryRestrictions::Both,
+            _ => AdsOnDeliveryRestrictions::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AdsOnDeliveryRestrictions {
+    fn from(value: String) -> AdsOnDeliveryRestrictions {
+        match value.as_str() {
+            "NONE" => AdsOnDeliveryRestrictions::None,
+            "RESTRICTED" => AdsOnDeliveryRestrictions::Restricted,
+            "UNRESTRICTED" => AdsOnDeliveryRestrictions::Unrestricted,
+            "BOTH" => AdsOnDeliveryRestrictions::Both,
+            _ => AdsOnDeliveryRestrictions::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AdsOnDeliveryRestrictions {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AdsOnDeliveryRestrictions, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AdsOnDeliveryRestrictions {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AdsOnDeliveryRestrictions {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AdsOnDeliveryRestrictions {
+    fn deserialize<D>(deserializer: D) -> Result<AdsOnDeliveryRestrictions, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>CDN Authorization credentials</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Authorization {
@@ -224,7 +438,7 @@ pub struct CreateHarvestJobResponse {
     /// include an explanation of why the HarvestJob failed.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<Status>,
 }
 
 /// <p>Configuration parameters used to create a new OriginEndpoint.</p>
@@ -267,7 +481,7 @@ pub struct CreateOriginEndpointRequest {
     /// requested. This can be helpful for Live to VOD harvesting, or for temporarily disabling origination</p>
     #[serde(rename = "Origination")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub origination: Option<String>,
+    pub origination: Option<Origination>,
     /// <p>Maximum duration (seconds) of content to retain for startover playback.
     /// If not specified, startover playback will be disabled for the OriginEndpoint.</p>
     #[serde(rename = "StartoverWindowSeconds")]
@@ -330,7 +544,7 @@ pub struct CreateOriginEndpointResponse {
     /// requested. This can be helpful for Live to VOD harvesting, or for temporarily disabling origination</p>
     #[serde(rename = "Origination")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub origination: Option<String>,
+    pub origination: Option<Origination>,
     /// <p>Maximum duration (seconds) of content to retain for startover playback.
     /// If not specified, startover playback will be disabled for the OriginEndpoint.</p>
     #[serde(rename = "StartoverWindowSeconds")]
@@ -370,17 +584,17 @@ pub struct DashEncryption {
 pub struct DashPackage {
     #[serde(rename = "AdTriggers")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ad_triggers: Option<Vec<String>>,
+    pub ad_triggers: Option<Vec<AdTriggersElement>>,
     #[serde(rename = "AdsOnDeliveryRestrictions")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ads_on_delivery_restrictions: Option<String>,
+    pub ads_on_delivery_restrictions: Option<AdsOnDeliveryRestrictions>,
     #[serde(rename = "Encryption")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub encryption: Option<DashEncryption>,
     /// <p>Determines the position of some tags in the Media Presentation Description (MPD).  When set to FULL, elements like SegmentTemplate and ContentProtection are included in each Representation.  When set to COMPACT, duplicate elements are combined and presented at the AdaptationSet level.</p>
     #[serde(rename = "ManifestLayout")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub manifest_layout: Option<String>,
+    pub manifest_layout: Option<ManifestLayout>,
     /// <p>Time window (in seconds) contained in each manifest.</p>
     #[serde(rename = "ManifestWindowSeconds")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -399,11 +613,11 @@ pub struct DashPackage {
     /// the Channel source contains SCTE-35 ad markers.</p>
     #[serde(rename = "PeriodTriggers")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub period_triggers: Option<Vec<String>>,
+    pub period_triggers: Option<Vec<PeriodTriggersElement>>,
     /// <p>The Dynamic Adaptive Streaming over HTTP (DASH) profile type.  When set to &quot;HBBTV<em>1</em>5&quot;, HbbTV 1.5 compliant output is enabled.</p>
     #[serde(rename = "Profile")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub profile: Option<String>,
+    pub profile: Option<Profile>,
     /// <p>Duration (in seconds) of each segment. Actual segments will be
     /// rounded to the nearest multiple of the source segment duration.</p>
     #[serde(rename = "SegmentDurationSeconds")]
@@ -412,7 +626,7 @@ pub struct DashPackage {
     /// <p>Determines the type of SegmentTemplate included in the Media Presentation Description (MPD).  When set to NUMBER<em>WITH</em>TIMELINE, a full timeline is presented in each SegmentTemplate, with $Number$ media URLs.  When set to TIME<em>WITH</em>TIMELINE, a full timeline is presented in each SegmentTemplate, with $Time$ media URLs. When set to NUMBER<em>WITH</em>DURATION, only a duration is included in each SegmentTemplate, with $Number$ media URLs.</p>
     #[serde(rename = "SegmentTemplateFormat")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub segment_template_format: Option<String>,
+    pub segment_template_format: Option<SegmentTemplateFormat>,
     #[serde(rename = "StreamSelection")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub stream_selection: Option<StreamSelection>,
@@ -526,7 +740,7 @@ pub struct DescribeHarvestJobResponse {
     /// include an explanation of why the HarvestJob failed.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<Status>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -580,7 +794,7 @@ pub struct DescribeOriginEndpointResponse {
     /// requested. This can be helpful for Live to VOD harvesting, or for temporarily disabling origination</p>
     #[serde(rename = "Origination")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub origination: Option<String>,
+    pub origination: Option<Origination>,
     /// <p>Maximum duration (seconds) of content to retain for startover playback.
     /// If not specified, startover playback will be disabled for the OriginEndpoint.</p>
     #[serde(rename = "StartoverWindowSeconds")]
@@ -604,6 +818,99 @@ pub struct DescribeOriginEndpointResponse {
     pub whitelist: Option<Vec<String>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EncryptionMethod {
+    /// `AES_128`
+    Aes128,
+    /// `SAMPLE_AES`
+    SampleAes,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EncryptionMethod {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EncryptionMethod::Aes128 => "AES_128",
+            EncryptionMethod::SampleAes => "SAMPLE_AES",
+            EncryptionMethod::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EncryptionMethod {
+    fn default() -> EncryptionMethod {
+        EncryptionMethod::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EncryptionMethod {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EncryptionMethod {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EncryptionMethod {
+    fn from(value: &'a str) -> EncryptionMethod {
+        match value {
+            "AES_128" => EncryptionMethod::Aes128,
+            "SAMPLE_AES" => EncryptionMethod::SampleAes,
+            _ => EncryptionMethod::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EncryptionMethod {
+    fn from(value: String) -> EncryptionMethod {
+        match value.as_str() {
+            "AES_128" => EncryptionMethod::Aes128,
+            "SAMPLE_AES" => EncryptionMethod::SampleAes,
+            _ => EncryptionMethod::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EncryptionMethod {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EncryptionMethod, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EncryptionMethod {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EncryptionMethod {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EncryptionMethod {
+    fn deserialize<D>(deserializer: D) -> Result<EncryptionMethod, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A HarvestJob resource configuration</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -646,7 +953,7 @@ pub struct HarvestJob {
     /// include an explanation of why the HarvestJob failed.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<Status>,
 }
 
 /// <p>An HTTP Live Streaming (HLS) encryption configuration.</p>
@@ -660,7 +967,7 @@ pub struct HlsEncryption {
     /// <p>The encryption method to use.</p>
     #[serde(rename = "EncryptionMethod")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub encryption_method: Option<String>,
+    pub encryption_method: Option<EncryptionMethod>,
     /// <p>Interval (in seconds) between each encryption key rotation.</p>
     #[serde(rename = "KeyRotationIntervalSeconds")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -695,7 +1002,7 @@ pub struct HlsManifest {
     /// messages in the input source.</p>
     #[serde(rename = "AdMarkers")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ad_markers: Option<String>,
+    pub ad_markers: Option<AdMarkers>,
     /// <p>The ID of the manifest. The ID must be unique within the OriginEndpoint and it cannot be changed after it is created.</p>
     #[serde(rename = "Id")]
     pub id: String,
@@ -712,7 +1019,7 @@ pub struct HlsManifest {
     /// entry will be included in the media playlist.</p>
     #[serde(rename = "PlaylistType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub playlist_type: Option<String>,
+    pub playlist_type: Option<PlaylistType>,
     /// <p>Time window (in seconds) contained in each parent manifest.</p>
     #[serde(rename = "PlaylistWindowSeconds")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -747,13 +1054,13 @@ pub struct HlsManifestCreateOrUpdateParameters {
     /// messages in the input source.</p>
     #[serde(rename = "AdMarkers")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ad_markers: Option<String>,
+    pub ad_markers: Option<AdMarkers>,
     #[serde(rename = "AdTriggers")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ad_triggers: Option<Vec<String>>,
+    pub ad_triggers: Option<Vec<AdTriggersElement>>,
     #[serde(rename = "AdsOnDeliveryRestrictions")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ads_on_delivery_restrictions: Option<String>,
+    pub ads_on_delivery_restrictions: Option<AdsOnDeliveryRestrictions>,
     /// <p>The ID of the manifest. The ID must be unique within the OriginEndpoint and it cannot be changed after it is created.</p>
     #[serde(rename = "Id")]
     pub id: String,
@@ -770,7 +1077,7 @@ pub struct HlsManifestCreateOrUpdateParameters {
     /// entry will be included in the media playlist.</p>
     #[serde(rename = "PlaylistType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub playlist_type: Option<String>,
+    pub playlist_type: Option<PlaylistType>,
     /// <p>Time window (in seconds) contained in each parent manifest.</p>
     #[serde(rename = "PlaylistWindowSeconds")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -800,13 +1107,13 @@ pub struct HlsPackage {
     /// messages in the input source.</p>
     #[serde(rename = "AdMarkers")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ad_markers: Option<String>,
+    pub ad_markers: Option<AdMarkers>,
     #[serde(rename = "AdTriggers")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ad_triggers: Option<Vec<String>>,
+    pub ad_triggers: Option<Vec<AdTriggersElement>>,
     #[serde(rename = "AdsOnDeliveryRestrictions")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ads_on_delivery_restrictions: Option<String>,
+    pub ads_on_delivery_restrictions: Option<AdsOnDeliveryRestrictions>,
     #[serde(rename = "Encryption")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub encryption: Option<HlsEncryption>,
@@ -819,7 +1126,7 @@ pub struct HlsPackage {
     /// entry will be included in the media playlist.</p>
     #[serde(rename = "PlaylistType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub playlist_type: Option<String>,
+    pub playlist_type: Option<PlaylistType>,
     /// <p>Time window (in seconds) contained in each parent manifest.</p>
     #[serde(rename = "PlaylistWindowSeconds")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -977,6 +1284,104 @@ pub struct ListTagsForResourceResponse {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ManifestLayout {
+    /// `FULL`
+    Full,
+    /// `COMPACT`
+    Compact,
+    /// `DRM_TOP_LEVEL_COMPACT`
+    DrmTopLevelCompact,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ManifestLayout {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ManifestLayout::Full => "FULL",
+            ManifestLayout::Compact => "COMPACT",
+            ManifestLayout::DrmTopLevelCompact => "DRM_TOP_LEVEL_COMPACT",
+            ManifestLayout::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ManifestLayout {
+    fn default() -> ManifestLayout {
+        ManifestLayout::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ManifestLayout {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ManifestLayout {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ManifestLayout {
+    fn from(value: &'a str) -> ManifestLayout {
+        match value {
+            "FULL" => ManifestLayout::Full,
+            "COMPACT" => ManifestLayout::Compact,
+            "DRM_TOP_LEVEL_COMPACT" => ManifestLayout::DrmTopLevelCompact,
+            _ => ManifestLayout::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ManifestLayout {
+    fn from(value: String) -> ManifestLayout {
+        match value.as_str() {
+            "FULL" => ManifestLayout::Full,
+            "COMPACT" => ManifestLayout::Compact,
+            "DRM_TOP_LEVEL_COMPACT" => ManifestLayout::DrmTopLevelCompact,
+            _ => ManifestLayout::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ManifestLayout {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ManifestLayout, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ManifestLayout {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ManifestLayout {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ManifestLayout {
+    fn deserialize<D>(deserializer: D) -> Result<ManifestLayout, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A Microsoft Smooth Streaming (MSS) encryption configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MssEncryption {
@@ -1047,7 +1452,7 @@ pub struct OriginEndpoint {
     /// requested. This can be helpful for Live to VOD harvesting, or for temporarily disabling origination</p>
     #[serde(rename = "Origination")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub origination: Option<String>,
+    pub origination: Option<Origination>,
     /// <p>Maximum duration (seconds) of content to retain for startover playback.
     /// If not specified, startover playback will be disabled for the OriginEndpoint.</p>
     #[serde(rename = "StartoverWindowSeconds")]
@@ -1071,6 +1476,300 @@ pub struct OriginEndpoint {
     pub whitelist: Option<Vec<String>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Origination {
+    /// `ALLOW`
+    Allow,
+    /// `DENY`
+    Deny,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Origination {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Origination::Allow => "ALLOW",
+            Origination::Deny => "DENY",
+            Origination::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Origination {
+    fn default() -> Origination {
+        Origination::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Origination {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Origination {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Origination {
+    fn from(value: &'a str) -> Origination {
+        match value {
+            "ALLOW" => Origination::Allow,
+            "DENY" => Origination::Deny,
+            _ => Origination::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Origination {
+    fn from(value: String) -> Origination {
+        match value.as_str() {
+            "ALLOW" => Origination::Allow,
+            "DENY" => Origination::Deny,
+            _ => Origination::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Origination {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Origination, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Origination {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Origination {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Origination {
+    fn deserialize<D>(deserializer: D) -> Result<Origination, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PlaylistType {
+    /// `NONE`
+    None,
+    /// `EVENT`
+    Event,
+    /// `VOD`
+    Vod,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PlaylistType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PlaylistType::None => "NONE",
+            PlaylistType::Event => "EVENT",
+            PlaylistType::Vod => "VOD",
+            PlaylistType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PlaylistType {
+    fn default() -> PlaylistType {
+        PlaylistType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PlaylistType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PlaylistType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PlaylistType {
+    fn from(value: &'a str) -> PlaylistType {
+        match value {
+            "NONE" => PlaylistType::None,
+            "EVENT" => PlaylistType::Event,
+            "VOD" => PlaylistType::Vod,
+            _ => PlaylistType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PlaylistType {
+    fn from(value: String) -> PlaylistType {
+        match value.as_str() {
+            "NONE" => PlaylistType::None,
+            "EVENT" => PlaylistType::Event,
+            "VOD" => PlaylistType::Vod,
+            _ => PlaylistType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PlaylistType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PlaylistType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PlaylistType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PlaylistType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PlaylistType {
+    fn deserialize<D>(deserializer: D) -> Result<PlaylistType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Profile {
+    /// `NONE`
+    None,
+    /// `HBBTV_1_5`
+    Hbbtv1_5,
+    /// `HYBRIDCAST`
+    Hybridcast,
+    /// `DVB_DASH_2014`
+    DvbDash2014,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Profile {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Profile::None => "NONE",
+            Profile::Hbbtv1_5 => "HBBTV_1_5",
+            Profile::Hybridcast => "HYBRIDCAST",
+            Profile::DvbDash2014 => "DVB_DASH_2014",
+            Profile::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Profile {
+    fn default() -> Profile {
+        Profile::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Profile {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Profile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Profile {
+    fn from(value: &'a str) -> Profile {
+        match value {
+            "NONE" => Profile::None,
+            "HBBTV_1_5" => Profile::Hbbtv1_5,
+            "HYBRIDCAST" => Profile::Hybridcast,
+            "DVB_DASH_2014" => Profile::DvbDash2014,
+            _ => Profile::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Profile {
+    fn from(value: String) -> Profile {
+        match value.as_str() {
+            "NONE" => Profile::None,
+            "HBBTV_1_5" => Profile::Hbbtv1_5,
+            "HYBRIDCAST" => Profile::Hybridcast,
+            "DVB_DASH_2014" => Profile::DvbDash2014,
+            _ => Profile::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Profile {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Profile, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Profile {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Profile {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Profile {
+    fn deserialize<D>(deserializer: D) -> Result<Profile, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct RotateChannelCredentialsRequest {
@@ -1150,6 +1849,104 @@ pub struct S3Destination {
     pub role_arn: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SegmentTemplateFormat {
+    /// `NUMBER_WITH_TIMELINE`
+    NumberWithTimeline,
+    /// `TIME_WITH_TIMELINE`
+    TimeWithTimeline,
+    /// `NUMBER_WITH_DURATION`
+    NumberWithDuration,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SegmentTemplateFormat {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SegmentTemplateFormat::NumberWithTimeline => "NUMBER_WITH_TIMELINE",
+            SegmentTemplateFormat::TimeWithTimeline => "TIME_WITH_TIMELINE",
+            SegmentTemplateFormat::NumberWithDuration => "NUMBER_WITH_DURATION",
+            SegmentTemplateFormat::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SegmentTemplateFormat {
+    fn default() -> SegmentTemplateFormat {
+        SegmentTemplateFormat::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SegmentTemplateFormat {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SegmentTemplateFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SegmentTemplateFormat {
+    fn from(value: &'a str) -> SegmentTemplateFormat {
+        match value {
+            "NUMBER_WITH_TIMELINE" => SegmentTemplateFormat::NumberWithTimeline,
+            "TIME_WITH_TIMELINE" => SegmentTemplateFormat::TimeWithTimeline,
+            "NUMBER_WITH_DURATION" => SegmentTemplateFormat::NumberWithDuration,
+            _ => SegmentTemplateFormat::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SegmentTemplateFormat {
+    fn from(value: String) -> SegmentTemplateFormat {
+        match value.as_str() {
+            "NUMBER_WITH_TIMELINE" => SegmentTemplateFormat::NumberWithTimeline,
+            "TIME_WITH_TIMELINE" => SegmentTemplateFormat::TimeWithTimeline,
+            "NUMBER_WITH_DURATION" => SegmentTemplateFormat::NumberWithDuration,
+            _ => SegmentTemplateFormat::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SegmentTemplateFormat {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SegmentTemplateFormat, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SegmentTemplateFormat {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SegmentTemplateFormat {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SegmentTemplateFormat {
+    fn deserialize<D>(deserializer: D) -> Result<SegmentTemplateFormat, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A configuration for accessing an external Secure Packager and Encoder Key Exchange (SPEKE) service that will provide encryption keys.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SpekeKeyProvider {
@@ -1174,6 +1971,202 @@ pub struct SpekeKeyProvider {
     pub url: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Status {
+    /// `IN_PROGRESS`
+    InProgress,
+    /// `SUCCEEDED`
+    Succeeded,
+    /// `FAILED`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Status {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Status::InProgress => "IN_PROGRESS",
+            Status::Succeeded => "SUCCEEDED",
+            Status::Failed => "FAILED",
+            Status::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Status {
+    fn default() -> Status {
+        Status::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Status {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Status {
+    fn from(value: &'a str) -> Status {
+        match value {
+            "IN_PROGRESS" => Status::InProgress,
+            "SUCCEEDED" => Status::Succeeded,
+            "FAILED" => Status::Failed,
+            _ => Status::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Status {
+    fn from(value: String) -> Status {
+        match value.as_str() {
+            "IN_PROGRESS" => Status::InProgress,
+            "SUCCEEDED" => Status::Succeeded,
+            "FAILED" => Status::Failed,
+            _ => Status::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Status {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Status, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Status {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Status {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Status {
+    fn deserialize<D>(deserializer: D) -> Result<Status, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StreamOrder {
+    /// `ORIGINAL`
+    Original,
+    /// `VIDEO_BITRATE_ASCENDING`
+    VideoBitrateAscending,
+    /// `VIDEO_BITRATE_DESCENDING`
+    VideoBitrateDescending,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl StreamOrder {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StreamOrder::Original => "ORIGINAL",
+            StreamOrder::VideoBitrateAscending => "VIDEO_BITRATE_ASCENDING",
+            StreamOrder::VideoBitrateDescending => "VIDEO_BITRATE_DESCENDING",
+            StreamOrder::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for StreamOrder {
+    fn default() -> StreamOrder {
+        StreamOrder::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for StreamOrder {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for StreamOrder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for StreamOrder {
+    fn from(value: &'a str) -> StreamOrder {
+        match value {
+            "ORIGINAL" => StreamOrder::Original,
+            "VIDEO_BITRATE_ASCENDING" => StreamOrder::VideoBitrateAscending,
+            "VIDEO_BITRATE_DESCENDING" => StreamOrder::VideoBitrateDescending,
+            _ => StreamOrder::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StreamOrder {
+    fn from(value: String) -> StreamOrder {
+        match value.as_str() {
+            "ORIGINAL" => StreamOrder::Original,
+            "VIDEO_BITRATE_ASCENDING" => StreamOrder::VideoBitrateAscending,
+            "VIDEO_BITRATE_DESCENDING" => StreamOrder::VideoBitrateDescending,
+            _ => StreamOrder::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for StreamOrder {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<StreamOrder, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StreamOrder {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for StreamOrder {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StreamOrder {
+    fn deserialize<D>(deserializer: D) -> Result<StreamOrder, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A StreamSelection configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct StreamSelection {
@@ -1188,7 +2181,7 @@ pub struct StreamSelection {
     /// <p>A directive that determines the order of streams in the output.</p>
     #[serde(rename = "StreamOrder")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stream_order: Option<String>,
+    pub stream_order: Option<StreamOrder>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -1281,7 +2274,7 @@ pub struct UpdateOriginEndpointRequest {
     /// requested. This can be helpful for Live to VOD harvesting, or for temporarily disabling origination</p>
     #[serde(rename = "Origination")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub origination: Option<String>,
+    pub origination: Option<Origination>,
     /// <p>Maximum duration (in seconds) of content to retain for startover playback.
     /// If not specified, startover playback will be disabled for the OriginEndpoint.</p>
     #[serde(rename = "StartoverWindowSeconds")]
@@ -1341,7 +2334,7 @@ pub struct UpdateOriginEndpointResponse {
     /// requested. This can be helpful for Live to VOD harvesting, or for temporarily disabling origination</p>
     #[serde(rename = "Origination")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub origination: Option<String>,
+    pub origination: Option<Origination>,
     /// <p>Maximum duration (seconds) of content to retain for startover playback.
     /// If not specified, startover playback will be disabled for the OriginEndpoint.</p>
     #[serde(rename = "StartoverWindowSeconds")]
@@ -1365,6 +2358,235 @@ pub struct UpdateOriginEndpointResponse {
     pub whitelist: Option<Vec<String>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AdTriggersElement {
+    /// `SPLICE_INSERT`
+    SpliceInsert,
+    /// `BREAK`
+    Break,
+    /// `PROVIDER_ADVERTISEMENT`
+    ProviderAdvertisement,
+    /// `DISTRIBUTOR_ADVERTISEMENT`
+    DistributorAdvertisement,
+    /// `PROVIDER_PLACEMENT_OPPORTUNITY`
+    ProviderPlacementOpportunity,
+    /// `DISTRIBUTOR_PLACEMENT_OPPORTUNITY`
+    DistributorPlacementOpportunity,
+    /// `PROVIDER_OVERLAY_PLACEMENT_OPPORTUNITY`
+    ProviderOverlayPlacementOpportunity,
+    /// `DISTRIBUTOR_OVERLAY_PLACEMENT_OPPORTUNITY`
+    DistributorOverlayPlacementOpportunity,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AdTriggersElement {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AdTriggersElement::SpliceInsert => "SPLICE_INSERT",
+            AdTriggersElement::Break => "BREAK",
+            AdTriggersElement::ProviderAdvertisement => "PROVIDER_ADVERTISEMENT",
+            AdTriggersElement::DistributorAdvertisement => "DISTRIBUTOR_ADVERTISEMENT",
+            AdTriggersElement::ProviderPlacementOpportunity => "PROVIDER_PLACEMENT_OPPORTUNITY",
+            AdTriggersElement::DistributorPlacementOpportunity => {
+                "DISTRIBUTOR_PLACEMENT_OPPORTUNITY"
+            }
+            AdTriggersElement::ProviderOverlayPlacementOpportunity => {
+                "PROVIDER_OVERLAY_PLACEMENT_OPPORTUNITY"
+            }
+            AdTriggersElement::DistributorOverlayPlacementOpportunity => {
+                "DISTRIBUTOR_OVERLAY_PLACEMENT_OPPORTUNITY"
+            }
+            AdTriggersElement::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AdTriggersElement {
+    fn default() -> AdTriggersElement {
+        AdTriggersElement::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AdTriggersElement {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AdTriggersElement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AdTriggersElement {
+    fn from(value: &'a str) -> AdTriggersElement {
+        match value {
+            "SPLICE_INSERT" => AdTriggersElement::SpliceInsert,
+            "BREAK" => AdTriggersElement::Break,
+            "PROVIDER_ADVERTISEMENT" => AdTriggersElement::ProviderAdvertisement,
+            "DISTRIBUTOR_ADVERTISEMENT" => AdTriggersElement::DistributorAdvertisement,
+            "PROVIDER_PLACEMENT_OPPORTUNITY" => AdTriggersElement::ProviderPlacementOpportunity,
+            "DISTRIBUTOR_PLACEMENT_OPPORTUNITY" => {
+                AdTriggersElement::DistributorPlacementOpportunity
+            }
+            "PROVIDER_OVERLAY_PLACEMENT_OPPORTUNITY" => {
+                AdTriggersElement::ProviderOverlayPlacementOpportunity
+            }
+            "DISTRIBUTOR_OVERLAY_PLACEMENT_OPPORTUNITY" => {
+                AdTriggersElement::DistributorOverlayPlacementOpportunity
+            }
+            _ => AdTriggersElement::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AdTriggersElement {
+    fn from(value: String) -> AdTriggersElement {
+        match value.as_str() {
+            "SPLICE_INSERT" => AdTriggersElement::SpliceInsert,
+            "BREAK" => AdTriggersElement::Break,
+            "PROVIDER_ADVERTISEMENT" => AdTriggersElement::ProviderAdvertisement,
+            "DISTRIBUTOR_ADVERTISEMENT" => AdTriggersElement::DistributorAdvertisement,
+            "PROVIDER_PLACEMENT_OPPORTUNITY" => AdTriggersElement::ProviderPlacementOpportunity,
+            "DISTRIBUTOR_PLACEMENT_OPPORTUNITY" => {
+                AdTriggersElement::DistributorPlacementOpportunity
+            }
+            "PROVIDER_OVERLAY_PLACEMENT_OPPORTUNITY" => {
+                AdTriggersElement::ProviderOverlayPlacementOpportunity
+            }
+            "DISTRIBUTOR_OVERLAY_PLACEMENT_OPPORTUNITY" => {
+                AdTriggersElement::DistributorOverlayPlacementOpportunity
+            }
+            _ => AdTriggersElement::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AdTriggersElement {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AdTriggersElement, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AdTriggersElement {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AdTriggersElement {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AdTriggersElement {
+    fn deserialize<D>(deserializer: D) -> Result<AdTriggersElement, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PeriodTriggersElement {
+    /// `ADS`
+    Ads,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PeriodTriggersElement {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PeriodTriggersElement::Ads => "ADS",
+            PeriodTriggersElement::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PeriodTriggersElement {
+    fn default() -> PeriodTriggersElement {
+        PeriodTriggersElement::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PeriodTriggersElement {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PeriodTriggersElement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PeriodTriggersElement {
+    fn from(value: &'a str) -> PeriodTriggersElement {
+        match value {
+            "ADS" => PeriodTriggersElement::Ads,
+            _ => PeriodTriggersElement::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PeriodTriggersElement {
+    fn from(value: String) -> PeriodTriggersElement {
+        match value.as_str() {
+            "ADS" => PeriodTriggersElement::Ads,
+            _ => PeriodTriggersElement::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PeriodTriggersElement {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PeriodTriggersElement, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PeriodTriggersElement {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PeriodTriggersElement {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PeriodTriggersElement {
+    fn deserialize<D>(deserializer: D) -> Result<PeriodTriggersElement, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// Errors returned by CreateChannel
 #[derive(Debug, PartialEq)]
 pub enum CreateChannelError {
@@ -2530,11 +3752,12 @@ pub struct MediaPackageClient {
 impl MediaPackageClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> MediaPackageClient {
         MediaPackageClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "MediaPackage"),
         }
     }
 
@@ -2568,6 +3791,7 @@ impl MediaPackage for MediaPackageClient {
         let request_uri = "/channels";
 
         let mut request = SignedRequest::new("POST", "mediapackage", &self.region, &request_uri);
+        request.set_operation("CreateChannel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2598,6 +3822,7 @@ impl MediaPackage for MediaPackageClient {
         let request_uri = "/harvest_jobs";
 
         let mut request = SignedRequest::new("POST", "mediapackage", &self.region, &request_uri);
+        request.set_operation("CreateHarvestJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2628,6 +3853,7 @@ impl MediaPackage for MediaPackageClient {
         let request_uri = "/origin_endpoints";
 
         let mut request = SignedRequest::new("POST", "mediapackage", &self.region, &request_uri);
+        request.set_operation("CreateOriginEndpoint");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2658,6 +3884,7 @@ impl MediaPackage for MediaPackageClient {
         let request_uri = format!("/channels/{id}", id = input.id);
 
         let mut request = SignedRequest::new("DELETE", "mediapackage", &self.region, &request_uri);
+        request.set_operation("DeleteChannel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2685,6 +3912,7 @@ impl MediaPackage for MediaPackageClient {
         let request_uri = format!("/origin_endpoints/{id}", id = input.id);
 
         let mut request = SignedRequest::new("DELETE", "mediapackage", &self.region, &request_uri);
+        request.set_operation("DeleteOriginEndpoint");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2712,6 +3940,7 @@ impl MediaPackage for MediaPackageClient {
         let request_uri = format!("/channels/{id}", id = input.id);
 
         let mut request = SignedRequest::new("GET", "mediapackage", &self.region, &request_uri);
+        request.set_operation("DescribeChannel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2739,6 +3968,7 @@ impl MediaPackage for MediaPackageClient {
         let request_uri = format!("/harvest_jobs/{id}", id = input.id);
 
         let mut request = SignedRequest::new("GET", "mediapackage", &self.region, &request_uri);
+        request.set_operation("DescribeHarvestJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2766,6 +3996,7 @@ impl MediaPackage for MediaPackageClient {
         let request_uri = format!("/origin_endpoints/{id}", id = input.id);
 
         let mut request = SignedRequest::new("GET", "mediapackage", &self.region, &request_uri);
+        request.set_operation("DescribeOriginEndpoint");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2793,6 +4024,7 @@ impl MediaPackage for MediaPackageClient {
         let request_uri = "/channels";
 
         let mut request = SignedRequest::new("GET", "mediapackage", &self.region, &request_uri);
+        request.set_operation("ListChannels");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2829,6 +4061,7 @@ impl MediaPackage for MediaPackageClient {
         let request_uri = "/harvest_jobs";
 
         let mut request = SignedRequest::new("GET", "mediapackage", &self.region, &request_uri);
+        request.set_operation("ListHarvestJobs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2871,6 +4104,7 @@ impl MediaPackage for MediaPackageClient {
         let request_uri = "/origin_endpoints";
 
         let mut request = SignedRequest::new("GET", "mediapackage", &self.region, &request_uri);
+        request.set_operation("ListOriginEndpoints");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2909,6 +4143,7 @@ impl MediaPackage for MediaPackageClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "mediapackage", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2936,6 +4171,7 @@ impl MediaPackage for MediaPackageClient {
         let request_uri = format!("/channels/{id}/credentials", id = input.id);
 
         let mut request = SignedRequest::new("PUT", "mediapackage", &self.region, &request_uri);
+        request.set_operation("RotateChannelCredentials");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2970,6 +4206,7 @@ impl MediaPackage for MediaPackageClient {
         );
 
         let mut request = SignedRequest::new("PUT", "mediapackage", &self.region, &request_uri);
+        request.set_operation("RotateIngestEndpointCredentials");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2998,6 +4235,7 @@ impl MediaPackage for MediaPackageClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "mediapackage", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3026,6 +4264,7 @@ impl MediaPackage for MediaPackageClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "mediapackage", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3058,6 +4297,7 @@ impl MediaPackage for MediaPackageClient {
         let request_uri = format!("/channels/{id}", id = input.id);
 
         let mut request = SignedRequest::new("PUT", "mediapackage", &self.region, &request_uri);
+        request.set_operation("UpdateChannel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3088,6 +4328,7 @@ impl MediaPackage for MediaPackageClient {
         let request_uri = format!("/origin_endpoints/{id}", id = input.id);
 
         let mut request = SignedRequest::new("PUT", "mediapackage", &self.region, &request_uri);
+        request.set_operation("UpdateOriginEndpoint");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3110,3 +4351,98 @@ impl MediaPackage for MediaPackageClient {
         }
     }
 }
+impl MediaPackageClient {
+    /// Auto-paginating version of `list_channels`, streaming every page of results.
+    pub fn list_channels_pages(
+        &self,
+        input: ListChannelsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListChannelsResponse, ListChannelsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MediaPackageClient, input: ListChannelsRequest| async move {
+                client.list_channels(input).await
+            },
+            |input: &ListChannelsRequest, output: &ListChannelsResponse| {
+                Some(ListChannelsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_channels`, streaming the `Channels` of every page of results.
+    pub fn list_channels_items(
+        &self,
+        input: ListChannelsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Channel, ListChannelsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_channels_pages(input),
+            |output: ListChannelsResponse| output.channels.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_harvest_jobs`, streaming every page of results.
+    pub fn list_harvest_jobs_pages(
+        &self,
+        input: ListHarvestJobsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListHarvestJobsResponse, ListHarvestJobsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MediaPackageClient, input: ListHarvestJobsRequest| async move {
+                client.list_harvest_jobs(input).await
+            },
+            |input: &ListHarvestJobsRequest, output: &ListHarvestJobsResponse| {
+                Some(ListHarvestJobsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_harvest_jobs`, streaming the `HarvestJobs` of every page of results.
+    pub fn list_harvest_jobs_items(
+        &self,
+        input: ListHarvestJobsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<HarvestJob, ListHarvestJobsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_harvest_jobs_pages(input),
+            |output: ListHarvestJobsResponse| output.harvest_jobs.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_origin_endpoints`, streaming every page of results.
+    pub fn list_origin_endpoints_pages(
+        &self,
+        input: ListOriginEndpointsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListOriginEndpointsResponse, ListOriginEndpointsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MediaPackageClient, input: ListOriginEndpointsRequest| async move {
+                client.list_origin_endpoints(input).await
+            },
+            |input: &ListOriginEndpointsRequest, output: &ListOriginEndpointsResponse| {
+                Some(ListOriginEndpointsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_origin_endpoints`, streaming the `OriginEndpoints` of every page of results.
+    pub fn list_origin_endpoints_items(
+        &self,
+        input: ListOriginEndpointsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<OriginEndpoint, ListOriginEndpointsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_origin_endpoints_pages(input),
+            |output: ListOriginEndpointsResponse| output.origin_endpoints.unwrap_or_default(),
+        )
+    }
+}
@@ -38,8 +38,13 @@ pub struct Container {
     pub access_logging_enabled: Option<bool>,
     /// <p>Unix timestamp.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The DNS endpoint of the container. Use the endpoint to identify the specific container when sending requests to the data plane. The service assigns this value when the container is created. Once the value has been assigned, it does not change.</p>
     #[serde(rename = "Endpoint")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -51,9 +56,107 @@ pub struct Container {
     /// <p>The status of container creation or deletion. The status is one of the following: <code>CREATING</code>, <code>ACTIVE</code>, or <code>DELETING</code>. While the service is creating the container, the status is <code>CREATING</code>. When the endpoint is available, the status changes to <code>ACTIVE</code>.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ContainerStatus>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ContainerStatus {
+    /// `ACTIVE`
+    Active,
+    /// `CREATING`
+    Creating,
+    /// `DELETING`
+    Deleting,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ContainerStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ContainerStatus::Active => "ACTIVE",
+            ContainerStatus::Creating => "CREATING",
+            ContainerStatus::Deleting => "DELETING",
+            ContainerStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ContainerStatus {
+    fn default() -> ContainerStatus {
+        ContainerStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ContainerStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ContainerStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ContainerStatus {
+    fn from(value: &'a str) -> ContainerStatus {
+        match value {
+            "ACTIVE" => ContainerStatus::Active,
+            "CREATING" => ContainerStatus::Creating,
+            "DELETING" => ContainerStatus::Deleting,
+            _ => ContainerStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ContainerStatus {
+    fn from(value: String) -> ContainerStatus {
+        match value.as_str() {
+            "ACTIVE" => ContainerStatus::Active,
+            "CREATING" => ContainerStatus::Creating,
+            "DELETING" => ContainerStatus::Deleting,
+            _ => ContainerStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ContainerStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ContainerStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ContainerStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ContainerStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ContainerStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ContainerStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A rule for a CORS policy. You can add up to 100 rules to a CORS policy. If more than one rule applies, the service uses the first applicable rule listed.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CorsRule {
@@ -63,7 +166,7 @@ pub struct CorsRule {
     /// <p>Identifies an HTTP method that the origin that is specified in the rule is allowed to execute.</p> <p>Each CORS rule must contain at least one <code>AllowedMethods</code> and one <code>AllowedOrigins</code> element.</p>
     #[serde(rename = "AllowedMethods")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub allowed_methods: Option<Vec<String>>,
+    pub allowed_methods: Option<Vec<MethodName>>,
     /// <p>One or more response headers that you want users to be able to access from their applications (for example, from a JavaScript <code>XMLHttpRequest</code> object).</p> <p>Each CORS rule must have at least one <code>AllowedOrigins</code> element. The string value can include only one wildcard character (*), for example, http://*.example.com. Additionally, you can specify only one wildcard character to allow cross-origin access for all origins.</p>
     #[serde(rename = "AllowedOrigins")]
     pub allowed_origins: Vec<String>,
@@ -253,6 +356,109 @@ pub struct ListTagsForResourceOutput {
     pub tags: Option<Vec<Tag>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MethodName {
+    /// `PUT`
+    Put,
+    /// `GET`
+    Get,
+    /// `DELETE`
+    Delete,
+    /// `HEAD`
+    Head,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl MethodName {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MethodName::Put => "PUT",
+            MethodName::Get => "GET",
+            MethodName::Delete => "DELETE",
+            MethodName::Head => "HEAD",
+            MethodName::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for MethodName {
+    fn default() -> MethodName {
+        MethodName::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for MethodName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for MethodName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for MethodName {
+    fn from(value: &'a str) -> MethodName {
+        match value {
+            "PUT" => MethodName::Put,
+            "GET" => MethodName::Get,
+            "DELETE" => MethodName::Delete,
+            "HEAD" => MethodName::Head,
+            _ => MethodName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MethodName {
+    fn from(value: String) -> MethodName {
+        match value.as_str() {
+            "PUT" => MethodName::Put,
+            "GET" => MethodName::Get,
+            "DELETE" => MethodName::Delete,
+            "HEAD" => MethodName::Head,
+            _ => MethodName::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for MethodName {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<MethodName, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MethodName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for MethodName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MethodName {
+    fn deserialize<D>(deserializer: D) -> Result<MethodName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct PutContainerPolicyInput {
@@ -1305,11 +1511,12 @@ pub struct MediaStoreClient {
 impl MediaStoreClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> MediaStoreClient {
         MediaStoreClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "MediaStore"),
         }
     }
 
@@ -1341,6 +1548,7 @@ impl MediaStore for MediaStoreClient {
         input: CreateContainerInput,
     ) -> Result<CreateContainerOutput, RusotoError<CreateContainerError>> {
         let mut request = SignedRequest::new("POST", "mediastore", &self.region, "/");
+        request.set_operation("CreateContainer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MediaStore_20170901.CreateContainer");
@@ -1368,6 +1576,7 @@ impl MediaStore for MediaStoreClient {
         input: DeleteContainerInput,
     ) -> Result<DeleteContainerOutput, RusotoError<DeleteContainerError>> {
         let mut request = SignedRequest::new("POST", "mediastore", &self.region, "/");
+        request.set_operation("DeleteContainer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MediaStore_20170901.DeleteContainer");
@@ -1395,6 +1604,7 @@ impl MediaStore for MediaStoreClient {
         input: DeleteContainerPolicyInput,
     ) -> Result<DeleteContainerPolicyOutput, RusotoError<DeleteContainerPolicyError>> {
         let mut request = SignedRequest::new("POST", "mediastore", &self.region, "/");
+        request.set_operation("DeleteContainerPolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MediaStore_20170901.DeleteContainerPolicy");
@@ -1423,6 +1633,7 @@ impl MediaStore for MediaStoreClient {
         input: DeleteCorsPolicyInput,
     ) -> Result<DeleteCorsPolicyOutput, RusotoError<DeleteCorsPolicyError>> {
         let mut request = SignedRequest::new("POST", "mediastore", &self.region, "/");
+        request.set_operation("DeleteCorsPolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MediaStore_20170901.DeleteCorsPolicy");
@@ -1450,6 +1661,7 @@ impl MediaStore for MediaStoreClient {
         input: DeleteLifecyclePolicyInput,
     ) -> Result<DeleteLifecyclePolicyOutput, RusotoError<DeleteLifecyclePolicyError>> {
         let mut request = SignedRequest::new("POST", "mediastore", &self.region, "/");
+        request.set_operation("DeleteLifecyclePolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MediaStore_20170901.DeleteLifecyclePolicy");
@@ -1478,6 +1690,7 @@ impl MediaStore for MediaStoreClient {
         input: DescribeContainerInput,
     ) -> Result<DescribeContainerOutput, RusotoError<DescribeContainerError>> {
         let mut request = SignedRequest::new("POST", "mediastore", &self.region, "/");
+        request.set_operation("DescribeContainer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MediaStore_20170901.DescribeContainer");
@@ -1505,6 +1718,7 @@ impl MediaStore for MediaStoreClient {
         input: GetContainerPolicyInput,
     ) -> Result<GetContainerPolicyOutput, RusotoError<GetContainerPolicyError>> {
         let mut request = SignedRequest::new("POST", "mediastore", &self.region, "/");
+        request.set_operation("GetContainerPolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MediaStore_20170901.GetContainerPolicy");
@@ -1533,6 +1747,7 @@ impl MediaStore for MediaStoreClient {
         input: GetCorsPolicyInput,
     ) -> Result<GetCorsPolicyOutput, RusotoError<GetCorsPolicyError>> {
         let mut request = SignedRequest::new("POST", "mediastore", &self.region, "/");
+        request.set_operation("GetCorsPolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MediaStore_20170901.GetCorsPolicy");
@@ -1560,6 +1775,7 @@ impl MediaStore for MediaStoreClient {
         input: GetLifecyclePolicyInput,
     ) -> Result<GetLifecyclePolicyOutput, RusotoError<GetLifecyclePolicyError>> {
         let mut request = SignedRequest::new("POST", "mediastore", &self.region, "/");
+        request.set_operation("GetLifecyclePolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MediaStore_20170901.GetLifecyclePolicy");
@@ -1588,6 +1804,7 @@ impl MediaStore for MediaStoreClient {
         input: ListContainersInput,
     ) -> Result<ListContainersOutput, RusotoError<ListContainersError>> {
         let mut request = SignedRequest::new("POST", "mediastore", &self.region, "/");
+        request.set_operation("ListContainers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MediaStore_20170901.ListContainers");
@@ -1615,6 +1832,7 @@ impl MediaStore for MediaStoreClient {
         input: ListTagsForResourceInput,
     ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
         let mut request = SignedRequest::new("POST", "mediastore", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MediaStore_20170901.ListTagsForResource");
@@ -1643,6 +1861,7 @@ impl MediaStore for MediaStoreClient {
         input: PutContainerPolicyInput,
     ) -> Result<PutContainerPolicyOutput, RusotoError<PutContainerPolicyError>> {
         let mut request = SignedRequest::new("POST", "mediastore", &self.region, "/");
+        request.set_operation("PutContainerPolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MediaStore_20170901.PutContainerPolicy");
@@ -1671,6 +1890,7 @@ impl MediaStore for MediaStoreClient {
         input: PutCorsPolicyInput,
     ) -> Result<PutCorsPolicyOutput, RusotoError<PutCorsPolicyError>> {
         let mut request = SignedRequest::new("POST", "mediastore", &self.region, "/");
+        request.set_operation("PutCorsPolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MediaStore_20170901.PutCorsPolicy");
@@ -1698,6 +1918,7 @@ impl MediaStore for MediaStoreClient {
         input: PutLifecyclePolicyInput,
     ) -> Result<PutLifecyclePolicyOutput, RusotoError<PutLifecyclePolicyError>> {
         let mut request = SignedRequest::new("POST", "mediastore", &self.region, "/");
+        request.set_operation("PutLifecyclePolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MediaStore_20170901.PutLifecyclePolicy");
@@ -1726,6 +1947,7 @@ impl MediaStore for MediaStoreClient {
         input: StartAccessLoggingInput,
     ) -> Result<StartAccessLoggingOutput, RusotoError<StartAccessLoggingError>> {
         let mut request = SignedRequest::new("POST", "mediastore", &self.region, "/");
+        request.set_operation("StartAccessLogging");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MediaStore_20170901.StartAccessLogging");
@@ -1754,6 +1976,7 @@ impl MediaStore for MediaStoreClient {
         input: StopAccessLoggingInput,
     ) -> Result<StopAccessLoggingOutput, RusotoError<StopAccessLoggingError>> {
         let mut request = SignedRequest::new("POST", "mediastore", &self.region, "/");
+        request.set_operation("StopAccessLogging");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MediaStore_20170901.StopAccessLogging");
@@ -1781,6 +2004,7 @@ impl MediaStore for MediaStoreClient {
         input: TagResourceInput,
     ) -> Result<TagResourceOutput, RusotoError<TagResourceError>> {
         let mut request = SignedRequest::new("POST", "mediastore", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MediaStore_20170901.TagResource");
@@ -1808,6 +2032,7 @@ impl MediaStore for MediaStoreClient {
         input: UntagResourceInput,
     ) -> Result<UntagResourceOutput, RusotoError<UntagResourceError>> {
         let mut request = SignedRequest::new("POST", "mediastore", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MediaStore_20170901.UntagResource");
@@ -1829,3 +2054,35 @@ impl MediaStore for MediaStoreClient {
         }
     }
 }
+impl MediaStoreClient {
+    /// Auto-paginating version of `list_containers`, streaming every page of results.
+    pub fn list_containers_pages(
+        &self,
+        input: ListContainersInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListContainersOutput, ListContainersError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MediaStoreClient, input: ListContainersInput| async move {
+                client.list_containers(input).await
+            },
+            |input: &ListContainersInput, output: &ListContainersOutput| {
+                Some(ListContainersInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_containers`, streaming the `Containers` of every page of results.
+    pub fn list_containers_items(
+        &self,
+        input: ListContainersInput,
+    ) -> rusoto_core::pagination::RusotoStream<Container, ListContainersError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_containers_pages(input),
+            |output: ListContainersOutput| output.containers,
+        )
+    }
+}
@@ -53,7 +53,7 @@ pub struct DashConfiguration {
     /// <p>The setting that controls whether MediaTailor handles manifests from the origin server as multi-period manifests or single-period manifests. If your origin server produces single-period manifests, set this to SINGLE_PERIOD. The default setting is MULTI_PERIOD. For multi-period manifests, omit this setting or set it to MULTI_PERIOD. </p>
     #[serde(rename = "OriginManifestType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub origin_manifest_type: Option<String>,
+    pub origin_manifest_type: Option<OriginManifestType>,
 }
 
 /// <p>The configuration for DASH PUT operations. </p>
@@ -67,7 +67,7 @@ pub struct DashConfigurationForPut {
     /// <p>The setting that controls whether MediaTailor handles manifests from the origin server as multi-period manifests or single-period manifests. If your origin server produces single-period manifests, set this to SINGLE_PERIOD. The default setting is MULTI_PERIOD. For multi-period manifests, omit this setting or set it to MULTI_PERIOD. </p>
     #[serde(rename = "OriginManifestType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub origin_manifest_type: Option<String>,
+    pub origin_manifest_type: Option<OriginManifestType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -218,6 +218,99 @@ pub struct LivePreRollConfiguration {
     pub max_duration_seconds: Option<i64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OriginManifestType {
+    /// `SINGLE_PERIOD`
+    SinglePeriod,
+    /// `MULTI_PERIOD`
+    MultiPeriod,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl OriginManifestType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OriginManifestType::SinglePeriod => "SINGLE_PERIOD",
+            OriginManifestType::MultiPeriod => "MULTI_PERIOD",
+            OriginManifestType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for OriginManifestType {
+    fn default() -> OriginManifestType {
+        OriginManifestType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for OriginManifestType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for OriginManifestType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for OriginManifestType {
+    fn from(value: &'a str) -> OriginManifestType {
+        match value {
+            "SINGLE_PERIOD" => OriginManifestType::SinglePeriod,
+            "MULTI_PERIOD" => OriginManifestType::MultiPeriod,
+            _ => OriginManifestType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OriginManifestType {
+    fn from(value: String) -> OriginManifestType {
+        match value.as_str() {
+            "SINGLE_PERIOD" => OriginManifestType::SinglePeriod,
+            "MULTI_PERIOD" => OriginManifestType::MultiPeriod,
+            _ => OriginManifestType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for OriginManifestType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<OriginManifestType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OriginManifestType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for OriginManifestType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OriginManifestType {
+    fn deserialize<D>(deserializer: D) -> Result<OriginManifestType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The AWSMediaTailor configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -634,11 +727,12 @@ pub struct MediaTailorClient {
 impl MediaTailorClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> MediaTailorClient {
         MediaTailorClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "MediaTailor"),
         }
     }
 
@@ -673,6 +767,7 @@ impl MediaTailor for MediaTailorClient {
         let request_uri = format!("/playbackConfiguration/{name}", name = input.name);
 
         let mut request = SignedRequest::new("DELETE", "mediatailor", &self.region, &request_uri);
+        request.set_operation("DeletePlaybackConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.mediatailor".to_string());
@@ -702,6 +797,7 @@ impl MediaTailor for MediaTailorClient {
         let request_uri = format!("/playbackConfiguration/{name}", name = input.name);
 
         let mut request = SignedRequest::new("GET", "mediatailor", &self.region, &request_uri);
+        request.set_operation("GetPlaybackConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.mediatailor".to_string());
@@ -732,6 +828,7 @@ impl MediaTailor for MediaTailorClient {
         let request_uri = "/playbackConfigurations";
 
         let mut request = SignedRequest::new("GET", "mediatailor", &self.region, &request_uri);
+        request.set_operation("ListPlaybackConfigurations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.mediatailor".to_string());
@@ -770,6 +867,7 @@ impl MediaTailor for MediaTailorClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "mediatailor", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.mediatailor".to_string());
@@ -799,6 +897,7 @@ impl MediaTailor for MediaTailorClient {
         let request_uri = "/playbackConfiguration";
 
         let mut request = SignedRequest::new("PUT", "mediatailor", &self.region, &request_uri);
+        request.set_operation("PutPlaybackConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.mediatailor".to_string());
@@ -830,6 +929,7 @@ impl MediaTailor for MediaTailorClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "mediatailor", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.mediatailor".to_string());
@@ -860,6 +960,7 @@ impl MediaTailor for MediaTailorClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "mediatailor", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.mediatailor".to_string());
@@ -886,3 +987,40 @@ impl MediaTailor for MediaTailorClient {
         }
     }
 }
+impl MediaTailorClient {
+    /// Auto-paginating version of `list_playback_configurations`, streaming every page of results.
+    pub fn list_playback_configurations_pages(
+        &self,
+        input: ListPlaybackConfigurationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListPlaybackConfigurationsResponse,
+        ListPlaybackConfigurationsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MediaTailorClient, input: ListPlaybackConfigurationsRequest| async move {
+                client.list_playback_configurations(input).await
+            },
+            |input: &ListPlaybackConfigurationsRequest,
+             output: &ListPlaybackConfigurationsResponse| {
+                Some(ListPlaybackConfigurationsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_playback_configurations`, streaming the `Items` of every page of results.
+    pub fn list_playback_configurations_items(
+        &self,
+        input: ListPlaybackConfigurationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<PlaybackConfiguration, ListPlaybackConfigurationsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_playback_configurations_pages(input),
+            |output: ListPlaybackConfigurationsResponse| output.items.unwrap_or_default(),
+        )
+    }
+}
@@ -62,7 +62,12 @@ pub struct MeterUsageRequest {
     pub product_code: String,
     /// <p>Timestamp, in UTC, for which the usage is being reported. Your application can meter usage for up to one hour in the past. Make sure the timestamp value is not before the start of the software usage.</p>
     #[serde(rename = "Timestamp")]
-    pub timestamp: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub timestamp: ::chrono::DateTime<::chrono::Utc>,
     /// <p>It will be one of the fcp dimension name provided during the publishing of the product.</p>
     #[serde(rename = "UsageDimension")]
     pub usage_dimension: String,
@@ -101,8 +106,13 @@ pub struct RegisterUsageRequest {
 pub struct RegisterUsageResult {
     /// <p>(Optional) Only included when public key version has expired</p>
     #[serde(rename = "PublicKeyRotationTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub public_key_rotation_timestamp: Option<f64>,
+    pub public_key_rotation_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>JWT Token</p>
     #[serde(rename = "Signature")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -147,7 +157,12 @@ pub struct UsageRecord {
     pub quantity: Option<i64>,
     /// <p>Timestamp, in UTC, for which the usage is being reported.</p> <p>Your application can meter usage for up to one hour in the past. Make sure the timestamp value is not before the start of the software usage.</p>
     #[serde(rename = "Timestamp")]
-    pub timestamp: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub timestamp: ::chrono::DateTime<::chrono::Utc>,
 }
 
 /// <p>A UsageRecordResult indicates the status of a given UsageRecord processed by BatchMeterUsage.</p>
@@ -161,13 +176,111 @@ pub struct UsageRecordResult {
     /// <p><p>The UsageRecordResult Status indicates the status of an individual UsageRecord processed by BatchMeterUsage.</p> <ul> <li> <p> <i>Success</i>- The UsageRecord was accepted and honored by BatchMeterUsage.</p> </li> <li> <p> <i>CustomerNotSubscribed</i>- The CustomerIdentifier specified is not subscribed to your product. The UsageRecord was not honored. Future UsageRecords for this customer will fail until the customer subscribes to your product.</p> </li> <li> <p> <i>DuplicateRecord</i>- Indicates that the UsageRecord was invalid and not honored. A previously metered UsageRecord had the same customer, dimension, and time, but a different quantity.</p> </li> </ul></p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<UsageRecordResultStatus>,
     /// <p>The UsageRecord that was part of the BatchMeterUsage request.</p>
     #[serde(rename = "UsageRecord")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub usage_record: Option<UsageRecord>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum UsageRecordResultStatus {
+    /// `Success`
+    Success,
+    /// `CustomerNotSubscribed`
+    CustomerNotSubscribed,
+    /// `DuplicateRecord`
+    DuplicateRecord,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl UsageRecordResultStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            UsageRecordResultStatus::Success => "Success",
+            UsageRecordResultStatus::CustomerNotSubscribed => "CustomerNotSubscribed",
+            UsageRecordResultStatus::DuplicateRecord => "DuplicateRecord",
+            UsageRecordResultStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for UsageRecordResultStatus {
+    fn default() -> UsageRecordResultStatus {
+        UsageRecordResultStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for UsageRecordResultStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for UsageRecordResultStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for UsageRecordResultStatus {
+    fn from(value: &'a str) -> UsageRecordResultStatus {
+        match value {
+            "Success" => UsageRecordResultStatus::Success,
+            "CustomerNotSubscribed" => UsageRecordResultStatus::CustomerNotSubscribed,
+            "DuplicateRecord" => UsageRecordResultStatus::DuplicateRecord,
+            _ => UsageRecordResultStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for UsageRecordResultStatus {
+    fn from(value: String) -> UsageRecordResultStatus {
+        match value.as_str() {
+            "Success" => UsageRecordResultStatus::Success,
+            "CustomerNotSubscribed" => UsageRecordResultStatus::CustomerNotSubscribed,
+            "DuplicateRecord" => UsageRecordResultStatus::DuplicateRecord,
+            _ => UsageRecordResultStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for UsageRecordResultStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<UsageRecordResultStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for UsageRecordResultStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for UsageRecordResultStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for UsageRecordResultStatus {
+    fn deserialize<D>(deserializer: D) -> Result<UsageRecordResultStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// Errors returned by BatchMeterUsage
 #[derive(Debug, PartialEq)]
 pub enum BatchMeterUsageError {
@@ -481,11 +594,12 @@ pub struct MarketplaceMeteringClient {
 impl MarketplaceMeteringClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> MarketplaceMeteringClient {
         MarketplaceMeteringClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Marketplace Metering"),
         }
     }
 
@@ -517,6 +631,7 @@ impl MarketplaceMetering for MarketplaceMeteringClient {
         input: BatchMeterUsageRequest,
     ) -> Result<BatchMeterUsageResult, RusotoError<BatchMeterUsageError>> {
         let mut request = SignedRequest::new("POST", "aws-marketplace", &self.region, "/");
+        request.set_operation("BatchMeterUsage");
         request.set_endpoint_prefix("metering.marketplace".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSMPMeteringService.BatchMeterUsage");
@@ -544,6 +659,7 @@ impl MarketplaceMetering for MarketplaceMeteringClient {
         input: MeterUsageRequest,
     ) -> Result<MeterUsageResult, RusotoError<MeterUsageError>> {
         let mut request = SignedRequest::new("POST", "aws-marketplace", &self.region, "/");
+        request.set_operation("MeterUsage");
         request.set_endpoint_prefix("metering.marketplace".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSMPMeteringService.MeterUsage");
@@ -571,6 +687,7 @@ impl MarketplaceMetering for MarketplaceMeteringClient {
         input: RegisterUsageRequest,
     ) -> Result<RegisterUsageResult, RusotoError<RegisterUsageError>> {
         let mut request = SignedRequest::new("POST", "aws-marketplace", &self.region, "/");
+        request.set_operation("RegisterUsage");
         request.set_endpoint_prefix("metering.marketplace".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSMPMeteringService.RegisterUsage");
@@ -598,6 +715,7 @@ impl MarketplaceMetering for MarketplaceMeteringClient {
         input: ResolveCustomerRequest,
     ) -> Result<ResolveCustomerResult, RusotoError<ResolveCustomerError>> {
         let mut request = SignedRequest::new("POST", "aws-marketplace", &self.region, "/");
+        request.set_operation("ResolveCustomer");
         request.set_endpoint_prefix("metering.marketplace".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSMPMeteringService.ResolveCustomer");
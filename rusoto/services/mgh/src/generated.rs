@@ -35,13 +35,116 @@ pub struct ApplicationState {
     /// <p>The current status of an application.</p>
     #[serde(rename = "ApplicationStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub application_status: Option<String>,
+    pub application_status: Option<ApplicationStatus>,
     /// <p>The timestamp when the application status was last updated.</p>
     #[serde(rename = "LastUpdatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_time: Option<f64>,
+    pub last_updated_time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ApplicationStatus {
+    /// `NOT_STARTED`
+    NotStarted,
+    /// `IN_PROGRESS`
+    InProgress,
+    /// `COMPLETED`
+    Completed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ApplicationStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ApplicationStatus::NotStarted => "NOT_STARTED",
+            ApplicationStatus::InProgress => "IN_PROGRESS",
+            ApplicationStatus::Completed => "COMPLETED",
+            ApplicationStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ApplicationStatus {
+    fn default() -> ApplicationStatus {
+        ApplicationStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ApplicationStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ApplicationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ApplicationStatus {
+    fn from(value: &'a str) -> ApplicationStatus {
+        match value {
+            "NOT_STARTED" => ApplicationStatus::NotStarted,
+            "IN_PROGRESS" => ApplicationStatus::InProgress,
+            "COMPLETED" => ApplicationStatus::Completed,
+            _ => ApplicationStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ApplicationStatus {
+    fn from(value: String) -> ApplicationStatus {
+        match value.as_str() {
+            "NOT_STARTED" => ApplicationStatus::NotStarted,
+            "IN_PROGRESS" => ApplicationStatus::InProgress,
+            "COMPLETED" => ApplicationStatus::Completed,
+            _ => ApplicationStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ApplicationStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ApplicationStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ApplicationStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ApplicationStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ApplicationStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ApplicationStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct AssociateCreatedArtifactRequest {
@@ -144,11 +247,16 @@ pub struct DescribeApplicationStateResult {
     /// <p>Status of the application - Not Started, In-Progress, Complete.</p>
     #[serde(rename = "ApplicationStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub application_status: Option<String>,
+    pub application_status: Option<ApplicationStatus>,
     /// <p>The timestamp when the application status was last updated.</p>
     #[serde(rename = "LastUpdatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_time: Option<f64>,
+    pub last_updated_time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -418,8 +526,13 @@ pub struct MigrationTask {
     pub task: Option<Task>,
     /// <p>The timestamp when the task was gathered.</p>
     #[serde(rename = "UpdateDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub update_date_time: Option<f64>,
+    pub update_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>MigrationTaskSummary includes <code>MigrationTaskName</code>, <code>ProgressPercent</code>, <code>ProgressUpdateStream</code>, <code>Status</code>, and <code>UpdateDateTime</code> for each task.</p>
@@ -441,15 +554,20 @@ pub struct MigrationTaskSummary {
     /// <p>Status of the task.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<Status>,
     /// <p>Detail information of what is being done within the overall status state.</p>
     #[serde(rename = "StatusDetail")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status_detail: Option<String>,
     /// <p>The timestamp when the task was gathered.</p>
     #[serde(rename = "UpdateDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub update_date_time: Option<f64>,
+    pub update_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -464,11 +582,16 @@ pub struct NotifyApplicationStateRequest {
     pub dry_run: Option<bool>,
     /// <p>Status of the application - Not Started, In-Progress, Complete.</p>
     #[serde(rename = "Status")]
-    pub status: String,
+    pub status: ApplicationStatus,
     /// <p>The timestamp when the application state changed.</p>
     #[serde(rename = "UpdateDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub update_date_time: Option<f64>,
+    pub update_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -496,7 +619,12 @@ pub struct NotifyMigrationTaskStateRequest {
     pub task: Task,
     /// <p>The timestamp when the task was gathered.</p>
     #[serde(rename = "UpdateDateTime")]
-    pub update_date_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub update_date_time: ::chrono::DateTime<::chrono::Utc>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -540,12 +668,248 @@ pub struct PutResourceAttributesResult {}
 pub struct ResourceAttribute {
     /// <p>Type of resource.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: ResourceAttributeType,
     /// <p>Value of the resource type.</p>
     #[serde(rename = "Value")]
     pub value: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ResourceAttributeType {
+    /// `IPV4_ADDRESS`
+    Ipv4Address,
+    /// `IPV6_ADDRESS`
+    Ipv6Address,
+    /// `MAC_ADDRESS`
+    MacAddress,
+    /// `FQDN`
+    Fqdn,
+    /// `VM_MANAGER_ID`
+    VmManagerId,
+    /// `VM_MANAGED_OBJECT_REFERENCE`
+    VmManagedObjectReference,
+    /// `VM_NAME`
+    VmName,
+    /// `VM_PATH`
+    VmPath,
+    /// `BIOS_ID`
+    BiosId,
+    /// `MOTHERBOARD_SERIAL_NUMBER`
+    MotherboardSerialNumber,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ResourceAttributeType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceAttributeType::Ipv4Address => "IPV4_ADDRESS",
+            ResourceAttributeType::Ipv6Address => "IPV6_ADDRESS",
+            ResourceAttributeType::MacAddress => "MAC_ADDRESS",
+            ResourceAttributeType::Fqdn => "FQDN",
+            ResourceAttributeType::VmManagerId => "VM_MANAGER_ID",
+            ResourceAttributeType::VmManagedObjectReference => "VM_MANAGED_OBJECT_REFERENCE",
+            ResourceAttributeType::VmName => "VM_NAME",
+            ResourceAttributeType::VmPath => "VM_PATH",
+            ResourceAttributeType::BiosId => "BIOS_ID",
+            ResourceAttributeType::MotherboardSerialNumber => "MOTHERBOARD_SERIAL_NUMBER",
+            ResourceAttributeType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ResourceAttributeType {
+    fn default() -> ResourceAttributeType {
+        ResourceAttributeType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ResourceAttributeType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ResourceAttributeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ResourceAttributeType {
+    fn from(value: &'a str) -> ResourceAttributeType {
+        match value {
+            "IPV4_ADDRESS" => ResourceAttributeType::Ipv4Address,
+            "IPV6_ADDRESS" => ResourceAttributeType::Ipv6Address,
+            "MAC_ADDRESS" => ResourceAttributeType::MacAddress,
+            "FQDN" => ResourceAttributeType::Fqdn,
+            "VM_MANAGER_ID" => ResourceAttributeType::VmManagerId,
+            "VM_MANAGED_OBJECT_REFERENCE" => ResourceAttributeType::VmManagedObjectReference,
+            "VM_NAME" => ResourceAttributeType::VmName,
+            "VM_PATH" => ResourceAttributeType::VmPath,
+            "BIOS_ID" => ResourceAttributeType::BiosId,
+            "MOTHERBOARD_SERIAL_NUMBER" => ResourceAttributeType::MotherboardSerialNumber,
+            _ => ResourceAttributeType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceAttributeType {
+    fn from(value: String) -> ResourceAttributeType {
+        match value.as_str() {
+            "IPV4_ADDRESS" => ResourceAttributeType::Ipv4Address,
+            "IPV6_ADDRESS" => ResourceAttributeType::Ipv6Address,
+            "MAC_ADDRESS" => ResourceAttributeType::MacAddress,
+            "FQDN" => ResourceAttributeType::Fqdn,
+            "VM_MANAGER_ID" => ResourceAttributeType::VmManagerId,
+            "VM_MANAGED_OBJECT_REFERENCE" => ResourceAttributeType::VmManagedObjectReference,
+            "VM_NAME" => ResourceAttributeType::VmName,
+            "VM_PATH" => ResourceAttributeType::VmPath,
+            "BIOS_ID" => ResourceAttributeType::BiosId,
+            "MOTHERBOARD_SERIAL_NUMBER" => ResourceAttributeType::MotherboardSerialNumber,
+            _ => ResourceAttributeType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ResourceAttributeType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ResourceAttributeType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ResourceAttributeType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ResourceAttributeType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ResourceAttributeType {
+    fn deserialize<D>(deserializer: D) -> Result<ResourceAttributeType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Status {
+    /// `NOT_STARTED`
+    NotStarted,
+    /// `IN_PROGRESS`
+    InProgress,
+    /// `FAILED`
+    Failed,
+    /// `COMPLETED`
+    Completed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Status {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Status::NotStarted => "NOT_STARTED",
+            Status::InProgress => "IN_PROGRESS",
+            Status::Failed => "FAILED",
+            Status::Completed => "COMPLETED",
+            Status::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Status {
+    fn default() -> Status {
+        Status::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Status {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Status {
+    fn from(value: &'a str) -> Status {
+        match value {
+            "NOT_STARTED" => Status::NotStarted,
+            "IN_PROGRESS" => Status::InProgress,
+            "FAILED" => Status::Failed,
+            "COMPLETED" => Status::Completed,
+            _ => Status::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Status {
+    fn from(value: String) -> Status {
+        match value.as_str() {
+            "NOT_STARTED" => Status::NotStarted,
+            "IN_PROGRESS" => Status::InProgress,
+            "FAILED" => Status::Failed,
+            "COMPLETED" => Status::Completed,
+            _ => Status::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Status {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Status, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Status {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Status {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Status {
+    fn deserialize<D>(deserializer: D) -> Result<Status, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Task object encapsulating task information.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Task {
@@ -555,7 +919,7 @@ pub struct Task {
     pub progress_percent: Option<i64>,
     /// <p>Status of the task - Not Started, In-Progress, Complete.</p>
     #[serde(rename = "Status")]
-    pub status: String,
+    pub status: Status,
     /// <p>Details of task status as notified by a migration tool. A tool might use this field to provide clarifying information about the status that is unique to that tool or that explains an error state.</p>
     #[serde(rename = "StatusDetail")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2086,11 +2450,12 @@ pub struct MigrationHubClient {
 impl MigrationHubClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> MigrationHubClient {
         MigrationHubClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Migration Hub"),
         }
     }
 
@@ -2122,6 +2487,7 @@ impl MigrationHub for MigrationHubClient {
         input: AssociateCreatedArtifactRequest,
     ) -> Result<AssociateCreatedArtifactResult, RusotoError<AssociateCreatedArtifactError>> {
         let mut request = SignedRequest::new("POST", "mgh", &self.region, "/");
+        request.set_operation("AssociateCreatedArtifact");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSMigrationHub.AssociateCreatedArtifact");
@@ -2151,6 +2517,7 @@ impl MigrationHub for MigrationHubClient {
     ) -> Result<AssociateDiscoveredResourceResult, RusotoError<AssociateDiscoveredResourceError>>
     {
         let mut request = SignedRequest::new("POST", "mgh", &self.region, "/");
+        request.set_operation("AssociateDiscoveredResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2183,6 +2550,7 @@ impl MigrationHub for MigrationHubClient {
     ) -> Result<CreateProgressUpdateStreamResult, RusotoError<CreateProgressUpdateStreamError>>
     {
         let mut request = SignedRequest::new("POST", "mgh", &self.region, "/");
+        request.set_operation("CreateProgressUpdateStream");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSMigrationHub.CreateProgressUpdateStream");
@@ -2212,6 +2580,7 @@ impl MigrationHub for MigrationHubClient {
     ) -> Result<DeleteProgressUpdateStreamResult, RusotoError<DeleteProgressUpdateStreamError>>
     {
         let mut request = SignedRequest::new("POST", "mgh", &self.region, "/");
+        request.set_operation("DeleteProgressUpdateStream");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSMigrationHub.DeleteProgressUpdateStream");
@@ -2240,6 +2609,7 @@ impl MigrationHub for MigrationHubClient {
         input: DescribeApplicationStateRequest,
     ) -> Result<DescribeApplicationStateResult, RusotoError<DescribeApplicationStateError>> {
         let mut request = SignedRequest::new("POST", "mgh", &self.region, "/");
+        request.set_operation("DescribeApplicationState");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSMigrationHub.DescribeApplicationState");
@@ -2268,6 +2638,7 @@ impl MigrationHub for MigrationHubClient {
         input: DescribeMigrationTaskRequest,
     ) -> Result<DescribeMigrationTaskResult, RusotoError<DescribeMigrationTaskError>> {
         let mut request = SignedRequest::new("POST", "mgh", &self.region, "/");
+        request.set_operation("DescribeMigrationTask");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSMigrationHub.DescribeMigrationTask");
@@ -2297,6 +2668,7 @@ impl MigrationHub for MigrationHubClient {
     ) -> Result<DisassociateCreatedArtifactResult, RusotoError<DisassociateCreatedArtifactError>>
     {
         let mut request = SignedRequest::new("POST", "mgh", &self.region, "/");
+        request.set_operation("DisassociateCreatedArtifact");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2331,6 +2703,7 @@ impl MigrationHub for MigrationHubClient {
         RusotoError<DisassociateDiscoveredResourceError>,
     > {
         let mut request = SignedRequest::new("POST", "mgh", &self.region, "/");
+        request.set_operation("DisassociateDiscoveredResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2362,6 +2735,7 @@ impl MigrationHub for MigrationHubClient {
         input: ImportMigrationTaskRequest,
     ) -> Result<ImportMigrationTaskResult, RusotoError<ImportMigrationTaskError>> {
         let mut request = SignedRequest::new("POST", "mgh", &self.region, "/");
+        request.set_operation("ImportMigrationTask");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSMigrationHub.ImportMigrationTask");
@@ -2390,6 +2764,7 @@ impl MigrationHub for MigrationHubClient {
         input: ListApplicationStatesRequest,
     ) -> Result<ListApplicationStatesResult, RusotoError<ListApplicationStatesError>> {
         let mut request = SignedRequest::new("POST", "mgh", &self.region, "/");
+        request.set_operation("ListApplicationStates");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSMigrationHub.ListApplicationStates");
@@ -2418,6 +2793,7 @@ impl MigrationHub for MigrationHubClient {
         input: ListCreatedArtifactsRequest,
     ) -> Result<ListCreatedArtifactsResult, RusotoError<ListCreatedArtifactsError>> {
         let mut request = SignedRequest::new("POST", "mgh", &self.region, "/");
+        request.set_operation("ListCreatedArtifacts");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSMigrationHub.ListCreatedArtifacts");
@@ -2446,6 +2822,7 @@ impl MigrationHub for MigrationHubClient {
         input: ListDiscoveredResourcesRequest,
     ) -> Result<ListDiscoveredResourcesResult, RusotoError<ListDiscoveredResourcesError>> {
         let mut request = SignedRequest::new("POST", "mgh", &self.region, "/");
+        request.set_operation("ListDiscoveredResources");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSMigrationHub.ListDiscoveredResources");
@@ -2474,6 +2851,7 @@ impl MigrationHub for MigrationHubClient {
         input: ListMigrationTasksRequest,
     ) -> Result<ListMigrationTasksResult, RusotoError<ListMigrationTasksError>> {
         let mut request = SignedRequest::new("POST", "mgh", &self.region, "/");
+        request.set_operation("ListMigrationTasks");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSMigrationHub.ListMigrationTasks");
@@ -2502,6 +2880,7 @@ impl MigrationHub for MigrationHubClient {
         input: ListProgressUpdateStreamsRequest,
     ) -> Result<ListProgressUpdateStreamsResult, RusotoError<ListProgressUpdateStreamsError>> {
         let mut request = SignedRequest::new("POST", "mgh", &self.region, "/");
+        request.set_operation("ListProgressUpdateStreams");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSMigrationHub.ListProgressUpdateStreams");
@@ -2530,6 +2909,7 @@ impl MigrationHub for MigrationHubClient {
         input: NotifyApplicationStateRequest,
     ) -> Result<NotifyApplicationStateResult, RusotoError<NotifyApplicationStateError>> {
         let mut request = SignedRequest::new("POST", "mgh", &self.region, "/");
+        request.set_operation("NotifyApplicationState");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSMigrationHub.NotifyApplicationState");
@@ -2558,6 +2938,7 @@ impl MigrationHub for MigrationHubClient {
         input: NotifyMigrationTaskStateRequest,
     ) -> Result<NotifyMigrationTaskStateResult, RusotoError<NotifyMigrationTaskStateError>> {
         let mut request = SignedRequest::new("POST", "mgh", &self.region, "/");
+        request.set_operation("NotifyMigrationTaskState");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSMigrationHub.NotifyMigrationTaskState");
@@ -2586,6 +2967,7 @@ impl MigrationHub for MigrationHubClient {
         input: PutResourceAttributesRequest,
     ) -> Result<PutResourceAttributesResult, RusotoError<PutResourceAttributesError>> {
         let mut request = SignedRequest::new("POST", "mgh", &self.region, "/");
+        request.set_operation("PutResourceAttributes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSMigrationHub.PutResourceAttributes");
@@ -2608,3 +2990,182 @@ impl MigrationHub for MigrationHubClient {
         }
     }
 }
+impl MigrationHubClient {
+    /// Auto-paginating version of `list_application_states`, streaming every page of results.
+    pub fn list_application_states_pages(
+        &self,
+        input: ListApplicationStatesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListApplicationStatesResult,
+        ListApplicationStatesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MigrationHubClient, input: ListApplicationStatesRequest| async move {
+                client.list_application_states(input).await
+            },
+            |input: &ListApplicationStatesRequest, output: &ListApplicationStatesResult| {
+                Some(ListApplicationStatesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_application_states`, streaming the `ApplicationStateList` of every page of results.
+    pub fn list_application_states_items(
+        &self,
+        input: ListApplicationStatesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ApplicationState, ListApplicationStatesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_application_states_pages(input),
+            |output: ListApplicationStatesResult| output.application_state_list.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_created_artifacts`, streaming every page of results.
+    pub fn list_created_artifacts_pages(
+        &self,
+        input: ListCreatedArtifactsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListCreatedArtifactsResult, ListCreatedArtifactsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MigrationHubClient, input: ListCreatedArtifactsRequest| async move {
+                client.list_created_artifacts(input).await
+            },
+            |input: &ListCreatedArtifactsRequest, output: &ListCreatedArtifactsResult| {
+                Some(ListCreatedArtifactsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_created_artifacts`, streaming the `CreatedArtifactList` of every page of results.
+    pub fn list_created_artifacts_items(
+        &self,
+        input: ListCreatedArtifactsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<CreatedArtifact, ListCreatedArtifactsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_created_artifacts_pages(input),
+            |output: ListCreatedArtifactsResult| output.created_artifact_list.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_discovered_resources`, streaming every page of results.
+    pub fn list_discovered_resources_pages(
+        &self,
+        input: ListDiscoveredResourcesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListDiscoveredResourcesResult,
+        ListDiscoveredResourcesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MigrationHubClient, input: ListDiscoveredResourcesRequest| async move {
+                client.list_discovered_resources(input).await
+            },
+            |input: &ListDiscoveredResourcesRequest, output: &ListDiscoveredResourcesResult| {
+                Some(ListDiscoveredResourcesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_discovered_resources`, streaming the `DiscoveredResourceList` of every page of results.
+    pub fn list_discovered_resources_items(
+        &self,
+        input: ListDiscoveredResourcesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DiscoveredResource, ListDiscoveredResourcesError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_discovered_resources_pages(input),
+            |output: ListDiscoveredResourcesResult| {
+                output.discovered_resource_list.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_migration_tasks`, streaming every page of results.
+    pub fn list_migration_tasks_pages(
+        &self,
+        input: ListMigrationTasksRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListMigrationTasksResult, ListMigrationTasksError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MigrationHubClient, input: ListMigrationTasksRequest| async move {
+                client.list_migration_tasks(input).await
+            },
+            |input: &ListMigrationTasksRequest, output: &ListMigrationTasksResult| {
+                Some(ListMigrationTasksRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_migration_tasks`, streaming the `MigrationTaskSummaryList` of every page of results.
+    pub fn list_migration_tasks_items(
+        &self,
+        input: ListMigrationTasksRequest,
+    ) -> rusoto_core::pagination::RusotoStream<MigrationTaskSummary, ListMigrationTasksError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_migration_tasks_pages(input),
+            |output: ListMigrationTasksResult| {
+                output.migration_task_summary_list.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_progress_update_streams`, streaming every page of results.
+    pub fn list_progress_update_streams_pages(
+        &self,
+        input: ListProgressUpdateStreamsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListProgressUpdateStreamsResult,
+        ListProgressUpdateStreamsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MigrationHubClient, input: ListProgressUpdateStreamsRequest| async move {
+                client.list_progress_update_streams(input).await
+            },
+            |input: &ListProgressUpdateStreamsRequest, output: &ListProgressUpdateStreamsResult| {
+                Some(ListProgressUpdateStreamsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_progress_update_streams`, streaming the `ProgressUpdateStreamSummaryList` of every page of results.
+    pub fn list_progress_update_streams_items(
+        &self,
+        input: ListProgressUpdateStreamsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ProgressUpdateStreamSummary,
+        ListProgressUpdateStreamsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_progress_update_streams_pages(input),
+            |output: ListProgressUpdateStreamsResult| {
+                output
+                    .progress_update_stream_summary_list
+                    .unwrap_or_default()
+            },
+        )
+    }
+}
@@ -113,8 +113,13 @@ pub struct HomeRegionControl {
     pub home_region: Option<String>,
     /// <p>A timestamp representing the time when the customer called <code>CreateHomeregionControl</code> and set the home region for the account.</p>
     #[serde(rename = "RequestedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub requested_time: Option<f64>,
+    pub requested_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The target parameter specifies the identifier to which the home region is applied, which is always an <code>ACCOUNT</code>. It applies the home region to the current <code>ACCOUNT</code>.</p>
     #[serde(rename = "Target")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -130,9 +135,97 @@ pub struct Target {
     pub id: Option<String>,
     /// <p>The target type is always an <code>ACCOUNT</code>.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: TargetType,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TargetType {
+    /// `ACCOUNT`
+    Account,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TargetType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TargetType::Account => "ACCOUNT",
+            TargetType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TargetType {
+    fn default() -> TargetType {
+        TargetType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TargetType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TargetType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TargetType {
+    fn from(value: &'a str) -> TargetType {
+        match value {
+            "ACCOUNT" => TargetType::Account,
+            _ => TargetType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TargetType {
+    fn from(value: String) -> TargetType {
+        match value.as_str() {
+            "ACCOUNT" => TargetType::Account,
+            _ => TargetType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TargetType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TargetType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TargetType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TargetType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TargetType {
+    fn deserialize<D>(deserializer: D) -> Result<TargetType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// Errors returned by CreateHomeRegionControl
 #[derive(Debug, PartialEq)]
 pub enum CreateHomeRegionControlError {
@@ -336,11 +429,12 @@ pub struct MigrationHubConfigClient {
 impl MigrationHubConfigClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> MigrationHubConfigClient {
         MigrationHubConfigClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "MigrationHub Config"),
         }
     }
 
@@ -372,6 +466,7 @@ impl MigrationHubConfig for MigrationHubConfigClient {
         input: CreateHomeRegionControlRequest,
     ) -> Result<CreateHomeRegionControlResult, RusotoError<CreateHomeRegionControlError>> {
         let mut request = SignedRequest::new("POST", "mgh", &self.region, "/");
+        request.set_operation("CreateHomeRegionControl");
         request.set_endpoint_prefix("migrationhub-config".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -404,6 +499,7 @@ impl MigrationHubConfig for MigrationHubConfigClient {
     ) -> Result<DescribeHomeRegionControlsResult, RusotoError<DescribeHomeRegionControlsError>>
     {
         let mut request = SignedRequest::new("POST", "mgh", &self.region, "/");
+        request.set_operation("DescribeHomeRegionControls");
         request.set_endpoint_prefix("migrationhub-config".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -434,6 +530,7 @@ impl MigrationHubConfig for MigrationHubConfigClient {
         &self,
     ) -> Result<GetHomeRegionResult, RusotoError<GetHomeRegionError>> {
         let mut request = SignedRequest::new("POST", "mgh", &self.region, "/");
+        request.set_operation("GetHomeRegion");
         request.set_endpoint_prefix("migrationhub-config".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -30,7 +30,7 @@ use serde::{Deserialize, Serialize};
 pub struct BundleDetails {
     #[serde(rename = "availablePlatforms")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub available_platforms: Option<Vec<String>>,
+    pub available_platforms: Option<Vec<Platform>>,
     #[serde(rename = "bundleId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub bundle_id: Option<String>,
@@ -159,7 +159,7 @@ pub struct ExportBundleRequest {
     /// <p> Developer desktop or target application platform. </p>
     #[serde(rename = "platform")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub platform: Option<String>,
+    pub platform: Option<Platform>,
     /// <p> Unique project identifier. </p>
     #[serde(rename = "projectId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -257,6 +257,125 @@ pub struct ListProjectsResult {
     pub projects: Option<Vec<ProjectSummary>>,
 }
 
+/// <p> Developer desktop or target mobile app or website platform. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Platform {
+    /// `OSX`
+    Osx,
+    /// `WINDOWS`
+    Windows,
+    /// `LINUX`
+    Linux,
+    /// `OBJC`
+    Objc,
+    /// `SWIFT`
+    Swift,
+    /// `ANDROID`
+    Android,
+    /// `JAVASCRIPT`
+    Javascript,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Platform {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Platform::Osx => "OSX",
+            Platform::Windows => "WINDOWS",
+            Platform::Linux => "LINUX",
+            Platform::Objc => "OBJC",
+            Platform::Swift => "SWIFT",
+            Platform::Android => "ANDROID",
+            Platform::Javascript => "JAVASCRIPT",
+            Platform::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Platform {
+    fn default() -> Platform {
+        Platform::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Platform {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Platform {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Platform {
+    fn from(value: &'a str) -> Platform {
+        match value {
+            "OSX" => Platform::Osx,
+            "WINDOWS" => Platform::Windows,
+            "LINUX" => Platform::Linux,
+            "OBJC" => Platform::Objc,
+            "SWIFT" => Platform::Swift,
+            "ANDROID" => Platform::Android,
+            "JAVASCRIPT" => Platform::Javascript,
+            _ => Platform::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Platform {
+    fn from(value: String) -> Platform {
+        match value.as_str() {
+            "OSX" => Platform::Osx,
+            "WINDOWS" => Platform::Windows,
+            "LINUX" => Platform::Linux,
+            "OBJC" => Platform::Objc,
+            "SWIFT" => Platform::Swift,
+            "ANDROID" => Platform::Android,
+            "JAVASCRIPT" => Platform::Javascript,
+            _ => Platform::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Platform {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Platform, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Platform {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Platform {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Platform {
+    fn deserialize<D>(deserializer: D) -> Result<Platform, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p> Detailed information about an AWS Mobile Hub project. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -267,12 +386,22 @@ pub struct ProjectDetails {
     pub console_url: Option<String>,
     /// <p> Date the project was created. </p>
     #[serde(rename = "createdDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p> Date of the last modification of the project. </p>
     #[serde(rename = "lastUpdatedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date: Option<f64>,
+    pub last_updated_date: Option<::chrono::DateTime<::chrono::Utc>>,
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
@@ -287,9 +416,108 @@ pub struct ProjectDetails {
     pub resources: Option<Vec<Resource>>,
     #[serde(rename = "state")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<ProjectState>,
+}
+
+/// <p> Synchronization state for a project. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ProjectState {
+    /// `NORMAL`
+    Normal,
+    /// `SYNCING`
+    Syncing,
+    /// `IMPORTING`
+    Importing,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ProjectState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ProjectState::Normal => "NORMAL",
+            ProjectState::Syncing => "SYNCING",
+            ProjectState::Importing => "IMPORTING",
+            ProjectState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ProjectState {
+    fn default() -> ProjectState {
+        ProjectState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ProjectState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
 }
 
+impl fmt::Display for ProjectState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ProjectState {
+    fn from(value: &'a str) -> ProjectState {
+        match value {
+            "NORMAL" => ProjectState::Normal,
+            "SYNCING" => ProjectState::Syncing,
+            "IMPORTING" => ProjectState::Importing,
+            _ => ProjectState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ProjectState {
+    fn from(value: String) -> ProjectState {
+        match value.as_str() {
+            "NORMAL" => ProjectState::Normal,
+            "SYNCING" => ProjectState::Syncing,
+            "IMPORTING" => ProjectState::Importing,
+            _ => ProjectState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ProjectState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ProjectState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ProjectState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ProjectState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ProjectState {
+    fn deserialize<D>(deserializer: D) -> Result<ProjectState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p> Summary information about an AWS Mobile Hub project. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -960,11 +1188,12 @@ pub struct MobileClient {
 impl MobileClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> MobileClient {
         MobileClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Mobile"),
         }
     }
 
@@ -999,6 +1228,7 @@ impl Mobile for MobileClient {
 
         let mut request =
             SignedRequest::new("POST", "AWSMobileHubService", &self.region, &request_uri);
+        request.set_operation("CreateProject");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("mobile".to_string());
@@ -1047,6 +1277,7 @@ impl Mobile for MobileClient {
 
         let mut request =
             SignedRequest::new("DELETE", "AWSMobileHubService", &self.region, &request_uri);
+        request.set_operation("DeleteProject");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("mobile".to_string());
@@ -1077,6 +1308,7 @@ impl Mobile for MobileClient {
 
         let mut request =
             SignedRequest::new("GET", "AWSMobileHubService", &self.region, &request_uri);
+        request.set_operation("DescribeBundle");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("mobile".to_string());
@@ -1107,6 +1339,7 @@ impl Mobile for MobileClient {
 
         let mut request =
             SignedRequest::new("GET", "AWSMobileHubService", &self.region, &request_uri);
+        request.set_operation("DescribeProject");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("mobile".to_string());
@@ -1144,6 +1377,7 @@ impl Mobile for MobileClient {
 
         let mut request =
             SignedRequest::new("POST", "AWSMobileHubService", &self.region, &request_uri);
+        request.set_operation("ExportBundle");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("mobile".to_string());
@@ -1183,6 +1417,7 @@ impl Mobile for MobileClient {
 
         let mut request =
             SignedRequest::new("POST", "AWSMobileHubService", &self.region, &request_uri);
+        request.set_operation("ExportProject");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("mobile".to_string());
@@ -1213,6 +1448,7 @@ impl Mobile for MobileClient {
 
         let mut request =
             SignedRequest::new("GET", "AWSMobileHubService", &self.region, &request_uri);
+        request.set_operation("ListBundles");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("mobile".to_string());
@@ -1252,6 +1488,7 @@ impl Mobile for MobileClient {
 
         let mut request =
             SignedRequest::new("GET", "AWSMobileHubService", &self.region, &request_uri);
+        request.set_operation("ListProjects");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("mobile".to_string());
@@ -1291,6 +1528,7 @@ impl Mobile for MobileClient {
 
         let mut request =
             SignedRequest::new("POST", "AWSMobileHubService", &self.region, &request_uri);
+        request.set_operation("UpdateProject");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("mobile".to_string());
@@ -1322,3 +1560,66 @@ impl Mobile for MobileClient {
         }
     }
 }
+impl MobileClient {
+    /// Auto-paginating version of `list_bundles`, streaming every page of results.
+    pub fn list_bundles_pages(
+        &self,
+        input: ListBundlesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListBundlesResult, ListBundlesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MobileClient, input: ListBundlesRequest| async move {
+                client.list_bundles(input).await
+            },
+            |input: &ListBundlesRequest, output: &ListBundlesResult| {
+                Some(ListBundlesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_bundles`, streaming the `bundleList` of every page of results.
+    pub fn list_bundles_items(
+        &self,
+        input: ListBundlesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<BundleDetails, ListBundlesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_bundles_pages(input),
+            |output: ListBundlesResult| output.bundle_list.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_projects`, streaming every page of results.
+    pub fn list_projects_pages(
+        &self,
+        input: ListProjectsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListProjectsResult, ListProjectsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MobileClient, input: ListProjectsRequest| async move {
+                client.list_projects(input).await
+            },
+            |input: &ListProjectsRequest, output: &ListProjectsResult| {
+                Some(ListProjectsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_projects`, streaming the `projects` of every page of results.
+    pub fn list_projects_items(
+        &self,
+        input: ListProjectsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ProjectSummary, ListProjectsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_projects_pages(input),
+            |output: ListProjectsResult| output.projects.unwrap_or_default(),
+        )
+    }
+}
@@ -42,7 +42,7 @@ pub struct BrokerEngineType {
     /// <p>The type of broker engine.</p>
     #[serde(rename = "EngineType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub engine_type: Option<String>,
+    pub engine_type: Option<EngineType>,
     /// <p>The list of engine versions.</p>
     #[serde(rename = "EngineVersions")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -78,7 +78,7 @@ pub struct BrokerInstanceOption {
     /// <p>The type of broker engine.</p>
     #[serde(rename = "EngineType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub engine_type: Option<String>,
+    pub engine_type: Option<EngineType>,
     /// <p>The type of broker instance.</p>
     #[serde(rename = "HostInstanceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -86,17 +86,230 @@ pub struct BrokerInstanceOption {
     /// <p>The broker&#39;s storage type.</p>
     #[serde(rename = "StorageType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub storage_type: Option<String>,
+    pub storage_type: Option<BrokerStorageType>,
     /// <p>The list of supported deployment modes.</p>
     #[serde(rename = "SupportedDeploymentModes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub supported_deployment_modes: Option<Vec<String>>,
+    pub supported_deployment_modes: Option<Vec<DeploymentMode>>,
     /// <p>The list of supported engine versions.</p>
     #[serde(rename = "SupportedEngineVersions")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub supported_engine_versions: Option<Vec<String>>,
 }
 
+/// <p>The broker's status.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BrokerState {
+    /// `CREATION_IN_PROGRESS`
+    CreationInProgress,
+    /// `CREATION_FAILED`
+    CreationFailed,
+    /// `DELETION_IN_PROGRESS`
+    DeletionInProgress,
+    /// `RUNNING`
+    Running,
+    /// `REBOOT_IN_PROGRESS`
+    RebootInProgress,
+    /// `CRITICAL_ACTION_REQUIRED`
+    CriticalActionRequired,
+    /// `REPLICA`
+    Replica,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl BrokerState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BrokerState::CreationInProgress => "CREATION_IN_PROGRESS",
+            BrokerState::CreationFailed => "CREATION_FAILED",
+            BrokerState::DeletionInProgress => "DELETION_IN_PROGRESS",
+            BrokerState::Running => "RUNNING",
+            BrokerState::RebootInProgress => "REBOOT_IN_PROGRESS",
+            BrokerState::CriticalActionRequired => "CRITICAL_ACTION_REQUIRED",
+            BrokerState::Replica => "REPLICA",
+            BrokerState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for BrokerState {
+    fn default() -> BrokerState {
+        BrokerState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for BrokerState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for BrokerState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for BrokerState {
+    fn from(value: &'a str) -> BrokerState {
+        match value {
+            "CREATION_IN_PROGRESS" => BrokerState::CreationInProgress,
+            "CREATION_FAILED" => BrokerState::CreationFailed,
+            "DELETION_IN_PROGRESS" => BrokerState::DeletionInProgress,
+            "RUNNING" => BrokerState::Running,
+            "REBOOT_IN_PROGRESS" => BrokerState::RebootInProgress,
+            "CRITICAL_ACTION_REQUIRED" => BrokerState::CriticalActionRequired,
+            "REPLICA" => BrokerState::Replica,
+            _ => BrokerState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BrokerState {
+    fn from(value: String) -> BrokerState {
+        match value.as_str() {
+            "CREATION_IN_PROGRESS" => BrokerState::CreationInProgress,
+            "CREATION_FAILED" => BrokerState::CreationFailed,
+            "DELETION_IN_PROGRESS" => BrokerState::DeletionInProgress,
+            "RUNNING" => BrokerState::Running,
+            "REBOOT_IN_PROGRESS" => BrokerState::RebootInProgress,
+            "CRITICAL_ACTION_REQUIRED" => BrokerState::CriticalActionRequired,
+            "REPLICA" => BrokerState::Replica,
+            _ => BrokerState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for BrokerState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<BrokerState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for BrokerState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for BrokerState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BrokerState {
+    fn deserialize<D>(deserializer: D) -> Result<BrokerState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>The broker's storage type.</p> <important><p>EFS is not supported for RabbitMQ engine type.</p></important>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BrokerStorageType {
+    /// `EBS`
+    Ebs,
+    /// `EFS`
+    Efs,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl BrokerStorageType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BrokerStorageType::Ebs => "EBS",
+            BrokerStorageType::Efs => "EFS",
+            BrokerStorageType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for BrokerStorageType {
+    fn default() -> BrokerStorageType {
+        BrokerStorageType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for BrokerStorageType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for BrokerStorageType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for BrokerStorageType {
+    fn from(value: &'a str) -> BrokerStorageType {
+        match value {
+            "EBS" => BrokerStorageType::Ebs,
+            "EFS" => BrokerStorageType::Efs,
+            _ => BrokerStorageType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BrokerStorageType {
+    fn from(value: String) -> BrokerStorageType {
+        match value.as_str() {
+            "EBS" => BrokerStorageType::Ebs,
+            "EFS" => BrokerStorageType::Efs,
+            _ => BrokerStorageType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for BrokerStorageType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<BrokerStorageType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for BrokerStorageType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for BrokerStorageType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BrokerStorageType {
+    fn deserialize<D>(deserializer: D) -> Result<BrokerStorageType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The Amazon Resource Name (ARN) of the broker.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -116,21 +329,125 @@ pub struct BrokerSummary {
     /// <p>The status of the broker.</p>
     #[serde(rename = "BrokerState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub broker_state: Option<String>,
+    pub broker_state: Option<BrokerState>,
     /// <p>The time when the broker was created.</p>
     #[serde(rename = "Created")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created: Option<f64>,
+    pub created: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Required. The deployment mode of the broker.</p>
     #[serde(rename = "DeploymentMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deployment_mode: Option<String>,
+    pub deployment_mode: Option<DeploymentMode>,
     /// <p>The broker&#39;s instance type.</p>
     #[serde(rename = "HostInstanceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub host_instance_type: Option<String>,
 }
 
+/// <p>The type of change pending for the ActiveMQ user.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ChangeType {
+    /// `CREATE`
+    Create,
+    /// `UPDATE`
+    Update,
+    /// `DELETE`
+    Delete,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ChangeType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ChangeType::Create => "CREATE",
+            ChangeType::Update => "UPDATE",
+            ChangeType::Delete => "DELETE",
+            ChangeType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ChangeType {
+    fn default() -> ChangeType {
+        ChangeType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ChangeType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ChangeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ChangeType {
+    fn from(value: &'a str) -> ChangeType {
+        match value {
+            "CREATE" => ChangeType::Create,
+            "UPDATE" => ChangeType::Update,
+            "DELETE" => ChangeType::Delete,
+            _ => ChangeType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ChangeType {
+    fn from(value: String) -> ChangeType {
+        match value.as_str() {
+            "CREATE" => ChangeType::Create,
+            "UPDATE" => ChangeType::Update,
+            "DELETE" => ChangeType::Delete,
+            _ => ChangeType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ChangeType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ChangeType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ChangeType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ChangeType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ChangeType {
+    fn deserialize<D>(deserializer: D) -> Result<ChangeType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Returns information about all configurations.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -141,8 +458,13 @@ pub struct Configuration {
     pub arn: Option<String>,
     /// <p>Required. The date and time of the configuration revision.</p>
     #[serde(rename = "Created")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created: Option<f64>,
+    pub created: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Required. The description of the configuration.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -150,7 +472,7 @@ pub struct Configuration {
     /// <p>Required. The type of broker engine. Note: Currently, Amazon MQ supports only ACTIVEMQ.</p>
     #[serde(rename = "EngineType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub engine_type: Option<String>,
+    pub engine_type: Option<EngineType>,
     /// <p>Required. The version of the broker engine. For a list of supported engine versions, see https://docs.aws.amazon.com/amazon-mq/latest/developer-guide/broker-engine.html</p>
     #[serde(rename = "EngineVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -192,8 +514,13 @@ pub struct ConfigurationId {
 pub struct ConfigurationRevision {
     /// <p>Required. The date and time of the configuration revision.</p>
     #[serde(rename = "Created")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created: Option<f64>,
+    pub created: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The description of the configuration revision.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -245,7 +572,7 @@ pub struct CreateBrokerRequest {
     /// <p>Required. The deployment mode of the broker.</p>
     #[serde(rename = "DeploymentMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deployment_mode: Option<String>,
+    pub deployment_mode: Option<DeploymentMode>,
     /// <p>Encryption options for the broker.</p>
     #[serde(rename = "EncryptionOptions")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -253,7 +580,7 @@ pub struct CreateBrokerRequest {
     /// <p>Required. The type of broker engine. Note: Currently, Amazon MQ supports only ACTIVEMQ.</p>
     #[serde(rename = "EngineType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub engine_type: Option<String>,
+    pub engine_type: Option<EngineType>,
     /// <p>Required. The version of the broker engine. For a list of supported engine versions, see https://docs.aws.amazon.com/amazon-mq/latest/developer-guide/broker-engine.html</p>
     #[serde(rename = "EngineVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -281,7 +608,7 @@ pub struct CreateBrokerRequest {
     /// <p>The broker&#39;s storage type.</p>
     #[serde(rename = "StorageType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub storage_type: Option<String>,
+    pub storage_type: Option<BrokerStorageType>,
     /// <p>The list of groups (2 maximum) that define which subnets and IP ranges the broker can use from different Availability Zones. A SINGLE<em>INSTANCE deployment requires one subnet (for example, the default subnet). An ACTIVE</em>STANDBY<em>MULTI</em>AZ deployment requires two subnets.</p>
     #[serde(rename = "SubnetIds")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -316,7 +643,7 @@ pub struct CreateConfigurationRequest {
     /// <p>Required. The type of broker engine. Note: Currently, Amazon MQ supports only ACTIVEMQ.</p>
     #[serde(rename = "EngineType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub engine_type: Option<String>,
+    pub engine_type: Option<EngineType>,
     /// <p>Required. The version of the broker engine. For a list of supported engine versions, see https://docs.aws.amazon.com/amazon-mq/latest/developer-guide/broker-engine.html</p>
     #[serde(rename = "EngineVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -340,8 +667,13 @@ pub struct CreateConfigurationResponse {
     pub arn: Option<String>,
     /// <p>Required. The date and time of the configuration.</p>
     #[serde(rename = "Created")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created: Option<f64>,
+    pub created: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Required. The unique ID that Amazon MQ generates for the configuration.</p>
     #[serde(rename = "Id")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -397,6 +729,124 @@ pub struct CreateUserRequest {
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateUserResponse {}
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DayOfWeek {
+    /// `MONDAY`
+    Monday,
+    /// `TUESDAY`
+    Tuesday,
+    /// `WEDNESDAY`
+    Wednesday,
+    /// `THURSDAY`
+    Thursday,
+    /// `FRIDAY`
+    Friday,
+    /// `SATURDAY`
+    Saturday,
+    /// `SUNDAY`
+    Sunday,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DayOfWeek {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DayOfWeek::Monday => "MONDAY",
+            DayOfWeek::Tuesday => "TUESDAY",
+            DayOfWeek::Wednesday => "WEDNESDAY",
+            DayOfWeek::Thursday => "THURSDAY",
+            DayOfWeek::Friday => "FRIDAY",
+            DayOfWeek::Saturday => "SATURDAY",
+            DayOfWeek::Sunday => "SUNDAY",
+            DayOfWeek::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DayOfWeek {
+    fn default() -> DayOfWeek {
+        DayOfWeek::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DayOfWeek {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DayOfWeek {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DayOfWeek {
+    fn from(value: &'a str) -> DayOfWeek {
+        match value {
+            "MONDAY" => DayOfWeek::Monday,
+            "TUESDAY" => DayOfWeek::Tuesday,
+            "WEDNESDAY" => DayOfWeek::Wednesday,
+            "THURSDAY" => DayOfWeek::Thursday,
+            "FRIDAY" => DayOfWeek::Friday,
+            "SATURDAY" => DayOfWeek::Saturday,
+            "SUNDAY" => DayOfWeek::Sunday,
+            _ => DayOfWeek::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DayOfWeek {
+    fn from(value: String) -> DayOfWeek {
+        match value.as_str() {
+            "MONDAY" => DayOfWeek::Monday,
+            "TUESDAY" => DayOfWeek::Tuesday,
+            "WEDNESDAY" => DayOfWeek::Wednesday,
+            "THURSDAY" => DayOfWeek::Thursday,
+            "FRIDAY" => DayOfWeek::Friday,
+            "SATURDAY" => DayOfWeek::Saturday,
+            "SUNDAY" => DayOfWeek::Sunday,
+            _ => DayOfWeek::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DayOfWeek {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DayOfWeek, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DayOfWeek {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DayOfWeek {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DayOfWeek {
+    fn deserialize<D>(deserializer: D) -> Result<DayOfWeek, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct DeleteBrokerRequest {
@@ -440,6 +890,105 @@ pub struct DeleteUserRequest {
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteUserResponse {}
 
+/// <p>The broker's deployment mode.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DeploymentMode {
+    /// `SINGLE_INSTANCE`
+    SingleInstance,
+    /// `ACTIVE_STANDBY_MULTI_AZ`
+    ActiveStandbyMultiAz,
+    /// `CLUSTER_MULTI_AZ`
+    ClusterMultiAz,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DeploymentMode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeploymentMode::SingleInstance => "SINGLE_INSTANCE",
+            DeploymentMode::ActiveStandbyMultiAz => "ACTIVE_STANDBY_MULTI_AZ",
+            DeploymentMode::ClusterMultiAz => "CLUSTER_MULTI_AZ",
+            DeploymentMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DeploymentMode {
+    fn default() -> DeploymentMode {
+        DeploymentMode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DeploymentMode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DeploymentMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DeploymentMode {
+    fn from(value: &'a str) -> DeploymentMode {
+        match value {
+            "SINGLE_INSTANCE" => DeploymentMode::SingleInstance,
+            "ACTIVE_STANDBY_MULTI_AZ" => DeploymentMode::ActiveStandbyMultiAz,
+            "CLUSTER_MULTI_AZ" => DeploymentMode::ClusterMultiAz,
+            _ => DeploymentMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeploymentMode {
+    fn from(value: String) -> DeploymentMode {
+        match value.as_str() {
+            "SINGLE_INSTANCE" => DeploymentMode::SingleInstance,
+            "ACTIVE_STANDBY_MULTI_AZ" => DeploymentMode::ActiveStandbyMultiAz,
+            "CLUSTER_MULTI_AZ" => DeploymentMode::ClusterMultiAz,
+            _ => DeploymentMode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DeploymentMode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DeploymentMode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DeploymentMode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DeploymentMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeploymentMode {
+    fn deserialize<D>(deserializer: D) -> Result<DeploymentMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct DescribeBrokerEngineTypesRequest {
@@ -550,19 +1099,24 @@ pub struct DescribeBrokerResponse {
     /// <p>The status of the broker.</p>
     #[serde(rename = "BrokerState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub broker_state: Option<String>,
+    pub broker_state: Option<BrokerState>,
     /// <p>The list of all revisions for the specified configuration.</p>
     #[serde(rename = "Configurations")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub configurations: Option<Configurations>,
     /// <p>The time when the broker was created.</p>
     #[serde(rename = "Created")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created: Option<f64>,
+    pub created: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Required. The deployment mode of the broker.</p>
     #[serde(rename = "DeploymentMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deployment_mode: Option<String>,
+    pub deployment_mode: Option<DeploymentMode>,
     /// <p>Encryption options for the broker.</p>
     #[serde(rename = "EncryptionOptions")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -570,7 +1124,7 @@ pub struct DescribeBrokerResponse {
     /// <p>Required. The type of broker engine. Note: Currently, Amazon MQ supports only ACTIVEMQ.</p>
     #[serde(rename = "EngineType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub engine_type: Option<String>,
+    pub engine_type: Option<EngineType>,
     /// <p>The version of the broker engine. For a list of supported engine versions, see https://docs.aws.amazon.com/amazon-mq/latest/developer-guide/broker-engine.html</p>
     #[serde(rename = "EngineVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -610,7 +1164,7 @@ pub struct DescribeBrokerResponse {
     /// <p>The broker&#39;s storage type.</p>
     #[serde(rename = "StorageType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub storage_type: Option<String>,
+    pub storage_type: Option<BrokerStorageType>,
     /// <p>The list of groups (2 maximum) that define which subnets and IP ranges the broker can use from different Availability Zones. A SINGLE<em>INSTANCE deployment requires one subnet (for example, the default subnet). An ACTIVE</em>STANDBY<em>MULTI</em>AZ deployment requires two subnets.</p>
     #[serde(rename = "SubnetIds")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -642,8 +1196,13 @@ pub struct DescribeConfigurationResponse {
     pub arn: Option<String>,
     /// <p>Required. The date and time of the configuration revision.</p>
     #[serde(rename = "Created")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created: Option<f64>,
+    pub created: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Required. The description of the configuration.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -651,7 +1210,7 @@ pub struct DescribeConfigurationResponse {
     /// <p>Required. The type of broker engine. Note: Currently, Amazon MQ supports only ACTIVEMQ.</p>
     #[serde(rename = "EngineType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub engine_type: Option<String>,
+    pub engine_type: Option<EngineType>,
     /// <p>Required. The version of the broker engine. For a list of supported engine versions, see https://docs.aws.amazon.com/amazon-mq/latest/developer-guide/broker-engine.html</p>
     #[serde(rename = "EngineVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -694,8 +1253,13 @@ pub struct DescribeConfigurationRevisionResponse {
     pub configuration_id: Option<String>,
     /// <p>Required. The date and time of the configuration.</p>
     #[serde(rename = "Created")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created: Option<f64>,
+    pub created: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Required. The base64-encoded XML configuration.</p>
     #[serde(rename = "Data")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -754,6 +1318,100 @@ pub struct EncryptionOptions {
     pub use_aws_owned_key: bool,
 }
 
+/// <p>The type of broker engine. Amazon MQ supports ActiveMQ and RabbitMQ.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EngineType {
+    /// `ACTIVEMQ`
+    Activemq,
+    /// `RABBITMQ`
+    Rabbitmq,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EngineType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EngineType::Activemq => "ACTIVEMQ",
+            EngineType::Rabbitmq => "RABBITMQ",
+            EngineType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EngineType {
+    fn default() -> EngineType {
+        EngineType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EngineType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EngineType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EngineType {
+    fn from(value: &'a str) -> EngineType {
+        match value {
+            "ACTIVEMQ" => EngineType::Activemq,
+            "RABBITMQ" => EngineType::Rabbitmq,
+            _ => EngineType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EngineType {
+    fn from(value: String) -> EngineType {
+        match value.as_str() {
+            "ACTIVEMQ" => EngineType::Activemq,
+            "RABBITMQ" => EngineType::Rabbitmq,
+            _ => EngineType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EngineType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EngineType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EngineType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EngineType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EngineType {
+    fn deserialize<D>(deserializer: D) -> Result<EngineType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Id of the engine version.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -991,9 +1649,114 @@ pub struct SanitizationWarning {
     /// <p>Required. The reason for which the XML elements or attributes were sanitized.</p>
     #[serde(rename = "Reason")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub reason: Option<String>,
+    pub reason: Option<SanitizationWarningReason>,
+}
+
+/// <p>The reason for which the configuration elements or attributes were sanitized.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SanitizationWarningReason {
+    /// `DISALLOWED_ELEMENT_REMOVED`
+    DisallowedElementRemoved,
+    /// `DISALLOWED_ATTRIBUTE_REMOVED`
+    DisallowedAttributeRemoved,
+    /// `INVALID_ATTRIBUTE_VALUE_REMOVED`
+    InvalidAttributeValueRemoved,
+    /// A value not known to this version of the crate
+    Unknown(String),
 }
 
+impl SanitizationWarningReason {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SanitizationWarningReason::DisallowedElementRemoved => "DISALLOWED_ELEMENT_REMOVED",
+            SanitizationWarningReason::DisallowedAttributeRemoved => "DISALLOWED_ATTRIBUTE_REMOVED",
+            SanitizationWarningReason::InvalidAttributeValueRemoved => {
+                "INVALID_ATTRIBUTE_VALUE_REMOVED"
+            }
+            SanitizationWarningReason::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SanitizationWarningReason {
+    fn default() -> SanitizationWarningReason {
+        SanitizationWarningReason::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SanitizationWarningReason {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SanitizationWarningReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SanitizationWarningReason {
+    fn from(value: &'a str) -> SanitizationWarningReason {
+        match value {
+            "DISALLOWED_ELEMENT_REMOVED" => SanitizationWarningReason::DisallowedElementRemoved,
+            "DISALLOWED_ATTRIBUTE_REMOVED" => SanitizationWarningReason::DisallowedAttributeRemoved,
+            "INVALID_ATTRIBUTE_VALUE_REMOVED" => {
+                SanitizationWarningReason::InvalidAttributeValueRemoved
+            }
+            _ => SanitizationWarningReason::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SanitizationWarningReason {
+    fn from(value: String) -> SanitizationWarningReason {
+        match value.as_str() {
+            "DISALLOWED_ELEMENT_REMOVED" => SanitizationWarningReason::DisallowedElementRemoved,
+            "DISALLOWED_ATTRIBUTE_REMOVED" => SanitizationWarningReason::DisallowedAttributeRemoved,
+            "INVALID_ATTRIBUTE_VALUE_REMOVED" => {
+                SanitizationWarningReason::InvalidAttributeValueRemoved
+            }
+            _ => SanitizationWarningReason::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SanitizationWarningReason {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SanitizationWarningReason, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SanitizationWarningReason {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SanitizationWarningReason {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SanitizationWarningReason {
+    fn deserialize<D>(deserializer: D) -> Result<SanitizationWarningReason, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Updates the broker using the specified properties.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -1086,8 +1849,13 @@ pub struct UpdateConfigurationResponse {
     pub arn: Option<String>,
     /// <p>Required. The date and time of the configuration.</p>
     #[serde(rename = "Created")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created: Option<f64>,
+    pub created: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Required. The unique ID that Amazon MQ generates for the configuration.</p>
     #[serde(rename = "Id")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1171,7 +1939,7 @@ pub struct UserPendingChanges {
     /// <p>Required. The type of change pending for the ActiveMQ user.</p>
     #[serde(rename = "PendingChange")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub pending_change: Option<String>,
+    pub pending_change: Option<ChangeType>,
 }
 
 /// <p>Returns a list of all ActiveMQ users.</p>
@@ -1181,7 +1949,7 @@ pub struct UserSummary {
     /// <p>The type of change pending for the ActiveMQ user.</p>
     #[serde(rename = "PendingChange")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub pending_change: Option<String>,
+    pub pending_change: Option<ChangeType>,
     /// <p>Required. The username of the ActiveMQ user. This value can contain only alphanumeric characters, dashes, periods, underscores, and tildes (- . _ ~). This value must be 2-100 characters long.</p>
     #[serde(rename = "Username")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1194,7 +1962,7 @@ pub struct WeeklyStartTime {
     /// <p>Required. The day of the week.</p>
     #[serde(rename = "DayOfWeek")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub day_of_week: Option<String>,
+    pub day_of_week: Option<DayOfWeek>,
     /// <p>Required. The time, in 24-hour format.</p>
     #[serde(rename = "TimeOfDay")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2464,11 +3232,12 @@ pub struct MQClient {
 impl MQClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> MQClient {
         MQClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "mq"),
         }
     }
 
@@ -2502,6 +3271,7 @@ impl MQ for MQClient {
         let request_uri = "/v1/brokers";
 
         let mut request = SignedRequest::new("POST", "mq", &self.region, &request_uri);
+        request.set_operation("CreateBroker");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2532,6 +3302,7 @@ impl MQ for MQClient {
         let request_uri = "/v1/configurations";
 
         let mut request = SignedRequest::new("POST", "mq", &self.region, &request_uri);
+        request.set_operation("CreateConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2562,6 +3333,7 @@ impl MQ for MQClient {
         let request_uri = format!("/v1/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "mq", &self.region, &request_uri);
+        request.set_operation("CreateTags");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2595,6 +3367,7 @@ impl MQ for MQClient {
         );
 
         let mut request = SignedRequest::new("POST", "mq", &self.region, &request_uri);
+        request.set_operation("CreateUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2625,6 +3398,7 @@ impl MQ for MQClient {
         let request_uri = format!("/v1/brokers/{broker_id}", broker_id = input.broker_id);
 
         let mut request = SignedRequest::new("DELETE", "mq", &self.region, &request_uri);
+        request.set_operation("DeleteBroker");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2652,6 +3426,7 @@ impl MQ for MQClient {
         let request_uri = format!("/v1/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "mq", &self.region, &request_uri);
+        request.set_operation("DeleteTags");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2688,6 +3463,7 @@ impl MQ for MQClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "mq", &self.region, &request_uri);
+        request.set_operation("DeleteUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2715,6 +3491,7 @@ impl MQ for MQClient {
         let request_uri = format!("/v1/brokers/{broker_id}", broker_id = input.broker_id);
 
         let mut request = SignedRequest::new("GET", "mq", &self.region, &request_uri);
+        request.set_operation("DescribeBroker");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2743,6 +3520,7 @@ impl MQ for MQClient {
         let request_uri = "/v1/broker-engine-types";
 
         let mut request = SignedRequest::new("GET", "mq", &self.region, &request_uri);
+        request.set_operation("DescribeBrokerEngineTypes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2785,6 +3563,7 @@ impl MQ for MQClient {
         let request_uri = "/v1/broker-instance-options";
 
         let mut request = SignedRequest::new("GET", "mq", &self.region, &request_uri);
+        request.set_operation("DescribeBrokerInstanceOptions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2833,6 +3612,7 @@ impl MQ for MQClient {
         );
 
         let mut request = SignedRequest::new("GET", "mq", &self.region, &request_uri);
+        request.set_operation("DescribeConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2867,6 +3647,7 @@ impl MQ for MQClient {
         );
 
         let mut request = SignedRequest::new("GET", "mq", &self.region, &request_uri);
+        request.set_operation("DescribeConfigurationRevision");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2898,6 +3679,7 @@ impl MQ for MQClient {
         );
 
         let mut request = SignedRequest::new("GET", "mq", &self.region, &request_uri);
+        request.set_operation("DescribeUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2925,6 +3707,7 @@ impl MQ for MQClient {
         let request_uri = "/v1/brokers";
 
         let mut request = SignedRequest::new("GET", "mq", &self.region, &request_uri);
+        request.set_operation("ListBrokers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2965,6 +3748,7 @@ impl MQ for MQClient {
         );
 
         let mut request = SignedRequest::new("GET", "mq", &self.region, &request_uri);
+        request.set_operation("ListConfigurationRevisions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3001,6 +3785,7 @@ impl MQ for MQClient {
         let request_uri = "/v1/configurations";
 
         let mut request = SignedRequest::new("GET", "mq", &self.region, &request_uri);
+        request.set_operation("ListConfigurations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3037,6 +3822,7 @@ impl MQ for MQClient {
         let request_uri = format!("/v1/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "mq", &self.region, &request_uri);
+        request.set_operation("ListTags");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3064,6 +3850,7 @@ impl MQ for MQClient {
         let request_uri = format!("/v1/brokers/{broker_id}/users", broker_id = input.broker_id);
 
         let mut request = SignedRequest::new("GET", "mq", &self.region, &request_uri);
+        request.set_operation("ListUsers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3103,6 +3890,7 @@ impl MQ for MQClient {
         );
 
         let mut request = SignedRequest::new("POST", "mq", &self.region, &request_uri);
+        request.set_operation("RebootBroker");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3130,6 +3918,7 @@ impl MQ for MQClient {
         let request_uri = format!("/v1/brokers/{broker_id}", broker_id = input.broker_id);
 
         let mut request = SignedRequest::new("PUT", "mq", &self.region, &request_uri);
+        request.set_operation("UpdateBroker");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3163,6 +3952,7 @@ impl MQ for MQClient {
         );
 
         let mut request = SignedRequest::new("PUT", "mq", &self.region, &request_uri);
+        request.set_operation("UpdateConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3197,6 +3987,7 @@ impl MQ for MQClient {
         );
 
         let mut request = SignedRequest::new("PUT", "mq", &self.region, &request_uri);
+        request.set_operation("UpdateUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3219,3 +4010,35 @@ impl MQ for MQClient {
         }
     }
 }
+impl MQClient {
+    /// Auto-paginating version of `list_brokers`, streaming every page of results.
+    pub fn list_brokers_pages(
+        &self,
+        input: ListBrokersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListBrokersResponse, ListBrokersError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MQClient, input: ListBrokersRequest| async move {
+                client.list_brokers(input).await
+            },
+            |input: &ListBrokersRequest, output: &ListBrokersResponse| {
+                Some(ListBrokersRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_brokers`, streaming the `BrokerSummaries` of every page of results.
+    pub fn list_brokers_items(
+        &self,
+        input: ListBrokersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<BrokerSummary, ListBrokersError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_brokers_pages(input),
+            |output: ListBrokersResponse| output.broker_summaries.unwrap_or_default(),
+        )
+    }
+}
@@ -66,16 +66,26 @@ pub struct ApproveAssignmentResponse {}
 pub struct Assignment {
     /// <p> The date and time the Worker accepted the assignment.</p>
     #[serde(rename = "AcceptTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub accept_time: Option<f64>,
+    pub accept_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p> The Worker's answers submitted for the HIT contained in a QuestionFormAnswers document, if the Worker provides an answer. If the Worker does not provide any answers, Answer may contain a QuestionFormAnswers document, or Answer may be empty.</p>
     #[serde(rename = "Answer")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub answer: Option<String>,
     /// <p> If the Worker has submitted results and the Requester has approved the results, ApprovalTime is the date and time the Requester approved the results. This value is omitted from the assignment if the Requester has not yet approved the results.</p>
     #[serde(rename = "ApprovalTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub approval_time: Option<f64>,
+    pub approval_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p> A unique identifier for the assignment.</p>
     #[serde(rename = "AssignmentId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -83,37 +93,155 @@ pub struct Assignment {
     /// <p> The status of the assignment.</p>
     #[serde(rename = "AssignmentStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub assignment_status: Option<String>,
+    pub assignment_status: Option<AssignmentStatus>,
     /// <p> If results have been submitted, AutoApprovalTime is the date and time the results of the assignment results are considered Approved automatically if they have not already been explicitly approved or rejected by the Requester. This value is derived from the auto-approval delay specified by the Requester in the HIT. This value is omitted from the assignment if the Worker has not yet submitted results.</p>
     #[serde(rename = "AutoApprovalTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub auto_approval_time: Option<f64>,
+    pub auto_approval_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p> The date and time of the deadline for the assignment. This value is derived from the deadline specification for the HIT and the date and time the Worker accepted the HIT.</p>
     #[serde(rename = "Deadline")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deadline: Option<f64>,
+    pub deadline: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p> The ID of the HIT.</p>
     #[serde(rename = "HITId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub hit_id: Option<String>,
     /// <p> If the Worker has submitted results and the Requester has rejected the results, RejectionTime is the date and time the Requester rejected the results.</p>
     #[serde(rename = "RejectionTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub rejection_time: Option<f64>,
+    pub rejection_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p> The feedback string included with the call to the ApproveAssignment operation or the RejectAssignment operation, if the Requester approved or rejected the assignment and specified feedback.</p>
     #[serde(rename = "RequesterFeedback")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub requester_feedback: Option<String>,
     /// <p> If the Worker has submitted results, SubmitTime is the date and time the assignment was submitted. This value is omitted from the assignment if the Worker has not yet submitted results.</p>
     #[serde(rename = "SubmitTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submit_time: Option<f64>,
+    pub submit_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p> The ID of the Worker who accepted the HIT.</p>
     #[serde(rename = "WorkerId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub worker_id: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AssignmentStatus {
+    /// `Submitted`
+    Submitted,
+    /// `Approved`
+    Approved,
+    /// `Rejected`
+    Rejected,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AssignmentStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AssignmentStatus::Submitted => "Submitted",
+            AssignmentStatus::Approved => "Approved",
+            AssignmentStatus::Rejected => "Rejected",
+            AssignmentStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AssignmentStatus {
+    fn default() -> AssignmentStatus {
+        AssignmentStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AssignmentStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AssignmentStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AssignmentStatus {
+    fn from(value: &'a str) -> AssignmentStatus {
+        match value {
+            "Submitted" => AssignmentStatus::Submitted,
+            "Approved" => AssignmentStatus::Approved,
+            "Rejected" => AssignmentStatus::Rejected,
+            _ => AssignmentStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AssignmentStatus {
+    fn from(value: String) -> AssignmentStatus {
+        match value.as_str() {
+            "Submitted" => AssignmentStatus::Submitted,
+            "Approved" => AssignmentStatus::Approved,
+            "Rejected" => AssignmentStatus::Rejected,
+            _ => AssignmentStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AssignmentStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AssignmentStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AssignmentStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AssignmentStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AssignmentStatus {
+    fn deserialize<D>(deserializer: D) -> Result<AssignmentStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct AssociateQualificationWithWorkerRequest {
@@ -150,8 +278,13 @@ pub struct BonusPayment {
     pub bonus_amount: Option<String>,
     /// <p>The date and time of when the bonus was granted.</p>
     #[serde(rename = "GrantTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub grant_time: Option<f64>,
+    pub grant_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The Reason text given when the bonus was granted, if any.</p>
     #[serde(rename = "Reason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -162,6 +295,139 @@ pub struct BonusPayment {
     pub worker_id: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Comparator {
+    /// `LessThan`
+    LessThan,
+    /// `LessThanOrEqualTo`
+    LessThanOrEqualTo,
+    /// `GreaterThan`
+    GreaterThan,
+    /// `GreaterThanOrEqualTo`
+    GreaterThanOrEqualTo,
+    /// `EqualTo`
+    EqualTo,
+    /// `NotEqualTo`
+    NotEqualTo,
+    /// `Exists`
+    Exists,
+    /// `DoesNotExist`
+    DoesNotExist,
+    /// `In`
+    In,
+    /// `NotIn`
+    NotIn,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Comparator {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Comparator::LessThan => "LessThan",
+            Comparator::LessThanOrEqualTo => "LessThanOrEqualTo",
+            Comparator::GreaterThan => "GreaterThan",
+            Comparator::GreaterThanOrEqualTo => "GreaterThanOrEqualTo",
+            Comparator::EqualTo => "EqualTo",
+            Comparator::NotEqualTo => "NotEqualTo",
+            Comparator::Exists => "Exists",
+            Comparator::DoesNotExist => "DoesNotExist",
+            Comparator::In => "In",
+            Comparator::NotIn => "NotIn",
+            Comparator::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Comparator {
+    fn default() -> Comparator {
+        Comparator::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Comparator {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Comparator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Comparator {
+    fn from(value: &'a str) -> Comparator {
+        match value {
+            "LessThan" => Comparator::LessThan,
+            "LessThanOrEqualTo" => Comparator::LessThanOrEqualTo,
+            "GreaterThan" => Comparator::GreaterThan,
+            "GreaterThanOrEqualTo" => Comparator::GreaterThanOrEqualTo,
+            "EqualTo" => Comparator::EqualTo,
+            "NotEqualTo" => Comparator::NotEqualTo,
+            "Exists" => Comparator::Exists,
+            "DoesNotExist" => Comparator::DoesNotExist,
+            "In" => Comparator::In,
+            "NotIn" => Comparator::NotIn,
+            _ => Comparator::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Comparator {
+    fn from(value: String) -> Comparator {
+        match value.as_str() {
+            "LessThan" => Comparator::LessThan,
+            "LessThanOrEqualTo" => Comparator::LessThanOrEqualTo,
+            "GreaterThan" => Comparator::GreaterThan,
+            "GreaterThanOrEqualTo" => Comparator::GreaterThanOrEqualTo,
+            "EqualTo" => Comparator::EqualTo,
+            "NotEqualTo" => Comparator::NotEqualTo,
+            "Exists" => Comparator::Exists,
+            "DoesNotExist" => Comparator::DoesNotExist,
+            "In" => Comparator::In,
+            "NotIn" => Comparator::NotIn,
+            _ => Comparator::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Comparator {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Comparator, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Comparator {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Comparator {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Comparator {
+    fn deserialize<D>(deserializer: D) -> Result<Comparator, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateAdditionalAssignmentsForHITRequest {
@@ -371,7 +637,7 @@ pub struct CreateQualificationTypeRequest {
     pub name: String,
     /// <p>The initial status of the Qualification type.</p> <p>Constraints: Valid values are: Active | Inactive</p>
     #[serde(rename = "QualificationTypeStatus")]
-    pub qualification_type_status: String,
+    pub qualification_type_status: QualificationTypeStatus,
     /// <p>The number of seconds that a Worker must wait after requesting a Qualification of the Qualification type before the worker can retry the Qualification request.</p> <p>Constraints: None. If not specified, retries are disabled and Workers can request a Qualification of this type only once, even if the Worker has not been granted the Qualification. It is not possible to disable retries for a Qualification type after it has been created with retries enabled. If you want to disable retries, you must delete existing retry-enabled Qualification type and then create a new Qualification type with retries disabled.</p>
     #[serde(rename = "RetryDelayInSeconds")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -469,6 +735,149 @@ pub struct DisassociateQualificationFromWorkerRequest {
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DisassociateQualificationFromWorkerResponse {}
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EventType {
+    /// `AssignmentAccepted`
+    AssignmentAccepted,
+    /// `AssignmentAbandoned`
+    AssignmentAbandoned,
+    /// `AssignmentReturned`
+    AssignmentReturned,
+    /// `AssignmentSubmitted`
+    AssignmentSubmitted,
+    /// `AssignmentRejected`
+    AssignmentRejected,
+    /// `AssignmentApproved`
+    AssignmentApproved,
+    /// `HITCreated`
+    HITCreated,
+    /// `HITExpired`
+    HITExpired,
+    /// `HITReviewable`
+    HITReviewable,
+    /// `HITExtended`
+    HITExtended,
+    /// `HITDisposed`
+    HITDisposed,
+    /// `Ping`
+    Ping,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EventType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EventType::AssignmentAccepted => "AssignmentAccepted",
+            EventType::AssignmentAbandoned => "AssignmentAbandoned",
+            EventType::AssignmentReturned => "AssignmentReturned",
+            EventType::AssignmentSubmitted => "AssignmentSubmitted",
+            EventType::AssignmentRejected => "AssignmentRejected",
+            EventType::AssignmentApproved => "AssignmentApproved",
+            EventType::HITCreated => "HITCreated",
+            EventType::HITExpired => "HITExpired",
+            EventType::HITReviewable => "HITReviewable",
+            EventType::HITExtended => "HITExtended",
+            EventType::HITDisposed => "HITDisposed",
+            EventType::Ping => "Ping",
+            EventType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EventType {
+    fn default() -> EventType {
+        EventType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EventType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EventType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EventType {
+    fn from(value: &'a str) -> EventType {
+        match value {
+            "AssignmentAccepted" => EventType::AssignmentAccepted,
+            "AssignmentAbandoned" => EventType::AssignmentAbandoned,
+            "AssignmentReturned" => EventType::AssignmentReturned,
+            "AssignmentSubmitted" => EventType::AssignmentSubmitted,
+            "AssignmentRejected" => EventType::AssignmentRejected,
+            "AssignmentApproved" => EventType::AssignmentApproved,
+            "HITCreated" => EventType::HITCreated,
+            "HITExpired" => EventType::HITExpired,
+            "HITReviewable" => EventType::HITReviewable,
+            "HITExtended" => EventType::HITExtended,
+            "HITDisposed" => EventType::HITDisposed,
+            "Ping" => EventType::Ping,
+            _ => EventType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EventType {
+    fn from(value: String) -> EventType {
+        match value.as_str() {
+            "AssignmentAccepted" => EventType::AssignmentAccepted,
+            "AssignmentAbandoned" => EventType::AssignmentAbandoned,
+            "AssignmentReturned" => EventType::AssignmentReturned,
+            "AssignmentSubmitted" => EventType::AssignmentSubmitted,
+            "AssignmentRejected" => EventType::AssignmentRejected,
+            "AssignmentApproved" => EventType::AssignmentApproved,
+            "HITCreated" => EventType::HITCreated,
+            "HITExpired" => EventType::HITExpired,
+            "HITReviewable" => EventType::HITReviewable,
+            "HITExtended" => EventType::HITExtended,
+            "HITDisposed" => EventType::HITDisposed,
+            "Ping" => EventType::Ping,
+            _ => EventType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EventType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EventType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EventType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EventType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EventType {
+    fn deserialize<D>(deserializer: D) -> Result<EventType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct GetAccountBalanceRequest {}
@@ -593,16 +1002,26 @@ pub struct HIT {
     pub auto_approval_delay_in_seconds: Option<i64>,
     /// <p> The date and time the HIT was created.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p> A general description of the HIT.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     /// <p>The date and time the HIT expires.</p>
     #[serde(rename = "Expiration")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expiration: Option<f64>,
+    pub expiration: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p> The ID of the HIT Group of this HIT.</p>
     #[serde(rename = "HITGroupId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -618,11 +1037,11 @@ pub struct HIT {
     /// <p> Indicates the review status of the HIT. Valid Values are NotReviewed | MarkedForReview | ReviewedAppropriate | ReviewedInappropriate.</p>
     #[serde(rename = "HITReviewStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub hit_review_status: Option<String>,
+    pub hit_review_status: Option<HITReviewStatus>,
     /// <p>The status of the HIT and its assignments. Valid Values are Assignable | Unassignable | Reviewable | Reviewing | Disposed. </p>
     #[serde(rename = "HITStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub hit_status: Option<String>,
+    pub hit_status: Option<HITStatus>,
     /// <p>The ID of the HIT type of this HIT</p>
     #[serde(rename = "HITTypeId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -668,6 +1087,104 @@ pub struct HIT {
     pub title: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum HITAccessActions {
+    /// `Accept`
+    Accept,
+    /// `PreviewAndAccept`
+    PreviewAndAccept,
+    /// `DiscoverPreviewAndAccept`
+    DiscoverPreviewAndAccept,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl HITAccessActions {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            HITAccessActions::Accept => "Accept",
+            HITAccessActions::PreviewAndAccept => "PreviewAndAccept",
+            HITAccessActions::DiscoverPreviewAndAccept => "DiscoverPreviewAndAccept",
+            HITAccessActions::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for HITAccessActions {
+    fn default() -> HITAccessActions {
+        HITAccessActions::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for HITAccessActions {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for HITAccessActions {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for HITAccessActions {
+    fn from(value: &'a str) -> HITAccessActions {
+        match value {
+            "Accept" => HITAccessActions::Accept,
+            "PreviewAndAccept" => HITAccessActions::PreviewAndAccept,
+            "DiscoverPreviewAndAccept" => HITAccessActions::DiscoverPreviewAndAccept,
+            _ => HITAccessActions::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for HITAccessActions {
+    fn from(value: String) -> HITAccessActions {
+        match value.as_str() {
+            "Accept" => HITAccessActions::Accept,
+            "PreviewAndAccept" => HITAccessActions::PreviewAndAccept,
+            "DiscoverPreviewAndAccept" => HITAccessActions::DiscoverPreviewAndAccept,
+            _ => HITAccessActions::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for HITAccessActions {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<HITAccessActions, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for HITAccessActions {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for HITAccessActions {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for HITAccessActions {
+    fn deserialize<D>(deserializer: D) -> Result<HITAccessActions, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p> The HITLayoutParameter data structure defines parameter values used with a HITLayout. A HITLayout is a reusable Amazon Mechanical Turk project template used to provide Human Intelligence Task (HIT) question data for CreateHIT. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -680,13 +1197,224 @@ pub struct HITLayoutParameter {
     pub value: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum HITReviewStatus {
+    /// `NotReviewed`
+    NotReviewed,
+    /// `MarkedForReview`
+    MarkedForReview,
+    /// `ReviewedAppropriate`
+    ReviewedAppropriate,
+    /// `ReviewedInappropriate`
+    ReviewedInappropriate,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl HITReviewStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            HITReviewStatus::NotReviewed => "NotReviewed",
+            HITReviewStatus::MarkedForReview => "MarkedForReview",
+            HITReviewStatus::ReviewedAppropriate => "ReviewedAppropriate",
+            HITReviewStatus::ReviewedInappropriate => "ReviewedInappropriate",
+            HITReviewStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for HITReviewStatus {
+    fn default() -> HITReviewStatus {
+        HITReviewStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for HITReviewStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for HITReviewStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for HITReviewStatus {
+    fn from(value: &'a str) -> HITReviewStatus {
+        match value {
+            "NotReviewed" => HITReviewStatus::NotReviewed,
+            "MarkedForReview" => HITReviewStatus::MarkedForReview,
+            "ReviewedAppropriate" => HITReviewStatus::ReviewedAppropriate,
+            "ReviewedInappropriate" => HITReviewStatus::ReviewedInappropriate,
+            _ => HITReviewStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for HITReviewStatus {
+    fn from(value: String) -> HITReviewStatus {
+        match value.as_str() {
+            "NotReviewed" => HITReviewStatus::NotReviewed,
+            "MarkedForReview" => HITReviewStatus::MarkedForReview,
+            "ReviewedAppropriate" => HITReviewStatus::ReviewedAppropriate,
+            "ReviewedInappropriate" => HITReviewStatus::ReviewedInappropriate,
+            _ => HITReviewStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for HITReviewStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<HITReviewStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for HITReviewStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for HITReviewStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for HITReviewStatus {
+    fn deserialize<D>(deserializer: D) -> Result<HITReviewStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum HITStatus {
+    /// `Assignable`
+    Assignable,
+    /// `Unassignable`
+    Unassignable,
+    /// `Reviewable`
+    Reviewable,
+    /// `Reviewing`
+    Reviewing,
+    /// `Disposed`
+    Disposed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl HITStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            HITStatus::Assignable => "Assignable",
+            HITStatus::Unassignable => "Unassignable",
+            HITStatus::Reviewable => "Reviewable",
+            HITStatus::Reviewing => "Reviewing",
+            HITStatus::Disposed => "Disposed",
+            HITStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for HITStatus {
+    fn default() -> HITStatus {
+        HITStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for HITStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for HITStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for HITStatus {
+    fn from(value: &'a str) -> HITStatus {
+        match value {
+            "Assignable" => HITStatus::Assignable,
+            "Unassignable" => HITStatus::Unassignable,
+            "Reviewable" => HITStatus::Reviewable,
+            "Reviewing" => HITStatus::Reviewing,
+            "Disposed" => HITStatus::Disposed,
+            _ => HITStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for HITStatus {
+    fn from(value: String) -> HITStatus {
+        match value.as_str() {
+            "Assignable" => HITStatus::Assignable,
+            "Unassignable" => HITStatus::Unassignable,
+            "Reviewable" => HITStatus::Reviewable,
+            "Reviewing" => HITStatus::Reviewing,
+            "Disposed" => HITStatus::Disposed,
+            _ => HITStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for HITStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<HITStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for HITStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for HITStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for HITStatus {
+    fn deserialize<D>(deserializer: D) -> Result<HITStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ListAssignmentsForHITRequest {
     /// <p>The status of the assignments to return: Submitted | Approved | Rejected</p>
     #[serde(rename = "AssignmentStatuses")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub assignment_statuses: Option<Vec<String>>,
+    pub assignment_statuses: Option<Vec<AssignmentStatus>>,
     /// <p>The ID of the HIT.</p>
     #[serde(rename = "HITId")]
     pub hit_id: String,
@@ -899,7 +1627,7 @@ pub struct ListReviewPolicyResultsForHITRequest {
     /// <p> The Policy Level(s) to retrieve review results for - HIT or Assignment. If omitted, the default behavior is to retrieve all data for both policy levels. For a list of all the described policies, see Review Policies. </p>
     #[serde(rename = "PolicyLevels")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub policy_levels: Option<Vec<String>>,
+    pub policy_levels: Option<Vec<ReviewPolicyLevel>>,
     /// <p> Specify if the operation should retrieve a list of the actions taken executing the Review Policies and their outcomes. </p>
     #[serde(rename = "RetrieveActions")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -956,7 +1684,7 @@ pub struct ListReviewableHITsRequest {
     /// <p> Can be either <code>Reviewable</code> or <code>Reviewing</code>. Reviewable is the default value. </p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ReviewableHITStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1020,7 +1748,7 @@ pub struct ListWorkersWithQualificationTypeRequest {
     /// <p> The status of the Qualifications to return. Can be <code>Granted | Revoked</code>. </p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<QualificationStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1060,15 +1788,206 @@ pub struct NotificationSpecification {
     pub destination: String,
     /// <p> The list of events that should cause notifications to be sent. Valid Values: AssignmentAccepted | AssignmentAbandoned | AssignmentReturned | AssignmentSubmitted | AssignmentRejected | AssignmentApproved | HITCreated | HITExtended | HITDisposed | HITReviewable | HITExpired | Ping. The Ping event is only valid for the SendTestEventNotification operation. </p>
     #[serde(rename = "EventTypes")]
-    pub event_types: Vec<String>,
+    pub event_types: Vec<EventType>,
     /// <p> The method Amazon Mechanical Turk uses to send the notification. Valid Values: Email | SQS | SNS. </p>
     #[serde(rename = "Transport")]
-    pub transport: String,
+    pub transport: NotificationTransport,
     /// <p>The version of the Notification API to use. Valid value is 2006-05-05.</p>
     #[serde(rename = "Version")]
     pub version: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum NotificationTransport {
+    /// `Email`
+    Email,
+    /// `SQS`
+    Sqs,
+    /// `SNS`
+    Sns,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl NotificationTransport {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            NotificationTransport::Email => "Email",
+            NotificationTransport::Sqs => "SQS",
+            NotificationTransport::Sns => "SNS",
+            NotificationTransport::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for NotificationTransport {
+    fn default() -> NotificationTransport {
+        NotificationTransport::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for NotificationTransport {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for NotificationTransport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for NotificationTransport {
+    fn from(value: &'a str) -> NotificationTransport {
+        match value {
+            "Email" => NotificationTransport::Email,
+            "SQS" => NotificationTransport::Sqs,
+            "SNS" => NotificationTransport::Sns,
+            _ => NotificationTransport::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for NotificationTransport {
+    fn from(value: String) -> NotificationTransport {
+        match value.as_str() {
+            "Email" => NotificationTransport::Email,
+            "SQS" => NotificationTransport::Sqs,
+            "SNS" => NotificationTransport::Sns,
+            _ => NotificationTransport::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for NotificationTransport {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<NotificationTransport, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for NotificationTransport {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for NotificationTransport {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for NotificationTransport {
+    fn deserialize<D>(deserializer: D) -> Result<NotificationTransport, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum NotifyWorkersFailureCode {
+    /// `SoftFailure`
+    SoftFailure,
+    /// `HardFailure`
+    HardFailure,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl NotifyWorkersFailureCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            NotifyWorkersFailureCode::SoftFailure => "SoftFailure",
+            NotifyWorkersFailureCode::HardFailure => "HardFailure",
+            NotifyWorkersFailureCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for NotifyWorkersFailureCode {
+    fn default() -> NotifyWorkersFailureCode {
+        NotifyWorkersFailureCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for NotifyWorkersFailureCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for NotifyWorkersFailureCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for NotifyWorkersFailureCode {
+    fn from(value: &'a str) -> NotifyWorkersFailureCode {
+        match value {
+            "SoftFailure" => NotifyWorkersFailureCode::SoftFailure,
+            "HardFailure" => NotifyWorkersFailureCode::HardFailure,
+            _ => NotifyWorkersFailureCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for NotifyWorkersFailureCode {
+    fn from(value: String) -> NotifyWorkersFailureCode {
+        match value.as_str() {
+            "SoftFailure" => NotifyWorkersFailureCode::SoftFailure,
+            "HardFailure" => NotifyWorkersFailureCode::HardFailure,
+            _ => NotifyWorkersFailureCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for NotifyWorkersFailureCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<NotifyWorkersFailureCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for NotifyWorkersFailureCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for NotifyWorkersFailureCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for NotifyWorkersFailureCode {
+    fn deserialize<D>(deserializer: D) -> Result<NotifyWorkersFailureCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p> When MTurk encounters an issue with notifying the Workers you specified, it returns back this object with failure details. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1076,7 +1995,7 @@ pub struct NotifyWorkersFailureStatus {
     /// <p> Encoded value for the failure type. </p>
     #[serde(rename = "NotifyWorkersFailureCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub notify_workers_failure_code: Option<String>,
+    pub notify_workers_failure_code: Option<NotifyWorkersFailureCode>,
     /// <p> A message detailing the reason the Worker could not be notified. </p>
     #[serde(rename = "NotifyWorkersFailureMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1146,8 +2065,13 @@ pub struct PolicyParameter {
 pub struct Qualification {
     /// <p> The date and time the Qualification was granted to the Worker. If the Worker's Qualification was revoked, and then re-granted based on a new Qualification request, GrantTime is the date and time of the last call to the AcceptQualificationRequest operation.</p>
     #[serde(rename = "GrantTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub grant_time: Option<f64>,
+    pub grant_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p> The value (score) of the Qualification, if the Qualification has an integer value.</p>
     #[serde(rename = "IntegerValue")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1162,7 +2086,7 @@ pub struct Qualification {
     /// <p> The status of the Qualification. Valid values are Granted | Revoked.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<QualificationStatus>,
     /// <p> The ID of the Worker who possesses the Qualification. </p>
     #[serde(rename = "WorkerId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1187,8 +2111,13 @@ pub struct QualificationRequest {
     pub qualification_type_id: Option<String>,
     /// <p>The date and time the Qualification request had a status of Submitted. This is either the time the Worker submitted answers for a Qualification test, or the time the Worker requested the Qualification if the Qualification type does not have a test. </p>
     #[serde(rename = "SubmitTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submit_time: Option<f64>,
+    pub submit_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p> The contents of the Qualification test that was presented to the Worker, if the type has a test and the Worker has submitted answers. This value is identical to the QuestionForm associated with the Qualification type at the time the Worker requests the Qualification.</p>
     #[serde(rename = "Test")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1205,10 +2134,10 @@ pub struct QualificationRequirement {
     /// <p> Setting this attribute prevents Workers whose Qualifications do not meet this QualificationRequirement from taking the specified action. Valid arguments include "Accept" (Worker cannot accept the HIT, but can preview the HIT and see it in their search results), "PreviewAndAccept" (Worker cannot accept or preview the HIT, but can see the HIT in their search results), and "DiscoverPreviewAndAccept" (Worker cannot accept, preview, or see the HIT in their search results). It's possible for you to create a HIT with multiple QualificationRequirements (which can have different values for the ActionGuarded attribute). In this case, the Worker is only permitted to perform an action when they have met all QualificationRequirements guarding the action. The actions in the order of least restrictive to most restrictive are Discover, Preview and Accept. For example, if a Worker meets all QualificationRequirements that are set to DiscoverPreviewAndAccept, but do not meet all requirements that are set with PreviewAndAccept, then the Worker will be able to Discover, i.e. see the HIT in their search result, but will not be able to Preview or Accept the HIT. ActionsGuarded should not be used in combination with the <code>RequiredToPreview</code> field. </p>
     #[serde(rename = "ActionsGuarded")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub actions_guarded: Option<String>,
+    pub actions_guarded: Option<HITAccessActions>,
     /// <p>The kind of comparison to make against a Qualification's value. You can compare a Qualification's value to an IntegerValue to see if it is LessThan, LessThanOrEqualTo, GreaterThan, GreaterThanOrEqualTo, EqualTo, or NotEqualTo the IntegerValue. You can compare it to a LocaleValue to see if it is EqualTo, or NotEqualTo the LocaleValue. You can check to see if the value is In or NotIn a set of IntegerValue or LocaleValue values. Lastly, a Qualification requirement can also test if a Qualification Exists or DoesNotExist in the user's profile, regardless of its value. </p>
     #[serde(rename = "Comparator")]
-    pub comparator: String,
+    pub comparator: Comparator,
     /// <p> The integer value to compare against the Qualification's value. IntegerValue must not be present if Comparator is Exists or DoesNotExist. IntegerValue can only be used if the Qualification type has an integer value; it cannot be used with the Worker_Locale QualificationType ID. When performing a set comparison by using the In or the NotIn comparator, you can use up to 15 IntegerValue elements in a QualificationRequirement data structure. </p>
     #[serde(rename = "IntegerValues")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1222,6 +2151,99 @@ pub struct QualificationRequirement {
     pub qualification_type_id: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum QualificationStatus {
+    /// `Granted`
+    Granted,
+    /// `Revoked`
+    Revoked,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl QualificationStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            QualificationStatus::Granted => "Granted",
+            QualificationStatus::Revoked => "Revoked",
+            QualificationStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for QualificationStatus {
+    fn default() -> QualificationStatus {
+        QualificationStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for QualificationStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for QualificationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for QualificationStatus {
+    fn from(value: &'a str) -> QualificationStatus {
+        match value {
+            "Granted" => QualificationStatus::Granted,
+            "Revoked" => QualificationStatus::Revoked,
+            _ => QualificationStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for QualificationStatus {
+    fn from(value: String) -> QualificationStatus {
+        match value.as_str() {
+            "Granted" => QualificationStatus::Granted,
+            "Revoked" => QualificationStatus::Revoked,
+            _ => QualificationStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for QualificationStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<QualificationStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for QualificationStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for QualificationStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for QualificationStatus {
+    fn deserialize<D>(deserializer: D) -> Result<QualificationStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p> The QualificationType data structure represents a Qualification type, a description of a property of a Worker that must match the requirements of a HIT for the Worker to be able to accept the HIT. The type also describes how a Worker can obtain a Qualification of that type, such as through a Qualification test. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1240,8 +2262,13 @@ pub struct QualificationType {
     pub auto_granted_value: Option<i64>,
     /// <p> The date and time the Qualification type was created. </p>
     #[serde(rename = "CreationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p> A long description for the Qualification type. </p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1265,7 +2292,7 @@ pub struct QualificationType {
     /// <p> The status of the Qualification type. A Qualification type's status determines if users can apply to receive a Qualification of this type, and if HITs can be created with requirements based on this type. Valid values are Active | Inactive. </p>
     #[serde(rename = "QualificationTypeStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub qualification_type_status: Option<String>,
+    pub qualification_type_status: Option<QualificationTypeStatus>,
     /// <p> The amount of time, in seconds, Workers must wait after taking the Qualification test before they can take it again. Workers can take a Qualification test multiple times if they were not granted the Qualification from a previous attempt, or if the test offers a gradient score and they want a better score. If not specified, retries are disabled and Workers can request a Qualification only once. </p>
     #[serde(rename = "RetryDelayInSeconds")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1280,6 +2307,99 @@ pub struct QualificationType {
     pub test_duration_in_seconds: Option<i64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum QualificationTypeStatus {
+    /// `Active`
+    Active,
+    /// `Inactive`
+    Inactive,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl QualificationTypeStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            QualificationTypeStatus::Active => "Active",
+            QualificationTypeStatus::Inactive => "Inactive",
+            QualificationTypeStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for QualificationTypeStatus {
+    fn default() -> QualificationTypeStatus {
+        QualificationTypeStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for QualificationTypeStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for QualificationTypeStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for QualificationTypeStatus {
+    fn from(value: &'a str) -> QualificationTypeStatus {
+        match value {
+            "Active" => QualificationTypeStatus::Active,
+            "Inactive" => QualificationTypeStatus::Inactive,
+            _ => QualificationTypeStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for QualificationTypeStatus {
+    fn from(value: String) -> QualificationTypeStatus {
+        match value.as_str() {
+            "Active" => QualificationTypeStatus::Active,
+            "Inactive" => QualificationTypeStatus::Inactive,
+            _ => QualificationTypeStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for QualificationTypeStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<QualificationTypeStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for QualificationTypeStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for QualificationTypeStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for QualificationTypeStatus {
+    fn deserialize<D>(deserializer: D) -> Result<QualificationTypeStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct RejectAssignmentRequest {
@@ -1325,8 +2445,13 @@ pub struct ReviewActionDetail {
     pub action_name: Option<String>,
     /// <p> The date when the action was completed.</p>
     #[serde(rename = "CompleteTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub complete_time: Option<f64>,
+    pub complete_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p> Present only when the Results have a FAILED Status.</p>
     #[serde(rename = "ErrorCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1338,7 +2463,7 @@ pub struct ReviewActionDetail {
     /// <p> The current disposition of the action: INTENDED, SUCCEEDED, FAILED, or CANCELLED. </p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ReviewActionStatus>,
     /// <p> The specific HITId or AssignmentID targeted by the action.</p>
     #[serde(rename = "TargetId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1349,6 +2474,109 @@ pub struct ReviewActionDetail {
     pub target_type: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ReviewActionStatus {
+    /// `Intended`
+    Intended,
+    /// `Succeeded`
+    Succeeded,
+    /// `Failed`
+    Failed,
+    /// `Cancelled`
+    Cancelled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ReviewActionStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReviewActionStatus::Intended => "Intended",
+            ReviewActionStatus::Succeeded => "Succeeded",
+            ReviewActionStatus::Failed => "Failed",
+            ReviewActionStatus::Cancelled => "Cancelled",
+            ReviewActionStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ReviewActionStatus {
+    fn default() -> ReviewActionStatus {
+        ReviewActionStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ReviewActionStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ReviewActionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ReviewActionStatus {
+    fn from(value: &'a str) -> ReviewActionStatus {
+        match value {
+            "Intended" => ReviewActionStatus::Intended,
+            "Succeeded" => ReviewActionStatus::Succeeded,
+            "Failed" => ReviewActionStatus::Failed,
+            "Cancelled" => ReviewActionStatus::Cancelled,
+            _ => ReviewActionStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReviewActionStatus {
+    fn from(value: String) -> ReviewActionStatus {
+        match value.as_str() {
+            "Intended" => ReviewActionStatus::Intended,
+            "Succeeded" => ReviewActionStatus::Succeeded,
+            "Failed" => ReviewActionStatus::Failed,
+            "Cancelled" => ReviewActionStatus::Cancelled,
+            _ => ReviewActionStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ReviewActionStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ReviewActionStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ReviewActionStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ReviewActionStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ReviewActionStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ReviewActionStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p> HIT Review Policy data structures represent HIT review policies, which you specify when you create a HIT. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ReviewPolicy {
@@ -1361,6 +2589,99 @@ pub struct ReviewPolicy {
     pub policy_name: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ReviewPolicyLevel {
+    /// `Assignment`
+    Assignment,
+    /// `HIT`
+    Hit,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ReviewPolicyLevel {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReviewPolicyLevel::Assignment => "Assignment",
+            ReviewPolicyLevel::Hit => "HIT",
+            ReviewPolicyLevel::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ReviewPolicyLevel {
+    fn default() -> ReviewPolicyLevel {
+        ReviewPolicyLevel::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ReviewPolicyLevel {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ReviewPolicyLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ReviewPolicyLevel {
+    fn from(value: &'a str) -> ReviewPolicyLevel {
+        match value {
+            "Assignment" => ReviewPolicyLevel::Assignment,
+            "HIT" => ReviewPolicyLevel::Hit,
+            _ => ReviewPolicyLevel::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReviewPolicyLevel {
+    fn from(value: String) -> ReviewPolicyLevel {
+        match value.as_str() {
+            "Assignment" => ReviewPolicyLevel::Assignment,
+            "HIT" => ReviewPolicyLevel::Hit,
+            _ => ReviewPolicyLevel::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ReviewPolicyLevel {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ReviewPolicyLevel, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ReviewPolicyLevel {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ReviewPolicyLevel {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ReviewPolicyLevel {
+    fn deserialize<D>(deserializer: D) -> Result<ReviewPolicyLevel, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p> Contains both ReviewResult and ReviewAction elements for a particular HIT. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1405,6 +2726,99 @@ pub struct ReviewResultDetail {
     pub value: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ReviewableHITStatus {
+    /// `Reviewable`
+    Reviewable,
+    /// `Reviewing`
+    Reviewing,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ReviewableHITStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReviewableHITStatus::Reviewable => "Reviewable",
+            ReviewableHITStatus::Reviewing => "Reviewing",
+            ReviewableHITStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ReviewableHITStatus {
+    fn default() -> ReviewableHITStatus {
+        ReviewableHITStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ReviewableHITStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ReviewableHITStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ReviewableHITStatus {
+    fn from(value: &'a str) -> ReviewableHITStatus {
+        match value {
+            "Reviewable" => ReviewableHITStatus::Reviewable,
+            "Reviewing" => ReviewableHITStatus::Reviewing,
+            _ => ReviewableHITStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReviewableHITStatus {
+    fn from(value: String) -> ReviewableHITStatus {
+        match value.as_str() {
+            "Reviewable" => ReviewableHITStatus::Reviewable,
+            "Reviewing" => ReviewableHITStatus::Reviewing,
+            _ => ReviewableHITStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ReviewableHITStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ReviewableHITStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ReviewableHITStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ReviewableHITStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ReviewableHITStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ReviewableHITStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct SendBonusRequest {
@@ -1438,7 +2852,7 @@ pub struct SendTestEventNotificationRequest {
     pub notification: NotificationSpecification,
     /// <p> The event to simulate to test the notification specification. This event is included in the test message even if the notification specification does not include the event type. The notification specification does not filter out the test event. </p>
     #[serde(rename = "TestEventType")]
-    pub test_event_type: String,
+    pub test_event_type: EventType,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1450,7 +2864,12 @@ pub struct SendTestEventNotificationResponse {}
 pub struct UpdateExpirationForHITRequest {
     /// <p> The date and time at which you want the HIT to expire </p>
     #[serde(rename = "ExpireAt")]
-    pub expire_at: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub expire_at: ::chrono::DateTime<::chrono::Utc>,
     /// <p> The HIT to update. </p>
     #[serde(rename = "HITId")]
     pub hit_id: String,
@@ -1536,7 +2955,7 @@ pub struct UpdateQualificationTypeRequest {
     /// <p>The new status of the Qualification type - Active | Inactive</p>
     #[serde(rename = "QualificationTypeStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub qualification_type_status: Option<String>,
+    pub qualification_type_status: Option<QualificationTypeStatus>,
     /// <p>The amount of time, in seconds, that Workers must wait after requesting a Qualification of the specified Qualification type before they can retry the Qualification request. It is not possible to disable retries for a Qualification type after it has been created with retries enabled. If you want to disable retries, you must dispose of the existing retry-enabled Qualification type using DisposeQualificationType and then create a new Qualification type with retries disabled using CreateQualificationType.</p>
     #[serde(rename = "RetryDelayInSeconds")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3325,11 +4744,12 @@ pub struct MechanicalTurkClient {
 impl MechanicalTurkClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> MechanicalTurkClient {
         MechanicalTurkClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "MTurk"),
         }
     }
 
@@ -3362,6 +4782,7 @@ impl MechanicalTurk for MechanicalTurkClient {
     ) -> Result<AcceptQualificationRequestResponse, RusotoError<AcceptQualificationRequestError>>
     {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("AcceptQualificationRequest");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3393,6 +4814,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: ApproveAssignmentRequest,
     ) -> Result<ApproveAssignmentResponse, RusotoError<ApproveAssignmentError>> {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("ApproveAssignment");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3427,6 +4849,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         RusotoError<AssociateQualificationWithWorkerError>,
     > {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("AssociateQualificationWithWorker");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3463,6 +4886,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         RusotoError<CreateAdditionalAssignmentsForHITError>,
     > {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("CreateAdditionalAssignmentsForHIT");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3496,6 +4920,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: CreateHITRequest,
     ) -> Result<CreateHITResponse, RusotoError<CreateHITError>> {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("CreateHIT");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MTurkRequesterServiceV20170117.CreateHIT");
@@ -3523,6 +4948,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: CreateHITTypeRequest,
     ) -> Result<CreateHITTypeResponse, RusotoError<CreateHITTypeError>> {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("CreateHITType");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3553,6 +4979,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: CreateHITWithHITTypeRequest,
     ) -> Result<CreateHITWithHITTypeResponse, RusotoError<CreateHITWithHITTypeError>> {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("CreateHITWithHITType");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3584,6 +5011,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: CreateQualificationTypeRequest,
     ) -> Result<CreateQualificationTypeResponse, RusotoError<CreateQualificationTypeError>> {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("CreateQualificationType");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3615,6 +5043,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: CreateWorkerBlockRequest,
     ) -> Result<CreateWorkerBlockResponse, RusotoError<CreateWorkerBlockError>> {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("CreateWorkerBlock");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3646,6 +5075,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: DeleteHITRequest,
     ) -> Result<DeleteHITResponse, RusotoError<DeleteHITError>> {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("DeleteHIT");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MTurkRequesterServiceV20170117.DeleteHIT");
@@ -3673,6 +5103,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: DeleteQualificationTypeRequest,
     ) -> Result<DeleteQualificationTypeResponse, RusotoError<DeleteQualificationTypeError>> {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("DeleteQualificationType");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3704,6 +5135,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: DeleteWorkerBlockRequest,
     ) -> Result<DeleteWorkerBlockResponse, RusotoError<DeleteWorkerBlockError>> {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("DeleteWorkerBlock");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3738,6 +5170,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         RusotoError<DisassociateQualificationFromWorkerError>,
     > {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("DisassociateQualificationFromWorker");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3770,6 +5203,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         &self,
     ) -> Result<GetAccountBalanceResponse, RusotoError<GetAccountBalanceError>> {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("GetAccountBalance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3800,6 +5234,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: GetAssignmentRequest,
     ) -> Result<GetAssignmentResponse, RusotoError<GetAssignmentError>> {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("GetAssignment");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3830,6 +5265,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: GetFileUploadURLRequest,
     ) -> Result<GetFileUploadURLResponse, RusotoError<GetFileUploadURLError>> {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("GetFileUploadURL");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3861,6 +5297,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: GetHITRequest,
     ) -> Result<GetHITResponse, RusotoError<GetHITError>> {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("GetHIT");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MTurkRequesterServiceV20170117.GetHIT");
@@ -3888,6 +5325,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: GetQualificationScoreRequest,
     ) -> Result<GetQualificationScoreResponse, RusotoError<GetQualificationScoreError>> {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("GetQualificationScore");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3919,6 +5357,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: GetQualificationTypeRequest,
     ) -> Result<GetQualificationTypeResponse, RusotoError<GetQualificationTypeError>> {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("GetQualificationType");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3950,6 +5389,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: ListAssignmentsForHITRequest,
     ) -> Result<ListAssignmentsForHITResponse, RusotoError<ListAssignmentsForHITError>> {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("ListAssignmentsForHIT");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3981,6 +5421,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: ListBonusPaymentsRequest,
     ) -> Result<ListBonusPaymentsResponse, RusotoError<ListBonusPaymentsError>> {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("ListBonusPayments");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4012,6 +5453,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: ListHITsRequest,
     ) -> Result<ListHITsResponse, RusotoError<ListHITsError>> {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("ListHITs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MTurkRequesterServiceV20170117.ListHITs");
@@ -4040,6 +5482,7 @@ impl MechanicalTurk for MechanicalTurkClient {
     ) -> Result<ListHITsForQualificationTypeResponse, RusotoError<ListHITsForQualificationTypeError>>
     {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("ListHITsForQualificationType");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4072,6 +5515,7 @@ impl MechanicalTurk for MechanicalTurkClient {
     ) -> Result<ListQualificationRequestsResponse, RusotoError<ListQualificationRequestsError>>
     {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("ListQualificationRequests");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4103,6 +5547,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: ListQualificationTypesRequest,
     ) -> Result<ListQualificationTypesResponse, RusotoError<ListQualificationTypesError>> {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("ListQualificationTypes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4137,6 +5582,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         RusotoError<ListReviewPolicyResultsForHITError>,
     > {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("ListReviewPolicyResultsForHIT");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4168,6 +5614,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: ListReviewableHITsRequest,
     ) -> Result<ListReviewableHITsResponse, RusotoError<ListReviewableHITsError>> {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("ListReviewableHITs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4199,6 +5646,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: ListWorkerBlocksRequest,
     ) -> Result<ListWorkerBlocksResponse, RusotoError<ListWorkerBlocksError>> {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("ListWorkerBlocks");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4233,6 +5681,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         RusotoError<ListWorkersWithQualificationTypeError>,
     > {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("ListWorkersWithQualificationType");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4266,6 +5715,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: NotifyWorkersRequest,
     ) -> Result<NotifyWorkersResponse, RusotoError<NotifyWorkersError>> {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("NotifyWorkers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4296,6 +5746,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: RejectAssignmentRequest,
     ) -> Result<RejectAssignmentResponse, RusotoError<RejectAssignmentError>> {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("RejectAssignment");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4328,6 +5779,7 @@ impl MechanicalTurk for MechanicalTurkClient {
     ) -> Result<RejectQualificationRequestResponse, RusotoError<RejectQualificationRequestError>>
     {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("RejectQualificationRequest");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4359,6 +5811,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: SendBonusRequest,
     ) -> Result<SendBonusResponse, RusotoError<SendBonusError>> {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("SendBonus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MTurkRequesterServiceV20170117.SendBonus");
@@ -4387,6 +5840,7 @@ impl MechanicalTurk for MechanicalTurkClient {
     ) -> Result<SendTestEventNotificationResponse, RusotoError<SendTestEventNotificationError>>
     {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("SendTestEventNotification");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4418,6 +5872,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: UpdateExpirationForHITRequest,
     ) -> Result<UpdateExpirationForHITResponse, RusotoError<UpdateExpirationForHITError>> {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("UpdateExpirationForHIT");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4449,6 +5904,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: UpdateHITReviewStatusRequest,
     ) -> Result<UpdateHITReviewStatusResponse, RusotoError<UpdateHITReviewStatusError>> {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("UpdateHITReviewStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4480,6 +5936,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: UpdateHITTypeOfHITRequest,
     ) -> Result<UpdateHITTypeOfHITResponse, RusotoError<UpdateHITTypeOfHITError>> {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("UpdateHITTypeOfHIT");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4512,6 +5969,7 @@ impl MechanicalTurk for MechanicalTurkClient {
     ) -> Result<UpdateNotificationSettingsResponse, RusotoError<UpdateNotificationSettingsError>>
     {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("UpdateNotificationSettings");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4543,6 +6001,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: UpdateQualificationTypeRequest,
     ) -> Result<UpdateQualificationTypeResponse, RusotoError<UpdateQualificationTypeError>> {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("UpdateQualificationType");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4568,3 +6027,310 @@ impl MechanicalTurk for MechanicalTurkClient {
         }
     }
 }
+impl MechanicalTurkClient {
+    /// Auto-paginating version of `list_assignments_for_hit`, streaming every page of results.
+    pub fn list_assignments_for_hit_pages(
+        &self,
+        input: ListAssignmentsForHITRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListAssignmentsForHITResponse,
+        ListAssignmentsForHITError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MechanicalTurkClient, input: ListAssignmentsForHITRequest| async move {
+                client.list_assignments_for_hit(input).await
+            },
+            |input: &ListAssignmentsForHITRequest, output: &ListAssignmentsForHITResponse| {
+                Some(ListAssignmentsForHITRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_assignments_for_hit`, streaming the `Assignments` of every page of results.
+    pub fn list_assignments_for_hit_items(
+        &self,
+        input: ListAssignmentsForHITRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Assignment, ListAssignmentsForHITError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_assignments_for_hit_pages(input),
+            |output: ListAssignmentsForHITResponse| output.assignments.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_bonus_payments`, streaming every page of results.
+    pub fn list_bonus_payments_pages(
+        &self,
+        input: ListBonusPaymentsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListBonusPaymentsResponse, ListBonusPaymentsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MechanicalTurkClient, input: ListBonusPaymentsRequest| async move {
+                client.list_bonus_payments(input).await
+            },
+            |input: &ListBonusPaymentsRequest, output: &ListBonusPaymentsResponse| {
+                Some(ListBonusPaymentsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_bonus_payments`, streaming the `BonusPayments` of every page of results.
+    pub fn list_bonus_payments_items(
+        &self,
+        input: ListBonusPaymentsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<BonusPayment, ListBonusPaymentsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_bonus_payments_pages(input),
+            |output: ListBonusPaymentsResponse| output.bonus_payments.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_hi_ts`, streaming every page of results.
+    pub fn list_hi_ts_pages(
+        &self,
+        input: ListHITsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListHITsResponse, ListHITsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MechanicalTurkClient, input: ListHITsRequest| async move {
+                client.list_hi_ts(input).await
+            },
+            |input: &ListHITsRequest, output: &ListHITsResponse| {
+                Some(ListHITsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_hi_ts`, streaming the `HITs` of every page of results.
+    pub fn list_hi_ts_items(
+        &self,
+        input: ListHITsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<HIT, ListHITsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_hi_ts_pages(input),
+            |output: ListHITsResponse| output.hi_ts.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_hi_ts_for_qualification_type`, streaming every page of results.
+    pub fn list_hi_ts_for_qualification_type_pages(
+        &self,
+        input: ListHITsForQualificationTypeRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListHITsForQualificationTypeResponse,
+        ListHITsForQualificationTypeError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MechanicalTurkClient, input: ListHITsForQualificationTypeRequest| async move {
+                client.list_hi_ts_for_qualification_type(input).await
+            },
+            |input: &ListHITsForQualificationTypeRequest,
+             output: &ListHITsForQualificationTypeResponse| {
+                Some(ListHITsForQualificationTypeRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_hi_ts_for_qualification_type`, streaming the `HITs` of every page of results.
+    pub fn list_hi_ts_for_qualification_type_items(
+        &self,
+        input: ListHITsForQualificationTypeRequest,
+    ) -> rusoto_core::pagination::RusotoStream<HIT, ListHITsForQualificationTypeError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_hi_ts_for_qualification_type_pages(input),
+            |output: ListHITsForQualificationTypeResponse| output.hi_ts.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_qualification_requests`, streaming every page of results.
+    pub fn list_qualification_requests_pages(
+        &self,
+        input: ListQualificationRequestsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListQualificationRequestsResponse,
+        ListQualificationRequestsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MechanicalTurkClient, input: ListQualificationRequestsRequest| async move {
+                client.list_qualification_requests(input).await
+            },
+            |input: &ListQualificationRequestsRequest,
+             output: &ListQualificationRequestsResponse| {
+                Some(ListQualificationRequestsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_qualification_requests`, streaming the `QualificationRequests` of every page of results.
+    pub fn list_qualification_requests_items(
+        &self,
+        input: ListQualificationRequestsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<QualificationRequest, ListQualificationRequestsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_qualification_requests_pages(input),
+            |output: ListQualificationRequestsResponse| {
+                output.qualification_requests.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_qualification_types`, streaming every page of results.
+    pub fn list_qualification_types_pages(
+        &self,
+        input: ListQualificationTypesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListQualificationTypesResponse,
+        ListQualificationTypesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MechanicalTurkClient, input: ListQualificationTypesRequest| async move {
+                client.list_qualification_types(input).await
+            },
+            |input: &ListQualificationTypesRequest, output: &ListQualificationTypesResponse| {
+                Some(ListQualificationTypesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_qualification_types`, streaming the `QualificationTypes` of every page of results.
+    pub fn list_qualification_types_items(
+        &self,
+        input: ListQualificationTypesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<QualificationType, ListQualificationTypesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_qualification_types_pages(input),
+            |output: ListQualificationTypesResponse| output.qualification_types.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_reviewable_hi_ts`, streaming every page of results.
+    pub fn list_reviewable_hi_ts_pages(
+        &self,
+        input: ListReviewableHITsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListReviewableHITsResponse, ListReviewableHITsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MechanicalTurkClient, input: ListReviewableHITsRequest| async move {
+                client.list_reviewable_hi_ts(input).await
+            },
+            |input: &ListReviewableHITsRequest, output: &ListReviewableHITsResponse| {
+                Some(ListReviewableHITsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_reviewable_hi_ts`, streaming the `HITs` of every page of results.
+    pub fn list_reviewable_hi_ts_items(
+        &self,
+        input: ListReviewableHITsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<HIT, ListReviewableHITsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_reviewable_hi_ts_pages(input),
+            |output: ListReviewableHITsResponse| output.hi_ts.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_worker_blocks`, streaming every page of results.
+    pub fn list_worker_blocks_pages(
+        &self,
+        input: ListWorkerBlocksRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListWorkerBlocksResponse, ListWorkerBlocksError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MechanicalTurkClient, input: ListWorkerBlocksRequest| async move {
+                client.list_worker_blocks(input).await
+            },
+            |input: &ListWorkerBlocksRequest, output: &ListWorkerBlocksResponse| {
+                Some(ListWorkerBlocksRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_worker_blocks`, streaming the `WorkerBlocks` of every page of results.
+    pub fn list_worker_blocks_items(
+        &self,
+        input: ListWorkerBlocksRequest,
+    ) -> rusoto_core::pagination::RusotoStream<WorkerBlock, ListWorkerBlocksError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_worker_blocks_pages(input),
+            |output: ListWorkerBlocksResponse| output.worker_blocks.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_workers_with_qualification_type`, streaming every page of results.
+    pub fn list_workers_with_qualification_type_pages(
+        &self,
+        input: ListWorkersWithQualificationTypeRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListWorkersWithQualificationTypeResponse,
+        ListWorkersWithQualificationTypeError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MechanicalTurkClient, input: ListWorkersWithQualificationTypeRequest| async move {
+                client.list_workers_with_qualification_type(input).await
+            },
+            |input: &ListWorkersWithQualificationTypeRequest,
+             output: &ListWorkersWithQualificationTypeResponse| {
+                Some(ListWorkersWithQualificationTypeRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_workers_with_qualification_type`, streaming the `Qualifications` of every page of results.
+    pub fn list_workers_with_qualification_type_items(
+        &self,
+        input: ListWorkersWithQualificationTypeRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Qualification, ListWorkersWithQualificationTypeError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_workers_with_qualification_type_pages(input),
+            |output: ListWorkersWithQualificationTypeResponse| {
+                output.qualifications.unwrap_or_default()
+            },
+        )
+    }
+}
@@ -141,12 +141,110 @@ impl AddTagsToResourceMessageSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ApplyMethod {
+    /// `immediate`
+    Immediate,
+    /// `pending-reboot`
+    PendingReboot,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ApplyMethod {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ApplyMethod::Immediate => "immediate",
+            ApplyMethod::PendingReboot => "pending-reboot",
+            ApplyMethod::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ApplyMethod {
+    fn default() -> ApplyMethod {
+        ApplyMethod::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ApplyMethod {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ApplyMethod {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ApplyMethod {
+    fn from(value: &'a str) -> ApplyMethod {
+        match value {
+            "immediate" => ApplyMethod::Immediate,
+            "pending-reboot" => ApplyMethod::PendingReboot,
+            _ => ApplyMethod::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ApplyMethod {
+    fn from(value: String) -> ApplyMethod {
+        match value.as_str() {
+            "immediate" => ApplyMethod::Immediate,
+            "pending-reboot" => ApplyMethod::PendingReboot,
+            _ => ApplyMethod::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ApplyMethod {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ApplyMethod, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ApplyMethod {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ApplyMethod {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ApplyMethod {
+    fn deserialize<D>(deserializer: D) -> Result<ApplyMethod, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct ApplyMethodDeserializer;
 impl ApplyMethodDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ApplyMethod, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -1499,7 +1597,7 @@ pub struct DBCluster {
     /// <p>Identifies the clone group to which the DB cluster is associated.</p>
     pub clone_group_id: Option<String>,
     /// <p>Specifies the time when the DB cluster was created, in Universal Coordinated Time (UTC).</p>
-    pub cluster_create_time: Option<String>,
+    pub cluster_create_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The Amazon Resource Name (ARN) for the DB cluster.</p>
     pub db_cluster_arn: Option<String>,
     /// <p>Contains a user-supplied DB cluster identifier. This identifier is the unique key that identifies a DB cluster.</p>
@@ -1519,7 +1617,7 @@ pub struct DBCluster {
     /// <p>Indicates if the DB cluster has deletion protection enabled. The database can't be deleted when deletion protection is enabled. </p>
     pub deletion_protection: Option<bool>,
     /// <p>Specifies the earliest time to which a database can be restored with point-in-time restore.</p>
-    pub earliest_restorable_time: Option<String>,
+    pub earliest_restorable_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A list of log types that this DB cluster is configured to export to CloudWatch Logs.</p>
     pub enabled_cloudwatch_logs_exports: Option<Vec<String>>,
     /// <p>Specifies the connection endpoint for the primary instance of the DB cluster.</p>
@@ -1535,7 +1633,7 @@ pub struct DBCluster {
     /// <p>If <code>StorageEncrypted</code> is true, the AWS KMS key identifier for the encrypted DB cluster.</p>
     pub kms_key_id: Option<String>,
     /// <p>Specifies the latest time to which a database can be restored with point-in-time restore.</p>
-    pub latest_restorable_time: Option<String>,
+    pub latest_restorable_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Contains the master username for the DB cluster.</p>
     pub master_username: Option<String>,
     /// <p>Specifies whether the DB cluster has instances in multiple Availability Zones.</p>
@@ -2181,7 +2279,7 @@ pub struct DBClusterSnapshot {
     /// <p>Provides the list of EC2 Availability Zones that instances in the DB cluster snapshot can be restored in.</p>
     pub availability_zones: Option<Vec<String>>,
     /// <p>Specifies the time when the DB cluster was created, in Universal Coordinated Time (UTC).</p>
-    pub cluster_create_time: Option<String>,
+    pub cluster_create_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Specifies the DB cluster identifier of the DB cluster that this DB cluster snapshot was created from.</p>
     pub db_cluster_identifier: Option<String>,
     /// <p>The Amazon Resource Name (ARN) for the DB cluster snapshot.</p>
@@ -2205,7 +2303,7 @@ pub struct DBClusterSnapshot {
     /// <p>Specifies the port that the DB cluster was listening on at the time of the snapshot.</p>
     pub port: Option<i64>,
     /// <p>Provides the time when the snapshot was taken, in Universal Coordinated Time (UTC).</p>
-    pub snapshot_create_time: Option<String>,
+    pub snapshot_create_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Provides the type of the DB cluster snapshot.</p>
     pub snapshot_type: Option<String>,
     /// <p>If the DB cluster snapshot was copied from a source DB cluster snapshot, the Amazon Resource Name (ARN) for the source DB cluster snapshot, otherwise, a null value.</p>
@@ -2709,13 +2807,13 @@ pub struct DBInstance {
     /// <p>True if AWS Identity and Access Management (IAM) authentication is enabled, and otherwise false.</p>
     pub iam_database_authentication_enabled: Option<bool>,
     /// <p>Provides the date and time the DB instance was created.</p>
-    pub instance_create_time: Option<String>,
+    pub instance_create_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Specifies the Provisioned IOPS (I/O operations per second) value.</p>
     pub iops: Option<i64>,
     /// <p> Not supported: The encryption for DB instances is managed by the DB cluster.</p>
     pub kms_key_id: Option<String>,
     /// <p>Specifies the latest time to which a database can be restored with point-in-time restore.</p>
-    pub latest_restorable_time: Option<String>,
+    pub latest_restorable_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>License model information for this DB instance.</p>
     pub license_model: Option<String>,
     /// <p>Contains the master username for the DB instance.</p>
@@ -4618,7 +4716,7 @@ pub struct DescribeEventsMessage {
     /// <p>The number of minutes to retrieve events for.</p> <p>Default: 60</p>
     pub duration: Option<i64>,
     /// <p> The end of the time interval for which to retrieve events, specified in ISO 8601 format. For more information about ISO 8601, go to the <a href="http://en.wikipedia.org/wiki/ISO_8601">ISO8601 Wikipedia page.</a> </p> <p>Example: 2009-07-08T18:00Z</p>
-    pub end_time: Option<String>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A list of event categories that trigger notifications for a event notification subscription.</p>
     pub event_categories: Option<Vec<String>>,
     /// <p>This parameter is not currently supported.</p>
@@ -4630,9 +4728,9 @@ pub struct DescribeEventsMessage {
     /// <p><p>The identifier of the event source for which events are returned. If not specified, then all sources are included in the response.</p> <p>Constraints:</p> <ul> <li> <p>If SourceIdentifier is supplied, SourceType must also be provided.</p> </li> <li> <p>If the source type is <code>DBInstance</code>, then a <code>DBInstanceIdentifier</code> must be supplied.</p> </li> <li> <p>If the source type is <code>DBSecurityGroup</code>, a <code>DBSecurityGroupName</code> must be supplied.</p> </li> <li> <p>If the source type is <code>DBParameterGroup</code>, a <code>DBParameterGroupName</code> must be supplied.</p> </li> <li> <p>If the source type is <code>DBSnapshot</code>, a <code>DBSnapshotIdentifier</code> must be supplied.</p> </li> <li> <p>Cannot end with a hyphen or contain two consecutive hyphens.</p> </li> </ul></p>
     pub source_identifier: Option<String>,
     /// <p>The event source to retrieve events for. If no value is specified, all events are returned.</p>
-    pub source_type: Option<String>,
+    pub source_type: Option<SourceType>,
     /// <p> The beginning of the time interval to retrieve events for, specified in ISO 8601 format. For more information about ISO 8601, go to the <a href="http://en.wikipedia.org/wiki/ISO_8601">ISO8601 Wikipedia page.</a> </p> <p>Example: 2009-07-08T18:00Z</p>
-    pub start_time: Option<String>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// Serialize `DescribeEventsMessage` contents to a `SignedRequest`.
@@ -4648,7 +4746,10 @@ impl DescribeEventsMessageSerializer {
             params.put(&format!("{}{}", prefix, "Duration"), &field_value);
         }
         if let Some(ref field_value) = obj.end_time {
-            params.put(&format!("{}{}", prefix, "EndTime"), &field_value);
+            params.put(
+                &format!("{}{}", prefix, "EndTime"),
+                &rusoto_core::serialization::TimestampFormat::Iso8601.format(field_value),
+            );
         }
         if let Some(ref field_value) = obj.event_categories {
             EventCategoriesListSerializer::serialize(
@@ -4677,7 +4778,10 @@ impl DescribeEventsMessageSerializer {
             params.put(&format!("{}{}", prefix, "SourceType"), &field_value);
         }
         if let Some(ref field_value) = obj.start_time {
-            params.put(&format!("{}{}", prefix, "StartTime"), &field_value);
+            params.put(
+                &format!("{}{}", prefix, "StartTime"),
+                &rusoto_core::serialization::TimestampFormat::Iso8601.format(field_value),
+            );
         }
     }
 }
@@ -5057,7 +5161,7 @@ impl EngineDefaultsDeserializer {
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct Event {
     /// <p>Specifies the date and time of the event.</p>
-    pub date: Option<String>,
+    pub date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Specifies the category for the event.</p>
     pub event_categories: Option<Vec<String>>,
     /// <p>Provides the text of this event.</p>
@@ -5067,7 +5171,7 @@ pub struct Event {
     /// <p>Provides the identifier for the source of the event.</p>
     pub source_identifier: Option<String>,
     /// <p>Specifies the source type for this event.</p>
-    pub source_type: Option<String>,
+    pub source_type: Option<SourceType>,
 }
 
 struct EventDeserializer;
@@ -6606,7 +6710,7 @@ pub struct Parameter {
     /// <p>Specifies the valid range of values for the parameter.</p>
     pub allowed_values: Option<String>,
     /// <p>Indicates when to apply parameter updates.</p>
-    pub apply_method: Option<String>,
+    pub apply_method: Option<ApplyMethod>,
     /// <p>Specifies the engine specific parameters type.</p>
     pub apply_type: Option<String>,
     /// <p>Specifies the valid data type for the parameter.</p>
@@ -6799,13 +6903,13 @@ pub struct PendingMaintenanceAction {
     /// <p>The type of pending maintenance action that is available for the resource.</p>
     pub action: Option<String>,
     /// <p>The date of the maintenance window when the action is applied. The maintenance action is applied to the resource during its first maintenance window after this date. If this date is specified, any <code>next-maintenance</code> opt-in requests are ignored.</p>
-    pub auto_applied_after_date: Option<String>,
+    pub auto_applied_after_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The effective date when the pending maintenance action is applied to the resource. This date takes into account opt-in requests received from the <a>ApplyPendingMaintenanceAction</a> API, the <code>AutoAppliedAfterDate</code>, and the <code>ForcedApplyDate</code>. This value is blank if an opt-in request has not been received and nothing has been specified as <code>AutoAppliedAfterDate</code> or <code>ForcedApplyDate</code>.</p>
-    pub current_apply_date: Option<String>,
+    pub current_apply_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A description providing more detail about the maintenance action.</p>
     pub description: Option<String>,
     /// <p>The date when the maintenance action is automatically applied. The maintenance action is applied to the resource on this date regardless of the maintenance window for the resource. If this date is specified, any <code>immediate</code> opt-in requests are ignored.</p>
-    pub forced_apply_date: Option<String>,
+    pub forced_apply_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Indicates the type of opt-in request that has been received for the resource.</p>
     pub opt_in_status: Option<String>,
 }
@@ -7659,7 +7763,7 @@ pub struct RestoreDBClusterToPointInTimeMessage {
     /// <p>The port number on which the new DB cluster accepts connections.</p> <p>Constraints: Value must be <code>1150-65535</code> </p> <p>Default: The same port as the original DB cluster.</p>
     pub port: Option<i64>,
     /// <p>The date and time to restore the DB cluster to.</p> <p>Valid Values: Value must be a time in Universal Coordinated Time (UTC) format</p> <p>Constraints:</p> <ul> <li> <p>Must be before the latest restorable time for the DB instance</p> </li> <li> <p>Must be specified if <code>UseLatestRestorableTime</code> parameter is not provided</p> </li> <li> <p>Cannot be specified if <code>UseLatestRestorableTime</code> parameter is true</p> </li> <li> <p>Cannot be specified if <code>RestoreType</code> parameter is <code>copy-on-write</code> </p> </li> </ul> <p>Example: <code>2015-03-07T23:45:00Z</code> </p>
-    pub restore_to_time: Option<String>,
+    pub restore_to_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The type of restore to be performed. You can specify one of the following values:</p> <ul> <li> <p> <code>full-copy</code> - The new DB cluster is restored as a full copy of the source DB cluster.</p> </li> <li> <p> <code>copy-on-write</code> - The new DB cluster is restored as a clone of the source DB cluster.</p> </li> </ul> <p>If you don't specify a <code>RestoreType</code> value, then the new DB cluster is restored as a full copy of the source DB cluster.</p>
     pub restore_type: Option<String>,
     /// <p><p>The identifier of the source DB cluster from which to restore.</p> <p>Constraints:</p> <ul> <li> <p>Must match the identifier of an existing DBCluster.</p> </li> </ul></p>
@@ -7720,7 +7824,10 @@ impl RestoreDBClusterToPointInTimeMessageSerializer {
             params.put(&format!("{}{}", prefix, "Port"), &field_value);
         }
         if let Some(ref field_value) = obj.restore_to_time {
-            params.put(&format!("{}{}", prefix, "RestoreToTime"), &field_value);
+            params.put(
+                &format!("{}{}", prefix, "RestoreToTime"),
+                &rusoto_core::serialization::TimestampFormat::Iso8601.format(field_value),
+            );
         }
         if let Some(ref field_value) = obj.restore_type {
             params.put(&format!("{}{}", prefix, "RestoreType"), &field_value);
@@ -7806,12 +7913,130 @@ impl SourceIdsListSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SourceType {
+    /// `db-instance`
+    DbInstance,
+    /// `db-parameter-group`
+    DbParameterGroup,
+    /// `db-security-group`
+    DbSecurityGroup,
+    /// `db-snapshot`
+    DbSnapshot,
+    /// `db-cluster`
+    DbCluster,
+    /// `db-cluster-snapshot`
+    DbClusterSnapshot,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SourceType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SourceType::DbInstance => "db-instance",
+            SourceType::DbParameterGroup => "db-parameter-group",
+            SourceType::DbSecurityGroup => "db-security-group",
+            SourceType::DbSnapshot => "db-snapshot",
+            SourceType::DbCluster => "db-cluster",
+            SourceType::DbClusterSnapshot => "db-cluster-snapshot",
+            SourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SourceType {
+    fn default() -> SourceType {
+        SourceType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SourceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SourceType {
+    fn from(value: &'a str) -> SourceType {
+        match value {
+            "db-instance" => SourceType::DbInstance,
+            "db-parameter-group" => SourceType::DbParameterGroup,
+            "db-security-group" => SourceType::DbSecurityGroup,
+            "db-snapshot" => SourceType::DbSnapshot,
+            "db-cluster" => SourceType::DbCluster,
+            "db-cluster-snapshot" => SourceType::DbClusterSnapshot,
+            _ => SourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SourceType {
+    fn from(value: String) -> SourceType {
+        match value.as_str() {
+            "db-instance" => SourceType::DbInstance,
+            "db-parameter-group" => SourceType::DbParameterGroup,
+            "db-security-group" => SourceType::DbSecurityGroup,
+            "db-snapshot" => SourceType::DbSnapshot,
+            "db-cluster" => SourceType::DbCluster,
+            "db-cluster-snapshot" => SourceType::DbClusterSnapshot,
+            _ => SourceType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SourceType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SourceType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SourceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for SourceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for SourceType {
+    fn deserialize<D>(deserializer: D) -> Result<SourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct SourceTypeDeserializer;
 impl SourceTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<SourceType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -7935,9 +8160,12 @@ impl SupportedTimezonesListDeserializer {
 struct TStampDeserializer;
 impl TStampDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<::chrono::DateTime<::chrono::Utc>, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::proto::xml::util::timestamp_characters(stack)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -13039,11 +13267,12 @@ pub struct NeptuneClient {
 impl NeptuneClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> NeptuneClient {
         NeptuneClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Neptune"),
         }
     }
 
@@ -13075,6 +13304,7 @@ impl Neptune for NeptuneClient {
         input: AddRoleToDBClusterMessage,
     ) -> Result<(), RusotoError<AddRoleToDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("AddRoleToDBCluster");
         let mut params = Params::new();
 
         params.put("Action", "AddRoleToDBCluster");
@@ -13106,6 +13336,7 @@ impl Neptune for NeptuneClient {
         RusotoError<AddSourceIdentifierToSubscriptionError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("AddSourceIdentifierToSubscription");
         let mut params = Params::new();
 
         params.put("Action", "AddSourceIdentifierToSubscription");
@@ -13157,6 +13388,7 @@ impl Neptune for NeptuneClient {
         input: AddTagsToResourceMessage,
     ) -> Result<(), RusotoError<AddTagsToResourceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("AddTagsToResource");
         let mut params = Params::new();
 
         params.put("Action", "AddTagsToResource");
@@ -13186,6 +13418,7 @@ impl Neptune for NeptuneClient {
     ) -> Result<ApplyPendingMaintenanceActionResult, RusotoError<ApplyPendingMaintenanceActionError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ApplyPendingMaintenanceAction");
         let mut params = Params::new();
 
         params.put("Action", "ApplyPendingMaintenanceAction");
@@ -13236,6 +13469,7 @@ impl Neptune for NeptuneClient {
     ) -> Result<CopyDBClusterParameterGroupResult, RusotoError<CopyDBClusterParameterGroupError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CopyDBClusterParameterGroup");
         let mut params = Params::new();
 
         params.put("Action", "CopyDBClusterParameterGroup");
@@ -13285,6 +13519,7 @@ impl Neptune for NeptuneClient {
         input: CopyDBClusterSnapshotMessage,
     ) -> Result<CopyDBClusterSnapshotResult, RusotoError<CopyDBClusterSnapshotError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CopyDBClusterSnapshot");
         let mut params = Params::new();
 
         params.put("Action", "CopyDBClusterSnapshot");
@@ -13334,6 +13569,7 @@ impl Neptune for NeptuneClient {
         input: CopyDBParameterGroupMessage,
     ) -> Result<CopyDBParameterGroupResult, RusotoError<CopyDBParameterGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CopyDBParameterGroup");
         let mut params = Params::new();
 
         params.put("Action", "CopyDBParameterGroup");
@@ -13383,6 +13619,7 @@ impl Neptune for NeptuneClient {
         input: CreateDBClusterMessage,
     ) -> Result<CreateDBClusterResult, RusotoError<CreateDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateDBCluster");
         let mut params = Params::new();
 
         params.put("Action", "CreateDBCluster");
@@ -13433,6 +13670,7 @@ impl Neptune for NeptuneClient {
     ) -> Result<CreateDBClusterParameterGroupResult, RusotoError<CreateDBClusterParameterGroupError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateDBClusterParameterGroup");
         let mut params = Params::new();
 
         params.put("Action", "CreateDBClusterParameterGroup");
@@ -13482,6 +13720,7 @@ impl Neptune for NeptuneClient {
         input: CreateDBClusterSnapshotMessage,
     ) -> Result<CreateDBClusterSnapshotResult, RusotoError<CreateDBClusterSnapshotError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateDBClusterSnapshot");
         let mut params = Params::new();
 
         params.put("Action", "CreateDBClusterSnapshot");
@@ -13531,6 +13770,7 @@ impl Neptune for NeptuneClient {
         input: CreateDBInstanceMessage,
     ) -> Result<CreateDBInstanceResult, RusotoError<CreateDBInstanceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateDBInstance");
         let mut params = Params::new();
 
         params.put("Action", "CreateDBInstance");
@@ -13580,6 +13820,7 @@ impl Neptune for NeptuneClient {
         input: CreateDBParameterGroupMessage,
     ) -> Result<CreateDBParameterGroupResult, RusotoError<CreateDBParameterGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateDBParameterGroup");
         let mut params = Params::new();
 
         params.put("Action", "CreateDBParameterGroup");
@@ -13629,6 +13870,7 @@ impl Neptune for NeptuneClient {
         input: CreateDBSubnetGroupMessage,
     ) -> Result<CreateDBSubnetGroupResult, RusotoError<CreateDBSubnetGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateDBSubnetGroup");
         let mut params = Params::new();
 
         params.put("Action", "CreateDBSubnetGroup");
@@ -13678,6 +13920,7 @@ impl Neptune for NeptuneClient {
         input: CreateEventSubscriptionMessage,
     ) -> Result<CreateEventSubscriptionResult, RusotoError<CreateEventSubscriptionError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateEventSubscription");
         let mut params = Params::new();
 
         params.put("Action", "CreateEventSubscription");
@@ -13727,6 +13970,7 @@ impl Neptune for NeptuneClient {
         input: DeleteDBClusterMessage,
     ) -> Result<DeleteDBClusterResult, RusotoError<DeleteDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteDBCluster");
         let mut params = Params::new();
 
         params.put("Action", "DeleteDBCluster");
@@ -13776,6 +14020,7 @@ impl Neptune for NeptuneClient {
         input: DeleteDBClusterParameterGroupMessage,
     ) -> Result<(), RusotoError<DeleteDBClusterParameterGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteDBClusterParameterGroup");
         let mut params = Params::new();
 
         params.put("Action", "DeleteDBClusterParameterGroup");
@@ -13804,6 +14049,7 @@ impl Neptune for NeptuneClient {
         input: DeleteDBClusterSnapshotMessage,
     ) -> Result<DeleteDBClusterSnapshotResult, RusotoError<DeleteDBClusterSnapshotError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteDBClusterSnapshot");
         let mut params = Params::new();
 
         params.put("Action", "DeleteDBClusterSnapshot");
@@ -13853,6 +14099,7 @@ impl Neptune for NeptuneClient {
         input: DeleteDBInstanceMessage,
     ) -> Result<DeleteDBInstanceResult, RusotoError<DeleteDBInstanceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteDBInstance");
         let mut params = Params::new();
 
         params.put("Action", "DeleteDBInstance");
@@ -13902,6 +14149,7 @@ impl Neptune for NeptuneClient {
         input: DeleteDBParameterGroupMessage,
     ) -> Result<(), RusotoError<DeleteDBParameterGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteDBParameterGroup");
         let mut params = Params::new();
 
         params.put("Action", "DeleteDBParameterGroup");
@@ -13930,6 +14178,7 @@ impl Neptune for NeptuneClient {
         input: DeleteDBSubnetGroupMessage,
     ) -> Result<(), RusotoError<DeleteDBSubnetGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteDBSubnetGroup");
         let mut params = Params::new();
 
         params.put("Action", "DeleteDBSubnetGroup");
@@ -13958,6 +14207,7 @@ impl Neptune for NeptuneClient {
         input: DeleteEventSubscriptionMessage,
     ) -> Result<DeleteEventSubscriptionResult, RusotoError<DeleteEventSubscriptionError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteEventSubscription");
         let mut params = Params::new();
 
         params.put("Action", "DeleteEventSubscription");
@@ -14008,6 +14258,7 @@ impl Neptune for NeptuneClient {
     ) -> Result<DBClusterParameterGroupsMessage, RusotoError<DescribeDBClusterParameterGroupsError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBClusterParameterGroups");
         let mut params = Params::new();
 
         params.put("Action", "DescribeDBClusterParameterGroups");
@@ -14059,6 +14310,7 @@ impl Neptune for NeptuneClient {
         input: DescribeDBClusterParametersMessage,
     ) -> Result<DBClusterParameterGroupDetails, RusotoError<DescribeDBClusterParametersError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBClusterParameters");
         let mut params = Params::new();
 
         params.put("Action", "DescribeDBClusterParameters");
@@ -14111,6 +14363,7 @@ impl Neptune for NeptuneClient {
         RusotoError<DescribeDBClusterSnapshotAttributesError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBClusterSnapshotAttributes");
         let mut params = Params::new();
 
         params.put("Action", "DescribeDBClusterSnapshotAttributes");
@@ -14162,6 +14415,7 @@ impl Neptune for NeptuneClient {
         input: DescribeDBClusterSnapshotsMessage,
     ) -> Result<DBClusterSnapshotMessage, RusotoError<DescribeDBClusterSnapshotsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBClusterSnapshots");
         let mut params = Params::new();
 
         params.put("Action", "DescribeDBClusterSnapshots");
@@ -14211,6 +14465,7 @@ impl Neptune for NeptuneClient {
         input: DescribeDBClustersMessage,
     ) -> Result<DBClusterMessage, RusotoError<DescribeDBClustersError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBClusters");
         let mut params = Params::new();
 
         params.put("Action", "DescribeDBClusters");
@@ -14258,6 +14513,7 @@ impl Neptune for NeptuneClient {
         input: DescribeDBEngineVersionsMessage,
     ) -> Result<DBEngineVersionMessage, RusotoError<DescribeDBEngineVersionsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBEngineVersions");
         let mut params = Params::new();
 
         params.put("Action", "DescribeDBEngineVersions");
@@ -14307,6 +14563,7 @@ impl Neptune for NeptuneClient {
         input: DescribeDBInstancesMessage,
     ) -> Result<DBInstanceMessage, RusotoError<DescribeDBInstancesError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBInstances");
         let mut params = Params::new();
 
         params.put("Action", "DescribeDBInstances");
@@ -14356,6 +14613,7 @@ impl Neptune for NeptuneClient {
         input: DescribeDBParameterGroupsMessage,
     ) -> Result<DBParameterGroupsMessage, RusotoError<DescribeDBParameterGroupsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBParameterGroups");
         let mut params = Params::new();
 
         params.put("Action", "DescribeDBParameterGroups");
@@ -14405,6 +14663,7 @@ impl Neptune for NeptuneClient {
         input: DescribeDBParametersMessage,
     ) -> Result<DBParameterGroupDetails, RusotoError<DescribeDBParametersError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBParameters");
         let mut params = Params::new();
 
         params.put("Action", "DescribeDBParameters");
@@ -14454,6 +14713,7 @@ impl Neptune for NeptuneClient {
         input: DescribeDBSubnetGroupsMessage,
     ) -> Result<DBSubnetGroupMessage, RusotoError<DescribeDBSubnetGroupsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBSubnetGroups");
         let mut params = Params::new();
 
         params.put("Action", "DescribeDBSubnetGroups");
@@ -14506,6 +14766,7 @@ impl Neptune for NeptuneClient {
         RusotoError<DescribeEngineDefaultClusterParametersError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeEngineDefaultClusterParameters");
         let mut params = Params::new();
 
         params.put("Action", "DescribeEngineDefaultClusterParameters");
@@ -14560,6 +14821,7 @@ impl Neptune for NeptuneClient {
         RusotoError<DescribeEngineDefaultParametersError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeEngineDefaultParameters");
         let mut params = Params::new();
 
         params.put("Action", "DescribeEngineDefaultParameters");
@@ -14611,6 +14873,7 @@ impl Neptune for NeptuneClient {
         input: DescribeEventCategoriesMessage,
     ) -> Result<EventCategoriesMessage, RusotoError<DescribeEventCategoriesError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeEventCategories");
         let mut params = Params::new();
 
         params.put("Action", "DescribeEventCategories");
@@ -14660,6 +14923,7 @@ impl Neptune for NeptuneClient {
         input: DescribeEventSubscriptionsMessage,
     ) -> Result<EventSubscriptionsMessage, RusotoError<DescribeEventSubscriptionsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeEventSubscriptions");
         let mut params = Params::new();
 
         params.put("Action", "DescribeEventSubscriptions");
@@ -14709,6 +14973,7 @@ impl Neptune for NeptuneClient {
         input: DescribeEventsMessage,
     ) -> Result<EventsMessage, RusotoError<DescribeEventsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeEvents");
         let mut params = Params::new();
 
         params.put("Action", "DescribeEvents");
@@ -14758,6 +15023,7 @@ impl Neptune for NeptuneClient {
         RusotoError<DescribeOrderableDBInstanceOptionsError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeOrderableDBInstanceOptions");
         let mut params = Params::new();
 
         params.put("Action", "DescribeOrderableDBInstanceOptions");
@@ -14810,6 +15076,7 @@ impl Neptune for NeptuneClient {
     ) -> Result<PendingMaintenanceActionsMessage, RusotoError<DescribePendingMaintenanceActionsError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribePendingMaintenanceActions");
         let mut params = Params::new();
 
         params.put("Action", "DescribePendingMaintenanceActions");
@@ -14864,6 +15131,7 @@ impl Neptune for NeptuneClient {
         RusotoError<DescribeValidDBInstanceModificationsError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeValidDBInstanceModifications");
         let mut params = Params::new();
 
         params.put("Action", "DescribeValidDBInstanceModifications");
@@ -14915,6 +15183,7 @@ impl Neptune for NeptuneClient {
         input: FailoverDBClusterMessage,
     ) -> Result<FailoverDBClusterResult, RusotoError<FailoverDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("FailoverDBCluster");
         let mut params = Params::new();
 
         params.put("Action", "FailoverDBCluster");
@@ -14964,6 +15233,7 @@ impl Neptune for NeptuneClient {
         input: ListTagsForResourceMessage,
     ) -> Result<TagListMessage, RusotoError<ListTagsForResourceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ListTagsForResource");
         let mut params = Params::new();
 
         params.put("Action", "ListTagsForResource");
@@ -15011,6 +15281,7 @@ impl Neptune for NeptuneClient {
         input: ModifyDBClusterMessage,
     ) -> Result<ModifyDBClusterResult, RusotoError<ModifyDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyDBCluster");
         let mut params = Params::new();
 
         params.put("Action", "ModifyDBCluster");
@@ -15061,6 +15332,7 @@ impl Neptune for NeptuneClient {
     ) -> Result<DBClusterParameterGroupNameMessage, RusotoError<ModifyDBClusterParameterGroupError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyDBClusterParameterGroup");
         let mut params = Params::new();
 
         params.put("Action", "ModifyDBClusterParameterGroup");
@@ -15113,6 +15385,7 @@ impl Neptune for NeptuneClient {
         RusotoError<ModifyDBClusterSnapshotAttributeError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyDBClusterSnapshotAttribute");
         let mut params = Params::new();
 
         params.put("Action", "ModifyDBClusterSnapshotAttribute");
@@ -15164,6 +15437,7 @@ impl Neptune for NeptuneClient {
         input: ModifyDBInstanceMessage,
     ) -> Result<ModifyDBInstanceResult, RusotoError<ModifyDBInstanceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyDBInstance");
         let mut params = Params::new();
 
         params.put("Action", "ModifyDBInstance");
@@ -15213,6 +15487,7 @@ impl Neptune for NeptuneClient {
         input: ModifyDBParameterGroupMessage,
     ) -> Result<DBParameterGroupNameMessage, RusotoError<ModifyDBParameterGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyDBParameterGroup");
         let mut params = Params::new();
 
         params.put("Action", "ModifyDBParameterGroup");
@@ -15262,6 +15537,7 @@ impl Neptune for NeptuneClient {
         input: ModifyDBSubnetGroupMessage,
     ) -> Result<ModifyDBSubnetGroupResult, RusotoError<ModifyDBSubnetGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyDBSubnetGroup");
         let mut params = Params::new();
 
         params.put("Action", "ModifyDBSubnetGroup");
@@ -15311,6 +15587,7 @@ impl Neptune for NeptuneClient {
         input: ModifyEventSubscriptionMessage,
     ) -> Result<ModifyEventSubscriptionResult, RusotoError<ModifyEventSubscriptionError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyEventSubscription");
         let mut params = Params::new();
 
         params.put("Action", "ModifyEventSubscription");
@@ -15361,6 +15638,7 @@ impl Neptune for NeptuneClient {
     ) -> Result<PromoteReadReplicaDBClusterResult, RusotoError<PromoteReadReplicaDBClusterError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("PromoteReadReplicaDBCluster");
         let mut params = Params::new();
 
         params.put("Action", "PromoteReadReplicaDBCluster");
@@ -15410,6 +15688,7 @@ impl Neptune for NeptuneClient {
         input: RebootDBInstanceMessage,
     ) -> Result<RebootDBInstanceResult, RusotoError<RebootDBInstanceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("RebootDBInstance");
         let mut params = Params::new();
 
         params.put("Action", "RebootDBInstance");
@@ -15459,6 +15738,7 @@ impl Neptune for NeptuneClient {
         input: RemoveRoleFromDBClusterMessage,
     ) -> Result<(), RusotoError<RemoveRoleFromDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("RemoveRoleFromDBCluster");
         let mut params = Params::new();
 
         params.put("Action", "RemoveRoleFromDBCluster");
@@ -15490,6 +15770,7 @@ impl Neptune for NeptuneClient {
         RusotoError<RemoveSourceIdentifierFromSubscriptionError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("RemoveSourceIdentifierFromSubscription");
         let mut params = Params::new();
 
         params.put("Action", "RemoveSourceIdentifierFromSubscription");
@@ -15541,6 +15822,7 @@ impl Neptune for NeptuneClient {
         input: RemoveTagsFromResourceMessage,
     ) -> Result<(), RusotoError<RemoveTagsFromResourceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("RemoveTagsFromResource");
         let mut params = Params::new();
 
         params.put("Action", "RemoveTagsFromResource");
@@ -15570,6 +15852,7 @@ impl Neptune for NeptuneClient {
     ) -> Result<DBClusterParameterGroupNameMessage, RusotoError<ResetDBClusterParameterGroupError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ResetDBClusterParameterGroup");
         let mut params = Params::new();
 
         params.put("Action", "ResetDBClusterParameterGroup");
@@ -15619,6 +15902,7 @@ impl Neptune for NeptuneClient {
         input: ResetDBParameterGroupMessage,
     ) -> Result<DBParameterGroupNameMessage, RusotoError<ResetDBParameterGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ResetDBParameterGroup");
         let mut params = Params::new();
 
         params.put("Action", "ResetDBParameterGroup");
@@ -15669,6 +15953,7 @@ impl Neptune for NeptuneClient {
     ) -> Result<RestoreDBClusterFromSnapshotResult, RusotoError<RestoreDBClusterFromSnapshotError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("RestoreDBClusterFromSnapshot");
         let mut params = Params::new();
 
         params.put("Action", "RestoreDBClusterFromSnapshot");
@@ -15719,6 +16004,7 @@ impl Neptune for NeptuneClient {
     ) -> Result<RestoreDBClusterToPointInTimeResult, RusotoError<RestoreDBClusterToPointInTimeError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("RestoreDBClusterToPointInTime");
         let mut params = Params::new();
 
         params.put("Action", "RestoreDBClusterToPointInTime");
@@ -15762,3 +16048,679 @@ impl Neptune for NeptuneClient {
         Ok(result)
     }
 }
+impl NeptuneClient {
+    /// Auto-paginating version of `describe_db_cluster_parameter_groups`, streaming every page of results.
+    pub fn describe_db_cluster_parameter_groups_pages(
+        &self,
+        input: DescribeDBClusterParameterGroupsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DBClusterParameterGroupsMessage,
+        DescribeDBClusterParameterGroupsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: NeptuneClient, input: DescribeDBClusterParameterGroupsMessage| async move {
+                client.describe_db_cluster_parameter_groups(input).await
+            },
+            |input: &DescribeDBClusterParameterGroupsMessage,
+             output: &DBClusterParameterGroupsMessage| {
+                Some(DescribeDBClusterParameterGroupsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_cluster_parameter_groups`, streaming the `DBClusterParameterGroups` of every page of results.
+    pub fn describe_db_cluster_parameter_groups_items(
+        &self,
+        input: DescribeDBClusterParameterGroupsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DBClusterParameterGroup,
+        DescribeDBClusterParameterGroupsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_db_cluster_parameter_groups_pages(input),
+            |output: DBClusterParameterGroupsMessage| {
+                output.db_cluster_parameter_groups.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_cluster_parameters`, streaming every page of results.
+    pub fn describe_db_cluster_parameters_pages(
+        &self,
+        input: DescribeDBClusterParametersMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DBClusterParameterGroupDetails,
+        DescribeDBClusterParametersError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: NeptuneClient, input: DescribeDBClusterParametersMessage| async move {
+                client.describe_db_cluster_parameters(input).await
+            },
+            |input: &DescribeDBClusterParametersMessage,
+             output: &DBClusterParameterGroupDetails| {
+                Some(DescribeDBClusterParametersMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_cluster_parameters`, streaming the `Parameters` of every page of results.
+    pub fn describe_db_cluster_parameters_items(
+        &self,
+        input: DescribeDBClusterParametersMessage,
+    ) -> rusoto_core::pagination::RusotoStream<Parameter, DescribeDBClusterParametersError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_db_cluster_parameters_pages(input),
+            |output: DBClusterParameterGroupDetails| output.parameters.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_cluster_snapshots`, streaming every page of results.
+    pub fn describe_db_cluster_snapshots_pages(
+        &self,
+        input: DescribeDBClusterSnapshotsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DBClusterSnapshotMessage,
+        DescribeDBClusterSnapshotsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: NeptuneClient, input: DescribeDBClusterSnapshotsMessage| async move {
+                client.describe_db_cluster_snapshots(input).await
+            },
+            |input: &DescribeDBClusterSnapshotsMessage, output: &DBClusterSnapshotMessage| {
+                Some(DescribeDBClusterSnapshotsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_cluster_snapshots`, streaming the `DBClusterSnapshots` of every page of results.
+    pub fn describe_db_cluster_snapshots_items(
+        &self,
+        input: DescribeDBClusterSnapshotsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<DBClusterSnapshot, DescribeDBClusterSnapshotsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_db_cluster_snapshots_pages(input),
+            |output: DBClusterSnapshotMessage| output.db_cluster_snapshots.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_clusters`, streaming every page of results.
+    pub fn describe_db_clusters_pages(
+        &self,
+        input: DescribeDBClustersMessage,
+    ) -> rusoto_core::pagination::RusotoStream<DBClusterMessage, DescribeDBClustersError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: NeptuneClient, input: DescribeDBClustersMessage| async move {
+                client.describe_db_clusters(input).await
+            },
+            |input: &DescribeDBClustersMessage, output: &DBClusterMessage| {
+                Some(DescribeDBClustersMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_clusters`, streaming the `DBClusters` of every page of results.
+    pub fn describe_db_clusters_items(
+        &self,
+        input: DescribeDBClustersMessage,
+    ) -> rusoto_core::pagination::RusotoStream<DBCluster, DescribeDBClustersError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_db_clusters_pages(input),
+            |output: DBClusterMessage| output.db_clusters.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_engine_versions`, streaming every page of results.
+    pub fn describe_db_engine_versions_pages(
+        &self,
+        input: DescribeDBEngineVersionsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<DBEngineVersionMessage, DescribeDBEngineVersionsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: NeptuneClient, input: DescribeDBEngineVersionsMessage| async move {
+                client.describe_db_engine_versions(input).await
+            },
+            |input: &DescribeDBEngineVersionsMessage, output: &DBEngineVersionMessage| {
+                Some(DescribeDBEngineVersionsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_engine_versions`, streaming the `DBEngineVersions` of every page of results.
+    pub fn describe_db_engine_versions_items(
+        &self,
+        input: DescribeDBEngineVersionsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<DBEngineVersion, DescribeDBEngineVersionsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_db_engine_versions_pages(input),
+            |output: DBEngineVersionMessage| output.db_engine_versions.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_instances`, streaming every page of results.
+    pub fn describe_db_instances_pages(
+        &self,
+        input: DescribeDBInstancesMessage,
+    ) -> rusoto_core::pagination::RusotoStream<DBInstanceMessage, DescribeDBInstancesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: NeptuneClient, input: DescribeDBInstancesMessage| async move {
+                client.describe_db_instances(input).await
+            },
+            |input: &DescribeDBInstancesMessage, output: &DBInstanceMessage| {
+                Some(DescribeDBInstancesMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_instances`, streaming the `DBInstances` of every page of results.
+    pub fn describe_db_instances_items(
+        &self,
+        input: DescribeDBInstancesMessage,
+    ) -> rusoto_core::pagination::RusotoStream<DBInstance, DescribeDBInstancesError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_db_instances_pages(input),
+            |output: DBInstanceMessage| output.db_instances.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_parameter_groups`, streaming every page of results.
+    pub fn describe_db_parameter_groups_pages(
+        &self,
+        input: DescribeDBParameterGroupsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DBParameterGroupsMessage,
+        DescribeDBParameterGroupsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: NeptuneClient, input: DescribeDBParameterGroupsMessage| async move {
+                client.describe_db_parameter_groups(input).await
+            },
+            |input: &DescribeDBParameterGroupsMessage, output: &DBParameterGroupsMessage| {
+                Some(DescribeDBParameterGroupsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_parameter_groups`, streaming the `DBParameterGroups` of every page of results.
+    pub fn describe_db_parameter_groups_items(
+        &self,
+        input: DescribeDBParameterGroupsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<DBParameterGroup, DescribeDBParameterGroupsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_db_parameter_groups_pages(input),
+            |output: DBParameterGroupsMessage| output.db_parameter_groups.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_parameters`, streaming every page of results.
+    pub fn describe_db_parameters_pages(
+        &self,
+        input: DescribeDBParametersMessage,
+    ) -> rusoto_core::pagination::RusotoStream<DBParameterGroupDetails, DescribeDBParametersError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: NeptuneClient, input: DescribeDBParametersMessage| async move {
+                client.describe_db_parameters(input).await
+            },
+            |input: &DescribeDBParametersMessage, output: &DBParameterGroupDetails| {
+                Some(DescribeDBParametersMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_parameters`, streaming the `Parameters` of every page of results.
+    pub fn describe_db_parameters_items(
+        &self,
+        input: DescribeDBParametersMessage,
+    ) -> rusoto_core::pagination::RusotoStream<Parameter, DescribeDBParametersError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_db_parameters_pages(input),
+            |output: DBParameterGroupDetails| output.parameters.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_subnet_groups`, streaming every page of results.
+    pub fn describe_db_subnet_groups_pages(
+        &self,
+        input: DescribeDBSubnetGroupsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<DBSubnetGroupMessage, DescribeDBSubnetGroupsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: NeptuneClient, input: DescribeDBSubnetGroupsMessage| async move {
+                client.describe_db_subnet_groups(input).await
+            },
+            |input: &DescribeDBSubnetGroupsMessage, output: &DBSubnetGroupMessage| {
+                Some(DescribeDBSubnetGroupsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_subnet_groups`, streaming the `DBSubnetGroups` of every page of results.
+    pub fn describe_db_subnet_groups_items(
+        &self,
+        input: DescribeDBSubnetGroupsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<DBSubnetGroup, DescribeDBSubnetGroupsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_db_subnet_groups_pages(input),
+            |output: DBSubnetGroupMessage| output.db_subnet_groups.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_engine_default_parameters`, streaming every page of results.
+    pub fn describe_engine_default_parameters_pages(
+        &self,
+        input: DescribeEngineDefaultParametersMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeEngineDefaultParametersResult,
+        DescribeEngineDefaultParametersError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: NeptuneClient, input: DescribeEngineDefaultParametersMessage| async move {
+                client.describe_engine_default_parameters(input).await
+            },
+            |input: &DescribeEngineDefaultParametersMessage,
+             output: &DescribeEngineDefaultParametersResult| {
+                Some(DescribeEngineDefaultParametersMessage {
+                    marker: Some(
+                        output
+                            .engine_defaults
+                            .as_ref()
+                            .and_then(|v| v.marker.clone())?,
+                    ),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_engine_default_parameters`, streaming the `EngineDefaults.Parameters` of every page of results.
+    pub fn describe_engine_default_parameters_items(
+        &self,
+        input: DescribeEngineDefaultParametersMessage,
+    ) -> rusoto_core::pagination::RusotoStream<Parameter, DescribeEngineDefaultParametersError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_engine_default_parameters_pages(input),
+            |output: DescribeEngineDefaultParametersResult| {
+                output
+                    .engine_defaults
+                    .and_then(|v| v.parameters)
+                    .unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_event_subscriptions`, streaming every page of results.
+    pub fn describe_event_subscriptions_pages(
+        &self,
+        input: DescribeEventSubscriptionsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        EventSubscriptionsMessage,
+        DescribeEventSubscriptionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: NeptuneClient, input: DescribeEventSubscriptionsMessage| async move {
+                client.describe_event_subscriptions(input).await
+            },
+            |input: &DescribeEventSubscriptionsMessage, output: &EventSubscriptionsMessage| {
+                Some(DescribeEventSubscriptionsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_event_subscriptions`, streaming the `EventSubscriptionsList` of every page of results.
+    pub fn describe_event_subscriptions_items(
+        &self,
+        input: DescribeEventSubscriptionsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<EventSubscription, DescribeEventSubscriptionsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_event_subscriptions_pages(input),
+            |output: EventSubscriptionsMessage| output.event_subscriptions_list.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_events`, streaming every page of results.
+    pub fn describe_events_pages(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<EventsMessage, DescribeEventsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: NeptuneClient, input: DescribeEventsMessage| async move {
+                client.describe_events(input).await
+            },
+            |input: &DescribeEventsMessage, output: &EventsMessage| {
+                Some(DescribeEventsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_events`, streaming the `Events` of every page of results.
+    pub fn describe_events_items(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<Event, DescribeEventsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_events_pages(input),
+            |output: EventsMessage| output.events.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_orderable_db_instance_options`, streaming every page of results.
+    pub fn describe_orderable_db_instance_options_pages(
+        &self,
+        input: DescribeOrderableDBInstanceOptionsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        OrderableDBInstanceOptionsMessage,
+        DescribeOrderableDBInstanceOptionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: NeptuneClient, input: DescribeOrderableDBInstanceOptionsMessage| async move {
+                client.describe_orderable_db_instance_options(input).await
+            },
+            |input: &DescribeOrderableDBInstanceOptionsMessage,
+             output: &OrderableDBInstanceOptionsMessage| {
+                Some(DescribeOrderableDBInstanceOptionsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_orderable_db_instance_options`, streaming the `OrderableDBInstanceOptions` of every page of results.
+    pub fn describe_orderable_db_instance_options_items(
+        &self,
+        input: DescribeOrderableDBInstanceOptionsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        OrderableDBInstanceOption,
+        DescribeOrderableDBInstanceOptionsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_orderable_db_instance_options_pages(input),
+            |output: OrderableDBInstanceOptionsMessage| {
+                output.orderable_db_instance_options.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_pending_maintenance_actions`, streaming every page of results.
+    pub fn describe_pending_maintenance_actions_pages(
+        &self,
+        input: DescribePendingMaintenanceActionsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        PendingMaintenanceActionsMessage,
+        DescribePendingMaintenanceActionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: NeptuneClient, input: DescribePendingMaintenanceActionsMessage| async move {
+                client.describe_pending_maintenance_actions(input).await
+            },
+            |input: &DescribePendingMaintenanceActionsMessage,
+             output: &PendingMaintenanceActionsMessage| {
+                Some(DescribePendingMaintenanceActionsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_pending_maintenance_actions`, streaming the `PendingMaintenanceActions` of every page of results.
+    pub fn describe_pending_maintenance_actions_items(
+        &self,
+        input: DescribePendingMaintenanceActionsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ResourcePendingMaintenanceActions,
+        DescribePendingMaintenanceActionsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_pending_maintenance_actions_pages(input),
+            |output: PendingMaintenanceActionsMessage| {
+                output.pending_maintenance_actions.unwrap_or_default()
+            },
+        )
+    }
+}
+impl NeptuneClient {
+    /// Waits until `describe_db_instances` reports the `DBInstanceAvailable` state, polling every 30 seconds up to 60 times.
+    pub async fn wait_until_db_instance_available(
+        &self,
+        input: DescribeDBInstancesMessage,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeDBInstancesError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(30), 60);
+        self.wait_until_db_instance_available_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_db_instance_available`, polling as often and as many times as `config` says.
+    pub async fn wait_until_db_instance_available_with_config(
+        &self,
+        input: DescribeDBInstancesMessage,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeDBInstancesError>> {
+        rusoto_core::waiter::wait(
+            "DBInstanceAvailable",
+            &config,
+            || self.describe_db_instances(input.clone()),
+            |result: &Result<DBInstanceMessage, RusotoError<DescribeDBInstancesError>>| match result
+            {
+                Ok(output)
+                    if rusoto_core::waiter::all_match(
+                        output
+                            .db_instances
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.db_instance_status.iter()),
+                        |v| v == "available",
+                    ) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if output
+                        .db_instances
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.db_instance_status.iter())
+                        .any(|v| v == "deleted") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .db_instances
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.db_instance_status.iter())
+                        .any(|v| v == "deleting") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .db_instances
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.db_instance_status.iter())
+                        .any(|v| v == "failed") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .db_instances
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.db_instance_status.iter())
+                        .any(|v| v == "incompatible-restore") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .db_instances
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.db_instance_status.iter())
+                        .any(|v| v == "incompatible-parameters") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+
+    /// Waits until `describe_db_instances` reports the `DBInstanceDeleted` state, polling every 30 seconds up to 60 times.
+    pub async fn wait_until_db_instance_deleted(
+        &self,
+        input: DescribeDBInstancesMessage,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeDBInstancesError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(30), 60);
+        self.wait_until_db_instance_deleted_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_db_instance_deleted`, polling as often and as many times as `config` says.
+    pub async fn wait_until_db_instance_deleted_with_config(
+        &self,
+        input: DescribeDBInstancesMessage,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeDBInstancesError>> {
+        rusoto_core::waiter::wait(
+            "DBInstanceDeleted",
+            &config,
+            || self.describe_db_instances(input.clone()),
+            |result: &Result<DBInstanceMessage, RusotoError<DescribeDBInstancesError>>| match result
+            {
+                Ok(output)
+                    if rusoto_core::waiter::all_match(
+                        output
+                            .db_instances
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.db_instance_status.iter()),
+                        |v| v == "deleted",
+                    ) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Err(RusotoError::Service(DescribeDBInstancesError::DBInstanceNotFoundFault(_))) => {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Err(RusotoError::Unknown(response))
+                    if rusoto_core::retry::error_code(response).as_deref()
+                        == Some("DBInstanceNotFound") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if output
+                        .db_instances
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.db_instance_status.iter())
+                        .any(|v| v == "creating") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .db_instances
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.db_instance_status.iter())
+                        .any(|v| v == "modifying") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .db_instances
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.db_instance_status.iter())
+                        .any(|v| v == "rebooting") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .db_instances
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.db_instance_status.iter())
+                        .any(|v| v == "resetting-master-credentials") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+}
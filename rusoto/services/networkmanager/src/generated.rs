@@ -250,9 +250,112 @@ pub struct CustomerGatewayAssociation {
     /// <p>The association state.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<CustomerGatewayAssociationState>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CustomerGatewayAssociationState {
+    /// `PENDING`
+    Pending,
+    /// `AVAILABLE`
+    Available,
+    /// `DELETING`
+    Deleting,
+    /// `DELETED`
+    Deleted,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CustomerGatewayAssociationState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CustomerGatewayAssociationState::Pending => "PENDING",
+            CustomerGatewayAssociationState::Available => "AVAILABLE",
+            CustomerGatewayAssociationState::Deleting => "DELETING",
+            CustomerGatewayAssociationState::Deleted => "DELETED",
+            CustomerGatewayAssociationState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CustomerGatewayAssociationState {
+    fn default() -> CustomerGatewayAssociationState {
+        CustomerGatewayAssociationState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CustomerGatewayAssociationState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CustomerGatewayAssociationState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CustomerGatewayAssociationState {
+    fn from(value: &'a str) -> CustomerGatewayAssociationState {
+        match value {
+            "PENDING" => CustomerGatewayAssociationState::Pending,
+            "AVAILABLE" => CustomerGatewayAssociationState::Available,
+            "DELETING" => CustomerGatewayAssociationState::Deleting,
+            "DELETED" => CustomerGatewayAssociationState::Deleted,
+            _ => CustomerGatewayAssociationState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CustomerGatewayAssociationState {
+    fn from(value: String) -> CustomerGatewayAssociationState {
+        match value.as_str() {
+            "PENDING" => CustomerGatewayAssociationState::Pending,
+            "AVAILABLE" => CustomerGatewayAssociationState::Available,
+            "DELETING" => CustomerGatewayAssociationState::Deleting,
+            "DELETED" => CustomerGatewayAssociationState::Deleted,
+            _ => CustomerGatewayAssociationState::Unknown(value),
+        }
+    }
 }
 
+impl ::std::str::FromStr for CustomerGatewayAssociationState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CustomerGatewayAssociationState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CustomerGatewayAssociationState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CustomerGatewayAssociationState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CustomerGatewayAssociationState {
+    fn deserialize<D>(deserializer: D) -> Result<CustomerGatewayAssociationState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct DeleteDeviceRequest {
@@ -386,8 +489,13 @@ pub struct DescribeGlobalNetworksResponse {
 pub struct Device {
     /// <p>The date and time that the site was created.</p>
     #[serde(rename = "CreatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The description of the device.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -423,7 +531,7 @@ pub struct Device {
     /// <p>The device state.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<DeviceState>,
     /// <p>The tags for the device.</p>
     #[serde(rename = "Tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -438,6 +546,109 @@ pub struct Device {
     pub vendor: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DeviceState {
+    /// `PENDING`
+    Pending,
+    /// `AVAILABLE`
+    Available,
+    /// `DELETING`
+    Deleting,
+    /// `UPDATING`
+    Updating,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DeviceState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeviceState::Pending => "PENDING",
+            DeviceState::Available => "AVAILABLE",
+            DeviceState::Deleting => "DELETING",
+            DeviceState::Updating => "UPDATING",
+            DeviceState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DeviceState {
+    fn default() -> DeviceState {
+        DeviceState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DeviceState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DeviceState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DeviceState {
+    fn from(value: &'a str) -> DeviceState {
+        match value {
+            "PENDING" => DeviceState::Pending,
+            "AVAILABLE" => DeviceState::Available,
+            "DELETING" => DeviceState::Deleting,
+            "UPDATING" => DeviceState::Updating,
+            _ => DeviceState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeviceState {
+    fn from(value: String) -> DeviceState {
+        match value.as_str() {
+            "PENDING" => DeviceState::Pending,
+            "AVAILABLE" => DeviceState::Available,
+            "DELETING" => DeviceState::Deleting,
+            "UPDATING" => DeviceState::Updating,
+            _ => DeviceState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DeviceState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DeviceState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DeviceState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DeviceState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeviceState {
+    fn deserialize<D>(deserializer: D) -> Result<DeviceState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct DisassociateCustomerGatewayRequest {
@@ -705,8 +916,13 @@ pub struct GetTransitGatewayRegistrationsResponse {
 pub struct GlobalNetwork {
     /// <p>The date and time that the global network was created.</p>
     #[serde(rename = "CreatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The description of the global network.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -722,13 +938,116 @@ pub struct GlobalNetwork {
     /// <p>The state of the global network.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<GlobalNetworkState>,
     /// <p>The tags for the global network.</p>
     #[serde(rename = "Tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tags: Option<Vec<Tag>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum GlobalNetworkState {
+    /// `PENDING`
+    Pending,
+    /// `AVAILABLE`
+    Available,
+    /// `DELETING`
+    Deleting,
+    /// `UPDATING`
+    Updating,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl GlobalNetworkState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            GlobalNetworkState::Pending => "PENDING",
+            GlobalNetworkState::Available => "AVAILABLE",
+            GlobalNetworkState::Deleting => "DELETING",
+            GlobalNetworkState::Updating => "UPDATING",
+            GlobalNetworkState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for GlobalNetworkState {
+    fn default() -> GlobalNetworkState {
+        GlobalNetworkState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for GlobalNetworkState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for GlobalNetworkState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for GlobalNetworkState {
+    fn from(value: &'a str) -> GlobalNetworkState {
+        match value {
+            "PENDING" => GlobalNetworkState::Pending,
+            "AVAILABLE" => GlobalNetworkState::Available,
+            "DELETING" => GlobalNetworkState::Deleting,
+            "UPDATING" => GlobalNetworkState::Updating,
+            _ => GlobalNetworkState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for GlobalNetworkState {
+    fn from(value: String) -> GlobalNetworkState {
+        match value.as_str() {
+            "PENDING" => GlobalNetworkState::Pending,
+            "AVAILABLE" => GlobalNetworkState::Available,
+            "DELETING" => GlobalNetworkState::Deleting,
+            "UPDATING" => GlobalNetworkState::Updating,
+            _ => GlobalNetworkState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for GlobalNetworkState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<GlobalNetworkState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for GlobalNetworkState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for GlobalNetworkState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for GlobalNetworkState {
+    fn deserialize<D>(deserializer: D) -> Result<GlobalNetworkState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes a link.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -739,8 +1058,13 @@ pub struct Link {
     pub bandwidth: Option<Bandwidth>,
     /// <p>The date and time that the link was created.</p>
     #[serde(rename = "CreatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The description of the link.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -768,7 +1092,7 @@ pub struct Link {
     /// <p>The state of the link.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<LinkState>,
     /// <p>The tags for the link.</p>
     #[serde(rename = "Tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -794,13 +1118,219 @@ pub struct LinkAssociation {
     /// <p>The state of the association.</p>
     #[serde(rename = "LinkAssociationState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub link_association_state: Option<String>,
+    pub link_association_state: Option<LinkAssociationState>,
     /// <p>The ID of the link.</p>
     #[serde(rename = "LinkId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub link_id: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LinkAssociationState {
+    /// `PENDING`
+    Pending,
+    /// `AVAILABLE`
+    Available,
+    /// `DELETING`
+    Deleting,
+    /// `DELETED`
+    Deleted,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LinkAssociationState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LinkAssociationState::Pending => "PENDING",
+            LinkAssociationState::Available => "AVAILABLE",
+            LinkAssociationState::Deleting => "DELETING",
+            LinkAssociationState::Deleted => "DELETED",
+            LinkAssociationState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LinkAssociationState {
+    fn default() -> LinkAssociationState {
+        LinkAssociationState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LinkAssociationState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LinkAssociationState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LinkAssociationState {
+    fn from(value: &'a str) -> LinkAssociationState {
+        match value {
+            "PENDING" => LinkAssociationState::Pending,
+            "AVAILABLE" => LinkAssociationState::Available,
+            "DELETING" => LinkAssociationState::Deleting,
+            "DELETED" => LinkAssociationState::Deleted,
+            _ => LinkAssociationState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LinkAssociationState {
+    fn from(value: String) -> LinkAssociationState {
+        match value.as_str() {
+            "PENDING" => LinkAssociationState::Pending,
+            "AVAILABLE" => LinkAssociationState::Available,
+            "DELETING" => LinkAssociationState::Deleting,
+            "DELETED" => LinkAssociationState::Deleted,
+            _ => LinkAssociationState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LinkAssociationState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LinkAssociationState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LinkAssociationState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LinkAssociationState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LinkAssociationState {
+    fn deserialize<D>(deserializer: D) -> Result<LinkAssociationState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LinkState {
+    /// `PENDING`
+    Pending,
+    /// `AVAILABLE`
+    Available,
+    /// `DELETING`
+    Deleting,
+    /// `UPDATING`
+    Updating,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LinkState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LinkState::Pending => "PENDING",
+            LinkState::Available => "AVAILABLE",
+            LinkState::Deleting => "DELETING",
+            LinkState::Updating => "UPDATING",
+            LinkState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LinkState {
+    fn default() -> LinkState {
+        LinkState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LinkState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LinkState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LinkState {
+    fn from(value: &'a str) -> LinkState {
+        match value {
+            "PENDING" => LinkState::Pending,
+            "AVAILABLE" => LinkState::Available,
+            "DELETING" => LinkState::Deleting,
+            "UPDATING" => LinkState::Updating,
+            _ => LinkState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LinkState {
+    fn from(value: String) -> LinkState {
+        match value.as_str() {
+            "PENDING" => LinkState::Pending,
+            "AVAILABLE" => LinkState::Available,
+            "DELETING" => LinkState::Deleting,
+            "UPDATING" => LinkState::Updating,
+            _ => LinkState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LinkState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LinkState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LinkState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LinkState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LinkState {
+    fn deserialize<D>(deserializer: D) -> Result<LinkState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ListTagsForResourceRequest {
@@ -861,8 +1391,13 @@ pub struct RegisterTransitGatewayResponse {
 pub struct Site {
     /// <p>The date and time that the site was created.</p>
     #[serde(rename = "CreatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The description of the site.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -886,13 +1421,116 @@ pub struct Site {
     /// <p>The state of the site.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<SiteState>,
     /// <p>The tags for the site.</p>
     #[serde(rename = "Tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tags: Option<Vec<Tag>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SiteState {
+    /// `PENDING`
+    Pending,
+    /// `AVAILABLE`
+    Available,
+    /// `DELETING`
+    Deleting,
+    /// `UPDATING`
+    Updating,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SiteState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SiteState::Pending => "PENDING",
+            SiteState::Available => "AVAILABLE",
+            SiteState::Deleting => "DELETING",
+            SiteState::Updating => "UPDATING",
+            SiteState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SiteState {
+    fn default() -> SiteState {
+        SiteState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SiteState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SiteState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SiteState {
+    fn from(value: &'a str) -> SiteState {
+        match value {
+            "PENDING" => SiteState::Pending,
+            "AVAILABLE" => SiteState::Available,
+            "DELETING" => SiteState::Deleting,
+            "UPDATING" => SiteState::Updating,
+            _ => SiteState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SiteState {
+    fn from(value: String) -> SiteState {
+        match value.as_str() {
+            "PENDING" => SiteState::Pending,
+            "AVAILABLE" => SiteState::Available,
+            "DELETING" => SiteState::Deleting,
+            "UPDATING" => SiteState::Updating,
+            _ => SiteState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SiteState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SiteState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SiteState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SiteState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SiteState {
+    fn deserialize<D>(deserializer: D) -> Result<SiteState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes a tag.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Tag {
@@ -939,6 +1577,114 @@ pub struct TransitGatewayRegistration {
     pub transit_gateway_arn: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TransitGatewayRegistrationState {
+    /// `PENDING`
+    Pending,
+    /// `AVAILABLE`
+    Available,
+    /// `DELETING`
+    Deleting,
+    /// `DELETED`
+    Deleted,
+    /// `FAILED`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TransitGatewayRegistrationState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TransitGatewayRegistrationState::Pending => "PENDING",
+            TransitGatewayRegistrationState::Available => "AVAILABLE",
+            TransitGatewayRegistrationState::Deleting => "DELETING",
+            TransitGatewayRegistrationState::Deleted => "DELETED",
+            TransitGatewayRegistrationState::Failed => "FAILED",
+            TransitGatewayRegistrationState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TransitGatewayRegistrationState {
+    fn default() -> TransitGatewayRegistrationState {
+        TransitGatewayRegistrationState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TransitGatewayRegistrationState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TransitGatewayRegistrationState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TransitGatewayRegistrationState {
+    fn from(value: &'a str) -> TransitGatewayRegistrationState {
+        match value {
+            "PENDING" => TransitGatewayRegistrationState::Pending,
+            "AVAILABLE" => TransitGatewayRegistrationState::Available,
+            "DELETING" => TransitGatewayRegistrationState::Deleting,
+            "DELETED" => TransitGatewayRegistrationState::Deleted,
+            "FAILED" => TransitGatewayRegistrationState::Failed,
+            _ => TransitGatewayRegistrationState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TransitGatewayRegistrationState {
+    fn from(value: String) -> TransitGatewayRegistrationState {
+        match value.as_str() {
+            "PENDING" => TransitGatewayRegistrationState::Pending,
+            "AVAILABLE" => TransitGatewayRegistrationState::Available,
+            "DELETING" => TransitGatewayRegistrationState::Deleting,
+            "DELETED" => TransitGatewayRegistrationState::Deleted,
+            "FAILED" => TransitGatewayRegistrationState::Failed,
+            _ => TransitGatewayRegistrationState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TransitGatewayRegistrationState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TransitGatewayRegistrationState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TransitGatewayRegistrationState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TransitGatewayRegistrationState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TransitGatewayRegistrationState {
+    fn deserialize<D>(deserializer: D) -> Result<TransitGatewayRegistrationState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes the status of a transit gateway registration.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -946,7 +1692,7 @@ pub struct TransitGatewayRegistrationStateReason {
     /// <p>The code for the state reason.</p>
     #[serde(rename = "Code")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub code: Option<String>,
+    pub code: Option<TransitGatewayRegistrationState>,
     /// <p>The message for the state reason.</p>
     #[serde(rename = "Message")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1109,6 +1855,109 @@ pub struct ValidationExceptionField {
     pub name: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ValidationExceptionReason {
+    /// `UnknownOperation`
+    UnknownOperation,
+    /// `CannotParse`
+    CannotParse,
+    /// `FieldValidationFailed`
+    FieldValidationFailed,
+    /// `Other`
+    Other,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ValidationExceptionReason {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ValidationExceptionReason::UnknownOperation => "UnknownOperation",
+            ValidationExceptionReason::CannotParse => "CannotParse",
+            ValidationExceptionReason::FieldValidationFailed => "FieldValidationFailed",
+            ValidationExceptionReason::Other => "Other",
+            ValidationExceptionReason::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ValidationExceptionReason {
+    fn default() -> ValidationExceptionReason {
+        ValidationExceptionReason::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ValidationExceptionReason {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ValidationExceptionReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ValidationExceptionReason {
+    fn from(value: &'a str) -> ValidationExceptionReason {
+        match value {
+            "UnknownOperation" => ValidationExceptionReason::UnknownOperation,
+            "CannotParse" => ValidationExceptionReason::CannotParse,
+            "FieldValidationFailed" => ValidationExceptionReason::FieldValidationFailed,
+            "Other" => ValidationExceptionReason::Other,
+            _ => ValidationExceptionReason::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ValidationExceptionReason {
+    fn from(value: String) -> ValidationExceptionReason {
+        match value.as_str() {
+            "UnknownOperation" => ValidationExceptionReason::UnknownOperation,
+            "CannotParse" => ValidationExceptionReason::CannotParse,
+            "FieldValidationFailed" => ValidationExceptionReason::FieldValidationFailed,
+            "Other" => ValidationExceptionReason::Other,
+            _ => ValidationExceptionReason::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ValidationExceptionReason {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ValidationExceptionReason, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ValidationExceptionReason {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ValidationExceptionReason {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ValidationExceptionReason {
+    fn deserialize<D>(deserializer: D) -> Result<ValidationExceptionReason, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// Errors returned by AssociateCustomerGateway
 #[derive(Debug, PartialEq)]
 pub enum AssociateCustomerGatewayError {
@@ -2884,11 +3733,12 @@ pub struct NetworkManagerClient {
 impl NetworkManagerClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> NetworkManagerClient {
         NetworkManagerClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "NetworkManager"),
         }
     }
 
@@ -2925,6 +3775,7 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("POST", "networkmanager", &self.region, &request_uri);
+        request.set_operation("AssociateCustomerGateway");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2958,6 +3809,7 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("POST", "networkmanager", &self.region, &request_uri);
+        request.set_operation("AssociateLink");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2991,6 +3843,7 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("POST", "networkmanager", &self.region, &request_uri);
+        request.set_operation("CreateDevice");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3021,6 +3874,7 @@ impl NetworkManager for NetworkManagerClient {
         let request_uri = "/global-networks";
 
         let mut request = SignedRequest::new("POST", "networkmanager", &self.region, &request_uri);
+        request.set_operation("CreateGlobalNetwork");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3054,6 +3908,7 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("POST", "networkmanager", &self.region, &request_uri);
+        request.set_operation("CreateLink");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3087,6 +3942,7 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("POST", "networkmanager", &self.region, &request_uri);
+        request.set_operation("CreateSite");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3122,6 +3978,7 @@ impl NetworkManager for NetworkManagerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "networkmanager", &self.region, &request_uri);
+        request.set_operation("DeleteDevice");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3153,6 +4010,7 @@ impl NetworkManager for NetworkManagerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "networkmanager", &self.region, &request_uri);
+        request.set_operation("DeleteGlobalNetwork");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3185,6 +4043,7 @@ impl NetworkManager for NetworkManagerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "networkmanager", &self.region, &request_uri);
+        request.set_operation("DeleteLink");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3217,6 +4076,7 @@ impl NetworkManager for NetworkManagerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "networkmanager", &self.region, &request_uri);
+        request.set_operation("DeleteSite");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3245,6 +4105,7 @@ impl NetworkManager for NetworkManagerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "networkmanager", &self.region, &request_uri);
+        request.set_operation("DeregisterTransitGateway");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3272,6 +4133,7 @@ impl NetworkManager for NetworkManagerClient {
         let request_uri = "/global-networks";
 
         let mut request = SignedRequest::new("GET", "networkmanager", &self.region, &request_uri);
+        request.set_operation("DescribeGlobalNetworks");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3315,6 +4177,7 @@ impl NetworkManager for NetworkManagerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "networkmanager", &self.region, &request_uri);
+        request.set_operation("DisassociateCustomerGateway");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3346,6 +4209,7 @@ impl NetworkManager for NetworkManagerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "networkmanager", &self.region, &request_uri);
+        request.set_operation("DisassociateLink");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3384,6 +4248,7 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("GET", "networkmanager", &self.region, &request_uri);
+        request.set_operation("GetCustomerGatewayAssociations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3428,6 +4293,7 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("GET", "networkmanager", &self.region, &request_uri);
+        request.set_operation("GetDevices");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3475,6 +4341,7 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("GET", "networkmanager", &self.region, &request_uri);
+        request.set_operation("GetLinkAssociations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3520,6 +4387,7 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("GET", "networkmanager", &self.region, &request_uri);
+        request.set_operation("GetLinks");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3573,6 +4441,7 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("GET", "networkmanager", &self.region, &request_uri);
+        request.set_operation("GetSites");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3620,6 +4489,7 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("GET", "networkmanager", &self.region, &request_uri);
+        request.set_operation("GetTransitGatewayRegistrations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3661,6 +4531,7 @@ impl NetworkManager for NetworkManagerClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "networkmanager", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3691,6 +4562,7 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("POST", "networkmanager", &self.region, &request_uri);
+        request.set_operation("RegisterTransitGateway");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3721,6 +4593,7 @@ impl NetworkManager for NetworkManagerClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "networkmanager", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3752,6 +4625,7 @@ impl NetworkManager for NetworkManagerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "networkmanager", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3789,6 +4663,7 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "networkmanager", &self.region, &request_uri);
+        request.set_operation("UpdateDevice");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3822,6 +4697,7 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "networkmanager", &self.region, &request_uri);
+        request.set_operation("UpdateGlobalNetwork");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3856,6 +4732,7 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "networkmanager", &self.region, &request_uri);
+        request.set_operation("UpdateLink");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3890,6 +4767,7 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "networkmanager", &self.region, &request_uri);
+        request.set_operation("UpdateSite");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3912,3 +4790,243 @@ impl NetworkManager for NetworkManagerClient {
         }
     }
 }
+impl NetworkManagerClient {
+    /// Auto-paginating version of `describe_global_networks`, streaming every page of results.
+    pub fn describe_global_networks_pages(
+        &self,
+        input: DescribeGlobalNetworksRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeGlobalNetworksResponse,
+        DescribeGlobalNetworksError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: NetworkManagerClient, input: DescribeGlobalNetworksRequest| async move {
+                client.describe_global_networks(input).await
+            },
+            |input: &DescribeGlobalNetworksRequest, output: &DescribeGlobalNetworksResponse| {
+                Some(DescribeGlobalNetworksRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_global_networks`, streaming the `GlobalNetworks` of every page of results.
+    pub fn describe_global_networks_items(
+        &self,
+        input: DescribeGlobalNetworksRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GlobalNetwork, DescribeGlobalNetworksError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_global_networks_pages(input),
+            |output: DescribeGlobalNetworksResponse| output.global_networks.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_customer_gateway_associations`, streaming every page of results.
+    pub fn get_customer_gateway_associations_pages(
+        &self,
+        input: GetCustomerGatewayAssociationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        GetCustomerGatewayAssociationsResponse,
+        GetCustomerGatewayAssociationsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: NetworkManagerClient, input: GetCustomerGatewayAssociationsRequest| async move {
+                client.get_customer_gateway_associations(input).await
+            },
+            |input: &GetCustomerGatewayAssociationsRequest,
+             output: &GetCustomerGatewayAssociationsResponse| {
+                Some(GetCustomerGatewayAssociationsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_customer_gateway_associations`, streaming the `CustomerGatewayAssociations` of every page of results.
+    pub fn get_customer_gateway_associations_items(
+        &self,
+        input: GetCustomerGatewayAssociationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        CustomerGatewayAssociation,
+        GetCustomerGatewayAssociationsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.get_customer_gateway_associations_pages(input),
+            |output: GetCustomerGatewayAssociationsResponse| {
+                output.customer_gateway_associations.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_devices`, streaming every page of results.
+    pub fn get_devices_pages(
+        &self,
+        input: GetDevicesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetDevicesResponse, GetDevicesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: NetworkManagerClient, input: GetDevicesRequest| async move {
+                client.get_devices(input).await
+            },
+            |input: &GetDevicesRequest, output: &GetDevicesResponse| {
+                Some(GetDevicesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_devices`, streaming the `Devices` of every page of results.
+    pub fn get_devices_items(
+        &self,
+        input: GetDevicesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Device, GetDevicesError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_devices_pages(input),
+            |output: GetDevicesResponse| output.devices.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_link_associations`, streaming every page of results.
+    pub fn get_link_associations_pages(
+        &self,
+        input: GetLinkAssociationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetLinkAssociationsResponse, GetLinkAssociationsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: NetworkManagerClient, input: GetLinkAssociationsRequest| async move {
+                client.get_link_associations(input).await
+            },
+            |input: &GetLinkAssociationsRequest, output: &GetLinkAssociationsResponse| {
+                Some(GetLinkAssociationsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_link_associations`, streaming the `LinkAssociations` of every page of results.
+    pub fn get_link_associations_items(
+        &self,
+        input: GetLinkAssociationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<LinkAssociation, GetLinkAssociationsError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_link_associations_pages(input),
+            |output: GetLinkAssociationsResponse| output.link_associations.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_links`, streaming every page of results.
+    pub fn get_links_pages(
+        &self,
+        input: GetLinksRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetLinksResponse, GetLinksError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: NetworkManagerClient, input: GetLinksRequest| async move {
+                client.get_links(input).await
+            },
+            |input: &GetLinksRequest, output: &GetLinksResponse| {
+                Some(GetLinksRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_links`, streaming the `Links` of every page of results.
+    pub fn get_links_items(
+        &self,
+        input: GetLinksRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Link, GetLinksError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_links_pages(input),
+            |output: GetLinksResponse| output.links.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_sites`, streaming every page of results.
+    pub fn get_sites_pages(
+        &self,
+        input: GetSitesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetSitesResponse, GetSitesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: NetworkManagerClient, input: GetSitesRequest| async move {
+                client.get_sites(input).await
+            },
+            |input: &GetSitesRequest, output: &GetSitesResponse| {
+                Some(GetSitesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_sites`, streaming the `Sites` of every page of results.
+    pub fn get_sites_items(
+        &self,
+        input: GetSitesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Site, GetSitesError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_sites_pages(input),
+            |output: GetSitesResponse| output.sites.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_transit_gateway_registrations`, streaming every page of results.
+    pub fn get_transit_gateway_registrations_pages(
+        &self,
+        input: GetTransitGatewayRegistrationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        GetTransitGatewayRegistrationsResponse,
+        GetTransitGatewayRegistrationsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: NetworkManagerClient, input: GetTransitGatewayRegistrationsRequest| async move {
+                client.get_transit_gateway_registrations(input).await
+            },
+            |input: &GetTransitGatewayRegistrationsRequest,
+             output: &GetTransitGatewayRegistrationsResponse| {
+                Some(GetTransitGatewayRegistrationsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_transit_gateway_registrations`, streaming the `TransitGatewayRegistrations` of every page of results.
+    pub fn get_transit_gateway_registrations_items(
+        &self,
+        input: GetTransitGatewayRegistrationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        TransitGatewayRegistration,
+        GetTransitGatewayRegistrationsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.get_transit_gateway_registrations_pages(input),
+            |output: GetTransitGatewayRegistrationsResponse| {
+                output.transit_gateway_registrations.unwrap_or_default()
+            },
+        )
+    }
+}
@@ -53,7 +53,7 @@ pub struct App {
     /// <p>The stack attributes.</p>
     #[serde(rename = "Attributes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub attributes: Option<::std::collections::HashMap<String, String>>,
+    pub attributes: Option<::std::collections::HashMap<AppAttributesKeys, String>>,
     /// <p>When the app was created.</p>
     #[serde(rename = "CreatedAt")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -97,9 +97,323 @@ pub struct App {
     /// <p>The app type.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<AppType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AppAttributesKeys {
+    /// `DocumentRoot`
+    DocumentRoot,
+    /// `RailsEnv`
+    RailsEnv,
+    /// `AutoBundleOnDeploy`
+    AutoBundleOnDeploy,
+    /// `AwsFlowRubySettings`
+    AwsFlowRubySettings,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AppAttributesKeys {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AppAttributesKeys::DocumentRoot => "DocumentRoot",
+            AppAttributesKeys::RailsEnv => "RailsEnv",
+            AppAttributesKeys::AutoBundleOnDeploy => "AutoBundleOnDeploy",
+            AppAttributesKeys::AwsFlowRubySettings => "AwsFlowRubySettings",
+            AppAttributesKeys::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AppAttributesKeys {
+    fn default() -> AppAttributesKeys {
+        AppAttributesKeys::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AppAttributesKeys {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AppAttributesKeys {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AppAttributesKeys {
+    fn from(value: &'a str) -> AppAttributesKeys {
+        match value {
+            "DocumentRoot" => AppAttributesKeys::DocumentRoot,
+            "RailsEnv" => AppAttributesKeys::RailsEnv,
+            "AutoBundleOnDeploy" => AppAttributesKeys::AutoBundleOnDeploy,
+            "AwsFlowRubySettings" => AppAttributesKeys::AwsFlowRubySettings,
+            _ => AppAttributesKeys::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AppAttributesKeys {
+    fn from(value: String) -> AppAttributesKeys {
+        match value.as_str() {
+            "DocumentRoot" => AppAttributesKeys::DocumentRoot,
+            "RailsEnv" => AppAttributesKeys::RailsEnv,
+            "AutoBundleOnDeploy" => AppAttributesKeys::AutoBundleOnDeploy,
+            "AwsFlowRubySettings" => AppAttributesKeys::AwsFlowRubySettings,
+            _ => AppAttributesKeys::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AppAttributesKeys {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AppAttributesKeys, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AppAttributesKeys {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AppAttributesKeys {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for AppAttributesKeys {
+    fn deserialize<D>(deserializer: D) -> Result<AppAttributesKeys, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AppType {
+    /// `aws-flow-ruby`
+    AwsFlowRuby,
+    /// `java`
+    Java,
+    /// `rails`
+    Rails,
+    /// `php`
+    Php,
+    /// `nodejs`
+    Nodejs,
+    /// `static`
+    Static,
+    /// `other`
+    Other,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AppType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AppType::AwsFlowRuby => "aws-flow-ruby",
+            AppType::Java => "java",
+            AppType::Rails => "rails",
+            AppType::Php => "php",
+            AppType::Nodejs => "nodejs",
+            AppType::Static => "static",
+            AppType::Other => "other",
+            AppType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AppType {
+    fn default() -> AppType {
+        AppType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AppType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AppType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AppType {
+    fn from(value: &'a str) -> AppType {
+        match value {
+            "aws-flow-ruby" => AppType::AwsFlowRuby,
+            "java" => AppType::Java,
+            "rails" => AppType::Rails,
+            "php" => AppType::Php,
+            "nodejs" => AppType::Nodejs,
+            "static" => AppType::Static,
+            "other" => AppType::Other,
+            _ => AppType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AppType {
+    fn from(value: String) -> AppType {
+        match value.as_str() {
+            "aws-flow-ruby" => AppType::AwsFlowRuby,
+            "java" => AppType::Java,
+            "rails" => AppType::Rails,
+            "php" => AppType::Php,
+            "nodejs" => AppType::Nodejs,
+            "static" => AppType::Static,
+            "other" => AppType::Other,
+            _ => AppType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AppType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AppType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AppType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AppType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AppType {
+    fn deserialize<D>(deserializer: D) -> Result<AppType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Architecture {
+    /// `x86_64`
+    X86_64,
+    /// `i386`
+    I386,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Architecture {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Architecture::X86_64 => "x86_64",
+            Architecture::I386 => "i386",
+            Architecture::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Architecture {
+    fn default() -> Architecture {
+        Architecture::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Architecture {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Architecture {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Architecture {
+    fn from(value: &'a str) -> Architecture {
+        match value {
+            "x86_64" => Architecture::X86_64,
+            "i386" => Architecture::I386,
+            _ => Architecture::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Architecture {
+    fn from(value: String) -> Architecture {
+        match value.as_str() {
+            "x86_64" => Architecture::X86_64,
+            "i386" => Architecture::I386,
+            _ => Architecture::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Architecture {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Architecture, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Architecture {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Architecture {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Architecture {
+    fn deserialize<D>(deserializer: D) -> Result<Architecture, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct AssignInstanceRequest {
@@ -179,6 +493,99 @@ pub struct AutoScalingThresholds {
     pub thresholds_wait_time: Option<i64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AutoScalingType {
+    /// `load`
+    Load,
+    /// `timer`
+    Timer,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AutoScalingType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AutoScalingType::Load => "load",
+            AutoScalingType::Timer => "timer",
+            AutoScalingType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AutoScalingType {
+    fn default() -> AutoScalingType {
+        AutoScalingType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AutoScalingType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AutoScalingType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AutoScalingType {
+    fn from(value: &'a str) -> AutoScalingType {
+        match value {
+            "load" => AutoScalingType::Load,
+            "timer" => AutoScalingType::Timer,
+            _ => AutoScalingType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AutoScalingType {
+    fn from(value: String) -> AutoScalingType {
+        match value.as_str() {
+            "load" => AutoScalingType::Load,
+            "timer" => AutoScalingType::Timer,
+            _ => AutoScalingType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AutoScalingType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AutoScalingType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AutoScalingType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AutoScalingType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AutoScalingType {
+    fn deserialize<D>(deserializer: D) -> Result<AutoScalingType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes a block device mapping. This data type maps directly to the Amazon EC2 <a href="https://docs.aws.amazon.com/AWSEC2/latest/APIReference/API_BlockDeviceMapping.html">BlockDeviceMapping</a> data type. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct BlockDeviceMapping {
@@ -223,7 +630,7 @@ pub struct CloneStackRequest {
     /// <p>A list of stack attributes and values as key/value pairs to be added to the cloned stack.</p>
     #[serde(rename = "Attributes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub attributes: Option<::std::collections::HashMap<String, String>>,
+    pub attributes: Option<::std::collections::HashMap<StackAttributesKeys, String>>,
     /// <p>A <code>ChefConfiguration</code> object that specifies whether to enable Berkshelf and the Berkshelf version on Chef 11.10 stacks. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workingstacks-creating.html">Create a New Stack</a>.</p>
     #[serde(rename = "ChefConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -263,7 +670,7 @@ pub struct CloneStackRequest {
     /// <p>The default root device type. This value is used by default for all instances in the cloned stack, but you can override it when you create an instance. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ComponentsAMIs.html#storage-for-the-root-device">Storage for the Root Device</a>.</p>
     #[serde(rename = "DefaultRootDeviceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub default_root_device_type: Option<String>,
+    pub default_root_device_type: Option<RootDeviceType>,
     /// <p>A default Amazon EC2 key pair name. The default value is none. If you specify a key pair name, AWS OpsWorks installs the public key on the instance and you can use the private key with an SSH client to log in to the instance. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinginstances-ssh.html"> Using SSH to Communicate with an Instance</a> and <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/security-ssh-access.html"> Managing SSH Access</a>. You can override this setting by specifying a different key pair, or no key pair, when you <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinginstances-add.html"> create an instance</a>. </p>
     #[serde(rename = "DefaultSshKeyName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -327,6 +734,644 @@ pub struct CloudWatchLogsConfiguration {
     pub log_streams: Option<Vec<CloudWatchLogsLogStream>>,
 }
 
+/// <p>Specifies the encoding of the log file so that the file can be read correctly. The default is <code>utf_8</code>. Encodings supported by Python <code>codecs.decode()</code> can be used here.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CloudWatchLogsEncoding {
+    /// `ascii`
+    Ascii,
+    /// `big5`
+    Big5,
+    /// `big5hkscs`
+    Big5hkscs,
+    /// `cp037`
+    Cp037,
+    /// `cp424`
+    Cp424,
+    /// `cp437`
+    Cp437,
+    /// `cp500`
+    Cp500,
+    /// `cp720`
+    Cp720,
+    /// `cp737`
+    Cp737,
+    /// `cp775`
+    Cp775,
+    /// `cp850`
+    Cp850,
+    /// `cp852`
+    Cp852,
+    /// `cp855`
+    Cp855,
+    /// `cp856`
+    Cp856,
+    /// `cp857`
+    Cp857,
+    /// `cp858`
+    Cp858,
+    /// `cp860`
+    Cp860,
+    /// `cp861`
+    Cp861,
+    /// `cp862`
+    Cp862,
+    /// `cp863`
+    Cp863,
+    /// `cp864`
+    Cp864,
+    /// `cp865`
+    Cp865,
+    /// `cp866`
+    Cp866,
+    /// `cp869`
+    Cp869,
+    /// `cp874`
+    Cp874,
+    /// `cp875`
+    Cp875,
+    /// `cp932`
+    Cp932,
+    /// `cp949`
+    Cp949,
+    /// `cp950`
+    Cp950,
+    /// `cp1006`
+    Cp1006,
+    /// `cp1026`
+    Cp1026,
+    /// `cp1140`
+    Cp1140,
+    /// `cp1250`
+    Cp1250,
+    /// `cp1251`
+    Cp1251,
+    /// `cp1252`
+    Cp1252,
+    /// `cp1253`
+    Cp1253,
+    /// `cp1254`
+    Cp1254,
+    /// `cp1255`
+    Cp1255,
+    /// `cp1256`
+    Cp1256,
+    /// `cp1257`
+    Cp1257,
+    /// `cp1258`
+    Cp1258,
+    /// `euc_jp`
+    EucJp,
+    /// `euc_jis_2004`
+    EucJis2004,
+    /// `euc_jisx0213`
+    EucJisx0213,
+    /// `euc_kr`
+    EucKr,
+    /// `gb2312`
+    Gb2312,
+    /// `gbk`
+    Gbk,
+    /// `gb18030`
+    Gb18030,
+    /// `hz`
+    Hz,
+    /// `iso2022_jp`
+    Iso2022Jp,
+    /// `iso2022_jp_1`
+    Iso2022Jp1,
+    /// `iso2022_jp_2`
+    Iso2022Jp2,
+    /// `iso2022_jp_2004`
+    Iso2022Jp2004,
+    /// `iso2022_jp_3`
+    Iso2022Jp3,
+    /// `iso2022_jp_ext`
+    Iso2022JpExt,
+    /// `iso2022_kr`
+    Iso2022Kr,
+    /// `latin_1`
+    Latin1,
+    /// `iso8859_2`
+    Iso8859_2,
+    /// `iso8859_3`
+    Iso8859_3,
+    /// `iso8859_4`
+    Iso8859_4,
+    /// `iso8859_5`
+    Iso8859_5,
+    /// `iso8859_6`
+    Iso8859_6,
+    /// `iso8859_7`
+    Iso8859_7,
+    /// `iso8859_8`
+    Iso8859_8,
+    /// `iso8859_9`
+    Iso8859_9,
+    /// `iso8859_10`
+    Iso8859_10,
+    /// `iso8859_13`
+    Iso8859_13,
+    /// `iso8859_14`
+    Iso8859_14,
+    /// `iso8859_15`
+    Iso8859_15,
+    /// `iso8859_16`
+    Iso8859_16,
+    /// `johab`
+    Johab,
+    /// `koi8_r`
+    Koi8R,
+    /// `koi8_u`
+    Koi8U,
+    /// `mac_cyrillic`
+    MacCyrillic,
+    /// `mac_greek`
+    MacGreek,
+    /// `mac_iceland`
+    MacIceland,
+    /// `mac_latin2`
+    MacLatin2,
+    /// `mac_roman`
+    MacRoman,
+    /// `mac_turkish`
+    MacTurkish,
+    /// `ptcp154`
+    Ptcp154,
+    /// `shift_jis`
+    ShiftJis,
+    /// `shift_jis_2004`
+    ShiftJis2004,
+    /// `shift_jisx0213`
+    ShiftJisx0213,
+    /// `utf_32`
+    Utf32,
+    /// `utf_32_be`
+    Utf32Be,
+    /// `utf_32_le`
+    Utf32Le,
+    /// `utf_16`
+    Utf16,
+    /// `utf_16_be`
+    Utf16Be,
+    /// `utf_16_le`
+    Utf16Le,
+    /// `utf_7`
+    Utf7,
+    /// `utf_8`
+    Utf8,
+    /// `utf_8_sig`
+    Utf8Sig,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CloudWatchLogsEncoding {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CloudWatchLogsEncoding::Ascii => "ascii",
+            CloudWatchLogsEncoding::Big5 => "big5",
+            CloudWatchLogsEncoding::Big5hkscs => "big5hkscs",
+            CloudWatchLogsEncoding::Cp037 => "cp037",
+            CloudWatchLogsEncoding::Cp424 => "cp424",
+            CloudWatchLogsEncoding::Cp437 => "cp437",
+            CloudWatchLogsEncoding::Cp500 => "cp500",
+            CloudWatchLogsEncoding::Cp720 => "cp720",
+            CloudWatchLogsEncoding::Cp737 => "cp737",
+            CloudWatchLogsEncoding::Cp775 => "cp775",
+            CloudWatchLogsEncoding::Cp850 => "cp850",
+            CloudWatchLogsEncoding::Cp852 => "cp852",
+            CloudWatchLogsEncoding::Cp855 => "cp855",
+            CloudWatchLogsEncoding::Cp856 => "cp856",
+            CloudWatchLogsEncoding::Cp857 => "cp857",
+            CloudWatchLogsEncoding::Cp858 => "cp858",
+            CloudWatchLogsEncoding::Cp860 => "cp860",
+            CloudWatchLogsEncoding::Cp861 => "cp861",
+            CloudWatchLogsEncoding::Cp862 => "cp862",
+            CloudWatchLogsEncoding::Cp863 => "cp863",
+            CloudWatchLogsEncoding::Cp864 => "cp864",
+            CloudWatchLogsEncoding::Cp865 => "cp865",
+            CloudWatchLogsEncoding::Cp866 => "cp866",
+            CloudWatchLogsEncoding::Cp869 => "cp869",
+            CloudWatchLogsEncoding::Cp874 => "cp874",
+            CloudWatchLogsEncoding::Cp875 => "cp875",
+            CloudWatchLogsEncoding::Cp932 => "cp932",
+            CloudWatchLogsEncoding::Cp949 => "cp949",
+            CloudWatchLogsEncoding::Cp950 => "cp950",
+            CloudWatchLogsEncoding::Cp1006 => "cp1006",
+            CloudWatchLogsEncoding::Cp1026 => "cp1026",
+            CloudWatchLogsEncoding::Cp1140 => "cp1140",
+            CloudWatchLogsEncoding::Cp1250 => "cp1250",
+            CloudWatchLogsEncoding::Cp1251 => "cp1251",
+            CloudWatchLogsEncoding::Cp1252 => "cp1252",
+            CloudWatchLogsEncoding::Cp1253 => "cp1253",
+            CloudWatchLogsEncoding::Cp1254 => "cp1254",
+            CloudWatchLogsEncoding::Cp1255 => "cp1255",
+            CloudWatchLogsEncoding::Cp1256 => "cp1256",
+            CloudWatchLogsEncoding::Cp1257 => "cp1257",
+            CloudWatchLogsEncoding::Cp1258 => "cp1258",
+            CloudWatchLogsEncoding::EucJp => "euc_jp",
+            CloudWatchLogsEncoding::EucJis2004 => "euc_jis_2004",
+            CloudWatchLogsEncoding::EucJisx0213 => "euc_jisx0213",
+            CloudWatchLogsEncoding::EucKr => "euc_kr",
+            CloudWatchLogsEncoding::Gb2312 => "gb2312",
+            CloudWatchLogsEncoding::Gbk => "gbk",
+            CloudWatchLogsEncoding::Gb18030 => "gb18030",
+            CloudWatchLogsEncoding::Hz => "hz",
+            CloudWatchLogsEncoding::Iso2022Jp => "iso2022_jp",
+            CloudWatchLogsEncoding::Iso2022Jp1 => "iso2022_jp_1",
+            CloudWatchLogsEncoding::Iso2022Jp2 => "iso2022_jp_2",
+            CloudWatchLogsEncoding::Iso2022Jp2004 => "iso2022_jp_2004",
+            CloudWatchLogsEncoding::Iso2022Jp3 => "iso2022_jp_3",
+            CloudWatchLogsEncoding::Iso2022JpExt => "iso2022_jp_ext",
+            CloudWatchLogsEncoding::Iso2022Kr => "iso2022_kr",
+            CloudWatchLogsEncoding::Latin1 => "latin_1",
+            CloudWatchLogsEncoding::Iso8859_2 => "iso8859_2",
+            CloudWatchLogsEncoding::Iso8859_3 => "iso8859_3",
+            CloudWatchLogsEncoding::Iso8859_4 => "iso8859_4",
+            CloudWatchLogsEncoding::Iso8859_5 => "iso8859_5",
+            CloudWatchLogsEncoding::Iso8859_6 => "iso8859_6",
+            CloudWatchLogsEncoding::Iso8859_7 => "iso8859_7",
+            CloudWatchLogsEncoding::Iso8859_8 => "iso8859_8",
+            CloudWatchLogsEncoding::Iso8859_9 => "iso8859_9",
+            CloudWatchLogsEncoding::Iso8859_10 => "iso8859_10",
+            CloudWatchLogsEncoding::Iso8859_13 => "iso8859_13",
+            CloudWatchLogsEncoding::Iso8859_14 => "iso8859_14",
+            CloudWatchLogsEncoding::Iso8859_15 => "iso8859_15",
+            CloudWatchLogsEncoding::Iso8859_16 => "iso8859_16",
+            CloudWatchLogsEncoding::Johab => "johab",
+            CloudWatchLogsEncoding::Koi8R => "koi8_r",
+            CloudWatchLogsEncoding::Koi8U => "koi8_u",
+            CloudWatchLogsEncoding::MacCyrillic => "mac_cyrillic",
+            CloudWatchLogsEncoding::MacGreek => "mac_greek",
+            CloudWatchLogsEncoding::MacIceland => "mac_iceland",
+            CloudWatchLogsEncoding::MacLatin2 => "mac_latin2",
+            CloudWatchLogsEncoding::MacRoman => "mac_roman",
+            CloudWatchLogsEncoding::MacTurkish => "mac_turkish",
+            CloudWatchLogsEncoding::Ptcp154 => "ptcp154",
+            CloudWatchLogsEncoding::ShiftJis => "shift_jis",
+            CloudWatchLogsEncoding::ShiftJis2004 => "shift_jis_2004",
+            CloudWatchLogsEncoding::ShiftJisx0213 => "shift_jisx0213",
+            CloudWatchLogsEncoding::Utf32 => "utf_32",
+            CloudWatchLogsEncoding::Utf32Be => "utf_32_be",
+            CloudWatchLogsEncoding::Utf32Le => "utf_32_le",
+            CloudWatchLogsEncoding::Utf16 => "utf_16",
+            CloudWatchLogsEncoding::Utf16Be => "utf_16_be",
+            CloudWatchLogsEncoding::Utf16Le => "utf_16_le",
+            CloudWatchLogsEncoding::Utf7 => "utf_7",
+            CloudWatchLogsEncoding::Utf8 => "utf_8",
+            CloudWatchLogsEncoding::Utf8Sig => "utf_8_sig",
+            CloudWatchLogsEncoding::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CloudWatchLogsEncoding {
+    fn default() -> CloudWatchLogsEncoding {
+        CloudWatchLogsEncoding::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CloudWatchLogsEncoding {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CloudWatchLogsEncoding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CloudWatchLogsEncoding {
+    fn from(value: &'a str) -> CloudWatchLogsEncoding {
+        match value {
+            "ascii" => CloudWatchLogsEncoding::Ascii,
+            "big5" => CloudWatchLogsEncoding::Big5,
+            "big5hkscs" => CloudWatchLogsEncoding::Big5hkscs,
+            "cp037" => CloudWatchLogsEncoding::Cp037,
+            "cp424" => CloudWatchLogsEncoding::Cp424,
+            "cp437" => CloudWatchLogsEncoding::Cp437,
+            "cp500" => CloudWatchLogsEncoding::Cp500,
+            "cp720" => CloudWatchLogsEncoding::Cp720,
+            "cp737" => CloudWatchLogsEncoding::Cp737,
+            "cp775" => CloudWatchLogsEncoding::Cp775,
+            "cp850" => CloudWatchLogsEncoding::Cp850,
+            "cp852" => CloudWatchLogsEncoding::Cp852,
+            "cp855" => CloudWatchLogsEncoding::Cp855,
+            "cp856" => CloudWatchLogsEncoding::Cp856,
+            "cp857" => CloudWatchLogsEncoding::Cp857,
+            "cp858" => CloudWatchLogsEncoding::Cp858,
+            "cp860" => CloudWatchLogsEncoding::Cp860,
+            "cp861" => CloudWatchLogsEncoding::Cp861,
+            "cp862" => CloudWatchLogsEncoding::Cp862,
+            "cp863" => CloudWatchLogsEncoding::Cp863,
+            "cp864" => CloudWatchLogsEncoding::Cp864,
+            "cp865" => CloudWatchLogsEncoding::Cp865,
+            "cp866" => CloudWatchLogsEncoding::Cp866,
+            "cp869" => CloudWatchLogsEncoding::Cp869,
+            "cp874" => CloudWatchLogsEncoding::Cp874,
+            "cp875" => CloudWatchLogsEncoding::Cp875,
+            "cp932" => CloudWatchLogsEncoding::Cp932,
+            "cp949" => CloudWatchLogsEncoding::Cp949,
+            "cp950" => CloudWatchLogsEncoding::Cp950,
+            "cp1006" => CloudWatchLogsEncoding::Cp1006,
+            "cp1026" => CloudWatchLogsEncoding::Cp1026,
+            "cp1140" => CloudWatchLogsEncoding::Cp1140,
+            "cp1250" => CloudWatchLogsEncoding::Cp1250,
+            "cp1251" => CloudWatchLogsEncoding::Cp1251,
+            "cp1252" => CloudWatchLogsEncoding::Cp1252,
+            "cp1253" => CloudWatchLogsEncoding::Cp1253,
+            "cp1254" => CloudWatchLogsEncoding::Cp1254,
+            "cp1255" => CloudWatchLogsEncoding::Cp1255,
+            "cp1256" => CloudWatchLogsEncoding::Cp1256,
+            "cp1257" => CloudWatchLogsEncoding::Cp1257,
+            "cp1258" => CloudWatchLogsEncoding::Cp1258,
+            "euc_jp" => CloudWatchLogsEncoding::EucJp,
+            "euc_jis_2004" => CloudWatchLogsEncoding::EucJis2004,
+            "euc_jisx0213" => CloudWatchLogsEncoding::EucJisx0213,
+            "euc_kr" => CloudWatchLogsEncoding::EucKr,
+            "gb2312" => CloudWatchLogsEncoding::Gb2312,
+            "gbk" => CloudWatchLogsEncoding::Gbk,
+            "gb18030" => CloudWatchLogsEncoding::Gb18030,
+            "hz" => CloudWatchLogsEncoding::Hz,
+            "iso2022_jp" => CloudWatchLogsEncoding::Iso2022Jp,
+            "iso2022_jp_1" => CloudWatchLogsEncoding::Iso2022Jp1,
+            "iso2022_jp_2" => CloudWatchLogsEncoding::Iso2022Jp2,
+            "iso2022_jp_2004" => CloudWatchLogsEncoding::Iso2022Jp2004,
+            "iso2022_jp_3" => CloudWatchLogsEncoding::Iso2022Jp3,
+            "iso2022_jp_ext" => CloudWatchLogsEncoding::Iso2022JpExt,
+            "iso2022_kr" => CloudWatchLogsEncoding::Iso2022Kr,
+            "latin_1" => CloudWatchLogsEncoding::Latin1,
+            "iso8859_2" => CloudWatchLogsEncoding::Iso8859_2,
+            "iso8859_3" => CloudWatchLogsEncoding::Iso8859_3,
+            "iso8859_4" => CloudWatchLogsEncoding::Iso8859_4,
+            "iso8859_5" => CloudWatchLogsEncoding::Iso8859_5,
+            "iso8859_6" => CloudWatchLogsEncoding::Iso8859_6,
+            "iso8859_7" => CloudWatchLogsEncoding::Iso8859_7,
+            "iso8859_8" => CloudWatchLogsEncoding::Iso8859_8,
+            "iso8859_9" => CloudWatchLogsEncoding::Iso8859_9,
+            "iso8859_10" => CloudWatchLogsEncoding::Iso8859_10,
+            "iso8859_13" => CloudWatchLogsEncoding::Iso8859_13,
+            "iso8859_14" => CloudWatchLogsEncoding::Iso8859_14,
+            "iso8859_15" => CloudWatchLogsEncoding::Iso8859_15,
+            "iso8859_16" => CloudWatchLogsEncoding::Iso8859_16,
+            "johab" => CloudWatchLogsEncoding::Johab,
+            "koi8_r" => CloudWatchLogsEncoding::Koi8R,
+            "koi8_u" => CloudWatchLogsEncoding::Koi8U,
+            "mac_cyrillic" => CloudWatchLogsEncoding::MacCyrillic,
+            "mac_greek" => CloudWatchLogsEncoding::MacGreek,
+            "mac_iceland" => CloudWatchLogsEncoding::MacIceland,
+            "mac_latin2" => CloudWatchLogsEncoding::MacLatin2,
+            "mac_roman" => CloudWatchLogsEncoding::MacRoman,
+            "mac_turkish" => CloudWatchLogsEncoding::MacTurkish,
+            "ptcp154" => CloudWatchLogsEncoding::Ptcp154,
+            "shift_jis" => CloudWatchLogsEncoding::ShiftJis,
+            "shift_jis_2004" => CloudWatchLogsEncoding::ShiftJis2004,
+            "shift_jisx0213" => CloudWatchLogsEncoding::ShiftJisx0213,
+            "utf_32" => CloudWatchLogsEncoding::Utf32,
+            "utf_32_be" => CloudWatchLogsEncoding::Utf32Be,
+            "utf_32_le" => CloudWatchLogsEncoding::Utf32Le,
+            "utf_16" => CloudWatchLogsEncoding::Utf16,
+            "utf_16_be" => CloudWatchLogsEncoding::Utf16Be,
+            "utf_16_le" => CloudWatchLogsEncoding::Utf16Le,
+            "utf_7" => CloudWatchLogsEncoding::Utf7,
+            "utf_8" => CloudWatchLogsEncoding::Utf8,
+            "utf_8_sig" => CloudWatchLogsEncoding::Utf8Sig,
+            _ => CloudWatchLogsEncoding::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CloudWatchLogsEncoding {
+    fn from(value: String) -> CloudWatchLogsEncoding {
+        match value.as_str() {
+            "ascii" => CloudWatchLogsEncoding::Ascii,
+            "big5" => CloudWatchLogsEncoding::Big5,
+            "big5hkscs" => CloudWatchLogsEncoding::Big5hkscs,
+            "cp037" => CloudWatchLogsEncoding::Cp037,
+            "cp424" => CloudWatchLogsEncoding::Cp424,
+            "cp437" => CloudWatchLogsEncoding::Cp437,
+            "cp500" => CloudWatchLogsEncoding::Cp500,
+            "cp720" => CloudWatchLogsEncoding::Cp720,
+            "cp737" => CloudWatchLogsEncoding::Cp737,
+            "cp775" => CloudWatchLogsEncoding::Cp775,
+            "cp850" => CloudWatchLogsEncoding::Cp850,
+            "cp852" => CloudWatchLogsEncoding::Cp852,
+            "cp855" => CloudWatchLogsEncoding::Cp855,
+            "cp856" => CloudWatchLogsEncoding::Cp856,
+            "cp857" => CloudWatchLogsEncoding::Cp857,
+            "cp858" => CloudWatchLogsEncoding::Cp858,
+            "cp860" => CloudWatchLogsEncoding::Cp860,
+            "cp861" => CloudWatchLogsEncoding::Cp861,
+            "cp862" => CloudWatchLogsEncoding::Cp862,
+            "cp863" => CloudWatchLogsEncoding::Cp863,
+            "cp864" => CloudWatchLogsEncoding::Cp864,
+            "cp865" => CloudWatchLogsEncoding::Cp865,
+            "cp866" => CloudWatchLogsEncoding::Cp866,
+            "cp869" => CloudWatchLogsEncoding::Cp869,
+            "cp874" => CloudWatchLogsEncoding::Cp874,
+            "cp875" => CloudWatchLogsEncoding::Cp875,
+            "cp932" => CloudWatchLogsEncoding::Cp932,
+            "cp949" => CloudWatchLogsEncoding::Cp949,
+            "cp950" => CloudWatchLogsEncoding::Cp950,
+            "cp1006" => CloudWatchLogsEncoding::Cp1006,
+            "cp1026" => CloudWatchLogsEncoding::Cp1026,
+            "cp1140" => CloudWatchLogsEncoding::Cp1140,
+            "cp1250" => CloudWatchLogsEncoding::Cp1250,
+            "cp1251" => CloudWatchLogsEncoding::Cp1251,
+            "cp1252" => CloudWatchLogsEncoding::Cp1252,
+            "cp1253" => CloudWatchLogsEncoding::Cp1253,
+            "cp1254" => CloudWatchLogsEncoding::Cp1254,
+            "cp1255" => CloudWatchLogsEncoding::Cp1255,
+            "cp1256" => CloudWatchLogsEncoding::Cp1256,
+            "cp1257" => CloudWatchLogsEncoding::Cp1257,
+            "cp1258" => CloudWatchLogsEncoding::Cp1258,
+            "euc_jp" => CloudWatchLogsEncoding::EucJp,
+            "euc_jis_2004" => CloudWatchLogsEncoding::EucJis2004,
+            "euc_jisx0213" => CloudWatchLogsEncoding::EucJisx0213,
+            "euc_kr" => CloudWatchLogsEncoding::EucKr,
+            "gb2312" => CloudWatchLogsEncoding::Gb2312,
+            "gbk" => CloudWatchLogsEncoding::Gbk,
+            "gb18030" => CloudWatchLogsEncoding::Gb18030,
+            "hz" => CloudWatchLogsEncoding::Hz,
+            "iso2022_jp" => CloudWatchLogsEncoding::Iso2022Jp,
+            "iso2022_jp_1" => CloudWatchLogsEncoding::Iso2022Jp1,
+            "iso2022_jp_2" => CloudWatchLogsEncoding::Iso2022Jp2,
+            "iso2022_jp_2004" => CloudWatchLogsEncoding::Iso2022Jp2004,
+            "iso2022_jp_3" => CloudWatchLogsEncoding::Iso2022Jp3,
+            "iso2022_jp_ext" => CloudWatchLogsEncoding::Iso2022JpExt,
+            "iso2022_kr" => CloudWatchLogsEncoding::Iso2022Kr,
+            "latin_1" => CloudWatchLogsEncoding::Latin1,
+            "iso8859_2" => CloudWatchLogsEncoding::Iso8859_2,
+            "iso8859_3" => CloudWatchLogsEncoding::Iso8859_3,
+            "iso8859_4" => CloudWatchLogsEncoding::Iso8859_4,
+            "iso8859_5" => CloudWatchLogsEncoding::Iso8859_5,
+            "iso8859_6" => CloudWatchLogsEncoding::Iso8859_6,
+            "iso8859_7" => CloudWatchLogsEncoding::Iso8859_7,
+            "iso8859_8" => CloudWatchLogsEncoding::Iso8859_8,
+            "iso8859_9" => CloudWatchLogsEncoding::Iso8859_9,
+            "iso8859_10" => CloudWatchLogsEncoding::Iso8859_10,
+            "iso8859_13" => CloudWatchLogsEncoding::Iso8859_13,
+            "iso8859_14" => CloudWatchLogsEncoding::Iso8859_14,
+            "iso8859_15" => CloudWatchLogsEncoding::Iso8859_15,
+            "iso8859_16" => CloudWatchLogsEncoding::Iso8859_16,
+            "johab" => CloudWatchLogsEncoding::Johab,
+            "koi8_r" => CloudWatchLogsEncoding::Koi8R,
+            "koi8_u" => CloudWatchLogsEncoding::Koi8U,
+            "mac_cyrillic" => CloudWatchLogsEncoding::MacCyrillic,
+            "mac_greek" => CloudWatchLogsEncoding::MacGreek,
+            "mac_iceland" => CloudWatchLogsEncoding::MacIceland,
+            "mac_latin2" => CloudWatchLogsEncoding::MacLatin2,
+            "mac_roman" => CloudWatchLogsEncoding::MacRoman,
+            "mac_turkish" => CloudWatchLogsEncoding::MacTurkish,
+            "ptcp154" => CloudWatchLogsEncoding::Ptcp154,
+            "shift_jis" => CloudWatchLogsEncoding::ShiftJis,
+            "shift_jis_2004" => CloudWatchLogsEncoding::ShiftJis2004,
+            "shift_jisx0213" => CloudWatchLogsEncoding::ShiftJisx0213,
+            "utf_32" => CloudWatchLogsEncoding::Utf32,
+            "utf_32_be" => CloudWatchLogsEncoding::Utf32Be,
+            "utf_32_le" => CloudWatchLogsEncoding::Utf32Le,
+            "utf_16" => CloudWatchLogsEncoding::Utf16,
+            "utf_16_be" => CloudWatchLogsEncoding::Utf16Be,
+            "utf_16_le" => CloudWatchLogsEncoding::Utf16Le,
+            "utf_7" => CloudWatchLogsEncoding::Utf7,
+            "utf_8" => CloudWatchLogsEncoding::Utf8,
+            "utf_8_sig" => CloudWatchLogsEncoding::Utf8Sig,
+            _ => CloudWatchLogsEncoding::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CloudWatchLogsEncoding {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CloudWatchLogsEncoding, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CloudWatchLogsEncoding {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CloudWatchLogsEncoding {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CloudWatchLogsEncoding {
+    fn deserialize<D>(deserializer: D) -> Result<CloudWatchLogsEncoding, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Specifies where to start to read data (start_of_file or end_of_file). The default is start_of_file. It's only used if there is no state persisted for that log stream.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CloudWatchLogsInitialPosition {
+    /// `start_of_file`
+    StartOfFile,
+    /// `end_of_file`
+    EndOfFile,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CloudWatchLogsInitialPosition {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CloudWatchLogsInitialPosition::StartOfFile => "start_of_file",
+            CloudWatchLogsInitialPosition::EndOfFile => "end_of_file",
+            CloudWatchLogsInitialPosition::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CloudWatchLogsInitialPosition {
+    fn default() -> CloudWatchLogsInitialPosition {
+        CloudWatchLogsInitialPosition::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CloudWatchLogsInitialPosition {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CloudWatchLogsInitialPosition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CloudWatchLogsInitialPosition {
+    fn from(value: &'a str) -> CloudWatchLogsInitialPosition {
+        match value {
+            "start_of_file" => CloudWatchLogsInitialPosition::StartOfFile,
+            "end_of_file" => CloudWatchLogsInitialPosition::EndOfFile,
+            _ => CloudWatchLogsInitialPosition::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CloudWatchLogsInitialPosition {
+    fn from(value: String) -> CloudWatchLogsInitialPosition {
+        match value.as_str() {
+            "start_of_file" => CloudWatchLogsInitialPosition::StartOfFile,
+            "end_of_file" => CloudWatchLogsInitialPosition::EndOfFile,
+            _ => CloudWatchLogsInitialPosition::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CloudWatchLogsInitialPosition {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CloudWatchLogsInitialPosition, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CloudWatchLogsInitialPosition {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CloudWatchLogsInitialPosition {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CloudWatchLogsInitialPosition {
+    fn deserialize<D>(deserializer: D) -> Result<CloudWatchLogsInitialPosition, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes the Amazon CloudWatch logs configuration for a layer. For detailed information about members of this data type, see the <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/logs/AgentReference.html">CloudWatch Logs Agent Reference</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CloudWatchLogsLogStream {
@@ -349,7 +1394,7 @@ pub struct CloudWatchLogsLogStream {
     /// <p>Specifies the encoding of the log file so that the file can be read correctly. The default is <code>utf_8</code>. Encodings supported by Python <code>codecs.decode()</code> can be used here.</p>
     #[serde(rename = "Encoding")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub encoding: Option<String>,
+    pub encoding: Option<CloudWatchLogsEncoding>,
     /// <p>Specifies log files that you want to push to CloudWatch Logs.</p> <p> <code>File</code> can point to a specific file or multiple files (by using wild card characters such as <code>/var/log/system.log*</code>). Only the latest file is pushed to CloudWatch Logs, based on file modification time. We recommend that you use wild card characters to specify a series of files of the same type, such as <code>access_log.2014-06-01-01</code>, <code>access_log.2014-06-01-02</code>, and so on by using a pattern like <code>access_log.*</code>. Don't use a wildcard to match multiple file types, such as <code>access_log_80</code> and <code>access_log_443</code>. To specify multiple, different file types, add another log stream entry to the configuration file, so that each log file type is stored in a different log group.</p> <p>Zipped files are not supported.</p>
     #[serde(rename = "File")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -361,7 +1406,7 @@ pub struct CloudWatchLogsLogStream {
     /// <p>Specifies where to start to read data (start_of_file or end_of_file). The default is start_of_file. This setting is only used if there is no state persisted for that log stream.</p>
     #[serde(rename = "InitialPosition")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub initial_position: Option<String>,
+    pub initial_position: Option<CloudWatchLogsInitialPosition>,
     /// <p>Specifies the destination log group. A log group is created automatically if it doesn't already exist. Log group names can be between 1 and 512 characters long. Allowed characters include a-z, A-Z, 0-9, '_' (underscore), '-' (hyphen), '/' (forward slash), and '.' (period).</p>
     #[serde(rename = "LogGroupName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -373,9 +1418,103 @@ pub struct CloudWatchLogsLogStream {
     /// <p>Specifies the time zone of log event time stamps.</p>
     #[serde(rename = "TimeZone")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub time_zone: Option<String>,
+    pub time_zone: Option<CloudWatchLogsTimeZone>,
+}
+
+/// <p>The preferred time zone for logs streamed to CloudWatch Logs. Valid values are <code>LOCAL</code> and <code>UTC</code>, for Coordinated Universal Time.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CloudWatchLogsTimeZone {
+    /// `LOCAL`
+    Local,
+    /// `UTC`
+    Utc,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CloudWatchLogsTimeZone {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CloudWatchLogsTimeZone::Local => "LOCAL",
+            CloudWatchLogsTimeZone::Utc => "UTC",
+            CloudWatchLogsTimeZone::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CloudWatchLogsTimeZone {
+    fn default() -> CloudWatchLogsTimeZone {
+        CloudWatchLogsTimeZone::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CloudWatchLogsTimeZone {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CloudWatchLogsTimeZone {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CloudWatchLogsTimeZone {
+    fn from(value: &'a str) -> CloudWatchLogsTimeZone {
+        match value {
+            "LOCAL" => CloudWatchLogsTimeZone::Local,
+            "UTC" => CloudWatchLogsTimeZone::Utc,
+            _ => CloudWatchLogsTimeZone::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CloudWatchLogsTimeZone {
+    fn from(value: String) -> CloudWatchLogsTimeZone {
+        match value.as_str() {
+            "LOCAL" => CloudWatchLogsTimeZone::Local,
+            "UTC" => CloudWatchLogsTimeZone::Utc,
+            _ => CloudWatchLogsTimeZone::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CloudWatchLogsTimeZone {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CloudWatchLogsTimeZone, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CloudWatchLogsTimeZone {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CloudWatchLogsTimeZone {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for CloudWatchLogsTimeZone {
+    fn deserialize<D>(deserializer: D) -> Result<CloudWatchLogsTimeZone, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes a command.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -432,7 +1571,7 @@ pub struct CreateAppRequest {
     /// <p>One or more user-defined key/value pairs to be added to the stack attributes.</p>
     #[serde(rename = "Attributes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub attributes: Option<::std::collections::HashMap<String, String>>,
+    pub attributes: Option<::std::collections::HashMap<AppAttributesKeys, String>>,
     /// <p>The app's data source.</p>
     #[serde(rename = "DataSources")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -469,7 +1608,7 @@ pub struct CreateAppRequest {
     pub stack_id: String,
     /// <p>The app type. Each supported type is associated with a particular layer. For example, PHP applications are associated with a PHP layer. AWS OpsWorks Stacks deploys an application to those instances that are members of the corresponding layer. If your app isn't one of the standard types, or you prefer to implement your own Deploy recipes, specify <code>other</code>.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: AppType,
 }
 
 /// <p>Contains the response to a <code>CreateApp</code> request.</p>
@@ -537,11 +1676,11 @@ pub struct CreateInstanceRequest {
     /// <p>The instance architecture. The default option is <code>x86_64</code>. Instance types do not necessarily support both architectures. For a list of the architectures that are supported by the different instance types, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/instance-types.html">Instance Families and Types</a>.</p>
     #[serde(rename = "Architecture")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub architecture: Option<String>,
+    pub architecture: Option<Architecture>,
     /// <p>For load-based or time-based instances, the type. Windows stacks can use only time-based instances.</p>
     #[serde(rename = "AutoScalingType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub auto_scaling_type: Option<String>,
+    pub auto_scaling_type: Option<AutoScalingType>,
     /// <p>The instance Availability Zone. For more information, see <a href="https://docs.aws.amazon.com/general/latest/gr/rande.html">Regions and Endpoints</a>.</p>
     #[serde(rename = "AvailabilityZone")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -575,7 +1714,7 @@ pub struct CreateInstanceRequest {
     /// <p>The instance root device type. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ComponentsAMIs.html#storage-for-the-root-device">Storage for the Root Device</a>.</p>
     #[serde(rename = "RootDeviceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub root_device_type: Option<String>,
+    pub root_device_type: Option<RootDeviceType>,
     /// <p>The instance's Amazon EC2 key-pair name.</p>
     #[serde(rename = "SshKeyName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -613,7 +1752,7 @@ pub struct CreateLayerRequest {
     /// <p>One or more user-defined key-value pairs to be added to the stack attributes.</p> <p>To create a cluster layer, set the <code>EcsClusterArn</code> attribute to the cluster's ARN.</p>
     #[serde(rename = "Attributes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub attributes: Option<::std::collections::HashMap<String, String>>,
+    pub attributes: Option<::std::collections::HashMap<LayerAttributesKeys, String>>,
     /// <p>Whether to automatically assign an <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/elastic-ip-addresses-eip.html">Elastic IP address</a> to the layer's instances. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinglayers-basics-edit.html">How to Edit a Layer</a>.</p>
     #[serde(rename = "AutoAssignElasticIps")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -669,7 +1808,7 @@ pub struct CreateLayerRequest {
     pub stack_id: String,
     /// <p>The layer type. A stack cannot have more than one built-in layer of the same type. It can have any number of custom layers. Built-in layers are not available in Chef 12 stacks.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: LayerType,
     /// <p>Whether to use Amazon EBS-optimized instances.</p>
     #[serde(rename = "UseEbsOptimizedInstances")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -700,7 +1839,7 @@ pub struct CreateStackRequest {
     /// <p>One or more user-defined key-value pairs to be added to the stack attributes.</p>
     #[serde(rename = "Attributes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub attributes: Option<::std::collections::HashMap<String, String>>,
+    pub attributes: Option<::std::collections::HashMap<StackAttributesKeys, String>>,
     /// <p>A <code>ChefConfiguration</code> object that specifies whether to enable Berkshelf and the Berkshelf version on Chef 11.10 stacks. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workingstacks-creating.html">Create a New Stack</a>.</p>
     #[serde(rename = "ChefConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -731,7 +1870,7 @@ pub struct CreateStackRequest {
     /// <p>The default root device type. This value is the default for all instances in the stack, but you can override it when you create an instance. The default option is <code>instance-store</code>. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ComponentsAMIs.html#storage-for-the-root-device">Storage for the Root Device</a>.</p>
     #[serde(rename = "DefaultRootDeviceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub default_root_device_type: Option<String>,
+    pub default_root_device_type: Option<RootDeviceType>,
     /// <p>A default Amazon EC2 key pair name. The default value is none. If you specify a key pair name, AWS OpsWorks installs the public key on the instance and you can use the private key with an SSH client to log in to the instance. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinginstances-ssh.html"> Using SSH to Communicate with an Instance</a> and <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/security-ssh-access.html"> Managing SSH Access</a>. You can override this setting by specifying a different key pair, or no key pair, when you <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinginstances-add.html"> create an instance</a>. </p>
     #[serde(rename = "DefaultSshKeyName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -935,9 +2074,152 @@ pub struct DeploymentCommand {
     pub args: Option<::std::collections::HashMap<String, Vec<String>>>,
     /// <p><p>Specifies the operation. You can specify only one command.</p> <p>For stacks, the following commands are available:</p> <ul> <li> <p> <code>execute<em>recipes</code>: Execute one or more recipes. To specify the recipes, set an <code>Args</code> parameter named <code>recipes</code> to the list of recipes to be executed. For example, to execute <code>phpapp::appsetup</code>, set <code>Args</code> to <code>{&quot;recipes&quot;:[&quot;phpapp::appsetup&quot;]}</code>.</p> </li> <li> <p> <code>install</em>dependencies</code>: Install the stack&#39;s dependencies.</p> </li> <li> <p> <code>update<em>custom</em>cookbooks</code>: Update the stack&#39;s custom cookbooks.</p> </li> <li> <p> <code>update<em>dependencies</code>: Update the stack&#39;s dependencies.</p> </li> </ul> <note> <p>The update</em>dependencies and install_dependencies commands are supported only for Linux instances. You can run the commands successfully on Windows instances, but they do nothing.</p> </note> <p>For apps, the following commands are available:</p> <ul> <li> <p> <code>deploy</code>: Deploy an app. Ruby on Rails apps have an optional <code>Args</code> parameter named <code>migrate</code>. Set <code>Args</code> to {&quot;migrate&quot;:[&quot;true&quot;]} to migrate the database. The default setting is {&quot;migrate&quot;:[&quot;false&quot;]}.</p> </li> <li> <p> <code>rollback</code> Roll the app back to the previous version. When you update an app, AWS OpsWorks Stacks stores the previous version, up to a maximum of five versions. You can use this command to roll an app back as many as four versions.</p> </li> <li> <p> <code>start</code>: Start the app&#39;s web or application server.</p> </li> <li> <p> <code>stop</code>: Stop the app&#39;s web or application server.</p> </li> <li> <p> <code>restart</code>: Restart the app&#39;s web or application server.</p> </li> <li> <p> <code>undeploy</code>: Undeploy the app.</p> </li> </ul></p>
     #[serde(rename = "Name")]
-    pub name: String,
+    pub name: DeploymentCommandName,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DeploymentCommandName {
+    /// `install_dependencies`
+    InstallDependencies,
+    /// `update_dependencies`
+    UpdateDependencies,
+    /// `update_custom_cookbooks`
+    UpdateCustomCookbooks,
+    /// `execute_recipes`
+    ExecuteRecipes,
+    /// `configure`
+    Configure,
+    /// `setup`
+    Setup,
+    /// `deploy`
+    Deploy,
+    /// `rollback`
+    Rollback,
+    /// `start`
+    Start,
+    /// `stop`
+    Stop,
+    /// `restart`
+    Restart,
+    /// `undeploy`
+    Undeploy,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DeploymentCommandName {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeploymentCommandName::InstallDependencies => "install_dependencies",
+            DeploymentCommandName::UpdateDependencies => "update_dependencies",
+            DeploymentCommandName::UpdateCustomCookbooks => "update_custom_cookbooks",
+            DeploymentCommandName::ExecuteRecipes => "execute_recipes",
+            DeploymentCommandName::Configure => "configure",
+            DeploymentCommandName::Setup => "setup",
+            DeploymentCommandName::Deploy => "deploy",
+            DeploymentCommandName::Rollback => "rollback",
+            DeploymentCommandName::Start => "start",
+            DeploymentCommandName::Stop => "stop",
+            DeploymentCommandName::Restart => "restart",
+            DeploymentCommandName::Undeploy => "undeploy",
+            DeploymentCommandName::Unknown(ref value) => value,
+        }
+    }
 }
 
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DeploymentCommandName {
+    fn default() -> DeploymentCommandName {
+        DeploymentCommandName::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DeploymentCommandName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DeploymentCommandName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DeploymentCommandName {
+    fn from(value: &'a str) -> DeploymentCommandName {
+        match value {
+            "install_dependencies" => DeploymentCommandName::InstallDependencies,
+            "update_dependencies" => DeploymentCommandName::UpdateDependencies,
+            "update_custom_cookbooks" => DeploymentCommandName::UpdateCustomCookbooks,
+            "execute_recipes" => DeploymentCommandName::ExecuteRecipes,
+            "configure" => DeploymentCommandName::Configure,
+            "setup" => DeploymentCommandName::Setup,
+            "deploy" => DeploymentCommandName::Deploy,
+            "rollback" => DeploymentCommandName::Rollback,
+            "start" => DeploymentCommandName::Start,
+            "stop" => DeploymentCommandName::Stop,
+            "restart" => DeploymentCommandName::Restart,
+            "undeploy" => DeploymentCommandName::Undeploy,
+            _ => DeploymentCommandName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeploymentCommandName {
+    fn from(value: String) -> DeploymentCommandName {
+        match value.as_str() {
+            "install_dependencies" => DeploymentCommandName::InstallDependencies,
+            "update_dependencies" => DeploymentCommandName::UpdateDependencies,
+            "update_custom_cookbooks" => DeploymentCommandName::UpdateCustomCookbooks,
+            "execute_recipes" => DeploymentCommandName::ExecuteRecipes,
+            "configure" => DeploymentCommandName::Configure,
+            "setup" => DeploymentCommandName::Setup,
+            "deploy" => DeploymentCommandName::Deploy,
+            "rollback" => DeploymentCommandName::Rollback,
+            "start" => DeploymentCommandName::Start,
+            "stop" => DeploymentCommandName::Stop,
+            "restart" => DeploymentCommandName::Restart,
+            "undeploy" => DeploymentCommandName::Undeploy,
+            _ => DeploymentCommandName::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DeploymentCommandName {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DeploymentCommandName, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DeploymentCommandName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DeploymentCommandName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeploymentCommandName {
+    fn deserialize<D>(deserializer: D) -> Result<DeploymentCommandName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct DeregisterEcsClusterRequest {
@@ -1518,7 +2800,7 @@ pub struct EbsBlockDevice {
     /// <p>The volume type. <code>gp2</code> for General Purpose (SSD) volumes, <code>io1</code> for Provisioned IOPS (SSD) volumes, <code>st1</code> for Throughput Optimized hard disk drives (HDD), <code>sc1</code> for Cold HDD,and <code>standard</code> for Magnetic volumes.</p> <p>If you specify the <code>io1</code> volume type, you must also specify a value for the <code>Iops</code> attribute. The maximum ratio of provisioned IOPS to requested volume size (in GiB) is 50:1. AWS uses the default volume size (in GiB) specified in the AMI attributes to set IOPS to 50 x (volume size).</p>
     #[serde(rename = "VolumeType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub volume_type: Option<String>,
+    pub volume_type: Option<VolumeType>,
 }
 
 /// <p>Describes a registered Amazon ECS cluster.</p>
@@ -1685,7 +2967,7 @@ pub struct Instance {
     /// <p>The instance architecture: "i386" or "x86_64".</p>
     #[serde(rename = "Architecture")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub architecture: Option<String>,
+    pub architecture: Option<Architecture>,
     /// <p>The instance's Amazon Resource Number (ARN).</p>
     #[serde(rename = "Arn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1693,7 +2975,7 @@ pub struct Instance {
     /// <p>For load-based or time-based instances, the type.</p>
     #[serde(rename = "AutoScalingType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub auto_scaling_type: Option<String>,
+    pub auto_scaling_type: Option<AutoScalingType>,
     /// <p>The instance Availability Zone. For more information, see <a href="https://docs.aws.amazon.com/general/latest/gr/rande.html">Regions and Endpoints</a>.</p>
     #[serde(rename = "AvailabilityZone")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1797,7 +3079,7 @@ pub struct Instance {
     /// <p>The instance's root device type. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ComponentsAMIs.html#storage-for-the-root-device">Storage for the Root Device</a>.</p>
     #[serde(rename = "RootDeviceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub root_device_type: Option<String>,
+    pub root_device_type: Option<RootDeviceType>,
     /// <p>The root device volume ID.</p>
     #[serde(rename = "RootDeviceVolumeId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1837,7 +3119,7 @@ pub struct Instance {
     /// <p>The instance's virtualization type: <code>paravirtual</code> or <code>hvm</code>.</p>
     #[serde(rename = "VirtualizationType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub virtualization_type: Option<String>,
+    pub virtualization_type: Option<VirtualizationType>,
 }
 
 /// <p>Contains a description of an Amazon EC2 instance from the Amazon EC2 metadata service. For more information, see <a href="https://docs.aws.amazon.com/sdkfornet/latest/apidocs/Index.html">Instance Metadata and User Data</a>.</p>
@@ -1951,7 +3233,7 @@ pub struct Layer {
     /// <p>The layer attributes.</p> <p>For the <code>HaproxyStatsPassword</code>, <code>MysqlRootPassword</code>, and <code>GangliaPassword</code> attributes, AWS OpsWorks Stacks returns <code>*****FILTERED*****</code> instead of the actual value</p> <p>For an ECS Cluster layer, AWS OpsWorks Stacks the <code>EcsClusterArn</code> attribute is set to the cluster's ARN.</p>
     #[serde(rename = "Attributes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub attributes: Option<::std::collections::HashMap<String, String>>,
+    pub attributes: Option<::std::collections::HashMap<LayerAttributesKeys, String>>,
     /// <p>Whether to automatically assign an <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/elastic-ip-addresses-eip.html">Elastic IP address</a> to the layer's instances. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinglayers-basics-edit.html">How to Edit a Layer</a>.</p>
     #[serde(rename = "AutoAssignElasticIps")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2027,7 +3309,7 @@ pub struct Layer {
     /// <p>The layer type.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<LayerType>,
     /// <p>Whether the layer uses Amazon EBS-optimized instances.</p>
     #[serde(rename = "UseEbsOptimizedInstances")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2038,6 +3320,357 @@ pub struct Layer {
     pub volume_configurations: Option<Vec<VolumeConfiguration>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LayerAttributesKeys {
+    /// `EcsClusterArn`
+    EcsClusterArn,
+    /// `EnableHaproxyStats`
+    EnableHaproxyStats,
+    /// `HaproxyStatsUrl`
+    HaproxyStatsUrl,
+    /// `HaproxyStatsUser`
+    HaproxyStatsUser,
+    /// `HaproxyStatsPassword`
+    HaproxyStatsPassword,
+    /// `HaproxyHealthCheckUrl`
+    HaproxyHealthCheckUrl,
+    /// `HaproxyHealthCheckMethod`
+    HaproxyHealthCheckMethod,
+    /// `MysqlRootPassword`
+    MysqlRootPassword,
+    /// `MysqlRootPasswordUbiquitous`
+    MysqlRootPasswordUbiquitous,
+    /// `GangliaUrl`
+    GangliaUrl,
+    /// `GangliaUser`
+    GangliaUser,
+    /// `GangliaPassword`
+    GangliaPassword,
+    /// `MemcachedMemory`
+    MemcachedMemory,
+    /// `NodejsVersion`
+    NodejsVersion,
+    /// `RubyVersion`
+    RubyVersion,
+    /// `RubygemsVersion`
+    RubygemsVersion,
+    /// `ManageBundler`
+    ManageBundler,
+    /// `BundlerVersion`
+    BundlerVersion,
+    /// `RailsStack`
+    RailsStack,
+    /// `PassengerVersion`
+    PassengerVersion,
+    /// `Jvm`
+    Jvm,
+    /// `JvmVersion`
+    JvmVersion,
+    /// `JvmOptions`
+    JvmOptions,
+    /// `JavaAppServer`
+    JavaAppServer,
+    /// `JavaAppServerVersion`
+    JavaAppServerVersion,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LayerAttributesKeys {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LayerAttributesKeys::EcsClusterArn => "EcsClusterArn",
+            LayerAttributesKeys::EnableHaproxyStats => "EnableHaproxyStats",
+            LayerAttributesKeys::HaproxyStatsUrl => "HaproxyStatsUrl",
+            LayerAttributesKeys::HaproxyStatsUser => "HaproxyStatsUser",
+            LayerAttributesKeys::HaproxyStatsPassword => "HaproxyStatsPassword",
+            LayerAttributesKeys::HaproxyHealthCheckUrl => "HaproxyHealthCheckUrl",
+            LayerAttributesKeys::HaproxyHealthCheckMethod => "HaproxyHealthCheckMethod",
+            LayerAttributesKeys::MysqlRootPassword => "MysqlRootPassword",
+            LayerAttributesKeys::MysqlRootPasswordUbiquitous => "MysqlRootPasswordUbiquitous",
+            LayerAttributesKeys::GangliaUrl => "GangliaUrl",
+            LayerAttributesKeys::GangliaUser => "GangliaUser",
+            LayerAttributesKeys::GangliaPassword => "GangliaPassword",
+            LayerAttributesKeys::MemcachedMemory => "MemcachedMemory",
+            LayerAttributesKeys::NodejsVersion => "NodejsVersion",
+            LayerAttributesKeys::RubyVersion => "RubyVersion",
+            LayerAttributesKeys::RubygemsVersion => "RubygemsVersion",
+            LayerAttributesKeys::ManageBundler => "ManageBundler",
+            LayerAttributesKeys::BundlerVersion => "BundlerVersion",
+            LayerAttributesKeys::RailsStack => "RailsStack",
+            LayerAttributesKeys::PassengerVersion => "PassengerVersion",
+            LayerAttributesKeys::Jvm => "Jvm",
+            LayerAttributesKeys::JvmVersion => "JvmVersion",
+            LayerAttributesKeys::JvmOptions => "JvmOptions",
+            LayerAttributesKeys::JavaAppServer => "JavaAppServer",
+            LayerAttributesKeys::JavaAppServerVersion => "JavaAppServerVersion",
+            LayerAttributesKeys::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LayerAttributesKeys {
+    fn default() -> LayerAttributesKeys {
+        LayerAttributesKeys::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LayerAttributesKeys {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LayerAttributesKeys {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LayerAttributesKeys {
+    fn from(value: &'a str) -> LayerAttributesKeys {
+        match value {
+            "EcsClusterArn" => LayerAttributesKeys::EcsClusterArn,
+            "EnableHaproxyStats" => LayerAttributesKeys::EnableHaproxyStats,
+            "HaproxyStatsUrl" => LayerAttributesKeys::HaproxyStatsUrl,
+            "HaproxyStatsUser" => LayerAttributesKeys::HaproxyStatsUser,
+            "HaproxyStatsPassword" => LayerAttributesKeys::HaproxyStatsPassword,
+            "HaproxyHealthCheckUrl" => LayerAttributesKeys::HaproxyHealthCheckUrl,
+            "HaproxyHealthCheckMethod" => LayerAttributesKeys::HaproxyHealthCheckMethod,
+            "MysqlRootPassword" => LayerAttributesKeys::MysqlRootPassword,
+            "MysqlRootPasswordUbiquitous" => LayerAttributesKeys::MysqlRootPasswordUbiquitous,
+            "GangliaUrl" => LayerAttributesKeys::GangliaUrl,
+            "GangliaUser" => LayerAttributesKeys::GangliaUser,
+            "GangliaPassword" => LayerAttributesKeys::GangliaPassword,
+            "MemcachedMemory" => LayerAttributesKeys::MemcachedMemory,
+            "NodejsVersion" => LayerAttributesKeys::NodejsVersion,
+            "RubyVersion" => LayerAttributesKeys::RubyVersion,
+            "RubygemsVersion" => LayerAttributesKeys::RubygemsVersion,
+            "ManageBundler" => LayerAttributesKeys::ManageBundler,
+            "BundlerVersion" => LayerAttributesKeys::BundlerVersion,
+            "RailsStack" => LayerAttributesKeys::RailsStack,
+            "PassengerVersion" => LayerAttributesKeys::PassengerVersion,
+            "Jvm" => LayerAttributesKeys::Jvm,
+            "JvmVersion" => LayerAttributesKeys::JvmVersion,
+            "JvmOptions" => LayerAttributesKeys::JvmOptions,
+            "JavaAppServer" => LayerAttributesKeys::JavaAppServer,
+            "JavaAppServerVersion" => LayerAttributesKeys::JavaAppServerVersion,
+            _ => LayerAttributesKeys::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LayerAttributesKeys {
+    fn from(value: String) -> LayerAttributesKeys {
+        match value.as_str() {
+            "EcsClusterArn" => LayerAttributesKeys::EcsClusterArn,
+            "EnableHaproxyStats" => LayerAttributesKeys::EnableHaproxyStats,
+            "HaproxyStatsUrl" => LayerAttributesKeys::HaproxyStatsUrl,
+            "HaproxyStatsUser" => LayerAttributesKeys::HaproxyStatsUser,
+            "HaproxyStatsPassword" => LayerAttributesKeys::HaproxyStatsPassword,
+            "HaproxyHealthCheckUrl" => LayerAttributesKeys::HaproxyHealthCheckUrl,
+            "HaproxyHealthCheckMethod" => LayerAttributesKeys::HaproxyHealthCheckMethod,
+            "MysqlRootPassword" => LayerAttributesKeys::MysqlRootPassword,
+            "MysqlRootPasswordUbiquitous" => LayerAttributesKeys::MysqlRootPasswordUbiquitous,
+            "GangliaUrl" => LayerAttributesKeys::GangliaUrl,
+            "GangliaUser" => LayerAttributesKeys::GangliaUser,
+            "GangliaPassword" => LayerAttributesKeys::GangliaPassword,
+            "MemcachedMemory" => LayerAttributesKeys::MemcachedMemory,
+            "NodejsVersion" => LayerAttributesKeys::NodejsVersion,
+            "RubyVersion" => LayerAttributesKeys::RubyVersion,
+            "RubygemsVersion" => LayerAttributesKeys::RubygemsVersion,
+            "ManageBundler" => LayerAttributesKeys::ManageBundler,
+            "BundlerVersion" => LayerAttributesKeys::BundlerVersion,
+            "RailsStack" => LayerAttributesKeys::RailsStack,
+            "PassengerVersion" => LayerAttributesKeys::PassengerVersion,
+            "Jvm" => LayerAttributesKeys::Jvm,
+            "JvmVersion" => LayerAttributesKeys::JvmVersion,
+            "JvmOptions" => LayerAttributesKeys::JvmOptions,
+            "JavaAppServer" => LayerAttributesKeys::JavaAppServer,
+            "JavaAppServerVersion" => LayerAttributesKeys::JavaAppServerVersion,
+            _ => LayerAttributesKeys::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LayerAttributesKeys {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LayerAttributesKeys, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LayerAttributesKeys {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LayerAttributesKeys {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LayerAttributesKeys {
+    fn deserialize<D>(deserializer: D) -> Result<LayerAttributesKeys, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LayerType {
+    /// `aws-flow-ruby`
+    AwsFlowRuby,
+    /// `ecs-cluster`
+    EcsCluster,
+    /// `java-app`
+    JavaApp,
+    /// `lb`
+    Lb,
+    /// `web`
+    Web,
+    /// `php-app`
+    PhpApp,
+    /// `rails-app`
+    RailsApp,
+    /// `nodejs-app`
+    NodejsApp,
+    /// `memcached`
+    Memcached,
+    /// `db-master`
+    DbMaster,
+    /// `monitoring-master`
+    MonitoringMaster,
+    /// `custom`
+    Custom,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LayerType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LayerType::AwsFlowRuby => "aws-flow-ruby",
+            LayerType::EcsCluster => "ecs-cluster",
+            LayerType::JavaApp => "java-app",
+            LayerType::Lb => "lb",
+            LayerType::Web => "web",
+            LayerType::PhpApp => "php-app",
+            LayerType::RailsApp => "rails-app",
+            LayerType::NodejsApp => "nodejs-app",
+            LayerType::Memcached => "memcached",
+            LayerType::DbMaster => "db-master",
+            LayerType::MonitoringMaster => "monitoring-master",
+            LayerType::Custom => "custom",
+            LayerType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LayerType {
+    fn default() -> LayerType {
+        LayerType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LayerType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LayerType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LayerType {
+    fn from(value: &'a str) -> LayerType {
+        match value {
+            "aws-flow-ruby" => LayerType::AwsFlowRuby,
+            "ecs-cluster" => LayerType::EcsCluster,
+            "java-app" => LayerType::JavaApp,
+            "lb" => LayerType::Lb,
+            "web" => LayerType::Web,
+            "php-app" => LayerType::PhpApp,
+            "rails-app" => LayerType::RailsApp,
+            "nodejs-app" => LayerType::NodejsApp,
+            "memcached" => LayerType::Memcached,
+            "db-master" => LayerType::DbMaster,
+            "monitoring-master" => LayerType::MonitoringMaster,
+            "custom" => LayerType::Custom,
+            _ => LayerType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LayerType {
+    fn from(value: String) -> LayerType {
+        match value.as_str() {
+            "aws-flow-ruby" => LayerType::AwsFlowRuby,
+            "ecs-cluster" => LayerType::EcsCluster,
+            "java-app" => LayerType::JavaApp,
+            "lb" => LayerType::Lb,
+            "web" => LayerType::Web,
+            "php-app" => LayerType::PhpApp,
+            "rails-app" => LayerType::RailsApp,
+            "nodejs-app" => LayerType::NodejsApp,
+            "memcached" => LayerType::Memcached,
+            "db-master" => LayerType::DbMaster,
+            "monitoring-master" => LayerType::MonitoringMaster,
+            "custom" => LayerType::Custom,
+            _ => LayerType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LayerType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LayerType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LayerType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LayerType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LayerType {
+    fn deserialize<D>(deserializer: D) -> Result<LayerType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Specifies the lifecycle event configuration</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct LifecycleEventConfiguration {
@@ -2429,24 +4062,117 @@ pub struct RegisterVolumeResult {
     pub volume_id: Option<String>,
 }
 
-/// <p>A registered instance's reported operating system.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct ReportedOs {
-    /// <p>The operating system family.</p>
-    #[serde(rename = "Family")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub family: Option<String>,
-    /// <p>The operating system name.</p>
-    #[serde(rename = "Name")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
-    /// <p>The operating system version.</p>
-    #[serde(rename = "Version")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub version: Option<String>,
+/// <p>A registered instance's reported operating system.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct ReportedOs {
+    /// <p>The operating system family.</p>
+    #[serde(rename = "Family")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub family: Option<String>,
+    /// <p>The operating system name.</p>
+    #[serde(rename = "Name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// <p>The operating system version.</p>
+    #[serde(rename = "Version")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RootDeviceType {
+    /// `ebs`
+    Ebs,
+    /// `instance-store`
+    InstanceStore,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RootDeviceType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RootDeviceType::Ebs => "ebs",
+            RootDeviceType::InstanceStore => "instance-store",
+            RootDeviceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RootDeviceType {
+    fn default() -> RootDeviceType {
+        RootDeviceType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RootDeviceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RootDeviceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RootDeviceType {
+    fn from(value: &'a str) -> RootDeviceType {
+        match value {
+            "ebs" => RootDeviceType::Ebs,
+            "instance-store" => RootDeviceType::InstanceStore,
+            _ => RootDeviceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RootDeviceType {
+    fn from(value: String) -> RootDeviceType {
+        match value.as_str() {
+            "ebs" => RootDeviceType::Ebs,
+            "instance-store" => RootDeviceType::InstanceStore,
+            _ => RootDeviceType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RootDeviceType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RootDeviceType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RootDeviceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RootDeviceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for RootDeviceType {
+    fn deserialize<D>(deserializer: D) -> Result<RootDeviceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes a user's SSH information.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -2585,7 +4311,7 @@ pub struct Source {
     /// <p>The repository type.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<SourceType>,
     /// <p>The source URL. The following is an example of an Amazon S3 source URL: <code>https://s3.amazonaws.com/opsworks-demo-bucket/opsworks_cookbook_demo.tar.gz</code>.</p>
     #[serde(rename = "Url")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2596,6 +4322,109 @@ pub struct Source {
     pub username: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SourceType {
+    /// `git`
+    Git,
+    /// `svn`
+    Svn,
+    /// `archive`
+    Archive,
+    /// `s3`
+    S3,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SourceType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SourceType::Git => "git",
+            SourceType::Svn => "svn",
+            SourceType::Archive => "archive",
+            SourceType::S3 => "s3",
+            SourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SourceType {
+    fn default() -> SourceType {
+        SourceType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SourceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SourceType {
+    fn from(value: &'a str) -> SourceType {
+        match value {
+            "git" => SourceType::Git,
+            "svn" => SourceType::Svn,
+            "archive" => SourceType::Archive,
+            "s3" => SourceType::S3,
+            _ => SourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SourceType {
+    fn from(value: String) -> SourceType {
+        match value.as_str() {
+            "git" => SourceType::Git,
+            "svn" => SourceType::Svn,
+            "archive" => SourceType::Archive,
+            "s3" => SourceType::S3,
+            _ => SourceType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SourceType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SourceType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SourceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SourceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SourceType {
+    fn deserialize<D>(deserializer: D) -> Result<SourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes an app's SSL configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SslConfiguration {
@@ -2626,7 +4455,7 @@ pub struct Stack {
     /// <p>The stack's attributes.</p>
     #[serde(rename = "Attributes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub attributes: Option<::std::collections::HashMap<String, String>>,
+    pub attributes: Option<::std::collections::HashMap<StackAttributesKeys, String>>,
     /// <p>A <code>ChefConfiguration</code> object that specifies whether to enable Berkshelf and the Berkshelf version. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workingstacks-creating.html">Create a New Stack</a>.</p>
     #[serde(rename = "ChefConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2662,7 +4491,7 @@ pub struct Stack {
     /// <p>The default root device type. This value is used by default for all instances in the stack, but you can override it when you create an instance. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ComponentsAMIs.html#storage-for-the-root-device">Storage for the Root Device</a>.</p>
     #[serde(rename = "DefaultRootDeviceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub default_root_device_type: Option<String>,
+    pub default_root_device_type: Option<RootDeviceType>,
     /// <p>A default Amazon EC2 key pair for the stack's instances. You can override this value when you create or update an instance.</p>
     #[serde(rename = "DefaultSshKeyName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2705,6 +4534,94 @@ pub struct Stack {
     pub vpc_id: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StackAttributesKeys {
+    /// `Color`
+    Color,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl StackAttributesKeys {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StackAttributesKeys::Color => "Color",
+            StackAttributesKeys::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for StackAttributesKeys {
+    fn default() -> StackAttributesKeys {
+        StackAttributesKeys::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for StackAttributesKeys {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for StackAttributesKeys {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for StackAttributesKeys {
+    fn from(value: &'a str) -> StackAttributesKeys {
+        match value {
+            "Color" => StackAttributesKeys::Color,
+            _ => StackAttributesKeys::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StackAttributesKeys {
+    fn from(value: String) -> StackAttributesKeys {
+        match value.as_str() {
+            "Color" => StackAttributesKeys::Color,
+            _ => StackAttributesKeys::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for StackAttributesKeys {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<StackAttributesKeys, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StackAttributesKeys {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for StackAttributesKeys {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StackAttributesKeys {
+    fn deserialize<D>(deserializer: D) -> Result<StackAttributesKeys, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes the configuration manager.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct StackConfigurationManager {
@@ -2871,7 +4788,7 @@ pub struct UpdateAppRequest {
     /// <p>One or more user-defined key/value pairs to be added to the stack attributes.</p>
     #[serde(rename = "Attributes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub attributes: Option<::std::collections::HashMap<String, String>>,
+    pub attributes: Option<::std::collections::HashMap<AppAttributesKeys, String>>,
     /// <p>The app's data sources.</p>
     #[serde(rename = "DataSources")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2903,7 +4820,7 @@ pub struct UpdateAppRequest {
     /// <p>The app type.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<AppType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -2932,11 +4849,11 @@ pub struct UpdateInstanceRequest {
     /// <p>The instance architecture. Instance types do not necessarily support both architectures. For a list of the architectures that are supported by the different instance types, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/instance-types.html">Instance Families and Types</a>.</p>
     #[serde(rename = "Architecture")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub architecture: Option<String>,
+    pub architecture: Option<Architecture>,
     /// <p>For load-based or time-based instances, the type. Windows stacks can use only time-based instances.</p>
     #[serde(rename = "AutoScalingType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub auto_scaling_type: Option<String>,
+    pub auto_scaling_type: Option<AutoScalingType>,
     /// <p>This property cannot be updated.</p>
     #[serde(rename = "EbsOptimized")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2976,7 +4893,7 @@ pub struct UpdateLayerRequest {
     /// <p>One or more user-defined key/value pairs to be added to the stack attributes.</p>
     #[serde(rename = "Attributes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub attributes: Option<::std::collections::HashMap<String, String>>,
+    pub attributes: Option<::std::collections::HashMap<LayerAttributesKeys, String>>,
     /// <p>Whether to automatically assign an <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/elastic-ip-addresses-eip.html">Elastic IP address</a> to the layer's instances. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinglayers-basics-edit.html">How to Edit a Layer</a>.</p>
     #[serde(rename = "AutoAssignElasticIps")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3077,7 +4994,7 @@ pub struct UpdateStackRequest {
     /// <p>One or more user-defined key-value pairs to be added to the stack attributes.</p>
     #[serde(rename = "Attributes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub attributes: Option<::std::collections::HashMap<String, String>>,
+    pub attributes: Option<::std::collections::HashMap<StackAttributesKeys, String>>,
     /// <p>A <code>ChefConfiguration</code> object that specifies whether to enable Berkshelf and the Berkshelf version on Chef 11.10 stacks. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workingstacks-creating.html">Create a New Stack</a>.</p>
     #[serde(rename = "ChefConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3109,7 +5026,7 @@ pub struct UpdateStackRequest {
     /// <p>The default root device type. This value is used by default for all instances in the stack, but you can override it when you create an instance. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ComponentsAMIs.html#storage-for-the-root-device">Storage for the Root Device</a>.</p>
     #[serde(rename = "DefaultRootDeviceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub default_root_device_type: Option<String>,
+    pub default_root_device_type: Option<RootDeviceType>,
     /// <p>A default Amazon EC2 key-pair name. The default value is <code>none</code>. If you specify a key-pair name, AWS OpsWorks Stacks installs the public key on the instance and you can use the private key with an SSH client to log in to the instance. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinginstances-ssh.html"> Using SSH to Communicate with an Instance</a> and <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/security-ssh-access.html"> Managing SSH Access</a>. You can override this setting by specifying a different key pair, or no key pair, when you <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinginstances-add.html"> create an instance</a>. </p>
     #[serde(rename = "DefaultSshKeyName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3205,6 +5122,99 @@ pub struct UserProfile {
     pub ssh_username: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum VirtualizationType {
+    /// `paravirtual`
+    Paravirtual,
+    /// `hvm`
+    Hvm,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl VirtualizationType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            VirtualizationType::Paravirtual => "paravirtual",
+            VirtualizationType::Hvm => "hvm",
+            VirtualizationType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for VirtualizationType {
+    fn default() -> VirtualizationType {
+        VirtualizationType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for VirtualizationType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for VirtualizationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for VirtualizationType {
+    fn from(value: &'a str) -> VirtualizationType {
+        match value {
+            "paravirtual" => VirtualizationType::Paravirtual,
+            "hvm" => VirtualizationType::Hvm,
+            _ => VirtualizationType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for VirtualizationType {
+    fn from(value: String) -> VirtualizationType {
+        match value.as_str() {
+            "paravirtual" => VirtualizationType::Paravirtual,
+            "hvm" => VirtualizationType::Hvm,
+            _ => VirtualizationType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for VirtualizationType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<VirtualizationType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for VirtualizationType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for VirtualizationType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for VirtualizationType {
+    fn deserialize<D>(deserializer: D) -> Result<VirtualizationType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes an instance's Amazon EBS volume.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -3297,6 +5307,104 @@ pub struct VolumeConfiguration {
     pub volume_type: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum VolumeType {
+    /// `gp2`
+    Gp2,
+    /// `io1`
+    Io1,
+    /// `standard`
+    Standard,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl VolumeType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            VolumeType::Gp2 => "gp2",
+            VolumeType::Io1 => "io1",
+            VolumeType::Standard => "standard",
+            VolumeType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for VolumeType {
+    fn default() -> VolumeType {
+        VolumeType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for VolumeType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for VolumeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for VolumeType {
+    fn from(value: &'a str) -> VolumeType {
+        match value {
+            "gp2" => VolumeType::Gp2,
+            "io1" => VolumeType::Io1,
+            "standard" => VolumeType::Standard,
+            _ => VolumeType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for VolumeType {
+    fn from(value: String) -> VolumeType {
+        match value.as_str() {
+            "gp2" => VolumeType::Gp2,
+            "io1" => VolumeType::Io1,
+            "standard" => VolumeType::Standard,
+            _ => VolumeType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for VolumeType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<VolumeType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for VolumeType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for VolumeType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for VolumeType {
+    fn deserialize<D>(deserializer: D) -> Result<VolumeType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes a time-based instance's auto scaling schedule. The schedule consists of a set of key-value pairs.</p> <ul> <li> <p>The key is the time period (a UTC hour) and must be an integer from 0 - 23.</p> </li> <li> <p>The value indicates whether the instance should be online or offline for the specified period, and must be set to "on" or "off"</p> </li> </ul> <p>The default setting for all time periods is off, so you use the following parameters primarily to specify the online periods. You don't have to explicitly specify offline periods unless you want to change an online period to an offline period.</p> <p>The following example specifies that the instance should be online for four hours, from UTC 1200 - 1600. It will be off for the remainder of the day.</p> <p> <code> { "12":"on", "13":"on", "14":"on", "15":"on" } </code> </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct WeeklyAutoScalingSchedule {
@@ -6021,11 +8129,12 @@ pub struct OpsWorksClient {
 impl OpsWorksClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> OpsWorksClient {
         OpsWorksClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "OpsWorks"),
         }
     }
 
@@ -6057,6 +8166,7 @@ impl OpsWorks for OpsWorksClient {
         input: AssignInstanceRequest,
     ) -> Result<(), RusotoError<AssignInstanceError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("AssignInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.AssignInstance");
@@ -6084,6 +8194,7 @@ impl OpsWorks for OpsWorksClient {
         input: AssignVolumeRequest,
     ) -> Result<(), RusotoError<AssignVolumeError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("AssignVolume");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.AssignVolume");
@@ -6111,6 +8222,7 @@ impl OpsWorks for OpsWorksClient {
         input: AssociateElasticIpRequest,
     ) -> Result<(), RusotoError<AssociateElasticIpError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("AssociateElasticIp");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.AssociateElasticIp");
@@ -6138,6 +8250,7 @@ impl OpsWorks for OpsWorksClient {
         input: AttachElasticLoadBalancerRequest,
     ) -> Result<(), RusotoError<AttachElasticLoadBalancerError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("AttachElasticLoadBalancer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6168,6 +8281,7 @@ impl OpsWorks for OpsWorksClient {
         input: CloneStackRequest,
     ) -> Result<CloneStackResult, RusotoError<CloneStackError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("CloneStack");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.CloneStack");
@@ -6195,6 +8309,7 @@ impl OpsWorks for OpsWorksClient {
         input: CreateAppRequest,
     ) -> Result<CreateAppResult, RusotoError<CreateAppError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("CreateApp");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.CreateApp");
@@ -6222,6 +8337,7 @@ impl OpsWorks for OpsWorksClient {
         input: CreateDeploymentRequest,
     ) -> Result<CreateDeploymentResult, RusotoError<CreateDeploymentError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("CreateDeployment");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.CreateDeployment");
@@ -6249,6 +8365,7 @@ impl OpsWorks for OpsWorksClient {
         input: CreateInstanceRequest,
     ) -> Result<CreateInstanceResult, RusotoError<CreateInstanceError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("CreateInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.CreateInstance");
@@ -6276,6 +8393,7 @@ impl OpsWorks for OpsWorksClient {
         input: CreateLayerRequest,
     ) -> Result<CreateLayerResult, RusotoError<CreateLayerError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("CreateLayer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.CreateLayer");
@@ -6303,6 +8421,7 @@ impl OpsWorks for OpsWorksClient {
         input: CreateStackRequest,
     ) -> Result<CreateStackResult, RusotoError<CreateStackError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("CreateStack");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.CreateStack");
@@ -6330,6 +8449,7 @@ impl OpsWorks for OpsWorksClient {
         input: CreateUserProfileRequest,
     ) -> Result<CreateUserProfileResult, RusotoError<CreateUserProfileError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("CreateUserProfile");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.CreateUserProfile");
@@ -6354,6 +8474,7 @@ impl OpsWorks for OpsWorksClient {
     /// <p>Deletes a specified app.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     async fn delete_app(&self, input: DeleteAppRequest) -> Result<(), RusotoError<DeleteAppError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DeleteApp");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.DeleteApp");
@@ -6381,6 +8502,7 @@ impl OpsWorks for OpsWorksClient {
         input: DeleteInstanceRequest,
     ) -> Result<(), RusotoError<DeleteInstanceError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DeleteInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.DeleteInstance");
@@ -6408,6 +8530,7 @@ impl OpsWorks for OpsWorksClient {
         input: DeleteLayerRequest,
     ) -> Result<(), RusotoError<DeleteLayerError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DeleteLayer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.DeleteLayer");
@@ -6435,6 +8558,7 @@ impl OpsWorks for OpsWorksClient {
         input: DeleteStackRequest,
     ) -> Result<(), RusotoError<DeleteStackError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DeleteStack");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.DeleteStack");
@@ -6462,6 +8586,7 @@ impl OpsWorks for OpsWorksClient {
         input: DeleteUserProfileRequest,
     ) -> Result<(), RusotoError<DeleteUserProfileError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DeleteUserProfile");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.DeleteUserProfile");
@@ -6489,6 +8614,7 @@ impl OpsWorks for OpsWorksClient {
         input: DeregisterEcsClusterRequest,
     ) -> Result<(), RusotoError<DeregisterEcsClusterError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DeregisterEcsCluster");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.DeregisterEcsCluster");
@@ -6516,6 +8642,7 @@ impl OpsWorks for OpsWorksClient {
         input: DeregisterElasticIpRequest,
     ) -> Result<(), RusotoError<DeregisterElasticIpError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DeregisterElasticIp");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.DeregisterElasticIp");
@@ -6543,6 +8670,7 @@ impl OpsWorks for OpsWorksClient {
         input: DeregisterInstanceRequest,
     ) -> Result<(), RusotoError<DeregisterInstanceError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DeregisterInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.DeregisterInstance");
@@ -6570,6 +8698,7 @@ impl OpsWorks for OpsWorksClient {
         input: DeregisterRdsDbInstanceRequest,
     ) -> Result<(), RusotoError<DeregisterRdsDbInstanceError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DeregisterRdsDbInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.DeregisterRdsDbInstance");
@@ -6597,6 +8726,7 @@ impl OpsWorks for OpsWorksClient {
         input: DeregisterVolumeRequest,
     ) -> Result<(), RusotoError<DeregisterVolumeError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DeregisterVolume");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.DeregisterVolume");
@@ -6624,6 +8754,7 @@ impl OpsWorks for OpsWorksClient {
         input: DescribeAgentVersionsRequest,
     ) -> Result<DescribeAgentVersionsResult, RusotoError<DescribeAgentVersionsError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribeAgentVersions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeAgentVersions");
@@ -6652,6 +8783,7 @@ impl OpsWorks for OpsWorksClient {
         input: DescribeAppsRequest,
     ) -> Result<DescribeAppsResult, RusotoError<DescribeAppsError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribeApps");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeApps");
@@ -6679,6 +8811,7 @@ impl OpsWorks for OpsWorksClient {
         input: DescribeCommandsRequest,
     ) -> Result<DescribeCommandsResult, RusotoError<DescribeCommandsError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribeCommands");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeCommands");
@@ -6706,6 +8839,7 @@ impl OpsWorks for OpsWorksClient {
         input: DescribeDeploymentsRequest,
     ) -> Result<DescribeDeploymentsResult, RusotoError<DescribeDeploymentsError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribeDeployments");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeDeployments");
@@ -6734,6 +8868,7 @@ impl OpsWorks for OpsWorksClient {
         input: DescribeEcsClustersRequest,
     ) -> Result<DescribeEcsClustersResult, RusotoError<DescribeEcsClustersError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribeEcsClusters");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeEcsClusters");
@@ -6762,6 +8897,7 @@ impl OpsWorks for OpsWorksClient {
         input: DescribeElasticIpsRequest,
     ) -> Result<DescribeElasticIpsResult, RusotoError<DescribeElasticIpsError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribeElasticIps");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeElasticIps");
@@ -6791,6 +8927,7 @@ impl OpsWorks for OpsWorksClient {
     ) -> Result<DescribeElasticLoadBalancersResult, RusotoError<DescribeElasticLoadBalancersError>>
     {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribeElasticLoadBalancers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6822,6 +8959,7 @@ impl OpsWorks for OpsWorksClient {
         input: DescribeInstancesRequest,
     ) -> Result<DescribeInstancesResult, RusotoError<DescribeInstancesError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribeInstances");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeInstances");
@@ -6849,6 +8987,7 @@ impl OpsWorks for OpsWorksClient {
         input: DescribeLayersRequest,
     ) -> Result<DescribeLayersResult, RusotoError<DescribeLayersError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribeLayers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeLayers");
@@ -6877,6 +9016,7 @@ impl OpsWorks for OpsWorksClient {
     ) -> Result<DescribeLoadBasedAutoScalingResult, RusotoError<DescribeLoadBasedAutoScalingError>>
     {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribeLoadBasedAutoScaling");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6907,6 +9047,7 @@ impl OpsWorks for OpsWorksClient {
         &self,
     ) -> Result<DescribeMyUserProfileResult, RusotoError<DescribeMyUserProfileError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribeMyUserProfile");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeMyUserProfile");
@@ -6933,6 +9074,7 @@ impl OpsWorks for OpsWorksClient {
         &self,
     ) -> Result<DescribeOperatingSystemsResponse, RusotoError<DescribeOperatingSystemsError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribeOperatingSystems");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeOperatingSystems");
@@ -6960,6 +9102,7 @@ impl OpsWorks for OpsWorksClient {
         input: DescribePermissionsRequest,
     ) -> Result<DescribePermissionsResult, RusotoError<DescribePermissionsError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribePermissions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribePermissions");
@@ -6988,6 +9131,7 @@ impl OpsWorks for OpsWorksClient {
         input: DescribeRaidArraysRequest,
     ) -> Result<DescribeRaidArraysResult, RusotoError<DescribeRaidArraysError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribeRaidArrays");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeRaidArrays");
@@ -7016,6 +9160,7 @@ impl OpsWorks for OpsWorksClient {
         input: DescribeRdsDbInstancesRequest,
     ) -> Result<DescribeRdsDbInstancesResult, RusotoError<DescribeRdsDbInstancesError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribeRdsDbInstances");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeRdsDbInstances");
@@ -7044,6 +9189,7 @@ impl OpsWorks for OpsWorksClient {
         input: DescribeServiceErrorsRequest,
     ) -> Result<DescribeServiceErrorsResult, RusotoError<DescribeServiceErrorsError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribeServiceErrors");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeServiceErrors");
@@ -7075,6 +9221,7 @@ impl OpsWorks for OpsWorksClient {
         RusotoError<DescribeStackProvisioningParametersError>,
     > {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribeStackProvisioningParameters");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7108,6 +9255,7 @@ impl OpsWorks for OpsWorksClient {
         input: DescribeStackSummaryRequest,
     ) -> Result<DescribeStackSummaryResult, RusotoError<DescribeStackSummaryError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribeStackSummary");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeStackSummary");
@@ -7136,6 +9284,7 @@ impl OpsWorks for OpsWorksClient {
         input: DescribeStacksRequest,
     ) -> Result<DescribeStacksResult, RusotoError<DescribeStacksError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribeStacks");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeStacks");
@@ -7164,6 +9313,7 @@ impl OpsWorks for OpsWorksClient {
     ) -> Result<DescribeTimeBasedAutoScalingResult, RusotoError<DescribeTimeBasedAutoScalingError>>
     {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribeTimeBasedAutoScaling");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7195,6 +9345,7 @@ impl OpsWorks for OpsWorksClient {
         input: DescribeUserProfilesRequest,
     ) -> Result<DescribeUserProfilesResult, RusotoError<DescribeUserProfilesError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribeUserProfiles");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeUserProfiles");
@@ -7223,6 +9374,7 @@ impl OpsWorks for OpsWorksClient {
         input: DescribeVolumesRequest,
     ) -> Result<DescribeVolumesResult, RusotoError<DescribeVolumesError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribeVolumes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeVolumes");
@@ -7250,6 +9402,7 @@ impl OpsWorks for OpsWorksClient {
         input: DetachElasticLoadBalancerRequest,
     ) -> Result<(), RusotoError<DetachElasticLoadBalancerError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DetachElasticLoadBalancer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7280,6 +9433,7 @@ impl OpsWorks for OpsWorksClient {
         input: DisassociateElasticIpRequest,
     ) -> Result<(), RusotoError<DisassociateElasticIpError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DisassociateElasticIp");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.DisassociateElasticIp");
@@ -7307,6 +9461,7 @@ impl OpsWorks for OpsWorksClient {
         input: GetHostnameSuggestionRequest,
     ) -> Result<GetHostnameSuggestionResult, RusotoError<GetHostnameSuggestionError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("GetHostnameSuggestion");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.GetHostnameSuggestion");
@@ -7335,6 +9490,7 @@ impl OpsWorks for OpsWorksClient {
         input: GrantAccessRequest,
     ) -> Result<GrantAccessResult, RusotoError<GrantAccessError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("GrantAccess");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.GrantAccess");
@@ -7362,6 +9518,7 @@ impl OpsWorks for OpsWorksClient {
         input: ListTagsRequest,
     ) -> Result<ListTagsResult, RusotoError<ListTagsError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("ListTags");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.ListTags");
@@ -7389,6 +9546,7 @@ impl OpsWorks for OpsWorksClient {
         input: RebootInstanceRequest,
     ) -> Result<(), RusotoError<RebootInstanceError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("RebootInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.RebootInstance");
@@ -7416,6 +9574,7 @@ impl OpsWorks for OpsWorksClient {
         input: RegisterEcsClusterRequest,
     ) -> Result<RegisterEcsClusterResult, RusotoError<RegisterEcsClusterError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("RegisterEcsCluster");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.RegisterEcsCluster");
@@ -7444,6 +9603,7 @@ impl OpsWorks for OpsWorksClient {
         input: RegisterElasticIpRequest,
     ) -> Result<RegisterElasticIpResult, RusotoError<RegisterElasticIpError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("RegisterElasticIp");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.RegisterElasticIp");
@@ -7471,6 +9631,7 @@ impl OpsWorks for OpsWorksClient {
         input: RegisterInstanceRequest,
     ) -> Result<RegisterInstanceResult, RusotoError<RegisterInstanceError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("RegisterInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.RegisterInstance");
@@ -7498,6 +9659,7 @@ impl OpsWorks for OpsWorksClient {
         input: RegisterRdsDbInstanceRequest,
     ) -> Result<(), RusotoError<RegisterRdsDbInstanceError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("RegisterRdsDbInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.RegisterRdsDbInstance");
@@ -7525,6 +9687,7 @@ impl OpsWorks for OpsWorksClient {
         input: RegisterVolumeRequest,
     ) -> Result<RegisterVolumeResult, RusotoError<RegisterVolumeError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("RegisterVolume");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.RegisterVolume");
@@ -7552,6 +9715,7 @@ impl OpsWorks for OpsWorksClient {
         input: SetLoadBasedAutoScalingRequest,
     ) -> Result<(), RusotoError<SetLoadBasedAutoScalingError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("SetLoadBasedAutoScaling");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.SetLoadBasedAutoScaling");
@@ -7579,6 +9743,7 @@ impl OpsWorks for OpsWorksClient {
         input: SetPermissionRequest,
     ) -> Result<(), RusotoError<SetPermissionError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("SetPermission");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.SetPermission");
@@ -7606,6 +9771,7 @@ impl OpsWorks for OpsWorksClient {
         input: SetTimeBasedAutoScalingRequest,
     ) -> Result<(), RusotoError<SetTimeBasedAutoScalingError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("SetTimeBasedAutoScaling");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.SetTimeBasedAutoScaling");
@@ -7633,6 +9799,7 @@ impl OpsWorks for OpsWorksClient {
         input: StartInstanceRequest,
     ) -> Result<(), RusotoError<StartInstanceError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("StartInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.StartInstance");
@@ -7660,6 +9827,7 @@ impl OpsWorks for OpsWorksClient {
         input: StartStackRequest,
     ) -> Result<(), RusotoError<StartStackError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("StartStack");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.StartStack");
@@ -7687,6 +9855,7 @@ impl OpsWorks for OpsWorksClient {
         input: StopInstanceRequest,
     ) -> Result<(), RusotoError<StopInstanceError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("StopInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.StopInstance");
@@ -7711,6 +9880,7 @@ impl OpsWorks for OpsWorksClient {
     /// <p>Stops a specified stack.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     async fn stop_stack(&self, input: StopStackRequest) -> Result<(), RusotoError<StopStackError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("StopStack");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.StopStack");
@@ -7738,6 +9908,7 @@ impl OpsWorks for OpsWorksClient {
         input: TagResourceRequest,
     ) -> Result<(), RusotoError<TagResourceError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.TagResource");
@@ -7765,6 +9936,7 @@ impl OpsWorks for OpsWorksClient {
         input: UnassignInstanceRequest,
     ) -> Result<(), RusotoError<UnassignInstanceError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("UnassignInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.UnassignInstance");
@@ -7792,6 +9964,7 @@ impl OpsWorks for OpsWorksClient {
         input: UnassignVolumeRequest,
     ) -> Result<(), RusotoError<UnassignVolumeError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("UnassignVolume");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.UnassignVolume");
@@ -7819,6 +9992,7 @@ impl OpsWorks for OpsWorksClient {
         input: UntagResourceRequest,
     ) -> Result<(), RusotoError<UntagResourceError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.UntagResource");
@@ -7843,6 +10017,7 @@ impl OpsWorks for OpsWorksClient {
     /// <p>Updates a specified app.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Deploy or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     async fn update_app(&self, input: UpdateAppRequest) -> Result<(), RusotoError<UpdateAppError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("UpdateApp");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.UpdateApp");
@@ -7870,6 +10045,7 @@ impl OpsWorks for OpsWorksClient {
         input: UpdateElasticIpRequest,
     ) -> Result<(), RusotoError<UpdateElasticIpError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("UpdateElasticIp");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.UpdateElasticIp");
@@ -7897,6 +10073,7 @@ impl OpsWorks for OpsWorksClient {
         input: UpdateInstanceRequest,
     ) -> Result<(), RusotoError<UpdateInstanceError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("UpdateInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.UpdateInstance");
@@ -7924,6 +10101,7 @@ impl OpsWorks for OpsWorksClient {
         input: UpdateLayerRequest,
     ) -> Result<(), RusotoError<UpdateLayerError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("UpdateLayer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.UpdateLayer");
@@ -7951,6 +10129,7 @@ impl OpsWorks for OpsWorksClient {
         input: UpdateMyUserProfileRequest,
     ) -> Result<(), RusotoError<UpdateMyUserProfileError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("UpdateMyUserProfile");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.UpdateMyUserProfile");
@@ -7978,6 +10157,7 @@ impl OpsWorks for OpsWorksClient {
         input: UpdateRdsDbInstanceRequest,
     ) -> Result<(), RusotoError<UpdateRdsDbInstanceError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("UpdateRdsDbInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.UpdateRdsDbInstance");
@@ -8005,6 +10185,7 @@ impl OpsWorks for OpsWorksClient {
         input: UpdateStackRequest,
     ) -> Result<(), RusotoError<UpdateStackError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("UpdateStack");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.UpdateStack");
@@ -8032,6 +10213,7 @@ impl OpsWorks for OpsWorksClient {
         input: UpdateUserProfileRequest,
     ) -> Result<(), RusotoError<UpdateUserProfileError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("UpdateUserProfile");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.UpdateUserProfile");
@@ -8059,6 +10241,7 @@ impl OpsWorks for OpsWorksClient {
         input: UpdateVolumeRequest,
     ) -> Result<(), RusotoError<UpdateVolumeError>> {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("UpdateVolume");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.UpdateVolume");
@@ -8080,3 +10263,601 @@ impl OpsWorks for OpsWorksClient {
         }
     }
 }
+impl OpsWorksClient {
+    /// Auto-paginating version of `describe_ecs_clusters`, streaming every page of results.
+    pub fn describe_ecs_clusters_pages(
+        &self,
+        input: DescribeEcsClustersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeEcsClustersResult, DescribeEcsClustersError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: OpsWorksClient, input: DescribeEcsClustersRequest| async move {
+                client.describe_ecs_clusters(input).await
+            },
+            |input: &DescribeEcsClustersRequest, output: &DescribeEcsClustersResult| {
+                Some(DescribeEcsClustersRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_ecs_clusters`, streaming the `EcsClusters` of every page of results.
+    pub fn describe_ecs_clusters_items(
+        &self,
+        input: DescribeEcsClustersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<EcsCluster, DescribeEcsClustersError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_ecs_clusters_pages(input),
+            |output: DescribeEcsClustersResult| output.ecs_clusters.unwrap_or_default(),
+        )
+    }
+}
+impl OpsWorksClient {
+    /// Waits until `describe_apps` reports the `AppExists` state, polling every 1 seconds up to 40 times.
+    pub async fn wait_until_app_exists(
+        &self,
+        input: DescribeAppsRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeAppsError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(1), 40);
+        self.wait_until_app_exists_with_config(input, config).await
+    }
+
+    /// Same as `wait_until_app_exists`, polling as often and as many times as `config` says.
+    pub async fn wait_until_app_exists_with_config(
+        &self,
+        input: DescribeAppsRequest,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeAppsError>> {
+        rusoto_core::waiter::wait(
+            "AppExists",
+            &config,
+            || self.describe_apps(input.clone()),
+            |result: &Result<DescribeAppsResult, RusotoError<DescribeAppsError>>| match result {
+                Ok(_) => Some(rusoto_core::waiter::WaiterState::Success),
+                Err(RusotoError::Unknown(response)) if response.status.as_u16() == 400 => {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+
+    /// Waits until `describe_deployments` reports the `DeploymentSuccessful` state, polling every 15 seconds up to 40 times.
+    pub async fn wait_until_deployment_successful(
+        &self,
+        input: DescribeDeploymentsRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeDeploymentsError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(15), 40);
+        self.wait_until_deployment_successful_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_deployment_successful`, polling as often and as many times as `config` says.
+    pub async fn wait_until_deployment_successful_with_config(
+        &self,
+        input: DescribeDeploymentsRequest,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeDeploymentsError>> {
+        rusoto_core::waiter::wait(
+            "DeploymentSuccessful",
+            &config,
+            || self.describe_deployments(input.clone()),
+            |result: &Result<DescribeDeploymentsResult, RusotoError<DescribeDeploymentsError>>| {
+                match result {
+                    Ok(output)
+                        if rusoto_core::waiter::all_match(
+                            output
+                                .deployments
+                                .iter()
+                                .flat_map(|v| v.iter())
+                                .flat_map(|v| v.status.iter()),
+                            |v| v == "successful",
+                        ) =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Success)
+                    }
+                    Ok(output)
+                        if output
+                            .deployments
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.status.iter())
+                            .any(|v| v == "failed") =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    _ => None,
+                }
+            },
+        )
+        .await
+    }
+
+    /// Waits until `describe_instances` reports the `InstanceOnline` state, polling every 15 seconds up to 40 times.
+    pub async fn wait_until_instance_online(
+        &self,
+        input: DescribeInstancesRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeInstancesError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(15), 40);
+        self.wait_until_instance_online_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_instance_online`, polling as often and as many times as `config` says.
+    pub async fn wait_until_instance_online_with_config(
+        &self,
+        input: DescribeInstancesRequest,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeInstancesError>> {
+        rusoto_core::waiter::wait(
+            "InstanceOnline",
+            &config,
+            || self.describe_instances(input.clone()),
+            |result: &Result<DescribeInstancesResult, RusotoError<DescribeInstancesError>>| {
+                match result {
+                    Ok(output)
+                        if rusoto_core::waiter::all_match(
+                            output
+                                .instances
+                                .iter()
+                                .flat_map(|v| v.iter())
+                                .flat_map(|v| v.status.iter()),
+                            |v| v == "online",
+                        ) =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Success)
+                    }
+                    Ok(output)
+                        if output
+                            .instances
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.status.iter())
+                            .any(|v| v == "setup_failed") =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    Ok(output)
+                        if output
+                            .instances
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.status.iter())
+                            .any(|v| v == "shutting_down") =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    Ok(output)
+                        if output
+                            .instances
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.status.iter())
+                            .any(|v| v == "start_failed") =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    Ok(output)
+                        if output
+                            .instances
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.status.iter())
+                            .any(|v| v == "stopped") =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    Ok(output)
+                        if output
+                            .instances
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.status.iter())
+                            .any(|v| v == "stopping") =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    Ok(output)
+                        if output
+                            .instances
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.status.iter())
+                            .any(|v| v == "terminating") =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    Ok(output)
+                        if output
+                            .instances
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.status.iter())
+                            .any(|v| v == "terminated") =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    Ok(output)
+                        if output
+                            .instances
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.status.iter())
+                            .any(|v| v == "stop_failed") =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    _ => None,
+                }
+            },
+        )
+        .await
+    }
+
+    /// Waits until `describe_instances` reports the `InstanceRegistered` state, polling every 15 seconds up to 40 times.
+    pub async fn wait_until_instance_registered(
+        &self,
+        input: DescribeInstancesRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeInstancesError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(15), 40);
+        self.wait_until_instance_registered_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_instance_registered`, polling as often and as many times as `config` says.
+    pub async fn wait_until_instance_registered_with_config(
+        &self,
+        input: DescribeInstancesRequest,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeInstancesError>> {
+        rusoto_core::waiter::wait(
+            "InstanceRegistered",
+            &config,
+            || self.describe_instances(input.clone()),
+            |result: &Result<DescribeInstancesResult, RusotoError<DescribeInstancesError>>| {
+                match result {
+                    Ok(output)
+                        if rusoto_core::waiter::all_match(
+                            output
+                                .instances
+                                .iter()
+                                .flat_map(|v| v.iter())
+                                .flat_map(|v| v.status.iter()),
+                            |v| v == "registered",
+                        ) =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Success)
+                    }
+                    Ok(output)
+                        if output
+                            .instances
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.status.iter())
+                            .any(|v| v == "setup_failed") =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    Ok(output)
+                        if output
+                            .instances
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.status.iter())
+                            .any(|v| v == "shutting_down") =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    Ok(output)
+                        if output
+                            .instances
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.status.iter())
+                            .any(|v| v == "stopped") =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    Ok(output)
+                        if output
+                            .instances
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.status.iter())
+                            .any(|v| v == "stopping") =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    Ok(output)
+                        if output
+                            .instances
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.status.iter())
+                            .any(|v| v == "terminating") =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    Ok(output)
+                        if output
+                            .instances
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.status.iter())
+                            .any(|v| v == "terminated") =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    Ok(output)
+                        if output
+                            .instances
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.status.iter())
+                            .any(|v| v == "stop_failed") =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    _ => None,
+                }
+            },
+        )
+        .await
+    }
+
+    /// Waits until `describe_instances` reports the `InstanceStopped` state, polling every 15 seconds up to 40 times.
+    pub async fn wait_until_instance_stopped(
+        &self,
+        input: DescribeInstancesRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeInstancesError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(15), 40);
+        self.wait_until_instance_stopped_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_instance_stopped`, polling as often and as many times as `config` says.
+    pub async fn wait_until_instance_stopped_with_config(
+        &self,
+        input: DescribeInstancesRequest,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeInstancesError>> {
+        rusoto_core::waiter::wait(
+            "InstanceStopped",
+            &config,
+            || self.describe_instances(input.clone()),
+            |result: &Result<DescribeInstancesResult, RusotoError<DescribeInstancesError>>| {
+                match result {
+                    Ok(output)
+                        if rusoto_core::waiter::all_match(
+                            output
+                                .instances
+                                .iter()
+                                .flat_map(|v| v.iter())
+                                .flat_map(|v| v.status.iter()),
+                            |v| v == "stopped",
+                        ) =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Success)
+                    }
+                    Ok(output)
+                        if output
+                            .instances
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.status.iter())
+                            .any(|v| v == "booting") =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    Ok(output)
+                        if output
+                            .instances
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.status.iter())
+                            .any(|v| v == "pending") =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    Ok(output)
+                        if output
+                            .instances
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.status.iter())
+                            .any(|v| v == "rebooting") =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    Ok(output)
+                        if output
+                            .instances
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.status.iter())
+                            .any(|v| v == "requested") =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    Ok(output)
+                        if output
+                            .instances
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.status.iter())
+                            .any(|v| v == "running_setup") =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    Ok(output)
+                        if output
+                            .instances
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.status.iter())
+                            .any(|v| v == "setup_failed") =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    Ok(output)
+                        if output
+                            .instances
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.status.iter())
+                            .any(|v| v == "start_failed") =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    Ok(output)
+                        if output
+                            .instances
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.status.iter())
+                            .any(|v| v == "stop_failed") =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    _ => None,
+                }
+            },
+        )
+        .await
+    }
+
+    /// Waits until `describe_instances` reports the `InstanceTerminated` state, polling every 15 seconds up to 40 times.
+    pub async fn wait_until_instance_terminated(
+        &self,
+        input: DescribeInstancesRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeInstancesError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(15), 40);
+        self.wait_until_instance_terminated_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_instance_terminated`, polling as often and as many times as `config` says.
+    pub async fn wait_until_instance_terminated_with_config(
+        &self,
+        input: DescribeInstancesRequest,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeInstancesError>> {
+        rusoto_core::waiter::wait(
+            "InstanceTerminated",
+            &config,
+            || self.describe_instances(input.clone()),
+            |result: &Result<DescribeInstancesResult, RusotoError<DescribeInstancesError>>| {
+                match result {
+                    Ok(output)
+                        if rusoto_core::waiter::all_match(
+                            output
+                                .instances
+                                .iter()
+                                .flat_map(|v| v.iter())
+                                .flat_map(|v| v.status.iter()),
+                            |v| v == "terminated",
+                        ) =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Success)
+                    }
+                    Err(RusotoError::Service(DescribeInstancesError::ResourceNotFound(_))) => {
+                        Some(rusoto_core::waiter::WaiterState::Success)
+                    }
+                    Err(RusotoError::Unknown(response))
+                        if rusoto_core::retry::error_code(response).as_deref()
+                            == Some("ResourceNotFoundException") =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Success)
+                    }
+                    Ok(output)
+                        if output
+                            .instances
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.status.iter())
+                            .any(|v| v == "booting") =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    Ok(output)
+                        if output
+                            .instances
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.status.iter())
+                            .any(|v| v == "online") =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    Ok(output)
+                        if output
+                            .instances
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.status.iter())
+                            .any(|v| v == "pending") =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    Ok(output)
+                        if output
+                            .instances
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.status.iter())
+                            .any(|v| v == "rebooting") =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    Ok(output)
+                        if output
+                            .instances
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.status.iter())
+                            .any(|v| v == "requested") =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    Ok(output)
+                        if output
+                            .instances
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.status.iter())
+                            .any(|v| v == "running_setup") =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    Ok(output)
+                        if output
+                            .instances
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.status.iter())
+                            .any(|v| v == "setup_failed") =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    Ok(output)
+                        if output
+                            .instances
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.status.iter())
+                            .any(|v| v == "start_failed") =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    _ => None,
+                }
+            },
+        )
+        .await
+    }
+}
@@ -80,11 +80,16 @@ pub struct Backup {
     /// <p> The backup type. Valid values are <code>automated</code> or <code>manual</code>. </p>
     #[serde(rename = "BackupType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub backup_type: Option<String>,
+    pub backup_type: Option<BackupType>,
     /// <p> The time stamp when the backup was created in the database. Example: <code>2016-07-29T13:38:47.520Z</code> </p>
     #[serde(rename = "CreatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p> A user-provided description for a manual backup. This field is empty for automated backups. </p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -140,7 +145,7 @@ pub struct Backup {
     /// <p>The status of a backup while in progress. </p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<BackupStatus>,
     /// <p> An informational message about backup status. </p>
     #[serde(rename = "StatusDescription")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -159,6 +164,202 @@ pub struct Backup {
     pub user_arn: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BackupStatus {
+    /// `IN_PROGRESS`
+    InProgress,
+    /// `OK`
+    Ok,
+    /// `FAILED`
+    Failed,
+    /// `DELETING`
+    Deleting,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl BackupStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BackupStatus::InProgress => "IN_PROGRESS",
+            BackupStatus::Ok => "OK",
+            BackupStatus::Failed => "FAILED",
+            BackupStatus::Deleting => "DELETING",
+            BackupStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for BackupStatus {
+    fn default() -> BackupStatus {
+        BackupStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for BackupStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for BackupStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for BackupStatus {
+    fn from(value: &'a str) -> BackupStatus {
+        match value {
+            "IN_PROGRESS" => BackupStatus::InProgress,
+            "OK" => BackupStatus::Ok,
+            "FAILED" => BackupStatus::Failed,
+            "DELETING" => BackupStatus::Deleting,
+            _ => BackupStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BackupStatus {
+    fn from(value: String) -> BackupStatus {
+        match value.as_str() {
+            "IN_PROGRESS" => BackupStatus::InProgress,
+            "OK" => BackupStatus::Ok,
+            "FAILED" => BackupStatus::Failed,
+            "DELETING" => BackupStatus::Deleting,
+            _ => BackupStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for BackupStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<BackupStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for BackupStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for BackupStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BackupStatus {
+    fn deserialize<D>(deserializer: D) -> Result<BackupStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BackupType {
+    /// `AUTOMATED`
+    Automated,
+    /// `MANUAL`
+    Manual,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl BackupType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BackupType::Automated => "AUTOMATED",
+            BackupType::Manual => "MANUAL",
+            BackupType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for BackupType {
+    fn default() -> BackupType {
+        BackupType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for BackupType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for BackupType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for BackupType {
+    fn from(value: &'a str) -> BackupType {
+        match value {
+            "AUTOMATED" => BackupType::Automated,
+            "MANUAL" => BackupType::Manual,
+            _ => BackupType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BackupType {
+    fn from(value: String) -> BackupType {
+        match value.as_str() {
+            "AUTOMATED" => BackupType::Automated,
+            "MANUAL" => BackupType::Manual,
+            _ => BackupType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for BackupType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<BackupType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for BackupType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for BackupType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BackupType {
+    fn deserialize<D>(deserializer: D) -> Result<BackupType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateBackupRequest {
@@ -399,7 +600,7 @@ pub struct DescribeNodeAssociationStatusResponse {
     /// <p><p>The status of the association or disassociation request. </p> <p class="title"> <b>Possible values:</b> </p> <ul> <li> <p> <code>SUCCESS</code>: The association or disassociation succeeded. </p> </li> <li> <p> <code>FAILED</code>: The association or disassociation failed. </p> </li> <li> <p> <code>IN_PROGRESS</code>: The association or disassociation is still in progress. </p> </li> </ul></p>
     #[serde(rename = "NodeAssociationStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub node_association_status: Option<String>,
+    pub node_association_status: Option<NodeAssociationStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -526,6 +727,198 @@ pub struct ListTagsForResourceResponse {
     pub tags: Option<Vec<Tag>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MaintenanceStatus {
+    /// `SUCCESS`
+    Success,
+    /// `FAILED`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl MaintenanceStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MaintenanceStatus::Success => "SUCCESS",
+            MaintenanceStatus::Failed => "FAILED",
+            MaintenanceStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for MaintenanceStatus {
+    fn default() -> MaintenanceStatus {
+        MaintenanceStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for MaintenanceStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for MaintenanceStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for MaintenanceStatus {
+    fn from(value: &'a str) -> MaintenanceStatus {
+        match value {
+            "SUCCESS" => MaintenanceStatus::Success,
+            "FAILED" => MaintenanceStatus::Failed,
+            _ => MaintenanceStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MaintenanceStatus {
+    fn from(value: String) -> MaintenanceStatus {
+        match value.as_str() {
+            "SUCCESS" => MaintenanceStatus::Success,
+            "FAILED" => MaintenanceStatus::Failed,
+            _ => MaintenanceStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for MaintenanceStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<MaintenanceStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MaintenanceStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for MaintenanceStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MaintenanceStatus {
+    fn deserialize<D>(deserializer: D) -> Result<MaintenanceStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>The status of the association or disassociation request. </p> <p class="title"> <b>Possible values:</b> </p> <ul> <li> <p> <code>SUCCESS</code>: The association or disassociation succeeded. </p> </li> <li> <p> <code>FAILED</code>: The association or disassociation failed. </p> </li> <li> <p> <code>IN_PROGRESS</code>: The association or disassociation is still in progress. </p> </li> </ul>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum NodeAssociationStatus {
+    /// `SUCCESS`
+    Success,
+    /// `FAILED`
+    Failed,
+    /// `IN_PROGRESS`
+    InProgress,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl NodeAssociationStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            NodeAssociationStatus::Success => "SUCCESS",
+            NodeAssociationStatus::Failed => "FAILED",
+            NodeAssociationStatus::InProgress => "IN_PROGRESS",
+            NodeAssociationStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for NodeAssociationStatus {
+    fn default() -> NodeAssociationStatus {
+        NodeAssociationStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for NodeAssociationStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for NodeAssociationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for NodeAssociationStatus {
+    fn from(value: &'a str) -> NodeAssociationStatus {
+        match value {
+            "SUCCESS" => NodeAssociationStatus::Success,
+            "FAILED" => NodeAssociationStatus::Failed,
+            "IN_PROGRESS" => NodeAssociationStatus::InProgress,
+            _ => NodeAssociationStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for NodeAssociationStatus {
+    fn from(value: String) -> NodeAssociationStatus {
+        match value.as_str() {
+            "SUCCESS" => NodeAssociationStatus::Success,
+            "FAILED" => NodeAssociationStatus::Failed,
+            "IN_PROGRESS" => NodeAssociationStatus::InProgress,
+            _ => NodeAssociationStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for NodeAssociationStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<NodeAssociationStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for NodeAssociationStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for NodeAssociationStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for NodeAssociationStatus {
+    fn deserialize<D>(deserializer: D) -> Result<NodeAssociationStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct RestoreServerRequest {
@@ -567,8 +960,13 @@ pub struct Server {
     pub cloud_formation_stack_arn: Option<String>,
     /// <p>Time stamp of server creation. Example <code>2016-07-29T13:38:47.520Z</code> </p>
     #[serde(rename = "CreatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>An optional public endpoint of a server, such as <code>https://aws.my-company.com</code>. You cannot access the server by using the <code>Endpoint</code> value if the server has a <code>CustomDomain</code> specified.</p>
     #[serde(rename = "CustomDomain")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -612,7 +1010,7 @@ pub struct Server {
     /// <p>The status of the most recent server maintenance run. Shows <code>SUCCESS</code> or <code>FAILED</code>. </p>
     #[serde(rename = "MaintenanceStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub maintenance_status: Option<String>,
+    pub maintenance_status: Option<MaintenanceStatus>,
     /// <p>The preferred backup period specified for the server. </p>
     #[serde(rename = "PreferredBackupWindow")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -640,7 +1038,7 @@ pub struct Server {
     /// <p> The server's status. This field displays the states of actions in progress, such as creating, running, or backing up the server, as well as the server's health state. </p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ServerStatus>,
     /// <p> Depending on the server status, this field has either a human-readable message (such as a create or backup error), or an escaped block of JSON (used for health check results). </p>
     #[serde(rename = "StatusReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -657,8 +1055,13 @@ pub struct Server {
 pub struct ServerEvent {
     /// <p>The time when the event occurred. </p>
     #[serde(rename = "CreatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The Amazon S3 URL of the event's log file.</p>
     #[serde(rename = "LogUrl")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -673,6 +1076,154 @@ pub struct ServerEvent {
     pub server_name: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ServerStatus {
+    /// `BACKING_UP`
+    BackingUp,
+    /// `CONNECTION_LOST`
+    ConnectionLost,
+    /// `CREATING`
+    Creating,
+    /// `DELETING`
+    Deleting,
+    /// `MODIFYING`
+    Modifying,
+    /// `FAILED`
+    Failed,
+    /// `HEALTHY`
+    Healthy,
+    /// `RUNNING`
+    Running,
+    /// `RESTORING`
+    Restoring,
+    /// `SETUP`
+    Setup,
+    /// `UNDER_MAINTENANCE`
+    UnderMaintenance,
+    /// `UNHEALTHY`
+    Unhealthy,
+    /// `TERMINATED`
+    Terminated,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ServerStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ServerStatus::BackingUp => "BACKING_UP",
+            ServerStatus::ConnectionLost => "CONNECTION_LOST",
+            ServerStatus::Creating => "CREATING",
+            ServerStatus::Deleting => "DELETING",
+            ServerStatus::Modifying => "MODIFYING",
+            ServerStatus::Failed => "FAILED",
+            ServerStatus::Healthy => "HEALTHY",
+            ServerStatus::Running => "RUNNING",
+            ServerStatus::Restoring => "RESTORING",
+            ServerStatus::Setup => "SETUP",
+            ServerStatus::UnderMaintenance => "UNDER_MAINTENANCE",
+            ServerStatus::Unhealthy => "UNHEALTHY",
+            ServerStatus::Terminated => "TERMINATED",
+            ServerStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ServerStatus {
+    fn default() -> ServerStatus {
+        ServerStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ServerStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ServerStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ServerStatus {
+    fn from(value: &'a str) -> ServerStatus {
+        match value {
+            "BACKING_UP" => ServerStatus::BackingUp,
+            "CONNECTION_LOST" => ServerStatus::ConnectionLost,
+            "CREATING" => ServerStatus::Creating,
+            "DELETING" => ServerStatus::Deleting,
+            "MODIFYING" => ServerStatus::Modifying,
+            "FAILED" => ServerStatus::Failed,
+            "HEALTHY" => ServerStatus::Healthy,
+            "RUNNING" => ServerStatus::Running,
+            "RESTORING" => ServerStatus::Restoring,
+            "SETUP" => ServerStatus::Setup,
+            "UNDER_MAINTENANCE" => ServerStatus::UnderMaintenance,
+            "UNHEALTHY" => ServerStatus::Unhealthy,
+            "TERMINATED" => ServerStatus::Terminated,
+            _ => ServerStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ServerStatus {
+    fn from(value: String) -> ServerStatus {
+        match value.as_str() {
+            "BACKING_UP" => ServerStatus::BackingUp,
+            "CONNECTION_LOST" => ServerStatus::ConnectionLost,
+            "CREATING" => ServerStatus::Creating,
+            "DELETING" => ServerStatus::Deleting,
+            "MODIFYING" => ServerStatus::Modifying,
+            "FAILED" => ServerStatus::Failed,
+            "HEALTHY" => ServerStatus::Healthy,
+            "RUNNING" => ServerStatus::Running,
+            "RESTORING" => ServerStatus::Restoring,
+            "SETUP" => ServerStatus::Setup,
+            "UNDER_MAINTENANCE" => ServerStatus::UnderMaintenance,
+            "UNHEALTHY" => ServerStatus::Unhealthy,
+            "TERMINATED" => ServerStatus::Terminated,
+            _ => ServerStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ServerStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ServerStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ServerStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ServerStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ServerStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ServerStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct StartMaintenanceRequest {
@@ -1611,11 +2162,12 @@ pub struct OpsWorksCMClient {
 impl OpsWorksCMClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> OpsWorksCMClient {
         OpsWorksCMClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "OpsWorksCM"),
         }
     }
 
@@ -1647,6 +2199,7 @@ impl OpsWorksCM for OpsWorksCMClient {
         input: AssociateNodeRequest,
     ) -> Result<AssociateNodeResponse, RusotoError<AssociateNodeError>> {
         let mut request = SignedRequest::new("POST", "opsworks-cm", &self.region, "/");
+        request.set_operation("AssociateNode");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.AssociateNode");
@@ -1674,6 +2227,7 @@ impl OpsWorksCM for OpsWorksCMClient {
         input: CreateBackupRequest,
     ) -> Result<CreateBackupResponse, RusotoError<CreateBackupError>> {
         let mut request = SignedRequest::new("POST", "opsworks-cm", &self.region, "/");
+        request.set_operation("CreateBackup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.CreateBackup");
@@ -1701,6 +2255,7 @@ impl OpsWorksCM for OpsWorksCMClient {
         input: CreateServerRequest,
     ) -> Result<CreateServerResponse, RusotoError<CreateServerError>> {
         let mut request = SignedRequest::new("POST", "opsworks-cm", &self.region, "/");
+        request.set_operation("CreateServer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.CreateServer");
@@ -1728,6 +2283,7 @@ impl OpsWorksCM for OpsWorksCMClient {
         input: DeleteBackupRequest,
     ) -> Result<DeleteBackupResponse, RusotoError<DeleteBackupError>> {
         let mut request = SignedRequest::new("POST", "opsworks-cm", &self.region, "/");
+        request.set_operation("DeleteBackup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.DeleteBackup");
@@ -1755,6 +2311,7 @@ impl OpsWorksCM for OpsWorksCMClient {
         input: DeleteServerRequest,
     ) -> Result<DeleteServerResponse, RusotoError<DeleteServerError>> {
         let mut request = SignedRequest::new("POST", "opsworks-cm", &self.region, "/");
+        request.set_operation("DeleteServer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.DeleteServer");
@@ -1782,6 +2339,7 @@ impl OpsWorksCM for OpsWorksCMClient {
     ) -> Result<DescribeAccountAttributesResponse, RusotoError<DescribeAccountAttributesError>>
     {
         let mut request = SignedRequest::new("POST", "opsworks-cm", &self.region, "/");
+        request.set_operation("DescribeAccountAttributes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1812,6 +2370,7 @@ impl OpsWorksCM for OpsWorksCMClient {
         input: DescribeBackupsRequest,
     ) -> Result<DescribeBackupsResponse, RusotoError<DescribeBackupsError>> {
         let mut request = SignedRequest::new("POST", "opsworks-cm", &self.region, "/");
+        request.set_operation("DescribeBackups");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.DescribeBackups");
@@ -1839,6 +2398,7 @@ impl OpsWorksCM for OpsWorksCMClient {
         input: DescribeEventsRequest,
     ) -> Result<DescribeEventsResponse, RusotoError<DescribeEventsError>> {
         let mut request = SignedRequest::new("POST", "opsworks-cm", &self.region, "/");
+        request.set_operation("DescribeEvents");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.DescribeEvents");
@@ -1869,6 +2429,7 @@ impl OpsWorksCM for OpsWorksCMClient {
         RusotoError<DescribeNodeAssociationStatusError>,
     > {
         let mut request = SignedRequest::new("POST", "opsworks-cm", &self.region, "/");
+        request.set_operation("DescribeNodeAssociationStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1900,6 +2461,7 @@ impl OpsWorksCM for OpsWorksCMClient {
         input: DescribeServersRequest,
     ) -> Result<DescribeServersResponse, RusotoError<DescribeServersError>> {
         let mut request = SignedRequest::new("POST", "opsworks-cm", &self.region, "/");
+        request.set_operation("DescribeServers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.DescribeServers");
@@ -1927,6 +2489,7 @@ impl OpsWorksCM for OpsWorksCMClient {
         input: DisassociateNodeRequest,
     ) -> Result<DisassociateNodeResponse, RusotoError<DisassociateNodeError>> {
         let mut request = SignedRequest::new("POST", "opsworks-cm", &self.region, "/");
+        request.set_operation("DisassociateNode");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.DisassociateNode");
@@ -1956,6 +2519,7 @@ impl OpsWorksCM for OpsWorksCMClient {
     ) -> Result<ExportServerEngineAttributeResponse, RusotoError<ExportServerEngineAttributeError>>
     {
         let mut request = SignedRequest::new("POST", "opsworks-cm", &self.region, "/");
+        request.set_operation("ExportServerEngineAttribute");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1987,6 +2551,7 @@ impl OpsWorksCM for OpsWorksCMClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = SignedRequest::new("POST", "opsworks-cm", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.ListTagsForResource");
@@ -2015,6 +2580,7 @@ impl OpsWorksCM for OpsWorksCMClient {
         input: RestoreServerRequest,
     ) -> Result<RestoreServerResponse, RusotoError<RestoreServerError>> {
         let mut request = SignedRequest::new("POST", "opsworks-cm", &self.region, "/");
+        request.set_operation("RestoreServer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.RestoreServer");
@@ -2042,6 +2608,7 @@ impl OpsWorksCM for OpsWorksCMClient {
         input: StartMaintenanceRequest,
     ) -> Result<StartMaintenanceResponse, RusotoError<StartMaintenanceError>> {
         let mut request = SignedRequest::new("POST", "opsworks-cm", &self.region, "/");
+        request.set_operation("StartMaintenance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.StartMaintenance");
@@ -2070,6 +2637,7 @@ impl OpsWorksCM for OpsWorksCMClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = SignedRequest::new("POST", "opsworks-cm", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.TagResource");
@@ -2097,6 +2665,7 @@ impl OpsWorksCM for OpsWorksCMClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = SignedRequest::new("POST", "opsworks-cm", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.UntagResource");
@@ -2124,6 +2693,7 @@ impl OpsWorksCM for OpsWorksCMClient {
         input: UpdateServerRequest,
     ) -> Result<UpdateServerResponse, RusotoError<UpdateServerError>> {
         let mut request = SignedRequest::new("POST", "opsworks-cm", &self.region, "/");
+        request.set_operation("UpdateServer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.UpdateServer");
@@ -2152,6 +2722,7 @@ impl OpsWorksCM for OpsWorksCMClient {
     ) -> Result<UpdateServerEngineAttributesResponse, RusotoError<UpdateServerEngineAttributesError>>
     {
         let mut request = SignedRequest::new("POST", "opsworks-cm", &self.region, "/");
+        request.set_operation("UpdateServerEngineAttributes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2177,3 +2748,176 @@ impl OpsWorksCM for OpsWorksCMClient {
         }
     }
 }
+impl OpsWorksCMClient {
+    /// Auto-paginating version of `describe_backups`, streaming every page of results.
+    pub fn describe_backups_pages(
+        &self,
+        input: DescribeBackupsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeBackupsResponse, DescribeBackupsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: OpsWorksCMClient, input: DescribeBackupsRequest| async move {
+                client.describe_backups(input).await
+            },
+            |input: &DescribeBackupsRequest, output: &DescribeBackupsResponse| {
+                Some(DescribeBackupsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_backups`, streaming the `Backups` of every page of results.
+    pub fn describe_backups_items(
+        &self,
+        input: DescribeBackupsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Backup, DescribeBackupsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_backups_pages(input),
+            |output: DescribeBackupsResponse| output.backups.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_events`, streaming every page of results.
+    pub fn describe_events_pages(
+        &self,
+        input: DescribeEventsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeEventsResponse, DescribeEventsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: OpsWorksCMClient, input: DescribeEventsRequest| async move {
+                client.describe_events(input).await
+            },
+            |input: &DescribeEventsRequest, output: &DescribeEventsResponse| {
+                Some(DescribeEventsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_events`, streaming the `ServerEvents` of every page of results.
+    pub fn describe_events_items(
+        &self,
+        input: DescribeEventsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ServerEvent, DescribeEventsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_events_pages(input),
+            |output: DescribeEventsResponse| output.server_events.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_servers`, streaming every page of results.
+    pub fn describe_servers_pages(
+        &self,
+        input: DescribeServersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeServersResponse, DescribeServersError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: OpsWorksCMClient, input: DescribeServersRequest| async move {
+                client.describe_servers(input).await
+            },
+            |input: &DescribeServersRequest, output: &DescribeServersResponse| {
+                Some(DescribeServersRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_servers`, streaming the `Servers` of every page of results.
+    pub fn describe_servers_items(
+        &self,
+        input: DescribeServersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Server, DescribeServersError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_servers_pages(input),
+            |output: DescribeServersResponse| output.servers.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_tags_for_resource`, streaming every page of results.
+    pub fn list_tags_for_resource_pages(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListTagsForResourceResponse, ListTagsForResourceError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: OpsWorksCMClient, input: ListTagsForResourceRequest| async move {
+                client.list_tags_for_resource(input).await
+            },
+            |input: &ListTagsForResourceRequest, output: &ListTagsForResourceResponse| {
+                Some(ListTagsForResourceRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_tags_for_resource`, streaming the `Tags` of every page of results.
+    pub fn list_tags_for_resource_items(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Tag, ListTagsForResourceError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_tags_for_resource_pages(input),
+            |output: ListTagsForResourceResponse| output.tags.unwrap_or_default(),
+        )
+    }
+}
+impl OpsWorksCMClient {
+    /// Waits until `describe_node_association_status` reports the `NodeAssociated` state, polling every 15 seconds up to 15 times.
+    pub async fn wait_until_node_associated(
+        &self,
+        input: DescribeNodeAssociationStatusRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeNodeAssociationStatusError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(15), 15);
+        self.wait_until_node_associated_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_node_associated`, polling as often and as many times as `config` says.
+    pub async fn wait_until_node_associated_with_config(
+        &self,
+        input: DescribeNodeAssociationStatusRequest,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeNodeAssociationStatusError>> {
+        rusoto_core::waiter::wait(
+            "NodeAssociated",
+            &config,
+            || self.describe_node_association_status(input.clone()),
+            |result: &Result<
+                DescribeNodeAssociationStatusResponse,
+                RusotoError<DescribeNodeAssociationStatusError>,
+            >| match result {
+                Ok(output)
+                    if output
+                        .node_association_status
+                        .iter()
+                        .any(|v| *v == NodeAssociationStatus::Success) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if output
+                        .node_association_status
+                        .iter()
+                        .any(|v| *v == NodeAssociationStatus::Failed) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+}
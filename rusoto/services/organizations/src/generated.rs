@@ -41,6 +41,100 @@ pub struct AcceptHandshakeResponse {
     pub handshake: Option<Handshake>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AccessDeniedForDependencyExceptionReason {
+    /// `ACCESS_DENIED_DURING_CREATE_SERVICE_LINKED_ROLE`
+    AccessDeniedDuringCreateServiceLinkedRole,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AccessDeniedForDependencyExceptionReason {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AccessDeniedForDependencyExceptionReason::AccessDeniedDuringCreateServiceLinkedRole => {
+                "ACCESS_DENIED_DURING_CREATE_SERVICE_LINKED_ROLE"
+            }
+            AccessDeniedForDependencyExceptionReason::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AccessDeniedForDependencyExceptionReason {
+    fn default() -> AccessDeniedForDependencyExceptionReason {
+        AccessDeniedForDependencyExceptionReason::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AccessDeniedForDependencyExceptionReason {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AccessDeniedForDependencyExceptionReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AccessDeniedForDependencyExceptionReason {
+    fn from(value: &'a str) -> AccessDeniedForDependencyExceptionReason {
+        match value {
+            "ACCESS_DENIED_DURING_CREATE_SERVICE_LINKED_ROLE" => {
+                AccessDeniedForDependencyExceptionReason::AccessDeniedDuringCreateServiceLinkedRole
+            }
+            _ => AccessDeniedForDependencyExceptionReason::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AccessDeniedForDependencyExceptionReason {
+    fn from(value: String) -> AccessDeniedForDependencyExceptionReason {
+        match value.as_str() {
+            "ACCESS_DENIED_DURING_CREATE_SERVICE_LINKED_ROLE" => {
+                AccessDeniedForDependencyExceptionReason::AccessDeniedDuringCreateServiceLinkedRole
+            }
+            _ => AccessDeniedForDependencyExceptionReason::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AccessDeniedForDependencyExceptionReason {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AccessDeniedForDependencyExceptionReason, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AccessDeniedForDependencyExceptionReason {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AccessDeniedForDependencyExceptionReason {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AccessDeniedForDependencyExceptionReason {
+    fn deserialize<D>(deserializer: D) -> Result<AccessDeniedForDependencyExceptionReason, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Contains information about an AWS account that is a member of an organization.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -60,11 +154,16 @@ pub struct Account {
     /// <p>The method by which the account joined the organization.</p>
     #[serde(rename = "JoinedMethod")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub joined_method: Option<String>,
+    pub joined_method: Option<AccountJoinedMethod>,
     /// <p>The date the account became a part of the organization.</p>
     #[serde(rename = "JoinedTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub joined_timestamp: Option<f64>,
+    pub joined_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The friendly name of the account.</p> <p>The <a href="http://wikipedia.org/wiki/regex">regex pattern</a> that is used to validate this parameter is a string of any of the characters in the ASCII character range.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -72,9 +171,309 @@ pub struct Account {
     /// <p>The status of the account in the organization.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<AccountStatus>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AccountJoinedMethod {
+    /// `INVITED`
+    Invited,
+    /// `CREATED`
+    Created,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AccountJoinedMethod {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AccountJoinedMethod::Invited => "INVITED",
+            AccountJoinedMethod::Created => "CREATED",
+            AccountJoinedMethod::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AccountJoinedMethod {
+    fn default() -> AccountJoinedMethod {
+        AccountJoinedMethod::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AccountJoinedMethod {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AccountJoinedMethod {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AccountJoinedMethod {
+    fn from(value: &'a str) -> AccountJoinedMethod {
+        match value {
+            "INVITED" => AccountJoinedMethod::Invited,
+            "CREATED" => AccountJoinedMethod::Created,
+            _ => AccountJoinedMethod::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AccountJoinedMethod {
+    fn from(value: String) -> AccountJoinedMethod {
+        match value.as_str() {
+            "INVITED" => AccountJoinedMethod::Invited,
+            "CREATED" => AccountJoinedMethod::Created,
+            _ => AccountJoinedMethod::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AccountJoinedMethod {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AccountJoinedMethod, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AccountJoinedMethod {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AccountJoinedMethod {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AccountJoinedMethod {
+    fn deserialize<D>(deserializer: D) -> Result<AccountJoinedMethod, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AccountStatus {
+    /// `ACTIVE`
+    Active,
+    /// `SUSPENDED`
+    Suspended,
+    /// `PENDING_CLOSURE`
+    PendingClosure,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AccountStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AccountStatus::Active => "ACTIVE",
+            AccountStatus::Suspended => "SUSPENDED",
+            AccountStatus::PendingClosure => "PENDING_CLOSURE",
+            AccountStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AccountStatus {
+    fn default() -> AccountStatus {
+        AccountStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AccountStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AccountStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AccountStatus {
+    fn from(value: &'a str) -> AccountStatus {
+        match value {
+            "ACTIVE" => AccountStatus::Active,
+            "SUSPENDED" => AccountStatus::Suspended,
+            "PENDING_CLOSURE" => AccountStatus::PendingClosure,
+            _ => AccountStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AccountStatus {
+    fn from(value: String) -> AccountStatus {
+        match value.as_str() {
+            "ACTIVE" => AccountStatus::Active,
+            "SUSPENDED" => AccountStatus::Suspended,
+            "PENDING_CLOSURE" => AccountStatus::PendingClosure,
+            _ => AccountStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AccountStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AccountStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AccountStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AccountStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AccountStatus {
+    fn deserialize<D>(deserializer: D) -> Result<AccountStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ActionType {
+    /// `INVITE`
+    Invite,
+    /// `ENABLE_ALL_FEATURES`
+    EnableAllFeatures,
+    /// `APPROVE_ALL_FEATURES`
+    ApproveAllFeatures,
+    /// `ADD_ORGANIZATIONS_SERVICE_LINKED_ROLE`
+    AddOrganizationsServiceLinkedRole,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ActionType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ActionType::Invite => "INVITE",
+            ActionType::EnableAllFeatures => "ENABLE_ALL_FEATURES",
+            ActionType::ApproveAllFeatures => "APPROVE_ALL_FEATURES",
+            ActionType::AddOrganizationsServiceLinkedRole => {
+                "ADD_ORGANIZATIONS_SERVICE_LINKED_ROLE"
+            }
+            ActionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ActionType {
+    fn default() -> ActionType {
+        ActionType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ActionType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ActionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ActionType {
+    fn from(value: &'a str) -> ActionType {
+        match value {
+            "INVITE" => ActionType::Invite,
+            "ENABLE_ALL_FEATURES" => ActionType::EnableAllFeatures,
+            "APPROVE_ALL_FEATURES" => ActionType::ApproveAllFeatures,
+            "ADD_ORGANIZATIONS_SERVICE_LINKED_ROLE" => {
+                ActionType::AddOrganizationsServiceLinkedRole
+            }
+            _ => ActionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ActionType {
+    fn from(value: String) -> ActionType {
+        match value.as_str() {
+            "INVITE" => ActionType::Invite,
+            "ENABLE_ALL_FEATURES" => ActionType::EnableAllFeatures,
+            "APPROVE_ALL_FEATURES" => ActionType::ApproveAllFeatures,
+            "ADD_ORGANIZATIONS_SERVICE_LINKED_ROLE" => {
+                ActionType::AddOrganizationsServiceLinkedRole
+            }
+            _ => ActionType::Unknown(value),
+        }
+    }
 }
 
+impl ::std::str::FromStr for ActionType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ActionType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ActionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ActionType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ActionType {
+    fn deserialize<D>(deserializer: D) -> Result<ActionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct AttachPolicyRequest {
@@ -114,115 +513,751 @@ pub struct Child {
     /// <p>The type of this child entity.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<ChildType>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct CreateAccountRequest {
-    /// <p>The friendly name of the member account.</p>
-    #[serde(rename = "AccountName")]
-    pub account_name: String,
-    /// <p>The email address of the owner to assign to the new member account. This email address must not already be associated with another AWS account. You must use a valid email address to complete account creation. You can't access the root user of the account or remove an account that was created with an invalid email address.</p>
-    #[serde(rename = "Email")]
-    pub email: String,
-    /// <p>If set to <code>ALLOW</code>, the new account enables IAM users to access account billing information <i>if</i> they have the required permissions. If set to <code>DENY</code>, only the root user of the new account can access account billing information. For more information, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/grantaccess.html#ControllingAccessWebsite-Activate">Activating Access to the Billing and Cost Management Console</a> in the <i>AWS Billing and Cost Management User Guide</i>.</p> <p>If you don't specify this parameter, the value defaults to <code>ALLOW</code>. This value allows IAM users and roles with the required permissions to access billing information for the new account.</p>
-    #[serde(rename = "IamUserAccessToBilling")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub iam_user_access_to_billing: Option<String>,
-    /// <p>(Optional)</p> <p>The name of an IAM role that AWS Organizations automatically preconfigures in the new member account. This role trusts the master account, allowing users in the master account to assume the role, as permitted by the master account administrator. The role has administrator permissions in the new member account.</p> <p>If you don't specify this parameter, the role name defaults to <code>OrganizationAccountAccessRole</code>.</p> <p>For more information about how to use this role to access the member account, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_accounts_access.html#orgs_manage_accounts_create-cross-account-role">Accessing and Administering the Member Accounts in Your Organization</a> in the <i>AWS Organizations User Guide</i>. Also see steps 2 and 3 in <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/tutorial_cross-account-with-roles.html">Tutorial: Delegate Access Across AWS Accounts Using IAM Roles</a> in the <i>IAM User Guide.</i> </p> <p>The <a href="http://wikipedia.org/wiki/regex">regex pattern</a> that is used to validate this parameter. The pattern can include uppercase letters, lowercase letters, digits with no spaces, and any of the following characters: =,.@-</p>
-    #[serde(rename = "RoleName")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub role_name: Option<String>,
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ChildType {
+    /// `ACCOUNT`
+    Account,
+    /// `ORGANIZATIONAL_UNIT`
+    OrganizationalUnit,
+    /// A value not known to this version of the crate
+    Unknown(String),
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct CreateAccountResponse {
-    /// <p>A structure that contains details about the request to create an account. This response structure might not be fully populated when you first receive it because account creation is an asynchronous process. You can pass the returned <code>CreateAccountStatus</code> ID as a parameter to <a>DescribeCreateAccountStatus</a> to get status about the progress of the request at later times. You can also check the AWS CloudTrail log for the <code>CreateAccountResult</code> event. For more information, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_monitoring.html">Monitoring the Activity in Your Organization</a> in the <i>AWS Organizations User Guide</i>.</p>
-    #[serde(rename = "CreateAccountStatus")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub create_account_status: Option<CreateAccountStatus>,
+impl ChildType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ChildType::Account => "ACCOUNT",
+            ChildType::OrganizationalUnit => "ORGANIZATIONAL_UNIT",
+            ChildType::Unknown(ref value) => value,
+        }
+    }
 }
 
-/// <p>Contains the status about a <a>CreateAccount</a> or <a>CreateGovCloudAccount</a> request to create an AWS account or an AWS GovCloud (US) account in an organization.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct CreateAccountStatus {
-    /// <p>If the account was created successfully, the unique identifier (ID) of the new account.</p> <p>The <a href="http://wikipedia.org/wiki/regex">regex pattern</a> for an account ID string requires exactly 12 digits.</p>
-    #[serde(rename = "AccountId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub account_id: Option<String>,
-    /// <p>The account name given to the account when it was created.</p>
-    #[serde(rename = "AccountName")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub account_name: Option<String>,
-    /// <p>The date and time that the account was created and the request completed.</p>
-    #[serde(rename = "CompletedTimestamp")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub completed_timestamp: Option<f64>,
-    /// <p><p>If the request failed, a description of the reason for the failure.</p> <ul> <li> <p>ACCOUNT<em>LIMIT</em>EXCEEDED: The account could not be created because you have reached the limit on the number of accounts in your organization.</p> </li> <li> <p>EMAIL<em>ALREADY</em>EXISTS: The account could not be created because another AWS account with that email address already exists.</p> </li> <li> <p>GOVCLOUD<em>ACCOUNT</em>ALREADY<em>EXISTS: The account in the AWS GovCloud (US) Region could not be created because this Region already includes an account with that email address.</p> </li> <li> <p>INVALID</em>ADDRESS: The account could not be created because the address you provided is not valid.</p> </li> <li> <p>INVALID<em>EMAIL: The account could not be created because the email address you provided is not valid.</p> </li> <li> <p>INTERNAL</em>FAILURE: The account could not be created because of an internal failure. Try again later. If the problem persists, contact AWS Support.</p> </li> </ul></p>
-    #[serde(rename = "FailureReason")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub failure_reason: Option<String>,
-    /// <p>If the account was created successfully, the unique identifier (ID) of the new account in the AWS GovCloud (US) Region.</p>
-    #[serde(rename = "GovCloudAccountId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub gov_cloud_account_id: Option<String>,
-    /// <p>The unique identifier (ID) that references this request. You get this value from the response of the initial <a>CreateAccount</a> request to create the account.</p> <p>The <a href="http://wikipedia.org/wiki/regex">regex pattern</a> for a create account request ID string requires "car-" followed by from 8 to 32 lower-case letters or digits.</p>
-    #[serde(rename = "Id")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
-    /// <p>The date and time that the request was made for the account creation.</p>
-    #[serde(rename = "RequestedTimestamp")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub requested_timestamp: Option<f64>,
-    /// <p>The status of the request.</p>
-    #[serde(rename = "State")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ChildType {
+    fn default() -> ChildType {
+        ChildType::Unknown(String::new())
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct CreateGovCloudAccountRequest {
-    /// <p>The friendly name of the member account.</p>
-    #[serde(rename = "AccountName")]
-    pub account_name: String,
-    /// <p>The email address of the owner to assign to the new member account in the commercial Region. This email address must not already be associated with another AWS account. You must use a valid email address to complete account creation. You can't access the root user of the account or remove an account that was created with an invalid email address. Like all request parameters for <code>CreateGovCloudAccount</code>, the request for the email address for the AWS GovCloud (US) account originates from the commercial Region. It does not come from the AWS GovCloud (US) Region.</p>
-    #[serde(rename = "Email")]
-    pub email: String,
-    /// <p>If set to <code>ALLOW</code>, the new linked account in the commercial Region enables IAM users to access account billing information <i>if</i> they have the required permissions. If set to <code>DENY</code>, only the root user of the new account can access account billing information. For more information, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/grantaccess.html#ControllingAccessWebsite-Activate">Activating Access to the Billing and Cost Management Console</a> in the <i>AWS Billing and Cost Management User Guide.</i> </p> <p>If you don't specify this parameter, the value defaults to <code>ALLOW</code>, and IAM users and roles with the required permissions can access billing information for the new account.</p>
-    #[serde(rename = "IamUserAccessToBilling")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub iam_user_access_to_billing: Option<String>,
-    /// <p>(Optional)</p> <p>The name of an IAM role that AWS Organizations automatically preconfigures in the new member accounts in both the AWS GovCloud (US) Region and in the commercial Region. This role trusts the master account, allowing users in the master account to assume the role, as permitted by the master account administrator. The role has administrator permissions in the new member account.</p> <p>If you don't specify this parameter, the role name defaults to <code>OrganizationAccountAccessRole</code>.</p> <p>For more information about how to use this role to access the member account, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_accounts_access.html#orgs_manage_accounts_create-cross-account-role">Accessing and Administering the Member Accounts in Your Organization</a> in the <i>AWS Organizations User Guide</i>. See also steps 2 and 3 in <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/tutorial_cross-account-with-roles.html">Tutorial: Delegate Access Across AWS Accounts Using IAM Roles</a> in the <i>IAM User Guide.</i> </p> <p>The <a href="http://wikipedia.org/wiki/regex">regex pattern</a> that is used to validate this parameter. The pattern can include uppercase letters, lowercase letters, digits with no spaces, and any of the following characters: =,.@-</p>
-    #[serde(rename = "RoleName")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub role_name: Option<String>,
+impl AsRef<str> for ChildType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct CreateGovCloudAccountResponse {
-    #[serde(rename = "CreateAccountStatus")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub create_account_status: Option<CreateAccountStatus>,
+impl fmt::Display for ChildType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct CreateOrganizationRequest {
-    /// <p><p>Specifies the feature set supported by the new organization. Each feature set supports different levels of functionality.</p> <ul> <li> <p> <code>CONSOLIDATED<em>BILLING</code>: All member accounts have their bills consolidated to and paid by the master account. For more information, see &lt;a href=&quot;https://docs.aws.amazon.com/organizations/latest/userguide/orgs</em>getting-started<em>concepts.html#feature-set-cb-only&quot;&gt;Consolidated billing</a> in the <i>AWS Organizations User Guide.</i> </p> <p> The consolidated billing feature subset isn&#39;t available for organizations in the AWS GovCloud (US) Region.</p> </li> <li> <p> <code>ALL</code>: In addition to all the features that consolidated billing feature set supports, the master account can also apply any policy type to any member account in the organization. For more information, see &lt;a href=&quot;https://docs.aws.amazon.com/organizations/latest/userguide/orgs</em>getting-started_concepts.html#feature-set-all&quot;&gt;All features</a> in the <i>AWS Organizations User Guide.</i> </p> </li> </ul></p>
-    #[serde(rename = "FeatureSet")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub feature_set: Option<String>,
+impl<'a> From<&'a str> for ChildType {
+    fn from(value: &'a str) -> ChildType {
+        match value {
+            "ACCOUNT" => ChildType::Account,
+            "ORGANIZATIONAL_UNIT" => ChildType::OrganizationalUnit,
+            _ => ChildType::Unknown(value.to_owned()),
+        }
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct CreateOrganizationResponse {
-    /// <p>A structure that contains details about the newly created organization.</p>
+impl From<String> for ChildType {
+    fn from(value: String) -> ChildType {
+        match value.as_str() {
+            "ACCOUNT" => ChildType::Account,
+            "ORGANIZATIONAL_UNIT" => ChildType::OrganizationalUnit,
+            _ => ChildType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ChildType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ChildType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ChildType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ChildType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ChildType {
+    fn deserialize<D>(deserializer: D) -> Result<ChildType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ConstraintViolationExceptionReason {
+    /// `ACCOUNT_NUMBER_LIMIT_EXCEEDED`
+    AccountNumberLimitExceeded,
+    /// `HANDSHAKE_RATE_LIMIT_EXCEEDED`
+    HandshakeRateLimitExceeded,
+    /// `OU_NUMBER_LIMIT_EXCEEDED`
+    OuNumberLimitExceeded,
+    /// `OU_DEPTH_LIMIT_EXCEEDED`
+    OuDepthLimitExceeded,
+    /// `POLICY_NUMBER_LIMIT_EXCEEDED`
+    PolicyNumberLimitExceeded,
+    /// `POLICY_CONTENT_LIMIT_EXCEEDED`
+    PolicyContentLimitExceeded,
+    /// `MAX_POLICY_TYPE_ATTACHMENT_LIMIT_EXCEEDED`
+    MaxPolicyTypeAttachmentLimitExceeded,
+    /// `MIN_POLICY_TYPE_ATTACHMENT_LIMIT_EXCEEDED`
+    MinPolicyTypeAttachmentLimitExceeded,
+    /// `ACCOUNT_CANNOT_LEAVE_ORGANIZATION`
+    AccountCannotLeaveOrganization,
+    /// `ACCOUNT_CANNOT_LEAVE_WITHOUT_EULA`
+    AccountCannotLeaveWithoutEula,
+    /// `ACCOUNT_CANNOT_LEAVE_WITHOUT_PHONE_VERIFICATION`
+    AccountCannotLeaveWithoutPhoneVerification,
+    /// `MASTER_ACCOUNT_PAYMENT_INSTRUMENT_REQUIRED`
+    MasterAccountPaymentInstrumentRequired,
+    /// `MEMBER_ACCOUNT_PAYMENT_INSTRUMENT_REQUIRED`
+    MemberAccountPaymentInstrumentRequired,
+    /// `ACCOUNT_CREATION_RATE_LIMIT_EXCEEDED`
+    AccountCreationRateLimitExceeded,
+    /// `MASTER_ACCOUNT_ADDRESS_DOES_NOT_MATCH_MARKETPLACE`
+    MasterAccountAddressDoesNotMatchMarketplace,
+    /// `MASTER_ACCOUNT_MISSING_CONTACT_INFO`
+    MasterAccountMissingContactInfo,
+    /// `MASTER_ACCOUNT_NOT_GOVCLOUD_ENABLED`
+    MasterAccountNotGovcloudEnabled,
+    /// `ORGANIZATION_NOT_IN_ALL_FEATURES_MODE`
+    OrganizationNotInAllFeaturesMode,
+    /// `CREATE_ORGANIZATION_IN_BILLING_MODE_UNSUPPORTED_REGION`
+    CreateOrganizationInBillingModeUnsupportedRegion,
+    /// `EMAIL_VERIFICATION_CODE_EXPIRED`
+    EmailVerificationCodeExpired,
+    /// `WAIT_PERIOD_ACTIVE`
+    WaitPeriodActive,
+    /// `MAX_TAG_LIMIT_EXCEEDED`
+    MaxTagLimitExceeded,
+    /// `TAG_POLICY_VIOLATION`
+    TagPolicyViolation,
+    /// `MAX_DELEGATED_ADMINISTRATORS_FOR_SERVICE_LIMIT_EXCEEDED`
+    MaxDelegatedAdministratorsForServiceLimitExceeded,
+    /// `CANNOT_REGISTER_MASTER_AS_DELEGATED_ADMINISTRATOR`
+    CannotRegisterMasterAsDelegatedAdministrator,
+    /// `CANNOT_REMOVE_DELEGATED_ADMINISTRATOR_FROM_ORG`
+    CannotRemoveDelegatedAdministratorFromOrg,
+    /// `DELEGATED_ADMINISTRATOR_EXISTS_FOR_THIS_SERVICE`
+    DelegatedAdministratorExistsForThisService,
+    /// `MASTER_ACCOUNT_MISSING_BUSINESS_LICENSE`
+    MasterAccountMissingBusinessLicense,
+    /// `CANNOT_CLOSE_MANAGEMENT_ACCOUNT`
+    CannotCloseManagementAccount,
+    /// `CLOSE_ACCOUNT_QUOTA_EXCEEDED`
+    CloseAccountQuotaExceeded,
+    /// `CLOSE_ACCOUNT_REQUESTS_LIMIT_EXCEEDED`
+    CloseAccountRequestsLimitExceeded,
+    /// `SERVICE_ACCESS_NOT_ENABLED`
+    ServiceAccessNotEnabled,
+    /// `INVALID_PAYMENT_INSTRUMENT`
+    InvalidPaymentInstrument,
+    /// `ACCOUNT_CREATION_NOT_COMPLETE`
+    AccountCreationNotComplete,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ConstraintViolationExceptionReason {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+                    ConstraintViolationExceptionReason::AccountNumberLimitExceeded => "ACCOUNT_NUMBER_LIMIT_EXCEEDED",
+ConstraintViolationExceptionReason::HandshakeRateLimitExceeded => "HANDSHAKE_RATE_LIMIT_EXCEEDED",
+ConstraintViolationExceptionReason::OuNumberLimitExceeded => "OU_NUMBER_LIMIT_EXCEEDED",
+ConstraintViolationExceptionReason::OuDepthLimitExceeded => "OU_DEPTH_LIMIT_EXCEEDED",
+ConstraintViolationExceptionReason::PolicyNumberLimitExceeded => "POLICY_NUMBER_LIMIT_EXCEEDED",
+ConstraintViolationExceptionReason::PolicyContentLimitExceeded => "POLICY_CONTENT_LIMIT_EXCEEDED",
+ConstraintViolationExceptionReason::MaxPolicyTypeAttachmentLimitExceeded => "MAX_POLICY_TYPE_ATTACHMENT_LIMIT_EXCEEDED",
+ConstraintViolationExceptionReason::MinPolicyTypeAttachmentLimitExceeded => "MIN_POLICY_TYPE_ATTACHMENT_LIMIT_EXCEEDED",
+ConstraintViolationExceptionReason::AccountCannotLeaveOrganization => "ACCOUNT_CANNOT_LEAVE_ORGANIZATION",
+ConstraintViolationExceptionReason::AccountCannotLeaveWithoutEula => "ACCOUNT_CANNOT_LEAVE_WITHOUT_EULA",
+ConstraintViolationExceptionReason::AccountCannotLeaveWithoutPhoneVerification => "ACCOUNT_CANNOT_LEAVE_WITHOUT_PHONE_VERIFICATION",
+ConstraintViolationExceptionReason::MasterAccountPaymentInstrumentRequired => "MASTER_ACCOUNT_PAYMENT_INSTRUMENT_REQUIRED",
+ConstraintViolationExceptionReason::MemberAccountPaymentInstrumentRequired => "MEMBER_ACCOUNT_PAYMENT_INSTRUMENT_REQUIRED",
+ConstraintViolationExceptionReason::AccountCreationRateLimitExceeded => "ACCOUNT_CREATION_RATE_LIMIT_EXCEEDED",
+ConstraintViolationExceptionReason::MasterAccountAddressDoesNotMatchMarketplace => "MASTER_ACCOUNT_ADDRESS_DOES_NOT_MATCH_MARKETPLACE",
+ConstraintViolationExceptionReason::MasterAccountMissingContactInfo => "MASTER_ACCOUNT_MISSING_CONTACT_INFO",
+ConstraintViolationExceptionReason::MasterAccountNotGovcloudEnabled => "MASTER_ACCOUNT_NOT_GOVCLOUD_ENABLED",
+ConstraintViolationExceptionReason::OrganizationNotInAllFeaturesMode => "ORGANIZATION_NOT_IN_ALL_FEATURES_MODE",
+ConstraintViolationExceptionReason::CreateOrganizationInBillingModeUnsupportedRegion => "CREATE_ORGANIZATION_IN_BILLING_MODE_UNSUPPORTED_REGION",
+ConstraintViolationExceptionReason::EmailVerificationCodeExpired => "EMAIL_VERIFICATION_CODE_EXPIRED",
+ConstraintViolationExceptionReason::WaitPeriodActive => "WAIT_PERIOD_ACTIVE",
+ConstraintViolationExceptionReason::MaxTagLimitExceeded => "MAX_TAG_LIMIT_EXCEEDED",
+ConstraintViolationExceptionReason::TagPolicyViolation => "TAG_POLICY_VIOLATION",
+ConstraintViolationExceptionReason::MaxDelegatedAdministratorsForServiceLimitExceeded => "MAX_DELEGATED_ADMINISTRATORS_FOR_SERVICE_LIMIT_EXCEEDED",
+ConstraintViolationExceptionReason::CannotRegisterMasterAsDelegatedAdministrator => "CANNOT_REGISTER_MASTER_AS_DELEGATED_ADMINISTRATOR",
+ConstraintViolationExceptionReason::CannotRemoveDelegatedAdministratorFromOrg => "CANNOT_REMOVE_DELEGATED_ADMINISTRATOR_FROM_ORG",
+ConstraintViolationExceptionReason::DelegatedAdministratorExistsForThisService => "DELEGATED_ADMINISTRATOR_EXISTS_FOR_THIS_SERVICE",
+ConstraintViolationExceptionReason::MasterAccountMissingBusinessLicense => "MASTER_ACCOUNT_MISSING_BUSINESS_LICENSE",
+ConstraintViolationExceptionReason::CannotCloseManagementAccount => "CANNOT_CLOSE_MANAGEMENT_ACCOUNT",
+ConstraintViolationExceptionReason::CloseAccountQuotaExceeded => "CLOSE_ACCOUNT_QUOTA_EXCEEDED",
+ConstraintViolationExceptionReason::CloseAccountRequestsLimitExceeded => "CLOSE_ACCOUNT_REQUESTS_LIMIT_EXCEEDED",
+ConstraintViolationExceptionReason::ServiceAccessNotEnabled => "SERVICE_ACCESS_NOT_ENABLED",
+ConstraintViolationExceptionReason::InvalidPaymentInstrument => "INVALID_PAYMENT_INSTRUMENT",
+ConstraintViolationExceptionReason::AccountCreationNotComplete => "ACCOUNT_CREATION_NOT_COMPLETE",
+                    ConstraintViolationExceptionReason::Unknown(ref value) => value,
+                }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ConstraintViolationExceptionReason {
+    fn default() -> ConstraintViolationExceptionReason {
+        ConstraintViolationExceptionReason::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ConstraintViolationExceptionReason {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ConstraintViolationExceptionReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ConstraintViolationExceptionReason {
+    fn from(value: &'a str) -> ConstraintViolationExceptionReason {
+        match value {
+                    "ACCOUNT_NUMBER_LIMIT_EXCEEDED" => ConstraintViolationExceptionReason::AccountNumberLimitExceeded,
+"HANDSHAKE_RATE_LIMIT_EXCEEDED" => ConstraintViolationExceptionReason::HandshakeRateLimitExceeded,
+"OU_NUMBER_LIMIT_EXCEEDED" => ConstraintViolationExceptionReason::OuNumberLimitExceeded,
+"OU_DEPTH_LIMIT_EXCEEDED" => ConstraintViolationExceptionReason::OuDepthLimitExceeded,
+"POLICY_NUMBER_LIMIT_EXCEEDED" => ConstraintViolationExceptionReason::PolicyNumberLimitExceeded,
+"POLICY_CONTENT_LIMIT_EXCEEDED" => ConstraintViolationExceptionReason::PolicyContentLimitExceeded,
+"MAX_POLICY_TYPE_ATTACHMENT_LIMIT_EXCEEDED" => ConstraintViolationExceptionReason::MaxPolicyTypeAttachmentLimitExceeded,
+"MIN_POLICY_TYPE_ATTACHMENT_LIMIT_EXCEEDED" => ConstraintViolationExceptionReason::MinPolicyTypeAttachmentLimitExceeded,
+"ACCOUNT_CANNOT_LEAVE_ORGANIZATION" => ConstraintViolationExceptionReason::AccountCannotLeaveOrganization,
+"ACCOUNT_CANNOT_LEAVE_WITHOUT_EULA" => ConstraintViolationExceptionReason::AccountCannotLeaveWithoutEula,
+"ACCOUNT_CANNOT_LEAVE_WITHOUT_PHONE_VERIFICATION" => ConstraintViolationExceptionReason::AccountCannotLeaveWithoutPhoneVerification,
+"MASTER_ACCOUNT_PAYMENT_INSTRUMENT_REQUIRED" => ConstraintViolationExceptionReason::MasterAccountPaymentInstrumentRequired,
+"MEMBER_ACCOUNT_PAYMENT_INSTRUMENT_REQUIRED" => ConstraintViolationExceptionReason::MemberAccountPaymentInstrumentRequired,
+"ACCOUNT_CREATION_RATE_LIMIT_EXCEEDED" => ConstraintViolationExceptionReason::AccountCreationRateLimitExceeded,
+"MASTER_ACCOUNT_ADDRESS_DOES_NOT_MATCH_MARKETPLACE" => ConstraintViolationExceptionReason::MasterAccountAddressDoesNotMatchMarketplace,
+"MASTER_ACCOUNT_MISSING_CONTACT_INFO" => ConstraintViolationExceptionReason::MasterAccountMissingContactInfo,
+"MASTER_ACCOUNT_NOT_GOVCLOUD_ENABLED" => ConstraintViolationExceptionReason::MasterAccountNotGovcloudEnabled,
+"ORGANIZATION_NOT_IN_ALL_FEATURES_MODE" => ConstraintViolationExceptionReason::OrganizationNotInAllFeaturesMode,
+"CREATE_ORGANIZATION_IN_BILLING_MODE_UNSUPPORTED_REGION" => ConstraintViolationExceptionReason::CreateOrganizationInBillingModeUnsupportedRegion,
+"EMAIL_VERIFICATION_CODE_EXPIRED" => ConstraintViolationExceptionReason::EmailVerificationCodeExpired,
+"WAIT_PERIOD_ACTIVE" => ConstraintViolationExceptionReason::WaitPeriodActive,
+"MAX_TAG_LIMIT_EXCEEDED" => ConstraintViolationExceptionReason::MaxTagLimitExceeded,
+"TAG_POLICY_VIOLATION" => ConstraintViolationExceptionReason::TagPolicyViolation,
+"MAX_DELEGATED_ADMINISTRATORS_FOR_SERVICE_LIMIT_EXCEEDED" => ConstraintViolationExceptionReason::MaxDelegatedAdministratorsForServiceLimitExceeded,
+"CANNOT_REGISTER_MASTER_AS_DELEGATED_ADMINISTRATOR" => ConstraintViolationExceptionReason::CannotRegisterMasterAsDelegatedAdministrator,
+"CANNOT_REMOVE_DELEGATED_ADMINISTRATOR_FROM_ORG" => ConstraintViolationExceptionReason::CannotRemoveDelegatedAdministratorFromOrg,
+"DELEGATED_ADMINISTRATOR_EXISTS_FOR_THIS_SERVICE" => ConstraintViolationExceptionReason::DelegatedAdministratorExistsForThisService,
+"MASTER_ACCOUNT_MISSING_BUSINESS_LICENSE" => ConstraintViolationExceptionReason::MasterAccountMissingBusinessLicense,
+"CANNOT_CLOSE_MANAGEMENT_ACCOUNT" => ConstraintViolationExceptionReason::CannotCloseManagementAccount,
+"CLOSE_ACCOUNT_QUOTA_EXCEEDED" => ConstraintViolationExceptionReason::CloseAccountQuotaExceeded,
+"CLOSE_ACCOUNT_REQUESTS_LIMIT_EXCEEDED" => ConstraintViolationExceptionReason::CloseAccountRequestsLimitExceeded,
+"SERVICE_ACCESS_NOT_ENABLED" => ConstraintViolationExceptionReason::ServiceAccessNotEnabled,
+"INVALID_PAYMENT_INSTRUMENT" => ConstraintViolationExceptionReason::InvalidPaymentInstrument,
+"ACCOUNT_CREATION_NOT_COMPLETE" => ConstraintViolationExceptionReason::AccountCreationNotComplete,
+                    _ => ConstraintViolationExceptionReason::Unknown(value.to_owned()),
+                }
+    }
+}
+
+impl From<String> for ConstraintViolationExceptionReason {
+    fn from(value: String) -> ConstraintViolationExceptionReason {
+        match value.as_str() {
+                    "ACCOUNT_NUMBER_LIMIT_EXCEEDED" => ConstraintViolationExceptionReason::AccountNumberLimitExceeded,
+"HANDSHAKE_RATE_LIMIT_EXCEEDED" => ConstraintViolationExceptionReason::HandshakeRateLimitExceeded,
+"OU_NUMBER_LIMIT_EXCEEDED" => ConstraintViolationExceptionReason::OuNumberLimitExceeded,
+"OU_DEPTH_LIMIT_EXCEEDED" => ConstraintViolationExceptionReason::OuDepthLimitExceeded,
+"POLICY_NUMBER_LIMIT_EXCEEDED" => ConstraintViolationExceptionReason::PolicyNumberLimitExceeded,
+"POLICY_CONTENT_LIMIT_EXCEEDED" => ConstraintViolationExceptionReason::PolicyContentLimitExceeded,
+"MAX_POLICY_TYPE_ATTACHMENT_LIMIT_EXCEEDED" => ConstraintViolationExceptionReason::MaxPolicyTypeAttachmentLimitExceeded,
+"MIN_POLICY_TYPE_ATTACHMENT_LIMIT_EXCEEDED" => ConstraintViolationExceptionReason::MinPolicyTypeAttachmentLimitExceeded,
+"ACCOUNT_CANNOT_LEAVE_ORGANIZATION" => ConstraintViolationExceptionReason::AccountCannotLeaveOrganization,
+"ACCOUNT_CANNOT_LEAVE_WITHOUT_EULA" => ConstraintViolationExceptionReason::AccountCannotLeaveWithoutEula,
+"ACCOUNT_CANNOT_LEAVE_WITHOUT_PHONE_VERIFICATION" => ConstraintViolationExceptionReason::AccountCannotLeaveWithoutPhoneVerification,
+"MASTER_ACCOUNT_PAYMENT_INSTRUMENT_REQUIRED" => ConstraintViolationExceptionReason::MasterAccountPaymentInstrumentRequired,
+"MEMBER_ACCOUNT_PAYMENT_INSTRUMENT_REQUIRED" => ConstraintViolationExceptionReason::MemberAccountPaymentInstrumentRequired,
+"ACCOUNT_CREATION_RATE_LIMIT_EXCEEDED" => ConstraintViolationExceptionReason::AccountCreationRateLimitExceeded,
+"MASTER_ACCOUNT_ADDRESS_DOES_NOT_MATCH_MARKETPLACE" => ConstraintViolationExceptionReason::MasterAccountAddressDoesNotMatchMarketplace,
+"MASTER_ACCOUNT_MISSING_CONTACT_INFO" => ConstraintViolationExceptionReason::MasterAccountMissingContactInfo,
+"MASTER_ACCOUNT_NOT_GOVCLOUD_ENABLED" => ConstraintViolationExceptionReason::MasterAccountNotGovcloudEnabled,
+"ORGANIZATION_NOT_IN_ALL_FEATURES_MODE" => ConstraintViolationExceptionReason::OrganizationNotInAllFeaturesMode,
+"CREATE_ORGANIZATION_IN_BILLING_MODE_UNSUPPORTED_REGION" => ConstraintViolationExceptionReason::CreateOrganizationInBillingModeUnsupportedRegion,
+"EMAIL_VERIFICATION_CODE_EXPIRED" => ConstraintViolationExceptionReason::EmailVerificationCodeExpired,
+"WAIT_PERIOD_ACTIVE" => ConstraintViolationExceptionReason::WaitPeriodActive,
+"MAX_TAG_LIMIT_EXCEEDED" => ConstraintViolationExceptionReason::MaxTagLimitExceeded,
+"TAG_POLICY_VIOLATION" => ConstraintViolationExceptionReason::TagPolicyViolation,
+"MAX_DELEGATED_ADMINISTRATORS_FOR_SERVICE_LIMIT_EXCEEDED" => ConstraintViolationExceptionReason::MaxDelegatedAdministratorsForServiceLimitExceeded,
+"CANNOT_REGISTER_MASTER_AS_DELEGATED_ADMINISTRATOR" => ConstraintViolationExceptionReason::CannotRegisterMasterAsDelegatedAdministrator,
+"CANNOT_REMOVE_DELEGATED_ADMINISTRATOR_FROM_ORG" => ConstraintViolationExceptionReason::CannotRemoveDelegatedAdministratorFromOrg,
+"DELEGATED_ADMINISTRATOR_EXISTS_FOR_THIS_SERVICE" => ConstraintViolationExceptionReason::DelegatedAdministratorExistsForThisService,
+"MASTER_ACCOUNT_MISSING_BUSINESS_LICENSE" => ConstraintViolationExceptionReason::MasterAccountMissingBusinessLicense,
+"CANNOT_CLOSE_MANAGEMENT_ACCOUNT" => ConstraintViolationExceptionReason::CannotCloseManagementAccount,
+"CLOSE_ACCOUNT_QUOTA_EXCEEDED" => ConstraintViolationExceptionReason::CloseAccountQuotaExceeded,
+"CLOSE_ACCOUNT_REQUESTS_LIMIT_EXCEEDED" => ConstraintViolationExceptionReason::CloseAccountRequestsLimitExceeded,
+"SERVICE_ACCESS_NOT_ENABLED" => ConstraintViolationExceptionReason::ServiceAccessNotEnabled,
+"INVALID_PAYMENT_INSTRUMENT" => ConstraintViolationExceptionReason::InvalidPaymentInstrument,
+"ACCOUNT_CREATION_NOT_COMPLETE" => ConstraintViolationExceptionReason::AccountCreationNotComplete,
+                    _ => ConstraintViolationExceptionReason::Unknown(value),
+                }
+    }
+}
+
+impl ::std::str::FromStr for ConstraintViolationExceptionReason {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ConstraintViolationExceptionReason, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ConstraintViolationExceptionReason {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ConstraintViolationExceptionReason {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ConstraintViolationExceptionReason {
+    fn deserialize<D>(deserializer: D) -> Result<ConstraintViolationExceptionReason, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CreateAccountFailureReason {
+    /// `ACCOUNT_LIMIT_EXCEEDED`
+    AccountLimitExceeded,
+    /// `EMAIL_ALREADY_EXISTS`
+    EmailAlreadyExists,
+    /// `INVALID_ADDRESS`
+    InvalidAddress,
+    /// `INVALID_EMAIL`
+    InvalidEmail,
+    /// `CONCURRENT_ACCOUNT_MODIFICATION`
+    ConcurrentAccountModification,
+    /// `INTERNAL_FAILURE`
+    InternalFailure,
+    /// `GOVCLOUD_ACCOUNT_ALREADY_EXISTS`
+    GovcloudAccountAlreadyExists,
+    /// `MISSING_BUSINESS_VALIDATION`
+    MissingBusinessValidation,
+    /// `FAILED_BUSINESS_VALIDATION`
+    FailedBusinessValidation,
+    /// `PENDING_BUSINESS_VALIDATION`
+    PendingBusinessValidation,
+    /// `INVALID_IDENTITY_FOR_BUSINESS_VALIDATION`
+    InvalidIdentityForBusinessValidation,
+    /// `UNKNOWN_BUSINESS_VALIDATION`
+    UnknownBusinessValidation,
+    /// `MISSING_PAYMENT_INSTRUMENT`
+    MissingPaymentInstrument,
+    /// `INVALID_PAYMENT_INSTRUMENT`
+    InvalidPaymentInstrument,
+    /// `UPDATE_EXISTING_RESOURCE_POLICY_WITH_TAGS_NOT_SUPPORTED`
+    UpdateExistingResourcePolicyWithTagsNotSupported,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CreateAccountFailureReason {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CreateAccountFailureReason::AccountLimitExceeded => "ACCOUNT_LIMIT_EXCEEDED",
+            CreateAccountFailureReason::EmailAlreadyExists => "EMAIL_ALREADY_EXISTS",
+            CreateAccountFailureReason::InvalidAddress => "INVALID_ADDRESS",
+            CreateAccountFailureReason::InvalidEmail => "INVALID_EMAIL",
+            CreateAccountFailureReason::ConcurrentAccountModification => {
+                "CONCURRENT_ACCOUNT_MODIFICATION"
+            }
+            CreateAccountFailureReason::InternalFailure => "INTERNAL_FAILURE",
+            CreateAccountFailureReason::GovcloudAccountAlreadyExists => {
+                "GOVCLOUD_ACCOUNT_ALREADY_EXISTS"
+            }
+            CreateAccountFailureReason::MissingBusinessValidation => "MISSING_BUSINESS_VALIDATION",
+            CreateAccountFailureReason::FailedBusinessValidation => "FAILED_BUSINESS_VALIDATION",
+            CreateAccountFailureReason::PendingBusinessValidation => "PENDING_BUSINESS_VALIDATION",
+            CreateAccountFailureReason::InvalidIdentityForBusinessValidation => {
+                "INVALID_IDENTITY_FOR_BUSINESS_VALIDATION"
+            }
+            CreateAccountFailureReason::UnknownBusinessValidation => "UNKNOWN_BUSINESS_VALIDATION",
+            CreateAccountFailureReason::MissingPaymentInstrument => "MISSING_PAYMENT_INSTRUMENT",
+            CreateAccountFailureReason::InvalidPaymentInstrument => "INVALID_PAYMENT_INSTRUMENT",
+            CreateAccountFailureReason::UpdateExistingResourcePolicyWithTagsNotSupported => {
+                "UPDATE_EXISTING_RESOURCE_POLICY_WITH_TAGS_NOT_SUPPORTED"
+            }
+            CreateAccountFailureReason::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CreateAccountFailureReason {
+    fn default() -> CreateAccountFailureReason {
+        CreateAccountFailureReason::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CreateAccountFailureReason {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CreateAccountFailureReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CreateAccountFailureReason {
+    fn from(value: &'a str) -> CreateAccountFailureReason {
+        match value {
+            "ACCOUNT_LIMIT_EXCEEDED" => CreateAccountFailureReason::AccountLimitExceeded,
+            "EMAIL_ALREADY_EXISTS" => CreateAccountFailureReason::EmailAlreadyExists,
+            "INVALID_ADDRESS" => CreateAccountFailureReason::InvalidAddress,
+            "INVALID_EMAIL" => CreateAccountFailureReason::InvalidEmail,
+            "CONCURRENT_ACCOUNT_MODIFICATION" => {
+                CreateAccountFailureReason::ConcurrentAccountModification
+            }
+            "INTERNAL_FAILURE" => CreateAccountFailureReason::InternalFailure,
+            "GOVCLOUD_ACCOUNT_ALREADY_EXISTS" => {
+                CreateAccountFailureReason::GovcloudAccountAlreadyExists
+            }
+            "MISSING_BUSINESS_VALIDATION" => CreateAccountFailureReason::MissingBusinessValidation,
+            "FAILED_BUSINESS_VALIDATION" => CreateAccountFailureReason::FailedBusinessValidation,
+            "PENDING_BUSINESS_VALIDATION" => CreateAccountFailureReason::PendingBusinessValidation,
+            "INVALID_IDENTITY_FOR_BUSINESS_VALIDATION" => {
+                CreateAccountFailureReason::InvalidIdentityForBusinessValidation
+            }
+            "UNKNOWN_BUSINESS_VALIDATION" => CreateAccountFailureReason::UnknownBusinessValidation,
+            "MISSING_PAYMENT_INSTRUMENT" => CreateAccountFailureReason::MissingPaymentInstrument,
+            "INVALID_PAYMENT_INSTRUMENT" => CreateAccountFailureReason::InvalidPaymentInstrument,
+            "UPDATE_EXISTING_RESOURCE_POLICY_WITH_TAGS_NOT_SUPPORTED" => {
+                CreateAccountFailureReason::UpdateExistingResourcePolicyWithTagsNotSupported
+            }
+            _ => CreateAccountFailureReason::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CreateAccountFailureReason {
+    fn from(value: String) -> CreateAccountFailureReason {
+        match value.as_str() {
+            "ACCOUNT_LIMIT_EXCEEDED" => CreateAccountFailureReason::AccountLimitExceeded,
+            "EMAIL_ALREADY_EXISTS" => CreateAccountFailureReason::EmailAlreadyExists,
+            "INVALID_ADDRESS" => CreateAccountFailureReason::InvalidAddress,
+            "INVALID_EMAIL" => CreateAccountFailureReason::InvalidEmail,
+            "CONCURRENT_ACCOUNT_MODIFICATION" => {
+                CreateAccountFailureReason::ConcurrentAccountModification
+            }
+            "INTERNAL_FAILURE" => CreateAccountFailureReason::InternalFailure,
+            "GOVCLOUD_ACCOUNT_ALREADY_EXISTS" => {
+                CreateAccountFailureReason::GovcloudAccountAlreadyExists
+            }
+            "MISSING_BUSINESS_VALIDATION" => CreateAccountFailureReason::MissingBusinessValidation,
+            "FAILED_BUSINESS_VALIDATION" => CreateAccountFailureReason::FailedBusinessValidation,
+            "PENDING_BUSINESS_VALIDATION" => CreateAccountFailureReason::PendingBusinessValidation,
+            "INVALID_IDENTITY_FOR_BUSINESS_VALIDATION" => {
+                CreateAccountFailureReason::InvalidIdentityForBusinessValidation
+            }
+            "UNKNOWN_BUSINESS_VALIDATION" => CreateAccountFailureReason::UnknownBusinessValidation,
+            "MISSING_PAYMENT_INSTRUMENT" => CreateAccountFailureReason::MissingPaymentInstrument,
+            "INVALID_PAYMENT_INSTRUMENT" => CreateAccountFailureReason::InvalidPaymentInstrument,
+            "UPDATE_EXISTING_RESOURCE_POLICY_WITH_TAGS_NOT_SUPPORTED" => {
+                CreateAccountFailureReason::UpdateExistingResourcePolicyWithTagsNotSupported
+            }
+            _ => CreateAccountFailureReason::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CreateAccountFailureReason {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CreateAccountFailureReason, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CreateAccountFailureReason {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CreateAccountFailureReason {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CreateAccountFailureReason {
+    fn deserialize<D>(deserializer: D) -> Result<CreateAccountFailureReason, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct CreateAccountRequest {
+    /// <p>The friendly name of the member account.</p>
+    #[serde(rename = "AccountName")]
+    pub account_name: String,
+    /// <p>The email address of the owner to assign to the new member account. This email address must not already be associated with another AWS account. You must use a valid email address to complete account creation. You can't access the root user of the account or remove an account that was created with an invalid email address.</p>
+    #[serde(rename = "Email")]
+    pub email: String,
+    /// <p>If set to <code>ALLOW</code>, the new account enables IAM users to access account billing information <i>if</i> they have the required permissions. If set to <code>DENY</code>, only the root user of the new account can access account billing information. For more information, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/grantaccess.html#ControllingAccessWebsite-Activate">Activating Access to the Billing and Cost Management Console</a> in the <i>AWS Billing and Cost Management User Guide</i>.</p> <p>If you don't specify this parameter, the value defaults to <code>ALLOW</code>. This value allows IAM users and roles with the required permissions to access billing information for the new account.</p>
+    #[serde(rename = "IamUserAccessToBilling")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iam_user_access_to_billing: Option<IAMUserAccessToBilling>,
+    /// <p>(Optional)</p> <p>The name of an IAM role that AWS Organizations automatically preconfigures in the new member account. This role trusts the master account, allowing users in the master account to assume the role, as permitted by the master account administrator. The role has administrator permissions in the new member account.</p> <p>If you don't specify this parameter, the role name defaults to <code>OrganizationAccountAccessRole</code>.</p> <p>For more information about how to use this role to access the member account, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_accounts_access.html#orgs_manage_accounts_create-cross-account-role">Accessing and Administering the Member Accounts in Your Organization</a> in the <i>AWS Organizations User Guide</i>. Also see steps 2 and 3 in <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/tutorial_cross-account-with-roles.html">Tutorial: Delegate Access Across AWS Accounts Using IAM Roles</a> in the <i>IAM User Guide.</i> </p> <p>The <a href="http://wikipedia.org/wiki/regex">regex pattern</a> that is used to validate this parameter. The pattern can include uppercase letters, lowercase letters, digits with no spaces, and any of the following characters: =,.@-</p>
+    #[serde(rename = "RoleName")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub role_name: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct CreateAccountResponse {
+    /// <p>A structure that contains details about the request to create an account. This response structure might not be fully populated when you first receive it because account creation is an asynchronous process. You can pass the returned <code>CreateAccountStatus</code> ID as a parameter to <a>DescribeCreateAccountStatus</a> to get status about the progress of the request at later times. You can also check the AWS CloudTrail log for the <code>CreateAccountResult</code> event. For more information, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_monitoring.html">Monitoring the Activity in Your Organization</a> in the <i>AWS Organizations User Guide</i>.</p>
+    #[serde(rename = "CreateAccountStatus")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub create_account_status: Option<CreateAccountStatus>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CreateAccountState {
+    /// `IN_PROGRESS`
+    InProgress,
+    /// `SUCCEEDED`
+    Succeeded,
+    /// `FAILED`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CreateAccountState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CreateAccountState::InProgress => "IN_PROGRESS",
+            CreateAccountState::Succeeded => "SUCCEEDED",
+            CreateAccountState::Failed => "FAILED",
+            CreateAccountState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CreateAccountState {
+    fn default() -> CreateAccountState {
+        CreateAccountState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CreateAccountState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CreateAccountState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CreateAccountState {
+    fn from(value: &'a str) -> CreateAccountState {
+        match value {
+            "IN_PROGRESS" => CreateAccountState::InProgress,
+            "SUCCEEDED" => CreateAccountState::Succeeded,
+            "FAILED" => CreateAccountState::Failed,
+            _ => CreateAccountState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CreateAccountState {
+    fn from(value: String) -> CreateAccountState {
+        match value.as_str() {
+            "IN_PROGRESS" => CreateAccountState::InProgress,
+            "SUCCEEDED" => CreateAccountState::Succeeded,
+            "FAILED" => CreateAccountState::Failed,
+            _ => CreateAccountState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CreateAccountState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CreateAccountState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CreateAccountState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CreateAccountState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CreateAccountState {
+    fn deserialize<D>(deserializer: D) -> Result<CreateAccountState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Contains the status about a <a>CreateAccount</a> or <a>CreateGovCloudAccount</a> request to create an AWS account or an AWS GovCloud (US) account in an organization.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct CreateAccountStatus {
+    /// <p>If the account was created successfully, the unique identifier (ID) of the new account.</p> <p>The <a href="http://wikipedia.org/wiki/regex">regex pattern</a> for an account ID string requires exactly 12 digits.</p>
+    #[serde(rename = "AccountId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_id: Option<String>,
+    /// <p>The account name given to the account when it was created.</p>
+    #[serde(rename = "AccountName")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_name: Option<String>,
+    /// <p>The date and time that the account was created and the request completed.</p>
+    #[serde(rename = "CompletedTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
+    /// <p><p>If the request failed, a description of the reason for the failure.</p> <ul> <li> <p>ACCOUNT<em>LIMIT</em>EXCEEDED: The account could not be created because you have reached the limit on the number of accounts in your organization.</p> </li> <li> <p>EMAIL<em>ALREADY</em>EXISTS: The account could not be created because another AWS account with that email address already exists.</p> </li> <li> <p>GOVCLOUD<em>ACCOUNT</em>ALREADY<em>EXISTS: The account in the AWS GovCloud (US) Region could not be created because this Region already includes an account with that email address.</p> </li> <li> <p>INVALID</em>ADDRESS: The account could not be created because the address you provided is not valid.</p> </li> <li> <p>INVALID<em>EMAIL: The account could not be created because the email address you provided is not valid.</p> </li> <li> <p>INTERNAL</em>FAILURE: The account could not be created because of an internal failure. Try again later. If the problem persists, contact AWS Support.</p> </li> </ul></p>
+    #[serde(rename = "FailureReason")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub failure_reason: Option<CreateAccountFailureReason>,
+    /// <p>If the account was created successfully, the unique identifier (ID) of the new account in the AWS GovCloud (US) Region.</p>
+    #[serde(rename = "GovCloudAccountId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gov_cloud_account_id: Option<String>,
+    /// <p>The unique identifier (ID) that references this request. You get this value from the response of the initial <a>CreateAccount</a> request to create the account.</p> <p>The <a href="http://wikipedia.org/wiki/regex">regex pattern</a> for a create account request ID string requires "car-" followed by from 8 to 32 lower-case letters or digits.</p>
+    #[serde(rename = "Id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// <p>The date and time that the request was made for the account creation.</p>
+    #[serde(rename = "RequestedTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requested_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
+    /// <p>The status of the request.</p>
+    #[serde(rename = "State")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<CreateAccountState>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct CreateGovCloudAccountRequest {
+    /// <p>The friendly name of the member account.</p>
+    #[serde(rename = "AccountName")]
+    pub account_name: String,
+    /// <p>The email address of the owner to assign to the new member account in the commercial Region. This email address must not already be associated with another AWS account. You must use a valid email address to complete account creation. You can't access the root user of the account or remove an account that was created with an invalid email address. Like all request parameters for <code>CreateGovCloudAccount</code>, the request for the email address for the AWS GovCloud (US) account originates from the commercial Region. It does not come from the AWS GovCloud (US) Region.</p>
+    #[serde(rename = "Email")]
+    pub email: String,
+    /// <p>If set to <code>ALLOW</code>, the new linked account in the commercial Region enables IAM users to access account billing information <i>if</i> they have the required permissions. If set to <code>DENY</code>, only the root user of the new account can access account billing information. For more information, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/grantaccess.html#ControllingAccessWebsite-Activate">Activating Access to the Billing and Cost Management Console</a> in the <i>AWS Billing and Cost Management User Guide.</i> </p> <p>If you don't specify this parameter, the value defaults to <code>ALLOW</code>, and IAM users and roles with the required permissions can access billing information for the new account.</p>
+    #[serde(rename = "IamUserAccessToBilling")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iam_user_access_to_billing: Option<IAMUserAccessToBilling>,
+    /// <p>(Optional)</p> <p>The name of an IAM role that AWS Organizations automatically preconfigures in the new member accounts in both the AWS GovCloud (US) Region and in the commercial Region. This role trusts the master account, allowing users in the master account to assume the role, as permitted by the master account administrator. The role has administrator permissions in the new member account.</p> <p>If you don't specify this parameter, the role name defaults to <code>OrganizationAccountAccessRole</code>.</p> <p>For more information about how to use this role to access the member account, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_accounts_access.html#orgs_manage_accounts_create-cross-account-role">Accessing and Administering the Member Accounts in Your Organization</a> in the <i>AWS Organizations User Guide</i>. See also steps 2 and 3 in <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/tutorial_cross-account-with-roles.html">Tutorial: Delegate Access Across AWS Accounts Using IAM Roles</a> in the <i>IAM User Guide.</i> </p> <p>The <a href="http://wikipedia.org/wiki/regex">regex pattern</a> that is used to validate this parameter. The pattern can include uppercase letters, lowercase letters, digits with no spaces, and any of the following characters: =,.@-</p>
+    #[serde(rename = "RoleName")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub role_name: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct CreateGovCloudAccountResponse {
+    #[serde(rename = "CreateAccountStatus")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub create_account_status: Option<CreateAccountStatus>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct CreateOrganizationRequest {
+    /// <p><p>Specifies the feature set supported by the new organization. Each feature set supports different levels of functionality.</p> <ul> <li> <p> <code>CONSOLIDATED<em>BILLING</code>: All member accounts have their bills consolidated to and paid by the master account. For more information, see &lt;a href=&quot;https://docs.aws.amazon.com/organizations/latest/userguide/orgs</em>getting-started<em>concepts.html#feature-set-cb-only&quot;&gt;Consolidated billing</a> in the <i>AWS Organizations User Guide.</i> </p> <p> The consolidated billing feature subset isn&#39;t available for organizations in the AWS GovCloud (US) Region.</p> </li> <li> <p> <code>ALL</code>: In addition to all the features that consolidated billing feature set supports, the master account can also apply any policy type to any member account in the organization. For more information, see &lt;a href=&quot;https://docs.aws.amazon.com/organizations/latest/userguide/orgs</em>getting-started_concepts.html#feature-set-all&quot;&gt;All features</a> in the <i>AWS Organizations User Guide.</i> </p> </li> </ul></p>
+    #[serde(rename = "FeatureSet")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub feature_set: Option<OrganizationFeatureSet>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct CreateOrganizationResponse {
+    /// <p>A structure that contains details about the newly created organization.</p>
     #[serde(rename = "Organization")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub organization: Option<Organization>,
@@ -262,7 +1297,7 @@ pub struct CreatePolicyRequest {
     pub name: String,
     /// <p>The type of policy to create.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: PolicyType,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -346,7 +1381,7 @@ pub struct DescribeCreateAccountStatusResponse {
 pub struct DescribeEffectivePolicyRequest {
     /// <p>The type of policy that you want information about.</p>
     #[serde(rename = "PolicyType")]
-    pub policy_type: String,
+    pub policy_type: EffectivePolicyType,
     /// <p>When you're signed in as the master account, specify the ID of the account that you want details about. Specifying an organization root or OU as the target is not supported. </p>
     #[serde(rename = "TargetId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -446,7 +1481,7 @@ pub struct DisableAWSServiceAccessRequest {
 pub struct DisablePolicyTypeRequest {
     /// <p>The policy type that you want to disable in this root.</p>
     #[serde(rename = "PolicyType")]
-    pub policy_type: String,
+    pub policy_type: PolicyType,
     /// <p>The unique identifier (ID) of the root in which you want to disable a policy type. You can get the ID from the <a>ListRoots</a> operation.</p> <p>The <a href="http://wikipedia.org/wiki/regex">regex pattern</a> for a root ID string requires "r-" followed by from 4 to 32 lowercase letters or digits.</p>
     #[serde(rename = "RootId")]
     pub root_id: String,
@@ -467,8 +1502,13 @@ pub struct DisablePolicyTypeResponse {
 pub struct EffectivePolicy {
     /// <p>The time of the last update to this policy.</p>
     #[serde(rename = "LastUpdatedTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_timestamp: Option<f64>,
+    pub last_updated_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The text content of the policy.</p>
     #[serde(rename = "PolicyContent")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -476,149 +1516,1101 @@ pub struct EffectivePolicy {
     /// <p>The policy type.</p>
     #[serde(rename = "PolicyType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub policy_type: Option<String>,
+    pub policy_type: Option<EffectivePolicyType>,
     /// <p>The account ID of the policy target. </p>
     #[serde(rename = "TargetId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub target_id: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct EnableAWSServiceAccessRequest {
-    /// <p>The service principal name of the AWS service for which you want to enable integration with your organization. This is typically in the form of a URL, such as <code> <i>service-abbreviation</i>.amazonaws.com</code>.</p>
-    #[serde(rename = "ServicePrincipal")]
-    pub service_principal: String,
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EffectivePolicyType {
+    /// `TAG_POLICY`
+    TagPolicy,
+    /// `BACKUP_POLICY`
+    BackupPolicy,
+    /// `AISERVICES_OPT_OUT_POLICY`
+    AiservicesOptOutPolicy,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EffectivePolicyType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EffectivePolicyType::TagPolicy => "TAG_POLICY",
+            EffectivePolicyType::BackupPolicy => "BACKUP_POLICY",
+            EffectivePolicyType::AiservicesOptOutPolicy => "AISERVICES_OPT_OUT_POLICY",
+            EffectivePolicyType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EffectivePolicyType {
+    fn default() -> EffectivePolicyType {
+        EffectivePolicyType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EffectivePolicyType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EffectivePolicyType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EffectivePolicyType {
+    fn from(value: &'a str) -> EffectivePolicyType {
+        match value {
+            "TAG_POLICY" => EffectivePolicyType::TagPolicy,
+            "BACKUP_POLICY" => EffectivePolicyType::BackupPolicy,
+            "AISERVICES_OPT_OUT_POLICY" => EffectivePolicyType::AiservicesOptOutPolicy,
+            _ => EffectivePolicyType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EffectivePolicyType {
+    fn from(value: String) -> EffectivePolicyType {
+        match value.as_str() {
+            "TAG_POLICY" => EffectivePolicyType::TagPolicy,
+            "BACKUP_POLICY" => EffectivePolicyType::BackupPolicy,
+            "AISERVICES_OPT_OUT_POLICY" => EffectivePolicyType::AiservicesOptOutPolicy,
+            _ => EffectivePolicyType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EffectivePolicyType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EffectivePolicyType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EffectivePolicyType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EffectivePolicyType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EffectivePolicyType {
+    fn deserialize<D>(deserializer: D) -> Result<EffectivePolicyType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct EnableAWSServiceAccessRequest {
+    /// <p>The service principal name of the AWS service for which you want to enable integration with your organization. This is typically in the form of a URL, such as <code> <i>service-abbreviation</i>.amazonaws.com</code>.</p>
+    #[serde(rename = "ServicePrincipal")]
+    pub service_principal: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct EnableAllFeaturesRequest {}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct EnableAllFeaturesResponse {
+    /// <p>A structure that contains details about the handshake created to support this request to enable all features in the organization.</p>
+    #[serde(rename = "Handshake")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub handshake: Option<Handshake>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct EnablePolicyTypeRequest {
+    /// <p>The policy type that you want to enable.</p>
+    #[serde(rename = "PolicyType")]
+    pub policy_type: PolicyType,
+    /// <p>The unique identifier (ID) of the root in which you want to enable a policy type. You can get the ID from the <a>ListRoots</a> operation.</p> <p>The <a href="http://wikipedia.org/wiki/regex">regex pattern</a> for a root ID string requires "r-" followed by from 4 to 32 lowercase letters or digits.</p>
+    #[serde(rename = "RootId")]
+    pub root_id: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct EnablePolicyTypeResponse {
+    /// <p>A structure that shows the root with the updated list of enabled policy types.</p>
+    #[serde(rename = "Root")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub root: Option<Root>,
+}
+
+/// <p>A structure that contains details of a service principal that is enabled to integrate with AWS Organizations.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct EnabledServicePrincipal {
+    /// <p>The date that the service principal was enabled for integration with AWS Organizations.</p>
+    #[serde(rename = "DateEnabled")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_enabled: Option<::chrono::DateTime<::chrono::Utc>>,
+    /// <p>The name of the service principal. This is typically in the form of a URL, such as: <code> <i>servicename</i>.amazonaws.com</code>.</p>
+    #[serde(rename = "ServicePrincipal")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service_principal: Option<String>,
+}
+
+/// <p>Contains information that must be exchanged to securely establish a relationship between two accounts (an <i>originator</i> and a <i>recipient</i>). For example, assume that a master account (the originator) invites another account (the recipient) to join its organization. In that case, the two accounts exchange information as a series of handshake requests and responses.</p> <p> <b>Note:</b> Handshakes that are CANCELED, ACCEPTED, or DECLINED show up in lists for only 30 days after entering that state. After that, they are deleted.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct Handshake {
+    /// <p><p>The type of handshake, indicating what action occurs when the recipient accepts the handshake. The following handshake types are supported:</p> <ul> <li> <p> <b>INVITE</b>: This type of handshake represents a request to join an organization. It is always sent from the master account to only non-member accounts.</p> </li> <li> <p> <b>ENABLE<em>ALL</em>FEATURES</b>: This type of handshake represents a request to enable all features in an organization. It is always sent from the master account to only <i>invited</i> member accounts. Created accounts do not receive this because those accounts were created by the organization&#39;s master account and approval is inferred.</p> </li> <li> <p> <b>APPROVE<em>ALL</em>FEATURES</b>: This type of handshake is sent from the Organizations service when all member accounts have approved the <code>ENABLE<em>ALL</em>FEATURES</code> invitation. It is sent only to the master account and signals the master that it can finalize the process to enable all features.</p> </li> </ul></p>
+    #[serde(rename = "Action")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<ActionType>,
+    /// <p>The Amazon Resource Name (ARN) of a handshake.</p> <p>For more information about ARNs in Organizations, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_permissions.html#orgs-permissions-arns">ARN Formats Supported by Organizations</a> in the <i>AWS Organizations User Guide</i>.</p>
+    #[serde(rename = "Arn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub arn: Option<String>,
+    /// <p>The date and time that the handshake expires. If the recipient of the handshake request fails to respond before the specified date and time, the handshake becomes inactive and is no longer valid.</p>
+    #[serde(rename = "ExpirationTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiration_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
+    /// <p>The unique identifier (ID) of a handshake. The originating account creates the ID when it initiates the handshake.</p> <p>The <a href="http://wikipedia.org/wiki/regex">regex pattern</a> for handshake ID string requires "h-" followed by from 8 to 32 lower-case letters or digits.</p>
+    #[serde(rename = "Id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// <p>Information about the two accounts that are participating in the handshake.</p>
+    #[serde(rename = "Parties")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parties: Option<Vec<HandshakeParty>>,
+    /// <p>The date and time that the handshake request was made.</p>
+    #[serde(rename = "RequestedTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requested_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
+    /// <p>Additional information that is needed to process the handshake.</p>
+    #[serde(rename = "Resources")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resources: Option<Vec<HandshakeResource>>,
+    /// <p><p>The current state of the handshake. Use the state to trace the flow of the handshake through the process from its creation to its acceptance. The meaning of each of the valid values is as follows:</p> <ul> <li> <p> <b>REQUESTED</b>: This handshake was sent to multiple recipients (applicable to only some handshake types) and not all recipients have responded yet. The request stays in this state until all recipients respond.</p> </li> <li> <p> <b>OPEN</b>: This handshake was sent to multiple recipients (applicable to only some policy types) and all recipients have responded, allowing the originator to complete the handshake action.</p> </li> <li> <p> <b>CANCELED</b>: This handshake is no longer active because it was canceled by the originating account.</p> </li> <li> <p> <b>ACCEPTED</b>: This handshake is complete because it has been accepted by the recipient.</p> </li> <li> <p> <b>DECLINED</b>: This handshake is no longer active because it was declined by the recipient account.</p> </li> <li> <p> <b>EXPIRED</b>: This handshake is no longer active because the originator did not receive a response of any kind from the recipient before the expiration time (15 days).</p> </li> </ul></p>
+    #[serde(rename = "State")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<HandshakeState>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum HandshakeConstraintViolationExceptionReason {
+    /// `ACCOUNT_NUMBER_LIMIT_EXCEEDED`
+    AccountNumberLimitExceeded,
+    /// `HANDSHAKE_RATE_LIMIT_EXCEEDED`
+    HandshakeRateLimitExceeded,
+    /// `ALREADY_IN_AN_ORGANIZATION`
+    AlreadyInAnOrganization,
+    /// `ORGANIZATION_ALREADY_HAS_ALL_FEATURES`
+    OrganizationAlreadyHasAllFeatures,
+    /// `ORGANIZATION_IS_ALREADY_PENDING_ALL_FEATURES_MIGRATION`
+    OrganizationIsAlreadyPendingAllFeaturesMigration,
+    /// `INVITE_DISABLED_DURING_ENABLE_ALL_FEATURES`
+    InviteDisabledDuringEnableAllFeatures,
+    /// `PAYMENT_INSTRUMENT_REQUIRED`
+    PaymentInstrumentRequired,
+    /// `ORGANIZATION_FROM_DIFFERENT_SELLER_OF_RECORD`
+    OrganizationFromDifferentSellerOfRecord,
+    /// `ORGANIZATION_MEMBERSHIP_CHANGE_RATE_LIMIT_EXCEEDED`
+    OrganizationMembershipChangeRateLimitExceeded,
+    /// `MANAGEMENT_ACCOUNT_EMAIL_NOT_VERIFIED`
+    ManagementAccountEmailNotVerified,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl HandshakeConstraintViolationExceptionReason {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+                    HandshakeConstraintViolationExceptionReason::AccountNumberLimitExceeded => "ACCOUNT_NUMBER_LIMIT_EXCEEDED",
+HandshakeConstraintViolationExceptionReason::HandshakeRateLimitExceeded => "HANDSHAKE_RATE_LIMIT_EXCEEDED",
+HandshakeConstraintViolationExceptionReason::AlreadyInAnOrganization => "ALREADY_IN_AN_ORGANIZATION",
+HandshakeConstraintViolationExceptionReason::OrganizationAlreadyHasAllFeatures => "ORGANIZATION_ALREADY_HAS_ALL_FEATURES",
+HandshakeConstraintViolationExceptionReason::OrganizationIsAlreadyPendingAllFeaturesMigration => "ORGANIZATION_IS_ALREADY_PENDING_ALL_FEATURES_MIGRATION",
+HandshakeConstraintViolationExceptionReason::InviteDisabledDuringEnableAllFeatures => "INVITE_DISABLED_DURING_ENABLE_ALL_FEATURES",
+HandshakeConstraintViolationExceptionReason::PaymentInstrumentRequired => "PAYMENT_INSTRUMENT_REQUIRED",
+HandshakeConstraintViolationExceptionReason::OrganizationFromDifferentSellerOfRecord => "ORGANIZATION_FROM_DIFFERENT_SELLER_OF_RECORD",
+HandshakeConstraintViolationExceptionReason::OrganizationMembershipChangeRateLimitExceeded => "ORGANIZATION_MEMBERSHIP_CHANGE_RATE_LIMIT_EXCEEDED",
+HandshakeConstraintViolationExceptionReason::ManagementAccountEmailNotVerified => "MANAGEMENT_ACCOUNT_EMAIL_NOT_VERIFIED",
+                    HandshakeConstraintViolationExceptionReason::Unknown(ref value) => value,
+                }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for HandshakeConstraintViolationExceptionReason {
+    fn default() -> HandshakeConstraintViolationExceptionReason {
+        HandshakeConstraintViolationExceptionReason::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for HandshakeConstraintViolationExceptionReason {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for HandshakeConstraintViolationExceptionReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for HandshakeConstraintViolationExceptionReason {
+    fn from(value: &'a str) -> HandshakeConstraintViolationExceptionReason {
+        match value {
+                    "ACCOUNT_NUMBER_LIMIT_EXCEEDED" => HandshakeConstraintViolationExceptionReason::AccountNumberLimitExceeded,
+"HANDSHAKE_RATE_LIMIT_EXCEEDED" => HandshakeConstraintViolationExceptionReason::HandshakeRateLimitExceeded,
+"ALREADY_IN_AN_ORGANIZATION" => HandshakeConstraintViolationExceptionReason::AlreadyInAnOrganization,
+"ORGANIZATION_ALREADY_HAS_ALL_FEATURES" => HandshakeConstraintViolationExceptionReason::OrganizationAlreadyHasAllFeatures,
+"ORGANIZATION_IS_ALREADY_PENDING_ALL_FEATURES_MIGRATION" => HandshakeConstraintViolationExceptionReason::OrganizationIsAlreadyPendingAllFeaturesMigration,
+"INVITE_DISABLED_DURING_ENABLE_ALL_FEATURES" => HandshakeConstraintViolationExceptionReason::InviteDisabledDuringEnableAllFeatures,
+"PAYMENT_INSTRUMENT_REQUIRED" => HandshakeConstraintViolationExceptionReason::PaymentInstrumentRequired,
+"ORGANIZATION_FROM_DIFFERENT_SELLER_OF_RECORD" => HandshakeConstraintViolationExceptionReason::OrganizationFromDifferentSellerOfRecord,
+"ORGANIZATION_MEMBERSHIP_CHANGE_RATE_LIMIT_EXCEEDED" => HandshakeConstraintViolationExceptionReason::OrganizationMembershipChangeRateLimitExceeded,
+"MANAGEMENT_ACCOUNT_EMAIL_NOT_VERIFIED" => HandshakeConstraintViolationExceptionReason::ManagementAccountEmailNotVerified,
+                    _ => HandshakeConstraintViolationExceptionReason::Unknown(value.to_owned()),
+                }
+    }
+}
+
+impl From<String> for HandshakeConstraintViolationExceptionReason {
+    fn from(value: String) -> HandshakeConstraintViolationExceptionReason {
+        match value.as_str() {
+                    "ACCOUNT_NUMBER_LIMIT_EXCEEDED" => HandshakeConstraintViolationExceptionReason::AccountNumberLimitExceeded,
+"HANDSHAKE_RATE_LIMIT_EXCEEDED" => HandshakeConstraintViolationExceptionReason::HandshakeRateLimitExceeded,
+"ALREADY_IN_AN_ORGANIZATION" => HandshakeConstraintViolationExceptionReason::AlreadyInAnOrganization,
+"ORGANIZATION_ALREADY_HAS_ALL_FEATURES" => HandshakeConstraintViolationExceptionReason::OrganizationAlreadyHasAllFeatures,
+"ORGANIZATION_IS_ALREADY_PENDING_ALL_FEATURES_MIGRATION" => HandshakeConstraintViolationExceptionReason::OrganizationIsAlreadyPendingAllFeaturesMigration,
+"INVITE_DISABLED_DURING_ENABLE_ALL_FEATURES" => HandshakeConstraintViolationExceptionReason::InviteDisabledDuringEnableAllFeatures,
+"PAYMENT_INSTRUMENT_REQUIRED" => HandshakeConstraintViolationExceptionReason::PaymentInstrumentRequired,
+"ORGANIZATION_FROM_DIFFERENT_SELLER_OF_RECORD" => HandshakeConstraintViolationExceptionReason::OrganizationFromDifferentSellerOfRecord,
+"ORGANIZATION_MEMBERSHIP_CHANGE_RATE_LIMIT_EXCEEDED" => HandshakeConstraintViolationExceptionReason::OrganizationMembershipChangeRateLimitExceeded,
+"MANAGEMENT_ACCOUNT_EMAIL_NOT_VERIFIED" => HandshakeConstraintViolationExceptionReason::ManagementAccountEmailNotVerified,
+                    _ => HandshakeConstraintViolationExceptionReason::Unknown(value),
+                }
+    }
+}
+
+impl ::std::str::FromStr for HandshakeConstraintViolationExceptionReason {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<HandshakeConstraintViolationExceptionReason, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for HandshakeConstraintViolationExceptionReason {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for HandshakeConstraintViolationExceptionReason {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for HandshakeConstraintViolationExceptionReason {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> Result<HandshakeConstraintViolationExceptionReason, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Specifies the criteria that are used to select the handshakes for the operation.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct HandshakeFilter {
+    /// <p>Specifies the type of handshake action.</p> <p>If you specify <code>ActionType</code>, you cannot also specify <code>ParentHandshakeId</code>.</p>
+    #[serde(rename = "ActionType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_type: Option<ActionType>,
+    /// <p>Specifies the parent handshake. Only used for handshake types that are a child of another type.</p> <p>If you specify <code>ParentHandshakeId</code>, you cannot also specify <code>ActionType</code>.</p> <p>The <a href="http://wikipedia.org/wiki/regex">regex pattern</a> for handshake ID string requires "h-" followed by from 8 to 32 lower-case letters or digits.</p>
+    #[serde(rename = "ParentHandshakeId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_handshake_id: Option<String>,
+}
+
+/// <p>Identifies a participant in a handshake.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HandshakeParty {
+    /// <p>The unique identifier (ID) for the party.</p> <p>The <a href="http://wikipedia.org/wiki/regex">regex pattern</a> for handshake ID string requires "h-" followed by from 8 to 32 lower-case letters or digits.</p>
+    #[serde(rename = "Id")]
+    pub id: String,
+    /// <p>The type of party.</p>
+    #[serde(rename = "Type")]
+    pub type_: HandshakePartyType,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum HandshakePartyType {
+    /// `ACCOUNT`
+    Account,
+    /// `ORGANIZATION`
+    Organization,
+    /// `EMAIL`
+    Email,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl HandshakePartyType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            HandshakePartyType::Account => "ACCOUNT",
+            HandshakePartyType::Organization => "ORGANIZATION",
+            HandshakePartyType::Email => "EMAIL",
+            HandshakePartyType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for HandshakePartyType {
+    fn default() -> HandshakePartyType {
+        HandshakePartyType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for HandshakePartyType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for HandshakePartyType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for HandshakePartyType {
+    fn from(value: &'a str) -> HandshakePartyType {
+        match value {
+            "ACCOUNT" => HandshakePartyType::Account,
+            "ORGANIZATION" => HandshakePartyType::Organization,
+            "EMAIL" => HandshakePartyType::Email,
+            _ => HandshakePartyType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for HandshakePartyType {
+    fn from(value: String) -> HandshakePartyType {
+        match value.as_str() {
+            "ACCOUNT" => HandshakePartyType::Account,
+            "ORGANIZATION" => HandshakePartyType::Organization,
+            "EMAIL" => HandshakePartyType::Email,
+            _ => HandshakePartyType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for HandshakePartyType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<HandshakePartyType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for HandshakePartyType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for HandshakePartyType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for HandshakePartyType {
+    fn deserialize<D>(deserializer: D) -> Result<HandshakePartyType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Contains additional data that is needed to process a handshake.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct HandshakeResource {
+    /// <p>When needed, contains an additional array of <code>HandshakeResource</code> objects.</p>
+    #[serde(rename = "Resources")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resources: Option<Vec<HandshakeResource>>,
+    /// <p><p>The type of information being passed, specifying how the value is to be interpreted by the other party:</p> <ul> <li> <p> <code>ACCOUNT</code> - Specifies an AWS account ID number.</p> </li> <li> <p> <code>ORGANIZATION</code> - Specifies an organization ID number.</p> </li> <li> <p> <code>EMAIL</code> - Specifies the email address that is associated with the account that receives the handshake. </p> </li> <li> <p> <code>OWNER<em>EMAIL</code> - Specifies the email address associated with the master account. Included as information about an organization. </p> </li> <li> <p> <code>OWNER</em>NAME</code> - Specifies the name associated with the master account. Included as information about an organization. </p> </li> <li> <p> <code>NOTES</code> - Additional text provided by the handshake initiator and intended for the recipient to read.</p> </li> </ul></p>
+    #[serde(rename = "Type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_: Option<HandshakeResourceType>,
+    /// <p>The information that is passed to the other party in the handshake. The format of the value string must match the requirements of the specified type.</p>
+    #[serde(rename = "Value")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum HandshakeResourceType {
+    /// `ACCOUNT`
+    Account,
+    /// `ORGANIZATION`
+    Organization,
+    /// `ORGANIZATION_FEATURE_SET`
+    OrganizationFeatureSet,
+    /// `EMAIL`
+    Email,
+    /// `MASTER_EMAIL`
+    MasterEmail,
+    /// `MASTER_NAME`
+    MasterName,
+    /// `NOTES`
+    Notes,
+    /// `PARENT_HANDSHAKE`
+    ParentHandshake,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl HandshakeResourceType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            HandshakeResourceType::Account => "ACCOUNT",
+            HandshakeResourceType::Organization => "ORGANIZATION",
+            HandshakeResourceType::OrganizationFeatureSet => "ORGANIZATION_FEATURE_SET",
+            HandshakeResourceType::Email => "EMAIL",
+            HandshakeResourceType::MasterEmail => "MASTER_EMAIL",
+            HandshakeResourceType::MasterName => "MASTER_NAME",
+            HandshakeResourceType::Notes => "NOTES",
+            HandshakeResourceType::ParentHandshake => "PARENT_HANDSHAKE",
+            HandshakeResourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for HandshakeResourceType {
+    fn default() -> HandshakeResourceType {
+        HandshakeResourceType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for HandshakeResourceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for HandshakeResourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for HandshakeResourceType {
+    fn from(value: &'a str) -> HandshakeResourceType {
+        match value {
+            "ACCOUNT" => HandshakeResourceType::Account,
+            "ORGANIZATION" => HandshakeResourceType::Organization,
+            "ORGANIZATION_FEATURE_SET" => HandshakeResourceType::OrganizationFeatureSet,
+            "EMAIL" => HandshakeResourceType::Email,
+            "MASTER_EMAIL" => HandshakeResourceType::MasterEmail,
+            "MASTER_NAME" => HandshakeResourceType::MasterName,
+            "NOTES" => HandshakeResourceType::Notes,
+            "PARENT_HANDSHAKE" => HandshakeResourceType::ParentHandshake,
+            _ => HandshakeResourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for HandshakeResourceType {
+    fn from(value: String) -> HandshakeResourceType {
+        match value.as_str() {
+            "ACCOUNT" => HandshakeResourceType::Account,
+            "ORGANIZATION" => HandshakeResourceType::Organization,
+            "ORGANIZATION_FEATURE_SET" => HandshakeResourceType::OrganizationFeatureSet,
+            "EMAIL" => HandshakeResourceType::Email,
+            "MASTER_EMAIL" => HandshakeResourceType::MasterEmail,
+            "MASTER_NAME" => HandshakeResourceType::MasterName,
+            "NOTES" => HandshakeResourceType::Notes,
+            "PARENT_HANDSHAKE" => HandshakeResourceType::ParentHandshake,
+            _ => HandshakeResourceType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for HandshakeResourceType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<HandshakeResourceType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for HandshakeResourceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for HandshakeResourceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for HandshakeResourceType {
+    fn deserialize<D>(deserializer: D) -> Result<HandshakeResourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum HandshakeState {
+    /// `REQUESTED`
+    Requested,
+    /// `OPEN`
+    Open,
+    /// `CANCELED`
+    Canceled,
+    /// `ACCEPTED`
+    Accepted,
+    /// `DECLINED`
+    Declined,
+    /// `EXPIRED`
+    Expired,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl HandshakeState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            HandshakeState::Requested => "REQUESTED",
+            HandshakeState::Open => "OPEN",
+            HandshakeState::Canceled => "CANCELED",
+            HandshakeState::Accepted => "ACCEPTED",
+            HandshakeState::Declined => "DECLINED",
+            HandshakeState::Expired => "EXPIRED",
+            HandshakeState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for HandshakeState {
+    fn default() -> HandshakeState {
+        HandshakeState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for HandshakeState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for HandshakeState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for HandshakeState {
+    fn from(value: &'a str) -> HandshakeState {
+        match value {
+            "REQUESTED" => HandshakeState::Requested,
+            "OPEN" => HandshakeState::Open,
+            "CANCELED" => HandshakeState::Canceled,
+            "ACCEPTED" => HandshakeState::Accepted,
+            "DECLINED" => HandshakeState::Declined,
+            "EXPIRED" => HandshakeState::Expired,
+            _ => HandshakeState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for HandshakeState {
+    fn from(value: String) -> HandshakeState {
+        match value.as_str() {
+            "REQUESTED" => HandshakeState::Requested,
+            "OPEN" => HandshakeState::Open,
+            "CANCELED" => HandshakeState::Canceled,
+            "ACCEPTED" => HandshakeState::Accepted,
+            "DECLINED" => HandshakeState::Declined,
+            "EXPIRED" => HandshakeState::Expired,
+            _ => HandshakeState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for HandshakeState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<HandshakeState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for HandshakeState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for HandshakeState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for HandshakeState {
+    fn deserialize<D>(deserializer: D) -> Result<HandshakeState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum IAMUserAccessToBilling {
+    /// `ALLOW`
+    Allow,
+    /// `DENY`
+    Deny,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl IAMUserAccessToBilling {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            IAMUserAccessToBilling::Allow => "ALLOW",
+            IAMUserAccessToBilling::Deny => "DENY",
+            IAMUserAccessToBilling::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for IAMUserAccessToBilling {
+    fn default() -> IAMUserAccessToBilling {
+        IAMUserAccessToBilling::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for IAMUserAccessToBilling {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for IAMUserAccessToBilling {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for IAMUserAccessToBilling {
+    fn from(value: &'a str) -> IAMUserAccessToBilling {
+        match value {
+            "ALLOW" => IAMUserAccessToBilling::Allow,
+            "DENY" => IAMUserAccessToBilling::Deny,
+            _ => IAMUserAccessToBilling::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for IAMUserAccessToBilling {
+    fn from(value: String) -> IAMUserAccessToBilling {
+        match value.as_str() {
+            "ALLOW" => IAMUserAccessToBilling::Allow,
+            "DENY" => IAMUserAccessToBilling::Deny,
+            _ => IAMUserAccessToBilling::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for IAMUserAccessToBilling {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<IAMUserAccessToBilling, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for IAMUserAccessToBilling {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for IAMUserAccessToBilling {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct EnableAllFeaturesRequest {}
+impl<'de> ::serde::Deserialize<'de> for IAMUserAccessToBilling {
+    fn deserialize<D>(deserializer: D) -> Result<IAMUserAccessToBilling, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum InvalidInputExceptionReason {
+    /// `INVALID_PARTY_TYPE_TARGET`
+    InvalidPartyTypeTarget,
+    /// `INVALID_SYNTAX_ORGANIZATION_ARN`
+    InvalidSyntaxOrganizationArn,
+    /// `INVALID_SYNTAX_POLICY_ID`
+    InvalidSyntaxPolicyId,
+    /// `INVALID_ENUM`
+    InvalidEnum,
+    /// `INVALID_ENUM_POLICY_TYPE`
+    InvalidEnumPolicyType,
+    /// `INVALID_LIST_MEMBER`
+    InvalidListMember,
+    /// `MAX_LENGTH_EXCEEDED`
+    MaxLengthExceeded,
+    /// `MAX_VALUE_EXCEEDED`
+    MaxValueExceeded,
+    /// `MIN_LENGTH_EXCEEDED`
+    MinLengthExceeded,
+    /// `MIN_VALUE_EXCEEDED`
+    MinValueExceeded,
+    /// `IMMUTABLE_POLICY`
+    ImmutablePolicy,
+    /// `INVALID_PATTERN`
+    InvalidPattern,
+    /// `INVALID_PATTERN_TARGET_ID`
+    InvalidPatternTargetId,
+    /// `INPUT_REQUIRED`
+    InputRequired,
+    /// `INVALID_NEXT_TOKEN`
+    InvalidNextToken,
+    /// `MAX_LIMIT_EXCEEDED_FILTER`
+    MaxLimitExceededFilter,
+    /// `MOVING_ACCOUNT_BETWEEN_DIFFERENT_ROOTS`
+    MovingAccountBetweenDifferentRoots,
+    /// `INVALID_FULL_NAME_TARGET`
+    InvalidFullNameTarget,
+    /// `UNRECOGNIZED_SERVICE_PRINCIPAL`
+    UnrecognizedServicePrincipal,
+    /// `INVALID_ROLE_NAME`
+    InvalidRoleName,
+    /// `INVALID_SYSTEM_TAGS_PARAMETER`
+    InvalidSystemTagsParameter,
+    /// `DUPLICATE_TAG_KEY`
+    DuplicateTagKey,
+    /// `TARGET_NOT_SUPPORTED`
+    TargetNotSupported,
+    /// `INVALID_EMAIL_ADDRESS_TARGET`
+    InvalidEmailAddressTarget,
+    /// `INVALID_RESOURCE_POLICY_JSON`
+    InvalidResourcePolicyJson,
+    /// `UNSUPPORTED_ACTION_IN_RESOURCE_POLICY`
+    UnsupportedActionInResourcePolicy,
+    /// `UNSUPPORTED_POLICY_TYPE_IN_RESOURCE_POLICY`
+    UnsupportedPolicyTypeInResourcePolicy,
+    /// `UNSUPPORTED_RESOURCE_IN_RESOURCE_POLICY`
+    UnsupportedResourceInResourcePolicy,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl InvalidInputExceptionReason {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InvalidInputExceptionReason::InvalidPartyTypeTarget => "INVALID_PARTY_TYPE_TARGET",
+            InvalidInputExceptionReason::InvalidSyntaxOrganizationArn => {
+                "INVALID_SYNTAX_ORGANIZATION_ARN"
+            }
+            InvalidInputExceptionReason::InvalidSyntaxPolicyId => "INVALID_SYNTAX_POLICY_ID",
+            InvalidInputExceptionReason::InvalidEnum => "INVALID_ENUM",
+            InvalidInputExceptionReason::InvalidEnumPolicyType => "INVALID_ENUM_POLICY_TYPE",
+            InvalidInputExceptionReason::InvalidListMember => "INVALID_LIST_MEMBER",
+            InvalidInputExceptionReason::MaxLengthExceeded => "MAX_LENGTH_EXCEEDED",
+            InvalidInputExceptionReason::MaxValueExceeded => "MAX_VALUE_EXCEEDED",
+            InvalidInputExceptionReason::MinLengthExceeded => "MIN_LENGTH_EXCEEDED",
+            InvalidInputExceptionReason::MinValueExceeded => "MIN_VALUE_EXCEEDED",
+            InvalidInputExceptionReason::ImmutablePolicy => "IMMUTABLE_POLICY",
+            InvalidInputExceptionReason::InvalidPattern => "INVALID_PATTERN",
+            InvalidInputExceptionReason::InvalidPatternTargetId => "INVALID_PATTERN_TARGET_ID",
+            InvalidInputExceptionReason::InputRequired => "INPUT_REQUIRED",
+            InvalidInputExceptionReason::InvalidNextToken => "INVALID_NEXT_TOKEN",
+            InvalidInputExceptionReason::MaxLimitExceededFilter => "MAX_LIMIT_EXCEEDED_FILTER",
+            InvalidInputExceptionReason::MovingAccountBetweenDifferentRoots => {
+                "MOVING_ACCOUNT_BETWEEN_DIFFERENT_ROOTS"
+            }
+            InvalidInputExceptionReason::InvalidFullNameTarget => "INVALID_FULL_NAME_TARGET",
+            InvalidInputExceptionReason::UnrecognizedServicePrincipal => {
+                "UNRECOGNIZED_SERVICE_PRINCIPAL"
+            }
+            InvalidInputExceptionReason::InvalidRoleName => "INVALID_ROLE_NAME",
+            InvalidInputExceptionReason::InvalidSystemTagsParameter => {
+                "INVALID_SYSTEM_TAGS_PARAMETER"
+            }
+            InvalidInputExceptionReason::DuplicateTagKey => "DUPLICATE_TAG_KEY",
+            InvalidInputExceptionReason::TargetNotSupported => "TARGET_NOT_SUPPORTED",
+            InvalidInputExceptionReason::InvalidEmailAddressTarget => {
+                "INVALID_EMAIL_ADDRESS_TARGET"
+            }
+            InvalidInputExceptionReason::InvalidResourcePolicyJson => {
+                "INVALID_RESOURCE_POLICY_JSON"
+            }
+            InvalidInputExceptionReason::UnsupportedActionInResourcePolicy => {
+                "UNSUPPORTED_ACTION_IN_RESOURCE_POLICY"
+            }
+            InvalidInputExceptionReason::UnsupportedPolicyTypeInResourcePolicy => {
+                "UNSUPPORTED_POLICY_TYPE_IN_RESOURCE_POLICY"
+            }
+            InvalidInputExceptionReason::UnsupportedResourceInResourcePolicy => {
+                "UNSUPPORTED_RESOURCE_IN_RESOURCE_POLICY"
+            }
+            InvalidInputExceptionReason::Unknown(ref value) => value,
+        }
+    }
+}
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct EnableAllFeaturesResponse {
-    /// <p>A structure that contains details about the handshake created to support this request to enable all features in the organization.</p>
-    #[serde(rename = "Handshake")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub handshake: Option<Handshake>,
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for InvalidInputExceptionReason {
+    fn default() -> InvalidInputExceptionReason {
+        InvalidInputExceptionReason::Unknown(String::new())
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct EnablePolicyTypeRequest {
-    /// <p>The policy type that you want to enable.</p>
-    #[serde(rename = "PolicyType")]
-    pub policy_type: String,
-    /// <p>The unique identifier (ID) of the root in which you want to enable a policy type. You can get the ID from the <a>ListRoots</a> operation.</p> <p>The <a href="http://wikipedia.org/wiki/regex">regex pattern</a> for a root ID string requires "r-" followed by from 4 to 32 lowercase letters or digits.</p>
-    #[serde(rename = "RootId")]
-    pub root_id: String,
+impl AsRef<str> for InvalidInputExceptionReason {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct EnablePolicyTypeResponse {
-    /// <p>A structure that shows the root with the updated list of enabled policy types.</p>
-    #[serde(rename = "Root")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub root: Option<Root>,
+impl fmt::Display for InvalidInputExceptionReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
-/// <p>A structure that contains details of a service principal that is enabled to integrate with AWS Organizations.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct EnabledServicePrincipal {
-    /// <p>The date that the service principal was enabled for integration with AWS Organizations.</p>
-    #[serde(rename = "DateEnabled")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub date_enabled: Option<f64>,
-    /// <p>The name of the service principal. This is typically in the form of a URL, such as: <code> <i>servicename</i>.amazonaws.com</code>.</p>
-    #[serde(rename = "ServicePrincipal")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub service_principal: Option<String>,
+impl<'a> From<&'a str> for InvalidInputExceptionReason {
+    fn from(value: &'a str) -> InvalidInputExceptionReason {
+        match value {
+            "INVALID_PARTY_TYPE_TARGET" => InvalidInputExceptionReason::InvalidPartyTypeTarget,
+            "INVALID_SYNTAX_ORGANIZATION_ARN" => {
+                InvalidInputExceptionReason::InvalidSyntaxOrganizationArn
+            }
+            "INVALID_SYNTAX_POLICY_ID" => InvalidInputExceptionReason::InvalidSyntaxPolicyId,
+            "INVALID_ENUM" => InvalidInputExceptionReason::InvalidEnum,
+            "INVALID_ENUM_POLICY_TYPE" => InvalidInputExceptionReason::InvalidEnumPolicyType,
+            "INVALID_LIST_MEMBER" => InvalidInputExceptionReason::InvalidListMember,
+            "MAX_LENGTH_EXCEEDED" => InvalidInputExceptionReason::MaxLengthExceeded,
+            "MAX_VALUE_EXCEEDED" => InvalidInputExceptionReason::MaxValueExceeded,
+            "MIN_LENGTH_EXCEEDED" => InvalidInputExceptionReason::MinLengthExceeded,
+            "MIN_VALUE_EXCEEDED" => InvalidInputExceptionReason::MinValueExceeded,
+            "IMMUTABLE_POLICY" => InvalidInputExceptionReason::ImmutablePolicy,
+            "INVALID_PATTERN" => InvalidInputExceptionReason::InvalidPattern,
+            "INVALID_PATTERN_TARGET_ID" => InvalidInputExceptionReason::InvalidPatternTargetId,
+            "INPUT_REQUIRED" => InvalidInputExceptionReason::InputRequired,
+            "INVALID_NEXT_TOKEN" => InvalidInputExceptionReason::InvalidNextToken,
+            "MAX_LIMIT_EXCEEDED_FILTER" => InvalidInputExceptionReason::MaxLimitExceededFilter,
+            "MOVING_ACCOUNT_BETWEEN_DIFFERENT_ROOTS" => {
+                InvalidInputExceptionReason::MovingAccountBetweenDifferentRoots
+            }
+            "INVALID_FULL_NAME_TARGET" => InvalidInputExceptionReason::InvalidFullNameTarget,
+            "UNRECOGNIZED_SERVICE_PRINCIPAL" => {
+                InvalidInputExceptionReason::UnrecognizedServicePrincipal
+            }
+            "INVALID_ROLE_NAME" => InvalidInputExceptionReason::InvalidRoleName,
+            "INVALID_SYSTEM_TAGS_PARAMETER" => {
+                InvalidInputExceptionReason::InvalidSystemTagsParameter
+            }
+            "DUPLICATE_TAG_KEY" => InvalidInputExceptionReason::DuplicateTagKey,
+            "TARGET_NOT_SUPPORTED" => InvalidInputExceptionReason::TargetNotSupported,
+            "INVALID_EMAIL_ADDRESS_TARGET" => {
+                InvalidInputExceptionReason::InvalidEmailAddressTarget
+            }
+            "INVALID_RESOURCE_POLICY_JSON" => {
+                InvalidInputExceptionReason::InvalidResourcePolicyJson
+            }
+            "UNSUPPORTED_ACTION_IN_RESOURCE_POLICY" => {
+                InvalidInputExceptionReason::UnsupportedActionInResourcePolicy
+            }
+            "UNSUPPORTED_POLICY_TYPE_IN_RESOURCE_POLICY" => {
+                InvalidInputExceptionReason::UnsupportedPolicyTypeInResourcePolicy
+            }
+            "UNSUPPORTED_RESOURCE_IN_RESOURCE_POLICY" => {
+                InvalidInputExceptionReason::UnsupportedResourceInResourcePolicy
+            }
+            _ => InvalidInputExceptionReason::Unknown(value.to_owned()),
+        }
+    }
 }
 
-/// <p>Contains information that must be exchanged to securely establish a relationship between two accounts (an <i>originator</i> and a <i>recipient</i>). For example, assume that a master account (the originator) invites another account (the recipient) to join its organization. In that case, the two accounts exchange information as a series of handshake requests and responses.</p> <p> <b>Note:</b> Handshakes that are CANCELED, ACCEPTED, or DECLINED show up in lists for only 30 days after entering that state. After that, they are deleted.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct Handshake {
-    /// <p><p>The type of handshake, indicating what action occurs when the recipient accepts the handshake. The following handshake types are supported:</p> <ul> <li> <p> <b>INVITE</b>: This type of handshake represents a request to join an organization. It is always sent from the master account to only non-member accounts.</p> </li> <li> <p> <b>ENABLE<em>ALL</em>FEATURES</b>: This type of handshake represents a request to enable all features in an organization. It is always sent from the master account to only <i>invited</i> member accounts. Created accounts do not receive this because those accounts were created by the organization&#39;s master account and approval is inferred.</p> </li> <li> <p> <b>APPROVE<em>ALL</em>FEATURES</b>: This type of handshake is sent from the Organizations service when all member accounts have approved the <code>ENABLE<em>ALL</em>FEATURES</code> invitation. It is sent only to the master account and signals the master that it can finalize the process to enable all features.</p> </li> </ul></p>
-    #[serde(rename = "Action")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub action: Option<String>,
-    /// <p>The Amazon Resource Name (ARN) of a handshake.</p> <p>For more information about ARNs in Organizations, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_permissions.html#orgs-permissions-arns">ARN Formats Supported by Organizations</a> in the <i>AWS Organizations User Guide</i>.</p>
-    #[serde(rename = "Arn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub arn: Option<String>,
-    /// <p>The date and time that the handshake expires. If the recipient of the handshake request fails to respond before the specified date and time, the handshake becomes inactive and is no longer valid.</p>
-    #[serde(rename = "ExpirationTimestamp")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub expiration_timestamp: Option<f64>,
-    /// <p>The unique identifier (ID) of a handshake. The originating account creates the ID when it initiates the handshake.</p> <p>The <a href="http://wikipedia.org/wiki/regex">regex pattern</a> for handshake ID string requires "h-" followed by from 8 to 32 lower-case letters or digits.</p>
-    #[serde(rename = "Id")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
-    /// <p>Information about the two accounts that are participating in the handshake.</p>
-    #[serde(rename = "Parties")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub parties: Option<Vec<HandshakeParty>>,
-    /// <p>The date and time that the handshake request was made.</p>
-    #[serde(rename = "RequestedTimestamp")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub requested_timestamp: Option<f64>,
-    /// <p>Additional information that is needed to process the handshake.</p>
-    #[serde(rename = "Resources")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub resources: Option<Vec<HandshakeResource>>,
-    /// <p><p>The current state of the handshake. Use the state to trace the flow of the handshake through the process from its creation to its acceptance. The meaning of each of the valid values is as follows:</p> <ul> <li> <p> <b>REQUESTED</b>: This handshake was sent to multiple recipients (applicable to only some handshake types) and not all recipients have responded yet. The request stays in this state until all recipients respond.</p> </li> <li> <p> <b>OPEN</b>: This handshake was sent to multiple recipients (applicable to only some policy types) and all recipients have responded, allowing the originator to complete the handshake action.</p> </li> <li> <p> <b>CANCELED</b>: This handshake is no longer active because it was canceled by the originating account.</p> </li> <li> <p> <b>ACCEPTED</b>: This handshake is complete because it has been accepted by the recipient.</p> </li> <li> <p> <b>DECLINED</b>: This handshake is no longer active because it was declined by the recipient account.</p> </li> <li> <p> <b>EXPIRED</b>: This handshake is no longer active because the originator did not receive a response of any kind from the recipient before the expiration time (15 days).</p> </li> </ul></p>
-    #[serde(rename = "State")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+impl From<String> for InvalidInputExceptionReason {
+    fn from(value: String) -> InvalidInputExceptionReason {
+        match value.as_str() {
+            "INVALID_PARTY_TYPE_TARGET" => InvalidInputExceptionReason::InvalidPartyTypeTarget,
+            "INVALID_SYNTAX_ORGANIZATION_ARN" => {
+                InvalidInputExceptionReason::InvalidSyntaxOrganizationArn
+            }
+            "INVALID_SYNTAX_POLICY_ID" => InvalidInputExceptionReason::InvalidSyntaxPolicyId,
+            "INVALID_ENUM" => InvalidInputExceptionReason::InvalidEnum,
+            "INVALID_ENUM_POLICY_TYPE" => InvalidInputExceptionReason::InvalidEnumPolicyType,
+            "INVALID_LIST_MEMBER" => InvalidInputExceptionReason::InvalidListMember,
+            "MAX_LENGTH_EXCEEDED" => InvalidInputExceptionReason::MaxLengthExceeded,
+            "MAX_VALUE_EXCEEDED" => InvalidInputExceptionReason::MaxValueExceeded,
+            "MIN_LENGTH_EXCEEDED" => InvalidInputExceptionReason::MinLengthExceeded,
+            "MIN_VALUE_EXCEEDED" => InvalidInputExceptionReason::MinValueExceeded,
+            "IMMUTABLE_POLICY" => InvalidInputExceptionReason::ImmutablePolicy,
+            "INVALID_PATTERN" => InvalidInputExceptionReason::InvalidPattern,
+            "INVALID_PATTERN_TARGET_ID" => InvalidInputExceptionReason::InvalidPatternTargetId,
+            "INPUT_REQUIRED" => InvalidInputExceptionReason::InputRequired,
+            "INVALID_NEXT_TOKEN" => InvalidInputExceptionReason::InvalidNextToken,
+            "MAX_LIMIT_EXCEEDED_FILTER" => InvalidInputExceptionReason::MaxLimitExceededFilter,
+            "MOVING_ACCOUNT_BETWEEN_DIFFERENT_ROOTS" => {
+                InvalidInputExceptionReason::MovingAccountBetweenDifferentRoots
+            }
+            "INVALID_FULL_NAME_TARGET" => InvalidInputExceptionReason::InvalidFullNameTarget,
+            "UNRECOGNIZED_SERVICE_PRINCIPAL" => {
+                InvalidInputExceptionReason::UnrecognizedServicePrincipal
+            }
+            "INVALID_ROLE_NAME" => InvalidInputExceptionReason::InvalidRoleName,
+            "INVALID_SYSTEM_TAGS_PARAMETER" => {
+                InvalidInputExceptionReason::InvalidSystemTagsParameter
+            }
+            "DUPLICATE_TAG_KEY" => InvalidInputExceptionReason::DuplicateTagKey,
+            "TARGET_NOT_SUPPORTED" => InvalidInputExceptionReason::TargetNotSupported,
+            "INVALID_EMAIL_ADDRESS_TARGET" => {
+                InvalidInputExceptionReason::InvalidEmailAddressTarget
+            }
+            "INVALID_RESOURCE_POLICY_JSON" => {
+                InvalidInputExceptionReason::InvalidResourcePolicyJson
+            }
+            "UNSUPPORTED_ACTION_IN_RESOURCE_POLICY" => {
+                InvalidInputExceptionReason::UnsupportedActionInResourcePolicy
+            }
+            "UNSUPPORTED_POLICY_TYPE_IN_RESOURCE_POLICY" => {
+                InvalidInputExceptionReason::UnsupportedPolicyTypeInResourcePolicy
+            }
+            "UNSUPPORTED_RESOURCE_IN_RESOURCE_POLICY" => {
+                InvalidInputExceptionReason::UnsupportedResourceInResourcePolicy
+            }
+            _ => InvalidInputExceptionReason::Unknown(value),
+        }
+    }
 }
 
-/// <p>Specifies the criteria that are used to select the handshakes for the operation.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct HandshakeFilter {
-    /// <p>Specifies the type of handshake action.</p> <p>If you specify <code>ActionType</code>, you cannot also specify <code>ParentHandshakeId</code>.</p>
-    #[serde(rename = "ActionType")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub action_type: Option<String>,
-    /// <p>Specifies the parent handshake. Only used for handshake types that are a child of another type.</p> <p>If you specify <code>ParentHandshakeId</code>, you cannot also specify <code>ActionType</code>.</p> <p>The <a href="http://wikipedia.org/wiki/regex">regex pattern</a> for handshake ID string requires "h-" followed by from 8 to 32 lower-case letters or digits.</p>
-    #[serde(rename = "ParentHandshakeId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub parent_handshake_id: Option<String>,
+impl ::std::str::FromStr for InvalidInputExceptionReason {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<InvalidInputExceptionReason, Self::Err> {
+        Ok(value.into())
+    }
 }
 
-/// <p>Identifies a participant in a handshake.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct HandshakeParty {
-    /// <p>The unique identifier (ID) for the party.</p> <p>The <a href="http://wikipedia.org/wiki/regex">regex pattern</a> for handshake ID string requires "h-" followed by from 8 to 32 lower-case letters or digits.</p>
-    #[serde(rename = "Id")]
-    pub id: String,
-    /// <p>The type of party.</p>
-    #[serde(rename = "Type")]
-    pub type_: String,
+impl ::rusoto_core::param::ToParam for InvalidInputExceptionReason {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
 }
 
-/// <p>Contains additional data that is needed to process a handshake.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct HandshakeResource {
-    /// <p>When needed, contains an additional array of <code>HandshakeResource</code> objects.</p>
-    #[serde(rename = "Resources")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub resources: Option<Vec<HandshakeResource>>,
-    /// <p><p>The type of information being passed, specifying how the value is to be interpreted by the other party:</p> <ul> <li> <p> <code>ACCOUNT</code> - Specifies an AWS account ID number.</p> </li> <li> <p> <code>ORGANIZATION</code> - Specifies an organization ID number.</p> </li> <li> <p> <code>EMAIL</code> - Specifies the email address that is associated with the account that receives the handshake. </p> </li> <li> <p> <code>OWNER<em>EMAIL</code> - Specifies the email address associated with the master account. Included as information about an organization. </p> </li> <li> <p> <code>OWNER</em>NAME</code> - Specifies the name associated with the master account. Included as information about an organization. </p> </li> <li> <p> <code>NOTES</code> - Additional text provided by the handshake initiator and intended for the recipient to read.</p> </li> </ul></p>
-    #[serde(rename = "Type")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
-    /// <p>The information that is passed to the other party in the handshake. The format of the value string must match the requirements of the specified type.</p>
-    #[serde(rename = "Value")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub value: Option<String>,
+impl ::serde::Serialize for InvalidInputExceptionReason {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for InvalidInputExceptionReason {
+    fn deserialize<D>(deserializer: D) -> Result<InvalidInputExceptionReason, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct InviteAccountToOrganizationRequest {
@@ -726,7 +2718,7 @@ pub struct ListAccountsResponse {
 pub struct ListChildrenRequest {
     /// <p>Filters the output to include only the specified child type.</p>
     #[serde(rename = "ChildType")]
-    pub child_type: String,
+    pub child_type: ChildType,
     /// <p>(Optional) Use this to limit the number of results you want included per page in the response. If you do not include this parameter, it defaults to a value that is specific to the operation. If additional items exist beyond the maximum you specify, the <code>NextToken</code> response element is present and has a value (is not null). Include that value as the <code>NextToken</code> request parameter in the next call to the operation to get the next part of the results. Note that Organizations might return fewer results than the maximum even when there are more results available. You should check <code>NextToken</code> after every operation to ensure that you receive all of the results.</p>
     #[serde(rename = "MaxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -767,7 +2759,7 @@ pub struct ListCreateAccountStatusRequest {
     /// <p>A list of one or more states that you want included in the response. If this parameter isn't present, all requests are included in the response.</p>
     #[serde(rename = "States")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub states: Option<Vec<String>>,
+    pub states: Option<Vec<CreateAccountState>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -906,7 +2898,7 @@ pub struct ListParentsResponse {
 pub struct ListPoliciesForTargetRequest {
     /// <p>The type of policy that you want to include in the returned list.</p>
     #[serde(rename = "Filter")]
-    pub filter: String,
+    pub filter: PolicyType,
     /// <p>(Optional) Use this to limit the number of results you want included per page in the response. If you do not include this parameter, it defaults to a value that is specific to the operation. If additional items exist beyond the maximum you specify, the <code>NextToken</code> response element is present and has a value (is not null). Include that value as the <code>NextToken</code> request parameter in the next call to the operation to get the next part of the results. Note that Organizations might return fewer results than the maximum even when there are more results available. You should check <code>NextToken</code> after every operation to ensure that you receive all of the results.</p>
     #[serde(rename = "MaxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -938,7 +2930,7 @@ pub struct ListPoliciesForTargetResponse {
 pub struct ListPoliciesRequest {
     /// <p>Specifies the type of policy that you want to include in the response.</p>
     #[serde(rename = "Filter")]
-    pub filter: String,
+    pub filter: PolicyType,
     /// <p>(Optional) Use this to limit the number of results you want included per page in the response. If you do not include this parameter, it defaults to a value that is specific to the operation. If additional items exist beyond the maximum you specify, the <code>NextToken</code> response element is present and has a value (is not null). Include that value as the <code>NextToken</code> request parameter in the next call to the operation to get the next part of the results. Note that Organizations might return fewer results than the maximum even when there are more results available. You should check <code>NextToken</code> after every operation to ensure that you receive all of the results.</p>
     #[serde(rename = "MaxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1071,7 +3063,7 @@ pub struct Organization {
     /// <p>Specifies the functionality that currently is available to the organization. If set to "ALL", then all features are enabled and policies can be applied to accounts in the organization. If set to "CONSOLIDATED_BILLING", then only consolidated billing functionality is available. For more information, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_org_support-all-features.html">Enabling All Features in Your Organization</a> in the <i>AWS Organizations User Guide</i>.</p>
     #[serde(rename = "FeatureSet")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub feature_set: Option<String>,
+    pub feature_set: Option<OrganizationFeatureSet>,
     /// <p>The unique identifier (ID) of an organization.</p> <p>The <a href="http://wikipedia.org/wiki/regex">regex pattern</a> for an organization ID string requires "o-" followed by from 10 to 32 lower-case letters or digits.</p>
     #[serde(rename = "Id")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1090,38 +3082,224 @@ pub struct Organization {
     pub master_account_id: Option<String>,
 }
 
-/// <p>Contains details about an organizational unit (OU). An OU is a container of AWS accounts within a root of an organization. Policies that are attached to an OU apply to all accounts contained in that OU and in any child OUs.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct OrganizationalUnit {
-    /// <p>The Amazon Resource Name (ARN) of this OU.</p> <p>For more information about ARNs in Organizations, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_permissions.html#orgs-permissions-arns">ARN Formats Supported by Organizations</a> in the <i>AWS Organizations User Guide</i>.</p>
-    #[serde(rename = "Arn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub arn: Option<String>,
-    /// <p>The unique identifier (ID) associated with this OU.</p> <p>The <a href="http://wikipedia.org/wiki/regex">regex pattern</a> for an organizational unit ID string requires "ou-" followed by from 4 to 32 lower-case letters or digits (the ID of the root that contains the OU). This string is followed by a second "-" dash and from 8 to 32 additional lower-case letters or digits.</p>
-    #[serde(rename = "Id")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
-    /// <p>The friendly name of this OU.</p> <p>The <a href="http://wikipedia.org/wiki/regex">regex pattern</a> that is used to validate this parameter is a string of any of the characters in the ASCII character range.</p>
-    #[serde(rename = "Name")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OrganizationFeatureSet {
+    /// `ALL`
+    All,
+    /// `CONSOLIDATED_BILLING`
+    ConsolidatedBilling,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl OrganizationFeatureSet {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OrganizationFeatureSet::All => "ALL",
+            OrganizationFeatureSet::ConsolidatedBilling => "CONSOLIDATED_BILLING",
+            OrganizationFeatureSet::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for OrganizationFeatureSet {
+    fn default() -> OrganizationFeatureSet {
+        OrganizationFeatureSet::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for OrganizationFeatureSet {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for OrganizationFeatureSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for OrganizationFeatureSet {
+    fn from(value: &'a str) -> OrganizationFeatureSet {
+        match value {
+            "ALL" => OrganizationFeatureSet::All,
+            "CONSOLIDATED_BILLING" => OrganizationFeatureSet::ConsolidatedBilling,
+            _ => OrganizationFeatureSet::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OrganizationFeatureSet {
+    fn from(value: String) -> OrganizationFeatureSet {
+        match value.as_str() {
+            "ALL" => OrganizationFeatureSet::All,
+            "CONSOLIDATED_BILLING" => OrganizationFeatureSet::ConsolidatedBilling,
+            _ => OrganizationFeatureSet::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for OrganizationFeatureSet {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<OrganizationFeatureSet, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OrganizationFeatureSet {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for OrganizationFeatureSet {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OrganizationFeatureSet {
+    fn deserialize<D>(deserializer: D) -> Result<OrganizationFeatureSet, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Contains details about an organizational unit (OU). An OU is a container of AWS accounts within a root of an organization. Policies that are attached to an OU apply to all accounts contained in that OU and in any child OUs.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct OrganizationalUnit {
+    /// <p>The Amazon Resource Name (ARN) of this OU.</p> <p>For more information about ARNs in Organizations, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_permissions.html#orgs-permissions-arns">ARN Formats Supported by Organizations</a> in the <i>AWS Organizations User Guide</i>.</p>
+    #[serde(rename = "Arn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub arn: Option<String>,
+    /// <p>The unique identifier (ID) associated with this OU.</p> <p>The <a href="http://wikipedia.org/wiki/regex">regex pattern</a> for an organizational unit ID string requires "ou-" followed by from 4 to 32 lower-case letters or digits (the ID of the root that contains the OU). This string is followed by a second "-" dash and from 8 to 32 additional lower-case letters or digits.</p>
+    #[serde(rename = "Id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// <p>The friendly name of this OU.</p> <p>The <a href="http://wikipedia.org/wiki/regex">regex pattern</a> that is used to validate this parameter is a string of any of the characters in the ASCII character range.</p>
+    #[serde(rename = "Name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// <p>Contains information about either a root or an organizational unit (OU) that can contain OUs or accounts in an organization.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct Parent {
+    /// <p><p>The unique identifier (ID) of the parent entity.</p> <p>The <a href="http://wikipedia.org/wiki/regex">regex pattern</a> for a parent ID string requires one of the following:</p> <ul> <li> <p>Root: A string that begins with &quot;r-&quot; followed by from 4 to 32 lower-case letters or digits.</p> </li> <li> <p>Organizational unit (OU): A string that begins with &quot;ou-&quot; followed by from 4 to 32 lower-case letters or digits (the ID of the root that the OU is in). This string is followed by a second &quot;-&quot; dash and from 8 to 32 additional lower-case letters or digits.</p> </li> </ul></p>
+    #[serde(rename = "Id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// <p>The type of the parent entity.</p>
+    #[serde(rename = "Type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_: Option<ParentType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ParentType {
+    /// `ROOT`
+    Root,
+    /// `ORGANIZATIONAL_UNIT`
+    OrganizationalUnit,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ParentType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ParentType::Root => "ROOT",
+            ParentType::OrganizationalUnit => "ORGANIZATIONAL_UNIT",
+            ParentType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ParentType {
+    fn default() -> ParentType {
+        ParentType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ParentType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ParentType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ParentType {
+    fn from(value: &'a str) -> ParentType {
+        match value {
+            "ROOT" => ParentType::Root,
+            "ORGANIZATIONAL_UNIT" => ParentType::OrganizationalUnit,
+            _ => ParentType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ParentType {
+    fn from(value: String) -> ParentType {
+        match value.as_str() {
+            "ROOT" => ParentType::Root,
+            "ORGANIZATIONAL_UNIT" => ParentType::OrganizationalUnit,
+            _ => ParentType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ParentType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ParentType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ParentType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
 }
 
-/// <p>Contains information about either a root or an organizational unit (OU) that can contain OUs or accounts in an organization.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct Parent {
-    /// <p><p>The unique identifier (ID) of the parent entity.</p> <p>The <a href="http://wikipedia.org/wiki/regex">regex pattern</a> for a parent ID string requires one of the following:</p> <ul> <li> <p>Root: A string that begins with &quot;r-&quot; followed by from 4 to 32 lower-case letters or digits.</p> </li> <li> <p>Organizational unit (OU): A string that begins with &quot;ou-&quot; followed by from 4 to 32 lower-case letters or digits (the ID of the root that the OU is in). This string is followed by a second &quot;-&quot; dash and from 8 to 32 additional lower-case letters or digits.</p> </li> </ul></p>
-    #[serde(rename = "Id")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
-    /// <p>The type of the parent entity.</p>
-    #[serde(rename = "Type")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+impl ::serde::Serialize for ParentType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for ParentType {
+    fn deserialize<D>(deserializer: D) -> Result<ParentType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Contains rules to be applied to the affected accounts. Policies can be attached directly to accounts, or to roots and OUs to affect all accounts in those hierarchies.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1163,7 +3341,7 @@ pub struct PolicySummary {
     /// <p>The type of policy.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<PolicyType>,
 }
 
 /// <p>Contains information about a root, OU, or account that a policy is attached to.</p>
@@ -1185,9 +3363,210 @@ pub struct PolicyTargetSummary {
     /// <p>The type of the policy target.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<TargetType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PolicyType {
+    /// `SERVICE_CONTROL_POLICY`
+    ServiceControlPolicy,
+    /// `TAG_POLICY`
+    TagPolicy,
+    /// `BACKUP_POLICY`
+    BackupPolicy,
+    /// `AISERVICES_OPT_OUT_POLICY`
+    AiservicesOptOutPolicy,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PolicyType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PolicyType::ServiceControlPolicy => "SERVICE_CONTROL_POLICY",
+            PolicyType::TagPolicy => "TAG_POLICY",
+            PolicyType::BackupPolicy => "BACKUP_POLICY",
+            PolicyType::AiservicesOptOutPolicy => "AISERVICES_OPT_OUT_POLICY",
+            PolicyType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PolicyType {
+    fn default() -> PolicyType {
+        PolicyType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PolicyType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PolicyType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PolicyType {
+    fn from(value: &'a str) -> PolicyType {
+        match value {
+            "SERVICE_CONTROL_POLICY" => PolicyType::ServiceControlPolicy,
+            "TAG_POLICY" => PolicyType::TagPolicy,
+            "BACKUP_POLICY" => PolicyType::BackupPolicy,
+            "AISERVICES_OPT_OUT_POLICY" => PolicyType::AiservicesOptOutPolicy,
+            _ => PolicyType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PolicyType {
+    fn from(value: String) -> PolicyType {
+        match value.as_str() {
+            "SERVICE_CONTROL_POLICY" => PolicyType::ServiceControlPolicy,
+            "TAG_POLICY" => PolicyType::TagPolicy,
+            "BACKUP_POLICY" => PolicyType::BackupPolicy,
+            "AISERVICES_OPT_OUT_POLICY" => PolicyType::AiservicesOptOutPolicy,
+            _ => PolicyType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PolicyType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PolicyType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PolicyType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PolicyType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PolicyType {
+    fn deserialize<D>(deserializer: D) -> Result<PolicyType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PolicyTypeStatus {
+    /// `ENABLED`
+    Enabled,
+    /// `PENDING_ENABLE`
+    PendingEnable,
+    /// `PENDING_DISABLE`
+    PendingDisable,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PolicyTypeStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PolicyTypeStatus::Enabled => "ENABLED",
+            PolicyTypeStatus::PendingEnable => "PENDING_ENABLE",
+            PolicyTypeStatus::PendingDisable => "PENDING_DISABLE",
+            PolicyTypeStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PolicyTypeStatus {
+    fn default() -> PolicyTypeStatus {
+        PolicyTypeStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PolicyTypeStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PolicyTypeStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PolicyTypeStatus {
+    fn from(value: &'a str) -> PolicyTypeStatus {
+        match value {
+            "ENABLED" => PolicyTypeStatus::Enabled,
+            "PENDING_ENABLE" => PolicyTypeStatus::PendingEnable,
+            "PENDING_DISABLE" => PolicyTypeStatus::PendingDisable,
+            _ => PolicyTypeStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PolicyTypeStatus {
+    fn from(value: String) -> PolicyTypeStatus {
+        match value.as_str() {
+            "ENABLED" => PolicyTypeStatus::Enabled,
+            "PENDING_ENABLE" => PolicyTypeStatus::PendingEnable,
+            "PENDING_DISABLE" => PolicyTypeStatus::PendingDisable,
+            _ => PolicyTypeStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PolicyTypeStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PolicyTypeStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PolicyTypeStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PolicyTypeStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for PolicyTypeStatus {
+    fn deserialize<D>(deserializer: D) -> Result<PolicyTypeStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Contains information about a policy type and its status in the associated root.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1195,11 +3574,11 @@ pub struct PolicyTypeSummary {
     /// <p>The status of the policy type as it relates to the associated root. You can attach a policy of the specified type to a root or to an OU or account in that root. To do so, the policy must be available in the organization and enabled for that root.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<PolicyTypeStatus>,
     /// <p>The name of the policy type.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<PolicyType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -1254,6 +3633,104 @@ pub struct TagResourceRequest {
     pub tags: Vec<Tag>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TargetType {
+    /// `ACCOUNT`
+    Account,
+    /// `ORGANIZATIONAL_UNIT`
+    OrganizationalUnit,
+    /// `ROOT`
+    Root,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TargetType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TargetType::Account => "ACCOUNT",
+            TargetType::OrganizationalUnit => "ORGANIZATIONAL_UNIT",
+            TargetType::Root => "ROOT",
+            TargetType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TargetType {
+    fn default() -> TargetType {
+        TargetType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TargetType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TargetType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TargetType {
+    fn from(value: &'a str) -> TargetType {
+        match value {
+            "ACCOUNT" => TargetType::Account,
+            "ORGANIZATIONAL_UNIT" => TargetType::OrganizationalUnit,
+            "ROOT" => TargetType::Root,
+            _ => TargetType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TargetType {
+    fn from(value: String) -> TargetType {
+        match value.as_str() {
+            "ACCOUNT" => TargetType::Account,
+            "ORGANIZATIONAL_UNIT" => TargetType::OrganizationalUnit,
+            "ROOT" => TargetType::Root,
+            _ => TargetType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TargetType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TargetType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TargetType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TargetType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TargetType {
+    fn deserialize<D>(deserializer: D) -> Result<TargetType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct UntagResourceRequest {
@@ -5455,11 +7932,12 @@ pub struct OrganizationsClient {
 impl OrganizationsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> OrganizationsClient {
         OrganizationsClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Organizations"),
         }
     }
 
@@ -5491,6 +7969,7 @@ impl Organizations for OrganizationsClient {
         input: AcceptHandshakeRequest,
     ) -> Result<AcceptHandshakeResponse, RusotoError<AcceptHandshakeError>> {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("AcceptHandshake");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.AcceptHandshake");
@@ -5518,6 +7997,7 @@ impl Organizations for OrganizationsClient {
         input: AttachPolicyRequest,
     ) -> Result<(), RusotoError<AttachPolicyError>> {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("AttachPolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.AttachPolicy");
@@ -5545,6 +8025,7 @@ impl Organizations for OrganizationsClient {
         input: CancelHandshakeRequest,
     ) -> Result<CancelHandshakeResponse, RusotoError<CancelHandshakeError>> {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("CancelHandshake");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.CancelHandshake");
@@ -5572,6 +8053,7 @@ impl Organizations for OrganizationsClient {
         input: CreateAccountRequest,
     ) -> Result<CreateAccountResponse, RusotoError<CreateAccountError>> {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("CreateAccount");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.CreateAccount");
@@ -5599,6 +8081,7 @@ impl Organizations for OrganizationsClient {
         input: CreateGovCloudAccountRequest,
     ) -> Result<CreateGovCloudAccountResponse, RusotoError<CreateGovCloudAccountError>> {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("CreateGovCloudAccount");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5630,6 +8113,7 @@ impl Organizations for OrganizationsClient {
         input: CreateOrganizationRequest,
     ) -> Result<CreateOrganizationResponse, RusotoError<CreateOrganizationError>> {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("CreateOrganization");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5661,6 +8145,7 @@ impl Organizations for OrganizationsClient {
         input: CreateOrganizationalUnitRequest,
     ) -> Result<CreateOrganizationalUnitResponse, RusotoError<CreateOrganizationalUnitError>> {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("CreateOrganizationalUnit");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5692,6 +8177,7 @@ impl Organizations for OrganizationsClient {
         input: CreatePolicyRequest,
     ) -> Result<CreatePolicyResponse, RusotoError<CreatePolicyError>> {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("CreatePolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.CreatePolicy");
@@ -5719,6 +8205,7 @@ impl Organizations for OrganizationsClient {
         input: DeclineHandshakeRequest,
     ) -> Result<DeclineHandshakeResponse, RusotoError<DeclineHandshakeError>> {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("DeclineHandshake");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.DeclineHandshake");
@@ -5744,6 +8231,7 @@ impl Organizations for OrganizationsClient {
     /// <p>Deletes the organization. You can delete an organization only by using credentials from the master account. The organization must be empty of member accounts.</p>
     async fn delete_organization(&self) -> Result<(), RusotoError<DeleteOrganizationError>> {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("DeleteOrganization");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5773,6 +8261,7 @@ impl Organizations for OrganizationsClient {
         input: DeleteOrganizationalUnitRequest,
     ) -> Result<(), RusotoError<DeleteOrganizationalUnitError>> {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("DeleteOrganizationalUnit");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5803,6 +8292,7 @@ impl Organizations for OrganizationsClient {
         input: DeletePolicyRequest,
     ) -> Result<(), RusotoError<DeletePolicyError>> {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("DeletePolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.DeletePolicy");
@@ -5830,6 +8320,7 @@ impl Organizations for OrganizationsClient {
         input: DescribeAccountRequest,
     ) -> Result<DescribeAccountResponse, RusotoError<DescribeAccountError>> {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("DescribeAccount");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.DescribeAccount");
@@ -5858,6 +8349,7 @@ impl Organizations for OrganizationsClient {
     ) -> Result<DescribeCreateAccountStatusResponse, RusotoError<DescribeCreateAccountStatusError>>
     {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("DescribeCreateAccountStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5889,6 +8381,7 @@ impl Organizations for OrganizationsClient {
         input: DescribeEffectivePolicyRequest,
     ) -> Result<DescribeEffectivePolicyResponse, RusotoError<DescribeEffectivePolicyError>> {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("DescribeEffectivePolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5920,6 +8413,7 @@ impl Organizations for OrganizationsClient {
         input: DescribeHandshakeRequest,
     ) -> Result<DescribeHandshakeResponse, RusotoError<DescribeHandshakeError>> {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("DescribeHandshake");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5950,6 +8444,7 @@ impl Organizations for OrganizationsClient {
         &self,
     ) -> Result<DescribeOrganizationResponse, RusotoError<DescribeOrganizationError>> {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("DescribeOrganization");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5981,6 +8476,7 @@ impl Organizations for OrganizationsClient {
     ) -> Result<DescribeOrganizationalUnitResponse, RusotoError<DescribeOrganizationalUnitError>>
     {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("DescribeOrganizationalUnit");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6012,6 +8508,7 @@ impl Organizations for OrganizationsClient {
         input: DescribePolicyRequest,
     ) -> Result<DescribePolicyResponse, RusotoError<DescribePolicyError>> {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("DescribePolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.DescribePolicy");
@@ -6039,6 +8536,7 @@ impl Organizations for OrganizationsClient {
         input: DetachPolicyRequest,
     ) -> Result<(), RusotoError<DetachPolicyError>> {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("DetachPolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.DetachPolicy");
@@ -6066,6 +8564,7 @@ impl Organizations for OrganizationsClient {
         input: DisableAWSServiceAccessRequest,
     ) -> Result<(), RusotoError<DisableAWSServiceAccessError>> {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("DisableAWSServiceAccess");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6096,6 +8595,7 @@ impl Organizations for OrganizationsClient {
         input: DisablePolicyTypeRequest,
     ) -> Result<DisablePolicyTypeResponse, RusotoError<DisablePolicyTypeError>> {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("DisablePolicyType");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6127,6 +8627,7 @@ impl Organizations for OrganizationsClient {
         input: EnableAWSServiceAccessRequest,
     ) -> Result<(), RusotoError<EnableAWSServiceAccessError>> {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("EnableAWSServiceAccess");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6156,6 +8657,7 @@ impl Organizations for OrganizationsClient {
         &self,
     ) -> Result<EnableAllFeaturesResponse, RusotoError<EnableAllFeaturesError>> {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("EnableAllFeatures");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6186,6 +8688,7 @@ impl Organizations for OrganizationsClient {
         input: EnablePolicyTypeRequest,
     ) -> Result<EnablePolicyTypeResponse, RusotoError<EnablePolicyTypeError>> {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("EnablePolicyType");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.EnablePolicyType");
@@ -6215,6 +8718,7 @@ impl Organizations for OrganizationsClient {
     ) -> Result<InviteAccountToOrganizationResponse, RusotoError<InviteAccountToOrganizationError>>
     {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("InviteAccountToOrganization");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6243,6 +8747,7 @@ impl Organizations for OrganizationsClient {
     /// <p><p>Removes a member account from its parent organization. This version of the operation is performed by the account that wants to leave. To remove a member account as a user in the master account, use <a>RemoveAccountFromOrganization</a> instead.</p> <p>This operation can be called only from a member account in the organization.</p> <important> <ul> <li> <p>The master account in an organization with all features enabled can set service control policies (SCPs) that can restrict what administrators of member accounts can do. These restrictions can include preventing member accounts from successfully calling <code>LeaveOrganization</code>. </p> </li> <li> <p>You can leave an organization as a member account only if the account is configured with the information required to operate as a standalone account. When you create an account in an organization using the AWS Organizations console, API, or CLI, the information required of standalone accounts is <i>not</i> automatically collected. For each account that you want to make standalone, you must accept the end user license agreement (EULA). You must also choose a support plan, provide and verify the required contact information, and provide a current payment method. AWS uses the payment method to charge for any billable (not free tier) AWS activity that occurs while the account isn&#39;t attached to an organization. Follow the steps at <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_accounts_remove.html#leave-without-all-info"> To leave an organization when all required account information has not yet been provided</a> in the <i>AWS Organizations User Guide.</i> </p> </li> <li> <p>You can leave an organization only after you enable IAM user access to billing in your account. For more information, see <a href="http://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/grantaccess.html#ControllingAccessWebsite-Activate">Activating Access to the Billing and Cost Management Console</a> in the <i>AWS Billing and Cost Management User Guide.</i> </p> </li> </ul> </important></p>
     async fn leave_organization(&self) -> Result<(), RusotoError<LeaveOrganizationError>> {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("LeaveOrganization");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6275,6 +8780,7 @@ impl Organizations for OrganizationsClient {
         RusotoError<ListAWSServiceAccessForOrganizationError>,
     > {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("ListAWSServiceAccessForOrganization");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6308,6 +8814,7 @@ impl Organizations for OrganizationsClient {
         input: ListAccountsRequest,
     ) -> Result<ListAccountsResponse, RusotoError<ListAccountsError>> {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("ListAccounts");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.ListAccounts");
@@ -6335,6 +8842,7 @@ impl Organizations for OrganizationsClient {
         input: ListAccountsForParentRequest,
     ) -> Result<ListAccountsForParentResponse, RusotoError<ListAccountsForParentError>> {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("ListAccountsForParent");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6366,6 +8874,7 @@ impl Organizations for OrganizationsClient {
         input: ListChildrenRequest,
     ) -> Result<ListChildrenResponse, RusotoError<ListChildrenError>> {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("ListChildren");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.ListChildren");
@@ -6393,6 +8902,7 @@ impl Organizations for OrganizationsClient {
         input: ListCreateAccountStatusRequest,
     ) -> Result<ListCreateAccountStatusResponse, RusotoError<ListCreateAccountStatusError>> {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("ListCreateAccountStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6424,6 +8934,7 @@ impl Organizations for OrganizationsClient {
         input: ListHandshakesForAccountRequest,
     ) -> Result<ListHandshakesForAccountResponse, RusotoError<ListHandshakesForAccountError>> {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("ListHandshakesForAccount");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6458,6 +8969,7 @@ impl Organizations for OrganizationsClient {
         RusotoError<ListHandshakesForOrganizationError>,
     > {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("ListHandshakesForOrganization");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6492,6 +9004,7 @@ impl Organizations for OrganizationsClient {
         RusotoError<ListOrganizationalUnitsForParentError>,
     > {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("ListOrganizationalUnitsForParent");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6525,6 +9038,7 @@ impl Organizations for OrganizationsClient {
         input: ListParentsRequest,
     ) -> Result<ListParentsResponse, RusotoError<ListParentsError>> {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("ListParents");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.ListParents");
@@ -6552,6 +9066,7 @@ impl Organizations for OrganizationsClient {
         input: ListPoliciesRequest,
     ) -> Result<ListPoliciesResponse, RusotoError<ListPoliciesError>> {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("ListPolicies");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.ListPolicies");
@@ -6579,6 +9094,7 @@ impl Organizations for OrganizationsClient {
         input: ListPoliciesForTargetRequest,
     ) -> Result<ListPoliciesForTargetResponse, RusotoError<ListPoliciesForTargetError>> {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("ListPoliciesForTarget");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6610,6 +9126,7 @@ impl Organizations for OrganizationsClient {
         input: ListRootsRequest,
     ) -> Result<ListRootsResponse, RusotoError<ListRootsError>> {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("ListRoots");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.ListRoots");
@@ -6637,6 +9154,7 @@ impl Organizations for OrganizationsClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6668,6 +9186,7 @@ impl Organizations for OrganizationsClient {
         input: ListTargetsForPolicyRequest,
     ) -> Result<ListTargetsForPolicyResponse, RusotoError<ListTargetsForPolicyError>> {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("ListTargetsForPolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6699,6 +9218,7 @@ impl Organizations for OrganizationsClient {
         input: MoveAccountRequest,
     ) -> Result<(), RusotoError<MoveAccountError>> {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("MoveAccount");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.MoveAccount");
@@ -6726,6 +9246,7 @@ impl Organizations for OrganizationsClient {
         input: RemoveAccountFromOrganizationRequest,
     ) -> Result<(), RusotoError<RemoveAccountFromOrganizationError>> {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("RemoveAccountFromOrganization");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6756,6 +9277,7 @@ impl Organizations for OrganizationsClient {
         input: TagResourceRequest,
     ) -> Result<(), RusotoError<TagResourceError>> {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.TagResource");
@@ -6783,6 +9305,7 @@ impl Organizations for OrganizationsClient {
         input: UntagResourceRequest,
     ) -> Result<(), RusotoError<UntagResourceError>> {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.UntagResource");
@@ -6810,6 +9333,7 @@ impl Organizations for OrganizationsClient {
         input: UpdateOrganizationalUnitRequest,
     ) -> Result<UpdateOrganizationalUnitResponse, RusotoError<UpdateOrganizationalUnitError>> {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("UpdateOrganizationalUnit");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6841,6 +9365,7 @@ impl Organizations for OrganizationsClient {
         input: UpdatePolicyRequest,
     ) -> Result<UpdatePolicyResponse, RusotoError<UpdatePolicyError>> {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("UpdatePolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.UpdatePolicy");
@@ -6862,3 +9387,479 @@ impl Organizations for OrganizationsClient {
         }
     }
 }
+impl OrganizationsClient {
+    /// Auto-paginating version of `list_aws_service_access_for_organization`, streaming every page of results.
+    pub fn list_aws_service_access_for_organization_pages(
+        &self,
+        input: ListAWSServiceAccessForOrganizationRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListAWSServiceAccessForOrganizationResponse,
+        ListAWSServiceAccessForOrganizationError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: OrganizationsClient, input: ListAWSServiceAccessForOrganizationRequest| async move {
+                client.list_aws_service_access_for_organization(input).await
+            },
+            |input: &ListAWSServiceAccessForOrganizationRequest,
+             output: &ListAWSServiceAccessForOrganizationResponse| {
+                Some(ListAWSServiceAccessForOrganizationRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_aws_service_access_for_organization`, streaming the `EnabledServicePrincipals` of every page of results.
+    pub fn list_aws_service_access_for_organization_items(
+        &self,
+        input: ListAWSServiceAccessForOrganizationRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        EnabledServicePrincipal,
+        ListAWSServiceAccessForOrganizationError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_aws_service_access_for_organization_pages(input),
+            |output: ListAWSServiceAccessForOrganizationResponse| {
+                output.enabled_service_principals.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_accounts`, streaming every page of results.
+    pub fn list_accounts_pages(
+        &self,
+        input: ListAccountsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListAccountsResponse, ListAccountsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: OrganizationsClient, input: ListAccountsRequest| async move {
+                client.list_accounts(input).await
+            },
+            |input: &ListAccountsRequest, output: &ListAccountsResponse| {
+                Some(ListAccountsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_accounts`, streaming the `Accounts` of every page of results.
+    pub fn list_accounts_items(
+        &self,
+        input: ListAccountsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Account, ListAccountsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_accounts_pages(input),
+            |output: ListAccountsResponse| output.accounts.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_accounts_for_parent`, streaming every page of results.
+    pub fn list_accounts_for_parent_pages(
+        &self,
+        input: ListAccountsForParentRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListAccountsForParentResponse,
+        ListAccountsForParentError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: OrganizationsClient, input: ListAccountsForParentRequest| async move {
+                client.list_accounts_for_parent(input).await
+            },
+            |input: &ListAccountsForParentRequest, output: &ListAccountsForParentResponse| {
+                Some(ListAccountsForParentRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_accounts_for_parent`, streaming the `Accounts` of every page of results.
+    pub fn list_accounts_for_parent_items(
+        &self,
+        input: ListAccountsForParentRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Account, ListAccountsForParentError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_accounts_for_parent_pages(input),
+            |output: ListAccountsForParentResponse| output.accounts.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_children`, streaming every page of results.
+    pub fn list_children_pages(
+        &self,
+        input: ListChildrenRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListChildrenResponse, ListChildrenError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: OrganizationsClient, input: ListChildrenRequest| async move {
+                client.list_children(input).await
+            },
+            |input: &ListChildrenRequest, output: &ListChildrenResponse| {
+                Some(ListChildrenRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_children`, streaming the `Children` of every page of results.
+    pub fn list_children_items(
+        &self,
+        input: ListChildrenRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Child, ListChildrenError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_children_pages(input),
+            |output: ListChildrenResponse| output.children.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_create_account_status`, streaming every page of results.
+    pub fn list_create_account_status_pages(
+        &self,
+        input: ListCreateAccountStatusRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListCreateAccountStatusResponse,
+        ListCreateAccountStatusError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: OrganizationsClient, input: ListCreateAccountStatusRequest| async move {
+                client.list_create_account_status(input).await
+            },
+            |input: &ListCreateAccountStatusRequest, output: &ListCreateAccountStatusResponse| {
+                Some(ListCreateAccountStatusRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_create_account_status`, streaming the `CreateAccountStatuses` of every page of results.
+    pub fn list_create_account_status_items(
+        &self,
+        input: ListCreateAccountStatusRequest,
+    ) -> rusoto_core::pagination::RusotoStream<CreateAccountStatus, ListCreateAccountStatusError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_create_account_status_pages(input),
+            |output: ListCreateAccountStatusResponse| {
+                output.create_account_statuses.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_handshakes_for_account`, streaming every page of results.
+    pub fn list_handshakes_for_account_pages(
+        &self,
+        input: ListHandshakesForAccountRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListHandshakesForAccountResponse,
+        ListHandshakesForAccountError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: OrganizationsClient, input: ListHandshakesForAccountRequest| async move {
+                client.list_handshakes_for_account(input).await
+            },
+            |input: &ListHandshakesForAccountRequest, output: &ListHandshakesForAccountResponse| {
+                Some(ListHandshakesForAccountRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_handshakes_for_account`, streaming the `Handshakes` of every page of results.
+    pub fn list_handshakes_for_account_items(
+        &self,
+        input: ListHandshakesForAccountRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Handshake, ListHandshakesForAccountError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_handshakes_for_account_pages(input),
+            |output: ListHandshakesForAccountResponse| output.handshakes.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_handshakes_for_organization`, streaming every page of results.
+    pub fn list_handshakes_for_organization_pages(
+        &self,
+        input: ListHandshakesForOrganizationRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListHandshakesForOrganizationResponse,
+        ListHandshakesForOrganizationError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: OrganizationsClient, input: ListHandshakesForOrganizationRequest| async move {
+                client.list_handshakes_for_organization(input).await
+            },
+            |input: &ListHandshakesForOrganizationRequest,
+             output: &ListHandshakesForOrganizationResponse| {
+                Some(ListHandshakesForOrganizationRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_handshakes_for_organization`, streaming the `Handshakes` of every page of results.
+    pub fn list_handshakes_for_organization_items(
+        &self,
+        input: ListHandshakesForOrganizationRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Handshake, ListHandshakesForOrganizationError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_handshakes_for_organization_pages(input),
+            |output: ListHandshakesForOrganizationResponse| output.handshakes.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_organizational_units_for_parent`, streaming every page of results.
+    pub fn list_organizational_units_for_parent_pages(
+        &self,
+        input: ListOrganizationalUnitsForParentRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListOrganizationalUnitsForParentResponse,
+        ListOrganizationalUnitsForParentError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: OrganizationsClient, input: ListOrganizationalUnitsForParentRequest| async move {
+                client.list_organizational_units_for_parent(input).await
+            },
+            |input: &ListOrganizationalUnitsForParentRequest,
+             output: &ListOrganizationalUnitsForParentResponse| {
+                Some(ListOrganizationalUnitsForParentRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_organizational_units_for_parent`, streaming the `OrganizationalUnits` of every page of results.
+    pub fn list_organizational_units_for_parent_items(
+        &self,
+        input: ListOrganizationalUnitsForParentRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        OrganizationalUnit,
+        ListOrganizationalUnitsForParentError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_organizational_units_for_parent_pages(input),
+            |output: ListOrganizationalUnitsForParentResponse| {
+                output.organizational_units.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_parents`, streaming every page of results.
+    pub fn list_parents_pages(
+        &self,
+        input: ListParentsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListParentsResponse, ListParentsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: OrganizationsClient, input: ListParentsRequest| async move {
+                client.list_parents(input).await
+            },
+            |input: &ListParentsRequest, output: &ListParentsResponse| {
+                Some(ListParentsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_parents`, streaming the `Parents` of every page of results.
+    pub fn list_parents_items(
+        &self,
+        input: ListParentsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Parent, ListParentsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_parents_pages(input),
+            |output: ListParentsResponse| output.parents.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_policies`, streaming every page of results.
+    pub fn list_policies_pages(
+        &self,
+        input: ListPoliciesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListPoliciesResponse, ListPoliciesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: OrganizationsClient, input: ListPoliciesRequest| async move {
+                client.list_policies(input).await
+            },
+            |input: &ListPoliciesRequest, output: &ListPoliciesResponse| {
+                Some(ListPoliciesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_policies`, streaming the `Policies` of every page of results.
+    pub fn list_policies_items(
+        &self,
+        input: ListPoliciesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<PolicySummary, ListPoliciesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_policies_pages(input),
+            |output: ListPoliciesResponse| output.policies.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_policies_for_target`, streaming every page of results.
+    pub fn list_policies_for_target_pages(
+        &self,
+        input: ListPoliciesForTargetRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListPoliciesForTargetResponse,
+        ListPoliciesForTargetError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: OrganizationsClient, input: ListPoliciesForTargetRequest| async move {
+                client.list_policies_for_target(input).await
+            },
+            |input: &ListPoliciesForTargetRequest, output: &ListPoliciesForTargetResponse| {
+                Some(ListPoliciesForTargetRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_policies_for_target`, streaming the `Policies` of every page of results.
+    pub fn list_policies_for_target_items(
+        &self,
+        input: ListPoliciesForTargetRequest,
+    ) -> rusoto_core::pagination::RusotoStream<PolicySummary, ListPoliciesForTargetError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_policies_for_target_pages(input),
+            |output: ListPoliciesForTargetResponse| output.policies.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_roots`, streaming every page of results.
+    pub fn list_roots_pages(
+        &self,
+        input: ListRootsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListRootsResponse, ListRootsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: OrganizationsClient, input: ListRootsRequest| async move {
+                client.list_roots(input).await
+            },
+            |input: &ListRootsRequest, output: &ListRootsResponse| {
+                Some(ListRootsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_roots`, streaming the `Roots` of every page of results.
+    pub fn list_roots_items(
+        &self,
+        input: ListRootsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Root, ListRootsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_roots_pages(input),
+            |output: ListRootsResponse| output.roots.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_tags_for_resource`, streaming every page of results.
+    pub fn list_tags_for_resource_pages(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListTagsForResourceResponse, ListTagsForResourceError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: OrganizationsClient, input: ListTagsForResourceRequest| async move {
+                client.list_tags_for_resource(input).await
+            },
+            |input: &ListTagsForResourceRequest, output: &ListTagsForResourceResponse| {
+                Some(ListTagsForResourceRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_tags_for_resource`, streaming the `Tags` of every page of results.
+    pub fn list_tags_for_resource_items(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Tag, ListTagsForResourceError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_tags_for_resource_pages(input),
+            |output: ListTagsForResourceResponse| output.tags.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_targets_for_policy`, streaming every page of results.
+    pub fn list_targets_for_policy_pages(
+        &self,
+        input: ListTargetsForPolicyRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListTargetsForPolicyResponse,
+        ListTargetsForPolicyError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: OrganizationsClient, input: ListTargetsForPolicyRequest| async move {
+                client.list_targets_for_policy(input).await
+            },
+            |input: &ListTargetsForPolicyRequest, output: &ListTargetsForPolicyResponse| {
+                Some(ListTargetsForPolicyRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_targets_for_policy`, streaming the `Targets` of every page of results.
+    pub fn list_targets_for_policy_items(
+        &self,
+        input: ListTargetsForPolicyRequest,
+    ) -> rusoto_core::pagination::RusotoStream<PolicyTargetSummary, ListTargetsForPolicyError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_targets_for_policy_pages(input),
+            |output: ListTargetsForPolicyResponse| output.targets.unwrap_or_default(),
+        )
+    }
+}
@@ -452,11 +452,12 @@ pub struct OutpostsClient {
 impl OutpostsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> OutpostsClient {
         OutpostsClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Outposts"),
         }
     }
 
@@ -490,6 +491,7 @@ impl Outposts for OutpostsClient {
         let request_uri = "/outposts";
 
         let mut request = SignedRequest::new("POST", "outposts", &self.region, &request_uri);
+        request.set_operation("CreateOutpost");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -520,6 +522,7 @@ impl Outposts for OutpostsClient {
         let request_uri = format!("/outposts/{outpost_id}", outpost_id = input.outpost_id);
 
         let mut request = SignedRequest::new("GET", "outposts", &self.region, &request_uri);
+        request.set_operation("GetOutpost");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -550,6 +553,7 @@ impl Outposts for OutpostsClient {
         );
 
         let mut request = SignedRequest::new("GET", "outposts", &self.region, &request_uri);
+        request.set_operation("GetOutpostInstanceTypes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -586,6 +590,7 @@ impl Outposts for OutpostsClient {
         let request_uri = "/outposts";
 
         let mut request = SignedRequest::new("GET", "outposts", &self.region, &request_uri);
+        request.set_operation("ListOutposts");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -622,6 +627,7 @@ impl Outposts for OutpostsClient {
         let request_uri = "/sites";
 
         let mut request = SignedRequest::new("GET", "outposts", &self.region, &request_uri);
+        request.set_operation("ListSites");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -650,3 +656,100 @@ impl Outposts for OutpostsClient {
         }
     }
 }
+impl OutpostsClient {
+    /// Auto-paginating version of `get_outpost_instance_types`, streaming every page of results.
+    pub fn get_outpost_instance_types_pages(
+        &self,
+        input: GetOutpostInstanceTypesInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        GetOutpostInstanceTypesOutput,
+        GetOutpostInstanceTypesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: OutpostsClient, input: GetOutpostInstanceTypesInput| async move {
+                client.get_outpost_instance_types(input).await
+            },
+            |input: &GetOutpostInstanceTypesInput, output: &GetOutpostInstanceTypesOutput| {
+                Some(GetOutpostInstanceTypesInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_outpost_instance_types`, streaming the `InstanceTypes` of every page of results.
+    pub fn get_outpost_instance_types_items(
+        &self,
+        input: GetOutpostInstanceTypesInput,
+    ) -> rusoto_core::pagination::RusotoStream<InstanceTypeItem, GetOutpostInstanceTypesError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_outpost_instance_types_pages(input),
+            |output: GetOutpostInstanceTypesOutput| output.instance_types.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_outposts`, streaming every page of results.
+    pub fn list_outposts_pages(
+        &self,
+        input: ListOutpostsInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListOutpostsOutput, ListOutpostsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: OutpostsClient, input: ListOutpostsInput| async move {
+                client.list_outposts(input).await
+            },
+            |input: &ListOutpostsInput, output: &ListOutpostsOutput| {
+                Some(ListOutpostsInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_outposts`, streaming the `Outposts` of every page of results.
+    pub fn list_outposts_items(
+        &self,
+        input: ListOutpostsInput,
+    ) -> rusoto_core::pagination::RusotoStream<Outpost, ListOutpostsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_outposts_pages(input),
+            |output: ListOutpostsOutput| output.outposts.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_sites`, streaming every page of results.
+    pub fn list_sites_pages(
+        &self,
+        input: ListSitesInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListSitesOutput, ListSitesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: OutpostsClient, input: ListSitesInput| async move {
+                client.list_sites(input).await
+            },
+            |input: &ListSitesInput, output: &ListSitesOutput| {
+                Some(ListSitesInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_sites`, streaming the `Sites` of every page of results.
+    pub fn list_sites_items(
+        &self,
+        input: ListSitesInput,
+    ) -> rusoto_core::pagination::RusotoStream<Site, ListSitesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_sites_pages(input),
+            |output: ListSitesOutput| output.sites.unwrap_or_default(),
+        )
+    }
+}
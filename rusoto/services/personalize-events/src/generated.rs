@@ -40,7 +40,12 @@ pub struct Event {
     pub properties: String,
     /// <p>The timestamp on the client side when the event occurred.</p>
     #[serde(rename = "sentAt")]
-    pub sent_at: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub sent_at: ::chrono::DateTime<::chrono::Utc>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -107,11 +112,12 @@ pub struct PersonalizeEventsClient {
 impl PersonalizeEventsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> PersonalizeEventsClient {
         PersonalizeEventsClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Personalize Events"),
         }
     }
 
@@ -142,6 +148,7 @@ impl PersonalizeEvents for PersonalizeEventsClient {
         let request_uri = "/events";
 
         let mut request = SignedRequest::new("POST", "personalize", &self.region, &request_uri);
+        request.set_operation("PutEvents");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("personalize-events".to_string());
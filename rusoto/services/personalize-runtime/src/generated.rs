@@ -193,11 +193,12 @@ pub struct PersonalizeRuntimeClient {
 impl PersonalizeRuntimeClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> PersonalizeRuntimeClient {
         PersonalizeRuntimeClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Personalize Runtime"),
         }
     }
 
@@ -231,6 +232,7 @@ impl PersonalizeRuntime for PersonalizeRuntimeClient {
         let request_uri = "/personalize-ranking";
 
         let mut request = SignedRequest::new("POST", "personalize", &self.region, &request_uri);
+        request.set_operation("GetPersonalizedRanking");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("personalize-runtime".to_string());
@@ -262,6 +264,7 @@ impl PersonalizeRuntime for PersonalizeRuntimeClient {
         let request_uri = "/recommendations";
 
         let mut request = SignedRequest::new("POST", "personalize", &self.region, &request_uri);
+        request.set_operation("GetRecommendations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("personalize-runtime".to_string());
@@ -38,8 +38,13 @@ pub struct Algorithm {
     pub algorithm_image: Option<AlgorithmImage>,
     /// <p>The date and time (in Unix time) that the algorithm was created.</p>
     #[serde(rename = "creationDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date_time: Option<f64>,
+    pub creation_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Specifies the default hyperparameters, their ranges, and whether they are tunable. A tunable hyperparameter can have its value determined during hyperparameter optimization (HPO).</p>
     #[serde(rename = "defaultHyperParameterRanges")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -54,8 +59,13 @@ pub struct Algorithm {
     pub default_resource_config: Option<::std::collections::HashMap<String, String>>,
     /// <p>The date and time (in Unix time) that the algorithm was last updated.</p>
     #[serde(rename = "lastUpdatedDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date_time: Option<f64>,
+    pub last_updated_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the algorithm.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -116,8 +126,13 @@ pub struct BatchInferenceJob {
     pub batch_inference_job_arn: Option<String>,
     /// <p>The time at which the batch inference job was created.</p>
     #[serde(rename = "creationDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date_time: Option<f64>,
+    pub creation_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>If the batch inference job failed, the reason for the failure.</p>
     #[serde(rename = "failureReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -136,8 +151,13 @@ pub struct BatchInferenceJob {
     pub job_output: Option<BatchInferenceJobOutput>,
     /// <p>The time at which the batch inference job was last updated.</p>
     #[serde(rename = "lastUpdatedDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date_time: Option<f64>,
+    pub last_updated_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The number of recommendations generated by the batch inference job. This number includes the error messages generated for failed input records.</p>
     #[serde(rename = "numResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -182,8 +202,13 @@ pub struct BatchInferenceJobSummary {
     pub batch_inference_job_arn: Option<String>,
     /// <p>The time at which the batch inference job was created.</p>
     #[serde(rename = "creationDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date_time: Option<f64>,
+    pub creation_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>If the batch inference job failed, the reason for the failure.</p>
     #[serde(rename = "failureReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -194,8 +219,13 @@ pub struct BatchInferenceJobSummary {
     pub job_name: Option<String>,
     /// <p>The time at which the batch inference job was last updated.</p>
     #[serde(rename = "lastUpdatedDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date_time: Option<f64>,
+    pub last_updated_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>The status of the batch inference job. The status is one of the following values:</p> <ul> <li> <p>PENDING</p> </li> <li> <p>IN PROGRESS</p> </li> <li> <p>ACTIVE</p> </li> <li> <p>CREATE FAILED</p> </li> </ul></p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -212,16 +242,26 @@ pub struct Campaign {
     pub campaign_arn: Option<String>,
     /// <p>The date and time (in Unix format) that the campaign was created.</p>
     #[serde(rename = "creationDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date_time: Option<f64>,
+    pub creation_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>If a campaign fails, the reason behind the failure.</p>
     #[serde(rename = "failureReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub failure_reason: Option<String>,
     /// <p>The date and time (in Unix format) that the campaign was last updated.</p>
     #[serde(rename = "lastUpdatedDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date_time: Option<f64>,
+    pub last_updated_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     #[serde(rename = "latestCampaignUpdate")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub latest_campaign_update: Option<CampaignUpdateSummary>,
@@ -253,16 +293,26 @@ pub struct CampaignSummary {
     pub campaign_arn: Option<String>,
     /// <p>The date and time (in Unix time) that the campaign was created.</p>
     #[serde(rename = "creationDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date_time: Option<f64>,
+    pub creation_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>If a campaign fails, the reason behind the failure.</p>
     #[serde(rename = "failureReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub failure_reason: Option<String>,
     /// <p>The date and time (in Unix time) that the campaign was last updated.</p>
     #[serde(rename = "lastUpdatedDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date_time: Option<f64>,
+    pub last_updated_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the campaign.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -279,16 +329,26 @@ pub struct CampaignSummary {
 pub struct CampaignUpdateSummary {
     /// <p>The date and time (in Unix time) that the campaign update was created.</p>
     #[serde(rename = "creationDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date_time: Option<f64>,
+    pub creation_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>If a campaign update fails, the reason behind the failure.</p>
     #[serde(rename = "failureReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub failure_reason: Option<String>,
     /// <p>The date and time (in Unix time) that the campaign update was last updated.</p>
     #[serde(rename = "lastUpdatedDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date_time: Option<f64>,
+    pub last_updated_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Specifies the requested minimum provisioned transactions (recommendations) per second that Amazon Personalize will support.</p>
     #[serde(rename = "minProvisionedTPS")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -559,7 +619,7 @@ pub struct CreateSolutionVersionRequest {
     /// <p><p>The scope of training to be performed when creating the solution version. The <code>FULL</code> option trains the solution version based on the entirety of the input solution&#39;s training data, while the <code>UPDATE</code> option processes only the data that has changed in comparison to the input solution. Choose <code>UPDATE</code> when you want to incrementally update your solution version instead of creating an entirely new one.</p> <important> <p>The <code>UPDATE</code> option can only be used when you already have an active solution version created from the input solution using the <code>FULL</code> option and the input solution was trained with the <a>native-recipe-hrnn-coldstart</a> recipe.</p> </important></p>
     #[serde(rename = "trainingMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub training_mode: Option<String>,
+    pub training_mode: Option<TrainingMode>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -586,8 +646,13 @@ pub struct DataSource {
 pub struct Dataset {
     /// <p>The creation date and time (in Unix time) of the dataset.</p>
     #[serde(rename = "creationDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date_time: Option<f64>,
+    pub creation_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The Amazon Resource Name (ARN) of the dataset that you want metadata for.</p>
     #[serde(rename = "datasetArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -602,8 +667,13 @@ pub struct Dataset {
     pub dataset_type: Option<String>,
     /// <p>A time stamp that shows when the dataset was updated.</p>
     #[serde(rename = "lastUpdatedDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date_time: Option<f64>,
+    pub last_updated_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the dataset.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -624,8 +694,13 @@ pub struct Dataset {
 pub struct DatasetGroup {
     /// <p>The creation date and time (in Unix time) of the dataset group.</p>
     #[serde(rename = "creationDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date_time: Option<f64>,
+    pub creation_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The Amazon Resource Name (ARN) of the dataset group.</p>
     #[serde(rename = "datasetGroupArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -640,8 +715,13 @@ pub struct DatasetGroup {
     pub kms_key_arn: Option<String>,
     /// <p>The last update date and time (in Unix time) of the dataset group.</p>
     #[serde(rename = "lastUpdatedDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date_time: Option<f64>,
+    pub last_updated_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the dataset group.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -662,8 +742,13 @@ pub struct DatasetGroup {
 pub struct DatasetGroupSummary {
     /// <p>The date and time (in Unix time) that the dataset group was created.</p>
     #[serde(rename = "creationDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date_time: Option<f64>,
+    pub creation_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The Amazon Resource Name (ARN) of the dataset group.</p>
     #[serde(rename = "datasetGroupArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -674,8 +759,13 @@ pub struct DatasetGroupSummary {
     pub failure_reason: Option<String>,
     /// <p>The date and time (in Unix time) that the dataset group was last updated.</p>
     #[serde(rename = "lastUpdatedDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date_time: Option<f64>,
+    pub last_updated_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the dataset group.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -692,8 +782,13 @@ pub struct DatasetGroupSummary {
 pub struct DatasetImportJob {
     /// <p>The creation date and time (in Unix time) of the dataset import job.</p>
     #[serde(rename = "creationDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date_time: Option<f64>,
+    pub creation_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The Amazon S3 bucket that contains the training data to import.</p>
     #[serde(rename = "dataSource")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -716,8 +811,13 @@ pub struct DatasetImportJob {
     pub job_name: Option<String>,
     /// <p>The date and time (in Unix time) the dataset was last updated.</p>
     #[serde(rename = "lastUpdatedDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date_time: Option<f64>,
+    pub last_updated_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The ARN of the AWS Identity and Access Management (IAM) role that has permissions to read from the Amazon S3 data source.</p>
     #[serde(rename = "roleArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -734,8 +834,13 @@ pub struct DatasetImportJob {
 pub struct DatasetImportJobSummary {
     /// <p>The date and time (in Unix time) that the dataset import job was created.</p>
     #[serde(rename = "creationDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date_time: Option<f64>,
+    pub creation_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The Amazon Resource Name (ARN) of the dataset import job.</p>
     #[serde(rename = "datasetImportJobArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -750,8 +855,13 @@ pub struct DatasetImportJobSummary {
     pub job_name: Option<String>,
     /// <p>The date and time (in Unix time) that the dataset was last updated.</p>
     #[serde(rename = "lastUpdatedDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date_time: Option<f64>,
+    pub last_updated_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>The status of the dataset import job.</p> <p>A dataset import job can be in one of the following states:</p> <ul> <li> <p>CREATE PENDING &gt; CREATE IN_PROGRESS &gt; ACTIVE -or- CREATE FAILED</p> </li> </ul></p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -764,12 +874,22 @@ pub struct DatasetImportJobSummary {
 pub struct DatasetSchema {
     /// <p>The date and time (in Unix time) that the schema was created.</p>
     #[serde(rename = "creationDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date_time: Option<f64>,
+    pub creation_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The date and time (in Unix time) that the schema was last updated.</p>
     #[serde(rename = "lastUpdatedDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date_time: Option<f64>,
+    pub last_updated_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the schema.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -790,12 +910,22 @@ pub struct DatasetSchema {
 pub struct DatasetSchemaSummary {
     /// <p>The date and time (in Unix time) that the schema was created.</p>
     #[serde(rename = "creationDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date_time: Option<f64>,
+    pub creation_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The date and time (in Unix time) that the schema was last updated.</p>
     #[serde(rename = "lastUpdatedDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date_time: Option<f64>,
+    pub last_updated_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the schema.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -812,8 +942,13 @@ pub struct DatasetSchemaSummary {
 pub struct DatasetSummary {
     /// <p>The date and time (in Unix time) that the dataset was created.</p>
     #[serde(rename = "creationDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date_time: Option<f64>,
+    pub creation_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The Amazon Resource Name (ARN) of the dataset.</p>
     #[serde(rename = "datasetArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -824,8 +959,13 @@ pub struct DatasetSummary {
     pub dataset_type: Option<String>,
     /// <p>The date and time (in Unix time) that the dataset was last updated.</p>
     #[serde(rename = "lastUpdatedDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date_time: Option<f64>,
+    pub last_updated_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the dataset.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1178,8 +1318,13 @@ pub struct EventTracker {
     pub account_id: Option<String>,
     /// <p>The date and time (in Unix format) that the event tracker was created.</p>
     #[serde(rename = "creationDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date_time: Option<f64>,
+    pub creation_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The Amazon Resource Name (ARN) of the dataset group that receives the event data.</p>
     #[serde(rename = "datasetGroupArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1190,8 +1335,13 @@ pub struct EventTracker {
     pub event_tracker_arn: Option<String>,
     /// <p>The date and time (in Unix time) that the event tracker was last updated.</p>
     #[serde(rename = "lastUpdatedDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date_time: Option<f64>,
+    pub last_updated_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the event tracker.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1212,16 +1362,26 @@ pub struct EventTracker {
 pub struct EventTrackerSummary {
     /// <p>The date and time (in Unix time) that the event tracker was created.</p>
     #[serde(rename = "creationDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date_time: Option<f64>,
+    pub creation_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The Amazon Resource Name (ARN) of the event tracker.</p>
     #[serde(rename = "eventTrackerArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub event_tracker_arn: Option<String>,
     /// <p>The date and time (in Unix time) that the event tracker was last updated.</p>
     #[serde(rename = "lastUpdatedDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date_time: Option<f64>,
+    pub last_updated_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the event tracker.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1238,8 +1398,13 @@ pub struct EventTrackerSummary {
 pub struct FeatureTransformation {
     /// <p>The creation date and time (in Unix time) of the feature transformation.</p>
     #[serde(rename = "creationDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date_time: Option<f64>,
+    pub creation_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Provides the default parameters for feature transformation.</p>
     #[serde(rename = "defaultParameters")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1250,8 +1415,13 @@ pub struct FeatureTransformation {
     pub feature_transformation_arn: Option<String>,
     /// <p>The last update date and time (in Unix time) of the feature transformation.</p>
     #[serde(rename = "lastUpdatedDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date_time: Option<f64>,
+    pub last_updated_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the feature transformation.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1554,7 +1724,7 @@ pub struct ListRecipesRequest {
     /// <p>The default is <code>SERVICE</code>.</p>
     #[serde(rename = "recipeProvider")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub recipe_provider: Option<String>,
+    pub recipe_provider: Option<RecipeProvider>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1666,8 +1836,13 @@ pub struct Recipe {
     pub algorithm_arn: Option<String>,
     /// <p>The date and time (in Unix format) that the recipe was created.</p>
     #[serde(rename = "creationDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date_time: Option<f64>,
+    pub creation_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The description of the recipe.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1678,8 +1853,13 @@ pub struct Recipe {
     pub feature_transformation_arn: Option<String>,
     /// <p>The date and time (in Unix format) that the recipe was last updated.</p>
     #[serde(rename = "lastUpdatedDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date_time: Option<f64>,
+    pub last_updated_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the recipe.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1698,18 +1878,116 @@ pub struct Recipe {
     pub status: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RecipeProvider {
+    /// `SERVICE`
+    Service,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RecipeProvider {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RecipeProvider::Service => "SERVICE",
+            RecipeProvider::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RecipeProvider {
+    fn default() -> RecipeProvider {
+        RecipeProvider::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RecipeProvider {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RecipeProvider {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RecipeProvider {
+    fn from(value: &'a str) -> RecipeProvider {
+        match value {
+            "SERVICE" => RecipeProvider::Service,
+            _ => RecipeProvider::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RecipeProvider {
+    fn from(value: String) -> RecipeProvider {
+        match value.as_str() {
+            "SERVICE" => RecipeProvider::Service,
+            _ => RecipeProvider::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RecipeProvider {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RecipeProvider, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RecipeProvider {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RecipeProvider {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RecipeProvider {
+    fn deserialize<D>(deserializer: D) -> Result<RecipeProvider, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Provides a summary of the properties of a recipe. For a complete listing, call the <a>DescribeRecipe</a> API.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RecipeSummary {
     /// <p>The date and time (in Unix time) that the recipe was created.</p>
     #[serde(rename = "creationDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date_time: Option<f64>,
+    pub creation_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The date and time (in Unix time) that the recipe was last updated.</p>
     #[serde(rename = "lastUpdatedDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date_time: Option<f64>,
+    pub last_updated_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the recipe.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1746,8 +2024,13 @@ pub struct Solution {
     pub auto_ml_result: Option<AutoMLResult>,
     /// <p>The creation date and time (in Unix time) of the solution.</p>
     #[serde(rename = "creationDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date_time: Option<f64>,
+    pub creation_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The Amazon Resource Name (ARN) of the dataset group that provides the training data.</p>
     #[serde(rename = "datasetGroupArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1758,8 +2041,13 @@ pub struct Solution {
     pub event_type: Option<String>,
     /// <p>The date and time (in Unix time) that the solution was last updated.</p>
     #[serde(rename = "lastUpdatedDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date_time: Option<f64>,
+    pub last_updated_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Describes the latest version of the solution, including the status and the ARN.</p>
     #[serde(rename = "latestSolutionVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1825,12 +2113,22 @@ pub struct SolutionConfig {
 pub struct SolutionSummary {
     /// <p>The date and time (in Unix time) that the solution was created.</p>
     #[serde(rename = "creationDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date_time: Option<f64>,
+    pub creation_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The date and time (in Unix time) that the solution was last updated.</p>
     #[serde(rename = "lastUpdatedDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date_time: Option<f64>,
+    pub last_updated_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the solution.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1851,8 +2149,13 @@ pub struct SolutionSummary {
 pub struct SolutionVersion {
     /// <p>The date and time (in Unix time) that this version of the solution was created.</p>
     #[serde(rename = "creationDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date_time: Option<f64>,
+    pub creation_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The Amazon Resource Name (ARN) of the dataset group providing the training data.</p>
     #[serde(rename = "datasetGroupArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1867,8 +2170,13 @@ pub struct SolutionVersion {
     pub failure_reason: Option<String>,
     /// <p>The date and time (in Unix time) that the solution was last updated.</p>
     #[serde(rename = "lastUpdatedDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date_time: Option<f64>,
+    pub last_updated_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>When true, Amazon Personalize searches for the most optimal recipe according to the solution configuration. When false (the default), Amazon Personalize uses <code>recipeArn</code>.</p>
     #[serde(rename = "performAutoML")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1904,7 +2212,7 @@ pub struct SolutionVersion {
     /// <p><p>The scope of training used to create the solution version. The <code>FULL</code> option trains the solution version based on the entirety of the input solution&#39;s training data, while the <code>UPDATE</code> option processes only the training data that has changed since the creation of the last solution version. Choose <code>UPDATE</code> when you want to start recommending items added to the dataset without retraining the model.</p> <important> <p>The <code>UPDATE</code> option can only be used after you&#39;ve created a solution version with the <code>FULL</code> option and the training solution uses the <a>native-recipe-hrnn-coldstart</a>.</p> </important></p>
     #[serde(rename = "trainingMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub training_mode: Option<String>,
+    pub training_mode: Option<TrainingMode>,
 }
 
 /// <p>Provides a summary of the properties of a solution version. For a complete listing, call the <a>DescribeSolutionVersion</a> API.</p>
@@ -1913,16 +2221,26 @@ pub struct SolutionVersion {
 pub struct SolutionVersionSummary {
     /// <p>The date and time (in Unix time) that this version of a solution was created.</p>
     #[serde(rename = "creationDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date_time: Option<f64>,
+    pub creation_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>If a solution version fails, the reason behind the failure.</p>
     #[serde(rename = "failureReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub failure_reason: Option<String>,
     /// <p>The date and time (in Unix time) that the solution version was last updated.</p>
     #[serde(rename = "lastUpdatedDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date_time: Option<f64>,
+    pub last_updated_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The Amazon Resource Name (ARN) of the solution version.</p>
     #[serde(rename = "solutionVersionArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1933,6 +2251,99 @@ pub struct SolutionVersionSummary {
     pub status: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TrainingMode {
+    /// `FULL`
+    Full,
+    /// `UPDATE`
+    Update,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TrainingMode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TrainingMode::Full => "FULL",
+            TrainingMode::Update => "UPDATE",
+            TrainingMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TrainingMode {
+    fn default() -> TrainingMode {
+        TrainingMode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TrainingMode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TrainingMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TrainingMode {
+    fn from(value: &'a str) -> TrainingMode {
+        match value {
+            "FULL" => TrainingMode::Full,
+            "UPDATE" => TrainingMode::Update,
+            _ => TrainingMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TrainingMode {
+    fn from(value: String) -> TrainingMode {
+        match value.as_str() {
+            "FULL" => TrainingMode::Full,
+            "UPDATE" => TrainingMode::Update,
+            _ => TrainingMode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TrainingMode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TrainingMode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TrainingMode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TrainingMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TrainingMode {
+    fn deserialize<D>(deserializer: D) -> Result<TrainingMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct UpdateCampaignRequest {
@@ -3836,11 +4247,12 @@ pub struct PersonalizeClient {
 impl PersonalizeClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> PersonalizeClient {
         PersonalizeClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Personalize"),
         }
     }
 
@@ -3872,6 +4284,7 @@ impl Personalize for PersonalizeClient {
         input: CreateBatchInferenceJobRequest,
     ) -> Result<CreateBatchInferenceJobResponse, RusotoError<CreateBatchInferenceJobError>> {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("CreateBatchInferenceJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.CreateBatchInferenceJob");
@@ -3900,6 +4313,7 @@ impl Personalize for PersonalizeClient {
         input: CreateCampaignRequest,
     ) -> Result<CreateCampaignResponse, RusotoError<CreateCampaignError>> {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("CreateCampaign");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.CreateCampaign");
@@ -3927,6 +4341,7 @@ impl Personalize for PersonalizeClient {
         input: CreateDatasetRequest,
     ) -> Result<CreateDatasetResponse, RusotoError<CreateDatasetError>> {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("CreateDataset");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.CreateDataset");
@@ -3954,6 +4369,7 @@ impl Personalize for PersonalizeClient {
         input: CreateDatasetGroupRequest,
     ) -> Result<CreateDatasetGroupResponse, RusotoError<CreateDatasetGroupError>> {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("CreateDatasetGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.CreateDatasetGroup");
@@ -3982,6 +4398,7 @@ impl Personalize for PersonalizeClient {
         input: CreateDatasetImportJobRequest,
     ) -> Result<CreateDatasetImportJobResponse, RusotoError<CreateDatasetImportJobError>> {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("CreateDatasetImportJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.CreateDatasetImportJob");
@@ -4010,6 +4427,7 @@ impl Personalize for PersonalizeClient {
         input: CreateEventTrackerRequest,
     ) -> Result<CreateEventTrackerResponse, RusotoError<CreateEventTrackerError>> {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("CreateEventTracker");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.CreateEventTracker");
@@ -4038,6 +4456,7 @@ impl Personalize for PersonalizeClient {
         input: CreateSchemaRequest,
     ) -> Result<CreateSchemaResponse, RusotoError<CreateSchemaError>> {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("CreateSchema");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.CreateSchema");
@@ -4065,6 +4484,7 @@ impl Personalize for PersonalizeClient {
         input: CreateSolutionRequest,
     ) -> Result<CreateSolutionResponse, RusotoError<CreateSolutionError>> {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("CreateSolution");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.CreateSolution");
@@ -4092,6 +4512,7 @@ impl Personalize for PersonalizeClient {
         input: CreateSolutionVersionRequest,
     ) -> Result<CreateSolutionVersionResponse, RusotoError<CreateSolutionVersionError>> {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("CreateSolutionVersion");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.CreateSolutionVersion");
@@ -4120,6 +4541,7 @@ impl Personalize for PersonalizeClient {
         input: DeleteCampaignRequest,
     ) -> Result<(), RusotoError<DeleteCampaignError>> {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("DeleteCampaign");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.DeleteCampaign");
@@ -4147,6 +4569,7 @@ impl Personalize for PersonalizeClient {
         input: DeleteDatasetRequest,
     ) -> Result<(), RusotoError<DeleteDatasetError>> {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("DeleteDataset");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.DeleteDataset");
@@ -4174,6 +4597,7 @@ impl Personalize for PersonalizeClient {
         input: DeleteDatasetGroupRequest,
     ) -> Result<(), RusotoError<DeleteDatasetGroupError>> {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("DeleteDatasetGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.DeleteDatasetGroup");
@@ -4201,6 +4625,7 @@ impl Personalize for PersonalizeClient {
         input: DeleteEventTrackerRequest,
     ) -> Result<(), RusotoError<DeleteEventTrackerError>> {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("DeleteEventTracker");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.DeleteEventTracker");
@@ -4228,6 +4653,7 @@ impl Personalize for PersonalizeClient {
         input: DeleteSchemaRequest,
     ) -> Result<(), RusotoError<DeleteSchemaError>> {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("DeleteSchema");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.DeleteSchema");
@@ -4255,6 +4681,7 @@ impl Personalize for PersonalizeClient {
         input: DeleteSolutionRequest,
     ) -> Result<(), RusotoError<DeleteSolutionError>> {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("DeleteSolution");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.DeleteSolution");
@@ -4282,6 +4709,7 @@ impl Personalize for PersonalizeClient {
         input: DescribeAlgorithmRequest,
     ) -> Result<DescribeAlgorithmResponse, RusotoError<DescribeAlgorithmError>> {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("DescribeAlgorithm");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.DescribeAlgorithm");
@@ -4311,6 +4739,7 @@ impl Personalize for PersonalizeClient {
     ) -> Result<DescribeBatchInferenceJobResponse, RusotoError<DescribeBatchInferenceJobError>>
     {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("DescribeBatchInferenceJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4342,6 +4771,7 @@ impl Personalize for PersonalizeClient {
         input: DescribeCampaignRequest,
     ) -> Result<DescribeCampaignResponse, RusotoError<DescribeCampaignError>> {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("DescribeCampaign");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.DescribeCampaign");
@@ -4370,6 +4800,7 @@ impl Personalize for PersonalizeClient {
         input: DescribeDatasetRequest,
     ) -> Result<DescribeDatasetResponse, RusotoError<DescribeDatasetError>> {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("DescribeDataset");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.DescribeDataset");
@@ -4397,6 +4828,7 @@ impl Personalize for PersonalizeClient {
         input: DescribeDatasetGroupRequest,
     ) -> Result<DescribeDatasetGroupResponse, RusotoError<DescribeDatasetGroupError>> {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("DescribeDatasetGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.DescribeDatasetGroup");
@@ -4425,6 +4857,7 @@ impl Personalize for PersonalizeClient {
         input: DescribeDatasetImportJobRequest,
     ) -> Result<DescribeDatasetImportJobResponse, RusotoError<DescribeDatasetImportJobError>> {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("DescribeDatasetImportJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.DescribeDatasetImportJob");
@@ -4453,6 +4886,7 @@ impl Personalize for PersonalizeClient {
         input: DescribeEventTrackerRequest,
     ) -> Result<DescribeEventTrackerResponse, RusotoError<DescribeEventTrackerError>> {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("DescribeEventTracker");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.DescribeEventTracker");
@@ -4484,6 +4918,7 @@ impl Personalize for PersonalizeClient {
         RusotoError<DescribeFeatureTransformationError>,
     > {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("DescribeFeatureTransformation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4515,6 +4950,7 @@ impl Personalize for PersonalizeClient {
         input: DescribeRecipeRequest,
     ) -> Result<DescribeRecipeResponse, RusotoError<DescribeRecipeError>> {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("DescribeRecipe");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.DescribeRecipe");
@@ -4542,6 +4978,7 @@ impl Personalize for PersonalizeClient {
         input: DescribeSchemaRequest,
     ) -> Result<DescribeSchemaResponse, RusotoError<DescribeSchemaError>> {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("DescribeSchema");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.DescribeSchema");
@@ -4569,6 +5006,7 @@ impl Personalize for PersonalizeClient {
         input: DescribeSolutionRequest,
     ) -> Result<DescribeSolutionResponse, RusotoError<DescribeSolutionError>> {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("DescribeSolution");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.DescribeSolution");
@@ -4597,6 +5035,7 @@ impl Personalize for PersonalizeClient {
         input: DescribeSolutionVersionRequest,
     ) -> Result<DescribeSolutionVersionResponse, RusotoError<DescribeSolutionVersionError>> {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("DescribeSolutionVersion");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.DescribeSolutionVersion");
@@ -4625,6 +5064,7 @@ impl Personalize for PersonalizeClient {
         input: GetSolutionMetricsRequest,
     ) -> Result<GetSolutionMetricsResponse, RusotoError<GetSolutionMetricsError>> {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("GetSolutionMetrics");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.GetSolutionMetrics");
@@ -4653,6 +5093,7 @@ impl Personalize for PersonalizeClient {
         input: ListBatchInferenceJobsRequest,
     ) -> Result<ListBatchInferenceJobsResponse, RusotoError<ListBatchInferenceJobsError>> {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("ListBatchInferenceJobs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.ListBatchInferenceJobs");
@@ -4681,6 +5122,7 @@ impl Personalize for PersonalizeClient {
         input: ListCampaignsRequest,
     ) -> Result<ListCampaignsResponse, RusotoError<ListCampaignsError>> {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("ListCampaigns");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.ListCampaigns");
@@ -4708,6 +5150,7 @@ impl Personalize for PersonalizeClient {
         input: ListDatasetGroupsRequest,
     ) -> Result<ListDatasetGroupsResponse, RusotoError<ListDatasetGroupsError>> {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("ListDatasetGroups");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.ListDatasetGroups");
@@ -4736,6 +5179,7 @@ impl Personalize for PersonalizeClient {
         input: ListDatasetImportJobsRequest,
     ) -> Result<ListDatasetImportJobsResponse, RusotoError<ListDatasetImportJobsError>> {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("ListDatasetImportJobs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.ListDatasetImportJobs");
@@ -4764,6 +5208,7 @@ impl Personalize for PersonalizeClient {
         input: ListDatasetsRequest,
     ) -> Result<ListDatasetsResponse, RusotoError<ListDatasetsError>> {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("ListDatasets");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.ListDatasets");
@@ -4791,6 +5236,7 @@ impl Personalize for PersonalizeClient {
         input: ListEventTrackersRequest,
     ) -> Result<ListEventTrackersResponse, RusotoError<ListEventTrackersError>> {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("ListEventTrackers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.ListEventTrackers");
@@ -4819,6 +5265,7 @@ impl Personalize for PersonalizeClient {
         input: ListRecipesRequest,
     ) -> Result<ListRecipesResponse, RusotoError<ListRecipesError>> {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("ListRecipes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.ListRecipes");
@@ -4846,6 +5293,7 @@ impl Personalize for PersonalizeClient {
         input: ListSchemasRequest,
     ) -> Result<ListSchemasResponse, RusotoError<ListSchemasError>> {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("ListSchemas");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.ListSchemas");
@@ -4873,6 +5321,7 @@ impl Personalize for PersonalizeClient {
         input: ListSolutionVersionsRequest,
     ) -> Result<ListSolutionVersionsResponse, RusotoError<ListSolutionVersionsError>> {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("ListSolutionVersions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.ListSolutionVersions");
@@ -4901,6 +5350,7 @@ impl Personalize for PersonalizeClient {
         input: ListSolutionsRequest,
     ) -> Result<ListSolutionsResponse, RusotoError<ListSolutionsError>> {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("ListSolutions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.ListSolutions");
@@ -4928,6 +5378,7 @@ impl Personalize for PersonalizeClient {
         input: UpdateCampaignRequest,
     ) -> Result<UpdateCampaignResponse, RusotoError<UpdateCampaignError>> {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("UpdateCampaign");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.UpdateCampaign");
@@ -4949,3 +5400,330 @@ impl Personalize for PersonalizeClient {
         }
     }
 }
+impl PersonalizeClient {
+    /// Auto-paginating version of `list_batch_inference_jobs`, streaming every page of results.
+    pub fn list_batch_inference_jobs_pages(
+        &self,
+        input: ListBatchInferenceJobsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListBatchInferenceJobsResponse,
+        ListBatchInferenceJobsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: PersonalizeClient, input: ListBatchInferenceJobsRequest| async move {
+                client.list_batch_inference_jobs(input).await
+            },
+            |input: &ListBatchInferenceJobsRequest, output: &ListBatchInferenceJobsResponse| {
+                Some(ListBatchInferenceJobsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_batch_inference_jobs`, streaming the `batchInferenceJobs` of every page of results.
+    pub fn list_batch_inference_jobs_items(
+        &self,
+        input: ListBatchInferenceJobsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<BatchInferenceJobSummary, ListBatchInferenceJobsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_batch_inference_jobs_pages(input),
+            |output: ListBatchInferenceJobsResponse| {
+                output.batch_inference_jobs.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_campaigns`, streaming every page of results.
+    pub fn list_campaigns_pages(
+        &self,
+        input: ListCampaignsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListCampaignsResponse, ListCampaignsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: PersonalizeClient, input: ListCampaignsRequest| async move {
+                client.list_campaigns(input).await
+            },
+            |input: &ListCampaignsRequest, output: &ListCampaignsResponse| {
+                Some(ListCampaignsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_campaigns`, streaming the `campaigns` of every page of results.
+    pub fn list_campaigns_items(
+        &self,
+        input: ListCampaignsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<CampaignSummary, ListCampaignsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_campaigns_pages(input),
+            |output: ListCampaignsResponse| output.campaigns.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_dataset_groups`, streaming every page of results.
+    pub fn list_dataset_groups_pages(
+        &self,
+        input: ListDatasetGroupsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListDatasetGroupsResponse, ListDatasetGroupsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: PersonalizeClient, input: ListDatasetGroupsRequest| async move {
+                client.list_dataset_groups(input).await
+            },
+            |input: &ListDatasetGroupsRequest, output: &ListDatasetGroupsResponse| {
+                Some(ListDatasetGroupsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_dataset_groups`, streaming the `datasetGroups` of every page of results.
+    pub fn list_dataset_groups_items(
+        &self,
+        input: ListDatasetGroupsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DatasetGroupSummary, ListDatasetGroupsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_dataset_groups_pages(input),
+            |output: ListDatasetGroupsResponse| output.dataset_groups.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_dataset_import_jobs`, streaming every page of results.
+    pub fn list_dataset_import_jobs_pages(
+        &self,
+        input: ListDatasetImportJobsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListDatasetImportJobsResponse,
+        ListDatasetImportJobsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: PersonalizeClient, input: ListDatasetImportJobsRequest| async move {
+                client.list_dataset_import_jobs(input).await
+            },
+            |input: &ListDatasetImportJobsRequest, output: &ListDatasetImportJobsResponse| {
+                Some(ListDatasetImportJobsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_dataset_import_jobs`, streaming the `datasetImportJobs` of every page of results.
+    pub fn list_dataset_import_jobs_items(
+        &self,
+        input: ListDatasetImportJobsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DatasetImportJobSummary, ListDatasetImportJobsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_dataset_import_jobs_pages(input),
+            |output: ListDatasetImportJobsResponse| output.dataset_import_jobs.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_datasets`, streaming every page of results.
+    pub fn list_datasets_pages(
+        &self,
+        input: ListDatasetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListDatasetsResponse, ListDatasetsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: PersonalizeClient, input: ListDatasetsRequest| async move {
+                client.list_datasets(input).await
+            },
+            |input: &ListDatasetsRequest, output: &ListDatasetsResponse| {
+                Some(ListDatasetsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_datasets`, streaming the `datasets` of every page of results.
+    pub fn list_datasets_items(
+        &self,
+        input: ListDatasetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DatasetSummary, ListDatasetsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_datasets_pages(input),
+            |output: ListDatasetsResponse| output.datasets.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_event_trackers`, streaming every page of results.
+    pub fn list_event_trackers_pages(
+        &self,
+        input: ListEventTrackersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListEventTrackersResponse, ListEventTrackersError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: PersonalizeClient, input: ListEventTrackersRequest| async move {
+                client.list_event_trackers(input).await
+            },
+            |input: &ListEventTrackersRequest, output: &ListEventTrackersResponse| {
+                Some(ListEventTrackersRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_event_trackers`, streaming the `eventTrackers` of every page of results.
+    pub fn list_event_trackers_items(
+        &self,
+        input: ListEventTrackersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<EventTrackerSummary, ListEventTrackersError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_event_trackers_pages(input),
+            |output: ListEventTrackersResponse| output.event_trackers.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_recipes`, streaming every page of results.
+    pub fn list_recipes_pages(
+        &self,
+        input: ListRecipesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListRecipesResponse, ListRecipesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: PersonalizeClient, input: ListRecipesRequest| async move {
+                client.list_recipes(input).await
+            },
+            |input: &ListRecipesRequest, output: &ListRecipesResponse| {
+                Some(ListRecipesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_recipes`, streaming the `recipes` of every page of results.
+    pub fn list_recipes_items(
+        &self,
+        input: ListRecipesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<RecipeSummary, ListRecipesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_recipes_pages(input),
+            |output: ListRecipesResponse| output.recipes.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_schemas`, streaming every page of results.
+    pub fn list_schemas_pages(
+        &self,
+        input: ListSchemasRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListSchemasResponse, ListSchemasError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: PersonalizeClient, input: ListSchemasRequest| async move {
+                client.list_schemas(input).await
+            },
+            |input: &ListSchemasRequest, output: &ListSchemasResponse| {
+                Some(ListSchemasRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_schemas`, streaming the `schemas` of every page of results.
+    pub fn list_schemas_items(
+        &self,
+        input: ListSchemasRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DatasetSchemaSummary, ListSchemasError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_schemas_pages(input),
+            |output: ListSchemasResponse| output.schemas.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_solution_versions`, streaming every page of results.
+    pub fn list_solution_versions_pages(
+        &self,
+        input: ListSolutionVersionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListSolutionVersionsResponse,
+        ListSolutionVersionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: PersonalizeClient, input: ListSolutionVersionsRequest| async move {
+                client.list_solution_versions(input).await
+            },
+            |input: &ListSolutionVersionsRequest, output: &ListSolutionVersionsResponse| {
+                Some(ListSolutionVersionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_solution_versions`, streaming the `solutionVersions` of every page of results.
+    pub fn list_solution_versions_items(
+        &self,
+        input: ListSolutionVersionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<SolutionVersionSummary, ListSolutionVersionsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_solution_versions_pages(input),
+            |output: ListSolutionVersionsResponse| output.solution_versions.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_solutions`, streaming every page of results.
+    pub fn list_solutions_pages(
+        &self,
+        input: ListSolutionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListSolutionsResponse, ListSolutionsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: PersonalizeClient, input: ListSolutionsRequest| async move {
+                client.list_solutions(input).await
+            },
+            |input: &ListSolutionsRequest, output: &ListSolutionsResponse| {
+                Some(ListSolutionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_solutions`, streaming the `solutions` of every page of results.
+    pub fn list_solutions_items(
+        &self,
+        input: ListSolutionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<SolutionSummary, ListSolutionsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_solutions_pages(input),
+            |output: ListSolutionsResponse| output.solutions.unwrap_or_default(),
+        )
+    }
+}
@@ -30,7 +30,12 @@ use serde_json;
 pub struct DataPoint {
     /// <p>The time, in epoch format, associated with a particular <code>Value</code>.</p>
     #[serde(rename = "Timestamp")]
-    pub timestamp: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub timestamp: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The actual value associated with a particular <code>Timestamp</code>.</p>
     #[serde(rename = "Value")]
     pub value: f64,
@@ -41,7 +46,12 @@ pub struct DataPoint {
 pub struct DescribeDimensionKeysRequest {
     /// <p>The date and time specifying the end of the requested time series data. The value specified is <i>exclusive</i> - data points less than (but not equal to) <code>EndTime</code> will be returned.</p> <p>The value for <code>EndTime</code> must be later than the value for <code>StartTime</code>.</p>
     #[serde(rename = "EndTime")]
-    pub end_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub end_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p><p>One or more filters to apply in the request. Restrictions:</p> <ul> <li> <p>Any number of filters by the same dimension, as specified in the <code>GroupBy</code> or <code>Partition</code> parameters.</p> </li> <li> <p>A single filter for any other dimension in this dimension group.</p> </li> </ul></p>
     #[serde(rename = "Filter")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -73,10 +83,15 @@ pub struct DescribeDimensionKeysRequest {
     pub period_in_seconds: Option<i64>,
     /// <p>The AWS service for which Performance Insights will return metrics. The only valid value for <i>ServiceType</i> is: <code>RDS</code> </p>
     #[serde(rename = "ServiceType")]
-    pub service_type: String,
+    pub service_type: ServiceType,
     /// <p>The date and time specifying the beginning of the requested time series data. You can't specify a <code>StartTime</code> that's earlier than 7 days ago. The value specified is <i>inclusive</i> - data points equal to or greater than <code>StartTime</code> will be returned.</p> <p>The value for <code>StartTime</code> must be earlier than the value for <code>EndTime</code>.</p>
     #[serde(rename = "StartTime")]
-    pub start_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub start_time: ::chrono::DateTime<::chrono::Utc>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -84,12 +99,22 @@ pub struct DescribeDimensionKeysRequest {
 pub struct DescribeDimensionKeysResponse {
     /// <p>The end time for the returned dimension keys, after alignment to a granular boundary (as specified by <code>PeriodInSeconds</code>). <code>AlignedEndTime</code> will be greater than or equal to the value of the user-specified <code>Endtime</code>.</p>
     #[serde(rename = "AlignedEndTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub aligned_end_time: Option<f64>,
+    pub aligned_end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The start time for the returned dimension keys, after alignment to a granular boundary (as specified by <code>PeriodInSeconds</code>). <code>AlignedStartTime</code> will be less than or equal to the value of the user-specified <code>StartTime</code>.</p>
     #[serde(rename = "AlignedStartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub aligned_start_time: Option<f64>,
+    pub aligned_start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The dimension keys that were requested.</p>
     #[serde(rename = "Keys")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -144,7 +169,12 @@ pub struct DimensionKeyDescription {
 pub struct GetResourceMetricsRequest {
     /// <p>The date and time specifiying the end of the requested time series data. The value specified is <i>exclusive</i> - data points less than (but not equal to) <code>EndTime</code> will be returned.</p> <p>The value for <code>EndTime</code> must be later than the value for <code>StartTime</code>.</p>
     #[serde(rename = "EndTime")]
-    pub end_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub end_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>An immutable, AWS Region-unique identifier for a data source. Performance Insights gathers metrics from this data source.</p> <p>To use an Amazon RDS instance as a data source, you specify its <code>DbiResourceId</code> value - for example: <code>db-FAIHNTYBKTGAUSUZQYPDS2GW4A</code> </p>
     #[serde(rename = "Identifier")]
     pub identifier: String,
@@ -165,10 +195,15 @@ pub struct GetResourceMetricsRequest {
     pub period_in_seconds: Option<i64>,
     /// <p>The AWS service for which Performance Insights will return metrics. The only valid value for <i>ServiceType</i> is: <code>RDS</code> </p>
     #[serde(rename = "ServiceType")]
-    pub service_type: String,
+    pub service_type: ServiceType,
     /// <p>The date and time specifying the beginning of the requested time series data. You can't specify a <code>StartTime</code> that's earlier than 7 days ago. The value specified is <i>inclusive</i> - data points equal to or greater than <code>StartTime</code> will be returned.</p> <p>The value for <code>StartTime</code> must be earlier than the value for <code>EndTime</code>.</p>
     #[serde(rename = "StartTime")]
-    pub start_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub start_time: ::chrono::DateTime<::chrono::Utc>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -176,12 +211,22 @@ pub struct GetResourceMetricsRequest {
 pub struct GetResourceMetricsResponse {
     /// <p>The end time for the returned metrics, after alignment to a granular boundary (as specified by <code>PeriodInSeconds</code>). <code>AlignedEndTime</code> will be greater than or equal to the value of the user-specified <code>Endtime</code>.</p>
     #[serde(rename = "AlignedEndTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub aligned_end_time: Option<f64>,
+    pub aligned_end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The start time for the returned metrics, after alignment to a granular boundary (as specified by <code>PeriodInSeconds</code>). <code>AlignedStartTime</code> will be less than or equal to the value of the user-specified <code>StartTime</code>.</p>
     #[serde(rename = "AlignedStartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub aligned_start_time: Option<f64>,
+    pub aligned_start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>An immutable, AWS Region-unique identifier for a data source. Performance Insights gathers metrics from this data source.</p> <p>To use an Amazon RDS instance as a data source, you specify its <code>DbiResourceId</code> value - for example: <code>db-FAIHNTYBKTGAUSUZQYPDS2GW4A</code> </p>
     #[serde(rename = "Identifier")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -249,6 +294,99 @@ pub struct ResponseResourceMetricKey {
     pub metric: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ServiceType {
+    /// `RDS`
+    Rds,
+    /// `DOCDB`
+    Docdb,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ServiceType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ServiceType::Rds => "RDS",
+            ServiceType::Docdb => "DOCDB",
+            ServiceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ServiceType {
+    fn default() -> ServiceType {
+        ServiceType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ServiceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ServiceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ServiceType {
+    fn from(value: &'a str) -> ServiceType {
+        match value {
+            "RDS" => ServiceType::Rds,
+            "DOCDB" => ServiceType::Docdb,
+            _ => ServiceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ServiceType {
+    fn from(value: String) -> ServiceType {
+        match value.as_str() {
+            "RDS" => ServiceType::Rds,
+            "DOCDB" => ServiceType::Docdb,
+            _ => ServiceType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ServiceType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ServiceType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ServiceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ServiceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ServiceType {
+    fn deserialize<D>(deserializer: D) -> Result<ServiceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// Errors returned by DescribeDimensionKeys
 #[derive(Debug, PartialEq)]
 pub enum DescribeDimensionKeysError {
@@ -364,11 +502,12 @@ pub struct PerformanceInsightsClient {
 impl PerformanceInsightsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> PerformanceInsightsClient {
         PerformanceInsightsClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "PI"),
         }
     }
 
@@ -400,6 +539,7 @@ impl PerformanceInsights for PerformanceInsightsClient {
         input: DescribeDimensionKeysRequest,
     ) -> Result<DescribeDimensionKeysResponse, RusotoError<DescribeDimensionKeysError>> {
         let mut request = SignedRequest::new("POST", "pi", &self.region, "/");
+        request.set_operation("DescribeDimensionKeys");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -431,6 +571,7 @@ impl PerformanceInsights for PerformanceInsightsClient {
         input: GetResourceMetricsRequest,
     ) -> Result<GetResourceMetricsResponse, RusotoError<GetResourceMetricsError>> {
         let mut request = SignedRequest::new("POST", "pi", &self.region, "/");
+        request.set_operation("GetResourceMetrics");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -25,6 +25,100 @@ use rusoto_core::signature::SignedRequest;
 #[allow(unused_imports)]
 use serde::{Deserialize, Serialize};
 use serde_json;
+/// <p>The action that you want Amazon Pinpoint to take if it can't read the required MX record for a custom MAIL FROM domain. When you set this value to <code>UseDefaultValue</code>, Amazon Pinpoint uses <i>amazonses.com</i> as the MAIL FROM domain. When you set this value to <code>RejectMessage</code>, Amazon Pinpoint returns a <code>MailFromDomainNotVerified</code> error, and doesn't attempt to deliver the email.</p> <p>These behaviors are taken when the custom MAIL FROM domain configuration is in the <code>Pending</code>, <code>Failed</code>, and <code>TemporaryFailure</code> states.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BehaviorOnMxFailure {
+    /// `USE_DEFAULT_VALUE`
+    UseDefaultValue,
+    /// `REJECT_MESSAGE`
+    RejectMessage,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl BehaviorOnMxFailure {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BehaviorOnMxFailure::UseDefaultValue => "USE_DEFAULT_VALUE",
+            BehaviorOnMxFailure::RejectMessage => "REJECT_MESSAGE",
+            BehaviorOnMxFailure::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for BehaviorOnMxFailure {
+    fn default() -> BehaviorOnMxFailure {
+        BehaviorOnMxFailure::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for BehaviorOnMxFailure {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for BehaviorOnMxFailure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for BehaviorOnMxFailure {
+    fn from(value: &'a str) -> BehaviorOnMxFailure {
+        match value {
+            "USE_DEFAULT_VALUE" => BehaviorOnMxFailure::UseDefaultValue,
+            "REJECT_MESSAGE" => BehaviorOnMxFailure::RejectMessage,
+            _ => BehaviorOnMxFailure::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BehaviorOnMxFailure {
+    fn from(value: String) -> BehaviorOnMxFailure {
+        match value.as_str() {
+            "USE_DEFAULT_VALUE" => BehaviorOnMxFailure::UseDefaultValue,
+            "REJECT_MESSAGE" => BehaviorOnMxFailure::RejectMessage,
+            _ => BehaviorOnMxFailure::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for BehaviorOnMxFailure {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<BehaviorOnMxFailure, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for BehaviorOnMxFailure {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for BehaviorOnMxFailure {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BehaviorOnMxFailure {
+    fn deserialize<D>(deserializer: D) -> Result<BehaviorOnMxFailure, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An object that contains information about a blacklisting event that impacts one of the dedicated IP addresses that is associated with your account.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -35,8 +129,13 @@ pub struct BlacklistEntry {
     pub description: Option<String>,
     /// <p>The time when the blacklisting event occurred, shown in Unix time format.</p>
     #[serde(rename = "ListingTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub listing_time: Option<f64>,
+    pub listing_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the blacklist that the IP address appears on.</p>
     #[serde(rename = "RblName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -76,7 +175,7 @@ pub struct CloudWatchDimensionConfiguration {
     pub dimension_name: String,
     /// <p>The location where Amazon Pinpoint finds the value of a dimension to publish to Amazon CloudWatch. If you want Amazon Pinpoint to use the message tags that you specify using an X-SES-MESSAGE-TAGS header or a parameter to the SendEmail/SendRawEmail API, choose <code>messageTag</code>. If you want Amazon Pinpoint to use your own email headers, choose <code>emailHeader</code>. If you want Amazon Pinpoint to use link tags, choose <code>linkTags</code>.</p>
     #[serde(rename = "DimensionValueSource")]
-    pub dimension_value_source: String,
+    pub dimension_value_source: DimensionValueSource,
 }
 
 /// <p>An object that represents the content of the email, and optionally a character set specification.</p>
@@ -190,7 +289,7 @@ pub struct CreateDeliverabilityTestReportRequest {
 pub struct CreateDeliverabilityTestReportResponse {
     /// <p>The status of the predictive inbox placement test. If the status is <code>IN_PROGRESS</code>, then the predictive inbox placement test is currently running. Predictive inbox placement tests are usually complete within 24 hours of creating the test. If the status is <code>COMPLETE</code>, then the test is finished, and you can use the <code>GetDeliverabilityTestReport</code> to view the results of the test.</p>
     #[serde(rename = "DeliverabilityTestStatus")]
-    pub deliverability_test_status: String,
+    pub deliverability_test_status: DeliverabilityTestStatus,
     /// <p>A unique string that identifies the predictive inbox placement test.</p>
     #[serde(rename = "ReportId")]
     pub report_id: String,
@@ -220,7 +319,7 @@ pub struct CreateEmailIdentityResponse {
     /// <p>The email identity type.</p>
     #[serde(rename = "IdentityType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub identity_type: Option<String>,
+    pub identity_type: Option<IdentityType>,
     /// <p>Specifies whether or not the identity is verified. In Amazon Pinpoint, you can only send email from verified email addresses or domains. For more information about verifying identities, see the <a href="https://docs.aws.amazon.com/pinpoint/latest/userguide/channels-email-manage-verify.html">Amazon Pinpoint User Guide</a>.</p>
     #[serde(rename = "VerifiedForSendingStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -237,8 +336,13 @@ pub struct DailyVolume {
     pub domain_isp_placements: Option<Vec<DomainIspPlacement>>,
     /// <p>The date that the DailyVolume metrics apply to, in Unix time.</p>
     #[serde(rename = "StartDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_date: Option<f64>,
+    pub start_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>An object that contains inbox placement metrics for a specific day in the analysis period.</p>
     #[serde(rename = "VolumeStatistics")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -261,7 +365,7 @@ pub struct DedicatedIp {
     pub warmup_percentage: i64,
     /// <p><p>The warm-up status of a dedicated IP address. The status can have one of the following values:</p> <ul> <li> <p> <code>IN_PROGRESS</code> – The IP address isn&#39;t ready to use because the dedicated IP warm-up process is ongoing.</p> </li> <li> <p> <code>DONE</code> – The dedicated IP warm-up process is complete, and the IP address is ready to use.</p> </li> </ul></p>
     #[serde(rename = "WarmupStatus")]
-    pub warmup_status: String,
+    pub warmup_status: WarmupStatus,
 }
 
 /// <p>A request to delete an event destination from a configuration set.</p>
@@ -323,18 +427,122 @@ pub struct DeleteEmailIdentityRequest {
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteEmailIdentityResponse {}
 
+/// <p>The current status of your Deliverability dashboard subscription. If this value is <code>PENDING_EXPIRATION</code>, your subscription is scheduled to expire at the end of the current calendar month.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DeliverabilityDashboardAccountStatus {
+    /// `ACTIVE`
+    Active,
+    /// `PENDING_EXPIRATION`
+    PendingExpiration,
+    /// `DISABLED`
+    Disabled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DeliverabilityDashboardAccountStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeliverabilityDashboardAccountStatus::Active => "ACTIVE",
+            DeliverabilityDashboardAccountStatus::PendingExpiration => "PENDING_EXPIRATION",
+            DeliverabilityDashboardAccountStatus::Disabled => "DISABLED",
+            DeliverabilityDashboardAccountStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DeliverabilityDashboardAccountStatus {
+    fn default() -> DeliverabilityDashboardAccountStatus {
+        DeliverabilityDashboardAccountStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DeliverabilityDashboardAccountStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DeliverabilityDashboardAccountStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DeliverabilityDashboardAccountStatus {
+    fn from(value: &'a str) -> DeliverabilityDashboardAccountStatus {
+        match value {
+            "ACTIVE" => DeliverabilityDashboardAccountStatus::Active,
+            "PENDING_EXPIRATION" => DeliverabilityDashboardAccountStatus::PendingExpiration,
+            "DISABLED" => DeliverabilityDashboardAccountStatus::Disabled,
+            _ => DeliverabilityDashboardAccountStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeliverabilityDashboardAccountStatus {
+    fn from(value: String) -> DeliverabilityDashboardAccountStatus {
+        match value.as_str() {
+            "ACTIVE" => DeliverabilityDashboardAccountStatus::Active,
+            "PENDING_EXPIRATION" => DeliverabilityDashboardAccountStatus::PendingExpiration,
+            "DISABLED" => DeliverabilityDashboardAccountStatus::Disabled,
+            _ => DeliverabilityDashboardAccountStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DeliverabilityDashboardAccountStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DeliverabilityDashboardAccountStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DeliverabilityDashboardAccountStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DeliverabilityDashboardAccountStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeliverabilityDashboardAccountStatus {
+    fn deserialize<D>(deserializer: D) -> Result<DeliverabilityDashboardAccountStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An object that contains metadata related to a predictive inbox placement test.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeliverabilityTestReport {
     /// <p>The date and time when the predictive inbox placement test was created, in Unix time format.</p>
     #[serde(rename = "CreateDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub create_date: Option<f64>,
+    pub create_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The status of the predictive inbox placement test. If the status is <code>IN_PROGRESS</code>, then the predictive inbox placement test is currently running. Predictive inbox placement tests are usually complete within 24 hours of creating the test. If the status is <code>COMPLETE</code>, then the test is finished, and you can use the <code>GetDeliverabilityTestReport</code> to view the results of the test.</p>
     #[serde(rename = "DeliverabilityTestStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deliverability_test_status: Option<String>,
+    pub deliverability_test_status: Option<DeliverabilityTestStatus>,
     /// <p>The sender address that you specified for the predictive inbox placement test.</p>
     #[serde(rename = "FromEmailAddress")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -353,6 +561,100 @@ pub struct DeliverabilityTestReport {
     pub subject: Option<String>,
 }
 
+/// <p>The status of a predictive inbox placement test. If the status is <code>IN_PROGRESS</code>, then the predictive inbox placement test is currently running. Predictive inbox placement tests are usually complete within 24 hours of creating the test. If the status is <code>COMPLETE</code>, then the test is finished, and you can use the <code>GetDeliverabilityTestReport</code> operation to view the results of the test.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DeliverabilityTestStatus {
+    /// `IN_PROGRESS`
+    InProgress,
+    /// `COMPLETED`
+    Completed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DeliverabilityTestStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeliverabilityTestStatus::InProgress => "IN_PROGRESS",
+            DeliverabilityTestStatus::Completed => "COMPLETED",
+            DeliverabilityTestStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DeliverabilityTestStatus {
+    fn default() -> DeliverabilityTestStatus {
+        DeliverabilityTestStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DeliverabilityTestStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DeliverabilityTestStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DeliverabilityTestStatus {
+    fn from(value: &'a str) -> DeliverabilityTestStatus {
+        match value {
+            "IN_PROGRESS" => DeliverabilityTestStatus::InProgress,
+            "COMPLETED" => DeliverabilityTestStatus::Completed,
+            _ => DeliverabilityTestStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeliverabilityTestStatus {
+    fn from(value: String) -> DeliverabilityTestStatus {
+        match value.as_str() {
+            "IN_PROGRESS" => DeliverabilityTestStatus::InProgress,
+            "COMPLETED" => DeliverabilityTestStatus::Completed,
+            _ => DeliverabilityTestStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DeliverabilityTestStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DeliverabilityTestStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DeliverabilityTestStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DeliverabilityTestStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeliverabilityTestStatus {
+    fn deserialize<D>(deserializer: D) -> Result<DeliverabilityTestStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Used to associate a configuration set with a dedicated IP pool.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DeliveryOptions {
@@ -363,7 +665,7 @@ pub struct DeliveryOptions {
     /// <p>Specifies whether messages that use the configuration set are required to use Transport Layer Security (TLS). If the value is <code>Require</code>, messages are only delivered if a TLS connection can be established. If the value is <code>Optional</code>, messages can be delivered in plain text if a TLS connection can't be established.</p>
     #[serde(rename = "TlsPolicy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub tls_policy: Option<String>,
+    pub tls_policy: Option<TlsPolicy>,
 }
 
 /// <p>An object that describes the recipients for an email.</p>
@@ -384,6 +686,105 @@ pub struct Destination {
     pub to_addresses: Option<Vec<String>>,
 }
 
+/// <p>The location where Amazon Pinpoint finds the value of a dimension to publish to Amazon CloudWatch. If you want Amazon Pinpoint to use the message tags that you specify using an X-SES-MESSAGE-TAGS header or a parameter to the SendEmail/SendRawEmail API, choose <code>messageTag</code>. If you want Amazon Pinpoint to use your own email headers, choose <code>emailHeader</code>. If you want Amazon Pinpoint to use link tags, choose <code>linkTags</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DimensionValueSource {
+    /// `MESSAGE_TAG`
+    MessageTag,
+    /// `EMAIL_HEADER`
+    EmailHeader,
+    /// `LINK_TAG`
+    LinkTag,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DimensionValueSource {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DimensionValueSource::MessageTag => "MESSAGE_TAG",
+            DimensionValueSource::EmailHeader => "EMAIL_HEADER",
+            DimensionValueSource::LinkTag => "LINK_TAG",
+            DimensionValueSource::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DimensionValueSource {
+    fn default() -> DimensionValueSource {
+        DimensionValueSource::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DimensionValueSource {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DimensionValueSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DimensionValueSource {
+    fn from(value: &'a str) -> DimensionValueSource {
+        match value {
+            "MESSAGE_TAG" => DimensionValueSource::MessageTag,
+            "EMAIL_HEADER" => DimensionValueSource::EmailHeader,
+            "LINK_TAG" => DimensionValueSource::LinkTag,
+            _ => DimensionValueSource::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DimensionValueSource {
+    fn from(value: String) -> DimensionValueSource {
+        match value.as_str() {
+            "MESSAGE_TAG" => DimensionValueSource::MessageTag,
+            "EMAIL_HEADER" => DimensionValueSource::EmailHeader,
+            "LINK_TAG" => DimensionValueSource::LinkTag,
+            _ => DimensionValueSource::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DimensionValueSource {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DimensionValueSource, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DimensionValueSource {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DimensionValueSource {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DimensionValueSource {
+    fn deserialize<D>(deserializer: D) -> Result<DimensionValueSource, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An object that contains information about the DKIM configuration for an email identity.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -395,13 +796,122 @@ pub struct DkimAttributes {
     /// <p><p>Describes whether or not Amazon Pinpoint has successfully located the DKIM records in the DNS records for the domain. The status can be one of the following:</p> <ul> <li> <p> <code>PENDING</code> – Amazon Pinpoint hasn&#39;t yet located the DKIM records in the DNS configuration for the domain, but will continue to attempt to locate them.</p> </li> <li> <p> <code>SUCCESS</code> – Amazon Pinpoint located the DKIM records in the DNS configuration for the domain and determined that they&#39;re correct. Amazon Pinpoint can now send DKIM-signed email from the identity.</p> </li> <li> <p> <code>FAILED</code> – Amazon Pinpoint was unable to locate the DKIM records in the DNS settings for the domain, and won&#39;t continue to search for them.</p> </li> <li> <p> <code>TEMPORARY<em>FAILURE</code> – A temporary issue occurred, which prevented Amazon Pinpoint from determining the DKIM status for the domain.</p> </li> <li> <p> <code>NOT</em>STARTED</code> – Amazon Pinpoint hasn&#39;t yet started searching for the DKIM records in the DKIM records for the domain.</p> </li> </ul></p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<DkimStatus>,
     /// <p>A set of unique strings that you use to create a set of CNAME records that you add to the DNS configuration for your domain. When Amazon Pinpoint detects these records in the DNS configuration for your domain, the DKIM authentication process is complete. Amazon Pinpoint usually detects these records within about 72 hours of adding them to the DNS configuration for your domain.</p>
     #[serde(rename = "Tokens")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tokens: Option<Vec<String>>,
 }
 
+/// <p>The DKIM authentication status of the identity. The status can be one of the following:</p> <ul> <li> <p> <code>PENDING</code> – The DKIM verification process was initiated, and Amazon Pinpoint is still waiting for the required CNAME records to appear in the DNS configuration for the domain.</p> </li> <li> <p> <code>SUCCESS</code> – The DKIM authentication process completed successfully.</p> </li> <li> <p> <code>FAILED</code> – The DKIM authentication process failed. This can happen when Amazon Pinpoint fails to find the required CNAME records in the DNS configuration of the domain.</p> </li> <li> <p> <code>TEMPORARY_FAILURE</code> – A temporary issue is preventing Amazon Pinpoint from determining the DKIM authentication status of the domain.</p> </li> <li> <p> <code>NOT_STARTED</code> – The DKIM verification process hasn't been initiated for the domain.</p> </li> </ul>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DkimStatus {
+    /// `PENDING`
+    Pending,
+    /// `SUCCESS`
+    Success,
+    /// `FAILED`
+    Failed,
+    /// `TEMPORARY_FAILURE`
+    TemporaryFailure,
+    /// `NOT_STARTED`
+    NotStarted,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DkimStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DkimStatus::Pending => "PENDING",
+            DkimStatus::Success => "SUCCESS",
+            DkimStatus::Failed => "FAILED",
+            DkimStatus::TemporaryFailure => "TEMPORARY_FAILURE",
+            DkimStatus::NotStarted => "NOT_STARTED",
+            DkimStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DkimStatus {
+    fn default() -> DkimStatus {
+        DkimStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DkimStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DkimStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DkimStatus {
+    fn from(value: &'a str) -> DkimStatus {
+        match value {
+            "PENDING" => DkimStatus::Pending,
+            "SUCCESS" => DkimStatus::Success,
+            "FAILED" => DkimStatus::Failed,
+            "TEMPORARY_FAILURE" => DkimStatus::TemporaryFailure,
+            "NOT_STARTED" => DkimStatus::NotStarted,
+            _ => DkimStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DkimStatus {
+    fn from(value: String) -> DkimStatus {
+        match value.as_str() {
+            "PENDING" => DkimStatus::Pending,
+            "SUCCESS" => DkimStatus::Success,
+            "FAILED" => DkimStatus::Failed,
+            "TEMPORARY_FAILURE" => DkimStatus::TemporaryFailure,
+            "NOT_STARTED" => DkimStatus::NotStarted,
+            _ => DkimStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DkimStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DkimStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DkimStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DkimStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DkimStatus {
+    fn deserialize<D>(deserializer: D) -> Result<DkimStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An object that contains the deliverability data for a specific campaign. This data is available for a campaign only if the campaign sent email by using a domain that the Deliverability dashboard is enabled for (<code>PutDeliverabilityDashboardOption</code> operation).</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -420,8 +930,13 @@ pub struct DomainDeliverabilityCampaign {
     pub esps: Option<Vec<String>>,
     /// <p>The first time, in Unix time format, when the email message was delivered to any recipient's inbox. This value can help you determine how long it took for a campaign to deliver an email message.</p>
     #[serde(rename = "FirstSeenDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub first_seen_date_time: Option<f64>,
+    pub first_seen_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The verified email address that the email message was sent from.</p>
     #[serde(rename = "FromAddress")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -436,8 +951,13 @@ pub struct DomainDeliverabilityCampaign {
     pub inbox_count: Option<i64>,
     /// <p>The last time, in Unix time format, when the email message was delivered to any recipient's inbox. This value can help you determine how long it took for a campaign to deliver an email message.</p>
     #[serde(rename = "LastSeenDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_seen_date_time: Option<f64>,
+    pub last_seen_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The projected number of recipients that the email message was sent to.</p>
     #[serde(rename = "ProjectedVolume")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -477,8 +997,13 @@ pub struct DomainDeliverabilityTrackingOption {
     pub inbox_placement_tracking_option: Option<InboxPlacementTrackingOption>,
     /// <p>The date, in Unix time format, when you enabled the Deliverability dashboard for the domain.</p>
     #[serde(rename = "SubscriptionStartDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub subscription_start_date: Option<f64>,
+    pub subscription_start_date: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>An object that contains inbox placement data for email sent from one of your email domains to a specific email provider.</p>
@@ -543,7 +1068,7 @@ pub struct EventDestination {
     pub kinesis_firehose_destination: Option<KinesisFirehoseDestination>,
     /// <p>The types of events that Amazon Pinpoint sends to the specified event destinations.</p>
     #[serde(rename = "MatchingEventTypes")]
-    pub matching_event_types: Vec<String>,
+    pub matching_event_types: Vec<EventType>,
     /// <p>A name that identifies the event destination.</p>
     #[serde(rename = "Name")]
     pub name: String,
@@ -576,7 +1101,7 @@ pub struct EventDestinationDefinition {
     /// <p>An array that specifies which events Amazon Pinpoint should send to the destinations in this <code>EventDestinationDefinition</code>.</p>
     #[serde(rename = "MatchingEventTypes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub matching_event_types: Option<Vec<String>>,
+    pub matching_event_types: Option<Vec<EventType>>,
     /// <p>An object that defines a Amazon Pinpoint destination for email events. You can use Amazon Pinpoint events to create attributes in Amazon Pinpoint projects. You can use these attributes to create segments for your campaigns.</p>
     #[serde(rename = "PinpointDestination")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -587,6 +1112,130 @@ pub struct EventDestinationDefinition {
     pub sns_destination: Option<SnsDestination>,
 }
 
+/// <p>An email sending event type. For example, email sends, opens, and bounces are all email events.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EventType {
+    /// `SEND`
+    Send,
+    /// `REJECT`
+    Reject,
+    /// `BOUNCE`
+    Bounce,
+    /// `COMPLAINT`
+    Complaint,
+    /// `DELIVERY`
+    Delivery,
+    /// `OPEN`
+    Open,
+    /// `CLICK`
+    Click,
+    /// `RENDERING_FAILURE`
+    RenderingFailure,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EventType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EventType::Send => "SEND",
+            EventType::Reject => "REJECT",
+            EventType::Bounce => "BOUNCE",
+            EventType::Complaint => "COMPLAINT",
+            EventType::Delivery => "DELIVERY",
+            EventType::Open => "OPEN",
+            EventType::Click => "CLICK",
+            EventType::RenderingFailure => "RENDERING_FAILURE",
+            EventType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EventType {
+    fn default() -> EventType {
+        EventType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EventType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EventType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EventType {
+    fn from(value: &'a str) -> EventType {
+        match value {
+            "SEND" => EventType::Send,
+            "REJECT" => EventType::Reject,
+            "BOUNCE" => EventType::Bounce,
+            "COMPLAINT" => EventType::Complaint,
+            "DELIVERY" => EventType::Delivery,
+            "OPEN" => EventType::Open,
+            "CLICK" => EventType::Click,
+            "RENDERING_FAILURE" => EventType::RenderingFailure,
+            _ => EventType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EventType {
+    fn from(value: String) -> EventType {
+        match value.as_str() {
+            "SEND" => EventType::Send,
+            "REJECT" => EventType::Reject,
+            "BOUNCE" => EventType::Bounce,
+            "COMPLAINT" => EventType::Complaint,
+            "DELIVERY" => EventType::Delivery,
+            "OPEN" => EventType::Open,
+            "CLICK" => EventType::Click,
+            "RENDERING_FAILURE" => EventType::RenderingFailure,
+            _ => EventType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EventType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EventType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EventType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EventType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EventType {
+    fn deserialize<D>(deserializer: D) -> Result<EventType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A request to obtain information about the email-sending capabilities of your Amazon Pinpoint account.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -757,7 +1406,7 @@ pub struct GetDeliverabilityDashboardOptionsResponse {
     /// <p>The current status of your Deliverability dashboard subscription. If this value is <code>PENDING_EXPIRATION</code>, your subscription is scheduled to expire at the end of the current calendar month.</p>
     #[serde(rename = "AccountStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub account_status: Option<String>,
+    pub account_status: Option<DeliverabilityDashboardAccountStatus>,
     /// <p>An array of objects, one for each verified domain that you use to send email and currently has an active Deliverability dashboard subscription that isn’t scheduled to expire at the end of the current calendar month.</p>
     #[serde(rename = "ActiveSubscribedDomains")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -771,8 +1420,13 @@ pub struct GetDeliverabilityDashboardOptionsResponse {
     pub pending_expiration_subscribed_domains: Option<Vec<DomainDeliverabilityTrackingOption>>,
     /// <p>The date, in Unix time format, when your current subscription to the Deliverability dashboard is scheduled to expire, if your subscription is scheduled to expire at the end of the current calendar month. This value is null if you have an active subscription that isn’t due to expire at the end of the month.</p>
     #[serde(rename = "SubscriptionExpiryDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub subscription_expiry_date: Option<f64>,
+    pub subscription_expiry_date: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>A request to retrieve the results of a predictive inbox placement test.</p>
@@ -834,10 +1488,20 @@ pub struct GetDomainStatisticsReportRequest {
     pub domain: String,
     /// <p>The last day (in Unix time) that you want to obtain domain deliverability metrics for. The <code>EndDate</code> that you specify has to be less than or equal to 30 days after the <code>StartDate</code>.</p>
     #[serde(rename = "EndDate")]
-    pub end_date: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
+    pub end_date: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The first day (in Unix time) that you want to obtain domain deliverability metrics for.</p>
     #[serde(rename = "StartDate")]
-    pub start_date: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
+    pub start_date: ::chrono::DateTime<::chrono::Utc>,
 }
 
 /// <p>An object that includes statistics that are related to the domain that you specified.</p>
@@ -876,7 +1540,7 @@ pub struct GetEmailIdentityResponse {
     /// <p>The email identity type.</p>
     #[serde(rename = "IdentityType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub identity_type: Option<String>,
+    pub identity_type: Option<IdentityType>,
     /// <p>An object that contains information about the Mail-From attributes for the email identity.</p>
     #[serde(rename = "MailFromAttributes")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -902,13 +1566,112 @@ pub struct IdentityInfo {
     /// <p><p>The email identity type. The identity type can be one of the following:</p> <ul> <li> <p> <code>EMAIL<em>ADDRESS</code> – The identity is an email address.</p> </li> <li> <p> <code>DOMAIN</code> – The identity is a domain.</p> </li> <li> <p> <code>MANAGED</em>DOMAIN</code> – The identity is a domain that is managed by AWS.</p> </li> </ul></p>
     #[serde(rename = "IdentityType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub identity_type: Option<String>,
+    pub identity_type: Option<IdentityType>,
     /// <p>Indicates whether or not you can send email from the identity.</p> <p>In Amazon Pinpoint, an identity is an email address or domain that you send email from. Before you can send email from an identity, you have to demostrate that you own the identity, and that you authorize Amazon Pinpoint to send email from that identity.</p>
     #[serde(rename = "SendingEnabled")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sending_enabled: Option<bool>,
 }
 
+/// <p>The email identity type. The identity type can be one of the following:</p> <ul> <li> <p> <code>EMAIL_ADDRESS</code> – The identity is an email address.</p> </li> <li> <p> <code>DOMAIN</code> – The identity is a domain.</p> </li> </ul>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum IdentityType {
+    /// `EMAIL_ADDRESS`
+    EmailAddress,
+    /// `DOMAIN`
+    Domain,
+    /// `MANAGED_DOMAIN`
+    ManagedDomain,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl IdentityType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            IdentityType::EmailAddress => "EMAIL_ADDRESS",
+            IdentityType::Domain => "DOMAIN",
+            IdentityType::ManagedDomain => "MANAGED_DOMAIN",
+            IdentityType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for IdentityType {
+    fn default() -> IdentityType {
+        IdentityType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for IdentityType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for IdentityType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for IdentityType {
+    fn from(value: &'a str) -> IdentityType {
+        match value {
+            "EMAIL_ADDRESS" => IdentityType::EmailAddress,
+            "DOMAIN" => IdentityType::Domain,
+            "MANAGED_DOMAIN" => IdentityType::ManagedDomain,
+            _ => IdentityType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for IdentityType {
+    fn from(value: String) -> IdentityType {
+        match value.as_str() {
+            "EMAIL_ADDRESS" => IdentityType::EmailAddress,
+            "DOMAIN" => IdentityType::Domain,
+            "MANAGED_DOMAIN" => IdentityType::ManagedDomain,
+            _ => IdentityType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for IdentityType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<IdentityType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for IdentityType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for IdentityType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for IdentityType {
+    fn deserialize<D>(deserializer: D) -> Result<IdentityType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An object that contains information about the inbox placement data settings for a verified domain that’s associated with your AWS account. This data is available only if you enabled the Deliverability dashboard for the domain (<code>PutDeliverabilityDashboardOption</code> operation).</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct InboxPlacementTrackingOption {
@@ -1036,7 +1799,12 @@ pub struct ListDeliverabilityTestReportsResponse {
 pub struct ListDomainDeliverabilityCampaignsRequest {
     /// <p>The last day, in Unix time format, that you want to obtain deliverability data for. This value has to be less than or equal to 30 days after the value of the <code>StartDate</code> parameter.</p>
     #[serde(rename = "EndDate")]
-    pub end_date: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
+    pub end_date: ::chrono::DateTime<::chrono::Utc>,
     /// <p>A token that’s returned from a previous call to the <code>ListDomainDeliverabilityCampaigns</code> operation. This token indicates the position of a campaign in the list of campaigns.</p>
     #[serde(rename = "NextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1047,7 +1815,12 @@ pub struct ListDomainDeliverabilityCampaignsRequest {
     pub page_size: Option<i64>,
     /// <p>The first day, in Unix time format, that you want to obtain deliverability data for.</p>
     #[serde(rename = "StartDate")]
-    pub start_date: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
+    pub start_date: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The domain to obtain deliverability data for.</p>
     #[serde(rename = "SubscribedDomain")]
     pub subscribed_domain: String,
@@ -1116,15 +1889,119 @@ pub struct ListTagsForResourceResponse {
 pub struct MailFromAttributes {
     /// <p>The action that Amazon Pinpoint to takes if it can't read the required MX record for a custom MAIL FROM domain. When you set this value to <code>UseDefaultValue</code>, Amazon Pinpoint uses <i>amazonses.com</i> as the MAIL FROM domain. When you set this value to <code>RejectMessage</code>, Amazon Pinpoint returns a <code>MailFromDomainNotVerified</code> error, and doesn't attempt to deliver the email.</p> <p>These behaviors are taken when the custom MAIL FROM domain configuration is in the <code>Pending</code>, <code>Failed</code>, and <code>TemporaryFailure</code> states.</p>
     #[serde(rename = "BehaviorOnMxFailure")]
-    pub behavior_on_mx_failure: String,
+    pub behavior_on_mx_failure: BehaviorOnMxFailure,
     /// <p>The name of a domain that an email identity uses as a custom MAIL FROM domain.</p>
     #[serde(rename = "MailFromDomain")]
     pub mail_from_domain: String,
     /// <p><p>The status of the MAIL FROM domain. This status can have the following values:</p> <ul> <li> <p> <code>PENDING</code> – Amazon Pinpoint hasn&#39;t started searching for the MX record yet.</p> </li> <li> <p> <code>SUCCESS</code> – Amazon Pinpoint detected the required MX record for the MAIL FROM domain.</p> </li> <li> <p> <code>FAILED</code> – Amazon Pinpoint can&#39;t find the required MX record, or the record no longer exists.</p> </li> <li> <p> <code>TEMPORARY_FAILURE</code> – A temporary issue occurred, which prevented Amazon Pinpoint from determining the status of the MAIL FROM domain.</p> </li> </ul></p>
     #[serde(rename = "MailFromDomainStatus")]
-    pub mail_from_domain_status: String,
+    pub mail_from_domain_status: MailFromDomainStatus,
+}
+
+/// <p>The status of the MAIL FROM domain. This status can have the following values:</p> <ul> <li> <p> <code>PENDING</code> – Amazon Pinpoint hasn't started searching for the MX record yet.</p> </li> <li> <p> <code>SUCCESS</code> – Amazon Pinpoint detected the required MX record for the MAIL FROM domain.</p> </li> <li> <p> <code>FAILED</code> – Amazon Pinpoint can't find the required MX record, or the record no longer exists.</p> </li> <li> <p> <code>TEMPORARY_FAILURE</code> – A temporary issue occurred, which prevented Amazon Pinpoint from determining the status of the MAIL FROM domain.</p> </li> </ul>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MailFromDomainStatus {
+    /// `PENDING`
+    Pending,
+    /// `SUCCESS`
+    Success,
+    /// `FAILED`
+    Failed,
+    /// `TEMPORARY_FAILURE`
+    TemporaryFailure,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl MailFromDomainStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MailFromDomainStatus::Pending => "PENDING",
+            MailFromDomainStatus::Success => "SUCCESS",
+            MailFromDomainStatus::Failed => "FAILED",
+            MailFromDomainStatus::TemporaryFailure => "TEMPORARY_FAILURE",
+            MailFromDomainStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for MailFromDomainStatus {
+    fn default() -> MailFromDomainStatus {
+        MailFromDomainStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for MailFromDomainStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
 }
 
+impl fmt::Display for MailFromDomainStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for MailFromDomainStatus {
+    fn from(value: &'a str) -> MailFromDomainStatus {
+        match value {
+            "PENDING" => MailFromDomainStatus::Pending,
+            "SUCCESS" => MailFromDomainStatus::Success,
+            "FAILED" => MailFromDomainStatus::Failed,
+            "TEMPORARY_FAILURE" => MailFromDomainStatus::TemporaryFailure,
+            _ => MailFromDomainStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MailFromDomainStatus {
+    fn from(value: String) -> MailFromDomainStatus {
+        match value.as_str() {
+            "PENDING" => MailFromDomainStatus::Pending,
+            "SUCCESS" => MailFromDomainStatus::Success,
+            "FAILED" => MailFromDomainStatus::Failed,
+            "TEMPORARY_FAILURE" => MailFromDomainStatus::TemporaryFailure,
+            _ => MailFromDomainStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for MailFromDomainStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<MailFromDomainStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MailFromDomainStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for MailFromDomainStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MailFromDomainStatus {
+    fn deserialize<D>(deserializer: D) -> Result<MailFromDomainStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents the email message that you're sending. The <code>Message</code> object consists of a subject line and a message body.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -1246,7 +2123,7 @@ pub struct PutConfigurationSetDeliveryOptionsRequest {
     /// <p>Specifies whether messages that use the configuration set are required to use Transport Layer Security (TLS). If the value is <code>Require</code>, messages are only delivered if a TLS connection can be established. If the value is <code>Optional</code>, messages can be delivered in plain text if a TLS connection can't be established.</p>
     #[serde(rename = "TlsPolicy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub tls_policy: Option<String>,
+    pub tls_policy: Option<TlsPolicy>,
 }
 
 /// <p>An HTTP 200 response if the request succeeds, or an error message if the request fails.</p>
@@ -1403,7 +2280,7 @@ pub struct PutEmailIdentityMailFromAttributesRequest {
     /// <p>The action that you want Amazon Pinpoint to take if it can't read the required MX record when you send an email. When you set this value to <code>UseDefaultValue</code>, Amazon Pinpoint uses <i>amazonses.com</i> as the MAIL FROM domain. When you set this value to <code>RejectMessage</code>, Amazon Pinpoint returns a <code>MailFromDomainNotVerified</code> error, and doesn't attempt to deliver the email.</p> <p>These behaviors are taken when the custom MAIL FROM domain configuration is in the <code>Pending</code>, <code>Failed</code>, and <code>TemporaryFailure</code> states.</p>
     #[serde(rename = "BehaviorOnMxFailure")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub behavior_on_mx_failure: Option<String>,
+    pub behavior_on_mx_failure: Option<BehaviorOnMxFailure>,
     /// <p>The verified email identity that you want to set up the custom MAIL FROM domain for.</p>
     #[serde(rename = "EmailIdentity")]
     pub email_identity: String,
@@ -1437,8 +2314,13 @@ pub struct RawMessage {
 pub struct ReputationOptions {
     /// <p>The date and time (in Unix time) when the reputation metrics were last given a fresh start. When your account is given a fresh start, your reputation metrics are calculated starting from the date of the fresh start.</p>
     #[serde(rename = "LastFreshStart")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_fresh_start: Option<f64>,
+    pub last_fresh_start: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>If <code>true</code>, tracking of reputation metrics is enabled for the configuration set. If <code>false</code>, tracking of reputation metrics is disabled for the configuration set.</p>
     #[serde(rename = "ReputationMetricsEnabled")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1561,6 +2443,100 @@ pub struct Template {
     pub template_data: Option<String>,
 }
 
+/// <p>Specifies whether messages that use the configuration set are required to use Transport Layer Security (TLS). If the value is <code>Require</code>, messages are only delivered if a TLS connection can be established. If the value is <code>Optional</code>, messages can be delivered in plain text if a TLS connection can't be established.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TlsPolicy {
+    /// `REQUIRE`
+    Require,
+    /// `OPTIONAL`
+    Optional,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TlsPolicy {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TlsPolicy::Require => "REQUIRE",
+            TlsPolicy::Optional => "OPTIONAL",
+            TlsPolicy::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TlsPolicy {
+    fn default() -> TlsPolicy {
+        TlsPolicy::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TlsPolicy {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TlsPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TlsPolicy {
+    fn from(value: &'a str) -> TlsPolicy {
+        match value {
+            "REQUIRE" => TlsPolicy::Require,
+            "OPTIONAL" => TlsPolicy::Optional,
+            _ => TlsPolicy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TlsPolicy {
+    fn from(value: String) -> TlsPolicy {
+        match value.as_str() {
+            "REQUIRE" => TlsPolicy::Require,
+            "OPTIONAL" => TlsPolicy::Optional,
+            _ => TlsPolicy::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TlsPolicy {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TlsPolicy, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TlsPolicy {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TlsPolicy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TlsPolicy {
+    fn deserialize<D>(deserializer: D) -> Result<TlsPolicy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An object that defines the tracking options for a configuration set. When you use Amazon Pinpoint to send an email, it contains an invisible image that's used to track when recipients open your email. If your email contains links, those links are changed slightly in order to track when recipients click them.</p> <p>These images and links include references to a domain operated by AWS. You can optionally configure Amazon Pinpoint to use a domain that you operate for these images and links.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TrackingOptions {
@@ -1626,6 +2602,100 @@ pub struct VolumeStatistics {
     pub spam_raw_count: Option<i64>,
 }
 
+/// <p>The warmup status of a dedicated IP.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum WarmupStatus {
+    /// `IN_PROGRESS`
+    InProgress,
+    /// `DONE`
+    Done,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl WarmupStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            WarmupStatus::InProgress => "IN_PROGRESS",
+            WarmupStatus::Done => "DONE",
+            WarmupStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for WarmupStatus {
+    fn default() -> WarmupStatus {
+        WarmupStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for WarmupStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for WarmupStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for WarmupStatus {
+    fn from(value: &'a str) -> WarmupStatus {
+        match value {
+            "IN_PROGRESS" => WarmupStatus::InProgress,
+            "DONE" => WarmupStatus::Done,
+            _ => WarmupStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for WarmupStatus {
+    fn from(value: String) -> WarmupStatus {
+        match value.as_str() {
+            "IN_PROGRESS" => WarmupStatus::InProgress,
+            "DONE" => WarmupStatus::Done,
+            _ => WarmupStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for WarmupStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<WarmupStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for WarmupStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for WarmupStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for WarmupStatus {
+    fn deserialize<D>(deserializer: D) -> Result<WarmupStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// Errors returned by CreateConfigurationSet
 #[derive(Debug, PartialEq)]
 pub enum CreateConfigurationSetError {
@@ -4138,11 +5208,12 @@ pub struct PinpointEmailClient {
 impl PinpointEmailClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> PinpointEmailClient {
         PinpointEmailClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Pinpoint Email"),
         }
     }
 
@@ -4176,6 +5247,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/configuration-sets";
 
         let mut request = SignedRequest::new("POST", "ses", &self.region, &request_uri);
+        request.set_operation("CreateConfigurationSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4213,6 +5285,7 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("POST", "ses", &self.region, &request_uri);
+        request.set_operation("CreateConfigurationSetEventDestination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4246,6 +5319,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/dedicated-ip-pools";
 
         let mut request = SignedRequest::new("POST", "ses", &self.region, &request_uri);
+        request.set_operation("CreateDedicatedIpPool");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4280,6 +5354,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/deliverability-dashboard/test";
 
         let mut request = SignedRequest::new("POST", "ses", &self.region, &request_uri);
+        request.set_operation("CreateDeliverabilityTestReport");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4311,6 +5386,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/identities";
 
         let mut request = SignedRequest::new("POST", "ses", &self.region, &request_uri);
+        request.set_operation("CreateEmailIdentity");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4345,6 +5421,7 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "ses", &self.region, &request_uri);
+        request.set_operation("DeleteConfigurationSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4377,6 +5454,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = format!("/v1/email/configuration-sets/{configuration_set_name}/event-destinations/{event_destination_name}", configuration_set_name = input.configuration_set_name, event_destination_name = input.event_destination_name);
 
         let mut request = SignedRequest::new("DELETE", "ses", &self.region, &request_uri);
+        request.set_operation("DeleteConfigurationSetEventDestination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4411,6 +5489,7 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "ses", &self.region, &request_uri);
+        request.set_operation("DeleteDedicatedIpPool");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4443,6 +5522,7 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "ses", &self.region, &request_uri);
+        request.set_operation("DeleteEmailIdentity");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4469,6 +5549,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/account";
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("GetAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4498,6 +5579,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/deliverability-dashboard/blacklist-report";
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("GetBlacklistReports");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4536,6 +5618,7 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("GetConfigurationSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4571,6 +5654,7 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("GetConfigurationSetEventDestinations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4602,6 +5686,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = format!("/v1/email/dedicated-ips/{ip}", ip = input.ip);
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("GetDedicatedIp");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4631,6 +5716,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/dedicated-ips";
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("GetDedicatedIps");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4674,6 +5760,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/deliverability-dashboard";
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("GetDeliverabilityDashboardOptions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4709,6 +5796,7 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("GetDeliverabilityTestReport");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4744,6 +5832,7 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("GetDomainDeliverabilityCampaign");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4779,13 +5868,20 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("GetDomainStatisticsReport");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
 
         let mut params = Params::new();
-        params.put("EndDate", &input.end_date);
-        params.put("StartDate", &input.start_date);
+        params.put(
+            "EndDate",
+            &rusoto_core::serialization::TimestampFormat::Iso8601.format(&input.end_date),
+        );
+        params.put(
+            "StartDate",
+            &rusoto_core::serialization::TimestampFormat::Iso8601.format(&input.start_date),
+        );
         request.set_params(params);
 
         let mut response = self
@@ -4816,6 +5912,7 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("GetEmailIdentity");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4845,6 +5942,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/configuration-sets";
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("ListConfigurationSets");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4883,6 +5981,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/dedicated-ip-pools";
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("ListDedicatedIpPools");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4924,6 +6023,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/deliverability-dashboard/test-reports";
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("ListDeliverabilityTestReports");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4968,19 +6068,26 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("ListDomainDeliverabilityCampaigns");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
 
         let mut params = Params::new();
-        params.put("EndDate", &input.end_date);
+        params.put(
+            "EndDate",
+            &rusoto_core::serialization::TimestampFormat::Iso8601.format(&input.end_date),
+        );
         if let Some(ref x) = input.next_token {
             params.put("NextToken", x);
         }
         if let Some(ref x) = input.page_size {
             params.put("PageSize", x);
         }
-        params.put("StartDate", &input.start_date);
+        params.put(
+            "StartDate",
+            &rusoto_core::serialization::TimestampFormat::Iso8601.format(&input.start_date),
+        );
         request.set_params(params);
 
         let mut response = self
@@ -5010,6 +6117,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/identities";
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("ListEmailIdentities");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5048,6 +6156,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/tags";
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5084,6 +6193,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/account/dedicated-ips/warmup";
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutAccountDedicatedIpWarmupAttributes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5118,6 +6228,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/account/sending";
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutAccountSendingAttributes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5155,6 +6266,7 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutConfigurationSetDeliveryOptions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5194,6 +6306,7 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutConfigurationSetReputationOptions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5233,6 +6346,7 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutConfigurationSetSendingOptions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5272,6 +6386,7 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutConfigurationSetTrackingOptions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5305,6 +6420,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = format!("/v1/email/dedicated-ips/{ip}/pool", ip = input.ip);
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutDedicatedIpInPool");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5339,6 +6455,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = format!("/v1/email/dedicated-ips/{ip}/warmup", ip = input.ip);
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutDedicatedIpWarmupAttributes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5373,6 +6490,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/deliverability-dashboard";
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutDeliverabilityDashboardOption");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5412,6 +6530,7 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutEmailIdentityDkimAttributes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5449,6 +6568,7 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutEmailIdentityFeedbackAttributes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5488,6 +6608,7 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutEmailIdentityMailFromAttributes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5521,6 +6642,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/outbound-emails";
 
         let mut request = SignedRequest::new("POST", "ses", &self.region, &request_uri);
+        request.set_operation("SendEmail");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5552,6 +6674,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/tags";
 
         let mut request = SignedRequest::new("POST", "ses", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5583,6 +6706,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/tags";
 
         let mut request = SignedRequest::new("DELETE", "ses", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5622,6 +6746,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = format!("/v1/email/configuration-sets/{configuration_set_name}/event-destinations/{event_destination_name}", configuration_set_name = input.configuration_set_name, event_destination_name = input.event_destination_name);
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("UpdateConfigurationSetEventDestination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5647,3 +6772,173 @@ impl PinpointEmail for PinpointEmailClient {
         }
     }
 }
+impl PinpointEmailClient {
+    /// Auto-paginating version of `get_dedicated_ips`, streaming every page of results.
+    pub fn get_dedicated_ips_pages(
+        &self,
+        input: GetDedicatedIpsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetDedicatedIpsResponse, GetDedicatedIpsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: PinpointEmailClient, input: GetDedicatedIpsRequest| async move {
+                client.get_dedicated_ips(input).await
+            },
+            |input: &GetDedicatedIpsRequest, output: &GetDedicatedIpsResponse| {
+                Some(GetDedicatedIpsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_dedicated_ips`, streaming the `DedicatedIps` of every page of results.
+    pub fn get_dedicated_ips_items(
+        &self,
+        input: GetDedicatedIpsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DedicatedIp, GetDedicatedIpsError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_dedicated_ips_pages(input),
+            |output: GetDedicatedIpsResponse| output.dedicated_ips.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_configuration_sets`, streaming every page of results.
+    pub fn list_configuration_sets_pages(
+        &self,
+        input: ListConfigurationSetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListConfigurationSetsResponse,
+        ListConfigurationSetsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: PinpointEmailClient, input: ListConfigurationSetsRequest| async move {
+                client.list_configuration_sets(input).await
+            },
+            |input: &ListConfigurationSetsRequest, output: &ListConfigurationSetsResponse| {
+                Some(ListConfigurationSetsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_configuration_sets`, streaming the `ConfigurationSets` of every page of results.
+    pub fn list_configuration_sets_items(
+        &self,
+        input: ListConfigurationSetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListConfigurationSetsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_configuration_sets_pages(input),
+            |output: ListConfigurationSetsResponse| output.configuration_sets.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_dedicated_ip_pools`, streaming every page of results.
+    pub fn list_dedicated_ip_pools_pages(
+        &self,
+        input: ListDedicatedIpPoolsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListDedicatedIpPoolsResponse,
+        ListDedicatedIpPoolsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: PinpointEmailClient, input: ListDedicatedIpPoolsRequest| async move {
+                client.list_dedicated_ip_pools(input).await
+            },
+            |input: &ListDedicatedIpPoolsRequest, output: &ListDedicatedIpPoolsResponse| {
+                Some(ListDedicatedIpPoolsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_dedicated_ip_pools`, streaming the `DedicatedIpPools` of every page of results.
+    pub fn list_dedicated_ip_pools_items(
+        &self,
+        input: ListDedicatedIpPoolsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListDedicatedIpPoolsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_dedicated_ip_pools_pages(input),
+            |output: ListDedicatedIpPoolsResponse| output.dedicated_ip_pools.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_deliverability_test_reports`, streaming every page of results.
+    pub fn list_deliverability_test_reports_pages(
+        &self,
+        input: ListDeliverabilityTestReportsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListDeliverabilityTestReportsResponse,
+        ListDeliverabilityTestReportsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: PinpointEmailClient, input: ListDeliverabilityTestReportsRequest| async move {
+                client.list_deliverability_test_reports(input).await
+            },
+            |input: &ListDeliverabilityTestReportsRequest,
+             output: &ListDeliverabilityTestReportsResponse| {
+                Some(ListDeliverabilityTestReportsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_deliverability_test_reports`, streaming the `DeliverabilityTestReports` of every page of results.
+    pub fn list_deliverability_test_reports_items(
+        &self,
+        input: ListDeliverabilityTestReportsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DeliverabilityTestReport,
+        ListDeliverabilityTestReportsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_deliverability_test_reports_pages(input),
+            |output: ListDeliverabilityTestReportsResponse| output.deliverability_test_reports,
+        )
+    }
+
+    /// Auto-paginating version of `list_email_identities`, streaming every page of results.
+    pub fn list_email_identities_pages(
+        &self,
+        input: ListEmailIdentitiesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListEmailIdentitiesResponse, ListEmailIdentitiesError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: PinpointEmailClient, input: ListEmailIdentitiesRequest| async move {
+                client.list_email_identities(input).await
+            },
+            |input: &ListEmailIdentitiesRequest, output: &ListEmailIdentitiesResponse| {
+                Some(ListEmailIdentitiesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_email_identities`, streaming the `EmailIdentities` of every page of results.
+    pub fn list_email_identities_items(
+        &self,
+        input: ListEmailIdentitiesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<IdentityInfo, ListEmailIdentitiesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_email_identities_pages(input),
+            |output: ListEmailIdentitiesResponse| output.email_identities.unwrap_or_default(),
+        )
+    }
+}
@@ -128,7 +128,7 @@ pub struct EventDestination {
     pub kinesis_firehose_destination: Option<KinesisFirehoseDestination>,
     #[serde(rename = "MatchingEventTypes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub matching_event_types: Option<Vec<String>>,
+    pub matching_event_types: Option<Vec<EventType>>,
     /// <p>A name that identifies the event destination configuration.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -154,12 +154,131 @@ pub struct EventDestinationDefinition {
     pub kinesis_firehose_destination: Option<KinesisFirehoseDestination>,
     #[serde(rename = "MatchingEventTypes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub matching_event_types: Option<Vec<String>>,
+    pub matching_event_types: Option<Vec<EventType>>,
     #[serde(rename = "SnsDestination")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sns_destination: Option<SnsDestination>,
 }
 
+/// The types of events that are sent to the event destination.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EventType {
+    /// `INITIATED_CALL`
+    InitiatedCall,
+    /// `RINGING`
+    Ringing,
+    /// `ANSWERED`
+    Answered,
+    /// `COMPLETED_CALL`
+    CompletedCall,
+    /// `BUSY`
+    Busy,
+    /// `FAILED`
+    Failed,
+    /// `NO_ANSWER`
+    NoAnswer,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EventType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EventType::InitiatedCall => "INITIATED_CALL",
+            EventType::Ringing => "RINGING",
+            EventType::Answered => "ANSWERED",
+            EventType::CompletedCall => "COMPLETED_CALL",
+            EventType::Busy => "BUSY",
+            EventType::Failed => "FAILED",
+            EventType::NoAnswer => "NO_ANSWER",
+            EventType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EventType {
+    fn default() -> EventType {
+        EventType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EventType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EventType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EventType {
+    fn from(value: &'a str) -> EventType {
+        match value {
+            "INITIATED_CALL" => EventType::InitiatedCall,
+            "RINGING" => EventType::Ringing,
+            "ANSWERED" => EventType::Answered,
+            "COMPLETED_CALL" => EventType::CompletedCall,
+            "BUSY" => EventType::Busy,
+            "FAILED" => EventType::Failed,
+            "NO_ANSWER" => EventType::NoAnswer,
+            _ => EventType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EventType {
+    fn from(value: String) -> EventType {
+        match value.as_str() {
+            "INITIATED_CALL" => EventType::InitiatedCall,
+            "RINGING" => EventType::Ringing,
+            "ANSWERED" => EventType::Answered,
+            "COMPLETED_CALL" => EventType::CompletedCall,
+            "BUSY" => EventType::Busy,
+            "FAILED" => EventType::Failed,
+            "NO_ANSWER" => EventType::NoAnswer,
+            _ => EventType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EventType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EventType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EventType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EventType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EventType {
+    fn deserialize<D>(deserializer: D) -> Result<EventType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct GetConfigurationSetEventDestinationsRequest {
@@ -814,11 +933,12 @@ pub struct PinpointSmsVoiceClient {
 impl PinpointSmsVoiceClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> PinpointSmsVoiceClient {
         PinpointSmsVoiceClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Pinpoint SMS Voice"),
         }
     }
 
@@ -852,6 +972,7 @@ impl PinpointSmsVoice for PinpointSmsVoiceClient {
         let request_uri = "/v1/sms-voice/configuration-sets";
 
         let mut request = SignedRequest::new("POST", "sms-voice", &self.region, &request_uri);
+        request.set_operation("CreateConfigurationSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("sms-voice.pinpoint".to_string());
@@ -889,6 +1010,7 @@ impl PinpointSmsVoice for PinpointSmsVoiceClient {
         );
 
         let mut request = SignedRequest::new("POST", "sms-voice", &self.region, &request_uri);
+        request.set_operation("CreateConfigurationSetEventDestination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("sms-voice.pinpoint".to_string());
@@ -925,6 +1047,7 @@ impl PinpointSmsVoice for PinpointSmsVoiceClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "sms-voice", &self.region, &request_uri);
+        request.set_operation("DeleteConfigurationSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("sms-voice.pinpoint".to_string());
@@ -957,6 +1080,7 @@ impl PinpointSmsVoice for PinpointSmsVoiceClient {
         let request_uri = format!("/v1/sms-voice/configuration-sets/{configuration_set_name}/event-destinations/{event_destination_name}", configuration_set_name = input.configuration_set_name, event_destination_name = input.event_destination_name);
 
         let mut request = SignedRequest::new("DELETE", "sms-voice", &self.region, &request_uri);
+        request.set_operation("DeleteConfigurationSetEventDestination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("sms-voice.pinpoint".to_string());
@@ -994,6 +1118,7 @@ impl PinpointSmsVoice for PinpointSmsVoiceClient {
         );
 
         let mut request = SignedRequest::new("GET", "sms-voice", &self.region, &request_uri);
+        request.set_operation("GetConfigurationSetEventDestinations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("sms-voice.pinpoint".to_string());
@@ -1025,6 +1150,7 @@ impl PinpointSmsVoice for PinpointSmsVoiceClient {
         let request_uri = "/v1/sms-voice/voice/message";
 
         let mut request = SignedRequest::new("POST", "sms-voice", &self.region, &request_uri);
+        request.set_operation("SendVoiceMessage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("sms-voice.pinpoint".to_string());
@@ -1059,6 +1185,7 @@ impl PinpointSmsVoice for PinpointSmsVoiceClient {
         let request_uri = format!("/v1/sms-voice/configuration-sets/{configuration_set_name}/event-destinations/{event_destination_name}", configuration_set_name = input.configuration_set_name, event_destination_name = input.event_destination_name);
 
         let mut request = SignedRequest::new("PUT", "sms-voice", &self.region, &request_uri);
+        request.set_operation("UpdateConfigurationSetEventDestination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("sms-voice.pinpoint".to_string());
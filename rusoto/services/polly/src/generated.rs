@@ -43,7 +43,7 @@ pub struct DescribeVoicesInput {
     /// <p>Specifies the engine (<code>standard</code> or <code>neural</code>) used by Amazon Polly when processing input text for speech synthesis. </p>
     #[serde(rename = "Engine")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub engine: Option<String>,
+    pub engine: Option<Engine>,
     /// <p>Boolean value indicating whether to return any bilingual voices that use the specified language as an additional language. For instance, if you request all languages that use US English (es-US), and there is an Italian voice that speaks both Italian (it-IT) and US English, that voice will be included if you specify <code>yes</code> but not if you specify <code>no</code>.</p>
     #[serde(rename = "IncludeAdditionalLanguageCodes")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -51,7 +51,7 @@ pub struct DescribeVoicesInput {
     /// <p> The language identification tag (ISO 639 code for the language name-ISO 3166 country code) for filtering the list of voices returned. If you don't specify this optional parameter, all available voices are returned. </p>
     #[serde(rename = "LanguageCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub language_code: Option<String>,
+    pub language_code: Option<LanguageCode>,
     /// <p>An opaque pagination token returned from the previous <code>DescribeVoices</code> operation. If present, this indicates where to continue the listing.</p>
     #[serde(rename = "NextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -71,6 +71,197 @@ pub struct DescribeVoicesOutput {
     pub voices: Option<Vec<Voice>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Engine {
+    /// `standard`
+    Standard,
+    /// `neural`
+    Neural,
+    /// `long-form`
+    LongForm,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Engine {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Engine::Standard => "standard",
+            Engine::Neural => "neural",
+            Engine::LongForm => "long-form",
+            Engine::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Engine {
+    fn default() -> Engine {
+        Engine::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Engine {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Engine {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Engine {
+    fn from(value: &'a str) -> Engine {
+        match value {
+            "standard" => Engine::Standard,
+            "neural" => Engine::Neural,
+            "long-form" => Engine::LongForm,
+            _ => Engine::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Engine {
+    fn from(value: String) -> Engine {
+        match value.as_str() {
+            "standard" => Engine::Standard,
+            "neural" => Engine::Neural,
+            "long-form" => Engine::LongForm,
+            _ => Engine::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Engine {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Engine, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Engine {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Engine {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Engine {
+    fn deserialize<D>(deserializer: D) -> Result<Engine, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Gender {
+    /// `Female`
+    Female,
+    /// `Male`
+    Male,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Gender {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Gender::Female => "Female",
+            Gender::Male => "Male",
+            Gender::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Gender {
+    fn default() -> Gender {
+        Gender::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Gender {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Gender {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Gender {
+    fn from(value: &'a str) -> Gender {
+        match value {
+            "Female" => Gender::Female,
+            "Male" => Gender::Male,
+            _ => Gender::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Gender {
+    fn from(value: String) -> Gender {
+        match value.as_str() {
+            "Female" => Gender::Female,
+            "Male" => Gender::Male,
+            _ => Gender::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Gender {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Gender, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Gender {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Gender {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Gender {
+    fn deserialize<D>(deserializer: D) -> Result<Gender, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct GetLexiconInput {
@@ -109,6 +300,284 @@ pub struct GetSpeechSynthesisTaskOutput {
     pub synthesis_task: Option<SynthesisTask>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LanguageCode {
+    /// `arb`
+    Arb,
+    /// `cmn-CN`
+    CmnCn,
+    /// `cy-GB`
+    CyGb,
+    /// `da-DK`
+    DaDk,
+    /// `de-DE`
+    DeDe,
+    /// `en-AU`
+    EnAu,
+    /// `en-GB`
+    EnGb,
+    /// `en-GB-WLS`
+    EnGbWls,
+    /// `en-IN`
+    EnIn,
+    /// `en-US`
+    EnUs,
+    /// `es-ES`
+    EsEs,
+    /// `es-MX`
+    EsMx,
+    /// `es-US`
+    EsUs,
+    /// `fr-CA`
+    FrCa,
+    /// `fr-FR`
+    FrFr,
+    /// `is-IS`
+    IsIs,
+    /// `it-IT`
+    ItIt,
+    /// `ja-JP`
+    JaJp,
+    /// `hi-IN`
+    HiIn,
+    /// `ko-KR`
+    KoKr,
+    /// `nb-NO`
+    NbNo,
+    /// `nl-NL`
+    NlNl,
+    /// `pl-PL`
+    PlPl,
+    /// `pt-BR`
+    PtBr,
+    /// `pt-PT`
+    PtPt,
+    /// `ro-RO`
+    RoRo,
+    /// `ru-RU`
+    RuRu,
+    /// `sv-SE`
+    SvSe,
+    /// `tr-TR`
+    TrTr,
+    /// `en-NZ`
+    EnNz,
+    /// `en-ZA`
+    EnZa,
+    /// `ca-ES`
+    CaEs,
+    /// `de-AT`
+    DeAt,
+    /// `yue-CN`
+    YueCn,
+    /// `ar-AE`
+    ArAe,
+    /// `fi-FI`
+    FiFi,
+    /// `en-IE`
+    EnIe,
+    /// `nl-BE`
+    NlBe,
+    /// `fr-BE`
+    FrBe,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LanguageCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LanguageCode::Arb => "arb",
+            LanguageCode::CmnCn => "cmn-CN",
+            LanguageCode::CyGb => "cy-GB",
+            LanguageCode::DaDk => "da-DK",
+            LanguageCode::DeDe => "de-DE",
+            LanguageCode::EnAu => "en-AU",
+            LanguageCode::EnGb => "en-GB",
+            LanguageCode::EnGbWls => "en-GB-WLS",
+            LanguageCode::EnIn => "en-IN",
+            LanguageCode::EnUs => "en-US",
+            LanguageCode::EsEs => "es-ES",
+            LanguageCode::EsMx => "es-MX",
+            LanguageCode::EsUs => "es-US",
+            LanguageCode::FrCa => "fr-CA",
+            LanguageCode::FrFr => "fr-FR",
+            LanguageCode::IsIs => "is-IS",
+            LanguageCode::ItIt => "it-IT",
+            LanguageCode::JaJp => "ja-JP",
+            LanguageCode::HiIn => "hi-IN",
+            LanguageCode::KoKr => "ko-KR",
+            LanguageCode::NbNo => "nb-NO",
+            LanguageCode::NlNl => "nl-NL",
+            LanguageCode::PlPl => "pl-PL",
+            LanguageCode::PtBr => "pt-BR",
+            LanguageCode::PtPt => "pt-PT",
+            LanguageCode::RoRo => "ro-RO",
+            LanguageCode::RuRu => "ru-RU",
+            LanguageCode::SvSe => "sv-SE",
+            LanguageCode::TrTr => "tr-TR",
+            LanguageCode::EnNz => "en-NZ",
+            LanguageCode::EnZa => "en-ZA",
+            LanguageCode::CaEs => "ca-ES",
+            LanguageCode::DeAt => "de-AT",
+            LanguageCode::YueCn => "yue-CN",
+            LanguageCode::ArAe => "ar-AE",
+            LanguageCode::FiFi => "fi-FI",
+            LanguageCode::EnIe => "en-IE",
+            LanguageCode::NlBe => "nl-BE",
+            LanguageCode::FrBe => "fr-BE",
+            LanguageCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LanguageCode {
+    fn default() -> LanguageCode {
+        LanguageCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LanguageCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LanguageCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LanguageCode {
+    fn from(value: &'a str) -> LanguageCode {
+        match value {
+            "arb" => LanguageCode::Arb,
+            "cmn-CN" => LanguageCode::CmnCn,
+            "cy-GB" => LanguageCode::CyGb,
+            "da-DK" => LanguageCode::DaDk,
+            "de-DE" => LanguageCode::DeDe,
+            "en-AU" => LanguageCode::EnAu,
+            "en-GB" => LanguageCode::EnGb,
+            "en-GB-WLS" => LanguageCode::EnGbWls,
+            "en-IN" => LanguageCode::EnIn,
+            "en-US" => LanguageCode::EnUs,
+            "es-ES" => LanguageCode::EsEs,
+            "es-MX" => LanguageCode::EsMx,
+            "es-US" => LanguageCode::EsUs,
+            "fr-CA" => LanguageCode::FrCa,
+            "fr-FR" => LanguageCode::FrFr,
+            "is-IS" => LanguageCode::IsIs,
+            "it-IT" => LanguageCode::ItIt,
+            "ja-JP" => LanguageCode::JaJp,
+            "hi-IN" => LanguageCode::HiIn,
+            "ko-KR" => LanguageCode::KoKr,
+            "nb-NO" => LanguageCode::NbNo,
+            "nl-NL" => LanguageCode::NlNl,
+            "pl-PL" => LanguageCode::PlPl,
+            "pt-BR" => LanguageCode::PtBr,
+            "pt-PT" => LanguageCode::PtPt,
+            "ro-RO" => LanguageCode::RoRo,
+            "ru-RU" => LanguageCode::RuRu,
+            "sv-SE" => LanguageCode::SvSe,
+            "tr-TR" => LanguageCode::TrTr,
+            "en-NZ" => LanguageCode::EnNz,
+            "en-ZA" => LanguageCode::EnZa,
+            "ca-ES" => LanguageCode::CaEs,
+            "de-AT" => LanguageCode::DeAt,
+            "yue-CN" => LanguageCode::YueCn,
+            "ar-AE" => LanguageCode::ArAe,
+            "fi-FI" => LanguageCode::FiFi,
+            "en-IE" => LanguageCode::EnIe,
+            "nl-BE" => LanguageCode::NlBe,
+            "fr-BE" => LanguageCode::FrBe,
+            _ => LanguageCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LanguageCode {
+    fn from(value: String) -> LanguageCode {
+        match value.as_str() {
+            "arb" => LanguageCode::Arb,
+            "cmn-CN" => LanguageCode::CmnCn,
+            "cy-GB" => LanguageCode::CyGb,
+            "da-DK" => LanguageCode::DaDk,
+            "de-DE" => LanguageCode::DeDe,
+            "en-AU" => LanguageCode::EnAu,
+            "en-GB" => LanguageCode::EnGb,
+            "en-GB-WLS" => LanguageCode::EnGbWls,
+            "en-IN" => LanguageCode::EnIn,
+            "en-US" => LanguageCode::EnUs,
+            "es-ES" => LanguageCode::EsEs,
+            "es-MX" => LanguageCode::EsMx,
+            "es-US" => LanguageCode::EsUs,
+            "fr-CA" => LanguageCode::FrCa,
+            "fr-FR" => LanguageCode::FrFr,
+            "is-IS" => LanguageCode::IsIs,
+            "it-IT" => LanguageCode::ItIt,
+            "ja-JP" => LanguageCode::JaJp,
+            "hi-IN" => LanguageCode::HiIn,
+            "ko-KR" => LanguageCode::KoKr,
+            "nb-NO" => LanguageCode::NbNo,
+            "nl-NL" => LanguageCode::NlNl,
+            "pl-PL" => LanguageCode::PlPl,
+            "pt-BR" => LanguageCode::PtBr,
+            "pt-PT" => LanguageCode::PtPt,
+            "ro-RO" => LanguageCode::RoRo,
+            "ru-RU" => LanguageCode::RuRu,
+            "sv-SE" => LanguageCode::SvSe,
+            "tr-TR" => LanguageCode::TrTr,
+            "en-NZ" => LanguageCode::EnNz,
+            "en-ZA" => LanguageCode::EnZa,
+            "ca-ES" => LanguageCode::CaEs,
+            "de-AT" => LanguageCode::DeAt,
+            "yue-CN" => LanguageCode::YueCn,
+            "ar-AE" => LanguageCode::ArAe,
+            "fi-FI" => LanguageCode::FiFi,
+            "en-IE" => LanguageCode::EnIe,
+            "nl-BE" => LanguageCode::NlBe,
+            "fr-BE" => LanguageCode::FrBe,
+            _ => LanguageCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LanguageCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LanguageCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LanguageCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LanguageCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LanguageCode {
+    fn deserialize<D>(deserializer: D) -> Result<LanguageCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Provides lexicon name and lexicon content in string format. For more information, see <a href="https://www.w3.org/TR/pronunciation-lexicon/">Pronunciation Lexicon Specification (PLS) Version 1.0</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -134,11 +603,16 @@ pub struct LexiconAttributes {
     /// <p>Language code that the lexicon applies to. A lexicon with a language code such as "en" would be applied to all English languages (en-GB, en-US, en-AUS, en-WLS, and so on.</p>
     #[serde(rename = "LanguageCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub language_code: Option<String>,
+    pub language_code: Option<LanguageCode>,
     /// <p>Date lexicon was last modified (a timestamp value).</p>
     #[serde(rename = "LastModified")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified: Option<f64>,
+    pub last_modified: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Number of lexemes in the lexicon.</p>
     #[serde(rename = "LexemesCount")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -203,7 +677,7 @@ pub struct ListSpeechSynthesisTasksInput {
     /// <p>Status of the speech synthesis tasks returned in a List operation</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<TaskStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -219,6 +693,109 @@ pub struct ListSpeechSynthesisTasksOutput {
     pub synthesis_tasks: Option<Vec<SynthesisTask>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OutputFormat {
+    /// `json`
+    Json,
+    /// `mp3`
+    Mp3,
+    /// `ogg_vorbis`
+    OggVorbis,
+    /// `pcm`
+    Pcm,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl OutputFormat {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OutputFormat::Json => "json",
+            OutputFormat::Mp3 => "mp3",
+            OutputFormat::OggVorbis => "ogg_vorbis",
+            OutputFormat::Pcm => "pcm",
+            OutputFormat::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for OutputFormat {
+    fn default() -> OutputFormat {
+        OutputFormat::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for OutputFormat {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for OutputFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for OutputFormat {
+    fn from(value: &'a str) -> OutputFormat {
+        match value {
+            "json" => OutputFormat::Json,
+            "mp3" => OutputFormat::Mp3,
+            "ogg_vorbis" => OutputFormat::OggVorbis,
+            "pcm" => OutputFormat::Pcm,
+            _ => OutputFormat::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OutputFormat {
+    fn from(value: String) -> OutputFormat {
+        match value.as_str() {
+            "json" => OutputFormat::Json,
+            "mp3" => OutputFormat::Mp3,
+            "ogg_vorbis" => OutputFormat::OggVorbis,
+            "pcm" => OutputFormat::Pcm,
+            _ => OutputFormat::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for OutputFormat {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<OutputFormat, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OutputFormat {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for OutputFormat {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OutputFormat {
+    fn deserialize<D>(deserializer: D) -> Result<OutputFormat, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct PutLexiconInput {
@@ -234,24 +811,127 @@ pub struct PutLexiconInput {
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutLexiconOutput {}
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SpeechMarkType {
+    /// `sentence`
+    Sentence,
+    /// `ssml`
+    Ssml,
+    /// `viseme`
+    Viseme,
+    /// `word`
+    Word,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SpeechMarkType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SpeechMarkType::Sentence => "sentence",
+            SpeechMarkType::Ssml => "ssml",
+            SpeechMarkType::Viseme => "viseme",
+            SpeechMarkType::Word => "word",
+            SpeechMarkType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SpeechMarkType {
+    fn default() -> SpeechMarkType {
+        SpeechMarkType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SpeechMarkType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SpeechMarkType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SpeechMarkType {
+    fn from(value: &'a str) -> SpeechMarkType {
+        match value {
+            "sentence" => SpeechMarkType::Sentence,
+            "ssml" => SpeechMarkType::Ssml,
+            "viseme" => SpeechMarkType::Viseme,
+            "word" => SpeechMarkType::Word,
+            _ => SpeechMarkType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SpeechMarkType {
+    fn from(value: String) -> SpeechMarkType {
+        match value.as_str() {
+            "sentence" => SpeechMarkType::Sentence,
+            "ssml" => SpeechMarkType::Ssml,
+            "viseme" => SpeechMarkType::Viseme,
+            "word" => SpeechMarkType::Word,
+            _ => SpeechMarkType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SpeechMarkType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SpeechMarkType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SpeechMarkType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SpeechMarkType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SpeechMarkType {
+    fn deserialize<D>(deserializer: D) -> Result<SpeechMarkType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct StartSpeechSynthesisTaskInput {
     /// <p>Specifies the engine (<code>standard</code> or <code>neural</code>) for Amazon Polly to use when processing input text for speech synthesis. Using a voice that is not supported for the engine selected will result in an error.</p>
     #[serde(rename = "Engine")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub engine: Option<String>,
+    pub engine: Option<Engine>,
     /// <p>Optional language code for the Speech Synthesis request. This is only necessary if using a bilingual voice, such as Aditi, which can be used for either Indian English (en-IN) or Hindi (hi-IN). </p> <p>If a bilingual voice is used and no language code is specified, Amazon Polly will use the default language of the bilingual voice. The default language for any voice is the one returned by the <a href="https://docs.aws.amazon.com/polly/latest/dg/API_DescribeVoices.html">DescribeVoices</a> operation for the <code>LanguageCode</code> parameter. For example, if no language code is specified, Aditi will use Indian English rather than Hindi.</p>
     #[serde(rename = "LanguageCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub language_code: Option<String>,
+    pub language_code: Option<LanguageCode>,
     /// <p>List of one or more pronunciation lexicon names you want the service to apply during synthesis. Lexicons are applied only if the language of the lexicon is the same as the language of the voice. </p>
     #[serde(rename = "LexiconNames")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub lexicon_names: Option<Vec<String>>,
     /// <p>The format in which the returned output will be encoded. For audio stream, this will be mp3, ogg_vorbis, or pcm. For speech marks, this will be json. </p>
     #[serde(rename = "OutputFormat")]
-    pub output_format: String,
+    pub output_format: OutputFormat,
     /// <p>Amazon S3 bucket name to which the output file will be saved.</p>
     #[serde(rename = "OutputS3BucketName")]
     pub output_s3_bucket_name: String,
@@ -270,17 +950,17 @@ pub struct StartSpeechSynthesisTaskInput {
     /// <p>The type of speech marks returned for the input text.</p>
     #[serde(rename = "SpeechMarkTypes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub speech_mark_types: Option<Vec<String>>,
+    pub speech_mark_types: Option<Vec<SpeechMarkType>>,
     /// <p>The input text to synthesize. If you specify ssml as the TextType, follow the SSML format for the input text. </p>
     #[serde(rename = "Text")]
     pub text: String,
     /// <p>Specifies whether the input text is plain text or SSML. The default value is plain text. </p>
     #[serde(rename = "TextType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub text_type: Option<String>,
+    pub text_type: Option<TextType>,
     /// <p>Voice ID to use for the synthesis. </p>
     #[serde(rename = "VoiceId")]
-    pub voice_id: String,
+    pub voice_id: VoiceId,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -298,16 +978,21 @@ pub struct StartSpeechSynthesisTaskOutput {
 pub struct SynthesisTask {
     /// <p>Timestamp for the time the synthesis task was started.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Specifies the engine (<code>standard</code> or <code>neural</code>) for Amazon Polly to use when processing input text for speech synthesis. Using a voice that is not supported for the engine selected will result in an error.</p>
     #[serde(rename = "Engine")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub engine: Option<String>,
+    pub engine: Option<Engine>,
     /// <p>Optional language code for a synthesis task. This is only necessary if using a bilingual voice, such as Aditi, which can be used for either Indian English (en-IN) or Hindi (hi-IN). </p> <p>If a bilingual voice is used and no language code is specified, Amazon Polly will use the default language of the bilingual voice. The default language for any voice is the one returned by the <a href="https://docs.aws.amazon.com/polly/latest/dg/API_DescribeVoices.html">DescribeVoices</a> operation for the <code>LanguageCode</code> parameter. For example, if no language code is specified, Aditi will use Indian English rather than Hindi.</p>
     #[serde(rename = "LanguageCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub language_code: Option<String>,
+    pub language_code: Option<LanguageCode>,
     /// <p>List of one or more pronunciation lexicon names you want the service to apply during synthesis. Lexicons are applied only if the language of the lexicon is the same as the language of the voice. </p>
     #[serde(rename = "LexiconNames")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -315,7 +1000,7 @@ pub struct SynthesisTask {
     /// <p>The format in which the returned output will be encoded. For audio stream, this will be mp3, ogg_vorbis, or pcm. For speech marks, this will be json. </p>
     #[serde(rename = "OutputFormat")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub output_format: Option<String>,
+    pub output_format: Option<OutputFormat>,
     /// <p>Pathway for the output speech file.</p>
     #[serde(rename = "OutputUri")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -335,7 +1020,7 @@ pub struct SynthesisTask {
     /// <p>The type of speech marks returned for the input text.</p>
     #[serde(rename = "SpeechMarkTypes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub speech_mark_types: Option<Vec<String>>,
+    pub speech_mark_types: Option<Vec<SpeechMarkType>>,
     /// <p>The Amazon Polly generated identifier for a speech synthesis task.</p>
     #[serde(rename = "TaskId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -343,7 +1028,7 @@ pub struct SynthesisTask {
     /// <p>Current status of the individual speech synthesis task.</p>
     #[serde(rename = "TaskStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub task_status: Option<String>,
+    pub task_status: Option<TaskStatus>,
     /// <p>Reason for the current status of a specific speech synthesis task, including errors if the task has failed.</p>
     #[serde(rename = "TaskStatusReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -351,11 +1036,11 @@ pub struct SynthesisTask {
     /// <p>Specifies whether the input text is plain text or SSML. The default value is plain text. </p>
     #[serde(rename = "TextType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub text_type: Option<String>,
+    pub text_type: Option<TextType>,
     /// <p>Voice ID to use for the synthesis. </p>
     #[serde(rename = "VoiceId")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub voice_id: Option<String>,
+    pub voice_id: Option<VoiceId>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -364,18 +1049,18 @@ pub struct SynthesizeSpeechInput {
     /// <p>Specifies the engine (<code>standard</code> or <code>neural</code>) for Amazon Polly to use when processing input text for speech synthesis. Using a voice that is not supported for the engine selected will result in an error.</p>
     #[serde(rename = "Engine")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub engine: Option<String>,
+    pub engine: Option<Engine>,
     /// <p>Optional language code for the Synthesize Speech request. This is only necessary if using a bilingual voice, such as Aditi, which can be used for either Indian English (en-IN) or Hindi (hi-IN). </p> <p>If a bilingual voice is used and no language code is specified, Amazon Polly will use the default language of the bilingual voice. The default language for any voice is the one returned by the <a href="https://docs.aws.amazon.com/polly/latest/dg/API_DescribeVoices.html">DescribeVoices</a> operation for the <code>LanguageCode</code> parameter. For example, if no language code is specified, Aditi will use Indian English rather than Hindi.</p>
     #[serde(rename = "LanguageCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub language_code: Option<String>,
+    pub language_code: Option<LanguageCode>,
     /// <p>List of one or more pronunciation lexicon names you want the service to apply during synthesis. Lexicons are applied only if the language of the lexicon is the same as the language of the voice. For information about storing lexicons, see <a href="https://docs.aws.amazon.com/polly/latest/dg/API_PutLexicon.html">PutLexicon</a>.</p>
     #[serde(rename = "LexiconNames")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub lexicon_names: Option<Vec<String>>,
     /// <p> The format in which the returned output will be encoded. For audio stream, this will be mp3, ogg_vorbis, or pcm. For speech marks, this will be json. </p> <p>When pcm is used, the content returned is audio/pcm in a signed 16-bit, 1 channel (mono), little-endian format. </p>
     #[serde(rename = "OutputFormat")]
-    pub output_format: String,
+    pub output_format: OutputFormat,
     /// <p>The audio frequency specified in Hz.</p> <p>The valid values for mp3 and ogg_vorbis are "8000", "16000", "22050", and "24000". The default value for standard voices is "22050". The default value for neural voices is "24000".</p> <p>Valid values for pcm are "8000" and "16000" The default value is "16000". </p>
     #[serde(rename = "SampleRate")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -383,17 +1068,17 @@ pub struct SynthesizeSpeechInput {
     /// <p>The type of speech marks returned for the input text.</p>
     #[serde(rename = "SpeechMarkTypes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub speech_mark_types: Option<Vec<String>>,
+    pub speech_mark_types: Option<Vec<SpeechMarkType>>,
     /// <p> Input text to synthesize. If you specify <code>ssml</code> as the <code>TextType</code>, follow the SSML format for the input text. </p>
     #[serde(rename = "Text")]
     pub text: String,
     /// <p> Specifies whether the input text is plain text or SSML. The default value is plain text. For more information, see <a href="https://docs.aws.amazon.com/polly/latest/dg/ssml.html">Using SSML</a>.</p>
     #[serde(rename = "TextType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub text_type: Option<String>,
+    pub text_type: Option<TextType>,
     /// <p> Voice ID to use for the synthesis. You can get a list of available voice IDs by calling the <a href="https://docs.aws.amazon.com/polly/latest/dg/API_DescribeVoices.html">DescribeVoices</a> operation. </p>
     #[serde(rename = "VoiceId")]
-    pub voice_id: String,
+    pub voice_id: VoiceId,
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
@@ -406,6 +1091,202 @@ pub struct SynthesizeSpeechOutput {
     pub request_characters: Option<i64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TaskStatus {
+    /// `scheduled`
+    Scheduled,
+    /// `inProgress`
+    InProgress,
+    /// `completed`
+    Completed,
+    /// `failed`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TaskStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TaskStatus::Scheduled => "scheduled",
+            TaskStatus::InProgress => "inProgress",
+            TaskStatus::Completed => "completed",
+            TaskStatus::Failed => "failed",
+            TaskStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TaskStatus {
+    fn default() -> TaskStatus {
+        TaskStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TaskStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TaskStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TaskStatus {
+    fn from(value: &'a str) -> TaskStatus {
+        match value {
+            "scheduled" => TaskStatus::Scheduled,
+            "inProgress" => TaskStatus::InProgress,
+            "completed" => TaskStatus::Completed,
+            "failed" => TaskStatus::Failed,
+            _ => TaskStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TaskStatus {
+    fn from(value: String) -> TaskStatus {
+        match value.as_str() {
+            "scheduled" => TaskStatus::Scheduled,
+            "inProgress" => TaskStatus::InProgress,
+            "completed" => TaskStatus::Completed,
+            "failed" => TaskStatus::Failed,
+            _ => TaskStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TaskStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TaskStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TaskStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TaskStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TaskStatus {
+    fn deserialize<D>(deserializer: D) -> Result<TaskStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TextType {
+    /// `ssml`
+    Ssml,
+    /// `text`
+    Text,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TextType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TextType::Ssml => "ssml",
+            TextType::Text => "text",
+            TextType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TextType {
+    fn default() -> TextType {
+        TextType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TextType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TextType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TextType {
+    fn from(value: &'a str) -> TextType {
+        match value {
+            "ssml" => TextType::Ssml,
+            "text" => TextType::Text,
+            _ => TextType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TextType {
+    fn from(value: String) -> TextType {
+        match value.as_str() {
+            "ssml" => TextType::Ssml,
+            "text" => TextType::Text,
+            _ => TextType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TextType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TextType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TextType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TextType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TextType {
+    fn deserialize<D>(deserializer: D) -> Result<TextType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Description of the voice.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -413,19 +1294,19 @@ pub struct Voice {
     /// <p>Additional codes for languages available for the specified voice in addition to its default language. </p> <p>For example, the default language for Aditi is Indian English (en-IN) because it was first used for that language. Since Aditi is bilingual and fluent in both Indian English and Hindi, this parameter would show the code <code>hi-IN</code>.</p>
     #[serde(rename = "AdditionalLanguageCodes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub additional_language_codes: Option<Vec<String>>,
+    pub additional_language_codes: Option<Vec<LanguageCode>>,
     /// <p>Gender of the voice.</p>
     #[serde(rename = "Gender")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub gender: Option<String>,
+    pub gender: Option<Gender>,
     /// <p>Amazon Polly assigned voice ID. This is the ID that you specify when calling the <code>SynthesizeSpeech</code> operation.</p>
     #[serde(rename = "Id")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    pub id: Option<VoiceId>,
     /// <p>Language code of the voice.</p>
     #[serde(rename = "LanguageCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub language_code: Option<String>,
+    pub language_code: Option<LanguageCode>,
     /// <p>Human readable name of the language in English.</p>
     #[serde(rename = "LanguageName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -437,9 +1318,567 @@ pub struct Voice {
     /// <p>Specifies which engines (<code>standard</code> or <code>neural</code>) that are supported by a given voice.</p>
     #[serde(rename = "SupportedEngines")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub supported_engines: Option<Vec<String>>,
+    pub supported_engines: Option<Vec<Engine>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum VoiceId {
+    /// `Aditi`
+    Aditi,
+    /// `Amy`
+    Amy,
+    /// `Astrid`
+    Astrid,
+    /// `Bianca`
+    Bianca,
+    /// `Brian`
+    Brian,
+    /// `Camila`
+    Camila,
+    /// `Carla`
+    Carla,
+    /// `Carmen`
+    Carmen,
+    /// `Celine`
+    Celine,
+    /// `Chantal`
+    Chantal,
+    /// `Conchita`
+    Conchita,
+    /// `Cristiano`
+    Cristiano,
+    /// `Dora`
+    Dora,
+    /// `Emma`
+    Emma,
+    /// `Enrique`
+    Enrique,
+    /// `Ewa`
+    Ewa,
+    /// `Filiz`
+    Filiz,
+    /// `Gabrielle`
+    Gabrielle,
+    /// `Geraint`
+    Geraint,
+    /// `Giorgio`
+    Giorgio,
+    /// `Gwyneth`
+    Gwyneth,
+    /// `Hans`
+    Hans,
+    /// `Ines`
+    Ines,
+    /// `Ivy`
+    Ivy,
+    /// `Jacek`
+    Jacek,
+    /// `Jan`
+    Jan,
+    /// `Joanna`
+    Joanna,
+    /// `Joey`
+    Joey,
+    /// `Justin`
+    Justin,
+    /// `Karl`
+    Karl,
+    /// `Kendra`
+    Kendra,
+    /// `Kevin`
+    Kevin,
+    /// `Kimberly`
+    Kimberly,
+    /// `Lea`
+    Lea,
+    /// `Liv`
+    Liv,
+    /// `Lotte`
+    Lotte,
+    /// `Lucia`
+    Lucia,
+    /// `Lupe`
+    Lupe,
+    /// `Mads`
+    Mads,
+    /// `Maja`
+    Maja,
+    /// `Marlene`
+    Marlene,
+    /// `Mathieu`
+    Mathieu,
+    /// `Matthew`
+    Matthew,
+    /// `Maxim`
+    Maxim,
+    /// `Mia`
+    Mia,
+    /// `Miguel`
+    Miguel,
+    /// `Mizuki`
+    Mizuki,
+    /// `Naja`
+    Naja,
+    /// `Nicole`
+    Nicole,
+    /// `Olivia`
+    Olivia,
+    /// `Penelope`
+    Penelope,
+    /// `Raveena`
+    Raveena,
+    /// `Ricardo`
+    Ricardo,
+    /// `Ruben`
+    Ruben,
+    /// `Russell`
+    Russell,
+    /// `Salli`
+    Salli,
+    /// `Seoyeon`
+    Seoyeon,
+    /// `Takumi`
+    Takumi,
+    /// `Tatyana`
+    Tatyana,
+    /// `Vicki`
+    Vicki,
+    /// `Vitoria`
+    Vitoria,
+    /// `Zeina`
+    Zeina,
+    /// `Zhiyu`
+    Zhiyu,
+    /// `Aria`
+    Aria,
+    /// `Ayanda`
+    Ayanda,
+    /// `Arlet`
+    Arlet,
+    /// `Hannah`
+    Hannah,
+    /// `Arthur`
+    Arthur,
+    /// `Daniel`
+    Daniel,
+    /// `Liam`
+    Liam,
+    /// `Pedro`
+    Pedro,
+    /// `Kajal`
+    Kajal,
+    /// `Hiujin`
+    Hiujin,
+    /// `Laura`
+    Laura,
+    /// `Elin`
+    Elin,
+    /// `Ida`
+    Ida,
+    /// `Suvi`
+    Suvi,
+    /// `Ola`
+    Ola,
+    /// `Hala`
+    Hala,
+    /// `Andres`
+    Andres,
+    /// `Sergio`
+    Sergio,
+    /// `Remi`
+    Remi,
+    /// `Adriano`
+    Adriano,
+    /// `Thiago`
+    Thiago,
+    /// `Ruth`
+    Ruth,
+    /// `Stephen`
+    Stephen,
+    /// `Kazuha`
+    Kazuha,
+    /// `Tomoko`
+    Tomoko,
+    /// `Niamh`
+    Niamh,
+    /// `Sofie`
+    Sofie,
+    /// `Lisa`
+    Lisa,
+    /// `Isabelle`
+    Isabelle,
+    /// `Zayd`
+    Zayd,
+    /// `Danielle`
+    Danielle,
+    /// `Gregory`
+    Gregory,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl VoiceId {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            VoiceId::Aditi => "Aditi",
+            VoiceId::Amy => "Amy",
+            VoiceId::Astrid => "Astrid",
+            VoiceId::Bianca => "Bianca",
+            VoiceId::Brian => "Brian",
+            VoiceId::Camila => "Camila",
+            VoiceId::Carla => "Carla",
+            VoiceId::Carmen => "Carmen",
+            VoiceId::Celine => "Celine",
+            VoiceId::Chantal => "Chantal",
+            VoiceId::Conchita => "Conchita",
+            VoiceId::Cristiano => "Cristiano",
+            VoiceId::Dora => "Dora",
+            VoiceId::Emma => "Emma",
+            VoiceId::Enrique => "Enrique",
+            VoiceId::Ewa => "Ewa",
+            VoiceId::Filiz => "Filiz",
+            VoiceId::Gabrielle => "Gabrielle",
+            VoiceId::Geraint => "Geraint",
+            VoiceId::Giorgio => "Giorgio",
+            VoiceId::Gwyneth => "Gwyneth",
+            VoiceId::Hans => "Hans",
+            VoiceId::Ines => "Ines",
+            VoiceId::Ivy => "Ivy",
+            VoiceId::Jacek => "Jacek",
+            VoiceId::Jan => "Jan",
+            VoiceId::Joanna => "Joanna",
+            VoiceId::Joey => "Joey",
+            VoiceId::Justin => "Justin",
+            VoiceId::Karl => "Karl",
+            VoiceId::Kendra => "Kendra",
+            VoiceId::Kevin => "Kevin",
+            VoiceId::Kimberly => "Kimberly",
+            VoiceId::Lea => "Lea",
+            VoiceId::Liv => "Liv",
+            VoiceId::Lotte => "Lotte",
+            VoiceId::Lucia => "Lucia",
+            VoiceId::Lupe => "Lupe",
+            VoiceId::Mads => "Mads",
+            VoiceId::Maja => "Maja",
+            VoiceId::Marlene => "Marlene",
+            VoiceId::Mathieu => "Mathieu",
+            VoiceId::Matthew => "Matthew",
+            VoiceId::Maxim => "Maxim",
+            VoiceId::Mia => "Mia",
+            VoiceId::Miguel => "Miguel",
+            VoiceId::Mizuki => "Mizuki",
+            VoiceId::Naja => "Naja",
+            VoiceId::Nicole => "Nicole",
+            VoiceId::Olivia => "Olivia",
+            VoiceId::Penelope => "Penelope",
+            VoiceId::Raveena => "Raveena",
+            VoiceId::Ricardo => "Ricardo",
+            VoiceId::Ruben => "Ruben",
+            VoiceId::Russell => "Russell",
+            VoiceId::Salli => "Salli",
+            VoiceId::Seoyeon => "Seoyeon",
+            VoiceId::Takumi => "Takumi",
+            VoiceId::Tatyana => "Tatyana",
+            VoiceId::Vicki => "Vicki",
+            VoiceId::Vitoria => "Vitoria",
+            VoiceId::Zeina => "Zeina",
+            VoiceId::Zhiyu => "Zhiyu",
+            VoiceId::Aria => "Aria",
+            VoiceId::Ayanda => "Ayanda",
+            VoiceId::Arlet => "Arlet",
+            VoiceId::Hannah => "Hannah",
+            VoiceId::Arthur => "Arthur",
+            VoiceId::Daniel => "Daniel",
+            VoiceId::Liam => "Liam",
+            VoiceId::Pedro => "Pedro",
+            VoiceId::Kajal => "Kajal",
+            VoiceId::Hiujin => "Hiujin",
+            VoiceId::Laura => "Laura",
+            VoiceId::Elin => "Elin",
+            VoiceId::Ida => "Ida",
+            VoiceId::Suvi => "Suvi",
+            VoiceId::Ola => "Ola",
+            VoiceId::Hala => "Hala",
+            VoiceId::Andres => "Andres",
+            VoiceId::Sergio => "Sergio",
+            VoiceId::Remi => "Remi",
+            VoiceId::Adriano => "Adriano",
+            VoiceId::Thiago => "Thiago",
+            VoiceId::Ruth => "Ruth",
+            VoiceId::Stephen => "Stephen",
+            VoiceId::Kazuha => "Kazuha",
+            VoiceId::Tomoko => "Tomoko",
+            VoiceId::Niamh => "Niamh",
+            VoiceId::Sofie => "Sofie",
+            VoiceId::Lisa => "Lisa",
+            VoiceId::Isabelle => "Isabelle",
+            VoiceId::Zayd => "Zayd",
+            VoiceId::Danielle => "Danielle",
+            VoiceId::Gregory => "Gregory",
+            VoiceId::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for VoiceId {
+    fn default() -> VoiceId {
+        VoiceId::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for VoiceId {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for VoiceId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for VoiceId {
+    fn from(value: &'a str) -> VoiceId {
+        match value {
+            "Aditi" => VoiceId::Aditi,
+            "Amy" => VoiceId::Amy,
+            "Astrid" => VoiceId::Astrid,
+            "Bianca" => VoiceId::Bianca,
+            "Brian" => VoiceId::Brian,
+            "Camila" => VoiceId::Camila,
+            "Carla" => VoiceId::Carla,
+            "Carmen" => VoiceId::Carmen,
+            "Celine" => VoiceId::Celine,
+            "Chantal" => VoiceId::Chantal,
+            "Conchita" => VoiceId::Conchita,
+            "Cristiano" => VoiceId::Cristiano,
+            "Dora" => VoiceId::Dora,
+            "Emma" => VoiceId::Emma,
+            "Enrique" => VoiceId::Enrique,
+            "Ewa" => VoiceId::Ewa,
+            "Filiz" => VoiceId::Filiz,
+            "Gabrielle" => VoiceId::Gabrielle,
+            "Geraint" => VoiceId::Geraint,
+            "Giorgio" => VoiceId::Giorgio,
+            "Gwyneth" => VoiceId::Gwyneth,
+            "Hans" => VoiceId::Hans,
+            "Ines" => VoiceId::Ines,
+            "Ivy" => VoiceId::Ivy,
+            "Jacek" => VoiceId::Jacek,
+            "Jan" => VoiceId::Jan,
+            "Joanna" => VoiceId::Joanna,
+            "Joey" => VoiceId::Joey,
+            "Justin" => VoiceId::Justin,
+            "Karl" => VoiceId::Karl,
+            "Kendra" => VoiceId::Kendra,
+            "Kevin" => VoiceId::Kevin,
+            "Kimberly" => VoiceId::Kimberly,
+            "Lea" => VoiceId::Lea,
+            "Liv" => VoiceId::Liv,
+            "Lotte" => VoiceId::Lotte,
+            "Lucia" => VoiceId::Lucia,
+            "Lupe" => VoiceId::Lupe,
+            "Mads" => VoiceId::Mads,
+            "Maja" => VoiceId::Maja,
+            "Marlene" => VoiceId::Marlene,
+            "Mathieu" => VoiceId::Mathieu,
+            "Matthew" => VoiceId::Matthew,
+            "Maxim" => VoiceId::Maxim,
+            "Mia" => VoiceId::Mia,
+            "Miguel" => VoiceId::Miguel,
+            "Mizuki" => VoiceId::Mizuki,
+            "Naja" => VoiceId::Naja,
+            "Nicole" => VoiceId::Nicole,
+            "Olivia" => VoiceId::Olivia,
+            "Penelope" => VoiceId::Penelope,
+            "Raveena" => VoiceId::Raveena,
+            "Ricardo" => VoiceId::Ricardo,
+            "Ruben" => VoiceId::Ruben,
+            "Russell" => VoiceId::Russell,
+            "Salli" => VoiceId::Salli,
+            "Seoyeon" => VoiceId::Seoyeon,
+            "Takumi" => VoiceId::Takumi,
+            "Tatyana" => VoiceId::Tatyana,
+            "Vicki" => VoiceId::Vicki,
+            "Vitoria" => VoiceId::Vitoria,
+            "Zeina" => VoiceId::Zeina,
+            "Zhiyu" => VoiceId::Zhiyu,
+            "Aria" => VoiceId::Aria,
+            "Ayanda" => VoiceId::Ayanda,
+            "Arlet" => VoiceId::Arlet,
+            "Hannah" => VoiceId::Hannah,
+            "Arthur" => VoiceId::Arthur,
+            "Daniel" => VoiceId::Daniel,
+            "Liam" => VoiceId::Liam,
+            "Pedro" => VoiceId::Pedro,
+            "Kajal" => VoiceId::Kajal,
+            "Hiujin" => VoiceId::Hiujin,
+            "Laura" => VoiceId::Laura,
+            "Elin" => VoiceId::Elin,
+            "Ida" => VoiceId::Ida,
+            "Suvi" => VoiceId::Suvi,
+            "Ola" => VoiceId::Ola,
+            "Hala" => VoiceId::Hala,
+            "Andres" => VoiceId::Andres,
+            "Sergio" => VoiceId::Sergio,
+            "Remi" => VoiceId::Remi,
+            "Adriano" => VoiceId::Adriano,
+            "Thiago" => VoiceId::Thiago,
+            "Ruth" => VoiceId::Ruth,
+            "Stephen" => VoiceId::Stephen,
+            "Kazuha" => VoiceId::Kazuha,
+            "Tomoko" => VoiceId::Tomoko,
+            "Niamh" => VoiceId::Niamh,
+            "Sofie" => VoiceId::Sofie,
+            "Lisa" => VoiceId::Lisa,
+            "Isabelle" => VoiceId::Isabelle,
+            "Zayd" => VoiceId::Zayd,
+            "Danielle" => VoiceId::Danielle,
+            "Gregory" => VoiceId::Gregory,
+            _ => VoiceId::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for VoiceId {
+    fn from(value: String) -> VoiceId {
+        match value.as_str() {
+            "Aditi" => VoiceId::Aditi,
+            "Amy" => VoiceId::Amy,
+            "Astrid" => VoiceId::Astrid,
+            "Bianca" => VoiceId::Bianca,
+            "Brian" => VoiceId::Brian,
+            "Camila" => VoiceId::Camila,
+            "Carla" => VoiceId::Carla,
+            "Carmen" => VoiceId::Carmen,
+            "Celine" => VoiceId::Celine,
+            "Chantal" => VoiceId::Chantal,
+            "Conchita" => VoiceId::Conchita,
+            "Cristiano" => VoiceId::Cristiano,
+            "Dora" => VoiceId::Dora,
+            "Emma" => VoiceId::Emma,
+            "Enrique" => VoiceId::Enrique,
+            "Ewa" => VoiceId::Ewa,
+            "Filiz" => VoiceId::Filiz,
+            "Gabrielle" => VoiceId::Gabrielle,
+            "Geraint" => VoiceId::Geraint,
+            "Giorgio" => VoiceId::Giorgio,
+            "Gwyneth" => VoiceId::Gwyneth,
+            "Hans" => VoiceId::Hans,
+            "Ines" => VoiceId::Ines,
+            "Ivy" => VoiceId::Ivy,
+            "Jacek" => VoiceId::Jacek,
+            "Jan" => VoiceId::Jan,
+            "Joanna" => VoiceId::Joanna,
+            "Joey" => VoiceId::Joey,
+            "Justin" => VoiceId::Justin,
+            "Karl" => VoiceId::Karl,
+            "Kendra" => VoiceId::Kendra,
+            "Kevin" => VoiceId::Kevin,
+            "Kimberly" => VoiceId::Kimberly,
+            "Lea" => VoiceId::Lea,
+            "Liv" => VoiceId::Liv,
+            "Lotte" => VoiceId::Lotte,
+            "Lucia" => VoiceId::Lucia,
+            "Lupe" => VoiceId::Lupe,
+            "Mads" => VoiceId::Mads,
+            "Maja" => VoiceId::Maja,
+            "Marlene" => VoiceId::Marlene,
+            "Mathieu" => VoiceId::Mathieu,
+            "Matthew" => VoiceId::Matthew,
+            "Maxim" => VoiceId::Maxim,
+            "Mia" => VoiceId::Mia,
+            "Miguel" => VoiceId::Miguel,
+            "Mizuki" => VoiceId::Mizuki,
+            "Naja" => VoiceId::Naja,
+            "Nicole" => VoiceId::Nicole,
+            "Olivia" => VoiceId::Olivia,
+            "Penelope" => VoiceId::Penelope,
+            "Raveena" => VoiceId::Raveena,
+            "Ricardo" => VoiceId::Ricardo,
+            "Ruben" => VoiceId::Ruben,
+            "Russell" => VoiceId::Russell,
+            "Salli" => VoiceId::Salli,
+            "Seoyeon" => VoiceId::Seoyeon,
+            "Takumi" => VoiceId::Takumi,
+            "Tatyana" => VoiceId::Tatyana,
+            "Vicki" => VoiceId::Vicki,
+            "Vitoria" => VoiceId::Vitoria,
+            "Zeina" => VoiceId::Zeina,
+            "Zhiyu" => VoiceId::Zhiyu,
+            "Aria" => VoiceId::Aria,
+            "Ayanda" => VoiceId::Ayanda,
+            "Arlet" => VoiceId::Arlet,
+            "Hannah" => VoiceId::Hannah,
+            "Arthur" => VoiceId::Arthur,
+            "Daniel" => VoiceId::Daniel,
+            "Liam" => VoiceId::Liam,
+            "Pedro" => VoiceId::Pedro,
+            "Kajal" => VoiceId::Kajal,
+            "Hiujin" => VoiceId::Hiujin,
+            "Laura" => VoiceId::Laura,
+            "Elin" => VoiceId::Elin,
+            "Ida" => VoiceId::Ida,
+            "Suvi" => VoiceId::Suvi,
+            "Ola" => VoiceId::Ola,
+            "Hala" => VoiceId::Hala,
+            "Andres" => VoiceId::Andres,
+            "Sergio" => VoiceId::Sergio,
+            "Remi" => VoiceId::Remi,
+            "Adriano" => VoiceId::Adriano,
+            "Thiago" => VoiceId::Thiago,
+            "Ruth" => VoiceId::Ruth,
+            "Stephen" => VoiceId::Stephen,
+            "Kazuha" => VoiceId::Kazuha,
+            "Tomoko" => VoiceId::Tomoko,
+            "Niamh" => VoiceId::Niamh,
+            "Sofie" => VoiceId::Sofie,
+            "Lisa" => VoiceId::Lisa,
+            "Isabelle" => VoiceId::Isabelle,
+            "Zayd" => VoiceId::Zayd,
+            "Danielle" => VoiceId::Danielle,
+            "Gregory" => VoiceId::Gregory,
+            _ => VoiceId::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for VoiceId {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<VoiceId, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for VoiceId {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for VoiceId {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for VoiceId {
+    fn deserialize<D>(deserializer: D) -> Result<VoiceId, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// Errors returned by DeleteLexicon
 #[derive(Debug, PartialEq)]
 pub enum DeleteLexiconError {
@@ -1019,11 +2458,12 @@ pub struct PollyClient {
 impl PollyClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> PollyClient {
         PollyClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Polly"),
         }
     }
 
@@ -1057,6 +2497,7 @@ impl Polly for PollyClient {
         let request_uri = format!("/v1/lexicons/{lexicon_name}", lexicon_name = input.name);
 
         let mut request = SignedRequest::new("DELETE", "polly", &self.region, &request_uri);
+        request.set_operation("DeleteLexicon");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1084,6 +2525,7 @@ impl Polly for PollyClient {
         let request_uri = "/v1/voices";
 
         let mut request = SignedRequest::new("GET", "polly", &self.region, &request_uri);
+        request.set_operation("DescribeVoices");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1126,6 +2568,7 @@ impl Polly for PollyClient {
         let request_uri = format!("/v1/lexicons/{lexicon_name}", lexicon_name = input.name);
 
         let mut request = SignedRequest::new("GET", "polly", &self.region, &request_uri);
+        request.set_operation("GetLexicon");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1153,6 +2596,7 @@ impl Polly for PollyClient {
         let request_uri = format!("/v1/synthesisTasks/{task_id}", task_id = input.task_id);
 
         let mut request = SignedRequest::new("GET", "polly", &self.region, &request_uri);
+        request.set_operation("GetSpeechSynthesisTask");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1180,6 +2624,7 @@ impl Polly for PollyClient {
         let request_uri = "/v1/lexicons";
 
         let mut request = SignedRequest::new("GET", "polly", &self.region, &request_uri);
+        request.set_operation("ListLexicons");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1213,6 +2658,7 @@ impl Polly for PollyClient {
         let request_uri = "/v1/synthesisTasks";
 
         let mut request = SignedRequest::new("GET", "polly", &self.region, &request_uri);
+        request.set_operation("ListSpeechSynthesisTasks");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1252,6 +2698,7 @@ impl Polly for PollyClient {
         let request_uri = format!("/v1/lexicons/{lexicon_name}", lexicon_name = input.name);
 
         let mut request = SignedRequest::new("PUT", "polly", &self.region, &request_uri);
+        request.set_operation("PutLexicon");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1282,6 +2729,7 @@ impl Polly for PollyClient {
         let request_uri = "/v1/synthesisTasks";
 
         let mut request = SignedRequest::new("POST", "polly", &self.region, &request_uri);
+        request.set_operation("StartSpeechSynthesisTask");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1312,6 +2760,7 @@ impl Polly for PollyClient {
         let request_uri = "/v1/speech";
 
         let mut request = SignedRequest::new("POST", "polly", &self.region, &request_uri);
+        request.set_operation("SynthesizeSpeech");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1344,3 +2793,99 @@ impl Polly for PollyClient {
         }
     }
 }
+impl PollyClient {
+    /// Auto-paginating version of `describe_voices`, streaming every page of results.
+    pub fn describe_voices_pages(
+        &self,
+        input: DescribeVoicesInput,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeVoicesOutput, DescribeVoicesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: PollyClient, input: DescribeVoicesInput| async move {
+                client.describe_voices(input).await
+            },
+            |input: &DescribeVoicesInput, output: &DescribeVoicesOutput| {
+                Some(DescribeVoicesInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_voices`, streaming the `Voices` of every page of results.
+    pub fn describe_voices_items(
+        &self,
+        input: DescribeVoicesInput,
+    ) -> rusoto_core::pagination::RusotoStream<Voice, DescribeVoicesError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_voices_pages(input),
+            |output: DescribeVoicesOutput| output.voices.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_lexicons`, streaming every page of results.
+    pub fn list_lexicons_pages(
+        &self,
+        input: ListLexiconsInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListLexiconsOutput, ListLexiconsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: PollyClient, input: ListLexiconsInput| async move {
+                client.list_lexicons(input).await
+            },
+            |_input: &ListLexiconsInput, output: &ListLexiconsOutput| {
+                Some(ListLexiconsInput {
+                    next_token: Some(output.next_token.clone()?),
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_lexicons`, streaming the `Lexicons` of every page of results.
+    pub fn list_lexicons_items(
+        &self,
+        input: ListLexiconsInput,
+    ) -> rusoto_core::pagination::RusotoStream<LexiconDescription, ListLexiconsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_lexicons_pages(input),
+            |output: ListLexiconsOutput| output.lexicons.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_speech_synthesis_tasks`, streaming every page of results.
+    pub fn list_speech_synthesis_tasks_pages(
+        &self,
+        input: ListSpeechSynthesisTasksInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListSpeechSynthesisTasksOutput,
+        ListSpeechSynthesisTasksError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: PollyClient, input: ListSpeechSynthesisTasksInput| async move {
+                client.list_speech_synthesis_tasks(input).await
+            },
+            |input: &ListSpeechSynthesisTasksInput, output: &ListSpeechSynthesisTasksOutput| {
+                Some(ListSpeechSynthesisTasksInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_speech_synthesis_tasks`, streaming the `SynthesisTasks` of every page of results.
+    pub fn list_speech_synthesis_tasks_items(
+        &self,
+        input: ListSpeechSynthesisTasksInput,
+    ) -> rusoto_core::pagination::RusotoStream<SynthesisTask, ListSpeechSynthesisTasksError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_speech_synthesis_tasks_pages(input),
+            |output: ListSpeechSynthesisTasksOutput| output.synthesis_tasks.unwrap_or_default(),
+        )
+    }
+}
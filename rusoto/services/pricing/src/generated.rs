@@ -81,12 +81,100 @@ pub struct Filter {
     pub field: String,
     /// <p>The type of filter that you want to use.</p> <p>Valid values are: <code>TERM_MATCH</code>. <code>TERM_MATCH</code> returns only products that match both the given filter field and the given value.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: FilterType,
     /// <p>The service code or attribute value that you want to filter by. If you are filtering by service code this is the actual service code, such as <code>AmazonEC2</code>. If you are filtering by attribute name, this is the attribute value that you want the returned products to match, such as a <code>Provisioned IOPS</code> volume.</p>
     #[serde(rename = "Value")]
     pub value: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FilterType {
+    /// `TERM_MATCH`
+    TermMatch,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl FilterType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FilterType::TermMatch => "TERM_MATCH",
+            FilterType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for FilterType {
+    fn default() -> FilterType {
+        FilterType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for FilterType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FilterType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FilterType {
+    fn from(value: &'a str) -> FilterType {
+        match value {
+            "TERM_MATCH" => FilterType::TermMatch,
+            _ => FilterType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FilterType {
+    fn from(value: String) -> FilterType {
+        match value.as_str() {
+            "TERM_MATCH" => FilterType::TermMatch,
+            _ => FilterType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for FilterType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<FilterType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FilterType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FilterType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FilterType {
+    fn deserialize<D>(deserializer: D) -> Result<FilterType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct GetAttributeValuesRequest {
@@ -368,11 +456,12 @@ pub struct PricingClient {
 impl PricingClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> PricingClient {
         PricingClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Pricing"),
         }
     }
 
@@ -404,6 +493,7 @@ impl Pricing for PricingClient {
         input: DescribeServicesRequest,
     ) -> Result<DescribeServicesResponse, RusotoError<DescribeServicesError>> {
         let mut request = SignedRequest::new("POST", "pricing", &self.region, "/");
+        request.set_operation("DescribeServices");
         request.set_endpoint_prefix("api.pricing".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSPriceListService.DescribeServices");
@@ -432,6 +522,7 @@ impl Pricing for PricingClient {
         input: GetAttributeValuesRequest,
     ) -> Result<GetAttributeValuesResponse, RusotoError<GetAttributeValuesError>> {
         let mut request = SignedRequest::new("POST", "pricing", &self.region, "/");
+        request.set_operation("GetAttributeValues");
         request.set_endpoint_prefix("api.pricing".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSPriceListService.GetAttributeValues");
@@ -460,6 +551,7 @@ impl Pricing for PricingClient {
         input: GetProductsRequest,
     ) -> Result<GetProductsResponse, RusotoError<GetProductsError>> {
         let mut request = SignedRequest::new("POST", "pricing", &self.region, "/");
+        request.set_operation("GetProducts");
         request.set_endpoint_prefix("api.pricing".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSPriceListService.GetProducts");
@@ -481,3 +573,99 @@ impl Pricing for PricingClient {
         }
     }
 }
+impl PricingClient {
+    /// Auto-paginating version of `describe_services`, streaming every page of results.
+    pub fn describe_services_pages(
+        &self,
+        input: DescribeServicesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeServicesResponse, DescribeServicesError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: PricingClient, input: DescribeServicesRequest| async move {
+                client.describe_services(input).await
+            },
+            |input: &DescribeServicesRequest, output: &DescribeServicesResponse| {
+                Some(DescribeServicesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_services`, streaming the `Services` of every page of results.
+    pub fn describe_services_items(
+        &self,
+        input: DescribeServicesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Service, DescribeServicesError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_services_pages(input),
+            |output: DescribeServicesResponse| output.services.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_attribute_values`, streaming every page of results.
+    pub fn get_attribute_values_pages(
+        &self,
+        input: GetAttributeValuesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetAttributeValuesResponse, GetAttributeValuesError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: PricingClient, input: GetAttributeValuesRequest| async move {
+                client.get_attribute_values(input).await
+            },
+            |input: &GetAttributeValuesRequest, output: &GetAttributeValuesResponse| {
+                Some(GetAttributeValuesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_attribute_values`, streaming the `AttributeValues` of every page of results.
+    pub fn get_attribute_values_items(
+        &self,
+        input: GetAttributeValuesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<AttributeValue, GetAttributeValuesError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_attribute_values_pages(input),
+            |output: GetAttributeValuesResponse| output.attribute_values.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_products`, streaming every page of results.
+    pub fn get_products_pages(
+        &self,
+        input: GetProductsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetProductsResponse, GetProductsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: PricingClient, input: GetProductsRequest| async move {
+                client.get_products(input).await
+            },
+            |input: &GetProductsRequest, output: &GetProductsResponse| {
+                Some(GetProductsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_products`, streaming the `PriceList` of every page of results.
+    pub fn get_products_items(
+        &self,
+        input: GetProductsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, GetProductsError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_products_pages(input),
+            |output: GetProductsResponse| output.price_list.unwrap_or_default(),
+        )
+    }
+}
@@ -337,11 +337,12 @@ pub struct QldbSessionClient {
 impl QldbSessionClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> QldbSessionClient {
         QldbSessionClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "QLDB Session"),
         }
     }
 
@@ -373,6 +374,7 @@ impl QldbSession for QldbSessionClient {
         input: SendCommandRequest,
     ) -> Result<SendCommandResult, RusotoError<SendCommandError>> {
         let mut request = SignedRequest::new("POST", "qldb", &self.region, "/");
+        request.set_operation("SendCommand");
         request.set_endpoint_prefix("session.qldb".to_string());
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "QLDBSession.SendCommand");
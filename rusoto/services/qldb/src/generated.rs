@@ -37,7 +37,7 @@ pub struct CreateLedgerRequest {
     pub name: String,
     /// <p>The permissions mode to assign to the ledger that you want to create.</p>
     #[serde(rename = "PermissionsMode")]
-    pub permissions_mode: String,
+    pub permissions_mode: PermissionsMode,
     /// <p>The key-value pairs to add as tags to the ledger that you want to create. Tag keys are case sensitive. Tag values are case sensitive and can be null.</p>
     #[serde(rename = "Tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -53,8 +53,13 @@ pub struct CreateLedgerResponse {
     pub arn: Option<String>,
     /// <p>The date and time, in epoch time format, when the ledger was created. (Epoch time format is the number of seconds elapsed since 12:00:00 AM January 1, 1970 UTC.)</p>
     #[serde(rename = "CreationDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date_time: Option<f64>,
+    pub creation_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The flag that prevents a ledger from being deleted by any user. If not provided on ledger creation, this feature is enabled (<code>true</code>) by default.</p> <p>If deletion protection is enabled, you must first disable it before you can delete the ledger using the QLDB API or the AWS Command Line Interface (AWS CLI). You can disable it by calling the <code>UpdateLedger</code> operation to set the flag to <code>false</code>. The QLDB console disables deletion protection for you when you use it to delete a ledger.</p>
     #[serde(rename = "DeletionProtection")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -66,7 +71,7 @@ pub struct CreateLedgerResponse {
     /// <p>The current status of the ledger.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<LedgerState>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -113,8 +118,13 @@ pub struct DescribeLedgerResponse {
     pub arn: Option<String>,
     /// <p>The date and time, in epoch time format, when the ledger was created. (Epoch time format is the number of seconds elapsed since 12:00:00 AM January 1, 1970 UTC.)</p>
     #[serde(rename = "CreationDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date_time: Option<f64>,
+    pub creation_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The flag that prevents a ledger from being deleted by any user. If not provided on ledger creation, this feature is enabled (<code>true</code>) by default.</p> <p>If deletion protection is enabled, you must first disable it before you can delete the ledger using the QLDB API or the AWS Command Line Interface (AWS CLI). You can disable it by calling the <code>UpdateLedger</code> operation to set the flag to <code>false</code>. The QLDB console disables deletion protection for you when you use it to delete a ledger.</p>
     #[serde(rename = "DeletionProtection")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -126,7 +136,7 @@ pub struct DescribeLedgerResponse {
     /// <p>The current status of the ledger.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<LedgerState>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -134,10 +144,20 @@ pub struct DescribeLedgerResponse {
 pub struct ExportJournalToS3Request {
     /// <p>The exclusive end date and time for the range of journal contents that you want to export.</p> <p>The <code>ExclusiveEndTime</code> must be in <code>ISO 8601</code> date and time format and in Universal Coordinated Time (UTC). For example: <code>2019-06-13T21:36:34Z</code> </p> <p>The <code>ExclusiveEndTime</code> must be less than or equal to the current UTC date and time.</p>
     #[serde(rename = "ExclusiveEndTime")]
-    pub exclusive_end_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub exclusive_end_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The inclusive start date and time for the range of journal contents that you want to export.</p> <p>The <code>InclusiveStartTime</code> must be in <code>ISO 8601</code> date and time format and in Universal Coordinated Time (UTC). For example: <code>2019-06-13T21:36:34Z</code> </p> <p>The <code>InclusiveStartTime</code> must be before <code>ExclusiveEndTime</code>.</p> <p>If you provide an <code>InclusiveStartTime</code> that is before the ledger's <code>CreationDateTime</code>, Amazon QLDB defaults it to the ledger's <code>CreationDateTime</code>.</p>
     #[serde(rename = "InclusiveStartTime")]
-    pub inclusive_start_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub inclusive_start_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The name of the ledger.</p>
     #[serde(rename = "Name")]
     pub name: String,
@@ -157,6 +177,104 @@ pub struct ExportJournalToS3Response {
     pub export_id: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ExportStatus {
+    /// `IN_PROGRESS`
+    InProgress,
+    /// `COMPLETED`
+    Completed,
+    /// `CANCELLED`
+    Cancelled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ExportStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ExportStatus::InProgress => "IN_PROGRESS",
+            ExportStatus::Completed => "COMPLETED",
+            ExportStatus::Cancelled => "CANCELLED",
+            ExportStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ExportStatus {
+    fn default() -> ExportStatus {
+        ExportStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ExportStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ExportStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ExportStatus {
+    fn from(value: &'a str) -> ExportStatus {
+        match value {
+            "IN_PROGRESS" => ExportStatus::InProgress,
+            "COMPLETED" => ExportStatus::Completed,
+            "CANCELLED" => ExportStatus::Cancelled,
+            _ => ExportStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ExportStatus {
+    fn from(value: String) -> ExportStatus {
+        match value.as_str() {
+            "IN_PROGRESS" => ExportStatus::InProgress,
+            "COMPLETED" => ExportStatus::Completed,
+            "CANCELLED" => ExportStatus::Cancelled,
+            _ => ExportStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ExportStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ExportStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ExportStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ExportStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ExportStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ExportStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct GetBlockRequest {
@@ -244,16 +362,31 @@ pub struct GetRevisionResponse {
 pub struct JournalS3ExportDescription {
     /// <p>The exclusive end date and time for the range of journal contents that are specified in the original export request.</p>
     #[serde(rename = "ExclusiveEndTime")]
-    pub exclusive_end_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub exclusive_end_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The date and time, in epoch time format, when the export job was created. (Epoch time format is the number of seconds elapsed since 12:00:00 AM January 1, 1970 UTC.)</p>
     #[serde(rename = "ExportCreationTime")]
-    pub export_creation_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub export_creation_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The unique ID of the journal export job.</p>
     #[serde(rename = "ExportId")]
     pub export_id: String,
     /// <p>The inclusive start date and time for the range of journal contents that are specified in the original export request.</p>
     #[serde(rename = "InclusiveStartTime")]
-    pub inclusive_start_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub inclusive_start_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The name of the ledger.</p>
     #[serde(rename = "LedgerName")]
     pub ledger_name: String,
@@ -264,17 +397,125 @@ pub struct JournalS3ExportDescription {
     pub s3_export_configuration: S3ExportConfiguration,
     /// <p>The current state of the journal export job.</p>
     #[serde(rename = "Status")]
-    pub status: String,
+    pub status: ExportStatus,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LedgerState {
+    /// `CREATING`
+    Creating,
+    /// `ACTIVE`
+    Active,
+    /// `DELETING`
+    Deleting,
+    /// `DELETED`
+    Deleted,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LedgerState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LedgerState::Creating => "CREATING",
+            LedgerState::Active => "ACTIVE",
+            LedgerState::Deleting => "DELETING",
+            LedgerState::Deleted => "DELETED",
+            LedgerState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LedgerState {
+    fn default() -> LedgerState {
+        LedgerState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LedgerState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LedgerState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LedgerState {
+    fn from(value: &'a str) -> LedgerState {
+        match value {
+            "CREATING" => LedgerState::Creating,
+            "ACTIVE" => LedgerState::Active,
+            "DELETING" => LedgerState::Deleting,
+            "DELETED" => LedgerState::Deleted,
+            _ => LedgerState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LedgerState {
+    fn from(value: String) -> LedgerState {
+        match value.as_str() {
+            "CREATING" => LedgerState::Creating,
+            "ACTIVE" => LedgerState::Active,
+            "DELETING" => LedgerState::Deleting,
+            "DELETED" => LedgerState::Deleted,
+            _ => LedgerState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LedgerState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LedgerState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LedgerState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LedgerState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for LedgerState {
+    fn deserialize<D>(deserializer: D) -> Result<LedgerState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about a ledger, including its name, state, and when it was created.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LedgerSummary {
     /// <p>The date and time, in epoch time format, when the ledger was created. (Epoch time format is the number of seconds elapsed since 12:00:00 AM January 1, 1970 UTC.)</p>
     #[serde(rename = "CreationDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date_time: Option<f64>,
+    pub creation_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the ledger.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -282,7 +523,7 @@ pub struct LedgerSummary {
     /// <p>The current status of the ledger.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<LedgerState>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -383,6 +624,99 @@ pub struct ListTagsForResourceResponse {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PermissionsMode {
+    /// `ALLOW_ALL`
+    AllowAll,
+    /// `STANDARD`
+    Standard,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PermissionsMode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PermissionsMode::AllowAll => "ALLOW_ALL",
+            PermissionsMode::Standard => "STANDARD",
+            PermissionsMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PermissionsMode {
+    fn default() -> PermissionsMode {
+        PermissionsMode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PermissionsMode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PermissionsMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PermissionsMode {
+    fn from(value: &'a str) -> PermissionsMode {
+        match value {
+            "ALLOW_ALL" => PermissionsMode::AllowAll,
+            "STANDARD" => PermissionsMode::Standard,
+            _ => PermissionsMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PermissionsMode {
+    fn from(value: String) -> PermissionsMode {
+        match value.as_str() {
+            "ALLOW_ALL" => PermissionsMode::AllowAll,
+            "STANDARD" => PermissionsMode::Standard,
+            _ => PermissionsMode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PermissionsMode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PermissionsMode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PermissionsMode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PermissionsMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PermissionsMode {
+    fn deserialize<D>(deserializer: D) -> Result<PermissionsMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The encryption settings that are used by a journal export job to write data in an Amazon Simple Storage Service (Amazon S3) bucket.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct S3EncryptionConfiguration {
@@ -392,7 +726,7 @@ pub struct S3EncryptionConfiguration {
     pub kms_key_arn: Option<String>,
     /// <p>The Amazon S3 object encryption type.</p> <p>To learn more about server-side encryption options in Amazon S3, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/serv-side-encryption.html">Protecting Data Using Server-Side Encryption</a> in the <i>Amazon S3 Developer Guide</i>.</p>
     #[serde(rename = "ObjectEncryptionType")]
-    pub object_encryption_type: String,
+    pub object_encryption_type: S3ObjectEncryptionType,
 }
 
 /// <p>The Amazon Simple Storage Service (Amazon S3) bucket location in which a journal export job writes the journal contents.</p>
@@ -409,6 +743,104 @@ pub struct S3ExportConfiguration {
     pub prefix: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum S3ObjectEncryptionType {
+    /// `SSE_KMS`
+    SseKms,
+    /// `SSE_S3`
+    SseS3,
+    /// `NO_ENCRYPTION`
+    NoEncryption,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl S3ObjectEncryptionType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            S3ObjectEncryptionType::SseKms => "SSE_KMS",
+            S3ObjectEncryptionType::SseS3 => "SSE_S3",
+            S3ObjectEncryptionType::NoEncryption => "NO_ENCRYPTION",
+            S3ObjectEncryptionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for S3ObjectEncryptionType {
+    fn default() -> S3ObjectEncryptionType {
+        S3ObjectEncryptionType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for S3ObjectEncryptionType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for S3ObjectEncryptionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for S3ObjectEncryptionType {
+    fn from(value: &'a str) -> S3ObjectEncryptionType {
+        match value {
+            "SSE_KMS" => S3ObjectEncryptionType::SseKms,
+            "SSE_S3" => S3ObjectEncryptionType::SseS3,
+            "NO_ENCRYPTION" => S3ObjectEncryptionType::NoEncryption,
+            _ => S3ObjectEncryptionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for S3ObjectEncryptionType {
+    fn from(value: String) -> S3ObjectEncryptionType {
+        match value.as_str() {
+            "SSE_KMS" => S3ObjectEncryptionType::SseKms,
+            "SSE_S3" => S3ObjectEncryptionType::SseS3,
+            "NO_ENCRYPTION" => S3ObjectEncryptionType::NoEncryption,
+            _ => S3ObjectEncryptionType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for S3ObjectEncryptionType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<S3ObjectEncryptionType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for S3ObjectEncryptionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for S3ObjectEncryptionType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for S3ObjectEncryptionType {
+    fn deserialize<D>(deserializer: D) -> Result<S3ObjectEncryptionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct TagResourceRequest {
@@ -460,8 +892,13 @@ pub struct UpdateLedgerResponse {
     pub arn: Option<String>,
     /// <p>The date and time, in epoch time format, when the ledger was created. (Epoch time format is the number of seconds elapsed since 12:00:00 AM January 1, 1970 UTC.)</p>
     #[serde(rename = "CreationDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date_time: Option<f64>,
+    pub creation_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The flag that prevents a ledger from being deleted by any user. If not provided on ledger creation, this feature is enabled (<code>true</code>) by default.</p> <p>If deletion protection is enabled, you must first disable it before you can delete the ledger using the QLDB API or the AWS Command Line Interface (AWS CLI). You can disable it by calling the <code>UpdateLedger</code> operation to set the flag to <code>false</code>. The QLDB console disables deletion protection for you when you use it to delete a ledger.</p>
     #[serde(rename = "DeletionProtection")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -473,7 +910,7 @@ pub struct UpdateLedgerResponse {
     /// <p>The current status of the ledger.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<LedgerState>,
 }
 
 /// <p>A structure that can contain an Amazon Ion value in multiple encoding formats.</p>
@@ -1141,11 +1578,12 @@ pub struct QldbClient {
 impl QldbClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> QldbClient {
         QldbClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "QLDB"),
         }
     }
 
@@ -1179,6 +1617,7 @@ impl Qldb for QldbClient {
         let request_uri = "/ledgers";
 
         let mut request = SignedRequest::new("POST", "qldb", &self.region, &request_uri);
+        request.set_operation("CreateLedger");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1209,6 +1648,7 @@ impl Qldb for QldbClient {
         let request_uri = format!("/ledgers/{name}", name = input.name);
 
         let mut request = SignedRequest::new("DELETE", "qldb", &self.region, &request_uri);
+        request.set_operation("DeleteLedger");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1239,6 +1679,7 @@ impl Qldb for QldbClient {
         );
 
         let mut request = SignedRequest::new("GET", "qldb", &self.region, &request_uri);
+        request.set_operation("DescribeJournalS3Export");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1266,6 +1707,7 @@ impl Qldb for QldbClient {
         let request_uri = format!("/ledgers/{name}", name = input.name);
 
         let mut request = SignedRequest::new("GET", "qldb", &self.region, &request_uri);
+        request.set_operation("DescribeLedger");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1293,6 +1735,7 @@ impl Qldb for QldbClient {
         let request_uri = format!("/ledgers/{name}/journal-s3-exports", name = input.name);
 
         let mut request = SignedRequest::new("POST", "qldb", &self.region, &request_uri);
+        request.set_operation("ExportJournalToS3");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1323,6 +1766,7 @@ impl Qldb for QldbClient {
         let request_uri = format!("/ledgers/{name}/block", name = input.name);
 
         let mut request = SignedRequest::new("POST", "qldb", &self.region, &request_uri);
+        request.set_operation("GetBlock");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1353,6 +1797,7 @@ impl Qldb for QldbClient {
         let request_uri = format!("/ledgers/{name}/digest", name = input.name);
 
         let mut request = SignedRequest::new("POST", "qldb", &self.region, &request_uri);
+        request.set_operation("GetDigest");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1380,6 +1825,7 @@ impl Qldb for QldbClient {
         let request_uri = format!("/ledgers/{name}/revision", name = input.name);
 
         let mut request = SignedRequest::new("POST", "qldb", &self.region, &request_uri);
+        request.set_operation("GetRevision");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1410,6 +1856,7 @@ impl Qldb for QldbClient {
         let request_uri = "/journal-s3-exports";
 
         let mut request = SignedRequest::new("GET", "qldb", &self.region, &request_uri);
+        request.set_operation("ListJournalS3Exports");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1449,6 +1896,7 @@ impl Qldb for QldbClient {
         let request_uri = format!("/ledgers/{name}/journal-s3-exports", name = input.name);
 
         let mut request = SignedRequest::new("GET", "qldb", &self.region, &request_uri);
+        request.set_operation("ListJournalS3ExportsForLedger");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1485,6 +1933,7 @@ impl Qldb for QldbClient {
         let request_uri = "/ledgers";
 
         let mut request = SignedRequest::new("GET", "qldb", &self.region, &request_uri);
+        request.set_operation("ListLedgers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1521,6 +1970,7 @@ impl Qldb for QldbClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "qldb", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1548,6 +1998,7 @@ impl Qldb for QldbClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "qldb", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1578,6 +2029,7 @@ impl Qldb for QldbClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "qldb", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1611,6 +2063,7 @@ impl Qldb for QldbClient {
         let request_uri = format!("/ledgers/{name}", name = input.name);
 
         let mut request = SignedRequest::new("PATCH", "qldb", &self.region, &request_uri);
+        request.set_operation("UpdateLedger");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -46,7 +46,7 @@ pub struct AdHocFilteringOption {
     /// <p>Availability status.</p>
     #[serde(rename = "AvailabilityStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub availability_status: Option<String>,
+    pub availability_status: Option<DashboardBehavior>,
 }
 
 /// <p>Amazon Elasticsearch Service parameters.</p>
@@ -57,6 +57,104 @@ pub struct AmazonElasticsearchParameters {
     pub domain: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AssignmentStatus {
+    /// `ENABLED`
+    Enabled,
+    /// `DRAFT`
+    Draft,
+    /// `DISABLED`
+    Disabled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AssignmentStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AssignmentStatus::Enabled => "ENABLED",
+            AssignmentStatus::Draft => "DRAFT",
+            AssignmentStatus::Disabled => "DISABLED",
+            AssignmentStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AssignmentStatus {
+    fn default() -> AssignmentStatus {
+        AssignmentStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AssignmentStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AssignmentStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AssignmentStatus {
+    fn from(value: &'a str) -> AssignmentStatus {
+        match value {
+            "ENABLED" => AssignmentStatus::Enabled,
+            "DRAFT" => AssignmentStatus::Draft,
+            "DISABLED" => AssignmentStatus::Disabled,
+            _ => AssignmentStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AssignmentStatus {
+    fn from(value: String) -> AssignmentStatus {
+        match value.as_str() {
+            "ENABLED" => AssignmentStatus::Enabled,
+            "DRAFT" => AssignmentStatus::Draft,
+            "DISABLED" => AssignmentStatus::Disabled,
+            _ => AssignmentStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AssignmentStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AssignmentStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AssignmentStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AssignmentStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AssignmentStatus {
+    fn deserialize<D>(deserializer: D) -> Result<AssignmentStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Amazon Athena parameters.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AthenaParameters {
@@ -163,9 +261,112 @@ pub struct CastColumnTypeOperation {
     pub format: Option<String>,
     /// <p>New column data type.</p>
     #[serde(rename = "NewColumnType")]
-    pub new_column_type: String,
+    pub new_column_type: ColumnDataType,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ColumnDataType {
+    /// `STRING`
+    String,
+    /// `INTEGER`
+    Integer,
+    /// `DECIMAL`
+    Decimal,
+    /// `DATETIME`
+    Datetime,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ColumnDataType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ColumnDataType::String => "STRING",
+            ColumnDataType::Integer => "INTEGER",
+            ColumnDataType::Decimal => "DECIMAL",
+            ColumnDataType::Datetime => "DATETIME",
+            ColumnDataType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ColumnDataType {
+    fn default() -> ColumnDataType {
+        ColumnDataType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ColumnDataType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ColumnDataType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ColumnDataType {
+    fn from(value: &'a str) -> ColumnDataType {
+        match value {
+            "STRING" => ColumnDataType::String,
+            "INTEGER" => ColumnDataType::Integer,
+            "DECIMAL" => ColumnDataType::Decimal,
+            "DATETIME" => ColumnDataType::Datetime,
+            _ => ColumnDataType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ColumnDataType {
+    fn from(value: String) -> ColumnDataType {
+        match value.as_str() {
+            "STRING" => ColumnDataType::String,
+            "INTEGER" => ColumnDataType::Integer,
+            "DECIMAL" => ColumnDataType::Decimal,
+            "DATETIME" => ColumnDataType::Datetime,
+            _ => ColumnDataType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ColumnDataType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ColumnDataType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ColumnDataType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ColumnDataType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for ColumnDataType {
+    fn deserialize<D>(deserializer: D) -> Result<ColumnDataType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Groupings of columns that work together in certain Amazon QuickSight features. This is a variant type structure. For this structure to be valid, only one of the attributes can be non-null.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ColumnGroup {
@@ -223,7 +424,7 @@ pub struct ColumnTag {
     /// <p>A geospatial role for a column.</p>
     #[serde(rename = "ColumnGeographicRole")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub column_geographic_role: Option<String>,
+    pub column_geographic_role: Option<GeoSpatialDataRole>,
 }
 
 /// <p>A transform operation that creates calculated columns. Columns created in one such operation form a lexical closure.</p>
@@ -281,7 +482,7 @@ pub struct CreateDashboardResponse {
     /// <p>The status of the dashboard creation request.</p>
     #[serde(rename = "CreationStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_status: Option<String>,
+    pub creation_status: Option<ResourceStatus>,
     /// <p>The ID for the dashboard.</p>
     #[serde(rename = "DashboardId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -315,7 +516,7 @@ pub struct CreateDataSetRequest {
     pub data_set_id: String,
     /// <p>Indicates whether you want to import the data into SPICE.</p>
     #[serde(rename = "ImportMode")]
-    pub import_mode: String,
+    pub import_mode: DataSetImportMode,
     /// <p>Configures the combination and transformation of the data from the physical tables.</p>
     #[serde(rename = "LogicalTableMap")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -403,7 +604,7 @@ pub struct CreateDataSourceRequest {
     pub tags: Option<Vec<Tag>>,
     /// <p>The type of the data source. Currently, the supported types for this operation are: <code>ATHENA, AURORA, AURORA_POSTGRESQL, MARIADB, MYSQL, POSTGRESQL, PRESTO, REDSHIFT, S3, SNOWFLAKE, SPARK, SQLSERVER, TERADATA</code>. Use <code>ListDataSources</code> to return a list of all data sources.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: DataSourceType,
     /// <p>Use this parameter only when you want QuickSight to use a VPC connection when connecting to your underlying source.</p>
     #[serde(rename = "VpcConnectionProperties")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -420,7 +621,7 @@ pub struct CreateDataSourceResponse {
     /// <p>The status of creating the data source.</p>
     #[serde(rename = "CreationStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_status: Option<String>,
+    pub creation_status: Option<ResourceStatus>,
     /// <p>The ID of the data source. This ID is unique per AWS Region for each AWS account.</p>
     #[serde(rename = "DataSourceId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -514,7 +715,7 @@ pub struct CreateIAMPolicyAssignmentRequest {
     pub assignment_name: String,
     /// <p><p>The status of the assignment. Possible values are as follows:</p> <ul> <li> <p> <code>ENABLED</code> - Anything specified in this assignment is used when creating the data source.</p> </li> <li> <p> <code>DISABLED</code> - This assignment isn&#39;t used when creating the data source.</p> </li> <li> <p> <code>DRAFT</code> - This assignment is an unfinished draft and isn&#39;t used when creating the data source.</p> </li> </ul></p>
     #[serde(rename = "AssignmentStatus")]
-    pub assignment_status: String,
+    pub assignment_status: AssignmentStatus,
     /// <p>The ID of the AWS account where you want to assign an IAM policy to QuickSight users or groups.</p>
     #[serde(rename = "AwsAccountId")]
     pub aws_account_id: String,
@@ -545,7 +746,7 @@ pub struct CreateIAMPolicyAssignmentResponse {
     /// <p><p>The status of the assignment. Possible values are as follows:</p> <ul> <li> <p> <code>ENABLED</code> - Anything specified in this assignment is used when creating the data source.</p> </li> <li> <p> <code>DISABLED</code> - This assignment isn&#39;t used when creating the data source.</p> </li> <li> <p> <code>DRAFT</code> - This assignment is an unfinished draft and isn&#39;t used when creating the data source.</p> </li> </ul></p>
     #[serde(rename = "AssignmentStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub assignment_status: Option<String>,
+    pub assignment_status: Option<AssignmentStatus>,
     /// <p>The QuickSight users, groups, or both that the IAM policy is assigned to.</p>
     #[serde(rename = "Identities")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -592,7 +793,7 @@ pub struct CreateIngestionResponse {
     /// <p>The ingestion status.</p>
     #[serde(rename = "IngestionStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ingestion_status: Option<String>,
+    pub ingestion_status: Option<IngestionStatus>,
     /// <p>The AWS request ID for this operation.</p>
     #[serde(rename = "RequestId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -677,7 +878,7 @@ pub struct CreateTemplateResponse {
     /// <p>The template creation status.</p>
     #[serde(rename = "CreationStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_status: Option<String>,
+    pub creation_status: Option<ResourceStatus>,
     /// <p>The AWS request ID for this operation.</p>
     #[serde(rename = "RequestId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -736,20 +937,35 @@ pub struct Dashboard {
     pub arn: Option<String>,
     /// <p>The time that this dataset was created.</p>
     #[serde(rename = "CreatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time: Option<f64>,
+    pub created_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Dashboard ID.</p>
     #[serde(rename = "DashboardId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub dashboard_id: Option<String>,
     /// <p>The last time that this dataset was published.</p>
     #[serde(rename = "LastPublishedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_published_time: Option<f64>,
+    pub last_published_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The last time that this dataset was updated.</p>
     #[serde(rename = "LastUpdatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_time: Option<f64>,
+    pub last_updated_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A display name for the dataset.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -760,6 +976,99 @@ pub struct Dashboard {
     pub version: Option<DashboardVersion>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DashboardBehavior {
+    /// `ENABLED`
+    Enabled,
+    /// `DISABLED`
+    Disabled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DashboardBehavior {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DashboardBehavior::Enabled => "ENABLED",
+            DashboardBehavior::Disabled => "DISABLED",
+            DashboardBehavior::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DashboardBehavior {
+    fn default() -> DashboardBehavior {
+        DashboardBehavior::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DashboardBehavior {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DashboardBehavior {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DashboardBehavior {
+    fn from(value: &'a str) -> DashboardBehavior {
+        match value {
+            "ENABLED" => DashboardBehavior::Enabled,
+            "DISABLED" => DashboardBehavior::Disabled,
+            _ => DashboardBehavior::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DashboardBehavior {
+    fn from(value: String) -> DashboardBehavior {
+        match value.as_str() {
+            "ENABLED" => DashboardBehavior::Enabled,
+            "DISABLED" => DashboardBehavior::Disabled,
+            _ => DashboardBehavior::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DashboardBehavior {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DashboardBehavior, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DashboardBehavior {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DashboardBehavior {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DashboardBehavior {
+    fn deserialize<D>(deserializer: D) -> Result<DashboardBehavior, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Dashboard error.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -771,9 +1080,142 @@ pub struct DashboardError {
     /// <p>Type.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<DashboardErrorType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DashboardErrorType {
+    /// `ACCESS_DENIED`
+    AccessDenied,
+    /// `SOURCE_NOT_FOUND`
+    SourceNotFound,
+    /// `DATA_SET_NOT_FOUND`
+    DataSetNotFound,
+    /// `INTERNAL_FAILURE`
+    InternalFailure,
+    /// `PARAMETER_VALUE_INCOMPATIBLE`
+    ParameterValueIncompatible,
+    /// `PARAMETER_TYPE_INVALID`
+    ParameterTypeInvalid,
+    /// `PARAMETER_NOT_FOUND`
+    ParameterNotFound,
+    /// `COLUMN_TYPE_MISMATCH`
+    ColumnTypeMismatch,
+    /// `COLUMN_GEOGRAPHIC_ROLE_MISMATCH`
+    ColumnGeographicRoleMismatch,
+    /// `COLUMN_REPLACEMENT_MISSING`
+    ColumnReplacementMissing,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DashboardErrorType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DashboardErrorType::AccessDenied => "ACCESS_DENIED",
+            DashboardErrorType::SourceNotFound => "SOURCE_NOT_FOUND",
+            DashboardErrorType::DataSetNotFound => "DATA_SET_NOT_FOUND",
+            DashboardErrorType::InternalFailure => "INTERNAL_FAILURE",
+            DashboardErrorType::ParameterValueIncompatible => "PARAMETER_VALUE_INCOMPATIBLE",
+            DashboardErrorType::ParameterTypeInvalid => "PARAMETER_TYPE_INVALID",
+            DashboardErrorType::ParameterNotFound => "PARAMETER_NOT_FOUND",
+            DashboardErrorType::ColumnTypeMismatch => "COLUMN_TYPE_MISMATCH",
+            DashboardErrorType::ColumnGeographicRoleMismatch => "COLUMN_GEOGRAPHIC_ROLE_MISMATCH",
+            DashboardErrorType::ColumnReplacementMissing => "COLUMN_REPLACEMENT_MISSING",
+            DashboardErrorType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DashboardErrorType {
+    fn default() -> DashboardErrorType {
+        DashboardErrorType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DashboardErrorType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
 }
 
+impl fmt::Display for DashboardErrorType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DashboardErrorType {
+    fn from(value: &'a str) -> DashboardErrorType {
+        match value {
+            "ACCESS_DENIED" => DashboardErrorType::AccessDenied,
+            "SOURCE_NOT_FOUND" => DashboardErrorType::SourceNotFound,
+            "DATA_SET_NOT_FOUND" => DashboardErrorType::DataSetNotFound,
+            "INTERNAL_FAILURE" => DashboardErrorType::InternalFailure,
+            "PARAMETER_VALUE_INCOMPATIBLE" => DashboardErrorType::ParameterValueIncompatible,
+            "PARAMETER_TYPE_INVALID" => DashboardErrorType::ParameterTypeInvalid,
+            "PARAMETER_NOT_FOUND" => DashboardErrorType::ParameterNotFound,
+            "COLUMN_TYPE_MISMATCH" => DashboardErrorType::ColumnTypeMismatch,
+            "COLUMN_GEOGRAPHIC_ROLE_MISMATCH" => DashboardErrorType::ColumnGeographicRoleMismatch,
+            "COLUMN_REPLACEMENT_MISSING" => DashboardErrorType::ColumnReplacementMissing,
+            _ => DashboardErrorType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DashboardErrorType {
+    fn from(value: String) -> DashboardErrorType {
+        match value.as_str() {
+            "ACCESS_DENIED" => DashboardErrorType::AccessDenied,
+            "SOURCE_NOT_FOUND" => DashboardErrorType::SourceNotFound,
+            "DATA_SET_NOT_FOUND" => DashboardErrorType::DataSetNotFound,
+            "INTERNAL_FAILURE" => DashboardErrorType::InternalFailure,
+            "PARAMETER_VALUE_INCOMPATIBLE" => DashboardErrorType::ParameterValueIncompatible,
+            "PARAMETER_TYPE_INVALID" => DashboardErrorType::ParameterTypeInvalid,
+            "PARAMETER_NOT_FOUND" => DashboardErrorType::ParameterNotFound,
+            "COLUMN_TYPE_MISMATCH" => DashboardErrorType::ColumnTypeMismatch,
+            "COLUMN_GEOGRAPHIC_ROLE_MISMATCH" => DashboardErrorType::ColumnGeographicRoleMismatch,
+            "COLUMN_REPLACEMENT_MISSING" => DashboardErrorType::ColumnReplacementMissing,
+            _ => DashboardErrorType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DashboardErrorType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DashboardErrorType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DashboardErrorType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DashboardErrorType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DashboardErrorType {
+    fn deserialize<D>(deserializer: D) -> Result<DashboardErrorType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Dashboard publish options.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -824,20 +1266,35 @@ pub struct DashboardSummary {
     pub arn: Option<String>,
     /// <p>The time that this dashboard was created.</p>
     #[serde(rename = "CreatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time: Option<f64>,
+    pub created_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Dashboard ID.</p>
     #[serde(rename = "DashboardId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub dashboard_id: Option<String>,
     /// <p>The last time that this dashboard was published.</p>
     #[serde(rename = "LastPublishedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_published_time: Option<f64>,
+    pub last_published_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The last time that this dashboard was updated.</p>
     #[serde(rename = "LastUpdatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_time: Option<f64>,
+    pub last_updated_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A display name for the dashboard.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -848,6 +1305,99 @@ pub struct DashboardSummary {
     pub published_version_number: Option<i64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DashboardUIState {
+    /// `EXPANDED`
+    Expanded,
+    /// `COLLAPSED`
+    Collapsed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DashboardUIState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DashboardUIState::Expanded => "EXPANDED",
+            DashboardUIState::Collapsed => "COLLAPSED",
+            DashboardUIState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DashboardUIState {
+    fn default() -> DashboardUIState {
+        DashboardUIState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DashboardUIState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DashboardUIState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DashboardUIState {
+    fn from(value: &'a str) -> DashboardUIState {
+        match value {
+            "EXPANDED" => DashboardUIState::Expanded,
+            "COLLAPSED" => DashboardUIState::Collapsed,
+            _ => DashboardUIState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DashboardUIState {
+    fn from(value: String) -> DashboardUIState {
+        match value.as_str() {
+            "EXPANDED" => DashboardUIState::Expanded,
+            "COLLAPSED" => DashboardUIState::Collapsed,
+            _ => DashboardUIState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DashboardUIState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DashboardUIState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DashboardUIState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DashboardUIState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DashboardUIState {
+    fn deserialize<D>(deserializer: D) -> Result<DashboardUIState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Dashboard version.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -858,8 +1408,13 @@ pub struct DashboardVersion {
     pub arn: Option<String>,
     /// <p>The time that this dashboard version was created.</p>
     #[serde(rename = "CreatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time: Option<f64>,
+    pub created_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Description.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -875,7 +1430,7 @@ pub struct DashboardVersion {
     /// <p>The HTTP status of the request.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ResourceStatus>,
     /// <p>Version number.</p>
     #[serde(rename = "VersionNumber")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -892,8 +1447,13 @@ pub struct DashboardVersionSummary {
     pub arn: Option<String>,
     /// <p>The time that this dashboard version was created.</p>
     #[serde(rename = "CreatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time: Option<f64>,
+    pub created_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Description.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -905,7 +1465,7 @@ pub struct DashboardVersionSummary {
     /// <p>The HTTP status of the request.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ResourceStatus>,
     /// <p>Version number.</p>
     #[serde(rename = "VersionNumber")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -930,8 +1490,13 @@ pub struct DataSet {
     pub consumed_spice_capacity_in_bytes: Option<i64>,
     /// <p>The time that this dataset was created.</p>
     #[serde(rename = "CreatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time: Option<f64>,
+    pub created_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The ID of the dataset.</p>
     #[serde(rename = "DataSetId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -939,11 +1504,16 @@ pub struct DataSet {
     /// <p>Indicates whether you want to import the data into SPICE.</p>
     #[serde(rename = "ImportMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub import_mode: Option<String>,
+    pub import_mode: Option<DataSetImportMode>,
     /// <p>The last time that this dataset was updated.</p>
     #[serde(rename = "LastUpdatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_time: Option<f64>,
+    pub last_updated_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Configures the combination and transformation of the data from the physical tables.</p>
     #[serde(rename = "LogicalTableMap")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -984,6 +1554,99 @@ pub struct DataSetConfiguration {
     pub placeholder: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DataSetImportMode {
+    /// `SPICE`
+    Spice,
+    /// `DIRECT_QUERY`
+    DirectQuery,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DataSetImportMode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DataSetImportMode::Spice => "SPICE",
+            DataSetImportMode::DirectQuery => "DIRECT_QUERY",
+            DataSetImportMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DataSetImportMode {
+    fn default() -> DataSetImportMode {
+        DataSetImportMode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DataSetImportMode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DataSetImportMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DataSetImportMode {
+    fn from(value: &'a str) -> DataSetImportMode {
+        match value {
+            "SPICE" => DataSetImportMode::Spice,
+            "DIRECT_QUERY" => DataSetImportMode::DirectQuery,
+            _ => DataSetImportMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DataSetImportMode {
+    fn from(value: String) -> DataSetImportMode {
+        match value.as_str() {
+            "SPICE" => DataSetImportMode::Spice,
+            "DIRECT_QUERY" => DataSetImportMode::DirectQuery,
+            _ => DataSetImportMode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DataSetImportMode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DataSetImportMode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DataSetImportMode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DataSetImportMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DataSetImportMode {
+    fn deserialize<D>(deserializer: D) -> Result<DataSetImportMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Dataset reference.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -1016,8 +1679,13 @@ pub struct DataSetSummary {
     pub arn: Option<String>,
     /// <p>The time that this dataset was created.</p>
     #[serde(rename = "CreatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time: Option<f64>,
+    pub created_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The ID of the dataset.</p>
     #[serde(rename = "DataSetId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1025,11 +1693,16 @@ pub struct DataSetSummary {
     /// <p>Indicates whether you want to import the data into SPICE.</p>
     #[serde(rename = "ImportMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub import_mode: Option<String>,
+    pub import_mode: Option<DataSetImportMode>,
     /// <p>The last time that this dataset was updated.</p>
     #[serde(rename = "LastUpdatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_time: Option<f64>,
+    pub last_updated_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A display name for the dataset.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1050,8 +1723,13 @@ pub struct DataSource {
     pub arn: Option<String>,
     /// <p>The time that this data source was created.</p>
     #[serde(rename = "CreatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time: Option<f64>,
+    pub created_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The ID of the data source. This ID is unique per AWS Region for each AWS account.</p>
     #[serde(rename = "DataSourceId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1066,8 +1744,13 @@ pub struct DataSource {
     pub error_info: Option<DataSourceErrorInfo>,
     /// <p>The last time that this data source was updated.</p>
     #[serde(rename = "LastUpdatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_time: Option<f64>,
+    pub last_updated_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A display name for the data source.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1079,11 +1762,11 @@ pub struct DataSource {
     /// <p>The HTTP status of the request.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ResourceStatus>,
     /// <p>The type of the data source. This type indicates which database engine the data source connects to.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<DataSourceType>,
     /// <p>The VPC connection information. You need to use this parameter only when you want QuickSight to use a VPC connection when connecting to your underlying source.</p>
     #[serde(rename = "VpcConnectionProperties")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1111,24 +1794,147 @@ pub struct DataSourceErrorInfo {
     /// <p>Error type.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<DataSourceErrorInfoType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DataSourceErrorInfoType {
+    /// `ACCESS_DENIED`
+    AccessDenied,
+    /// `COPY_SOURCE_NOT_FOUND`
+    CopySourceNotFound,
+    /// `TIMEOUT`
+    Timeout,
+    /// `ENGINE_VERSION_NOT_SUPPORTED`
+    EngineVersionNotSupported,
+    /// `UNKNOWN_HOST`
+    UnknownHost,
+    /// `GENERIC_SQL_FAILURE`
+    GenericSqlFailure,
+    /// `CONFLICT`
+    Conflict,
+    /// `UNKNOWN`
+    UnknownValue,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DataSourceErrorInfoType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DataSourceErrorInfoType::AccessDenied => "ACCESS_DENIED",
+            DataSourceErrorInfoType::CopySourceNotFound => "COPY_SOURCE_NOT_FOUND",
+            DataSourceErrorInfoType::Timeout => "TIMEOUT",
+            DataSourceErrorInfoType::EngineVersionNotSupported => "ENGINE_VERSION_NOT_SUPPORTED",
+            DataSourceErrorInfoType::UnknownHost => "UNKNOWN_HOST",
+            DataSourceErrorInfoType::GenericSqlFailure => "GENERIC_SQL_FAILURE",
+            DataSourceErrorInfoType::Conflict => "CONFLICT",
+            DataSourceErrorInfoType::UnknownValue => "UNKNOWN",
+            DataSourceErrorInfoType::Unknown(ref value) => value,
+        }
+    }
 }
 
-/// <p>The parameters that Amazon QuickSight uses to connect to your underlying data source. This is a variant type structure. For this structure to be valid, only one of the attributes can be non-null.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct DataSourceParameters {
-    /// <p>Amazon Elasticsearch Service parameters.</p>
-    #[serde(rename = "AmazonElasticsearchParameters")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub amazon_elasticsearch_parameters: Option<AmazonElasticsearchParameters>,
-    /// <p>Amazon Athena parameters.</p>
-    #[serde(rename = "AthenaParameters")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub athena_parameters: Option<AthenaParameters>,
-    /// <p>Amazon Aurora MySQL parameters.</p>
-    #[serde(rename = "AuroraParameters")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub aurora_parameters: Option<AuroraParameters>,
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DataSourceErrorInfoType {
+    fn default() -> DataSourceErrorInfoType {
+        DataSourceErrorInfoType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DataSourceErrorInfoType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DataSourceErrorInfoType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DataSourceErrorInfoType {
+    fn from(value: &'a str) -> DataSourceErrorInfoType {
+        match value {
+            "ACCESS_DENIED" => DataSourceErrorInfoType::AccessDenied,
+            "COPY_SOURCE_NOT_FOUND" => DataSourceErrorInfoType::CopySourceNotFound,
+            "TIMEOUT" => DataSourceErrorInfoType::Timeout,
+            "ENGINE_VERSION_NOT_SUPPORTED" => DataSourceErrorInfoType::EngineVersionNotSupported,
+            "UNKNOWN_HOST" => DataSourceErrorInfoType::UnknownHost,
+            "GENERIC_SQL_FAILURE" => DataSourceErrorInfoType::GenericSqlFailure,
+            "CONFLICT" => DataSourceErrorInfoType::Conflict,
+            "UNKNOWN" => DataSourceErrorInfoType::UnknownValue,
+            _ => DataSourceErrorInfoType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DataSourceErrorInfoType {
+    fn from(value: String) -> DataSourceErrorInfoType {
+        match value.as_str() {
+            "ACCESS_DENIED" => DataSourceErrorInfoType::AccessDenied,
+            "COPY_SOURCE_NOT_FOUND" => DataSourceErrorInfoType::CopySourceNotFound,
+            "TIMEOUT" => DataSourceErrorInfoType::Timeout,
+            "ENGINE_VERSION_NOT_SUPPORTED" => DataSourceErrorInfoType::EngineVersionNotSupported,
+            "UNKNOWN_HOST" => DataSourceErrorInfoType::UnknownHost,
+            "GENERIC_SQL_FAILURE" => DataSourceErrorInfoType::GenericSqlFailure,
+            "CONFLICT" => DataSourceErrorInfoType::Conflict,
+            "UNKNOWN" => DataSourceErrorInfoType::UnknownValue,
+            _ => DataSourceErrorInfoType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DataSourceErrorInfoType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DataSourceErrorInfoType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DataSourceErrorInfoType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DataSourceErrorInfoType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DataSourceErrorInfoType {
+    fn deserialize<D>(deserializer: D) -> Result<DataSourceErrorInfoType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>The parameters that Amazon QuickSight uses to connect to your underlying data source. This is a variant type structure. For this structure to be valid, only one of the attributes can be non-null.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DataSourceParameters {
+    /// <p>Amazon Elasticsearch Service parameters.</p>
+    #[serde(rename = "AmazonElasticsearchParameters")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amazon_elasticsearch_parameters: Option<AmazonElasticsearchParameters>,
+    /// <p>Amazon Athena parameters.</p>
+    #[serde(rename = "AthenaParameters")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub athena_parameters: Option<AthenaParameters>,
+    /// <p>Amazon Aurora MySQL parameters.</p>
+    #[serde(rename = "AuroraParameters")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aurora_parameters: Option<AuroraParameters>,
     /// <p>Aurora PostgreSQL parameters.</p>
     #[serde(rename = "AuroraPostgreSqlParameters")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1195,6 +2001,234 @@ pub struct DataSourceParameters {
     pub twitter_parameters: Option<TwitterParameters>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DataSourceType {
+    /// `ADOBE_ANALYTICS`
+    AdobeAnalytics,
+    /// `AMAZON_ELASTICSEARCH`
+    AmazonElasticsearch,
+    /// `ATHENA`
+    Athena,
+    /// `AURORA`
+    Aurora,
+    /// `AURORA_POSTGRESQL`
+    AuroraPostgresql,
+    /// `AWS_IOT_ANALYTICS`
+    AwsIotAnalytics,
+    /// `GITHUB`
+    Github,
+    /// `JIRA`
+    Jira,
+    /// `MARIADB`
+    Mariadb,
+    /// `MYSQL`
+    Mysql,
+    /// `ORACLE`
+    Oracle,
+    /// `POSTGRESQL`
+    Postgresql,
+    /// `PRESTO`
+    Presto,
+    /// `REDSHIFT`
+    Redshift,
+    /// `S3`
+    S3,
+    /// `SALESFORCE`
+    Salesforce,
+    /// `SERVICENOW`
+    Servicenow,
+    /// `SNOWFLAKE`
+    Snowflake,
+    /// `SPARK`
+    Spark,
+    /// `SQLSERVER`
+    Sqlserver,
+    /// `TERADATA`
+    Teradata,
+    /// `TWITTER`
+    Twitter,
+    /// `TIMESTREAM`
+    Timestream,
+    /// `AMAZON_OPENSEARCH`
+    AmazonOpensearch,
+    /// `EXASOL`
+    Exasol,
+    /// `DATABRICKS`
+    Databricks,
+    /// `STARBURST`
+    Starburst,
+    /// `TRINO`
+    Trino,
+    /// `BIGQUERY`
+    Bigquery,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DataSourceType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DataSourceType::AdobeAnalytics => "ADOBE_ANALYTICS",
+            DataSourceType::AmazonElasticsearch => "AMAZON_ELASTICSEARCH",
+            DataSourceType::Athena => "ATHENA",
+            DataSourceType::Aurora => "AURORA",
+            DataSourceType::AuroraPostgresql => "AURORA_POSTGRESQL",
+            DataSourceType::AwsIotAnalytics => "AWS_IOT_ANALYTICS",
+            DataSourceType::Github => "GITHUB",
+            DataSourceType::Jira => "JIRA",
+            DataSourceType::Mariadb => "MARIADB",
+            DataSourceType::Mysql => "MYSQL",
+            DataSourceType::Oracle => "ORACLE",
+            DataSourceType::Postgresql => "POSTGRESQL",
+            DataSourceType::Presto => "PRESTO",
+            DataSourceType::Redshift => "REDSHIFT",
+            DataSourceType::S3 => "S3",
+            DataSourceType::Salesforce => "SALESFORCE",
+            DataSourceType::Servicenow => "SERVICENOW",
+            DataSourceType::Snowflake => "SNOWFLAKE",
+            DataSourceType::Spark => "SPARK",
+            DataSourceType::Sqlserver => "SQLSERVER",
+            DataSourceType::Teradata => "TERADATA",
+            DataSourceType::Twitter => "TWITTER",
+            DataSourceType::Timestream => "TIMESTREAM",
+            DataSourceType::AmazonOpensearch => "AMAZON_OPENSEARCH",
+            DataSourceType::Exasol => "EXASOL",
+            DataSourceType::Databricks => "DATABRICKS",
+            DataSourceType::Starburst => "STARBURST",
+            DataSourceType::Trino => "TRINO",
+            DataSourceType::Bigquery => "BIGQUERY",
+            DataSourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DataSourceType {
+    fn default() -> DataSourceType {
+        DataSourceType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DataSourceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DataSourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DataSourceType {
+    fn from(value: &'a str) -> DataSourceType {
+        match value {
+            "ADOBE_ANALYTICS" => DataSourceType::AdobeAnalytics,
+            "AMAZON_ELASTICSEARCH" => DataSourceType::AmazonElasticsearch,
+            "ATHENA" => DataSourceType::Athena,
+            "AURORA" => DataSourceType::Aurora,
+            "AURORA_POSTGRESQL" => DataSourceType::AuroraPostgresql,
+            "AWS_IOT_ANALYTICS" => DataSourceType::AwsIotAnalytics,
+            "GITHUB" => DataSourceType::Github,
+            "JIRA" => DataSourceType::Jira,
+            "MARIADB" => DataSourceType::Mariadb,
+            "MYSQL" => DataSourceType::Mysql,
+            "ORACLE" => DataSourceType::Oracle,
+            "POSTGRESQL" => DataSourceType::Postgresql,
+            "PRESTO" => DataSourceType::Presto,
+            "REDSHIFT" => DataSourceType::Redshift,
+            "S3" => DataSourceType::S3,
+            "SALESFORCE" => DataSourceType::Salesforce,
+            "SERVICENOW" => DataSourceType::Servicenow,
+            "SNOWFLAKE" => DataSourceType::Snowflake,
+            "SPARK" => DataSourceType::Spark,
+            "SQLSERVER" => DataSourceType::Sqlserver,
+            "TERADATA" => DataSourceType::Teradata,
+            "TWITTER" => DataSourceType::Twitter,
+            "TIMESTREAM" => DataSourceType::Timestream,
+            "AMAZON_OPENSEARCH" => DataSourceType::AmazonOpensearch,
+            "EXASOL" => DataSourceType::Exasol,
+            "DATABRICKS" => DataSourceType::Databricks,
+            "STARBURST" => DataSourceType::Starburst,
+            "TRINO" => DataSourceType::Trino,
+            "BIGQUERY" => DataSourceType::Bigquery,
+            _ => DataSourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DataSourceType {
+    fn from(value: String) -> DataSourceType {
+        match value.as_str() {
+            "ADOBE_ANALYTICS" => DataSourceType::AdobeAnalytics,
+            "AMAZON_ELASTICSEARCH" => DataSourceType::AmazonElasticsearch,
+            "ATHENA" => DataSourceType::Athena,
+            "AURORA" => DataSourceType::Aurora,
+            "AURORA_POSTGRESQL" => DataSourceType::AuroraPostgresql,
+            "AWS_IOT_ANALYTICS" => DataSourceType::AwsIotAnalytics,
+            "GITHUB" => DataSourceType::Github,
+            "JIRA" => DataSourceType::Jira,
+            "MARIADB" => DataSourceType::Mariadb,
+            "MYSQL" => DataSourceType::Mysql,
+            "ORACLE" => DataSourceType::Oracle,
+            "POSTGRESQL" => DataSourceType::Postgresql,
+            "PRESTO" => DataSourceType::Presto,
+            "REDSHIFT" => DataSourceType::Redshift,
+            "S3" => DataSourceType::S3,
+            "SALESFORCE" => DataSourceType::Salesforce,
+            "SERVICENOW" => DataSourceType::Servicenow,
+            "SNOWFLAKE" => DataSourceType::Snowflake,
+            "SPARK" => DataSourceType::Spark,
+            "SQLSERVER" => DataSourceType::Sqlserver,
+            "TERADATA" => DataSourceType::Teradata,
+            "TWITTER" => DataSourceType::Twitter,
+            "TIMESTREAM" => DataSourceType::Timestream,
+            "AMAZON_OPENSEARCH" => DataSourceType::AmazonOpensearch,
+            "EXASOL" => DataSourceType::Exasol,
+            "DATABRICKS" => DataSourceType::Databricks,
+            "STARBURST" => DataSourceType::Starburst,
+            "TRINO" => DataSourceType::Trino,
+            "BIGQUERY" => DataSourceType::Bigquery,
+            _ => DataSourceType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DataSourceType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DataSourceType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DataSourceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DataSourceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DataSourceType {
+    fn deserialize<D>(deserializer: D) -> Result<DataSourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Date time parameter.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -1204,7 +2238,12 @@ pub struct DateTimeParameter {
     pub name: String,
     /// <p>Values.</p>
     #[serde(rename = "Values")]
-    pub values: Vec<f64>,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestampList::deserialize_timestamp_list",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestampList::serialize_unix_timestamp_list",
+        default
+    )]
+    pub values: Vec<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>Decimal parameter.</p>
@@ -1960,6 +2999,104 @@ pub struct DescribeUserResponse {
     pub user: Option<User>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EmbeddingIdentityType {
+    /// `IAM`
+    Iam,
+    /// `QUICKSIGHT`
+    Quicksight,
+    /// `ANONYMOUS`
+    Anonymous,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EmbeddingIdentityType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EmbeddingIdentityType::Iam => "IAM",
+            EmbeddingIdentityType::Quicksight => "QUICKSIGHT",
+            EmbeddingIdentityType::Anonymous => "ANONYMOUS",
+            EmbeddingIdentityType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EmbeddingIdentityType {
+    fn default() -> EmbeddingIdentityType {
+        EmbeddingIdentityType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EmbeddingIdentityType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EmbeddingIdentityType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EmbeddingIdentityType {
+    fn from(value: &'a str) -> EmbeddingIdentityType {
+        match value {
+            "IAM" => EmbeddingIdentityType::Iam,
+            "QUICKSIGHT" => EmbeddingIdentityType::Quicksight,
+            "ANONYMOUS" => EmbeddingIdentityType::Anonymous,
+            _ => EmbeddingIdentityType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EmbeddingIdentityType {
+    fn from(value: String) -> EmbeddingIdentityType {
+        match value.as_str() {
+            "IAM" => EmbeddingIdentityType::Iam,
+            "QUICKSIGHT" => EmbeddingIdentityType::Quicksight,
+            "ANONYMOUS" => EmbeddingIdentityType::Anonymous,
+            _ => EmbeddingIdentityType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EmbeddingIdentityType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EmbeddingIdentityType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EmbeddingIdentityType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EmbeddingIdentityType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EmbeddingIdentityType {
+    fn deserialize<D>(deserializer: D) -> Result<EmbeddingIdentityType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Error information for the SPICE ingestion of a dataset.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1971,9 +3108,137 @@ pub struct ErrorInfo {
     /// <p>Error type.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<IngestionErrorType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ExceptionResourceType {
+    /// `USER`
+    User,
+    /// `GROUP`
+    Group,
+    /// `NAMESPACE`
+    Namespace,
+    /// `ACCOUNT_SETTINGS`
+    AccountSettings,
+    /// `IAMPOLICY_ASSIGNMENT`
+    IampolicyAssignment,
+    /// `DATA_SOURCE`
+    DataSource,
+    /// `DATA_SET`
+    DataSet,
+    /// `VPC_CONNECTION`
+    VpcConnection,
+    /// `INGESTION`
+    Ingestion,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ExceptionResourceType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ExceptionResourceType::User => "USER",
+            ExceptionResourceType::Group => "GROUP",
+            ExceptionResourceType::Namespace => "NAMESPACE",
+            ExceptionResourceType::AccountSettings => "ACCOUNT_SETTINGS",
+            ExceptionResourceType::IampolicyAssignment => "IAMPOLICY_ASSIGNMENT",
+            ExceptionResourceType::DataSource => "DATA_SOURCE",
+            ExceptionResourceType::DataSet => "DATA_SET",
+            ExceptionResourceType::VpcConnection => "VPC_CONNECTION",
+            ExceptionResourceType::Ingestion => "INGESTION",
+            ExceptionResourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ExceptionResourceType {
+    fn default() -> ExceptionResourceType {
+        ExceptionResourceType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ExceptionResourceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ExceptionResourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ExceptionResourceType {
+    fn from(value: &'a str) -> ExceptionResourceType {
+        match value {
+            "USER" => ExceptionResourceType::User,
+            "GROUP" => ExceptionResourceType::Group,
+            "NAMESPACE" => ExceptionResourceType::Namespace,
+            "ACCOUNT_SETTINGS" => ExceptionResourceType::AccountSettings,
+            "IAMPOLICY_ASSIGNMENT" => ExceptionResourceType::IampolicyAssignment,
+            "DATA_SOURCE" => ExceptionResourceType::DataSource,
+            "DATA_SET" => ExceptionResourceType::DataSet,
+            "VPC_CONNECTION" => ExceptionResourceType::VpcConnection,
+            "INGESTION" => ExceptionResourceType::Ingestion,
+            _ => ExceptionResourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ExceptionResourceType {
+    fn from(value: String) -> ExceptionResourceType {
+        match value.as_str() {
+            "USER" => ExceptionResourceType::User,
+            "GROUP" => ExceptionResourceType::Group,
+            "NAMESPACE" => ExceptionResourceType::Namespace,
+            "ACCOUNT_SETTINGS" => ExceptionResourceType::AccountSettings,
+            "IAMPOLICY_ASSIGNMENT" => ExceptionResourceType::IampolicyAssignment,
+            "DATA_SOURCE" => ExceptionResourceType::DataSource,
+            "DATA_SET" => ExceptionResourceType::DataSet,
+            "VPC_CONNECTION" => ExceptionResourceType::VpcConnection,
+            "INGESTION" => ExceptionResourceType::Ingestion,
+            _ => ExceptionResourceType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ExceptionResourceType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ExceptionResourceType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ExceptionResourceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ExceptionResourceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for ExceptionResourceType {
+    fn deserialize<D>(deserializer: D) -> Result<ExceptionResourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Export to .csv option.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -1981,9 +3246,122 @@ pub struct ExportToCSVOption {
     /// <p>Availability status.</p>
     #[serde(rename = "AvailabilityStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub availability_status: Option<String>,
+    pub availability_status: Option<DashboardBehavior>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FileFormat {
+    /// `CSV`
+    Csv,
+    /// `TSV`
+    Tsv,
+    /// `CLF`
+    Clf,
+    /// `ELF`
+    Elf,
+    /// `XLSX`
+    Xlsx,
+    /// `JSON`
+    Json,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl FileFormat {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FileFormat::Csv => "CSV",
+            FileFormat::Tsv => "TSV",
+            FileFormat::Clf => "CLF",
+            FileFormat::Elf => "ELF",
+            FileFormat::Xlsx => "XLSX",
+            FileFormat::Json => "JSON",
+            FileFormat::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for FileFormat {
+    fn default() -> FileFormat {
+        FileFormat::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for FileFormat {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FileFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FileFormat {
+    fn from(value: &'a str) -> FileFormat {
+        match value {
+            "CSV" => FileFormat::Csv,
+            "TSV" => FileFormat::Tsv,
+            "CLF" => FileFormat::Clf,
+            "ELF" => FileFormat::Elf,
+            "XLSX" => FileFormat::Xlsx,
+            "JSON" => FileFormat::Json,
+            _ => FileFormat::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FileFormat {
+    fn from(value: String) -> FileFormat {
+        match value.as_str() {
+            "CSV" => FileFormat::Csv,
+            "TSV" => FileFormat::Tsv,
+            "CLF" => FileFormat::Clf,
+            "ELF" => FileFormat::Elf,
+            "XLSX" => FileFormat::Xlsx,
+            "JSON" => FileFormat::Json,
+            _ => FileFormat::Unknown(value),
+        }
+    }
 }
 
+impl ::std::str::FromStr for FileFormat {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<FileFormat, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FileFormat {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FileFormat {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FileFormat {
+    fn deserialize<D>(deserializer: D) -> Result<FileFormat, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A transform operation that filters rows based on a condition.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FilterOperation {
@@ -2000,12 +3378,218 @@ pub struct GeoSpatialColumnGroup {
     pub columns: Vec<String>,
     /// <p>Country code.</p>
     #[serde(rename = "CountryCode")]
-    pub country_code: String,
+    pub country_code: GeoSpatialCountryCode,
     /// <p>A display name for the hierarchy.</p>
     #[serde(rename = "Name")]
     pub name: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum GeoSpatialCountryCode {
+    /// `US`
+    Us,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl GeoSpatialCountryCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            GeoSpatialCountryCode::Us => "US",
+            GeoSpatialCountryCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for GeoSpatialCountryCode {
+    fn default() -> GeoSpatialCountryCode {
+        GeoSpatialCountryCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for GeoSpatialCountryCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for GeoSpatialCountryCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for GeoSpatialCountryCode {
+    fn from(value: &'a str) -> GeoSpatialCountryCode {
+        match value {
+            "US" => GeoSpatialCountryCode::Us,
+            _ => GeoSpatialCountryCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for GeoSpatialCountryCode {
+    fn from(value: String) -> GeoSpatialCountryCode {
+        match value.as_str() {
+            "US" => GeoSpatialCountryCode::Us,
+            _ => GeoSpatialCountryCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for GeoSpatialCountryCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<GeoSpatialCountryCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for GeoSpatialCountryCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for GeoSpatialCountryCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for GeoSpatialCountryCode {
+    fn deserialize<D>(deserializer: D) -> Result<GeoSpatialCountryCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum GeoSpatialDataRole {
+    /// `COUNTRY`
+    Country,
+    /// `STATE`
+    State,
+    /// `COUNTY`
+    County,
+    /// `CITY`
+    City,
+    /// `POSTCODE`
+    Postcode,
+    /// `LONGITUDE`
+    Longitude,
+    /// `LATITUDE`
+    Latitude,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl GeoSpatialDataRole {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            GeoSpatialDataRole::Country => "COUNTRY",
+            GeoSpatialDataRole::State => "STATE",
+            GeoSpatialDataRole::County => "COUNTY",
+            GeoSpatialDataRole::City => "CITY",
+            GeoSpatialDataRole::Postcode => "POSTCODE",
+            GeoSpatialDataRole::Longitude => "LONGITUDE",
+            GeoSpatialDataRole::Latitude => "LATITUDE",
+            GeoSpatialDataRole::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for GeoSpatialDataRole {
+    fn default() -> GeoSpatialDataRole {
+        GeoSpatialDataRole::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for GeoSpatialDataRole {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for GeoSpatialDataRole {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for GeoSpatialDataRole {
+    fn from(value: &'a str) -> GeoSpatialDataRole {
+        match value {
+            "COUNTRY" => GeoSpatialDataRole::Country,
+            "STATE" => GeoSpatialDataRole::State,
+            "COUNTY" => GeoSpatialDataRole::County,
+            "CITY" => GeoSpatialDataRole::City,
+            "POSTCODE" => GeoSpatialDataRole::Postcode,
+            "LONGITUDE" => GeoSpatialDataRole::Longitude,
+            "LATITUDE" => GeoSpatialDataRole::Latitude,
+            _ => GeoSpatialDataRole::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for GeoSpatialDataRole {
+    fn from(value: String) -> GeoSpatialDataRole {
+        match value.as_str() {
+            "COUNTRY" => GeoSpatialDataRole::Country,
+            "STATE" => GeoSpatialDataRole::State,
+            "COUNTY" => GeoSpatialDataRole::County,
+            "CITY" => GeoSpatialDataRole::City,
+            "POSTCODE" => GeoSpatialDataRole::Postcode,
+            "LONGITUDE" => GeoSpatialDataRole::Longitude,
+            "LATITUDE" => GeoSpatialDataRole::Latitude,
+            _ => GeoSpatialDataRole::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for GeoSpatialDataRole {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<GeoSpatialDataRole, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for GeoSpatialDataRole {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for GeoSpatialDataRole {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for GeoSpatialDataRole {
+    fn deserialize<D>(deserializer: D) -> Result<GeoSpatialDataRole, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct GetDashboardEmbedUrlRequest {
@@ -2017,7 +3601,7 @@ pub struct GetDashboardEmbedUrlRequest {
     pub dashboard_id: String,
     /// <p>The authentication method that the user uses to sign in.</p>
     #[serde(rename = "IdentityType")]
-    pub identity_type: String,
+    pub identity_type: EmbeddingIdentityType,
     /// <p>Remove the reset button on the embedded dashboard. The default is FALSE, which enables the reset button.</p>
     #[serde(rename = "ResetDisabled")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2104,7 +3688,7 @@ pub struct IAMPolicyAssignment {
     /// <p>Assignment status.</p>
     #[serde(rename = "AssignmentStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub assignment_status: Option<String>,
+    pub assignment_status: Option<AssignmentStatus>,
     /// <p>The AWS account ID.</p>
     #[serde(rename = "AwsAccountId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2130,11 +3714,109 @@ pub struct IAMPolicyAssignmentSummary {
     /// <p>Assignment status.</p>
     #[serde(rename = "AssignmentStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub assignment_status: Option<String>,
+    pub assignment_status: Option<AssignmentStatus>,
 }
 
-/// <p>Information about the SPICE ingestion for a dataset.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum IdentityType {
+    /// `IAM`
+    Iam,
+    /// `QUICKSIGHT`
+    Quicksight,
+    /// `IAM_IDENTITY_CENTER`
+    IamIdentityCenter,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl IdentityType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            IdentityType::Iam => "IAM",
+            IdentityType::Quicksight => "QUICKSIGHT",
+            IdentityType::IamIdentityCenter => "IAM_IDENTITY_CENTER",
+            IdentityType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for IdentityType {
+    fn default() -> IdentityType {
+        IdentityType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for IdentityType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for IdentityType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for IdentityType {
+    fn from(value: &'a str) -> IdentityType {
+        match value {
+            "IAM" => IdentityType::Iam,
+            "QUICKSIGHT" => IdentityType::Quicksight,
+            "IAM_IDENTITY_CENTER" => IdentityType::IamIdentityCenter,
+            _ => IdentityType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for IdentityType {
+    fn from(value: String) -> IdentityType {
+        match value.as_str() {
+            "IAM" => IdentityType::Iam,
+            "QUICKSIGHT" => IdentityType::Quicksight,
+            "IAM_IDENTITY_CENTER" => IdentityType::IamIdentityCenter,
+            _ => IdentityType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for IdentityType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<IdentityType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for IdentityType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for IdentityType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for IdentityType {
+    fn deserialize<D>(deserializer: D) -> Result<IdentityType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Information about the SPICE ingestion for a dataset.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Ingestion {
     /// <p>The Amazon Resource Name (ARN) of the resource.</p>
@@ -2142,7 +3824,12 @@ pub struct Ingestion {
     pub arn: String,
     /// <p>The time that this ingestion started.</p>
     #[serde(rename = "CreatedTime")]
-    pub created_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub created_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>Error information for this ingestion.</p>
     #[serde(rename = "ErrorInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2157,7 +3844,7 @@ pub struct Ingestion {
     pub ingestion_size_in_bytes: Option<i64>,
     /// <p>Ingestion status.</p>
     #[serde(rename = "IngestionStatus")]
-    pub ingestion_status: String,
+    pub ingestion_status: IngestionStatus,
     /// <p>The time that this ingestion took, measured in seconds.</p>
     #[serde(rename = "IngestionTimeInSeconds")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2168,16 +3855,646 @@ pub struct Ingestion {
     /// <p>Event source for this ingestion.</p>
     #[serde(rename = "RequestSource")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub request_source: Option<String>,
+    pub request_source: Option<IngestionRequestSource>,
     /// <p>Type of this ingestion.</p>
     #[serde(rename = "RequestType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub request_type: Option<String>,
+    pub request_type: Option<IngestionRequestType>,
     #[serde(rename = "RowInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub row_info: Option<RowInfo>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum IngestionErrorType {
+    /// `FAILURE_TO_ASSUME_ROLE`
+    FailureToAssumeRole,
+    /// `INGESTION_SUPERSEDED`
+    IngestionSuperseded,
+    /// `INGESTION_CANCELED`
+    IngestionCanceled,
+    /// `DATA_SET_DELETED`
+    DataSetDeleted,
+    /// `DATA_SET_NOT_SPICE`
+    DataSetNotSpice,
+    /// `S3_UPLOADED_FILE_DELETED`
+    S3UploadedFileDeleted,
+    /// `S3_MANIFEST_ERROR`
+    S3ManifestError,
+    /// `DATA_TOLERANCE_EXCEPTION`
+    DataToleranceException,
+    /// `SPICE_TABLE_NOT_FOUND`
+    SpiceTableNotFound,
+    /// `DATA_SET_SIZE_LIMIT_EXCEEDED`
+    DataSetSizeLimitExceeded,
+    /// `ROW_SIZE_LIMIT_EXCEEDED`
+    RowSizeLimitExceeded,
+    /// `ACCOUNT_CAPACITY_LIMIT_EXCEEDED`
+    AccountCapacityLimitExceeded,
+    /// `CUSTOMER_ERROR`
+    CustomerError,
+    /// `DATA_SOURCE_NOT_FOUND`
+    DataSourceNotFound,
+    /// `IAM_ROLE_NOT_AVAILABLE`
+    IamRoleNotAvailable,
+    /// `CONNECTION_FAILURE`
+    ConnectionFailure,
+    /// `SQL_TABLE_NOT_FOUND`
+    SqlTableNotFound,
+    /// `PERMISSION_DENIED`
+    PermissionDenied,
+    /// `SSL_CERTIFICATE_VALIDATION_FAILURE`
+    SslCertificateValidationFailure,
+    /// `OAUTH_TOKEN_FAILURE`
+    OauthTokenFailure,
+    /// `SOURCE_API_LIMIT_EXCEEDED_FAILURE`
+    SourceApiLimitExceededFailure,
+    /// `PASSWORD_AUTHENTICATION_FAILURE`
+    PasswordAuthenticationFailure,
+    /// `SQL_SCHEMA_MISMATCH_ERROR`
+    SqlSchemaMismatchError,
+    /// `INVALID_DATE_FORMAT`
+    InvalidDateFormat,
+    /// `INVALID_DATAPREP_SYNTAX`
+    InvalidDataprepSyntax,
+    /// `SOURCE_RESOURCE_LIMIT_EXCEEDED`
+    SourceResourceLimitExceeded,
+    /// `SQL_INVALID_PARAMETER_VALUE`
+    SqlInvalidParameterValue,
+    /// `QUERY_TIMEOUT`
+    QueryTimeout,
+    /// `SQL_NUMERIC_OVERFLOW`
+    SqlNumericOverflow,
+    /// `UNRESOLVABLE_HOST`
+    UnresolvableHost,
+    /// `UNROUTABLE_HOST`
+    UnroutableHost,
+    /// `SQL_EXCEPTION`
+    SqlException,
+    /// `S3_FILE_INACCESSIBLE`
+    S3FileInaccessible,
+    /// `IOT_FILE_NOT_FOUND`
+    IotFileNotFound,
+    /// `IOT_DATA_SET_FILE_EMPTY`
+    IotDataSetFileEmpty,
+    /// `INVALID_DATA_SOURCE_CONFIG`
+    InvalidDataSourceConfig,
+    /// `DATA_SOURCE_AUTH_FAILED`
+    DataSourceAuthFailed,
+    /// `DATA_SOURCE_CONNECTION_FAILED`
+    DataSourceConnectionFailed,
+    /// `FAILURE_TO_PROCESS_JSON_FILE`
+    FailureToProcessJsonFile,
+    /// `INTERNAL_SERVICE_ERROR`
+    InternalServiceError,
+    /// `REFRESH_SUPPRESSED_BY_EDIT`
+    RefreshSuppressedByEdit,
+    /// `PERMISSION_NOT_FOUND`
+    PermissionNotFound,
+    /// `ELASTICSEARCH_CURSOR_NOT_ENABLED`
+    ElasticsearchCursorNotEnabled,
+    /// `CURSOR_NOT_ENABLED`
+    CursorNotEnabled,
+    /// `DUPLICATE_COLUMN_NAMES_FOUND`
+    DuplicateColumnNamesFound,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl IngestionErrorType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            IngestionErrorType::FailureToAssumeRole => "FAILURE_TO_ASSUME_ROLE",
+            IngestionErrorType::IngestionSuperseded => "INGESTION_SUPERSEDED",
+            IngestionErrorType::IngestionCanceled => "INGESTION_CANCELED",
+            IngestionErrorType::DataSetDeleted => "DATA_SET_DELETED",
+            IngestionErrorType::DataSetNotSpice => "DATA_SET_NOT_SPICE",
+            IngestionErrorType::S3UploadedFileDeleted => "S3_UPLOADED_FILE_DELETED",
+            IngestionErrorType::S3ManifestError => "S3_MANIFEST_ERROR",
+            IngestionErrorType::DataToleranceException => "DATA_TOLERANCE_EXCEPTION",
+            IngestionErrorType::SpiceTableNotFound => "SPICE_TABLE_NOT_FOUND",
+            IngestionErrorType::DataSetSizeLimitExceeded => "DATA_SET_SIZE_LIMIT_EXCEEDED",
+            IngestionErrorType::RowSizeLimitExceeded => "ROW_SIZE_LIMIT_EXCEEDED",
+            IngestionErrorType::AccountCapacityLimitExceeded => "ACCOUNT_CAPACITY_LIMIT_EXCEEDED",
+            IngestionErrorType::CustomerError => "CUSTOMER_ERROR",
+            IngestionErrorType::DataSourceNotFound => "DATA_SOURCE_NOT_FOUND",
+            IngestionErrorType::IamRoleNotAvailable => "IAM_ROLE_NOT_AVAILABLE",
+            IngestionErrorType::ConnectionFailure => "CONNECTION_FAILURE",
+            IngestionErrorType::SqlTableNotFound => "SQL_TABLE_NOT_FOUND",
+            IngestionErrorType::PermissionDenied => "PERMISSION_DENIED",
+            IngestionErrorType::SslCertificateValidationFailure => {
+                "SSL_CERTIFICATE_VALIDATION_FAILURE"
+            }
+            IngestionErrorType::OauthTokenFailure => "OAUTH_TOKEN_FAILURE",
+            IngestionErrorType::SourceApiLimitExceededFailure => {
+                "SOURCE_API_LIMIT_EXCEEDED_FAILURE"
+            }
+            IngestionErrorType::PasswordAuthenticationFailure => "PASSWORD_AUTHENTICATION_FAILURE",
+            IngestionErrorType::SqlSchemaMismatchError => "SQL_SCHEMA_MISMATCH_ERROR",
+            IngestionErrorType::InvalidDateFormat => "INVALID_DATE_FORMAT",
+            IngestionErrorType::InvalidDataprepSyntax => "INVALID_DATAPREP_SYNTAX",
+            IngestionErrorType::SourceResourceLimitExceeded => "SOURCE_RESOURCE_LIMIT_EXCEEDED",
+            IngestionErrorType::SqlInvalidParameterValue => "SQL_INVALID_PARAMETER_VALUE",
+            IngestionErrorType::QueryTimeout => "QUERY_TIMEOUT",
+            IngestionErrorType::SqlNumericOverflow => "SQL_NUMERIC_OVERFLOW",
+            IngestionErrorType::UnresolvableHost => "UNRESOLVABLE_HOST",
+            IngestionErrorType::UnroutableHost => "UNROUTABLE_HOST",
+            IngestionErrorType::SqlException => "SQL_EXCEPTION",
+            IngestionErrorType::S3FileInaccessible => "S3_FILE_INACCESSIBLE",
+            IngestionErrorType::IotFileNotFound => "IOT_FILE_NOT_FOUND",
+            IngestionErrorType::IotDataSetFileEmpty => "IOT_DATA_SET_FILE_EMPTY",
+            IngestionErrorType::InvalidDataSourceConfig => "INVALID_DATA_SOURCE_CONFIG",
+            IngestionErrorType::DataSourceAuthFailed => "DATA_SOURCE_AUTH_FAILED",
+            IngestionErrorType::DataSourceConnectionFailed => "DATA_SOURCE_CONNECTION_FAILED",
+            IngestionErrorType::FailureToProcessJsonFile => "FAILURE_TO_PROCESS_JSON_FILE",
+            IngestionErrorType::InternalServiceError => "INTERNAL_SERVICE_ERROR",
+            IngestionErrorType::RefreshSuppressedByEdit => "REFRESH_SUPPRESSED_BY_EDIT",
+            IngestionErrorType::PermissionNotFound => "PERMISSION_NOT_FOUND",
+            IngestionErrorType::ElasticsearchCursorNotEnabled => "ELASTICSEARCH_CURSOR_NOT_ENABLED",
+            IngestionErrorType::CursorNotEnabled => "CURSOR_NOT_ENABLED",
+            IngestionErrorType::DuplicateColumnNamesFound => "DUPLICATE_COLUMN_NAMES_FOUND",
+            IngestionErrorType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for IngestionErrorType {
+    fn default() -> IngestionErrorType {
+        IngestionErrorType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for IngestionErrorType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for IngestionErrorType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for IngestionErrorType {
+    fn from(value: &'a str) -> IngestionErrorType {
+        match value {
+            "FAILURE_TO_ASSUME_ROLE" => IngestionErrorType::FailureToAssumeRole,
+            "INGESTION_SUPERSEDED" => IngestionErrorType::IngestionSuperseded,
+            "INGESTION_CANCELED" => IngestionErrorType::IngestionCanceled,
+            "DATA_SET_DELETED" => IngestionErrorType::DataSetDeleted,
+            "DATA_SET_NOT_SPICE" => IngestionErrorType::DataSetNotSpice,
+            "S3_UPLOADED_FILE_DELETED" => IngestionErrorType::S3UploadedFileDeleted,
+            "S3_MANIFEST_ERROR" => IngestionErrorType::S3ManifestError,
+            "DATA_TOLERANCE_EXCEPTION" => IngestionErrorType::DataToleranceException,
+            "SPICE_TABLE_NOT_FOUND" => IngestionErrorType::SpiceTableNotFound,
+            "DATA_SET_SIZE_LIMIT_EXCEEDED" => IngestionErrorType::DataSetSizeLimitExceeded,
+            "ROW_SIZE_LIMIT_EXCEEDED" => IngestionErrorType::RowSizeLimitExceeded,
+            "ACCOUNT_CAPACITY_LIMIT_EXCEEDED" => IngestionErrorType::AccountCapacityLimitExceeded,
+            "CUSTOMER_ERROR" => IngestionErrorType::CustomerError,
+            "DATA_SOURCE_NOT_FOUND" => IngestionErrorType::DataSourceNotFound,
+            "IAM_ROLE_NOT_AVAILABLE" => IngestionErrorType::IamRoleNotAvailable,
+            "CONNECTION_FAILURE" => IngestionErrorType::ConnectionFailure,
+            "SQL_TABLE_NOT_FOUND" => IngestionErrorType::SqlTableNotFound,
+            "PERMISSION_DENIED" => IngestionErrorType::PermissionDenied,
+            "SSL_CERTIFICATE_VALIDATION_FAILURE" => {
+                IngestionErrorType::SslCertificateValidationFailure
+            }
+            "OAUTH_TOKEN_FAILURE" => IngestionErrorType::OauthTokenFailure,
+            "SOURCE_API_LIMIT_EXCEEDED_FAILURE" => {
+                IngestionErrorType::SourceApiLimitExceededFailure
+            }
+            "PASSWORD_AUTHENTICATION_FAILURE" => IngestionErrorType::PasswordAuthenticationFailure,
+            "SQL_SCHEMA_MISMATCH_ERROR" => IngestionErrorType::SqlSchemaMismatchError,
+            "INVALID_DATE_FORMAT" => IngestionErrorType::InvalidDateFormat,
+            "INVALID_DATAPREP_SYNTAX" => IngestionErrorType::InvalidDataprepSyntax,
+            "SOURCE_RESOURCE_LIMIT_EXCEEDED" => IngestionErrorType::SourceResourceLimitExceeded,
+            "SQL_INVALID_PARAMETER_VALUE" => IngestionErrorType::SqlInvalidParameterValue,
+            "QUERY_TIMEOUT" => IngestionErrorType::QueryTimeout,
+            "SQL_NUMERIC_OVERFLOW" => IngestionErrorType::SqlNumericOverflow,
+            "UNRESOLVABLE_HOST" => IngestionErrorType::UnresolvableHost,
+            "UNROUTABLE_HOST" => IngestionErrorType::UnroutableHost,
+            "SQL_EXCEPTION" => IngestionErrorType::SqlException,
+            "S3_FILE_INACCESSIBLE" => IngestionErrorType::S3FileInaccessible,
+            "IOT_FILE_NOT_FOUND" => IngestionErrorType::IotFileNotFound,
+            "IOT_DATA_SET_FILE_EMPTY" => IngestionErrorType::IotDataSetFileEmpty,
+            "INVALID_DATA_SOURCE_CONFIG" => IngestionErrorType::InvalidDataSourceConfig,
+            "DATA_SOURCE_AUTH_FAILED" => IngestionErrorType::DataSourceAuthFailed,
+            "DATA_SOURCE_CONNECTION_FAILED" => IngestionErrorType::DataSourceConnectionFailed,
+            "FAILURE_TO_PROCESS_JSON_FILE" => IngestionErrorType::FailureToProcessJsonFile,
+            "INTERNAL_SERVICE_ERROR" => IngestionErrorType::InternalServiceError,
+            "REFRESH_SUPPRESSED_BY_EDIT" => IngestionErrorType::RefreshSuppressedByEdit,
+            "PERMISSION_NOT_FOUND" => IngestionErrorType::PermissionNotFound,
+            "ELASTICSEARCH_CURSOR_NOT_ENABLED" => IngestionErrorType::ElasticsearchCursorNotEnabled,
+            "CURSOR_NOT_ENABLED" => IngestionErrorType::CursorNotEnabled,
+            "DUPLICATE_COLUMN_NAMES_FOUND" => IngestionErrorType::DuplicateColumnNamesFound,
+            _ => IngestionErrorType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for IngestionErrorType {
+    fn from(value: String) -> IngestionErrorType {
+        match value.as_str() {
+            "FAILURE_TO_ASSUME_ROLE" => IngestionErrorType::FailureToAssumeRole,
+            "INGESTION_SUPERSEDED" => IngestionErrorType::IngestionSuperseded,
+            "INGESTION_CANCELED" => IngestionErrorType::IngestionCanceled,
+            "DATA_SET_DELETED" => IngestionErrorType::DataSetDeleted,
+            "DATA_SET_NOT_SPICE" => IngestionErrorType::DataSetNotSpice,
+            "S3_UPLOADED_FILE_DELETED" => IngestionErrorType::S3UploadedFileDeleted,
+            "S3_MANIFEST_ERROR" => IngestionErrorType::S3ManifestError,
+            "DATA_TOLERANCE_EXCEPTION" => IngestionErrorType::DataToleranceException,
+            "SPICE_TABLE_NOT_FOUND" => IngestionErrorType::SpiceTableNotFound,
+            "DATA_SET_SIZE_LIMIT_EXCEEDED" => IngestionErrorType::DataSetSizeLimitExceeded,
+            "ROW_SIZE_LIMIT_EXCEEDED" => IngestionErrorType::RowSizeLimitExceeded,
+            "ACCOUNT_CAPACITY_LIMIT_EXCEEDED" => IngestionErrorType::AccountCapacityLimitExceeded,
+            "CUSTOMER_ERROR" => IngestionErrorType::CustomerError,
+            "DATA_SOURCE_NOT_FOUND" => IngestionErrorType::DataSourceNotFound,
+            "IAM_ROLE_NOT_AVAILABLE" => IngestionErrorType::IamRoleNotAvailable,
+            "CONNECTION_FAILURE" => IngestionErrorType::ConnectionFailure,
+            "SQL_TABLE_NOT_FOUND" => IngestionErrorType::SqlTableNotFound,
+            "PERMISSION_DENIED" => IngestionErrorType::PermissionDenied,
+            "SSL_CERTIFICATE_VALIDATION_FAILURE" => {
+                IngestionErrorType::SslCertificateValidationFailure
+            }
+            "OAUTH_TOKEN_FAILURE" => IngestionErrorType::OauthTokenFailure,
+            "SOURCE_API_LIMIT_EXCEEDED_FAILURE" => {
+                IngestionErrorType::SourceApiLimitExceededFailure
+            }
+            "PASSWORD_AUTHENTICATION_FAILURE" => IngestionErrorType::PasswordAuthenticationFailure,
+            "SQL_SCHEMA_MISMATCH_ERROR" => IngestionErrorType::SqlSchemaMismatchError,
+            "INVALID_DATE_FORMAT" => IngestionErrorType::InvalidDateFormat,
+            "INVALID_DATAPREP_SYNTAX" => IngestionErrorType::InvalidDataprepSyntax,
+            "SOURCE_RESOURCE_LIMIT_EXCEEDED" => IngestionErrorType::SourceResourceLimitExceeded,
+            "SQL_INVALID_PARAMETER_VALUE" => IngestionErrorType::SqlInvalidParameterValue,
+            "QUERY_TIMEOUT" => IngestionErrorType::QueryTimeout,
+            "SQL_NUMERIC_OVERFLOW" => IngestionErrorType::SqlNumericOverflow,
+            "UNRESOLVABLE_HOST" => IngestionErrorType::UnresolvableHost,
+            "UNROUTABLE_HOST" => IngestionErrorType::UnroutableHost,
+            "SQL_EXCEPTION" => IngestionErrorType::SqlException,
+            "S3_FILE_INACCESSIBLE" => IngestionErrorType::S3FileInaccessible,
+            "IOT_FILE_NOT_FOUND" => IngestionErrorType::IotFileNotFound,
+            "IOT_DATA_SET_FILE_EMPTY" => IngestionErrorType::IotDataSetFileEmpty,
+            "INVALID_DATA_SOURCE_CONFIG" => IngestionErrorType::InvalidDataSourceConfig,
+            "DATA_SOURCE_AUTH_FAILED" => IngestionErrorType::DataSourceAuthFailed,
+            "DATA_SOURCE_CONNECTION_FAILED" => IngestionErrorType::DataSourceConnectionFailed,
+            "FAILURE_TO_PROCESS_JSON_FILE" => IngestionErrorType::FailureToProcessJsonFile,
+            "INTERNAL_SERVICE_ERROR" => IngestionErrorType::InternalServiceError,
+            "REFRESH_SUPPRESSED_BY_EDIT" => IngestionErrorType::RefreshSuppressedByEdit,
+            "PERMISSION_NOT_FOUND" => IngestionErrorType::PermissionNotFound,
+            "ELASTICSEARCH_CURSOR_NOT_ENABLED" => IngestionErrorType::ElasticsearchCursorNotEnabled,
+            "CURSOR_NOT_ENABLED" => IngestionErrorType::CursorNotEnabled,
+            "DUPLICATE_COLUMN_NAMES_FOUND" => IngestionErrorType::DuplicateColumnNamesFound,
+            _ => IngestionErrorType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for IngestionErrorType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<IngestionErrorType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for IngestionErrorType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for IngestionErrorType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for IngestionErrorType {
+    fn deserialize<D>(deserializer: D) -> Result<IngestionErrorType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum IngestionRequestSource {
+    /// `MANUAL`
+    Manual,
+    /// `SCHEDULED`
+    Scheduled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl IngestionRequestSource {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            IngestionRequestSource::Manual => "MANUAL",
+            IngestionRequestSource::Scheduled => "SCHEDULED",
+            IngestionRequestSource::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for IngestionRequestSource {
+    fn default() -> IngestionRequestSource {
+        IngestionRequestSource::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for IngestionRequestSource {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for IngestionRequestSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for IngestionRequestSource {
+    fn from(value: &'a str) -> IngestionRequestSource {
+        match value {
+            "MANUAL" => IngestionRequestSource::Manual,
+            "SCHEDULED" => IngestionRequestSource::Scheduled,
+            _ => IngestionRequestSource::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for IngestionRequestSource {
+    fn from(value: String) -> IngestionRequestSource {
+        match value.as_str() {
+            "MANUAL" => IngestionRequestSource::Manual,
+            "SCHEDULED" => IngestionRequestSource::Scheduled,
+            _ => IngestionRequestSource::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for IngestionRequestSource {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<IngestionRequestSource, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for IngestionRequestSource {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for IngestionRequestSource {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for IngestionRequestSource {
+    fn deserialize<D>(deserializer: D) -> Result<IngestionRequestSource, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// This defines the type of ingestion request. This is returned as part of create ingestion response.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum IngestionRequestType {
+    /// `INITIAL_INGESTION`
+    InitialIngestion,
+    /// `EDIT`
+    Edit,
+    /// `INCREMENTAL_REFRESH`
+    IncrementalRefresh,
+    /// `FULL_REFRESH`
+    FullRefresh,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl IngestionRequestType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            IngestionRequestType::InitialIngestion => "INITIAL_INGESTION",
+            IngestionRequestType::Edit => "EDIT",
+            IngestionRequestType::IncrementalRefresh => "INCREMENTAL_REFRESH",
+            IngestionRequestType::FullRefresh => "FULL_REFRESH",
+            IngestionRequestType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for IngestionRequestType {
+    fn default() -> IngestionRequestType {
+        IngestionRequestType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for IngestionRequestType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for IngestionRequestType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for IngestionRequestType {
+    fn from(value: &'a str) -> IngestionRequestType {
+        match value {
+            "INITIAL_INGESTION" => IngestionRequestType::InitialIngestion,
+            "EDIT" => IngestionRequestType::Edit,
+            "INCREMENTAL_REFRESH" => IngestionRequestType::IncrementalRefresh,
+            "FULL_REFRESH" => IngestionRequestType::FullRefresh,
+            _ => IngestionRequestType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for IngestionRequestType {
+    fn from(value: String) -> IngestionRequestType {
+        match value.as_str() {
+            "INITIAL_INGESTION" => IngestionRequestType::InitialIngestion,
+            "EDIT" => IngestionRequestType::Edit,
+            "INCREMENTAL_REFRESH" => IngestionRequestType::IncrementalRefresh,
+            "FULL_REFRESH" => IngestionRequestType::FullRefresh,
+            _ => IngestionRequestType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for IngestionRequestType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<IngestionRequestType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for IngestionRequestType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for IngestionRequestType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for IngestionRequestType {
+    fn deserialize<D>(deserializer: D) -> Result<IngestionRequestType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum IngestionStatus {
+    /// `INITIALIZED`
+    Initialized,
+    /// `QUEUED`
+    Queued,
+    /// `RUNNING`
+    Running,
+    /// `FAILED`
+    Failed,
+    /// `COMPLETED`
+    Completed,
+    /// `CANCELLED`
+    Cancelled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl IngestionStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            IngestionStatus::Initialized => "INITIALIZED",
+            IngestionStatus::Queued => "QUEUED",
+            IngestionStatus::Running => "RUNNING",
+            IngestionStatus::Failed => "FAILED",
+            IngestionStatus::Completed => "COMPLETED",
+            IngestionStatus::Cancelled => "CANCELLED",
+            IngestionStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for IngestionStatus {
+    fn default() -> IngestionStatus {
+        IngestionStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for IngestionStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for IngestionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for IngestionStatus {
+    fn from(value: &'a str) -> IngestionStatus {
+        match value {
+            "INITIALIZED" => IngestionStatus::Initialized,
+            "QUEUED" => IngestionStatus::Queued,
+            "RUNNING" => IngestionStatus::Running,
+            "FAILED" => IngestionStatus::Failed,
+            "COMPLETED" => IngestionStatus::Completed,
+            "CANCELLED" => IngestionStatus::Cancelled,
+            _ => IngestionStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for IngestionStatus {
+    fn from(value: String) -> IngestionStatus {
+        match value.as_str() {
+            "INITIALIZED" => IngestionStatus::Initialized,
+            "QUEUED" => IngestionStatus::Queued,
+            "RUNNING" => IngestionStatus::Running,
+            "FAILED" => IngestionStatus::Failed,
+            "COMPLETED" => IngestionStatus::Completed,
+            "CANCELLED" => IngestionStatus::Cancelled,
+            _ => IngestionStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for IngestionStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<IngestionStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for IngestionStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for IngestionStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for IngestionStatus {
+    fn deserialize<D>(deserializer: D) -> Result<IngestionStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Metadata for a column that is used as the input of a transform operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct InputColumn {
@@ -2186,9 +4503,127 @@ pub struct InputColumn {
     pub name: String,
     /// <p>The data type of the column.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: InputColumnDataType,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum InputColumnDataType {
+    /// `STRING`
+    String,
+    /// `INTEGER`
+    Integer,
+    /// `DECIMAL`
+    Decimal,
+    /// `DATETIME`
+    Datetime,
+    /// `BIT`
+    Bit,
+    /// `BOOLEAN`
+    Boolean,
+    /// `JSON`
+    Json,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl InputColumnDataType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InputColumnDataType::String => "STRING",
+            InputColumnDataType::Integer => "INTEGER",
+            InputColumnDataType::Decimal => "DECIMAL",
+            InputColumnDataType::Datetime => "DATETIME",
+            InputColumnDataType::Bit => "BIT",
+            InputColumnDataType::Boolean => "BOOLEAN",
+            InputColumnDataType::Json => "JSON",
+            InputColumnDataType::Unknown(ref value) => value,
+        }
+    }
 }
 
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for InputColumnDataType {
+    fn default() -> InputColumnDataType {
+        InputColumnDataType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for InputColumnDataType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for InputColumnDataType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for InputColumnDataType {
+    fn from(value: &'a str) -> InputColumnDataType {
+        match value {
+            "STRING" => InputColumnDataType::String,
+            "INTEGER" => InputColumnDataType::Integer,
+            "DECIMAL" => InputColumnDataType::Decimal,
+            "DATETIME" => InputColumnDataType::Datetime,
+            "BIT" => InputColumnDataType::Bit,
+            "BOOLEAN" => InputColumnDataType::Boolean,
+            "JSON" => InputColumnDataType::Json,
+            _ => InputColumnDataType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InputColumnDataType {
+    fn from(value: String) -> InputColumnDataType {
+        match value.as_str() {
+            "STRING" => InputColumnDataType::String,
+            "INTEGER" => InputColumnDataType::Integer,
+            "DECIMAL" => InputColumnDataType::Decimal,
+            "DATETIME" => InputColumnDataType::Datetime,
+            "BIT" => InputColumnDataType::Bit,
+            "BOOLEAN" => InputColumnDataType::Boolean,
+            "JSON" => InputColumnDataType::Json,
+            _ => InputColumnDataType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for InputColumnDataType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<InputColumnDataType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for InputColumnDataType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for InputColumnDataType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InputColumnDataType {
+    fn deserialize<D>(deserializer: D) -> Result<InputColumnDataType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Integer parameter.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -2223,9 +4658,112 @@ pub struct JoinInstruction {
     pub right_operand: String,
     /// <p>Type.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: JoinType,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum JoinType {
+    /// `INNER`
+    Inner,
+    /// `OUTER`
+    Outer,
+    /// `LEFT`
+    Left,
+    /// `RIGHT`
+    Right,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl JoinType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            JoinType::Inner => "INNER",
+            JoinType::Outer => "OUTER",
+            JoinType::Left => "LEFT",
+            JoinType::Right => "RIGHT",
+            JoinType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for JoinType {
+    fn default() -> JoinType {
+        JoinType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for JoinType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for JoinType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for JoinType {
+    fn from(value: &'a str) -> JoinType {
+        match value {
+            "INNER" => JoinType::Inner,
+            "OUTER" => JoinType::Outer,
+            "LEFT" => JoinType::Left,
+            "RIGHT" => JoinType::Right,
+            _ => JoinType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for JoinType {
+    fn from(value: String) -> JoinType {
+        match value.as_str() {
+            "INNER" => JoinType::Inner,
+            "OUTER" => JoinType::Outer,
+            "LEFT" => JoinType::Left,
+            "RIGHT" => JoinType::Right,
+            _ => JoinType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for JoinType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<JoinType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for JoinType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for JoinType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for JoinType {
+    fn deserialize<D>(deserializer: D) -> Result<JoinType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ListDashboardVersionsRequest {
@@ -2509,7 +5047,7 @@ pub struct ListIAMPolicyAssignmentsRequest {
     /// <p>The status of the assignments.</p>
     #[serde(rename = "AssignmentStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub assignment_status: Option<String>,
+    pub assignment_status: Option<AssignmentStatus>,
     /// <p>The ID of the AWS account that contains these IAM policy assignments.</p>
     #[serde(rename = "AwsAccountId")]
     pub aws_account_id: String,
@@ -2890,7 +5428,7 @@ pub struct OutputColumn {
     /// <p>Type.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<ColumnDataType>,
 }
 
 /// <p>Parameters.</p>
@@ -3026,7 +5564,7 @@ pub struct RegisterUserRequest {
     pub iam_arn: Option<String>,
     /// <p><p>Amazon QuickSight supports several ways of managing the identity of users. This parameter accepts two values:</p> <ul> <li> <p> <code>IAM</code>: A user whose identity maps to an existing IAM user or role. </p> </li> <li> <p> <code>QUICKSIGHT</code>: A user whose identity is owned and managed internally by Amazon QuickSight. </p> </li> </ul></p>
     #[serde(rename = "IdentityType")]
-    pub identity_type: String,
+    pub identity_type: IdentityType,
     /// <p>The namespace. Currently, you should set this to <code>default</code>.</p>
     #[serde(rename = "Namespace")]
     pub namespace: String,
@@ -3040,7 +5578,7 @@ pub struct RegisterUserRequest {
     pub user_name: Option<String>,
     /// <p><p>The Amazon QuickSight role for the user. The user role can be one of the following:</p> <ul> <li> <p> <code>READER</code>: A user who has read-only access to dashboards.</p> </li> <li> <p> <code>AUTHOR</code>: A user who can create data sources, datasets, analyses, and dashboards.</p> </li> <li> <p> <code>ADMIN</code>: A user who is an author, who can also manage Amazon QuickSight settings.</p> </li> <li> <p> <code>RESTRICTED<em>READER</code>: This role isn&#39;t currently available for use.</p> </li> <li> <p> <code>RESTRICTED</em>AUTHOR</code>: This role isn&#39;t currently available for use.</p> </li> </ul></p>
     #[serde(rename = "UserRole")]
-    pub user_role: String,
+    pub user_role: UserRole,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -3093,42 +5631,253 @@ pub struct RenameColumnOperation {
     pub new_column_name: String,
 }
 
-/// <p>Permission for the resource.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct ResourcePermission {
-    /// <p>The action to grant or revoke permissions on, for example <code>"quicksight:DescribeDashboard"</code>.</p>
-    #[serde(rename = "Actions")]
-    pub actions: Vec<String>,
-    /// <p>The Amazon Resource Name (ARN) of an Amazon QuickSight user or group, or an IAM ARN. If you are using cross-account resource sharing, this is the IAM ARN of an account root. Otherwise, it is the ARN of a QuickSight user or group. .</p>
-    #[serde(rename = "Principal")]
-    pub principal: String,
+/// <p>Permission for the resource.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResourcePermission {
+    /// <p>The action to grant or revoke permissions on, for example <code>"quicksight:DescribeDashboard"</code>.</p>
+    #[serde(rename = "Actions")]
+    pub actions: Vec<String>,
+    /// <p>The Amazon Resource Name (ARN) of an Amazon QuickSight user or group, or an IAM ARN. If you are using cross-account resource sharing, this is the IAM ARN of an account root. Otherwise, it is the ARN of a QuickSight user or group. .</p>
+    #[serde(rename = "Principal")]
+    pub principal: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ResourceStatus {
+    /// `CREATION_IN_PROGRESS`
+    CreationInProgress,
+    /// `CREATION_SUCCESSFUL`
+    CreationSuccessful,
+    /// `CREATION_FAILED`
+    CreationFailed,
+    /// `UPDATE_IN_PROGRESS`
+    UpdateInProgress,
+    /// `UPDATE_SUCCESSFUL`
+    UpdateSuccessful,
+    /// `UPDATE_FAILED`
+    UpdateFailed,
+    /// `DELETED`
+    Deleted,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ResourceStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceStatus::CreationInProgress => "CREATION_IN_PROGRESS",
+            ResourceStatus::CreationSuccessful => "CREATION_SUCCESSFUL",
+            ResourceStatus::CreationFailed => "CREATION_FAILED",
+            ResourceStatus::UpdateInProgress => "UPDATE_IN_PROGRESS",
+            ResourceStatus::UpdateSuccessful => "UPDATE_SUCCESSFUL",
+            ResourceStatus::UpdateFailed => "UPDATE_FAILED",
+            ResourceStatus::Deleted => "DELETED",
+            ResourceStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ResourceStatus {
+    fn default() -> ResourceStatus {
+        ResourceStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ResourceStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ResourceStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ResourceStatus {
+    fn from(value: &'a str) -> ResourceStatus {
+        match value {
+            "CREATION_IN_PROGRESS" => ResourceStatus::CreationInProgress,
+            "CREATION_SUCCESSFUL" => ResourceStatus::CreationSuccessful,
+            "CREATION_FAILED" => ResourceStatus::CreationFailed,
+            "UPDATE_IN_PROGRESS" => ResourceStatus::UpdateInProgress,
+            "UPDATE_SUCCESSFUL" => ResourceStatus::UpdateSuccessful,
+            "UPDATE_FAILED" => ResourceStatus::UpdateFailed,
+            "DELETED" => ResourceStatus::Deleted,
+            _ => ResourceStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceStatus {
+    fn from(value: String) -> ResourceStatus {
+        match value.as_str() {
+            "CREATION_IN_PROGRESS" => ResourceStatus::CreationInProgress,
+            "CREATION_SUCCESSFUL" => ResourceStatus::CreationSuccessful,
+            "CREATION_FAILED" => ResourceStatus::CreationFailed,
+            "UPDATE_IN_PROGRESS" => ResourceStatus::UpdateInProgress,
+            "UPDATE_SUCCESSFUL" => ResourceStatus::UpdateSuccessful,
+            "UPDATE_FAILED" => ResourceStatus::UpdateFailed,
+            "DELETED" => ResourceStatus::Deleted,
+            _ => ResourceStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ResourceStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ResourceStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ResourceStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ResourceStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ResourceStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ResourceStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Information about rows for a data set SPICE ingestion.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct RowInfo {
+    /// <p>The number of rows that were not ingested.</p>
+    #[serde(rename = "RowsDropped")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rows_dropped: Option<i64>,
+    /// <p>The number of rows that were ingested.</p>
+    #[serde(rename = "RowsIngested")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rows_ingested: Option<i64>,
+}
+
+/// <p>The row-level security configuration for the dataset.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RowLevelPermissionDataSet {
+    /// <p>The Amazon Resource Name (ARN) of the permission dataset.</p>
+    #[serde(rename = "Arn")]
+    pub arn: String,
+    /// <p>Permission policy.</p>
+    #[serde(rename = "PermissionPolicy")]
+    pub permission_policy: RowLevelPermissionPolicy,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RowLevelPermissionPolicy {
+    /// `GRANT_ACCESS`
+    GrantAccess,
+    /// `DENY_ACCESS`
+    DenyAccess,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RowLevelPermissionPolicy {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RowLevelPermissionPolicy::GrantAccess => "GRANT_ACCESS",
+            RowLevelPermissionPolicy::DenyAccess => "DENY_ACCESS",
+            RowLevelPermissionPolicy::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RowLevelPermissionPolicy {
+    fn default() -> RowLevelPermissionPolicy {
+        RowLevelPermissionPolicy::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RowLevelPermissionPolicy {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RowLevelPermissionPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RowLevelPermissionPolicy {
+    fn from(value: &'a str) -> RowLevelPermissionPolicy {
+        match value {
+            "GRANT_ACCESS" => RowLevelPermissionPolicy::GrantAccess,
+            "DENY_ACCESS" => RowLevelPermissionPolicy::DenyAccess,
+            _ => RowLevelPermissionPolicy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RowLevelPermissionPolicy {
+    fn from(value: String) -> RowLevelPermissionPolicy {
+        match value.as_str() {
+            "GRANT_ACCESS" => RowLevelPermissionPolicy::GrantAccess,
+            "DENY_ACCESS" => RowLevelPermissionPolicy::DenyAccess,
+            _ => RowLevelPermissionPolicy::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RowLevelPermissionPolicy {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RowLevelPermissionPolicy, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RowLevelPermissionPolicy {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
 }
 
-/// <p>Information about rows for a data set SPICE ingestion.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct RowInfo {
-    /// <p>The number of rows that were not ingested.</p>
-    #[serde(rename = "RowsDropped")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub rows_dropped: Option<i64>,
-    /// <p>The number of rows that were ingested.</p>
-    #[serde(rename = "RowsIngested")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub rows_ingested: Option<i64>,
+impl ::serde::Serialize for RowLevelPermissionPolicy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
-/// <p>The row-level security configuration for the dataset.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct RowLevelPermissionDataSet {
-    /// <p>The Amazon Resource Name (ARN) of the permission dataset.</p>
-    #[serde(rename = "Arn")]
-    pub arn: String,
-    /// <p>Permission policy.</p>
-    #[serde(rename = "PermissionPolicy")]
-    pub permission_policy: String,
+impl<'de> ::serde::Deserialize<'de> for RowLevelPermissionPolicy {
+    fn deserialize<D>(deserializer: D) -> Result<RowLevelPermissionPolicy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
 }
-
 /// <p>S3 parameters.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct S3Parameters {
@@ -3167,7 +5916,7 @@ pub struct SheetControlsOption {
     /// <p>Visibility state.</p>
     #[serde(rename = "VisibilityState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub visibility_state: Option<String>,
+    pub visibility_state: Option<DashboardUIState>,
 }
 
 /// <p>Snowflake parameters.</p>
@@ -3286,12 +6035,22 @@ pub struct Template {
     pub arn: Option<String>,
     /// <p>Time when this was created.</p>
     #[serde(rename = "CreatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time: Option<f64>,
+    pub created_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Time when this was last updated.</p>
     #[serde(rename = "LastUpdatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_time: Option<f64>,
+    pub last_updated_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The display name of the template.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3335,9 +6094,112 @@ pub struct TemplateError {
     /// <p>Type of error.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<TemplateErrorType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TemplateErrorType {
+    /// `SOURCE_NOT_FOUND`
+    SourceNotFound,
+    /// `DATA_SET_NOT_FOUND`
+    DataSetNotFound,
+    /// `INTERNAL_FAILURE`
+    InternalFailure,
+    /// `ACCESS_DENIED`
+    AccessDenied,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TemplateErrorType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TemplateErrorType::SourceNotFound => "SOURCE_NOT_FOUND",
+            TemplateErrorType::DataSetNotFound => "DATA_SET_NOT_FOUND",
+            TemplateErrorType::InternalFailure => "INTERNAL_FAILURE",
+            TemplateErrorType::AccessDenied => "ACCESS_DENIED",
+            TemplateErrorType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TemplateErrorType {
+    fn default() -> TemplateErrorType {
+        TemplateErrorType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TemplateErrorType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TemplateErrorType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TemplateErrorType {
+    fn from(value: &'a str) -> TemplateErrorType {
+        match value {
+            "SOURCE_NOT_FOUND" => TemplateErrorType::SourceNotFound,
+            "DATA_SET_NOT_FOUND" => TemplateErrorType::DataSetNotFound,
+            "INTERNAL_FAILURE" => TemplateErrorType::InternalFailure,
+            "ACCESS_DENIED" => TemplateErrorType::AccessDenied,
+            _ => TemplateErrorType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TemplateErrorType {
+    fn from(value: String) -> TemplateErrorType {
+        match value.as_str() {
+            "SOURCE_NOT_FOUND" => TemplateErrorType::SourceNotFound,
+            "DATA_SET_NOT_FOUND" => TemplateErrorType::DataSetNotFound,
+            "INTERNAL_FAILURE" => TemplateErrorType::InternalFailure,
+            "ACCESS_DENIED" => TemplateErrorType::AccessDenied,
+            _ => TemplateErrorType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TemplateErrorType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TemplateErrorType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TemplateErrorType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TemplateErrorType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for TemplateErrorType {
+    fn deserialize<D>(deserializer: D) -> Result<TemplateErrorType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The source analysis of the template.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -3383,12 +6245,22 @@ pub struct TemplateSummary {
     pub arn: Option<String>,
     /// <p>The last time that this template was created.</p>
     #[serde(rename = "CreatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time: Option<f64>,
+    pub created_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The last time that this template was updated.</p>
     #[serde(rename = "LastUpdatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_time: Option<f64>,
+    pub last_updated_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A structure containing a list of version numbers for the template summary.</p>
     #[serde(rename = "LatestVersionNumber")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3409,8 +6281,13 @@ pub struct TemplateSummary {
 pub struct TemplateVersion {
     /// <p>The time that this template version was created.</p>
     #[serde(rename = "CreatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time: Option<f64>,
+    pub created_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Schema of the dataset identified by the placeholder. The idea is that any dashboard created from the template should be bound to new datasets matching the same schema described through this API. .</p>
     #[serde(rename = "DataSetConfigurations")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3430,7 +6307,7 @@ pub struct TemplateVersion {
     /// <p>The HTTP status of the request.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ResourceStatus>,
     /// <p>The version number of the template.</p>
     #[serde(rename = "VersionNumber")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3447,8 +6324,13 @@ pub struct TemplateVersionSummary {
     pub arn: Option<String>,
     /// <p>The time that this template version was created.</p>
     #[serde(rename = "CreatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time: Option<f64>,
+    pub created_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The description of the template version.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3456,7 +6338,7 @@ pub struct TemplateVersionSummary {
     /// <p>The status of the template version.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ResourceStatus>,
     /// <p>The version number of the template version.</p>
     #[serde(rename = "VersionNumber")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3477,6 +6359,99 @@ pub struct TeradataParameters {
     pub port: i64,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TextQualifier {
+    /// `DOUBLE_QUOTE`
+    DoubleQuote,
+    /// `SINGLE_QUOTE`
+    SingleQuote,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TextQualifier {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TextQualifier::DoubleQuote => "DOUBLE_QUOTE",
+            TextQualifier::SingleQuote => "SINGLE_QUOTE",
+            TextQualifier::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TextQualifier {
+    fn default() -> TextQualifier {
+        TextQualifier::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TextQualifier {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TextQualifier {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TextQualifier {
+    fn from(value: &'a str) -> TextQualifier {
+        match value {
+            "DOUBLE_QUOTE" => TextQualifier::DoubleQuote,
+            "SINGLE_QUOTE" => TextQualifier::SingleQuote,
+            _ => TextQualifier::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TextQualifier {
+    fn from(value: String) -> TextQualifier {
+        match value.as_str() {
+            "DOUBLE_QUOTE" => TextQualifier::DoubleQuote,
+            "SINGLE_QUOTE" => TextQualifier::SingleQuote,
+            _ => TextQualifier::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TextQualifier {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TextQualifier, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TextQualifier {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TextQualifier {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TextQualifier {
+    fn deserialize<D>(deserializer: D) -> Result<TextQualifier, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A data transformation on a logical table. This is a variant type structure. For this structure to be valid, only one of the attributes can be non-null.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TransformOperation {
@@ -3659,7 +6634,7 @@ pub struct UpdateDashboardResponse {
     /// <p>The creation status of the request.</p>
     #[serde(rename = "CreationStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_status: Option<String>,
+    pub creation_status: Option<ResourceStatus>,
     /// <p>The ID for the dashboard.</p>
     #[serde(rename = "DashboardId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3733,7 +6708,7 @@ pub struct UpdateDataSetRequest {
     pub data_set_id: String,
     /// <p>Indicates whether you want to import the data into SPICE.</p>
     #[serde(rename = "ImportMode")]
-    pub import_mode: String,
+    pub import_mode: DataSetImportMode,
     /// <p>Configures the combination and transformation of the data from the physical tables.</p>
     #[serde(rename = "LogicalTableMap")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3871,7 +6846,7 @@ pub struct UpdateDataSourceResponse {
     /// <p>The update status of the data source's last update.</p>
     #[serde(rename = "UpdateStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub update_status: Option<String>,
+    pub update_status: Option<ResourceStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -3918,7 +6893,7 @@ pub struct UpdateIAMPolicyAssignmentRequest {
     /// <p><p>The status of the assignment. Possible values are as follows:</p> <ul> <li> <p> <code>ENABLED</code> - Anything specified in this assignment is used when creating the data source.</p> </li> <li> <p> <code>DISABLED</code> - This assignment isn&#39;t used when creating the data source.</p> </li> <li> <p> <code>DRAFT</code> - This assignment is an unfinished draft and isn&#39;t used when creating the data source.</p> </li> </ul></p>
     #[serde(rename = "AssignmentStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub assignment_status: Option<String>,
+    pub assignment_status: Option<AssignmentStatus>,
     /// <p>The ID of the AWS account that contains the IAM policy assignment.</p>
     #[serde(rename = "AwsAccountId")]
     pub aws_account_id: String,
@@ -3949,7 +6924,7 @@ pub struct UpdateIAMPolicyAssignmentResponse {
     /// <p><p>The status of the assignment. Possible values are as follows:</p> <ul> <li> <p> <code>ENABLED</code> - Anything specified in this assignment is used when creating the data source.</p> </li> <li> <p> <code>DISABLED</code> - This assignment isn&#39;t used when creating the data source.</p> </li> <li> <p> <code>DRAFT</code> - This assignment is an unfinished draft and isn&#39;t used when creating the data source.</p> </li> </ul></p>
     #[serde(rename = "AssignmentStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub assignment_status: Option<String>,
+    pub assignment_status: Option<AssignmentStatus>,
     /// <p>The QuickSight users, groups, or both that the IAM policy is assigned to.</p>
     #[serde(rename = "Identities")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4078,7 +7053,7 @@ pub struct UpdateTemplateResponse {
     /// <p>The creation status of the template.</p>
     #[serde(rename = "CreationStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_status: Option<String>,
+    pub creation_status: Option<ResourceStatus>,
     /// <p>The AWS request ID for this operation.</p>
     #[serde(rename = "RequestId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4111,7 +7086,7 @@ pub struct UpdateUserRequest {
     pub namespace: String,
     /// <p><p>The Amazon QuickSight role of the user. The user role can be one of the following:</p> <ul> <li> <p> <code>READER</code>: A user who has read-only access to dashboards.</p> </li> <li> <p> <code>AUTHOR</code>: A user who can create data sources, datasets, analyses, and dashboards.</p> </li> <li> <p> <code>ADMIN</code>: A user who is an author, who can also manage Amazon QuickSight settings.</p> </li> </ul></p>
     #[serde(rename = "Role")]
-    pub role: String,
+    pub role: UserRole,
     /// <p>The Amazon QuickSight user name that you want to update.</p>
     #[serde(rename = "UserName")]
     pub user_name: String,
@@ -4148,7 +7123,7 @@ pub struct UploadSettings {
     /// <p>File format.</p>
     #[serde(rename = "Format")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub format: Option<String>,
+    pub format: Option<FileFormat>,
     /// <p>A row number to start reading data from.</p>
     #[serde(rename = "StartFromRow")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4156,7 +7131,7 @@ pub struct UploadSettings {
     /// <p>Text qualifier.</p>
     #[serde(rename = "TextQualifier")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub text_qualifier: Option<String>,
+    pub text_qualifier: Option<TextQualifier>,
 }
 
 /// <p>A registered user of Amazon QuickSight. Currently, an Amazon QuickSight subscription can't contain more than 20 million users.</p>
@@ -4178,7 +7153,7 @@ pub struct User {
     /// <p>The type of identity authentication used by the user.</p>
     #[serde(rename = "IdentityType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub identity_type: Option<String>,
+    pub identity_type: Option<IdentityType>,
     /// <p>The principal ID of the user.</p>
     #[serde(rename = "PrincipalId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4186,13 +7161,121 @@ pub struct User {
     /// <p><p>The Amazon QuickSight role for the user. The user role can be one of the following:.</p> <ul> <li> <p> <code>READER</code>: A user who has read-only access to dashboards.</p> </li> <li> <p> <code>AUTHOR</code>: A user who can create data sources, datasets, analyses, and dashboards.</p> </li> <li> <p> <code>ADMIN</code>: A user who is an author, who can also manage Amazon QuickSight settings.</p> </li> <li> <p> <code>RESTRICTED<em>READER</code>: This role isn&#39;t currently available for use.</p> </li> <li> <p> <code>RESTRICTED</em>AUTHOR</code>: This role isn&#39;t currently available for use.</p> </li> </ul></p>
     #[serde(rename = "Role")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub role: Option<String>,
+    pub role: Option<UserRole>,
     /// <p>The user's user name.</p>
     #[serde(rename = "UserName")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub user_name: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum UserRole {
+    /// `ADMIN`
+    Admin,
+    /// `AUTHOR`
+    Author,
+    /// `READER`
+    Reader,
+    /// `RESTRICTED_AUTHOR`
+    RestrictedAuthor,
+    /// `RESTRICTED_READER`
+    RestrictedReader,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl UserRole {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            UserRole::Admin => "ADMIN",
+            UserRole::Author => "AUTHOR",
+            UserRole::Reader => "READER",
+            UserRole::RestrictedAuthor => "RESTRICTED_AUTHOR",
+            UserRole::RestrictedReader => "RESTRICTED_READER",
+            UserRole::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for UserRole {
+    fn default() -> UserRole {
+        UserRole::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for UserRole {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for UserRole {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for UserRole {
+    fn from(value: &'a str) -> UserRole {
+        match value {
+            "ADMIN" => UserRole::Admin,
+            "AUTHOR" => UserRole::Author,
+            "READER" => UserRole::Reader,
+            "RESTRICTED_AUTHOR" => UserRole::RestrictedAuthor,
+            "RESTRICTED_READER" => UserRole::RestrictedReader,
+            _ => UserRole::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for UserRole {
+    fn from(value: String) -> UserRole {
+        match value.as_str() {
+            "ADMIN" => UserRole::Admin,
+            "AUTHOR" => UserRole::Author,
+            "READER" => UserRole::Reader,
+            "RESTRICTED_AUTHOR" => UserRole::RestrictedAuthor,
+            "RESTRICTED_READER" => UserRole::RestrictedReader,
+            _ => UserRole::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for UserRole {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<UserRole, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for UserRole {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for UserRole {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for UserRole {
+    fn deserialize<D>(deserializer: D) -> Result<UserRole, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>VPC connection properties.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct VpcConnectionProperties {
@@ -9077,11 +12160,12 @@ pub struct QuicksightClient {
 impl QuicksightClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> QuicksightClient {
         QuicksightClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "QuickSight"),
         }
     }
 
@@ -9120,6 +12204,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation("CancelIngestion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -9152,6 +12237,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation("CreateDashboard");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9186,6 +12272,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation("CreateDataSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9220,6 +12307,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation("CreateDataSource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9255,6 +12343,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation("CreateGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9286,6 +12375,7 @@ impl Quicksight for QuicksightClient {
         let request_uri = format!("/accounts/{aws_account_id}/namespaces/{namespace}/groups/{group_name}/members/{member_name}", aws_account_id = input.aws_account_id, group_name = input.group_name, member_name = input.member_name, namespace = input.namespace);
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation("CreateGroupMembership");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -9319,6 +12409,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation("CreateIAMPolicyAssignment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9355,6 +12446,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation("CreateIngestion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -9387,6 +12479,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation("CreateTemplate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9423,6 +12516,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation("CreateTemplateAlias");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9458,6 +12552,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation("DeleteDashboard");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9496,6 +12591,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation("DeleteDataSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -9528,6 +12624,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation("DeleteDataSource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -9561,6 +12658,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation("DeleteGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -9589,6 +12687,7 @@ impl Quicksight for QuicksightClient {
         let request_uri = format!("/accounts/{aws_account_id}/namespaces/{namespace}/groups/{group_name}/members/{member_name}", aws_account_id = input.aws_account_id, group_name = input.group_name, member_name = input.member_name, namespace = input.namespace);
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation("DeleteGroupMembership");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -9618,6 +12717,7 @@ impl Quicksight for QuicksightClient {
         let request_uri = format!("/accounts/{aws_account_id}/namespace/{namespace}/iam-policy-assignments/{assignment_name}", assignment_name = input.assignment_name, aws_account_id = input.aws_account_id, namespace = input.namespace);
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation("DeleteIAMPolicyAssignment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -9650,6 +12750,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation("DeleteTemplate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9689,6 +12790,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation("DeleteTemplateAlias");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -9722,6 +12824,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation("DeleteUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -9755,6 +12858,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation("DeleteUserByPrincipalId");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -9787,6 +12891,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("DescribeDashboard");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9829,6 +12934,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("DescribeDashboardPermissions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -9861,6 +12967,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("DescribeDataSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -9894,6 +13001,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("DescribeDataSetPermissions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -9926,6 +13034,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("DescribeDataSource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -9961,6 +13070,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("DescribeDataSourcePermissions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -9994,6 +13104,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("DescribeGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -10023,6 +13134,7 @@ impl Quicksight for QuicksightClient {
         let request_uri = format!("/accounts/{aws_account_id}/namespaces/{namespace}/iam-policy-assignments/{assignment_name}", assignment_name = input.assignment_name, aws_account_id = input.aws_account_id, namespace = input.namespace);
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("DescribeIAMPolicyAssignment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -10056,6 +13168,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("DescribeIngestion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -10088,6 +13201,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("DescribeTemplate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10130,6 +13244,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("DescribeTemplateAlias");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -10163,6 +13278,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("DescribeTemplatePermissions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -10196,6 +13312,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("DescribeUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -10228,6 +13345,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("GetDashboardEmbedUrl");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10276,6 +13394,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListDashboardVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10316,6 +13435,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListDashboards");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10356,6 +13476,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListDataSets");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10396,6 +13517,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListDataSources");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10438,6 +13560,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListGroupMemberships");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10479,6 +13602,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListGroups");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10520,6 +13644,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListIAMPolicyAssignments");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10563,6 +13688,7 @@ impl Quicksight for QuicksightClient {
         let request_uri = format!("/accounts/{aws_account_id}/namespaces/{namespace}/users/{user_name}/iam-policy-assignments", aws_account_id = input.aws_account_id, namespace = input.namespace, user_name = input.user_name);
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListIAMPolicyAssignmentsForUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10606,6 +13732,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListIngestions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10646,6 +13773,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -10678,6 +13806,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListTemplateAliases");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10719,6 +13848,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListTemplateVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10759,6 +13889,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListTemplates");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10801,6 +13932,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListUserGroups");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10842,6 +13974,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListUsers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10883,6 +14016,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation("RegisterUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10917,6 +14051,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10951,6 +14086,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10989,6 +14125,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation("UpdateDashboard");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11024,6 +14161,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation("UpdateDashboardPermissions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11063,6 +14201,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation("UpdateDashboardPublishedVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11097,6 +14236,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation("UpdateDataSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11132,6 +14272,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation("UpdateDataSetPermissions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11167,6 +14308,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation("UpdateDataSource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11203,6 +14345,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation("UpdateDataSourcePermissions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11239,6 +14382,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation("UpdateGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11271,6 +14415,7 @@ impl Quicksight for QuicksightClient {
         let request_uri = format!("/accounts/{aws_account_id}/namespaces/{namespace}/iam-policy-assignments/{assignment_name}", assignment_name = input.assignment_name, aws_account_id = input.aws_account_id, namespace = input.namespace);
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation("UpdateIAMPolicyAssignment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11306,6 +14451,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation("UpdateTemplate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11342,6 +14488,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation("UpdateTemplateAlias");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11378,6 +14525,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation("UpdateTemplatePermissions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11414,6 +14562,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation("UpdateUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11437,3 +14586,471 @@ impl Quicksight for QuicksightClient {
         }
     }
 }
+impl QuicksightClient {
+    /// Auto-paginating version of `list_dashboard_versions`, streaming every page of results.
+    pub fn list_dashboard_versions_pages(
+        &self,
+        input: ListDashboardVersionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListDashboardVersionsResponse,
+        ListDashboardVersionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: QuicksightClient, input: ListDashboardVersionsRequest| async move {
+                client.list_dashboard_versions(input).await
+            },
+            |input: &ListDashboardVersionsRequest, output: &ListDashboardVersionsResponse| {
+                Some(ListDashboardVersionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_dashboard_versions`, streaming the `DashboardVersionSummaryList` of every page of results.
+    pub fn list_dashboard_versions_items(
+        &self,
+        input: ListDashboardVersionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DashboardVersionSummary, ListDashboardVersionsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_dashboard_versions_pages(input),
+            |output: ListDashboardVersionsResponse| {
+                output.dashboard_version_summary_list.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_dashboards`, streaming every page of results.
+    pub fn list_dashboards_pages(
+        &self,
+        input: ListDashboardsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListDashboardsResponse, ListDashboardsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: QuicksightClient, input: ListDashboardsRequest| async move {
+                client.list_dashboards(input).await
+            },
+            |input: &ListDashboardsRequest, output: &ListDashboardsResponse| {
+                Some(ListDashboardsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_dashboards`, streaming the `DashboardSummaryList` of every page of results.
+    pub fn list_dashboards_items(
+        &self,
+        input: ListDashboardsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DashboardSummary, ListDashboardsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_dashboards_pages(input),
+            |output: ListDashboardsResponse| output.dashboard_summary_list.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_data_sets`, streaming every page of results.
+    pub fn list_data_sets_pages(
+        &self,
+        input: ListDataSetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListDataSetsResponse, ListDataSetsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: QuicksightClient, input: ListDataSetsRequest| async move {
+                client.list_data_sets(input).await
+            },
+            |input: &ListDataSetsRequest, output: &ListDataSetsResponse| {
+                Some(ListDataSetsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_data_sets`, streaming the `DataSetSummaries` of every page of results.
+    pub fn list_data_sets_items(
+        &self,
+        input: ListDataSetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DataSetSummary, ListDataSetsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_data_sets_pages(input),
+            |output: ListDataSetsResponse| output.data_set_summaries.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_data_sources`, streaming every page of results.
+    pub fn list_data_sources_pages(
+        &self,
+        input: ListDataSourcesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListDataSourcesResponse, ListDataSourcesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: QuicksightClient, input: ListDataSourcesRequest| async move {
+                client.list_data_sources(input).await
+            },
+            |input: &ListDataSourcesRequest, output: &ListDataSourcesResponse| {
+                Some(ListDataSourcesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_data_sources`, streaming the `DataSources` of every page of results.
+    pub fn list_data_sources_items(
+        &self,
+        input: ListDataSourcesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DataSource, ListDataSourcesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_data_sources_pages(input),
+            |output: ListDataSourcesResponse| output.data_sources.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_group_memberships`, streaming every page of results.
+    pub fn list_group_memberships_pages(
+        &self,
+        input: ListGroupMembershipsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListGroupMembershipsResponse,
+        ListGroupMembershipsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: QuicksightClient, input: ListGroupMembershipsRequest| async move {
+                client.list_group_memberships(input).await
+            },
+            |input: &ListGroupMembershipsRequest, output: &ListGroupMembershipsResponse| {
+                Some(ListGroupMembershipsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_group_memberships`, streaming the `GroupMemberList` of every page of results.
+    pub fn list_group_memberships_items(
+        &self,
+        input: ListGroupMembershipsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GroupMember, ListGroupMembershipsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_group_memberships_pages(input),
+            |output: ListGroupMembershipsResponse| output.group_member_list.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_groups`, streaming every page of results.
+    pub fn list_groups_pages(
+        &self,
+        input: ListGroupsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListGroupsResponse, ListGroupsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: QuicksightClient, input: ListGroupsRequest| async move {
+                client.list_groups(input).await
+            },
+            |input: &ListGroupsRequest, output: &ListGroupsResponse| {
+                Some(ListGroupsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_groups`, streaming the `GroupList` of every page of results.
+    pub fn list_groups_items(
+        &self,
+        input: ListGroupsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Group, ListGroupsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_groups_pages(input),
+            |output: ListGroupsResponse| output.group_list.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_iam_policy_assignments`, streaming every page of results.
+    pub fn list_iam_policy_assignments_pages(
+        &self,
+        input: ListIAMPolicyAssignmentsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListIAMPolicyAssignmentsResponse,
+        ListIAMPolicyAssignmentsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: QuicksightClient, input: ListIAMPolicyAssignmentsRequest| async move {
+                client.list_iam_policy_assignments(input).await
+            },
+            |input: &ListIAMPolicyAssignmentsRequest, output: &ListIAMPolicyAssignmentsResponse| {
+                Some(ListIAMPolicyAssignmentsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_iam_policy_assignments`, streaming the `IAMPolicyAssignments` of every page of results.
+    pub fn list_iam_policy_assignments_items(
+        &self,
+        input: ListIAMPolicyAssignmentsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        IAMPolicyAssignmentSummary,
+        ListIAMPolicyAssignmentsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_iam_policy_assignments_pages(input),
+            |output: ListIAMPolicyAssignmentsResponse| {
+                output.iam_policy_assignments.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_iam_policy_assignments_for_user`, streaming every page of results.
+    pub fn list_iam_policy_assignments_for_user_pages(
+        &self,
+        input: ListIAMPolicyAssignmentsForUserRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListIAMPolicyAssignmentsForUserResponse,
+        ListIAMPolicyAssignmentsForUserError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: QuicksightClient, input: ListIAMPolicyAssignmentsForUserRequest| async move {
+                client.list_iam_policy_assignments_for_user(input).await
+            },
+            |input: &ListIAMPolicyAssignmentsForUserRequest,
+             output: &ListIAMPolicyAssignmentsForUserResponse| {
+                Some(ListIAMPolicyAssignmentsForUserRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_iam_policy_assignments_for_user`, streaming the `ActiveAssignments` of every page of results.
+    pub fn list_iam_policy_assignments_for_user_items(
+        &self,
+        input: ListIAMPolicyAssignmentsForUserRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ActiveIAMPolicyAssignment,
+        ListIAMPolicyAssignmentsForUserError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_iam_policy_assignments_for_user_pages(input),
+            |output: ListIAMPolicyAssignmentsForUserResponse| {
+                output.active_assignments.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_ingestions`, streaming every page of results.
+    pub fn list_ingestions_pages(
+        &self,
+        input: ListIngestionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListIngestionsResponse, ListIngestionsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: QuicksightClient, input: ListIngestionsRequest| async move {
+                client.list_ingestions(input).await
+            },
+            |input: &ListIngestionsRequest, output: &ListIngestionsResponse| {
+                Some(ListIngestionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_ingestions`, streaming the `Ingestions` of every page of results.
+    pub fn list_ingestions_items(
+        &self,
+        input: ListIngestionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Ingestion, ListIngestionsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_ingestions_pages(input),
+            |output: ListIngestionsResponse| output.ingestions.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_template_aliases`, streaming every page of results.
+    pub fn list_template_aliases_pages(
+        &self,
+        input: ListTemplateAliasesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListTemplateAliasesResponse, ListTemplateAliasesError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: QuicksightClient, input: ListTemplateAliasesRequest| async move {
+                client.list_template_aliases(input).await
+            },
+            |input: &ListTemplateAliasesRequest, output: &ListTemplateAliasesResponse| {
+                Some(ListTemplateAliasesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_template_aliases`, streaming the `TemplateAliasList` of every page of results.
+    pub fn list_template_aliases_items(
+        &self,
+        input: ListTemplateAliasesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<TemplateAlias, ListTemplateAliasesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_template_aliases_pages(input),
+            |output: ListTemplateAliasesResponse| output.template_alias_list.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_template_versions`, streaming every page of results.
+    pub fn list_template_versions_pages(
+        &self,
+        input: ListTemplateVersionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListTemplateVersionsResponse,
+        ListTemplateVersionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: QuicksightClient, input: ListTemplateVersionsRequest| async move {
+                client.list_template_versions(input).await
+            },
+            |input: &ListTemplateVersionsRequest, output: &ListTemplateVersionsResponse| {
+                Some(ListTemplateVersionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_template_versions`, streaming the `TemplateVersionSummaryList` of every page of results.
+    pub fn list_template_versions_items(
+        &self,
+        input: ListTemplateVersionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<TemplateVersionSummary, ListTemplateVersionsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_template_versions_pages(input),
+            |output: ListTemplateVersionsResponse| {
+                output.template_version_summary_list.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_templates`, streaming every page of results.
+    pub fn list_templates_pages(
+        &self,
+        input: ListTemplatesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListTemplatesResponse, ListTemplatesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: QuicksightClient, input: ListTemplatesRequest| async move {
+                client.list_templates(input).await
+            },
+            |input: &ListTemplatesRequest, output: &ListTemplatesResponse| {
+                Some(ListTemplatesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_templates`, streaming the `TemplateSummaryList` of every page of results.
+    pub fn list_templates_items(
+        &self,
+        input: ListTemplatesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<TemplateSummary, ListTemplatesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_templates_pages(input),
+            |output: ListTemplatesResponse| output.template_summary_list.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_user_groups`, streaming every page of results.
+    pub fn list_user_groups_pages(
+        &self,
+        input: ListUserGroupsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListUserGroupsResponse, ListUserGroupsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: QuicksightClient, input: ListUserGroupsRequest| async move {
+                client.list_user_groups(input).await
+            },
+            |input: &ListUserGroupsRequest, output: &ListUserGroupsResponse| {
+                Some(ListUserGroupsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_user_groups`, streaming the `GroupList` of every page of results.
+    pub fn list_user_groups_items(
+        &self,
+        input: ListUserGroupsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Group, ListUserGroupsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_user_groups_pages(input),
+            |output: ListUserGroupsResponse| output.group_list.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_users`, streaming every page of results.
+    pub fn list_users_pages(
+        &self,
+        input: ListUsersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListUsersResponse, ListUsersError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: QuicksightClient, input: ListUsersRequest| async move {
+                client.list_users(input).await
+            },
+            |input: &ListUsersRequest, output: &ListUsersResponse| {
+                Some(ListUsersRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_users`, streaming the `UserList` of every page of results.
+    pub fn list_users_items(
+        &self,
+        input: ListUsersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<User, ListUsersError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_users_pages(input),
+            |output: ListUsersResponse| output.user_list.unwrap_or_default(),
+        )
+    }
+}
@@ -319,10 +319,10 @@ pub struct GetResourceShareAssociationsRequest {
     /// <p>The association status.</p>
     #[serde(rename = "associationStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub association_status: Option<String>,
+    pub association_status: Option<ResourceShareAssociationStatus>,
     /// <p>The association type. Specify <code>PRINCIPAL</code> to list the principals that are associated with the specified resource share. Specify <code>RESOURCE</code> to list the resources that are associated with the specified resource share.</p>
     #[serde(rename = "associationType")]
-    pub association_type: String,
+    pub association_type: ResourceShareAssociationType,
     /// <p>The maximum number of results to return with a single call. To retrieve the remaining results, make another call with the returned <code>nextToken</code> value.</p>
     #[serde(rename = "maxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -409,7 +409,7 @@ pub struct GetResourceSharesRequest {
     pub next_token: Option<String>,
     /// <p>The type of owner.</p>
     #[serde(rename = "resourceOwner")]
-    pub resource_owner: String,
+    pub resource_owner: ResourceOwner,
     /// <p>The Amazon Resource Names (ARN) of the resource shares.</p>
     #[serde(rename = "resourceShareArns")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -417,7 +417,7 @@ pub struct GetResourceSharesRequest {
     /// <p>The status of the resource share.</p>
     #[serde(rename = "resourceShareStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_share_status: Option<String>,
+    pub resource_share_status: Option<ResourceShareStatus>,
     /// <p>One or more tag filters.</p>
     #[serde(rename = "tagFilters")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -517,7 +517,7 @@ pub struct ListPrincipalsRequest {
     pub resource_arn: Option<String>,
     /// <p>The type of owner.</p>
     #[serde(rename = "resourceOwner")]
-    pub resource_owner: String,
+    pub resource_owner: ResourceOwner,
     /// <p>The Amazon Resource Names (ARN) of the resource shares.</p>
     #[serde(rename = "resourceShareArns")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -591,7 +591,7 @@ pub struct ListResourcesRequest {
     pub resource_arns: Option<Vec<String>>,
     /// <p>The type of owner.</p>
     #[serde(rename = "resourceOwner")]
-    pub resource_owner: String,
+    pub resource_owner: ResourceOwner,
     /// <p>The Amazon Resource Names (ARN) of the resource shares.</p>
     #[serde(rename = "resourceShareArns")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -621,8 +621,13 @@ pub struct ListResourcesResponse {
 pub struct Principal {
     /// <p>The time when the principal was associated with the resource share.</p>
     #[serde(rename = "creationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Indicates whether the principal belongs to the same AWS organization as the AWS account that owns the resource share.</p>
     #[serde(rename = "external")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -633,8 +638,13 @@ pub struct Principal {
     pub id: Option<String>,
     /// <p>The time when the association was last updated.</p>
     #[serde(rename = "lastUpdatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_time: Option<f64>,
+    pub last_updated_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The Amazon Resource Name (ARN) of the resource share.</p>
     #[serde(rename = "resourceShareArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -693,12 +703,22 @@ pub struct Resource {
     pub arn: Option<String>,
     /// <p>The time when the resource was associated with the resource share.</p>
     #[serde(rename = "creationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The time when the association was last updated.</p>
     #[serde(rename = "lastUpdatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_time: Option<f64>,
+    pub last_updated_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The ARN of the resource group. This value is returned only if the resource is a resource group.</p>
     #[serde(rename = "resourceGroupArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -710,7 +730,7 @@ pub struct Resource {
     /// <p>The status of the resource.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ResourceStatus>,
     /// <p>A message about the status of the resource.</p>
     #[serde(rename = "statusMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -721,6 +741,99 @@ pub struct Resource {
     pub type_: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ResourceOwner {
+    /// `SELF`
+    SelfValue,
+    /// `OTHER-ACCOUNTS`
+    OtherAccounts,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ResourceOwner {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceOwner::SelfValue => "SELF",
+            ResourceOwner::OtherAccounts => "OTHER-ACCOUNTS",
+            ResourceOwner::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ResourceOwner {
+    fn default() -> ResourceOwner {
+        ResourceOwner::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ResourceOwner {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ResourceOwner {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ResourceOwner {
+    fn from(value: &'a str) -> ResourceOwner {
+        match value {
+            "SELF" => ResourceOwner::SelfValue,
+            "OTHER-ACCOUNTS" => ResourceOwner::OtherAccounts,
+            _ => ResourceOwner::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceOwner {
+    fn from(value: String) -> ResourceOwner {
+        match value.as_str() {
+            "SELF" => ResourceOwner::SelfValue,
+            "OTHER-ACCOUNTS" => ResourceOwner::OtherAccounts,
+            _ => ResourceOwner::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ResourceOwner {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ResourceOwner, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ResourceOwner {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ResourceOwner {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ResourceOwner {
+    fn deserialize<D>(deserializer: D) -> Result<ResourceOwner, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes a resource share.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -731,16 +844,26 @@ pub struct ResourceShare {
     pub allow_external_principals: Option<bool>,
     /// <p>The time when the resource share was created.</p>
     #[serde(rename = "creationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>Indicates how the resource share was created. Possible values include:</p> <ul> <li> <p> <code>CREATED<em>FROM</em>POLICY</code> - Indicates that the resource share was created from an AWS Identity and Access Management (AWS IAM) policy attached to a resource. These resource shares are visible only to the AWS account that created it. They cannot be modified in AWS RAM.</p> </li> <li> <p> <code>PROMOTING<em>TO</em>STANDARD</code> - The resource share is in the process of being promoted. For more information, see <a>PromoteResourceShareCreatedFromPolicy</a>.</p> </li> <li> <p> <code>STANDARD</code> - Indicates that the resource share was created in AWS RAM using the console or APIs. These resource shares are visible to all principals. They can be modified in AWS RAM.</p> </li> </ul></p>
     #[serde(rename = "featureSet")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub feature_set: Option<String>,
+    pub feature_set: Option<ResourceShareFeatureSet>,
     /// <p>The time when the resource share was last updated.</p>
     #[serde(rename = "lastUpdatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_time: Option<f64>,
+    pub last_updated_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the resource share.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -756,7 +879,7 @@ pub struct ResourceShare {
     /// <p>The status of the resource share.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ResourceShareStatus>,
     /// <p>A message about the status of the resource share.</p>
     #[serde(rename = "statusMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -778,19 +901,29 @@ pub struct ResourceShareAssociation {
     /// <p>The association type.</p>
     #[serde(rename = "associationType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub association_type: Option<String>,
+    pub association_type: Option<ResourceShareAssociationType>,
     /// <p>The time when the association was created.</p>
     #[serde(rename = "creationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Indicates whether the principal belongs to the same AWS organization as the AWS account that owns the resource share.</p>
     #[serde(rename = "external")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub external: Option<bool>,
     /// <p>The time when the association was last updated.</p>
     #[serde(rename = "lastUpdatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_time: Option<f64>,
+    pub last_updated_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The Amazon Resource Name (ARN) of the resource share.</p>
     #[serde(rename = "resourceShareArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -802,21 +935,325 @@ pub struct ResourceShareAssociation {
     /// <p>The status of the association.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ResourceShareAssociationStatus>,
     /// <p>A message about the status of the association.</p>
     #[serde(rename = "statusMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status_message: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ResourceShareAssociationStatus {
+    /// `ASSOCIATING`
+    Associating,
+    /// `ASSOCIATED`
+    Associated,
+    /// `FAILED`
+    Failed,
+    /// `DISASSOCIATING`
+    Disassociating,
+    /// `DISASSOCIATED`
+    Disassociated,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ResourceShareAssociationStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceShareAssociationStatus::Associating => "ASSOCIATING",
+            ResourceShareAssociationStatus::Associated => "ASSOCIATED",
+            ResourceShareAssociationStatus::Failed => "FAILED",
+            ResourceShareAssociationStatus::Disassociating => "DISASSOCIATING",
+            ResourceShareAssociationStatus::Disassociated => "DISASSOCIATED",
+            ResourceShareAssociationStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ResourceShareAssociationStatus {
+    fn default() -> ResourceShareAssociationStatus {
+        ResourceShareAssociationStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ResourceShareAssociationStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ResourceShareAssociationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ResourceShareAssociationStatus {
+    fn from(value: &'a str) -> ResourceShareAssociationStatus {
+        match value {
+            "ASSOCIATING" => ResourceShareAssociationStatus::Associating,
+            "ASSOCIATED" => ResourceShareAssociationStatus::Associated,
+            "FAILED" => ResourceShareAssociationStatus::Failed,
+            "DISASSOCIATING" => ResourceShareAssociationStatus::Disassociating,
+            "DISASSOCIATED" => ResourceShareAssociationStatus::Disassociated,
+            _ => ResourceShareAssociationStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceShareAssociationStatus {
+    fn from(value: String) -> ResourceShareAssociationStatus {
+        match value.as_str() {
+            "ASSOCIATING" => ResourceShareAssociationStatus::Associating,
+            "ASSOCIATED" => ResourceShareAssociationStatus::Associated,
+            "FAILED" => ResourceShareAssociationStatus::Failed,
+            "DISASSOCIATING" => ResourceShareAssociationStatus::Disassociating,
+            "DISASSOCIATED" => ResourceShareAssociationStatus::Disassociated,
+            _ => ResourceShareAssociationStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ResourceShareAssociationStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ResourceShareAssociationStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ResourceShareAssociationStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ResourceShareAssociationStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ResourceShareAssociationStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ResourceShareAssociationStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ResourceShareAssociationType {
+    /// `PRINCIPAL`
+    Principal,
+    /// `RESOURCE`
+    Resource,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ResourceShareAssociationType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceShareAssociationType::Principal => "PRINCIPAL",
+            ResourceShareAssociationType::Resource => "RESOURCE",
+            ResourceShareAssociationType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ResourceShareAssociationType {
+    fn default() -> ResourceShareAssociationType {
+        ResourceShareAssociationType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ResourceShareAssociationType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ResourceShareAssociationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ResourceShareAssociationType {
+    fn from(value: &'a str) -> ResourceShareAssociationType {
+        match value {
+            "PRINCIPAL" => ResourceShareAssociationType::Principal,
+            "RESOURCE" => ResourceShareAssociationType::Resource,
+            _ => ResourceShareAssociationType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceShareAssociationType {
+    fn from(value: String) -> ResourceShareAssociationType {
+        match value.as_str() {
+            "PRINCIPAL" => ResourceShareAssociationType::Principal,
+            "RESOURCE" => ResourceShareAssociationType::Resource,
+            _ => ResourceShareAssociationType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ResourceShareAssociationType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ResourceShareAssociationType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ResourceShareAssociationType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ResourceShareAssociationType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ResourceShareAssociationType {
+    fn deserialize<D>(deserializer: D) -> Result<ResourceShareAssociationType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ResourceShareFeatureSet {
+    /// `CREATED_FROM_POLICY`
+    CreatedFromPolicy,
+    /// `PROMOTING_TO_STANDARD`
+    PromotingToStandard,
+    /// `STANDARD`
+    Standard,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ResourceShareFeatureSet {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceShareFeatureSet::CreatedFromPolicy => "CREATED_FROM_POLICY",
+            ResourceShareFeatureSet::PromotingToStandard => "PROMOTING_TO_STANDARD",
+            ResourceShareFeatureSet::Standard => "STANDARD",
+            ResourceShareFeatureSet::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ResourceShareFeatureSet {
+    fn default() -> ResourceShareFeatureSet {
+        ResourceShareFeatureSet::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ResourceShareFeatureSet {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ResourceShareFeatureSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ResourceShareFeatureSet {
+    fn from(value: &'a str) -> ResourceShareFeatureSet {
+        match value {
+            "CREATED_FROM_POLICY" => ResourceShareFeatureSet::CreatedFromPolicy,
+            "PROMOTING_TO_STANDARD" => ResourceShareFeatureSet::PromotingToStandard,
+            "STANDARD" => ResourceShareFeatureSet::Standard,
+            _ => ResourceShareFeatureSet::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceShareFeatureSet {
+    fn from(value: String) -> ResourceShareFeatureSet {
+        match value.as_str() {
+            "CREATED_FROM_POLICY" => ResourceShareFeatureSet::CreatedFromPolicy,
+            "PROMOTING_TO_STANDARD" => ResourceShareFeatureSet::PromotingToStandard,
+            "STANDARD" => ResourceShareFeatureSet::Standard,
+            _ => ResourceShareFeatureSet::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ResourceShareFeatureSet {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ResourceShareFeatureSet, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ResourceShareFeatureSet {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ResourceShareFeatureSet {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ResourceShareFeatureSet {
+    fn deserialize<D>(deserializer: D) -> Result<ResourceShareFeatureSet, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes an invitation to join a resource share.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ResourceShareInvitation {
     /// <p>The date and time when the invitation was sent.</p>
     #[serde(rename = "invitationTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub invitation_timestamp: Option<f64>,
+    pub invitation_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The ID of the AWS account that received the invitation.</p>
     #[serde(rename = "receiverAccountId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -840,9 +1277,112 @@ pub struct ResourceShareInvitation {
     /// <p>The status of the invitation.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ResourceShareInvitationStatus>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ResourceShareInvitationStatus {
+    /// `PENDING`
+    Pending,
+    /// `ACCEPTED`
+    Accepted,
+    /// `REJECTED`
+    Rejected,
+    /// `EXPIRED`
+    Expired,
+    /// A value not known to this version of the crate
+    Unknown(String),
 }
 
+impl ResourceShareInvitationStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceShareInvitationStatus::Pending => "PENDING",
+            ResourceShareInvitationStatus::Accepted => "ACCEPTED",
+            ResourceShareInvitationStatus::Rejected => "REJECTED",
+            ResourceShareInvitationStatus::Expired => "EXPIRED",
+            ResourceShareInvitationStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ResourceShareInvitationStatus {
+    fn default() -> ResourceShareInvitationStatus {
+        ResourceShareInvitationStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ResourceShareInvitationStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ResourceShareInvitationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ResourceShareInvitationStatus {
+    fn from(value: &'a str) -> ResourceShareInvitationStatus {
+        match value {
+            "PENDING" => ResourceShareInvitationStatus::Pending,
+            "ACCEPTED" => ResourceShareInvitationStatus::Accepted,
+            "REJECTED" => ResourceShareInvitationStatus::Rejected,
+            "EXPIRED" => ResourceShareInvitationStatus::Expired,
+            _ => ResourceShareInvitationStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceShareInvitationStatus {
+    fn from(value: String) -> ResourceShareInvitationStatus {
+        match value.as_str() {
+            "PENDING" => ResourceShareInvitationStatus::Pending,
+            "ACCEPTED" => ResourceShareInvitationStatus::Accepted,
+            "REJECTED" => ResourceShareInvitationStatus::Rejected,
+            "EXPIRED" => ResourceShareInvitationStatus::Expired,
+            _ => ResourceShareInvitationStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ResourceShareInvitationStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ResourceShareInvitationStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ResourceShareInvitationStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ResourceShareInvitationStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ResourceShareInvitationStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ResourceShareInvitationStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about an AWS RAM permission.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -853,16 +1393,26 @@ pub struct ResourceSharePermissionDetail {
     pub arn: Option<String>,
     /// <p>The date and time when the permission was created.</p>
     #[serde(rename = "creationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The identifier for the version of the permission that is set as the default version.</p>
     #[serde(rename = "defaultVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub default_version: Option<bool>,
     /// <p>The date and time when the permission was last updated.</p>
     #[serde(rename = "lastUpdatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_time: Option<f64>,
+    pub last_updated_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the permission.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -891,16 +1441,26 @@ pub struct ResourceSharePermissionSummary {
     pub arn: Option<String>,
     /// <p>The date and time when the permission was created.</p>
     #[serde(rename = "creationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The identifier for the version of the permission that is set as the default version.</p>
     #[serde(rename = "defaultVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub default_version: Option<bool>,
     /// <p>The date and time when the permission was last updated.</p>
     #[serde(rename = "lastUpdatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_time: Option<f64>,
+    pub last_updated_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the permission.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -919,6 +1479,222 @@ pub struct ResourceSharePermissionSummary {
     pub version: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ResourceShareStatus {
+    /// `PENDING`
+    Pending,
+    /// `ACTIVE`
+    Active,
+    /// `FAILED`
+    Failed,
+    /// `DELETING`
+    Deleting,
+    /// `DELETED`
+    Deleted,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ResourceShareStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceShareStatus::Pending => "PENDING",
+            ResourceShareStatus::Active => "ACTIVE",
+            ResourceShareStatus::Failed => "FAILED",
+            ResourceShareStatus::Deleting => "DELETING",
+            ResourceShareStatus::Deleted => "DELETED",
+            ResourceShareStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ResourceShareStatus {
+    fn default() -> ResourceShareStatus {
+        ResourceShareStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ResourceShareStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ResourceShareStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ResourceShareStatus {
+    fn from(value: &'a str) -> ResourceShareStatus {
+        match value {
+            "PENDING" => ResourceShareStatus::Pending,
+            "ACTIVE" => ResourceShareStatus::Active,
+            "FAILED" => ResourceShareStatus::Failed,
+            "DELETING" => ResourceShareStatus::Deleting,
+            "DELETED" => ResourceShareStatus::Deleted,
+            _ => ResourceShareStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceShareStatus {
+    fn from(value: String) -> ResourceShareStatus {
+        match value.as_str() {
+            "PENDING" => ResourceShareStatus::Pending,
+            "ACTIVE" => ResourceShareStatus::Active,
+            "FAILED" => ResourceShareStatus::Failed,
+            "DELETING" => ResourceShareStatus::Deleting,
+            "DELETED" => ResourceShareStatus::Deleted,
+            _ => ResourceShareStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ResourceShareStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ResourceShareStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ResourceShareStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ResourceShareStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ResourceShareStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ResourceShareStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ResourceStatus {
+    /// `AVAILABLE`
+    Available,
+    /// `ZONAL_RESOURCE_INACCESSIBLE`
+    ZonalResourceInaccessible,
+    /// `LIMIT_EXCEEDED`
+    LimitExceeded,
+    /// `UNAVAILABLE`
+    Unavailable,
+    /// `PENDING`
+    Pending,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ResourceStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceStatus::Available => "AVAILABLE",
+            ResourceStatus::ZonalResourceInaccessible => "ZONAL_RESOURCE_INACCESSIBLE",
+            ResourceStatus::LimitExceeded => "LIMIT_EXCEEDED",
+            ResourceStatus::Unavailable => "UNAVAILABLE",
+            ResourceStatus::Pending => "PENDING",
+            ResourceStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ResourceStatus {
+    fn default() -> ResourceStatus {
+        ResourceStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ResourceStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ResourceStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ResourceStatus {
+    fn from(value: &'a str) -> ResourceStatus {
+        match value {
+            "AVAILABLE" => ResourceStatus::Available,
+            "ZONAL_RESOURCE_INACCESSIBLE" => ResourceStatus::ZonalResourceInaccessible,
+            "LIMIT_EXCEEDED" => ResourceStatus::LimitExceeded,
+            "UNAVAILABLE" => ResourceStatus::Unavailable,
+            "PENDING" => ResourceStatus::Pending,
+            _ => ResourceStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceStatus {
+    fn from(value: String) -> ResourceStatus {
+        match value.as_str() {
+            "AVAILABLE" => ResourceStatus::Available,
+            "ZONAL_RESOURCE_INACCESSIBLE" => ResourceStatus::ZonalResourceInaccessible,
+            "LIMIT_EXCEEDED" => ResourceStatus::LimitExceeded,
+            "UNAVAILABLE" => ResourceStatus::Unavailable,
+            "PENDING" => ResourceStatus::Pending,
+            _ => ResourceStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ResourceStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ResourceStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ResourceStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ResourceStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ResourceStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ResourceStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about a tag.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Tag {
@@ -3148,11 +3924,12 @@ pub struct RamClient {
 impl RamClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> RamClient {
         RamClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "RAM"),
         }
     }
 
@@ -3189,6 +3966,7 @@ impl Ram for RamClient {
         let request_uri = "/acceptresourceshareinvitation";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("AcceptResourceShareInvitation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3219,6 +3997,7 @@ impl Ram for RamClient {
         let request_uri = "/associateresourceshare";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("AssociateResourceShare");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3252,6 +4031,7 @@ impl Ram for RamClient {
         let request_uri = "/associateresourcesharepermission";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("AssociateResourceSharePermission");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3284,6 +4064,7 @@ impl Ram for RamClient {
         let request_uri = "/createresourceshare";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("CreateResourceShare");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3314,6 +4095,7 @@ impl Ram for RamClient {
         let request_uri = "/deleteresourceshare";
 
         let mut request = SignedRequest::new("DELETE", "ram", &self.region, &request_uri);
+        request.set_operation("DeleteResourceShare");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3349,6 +4131,7 @@ impl Ram for RamClient {
         let request_uri = "/disassociateresourceshare";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("DisassociateResourceShare");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3382,6 +4165,7 @@ impl Ram for RamClient {
         let request_uri = "/disassociateresourcesharepermission";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("DisassociateResourceSharePermission");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3416,6 +4200,7 @@ impl Ram for RamClient {
         let request_uri = "/enablesharingwithawsorganization";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("EnableSharingWithAwsOrganization");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3445,6 +4230,7 @@ impl Ram for RamClient {
         let request_uri = "/getpermission";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("GetPermission");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3475,6 +4261,7 @@ impl Ram for RamClient {
         let request_uri = "/getresourcepolicies";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("GetResourcePolicies");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3506,6 +4293,7 @@ impl Ram for RamClient {
         let request_uri = "/getresourceshareassociations";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("GetResourceShareAssociations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3537,6 +4325,7 @@ impl Ram for RamClient {
         let request_uri = "/getresourceshareinvitations";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("GetResourceShareInvitations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3567,6 +4356,7 @@ impl Ram for RamClient {
         let request_uri = "/getresourceshares";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("GetResourceShares");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3600,6 +4390,7 @@ impl Ram for RamClient {
         let request_uri = "/listpendinginvitationresources";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("ListPendingInvitationResources");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3630,6 +4421,7 @@ impl Ram for RamClient {
         let request_uri = "/listpermissions";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("ListPermissions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3660,6 +4452,7 @@ impl Ram for RamClient {
         let request_uri = "/listprincipals";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("ListPrincipals");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3691,6 +4484,7 @@ impl Ram for RamClient {
         let request_uri = "/listresourcesharepermissions";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("ListResourceSharePermissions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3721,6 +4515,7 @@ impl Ram for RamClient {
         let request_uri = "/listresources";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("ListResources");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3754,6 +4549,7 @@ impl Ram for RamClient {
         let request_uri = "/promoteresourcesharecreatedfrompolicy";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("PromoteResourceShareCreatedFromPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3790,6 +4586,7 @@ impl Ram for RamClient {
         let request_uri = "/rejectresourceshareinvitation";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("RejectResourceShareInvitation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3820,6 +4617,7 @@ impl Ram for RamClient {
         let request_uri = "/tagresource";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3850,6 +4648,7 @@ impl Ram for RamClient {
         let request_uri = "/untagresource";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3880,6 +4679,7 @@ impl Ram for RamClient {
         let request_uri = "/updateresourceshare";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("UpdateResourceShare");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3902,3 +4702,210 @@ impl Ram for RamClient {
         }
     }
 }
+impl RamClient {
+    /// Auto-paginating version of `get_resource_policies`, streaming every page of results.
+    pub fn get_resource_policies_pages(
+        &self,
+        input: GetResourcePoliciesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetResourcePoliciesResponse, GetResourcePoliciesError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RamClient, input: GetResourcePoliciesRequest| async move {
+                client.get_resource_policies(input).await
+            },
+            |input: &GetResourcePoliciesRequest, output: &GetResourcePoliciesResponse| {
+                Some(GetResourcePoliciesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_resource_policies`, streaming the `policies` of every page of results.
+    pub fn get_resource_policies_items(
+        &self,
+        input: GetResourcePoliciesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, GetResourcePoliciesError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_resource_policies_pages(input),
+            |output: GetResourcePoliciesResponse| output.policies.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_resource_share_associations`, streaming every page of results.
+    pub fn get_resource_share_associations_pages(
+        &self,
+        input: GetResourceShareAssociationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        GetResourceShareAssociationsResponse,
+        GetResourceShareAssociationsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RamClient, input: GetResourceShareAssociationsRequest| async move {
+                client.get_resource_share_associations(input).await
+            },
+            |input: &GetResourceShareAssociationsRequest,
+             output: &GetResourceShareAssociationsResponse| {
+                Some(GetResourceShareAssociationsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_resource_share_associations`, streaming the `resourceShareAssociations` of every page of results.
+    pub fn get_resource_share_associations_items(
+        &self,
+        input: GetResourceShareAssociationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ResourceShareAssociation,
+        GetResourceShareAssociationsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.get_resource_share_associations_pages(input),
+            |output: GetResourceShareAssociationsResponse| {
+                output.resource_share_associations.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_resource_share_invitations`, streaming every page of results.
+    pub fn get_resource_share_invitations_pages(
+        &self,
+        input: GetResourceShareInvitationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        GetResourceShareInvitationsResponse,
+        GetResourceShareInvitationsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RamClient, input: GetResourceShareInvitationsRequest| async move {
+                client.get_resource_share_invitations(input).await
+            },
+            |input: &GetResourceShareInvitationsRequest,
+             output: &GetResourceShareInvitationsResponse| {
+                Some(GetResourceShareInvitationsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_resource_share_invitations`, streaming the `resourceShareInvitations` of every page of results.
+    pub fn get_resource_share_invitations_items(
+        &self,
+        input: GetResourceShareInvitationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ResourceShareInvitation,
+        GetResourceShareInvitationsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.get_resource_share_invitations_pages(input),
+            |output: GetResourceShareInvitationsResponse| {
+                output.resource_share_invitations.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_resource_shares`, streaming every page of results.
+    pub fn get_resource_shares_pages(
+        &self,
+        input: GetResourceSharesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetResourceSharesResponse, GetResourceSharesError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RamClient, input: GetResourceSharesRequest| async move {
+                client.get_resource_shares(input).await
+            },
+            |input: &GetResourceSharesRequest, output: &GetResourceSharesResponse| {
+                Some(GetResourceSharesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_resource_shares`, streaming the `resourceShares` of every page of results.
+    pub fn get_resource_shares_items(
+        &self,
+        input: GetResourceSharesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ResourceShare, GetResourceSharesError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_resource_shares_pages(input),
+            |output: GetResourceSharesResponse| output.resource_shares.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_principals`, streaming every page of results.
+    pub fn list_principals_pages(
+        &self,
+        input: ListPrincipalsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListPrincipalsResponse, ListPrincipalsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RamClient, input: ListPrincipalsRequest| async move {
+                client.list_principals(input).await
+            },
+            |input: &ListPrincipalsRequest, output: &ListPrincipalsResponse| {
+                Some(ListPrincipalsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_principals`, streaming the `principals` of every page of results.
+    pub fn list_principals_items(
+        &self,
+        input: ListPrincipalsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Principal, ListPrincipalsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_principals_pages(input),
+            |output: ListPrincipalsResponse| output.principals.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_resources`, streaming every page of results.
+    pub fn list_resources_pages(
+        &self,
+        input: ListResourcesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListResourcesResponse, ListResourcesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RamClient, input: ListResourcesRequest| async move {
+                client.list_resources(input).await
+            },
+            |input: &ListResourcesRequest, output: &ListResourcesResponse| {
+                Some(ListResourcesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_resources`, streaming the `resources` of every page of results.
+    pub fn list_resources_items(
+        &self,
+        input: ListResourcesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Resource, ListResourcesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_resources_pages(input),
+            |output: ListResourcesResponse| output.resources.unwrap_or_default(),
+        )
+    }
+}
@@ -207,6 +207,99 @@ pub struct CommitTransactionResponse {
     pub transaction_status: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DecimalReturnType {
+    /// `STRING`
+    String,
+    /// `DOUBLE_OR_LONG`
+    DoubleOrLong,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DecimalReturnType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DecimalReturnType::String => "STRING",
+            DecimalReturnType::DoubleOrLong => "DOUBLE_OR_LONG",
+            DecimalReturnType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DecimalReturnType {
+    fn default() -> DecimalReturnType {
+        DecimalReturnType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DecimalReturnType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DecimalReturnType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DecimalReturnType {
+    fn from(value: &'a str) -> DecimalReturnType {
+        match value {
+            "STRING" => DecimalReturnType::String,
+            "DOUBLE_OR_LONG" => DecimalReturnType::DoubleOrLong,
+            _ => DecimalReturnType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DecimalReturnType {
+    fn from(value: String) -> DecimalReturnType {
+        match value.as_str() {
+            "STRING" => DecimalReturnType::String,
+            "DOUBLE_OR_LONG" => DecimalReturnType::DoubleOrLong,
+            _ => DecimalReturnType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DecimalReturnType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DecimalReturnType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DecimalReturnType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DecimalReturnType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DecimalReturnType {
+    fn deserialize<D>(deserializer: D) -> Result<DecimalReturnType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The request parameters represent the input of a request to run one or more SQL statements.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -388,7 +481,7 @@ pub struct ResultSetOptions {
     /// <p><p>A value that indicates how a field of <code>DECIMAL</code> type is represented in the response. The value of <code>STRING</code>, the default, specifies that it is converted to a String value. The value of <code>DOUBLE<em>OR</em>LONG</code> specifies that it is converted to a Long value if its scale is 0, or to a Double value otherwise.</p> <important> <p>Conversion to Double or Long can result in roundoff errors due to precision loss. We recommend converting to String, especially when working with currency values.</p> </important></p>
     #[serde(rename = "decimalReturnType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub decimal_return_type: Option<String>,
+    pub decimal_return_type: Option<DecimalReturnType>,
 }
 
 /// <p>The request parameters represent the input of a request to perform a rollback of a transaction.</p>
@@ -427,7 +520,7 @@ pub struct SqlParameter {
     /// <p><p>A hint that specifies the correct object type for data type mapping.</p> <p> <b>Values:</b> </p> <ul> <li> <p> <code>DECIMAL</code> - The corresponding <code>String</code> parameter value is sent as an object of <code>DECIMAL</code> type to the database.</p> </li> <li> <p> <code>TIMESTAMP</code> - The corresponding <code>String</code> parameter value is sent as an object of <code>TIMESTAMP</code> type to the database. The accepted format is <code>YYYY-MM-DD HH:MM:SS[.FFF]</code>.</p> </li> <li> <p> <code>TIME</code> - The corresponding <code>String</code> parameter value is sent as an object of <code>TIME</code> type to the database. The accepted format is <code>HH:MM:SS[.FFF]</code>.</p> </li> <li> <p> <code>DATE</code> - The corresponding <code>String</code> parameter value is sent as an object of <code>DATE</code> type to the database. The accepted format is <code>YYYY-MM-DD</code>.</p> </li> </ul></p>
     #[serde(rename = "typeHint")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_hint: Option<String>,
+    pub type_hint: Option<TypeHint>,
     /// <p>The value of the parameter.</p>
     #[serde(rename = "value")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -458,6 +551,119 @@ pub struct StructValue {
     pub attributes: Option<Vec<Value>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TypeHint {
+    /// `JSON`
+    Json,
+    /// `UUID`
+    Uuid,
+    /// `TIMESTAMP`
+    Timestamp,
+    /// `DATE`
+    Date,
+    /// `TIME`
+    Time,
+    /// `DECIMAL`
+    Decimal,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TypeHint {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TypeHint::Json => "JSON",
+            TypeHint::Uuid => "UUID",
+            TypeHint::Timestamp => "TIMESTAMP",
+            TypeHint::Date => "DATE",
+            TypeHint::Time => "TIME",
+            TypeHint::Decimal => "DECIMAL",
+            TypeHint::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TypeHint {
+    fn default() -> TypeHint {
+        TypeHint::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TypeHint {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TypeHint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TypeHint {
+    fn from(value: &'a str) -> TypeHint {
+        match value {
+            "JSON" => TypeHint::Json,
+            "UUID" => TypeHint::Uuid,
+            "TIMESTAMP" => TypeHint::Timestamp,
+            "DATE" => TypeHint::Date,
+            "TIME" => TypeHint::Time,
+            "DECIMAL" => TypeHint::Decimal,
+            _ => TypeHint::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TypeHint {
+    fn from(value: String) -> TypeHint {
+        match value.as_str() {
+            "JSON" => TypeHint::Json,
+            "UUID" => TypeHint::Uuid,
+            "TIMESTAMP" => TypeHint::Timestamp,
+            "DATE" => TypeHint::Date,
+            "TIME" => TypeHint::Time,
+            "DECIMAL" => TypeHint::Decimal,
+            _ => TypeHint::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TypeHint {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TypeHint, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TypeHint {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TypeHint {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TypeHint {
+    fn deserialize<D>(deserializer: D) -> Result<TypeHint, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The response elements represent the results of an update.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -924,11 +1130,12 @@ pub struct RdsDataClient {
 impl RdsDataClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> RdsDataClient {
         RdsDataClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "RDS Data"),
         }
     }
 
@@ -962,6 +1169,7 @@ impl RdsData for RdsDataClient {
         let request_uri = "/BatchExecute";
 
         let mut request = SignedRequest::new("POST", "rds-data", &self.region, &request_uri);
+        request.set_operation("BatchExecuteStatement");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -992,6 +1200,7 @@ impl RdsData for RdsDataClient {
         let request_uri = "/BeginTransaction";
 
         let mut request = SignedRequest::new("POST", "rds-data", &self.region, &request_uri);
+        request.set_operation("BeginTransaction");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1022,6 +1231,7 @@ impl RdsData for RdsDataClient {
         let request_uri = "/CommitTransaction";
 
         let mut request = SignedRequest::new("POST", "rds-data", &self.region, &request_uri);
+        request.set_operation("CommitTransaction");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1052,6 +1262,7 @@ impl RdsData for RdsDataClient {
         let request_uri = "/ExecuteSql";
 
         let mut request = SignedRequest::new("POST", "rds-data", &self.region, &request_uri);
+        request.set_operation("ExecuteSql");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1082,6 +1293,7 @@ impl RdsData for RdsDataClient {
         let request_uri = "/Execute";
 
         let mut request = SignedRequest::new("POST", "rds-data", &self.region, &request_uri);
+        request.set_operation("ExecuteStatement");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1112,6 +1324,7 @@ impl RdsData for RdsDataClient {
         let request_uri = "/RollbackTransaction";
 
         let mut request = SignedRequest::new("POST", "rds-data", &self.region, &request_uri);
+        request.set_operation("RollbackTransaction");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
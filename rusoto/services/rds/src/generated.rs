@@ -121,23 +121,229 @@ impl AccountQuotaListDeserializer {
         })
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ActivityStreamMode {
+    /// `sync`
+    Sync,
+    /// `async`
+    Async,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ActivityStreamMode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ActivityStreamMode::Sync => "sync",
+            ActivityStreamMode::Async => "async",
+            ActivityStreamMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ActivityStreamMode {
+    fn default() -> ActivityStreamMode {
+        ActivityStreamMode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ActivityStreamMode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ActivityStreamMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ActivityStreamMode {
+    fn from(value: &'a str) -> ActivityStreamMode {
+        match value {
+            "sync" => ActivityStreamMode::Sync,
+            "async" => ActivityStreamMode::Async,
+            _ => ActivityStreamMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ActivityStreamMode {
+    fn from(value: String) -> ActivityStreamMode {
+        match value.as_str() {
+            "sync" => ActivityStreamMode::Sync,
+            "async" => ActivityStreamMode::Async,
+            _ => ActivityStreamMode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ActivityStreamMode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ActivityStreamMode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ActivityStreamMode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ActivityStreamMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ActivityStreamMode {
+    fn deserialize<D>(deserializer: D) -> Result<ActivityStreamMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct ActivityStreamModeDeserializer;
 impl ActivityStreamModeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ActivityStreamMode, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ActivityStreamStatus {
+    /// `stopped`
+    Stopped,
+    /// `starting`
+    Starting,
+    /// `started`
+    Started,
+    /// `stopping`
+    Stopping,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ActivityStreamStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ActivityStreamStatus::Stopped => "stopped",
+            ActivityStreamStatus::Starting => "starting",
+            ActivityStreamStatus::Started => "started",
+            ActivityStreamStatus::Stopping => "stopping",
+            ActivityStreamStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ActivityStreamStatus {
+    fn default() -> ActivityStreamStatus {
+        ActivityStreamStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ActivityStreamStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ActivityStreamStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ActivityStreamStatus {
+    fn from(value: &'a str) -> ActivityStreamStatus {
+        match value {
+            "stopped" => ActivityStreamStatus::Stopped,
+            "starting" => ActivityStreamStatus::Starting,
+            "started" => ActivityStreamStatus::Started,
+            "stopping" => ActivityStreamStatus::Stopping,
+            _ => ActivityStreamStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ActivityStreamStatus {
+    fn from(value: String) -> ActivityStreamStatus {
+        match value.as_str() {
+            "stopped" => ActivityStreamStatus::Stopped,
+            "starting" => ActivityStreamStatus::Starting,
+            "started" => ActivityStreamStatus::Started,
+            "stopping" => ActivityStreamStatus::Stopping,
+            _ => ActivityStreamStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ActivityStreamStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ActivityStreamStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ActivityStreamStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ActivityStreamStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ActivityStreamStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ActivityStreamStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct ActivityStreamStatusDeserializer;
 impl ActivityStreamStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ActivityStreamStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -288,12 +494,110 @@ impl AddTagsToResourceMessageSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ApplyMethod {
+    /// `immediate`
+    Immediate,
+    /// `pending-reboot`
+    PendingReboot,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ApplyMethod {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ApplyMethod::Immediate => "immediate",
+            ApplyMethod::PendingReboot => "pending-reboot",
+            ApplyMethod::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ApplyMethod {
+    fn default() -> ApplyMethod {
+        ApplyMethod::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ApplyMethod {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ApplyMethod {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ApplyMethod {
+    fn from(value: &'a str) -> ApplyMethod {
+        match value {
+            "immediate" => ApplyMethod::Immediate,
+            "pending-reboot" => ApplyMethod::PendingReboot,
+            _ => ApplyMethod::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ApplyMethod {
+    fn from(value: String) -> ApplyMethod {
+        match value.as_str() {
+            "immediate" => ApplyMethod::Immediate,
+            "pending-reboot" => ApplyMethod::PendingReboot,
+            _ => ApplyMethod::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ApplyMethod {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ApplyMethod, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ApplyMethod {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ApplyMethod {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ApplyMethod {
+    fn deserialize<D>(deserializer: D) -> Result<ApplyMethod, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct ApplyMethodDeserializer;
 impl ApplyMethodDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ApplyMethod, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -390,12 +694,105 @@ impl AttributeValueListSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AuthScheme {
+    /// `SECRETS`
+    Secrets,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AuthScheme {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AuthScheme::Secrets => "SECRETS",
+            AuthScheme::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AuthScheme {
+    fn default() -> AuthScheme {
+        AuthScheme::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AuthScheme {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AuthScheme {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AuthScheme {
+    fn from(value: &'a str) -> AuthScheme {
+        match value {
+            "SECRETS" => AuthScheme::Secrets,
+            _ => AuthScheme::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AuthScheme {
+    fn from(value: String) -> AuthScheme {
+        match value.as_str() {
+            "SECRETS" => AuthScheme::Secrets,
+            _ => AuthScheme::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AuthScheme {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AuthScheme, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AuthScheme {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for AuthScheme {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for AuthScheme {
+    fn deserialize<D>(deserializer: D) -> Result<AuthScheme, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct AuthSchemeDeserializer;
 impl AuthSchemeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<AuthScheme, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -624,7 +1021,7 @@ impl AvailableProcessorFeatureListDeserializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct BacktrackDBClusterMessage {
     /// <p>The timestamp of the time to backtrack the DB cluster to, specified in ISO 8601 format. For more information about ISO 8601, see the <a href="http://en.wikipedia.org/wiki/ISO_8601">ISO8601 Wikipedia page.</a> </p> <note> <p>If the specified time isn't a consistent time for the DB cluster, Aurora automatically chooses the nearest possible consistent time for the DB cluster.</p> </note> <p>Constraints:</p> <ul> <li> <p>Must contain a valid ISO 8601 timestamp.</p> </li> <li> <p>Can't contain a timestamp set in the future.</p> </li> </ul> <p>Example: <code>2017-07-08T18:00Z</code> </p>
-    pub backtrack_to: String,
+    pub backtrack_to: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The DB cluster identifier of the DB cluster to be backtracked. This parameter is stored as a lowercase string.</p> <p>Constraints:</p> <ul> <li> <p>Must contain from 1 to 63 alphanumeric characters or hyphens.</p> </li> <li> <p>First character must be a letter.</p> </li> <li> <p>Can't end with a hyphen or contain two consecutive hyphens.</p> </li> </ul> <p>Example: <code>my-cluster1</code> </p>
     pub db_cluster_identifier: String,
     /// <p>A value that indicates whether to force the DB cluster to backtrack when binary logging is enabled. Otherwise, an error occurs when binary logging is enabled.</p>
@@ -642,7 +1039,10 @@ impl BacktrackDBClusterMessageSerializer {
             prefix.push_str(".");
         }
 
-        params.put(&format!("{}{}", prefix, "BacktrackTo"), &obj.backtrack_to);
+        params.put(
+            &format!("{}{}", prefix, "BacktrackTo"),
+            &rusoto_core::serialization::TimestampFormat::Iso8601.format(&obj.backtrack_to),
+        );
         params.put(
             &format!("{}{}", prefix, "DBClusterIdentifier"),
             &obj.db_cluster_identifier,
@@ -717,13 +1117,13 @@ pub struct Certificate {
     /// <p>Whether there is an override for the default certificate identifier.</p>
     pub customer_override: Option<bool>,
     /// <p>If there is an override for the default certificate identifier, when the override expires.</p>
-    pub customer_override_valid_till: Option<String>,
+    pub customer_override_valid_till: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The thumbprint of the certificate.</p>
     pub thumbprint: Option<String>,
     /// <p>The starting date from which the certificate is valid.</p>
-    pub valid_from: Option<String>,
+    pub valid_from: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The final date that the certificate continues to be valid.</p>
-    pub valid_till: Option<String>,
+    pub valid_till: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 struct CertificateDeserializer;
@@ -2507,7 +2907,7 @@ pub struct CreateDBProxyRequest {
     /// <p>Whether the proxy includes detailed information about SQL statements in its logs. This information helps you to debug issues involving SQL behavior or the performance and scalability of the proxy connections. The debug information includes the text of SQL statements that you submit through the proxy. Thus, only enable this setting when needed for debugging, and only when you have security measures in place to safeguard any sensitive information that appears in the logs.</p>
     pub debug_logging: Option<bool>,
     /// <p>The kinds of databases that the proxy can connect to. This value determines which database network protocol the proxy recognizes when it interprets network traffic to and from the database. Currently, this value is always <code>MYSQL</code>. The engine family applies to both RDS MySQL and Aurora MySQL.</p>
-    pub engine_family: String,
+    pub engine_family: EngineFamily,
     /// <p>The number of seconds that a connection to the proxy can be inactive before the proxy disconnects it. You can set this value higher or lower than the connection timeout limit for the associated database.</p>
     pub idle_client_timeout: Option<i64>,
     /// <p>A Boolean parameter that specifies whether Transport Layer Security (TLS) encryption is required for connections to the proxy. By enabling this setting, you can enforce encrypted TLS connections to the proxy.</p>
@@ -3160,9 +3560,9 @@ pub struct DBCluster {
     /// <p>The AWS KMS key identifier used for encrypting messages in the database activity stream.</p>
     pub activity_stream_kms_key_id: Option<String>,
     /// <p>The mode of the database activity stream. Database events such as a change or access generate an activity stream event. The database session can handle these events either synchronously or asynchronously. </p>
-    pub activity_stream_mode: Option<String>,
+    pub activity_stream_mode: Option<ActivityStreamMode>,
     /// <p>The status of the database activity stream.</p>
-    pub activity_stream_status: Option<String>,
+    pub activity_stream_status: Option<ActivityStreamStatus>,
     /// <p>For all database engines except Amazon Aurora, <code>AllocatedStorage</code> specifies the allocated storage size in gibibytes (GiB). For Aurora, <code>AllocatedStorage</code> always returns 1, because Aurora DB cluster storage size isn't fixed, but instead automatically adjusts as needed.</p>
     pub allocated_storage: Option<i64>,
     /// <p>Provides a list of the AWS Identity and Access Management (IAM) roles that are associated with the DB cluster. IAM roles that are associated with a DB cluster grant permission for the DB cluster to access other AWS services on your behalf.</p>
@@ -3182,7 +3582,7 @@ pub struct DBCluster {
     /// <p>Identifies the clone group to which the DB cluster is associated.</p>
     pub clone_group_id: Option<String>,
     /// <p>Specifies the time when the DB cluster was created, in Universal Coordinated Time (UTC).</p>
-    pub cluster_create_time: Option<String>,
+    pub cluster_create_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Specifies whether tags are copied from the DB cluster to snapshots of the DB cluster.</p>
     pub copy_tags_to_snapshot: Option<bool>,
     /// <p>Specifies whether the DB cluster is a clone of a DB cluster owned by a different AWS account.</p>
@@ -3208,9 +3608,9 @@ pub struct DBCluster {
     /// <p>Indicates if the DB cluster has deletion protection enabled. The database can't be deleted when deletion protection is enabled. </p>
     pub deletion_protection: Option<bool>,
     /// <p>The earliest time to which a DB cluster can be backtracked.</p>
-    pub earliest_backtrack_time: Option<String>,
+    pub earliest_backtrack_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The earliest time to which a database can be restored with point-in-time restore.</p>
-    pub earliest_restorable_time: Option<String>,
+    pub earliest_restorable_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A list of log types that this DB cluster is configured to export to CloudWatch Logs.</p> <p>Log types vary by DB engine. For information about the log types for each DB engine, see <a href="https://docs.aws.amazon.com/AmazonRDS/latest/AuroraUserGuide/USER_LogAccess.html">Amazon RDS Database Log Files</a> in the <i>Amazon Aurora User Guide.</i> </p>
     pub enabled_cloudwatch_logs_exports: Option<Vec<String>>,
     /// <p>Specifies the connection endpoint for the primary instance of the DB cluster.</p>
@@ -3230,7 +3630,7 @@ pub struct DBCluster {
     /// <p>If <code>StorageEncrypted</code> is enabled, the AWS KMS key identifier for the encrypted DB cluster.</p>
     pub kms_key_id: Option<String>,
     /// <p>Specifies the latest time to which a database can be restored with point-in-time restore.</p>
-    pub latest_restorable_time: Option<String>,
+    pub latest_restorable_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Contains the master username for the DB cluster.</p>
     pub master_username: Option<String>,
     /// <p>Specifies whether the DB cluster has instances in multiple Availability Zones.</p>
@@ -3547,11 +3947,11 @@ pub struct DBClusterBacktrack {
     /// <p>Contains the backtrack identifier.</p>
     pub backtrack_identifier: Option<String>,
     /// <p>The timestamp of the time at which the backtrack was requested.</p>
-    pub backtrack_request_creation_time: Option<String>,
+    pub backtrack_request_creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The timestamp of the time to which the DB cluster was backtracked.</p>
-    pub backtrack_to: Option<String>,
+    pub backtrack_to: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The timestamp of the time from which the DB cluster was backtracked.</p>
-    pub backtracked_from: Option<String>,
+    pub backtracked_from: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Contains a user-supplied DB cluster identifier. This identifier is the unique key that identifies a DB cluster.</p>
     pub db_cluster_identifier: Option<String>,
     /// <p><p>The status of the backtrack. This property returns one of the following values:</p> <ul> <li> <p> <code>applying</code> - The backtrack is currently being applied to or rolled back from the DB cluster.</p> </li> <li> <p> <code>completed</code> - The backtrack has successfully been applied to or rolled back from the DB cluster.</p> </li> <li> <p> <code>failed</code> - An error occurred while the backtrack was applied to or rolled back from the DB cluster.</p> </li> <li> <p> <code>pending</code> - The backtrack is currently pending application to or rollback from the DB cluster.</p> </li> </ul></p>
@@ -4297,7 +4697,7 @@ pub struct DBClusterSnapshot {
     /// <p>Provides the list of Availability Zones (AZs) where instances in the DB cluster snapshot can be restored.</p>
     pub availability_zones: Option<Vec<String>>,
     /// <p>Specifies the time when the DB cluster was created, in Universal Coordinated Time (UTC).</p>
-    pub cluster_create_time: Option<String>,
+    pub cluster_create_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Specifies the DB cluster identifier of the DB cluster that this DB cluster snapshot was created from.</p>
     pub db_cluster_identifier: Option<String>,
     /// <p>The Amazon Resource Name (ARN) for the DB cluster snapshot.</p>
@@ -4321,7 +4721,7 @@ pub struct DBClusterSnapshot {
     /// <p>Specifies the port that the DB cluster was listening on at the time of the snapshot.</p>
     pub port: Option<i64>,
     /// <p>Provides the time when the snapshot was taken, in Universal Coordinated Time (UTC).</p>
-    pub snapshot_create_time: Option<String>,
+    pub snapshot_create_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Provides the type of the DB cluster snapshot.</p>
     pub snapshot_type: Option<String>,
     /// <p>If the DB cluster snapshot was copied from a source DB cluster snapshot, the Amazon Resource Name (ARN) for the source DB cluster snapshot, otherwise, a null value.</p>
@@ -4848,13 +5248,13 @@ pub struct DBInstance {
     /// <p><p>True if mapping of AWS Identity and Access Management (IAM) accounts to database accounts is enabled, and otherwise false.</p> <p>IAM database authentication can be enabled for the following database engines</p> <ul> <li> <p>For MySQL 5.6, minor version 5.6.34 or higher</p> </li> <li> <p>For MySQL 5.7, minor version 5.7.16 or higher</p> </li> <li> <p>Aurora 5.6 or higher. To enable IAM database authentication for Aurora, see DBCluster Type.</p> </li> </ul></p>
     pub iam_database_authentication_enabled: Option<bool>,
     /// <p>Provides the date and time the DB instance was created.</p>
-    pub instance_create_time: Option<String>,
+    pub instance_create_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Specifies the Provisioned IOPS (I/O operations per second) value.</p>
     pub iops: Option<i64>,
     /// <p> If <code>StorageEncrypted</code> is true, the AWS KMS key identifier for the encrypted DB instance. </p>
     pub kms_key_id: Option<String>,
     /// <p>Specifies the latest time to which a database can be restored with point-in-time restore.</p>
-    pub latest_restorable_time: Option<String>,
+    pub latest_restorable_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>License model information for this DB instance.</p>
     pub license_model: Option<String>,
     /// <p>Specifies the listener connection endpoint for SQL Server Always On.</p>
@@ -5262,7 +5662,7 @@ pub struct DBInstanceAutomatedBackup {
     /// <p>True if mapping of AWS Identity and Access Management (IAM) accounts to database accounts is enabled, and otherwise false.</p>
     pub iam_database_authentication_enabled: Option<bool>,
     /// <p>Provides the date and time that the DB instance was created. </p>
-    pub instance_create_time: Option<String>,
+    pub instance_create_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The IOPS (I/O operations per second) value for the automated backup. </p>
     pub iops: Option<i64>,
     /// <p>The AWS KMS key ID for an automated backup. The KMS key ID is the Amazon Resource Name (ARN), KMS key identifier, or the KMS key alias for the KMS encryption key. </p>
@@ -5873,7 +6273,7 @@ pub struct DBProxy {
     /// <p>One or more data structures specifying the authorization mechanism to connect to the associated RDS DB instance or Aurora DB cluster.</p>
     pub auth: Option<Vec<UserAuthConfigInfo>>,
     /// <p>The date and time when the proxy was first created.</p>
-    pub created_date: Option<String>,
+    pub created_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The Amazon Resource Name (ARN) for the proxy.</p>
     pub db_proxy_arn: Option<String>,
     /// <p>The identifier for the proxy. This name must be unique for all proxies owned by your AWS account in the specified AWS Region.</p>
@@ -5891,9 +6291,9 @@ pub struct DBProxy {
     /// <p>The Amazon Resource Name (ARN) for the IAM role that the proxy uses to access Amazon Secrets Manager.</p>
     pub role_arn: Option<String>,
     /// <p>The current status of this proxy. A status of <code>available</code> means the proxy is ready to handle requests. Other values indicate that you must wait for the proxy to be ready, or take some action to resolve an issue.</p>
-    pub status: Option<String>,
+    pub status: Option<DBProxyStatus>,
     /// <p>The date and time when the proxy was last updated.</p>
-    pub updated_date: Option<String>,
+    pub updated_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Provides a list of VPC security groups that the proxy belongs to.</p>
     pub vpc_security_group_ids: Option<Vec<String>>,
     /// <p>The EC2 subnet IDs for the proxy.</p>
@@ -5986,12 +6386,145 @@ impl DBProxyListDeserializer {
         })
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DBProxyStatus {
+    /// `available`
+    Available,
+    /// `modifying`
+    Modifying,
+    /// `incompatible-network`
+    IncompatibleNetwork,
+    /// `insufficient-resource-limits`
+    InsufficientResourceLimits,
+    /// `creating`
+    Creating,
+    /// `deleting`
+    Deleting,
+    /// `suspended`
+    Suspended,
+    /// `suspending`
+    Suspending,
+    /// `reactivating`
+    Reactivating,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DBProxyStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DBProxyStatus::Available => "available",
+            DBProxyStatus::Modifying => "modifying",
+            DBProxyStatus::IncompatibleNetwork => "incompatible-network",
+            DBProxyStatus::InsufficientResourceLimits => "insufficient-resource-limits",
+            DBProxyStatus::Creating => "creating",
+            DBProxyStatus::Deleting => "deleting",
+            DBProxyStatus::Suspended => "suspended",
+            DBProxyStatus::Suspending => "suspending",
+            DBProxyStatus::Reactivating => "reactivating",
+            DBProxyStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DBProxyStatus {
+    fn default() -> DBProxyStatus {
+        DBProxyStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DBProxyStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DBProxyStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DBProxyStatus {
+    fn from(value: &'a str) -> DBProxyStatus {
+        match value {
+            "available" => DBProxyStatus::Available,
+            "modifying" => DBProxyStatus::Modifying,
+            "incompatible-network" => DBProxyStatus::IncompatibleNetwork,
+            "insufficient-resource-limits" => DBProxyStatus::InsufficientResourceLimits,
+            "creating" => DBProxyStatus::Creating,
+            "deleting" => DBProxyStatus::Deleting,
+            "suspended" => DBProxyStatus::Suspended,
+            "suspending" => DBProxyStatus::Suspending,
+            "reactivating" => DBProxyStatus::Reactivating,
+            _ => DBProxyStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DBProxyStatus {
+    fn from(value: String) -> DBProxyStatus {
+        match value.as_str() {
+            "available" => DBProxyStatus::Available,
+            "modifying" => DBProxyStatus::Modifying,
+            "incompatible-network" => DBProxyStatus::IncompatibleNetwork,
+            "insufficient-resource-limits" => DBProxyStatus::InsufficientResourceLimits,
+            "creating" => DBProxyStatus::Creating,
+            "deleting" => DBProxyStatus::Deleting,
+            "suspended" => DBProxyStatus::Suspended,
+            "suspending" => DBProxyStatus::Suspending,
+            "reactivating" => DBProxyStatus::Reactivating,
+            _ => DBProxyStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DBProxyStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DBProxyStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DBProxyStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for DBProxyStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for DBProxyStatus {
+    fn deserialize<D>(deserializer: D) -> Result<DBProxyStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct DBProxyStatusDeserializer;
 impl DBProxyStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<DBProxyStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -6012,7 +6545,7 @@ pub struct DBProxyTarget {
     /// <p>The DB cluster identifier when the target represents an Aurora DB cluster. This field is blank when the target represents an RDS DB instance.</p>
     pub tracked_cluster_id: Option<String>,
     /// <p>Specifies the kind of database, such as an RDS DB instance or an Aurora DB cluster, that the target represents.</p>
-    pub type_: Option<String>,
+    pub type_: Option<TargetType>,
 }
 
 struct DBProxyTargetDeserializer;
@@ -6057,7 +6590,7 @@ pub struct DBProxyTargetGroup {
     /// <p>The settings that determine the size and behavior of the connection pool for the target group.</p>
     pub connection_pool_config: Option<ConnectionPoolConfigurationInfo>,
     /// <p>The date and time when the target group was first created.</p>
-    pub created_date: Option<String>,
+    pub created_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The identifier for the RDS proxy associated with this target group.</p>
     pub db_proxy_name: Option<String>,
     /// <p>Whether this target group is the first one used for connection requests by the associated proxy. Because each proxy is currently associated with a single target group, currently this setting is always <code>true</code>.</p>
@@ -6069,7 +6602,7 @@ pub struct DBProxyTargetGroup {
     /// <p>The identifier for the target group. This name must be unique for all target groups owned by your AWS account in the specified AWS Region.</p>
     pub target_group_name: Option<String>,
     /// <p>The date and time when the target group was last updated.</p>
-    pub updated_date: Option<String>,
+    pub updated_date: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 struct DBProxyTargetGroupDeserializer;
@@ -6335,7 +6868,7 @@ pub struct DBSnapshot {
     /// <p>True if mapping of AWS Identity and Access Management (IAM) accounts to database accounts is enabled, and otherwise false.</p>
     pub iam_database_authentication_enabled: Option<bool>,
     /// <p>Specifies the time when the snapshot was taken, in Universal Coordinated Time (UTC).</p>
-    pub instance_create_time: Option<String>,
+    pub instance_create_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Specifies the Provisioned IOPS (I/O operations per second) value of the DB instance at the time of the snapshot.</p>
     pub iops: Option<i64>,
     /// <p> If <code>Encrypted</code> is true, the AWS KMS key identifier for the encrypted DB snapshot. </p>
@@ -6353,7 +6886,7 @@ pub struct DBSnapshot {
     /// <p>The number of CPU cores and the number of threads per core for the DB instance class of the DB instance when the DB snapshot was created.</p>
     pub processor_features: Option<Vec<ProcessorFeature>>,
     /// <p>Provides the time when the snapshot was taken, in Universal Coordinated Time (UTC).</p>
-    pub snapshot_create_time: Option<String>,
+    pub snapshot_create_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Provides the type of the DB snapshot.</p>
     pub snapshot_type: Option<String>,
     /// <p>The DB snapshot Amazon Resource Name (ARN) that the DB snapshot was copied from. It only has value in case of cross-customer or cross-region copy.</p>
@@ -9084,7 +9617,7 @@ pub struct DescribeEventsMessage {
     /// <p>The number of minutes to retrieve events for.</p> <p>Default: 60</p>
     pub duration: Option<i64>,
     /// <p> The end of the time interval for which to retrieve events, specified in ISO 8601 format. For more information about ISO 8601, go to the <a href="http://en.wikipedia.org/wiki/ISO_8601">ISO8601 Wikipedia page.</a> </p> <p>Example: 2009-07-08T18:00Z</p>
-    pub end_time: Option<String>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A list of event categories that trigger notifications for a event notification subscription.</p>
     pub event_categories: Option<Vec<String>>,
     /// <p>This parameter isn't currently supported.</p>
@@ -9096,9 +9629,9 @@ pub struct DescribeEventsMessage {
     /// <p><p>The identifier of the event source for which events are returned. If not specified, then all sources are included in the response.</p> <p>Constraints:</p> <ul> <li> <p>If SourceIdentifier is supplied, SourceType must also be provided.</p> </li> <li> <p>If the source type is <code>DBInstance</code>, then a <code>DBInstanceIdentifier</code> must be supplied.</p> </li> <li> <p>If the source type is <code>DBSecurityGroup</code>, a <code>DBSecurityGroupName</code> must be supplied.</p> </li> <li> <p>If the source type is <code>DBParameterGroup</code>, a <code>DBParameterGroupName</code> must be supplied.</p> </li> <li> <p>If the source type is <code>DBSnapshot</code>, a <code>DBSnapshotIdentifier</code> must be supplied.</p> </li> <li> <p>Can&#39;t end with a hyphen or contain two consecutive hyphens.</p> </li> </ul></p>
     pub source_identifier: Option<String>,
     /// <p>The event source to retrieve events for. If no value is specified, all events are returned.</p>
-    pub source_type: Option<String>,
+    pub source_type: Option<SourceType>,
     /// <p> The beginning of the time interval to retrieve events for, specified in ISO 8601 format. For more information about ISO 8601, go to the <a href="http://en.wikipedia.org/wiki/ISO_8601">ISO8601 Wikipedia page.</a> </p> <p>Example: 2009-07-08T18:00Z</p>
-    pub start_time: Option<String>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// Serialize `DescribeEventsMessage` contents to a `SignedRequest`.
@@ -9114,7 +9647,10 @@ impl DescribeEventsMessageSerializer {
             params.put(&format!("{}{}", prefix, "Duration"), &field_value);
         }
         if let Some(ref field_value) = obj.end_time {
-            params.put(&format!("{}{}", prefix, "EndTime"), &field_value);
+            params.put(
+                &format!("{}{}", prefix, "EndTime"),
+                &rusoto_core::serialization::TimestampFormat::Iso8601.format(field_value),
+            );
         }
         if let Some(ref field_value) = obj.event_categories {
             EventCategoriesListSerializer::serialize(
@@ -9143,7 +9679,10 @@ impl DescribeEventsMessageSerializer {
             params.put(&format!("{}{}", prefix, "SourceType"), &field_value);
         }
         if let Some(ref field_value) = obj.start_time {
-            params.put(&format!("{}{}", prefix, "StartTime"), &field_value);
+            params.put(
+                &format!("{}{}", prefix, "StartTime"),
+                &rusoto_core::serialization::TimestampFormat::Iso8601.format(field_value),
+            );
         }
     }
 }
@@ -10105,6 +10644,106 @@ impl EngineDefaultsDeserializer {
         })
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EngineFamily {
+    /// `MYSQL`
+    Mysql,
+    /// `POSTGRESQL`
+    Postgresql,
+    /// `SQLSERVER`
+    Sqlserver,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EngineFamily {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EngineFamily::Mysql => "MYSQL",
+            EngineFamily::Postgresql => "POSTGRESQL",
+            EngineFamily::Sqlserver => "SQLSERVER",
+            EngineFamily::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EngineFamily {
+    fn default() -> EngineFamily {
+        EngineFamily::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EngineFamily {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EngineFamily {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EngineFamily {
+    fn from(value: &'a str) -> EngineFamily {
+        match value {
+            "MYSQL" => EngineFamily::Mysql,
+            "POSTGRESQL" => EngineFamily::Postgresql,
+            "SQLSERVER" => EngineFamily::Sqlserver,
+            _ => EngineFamily::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EngineFamily {
+    fn from(value: String) -> EngineFamily {
+        match value.as_str() {
+            "MYSQL" => EngineFamily::Mysql,
+            "POSTGRESQL" => EngineFamily::Postgresql,
+            "SQLSERVER" => EngineFamily::Sqlserver,
+            _ => EngineFamily::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EngineFamily {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EngineFamily, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EngineFamily {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for EngineFamily {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for EngineFamily {
+    fn deserialize<D>(deserializer: D) -> Result<EngineFamily, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct EngineModeListDeserializer;
 impl EngineModeListDeserializer {
     #[allow(unused_variables)]
@@ -10139,7 +10778,7 @@ impl EngineModeListSerializer {
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct Event {
     /// <p>Specifies the date and time of the event.</p>
-    pub date: Option<String>,
+    pub date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Specifies the category for the event.</p>
     pub event_categories: Option<Vec<String>>,
     /// <p>Provides the text of this event.</p>
@@ -10149,7 +10788,7 @@ pub struct Event {
     /// <p>Provides the identifier for the source of the event.</p>
     pub source_identifier: Option<String>,
     /// <p>Specifies the source type for this event.</p>
-    pub source_type: Option<String>,
+    pub source_type: Option<SourceType>,
 }
 
 struct EventDeserializer;
@@ -10516,15 +11155,15 @@ pub struct ExportTask {
     /// <p>The Amazon S3 bucket prefix that is the file name and path of the exported snapshot.</p>
     pub s3_prefix: Option<String>,
     /// <p>The time that the snapshot was created.</p>
-    pub snapshot_time: Option<String>,
+    pub snapshot_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The Amazon Resource Name (ARN) of the snapshot exported to Amazon S3.</p>
     pub source_arn: Option<String>,
     /// <p>The progress status of the export task.</p>
     pub status: Option<String>,
     /// <p>The time that the snapshot export task completed.</p>
-    pub task_end_time: Option<String>,
+    pub task_end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The time that the snapshot export task started.</p>
-    pub task_start_time: Option<String>,
+    pub task_start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The total amount of data exported, in gigabytes.</p>
     pub total_extracted_data_in_gb: Option<i64>,
     /// <p>A warning about the snapshot export task.</p>
@@ -10984,12 +11623,115 @@ impl GlobalClustersMessageDeserializer {
         })
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum IAMAuthMode {
+    /// `DISABLED`
+    Disabled,
+    /// `REQUIRED`
+    Required,
+    /// `ENABLED`
+    Enabled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl IAMAuthMode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            IAMAuthMode::Disabled => "DISABLED",
+            IAMAuthMode::Required => "REQUIRED",
+            IAMAuthMode::Enabled => "ENABLED",
+            IAMAuthMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for IAMAuthMode {
+    fn default() -> IAMAuthMode {
+        IAMAuthMode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for IAMAuthMode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for IAMAuthMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for IAMAuthMode {
+    fn from(value: &'a str) -> IAMAuthMode {
+        match value {
+            "DISABLED" => IAMAuthMode::Disabled,
+            "REQUIRED" => IAMAuthMode::Required,
+            "ENABLED" => IAMAuthMode::Enabled,
+            _ => IAMAuthMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for IAMAuthMode {
+    fn from(value: String) -> IAMAuthMode {
+        match value.as_str() {
+            "DISABLED" => IAMAuthMode::Disabled,
+            "REQUIRED" => IAMAuthMode::Required,
+            "ENABLED" => IAMAuthMode::Enabled,
+            _ => IAMAuthMode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for IAMAuthMode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<IAMAuthMode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for IAMAuthMode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for IAMAuthMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for IAMAuthMode {
+    fn deserialize<D>(deserializer: D) -> Result<IAMAuthMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct IAMAuthModeDeserializer;
 impl IAMAuthModeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<IAMAuthMode, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -13976,7 +14718,7 @@ pub struct Parameter {
     /// <p>Specifies the valid range of values for the parameter.</p>
     pub allowed_values: Option<String>,
     /// <p>Indicates when to apply parameter updates.</p>
-    pub apply_method: Option<String>,
+    pub apply_method: Option<ApplyMethod>,
     /// <p>Specifies the engine specific parameters type.</p>
     pub apply_type: Option<String>,
     /// <p>Specifies the valid data type for the parameter.</p>
@@ -14183,13 +14925,13 @@ pub struct PendingMaintenanceAction {
     /// <p>The type of pending maintenance action that is available for the resource. Valid actions are <code>system-update</code>, <code>db-upgrade</code>, <code>hardware-maintenance</code>, and <code>ca-certificate-rotation</code>.</p>
     pub action: Option<String>,
     /// <p>The date of the maintenance window when the action is applied. The maintenance action is applied to the resource during its first maintenance window after this date.</p>
-    pub auto_applied_after_date: Option<String>,
+    pub auto_applied_after_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The effective date when the pending maintenance action is applied to the resource. This date takes into account opt-in requests received from the <code>ApplyPendingMaintenanceAction</code> API, the <code>AutoAppliedAfterDate</code>, and the <code>ForcedApplyDate</code>. This value is blank if an opt-in request has not been received and nothing has been specified as <code>AutoAppliedAfterDate</code> or <code>ForcedApplyDate</code>.</p>
-    pub current_apply_date: Option<String>,
+    pub current_apply_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A description providing more detail about the maintenance action.</p>
     pub description: Option<String>,
     /// <p>The date when the maintenance action is automatically applied. The maintenance action is applied to the resource on this date regardless of the maintenance window for the resource.</p>
-    pub forced_apply_date: Option<String>,
+    pub forced_apply_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Indicates the type of opt-in request that has been received for the resource.</p>
     pub opt_in_status: Option<String>,
 }
@@ -15277,7 +16019,7 @@ pub struct ReservedDBInstance {
     /// <p>The offering identifier.</p>
     pub reserved_db_instances_offering_id: Option<String>,
     /// <p>The time the reservation started.</p>
-    pub start_time: Option<String>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The state of the reserved DB instance.</p>
     pub state: Option<String>,
     /// <p>The hourly price charged for this reserved DB instance.</p>
@@ -16111,7 +16853,7 @@ pub struct RestoreDBClusterToPointInTimeMessage {
     /// <p>The port number on which the new DB cluster accepts connections.</p> <p>Constraints: A value from <code>1150-65535</code>. </p> <p>Default: The default port for the engine.</p>
     pub port: Option<i64>,
     /// <p>The date and time to restore the DB cluster to.</p> <p>Valid Values: Value must be a time in Universal Coordinated Time (UTC) format</p> <p>Constraints:</p> <ul> <li> <p>Must be before the latest restorable time for the DB instance</p> </li> <li> <p>Must be specified if <code>UseLatestRestorableTime</code> parameter isn't provided</p> </li> <li> <p>Can't be specified if the <code>UseLatestRestorableTime</code> parameter is enabled</p> </li> <li> <p>Can't be specified if the <code>RestoreType</code> parameter is <code>copy-on-write</code> </p> </li> </ul> <p>Example: <code>2015-03-07T23:45:00Z</code> </p>
-    pub restore_to_time: Option<String>,
+    pub restore_to_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The type of restore to be performed. You can specify one of the following values:</p> <ul> <li> <p> <code>full-copy</code> - The new DB cluster is restored as a full copy of the source DB cluster.</p> </li> <li> <p> <code>copy-on-write</code> - The new DB cluster is restored as a clone of the source DB cluster.</p> </li> </ul> <p>Constraints: You can't specify <code>copy-on-write</code> if the engine version of the source DB cluster is earlier than 1.11.</p> <p>If you don't specify a <code>RestoreType</code> value, then the new DB cluster is restored as a full copy of the source DB cluster.</p>
     pub restore_type: Option<String>,
     /// <p><p>The identifier of the source DB cluster from which to restore.</p> <p>Constraints:</p> <ul> <li> <p>Must match the identifier of an existing DBCluster.</p> </li> </ul></p>
@@ -16177,7 +16919,10 @@ impl RestoreDBClusterToPointInTimeMessageSerializer {
             params.put(&format!("{}{}", prefix, "Port"), &field_value);
         }
         if let Some(ref field_value) = obj.restore_to_time {
-            params.put(&format!("{}{}", prefix, "RestoreToTime"), &field_value);
+            params.put(
+                &format!("{}{}", prefix, "RestoreToTime"),
+                &rusoto_core::serialization::TimestampFormat::Iso8601.format(field_value),
+            );
         }
         if let Some(ref field_value) = obj.restore_type {
             params.put(&format!("{}{}", prefix, "RestoreType"), &field_value);
@@ -16802,7 +17547,7 @@ pub struct RestoreDBInstanceToPointInTimeMessage {
     /// <p>A value that indicates whether the DB instance is publicly accessible. When the DB instance is publicly accessible, it is an Internet-facing instance with a publicly resolvable DNS name, which resolves to a public IP address. When the DB instance isn't publicly accessible, it is an internal instance with a DNS name that resolves to a private IP address. For more information, see <a>CreateDBInstance</a>.</p>
     pub publicly_accessible: Option<bool>,
     /// <p>The date and time to restore from.</p> <p>Valid Values: Value must be a time in Universal Coordinated Time (UTC) format</p> <p>Constraints:</p> <ul> <li> <p>Must be before the latest restorable time for the DB instance</p> </li> <li> <p>Can't be specified if the <code>UseLatestRestorableTime</code> parameter is enabled</p> </li> </ul> <p>Example: <code>2009-09-07T23:45:00Z</code> </p>
-    pub restore_time: Option<String>,
+    pub restore_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>The identifier of the source DB instance from which to restore.</p> <p>Constraints:</p> <ul> <li> <p>Must match the identifier of an existing DB instance.</p> </li> </ul></p>
     pub source_db_instance_identifier: Option<String>,
     /// <p>The resource ID of the source DB instance from which to restore.</p>
@@ -16911,7 +17656,10 @@ impl RestoreDBInstanceToPointInTimeMessageSerializer {
             params.put(&format!("{}{}", prefix, "PubliclyAccessible"), &field_value);
         }
         if let Some(ref field_value) = obj.restore_time {
-            params.put(&format!("{}{}", prefix, "RestoreTime"), &field_value);
+            params.put(
+                &format!("{}{}", prefix, "RestoreTime"),
+                &rusoto_core::serialization::TimestampFormat::Iso8601.format(field_value),
+            );
         }
         if let Some(ref field_value) = obj.source_db_instance_identifier {
             params.put(
@@ -17000,9 +17748,9 @@ impl RestoreDBInstanceToPointInTimeResultDeserializer {
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct RestoreWindow {
     /// <p>The earliest time you can restore an instance to.</p>
-    pub earliest_time: Option<String>,
+    pub earliest_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The latest time you can restore an instance to.</p>
-    pub latest_time: Option<String>,
+    pub latest_time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 struct RestoreWindowDeserializer;
@@ -17336,12 +18084,145 @@ impl SourceRegionMessageDeserializer {
         })
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SourceType {
+    /// `db-instance`
+    DbInstance,
+    /// `db-parameter-group`
+    DbParameterGroup,
+    /// `db-security-group`
+    DbSecurityGroup,
+    /// `db-snapshot`
+    DbSnapshot,
+    /// `db-cluster`
+    DbCluster,
+    /// `db-cluster-snapshot`
+    DbClusterSnapshot,
+    /// `custom-engine-version`
+    CustomEngineVersion,
+    /// `db-proxy`
+    DbProxy,
+    /// `blue-green-deployment`
+    BlueGreenDeployment,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SourceType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SourceType::DbInstance => "db-instance",
+            SourceType::DbParameterGroup => "db-parameter-group",
+            SourceType::DbSecurityGroup => "db-security-group",
+            SourceType::DbSnapshot => "db-snapshot",
+            SourceType::DbCluster => "db-cluster",
+            SourceType::DbClusterSnapshot => "db-cluster-snapshot",
+            SourceType::CustomEngineVersion => "custom-engine-version",
+            SourceType::DbProxy => "db-proxy",
+            SourceType::BlueGreenDeployment => "blue-green-deployment",
+            SourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SourceType {
+    fn default() -> SourceType {
+        SourceType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SourceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SourceType {
+    fn from(value: &'a str) -> SourceType {
+        match value {
+            "db-instance" => SourceType::DbInstance,
+            "db-parameter-group" => SourceType::DbParameterGroup,
+            "db-security-group" => SourceType::DbSecurityGroup,
+            "db-snapshot" => SourceType::DbSnapshot,
+            "db-cluster" => SourceType::DbCluster,
+            "db-cluster-snapshot" => SourceType::DbClusterSnapshot,
+            "custom-engine-version" => SourceType::CustomEngineVersion,
+            "db-proxy" => SourceType::DbProxy,
+            "blue-green-deployment" => SourceType::BlueGreenDeployment,
+            _ => SourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SourceType {
+    fn from(value: String) -> SourceType {
+        match value.as_str() {
+            "db-instance" => SourceType::DbInstance,
+            "db-parameter-group" => SourceType::DbParameterGroup,
+            "db-security-group" => SourceType::DbSecurityGroup,
+            "db-snapshot" => SourceType::DbSnapshot,
+            "db-cluster" => SourceType::DbCluster,
+            "db-cluster-snapshot" => SourceType::DbClusterSnapshot,
+            "custom-engine-version" => SourceType::CustomEngineVersion,
+            "db-proxy" => SourceType::DbProxy,
+            "blue-green-deployment" => SourceType::BlueGreenDeployment,
+            _ => SourceType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SourceType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SourceType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SourceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for SourceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for SourceType {
+    fn deserialize<D>(deserializer: D) -> Result<SourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct SourceTypeDeserializer;
 impl SourceTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<SourceType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -17355,7 +18236,7 @@ pub struct StartActivityStreamRequest {
     /// <p>The AWS KMS key identifier for encrypting messages in the database activity stream. The key identifier can be either a key ID, a key ARN, or a key alias.</p>
     pub kms_key_id: String,
     /// <p>Specifies the mode of the database activity stream. Database events such as a change or access generate an activity stream event. The database session can handle these events either synchronously or asynchronously. </p>
-    pub mode: String,
+    pub mode: ActivityStreamMode,
     /// <p>The Amazon Resource Name (ARN) of the DB cluster, for example <code>arn:aws:rds:us-east-1:12345667890:cluster:das-cluster</code>.</p>
     pub resource_arn: String,
 }
@@ -17388,9 +18269,9 @@ pub struct StartActivityStreamResponse {
     /// <p>The AWS KMS key identifier for encryption of messages in the database activity stream.</p>
     pub kms_key_id: Option<String>,
     /// <p>The mode of the database activity stream.</p>
-    pub mode: Option<String>,
+    pub mode: Option<ActivityStreamMode>,
     /// <p>The status of the database activity stream.</p>
-    pub status: Option<String>,
+    pub status: Option<ActivityStreamStatus>,
 }
 
 struct StartActivityStreamResponseDeserializer;
@@ -17612,7 +18493,7 @@ pub struct StopActivityStreamResponse {
     /// <p>The AWS KMS key identifier used for encrypting messages in the database activity stream.</p>
     pub kms_key_id: Option<String>,
     /// <p>The status of the database activity stream.</p>
-    pub status: Option<String>,
+    pub status: Option<ActivityStreamStatus>,
 }
 
 struct StopActivityStreamResponseDeserializer;
@@ -17906,9 +18787,12 @@ impl SupportedTimezonesListDeserializer {
 struct TStampDeserializer;
 impl TStampDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<::chrono::DateTime<::chrono::Utc>, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::proto::xml::util::timestamp_characters(stack)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -18055,12 +18939,115 @@ impl TargetListDeserializer {
         })
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TargetType {
+    /// `RDS_INSTANCE`
+    RdsInstance,
+    /// `RDS_SERVERLESS_ENDPOINT`
+    RdsServerlessEndpoint,
+    /// `TRACKED_CLUSTER`
+    TrackedCluster,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TargetType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TargetType::RdsInstance => "RDS_INSTANCE",
+            TargetType::RdsServerlessEndpoint => "RDS_SERVERLESS_ENDPOINT",
+            TargetType::TrackedCluster => "TRACKED_CLUSTER",
+            TargetType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TargetType {
+    fn default() -> TargetType {
+        TargetType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TargetType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TargetType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TargetType {
+    fn from(value: &'a str) -> TargetType {
+        match value {
+            "RDS_INSTANCE" => TargetType::RdsInstance,
+            "RDS_SERVERLESS_ENDPOINT" => TargetType::RdsServerlessEndpoint,
+            "TRACKED_CLUSTER" => TargetType::TrackedCluster,
+            _ => TargetType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TargetType {
+    fn from(value: String) -> TargetType {
+        match value.as_str() {
+            "RDS_INSTANCE" => TargetType::RdsInstance,
+            "RDS_SERVERLESS_ENDPOINT" => TargetType::RdsServerlessEndpoint,
+            "TRACKED_CLUSTER" => TargetType::TrackedCluster,
+            _ => TargetType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TargetType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TargetType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TargetType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for TargetType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for TargetType {
+    fn deserialize<D>(deserializer: D) -> Result<TargetType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct TargetTypeDeserializer;
 impl TargetTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<TargetType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -18149,11 +19136,11 @@ impl UpgradeTargetDeserializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct UserAuthConfig {
     /// <p>The type of authentication that the proxy uses for connections from the proxy to the underlying database.</p>
-    pub auth_scheme: Option<String>,
+    pub auth_scheme: Option<AuthScheme>,
     /// <p>A user-specified description about the authentication used by a proxy to log in as a specific database user.</p>
     pub description: Option<String>,
     /// <p>Whether to require or disallow AWS Identity and Access Management (IAM) authentication for connections to the proxy.</p>
-    pub iam_auth: Option<String>,
+    pub iam_auth: Option<IAMAuthMode>,
     /// <p>The Amazon Resource Name (ARN) representing the secret that the proxy uses to authenticate to the RDS DB instance or Aurora DB cluster. These secrets are stored within Amazon Secrets Manager.</p>
     pub secret_arn: Option<String>,
     /// <p>The name of the database user to which the proxy connects.</p>
@@ -18192,11 +19179,11 @@ impl UserAuthConfigSerializer {
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct UserAuthConfigInfo {
     /// <p>The type of authentication that the proxy uses for connections from the proxy to the underlying database.</p>
-    pub auth_scheme: Option<String>,
+    pub auth_scheme: Option<AuthScheme>,
     /// <p>A user-specified description about the authentication used by a proxy to log in as a specific database user.</p>
     pub description: Option<String>,
     /// <p>Whether to require or disallow AWS Identity and Access Management (IAM) authentication for connections to the proxy.</p>
-    pub iam_auth: Option<String>,
+    pub iam_auth: Option<IAMAuthMode>,
     /// <p>The Amazon Resource Name (ARN) representing the secret that the proxy uses to authenticate to the RDS DB instance or Aurora DB cluster. These secrets are stored within Amazon Secrets Manager.</p>
     pub secret_arn: Option<String>,
     /// <p>The name of the database user to which the proxy connects.</p>
@@ -29346,11 +30333,12 @@ pub struct RdsClient {
 impl RdsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> RdsClient {
         RdsClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "RDS"),
         }
     }
 
@@ -29382,6 +30370,7 @@ impl Rds for RdsClient {
         input: AddRoleToDBClusterMessage,
     ) -> Result<(), RusotoError<AddRoleToDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("AddRoleToDBCluster");
         let mut params = Params::new();
 
         params.put("Action", "AddRoleToDBCluster");
@@ -29410,6 +30399,7 @@ impl Rds for RdsClient {
         input: AddRoleToDBInstanceMessage,
     ) -> Result<(), RusotoError<AddRoleToDBInstanceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("AddRoleToDBInstance");
         let mut params = Params::new();
 
         params.put("Action", "AddRoleToDBInstance");
@@ -29441,6 +30431,7 @@ impl Rds for RdsClient {
         RusotoError<AddSourceIdentifierToSubscriptionError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("AddSourceIdentifierToSubscription");
         let mut params = Params::new();
 
         params.put("Action", "AddSourceIdentifierToSubscription");
@@ -29492,6 +30483,7 @@ impl Rds for RdsClient {
         input: AddTagsToResourceMessage,
     ) -> Result<(), RusotoError<AddTagsToResourceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("AddTagsToResource");
         let mut params = Params::new();
 
         params.put("Action", "AddTagsToResource");
@@ -29521,6 +30513,7 @@ impl Rds for RdsClient {
     ) -> Result<ApplyPendingMaintenanceActionResult, RusotoError<ApplyPendingMaintenanceActionError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ApplyPendingMaintenanceAction");
         let mut params = Params::new();
 
         params.put("Action", "ApplyPendingMaintenanceAction");
@@ -29573,6 +30566,7 @@ impl Rds for RdsClient {
         RusotoError<AuthorizeDBSecurityGroupIngressError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("AuthorizeDBSecurityGroupIngress");
         let mut params = Params::new();
 
         params.put("Action", "AuthorizeDBSecurityGroupIngress");
@@ -29624,6 +30618,7 @@ impl Rds for RdsClient {
         input: BacktrackDBClusterMessage,
     ) -> Result<DBClusterBacktrack, RusotoError<BacktrackDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("BacktrackDBCluster");
         let mut params = Params::new();
 
         params.put("Action", "BacktrackDBCluster");
@@ -29673,6 +30668,7 @@ impl Rds for RdsClient {
         input: CancelExportTaskMessage,
     ) -> Result<ExportTask, RusotoError<CancelExportTaskError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CancelExportTask");
         let mut params = Params::new();
 
         params.put("Action", "CancelExportTask");
@@ -29720,6 +30716,7 @@ impl Rds for RdsClient {
     ) -> Result<CopyDBClusterParameterGroupResult, RusotoError<CopyDBClusterParameterGroupError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CopyDBClusterParameterGroup");
         let mut params = Params::new();
 
         params.put("Action", "CopyDBClusterParameterGroup");
@@ -29769,6 +30766,7 @@ impl Rds for RdsClient {
         input: CopyDBClusterSnapshotMessage,
     ) -> Result<CopyDBClusterSnapshotResult, RusotoError<CopyDBClusterSnapshotError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CopyDBClusterSnapshot");
         let mut params = Params::new();
 
         params.put("Action", "CopyDBClusterSnapshot");
@@ -29818,6 +30816,7 @@ impl Rds for RdsClient {
         input: CopyDBParameterGroupMessage,
     ) -> Result<CopyDBParameterGroupResult, RusotoError<CopyDBParameterGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CopyDBParameterGroup");
         let mut params = Params::new();
 
         params.put("Action", "CopyDBParameterGroup");
@@ -29867,6 +30866,7 @@ impl Rds for RdsClient {
         input: CopyDBSnapshotMessage,
     ) -> Result<CopyDBSnapshotResult, RusotoError<CopyDBSnapshotError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CopyDBSnapshot");
         let mut params = Params::new();
 
         params.put("Action", "CopyDBSnapshot");
@@ -29914,6 +30914,7 @@ impl Rds for RdsClient {
         input: CopyOptionGroupMessage,
     ) -> Result<CopyOptionGroupResult, RusotoError<CopyOptionGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CopyOptionGroup");
         let mut params = Params::new();
 
         params.put("Action", "CopyOptionGroup");
@@ -29964,6 +30965,7 @@ impl Rds for RdsClient {
     ) -> Result<CreateCustomAvailabilityZoneResult, RusotoError<CreateCustomAvailabilityZoneError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateCustomAvailabilityZone");
         let mut params = Params::new();
 
         params.put("Action", "CreateCustomAvailabilityZone");
@@ -30013,6 +31015,7 @@ impl Rds for RdsClient {
         input: CreateDBClusterMessage,
     ) -> Result<CreateDBClusterResult, RusotoError<CreateDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateDBCluster");
         let mut params = Params::new();
 
         params.put("Action", "CreateDBCluster");
@@ -30062,6 +31065,7 @@ impl Rds for RdsClient {
         input: CreateDBClusterEndpointMessage,
     ) -> Result<DBClusterEndpoint, RusotoError<CreateDBClusterEndpointError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateDBClusterEndpoint");
         let mut params = Params::new();
 
         params.put("Action", "CreateDBClusterEndpoint");
@@ -30112,6 +31116,7 @@ impl Rds for RdsClient {
     ) -> Result<CreateDBClusterParameterGroupResult, RusotoError<CreateDBClusterParameterGroupError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateDBClusterParameterGroup");
         let mut params = Params::new();
 
         params.put("Action", "CreateDBClusterParameterGroup");
@@ -30161,6 +31166,7 @@ impl Rds for RdsClient {
         input: CreateDBClusterSnapshotMessage,
     ) -> Result<CreateDBClusterSnapshotResult, RusotoError<CreateDBClusterSnapshotError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateDBClusterSnapshot");
         let mut params = Params::new();
 
         params.put("Action", "CreateDBClusterSnapshot");
@@ -30210,6 +31216,7 @@ impl Rds for RdsClient {
         input: CreateDBInstanceMessage,
     ) -> Result<CreateDBInstanceResult, RusotoError<CreateDBInstanceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateDBInstance");
         let mut params = Params::new();
 
         params.put("Action", "CreateDBInstance");
@@ -30260,6 +31267,7 @@ impl Rds for RdsClient {
     ) -> Result<CreateDBInstanceReadReplicaResult, RusotoError<CreateDBInstanceReadReplicaError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateDBInstanceReadReplica");
         let mut params = Params::new();
 
         params.put("Action", "CreateDBInstanceReadReplica");
@@ -30309,6 +31317,7 @@ impl Rds for RdsClient {
         input: CreateDBParameterGroupMessage,
     ) -> Result<CreateDBParameterGroupResult, RusotoError<CreateDBParameterGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateDBParameterGroup");
         let mut params = Params::new();
 
         params.put("Action", "CreateDBParameterGroup");
@@ -30358,6 +31367,7 @@ impl Rds for RdsClient {
         input: CreateDBProxyRequest,
     ) -> Result<CreateDBProxyResponse, RusotoError<CreateDBProxyError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateDBProxy");
         let mut params = Params::new();
 
         params.put("Action", "CreateDBProxy");
@@ -30405,6 +31415,7 @@ impl Rds for RdsClient {
         input: CreateDBSecurityGroupMessage,
     ) -> Result<CreateDBSecurityGroupResult, RusotoError<CreateDBSecurityGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateDBSecurityGroup");
         let mut params = Params::new();
 
         params.put("Action", "CreateDBSecurityGroup");
@@ -30454,6 +31465,7 @@ impl Rds for RdsClient {
         input: CreateDBSnapshotMessage,
     ) -> Result<CreateDBSnapshotResult, RusotoError<CreateDBSnapshotError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateDBSnapshot");
         let mut params = Params::new();
 
         params.put("Action", "CreateDBSnapshot");
@@ -30503,6 +31515,7 @@ impl Rds for RdsClient {
         input: CreateDBSubnetGroupMessage,
     ) -> Result<CreateDBSubnetGroupResult, RusotoError<CreateDBSubnetGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateDBSubnetGroup");
         let mut params = Params::new();
 
         params.put("Action", "CreateDBSubnetGroup");
@@ -30552,6 +31565,7 @@ impl Rds for RdsClient {
         input: CreateEventSubscriptionMessage,
     ) -> Result<CreateEventSubscriptionResult, RusotoError<CreateEventSubscriptionError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateEventSubscription");
         let mut params = Params::new();
 
         params.put("Action", "CreateEventSubscription");
@@ -30601,6 +31615,7 @@ impl Rds for RdsClient {
         input: CreateGlobalClusterMessage,
     ) -> Result<CreateGlobalClusterResult, RusotoError<CreateGlobalClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateGlobalCluster");
         let mut params = Params::new();
 
         params.put("Action", "CreateGlobalCluster");
@@ -30650,6 +31665,7 @@ impl Rds for RdsClient {
         input: CreateOptionGroupMessage,
     ) -> Result<CreateOptionGroupResult, RusotoError<CreateOptionGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateOptionGroup");
         let mut params = Params::new();
 
         params.put("Action", "CreateOptionGroup");
@@ -30700,6 +31716,7 @@ impl Rds for RdsClient {
     ) -> Result<DeleteCustomAvailabilityZoneResult, RusotoError<DeleteCustomAvailabilityZoneError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteCustomAvailabilityZone");
         let mut params = Params::new();
 
         params.put("Action", "DeleteCustomAvailabilityZone");
@@ -30749,6 +31766,7 @@ impl Rds for RdsClient {
         input: DeleteDBClusterMessage,
     ) -> Result<DeleteDBClusterResult, RusotoError<DeleteDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteDBCluster");
         let mut params = Params::new();
 
         params.put("Action", "DeleteDBCluster");
@@ -30798,6 +31816,7 @@ impl Rds for RdsClient {
         input: DeleteDBClusterEndpointMessage,
     ) -> Result<DBClusterEndpoint, RusotoError<DeleteDBClusterEndpointError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteDBClusterEndpoint");
         let mut params = Params::new();
 
         params.put("Action", "DeleteDBClusterEndpoint");
@@ -30847,6 +31866,7 @@ impl Rds for RdsClient {
         input: DeleteDBClusterParameterGroupMessage,
     ) -> Result<(), RusotoError<DeleteDBClusterParameterGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteDBClusterParameterGroup");
         let mut params = Params::new();
 
         params.put("Action", "DeleteDBClusterParameterGroup");
@@ -30875,6 +31895,7 @@ impl Rds for RdsClient {
         input: DeleteDBClusterSnapshotMessage,
     ) -> Result<DeleteDBClusterSnapshotResult, RusotoError<DeleteDBClusterSnapshotError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteDBClusterSnapshot");
         let mut params = Params::new();
 
         params.put("Action", "DeleteDBClusterSnapshot");
@@ -30924,6 +31945,7 @@ impl Rds for RdsClient {
         input: DeleteDBInstanceMessage,
     ) -> Result<DeleteDBInstanceResult, RusotoError<DeleteDBInstanceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteDBInstance");
         let mut params = Params::new();
 
         params.put("Action", "DeleteDBInstance");
@@ -30976,6 +31998,7 @@ impl Rds for RdsClient {
         RusotoError<DeleteDBInstanceAutomatedBackupError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteDBInstanceAutomatedBackup");
         let mut params = Params::new();
 
         params.put("Action", "DeleteDBInstanceAutomatedBackup");
@@ -31027,6 +32050,7 @@ impl Rds for RdsClient {
         input: DeleteDBParameterGroupMessage,
     ) -> Result<(), RusotoError<DeleteDBParameterGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteDBParameterGroup");
         let mut params = Params::new();
 
         params.put("Action", "DeleteDBParameterGroup");
@@ -31055,6 +32079,7 @@ impl Rds for RdsClient {
         input: DeleteDBProxyRequest,
     ) -> Result<DeleteDBProxyResponse, RusotoError<DeleteDBProxyError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteDBProxy");
         let mut params = Params::new();
 
         params.put("Action", "DeleteDBProxy");
@@ -31102,6 +32127,7 @@ impl Rds for RdsClient {
         input: DeleteDBSecurityGroupMessage,
     ) -> Result<(), RusotoError<DeleteDBSecurityGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteDBSecurityGroup");
         let mut params = Params::new();
 
         params.put("Action", "DeleteDBSecurityGroup");
@@ -31130,6 +32156,7 @@ impl Rds for RdsClient {
         input: DeleteDBSnapshotMessage,
     ) -> Result<DeleteDBSnapshotResult, RusotoError<DeleteDBSnapshotError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteDBSnapshot");
         let mut params = Params::new();
 
         params.put("Action", "DeleteDBSnapshot");
@@ -31179,6 +32206,7 @@ impl Rds for RdsClient {
         input: DeleteDBSubnetGroupMessage,
     ) -> Result<(), RusotoError<DeleteDBSubnetGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteDBSubnetGroup");
         let mut params = Params::new();
 
         params.put("Action", "DeleteDBSubnetGroup");
@@ -31207,6 +32235,7 @@ impl Rds for RdsClient {
         input: DeleteEventSubscriptionMessage,
     ) -> Result<DeleteEventSubscriptionResult, RusotoError<DeleteEventSubscriptionError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteEventSubscription");
         let mut params = Params::new();
 
         params.put("Action", "DeleteEventSubscription");
@@ -31256,6 +32285,7 @@ impl Rds for RdsClient {
         input: DeleteGlobalClusterMessage,
     ) -> Result<DeleteGlobalClusterResult, RusotoError<DeleteGlobalClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteGlobalCluster");
         let mut params = Params::new();
 
         params.put("Action", "DeleteGlobalCluster");
@@ -31305,6 +32335,7 @@ impl Rds for RdsClient {
         input: DeleteInstallationMediaMessage,
     ) -> Result<InstallationMedia, RusotoError<DeleteInstallationMediaError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteInstallationMedia");
         let mut params = Params::new();
 
         params.put("Action", "DeleteInstallationMedia");
@@ -31354,6 +32385,7 @@ impl Rds for RdsClient {
         input: DeleteOptionGroupMessage,
     ) -> Result<(), RusotoError<DeleteOptionGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteOptionGroup");
         let mut params = Params::new();
 
         params.put("Action", "DeleteOptionGroup");
@@ -31382,6 +32414,7 @@ impl Rds for RdsClient {
         input: DeregisterDBProxyTargetsRequest,
     ) -> Result<DeregisterDBProxyTargetsResponse, RusotoError<DeregisterDBProxyTargetsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeregisterDBProxyTargets");
         let mut params = Params::new();
 
         params.put("Action", "DeregisterDBProxyTargets");
@@ -31431,6 +32464,7 @@ impl Rds for RdsClient {
         input: DescribeAccountAttributesMessage,
     ) -> Result<AccountAttributesMessage, RusotoError<DescribeAccountAttributesError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeAccountAttributes");
         let mut params = Params::new();
 
         params.put("Action", "DescribeAccountAttributes");
@@ -31480,6 +32514,7 @@ impl Rds for RdsClient {
         input: DescribeCertificatesMessage,
     ) -> Result<CertificateMessage, RusotoError<DescribeCertificatesError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeCertificates");
         let mut params = Params::new();
 
         params.put("Action", "DescribeCertificates");
@@ -31530,6 +32565,7 @@ impl Rds for RdsClient {
     ) -> Result<CustomAvailabilityZoneMessage, RusotoError<DescribeCustomAvailabilityZonesError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeCustomAvailabilityZones");
         let mut params = Params::new();
 
         params.put("Action", "DescribeCustomAvailabilityZones");
@@ -31581,6 +32617,7 @@ impl Rds for RdsClient {
         input: DescribeDBClusterBacktracksMessage,
     ) -> Result<DBClusterBacktrackMessage, RusotoError<DescribeDBClusterBacktracksError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBClusterBacktracks");
         let mut params = Params::new();
 
         params.put("Action", "DescribeDBClusterBacktracks");
@@ -31630,6 +32667,7 @@ impl Rds for RdsClient {
         input: DescribeDBClusterEndpointsMessage,
     ) -> Result<DBClusterEndpointMessage, RusotoError<DescribeDBClusterEndpointsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBClusterEndpoints");
         let mut params = Params::new();
 
         params.put("Action", "DescribeDBClusterEndpoints");
@@ -31680,6 +32718,7 @@ impl Rds for RdsClient {
     ) -> Result<DBClusterParameterGroupsMessage, RusotoError<DescribeDBClusterParameterGroupsError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBClusterParameterGroups");
         let mut params = Params::new();
 
         params.put("Action", "DescribeDBClusterParameterGroups");
@@ -31731,6 +32770,7 @@ impl Rds for RdsClient {
         input: DescribeDBClusterParametersMessage,
     ) -> Result<DBClusterParameterGroupDetails, RusotoError<DescribeDBClusterParametersError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBClusterParameters");
         let mut params = Params::new();
 
         params.put("Action", "DescribeDBClusterParameters");
@@ -31783,6 +32823,7 @@ impl Rds for RdsClient {
         RusotoError<DescribeDBClusterSnapshotAttributesError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBClusterSnapshotAttributes");
         let mut params = Params::new();
 
         params.put("Action", "DescribeDBClusterSnapshotAttributes");
@@ -31834,6 +32875,7 @@ impl Rds for RdsClient {
         input: DescribeDBClusterSnapshotsMessage,
     ) -> Result<DBClusterSnapshotMessage, RusotoError<DescribeDBClusterSnapshotsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBClusterSnapshots");
         let mut params = Params::new();
 
         params.put("Action", "DescribeDBClusterSnapshots");
@@ -31883,6 +32925,7 @@ impl Rds for RdsClient {
         input: DescribeDBClustersMessage,
     ) -> Result<DBClusterMessage, RusotoError<DescribeDBClustersError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBClusters");
         let mut params = Params::new();
 
         params.put("Action", "DescribeDBClusters");
@@ -31930,6 +32973,7 @@ impl Rds for RdsClient {
         input: DescribeDBEngineVersionsMessage,
     ) -> Result<DBEngineVersionMessage, RusotoError<DescribeDBEngineVersionsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBEngineVersions");
         let mut params = Params::new();
 
         params.put("Action", "DescribeDBEngineVersions");
@@ -31982,6 +33026,7 @@ impl Rds for RdsClient {
         RusotoError<DescribeDBInstanceAutomatedBackupsError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBInstanceAutomatedBackups");
         let mut params = Params::new();
 
         params.put("Action", "DescribeDBInstanceAutomatedBackups");
@@ -32033,6 +33078,7 @@ impl Rds for RdsClient {
         input: DescribeDBInstancesMessage,
     ) -> Result<DBInstanceMessage, RusotoError<DescribeDBInstancesError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBInstances");
         let mut params = Params::new();
 
         params.put("Action", "DescribeDBInstances");
@@ -32082,6 +33128,7 @@ impl Rds for RdsClient {
         input: DescribeDBLogFilesMessage,
     ) -> Result<DescribeDBLogFilesResponse, RusotoError<DescribeDBLogFilesError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBLogFiles");
         let mut params = Params::new();
 
         params.put("Action", "DescribeDBLogFiles");
@@ -32131,6 +33178,7 @@ impl Rds for RdsClient {
         input: DescribeDBParameterGroupsMessage,
     ) -> Result<DBParameterGroupsMessage, RusotoError<DescribeDBParameterGroupsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBParameterGroups");
         let mut params = Params::new();
 
         params.put("Action", "DescribeDBParameterGroups");
@@ -32180,6 +33228,7 @@ impl Rds for RdsClient {
         input: DescribeDBParametersMessage,
     ) -> Result<DBParameterGroupDetails, RusotoError<DescribeDBParametersError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBParameters");
         let mut params = Params::new();
 
         params.put("Action", "DescribeDBParameters");
@@ -32229,6 +33278,7 @@ impl Rds for RdsClient {
         input: DescribeDBProxiesRequest,
     ) -> Result<DescribeDBProxiesResponse, RusotoError<DescribeDBProxiesError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBProxies");
         let mut params = Params::new();
 
         params.put("Action", "DescribeDBProxies");
@@ -32279,6 +33329,7 @@ impl Rds for RdsClient {
     ) -> Result<DescribeDBProxyTargetGroupsResponse, RusotoError<DescribeDBProxyTargetGroupsError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBProxyTargetGroups");
         let mut params = Params::new();
 
         params.put("Action", "DescribeDBProxyTargetGroups");
@@ -32328,6 +33379,7 @@ impl Rds for RdsClient {
         input: DescribeDBProxyTargetsRequest,
     ) -> Result<DescribeDBProxyTargetsResponse, RusotoError<DescribeDBProxyTargetsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBProxyTargets");
         let mut params = Params::new();
 
         params.put("Action", "DescribeDBProxyTargets");
@@ -32377,6 +33429,7 @@ impl Rds for RdsClient {
         input: DescribeDBSecurityGroupsMessage,
     ) -> Result<DBSecurityGroupMessage, RusotoError<DescribeDBSecurityGroupsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBSecurityGroups");
         let mut params = Params::new();
 
         params.put("Action", "DescribeDBSecurityGroups");
@@ -32427,6 +33480,7 @@ impl Rds for RdsClient {
     ) -> Result<DescribeDBSnapshotAttributesResult, RusotoError<DescribeDBSnapshotAttributesError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBSnapshotAttributes");
         let mut params = Params::new();
 
         params.put("Action", "DescribeDBSnapshotAttributes");
@@ -32476,6 +33530,7 @@ impl Rds for RdsClient {
         input: DescribeDBSnapshotsMessage,
     ) -> Result<DBSnapshotMessage, RusotoError<DescribeDBSnapshotsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBSnapshots");
         let mut params = Params::new();
 
         params.put("Action", "DescribeDBSnapshots");
@@ -32525,6 +33580,7 @@ impl Rds for RdsClient {
         input: DescribeDBSubnetGroupsMessage,
     ) -> Result<DBSubnetGroupMessage, RusotoError<DescribeDBSubnetGroupsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBSubnetGroups");
         let mut params = Params::new();
 
         params.put("Action", "DescribeDBSubnetGroups");
@@ -32577,6 +33633,7 @@ impl Rds for RdsClient {
         RusotoError<DescribeEngineDefaultClusterParametersError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeEngineDefaultClusterParameters");
         let mut params = Params::new();
 
         params.put("Action", "DescribeEngineDefaultClusterParameters");
@@ -32631,6 +33688,7 @@ impl Rds for RdsClient {
         RusotoError<DescribeEngineDefaultParametersError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeEngineDefaultParameters");
         let mut params = Params::new();
 
         params.put("Action", "DescribeEngineDefaultParameters");
@@ -32682,6 +33740,7 @@ impl Rds for RdsClient {
         input: DescribeEventCategoriesMessage,
     ) -> Result<EventCategoriesMessage, RusotoError<DescribeEventCategoriesError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeEventCategories");
         let mut params = Params::new();
 
         params.put("Action", "DescribeEventCategories");
@@ -32731,6 +33790,7 @@ impl Rds for RdsClient {
         input: DescribeEventSubscriptionsMessage,
     ) -> Result<EventSubscriptionsMessage, RusotoError<DescribeEventSubscriptionsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeEventSubscriptions");
         let mut params = Params::new();
 
         params.put("Action", "DescribeEventSubscriptions");
@@ -32780,6 +33840,7 @@ impl Rds for RdsClient {
         input: DescribeEventsMessage,
     ) -> Result<EventsMessage, RusotoError<DescribeEventsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeEvents");
         let mut params = Params::new();
 
         params.put("Action", "DescribeEvents");
@@ -32826,6 +33887,7 @@ impl Rds for RdsClient {
         input: DescribeExportTasksMessage,
     ) -> Result<ExportTasksMessage, RusotoError<DescribeExportTasksError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeExportTasks");
         let mut params = Params::new();
 
         params.put("Action", "DescribeExportTasks");
@@ -32875,6 +33937,7 @@ impl Rds for RdsClient {
         input: DescribeGlobalClustersMessage,
     ) -> Result<GlobalClustersMessage, RusotoError<DescribeGlobalClustersError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeGlobalClusters");
         let mut params = Params::new();
 
         params.put("Action", "DescribeGlobalClusters");
@@ -32924,6 +33987,7 @@ impl Rds for RdsClient {
         input: DescribeInstallationMediaMessage,
     ) -> Result<InstallationMediaMessage, RusotoError<DescribeInstallationMediaError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeInstallationMedia");
         let mut params = Params::new();
 
         params.put("Action", "DescribeInstallationMedia");
@@ -32973,6 +34037,7 @@ impl Rds for RdsClient {
         input: DescribeOptionGroupOptionsMessage,
     ) -> Result<OptionGroupOptionsMessage, RusotoError<DescribeOptionGroupOptionsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeOptionGroupOptions");
         let mut params = Params::new();
 
         params.put("Action", "DescribeOptionGroupOptions");
@@ -33022,6 +34087,7 @@ impl Rds for RdsClient {
         input: DescribeOptionGroupsMessage,
     ) -> Result<OptionGroups, RusotoError<DescribeOptionGroupsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeOptionGroups");
         let mut params = Params::new();
 
         params.put("Action", "DescribeOptionGroups");
@@ -33072,6 +34138,7 @@ impl Rds for RdsClient {
         RusotoError<DescribeOrderableDBInstanceOptionsError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeOrderableDBInstanceOptions");
         let mut params = Params::new();
 
         params.put("Action", "DescribeOrderableDBInstanceOptions");
@@ -33124,6 +34191,7 @@ impl Rds for RdsClient {
     ) -> Result<PendingMaintenanceActionsMessage, RusotoError<DescribePendingMaintenanceActionsError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribePendingMaintenanceActions");
         let mut params = Params::new();
 
         params.put("Action", "DescribePendingMaintenanceActions");
@@ -33175,6 +34243,7 @@ impl Rds for RdsClient {
         input: DescribeReservedDBInstancesMessage,
     ) -> Result<ReservedDBInstanceMessage, RusotoError<DescribeReservedDBInstancesError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeReservedDBInstances");
         let mut params = Params::new();
 
         params.put("Action", "DescribeReservedDBInstances");
@@ -33227,6 +34296,7 @@ impl Rds for RdsClient {
         RusotoError<DescribeReservedDBInstancesOfferingsError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeReservedDBInstancesOfferings");
         let mut params = Params::new();
 
         params.put("Action", "DescribeReservedDBInstancesOfferings");
@@ -33278,6 +34348,7 @@ impl Rds for RdsClient {
         input: DescribeSourceRegionsMessage,
     ) -> Result<SourceRegionMessage, RusotoError<DescribeSourceRegionsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeSourceRegions");
         let mut params = Params::new();
 
         params.put("Action", "DescribeSourceRegions");
@@ -33330,6 +34401,7 @@ impl Rds for RdsClient {
         RusotoError<DescribeValidDBInstanceModificationsError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeValidDBInstanceModifications");
         let mut params = Params::new();
 
         params.put("Action", "DescribeValidDBInstanceModifications");
@@ -33381,6 +34453,7 @@ impl Rds for RdsClient {
         input: DownloadDBLogFilePortionMessage,
     ) -> Result<DownloadDBLogFilePortionDetails, RusotoError<DownloadDBLogFilePortionError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DownloadDBLogFilePortion");
         let mut params = Params::new();
 
         params.put("Action", "DownloadDBLogFilePortion");
@@ -33430,6 +34503,7 @@ impl Rds for RdsClient {
         input: FailoverDBClusterMessage,
     ) -> Result<FailoverDBClusterResult, RusotoError<FailoverDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("FailoverDBCluster");
         let mut params = Params::new();
 
         params.put("Action", "FailoverDBCluster");
@@ -33479,6 +34553,7 @@ impl Rds for RdsClient {
         input: ImportInstallationMediaMessage,
     ) -> Result<InstallationMedia, RusotoError<ImportInstallationMediaError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ImportInstallationMedia");
         let mut params = Params::new();
 
         params.put("Action", "ImportInstallationMedia");
@@ -33528,6 +34603,7 @@ impl Rds for RdsClient {
         input: ListTagsForResourceMessage,
     ) -> Result<TagListMessage, RusotoError<ListTagsForResourceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ListTagsForResource");
         let mut params = Params::new();
 
         params.put("Action", "ListTagsForResource");
@@ -33575,6 +34651,7 @@ impl Rds for RdsClient {
         input: ModifyCertificatesMessage,
     ) -> Result<ModifyCertificatesResult, RusotoError<ModifyCertificatesError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyCertificates");
         let mut params = Params::new();
 
         params.put("Action", "ModifyCertificates");
@@ -33624,6 +34701,7 @@ impl Rds for RdsClient {
         input: ModifyCurrentDBClusterCapacityMessage,
     ) -> Result<DBClusterCapacityInfo, RusotoError<ModifyCurrentDBClusterCapacityError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyCurrentDBClusterCapacity");
         let mut params = Params::new();
 
         params.put("Action", "ModifyCurrentDBClusterCapacity");
@@ -33673,6 +34751,7 @@ impl Rds for RdsClient {
         input: ModifyDBClusterMessage,
     ) -> Result<ModifyDBClusterResult, RusotoError<ModifyDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyDBCluster");
         let mut params = Params::new();
 
         params.put("Action", "ModifyDBCluster");
@@ -33722,6 +34801,7 @@ impl Rds for RdsClient {
         input: ModifyDBClusterEndpointMessage,
     ) -> Result<DBClusterEndpoint, RusotoError<ModifyDBClusterEndpointError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyDBClusterEndpoint");
         let mut params = Params::new();
 
         params.put("Action", "ModifyDBClusterEndpoint");
@@ -33772,6 +34852,7 @@ impl Rds for RdsClient {
     ) -> Result<DBClusterParameterGroupNameMessage, RusotoError<ModifyDBClusterParameterGroupError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyDBClusterParameterGroup");
         let mut params = Params::new();
 
         params.put("Action", "ModifyDBClusterParameterGroup");
@@ -33824,6 +34905,7 @@ impl Rds for RdsClient {
         RusotoError<ModifyDBClusterSnapshotAttributeError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyDBClusterSnapshotAttribute");
         let mut params = Params::new();
 
         params.put("Action", "ModifyDBClusterSnapshotAttribute");
@@ -33875,6 +34957,7 @@ impl Rds for RdsClient {
         input: ModifyDBInstanceMessage,
     ) -> Result<ModifyDBInstanceResult, RusotoError<ModifyDBInstanceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyDBInstance");
         let mut params = Params::new();
 
         params.put("Action", "ModifyDBInstance");
@@ -33924,6 +35007,7 @@ impl Rds for RdsClient {
         input: ModifyDBParameterGroupMessage,
     ) -> Result<DBParameterGroupNameMessage, RusotoError<ModifyDBParameterGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyDBParameterGroup");
         let mut params = Params::new();
 
         params.put("Action", "ModifyDBParameterGroup");
@@ -33973,6 +35057,7 @@ impl Rds for RdsClient {
         input: ModifyDBProxyRequest,
     ) -> Result<ModifyDBProxyResponse, RusotoError<ModifyDBProxyError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyDBProxy");
         let mut params = Params::new();
 
         params.put("Action", "ModifyDBProxy");
@@ -34020,6 +35105,7 @@ impl Rds for RdsClient {
         input: ModifyDBProxyTargetGroupRequest,
     ) -> Result<ModifyDBProxyTargetGroupResponse, RusotoError<ModifyDBProxyTargetGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyDBProxyTargetGroup");
         let mut params = Params::new();
 
         params.put("Action", "ModifyDBProxyTargetGroup");
@@ -34069,6 +35155,7 @@ impl Rds for RdsClient {
         input: ModifyDBSnapshotMessage,
     ) -> Result<ModifyDBSnapshotResult, RusotoError<ModifyDBSnapshotError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyDBSnapshot");
         let mut params = Params::new();
 
         params.put("Action", "ModifyDBSnapshot");
@@ -34118,6 +35205,7 @@ impl Rds for RdsClient {
         input: ModifyDBSnapshotAttributeMessage,
     ) -> Result<ModifyDBSnapshotAttributeResult, RusotoError<ModifyDBSnapshotAttributeError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyDBSnapshotAttribute");
         let mut params = Params::new();
 
         params.put("Action", "ModifyDBSnapshotAttribute");
@@ -34167,6 +35255,7 @@ impl Rds for RdsClient {
         input: ModifyDBSubnetGroupMessage,
     ) -> Result<ModifyDBSubnetGroupResult, RusotoError<ModifyDBSubnetGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyDBSubnetGroup");
         let mut params = Params::new();
 
         params.put("Action", "ModifyDBSubnetGroup");
@@ -34216,6 +35305,7 @@ impl Rds for RdsClient {
         input: ModifyEventSubscriptionMessage,
     ) -> Result<ModifyEventSubscriptionResult, RusotoError<ModifyEventSubscriptionError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyEventSubscription");
         let mut params = Params::new();
 
         params.put("Action", "ModifyEventSubscription");
@@ -34265,6 +35355,7 @@ impl Rds for RdsClient {
         input: ModifyGlobalClusterMessage,
     ) -> Result<ModifyGlobalClusterResult, RusotoError<ModifyGlobalClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyGlobalCluster");
         let mut params = Params::new();
 
         params.put("Action", "ModifyGlobalCluster");
@@ -34314,6 +35405,7 @@ impl Rds for RdsClient {
         input: ModifyOptionGroupMessage,
     ) -> Result<ModifyOptionGroupResult, RusotoError<ModifyOptionGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyOptionGroup");
         let mut params = Params::new();
 
         params.put("Action", "ModifyOptionGroup");
@@ -34363,6 +35455,7 @@ impl Rds for RdsClient {
         input: PromoteReadReplicaMessage,
     ) -> Result<PromoteReadReplicaResult, RusotoError<PromoteReadReplicaError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("PromoteReadReplica");
         let mut params = Params::new();
 
         params.put("Action", "PromoteReadReplica");
@@ -34413,6 +35506,7 @@ impl Rds for RdsClient {
     ) -> Result<PromoteReadReplicaDBClusterResult, RusotoError<PromoteReadReplicaDBClusterError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("PromoteReadReplicaDBCluster");
         let mut params = Params::new();
 
         params.put("Action", "PromoteReadReplicaDBCluster");
@@ -34465,6 +35559,7 @@ impl Rds for RdsClient {
         RusotoError<PurchaseReservedDBInstancesOfferingError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("PurchaseReservedDBInstancesOffering");
         let mut params = Params::new();
 
         params.put("Action", "PurchaseReservedDBInstancesOffering");
@@ -34516,6 +35611,7 @@ impl Rds for RdsClient {
         input: RebootDBInstanceMessage,
     ) -> Result<RebootDBInstanceResult, RusotoError<RebootDBInstanceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("RebootDBInstance");
         let mut params = Params::new();
 
         params.put("Action", "RebootDBInstance");
@@ -34565,6 +35661,7 @@ impl Rds for RdsClient {
         input: RegisterDBProxyTargetsRequest,
     ) -> Result<RegisterDBProxyTargetsResponse, RusotoError<RegisterDBProxyTargetsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("RegisterDBProxyTargets");
         let mut params = Params::new();
 
         params.put("Action", "RegisterDBProxyTargets");
@@ -34614,6 +35711,7 @@ impl Rds for RdsClient {
         input: RemoveFromGlobalClusterMessage,
     ) -> Result<RemoveFromGlobalClusterResult, RusotoError<RemoveFromGlobalClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("RemoveFromGlobalCluster");
         let mut params = Params::new();
 
         params.put("Action", "RemoveFromGlobalCluster");
@@ -34663,6 +35761,7 @@ impl Rds for RdsClient {
         input: RemoveRoleFromDBClusterMessage,
     ) -> Result<(), RusotoError<RemoveRoleFromDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("RemoveRoleFromDBCluster");
         let mut params = Params::new();
 
         params.put("Action", "RemoveRoleFromDBCluster");
@@ -34691,6 +35790,7 @@ impl Rds for RdsClient {
         input: RemoveRoleFromDBInstanceMessage,
     ) -> Result<(), RusotoError<RemoveRoleFromDBInstanceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("RemoveRoleFromDBInstance");
         let mut params = Params::new();
 
         params.put("Action", "RemoveRoleFromDBInstance");
@@ -34722,6 +35822,7 @@ impl Rds for RdsClient {
         RusotoError<RemoveSourceIdentifierFromSubscriptionError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("RemoveSourceIdentifierFromSubscription");
         let mut params = Params::new();
 
         params.put("Action", "RemoveSourceIdentifierFromSubscription");
@@ -34773,6 +35874,7 @@ impl Rds for RdsClient {
         input: RemoveTagsFromResourceMessage,
     ) -> Result<(), RusotoError<RemoveTagsFromResourceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("RemoveTagsFromResource");
         let mut params = Params::new();
 
         params.put("Action", "RemoveTagsFromResource");
@@ -34802,6 +35904,7 @@ impl Rds for RdsClient {
     ) -> Result<DBClusterParameterGroupNameMessage, RusotoError<ResetDBClusterParameterGroupError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ResetDBClusterParameterGroup");
         let mut params = Params::new();
 
         params.put("Action", "ResetDBClusterParameterGroup");
@@ -34851,6 +35954,7 @@ impl Rds for RdsClient {
         input: ResetDBParameterGroupMessage,
     ) -> Result<DBParameterGroupNameMessage, RusotoError<ResetDBParameterGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ResetDBParameterGroup");
         let mut params = Params::new();
 
         params.put("Action", "ResetDBParameterGroup");
@@ -34900,6 +36004,7 @@ impl Rds for RdsClient {
         input: RestoreDBClusterFromS3Message,
     ) -> Result<RestoreDBClusterFromS3Result, RusotoError<RestoreDBClusterFromS3Error>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("RestoreDBClusterFromS3");
         let mut params = Params::new();
 
         params.put("Action", "RestoreDBClusterFromS3");
@@ -34950,6 +36055,7 @@ impl Rds for RdsClient {
     ) -> Result<RestoreDBClusterFromSnapshotResult, RusotoError<RestoreDBClusterFromSnapshotError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("RestoreDBClusterFromSnapshot");
         let mut params = Params::new();
 
         params.put("Action", "RestoreDBClusterFromSnapshot");
@@ -35000,6 +36106,7 @@ impl Rds for RdsClient {
     ) -> Result<RestoreDBClusterToPointInTimeResult, RusotoError<RestoreDBClusterToPointInTimeError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("RestoreDBClusterToPointInTime");
         let mut params = Params::new();
 
         params.put("Action", "RestoreDBClusterToPointInTime");
@@ -35052,6 +36159,7 @@ impl Rds for RdsClient {
         RusotoError<RestoreDBInstanceFromDBSnapshotError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("RestoreDBInstanceFromDBSnapshot");
         let mut params = Params::new();
 
         params.put("Action", "RestoreDBInstanceFromDBSnapshot");
@@ -35103,6 +36211,7 @@ impl Rds for RdsClient {
         input: RestoreDBInstanceFromS3Message,
     ) -> Result<RestoreDBInstanceFromS3Result, RusotoError<RestoreDBInstanceFromS3Error>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("RestoreDBInstanceFromS3");
         let mut params = Params::new();
 
         params.put("Action", "RestoreDBInstanceFromS3");
@@ -35155,6 +36264,7 @@ impl Rds for RdsClient {
         RusotoError<RestoreDBInstanceToPointInTimeError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("RestoreDBInstanceToPointInTime");
         let mut params = Params::new();
 
         params.put("Action", "RestoreDBInstanceToPointInTime");
@@ -35205,6 +36315,7 @@ impl Rds for RdsClient {
     ) -> Result<RevokeDBSecurityGroupIngressResult, RusotoError<RevokeDBSecurityGroupIngressError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("RevokeDBSecurityGroupIngress");
         let mut params = Params::new();
 
         params.put("Action", "RevokeDBSecurityGroupIngress");
@@ -35254,6 +36365,7 @@ impl Rds for RdsClient {
         input: StartActivityStreamRequest,
     ) -> Result<StartActivityStreamResponse, RusotoError<StartActivityStreamError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("StartActivityStream");
         let mut params = Params::new();
 
         params.put("Action", "StartActivityStream");
@@ -35303,6 +36415,7 @@ impl Rds for RdsClient {
         input: StartDBClusterMessage,
     ) -> Result<StartDBClusterResult, RusotoError<StartDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("StartDBCluster");
         let mut params = Params::new();
 
         params.put("Action", "StartDBCluster");
@@ -35350,6 +36463,7 @@ impl Rds for RdsClient {
         input: StartDBInstanceMessage,
     ) -> Result<StartDBInstanceResult, RusotoError<StartDBInstanceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("StartDBInstance");
         let mut params = Params::new();
 
         params.put("Action", "StartDBInstance");
@@ -35399,6 +36513,7 @@ impl Rds for RdsClient {
         input: StartExportTaskMessage,
     ) -> Result<ExportTask, RusotoError<StartExportTaskError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("StartExportTask");
         let mut params = Params::new();
 
         params.put("Action", "StartExportTask");
@@ -35445,6 +36560,7 @@ impl Rds for RdsClient {
         input: StopActivityStreamRequest,
     ) -> Result<StopActivityStreamResponse, RusotoError<StopActivityStreamError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("StopActivityStream");
         let mut params = Params::new();
 
         params.put("Action", "StopActivityStream");
@@ -35494,6 +36610,7 @@ impl Rds for RdsClient {
         input: StopDBClusterMessage,
     ) -> Result<StopDBClusterResult, RusotoError<StopDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("StopDBCluster");
         let mut params = Params::new();
 
         params.put("Action", "StopDBCluster");
@@ -35541,6 +36658,7 @@ impl Rds for RdsClient {
         input: StopDBInstanceMessage,
     ) -> Result<StopDBInstanceResult, RusotoError<StopDBInstanceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("StopDBInstance");
         let mut params = Params::new();
 
         params.put("Action", "StopDBInstance");
@@ -35582,6 +36700,1890 @@ impl Rds for RdsClient {
         Ok(result)
     }
 }
+impl RdsClient {
+    /// Auto-paginating version of `describe_certificates`, streaming every page of results.
+    pub fn describe_certificates_pages(
+        &self,
+        input: DescribeCertificatesMessage,
+    ) -> rusoto_core::pagination::RusotoStream<CertificateMessage, DescribeCertificatesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RdsClient, input: DescribeCertificatesMessage| async move {
+                client.describe_certificates(input).await
+            },
+            |input: &DescribeCertificatesMessage, output: &CertificateMessage| {
+                Some(DescribeCertificatesMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_certificates`, streaming the `Certificates` of every page of results.
+    pub fn describe_certificates_items(
+        &self,
+        input: DescribeCertificatesMessage,
+    ) -> rusoto_core::pagination::RusotoStream<Certificate, DescribeCertificatesError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_certificates_pages(input),
+            |output: CertificateMessage| output.certificates.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_cluster_backtracks`, streaming every page of results.
+    pub fn describe_db_cluster_backtracks_pages(
+        &self,
+        input: DescribeDBClusterBacktracksMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DBClusterBacktrackMessage,
+        DescribeDBClusterBacktracksError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RdsClient, input: DescribeDBClusterBacktracksMessage| async move {
+                client.describe_db_cluster_backtracks(input).await
+            },
+            |input: &DescribeDBClusterBacktracksMessage, output: &DBClusterBacktrackMessage| {
+                Some(DescribeDBClusterBacktracksMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_cluster_backtracks`, streaming the `DBClusterBacktracks` of every page of results.
+    pub fn describe_db_cluster_backtracks_items(
+        &self,
+        input: DescribeDBClusterBacktracksMessage,
+    ) -> rusoto_core::pagination::RusotoStream<DBClusterBacktrack, DescribeDBClusterBacktracksError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_db_cluster_backtracks_pages(input),
+            |output: DBClusterBacktrackMessage| output.db_cluster_backtracks.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_cluster_endpoints`, streaming every page of results.
+    pub fn describe_db_cluster_endpoints_pages(
+        &self,
+        input: DescribeDBClusterEndpointsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DBClusterEndpointMessage,
+        DescribeDBClusterEndpointsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RdsClient, input: DescribeDBClusterEndpointsMessage| async move {
+                client.describe_db_cluster_endpoints(input).await
+            },
+            |input: &DescribeDBClusterEndpointsMessage, output: &DBClusterEndpointMessage| {
+                Some(DescribeDBClusterEndpointsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_cluster_endpoints`, streaming the `DBClusterEndpoints` of every page of results.
+    pub fn describe_db_cluster_endpoints_items(
+        &self,
+        input: DescribeDBClusterEndpointsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<DBClusterEndpoint, DescribeDBClusterEndpointsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_db_cluster_endpoints_pages(input),
+            |output: DBClusterEndpointMessage| output.db_cluster_endpoints.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_cluster_parameter_groups`, streaming every page of results.
+    pub fn describe_db_cluster_parameter_groups_pages(
+        &self,
+        input: DescribeDBClusterParameterGroupsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DBClusterParameterGroupsMessage,
+        DescribeDBClusterParameterGroupsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RdsClient, input: DescribeDBClusterParameterGroupsMessage| async move {
+                client.describe_db_cluster_parameter_groups(input).await
+            },
+            |input: &DescribeDBClusterParameterGroupsMessage,
+             output: &DBClusterParameterGroupsMessage| {
+                Some(DescribeDBClusterParameterGroupsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_cluster_parameter_groups`, streaming the `DBClusterParameterGroups` of every page of results.
+    pub fn describe_db_cluster_parameter_groups_items(
+        &self,
+        input: DescribeDBClusterParameterGroupsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DBClusterParameterGroup,
+        DescribeDBClusterParameterGroupsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_db_cluster_parameter_groups_pages(input),
+            |output: DBClusterParameterGroupsMessage| {
+                output.db_cluster_parameter_groups.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_cluster_parameters`, streaming every page of results.
+    pub fn describe_db_cluster_parameters_pages(
+        &self,
+        input: DescribeDBClusterParametersMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DBClusterParameterGroupDetails,
+        DescribeDBClusterParametersError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RdsClient, input: DescribeDBClusterParametersMessage| async move {
+                client.describe_db_cluster_parameters(input).await
+            },
+            |input: &DescribeDBClusterParametersMessage,
+             output: &DBClusterParameterGroupDetails| {
+                Some(DescribeDBClusterParametersMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_cluster_parameters`, streaming the `Parameters` of every page of results.
+    pub fn describe_db_cluster_parameters_items(
+        &self,
+        input: DescribeDBClusterParametersMessage,
+    ) -> rusoto_core::pagination::RusotoStream<Parameter, DescribeDBClusterParametersError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_db_cluster_parameters_pages(input),
+            |output: DBClusterParameterGroupDetails| output.parameters.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_cluster_snapshots`, streaming every page of results.
+    pub fn describe_db_cluster_snapshots_pages(
+        &self,
+        input: DescribeDBClusterSnapshotsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DBClusterSnapshotMessage,
+        DescribeDBClusterSnapshotsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RdsClient, input: DescribeDBClusterSnapshotsMessage| async move {
+                client.describe_db_cluster_snapshots(input).await
+            },
+            |input: &DescribeDBClusterSnapshotsMessage, output: &DBClusterSnapshotMessage| {
+                Some(DescribeDBClusterSnapshotsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_cluster_snapshots`, streaming the `DBClusterSnapshots` of every page of results.
+    pub fn describe_db_cluster_snapshots_items(
+        &self,
+        input: DescribeDBClusterSnapshotsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<DBClusterSnapshot, DescribeDBClusterSnapshotsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_db_cluster_snapshots_pages(input),
+            |output: DBClusterSnapshotMessage| output.db_cluster_snapshots.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_clusters`, streaming every page of results.
+    pub fn describe_db_clusters_pages(
+        &self,
+        input: DescribeDBClustersMessage,
+    ) -> rusoto_core::pagination::RusotoStream<DBClusterMessage, DescribeDBClustersError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RdsClient, input: DescribeDBClustersMessage| async move {
+                client.describe_db_clusters(input).await
+            },
+            |input: &DescribeDBClustersMessage, output: &DBClusterMessage| {
+                Some(DescribeDBClustersMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_clusters`, streaming the `DBClusters` of every page of results.
+    pub fn describe_db_clusters_items(
+        &self,
+        input: DescribeDBClustersMessage,
+    ) -> rusoto_core::pagination::RusotoStream<DBCluster, DescribeDBClustersError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_db_clusters_pages(input),
+            |output: DBClusterMessage| output.db_clusters.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_engine_versions`, streaming every page of results.
+    pub fn describe_db_engine_versions_pages(
+        &self,
+        input: DescribeDBEngineVersionsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<DBEngineVersionMessage, DescribeDBEngineVersionsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RdsClient, input: DescribeDBEngineVersionsMessage| async move {
+                client.describe_db_engine_versions(input).await
+            },
+            |input: &DescribeDBEngineVersionsMessage, output: &DBEngineVersionMessage| {
+                Some(DescribeDBEngineVersionsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_engine_versions`, streaming the `DBEngineVersions` of every page of results.
+    pub fn describe_db_engine_versions_items(
+        &self,
+        input: DescribeDBEngineVersionsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<DBEngineVersion, DescribeDBEngineVersionsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_db_engine_versions_pages(input),
+            |output: DBEngineVersionMessage| output.db_engine_versions.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_instance_automated_backups`, streaming every page of results.
+    pub fn describe_db_instance_automated_backups_pages(
+        &self,
+        input: DescribeDBInstanceAutomatedBackupsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DBInstanceAutomatedBackupMessage,
+        DescribeDBInstanceAutomatedBackupsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RdsClient, input: DescribeDBInstanceAutomatedBackupsMessage| async move {
+                client.describe_db_instance_automated_backups(input).await
+            },
+            |input: &DescribeDBInstanceAutomatedBackupsMessage,
+             output: &DBInstanceAutomatedBackupMessage| {
+                Some(DescribeDBInstanceAutomatedBackupsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_instance_automated_backups`, streaming the `DBInstanceAutomatedBackups` of every page of results.
+    pub fn describe_db_instance_automated_backups_items(
+        &self,
+        input: DescribeDBInstanceAutomatedBackupsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DBInstanceAutomatedBackup,
+        DescribeDBInstanceAutomatedBackupsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_db_instance_automated_backups_pages(input),
+            |output: DBInstanceAutomatedBackupMessage| {
+                output.db_instance_automated_backups.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_instances`, streaming every page of results.
+    pub fn describe_db_instances_pages(
+        &self,
+        input: DescribeDBInstancesMessage,
+    ) -> rusoto_core::pagination::RusotoStream<DBInstanceMessage, DescribeDBInstancesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RdsClient, input: DescribeDBInstancesMessage| async move {
+                client.describe_db_instances(input).await
+            },
+            |input: &DescribeDBInstancesMessage, output: &DBInstanceMessage| {
+                Some(DescribeDBInstancesMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_instances`, streaming the `DBInstances` of every page of results.
+    pub fn describe_db_instances_items(
+        &self,
+        input: DescribeDBInstancesMessage,
+    ) -> rusoto_core::pagination::RusotoStream<DBInstance, DescribeDBInstancesError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_db_instances_pages(input),
+            |output: DBInstanceMessage| output.db_instances.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_log_files`, streaming every page of results.
+    pub fn describe_db_log_files_pages(
+        &self,
+        input: DescribeDBLogFilesMessage,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeDBLogFilesResponse, DescribeDBLogFilesError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RdsClient, input: DescribeDBLogFilesMessage| async move {
+                client.describe_db_log_files(input).await
+            },
+            |input: &DescribeDBLogFilesMessage, output: &DescribeDBLogFilesResponse| {
+                Some(DescribeDBLogFilesMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_log_files`, streaming the `DescribeDBLogFiles` of every page of results.
+    pub fn describe_db_log_files_items(
+        &self,
+        input: DescribeDBLogFilesMessage,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeDBLogFilesDetails, DescribeDBLogFilesError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_db_log_files_pages(input),
+            |output: DescribeDBLogFilesResponse| output.describe_db_log_files.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_parameter_groups`, streaming every page of results.
+    pub fn describe_db_parameter_groups_pages(
+        &self,
+        input: DescribeDBParameterGroupsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DBParameterGroupsMessage,
+        DescribeDBParameterGroupsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RdsClient, input: DescribeDBParameterGroupsMessage| async move {
+                client.describe_db_parameter_groups(input).await
+            },
+            |input: &DescribeDBParameterGroupsMessage, output: &DBParameterGroupsMessage| {
+                Some(DescribeDBParameterGroupsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_parameter_groups`, streaming the `DBParameterGroups` of every page of results.
+    pub fn describe_db_parameter_groups_items(
+        &self,
+        input: DescribeDBParameterGroupsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<DBParameterGroup, DescribeDBParameterGroupsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_db_parameter_groups_pages(input),
+            |output: DBParameterGroupsMessage| output.db_parameter_groups.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_parameters`, streaming every page of results.
+    pub fn describe_db_parameters_pages(
+        &self,
+        input: DescribeDBParametersMessage,
+    ) -> rusoto_core::pagination::RusotoStream<DBParameterGroupDetails, DescribeDBParametersError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RdsClient, input: DescribeDBParametersMessage| async move {
+                client.describe_db_parameters(input).await
+            },
+            |input: &DescribeDBParametersMessage, output: &DBParameterGroupDetails| {
+                Some(DescribeDBParametersMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_parameters`, streaming the `Parameters` of every page of results.
+    pub fn describe_db_parameters_items(
+        &self,
+        input: DescribeDBParametersMessage,
+    ) -> rusoto_core::pagination::RusotoStream<Parameter, DescribeDBParametersError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_db_parameters_pages(input),
+            |output: DBParameterGroupDetails| output.parameters.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_proxies`, streaming every page of results.
+    pub fn describe_db_proxies_pages(
+        &self,
+        input: DescribeDBProxiesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeDBProxiesResponse, DescribeDBProxiesError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RdsClient, input: DescribeDBProxiesRequest| async move {
+                client.describe_db_proxies(input).await
+            },
+            |input: &DescribeDBProxiesRequest, output: &DescribeDBProxiesResponse| {
+                Some(DescribeDBProxiesRequest {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_proxies`, streaming the `DBProxies` of every page of results.
+    pub fn describe_db_proxies_items(
+        &self,
+        input: DescribeDBProxiesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DBProxy, DescribeDBProxiesError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_db_proxies_pages(input),
+            |output: DescribeDBProxiesResponse| output.db_proxies.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_proxy_target_groups`, streaming every page of results.
+    pub fn describe_db_proxy_target_groups_pages(
+        &self,
+        input: DescribeDBProxyTargetGroupsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeDBProxyTargetGroupsResponse,
+        DescribeDBProxyTargetGroupsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RdsClient, input: DescribeDBProxyTargetGroupsRequest| async move {
+                client.describe_db_proxy_target_groups(input).await
+            },
+            |input: &DescribeDBProxyTargetGroupsRequest,
+             output: &DescribeDBProxyTargetGroupsResponse| {
+                Some(DescribeDBProxyTargetGroupsRequest {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_proxy_target_groups`, streaming the `TargetGroups` of every page of results.
+    pub fn describe_db_proxy_target_groups_items(
+        &self,
+        input: DescribeDBProxyTargetGroupsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DBProxyTargetGroup, DescribeDBProxyTargetGroupsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_db_proxy_target_groups_pages(input),
+            |output: DescribeDBProxyTargetGroupsResponse| output.target_groups.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_proxy_targets`, streaming every page of results.
+    pub fn describe_db_proxy_targets_pages(
+        &self,
+        input: DescribeDBProxyTargetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeDBProxyTargetsResponse,
+        DescribeDBProxyTargetsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RdsClient, input: DescribeDBProxyTargetsRequest| async move {
+                client.describe_db_proxy_targets(input).await
+            },
+            |input: &DescribeDBProxyTargetsRequest, output: &DescribeDBProxyTargetsResponse| {
+                Some(DescribeDBProxyTargetsRequest {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_proxy_targets`, streaming the `Targets` of every page of results.
+    pub fn describe_db_proxy_targets_items(
+        &self,
+        input: DescribeDBProxyTargetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DBProxyTarget, DescribeDBProxyTargetsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_db_proxy_targets_pages(input),
+            |output: DescribeDBProxyTargetsResponse| output.targets.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_security_groups`, streaming every page of results.
+    pub fn describe_db_security_groups_pages(
+        &self,
+        input: DescribeDBSecurityGroupsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<DBSecurityGroupMessage, DescribeDBSecurityGroupsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RdsClient, input: DescribeDBSecurityGroupsMessage| async move {
+                client.describe_db_security_groups(input).await
+            },
+            |input: &DescribeDBSecurityGroupsMessage, output: &DBSecurityGroupMessage| {
+                Some(DescribeDBSecurityGroupsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_security_groups`, streaming the `DBSecurityGroups` of every page of results.
+    pub fn describe_db_security_groups_items(
+        &self,
+        input: DescribeDBSecurityGroupsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<DBSecurityGroup, DescribeDBSecurityGroupsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_db_security_groups_pages(input),
+            |output: DBSecurityGroupMessage| output.db_security_groups.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_snapshots`, streaming every page of results.
+    pub fn describe_db_snapshots_pages(
+        &self,
+        input: DescribeDBSnapshotsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<DBSnapshotMessage, DescribeDBSnapshotsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RdsClient, input: DescribeDBSnapshotsMessage| async move {
+                client.describe_db_snapshots(input).await
+            },
+            |input: &DescribeDBSnapshotsMessage, output: &DBSnapshotMessage| {
+                Some(DescribeDBSnapshotsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_snapshots`, streaming the `DBSnapshots` of every page of results.
+    pub fn describe_db_snapshots_items(
+        &self,
+        input: DescribeDBSnapshotsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<DBSnapshot, DescribeDBSnapshotsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_db_snapshots_pages(input),
+            |output: DBSnapshotMessage| output.db_snapshots.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_subnet_groups`, streaming every page of results.
+    pub fn describe_db_subnet_groups_pages(
+        &self,
+        input: DescribeDBSubnetGroupsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<DBSubnetGroupMessage, DescribeDBSubnetGroupsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RdsClient, input: DescribeDBSubnetGroupsMessage| async move {
+                client.describe_db_subnet_groups(input).await
+            },
+            |input: &DescribeDBSubnetGroupsMessage, output: &DBSubnetGroupMessage| {
+                Some(DescribeDBSubnetGroupsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_db_subnet_groups`, streaming the `DBSubnetGroups` of every page of results.
+    pub fn describe_db_subnet_groups_items(
+        &self,
+        input: DescribeDBSubnetGroupsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<DBSubnetGroup, DescribeDBSubnetGroupsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_db_subnet_groups_pages(input),
+            |output: DBSubnetGroupMessage| output.db_subnet_groups.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_engine_default_cluster_parameters`, streaming every page of results.
+    pub fn describe_engine_default_cluster_parameters_pages(
+        &self,
+        input: DescribeEngineDefaultClusterParametersMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeEngineDefaultClusterParametersResult,
+        DescribeEngineDefaultClusterParametersError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RdsClient, input: DescribeEngineDefaultClusterParametersMessage| async move {
+                client
+                    .describe_engine_default_cluster_parameters(input)
+                    .await
+            },
+            |input: &DescribeEngineDefaultClusterParametersMessage,
+             output: &DescribeEngineDefaultClusterParametersResult| {
+                Some(DescribeEngineDefaultClusterParametersMessage {
+                    marker: Some(
+                        output
+                            .engine_defaults
+                            .as_ref()
+                            .and_then(|v| v.marker.clone())?,
+                    ),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_engine_default_cluster_parameters`, streaming the `EngineDefaults.Parameters` of every page of results.
+    pub fn describe_engine_default_cluster_parameters_items(
+        &self,
+        input: DescribeEngineDefaultClusterParametersMessage,
+    ) -> rusoto_core::pagination::RusotoStream<Parameter, DescribeEngineDefaultClusterParametersError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_engine_default_cluster_parameters_pages(input),
+            |output: DescribeEngineDefaultClusterParametersResult| {
+                output
+                    .engine_defaults
+                    .and_then(|v| v.parameters)
+                    .unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_engine_default_parameters`, streaming every page of results.
+    pub fn describe_engine_default_parameters_pages(
+        &self,
+        input: DescribeEngineDefaultParametersMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeEngineDefaultParametersResult,
+        DescribeEngineDefaultParametersError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RdsClient, input: DescribeEngineDefaultParametersMessage| async move {
+                client.describe_engine_default_parameters(input).await
+            },
+            |input: &DescribeEngineDefaultParametersMessage,
+             output: &DescribeEngineDefaultParametersResult| {
+                Some(DescribeEngineDefaultParametersMessage {
+                    marker: Some(
+                        output
+                            .engine_defaults
+                            .as_ref()
+                            .and_then(|v| v.marker.clone())?,
+                    ),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_engine_default_parameters`, streaming the `EngineDefaults.Parameters` of every page of results.
+    pub fn describe_engine_default_parameters_items(
+        &self,
+        input: DescribeEngineDefaultParametersMessage,
+    ) -> rusoto_core::pagination::RusotoStream<Parameter, DescribeEngineDefaultParametersError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_engine_default_parameters_pages(input),
+            |output: DescribeEngineDefaultParametersResult| {
+                output
+                    .engine_defaults
+                    .and_then(|v| v.parameters)
+                    .unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_event_subscriptions`, streaming every page of results.
+    pub fn describe_event_subscriptions_pages(
+        &self,
+        input: DescribeEventSubscriptionsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        EventSubscriptionsMessage,
+        DescribeEventSubscriptionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RdsClient, input: DescribeEventSubscriptionsMessage| async move {
+                client.describe_event_subscriptions(input).await
+            },
+            |input: &DescribeEventSubscriptionsMessage, output: &EventSubscriptionsMessage| {
+                Some(DescribeEventSubscriptionsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_event_subscriptions`, streaming the `EventSubscriptionsList` of every page of results.
+    pub fn describe_event_subscriptions_items(
+        &self,
+        input: DescribeEventSubscriptionsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<EventSubscription, DescribeEventSubscriptionsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_event_subscriptions_pages(input),
+            |output: EventSubscriptionsMessage| output.event_subscriptions_list.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_events`, streaming every page of results.
+    pub fn describe_events_pages(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<EventsMessage, DescribeEventsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RdsClient, input: DescribeEventsMessage| async move {
+                client.describe_events(input).await
+            },
+            |input: &DescribeEventsMessage, output: &EventsMessage| {
+                Some(DescribeEventsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_events`, streaming the `Events` of every page of results.
+    pub fn describe_events_items(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<Event, DescribeEventsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_events_pages(input),
+            |output: EventsMessage| output.events.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_export_tasks`, streaming every page of results.
+    pub fn describe_export_tasks_pages(
+        &self,
+        input: DescribeExportTasksMessage,
+    ) -> rusoto_core::pagination::RusotoStream<ExportTasksMessage, DescribeExportTasksError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RdsClient, input: DescribeExportTasksMessage| async move {
+                client.describe_export_tasks(input).await
+            },
+            |input: &DescribeExportTasksMessage, output: &ExportTasksMessage| {
+                Some(DescribeExportTasksMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_export_tasks`, streaming the `ExportTasks` of every page of results.
+    pub fn describe_export_tasks_items(
+        &self,
+        input: DescribeExportTasksMessage,
+    ) -> rusoto_core::pagination::RusotoStream<ExportTask, DescribeExportTasksError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_export_tasks_pages(input),
+            |output: ExportTasksMessage| output.export_tasks.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_global_clusters`, streaming every page of results.
+    pub fn describe_global_clusters_pages(
+        &self,
+        input: DescribeGlobalClustersMessage,
+    ) -> rusoto_core::pagination::RusotoStream<GlobalClustersMessage, DescribeGlobalClustersError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RdsClient, input: DescribeGlobalClustersMessage| async move {
+                client.describe_global_clusters(input).await
+            },
+            |input: &DescribeGlobalClustersMessage, output: &GlobalClustersMessage| {
+                Some(DescribeGlobalClustersMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_global_clusters`, streaming the `GlobalClusters` of every page of results.
+    pub fn describe_global_clusters_items(
+        &self,
+        input: DescribeGlobalClustersMessage,
+    ) -> rusoto_core::pagination::RusotoStream<GlobalCluster, DescribeGlobalClustersError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_global_clusters_pages(input),
+            |output: GlobalClustersMessage| output.global_clusters.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_option_group_options`, streaming every page of results.
+    pub fn describe_option_group_options_pages(
+        &self,
+        input: DescribeOptionGroupOptionsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        OptionGroupOptionsMessage,
+        DescribeOptionGroupOptionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RdsClient, input: DescribeOptionGroupOptionsMessage| async move {
+                client.describe_option_group_options(input).await
+            },
+            |input: &DescribeOptionGroupOptionsMessage, output: &OptionGroupOptionsMessage| {
+                Some(DescribeOptionGroupOptionsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_option_group_options`, streaming the `OptionGroupOptions` of every page of results.
+    pub fn describe_option_group_options_items(
+        &self,
+        input: DescribeOptionGroupOptionsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<OptionGroupOption, DescribeOptionGroupOptionsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_option_group_options_pages(input),
+            |output: OptionGroupOptionsMessage| output.option_group_options.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_option_groups`, streaming every page of results.
+    pub fn describe_option_groups_pages(
+        &self,
+        input: DescribeOptionGroupsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<OptionGroups, DescribeOptionGroupsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RdsClient, input: DescribeOptionGroupsMessage| async move {
+                client.describe_option_groups(input).await
+            },
+            |input: &DescribeOptionGroupsMessage, output: &OptionGroups| {
+                Some(DescribeOptionGroupsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_option_groups`, streaming the `OptionGroupsList` of every page of results.
+    pub fn describe_option_groups_items(
+        &self,
+        input: DescribeOptionGroupsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<OptionGroup, DescribeOptionGroupsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_option_groups_pages(input),
+            |output: OptionGroups| output.option_groups_list.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_orderable_db_instance_options`, streaming every page of results.
+    pub fn describe_orderable_db_instance_options_pages(
+        &self,
+        input: DescribeOrderableDBInstanceOptionsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        OrderableDBInstanceOptionsMessage,
+        DescribeOrderableDBInstanceOptionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RdsClient, input: DescribeOrderableDBInstanceOptionsMessage| async move {
+                client.describe_orderable_db_instance_options(input).await
+            },
+            |input: &DescribeOrderableDBInstanceOptionsMessage,
+             output: &OrderableDBInstanceOptionsMessage| {
+                Some(DescribeOrderableDBInstanceOptionsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_orderable_db_instance_options`, streaming the `OrderableDBInstanceOptions` of every page of results.
+    pub fn describe_orderable_db_instance_options_items(
+        &self,
+        input: DescribeOrderableDBInstanceOptionsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        OrderableDBInstanceOption,
+        DescribeOrderableDBInstanceOptionsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_orderable_db_instance_options_pages(input),
+            |output: OrderableDBInstanceOptionsMessage| {
+                output.orderable_db_instance_options.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_pending_maintenance_actions`, streaming every page of results.
+    pub fn describe_pending_maintenance_actions_pages(
+        &self,
+        input: DescribePendingMaintenanceActionsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        PendingMaintenanceActionsMessage,
+        DescribePendingMaintenanceActionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RdsClient, input: DescribePendingMaintenanceActionsMessage| async move {
+                client.describe_pending_maintenance_actions(input).await
+            },
+            |input: &DescribePendingMaintenanceActionsMessage,
+             output: &PendingMaintenanceActionsMessage| {
+                Some(DescribePendingMaintenanceActionsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_pending_maintenance_actions`, streaming the `PendingMaintenanceActions` of every page of results.
+    pub fn describe_pending_maintenance_actions_items(
+        &self,
+        input: DescribePendingMaintenanceActionsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ResourcePendingMaintenanceActions,
+        DescribePendingMaintenanceActionsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_pending_maintenance_actions_pages(input),
+            |output: PendingMaintenanceActionsMessage| {
+                output.pending_maintenance_actions.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_reserved_db_instances`, streaming every page of results.
+    pub fn describe_reserved_db_instances_pages(
+        &self,
+        input: DescribeReservedDBInstancesMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ReservedDBInstanceMessage,
+        DescribeReservedDBInstancesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RdsClient, input: DescribeReservedDBInstancesMessage| async move {
+                client.describe_reserved_db_instances(input).await
+            },
+            |input: &DescribeReservedDBInstancesMessage, output: &ReservedDBInstanceMessage| {
+                Some(DescribeReservedDBInstancesMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_reserved_db_instances`, streaming the `ReservedDBInstances` of every page of results.
+    pub fn describe_reserved_db_instances_items(
+        &self,
+        input: DescribeReservedDBInstancesMessage,
+    ) -> rusoto_core::pagination::RusotoStream<ReservedDBInstance, DescribeReservedDBInstancesError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_reserved_db_instances_pages(input),
+            |output: ReservedDBInstanceMessage| output.reserved_db_instances.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_reserved_db_instances_offerings`, streaming every page of results.
+    pub fn describe_reserved_db_instances_offerings_pages(
+        &self,
+        input: DescribeReservedDBInstancesOfferingsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ReservedDBInstancesOfferingMessage,
+        DescribeReservedDBInstancesOfferingsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RdsClient, input: DescribeReservedDBInstancesOfferingsMessage| async move {
+                client.describe_reserved_db_instances_offerings(input).await
+            },
+            |input: &DescribeReservedDBInstancesOfferingsMessage,
+             output: &ReservedDBInstancesOfferingMessage| {
+                Some(DescribeReservedDBInstancesOfferingsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_reserved_db_instances_offerings`, streaming the `ReservedDBInstancesOfferings` of every page of results.
+    pub fn describe_reserved_db_instances_offerings_items(
+        &self,
+        input: DescribeReservedDBInstancesOfferingsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ReservedDBInstancesOffering,
+        DescribeReservedDBInstancesOfferingsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_reserved_db_instances_offerings_pages(input),
+            |output: ReservedDBInstancesOfferingMessage| {
+                output.reserved_db_instances_offerings.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_source_regions`, streaming every page of results.
+    pub fn describe_source_regions_pages(
+        &self,
+        input: DescribeSourceRegionsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<SourceRegionMessage, DescribeSourceRegionsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RdsClient, input: DescribeSourceRegionsMessage| async move {
+                client.describe_source_regions(input).await
+            },
+            |input: &DescribeSourceRegionsMessage, output: &SourceRegionMessage| {
+                Some(DescribeSourceRegionsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_source_regions`, streaming the `SourceRegions` of every page of results.
+    pub fn describe_source_regions_items(
+        &self,
+        input: DescribeSourceRegionsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<SourceRegion, DescribeSourceRegionsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_source_regions_pages(input),
+            |output: SourceRegionMessage| output.source_regions.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `download_db_log_file_portion`, streaming every page of results.
+    pub fn download_db_log_file_portion_pages(
+        &self,
+        input: DownloadDBLogFilePortionMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DownloadDBLogFilePortionDetails,
+        DownloadDBLogFilePortionError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RdsClient, input: DownloadDBLogFilePortionMessage| async move {
+                client.download_db_log_file_portion(input).await
+            },
+            |input: &DownloadDBLogFilePortionMessage, output: &DownloadDBLogFilePortionDetails| {
+                if !output.additional_data_pending.unwrap_or(false) {
+                    return None;
+                }
+                Some(DownloadDBLogFilePortionMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+}
+impl RdsClient {
+    /// Waits until `describe_db_clusters` reports the `DBClusterAvailable` state, polling every 30 seconds up to 60 times.
+    pub async fn wait_until_db_cluster_available(
+        &self,
+        input: DescribeDBClustersMessage,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeDBClustersError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(30), 60);
+        self.wait_until_db_cluster_available_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_db_cluster_available`, polling as often and as many times as `config` says.
+    pub async fn wait_until_db_cluster_available_with_config(
+        &self,
+        input: DescribeDBClustersMessage,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeDBClustersError>> {
+        rusoto_core::waiter::wait(
+            "DBClusterAvailable",
+            &config,
+            || self.describe_db_clusters(input.clone()),
+            |result: &Result<DBClusterMessage, RusotoError<DescribeDBClustersError>>| match result {
+                Ok(output)
+                    if rusoto_core::waiter::all_match(
+                        output
+                            .db_clusters
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.status.iter()),
+                        |v| v == "available",
+                    ) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if output
+                        .db_clusters
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| v == "deleted") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .db_clusters
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| v == "deleting") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .db_clusters
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| v == "failed") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .db_clusters
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| v == "incompatible-restore") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .db_clusters
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| v == "incompatible-parameters") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+
+    /// Waits until `describe_db_clusters` reports the `DBClusterDeleted` state, polling every 30 seconds up to 60 times.
+    pub async fn wait_until_db_cluster_deleted(
+        &self,
+        input: DescribeDBClustersMessage,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeDBClustersError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(30), 60);
+        self.wait_until_db_cluster_deleted_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_db_cluster_deleted`, polling as often and as many times as `config` says.
+    pub async fn wait_until_db_cluster_deleted_with_config(
+        &self,
+        input: DescribeDBClustersMessage,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeDBClustersError>> {
+        rusoto_core::waiter::wait(
+            "DBClusterDeleted",
+            &config,
+            || self.describe_db_clusters(input.clone()),
+            |result: &Result<DBClusterMessage, RusotoError<DescribeDBClustersError>>| match result {
+                Ok(output) if output.db_clusters.iter().next().map_or(0, |v| v.len()) == 0 => {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Err(RusotoError::Service(DescribeDBClustersError::DBClusterNotFoundFault(_))) => {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Err(RusotoError::Unknown(response))
+                    if rusoto_core::retry::error_code(response).as_deref()
+                        == Some("DBClusterNotFoundFault") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if output
+                        .db_clusters
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| v == "creating") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .db_clusters
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| v == "modifying") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .db_clusters
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| v == "rebooting") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .db_clusters
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| v == "resetting-master-credentials") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+
+    /// Waits until `describe_db_cluster_snapshots` reports the `DBClusterSnapshotAvailable` state, polling every 30 seconds up to 60 times.
+    pub async fn wait_until_db_cluster_snapshot_available(
+        &self,
+        input: DescribeDBClusterSnapshotsMessage,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeDBClusterSnapshotsError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(30), 60);
+        self.wait_until_db_cluster_snapshot_available_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_db_cluster_snapshot_available`, polling as often and as many times as `config` says.
+    pub async fn wait_until_db_cluster_snapshot_available_with_config(
+        &self,
+        input: DescribeDBClusterSnapshotsMessage,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeDBClusterSnapshotsError>> {
+        rusoto_core::waiter::wait(
+            "DBClusterSnapshotAvailable",
+            &config,
+            || self.describe_db_cluster_snapshots(input.clone()),
+            |result: &Result<
+                DBClusterSnapshotMessage,
+                RusotoError<DescribeDBClusterSnapshotsError>,
+            >| match result {
+                Ok(output)
+                    if rusoto_core::waiter::all_match(
+                        output
+                            .db_cluster_snapshots
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.status.iter()),
+                        |v| v == "available",
+                    ) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if output
+                        .db_cluster_snapshots
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| v == "deleted") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .db_cluster_snapshots
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| v == "deleting") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .db_cluster_snapshots
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| v == "failed") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .db_cluster_snapshots
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| v == "incompatible-restore") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .db_cluster_snapshots
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| v == "incompatible-parameters") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+
+    /// Waits until `describe_db_cluster_snapshots` reports the `DBClusterSnapshotDeleted` state, polling every 30 seconds up to 60 times.
+    pub async fn wait_until_db_cluster_snapshot_deleted(
+        &self,
+        input: DescribeDBClusterSnapshotsMessage,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeDBClusterSnapshotsError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(30), 60);
+        self.wait_until_db_cluster_snapshot_deleted_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_db_cluster_snapshot_deleted`, polling as often and as many times as `config` says.
+    pub async fn wait_until_db_cluster_snapshot_deleted_with_config(
+        &self,
+        input: DescribeDBClusterSnapshotsMessage,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeDBClusterSnapshotsError>> {
+        rusoto_core::waiter::wait(
+            "DBClusterSnapshotDeleted",
+            &config,
+            || self.describe_db_cluster_snapshots(input.clone()),
+            |result: &Result<
+                DBClusterSnapshotMessage,
+                RusotoError<DescribeDBClusterSnapshotsError>,
+            >| match result {
+                Ok(output)
+                    if output
+                        .db_cluster_snapshots
+                        .iter()
+                        .next()
+                        .map_or(0, |v| v.len())
+                        == 0 =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Err(RusotoError::Service(
+                    DescribeDBClusterSnapshotsError::DBClusterSnapshotNotFoundFault(_),
+                )) => Some(rusoto_core::waiter::WaiterState::Success),
+                Err(RusotoError::Unknown(response))
+                    if rusoto_core::retry::error_code(response).as_deref()
+                        == Some("DBClusterSnapshotNotFoundFault") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if output
+                        .db_cluster_snapshots
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| v == "creating") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .db_cluster_snapshots
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| v == "modifying") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .db_cluster_snapshots
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| v == "rebooting") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .db_cluster_snapshots
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| v == "resetting-master-credentials") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+
+    /// Waits until `describe_db_instances` reports the `DBInstanceAvailable` state, polling every 30 seconds up to 60 times.
+    pub async fn wait_until_db_instance_available(
+        &self,
+        input: DescribeDBInstancesMessage,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeDBInstancesError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(30), 60);
+        self.wait_until_db_instance_available_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_db_instance_available`, polling as often and as many times as `config` says.
+    pub async fn wait_until_db_instance_available_with_config(
+        &self,
+        input: DescribeDBInstancesMessage,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeDBInstancesError>> {
+        rusoto_core::waiter::wait(
+            "DBInstanceAvailable",
+            &config,
+            || self.describe_db_instances(input.clone()),
+            |result: &Result<DBInstanceMessage, RusotoError<DescribeDBInstancesError>>| match result
+            {
+                Ok(output)
+                    if rusoto_core::waiter::all_match(
+                        output
+                            .db_instances
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.db_instance_status.iter()),
+                        |v| v == "available",
+                    ) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if output
+                        .db_instances
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.db_instance_status.iter())
+                        .any(|v| v == "deleted") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .db_instances
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.db_instance_status.iter())
+                        .any(|v| v == "deleting") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .db_instances
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.db_instance_status.iter())
+                        .any(|v| v == "failed") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .db_instances
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.db_instance_status.iter())
+                        .any(|v| v == "incompatible-restore") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .db_instances
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.db_instance_status.iter())
+                        .any(|v| v == "incompatible-parameters") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+
+    /// Waits until `describe_db_instances` reports the `DBInstanceDeleted` state, polling every 30 seconds up to 60 times.
+    pub async fn wait_until_db_instance_deleted(
+        &self,
+        input: DescribeDBInstancesMessage,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeDBInstancesError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(30), 60);
+        self.wait_until_db_instance_deleted_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_db_instance_deleted`, polling as often and as many times as `config` says.
+    pub async fn wait_until_db_instance_deleted_with_config(
+        &self,
+        input: DescribeDBInstancesMessage,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeDBInstancesError>> {
+        rusoto_core::waiter::wait(
+            "DBInstanceDeleted",
+            &config,
+            || self.describe_db_instances(input.clone()),
+            |result: &Result<DBInstanceMessage, RusotoError<DescribeDBInstancesError>>| match result
+            {
+                Ok(output) if output.db_instances.iter().next().map_or(0, |v| v.len()) == 0 => {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Err(RusotoError::Service(DescribeDBInstancesError::DBInstanceNotFoundFault(_))) => {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Err(RusotoError::Unknown(response))
+                    if rusoto_core::retry::error_code(response).as_deref()
+                        == Some("DBInstanceNotFound") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if output
+                        .db_instances
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.db_instance_status.iter())
+                        .any(|v| v == "creating") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .db_instances
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.db_instance_status.iter())
+                        .any(|v| v == "modifying") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .db_instances
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.db_instance_status.iter())
+                        .any(|v| v == "rebooting") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .db_instances
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.db_instance_status.iter())
+                        .any(|v| v == "resetting-master-credentials") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+
+    /// Waits until `describe_db_snapshots` reports the `DBSnapshotAvailable` state, polling every 30 seconds up to 60 times.
+    pub async fn wait_until_db_snapshot_available(
+        &self,
+        input: DescribeDBSnapshotsMessage,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeDBSnapshotsError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(30), 60);
+        self.wait_until_db_snapshot_available_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_db_snapshot_available`, polling as often and as many times as `config` says.
+    pub async fn wait_until_db_snapshot_available_with_config(
+        &self,
+        input: DescribeDBSnapshotsMessage,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeDBSnapshotsError>> {
+        rusoto_core::waiter::wait(
+            "DBSnapshotAvailable",
+            &config,
+            || self.describe_db_snapshots(input.clone()),
+            |result: &Result<DBSnapshotMessage, RusotoError<DescribeDBSnapshotsError>>| match result
+            {
+                Ok(output)
+                    if rusoto_core::waiter::all_match(
+                        output
+                            .db_snapshots
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.status.iter()),
+                        |v| v == "available",
+                    ) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if output
+                        .db_snapshots
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| v == "deleted") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .db_snapshots
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| v == "deleting") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .db_snapshots
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| v == "failed") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .db_snapshots
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| v == "incompatible-restore") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .db_snapshots
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| v == "incompatible-parameters") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+
+    /// Waits until `describe_db_snapshots` reports the `DBSnapshotCompleted` state, polling every 15 seconds up to 40 times.
+    pub async fn wait_until_db_snapshot_completed(
+        &self,
+        input: DescribeDBSnapshotsMessage,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeDBSnapshotsError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(15), 40);
+        self.wait_until_db_snapshot_completed_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_db_snapshot_completed`, polling as often and as many times as `config` says.
+    pub async fn wait_until_db_snapshot_completed_with_config(
+        &self,
+        input: DescribeDBSnapshotsMessage,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeDBSnapshotsError>> {
+        rusoto_core::waiter::wait(
+            "DBSnapshotCompleted",
+            &config,
+            || self.describe_db_snapshots(input.clone()),
+            |result: &Result<DBSnapshotMessage, RusotoError<DescribeDBSnapshotsError>>| match result
+            {
+                Err(RusotoError::Service(DescribeDBSnapshotsError::DBSnapshotNotFoundFault(_))) => {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Err(RusotoError::Unknown(response))
+                    if rusoto_core::retry::error_code(response).as_deref()
+                        == Some("DBSnapshotNotFound") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if rusoto_core::waiter::all_match(
+                        output
+                            .db_snapshots
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.status.iter()),
+                        |v| v == "available",
+                    ) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+
+    /// Waits until `describe_db_snapshots` reports the `DBSnapshotDeleted` state, polling every 30 seconds up to 60 times.
+    pub async fn wait_until_db_snapshot_deleted(
+        &self,
+        input: DescribeDBSnapshotsMessage,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeDBSnapshotsError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(30), 60);
+        self.wait_until_db_snapshot_deleted_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_db_snapshot_deleted`, polling as often and as many times as `config` says.
+    pub async fn wait_until_db_snapshot_deleted_with_config(
+        &self,
+        input: DescribeDBSnapshotsMessage,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeDBSnapshotsError>> {
+        rusoto_core::waiter::wait(
+            "DBSnapshotDeleted",
+            &config,
+            || self.describe_db_snapshots(input.clone()),
+            |result: &Result<DBSnapshotMessage, RusotoError<DescribeDBSnapshotsError>>| match result
+            {
+                Ok(output) if output.db_snapshots.iter().next().map_or(0, |v| v.len()) == 0 => {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Err(RusotoError::Service(DescribeDBSnapshotsError::DBSnapshotNotFoundFault(_))) => {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Err(RusotoError::Unknown(response))
+                    if rusoto_core::retry::error_code(response).as_deref()
+                        == Some("DBSnapshotNotFound") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if output
+                        .db_snapshots
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| v == "creating") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .db_snapshots
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| v == "modifying") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .db_snapshots
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| v == "rebooting") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .db_snapshots
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| v == "resetting-master-credentials") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+}
 
 #[cfg(test)]
 mod protocol_tests {
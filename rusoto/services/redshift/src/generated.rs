@@ -210,6 +210,106 @@ impl AccountsWithRestoreAccessListDeserializer {
         })
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ActionType {
+    /// `restore-cluster`
+    RestoreCluster,
+    /// `recommend-node-config`
+    RecommendNodeConfig,
+    /// `resize-cluster`
+    ResizeCluster,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ActionType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ActionType::RestoreCluster => "restore-cluster",
+            ActionType::RecommendNodeConfig => "recommend-node-config",
+            ActionType::ResizeCluster => "resize-cluster",
+            ActionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ActionType {
+    fn default() -> ActionType {
+        ActionType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ActionType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ActionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ActionType {
+    fn from(value: &'a str) -> ActionType {
+        match value {
+            "restore-cluster" => ActionType::RestoreCluster,
+            "recommend-node-config" => ActionType::RecommendNodeConfig,
+            "resize-cluster" => ActionType::ResizeCluster,
+            _ => ActionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ActionType {
+    fn from(value: String) -> ActionType {
+        match value.as_str() {
+            "restore-cluster" => ActionType::RestoreCluster,
+            "recommend-node-config" => ActionType::RecommendNodeConfig,
+            "resize-cluster" => ActionType::ResizeCluster,
+            _ => ActionType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ActionType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ActionType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ActionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ActionType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ActionType {
+    fn deserialize<D>(deserializer: D) -> Result<ActionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct AssociatedClusterListDeserializer;
 impl AssociatedClusterListDeserializer {
     #[allow(unused_variables)]
@@ -749,7 +849,7 @@ pub struct Cluster {
     /// <p><p>The availability status of the cluster for queries. Possible values are the following:</p> <ul> <li> <p>Available - The cluster is available for queries. </p> </li> <li> <p>Unavailable - The cluster is not available for queries.</p> </li> <li> <p>Maintenance - The cluster is intermittently available for queries due to maintenance activities.</p> </li> <li> <p>Modifying - The cluster is intermittently available for queries due to changes that modify the cluster.</p> </li> <li> <p>Failed - The cluster failed and is not available for queries.</p> </li> </ul></p>
     pub cluster_availability_status: Option<String>,
     /// <p>The date and time that the cluster was created.</p>
-    pub cluster_create_time: Option<String>,
+    pub cluster_create_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The unique identifier of the cluster.</p>
     pub cluster_identifier: Option<String>,
     /// <p>The nodes in the cluster.</p>
@@ -787,7 +887,7 @@ pub struct Cluster {
     /// <p>An option that specifies whether to create the cluster with enhanced VPC routing enabled. To create a cluster that uses enhanced VPC routing, the cluster must be in a VPC. For more information, see <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/enhanced-vpc-routing.html">Enhanced VPC Routing</a> in the Amazon Redshift Cluster Management Guide.</p> <p>If this option is <code>true</code>, enhanced VPC routing is enabled. </p> <p>Default: false</p>
     pub enhanced_vpc_routing: Option<bool>,
     /// <p>The date and time when the next snapshot is expected to be taken for clusters with a valid snapshot schedule and backups enabled. </p>
-    pub expected_next_snapshot_schedule_time: Option<String>,
+    pub expected_next_snapshot_schedule_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p> The status of next expected snapshot for clusters having a valid snapshot schedule and backups enabled. Possible values are the following:</p> <ul> <li> <p>OnTrack - The next snapshot is expected to be taken on time. </p> </li> <li> <p>Pending - The next snapshot is pending to be taken. </p> </li> </ul></p>
     pub expected_next_snapshot_schedule_time_status: Option<String>,
     /// <p>A value that reports whether the Amazon Redshift cluster has finished applying any hardware security module (HSM) settings changes specified in a modify cluster command.</p> <p>Values: active, applying</p>
@@ -805,7 +905,7 @@ pub struct Cluster {
     /// <p>The status of a modify operation, if any, initiated for the cluster.</p>
     pub modify_status: Option<String>,
     /// <p>The date and time in UTC when system maintenance can begin.</p>
-    pub next_maintenance_window_start_time: Option<String>,
+    pub next_maintenance_window_start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The node type for the nodes in the cluster.</p>
     pub node_type: Option<String>,
     /// <p>The number of compute nodes in the cluster.</p>
@@ -825,7 +925,7 @@ pub struct Cluster {
     /// <p>A unique identifier for the cluster snapshot schedule.</p>
     pub snapshot_schedule_identifier: Option<String>,
     /// <p>The current state of the cluster snapshot schedule.</p>
-    pub snapshot_schedule_state: Option<String>,
+    pub snapshot_schedule_state: Option<ScheduleState>,
     /// <p>The list of tags for the cluster.</p>
     pub tags: Option<Vec<Tag>>,
     /// <p>The identifier of the VPC the cluster is in, if the cluster is in a VPC.</p>
@@ -1098,7 +1198,7 @@ pub struct ClusterAssociatedToSchedule {
     /// <p><p/></p>
     pub cluster_identifier: Option<String>,
     /// <p><p/></p>
-    pub schedule_association_state: Option<String>,
+    pub schedule_association_state: Option<ScheduleState>,
 }
 
 struct ClusterAssociatedToScheduleDeserializer;
@@ -1140,7 +1240,7 @@ pub struct ClusterCredentials {
     /// <p>A database user name that is authorized to log on to the database <code>DbName</code> using the password <code>DbPassword</code>. If the specified DbUser exists in the database, the new user name has the same database privileges as the the user named in DbUser. By default, the user is added to PUBLIC. If the <code>DbGroups</code> parameter is specifed, <code>DbUser</code> is added to the listed groups for any sessions created using these credentials.</p>
     pub db_user: Option<String>,
     /// <p>The date and time the password in <code>DbPassword</code> expires.</p>
-    pub expiration: Option<String>,
+    pub expiration: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 struct ClusterCredentialsDeserializer;
@@ -1179,7 +1279,7 @@ pub struct ClusterDbRevision {
     /// <p>A string representing the current cluster version.</p>
     pub current_database_revision: Option<String>,
     /// <p>The date on which the database revision was released.</p>
-    pub database_revision_release_date: Option<String>,
+    pub database_revision_release_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A list of <code>RevisionTarget</code> objects, where each object describes the database revision that a cluster can be updated to.</p>
     pub revision_targets: Option<Vec<RevisionTarget>>,
 }
@@ -3018,7 +3118,7 @@ pub struct CreateScheduledActionMessage {
     /// <p>If true, the schedule is enabled. If false, the scheduled action does not trigger. For more information about <code>state</code> of the scheduled action, see <a>ScheduledAction</a>. </p>
     pub enable: Option<bool>,
     /// <p>The end time in UTC of the scheduled action. After this time, the scheduled action does not trigger. For more information about this parameter, see <a>ScheduledAction</a>. </p>
-    pub end_time: Option<String>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The IAM role to assume to run the target action. For more information about this parameter, see <a>ScheduledAction</a>. </p>
     pub iam_role: String,
     /// <p>The schedule in <code>at( )</code> or <code>cron( )</code> format. For more information about this parameter, see <a>ScheduledAction</a>.</p>
@@ -3028,7 +3128,7 @@ pub struct CreateScheduledActionMessage {
     /// <p>The name of the scheduled action. The name must be unique within an account. For more information about this parameter, see <a>ScheduledAction</a>. </p>
     pub scheduled_action_name: String,
     /// <p>The start time in UTC of the scheduled action. Before this time, the scheduled action does not trigger. For more information about this parameter, see <a>ScheduledAction</a>.</p>
-    pub start_time: Option<String>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A JSON format string of the Amazon Redshift API operation with input parameters. For more information about this parameter, see <a>ScheduledAction</a>. </p>
     pub target_action: ScheduledActionType,
 }
@@ -3046,7 +3146,10 @@ impl CreateScheduledActionMessageSerializer {
             params.put(&format!("{}{}", prefix, "Enable"), &field_value);
         }
         if let Some(ref field_value) = obj.end_time {
-            params.put(&format!("{}{}", prefix, "EndTime"), &field_value);
+            params.put(
+                &format!("{}{}", prefix, "EndTime"),
+                &rusoto_core::serialization::TimestampFormat::Iso8601.format(field_value),
+            );
         }
         params.put(&format!("{}{}", prefix, "IamRole"), &obj.iam_role);
         params.put(&format!("{}{}", prefix, "Schedule"), &obj.schedule);
@@ -3061,7 +3164,10 @@ impl CreateScheduledActionMessageSerializer {
             &obj.scheduled_action_name,
         );
         if let Some(ref field_value) = obj.start_time {
-            params.put(&format!("{}{}", prefix, "StartTime"), &field_value);
+            params.put(
+                &format!("{}{}", prefix, "StartTime"),
+                &rusoto_core::serialization::TimestampFormat::Iso8601.format(field_value),
+            );
         }
         ScheduledActionTypeSerializer::serialize(
             params,
@@ -3381,11 +3487,11 @@ impl DefaultClusterParametersDeserializer {
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct DeferredMaintenanceWindow {
     /// <p> A timestamp for the end of the time period when we defer maintenance.</p>
-    pub defer_maintenance_end_time: Option<String>,
+    pub defer_maintenance_end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A unique identifier for the maintenance window.</p>
     pub defer_maintenance_identifier: Option<String>,
     /// <p> A timestamp for the beginning of the time period when we defer maintenance.</p>
-    pub defer_maintenance_start_time: Option<String>,
+    pub defer_maintenance_start_time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 struct DeferredMaintenanceWindowDeserializer;
@@ -4037,7 +4143,7 @@ pub struct DescribeClusterSnapshotsMessage {
     /// <p>The identifier of the cluster which generated the requested snapshots.</p>
     pub cluster_identifier: Option<String>,
     /// <p>A time value that requests only snapshots created at or before the specified time. The time value is specified in ISO 8601 format. For more information about ISO 8601, go to the <a href="http://en.wikipedia.org/wiki/ISO_8601">ISO8601 Wikipedia page.</a> </p> <p>Example: <code>2012-07-16T18:00:00Z</code> </p>
-    pub end_time: Option<String>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>An optional parameter that specifies the starting point to return a set of response records. When the results of a <a>DescribeClusterSnapshots</a> request exceed the value specified in <code>MaxRecords</code>, AWS returns a value in the <code>Marker</code> field of the response. You can retrieve the next set of response records by providing the returned marker value in the <code>Marker</code> parameter and retrying the request. </p>
     pub marker: Option<String>,
     /// <p>The maximum number of response records to return in each call. If the number of remaining response records exceeds the specified <code>MaxRecords</code> value, a value is returned in a <code>marker</code> field of the response. You can retrieve the next set of records by retrying the command with the returned marker value. </p> <p>Default: <code>100</code> </p> <p>Constraints: minimum 20, maximum 100.</p>
@@ -4051,7 +4157,7 @@ pub struct DescribeClusterSnapshotsMessage {
     /// <p><p/></p>
     pub sorting_entities: Option<Vec<SnapshotSortingEntity>>,
     /// <p>A value that requests only snapshots created at or after the specified time. The time value is specified in ISO 8601 format. For more information about ISO 8601, go to the <a href="http://en.wikipedia.org/wiki/ISO_8601">ISO8601 Wikipedia page.</a> </p> <p>Example: <code>2012-07-16T18:00:00Z</code> </p>
-    pub start_time: Option<String>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A tag key or keys for which you want to return all matching cluster snapshots that are associated with the specified key or keys. For example, suppose that you have snapshots that are tagged with keys called <code>owner</code> and <code>environment</code>. If you specify both of these tag keys in the request, Amazon Redshift returns a response with the snapshots that have either or both of these tag keys associated with them.</p>
     pub tag_keys: Option<Vec<String>>,
     /// <p>A tag value or values for which you want to return all matching cluster snapshots that are associated with the specified tag value or values. For example, suppose that you have snapshots that are tagged with values called <code>admin</code> and <code>test</code>. If you specify both of these tag values in the request, Amazon Redshift returns a response with the snapshots that have either or both of these tag values associated with them.</p>
@@ -4074,7 +4180,10 @@ impl DescribeClusterSnapshotsMessageSerializer {
             params.put(&format!("{}{}", prefix, "ClusterIdentifier"), &field_value);
         }
         if let Some(ref field_value) = obj.end_time {
-            params.put(&format!("{}{}", prefix, "EndTime"), &field_value);
+            params.put(
+                &format!("{}{}", prefix, "EndTime"),
+                &rusoto_core::serialization::TimestampFormat::Iso8601.format(field_value),
+            );
         }
         if let Some(ref field_value) = obj.marker {
             params.put(&format!("{}{}", prefix, "Marker"), &field_value);
@@ -4099,7 +4208,10 @@ impl DescribeClusterSnapshotsMessageSerializer {
             );
         }
         if let Some(ref field_value) = obj.start_time {
-            params.put(&format!("{}{}", prefix, "StartTime"), &field_value);
+            params.put(
+                &format!("{}{}", prefix, "StartTime"),
+                &rusoto_core::serialization::TimestampFormat::Iso8601.format(field_value),
+            );
         }
         if let Some(ref field_value) = obj.tag_keys {
             TagKeyListSerializer::serialize(
@@ -4446,7 +4558,7 @@ pub struct DescribeEventsMessage {
     /// <p>The number of minutes prior to the time of the request for which to retrieve events. For example, if the request is sent at 18:00 and you specify a duration of 60, then only events which have occurred after 17:00 will be returned.</p> <p>Default: <code>60</code> </p>
     pub duration: Option<i64>,
     /// <p>The end of the time interval for which to retrieve events, specified in ISO 8601 format. For more information about ISO 8601, go to the <a href="http://en.wikipedia.org/wiki/ISO_8601">ISO8601 Wikipedia page.</a> </p> <p>Example: <code>2009-07-08T18:00Z</code> </p>
-    pub end_time: Option<String>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>An optional parameter that specifies the starting point to return a set of response records. When the results of a <a>DescribeEvents</a> request exceed the value specified in <code>MaxRecords</code>, AWS returns a value in the <code>Marker</code> field of the response. You can retrieve the next set of response records by providing the returned marker value in the <code>Marker</code> parameter and retrying the request. </p>
     pub marker: Option<String>,
     /// <p>The maximum number of response records to return in each call. If the number of remaining response records exceeds the specified <code>MaxRecords</code> value, a value is returned in a <code>marker</code> field of the response. You can retrieve the next set of records by retrying the command with the returned marker value. </p> <p>Default: <code>100</code> </p> <p>Constraints: minimum 20, maximum 100.</p>
@@ -4454,9 +4566,9 @@ pub struct DescribeEventsMessage {
     /// <p><p>The identifier of the event source for which events will be returned. If this parameter is not specified, then all sources are included in the response.</p> <p>Constraints:</p> <p>If <i>SourceIdentifier</i> is supplied, <i>SourceType</i> must also be provided.</p> <ul> <li> <p>Specify a cluster identifier when <i>SourceType</i> is <code>cluster</code>.</p> </li> <li> <p>Specify a cluster security group name when <i>SourceType</i> is <code>cluster-security-group</code>.</p> </li> <li> <p>Specify a cluster parameter group name when <i>SourceType</i> is <code>cluster-parameter-group</code>.</p> </li> <li> <p>Specify a cluster snapshot identifier when <i>SourceType</i> is <code>cluster-snapshot</code>.</p> </li> </ul></p>
     pub source_identifier: Option<String>,
     /// <p><p>The event source to retrieve events for. If no value is specified, all events are returned.</p> <p>Constraints:</p> <p>If <i>SourceType</i> is supplied, <i>SourceIdentifier</i> must also be provided.</p> <ul> <li> <p>Specify <code>cluster</code> when <i>SourceIdentifier</i> is a cluster identifier.</p> </li> <li> <p>Specify <code>cluster-security-group</code> when <i>SourceIdentifier</i> is a cluster security group name.</p> </li> <li> <p>Specify <code>cluster-parameter-group</code> when <i>SourceIdentifier</i> is a cluster parameter group name.</p> </li> <li> <p>Specify <code>cluster-snapshot</code> when <i>SourceIdentifier</i> is a cluster snapshot identifier.</p> </li> </ul></p>
-    pub source_type: Option<String>,
+    pub source_type: Option<SourceType>,
     /// <p>The beginning of the time interval to retrieve events for, specified in ISO 8601 format. For more information about ISO 8601, go to the <a href="http://en.wikipedia.org/wiki/ISO_8601">ISO8601 Wikipedia page.</a> </p> <p>Example: <code>2009-07-08T18:00Z</code> </p>
-    pub start_time: Option<String>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// Serialize `DescribeEventsMessage` contents to a `SignedRequest`.
@@ -4472,7 +4584,10 @@ impl DescribeEventsMessageSerializer {
             params.put(&format!("{}{}", prefix, "Duration"), &field_value);
         }
         if let Some(ref field_value) = obj.end_time {
-            params.put(&format!("{}{}", prefix, "EndTime"), &field_value);
+            params.put(
+                &format!("{}{}", prefix, "EndTime"),
+                &rusoto_core::serialization::TimestampFormat::Iso8601.format(field_value),
+            );
         }
         if let Some(ref field_value) = obj.marker {
             params.put(&format!("{}{}", prefix, "Marker"), &field_value);
@@ -4487,7 +4602,10 @@ impl DescribeEventsMessageSerializer {
             params.put(&format!("{}{}", prefix, "SourceType"), &field_value);
         }
         if let Some(ref field_value) = obj.start_time {
-            params.put(&format!("{}{}", prefix, "StartTime"), &field_value);
+            params.put(
+                &format!("{}{}", prefix, "StartTime"),
+                &rusoto_core::serialization::TimestampFormat::Iso8601.format(field_value),
+            );
         }
     }
 }
@@ -4628,7 +4746,7 @@ impl DescribeLoggingStatusMessageSerializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct DescribeNodeConfigurationOptionsMessage {
     /// <p>The action type to evaluate for possible node configurations. Specify "restore-cluster" to get configuration combinations based on an existing snapshot. Specify "recommend-node-config" to get configuration recommendations based on an existing cluster or snapshot. </p>
-    pub action_type: String,
+    pub action_type: ActionType,
     /// <p>The identifier of the cluster to evaluate for possible node configurations.</p>
     pub cluster_identifier: Option<String>,
     /// <p>A set of name, operator, and value items to filter the results.</p>
@@ -4815,7 +4933,7 @@ pub struct DescribeScheduledActionsMessage {
     /// <p>If true, retrieve only active scheduled actions. If false, retrieve only disabled scheduled actions. </p>
     pub active: Option<bool>,
     /// <p>The end time in UTC of the scheduled action to retrieve. Only active scheduled actions that have invocations before this time are retrieved.</p>
-    pub end_time: Option<String>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>List of scheduled action filters. </p>
     pub filters: Option<Vec<ScheduledActionFilter>>,
     /// <p>An optional parameter that specifies the starting point to return a set of response records. When the results of a <a>DescribeScheduledActions</a> request exceed the value specified in <code>MaxRecords</code>, AWS returns a value in the <code>Marker</code> field of the response. You can retrieve the next set of response records by providing the returned marker value in the <code>Marker</code> parameter and retrying the request. </p>
@@ -4825,9 +4943,9 @@ pub struct DescribeScheduledActionsMessage {
     /// <p>The name of the scheduled action to retrieve. </p>
     pub scheduled_action_name: Option<String>,
     /// <p>The start time in UTC of the scheduled actions to retrieve. Only active scheduled actions that have invocations after this time are retrieved.</p>
-    pub start_time: Option<String>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The type of the scheduled actions to retrieve. </p>
-    pub target_action_type: Option<String>,
+    pub target_action_type: Option<ScheduledActionTypeValues>,
 }
 
 /// Serialize `DescribeScheduledActionsMessage` contents to a `SignedRequest`.
@@ -4843,7 +4961,10 @@ impl DescribeScheduledActionsMessageSerializer {
             params.put(&format!("{}{}", prefix, "Active"), &field_value);
         }
         if let Some(ref field_value) = obj.end_time {
-            params.put(&format!("{}{}", prefix, "EndTime"), &field_value);
+            params.put(
+                &format!("{}{}", prefix, "EndTime"),
+                &rusoto_core::serialization::TimestampFormat::Iso8601.format(field_value),
+            );
         }
         if let Some(ref field_value) = obj.filters {
             ScheduledActionFilterListSerializer::serialize(
@@ -4865,7 +4986,10 @@ impl DescribeScheduledActionsMessageSerializer {
             );
         }
         if let Some(ref field_value) = obj.start_time {
-            params.put(&format!("{}{}", prefix, "StartTime"), &field_value);
+            params.put(
+                &format!("{}{}", prefix, "StartTime"),
+                &rusoto_core::serialization::TimestampFormat::Iso8601.format(field_value),
+            );
         }
         if let Some(ref field_value) = obj.target_action_type {
             params.put(&format!("{}{}", prefix, "TargetActionType"), &field_value);
@@ -5483,7 +5607,7 @@ impl EndpointDeserializer {
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct Event {
     /// <p>The date and time of the event.</p>
-    pub date: Option<String>,
+    pub date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A list of the event categories.</p> <p>Values: Configuration, Management, Monitoring, Security</p>
     pub event_categories: Option<Vec<String>>,
     /// <p>The identifier of the event.</p>
@@ -5495,7 +5619,7 @@ pub struct Event {
     /// <p>The identifier for the source of the event.</p>
     pub source_identifier: Option<String>,
     /// <p>The source type for this event.</p>
-    pub source_type: Option<String>,
+    pub source_type: Option<SourceType>,
 }
 
 struct EventDeserializer;
@@ -5752,7 +5876,7 @@ pub struct EventSubscription {
     /// <p><p>The status of the Amazon Redshift event notification subscription.</p> <p>Constraints:</p> <ul> <li> <p>Can be one of the following: active | no-permission | topic-not-exist</p> </li> <li> <p>The status &quot;no-permission&quot; indicates that Amazon Redshift no longer has permission to post to the Amazon SNS topic. The status &quot;topic-not-exist&quot; indicates that the topic was deleted after the subscription was created.</p> </li> </ul></p>
     pub status: Option<String>,
     /// <p>The date and time the Amazon Redshift event notification subscription was created.</p>
-    pub subscription_creation_time: Option<String>,
+    pub subscription_creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The list of tags for the event subscription.</p>
     pub tags: Option<Vec<Tag>>,
 }
@@ -6448,9 +6572,9 @@ pub struct LoggingStatus {
     /// <p>The message indicating that logs failed to be delivered.</p>
     pub last_failure_message: Option<String>,
     /// <p>The last time when logs failed to be delivered.</p>
-    pub last_failure_time: Option<String>,
+    pub last_failure_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The last time that logs were delivered.</p>
-    pub last_successful_delivery_time: Option<String>,
+    pub last_successful_delivery_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p> <code>true</code> if logging is on, <code>false</code> if logging is off.</p>
     pub logging_enabled: Option<bool>,
     /// <p>The prefix applied to the log file names.</p>
@@ -6566,12 +6690,107 @@ impl MaintenanceTrackDeserializer {
         })
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Mode {
+    /// `standard`
+    Standard,
+    /// `high-performance`
+    HighPerformance,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Mode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Mode::Standard => "standard",
+            Mode::HighPerformance => "high-performance",
+            Mode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Mode {
+    fn default() -> Mode {
+        Mode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Mode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Mode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Mode {
+    fn from(value: &'a str) -> Mode {
+        match value {
+            "standard" => Mode::Standard,
+            "high-performance" => Mode::HighPerformance,
+            _ => Mode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Mode {
+    fn from(value: String) -> Mode {
+        match value.as_str() {
+            "standard" => Mode::Standard,
+            "high-performance" => Mode::HighPerformance,
+            _ => Mode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Mode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Mode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Mode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for Mode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for Mode {
+    fn deserialize<D>(deserializer: D) -> Result<Mode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct ModeDeserializer;
 impl ModeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<Mode, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -6714,11 +6933,11 @@ pub struct ModifyClusterMaintenanceMessage {
     /// <p>An integer indicating the duration of the maintenance window in days. If you specify a duration, you can't specify an end time. The duration must be 45 days or less.</p>
     pub defer_maintenance_duration: Option<i64>,
     /// <p>A timestamp indicating end time for the deferred maintenance window. If you specify an end time, you can't specify a duration.</p>
-    pub defer_maintenance_end_time: Option<String>,
+    pub defer_maintenance_end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A unique identifier for the deferred maintenance window.</p>
     pub defer_maintenance_identifier: Option<String>,
     /// <p>A timestamp indicating the start time for the deferred maintenance window.</p>
-    pub defer_maintenance_start_time: Option<String>,
+    pub defer_maintenance_start_time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// Serialize `ModifyClusterMaintenanceMessage` contents to a `SignedRequest`.
@@ -6746,7 +6965,7 @@ impl ModifyClusterMaintenanceMessageSerializer {
         if let Some(ref field_value) = obj.defer_maintenance_end_time {
             params.put(
                 &format!("{}{}", prefix, "DeferMaintenanceEndTime"),
-                &field_value,
+                &rusoto_core::serialization::TimestampFormat::Iso8601.format(field_value),
             );
         }
         if let Some(ref field_value) = obj.defer_maintenance_identifier {
@@ -6758,7 +6977,7 @@ impl ModifyClusterMaintenanceMessageSerializer {
         if let Some(ref field_value) = obj.defer_maintenance_start_time {
             params.put(
                 &format!("{}{}", prefix, "DeferMaintenanceStartTime"),
-                &field_value,
+                &rusoto_core::serialization::TimestampFormat::Iso8601.format(field_value),
             );
         }
     }
@@ -7278,7 +7497,7 @@ pub struct ModifyScheduledActionMessage {
     /// <p>A modified enable flag of the scheduled action. If true, the scheduled action is active. If false, the scheduled action is disabled. </p>
     pub enable: Option<bool>,
     /// <p>A modified end time of the scheduled action. For more information about this parameter, see <a>ScheduledAction</a>. </p>
-    pub end_time: Option<String>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A different IAM role to assume to run the target action. For more information about this parameter, see <a>ScheduledAction</a>.</p>
     pub iam_role: Option<String>,
     /// <p>A modified schedule in either <code>at( )</code> or <code>cron( )</code> format. For more information about this parameter, see <a>ScheduledAction</a>.</p>
@@ -7288,7 +7507,7 @@ pub struct ModifyScheduledActionMessage {
     /// <p>The name of the scheduled action to modify. </p>
     pub scheduled_action_name: String,
     /// <p>A modified start time of the scheduled action. For more information about this parameter, see <a>ScheduledAction</a>. </p>
-    pub start_time: Option<String>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A modified JSON format of the scheduled action. For more information about this parameter, see <a>ScheduledAction</a>. </p>
     pub target_action: Option<ScheduledActionType>,
 }
@@ -7306,7 +7525,10 @@ impl ModifyScheduledActionMessageSerializer {
             params.put(&format!("{}{}", prefix, "Enable"), &field_value);
         }
         if let Some(ref field_value) = obj.end_time {
-            params.put(&format!("{}{}", prefix, "EndTime"), &field_value);
+            params.put(
+                &format!("{}{}", prefix, "EndTime"),
+                &rusoto_core::serialization::TimestampFormat::Iso8601.format(field_value),
+            );
         }
         if let Some(ref field_value) = obj.iam_role {
             params.put(&format!("{}{}", prefix, "IamRole"), &field_value);
@@ -7325,7 +7547,10 @@ impl ModifyScheduledActionMessageSerializer {
             &obj.scheduled_action_name,
         );
         if let Some(ref field_value) = obj.start_time {
-            params.put(&format!("{}{}", prefix, "StartTime"), &field_value);
+            params.put(
+                &format!("{}{}", prefix, "StartTime"),
+                &rusoto_core::serialization::TimestampFormat::Iso8601.format(field_value),
+            );
         }
         if let Some(ref field_value) = obj.target_action {
             ScheduledActionTypeSerializer::serialize(
@@ -7437,7 +7662,7 @@ pub struct NodeConfigurationOption {
     /// <p>The estimated disk utilizaton percentage.</p>
     pub estimated_disk_utilization_percent: Option<f64>,
     /// <p>The category of the node configuration recommendation.</p>
-    pub mode: Option<String>,
+    pub mode: Option<Mode>,
     /// <p>The node type, such as, "ds2.8xlarge".</p>
     pub node_type: Option<String>,
     /// <p>The number of nodes.</p>
@@ -7505,9 +7730,9 @@ impl NodeConfigurationOptionListDeserializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct NodeConfigurationOptionsFilter {
     /// <p>The name of the element to filter.</p>
-    pub name: Option<String>,
+    pub name: Option<NodeConfigurationOptionsFilterName>,
     /// <p>The filter operator. If filter Name is NodeType only the 'in' operator is supported. Provide one value to evaluate for 'eq', 'lt', 'le', 'gt', and 'ge'. Provide two values to evaluate for 'between'. Provide a list of values for 'in'.</p>
-    pub operator: Option<String>,
+    pub operator: Option<OperatorType>,
     /// <p>List of values. Compare Name using Operator to Values. If filter Name is NumberOfNodes, then values can range from 0 to 200. If filter Name is EstimatedDiskUtilizationPercent, then values can range from 0 to 100. For example, filter NumberOfNodes (name) GT (operator) 3 (values).</p>
     pub values: Option<Vec<String>>,
 }
@@ -7548,6 +7773,117 @@ impl NodeConfigurationOptionsFilterListSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum NodeConfigurationOptionsFilterName {
+    /// `NodeType`
+    NodeType,
+    /// `NumberOfNodes`
+    NumberOfNodes,
+    /// `EstimatedDiskUtilizationPercent`
+    EstimatedDiskUtilizationPercent,
+    /// `Mode`
+    Mode,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl NodeConfigurationOptionsFilterName {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            NodeConfigurationOptionsFilterName::NodeType => "NodeType",
+            NodeConfigurationOptionsFilterName::NumberOfNodes => "NumberOfNodes",
+            NodeConfigurationOptionsFilterName::EstimatedDiskUtilizationPercent => {
+                "EstimatedDiskUtilizationPercent"
+            }
+            NodeConfigurationOptionsFilterName::Mode => "Mode",
+            NodeConfigurationOptionsFilterName::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for NodeConfigurationOptionsFilterName {
+    fn default() -> NodeConfigurationOptionsFilterName {
+        NodeConfigurationOptionsFilterName::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for NodeConfigurationOptionsFilterName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for NodeConfigurationOptionsFilterName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for NodeConfigurationOptionsFilterName {
+    fn from(value: &'a str) -> NodeConfigurationOptionsFilterName {
+        match value {
+            "NodeType" => NodeConfigurationOptionsFilterName::NodeType,
+            "NumberOfNodes" => NodeConfigurationOptionsFilterName::NumberOfNodes,
+            "EstimatedDiskUtilizationPercent" => {
+                NodeConfigurationOptionsFilterName::EstimatedDiskUtilizationPercent
+            }
+            "Mode" => NodeConfigurationOptionsFilterName::Mode,
+            _ => NodeConfigurationOptionsFilterName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for NodeConfigurationOptionsFilterName {
+    fn from(value: String) -> NodeConfigurationOptionsFilterName {
+        match value.as_str() {
+            "NodeType" => NodeConfigurationOptionsFilterName::NodeType,
+            "NumberOfNodes" => NodeConfigurationOptionsFilterName::NumberOfNodes,
+            "EstimatedDiskUtilizationPercent" => {
+                NodeConfigurationOptionsFilterName::EstimatedDiskUtilizationPercent
+            }
+            "Mode" => NodeConfigurationOptionsFilterName::Mode,
+            _ => NodeConfigurationOptionsFilterName::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for NodeConfigurationOptionsFilterName {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<NodeConfigurationOptionsFilterName, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for NodeConfigurationOptionsFilterName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for NodeConfigurationOptionsFilterName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for NodeConfigurationOptionsFilterName {
+    fn deserialize<D>(deserializer: D) -> Result<NodeConfigurationOptionsFilterName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct NodeConfigurationOptionsMessage {
@@ -7587,6 +7923,126 @@ impl NodeConfigurationOptionsMessageDeserializer {
         )
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OperatorType {
+    /// `eq`
+    Eq,
+    /// `lt`
+    Lt,
+    /// `gt`
+    Gt,
+    /// `le`
+    Le,
+    /// `ge`
+    Ge,
+    /// `in`
+    In,
+    /// `between`
+    Between,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl OperatorType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OperatorType::Eq => "eq",
+            OperatorType::Lt => "lt",
+            OperatorType::Gt => "gt",
+            OperatorType::Le => "le",
+            OperatorType::Ge => "ge",
+            OperatorType::In => "in",
+            OperatorType::Between => "between",
+            OperatorType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for OperatorType {
+    fn default() -> OperatorType {
+        OperatorType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for OperatorType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for OperatorType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for OperatorType {
+    fn from(value: &'a str) -> OperatorType {
+        match value {
+            "eq" => OperatorType::Eq,
+            "lt" => OperatorType::Lt,
+            "gt" => OperatorType::Gt,
+            "le" => OperatorType::Le,
+            "ge" => OperatorType::Ge,
+            "in" => OperatorType::In,
+            "between" => OperatorType::Between,
+            _ => OperatorType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OperatorType {
+    fn from(value: String) -> OperatorType {
+        match value.as_str() {
+            "eq" => OperatorType::Eq,
+            "lt" => OperatorType::Lt,
+            "gt" => OperatorType::Gt,
+            "le" => OperatorType::Le,
+            "ge" => OperatorType::Ge,
+            "in" => OperatorType::In,
+            "between" => OperatorType::Between,
+            _ => OperatorType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for OperatorType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<OperatorType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OperatorType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for OperatorType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for OperatorType {
+    fn deserialize<D>(deserializer: D) -> Result<OperatorType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes an orderable cluster option.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
@@ -7699,7 +8155,7 @@ pub struct Parameter {
     /// <p>The valid range of values for the parameter.</p>
     pub allowed_values: Option<String>,
     /// <p>Specifies how to apply the WLM configuration parameter. Some properties can be applied dynamically, while other properties require that any associated clusters be rebooted for the configuration changes to be applied. For more information about parameters and parameter groups, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-parameter-groups.html">Amazon Redshift Parameter Groups</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
-    pub apply_type: Option<String>,
+    pub apply_type: Option<ParameterApplyType>,
     /// <p>The data type of the parameter.</p>
     pub data_type: Option<String>,
     /// <p>A description of the parameter.</p>
@@ -7811,12 +8267,110 @@ impl ParameterSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ParameterApplyType {
+    /// `static`
+    Static,
+    /// `dynamic`
+    Dynamic,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ParameterApplyType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ParameterApplyType::Static => "static",
+            ParameterApplyType::Dynamic => "dynamic",
+            ParameterApplyType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ParameterApplyType {
+    fn default() -> ParameterApplyType {
+        ParameterApplyType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ParameterApplyType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ParameterApplyType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ParameterApplyType {
+    fn from(value: &'a str) -> ParameterApplyType {
+        match value {
+            "static" => ParameterApplyType::Static,
+            "dynamic" => ParameterApplyType::Dynamic,
+            _ => ParameterApplyType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ParameterApplyType {
+    fn from(value: String) -> ParameterApplyType {
+        match value.as_str() {
+            "static" => ParameterApplyType::Static,
+            "dynamic" => ParameterApplyType::Dynamic,
+            _ => ParameterApplyType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ParameterApplyType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ParameterApplyType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ParameterApplyType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ParameterApplyType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ParameterApplyType {
+    fn deserialize<D>(deserializer: D) -> Result<ParameterApplyType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct ParameterApplyTypeDeserializer;
 impl ParameterApplyTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ParameterApplyType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -8174,9 +8728,9 @@ pub struct ReservedNode {
     /// <p>The identifier for the reserved node offering.</p>
     pub reserved_node_offering_id: Option<String>,
     /// <p><p/></p>
-    pub reserved_node_offering_type: Option<String>,
+    pub reserved_node_offering_type: Option<ReservedNodeOfferingType>,
     /// <p>The time the reservation started. You purchase a reserved node offering for a duration. This is the start time of that duration.</p>
-    pub start_time: Option<String>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>The state of the reserved compute node.</p> <p>Possible Values:</p> <ul> <li> <p>pending-payment-This reserved node has recently been purchased, and the sale has been approved, but payment has not yet been confirmed.</p> </li> <li> <p>active-This reserved node is owned by the caller and is available for use.</p> </li> <li> <p>payment-failed-Payment failed for the purchase attempt.</p> </li> <li> <p>retired-The reserved node is no longer available. </p> </li> <li> <p>exchanging-The owner is exchanging the reserved node for another reserved node.</p> </li> </ul></p>
     pub state: Option<String>,
     /// <p>The hourly rate Amazon Redshift charges you for this reserved node.</p>
@@ -8288,7 +8842,7 @@ pub struct ReservedNodeOffering {
     /// <p>The offering identifier.</p>
     pub reserved_node_offering_id: Option<String>,
     /// <p><p/></p>
-    pub reserved_node_offering_type: Option<String>,
+    pub reserved_node_offering_type: Option<ReservedNodeOfferingType>,
     /// <p>The rate you are charged for each hour the cluster that is using the offering is running.</p>
     pub usage_price: Option<f64>,
 }
@@ -8366,26 +8920,124 @@ impl ReservedNodeOfferingListDeserializer {
         })
     }
 }
-struct ReservedNodeOfferingTypeDeserializer;
-impl ReservedNodeOfferingTypeDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
-        start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
-        end_element(tag_name, stack)?;
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ReservedNodeOfferingType {
+    /// `Regular`
+    Regular,
+    /// `Upgradable`
+    Upgradable,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
 
-        Ok(obj)
+impl ReservedNodeOfferingType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReservedNodeOfferingType::Regular => "Regular",
+            ReservedNodeOfferingType::Upgradable => "Upgradable",
+            ReservedNodeOfferingType::Unknown(ref value) => value,
+        }
     }
 }
-/// <p><p/></p>
-#[derive(Default, Debug, Clone, PartialEq)]
-#[cfg_attr(feature = "serialize_structs", derive(Serialize))]
-pub struct ReservedNodeOfferingsMessage {
-    /// <p>A value that indicates the starting point for the next set of response records in a subsequent request. If a value is returned in a response, you can retrieve the next set of records by providing this returned marker value in the <code>Marker</code> parameter and retrying the command. If the <code>Marker</code> field is empty, all response records have been retrieved for the request. </p>
-    pub marker: Option<String>,
-    /// <p>A list of <code>ReservedNodeOffering</code> objects.</p>
-    pub reserved_node_offerings: Option<Vec<ReservedNodeOffering>>,
-}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ReservedNodeOfferingType {
+    fn default() -> ReservedNodeOfferingType {
+        ReservedNodeOfferingType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ReservedNodeOfferingType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ReservedNodeOfferingType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ReservedNodeOfferingType {
+    fn from(value: &'a str) -> ReservedNodeOfferingType {
+        match value {
+            "Regular" => ReservedNodeOfferingType::Regular,
+            "Upgradable" => ReservedNodeOfferingType::Upgradable,
+            _ => ReservedNodeOfferingType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReservedNodeOfferingType {
+    fn from(value: String) -> ReservedNodeOfferingType {
+        match value.as_str() {
+            "Regular" => ReservedNodeOfferingType::Regular,
+            "Upgradable" => ReservedNodeOfferingType::Upgradable,
+            _ => ReservedNodeOfferingType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ReservedNodeOfferingType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ReservedNodeOfferingType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ReservedNodeOfferingType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ReservedNodeOfferingType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ReservedNodeOfferingType {
+    fn deserialize<D>(deserializer: D) -> Result<ReservedNodeOfferingType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+struct ReservedNodeOfferingTypeDeserializer;
+impl ReservedNodeOfferingTypeDeserializer {
+    #[allow(unused_variables)]
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ReservedNodeOfferingType, XmlParseError> {
+        start_element(tag_name, stack)?;
+        let obj = characters(stack)?.into();
+        end_element(tag_name, stack)?;
+
+        Ok(obj)
+    }
+}
+/// <p><p/></p>
+#[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize_structs", derive(Serialize))]
+pub struct ReservedNodeOfferingsMessage {
+    /// <p>A value that indicates the starting point for the next set of response records in a subsequent request. If a value is returned in a response, you can retrieve the next set of records by providing this returned marker value in the <code>Marker</code> parameter and retrying the command. If the <code>Marker</code> field is empty, all response records have been retrieved for the request. </p>
+    pub marker: Option<String>,
+    /// <p>A list of <code>ReservedNodeOffering</code> objects.</p>
+    pub reserved_node_offerings: Option<Vec<ReservedNodeOffering>>,
+}
 
 struct ReservedNodeOfferingsMessageDeserializer;
 impl ReservedNodeOfferingsMessageDeserializer {
@@ -9173,7 +9825,7 @@ pub struct RevisionTarget {
     /// <p>A unique string that identifies the version to update the cluster to. You can use this value in <a>ModifyClusterDbRevision</a>.</p>
     pub database_revision: Option<String>,
     /// <p>The date on which the database revision was released.</p>
-    pub database_revision_release_date: Option<String>,
+    pub database_revision_release_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A string that describes the changes and features that will be applied to the cluster when it is updated to the corresponding <a>ClusterDbRevision</a>.</p>
     pub description: Option<String>,
 }
@@ -9453,12 +10105,115 @@ impl ScheduleDefinitionListSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ScheduleState {
+    /// `MODIFYING`
+    Modifying,
+    /// `ACTIVE`
+    Active,
+    /// `FAILED`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ScheduleState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ScheduleState::Modifying => "MODIFYING",
+            ScheduleState::Active => "ACTIVE",
+            ScheduleState::Failed => "FAILED",
+            ScheduleState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ScheduleState {
+    fn default() -> ScheduleState {
+        ScheduleState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ScheduleState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ScheduleState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ScheduleState {
+    fn from(value: &'a str) -> ScheduleState {
+        match value {
+            "MODIFYING" => ScheduleState::Modifying,
+            "ACTIVE" => ScheduleState::Active,
+            "FAILED" => ScheduleState::Failed,
+            _ => ScheduleState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ScheduleState {
+    fn from(value: String) -> ScheduleState {
+        match value.as_str() {
+            "MODIFYING" => ScheduleState::Modifying,
+            "ACTIVE" => ScheduleState::Active,
+            "FAILED" => ScheduleState::Failed,
+            _ => ScheduleState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ScheduleState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ScheduleState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ScheduleState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ScheduleState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ScheduleState {
+    fn deserialize<D>(deserializer: D) -> Result<ScheduleState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct ScheduleStateDeserializer;
 impl ScheduleStateDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ScheduleState, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -9469,11 +10224,11 @@ impl ScheduleStateDeserializer {
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct ScheduledAction {
     /// <p>The end time in UTC when the schedule is no longer active. After this time, the scheduled action does not trigger. </p>
-    pub end_time: Option<String>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The IAM role to assume to run the scheduled action. This IAM role must have permission to run the Amazon Redshift API operation in the scheduled action. This IAM role must allow the Amazon Redshift scheduler (Principal scheduler.redshift.amazonaws.com) to assume permissions on your behalf. For more information about the IAM role to use with the Amazon Redshift scheduler, see <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/redshift-iam-access-control-identity-based.html">Using Identity-Based Policies for Amazon Redshift</a> in the <i>Amazon Redshift Cluster Management Guide</i>. </p>
     pub iam_role: Option<String>,
     /// <p>List of times when the scheduled action will run. </p>
-    pub next_invocations: Option<Vec<String>>,
+    pub next_invocations: Option<Vec<::chrono::DateTime<::chrono::Utc>>>,
     /// <p>The schedule for a one-time (at format) or recurring (cron format) scheduled action. Schedule invocations must be separated by at least one hour.</p> <p>Format of at expressions is "<code>at(yyyy-mm-ddThh:mm:ss)</code>". For example, "<code>at(2016-03-04T17:27:00)</code>".</p> <p>Format of cron expressions is "<code>cron(Minutes Hours Day-of-month Month Day-of-week Year)</code>". For example, "<code>cron(0, 10, *, *, MON, *)</code>". For more information, see <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/events/ScheduledEvents.html#CronExpressions">Cron Expressions</a> in the <i>Amazon CloudWatch Events User Guide</i>.</p>
     pub schedule: Option<String>,
     /// <p>The description of the scheduled action. </p>
@@ -9481,9 +10236,9 @@ pub struct ScheduledAction {
     /// <p>The name of the scheduled action. </p>
     pub scheduled_action_name: Option<String>,
     /// <p>The start time in UTC when the schedule is active. Before this time, the scheduled action does not trigger. </p>
-    pub start_time: Option<String>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The state of the scheduled action. For example, <code>DISABLED</code>. </p>
-    pub state: Option<String>,
+    pub state: Option<ScheduledActionState>,
     /// <p>A JSON format string of the Amazon Redshift API operation with input parameters. </p> <p>"<code>{\"ResizeCluster\":{\"NodeType\":\"ds2.8xlarge\",\"ClusterIdentifier\":\"my-test-cluster\",\"NumberOfNodes\":3}}</code>". </p>
     pub target_action: Option<ScheduledActionType>,
 }
@@ -9548,7 +10303,7 @@ impl ScheduledActionDeserializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ScheduledActionFilter {
     /// <p>The type of element to filter. </p>
-    pub name: String,
+    pub name: ScheduledActionFilterName,
     /// <p>List of values. Compare if the value (of type defined by <code>Name</code>) equals an item in the list of scheduled actions. </p>
     pub values: Vec<String>,
 }
@@ -9578,6 +10333,101 @@ impl ScheduledActionFilterListSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ScheduledActionFilterName {
+    /// `cluster-identifier`
+    ClusterIdentifier,
+    /// `iam-role`
+    IamRole,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ScheduledActionFilterName {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ScheduledActionFilterName::ClusterIdentifier => "cluster-identifier",
+            ScheduledActionFilterName::IamRole => "iam-role",
+            ScheduledActionFilterName::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ScheduledActionFilterName {
+    fn default() -> ScheduledActionFilterName {
+        ScheduledActionFilterName::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ScheduledActionFilterName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ScheduledActionFilterName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ScheduledActionFilterName {
+    fn from(value: &'a str) -> ScheduledActionFilterName {
+        match value {
+            "cluster-identifier" => ScheduledActionFilterName::ClusterIdentifier,
+            "iam-role" => ScheduledActionFilterName::IamRole,
+            _ => ScheduledActionFilterName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ScheduledActionFilterName {
+    fn from(value: String) -> ScheduledActionFilterName {
+        match value.as_str() {
+            "cluster-identifier" => ScheduledActionFilterName::ClusterIdentifier,
+            "iam-role" => ScheduledActionFilterName::IamRole,
+            _ => ScheduledActionFilterName::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ScheduledActionFilterName {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ScheduledActionFilterName, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ScheduledActionFilterName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ScheduledActionFilterName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ScheduledActionFilterName {
+    fn deserialize<D>(deserializer: D) -> Result<ScheduledActionFilterName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct ScheduledActionListDeserializer;
 impl ScheduledActionListDeserializer {
     #[allow(unused_variables)]
@@ -9598,12 +10448,110 @@ impl ScheduledActionListDeserializer {
         })
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ScheduledActionState {
+    /// `ACTIVE`
+    Active,
+    /// `DISABLED`
+    Disabled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ScheduledActionState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ScheduledActionState::Active => "ACTIVE",
+            ScheduledActionState::Disabled => "DISABLED",
+            ScheduledActionState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ScheduledActionState {
+    fn default() -> ScheduledActionState {
+        ScheduledActionState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ScheduledActionState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ScheduledActionState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ScheduledActionState {
+    fn from(value: &'a str) -> ScheduledActionState {
+        match value {
+            "ACTIVE" => ScheduledActionState::Active,
+            "DISABLED" => ScheduledActionState::Disabled,
+            _ => ScheduledActionState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ScheduledActionState {
+    fn from(value: String) -> ScheduledActionState {
+        match value.as_str() {
+            "ACTIVE" => ScheduledActionState::Active,
+            "DISABLED" => ScheduledActionState::Disabled,
+            _ => ScheduledActionState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ScheduledActionState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ScheduledActionState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ScheduledActionState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ScheduledActionState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ScheduledActionState {
+    fn deserialize<D>(deserializer: D) -> Result<ScheduledActionState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct ScheduledActionStateDeserializer;
 impl ScheduledActionStateDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ScheduledActionState, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -9615,7 +10563,7 @@ impl ScheduledActionTimeListDeserializer {
     fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
-    ) -> Result<Vec<String>, XmlParseError> {
+    ) -> Result<Vec<::chrono::DateTime<::chrono::Utc>>, XmlParseError> {
         deserialize_elements::<_, Vec<_>, _>(tag_name, stack, |name, stack, obj| {
             if name == "ScheduledActionTime" {
                 obj.push(TStampDeserializer::deserialize(
@@ -9679,6 +10627,106 @@ impl ScheduledActionTypeSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ScheduledActionTypeValues {
+    /// `ResizeCluster`
+    ResizeCluster,
+    /// `PauseCluster`
+    PauseCluster,
+    /// `ResumeCluster`
+    ResumeCluster,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ScheduledActionTypeValues {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ScheduledActionTypeValues::ResizeCluster => "ResizeCluster",
+            ScheduledActionTypeValues::PauseCluster => "PauseCluster",
+            ScheduledActionTypeValues::ResumeCluster => "ResumeCluster",
+            ScheduledActionTypeValues::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ScheduledActionTypeValues {
+    fn default() -> ScheduledActionTypeValues {
+        ScheduledActionTypeValues::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ScheduledActionTypeValues {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ScheduledActionTypeValues {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ScheduledActionTypeValues {
+    fn from(value: &'a str) -> ScheduledActionTypeValues {
+        match value {
+            "ResizeCluster" => ScheduledActionTypeValues::ResizeCluster,
+            "PauseCluster" => ScheduledActionTypeValues::PauseCluster,
+            "ResumeCluster" => ScheduledActionTypeValues::ResumeCluster,
+            _ => ScheduledActionTypeValues::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ScheduledActionTypeValues {
+    fn from(value: String) -> ScheduledActionTypeValues {
+        match value.as_str() {
+            "ResizeCluster" => ScheduledActionTypeValues::ResizeCluster,
+            "PauseCluster" => ScheduledActionTypeValues::PauseCluster,
+            "ResumeCluster" => ScheduledActionTypeValues::ResumeCluster,
+            _ => ScheduledActionTypeValues::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ScheduledActionTypeValues {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ScheduledActionTypeValues, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ScheduledActionTypeValues {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ScheduledActionTypeValues {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ScheduledActionTypeValues {
+    fn deserialize<D>(deserializer: D) -> Result<ScheduledActionTypeValues, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct ScheduledActionsMessage {
@@ -9724,7 +10772,7 @@ impl ScheduledSnapshotTimeListDeserializer {
     fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
-    ) -> Result<Vec<String>, XmlParseError> {
+    ) -> Result<Vec<::chrono::DateTime<::chrono::Utc>>, XmlParseError> {
         deserialize_elements::<_, Vec<_>, _>(tag_name, stack, |name, stack, obj| {
             if name == "SnapshotTime" {
                 obj.push(TStampDeserializer::deserialize("SnapshotTime", stack)?);
@@ -9759,7 +10807,7 @@ pub struct Snapshot {
     /// <p>The number of megabytes that have been transferred to the snapshot backup.</p>
     pub backup_progress_in_mega_bytes: Option<f64>,
     /// <p>The time (UTC) when the cluster was originally created.</p>
-    pub cluster_create_time: Option<String>,
+    pub cluster_create_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The identifier of the cluster for which the snapshot was taken.</p>
     pub cluster_identifier: Option<String>,
     /// <p>The version ID of the Amazon Redshift engine that is running on the cluster.</p>
@@ -9799,11 +10847,11 @@ pub struct Snapshot {
     /// <p>The list of node types that this cluster snapshot is able to restore into.</p>
     pub restorable_node_types: Option<Vec<String>>,
     /// <p>The time (in UTC format) when Amazon Redshift began the snapshot. A snapshot contains a copy of the cluster data as of this exact time.</p>
-    pub snapshot_create_time: Option<String>,
+    pub snapshot_create_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The snapshot identifier that is provided in the request.</p>
     pub snapshot_identifier: Option<String>,
     /// <p>A timestamp representing the start of the retention period for the snapshot.</p>
-    pub snapshot_retention_start_time: Option<String>,
+    pub snapshot_retention_start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The snapshot type. Snapshots created using <a>CreateClusterSnapshot</a> and <a>CopyClusterSnapshot</a> are of type "manual". </p>
     pub snapshot_type: Option<String>,
     /// <p>The source region from which the snapshot was copied.</p>
@@ -9997,31 +11045,131 @@ impl SnapshotDeserializer {
         })
     }
 }
-/// <p>The snapshot copy grant that grants Amazon Redshift permission to encrypt copied snapshots with the specified customer master key (CMK) from AWS KMS in the destination region.</p> <p> For more information about managing snapshot copy grants, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-db-encryption.html">Amazon Redshift Database Encryption</a> in the <i>Amazon Redshift Cluster Management Guide</i>. </p>
-#[derive(Default, Debug, Clone, PartialEq)]
-#[cfg_attr(feature = "serialize_structs", derive(Serialize))]
-pub struct SnapshotCopyGrant {
-    /// <p>The unique identifier of the customer master key (CMK) in AWS KMS to which Amazon Redshift is granted permission.</p>
-    pub kms_key_id: Option<String>,
-    /// <p>The name of the snapshot copy grant.</p>
-    pub snapshot_copy_grant_name: Option<String>,
-    /// <p>A list of tag instances.</p>
-    pub tags: Option<Vec<Tag>>,
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SnapshotAttributeToSortBy {
+    /// `SOURCE_TYPE`
+    SourceType,
+    /// `TOTAL_SIZE`
+    TotalSize,
+    /// `CREATE_TIME`
+    CreateTime,
+    /// A value not known to this version of the crate
+    Unknown(String),
 }
 
-struct SnapshotCopyGrantDeserializer;
-impl SnapshotCopyGrantDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
-        tag_name: &str,
-        stack: &mut T,
-    ) -> Result<SnapshotCopyGrant, XmlParseError> {
-        deserialize_elements::<_, SnapshotCopyGrant, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "KmsKeyId" => {
-                    obj.kms_key_id = Some(StringDeserializer::deserialize("KmsKeyId", stack)?);
-                }
-                "SnapshotCopyGrantName" => {
+impl SnapshotAttributeToSortBy {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SnapshotAttributeToSortBy::SourceType => "SOURCE_TYPE",
+            SnapshotAttributeToSortBy::TotalSize => "TOTAL_SIZE",
+            SnapshotAttributeToSortBy::CreateTime => "CREATE_TIME",
+            SnapshotAttributeToSortBy::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SnapshotAttributeToSortBy {
+    fn default() -> SnapshotAttributeToSortBy {
+        SnapshotAttributeToSortBy::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SnapshotAttributeToSortBy {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SnapshotAttributeToSortBy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SnapshotAttributeToSortBy {
+    fn from(value: &'a str) -> SnapshotAttributeToSortBy {
+        match value {
+            "SOURCE_TYPE" => SnapshotAttributeToSortBy::SourceType,
+            "TOTAL_SIZE" => SnapshotAttributeToSortBy::TotalSize,
+            "CREATE_TIME" => SnapshotAttributeToSortBy::CreateTime,
+            _ => SnapshotAttributeToSortBy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SnapshotAttributeToSortBy {
+    fn from(value: String) -> SnapshotAttributeToSortBy {
+        match value.as_str() {
+            "SOURCE_TYPE" => SnapshotAttributeToSortBy::SourceType,
+            "TOTAL_SIZE" => SnapshotAttributeToSortBy::TotalSize,
+            "CREATE_TIME" => SnapshotAttributeToSortBy::CreateTime,
+            _ => SnapshotAttributeToSortBy::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SnapshotAttributeToSortBy {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SnapshotAttributeToSortBy, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SnapshotAttributeToSortBy {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for SnapshotAttributeToSortBy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for SnapshotAttributeToSortBy {
+    fn deserialize<D>(deserializer: D) -> Result<SnapshotAttributeToSortBy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>The snapshot copy grant that grants Amazon Redshift permission to encrypt copied snapshots with the specified customer master key (CMK) from AWS KMS in the destination region.</p> <p> For more information about managing snapshot copy grants, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-db-encryption.html">Amazon Redshift Database Encryption</a> in the <i>Amazon Redshift Cluster Management Guide</i>. </p>
+#[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize_structs", derive(Serialize))]
+pub struct SnapshotCopyGrant {
+    /// <p>The unique identifier of the customer master key (CMK) in AWS KMS to which Amazon Redshift is granted permission.</p>
+    pub kms_key_id: Option<String>,
+    /// <p>The name of the snapshot copy grant.</p>
+    pub snapshot_copy_grant_name: Option<String>,
+    /// <p>A list of tag instances.</p>
+    pub tags: Option<Vec<Tag>>,
+}
+
+struct SnapshotCopyGrantDeserializer;
+impl SnapshotCopyGrantDeserializer {
+    #[allow(unused_variables)]
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<SnapshotCopyGrant, XmlParseError> {
+        deserialize_elements::<_, SnapshotCopyGrant, _>(tag_name, stack, |name, stack, obj| {
+            match name {
+                "KmsKeyId" => {
+                    obj.kms_key_id = Some(StringDeserializer::deserialize("KmsKeyId", stack)?);
+                }
+                "SnapshotCopyGrantName" => {
                     obj.snapshot_copy_grant_name = Some(StringDeserializer::deserialize(
                         "SnapshotCopyGrantName",
                         stack,
@@ -10234,7 +11382,7 @@ pub struct SnapshotSchedule {
     /// <p>A list of clusters associated with the schedule. A maximum of 100 clusters is returned.</p>
     pub associated_clusters: Option<Vec<ClusterAssociatedToSchedule>>,
     /// <p><p/></p>
-    pub next_invocations: Option<Vec<String>>,
+    pub next_invocations: Option<Vec<::chrono::DateTime<::chrono::Utc>>>,
     /// <p>A list of ScheduleDefinitions.</p>
     pub schedule_definitions: Option<Vec<String>>,
     /// <p>The description of the schedule.</p>
@@ -10332,9 +11480,9 @@ impl SnapshotScheduleListDeserializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct SnapshotSortingEntity {
     /// <p>The category for sorting the snapshots.</p>
-    pub attribute: String,
+    pub attribute: SnapshotAttributeToSortBy,
     /// <p>The order for listing the attributes.</p>
-    pub sort_order: Option<String>,
+    pub sort_order: Option<SortByOrder>,
 }
 
 /// Serialize `SnapshotSortingEntity` contents to a `SignedRequest`.
@@ -10364,6 +11512,101 @@ impl SnapshotSortingEntityListSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SortByOrder {
+    /// `ASC`
+    Asc,
+    /// `DESC`
+    Desc,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SortByOrder {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SortByOrder::Asc => "ASC",
+            SortByOrder::Desc => "DESC",
+            SortByOrder::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SortByOrder {
+    fn default() -> SortByOrder {
+        SortByOrder::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SortByOrder {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SortByOrder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SortByOrder {
+    fn from(value: &'a str) -> SortByOrder {
+        match value {
+            "ASC" => SortByOrder::Asc,
+            "DESC" => SortByOrder::Desc,
+            _ => SortByOrder::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SortByOrder {
+    fn from(value: String) -> SortByOrder {
+        match value.as_str() {
+            "ASC" => SortByOrder::Asc,
+            "DESC" => SortByOrder::Desc,
+            _ => SortByOrder::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SortByOrder {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SortByOrder, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SortByOrder {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for SortByOrder {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for SortByOrder {
+    fn deserialize<D>(deserializer: D) -> Result<SortByOrder, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct SourceIdsListDeserializer;
 impl SourceIdsListDeserializer {
     #[allow(unused_variables)]
@@ -10393,12 +11636,125 @@ impl SourceIdsListSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SourceType {
+    /// `cluster`
+    Cluster,
+    /// `cluster-parameter-group`
+    ClusterParameterGroup,
+    /// `cluster-security-group`
+    ClusterSecurityGroup,
+    /// `cluster-snapshot`
+    ClusterSnapshot,
+    /// `scheduled-action`
+    ScheduledAction,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SourceType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SourceType::Cluster => "cluster",
+            SourceType::ClusterParameterGroup => "cluster-parameter-group",
+            SourceType::ClusterSecurityGroup => "cluster-security-group",
+            SourceType::ClusterSnapshot => "cluster-snapshot",
+            SourceType::ScheduledAction => "scheduled-action",
+            SourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SourceType {
+    fn default() -> SourceType {
+        SourceType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SourceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SourceType {
+    fn from(value: &'a str) -> SourceType {
+        match value {
+            "cluster" => SourceType::Cluster,
+            "cluster-parameter-group" => SourceType::ClusterParameterGroup,
+            "cluster-security-group" => SourceType::ClusterSecurityGroup,
+            "cluster-snapshot" => SourceType::ClusterSnapshot,
+            "scheduled-action" => SourceType::ScheduledAction,
+            _ => SourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SourceType {
+    fn from(value: String) -> SourceType {
+        match value.as_str() {
+            "cluster" => SourceType::Cluster,
+            "cluster-parameter-group" => SourceType::ClusterParameterGroup,
+            "cluster-security-group" => SourceType::ClusterSecurityGroup,
+            "cluster-snapshot" => SourceType::ClusterSnapshot,
+            "scheduled-action" => SourceType::ScheduledAction,
+            _ => SourceType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SourceType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SourceType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SourceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for SourceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for SourceType {
+    fn deserialize<D>(deserializer: D) -> Result<SourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct SourceTypeDeserializer;
 impl SourceTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<SourceType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -10578,9 +11934,12 @@ impl SupportedPlatformsListDeserializer {
 struct TStampDeserializer;
 impl TStampDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<::chrono::DateTime<::chrono::Utc>, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::proto::xml::util::timestamp_characters(stack)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -10599,7 +11958,7 @@ pub struct TableRestoreStatus {
     /// <p>The amount of data restored to the new table so far, in megabytes (MB).</p>
     pub progress_in_mega_bytes: Option<i64>,
     /// <p>The time that the table restore request was made, in Universal Coordinated Time (UTC).</p>
-    pub request_time: Option<String>,
+    pub request_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The identifier of the snapshot that the table is being restored from.</p>
     pub snapshot_identifier: Option<String>,
     /// <p>The name of the source database that contains the table being restored.</p>
@@ -10609,7 +11968,7 @@ pub struct TableRestoreStatus {
     /// <p>The name of the source table being restored.</p>
     pub source_table_name: Option<String>,
     /// <p>A value that describes the current state of the table restore request.</p> <p>Valid Values: <code>SUCCEEDED</code>, <code>FAILED</code>, <code>CANCELED</code>, <code>PENDING</code>, <code>IN_PROGRESS</code> </p>
-    pub status: Option<String>,
+    pub status: Option<TableRestoreStatusType>,
     /// <p>The unique identifier for the table restore request.</p>
     pub table_restore_request_id: Option<String>,
     /// <p>The name of the database to restore the table to.</p>
@@ -10762,12 +12121,125 @@ impl TableRestoreStatusMessageDeserializer {
         )
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TableRestoreStatusType {
+    /// `PENDING`
+    Pending,
+    /// `IN_PROGRESS`
+    InProgress,
+    /// `SUCCEEDED`
+    Succeeded,
+    /// `FAILED`
+    Failed,
+    /// `CANCELED`
+    Canceled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TableRestoreStatusType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TableRestoreStatusType::Pending => "PENDING",
+            TableRestoreStatusType::InProgress => "IN_PROGRESS",
+            TableRestoreStatusType::Succeeded => "SUCCEEDED",
+            TableRestoreStatusType::Failed => "FAILED",
+            TableRestoreStatusType::Canceled => "CANCELED",
+            TableRestoreStatusType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TableRestoreStatusType {
+    fn default() -> TableRestoreStatusType {
+        TableRestoreStatusType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TableRestoreStatusType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TableRestoreStatusType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TableRestoreStatusType {
+    fn from(value: &'a str) -> TableRestoreStatusType {
+        match value {
+            "PENDING" => TableRestoreStatusType::Pending,
+            "IN_PROGRESS" => TableRestoreStatusType::InProgress,
+            "SUCCEEDED" => TableRestoreStatusType::Succeeded,
+            "FAILED" => TableRestoreStatusType::Failed,
+            "CANCELED" => TableRestoreStatusType::Canceled,
+            _ => TableRestoreStatusType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TableRestoreStatusType {
+    fn from(value: String) -> TableRestoreStatusType {
+        match value.as_str() {
+            "PENDING" => TableRestoreStatusType::Pending,
+            "IN_PROGRESS" => TableRestoreStatusType::InProgress,
+            "SUCCEEDED" => TableRestoreStatusType::Succeeded,
+            "FAILED" => TableRestoreStatusType::Failed,
+            "CANCELED" => TableRestoreStatusType::Canceled,
+            _ => TableRestoreStatusType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TableRestoreStatusType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TableRestoreStatusType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TableRestoreStatusType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for TableRestoreStatusType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for TableRestoreStatusType {
+    fn deserialize<D>(deserializer: D) -> Result<TableRestoreStatusType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct TableRestoreStatusTypeDeserializer;
 impl TableRestoreStatusTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<TableRestoreStatusType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -18259,11 +19731,12 @@ pub struct RedshiftClient {
 impl RedshiftClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> RedshiftClient {
         RedshiftClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Redshift"),
         }
     }
 
@@ -18296,6 +19769,7 @@ impl Redshift for RedshiftClient {
     ) -> Result<AcceptReservedNodeExchangeOutputMessage, RusotoError<AcceptReservedNodeExchangeError>>
     {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("AcceptReservedNodeExchange");
         let mut params = Params::new();
 
         params.put("Action", "AcceptReservedNodeExchange");
@@ -18348,6 +19822,7 @@ impl Redshift for RedshiftClient {
         RusotoError<AuthorizeClusterSecurityGroupIngressError>,
     > {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("AuthorizeClusterSecurityGroupIngress");
         let mut params = Params::new();
 
         params.put("Action", "AuthorizeClusterSecurityGroupIngress");
@@ -18399,6 +19874,7 @@ impl Redshift for RedshiftClient {
         input: AuthorizeSnapshotAccessMessage,
     ) -> Result<AuthorizeSnapshotAccessResult, RusotoError<AuthorizeSnapshotAccessError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("AuthorizeSnapshotAccess");
         let mut params = Params::new();
 
         params.put("Action", "AuthorizeSnapshotAccess");
@@ -18449,6 +19925,7 @@ impl Redshift for RedshiftClient {
     ) -> Result<BatchDeleteClusterSnapshotsResult, RusotoError<BatchDeleteClusterSnapshotsError>>
     {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("BatchDeleteClusterSnapshots");
         let mut params = Params::new();
 
         params.put("Action", "BatchDeleteClusterSnapshots");
@@ -18501,6 +19978,7 @@ impl Redshift for RedshiftClient {
         RusotoError<BatchModifyClusterSnapshotsError>,
     > {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("BatchModifyClusterSnapshots");
         let mut params = Params::new();
 
         params.put("Action", "BatchModifyClusterSnapshots");
@@ -18550,6 +20028,7 @@ impl Redshift for RedshiftClient {
         input: CancelResizeMessage,
     ) -> Result<ResizeProgressMessage, RusotoError<CancelResizeError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("CancelResize");
         let mut params = Params::new();
 
         params.put("Action", "CancelResize");
@@ -18597,6 +20076,7 @@ impl Redshift for RedshiftClient {
         input: CopyClusterSnapshotMessage,
     ) -> Result<CopyClusterSnapshotResult, RusotoError<CopyClusterSnapshotError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("CopyClusterSnapshot");
         let mut params = Params::new();
 
         params.put("Action", "CopyClusterSnapshot");
@@ -18646,6 +20126,7 @@ impl Redshift for RedshiftClient {
         input: CreateClusterMessage,
     ) -> Result<CreateClusterResult, RusotoError<CreateClusterError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("CreateCluster");
         let mut params = Params::new();
 
         params.put("Action", "CreateCluster");
@@ -18694,6 +20175,7 @@ impl Redshift for RedshiftClient {
     ) -> Result<CreateClusterParameterGroupResult, RusotoError<CreateClusterParameterGroupError>>
     {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("CreateClusterParameterGroup");
         let mut params = Params::new();
 
         params.put("Action", "CreateClusterParameterGroup");
@@ -18744,6 +20226,7 @@ impl Redshift for RedshiftClient {
     ) -> Result<CreateClusterSecurityGroupResult, RusotoError<CreateClusterSecurityGroupError>>
     {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("CreateClusterSecurityGroup");
         let mut params = Params::new();
 
         params.put("Action", "CreateClusterSecurityGroup");
@@ -18793,6 +20276,7 @@ impl Redshift for RedshiftClient {
         input: CreateClusterSnapshotMessage,
     ) -> Result<CreateClusterSnapshotResult, RusotoError<CreateClusterSnapshotError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("CreateClusterSnapshot");
         let mut params = Params::new();
 
         params.put("Action", "CreateClusterSnapshot");
@@ -18842,6 +20326,7 @@ impl Redshift for RedshiftClient {
         input: CreateClusterSubnetGroupMessage,
     ) -> Result<CreateClusterSubnetGroupResult, RusotoError<CreateClusterSubnetGroupError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("CreateClusterSubnetGroup");
         let mut params = Params::new();
 
         params.put("Action", "CreateClusterSubnetGroup");
@@ -18891,6 +20376,7 @@ impl Redshift for RedshiftClient {
         input: CreateEventSubscriptionMessage,
     ) -> Result<CreateEventSubscriptionResult, RusotoError<CreateEventSubscriptionError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("CreateEventSubscription");
         let mut params = Params::new();
 
         params.put("Action", "CreateEventSubscription");
@@ -18941,6 +20427,7 @@ impl Redshift for RedshiftClient {
     ) -> Result<CreateHsmClientCertificateResult, RusotoError<CreateHsmClientCertificateError>>
     {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("CreateHsmClientCertificate");
         let mut params = Params::new();
 
         params.put("Action", "CreateHsmClientCertificate");
@@ -18990,6 +20477,7 @@ impl Redshift for RedshiftClient {
         input: CreateHsmConfigurationMessage,
     ) -> Result<CreateHsmConfigurationResult, RusotoError<CreateHsmConfigurationError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("CreateHsmConfiguration");
         let mut params = Params::new();
 
         params.put("Action", "CreateHsmConfiguration");
@@ -19039,6 +20527,7 @@ impl Redshift for RedshiftClient {
         input: CreateScheduledActionMessage,
     ) -> Result<ScheduledAction, RusotoError<CreateScheduledActionError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("CreateScheduledAction");
         let mut params = Params::new();
 
         params.put("Action", "CreateScheduledAction");
@@ -19088,6 +20577,7 @@ impl Redshift for RedshiftClient {
         input: CreateSnapshotCopyGrantMessage,
     ) -> Result<CreateSnapshotCopyGrantResult, RusotoError<CreateSnapshotCopyGrantError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("CreateSnapshotCopyGrant");
         let mut params = Params::new();
 
         params.put("Action", "CreateSnapshotCopyGrant");
@@ -19137,6 +20627,7 @@ impl Redshift for RedshiftClient {
         input: CreateSnapshotScheduleMessage,
     ) -> Result<SnapshotSchedule, RusotoError<CreateSnapshotScheduleError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("CreateSnapshotSchedule");
         let mut params = Params::new();
 
         params.put("Action", "CreateSnapshotSchedule");
@@ -19186,6 +20677,7 @@ impl Redshift for RedshiftClient {
         input: CreateTagsMessage,
     ) -> Result<(), RusotoError<CreateTagsError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("CreateTags");
         let mut params = Params::new();
 
         params.put("Action", "CreateTags");
@@ -19214,6 +20706,7 @@ impl Redshift for RedshiftClient {
         input: DeleteClusterMessage,
     ) -> Result<DeleteClusterResult, RusotoError<DeleteClusterError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DeleteCluster");
         let mut params = Params::new();
 
         params.put("Action", "DeleteCluster");
@@ -19261,6 +20754,7 @@ impl Redshift for RedshiftClient {
         input: DeleteClusterParameterGroupMessage,
     ) -> Result<(), RusotoError<DeleteClusterParameterGroupError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DeleteClusterParameterGroup");
         let mut params = Params::new();
 
         params.put("Action", "DeleteClusterParameterGroup");
@@ -19289,6 +20783,7 @@ impl Redshift for RedshiftClient {
         input: DeleteClusterSecurityGroupMessage,
     ) -> Result<(), RusotoError<DeleteClusterSecurityGroupError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DeleteClusterSecurityGroup");
         let mut params = Params::new();
 
         params.put("Action", "DeleteClusterSecurityGroup");
@@ -19317,6 +20812,7 @@ impl Redshift for RedshiftClient {
         input: DeleteClusterSnapshotMessage,
     ) -> Result<DeleteClusterSnapshotResult, RusotoError<DeleteClusterSnapshotError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DeleteClusterSnapshot");
         let mut params = Params::new();
 
         params.put("Action", "DeleteClusterSnapshot");
@@ -19366,6 +20862,7 @@ impl Redshift for RedshiftClient {
         input: DeleteClusterSubnetGroupMessage,
     ) -> Result<(), RusotoError<DeleteClusterSubnetGroupError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DeleteClusterSubnetGroup");
         let mut params = Params::new();
 
         params.put("Action", "DeleteClusterSubnetGroup");
@@ -19394,6 +20891,7 @@ impl Redshift for RedshiftClient {
         input: DeleteEventSubscriptionMessage,
     ) -> Result<(), RusotoError<DeleteEventSubscriptionError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DeleteEventSubscription");
         let mut params = Params::new();
 
         params.put("Action", "DeleteEventSubscription");
@@ -19422,6 +20920,7 @@ impl Redshift for RedshiftClient {
         input: DeleteHsmClientCertificateMessage,
     ) -> Result<(), RusotoError<DeleteHsmClientCertificateError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DeleteHsmClientCertificate");
         let mut params = Params::new();
 
         params.put("Action", "DeleteHsmClientCertificate");
@@ -19450,6 +20949,7 @@ impl Redshift for RedshiftClient {
         input: DeleteHsmConfigurationMessage,
     ) -> Result<(), RusotoError<DeleteHsmConfigurationError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DeleteHsmConfiguration");
         let mut params = Params::new();
 
         params.put("Action", "DeleteHsmConfiguration");
@@ -19478,6 +20978,7 @@ impl Redshift for RedshiftClient {
         input: DeleteScheduledActionMessage,
     ) -> Result<(), RusotoError<DeleteScheduledActionError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DeleteScheduledAction");
         let mut params = Params::new();
 
         params.put("Action", "DeleteScheduledAction");
@@ -19506,6 +21007,7 @@ impl Redshift for RedshiftClient {
         input: DeleteSnapshotCopyGrantMessage,
     ) -> Result<(), RusotoError<DeleteSnapshotCopyGrantError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DeleteSnapshotCopyGrant");
         let mut params = Params::new();
 
         params.put("Action", "DeleteSnapshotCopyGrant");
@@ -19534,6 +21036,7 @@ impl Redshift for RedshiftClient {
         input: DeleteSnapshotScheduleMessage,
     ) -> Result<(), RusotoError<DeleteSnapshotScheduleError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DeleteSnapshotSchedule");
         let mut params = Params::new();
 
         params.put("Action", "DeleteSnapshotSchedule");
@@ -19562,6 +21065,7 @@ impl Redshift for RedshiftClient {
         input: DeleteTagsMessage,
     ) -> Result<(), RusotoError<DeleteTagsError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DeleteTags");
         let mut params = Params::new();
 
         params.put("Action", "DeleteTags");
@@ -19590,6 +21094,7 @@ impl Redshift for RedshiftClient {
         input: DescribeAccountAttributesMessage,
     ) -> Result<AccountAttributeList, RusotoError<DescribeAccountAttributesError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeAccountAttributes");
         let mut params = Params::new();
 
         params.put("Action", "DescribeAccountAttributes");
@@ -19639,6 +21144,7 @@ impl Redshift for RedshiftClient {
         input: DescribeClusterDbRevisionsMessage,
     ) -> Result<ClusterDbRevisionsMessage, RusotoError<DescribeClusterDbRevisionsError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeClusterDbRevisions");
         let mut params = Params::new();
 
         params.put("Action", "DescribeClusterDbRevisions");
@@ -19689,6 +21195,7 @@ impl Redshift for RedshiftClient {
     ) -> Result<ClusterParameterGroupsMessage, RusotoError<DescribeClusterParameterGroupsError>>
     {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeClusterParameterGroups");
         let mut params = Params::new();
 
         params.put("Action", "DescribeClusterParameterGroups");
@@ -19738,6 +21245,7 @@ impl Redshift for RedshiftClient {
         input: DescribeClusterParametersMessage,
     ) -> Result<ClusterParameterGroupDetails, RusotoError<DescribeClusterParametersError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeClusterParameters");
         let mut params = Params::new();
 
         params.put("Action", "DescribeClusterParameters");
@@ -19787,6 +21295,7 @@ impl Redshift for RedshiftClient {
         input: DescribeClusterSecurityGroupsMessage,
     ) -> Result<ClusterSecurityGroupMessage, RusotoError<DescribeClusterSecurityGroupsError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeClusterSecurityGroups");
         let mut params = Params::new();
 
         params.put("Action", "DescribeClusterSecurityGroups");
@@ -19836,6 +21345,7 @@ impl Redshift for RedshiftClient {
         input: DescribeClusterSnapshotsMessage,
     ) -> Result<SnapshotMessage, RusotoError<DescribeClusterSnapshotsError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeClusterSnapshots");
         let mut params = Params::new();
 
         params.put("Action", "DescribeClusterSnapshots");
@@ -19885,6 +21395,7 @@ impl Redshift for RedshiftClient {
         input: DescribeClusterSubnetGroupsMessage,
     ) -> Result<ClusterSubnetGroupMessage, RusotoError<DescribeClusterSubnetGroupsError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeClusterSubnetGroups");
         let mut params = Params::new();
 
         params.put("Action", "DescribeClusterSubnetGroups");
@@ -19934,6 +21445,7 @@ impl Redshift for RedshiftClient {
         input: DescribeClusterTracksMessage,
     ) -> Result<TrackListMessage, RusotoError<DescribeClusterTracksError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeClusterTracks");
         let mut params = Params::new();
 
         params.put("Action", "DescribeClusterTracks");
@@ -19983,6 +21495,7 @@ impl Redshift for RedshiftClient {
         input: DescribeClusterVersionsMessage,
     ) -> Result<ClusterVersionsMessage, RusotoError<DescribeClusterVersionsError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeClusterVersions");
         let mut params = Params::new();
 
         params.put("Action", "DescribeClusterVersions");
@@ -20032,6 +21545,7 @@ impl Redshift for RedshiftClient {
         input: DescribeClustersMessage,
     ) -> Result<ClustersMessage, RusotoError<DescribeClustersError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeClusters");
         let mut params = Params::new();
 
         params.put("Action", "DescribeClusters");
@@ -20082,6 +21596,7 @@ impl Redshift for RedshiftClient {
         RusotoError<DescribeDefaultClusterParametersError>,
     > {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeDefaultClusterParameters");
         let mut params = Params::new();
 
         params.put("Action", "DescribeDefaultClusterParameters");
@@ -20133,6 +21648,7 @@ impl Redshift for RedshiftClient {
         input: DescribeEventCategoriesMessage,
     ) -> Result<EventCategoriesMessage, RusotoError<DescribeEventCategoriesError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeEventCategories");
         let mut params = Params::new();
 
         params.put("Action", "DescribeEventCategories");
@@ -20182,6 +21698,7 @@ impl Redshift for RedshiftClient {
         input: DescribeEventSubscriptionsMessage,
     ) -> Result<EventSubscriptionsMessage, RusotoError<DescribeEventSubscriptionsError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeEventSubscriptions");
         let mut params = Params::new();
 
         params.put("Action", "DescribeEventSubscriptions");
@@ -20231,6 +21748,7 @@ impl Redshift for RedshiftClient {
         input: DescribeEventsMessage,
     ) -> Result<EventsMessage, RusotoError<DescribeEventsError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeEvents");
         let mut params = Params::new();
 
         params.put("Action", "DescribeEvents");
@@ -20277,6 +21795,7 @@ impl Redshift for RedshiftClient {
         input: DescribeHsmClientCertificatesMessage,
     ) -> Result<HsmClientCertificateMessage, RusotoError<DescribeHsmClientCertificatesError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeHsmClientCertificates");
         let mut params = Params::new();
 
         params.put("Action", "DescribeHsmClientCertificates");
@@ -20326,6 +21845,7 @@ impl Redshift for RedshiftClient {
         input: DescribeHsmConfigurationsMessage,
     ) -> Result<HsmConfigurationMessage, RusotoError<DescribeHsmConfigurationsError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeHsmConfigurations");
         let mut params = Params::new();
 
         params.put("Action", "DescribeHsmConfigurations");
@@ -20375,6 +21895,7 @@ impl Redshift for RedshiftClient {
         input: DescribeLoggingStatusMessage,
     ) -> Result<LoggingStatus, RusotoError<DescribeLoggingStatusError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeLoggingStatus");
         let mut params = Params::new();
 
         params.put("Action", "DescribeLoggingStatus");
@@ -20423,6 +21944,7 @@ impl Redshift for RedshiftClient {
     ) -> Result<NodeConfigurationOptionsMessage, RusotoError<DescribeNodeConfigurationOptionsError>>
     {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeNodeConfigurationOptions");
         let mut params = Params::new();
 
         params.put("Action", "DescribeNodeConfigurationOptions");
@@ -20475,6 +21997,7 @@ impl Redshift for RedshiftClient {
     ) -> Result<OrderableClusterOptionsMessage, RusotoError<DescribeOrderableClusterOptionsError>>
     {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeOrderableClusterOptions");
         let mut params = Params::new();
 
         params.put("Action", "DescribeOrderableClusterOptions");
@@ -20526,6 +22049,7 @@ impl Redshift for RedshiftClient {
         input: DescribeReservedNodeOfferingsMessage,
     ) -> Result<ReservedNodeOfferingsMessage, RusotoError<DescribeReservedNodeOfferingsError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeReservedNodeOfferings");
         let mut params = Params::new();
 
         params.put("Action", "DescribeReservedNodeOfferings");
@@ -20575,6 +22099,7 @@ impl Redshift for RedshiftClient {
         input: DescribeReservedNodesMessage,
     ) -> Result<ReservedNodesMessage, RusotoError<DescribeReservedNodesError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeReservedNodes");
         let mut params = Params::new();
 
         params.put("Action", "DescribeReservedNodes");
@@ -20624,6 +22149,7 @@ impl Redshift for RedshiftClient {
         input: DescribeResizeMessage,
     ) -> Result<ResizeProgressMessage, RusotoError<DescribeResizeError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeResize");
         let mut params = Params::new();
 
         params.put("Action", "DescribeResize");
@@ -20671,6 +22197,7 @@ impl Redshift for RedshiftClient {
         input: DescribeScheduledActionsMessage,
     ) -> Result<ScheduledActionsMessage, RusotoError<DescribeScheduledActionsError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeScheduledActions");
         let mut params = Params::new();
 
         params.put("Action", "DescribeScheduledActions");
@@ -20720,6 +22247,7 @@ impl Redshift for RedshiftClient {
         input: DescribeSnapshotCopyGrantsMessage,
     ) -> Result<SnapshotCopyGrantMessage, RusotoError<DescribeSnapshotCopyGrantsError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeSnapshotCopyGrants");
         let mut params = Params::new();
 
         params.put("Action", "DescribeSnapshotCopyGrants");
@@ -20770,6 +22298,7 @@ impl Redshift for RedshiftClient {
     ) -> Result<DescribeSnapshotSchedulesOutputMessage, RusotoError<DescribeSnapshotSchedulesError>>
     {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeSnapshotSchedules");
         let mut params = Params::new();
 
         params.put("Action", "DescribeSnapshotSchedules");
@@ -20818,6 +22347,7 @@ impl Redshift for RedshiftClient {
         &self,
     ) -> Result<CustomerStorageMessage, RusotoError<DescribeStorageError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeStorage");
         let mut params = Params::new();
 
         params.put("Action", "DescribeStorage");
@@ -20867,6 +22397,7 @@ impl Redshift for RedshiftClient {
         input: DescribeTableRestoreStatusMessage,
     ) -> Result<TableRestoreStatusMessage, RusotoError<DescribeTableRestoreStatusError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeTableRestoreStatus");
         let mut params = Params::new();
 
         params.put("Action", "DescribeTableRestoreStatus");
@@ -20916,6 +22447,7 @@ impl Redshift for RedshiftClient {
         input: DescribeTagsMessage,
     ) -> Result<TaggedResourceListMessage, RusotoError<DescribeTagsError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeTags");
         let mut params = Params::new();
 
         params.put("Action", "DescribeTags");
@@ -20965,6 +22497,7 @@ impl Redshift for RedshiftClient {
         input: DisableLoggingMessage,
     ) -> Result<LoggingStatus, RusotoError<DisableLoggingError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DisableLogging");
         let mut params = Params::new();
 
         params.put("Action", "DisableLogging");
@@ -21011,6 +22544,7 @@ impl Redshift for RedshiftClient {
         input: DisableSnapshotCopyMessage,
     ) -> Result<DisableSnapshotCopyResult, RusotoError<DisableSnapshotCopyError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DisableSnapshotCopy");
         let mut params = Params::new();
 
         params.put("Action", "DisableSnapshotCopy");
@@ -21060,6 +22594,7 @@ impl Redshift for RedshiftClient {
         input: EnableLoggingMessage,
     ) -> Result<LoggingStatus, RusotoError<EnableLoggingError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("EnableLogging");
         let mut params = Params::new();
 
         params.put("Action", "EnableLogging");
@@ -21106,6 +22641,7 @@ impl Redshift for RedshiftClient {
         input: EnableSnapshotCopyMessage,
     ) -> Result<EnableSnapshotCopyResult, RusotoError<EnableSnapshotCopyError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("EnableSnapshotCopy");
         let mut params = Params::new();
 
         params.put("Action", "EnableSnapshotCopy");
@@ -21155,6 +22691,7 @@ impl Redshift for RedshiftClient {
         input: GetClusterCredentialsMessage,
     ) -> Result<ClusterCredentials, RusotoError<GetClusterCredentialsError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("GetClusterCredentials");
         let mut params = Params::new();
 
         params.put("Action", "GetClusterCredentials");
@@ -21207,6 +22744,7 @@ impl Redshift for RedshiftClient {
         RusotoError<GetReservedNodeExchangeOfferingsError>,
     > {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("GetReservedNodeExchangeOfferings");
         let mut params = Params::new();
 
         params.put("Action", "GetReservedNodeExchangeOfferings");
@@ -21258,6 +22796,7 @@ impl Redshift for RedshiftClient {
         input: ModifyClusterMessage,
     ) -> Result<ModifyClusterResult, RusotoError<ModifyClusterError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("ModifyCluster");
         let mut params = Params::new();
 
         params.put("Action", "ModifyCluster");
@@ -21305,6 +22844,7 @@ impl Redshift for RedshiftClient {
         input: ModifyClusterDbRevisionMessage,
     ) -> Result<ModifyClusterDbRevisionResult, RusotoError<ModifyClusterDbRevisionError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("ModifyClusterDbRevision");
         let mut params = Params::new();
 
         params.put("Action", "ModifyClusterDbRevision");
@@ -21354,6 +22894,7 @@ impl Redshift for RedshiftClient {
         input: ModifyClusterIamRolesMessage,
     ) -> Result<ModifyClusterIamRolesResult, RusotoError<ModifyClusterIamRolesError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("ModifyClusterIamRoles");
         let mut params = Params::new();
 
         params.put("Action", "ModifyClusterIamRoles");
@@ -21403,6 +22944,7 @@ impl Redshift for RedshiftClient {
         input: ModifyClusterMaintenanceMessage,
     ) -> Result<ModifyClusterMaintenanceResult, RusotoError<ModifyClusterMaintenanceError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("ModifyClusterMaintenance");
         let mut params = Params::new();
 
         params.put("Action", "ModifyClusterMaintenance");
@@ -21453,6 +22995,7 @@ impl Redshift for RedshiftClient {
     ) -> Result<ClusterParameterGroupNameMessage, RusotoError<ModifyClusterParameterGroupError>>
     {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("ModifyClusterParameterGroup");
         let mut params = Params::new();
 
         params.put("Action", "ModifyClusterParameterGroup");
@@ -21502,6 +23045,7 @@ impl Redshift for RedshiftClient {
         input: ModifyClusterSnapshotMessage,
     ) -> Result<ModifyClusterSnapshotResult, RusotoError<ModifyClusterSnapshotError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("ModifyClusterSnapshot");
         let mut params = Params::new();
 
         params.put("Action", "ModifyClusterSnapshot");
@@ -21551,6 +23095,7 @@ impl Redshift for RedshiftClient {
         input: ModifyClusterSnapshotScheduleMessage,
     ) -> Result<(), RusotoError<ModifyClusterSnapshotScheduleError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("ModifyClusterSnapshotSchedule");
         let mut params = Params::new();
 
         params.put("Action", "ModifyClusterSnapshotSchedule");
@@ -21579,6 +23124,7 @@ impl Redshift for RedshiftClient {
         input: ModifyClusterSubnetGroupMessage,
     ) -> Result<ModifyClusterSubnetGroupResult, RusotoError<ModifyClusterSubnetGroupError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("ModifyClusterSubnetGroup");
         let mut params = Params::new();
 
         params.put("Action", "ModifyClusterSubnetGroup");
@@ -21628,6 +23174,7 @@ impl Redshift for RedshiftClient {
         input: ModifyEventSubscriptionMessage,
     ) -> Result<ModifyEventSubscriptionResult, RusotoError<ModifyEventSubscriptionError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("ModifyEventSubscription");
         let mut params = Params::new();
 
         params.put("Action", "ModifyEventSubscription");
@@ -21677,6 +23224,7 @@ impl Redshift for RedshiftClient {
         input: ModifyScheduledActionMessage,
     ) -> Result<ScheduledAction, RusotoError<ModifyScheduledActionError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("ModifyScheduledAction");
         let mut params = Params::new();
 
         params.put("Action", "ModifyScheduledAction");
@@ -21729,6 +23277,7 @@ impl Redshift for RedshiftClient {
         RusotoError<ModifySnapshotCopyRetentionPeriodError>,
     > {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("ModifySnapshotCopyRetentionPeriod");
         let mut params = Params::new();
 
         params.put("Action", "ModifySnapshotCopyRetentionPeriod");
@@ -21780,6 +23329,7 @@ impl Redshift for RedshiftClient {
         input: ModifySnapshotScheduleMessage,
     ) -> Result<SnapshotSchedule, RusotoError<ModifySnapshotScheduleError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("ModifySnapshotSchedule");
         let mut params = Params::new();
 
         params.put("Action", "ModifySnapshotSchedule");
@@ -21830,6 +23380,7 @@ impl Redshift for RedshiftClient {
     ) -> Result<PurchaseReservedNodeOfferingResult, RusotoError<PurchaseReservedNodeOfferingError>>
     {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("PurchaseReservedNodeOffering");
         let mut params = Params::new();
 
         params.put("Action", "PurchaseReservedNodeOffering");
@@ -21879,6 +23430,7 @@ impl Redshift for RedshiftClient {
         input: RebootClusterMessage,
     ) -> Result<RebootClusterResult, RusotoError<RebootClusterError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("RebootCluster");
         let mut params = Params::new();
 
         params.put("Action", "RebootCluster");
@@ -21927,6 +23479,7 @@ impl Redshift for RedshiftClient {
     ) -> Result<ClusterParameterGroupNameMessage, RusotoError<ResetClusterParameterGroupError>>
     {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("ResetClusterParameterGroup");
         let mut params = Params::new();
 
         params.put("Action", "ResetClusterParameterGroup");
@@ -21976,6 +23529,7 @@ impl Redshift for RedshiftClient {
         input: ResizeClusterMessage,
     ) -> Result<ResizeClusterResult, RusotoError<ResizeClusterError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("ResizeCluster");
         let mut params = Params::new();
 
         params.put("Action", "ResizeCluster");
@@ -22024,6 +23578,7 @@ impl Redshift for RedshiftClient {
     ) -> Result<RestoreFromClusterSnapshotResult, RusotoError<RestoreFromClusterSnapshotError>>
     {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("RestoreFromClusterSnapshot");
         let mut params = Params::new();
 
         params.put("Action", "RestoreFromClusterSnapshot");
@@ -22076,6 +23631,7 @@ impl Redshift for RedshiftClient {
         RusotoError<RestoreTableFromClusterSnapshotError>,
     > {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("RestoreTableFromClusterSnapshot");
         let mut params = Params::new();
 
         params.put("Action", "RestoreTableFromClusterSnapshot");
@@ -22130,6 +23686,7 @@ impl Redshift for RedshiftClient {
         RusotoError<RevokeClusterSecurityGroupIngressError>,
     > {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("RevokeClusterSecurityGroupIngress");
         let mut params = Params::new();
 
         params.put("Action", "RevokeClusterSecurityGroupIngress");
@@ -22181,6 +23738,7 @@ impl Redshift for RedshiftClient {
         input: RevokeSnapshotAccessMessage,
     ) -> Result<RevokeSnapshotAccessResult, RusotoError<RevokeSnapshotAccessError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("RevokeSnapshotAccess");
         let mut params = Params::new();
 
         params.put("Action", "RevokeSnapshotAccess");
@@ -22230,6 +23788,7 @@ impl Redshift for RedshiftClient {
         input: RotateEncryptionKeyMessage,
     ) -> Result<RotateEncryptionKeyResult, RusotoError<RotateEncryptionKeyError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("RotateEncryptionKey");
         let mut params = Params::new();
 
         params.put("Action", "RotateEncryptionKey");
@@ -22273,6 +23832,1090 @@ impl Redshift for RedshiftClient {
         Ok(result)
     }
 }
+impl RedshiftClient {
+    /// Auto-paginating version of `describe_cluster_db_revisions`, streaming every page of results.
+    pub fn describe_cluster_db_revisions_pages(
+        &self,
+        input: DescribeClusterDbRevisionsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ClusterDbRevisionsMessage,
+        DescribeClusterDbRevisionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RedshiftClient, input: DescribeClusterDbRevisionsMessage| async move {
+                client.describe_cluster_db_revisions(input).await
+            },
+            |input: &DescribeClusterDbRevisionsMessage, output: &ClusterDbRevisionsMessage| {
+                Some(DescribeClusterDbRevisionsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_cluster_db_revisions`, streaming the `ClusterDbRevisions` of every page of results.
+    pub fn describe_cluster_db_revisions_items(
+        &self,
+        input: DescribeClusterDbRevisionsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<ClusterDbRevision, DescribeClusterDbRevisionsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_cluster_db_revisions_pages(input),
+            |output: ClusterDbRevisionsMessage| output.cluster_db_revisions.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_cluster_parameter_groups`, streaming every page of results.
+    pub fn describe_cluster_parameter_groups_pages(
+        &self,
+        input: DescribeClusterParameterGroupsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ClusterParameterGroupsMessage,
+        DescribeClusterParameterGroupsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RedshiftClient, input: DescribeClusterParameterGroupsMessage| async move {
+                client.describe_cluster_parameter_groups(input).await
+            },
+            |input: &DescribeClusterParameterGroupsMessage,
+             output: &ClusterParameterGroupsMessage| {
+                Some(DescribeClusterParameterGroupsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_cluster_parameter_groups`, streaming the `ParameterGroups` of every page of results.
+    pub fn describe_cluster_parameter_groups_items(
+        &self,
+        input: DescribeClusterParameterGroupsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ClusterParameterGroup,
+        DescribeClusterParameterGroupsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_cluster_parameter_groups_pages(input),
+            |output: ClusterParameterGroupsMessage| output.parameter_groups.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_cluster_parameters`, streaming every page of results.
+    pub fn describe_cluster_parameters_pages(
+        &self,
+        input: DescribeClusterParametersMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ClusterParameterGroupDetails,
+        DescribeClusterParametersError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RedshiftClient, input: DescribeClusterParametersMessage| async move {
+                client.describe_cluster_parameters(input).await
+            },
+            |input: &DescribeClusterParametersMessage, output: &ClusterParameterGroupDetails| {
+                Some(DescribeClusterParametersMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_cluster_parameters`, streaming the `Parameters` of every page of results.
+    pub fn describe_cluster_parameters_items(
+        &self,
+        input: DescribeClusterParametersMessage,
+    ) -> rusoto_core::pagination::RusotoStream<Parameter, DescribeClusterParametersError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_cluster_parameters_pages(input),
+            |output: ClusterParameterGroupDetails| output.parameters.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_cluster_security_groups`, streaming every page of results.
+    pub fn describe_cluster_security_groups_pages(
+        &self,
+        input: DescribeClusterSecurityGroupsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ClusterSecurityGroupMessage,
+        DescribeClusterSecurityGroupsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RedshiftClient, input: DescribeClusterSecurityGroupsMessage| async move {
+                client.describe_cluster_security_groups(input).await
+            },
+            |input: &DescribeClusterSecurityGroupsMessage, output: &ClusterSecurityGroupMessage| {
+                Some(DescribeClusterSecurityGroupsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_cluster_security_groups`, streaming the `ClusterSecurityGroups` of every page of results.
+    pub fn describe_cluster_security_groups_items(
+        &self,
+        input: DescribeClusterSecurityGroupsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ClusterSecurityGroup,
+        DescribeClusterSecurityGroupsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_cluster_security_groups_pages(input),
+            |output: ClusterSecurityGroupMessage| {
+                output.cluster_security_groups.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_cluster_snapshots`, streaming every page of results.
+    pub fn describe_cluster_snapshots_pages(
+        &self,
+        input: DescribeClusterSnapshotsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<SnapshotMessage, DescribeClusterSnapshotsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RedshiftClient, input: DescribeClusterSnapshotsMessage| async move {
+                client.describe_cluster_snapshots(input).await
+            },
+            |input: &DescribeClusterSnapshotsMessage, output: &SnapshotMessage| {
+                Some(DescribeClusterSnapshotsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_cluster_snapshots`, streaming the `Snapshots` of every page of results.
+    pub fn describe_cluster_snapshots_items(
+        &self,
+        input: DescribeClusterSnapshotsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<Snapshot, DescribeClusterSnapshotsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_cluster_snapshots_pages(input),
+            |output: SnapshotMessage| output.snapshots.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_cluster_subnet_groups`, streaming every page of results.
+    pub fn describe_cluster_subnet_groups_pages(
+        &self,
+        input: DescribeClusterSubnetGroupsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ClusterSubnetGroupMessage,
+        DescribeClusterSubnetGroupsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RedshiftClient, input: DescribeClusterSubnetGroupsMessage| async move {
+                client.describe_cluster_subnet_groups(input).await
+            },
+            |input: &DescribeClusterSubnetGroupsMessage, output: &ClusterSubnetGroupMessage| {
+                Some(DescribeClusterSubnetGroupsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_cluster_subnet_groups`, streaming the `ClusterSubnetGroups` of every page of results.
+    pub fn describe_cluster_subnet_groups_items(
+        &self,
+        input: DescribeClusterSubnetGroupsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<ClusterSubnetGroup, DescribeClusterSubnetGroupsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_cluster_subnet_groups_pages(input),
+            |output: ClusterSubnetGroupMessage| output.cluster_subnet_groups.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_cluster_tracks`, streaming every page of results.
+    pub fn describe_cluster_tracks_pages(
+        &self,
+        input: DescribeClusterTracksMessage,
+    ) -> rusoto_core::pagination::RusotoStream<TrackListMessage, DescribeClusterTracksError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RedshiftClient, input: DescribeClusterTracksMessage| async move {
+                client.describe_cluster_tracks(input).await
+            },
+            |input: &DescribeClusterTracksMessage, output: &TrackListMessage| {
+                Some(DescribeClusterTracksMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_cluster_tracks`, streaming the `MaintenanceTracks` of every page of results.
+    pub fn describe_cluster_tracks_items(
+        &self,
+        input: DescribeClusterTracksMessage,
+    ) -> rusoto_core::pagination::RusotoStream<MaintenanceTrack, DescribeClusterTracksError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_cluster_tracks_pages(input),
+            |output: TrackListMessage| output.maintenance_tracks.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_cluster_versions`, streaming every page of results.
+    pub fn describe_cluster_versions_pages(
+        &self,
+        input: DescribeClusterVersionsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<ClusterVersionsMessage, DescribeClusterVersionsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RedshiftClient, input: DescribeClusterVersionsMessage| async move {
+                client.describe_cluster_versions(input).await
+            },
+            |input: &DescribeClusterVersionsMessage, output: &ClusterVersionsMessage| {
+                Some(DescribeClusterVersionsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_cluster_versions`, streaming the `ClusterVersions` of every page of results.
+    pub fn describe_cluster_versions_items(
+        &self,
+        input: DescribeClusterVersionsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<ClusterVersion, DescribeClusterVersionsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_cluster_versions_pages(input),
+            |output: ClusterVersionsMessage| output.cluster_versions.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_clusters`, streaming every page of results.
+    pub fn describe_clusters_pages(
+        &self,
+        input: DescribeClustersMessage,
+    ) -> rusoto_core::pagination::RusotoStream<ClustersMessage, DescribeClustersError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RedshiftClient, input: DescribeClustersMessage| async move {
+                client.describe_clusters(input).await
+            },
+            |input: &DescribeClustersMessage, output: &ClustersMessage| {
+                Some(DescribeClustersMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_clusters`, streaming the `Clusters` of every page of results.
+    pub fn describe_clusters_items(
+        &self,
+        input: DescribeClustersMessage,
+    ) -> rusoto_core::pagination::RusotoStream<Cluster, DescribeClustersError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_clusters_pages(input),
+            |output: ClustersMessage| output.clusters.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_default_cluster_parameters`, streaming every page of results.
+    pub fn describe_default_cluster_parameters_pages(
+        &self,
+        input: DescribeDefaultClusterParametersMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeDefaultClusterParametersResult,
+        DescribeDefaultClusterParametersError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RedshiftClient, input: DescribeDefaultClusterParametersMessage| async move {
+                client.describe_default_cluster_parameters(input).await
+            },
+            |input: &DescribeDefaultClusterParametersMessage,
+             output: &DescribeDefaultClusterParametersResult| {
+                Some(DescribeDefaultClusterParametersMessage {
+                    marker: Some(
+                        output
+                            .default_cluster_parameters
+                            .as_ref()
+                            .and_then(|v| v.marker.clone())?,
+                    ),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_default_cluster_parameters`, streaming the `DefaultClusterParameters.Parameters` of every page of results.
+    pub fn describe_default_cluster_parameters_items(
+        &self,
+        input: DescribeDefaultClusterParametersMessage,
+    ) -> rusoto_core::pagination::RusotoStream<Parameter, DescribeDefaultClusterParametersError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_default_cluster_parameters_pages(input),
+            |output: DescribeDefaultClusterParametersResult| {
+                output
+                    .default_cluster_parameters
+                    .and_then(|v| v.parameters)
+                    .unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_event_subscriptions`, streaming every page of results.
+    pub fn describe_event_subscriptions_pages(
+        &self,
+        input: DescribeEventSubscriptionsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        EventSubscriptionsMessage,
+        DescribeEventSubscriptionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RedshiftClient, input: DescribeEventSubscriptionsMessage| async move {
+                client.describe_event_subscriptions(input).await
+            },
+            |input: &DescribeEventSubscriptionsMessage, output: &EventSubscriptionsMessage| {
+                Some(DescribeEventSubscriptionsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_event_subscriptions`, streaming the `EventSubscriptionsList` of every page of results.
+    pub fn describe_event_subscriptions_items(
+        &self,
+        input: DescribeEventSubscriptionsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<EventSubscription, DescribeEventSubscriptionsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_event_subscriptions_pages(input),
+            |output: EventSubscriptionsMessage| output.event_subscriptions_list.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_events`, streaming every page of results.
+    pub fn describe_events_pages(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<EventsMessage, DescribeEventsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RedshiftClient, input: DescribeEventsMessage| async move {
+                client.describe_events(input).await
+            },
+            |input: &DescribeEventsMessage, output: &EventsMessage| {
+                Some(DescribeEventsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_events`, streaming the `Events` of every page of results.
+    pub fn describe_events_items(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<Event, DescribeEventsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_events_pages(input),
+            |output: EventsMessage| output.events.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_hsm_client_certificates`, streaming every page of results.
+    pub fn describe_hsm_client_certificates_pages(
+        &self,
+        input: DescribeHsmClientCertificatesMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        HsmClientCertificateMessage,
+        DescribeHsmClientCertificatesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RedshiftClient, input: DescribeHsmClientCertificatesMessage| async move {
+                client.describe_hsm_client_certificates(input).await
+            },
+            |input: &DescribeHsmClientCertificatesMessage, output: &HsmClientCertificateMessage| {
+                Some(DescribeHsmClientCertificatesMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_hsm_client_certificates`, streaming the `HsmClientCertificates` of every page of results.
+    pub fn describe_hsm_client_certificates_items(
+        &self,
+        input: DescribeHsmClientCertificatesMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        HsmClientCertificate,
+        DescribeHsmClientCertificatesError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_hsm_client_certificates_pages(input),
+            |output: HsmClientCertificateMessage| {
+                output.hsm_client_certificates.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_hsm_configurations`, streaming every page of results.
+    pub fn describe_hsm_configurations_pages(
+        &self,
+        input: DescribeHsmConfigurationsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        HsmConfigurationMessage,
+        DescribeHsmConfigurationsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RedshiftClient, input: DescribeHsmConfigurationsMessage| async move {
+                client.describe_hsm_configurations(input).await
+            },
+            |input: &DescribeHsmConfigurationsMessage, output: &HsmConfigurationMessage| {
+                Some(DescribeHsmConfigurationsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_hsm_configurations`, streaming the `HsmConfigurations` of every page of results.
+    pub fn describe_hsm_configurations_items(
+        &self,
+        input: DescribeHsmConfigurationsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<HsmConfiguration, DescribeHsmConfigurationsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_hsm_configurations_pages(input),
+            |output: HsmConfigurationMessage| output.hsm_configurations.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_node_configuration_options`, streaming every page of results.
+    pub fn describe_node_configuration_options_pages(
+        &self,
+        input: DescribeNodeConfigurationOptionsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        NodeConfigurationOptionsMessage,
+        DescribeNodeConfigurationOptionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RedshiftClient, input: DescribeNodeConfigurationOptionsMessage| async move {
+                client.describe_node_configuration_options(input).await
+            },
+            |input: &DescribeNodeConfigurationOptionsMessage,
+             output: &NodeConfigurationOptionsMessage| {
+                Some(DescribeNodeConfigurationOptionsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_node_configuration_options`, streaming the `NodeConfigurationOptionList` of every page of results.
+    pub fn describe_node_configuration_options_items(
+        &self,
+        input: DescribeNodeConfigurationOptionsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        NodeConfigurationOption,
+        DescribeNodeConfigurationOptionsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_node_configuration_options_pages(input),
+            |output: NodeConfigurationOptionsMessage| {
+                output.node_configuration_option_list.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_orderable_cluster_options`, streaming every page of results.
+    pub fn describe_orderable_cluster_options_pages(
+        &self,
+        input: DescribeOrderableClusterOptionsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        OrderableClusterOptionsMessage,
+        DescribeOrderableClusterOptionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RedshiftClient, input: DescribeOrderableClusterOptionsMessage| async move {
+                client.describe_orderable_cluster_options(input).await
+            },
+            |input: &DescribeOrderableClusterOptionsMessage,
+             output: &OrderableClusterOptionsMessage| {
+                Some(DescribeOrderableClusterOptionsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_orderable_cluster_options`, streaming the `OrderableClusterOptions` of every page of results.
+    pub fn describe_orderable_cluster_options_items(
+        &self,
+        input: DescribeOrderableClusterOptionsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        OrderableClusterOption,
+        DescribeOrderableClusterOptionsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_orderable_cluster_options_pages(input),
+            |output: OrderableClusterOptionsMessage| {
+                output.orderable_cluster_options.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_reserved_node_offerings`, streaming every page of results.
+    pub fn describe_reserved_node_offerings_pages(
+        &self,
+        input: DescribeReservedNodeOfferingsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ReservedNodeOfferingsMessage,
+        DescribeReservedNodeOfferingsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RedshiftClient, input: DescribeReservedNodeOfferingsMessage| async move {
+                client.describe_reserved_node_offerings(input).await
+            },
+            |input: &DescribeReservedNodeOfferingsMessage,
+             output: &ReservedNodeOfferingsMessage| {
+                Some(DescribeReservedNodeOfferingsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_reserved_node_offerings`, streaming the `ReservedNodeOfferings` of every page of results.
+    pub fn describe_reserved_node_offerings_items(
+        &self,
+        input: DescribeReservedNodeOfferingsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ReservedNodeOffering,
+        DescribeReservedNodeOfferingsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_reserved_node_offerings_pages(input),
+            |output: ReservedNodeOfferingsMessage| {
+                output.reserved_node_offerings.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_reserved_nodes`, streaming every page of results.
+    pub fn describe_reserved_nodes_pages(
+        &self,
+        input: DescribeReservedNodesMessage,
+    ) -> rusoto_core::pagination::RusotoStream<ReservedNodesMessage, DescribeReservedNodesError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RedshiftClient, input: DescribeReservedNodesMessage| async move {
+                client.describe_reserved_nodes(input).await
+            },
+            |input: &DescribeReservedNodesMessage, output: &ReservedNodesMessage| {
+                Some(DescribeReservedNodesMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_reserved_nodes`, streaming the `ReservedNodes` of every page of results.
+    pub fn describe_reserved_nodes_items(
+        &self,
+        input: DescribeReservedNodesMessage,
+    ) -> rusoto_core::pagination::RusotoStream<ReservedNode, DescribeReservedNodesError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_reserved_nodes_pages(input),
+            |output: ReservedNodesMessage| output.reserved_nodes.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_scheduled_actions`, streaming every page of results.
+    pub fn describe_scheduled_actions_pages(
+        &self,
+        input: DescribeScheduledActionsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<ScheduledActionsMessage, DescribeScheduledActionsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RedshiftClient, input: DescribeScheduledActionsMessage| async move {
+                client.describe_scheduled_actions(input).await
+            },
+            |input: &DescribeScheduledActionsMessage, output: &ScheduledActionsMessage| {
+                Some(DescribeScheduledActionsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_scheduled_actions`, streaming the `ScheduledActions` of every page of results.
+    pub fn describe_scheduled_actions_items(
+        &self,
+        input: DescribeScheduledActionsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<ScheduledAction, DescribeScheduledActionsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_scheduled_actions_pages(input),
+            |output: ScheduledActionsMessage| output.scheduled_actions.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_snapshot_copy_grants`, streaming every page of results.
+    pub fn describe_snapshot_copy_grants_pages(
+        &self,
+        input: DescribeSnapshotCopyGrantsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        SnapshotCopyGrantMessage,
+        DescribeSnapshotCopyGrantsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RedshiftClient, input: DescribeSnapshotCopyGrantsMessage| async move {
+                client.describe_snapshot_copy_grants(input).await
+            },
+            |input: &DescribeSnapshotCopyGrantsMessage, output: &SnapshotCopyGrantMessage| {
+                Some(DescribeSnapshotCopyGrantsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_snapshot_copy_grants`, streaming the `SnapshotCopyGrants` of every page of results.
+    pub fn describe_snapshot_copy_grants_items(
+        &self,
+        input: DescribeSnapshotCopyGrantsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<SnapshotCopyGrant, DescribeSnapshotCopyGrantsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_snapshot_copy_grants_pages(input),
+            |output: SnapshotCopyGrantMessage| output.snapshot_copy_grants.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_snapshot_schedules`, streaming every page of results.
+    pub fn describe_snapshot_schedules_pages(
+        &self,
+        input: DescribeSnapshotSchedulesMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeSnapshotSchedulesOutputMessage,
+        DescribeSnapshotSchedulesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RedshiftClient, input: DescribeSnapshotSchedulesMessage| async move {
+                client.describe_snapshot_schedules(input).await
+            },
+            |input: &DescribeSnapshotSchedulesMessage,
+             output: &DescribeSnapshotSchedulesOutputMessage| {
+                Some(DescribeSnapshotSchedulesMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_snapshot_schedules`, streaming the `SnapshotSchedules` of every page of results.
+    pub fn describe_snapshot_schedules_items(
+        &self,
+        input: DescribeSnapshotSchedulesMessage,
+    ) -> rusoto_core::pagination::RusotoStream<SnapshotSchedule, DescribeSnapshotSchedulesError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_snapshot_schedules_pages(input),
+            |output: DescribeSnapshotSchedulesOutputMessage| {
+                output.snapshot_schedules.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_table_restore_status`, streaming every page of results.
+    pub fn describe_table_restore_status_pages(
+        &self,
+        input: DescribeTableRestoreStatusMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        TableRestoreStatusMessage,
+        DescribeTableRestoreStatusError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RedshiftClient, input: DescribeTableRestoreStatusMessage| async move {
+                client.describe_table_restore_status(input).await
+            },
+            |input: &DescribeTableRestoreStatusMessage, output: &TableRestoreStatusMessage| {
+                Some(DescribeTableRestoreStatusMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_table_restore_status`, streaming the `TableRestoreStatusDetails` of every page of results.
+    pub fn describe_table_restore_status_items(
+        &self,
+        input: DescribeTableRestoreStatusMessage,
+    ) -> rusoto_core::pagination::RusotoStream<TableRestoreStatus, DescribeTableRestoreStatusError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_table_restore_status_pages(input),
+            |output: TableRestoreStatusMessage| {
+                output.table_restore_status_details.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_tags`, streaming every page of results.
+    pub fn describe_tags_pages(
+        &self,
+        input: DescribeTagsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<TaggedResourceListMessage, DescribeTagsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RedshiftClient, input: DescribeTagsMessage| async move {
+                client.describe_tags(input).await
+            },
+            |input: &DescribeTagsMessage, output: &TaggedResourceListMessage| {
+                Some(DescribeTagsMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_tags`, streaming the `TaggedResources` of every page of results.
+    pub fn describe_tags_items(
+        &self,
+        input: DescribeTagsMessage,
+    ) -> rusoto_core::pagination::RusotoStream<TaggedResource, DescribeTagsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_tags_pages(input),
+            |output: TaggedResourceListMessage| output.tagged_resources.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_reserved_node_exchange_offerings`, streaming every page of results.
+    pub fn get_reserved_node_exchange_offerings_pages(
+        &self,
+        input: GetReservedNodeExchangeOfferingsInputMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        GetReservedNodeExchangeOfferingsOutputMessage,
+        GetReservedNodeExchangeOfferingsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RedshiftClient, input: GetReservedNodeExchangeOfferingsInputMessage| async move {
+                client.get_reserved_node_exchange_offerings(input).await
+            },
+            |input: &GetReservedNodeExchangeOfferingsInputMessage,
+             output: &GetReservedNodeExchangeOfferingsOutputMessage| {
+                Some(GetReservedNodeExchangeOfferingsInputMessage {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_reserved_node_exchange_offerings`, streaming the `ReservedNodeOfferings` of every page of results.
+    pub fn get_reserved_node_exchange_offerings_items(
+        &self,
+        input: GetReservedNodeExchangeOfferingsInputMessage,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ReservedNodeOffering,
+        GetReservedNodeExchangeOfferingsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.get_reserved_node_exchange_offerings_pages(input),
+            |output: GetReservedNodeExchangeOfferingsOutputMessage| {
+                output.reserved_node_offerings.unwrap_or_default()
+            },
+        )
+    }
+}
+impl RedshiftClient {
+    /// Waits until `describe_clusters` reports the `ClusterAvailable` state, polling every 60 seconds up to 30 times.
+    pub async fn wait_until_cluster_available(
+        &self,
+        input: DescribeClustersMessage,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeClustersError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(60), 30);
+        self.wait_until_cluster_available_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_cluster_available`, polling as often and as many times as `config` says.
+    pub async fn wait_until_cluster_available_with_config(
+        &self,
+        input: DescribeClustersMessage,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeClustersError>> {
+        rusoto_core::waiter::wait(
+            "ClusterAvailable",
+            &config,
+            || self.describe_clusters(input.clone()),
+            |result: &Result<ClustersMessage, RusotoError<DescribeClustersError>>| match result {
+                Ok(output)
+                    if rusoto_core::waiter::all_match(
+                        output
+                            .clusters
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.cluster_status.iter()),
+                        |v| v == "available",
+                    ) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if output
+                        .clusters
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.cluster_status.iter())
+                        .any(|v| v == "deleting") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Err(RusotoError::Service(DescribeClustersError::ClusterNotFoundFault(_))) => {
+                    Some(rusoto_core::waiter::WaiterState::Retry)
+                }
+                Err(RusotoError::Unknown(response))
+                    if rusoto_core::retry::error_code(response).as_deref()
+                        == Some("ClusterNotFound") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Retry)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+
+    /// Waits until `describe_clusters` reports the `ClusterDeleted` state, polling every 60 seconds up to 30 times.
+    pub async fn wait_until_cluster_deleted(
+        &self,
+        input: DescribeClustersMessage,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeClustersError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(60), 30);
+        self.wait_until_cluster_deleted_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_cluster_deleted`, polling as often and as many times as `config` says.
+    pub async fn wait_until_cluster_deleted_with_config(
+        &self,
+        input: DescribeClustersMessage,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeClustersError>> {
+        rusoto_core::waiter::wait(
+            "ClusterDeleted",
+            &config,
+            || self.describe_clusters(input.clone()),
+            |result: &Result<ClustersMessage, RusotoError<DescribeClustersError>>| match result {
+                Err(RusotoError::Service(DescribeClustersError::ClusterNotFoundFault(_))) => {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Err(RusotoError::Unknown(response))
+                    if rusoto_core::retry::error_code(response).as_deref()
+                        == Some("ClusterNotFound") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if output
+                        .clusters
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.cluster_status.iter())
+                        .any(|v| v == "creating") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .clusters
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.cluster_status.iter())
+                        .any(|v| v == "modifying") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+
+    /// Waits until `describe_clusters` reports the `ClusterRestored` state, polling every 60 seconds up to 30 times.
+    pub async fn wait_until_cluster_restored(
+        &self,
+        input: DescribeClustersMessage,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeClustersError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(60), 30);
+        self.wait_until_cluster_restored_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_cluster_restored`, polling as often and as many times as `config` says.
+    pub async fn wait_until_cluster_restored_with_config(
+        &self,
+        input: DescribeClustersMessage,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeClustersError>> {
+        rusoto_core::waiter::wait(
+            "ClusterRestored",
+            &config,
+            || self.describe_clusters(input.clone()),
+            |result: &Result<ClustersMessage, RusotoError<DescribeClustersError>>| match result {
+                Ok(output)
+                    if rusoto_core::waiter::all_match(
+                        output
+                            .clusters
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.restore_status.iter())
+                            .flat_map(|v| v.status.iter()),
+                        |v| v == "completed",
+                    ) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if output
+                        .clusters
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.cluster_status.iter())
+                        .any(|v| v == "deleting") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+
+    /// Waits until `describe_cluster_snapshots` reports the `SnapshotAvailable` state, polling every 15 seconds up to 20 times.
+    pub async fn wait_until_snapshot_available(
+        &self,
+        input: DescribeClusterSnapshotsMessage,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeClusterSnapshotsError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(15), 20);
+        self.wait_until_snapshot_available_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_snapshot_available`, polling as often and as many times as `config` says.
+    pub async fn wait_until_snapshot_available_with_config(
+        &self,
+        input: DescribeClusterSnapshotsMessage,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeClusterSnapshotsError>> {
+        rusoto_core::waiter::wait(
+            "SnapshotAvailable",
+            &config,
+            || self.describe_cluster_snapshots(input.clone()),
+            |result: &Result<SnapshotMessage, RusotoError<DescribeClusterSnapshotsError>>| {
+                match result {
+                    Ok(output)
+                        if rusoto_core::waiter::all_match(
+                            output
+                                .snapshots
+                                .iter()
+                                .flat_map(|v| v.iter())
+                                .flat_map(|v| v.status.iter()),
+                            |v| v == "available",
+                        ) =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Success)
+                    }
+                    Ok(output)
+                        if output
+                            .snapshots
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.status.iter())
+                            .any(|v| v == "failed") =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    Ok(output)
+                        if output
+                            .snapshots
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.status.iter())
+                            .any(|v| v == "deleted") =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    _ => None,
+                }
+            },
+        )
+        .await
+    }
+}
 
 #[cfg(test)]
 mod protocol_tests {
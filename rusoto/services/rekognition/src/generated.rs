@@ -46,6 +46,159 @@ pub struct Asset {
     pub ground_truth_manifest: Option<GroundTruthManifest>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Attribute {
+    /// `DEFAULT`
+    Default,
+    /// `ALL`
+    All,
+    /// `AGE_RANGE`
+    AgeRange,
+    /// `BEARD`
+    Beard,
+    /// `EMOTIONS`
+    Emotions,
+    /// `EYE_DIRECTION`
+    EyeDirection,
+    /// `EYEGLASSES`
+    Eyeglasses,
+    /// `EYES_OPEN`
+    EyesOpen,
+    /// `GENDER`
+    Gender,
+    /// `MOUTH_OPEN`
+    MouthOpen,
+    /// `MUSTACHE`
+    Mustache,
+    /// `FACE_OCCLUDED`
+    FaceOccluded,
+    /// `SMILE`
+    Smile,
+    /// `SUNGLASSES`
+    Sunglasses,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Attribute {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Attribute::Default => "DEFAULT",
+            Attribute::All => "ALL",
+            Attribute::AgeRange => "AGE_RANGE",
+            Attribute::Beard => "BEARD",
+            Attribute::Emotions => "EMOTIONS",
+            Attribute::EyeDirection => "EYE_DIRECTION",
+            Attribute::Eyeglasses => "EYEGLASSES",
+            Attribute::EyesOpen => "EYES_OPEN",
+            Attribute::Gender => "GENDER",
+            Attribute::MouthOpen => "MOUTH_OPEN",
+            Attribute::Mustache => "MUSTACHE",
+            Attribute::FaceOccluded => "FACE_OCCLUDED",
+            Attribute::Smile => "SMILE",
+            Attribute::Sunglasses => "SUNGLASSES",
+            Attribute::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Attribute {
+    fn default() -> Attribute {
+        Attribute::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Attribute {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Attribute {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Attribute {
+    fn from(value: &'a str) -> Attribute {
+        match value {
+            "DEFAULT" => Attribute::Default,
+            "ALL" => Attribute::All,
+            "AGE_RANGE" => Attribute::AgeRange,
+            "BEARD" => Attribute::Beard,
+            "EMOTIONS" => Attribute::Emotions,
+            "EYE_DIRECTION" => Attribute::EyeDirection,
+            "EYEGLASSES" => Attribute::Eyeglasses,
+            "EYES_OPEN" => Attribute::EyesOpen,
+            "GENDER" => Attribute::Gender,
+            "MOUTH_OPEN" => Attribute::MouthOpen,
+            "MUSTACHE" => Attribute::Mustache,
+            "FACE_OCCLUDED" => Attribute::FaceOccluded,
+            "SMILE" => Attribute::Smile,
+            "SUNGLASSES" => Attribute::Sunglasses,
+            _ => Attribute::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Attribute {
+    fn from(value: String) -> Attribute {
+        match value.as_str() {
+            "DEFAULT" => Attribute::Default,
+            "ALL" => Attribute::All,
+            "AGE_RANGE" => Attribute::AgeRange,
+            "BEARD" => Attribute::Beard,
+            "EMOTIONS" => Attribute::Emotions,
+            "EYE_DIRECTION" => Attribute::EyeDirection,
+            "EYEGLASSES" => Attribute::Eyeglasses,
+            "EYES_OPEN" => Attribute::EyesOpen,
+            "GENDER" => Attribute::Gender,
+            "MOUTH_OPEN" => Attribute::MouthOpen,
+            "MUSTACHE" => Attribute::Mustache,
+            "FACE_OCCLUDED" => Attribute::FaceOccluded,
+            "SMILE" => Attribute::Smile,
+            "SUNGLASSES" => Attribute::Sunglasses,
+            _ => Attribute::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Attribute {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Attribute, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Attribute {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Attribute {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Attribute {
+    fn deserialize<D>(deserializer: D) -> Result<Attribute, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Indicates whether or not the face has a beard, and the confidence level in the determination.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -152,6 +305,99 @@ pub struct CelebrityRecognition {
     pub timestamp: Option<i64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CelebrityRecognitionSortBy {
+    /// `ID`
+    Id,
+    /// `TIMESTAMP`
+    Timestamp,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CelebrityRecognitionSortBy {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CelebrityRecognitionSortBy::Id => "ID",
+            CelebrityRecognitionSortBy::Timestamp => "TIMESTAMP",
+            CelebrityRecognitionSortBy::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CelebrityRecognitionSortBy {
+    fn default() -> CelebrityRecognitionSortBy {
+        CelebrityRecognitionSortBy::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CelebrityRecognitionSortBy {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CelebrityRecognitionSortBy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CelebrityRecognitionSortBy {
+    fn from(value: &'a str) -> CelebrityRecognitionSortBy {
+        match value {
+            "ID" => CelebrityRecognitionSortBy::Id,
+            "TIMESTAMP" => CelebrityRecognitionSortBy::Timestamp,
+            _ => CelebrityRecognitionSortBy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CelebrityRecognitionSortBy {
+    fn from(value: String) -> CelebrityRecognitionSortBy {
+        match value.as_str() {
+            "ID" => CelebrityRecognitionSortBy::Id,
+            "TIMESTAMP" => CelebrityRecognitionSortBy::Timestamp,
+            _ => CelebrityRecognitionSortBy::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CelebrityRecognitionSortBy {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CelebrityRecognitionSortBy, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CelebrityRecognitionSortBy {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CelebrityRecognitionSortBy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CelebrityRecognitionSortBy {
+    fn deserialize<D>(deserializer: D) -> Result<CelebrityRecognitionSortBy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Provides information about a face in a target image that matches the source image face analyzed by <code>CompareFaces</code>. The <code>Face</code> property contains the bounding box of the face in the target image. The <code>Similarity</code> property is the confidence that the source image face matches the face in the bounding box.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -172,7 +418,7 @@ pub struct CompareFacesRequest {
     /// <p>A filter that specifies a quality bar for how much filtering is done to identify faces. Filtered faces aren't compared. If you specify <code>AUTO</code>, Amazon Rekognition chooses the quality bar. If you specify <code>LOW</code>, <code>MEDIUM</code>, or <code>HIGH</code>, filtering removes all faces that don’t meet the chosen quality bar. The quality bar is based on a variety of common use cases. Low-quality detections can occur for a number of reasons. Some examples are an object that's misidentified as a face, a face that's too blurry, or a face with a pose that's too extreme to use. If you specify <code>NONE</code>, no filtering is performed. The default value is <code>NONE</code>. </p> <p>To use quality filtering, the collection you are using must be associated with version 3 of the face model or higher.</p>
     #[serde(rename = "QualityFilter")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub quality_filter: Option<String>,
+    pub quality_filter: Option<QualityFilter>,
     /// <p>The minimum level of confidence in the face matches that a match must meet to be included in the <code>FaceMatches</code> array.</p>
     #[serde(rename = "SimilarityThreshold")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -199,11 +445,11 @@ pub struct CompareFacesResponse {
     /// <p>The value of <code>SourceImageOrientationCorrection</code> is always null.</p> <p>If the input image is in .jpeg format, it might contain exchangeable image file format (Exif) metadata that includes the image's orientation. Amazon Rekognition uses this orientation information to perform image correction. The bounding box coordinates are translated to represent object locations after the orientation information in the Exif metadata is used to correct the image orientation. Images in .png format don't contain Exif metadata.</p> <p>Amazon Rekognition doesn’t perform image correction for images in .png format and .jpeg images without orientation information in the image Exif metadata. The bounding box coordinates aren't translated and represent the object locations before the image is rotated. </p>
     #[serde(rename = "SourceImageOrientationCorrection")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub source_image_orientation_correction: Option<String>,
+    pub source_image_orientation_correction: Option<OrientationCorrection>,
     /// <p>The value of <code>TargetImageOrientationCorrection</code> is always null.</p> <p>If the input image is in .jpeg format, it might contain exchangeable image file format (Exif) metadata that includes the image's orientation. Amazon Rekognition uses this orientation information to perform image correction. The bounding box coordinates are translated to represent object locations after the orientation information in the Exif metadata is used to correct the image orientation. Images in .png format don't contain Exif metadata.</p> <p>Amazon Rekognition doesn’t perform image correction for images in .png format and .jpeg images without orientation information in the image Exif metadata. The bounding box coordinates aren't translated and represent the object locations before the image is rotated. </p>
     #[serde(rename = "TargetImageOrientationCorrection")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub target_image_orientation_correction: Option<String>,
+    pub target_image_orientation_correction: Option<OrientationCorrection>,
     /// <p>An array of faces in the target image that did not match the source image face.</p>
     #[serde(rename = "UnmatchedFaces")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -250,6 +496,105 @@ pub struct ComparedSourceImageFace {
     pub confidence: Option<f32>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ContentClassifier {
+    /// `FreeOfPersonallyIdentifiableInformation`
+    FreeOfPersonallyIdentifiableInformation,
+    /// `FreeOfAdultContent`
+    FreeOfAdultContent,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ContentClassifier {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ContentClassifier::FreeOfPersonallyIdentifiableInformation => {
+                "FreeOfPersonallyIdentifiableInformation"
+            }
+            ContentClassifier::FreeOfAdultContent => "FreeOfAdultContent",
+            ContentClassifier::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ContentClassifier {
+    fn default() -> ContentClassifier {
+        ContentClassifier::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ContentClassifier {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ContentClassifier {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ContentClassifier {
+    fn from(value: &'a str) -> ContentClassifier {
+        match value {
+            "FreeOfPersonallyIdentifiableInformation" => {
+                ContentClassifier::FreeOfPersonallyIdentifiableInformation
+            }
+            "FreeOfAdultContent" => ContentClassifier::FreeOfAdultContent,
+            _ => ContentClassifier::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ContentClassifier {
+    fn from(value: String) -> ContentClassifier {
+        match value.as_str() {
+            "FreeOfPersonallyIdentifiableInformation" => {
+                ContentClassifier::FreeOfPersonallyIdentifiableInformation
+            }
+            "FreeOfAdultContent" => ContentClassifier::FreeOfAdultContent,
+            _ => ContentClassifier::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ContentClassifier {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ContentClassifier, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ContentClassifier {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ContentClassifier {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ContentClassifier {
+    fn deserialize<D>(deserializer: D) -> Result<ContentClassifier, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about an unsafe content label detection in a stored video.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -264,6 +609,99 @@ pub struct ContentModerationDetection {
     pub timestamp: Option<i64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ContentModerationSortBy {
+    /// `NAME`
+    Name,
+    /// `TIMESTAMP`
+    Timestamp,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ContentModerationSortBy {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ContentModerationSortBy::Name => "NAME",
+            ContentModerationSortBy::Timestamp => "TIMESTAMP",
+            ContentModerationSortBy::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ContentModerationSortBy {
+    fn default() -> ContentModerationSortBy {
+        ContentModerationSortBy::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ContentModerationSortBy {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ContentModerationSortBy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ContentModerationSortBy {
+    fn from(value: &'a str) -> ContentModerationSortBy {
+        match value {
+            "NAME" => ContentModerationSortBy::Name,
+            "TIMESTAMP" => ContentModerationSortBy::Timestamp,
+            _ => ContentModerationSortBy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ContentModerationSortBy {
+    fn from(value: String) -> ContentModerationSortBy {
+        match value.as_str() {
+            "NAME" => ContentModerationSortBy::Name,
+            "TIMESTAMP" => ContentModerationSortBy::Timestamp,
+            _ => ContentModerationSortBy::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ContentModerationSortBy {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ContentModerationSortBy, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ContentModerationSortBy {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ContentModerationSortBy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ContentModerationSortBy {
+    fn deserialize<D>(deserializer: D) -> Result<ContentModerationSortBy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateCollectionRequest {
@@ -448,8 +886,13 @@ pub struct DescribeCollectionResponse {
     pub collection_arn: Option<String>,
     /// <p>The number of milliseconds since the Unix epoch time until the creation of the collection. The Unix epoch time is 00:00:00 Coordinated Universal Time (UTC), Thursday, 1 January 1970.</p>
     #[serde(rename = "CreationTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_timestamp: Option<f64>,
+    pub creation_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The number of faces that are indexed into the collection. To index faces into a collection, use <a>IndexFaces</a>.</p>
     #[serde(rename = "FaceCount")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -532,16 +975,26 @@ pub struct DescribeStreamProcessorRequest {
 pub struct DescribeStreamProcessorResponse {
     /// <p>Date and time the stream processor was created</p>
     #[serde(rename = "CreationTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_timestamp: Option<f64>,
+    pub creation_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Kinesis video stream that provides the source streaming video.</p>
     #[serde(rename = "Input")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub input: Option<StreamProcessorInput>,
     /// <p>The time, in Unix format, the stream processor was last updated. For example, when the stream processor moves from a running state to a failed state, or when the user starts or stops the stream processor.</p>
     #[serde(rename = "LastUpdateTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_update_timestamp: Option<f64>,
+    pub last_update_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Name of the stream processor. </p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -561,7 +1014,7 @@ pub struct DescribeStreamProcessorResponse {
     /// <p>Current status of the stream processor.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<StreamProcessorStatus>,
     /// <p>Detailed status message about the stream processor.</p>
     #[serde(rename = "StatusMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -605,7 +1058,7 @@ pub struct DetectFacesRequest {
     /// <p>An array of facial attributes you want to be returned. This can be the default list of attributes or all attributes. If you don't specify a value for <code>Attributes</code> or if you specify <code>["DEFAULT"]</code>, the API returns the following subset of facial attributes: <code>BoundingBox</code>, <code>Confidence</code>, <code>Pose</code>, <code>Quality</code>, and <code>Landmarks</code>. If you provide <code>["ALL"]</code>, all facial attributes are returned, but the operation takes longer to complete.</p> <p>If you provide both, <code>["ALL", "DEFAULT"]</code>, the service uses a logical AND operator to determine which attributes to return (in this case, all attributes). </p>
     #[serde(rename = "Attributes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub attributes: Option<Vec<String>>,
+    pub attributes: Option<Vec<Attribute>>,
     /// <p>The input image as base64-encoded bytes or an S3 object. If you use the AWS CLI to call Amazon Rekognition operations, passing base64-encoded image bytes is not supported. </p> <p>If you are using an AWS SDK to call Amazon Rekognition, you might not need to base64-encode image bytes passed using the <code>Bytes</code> field. For more information, see Images in the Amazon Rekognition developer guide.</p>
     #[serde(rename = "Image")]
     pub image: Image,
@@ -621,7 +1074,7 @@ pub struct DetectFacesResponse {
     /// <p>The value of <code>OrientationCorrection</code> is always null.</p> <p>If the input image is in .jpeg format, it might contain exchangeable image file format (Exif) metadata that includes the image's orientation. Amazon Rekognition uses this orientation information to perform image correction. The bounding box coordinates are translated to represent object locations after the orientation information in the Exif metadata is used to correct the image orientation. Images in .png format don't contain Exif metadata.</p> <p>Amazon Rekognition doesn’t perform image correction for images in .png format and .jpeg images without orientation information in the image Exif metadata. The bounding box coordinates aren't translated and represent the object locations before the image is rotated. </p>
     #[serde(rename = "OrientationCorrection")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub orientation_correction: Option<String>,
+    pub orientation_correction: Option<OrientationCorrection>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -654,7 +1107,7 @@ pub struct DetectLabelsResponse {
     /// <p>The value of <code>OrientationCorrection</code> is always null.</p> <p>If the input image is in .jpeg format, it might contain exchangeable image file format (Exif) metadata that includes the image's orientation. Amazon Rekognition uses this orientation information to perform image correction. The bounding box coordinates are translated to represent object locations after the orientation information in the Exif metadata is used to correct the image orientation. Images in .png format don't contain Exif metadata.</p> <p>Amazon Rekognition doesn’t perform image correction for images in .png format and .jpeg images without orientation information in the image Exif metadata. The bounding box coordinates aren't translated and represent the object locations before the image is rotated. </p>
     #[serde(rename = "OrientationCorrection")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub orientation_correction: Option<String>,
+    pub orientation_correction: Option<OrientationCorrection>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -718,9 +1171,137 @@ pub struct Emotion {
     /// <p>Type of emotion detected.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<EmotionName>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EmotionName {
+    /// `HAPPY`
+    Happy,
+    /// `SAD`
+    Sad,
+    /// `ANGRY`
+    Angry,
+    /// `CONFUSED`
+    Confused,
+    /// `DISGUSTED`
+    Disgusted,
+    /// `SURPRISED`
+    Surprised,
+    /// `CALM`
+    Calm,
+    /// `UNKNOWN`
+    UnknownValue,
+    /// `FEAR`
+    Fear,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EmotionName {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EmotionName::Happy => "HAPPY",
+            EmotionName::Sad => "SAD",
+            EmotionName::Angry => "ANGRY",
+            EmotionName::Confused => "CONFUSED",
+            EmotionName::Disgusted => "DISGUSTED",
+            EmotionName::Surprised => "SURPRISED",
+            EmotionName::Calm => "CALM",
+            EmotionName::UnknownValue => "UNKNOWN",
+            EmotionName::Fear => "FEAR",
+            EmotionName::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EmotionName {
+    fn default() -> EmotionName {
+        EmotionName::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EmotionName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EmotionName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EmotionName {
+    fn from(value: &'a str) -> EmotionName {
+        match value {
+            "HAPPY" => EmotionName::Happy,
+            "SAD" => EmotionName::Sad,
+            "ANGRY" => EmotionName::Angry,
+            "CONFUSED" => EmotionName::Confused,
+            "DISGUSTED" => EmotionName::Disgusted,
+            "SURPRISED" => EmotionName::Surprised,
+            "CALM" => EmotionName::Calm,
+            "UNKNOWN" => EmotionName::UnknownValue,
+            "FEAR" => EmotionName::Fear,
+            _ => EmotionName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EmotionName {
+    fn from(value: String) -> EmotionName {
+        match value.as_str() {
+            "HAPPY" => EmotionName::Happy,
+            "SAD" => EmotionName::Sad,
+            "ANGRY" => EmotionName::Angry,
+            "CONFUSED" => EmotionName::Confused,
+            "DISGUSTED" => EmotionName::Disgusted,
+            "SURPRISED" => EmotionName::Surprised,
+            "CALM" => EmotionName::Calm,
+            "UNKNOWN" => EmotionName::UnknownValue,
+            "FEAR" => EmotionName::Fear,
+            _ => EmotionName::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EmotionName {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EmotionName, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EmotionName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EmotionName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for EmotionName {
+    fn deserialize<D>(deserializer: D) -> Result<EmotionName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The evaluation results for the training of a model.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -789,6 +1370,99 @@ pub struct Face {
     pub image_id: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FaceAttributes {
+    /// `DEFAULT`
+    Default,
+    /// `ALL`
+    All,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl FaceAttributes {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FaceAttributes::Default => "DEFAULT",
+            FaceAttributes::All => "ALL",
+            FaceAttributes::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for FaceAttributes {
+    fn default() -> FaceAttributes {
+        FaceAttributes::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for FaceAttributes {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FaceAttributes {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FaceAttributes {
+    fn from(value: &'a str) -> FaceAttributes {
+        match value {
+            "DEFAULT" => FaceAttributes::Default,
+            "ALL" => FaceAttributes::All,
+            _ => FaceAttributes::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FaceAttributes {
+    fn from(value: String) -> FaceAttributes {
+        match value.as_str() {
+            "DEFAULT" => FaceAttributes::Default,
+            "ALL" => FaceAttributes::All,
+            _ => FaceAttributes::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for FaceAttributes {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<FaceAttributes, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FaceAttributes {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FaceAttributes {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FaceAttributes {
+    fn deserialize<D>(deserializer: D) -> Result<FaceAttributes, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Structure containing attributes of the face that the algorithm detected.</p> <p>A <code>FaceDetail</code> object contains either the default facial attributes or all facial attributes. The default attributes are <code>BoundingBox</code>, <code>Confidence</code>, <code>Landmarks</code>, <code>Pose</code>, and <code>Quality</code>.</p> <p> <a>GetFaceDetection</a> is the only Amazon Rekognition Video stored video operation that can return a <code>FaceDetail</code> object with all attributes. To specify which attributes to return, use the <code>FaceAttributes</code> input parameter for <a>StartFaceDetection</a>. The following Amazon Rekognition Video operations return only the default attributes. The corresponding Start operations don't have a <code>FaceAttributes</code> input parameter.</p> <ul> <li> <p>GetCelebrityRecognition</p> </li> <li> <p>GetPersonTracking</p> </li> <li> <p>GetFaceSearch</p> </li> </ul> <p>The Amazon Rekognition Image <a>DetectFaces</a> and <a>IndexFaces</a> operations can return all facial attributes. To specify which attributes to return, use the <code>Attributes</code> input parameter for <code>DetectFaces</code>. For <code>IndexFaces</code>, use the <code>DetectAttributes</code> input parameter.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -910,10 +1584,103 @@ pub struct FaceSearchSettings {
     pub face_match_threshold: Option<f32>,
 }
 
-/// <p>The predicted gender of a detected face. </p> <p>Amazon Rekognition makes gender binary (male/female) predictions based on the physical appearance of a face in a particular image. This kind of prediction is not designed to categorize a person’s gender identity, and you shouldn't use Amazon Rekognition to make such a determination. For example, a male actor wearing a long-haired wig and earrings for a role might be predicted as female.</p> <p>Using Amazon Rekognition to make gender binary predictions is best suited for use cases where aggregate gender distribution statistics need to be analyzed without identifying specific users. For example, the percentage of female users compared to male users on a social media platform. </p> <p>We don't recommend using gender binary predictions to make decisions that impact&#x2028; an individual's rights, privacy, or access to services.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct Gender {
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FaceSearchSortBy {
+    /// `INDEX`
+    Index,
+    /// `TIMESTAMP`
+    Timestamp,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl FaceSearchSortBy {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FaceSearchSortBy::Index => "INDEX",
+            FaceSearchSortBy::Timestamp => "TIMESTAMP",
+            FaceSearchSortBy::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for FaceSearchSortBy {
+    fn default() -> FaceSearchSortBy {
+        FaceSearchSortBy::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for FaceSearchSortBy {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FaceSearchSortBy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FaceSearchSortBy {
+    fn from(value: &'a str) -> FaceSearchSortBy {
+        match value {
+            "INDEX" => FaceSearchSortBy::Index,
+            "TIMESTAMP" => FaceSearchSortBy::Timestamp,
+            _ => FaceSearchSortBy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FaceSearchSortBy {
+    fn from(value: String) -> FaceSearchSortBy {
+        match value.as_str() {
+            "INDEX" => FaceSearchSortBy::Index,
+            "TIMESTAMP" => FaceSearchSortBy::Timestamp,
+            _ => FaceSearchSortBy::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for FaceSearchSortBy {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<FaceSearchSortBy, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FaceSearchSortBy {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FaceSearchSortBy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FaceSearchSortBy {
+    fn deserialize<D>(deserializer: D) -> Result<FaceSearchSortBy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>The predicted gender of a detected face. </p> <p>Amazon Rekognition makes gender binary (male/female) predictions based on the physical appearance of a face in a particular image. This kind of prediction is not designed to categorize a person’s gender identity, and you shouldn't use Amazon Rekognition to make such a determination. For example, a male actor wearing a long-haired wig and earrings for a role might be predicted as female.</p> <p>Using Amazon Rekognition to make gender binary predictions is best suited for use cases where aggregate gender distribution statistics need to be analyzed without identifying specific users. For example, the percentage of female users compared to male users on a social media platform. </p> <p>We don't recommend using gender binary predictions to make decisions that impact&#x2028; an individual's rights, privacy, or access to services.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct Gender {
     /// <p>Level of confidence in the prediction.</p>
     #[serde(rename = "Confidence")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -921,9 +1688,102 @@ pub struct Gender {
     /// <p>The predicted gender of the face.</p>
     #[serde(rename = "Value")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub value: Option<String>,
+    pub value: Option<GenderType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum GenderType {
+    /// `Male`
+    Male,
+    /// `Female`
+    Female,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl GenderType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            GenderType::Male => "Male",
+            GenderType::Female => "Female",
+            GenderType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for GenderType {
+    fn default() -> GenderType {
+        GenderType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for GenderType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for GenderType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
+impl<'a> From<&'a str> for GenderType {
+    fn from(value: &'a str) -> GenderType {
+        match value {
+            "Male" => GenderType::Male,
+            "Female" => GenderType::Female,
+            _ => GenderType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for GenderType {
+    fn from(value: String) -> GenderType {
+        match value.as_str() {
+            "Male" => GenderType::Male,
+            "Female" => GenderType::Female,
+            _ => GenderType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for GenderType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<GenderType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for GenderType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for GenderType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for GenderType {
+    fn deserialize<D>(deserializer: D) -> Result<GenderType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about where an object (<a>DetectCustomLabels</a>) or text (<a>DetectText</a>) is located on an image.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -976,7 +1836,7 @@ pub struct GetCelebrityRecognitionRequest {
     /// <p>Sort to use for celebrities returned in <code>Celebrities</code> field. Specify <code>ID</code> to sort by the celebrity identifier, specify <code>TIMESTAMP</code> to sort by the time the celebrity was recognized.</p>
     #[serde(rename = "SortBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort_by: Option<String>,
+    pub sort_by: Option<CelebrityRecognitionSortBy>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -989,7 +1849,7 @@ pub struct GetCelebrityRecognitionResponse {
     /// <p>The current status of the celebrity recognition job.</p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<VideoJobStatus>,
     /// <p>If the response is truncated, Amazon Rekognition Video returns this token that you can use in the subsequent request to retrieve the next set of celebrities.</p>
     #[serde(rename = "NextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1021,7 +1881,7 @@ pub struct GetContentModerationRequest {
     /// <p>Sort to use for elements in the <code>ModerationLabelDetections</code> array. Use <code>TIMESTAMP</code> to sort array elements by the time labels are detected. Use <code>NAME</code> to alphabetically group elements for a label together. Within each label group, the array element are sorted by detection confidence. The default sort is by <code>TIMESTAMP</code>.</p>
     #[serde(rename = "SortBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort_by: Option<String>,
+    pub sort_by: Option<ContentModerationSortBy>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1030,7 +1890,7 @@ pub struct GetContentModerationResponse {
     /// <p>The current status of the unsafe content analysis job.</p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<VideoJobStatus>,
     /// <p>The detected unsafe content labels and the time(s) they were detected.</p>
     #[serde(rename = "ModerationLabels")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1079,7 +1939,7 @@ pub struct GetFaceDetectionResponse {
     /// <p>The current status of the face detection job.</p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<VideoJobStatus>,
     /// <p>If the response is truncated, Amazon Rekognition returns this token that you can use in the subsequent request to retrieve the next set of faces. </p>
     #[serde(rename = "NextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1111,7 +1971,7 @@ pub struct GetFaceSearchRequest {
     /// <p>Sort to use for grouping faces in the response. Use <code>TIMESTAMP</code> to group faces by the time that they are recognized. Use <code>INDEX</code> to sort by recognized faces. </p>
     #[serde(rename = "SortBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort_by: Option<String>,
+    pub sort_by: Option<FaceSearchSortBy>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1120,7 +1980,7 @@ pub struct GetFaceSearchResponse {
     /// <p>The current status of the face search job.</p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<VideoJobStatus>,
     /// <p>If the response is truncated, Amazon Rekognition Video returns this token that you can use in the subsequent request to retrieve the next set of search results. </p>
     #[serde(rename = "NextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1156,7 +2016,7 @@ pub struct GetLabelDetectionRequest {
     /// <p>Sort to use for elements in the <code>Labels</code> array. Use <code>TIMESTAMP</code> to sort array elements by the time labels are detected. Use <code>NAME</code> to alphabetically group elements for a label together. Within each label group, the array element are sorted by detection confidence. The default sort is by <code>TIMESTAMP</code>.</p>
     #[serde(rename = "SortBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort_by: Option<String>,
+    pub sort_by: Option<LabelDetectionSortBy>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1165,7 +2025,7 @@ pub struct GetLabelDetectionResponse {
     /// <p>The current status of the label detection job.</p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<VideoJobStatus>,
     /// <p>Version number of the label detection model that was used to detect labels.</p>
     #[serde(rename = "LabelModelVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1205,7 +2065,7 @@ pub struct GetPersonTrackingRequest {
     /// <p>Sort to use for elements in the <code>Persons</code> array. Use <code>TIMESTAMP</code> to sort array elements by the time persons are detected. Use <code>INDEX</code> to sort by the tracked persons. If you sort by <code>INDEX</code>, the array elements for each person are sorted by detection confidence. The default sort is by <code>TIMESTAMP</code>.</p>
     #[serde(rename = "SortBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort_by: Option<String>,
+    pub sort_by: Option<PersonTrackingSortBy>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1214,7 +2074,7 @@ pub struct GetPersonTrackingResponse {
     /// <p>The current status of the person tracking job.</p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<VideoJobStatus>,
     /// <p>If the response is truncated, Amazon Rekognition Video returns this token that you can use in the subsequent request to retrieve the next set of persons. </p>
     #[serde(rename = "NextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1282,7 +2142,7 @@ pub struct HumanLoopDataAttributes {
     /// <p>Sets whether the input image is free of personally identifiable information.</p>
     #[serde(rename = "ContentClassifiers")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub content_classifiers: Option<Vec<String>>,
+    pub content_classifiers: Option<Vec<ContentClassifier>>,
 }
 
 /// <p>Provides the input image either as bytes or an S3 object.</p> <p>You pass image bytes to an Amazon Rekognition API operation by using the <code>Bytes</code> property. For example, you would use the <code>Bytes</code> property to pass an image loaded from a local file system. Image bytes passed by using the <code>Bytes</code> property must be base64-encoded. Your code may not need to encode image bytes if you are using an AWS SDK to call Amazon Rekognition API operations. </p> <p>For more information, see Analyzing an Image Loaded from a Local File System in the Amazon Rekognition Developer Guide.</p> <p> You pass images stored in an S3 bucket to an Amazon Rekognition API operation by using the <code>S3Object</code> property. Images stored in an S3 bucket do not need to be base64-encoded.</p> <p>The region for the S3 bucket containing the S3 object must match the region you use for Amazon Rekognition operations.</p> <p>If you use the AWS CLI to call Amazon Rekognition operations, passing image bytes using the Bytes property is not supported. You must first upload the image to an Amazon S3 bucket and then call the operation using the S3Object property.</p> <p>For Amazon Rekognition to process an S3 object, the user must have permission to access the S3 object. For more information, see Resource Based Policies in the Amazon Rekognition Developer Guide. </p>
@@ -1327,7 +2187,7 @@ pub struct IndexFacesRequest {
     /// <p>An array of facial attributes that you want to be returned. This can be the default list of attributes or all attributes. If you don't specify a value for <code>Attributes</code> or if you specify <code>["DEFAULT"]</code>, the API returns the following subset of facial attributes: <code>BoundingBox</code>, <code>Confidence</code>, <code>Pose</code>, <code>Quality</code>, and <code>Landmarks</code>. If you provide <code>["ALL"]</code>, all facial attributes are returned, but the operation takes longer to complete.</p> <p>If you provide both, <code>["ALL", "DEFAULT"]</code>, the service uses a logical AND operator to determine which attributes to return (in this case, all attributes). </p>
     #[serde(rename = "DetectionAttributes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub detection_attributes: Option<Vec<String>>,
+    pub detection_attributes: Option<Vec<Attribute>>,
     /// <p>The ID you want to assign to all the faces detected in the image.</p>
     #[serde(rename = "ExternalImageId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1342,7 +2202,7 @@ pub struct IndexFacesRequest {
     /// <p>A filter that specifies a quality bar for how much filtering is done to identify faces. Filtered faces aren't indexed. If you specify <code>AUTO</code>, Amazon Rekognition chooses the quality bar. If you specify <code>LOW</code>, <code>MEDIUM</code>, or <code>HIGH</code>, filtering removes all faces that don’t meet the chosen quality bar. The default value is <code>AUTO</code>. The quality bar is based on a variety of common use cases. Low-quality detections can occur for a number of reasons. Some examples are an object that's misidentified as a face, a face that's too blurry, or a face with a pose that's too extreme to use. If you specify <code>NONE</code>, no filtering is performed. </p> <p>To use quality filtering, the collection you are using must be associated with version 3 of the face model or higher.</p>
     #[serde(rename = "QualityFilter")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub quality_filter: Option<String>,
+    pub quality_filter: Option<QualityFilter>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1359,7 +2219,7 @@ pub struct IndexFacesResponse {
     /// <p>If your collection is associated with a face detection model that's later than version 3.0, the value of <code>OrientationCorrection</code> is always null and no orientation information is returned.</p> <p>If your collection is associated with a face detection model that's version 3.0 or earlier, the following applies:</p> <ul> <li> <p>If the input image is in .jpeg format, it might contain exchangeable image file format (Exif) metadata that includes the image's orientation. Amazon Rekognition uses this orientation information to perform image correction - the bounding box coordinates are translated to represent object locations after the orientation information in the Exif metadata is used to correct the image orientation. Images in .png format don't contain Exif metadata. The value of <code>OrientationCorrection</code> is null.</p> </li> <li> <p>If the image doesn't contain orientation information in its Exif metadata, Amazon Rekognition returns an estimated orientation (ROTATE_0, ROTATE_90, ROTATE_180, ROTATE_270). Amazon Rekognition doesn’t perform image correction for images. The bounding box coordinates aren't translated and represent the object locations before the image is rotated.</p> </li> </ul> <p>Bounding box information is returned in the <code>FaceRecords</code> array. You can get the version of the face detection model by calling <a>DescribeCollection</a>. </p>
     #[serde(rename = "OrientationCorrection")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub orientation_correction: Option<String>,
+    pub orientation_correction: Option<OrientationCorrection>,
     /// <p>An array of faces that were detected in the image but weren't indexed. They weren't indexed because the quality filter identified them as low quality, or the <code>MaxFaces</code> request parameter filtered them out. To use the quality filter, you specify the <code>QualityFilter</code> request parameter.</p>
     #[serde(rename = "UnindexedFaces")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1434,6 +2294,99 @@ pub struct LabelDetection {
     pub timestamp: Option<i64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LabelDetectionSortBy {
+    /// `NAME`
+    Name,
+    /// `TIMESTAMP`
+    Timestamp,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LabelDetectionSortBy {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LabelDetectionSortBy::Name => "NAME",
+            LabelDetectionSortBy::Timestamp => "TIMESTAMP",
+            LabelDetectionSortBy::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LabelDetectionSortBy {
+    fn default() -> LabelDetectionSortBy {
+        LabelDetectionSortBy::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LabelDetectionSortBy {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LabelDetectionSortBy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LabelDetectionSortBy {
+    fn from(value: &'a str) -> LabelDetectionSortBy {
+        match value {
+            "NAME" => LabelDetectionSortBy::Name,
+            "TIMESTAMP" => LabelDetectionSortBy::Timestamp,
+            _ => LabelDetectionSortBy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LabelDetectionSortBy {
+    fn from(value: String) -> LabelDetectionSortBy {
+        match value.as_str() {
+            "NAME" => LabelDetectionSortBy::Name,
+            "TIMESTAMP" => LabelDetectionSortBy::Timestamp,
+            _ => LabelDetectionSortBy::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LabelDetectionSortBy {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LabelDetectionSortBy, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LabelDetectionSortBy {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LabelDetectionSortBy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LabelDetectionSortBy {
+    fn deserialize<D>(deserializer: D) -> Result<LabelDetectionSortBy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Indicates the location of the landmark on the face.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1441,7 +2394,7 @@ pub struct Landmark {
     /// <p>Type of landmark.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<LandmarkType>,
     /// <p>The x-coordinate from the top left of the landmark expressed as the ratio of the width of the image. For example, if the image is 700 x 200 and the x-coordinate of the landmark is at 350 pixels, this value is 0.5. </p>
     #[serde(rename = "X")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1452,6 +2405,239 @@ pub struct Landmark {
     pub y: Option<f32>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LandmarkType {
+    /// `eyeLeft`
+    EyeLeft,
+    /// `eyeRight`
+    EyeRight,
+    /// `nose`
+    Nose,
+    /// `mouthLeft`
+    MouthLeft,
+    /// `mouthRight`
+    MouthRight,
+    /// `leftEyeBrowLeft`
+    LeftEyeBrowLeft,
+    /// `leftEyeBrowRight`
+    LeftEyeBrowRight,
+    /// `leftEyeBrowUp`
+    LeftEyeBrowUp,
+    /// `rightEyeBrowLeft`
+    RightEyeBrowLeft,
+    /// `rightEyeBrowRight`
+    RightEyeBrowRight,
+    /// `rightEyeBrowUp`
+    RightEyeBrowUp,
+    /// `leftEyeLeft`
+    LeftEyeLeft,
+    /// `leftEyeRight`
+    LeftEyeRight,
+    /// `leftEyeUp`
+    LeftEyeUp,
+    /// `leftEyeDown`
+    LeftEyeDown,
+    /// `rightEyeLeft`
+    RightEyeLeft,
+    /// `rightEyeRight`
+    RightEyeRight,
+    /// `rightEyeUp`
+    RightEyeUp,
+    /// `rightEyeDown`
+    RightEyeDown,
+    /// `noseLeft`
+    NoseLeft,
+    /// `noseRight`
+    NoseRight,
+    /// `mouthUp`
+    MouthUp,
+    /// `mouthDown`
+    MouthDown,
+    /// `leftPupil`
+    LeftPupil,
+    /// `rightPupil`
+    RightPupil,
+    /// `upperJawlineLeft`
+    UpperJawlineLeft,
+    /// `midJawlineLeft`
+    MidJawlineLeft,
+    /// `chinBottom`
+    ChinBottom,
+    /// `midJawlineRight`
+    MidJawlineRight,
+    /// `upperJawlineRight`
+    UpperJawlineRight,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LandmarkType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LandmarkType::EyeLeft => "eyeLeft",
+            LandmarkType::EyeRight => "eyeRight",
+            LandmarkType::Nose => "nose",
+            LandmarkType::MouthLeft => "mouthLeft",
+            LandmarkType::MouthRight => "mouthRight",
+            LandmarkType::LeftEyeBrowLeft => "leftEyeBrowLeft",
+            LandmarkType::LeftEyeBrowRight => "leftEyeBrowRight",
+            LandmarkType::LeftEyeBrowUp => "leftEyeBrowUp",
+            LandmarkType::RightEyeBrowLeft => "rightEyeBrowLeft",
+            LandmarkType::RightEyeBrowRight => "rightEyeBrowRight",
+            LandmarkType::RightEyeBrowUp => "rightEyeBrowUp",
+            LandmarkType::LeftEyeLeft => "leftEyeLeft",
+            LandmarkType::LeftEyeRight => "leftEyeRight",
+            LandmarkType::LeftEyeUp => "leftEyeUp",
+            LandmarkType::LeftEyeDown => "leftEyeDown",
+            LandmarkType::RightEyeLeft => "rightEyeLeft",
+            LandmarkType::RightEyeRight => "rightEyeRight",
+            LandmarkType::RightEyeUp => "rightEyeUp",
+            LandmarkType::RightEyeDown => "rightEyeDown",
+            LandmarkType::NoseLeft => "noseLeft",
+            LandmarkType::NoseRight => "noseRight",
+            LandmarkType::MouthUp => "mouthUp",
+            LandmarkType::MouthDown => "mouthDown",
+            LandmarkType::LeftPupil => "leftPupil",
+            LandmarkType::RightPupil => "rightPupil",
+            LandmarkType::UpperJawlineLeft => "upperJawlineLeft",
+            LandmarkType::MidJawlineLeft => "midJawlineLeft",
+            LandmarkType::ChinBottom => "chinBottom",
+            LandmarkType::MidJawlineRight => "midJawlineRight",
+            LandmarkType::UpperJawlineRight => "upperJawlineRight",
+            LandmarkType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LandmarkType {
+    fn default() -> LandmarkType {
+        LandmarkType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LandmarkType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LandmarkType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LandmarkType {
+    fn from(value: &'a str) -> LandmarkType {
+        match value {
+            "eyeLeft" => LandmarkType::EyeLeft,
+            "eyeRight" => LandmarkType::EyeRight,
+            "nose" => LandmarkType::Nose,
+            "mouthLeft" => LandmarkType::MouthLeft,
+            "mouthRight" => LandmarkType::MouthRight,
+            "leftEyeBrowLeft" => LandmarkType::LeftEyeBrowLeft,
+            "leftEyeBrowRight" => LandmarkType::LeftEyeBrowRight,
+            "leftEyeBrowUp" => LandmarkType::LeftEyeBrowUp,
+            "rightEyeBrowLeft" => LandmarkType::RightEyeBrowLeft,
+            "rightEyeBrowRight" => LandmarkType::RightEyeBrowRight,
+            "rightEyeBrowUp" => LandmarkType::RightEyeBrowUp,
+            "leftEyeLeft" => LandmarkType::LeftEyeLeft,
+            "leftEyeRight" => LandmarkType::LeftEyeRight,
+            "leftEyeUp" => LandmarkType::LeftEyeUp,
+            "leftEyeDown" => LandmarkType::LeftEyeDown,
+            "rightEyeLeft" => LandmarkType::RightEyeLeft,
+            "rightEyeRight" => LandmarkType::RightEyeRight,
+            "rightEyeUp" => LandmarkType::RightEyeUp,
+            "rightEyeDown" => LandmarkType::RightEyeDown,
+            "noseLeft" => LandmarkType::NoseLeft,
+            "noseRight" => LandmarkType::NoseRight,
+            "mouthUp" => LandmarkType::MouthUp,
+            "mouthDown" => LandmarkType::MouthDown,
+            "leftPupil" => LandmarkType::LeftPupil,
+            "rightPupil" => LandmarkType::RightPupil,
+            "upperJawlineLeft" => LandmarkType::UpperJawlineLeft,
+            "midJawlineLeft" => LandmarkType::MidJawlineLeft,
+            "chinBottom" => LandmarkType::ChinBottom,
+            "midJawlineRight" => LandmarkType::MidJawlineRight,
+            "upperJawlineRight" => LandmarkType::UpperJawlineRight,
+            _ => LandmarkType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LandmarkType {
+    fn from(value: String) -> LandmarkType {
+        match value.as_str() {
+            "eyeLeft" => LandmarkType::EyeLeft,
+            "eyeRight" => LandmarkType::EyeRight,
+            "nose" => LandmarkType::Nose,
+            "mouthLeft" => LandmarkType::MouthLeft,
+            "mouthRight" => LandmarkType::MouthRight,
+            "leftEyeBrowLeft" => LandmarkType::LeftEyeBrowLeft,
+            "leftEyeBrowRight" => LandmarkType::LeftEyeBrowRight,
+            "leftEyeBrowUp" => LandmarkType::LeftEyeBrowUp,
+            "rightEyeBrowLeft" => LandmarkType::RightEyeBrowLeft,
+            "rightEyeBrowRight" => LandmarkType::RightEyeBrowRight,
+            "rightEyeBrowUp" => LandmarkType::RightEyeBrowUp,
+            "leftEyeLeft" => LandmarkType::LeftEyeLeft,
+            "leftEyeRight" => LandmarkType::LeftEyeRight,
+            "leftEyeUp" => LandmarkType::LeftEyeUp,
+            "leftEyeDown" => LandmarkType::LeftEyeDown,
+            "rightEyeLeft" => LandmarkType::RightEyeLeft,
+            "rightEyeRight" => LandmarkType::RightEyeRight,
+            "rightEyeUp" => LandmarkType::RightEyeUp,
+            "rightEyeDown" => LandmarkType::RightEyeDown,
+            "noseLeft" => LandmarkType::NoseLeft,
+            "noseRight" => LandmarkType::NoseRight,
+            "mouthUp" => LandmarkType::MouthUp,
+            "mouthDown" => LandmarkType::MouthDown,
+            "leftPupil" => LandmarkType::LeftPupil,
+            "rightPupil" => LandmarkType::RightPupil,
+            "upperJawlineLeft" => LandmarkType::UpperJawlineLeft,
+            "midJawlineLeft" => LandmarkType::MidJawlineLeft,
+            "chinBottom" => LandmarkType::ChinBottom,
+            "midJawlineRight" => LandmarkType::MidJawlineRight,
+            "upperJawlineRight" => LandmarkType::UpperJawlineRight,
+            _ => LandmarkType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LandmarkType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LandmarkType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LandmarkType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LandmarkType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LandmarkType {
+    fn deserialize<D>(deserializer: D) -> Result<LandmarkType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ListCollectionsRequest {
@@ -1599,6 +2785,109 @@ pub struct NotificationChannel {
     pub sns_topic_arn: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OrientationCorrection {
+    /// `ROTATE_0`
+    Rotate0,
+    /// `ROTATE_90`
+    Rotate90,
+    /// `ROTATE_180`
+    Rotate180,
+    /// `ROTATE_270`
+    Rotate270,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl OrientationCorrection {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OrientationCorrection::Rotate0 => "ROTATE_0",
+            OrientationCorrection::Rotate90 => "ROTATE_90",
+            OrientationCorrection::Rotate180 => "ROTATE_180",
+            OrientationCorrection::Rotate270 => "ROTATE_270",
+            OrientationCorrection::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for OrientationCorrection {
+    fn default() -> OrientationCorrection {
+        OrientationCorrection::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for OrientationCorrection {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for OrientationCorrection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for OrientationCorrection {
+    fn from(value: &'a str) -> OrientationCorrection {
+        match value {
+            "ROTATE_0" => OrientationCorrection::Rotate0,
+            "ROTATE_90" => OrientationCorrection::Rotate90,
+            "ROTATE_180" => OrientationCorrection::Rotate180,
+            "ROTATE_270" => OrientationCorrection::Rotate270,
+            _ => OrientationCorrection::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OrientationCorrection {
+    fn from(value: String) -> OrientationCorrection {
+        match value.as_str() {
+            "ROTATE_0" => OrientationCorrection::Rotate0,
+            "ROTATE_90" => OrientationCorrection::Rotate90,
+            "ROTATE_180" => OrientationCorrection::Rotate180,
+            "ROTATE_270" => OrientationCorrection::Rotate270,
+            _ => OrientationCorrection::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for OrientationCorrection {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<OrientationCorrection, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OrientationCorrection {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for OrientationCorrection {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OrientationCorrection {
+    fn deserialize<D>(deserializer: D) -> Result<OrientationCorrection, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The S3 bucket and folder location where training output is placed.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct OutputConfig {
@@ -1672,6 +2961,99 @@ pub struct PersonMatch {
     pub timestamp: Option<i64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PersonTrackingSortBy {
+    /// `INDEX`
+    Index,
+    /// `TIMESTAMP`
+    Timestamp,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PersonTrackingSortBy {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PersonTrackingSortBy::Index => "INDEX",
+            PersonTrackingSortBy::Timestamp => "TIMESTAMP",
+            PersonTrackingSortBy::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PersonTrackingSortBy {
+    fn default() -> PersonTrackingSortBy {
+        PersonTrackingSortBy::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PersonTrackingSortBy {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PersonTrackingSortBy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PersonTrackingSortBy {
+    fn from(value: &'a str) -> PersonTrackingSortBy {
+        match value {
+            "INDEX" => PersonTrackingSortBy::Index,
+            "TIMESTAMP" => PersonTrackingSortBy::Timestamp,
+            _ => PersonTrackingSortBy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PersonTrackingSortBy {
+    fn from(value: String) -> PersonTrackingSortBy {
+        match value.as_str() {
+            "INDEX" => PersonTrackingSortBy::Index,
+            "TIMESTAMP" => PersonTrackingSortBy::Timestamp,
+            _ => PersonTrackingSortBy::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PersonTrackingSortBy {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PersonTrackingSortBy, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PersonTrackingSortBy {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PersonTrackingSortBy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PersonTrackingSortBy {
+    fn deserialize<D>(deserializer: D) -> Result<PersonTrackingSortBy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The X and Y coordinates of a point on an image. The X and Y values returned are ratios of the overall image size. For example, if the input image is 700x200 and the operation returns X=0.5 and Y=0.25, then the point is at the (350,50) pixel coordinate on the image.</p> <p>An array of <code>Point</code> objects, <code>Polygon</code>, is returned by <a>DetectText</a> and by <a>DetectCustomLabels</a>. <code>Polygon</code> represents a fine-grained polygon around a detected item. For more information, see Geometry in the Amazon Rekognition Developer Guide. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1704,74 +3086,566 @@ pub struct Pose {
     pub yaw: Option<f32>,
 }
 
-/// <p>A description of a Amazon Rekognition Custom Labels project.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct ProjectDescription {
-    /// <p>The Unix timestamp for the date and time that the project was created.</p>
-    #[serde(rename = "CreationTimestamp")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_timestamp: Option<f64>,
-    /// <p>The Amazon Resource Name (ARN) of the project.</p>
-    #[serde(rename = "ProjectArn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub project_arn: Option<String>,
-    /// <p>The current status of the project.</p>
-    #[serde(rename = "Status")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+/// <p>A description of a Amazon Rekognition Custom Labels project.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct ProjectDescription {
+    /// <p>The Unix timestamp for the date and time that the project was created.</p>
+    #[serde(rename = "CreationTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub creation_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
+    /// <p>The Amazon Resource Name (ARN) of the project.</p>
+    #[serde(rename = "ProjectArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_arn: Option<String>,
+    /// <p>The current status of the project.</p>
+    #[serde(rename = "Status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<ProjectStatus>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ProjectStatus {
+    /// `CREATING`
+    Creating,
+    /// `CREATED`
+    Created,
+    /// `DELETING`
+    Deleting,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ProjectStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ProjectStatus::Creating => "CREATING",
+            ProjectStatus::Created => "CREATED",
+            ProjectStatus::Deleting => "DELETING",
+            ProjectStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ProjectStatus {
+    fn default() -> ProjectStatus {
+        ProjectStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ProjectStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ProjectStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ProjectStatus {
+    fn from(value: &'a str) -> ProjectStatus {
+        match value {
+            "CREATING" => ProjectStatus::Creating,
+            "CREATED" => ProjectStatus::Created,
+            "DELETING" => ProjectStatus::Deleting,
+            _ => ProjectStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ProjectStatus {
+    fn from(value: String) -> ProjectStatus {
+        match value.as_str() {
+            "CREATING" => ProjectStatus::Creating,
+            "CREATED" => ProjectStatus::Created,
+            "DELETING" => ProjectStatus::Deleting,
+            _ => ProjectStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ProjectStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ProjectStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ProjectStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ProjectStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ProjectStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ProjectStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>The description of a version of a model.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct ProjectVersionDescription {
+    /// <p>The duration, in seconds, that the model version has been billed for training. This value is only returned if the model version has been successfully trained.</p>
+    #[serde(rename = "BillableTrainingTimeInSeconds")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub billable_training_time_in_seconds: Option<i64>,
+    /// <p>The Unix datetime for the date and time that training started.</p>
+    #[serde(rename = "CreationTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub creation_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
+    /// <p>The training results. <code>EvaluationResult</code> is only returned if training is successful.</p>
+    #[serde(rename = "EvaluationResult")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub evaluation_result: Option<EvaluationResult>,
+    /// <p>The minimum number of inference units used by the model. For more information, see <a>StartProjectVersion</a>.</p>
+    #[serde(rename = "MinInferenceUnits")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_inference_units: Option<i64>,
+    /// <p>The location where training results are saved.</p>
+    #[serde(rename = "OutputConfig")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output_config: Option<OutputConfig>,
+    /// <p>The Amazon Resource Name (ARN) of the model version. </p>
+    #[serde(rename = "ProjectVersionArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_version_arn: Option<String>,
+    /// <p>The current status of the model version.</p>
+    #[serde(rename = "Status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<ProjectVersionStatus>,
+    /// <p>A descriptive message for an error or warning that occurred.</p>
+    #[serde(rename = "StatusMessage")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_message: Option<String>,
+    /// <p>The manifest file that represents the testing results.</p>
+    #[serde(rename = "TestingDataResult")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub testing_data_result: Option<TestingDataResult>,
+    /// <p>The manifest file that represents the training results.</p>
+    #[serde(rename = "TrainingDataResult")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub training_data_result: Option<TrainingDataResult>,
+    /// <p>The Unix date and time that training of the model ended.</p>
+    #[serde(rename = "TrainingEndTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub training_end_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ProjectVersionStatus {
+    /// `TRAINING_IN_PROGRESS`
+    TrainingInProgress,
+    /// `TRAINING_COMPLETED`
+    TrainingCompleted,
+    /// `TRAINING_FAILED`
+    TrainingFailed,
+    /// `STARTING`
+    Starting,
+    /// `RUNNING`
+    Running,
+    /// `FAILED`
+    Failed,
+    /// `STOPPING`
+    Stopping,
+    /// `STOPPED`
+    Stopped,
+    /// `DELETING`
+    Deleting,
+    /// `COPYING_IN_PROGRESS`
+    CopyingInProgress,
+    /// `COPYING_COMPLETED`
+    CopyingCompleted,
+    /// `COPYING_FAILED`
+    CopyingFailed,
+    /// `DEPRECATED`
+    Deprecated,
+    /// `EXPIRED`
+    Expired,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ProjectVersionStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ProjectVersionStatus::TrainingInProgress => "TRAINING_IN_PROGRESS",
+            ProjectVersionStatus::TrainingCompleted => "TRAINING_COMPLETED",
+            ProjectVersionStatus::TrainingFailed => "TRAINING_FAILED",
+            ProjectVersionStatus::Starting => "STARTING",
+            ProjectVersionStatus::Running => "RUNNING",
+            ProjectVersionStatus::Failed => "FAILED",
+            ProjectVersionStatus::Stopping => "STOPPING",
+            ProjectVersionStatus::Stopped => "STOPPED",
+            ProjectVersionStatus::Deleting => "DELETING",
+            ProjectVersionStatus::CopyingInProgress => "COPYING_IN_PROGRESS",
+            ProjectVersionStatus::CopyingCompleted => "COPYING_COMPLETED",
+            ProjectVersionStatus::CopyingFailed => "COPYING_FAILED",
+            ProjectVersionStatus::Deprecated => "DEPRECATED",
+            ProjectVersionStatus::Expired => "EXPIRED",
+            ProjectVersionStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ProjectVersionStatus {
+    fn default() -> ProjectVersionStatus {
+        ProjectVersionStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ProjectVersionStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ProjectVersionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ProjectVersionStatus {
+    fn from(value: &'a str) -> ProjectVersionStatus {
+        match value {
+            "TRAINING_IN_PROGRESS" => ProjectVersionStatus::TrainingInProgress,
+            "TRAINING_COMPLETED" => ProjectVersionStatus::TrainingCompleted,
+            "TRAINING_FAILED" => ProjectVersionStatus::TrainingFailed,
+            "STARTING" => ProjectVersionStatus::Starting,
+            "RUNNING" => ProjectVersionStatus::Running,
+            "FAILED" => ProjectVersionStatus::Failed,
+            "STOPPING" => ProjectVersionStatus::Stopping,
+            "STOPPED" => ProjectVersionStatus::Stopped,
+            "DELETING" => ProjectVersionStatus::Deleting,
+            "COPYING_IN_PROGRESS" => ProjectVersionStatus::CopyingInProgress,
+            "COPYING_COMPLETED" => ProjectVersionStatus::CopyingCompleted,
+            "COPYING_FAILED" => ProjectVersionStatus::CopyingFailed,
+            "DEPRECATED" => ProjectVersionStatus::Deprecated,
+            "EXPIRED" => ProjectVersionStatus::Expired,
+            _ => ProjectVersionStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ProjectVersionStatus {
+    fn from(value: String) -> ProjectVersionStatus {
+        match value.as_str() {
+            "TRAINING_IN_PROGRESS" => ProjectVersionStatus::TrainingInProgress,
+            "TRAINING_COMPLETED" => ProjectVersionStatus::TrainingCompleted,
+            "TRAINING_FAILED" => ProjectVersionStatus::TrainingFailed,
+            "STARTING" => ProjectVersionStatus::Starting,
+            "RUNNING" => ProjectVersionStatus::Running,
+            "FAILED" => ProjectVersionStatus::Failed,
+            "STOPPING" => ProjectVersionStatus::Stopping,
+            "STOPPED" => ProjectVersionStatus::Stopped,
+            "DELETING" => ProjectVersionStatus::Deleting,
+            "COPYING_IN_PROGRESS" => ProjectVersionStatus::CopyingInProgress,
+            "COPYING_COMPLETED" => ProjectVersionStatus::CopyingCompleted,
+            "COPYING_FAILED" => ProjectVersionStatus::CopyingFailed,
+            "DEPRECATED" => ProjectVersionStatus::Deprecated,
+            "EXPIRED" => ProjectVersionStatus::Expired,
+            _ => ProjectVersionStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ProjectVersionStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ProjectVersionStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ProjectVersionStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ProjectVersionStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ProjectVersionStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ProjectVersionStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum QualityFilter {
+    /// `NONE`
+    None,
+    /// `AUTO`
+    Auto,
+    /// `LOW`
+    Low,
+    /// `MEDIUM`
+    Medium,
+    /// `HIGH`
+    High,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl QualityFilter {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            QualityFilter::None => "NONE",
+            QualityFilter::Auto => "AUTO",
+            QualityFilter::Low => "LOW",
+            QualityFilter::Medium => "MEDIUM",
+            QualityFilter::High => "HIGH",
+            QualityFilter::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for QualityFilter {
+    fn default() -> QualityFilter {
+        QualityFilter::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for QualityFilter {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for QualityFilter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for QualityFilter {
+    fn from(value: &'a str) -> QualityFilter {
+        match value {
+            "NONE" => QualityFilter::None,
+            "AUTO" => QualityFilter::Auto,
+            "LOW" => QualityFilter::Low,
+            "MEDIUM" => QualityFilter::Medium,
+            "HIGH" => QualityFilter::High,
+            _ => QualityFilter::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for QualityFilter {
+    fn from(value: String) -> QualityFilter {
+        match value.as_str() {
+            "NONE" => QualityFilter::None,
+            "AUTO" => QualityFilter::Auto,
+            "LOW" => QualityFilter::Low,
+            "MEDIUM" => QualityFilter::Medium,
+            "HIGH" => QualityFilter::High,
+            _ => QualityFilter::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for QualityFilter {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<QualityFilter, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for QualityFilter {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for QualityFilter {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for QualityFilter {
+    fn deserialize<D>(deserializer: D) -> Result<QualityFilter, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Reason {
+    /// `EXCEEDS_MAX_FACES`
+    ExceedsMaxFaces,
+    /// `EXTREME_POSE`
+    ExtremePose,
+    /// `LOW_BRIGHTNESS`
+    LowBrightness,
+    /// `LOW_SHARPNESS`
+    LowSharpness,
+    /// `LOW_CONFIDENCE`
+    LowConfidence,
+    /// `SMALL_BOUNDING_BOX`
+    SmallBoundingBox,
+    /// `LOW_FACE_QUALITY`
+    LowFaceQuality,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Reason {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Reason::ExceedsMaxFaces => "EXCEEDS_MAX_FACES",
+            Reason::ExtremePose => "EXTREME_POSE",
+            Reason::LowBrightness => "LOW_BRIGHTNESS",
+            Reason::LowSharpness => "LOW_SHARPNESS",
+            Reason::LowConfidence => "LOW_CONFIDENCE",
+            Reason::SmallBoundingBox => "SMALL_BOUNDING_BOX",
+            Reason::LowFaceQuality => "LOW_FACE_QUALITY",
+            Reason::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Reason {
+    fn default() -> Reason {
+        Reason::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Reason {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Reason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Reason {
+    fn from(value: &'a str) -> Reason {
+        match value {
+            "EXCEEDS_MAX_FACES" => Reason::ExceedsMaxFaces,
+            "EXTREME_POSE" => Reason::ExtremePose,
+            "LOW_BRIGHTNESS" => Reason::LowBrightness,
+            "LOW_SHARPNESS" => Reason::LowSharpness,
+            "LOW_CONFIDENCE" => Reason::LowConfidence,
+            "SMALL_BOUNDING_BOX" => Reason::SmallBoundingBox,
+            "LOW_FACE_QUALITY" => Reason::LowFaceQuality,
+            _ => Reason::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Reason {
+    fn from(value: String) -> Reason {
+        match value.as_str() {
+            "EXCEEDS_MAX_FACES" => Reason::ExceedsMaxFaces,
+            "EXTREME_POSE" => Reason::ExtremePose,
+            "LOW_BRIGHTNESS" => Reason::LowBrightness,
+            "LOW_SHARPNESS" => Reason::LowSharpness,
+            "LOW_CONFIDENCE" => Reason::LowConfidence,
+            "SMALL_BOUNDING_BOX" => Reason::SmallBoundingBox,
+            "LOW_FACE_QUALITY" => Reason::LowFaceQuality,
+            _ => Reason::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Reason {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Reason, Self::Err> {
+        Ok(value.into())
+    }
 }
 
-/// <p>The description of a version of a model.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct ProjectVersionDescription {
-    /// <p>The duration, in seconds, that the model version has been billed for training. This value is only returned if the model version has been successfully trained.</p>
-    #[serde(rename = "BillableTrainingTimeInSeconds")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub billable_training_time_in_seconds: Option<i64>,
-    /// <p>The Unix datetime for the date and time that training started.</p>
-    #[serde(rename = "CreationTimestamp")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_timestamp: Option<f64>,
-    /// <p>The training results. <code>EvaluationResult</code> is only returned if training is successful.</p>
-    #[serde(rename = "EvaluationResult")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub evaluation_result: Option<EvaluationResult>,
-    /// <p>The minimum number of inference units used by the model. For more information, see <a>StartProjectVersion</a>.</p>
-    #[serde(rename = "MinInferenceUnits")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub min_inference_units: Option<i64>,
-    /// <p>The location where training results are saved.</p>
-    #[serde(rename = "OutputConfig")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub output_config: Option<OutputConfig>,
-    /// <p>The Amazon Resource Name (ARN) of the model version. </p>
-    #[serde(rename = "ProjectVersionArn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub project_version_arn: Option<String>,
-    /// <p>The current status of the model version.</p>
-    #[serde(rename = "Status")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
-    /// <p>A descriptive message for an error or warning that occurred.</p>
-    #[serde(rename = "StatusMessage")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub status_message: Option<String>,
-    /// <p>The manifest file that represents the testing results.</p>
-    #[serde(rename = "TestingDataResult")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub testing_data_result: Option<TestingDataResult>,
-    /// <p>The manifest file that represents the training results.</p>
-    #[serde(rename = "TrainingDataResult")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub training_data_result: Option<TrainingDataResult>,
-    /// <p>The Unix date and time that training of the model ended.</p>
-    #[serde(rename = "TrainingEndTimestamp")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub training_end_timestamp: Option<f64>,
+impl ::rusoto_core::param::ToParam for Reason {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
 }
 
+impl ::serde::Serialize for Reason {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Reason {
+    fn deserialize<D>(deserializer: D) -> Result<Reason, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct RecognizeCelebritiesRequest {
@@ -1790,7 +3664,7 @@ pub struct RecognizeCelebritiesResponse {
     /// <p><p>The orientation of the input image (counterclockwise direction). If your application displays the image, you can use this value to correct the orientation. The bounding box coordinates returned in <code>CelebrityFaces</code> and <code>UnrecognizedFaces</code> represent face locations before the image orientation is corrected. </p> <note> <p>If the input image is in .jpeg format, it might contain exchangeable image (Exif) metadata that includes the image&#39;s orientation. If so, and the Exif metadata for the input image populates the orientation field, the value of <code>OrientationCorrection</code> is null. The <code>CelebrityFaces</code> and <code>UnrecognizedFaces</code> bounding box coordinates represent face locations after Exif metadata is used to correct the image orientation. Images in .png format don&#39;t contain Exif metadata. </p> </note></p>
     #[serde(rename = "OrientationCorrection")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub orientation_correction: Option<String>,
+    pub orientation_correction: Option<OrientationCorrection>,
     /// <p>Details about each unrecognized face in the image.</p>
     #[serde(rename = "UnrecognizedFaces")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1834,7 +3708,7 @@ pub struct SearchFacesByImageRequest {
     /// <p>A filter that specifies a quality bar for how much filtering is done to identify faces. Filtered faces aren't searched for in the collection. If you specify <code>AUTO</code>, Amazon Rekognition chooses the quality bar. If you specify <code>LOW</code>, <code>MEDIUM</code>, or <code>HIGH</code>, filtering removes all faces that don’t meet the chosen quality bar. The quality bar is based on a variety of common use cases. Low-quality detections can occur for a number of reasons. Some examples are an object that's misidentified as a face, a face that's too blurry, or a face with a pose that's too extreme to use. If you specify <code>NONE</code>, no filtering is performed. The default value is <code>NONE</code>. </p> <p>To use quality filtering, the collection you are using must be associated with version 3 of the face model or higher.</p>
     #[serde(rename = "QualityFilter")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub quality_filter: Option<String>,
+    pub quality_filter: Option<QualityFilter>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1980,7 +3854,7 @@ pub struct StartFaceDetectionRequest {
     /// <p>The face attributes you want returned.</p> <p> <code>DEFAULT</code> - The following subset of facial attributes are returned: BoundingBox, Confidence, Pose, Quality and Landmarks. </p> <p> <code>ALL</code> - All facial attributes are returned.</p>
     #[serde(rename = "FaceAttributes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub face_attributes: Option<String>,
+    pub face_attributes: Option<FaceAttributes>,
     /// <p>An identifier you specify that's returned in the completion notification that's published to your Amazon Simple Notification Service topic. For example, you can use <code>JobTag</code> to group related jobs and identify them in the completion notification.</p>
     #[serde(rename = "JobTag")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2118,7 +3992,7 @@ pub struct StartProjectVersionResponse {
     /// <p>The current running status of the model. </p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ProjectVersionStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -2147,7 +4021,7 @@ pub struct StopProjectVersionResponse {
     /// <p>The current status of the stop operation. </p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ProjectVersionStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -2173,7 +4047,7 @@ pub struct StreamProcessor {
     /// <p>Current status of the Amazon Rekognition stream processor.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<StreamProcessorStatus>,
 }
 
 /// <p>Information about the source streaming video. </p>
@@ -2203,6 +4077,119 @@ pub struct StreamProcessorSettings {
     pub face_search: Option<FaceSearchSettings>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StreamProcessorStatus {
+    /// `STOPPED`
+    Stopped,
+    /// `STARTING`
+    Starting,
+    /// `RUNNING`
+    Running,
+    /// `FAILED`
+    Failed,
+    /// `STOPPING`
+    Stopping,
+    /// `UPDATING`
+    Updating,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl StreamProcessorStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StreamProcessorStatus::Stopped => "STOPPED",
+            StreamProcessorStatus::Starting => "STARTING",
+            StreamProcessorStatus::Running => "RUNNING",
+            StreamProcessorStatus::Failed => "FAILED",
+            StreamProcessorStatus::Stopping => "STOPPING",
+            StreamProcessorStatus::Updating => "UPDATING",
+            StreamProcessorStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for StreamProcessorStatus {
+    fn default() -> StreamProcessorStatus {
+        StreamProcessorStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for StreamProcessorStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for StreamProcessorStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for StreamProcessorStatus {
+    fn from(value: &'a str) -> StreamProcessorStatus {
+        match value {
+            "STOPPED" => StreamProcessorStatus::Stopped,
+            "STARTING" => StreamProcessorStatus::Starting,
+            "RUNNING" => StreamProcessorStatus::Running,
+            "FAILED" => StreamProcessorStatus::Failed,
+            "STOPPING" => StreamProcessorStatus::Stopping,
+            "UPDATING" => StreamProcessorStatus::Updating,
+            _ => StreamProcessorStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StreamProcessorStatus {
+    fn from(value: String) -> StreamProcessorStatus {
+        match value.as_str() {
+            "STOPPED" => StreamProcessorStatus::Stopped,
+            "STARTING" => StreamProcessorStatus::Starting,
+            "RUNNING" => StreamProcessorStatus::Running,
+            "FAILED" => StreamProcessorStatus::Failed,
+            "STOPPING" => StreamProcessorStatus::Stopping,
+            "UPDATING" => StreamProcessorStatus::Updating,
+            _ => StreamProcessorStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for StreamProcessorStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<StreamProcessorStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StreamProcessorStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for StreamProcessorStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StreamProcessorStatus {
+    fn deserialize<D>(deserializer: D) -> Result<StreamProcessorStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The S3 bucket that contains the training summary. The training summary includes aggregated evaluation metrics for the entire testing dataset and metrics for each individual label. </p> <p>You get the training summary S3 bucket location by calling <a>DescribeProjectVersions</a>. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -2280,9 +4267,102 @@ pub struct TextDetection {
     /// <p>The type of text that was detected.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<TextTypes>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TextTypes {
+    /// `LINE`
+    Line,
+    /// `WORD`
+    Word,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TextTypes {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TextTypes::Line => "LINE",
+            TextTypes::Word => "WORD",
+            TextTypes::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TextTypes {
+    fn default() -> TextTypes {
+        TextTypes::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TextTypes {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TextTypes {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TextTypes {
+    fn from(value: &'a str) -> TextTypes {
+        match value {
+            "LINE" => TextTypes::Line,
+            "WORD" => TextTypes::Word,
+            _ => TextTypes::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TextTypes {
+    fn from(value: String) -> TextTypes {
+        match value.as_str() {
+            "LINE" => TextTypes::Line,
+            "WORD" => TextTypes::Word,
+            _ => TextTypes::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TextTypes {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TextTypes, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TextTypes {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
 }
 
+impl ::serde::Serialize for TextTypes {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TextTypes {
+    fn deserialize<D>(deserializer: D) -> Result<TextTypes, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The dataset used for training.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TrainingData {
@@ -2317,7 +4397,7 @@ pub struct UnindexedFace {
     /// <p><p>An array of reasons that specify why a face wasn&#39;t indexed. </p> <ul> <li> <p>EXTREME<em>POSE - The face is at a pose that can&#39;t be detected. For example, the head is turned too far away from the camera.</p> </li> <li> <p>EXCEEDS</em>MAX<em>FACES - The number of faces detected is already higher than that specified by the <code>MaxFaces</code> input parameter for <code>IndexFaces</code>.</p> </li> <li> <p>LOW</em>BRIGHTNESS - The image is too dark.</p> </li> <li> <p>LOW<em>SHARPNESS - The image is too blurry.</p> </li> <li> <p>LOW</em>CONFIDENCE - The face was detected with a low confidence.</p> </li> <li> <p>SMALL<em>BOUNDING</em>BOX - The bounding box around the face is too small.</p> </li> </ul></p>
     #[serde(rename = "Reasons")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub reasons: Option<Vec<String>>,
+    pub reasons: Option<Vec<Reason>>,
 }
 
 /// <p>Video file stored in an Amazon S3 bucket. Amazon Rekognition video start operations such as <a>StartLabelDetection</a> use <code>Video</code> to specify a video for analysis. The supported file formats are .mp4, .mov and .avi.</p>
@@ -2330,6 +4410,104 @@ pub struct Video {
     pub s3_object: Option<S3Object>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum VideoJobStatus {
+    /// `IN_PROGRESS`
+    InProgress,
+    /// `SUCCEEDED`
+    Succeeded,
+    /// `FAILED`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl VideoJobStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            VideoJobStatus::InProgress => "IN_PROGRESS",
+            VideoJobStatus::Succeeded => "SUCCEEDED",
+            VideoJobStatus::Failed => "FAILED",
+            VideoJobStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for VideoJobStatus {
+    fn default() -> VideoJobStatus {
+        VideoJobStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for VideoJobStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for VideoJobStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for VideoJobStatus {
+    fn from(value: &'a str) -> VideoJobStatus {
+        match value {
+            "IN_PROGRESS" => VideoJobStatus::InProgress,
+            "SUCCEEDED" => VideoJobStatus::Succeeded,
+            "FAILED" => VideoJobStatus::Failed,
+            _ => VideoJobStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for VideoJobStatus {
+    fn from(value: String) -> VideoJobStatus {
+        match value.as_str() {
+            "IN_PROGRESS" => VideoJobStatus::InProgress,
+            "SUCCEEDED" => VideoJobStatus::Succeeded,
+            "FAILED" => VideoJobStatus::Failed,
+            _ => VideoJobStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for VideoJobStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<VideoJobStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for VideoJobStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for VideoJobStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for VideoJobStatus {
+    fn deserialize<D>(deserializer: D) -> Result<VideoJobStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about a video that Amazon Rekognition analyzed. <code>Videometadata</code> is returned in every page of paginated responses from a Amazon Rekognition video operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -5795,11 +7973,12 @@ pub struct RekognitionClient {
 impl RekognitionClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> RekognitionClient {
         RekognitionClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Rekognition"),
         }
     }
 
@@ -5831,6 +8010,7 @@ impl Rekognition for RekognitionClient {
         input: CompareFacesRequest,
     ) -> Result<CompareFacesResponse, RusotoError<CompareFacesError>> {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("CompareFaces");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.CompareFaces");
@@ -5858,6 +8038,7 @@ impl Rekognition for RekognitionClient {
         input: CreateCollectionRequest,
     ) -> Result<CreateCollectionResponse, RusotoError<CreateCollectionError>> {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("CreateCollection");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.CreateCollection");
@@ -5886,6 +8067,7 @@ impl Rekognition for RekognitionClient {
         input: CreateProjectRequest,
     ) -> Result<CreateProjectResponse, RusotoError<CreateProjectError>> {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("CreateProject");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.CreateProject");
@@ -5913,6 +8095,7 @@ impl Rekognition for RekognitionClient {
         input: CreateProjectVersionRequest,
     ) -> Result<CreateProjectVersionResponse, RusotoError<CreateProjectVersionError>> {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("CreateProjectVersion");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.CreateProjectVersion");
@@ -5941,6 +8124,7 @@ impl Rekognition for RekognitionClient {
         input: CreateStreamProcessorRequest,
     ) -> Result<CreateStreamProcessorResponse, RusotoError<CreateStreamProcessorError>> {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("CreateStreamProcessor");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.CreateStreamProcessor");
@@ -5969,6 +8153,7 @@ impl Rekognition for RekognitionClient {
         input: DeleteCollectionRequest,
     ) -> Result<DeleteCollectionResponse, RusotoError<DeleteCollectionError>> {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("DeleteCollection");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.DeleteCollection");
@@ -5997,6 +8182,7 @@ impl Rekognition for RekognitionClient {
         input: DeleteFacesRequest,
     ) -> Result<DeleteFacesResponse, RusotoError<DeleteFacesError>> {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("DeleteFaces");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.DeleteFaces");
@@ -6024,6 +8210,7 @@ impl Rekognition for RekognitionClient {
         input: DeleteStreamProcessorRequest,
     ) -> Result<DeleteStreamProcessorResponse, RusotoError<DeleteStreamProcessorError>> {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("DeleteStreamProcessor");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.DeleteStreamProcessor");
@@ -6052,6 +8239,7 @@ impl Rekognition for RekognitionClient {
         input: DescribeCollectionRequest,
     ) -> Result<DescribeCollectionResponse, RusotoError<DescribeCollectionError>> {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("DescribeCollection");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.DescribeCollection");
@@ -6080,6 +8268,7 @@ impl Rekognition for RekognitionClient {
         input: DescribeProjectVersionsRequest,
     ) -> Result<DescribeProjectVersionsResponse, RusotoError<DescribeProjectVersionsError>> {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("DescribeProjectVersions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.DescribeProjectVersions");
@@ -6108,6 +8297,7 @@ impl Rekognition for RekognitionClient {
         input: DescribeProjectsRequest,
     ) -> Result<DescribeProjectsResponse, RusotoError<DescribeProjectsError>> {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("DescribeProjects");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.DescribeProjects");
@@ -6136,6 +8326,7 @@ impl Rekognition for RekognitionClient {
         input: DescribeStreamProcessorRequest,
     ) -> Result<DescribeStreamProcessorResponse, RusotoError<DescribeStreamProcessorError>> {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("DescribeStreamProcessor");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.DescribeStreamProcessor");
@@ -6164,6 +8355,7 @@ impl Rekognition for RekognitionClient {
         input: DetectCustomLabelsRequest,
     ) -> Result<DetectCustomLabelsResponse, RusotoError<DetectCustomLabelsError>> {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("DetectCustomLabels");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.DetectCustomLabels");
@@ -6192,6 +8384,7 @@ impl Rekognition for RekognitionClient {
         input: DetectFacesRequest,
     ) -> Result<DetectFacesResponse, RusotoError<DetectFacesError>> {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("DetectFaces");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.DetectFaces");
@@ -6219,6 +8412,7 @@ impl Rekognition for RekognitionClient {
         input: DetectLabelsRequest,
     ) -> Result<DetectLabelsResponse, RusotoError<DetectLabelsError>> {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("DetectLabels");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.DetectLabels");
@@ -6246,6 +8440,7 @@ impl Rekognition for RekognitionClient {
         input: DetectModerationLabelsRequest,
     ) -> Result<DetectModerationLabelsResponse, RusotoError<DetectModerationLabelsError>> {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("DetectModerationLabels");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.DetectModerationLabels");
@@ -6274,6 +8469,7 @@ impl Rekognition for RekognitionClient {
         input: DetectTextRequest,
     ) -> Result<DetectTextResponse, RusotoError<DetectTextError>> {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("DetectText");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.DetectText");
@@ -6301,6 +8497,7 @@ impl Rekognition for RekognitionClient {
         input: GetCelebrityInfoRequest,
     ) -> Result<GetCelebrityInfoResponse, RusotoError<GetCelebrityInfoError>> {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("GetCelebrityInfo");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.GetCelebrityInfo");
@@ -6329,6 +8526,7 @@ impl Rekognition for RekognitionClient {
         input: GetCelebrityRecognitionRequest,
     ) -> Result<GetCelebrityRecognitionResponse, RusotoError<GetCelebrityRecognitionError>> {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("GetCelebrityRecognition");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.GetCelebrityRecognition");
@@ -6357,6 +8555,7 @@ impl Rekognition for RekognitionClient {
         input: GetContentModerationRequest,
     ) -> Result<GetContentModerationResponse, RusotoError<GetContentModerationError>> {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("GetContentModeration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.GetContentModeration");
@@ -6385,6 +8584,7 @@ impl Rekognition for RekognitionClient {
         input: GetFaceDetectionRequest,
     ) -> Result<GetFaceDetectionResponse, RusotoError<GetFaceDetectionError>> {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("GetFaceDetection");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.GetFaceDetection");
@@ -6413,6 +8613,7 @@ impl Rekognition for RekognitionClient {
         input: GetFaceSearchRequest,
     ) -> Result<GetFaceSearchResponse, RusotoError<GetFaceSearchError>> {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("GetFaceSearch");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.GetFaceSearch");
@@ -6440,6 +8641,7 @@ impl Rekognition for RekognitionClient {
         input: GetLabelDetectionRequest,
     ) -> Result<GetLabelDetectionResponse, RusotoError<GetLabelDetectionError>> {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("GetLabelDetection");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.GetLabelDetection");
@@ -6468,6 +8670,7 @@ impl Rekognition for RekognitionClient {
         input: GetPersonTrackingRequest,
     ) -> Result<GetPersonTrackingResponse, RusotoError<GetPersonTrackingError>> {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("GetPersonTracking");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.GetPersonTracking");
@@ -6496,6 +8699,7 @@ impl Rekognition for RekognitionClient {
         input: IndexFacesRequest,
     ) -> Result<IndexFacesResponse, RusotoError<IndexFacesError>> {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("IndexFaces");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.IndexFaces");
@@ -6523,6 +8727,7 @@ impl Rekognition for RekognitionClient {
         input: ListCollectionsRequest,
     ) -> Result<ListCollectionsResponse, RusotoError<ListCollectionsError>> {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("ListCollections");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.ListCollections");
@@ -6550,6 +8755,7 @@ impl Rekognition for RekognitionClient {
         input: ListFacesRequest,
     ) -> Result<ListFacesResponse, RusotoError<ListFacesError>> {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("ListFaces");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.ListFaces");
@@ -6577,6 +8783,7 @@ impl Rekognition for RekognitionClient {
         input: ListStreamProcessorsRequest,
     ) -> Result<ListStreamProcessorsResponse, RusotoError<ListStreamProcessorsError>> {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("ListStreamProcessors");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.ListStreamProcessors");
@@ -6605,6 +8812,7 @@ impl Rekognition for RekognitionClient {
         input: RecognizeCelebritiesRequest,
     ) -> Result<RecognizeCelebritiesResponse, RusotoError<RecognizeCelebritiesError>> {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("RecognizeCelebrities");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.RecognizeCelebrities");
@@ -6633,6 +8841,7 @@ impl Rekognition for RekognitionClient {
         input: SearchFacesRequest,
     ) -> Result<SearchFacesResponse, RusotoError<SearchFacesError>> {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("SearchFaces");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.SearchFaces");
@@ -6660,6 +8869,7 @@ impl Rekognition for RekognitionClient {
         input: SearchFacesByImageRequest,
     ) -> Result<SearchFacesByImageResponse, RusotoError<SearchFacesByImageError>> {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("SearchFacesByImage");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.SearchFacesByImage");
@@ -6689,6 +8899,7 @@ impl Rekognition for RekognitionClient {
     ) -> Result<StartCelebrityRecognitionResponse, RusotoError<StartCelebrityRecognitionError>>
     {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("StartCelebrityRecognition");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6720,6 +8931,7 @@ impl Rekognition for RekognitionClient {
         input: StartContentModerationRequest,
     ) -> Result<StartContentModerationResponse, RusotoError<StartContentModerationError>> {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("StartContentModeration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.StartContentModeration");
@@ -6748,6 +8960,7 @@ impl Rekognition for RekognitionClient {
         input: StartFaceDetectionRequest,
     ) -> Result<StartFaceDetectionResponse, RusotoError<StartFaceDetectionError>> {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("StartFaceDetection");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.StartFaceDetection");
@@ -6776,6 +8989,7 @@ impl Rekognition for RekognitionClient {
         input: StartFaceSearchRequest,
     ) -> Result<StartFaceSearchResponse, RusotoError<StartFaceSearchError>> {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("StartFaceSearch");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.StartFaceSearch");
@@ -6803,6 +9017,7 @@ impl Rekognition for RekognitionClient {
         input: StartLabelDetectionRequest,
     ) -> Result<StartLabelDetectionResponse, RusotoError<StartLabelDetectionError>> {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("StartLabelDetection");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.StartLabelDetection");
@@ -6831,6 +9046,7 @@ impl Rekognition for RekognitionClient {
         input: StartPersonTrackingRequest,
     ) -> Result<StartPersonTrackingResponse, RusotoError<StartPersonTrackingError>> {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("StartPersonTracking");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.StartPersonTracking");
@@ -6859,6 +9075,7 @@ impl Rekognition for RekognitionClient {
         input: StartProjectVersionRequest,
     ) -> Result<StartProjectVersionResponse, RusotoError<StartProjectVersionError>> {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("StartProjectVersion");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.StartProjectVersion");
@@ -6887,6 +9104,7 @@ impl Rekognition for RekognitionClient {
         input: StartStreamProcessorRequest,
     ) -> Result<StartStreamProcessorResponse, RusotoError<StartStreamProcessorError>> {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("StartStreamProcessor");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.StartStreamProcessor");
@@ -6915,6 +9133,7 @@ impl Rekognition for RekognitionClient {
         input: StopProjectVersionRequest,
     ) -> Result<StopProjectVersionResponse, RusotoError<StopProjectVersionError>> {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("StopProjectVersion");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.StopProjectVersion");
@@ -6943,6 +9162,7 @@ impl Rekognition for RekognitionClient {
         input: StopStreamProcessorRequest,
     ) -> Result<StopStreamProcessorResponse, RusotoError<StopStreamProcessorError>> {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("StopStreamProcessor");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.StopStreamProcessor");
@@ -6965,3 +9185,277 @@ impl Rekognition for RekognitionClient {
         }
     }
 }
+impl RekognitionClient {
+    /// Auto-paginating version of `describe_project_versions`, streaming every page of results.
+    pub fn describe_project_versions_pages(
+        &self,
+        input: DescribeProjectVersionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeProjectVersionsResponse,
+        DescribeProjectVersionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RekognitionClient, input: DescribeProjectVersionsRequest| async move {
+                client.describe_project_versions(input).await
+            },
+            |input: &DescribeProjectVersionsRequest, output: &DescribeProjectVersionsResponse| {
+                Some(DescribeProjectVersionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_project_versions`, streaming the `ProjectVersionDescriptions` of every page of results.
+    pub fn describe_project_versions_items(
+        &self,
+        input: DescribeProjectVersionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ProjectVersionDescription,
+        DescribeProjectVersionsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_project_versions_pages(input),
+            |output: DescribeProjectVersionsResponse| {
+                output.project_version_descriptions.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_projects`, streaming every page of results.
+    pub fn describe_projects_pages(
+        &self,
+        input: DescribeProjectsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeProjectsResponse, DescribeProjectsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RekognitionClient, input: DescribeProjectsRequest| async move {
+                client.describe_projects(input).await
+            },
+            |input: &DescribeProjectsRequest, output: &DescribeProjectsResponse| {
+                Some(DescribeProjectsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_projects`, streaming the `ProjectDescriptions` of every page of results.
+    pub fn describe_projects_items(
+        &self,
+        input: DescribeProjectsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ProjectDescription, DescribeProjectsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_projects_pages(input),
+            |output: DescribeProjectsResponse| output.project_descriptions.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_collections`, streaming every page of results.
+    pub fn list_collections_pages(
+        &self,
+        input: ListCollectionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListCollectionsResponse, ListCollectionsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RekognitionClient, input: ListCollectionsRequest| async move {
+                client.list_collections(input).await
+            },
+            |input: &ListCollectionsRequest, output: &ListCollectionsResponse| {
+                Some(ListCollectionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_collections`, streaming the `CollectionIds` of every page of results.
+    pub fn list_collections_items(
+        &self,
+        input: ListCollectionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListCollectionsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_collections_pages(input),
+            |output: ListCollectionsResponse| output.collection_ids.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_faces`, streaming every page of results.
+    pub fn list_faces_pages(
+        &self,
+        input: ListFacesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListFacesResponse, ListFacesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RekognitionClient, input: ListFacesRequest| async move {
+                client.list_faces(input).await
+            },
+            |input: &ListFacesRequest, output: &ListFacesResponse| {
+                Some(ListFacesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_faces`, streaming the `Faces` of every page of results.
+    pub fn list_faces_items(
+        &self,
+        input: ListFacesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Face, ListFacesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_faces_pages(input),
+            |output: ListFacesResponse| output.faces.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_stream_processors`, streaming every page of results.
+    pub fn list_stream_processors_pages(
+        &self,
+        input: ListStreamProcessorsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListStreamProcessorsResponse,
+        ListStreamProcessorsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RekognitionClient, input: ListStreamProcessorsRequest| async move {
+                client.list_stream_processors(input).await
+            },
+            |input: &ListStreamProcessorsRequest, output: &ListStreamProcessorsResponse| {
+                Some(ListStreamProcessorsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_stream_processors`, streaming the `StreamProcessors` of every page of results.
+    pub fn list_stream_processors_items(
+        &self,
+        input: ListStreamProcessorsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<StreamProcessor, ListStreamProcessorsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_stream_processors_pages(input),
+            |output: ListStreamProcessorsResponse| output.stream_processors.unwrap_or_default(),
+        )
+    }
+}
+impl RekognitionClient {
+    /// Waits until `describe_project_versions` reports the `ProjectVersionRunning` state, polling every 30 seconds up to 40 times.
+    pub async fn wait_until_project_version_running(
+        &self,
+        input: DescribeProjectVersionsRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeProjectVersionsError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(30), 40);
+        self.wait_until_project_version_running_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_project_version_running`, polling as often and as many times as `config` says.
+    pub async fn wait_until_project_version_running_with_config(
+        &self,
+        input: DescribeProjectVersionsRequest,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeProjectVersionsError>> {
+        rusoto_core::waiter::wait(
+            "ProjectVersionRunning",
+            &config,
+            || self.describe_project_versions(input.clone()),
+            |result: &Result<
+                DescribeProjectVersionsResponse,
+                RusotoError<DescribeProjectVersionsError>,
+            >| match result {
+                Ok(output)
+                    if rusoto_core::waiter::all_match(
+                        output
+                            .project_version_descriptions
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.status.iter()),
+                        |v| *v == ProjectVersionStatus::Running,
+                    ) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if output
+                        .project_version_descriptions
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| *v == ProjectVersionStatus::Failed) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+
+    /// Waits until `describe_project_versions` reports the `ProjectVersionTrainingCompleted` state, polling every 120 seconds up to 360 times.
+    pub async fn wait_until_project_version_training_completed(
+        &self,
+        input: DescribeProjectVersionsRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeProjectVersionsError>> {
+        let config =
+            rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(120), 360);
+        self.wait_until_project_version_training_completed_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_project_version_training_completed`, polling as often and as many times as `config` says.
+    pub async fn wait_until_project_version_training_completed_with_config(
+        &self,
+        input: DescribeProjectVersionsRequest,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeProjectVersionsError>> {
+        rusoto_core::waiter::wait(
+            "ProjectVersionTrainingCompleted",
+            &config,
+            || self.describe_project_versions(input.clone()),
+            |result: &Result<
+                DescribeProjectVersionsResponse,
+                RusotoError<DescribeProjectVersionsError>,
+            >| match result {
+                Ok(output)
+                    if rusoto_core::waiter::all_match(
+                        output
+                            .project_version_descriptions
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .flat_map(|v| v.status.iter()),
+                        |v| *v == ProjectVersionStatus::TrainingCompleted,
+                    ) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if output
+                        .project_version_descriptions
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .flat_map(|v| v.status.iter())
+                        .any(|v| *v == ProjectVersionStatus::TrainingFailed) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+}
@@ -155,12 +155,105 @@ pub struct Group {
 pub struct GroupFilter {
     /// <p>The name of the filter. Filter names are case-sensitive.</p>
     #[serde(rename = "Name")]
-    pub name: String,
+    pub name: GroupFilterName,
     /// <p>One or more filter values. Allowed filter values vary by group filter name, and are case-sensitive.</p>
     #[serde(rename = "Values")]
     pub values: Vec<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum GroupFilterName {
+    /// `resource-type`
+    ResourceType,
+    /// `configuration-type`
+    ConfigurationType,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl GroupFilterName {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            GroupFilterName::ResourceType => "resource-type",
+            GroupFilterName::ConfigurationType => "configuration-type",
+            GroupFilterName::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for GroupFilterName {
+    fn default() -> GroupFilterName {
+        GroupFilterName::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for GroupFilterName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for GroupFilterName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for GroupFilterName {
+    fn from(value: &'a str) -> GroupFilterName {
+        match value {
+            "resource-type" => GroupFilterName::ResourceType,
+            "configuration-type" => GroupFilterName::ConfigurationType,
+            _ => GroupFilterName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for GroupFilterName {
+    fn from(value: String) -> GroupFilterName {
+        match value.as_str() {
+            "resource-type" => GroupFilterName::ResourceType,
+            "configuration-type" => GroupFilterName::ConfigurationType,
+            _ => GroupFilterName::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for GroupFilterName {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<GroupFilterName, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for GroupFilterName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for GroupFilterName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for GroupFilterName {
+    fn deserialize<D>(deserializer: D) -> Result<GroupFilterName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The ARN and group name of a group.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -261,25 +354,310 @@ pub struct QueryError {
     /// <p>Possible values are <code>CLOUDFORMATION_STACK_INACTIVE</code> and <code>CLOUDFORMATION_STACK_NOT_EXISTING</code>.</p>
     #[serde(rename = "ErrorCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub error_code: Option<String>,
+    pub error_code: Option<QueryErrorCode>,
     /// <p>A message that explains the <code>ErrorCode</code> value. Messages might state that the specified CloudFormation stack does not exist (or no longer exists). For <code>CLOUDFORMATION_STACK_INACTIVE</code>, the message typically states that the CloudFormation stack has a status that is not (or no longer) active, such as <code>CREATE_FAILED</code>.</p>
     #[serde(rename = "Message")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub message: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum QueryErrorCode {
+    /// `CLOUDFORMATION_STACK_INACTIVE`
+    CloudformationStackInactive,
+    /// `CLOUDFORMATION_STACK_NOT_EXISTING`
+    CloudformationStackNotExisting,
+    /// `CLOUDFORMATION_STACK_UNASSUMABLE_ROLE`
+    CloudformationStackUnassumableRole,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl QueryErrorCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            QueryErrorCode::CloudformationStackInactive => "CLOUDFORMATION_STACK_INACTIVE",
+            QueryErrorCode::CloudformationStackNotExisting => "CLOUDFORMATION_STACK_NOT_EXISTING",
+            QueryErrorCode::CloudformationStackUnassumableRole => {
+                "CLOUDFORMATION_STACK_UNASSUMABLE_ROLE"
+            }
+            QueryErrorCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for QueryErrorCode {
+    fn default() -> QueryErrorCode {
+        QueryErrorCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for QueryErrorCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for QueryErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for QueryErrorCode {
+    fn from(value: &'a str) -> QueryErrorCode {
+        match value {
+            "CLOUDFORMATION_STACK_INACTIVE" => QueryErrorCode::CloudformationStackInactive,
+            "CLOUDFORMATION_STACK_NOT_EXISTING" => QueryErrorCode::CloudformationStackNotExisting,
+            "CLOUDFORMATION_STACK_UNASSUMABLE_ROLE" => {
+                QueryErrorCode::CloudformationStackUnassumableRole
+            }
+            _ => QueryErrorCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for QueryErrorCode {
+    fn from(value: String) -> QueryErrorCode {
+        match value.as_str() {
+            "CLOUDFORMATION_STACK_INACTIVE" => QueryErrorCode::CloudformationStackInactive,
+            "CLOUDFORMATION_STACK_NOT_EXISTING" => QueryErrorCode::CloudformationStackNotExisting,
+            "CLOUDFORMATION_STACK_UNASSUMABLE_ROLE" => {
+                QueryErrorCode::CloudformationStackUnassumableRole
+            }
+            _ => QueryErrorCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for QueryErrorCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<QueryErrorCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for QueryErrorCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for QueryErrorCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for QueryErrorCode {
+    fn deserialize<D>(deserializer: D) -> Result<QueryErrorCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum QueryType {
+    /// `TAG_FILTERS_1_0`
+    TagFilters1_0,
+    /// `CLOUDFORMATION_STACK_1_0`
+    CloudformationStack1_0,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl QueryType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            QueryType::TagFilters1_0 => "TAG_FILTERS_1_0",
+            QueryType::CloudformationStack1_0 => "CLOUDFORMATION_STACK_1_0",
+            QueryType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for QueryType {
+    fn default() -> QueryType {
+        QueryType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for QueryType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for QueryType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for QueryType {
+    fn from(value: &'a str) -> QueryType {
+        match value {
+            "TAG_FILTERS_1_0" => QueryType::TagFilters1_0,
+            "CLOUDFORMATION_STACK_1_0" => QueryType::CloudformationStack1_0,
+            _ => QueryType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for QueryType {
+    fn from(value: String) -> QueryType {
+        match value.as_str() {
+            "TAG_FILTERS_1_0" => QueryType::TagFilters1_0,
+            "CLOUDFORMATION_STACK_1_0" => QueryType::CloudformationStack1_0,
+            _ => QueryType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for QueryType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<QueryType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for QueryType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for QueryType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for QueryType {
+    fn deserialize<D>(deserializer: D) -> Result<QueryType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A filter name and value pair that is used to obtain more specific results from a list of resources.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ResourceFilter {
     /// <p>The name of the filter. Filter names are case-sensitive.</p>
     #[serde(rename = "Name")]
-    pub name: String,
+    pub name: ResourceFilterName,
     /// <p>One or more filter values. Allowed filter values vary by resource filter name, and are case-sensitive.</p>
     #[serde(rename = "Values")]
     pub values: Vec<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ResourceFilterName {
+    /// `resource-type`
+    ResourceType,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ResourceFilterName {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceFilterName::ResourceType => "resource-type",
+            ResourceFilterName::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ResourceFilterName {
+    fn default() -> ResourceFilterName {
+        ResourceFilterName::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ResourceFilterName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ResourceFilterName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ResourceFilterName {
+    fn from(value: &'a str) -> ResourceFilterName {
+        match value {
+            "resource-type" => ResourceFilterName::ResourceType,
+            _ => ResourceFilterName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceFilterName {
+    fn from(value: String) -> ResourceFilterName {
+        match value.as_str() {
+            "resource-type" => ResourceFilterName::ResourceType,
+            _ => ResourceFilterName::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ResourceFilterName {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ResourceFilterName, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ResourceFilterName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ResourceFilterName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ResourceFilterName {
+    fn deserialize<D>(deserializer: D) -> Result<ResourceFilterName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The ARN of a resource, and its resource type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -302,7 +680,7 @@ pub struct ResourceQuery {
     pub query: String,
     /// <p>The type of the query. The valid values in this release are <code>TAG_FILTERS_1_0</code> and <code>CLOUDFORMATION_STACK_1_0</code>.</p> <p> <i> <code>TAG_FILTERS_1_0:</code> </i> A JSON syntax that lets you specify a collection of simple tag filters for resource types and tags, as supported by the AWS Tagging API <a href="https://docs.aws.amazon.com/resourcegroupstagging/latest/APIReference/API_GetResources.html">GetResources</a> operation. If you specify more than one tag key, only resources that match all tag keys, and at least one value of each specified tag key, are returned in your query. If you specify more than one value for a tag key, a resource matches the filter if it has a tag key value that matches <i>any</i> of the specified values.</p> <p>For example, consider the following sample query for resources that have two tags, <code>Stage</code> and <code>Version</code>, with two values each. (<code>[{"Key":"Stage","Values":["Test","Deploy"]},{"Key":"Version","Values":["1","2"]}]</code>) The results of this query might include the following.</p> <ul> <li> <p>An EC2 instance that has the following two tags: <code>{"Key":"Stage","Value":"Deploy"}</code>, and <code>{"Key":"Version","Value":"2"}</code> </p> </li> <li> <p>An S3 bucket that has the following two tags: {"Key":"Stage","Value":"Test"}, and {"Key":"Version","Value":"1"}</p> </li> </ul> <p>The query would not return the following results, however. The following EC2 instance does not have all tag keys specified in the filter, so it is rejected. The RDS database has all of the tag keys, but no values that match at least one of the specified tag key values in the filter.</p> <ul> <li> <p>An EC2 instance that has only the following tag: <code>{"Key":"Stage","Value":"Deploy"}</code>.</p> </li> <li> <p>An RDS database that has the following two tags: <code>{"Key":"Stage","Value":"Archived"}</code>, and <code>{"Key":"Version","Value":"4"}</code> </p> </li> </ul> <p> <i> <code>CLOUDFORMATION_STACK_1_0:</code> </i> A JSON syntax that lets you specify a CloudFormation stack ARN.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: QueryType,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -1218,11 +1596,12 @@ pub struct ResourceGroupsClient {
 impl ResourceGroupsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> ResourceGroupsClient {
         ResourceGroupsClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Resource Groups"),
         }
     }
 
@@ -1256,6 +1635,7 @@ impl ResourceGroups for ResourceGroupsClient {
         let request_uri = "/groups";
 
         let mut request = SignedRequest::new("POST", "resource-groups", &self.region, &request_uri);
+        request.set_operation("CreateGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1287,6 +1667,7 @@ impl ResourceGroups for ResourceGroupsClient {
 
         let mut request =
             SignedRequest::new("DELETE", "resource-groups", &self.region, &request_uri);
+        request.set_operation("DeleteGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1314,6 +1695,7 @@ impl ResourceGroups for ResourceGroupsClient {
         let request_uri = format!("/groups/{group_name}", group_name = input.group_name);
 
         let mut request = SignedRequest::new("GET", "resource-groups", &self.region, &request_uri);
+        request.set_operation("GetGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1341,6 +1723,7 @@ impl ResourceGroups for ResourceGroupsClient {
         let request_uri = format!("/groups/{group_name}/query", group_name = input.group_name);
 
         let mut request = SignedRequest::new("GET", "resource-groups", &self.region, &request_uri);
+        request.set_operation("GetGroupQuery");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1368,6 +1751,7 @@ impl ResourceGroups for ResourceGroupsClient {
         let request_uri = format!("/resources/{arn}/tags", arn = input.arn);
 
         let mut request = SignedRequest::new("GET", "resource-groups", &self.region, &request_uri);
+        request.set_operation("GetTags");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1398,6 +1782,7 @@ impl ResourceGroups for ResourceGroupsClient {
         );
 
         let mut request = SignedRequest::new("POST", "resource-groups", &self.region, &request_uri);
+        request.set_operation("ListGroupResources");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1437,6 +1822,7 @@ impl ResourceGroups for ResourceGroupsClient {
         let request_uri = "/groups-list";
 
         let mut request = SignedRequest::new("POST", "resource-groups", &self.region, &request_uri);
+        request.set_operation("ListGroups");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1476,6 +1862,7 @@ impl ResourceGroups for ResourceGroupsClient {
         let request_uri = "/resources/search";
 
         let mut request = SignedRequest::new("POST", "resource-groups", &self.region, &request_uri);
+        request.set_operation("SearchResources");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1503,6 +1890,7 @@ impl ResourceGroups for ResourceGroupsClient {
         let request_uri = format!("/resources/{arn}/tags", arn = input.arn);
 
         let mut request = SignedRequest::new("PUT", "resource-groups", &self.region, &request_uri);
+        request.set_operation("Tag");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1531,6 +1919,7 @@ impl ResourceGroups for ResourceGroupsClient {
 
         let mut request =
             SignedRequest::new("PATCH", "resource-groups", &self.region, &request_uri);
+        request.set_operation("Untag");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1561,6 +1950,7 @@ impl ResourceGroups for ResourceGroupsClient {
         let request_uri = format!("/groups/{group_name}", group_name = input.group_name);
 
         let mut request = SignedRequest::new("PUT", "resource-groups", &self.region, &request_uri);
+        request.set_operation("UpdateGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1591,6 +1981,7 @@ impl ResourceGroups for ResourceGroupsClient {
         let request_uri = format!("/groups/{group_name}/query", group_name = input.group_name);
 
         let mut request = SignedRequest::new("PUT", "resource-groups", &self.region, &request_uri);
+        request.set_operation("UpdateGroupQuery");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1613,3 +2004,66 @@ impl ResourceGroups for ResourceGroupsClient {
         }
     }
 }
+impl ResourceGroupsClient {
+    /// Auto-paginating version of `list_groups`, streaming every page of results.
+    pub fn list_groups_pages(
+        &self,
+        input: ListGroupsInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListGroupsOutput, ListGroupsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ResourceGroupsClient, input: ListGroupsInput| async move {
+                client.list_groups(input).await
+            },
+            |input: &ListGroupsInput, output: &ListGroupsOutput| {
+                Some(ListGroupsInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_groups`, streaming the `GroupIdentifiers` of every page of results.
+    pub fn list_groups_items(
+        &self,
+        input: ListGroupsInput,
+    ) -> rusoto_core::pagination::RusotoStream<GroupIdentifier, ListGroupsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_groups_pages(input),
+            |output: ListGroupsOutput| output.group_identifiers.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `search_resources`, streaming every page of results.
+    pub fn search_resources_pages(
+        &self,
+        input: SearchResourcesInput,
+    ) -> rusoto_core::pagination::RusotoStream<SearchResourcesOutput, SearchResourcesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ResourceGroupsClient, input: SearchResourcesInput| async move {
+                client.search_resources(input).await
+            },
+            |input: &SearchResourcesInput, output: &SearchResourcesOutput| {
+                Some(SearchResourcesInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `search_resources`, streaming the `ResourceIdentifiers` of every page of results.
+    pub fn search_resources_items(
+        &self,
+        input: SearchResourcesInput,
+    ) -> rusoto_core::pagination::RusotoStream<ResourceIdentifier, SearchResourcesError> {
+        rusoto_core::pagination::paginate_items(
+            self.search_resources_pages(input),
+            |output: SearchResourcesOutput| output.resource_identifiers.unwrap_or_default(),
+        )
+    }
+}
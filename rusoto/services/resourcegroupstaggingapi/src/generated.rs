@@ -63,6 +63,99 @@ pub struct DescribeReportCreationOutput {
     pub status: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorCode {
+    /// `InternalServiceException`
+    InternalServiceException,
+    /// `InvalidParameterException`
+    InvalidParameterException,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ErrorCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ErrorCode::InternalServiceException => "InternalServiceException",
+            ErrorCode::InvalidParameterException => "InvalidParameterException",
+            ErrorCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ErrorCode {
+    fn default() -> ErrorCode {
+        ErrorCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ErrorCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ErrorCode {
+    fn from(value: &'a str) -> ErrorCode {
+        match value {
+            "InternalServiceException" => ErrorCode::InternalServiceException,
+            "InvalidParameterException" => ErrorCode::InvalidParameterException,
+            _ => ErrorCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ErrorCode {
+    fn from(value: String) -> ErrorCode {
+        match value.as_str() {
+            "InternalServiceException" => ErrorCode::InternalServiceException,
+            "InvalidParameterException" => ErrorCode::InvalidParameterException,
+            _ => ErrorCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ErrorCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ErrorCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ErrorCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ErrorCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ErrorCode {
+    fn deserialize<D>(deserializer: D) -> Result<ErrorCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Details of the common errors that all actions return.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -70,7 +163,7 @@ pub struct FailureInfo {
     /// <p>The code of the common error. Valid values include <code>InternalServiceException</code>, <code>InvalidParameterException</code>, and any valid error code returned by the AWS service that hosts the resource that you want to tag.</p>
     #[serde(rename = "ErrorCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub error_code: Option<String>,
+    pub error_code: Option<ErrorCode>,
     /// <p>The message of the common error.</p>
     #[serde(rename = "ErrorMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -87,7 +180,7 @@ pub struct GetComplianceSummaryInput {
     /// <p>A list of attributes to group the counts of noncompliant resources by. If supplied, the counts are sorted by those attributes.</p>
     #[serde(rename = "GroupBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub group_by: Option<Vec<String>>,
+    pub group_by: Option<Vec<GroupByAttribute>>,
     /// <p>A limit that restricts the number of results that are returned per page.</p>
     #[serde(rename = "MaxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -220,6 +313,104 @@ pub struct GetTagValuesOutput {
     pub tag_values: Option<Vec<String>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum GroupByAttribute {
+    /// `TARGET_ID`
+    TargetId,
+    /// `REGION`
+    Region,
+    /// `RESOURCE_TYPE`
+    ResourceType,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl GroupByAttribute {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            GroupByAttribute::TargetId => "TARGET_ID",
+            GroupByAttribute::Region => "REGION",
+            GroupByAttribute::ResourceType => "RESOURCE_TYPE",
+            GroupByAttribute::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for GroupByAttribute {
+    fn default() -> GroupByAttribute {
+        GroupByAttribute::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for GroupByAttribute {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for GroupByAttribute {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for GroupByAttribute {
+    fn from(value: &'a str) -> GroupByAttribute {
+        match value {
+            "TARGET_ID" => GroupByAttribute::TargetId,
+            "REGION" => GroupByAttribute::Region,
+            "RESOURCE_TYPE" => GroupByAttribute::ResourceType,
+            _ => GroupByAttribute::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for GroupByAttribute {
+    fn from(value: String) -> GroupByAttribute {
+        match value.as_str() {
+            "TARGET_ID" => GroupByAttribute::TargetId,
+            "REGION" => GroupByAttribute::Region,
+            "RESOURCE_TYPE" => GroupByAttribute::ResourceType,
+            _ => GroupByAttribute::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for GroupByAttribute {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<GroupByAttribute, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for GroupByAttribute {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for GroupByAttribute {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for GroupByAttribute {
+    fn deserialize<D>(deserializer: D) -> Result<GroupByAttribute, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A list of resource ARNs and the tags (keys and values) that are associated with each.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -277,7 +468,7 @@ pub struct Summary {
     /// <p>Whether the target is an account, an OU, or the organization root.</p>
     #[serde(rename = "TargetIdType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub target_id_type: Option<String>,
+    pub target_id_type: Option<TargetIdType>,
 }
 
 /// <p>The metadata that you apply to AWS resources to help you categorize and organize them. Each tag consists of a key and an optional value, both of which you define. For more information, see <a href="http://docs.aws.amazon.com/general/latest/gr/aws_tagging.html">Tagging AWS Resources</a> in the <i>AWS General Reference</i>.</p>
@@ -326,6 +517,104 @@ pub struct TagResourcesOutput {
     pub failed_resources_map: Option<::std::collections::HashMap<String, FailureInfo>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TargetIdType {
+    /// `ACCOUNT`
+    Account,
+    /// `OU`
+    Ou,
+    /// `ROOT`
+    Root,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TargetIdType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TargetIdType::Account => "ACCOUNT",
+            TargetIdType::Ou => "OU",
+            TargetIdType::Root => "ROOT",
+            TargetIdType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TargetIdType {
+    fn default() -> TargetIdType {
+        TargetIdType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TargetIdType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TargetIdType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TargetIdType {
+    fn from(value: &'a str) -> TargetIdType {
+        match value {
+            "ACCOUNT" => TargetIdType::Account,
+            "OU" => TargetIdType::Ou,
+            "ROOT" => TargetIdType::Root,
+            _ => TargetIdType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TargetIdType {
+    fn from(value: String) -> TargetIdType {
+        match value.as_str() {
+            "ACCOUNT" => TargetIdType::Account,
+            "OU" => TargetIdType::Ou,
+            "ROOT" => TargetIdType::Root,
+            _ => TargetIdType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TargetIdType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TargetIdType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TargetIdType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TargetIdType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TargetIdType {
+    fn deserialize<D>(deserializer: D) -> Result<TargetIdType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct UntagResourcesInput {
@@ -802,11 +1091,15 @@ pub struct ResourceGroupsTaggingApiClient {
 impl ResourceGroupsTaggingApiClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> ResourceGroupsTaggingApiClient {
         ResourceGroupsTaggingApiClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(
+                region,
+                "Resource Groups Tagging API",
+            ),
         }
     }
 
@@ -840,6 +1133,7 @@ impl ResourceGroupsTaggingApi for ResourceGroupsTaggingApiClient {
         &self,
     ) -> Result<DescribeReportCreationOutput, RusotoError<DescribeReportCreationError>> {
         let mut request = SignedRequest::new("POST", "tagging", &self.region, "/");
+        request.set_operation("DescribeReportCreation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -870,6 +1164,7 @@ impl ResourceGroupsTaggingApi for ResourceGroupsTaggingApiClient {
         input: GetComplianceSummaryInput,
     ) -> Result<GetComplianceSummaryOutput, RusotoError<GetComplianceSummaryError>> {
         let mut request = SignedRequest::new("POST", "tagging", &self.region, "/");
+        request.set_operation("GetComplianceSummary");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -901,6 +1196,7 @@ impl ResourceGroupsTaggingApi for ResourceGroupsTaggingApiClient {
         input: GetResourcesInput,
     ) -> Result<GetResourcesOutput, RusotoError<GetResourcesError>> {
         let mut request = SignedRequest::new("POST", "tagging", &self.region, "/");
+        request.set_operation("GetResources");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -931,6 +1227,7 @@ impl ResourceGroupsTaggingApi for ResourceGroupsTaggingApiClient {
         input: GetTagKeysInput,
     ) -> Result<GetTagKeysOutput, RusotoError<GetTagKeysError>> {
         let mut request = SignedRequest::new("POST", "tagging", &self.region, "/");
+        request.set_operation("GetTagKeys");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -961,6 +1258,7 @@ impl ResourceGroupsTaggingApi for ResourceGroupsTaggingApiClient {
         input: GetTagValuesInput,
     ) -> Result<GetTagValuesOutput, RusotoError<GetTagValuesError>> {
         let mut request = SignedRequest::new("POST", "tagging", &self.region, "/");
+        request.set_operation("GetTagValues");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -991,6 +1289,7 @@ impl ResourceGroupsTaggingApi for ResourceGroupsTaggingApiClient {
         input: StartReportCreationInput,
     ) -> Result<StartReportCreationOutput, RusotoError<StartReportCreationError>> {
         let mut request = SignedRequest::new("POST", "tagging", &self.region, "/");
+        request.set_operation("StartReportCreation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1022,6 +1321,7 @@ impl ResourceGroupsTaggingApi for ResourceGroupsTaggingApiClient {
         input: TagResourcesInput,
     ) -> Result<TagResourcesOutput, RusotoError<TagResourcesError>> {
         let mut request = SignedRequest::new("POST", "tagging", &self.region, "/");
+        request.set_operation("TagResources");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1052,6 +1352,7 @@ impl ResourceGroupsTaggingApi for ResourceGroupsTaggingApiClient {
         input: UntagResourcesInput,
     ) -> Result<UntagResourcesOutput, RusotoError<UntagResourcesError>> {
         let mut request = SignedRequest::new("POST", "tagging", &self.region, "/");
+        request.set_operation("UntagResources");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1076,3 +1377,128 @@ impl ResourceGroupsTaggingApi for ResourceGroupsTaggingApiClient {
         }
     }
 }
+impl ResourceGroupsTaggingApiClient {
+    /// Auto-paginating version of `get_compliance_summary`, streaming every page of results.
+    pub fn get_compliance_summary_pages(
+        &self,
+        input: GetComplianceSummaryInput,
+    ) -> rusoto_core::pagination::RusotoStream<GetComplianceSummaryOutput, GetComplianceSummaryError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ResourceGroupsTaggingApiClient, input: GetComplianceSummaryInput| async move {
+                client.get_compliance_summary(input).await
+            },
+            |input: &GetComplianceSummaryInput, output: &GetComplianceSummaryOutput| {
+                Some(GetComplianceSummaryInput {
+                    pagination_token: Some(output.pagination_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_compliance_summary`, streaming the `SummaryList` of every page of results.
+    pub fn get_compliance_summary_items(
+        &self,
+        input: GetComplianceSummaryInput,
+    ) -> rusoto_core::pagination::RusotoStream<Summary, GetComplianceSummaryError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_compliance_summary_pages(input),
+            |output: GetComplianceSummaryOutput| output.summary_list.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_resources`, streaming every page of results.
+    pub fn get_resources_pages(
+        &self,
+        input: GetResourcesInput,
+    ) -> rusoto_core::pagination::RusotoStream<GetResourcesOutput, GetResourcesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ResourceGroupsTaggingApiClient, input: GetResourcesInput| async move {
+                client.get_resources(input).await
+            },
+            |input: &GetResourcesInput, output: &GetResourcesOutput| {
+                Some(GetResourcesInput {
+                    pagination_token: Some(output.pagination_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_resources`, streaming the `ResourceTagMappingList` of every page of results.
+    pub fn get_resources_items(
+        &self,
+        input: GetResourcesInput,
+    ) -> rusoto_core::pagination::RusotoStream<ResourceTagMapping, GetResourcesError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_resources_pages(input),
+            |output: GetResourcesOutput| output.resource_tag_mapping_list.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_tag_keys`, streaming every page of results.
+    pub fn get_tag_keys_pages(
+        &self,
+        input: GetTagKeysInput,
+    ) -> rusoto_core::pagination::RusotoStream<GetTagKeysOutput, GetTagKeysError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ResourceGroupsTaggingApiClient, input: GetTagKeysInput| async move {
+                client.get_tag_keys(input).await
+            },
+            |_input: &GetTagKeysInput, output: &GetTagKeysOutput| {
+                Some(GetTagKeysInput {
+                    pagination_token: Some(output.pagination_token.clone()?),
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_tag_keys`, streaming the `TagKeys` of every page of results.
+    pub fn get_tag_keys_items(
+        &self,
+        input: GetTagKeysInput,
+    ) -> rusoto_core::pagination::RusotoStream<String, GetTagKeysError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_tag_keys_pages(input),
+            |output: GetTagKeysOutput| output.tag_keys.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_tag_values`, streaming every page of results.
+    pub fn get_tag_values_pages(
+        &self,
+        input: GetTagValuesInput,
+    ) -> rusoto_core::pagination::RusotoStream<GetTagValuesOutput, GetTagValuesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ResourceGroupsTaggingApiClient, input: GetTagValuesInput| async move {
+                client.get_tag_values(input).await
+            },
+            |input: &GetTagValuesInput, output: &GetTagValuesOutput| {
+                Some(GetTagValuesInput {
+                    pagination_token: Some(output.pagination_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_tag_values`, streaming the `TagValues` of every page of results.
+    pub fn get_tag_values_items(
+        &self,
+        input: GetTagValuesInput,
+    ) -> rusoto_core::pagination::RusotoStream<String, GetTagValuesError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_tag_values_pages(input),
+            |output: GetTagValuesOutput| output.tag_values.unwrap_or_default(),
+        )
+    }
+}
@@ -25,6 +25,104 @@ use rusoto_core::signature::SignedRequest;
 #[allow(unused_imports)]
 use serde::{Deserialize, Serialize};
 use serde_json;
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Architecture {
+    /// `X86_64`
+    X86_64,
+    /// `ARM64`
+    Arm64,
+    /// `ARMHF`
+    Armhf,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Architecture {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Architecture::X86_64 => "X86_64",
+            Architecture::Arm64 => "ARM64",
+            Architecture::Armhf => "ARMHF",
+            Architecture::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Architecture {
+    fn default() -> Architecture {
+        Architecture::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Architecture {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Architecture {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Architecture {
+    fn from(value: &'a str) -> Architecture {
+        match value {
+            "X86_64" => Architecture::X86_64,
+            "ARM64" => Architecture::Arm64,
+            "ARMHF" => Architecture::Armhf,
+            _ => Architecture::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Architecture {
+    fn from(value: String) -> Architecture {
+        match value.as_str() {
+            "X86_64" => Architecture::X86_64,
+            "ARM64" => Architecture::Arm64,
+            "ARMHF" => Architecture::Armhf,
+            _ => Architecture::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Architecture {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Architecture, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Architecture {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Architecture {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Architecture {
+    fn deserialize<D>(deserializer: D) -> Result<Architecture, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct BatchDescribeSimulationJobRequest {
@@ -101,8 +199,13 @@ pub struct CreateDeploymentJobResponse {
     pub arn: Option<String>,
     /// <p>The time, in milliseconds since the epoch, when the fleet was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The deployment application configuration.</p>
     #[serde(rename = "deploymentApplicationConfigs")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -114,7 +217,7 @@ pub struct CreateDeploymentJobResponse {
     /// <p><p>The failure code of the simulation job if it failed:</p> <dl> <dt>BadPermissionError</dt> <dd> <p>AWS Greengrass requires a service-level role permission to access other services. The role must include the <a href="https://console.aws.amazon.com/iam/home?#/policies/arn:aws:iam::aws:policy/service-role/AWSGreengrassResourceAccessRolePolicy$jsonEditor"> <code>AWSGreengrassResourceAccessRolePolicy</code> managed policy</a>. </p> </dd> <dt>ExtractingBundleFailure</dt> <dd> <p>The robot application could not be extracted from the bundle.</p> </dd> <dt>FailureThresholdBreached</dt> <dd> <p>The percentage of robots that could not be updated exceeded the percentage set for the deployment.</p> </dd> <dt>GreengrassDeploymentFailed</dt> <dd> <p>The robot application could not be deployed to the robot.</p> </dd> <dt>GreengrassGroupVersionDoesNotExist</dt> <dd> <p>The AWS Greengrass group or version associated with a robot is missing.</p> </dd> <dt>InternalServerError</dt> <dd> <p>An internal error has occurred. Retry your request, but if the problem persists, contact us with details.</p> </dd> <dt>MissingRobotApplicationArchitecture</dt> <dd> <p>The robot application does not have a source that matches the architecture of the robot.</p> </dd> <dt>MissingRobotDeploymentResource</dt> <dd> <p>One or more of the resources specified for the robot application are missing. For example, does the robot application have the correct launch package and launch file?</p> </dd> <dt>PostLaunchFileFailure</dt> <dd> <p>The post-launch script failed.</p> </dd> <dt>PreLaunchFileFailure</dt> <dd> <p>The pre-launch script failed.</p> </dd> <dt>ResourceNotFound</dt> <dd> <p>One or more deployment resources are missing. For example, do robot application source bundles still exist? </p> </dd> <dt>RobotDeploymentNoResponse</dt> <dd> <p>There is no response from the robot. It might not be powered on or connected to the internet.</p> </dd> </dl></p>
     #[serde(rename = "failureCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub failure_code: Option<String>,
+    pub failure_code: Option<DeploymentJobErrorCode>,
     /// <p>The failure reason of the deployment job if it failed.</p>
     #[serde(rename = "failureReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -126,7 +229,7 @@ pub struct CreateDeploymentJobResponse {
     /// <p>The status of the deployment job.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<DeploymentStatus>,
     /// <p>The list of all tags added to the deployment job.</p>
     #[serde(rename = "tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -154,8 +257,13 @@ pub struct CreateFleetResponse {
     pub arn: Option<String>,
     /// <p>The time, in milliseconds since the epoch, when the fleet was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the fleet.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -193,8 +301,13 @@ pub struct CreateRobotApplicationResponse {
     pub arn: Option<String>,
     /// <p>The time, in milliseconds since the epoch, when the robot application was last updated.</p>
     #[serde(rename = "lastUpdatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_at: Option<f64>,
+    pub last_updated_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the robot application.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -242,8 +355,13 @@ pub struct CreateRobotApplicationVersionResponse {
     pub arn: Option<String>,
     /// <p>The time, in milliseconds since the epoch, when the robot application was last updated.</p>
     #[serde(rename = "lastUpdatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_at: Option<f64>,
+    pub last_updated_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the robot application.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -271,7 +389,7 @@ pub struct CreateRobotApplicationVersionResponse {
 pub struct CreateRobotRequest {
     /// <p>The target architecture of the robot.</p>
     #[serde(rename = "architecture")]
-    pub architecture: String,
+    pub architecture: Architecture,
     /// <p>The Greengrass group id.</p>
     #[serde(rename = "greengrassGroupId")]
     pub greengrass_group_id: String,
@@ -290,15 +408,20 @@ pub struct CreateRobotResponse {
     /// <p>The target architecture of the robot.</p>
     #[serde(rename = "architecture")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub architecture: Option<String>,
+    pub architecture: Option<Architecture>,
     /// <p>The Amazon Resource Name (ARN) of the robot.</p>
     #[serde(rename = "arn")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub arn: Option<String>,
     /// <p>The time, in milliseconds since the epoch, when the robot was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The Amazon Resource Name (ARN) of the Greengrass group associated with the robot.</p>
     #[serde(rename = "greengrassGroupId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -347,8 +470,13 @@ pub struct CreateSimulationApplicationResponse {
     pub arn: Option<String>,
     /// <p>The time, in milliseconds since the epoch, when the simulation application was last updated.</p>
     #[serde(rename = "lastUpdatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_at: Option<f64>,
+    pub last_updated_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the simulation application.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -404,8 +532,13 @@ pub struct CreateSimulationApplicationVersionResponse {
     pub arn: Option<String>,
     /// <p>The time, in milliseconds since the epoch, when the simulation application was last updated.</p>
     #[serde(rename = "lastUpdatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_at: Option<f64>,
+    pub last_updated_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the simulation application.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -450,7 +583,7 @@ pub struct CreateSimulationJobRequest {
     /// <p><p>The failure behavior the simulation job.</p> <dl> <dt>Continue</dt> <dd> <p>Restart the simulation job in the same host instance.</p> </dd> <dt>Fail</dt> <dd> <p>Stop the simulation job and terminate the instance.</p> </dd> </dl></p>
     #[serde(rename = "failureBehavior")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub failure_behavior: Option<String>,
+    pub failure_behavior: Option<FailureBehavior>,
     /// <p>The IAM role name that allows the simulation instance to call the AWS APIs that are specified in its associated policies on your behalf. This is how credentials are passed in to your simulation job. </p>
     #[serde(rename = "iamRole")]
     pub iam_role: String,
@@ -501,23 +634,33 @@ pub struct CreateSimulationJobResponse {
     /// <p>the failure behavior for the simulation job.</p>
     #[serde(rename = "failureBehavior")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub failure_behavior: Option<String>,
+    pub failure_behavior: Option<FailureBehavior>,
     /// <p><p>The failure code of the simulation job if it failed:</p> <dl> <dt>InternalServiceError</dt> <dd> <p>Internal service error.</p> </dd> <dt>RobotApplicationCrash</dt> <dd> <p>Robot application exited abnormally.</p> </dd> <dt>SimulationApplicationCrash</dt> <dd> <p> Simulation application exited abnormally.</p> </dd> <dt>BadPermissionsRobotApplication</dt> <dd> <p>Robot application bundle could not be downloaded.</p> </dd> <dt>BadPermissionsSimulationApplication</dt> <dd> <p>Simulation application bundle could not be downloaded.</p> </dd> <dt>BadPermissionsS3Output</dt> <dd> <p>Unable to publish outputs to customer-provided S3 bucket.</p> </dd> <dt>BadPermissionsCloudwatchLogs</dt> <dd> <p>Unable to publish logs to customer-provided CloudWatch Logs resource.</p> </dd> <dt>SubnetIpLimitExceeded</dt> <dd> <p>Subnet IP limit exceeded.</p> </dd> <dt>ENILimitExceeded</dt> <dd> <p>ENI limit exceeded.</p> </dd> <dt>BadPermissionsUserCredentials</dt> <dd> <p>Unable to use the Role provided.</p> </dd> <dt>InvalidBundleRobotApplication</dt> <dd> <p>Robot bundle cannot be extracted (invalid format, bundling error, or other issue).</p> </dd> <dt>InvalidBundleSimulationApplication</dt> <dd> <p>Simulation bundle cannot be extracted (invalid format, bundling error, or other issue).</p> </dd> <dt>RobotApplicationVersionMismatchedEtag</dt> <dd> <p>Etag for RobotApplication does not match value during version creation.</p> </dd> <dt>SimulationApplicationVersionMismatchedEtag</dt> <dd> <p>Etag for SimulationApplication does not match value during version creation.</p> </dd> </dl></p>
     #[serde(rename = "failureCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub failure_code: Option<String>,
+    pub failure_code: Option<SimulationJobErrorCode>,
     /// <p>The IAM role that allows the simulation job to call the AWS APIs that are specified in its associated policies on your behalf.</p>
     #[serde(rename = "iamRole")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub iam_role: Option<String>,
     /// <p>The time, in milliseconds since the epoch, when the simulation job was last started.</p>
     #[serde(rename = "lastStartedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_started_at: Option<f64>,
+    pub last_started_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The time, in milliseconds since the epoch, when the simulation job was last updated.</p>
     #[serde(rename = "lastUpdatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_at: Option<f64>,
+    pub last_updated_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The logging configuration.</p>
     #[serde(rename = "loggingConfig")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -545,7 +688,7 @@ pub struct CreateSimulationJobResponse {
     /// <p>The status of the simulation job.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<SimulationJobStatus>,
     /// <p>The list of all tags added to the simulation job.</p>
     #[serde(rename = "tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -690,8 +833,13 @@ pub struct DeploymentJob {
     pub arn: Option<String>,
     /// <p>The time, in milliseconds since the epoch, when the deployment job was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The deployment application configuration.</p>
     #[serde(rename = "deploymentApplicationConfigs")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -703,7 +851,7 @@ pub struct DeploymentJob {
     /// <p>The deployment job failure code.</p>
     #[serde(rename = "failureCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub failure_code: Option<String>,
+    pub failure_code: Option<DeploymentJobErrorCode>,
     /// <p>A short description of the reason why the deployment job failed.</p>
     #[serde(rename = "failureReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -715,9 +863,230 @@ pub struct DeploymentJob {
     /// <p>The status of the deployment job.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<DeploymentStatus>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DeploymentJobErrorCode {
+    /// `ResourceNotFound`
+    ResourceNotFound,
+    /// `EnvironmentSetupError`
+    EnvironmentSetupError,
+    /// `EtagMismatch`
+    EtagMismatch,
+    /// `FailureThresholdBreached`
+    FailureThresholdBreached,
+    /// `RobotDeploymentAborted`
+    RobotDeploymentAborted,
+    /// `RobotDeploymentNoResponse`
+    RobotDeploymentNoResponse,
+    /// `RobotAgentConnectionTimeout`
+    RobotAgentConnectionTimeout,
+    /// `GreengrassDeploymentFailed`
+    GreengrassDeploymentFailed,
+    /// `InvalidGreengrassGroup`
+    InvalidGreengrassGroup,
+    /// `MissingRobotArchitecture`
+    MissingRobotArchitecture,
+    /// `MissingRobotApplicationArchitecture`
+    MissingRobotApplicationArchitecture,
+    /// `MissingRobotDeploymentResource`
+    MissingRobotDeploymentResource,
+    /// `GreengrassGroupVersionDoesNotExist`
+    GreengrassGroupVersionDoesNotExist,
+    /// `LambdaDeleted`
+    LambdaDeleted,
+    /// `ExtractingBundleFailure`
+    ExtractingBundleFailure,
+    /// `PreLaunchFileFailure`
+    PreLaunchFileFailure,
+    /// `PostLaunchFileFailure`
+    PostLaunchFileFailure,
+    /// `BadPermissionError`
+    BadPermissionError,
+    /// `DownloadConditionFailed`
+    DownloadConditionFailed,
+    /// `BadLambdaAssociated`
+    BadLambdaAssociated,
+    /// `InternalServerError`
+    InternalServerError,
+    /// `RobotApplicationDoesNotExist`
+    RobotApplicationDoesNotExist,
+    /// `DeploymentFleetDoesNotExist`
+    DeploymentFleetDoesNotExist,
+    /// `FleetDeploymentTimeout`
+    FleetDeploymentTimeout,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DeploymentJobErrorCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeploymentJobErrorCode::ResourceNotFound => "ResourceNotFound",
+            DeploymentJobErrorCode::EnvironmentSetupError => "EnvironmentSetupError",
+            DeploymentJobErrorCode::EtagMismatch => "EtagMismatch",
+            DeploymentJobErrorCode::FailureThresholdBreached => "FailureThresholdBreached",
+            DeploymentJobErrorCode::RobotDeploymentAborted => "RobotDeploymentAborted",
+            DeploymentJobErrorCode::RobotDeploymentNoResponse => "RobotDeploymentNoResponse",
+            DeploymentJobErrorCode::RobotAgentConnectionTimeout => "RobotAgentConnectionTimeout",
+            DeploymentJobErrorCode::GreengrassDeploymentFailed => "GreengrassDeploymentFailed",
+            DeploymentJobErrorCode::InvalidGreengrassGroup => "InvalidGreengrassGroup",
+            DeploymentJobErrorCode::MissingRobotArchitecture => "MissingRobotArchitecture",
+            DeploymentJobErrorCode::MissingRobotApplicationArchitecture => {
+                "MissingRobotApplicationArchitecture"
+            }
+            DeploymentJobErrorCode::MissingRobotDeploymentResource => {
+                "MissingRobotDeploymentResource"
+            }
+            DeploymentJobErrorCode::GreengrassGroupVersionDoesNotExist => {
+                "GreengrassGroupVersionDoesNotExist"
+            }
+            DeploymentJobErrorCode::LambdaDeleted => "LambdaDeleted",
+            DeploymentJobErrorCode::ExtractingBundleFailure => "ExtractingBundleFailure",
+            DeploymentJobErrorCode::PreLaunchFileFailure => "PreLaunchFileFailure",
+            DeploymentJobErrorCode::PostLaunchFileFailure => "PostLaunchFileFailure",
+            DeploymentJobErrorCode::BadPermissionError => "BadPermissionError",
+            DeploymentJobErrorCode::DownloadConditionFailed => "DownloadConditionFailed",
+            DeploymentJobErrorCode::BadLambdaAssociated => "BadLambdaAssociated",
+            DeploymentJobErrorCode::InternalServerError => "InternalServerError",
+            DeploymentJobErrorCode::RobotApplicationDoesNotExist => "RobotApplicationDoesNotExist",
+            DeploymentJobErrorCode::DeploymentFleetDoesNotExist => "DeploymentFleetDoesNotExist",
+            DeploymentJobErrorCode::FleetDeploymentTimeout => "FleetDeploymentTimeout",
+            DeploymentJobErrorCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DeploymentJobErrorCode {
+    fn default() -> DeploymentJobErrorCode {
+        DeploymentJobErrorCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DeploymentJobErrorCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DeploymentJobErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DeploymentJobErrorCode {
+    fn from(value: &'a str) -> DeploymentJobErrorCode {
+        match value {
+            "ResourceNotFound" => DeploymentJobErrorCode::ResourceNotFound,
+            "EnvironmentSetupError" => DeploymentJobErrorCode::EnvironmentSetupError,
+            "EtagMismatch" => DeploymentJobErrorCode::EtagMismatch,
+            "FailureThresholdBreached" => DeploymentJobErrorCode::FailureThresholdBreached,
+            "RobotDeploymentAborted" => DeploymentJobErrorCode::RobotDeploymentAborted,
+            "RobotDeploymentNoResponse" => DeploymentJobErrorCode::RobotDeploymentNoResponse,
+            "RobotAgentConnectionTimeout" => DeploymentJobErrorCode::RobotAgentConnectionTimeout,
+            "GreengrassDeploymentFailed" => DeploymentJobErrorCode::GreengrassDeploymentFailed,
+            "InvalidGreengrassGroup" => DeploymentJobErrorCode::InvalidGreengrassGroup,
+            "MissingRobotArchitecture" => DeploymentJobErrorCode::MissingRobotArchitecture,
+            "MissingRobotApplicationArchitecture" => {
+                DeploymentJobErrorCode::MissingRobotApplicationArchitecture
+            }
+            "MissingRobotDeploymentResource" => {
+                DeploymentJobErrorCode::MissingRobotDeploymentResource
+            }
+            "GreengrassGroupVersionDoesNotExist" => {
+                DeploymentJobErrorCode::GreengrassGroupVersionDoesNotExist
+            }
+            "LambdaDeleted" => DeploymentJobErrorCode::LambdaDeleted,
+            "ExtractingBundleFailure" => DeploymentJobErrorCode::ExtractingBundleFailure,
+            "PreLaunchFileFailure" => DeploymentJobErrorCode::PreLaunchFileFailure,
+            "PostLaunchFileFailure" => DeploymentJobErrorCode::PostLaunchFileFailure,
+            "BadPermissionError" => DeploymentJobErrorCode::BadPermissionError,
+            "DownloadConditionFailed" => DeploymentJobErrorCode::DownloadConditionFailed,
+            "BadLambdaAssociated" => DeploymentJobErrorCode::BadLambdaAssociated,
+            "InternalServerError" => DeploymentJobErrorCode::InternalServerError,
+            "RobotApplicationDoesNotExist" => DeploymentJobErrorCode::RobotApplicationDoesNotExist,
+            "DeploymentFleetDoesNotExist" => DeploymentJobErrorCode::DeploymentFleetDoesNotExist,
+            "FleetDeploymentTimeout" => DeploymentJobErrorCode::FleetDeploymentTimeout,
+            _ => DeploymentJobErrorCode::Unknown(value.to_owned()),
+        }
+    }
 }
 
+impl From<String> for DeploymentJobErrorCode {
+    fn from(value: String) -> DeploymentJobErrorCode {
+        match value.as_str() {
+            "ResourceNotFound" => DeploymentJobErrorCode::ResourceNotFound,
+            "EnvironmentSetupError" => DeploymentJobErrorCode::EnvironmentSetupError,
+            "EtagMismatch" => DeploymentJobErrorCode::EtagMismatch,
+            "FailureThresholdBreached" => DeploymentJobErrorCode::FailureThresholdBreached,
+            "RobotDeploymentAborted" => DeploymentJobErrorCode::RobotDeploymentAborted,
+            "RobotDeploymentNoResponse" => DeploymentJobErrorCode::RobotDeploymentNoResponse,
+            "RobotAgentConnectionTimeout" => DeploymentJobErrorCode::RobotAgentConnectionTimeout,
+            "GreengrassDeploymentFailed" => DeploymentJobErrorCode::GreengrassDeploymentFailed,
+            "InvalidGreengrassGroup" => DeploymentJobErrorCode::InvalidGreengrassGroup,
+            "MissingRobotArchitecture" => DeploymentJobErrorCode::MissingRobotArchitecture,
+            "MissingRobotApplicationArchitecture" => {
+                DeploymentJobErrorCode::MissingRobotApplicationArchitecture
+            }
+            "MissingRobotDeploymentResource" => {
+                DeploymentJobErrorCode::MissingRobotDeploymentResource
+            }
+            "GreengrassGroupVersionDoesNotExist" => {
+                DeploymentJobErrorCode::GreengrassGroupVersionDoesNotExist
+            }
+            "LambdaDeleted" => DeploymentJobErrorCode::LambdaDeleted,
+            "ExtractingBundleFailure" => DeploymentJobErrorCode::ExtractingBundleFailure,
+            "PreLaunchFileFailure" => DeploymentJobErrorCode::PreLaunchFileFailure,
+            "PostLaunchFileFailure" => DeploymentJobErrorCode::PostLaunchFileFailure,
+            "BadPermissionError" => DeploymentJobErrorCode::BadPermissionError,
+            "DownloadConditionFailed" => DeploymentJobErrorCode::DownloadConditionFailed,
+            "BadLambdaAssociated" => DeploymentJobErrorCode::BadLambdaAssociated,
+            "InternalServerError" => DeploymentJobErrorCode::InternalServerError,
+            "RobotApplicationDoesNotExist" => DeploymentJobErrorCode::RobotApplicationDoesNotExist,
+            "DeploymentFleetDoesNotExist" => DeploymentJobErrorCode::DeploymentFleetDoesNotExist,
+            "FleetDeploymentTimeout" => DeploymentJobErrorCode::FleetDeploymentTimeout,
+            _ => DeploymentJobErrorCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DeploymentJobErrorCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DeploymentJobErrorCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DeploymentJobErrorCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DeploymentJobErrorCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeploymentJobErrorCode {
+    fn deserialize<D>(deserializer: D) -> Result<DeploymentJobErrorCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Configuration information for a deployment launch.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DeploymentLaunchConfig {
@@ -741,6 +1110,119 @@ pub struct DeploymentLaunchConfig {
     pub pre_launch_file: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DeploymentStatus {
+    /// `Pending`
+    Pending,
+    /// `Preparing`
+    Preparing,
+    /// `InProgress`
+    InProgress,
+    /// `Failed`
+    Failed,
+    /// `Succeeded`
+    Succeeded,
+    /// `Canceled`
+    Canceled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DeploymentStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeploymentStatus::Pending => "Pending",
+            DeploymentStatus::Preparing => "Preparing",
+            DeploymentStatus::InProgress => "InProgress",
+            DeploymentStatus::Failed => "Failed",
+            DeploymentStatus::Succeeded => "Succeeded",
+            DeploymentStatus::Canceled => "Canceled",
+            DeploymentStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DeploymentStatus {
+    fn default() -> DeploymentStatus {
+        DeploymentStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DeploymentStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DeploymentStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DeploymentStatus {
+    fn from(value: &'a str) -> DeploymentStatus {
+        match value {
+            "Pending" => DeploymentStatus::Pending,
+            "Preparing" => DeploymentStatus::Preparing,
+            "InProgress" => DeploymentStatus::InProgress,
+            "Failed" => DeploymentStatus::Failed,
+            "Succeeded" => DeploymentStatus::Succeeded,
+            "Canceled" => DeploymentStatus::Canceled,
+            _ => DeploymentStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeploymentStatus {
+    fn from(value: String) -> DeploymentStatus {
+        match value.as_str() {
+            "Pending" => DeploymentStatus::Pending,
+            "Preparing" => DeploymentStatus::Preparing,
+            "InProgress" => DeploymentStatus::InProgress,
+            "Failed" => DeploymentStatus::Failed,
+            "Succeeded" => DeploymentStatus::Succeeded,
+            "Canceled" => DeploymentStatus::Canceled,
+            _ => DeploymentStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DeploymentStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DeploymentStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DeploymentStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DeploymentStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeploymentStatus {
+    fn deserialize<D>(deserializer: D) -> Result<DeploymentStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct DeregisterRobotRequest {
@@ -782,8 +1264,13 @@ pub struct DescribeDeploymentJobResponse {
     pub arn: Option<String>,
     /// <p>The time, in milliseconds since the epoch, when the deployment job was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The deployment application configuration.</p>
     #[serde(rename = "deploymentApplicationConfigs")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -795,7 +1282,7 @@ pub struct DescribeDeploymentJobResponse {
     /// <p>The deployment job failure code.</p>
     #[serde(rename = "failureCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub failure_code: Option<String>,
+    pub failure_code: Option<DeploymentJobErrorCode>,
     /// <p>A short description of the reason why the deployment job failed.</p>
     #[serde(rename = "failureReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -811,7 +1298,7 @@ pub struct DescribeDeploymentJobResponse {
     /// <p>The status of the deployment job.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<DeploymentStatus>,
     /// <p>The list of all tags added to the specified deployment job.</p>
     #[serde(rename = "tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -835,8 +1322,13 @@ pub struct DescribeFleetResponse {
     pub arn: Option<String>,
     /// <p>The time, in milliseconds since the epoch, when the fleet was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The Amazon Resource Name (ARN) of the last deployment job.</p>
     #[serde(rename = "lastDeploymentJob")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -844,11 +1336,16 @@ pub struct DescribeFleetResponse {
     /// <p>The status of the last deployment.</p>
     #[serde(rename = "lastDeploymentStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_deployment_status: Option<String>,
+    pub last_deployment_status: Option<DeploymentStatus>,
     /// <p>The time of the last deployment.</p>
     #[serde(rename = "lastDeploymentTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_deployment_time: Option<f64>,
+    pub last_deployment_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the fleet.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -884,8 +1381,13 @@ pub struct DescribeRobotApplicationResponse {
     pub arn: Option<String>,
     /// <p>The time, in milliseconds since the epoch, when the robot application was last updated.</p>
     #[serde(rename = "lastUpdatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_at: Option<f64>,
+    pub last_updated_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the robot application.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -926,15 +1428,20 @@ pub struct DescribeRobotResponse {
     /// <p>The target architecture of the robot application.</p>
     #[serde(rename = "architecture")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub architecture: Option<String>,
+    pub architecture: Option<Architecture>,
     /// <p>The Amazon Resource Name (ARN) of the robot.</p>
     #[serde(rename = "arn")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub arn: Option<String>,
     /// <p>The time, in milliseconds since the epoch, when the robot was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The Amazon Resource Name (ARN) of the fleet.</p>
     #[serde(rename = "fleetArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -949,8 +1456,13 @@ pub struct DescribeRobotResponse {
     pub last_deployment_job: Option<String>,
     /// <p>The time of the last deployment job.</p>
     #[serde(rename = "lastDeploymentTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_deployment_time: Option<f64>,
+    pub last_deployment_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the robot.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -958,7 +1470,7 @@ pub struct DescribeRobotResponse {
     /// <p>The status of the fleet.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<RobotStatus>,
     /// <p>The list of all tags added to the specified robot.</p>
     #[serde(rename = "tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -986,8 +1498,13 @@ pub struct DescribeSimulationApplicationResponse {
     pub arn: Option<String>,
     /// <p>The time, in milliseconds since the epoch, when the simulation application was last updated.</p>
     #[serde(rename = "lastUpdatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_at: Option<f64>,
+    pub last_updated_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the simulation application.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1048,11 +1565,11 @@ pub struct DescribeSimulationJobResponse {
     /// <p>The failure behavior for the simulation job.</p>
     #[serde(rename = "failureBehavior")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub failure_behavior: Option<String>,
+    pub failure_behavior: Option<FailureBehavior>,
     /// <p><p>The failure code of the simulation job if it failed:</p> <dl> <dt>InternalServiceError</dt> <dd> <p>Internal service error.</p> </dd> <dt>RobotApplicationCrash</dt> <dd> <p>Robot application exited abnormally.</p> </dd> <dt>SimulationApplicationCrash</dt> <dd> <p> Simulation application exited abnormally.</p> </dd> <dt>BadPermissionsRobotApplication</dt> <dd> <p>Robot application bundle could not be downloaded.</p> </dd> <dt>BadPermissionsSimulationApplication</dt> <dd> <p>Simulation application bundle could not be downloaded.</p> </dd> <dt>BadPermissionsS3Output</dt> <dd> <p>Unable to publish outputs to customer-provided S3 bucket.</p> </dd> <dt>BadPermissionsCloudwatchLogs</dt> <dd> <p>Unable to publish logs to customer-provided CloudWatch Logs resource.</p> </dd> <dt>SubnetIpLimitExceeded</dt> <dd> <p>Subnet IP limit exceeded.</p> </dd> <dt>ENILimitExceeded</dt> <dd> <p>ENI limit exceeded.</p> </dd> <dt>BadPermissionsUserCredentials</dt> <dd> <p>Unable to use the Role provided.</p> </dd> <dt>InvalidBundleRobotApplication</dt> <dd> <p>Robot bundle cannot be extracted (invalid format, bundling error, or other issue).</p> </dd> <dt>InvalidBundleSimulationApplication</dt> <dd> <p>Simulation bundle cannot be extracted (invalid format, bundling error, or other issue).</p> </dd> <dt>RobotApplicationVersionMismatchedEtag</dt> <dd> <p>Etag for RobotApplication does not match value during version creation.</p> </dd> <dt>SimulationApplicationVersionMismatchedEtag</dt> <dd> <p>Etag for SimulationApplication does not match value during version creation.</p> </dd> </dl></p>
     #[serde(rename = "failureCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub failure_code: Option<String>,
+    pub failure_code: Option<SimulationJobErrorCode>,
     /// <p>Details about why the simulation job failed. For more information about troubleshooting, see <a href="https://docs.aws.amazon.com/robomaker/latest/dg/troubleshooting.html">Troubleshooting</a>.</p>
     #[serde(rename = "failureReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1063,12 +1580,22 @@ pub struct DescribeSimulationJobResponse {
     pub iam_role: Option<String>,
     /// <p>The time, in milliseconds since the epoch, when the simulation job was last started.</p>
     #[serde(rename = "lastStartedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_started_at: Option<f64>,
+    pub last_started_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The time, in milliseconds since the epoch, when the simulation job was last updated.</p>
     #[serde(rename = "lastUpdatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_at: Option<f64>,
+    pub last_updated_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The logging configuration.</p>
     #[serde(rename = "loggingConfig")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1104,7 +1631,7 @@ pub struct DescribeSimulationJobResponse {
     /// <p>The status of the simulation job.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<SimulationJobStatus>,
     /// <p>The list of all tags added to the specified simulation job.</p>
     #[serde(rename = "tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1115,6 +1642,99 @@ pub struct DescribeSimulationJobResponse {
     pub vpc_config: Option<VPCConfigResponse>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FailureBehavior {
+    /// `Fail`
+    Fail,
+    /// `Continue`
+    Continue,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl FailureBehavior {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FailureBehavior::Fail => "Fail",
+            FailureBehavior::Continue => "Continue",
+            FailureBehavior::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for FailureBehavior {
+    fn default() -> FailureBehavior {
+        FailureBehavior::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for FailureBehavior {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FailureBehavior {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FailureBehavior {
+    fn from(value: &'a str) -> FailureBehavior {
+        match value {
+            "Fail" => FailureBehavior::Fail,
+            "Continue" => FailureBehavior::Continue,
+            _ => FailureBehavior::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FailureBehavior {
+    fn from(value: String) -> FailureBehavior {
+        match value.as_str() {
+            "Fail" => FailureBehavior::Fail,
+            "Continue" => FailureBehavior::Continue,
+            _ => FailureBehavior::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for FailureBehavior {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<FailureBehavior, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FailureBehavior {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FailureBehavior {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FailureBehavior {
+    fn deserialize<D>(deserializer: D) -> Result<FailureBehavior, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about a filter.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -1139,8 +1759,13 @@ pub struct Fleet {
     pub arn: Option<String>,
     /// <p>The time, in milliseconds since the epoch, when the fleet was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The Amazon Resource Name (ARN) of the last deployment job.</p>
     #[serde(rename = "lastDeploymentJob")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1148,11 +1773,16 @@ pub struct Fleet {
     /// <p>The status of the last fleet deployment.</p>
     #[serde(rename = "lastDeploymentStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_deployment_status: Option<String>,
+    pub last_deployment_status: Option<DeploymentStatus>,
     /// <p>The time of the last deployment.</p>
     #[serde(rename = "lastDeploymentTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_deployment_time: Option<f64>,
+    pub last_deployment_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the fleet.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1452,7 +2082,7 @@ pub struct ProgressDetail {
     /// <p><p>The current progress status.</p> <dl> <dt>Validating</dt> <dd> <p>Validating the deployment.</p> </dd> <dt>DownloadingExtracting</dt> <dd> <p>Downloading and extracting the bundle on the robot.</p> </dd> <dt>ExecutingPreLaunch</dt> <dd> <p>Executing pre-launch script(s) if provided.</p> </dd> <dt>Launching</dt> <dd> <p>Launching the robot application.</p> </dd> <dt>ExecutingPostLaunch</dt> <dd> <p>Executing post-launch script(s) if provided.</p> </dd> <dt>Finished</dt> <dd> <p>Deployment is complete.</p> </dd> </dl></p>
     #[serde(rename = "currentProgress")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub current_progress: Option<String>,
+    pub current_progress: Option<RobotDeploymentStep>,
     /// <p>Estimated amount of time in seconds remaining in the step. This currently only applies to the <code>Downloading/Extracting</code> step of the deployment. It is empty for other steps.</p>
     #[serde(rename = "estimatedTimeRemainingSeconds")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1497,13 +2127,101 @@ pub struct RenderingEngine {
     /// <p>The name of the rendering engine.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
+    pub name: Option<RenderingEngineType>,
     /// <p>The version of the rendering engine.</p>
     #[serde(rename = "version")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub version: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RenderingEngineType {
+    /// `OGRE`
+    Ogre,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RenderingEngineType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RenderingEngineType::Ogre => "OGRE",
+            RenderingEngineType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RenderingEngineType {
+    fn default() -> RenderingEngineType {
+        RenderingEngineType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RenderingEngineType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RenderingEngineType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RenderingEngineType {
+    fn from(value: &'a str) -> RenderingEngineType {
+        match value {
+            "OGRE" => RenderingEngineType::Ogre,
+            _ => RenderingEngineType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RenderingEngineType {
+    fn from(value: String) -> RenderingEngineType {
+        match value.as_str() {
+            "OGRE" => RenderingEngineType::Ogre,
+            _ => RenderingEngineType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RenderingEngineType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RenderingEngineType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RenderingEngineType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RenderingEngineType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RenderingEngineType {
+    fn deserialize<D>(deserializer: D) -> Result<RenderingEngineType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct RestartSimulationJobRequest {
@@ -1523,15 +2241,20 @@ pub struct Robot {
     /// <p>The architecture of the robot.</p>
     #[serde(rename = "architecture")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub architecture: Option<String>,
+    pub architecture: Option<Architecture>,
     /// <p>The Amazon Resource Name (ARN) of the robot.</p>
     #[serde(rename = "arn")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub arn: Option<String>,
     /// <p>The time, in milliseconds since the epoch, when the robot was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The Amazon Resource Name (ARN) of the fleet.</p>
     #[serde(rename = "fleetArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1546,8 +2269,13 @@ pub struct Robot {
     pub last_deployment_job: Option<String>,
     /// <p>The time of the last deployment.</p>
     #[serde(rename = "lastDeploymentTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_deployment_time: Option<f64>,
+    pub last_deployment_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the robot.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1555,7 +2283,7 @@ pub struct Robot {
     /// <p>The status of the robot.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<RobotStatus>,
 }
 
 /// <p>Application configuration information for a robot.</p>
@@ -1583,8 +2311,13 @@ pub struct RobotApplicationSummary {
     pub arn: Option<String>,
     /// <p>The time, in milliseconds since the epoch, when the robot application was last updated.</p>
     #[serde(rename = "lastUpdatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_at: Option<f64>,
+    pub last_updated_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the robot application.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1599,53 +2332,500 @@ pub struct RobotApplicationSummary {
     pub version: Option<String>,
 }
 
-/// <p>Information about a robot deployment.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct RobotDeployment {
-    /// <p>The robot deployment Amazon Resource Name (ARN).</p>
-    #[serde(rename = "arn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub arn: Option<String>,
-    /// <p>The time, in milliseconds since the epoch, when the deployment finished.</p>
-    #[serde(rename = "deploymentFinishTime")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub deployment_finish_time: Option<f64>,
-    /// <p>The time, in milliseconds since the epoch, when the deployment was started.</p>
-    #[serde(rename = "deploymentStartTime")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub deployment_start_time: Option<f64>,
-    /// <p>The robot deployment failure code.</p>
-    #[serde(rename = "failureCode")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub failure_code: Option<String>,
-    /// <p>A short description of the reason why the robot deployment failed.</p>
-    #[serde(rename = "failureReason")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub failure_reason: Option<String>,
-    /// <p>Information about how the deployment is progressing.</p>
-    #[serde(rename = "progressDetail")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub progress_detail: Option<ProgressDetail>,
-    /// <p>The status of the robot deployment.</p>
-    #[serde(rename = "status")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+/// <p>Information about a robot deployment.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct RobotDeployment {
+    /// <p>The robot deployment Amazon Resource Name (ARN).</p>
+    #[serde(rename = "arn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub arn: Option<String>,
+    /// <p>The time, in milliseconds since the epoch, when the deployment finished.</p>
+    #[serde(rename = "deploymentFinishTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deployment_finish_time: Option<::chrono::DateTime<::chrono::Utc>>,
+    /// <p>The time, in milliseconds since the epoch, when the deployment was started.</p>
+    #[serde(rename = "deploymentStartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deployment_start_time: Option<::chrono::DateTime<::chrono::Utc>>,
+    /// <p>The robot deployment failure code.</p>
+    #[serde(rename = "failureCode")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub failure_code: Option<DeploymentJobErrorCode>,
+    /// <p>A short description of the reason why the robot deployment failed.</p>
+    #[serde(rename = "failureReason")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub failure_reason: Option<String>,
+    /// <p>Information about how the deployment is progressing.</p>
+    #[serde(rename = "progressDetail")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub progress_detail: Option<ProgressDetail>,
+    /// <p>The status of the robot deployment.</p>
+    #[serde(rename = "status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<RobotStatus>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RobotDeploymentStep {
+    /// `Validating`
+    Validating,
+    /// `DownloadingExtracting`
+    DownloadingExtracting,
+    /// `ExecutingDownloadCondition`
+    ExecutingDownloadCondition,
+    /// `ExecutingPreLaunch`
+    ExecutingPreLaunch,
+    /// `Launching`
+    Launching,
+    /// `ExecutingPostLaunch`
+    ExecutingPostLaunch,
+    /// `Finished`
+    Finished,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RobotDeploymentStep {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RobotDeploymentStep::Validating => "Validating",
+            RobotDeploymentStep::DownloadingExtracting => "DownloadingExtracting",
+            RobotDeploymentStep::ExecutingDownloadCondition => "ExecutingDownloadCondition",
+            RobotDeploymentStep::ExecutingPreLaunch => "ExecutingPreLaunch",
+            RobotDeploymentStep::Launching => "Launching",
+            RobotDeploymentStep::ExecutingPostLaunch => "ExecutingPostLaunch",
+            RobotDeploymentStep::Finished => "Finished",
+            RobotDeploymentStep::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RobotDeploymentStep {
+    fn default() -> RobotDeploymentStep {
+        RobotDeploymentStep::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RobotDeploymentStep {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RobotDeploymentStep {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RobotDeploymentStep {
+    fn from(value: &'a str) -> RobotDeploymentStep {
+        match value {
+            "Validating" => RobotDeploymentStep::Validating,
+            "DownloadingExtracting" => RobotDeploymentStep::DownloadingExtracting,
+            "ExecutingDownloadCondition" => RobotDeploymentStep::ExecutingDownloadCondition,
+            "ExecutingPreLaunch" => RobotDeploymentStep::ExecutingPreLaunch,
+            "Launching" => RobotDeploymentStep::Launching,
+            "ExecutingPostLaunch" => RobotDeploymentStep::ExecutingPostLaunch,
+            "Finished" => RobotDeploymentStep::Finished,
+            _ => RobotDeploymentStep::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RobotDeploymentStep {
+    fn from(value: String) -> RobotDeploymentStep {
+        match value.as_str() {
+            "Validating" => RobotDeploymentStep::Validating,
+            "DownloadingExtracting" => RobotDeploymentStep::DownloadingExtracting,
+            "ExecutingDownloadCondition" => RobotDeploymentStep::ExecutingDownloadCondition,
+            "ExecutingPreLaunch" => RobotDeploymentStep::ExecutingPreLaunch,
+            "Launching" => RobotDeploymentStep::Launching,
+            "ExecutingPostLaunch" => RobotDeploymentStep::ExecutingPostLaunch,
+            "Finished" => RobotDeploymentStep::Finished,
+            _ => RobotDeploymentStep::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RobotDeploymentStep {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RobotDeploymentStep, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RobotDeploymentStep {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RobotDeploymentStep {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RobotDeploymentStep {
+    fn deserialize<D>(deserializer: D) -> Result<RobotDeploymentStep, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Information about a robot software suite.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RobotSoftwareSuite {
+    /// <p>The name of the robot software suite.</p>
+    #[serde(rename = "name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<RobotSoftwareSuiteType>,
+    /// <p>The version of the robot software suite.</p>
+    #[serde(rename = "version")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<RobotSoftwareSuiteVersionType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RobotSoftwareSuiteType {
+    /// `ROS`
+    Ros,
+    /// `ROS2`
+    Ros2,
+    /// `General`
+    General,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RobotSoftwareSuiteType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RobotSoftwareSuiteType::Ros => "ROS",
+            RobotSoftwareSuiteType::Ros2 => "ROS2",
+            RobotSoftwareSuiteType::General => "General",
+            RobotSoftwareSuiteType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RobotSoftwareSuiteType {
+    fn default() -> RobotSoftwareSuiteType {
+        RobotSoftwareSuiteType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RobotSoftwareSuiteType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RobotSoftwareSuiteType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RobotSoftwareSuiteType {
+    fn from(value: &'a str) -> RobotSoftwareSuiteType {
+        match value {
+            "ROS" => RobotSoftwareSuiteType::Ros,
+            "ROS2" => RobotSoftwareSuiteType::Ros2,
+            "General" => RobotSoftwareSuiteType::General,
+            _ => RobotSoftwareSuiteType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RobotSoftwareSuiteType {
+    fn from(value: String) -> RobotSoftwareSuiteType {
+        match value.as_str() {
+            "ROS" => RobotSoftwareSuiteType::Ros,
+            "ROS2" => RobotSoftwareSuiteType::Ros2,
+            "General" => RobotSoftwareSuiteType::General,
+            _ => RobotSoftwareSuiteType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RobotSoftwareSuiteType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RobotSoftwareSuiteType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RobotSoftwareSuiteType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RobotSoftwareSuiteType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RobotSoftwareSuiteType {
+    fn deserialize<D>(deserializer: D) -> Result<RobotSoftwareSuiteType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RobotSoftwareSuiteVersionType {
+    /// `Kinetic`
+    Kinetic,
+    /// `Melodic`
+    Melodic,
+    /// `Dashing`
+    Dashing,
+    /// `Foxy`
+    Foxy,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RobotSoftwareSuiteVersionType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RobotSoftwareSuiteVersionType::Kinetic => "Kinetic",
+            RobotSoftwareSuiteVersionType::Melodic => "Melodic",
+            RobotSoftwareSuiteVersionType::Dashing => "Dashing",
+            RobotSoftwareSuiteVersionType::Foxy => "Foxy",
+            RobotSoftwareSuiteVersionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RobotSoftwareSuiteVersionType {
+    fn default() -> RobotSoftwareSuiteVersionType {
+        RobotSoftwareSuiteVersionType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RobotSoftwareSuiteVersionType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RobotSoftwareSuiteVersionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RobotSoftwareSuiteVersionType {
+    fn from(value: &'a str) -> RobotSoftwareSuiteVersionType {
+        match value {
+            "Kinetic" => RobotSoftwareSuiteVersionType::Kinetic,
+            "Melodic" => RobotSoftwareSuiteVersionType::Melodic,
+            "Dashing" => RobotSoftwareSuiteVersionType::Dashing,
+            "Foxy" => RobotSoftwareSuiteVersionType::Foxy,
+            _ => RobotSoftwareSuiteVersionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RobotSoftwareSuiteVersionType {
+    fn from(value: String) -> RobotSoftwareSuiteVersionType {
+        match value.as_str() {
+            "Kinetic" => RobotSoftwareSuiteVersionType::Kinetic,
+            "Melodic" => RobotSoftwareSuiteVersionType::Melodic,
+            "Dashing" => RobotSoftwareSuiteVersionType::Dashing,
+            "Foxy" => RobotSoftwareSuiteVersionType::Foxy,
+            _ => RobotSoftwareSuiteVersionType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RobotSoftwareSuiteVersionType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RobotSoftwareSuiteVersionType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RobotSoftwareSuiteVersionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RobotSoftwareSuiteVersionType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RobotSoftwareSuiteVersionType {
+    fn deserialize<D>(deserializer: D) -> Result<RobotSoftwareSuiteVersionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RobotStatus {
+    /// `Available`
+    Available,
+    /// `Registered`
+    Registered,
+    /// `PendingNewDeployment`
+    PendingNewDeployment,
+    /// `Deploying`
+    Deploying,
+    /// `Failed`
+    Failed,
+    /// `InSync`
+    InSync,
+    /// `NoResponse`
+    NoResponse,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RobotStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RobotStatus::Available => "Available",
+            RobotStatus::Registered => "Registered",
+            RobotStatus::PendingNewDeployment => "PendingNewDeployment",
+            RobotStatus::Deploying => "Deploying",
+            RobotStatus::Failed => "Failed",
+            RobotStatus::InSync => "InSync",
+            RobotStatus::NoResponse => "NoResponse",
+            RobotStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RobotStatus {
+    fn default() -> RobotStatus {
+        RobotStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RobotStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RobotStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RobotStatus {
+    fn from(value: &'a str) -> RobotStatus {
+        match value {
+            "Available" => RobotStatus::Available,
+            "Registered" => RobotStatus::Registered,
+            "PendingNewDeployment" => RobotStatus::PendingNewDeployment,
+            "Deploying" => RobotStatus::Deploying,
+            "Failed" => RobotStatus::Failed,
+            "InSync" => RobotStatus::InSync,
+            "NoResponse" => RobotStatus::NoResponse,
+            _ => RobotStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RobotStatus {
+    fn from(value: String) -> RobotStatus {
+        match value.as_str() {
+            "Available" => RobotStatus::Available,
+            "Registered" => RobotStatus::Registered,
+            "PendingNewDeployment" => RobotStatus::PendingNewDeployment,
+            "Deploying" => RobotStatus::Deploying,
+            "Failed" => RobotStatus::Failed,
+            "InSync" => RobotStatus::InSync,
+            "NoResponse" => RobotStatus::NoResponse,
+            _ => RobotStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RobotStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RobotStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RobotStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
 }
 
-/// <p>Information about a robot software suite.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct RobotSoftwareSuite {
-    /// <p>The name of the robot software suite.</p>
-    #[serde(rename = "name")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
-    /// <p>The version of the robot software suite.</p>
-    #[serde(rename = "version")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub version: Option<String>,
+impl ::serde::Serialize for RobotStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for RobotStatus {
+    fn deserialize<D>(deserializer: D) -> Result<RobotStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about S3 keys.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1700,8 +2880,13 @@ pub struct SimulationApplicationSummary {
     pub arn: Option<String>,
     /// <p>The time, in milliseconds since the epoch, when the simulation application was last updated.</p>
     #[serde(rename = "lastUpdatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_at: Option<f64>,
+    pub last_updated_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the simulation application.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1739,11 +2924,11 @@ pub struct SimulationJob {
     /// <p><p>The failure behavior the simulation job.</p> <dl> <dt>Continue</dt> <dd> <p>Restart the simulation job in the same host instance.</p> </dd> <dt>Fail</dt> <dd> <p>Stop the simulation job and terminate the instance.</p> </dd> </dl></p>
     #[serde(rename = "failureBehavior")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub failure_behavior: Option<String>,
+    pub failure_behavior: Option<FailureBehavior>,
     /// <p>The failure code of the simulation job if it failed.</p>
     #[serde(rename = "failureCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub failure_code: Option<String>,
+    pub failure_code: Option<SimulationJobErrorCode>,
     /// <p>The reason why the simulation job failed.</p>
     #[serde(rename = "failureReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1754,12 +2939,22 @@ pub struct SimulationJob {
     pub iam_role: Option<String>,
     /// <p>The time, in milliseconds since the epoch, when the simulation job was last started.</p>
     #[serde(rename = "lastStartedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_started_at: Option<f64>,
+    pub last_started_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The time, in milliseconds since the epoch, when the simulation job was last updated.</p>
     #[serde(rename = "lastUpdatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_at: Option<f64>,
+    pub last_updated_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The logging configuration.</p>
     #[serde(rename = "loggingConfig")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1795,7 +2990,7 @@ pub struct SimulationJob {
     /// <p>Status of the simulation job.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<SimulationJobStatus>,
     /// <p>A map that contains tag keys and tag values that are attached to the simulation job.</p>
     #[serde(rename = "tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1806,6 +3001,437 @@ pub struct SimulationJob {
     pub vpc_config: Option<VPCConfigResponse>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SimulationJobErrorCode {
+    /// `InternalServiceError`
+    InternalServiceError,
+    /// `RobotApplicationCrash`
+    RobotApplicationCrash,
+    /// `SimulationApplicationCrash`
+    SimulationApplicationCrash,
+    /// `RobotApplicationHealthCheckFailure`
+    RobotApplicationHealthCheckFailure,
+    /// `SimulationApplicationHealthCheckFailure`
+    SimulationApplicationHealthCheckFailure,
+    /// `BadPermissionsRobotApplication`
+    BadPermissionsRobotApplication,
+    /// `BadPermissionsSimulationApplication`
+    BadPermissionsSimulationApplication,
+    /// `BadPermissionsS3Object`
+    BadPermissionsS3Object,
+    /// `BadPermissionsS3Output`
+    BadPermissionsS3Output,
+    /// `BadPermissionsCloudwatchLogs`
+    BadPermissionsCloudwatchLogs,
+    /// `SubnetIpLimitExceeded`
+    SubnetIpLimitExceeded,
+    /// `ENILimitExceeded`
+    ENILimitExceeded,
+    /// `BadPermissionsUserCredentials`
+    BadPermissionsUserCredentials,
+    /// `InvalidBundleRobotApplication`
+    InvalidBundleRobotApplication,
+    /// `InvalidBundleSimulationApplication`
+    InvalidBundleSimulationApplication,
+    /// `InvalidS3Resource`
+    InvalidS3Resource,
+    /// `ThrottlingError`
+    ThrottlingError,
+    /// `LimitExceeded`
+    LimitExceeded,
+    /// `MismatchedEtag`
+    MismatchedEtag,
+    /// `RobotApplicationVersionMismatchedEtag`
+    RobotApplicationVersionMismatchedEtag,
+    /// `SimulationApplicationVersionMismatchedEtag`
+    SimulationApplicationVersionMismatchedEtag,
+    /// `ResourceNotFound`
+    ResourceNotFound,
+    /// `RequestThrottled`
+    RequestThrottled,
+    /// `BatchTimedOut`
+    BatchTimedOut,
+    /// `BatchCanceled`
+    BatchCanceled,
+    /// `InvalidInput`
+    InvalidInput,
+    /// `WrongRegionS3Bucket`
+    WrongRegionS3Bucket,
+    /// `WrongRegionS3Output`
+    WrongRegionS3Output,
+    /// `WrongRegionRobotApplication`
+    WrongRegionRobotApplication,
+    /// `WrongRegionSimulationApplication`
+    WrongRegionSimulationApplication,
+    /// `UploadContentMismatchError`
+    UploadContentMismatchError,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SimulationJobErrorCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SimulationJobErrorCode::InternalServiceError => "InternalServiceError",
+            SimulationJobErrorCode::RobotApplicationCrash => "RobotApplicationCrash",
+            SimulationJobErrorCode::SimulationApplicationCrash => "SimulationApplicationCrash",
+            SimulationJobErrorCode::RobotApplicationHealthCheckFailure => {
+                "RobotApplicationHealthCheckFailure"
+            }
+            SimulationJobErrorCode::SimulationApplicationHealthCheckFailure => {
+                "SimulationApplicationHealthCheckFailure"
+            }
+            SimulationJobErrorCode::BadPermissionsRobotApplication => {
+                "BadPermissionsRobotApplication"
+            }
+            SimulationJobErrorCode::BadPermissionsSimulationApplication => {
+                "BadPermissionsSimulationApplication"
+            }
+            SimulationJobErrorCode::BadPermissionsS3Object => "BadPermissionsS3Object",
+            SimulationJobErrorCode::BadPermissionsS3Output => "BadPermissionsS3Output",
+            SimulationJobErrorCode::BadPermissionsCloudwatchLogs => "BadPermissionsCloudwatchLogs",
+            SimulationJobErrorCode::SubnetIpLimitExceeded => "SubnetIpLimitExceeded",
+            SimulationJobErrorCode::ENILimitExceeded => "ENILimitExceeded",
+            SimulationJobErrorCode::BadPermissionsUserCredentials => {
+                "BadPermissionsUserCredentials"
+            }
+            SimulationJobErrorCode::InvalidBundleRobotApplication => {
+                "InvalidBundleRobotApplication"
+            }
+            SimulationJobErrorCode::InvalidBundleSimulationApplication => {
+                "InvalidBundleSimulationApplication"
+            }
+            SimulationJobErrorCode::InvalidS3Resource => "InvalidS3Resource",
+            SimulationJobErrorCode::ThrottlingError => "ThrottlingError",
+            SimulationJobErrorCode::LimitExceeded => "LimitExceeded",
+            SimulationJobErrorCode::MismatchedEtag => "MismatchedEtag",
+            SimulationJobErrorCode::RobotApplicationVersionMismatchedEtag => {
+                "RobotApplicationVersionMismatchedEtag"
+            }
+            SimulationJobErrorCode::SimulationApplicationVersionMismatchedEtag => {
+                "SimulationApplicationVersionMismatchedEtag"
+            }
+            SimulationJobErrorCode::ResourceNotFound => "ResourceNotFound",
+            SimulationJobErrorCode::RequestThrottled => "RequestThrottled",
+            SimulationJobErrorCode::BatchTimedOut => "BatchTimedOut",
+            SimulationJobErrorCode::BatchCanceled => "BatchCanceled",
+            SimulationJobErrorCode::InvalidInput => "InvalidInput",
+            SimulationJobErrorCode::WrongRegionS3Bucket => "WrongRegionS3Bucket",
+            SimulationJobErrorCode::WrongRegionS3Output => "WrongRegionS3Output",
+            SimulationJobErrorCode::WrongRegionRobotApplication => "WrongRegionRobotApplication",
+            SimulationJobErrorCode::WrongRegionSimulationApplication => {
+                "WrongRegionSimulationApplication"
+            }
+            SimulationJobErrorCode::UploadContentMismatchError => "UploadContentMismatchError",
+            SimulationJobErrorCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SimulationJobErrorCode {
+    fn default() -> SimulationJobErrorCode {
+        SimulationJobErrorCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SimulationJobErrorCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SimulationJobErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SimulationJobErrorCode {
+    fn from(value: &'a str) -> SimulationJobErrorCode {
+        match value {
+            "InternalServiceError" => SimulationJobErrorCode::InternalServiceError,
+            "RobotApplicationCrash" => SimulationJobErrorCode::RobotApplicationCrash,
+            "SimulationApplicationCrash" => SimulationJobErrorCode::SimulationApplicationCrash,
+            "RobotApplicationHealthCheckFailure" => {
+                SimulationJobErrorCode::RobotApplicationHealthCheckFailure
+            }
+            "SimulationApplicationHealthCheckFailure" => {
+                SimulationJobErrorCode::SimulationApplicationHealthCheckFailure
+            }
+            "BadPermissionsRobotApplication" => {
+                SimulationJobErrorCode::BadPermissionsRobotApplication
+            }
+            "BadPermissionsSimulationApplication" => {
+                SimulationJobErrorCode::BadPermissionsSimulationApplication
+            }
+            "BadPermissionsS3Object" => SimulationJobErrorCode::BadPermissionsS3Object,
+            "BadPermissionsS3Output" => SimulationJobErrorCode::BadPermissionsS3Output,
+            "BadPermissionsCloudwatchLogs" => SimulationJobErrorCode::BadPermissionsCloudwatchLogs,
+            "SubnetIpLimitExceeded" => SimulationJobErrorCode::SubnetIpLimitExceeded,
+            "ENILimitExceeded" => SimulationJobErrorCode::ENILimitExceeded,
+            "BadPermissionsUserCredentials" => {
+                SimulationJobErrorCode::BadPermissionsUserCredentials
+            }
+            "InvalidBundleRobotApplication" => {
+                SimulationJobErrorCode::InvalidBundleRobotApplication
+            }
+            "InvalidBundleSimulationApplication" => {
+                SimulationJobErrorCode::InvalidBundleSimulationApplication
+            }
+            "InvalidS3Resource" => SimulationJobErrorCode::InvalidS3Resource,
+            "ThrottlingError" => SimulationJobErrorCode::ThrottlingError,
+            "LimitExceeded" => SimulationJobErrorCode::LimitExceeded,
+            "MismatchedEtag" => SimulationJobErrorCode::MismatchedEtag,
+            "RobotApplicationVersionMismatchedEtag" => {
+                SimulationJobErrorCode::RobotApplicationVersionMismatchedEtag
+            }
+            "SimulationApplicationVersionMismatchedEtag" => {
+                SimulationJobErrorCode::SimulationApplicationVersionMismatchedEtag
+            }
+            "ResourceNotFound" => SimulationJobErrorCode::ResourceNotFound,
+            "RequestThrottled" => SimulationJobErrorCode::RequestThrottled,
+            "BatchTimedOut" => SimulationJobErrorCode::BatchTimedOut,
+            "BatchCanceled" => SimulationJobErrorCode::BatchCanceled,
+            "InvalidInput" => SimulationJobErrorCode::InvalidInput,
+            "WrongRegionS3Bucket" => SimulationJobErrorCode::WrongRegionS3Bucket,
+            "WrongRegionS3Output" => SimulationJobErrorCode::WrongRegionS3Output,
+            "WrongRegionRobotApplication" => SimulationJobErrorCode::WrongRegionRobotApplication,
+            "WrongRegionSimulationApplication" => {
+                SimulationJobErrorCode::WrongRegionSimulationApplication
+            }
+            "UploadContentMismatchError" => SimulationJobErrorCode::UploadContentMismatchError,
+            _ => SimulationJobErrorCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SimulationJobErrorCode {
+    fn from(value: String) -> SimulationJobErrorCode {
+        match value.as_str() {
+            "InternalServiceError" => SimulationJobErrorCode::InternalServiceError,
+            "RobotApplicationCrash" => SimulationJobErrorCode::RobotApplicationCrash,
+            "SimulationApplicationCrash" => SimulationJobErrorCode::SimulationApplicationCrash,
+            "RobotApplicationHealthCheckFailure" => {
+                SimulationJobErrorCode::RobotApplicationHealthCheckFailure
+            }
+            "SimulationApplicationHealthCheckFailure" => {
+                SimulationJobErrorCode::SimulationApplicationHealthCheckFailure
+            }
+            "BadPermissionsRobotApplication" => {
+                SimulationJobErrorCode::BadPermissionsRobotApplication
+            }
+            "BadPermissionsSimulationApplication" => {
+                SimulationJobErrorCode::BadPermissionsSimulationApplication
+            }
+            "BadPermissionsS3Object" => SimulationJobErrorCode::BadPermissionsS3Object,
+            "BadPermissionsS3Output" => SimulationJobErrorCode::BadPermissionsS3Output,
+            "BadPermissionsCloudwatchLogs" => SimulationJobErrorCode::BadPermissionsCloudwatchLogs,
+            "SubnetIpLimitExceeded" => SimulationJobErrorCode::SubnetIpLimitExceeded,
+            "ENILimitExceeded" => SimulationJobErrorCode::ENILimitExceeded,
+            "BadPermissionsUserCredentials" => {
+                SimulationJobErrorCode::BadPermissionsUserCredentials
+            }
+            "InvalidBundleRobotApplication" => {
+                SimulationJobErrorCode::InvalidBundleRobotApplication
+            }
+            "InvalidBundleSimulationApplication" => {
+                SimulationJobErrorCode::InvalidBundleSimulationApplication
+            }
+            "InvalidS3Resource" => SimulationJobErrorCode::InvalidS3Resource,
+            "ThrottlingError" => SimulationJobErrorCode::ThrottlingError,
+            "LimitExceeded" => SimulationJobErrorCode::LimitExceeded,
+            "MismatchedEtag" => SimulationJobErrorCode::MismatchedEtag,
+            "RobotApplicationVersionMismatchedEtag" => {
+                SimulationJobErrorCode::RobotApplicationVersionMismatchedEtag
+            }
+            "SimulationApplicationVersionMismatchedEtag" => {
+                SimulationJobErrorCode::SimulationApplicationVersionMismatchedEtag
+            }
+            "ResourceNotFound" => SimulationJobErrorCode::ResourceNotFound,
+            "RequestThrottled" => SimulationJobErrorCode::RequestThrottled,
+            "BatchTimedOut" => SimulationJobErrorCode::BatchTimedOut,
+            "BatchCanceled" => SimulationJobErrorCode::BatchCanceled,
+            "InvalidInput" => SimulationJobErrorCode::InvalidInput,
+            "WrongRegionS3Bucket" => SimulationJobErrorCode::WrongRegionS3Bucket,
+            "WrongRegionS3Output" => SimulationJobErrorCode::WrongRegionS3Output,
+            "WrongRegionRobotApplication" => SimulationJobErrorCode::WrongRegionRobotApplication,
+            "WrongRegionSimulationApplication" => {
+                SimulationJobErrorCode::WrongRegionSimulationApplication
+            }
+            "UploadContentMismatchError" => SimulationJobErrorCode::UploadContentMismatchError,
+            _ => SimulationJobErrorCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SimulationJobErrorCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SimulationJobErrorCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SimulationJobErrorCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SimulationJobErrorCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SimulationJobErrorCode {
+    fn deserialize<D>(deserializer: D) -> Result<SimulationJobErrorCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SimulationJobStatus {
+    /// `Pending`
+    Pending,
+    /// `Preparing`
+    Preparing,
+    /// `Running`
+    Running,
+    /// `Restarting`
+    Restarting,
+    /// `Completed`
+    Completed,
+    /// `Failed`
+    Failed,
+    /// `RunningFailed`
+    RunningFailed,
+    /// `Terminating`
+    Terminating,
+    /// `Terminated`
+    Terminated,
+    /// `Canceled`
+    Canceled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SimulationJobStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SimulationJobStatus::Pending => "Pending",
+            SimulationJobStatus::Preparing => "Preparing",
+            SimulationJobStatus::Running => "Running",
+            SimulationJobStatus::Restarting => "Restarting",
+            SimulationJobStatus::Completed => "Completed",
+            SimulationJobStatus::Failed => "Failed",
+            SimulationJobStatus::RunningFailed => "RunningFailed",
+            SimulationJobStatus::Terminating => "Terminating",
+            SimulationJobStatus::Terminated => "Terminated",
+            SimulationJobStatus::Canceled => "Canceled",
+            SimulationJobStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SimulationJobStatus {
+    fn default() -> SimulationJobStatus {
+        SimulationJobStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SimulationJobStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SimulationJobStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SimulationJobStatus {
+    fn from(value: &'a str) -> SimulationJobStatus {
+        match value {
+            "Pending" => SimulationJobStatus::Pending,
+            "Preparing" => SimulationJobStatus::Preparing,
+            "Running" => SimulationJobStatus::Running,
+            "Restarting" => SimulationJobStatus::Restarting,
+            "Completed" => SimulationJobStatus::Completed,
+            "Failed" => SimulationJobStatus::Failed,
+            "RunningFailed" => SimulationJobStatus::RunningFailed,
+            "Terminating" => SimulationJobStatus::Terminating,
+            "Terminated" => SimulationJobStatus::Terminated,
+            "Canceled" => SimulationJobStatus::Canceled,
+            _ => SimulationJobStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SimulationJobStatus {
+    fn from(value: String) -> SimulationJobStatus {
+        match value.as_str() {
+            "Pending" => SimulationJobStatus::Pending,
+            "Preparing" => SimulationJobStatus::Preparing,
+            "Running" => SimulationJobStatus::Running,
+            "Restarting" => SimulationJobStatus::Restarting,
+            "Completed" => SimulationJobStatus::Completed,
+            "Failed" => SimulationJobStatus::Failed,
+            "RunningFailed" => SimulationJobStatus::RunningFailed,
+            "Terminating" => SimulationJobStatus::Terminating,
+            "Terminated" => SimulationJobStatus::Terminated,
+            "Canceled" => SimulationJobStatus::Canceled,
+            _ => SimulationJobStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SimulationJobStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SimulationJobStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SimulationJobStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SimulationJobStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SimulationJobStatus {
+    fn deserialize<D>(deserializer: D) -> Result<SimulationJobStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Summary information for a simulation job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1820,8 +3446,13 @@ pub struct SimulationJobSummary {
     pub data_source_names: Option<Vec<String>>,
     /// <p>The time, in milliseconds since the epoch, when the simulation job was last updated.</p>
     #[serde(rename = "lastUpdatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_at: Option<f64>,
+    pub last_updated_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the simulation job.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1837,7 +3468,7 @@ pub struct SimulationJobSummary {
     /// <p>The status of the simulation job.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<SimulationJobStatus>,
 }
 
 /// <p>Information about a simulation software suite.</p>
@@ -1846,13 +3477,111 @@ pub struct SimulationSoftwareSuite {
     /// <p>The name of the simulation software suite.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
+    pub name: Option<SimulationSoftwareSuiteType>,
     /// <p>The version of the simulation software suite.</p>
     #[serde(rename = "version")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub version: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SimulationSoftwareSuiteType {
+    /// `Gazebo`
+    Gazebo,
+    /// `RosbagPlay`
+    RosbagPlay,
+    /// `SimulationRuntime`
+    SimulationRuntime,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SimulationSoftwareSuiteType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SimulationSoftwareSuiteType::Gazebo => "Gazebo",
+            SimulationSoftwareSuiteType::RosbagPlay => "RosbagPlay",
+            SimulationSoftwareSuiteType::SimulationRuntime => "SimulationRuntime",
+            SimulationSoftwareSuiteType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SimulationSoftwareSuiteType {
+    fn default() -> SimulationSoftwareSuiteType {
+        SimulationSoftwareSuiteType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SimulationSoftwareSuiteType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SimulationSoftwareSuiteType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SimulationSoftwareSuiteType {
+    fn from(value: &'a str) -> SimulationSoftwareSuiteType {
+        match value {
+            "Gazebo" => SimulationSoftwareSuiteType::Gazebo,
+            "RosbagPlay" => SimulationSoftwareSuiteType::RosbagPlay,
+            "SimulationRuntime" => SimulationSoftwareSuiteType::SimulationRuntime,
+            _ => SimulationSoftwareSuiteType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SimulationSoftwareSuiteType {
+    fn from(value: String) -> SimulationSoftwareSuiteType {
+        match value.as_str() {
+            "Gazebo" => SimulationSoftwareSuiteType::Gazebo,
+            "RosbagPlay" => SimulationSoftwareSuiteType::RosbagPlay,
+            "SimulationRuntime" => SimulationSoftwareSuiteType::SimulationRuntime,
+            _ => SimulationSoftwareSuiteType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SimulationSoftwareSuiteType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SimulationSoftwareSuiteType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SimulationSoftwareSuiteType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SimulationSoftwareSuiteType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SimulationSoftwareSuiteType {
+    fn deserialize<D>(deserializer: D) -> Result<SimulationSoftwareSuiteType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about a source.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1860,7 +3589,7 @@ pub struct Source {
     /// <p>The taget processor architecture for the application.</p>
     #[serde(rename = "architecture")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub architecture: Option<String>,
+    pub architecture: Option<Architecture>,
     /// <p>A hash of the object specified by <code>s3Bucket</code> and <code>s3Key</code>.</p>
     #[serde(rename = "etag")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1882,7 +3611,7 @@ pub struct SourceConfig {
     /// <p>The target processor architecture for the application.</p>
     #[serde(rename = "architecture")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub architecture: Option<String>,
+    pub architecture: Option<Architecture>,
     /// <p>The Amazon S3 bucket name.</p>
     #[serde(rename = "s3Bucket")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1913,8 +3642,13 @@ pub struct SyncDeploymentJobResponse {
     pub arn: Option<String>,
     /// <p>The time, in milliseconds since the epoch, when the fleet was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Information about the deployment application configurations.</p>
     #[serde(rename = "deploymentApplicationConfigs")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1926,7 +3660,7 @@ pub struct SyncDeploymentJobResponse {
     /// <p><p>The failure code if the job fails:</p> <dl> <dt>InternalServiceError</dt> <dd> <p>Internal service error.</p> </dd> <dt>RobotApplicationCrash</dt> <dd> <p>Robot application exited abnormally.</p> </dd> <dt>SimulationApplicationCrash</dt> <dd> <p> Simulation application exited abnormally.</p> </dd> <dt>BadPermissionsRobotApplication</dt> <dd> <p>Robot application bundle could not be downloaded.</p> </dd> <dt>BadPermissionsSimulationApplication</dt> <dd> <p>Simulation application bundle could not be downloaded.</p> </dd> <dt>BadPermissionsS3Output</dt> <dd> <p>Unable to publish outputs to customer-provided S3 bucket.</p> </dd> <dt>BadPermissionsCloudwatchLogs</dt> <dd> <p>Unable to publish logs to customer-provided CloudWatch Logs resource.</p> </dd> <dt>SubnetIpLimitExceeded</dt> <dd> <p>Subnet IP limit exceeded.</p> </dd> <dt>ENILimitExceeded</dt> <dd> <p>ENI limit exceeded.</p> </dd> <dt>BadPermissionsUserCredentials</dt> <dd> <p>Unable to use the Role provided.</p> </dd> <dt>InvalidBundleRobotApplication</dt> <dd> <p>Robot bundle cannot be extracted (invalid format, bundling error, or other issue).</p> </dd> <dt>InvalidBundleSimulationApplication</dt> <dd> <p>Simulation bundle cannot be extracted (invalid format, bundling error, or other issue).</p> </dd> <dt>RobotApplicationVersionMismatchedEtag</dt> <dd> <p>Etag for RobotApplication does not match value during version creation.</p> </dd> <dt>SimulationApplicationVersionMismatchedEtag</dt> <dd> <p>Etag for SimulationApplication does not match value during version creation.</p> </dd> </dl></p>
     #[serde(rename = "failureCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub failure_code: Option<String>,
+    pub failure_code: Option<DeploymentJobErrorCode>,
     /// <p>The failure reason if the job fails.</p>
     #[serde(rename = "failureReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1938,7 +3672,7 @@ pub struct SyncDeploymentJobResponse {
     /// <p>The status of the synchronization job.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<DeploymentStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -1998,8 +3732,13 @@ pub struct UpdateRobotApplicationResponse {
     pub arn: Option<String>,
     /// <p>The time, in milliseconds since the epoch, when the robot application was last updated.</p>
     #[serde(rename = "lastUpdatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_at: Option<f64>,
+    pub last_updated_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the robot application.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2056,8 +3795,13 @@ pub struct UpdateSimulationApplicationResponse {
     pub arn: Option<String>,
     /// <p>The time, in milliseconds since the epoch, when the simulation application was last updated.</p>
     #[serde(rename = "lastUpdatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_at: Option<f64>,
+    pub last_updated_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the simulation application.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4369,11 +6113,12 @@ pub struct RobomakerClient {
 impl RobomakerClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> RobomakerClient {
         RobomakerClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "RoboMaker"),
         }
     }
 
@@ -4408,6 +6153,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/batchDescribeSimulationJob";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("BatchDescribeSimulationJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4438,6 +6184,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/cancelDeploymentJob";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("CancelDeploymentJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4468,6 +6215,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/cancelSimulationJob";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("CancelSimulationJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4498,6 +6246,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/createDeploymentJob";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("CreateDeploymentJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4528,6 +6277,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/createFleet";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("CreateFleet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4558,6 +6308,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/createRobot";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("CreateRobot");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4588,6 +6339,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/createRobotApplication";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("CreateRobotApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4621,6 +6373,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/createRobotApplicationVersion";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("CreateRobotApplicationVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4652,6 +6405,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/createSimulationApplication";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("CreateSimulationApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4685,6 +6439,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/createSimulationApplicationVersion";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("CreateSimulationApplicationVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4717,6 +6472,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/createSimulationJob";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("CreateSimulationJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4747,6 +6503,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/deleteFleet";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("DeleteFleet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4777,6 +6534,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/deleteRobot";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("DeleteRobot");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4807,6 +6565,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/deleteRobotApplication";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("DeleteRobotApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4838,6 +6597,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/deleteSimulationApplication";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("DeleteSimulationApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4868,6 +6628,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/deregisterRobot";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("DeregisterRobot");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4898,6 +6659,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/describeDeploymentJob";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("DescribeDeploymentJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4928,6 +6690,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/describeFleet";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("DescribeFleet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4958,6 +6721,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/describeRobot";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("DescribeRobot");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4988,6 +6752,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/describeRobotApplication";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("DescribeRobotApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5021,6 +6786,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/describeSimulationApplication";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("DescribeSimulationApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5051,6 +6817,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/describeSimulationJob";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("DescribeSimulationJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5081,6 +6848,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/listDeploymentJobs";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("ListDeploymentJobs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5111,6 +6879,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/listFleets";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("ListFleets");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5141,6 +6910,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/listRobotApplications";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("ListRobotApplications");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5171,6 +6941,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/listRobots";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("ListRobots");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5202,6 +6973,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/listSimulationApplications";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("ListSimulationApplications");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5232,6 +7004,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/listSimulationJobs";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("ListSimulationJobs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5262,6 +7035,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "robomaker", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5289,6 +7063,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/registerRobot";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("RegisterRobot");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5319,6 +7094,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/restartSimulationJob";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("RestartSimulationJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5349,6 +7125,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/syncDeploymentJob";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("SyncDeploymentJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5379,6 +7156,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5409,6 +7187,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "robomaker", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5442,6 +7221,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/updateRobotApplication";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("UpdateRobotApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5473,6 +7253,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/updateSimulationApplication";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("UpdateSimulationApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5495,3 +7276,207 @@ impl Robomaker for RobomakerClient {
         }
     }
 }
+impl RobomakerClient {
+    /// Auto-paginating version of `list_deployment_jobs`, streaming every page of results.
+    pub fn list_deployment_jobs_pages(
+        &self,
+        input: ListDeploymentJobsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListDeploymentJobsResponse, ListDeploymentJobsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RobomakerClient, input: ListDeploymentJobsRequest| async move {
+                client.list_deployment_jobs(input).await
+            },
+            |input: &ListDeploymentJobsRequest, output: &ListDeploymentJobsResponse| {
+                Some(ListDeploymentJobsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_deployment_jobs`, streaming the `deploymentJobs` of every page of results.
+    pub fn list_deployment_jobs_items(
+        &self,
+        input: ListDeploymentJobsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DeploymentJob, ListDeploymentJobsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_deployment_jobs_pages(input),
+            |output: ListDeploymentJobsResponse| output.deployment_jobs.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_fleets`, streaming every page of results.
+    pub fn list_fleets_pages(
+        &self,
+        input: ListFleetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListFleetsResponse, ListFleetsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RobomakerClient, input: ListFleetsRequest| async move {
+                client.list_fleets(input).await
+            },
+            |input: &ListFleetsRequest, output: &ListFleetsResponse| {
+                Some(ListFleetsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_fleets`, streaming the `fleetDetails` of every page of results.
+    pub fn list_fleets_items(
+        &self,
+        input: ListFleetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Fleet, ListFleetsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_fleets_pages(input),
+            |output: ListFleetsResponse| output.fleet_details.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_robot_applications`, streaming every page of results.
+    pub fn list_robot_applications_pages(
+        &self,
+        input: ListRobotApplicationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListRobotApplicationsResponse,
+        ListRobotApplicationsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RobomakerClient, input: ListRobotApplicationsRequest| async move {
+                client.list_robot_applications(input).await
+            },
+            |input: &ListRobotApplicationsRequest, output: &ListRobotApplicationsResponse| {
+                Some(ListRobotApplicationsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_robot_applications`, streaming the `robotApplicationSummaries` of every page of results.
+    pub fn list_robot_applications_items(
+        &self,
+        input: ListRobotApplicationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<RobotApplicationSummary, ListRobotApplicationsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_robot_applications_pages(input),
+            |output: ListRobotApplicationsResponse| {
+                output.robot_application_summaries.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_robots`, streaming every page of results.
+    pub fn list_robots_pages(
+        &self,
+        input: ListRobotsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListRobotsResponse, ListRobotsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RobomakerClient, input: ListRobotsRequest| async move {
+                client.list_robots(input).await
+            },
+            |input: &ListRobotsRequest, output: &ListRobotsResponse| {
+                Some(ListRobotsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_robots`, streaming the `robots` of every page of results.
+    pub fn list_robots_items(
+        &self,
+        input: ListRobotsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Robot, ListRobotsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_robots_pages(input),
+            |output: ListRobotsResponse| output.robots.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_simulation_applications`, streaming every page of results.
+    pub fn list_simulation_applications_pages(
+        &self,
+        input: ListSimulationApplicationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListSimulationApplicationsResponse,
+        ListSimulationApplicationsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RobomakerClient, input: ListSimulationApplicationsRequest| async move {
+                client.list_simulation_applications(input).await
+            },
+            |input: &ListSimulationApplicationsRequest,
+             output: &ListSimulationApplicationsResponse| {
+                Some(ListSimulationApplicationsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_simulation_applications`, streaming the `simulationApplicationSummaries` of every page of results.
+    pub fn list_simulation_applications_items(
+        &self,
+        input: ListSimulationApplicationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        SimulationApplicationSummary,
+        ListSimulationApplicationsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_simulation_applications_pages(input),
+            |output: ListSimulationApplicationsResponse| {
+                output.simulation_application_summaries.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_simulation_jobs`, streaming every page of results.
+    pub fn list_simulation_jobs_pages(
+        &self,
+        input: ListSimulationJobsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListSimulationJobsResponse, ListSimulationJobsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RobomakerClient, input: ListSimulationJobsRequest| async move {
+                client.list_simulation_jobs(input).await
+            },
+            |input: &ListSimulationJobsRequest, output: &ListSimulationJobsResponse| {
+                Some(ListSimulationJobsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_simulation_jobs`, streaming the `simulationJobSummaries` of every page of results.
+    pub fn list_simulation_jobs_items(
+        &self,
+        input: ListSimulationJobsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<SimulationJobSummary, ListSimulationJobsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_simulation_jobs_pages(input),
+            |output: ListSimulationJobsResponse| output.simulation_job_summaries,
+        )
+    }
+}
@@ -1,6 +1,7 @@
 use crate::custom::util::quote_txt_record;
 use crate::generated::{
-    ListResourceRecordSetsError, ListResourceRecordSetsRequest, Route53, Route53Client,
+    ListHostedZonesRequest, ListResourceRecordSetsError, ListResourceRecordSetsRequest, Route53,
+    Route53Client,
 };
 use rusoto_core::{Region, RusotoError};
 
@@ -44,3 +45,19 @@ fn test_quoter() {
     assert_eq!(quote_txt_record("\"foo"), "\"foo\"");
     assert_eq!(quote_txt_record("foo\""), "\"foo\"");
 }
+
+#[tokio::test]
+async fn should_send_requests_to_the_global_endpoint() {
+    let mock = MockRequestDispatcher::with_status(200)
+        .with_body(r#"<?xml version="1.0"?><ListHostedZonesResponse><HostedZones/><IsTruncated>false</IsTruncated><MaxItems>100</MaxItems></ListHostedZonesResponse>"#)
+        .with_request_checker(|request| {
+            assert_eq!(request.hostname(), "route53.amazonaws.com");
+            assert_eq!(request.region, Region::UsEast1);
+        });
+
+    let client = Route53Client::new_with(mock, MockCredentialsProvider, Region::EuWest1);
+    client
+        .list_hosted_zones(ListHostedZonesRequest::default())
+        .await
+        .unwrap();
+}
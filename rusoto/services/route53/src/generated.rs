@@ -39,7 +39,7 @@ use xml::EventWriter;
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct AccountLimit {
     /// <p><p>The limit that you requested. Valid values include the following:</p> <ul> <li> <p> <b>MAX<em>HEALTH</em>CHECKS<em>BY</em>OWNER</b>: The maximum number of health checks that you can create using the current account.</p> </li> <li> <p> <b>MAX<em>HOSTED</em>ZONES<em>BY</em>OWNER</b>: The maximum number of hosted zones that you can create using the current account.</p> </li> <li> <p> <b>MAX<em>REUSABLE</em>DELEGATION<em>SETS</em>BY<em>OWNER</b>: The maximum number of reusable delegation sets that you can create using the current account.</p> </li> <li> <p> <b>MAX</em>TRAFFIC<em>POLICIES</em>BY<em>OWNER</b>: The maximum number of traffic policies that you can create using the current account.</p> </li> <li> <p> <b>MAX</em>TRAFFIC<em>POLICY</em>INSTANCES<em>BY</em>OWNER</b>: The maximum number of traffic policy instances that you can create using the current account. (Traffic policy instances are referred to as traffic flow policy records in the Amazon Route 53 console.)</p> </li> </ul></p>
-    pub type_: String,
+    pub type_: AccountLimitType,
     /// <p>The current value for the limit that is specified by <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_AccountLimit.html#Route53-Type-AccountLimit-Type">Type</a>.</p>
     pub value: i64,
 }
@@ -65,12 +65,137 @@ impl AccountLimitDeserializer {
         })
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AccountLimitType {
+    /// `MAX_HEALTH_CHECKS_BY_OWNER`
+    MaxHealthChecksByOwner,
+    /// `MAX_HOSTED_ZONES_BY_OWNER`
+    MaxHostedZonesByOwner,
+    /// `MAX_TRAFFIC_POLICY_INSTANCES_BY_OWNER`
+    MaxTrafficPolicyInstancesByOwner,
+    /// `MAX_REUSABLE_DELEGATION_SETS_BY_OWNER`
+    MaxReusableDelegationSetsByOwner,
+    /// `MAX_TRAFFIC_POLICIES_BY_OWNER`
+    MaxTrafficPoliciesByOwner,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AccountLimitType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AccountLimitType::MaxHealthChecksByOwner => "MAX_HEALTH_CHECKS_BY_OWNER",
+            AccountLimitType::MaxHostedZonesByOwner => "MAX_HOSTED_ZONES_BY_OWNER",
+            AccountLimitType::MaxTrafficPolicyInstancesByOwner => {
+                "MAX_TRAFFIC_POLICY_INSTANCES_BY_OWNER"
+            }
+            AccountLimitType::MaxReusableDelegationSetsByOwner => {
+                "MAX_REUSABLE_DELEGATION_SETS_BY_OWNER"
+            }
+            AccountLimitType::MaxTrafficPoliciesByOwner => "MAX_TRAFFIC_POLICIES_BY_OWNER",
+            AccountLimitType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AccountLimitType {
+    fn default() -> AccountLimitType {
+        AccountLimitType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AccountLimitType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AccountLimitType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AccountLimitType {
+    fn from(value: &'a str) -> AccountLimitType {
+        match value {
+            "MAX_HEALTH_CHECKS_BY_OWNER" => AccountLimitType::MaxHealthChecksByOwner,
+            "MAX_HOSTED_ZONES_BY_OWNER" => AccountLimitType::MaxHostedZonesByOwner,
+            "MAX_TRAFFIC_POLICY_INSTANCES_BY_OWNER" => {
+                AccountLimitType::MaxTrafficPolicyInstancesByOwner
+            }
+            "MAX_REUSABLE_DELEGATION_SETS_BY_OWNER" => {
+                AccountLimitType::MaxReusableDelegationSetsByOwner
+            }
+            "MAX_TRAFFIC_POLICIES_BY_OWNER" => AccountLimitType::MaxTrafficPoliciesByOwner,
+            _ => AccountLimitType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AccountLimitType {
+    fn from(value: String) -> AccountLimitType {
+        match value.as_str() {
+            "MAX_HEALTH_CHECKS_BY_OWNER" => AccountLimitType::MaxHealthChecksByOwner,
+            "MAX_HOSTED_ZONES_BY_OWNER" => AccountLimitType::MaxHostedZonesByOwner,
+            "MAX_TRAFFIC_POLICY_INSTANCES_BY_OWNER" => {
+                AccountLimitType::MaxTrafficPolicyInstancesByOwner
+            }
+            "MAX_REUSABLE_DELEGATION_SETS_BY_OWNER" => {
+                AccountLimitType::MaxReusableDelegationSetsByOwner
+            }
+            "MAX_TRAFFIC_POLICIES_BY_OWNER" => AccountLimitType::MaxTrafficPoliciesByOwner,
+            _ => AccountLimitType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AccountLimitType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AccountLimitType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AccountLimitType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for AccountLimitType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for AccountLimitType {
+    fn deserialize<D>(deserializer: D) -> Result<AccountLimitType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct AccountLimitTypeDeserializer;
 impl AccountLimitTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<AccountLimitType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -83,7 +208,7 @@ impl AccountLimitTypeSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &AccountLimitType,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -105,7 +230,7 @@ pub struct AlarmIdentifier {
     /// <p><p>The name of the CloudWatch alarm that you want Amazon Route 53 health checkers to use to determine whether this health check is healthy.</p> <note> <p>Route 53 supports CloudWatch alarms with the following features:</p> <ul> <li> <p>Standard-resolution metrics. High-resolution metrics aren&#39;t supported. For more information, see <a href="http://docs.aws.amazon.com/AmazonCloudWatch/latest/DeveloperGuide/publishingMetrics.html#high-resolution-metrics">High-Resolution Metrics</a> in the <i>Amazon CloudWatch User Guide</i>.</p> </li> <li> <p>Statistics: Average, Minimum, Maximum, Sum, and SampleCount. Extended statistics aren&#39;t supported.</p> </li> </ul> </note></p>
     pub name: String,
     /// <p>For the CloudWatch alarm that you want Route 53 health checkers to use to determine whether this health check is healthy, the region that the alarm was created in.</p> <p>For the current list of CloudWatch regions, see <a href="http://docs.aws.amazon.com/general/latest/gr/rande.html#cw_region">Amazon CloudWatch</a> in the <i>AWS Regions and Endpoints</i> chapter of the <i>Amazon Web Services General Reference</i>.</p>
-    pub region: String,
+    pub region: CloudWatchRegion,
 }
 
 struct AlarmIdentifierDeserializer;
@@ -145,13 +270,13 @@ impl AlarmIdentifierSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Name"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.name
+            value = &obj.name
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::start_element("Region"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.region
+            value = &obj.region
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::end_element())
@@ -277,19 +402,19 @@ impl AliasTargetSerializer {
         writer.write(xml::writer::XmlEvent::start_element("DNSName"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.dns_name
+            value = &obj.dns_name
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::start_element("EvaluateTargetHealth"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.evaluate_target_health
+            value = &obj.evaluate_target_health
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::start_element("HostedZoneId"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.hosted_zone_id
+            value = &obj.hosted_zone_id
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::end_element())
@@ -383,7 +508,7 @@ impl AssociateVPCWithHostedZoneResponseDeserializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct Change {
     /// <p><p>The action to perform:</p> <ul> <li> <p> <code>CREATE</code>: Creates a resource record set that has the specified values.</p> </li> <li> <p> <code>DELETE</code>: Deletes a existing resource record set.</p> <important> <p>To delete the resource record set that is associated with a traffic policy instance, use <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_DeleteTrafficPolicyInstance.html">DeleteTrafficPolicyInstance</a>. Amazon Route 53 will delete the resource record set automatically. If you delete the resource record set by using <code>ChangeResourceRecordSets</code>, Route 53 doesn&#39;t automatically delete the traffic policy instance, and you&#39;ll continue to be charged for it even though it&#39;s no longer in use. </p> </important> </li> <li> <p> <code>UPSERT</code>: If a resource record set doesn&#39;t already exist, Route 53 creates it. If a resource record set does exist, Route 53 updates it with the values in the request.</p> </li> </ul></p>
-    pub action: String,
+    pub action: ChangeAction,
     /// <p>Information about the resource record set to create, delete, or update.</p>
     pub resource_record_set: ResourceRecordSet,
 }
@@ -403,7 +528,7 @@ impl ChangeSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Action"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.action
+            value = &obj.action
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         ResourceRecordSetSerializer::serialize(
@@ -415,13 +540,114 @@ impl ChangeSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ChangeAction {
+    /// `CREATE`
+    Create,
+    /// `DELETE`
+    Delete,
+    /// `UPSERT`
+    Upsert,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ChangeAction {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ChangeAction::Create => "CREATE",
+            ChangeAction::Delete => "DELETE",
+            ChangeAction::Upsert => "UPSERT",
+            ChangeAction::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ChangeAction {
+    fn default() -> ChangeAction {
+        ChangeAction::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ChangeAction {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ChangeAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ChangeAction {
+    fn from(value: &'a str) -> ChangeAction {
+        match value {
+            "CREATE" => ChangeAction::Create,
+            "DELETE" => ChangeAction::Delete,
+            "UPSERT" => ChangeAction::Upsert,
+            _ => ChangeAction::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ChangeAction {
+    fn from(value: String) -> ChangeAction {
+        match value.as_str() {
+            "CREATE" => ChangeAction::Create,
+            "DELETE" => ChangeAction::Delete,
+            "UPSERT" => ChangeAction::Upsert,
+            _ => ChangeAction::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ChangeAction {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ChangeAction, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ChangeAction {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ChangeAction {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ChangeAction {
+    fn deserialize<D>(deserializer: D) -> Result<ChangeAction, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+
 pub struct ChangeActionSerializer;
 impl ChangeActionSerializer {
     #[allow(unused_variables, warnings)]
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &ChangeAction,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -479,9 +705,9 @@ pub struct ChangeInfo {
     /// <p>The ID of the request.</p>
     pub id: String,
     /// <p>The current state of the request. <code>PENDING</code> indicates that this request has not yet been applied to all Amazon Route 53 DNS servers.</p>
-    pub status: String,
+    pub status: ChangeStatus,
     /// <p>The date and time that the change request was submitted in <a href="https://en.wikipedia.org/wiki/ISO_8601">ISO 8601 format</a> and Coordinated Universal Time (UTC). For example, the value <code>2017-03-27T17:48:16.751Z</code> represents March 27, 2017 at 17:48:16.751 UTC.</p>
-    pub submitted_at: String,
+    pub submitted_at: ::chrono::DateTime<::chrono::Utc>,
 }
 
 struct ChangeInfoDeserializer;
@@ -570,12 +796,110 @@ impl ChangeResourceRecordSetsResponseDeserializer {
         )
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ChangeStatus {
+    /// `PENDING`
+    Pending,
+    /// `INSYNC`
+    Insync,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ChangeStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ChangeStatus::Pending => "PENDING",
+            ChangeStatus::Insync => "INSYNC",
+            ChangeStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ChangeStatus {
+    fn default() -> ChangeStatus {
+        ChangeStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ChangeStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ChangeStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ChangeStatus {
+    fn from(value: &'a str) -> ChangeStatus {
+        match value {
+            "PENDING" => ChangeStatus::Pending,
+            "INSYNC" => ChangeStatus::Insync,
+            _ => ChangeStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ChangeStatus {
+    fn from(value: String) -> ChangeStatus {
+        match value.as_str() {
+            "PENDING" => ChangeStatus::Pending,
+            "INSYNC" => ChangeStatus::Insync,
+            _ => ChangeStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ChangeStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ChangeStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ChangeStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ChangeStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ChangeStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ChangeStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct ChangeStatusDeserializer;
 impl ChangeStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ChangeStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -592,7 +916,7 @@ pub struct ChangeTagsForResourceRequest {
     /// <p>The ID of the resource for which you want to add, change, or delete tags.</p>
     pub resource_id: String,
     /// <p><p>The type of the resource.</p> <ul> <li> <p>The resource type for health checks is <code>healthcheck</code>.</p> </li> <li> <p>The resource type for hosted zones is <code>hostedzone</code>.</p> </li> </ul></p>
-    pub resource_type: String,
+    pub resource_type: TagResourceType,
 }
 
 pub struct ChangeTagsForResourceRequestSerializer;
@@ -722,7 +1046,7 @@ impl ChildHealthCheckListSerializer {
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct CloudWatchAlarmConfiguration {
     /// <p>For the metric that the CloudWatch alarm is associated with, the arithmetic operation that is used for the comparison.</p>
-    pub comparison_operator: String,
+    pub comparison_operator: ComparisonOperator,
     /// <p>For the metric that the CloudWatch alarm is associated with, a complex type that contains information about the dimensions for the metric. For information, see <a href="http://docs.aws.amazon.com/AmazonCloudWatch/latest/DeveloperGuide/CW_Support_For_AWS.html">Amazon CloudWatch Namespaces, Dimensions, and Metrics Reference</a> in the <i>Amazon CloudWatch User Guide</i>.</p>
     pub dimensions: Option<Vec<Dimension>>,
     /// <p>For the metric that the CloudWatch alarm is associated with, the number of periods that the metric is compared to the threshold.</p>
@@ -734,7 +1058,7 @@ pub struct CloudWatchAlarmConfiguration {
     /// <p>For the metric that the CloudWatch alarm is associated with, the duration of one evaluation period in seconds.</p>
     pub period: i64,
     /// <p>For the metric that the CloudWatch alarm is associated with, the statistic that is applied to the metric.</p>
-    pub statistic: String,
+    pub statistic: Statistic,
     /// <p>For the metric that the CloudWatch alarm is associated with, the value the metric is compared with.</p>
     pub threshold: f64,
 }
@@ -820,12 +1144,280 @@ impl CloudWatchLogsLogGroupArnSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CloudWatchRegion {
+    /// `us-east-1`
+    UsEast1,
+    /// `us-east-2`
+    UsEast2,
+    /// `us-west-1`
+    UsWest1,
+    /// `us-west-2`
+    UsWest2,
+    /// `ca-central-1`
+    CaCentral1,
+    /// `eu-central-1`
+    EuCentral1,
+    /// `eu-central-2`
+    EuCentral2,
+    /// `eu-west-1`
+    EuWest1,
+    /// `eu-west-2`
+    EuWest2,
+    /// `eu-west-3`
+    EuWest3,
+    /// `ap-east-1`
+    ApEast1,
+    /// `me-south-1`
+    MeSouth1,
+    /// `me-central-1`
+    MeCentral1,
+    /// `ap-south-1`
+    ApSouth1,
+    /// `ap-south-2`
+    ApSouth2,
+    /// `ap-southeast-1`
+    ApSoutheast1,
+    /// `ap-southeast-2`
+    ApSoutheast2,
+    /// `ap-southeast-3`
+    ApSoutheast3,
+    /// `ap-northeast-1`
+    ApNortheast1,
+    /// `ap-northeast-2`
+    ApNortheast2,
+    /// `ap-northeast-3`
+    ApNortheast3,
+    /// `eu-north-1`
+    EuNorth1,
+    /// `sa-east-1`
+    SaEast1,
+    /// `cn-northwest-1`
+    CnNorthwest1,
+    /// `cn-north-1`
+    CnNorth1,
+    /// `af-south-1`
+    AfSouth1,
+    /// `eu-south-1`
+    EuSouth1,
+    /// `eu-south-2`
+    EuSouth2,
+    /// `us-gov-west-1`
+    UsGovWest1,
+    /// `us-gov-east-1`
+    UsGovEast1,
+    /// `us-iso-east-1`
+    UsIsoEast1,
+    /// `us-iso-west-1`
+    UsIsoWest1,
+    /// `us-isob-east-1`
+    UsIsobEast1,
+    /// `ap-southeast-4`
+    ApSoutheast4,
+    /// `il-central-1`
+    IlCentral1,
+    /// `ca-west-1`
+    CaWest1,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CloudWatchRegion {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CloudWatchRegion::UsEast1 => "us-east-1",
+            CloudWatchRegion::UsEast2 => "us-east-2",
+            CloudWatchRegion::UsWest1 => "us-west-1",
+            CloudWatchRegion::UsWest2 => "us-west-2",
+            CloudWatchRegion::CaCentral1 => "ca-central-1",
+            CloudWatchRegion::EuCentral1 => "eu-central-1",
+            CloudWatchRegion::EuCentral2 => "eu-central-2",
+            CloudWatchRegion::EuWest1 => "eu-west-1",
+            CloudWatchRegion::EuWest2 => "eu-west-2",
+            CloudWatchRegion::EuWest3 => "eu-west-3",
+            CloudWatchRegion::ApEast1 => "ap-east-1",
+            CloudWatchRegion::MeSouth1 => "me-south-1",
+            CloudWatchRegion::MeCentral1 => "me-central-1",
+            CloudWatchRegion::ApSouth1 => "ap-south-1",
+            CloudWatchRegion::ApSouth2 => "ap-south-2",
+            CloudWatchRegion::ApSoutheast1 => "ap-southeast-1",
+            CloudWatchRegion::ApSoutheast2 => "ap-southeast-2",
+            CloudWatchRegion::ApSoutheast3 => "ap-southeast-3",
+            CloudWatchRegion::ApNortheast1 => "ap-northeast-1",
+            CloudWatchRegion::ApNortheast2 => "ap-northeast-2",
+            CloudWatchRegion::ApNortheast3 => "ap-northeast-3",
+            CloudWatchRegion::EuNorth1 => "eu-north-1",
+            CloudWatchRegion::SaEast1 => "sa-east-1",
+            CloudWatchRegion::CnNorthwest1 => "cn-northwest-1",
+            CloudWatchRegion::CnNorth1 => "cn-north-1",
+            CloudWatchRegion::AfSouth1 => "af-south-1",
+            CloudWatchRegion::EuSouth1 => "eu-south-1",
+            CloudWatchRegion::EuSouth2 => "eu-south-2",
+            CloudWatchRegion::UsGovWest1 => "us-gov-west-1",
+            CloudWatchRegion::UsGovEast1 => "us-gov-east-1",
+            CloudWatchRegion::UsIsoEast1 => "us-iso-east-1",
+            CloudWatchRegion::UsIsoWest1 => "us-iso-west-1",
+            CloudWatchRegion::UsIsobEast1 => "us-isob-east-1",
+            CloudWatchRegion::ApSoutheast4 => "ap-southeast-4",
+            CloudWatchRegion::IlCentral1 => "il-central-1",
+            CloudWatchRegion::CaWest1 => "ca-west-1",
+            CloudWatchRegion::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CloudWatchRegion {
+    fn default() -> CloudWatchRegion {
+        CloudWatchRegion::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CloudWatchRegion {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CloudWatchRegion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CloudWatchRegion {
+    fn from(value: &'a str) -> CloudWatchRegion {
+        match value {
+            "us-east-1" => CloudWatchRegion::UsEast1,
+            "us-east-2" => CloudWatchRegion::UsEast2,
+            "us-west-1" => CloudWatchRegion::UsWest1,
+            "us-west-2" => CloudWatchRegion::UsWest2,
+            "ca-central-1" => CloudWatchRegion::CaCentral1,
+            "eu-central-1" => CloudWatchRegion::EuCentral1,
+            "eu-central-2" => CloudWatchRegion::EuCentral2,
+            "eu-west-1" => CloudWatchRegion::EuWest1,
+            "eu-west-2" => CloudWatchRegion::EuWest2,
+            "eu-west-3" => CloudWatchRegion::EuWest3,
+            "ap-east-1" => CloudWatchRegion::ApEast1,
+            "me-south-1" => CloudWatchRegion::MeSouth1,
+            "me-central-1" => CloudWatchRegion::MeCentral1,
+            "ap-south-1" => CloudWatchRegion::ApSouth1,
+            "ap-south-2" => CloudWatchRegion::ApSouth2,
+            "ap-southeast-1" => CloudWatchRegion::ApSoutheast1,
+            "ap-southeast-2" => CloudWatchRegion::ApSoutheast2,
+            "ap-southeast-3" => CloudWatchRegion::ApSoutheast3,
+            "ap-northeast-1" => CloudWatchRegion::ApNortheast1,
+            "ap-northeast-2" => CloudWatchRegion::ApNortheast2,
+            "ap-northeast-3" => CloudWatchRegion::ApNortheast3,
+            "eu-north-1" => CloudWatchRegion::EuNorth1,
+            "sa-east-1" => CloudWatchRegion::SaEast1,
+            "cn-northwest-1" => CloudWatchRegion::CnNorthwest1,
+            "cn-north-1" => CloudWatchRegion::CnNorth1,
+            "af-south-1" => CloudWatchRegion::AfSouth1,
+            "eu-south-1" => CloudWatchRegion::EuSouth1,
+            "eu-south-2" => CloudWatchRegion::EuSouth2,
+            "us-gov-west-1" => CloudWatchRegion::UsGovWest1,
+            "us-gov-east-1" => CloudWatchRegion::UsGovEast1,
+            "us-iso-east-1" => CloudWatchRegion::UsIsoEast1,
+            "us-iso-west-1" => CloudWatchRegion::UsIsoWest1,
+            "us-isob-east-1" => CloudWatchRegion::UsIsobEast1,
+            "ap-southeast-4" => CloudWatchRegion::ApSoutheast4,
+            "il-central-1" => CloudWatchRegion::IlCentral1,
+            "ca-west-1" => CloudWatchRegion::CaWest1,
+            _ => CloudWatchRegion::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CloudWatchRegion {
+    fn from(value: String) -> CloudWatchRegion {
+        match value.as_str() {
+            "us-east-1" => CloudWatchRegion::UsEast1,
+            "us-east-2" => CloudWatchRegion::UsEast2,
+            "us-west-1" => CloudWatchRegion::UsWest1,
+            "us-west-2" => CloudWatchRegion::UsWest2,
+            "ca-central-1" => CloudWatchRegion::CaCentral1,
+            "eu-central-1" => CloudWatchRegion::EuCentral1,
+            "eu-central-2" => CloudWatchRegion::EuCentral2,
+            "eu-west-1" => CloudWatchRegion::EuWest1,
+            "eu-west-2" => CloudWatchRegion::EuWest2,
+            "eu-west-3" => CloudWatchRegion::EuWest3,
+            "ap-east-1" => CloudWatchRegion::ApEast1,
+            "me-south-1" => CloudWatchRegion::MeSouth1,
+            "me-central-1" => CloudWatchRegion::MeCentral1,
+            "ap-south-1" => CloudWatchRegion::ApSouth1,
+            "ap-south-2" => CloudWatchRegion::ApSouth2,
+            "ap-southeast-1" => CloudWatchRegion::ApSoutheast1,
+            "ap-southeast-2" => CloudWatchRegion::ApSoutheast2,
+            "ap-southeast-3" => CloudWatchRegion::ApSoutheast3,
+            "ap-northeast-1" => CloudWatchRegion::ApNortheast1,
+            "ap-northeast-2" => CloudWatchRegion::ApNortheast2,
+            "ap-northeast-3" => CloudWatchRegion::ApNortheast3,
+            "eu-north-1" => CloudWatchRegion::EuNorth1,
+            "sa-east-1" => CloudWatchRegion::SaEast1,
+            "cn-northwest-1" => CloudWatchRegion::CnNorthwest1,
+            "cn-north-1" => CloudWatchRegion::CnNorth1,
+            "af-south-1" => CloudWatchRegion::AfSouth1,
+            "eu-south-1" => CloudWatchRegion::EuSouth1,
+            "eu-south-2" => CloudWatchRegion::EuSouth2,
+            "us-gov-west-1" => CloudWatchRegion::UsGovWest1,
+            "us-gov-east-1" => CloudWatchRegion::UsGovEast1,
+            "us-iso-east-1" => CloudWatchRegion::UsIsoEast1,
+            "us-iso-west-1" => CloudWatchRegion::UsIsoWest1,
+            "us-isob-east-1" => CloudWatchRegion::UsIsobEast1,
+            "ap-southeast-4" => CloudWatchRegion::ApSoutheast4,
+            "il-central-1" => CloudWatchRegion::IlCentral1,
+            "ca-west-1" => CloudWatchRegion::CaWest1,
+            _ => CloudWatchRegion::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CloudWatchRegion {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CloudWatchRegion, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CloudWatchRegion {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for CloudWatchRegion {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for CloudWatchRegion {
+    fn deserialize<D>(deserializer: D) -> Result<CloudWatchRegion, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct CloudWatchRegionDeserializer;
 impl CloudWatchRegionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<CloudWatchRegion, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -838,7 +1430,7 @@ impl CloudWatchRegionSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &CloudWatchRegion,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -852,12 +1444,120 @@ impl CloudWatchRegionSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ComparisonOperator {
+    /// `GreaterThanOrEqualToThreshold`
+    GreaterThanOrEqualToThreshold,
+    /// `GreaterThanThreshold`
+    GreaterThanThreshold,
+    /// `LessThanThreshold`
+    LessThanThreshold,
+    /// `LessThanOrEqualToThreshold`
+    LessThanOrEqualToThreshold,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ComparisonOperator {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ComparisonOperator::GreaterThanOrEqualToThreshold => "GreaterThanOrEqualToThreshold",
+            ComparisonOperator::GreaterThanThreshold => "GreaterThanThreshold",
+            ComparisonOperator::LessThanThreshold => "LessThanThreshold",
+            ComparisonOperator::LessThanOrEqualToThreshold => "LessThanOrEqualToThreshold",
+            ComparisonOperator::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ComparisonOperator {
+    fn default() -> ComparisonOperator {
+        ComparisonOperator::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ComparisonOperator {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ComparisonOperator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ComparisonOperator {
+    fn from(value: &'a str) -> ComparisonOperator {
+        match value {
+            "GreaterThanOrEqualToThreshold" => ComparisonOperator::GreaterThanOrEqualToThreshold,
+            "GreaterThanThreshold" => ComparisonOperator::GreaterThanThreshold,
+            "LessThanThreshold" => ComparisonOperator::LessThanThreshold,
+            "LessThanOrEqualToThreshold" => ComparisonOperator::LessThanOrEqualToThreshold,
+            _ => ComparisonOperator::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ComparisonOperator {
+    fn from(value: String) -> ComparisonOperator {
+        match value.as_str() {
+            "GreaterThanOrEqualToThreshold" => ComparisonOperator::GreaterThanOrEqualToThreshold,
+            "GreaterThanThreshold" => ComparisonOperator::GreaterThanThreshold,
+            "LessThanThreshold" => ComparisonOperator::LessThanThreshold,
+            "LessThanOrEqualToThreshold" => ComparisonOperator::LessThanOrEqualToThreshold,
+            _ => ComparisonOperator::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ComparisonOperator {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ComparisonOperator, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ComparisonOperator {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ComparisonOperator {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ComparisonOperator {
+    fn deserialize<D>(deserializer: D) -> Result<ComparisonOperator, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct ComparisonOperatorDeserializer;
 impl ComparisonOperatorDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ComparisonOperator, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -2369,7 +3069,7 @@ impl GeoLocationSubdivisionNameDeserializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct GetAccountLimitRequest {
     /// <p><p>The limit that you want to get. Valid values include the following:</p> <ul> <li> <p> <b>MAX<em>HEALTH</em>CHECKS<em>BY</em>OWNER</b>: The maximum number of health checks that you can create using the current account.</p> </li> <li> <p> <b>MAX<em>HOSTED</em>ZONES<em>BY</em>OWNER</b>: The maximum number of hosted zones that you can create using the current account.</p> </li> <li> <p> <b>MAX<em>REUSABLE</em>DELEGATION<em>SETS</em>BY<em>OWNER</b>: The maximum number of reusable delegation sets that you can create using the current account.</p> </li> <li> <p> <b>MAX</em>TRAFFIC<em>POLICIES</em>BY<em>OWNER</b>: The maximum number of traffic policies that you can create using the current account.</p> </li> <li> <p> <b>MAX</em>TRAFFIC<em>POLICY</em>INSTANCES<em>BY</em>OWNER</b>: The maximum number of traffic policy instances that you can create using the current account. (Traffic policy instances are referred to as traffic flow policy records in the Amazon Route 53 console.)</p> </li> </ul></p>
-    pub type_: String,
+    pub type_: AccountLimitType,
 }
 
 /// <p>A complex type that contains the requested limit. </p>
@@ -2718,7 +3418,7 @@ pub struct GetHostedZoneLimitRequest {
     /// <p>The ID of the hosted zone that you want to get a limit for.</p>
     pub hosted_zone_id: String,
     /// <p><p>The limit that you want to get. Valid values include the following:</p> <ul> <li> <p> <b>MAX<em>RRSETS</em>BY<em>ZONE</b>: The maximum number of records that you can create in the specified hosted zone.</p> </li> <li> <p> <b>MAX</em>VPCS<em>ASSOCIATED</em>BY_ZONE</b>: The maximum number of Amazon VPCs that you can associate with the specified private hosted zone.</p> </li> </ul></p>
-    pub type_: String,
+    pub type_: HostedZoneLimitType,
 }
 
 /// <p>A complex type that contains the requested limit. </p>
@@ -2851,7 +3551,7 @@ pub struct GetReusableDelegationSetLimitRequest {
     /// <p>The ID of the delegation set that you want to get the limit for.</p>
     pub delegation_set_id: String,
     /// <p>Specify <code>MAX_ZONES_BY_REUSABLE_DELEGATION_SET</code> to get the maximum number of hosted zones that you can associate with the specified reusable delegation set.</p>
-    pub type_: String,
+    pub type_: ReusableDelegationSetLimitType,
 }
 
 /// <p>A complex type that contains the requested limit. </p>
@@ -3134,7 +3834,7 @@ pub struct HealthCheckConfig {
     /// <p>The IPv4 or IPv6 IP address of the endpoint that you want Amazon Route 53 to perform health checks on. If you don't specify a value for <code>IPAddress</code>, Route 53 sends a DNS request to resolve the domain name that you specify in <code>FullyQualifiedDomainName</code> at the interval that you specify in <code>RequestInterval</code>. Using an IP address returned by DNS, Route 53 then checks the health of the endpoint.</p> <p>Use one of the following formats for the value of <code>IPAddress</code>: </p> <ul> <li> <p> <b>IPv4 address</b>: four values between 0 and 255, separated by periods (.), for example, <code>192.0.2.44</code>.</p> </li> <li> <p> <b>IPv6 address</b>: eight groups of four hexadecimal values, separated by colons (:), for example, <code>2001:0db8:85a3:0000:0000:abcd:0001:2345</code>. You can also shorten IPv6 addresses as described in RFC 5952, for example, <code>2001:db8:85a3::abcd:1:2345</code>.</p> </li> </ul> <p>If the endpoint is an EC2 instance, we recommend that you create an Elastic IP address, associate it with your EC2 instance, and specify the Elastic IP address for <code>IPAddress</code>. This ensures that the IP address of your instance will never change.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_UpdateHealthCheck.html#Route53-UpdateHealthCheck-request-FullyQualifiedDomainName">FullyQualifiedDomainName</a>. </p> <p>Constraints: Route 53 can't check the health of endpoints for which the IP address is in local, private, non-routable, or multicast ranges. For more information about IP addresses for which you can't create health checks, see the following documents:</p> <ul> <li> <p> <a href="https://tools.ietf.org/html/rfc5735">RFC 5735, Special Use IPv4 Addresses</a> </p> </li> <li> <p> <a href="https://tools.ietf.org/html/rfc6598">RFC 6598, IANA-Reserved IPv4 Prefix for Shared Address Space</a> </p> </li> <li> <p> <a href="https://tools.ietf.org/html/rfc5156">RFC 5156, Special-Use IPv6 Addresses</a> </p> </li> </ul> <p>When the value of <code>Type</code> is <code>CALCULATED</code> or <code>CLOUDWATCH_METRIC</code>, omit <code>IPAddress</code>.</p>
     pub ip_address: Option<String>,
     /// <p><p>When CloudWatch has insufficient data about the metric to determine the alarm state, the status that you want Amazon Route 53 to assign to the health check:</p> <ul> <li> <p> <code>Healthy</code>: Route 53 considers the health check to be healthy.</p> </li> <li> <p> <code>Unhealthy</code>: Route 53 considers the health check to be unhealthy.</p> </li> <li> <p> <code>LastKnownStatus</code>: Route 53 uses the status of the health check from the last time that CloudWatch had sufficient data to determine the alarm state. For new health checks that have no last known status, the default status for the health check is healthy.</p> </li> </ul></p>
-    pub insufficient_data_health_status: Option<String>,
+    pub insufficient_data_health_status: Option<InsufficientDataHealthStatus>,
     /// <p>Specify whether you want Amazon Route 53 to invert the status of a health check, for example, to consider a health check unhealthy when it otherwise would be considered healthy.</p>
     pub inverted: Option<bool>,
     /// <p><p>Specify whether you want Amazon Route 53 to measure the latency between health checkers in multiple AWS regions and your endpoint, and to display CloudWatch latency graphs on the <b>Health Checks</b> page in the Route 53 console.</p> <important> <p>You can&#39;t change the value of <code>MeasureLatency</code> after you create a health check.</p> </important></p>
@@ -3142,7 +3842,7 @@ pub struct HealthCheckConfig {
     /// <p>The port on the endpoint on which you want Amazon Route 53 to perform health checks. Specify a value for <code>Port</code> only when you specify a value for <code>IPAddress</code>.</p>
     pub port: Option<i64>,
     /// <p>A complex type that contains one <code>Region</code> element for each region from which you want Amazon Route 53 health checkers to check the specified endpoint.</p> <p>If you don't specify any regions, Route 53 health checkers automatically performs checks from all of the regions that are listed under <b>Valid Values</b>.</p> <p>If you update a health check to remove a region that has been performing health checks, Route 53 will briefly continue to perform checks from that region to ensure that some health checkers are always checking the endpoint (for example, if you replace three regions with four different regions). </p>
-    pub regions: Option<Vec<String>>,
+    pub regions: Option<Vec<HealthCheckRegion>>,
     /// <p>The number of seconds between the time that Amazon Route 53 gets a response from your endpoint and the time that it sends the next health check request. Each Route 53 health checker makes requests at this interval.</p> <important> <p>You can't change the value of <code>RequestInterval</code> after you create a health check.</p> </important> <p>If you don't specify a value for <code>RequestInterval</code>, the default value is <code>30</code> seconds.</p>
     pub request_interval: Option<i64>,
     /// <p>The path, if any, that you want Amazon Route 53 to request when performing health checks. The path can be any value for which your endpoint will return an HTTP status code of 2xx or 3xx when the endpoint is healthy, for example, the file /docs/route53-health-check.html. You can also include query string parameters, for example, <code>/welcome.html?language=jp&amp;login=y</code>. </p>
@@ -3150,7 +3850,7 @@ pub struct HealthCheckConfig {
     /// <p>If the value of Type is <code>HTTP_STR_MATCH</code> or <code>HTTP_STR_MATCH</code>, the string that you want Amazon Route 53 to search for in the response body from the specified resource. If the string appears in the response body, Route 53 considers the resource healthy.</p> <p>Route 53 considers case when searching for <code>SearchString</code> in the response body. </p>
     pub search_string: Option<String>,
     /// <p>The type of health check that you want to create, which indicates how Amazon Route 53 determines whether an endpoint is healthy.</p> <important> <p>You can't change the value of <code>Type</code> after you create a health check.</p> </important> <p>You can create the following types of health checks:</p> <ul> <li> <p> <b>HTTP</b>: Route 53 tries to establish a TCP connection. If successful, Route 53 submits an HTTP request and waits for an HTTP status code of 200 or greater and less than 400.</p> </li> <li> <p> <b>HTTPS</b>: Route 53 tries to establish a TCP connection. If successful, Route 53 submits an HTTPS request and waits for an HTTP status code of 200 or greater and less than 400.</p> <important> <p>If you specify <code>HTTPS</code> for the value of <code>Type</code>, the endpoint must support TLS v1.0 or later.</p> </important> </li> <li> <p> <b>HTTP_STR_MATCH</b>: Route 53 tries to establish a TCP connection. If successful, Route 53 submits an HTTP request and searches the first 5,120 bytes of the response body for the string that you specify in <code>SearchString</code>.</p> </li> <li> <p> <b>HTTPS_STR_MATCH</b>: Route 53 tries to establish a TCP connection. If successful, Route 53 submits an <code>HTTPS</code> request and searches the first 5,120 bytes of the response body for the string that you specify in <code>SearchString</code>.</p> </li> <li> <p> <b>TCP</b>: Route 53 tries to establish a TCP connection.</p> </li> <li> <p> <b>CLOUDWATCH_METRIC</b>: The health check is associated with a CloudWatch alarm. If the state of the alarm is <code>OK</code>, the health check is considered healthy. If the state is <code>ALARM</code>, the health check is considered unhealthy. If CloudWatch doesn't have sufficient data to determine whether the state is <code>OK</code> or <code>ALARM</code>, the health check status depends on the setting for <code>InsufficientDataHealthStatus</code>: <code>Healthy</code>, <code>Unhealthy</code>, or <code>LastKnownStatus</code>. </p> </li> <li> <p> <b>CALCULATED</b>: For health checks that monitor the status of other health checks, Route 53 adds up the number of health checks that Route 53 health checkers consider to be healthy and compares that number with the value of <code>HealthThreshold</code>. </p> </li> </ul> <p>For more information, see <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/dns-failover-determining-health-of-endpoints.html">How Route 53 Determines Whether an Endpoint Is Healthy</a> in the <i>Amazon Route 53 Developer Guide</i>.</p>
-    pub type_: String,
+    pub type_: HealthCheckType,
 }
 
 struct HealthCheckConfigDeserializer;
@@ -3385,7 +4085,7 @@ impl HealthCheckConfigSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Type"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.type_
+            value = &obj.type_
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::end_element())
@@ -3474,7 +4174,7 @@ pub struct HealthCheckObservation {
     /// <p>The IP address of the Amazon Route 53 health checker that provided the failure reason in <code>StatusReport</code>.</p>
     pub ip_address: Option<String>,
     /// <p>The region of the Amazon Route 53 health checker that provided the status in <code>StatusReport</code>.</p>
-    pub region: Option<String>,
+    pub region: Option<HealthCheckRegion>,
     /// <p>A complex type that contains the last failure reason as reported by one Amazon Route 53 health checker and the time of the failed health check.</p>
     pub status_report: Option<StatusReport>,
 }
@@ -3526,12 +4226,140 @@ impl HealthCheckObservationsDeserializer {
         })
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum HealthCheckRegion {
+    /// `us-east-1`
+    UsEast1,
+    /// `us-west-1`
+    UsWest1,
+    /// `us-west-2`
+    UsWest2,
+    /// `eu-west-1`
+    EuWest1,
+    /// `ap-southeast-1`
+    ApSoutheast1,
+    /// `ap-southeast-2`
+    ApSoutheast2,
+    /// `ap-northeast-1`
+    ApNortheast1,
+    /// `sa-east-1`
+    SaEast1,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl HealthCheckRegion {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            HealthCheckRegion::UsEast1 => "us-east-1",
+            HealthCheckRegion::UsWest1 => "us-west-1",
+            HealthCheckRegion::UsWest2 => "us-west-2",
+            HealthCheckRegion::EuWest1 => "eu-west-1",
+            HealthCheckRegion::ApSoutheast1 => "ap-southeast-1",
+            HealthCheckRegion::ApSoutheast2 => "ap-southeast-2",
+            HealthCheckRegion::ApNortheast1 => "ap-northeast-1",
+            HealthCheckRegion::SaEast1 => "sa-east-1",
+            HealthCheckRegion::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for HealthCheckRegion {
+    fn default() -> HealthCheckRegion {
+        HealthCheckRegion::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for HealthCheckRegion {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for HealthCheckRegion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for HealthCheckRegion {
+    fn from(value: &'a str) -> HealthCheckRegion {
+        match value {
+            "us-east-1" => HealthCheckRegion::UsEast1,
+            "us-west-1" => HealthCheckRegion::UsWest1,
+            "us-west-2" => HealthCheckRegion::UsWest2,
+            "eu-west-1" => HealthCheckRegion::EuWest1,
+            "ap-southeast-1" => HealthCheckRegion::ApSoutheast1,
+            "ap-southeast-2" => HealthCheckRegion::ApSoutheast2,
+            "ap-northeast-1" => HealthCheckRegion::ApNortheast1,
+            "sa-east-1" => HealthCheckRegion::SaEast1,
+            _ => HealthCheckRegion::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for HealthCheckRegion {
+    fn from(value: String) -> HealthCheckRegion {
+        match value.as_str() {
+            "us-east-1" => HealthCheckRegion::UsEast1,
+            "us-west-1" => HealthCheckRegion::UsWest1,
+            "us-west-2" => HealthCheckRegion::UsWest2,
+            "eu-west-1" => HealthCheckRegion::EuWest1,
+            "ap-southeast-1" => HealthCheckRegion::ApSoutheast1,
+            "ap-southeast-2" => HealthCheckRegion::ApSoutheast2,
+            "ap-northeast-1" => HealthCheckRegion::ApNortheast1,
+            "sa-east-1" => HealthCheckRegion::SaEast1,
+            _ => HealthCheckRegion::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for HealthCheckRegion {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<HealthCheckRegion, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for HealthCheckRegion {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for HealthCheckRegion {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for HealthCheckRegion {
+    fn deserialize<D>(deserializer: D) -> Result<HealthCheckRegion, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct HealthCheckRegionDeserializer;
 impl HealthCheckRegionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<HealthCheckRegion, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -3544,7 +4372,7 @@ impl HealthCheckRegionSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &HealthCheckRegion,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -3564,7 +4392,7 @@ impl HealthCheckRegionListDeserializer {
     fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
-    ) -> Result<Vec<String>, XmlParseError> {
+    ) -> Result<Vec<HealthCheckRegion>, XmlParseError> {
         deserialize_elements::<_, Vec<_>, _>(tag_name, stack, |name, stack, obj| {
             if name == "Region" {
                 obj.push(HealthCheckRegionDeserializer::deserialize("Region", stack)?);
@@ -3582,7 +4410,7 @@ impl HealthCheckRegionListSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &Vec<String>,
+        obj: &Vec<HealthCheckRegion>,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -3596,12 +4424,140 @@ impl HealthCheckRegionListSerializer {
     }
 }
 
-struct HealthCheckTypeDeserializer;
-impl HealthCheckTypeDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
-        start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum HealthCheckType {
+    /// `HTTP`
+    Http,
+    /// `HTTPS`
+    Https,
+    /// `HTTP_STR_MATCH`
+    HttpStrMatch,
+    /// `HTTPS_STR_MATCH`
+    HttpsStrMatch,
+    /// `TCP`
+    Tcp,
+    /// `CALCULATED`
+    Calculated,
+    /// `CLOUDWATCH_METRIC`
+    CloudwatchMetric,
+    /// `RECOVERY_CONTROL`
+    RecoveryControl,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl HealthCheckType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            HealthCheckType::Http => "HTTP",
+            HealthCheckType::Https => "HTTPS",
+            HealthCheckType::HttpStrMatch => "HTTP_STR_MATCH",
+            HealthCheckType::HttpsStrMatch => "HTTPS_STR_MATCH",
+            HealthCheckType::Tcp => "TCP",
+            HealthCheckType::Calculated => "CALCULATED",
+            HealthCheckType::CloudwatchMetric => "CLOUDWATCH_METRIC",
+            HealthCheckType::RecoveryControl => "RECOVERY_CONTROL",
+            HealthCheckType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for HealthCheckType {
+    fn default() -> HealthCheckType {
+        HealthCheckType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for HealthCheckType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for HealthCheckType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for HealthCheckType {
+    fn from(value: &'a str) -> HealthCheckType {
+        match value {
+            "HTTP" => HealthCheckType::Http,
+            "HTTPS" => HealthCheckType::Https,
+            "HTTP_STR_MATCH" => HealthCheckType::HttpStrMatch,
+            "HTTPS_STR_MATCH" => HealthCheckType::HttpsStrMatch,
+            "TCP" => HealthCheckType::Tcp,
+            "CALCULATED" => HealthCheckType::Calculated,
+            "CLOUDWATCH_METRIC" => HealthCheckType::CloudwatchMetric,
+            "RECOVERY_CONTROL" => HealthCheckType::RecoveryControl,
+            _ => HealthCheckType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for HealthCheckType {
+    fn from(value: String) -> HealthCheckType {
+        match value.as_str() {
+            "HTTP" => HealthCheckType::Http,
+            "HTTPS" => HealthCheckType::Https,
+            "HTTP_STR_MATCH" => HealthCheckType::HttpStrMatch,
+            "HTTPS_STR_MATCH" => HealthCheckType::HttpsStrMatch,
+            "TCP" => HealthCheckType::Tcp,
+            "CALCULATED" => HealthCheckType::Calculated,
+            "CLOUDWATCH_METRIC" => HealthCheckType::CloudwatchMetric,
+            "RECOVERY_CONTROL" => HealthCheckType::RecoveryControl,
+            _ => HealthCheckType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for HealthCheckType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<HealthCheckType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for HealthCheckType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for HealthCheckType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for HealthCheckType {
+    fn deserialize<D>(deserializer: D) -> Result<HealthCheckType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+struct HealthCheckTypeDeserializer;
+impl HealthCheckTypeDeserializer {
+    #[allow(unused_variables)]
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<HealthCheckType, XmlParseError> {
+        start_element(tag_name, stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -3614,7 +4570,7 @@ impl HealthCheckTypeSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &HealthCheckType,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -3854,7 +4810,7 @@ impl HostedZoneCountDeserializer {
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct HostedZoneLimit {
     /// <p><p>The limit that you requested. Valid values include the following:</p> <ul> <li> <p> <b>MAX<em>RRSETS</em>BY<em>ZONE</b>: The maximum number of records that you can create in the specified hosted zone.</p> </li> <li> <p> <b>MAX</em>VPCS<em>ASSOCIATED</em>BY_ZONE</b>: The maximum number of Amazon VPCs that you can associate with the specified private hosted zone.</p> </li> </ul></p>
-    pub type_: String,
+    pub type_: HostedZoneLimitType,
     /// <p>The current value for the limit that is specified by <code>Type</code>.</p>
     pub value: i64,
 }
@@ -3880,12 +4836,110 @@ impl HostedZoneLimitDeserializer {
         })
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum HostedZoneLimitType {
+    /// `MAX_RRSETS_BY_ZONE`
+    MaxRrsetsByZone,
+    /// `MAX_VPCS_ASSOCIATED_BY_ZONE`
+    MaxVpcsAssociatedByZone,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl HostedZoneLimitType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            HostedZoneLimitType::MaxRrsetsByZone => "MAX_RRSETS_BY_ZONE",
+            HostedZoneLimitType::MaxVpcsAssociatedByZone => "MAX_VPCS_ASSOCIATED_BY_ZONE",
+            HostedZoneLimitType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for HostedZoneLimitType {
+    fn default() -> HostedZoneLimitType {
+        HostedZoneLimitType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for HostedZoneLimitType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for HostedZoneLimitType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for HostedZoneLimitType {
+    fn from(value: &'a str) -> HostedZoneLimitType {
+        match value {
+            "MAX_RRSETS_BY_ZONE" => HostedZoneLimitType::MaxRrsetsByZone,
+            "MAX_VPCS_ASSOCIATED_BY_ZONE" => HostedZoneLimitType::MaxVpcsAssociatedByZone,
+            _ => HostedZoneLimitType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for HostedZoneLimitType {
+    fn from(value: String) -> HostedZoneLimitType {
+        match value.as_str() {
+            "MAX_RRSETS_BY_ZONE" => HostedZoneLimitType::MaxRrsetsByZone,
+            "MAX_VPCS_ASSOCIATED_BY_ZONE" => HostedZoneLimitType::MaxVpcsAssociatedByZone,
+            _ => HostedZoneLimitType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for HostedZoneLimitType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<HostedZoneLimitType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for HostedZoneLimitType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for HostedZoneLimitType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for HostedZoneLimitType {
+    fn deserialize<D>(deserializer: D) -> Result<HostedZoneLimitType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct HostedZoneLimitTypeDeserializer;
 impl HostedZoneLimitTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<HostedZoneLimitType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -3898,7 +4952,7 @@ impl HostedZoneLimitTypeSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &HostedZoneLimitType,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -3983,12 +5037,115 @@ impl IPAddressCidrDeserializer {
         Ok(obj)
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum InsufficientDataHealthStatus {
+    /// `Healthy`
+    Healthy,
+    /// `Unhealthy`
+    Unhealthy,
+    /// `LastKnownStatus`
+    LastKnownStatus,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl InsufficientDataHealthStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InsufficientDataHealthStatus::Healthy => "Healthy",
+            InsufficientDataHealthStatus::Unhealthy => "Unhealthy",
+            InsufficientDataHealthStatus::LastKnownStatus => "LastKnownStatus",
+            InsufficientDataHealthStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for InsufficientDataHealthStatus {
+    fn default() -> InsufficientDataHealthStatus {
+        InsufficientDataHealthStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for InsufficientDataHealthStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for InsufficientDataHealthStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for InsufficientDataHealthStatus {
+    fn from(value: &'a str) -> InsufficientDataHealthStatus {
+        match value {
+            "Healthy" => InsufficientDataHealthStatus::Healthy,
+            "Unhealthy" => InsufficientDataHealthStatus::Unhealthy,
+            "LastKnownStatus" => InsufficientDataHealthStatus::LastKnownStatus,
+            _ => InsufficientDataHealthStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InsufficientDataHealthStatus {
+    fn from(value: String) -> InsufficientDataHealthStatus {
+        match value.as_str() {
+            "Healthy" => InsufficientDataHealthStatus::Healthy,
+            "Unhealthy" => InsufficientDataHealthStatus::Unhealthy,
+            "LastKnownStatus" => InsufficientDataHealthStatus::LastKnownStatus,
+            _ => InsufficientDataHealthStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for InsufficientDataHealthStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<InsufficientDataHealthStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for InsufficientDataHealthStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for InsufficientDataHealthStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for InsufficientDataHealthStatus {
+    fn deserialize<D>(deserializer: D) -> Result<InsufficientDataHealthStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct InsufficientDataHealthStatusDeserializer;
 impl InsufficientDataHealthStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<InsufficientDataHealthStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -4001,7 +5158,7 @@ impl InsufficientDataHealthStatusSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &InsufficientDataHealthStatus,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -4489,7 +5646,7 @@ pub struct ListResourceRecordSetsRequest {
     /// <p>The first name in the lexicographic ordering of resource record sets that you want to list.</p>
     pub start_record_name: Option<String>,
     /// <p>The type of resource record set to begin the record listing from.</p> <p>Valid values for basic resource record sets: <code>A</code> | <code>AAAA</code> | <code>CAA</code> | <code>CNAME</code> | <code>MX</code> | <code>NAPTR</code> | <code>NS</code> | <code>PTR</code> | <code>SOA</code> | <code>SPF</code> | <code>SRV</code> | <code>TXT</code> </p> <p>Values for weighted, latency, geolocation, and failover resource record sets: <code>A</code> | <code>AAAA</code> | <code>CAA</code> | <code>CNAME</code> | <code>MX</code> | <code>NAPTR</code> | <code>PTR</code> | <code>SPF</code> | <code>SRV</code> | <code>TXT</code> </p> <p>Values for alias resource record sets: </p> <ul> <li> <p> <b>API Gateway custom regional API or edge-optimized API</b>: A</p> </li> <li> <p> <b>CloudFront distribution</b>: A or AAAA</p> </li> <li> <p> <b>Elastic Beanstalk environment that has a regionalized subdomain</b>: A</p> </li> <li> <p> <b>Elastic Load Balancing load balancer</b>: A | AAAA</p> </li> <li> <p> <b>Amazon S3 bucket</b>: A</p> </li> <li> <p> <b>Amazon VPC interface VPC endpoint</b>: A</p> </li> <li> <p> <b>Another resource record set in this hosted zone:</b> The type of the resource record set that the alias references.</p> </li> </ul> <p>Constraint: Specifying <code>type</code> without specifying <code>name</code> returns an <code>InvalidInput</code> error.</p>
-    pub start_record_type: Option<String>,
+    pub start_record_type: Option<RRType>,
 }
 
 /// <p>A complex type that contains list information for the resource record set.</p>
@@ -4505,7 +5662,7 @@ pub struct ListResourceRecordSetsResponse {
     /// <p>If the results were truncated, the name of the next record in the list.</p> <p>This element is present only if <code>IsTruncated</code> is true. </p>
     pub next_record_name: Option<String>,
     /// <p>If the results were truncated, the type of the next record in the list.</p> <p>This element is present only if <code>IsTruncated</code> is true. </p>
-    pub next_record_type: Option<String>,
+    pub next_record_type: Option<RRType>,
     /// <p>Information about multiple resource record sets.</p>
     pub resource_record_sets: Vec<ResourceRecordSet>,
 }
@@ -4632,7 +5789,7 @@ pub struct ListTagsForResourceRequest {
     /// <p>The ID of the resource for which you want to retrieve tags.</p>
     pub resource_id: String,
     /// <p><p>The type of the resource.</p> <ul> <li> <p>The resource type for health checks is <code>healthcheck</code>.</p> </li> <li> <p>The resource type for hosted zones is <code>hostedzone</code>.</p> </li> </ul></p>
-    pub resource_type: String,
+    pub resource_type: TagResourceType,
 }
 
 /// <p>A complex type that contains information about the health checks or hosted zones for which you want to list tags.</p>
@@ -4673,7 +5830,7 @@ pub struct ListTagsForResourcesRequest {
     /// <p>A complex type that contains the ResourceId element for each resource for which you want to get a list of tags.</p>
     pub resource_ids: Vec<String>,
     /// <p><p>The type of the resources.</p> <ul> <li> <p>The resource type for health checks is <code>healthcheck</code>.</p> </li> <li> <p>The resource type for hosted zones is <code>hostedzone</code>.</p> </li> </ul></p>
-    pub resource_type: String,
+    pub resource_type: TagResourceType,
 }
 
 pub struct ListTagsForResourcesRequestSerializer;
@@ -4802,7 +5959,7 @@ pub struct ListTrafficPolicyInstancesByHostedZoneRequest {
     /// <p>If the value of <code>IsTruncated</code> in the previous response is true, you have more traffic policy instances. To get more traffic policy instances, submit another <code>ListTrafficPolicyInstances</code> request. For the value of <code>trafficpolicyinstancename</code>, specify the value of <code>TrafficPolicyInstanceNameMarker</code> from the previous response, which is the name of the first traffic policy instance in the next group of traffic policy instances.</p> <p>If the value of <code>IsTruncated</code> in the previous response was <code>false</code>, there are no more traffic policy instances to get.</p>
     pub traffic_policy_instance_name_marker: Option<String>,
     /// <p>If the value of <code>IsTruncated</code> in the previous response is true, you have more traffic policy instances. To get more traffic policy instances, submit another <code>ListTrafficPolicyInstances</code> request. For the value of <code>trafficpolicyinstancetype</code>, specify the value of <code>TrafficPolicyInstanceTypeMarker</code> from the previous response, which is the type of the first traffic policy instance in the next group of traffic policy instances.</p> <p>If the value of <code>IsTruncated</code> in the previous response was <code>false</code>, there are no more traffic policy instances to get.</p>
-    pub traffic_policy_instance_type_marker: Option<String>,
+    pub traffic_policy_instance_type_marker: Option<RRType>,
 }
 
 /// <p>A complex type that contains the response information for the request.</p>
@@ -4816,7 +5973,7 @@ pub struct ListTrafficPolicyInstancesByHostedZoneResponse {
     /// <p>If <code>IsTruncated</code> is <code>true</code>, <code>TrafficPolicyInstanceNameMarker</code> is the name of the first traffic policy instance in the next group of traffic policy instances.</p>
     pub traffic_policy_instance_name_marker: Option<String>,
     /// <p>If <code>IsTruncated</code> is true, <code>TrafficPolicyInstanceTypeMarker</code> is the DNS type of the resource record sets that are associated with the first traffic policy instance in the next group of traffic policy instances.</p>
-    pub traffic_policy_instance_type_marker: Option<String>,
+    pub traffic_policy_instance_type_marker: Option<RRType>,
     /// <p>A list that contains one <code>TrafficPolicyInstance</code> element for each traffic policy instance that matches the elements in the request. </p>
     pub traffic_policy_instances: Vec<TrafficPolicyInstance>,
 }
@@ -4882,7 +6039,7 @@ pub struct ListTrafficPolicyInstancesByPolicyRequest {
     /// <p>If the value of <code>IsTruncated</code> in the previous response was <code>true</code>, you have more traffic policy instances. To get more traffic policy instances, submit another <code>ListTrafficPolicyInstancesByPolicy</code> request.</p> <p>For the value of <code>trafficpolicyinstancename</code>, specify the value of <code>TrafficPolicyInstanceNameMarker</code> from the previous response, which is the name of the first traffic policy instance that Amazon Route 53 will return if you submit another request.</p> <p>If the value of <code>IsTruncated</code> in the previous response was <code>false</code>, there are no more traffic policy instances to get.</p>
     pub traffic_policy_instance_name_marker: Option<String>,
     /// <p>If the value of <code>IsTruncated</code> in the previous response was <code>true</code>, you have more traffic policy instances. To get more traffic policy instances, submit another <code>ListTrafficPolicyInstancesByPolicy</code> request.</p> <p>For the value of <code>trafficpolicyinstancetype</code>, specify the value of <code>TrafficPolicyInstanceTypeMarker</code> from the previous response, which is the name of the first traffic policy instance that Amazon Route 53 will return if you submit another request.</p> <p>If the value of <code>IsTruncated</code> in the previous response was <code>false</code>, there are no more traffic policy instances to get.</p>
-    pub traffic_policy_instance_type_marker: Option<String>,
+    pub traffic_policy_instance_type_marker: Option<RRType>,
     /// <p>The version of the traffic policy for which you want to list traffic policy instances. The version must be associated with the traffic policy that is specified by <code>TrafficPolicyId</code>.</p>
     pub traffic_policy_version: i64,
 }
@@ -4900,7 +6057,7 @@ pub struct ListTrafficPolicyInstancesByPolicyResponse {
     /// <p>If <code>IsTruncated</code> is <code>true</code>, <code>TrafficPolicyInstanceNameMarker</code> is the name of the first traffic policy instance in the next group of <code>MaxItems</code> traffic policy instances.</p>
     pub traffic_policy_instance_name_marker: Option<String>,
     /// <p>If <code>IsTruncated</code> is <code>true</code>, <code>TrafficPolicyInstanceTypeMarker</code> is the DNS type of the resource record sets that are associated with the first traffic policy instance in the next group of <code>MaxItems</code> traffic policy instances.</p>
-    pub traffic_policy_instance_type_marker: Option<String>,
+    pub traffic_policy_instance_type_marker: Option<RRType>,
     /// <p>A list that contains one <code>TrafficPolicyInstance</code> element for each traffic policy instance that matches the elements in the request.</p>
     pub traffic_policy_instances: Vec<TrafficPolicyInstance>,
 }
@@ -4970,7 +6127,7 @@ pub struct ListTrafficPolicyInstancesRequest {
     /// <p>If the value of <code>IsTruncated</code> in the previous response was <code>true</code>, you have more traffic policy instances. To get more traffic policy instances, submit another <code>ListTrafficPolicyInstances</code> request. For the value of <code>trafficpolicyinstancename</code>, specify the value of <code>TrafficPolicyInstanceNameMarker</code> from the previous response, which is the name of the first traffic policy instance in the next group of traffic policy instances.</p> <p>If the value of <code>IsTruncated</code> in the previous response was <code>false</code>, there are no more traffic policy instances to get.</p>
     pub traffic_policy_instance_name_marker: Option<String>,
     /// <p>If the value of <code>IsTruncated</code> in the previous response was <code>true</code>, you have more traffic policy instances. To get more traffic policy instances, submit another <code>ListTrafficPolicyInstances</code> request. For the value of <code>trafficpolicyinstancetype</code>, specify the value of <code>TrafficPolicyInstanceTypeMarker</code> from the previous response, which is the type of the first traffic policy instance in the next group of traffic policy instances.</p> <p>If the value of <code>IsTruncated</code> in the previous response was <code>false</code>, there are no more traffic policy instances to get.</p>
-    pub traffic_policy_instance_type_marker: Option<String>,
+    pub traffic_policy_instance_type_marker: Option<RRType>,
 }
 
 /// <p>A complex type that contains the response information for the request.</p>
@@ -4986,7 +6143,7 @@ pub struct ListTrafficPolicyInstancesResponse {
     /// <p>If <code>IsTruncated</code> is <code>true</code>, <code>TrafficPolicyInstanceNameMarker</code> is the name of the first traffic policy instance that Route 53 will return if you submit another <code>ListTrafficPolicyInstances</code> request. </p>
     pub traffic_policy_instance_name_marker: Option<String>,
     /// <p>If <code>IsTruncated</code> is <code>true</code>, <code>TrafficPolicyInstanceTypeMarker</code> is the DNS type of the resource record sets that are associated with the first traffic policy instance that Amazon Route 53 will return if you submit another <code>ListTrafficPolicyInstances</code> request. </p>
-    pub traffic_policy_instance_type_marker: Option<String>,
+    pub traffic_policy_instance_type_marker: Option<RRType>,
     /// <p>A list that contains one <code>TrafficPolicyInstance</code> element for each traffic policy instance that matches the elements in the request.</p>
     pub traffic_policy_instances: Vec<TrafficPolicyInstance>,
 }
@@ -5572,12 +6729,162 @@ impl RDataSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RRType {
+    /// `SOA`
+    Soa,
+    /// `A`
+    A,
+    /// `TXT`
+    Txt,
+    /// `NS`
+    Ns,
+    /// `CNAME`
+    Cname,
+    /// `MX`
+    Mx,
+    /// `NAPTR`
+    Naptr,
+    /// `PTR`
+    Ptr,
+    /// `SRV`
+    Srv,
+    /// `SPF`
+    Spf,
+    /// `AAAA`
+    Aaaa,
+    /// `CAA`
+    Caa,
+    /// `DS`
+    Ds,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RRType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RRType::Soa => "SOA",
+            RRType::A => "A",
+            RRType::Txt => "TXT",
+            RRType::Ns => "NS",
+            RRType::Cname => "CNAME",
+            RRType::Mx => "MX",
+            RRType::Naptr => "NAPTR",
+            RRType::Ptr => "PTR",
+            RRType::Srv => "SRV",
+            RRType::Spf => "SPF",
+            RRType::Aaaa => "AAAA",
+            RRType::Caa => "CAA",
+            RRType::Ds => "DS",
+            RRType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RRType {
+    fn default() -> RRType {
+        RRType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RRType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RRType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RRType {
+    fn from(value: &'a str) -> RRType {
+        match value {
+            "SOA" => RRType::Soa,
+            "A" => RRType::A,
+            "TXT" => RRType::Txt,
+            "NS" => RRType::Ns,
+            "CNAME" => RRType::Cname,
+            "MX" => RRType::Mx,
+            "NAPTR" => RRType::Naptr,
+            "PTR" => RRType::Ptr,
+            "SRV" => RRType::Srv,
+            "SPF" => RRType::Spf,
+            "AAAA" => RRType::Aaaa,
+            "CAA" => RRType::Caa,
+            "DS" => RRType::Ds,
+            _ => RRType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RRType {
+    fn from(value: String) -> RRType {
+        match value.as_str() {
+            "SOA" => RRType::Soa,
+            "A" => RRType::A,
+            "TXT" => RRType::Txt,
+            "NS" => RRType::Ns,
+            "CNAME" => RRType::Cname,
+            "MX" => RRType::Mx,
+            "NAPTR" => RRType::Naptr,
+            "PTR" => RRType::Ptr,
+            "SRV" => RRType::Srv,
+            "SPF" => RRType::Spf,
+            "AAAA" => RRType::Aaaa,
+            "CAA" => RRType::Caa,
+            "DS" => RRType::Ds,
+            _ => RRType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RRType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RRType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RRType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for RRType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for RRType {
+    fn deserialize<D>(deserializer: D) -> Result<RRType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct RRTypeDeserializer;
 impl RRTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<RRType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -5590,7 +6897,7 @@ impl RRTypeSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &RRType,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -5667,13 +6974,119 @@ impl RequestIntervalSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ResettableElementName {
+    /// `FullyQualifiedDomainName`
+    FullyQualifiedDomainName,
+    /// `Regions`
+    Regions,
+    /// `ResourcePath`
+    ResourcePath,
+    /// `ChildHealthChecks`
+    ChildHealthChecks,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ResettableElementName {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResettableElementName::FullyQualifiedDomainName => "FullyQualifiedDomainName",
+            ResettableElementName::Regions => "Regions",
+            ResettableElementName::ResourcePath => "ResourcePath",
+            ResettableElementName::ChildHealthChecks => "ChildHealthChecks",
+            ResettableElementName::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ResettableElementName {
+    fn default() -> ResettableElementName {
+        ResettableElementName::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ResettableElementName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ResettableElementName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ResettableElementName {
+    fn from(value: &'a str) -> ResettableElementName {
+        match value {
+            "FullyQualifiedDomainName" => ResettableElementName::FullyQualifiedDomainName,
+            "Regions" => ResettableElementName::Regions,
+            "ResourcePath" => ResettableElementName::ResourcePath,
+            "ChildHealthChecks" => ResettableElementName::ChildHealthChecks,
+            _ => ResettableElementName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResettableElementName {
+    fn from(value: String) -> ResettableElementName {
+        match value.as_str() {
+            "FullyQualifiedDomainName" => ResettableElementName::FullyQualifiedDomainName,
+            "Regions" => ResettableElementName::Regions,
+            "ResourcePath" => ResettableElementName::ResourcePath,
+            "ChildHealthChecks" => ResettableElementName::ChildHealthChecks,
+            _ => ResettableElementName::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ResettableElementName {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ResettableElementName, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ResettableElementName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ResettableElementName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ResettableElementName {
+    fn deserialize<D>(deserializer: D) -> Result<ResettableElementName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+
 pub struct ResettableElementNameSerializer;
 impl ResettableElementNameSerializer {
     #[allow(unused_variables, warnings)]
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &ResettableElementName,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -5693,7 +7106,7 @@ impl ResettableElementNameListSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &Vec<String>,
+        obj: &Vec<ResettableElementName>,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -5846,7 +7259,7 @@ impl ResourceRecordSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Value"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.value
+            value = &obj.value
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::end_element())
@@ -5861,7 +7274,7 @@ pub struct ResourceRecordSet {
     /// <p><p> <i>Alias resource record sets only:</i> Information about the AWS resource, such as a CloudFront distribution or an Amazon S3 bucket, that you want to route traffic to. </p> <p>If you&#39;re creating resource records sets for a private hosted zone, note the following:</p> <ul> <li> <p>You can&#39;t create an alias resource record set in a private hosted zone to route traffic to a CloudFront distribution.</p> </li> <li> <p>Creating geolocation alias resource record sets or latency alias resource record sets in a private hosted zone is unsupported.</p> </li> <li> <p>For information about creating failover resource record sets in a private hosted zone, see <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/dns-failover-private-hosted-zones.html">Configuring Failover in a Private Hosted Zone</a> in the <i>Amazon Route 53 Developer Guide</i>.</p> </li> </ul></p>
     pub alias_target: Option<AliasTarget>,
     /// <p><p> <i>Failover resource record sets only:</i> To configure failover, you add the <code>Failover</code> element to two resource record sets. For one resource record set, you specify <code>PRIMARY</code> as the value for <code>Failover</code>; for the other resource record set, you specify <code>SECONDARY</code>. In addition, you include the <code>HealthCheckId</code> element and specify the health check that you want Amazon Route 53 to perform for each resource record set.</p> <p>Except where noted, the following failover behaviors assume that you have included the <code>HealthCheckId</code> element in both resource record sets:</p> <ul> <li> <p>When the primary resource record set is healthy, Route 53 responds to DNS queries with the applicable value from the primary resource record set regardless of the health of the secondary resource record set.</p> </li> <li> <p>When the primary resource record set is unhealthy and the secondary resource record set is healthy, Route 53 responds to DNS queries with the applicable value from the secondary resource record set.</p> </li> <li> <p>When the secondary resource record set is unhealthy, Route 53 responds to DNS queries with the applicable value from the primary resource record set regardless of the health of the primary resource record set.</p> </li> <li> <p>If you omit the <code>HealthCheckId</code> element for the secondary resource record set, and if the primary resource record set is unhealthy, Route 53 always responds to DNS queries with the applicable value from the secondary resource record set. This is true regardless of the health of the associated endpoint.</p> </li> </ul> <p>You can&#39;t create non-failover resource record sets that have the same values for the <code>Name</code> and <code>Type</code> elements as failover resource record sets.</p> <p>For failover alias resource record sets, you must also include the <code>EvaluateTargetHealth</code> element and set the value to true.</p> <p>For more information about configuring failover for Route 53, see the following topics in the <i>Amazon Route 53 Developer Guide</i>: </p> <ul> <li> <p> <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/dns-failover.html">Route 53 Health Checks and DNS Failover</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/dns-failover-private-hosted-zones.html">Configuring Failover in a Private Hosted Zone</a> </p> </li> </ul></p>
-    pub failover: Option<String>,
+    pub failover: Option<ResourceRecordSetFailover>,
     /// <p> <i>Geolocation resource record sets only:</i> A complex type that lets you control how Amazon Route 53 responds to DNS queries based on the geographic origin of the query. For example, if you want all queries from Africa to be routed to a web server with an IP address of <code>192.0.2.111</code>, create a resource record set with a <code>Type</code> of <code>A</code> and a <code>ContinentCode</code> of <code>AF</code>.</p> <note> <p>Creating geolocation and geolocation alias resource record sets in private hosted zones is not supported.</p> </note> <p>If you create separate resource record sets for overlapping geographic regions (for example, one resource record set for a continent and one for a country on the same continent), priority goes to the smallest geographic region. This allows you to route most queries for a continent to one resource and to route queries for a country on that continent to a different resource.</p> <p>You can't create two geolocation resource record sets that specify the same geographic location.</p> <p>The value <code>*</code> in the <code>CountryCode</code> element matches all geographic locations that aren't specified in other geolocation resource record sets that have the same values for the <code>Name</code> and <code>Type</code> elements.</p> <important> <p>Geolocation works by mapping IP addresses to locations. However, some IP addresses aren't mapped to geographic locations, so even if you create geolocation resource record sets that cover all seven continents, Route 53 will receive some DNS queries from locations that it can't identify. We recommend that you create a resource record set for which the value of <code>CountryCode</code> is <code>*</code>, which handles both queries that come from locations for which you haven't created geolocation resource record sets and queries from IP addresses that aren't mapped to a location. If you don't create a <code>*</code> resource record set, Route 53 returns a "no answer" response for queries from those locations.</p> </important> <p>You can't create non-geolocation resource record sets that have the same values for the <code>Name</code> and <code>Type</code> elements as geolocation resource record sets.</p>
     pub geo_location: Option<GeoLocation>,
     /// <p><p>If you want Amazon Route 53 to return this resource record set in response to a DNS query only when the status of a health check is healthy, include the <code>HealthCheckId</code> element and specify the ID of the applicable health check.</p> <p>Route 53 determines whether a resource record set is healthy based on one of the following:</p> <ul> <li> <p>By periodically sending a request to the endpoint that is specified in the health check</p> </li> <li> <p>By aggregating the status of a specified group of health checks (calculated health checks)</p> </li> <li> <p>By determining the current state of a CloudWatch alarm (CloudWatch metric health checks)</p> </li> </ul> <important> <p>Route 53 doesn&#39;t check the health of the endpoint that is specified in the resource record set, for example, the endpoint specified by the IP address in the <code>Value</code> element. When you add a <code>HealthCheckId</code> element to a resource record set, Route 53 checks the health of the endpoint that you specified in the health check. </p> </important> <p>For more information, see the following topics in the <i>Amazon Route 53 Developer Guide</i>:</p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/dns-failover-determining-health-of-endpoints.html">How Amazon Route 53 Determines Whether an Endpoint Is Healthy</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/dns-failover.html">Route 53 Health Checks and DNS Failover</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/dns-failover-private-hosted-zones.html">Configuring Failover in a Private Hosted Zone</a> </p> </li> </ul> <p> <b>When to Specify HealthCheckId</b> </p> <p>Specifying a value for <code>HealthCheckId</code> is useful only when Route 53 is choosing between two or more resource record sets to respond to a DNS query, and you want Route 53 to base the choice in part on the status of a health check. Configuring health checks makes sense only in the following configurations:</p> <ul> <li> <p> <b>Non-alias resource record sets</b>: You&#39;re checking the health of a group of non-alias resource record sets that have the same routing policy, name, and type (such as multiple weighted records named www.example.com with a type of A) and you specify health check IDs for all the resource record sets. </p> <p>If the health check status for a resource record set is healthy, Route 53 includes the record among the records that it responds to DNS queries with.</p> <p>If the health check status for a resource record set is unhealthy, Route 53 stops responding to DNS queries using the value for that resource record set.</p> <p>If the health check status for all resource record sets in the group is unhealthy, Route 53 considers all resource record sets in the group healthy and responds to DNS queries accordingly. </p> </li> <li> <p> <b>Alias resource record sets</b>: You specify the following settings:</p> <ul> <li> <p>You set <code>EvaluateTargetHealth</code> to true for an alias resource record set in a group of resource record sets that have the same routing policy, name, and type (such as multiple weighted records named www.example.com with a type of A). </p> </li> <li> <p>You configure the alias resource record set to route traffic to a non-alias resource record set in the same hosted zone.</p> </li> <li> <p>You specify a health check ID for the non-alias resource record set. </p> </li> </ul> <p>If the health check status is healthy, Route 53 considers the alias resource record set to be healthy and includes the alias record among the records that it responds to DNS queries with.</p> <p>If the health check status is unhealthy, Route 53 stops responding to DNS queries using the alias resource record set.</p> <note> <p>The alias resource record set can also route traffic to a <i>group</i> of non-alias resource record sets that have the same routing policy, name, and type. In that configuration, associate health checks with all of the resource record sets in the group of non-alias resource record sets.</p> </note> </li> </ul> <p> <b>Geolocation Routing</b> </p> <p>For geolocation resource record sets, if an endpoint is unhealthy, Route 53 looks for a resource record set for the larger, associated geographic region. For example, suppose you have resource record sets for a state in the United States, for the entire United States, for North America, and a resource record set that has <code><em></code> for <code>CountryCode</code> is <code></em></code>, which applies to all locations. If the endpoint for the state resource record set is unhealthy, Route 53 checks for healthy resource record sets in the following order until it finds a resource record set for which the endpoint is healthy:</p> <ul> <li> <p>The United States</p> </li> <li> <p>North America</p> </li> <li> <p>The default resource record set</p> </li> </ul> <p> <b>Specifying the Health Check Endpoint by Domain Name</b> </p> <p>If your health checks specify the endpoint only by domain name, we recommend that you create a separate health check for each endpoint. For example, create a health check for each <code>HTTP</code> server that is serving content for <code>www.example.com</code>. For the value of <code>FullyQualifiedDomainName</code>, specify the domain name of the server (such as <code>us-east-2-www.example.com</code>), not the name of the resource record sets (<code>www.example.com</code>).</p> <important> <p>Health check results will be unpredictable if you do the following:</p> <ul> <li> <p>Create a health check that has the same value for <code>FullyQualifiedDomainName</code> as the name of a resource record set.</p> </li> <li> <p>Associate that health check with the resource record set.</p> </li> </ul> </important></p>
@@ -5871,7 +7284,7 @@ pub struct ResourceRecordSet {
     /// <p>For <code>ChangeResourceRecordSets</code> requests, the name of the record that you want to create, update, or delete. For <code>ListResourceRecordSets</code> responses, the name of a record in the specified hosted zone.</p> <p> <b>ChangeResourceRecordSets Only</b> </p> <p>Enter a fully qualified domain name, for example, <code>www.example.com</code>. You can optionally include a trailing dot. If you omit the trailing dot, Amazon Route 53 assumes that the domain name that you specify is fully qualified. This means that Route 53 treats <code>www.example.com</code> (without a trailing dot) and <code>www.example.com.</code> (with a trailing dot) as identical.</p> <p>For information about how to specify characters other than <code>a-z</code>, <code>0-9</code>, and <code>-</code> (hyphen) and how to specify internationalized domain names, see <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/DomainNameFormat.html">DNS Domain Name Format</a> in the <i>Amazon Route 53 Developer Guide</i>.</p> <p>You can use the asterisk (*) wildcard to replace the leftmost label in a domain name, for example, <code>*.example.com</code>. Note the following:</p> <ul> <li> <p>The * must replace the entire label. For example, you can't specify <code>*prod.example.com</code> or <code>prod*.example.com</code>.</p> </li> <li> <p>The * can't replace any of the middle labels, for example, marketing.*.example.com.</p> </li> <li> <p>If you include * in any position other than the leftmost label in a domain name, DNS treats it as an * character (ASCII 42), not as a wildcard.</p> <important> <p>You can't use the * wildcard for resource records sets that have a type of NS.</p> </important> </li> </ul> <p>You can use the * wildcard as the leftmost label in a domain name, for example, <code>*.example.com</code>. You can't use an * for one of the middle labels, for example, <code>marketing.*.example.com</code>. In addition, the * must replace the entire label; for example, you can't specify <code>prod*.example.com</code>.</p>
     pub name: String,
     /// <p><p> <i>Latency-based resource record sets only:</i> The Amazon EC2 Region where you created the resource that this resource record set refers to. The resource typically is an AWS resource, such as an EC2 instance or an ELB load balancer, and is referred to by an IP address or a DNS domain name, depending on the record type.</p> <note> <p>Creating latency and latency alias resource record sets in private hosted zones is not supported.</p> </note> <p>When Amazon Route 53 receives a DNS query for a domain name and type for which you have created latency resource record sets, Route 53 selects the latency resource record set that has the lowest latency between the end user and the associated Amazon EC2 Region. Route 53 then returns the value that is associated with the selected resource record set.</p> <p>Note the following:</p> <ul> <li> <p>You can only specify one <code>ResourceRecord</code> per latency resource record set.</p> </li> <li> <p>You can only create one latency resource record set for each Amazon EC2 Region.</p> </li> <li> <p>You aren&#39;t required to create latency resource record sets for all Amazon EC2 Regions. Route 53 will choose the region with the best latency from among the regions that you create latency resource record sets for.</p> </li> <li> <p>You can&#39;t create non-latency resource record sets that have the same values for the <code>Name</code> and <code>Type</code> elements as latency resource record sets.</p> </li> </ul></p>
-    pub region: Option<String>,
+    pub region: Option<ResourceRecordSetRegion>,
     /// <p><p>Information about the resource records to act upon.</p> <note> <p>If you&#39;re creating an alias resource record set, omit <code>ResourceRecords</code>.</p> </note></p>
     pub resource_records: Option<Vec<ResourceRecord>>,
     /// <p> <i>Resource record sets that have a routing policy other than simple:</i> An identifier that differentiates among multiple resource record sets that have the same combination of name and type, such as multiple weighted resource record sets named acme.example.com that have a type of A. In a group of resource record sets that have the same name and type, the value of <code>SetIdentifier</code> must be unique for each resource record set. </p> <p>For information about routing policies, see <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/routing-policy.html">Choosing a Routing Policy</a> in the <i>Amazon Route 53 Developer Guide</i>.</p>
@@ -5881,7 +7294,7 @@ pub struct ResourceRecordSet {
     /// <p><p>When you create a traffic policy instance, Amazon Route 53 automatically creates a resource record set. <code>TrafficPolicyInstanceId</code> is the ID of the traffic policy instance that Route 53 created this resource record set for.</p> <important> <p>To delete the resource record set that is associated with a traffic policy instance, use <code>DeleteTrafficPolicyInstance</code>. Route 53 will delete the resource record set automatically. If you delete the resource record set by using <code>ChangeResourceRecordSets</code>, Route 53 doesn&#39;t automatically delete the traffic policy instance, and you&#39;ll continue to be charged for it even though it&#39;s no longer in use. </p> </important></p>
     pub traffic_policy_instance_id: Option<String>,
     /// <p><p>The DNS record type. For information about different record types and how data is encoded for them, see <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/ResourceRecordTypes.html">Supported DNS Resource Record Types</a> in the <i>Amazon Route 53 Developer Guide</i>.</p> <p>Valid values for basic resource record sets: <code>A</code> | <code>AAAA</code> | <code>CAA</code> | <code>CNAME</code> | <code>MX</code> | <code>NAPTR</code> | <code>NS</code> | <code>PTR</code> | <code>SOA</code> | <code>SPF</code> | <code>SRV</code> | <code>TXT</code> </p> <p>Values for weighted, latency, geolocation, and failover resource record sets: <code>A</code> | <code>AAAA</code> | <code>CAA</code> | <code>CNAME</code> | <code>MX</code> | <code>NAPTR</code> | <code>PTR</code> | <code>SPF</code> | <code>SRV</code> | <code>TXT</code>. When creating a group of weighted, latency, geolocation, or failover resource record sets, specify the same value for all of the resource record sets in the group.</p> <p>Valid values for multivalue answer resource record sets: <code>A</code> | <code>AAAA</code> | <code>MX</code> | <code>NAPTR</code> | <code>PTR</code> | <code>SPF</code> | <code>SRV</code> | <code>TXT</code> </p> <note> <p>SPF records were formerly used to verify the identity of the sender of email messages. However, we no longer recommend that you create resource record sets for which the value of <code>Type</code> is <code>SPF</code>. RFC 7208, <i>Sender Policy Framework (SPF) for Authorizing Use of Domains in Email, Version 1</i>, has been updated to say, &quot;...[I]ts existence and mechanism defined in [RFC4408] have led to some interoperability issues. Accordingly, its use is no longer appropriate for SPF version 1; implementations are not to use it.&quot; In RFC 7208, see section 14.1, <a href="http://tools.ietf.org/html/rfc7208#section-14.1">The SPF DNS Record Type</a>.</p> </note> <p>Values for alias resource record sets:</p> <ul> <li> <p> <b>Amazon API Gateway custom regional APIs and edge-optimized APIs:</b> <code>A</code> </p> </li> <li> <p> <b>CloudFront distributions:</b> <code>A</code> </p> <p>If IPv6 is enabled for the distribution, create two resource record sets to route traffic to your distribution, one with a value of <code>A</code> and one with a value of <code>AAAA</code>. </p> </li> <li> <p> <b>AWS Elastic Beanstalk environment that has a regionalized subdomain</b>: <code>A</code> </p> </li> <li> <p> <b>ELB load balancers:</b> <code>A</code> | <code>AAAA</code> </p> </li> <li> <p> <b>Amazon S3 buckets:</b> <code>A</code> </p> </li> <li> <p> <b>Amazon Virtual Private Cloud interface VPC endpoints</b> <code>A</code> </p> </li> <li> <p> <b>Another resource record set in this hosted zone:</b> Specify the type of the resource record set that you&#39;re creating the alias for. All values are supported except <code>NS</code> and <code>SOA</code>.</p> <note> <p>If you&#39;re creating an alias record that has the same name as the hosted zone (known as the zone apex), you can&#39;t route traffic to a record for which the value of <code>Type</code> is <code>CNAME</code>. This is because the alias record must have the same type as the record you&#39;re routing traffic to, and creating a CNAME record for the zone apex isn&#39;t supported even for an alias record.</p> </note> </li> </ul></p>
-    pub type_: String,
+    pub type_: RRType,
     /// <p><p> <i>Weighted resource record sets only:</i> Among resource record sets that have the same combination of DNS name and type, a value that determines the proportion of DNS queries that Amazon Route 53 responds to using the current resource record set. Route 53 calculates the sum of the weights for the resource record sets that have the same combination of DNS name and type. Route 53 then responds to queries based on the ratio of a resource&#39;s weight to the total. Note the following:</p> <ul> <li> <p>You must specify a value for the <code>Weight</code> element for every weighted resource record set.</p> </li> <li> <p>You can only specify one <code>ResourceRecord</code> per weighted resource record set.</p> </li> <li> <p>You can&#39;t create latency, failover, or geolocation resource record sets that have the same values for the <code>Name</code> and <code>Type</code> elements as weighted resource record sets.</p> </li> <li> <p>You can create a maximum of 100 weighted resource record sets that have the same values for the <code>Name</code> and <code>Type</code> elements.</p> </li> <li> <p>For weighted (but not weighted alias) resource record sets, if you set <code>Weight</code> to <code>0</code> for a resource record set, Route 53 never responds to queries with the applicable value for that resource record set. However, if you set <code>Weight</code> to <code>0</code> for all resource record sets that have the same combination of DNS name and type, traffic is routed to all resources with equal probability.</p> <p>The effect of setting <code>Weight</code> to <code>0</code> is different when you associate health checks with weighted resource record sets. For more information, see <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/dns-failover-configuring-options.html">Options for Configuring Route 53 Active-Active and Active-Passive Failover</a> in the <i>Amazon Route 53 Developer Guide</i>.</p> </li> </ul></p>
     pub weight: Option<i64>,
 }
@@ -6011,7 +7424,7 @@ impl ResourceRecordSetSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Name"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.name
+            value = &obj.name
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         if let Some(ref value) = obj.region {
@@ -6054,7 +7467,7 @@ impl ResourceRecordSetSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Type"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.type_
+            value = &obj.type_
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         if let Some(ref value) = obj.weight {
@@ -6069,12 +7482,110 @@ impl ResourceRecordSetSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ResourceRecordSetFailover {
+    /// `PRIMARY`
+    Primary,
+    /// `SECONDARY`
+    Secondary,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ResourceRecordSetFailover {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceRecordSetFailover::Primary => "PRIMARY",
+            ResourceRecordSetFailover::Secondary => "SECONDARY",
+            ResourceRecordSetFailover::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ResourceRecordSetFailover {
+    fn default() -> ResourceRecordSetFailover {
+        ResourceRecordSetFailover::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ResourceRecordSetFailover {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ResourceRecordSetFailover {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ResourceRecordSetFailover {
+    fn from(value: &'a str) -> ResourceRecordSetFailover {
+        match value {
+            "PRIMARY" => ResourceRecordSetFailover::Primary,
+            "SECONDARY" => ResourceRecordSetFailover::Secondary,
+            _ => ResourceRecordSetFailover::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceRecordSetFailover {
+    fn from(value: String) -> ResourceRecordSetFailover {
+        match value.as_str() {
+            "PRIMARY" => ResourceRecordSetFailover::Primary,
+            "SECONDARY" => ResourceRecordSetFailover::Secondary,
+            _ => ResourceRecordSetFailover::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ResourceRecordSetFailover {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ResourceRecordSetFailover, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ResourceRecordSetFailover {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ResourceRecordSetFailover {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ResourceRecordSetFailover {
+    fn deserialize<D>(deserializer: D) -> Result<ResourceRecordSetFailover, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct ResourceRecordSetFailoverDeserializer;
 impl ResourceRecordSetFailoverDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ResourceRecordSetFailover, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -6087,7 +7598,7 @@ impl ResourceRecordSetFailoverSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &ResourceRecordSetFailover,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -6164,13 +7675,256 @@ impl ResourceRecordSetMultiValueAnswerSerializer {
         writer.write(xml::writer::XmlEvent::end_element())
     }
 }
-
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ResourceRecordSetRegion {
+    /// `us-east-1`
+    UsEast1,
+    /// `us-east-2`
+    UsEast2,
+    /// `us-west-1`
+    UsWest1,
+    /// `us-west-2`
+    UsWest2,
+    /// `ca-central-1`
+    CaCentral1,
+    /// `eu-west-1`
+    EuWest1,
+    /// `eu-west-2`
+    EuWest2,
+    /// `eu-west-3`
+    EuWest3,
+    /// `eu-central-1`
+    EuCentral1,
+    /// `eu-central-2`
+    EuCentral2,
+    /// `ap-southeast-1`
+    ApSoutheast1,
+    /// `ap-southeast-2`
+    ApSoutheast2,
+    /// `ap-southeast-3`
+    ApSoutheast3,
+    /// `ap-northeast-1`
+    ApNortheast1,
+    /// `ap-northeast-2`
+    ApNortheast2,
+    /// `ap-northeast-3`
+    ApNortheast3,
+    /// `eu-north-1`
+    EuNorth1,
+    /// `sa-east-1`
+    SaEast1,
+    /// `cn-north-1`
+    CnNorth1,
+    /// `cn-northwest-1`
+    CnNorthwest1,
+    /// `ap-east-1`
+    ApEast1,
+    /// `me-south-1`
+    MeSouth1,
+    /// `me-central-1`
+    MeCentral1,
+    /// `ap-south-1`
+    ApSouth1,
+    /// `ap-south-2`
+    ApSouth2,
+    /// `af-south-1`
+    AfSouth1,
+    /// `eu-south-1`
+    EuSouth1,
+    /// `eu-south-2`
+    EuSouth2,
+    /// `ap-southeast-4`
+    ApSoutheast4,
+    /// `il-central-1`
+    IlCentral1,
+    /// `ca-west-1`
+    CaWest1,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ResourceRecordSetRegion {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceRecordSetRegion::UsEast1 => "us-east-1",
+            ResourceRecordSetRegion::UsEast2 => "us-east-2",
+            ResourceRecordSetRegion::UsWest1 => "us-west-1",
+            ResourceRecordSetRegion::UsWest2 => "us-west-2",
+            ResourceRecordSetRegion::CaCentral1 => "ca-central-1",
+            ResourceRecordSetRegion::EuWest1 => "eu-west-1",
+            ResourceRecordSetRegion::EuWest2 => "eu-west-2",
+            ResourceRecordSetRegion::EuWest3 => "eu-west-3",
+            ResourceRecordSetRegion::EuCentral1 => "eu-central-1",
+            ResourceRecordSetRegion::EuCentral2 => "eu-central-2",
+            ResourceRecordSetRegion::ApSoutheast1 => "ap-southeast-1",
+            ResourceRecordSetRegion::ApSoutheast2 => "ap-southeast-2",
+            ResourceRecordSetRegion::ApSoutheast3 => "ap-southeast-3",
+            ResourceRecordSetRegion::ApNortheast1 => "ap-northeast-1",
+            ResourceRecordSetRegion::ApNortheast2 => "ap-northeast-2",
+            ResourceRecordSetRegion::ApNortheast3 => "ap-northeast-3",
+            ResourceRecordSetRegion::EuNorth1 => "eu-north-1",
+            ResourceRecordSetRegion::SaEast1 => "sa-east-1",
+            ResourceRecordSetRegion::CnNorth1 => "cn-north-1",
+            ResourceRecordSetRegion::CnNorthwest1 => "cn-northwest-1",
+            ResourceRecordSetRegion::ApEast1 => "ap-east-1",
+            ResourceRecordSetRegion::MeSouth1 => "me-south-1",
+            ResourceRecordSetRegion::MeCentral1 => "me-central-1",
+            ResourceRecordSetRegion::ApSouth1 => "ap-south-1",
+            ResourceRecordSetRegion::ApSouth2 => "ap-south-2",
+            ResourceRecordSetRegion::AfSouth1 => "af-south-1",
+            ResourceRecordSetRegion::EuSouth1 => "eu-south-1",
+            ResourceRecordSetRegion::EuSouth2 => "eu-south-2",
+            ResourceRecordSetRegion::ApSoutheast4 => "ap-southeast-4",
+            ResourceRecordSetRegion::IlCentral1 => "il-central-1",
+            ResourceRecordSetRegion::CaWest1 => "ca-west-1",
+            ResourceRecordSetRegion::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ResourceRecordSetRegion {
+    fn default() -> ResourceRecordSetRegion {
+        ResourceRecordSetRegion::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ResourceRecordSetRegion {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ResourceRecordSetRegion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ResourceRecordSetRegion {
+    fn from(value: &'a str) -> ResourceRecordSetRegion {
+        match value {
+            "us-east-1" => ResourceRecordSetRegion::UsEast1,
+            "us-east-2" => ResourceRecordSetRegion::UsEast2,
+            "us-west-1" => ResourceRecordSetRegion::UsWest1,
+            "us-west-2" => ResourceRecordSetRegion::UsWest2,
+            "ca-central-1" => ResourceRecordSetRegion::CaCentral1,
+            "eu-west-1" => ResourceRecordSetRegion::EuWest1,
+            "eu-west-2" => ResourceRecordSetRegion::EuWest2,
+            "eu-west-3" => ResourceRecordSetRegion::EuWest3,
+            "eu-central-1" => ResourceRecordSetRegion::EuCentral1,
+            "eu-central-2" => ResourceRecordSetRegion::EuCentral2,
+            "ap-southeast-1" => ResourceRecordSetRegion::ApSoutheast1,
+            "ap-southeast-2" => ResourceRecordSetRegion::ApSoutheast2,
+            "ap-southeast-3" => ResourceRecordSetRegion::ApSoutheast3,
+            "ap-northeast-1" => ResourceRecordSetRegion::ApNortheast1,
+            "ap-northeast-2" => ResourceRecordSetRegion::ApNortheast2,
+            "ap-northeast-3" => ResourceRecordSetRegion::ApNortheast3,
+            "eu-north-1" => ResourceRecordSetRegion::EuNorth1,
+            "sa-east-1" => ResourceRecordSetRegion::SaEast1,
+            "cn-north-1" => ResourceRecordSetRegion::CnNorth1,
+            "cn-northwest-1" => ResourceRecordSetRegion::CnNorthwest1,
+            "ap-east-1" => ResourceRecordSetRegion::ApEast1,
+            "me-south-1" => ResourceRecordSetRegion::MeSouth1,
+            "me-central-1" => ResourceRecordSetRegion::MeCentral1,
+            "ap-south-1" => ResourceRecordSetRegion::ApSouth1,
+            "ap-south-2" => ResourceRecordSetRegion::ApSouth2,
+            "af-south-1" => ResourceRecordSetRegion::AfSouth1,
+            "eu-south-1" => ResourceRecordSetRegion::EuSouth1,
+            "eu-south-2" => ResourceRecordSetRegion::EuSouth2,
+            "ap-southeast-4" => ResourceRecordSetRegion::ApSoutheast4,
+            "il-central-1" => ResourceRecordSetRegion::IlCentral1,
+            "ca-west-1" => ResourceRecordSetRegion::CaWest1,
+            _ => ResourceRecordSetRegion::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceRecordSetRegion {
+    fn from(value: String) -> ResourceRecordSetRegion {
+        match value.as_str() {
+            "us-east-1" => ResourceRecordSetRegion::UsEast1,
+            "us-east-2" => ResourceRecordSetRegion::UsEast2,
+            "us-west-1" => ResourceRecordSetRegion::UsWest1,
+            "us-west-2" => ResourceRecordSetRegion::UsWest2,
+            "ca-central-1" => ResourceRecordSetRegion::CaCentral1,
+            "eu-west-1" => ResourceRecordSetRegion::EuWest1,
+            "eu-west-2" => ResourceRecordSetRegion::EuWest2,
+            "eu-west-3" => ResourceRecordSetRegion::EuWest3,
+            "eu-central-1" => ResourceRecordSetRegion::EuCentral1,
+            "eu-central-2" => ResourceRecordSetRegion::EuCentral2,
+            "ap-southeast-1" => ResourceRecordSetRegion::ApSoutheast1,
+            "ap-southeast-2" => ResourceRecordSetRegion::ApSoutheast2,
+            "ap-southeast-3" => ResourceRecordSetRegion::ApSoutheast3,
+            "ap-northeast-1" => ResourceRecordSetRegion::ApNortheast1,
+            "ap-northeast-2" => ResourceRecordSetRegion::ApNortheast2,
+            "ap-northeast-3" => ResourceRecordSetRegion::ApNortheast3,
+            "eu-north-1" => ResourceRecordSetRegion::EuNorth1,
+            "sa-east-1" => ResourceRecordSetRegion::SaEast1,
+            "cn-north-1" => ResourceRecordSetRegion::CnNorth1,
+            "cn-northwest-1" => ResourceRecordSetRegion::CnNorthwest1,
+            "ap-east-1" => ResourceRecordSetRegion::ApEast1,
+            "me-south-1" => ResourceRecordSetRegion::MeSouth1,
+            "me-central-1" => ResourceRecordSetRegion::MeCentral1,
+            "ap-south-1" => ResourceRecordSetRegion::ApSouth1,
+            "ap-south-2" => ResourceRecordSetRegion::ApSouth2,
+            "af-south-1" => ResourceRecordSetRegion::AfSouth1,
+            "eu-south-1" => ResourceRecordSetRegion::EuSouth1,
+            "eu-south-2" => ResourceRecordSetRegion::EuSouth2,
+            "ap-southeast-4" => ResourceRecordSetRegion::ApSoutheast4,
+            "il-central-1" => ResourceRecordSetRegion::IlCentral1,
+            "ca-west-1" => ResourceRecordSetRegion::CaWest1,
+            _ => ResourceRecordSetRegion::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ResourceRecordSetRegion {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ResourceRecordSetRegion, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ResourceRecordSetRegion {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ResourceRecordSetRegion {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ResourceRecordSetRegion {
+    fn deserialize<D>(deserializer: D) -> Result<ResourceRecordSetRegion, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct ResourceRecordSetRegionDeserializer;
 impl ResourceRecordSetRegionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ResourceRecordSetRegion, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -6183,7 +7937,7 @@ impl ResourceRecordSetRegionSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &ResourceRecordSetRegion,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -6297,7 +8051,7 @@ pub struct ResourceTagSet {
     /// <p>The ID for the specified resource.</p>
     pub resource_id: Option<String>,
     /// <p><p>The type of the resource.</p> <ul> <li> <p>The resource type for health checks is <code>healthcheck</code>.</p> </li> <li> <p>The resource type for hosted zones is <code>hostedzone</code>.</p> </li> </ul></p>
-    pub resource_type: Option<String>,
+    pub resource_type: Option<TagResourceType>,
     /// <p>The tags associated with the specified resource.</p>
     pub tags: Option<Vec<Tag>>,
 }
@@ -6357,7 +8111,7 @@ impl ResourceTagSetListDeserializer {
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct ReusableDelegationSetLimit {
     /// <p>The limit that you requested: <code>MAX_ZONES_BY_REUSABLE_DELEGATION_SET</code>, the maximum number of hosted zones that you can associate with the specified reusable delegation set.</p>
-    pub type_: String,
+    pub type_: ReusableDelegationSetLimitType,
     /// <p>The current value for the <code>MAX_ZONES_BY_REUSABLE_DELEGATION_SET</code> limit.</p>
     pub value: i64,
 }
@@ -6388,12 +8142,111 @@ impl ReusableDelegationSetLimitDeserializer {
         )
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ReusableDelegationSetLimitType {
+    /// `MAX_ZONES_BY_REUSABLE_DELEGATION_SET`
+    MaxZonesByReusableDelegationSet,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ReusableDelegationSetLimitType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReusableDelegationSetLimitType::MaxZonesByReusableDelegationSet => {
+                "MAX_ZONES_BY_REUSABLE_DELEGATION_SET"
+            }
+            ReusableDelegationSetLimitType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ReusableDelegationSetLimitType {
+    fn default() -> ReusableDelegationSetLimitType {
+        ReusableDelegationSetLimitType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ReusableDelegationSetLimitType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ReusableDelegationSetLimitType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ReusableDelegationSetLimitType {
+    fn from(value: &'a str) -> ReusableDelegationSetLimitType {
+        match value {
+            "MAX_ZONES_BY_REUSABLE_DELEGATION_SET" => {
+                ReusableDelegationSetLimitType::MaxZonesByReusableDelegationSet
+            }
+            _ => ReusableDelegationSetLimitType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReusableDelegationSetLimitType {
+    fn from(value: String) -> ReusableDelegationSetLimitType {
+        match value.as_str() {
+            "MAX_ZONES_BY_REUSABLE_DELEGATION_SET" => {
+                ReusableDelegationSetLimitType::MaxZonesByReusableDelegationSet
+            }
+            _ => ReusableDelegationSetLimitType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ReusableDelegationSetLimitType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ReusableDelegationSetLimitType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ReusableDelegationSetLimitType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ReusableDelegationSetLimitType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ReusableDelegationSetLimitType {
+    fn deserialize<D>(deserializer: D) -> Result<ReusableDelegationSetLimitType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct ReusableDelegationSetLimitTypeDeserializer;
 impl ReusableDelegationSetLimitTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ReusableDelegationSetLimitType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -6406,7 +8259,7 @@ impl ReusableDelegationSetLimitTypeSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &ReusableDelegationSetLimitType,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -6463,12 +8316,125 @@ impl ServicePrincipalDeserializer {
         Ok(obj)
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Statistic {
+    /// `Average`
+    Average,
+    /// `Sum`
+    Sum,
+    /// `SampleCount`
+    SampleCount,
+    /// `Maximum`
+    Maximum,
+    /// `Minimum`
+    Minimum,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Statistic {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Statistic::Average => "Average",
+            Statistic::Sum => "Sum",
+            Statistic::SampleCount => "SampleCount",
+            Statistic::Maximum => "Maximum",
+            Statistic::Minimum => "Minimum",
+            Statistic::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Statistic {
+    fn default() -> Statistic {
+        Statistic::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Statistic {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Statistic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Statistic {
+    fn from(value: &'a str) -> Statistic {
+        match value {
+            "Average" => Statistic::Average,
+            "Sum" => Statistic::Sum,
+            "SampleCount" => Statistic::SampleCount,
+            "Maximum" => Statistic::Maximum,
+            "Minimum" => Statistic::Minimum,
+            _ => Statistic::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Statistic {
+    fn from(value: String) -> Statistic {
+        match value.as_str() {
+            "Average" => Statistic::Average,
+            "Sum" => Statistic::Sum,
+            "SampleCount" => Statistic::SampleCount,
+            "Maximum" => Statistic::Maximum,
+            "Minimum" => Statistic::Minimum,
+            _ => Statistic::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Statistic {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Statistic, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Statistic {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for Statistic {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for Statistic {
+    fn deserialize<D>(deserializer: D) -> Result<Statistic, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct StatisticDeserializer;
 impl StatisticDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Statistic, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -6490,7 +8456,7 @@ impl StatusDeserializer {
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct StatusReport {
     /// <p>The date and time that the health checker performed the health check in <a href="https://en.wikipedia.org/wiki/ISO_8601">ISO 8601 format</a> and Coordinated Universal Time (UTC). For example, the value <code>2017-03-27T17:48:16.751Z</code> represents March 27, 2017 at 17:48:16.751 UTC.</p>
-    pub checked_time: Option<String>,
+    pub checked_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A description of the status of the health check endpoint as reported by one of the Amazon Route 53 health checkers.</p>
     pub status: Option<String>,
 }
@@ -6774,12 +8740,110 @@ impl TagResourceIdListSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TagResourceType {
+    /// `healthcheck`
+    Healthcheck,
+    /// `hostedzone`
+    Hostedzone,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TagResourceType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TagResourceType::Healthcheck => "healthcheck",
+            TagResourceType::Hostedzone => "hostedzone",
+            TagResourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TagResourceType {
+    fn default() -> TagResourceType {
+        TagResourceType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TagResourceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TagResourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TagResourceType {
+    fn from(value: &'a str) -> TagResourceType {
+        match value {
+            "healthcheck" => TagResourceType::Healthcheck,
+            "hostedzone" => TagResourceType::Hostedzone,
+            _ => TagResourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TagResourceType {
+    fn from(value: String) -> TagResourceType {
+        match value.as_str() {
+            "healthcheck" => TagResourceType::Healthcheck,
+            "hostedzone" => TagResourceType::Hostedzone,
+            _ => TagResourceType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TagResourceType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TagResourceType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TagResourceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for TagResourceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for TagResourceType {
+    fn deserialize<D>(deserializer: D) -> Result<TagResourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct TagResourceTypeDeserializer;
 impl TagResourceTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<TagResourceType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -6792,7 +8856,7 @@ impl TagResourceTypeSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &TagResourceType,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -6851,7 +8915,7 @@ pub struct TestDNSAnswerRequest {
     /// <p>The name of the resource record set that you want Amazon Route 53 to simulate a query for.</p>
     pub record_name: String,
     /// <p>The type of the resource record set.</p>
-    pub record_type: String,
+    pub record_type: RRType,
     /// <p>If you want to simulate a request from a specific DNS resolver, specify the IP address for that resolver. If you omit this value, <code>TestDnsAnswer</code> uses the IP address of a DNS resolver in the AWS US East (N. Virginia) Region (<code>us-east-1</code>).</p>
     pub resolver_ip: Option<String>,
 }
@@ -6869,7 +8933,7 @@ pub struct TestDNSAnswerResponse {
     /// <p>The name of the resource record set that you submitted a request for.</p>
     pub record_name: String,
     /// <p>The type of the resource record set that you submitted a request for.</p>
-    pub record_type: String,
+    pub record_type: RRType,
     /// <p>A code that indicates whether the request is valid or not. The most common response code is <code>NOERROR</code>, meaning that the request is valid. If the response is not valid, Amazon Route 53 returns a response code that describes the error. For a list of possible response codes, see <a href="http://www.iana.org/assignments/dns-parameters/dns-parameters.xhtml#dns-parameters-6">DNS RCODES</a> on the IANA website. </p>
     pub response_code: String,
 }
@@ -6922,9 +8986,12 @@ impl ThresholdDeserializer {
 struct TimeStampDeserializer;
 impl TimeStampDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<::chrono::DateTime<::chrono::Utc>, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::proto::xml::util::timestamp_characters(stack)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -6963,7 +9030,7 @@ pub struct TrafficPolicy {
     /// <p>The name that you specified when you created the traffic policy.</p>
     pub name: String,
     /// <p>The DNS type of the resource record sets that Amazon Route 53 creates when you use a traffic policy to create a traffic policy instance.</p>
-    pub type_: String,
+    pub type_: RRType,
     /// <p>The version number that Amazon Route 53 assigns to a traffic policy. For a new traffic policy, the value of <code>Version</code> is always 1.</p>
     pub version: i64,
 }
@@ -7119,7 +9186,7 @@ pub struct TrafficPolicyInstance {
     /// <p>The ID of the traffic policy that Amazon Route 53 used to create resource record sets in the specified hosted zone.</p>
     pub traffic_policy_id: String,
     /// <p>The DNS type that Amazon Route 53 assigned to all of the resource record sets that it created for this traffic policy instance. </p>
-    pub traffic_policy_type: String,
+    pub traffic_policy_type: RRType,
     /// <p>The version of the traffic policy that Amazon Route 53 used to create resource record sets in the specified hosted zone.</p>
     pub traffic_policy_version: i64,
 }
@@ -7312,7 +9379,7 @@ pub struct TrafficPolicySummary {
     /// <p>The number of traffic policies that are associated with the current AWS account.</p>
     pub traffic_policy_count: i64,
     /// <p>The DNS type of the resource record sets that Amazon Route 53 creates when you use a traffic policy to create a traffic policy instance.</p>
-    pub type_: String,
+    pub type_: RRType,
 }
 
 struct TrafficPolicySummaryDeserializer;
@@ -7447,15 +9514,15 @@ pub struct UpdateHealthCheckRequest {
     /// <p><p>The IPv4 or IPv6 IP address for the endpoint that you want Amazon Route 53 to perform health checks on. If you don&#39;t specify a value for <code>IPAddress</code>, Route 53 sends a DNS request to resolve the domain name that you specify in <code>FullyQualifiedDomainName</code> at the interval that you specify in <code>RequestInterval</code>. Using an IP address that is returned by DNS, Route 53 then checks the health of the endpoint.</p> <p>Use one of the following formats for the value of <code>IPAddress</code>: </p> <ul> <li> <p> <b>IPv4 address</b>: four values between 0 and 255, separated by periods (.), for example, <code>192.0.2.44</code>.</p> </li> <li> <p> <b>IPv6 address</b>: eight groups of four hexadecimal values, separated by colons (:), for example, <code>2001:0db8:85a3:0000:0000:abcd:0001:2345</code>. You can also shorten IPv6 addresses as described in RFC 5952, for example, <code>2001:db8:85a3::abcd:1:2345</code>.</p> </li> </ul> <p>If the endpoint is an EC2 instance, we recommend that you create an Elastic IP address, associate it with your EC2 instance, and specify the Elastic IP address for <code>IPAddress</code>. This ensures that the IP address of your instance never changes. For more information, see the applicable documentation:</p> <ul> <li> <p>Linux: <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/elastic-ip-addresses-eip.html">Elastic IP Addresses (EIP)</a> in the <i>Amazon EC2 User Guide for Linux Instances</i> </p> </li> <li> <p>Windows: <a href="https://docs.aws.amazon.com/AWSEC2/latest/WindowsGuide/elastic-ip-addresses-eip.html">Elastic IP Addresses (EIP)</a> in the <i>Amazon EC2 User Guide for Windows Instances</i> </p> </li> </ul> <note> <p>If a health check already has a value for <code>IPAddress</code>, you can change the value. However, you can&#39;t update an existing health check to add or remove the value of <code>IPAddress</code>. </p> </note> <p>For more information, see <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_UpdateHealthCheck.html#Route53-UpdateHealthCheck-request-FullyQualifiedDomainName">FullyQualifiedDomainName</a>. </p> <p>Constraints: Route 53 can&#39;t check the health of endpoints for which the IP address is in local, private, non-routable, or multicast ranges. For more information about IP addresses for which you can&#39;t create health checks, see the following documents:</p> <ul> <li> <p> <a href="https://tools.ietf.org/html/rfc5735">RFC 5735, Special Use IPv4 Addresses</a> </p> </li> <li> <p> <a href="https://tools.ietf.org/html/rfc6598">RFC 6598, IANA-Reserved IPv4 Prefix for Shared Address Space</a> </p> </li> <li> <p> <a href="https://tools.ietf.org/html/rfc5156">RFC 5156, Special-Use IPv6 Addresses</a> </p> </li> </ul></p>
     pub ip_address: Option<String>,
     /// <p><p>When CloudWatch has insufficient data about the metric to determine the alarm state, the status that you want Amazon Route 53 to assign to the health check:</p> <ul> <li> <p> <code>Healthy</code>: Route 53 considers the health check to be healthy.</p> </li> <li> <p> <code>Unhealthy</code>: Route 53 considers the health check to be unhealthy.</p> </li> <li> <p> <code>LastKnownStatus</code>: Route 53 uses the status of the health check from the last time CloudWatch had sufficient data to determine the alarm state. For new health checks that have no last known status, the default status for the health check is healthy.</p> </li> </ul></p>
-    pub insufficient_data_health_status: Option<String>,
+    pub insufficient_data_health_status: Option<InsufficientDataHealthStatus>,
     /// <p>Specify whether you want Amazon Route 53 to invert the status of a health check, for example, to consider a health check unhealthy when it otherwise would be considered healthy.</p>
     pub inverted: Option<bool>,
     /// <p>The port on the endpoint on which you want Amazon Route 53 to perform health checks.</p>
     pub port: Option<i64>,
     /// <p>A complex type that contains one <code>Region</code> element for each region that you want Amazon Route 53 health checkers to check the specified endpoint from.</p>
-    pub regions: Option<Vec<String>>,
+    pub regions: Option<Vec<HealthCheckRegion>>,
     /// <p><p>A complex type that contains one <code>ResettableElementName</code> element for each element that you want to reset to the default value. Valid values for <code>ResettableElementName</code> include the following:</p> <ul> <li> <p> <code>ChildHealthChecks</code>: Amazon Route 53 resets <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_HealthCheckConfig.html#Route53-Type-HealthCheckConfig-ChildHealthChecks">ChildHealthChecks</a> to null.</p> </li> <li> <p> <code>FullyQualifiedDomainName</code>: Route 53 resets <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_UpdateHealthCheck.html#Route53-UpdateHealthCheck-request-FullyQualifiedDomainName">FullyQualifiedDomainName</a>. to null.</p> </li> <li> <p> <code>Regions</code>: Route 53 resets the <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_HealthCheckConfig.html#Route53-Type-HealthCheckConfig-Regions">Regions</a> list to the default set of regions. </p> </li> <li> <p> <code>ResourcePath</code>: Route 53 resets <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_HealthCheckConfig.html#Route53-Type-HealthCheckConfig-ResourcePath">ResourcePath</a> to null.</p> </li> </ul></p>
-    pub reset_elements: Option<Vec<String>>,
+    pub reset_elements: Option<Vec<ResettableElementName>>,
     /// <p>The path that you want Amazon Route 53 to request when performing health checks. The path can be any value for which your endpoint will return an HTTP status code of 2xx or 3xx when the endpoint is healthy, for example the file /docs/route53-health-check.html. You can also include query string parameters, for example, <code>/welcome.html?language=jp&amp;login=y</code>. </p> <p>Specify this value only if you want to change it.</p>
     pub resource_path: Option<String>,
     /// <p>If the value of <code>Type</code> is <code>HTTP_STR_MATCH</code> or <code>HTTP_STR_MATCH</code>, the string that you want Amazon Route 53 to search for in the response body from the specified resource. If the string appears in the response body, Route 53 considers the resource healthy. (You can't change the value of <code>Type</code> when you update a health check.)</p>
@@ -7777,7 +9844,7 @@ impl UsageCountDeserializer {
 pub struct VPC {
     pub vpc_id: Option<String>,
     /// <p>(Private hosted zones only) The region that an Amazon VPC was created in.</p>
-    pub vpc_region: Option<String>,
+    pub vpc_region: Option<VPCRegion>,
 }
 
 struct VPCDeserializer;
@@ -7863,12 +9930,275 @@ impl VPCIdSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum VPCRegion {
+    /// `us-east-1`
+    UsEast1,
+    /// `us-east-2`
+    UsEast2,
+    /// `us-west-1`
+    UsWest1,
+    /// `us-west-2`
+    UsWest2,
+    /// `eu-west-1`
+    EuWest1,
+    /// `eu-west-2`
+    EuWest2,
+    /// `eu-west-3`
+    EuWest3,
+    /// `eu-central-1`
+    EuCentral1,
+    /// `eu-central-2`
+    EuCentral2,
+    /// `ap-east-1`
+    ApEast1,
+    /// `me-south-1`
+    MeSouth1,
+    /// `us-gov-west-1`
+    UsGovWest1,
+    /// `us-gov-east-1`
+    UsGovEast1,
+    /// `us-iso-east-1`
+    UsIsoEast1,
+    /// `us-iso-west-1`
+    UsIsoWest1,
+    /// `us-isob-east-1`
+    UsIsobEast1,
+    /// `me-central-1`
+    MeCentral1,
+    /// `ap-southeast-1`
+    ApSoutheast1,
+    /// `ap-southeast-2`
+    ApSoutheast2,
+    /// `ap-southeast-3`
+    ApSoutheast3,
+    /// `ap-south-1`
+    ApSouth1,
+    /// `ap-south-2`
+    ApSouth2,
+    /// `ap-northeast-1`
+    ApNortheast1,
+    /// `ap-northeast-2`
+    ApNortheast2,
+    /// `ap-northeast-3`
+    ApNortheast3,
+    /// `eu-north-1`
+    EuNorth1,
+    /// `sa-east-1`
+    SaEast1,
+    /// `ca-central-1`
+    CaCentral1,
+    /// `cn-north-1`
+    CnNorth1,
+    /// `af-south-1`
+    AfSouth1,
+    /// `eu-south-1`
+    EuSouth1,
+    /// `eu-south-2`
+    EuSouth2,
+    /// `ap-southeast-4`
+    ApSoutheast4,
+    /// `il-central-1`
+    IlCentral1,
+    /// `ca-west-1`
+    CaWest1,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl VPCRegion {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            VPCRegion::UsEast1 => "us-east-1",
+            VPCRegion::UsEast2 => "us-east-2",
+            VPCRegion::UsWest1 => "us-west-1",
+            VPCRegion::UsWest2 => "us-west-2",
+            VPCRegion::EuWest1 => "eu-west-1",
+            VPCRegion::EuWest2 => "eu-west-2",
+            VPCRegion::EuWest3 => "eu-west-3",
+            VPCRegion::EuCentral1 => "eu-central-1",
+            VPCRegion::EuCentral2 => "eu-central-2",
+            VPCRegion::ApEast1 => "ap-east-1",
+            VPCRegion::MeSouth1 => "me-south-1",
+            VPCRegion::UsGovWest1 => "us-gov-west-1",
+            VPCRegion::UsGovEast1 => "us-gov-east-1",
+            VPCRegion::UsIsoEast1 => "us-iso-east-1",
+            VPCRegion::UsIsoWest1 => "us-iso-west-1",
+            VPCRegion::UsIsobEast1 => "us-isob-east-1",
+            VPCRegion::MeCentral1 => "me-central-1",
+            VPCRegion::ApSoutheast1 => "ap-southeast-1",
+            VPCRegion::ApSoutheast2 => "ap-southeast-2",
+            VPCRegion::ApSoutheast3 => "ap-southeast-3",
+            VPCRegion::ApSouth1 => "ap-south-1",
+            VPCRegion::ApSouth2 => "ap-south-2",
+            VPCRegion::ApNortheast1 => "ap-northeast-1",
+            VPCRegion::ApNortheast2 => "ap-northeast-2",
+            VPCRegion::ApNortheast3 => "ap-northeast-3",
+            VPCRegion::EuNorth1 => "eu-north-1",
+            VPCRegion::SaEast1 => "sa-east-1",
+            VPCRegion::CaCentral1 => "ca-central-1",
+            VPCRegion::CnNorth1 => "cn-north-1",
+            VPCRegion::AfSouth1 => "af-south-1",
+            VPCRegion::EuSouth1 => "eu-south-1",
+            VPCRegion::EuSouth2 => "eu-south-2",
+            VPCRegion::ApSoutheast4 => "ap-southeast-4",
+            VPCRegion::IlCentral1 => "il-central-1",
+            VPCRegion::CaWest1 => "ca-west-1",
+            VPCRegion::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for VPCRegion {
+    fn default() -> VPCRegion {
+        VPCRegion::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for VPCRegion {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for VPCRegion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for VPCRegion {
+    fn from(value: &'a str) -> VPCRegion {
+        match value {
+            "us-east-1" => VPCRegion::UsEast1,
+            "us-east-2" => VPCRegion::UsEast2,
+            "us-west-1" => VPCRegion::UsWest1,
+            "us-west-2" => VPCRegion::UsWest2,
+            "eu-west-1" => VPCRegion::EuWest1,
+            "eu-west-2" => VPCRegion::EuWest2,
+            "eu-west-3" => VPCRegion::EuWest3,
+            "eu-central-1" => VPCRegion::EuCentral1,
+            "eu-central-2" => VPCRegion::EuCentral2,
+            "ap-east-1" => VPCRegion::ApEast1,
+            "me-south-1" => VPCRegion::MeSouth1,
+            "us-gov-west-1" => VPCRegion::UsGovWest1,
+            "us-gov-east-1" => VPCRegion::UsGovEast1,
+            "us-iso-east-1" => VPCRegion::UsIsoEast1,
+            "us-iso-west-1" => VPCRegion::UsIsoWest1,
+            "us-isob-east-1" => VPCRegion::UsIsobEast1,
+            "me-central-1" => VPCRegion::MeCentral1,
+            "ap-southeast-1" => VPCRegion::ApSoutheast1,
+            "ap-southeast-2" => VPCRegion::ApSoutheast2,
+            "ap-southeast-3" => VPCRegion::ApSoutheast3,
+            "ap-south-1" => VPCRegion::ApSouth1,
+            "ap-south-2" => VPCRegion::ApSouth2,
+            "ap-northeast-1" => VPCRegion::ApNortheast1,
+            "ap-northeast-2" => VPCRegion::ApNortheast2,
+            "ap-northeast-3" => VPCRegion::ApNortheast3,
+            "eu-north-1" => VPCRegion::EuNorth1,
+            "sa-east-1" => VPCRegion::SaEast1,
+            "ca-central-1" => VPCRegion::CaCentral1,
+            "cn-north-1" => VPCRegion::CnNorth1,
+            "af-south-1" => VPCRegion::AfSouth1,
+            "eu-south-1" => VPCRegion::EuSouth1,
+            "eu-south-2" => VPCRegion::EuSouth2,
+            "ap-southeast-4" => VPCRegion::ApSoutheast4,
+            "il-central-1" => VPCRegion::IlCentral1,
+            "ca-west-1" => VPCRegion::CaWest1,
+            _ => VPCRegion::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for VPCRegion {
+    fn from(value: String) -> VPCRegion {
+        match value.as_str() {
+            "us-east-1" => VPCRegion::UsEast1,
+            "us-east-2" => VPCRegion::UsEast2,
+            "us-west-1" => VPCRegion::UsWest1,
+            "us-west-2" => VPCRegion::UsWest2,
+            "eu-west-1" => VPCRegion::EuWest1,
+            "eu-west-2" => VPCRegion::EuWest2,
+            "eu-west-3" => VPCRegion::EuWest3,
+            "eu-central-1" => VPCRegion::EuCentral1,
+            "eu-central-2" => VPCRegion::EuCentral2,
+            "ap-east-1" => VPCRegion::ApEast1,
+            "me-south-1" => VPCRegion::MeSouth1,
+            "us-gov-west-1" => VPCRegion::UsGovWest1,
+            "us-gov-east-1" => VPCRegion::UsGovEast1,
+            "us-iso-east-1" => VPCRegion::UsIsoEast1,
+            "us-iso-west-1" => VPCRegion::UsIsoWest1,
+            "us-isob-east-1" => VPCRegion::UsIsobEast1,
+            "me-central-1" => VPCRegion::MeCentral1,
+            "ap-southeast-1" => VPCRegion::ApSoutheast1,
+            "ap-southeast-2" => VPCRegion::ApSoutheast2,
+            "ap-southeast-3" => VPCRegion::ApSoutheast3,
+            "ap-south-1" => VPCRegion::ApSouth1,
+            "ap-south-2" => VPCRegion::ApSouth2,
+            "ap-northeast-1" => VPCRegion::ApNortheast1,
+            "ap-northeast-2" => VPCRegion::ApNortheast2,
+            "ap-northeast-3" => VPCRegion::ApNortheast3,
+            "eu-north-1" => VPCRegion::EuNorth1,
+            "sa-east-1" => VPCRegion::SaEast1,
+            "ca-central-1" => VPCRegion::CaCentral1,
+            "cn-north-1" => VPCRegion::CnNorth1,
+            "af-south-1" => VPCRegion::AfSouth1,
+            "eu-south-1" => VPCRegion::EuSouth1,
+            "eu-south-2" => VPCRegion::EuSouth2,
+            "ap-southeast-4" => VPCRegion::ApSoutheast4,
+            "il-central-1" => VPCRegion::IlCentral1,
+            "ca-west-1" => VPCRegion::CaWest1,
+            _ => VPCRegion::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for VPCRegion {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<VPCRegion, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for VPCRegion {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for VPCRegion {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for VPCRegion {
+    fn deserialize<D>(deserializer: D) -> Result<VPCRegion, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct VPCRegionDeserializer;
 impl VPCRegionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<VPCRegion, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -7881,7 +10211,7 @@ impl VPCRegionSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &VPCRegion,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -11962,11 +14292,12 @@ pub struct Route53Client {
 impl Route53Client {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> Route53Client {
         Route53Client {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Route 53"),
         }
     }
 
@@ -12007,6 +14338,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("AssociateVPCWithHostedZone");
 
         let mut writer = EventWriter::new(Vec::new());
         AssociateVPCWithHostedZoneRequestSerializer::serialize(
@@ -12064,6 +14396,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("ChangeResourceRecordSets");
 
         let mut writer = EventWriter::new(Vec::new());
         ChangeResourceRecordSetsRequestSerializer::serialize(
@@ -12121,6 +14454,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("ChangeTagsForResource");
 
         let mut writer = EventWriter::new(Vec::new());
         ChangeTagsForResourceRequestSerializer::serialize(
@@ -12172,6 +14506,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/healthcheck";
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("CreateHealthCheck");
 
         let mut writer = EventWriter::new(Vec::new());
         CreateHealthCheckRequestSerializer::serialize(
@@ -12222,6 +14557,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/hostedzone";
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("CreateHostedZone");
 
         let mut writer = EventWriter::new(Vec::new());
         CreateHostedZoneRequestSerializer::serialize(
@@ -12272,6 +14608,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/queryloggingconfig";
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("CreateQueryLoggingConfig");
 
         let mut writer = EventWriter::new(Vec::new());
         CreateQueryLoggingConfigRequestSerializer::serialize(
@@ -12325,6 +14662,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/delegationset";
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("CreateReusableDelegationSet");
 
         let mut writer = EventWriter::new(Vec::new());
         CreateReusableDelegationSetRequestSerializer::serialize(
@@ -12377,6 +14715,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/trafficpolicy";
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("CreateTrafficPolicy");
 
         let mut writer = EventWriter::new(Vec::new());
         CreateTrafficPolicyRequestSerializer::serialize(
@@ -12428,6 +14767,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/trafficpolicyinstance";
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("CreateTrafficPolicyInstance");
 
         let mut writer = EventWriter::new(Vec::new());
         CreateTrafficPolicyInstanceRequestSerializer::serialize(
@@ -12483,6 +14823,7 @@ impl Route53 for Route53Client {
             .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("CreateTrafficPolicyVersion");
 
         let mut writer = EventWriter::new(Vec::new());
         CreateTrafficPolicyVersionRequestSerializer::serialize(
@@ -12543,6 +14884,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("CreateVPCAssociationAuthorization");
 
         let mut writer = EventWriter::new(Vec::new());
         CreateVPCAssociationAuthorizationRequestSerializer::serialize(
@@ -12601,6 +14943,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("DELETE", "route53", &self.region, &request_uri);
+        request.set_operation("DeleteHealthCheck");
 
         let mut response = self
             .client
@@ -12643,6 +14986,7 @@ impl Route53 for Route53Client {
             .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("DELETE", "route53", &self.region, &request_uri);
+        request.set_operation("DeleteHostedZone");
 
         let mut response = self
             .client
@@ -12685,6 +15029,7 @@ impl Route53 for Route53Client {
             .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("DELETE", "route53", &self.region, &request_uri);
+        request.set_operation("DeleteQueryLoggingConfig");
 
         let mut response = self
             .client
@@ -12730,6 +15075,7 @@ impl Route53 for Route53Client {
             .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("DELETE", "route53", &self.region, &request_uri);
+        request.set_operation("DeleteReusableDelegationSet");
 
         let mut response = self
             .client
@@ -12778,6 +15124,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("DELETE", "route53", &self.region, &request_uri);
+        request.set_operation("DeleteTrafficPolicy");
 
         let mut response = self
             .client
@@ -12821,6 +15168,7 @@ impl Route53 for Route53Client {
             .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("DELETE", "route53", &self.region, &request_uri);
+        request.set_operation("DeleteTrafficPolicyInstance");
 
         let mut response = self
             .client
@@ -12871,6 +15219,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("DeleteVPCAssociationAuthorization");
 
         let mut writer = EventWriter::new(Vec::new());
         DeleteVPCAssociationAuthorizationRequestSerializer::serialize(
@@ -12932,6 +15281,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("DisassociateVPCFromHostedZone");
 
         let mut writer = EventWriter::new(Vec::new());
         DisassociateVPCFromHostedZoneRequestSerializer::serialize(
@@ -12985,6 +15335,7 @@ impl Route53 for Route53Client {
             .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetAccountLimit");
 
         let mut response = self
             .client
@@ -13027,6 +15378,7 @@ impl Route53 for Route53Client {
             .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetChange");
 
         let mut response = self
             .client
@@ -13066,6 +15418,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/checkeripranges";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetCheckerIpRanges");
 
         let mut response = self
             .client
@@ -13106,6 +15459,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/geolocation";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetGeoLocation");
 
         let mut params = Params::new();
         if let Some(ref x) = input.continent_code {
@@ -13162,6 +15516,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetHealthCheck");
 
         let mut response = self
             .client
@@ -13201,6 +15556,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/healthcheckcount";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetHealthCheckCount");
 
         let mut response = self
             .client
@@ -13249,6 +15605,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetHealthCheckLastFailureReason");
 
         let mut response = self
             .client
@@ -13298,6 +15655,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetHealthCheckStatus");
 
         let mut response = self
             .client
@@ -13342,6 +15700,7 @@ impl Route53 for Route53Client {
             .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetHostedZone");
 
         let mut response = self
             .client
@@ -13381,6 +15740,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/hostedzonecount";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetHostedZoneCount");
 
         let mut response = self
             .client
@@ -13421,6 +15781,7 @@ impl Route53 for Route53Client {
         let request_uri = format!("/2013-04-01/hostedzonelimit/{id}/{type}", id = input.hosted_zone_id, type = input.type_).replace("/hostedzone/hostedzone/", "/hostedzone/").replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetHostedZoneLimit");
 
         let mut response = self
             .client
@@ -13463,6 +15824,7 @@ impl Route53 for Route53Client {
             .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetQueryLoggingConfig");
 
         let mut response = self
             .client
@@ -13507,6 +15869,7 @@ impl Route53 for Route53Client {
             .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetReusableDelegationSet");
 
         let mut response = self
             .client
@@ -13552,6 +15915,7 @@ impl Route53 for Route53Client {
         let request_uri = format!("/2013-04-01/reusabledelegationsetlimit/{id}/{type}", id = input.delegation_set_id, type = input.type_).replace("/hostedzone/hostedzone/", "/hostedzone/").replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetReusableDelegationSetLimit");
 
         let mut response = self
             .client
@@ -13600,6 +15964,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetTrafficPolicy");
 
         let mut response = self
             .client
@@ -13642,6 +16007,7 @@ impl Route53 for Route53Client {
             .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetTrafficPolicyInstance");
 
         let mut response = self
             .client
@@ -13687,6 +16053,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/trafficpolicyinstancecount";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetTrafficPolicyInstanceCount");
 
         let mut response = self
             .client
@@ -13729,6 +16096,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/geolocations";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListGeoLocations");
 
         let mut params = Params::new();
         if let Some(ref x) = input.max_items {
@@ -13784,6 +16152,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/healthcheck";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListHealthChecks");
 
         let mut params = Params::new();
         if let Some(ref x) = input.marker {
@@ -13833,6 +16202,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/hostedzone";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListHostedZones");
 
         let mut params = Params::new();
         if let Some(ref x) = input.delegation_set_id {
@@ -13885,6 +16255,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/hostedzonesbyname";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListHostedZonesByName");
 
         let mut params = Params::new();
         if let Some(ref x) = input.dns_name {
@@ -13939,6 +16310,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/queryloggingconfig";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListQueryLoggingConfigs");
 
         let mut params = Params::new();
         if let Some(ref x) = input.hosted_zone_id {
@@ -13998,6 +16370,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListResourceRecordSets");
 
         let mut params = Params::new();
         if let Some(ref x) = input.max_items {
@@ -14056,6 +16429,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/delegationset";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListReusableDelegationSets");
 
         let mut params = Params::new();
         if let Some(ref x) = input.marker {
@@ -14113,6 +16487,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
 
         let mut response = self
             .client
@@ -14158,6 +16533,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("ListTagsForResources");
 
         let mut writer = EventWriter::new(Vec::new());
         ListTagsForResourcesRequestSerializer::serialize(
@@ -14209,6 +16585,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/trafficpolicies";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListTrafficPolicies");
 
         let mut params = Params::new();
         if let Some(ref x) = input.max_items {
@@ -14259,6 +16636,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/trafficpolicyinstances";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListTrafficPolicyInstances");
 
         let mut params = Params::new();
         if let Some(ref x) = input.hosted_zone_id_marker {
@@ -14319,6 +16697,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/trafficpolicyinstances/hostedzone";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListTrafficPolicyInstancesByHostedZone");
 
         let mut params = Params::new();
         params.put("id", &input.hosted_zone_id);
@@ -14379,6 +16758,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/trafficpolicyinstances/trafficpolicy";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListTrafficPolicyInstancesByPolicy");
 
         let mut params = Params::new();
         if let Some(ref x) = input.hosted_zone_id_marker {
@@ -14443,6 +16823,7 @@ impl Route53 for Route53Client {
             .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListTrafficPolicyVersions");
 
         let mut params = Params::new();
         if let Some(ref x) = input.max_items {
@@ -14502,6 +16883,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListVPCAssociationAuthorizations");
 
         let mut params = Params::new();
         if let Some(ref x) = input.max_results {
@@ -14555,6 +16937,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/testdnsanswer";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("TestDNSAnswer");
 
         let mut params = Params::new();
         if let Some(ref x) = input.edns0_client_subnet_ip {
@@ -14614,6 +16997,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("UpdateHealthCheck");
 
         let mut writer = EventWriter::new(Vec::new());
         UpdateHealthCheckRequestSerializer::serialize(
@@ -14665,6 +17049,7 @@ impl Route53 for Route53Client {
             .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("UpdateHostedZoneComment");
 
         let mut writer = EventWriter::new(Vec::new());
         UpdateHostedZoneCommentRequestSerializer::serialize(
@@ -14723,6 +17108,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("UpdateTrafficPolicyComment");
 
         let mut writer = EventWriter::new(Vec::new());
         UpdateTrafficPolicyCommentRequestSerializer::serialize(
@@ -14777,6 +17163,7 @@ impl Route53 for Route53Client {
             .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("UpdateTrafficPolicyInstance");
 
         let mut writer = EventWriter::new(Vec::new());
         UpdateTrafficPolicyInstanceRequestSerializer::serialize(
@@ -14819,6 +17206,229 @@ impl Route53 for Route53Client {
         Ok(result)
     }
 }
+impl Route53Client {
+    /// Auto-paginating version of `list_health_checks`, streaming every page of results.
+    pub fn list_health_checks_pages(
+        &self,
+        input: ListHealthChecksRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListHealthChecksResponse, ListHealthChecksError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: Route53Client, input: ListHealthChecksRequest| async move {
+                client.list_health_checks(input).await
+            },
+            |input: &ListHealthChecksRequest, output: &ListHealthChecksResponse| {
+                if !output.is_truncated {
+                    return None;
+                }
+                Some(ListHealthChecksRequest {
+                    marker: Some(output.next_marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_health_checks`, streaming the `HealthChecks` of every page of results.
+    pub fn list_health_checks_items(
+        &self,
+        input: ListHealthChecksRequest,
+    ) -> rusoto_core::pagination::RusotoStream<HealthCheck, ListHealthChecksError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_health_checks_pages(input),
+            |output: ListHealthChecksResponse| output.health_checks,
+        )
+    }
+
+    /// Auto-paginating version of `list_hosted_zones`, streaming every page of results.
+    pub fn list_hosted_zones_pages(
+        &self,
+        input: ListHostedZonesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListHostedZonesResponse, ListHostedZonesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: Route53Client, input: ListHostedZonesRequest| async move {
+                client.list_hosted_zones(input).await
+            },
+            |input: &ListHostedZonesRequest, output: &ListHostedZonesResponse| {
+                if !output.is_truncated {
+                    return None;
+                }
+                Some(ListHostedZonesRequest {
+                    marker: Some(output.next_marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_hosted_zones`, streaming the `HostedZones` of every page of results.
+    pub fn list_hosted_zones_items(
+        &self,
+        input: ListHostedZonesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<HostedZone, ListHostedZonesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_hosted_zones_pages(input),
+            |output: ListHostedZonesResponse| output.hosted_zones,
+        )
+    }
+
+    /// Auto-paginating version of `list_query_logging_configs`, streaming every page of results.
+    pub fn list_query_logging_configs_pages(
+        &self,
+        input: ListQueryLoggingConfigsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListQueryLoggingConfigsResponse,
+        ListQueryLoggingConfigsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: Route53Client, input: ListQueryLoggingConfigsRequest| async move {
+                client.list_query_logging_configs(input).await
+            },
+            |input: &ListQueryLoggingConfigsRequest, output: &ListQueryLoggingConfigsResponse| {
+                Some(ListQueryLoggingConfigsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_query_logging_configs`, streaming the `QueryLoggingConfigs` of every page of results.
+    pub fn list_query_logging_configs_items(
+        &self,
+        input: ListQueryLoggingConfigsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<QueryLoggingConfig, ListQueryLoggingConfigsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_query_logging_configs_pages(input),
+            |output: ListQueryLoggingConfigsResponse| output.query_logging_configs,
+        )
+    }
+
+    /// Auto-paginating version of `list_resource_record_sets`, streaming every page of results.
+    pub fn list_resource_record_sets_pages(
+        &self,
+        input: ListResourceRecordSetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListResourceRecordSetsResponse,
+        ListResourceRecordSetsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: Route53Client, input: ListResourceRecordSetsRequest| async move {
+                client.list_resource_record_sets(input).await
+            },
+            |input: &ListResourceRecordSetsRequest, output: &ListResourceRecordSetsResponse| {
+                if !output.is_truncated {
+                    return None;
+                }
+                let start_record_name = output.next_record_name.clone();
+                let start_record_type = output.next_record_type.clone();
+                let start_record_identifier = output.next_record_identifier.clone();
+                if start_record_name.is_none()
+                    && start_record_type.is_none()
+                    && start_record_identifier.is_none()
+                {
+                    return None;
+                }
+                Some(ListResourceRecordSetsRequest {
+                    start_record_name,
+                    start_record_type,
+                    start_record_identifier,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_resource_record_sets`, streaming the `ResourceRecordSets` of every page of results.
+    pub fn list_resource_record_sets_items(
+        &self,
+        input: ListResourceRecordSetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ResourceRecordSet, ListResourceRecordSetsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_resource_record_sets_pages(input),
+            |output: ListResourceRecordSetsResponse| output.resource_record_sets,
+        )
+    }
+
+    /// Auto-paginating version of `list_vpc_association_authorizations`, streaming every page of results.
+    pub fn list_vpc_association_authorizations_pages(
+        &self,
+        input: ListVPCAssociationAuthorizationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListVPCAssociationAuthorizationsResponse,
+        ListVPCAssociationAuthorizationsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: Route53Client, input: ListVPCAssociationAuthorizationsRequest| async move {
+                client.list_vpc_association_authorizations(input).await
+            },
+            |input: &ListVPCAssociationAuthorizationsRequest,
+             output: &ListVPCAssociationAuthorizationsResponse| {
+                Some(ListVPCAssociationAuthorizationsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_vpc_association_authorizations`, streaming the `VPCs` of every page of results.
+    pub fn list_vpc_association_authorizations_items(
+        &self,
+        input: ListVPCAssociationAuthorizationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<VPC, ListVPCAssociationAuthorizationsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_vpc_association_authorizations_pages(input),
+            |output: ListVPCAssociationAuthorizationsResponse| output.vp_cs,
+        )
+    }
+}
+impl Route53Client {
+    /// Waits until `get_change` reports the `ResourceRecordSetsChanged` state, polling every 30 seconds up to 60 times.
+    pub async fn wait_until_resource_record_sets_changed(
+        &self,
+        input: GetChangeRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetChangeError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(30), 60);
+        self.wait_until_resource_record_sets_changed_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_resource_record_sets_changed`, polling as often and as many times as `config` says.
+    pub async fn wait_until_resource_record_sets_changed_with_config(
+        &self,
+        input: GetChangeRequest,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetChangeError>> {
+        rusoto_core::waiter::wait(
+            "ResourceRecordSetsChanged",
+            &config,
+            || self.get_change(input.clone()),
+            |result: &Result<GetChangeResponse, RusotoError<GetChangeError>>| match result {
+                Ok(output)
+                    if std::iter::once(&output.change_info)
+                        .map(|v| &v.status)
+                        .any(|v| *v == ChangeStatus::Insync) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+}
 
 #[cfg(test)]
 mod protocol_tests {
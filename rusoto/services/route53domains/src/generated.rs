@@ -30,8 +30,13 @@ use serde_json;
 pub struct BillingRecord {
     /// <p>The date that the operation was billed, in Unix format.</p>
     #[serde(rename = "BillDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub bill_date: Option<f64>,
+    pub bill_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the domain that the billing record applies to. If the domain name contains characters other than a-z, 0-9, and - (hyphen), such as an internationalized domain name, then this value is in Punycode. For more information, see <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/DomainNameFormat.html">DNS Domain Name Format</a> in the <i>Amazon Route 53 Developer Guidezzz</i>.</p>
     #[serde(rename = "DomainName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -43,7 +48,7 @@ pub struct BillingRecord {
     /// <p>The operation that you were charged for.</p>
     #[serde(rename = "Operation")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub operation: Option<String>,
+    pub operation: Option<OperationType>,
     /// <p>The price that you were charged for the operation, in US dollars.</p> <p>Example value: 12.0</p>
     #[serde(rename = "Price")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -69,7 +74,7 @@ pub struct CheckDomainAvailabilityRequest {
 pub struct CheckDomainAvailabilityResponse {
     /// <p><p>Whether the domain name is available for registering.</p> <note> <p>You can register only domains designated as <code>AVAILABLE</code>.</p> </note> <p>Valid values:</p> <dl> <dt>AVAILABLE</dt> <dd> <p>The domain name is available.</p> </dd> <dt>AVAILABLE<em>RESERVED</dt> <dd> <p>The domain name is reserved under specific conditions.</p> </dd> <dt>AVAILABLE</em>PREORDER</dt> <dd> <p>The domain name is available and can be preordered.</p> </dd> <dt>DONT<em>KNOW</dt> <dd> <p>The TLD registry didn&#39;t reply with a definitive answer about whether the domain name is available. Amazon Route 53 can return this response for a variety of reasons, for example, the registry is performing maintenance. Try again later.</p> </dd> <dt>PENDING</dt> <dd> <p>The TLD registry didn&#39;t return a response in the expected amount of time. When the response is delayed, it usually takes just a few extra seconds. You can resubmit the request immediately.</p> </dd> <dt>RESERVED</dt> <dd> <p>The domain name has been reserved for another person or organization.</p> </dd> <dt>UNAVAILABLE</dt> <dd> <p>The domain name is not available.</p> </dd> <dt>UNAVAILABLE</em>PREMIUM</dt> <dd> <p>The domain name is not available.</p> </dd> <dt>UNAVAILABLE_RESTRICTED</dt> <dd> <p>The domain name is forbidden.</p> </dd> </dl></p>
     #[serde(rename = "Availability")]
-    pub availability: String,
+    pub availability: DomainAvailability,
 }
 
 /// <p>The CheckDomainTransferability request contains the following elements.</p>
@@ -112,11 +117,11 @@ pub struct ContactDetail {
     /// <p>Indicates whether the contact is a person, company, association, or public organization. If you choose an option other than <code>PERSON</code>, you must enter an organization name, and you can't enable privacy protection for the contact.</p>
     #[serde(rename = "ContactType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub contact_type: Option<String>,
+    pub contact_type: Option<ContactType>,
     /// <p>Code for the country of the contact's address.</p>
     #[serde(rename = "CountryCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub country_code: Option<String>,
+    pub country_code: Option<CountryCode>,
     /// <p>Email address of the contact.</p>
     #[serde(rename = "Email")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -155,6 +160,1452 @@ pub struct ContactDetail {
     pub zip_code: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ContactType {
+    /// `PERSON`
+    Person,
+    /// `COMPANY`
+    Company,
+    /// `ASSOCIATION`
+    Association,
+    /// `PUBLIC_BODY`
+    PublicBody,
+    /// `RESELLER`
+    Reseller,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ContactType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ContactType::Person => "PERSON",
+            ContactType::Company => "COMPANY",
+            ContactType::Association => "ASSOCIATION",
+            ContactType::PublicBody => "PUBLIC_BODY",
+            ContactType::Reseller => "RESELLER",
+            ContactType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ContactType {
+    fn default() -> ContactType {
+        ContactType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ContactType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ContactType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ContactType {
+    fn from(value: &'a str) -> ContactType {
+        match value {
+            "PERSON" => ContactType::Person,
+            "COMPANY" => ContactType::Company,
+            "ASSOCIATION" => ContactType::Association,
+            "PUBLIC_BODY" => ContactType::PublicBody,
+            "RESELLER" => ContactType::Reseller,
+            _ => ContactType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ContactType {
+    fn from(value: String) -> ContactType {
+        match value.as_str() {
+            "PERSON" => ContactType::Person,
+            "COMPANY" => ContactType::Company,
+            "ASSOCIATION" => ContactType::Association,
+            "PUBLIC_BODY" => ContactType::PublicBody,
+            "RESELLER" => ContactType::Reseller,
+            _ => ContactType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ContactType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ContactType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ContactType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ContactType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ContactType {
+    fn deserialize<D>(deserializer: D) -> Result<ContactType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CountryCode {
+    /// `AC`
+    Ac,
+    /// `AD`
+    Ad,
+    /// `AE`
+    Ae,
+    /// `AF`
+    Af,
+    /// `AG`
+    Ag,
+    /// `AI`
+    Ai,
+    /// `AL`
+    Al,
+    /// `AM`
+    Am,
+    /// `AN`
+    An,
+    /// `AO`
+    Ao,
+    /// `AQ`
+    Aq,
+    /// `AR`
+    Ar,
+    /// `AS`
+    As,
+    /// `AT`
+    At,
+    /// `AU`
+    Au,
+    /// `AW`
+    Aw,
+    /// `AX`
+    Ax,
+    /// `AZ`
+    Az,
+    /// `BA`
+    Ba,
+    /// `BB`
+    Bb,
+    /// `BD`
+    Bd,
+    /// `BE`
+    Be,
+    /// `BF`
+    Bf,
+    /// `BG`
+    Bg,
+    /// `BH`
+    Bh,
+    /// `BI`
+    Bi,
+    /// `BJ`
+    Bj,
+    /// `BL`
+    Bl,
+    /// `BM`
+    Bm,
+    /// `BN`
+    Bn,
+    /// `BO`
+    Bo,
+    /// `BQ`
+    Bq,
+    /// `BR`
+    Br,
+    /// `BS`
+    Bs,
+    /// `BT`
+    Bt,
+    /// `BV`
+    Bv,
+    /// `BW`
+    Bw,
+    /// `BY`
+    By,
+    /// `BZ`
+    Bz,
+    /// `CA`
+    Ca,
+    /// `CC`
+    Cc,
+    /// `CD`
+    Cd,
+    /// `CF`
+    Cf,
+    /// `CG`
+    Cg,
+    /// `CH`
+    Ch,
+    /// `CI`
+    Ci,
+    /// `CK`
+    Ck,
+    /// `CL`
+    Cl,
+    /// `CM`
+    Cm,
+    /// `CN`
+    Cn,
+    /// `CO`
+    Co,
+    /// `CR`
+    Cr,
+    /// `CU`
+    Cu,
+    /// `CV`
+    Cv,
+    /// `CW`
+    Cw,
+    /// `CX`
+    Cx,
+    /// `CY`
+    Cy,
+    /// `CZ`
+    Cz,
+    /// `DE`
+    De,
+    /// `DJ`
+    Dj,
+    /// `DK`
+    Dk,
+    /// `DM`
+    Dm,
+    /// `DO`
+    Do,
+    /// `DZ`
+    Dz,
+    /// `EC`
+    Ec,
+    /// `EE`
+    Ee,
+    /// `EG`
+    Eg,
+    /// `EH`
+    Eh,
+    /// `ER`
+    Er,
+    /// `ES`
+    Es,
+    /// `ET`
+    Et,
+    /// `FI`
+    Fi,
+    /// `FJ`
+    Fj,
+    /// `FK`
+    Fk,
+    /// `FM`
+    Fm,
+    /// `FO`
+    Fo,
+    /// `FR`
+    Fr,
+    /// `GA`
+    Ga,
+    /// `GB`
+    Gb,
+    /// `GD`
+    Gd,
+    /// `GE`
+    Ge,
+    /// `GF`
+    Gf,
+    /// `GG`
+    Gg,
+    /// `GH`
+    Gh,
+    /// `GI`
+    Gi,
+    /// `GL`
+    Gl,
+    /// `GM`
+    Gm,
+    /// `GN`
+    Gn,
+    /// `GP`
+    Gp,
+    /// `GQ`
+    Gq,
+    /// `GR`
+    Gr,
+    /// `GS`
+    Gs,
+    /// `GT`
+    Gt,
+    /// `GU`
+    Gu,
+    /// `GW`
+    Gw,
+    /// `GY`
+    Gy,
+    /// `HK`
+    Hk,
+    /// `HM`
+    Hm,
+    /// `HN`
+    Hn,
+    /// `HR`
+    Hr,
+    /// `HT`
+    Ht,
+    /// `HU`
+    Hu,
+    /// `ID`
+    Id,
+    /// `IE`
+    Ie,
+    /// `IL`
+    Il,
+    /// `IM`
+    Im,
+    /// `IN`
+    In,
+    /// `IO`
+    Io,
+    /// `IQ`
+    Iq,
+    /// `IR`
+    Ir,
+    /// `IS`
+    Is,
+    /// `IT`
+    It,
+    /// `JE`
+    Je,
+    /// `JM`
+    Jm,
+    /// `JO`
+    Jo,
+    /// `JP`
+    Jp,
+    /// `KE`
+    Ke,
+    /// `KG`
+    Kg,
+    /// `KH`
+    Kh,
+    /// `KI`
+    Ki,
+    /// `KM`
+    Km,
+    /// `KN`
+    Kn,
+    /// `KP`
+    Kp,
+    /// `KR`
+    Kr,
+    /// `KW`
+    Kw,
+    /// `KY`
+    Ky,
+    /// `KZ`
+    Kz,
+    /// `LA`
+    La,
+    /// `LB`
+    Lb,
+    /// `LC`
+    Lc,
+    /// `LI`
+    Li,
+    /// `LK`
+    Lk,
+    /// `LR`
+    Lr,
+    /// `LS`
+    Ls,
+    /// `LT`
+    Lt,
+    /// `LU`
+    Lu,
+    /// `LV`
+    Lv,
+    /// `LY`
+    Ly,
+    /// `MA`
+    Ma,
+    /// `MC`
+    Mc,
+    /// `MD`
+    Md,
+    /// `ME`
+    Me,
+    /// `MF`
+    Mf,
+    /// `MG`
+    Mg,
+    /// `MH`
+    Mh,
+    /// `MK`
+    Mk,
+    /// `ML`
+    Ml,
+    /// `MM`
+    Mm,
+    /// `MN`
+    Mn,
+    /// `MO`
+    Mo,
+    /// `MP`
+    Mp,
+    /// `MQ`
+    Mq,
+    /// `MR`
+    Mr,
+    /// `MS`
+    Ms,
+    /// `MT`
+    Mt,
+    /// `MU`
+    Mu,
+    /// `MV`
+    Mv,
+    /// `MW`
+    Mw,
+    /// `MX`
+    Mx,
+    /// `MY`
+    My,
+    /// `MZ`
+    Mz,
+    /// `NA`
+    Na,
+    /// `NC`
+    Nc,
+    /// `NE`
+    Ne,
+    /// `NF`
+    Nf,
+    /// `NG`
+    Ng,
+    /// `NI`
+    Ni,
+    /// `NL`
+    Nl,
+    /// `NO`
+    No,
+    /// `NP`
+    Np,
+    /// `NR`
+    Nr,
+    /// `NU`
+    Nu,
+    /// `NZ`
+    Nz,
+    /// `OM`
+    Om,
+    /// `PA`
+    Pa,
+    /// `PE`
+    Pe,
+    /// `PF`
+    Pf,
+    /// `PG`
+    Pg,
+    /// `PH`
+    Ph,
+    /// `PK`
+    Pk,
+    /// `PL`
+    Pl,
+    /// `PM`
+    Pm,
+    /// `PN`
+    Pn,
+    /// `PR`
+    Pr,
+    /// `PS`
+    Ps,
+    /// `PT`
+    Pt,
+    /// `PW`
+    Pw,
+    /// `PY`
+    Py,
+    /// `QA`
+    Qa,
+    /// `RE`
+    Re,
+    /// `RO`
+    Ro,
+    /// `RS`
+    Rs,
+    /// `RU`
+    Ru,
+    /// `RW`
+    Rw,
+    /// `SA`
+    Sa,
+    /// `SB`
+    Sb,
+    /// `SC`
+    Sc,
+    /// `SD`
+    Sd,
+    /// `SE`
+    Se,
+    /// `SG`
+    Sg,
+    /// `SH`
+    Sh,
+    /// `SI`
+    Si,
+    /// `SJ`
+    Sj,
+    /// `SK`
+    Sk,
+    /// `SL`
+    Sl,
+    /// `SM`
+    Sm,
+    /// `SN`
+    Sn,
+    /// `SO`
+    So,
+    /// `SR`
+    Sr,
+    /// `SS`
+    Ss,
+    /// `ST`
+    St,
+    /// `SV`
+    Sv,
+    /// `SX`
+    Sx,
+    /// `SY`
+    Sy,
+    /// `SZ`
+    Sz,
+    /// `TC`
+    Tc,
+    /// `TD`
+    Td,
+    /// `TF`
+    Tf,
+    /// `TG`
+    Tg,
+    /// `TH`
+    Th,
+    /// `TJ`
+    Tj,
+    /// `TK`
+    Tk,
+    /// `TL`
+    Tl,
+    /// `TM`
+    Tm,
+    /// `TN`
+    Tn,
+    /// `TO`
+    To,
+    /// `TP`
+    Tp,
+    /// `TR`
+    Tr,
+    /// `TT`
+    Tt,
+    /// `TV`
+    Tv,
+    /// `TW`
+    Tw,
+    /// `TZ`
+    Tz,
+    /// `UA`
+    Ua,
+    /// `UG`
+    Ug,
+    /// `US`
+    Us,
+    /// `UY`
+    Uy,
+    /// `UZ`
+    Uz,
+    /// `VA`
+    Va,
+    /// `VC`
+    Vc,
+    /// `VE`
+    Ve,
+    /// `VG`
+    Vg,
+    /// `VI`
+    Vi,
+    /// `VN`
+    Vn,
+    /// `VU`
+    Vu,
+    /// `WF`
+    Wf,
+    /// `WS`
+    Ws,
+    /// `YE`
+    Ye,
+    /// `YT`
+    Yt,
+    /// `ZA`
+    Za,
+    /// `ZM`
+    Zm,
+    /// `ZW`
+    Zw,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CountryCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CountryCode::Ac => "AC",
+            CountryCode::Ad => "AD",
+            CountryCode::Ae => "AE",
+            CountryCode::Af => "AF",
+            CountryCode::Ag => "AG",
+            CountryCode::Ai => "AI",
+            CountryCode::Al => "AL",
+            CountryCode::Am => "AM",
+            CountryCode::An => "AN",
+            CountryCode::Ao => "AO",
+            CountryCode::Aq => "AQ",
+            CountryCode::Ar => "AR",
+            CountryCode::As => "AS",
+            CountryCode::At => "AT",
+            CountryCode::Au => "AU",
+            CountryCode::Aw => "AW",
+            CountryCode::Ax => "AX",
+            CountryCode::Az => "AZ",
+            CountryCode::Ba => "BA",
+            CountryCode::Bb => "BB",
+            CountryCode::Bd => "BD",
+            CountryCode::Be => "BE",
+            CountryCode::Bf => "BF",
+            CountryCode::Bg => "BG",
+            CountryCode::Bh => "BH",
+            CountryCode::Bi => "BI",
+            CountryCode::Bj => "BJ",
+            CountryCode::Bl => "BL",
+            CountryCode::Bm => "BM",
+            CountryCode::Bn => "BN",
+            CountryCode::Bo => "BO",
+            CountryCode::Bq => "BQ",
+            CountryCode::Br => "BR",
+            CountryCode::Bs => "BS",
+            CountryCode::Bt => "BT",
+            CountryCode::Bv => "BV",
+            CountryCode::Bw => "BW",
+            CountryCode::By => "BY",
+            CountryCode::Bz => "BZ",
+            CountryCode::Ca => "CA",
+            CountryCode::Cc => "CC",
+            CountryCode::Cd => "CD",
+            CountryCode::Cf => "CF",
+            CountryCode::Cg => "CG",
+            CountryCode::Ch => "CH",
+            CountryCode::Ci => "CI",
+            CountryCode::Ck => "CK",
+            CountryCode::Cl => "CL",
+            CountryCode::Cm => "CM",
+            CountryCode::Cn => "CN",
+            CountryCode::Co => "CO",
+            CountryCode::Cr => "CR",
+            CountryCode::Cu => "CU",
+            CountryCode::Cv => "CV",
+            CountryCode::Cw => "CW",
+            CountryCode::Cx => "CX",
+            CountryCode::Cy => "CY",
+            CountryCode::Cz => "CZ",
+            CountryCode::De => "DE",
+            CountryCode::Dj => "DJ",
+            CountryCode::Dk => "DK",
+            CountryCode::Dm => "DM",
+            CountryCode::Do => "DO",
+            CountryCode::Dz => "DZ",
+            CountryCode::Ec => "EC",
+            CountryCode::Ee => "EE",
+            CountryCode::Eg => "EG",
+            CountryCode::Eh => "EH",
+            CountryCode::Er => "ER",
+            CountryCode::Es => "ES",
+            CountryCode::Et => "ET",
+            CountryCode::Fi => "FI",
+            CountryCode::Fj => "FJ",
+            CountryCode::Fk => "FK",
+            CountryCode::Fm => "FM",
+            CountryCode::Fo => "FO",
+            CountryCode::Fr => "FR",
+            CountryCode::Ga => "GA",
+            CountryCode::Gb => "GB",
+            CountryCode::Gd => "GD",
+            CountryCode::Ge => "GE",
+            CountryCode::Gf => "GF",
+            CountryCode::Gg => "GG",
+            CountryCode::Gh => "GH",
+            CountryCode::Gi => "GI",
+            CountryCode::Gl => "GL",
+            CountryCode::Gm => "GM",
+            CountryCode::Gn => "GN",
+            CountryCode::Gp => "GP",
+            CountryCode::Gq => "GQ",
+            CountryCode::Gr => "GR",
+            CountryCode::Gs => "GS",
+            CountryCode::Gt => "GT",
+            CountryCode::Gu => "GU",
+            CountryCode::Gw => "GW",
+            CountryCode::Gy => "GY",
+            CountryCode::Hk => "HK",
+            CountryCode::Hm => "HM",
+            CountryCode::Hn => "HN",
+            CountryCode::Hr => "HR",
+            CountryCode::Ht => "HT",
+            CountryCode::Hu => "HU",
+            CountryCode::Id => "ID",
+            CountryCode::Ie => "IE",
+            CountryCode::Il => "IL",
+            CountryCode::Im => "IM",
+            CountryCode::In => "IN",
+            CountryCode::Io => "IO",
+            CountryCode::Iq => "IQ",
+            CountryCode::Ir => "IR",
+            CountryCode::Is => "IS",
+            CountryCode::It => "IT",
+            CountryCode::Je => "JE",
+            CountryCode::Jm => "JM",
+            CountryCode::Jo => "JO",
+            CountryCode::Jp => "JP",
+            CountryCode::Ke => "KE",
+            CountryCode::Kg => "KG",
+            CountryCode::Kh => "KH",
+            CountryCode::Ki => "KI",
+            CountryCode::Km => "KM",
+            CountryCode::Kn => "KN",
+            CountryCode::Kp => "KP",
+            CountryCode::Kr => "KR",
+            CountryCode::Kw => "KW",
+            CountryCode::Ky => "KY",
+            CountryCode::Kz => "KZ",
+            CountryCode::La => "LA",
+            CountryCode::Lb => "LB",
+            CountryCode::Lc => "LC",
+            CountryCode::Li => "LI",
+            CountryCode::Lk => "LK",
+            CountryCode::Lr => "LR",
+            CountryCode::Ls => "LS",
+            CountryCode::Lt => "LT",
+            CountryCode::Lu => "LU",
+            CountryCode::Lv => "LV",
+            CountryCode::Ly => "LY",
+            CountryCode::Ma => "MA",
+            CountryCode::Mc => "MC",
+            CountryCode::Md => "MD",
+            CountryCode::Me => "ME",
+            CountryCode::Mf => "MF",
+            CountryCode::Mg => "MG",
+            CountryCode::Mh => "MH",
+            CountryCode::Mk => "MK",
+            CountryCode::Ml => "ML",
+            CountryCode::Mm => "MM",
+            CountryCode::Mn => "MN",
+            CountryCode::Mo => "MO",
+            CountryCode::Mp => "MP",
+            CountryCode::Mq => "MQ",
+            CountryCode::Mr => "MR",
+            CountryCode::Ms => "MS",
+            CountryCode::Mt => "MT",
+            CountryCode::Mu => "MU",
+            CountryCode::Mv => "MV",
+            CountryCode::Mw => "MW",
+            CountryCode::Mx => "MX",
+            CountryCode::My => "MY",
+            CountryCode::Mz => "MZ",
+            CountryCode::Na => "NA",
+            CountryCode::Nc => "NC",
+            CountryCode::Ne => "NE",
+            CountryCode::Nf => "NF",
+            CountryCode::Ng => "NG",
+            CountryCode::Ni => "NI",
+            CountryCode::Nl => "NL",
+            CountryCode::No => "NO",
+            CountryCode::Np => "NP",
+            CountryCode::Nr => "NR",
+            CountryCode::Nu => "NU",
+            CountryCode::Nz => "NZ",
+            CountryCode::Om => "OM",
+            CountryCode::Pa => "PA",
+            CountryCode::Pe => "PE",
+            CountryCode::Pf => "PF",
+            CountryCode::Pg => "PG",
+            CountryCode::Ph => "PH",
+            CountryCode::Pk => "PK",
+            CountryCode::Pl => "PL",
+            CountryCode::Pm => "PM",
+            CountryCode::Pn => "PN",
+            CountryCode::Pr => "PR",
+            CountryCode::Ps => "PS",
+            CountryCode::Pt => "PT",
+            CountryCode::Pw => "PW",
+            CountryCode::Py => "PY",
+            CountryCode::Qa => "QA",
+            CountryCode::Re => "RE",
+            CountryCode::Ro => "RO",
+            CountryCode::Rs => "RS",
+            CountryCode::Ru => "RU",
+            CountryCode::Rw => "RW",
+            CountryCode::Sa => "SA",
+            CountryCode::Sb => "SB",
+            CountryCode::Sc => "SC",
+            CountryCode::Sd => "SD",
+            CountryCode::Se => "SE",
+            CountryCode::Sg => "SG",
+            CountryCode::Sh => "SH",
+            CountryCode::Si => "SI",
+            CountryCode::Sj => "SJ",
+            CountryCode::Sk => "SK",
+            CountryCode::Sl => "SL",
+            CountryCode::Sm => "SM",
+            CountryCode::Sn => "SN",
+            CountryCode::So => "SO",
+            CountryCode::Sr => "SR",
+            CountryCode::Ss => "SS",
+            CountryCode::St => "ST",
+            CountryCode::Sv => "SV",
+            CountryCode::Sx => "SX",
+            CountryCode::Sy => "SY",
+            CountryCode::Sz => "SZ",
+            CountryCode::Tc => "TC",
+            CountryCode::Td => "TD",
+            CountryCode::Tf => "TF",
+            CountryCode::Tg => "TG",
+            CountryCode::Th => "TH",
+            CountryCode::Tj => "TJ",
+            CountryCode::Tk => "TK",
+            CountryCode::Tl => "TL",
+            CountryCode::Tm => "TM",
+            CountryCode::Tn => "TN",
+            CountryCode::To => "TO",
+            CountryCode::Tp => "TP",
+            CountryCode::Tr => "TR",
+            CountryCode::Tt => "TT",
+            CountryCode::Tv => "TV",
+            CountryCode::Tw => "TW",
+            CountryCode::Tz => "TZ",
+            CountryCode::Ua => "UA",
+            CountryCode::Ug => "UG",
+            CountryCode::Us => "US",
+            CountryCode::Uy => "UY",
+            CountryCode::Uz => "UZ",
+            CountryCode::Va => "VA",
+            CountryCode::Vc => "VC",
+            CountryCode::Ve => "VE",
+            CountryCode::Vg => "VG",
+            CountryCode::Vi => "VI",
+            CountryCode::Vn => "VN",
+            CountryCode::Vu => "VU",
+            CountryCode::Wf => "WF",
+            CountryCode::Ws => "WS",
+            CountryCode::Ye => "YE",
+            CountryCode::Yt => "YT",
+            CountryCode::Za => "ZA",
+            CountryCode::Zm => "ZM",
+            CountryCode::Zw => "ZW",
+            CountryCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CountryCode {
+    fn default() -> CountryCode {
+        CountryCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CountryCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CountryCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CountryCode {
+    fn from(value: &'a str) -> CountryCode {
+        match value {
+            "AC" => CountryCode::Ac,
+            "AD" => CountryCode::Ad,
+            "AE" => CountryCode::Ae,
+            "AF" => CountryCode::Af,
+            "AG" => CountryCode::Ag,
+            "AI" => CountryCode::Ai,
+            "AL" => CountryCode::Al,
+            "AM" => CountryCode::Am,
+            "AN" => CountryCode::An,
+            "AO" => CountryCode::Ao,
+            "AQ" => CountryCode::Aq,
+            "AR" => CountryCode::Ar,
+            "AS" => CountryCode::As,
+            "AT" => CountryCode::At,
+            "AU" => CountryCode::Au,
+            "AW" => CountryCode::Aw,
+            "AX" => CountryCode::Ax,
+            "AZ" => CountryCode::Az,
+            "BA" => CountryCode::Ba,
+            "BB" => CountryCode::Bb,
+            "BD" => CountryCode::Bd,
+            "BE" => CountryCode::Be,
+            "BF" => CountryCode::Bf,
+            "BG" => CountryCode::Bg,
+            "BH" => CountryCode::Bh,
+            "BI" => CountryCode::Bi,
+            "BJ" => CountryCode::Bj,
+            "BL" => CountryCode::Bl,
+            "BM" => CountryCode::Bm,
+            "BN" => CountryCode::Bn,
+            "BO" => CountryCode::Bo,
+            "BQ" => CountryCode::Bq,
+            "BR" => CountryCode::Br,
+            "BS" => CountryCode::Bs,
+            "BT" => CountryCode::Bt,
+            "BV" => CountryCode::Bv,
+            "BW" => CountryCode::Bw,
+            "BY" => CountryCode::By,
+            "BZ" => CountryCode::Bz,
+            "CA" => CountryCode::Ca,
+            "CC" => CountryCode::Cc,
+            "CD" => CountryCode::Cd,
+            "CF" => CountryCode::Cf,
+            "CG" => CountryCode::Cg,
+            "CH" => CountryCode::Ch,
+            "CI" => CountryCode::Ci,
+            "CK" => CountryCode::Ck,
+            "CL" => CountryCode::Cl,
+            "CM" => CountryCode::Cm,
+            "CN" => CountryCode::Cn,
+            "CO" => CountryCode::Co,
+            "CR" => CountryCode::Cr,
+            "CU" => CountryCode::Cu,
+            "CV" => CountryCode::Cv,
+            "CW" => CountryCode::Cw,
+            "CX" => CountryCode::Cx,
+            "CY" => CountryCode::Cy,
+            "CZ" => CountryCode::Cz,
+            "DE" => CountryCode::De,
+            "DJ" => CountryCode::Dj,
+            "DK" => CountryCode::Dk,
+            "DM" => CountryCode::Dm,
+            "DO" => CountryCode::Do,
+            "DZ" => CountryCode::Dz,
+            "EC" => CountryCode::Ec,
+            "EE" => CountryCode::Ee,
+            "EG" => CountryCode::Eg,
+            "EH" => CountryCode::Eh,
+            "ER" => CountryCode::Er,
+            "ES" => CountryCode::Es,
+            "ET" => CountryCode::Et,
+            "FI" => CountryCode::Fi,
+            "FJ" => CountryCode::Fj,
+            "FK" => CountryCode::Fk,
+            "FM" => CountryCode::Fm,
+            "FO" => CountryCode::Fo,
+            "FR" => CountryCode::Fr,
+            "GA" => CountryCode::Ga,
+            "GB" => CountryCode::Gb,
+            "GD" => CountryCode::Gd,
+            "GE" => CountryCode::Ge,
+            "GF" => CountryCode::Gf,
+            "GG" => CountryCode::Gg,
+            "GH" => CountryCode::Gh,
+            "GI" => CountryCode::Gi,
+            "GL" => CountryCode::Gl,
+            "GM" => CountryCode::Gm,
+            "GN" => CountryCode::Gn,
+            "GP" => CountryCode::Gp,
+            "GQ" => CountryCode::Gq,
+            "GR" => CountryCode::Gr,
+            "GS" => CountryCode::Gs,
+            "GT" => CountryCode::Gt,
+            "GU" => CountryCode::Gu,
+            "GW" => CountryCode::Gw,
+            "GY" => CountryCode::Gy,
+            "HK" => CountryCode::Hk,
+            "HM" => CountryCode::Hm,
+            "HN" => CountryCode::Hn,
+            "HR" => CountryCode::Hr,
+            "HT" => CountryCode::Ht,
+            "HU" => CountryCode::Hu,
+            "ID" => CountryCode::Id,
+            "IE" => CountryCode::Ie,
+            "IL" => CountryCode::Il,
+            "IM" => CountryCode::Im,
+            "IN" => CountryCode::In,
+            "IO" => CountryCode::Io,
+            "IQ" => CountryCode::Iq,
+            "IR" => CountryCode::Ir,
+            "IS" => CountryCode::Is,
+            "IT" => CountryCode::It,
+            "JE" => CountryCode::Je,
+            "JM" => CountryCode::Jm,
+            "JO" => CountryCode::Jo,
+            "JP" => CountryCode::Jp,
+            "KE" => CountryCode::Ke,
+            "KG" => CountryCode::Kg,
+            "KH" => CountryCode::Kh,
+            "KI" => CountryCode::Ki,
+            "KM" => CountryCode::Km,
+            "KN" => CountryCode::Kn,
+            "KP" => CountryCode::Kp,
+            "KR" => CountryCode::Kr,
+            "KW" => CountryCode::Kw,
+            "KY" => CountryCode::Ky,
+            "KZ" => CountryCode::Kz,
+            "LA" => CountryCode::La,
+            "LB" => CountryCode::Lb,
+            "LC" => CountryCode::Lc,
+            "LI" => CountryCode::Li,
+            "LK" => CountryCode::Lk,
+            "LR" => CountryCode::Lr,
+            "LS" => CountryCode::Ls,
+            "LT" => CountryCode::Lt,
+            "LU" => CountryCode::Lu,
+            "LV" => CountryCode::Lv,
+            "LY" => CountryCode::Ly,
+            "MA" => CountryCode::Ma,
+            "MC" => CountryCode::Mc,
+            "MD" => CountryCode::Md,
+            "ME" => CountryCode::Me,
+            "MF" => CountryCode::Mf,
+            "MG" => CountryCode::Mg,
+            "MH" => CountryCode::Mh,
+            "MK" => CountryCode::Mk,
+            "ML" => CountryCode::Ml,
+            "MM" => CountryCode::Mm,
+            "MN" => CountryCode::Mn,
+            "MO" => CountryCode::Mo,
+            "MP" => CountryCode::Mp,
+            "MQ" => CountryCode::Mq,
+            "MR" => CountryCode::Mr,
+            "MS" => CountryCode::Ms,
+            "MT" => CountryCode::Mt,
+            "MU" => CountryCode::Mu,
+            "MV" => CountryCode::Mv,
+            "MW" => CountryCode::Mw,
+            "MX" => CountryCode::Mx,
+            "MY" => CountryCode::My,
+            "MZ" => CountryCode::Mz,
+            "NA" => CountryCode::Na,
+            "NC" => CountryCode::Nc,
+            "NE" => CountryCode::Ne,
+            "NF" => CountryCode::Nf,
+            "NG" => CountryCode::Ng,
+            "NI" => CountryCode::Ni,
+            "NL" => CountryCode::Nl,
+            "NO" => CountryCode::No,
+            "NP" => CountryCode::Np,
+            "NR" => CountryCode::Nr,
+            "NU" => CountryCode::Nu,
+            "NZ" => CountryCode::Nz,
+            "OM" => CountryCode::Om,
+            "PA" => CountryCode::Pa,
+            "PE" => CountryCode::Pe,
+            "PF" => CountryCode::Pf,
+            "PG" => CountryCode::Pg,
+            "PH" => CountryCode::Ph,
+            "PK" => CountryCode::Pk,
+            "PL" => CountryCode::Pl,
+            "PM" => CountryCode::Pm,
+            "PN" => CountryCode::Pn,
+            "PR" => CountryCode::Pr,
+            "PS" => CountryCode::Ps,
+            "PT" => CountryCode::Pt,
+            "PW" => CountryCode::Pw,
+            "PY" => CountryCode::Py,
+            "QA" => CountryCode::Qa,
+            "RE" => CountryCode::Re,
+            "RO" => CountryCode::Ro,
+            "RS" => CountryCode::Rs,
+            "RU" => CountryCode::Ru,
+            "RW" => CountryCode::Rw,
+            "SA" => CountryCode::Sa,
+            "SB" => CountryCode::Sb,
+            "SC" => CountryCode::Sc,
+            "SD" => CountryCode::Sd,
+            "SE" => CountryCode::Se,
+            "SG" => CountryCode::Sg,
+            "SH" => CountryCode::Sh,
+            "SI" => CountryCode::Si,
+            "SJ" => CountryCode::Sj,
+            "SK" => CountryCode::Sk,
+            "SL" => CountryCode::Sl,
+            "SM" => CountryCode::Sm,
+            "SN" => CountryCode::Sn,
+            "SO" => CountryCode::So,
+            "SR" => CountryCode::Sr,
+            "SS" => CountryCode::Ss,
+            "ST" => CountryCode::St,
+            "SV" => CountryCode::Sv,
+            "SX" => CountryCode::Sx,
+            "SY" => CountryCode::Sy,
+            "SZ" => CountryCode::Sz,
+            "TC" => CountryCode::Tc,
+            "TD" => CountryCode::Td,
+            "TF" => CountryCode::Tf,
+            "TG" => CountryCode::Tg,
+            "TH" => CountryCode::Th,
+            "TJ" => CountryCode::Tj,
+            "TK" => CountryCode::Tk,
+            "TL" => CountryCode::Tl,
+            "TM" => CountryCode::Tm,
+            "TN" => CountryCode::Tn,
+            "TO" => CountryCode::To,
+            "TP" => CountryCode::Tp,
+            "TR" => CountryCode::Tr,
+            "TT" => CountryCode::Tt,
+            "TV" => CountryCode::Tv,
+            "TW" => CountryCode::Tw,
+            "TZ" => CountryCode::Tz,
+            "UA" => CountryCode::Ua,
+            "UG" => CountryCode::Ug,
+            "US" => CountryCode::Us,
+            "UY" => CountryCode::Uy,
+            "UZ" => CountryCode::Uz,
+            "VA" => CountryCode::Va,
+            "VC" => CountryCode::Vc,
+            "VE" => CountryCode::Ve,
+            "VG" => CountryCode::Vg,
+            "VI" => CountryCode::Vi,
+            "VN" => CountryCode::Vn,
+            "VU" => CountryCode::Vu,
+            "WF" => CountryCode::Wf,
+            "WS" => CountryCode::Ws,
+            "YE" => CountryCode::Ye,
+            "YT" => CountryCode::Yt,
+            "ZA" => CountryCode::Za,
+            "ZM" => CountryCode::Zm,
+            "ZW" => CountryCode::Zw,
+            _ => CountryCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CountryCode {
+    fn from(value: String) -> CountryCode {
+        match value.as_str() {
+            "AC" => CountryCode::Ac,
+            "AD" => CountryCode::Ad,
+            "AE" => CountryCode::Ae,
+            "AF" => CountryCode::Af,
+            "AG" => CountryCode::Ag,
+            "AI" => CountryCode::Ai,
+            "AL" => CountryCode::Al,
+            "AM" => CountryCode::Am,
+            "AN" => CountryCode::An,
+            "AO" => CountryCode::Ao,
+            "AQ" => CountryCode::Aq,
+            "AR" => CountryCode::Ar,
+            "AS" => CountryCode::As,
+            "AT" => CountryCode::At,
+            "AU" => CountryCode::Au,
+            "AW" => CountryCode::Aw,
+            "AX" => CountryCode::Ax,
+            "AZ" => CountryCode::Az,
+            "BA" => CountryCode::Ba,
+            "BB" => CountryCode::Bb,
+            "BD" => CountryCode::Bd,
+            "BE" => CountryCode::Be,
+            "BF" => CountryCode::Bf,
+            "BG" => CountryCode::Bg,
+            "BH" => CountryCode::Bh,
+            "BI" => CountryCode::Bi,
+            "BJ" => CountryCode::Bj,
+            "BL" => CountryCode::Bl,
+            "BM" => CountryCode::Bm,
+            "BN" => CountryCode::Bn,
+            "BO" => CountryCode::Bo,
+            "BQ" => CountryCode::Bq,
+            "BR" => CountryCode::Br,
+            "BS" => CountryCode::Bs,
+            "BT" => CountryCode::Bt,
+            "BV" => CountryCode::Bv,
+            "BW" => CountryCode::Bw,
+            "BY" => CountryCode::By,
+            "BZ" => CountryCode::Bz,
+            "CA" => CountryCode::Ca,
+            "CC" => CountryCode::Cc,
+            "CD" => CountryCode::Cd,
+            "CF" => CountryCode::Cf,
+            "CG" => CountryCode::Cg,
+            "CH" => CountryCode::Ch,
+            "CI" => CountryCode::Ci,
+            "CK" => CountryCode::Ck,
+            "CL" => CountryCode::Cl,
+            "CM" => CountryCode::Cm,
+            "CN" => CountryCode::Cn,
+            "CO" => CountryCode::Co,
+            "CR" => CountryCode::Cr,
+            "CU" => CountryCode::Cu,
+            "CV" => CountryCode::Cv,
+            "CW" => CountryCode::Cw,
+            "CX" => CountryCode::Cx,
+            "CY" => CountryCode::Cy,
+            "CZ" => CountryCode::Cz,
+            "DE" => CountryCode::De,
+            "DJ" => CountryCode::Dj,
+            "DK" => CountryCode::Dk,
+            "DM" => CountryCode::Dm,
+            "DO" => CountryCode::Do,
+            "DZ" => CountryCode::Dz,
+            "EC" => CountryCode::Ec,
+            "EE" => CountryCode::Ee,
+            "EG" => CountryCode::Eg,
+            "EH" => CountryCode::Eh,
+            "ER" => CountryCode::Er,
+            "ES" => CountryCode::Es,
+            "ET" => CountryCode::Et,
+            "FI" => CountryCode::Fi,
+            "FJ" => CountryCode::Fj,
+            "FK" => CountryCode::Fk,
+            "FM" => CountryCode::Fm,
+            "FO" => CountryCode::Fo,
+            "FR" => CountryCode::Fr,
+            "GA" => CountryCode::Ga,
+            "GB" => CountryCode::Gb,
+            "GD" => CountryCode::Gd,
+            "GE" => CountryCode::Ge,
+            "GF" => CountryCode::Gf,
+            "GG" => CountryCode::Gg,
+            "GH" => CountryCode::Gh,
+            "GI" => CountryCode::Gi,
+            "GL" => CountryCode::Gl,
+            "GM" => CountryCode::Gm,
+            "GN" => CountryCode::Gn,
+            "GP" => CountryCode::Gp,
+            "GQ" => CountryCode::Gq,
+            "GR" => CountryCode::Gr,
+            "GS" => CountryCode::Gs,
+            "GT" => CountryCode::Gt,
+            "GU" => CountryCode::Gu,
+            "GW" => CountryCode::Gw,
+            "GY" => CountryCode::Gy,
+            "HK" => CountryCode::Hk,
+            "HM" => CountryCode::Hm,
+            "HN" => CountryCode::Hn,
+            "HR" => CountryCode::Hr,
+            "HT" => CountryCode::Ht,
+            "HU" => CountryCode::Hu,
+            "ID" => CountryCode::Id,
+            "IE" => CountryCode::Ie,
+            "IL" => CountryCode::Il,
+            "IM" => CountryCode::Im,
+            "IN" => CountryCode::In,
+            "IO" => CountryCode::Io,
+            "IQ" => CountryCode::Iq,
+            "IR" => CountryCode::Ir,
+            "IS" => CountryCode::Is,
+            "IT" => CountryCode::It,
+            "JE" => CountryCode::Je,
+            "JM" => CountryCode::Jm,
+            "JO" => CountryCode::Jo,
+            "JP" => CountryCode::Jp,
+            "KE" => CountryCode::Ke,
+            "KG" => CountryCode::Kg,
+            "KH" => CountryCode::Kh,
+            "KI" => CountryCode::Ki,
+            "KM" => CountryCode::Km,
+            "KN" => CountryCode::Kn,
+            "KP" => CountryCode::Kp,
+            "KR" => CountryCode::Kr,
+            "KW" => CountryCode::Kw,
+            "KY" => CountryCode::Ky,
+            "KZ" => CountryCode::Kz,
+            "LA" => CountryCode::La,
+            "LB" => CountryCode::Lb,
+            "LC" => CountryCode::Lc,
+            "LI" => CountryCode::Li,
+            "LK" => CountryCode::Lk,
+            "LR" => CountryCode::Lr,
+            "LS" => CountryCode::Ls,
+            "LT" => CountryCode::Lt,
+            "LU" => CountryCode::Lu,
+            "LV" => CountryCode::Lv,
+            "LY" => CountryCode::Ly,
+            "MA" => CountryCode::Ma,
+            "MC" => CountryCode::Mc,
+            "MD" => CountryCode::Md,
+            "ME" => CountryCode::Me,
+            "MF" => CountryCode::Mf,
+            "MG" => CountryCode::Mg,
+            "MH" => CountryCode::Mh,
+            "MK" => CountryCode::Mk,
+            "ML" => CountryCode::Ml,
+            "MM" => CountryCode::Mm,
+            "MN" => CountryCode::Mn,
+            "MO" => CountryCode::Mo,
+            "MP" => CountryCode::Mp,
+            "MQ" => CountryCode::Mq,
+            "MR" => CountryCode::Mr,
+            "MS" => CountryCode::Ms,
+            "MT" => CountryCode::Mt,
+            "MU" => CountryCode::Mu,
+            "MV" => CountryCode::Mv,
+            "MW" => CountryCode::Mw,
+            "MX" => CountryCode::Mx,
+            "MY" => CountryCode::My,
+            "MZ" => CountryCode::Mz,
+            "NA" => CountryCode::Na,
+            "NC" => CountryCode::Nc,
+            "NE" => CountryCode::Ne,
+            "NF" => CountryCode::Nf,
+            "NG" => CountryCode::Ng,
+            "NI" => CountryCode::Ni,
+            "NL" => CountryCode::Nl,
+            "NO" => CountryCode::No,
+            "NP" => CountryCode::Np,
+            "NR" => CountryCode::Nr,
+            "NU" => CountryCode::Nu,
+            "NZ" => CountryCode::Nz,
+            "OM" => CountryCode::Om,
+            "PA" => CountryCode::Pa,
+            "PE" => CountryCode::Pe,
+            "PF" => CountryCode::Pf,
+            "PG" => CountryCode::Pg,
+            "PH" => CountryCode::Ph,
+            "PK" => CountryCode::Pk,
+            "PL" => CountryCode::Pl,
+            "PM" => CountryCode::Pm,
+            "PN" => CountryCode::Pn,
+            "PR" => CountryCode::Pr,
+            "PS" => CountryCode::Ps,
+            "PT" => CountryCode::Pt,
+            "PW" => CountryCode::Pw,
+            "PY" => CountryCode::Py,
+            "QA" => CountryCode::Qa,
+            "RE" => CountryCode::Re,
+            "RO" => CountryCode::Ro,
+            "RS" => CountryCode::Rs,
+            "RU" => CountryCode::Ru,
+            "RW" => CountryCode::Rw,
+            "SA" => CountryCode::Sa,
+            "SB" => CountryCode::Sb,
+            "SC" => CountryCode::Sc,
+            "SD" => CountryCode::Sd,
+            "SE" => CountryCode::Se,
+            "SG" => CountryCode::Sg,
+            "SH" => CountryCode::Sh,
+            "SI" => CountryCode::Si,
+            "SJ" => CountryCode::Sj,
+            "SK" => CountryCode::Sk,
+            "SL" => CountryCode::Sl,
+            "SM" => CountryCode::Sm,
+            "SN" => CountryCode::Sn,
+            "SO" => CountryCode::So,
+            "SR" => CountryCode::Sr,
+            "SS" => CountryCode::Ss,
+            "ST" => CountryCode::St,
+            "SV" => CountryCode::Sv,
+            "SX" => CountryCode::Sx,
+            "SY" => CountryCode::Sy,
+            "SZ" => CountryCode::Sz,
+            "TC" => CountryCode::Tc,
+            "TD" => CountryCode::Td,
+            "TF" => CountryCode::Tf,
+            "TG" => CountryCode::Tg,
+            "TH" => CountryCode::Th,
+            "TJ" => CountryCode::Tj,
+            "TK" => CountryCode::Tk,
+            "TL" => CountryCode::Tl,
+            "TM" => CountryCode::Tm,
+            "TN" => CountryCode::Tn,
+            "TO" => CountryCode::To,
+            "TP" => CountryCode::Tp,
+            "TR" => CountryCode::Tr,
+            "TT" => CountryCode::Tt,
+            "TV" => CountryCode::Tv,
+            "TW" => CountryCode::Tw,
+            "TZ" => CountryCode::Tz,
+            "UA" => CountryCode::Ua,
+            "UG" => CountryCode::Ug,
+            "US" => CountryCode::Us,
+            "UY" => CountryCode::Uy,
+            "UZ" => CountryCode::Uz,
+            "VA" => CountryCode::Va,
+            "VC" => CountryCode::Vc,
+            "VE" => CountryCode::Ve,
+            "VG" => CountryCode::Vg,
+            "VI" => CountryCode::Vi,
+            "VN" => CountryCode::Vn,
+            "VU" => CountryCode::Vu,
+            "WF" => CountryCode::Wf,
+            "WS" => CountryCode::Ws,
+            "YE" => CountryCode::Ye,
+            "YT" => CountryCode::Yt,
+            "ZA" => CountryCode::Za,
+            "ZM" => CountryCode::Zm,
+            "ZW" => CountryCode::Zw,
+            _ => CountryCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CountryCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CountryCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CountryCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CountryCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CountryCode {
+    fn deserialize<D>(deserializer: D) -> Result<CountryCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The DeleteTagsForDomainRequest includes the following elements.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -201,6 +1652,129 @@ pub struct DisableDomainTransferLockResponse {
     pub operation_id: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DomainAvailability {
+    /// `AVAILABLE`
+    Available,
+    /// `AVAILABLE_RESERVED`
+    AvailableReserved,
+    /// `AVAILABLE_PREORDER`
+    AvailablePreorder,
+    /// `UNAVAILABLE`
+    Unavailable,
+    /// `UNAVAILABLE_PREMIUM`
+    UnavailablePremium,
+    /// `UNAVAILABLE_RESTRICTED`
+    UnavailableRestricted,
+    /// `RESERVED`
+    Reserved,
+    /// `DONT_KNOW`
+    DontKnow,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DomainAvailability {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DomainAvailability::Available => "AVAILABLE",
+            DomainAvailability::AvailableReserved => "AVAILABLE_RESERVED",
+            DomainAvailability::AvailablePreorder => "AVAILABLE_PREORDER",
+            DomainAvailability::Unavailable => "UNAVAILABLE",
+            DomainAvailability::UnavailablePremium => "UNAVAILABLE_PREMIUM",
+            DomainAvailability::UnavailableRestricted => "UNAVAILABLE_RESTRICTED",
+            DomainAvailability::Reserved => "RESERVED",
+            DomainAvailability::DontKnow => "DONT_KNOW",
+            DomainAvailability::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DomainAvailability {
+    fn default() -> DomainAvailability {
+        DomainAvailability::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DomainAvailability {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DomainAvailability {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DomainAvailability {
+    fn from(value: &'a str) -> DomainAvailability {
+        match value {
+            "AVAILABLE" => DomainAvailability::Available,
+            "AVAILABLE_RESERVED" => DomainAvailability::AvailableReserved,
+            "AVAILABLE_PREORDER" => DomainAvailability::AvailablePreorder,
+            "UNAVAILABLE" => DomainAvailability::Unavailable,
+            "UNAVAILABLE_PREMIUM" => DomainAvailability::UnavailablePremium,
+            "UNAVAILABLE_RESTRICTED" => DomainAvailability::UnavailableRestricted,
+            "RESERVED" => DomainAvailability::Reserved,
+            "DONT_KNOW" => DomainAvailability::DontKnow,
+            _ => DomainAvailability::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DomainAvailability {
+    fn from(value: String) -> DomainAvailability {
+        match value.as_str() {
+            "AVAILABLE" => DomainAvailability::Available,
+            "AVAILABLE_RESERVED" => DomainAvailability::AvailableReserved,
+            "AVAILABLE_PREORDER" => DomainAvailability::AvailablePreorder,
+            "UNAVAILABLE" => DomainAvailability::Unavailable,
+            "UNAVAILABLE_PREMIUM" => DomainAvailability::UnavailablePremium,
+            "UNAVAILABLE_RESTRICTED" => DomainAvailability::UnavailableRestricted,
+            "RESERVED" => DomainAvailability::Reserved,
+            "DONT_KNOW" => DomainAvailability::DontKnow,
+            _ => DomainAvailability::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DomainAvailability {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DomainAvailability, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DomainAvailability {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DomainAvailability {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DomainAvailability {
+    fn deserialize<D>(deserializer: D) -> Result<DomainAvailability, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about one suggested domain name.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -228,8 +1802,13 @@ pub struct DomainSummary {
     pub domain_name: String,
     /// <p>Expiration date of the domain in Coordinated Universal Time (UTC).</p>
     #[serde(rename = "Expiry")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expiry: Option<f64>,
+    pub expiry: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Indicates whether a domain is locked from unauthorized transfer to another party.</p>
     #[serde(rename = "TransferLock")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -242,7 +1821,7 @@ pub struct DomainSummary {
 pub struct DomainTransferability {
     #[serde(rename = "Transferable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub transferable: Option<String>,
+    pub transferable: Option<Transferable>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -280,12 +1859,250 @@ pub struct EnableDomainTransferLockResponse {
 pub struct ExtraParam {
     /// <p>Name of the additional parameter required by the top-level domain. Here are the top-level domains that require additional parameters and which parameters they require:</p> <ul> <li> <p> <b>.com.au and .net.au:</b> <code>AU_ID_NUMBER</code> and <code>AU_ID_TYPE</code> </p> </li> <li> <p> <b>.ca:</b> <code>BRAND_NUMBER</code>, <code>CA_LEGAL_TYPE</code>, and <code>CA_BUSINESS_ENTITY_TYPE</code> </p> </li> <li> <p> <b>.es:</b> <code>ES_IDENTIFICATION</code>, <code>ES_IDENTIFICATION_TYPE</code>, and <code>ES_LEGAL_FORM</code> </p> </li> <li> <p> <b>.fi:</b> <code>BIRTH_DATE_IN_YYYY_MM_DD</code>, <code>FI_BUSINESS_NUMBER</code>, <code>FI_ID_NUMBER</code>, <code>FI_NATIONALITY</code>, and <code>FI_ORGANIZATION_TYPE</code> </p> </li> <li> <p> <b>.fr:</b> <code>BRAND_NUMBER</code>, <code>BIRTH_DEPARTMENT</code>, <code>BIRTH_DATE_IN_YYYY_MM_DD</code>, <code>BIRTH_COUNTRY</code>, and <code>BIRTH_CITY</code> </p> </li> <li> <p> <b>.it:</b> <code>BIRTH_COUNTRY</code>, <code>IT_PIN</code>, and <code>IT_REGISTRANT_ENTITY_TYPE</code> </p> </li> <li> <p> <b>.ru:</b> <code>BIRTH_DATE_IN_YYYY_MM_DD</code> and <code>RU_PASSPORT_DATA</code> </p> </li> <li> <p> <b>.se:</b> <code>BIRTH_COUNTRY</code> and <code>SE_ID_NUMBER</code> </p> </li> <li> <p> <b>.sg:</b> <code>SG_ID_NUMBER</code> </p> </li> <li> <p> <b>.co.uk, .me.uk, and .org.uk:</b> <code>UK_CONTACT_TYPE</code> and <code>UK_COMPANY_NUMBER</code> </p> </li> </ul> <p>In addition, many TLDs require <code>VAT_NUMBER</code>.</p>
     #[serde(rename = "Name")]
-    pub name: String,
+    pub name: ExtraParamName,
     /// <p>Values corresponding to the additional parameter names required by some top-level domains.</p>
     #[serde(rename = "Value")]
     pub value: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ExtraParamName {
+    /// `DUNS_NUMBER`
+    DunsNumber,
+    /// `BRAND_NUMBER`
+    BrandNumber,
+    /// `BIRTH_DEPARTMENT`
+    BirthDepartment,
+    /// `BIRTH_DATE_IN_YYYY_MM_DD`
+    BirthDateInYyyyMmDd,
+    /// `BIRTH_COUNTRY`
+    BirthCountry,
+    /// `BIRTH_CITY`
+    BirthCity,
+    /// `DOCUMENT_NUMBER`
+    DocumentNumber,
+    /// `AU_ID_NUMBER`
+    AuIdNumber,
+    /// `AU_ID_TYPE`
+    AuIdType,
+    /// `CA_LEGAL_TYPE`
+    CaLegalType,
+    /// `CA_BUSINESS_ENTITY_TYPE`
+    CaBusinessEntityType,
+    /// `CA_LEGAL_REPRESENTATIVE`
+    CaLegalRepresentative,
+    /// `CA_LEGAL_REPRESENTATIVE_CAPACITY`
+    CaLegalRepresentativeCapacity,
+    /// `ES_IDENTIFICATION`
+    EsIdentification,
+    /// `ES_IDENTIFICATION_TYPE`
+    EsIdentificationType,
+    /// `ES_LEGAL_FORM`
+    EsLegalForm,
+    /// `FI_BUSINESS_NUMBER`
+    FiBusinessNumber,
+    /// `FI_ID_NUMBER`
+    FiIdNumber,
+    /// `FI_NATIONALITY`
+    FiNationality,
+    /// `FI_ORGANIZATION_TYPE`
+    FiOrganizationType,
+    /// `IT_NATIONALITY`
+    ItNationality,
+    /// `IT_PIN`
+    ItPin,
+    /// `IT_REGISTRANT_ENTITY_TYPE`
+    ItRegistrantEntityType,
+    /// `RU_PASSPORT_DATA`
+    RuPassportData,
+    /// `SE_ID_NUMBER`
+    SeIdNumber,
+    /// `SG_ID_NUMBER`
+    SgIdNumber,
+    /// `VAT_NUMBER`
+    VatNumber,
+    /// `UK_CONTACT_TYPE`
+    UkContactType,
+    /// `UK_COMPANY_NUMBER`
+    UkCompanyNumber,
+    /// `EU_COUNTRY_OF_CITIZENSHIP`
+    EuCountryOfCitizenship,
+    /// `AU_PRIORITY_TOKEN`
+    AuPriorityToken,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ExtraParamName {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ExtraParamName::DunsNumber => "DUNS_NUMBER",
+            ExtraParamName::BrandNumber => "BRAND_NUMBER",
+            ExtraParamName::BirthDepartment => "BIRTH_DEPARTMENT",
+            ExtraParamName::BirthDateInYyyyMmDd => "BIRTH_DATE_IN_YYYY_MM_DD",
+            ExtraParamName::BirthCountry => "BIRTH_COUNTRY",
+            ExtraParamName::BirthCity => "BIRTH_CITY",
+            ExtraParamName::DocumentNumber => "DOCUMENT_NUMBER",
+            ExtraParamName::AuIdNumber => "AU_ID_NUMBER",
+            ExtraParamName::AuIdType => "AU_ID_TYPE",
+            ExtraParamName::CaLegalType => "CA_LEGAL_TYPE",
+            ExtraParamName::CaBusinessEntityType => "CA_BUSINESS_ENTITY_TYPE",
+            ExtraParamName::CaLegalRepresentative => "CA_LEGAL_REPRESENTATIVE",
+            ExtraParamName::CaLegalRepresentativeCapacity => "CA_LEGAL_REPRESENTATIVE_CAPACITY",
+            ExtraParamName::EsIdentification => "ES_IDENTIFICATION",
+            ExtraParamName::EsIdentificationType => "ES_IDENTIFICATION_TYPE",
+            ExtraParamName::EsLegalForm => "ES_LEGAL_FORM",
+            ExtraParamName::FiBusinessNumber => "FI_BUSINESS_NUMBER",
+            ExtraParamName::FiIdNumber => "FI_ID_NUMBER",
+            ExtraParamName::FiNationality => "FI_NATIONALITY",
+            ExtraParamName::FiOrganizationType => "FI_ORGANIZATION_TYPE",
+            ExtraParamName::ItNationality => "IT_NATIONALITY",
+            ExtraParamName::ItPin => "IT_PIN",
+            ExtraParamName::ItRegistrantEntityType => "IT_REGISTRANT_ENTITY_TYPE",
+            ExtraParamName::RuPassportData => "RU_PASSPORT_DATA",
+            ExtraParamName::SeIdNumber => "SE_ID_NUMBER",
+            ExtraParamName::SgIdNumber => "SG_ID_NUMBER",
+            ExtraParamName::VatNumber => "VAT_NUMBER",
+            ExtraParamName::UkContactType => "UK_CONTACT_TYPE",
+            ExtraParamName::UkCompanyNumber => "UK_COMPANY_NUMBER",
+            ExtraParamName::EuCountryOfCitizenship => "EU_COUNTRY_OF_CITIZENSHIP",
+            ExtraParamName::AuPriorityToken => "AU_PRIORITY_TOKEN",
+            ExtraParamName::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ExtraParamName {
+    fn default() -> ExtraParamName {
+        ExtraParamName::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ExtraParamName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ExtraParamName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ExtraParamName {
+    fn from(value: &'a str) -> ExtraParamName {
+        match value {
+            "DUNS_NUMBER" => ExtraParamName::DunsNumber,
+            "BRAND_NUMBER" => ExtraParamName::BrandNumber,
+            "BIRTH_DEPARTMENT" => ExtraParamName::BirthDepartment,
+            "BIRTH_DATE_IN_YYYY_MM_DD" => ExtraParamName::BirthDateInYyyyMmDd,
+            "BIRTH_COUNTRY" => ExtraParamName::BirthCountry,
+            "BIRTH_CITY" => ExtraParamName::BirthCity,
+            "DOCUMENT_NUMBER" => ExtraParamName::DocumentNumber,
+            "AU_ID_NUMBER" => ExtraParamName::AuIdNumber,
+            "AU_ID_TYPE" => ExtraParamName::AuIdType,
+            "CA_LEGAL_TYPE" => ExtraParamName::CaLegalType,
+            "CA_BUSINESS_ENTITY_TYPE" => ExtraParamName::CaBusinessEntityType,
+            "CA_LEGAL_REPRESENTATIVE" => ExtraParamName::CaLegalRepresentative,
+            "CA_LEGAL_REPRESENTATIVE_CAPACITY" => ExtraParamName::CaLegalRepresentativeCapacity,
+            "ES_IDENTIFICATION" => ExtraParamName::EsIdentification,
+            "ES_IDENTIFICATION_TYPE" => ExtraParamName::EsIdentificationType,
+            "ES_LEGAL_FORM" => ExtraParamName::EsLegalForm,
+            "FI_BUSINESS_NUMBER" => ExtraParamName::FiBusinessNumber,
+            "FI_ID_NUMBER" => ExtraParamName::FiIdNumber,
+            "FI_NATIONALITY" => ExtraParamName::FiNationality,
+            "FI_ORGANIZATION_TYPE" => ExtraParamName::FiOrganizationType,
+            "IT_NATIONALITY" => ExtraParamName::ItNationality,
+            "IT_PIN" => ExtraParamName::ItPin,
+            "IT_REGISTRANT_ENTITY_TYPE" => ExtraParamName::ItRegistrantEntityType,
+            "RU_PASSPORT_DATA" => ExtraParamName::RuPassportData,
+            "SE_ID_NUMBER" => ExtraParamName::SeIdNumber,
+            "SG_ID_NUMBER" => ExtraParamName::SgIdNumber,
+            "VAT_NUMBER" => ExtraParamName::VatNumber,
+            "UK_CONTACT_TYPE" => ExtraParamName::UkContactType,
+            "UK_COMPANY_NUMBER" => ExtraParamName::UkCompanyNumber,
+            "EU_COUNTRY_OF_CITIZENSHIP" => ExtraParamName::EuCountryOfCitizenship,
+            "AU_PRIORITY_TOKEN" => ExtraParamName::AuPriorityToken,
+            _ => ExtraParamName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ExtraParamName {
+    fn from(value: String) -> ExtraParamName {
+        match value.as_str() {
+            "DUNS_NUMBER" => ExtraParamName::DunsNumber,
+            "BRAND_NUMBER" => ExtraParamName::BrandNumber,
+            "BIRTH_DEPARTMENT" => ExtraParamName::BirthDepartment,
+            "BIRTH_DATE_IN_YYYY_MM_DD" => ExtraParamName::BirthDateInYyyyMmDd,
+            "BIRTH_COUNTRY" => ExtraParamName::BirthCountry,
+            "BIRTH_CITY" => ExtraParamName::BirthCity,
+            "DOCUMENT_NUMBER" => ExtraParamName::DocumentNumber,
+            "AU_ID_NUMBER" => ExtraParamName::AuIdNumber,
+            "AU_ID_TYPE" => ExtraParamName::AuIdType,
+            "CA_LEGAL_TYPE" => ExtraParamName::CaLegalType,
+            "CA_BUSINESS_ENTITY_TYPE" => ExtraParamName::CaBusinessEntityType,
+            "CA_LEGAL_REPRESENTATIVE" => ExtraParamName::CaLegalRepresentative,
+            "CA_LEGAL_REPRESENTATIVE_CAPACITY" => ExtraParamName::CaLegalRepresentativeCapacity,
+            "ES_IDENTIFICATION" => ExtraParamName::EsIdentification,
+            "ES_IDENTIFICATION_TYPE" => ExtraParamName::EsIdentificationType,
+            "ES_LEGAL_FORM" => ExtraParamName::EsLegalForm,
+            "FI_BUSINESS_NUMBER" => ExtraParamName::FiBusinessNumber,
+            "FI_ID_NUMBER" => ExtraParamName::FiIdNumber,
+            "FI_NATIONALITY" => ExtraParamName::FiNationality,
+            "FI_ORGANIZATION_TYPE" => ExtraParamName::FiOrganizationType,
+            "IT_NATIONALITY" => ExtraParamName::ItNationality,
+            "IT_PIN" => ExtraParamName::ItPin,
+            "IT_REGISTRANT_ENTITY_TYPE" => ExtraParamName::ItRegistrantEntityType,
+            "RU_PASSPORT_DATA" => ExtraParamName::RuPassportData,
+            "SE_ID_NUMBER" => ExtraParamName::SeIdNumber,
+            "SG_ID_NUMBER" => ExtraParamName::SgIdNumber,
+            "VAT_NUMBER" => ExtraParamName::VatNumber,
+            "UK_CONTACT_TYPE" => ExtraParamName::UkContactType,
+            "UK_COMPANY_NUMBER" => ExtraParamName::UkCompanyNumber,
+            "EU_COUNTRY_OF_CITIZENSHIP" => ExtraParamName::EuCountryOfCitizenship,
+            "AU_PRIORITY_TOKEN" => ExtraParamName::AuPriorityToken,
+            _ => ExtraParamName::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ExtraParamName {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ExtraParamName, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ExtraParamName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ExtraParamName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ExtraParamName {
+    fn deserialize<D>(deserializer: D) -> Result<ExtraParamName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct GetContactReachabilityStatusRequest {
@@ -305,7 +2122,7 @@ pub struct GetContactReachabilityStatusResponse {
     /// <p><p>Whether the registrant contact has responded. Values include the following:</p> <dl> <dt>PENDING</dt> <dd> <p>We sent the confirmation email and haven&#39;t received a response yet.</p> </dd> <dt>DONE</dt> <dd> <p>We sent the email and got confirmation from the registrant contact.</p> </dd> <dt>EXPIRED</dt> <dd> <p>The time limit expired before the registrant contact responded.</p> </dd> </dl></p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ReachabilityStatus>,
 }
 
 /// <p>The GetDomainDetail request includes the following element.</p>
@@ -342,8 +2159,13 @@ pub struct GetDomainDetailResponse {
     pub auto_renew: Option<bool>,
     /// <p>The date when the domain was created as found in the response to a WHOIS query. The date and time is in Coordinated Universal time (UTC).</p>
     #[serde(rename = "CreationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Reserved for future use.</p>
     #[serde(rename = "DnsSec")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -353,8 +2175,13 @@ pub struct GetDomainDetailResponse {
     pub domain_name: String,
     /// <p>The date when the registration for the domain is set to expire. The date and time is in Coordinated Universal time (UTC).</p>
     #[serde(rename = "ExpirationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expiration_date: Option<f64>,
+    pub expiration_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the domain.</p>
     #[serde(rename = "Nameservers")]
     pub nameservers: Vec<Nameserver>,
@@ -394,8 +2221,13 @@ pub struct GetDomainDetailResponse {
     pub tech_privacy: Option<bool>,
     /// <p>The last updated date of the domain as found in the response to a WHOIS query. The date and time is in Coordinated Universal time (UTC).</p>
     #[serde(rename = "UpdatedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_date: Option<f64>,
+    pub updated_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The fully qualified name of the WHOIS server that can answer the WHOIS query for the domain.</p>
     #[serde(rename = "WhoIsServer")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -453,15 +2285,20 @@ pub struct GetOperationDetailResponse {
     /// <p>The current status of the requested operation in the system.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<OperationStatus>,
     /// <p>The date when the request was submitted.</p>
     #[serde(rename = "SubmittedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submitted_date: Option<f64>,
+    pub submitted_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The type of operation that was requested.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<OperationType>,
 }
 
 /// <p>The ListDomains request includes the following elements.</p>
@@ -505,8 +2342,13 @@ pub struct ListOperationsRequest {
     pub max_items: Option<i64>,
     /// <p>An optional parameter that lets you get information about all the operations that you submitted after a specified date and time. Specify the date and time in Coordinated Universal time (UTC).</p>
     #[serde(rename = "SubmittedSince")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submitted_since: Option<f64>,
+    pub submitted_since: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>The ListOperations response includes the following elements.</p>
@@ -552,6 +2394,114 @@ pub struct Nameserver {
     pub name: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OperationStatus {
+    /// `SUBMITTED`
+    Submitted,
+    /// `IN_PROGRESS`
+    InProgress,
+    /// `ERROR`
+    Error,
+    /// `SUCCESSFUL`
+    Successful,
+    /// `FAILED`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl OperationStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OperationStatus::Submitted => "SUBMITTED",
+            OperationStatus::InProgress => "IN_PROGRESS",
+            OperationStatus::Error => "ERROR",
+            OperationStatus::Successful => "SUCCESSFUL",
+            OperationStatus::Failed => "FAILED",
+            OperationStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for OperationStatus {
+    fn default() -> OperationStatus {
+        OperationStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for OperationStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for OperationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for OperationStatus {
+    fn from(value: &'a str) -> OperationStatus {
+        match value {
+            "SUBMITTED" => OperationStatus::Submitted,
+            "IN_PROGRESS" => OperationStatus::InProgress,
+            "ERROR" => OperationStatus::Error,
+            "SUCCESSFUL" => OperationStatus::Successful,
+            "FAILED" => OperationStatus::Failed,
+            _ => OperationStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OperationStatus {
+    fn from(value: String) -> OperationStatus {
+        match value.as_str() {
+            "SUBMITTED" => OperationStatus::Submitted,
+            "IN_PROGRESS" => OperationStatus::InProgress,
+            "ERROR" => OperationStatus::Error,
+            "SUCCESSFUL" => OperationStatus::Successful,
+            "FAILED" => OperationStatus::Failed,
+            _ => OperationStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for OperationStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<OperationStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OperationStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for OperationStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OperationStatus {
+    fn deserialize<D>(deserializer: D) -> Result<OperationStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>OperationSummary includes the following elements.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -561,15 +2511,291 @@ pub struct OperationSummary {
     pub operation_id: String,
     /// <p>The current status of the requested operation in the system.</p>
     #[serde(rename = "Status")]
-    pub status: String,
+    pub status: OperationStatus,
     /// <p>The date when the request was submitted.</p>
     #[serde(rename = "SubmittedDate")]
-    pub submitted_date: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub submitted_date: ::chrono::DateTime<::chrono::Utc>,
     /// <p>Type of the action requested.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: OperationType,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OperationType {
+    /// `REGISTER_DOMAIN`
+    RegisterDomain,
+    /// `DELETE_DOMAIN`
+    DeleteDomain,
+    /// `TRANSFER_IN_DOMAIN`
+    TransferInDomain,
+    /// `UPDATE_DOMAIN_CONTACT`
+    UpdateDomainContact,
+    /// `UPDATE_NAMESERVER`
+    UpdateNameserver,
+    /// `CHANGE_PRIVACY_PROTECTION`
+    ChangePrivacyProtection,
+    /// `DOMAIN_LOCK`
+    DomainLock,
+    /// `ENABLE_AUTORENEW`
+    EnableAutorenew,
+    /// `DISABLE_AUTORENEW`
+    DisableAutorenew,
+    /// `ADD_DNSSEC`
+    AddDnssec,
+    /// `REMOVE_DNSSEC`
+    RemoveDnssec,
+    /// `EXPIRE_DOMAIN`
+    ExpireDomain,
+    /// `TRANSFER_OUT_DOMAIN`
+    TransferOutDomain,
+    /// `CHANGE_DOMAIN_OWNER`
+    ChangeDomainOwner,
+    /// `RENEW_DOMAIN`
+    RenewDomain,
+    /// `PUSH_DOMAIN`
+    PushDomain,
+    /// `INTERNAL_TRANSFER_OUT_DOMAIN`
+    InternalTransferOutDomain,
+    /// `INTERNAL_TRANSFER_IN_DOMAIN`
+    InternalTransferInDomain,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl OperationType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OperationType::RegisterDomain => "REGISTER_DOMAIN",
+            OperationType::DeleteDomain => "DELETE_DOMAIN",
+            OperationType::TransferInDomain => "TRANSFER_IN_DOMAIN",
+            OperationType::UpdateDomainContact => "UPDATE_DOMAIN_CONTACT",
+            OperationType::UpdateNameserver => "UPDATE_NAMESERVER",
+            OperationType::ChangePrivacyProtection => "CHANGE_PRIVACY_PROTECTION",
+            OperationType::DomainLock => "DOMAIN_LOCK",
+            OperationType::EnableAutorenew => "ENABLE_AUTORENEW",
+            OperationType::DisableAutorenew => "DISABLE_AUTORENEW",
+            OperationType::AddDnssec => "ADD_DNSSEC",
+            OperationType::RemoveDnssec => "REMOVE_DNSSEC",
+            OperationType::ExpireDomain => "EXPIRE_DOMAIN",
+            OperationType::TransferOutDomain => "TRANSFER_OUT_DOMAIN",
+            OperationType::ChangeDomainOwner => "CHANGE_DOMAIN_OWNER",
+            OperationType::RenewDomain => "RENEW_DOMAIN",
+            OperationType::PushDomain => "PUSH_DOMAIN",
+            OperationType::InternalTransferOutDomain => "INTERNAL_TRANSFER_OUT_DOMAIN",
+            OperationType::InternalTransferInDomain => "INTERNAL_TRANSFER_IN_DOMAIN",
+            OperationType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for OperationType {
+    fn default() -> OperationType {
+        OperationType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for OperationType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for OperationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for OperationType {
+    fn from(value: &'a str) -> OperationType {
+        match value {
+            "REGISTER_DOMAIN" => OperationType::RegisterDomain,
+            "DELETE_DOMAIN" => OperationType::DeleteDomain,
+            "TRANSFER_IN_DOMAIN" => OperationType::TransferInDomain,
+            "UPDATE_DOMAIN_CONTACT" => OperationType::UpdateDomainContact,
+            "UPDATE_NAMESERVER" => OperationType::UpdateNameserver,
+            "CHANGE_PRIVACY_PROTECTION" => OperationType::ChangePrivacyProtection,
+            "DOMAIN_LOCK" => OperationType::DomainLock,
+            "ENABLE_AUTORENEW" => OperationType::EnableAutorenew,
+            "DISABLE_AUTORENEW" => OperationType::DisableAutorenew,
+            "ADD_DNSSEC" => OperationType::AddDnssec,
+            "REMOVE_DNSSEC" => OperationType::RemoveDnssec,
+            "EXPIRE_DOMAIN" => OperationType::ExpireDomain,
+            "TRANSFER_OUT_DOMAIN" => OperationType::TransferOutDomain,
+            "CHANGE_DOMAIN_OWNER" => OperationType::ChangeDomainOwner,
+            "RENEW_DOMAIN" => OperationType::RenewDomain,
+            "PUSH_DOMAIN" => OperationType::PushDomain,
+            "INTERNAL_TRANSFER_OUT_DOMAIN" => OperationType::InternalTransferOutDomain,
+            "INTERNAL_TRANSFER_IN_DOMAIN" => OperationType::InternalTransferInDomain,
+            _ => OperationType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OperationType {
+    fn from(value: String) -> OperationType {
+        match value.as_str() {
+            "REGISTER_DOMAIN" => OperationType::RegisterDomain,
+            "DELETE_DOMAIN" => OperationType::DeleteDomain,
+            "TRANSFER_IN_DOMAIN" => OperationType::TransferInDomain,
+            "UPDATE_DOMAIN_CONTACT" => OperationType::UpdateDomainContact,
+            "UPDATE_NAMESERVER" => OperationType::UpdateNameserver,
+            "CHANGE_PRIVACY_PROTECTION" => OperationType::ChangePrivacyProtection,
+            "DOMAIN_LOCK" => OperationType::DomainLock,
+            "ENABLE_AUTORENEW" => OperationType::EnableAutorenew,
+            "DISABLE_AUTORENEW" => OperationType::DisableAutorenew,
+            "ADD_DNSSEC" => OperationType::AddDnssec,
+            "REMOVE_DNSSEC" => OperationType::RemoveDnssec,
+            "EXPIRE_DOMAIN" => OperationType::ExpireDomain,
+            "TRANSFER_OUT_DOMAIN" => OperationType::TransferOutDomain,
+            "CHANGE_DOMAIN_OWNER" => OperationType::ChangeDomainOwner,
+            "RENEW_DOMAIN" => OperationType::RenewDomain,
+            "PUSH_DOMAIN" => OperationType::PushDomain,
+            "INTERNAL_TRANSFER_OUT_DOMAIN" => OperationType::InternalTransferOutDomain,
+            "INTERNAL_TRANSFER_IN_DOMAIN" => OperationType::InternalTransferInDomain,
+            _ => OperationType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for OperationType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<OperationType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OperationType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for OperationType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OperationType {
+    fn deserialize<D>(deserializer: D) -> Result<OperationType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ReachabilityStatus {
+    /// `PENDING`
+    Pending,
+    /// `DONE`
+    Done,
+    /// `EXPIRED`
+    Expired,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ReachabilityStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReachabilityStatus::Pending => "PENDING",
+            ReachabilityStatus::Done => "DONE",
+            ReachabilityStatus::Expired => "EXPIRED",
+            ReachabilityStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ReachabilityStatus {
+    fn default() -> ReachabilityStatus {
+        ReachabilityStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ReachabilityStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ReachabilityStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ReachabilityStatus {
+    fn from(value: &'a str) -> ReachabilityStatus {
+        match value {
+            "PENDING" => ReachabilityStatus::Pending,
+            "DONE" => ReachabilityStatus::Done,
+            "EXPIRED" => ReachabilityStatus::Expired,
+            _ => ReachabilityStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReachabilityStatus {
+    fn from(value: String) -> ReachabilityStatus {
+        match value.as_str() {
+            "PENDING" => ReachabilityStatus::Pending,
+            "DONE" => ReachabilityStatus::Done,
+            "EXPIRED" => ReachabilityStatus::Expired,
+            _ => ReachabilityStatus::Unknown(value),
+        }
+    }
 }
 
+impl ::std::str::FromStr for ReachabilityStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ReachabilityStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ReachabilityStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ReachabilityStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ReachabilityStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ReachabilityStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The RegisterDomain request includes the following elements.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -759,6 +2985,120 @@ pub struct TransferDomainResponse {
     pub operation_id: String,
 }
 
+/// <p>Whether the domain name can be transferred to Route 53.</p> <note> <p>You can transfer only domains that have a value of <code>TRANSFERABLE</code> or <code>Transferable</code>.</p> </note> <p>Valid values:</p> <dl> <dt>TRANSFERABLE</dt> <dd> <p>The domain name can be transferred to Route 53.</p> </dd> <dt>UNTRANSFERRABLE</dt> <dd> <p>The domain name can't be transferred to Route 53.</p> </dd> <dt>DONT_KNOW</dt> <dd> <p>Reserved for future use.</p> </dd> <dt>DOMAIN_IN_OWN_ACCOUNT</dt> <dd> <p>The domain already exists in the current Amazon Web Services account.</p> </dd> <dt>DOMAIN_IN_ANOTHER_ACCOUNT</dt> <dd> <p> the domain exists in another Amazon Web Services account.</p> </dd> <dt>PREMIUM_DOMAIN</dt> <dd> <p>Premium domain transfer is not supported.</p> </dd> </dl>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Transferable {
+    /// `TRANSFERABLE`
+    Transferable,
+    /// `UNTRANSFERABLE`
+    Untransferable,
+    /// `DONT_KNOW`
+    DontKnow,
+    /// `DOMAIN_IN_OWN_ACCOUNT`
+    DomainInOwnAccount,
+    /// `DOMAIN_IN_ANOTHER_ACCOUNT`
+    DomainInAnotherAccount,
+    /// `PREMIUM_DOMAIN`
+    PremiumDomain,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Transferable {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Transferable::Transferable => "TRANSFERABLE",
+            Transferable::Untransferable => "UNTRANSFERABLE",
+            Transferable::DontKnow => "DONT_KNOW",
+            Transferable::DomainInOwnAccount => "DOMAIN_IN_OWN_ACCOUNT",
+            Transferable::DomainInAnotherAccount => "DOMAIN_IN_ANOTHER_ACCOUNT",
+            Transferable::PremiumDomain => "PREMIUM_DOMAIN",
+            Transferable::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Transferable {
+    fn default() -> Transferable {
+        Transferable::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Transferable {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Transferable {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Transferable {
+    fn from(value: &'a str) -> Transferable {
+        match value {
+            "TRANSFERABLE" => Transferable::Transferable,
+            "UNTRANSFERABLE" => Transferable::Untransferable,
+            "DONT_KNOW" => Transferable::DontKnow,
+            "DOMAIN_IN_OWN_ACCOUNT" => Transferable::DomainInOwnAccount,
+            "DOMAIN_IN_ANOTHER_ACCOUNT" => Transferable::DomainInAnotherAccount,
+            "PREMIUM_DOMAIN" => Transferable::PremiumDomain,
+            _ => Transferable::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Transferable {
+    fn from(value: String) -> Transferable {
+        match value.as_str() {
+            "TRANSFERABLE" => Transferable::Transferable,
+            "UNTRANSFERABLE" => Transferable::Untransferable,
+            "DONT_KNOW" => Transferable::DontKnow,
+            "DOMAIN_IN_OWN_ACCOUNT" => Transferable::DomainInOwnAccount,
+            "DOMAIN_IN_ANOTHER_ACCOUNT" => Transferable::DomainInAnotherAccount,
+            "PREMIUM_DOMAIN" => Transferable::PremiumDomain,
+            _ => Transferable::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Transferable {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Transferable, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Transferable {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Transferable {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Transferable {
+    fn deserialize<D>(deserializer: D) -> Result<Transferable, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The UpdateDomainContactPrivacy request includes the following elements.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -863,8 +3203,13 @@ pub struct UpdateTagsForDomainResponse {}
 pub struct ViewBillingRequest {
     /// <p>The end date and time for the time period for which you want a list of billing records. Specify the date and time in Coordinated Universal time (UTC).</p>
     #[serde(rename = "End")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end: Option<f64>,
+    pub end: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>For an initial request for a list of billing records, omit this element. If the number of billing records that are associated with the current AWS account during the specified period is greater than the value that you specified for <code>MaxItems</code>, you can use <code>Marker</code> to return additional billing records. Get the value of <code>NextPageMarker</code> from the previous response, and submit another request that includes the value of <code>NextPageMarker</code> in the <code>Marker</code> element. </p> <p>Constraints: The marker must match the value of <code>NextPageMarker</code> that was returned in the previous response.</p>
     #[serde(rename = "Marker")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -875,8 +3220,13 @@ pub struct ViewBillingRequest {
     pub max_items: Option<i64>,
     /// <p>The beginning date and time for the time period for which you want a list of billing records. Specify the date and time in Coordinated Universal time (UTC).</p>
     #[serde(rename = "Start")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start: Option<f64>,
+    pub start: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>The ViewBilling response includes the following elements.</p>
@@ -2191,11 +4541,12 @@ pub struct Route53DomainsClient {
 impl Route53DomainsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> Route53DomainsClient {
         Route53DomainsClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Route 53 Domains"),
         }
     }
 
@@ -2227,6 +4578,7 @@ impl Route53Domains for Route53DomainsClient {
         input: CheckDomainAvailabilityRequest,
     ) -> Result<CheckDomainAvailabilityResponse, RusotoError<CheckDomainAvailabilityError>> {
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("CheckDomainAvailability");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2259,6 +4611,7 @@ impl Route53Domains for Route53DomainsClient {
     ) -> Result<CheckDomainTransferabilityResponse, RusotoError<CheckDomainTransferabilityError>>
     {
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("CheckDomainTransferability");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2290,6 +4643,7 @@ impl Route53Domains for Route53DomainsClient {
         input: DeleteTagsForDomainRequest,
     ) -> Result<DeleteTagsForDomainResponse, RusotoError<DeleteTagsForDomainError>> {
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("DeleteTagsForDomain");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2321,6 +4675,7 @@ impl Route53Domains for Route53DomainsClient {
         input: DisableDomainAutoRenewRequest,
     ) -> Result<DisableDomainAutoRenewResponse, RusotoError<DisableDomainAutoRenewError>> {
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("DisableDomainAutoRenew");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2353,6 +4708,7 @@ impl Route53Domains for Route53DomainsClient {
     ) -> Result<DisableDomainTransferLockResponse, RusotoError<DisableDomainTransferLockError>>
     {
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("DisableDomainTransferLock");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2384,6 +4740,7 @@ impl Route53Domains for Route53DomainsClient {
         input: EnableDomainAutoRenewRequest,
     ) -> Result<EnableDomainAutoRenewResponse, RusotoError<EnableDomainAutoRenewError>> {
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("EnableDomainAutoRenew");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2415,6 +4772,7 @@ impl Route53Domains for Route53DomainsClient {
         input: EnableDomainTransferLockRequest,
     ) -> Result<EnableDomainTransferLockResponse, RusotoError<EnableDomainTransferLockError>> {
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("EnableDomainTransferLock");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2447,6 +4805,7 @@ impl Route53Domains for Route53DomainsClient {
     ) -> Result<GetContactReachabilityStatusResponse, RusotoError<GetContactReachabilityStatusError>>
     {
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("GetContactReachabilityStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2478,6 +4837,7 @@ impl Route53Domains for Route53DomainsClient {
         input: GetDomainDetailRequest,
     ) -> Result<GetDomainDetailResponse, RusotoError<GetDomainDetailError>> {
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("GetDomainDetail");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53Domains_v20140515.GetDomainDetail");
@@ -2505,6 +4865,7 @@ impl Route53Domains for Route53DomainsClient {
         input: GetDomainSuggestionsRequest,
     ) -> Result<GetDomainSuggestionsResponse, RusotoError<GetDomainSuggestionsError>> {
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("GetDomainSuggestions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2536,6 +4897,7 @@ impl Route53Domains for Route53DomainsClient {
         input: GetOperationDetailRequest,
     ) -> Result<GetOperationDetailResponse, RusotoError<GetOperationDetailError>> {
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("GetOperationDetail");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2567,6 +4929,7 @@ impl Route53Domains for Route53DomainsClient {
         input: ListDomainsRequest,
     ) -> Result<ListDomainsResponse, RusotoError<ListDomainsError>> {
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("ListDomains");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53Domains_v20140515.ListDomains");
@@ -2594,6 +4957,7 @@ impl Route53Domains for Route53DomainsClient {
         input: ListOperationsRequest,
     ) -> Result<ListOperationsResponse, RusotoError<ListOperationsError>> {
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("ListOperations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53Domains_v20140515.ListOperations");
@@ -2621,6 +4985,7 @@ impl Route53Domains for Route53DomainsClient {
         input: ListTagsForDomainRequest,
     ) -> Result<ListTagsForDomainResponse, RusotoError<ListTagsForDomainError>> {
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("ListTagsForDomain");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53Domains_v20140515.ListTagsForDomain");
@@ -2649,6 +5014,7 @@ impl Route53Domains for Route53DomainsClient {
         input: RegisterDomainRequest,
     ) -> Result<RegisterDomainResponse, RusotoError<RegisterDomainError>> {
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("RegisterDomain");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53Domains_v20140515.RegisterDomain");
@@ -2676,6 +5042,7 @@ impl Route53Domains for Route53DomainsClient {
         input: RenewDomainRequest,
     ) -> Result<RenewDomainResponse, RusotoError<RenewDomainError>> {
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("RenewDomain");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53Domains_v20140515.RenewDomain");
@@ -2706,6 +5073,7 @@ impl Route53Domains for Route53DomainsClient {
         RusotoError<ResendContactReachabilityEmailError>,
     > {
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("ResendContactReachabilityEmail");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2737,6 +5105,7 @@ impl Route53Domains for Route53DomainsClient {
         input: RetrieveDomainAuthCodeRequest,
     ) -> Result<RetrieveDomainAuthCodeResponse, RusotoError<RetrieveDomainAuthCodeError>> {
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("RetrieveDomainAuthCode");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2768,6 +5137,7 @@ impl Route53Domains for Route53DomainsClient {
         input: TransferDomainRequest,
     ) -> Result<TransferDomainResponse, RusotoError<TransferDomainError>> {
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("TransferDomain");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53Domains_v20140515.TransferDomain");
@@ -2795,6 +5165,7 @@ impl Route53Domains for Route53DomainsClient {
         input: UpdateDomainContactRequest,
     ) -> Result<UpdateDomainContactResponse, RusotoError<UpdateDomainContactError>> {
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("UpdateDomainContact");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2827,6 +5198,7 @@ impl Route53Domains for Route53DomainsClient {
     ) -> Result<UpdateDomainContactPrivacyResponse, RusotoError<UpdateDomainContactPrivacyError>>
     {
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("UpdateDomainContactPrivacy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2858,6 +5230,7 @@ impl Route53Domains for Route53DomainsClient {
         input: UpdateDomainNameserversRequest,
     ) -> Result<UpdateDomainNameserversResponse, RusotoError<UpdateDomainNameserversError>> {
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("UpdateDomainNameservers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2889,6 +5262,7 @@ impl Route53Domains for Route53DomainsClient {
         input: UpdateTagsForDomainRequest,
     ) -> Result<UpdateTagsForDomainResponse, RusotoError<UpdateTagsForDomainError>> {
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("UpdateTagsForDomain");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2920,6 +5294,7 @@ impl Route53Domains for Route53DomainsClient {
         input: ViewBillingRequest,
     ) -> Result<ViewBillingResponse, RusotoError<ViewBillingError>> {
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("ViewBilling");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53Domains_v20140515.ViewBilling");
@@ -2941,3 +5316,97 @@ impl Route53Domains for Route53DomainsClient {
         }
     }
 }
+impl Route53DomainsClient {
+    /// Auto-paginating version of `list_domains`, streaming every page of results.
+    pub fn list_domains_pages(
+        &self,
+        input: ListDomainsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListDomainsResponse, ListDomainsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: Route53DomainsClient, input: ListDomainsRequest| async move {
+                client.list_domains(input).await
+            },
+            |input: &ListDomainsRequest, output: &ListDomainsResponse| {
+                Some(ListDomainsRequest {
+                    marker: Some(output.next_page_marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_domains`, streaming the `Domains` of every page of results.
+    pub fn list_domains_items(
+        &self,
+        input: ListDomainsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DomainSummary, ListDomainsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_domains_pages(input),
+            |output: ListDomainsResponse| output.domains,
+        )
+    }
+
+    /// Auto-paginating version of `list_operations`, streaming every page of results.
+    pub fn list_operations_pages(
+        &self,
+        input: ListOperationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListOperationsResponse, ListOperationsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: Route53DomainsClient, input: ListOperationsRequest| async move {
+                client.list_operations(input).await
+            },
+            |input: &ListOperationsRequest, output: &ListOperationsResponse| {
+                Some(ListOperationsRequest {
+                    marker: Some(output.next_page_marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_operations`, streaming the `Operations` of every page of results.
+    pub fn list_operations_items(
+        &self,
+        input: ListOperationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<OperationSummary, ListOperationsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_operations_pages(input),
+            |output: ListOperationsResponse| output.operations,
+        )
+    }
+
+    /// Auto-paginating version of `view_billing`, streaming every page of results.
+    pub fn view_billing_pages(
+        &self,
+        input: ViewBillingRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ViewBillingResponse, ViewBillingError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: Route53DomainsClient, input: ViewBillingRequest| async move {
+                client.view_billing(input).await
+            },
+            |input: &ViewBillingRequest, output: &ViewBillingResponse| {
+                Some(ViewBillingRequest {
+                    marker: Some(output.next_page_marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `view_billing`, streaming the `BillingRecords` of every page of results.
+    pub fn view_billing_items(
+        &self,
+        input: ViewBillingRequest,
+    ) -> rusoto_core::pagination::RusotoStream<BillingRecord, ViewBillingError> {
+        rusoto_core::pagination::paginate_items(
+            self.view_billing_pages(input),
+            |output: ViewBillingResponse| output.billing_records.unwrap_or_default(),
+        )
+    }
+}
@@ -76,7 +76,7 @@ pub struct CreateResolverEndpointRequest {
     pub creator_request_id: String,
     /// <p><p>Specify the applicable value:</p> <ul> <li> <p> <code>INBOUND</code>: Resolver forwards DNS queries to the DNS service for a VPC from your network or another VPC</p> </li> <li> <p> <code>OUTBOUND</code>: Resolver forwards DNS queries from the DNS service for a VPC to your network or another VPC</p> </li> </ul></p>
     #[serde(rename = "Direction")]
-    pub direction: String,
+    pub direction: ResolverEndpointDirection,
     /// <p>The subnets and IP addresses in your VPC that you want DNS queries to pass through on the way from your VPCs to your network (for outbound endpoints) or on the way from your network to your VPCs (for inbound resolver endpoints). </p>
     #[serde(rename = "IpAddresses")]
     pub ip_addresses: Vec<IpAddressRequest>,
@@ -121,7 +121,7 @@ pub struct CreateResolverRuleRequest {
     pub resolver_endpoint_id: Option<String>,
     /// <p>Specify <code>FORWARD</code>. Other resolver rule types aren't supported.</p>
     #[serde(rename = "RuleType")]
-    pub rule_type: String,
+    pub rule_type: RuleTypeOption,
     /// <p>A list of the tag keys and values that you want to associate with the endpoint.</p>
     #[serde(rename = "Tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -333,7 +333,7 @@ pub struct IpAddressResponse {
     /// <p>A status code that gives the current status of the request.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<IpAddressStatus>,
     /// <p>A message that provides additional information about the status of the request.</p>
     #[serde(rename = "StatusMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -344,6 +344,149 @@ pub struct IpAddressResponse {
     pub subnet_id: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum IpAddressStatus {
+    /// `CREATING`
+    Creating,
+    /// `FAILED_CREATION`
+    FailedCreation,
+    /// `ATTACHING`
+    Attaching,
+    /// `ATTACHED`
+    Attached,
+    /// `REMAP_DETACHING`
+    RemapDetaching,
+    /// `REMAP_ATTACHING`
+    RemapAttaching,
+    /// `DETACHING`
+    Detaching,
+    /// `FAILED_RESOURCE_GONE`
+    FailedResourceGone,
+    /// `DELETING`
+    Deleting,
+    /// `DELETE_FAILED_FAS_EXPIRED`
+    DeleteFailedFasExpired,
+    /// `UPDATING`
+    Updating,
+    /// `UPDATE_FAILED`
+    UpdateFailed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl IpAddressStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            IpAddressStatus::Creating => "CREATING",
+            IpAddressStatus::FailedCreation => "FAILED_CREATION",
+            IpAddressStatus::Attaching => "ATTACHING",
+            IpAddressStatus::Attached => "ATTACHED",
+            IpAddressStatus::RemapDetaching => "REMAP_DETACHING",
+            IpAddressStatus::RemapAttaching => "REMAP_ATTACHING",
+            IpAddressStatus::Detaching => "DETACHING",
+            IpAddressStatus::FailedResourceGone => "FAILED_RESOURCE_GONE",
+            IpAddressStatus::Deleting => "DELETING",
+            IpAddressStatus::DeleteFailedFasExpired => "DELETE_FAILED_FAS_EXPIRED",
+            IpAddressStatus::Updating => "UPDATING",
+            IpAddressStatus::UpdateFailed => "UPDATE_FAILED",
+            IpAddressStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for IpAddressStatus {
+    fn default() -> IpAddressStatus {
+        IpAddressStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for IpAddressStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for IpAddressStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for IpAddressStatus {
+    fn from(value: &'a str) -> IpAddressStatus {
+        match value {
+            "CREATING" => IpAddressStatus::Creating,
+            "FAILED_CREATION" => IpAddressStatus::FailedCreation,
+            "ATTACHING" => IpAddressStatus::Attaching,
+            "ATTACHED" => IpAddressStatus::Attached,
+            "REMAP_DETACHING" => IpAddressStatus::RemapDetaching,
+            "REMAP_ATTACHING" => IpAddressStatus::RemapAttaching,
+            "DETACHING" => IpAddressStatus::Detaching,
+            "FAILED_RESOURCE_GONE" => IpAddressStatus::FailedResourceGone,
+            "DELETING" => IpAddressStatus::Deleting,
+            "DELETE_FAILED_FAS_EXPIRED" => IpAddressStatus::DeleteFailedFasExpired,
+            "UPDATING" => IpAddressStatus::Updating,
+            "UPDATE_FAILED" => IpAddressStatus::UpdateFailed,
+            _ => IpAddressStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for IpAddressStatus {
+    fn from(value: String) -> IpAddressStatus {
+        match value.as_str() {
+            "CREATING" => IpAddressStatus::Creating,
+            "FAILED_CREATION" => IpAddressStatus::FailedCreation,
+            "ATTACHING" => IpAddressStatus::Attaching,
+            "ATTACHED" => IpAddressStatus::Attached,
+            "REMAP_DETACHING" => IpAddressStatus::RemapDetaching,
+            "REMAP_ATTACHING" => IpAddressStatus::RemapAttaching,
+            "DETACHING" => IpAddressStatus::Detaching,
+            "FAILED_RESOURCE_GONE" => IpAddressStatus::FailedResourceGone,
+            "DELETING" => IpAddressStatus::Deleting,
+            "DELETE_FAILED_FAS_EXPIRED" => IpAddressStatus::DeleteFailedFasExpired,
+            "UPDATING" => IpAddressStatus::Updating,
+            "UPDATE_FAILED" => IpAddressStatus::UpdateFailed,
+            _ => IpAddressStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for IpAddressStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<IpAddressStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for IpAddressStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for IpAddressStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for IpAddressStatus {
+    fn deserialize<D>(deserializer: D) -> Result<IpAddressStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>In an <a>UpdateResolverEndpoint</a> request, information about an IP address to update.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -566,7 +709,7 @@ pub struct ResolverEndpoint {
     /// <p><p>Indicates whether the resolver endpoint allows inbound or outbound DNS queries:</p> <ul> <li> <p> <code>INBOUND</code>: allows DNS queries to your VPC from your network or another VPC</p> </li> <li> <p> <code>OUTBOUND</code>: allows DNS queries from your VPC to your network or another VPC</p> </li> </ul></p>
     #[serde(rename = "Direction")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub direction: Option<String>,
+    pub direction: Option<ResolverEndpointDirection>,
     /// <p>The ID of the VPC that you want to create the resolver endpoint in.</p>
     #[serde(rename = "HostVPCId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -594,13 +737,219 @@ pub struct ResolverEndpoint {
     /// <p>A code that specifies the current status of the resolver endpoint.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ResolverEndpointStatus>,
     /// <p>A detailed description of the status of the resolver endpoint.</p>
     #[serde(rename = "StatusMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status_message: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ResolverEndpointDirection {
+    /// `INBOUND`
+    Inbound,
+    /// `OUTBOUND`
+    Outbound,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ResolverEndpointDirection {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResolverEndpointDirection::Inbound => "INBOUND",
+            ResolverEndpointDirection::Outbound => "OUTBOUND",
+            ResolverEndpointDirection::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ResolverEndpointDirection {
+    fn default() -> ResolverEndpointDirection {
+        ResolverEndpointDirection::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ResolverEndpointDirection {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ResolverEndpointDirection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ResolverEndpointDirection {
+    fn from(value: &'a str) -> ResolverEndpointDirection {
+        match value {
+            "INBOUND" => ResolverEndpointDirection::Inbound,
+            "OUTBOUND" => ResolverEndpointDirection::Outbound,
+            _ => ResolverEndpointDirection::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResolverEndpointDirection {
+    fn from(value: String) -> ResolverEndpointDirection {
+        match value.as_str() {
+            "INBOUND" => ResolverEndpointDirection::Inbound,
+            "OUTBOUND" => ResolverEndpointDirection::Outbound,
+            _ => ResolverEndpointDirection::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ResolverEndpointDirection {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ResolverEndpointDirection, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ResolverEndpointDirection {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ResolverEndpointDirection {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ResolverEndpointDirection {
+    fn deserialize<D>(deserializer: D) -> Result<ResolverEndpointDirection, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ResolverEndpointStatus {
+    /// `CREATING`
+    Creating,
+    /// `OPERATIONAL`
+    Operational,
+    /// `UPDATING`
+    Updating,
+    /// `AUTO_RECOVERING`
+    AutoRecovering,
+    /// `ACTION_NEEDED`
+    ActionNeeded,
+    /// `DELETING`
+    Deleting,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ResolverEndpointStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResolverEndpointStatus::Creating => "CREATING",
+            ResolverEndpointStatus::Operational => "OPERATIONAL",
+            ResolverEndpointStatus::Updating => "UPDATING",
+            ResolverEndpointStatus::AutoRecovering => "AUTO_RECOVERING",
+            ResolverEndpointStatus::ActionNeeded => "ACTION_NEEDED",
+            ResolverEndpointStatus::Deleting => "DELETING",
+            ResolverEndpointStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ResolverEndpointStatus {
+    fn default() -> ResolverEndpointStatus {
+        ResolverEndpointStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ResolverEndpointStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ResolverEndpointStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ResolverEndpointStatus {
+    fn from(value: &'a str) -> ResolverEndpointStatus {
+        match value {
+            "CREATING" => ResolverEndpointStatus::Creating,
+            "OPERATIONAL" => ResolverEndpointStatus::Operational,
+            "UPDATING" => ResolverEndpointStatus::Updating,
+            "AUTO_RECOVERING" => ResolverEndpointStatus::AutoRecovering,
+            "ACTION_NEEDED" => ResolverEndpointStatus::ActionNeeded,
+            "DELETING" => ResolverEndpointStatus::Deleting,
+            _ => ResolverEndpointStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResolverEndpointStatus {
+    fn from(value: String) -> ResolverEndpointStatus {
+        match value.as_str() {
+            "CREATING" => ResolverEndpointStatus::Creating,
+            "OPERATIONAL" => ResolverEndpointStatus::Operational,
+            "UPDATING" => ResolverEndpointStatus::Updating,
+            "AUTO_RECOVERING" => ResolverEndpointStatus::AutoRecovering,
+            "ACTION_NEEDED" => ResolverEndpointStatus::ActionNeeded,
+            "DELETING" => ResolverEndpointStatus::Deleting,
+            _ => ResolverEndpointStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ResolverEndpointStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ResolverEndpointStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ResolverEndpointStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ResolverEndpointStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ResolverEndpointStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ResolverEndpointStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>For queries that originate in your VPC, detailed information about a resolver rule, which specifies how to route DNS queries out of the VPC. The <code>ResolverRule</code> parameter appears in the response to a <a>CreateResolverRule</a>, <a>DeleteResolverRule</a>, <a>GetResolverRule</a>, <a>ListResolverRules</a>, or <a>UpdateResolverRule</a> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -636,15 +985,15 @@ pub struct ResolverRule {
     /// <p>This value is always <code>FORWARD</code>. Other resolver rule types aren't supported.</p>
     #[serde(rename = "RuleType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub rule_type: Option<String>,
+    pub rule_type: Option<RuleTypeOption>,
     /// <p>Whether the rules is shared and, if so, whether the current account is sharing the rule with another account, or another account is sharing the rule with the current account.</p>
     #[serde(rename = "ShareStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub share_status: Option<String>,
+    pub share_status: Option<ShareStatus>,
     /// <p>A code that specifies the current status of the resolver rule.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ResolverRuleStatus>,
     /// <p>A detailed description of the status of a resolver rule.</p>
     #[serde(rename = "StatusMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -674,7 +1023,7 @@ pub struct ResolverRuleAssociation {
     /// <p>A code that specifies the current status of the association between a resolver rule and a VPC.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ResolverRuleAssociationStatus>,
     /// <p>A detailed description of the status of the association between a resolver rule and a VPC.</p>
     #[serde(rename = "StatusMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -685,6 +1034,114 @@ pub struct ResolverRuleAssociation {
     pub vpc_id: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ResolverRuleAssociationStatus {
+    /// `CREATING`
+    Creating,
+    /// `COMPLETE`
+    Complete,
+    /// `DELETING`
+    Deleting,
+    /// `FAILED`
+    Failed,
+    /// `OVERRIDDEN`
+    Overridden,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ResolverRuleAssociationStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResolverRuleAssociationStatus::Creating => "CREATING",
+            ResolverRuleAssociationStatus::Complete => "COMPLETE",
+            ResolverRuleAssociationStatus::Deleting => "DELETING",
+            ResolverRuleAssociationStatus::Failed => "FAILED",
+            ResolverRuleAssociationStatus::Overridden => "OVERRIDDEN",
+            ResolverRuleAssociationStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ResolverRuleAssociationStatus {
+    fn default() -> ResolverRuleAssociationStatus {
+        ResolverRuleAssociationStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ResolverRuleAssociationStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ResolverRuleAssociationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ResolverRuleAssociationStatus {
+    fn from(value: &'a str) -> ResolverRuleAssociationStatus {
+        match value {
+            "CREATING" => ResolverRuleAssociationStatus::Creating,
+            "COMPLETE" => ResolverRuleAssociationStatus::Complete,
+            "DELETING" => ResolverRuleAssociationStatus::Deleting,
+            "FAILED" => ResolverRuleAssociationStatus::Failed,
+            "OVERRIDDEN" => ResolverRuleAssociationStatus::Overridden,
+            _ => ResolverRuleAssociationStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResolverRuleAssociationStatus {
+    fn from(value: String) -> ResolverRuleAssociationStatus {
+        match value.as_str() {
+            "CREATING" => ResolverRuleAssociationStatus::Creating,
+            "COMPLETE" => ResolverRuleAssociationStatus::Complete,
+            "DELETING" => ResolverRuleAssociationStatus::Deleting,
+            "FAILED" => ResolverRuleAssociationStatus::Failed,
+            "OVERRIDDEN" => ResolverRuleAssociationStatus::Overridden,
+            _ => ResolverRuleAssociationStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ResolverRuleAssociationStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ResolverRuleAssociationStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ResolverRuleAssociationStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ResolverRuleAssociationStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ResolverRuleAssociationStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ResolverRuleAssociationStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>In an <a>UpdateResolverRule</a> request, information about the changes that you want to make.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -703,6 +1160,305 @@ pub struct ResolverRuleConfig {
     pub target_ips: Option<Vec<TargetAddress>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ResolverRuleStatus {
+    /// `COMPLETE`
+    Complete,
+    /// `DELETING`
+    Deleting,
+    /// `UPDATING`
+    Updating,
+    /// `FAILED`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ResolverRuleStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResolverRuleStatus::Complete => "COMPLETE",
+            ResolverRuleStatus::Deleting => "DELETING",
+            ResolverRuleStatus::Updating => "UPDATING",
+            ResolverRuleStatus::Failed => "FAILED",
+            ResolverRuleStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ResolverRuleStatus {
+    fn default() -> ResolverRuleStatus {
+        ResolverRuleStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ResolverRuleStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ResolverRuleStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ResolverRuleStatus {
+    fn from(value: &'a str) -> ResolverRuleStatus {
+        match value {
+            "COMPLETE" => ResolverRuleStatus::Complete,
+            "DELETING" => ResolverRuleStatus::Deleting,
+            "UPDATING" => ResolverRuleStatus::Updating,
+            "FAILED" => ResolverRuleStatus::Failed,
+            _ => ResolverRuleStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResolverRuleStatus {
+    fn from(value: String) -> ResolverRuleStatus {
+        match value.as_str() {
+            "COMPLETE" => ResolverRuleStatus::Complete,
+            "DELETING" => ResolverRuleStatus::Deleting,
+            "UPDATING" => ResolverRuleStatus::Updating,
+            "FAILED" => ResolverRuleStatus::Failed,
+            _ => ResolverRuleStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ResolverRuleStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ResolverRuleStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ResolverRuleStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ResolverRuleStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ResolverRuleStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ResolverRuleStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RuleTypeOption {
+    /// `FORWARD`
+    Forward,
+    /// `SYSTEM`
+    System,
+    /// `RECURSIVE`
+    Recursive,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RuleTypeOption {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RuleTypeOption::Forward => "FORWARD",
+            RuleTypeOption::System => "SYSTEM",
+            RuleTypeOption::Recursive => "RECURSIVE",
+            RuleTypeOption::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RuleTypeOption {
+    fn default() -> RuleTypeOption {
+        RuleTypeOption::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RuleTypeOption {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RuleTypeOption {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RuleTypeOption {
+    fn from(value: &'a str) -> RuleTypeOption {
+        match value {
+            "FORWARD" => RuleTypeOption::Forward,
+            "SYSTEM" => RuleTypeOption::System,
+            "RECURSIVE" => RuleTypeOption::Recursive,
+            _ => RuleTypeOption::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RuleTypeOption {
+    fn from(value: String) -> RuleTypeOption {
+        match value.as_str() {
+            "FORWARD" => RuleTypeOption::Forward,
+            "SYSTEM" => RuleTypeOption::System,
+            "RECURSIVE" => RuleTypeOption::Recursive,
+            _ => RuleTypeOption::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RuleTypeOption {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RuleTypeOption, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RuleTypeOption {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RuleTypeOption {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RuleTypeOption {
+    fn deserialize<D>(deserializer: D) -> Result<RuleTypeOption, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ShareStatus {
+    /// `NOT_SHARED`
+    NotShared,
+    /// `SHARED_WITH_ME`
+    SharedWithMe,
+    /// `SHARED_BY_ME`
+    SharedByMe,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ShareStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ShareStatus::NotShared => "NOT_SHARED",
+            ShareStatus::SharedWithMe => "SHARED_WITH_ME",
+            ShareStatus::SharedByMe => "SHARED_BY_ME",
+            ShareStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ShareStatus {
+    fn default() -> ShareStatus {
+        ShareStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ShareStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ShareStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ShareStatus {
+    fn from(value: &'a str) -> ShareStatus {
+        match value {
+            "NOT_SHARED" => ShareStatus::NotShared,
+            "SHARED_WITH_ME" => ShareStatus::SharedWithMe,
+            "SHARED_BY_ME" => ShareStatus::SharedByMe,
+            _ => ShareStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ShareStatus {
+    fn from(value: String) -> ShareStatus {
+        match value.as_str() {
+            "NOT_SHARED" => ShareStatus::NotShared,
+            "SHARED_WITH_ME" => ShareStatus::SharedWithMe,
+            "SHARED_BY_ME" => ShareStatus::SharedByMe,
+            _ => ShareStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ShareStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ShareStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ShareStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ShareStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ShareStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ShareStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>One tag that you want to add to the specified resource. A tag consists of a <code>Key</code> (a name for the tag) and a <code>Value</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Tag {
@@ -2381,11 +3137,12 @@ pub struct Route53ResolverClient {
 impl Route53ResolverClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> Route53ResolverClient {
         Route53ResolverClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Route53Resolver"),
         }
     }
 
@@ -2420,6 +3177,7 @@ impl Route53Resolver for Route53ResolverClient {
         RusotoError<AssociateResolverEndpointIpAddressError>,
     > {
         let mut request = SignedRequest::new("POST", "route53resolver", &self.region, "/");
+        request.set_operation("AssociateResolverEndpointIpAddress");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2453,6 +3211,7 @@ impl Route53Resolver for Route53ResolverClient {
         input: AssociateResolverRuleRequest,
     ) -> Result<AssociateResolverRuleResponse, RusotoError<AssociateResolverRuleError>> {
         let mut request = SignedRequest::new("POST", "route53resolver", &self.region, "/");
+        request.set_operation("AssociateResolverRule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53Resolver.AssociateResolverRule");
@@ -2481,6 +3240,7 @@ impl Route53Resolver for Route53ResolverClient {
         input: CreateResolverEndpointRequest,
     ) -> Result<CreateResolverEndpointResponse, RusotoError<CreateResolverEndpointError>> {
         let mut request = SignedRequest::new("POST", "route53resolver", &self.region, "/");
+        request.set_operation("CreateResolverEndpoint");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53Resolver.CreateResolverEndpoint");
@@ -2509,6 +3269,7 @@ impl Route53Resolver for Route53ResolverClient {
         input: CreateResolverRuleRequest,
     ) -> Result<CreateResolverRuleResponse, RusotoError<CreateResolverRuleError>> {
         let mut request = SignedRequest::new("POST", "route53resolver", &self.region, "/");
+        request.set_operation("CreateResolverRule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53Resolver.CreateResolverRule");
@@ -2537,6 +3298,7 @@ impl Route53Resolver for Route53ResolverClient {
         input: DeleteResolverEndpointRequest,
     ) -> Result<DeleteResolverEndpointResponse, RusotoError<DeleteResolverEndpointError>> {
         let mut request = SignedRequest::new("POST", "route53resolver", &self.region, "/");
+        request.set_operation("DeleteResolverEndpoint");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53Resolver.DeleteResolverEndpoint");
@@ -2565,6 +3327,7 @@ impl Route53Resolver for Route53ResolverClient {
         input: DeleteResolverRuleRequest,
     ) -> Result<DeleteResolverRuleResponse, RusotoError<DeleteResolverRuleError>> {
         let mut request = SignedRequest::new("POST", "route53resolver", &self.region, "/");
+        request.set_operation("DeleteResolverRule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53Resolver.DeleteResolverRule");
@@ -2596,6 +3359,7 @@ impl Route53Resolver for Route53ResolverClient {
         RusotoError<DisassociateResolverEndpointIpAddressError>,
     > {
         let mut request = SignedRequest::new("POST", "route53resolver", &self.region, "/");
+        request.set_operation("DisassociateResolverEndpointIpAddress");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2629,6 +3393,7 @@ impl Route53Resolver for Route53ResolverClient {
         input: DisassociateResolverRuleRequest,
     ) -> Result<DisassociateResolverRuleResponse, RusotoError<DisassociateResolverRuleError>> {
         let mut request = SignedRequest::new("POST", "route53resolver", &self.region, "/");
+        request.set_operation("DisassociateResolverRule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53Resolver.DisassociateResolverRule");
@@ -2657,6 +3422,7 @@ impl Route53Resolver for Route53ResolverClient {
         input: GetResolverEndpointRequest,
     ) -> Result<GetResolverEndpointResponse, RusotoError<GetResolverEndpointError>> {
         let mut request = SignedRequest::new("POST", "route53resolver", &self.region, "/");
+        request.set_operation("GetResolverEndpoint");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53Resolver.GetResolverEndpoint");
@@ -2685,6 +3451,7 @@ impl Route53Resolver for Route53ResolverClient {
         input: GetResolverRuleRequest,
     ) -> Result<GetResolverRuleResponse, RusotoError<GetResolverRuleError>> {
         let mut request = SignedRequest::new("POST", "route53resolver", &self.region, "/");
+        request.set_operation("GetResolverRule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53Resolver.GetResolverRule");
@@ -2713,6 +3480,7 @@ impl Route53Resolver for Route53ResolverClient {
     ) -> Result<GetResolverRuleAssociationResponse, RusotoError<GetResolverRuleAssociationError>>
     {
         let mut request = SignedRequest::new("POST", "route53resolver", &self.region, "/");
+        request.set_operation("GetResolverRuleAssociation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53Resolver.GetResolverRuleAssociation");
@@ -2741,6 +3509,7 @@ impl Route53Resolver for Route53ResolverClient {
         input: GetResolverRulePolicyRequest,
     ) -> Result<GetResolverRulePolicyResponse, RusotoError<GetResolverRulePolicyError>> {
         let mut request = SignedRequest::new("POST", "route53resolver", &self.region, "/");
+        request.set_operation("GetResolverRulePolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53Resolver.GetResolverRulePolicy");
@@ -2772,6 +3541,7 @@ impl Route53Resolver for Route53ResolverClient {
         RusotoError<ListResolverEndpointIpAddressesError>,
     > {
         let mut request = SignedRequest::new("POST", "route53resolver", &self.region, "/");
+        request.set_operation("ListResolverEndpointIpAddresses");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2805,6 +3575,7 @@ impl Route53Resolver for Route53ResolverClient {
         input: ListResolverEndpointsRequest,
     ) -> Result<ListResolverEndpointsResponse, RusotoError<ListResolverEndpointsError>> {
         let mut request = SignedRequest::new("POST", "route53resolver", &self.region, "/");
+        request.set_operation("ListResolverEndpoints");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53Resolver.ListResolverEndpoints");
@@ -2834,6 +3605,7 @@ impl Route53Resolver for Route53ResolverClient {
     ) -> Result<ListResolverRuleAssociationsResponse, RusotoError<ListResolverRuleAssociationsError>>
     {
         let mut request = SignedRequest::new("POST", "route53resolver", &self.region, "/");
+        request.set_operation("ListResolverRuleAssociations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2865,6 +3637,7 @@ impl Route53Resolver for Route53ResolverClient {
         input: ListResolverRulesRequest,
     ) -> Result<ListResolverRulesResponse, RusotoError<ListResolverRulesError>> {
         let mut request = SignedRequest::new("POST", "route53resolver", &self.region, "/");
+        request.set_operation("ListResolverRules");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53Resolver.ListResolverRules");
@@ -2893,6 +3666,7 @@ impl Route53Resolver for Route53ResolverClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = SignedRequest::new("POST", "route53resolver", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53Resolver.ListTagsForResource");
@@ -2921,6 +3695,7 @@ impl Route53Resolver for Route53ResolverClient {
         input: PutResolverRulePolicyRequest,
     ) -> Result<PutResolverRulePolicyResponse, RusotoError<PutResolverRulePolicyError>> {
         let mut request = SignedRequest::new("POST", "route53resolver", &self.region, "/");
+        request.set_operation("PutResolverRulePolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53Resolver.PutResolverRulePolicy");
@@ -2949,6 +3724,7 @@ impl Route53Resolver for Route53ResolverClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = SignedRequest::new("POST", "route53resolver", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53Resolver.TagResource");
@@ -2976,6 +3752,7 @@ impl Route53Resolver for Route53ResolverClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = SignedRequest::new("POST", "route53resolver", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53Resolver.UntagResource");
@@ -3003,6 +3780,7 @@ impl Route53Resolver for Route53ResolverClient {
         input: UpdateResolverEndpointRequest,
     ) -> Result<UpdateResolverEndpointResponse, RusotoError<UpdateResolverEndpointError>> {
         let mut request = SignedRequest::new("POST", "route53resolver", &self.region, "/");
+        request.set_operation("UpdateResolverEndpoint");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53Resolver.UpdateResolverEndpoint");
@@ -3031,6 +3809,7 @@ impl Route53Resolver for Route53ResolverClient {
         input: UpdateResolverRuleRequest,
     ) -> Result<UpdateResolverRuleResponse, RusotoError<UpdateResolverRuleError>> {
         let mut request = SignedRequest::new("POST", "route53resolver", &self.region, "/");
+        request.set_operation("UpdateResolverRule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53Resolver.UpdateResolverRule");
@@ -3053,3 +3832,182 @@ impl Route53Resolver for Route53ResolverClient {
         }
     }
 }
+impl Route53ResolverClient {
+    /// Auto-paginating version of `list_resolver_endpoint_ip_addresses`, streaming every page of results.
+    pub fn list_resolver_endpoint_ip_addresses_pages(
+        &self,
+        input: ListResolverEndpointIpAddressesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListResolverEndpointIpAddressesResponse,
+        ListResolverEndpointIpAddressesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: Route53ResolverClient, input: ListResolverEndpointIpAddressesRequest| async move {
+                client.list_resolver_endpoint_ip_addresses(input).await
+            },
+            |input: &ListResolverEndpointIpAddressesRequest,
+             output: &ListResolverEndpointIpAddressesResponse| {
+                Some(ListResolverEndpointIpAddressesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_resolver_endpoint_ip_addresses`, streaming the `IpAddresses` of every page of results.
+    pub fn list_resolver_endpoint_ip_addresses_items(
+        &self,
+        input: ListResolverEndpointIpAddressesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        IpAddressResponse,
+        ListResolverEndpointIpAddressesError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_resolver_endpoint_ip_addresses_pages(input),
+            |output: ListResolverEndpointIpAddressesResponse| {
+                output.ip_addresses.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_resolver_endpoints`, streaming every page of results.
+    pub fn list_resolver_endpoints_pages(
+        &self,
+        input: ListResolverEndpointsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListResolverEndpointsResponse,
+        ListResolverEndpointsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: Route53ResolverClient, input: ListResolverEndpointsRequest| async move {
+                client.list_resolver_endpoints(input).await
+            },
+            |input: &ListResolverEndpointsRequest, output: &ListResolverEndpointsResponse| {
+                Some(ListResolverEndpointsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_resolver_endpoints`, streaming the `ResolverEndpoints` of every page of results.
+    pub fn list_resolver_endpoints_items(
+        &self,
+        input: ListResolverEndpointsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ResolverEndpoint, ListResolverEndpointsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_resolver_endpoints_pages(input),
+            |output: ListResolverEndpointsResponse| output.resolver_endpoints.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_resolver_rule_associations`, streaming every page of results.
+    pub fn list_resolver_rule_associations_pages(
+        &self,
+        input: ListResolverRuleAssociationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListResolverRuleAssociationsResponse,
+        ListResolverRuleAssociationsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: Route53ResolverClient, input: ListResolverRuleAssociationsRequest| async move {
+                client.list_resolver_rule_associations(input).await
+            },
+            |input: &ListResolverRuleAssociationsRequest,
+             output: &ListResolverRuleAssociationsResponse| {
+                Some(ListResolverRuleAssociationsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_resolver_rule_associations`, streaming the `ResolverRuleAssociations` of every page of results.
+    pub fn list_resolver_rule_associations_items(
+        &self,
+        input: ListResolverRuleAssociationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ResolverRuleAssociation,
+        ListResolverRuleAssociationsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_resolver_rule_associations_pages(input),
+            |output: ListResolverRuleAssociationsResponse| {
+                output.resolver_rule_associations.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_resolver_rules`, streaming every page of results.
+    pub fn list_resolver_rules_pages(
+        &self,
+        input: ListResolverRulesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListResolverRulesResponse, ListResolverRulesError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: Route53ResolverClient, input: ListResolverRulesRequest| async move {
+                client.list_resolver_rules(input).await
+            },
+            |input: &ListResolverRulesRequest, output: &ListResolverRulesResponse| {
+                Some(ListResolverRulesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_resolver_rules`, streaming the `ResolverRules` of every page of results.
+    pub fn list_resolver_rules_items(
+        &self,
+        input: ListResolverRulesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ResolverRule, ListResolverRulesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_resolver_rules_pages(input),
+            |output: ListResolverRulesResponse| output.resolver_rules.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_tags_for_resource`, streaming every page of results.
+    pub fn list_tags_for_resource_pages(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListTagsForResourceResponse, ListTagsForResourceError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: Route53ResolverClient, input: ListTagsForResourceRequest| async move {
+                client.list_tags_for_resource(input).await
+            },
+            |input: &ListTagsForResourceRequest, output: &ListTagsForResourceResponse| {
+                Some(ListTagsForResourceRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_tags_for_resource`, streaming the `Tags` of every page of results.
+    pub fn list_tags_for_resource_items(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Tag, ListTagsForResourceError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_tags_for_resource_pages(input),
+            |output: ListTagsForResourceResponse| output.tags.unwrap_or_default(),
+        )
+    }
+}
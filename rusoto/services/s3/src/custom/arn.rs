@@ -0,0 +1,307 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use rusoto_core::Region;
+
+use super::config::is_dns_compatible;
+
+/// The ARN of an S3 access point, or of an access point of S3 on Outposts, which requests take
+/// in place of bucket names.
+///
+/// Requests about access points are sent to their own endpoints, such as
+/// `my-access-point-123456789012.s3-accesspoint.us-west-2.amazonaws.com`, and those about access
+/// points on Outposts are signed for the `s3-outposts` service.
+///
+/// # Examples
+///
+/// ```
+/// use rusoto_s3::AccessPointArn;
+///
+/// let arn: AccessPointArn = "arn:aws:s3:us-west-2:123456789012:accesspoint/my-access-point"
+///     .parse()
+///     .unwrap();
+/// assert_eq!(arn.region(), "us-west-2");
+/// assert_eq!(arn.name(), "my-access-point");
+/// assert_eq!(arn.outpost_id(), None);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AccessPointArn {
+    partition: String,
+    region: String,
+    account_id: String,
+    outpost_id: Option<String>,
+    name: String,
+}
+
+impl AccessPointArn {
+    /// Returns the partition of the access point, such as `aws` or `aws-cn`.
+    pub fn partition(&self) -> &str {
+        &self.partition
+    }
+
+    /// Returns the name of the region of the access point.
+    pub fn region(&self) -> &str {
+        &self.region
+    }
+
+    /// Returns the ID of the account owning the access point.
+    pub fn account_id(&self) -> &str {
+        &self.account_id
+    }
+
+    /// Returns the ID of the Outpost of the access point, if it's an access point of S3 on
+    /// Outposts.
+    pub fn outpost_id(&self) -> Option<&str> {
+        self.outpost_id.as_deref()
+    }
+
+    /// Returns the name of the access point.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the name of the service requests about the access point are signed for.
+    pub fn signing_service(&self) -> &'static str {
+        if self.outpost_id.is_some() {
+            "s3-outposts"
+        } else {
+            "s3"
+        }
+    }
+
+    /// Returns the hostname of the endpoint of the access point, in the partition of its region.
+    pub fn hostname(&self, dual_stack: bool) -> String {
+        let dns_suffix = if self.partition == "aws-cn" {
+            "amazonaws.com.cn"
+        } else {
+            "amazonaws.com"
+        };
+        match self.outpost_id {
+            Some(ref outpost_id) => format!(
+                "{}-{}.{}.s3-outposts.{}.{}",
+                self.name, self.account_id, outpost_id, self.region, dns_suffix
+            ),
+            None => format!(
+                "{}-{}.s3-accesspoint.{}{}.{}",
+                self.name,
+                self.account_id,
+                if dual_stack { "dualstack." } else { "" },
+                self.region,
+                dns_suffix
+            ),
+        }
+    }
+}
+
+impl FromStr for AccessPointArn {
+    type Err = AccessPointArnError;
+
+    fn from_str(s: &str) -> Result<AccessPointArn, AccessPointArnError> {
+        let invalid = || AccessPointArnError::Invalid(s.to_owned());
+        let parts: Vec<&str> = s.splitn(6, ':').collect();
+        let (partition, service, region, account_id, resource) = match parts[..] {
+            ["arn", partition, service, region, account_id, resource]
+                if !partition.is_empty() && !region.is_empty() =>
+            {
+                (partition, service, region, account_id, resource)
+            }
+            _ => return Err(invalid()),
+        };
+        if account_id.is_empty() || !account_id.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(invalid());
+        }
+
+        // resources are separated by slashes or colons alike
+        let resource: Vec<&str> = resource.split(['/', ':']).collect();
+        let (outpost_id, name) = match (service, &resource[..]) {
+            ("s3", ["accesspoint", name]) => (None, name),
+            ("s3-outposts", ["outpost", outpost_id, "accesspoint", name])
+                if !outpost_id.is_empty()
+                    && outpost_id
+                        .bytes()
+                        .all(|b| b.is_ascii_alphanumeric() || b == b'-') =>
+            {
+                (Some(outpost_id.to_string()), name)
+            }
+            _ => return Err(invalid()),
+        };
+        if !is_dns_compatible(name, false) {
+            return Err(invalid());
+        }
+
+        Ok(AccessPointArn {
+            partition: partition.to_owned(),
+            region: region.to_owned(),
+            account_id: account_id.to_owned(),
+            outpost_id,
+            name: name.to_string(),
+        })
+    }
+}
+
+/// Errors of access point ARNs given in place of bucket names.
+#[derive(Debug, PartialEq)]
+pub enum AccessPointArnError {
+    /// The ARN isn't the one of an access point
+    Invalid(String),
+    /// The access point is in another partition than the region of the client
+    PartitionMismatch {
+        /// Partition of the access point
+        arn: String,
+        /// Partition of the region of the client
+        client: String,
+    },
+    /// The access point is in another region than the client, which isn't configured to follow
+    /// the regions of ARNs
+    RegionMismatch {
+        /// Region of the access point
+        arn: String,
+        /// Region of the client
+        client: String,
+    },
+    /// The access point can't be reached through the endpoints the client is configured with
+    Unsupported(String),
+}
+
+impl fmt::Display for AccessPointArnError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            AccessPointArnError::Invalid(ref arn) => write!(f, "Invalid access point ARN: {}", arn),
+            AccessPointArnError::PartitionMismatch {
+                ref arn,
+                ref client,
+            } => write!(
+                f,
+                "Access point in partition {} can't be reached from partition {}",
+                arn, client
+            ),
+            AccessPointArnError::RegionMismatch {
+                ref arn,
+                ref client,
+            } => write!(
+                f,
+                "Access point in region {} can't be reached from region {} unless the region of ARNs is used",
+                arn, client
+            ),
+            AccessPointArnError::Unsupported(ref feature) => {
+                write!(f, "Access point can't be reached through {}", feature)
+            }
+        }
+    }
+}
+
+impl Error for AccessPointArnError {}
+
+/// Splits a path such as `/arn:aws:s3:us-west-2:123456789012:accesspoint/name/key` in the access
+/// point ARN and the path within it, `/key`.
+pub(crate) fn split_arn(path: &str) -> Option<(&str, &str)> {
+    let path = path.strip_prefix('/').unwrap_or(path);
+    if !path.starts_with("arn:") {
+        return None;
+    }
+    let service = path.split(':').nth(2)?;
+    // separators between the parts of the resource, the last one being the name
+    let mut separators = if service == "s3-outposts" { 3 } else { 1 };
+    let (resource_start, _) = path.match_indices(':').nth(4)?;
+    for (index, c) in path[resource_start + 1..].char_indices() {
+        match c {
+            '/' | ':' if separators > 0 => separators -= 1,
+            '/' => {
+                let index = resource_start + 1 + index;
+                return Some((&path[..index], &path[index..]));
+            }
+            _ => (),
+        }
+    }
+    Some((path, "/"))
+}
+
+/// Partition of `region`, which ARNs of resources it can reach are in.
+pub(crate) fn partition(region: &Region) -> &'static str {
+    let name = region.name();
+    if name.starts_with("cn-") {
+        "aws-cn"
+    } else if name.starts_with("us-gov-") {
+        "aws-us-gov"
+    } else {
+        "aws"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_access_point_arns() {
+        let arn: AccessPointArn = "arn:aws:s3:us-west-2:123456789012:accesspoint:my-ap"
+            .parse()
+            .unwrap();
+        assert_eq!(arn.account_id(), "123456789012");
+        assert_eq!(arn.name(), "my-ap");
+        assert_eq!(arn.signing_service(), "s3");
+        assert_eq!(
+            arn.hostname(true),
+            "my-ap-123456789012.s3-accesspoint.dualstack.us-west-2.amazonaws.com"
+        );
+
+        let outpost: AccessPointArn =
+            "arn:aws-cn:s3-outposts:cn-north-1:123456789012:outpost/op-01234567890123456/accesspoint/my-ap"
+                .parse()
+                .unwrap();
+        assert_eq!(outpost.outpost_id(), Some("op-01234567890123456"));
+        assert_eq!(outpost.signing_service(), "s3-outposts");
+        assert_eq!(
+            outpost.hostname(false),
+            "my-ap-123456789012.op-01234567890123456.s3-outposts.cn-north-1.amazonaws.com.cn"
+        );
+
+        for invalid in &[
+            "bucket",
+            "arn:aws:s3:us-west-2:123456789012:bucket/my-bucket",
+            "arn:aws:s3::123456789012:accesspoint/my-ap",
+            "arn:aws:s3:us-west-2:account:accesspoint/my-ap",
+            "arn:aws:s3:us-west-2:123456789012:accesspoint/My_AP",
+            "arn:aws:s3:us-west-2:123456789012:accesspoint/my-ap/extra",
+            "arn:aws:s3-outposts:us-west-2:123456789012:outpost/op-1/bucket/my-bucket",
+        ] {
+            assert_eq!(
+                invalid.parse::<AccessPointArn>(),
+                Err(AccessPointArnError::Invalid(invalid.to_string()))
+            );
+        }
+    }
+
+    #[test]
+    fn splits_arns_from_paths() {
+        assert_eq!(
+            split_arn("/arn:aws:s3:us-west-2:123456789012:accesspoint/my-ap/dir/key"),
+            Some((
+                "arn:aws:s3:us-west-2:123456789012:accesspoint/my-ap",
+                "/dir/key"
+            ))
+        );
+        assert_eq!(
+            split_arn("/arn:aws:s3:us-west-2:123456789012:accesspoint:my-ap"),
+            Some(("arn:aws:s3:us-west-2:123456789012:accesspoint:my-ap", "/"))
+        );
+        assert_eq!(
+            split_arn(
+                "/arn:aws:s3-outposts:us-west-2:123456789012:outpost/op-1/accesspoint/ap/key"
+            ),
+            Some((
+                "arn:aws:s3-outposts:us-west-2:123456789012:outpost/op-1/accesspoint/ap",
+                "/key"
+            ))
+        );
+        assert_eq!(split_arn("/bucket/key"), None);
+    }
+
+    #[test]
+    fn partitions_of_regions() {
+        assert_eq!(partition(&Region::UsEast1), "aws");
+        assert_eq!(partition(&Region::CnNorthwest1), "aws-cn");
+        assert_eq!(partition(&Region::UsGovWest1), "aws-us-gov");
+    }
+}
@@ -0,0 +1,239 @@
+use std::fmt;
+use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use bytes::Bytes;
+use futures::Stream;
+use rusoto_core::signature::{Checksum, ChecksumAlgorithm};
+use rusoto_core::ByteStream;
+
+use crate::generated::{GetObjectOutput, ServerSideEncryption, StreamingBody};
+
+/// Body of an object checked as it's read.
+///
+/// Once the whole body has been read, the stream fails with an error of kind
+/// `io::ErrorKind::InvalidData` if its checksum isn't the one expected. The bytes read until then
+/// are unverified, and shouldn't be trusted before the end of the stream is reached.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use futures::TryStreamExt;
+/// use rusoto_core::Region;
+/// use rusoto_s3::{verify_get_object, GetObjectRequest, S3Client, S3};
+///
+/// # async fn download() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = S3Client::new(Region::UsEast1);
+/// let mut output = client
+///     .get_object(GetObjectRequest {
+///         bucket: "bucket".to_owned(),
+///         key: "key".to_owned(),
+///         ..Default::default()
+///     })
+///     .await?;
+/// verify_get_object(&mut output);
+/// let body = output.body.unwrap().map_ok(|bytes| bytes.to_vec()).try_concat().await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct VerifiedBody {
+    inner: StreamingBody,
+    // left out once the body is checked
+    hasher: Option<Hasher>,
+    expected: String,
+}
+
+enum Hasher {
+    Md5(md5::Context),
+    Checksum(Checksum),
+}
+
+impl Hasher {
+    fn update(&mut self, data: &[u8]) {
+        match *self {
+            Hasher::Md5(ref mut context) => context.consume(data),
+            Hasher::Checksum(ref mut checksum) => checksum.update(data),
+        }
+    }
+
+    fn finalize(self) -> String {
+        match self {
+            Hasher::Md5(context) => format!("{:x}", context.compute()),
+            Hasher::Checksum(checksum) => checksum.finalize_base64(),
+        }
+    }
+}
+
+impl VerifiedBody {
+    /// Checks that the checksum of `body` computed with `algorithm` is `expected`, base64
+    /// encoded as in the `x-amz-checksum-*` headers.
+    pub fn new(body: StreamingBody, algorithm: ChecksumAlgorithm, expected: &str) -> VerifiedBody {
+        VerifiedBody {
+            inner: body,
+            hasher: Some(Hasher::Checksum(Checksum::new(algorithm))),
+            expected: expected.to_owned(),
+        }
+    }
+
+    /// Checks that the MD5 digest of `body` is `expected`, hex encoded as in ETags.
+    pub fn md5(body: StreamingBody, expected: &str) -> VerifiedBody {
+        VerifiedBody {
+            inner: body,
+            hasher: Some(Hasher::Md5(md5::Context::new())),
+            expected: expected.to_ascii_lowercase(),
+        }
+    }
+}
+
+impl Stream for VerifiedBody {
+    type Item = Result<Bytes, io::Error>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        match futures::ready!(Pin::new(&mut this.inner).poll_next(cx)) {
+            Some(Ok(bytes)) => {
+                if let Some(hasher) = this.hasher.as_mut() {
+                    hasher.update(&bytes);
+                }
+                Poll::Ready(Some(Ok(bytes)))
+            }
+            Some(Err(err)) => Poll::Ready(Some(Err(err))),
+            None => match this.hasher.take().map(Hasher::finalize) {
+                Some(actual) if actual != this.expected => Poll::Ready(Some(Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!(
+                        "checksum mismatch: expected {}, got {}",
+                        this.expected, actual
+                    ),
+                )))),
+                _ => Poll::Ready(None),
+            },
+        }
+    }
+}
+
+impl fmt::Debug for VerifiedBody {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VerifiedBody")
+            .field("inner", &self.inner)
+            .field("expected", &self.expected)
+            .finish()
+    }
+}
+
+/// Makes the body of `output` check that it matches the ETag of the object, returning whether it
+/// can be checked.
+///
+/// Only the ETags of objects uploaded in a single part, and not encrypted with SSE-KMS or SSE-C,
+/// are the MD5 digests of their content. Only whole objects can be checked, not ranges of them.
+pub fn verify_get_object(output: &mut GetObjectOutput) -> bool {
+    if output.content_range.is_some()
+        || !e_tag_is_md5(
+            output.server_side_encryption.as_ref(),
+            output.sse_customer_algorithm.as_deref(),
+        )
+    {
+        return false;
+    }
+    let expected = match output.e_tag.as_deref().and_then(md5_e_tag) {
+        Some(expected) => expected,
+        None => return false,
+    };
+    match output.body.take() {
+        Some(body) => {
+            let body = VerifiedBody::md5(body, &expected);
+            output.body = Some(match output.content_length {
+                Some(len) => ByteStream::new_with_size(body, len as usize),
+                None => ByteStream::new(body),
+            });
+            true
+        }
+        None => false,
+    }
+}
+
+/// Whether objects encrypted as the headers of a response say are MD5 digests of their content,
+/// provided they were uploaded in a single part.
+pub(crate) fn e_tag_is_md5(
+    server_side_encryption: Option<&ServerSideEncryption>,
+    sse_customer_algorithm: Option<&str>,
+) -> bool {
+    sse_customer_algorithm.is_none()
+        && !server_side_encryption.is_some_and(|sse| sse.as_str().starts_with("aws:kms"))
+}
+
+/// Returns the MD5 digest an ETag is, hex encoded in lower case, unless it's the ETag of an object
+/// uploaded in several parts.
+pub(crate) fn md5_e_tag(e_tag: &str) -> Option<String> {
+    let e_tag = e_tag.trim_matches('"');
+    if e_tag.len() == 32 && e_tag.bytes().all(|b| b.is_ascii_hexdigit()) {
+        Some(e_tag.to_ascii_lowercase())
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::{stream, TryStreamExt};
+
+    fn body(chunks: &[&'static [u8]]) -> StreamingBody {
+        let chunks: Vec<_> = chunks.iter().map(|chunk| Ok(Bytes::from(*chunk))).collect();
+        ByteStream::new(stream::iter(chunks))
+    }
+
+    async fn read(body: VerifiedBody) -> io::Result<Vec<u8>> {
+        body.map_ok(|bytes| bytes.to_vec()).try_concat().await
+    }
+
+    #[tokio::test]
+    async fn checks_md5_at_the_end() {
+        // MD5 of "hello world"
+        let md5 = "5EB63BBBE01EEED093CB22BB8F5ACDC3";
+        let verified = VerifiedBody::md5(body(&[b"hello ", b"world"]), md5);
+        assert_eq!(read(verified).await.unwrap(), b"hello world");
+
+        let corrupted = VerifiedBody::md5(body(&[b"hello ", b"wOrld"]), md5);
+        let err = read(corrupted).await.unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[tokio::test]
+    async fn checks_checksums_at_the_end() {
+        let crc32 = "DUoRhQ==";
+        let verified = VerifiedBody::new(
+            body(&[b"hello ", b"world"]),
+            ChecksumAlgorithm::Crc32,
+            crc32,
+        );
+        assert_eq!(read(verified).await.unwrap(), b"hello world");
+
+        let corrupted = VerifiedBody::new(body(&[b"hello"]), ChecksumAlgorithm::Crc32, crc32);
+        assert!(read(corrupted).await.is_err());
+    }
+
+    #[test]
+    fn verifies_whole_objects_with_md5_e_tags() {
+        let output = |e_tag: &str| GetObjectOutput {
+            body: Some(body(&[b"hello world"])),
+            e_tag: Some(e_tag.to_owned()),
+            ..Default::default()
+        };
+        assert!(verify_get_object(&mut output(
+            "\"5eb63bbbe01eeed093cb22bb8f5acdc3\""
+        )));
+        assert!(!verify_get_object(&mut output(
+            "\"5eb63bbbe01eeed093cb22bb8f5acdc3-2\""
+        )));
+
+        let mut range = output("\"5eb63bbbe01eeed093cb22bb8f5acdc3\"");
+        range.content_range = Some("bytes 0-4/11".to_owned());
+        assert!(!verify_get_object(&mut range));
+
+        let mut kms = output("\"5eb63bbbe01eeed093cb22bb8f5acdc3\"");
+        kms.server_side_encryption = Some(ServerSideEncryption::AwsKms);
+        assert!(!verify_get_object(&mut kms));
+    }
+}
@@ -0,0 +1,586 @@
+use rusoto_core::signature::ChecksumAlgorithm;
+use rusoto_core::signature::SignedRequest;
+use rusoto_core::Region;
+
+use super::arn::{partition, split_arn, AccessPointArn, AccessPointArnError};
+
+/// How requests address buckets.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AddressingStyle {
+    /// Bucket in the path, as in `https://s3.amazonaws.com/bucket/key`
+    #[default]
+    Path,
+    /// Bucket in the hostname, as in `https://bucket.s3.amazonaws.com/key`
+    VirtualHosted,
+    /// Virtual-hosted style for buckets whose names can be part of a hostname, path style for
+    /// the others and for custom endpoints, such as local stand-ins for S3
+    Auto,
+}
+
+/// Configuration of the endpoints an `S3Client` sends its requests to, and of the checksums it
+/// sends along with uploads.
+///
+/// Requests are sent path style by default. AWS is moving away from it, and buckets in regions
+/// launched from 2020 only accept virtual-hosted style requests, so `AddressingStyle::Auto` is
+/// recommended.
+///
+/// Transfer Acceleration and dual-stack endpoints don't apply to custom regions, whose endpoint
+/// is used as it is.
+///
+/// Requests taking access point ARNs in place of bucket names are sent to the endpoints of the
+/// access points, in the region of the client unless `with_use_arn_region` is set.
+///
+/// Uploads streamed are sent as `UNSIGNED-PAYLOAD` unless `with_chunked_signing` is set, which
+/// signs them chunk by chunk if their content-length is known and they're signed with AWS
+/// Signature Version 4. Only uploads streamed signed chunk by chunk carry their checksum, sent
+/// after them: `with_checksum_algorithm` doesn't turn chunked signing on.
+///
+/// # Examples
+///
+/// ```
+/// use rusoto_core::Region;
+/// use rusoto_s3::{AddressingStyle, S3Client, S3Config};
+///
+/// let config = S3Config::new()
+///     .with_addressing_style(AddressingStyle::Auto)
+///     .with_accelerate(true);
+/// let client = S3Client::new(Region::UsEast1).with_config(config);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct S3Config {
+    addressing_style: AddressingStyle,
+    accelerate: bool,
+    dual_stack: bool,
+    use_arn_region: bool,
+    checksum_algorithm: Option<ChecksumAlgorithm>,
+    chunked_signing: bool,
+    content_md5: bool,
+}
+
+impl S3Config {
+    /// Creates a configuration sending requests path style to the regular endpoints.
+    pub fn new() -> S3Config {
+        S3Config::default()
+    }
+
+    /// Sets how requests address buckets.
+    pub fn with_addressing_style(mut self, addressing_style: AddressingStyle) -> S3Config {
+        self.addressing_style = addressing_style;
+        self
+    }
+
+    /// Sends requests about buckets to the S3 Transfer Acceleration endpoint, virtual-hosted style.
+    ///
+    /// Buckets whose names can't be part of a hostname, or include dots, can't be accelerated and
+    /// are addressed as if it wasn't enabled.
+    pub fn with_accelerate(mut self, accelerate: bool) -> S3Config {
+        self.accelerate = accelerate;
+        self
+    }
+
+    /// Sends requests to the dual-stack endpoints, reachable over both IPv4 and IPv6.
+    pub fn with_dual_stack(mut self, dual_stack: bool) -> S3Config {
+        self.dual_stack = dual_stack;
+        self
+    }
+
+    /// Sends requests about access points in other regions than the one of the client to the
+    /// regions of the access points, signed for them, rather than failing.
+    ///
+    /// Access points have to be in the partition of the client regardless.
+    pub fn with_use_arn_region(mut self, use_arn_region: bool) -> S3Config {
+        self.use_arn_region = use_arn_region;
+        self
+    }
+
+    /// Sends the checksum of the objects and parts uploaded, computed with `algorithm`, for S3 to
+    /// check it.
+    ///
+    /// Uploads streamed are only sent with their checksum if `with_chunked_signing` is set too.
+    pub fn with_checksum_algorithm(mut self, algorithm: ChecksumAlgorithm) -> S3Config {
+        self.checksum_algorithm = Some(algorithm);
+        self
+    }
+
+    /// Sends the Content-MD5 header, for S3 to check it, along with the objects and parts
+    /// uploaded from buffers, as `StreamingBody::from(Vec<u8>)` makes, unless it's set already.
+    pub fn with_content_md5(mut self, content_md5: bool) -> S3Config {
+        self.content_md5 = content_md5;
+        self
+    }
+
+    /// Signs uploads streamed chunk by chunk, with the `aws-chunked` content encoding, rather
+    /// than sending them as `UNSIGNED-PAYLOAD`, if their content-length is known.
+    pub fn with_chunked_signing(mut self, chunked_signing: bool) -> S3Config {
+        self.chunked_signing = chunked_signing;
+        self
+    }
+
+    /// Points `request`, whose path starts with the bucket if it's about one, to the endpoint the
+    /// configuration calls for.
+    ///
+    /// Called by `S3Client` for every request it sends, which fails with a validation error if the
+    /// request is about an access point the configuration can't reach.
+    pub fn configure_request(
+        &self,
+        request: &mut SignedRequest,
+    ) -> Result<(), AccessPointArnError> {
+        if self.checksum_algorithm.is_some() && is_upload(request) {
+            request.set_checksum_algorithm(self.checksum_algorithm);
+        }
+        if self.chunked_signing {
+            request.set_chunked_signing(true);
+        }
+        if self.content_md5 && is_upload(request) && !request.headers.contains_key("content-md5") {
+            request.set_content_md5_header();
+        }
+
+        if let Some((arn, path)) = split_arn(request.path()) {
+            let arn = arn.parse::<AccessPointArn>()?;
+            let path = path.to_owned();
+            self.configure_access_point(request, &arn)?;
+            request.path = path;
+            return Ok(());
+        }
+
+        let custom = matches!(request.region, Region::Custom { .. });
+        let (bucket, path) = match split_bucket(request.path()) {
+            Some((bucket, path)) => (bucket.to_owned(), path.to_owned()),
+            None => {
+                if self.dual_stack && !custom {
+                    let hostname = regional_hostname(&request.region, true);
+                    request.set_hostname(Some(hostname));
+                }
+                return Ok(());
+            }
+        };
+
+        let accelerate = self.accelerate && !custom && is_dns_compatible(&bucket, false);
+        let virtual_hosted = accelerate
+            || match self.addressing_style {
+                AddressingStyle::Path => false,
+                AddressingStyle::VirtualHosted => true,
+                AddressingStyle::Auto => !custom && is_dns_compatible(&bucket, false),
+            };
+        let hostname = if custom {
+            request.hostname()
+        } else if accelerate && self.dual_stack {
+            "s3-accelerate.dualstack.amazonaws.com".to_owned()
+        } else if accelerate {
+            "s3-accelerate.amazonaws.com".to_owned()
+        } else if virtual_hosted || self.dual_stack {
+            regional_hostname(&request.region, self.dual_stack)
+        } else {
+            return Ok(());
+        };
+
+        if virtual_hosted {
+            request.set_hostname(Some(format!("{}.{}", bucket, hostname)));
+            request.path = path;
+        } else {
+            request.set_hostname(Some(hostname));
+        }
+        Ok(())
+    }
+
+    /// Points `request` to the endpoint of the access point `arn`, signed for its region and
+    /// service.
+    fn configure_access_point(
+        &self,
+        request: &mut SignedRequest,
+        arn: &AccessPointArn,
+    ) -> Result<(), AccessPointArnError> {
+        if self.accelerate {
+            return Err(AccessPointArnError::Unsupported(
+                "Transfer Acceleration".to_owned(),
+            ));
+        }
+        if self.dual_stack && arn.outpost_id().is_some() {
+            return Err(AccessPointArnError::Unsupported(
+                "dual-stack endpoints".to_owned(),
+            ));
+        }
+
+        // custom endpoints are used as they are, with the access point in the hostname
+        if let Region::Custom { .. } = request.region {
+            let hostname = match arn.outpost_id() {
+                Some(outpost_id) => format!(
+                    "{}-{}.{}.{}",
+                    arn.name(),
+                    arn.account_id(),
+                    outpost_id,
+                    request.hostname()
+                ),
+                None => format!("{}-{}.{}", arn.name(), arn.account_id(), request.hostname()),
+            };
+            request.set_hostname(Some(hostname));
+            request.service = arn.signing_service().to_owned();
+            return Ok(());
+        }
+
+        let client_partition = partition(&request.region);
+        if arn.partition() != client_partition {
+            return Err(AccessPointArnError::PartitionMismatch {
+                arn: arn.partition().to_owned(),
+                client: client_partition.to_owned(),
+            });
+        }
+        if arn.region() != request.region.name() {
+            let region = match arn.region().parse::<Region>() {
+                Ok(region) if self.use_arn_region && partition(&region) == client_partition => {
+                    region
+                }
+                _ => {
+                    return Err(AccessPointArnError::RegionMismatch {
+                        arn: arn.region().to_owned(),
+                        client: request.region.name().to_owned(),
+                    })
+                }
+            };
+            request.region = region;
+        }
+        request.set_hostname(Some(arn.hostname(self.dual_stack)));
+        request.service = arn.signing_service().to_owned();
+        Ok(())
+    }
+}
+
+/// Splits a path such as `/bucket/key` in the bucket and the path within it, `/key`.
+fn split_bucket(path: &str) -> Option<(&str, &str)> {
+    let path = path.strip_prefix('/').unwrap_or(path);
+    let (bucket, key) = match path.find('/') {
+        Some(index) => (&path[..index], &path[index..]),
+        None => (path, "/"),
+    };
+    if bucket.is_empty() {
+        None
+    } else {
+        Some((bucket, key))
+    }
+}
+
+/// Whether `request` uploads an object or a part of one, `PutObject` or `UploadPart`.
+fn is_upload(request: &SignedRequest) -> bool {
+    request.method() == "PUT"
+        && request.payload.is_some()
+        && split_bucket(request.path()).is_some_and(|(_, key)| key != "/")
+        && request
+            .params
+            .keys()
+            .all(|param| param == "partNumber" || param == "uploadId")
+}
+
+/// Hostname of the S3 endpoint of `region`, with the dots the endpoints of all regions support.
+fn regional_hostname(region: &Region, dual_stack: bool) -> String {
+    let dual_stack = if dual_stack { "dualstack." } else { "" };
+    match *region {
+        Region::UsEast1 if dual_stack.is_empty() => "s3.amazonaws.com".to_owned(),
+        _ if region.name().starts_with("cn-") => {
+            format!("s3.{}{}.amazonaws.com.cn", dual_stack, region.name())
+        }
+        _ => format!("s3.{}{}.amazonaws.com", dual_stack, region.name()),
+    }
+}
+
+/// Whether `bucket` can be part of a hostname, dots being left out unless `allow_dots` as they
+/// break the validation of the certificates of virtual-hosted style endpoints.
+pub(crate) fn is_dns_compatible(bucket: &str, allow_dots: bool) -> bool {
+    let valid_label = |label: &str| {
+        !label.is_empty()
+            && !label.starts_with('-')
+            && !label.ends_with('-')
+            && label
+                .bytes()
+                .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-')
+    };
+    let is_ip_address = bucket.split('.').count() == 4
+        && bucket.split('.').all(|label| label.parse::<u8>().is_ok());
+    (3..=63).contains(&bucket.len())
+        && (allow_dots || !bucket.contains('.'))
+        && bucket.split('.').all(valid_label)
+        && !is_ip_address
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rusoto_core::ByteStream;
+
+    fn configured(config: &S3Config, region: Region, path: &str) -> (String, String) {
+        let mut request = SignedRequest::new("GET", "s3", &region, path);
+        config.configure_request(&mut request).unwrap();
+        (request.hostname(), request.path().to_owned())
+    }
+
+    #[test]
+    fn path_style_by_default() {
+        let config = S3Config::new();
+        assert_eq!(
+            configured(&config, Region::UsEast1, "/bucket/key"),
+            ("s3.amazonaws.com".to_owned(), "/bucket/key".to_owned())
+        );
+        assert_eq!(
+            configured(&config, Region::EuWest1, "/bucket"),
+            (
+                "s3-eu-west-1.amazonaws.com".to_owned(),
+                "/bucket".to_owned()
+            )
+        );
+    }
+
+    #[test]
+    fn auto_addressing_style() {
+        let config = S3Config::new().with_addressing_style(AddressingStyle::Auto);
+        assert_eq!(
+            configured(&config, Region::UsEast1, "/bucket/dir/key"),
+            ("bucket.s3.amazonaws.com".to_owned(), "/dir/key".to_owned())
+        );
+        assert_eq!(
+            configured(&config, Region::MeSouth1, "/bucket"),
+            (
+                "bucket.s3.me-south-1.amazonaws.com".to_owned(),
+                "/".to_owned()
+            )
+        );
+        assert_eq!(
+            configured(&config, Region::CnNorth1, "/bucket/key"),
+            (
+                "bucket.s3.cn-north-1.amazonaws.com.cn".to_owned(),
+                "/key".to_owned()
+            )
+        );
+        // buckets that can't be part of a hostname
+        assert_eq!(
+            configured(&config, Region::UsEast1, "/my.bucket/key"),
+            ("s3.amazonaws.com".to_owned(), "/my.bucket/key".to_owned())
+        );
+        assert_eq!(
+            configured(&config, Region::UsEast1, "/My_Bucket/key"),
+            ("s3.amazonaws.com".to_owned(), "/My_Bucket/key".to_owned())
+        );
+        // requests not about a bucket
+        assert_eq!(
+            configured(&config, Region::UsEast1, "/"),
+            ("s3.amazonaws.com".to_owned(), "/".to_owned())
+        );
+    }
+
+    #[test]
+    fn custom_endpoints() {
+        let region = Region::Custom {
+            name: "local".to_owned(),
+            endpoint: "http://localhost:9000".to_owned(),
+        };
+        let auto = S3Config::new()
+            .with_addressing_style(AddressingStyle::Auto)
+            .with_accelerate(true)
+            .with_dual_stack(true);
+        assert_eq!(
+            configured(&auto, region.clone(), "/bucket/key"),
+            ("localhost:9000".to_owned(), "/bucket/key".to_owned())
+        );
+
+        let virtual_hosted = S3Config::new().with_addressing_style(AddressingStyle::VirtualHosted);
+        assert_eq!(
+            configured(&virtual_hosted, region, "/bucket/key"),
+            ("bucket.localhost:9000".to_owned(), "/key".to_owned())
+        );
+    }
+
+    #[test]
+    fn accelerate_and_dual_stack() {
+        let accelerate = S3Config::new().with_accelerate(true);
+        assert_eq!(
+            configured(&accelerate, Region::EuWest1, "/bucket/key"),
+            (
+                "bucket.s3-accelerate.amazonaws.com".to_owned(),
+                "/key".to_owned()
+            )
+        );
+        // buckets with dots can't be accelerated
+        assert_eq!(
+            configured(&accelerate, Region::EuWest1, "/my.bucket/key"),
+            (
+                "s3-eu-west-1.amazonaws.com".to_owned(),
+                "/my.bucket/key".to_owned()
+            )
+        );
+
+        let both = accelerate.with_dual_stack(true);
+        assert_eq!(
+            configured(&both, Region::EuWest1, "/bucket/key"),
+            (
+                "bucket.s3-accelerate.dualstack.amazonaws.com".to_owned(),
+                "/key".to_owned()
+            )
+        );
+
+        let dual_stack = S3Config::new().with_dual_stack(true);
+        assert_eq!(
+            configured(&dual_stack, Region::UsEast1, "/bucket/key"),
+            (
+                "s3.dualstack.us-east-1.amazonaws.com".to_owned(),
+                "/bucket/key".to_owned()
+            )
+        );
+        assert_eq!(
+            configured(&dual_stack, Region::EuWest1, "/"),
+            (
+                "s3.dualstack.eu-west-1.amazonaws.com".to_owned(),
+                "/".to_owned()
+            )
+        );
+    }
+
+    #[test]
+    fn access_points() {
+        let configured = |config: &S3Config, region: Region, path: &str| {
+            let mut request = SignedRequest::new("GET", "s3", &region, path);
+            config.configure_request(&mut request).map(|_| {
+                (
+                    request.hostname(),
+                    request.path,
+                    request.region,
+                    request.service,
+                )
+            })
+        };
+        let config = S3Config::new();
+        assert_eq!(
+            configured(
+                &config,
+                Region::UsWest2,
+                "/arn:aws:s3:us-west-2:123456789012:accesspoint/my-ap/dir/key"
+            ),
+            Ok((
+                "my-ap-123456789012.s3-accesspoint.us-west-2.amazonaws.com".to_owned(),
+                "/dir/key".to_owned(),
+                Region::UsWest2,
+                "s3".to_owned()
+            ))
+        );
+        assert_eq!(
+            configured(
+                &config,
+                Region::UsWest2,
+                "/arn:aws:s3-outposts:us-west-2:123456789012:outpost/op-01234567890123456/accesspoint/my-ap"
+            ),
+            Ok((
+                "my-ap-123456789012.op-01234567890123456.s3-outposts.us-west-2.amazonaws.com"
+                    .to_owned(),
+                "/".to_owned(),
+                Region::UsWest2,
+                "s3-outposts".to_owned()
+            ))
+        );
+
+        let other_region = "/arn:aws:s3:eu-west-1:123456789012:accesspoint/my-ap/key";
+        assert_eq!(
+            configured(&config, Region::UsWest2, other_region),
+            Err(AccessPointArnError::RegionMismatch {
+                arn: "eu-west-1".to_owned(),
+                client: "us-west-2".to_owned(),
+            })
+        );
+        let use_arn_region = S3Config::new().with_use_arn_region(true);
+        assert_eq!(
+            configured(&use_arn_region, Region::UsWest2, other_region),
+            Ok((
+                "my-ap-123456789012.s3-accesspoint.eu-west-1.amazonaws.com".to_owned(),
+                "/key".to_owned(),
+                Region::EuWest1,
+                "s3".to_owned()
+            ))
+        );
+        assert_eq!(
+            configured(
+                &use_arn_region,
+                Region::UsWest2,
+                "/arn:aws-cn:s3:cn-north-1:123456789012:accesspoint/my-ap/key"
+            ),
+            Err(AccessPointArnError::PartitionMismatch {
+                arn: "aws-cn".to_owned(),
+                client: "aws".to_owned(),
+            })
+        );
+
+        let accelerate = S3Config::new().with_accelerate(true);
+        assert!(configured(
+            &accelerate,
+            Region::UsWest2,
+            "/arn:aws:s3:us-west-2:123456789012:accesspoint/my-ap/key"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn checksums_of_uploads() {
+        let config = S3Config::new().with_checksum_algorithm(ChecksumAlgorithm::Crc32c);
+        let checksum_algorithm = |method: &str, path: &str, param: Option<&str>| {
+            let mut request = SignedRequest::new(method, "s3", &Region::UsEast1, path);
+            request.set_payload(Some(&b"data"[..]));
+            if let Some(param) = param {
+                request.add_param(param, "1");
+            }
+            config.configure_request(&mut request).unwrap();
+            request.checksum_algorithm()
+        };
+        assert_eq!(
+            checksum_algorithm("PUT", "/bucket/key", None),
+            Some(ChecksumAlgorithm::Crc32c)
+        );
+        assert_eq!(
+            checksum_algorithm("PUT", "/bucket/key", Some("partNumber")),
+            Some(ChecksumAlgorithm::Crc32c)
+        );
+        assert_eq!(
+            checksum_algorithm("PUT", "/bucket/key", Some("tagging")),
+            None
+        );
+        assert_eq!(checksum_algorithm("PUT", "/bucket", None), None);
+        assert_eq!(checksum_algorithm("POST", "/bucket/key", None), None);
+    }
+
+    #[test]
+    fn chunked_signing_is_opt_in() {
+        let chunked_signing = |config: S3Config| {
+            let mut request = SignedRequest::new("PUT", "s3", &Region::UsEast1, "/bucket/key");
+            config.configure_request(&mut request).unwrap();
+            request.chunked_signing()
+        };
+        assert!(!chunked_signing(S3Config::new()));
+        assert!(chunked_signing(S3Config::new().with_chunked_signing(true)));
+    }
+
+    #[test]
+    fn content_md5_of_uploads() {
+        let content_md5 = |config: &S3Config, path: &str| {
+            let mut request = SignedRequest::new("PUT", "s3", &Region::UsEast1, path);
+            request.set_payload_stream(ByteStream::from(b"hello world".to_vec()));
+            config.configure_request(&mut request).unwrap();
+            request
+                .headers
+                .get("content-md5")
+                .map(|values| values[0].clone())
+        };
+        let config = S3Config::new().with_content_md5(true);
+        assert_eq!(
+            content_md5(&config, "/bucket/key"),
+            Some(b"XrY7u+Ae7tCTyyK7j1rNww==".to_vec())
+        );
+        assert_eq!(content_md5(&config, "/bucket"), None);
+        assert_eq!(content_md5(&S3Config::new(), "/bucket/key"), None);
+    }
+
+    #[test]
+    fn dns_compatible_buckets() {
+        assert!(is_dns_compatible("my-bucket-1", false));
+        assert!(is_dns_compatible("my.bucket", true));
+        assert!(!is_dns_compatible("my.bucket", false));
+        assert!(!is_dns_compatible("ab", false));
+        assert!(!is_dns_compatible("-bucket", false));
+        assert!(!is_dns_compatible("my..bucket", true));
+        assert!(!is_dns_compatible("MyBucket", false));
+        assert!(!is_dns_compatible("192.168.5.4", true));
+    }
+}
@@ -1,5 +1,23 @@
 /// Utility helpers for working with S3
 pub mod util;
 
+mod arn;
+pub use self::arn::{AccessPointArn, AccessPointArnError};
+
+mod checksum;
+pub use self::checksum::{verify_get_object, VerifiedBody};
+
+mod config;
+pub use self::config::{AddressingStyle, S3Config};
+
+mod sse;
+pub use self::sse::{CopySourceSseCustomerKeyRequest, SseCustomerKey, SseCustomerKeyRequest};
+
+mod sync;
+pub use self::sync::{DirectorySync, SyncAction, SyncDirection, SyncError, SyncPlan};
+
+mod transfer;
+pub use self::transfer::{TransferError, TransferManager, TransferProgress};
+
 #[cfg(test)]
 mod custom_tests;
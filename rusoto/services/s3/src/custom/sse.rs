@@ -0,0 +1,198 @@
+use std::fmt;
+
+use crate::generated::{
+    CopyObjectRequest, CreateMultipartUploadRequest, GetObjectRequest, HeadObjectRequest,
+    PutObjectRequest, SelectObjectContentRequest, UploadPartCopyRequest, UploadPartRequest,
+};
+
+/// Algorithm S3 encrypts objects with customer-provided keys with
+const SSE_CUSTOMER_ALGORITHM: &str = "AES256";
+
+/// A 256-bit key S3 encrypts objects with, server-side encryption with customer-provided keys
+/// (SSE-C).
+///
+/// S3 doesn't store the key: every request about the object, reading, copying or uploading parts
+/// of it, has to carry the key again. `SseCustomerKeyRequest` sets it on these requests.
+///
+/// # Examples
+///
+/// ```
+/// use rusoto_s3::{GetObjectRequest, SseCustomerKey, SseCustomerKeyRequest};
+///
+/// let key = SseCustomerKey::new([7; 32]);
+/// let request = GetObjectRequest {
+///     bucket: "bucket".to_owned(),
+///     key: "secret".to_owned(),
+///     ..Default::default()
+/// }
+/// .with_sse_customer_key(&key);
+/// assert_eq!(request.sse_customer_algorithm.as_deref(), Some("AES256"));
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct SseCustomerKey {
+    key: [u8; 32],
+}
+
+impl SseCustomerKey {
+    /// Creates a customer-provided key from its 256 bits.
+    pub fn new(key: [u8; 32]) -> SseCustomerKey {
+        SseCustomerKey { key }
+    }
+
+    /// Returns the algorithm S3 encrypts objects with, `AES256`.
+    pub fn algorithm(&self) -> &'static str {
+        SSE_CUSTOMER_ALGORITHM
+    }
+
+    /// Returns the key, base64 encoded as S3 expects it.
+    pub fn key_base64(&self) -> String {
+        base64::encode(&self.key)
+    }
+
+    /// Returns the MD5 digest of the key, base64 encoded, which S3 checks the key against.
+    pub fn key_md5_base64(&self) -> String {
+        base64::encode(&*md5::compute(self.key))
+    }
+
+    /// Returns the headers carrying the key, which requests sent to presigned URLs of objects
+    /// encrypted with it need, their names in lower case.
+    pub fn headers(&self) -> Vec<(&'static str, String)> {
+        vec![
+            (
+                "x-amz-server-side-encryption-customer-algorithm",
+                self.algorithm().to_owned(),
+            ),
+            (
+                "x-amz-server-side-encryption-customer-key",
+                self.key_base64(),
+            ),
+            (
+                "x-amz-server-side-encryption-customer-key-md5",
+                self.key_md5_base64(),
+            ),
+        ]
+    }
+}
+
+impl From<[u8; 32]> for SseCustomerKey {
+    fn from(key: [u8; 32]) -> SseCustomerKey {
+        SseCustomerKey::new(key)
+    }
+}
+
+// the key is a secret, left out
+impl fmt::Debug for SseCustomerKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SseCustomerKey")
+            .field("key_md5", &self.key_md5_base64())
+            .finish()
+    }
+}
+
+/// Requests about objects encrypted with customer-provided keys.
+pub trait SseCustomerKeyRequest: Sized {
+    /// Sets the algorithm, key and MD5 digest of the key of the request.
+    fn set_sse_customer_key(&mut self, key: &SseCustomerKey);
+
+    /// Returns the request with the algorithm, key and MD5 digest of the key set.
+    fn with_sse_customer_key(mut self, key: &SseCustomerKey) -> Self {
+        self.set_sse_customer_key(key);
+        self
+    }
+}
+
+/// Requests copying objects encrypted with customer-provided keys.
+pub trait CopySourceSseCustomerKeyRequest: Sized {
+    /// Sets the algorithm, key and MD5 digest of the key of the object copied.
+    fn set_copy_source_sse_customer_key(&mut self, key: &SseCustomerKey);
+
+    /// Returns the request with the algorithm, key and MD5 digest of the key of the object
+    /// copied set.
+    fn with_copy_source_sse_customer_key(mut self, key: &SseCustomerKey) -> Self {
+        self.set_copy_source_sse_customer_key(key);
+        self
+    }
+}
+
+macro_rules! impl_sse_customer_key_request {
+    ($($request:ty),*) => {
+        $(
+            impl SseCustomerKeyRequest for $request {
+                fn set_sse_customer_key(&mut self, key: &SseCustomerKey) {
+                    self.sse_customer_algorithm = Some(key.algorithm().to_owned());
+                    self.sse_customer_key = Some(key.key_base64());
+                    self.sse_customer_key_md5 = Some(key.key_md5_base64());
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_copy_source_sse_customer_key_request {
+    ($($request:ty),*) => {
+        $(
+            impl CopySourceSseCustomerKeyRequest for $request {
+                fn set_copy_source_sse_customer_key(&mut self, key: &SseCustomerKey) {
+                    self.copy_source_sse_customer_algorithm = Some(key.algorithm().to_owned());
+                    self.copy_source_sse_customer_key = Some(key.key_base64());
+                    self.copy_source_sse_customer_key_md5 = Some(key.key_md5_base64());
+                }
+            }
+        )*
+    };
+}
+
+impl_sse_customer_key_request!(
+    CopyObjectRequest,
+    CreateMultipartUploadRequest,
+    GetObjectRequest,
+    HeadObjectRequest,
+    PutObjectRequest,
+    SelectObjectContentRequest,
+    UploadPartCopyRequest,
+    UploadPartRequest
+);
+
+impl_copy_source_sse_customer_key_request!(CopyObjectRequest, UploadPartCopyRequest);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encodes_keys() {
+        let key = SseCustomerKey::new([0; 32]);
+        assert_eq!(
+            key.key_base64(),
+            "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA="
+        );
+        // MD5 of 32 zero bytes, 70bc8f4b72a86921468bf8e8441dce51
+        assert_eq!(key.key_md5_base64(), "cLyPS3KoaSFGi/joRB3OUQ==");
+        assert!(!format!("{:?}", key).contains(&key.key_base64()));
+    }
+
+    #[test]
+    fn sets_keys_of_requests() {
+        let key = SseCustomerKey::new([1; 32]);
+        let source = SseCustomerKey::new([2; 32]);
+        let request = CopyObjectRequest::default()
+            .with_sse_customer_key(&key)
+            .with_copy_source_sse_customer_key(&source);
+
+        assert_eq!(request.sse_customer_algorithm.as_deref(), Some("AES256"));
+        assert_eq!(request.sse_customer_key, Some(key.key_base64()));
+        assert_eq!(request.sse_customer_key_md5, Some(key.key_md5_base64()));
+        assert_eq!(
+            request.copy_source_sse_customer_algorithm.as_deref(),
+            Some("AES256")
+        );
+        assert_eq!(
+            request.copy_source_sse_customer_key,
+            Some(source.key_base64())
+        );
+        assert_eq!(
+            request.copy_source_sse_customer_key_md5,
+            Some(source.key_md5_base64())
+        );
+    }
+}
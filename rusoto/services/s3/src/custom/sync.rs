@@ -0,0 +1,909 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use futures::{stream, StreamExt, TryStreamExt};
+use rusoto_core::RusotoError;
+use tokio::fs::{self, File};
+use tokio::io::AsyncReadExt;
+
+use super::transfer::{TransferError, TransferManager};
+use crate::generated::{
+    CreateMultipartUploadRequest, Delete, DeleteObjectsError, DeleteObjectsRequest,
+    GetObjectRequest, ListObjectsV2Error, ListObjectsV2Request, ObjectIdentifier, PutObjectError,
+    PutObjectRequest, S3Error, S3,
+};
+
+const DEFAULT_CONCURRENCY: usize = 4;
+// most keys a DeleteObjects request can delete
+const MAX_DELETE_KEYS: usize = 1000;
+const MIB: u64 = 1024 * 1024;
+
+/// Which way a `DirectorySync` copies files.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SyncDirection {
+    /// From the local directory to the bucket
+    Upload,
+    /// From the bucket to the local directory
+    Download,
+}
+
+/// A change a `DirectorySync` makes to mirror its source.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SyncAction {
+    /// Upload the file at `path` to `key`
+    Upload {
+        /// The file uploaded
+        path: PathBuf,
+        /// The key it's uploaded to
+        key: String,
+        /// Its size
+        size: u64,
+    },
+    /// Download the object `key` to `path`
+    Download {
+        /// The object downloaded
+        key: String,
+        /// The file it's downloaded to
+        path: PathBuf,
+        /// Its size
+        size: u64,
+    },
+    /// Delete the object `key`, missing from the local directory
+    DeleteObject {
+        /// The object deleted
+        key: String,
+    },
+    /// Delete the file at `path`, missing from the bucket
+    DeleteFile {
+        /// The file deleted
+        path: PathBuf,
+    },
+}
+
+/// The changes a `DirectorySync` makes, in the order it finds them.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SyncPlan {
+    /// The changes
+    pub actions: Vec<SyncAction>,
+}
+
+#[derive(Clone, Debug)]
+enum Filter {
+    Include(String),
+    Exclude(String),
+}
+
+/// Mirrors a local directory to a prefix of a bucket, or a prefix of a bucket to a local directory,
+/// like `aws s3 sync`.
+///
+/// Files are copied when they are missing from the destination, when their size differs or when
+/// the source was modified after the destination. `with_etag_comparison` compares the contents
+/// of files of the same size instead, computing the ETags of local files, multipart ones
+/// included: ETags of objects encrypted with SSE-C or SSE-KMS aren't digests of their contents,
+/// so they're always copied.
+///
+/// Files are named after their path relative to the directory, with `/` separating directories,
+/// and objects after their key without the prefix. Objects whose name would lead outside of the
+/// directory, and keys ending in `/` that mark directories, are skipped.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use rusoto_core::Region;
+/// use rusoto_s3::{DirectorySync, S3Client, TransferManager};
+///
+/// # async fn sync() -> Result<(), rusoto_s3::SyncError> {
+/// let transfers = TransferManager::new(S3Client::new(Region::UsEast1));
+/// let sync = DirectorySync::upload(transfers, "site", "bucket", "www")
+///     .with_exclude("*.tmp")
+///     .with_delete(true);
+///
+/// // dry run
+/// for action in sync.plan().await?.actions {
+///     println!("{:?}", action);
+/// }
+/// sync.run().await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct DirectorySync {
+    transfers: TransferManager,
+    direction: SyncDirection,
+    directory: PathBuf,
+    bucket: String,
+    prefix: String,
+    delete: bool,
+    compare_etags: bool,
+    filters: Vec<Filter>,
+    concurrency: usize,
+}
+
+struct LocalFile {
+    path: PathBuf,
+    size: u64,
+    modified: i64,
+}
+
+struct RemoteObject {
+    key: String,
+    size: u64,
+    e_tag: Option<String>,
+    modified: Option<i64>,
+}
+
+impl DirectorySync {
+    /// Creates a `DirectorySync` uploading the files of `directory` to `prefix` in `bucket`.
+    pub fn upload<D, B, P>(
+        transfers: TransferManager,
+        directory: D,
+        bucket: B,
+        prefix: P,
+    ) -> DirectorySync
+    where
+        D: Into<PathBuf>,
+        B: Into<String>,
+        P: Into<String>,
+    {
+        DirectorySync::new(SyncDirection::Upload, transfers, directory, bucket, prefix)
+    }
+
+    /// Creates a `DirectorySync` downloading the objects under `prefix` in `bucket` to
+    /// `directory`.
+    pub fn download<B, P, D>(
+        transfers: TransferManager,
+        bucket: B,
+        prefix: P,
+        directory: D,
+    ) -> DirectorySync
+    where
+        B: Into<String>,
+        P: Into<String>,
+        D: Into<PathBuf>,
+    {
+        DirectorySync::new(
+            SyncDirection::Download,
+            transfers,
+            directory,
+            bucket,
+            prefix,
+        )
+    }
+
+    fn new<D, B, P>(
+        direction: SyncDirection,
+        transfers: TransferManager,
+        directory: D,
+        bucket: B,
+        prefix: P,
+    ) -> DirectorySync
+    where
+        D: Into<PathBuf>,
+        B: Into<String>,
+        P: Into<String>,
+    {
+        let mut prefix = prefix.into();
+        if !prefix.is_empty() && !prefix.ends_with('/') {
+            prefix.push('/');
+        }
+        DirectorySync {
+            transfers,
+            direction,
+            directory: directory.into(),
+            bucket: bucket.into(),
+            prefix,
+            delete: false,
+            compare_etags: false,
+            filters: Vec::new(),
+            concurrency: DEFAULT_CONCURRENCY,
+        }
+    }
+
+    /// Sets whether files of the destination missing from the source are deleted.
+    pub fn with_delete(mut self, delete: bool) -> DirectorySync {
+        self.delete = delete;
+        self
+    }
+
+    /// Sets whether files of the same size are compared by ETag rather than modification time.
+    pub fn with_etag_comparison(mut self, compare_etags: bool) -> DirectorySync {
+        self.compare_etags = compare_etags;
+        self
+    }
+
+    /// Includes the files whose name matches `pattern`, excluded by an earlier filter.
+    ///
+    /// In patterns, `*` matches any sequence of characters, `/` included, and `?` any character.
+    /// All files are included until a filter excludes them, the last filter matching a file
+    /// deciding whether it's synced. Excluded files are neither copied nor deleted.
+    pub fn with_include<P: Into<String>>(mut self, pattern: P) -> DirectorySync {
+        self.filters.push(Filter::Include(pattern.into()));
+        self
+    }
+
+    /// Excludes the files whose name matches `pattern`: see `with_include`.
+    pub fn with_exclude<P: Into<String>>(mut self, pattern: P) -> DirectorySync {
+        self.filters.push(Filter::Exclude(pattern.into()));
+        self
+    }
+
+    /// Sets how many files are copied or deleted at the same time.
+    pub fn with_concurrency(mut self, concurrency: usize) -> DirectorySync {
+        self.concurrency = std::cmp::max(concurrency, 1);
+        self
+    }
+
+    /// Compares the directory to the bucket, returning the changes `execute` would make without
+    /// making any.
+    pub async fn plan(&self) -> Result<SyncPlan, SyncError> {
+        let local = self.local_files().await?;
+        let remote = self.remote_objects().await?;
+
+        let mut actions = Vec::new();
+        match self.direction {
+            SyncDirection::Upload => {
+                for (name, file) in &local {
+                    let changed = match remote.get(name) {
+                        Some(object) => self.differs(file, object).await?,
+                        None => true,
+                    };
+                    if changed {
+                        actions.push(SyncAction::Upload {
+                            path: file.path.clone(),
+                            key: format!("{}{}", self.prefix, name),
+                            size: file.size,
+                        });
+                    }
+                }
+                if self.delete {
+                    for (name, object) in &remote {
+                        if !local.contains_key(name) {
+                            actions.push(SyncAction::DeleteObject {
+                                key: object.key.clone(),
+                            });
+                        }
+                    }
+                }
+            }
+            SyncDirection::Download => {
+                for (name, object) in &remote {
+                    let changed = match local.get(name) {
+                        Some(file) => self.differs(file, object).await?,
+                        None => true,
+                    };
+                    if changed {
+                        actions.push(SyncAction::Download {
+                            key: object.key.clone(),
+                            path: name
+                                .split('/')
+                                .fold(self.directory.clone(), |path, name| path.join(name)),
+                            size: object.size,
+                        });
+                    }
+                }
+                if self.delete {
+                    for (name, file) in &local {
+                        if !remote.contains_key(name) {
+                            actions.push(SyncAction::DeleteFile {
+                                path: file.path.clone(),
+                            });
+                        }
+                    }
+                }
+            }
+        }
+        Ok(SyncPlan { actions })
+    }
+
+    /// Makes the changes of `plan`.
+    ///
+    /// Files smaller than the part size of the `TransferManager` are uploaded in a single
+    /// request, others in multipart uploads. Objects are deleted in batches.
+    pub async fn execute(&self, plan: &SyncPlan) -> Result<(), SyncError> {
+        let copies = plan.actions.iter().filter_map(|action| match *action {
+            SyncAction::DeleteObject { .. } => None,
+            ref action => Some(self.apply(action)),
+        });
+        stream::iter(copies)
+            .buffer_unordered(self.concurrency)
+            .try_collect::<Vec<()>>()
+            .await?;
+
+        let deleted: Vec<&str> = plan
+            .actions
+            .iter()
+            .filter_map(|action| match *action {
+                SyncAction::DeleteObject { ref key } => Some(key.as_str()),
+                _ => None,
+            })
+            .collect();
+        for keys in deleted.chunks(MAX_DELETE_KEYS) {
+            self.delete_objects(keys).await?;
+        }
+        Ok(())
+    }
+
+    /// Mirrors the source to the destination, returning the changes made.
+    pub async fn run(&self) -> Result<SyncPlan, SyncError> {
+        let plan = self.plan().await?;
+        self.execute(&plan).await?;
+        Ok(plan)
+    }
+
+    async fn apply(&self, action: &SyncAction) -> Result<(), SyncError> {
+        match *action {
+            SyncAction::Upload {
+                ref path,
+                ref key,
+                size,
+            } => {
+                if size > self.transfers.part_size() as u64 {
+                    let request = CreateMultipartUploadRequest {
+                        bucket: self.bucket.clone(),
+                        key: key.clone(),
+                        ..Default::default()
+                    };
+                    self.transfers.upload_file(request, path).await?;
+                } else {
+                    let data = fs::read(path).await?;
+                    // S3 rejects uploads not matching their Content-MD5
+                    let content_md5 = if self.transfers.checksums() {
+                        Some(base64::encode(&*md5::compute(&data)))
+                    } else {
+                        None
+                    };
+                    let request = PutObjectRequest {
+                        bucket: self.bucket.clone(),
+                        content_length: Some(data.len() as i64),
+                        content_md5,
+                        body: Some(data.into()),
+                        key: key.clone(),
+                        ..Default::default()
+                    };
+                    self.transfers
+                        .client()
+                        .put_object(request)
+                        .await
+                        .map_err(SyncError::PutObject)?;
+                }
+            }
+            SyncAction::Download {
+                ref key, ref path, ..
+            } => {
+                if let Some(parent) = path.parent() {
+                    fs::create_dir_all(parent).await?;
+                }
+                let request = GetObjectRequest {
+                    bucket: self.bucket.clone(),
+                    key: key.clone(),
+                    ..Default::default()
+                };
+                self.transfers.download_file(request, path).await?;
+            }
+            SyncAction::DeleteObject { ref key } => self.delete_objects(&[key]).await?,
+            SyncAction::DeleteFile { ref path } => fs::remove_file(path).await?,
+        }
+        Ok(())
+    }
+
+    async fn delete_objects(&self, keys: &[&str]) -> Result<(), SyncError> {
+        let request = DeleteObjectsRequest {
+            bucket: self.bucket.clone(),
+            delete: Delete {
+                objects: keys
+                    .iter()
+                    .map(|key| ObjectIdentifier {
+                        key: (*key).to_owned(),
+                        version_id: None,
+                    })
+                    .collect(),
+                quiet: Some(true),
+            },
+            ..Default::default()
+        };
+        let output = self
+            .transfers
+            .client()
+            .delete_objects(request)
+            .await
+            .map_err(SyncError::DeleteObjects)?;
+        match output.errors {
+            Some(errors) if !errors.is_empty() => Err(SyncError::NotDeleted(errors)),
+            _ => Ok(()),
+        }
+    }
+
+    async fn differs(&self, file: &LocalFile, object: &RemoteObject) -> Result<bool, SyncError> {
+        if file.size != object.size {
+            return Ok(true);
+        }
+        if self.compare_etags {
+            if let Some(ref e_tag) = object.e_tag {
+                let local_e_tag =
+                    e_tag_of(&file.path, file.size, e_tag, self.transfers.part_size()).await?;
+                return Ok(local_e_tag != *e_tag);
+            }
+        }
+        Ok(match self.direction {
+            SyncDirection::Upload => Some(file.modified) > object.modified,
+            SyncDirection::Download => object.modified > Some(file.modified),
+        })
+    }
+
+    async fn local_files(&self) -> Result<BTreeMap<String, LocalFile>, SyncError> {
+        let mut files = BTreeMap::new();
+        let mut directories = vec![self.directory.clone()];
+        while let Some(directory) = directories.pop() {
+            let mut entries = match fs::read_dir(&directory).await {
+                Ok(entries) => entries,
+                // nothing downloaded yet
+                Err(ref err)
+                    if err.kind() == io::ErrorKind::NotFound && directory == self.directory =>
+                {
+                    return Ok(files)
+                }
+                Err(err) => return Err(err.into()),
+            };
+            while let Some(entry) = entries.next_entry().await? {
+                let path = entry.path();
+                // links to directories aren't followed, as they may loop
+                if entry.file_type().await?.is_dir() {
+                    directories.push(path);
+                    continue;
+                }
+                let metadata = fs::metadata(&path).await?;
+                if !metadata.is_file() {
+                    continue;
+                }
+                let name = path
+                    .strip_prefix(&self.directory)
+                    .expect("files are in the directory")
+                    .components()
+                    .map(|component| component.as_os_str().to_string_lossy())
+                    .collect::<Vec<_>>()
+                    .join("/");
+                if self.is_included(&name) {
+                    let file = LocalFile {
+                        path,
+                        size: metadata.len(),
+                        modified: unix_timestamp(metadata.modified()?),
+                    };
+                    files.insert(name, file);
+                }
+            }
+        }
+        Ok(files)
+    }
+
+    async fn remote_objects(&self) -> Result<BTreeMap<String, RemoteObject>, SyncError> {
+        let request = ListObjectsV2Request {
+            bucket: self.bucket.clone(),
+            prefix: Some(self.prefix.clone()).filter(|prefix| !prefix.is_empty()),
+            ..Default::default()
+        };
+        self.transfers
+            .client()
+            .list_objects_v2_items(request)
+            .map_err(SyncError::ListObjectsV2)
+            .try_filter_map(|object| async move {
+                let key = match object.key {
+                    Some(key) => key,
+                    None => return Ok(None),
+                };
+                let name = match key.strip_prefix(&self.prefix) {
+                    Some(name) if is_safe_name(name) && self.is_included(name) => name.to_owned(),
+                    _ => return Ok(None),
+                };
+                let object = RemoteObject {
+                    size: object.size.unwrap_or(0) as u64,
+                    e_tag: object.e_tag.map(|e_tag| e_tag.trim_matches('"').to_owned()),
+                    modified: object.last_modified.map(|modified| modified.timestamp()),
+                    key,
+                };
+                Ok(Some((name, object)))
+            })
+            .try_collect()
+            .await
+    }
+
+    fn is_included(&self, name: &str) -> bool {
+        self.filters
+            .iter()
+            .fold(true, |included, filter| match *filter {
+                Filter::Include(ref pattern) if matches_glob(pattern, name) => true,
+                Filter::Exclude(ref pattern) if matches_glob(pattern, name) => false,
+                _ => included,
+            })
+    }
+}
+
+/// Whether the object named `name` can be downloaded in the directory, rather than being a
+/// directory marker or leading outside of it.
+fn is_safe_name(name: &str) -> bool {
+    name.split('/')
+        .all(|component| !component.is_empty() && component != "." && component != "..")
+}
+
+/// Whether `name` matches `pattern`, where `*` matches any sequence of characters and `?` any
+/// character.
+fn matches_glob(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    let (mut p, mut n) = (0, 0);
+    // position of the last `*` and of the character of the name it matches up to
+    let mut backtrack = None;
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, n));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match backtrack {
+                Some((star, matched)) => {
+                    p = star + 1;
+                    n = matched + 1;
+                    backtrack = Some((star, matched + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+fn unix_timestamp(time: SystemTime) -> i64 {
+    match time.duration_since(UNIX_EPOCH) {
+        Ok(elapsed) => elapsed.as_secs() as i64,
+        Err(err) => -(err.duration().as_secs() as i64),
+    }
+}
+
+/// Computes the ETag S3 would give the file at `path`, uploaded in as many parts as `e_tag` says
+/// if it's the ETag of a multipart upload.
+async fn e_tag_of(path: &Path, size: u64, e_tag: &str, part_size: usize) -> io::Result<String> {
+    let parts = e_tag
+        .rfind('-')
+        .and_then(|dash| e_tag[dash + 1..].parse::<u64>().ok());
+    let mut file = File::open(path).await?;
+    match parts {
+        None => {
+            let mut context = md5::Context::new();
+            let mut buffer = vec![0; 64 * 1024];
+            loop {
+                let read = file.read(&mut buffer).await?;
+                if read == 0 {
+                    return Ok(format!("{:x}", context.compute()));
+                }
+                context.consume(&buffer[..read]);
+            }
+        }
+        Some(parts) => {
+            let part_size = multipart_part_size(size, parts, part_size as u64);
+            let mut digests = Vec::new();
+            let mut count = 0;
+            loop {
+                let mut part = Vec::new();
+                (&mut file).take(part_size).read_to_end(&mut part).await?;
+                if part.is_empty() && count > 0 {
+                    break;
+                }
+                digests.extend_from_slice(&md5::compute(&part).0);
+                count += 1;
+                if part.is_empty() {
+                    break;
+                }
+            }
+            Ok(format!("{:x}-{}", md5::compute(&digests), count))
+        }
+    }
+}
+
+/// Guesses the size of the parts `size` bytes were uploaded in, given how many there were: the
+/// part size of the transfer manager, or the 8 MiB of the AWS CLI and SDKs, or the smallest whole
+/// number of MiB, if they make that many parts.
+fn multipart_part_size(size: u64, parts: u64, part_size: u64) -> u64 {
+    if parts <= 1 {
+        return std::cmp::max(size, 1);
+    }
+    let smallest = size.div_ceil(parts);
+    let candidates = [part_size, 8 * MIB, smallest.div_ceil(MIB) * MIB];
+    candidates
+        .iter()
+        .cloned()
+        .find(|&candidate| size.div_ceil(candidate) == parts)
+        .unwrap_or(smallest)
+}
+
+/// Errors returned by a `DirectorySync`.
+#[derive(Debug)]
+pub enum SyncError {
+    /// Listing the objects under the prefix failed
+    ListObjectsV2(RusotoError<ListObjectsV2Error>),
+    /// Uploading a small file failed
+    PutObject(RusotoError<PutObjectError>),
+    /// Deleting objects failed
+    DeleteObjects(RusotoError<DeleteObjectsError>),
+    /// Some objects weren't deleted, for the reasons given
+    NotDeleted(Vec<S3Error>),
+    /// Uploading or downloading a file failed
+    Transfer(TransferError),
+    /// Reading or writing local files failed
+    Io(io::Error),
+}
+
+impl fmt::Display for SyncError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            SyncError::ListObjectsV2(ref err) => write!(f, "Couldn't list objects: {}", err),
+            SyncError::PutObject(ref err) => write!(f, "Couldn't upload file: {}", err),
+            SyncError::DeleteObjects(ref err) => write!(f, "Couldn't delete objects: {}", err),
+            SyncError::NotDeleted(ref errors) => {
+                let keys: Vec<&str> = errors
+                    .iter()
+                    .filter_map(|error| error.key.as_deref())
+                    .collect();
+                write!(f, "Couldn't delete objects: {}", keys.join(", "))
+            }
+            SyncError::Transfer(ref err) => write!(f, "{}", err),
+            SyncError::Io(ref err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl Error for SyncError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            SyncError::ListObjectsV2(ref err) => Some(err),
+            SyncError::PutObject(ref err) => Some(err),
+            SyncError::DeleteObjects(ref err) => Some(err),
+            SyncError::NotDeleted(_) => None,
+            SyncError::Transfer(ref err) => Some(err),
+            SyncError::Io(ref err) => Some(err),
+        }
+    }
+}
+
+impl From<TransferError> for SyncError {
+    fn from(err: TransferError) -> SyncError {
+        SyncError::Transfer(err)
+    }
+}
+
+impl From<io::Error> for SyncError {
+    fn from(err: io::Error) -> SyncError {
+        SyncError::Io(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generated::S3Client;
+    use rusoto_core::signature::SignedRequest;
+    use rusoto_core::Region;
+    use rusoto_mock::{MockCredentialsProvider, MockRequestDispatcher};
+
+    // objects under `prefix/`, modified in the future so local files look older
+    const LISTING: &str = "<ListBucketResult xmlns=\"http://s3.amazonaws.com/doc/2006-03-01/\">\
+        <Name>bucket</Name><Prefix>prefix/</Prefix><IsTruncated>false</IsTruncated>\
+        <Contents><Key>prefix/same.txt</Key><LastModified>2100-01-01T00:00:00.000Z</LastModified>\
+        <ETag>&quot;5d41402abc4b2a76b9719d911017c592&quot;</ETag><Size>5</Size></Contents>\
+        <Contents><Key>prefix/resized.txt</Key><LastModified>2100-01-01T00:00:00.000Z</LastModified>\
+        <ETag>&quot;0&quot;</ETag><Size>1</Size></Contents>\
+        <Contents><Key>prefix/remote.txt</Key><LastModified>2100-01-01T00:00:00.000Z</LastModified>\
+        <ETag>&quot;0&quot;</ETag><Size>3</Size></Contents>\
+        <Contents><Key>prefix/dir/</Key><LastModified>2100-01-01T00:00:00.000Z</LastModified>\
+        <ETag>&quot;0&quot;</ETag><Size>0</Size></Contents>\
+        <Contents><Key>prefix/../escape.txt</Key><LastModified>2100-01-01T00:00:00.000Z</LastModified>\
+        <ETag>&quot;0&quot;</ETag><Size>3</Size></Contents>\
+        </ListBucketResult>";
+
+    fn directory(name: &str, files: &[(&str, &str)]) -> PathBuf {
+        let directory = std::env::temp_dir().join(name);
+        let _ = std::fs::remove_dir_all(&directory);
+        for (name, contents) in files {
+            let path = directory.join(name);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, contents).unwrap();
+        }
+        directory
+    }
+
+    fn transfers(dispatcher: MockRequestDispatcher) -> TransferManager {
+        TransferManager::new(S3Client::new_with(
+            dispatcher,
+            MockCredentialsProvider,
+            Region::UsEast1,
+        ))
+    }
+
+    #[tokio::test]
+    async fn plans_uploads() {
+        let directory = directory(
+            "rusoto_s3_sync_plans_uploads",
+            &[
+                ("same.txt", "hello"),
+                ("resized.txt", "hello"),
+                ("dir/local.txt", "hello"),
+                ("debug.log", "hello"),
+            ],
+        );
+        let dispatcher = MockRequestDispatcher::default()
+            .with_body(LISTING)
+            .with_request_checker(|request: &SignedRequest| {
+                assert_eq!(request.path, "/bucket");
+                assert_eq!(
+                    request.params.get("prefix"),
+                    Some(&Some("prefix/".to_owned()))
+                );
+            });
+        let sync = DirectorySync::upload(transfers(dispatcher), &directory, "bucket", "prefix")
+            .with_exclude("*.log")
+            .with_delete(true);
+
+        let plan = sync.plan().await.unwrap();
+        std::fs::remove_dir_all(&directory).unwrap();
+        assert_eq!(
+            plan.actions,
+            vec![
+                SyncAction::Upload {
+                    path: directory.join("dir").join("local.txt"),
+                    key: "prefix/dir/local.txt".to_owned(),
+                    size: 5,
+                },
+                SyncAction::Upload {
+                    path: directory.join("resized.txt"),
+                    key: "prefix/resized.txt".to_owned(),
+                    size: 5,
+                },
+                SyncAction::DeleteObject {
+                    key: "prefix/remote.txt".to_owned(),
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn plans_downloads() {
+        let directory = directory(
+            "rusoto_s3_sync_plans_downloads",
+            &[("same.txt", "hello"), ("local.txt", "hello")],
+        );
+        let dispatcher = MockRequestDispatcher::default().with_body(LISTING);
+        let sync = DirectorySync::download(transfers(dispatcher), "bucket", "prefix/", &directory)
+            .with_delete(true);
+
+        let plan = sync.plan().await.unwrap();
+        std::fs::remove_dir_all(&directory).unwrap();
+        // the local file is older than the object of the same size
+        assert_eq!(
+            plan.actions,
+            vec![
+                SyncAction::Download {
+                    key: "prefix/remote.txt".to_owned(),
+                    path: directory.join("remote.txt"),
+                    size: 3,
+                },
+                SyncAction::Download {
+                    key: "prefix/resized.txt".to_owned(),
+                    path: directory.join("resized.txt"),
+                    size: 1,
+                },
+                SyncAction::Download {
+                    key: "prefix/same.txt".to_owned(),
+                    path: directory.join("same.txt"),
+                    size: 5,
+                },
+                SyncAction::DeleteFile {
+                    path: directory.join("local.txt"),
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn compares_etags() {
+        let directory = directory(
+            "rusoto_s3_sync_compares_etags",
+            &[("same.txt", "hello"), ("remote.txt", "abc")],
+        );
+        let dispatcher = MockRequestDispatcher::default().with_body(LISTING);
+        let sync = DirectorySync::download(transfers(dispatcher), "bucket", "prefix", &directory)
+            .with_etag_comparison(true)
+            .with_include("*.txt")
+            .with_exclude("resized*");
+
+        let plan = sync.plan().await.unwrap();
+        std::fs::remove_dir_all(&directory).unwrap();
+        assert_eq!(
+            plan.actions,
+            vec![SyncAction::Download {
+                key: "prefix/remote.txt".to_owned(),
+                path: directory.join("remote.txt"),
+                size: 3,
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn executes_plans() {
+        let directory = directory(
+            "rusoto_s3_sync_executes_plans",
+            &[("new.txt", "hello"), ("old.txt", "hello")],
+        );
+        let dispatcher =
+            MockRequestDispatcher::default().with_request_checker(|request: &SignedRequest| {
+                assert_eq!(request.method, "PUT");
+                assert_eq!(request.path, "/bucket/prefix/new.txt");
+            });
+        let sync = DirectorySync::download(transfers(dispatcher), "bucket", "prefix", &directory);
+
+        let plan = SyncPlan {
+            actions: vec![
+                SyncAction::Upload {
+                    path: directory.join("new.txt"),
+                    key: "prefix/new.txt".to_owned(),
+                    size: 5,
+                },
+                SyncAction::DeleteFile {
+                    path: directory.join("old.txt"),
+                },
+            ],
+        };
+        sync.execute(&plan).await.unwrap();
+        assert!(directory.join("new.txt").exists());
+        assert!(!directory.join("old.txt").exists());
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[tokio::test]
+    async fn computes_multipart_etags() {
+        let directory = directory("rusoto_s3_sync_computes_multipart_etags", &[]);
+        std::fs::create_dir_all(&directory).unwrap();
+        let path = directory.join("object");
+        let contents: Vec<u8> = (0..2500u32).map(|i| i as u8).collect();
+        std::fs::write(&path, &contents).unwrap();
+
+        let mut digests = Vec::new();
+        for part in contents.chunks(1000) {
+            digests.extend_from_slice(&md5::compute(part).0);
+        }
+        let expected = format!("{:x}-3", md5::compute(&digests));
+
+        let e_tag = e_tag_of(&path, 2500, "0-3", 1000).await.unwrap();
+        let single = e_tag_of(&path, 2500, "0", 1000).await.unwrap();
+        std::fs::remove_dir_all(&directory).unwrap();
+        assert_eq!(e_tag, expected);
+        assert_eq!(single, format!("{:x}", md5::compute(&contents)));
+    }
+
+    #[test]
+    fn guesses_part_sizes() {
+        assert_eq!(multipart_part_size(2500, 3, 1000), 1000);
+        // 8 MiB parts, by a client other than the transfer manager
+        assert_eq!(multipart_part_size(20 * MIB, 3, 5 * MIB), 8 * MIB);
+        assert_eq!(multipart_part_size(7, 1, 5 * MIB), 7);
+        // no size but the smallest makes that many parts
+        assert_eq!(multipart_part_size(30 * MIB, 3, 5 * MIB), 10 * MIB);
+    }
+
+    #[test]
+    fn matches_globs() {
+        assert!(matches_glob("*.log", "debug.log"));
+        assert!(matches_glob("*.log", "dir/debug.log"));
+        assert!(matches_glob("dir/*", "dir/sub/file"));
+        assert!(matches_glob("file?.txt", "file1.txt"));
+        assert!(matches_glob("*a*b*", "xaxxbx"));
+        assert!(!matches_glob("*.log", "debug.txt"));
+        assert!(!matches_glob("file?.txt", "file.txt"));
+        assert!(!matches_glob("dir/*", "other/file"));
+    }
+}
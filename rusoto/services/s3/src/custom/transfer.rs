@@ -0,0 +1,1021 @@
+use std::cmp;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::io::{self, SeekFrom};
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+use futures::{stream, StreamExt, TryStreamExt};
+use rusoto_core::RusotoError;
+use tokio::fs::{File, OpenOptions};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWriteExt};
+
+use super::checksum::{e_tag_is_md5, md5_e_tag};
+use crate::generated::{
+    AbortMultipartUploadRequest, CompleteMultipartUploadError, CompleteMultipartUploadOutput,
+    CompleteMultipartUploadRequest, CompletedMultipartUpload, CompletedPart,
+    CreateMultipartUploadError, CreateMultipartUploadRequest, GetObjectError, GetObjectRequest,
+    HeadObjectError, HeadObjectRequest, ListPartsError, ListPartsRequest, Part, S3Client,
+    UploadPartError, UploadPartRequest, S3,
+};
+
+const DEFAULT_PART_SIZE: usize = 8 * 1024 * 1024;
+const DEFAULT_CONCURRENCY: usize = 4;
+// most parts a multipart upload can have
+const MAX_PARTS: u64 = 10_000;
+
+/// Progress of a transfer, reported to the callback of a `TransferManager`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TransferProgress {
+    /// Bucket of the object transferred
+    pub bucket: String,
+    /// Key of the object transferred
+    pub key: String,
+    /// Bytes of the object transferred so far, parts uploaded before an upload was resumed included
+    pub bytes_transferred: u64,
+    /// Size of the object, if known
+    pub total_bytes: Option<u64>,
+}
+
+type ProgressCallback = Arc<dyn Fn(&TransferProgress) + Send + Sync>;
+
+/// Uploads and downloads objects in parts, several at a time.
+///
+/// Uploads are multipart uploads, aborted when they fail unless `with_abort_on_failure(false)`
+/// says otherwise, so they can be resumed with `resume_upload`. Downloads are ranged `GetObject`
+/// requests for the same version of the object, written to a file as they complete.
+///
+/// Parts are 8 MiB by default, and S3 rejects parts smaller than 5 MiB, but for the last one.
+/// Uploads of files use bigger parts when needed to stay within the 10,000 parts of a multipart
+/// upload.
+///
+/// Integrity checks are opt-in with `with_checksums(true)`.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use rusoto_core::Region;
+/// use rusoto_s3::{CreateMultipartUploadRequest, S3Client, TransferManager};
+///
+/// # async fn upload() -> Result<(), rusoto_s3::TransferError> {
+/// let transfers = TransferManager::new(S3Client::new(Region::UsEast1))
+///     .with_concurrency(8)
+///     .with_progress(|progress| println!("{} bytes uploaded", progress.bytes_transferred));
+/// let request = CreateMultipartUploadRequest {
+///     bucket: "bucket".to_owned(),
+///     key: "backup.tar".to_owned(),
+///     ..Default::default()
+/// };
+/// transfers.upload_file(request, "backup.tar").await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct TransferManager {
+    client: S3Client,
+    part_size: usize,
+    concurrency: usize,
+    abort_on_failure: bool,
+    checksums: bool,
+    progress: Option<ProgressCallback>,
+}
+
+impl TransferManager {
+    /// Creates a `TransferManager` sending its requests with `client`.
+    pub fn new(client: S3Client) -> TransferManager {
+        TransferManager {
+            client,
+            part_size: DEFAULT_PART_SIZE,
+            concurrency: DEFAULT_CONCURRENCY,
+            abort_on_failure: true,
+            checksums: false,
+            progress: None,
+        }
+    }
+
+    /// Sets the size of the parts uploaded, and of the ranges downloaded.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `part_size` is zero.
+    pub fn with_part_size(mut self, part_size: usize) -> TransferManager {
+        assert!(part_size > 0, "parts can't be empty");
+        self.part_size = part_size;
+        self
+    }
+
+    /// Sets how many parts are transferred at the same time, each of them held in memory while
+    /// uploaded.
+    pub fn with_concurrency(mut self, concurrency: usize) -> TransferManager {
+        self.concurrency = cmp::max(concurrency, 1);
+        self
+    }
+
+    /// Sets whether failed uploads are aborted, discarding the parts already uploaded.
+    pub fn with_abort_on_failure(mut self, abort_on_failure: bool) -> TransferManager {
+        self.abort_on_failure = abort_on_failure;
+        self
+    }
+
+    /// Sets whether the integrity of the data transferred is checked.
+    ///
+    /// Parts are uploaded with their Content-MD5, for S3 to check it, and the ETags it returns
+    /// for them are checked to be their MD5 digests. Downloaded files are read back once written,
+    /// and checked to match the ETag of the object.
+    ///
+    /// ETags are only the MD5 digests of parts, and of objects uploaded in a single part, when
+    /// they're not encrypted with SSE-KMS or SSE-C. Other ETags aren't checked.
+    pub fn with_checksums(mut self, checksums: bool) -> TransferManager {
+        self.checksums = checksums;
+        self
+    }
+
+    /// Calls `callback` whenever a transfer makes progress.
+    pub fn with_progress<F>(mut self, callback: F) -> TransferManager
+    where
+        F: Fn(&TransferProgress) + Send + Sync + 'static,
+    {
+        self.progress = Some(Arc::new(callback));
+        self
+    }
+
+    pub(crate) fn client(&self) -> &S3Client {
+        &self.client
+    }
+
+    pub(crate) fn part_size(&self) -> usize {
+        self.part_size
+    }
+
+    pub(crate) fn checksums(&self) -> bool {
+        self.checksums
+    }
+
+    /// Uploads `body` in a new multipart upload created with `request`.
+    pub async fn upload<R>(
+        &self,
+        request: CreateMultipartUploadRequest,
+        body: R,
+    ) -> Result<CompleteMultipartUploadOutput, TransferError>
+    where
+        R: AsyncRead + Unpin + Send,
+    {
+        let upload_id = self.create_upload(request.clone()).await?;
+        self.finish_upload(
+            &request,
+            &upload_id,
+            body,
+            self.part_size,
+            None,
+            HashMap::new(),
+        )
+        .await
+    }
+
+    /// Uploads the file at `path` in a new multipart upload created with `request`.
+    pub async fn upload_file<P: AsRef<Path>>(
+        &self,
+        request: CreateMultipartUploadRequest,
+        path: P,
+    ) -> Result<CompleteMultipartUploadOutput, TransferError> {
+        let file = File::open(path).await?;
+        let size = file.metadata().await?.len();
+        let part_size = cmp::max(self.part_size as u64, size.div_ceil(MAX_PARTS));
+
+        let upload_id = self.create_upload(request.clone()).await?;
+        self.finish_upload(
+            &request,
+            &upload_id,
+            file,
+            part_size as usize,
+            Some(size),
+            HashMap::new(),
+        )
+        .await
+    }
+
+    /// Creates a multipart upload with `request`, returning its ID, for `resume_upload` to upload
+    /// the parts of.
+    pub async fn create_upload(
+        &self,
+        request: CreateMultipartUploadRequest,
+    ) -> Result<String, TransferError> {
+        let output = self
+            .client
+            .create_multipart_upload(request)
+            .await
+            .map_err(TransferError::CreateMultipartUpload)?;
+        output.upload_id.ok_or_else(|| {
+            TransferError::Io(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "no upload ID in response",
+            ))
+        })
+    }
+
+    /// Uploads `body` in the multipart upload `upload_id`, created with `request`, skipping the
+    /// parts `ListParts` says were already uploaded.
+    ///
+    /// `body` must be the same as when the upload was started, from its beginning: the parts
+    /// already uploaded are read, but not sent again. Their size is the size of the parts of the
+    /// rest of the upload.
+    pub async fn resume_upload<R>(
+        &self,
+        request: CreateMultipartUploadRequest,
+        upload_id: &str,
+        body: R,
+    ) -> Result<CompleteMultipartUploadOutput, TransferError>
+    where
+        R: AsyncRead + Unpin + Send,
+    {
+        let uploaded = self.uploaded_parts(&request, upload_id).await?;
+        let part_size = match uploaded.get(&1).and_then(|part| part.size) {
+            // the first part may be the last one, smaller than the others
+            Some(size) if uploaded.len() > 1 => size as usize,
+            _ => self.part_size,
+        };
+        self.finish_upload(&request, upload_id, body, part_size, None, uploaded)
+            .await
+    }
+
+    /// Downloads the object `request` gets to the file at `path`, in ranges of the part size,
+    /// returning its size.
+    pub async fn download_file<P: AsRef<Path>>(
+        &self,
+        request: GetObjectRequest,
+        path: P,
+    ) -> Result<u64, TransferError> {
+        let head = self
+            .client
+            .head_object(HeadObjectRequest {
+                bucket: request.bucket.clone(),
+                if_match: request.if_match.clone(),
+                if_modified_since: request.if_modified_since,
+                if_none_match: request.if_none_match.clone(),
+                if_unmodified_since: request.if_unmodified_since,
+                key: request.key.clone(),
+                request_payer: request.request_payer.clone(),
+                sse_customer_algorithm: request.sse_customer_algorithm.clone(),
+                sse_customer_key: request.sse_customer_key.clone(),
+                sse_customer_key_md5: request.sse_customer_key_md5.clone(),
+                version_id: request.version_id.clone(),
+                ..Default::default()
+            })
+            .await
+            .map_err(TransferError::HeadObject)?;
+        let size = head.content_length.unwrap_or(0) as u64;
+        let md5 = head
+            .e_tag
+            .as_deref()
+            .and_then(md5_e_tag)
+            .filter(|_| self.checksums)
+            .filter(|_| {
+                e_tag_is_md5(
+                    head.server_side_encryption.as_ref(),
+                    head.sse_customer_algorithm.as_deref(),
+                )
+            });
+        // every range from the same version of the object
+        let request = GetObjectRequest {
+            if_match: request.if_match.clone().or(head.e_tag),
+            ..request
+        };
+
+        let path = path.as_ref();
+        File::create(path).await?.set_len(size).await?;
+
+        let progress = ProgressReporter::new(self.progress.as_ref(), &request.bucket, &request.key)
+            .with_total_bytes(size);
+        let ranges = (0..size).step_by(self.part_size).map(|start| {
+            let end = cmp::min(start + self.part_size as u64, size);
+            self.download_range(&request, path, start, end, &progress)
+        });
+        stream::iter(ranges)
+            .buffer_unordered(self.concurrency)
+            .try_collect::<Vec<()>>()
+            .await?;
+
+        if let Some(md5) = md5 {
+            let mut file = File::open(path).await?;
+            let mut context = md5::Context::new();
+            let mut buffer = vec![0; 64 * 1024];
+            loop {
+                let read = file.read(&mut buffer).await?;
+                if read == 0 {
+                    break;
+                }
+                context.consume(&buffer[..read]);
+            }
+            let actual = format!("{:x}", context.compute());
+            if actual != md5 {
+                return Err(TransferError::ChecksumMismatch(format!(
+                    "downloaded {} has MD5 {}, not its ETag {}",
+                    request.key, actual, md5
+                )));
+            }
+        }
+        Ok(size)
+    }
+
+    async fn finish_upload<R>(
+        &self,
+        request: &CreateMultipartUploadRequest,
+        upload_id: &str,
+        body: R,
+        part_size: usize,
+        total_bytes: Option<u64>,
+        uploaded: HashMap<i64, Part>,
+    ) -> Result<CompleteMultipartUploadOutput, TransferError>
+    where
+        R: AsyncRead + Unpin + Send,
+    {
+        let result = self
+            .upload_parts(request, upload_id, body, part_size, total_bytes, &uploaded)
+            .await;
+        if result.is_err() && self.abort_on_failure {
+            // reporting why the upload failed rather than why aborting it did
+            let _ = self
+                .client
+                .abort_multipart_upload(AbortMultipartUploadRequest {
+                    bucket: request.bucket.clone(),
+                    key: request.key.clone(),
+                    request_payer: request.request_payer.clone(),
+                    upload_id: upload_id.to_owned(),
+                })
+                .await;
+        }
+        result
+    }
+
+    async fn upload_parts<R>(
+        &self,
+        request: &CreateMultipartUploadRequest,
+        upload_id: &str,
+        body: R,
+        part_size: usize,
+        total_bytes: Option<u64>,
+        uploaded: &HashMap<i64, Part>,
+    ) -> Result<CompleteMultipartUploadOutput, TransferError>
+    where
+        R: AsyncRead + Unpin + Send,
+    {
+        let mut progress =
+            ProgressReporter::new(self.progress.as_ref(), &request.bucket, &request.key);
+        if let Some(total_bytes) = total_bytes {
+            progress = progress.with_total_bytes(total_bytes);
+        }
+        let progress = &progress;
+
+        let parts = stream::try_unfold((body, 1), move |(mut body, part_number)| async move {
+            let data = read_part(&mut body, part_size).await?;
+            // an empty body is uploaded as an empty part
+            if data.is_empty() && part_number > 1 {
+                return Ok(None);
+            }
+            Ok(Some(((part_number, data), (body, part_number + 1))))
+        });
+        let mut completed: Vec<CompletedPart> = parts
+            .map_ok(|(part_number, data)| {
+                self.upload_part(request, upload_id, part_number, data, uploaded, progress)
+            })
+            .try_buffer_unordered(self.concurrency)
+            .try_collect()
+            .await?;
+        completed.sort_by_key(|part| part.part_number);
+
+        self.client
+            .complete_multipart_upload(CompleteMultipartUploadRequest {
+                bucket: request.bucket.clone(),
+                key: request.key.clone(),
+                multipart_upload: Some(CompletedMultipartUpload {
+                    parts: Some(completed),
+                }),
+                request_payer: request.request_payer.clone(),
+                upload_id: upload_id.to_owned(),
+            })
+            .await
+            .map_err(TransferError::CompleteMultipartUpload)
+    }
+
+    async fn upload_part(
+        &self,
+        request: &CreateMultipartUploadRequest,
+        upload_id: &str,
+        part_number: i64,
+        data: Vec<u8>,
+        uploaded: &HashMap<i64, Part>,
+        progress: &ProgressReporter<'_>,
+    ) -> Result<CompletedPart, TransferError> {
+        let len = data.len();
+        let e_tag = match uploaded.get(&part_number) {
+            Some(part) if part.size == Some(len as i64) => part.e_tag.clone(),
+            _ => {
+                let md5 = if self.checksums {
+                    Some(md5::compute(&data))
+                } else {
+                    None
+                };
+                let output = self
+                    .client
+                    .upload_part(UploadPartRequest {
+                        body: Some(data.into()),
+                        bucket: request.bucket.clone(),
+                        content_length: Some(len as i64),
+                        content_md5: md5.map(|md5| base64::encode(&*md5)),
+                        key: request.key.clone(),
+                        part_number,
+                        request_payer: request.request_payer.clone(),
+                        sse_customer_algorithm: request.sse_customer_algorithm.clone(),
+                        sse_customer_key: request.sse_customer_key.clone(),
+                        sse_customer_key_md5: request.sse_customer_key_md5.clone(),
+                        upload_id: upload_id.to_owned(),
+                    })
+                    .await
+                    .map_err(TransferError::UploadPart)?;
+                let md5 = md5.filter(|_| {
+                    e_tag_is_md5(
+                        output.server_side_encryption.as_ref(),
+                        output.sse_customer_algorithm.as_deref(),
+                    )
+                });
+                if let Some(md5) = md5 {
+                    let expected = format!("{:x}", md5);
+                    if output.e_tag.as_deref().and_then(md5_e_tag) != Some(expected.clone()) {
+                        return Err(TransferError::ChecksumMismatch(format!(
+                            "part {} has ETag {:?}, not its MD5 {}",
+                            part_number, output.e_tag, expected
+                        )));
+                    }
+                }
+                output.e_tag
+            }
+        };
+        progress.add(len as u64);
+
+        Ok(CompletedPart {
+            e_tag,
+            part_number: Some(part_number),
+        })
+    }
+
+    async fn uploaded_parts(
+        &self,
+        request: &CreateMultipartUploadRequest,
+        upload_id: &str,
+    ) -> Result<HashMap<i64, Part>, TransferError> {
+        let mut uploaded = HashMap::new();
+        let mut part_number_marker = None;
+        loop {
+            let output = self
+                .client
+                .list_parts(ListPartsRequest {
+                    bucket: request.bucket.clone(),
+                    key: request.key.clone(),
+                    part_number_marker,
+                    request_payer: request.request_payer.clone(),
+                    upload_id: upload_id.to_owned(),
+                    ..Default::default()
+                })
+                .await
+                .map_err(TransferError::ListParts)?;
+            for part in output.parts.unwrap_or_default() {
+                if let Some(part_number) = part.part_number {
+                    uploaded.insert(part_number, part);
+                }
+            }
+            part_number_marker = output.next_part_number_marker;
+            if output.is_truncated != Some(true) || part_number_marker.is_none() {
+                return Ok(uploaded);
+            }
+        }
+    }
+
+    async fn download_range(
+        &self,
+        request: &GetObjectRequest,
+        path: &Path,
+        start: u64,
+        end: u64,
+        progress: &ProgressReporter<'_>,
+    ) -> Result<(), TransferError> {
+        let output = self
+            .client
+            .get_object(GetObjectRequest {
+                range: Some(format!("bytes={}-{}", start, end - 1)),
+                ..request.clone()
+            })
+            .await
+            .map_err(TransferError::GetObject)?;
+
+        let mut file = OpenOptions::new().write(true).open(path).await?;
+        file.seek(SeekFrom::Start(start)).await?;
+        let mut written = 0;
+        if let Some(mut body) = output.body {
+            while let Some(chunk) = body.try_next().await? {
+                file.write_all(&chunk).await?;
+                written += chunk.len() as u64;
+                progress.add(chunk.len() as u64);
+            }
+        }
+        file.flush().await?;
+
+        if written != end - start {
+            return Err(TransferError::Io(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                format!("got {} bytes of range {}-{}", written, start, end - 1),
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for TransferManager {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TransferManager")
+            .field("part_size", &self.part_size)
+            .field("concurrency", &self.concurrency)
+            .field("abort_on_failure", &self.abort_on_failure)
+            .field("checksums", &self.checksums)
+            .field("progress", &self.progress.as_ref().map(|_| "Fn"))
+            .finish()
+    }
+}
+
+/// Reads the next part of `body`, short only at its end.
+async fn read_part<R: AsyncRead + Unpin>(body: &mut R, part_size: usize) -> io::Result<Vec<u8>> {
+    let mut part = Vec::with_capacity(part_size);
+    body.take(part_size as u64).read_to_end(&mut part).await?;
+    Ok(part)
+}
+
+/// Counts the bytes of a transfer, reporting them to the progress callback.
+struct ProgressReporter<'a> {
+    callback: Option<&'a ProgressCallback>,
+    bucket: &'a str,
+    key: &'a str,
+    total_bytes: Option<u64>,
+    bytes_transferred: AtomicU64,
+}
+
+impl<'a> ProgressReporter<'a> {
+    fn new(callback: Option<&'a ProgressCallback>, bucket: &'a str, key: &'a str) -> Self {
+        ProgressReporter {
+            callback,
+            bucket,
+            key,
+            total_bytes: None,
+            bytes_transferred: AtomicU64::new(0),
+        }
+    }
+
+    fn with_total_bytes(mut self, total_bytes: u64) -> Self {
+        self.total_bytes = Some(total_bytes);
+        self
+    }
+
+    fn add(&self, bytes: u64) {
+        let bytes_transferred = self.bytes_transferred.fetch_add(bytes, Ordering::SeqCst) + bytes;
+        if let Some(callback) = self.callback {
+            callback(&TransferProgress {
+                bucket: self.bucket.to_owned(),
+                key: self.key.to_owned(),
+                bytes_transferred,
+                total_bytes: self.total_bytes,
+            });
+        }
+    }
+}
+
+/// Errors returned by a `TransferManager`.
+#[derive(Debug)]
+pub enum TransferError {
+    /// Creating the multipart upload failed
+    CreateMultipartUpload(RusotoError<CreateMultipartUploadError>),
+    /// Uploading a part failed
+    UploadPart(RusotoError<UploadPartError>),
+    /// Listing the parts of the multipart upload resumed failed
+    ListParts(RusotoError<ListPartsError>),
+    /// Completing the multipart upload failed
+    CompleteMultipartUpload(RusotoError<CompleteMultipartUploadError>),
+    /// Getting the size of the object downloaded failed
+    HeadObject(RusotoError<HeadObjectError>),
+    /// Downloading a range of the object failed
+    GetObject(RusotoError<GetObjectError>),
+    /// The data transferred doesn't match its checksum
+    ChecksumMismatch(String),
+    /// Reading or writing the data transferred failed
+    Io(io::Error),
+}
+
+impl fmt::Display for TransferError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            TransferError::CreateMultipartUpload(ref err) => {
+                write!(f, "Couldn't create multipart upload: {}", err)
+            }
+            TransferError::UploadPart(ref err) => write!(f, "Couldn't upload part: {}", err),
+            TransferError::ListParts(ref err) => write!(f, "Couldn't list parts: {}", err),
+            TransferError::CompleteMultipartUpload(ref err) => {
+                write!(f, "Couldn't complete multipart upload: {}", err)
+            }
+            TransferError::HeadObject(ref err) => write!(f, "Couldn't get object size: {}", err),
+            TransferError::GetObject(ref err) => write!(f, "Couldn't get object range: {}", err),
+            TransferError::ChecksumMismatch(ref mismatch) => {
+                write!(f, "Checksum mismatch: {}", mismatch)
+            }
+            TransferError::Io(ref err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl Error for TransferError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            TransferError::CreateMultipartUpload(ref err) => Some(err),
+            TransferError::UploadPart(ref err) => Some(err),
+            TransferError::ListParts(ref err) => Some(err),
+            TransferError::CompleteMultipartUpload(ref err) => Some(err),
+            TransferError::HeadObject(ref err) => Some(err),
+            TransferError::GetObject(ref err) => Some(err),
+            TransferError::ChecksumMismatch(_) => None,
+            TransferError::Io(ref err) => Some(err),
+        }
+    }
+}
+
+impl From<io::Error> for TransferError {
+    fn from(err: io::Error) -> TransferError {
+        TransferError::Io(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    use http::{HeaderMap, StatusCode};
+    use rusoto_core::request::{DispatchSignedRequestFuture, HttpResponse};
+    use rusoto_core::signature::{SignedRequest, SignedRequestPayload};
+    use rusoto_core::{ByteStream, DispatchSignedRequest, Region};
+    use rusoto_mock::MockCredentialsProvider;
+
+    /// Multipart uploads and ranged downloads of a single object, kept in memory.
+    #[derive(Clone, Default)]
+    struct FakeS3 {
+        state: Arc<Mutex<FakeState>>,
+    }
+
+    #[derive(Default)]
+    struct FakeState {
+        object: Vec<u8>,
+        parts: BTreeMap<i64, Vec<u8>>,
+        parts_sent: Vec<i64>,
+        failing_part: Option<i64>,
+        aborted: bool,
+        ranges: Vec<(String, Option<String>)>,
+        content_md5s: Vec<String>,
+        e_tag: Option<String>,
+    }
+
+    impl FakeS3 {
+        fn state(&self) -> std::sync::MutexGuard<'_, FakeState> {
+            self.state.lock().unwrap()
+        }
+
+        fn client(&self) -> S3Client {
+            S3Client::new_with(self.clone(), MockCredentialsProvider, Region::UsEast1)
+        }
+
+        fn respond(&self, request: &SignedRequest, payload: Vec<u8>) -> HttpResponse {
+            let mut state = self.state();
+            let param = |name: &str| request.params.get(name).cloned().flatten();
+            let mut headers = HeaderMap::<String>::default();
+            let (status, body) = match request.method() {
+                "POST" if request.params.contains_key("uploads") => (
+                    200,
+                    "<InitiateMultipartUploadResult><UploadId>upload</UploadId>\
+                     </InitiateMultipartUploadResult>"
+                        .to_owned(),
+                ),
+                "PUT" => {
+                    let part_number: i64 = param("partNumber").unwrap().parse().unwrap();
+                    if state.failing_part == Some(part_number) {
+                        (500, "<Error><Code>InternalError</Code></Error>".to_owned())
+                    } else {
+                        state.parts_sent.push(part_number);
+                        state.content_md5s.extend(header(request, "content-md5"));
+                        state.parts.insert(part_number, payload);
+                        headers.insert("ETag", format!("\"{}\"", part_number));
+                        (200, String::new())
+                    }
+                }
+                "GET" if request.params.contains_key("uploadId") => {
+                    let parts: String = state
+                        .parts
+                        .iter()
+                        .map(|(part_number, data)| {
+                            format!(
+                                "<Part><PartNumber>{}</PartNumber><ETag>\"{}\"</ETag>\
+                                 <Size>{}</Size></Part>",
+                                part_number,
+                                part_number,
+                                data.len()
+                            )
+                        })
+                        .collect();
+                    (
+                        200,
+                        format!(
+                            "<ListPartsResult><IsTruncated>false</IsTruncated>{}</ListPartsResult>",
+                            parts
+                        ),
+                    )
+                }
+                "POST" => {
+                    let completed = String::from_utf8(payload).unwrap();
+                    let expected: String = state
+                        .parts
+                        .keys()
+                        .map(|part_number| {
+                            format!(
+                                "<Part><ETag>\"{}\"</ETag><PartNumber>{}</PartNumber></Part>",
+                                part_number, part_number
+                            )
+                        })
+                        .collect();
+                    assert!(completed.contains(&expected), "{}", completed);
+                    state.object = state.parts.values().flatten().cloned().collect();
+                    (
+                        200,
+                        "<CompleteMultipartUploadResult><ETag>\"object\"</ETag>\
+                         </CompleteMultipartUploadResult>"
+                            .to_owned(),
+                    )
+                }
+                "DELETE" => {
+                    state.aborted = true;
+                    (204, String::new())
+                }
+                "HEAD" => {
+                    headers.insert("Content-Length", state.object.len().to_string());
+                    let e_tag = state.e_tag.clone();
+                    headers.insert("ETag", e_tag.unwrap_or_else(|| "\"object\"".to_owned()));
+                    (200, String::new())
+                }
+                "GET" => {
+                    let range = header(request, "range").unwrap();
+                    state
+                        .ranges
+                        .push((range.clone(), header(request, "if-match")));
+                    let mut bounds = range["bytes=".len()..].split('-');
+                    let start: usize = bounds.next().unwrap().parse().unwrap();
+                    let end: usize = bounds.next().unwrap().parse().unwrap();
+                    let body = state.object[start..=end].to_vec();
+                    return HttpResponse {
+                        status: StatusCode::PARTIAL_CONTENT,
+                        body: ByteStream::from(body),
+                        headers,
+                    };
+                }
+                method => panic!("unexpected {} request", method),
+            };
+            HttpResponse {
+                status: StatusCode::from_u16(status).unwrap(),
+                body: ByteStream::from(body.into_bytes()),
+                headers,
+            }
+        }
+    }
+
+    impl DispatchSignedRequest for FakeS3 {
+        fn dispatch(
+            &self,
+            mut request: SignedRequest,
+            _timeout: Option<Duration>,
+        ) -> DispatchSignedRequestFuture {
+            let s3 = self.clone();
+            Box::pin(async move {
+                let payload = match request.payload.take() {
+                    Some(SignedRequestPayload::Buffer(bytes)) => bytes.to_vec(),
+                    Some(SignedRequestPayload::Stream(stream)) => {
+                        let chunks: Vec<_> = stream.try_collect().await.unwrap();
+                        if header(&request, "content-encoding").as_deref() == Some("aws-chunked") {
+                            decode_aws_chunked(&chunks.concat())
+                        } else {
+                            chunks.concat()
+                        }
+                    }
+                    None => Vec::new(),
+                };
+                Ok(s3.respond(&request, payload))
+            })
+        }
+    }
+
+    fn header(request: &SignedRequest, name: &str) -> Option<String> {
+        request
+            .headers
+            .get(name)
+            .map(|values| String::from_utf8(values[0].clone()).unwrap())
+    }
+
+    // the data of payloads signed chunk by chunk
+    fn decode_aws_chunked(mut encoded: &[u8]) -> Vec<u8> {
+        let mut decoded = Vec::new();
+        loop {
+            let header_end = encoded.windows(2).position(|w| w == b"\r\n").unwrap();
+            let header = std::str::from_utf8(&encoded[..header_end]).unwrap();
+            let len = usize::from_str_radix(header.split(';').next().unwrap(), 16).unwrap();
+            if len == 0 {
+                return decoded;
+            }
+            let data = &encoded[header_end + 2..];
+            decoded.extend_from_slice(&data[..len]);
+            encoded = &data[len + 2..];
+        }
+    }
+
+    fn object(len: usize) -> Vec<u8> {
+        (0..len).map(|i| (i % 251) as u8).collect()
+    }
+
+    fn upload_request() -> CreateMultipartUploadRequest {
+        CreateMultipartUploadRequest {
+            bucket: "bucket".to_owned(),
+            key: "key".to_owned(),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn uploads_in_parts() {
+        let s3 = FakeS3::default();
+        let progress = Arc::new(Mutex::new(Vec::new()));
+        let reported = progress.clone();
+        let transfers = TransferManager::new(s3.client())
+            .with_part_size(1000)
+            .with_concurrency(3)
+            .with_progress(move |progress| {
+                reported.lock().unwrap().push(progress.bytes_transferred)
+            });
+
+        let output = transfers
+            .upload(upload_request(), &object(4500)[..])
+            .await
+            .unwrap();
+        assert_eq!(output.e_tag.as_deref(), Some("\"object\""));
+
+        let state = s3.state();
+        assert_eq!(state.object, object(4500));
+        assert_eq!(state.parts.len(), 5);
+        assert!(!state.aborted);
+        // reported as parts complete, in any order
+        let progress = progress.lock().unwrap();
+        assert_eq!(progress.len(), 5);
+        assert_eq!(progress.iter().max(), Some(&4500));
+    }
+
+    #[tokio::test]
+    async fn uploads_files() {
+        let path = std::env::temp_dir().join("rusoto_s3_transfer_upload");
+        std::fs::write(&path, object(2048)).unwrap();
+
+        let s3 = FakeS3::default();
+        let totals = Arc::new(Mutex::new(Vec::new()));
+        let reported = totals.clone();
+        let transfers = TransferManager::new(s3.client())
+            .with_part_size(1024)
+            .with_progress(move |progress| reported.lock().unwrap().push(progress.total_bytes));
+        transfers
+            .upload_file(upload_request(), &path)
+            .await
+            .unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(s3.state().object, object(2048));
+        assert_eq!(*totals.lock().unwrap(), vec![Some(2048), Some(2048)]);
+    }
+
+    #[tokio::test]
+    async fn aborts_failed_uploads() {
+        let s3 = FakeS3::default();
+        s3.state().failing_part = Some(2);
+        let transfers = TransferManager::new(s3.client()).with_part_size(1000);
+
+        match transfers.upload(upload_request(), &object(3000)[..]).await {
+            Err(TransferError::UploadPart(_)) => {}
+            result => panic!("unexpected result: {:?}", result.map(|_| ())),
+        }
+        assert!(s3.state().aborted);
+    }
+
+    #[tokio::test]
+    async fn resumes_uploads() {
+        let s3 = FakeS3::default();
+        s3.state().failing_part = Some(3);
+        // a single part at a time, so the parts before the failing one are uploaded
+        let transfers = TransferManager::new(s3.client())
+            .with_part_size(1000)
+            .with_concurrency(1)
+            .with_abort_on_failure(false);
+
+        let upload_id = transfers.create_upload(upload_request()).await.unwrap();
+        assert!(transfers
+            .resume_upload(upload_request(), &upload_id, &object(3500)[..])
+            .await
+            .is_err());
+        assert!(!s3.state().aborted);
+        assert_eq!(s3.state().parts_sent, vec![1, 2]);
+
+        s3.state().failing_part = None;
+        transfers
+            .resume_upload(upload_request(), &upload_id, &object(3500)[..])
+            .await
+            .unwrap();
+        let state = s3.state();
+        assert_eq!(state.parts_sent, vec![1, 2, 3, 4]);
+        assert_eq!(state.object, object(3500));
+    }
+
+    #[tokio::test]
+    async fn downloads_ranges_to_files() {
+        let s3 = FakeS3::default();
+        s3.state().object = object(2500);
+        let path = std::env::temp_dir().join("rusoto_s3_transfer_download");
+        let transfers = TransferManager::new(s3.client()).with_part_size(1000);
+
+        let request = GetObjectRequest {
+            bucket: "bucket".to_owned(),
+            key: "key".to_owned(),
+            ..Default::default()
+        };
+        let size = transfers.download_file(request, &path).await.unwrap();
+        let downloaded = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(size, 2500);
+        assert_eq!(downloaded, object(2500));
+        let mut ranges = s3.state().ranges.clone();
+        ranges.sort();
+        let if_match = Some("\"object\"".to_owned());
+        assert_eq!(
+            ranges,
+            vec![
+                ("bytes=0-999".to_owned(), if_match.clone()),
+                ("bytes=1000-1999".to_owned(), if_match.clone()),
+                ("bytes=2000-2499".to_owned(), if_match),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn checks_e_tags_of_parts() {
+        let s3 = FakeS3::default();
+        let transfers = TransferManager::new(s3.client())
+            .with_part_size(1000)
+            .with_checksums(true);
+
+        // the ETags of the fake aren't MD5 digests
+        match transfers.upload(upload_request(), &object(1500)[..]).await {
+            Err(TransferError::ChecksumMismatch(_)) => {}
+            result => panic!("expected a checksum mismatch, got {:?}", result),
+        }
+        let state = s3.state();
+        assert!(state.aborted);
+        assert!(state
+            .content_md5s
+            .contains(&base64::encode(&*md5::compute(&object(1500)[..1000]))));
+    }
+
+    #[tokio::test]
+    async fn checks_downloaded_files() {
+        let s3 = FakeS3::default();
+        s3.state().object = object(2500);
+        let path = std::env::temp_dir().join("rusoto_s3_transfer_checked_download");
+        let transfers = TransferManager::new(s3.client())
+            .with_part_size(1000)
+            .with_checksums(true);
+        let request = GetObjectRequest {
+            bucket: "bucket".to_owned(),
+            key: "key".to_owned(),
+            ..Default::default()
+        };
+
+        s3.state().e_tag = Some(format!("\"{:x}\"", md5::compute(object(2500))));
+        let downloaded = transfers.download_file(request.clone(), &path).await;
+        assert_eq!(downloaded.unwrap(), 2500);
+
+        s3.state().e_tag = Some(format!("\"{:x}\"", md5::compute(object(2499))));
+        let downloaded = transfers.download_file(request, &path).await;
+        std::fs::remove_file(&path).unwrap();
+        match downloaded {
+            Err(TransferError::ChecksumMismatch(_)) => {}
+            result => panic!("expected a checksum mismatch, got {:?}", result),
+        }
+    }
+}
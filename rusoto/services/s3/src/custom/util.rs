@@ -7,6 +7,7 @@ use rusoto_core::region::Region;
 use rusoto_core::signature;
 use rusoto_core::signature::SignedRequest;
 use std::time::Duration;
+use time::{OffsetDateTime, PrimitiveDateTime};
 /// URL encodes an S3 object key. This is necessary for `copy_object` and `upload_part_copy`,
 /// which require the `copy_source` field to be URL encoded.
 ///
@@ -229,3 +230,217 @@ impl PreSignedRequest for UploadPartRequest {
         request.generate_presigned_url(credentials, &option.expires_in, false)
     }
 }
+
+/// Policy of a browser upload with an HTTP POST form, presigned with `build`.
+///
+/// https://docs.aws.amazon.com/AmazonS3/latest/API/sigv4-post-example.html
+///
+/// # Examples
+///
+/// ```
+/// use rusoto_core::credential::AwsCredentials;
+/// use rusoto_core::Region;
+/// use rusoto_s3::util::PostPolicy;
+///
+/// let credentials = AwsCredentials::new("access_key", "secret_key", None, None);
+/// let post = PostPolicy::new("my-bucket")
+///     .with_key_starts_with("uploads/")
+///     .with_content_length_range(1, 10 * 1024 * 1024)
+///     .with_acl("public-read")
+///     .build(&Region::UsEast1, &credentials);
+///
+/// // the form posts `post.fields`, followed by the `key` and `file` fields, to `post.url`
+/// assert_eq!(post.url, "https://s3.amazonaws.com/my-bucket");
+/// ```
+#[derive(Clone, Debug)]
+pub struct PostPolicy {
+    bucket: String,
+    expires_in: Duration,
+    conditions: Vec<PostPolicyCondition>,
+}
+
+#[derive(Clone, Debug)]
+enum PostPolicyCondition {
+    Equals(String, String),
+    StartsWith(String, String),
+    ContentLengthRange(u64, u64),
+}
+
+/// A presigned HTTP POST form, built from a `PostPolicy`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PresignedPost {
+    /// The URL to post the form to
+    pub url: String,
+    /// The fields of the form, in order, the file being the last one to follow them
+    pub fields: Vec<(String, String)>,
+}
+
+impl PostPolicy {
+    /// Creates a policy for uploads to `bucket`, expiring in an hour.
+    pub fn new<B: Into<String>>(bucket: B) -> PostPolicy {
+        PostPolicy {
+            bucket: bucket.into(),
+            expires_in: PreSignedRequestOption::default().expires_in,
+            conditions: Vec::new(),
+        }
+    }
+
+    /// Sets how long the form can be posted.
+    pub fn with_expires_in(mut self, expires_in: Duration) -> PostPolicy {
+        self.expires_in = expires_in;
+        self
+    }
+
+    /// Requires the object to be uploaded with `key`, which may include `${filename}`.
+    pub fn with_key<K: Into<String>>(self, key: K) -> PostPolicy {
+        self.with_condition("key", key)
+    }
+
+    /// Requires the key of the object to start with `prefix`, the form providing it.
+    pub fn with_key_starts_with<P: Into<String>>(self, prefix: P) -> PostPolicy {
+        self.with_starts_with("key", prefix)
+    }
+
+    /// Requires the size of the object to be between `min` and `max` bytes, inclusive.
+    pub fn with_content_length_range(mut self, min: u64, max: u64) -> PostPolicy {
+        self.conditions
+            .push(PostPolicyCondition::ContentLengthRange(min, max));
+        self
+    }
+
+    /// Requires the object to be uploaded with the canned ACL `acl`.
+    pub fn with_acl<A: Into<String>>(self, acl: A) -> PostPolicy {
+        self.with_condition("acl", acl)
+    }
+
+    /// Requires the object to be uploaded with the metadata `name` set to `value`.
+    pub fn with_metadata<N: AsRef<str>, V: Into<String>>(self, name: N, value: V) -> PostPolicy {
+        let field = format!("x-amz-meta-{}", name.as_ref());
+        self.with_condition(field, value)
+    }
+
+    /// Requires the form field `field` to be `value`, the presigned form including it.
+    pub fn with_condition<F: Into<String>, V: Into<String>>(
+        mut self,
+        field: F,
+        value: V,
+    ) -> PostPolicy {
+        self.conditions
+            .push(PostPolicyCondition::Equals(field.into(), value.into()));
+        self
+    }
+
+    /// Requires the form field `field` to start with `prefix`, an empty one allowing any value,
+    /// the form providing it.
+    pub fn with_starts_with<F: Into<String>, P: Into<String>>(
+        mut self,
+        field: F,
+        prefix: P,
+    ) -> PostPolicy {
+        self.conditions
+            .push(PostPolicyCondition::StartsWith(field.into(), prefix.into()));
+        self
+    }
+
+    /// Presigns the form for uploads in `region` with `credentials`.
+    pub fn build(&self, region: &Region, credentials: &AwsCredentials) -> PresignedPost {
+        let now = OffsetDateTime::now_utc();
+        self.build_at(
+            region,
+            credentials,
+            PrimitiveDateTime::new(now.date(), now.time()),
+        )
+    }
+
+    pub(crate) fn build_at(
+        &self,
+        region: &Region,
+        credentials: &AwsCredentials,
+        now: PrimitiveDateTime,
+    ) -> PresignedPost {
+        let request = SignedRequest::new("POST", "s3", region, &format!("/{}", self.bucket));
+        let url = format!(
+            "{}://{}{}",
+            request.scheme(),
+            request.hostname(),
+            request.canonical_path()
+        );
+
+        let date = now.format("%Y%m%dT%H%M%SZ");
+        let credential = format!(
+            "{}/{}/{}/s3/aws4_request",
+            credentials.aws_access_key_id(),
+            now.format("%Y%m%d"),
+            region.name()
+        );
+        let mut fields = Vec::new();
+        for condition in &self.conditions {
+            if let PostPolicyCondition::Equals(ref field, ref value) = *condition {
+                fields.push((field.clone(), value.clone()));
+            }
+        }
+        fields.push(("x-amz-algorithm".to_owned(), "AWS4-HMAC-SHA256".to_owned()));
+        fields.push(("x-amz-credential".to_owned(), credential));
+        fields.push(("x-amz-date".to_owned(), date));
+        if let Some(ref token) = *credentials.token() {
+            fields.push(("x-amz-security-token".to_owned(), token.clone()));
+        }
+
+        let mut conditions = vec![format!("{{\"bucket\":{}}}", json_string(&self.bucket))];
+        for condition in &self.conditions {
+            conditions.push(match *condition {
+                // the fields set by the form are listed below
+                PostPolicyCondition::Equals(..) => continue,
+                PostPolicyCondition::StartsWith(ref field, ref prefix) => format!(
+                    "[\"starts-with\",{},{}]",
+                    json_string(&format!("${}", field)),
+                    json_string(prefix)
+                ),
+                PostPolicyCondition::ContentLengthRange(min, max) => {
+                    format!("[\"content-length-range\",{},{}]", min, max)
+                }
+            });
+        }
+        for (field, value) in &fields {
+            conditions.push(format!("{{{}:{}}}", json_string(field), json_string(value)));
+        }
+        let expiration = now + self.expires_in;
+        let policy = format!(
+            "{{\"expiration\":\"{}.000Z\",\"conditions\":[{}]}}",
+            expiration.format("%Y-%m-%dT%H:%M:%S"),
+            conditions.join(",")
+        );
+
+        let policy = base64::encode(&policy);
+        let signature = signature::sign_string(
+            &policy,
+            credentials.aws_secret_access_key(),
+            now.date(),
+            region.name(),
+            "s3",
+        );
+        fields.push(("policy".to_owned(), policy));
+        fields.push(("x-amz-signature".to_owned(), signature));
+
+        PresignedPost { url, fields }
+    }
+}
+
+/// Quotes `value` as a JSON string.
+fn json_string(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if c.is_control() => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
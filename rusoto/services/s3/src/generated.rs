@@ -19,6 +19,8 @@ use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
 use rusoto_core::{Client, RusotoError};
 
+use crate::custom::S3Config;
+
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
 use rusoto_core::proto::xml::util::{
@@ -97,7 +99,7 @@ impl AbortIncompleteMultipartUploadSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct AbortMultipartUploadOutput {
-    pub request_charged: Option<String>,
+    pub request_charged: Option<RequestCharged>,
 }
 
 struct AbortMultipartUploadOutputDeserializer;
@@ -123,7 +125,7 @@ pub struct AbortMultipartUploadRequest {
     pub bucket: String,
     /// <p>Key of the object for which the multipart upload was initiated.</p>
     pub key: String,
-    pub request_payer: Option<String>,
+    pub request_payer: Option<RequestPayer>,
     /// <p>Upload ID that identifies the multipart upload.</p>
     pub upload_id: String,
 }
@@ -133,7 +135,7 @@ pub struct AbortMultipartUploadRequest {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct AccelerateConfiguration {
     /// <p>Specifies the transfer acceleration status of the bucket.</p>
-    pub status: Option<String>,
+    pub status: Option<BucketAccelerateStatus>,
 }
 
 pub struct AccelerateConfigurationSerializer;
@@ -198,7 +200,7 @@ impl AccessControlPolicySerializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct AccessControlTranslation {
     /// <p>Specifies the replica ownership. For default and valid values, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/API/RESTBucketPUTreplication.html">PUT bucket replication</a> in the <i>Amazon Simple Storage Service API Reference</i>.</p>
-    pub owner: String,
+    pub owner: OwnerOverride,
 }
 
 struct AccessControlTranslationDeserializer;
@@ -239,7 +241,7 @@ impl AccessControlTranslationSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Owner"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.owner
+            value = &obj.owner
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::end_element())
@@ -653,7 +655,7 @@ impl AnalyticsConfigurationSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Id"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.id
+            value = &obj.id
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         StorageClassAnalysisSerializer::serialize(
@@ -861,7 +863,7 @@ pub struct AnalyticsS3BucketDestination {
     /// <p>The account ID that owns the destination bucket. If no account ID is provided, the owner will not be validated prior to exporting data.</p>
     pub bucket_account_id: Option<String>,
     /// <p>Specifies the file format used when exporting data to Amazon S3.</p>
-    pub format: String,
+    pub format: AnalyticsS3ExportFileFormat,
     /// <p>The prefix to use when exporting data. The prefix is prepended to all results.</p>
     pub prefix: Option<String>,
 }
@@ -917,7 +919,7 @@ impl AnalyticsS3BucketDestinationSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Bucket"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.bucket
+            value = &obj.bucket
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         if let Some(ref value) = obj.bucket_account_id {
@@ -931,7 +933,7 @@ impl AnalyticsS3BucketDestinationSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Format"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.format
+            value = &obj.format
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         if let Some(ref value) = obj.prefix {
@@ -946,12 +948,105 @@ impl AnalyticsS3BucketDestinationSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AnalyticsS3ExportFileFormat {
+    /// `CSV`
+    Csv,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AnalyticsS3ExportFileFormat {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AnalyticsS3ExportFileFormat::Csv => "CSV",
+            AnalyticsS3ExportFileFormat::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AnalyticsS3ExportFileFormat {
+    fn default() -> AnalyticsS3ExportFileFormat {
+        AnalyticsS3ExportFileFormat::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AnalyticsS3ExportFileFormat {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AnalyticsS3ExportFileFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AnalyticsS3ExportFileFormat {
+    fn from(value: &'a str) -> AnalyticsS3ExportFileFormat {
+        match value {
+            "CSV" => AnalyticsS3ExportFileFormat::Csv,
+            _ => AnalyticsS3ExportFileFormat::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AnalyticsS3ExportFileFormat {
+    fn from(value: String) -> AnalyticsS3ExportFileFormat {
+        match value.as_str() {
+            "CSV" => AnalyticsS3ExportFileFormat::Csv,
+            _ => AnalyticsS3ExportFileFormat::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AnalyticsS3ExportFileFormat {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AnalyticsS3ExportFileFormat, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AnalyticsS3ExportFileFormat {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for AnalyticsS3ExportFileFormat {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for AnalyticsS3ExportFileFormat {
+    fn deserialize<D>(deserializer: D) -> Result<AnalyticsS3ExportFileFormat, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct AnalyticsS3ExportFileFormatDeserializer;
 impl AnalyticsS3ExportFileFormatDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<AnalyticsS3ExportFileFormat, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -964,7 +1059,7 @@ impl AnalyticsS3ExportFileFormatSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &AnalyticsS3ExportFileFormat,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -979,20 +1074,6 @@ impl AnalyticsS3ExportFileFormatSerializer {
 }
 
 pub type StreamingBody = ::rusoto_core::ByteStream;
-struct BodyDeserializer;
-impl BodyDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
-        tag_name: &str,
-        stack: &mut T,
-    ) -> Result<bytes::Bytes, XmlParseError> {
-        start_element(tag_name, stack)?;
-        let obj = characters(stack)?.into();
-        end_element(tag_name, stack)?;
-
-        Ok(obj)
-    }
-}
 
 pub struct BodySerializer;
 impl BodySerializer {
@@ -1019,7 +1100,7 @@ impl BodySerializer {
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct Bucket {
     /// <p>Date the bucket was created.</p>
-    pub creation_date: Option<String>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the bucket.</p>
     pub name: Option<String>,
 }
@@ -1045,12 +1126,110 @@ impl BucketDeserializer {
         })
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BucketAccelerateStatus {
+    /// `Enabled`
+    Enabled,
+    /// `Suspended`
+    Suspended,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl BucketAccelerateStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BucketAccelerateStatus::Enabled => "Enabled",
+            BucketAccelerateStatus::Suspended => "Suspended",
+            BucketAccelerateStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for BucketAccelerateStatus {
+    fn default() -> BucketAccelerateStatus {
+        BucketAccelerateStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for BucketAccelerateStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for BucketAccelerateStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for BucketAccelerateStatus {
+    fn from(value: &'a str) -> BucketAccelerateStatus {
+        match value {
+            "Enabled" => BucketAccelerateStatus::Enabled,
+            "Suspended" => BucketAccelerateStatus::Suspended,
+            _ => BucketAccelerateStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BucketAccelerateStatus {
+    fn from(value: String) -> BucketAccelerateStatus {
+        match value.as_str() {
+            "Enabled" => BucketAccelerateStatus::Enabled,
+            "Suspended" => BucketAccelerateStatus::Suspended,
+            _ => BucketAccelerateStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for BucketAccelerateStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<BucketAccelerateStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for BucketAccelerateStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for BucketAccelerateStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for BucketAccelerateStatus {
+    fn deserialize<D>(deserializer: D) -> Result<BucketAccelerateStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct BucketAccelerateStatusDeserializer;
 impl BucketAccelerateStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<BucketAccelerateStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -1063,7 +1242,7 @@ impl BucketAccelerateStatusSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &BucketAccelerateStatus,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -1077,6 +1256,111 @@ impl BucketAccelerateStatusSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BucketCannedACL {
+    /// `private`
+    Private,
+    /// `public-read`
+    PublicRead,
+    /// `public-read-write`
+    PublicReadWrite,
+    /// `authenticated-read`
+    AuthenticatedRead,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl BucketCannedACL {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BucketCannedACL::Private => "private",
+            BucketCannedACL::PublicRead => "public-read",
+            BucketCannedACL::PublicReadWrite => "public-read-write",
+            BucketCannedACL::AuthenticatedRead => "authenticated-read",
+            BucketCannedACL::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for BucketCannedACL {
+    fn default() -> BucketCannedACL {
+        BucketCannedACL::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for BucketCannedACL {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for BucketCannedACL {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for BucketCannedACL {
+    fn from(value: &'a str) -> BucketCannedACL {
+        match value {
+            "private" => BucketCannedACL::Private,
+            "public-read" => BucketCannedACL::PublicRead,
+            "public-read-write" => BucketCannedACL::PublicReadWrite,
+            "authenticated-read" => BucketCannedACL::AuthenticatedRead,
+            _ => BucketCannedACL::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BucketCannedACL {
+    fn from(value: String) -> BucketCannedACL {
+        match value.as_str() {
+            "private" => BucketCannedACL::Private,
+            "public-read" => BucketCannedACL::PublicRead,
+            "public-read-write" => BucketCannedACL::PublicReadWrite,
+            "authenticated-read" => BucketCannedACL::AuthenticatedRead,
+            _ => BucketCannedACL::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for BucketCannedACL {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<BucketCannedACL, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for BucketCannedACL {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for BucketCannedACL {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for BucketCannedACL {
+    fn deserialize<D>(deserializer: D) -> Result<BucketCannedACL, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Specifies the lifecycle configuration for objects in an Amazon S3 bucket. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/object-lifecycle-mgmt.html">Object Lifecycle Management</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -1102,12 +1386,240 @@ impl BucketLifecycleConfigurationSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BucketLocationConstraint {
+    /// `af-south-1`
+    AfSouth1,
+    /// `ap-east-1`
+    ApEast1,
+    /// `ap-northeast-1`
+    ApNortheast1,
+    /// `ap-northeast-2`
+    ApNortheast2,
+    /// `ap-northeast-3`
+    ApNortheast3,
+    /// `ap-south-1`
+    ApSouth1,
+    /// `ap-south-2`
+    ApSouth2,
+    /// `ap-southeast-1`
+    ApSoutheast1,
+    /// `ap-southeast-2`
+    ApSoutheast2,
+    /// `ap-southeast-3`
+    ApSoutheast3,
+    /// `ca-central-1`
+    CaCentral1,
+    /// `cn-north-1`
+    CnNorth1,
+    /// `cn-northwest-1`
+    CnNorthwest1,
+    /// `EU`
+    Eu,
+    /// `eu-central-1`
+    EuCentral1,
+    /// `eu-north-1`
+    EuNorth1,
+    /// `eu-south-1`
+    EuSouth1,
+    /// `eu-south-2`
+    EuSouth2,
+    /// `eu-west-1`
+    EuWest1,
+    /// `eu-west-2`
+    EuWest2,
+    /// `eu-west-3`
+    EuWest3,
+    /// `me-south-1`
+    MeSouth1,
+    /// `sa-east-1`
+    SaEast1,
+    /// `us-east-2`
+    UsEast2,
+    /// `us-gov-east-1`
+    UsGovEast1,
+    /// `us-gov-west-1`
+    UsGovWest1,
+    /// `us-west-1`
+    UsWest1,
+    /// `us-west-2`
+    UsWest2,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl BucketLocationConstraint {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BucketLocationConstraint::AfSouth1 => "af-south-1",
+            BucketLocationConstraint::ApEast1 => "ap-east-1",
+            BucketLocationConstraint::ApNortheast1 => "ap-northeast-1",
+            BucketLocationConstraint::ApNortheast2 => "ap-northeast-2",
+            BucketLocationConstraint::ApNortheast3 => "ap-northeast-3",
+            BucketLocationConstraint::ApSouth1 => "ap-south-1",
+            BucketLocationConstraint::ApSouth2 => "ap-south-2",
+            BucketLocationConstraint::ApSoutheast1 => "ap-southeast-1",
+            BucketLocationConstraint::ApSoutheast2 => "ap-southeast-2",
+            BucketLocationConstraint::ApSoutheast3 => "ap-southeast-3",
+            BucketLocationConstraint::CaCentral1 => "ca-central-1",
+            BucketLocationConstraint::CnNorth1 => "cn-north-1",
+            BucketLocationConstraint::CnNorthwest1 => "cn-northwest-1",
+            BucketLocationConstraint::Eu => "EU",
+            BucketLocationConstraint::EuCentral1 => "eu-central-1",
+            BucketLocationConstraint::EuNorth1 => "eu-north-1",
+            BucketLocationConstraint::EuSouth1 => "eu-south-1",
+            BucketLocationConstraint::EuSouth2 => "eu-south-2",
+            BucketLocationConstraint::EuWest1 => "eu-west-1",
+            BucketLocationConstraint::EuWest2 => "eu-west-2",
+            BucketLocationConstraint::EuWest3 => "eu-west-3",
+            BucketLocationConstraint::MeSouth1 => "me-south-1",
+            BucketLocationConstraint::SaEast1 => "sa-east-1",
+            BucketLocationConstraint::UsEast2 => "us-east-2",
+            BucketLocationConstraint::UsGovEast1 => "us-gov-east-1",
+            BucketLocationConstraint::UsGovWest1 => "us-gov-west-1",
+            BucketLocationConstraint::UsWest1 => "us-west-1",
+            BucketLocationConstraint::UsWest2 => "us-west-2",
+            BucketLocationConstraint::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for BucketLocationConstraint {
+    fn default() -> BucketLocationConstraint {
+        BucketLocationConstraint::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for BucketLocationConstraint {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for BucketLocationConstraint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for BucketLocationConstraint {
+    fn from(value: &'a str) -> BucketLocationConstraint {
+        match value {
+            "af-south-1" => BucketLocationConstraint::AfSouth1,
+            "ap-east-1" => BucketLocationConstraint::ApEast1,
+            "ap-northeast-1" => BucketLocationConstraint::ApNortheast1,
+            "ap-northeast-2" => BucketLocationConstraint::ApNortheast2,
+            "ap-northeast-3" => BucketLocationConstraint::ApNortheast3,
+            "ap-south-1" => BucketLocationConstraint::ApSouth1,
+            "ap-south-2" => BucketLocationConstraint::ApSouth2,
+            "ap-southeast-1" => BucketLocationConstraint::ApSoutheast1,
+            "ap-southeast-2" => BucketLocationConstraint::ApSoutheast2,
+            "ap-southeast-3" => BucketLocationConstraint::ApSoutheast3,
+            "ca-central-1" => BucketLocationConstraint::CaCentral1,
+            "cn-north-1" => BucketLocationConstraint::CnNorth1,
+            "cn-northwest-1" => BucketLocationConstraint::CnNorthwest1,
+            "EU" => BucketLocationConstraint::Eu,
+            "eu-central-1" => BucketLocationConstraint::EuCentral1,
+            "eu-north-1" => BucketLocationConstraint::EuNorth1,
+            "eu-south-1" => BucketLocationConstraint::EuSouth1,
+            "eu-south-2" => BucketLocationConstraint::EuSouth2,
+            "eu-west-1" => BucketLocationConstraint::EuWest1,
+            "eu-west-2" => BucketLocationConstraint::EuWest2,
+            "eu-west-3" => BucketLocationConstraint::EuWest3,
+            "me-south-1" => BucketLocationConstraint::MeSouth1,
+            "sa-east-1" => BucketLocationConstraint::SaEast1,
+            "us-east-2" => BucketLocationConstraint::UsEast2,
+            "us-gov-east-1" => BucketLocationConstraint::UsGovEast1,
+            "us-gov-west-1" => BucketLocationConstraint::UsGovWest1,
+            "us-west-1" => BucketLocationConstraint::UsWest1,
+            "us-west-2" => BucketLocationConstraint::UsWest2,
+            _ => BucketLocationConstraint::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BucketLocationConstraint {
+    fn from(value: String) -> BucketLocationConstraint {
+        match value.as_str() {
+            "af-south-1" => BucketLocationConstraint::AfSouth1,
+            "ap-east-1" => BucketLocationConstraint::ApEast1,
+            "ap-northeast-1" => BucketLocationConstraint::ApNortheast1,
+            "ap-northeast-2" => BucketLocationConstraint::ApNortheast2,
+            "ap-northeast-3" => BucketLocationConstraint::ApNortheast3,
+            "ap-south-1" => BucketLocationConstraint::ApSouth1,
+            "ap-south-2" => BucketLocationConstraint::ApSouth2,
+            "ap-southeast-1" => BucketLocationConstraint::ApSoutheast1,
+            "ap-southeast-2" => BucketLocationConstraint::ApSoutheast2,
+            "ap-southeast-3" => BucketLocationConstraint::ApSoutheast3,
+            "ca-central-1" => BucketLocationConstraint::CaCentral1,
+            "cn-north-1" => BucketLocationConstraint::CnNorth1,
+            "cn-northwest-1" => BucketLocationConstraint::CnNorthwest1,
+            "EU" => BucketLocationConstraint::Eu,
+            "eu-central-1" => BucketLocationConstraint::EuCentral1,
+            "eu-north-1" => BucketLocationConstraint::EuNorth1,
+            "eu-south-1" => BucketLocationConstraint::EuSouth1,
+            "eu-south-2" => BucketLocationConstraint::EuSouth2,
+            "eu-west-1" => BucketLocationConstraint::EuWest1,
+            "eu-west-2" => BucketLocationConstraint::EuWest2,
+            "eu-west-3" => BucketLocationConstraint::EuWest3,
+            "me-south-1" => BucketLocationConstraint::MeSouth1,
+            "sa-east-1" => BucketLocationConstraint::SaEast1,
+            "us-east-2" => BucketLocationConstraint::UsEast2,
+            "us-gov-east-1" => BucketLocationConstraint::UsGovEast1,
+            "us-gov-west-1" => BucketLocationConstraint::UsGovWest1,
+            "us-west-1" => BucketLocationConstraint::UsWest1,
+            "us-west-2" => BucketLocationConstraint::UsWest2,
+            _ => BucketLocationConstraint::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for BucketLocationConstraint {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<BucketLocationConstraint, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for BucketLocationConstraint {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for BucketLocationConstraint {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for BucketLocationConstraint {
+    fn deserialize<D>(deserializer: D) -> Result<BucketLocationConstraint, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct BucketLocationConstraintDeserializer;
 impl BucketLocationConstraintDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<BucketLocationConstraint, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -1120,7 +1632,7 @@ impl BucketLocationConstraintSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &BucketLocationConstraint,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -1160,12 +1672,115 @@ impl BucketLoggingStatusSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BucketLogsPermission {
+    /// `FULL_CONTROL`
+    FullControl,
+    /// `READ`
+    Read,
+    /// `WRITE`
+    Write,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl BucketLogsPermission {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BucketLogsPermission::FullControl => "FULL_CONTROL",
+            BucketLogsPermission::Read => "READ",
+            BucketLogsPermission::Write => "WRITE",
+            BucketLogsPermission::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for BucketLogsPermission {
+    fn default() -> BucketLogsPermission {
+        BucketLogsPermission::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for BucketLogsPermission {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for BucketLogsPermission {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for BucketLogsPermission {
+    fn from(value: &'a str) -> BucketLogsPermission {
+        match value {
+            "FULL_CONTROL" => BucketLogsPermission::FullControl,
+            "READ" => BucketLogsPermission::Read,
+            "WRITE" => BucketLogsPermission::Write,
+            _ => BucketLogsPermission::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BucketLogsPermission {
+    fn from(value: String) -> BucketLogsPermission {
+        match value.as_str() {
+            "FULL_CONTROL" => BucketLogsPermission::FullControl,
+            "READ" => BucketLogsPermission::Read,
+            "WRITE" => BucketLogsPermission::Write,
+            _ => BucketLogsPermission::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for BucketLogsPermission {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<BucketLogsPermission, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for BucketLogsPermission {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for BucketLogsPermission {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for BucketLogsPermission {
+    fn deserialize<D>(deserializer: D) -> Result<BucketLogsPermission, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct BucketLogsPermissionDeserializer;
 impl BucketLogsPermissionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<BucketLogsPermission, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -1178,7 +1793,7 @@ impl BucketLogsPermissionSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &BucketLogsPermission,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -1224,35 +1839,133 @@ impl BucketNameSerializer {
     }
 }
 
-struct BucketVersioningStatusDeserializer;
-impl BucketVersioningStatusDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
-        start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
-        end_element(tag_name, stack)?;
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BucketVersioningStatus {
+    /// `Enabled`
+    Enabled,
+    /// `Suspended`
+    Suspended,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
 
-        Ok(obj)
+impl BucketVersioningStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BucketVersioningStatus::Enabled => "Enabled",
+            BucketVersioningStatus::Suspended => "Suspended",
+            BucketVersioningStatus::Unknown(ref value) => value,
+        }
     }
 }
 
-pub struct BucketVersioningStatusSerializer;
-impl BucketVersioningStatusSerializer {
-    #[allow(unused_variables, warnings)]
-    pub fn serialize<W>(
-        mut writer: &mut EventWriter<W>,
-        name: &str,
-        obj: &String,
-    ) -> Result<(), xml::writer::Error>
-    where
-        W: Write,
-    {
-        writer.write(xml::writer::XmlEvent::start_element(name))?;
-        writer.write(xml::writer::XmlEvent::characters(&format!(
-            "{value}",
-            value = obj.to_string()
-        )))?;
-        writer.write(xml::writer::XmlEvent::end_element())
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for BucketVersioningStatus {
+    fn default() -> BucketVersioningStatus {
+        BucketVersioningStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for BucketVersioningStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for BucketVersioningStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for BucketVersioningStatus {
+    fn from(value: &'a str) -> BucketVersioningStatus {
+        match value {
+            "Enabled" => BucketVersioningStatus::Enabled,
+            "Suspended" => BucketVersioningStatus::Suspended,
+            _ => BucketVersioningStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BucketVersioningStatus {
+    fn from(value: String) -> BucketVersioningStatus {
+        match value.as_str() {
+            "Enabled" => BucketVersioningStatus::Enabled,
+            "Suspended" => BucketVersioningStatus::Suspended,
+            _ => BucketVersioningStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for BucketVersioningStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<BucketVersioningStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for BucketVersioningStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for BucketVersioningStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for BucketVersioningStatus {
+    fn deserialize<D>(deserializer: D) -> Result<BucketVersioningStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+struct BucketVersioningStatusDeserializer;
+impl BucketVersioningStatusDeserializer {
+    #[allow(unused_variables)]
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<BucketVersioningStatus, XmlParseError> {
+        start_element(tag_name, stack)?;
+        let obj = characters(stack)?.into();
+        end_element(tag_name, stack)?;
+
+        Ok(obj)
+    }
+}
+
+pub struct BucketVersioningStatusSerializer;
+impl BucketVersioningStatusSerializer {
+    #[allow(unused_variables, warnings)]
+    pub fn serialize<W>(
+        mut writer: &mut EventWriter<W>,
+        name: &str,
+        obj: &BucketVersioningStatus,
+    ) -> Result<(), xml::writer::Error>
+    where
+        W: Write,
+    {
+        writer.write(xml::writer::XmlEvent::start_element(name))?;
+        writer.write(xml::writer::XmlEvent::characters(&format!(
+            "{value}",
+            value = obj.to_string()
+        )))?;
+        writer.write(xml::writer::XmlEvent::end_element())
     }
 }
 
@@ -1483,7 +2196,7 @@ pub struct CSVInput {
     /// <p>A single character used to separate individual fields in a record. You can specify an arbitrary delimiter.</p>
     pub field_delimiter: Option<String>,
     /// <p><p>Describes the first line of input. Valid values are:</p> <ul> <li> <p> <code>NONE</code>: First line is not a header.</p> </li> <li> <p> <code>IGNORE</code>: First line is a header, but you can&#39;t use the header values to indicate the column in an expression. You can use column position (such as _1, <em>2, …) to indicate the column (<code>SELECT s.</em>1 FROM OBJECT s</code>).</p> </li> <li> <p> <code>Use</code>: First line is a header, and you can use the header value to identify a column in an expression (<code>SELECT &quot;name&quot; FROM OBJECT</code>). </p> </li> </ul></p>
-    pub file_header_info: Option<String>,
+    pub file_header_info: Option<FileHeaderInfo>,
     /// <p>A single character used for escaping when the field delimiter is part of the value. For example, if the value is <code>a, b</code>, Amazon S3 wraps this field value in quotation marks, as follows: <code>" a , b "</code>.</p> <p>Type: String</p> <p>Default: <code>"</code> </p> <p>Ancestors: <code>CSV</code> </p>
     pub quote_character: Option<String>,
     /// <p>A single character used for escaping the quotation mark character inside an already escaped value. For example, the value """ a , b """ is parsed as " a , b ".</p>
@@ -1577,7 +2290,7 @@ pub struct CSVOutput {
     /// <p>The single character used for escaping the quote character inside an already escaped value.</p>
     pub quote_escape_character: Option<String>,
     /// <p><p>Indicates whether to use quotation marks around output fields. </p> <ul> <li> <p> <code>ALWAYS</code>: Always use quotation marks for output fields.</p> </li> <li> <p> <code>ASNEEDED</code>: Use quotation marks for output fields when needed.</p> </li> </ul></p>
-    pub quote_fields: Option<String>,
+    pub quote_fields: Option<QuoteFields>,
     /// <p>A single character used to separate individual records in the output. Instead of the default value, you can specify an arbitrary delimiter.</p>
     pub record_delimiter: Option<String>,
 }
@@ -1678,7 +2391,7 @@ pub struct CloudFunctionConfiguration {
     /// <p>Lambda cloud function ARN that Amazon S3 can invoke when it detects events of the specified type.</p>
     pub cloud_function: Option<String>,
     /// <p>Bucket events for which to send notifications.</p>
-    pub events: Option<Vec<String>>,
+    pub events: Option<Vec<Event>>,
     pub id: Option<String>,
     /// <p>The role supporting the invocation of the Lambda function</p>
     pub invocation_role: Option<String>,
@@ -1898,11 +2611,11 @@ pub struct CompleteMultipartUploadOutput {
     pub key: Option<String>,
     /// <p>The URI that identifies the newly created object.</p>
     pub location: Option<String>,
-    pub request_charged: Option<String>,
+    pub request_charged: Option<RequestCharged>,
     /// <p>If present, specifies the ID of the AWS Key Management Service (AWS KMS) symmetric customer managed customer master key (CMK) that was used for the object.</p>
     pub ssekms_key_id: Option<String>,
     /// <p>If you specified server-side encryption either with an Amazon S3-managed encryption key or an AWS KMS customer master key (CMK) in your initiate multipart upload request, the response includes this header. It confirms the encryption algorithm that Amazon S3 used to encrypt the object.</p>
-    pub server_side_encryption: Option<String>,
+    pub server_side_encryption: Option<ServerSideEncryption>,
     /// <p>Version ID of the newly created object, in case the bucket has versioning turned on.</p>
     pub version_id: Option<String>,
 }
@@ -1947,7 +2660,7 @@ pub struct CompleteMultipartUploadRequest {
     pub key: String,
     /// <p>The container for the multipart upload request information.</p>
     pub multipart_upload: Option<CompletedMultipartUpload>,
-    pub request_payer: Option<String>,
+    pub request_payer: Option<RequestPayer>,
     /// <p>ID for the initiated multipart upload.</p>
     pub upload_id: String,
 }
@@ -2039,13 +2752,114 @@ impl CompletedPartListSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CompressionType {
+    /// `NONE`
+    None,
+    /// `GZIP`
+    Gzip,
+    /// `BZIP2`
+    Bzip2,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CompressionType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CompressionType::None => "NONE",
+            CompressionType::Gzip => "GZIP",
+            CompressionType::Bzip2 => "BZIP2",
+            CompressionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CompressionType {
+    fn default() -> CompressionType {
+        CompressionType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CompressionType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CompressionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CompressionType {
+    fn from(value: &'a str) -> CompressionType {
+        match value {
+            "NONE" => CompressionType::None,
+            "GZIP" => CompressionType::Gzip,
+            "BZIP2" => CompressionType::Bzip2,
+            _ => CompressionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CompressionType {
+    fn from(value: String) -> CompressionType {
+        match value.as_str() {
+            "NONE" => CompressionType::None,
+            "GZIP" => CompressionType::Gzip,
+            "BZIP2" => CompressionType::Bzip2,
+            _ => CompressionType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CompressionType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CompressionType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CompressionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for CompressionType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for CompressionType {
+    fn deserialize<D>(deserializer: D) -> Result<CompressionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+
 pub struct CompressionTypeSerializer;
 impl CompressionTypeSerializer {
     #[allow(unused_variables, warnings)]
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &CompressionType,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -2135,25 +2949,8 @@ impl ConditionSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
-#[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct ContinuationEvent {}
 
-struct ContinuationEventDeserializer;
-impl ContinuationEventDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
-        tag_name: &str,
-        stack: &mut T,
-    ) -> Result<ContinuationEvent, XmlParseError> {
-        start_element(tag_name, stack)?;
-
-        let obj = ContinuationEvent::default();
-
-        end_element(tag_name, stack)?;
-
-        Ok(obj)
-    }
-}
 #[derive(Default, Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct CopyObjectOutput {
@@ -2163,7 +2960,7 @@ pub struct CopyObjectOutput {
     pub copy_source_version_id: Option<String>,
     /// <p>If the object expiration is configured, the response includes this header.</p>
     pub expiration: Option<String>,
-    pub request_charged: Option<String>,
+    pub request_charged: Option<RequestCharged>,
     /// <p>If server-side encryption with a customer-provided encryption key was requested, the response will include this header confirming the encryption algorithm used.</p>
     pub sse_customer_algorithm: Option<String>,
     /// <p>If server-side encryption with a customer-provided encryption key was requested, the response will include this header to provide round-trip message integrity verification of the customer-provided encryption key.</p>
@@ -2173,7 +2970,7 @@ pub struct CopyObjectOutput {
     /// <p>If present, specifies the ID of the AWS Key Management Service (AWS KMS) symmetric customer managed customer master key (CMK) that was used for the object.</p>
     pub ssekms_key_id: Option<String>,
     /// <p>The server-side encryption algorithm used when storing this object in Amazon S3 (for example, AES256, aws:kms).</p>
-    pub server_side_encryption: Option<String>,
+    pub server_side_encryption: Option<ServerSideEncryption>,
     /// <p>Version ID of the newly created copy.</p>
     pub version_id: Option<String>,
 }
@@ -2198,7 +2995,7 @@ impl CopyObjectOutputDeserializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CopyObjectRequest {
     /// <p>The canned ACL to apply to the object.</p>
-    pub acl: Option<String>,
+    pub acl: Option<ObjectCannedACL>,
     /// <p>The name of the destination bucket.</p>
     pub bucket: String,
     /// <p>Specifies caching behavior along the request/reply chain.</p>
@@ -2216,11 +3013,11 @@ pub struct CopyObjectRequest {
     /// <p>Copies the object if its entity tag (ETag) matches the specified tag.</p>
     pub copy_source_if_match: Option<String>,
     /// <p>Copies the object if it has been modified since the specified time.</p>
-    pub copy_source_if_modified_since: Option<String>,
+    pub copy_source_if_modified_since: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Copies the object if its entity tag (ETag) is different than the specified ETag.</p>
     pub copy_source_if_none_match: Option<String>,
     /// <p>Copies the object if it hasn't been modified since the specified time.</p>
-    pub copy_source_if_unmodified_since: Option<String>,
+    pub copy_source_if_unmodified_since: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Specifies the algorithm to use when decrypting the source object (for example, AES256).</p>
     pub copy_source_sse_customer_algorithm: Option<String>,
     /// <p>Specifies the customer-provided encryption key for Amazon S3 to use to decrypt the source object. The encryption key provided in this header must be one that was used when the source object was created.</p>
@@ -2228,7 +3025,7 @@ pub struct CopyObjectRequest {
     /// <p>Specifies the 128-bit MD5 digest of the encryption key according to RFC 1321. Amazon S3 uses this header for a message integrity check to ensure that the encryption key was transmitted without error.</p>
     pub copy_source_sse_customer_key_md5: Option<String>,
     /// <p>The date and time at which the object is no longer cacheable.</p>
-    pub expires: Option<String>,
+    pub expires: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Gives the grantee READ, READ_ACP, and WRITE_ACP permissions on the object.</p>
     pub grant_full_control: Option<String>,
     /// <p>Allows grantee to read the object data and its metadata.</p>
@@ -2242,14 +3039,14 @@ pub struct CopyObjectRequest {
     /// <p>A map of metadata to store with the object in S3.</p>
     pub metadata: Option<::std::collections::HashMap<String, String>>,
     /// <p>Specifies whether the metadata is copied from the source object or replaced with metadata provided in the request.</p>
-    pub metadata_directive: Option<String>,
+    pub metadata_directive: Option<MetadataDirective>,
     /// <p>Specifies whether you want to apply a Legal Hold to the copied object.</p>
-    pub object_lock_legal_hold_status: Option<String>,
+    pub object_lock_legal_hold_status: Option<ObjectLockLegalHoldStatus>,
     /// <p>The Object Lock mode that you want to apply to the copied object.</p>
-    pub object_lock_mode: Option<String>,
+    pub object_lock_mode: Option<ObjectLockMode>,
     /// <p>The date and time when you want the copied object's Object Lock to expire.</p>
-    pub object_lock_retain_until_date: Option<String>,
-    pub request_payer: Option<String>,
+    pub object_lock_retain_until_date: Option<::chrono::DateTime<::chrono::Utc>>,
+    pub request_payer: Option<RequestPayer>,
     /// <p>Specifies the algorithm to use to when encrypting the object (for example, AES256).</p>
     pub sse_customer_algorithm: Option<String>,
     /// <p>Specifies the customer-provided encryption key for Amazon S3 to use in encrypting data. This value is used to store the object and then it is discarded; Amazon S3 does not store the encryption key. The key must be appropriate for use with the algorithm specified in the <code>x-amz-server-side​-encryption​-customer-algorithm</code> header.</p>
@@ -2261,13 +3058,13 @@ pub struct CopyObjectRequest {
     /// <p>Specifies the AWS KMS key ID to use for object encryption. All GET and PUT requests for an object protected by AWS KMS will fail if not made via SSL or using SigV4. For information about configuring using any of the officially supported AWS SDKs and AWS CLI, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/UsingAWSSDK.html#specify-signature-version">Specifying the Signature Version in Request Authentication</a> in the <i>Amazon S3 Developer Guide</i>.</p>
     pub ssekms_key_id: Option<String>,
     /// <p>The server-side encryption algorithm used when storing this object in Amazon S3 (for example, AES256, aws:kms).</p>
-    pub server_side_encryption: Option<String>,
+    pub server_side_encryption: Option<ServerSideEncryption>,
     /// <p>The type of storage to use for the object. Defaults to 'STANDARD'.</p>
-    pub storage_class: Option<String>,
+    pub storage_class: Option<StorageClass>,
     /// <p>The tag-set for the object destination object this value must be used in conjunction with the <code>TaggingDirective</code>. The tag-set must be encoded as URL Query parameters.</p>
     pub tagging: Option<String>,
     /// <p>Specifies whether the object tag-set are copied from the source object or replaced with tag-set provided in the request.</p>
-    pub tagging_directive: Option<String>,
+    pub tagging_directive: Option<TaggingDirective>,
     /// <p>If the bucket is configured as a website, redirects requests for this object to another object in the same bucket or to an external URL. Amazon S3 stores the value of this header in the object metadata.</p>
     pub website_redirect_location: Option<String>,
 }
@@ -2279,7 +3076,7 @@ pub struct CopyObjectResult {
     /// <p>Returns the ETag of the new object. The ETag reflects only changes to the contents of an object, not its metadata. The source and destination ETag is identical for a successfully copied object.</p>
     pub e_tag: Option<String>,
     /// <p>Returns the date that the object was last modified.</p>
-    pub last_modified: Option<String>,
+    pub last_modified: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 struct CopyObjectResultDeserializer;
@@ -2313,7 +3110,7 @@ pub struct CopyPartResult {
     /// <p>Entity tag of the object.</p>
     pub e_tag: Option<String>,
     /// <p>Date and time at which the object was uploaded.</p>
-    pub last_modified: Option<String>,
+    pub last_modified: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 struct CopyPartResultDeserializer;
@@ -2345,7 +3142,7 @@ impl CopyPartResultDeserializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateBucketConfiguration {
     /// <p>Specifies the Region where the bucket will be created. If you don't specify a Region, the bucket is created in the US East (N. Virginia) Region (us-east-1).</p>
-    pub location_constraint: Option<String>,
+    pub location_constraint: Option<BucketLocationConstraint>,
 }
 
 pub struct CreateBucketConfigurationSerializer;
@@ -2399,7 +3196,7 @@ impl CreateBucketOutputDeserializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateBucketRequest {
     /// <p>The canned ACL to apply to the bucket.</p>
-    pub acl: Option<String>,
+    pub acl: Option<BucketCannedACL>,
     /// <p>The name of the bucket to create.</p>
     pub bucket: String,
     /// <p>The configuration information for the bucket.</p>
@@ -2422,14 +3219,14 @@ pub struct CreateBucketRequest {
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct CreateMultipartUploadOutput {
     /// <p>If the bucket has a lifecycle rule configured with an action to abort incomplete multipart uploads and the prefix in the lifecycle rule matches the object name in the request, the response includes this header. The header indicates when the initiated multipart upload becomes eligible for an abort operation. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/mpuoverview.html#mpu-abort-incomplete-mpu-lifecycle-config"> Aborting Incomplete Multipart Uploads Using a Bucket Lifecycle Policy</a>.</p> <p>The response also includes the <code>x-amz-abort-rule-id</code> header that provides the ID of the lifecycle configuration rule that defines this action.</p>
-    pub abort_date: Option<String>,
+    pub abort_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>This header is returned along with the <code>x-amz-abort-date</code> header. It identifies the applicable lifecycle configuration rule that defines the action to abort incomplete multipart uploads.</p>
     pub abort_rule_id: Option<String>,
     /// <p>Name of the bucket to which the multipart upload was initiated. </p> <p>When using this API with an access point, you must direct requests to the access point hostname. The access point hostname takes the form <i>AccessPointName</i>-<i>AccountId</i>.s3-accesspoint.<i>Region</i>.amazonaws.com. When using this operation using an access point through the AWS SDKs, you provide the access point ARN in place of the bucket name. For more information about access point ARNs, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-access-points.html">Using Access Points</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p>
     pub bucket: Option<String>,
     /// <p>Object key for which the multipart upload was initiated.</p>
     pub key: Option<String>,
-    pub request_charged: Option<String>,
+    pub request_charged: Option<RequestCharged>,
     /// <p>If server-side encryption with a customer-provided encryption key was requested, the response will include this header confirming the encryption algorithm used.</p>
     pub sse_customer_algorithm: Option<String>,
     /// <p>If server-side encryption with a customer-provided encryption key was requested, the response will include this header to provide round-trip message integrity verification of the customer-provided encryption key.</p>
@@ -2439,7 +3236,7 @@ pub struct CreateMultipartUploadOutput {
     /// <p>If present, specifies the ID of the AWS Key Management Service (AWS KMS) symmetric customer managed customer master key (CMK) that was used for the object.</p>
     pub ssekms_key_id: Option<String>,
     /// <p>The server-side encryption algorithm used when storing this object in Amazon S3 (for example, AES256, aws:kms).</p>
-    pub server_side_encryption: Option<String>,
+    pub server_side_encryption: Option<ServerSideEncryption>,
     /// <p>ID for the initiated multipart upload.</p>
     pub upload_id: Option<String>,
 }
@@ -2478,7 +3275,7 @@ impl CreateMultipartUploadOutputDeserializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateMultipartUploadRequest {
     /// <p>The canned ACL to apply to the object.</p>
-    pub acl: Option<String>,
+    pub acl: Option<ObjectCannedACL>,
     /// <p>The name of the bucket to which to initiate the upload</p>
     pub bucket: String,
     /// <p>Specifies caching behavior along the request/reply chain.</p>
@@ -2492,7 +3289,7 @@ pub struct CreateMultipartUploadRequest {
     /// <p>A standard MIME type describing the format of the object data.</p>
     pub content_type: Option<String>,
     /// <p>The date and time at which the object is no longer cacheable.</p>
-    pub expires: Option<String>,
+    pub expires: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Gives the grantee READ, READ_ACP, and WRITE_ACP permissions on the object.</p>
     pub grant_full_control: Option<String>,
     /// <p>Allows grantee to read the object data and its metadata.</p>
@@ -2506,12 +3303,12 @@ pub struct CreateMultipartUploadRequest {
     /// <p>A map of metadata to store with the object in S3.</p>
     pub metadata: Option<::std::collections::HashMap<String, String>>,
     /// <p>Specifies whether you want to apply a Legal Hold to the uploaded object.</p>
-    pub object_lock_legal_hold_status: Option<String>,
+    pub object_lock_legal_hold_status: Option<ObjectLockLegalHoldStatus>,
     /// <p>Specifies the Object Lock mode that you want to apply to the uploaded object.</p>
-    pub object_lock_mode: Option<String>,
+    pub object_lock_mode: Option<ObjectLockMode>,
     /// <p>Specifies the date and time when you want the Object Lock to expire.</p>
-    pub object_lock_retain_until_date: Option<String>,
-    pub request_payer: Option<String>,
+    pub object_lock_retain_until_date: Option<::chrono::DateTime<::chrono::Utc>>,
+    pub request_payer: Option<RequestPayer>,
     /// <p>Specifies the algorithm to use to when encrypting the object (for example, AES256).</p>
     pub sse_customer_algorithm: Option<String>,
     /// <p>Specifies the customer-provided encryption key for Amazon S3 to use in encrypting data. This value is used to store the object and then it is discarded; Amazon S3 does not store the encryption key. The key must be appropriate for use with the algorithm specified in the <code>x-amz-server-side​-encryption​-customer-algorithm</code> header.</p>
@@ -2523,9 +3320,9 @@ pub struct CreateMultipartUploadRequest {
     /// <p>Specifies the ID of the symmetric customer managed AWS KMS CMK to use for object encryption. All GET and PUT requests for an object protected by AWS KMS will fail if not made via SSL or using SigV4. For information about configuring using any of the officially supported AWS SDKs and AWS CLI, see <a href="https://docs.aws.amazon.com/http:/docs.aws.amazon.com/AmazonS3/latest/dev/UsingAWSSDK.html#specify-signature-version">Specifying the Signature Version in Request Authentication</a> in the <i>Amazon S3 Developer Guide</i>.</p>
     pub ssekms_key_id: Option<String>,
     /// <p>The server-side encryption algorithm used when storing this object in Amazon S3 (for example, AES256, aws:kms).</p>
-    pub server_side_encryption: Option<String>,
+    pub server_side_encryption: Option<ServerSideEncryption>,
     /// <p>The type of storage to use for the object. Defaults to 'STANDARD'.</p>
-    pub storage_class: Option<String>,
+    pub storage_class: Option<StorageClass>,
     /// <p>The tag-set for the object. The tag-set must be encoded as URL Query parameters.</p>
     pub tagging: Option<String>,
     /// <p>If the bucket is configured as a website, redirects requests for this object to another object in the same bucket or to an external URL. Amazon S3 stores the value of this header in the object metadata.</p>
@@ -2535,9 +3332,12 @@ pub struct CreateMultipartUploadRequest {
 struct CreationDateDeserializer;
 impl CreationDateDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<::chrono::DateTime<::chrono::Utc>, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::proto::xml::util::timestamp_characters(stack)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -2546,9 +3346,12 @@ impl CreationDateDeserializer {
 struct DateDeserializer;
 impl DateDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<::chrono::DateTime<::chrono::Utc>, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::proto::xml::util::timestamp_characters(stack)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -2561,7 +3364,7 @@ impl DateSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &::chrono::DateTime<::chrono::Utc>,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -2569,7 +3372,7 @@ impl DateSerializer {
         writer.write(xml::writer::XmlEvent::start_element(name))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.to_string()
+            value = rusoto_core::serialization::TimestampFormat::Iso8601.format(obj)
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())
     }
@@ -2647,7 +3450,7 @@ pub struct DefaultRetention {
     /// <p>The number of days that you want to specify for the default retention period.</p>
     pub days: Option<i64>,
     /// <p>The default Object Lock retention mode you want to apply to new objects placed in the specified bucket.</p>
-    pub mode: Option<String>,
+    pub mode: Option<ObjectLockRetentionMode>,
     /// <p>The number of years that you want to specify for the default retention period.</p>
     pub years: Option<i64>,
 }
@@ -2857,7 +3660,7 @@ pub struct DeleteMarkerEntry {
     /// <p>The object key.</p>
     pub key: Option<String>,
     /// <p>Date and time the object was last modified.</p>
-    pub last_modified: Option<String>,
+    pub last_modified: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The account that created the delete marker.&gt;</p>
     pub owner: Option<Owner>,
     /// <p>Version ID of an object.</p>
@@ -2906,7 +3709,7 @@ impl DeleteMarkerEntryDeserializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct DeleteMarkerReplication {
     /// <p><p>Indicates whether to replicate delete markers.</p> <note> <p> In the current implementation, Amazon S3 doesn&#39;t replicate the delete markers. The status must be <code>Disabled</code>. </p> </note></p>
-    pub status: Option<String>,
+    pub status: Option<DeleteMarkerReplicationStatus>,
 }
 
 struct DeleteMarkerReplicationDeserializer;
@@ -2958,12 +3761,110 @@ impl DeleteMarkerReplicationSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DeleteMarkerReplicationStatus {
+    /// `Enabled`
+    Enabled,
+    /// `Disabled`
+    Disabled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DeleteMarkerReplicationStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeleteMarkerReplicationStatus::Enabled => "Enabled",
+            DeleteMarkerReplicationStatus::Disabled => "Disabled",
+            DeleteMarkerReplicationStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DeleteMarkerReplicationStatus {
+    fn default() -> DeleteMarkerReplicationStatus {
+        DeleteMarkerReplicationStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DeleteMarkerReplicationStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DeleteMarkerReplicationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DeleteMarkerReplicationStatus {
+    fn from(value: &'a str) -> DeleteMarkerReplicationStatus {
+        match value {
+            "Enabled" => DeleteMarkerReplicationStatus::Enabled,
+            "Disabled" => DeleteMarkerReplicationStatus::Disabled,
+            _ => DeleteMarkerReplicationStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeleteMarkerReplicationStatus {
+    fn from(value: String) -> DeleteMarkerReplicationStatus {
+        match value.as_str() {
+            "Enabled" => DeleteMarkerReplicationStatus::Enabled,
+            "Disabled" => DeleteMarkerReplicationStatus::Disabled,
+            _ => DeleteMarkerReplicationStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DeleteMarkerReplicationStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DeleteMarkerReplicationStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DeleteMarkerReplicationStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for DeleteMarkerReplicationStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for DeleteMarkerReplicationStatus {
+    fn deserialize<D>(deserializer: D) -> Result<DeleteMarkerReplicationStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct DeleteMarkerReplicationStatusDeserializer;
 impl DeleteMarkerReplicationStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<DeleteMarkerReplicationStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -2976,7 +3877,7 @@ impl DeleteMarkerReplicationStatusSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &DeleteMarkerReplicationStatus,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -3033,7 +3934,7 @@ impl DeleteMarkersDeserializer {
 pub struct DeleteObjectOutput {
     /// <p>Specifies whether the versioned object that was permanently deleted was (true) or was not (false) a delete marker.</p>
     pub delete_marker: Option<bool>,
-    pub request_charged: Option<String>,
+    pub request_charged: Option<RequestCharged>,
     /// <p>Returns the version ID of the delete marker created as a result of the DELETE operation.</p>
     pub version_id: Option<String>,
 }
@@ -3065,7 +3966,7 @@ pub struct DeleteObjectRequest {
     pub key: String,
     /// <p>The concatenation of the authentication device's serial number, a space, and the value that is displayed on your authentication device. Required to permanently delete a versioned object if versioning is configured with MFA delete enabled.</p>
     pub mfa: Option<String>,
-    pub request_payer: Option<String>,
+    pub request_payer: Option<RequestPayer>,
     /// <p>VersionId used to reference a specific version of the object.</p>
     pub version_id: Option<String>,
 }
@@ -3111,7 +4012,7 @@ pub struct DeleteObjectsOutput {
     pub deleted: Option<Vec<DeletedObject>>,
     /// <p>Container for a failed delete operation that describes the object that Amazon S3 attempted to delete and the error it encountered.</p>
     pub errors: Option<Vec<S3Error>>,
-    pub request_charged: Option<String>,
+    pub request_charged: Option<RequestCharged>,
 }
 
 struct DeleteObjectsOutputDeserializer;
@@ -3150,7 +4051,7 @@ pub struct DeleteObjectsRequest {
     pub delete: Delete,
     /// <p>The concatenation of the authentication device's serial number, a space, and the value that is displayed on your authentication device. Required to permanently delete a versioned object if versioning is configured with MFA delete enabled.</p>
     pub mfa: Option<String>,
-    pub request_payer: Option<String>,
+    pub request_payer: Option<RequestPayer>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
@@ -3308,7 +4209,7 @@ pub struct Destination {
     /// <p> A container specifying S3 Replication Time Control (S3 RTC), including whether S3 RTC is enabled and the time when all objects and operations on objects must be replicated. Must be specified together with a <code>Metrics</code> block. </p>
     pub replication_time: Option<ReplicationTime>,
     /// <p> The storage class to use when replicating objects, such as standard or reduced redundancy. By default, Amazon S3 uses the storage class of the source object to create the object replica. </p> <p>For valid values, see the <code>StorageClass</code> element of the <a href="https://docs.aws.amazon.com/AmazonS3/latest/API/RESTBucketPUTreplication.html">PUT Bucket replication</a> action in the <i>Amazon Simple Storage Service API Reference</i>.</p>
-    pub storage_class: Option<String>,
+    pub storage_class: Option<StorageClass>,
 }
 
 struct DestinationDeserializer;
@@ -3392,7 +4293,7 @@ impl DestinationSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Bucket"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.bucket
+            value = &obj.bucket
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         if let Some(ref value) = obj.encryption_configuration {
@@ -3536,12 +4437,106 @@ impl EnableRequestProgressSerializer {
     }
 }
 
+/// <p>Requests Amazon S3 to encode the object keys in the response and specifies the encoding method to use. An object key can contain any Unicode character; however, the XML 1.0 parser cannot parse some characters, such as characters with an ASCII value from 0 to 10. For characters that are not supported in XML 1.0, you can add this parameter to request that Amazon S3 encode the keys in the response.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EncodingType {
+    /// `url`
+    Url,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EncodingType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EncodingType::Url => "url",
+            EncodingType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EncodingType {
+    fn default() -> EncodingType {
+        EncodingType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EncodingType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EncodingType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EncodingType {
+    fn from(value: &'a str) -> EncodingType {
+        match value {
+            "url" => EncodingType::Url,
+            _ => EncodingType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EncodingType {
+    fn from(value: String) -> EncodingType {
+        match value.as_str() {
+            "url" => EncodingType::Url,
+            _ => EncodingType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EncodingType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EncodingType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EncodingType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for EncodingType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for EncodingType {
+    fn deserialize<D>(deserializer: D) -> Result<EncodingType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct EncodingTypeDeserializer;
 impl EncodingTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<EncodingType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -3554,7 +4549,7 @@ impl EncodingTypeSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &EncodingType,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -3573,7 +4568,7 @@ impl EncodingTypeSerializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct Encryption {
     /// <p>The server-side encryption algorithm used when storing job results in Amazon S3 (for example, AES256, aws:kms).</p>
-    pub encryption_type: String,
+    pub encryption_type: ServerSideEncryption,
     /// <p>If the encryption type is <code>aws:kms</code>, this optional value can be used to specify the encryption context for the restore results.</p>
     pub kms_context: Option<String>,
     /// <p>If the encryption type is <code>aws:kms</code>, this optional value specifies the ID of the symmetric customer managed AWS KMS CMK to use for encryption of job results. Amazon S3 only supports symmetric CMKs. For more information, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/symmetric-asymmetric.html">Using Symmetric and Asymmetric Keys</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
@@ -3595,7 +4590,7 @@ impl EncryptionSerializer {
         writer.write(xml::writer::XmlEvent::start_element("EncryptionType"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.encryption_type
+            value = &obj.encryption_type
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         if let Some(ref value) = obj.kms_context {
@@ -3699,25 +4694,8 @@ impl EndSerializer {
 
 /// <p>A message that indicates the request is complete and no more messages will be sent. You should not assume that the request is complete until the client receives an <code>EndEvent</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
-#[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct EndEvent {}
 
-struct EndEventDeserializer;
-impl EndEventDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
-        tag_name: &str,
-        stack: &mut T,
-    ) -> Result<EndEvent, XmlParseError> {
-        start_element(tag_name, stack)?;
-
-        let obj = EndEvent::default();
-
-        end_element(tag_name, stack)?;
-
-        Ok(obj)
-    }
-}
 /// <p>Container for all error elements.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
@@ -3805,7 +4783,7 @@ impl ErrorDocumentSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Key"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.key
+            value = &obj.key
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::end_element())
@@ -3839,25 +4817,276 @@ impl ErrorsDeserializer {
         Ok(obj)
     }
 }
-struct EventDeserializer;
-impl EventDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
-        start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
-        end_element(tag_name, stack)?;
-
-        Ok(obj)
-    }
-}
-
-pub struct EventSerializer;
-impl EventSerializer {
-    #[allow(unused_variables, warnings)]
+/// <p>The bucket event for which to send notifications.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Event {
+    /// `s3:ReducedRedundancyLostObject`
+    S3ReducedRedundancyLostObject,
+    /// `s3:ObjectCreated:*`
+    S3ObjectCreated,
+    /// `s3:ObjectCreated:Put`
+    S3ObjectCreatedPut,
+    /// `s3:ObjectCreated:Post`
+    S3ObjectCreatedPost,
+    /// `s3:ObjectCreated:Copy`
+    S3ObjectCreatedCopy,
+    /// `s3:ObjectCreated:CompleteMultipartUpload`
+    S3ObjectCreatedCompleteMultipartUpload,
+    /// `s3:ObjectRemoved:*`
+    S3ObjectRemoved,
+    /// `s3:ObjectRemoved:Delete`
+    S3ObjectRemovedDelete,
+    /// `s3:ObjectRemoved:DeleteMarkerCreated`
+    S3ObjectRemovedDeleteMarkerCreated,
+    /// `s3:ObjectRestore:*`
+    S3ObjectRestore,
+    /// `s3:ObjectRestore:Post`
+    S3ObjectRestorePost,
+    /// `s3:ObjectRestore:Completed`
+    S3ObjectRestoreCompleted,
+    /// `s3:Replication:*`
+    S3Replication,
+    /// `s3:Replication:OperationFailedReplication`
+    S3ReplicationOperationFailedReplication,
+    /// `s3:Replication:OperationNotTracked`
+    S3ReplicationOperationNotTracked,
+    /// `s3:Replication:OperationMissedThreshold`
+    S3ReplicationOperationMissedThreshold,
+    /// `s3:Replication:OperationReplicatedAfterThreshold`
+    S3ReplicationOperationReplicatedAfterThreshold,
+    /// `s3:ObjectRestore:Delete`
+    S3ObjectRestoreDelete,
+    /// `s3:LifecycleTransition`
+    S3LifecycleTransition,
+    /// `s3:IntelligentTiering`
+    S3IntelligentTiering,
+    /// `s3:ObjectAcl:Put`
+    S3ObjectAclPut,
+    /// `s3:LifecycleExpiration:*`
+    S3LifecycleExpiration,
+    /// `s3:LifecycleExpiration:Delete`
+    S3LifecycleExpirationDelete,
+    /// `s3:LifecycleExpiration:DeleteMarkerCreated`
+    S3LifecycleExpirationDeleteMarkerCreated,
+    /// `s3:ObjectTagging:*`
+    S3ObjectTagging,
+    /// `s3:ObjectTagging:Put`
+    S3ObjectTaggingPut,
+    /// `s3:ObjectTagging:Delete`
+    S3ObjectTaggingDelete,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Event {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Event::S3ReducedRedundancyLostObject => "s3:ReducedRedundancyLostObject",
+            Event::S3ObjectCreated => "s3:ObjectCreated:*",
+            Event::S3ObjectCreatedPut => "s3:ObjectCreated:Put",
+            Event::S3ObjectCreatedPost => "s3:ObjectCreated:Post",
+            Event::S3ObjectCreatedCopy => "s3:ObjectCreated:Copy",
+            Event::S3ObjectCreatedCompleteMultipartUpload => {
+                "s3:ObjectCreated:CompleteMultipartUpload"
+            }
+            Event::S3ObjectRemoved => "s3:ObjectRemoved:*",
+            Event::S3ObjectRemovedDelete => "s3:ObjectRemoved:Delete",
+            Event::S3ObjectRemovedDeleteMarkerCreated => "s3:ObjectRemoved:DeleteMarkerCreated",
+            Event::S3ObjectRestore => "s3:ObjectRestore:*",
+            Event::S3ObjectRestorePost => "s3:ObjectRestore:Post",
+            Event::S3ObjectRestoreCompleted => "s3:ObjectRestore:Completed",
+            Event::S3Replication => "s3:Replication:*",
+            Event::S3ReplicationOperationFailedReplication => {
+                "s3:Replication:OperationFailedReplication"
+            }
+            Event::S3ReplicationOperationNotTracked => "s3:Replication:OperationNotTracked",
+            Event::S3ReplicationOperationMissedThreshold => {
+                "s3:Replication:OperationMissedThreshold"
+            }
+            Event::S3ReplicationOperationReplicatedAfterThreshold => {
+                "s3:Replication:OperationReplicatedAfterThreshold"
+            }
+            Event::S3ObjectRestoreDelete => "s3:ObjectRestore:Delete",
+            Event::S3LifecycleTransition => "s3:LifecycleTransition",
+            Event::S3IntelligentTiering => "s3:IntelligentTiering",
+            Event::S3ObjectAclPut => "s3:ObjectAcl:Put",
+            Event::S3LifecycleExpiration => "s3:LifecycleExpiration:*",
+            Event::S3LifecycleExpirationDelete => "s3:LifecycleExpiration:Delete",
+            Event::S3LifecycleExpirationDeleteMarkerCreated => {
+                "s3:LifecycleExpiration:DeleteMarkerCreated"
+            }
+            Event::S3ObjectTagging => "s3:ObjectTagging:*",
+            Event::S3ObjectTaggingPut => "s3:ObjectTagging:Put",
+            Event::S3ObjectTaggingDelete => "s3:ObjectTagging:Delete",
+            Event::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Event {
+    fn default() -> Event {
+        Event::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Event {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Event {
+    fn from(value: &'a str) -> Event {
+        match value {
+            "s3:ReducedRedundancyLostObject" => Event::S3ReducedRedundancyLostObject,
+            "s3:ObjectCreated:*" => Event::S3ObjectCreated,
+            "s3:ObjectCreated:Put" => Event::S3ObjectCreatedPut,
+            "s3:ObjectCreated:Post" => Event::S3ObjectCreatedPost,
+            "s3:ObjectCreated:Copy" => Event::S3ObjectCreatedCopy,
+            "s3:ObjectCreated:CompleteMultipartUpload" => {
+                Event::S3ObjectCreatedCompleteMultipartUpload
+            }
+            "s3:ObjectRemoved:*" => Event::S3ObjectRemoved,
+            "s3:ObjectRemoved:Delete" => Event::S3ObjectRemovedDelete,
+            "s3:ObjectRemoved:DeleteMarkerCreated" => Event::S3ObjectRemovedDeleteMarkerCreated,
+            "s3:ObjectRestore:*" => Event::S3ObjectRestore,
+            "s3:ObjectRestore:Post" => Event::S3ObjectRestorePost,
+            "s3:ObjectRestore:Completed" => Event::S3ObjectRestoreCompleted,
+            "s3:Replication:*" => Event::S3Replication,
+            "s3:Replication:OperationFailedReplication" => {
+                Event::S3ReplicationOperationFailedReplication
+            }
+            "s3:Replication:OperationNotTracked" => Event::S3ReplicationOperationNotTracked,
+            "s3:Replication:OperationMissedThreshold" => {
+                Event::S3ReplicationOperationMissedThreshold
+            }
+            "s3:Replication:OperationReplicatedAfterThreshold" => {
+                Event::S3ReplicationOperationReplicatedAfterThreshold
+            }
+            "s3:ObjectRestore:Delete" => Event::S3ObjectRestoreDelete,
+            "s3:LifecycleTransition" => Event::S3LifecycleTransition,
+            "s3:IntelligentTiering" => Event::S3IntelligentTiering,
+            "s3:ObjectAcl:Put" => Event::S3ObjectAclPut,
+            "s3:LifecycleExpiration:*" => Event::S3LifecycleExpiration,
+            "s3:LifecycleExpiration:Delete" => Event::S3LifecycleExpirationDelete,
+            "s3:LifecycleExpiration:DeleteMarkerCreated" => {
+                Event::S3LifecycleExpirationDeleteMarkerCreated
+            }
+            "s3:ObjectTagging:*" => Event::S3ObjectTagging,
+            "s3:ObjectTagging:Put" => Event::S3ObjectTaggingPut,
+            "s3:ObjectTagging:Delete" => Event::S3ObjectTaggingDelete,
+            _ => Event::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Event {
+    fn from(value: String) -> Event {
+        match value.as_str() {
+            "s3:ReducedRedundancyLostObject" => Event::S3ReducedRedundancyLostObject,
+            "s3:ObjectCreated:*" => Event::S3ObjectCreated,
+            "s3:ObjectCreated:Put" => Event::S3ObjectCreatedPut,
+            "s3:ObjectCreated:Post" => Event::S3ObjectCreatedPost,
+            "s3:ObjectCreated:Copy" => Event::S3ObjectCreatedCopy,
+            "s3:ObjectCreated:CompleteMultipartUpload" => {
+                Event::S3ObjectCreatedCompleteMultipartUpload
+            }
+            "s3:ObjectRemoved:*" => Event::S3ObjectRemoved,
+            "s3:ObjectRemoved:Delete" => Event::S3ObjectRemovedDelete,
+            "s3:ObjectRemoved:DeleteMarkerCreated" => Event::S3ObjectRemovedDeleteMarkerCreated,
+            "s3:ObjectRestore:*" => Event::S3ObjectRestore,
+            "s3:ObjectRestore:Post" => Event::S3ObjectRestorePost,
+            "s3:ObjectRestore:Completed" => Event::S3ObjectRestoreCompleted,
+            "s3:Replication:*" => Event::S3Replication,
+            "s3:Replication:OperationFailedReplication" => {
+                Event::S3ReplicationOperationFailedReplication
+            }
+            "s3:Replication:OperationNotTracked" => Event::S3ReplicationOperationNotTracked,
+            "s3:Replication:OperationMissedThreshold" => {
+                Event::S3ReplicationOperationMissedThreshold
+            }
+            "s3:Replication:OperationReplicatedAfterThreshold" => {
+                Event::S3ReplicationOperationReplicatedAfterThreshold
+            }
+            "s3:ObjectRestore:Delete" => Event::S3ObjectRestoreDelete,
+            "s3:LifecycleTransition" => Event::S3LifecycleTransition,
+            "s3:IntelligentTiering" => Event::S3IntelligentTiering,
+            "s3:ObjectAcl:Put" => Event::S3ObjectAclPut,
+            "s3:LifecycleExpiration:*" => Event::S3LifecycleExpiration,
+            "s3:LifecycleExpiration:Delete" => Event::S3LifecycleExpirationDelete,
+            "s3:LifecycleExpiration:DeleteMarkerCreated" => {
+                Event::S3LifecycleExpirationDeleteMarkerCreated
+            }
+            "s3:ObjectTagging:*" => Event::S3ObjectTagging,
+            "s3:ObjectTagging:Put" => Event::S3ObjectTaggingPut,
+            "s3:ObjectTagging:Delete" => Event::S3ObjectTaggingDelete,
+            _ => Event::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Event {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Event, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Event {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for Event {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for Event {
+    fn deserialize<D>(deserializer: D) -> Result<Event, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+struct EventDeserializer;
+impl EventDeserializer {
+    #[allow(unused_variables)]
+    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<Event, XmlParseError> {
+        start_element(tag_name, stack)?;
+        let obj = characters(stack)?.into();
+        end_element(tag_name, stack)?;
+
+        Ok(obj)
+    }
+}
+
+pub struct EventSerializer;
+impl EventSerializer {
+    #[allow(unused_variables, warnings)]
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &Event,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -3877,7 +5106,7 @@ impl EventListDeserializer {
     fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
-    ) -> Result<Vec<String>, XmlParseError> {
+    ) -> Result<Vec<Event>, XmlParseError> {
         let mut obj = vec![];
 
         loop {
@@ -3905,7 +5134,7 @@ impl EventListSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &Vec<String>,
+        obj: &Vec<Event>,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -3923,7 +5152,7 @@ impl EventListSerializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ExistingObjectReplication {
     /// <p><p/></p>
-    pub status: String,
+    pub status: ExistingObjectReplicationStatus,
 }
 
 struct ExistingObjectReplicationDeserializer;
@@ -3966,19 +5195,117 @@ impl ExistingObjectReplicationSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Status"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.status
+            value = &obj.status
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::end_element())
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ExistingObjectReplicationStatus {
+    /// `Enabled`
+    Enabled,
+    /// `Disabled`
+    Disabled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ExistingObjectReplicationStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ExistingObjectReplicationStatus::Enabled => "Enabled",
+            ExistingObjectReplicationStatus::Disabled => "Disabled",
+            ExistingObjectReplicationStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ExistingObjectReplicationStatus {
+    fn default() -> ExistingObjectReplicationStatus {
+        ExistingObjectReplicationStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ExistingObjectReplicationStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ExistingObjectReplicationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ExistingObjectReplicationStatus {
+    fn from(value: &'a str) -> ExistingObjectReplicationStatus {
+        match value {
+            "Enabled" => ExistingObjectReplicationStatus::Enabled,
+            "Disabled" => ExistingObjectReplicationStatus::Disabled,
+            _ => ExistingObjectReplicationStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ExistingObjectReplicationStatus {
+    fn from(value: String) -> ExistingObjectReplicationStatus {
+        match value.as_str() {
+            "Enabled" => ExistingObjectReplicationStatus::Enabled,
+            "Disabled" => ExistingObjectReplicationStatus::Disabled,
+            _ => ExistingObjectReplicationStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ExistingObjectReplicationStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ExistingObjectReplicationStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ExistingObjectReplicationStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ExistingObjectReplicationStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ExistingObjectReplicationStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ExistingObjectReplicationStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct ExistingObjectReplicationStatusDeserializer;
 impl ExistingObjectReplicationStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ExistingObjectReplicationStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -3991,7 +5318,7 @@ impl ExistingObjectReplicationStatusSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &ExistingObjectReplicationStatus,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -4005,12 +5332,110 @@ impl ExistingObjectReplicationStatusSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ExpirationStatus {
+    /// `Enabled`
+    Enabled,
+    /// `Disabled`
+    Disabled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ExpirationStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ExpirationStatus::Enabled => "Enabled",
+            ExpirationStatus::Disabled => "Disabled",
+            ExpirationStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ExpirationStatus {
+    fn default() -> ExpirationStatus {
+        ExpirationStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ExpirationStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ExpirationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ExpirationStatus {
+    fn from(value: &'a str) -> ExpirationStatus {
+        match value {
+            "Enabled" => ExpirationStatus::Enabled,
+            "Disabled" => ExpirationStatus::Disabled,
+            _ => ExpirationStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ExpirationStatus {
+    fn from(value: String) -> ExpirationStatus {
+        match value.as_str() {
+            "Enabled" => ExpirationStatus::Enabled,
+            "Disabled" => ExpirationStatus::Disabled,
+            _ => ExpirationStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ExpirationStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ExpirationStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ExpirationStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ExpirationStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ExpirationStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ExpirationStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct ExpirationStatusDeserializer;
 impl ExpirationStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ExpirationStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -4023,7 +5448,7 @@ impl ExpirationStatusSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &ExpirationStatus,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -4167,13 +5592,104 @@ impl ExpressionSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ExpressionType {
+    /// `SQL`
+    Sql,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ExpressionType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ExpressionType::Sql => "SQL",
+            ExpressionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ExpressionType {
+    fn default() -> ExpressionType {
+        ExpressionType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ExpressionType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ExpressionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ExpressionType {
+    fn from(value: &'a str) -> ExpressionType {
+        match value {
+            "SQL" => ExpressionType::Sql,
+            _ => ExpressionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ExpressionType {
+    fn from(value: String) -> ExpressionType {
+        match value.as_str() {
+            "SQL" => ExpressionType::Sql,
+            _ => ExpressionType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ExpressionType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ExpressionType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ExpressionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ExpressionType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ExpressionType {
+    fn deserialize<D>(deserializer: D) -> Result<ExpressionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+
 pub struct ExpressionTypeSerializer;
 impl ExpressionTypeSerializer {
     #[allow(unused_variables, warnings)]
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &ExpressionType,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -4227,13 +5743,114 @@ impl FieldDelimiterSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FileHeaderInfo {
+    /// `USE`
+    Use,
+    /// `IGNORE`
+    Ignore,
+    /// `NONE`
+    None,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl FileHeaderInfo {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FileHeaderInfo::Use => "USE",
+            FileHeaderInfo::Ignore => "IGNORE",
+            FileHeaderInfo::None => "NONE",
+            FileHeaderInfo::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for FileHeaderInfo {
+    fn default() -> FileHeaderInfo {
+        FileHeaderInfo::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for FileHeaderInfo {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FileHeaderInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FileHeaderInfo {
+    fn from(value: &'a str) -> FileHeaderInfo {
+        match value {
+            "USE" => FileHeaderInfo::Use,
+            "IGNORE" => FileHeaderInfo::Ignore,
+            "NONE" => FileHeaderInfo::None,
+            _ => FileHeaderInfo::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FileHeaderInfo {
+    fn from(value: String) -> FileHeaderInfo {
+        match value.as_str() {
+            "USE" => FileHeaderInfo::Use,
+            "IGNORE" => FileHeaderInfo::Ignore,
+            "NONE" => FileHeaderInfo::None,
+            _ => FileHeaderInfo::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for FileHeaderInfo {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<FileHeaderInfo, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FileHeaderInfo {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for FileHeaderInfo {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for FileHeaderInfo {
+    fn deserialize<D>(deserializer: D) -> Result<FileHeaderInfo, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+
 pub struct FileHeaderInfoSerializer;
 impl FileHeaderInfoSerializer {
     #[allow(unused_variables, warnings)]
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &FileHeaderInfo,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -4253,7 +5870,7 @@ impl FileHeaderInfoSerializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct FilterRule {
     /// <p>The object key name prefix or suffix identifying one or more objects to which the filtering rule applies. The maximum length is 1,024 characters. Overlapping prefixes and suffixes are not supported. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/NotificationHowTo.html">Configuring Event Notifications</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p>
-    pub name: Option<String>,
+    pub name: Option<FilterRuleName>,
     /// <p>The value that the filter searches for in object key names.</p>
     pub value: Option<String>,
 }
@@ -4358,12 +5975,110 @@ impl FilterRuleListSerializer {
     }
 }
 
-struct FilterRuleNameDeserializer;
-impl FilterRuleNameDeserializer {
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FilterRuleName {
+    /// `prefix`
+    Prefix,
+    /// `suffix`
+    Suffix,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl FilterRuleName {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FilterRuleName::Prefix => "prefix",
+            FilterRuleName::Suffix => "suffix",
+            FilterRuleName::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for FilterRuleName {
+    fn default() -> FilterRuleName {
+        FilterRuleName::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for FilterRuleName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FilterRuleName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FilterRuleName {
+    fn from(value: &'a str) -> FilterRuleName {
+        match value {
+            "prefix" => FilterRuleName::Prefix,
+            "suffix" => FilterRuleName::Suffix,
+            _ => FilterRuleName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FilterRuleName {
+    fn from(value: String) -> FilterRuleName {
+        match value.as_str() {
+            "prefix" => FilterRuleName::Prefix,
+            "suffix" => FilterRuleName::Suffix,
+            _ => FilterRuleName::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for FilterRuleName {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<FilterRuleName, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FilterRuleName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for FilterRuleName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for FilterRuleName {
+    fn deserialize<D>(deserializer: D) -> Result<FilterRuleName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+struct FilterRuleNameDeserializer;
+impl FilterRuleNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<FilterRuleName, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -4376,7 +6091,7 @@ impl FilterRuleNameSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &FilterRuleName,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -4426,7 +6141,7 @@ impl FilterRuleValueSerializer {
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct GetBucketAccelerateConfigurationOutput {
     /// <p>The accelerate configuration of the bucket.</p>
-    pub status: Option<String>,
+    pub status: Option<BucketAccelerateStatus>,
 }
 
 struct GetBucketAccelerateConfigurationOutputDeserializer;
@@ -4708,7 +6423,7 @@ pub struct GetBucketLifecycleRequest {
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct GetBucketLocationOutput {
     /// <p>Specifies the Region where the bucket resides. For a list of all the Amazon S3 supported location constraints by Region, see <a href="https://docs.aws.amazon.com/general/latest/gr/rande.html#s3_region">Regions and Endpoints</a>.</p>
-    pub location_constraint: Option<String>,
+    pub location_constraint: Option<BucketLocationConstraint>,
 }
 
 struct GetBucketLocationOutputDeserializer;
@@ -4882,7 +6597,7 @@ pub struct GetBucketReplicationRequest {
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct GetBucketRequestPaymentOutput {
     /// <p>Specifies who pays for the download and request fees.</p>
-    pub payer: Option<String>,
+    pub payer: Option<Payer>,
 }
 
 struct GetBucketRequestPaymentOutputDeserializer;
@@ -4951,9 +6666,9 @@ pub struct GetBucketTaggingRequest {
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct GetBucketVersioningOutput {
     /// <p>Specifies whether MFA delete is enabled in the bucket versioning configuration. This element is only returned if the bucket has been configured with MFA delete. If the bucket has never been so configured, this element is not returned.</p>
-    pub mfa_delete: Option<String>,
+    pub mfa_delete: Option<MFADeleteStatus>,
     /// <p>The versioning state of the bucket.</p>
-    pub status: Option<String>,
+    pub status: Option<BucketVersioningStatus>,
 }
 
 struct GetBucketVersioningOutputDeserializer;
@@ -5059,7 +6774,7 @@ pub struct GetObjectAclOutput {
     pub grants: Option<Vec<Grant>>,
     /// <p> Container for the bucket owner's display name and ID.</p>
     pub owner: Option<Owner>,
-    pub request_charged: Option<String>,
+    pub request_charged: Option<RequestCharged>,
 }
 
 struct GetObjectAclOutputDeserializer;
@@ -5092,7 +6807,7 @@ pub struct GetObjectAclRequest {
     pub bucket: String,
     /// <p>The key of the object for which to get the ACL information.</p>
     pub key: String,
-    pub request_payer: Option<String>,
+    pub request_payer: Option<RequestPayer>,
     /// <p>VersionId used to reference a specific version of the object.</p>
     pub version_id: Option<String>,
 }
@@ -5127,7 +6842,7 @@ pub struct GetObjectLegalHoldRequest {
     pub bucket: String,
     /// <p>The key name for the object whose Legal Hold status you want to retrieve.</p>
     pub key: String,
-    pub request_payer: Option<String>,
+    pub request_payer: Option<RequestPayer>,
     /// <p>The version ID of the object whose Legal Hold status you want to retrieve.</p>
     pub version_id: Option<String>,
 }
@@ -5189,24 +6904,24 @@ pub struct GetObjectOutput {
     /// <p>If the object expiration is configured (see PUT Bucket lifecycle), the response includes this header. It includes the expiry-date and rule-id key-value pairs providing object expiration information. The value of the rule-id is URL encoded.</p>
     pub expiration: Option<String>,
     /// <p>The date and time at which the object is no longer cacheable.</p>
-    pub expires: Option<String>,
+    pub expires: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Last modified date of the object</p>
-    pub last_modified: Option<String>,
+    pub last_modified: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A map of metadata to store with the object in S3.</p>
     pub metadata: Option<::std::collections::HashMap<String, String>>,
     /// <p>This is set to the number of metadata entries not returned in <code>x-amz-meta</code> headers. This can happen if you create metadata using an API like SOAP that supports more flexible metadata than the REST API. For example, using SOAP, you can create metadata whose values are not legal HTTP headers.</p>
     pub missing_meta: Option<i64>,
     /// <p>Indicates whether this object has an active legal hold. This field is only returned if you have permission to view an object's legal hold status. </p>
-    pub object_lock_legal_hold_status: Option<String>,
+    pub object_lock_legal_hold_status: Option<ObjectLockLegalHoldStatus>,
     /// <p>The Object Lock mode currently in place for this object.</p>
-    pub object_lock_mode: Option<String>,
+    pub object_lock_mode: Option<ObjectLockMode>,
     /// <p>The date and time when this object's Object Lock will expire.</p>
-    pub object_lock_retain_until_date: Option<String>,
+    pub object_lock_retain_until_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The count of parts this object has.</p>
     pub parts_count: Option<i64>,
     /// <p>Amazon S3 can return this if your request involves a bucket that is either a source or destination in a replication rule.</p>
-    pub replication_status: Option<String>,
-    pub request_charged: Option<String>,
+    pub replication_status: Option<ReplicationStatus>,
+    pub request_charged: Option<RequestCharged>,
     /// <p>Provides information about object restoration operation and expiration time of the restored object copy.</p>
     pub restore: Option<String>,
     /// <p>If server-side encryption with a customer-provided encryption key was requested, the response will include this header confirming the encryption algorithm used.</p>
@@ -5216,9 +6931,9 @@ pub struct GetObjectOutput {
     /// <p>If present, specifies the ID of the AWS Key Management Service (AWS KMS) symmetric customer managed customer master key (CMK) that was used for the object.</p>
     pub ssekms_key_id: Option<String>,
     /// <p>The server-side encryption algorithm used when storing this object in Amazon S3 (for example, AES256, aws:kms).</p>
-    pub server_side_encryption: Option<String>,
+    pub server_side_encryption: Option<ServerSideEncryption>,
     /// <p>Provides storage class information of the object. Amazon S3 returns this header for all objects except for Standard storage class objects.</p>
-    pub storage_class: Option<String>,
+    pub storage_class: Option<StorageClass>,
     /// <p>The number of tags, if any, on the object.</p>
     pub tag_count: Option<i64>,
     /// <p>Version of the object.</p>
@@ -5235,18 +6950,18 @@ pub struct GetObjectRequest {
     /// <p>Return the object only if its entity tag (ETag) is the same as the one specified, otherwise return a 412 (precondition failed).</p>
     pub if_match: Option<String>,
     /// <p>Return the object only if it has been modified since the specified time, otherwise return a 304 (not modified).</p>
-    pub if_modified_since: Option<String>,
+    pub if_modified_since: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Return the object only if its entity tag (ETag) is different from the one specified, otherwise return a 304 (not modified).</p>
     pub if_none_match: Option<String>,
     /// <p>Return the object only if it has not been modified since the specified time, otherwise return a 412 (precondition failed).</p>
-    pub if_unmodified_since: Option<String>,
+    pub if_unmodified_since: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Key of the object to get.</p>
     pub key: String,
     /// <p>Part number of the object being read. This is a positive integer between 1 and 10,000. Effectively performs a 'ranged' GET request for the part specified. Useful for downloading just a part of an object.</p>
     pub part_number: Option<i64>,
     /// <p>Downloads the specified range bytes of an object. For more information about the HTTP Range header, see <a href="">http://www.w3.org/Protocols/rfc2616/rfc2616-sec14.html#sec14.35</a>.</p>
     pub range: Option<String>,
-    pub request_payer: Option<String>,
+    pub request_payer: Option<RequestPayer>,
     /// <p>Sets the <code>Cache-Control</code> header of the response.</p>
     pub response_cache_control: Option<String>,
     /// <p>Sets the <code>Content-Disposition</code> header of the response</p>
@@ -5258,7 +6973,7 @@ pub struct GetObjectRequest {
     /// <p>Sets the <code>Content-Type</code> header of the response.</p>
     pub response_content_type: Option<String>,
     /// <p>Sets the <code>Expires</code> header of the response.</p>
-    pub response_expires: Option<String>,
+    pub response_expires: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Specifies the algorithm to use to when encrypting the object (for example, AES256).</p>
     pub sse_customer_algorithm: Option<String>,
     /// <p>Specifies the customer-provided encryption key for Amazon S3 to use in encrypting data. This value is used to store the object and then it is discarded; Amazon S3 does not store the encryption key. The key must be appropriate for use with the algorithm specified in the <code>x-amz-server-side​-encryption​-customer-algorithm</code> header.</p>
@@ -5299,7 +7014,7 @@ pub struct GetObjectRetentionRequest {
     pub bucket: String,
     /// <p>The key name for the object whose retention settings you want to retrieve.</p>
     pub key: String,
-    pub request_payer: Option<String>,
+    pub request_payer: Option<RequestPayer>,
     /// <p>The version ID for the object whose retention settings you want to retrieve.</p>
     pub version_id: Option<String>,
 }
@@ -5347,7 +7062,7 @@ pub struct GetObjectTaggingRequest {
 pub struct GetObjectTorrentOutput {
     /// <p>A Bencoded dictionary as defined by the BitTorrent specification</p>
     pub body: Option<StreamingBody>,
-    pub request_charged: Option<String>,
+    pub request_charged: Option<RequestCharged>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
@@ -5357,7 +7072,7 @@ pub struct GetObjectTorrentRequest {
     pub bucket: String,
     /// <p>The object key for which to get the information.</p>
     pub key: String,
-    pub request_payer: Option<String>,
+    pub request_payer: Option<RequestPayer>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
@@ -5397,7 +7112,7 @@ pub struct GetPublicAccessBlockRequest {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct GlacierJobParameters {
     /// <p>Glacier retrieval tier at which the restore will be processed.</p>
-    pub tier: String,
+    pub tier: Tier,
 }
 
 pub struct GlacierJobParametersSerializer;
@@ -5415,7 +7130,7 @@ impl GlacierJobParametersSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Tier"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.tier
+            value = &obj.tier
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::end_element())
@@ -5430,7 +7145,7 @@ pub struct Grant {
     /// <p>The person being granted permissions.</p>
     pub grantee: Option<Grantee>,
     /// <p>Specifies the permission given to the grantee.</p>
-    pub permission: Option<String>,
+    pub permission: Option<Permission>,
 }
 
 struct GrantDeserializer;
@@ -5492,7 +7207,7 @@ pub struct Grantee {
     /// <p>The canonical user ID of the grantee.</p>
     pub id: Option<String>,
     /// <p>Type of grantee</p>
-    pub type_: String,
+    pub type_: Type,
     /// <p>URI of the grantee group.</p>
     pub uri: Option<String>,
 }
@@ -5571,7 +7286,7 @@ impl GranteeSerializer {
         writer.write(xml::writer::XmlEvent::start_element("xsi:type"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.type_
+            value = &obj.type_
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         if let Some(ref value) = obj.uri {
@@ -5655,24 +7370,24 @@ pub struct HeadObjectOutput {
     /// <p>If the object expiration is configured (see PUT Bucket lifecycle), the response includes this header. It includes the expiry-date and rule-id key-value pairs providing object expiration information. The value of the rule-id is URL encoded.</p>
     pub expiration: Option<String>,
     /// <p>The date and time at which the object is no longer cacheable.</p>
-    pub expires: Option<String>,
+    pub expires: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Last modified date of the object</p>
-    pub last_modified: Option<String>,
+    pub last_modified: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A map of metadata to store with the object in S3.</p>
     pub metadata: Option<::std::collections::HashMap<String, String>>,
     /// <p>This is set to the number of metadata entries not returned in <code>x-amz-meta</code> headers. This can happen if you create metadata using an API like SOAP that supports more flexible metadata than the REST API. For example, using SOAP, you can create metadata whose values are not legal HTTP headers.</p>
     pub missing_meta: Option<i64>,
     /// <p>Specifies whether a legal hold is in effect for this object. This header is only returned if the requester has the <code>s3:GetObjectLegalHold</code> permission. This header is not returned if the specified version of this object has never had a legal hold applied. For more information about S3 Object Lock, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/object-lock.html">Object Lock</a>.</p>
-    pub object_lock_legal_hold_status: Option<String>,
+    pub object_lock_legal_hold_status: Option<ObjectLockLegalHoldStatus>,
     /// <p>The Object Lock mode, if any, that's in effect for this object. This header is only returned if the requester has the <code>s3:GetObjectRetention</code> permission. For more information about S3 Object Lock, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/object-lock.html">Object Lock</a>. </p>
-    pub object_lock_mode: Option<String>,
+    pub object_lock_mode: Option<ObjectLockMode>,
     /// <p>The date and time when the Object Lock retention period expires. This header is only returned if the requester has the <code>s3:GetObjectRetention</code> permission.</p>
-    pub object_lock_retain_until_date: Option<String>,
+    pub object_lock_retain_until_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The count of parts this object has.</p>
     pub parts_count: Option<i64>,
     /// <p>Amazon S3 can return this header if your request involves a bucket that is either a source or destination in a replication rule.</p> <p>In replication, you have a source bucket on which you configure replication and destination bucket where Amazon S3 stores object replicas. When you request an object (<code>GetObject</code>) or object metadata (<code>HeadObject</code>) from these buckets, Amazon S3 will return the <code>x-amz-replication-status</code> header in the response as follows:</p> <ul> <li> <p>If requesting an object from the source bucket — Amazon S3 will return the <code>x-amz-replication-status</code> header if the object in your request is eligible for replication.</p> <p> For example, suppose that in your replication configuration, you specify object prefix <code>TaxDocs</code> requesting Amazon S3 to replicate objects with key prefix <code>TaxDocs</code>. Any objects you upload with this key name prefix, for example <code>TaxDocs/document1.pdf</code>, are eligible for replication. For any object request with this key name prefix, Amazon S3 will return the <code>x-amz-replication-status</code> header with value PENDING, COMPLETED or FAILED indicating object replication status.</p> </li> <li> <p>If requesting an object from the destination bucket — Amazon S3 will return the <code>x-amz-replication-status</code> header with value REPLICA if the object in your request is a replica that Amazon S3 created.</p> </li> </ul> <p>For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/NotificationHowTo.html">Replication</a>.</p>
-    pub replication_status: Option<String>,
-    pub request_charged: Option<String>,
+    pub replication_status: Option<ReplicationStatus>,
+    pub request_charged: Option<RequestCharged>,
     /// <p>If the object is an archived object (an object whose storage class is GLACIER), the response includes this header if either the archive restoration is in progress (see <a>RestoreObject</a> or an archive copy is already restored.</p> <p> If an archive copy is already restored, the header value indicates when Amazon S3 is scheduled to delete the object copy. For example:</p> <p> <code>x-amz-restore: ongoing-request="false", expiry-date="Fri, 23 Dec 2012 00:00:00 GMT"</code> </p> <p>If the object restoration is in progress, the header returns the value <code>ongoing-request="true"</code>.</p> <p>For more information about archiving objects, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/object-lifecycle-mgmt.html#lifecycle-transition-general-considerations">Transitioning Objects: General Considerations</a>.</p>
     pub restore: Option<String>,
     /// <p>If server-side encryption with a customer-provided encryption key was requested, the response will include this header confirming the encryption algorithm used.</p>
@@ -5682,9 +7397,9 @@ pub struct HeadObjectOutput {
     /// <p>If present, specifies the ID of the AWS Key Management Service (AWS KMS) symmetric customer managed customer master key (CMK) that was used for the object.</p>
     pub ssekms_key_id: Option<String>,
     /// <p>If the object is stored using server-side encryption either with an AWS KMS customer master key (CMK) or an Amazon S3-managed encryption key, the response includes this header with the value of the server-side encryption algorithm used when storing this object in Amazon S3 (for example, AES256, aws:kms).</p>
-    pub server_side_encryption: Option<String>,
+    pub server_side_encryption: Option<ServerSideEncryption>,
     /// <p>Provides storage class information of the object. Amazon S3 returns this header for all objects except for Standard storage class objects.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/storage-class-intro.html">Storage Classes</a>.</p>
-    pub storage_class: Option<String>,
+    pub storage_class: Option<StorageClass>,
     /// <p>Version of the object.</p>
     pub version_id: Option<String>,
     /// <p>If the bucket is configured as a website, redirects requests for this object to another object in the same bucket or to an external URL. Amazon S3 stores the value of this header in the object metadata.</p>
@@ -5715,18 +7430,18 @@ pub struct HeadObjectRequest {
     /// <p>Return the object only if its entity tag (ETag) is the same as the one specified, otherwise return a 412 (precondition failed).</p>
     pub if_match: Option<String>,
     /// <p>Return the object only if it has been modified since the specified time, otherwise return a 304 (not modified).</p>
-    pub if_modified_since: Option<String>,
+    pub if_modified_since: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Return the object only if its entity tag (ETag) is different from the one specified, otherwise return a 304 (not modified).</p>
     pub if_none_match: Option<String>,
     /// <p>Return the object only if it has not been modified since the specified time, otherwise return a 412 (precondition failed).</p>
-    pub if_unmodified_since: Option<String>,
+    pub if_unmodified_since: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The object key.</p>
     pub key: String,
     /// <p>Part number of the object being read. This is a positive integer between 1 and 10,000. Effectively performs a 'ranged' HEAD request for the part specified. Useful querying about the size of the part and the number of parts in this object.</p>
     pub part_number: Option<i64>,
     /// <p>Downloads the specified range bytes of an object. For more information about the HTTP Range header, see <a href="">http://www.w3.org/Protocols/rfc2616/rfc2616-sec14.html#sec14.35</a>.</p>
     pub range: Option<String>,
-    pub request_payer: Option<String>,
+    pub request_payer: Option<RequestPayer>,
     /// <p>Specifies the algorithm to use to when encrypting the object (for example, AES256).</p>
     pub sse_customer_algorithm: Option<String>,
     /// <p>Specifies the customer-provided encryption key for Amazon S3 to use in encrypting data. This value is used to store the object and then it is discarded; Amazon S3 does not store the encryption key. The key must be appropriate for use with the algorithm specified in the <code>x-amz-server-side​-encryption​-customer-algorithm</code> header.</p>
@@ -5908,7 +7623,7 @@ impl IndexDocumentSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Suffix"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.suffix
+            value = &obj.suffix
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::end_element())
@@ -5918,9 +7633,12 @@ impl IndexDocumentSerializer {
 struct InitiatedDeserializer;
 impl InitiatedDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<::chrono::DateTime<::chrono::Utc>, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::proto::xml::util::timestamp_characters(stack)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -5965,7 +7683,7 @@ pub struct InputSerialization {
     /// <p>Describes the serialization of a CSV-encoded object.</p>
     pub csv: Option<CSVInput>,
     /// <p>Specifies object's compression format. Valid values: NONE, GZIP, BZIP2. Default Value: NONE.</p>
-    pub compression_type: Option<String>,
+    pub compression_type: Option<CompressionType>,
     /// <p>Specifies JSON as object's input serialization format.</p>
     pub json: Option<JSONInput>,
     /// <p>Specifies Parquet as object's input serialization format.</p>
@@ -6017,11 +7735,11 @@ pub struct InventoryConfiguration {
     /// <p>The ID used to identify the inventory configuration.</p>
     pub id: String,
     /// <p>Object versions to include in the inventory list. If set to <code>All</code>, the list includes all the object versions, which adds the version-related fields <code>VersionId</code>, <code>IsLatest</code>, and <code>DeleteMarker</code> to the list. If set to <code>Current</code>, the list does not contain these version-related fields.</p>
-    pub included_object_versions: String,
+    pub included_object_versions: InventoryIncludedObjectVersions,
     /// <p>Specifies whether the inventory is enabled or disabled. If set to <code>True</code>, an inventory list is generated. If set to <code>False</code>, no inventory list is generated.</p>
     pub is_enabled: bool,
     /// <p>Contains the optional fields that are included in the inventory results.</p>
-    pub optional_fields: Option<Vec<String>>,
+    pub optional_fields: Option<Vec<InventoryOptionalField>>,
     /// <p>Specifies the schedule for generating inventory results.</p>
     pub schedule: InventorySchedule,
 }
@@ -6089,7 +7807,7 @@ impl InventoryConfigurationSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Id"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.id
+            value = &obj.id
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::start_element(
@@ -6097,13 +7815,13 @@ impl InventoryConfigurationSerializer {
         ))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.included_object_versions
+            value = &obj.included_object_versions
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::start_element("IsEnabled"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.is_enabled
+            value = &obj.is_enabled
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         if let Some(ref value) = obj.optional_fields {
@@ -6294,19 +8012,122 @@ impl InventoryFilterSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Prefix"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.prefix
+            value = &obj.prefix
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::end_element())
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum InventoryFormat {
+    /// `CSV`
+    Csv,
+    /// `ORC`
+    Orc,
+    /// `Parquet`
+    Parquet,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl InventoryFormat {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InventoryFormat::Csv => "CSV",
+            InventoryFormat::Orc => "ORC",
+            InventoryFormat::Parquet => "Parquet",
+            InventoryFormat::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for InventoryFormat {
+    fn default() -> InventoryFormat {
+        InventoryFormat::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for InventoryFormat {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for InventoryFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for InventoryFormat {
+    fn from(value: &'a str) -> InventoryFormat {
+        match value {
+            "CSV" => InventoryFormat::Csv,
+            "ORC" => InventoryFormat::Orc,
+            "Parquet" => InventoryFormat::Parquet,
+            _ => InventoryFormat::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InventoryFormat {
+    fn from(value: String) -> InventoryFormat {
+        match value.as_str() {
+            "CSV" => InventoryFormat::Csv,
+            "ORC" => InventoryFormat::Orc,
+            "Parquet" => InventoryFormat::Parquet,
+            _ => InventoryFormat::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for InventoryFormat {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<InventoryFormat, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for InventoryFormat {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for InventoryFormat {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for InventoryFormat {
+    fn deserialize<D>(deserializer: D) -> Result<InventoryFormat, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct InventoryFormatDeserializer;
 impl InventoryFormatDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<InventoryFormat, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -6319,7 +8140,7 @@ impl InventoryFormatSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &InventoryFormat,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -6333,12 +8154,110 @@ impl InventoryFormatSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum InventoryFrequency {
+    /// `Daily`
+    Daily,
+    /// `Weekly`
+    Weekly,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl InventoryFrequency {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InventoryFrequency::Daily => "Daily",
+            InventoryFrequency::Weekly => "Weekly",
+            InventoryFrequency::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for InventoryFrequency {
+    fn default() -> InventoryFrequency {
+        InventoryFrequency::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for InventoryFrequency {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for InventoryFrequency {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for InventoryFrequency {
+    fn from(value: &'a str) -> InventoryFrequency {
+        match value {
+            "Daily" => InventoryFrequency::Daily,
+            "Weekly" => InventoryFrequency::Weekly,
+            _ => InventoryFrequency::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InventoryFrequency {
+    fn from(value: String) -> InventoryFrequency {
+        match value.as_str() {
+            "Daily" => InventoryFrequency::Daily,
+            "Weekly" => InventoryFrequency::Weekly,
+            _ => InventoryFrequency::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for InventoryFrequency {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<InventoryFrequency, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for InventoryFrequency {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for InventoryFrequency {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for InventoryFrequency {
+    fn deserialize<D>(deserializer: D) -> Result<InventoryFrequency, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct InventoryFrequencyDeserializer;
 impl InventoryFrequencyDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<InventoryFrequency, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -6351,7 +8270,7 @@ impl InventoryFrequencySerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &InventoryFrequency,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -6397,12 +8316,110 @@ impl InventoryIdSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum InventoryIncludedObjectVersions {
+    /// `All`
+    All,
+    /// `Current`
+    Current,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl InventoryIncludedObjectVersions {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InventoryIncludedObjectVersions::All => "All",
+            InventoryIncludedObjectVersions::Current => "Current",
+            InventoryIncludedObjectVersions::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for InventoryIncludedObjectVersions {
+    fn default() -> InventoryIncludedObjectVersions {
+        InventoryIncludedObjectVersions::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for InventoryIncludedObjectVersions {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for InventoryIncludedObjectVersions {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for InventoryIncludedObjectVersions {
+    fn from(value: &'a str) -> InventoryIncludedObjectVersions {
+        match value {
+            "All" => InventoryIncludedObjectVersions::All,
+            "Current" => InventoryIncludedObjectVersions::Current,
+            _ => InventoryIncludedObjectVersions::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InventoryIncludedObjectVersions {
+    fn from(value: String) -> InventoryIncludedObjectVersions {
+        match value.as_str() {
+            "All" => InventoryIncludedObjectVersions::All,
+            "Current" => InventoryIncludedObjectVersions::Current,
+            _ => InventoryIncludedObjectVersions::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for InventoryIncludedObjectVersions {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<InventoryIncludedObjectVersions, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for InventoryIncludedObjectVersions {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for InventoryIncludedObjectVersions {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for InventoryIncludedObjectVersions {
+    fn deserialize<D>(deserializer: D) -> Result<InventoryIncludedObjectVersions, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct InventoryIncludedObjectVersionsDeserializer;
 impl InventoryIncludedObjectVersionsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<InventoryIncludedObjectVersions, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -6415,7 +8432,7 @@ impl InventoryIncludedObjectVersionsSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &InventoryIncludedObjectVersions,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -6429,12 +8446,175 @@ impl InventoryIncludedObjectVersionsSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum InventoryOptionalField {
+    /// `Size`
+    Size,
+    /// `LastModifiedDate`
+    LastModifiedDate,
+    /// `StorageClass`
+    StorageClass,
+    /// `ETag`
+    ETag,
+    /// `IsMultipartUploaded`
+    IsMultipartUploaded,
+    /// `ReplicationStatus`
+    ReplicationStatus,
+    /// `EncryptionStatus`
+    EncryptionStatus,
+    /// `ObjectLockRetainUntilDate`
+    ObjectLockRetainUntilDate,
+    /// `ObjectLockMode`
+    ObjectLockMode,
+    /// `ObjectLockLegalHoldStatus`
+    ObjectLockLegalHoldStatus,
+    /// `IntelligentTieringAccessTier`
+    IntelligentTieringAccessTier,
+    /// `BucketKeyStatus`
+    BucketKeyStatus,
+    /// `ChecksumAlgorithm`
+    ChecksumAlgorithm,
+    /// `ObjectAccessControlList`
+    ObjectAccessControlList,
+    /// `ObjectOwner`
+    ObjectOwner,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl InventoryOptionalField {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InventoryOptionalField::Size => "Size",
+            InventoryOptionalField::LastModifiedDate => "LastModifiedDate",
+            InventoryOptionalField::StorageClass => "StorageClass",
+            InventoryOptionalField::ETag => "ETag",
+            InventoryOptionalField::IsMultipartUploaded => "IsMultipartUploaded",
+            InventoryOptionalField::ReplicationStatus => "ReplicationStatus",
+            InventoryOptionalField::EncryptionStatus => "EncryptionStatus",
+            InventoryOptionalField::ObjectLockRetainUntilDate => "ObjectLockRetainUntilDate",
+            InventoryOptionalField::ObjectLockMode => "ObjectLockMode",
+            InventoryOptionalField::ObjectLockLegalHoldStatus => "ObjectLockLegalHoldStatus",
+            InventoryOptionalField::IntelligentTieringAccessTier => "IntelligentTieringAccessTier",
+            InventoryOptionalField::BucketKeyStatus => "BucketKeyStatus",
+            InventoryOptionalField::ChecksumAlgorithm => "ChecksumAlgorithm",
+            InventoryOptionalField::ObjectAccessControlList => "ObjectAccessControlList",
+            InventoryOptionalField::ObjectOwner => "ObjectOwner",
+            InventoryOptionalField::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for InventoryOptionalField {
+    fn default() -> InventoryOptionalField {
+        InventoryOptionalField::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for InventoryOptionalField {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for InventoryOptionalField {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for InventoryOptionalField {
+    fn from(value: &'a str) -> InventoryOptionalField {
+        match value {
+            "Size" => InventoryOptionalField::Size,
+            "LastModifiedDate" => InventoryOptionalField::LastModifiedDate,
+            "StorageClass" => InventoryOptionalField::StorageClass,
+            "ETag" => InventoryOptionalField::ETag,
+            "IsMultipartUploaded" => InventoryOptionalField::IsMultipartUploaded,
+            "ReplicationStatus" => InventoryOptionalField::ReplicationStatus,
+            "EncryptionStatus" => InventoryOptionalField::EncryptionStatus,
+            "ObjectLockRetainUntilDate" => InventoryOptionalField::ObjectLockRetainUntilDate,
+            "ObjectLockMode" => InventoryOptionalField::ObjectLockMode,
+            "ObjectLockLegalHoldStatus" => InventoryOptionalField::ObjectLockLegalHoldStatus,
+            "IntelligentTieringAccessTier" => InventoryOptionalField::IntelligentTieringAccessTier,
+            "BucketKeyStatus" => InventoryOptionalField::BucketKeyStatus,
+            "ChecksumAlgorithm" => InventoryOptionalField::ChecksumAlgorithm,
+            "ObjectAccessControlList" => InventoryOptionalField::ObjectAccessControlList,
+            "ObjectOwner" => InventoryOptionalField::ObjectOwner,
+            _ => InventoryOptionalField::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InventoryOptionalField {
+    fn from(value: String) -> InventoryOptionalField {
+        match value.as_str() {
+            "Size" => InventoryOptionalField::Size,
+            "LastModifiedDate" => InventoryOptionalField::LastModifiedDate,
+            "StorageClass" => InventoryOptionalField::StorageClass,
+            "ETag" => InventoryOptionalField::ETag,
+            "IsMultipartUploaded" => InventoryOptionalField::IsMultipartUploaded,
+            "ReplicationStatus" => InventoryOptionalField::ReplicationStatus,
+            "EncryptionStatus" => InventoryOptionalField::EncryptionStatus,
+            "ObjectLockRetainUntilDate" => InventoryOptionalField::ObjectLockRetainUntilDate,
+            "ObjectLockMode" => InventoryOptionalField::ObjectLockMode,
+            "ObjectLockLegalHoldStatus" => InventoryOptionalField::ObjectLockLegalHoldStatus,
+            "IntelligentTieringAccessTier" => InventoryOptionalField::IntelligentTieringAccessTier,
+            "BucketKeyStatus" => InventoryOptionalField::BucketKeyStatus,
+            "ChecksumAlgorithm" => InventoryOptionalField::ChecksumAlgorithm,
+            "ObjectAccessControlList" => InventoryOptionalField::ObjectAccessControlList,
+            "ObjectOwner" => InventoryOptionalField::ObjectOwner,
+            _ => InventoryOptionalField::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for InventoryOptionalField {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<InventoryOptionalField, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for InventoryOptionalField {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for InventoryOptionalField {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for InventoryOptionalField {
+    fn deserialize<D>(deserializer: D) -> Result<InventoryOptionalField, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct InventoryOptionalFieldDeserializer;
 impl InventoryOptionalFieldDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<InventoryOptionalField, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -6447,7 +8627,7 @@ impl InventoryOptionalFieldSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &InventoryOptionalField,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -6467,7 +8647,7 @@ impl InventoryOptionalFieldsDeserializer {
     fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
-    ) -> Result<Vec<String>, XmlParseError> {
+    ) -> Result<Vec<InventoryOptionalField>, XmlParseError> {
         deserialize_elements::<_, Vec<_>, _>(tag_name, stack, |name, stack, obj| {
             if name == "Field" {
                 obj.push(InventoryOptionalFieldDeserializer::deserialize(
@@ -6487,7 +8667,7 @@ impl InventoryOptionalFieldsSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &Vec<String>,
+        obj: &Vec<InventoryOptionalField>,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -6513,7 +8693,7 @@ pub struct InventoryS3BucketDestination {
     /// <p>Contains the type of server-side encryption used to encrypt the inventory results.</p>
     pub encryption: Option<InventoryEncryption>,
     /// <p>Specifies the output format of the inventory results.</p>
-    pub format: String,
+    pub format: InventoryFormat,
     /// <p>The prefix that is prepended to all inventory results.</p>
     pub prefix: Option<String>,
 }
@@ -6580,7 +8760,7 @@ impl InventoryS3BucketDestinationSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Bucket"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.bucket
+            value = &obj.bucket
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         if let Some(ref value) = obj.encryption {
@@ -6589,7 +8769,7 @@ impl InventoryS3BucketDestinationSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Format"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.format
+            value = &obj.format
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         if let Some(ref value) = obj.prefix {
@@ -6610,7 +8790,7 @@ impl InventoryS3BucketDestinationSerializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct InventorySchedule {
     /// <p>Specifies how frequently inventory results are produced.</p>
-    pub frequency: String,
+    pub frequency: InventoryFrequency,
 }
 
 struct InventoryScheduleDeserializer;
@@ -6648,7 +8828,7 @@ impl InventoryScheduleSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Frequency"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.frequency
+            value = &obj.frequency
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::end_element())
@@ -6725,7 +8905,7 @@ impl IsTruncatedDeserializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct JSONInput {
     /// <p>The type of JSON. Valid values: Document, Lines.</p>
-    pub type_: Option<String>,
+    pub type_: Option<JSONType>,
 }
 
 pub struct JSONInputSerializer;
@@ -6784,15 +8964,111 @@ impl JSONOutputSerializer {
     }
 }
 
-pub struct JSONTypeSerializer;
-impl JSONTypeSerializer {
-    #[allow(unused_variables, warnings)]
-    pub fn serialize<W>(
-        mut writer: &mut EventWriter<W>,
-        name: &str,
-        obj: &String,
-    ) -> Result<(), xml::writer::Error>
-    where
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum JSONType {
+    /// `DOCUMENT`
+    Document,
+    /// `LINES`
+    Lines,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl JSONType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            JSONType::Document => "DOCUMENT",
+            JSONType::Lines => "LINES",
+            JSONType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for JSONType {
+    fn default() -> JSONType {
+        JSONType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for JSONType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for JSONType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for JSONType {
+    fn from(value: &'a str) -> JSONType {
+        match value {
+            "DOCUMENT" => JSONType::Document,
+            "LINES" => JSONType::Lines,
+            _ => JSONType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for JSONType {
+    fn from(value: String) -> JSONType {
+        match value.as_str() {
+            "DOCUMENT" => JSONType::Document,
+            "LINES" => JSONType::Lines,
+            _ => JSONType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for JSONType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<JSONType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for JSONType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for JSONType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for JSONType {
+    fn deserialize<D>(deserializer: D) -> Result<JSONType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+
+pub struct JSONTypeSerializer;
+impl JSONTypeSerializer {
+    #[allow(unused_variables, warnings)]
+    pub fn serialize<W>(
+        mut writer: &mut EventWriter<W>,
+        name: &str,
+        obj: &JSONType,
+    ) -> Result<(), xml::writer::Error>
+    where
         W: Write,
     {
         writer.write(xml::writer::XmlEvent::start_element(name))?;
@@ -6937,7 +9213,7 @@ impl LambdaFunctionArnSerializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct LambdaFunctionConfiguration {
     /// <p>The Amazon S3 bucket event for which to invoke the AWS Lambda function. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/NotificationHowTo.html">Supported Event Types</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p>
-    pub events: Vec<String>,
+    pub events: Vec<Event>,
     pub filter: Option<NotificationConfigurationFilter>,
     pub id: Option<String>,
     /// <p>The Amazon Resource Name (ARN) of the AWS Lambda function that Amazon S3 invokes when the specified event type occurs.</p>
@@ -7008,7 +9284,7 @@ impl LambdaFunctionConfigurationSerializer {
         writer.write(xml::writer::XmlEvent::start_element("CloudFunction"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.lambda_function_arn
+            value = &obj.lambda_function_arn
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::end_element())
@@ -7066,9 +9342,12 @@ impl LambdaFunctionConfigurationListSerializer {
 struct LastModifiedDeserializer;
 impl LastModifiedDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<::chrono::DateTime<::chrono::Utc>, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::proto::xml::util::timestamp_characters(stack)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -7105,7 +9384,7 @@ impl LifecycleConfigurationSerializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct LifecycleExpiration {
     /// <p>Indicates at what date the object is to be moved or deleted. Should be in GMT ISO 8601 Format.</p>
-    pub date: Option<String>,
+    pub date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Indicates the lifetime, in days, of the objects that are subject to the rule. The value must be a non-zero positive integer.</p>
     pub days: Option<i64>,
     /// <p>Indicates whether Amazon S3 will remove a delete marker with no noncurrent versions. If set to true, the delete marker will be expired; if set to false the policy takes no action. This cannot be specified with Days or Date in a Lifecycle Expiration Policy.</p>
@@ -7157,7 +9436,7 @@ impl LifecycleExpirationSerializer {
             writer.write(xml::writer::XmlEvent::start_element("Date"))?;
             writer.write(xml::writer::XmlEvent::characters(&format!(
                 "{value}",
-                value = value
+                value = rusoto_core::serialization::TimestampFormat::Iso8601.format(value)
             )));
             writer.write(xml::writer::XmlEvent::end_element())?;
         }
@@ -7198,7 +9477,7 @@ pub struct LifecycleRule {
     /// <p> Specifies the transition rule for the lifecycle rule that describes when noncurrent objects transition to a specific storage class. If your bucket is versioning-enabled (or versioning is suspended), you can set this action to request that Amazon S3 transition noncurrent object versions to a specific storage class at a set period in the object's lifetime. </p>
     pub noncurrent_version_transitions: Option<Vec<NoncurrentVersionTransition>>,
     /// <p>If 'Enabled', the rule is currently being applied. If 'Disabled', the rule is not currently being applied.</p>
-    pub status: String,
+    pub status: ExpirationStatus,
     /// <p>Specifies when an Amazon S3 object transitions to a specified storage class.</p>
     pub transitions: Option<Vec<Transition>>,
 }
@@ -7313,7 +9592,7 @@ impl LifecycleRuleSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Status"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.status
+            value = &obj.status
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         if let Some(ref value) = obj.transitions {
@@ -7735,7 +10014,7 @@ pub struct ListMultipartUploadsOutput {
     /// <p>Contains the delimiter you specified in the request. If you don't specify a delimiter in your request, this element is absent from the response.</p>
     pub delimiter: Option<String>,
     /// <p>Encoding type used by Amazon S3 to encode object keys in the response.</p> <p>If you specify <code>encoding-type</code> request parameter, Amazon S3 includes this element in the response, and returns encoded key name values in the following response elements:</p> <p> <code>Delimiter</code>, <code>KeyMarker</code>, <code>Prefix</code>, <code>NextKeyMarker</code>, <code>Key</code>.</p>
-    pub encoding_type: Option<String>,
+    pub encoding_type: Option<EncodingType>,
     /// <p>Indicates whether the returned list of multipart uploads is truncated. A value of true indicates that the list was truncated. The list can be truncated if the number of multipart uploads exceeds the limit allowed or specified by max uploads.</p>
     pub is_truncated: Option<bool>,
     /// <p>The key at or after which the listing began.</p>
@@ -7837,7 +10116,7 @@ pub struct ListMultipartUploadsRequest {
     pub bucket: String,
     /// <p>Character you use to group keys.</p> <p>All keys that contain the same string between the prefix, if specified, and the first occurrence of the delimiter after the prefix are grouped under a single result element, <code>CommonPrefixes</code>. If you don't specify the prefix parameter, then the substring starts at the beginning of the key. The keys that are grouped under <code>CommonPrefixes</code> result element are not returned elsewhere in the response.</p>
     pub delimiter: Option<String>,
-    pub encoding_type: Option<String>,
+    pub encoding_type: Option<EncodingType>,
     /// <p>Together with upload-id-marker, this parameter specifies the multipart upload after which listing should begin.</p> <p>If <code>upload-id-marker</code> is not specified, only the keys lexicographically greater than the specified <code>key-marker</code> will be included in the list.</p> <p>If <code>upload-id-marker</code> is specified, any multipart uploads for a key equal to the <code>key-marker</code> might also be included, provided those multipart uploads have upload IDs lexicographically greater than the specified <code>upload-id-marker</code>.</p>
     pub key_marker: Option<String>,
     /// <p>Sets the maximum number of multipart uploads, from 1 to 1,000, to return in the response body. 1,000 is the maximum number of uploads that can be returned in a response.</p>
@@ -7858,7 +10137,7 @@ pub struct ListObjectVersionsOutput {
     /// <p>The delimiter grouping the included keys. A delimiter is a character that you specify to group keys. All keys that contain the same string between the prefix and the first occurrence of the delimiter are grouped under a single result element in <code>CommonPrefixes</code>. These groups are counted as one result against the max-keys limitation. These keys are not returned elsewhere in the response.</p>
     pub delimiter: Option<String>,
     /// <p> Encoding type used by Amazon S3 to encode object key names in the XML response.</p> <p>If you specify encoding-type request parameter, Amazon S3 includes this element in the response, and returns encoded key name values in the following response elements:</p> <p> <code>KeyMarker, NextKeyMarker, Prefix, Key</code>, and <code>Delimiter</code>.</p>
-    pub encoding_type: Option<String>,
+    pub encoding_type: Option<EncodingType>,
     /// <p>A flag that indicates whether Amazon S3 returned all of the results that satisfied the search criteria. If your results were truncated, you can make a follow-up paginated request using the NextKeyMarker and NextVersionIdMarker response parameters as a starting place in another request to return the rest of the results.</p>
     pub is_truncated: Option<bool>,
     /// <p>Marks the last key returned in a truncated response.</p>
@@ -7966,7 +10245,7 @@ pub struct ListObjectVersionsRequest {
     pub bucket: String,
     /// <p>A delimiter is a character that you specify to group keys. All keys that contain the same string between the <code>prefix</code> and the first occurrence of the delimiter are grouped under a single result element in CommonPrefixes. These groups are counted as one result against the max-keys limitation. These keys are not returned elsewhere in the response.</p>
     pub delimiter: Option<String>,
-    pub encoding_type: Option<String>,
+    pub encoding_type: Option<EncodingType>,
     /// <p>Specifies the key to start with when listing objects in a bucket.</p>
     pub key_marker: Option<String>,
     /// <p>Sets the maximum number of keys returned in the response. The response might contain fewer keys but will never contain more. If additional keys satisfy the search criteria, but were not returned because max-keys was exceeded, the response contains &lt;isTruncated&gt;true&lt;/isTruncated&gt;. To return the additional keys, see key-marker and version-id-marker.</p>
@@ -7987,7 +10266,7 @@ pub struct ListObjectsOutput {
     /// <p>Causes keys that contain the same string between the prefix and the first occurrence of the delimiter to be rolled up into a single result element in the <code>CommonPrefixes</code> collection. These rolled-up keys are not returned elsewhere in the response. Each rolled-up result counts as only one return against the <code>MaxKeys</code> value.</p>
     pub delimiter: Option<String>,
     /// <p>Encoding type used by Amazon S3 to encode object keys in the response.</p>
-    pub encoding_type: Option<String>,
+    pub encoding_type: Option<EncodingType>,
     /// <p>A flag that indicates whether Amazon S3 returned all of the results that satisfied the search criteria.</p>
     pub is_truncated: Option<bool>,
     /// <p>Indicates where in the bucket listing begins. Marker is included in the response if it was sent with the request.</p>
@@ -8063,7 +10342,7 @@ pub struct ListObjectsRequest {
     pub bucket: String,
     /// <p>A delimiter is a character you use to group keys.</p>
     pub delimiter: Option<String>,
-    pub encoding_type: Option<String>,
+    pub encoding_type: Option<EncodingType>,
     /// <p>Specifies the key to start with when listing objects in a bucket.</p>
     pub marker: Option<String>,
     /// <p>Sets the maximum number of keys returned in the response. The response might contain fewer keys but will never contain more.</p>
@@ -8071,7 +10350,7 @@ pub struct ListObjectsRequest {
     /// <p>Limits the response to keys that begin with the specified prefix.</p>
     pub prefix: Option<String>,
     /// <p>Confirms that the requester knows that she or he will be charged for the list objects request. Bucket owners need not specify this parameter in their requests.</p>
-    pub request_payer: Option<String>,
+    pub request_payer: Option<RequestPayer>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
@@ -8086,7 +10365,7 @@ pub struct ListObjectsV2Output {
     /// <p>Causes keys that contain the same string between the prefix and the first occurrence of the delimiter to be rolled up into a single result element in the CommonPrefixes collection. These rolled-up keys are not returned elsewhere in the response. Each rolled-up result counts as only one return against the <code>MaxKeys</code> value.</p>
     pub delimiter: Option<String>,
     /// <p>Encoding type used by Amazon S3 to encode object key names in the XML response.</p> <p>If you specify the encoding-type request parameter, Amazon S3 includes this element in the response, and returns encoded key name values in the following response elements:</p> <p> <code>Delimiter, Prefix, Key,</code> and <code>StartAfter</code>.</p>
-    pub encoding_type: Option<String>,
+    pub encoding_type: Option<EncodingType>,
     /// <p>Set to false if all of the results were returned. Set to true if more keys are available to return. If the number of results exceeds that specified by MaxKeys, all of the results might not be returned.</p>
     pub is_truncated: Option<bool>,
     /// <p>KeyCount is the number of keys returned with this request. KeyCount will always be less than equals to MaxKeys field. Say you ask for 50 keys, your result will include less than equals 50 keys </p>
@@ -8177,7 +10456,7 @@ pub struct ListObjectsV2Request {
     /// <p>A delimiter is a character you use to group keys.</p>
     pub delimiter: Option<String>,
     /// <p>Encoding type used by Amazon S3 to encode object keys in the response.</p>
-    pub encoding_type: Option<String>,
+    pub encoding_type: Option<EncodingType>,
     /// <p>The owner field is not present in listV2 by default, if you want to return owner field with each key in the result then set the fetch owner field to true.</p>
     pub fetch_owner: Option<bool>,
     /// <p>Sets the maximum number of keys returned in the response. The response might contain fewer keys but will never contain more.</p>
@@ -8185,7 +10464,7 @@ pub struct ListObjectsV2Request {
     /// <p>Limits the response to keys that begin with the specified prefix.</p>
     pub prefix: Option<String>,
     /// <p>Confirms that the requester knows that she or he will be charged for the list objects request in V2 style. Bucket owners need not specify this parameter in their requests.</p>
-    pub request_payer: Option<String>,
+    pub request_payer: Option<RequestPayer>,
     /// <p>StartAfter is where you want Amazon S3 to start listing from. Amazon S3 starts listing after this specified key. StartAfter can be any key in the bucket.</p>
     pub start_after: Option<String>,
 }
@@ -8194,7 +10473,7 @@ pub struct ListObjectsV2Request {
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct ListPartsOutput {
     /// <p>If the bucket has a lifecycle rule configured with an action to abort incomplete multipart uploads and the prefix in the lifecycle rule matches the object name in the request, then the response includes this header indicating when the initiated multipart upload will become eligible for abort operation. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/mpuoverview.html#mpu-abort-incomplete-mpu-lifecycle-config">Aborting Incomplete Multipart Uploads Using a Bucket Lifecycle Policy</a>.</p> <p>The response will also include the <code>x-amz-abort-rule-id</code> header that will provide the ID of the lifecycle configuration rule that defines this action.</p>
-    pub abort_date: Option<String>,
+    pub abort_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>This header is returned along with the <code>x-amz-abort-date</code> header. It identifies applicable lifecycle configuration rule that defines the action to abort incomplete multipart uploads.</p>
     pub abort_rule_id: Option<String>,
     /// <p>Name of the bucket to which the multipart upload was initiated.</p>
@@ -8215,9 +10494,9 @@ pub struct ListPartsOutput {
     pub part_number_marker: Option<i64>,
     /// <p> Container for elements related to a particular part. A response can contain zero or more <code>Part</code> elements.</p>
     pub parts: Option<Vec<Part>>,
-    pub request_charged: Option<String>,
+    pub request_charged: Option<RequestCharged>,
     /// <p>Class of storage (STANDARD or REDUCED_REDUNDANCY) used to store the uploaded object.</p>
-    pub storage_class: Option<String>,
+    pub storage_class: Option<StorageClass>,
     /// <p>Upload ID identifying the multipart upload whose parts are being listed.</p>
     pub upload_id: Option<String>,
 }
@@ -8296,7 +10575,7 @@ pub struct ListPartsRequest {
     pub max_parts: Option<i64>,
     /// <p>Specifies the part after which listing should begin. Only parts with higher part numbers will be listed.</p>
     pub part_number_marker: Option<i64>,
-    pub request_payer: Option<String>,
+    pub request_payer: Option<RequestPayer>,
     /// <p>Upload ID identifying the multipart upload whose parts are being listed.</p>
     pub upload_id: String,
 }
@@ -8390,7 +10669,7 @@ impl LoggingEnabledSerializer {
         writer.write(xml::writer::XmlEvent::start_element("TargetBucket"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.target_bucket
+            value = &obj.target_bucket
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         if let Some(ref value) = obj.target_grants {
@@ -8399,20 +10678,116 @@ impl LoggingEnabledSerializer {
         writer.write(xml::writer::XmlEvent::start_element("TargetPrefix"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.target_prefix
+            value = &obj.target_prefix
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::end_element())
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MFADelete {
+    /// `Enabled`
+    Enabled,
+    /// `Disabled`
+    Disabled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl MFADelete {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MFADelete::Enabled => "Enabled",
+            MFADelete::Disabled => "Disabled",
+            MFADelete::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for MFADelete {
+    fn default() -> MFADelete {
+        MFADelete::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for MFADelete {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for MFADelete {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for MFADelete {
+    fn from(value: &'a str) -> MFADelete {
+        match value {
+            "Enabled" => MFADelete::Enabled,
+            "Disabled" => MFADelete::Disabled,
+            _ => MFADelete::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MFADelete {
+    fn from(value: String) -> MFADelete {
+        match value.as_str() {
+            "Enabled" => MFADelete::Enabled,
+            "Disabled" => MFADelete::Disabled,
+            _ => MFADelete::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for MFADelete {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<MFADelete, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MFADelete {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for MFADelete {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for MFADelete {
+    fn deserialize<D>(deserializer: D) -> Result<MFADelete, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+
 pub struct MFADeleteSerializer;
 impl MFADeleteSerializer {
     #[allow(unused_variables, warnings)]
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &MFADelete,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -8426,12 +10801,110 @@ impl MFADeleteSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MFADeleteStatus {
+    /// `Enabled`
+    Enabled,
+    /// `Disabled`
+    Disabled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl MFADeleteStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MFADeleteStatus::Enabled => "Enabled",
+            MFADeleteStatus::Disabled => "Disabled",
+            MFADeleteStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for MFADeleteStatus {
+    fn default() -> MFADeleteStatus {
+        MFADeleteStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for MFADeleteStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for MFADeleteStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for MFADeleteStatus {
+    fn from(value: &'a str) -> MFADeleteStatus {
+        match value {
+            "Enabled" => MFADeleteStatus::Enabled,
+            "Disabled" => MFADeleteStatus::Disabled,
+            _ => MFADeleteStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MFADeleteStatus {
+    fn from(value: String) -> MFADeleteStatus {
+        match value.as_str() {
+            "Enabled" => MFADeleteStatus::Enabled,
+            "Disabled" => MFADeleteStatus::Disabled,
+            _ => MFADeleteStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for MFADeleteStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<MFADeleteStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MFADeleteStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for MFADeleteStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for MFADeleteStatus {
+    fn deserialize<D>(deserializer: D) -> Result<MFADeleteStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct MFADeleteStatusDeserializer;
 impl MFADeleteStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<MFADeleteStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -8608,6 +11081,101 @@ impl MessageDeserializer {
         Ok(obj)
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MetadataDirective {
+    /// `COPY`
+    Copy,
+    /// `REPLACE`
+    Replace,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl MetadataDirective {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MetadataDirective::Copy => "COPY",
+            MetadataDirective::Replace => "REPLACE",
+            MetadataDirective::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for MetadataDirective {
+    fn default() -> MetadataDirective {
+        MetadataDirective::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for MetadataDirective {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for MetadataDirective {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for MetadataDirective {
+    fn from(value: &'a str) -> MetadataDirective {
+        match value {
+            "COPY" => MetadataDirective::Copy,
+            "REPLACE" => MetadataDirective::Replace,
+            _ => MetadataDirective::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MetadataDirective {
+    fn from(value: String) -> MetadataDirective {
+        match value.as_str() {
+            "COPY" => MetadataDirective::Copy,
+            "REPLACE" => MetadataDirective::Replace,
+            _ => MetadataDirective::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for MetadataDirective {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<MetadataDirective, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MetadataDirective {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for MetadataDirective {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for MetadataDirective {
+    fn deserialize<D>(deserializer: D) -> Result<MetadataDirective, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A metadata key-value pair to store with an object.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -8698,7 +11266,7 @@ pub struct Metrics {
     /// <p> A container specifying the time threshold for emitting the <code>s3:Replication:OperationMissedThreshold</code> event. </p>
     pub event_threshold: ReplicationTimeValue,
     /// <p> Specifies whether the replication metrics are enabled. </p>
-    pub status: String,
+    pub status: MetricsStatus,
 }
 
 struct MetricsDeserializer;
@@ -8744,7 +11312,7 @@ impl MetricsSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Status"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.status
+            value = &obj.status
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::end_element())
@@ -8864,7 +11432,7 @@ impl MetricsConfigurationSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Id"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.id
+            value = &obj.id
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::end_element())
@@ -9000,12 +11568,110 @@ impl MetricsIdSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MetricsStatus {
+    /// `Enabled`
+    Enabled,
+    /// `Disabled`
+    Disabled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl MetricsStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MetricsStatus::Enabled => "Enabled",
+            MetricsStatus::Disabled => "Disabled",
+            MetricsStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for MetricsStatus {
+    fn default() -> MetricsStatus {
+        MetricsStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for MetricsStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for MetricsStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for MetricsStatus {
+    fn from(value: &'a str) -> MetricsStatus {
+        match value {
+            "Enabled" => MetricsStatus::Enabled,
+            "Disabled" => MetricsStatus::Disabled,
+            _ => MetricsStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MetricsStatus {
+    fn from(value: String) -> MetricsStatus {
+        match value.as_str() {
+            "Enabled" => MetricsStatus::Enabled,
+            "Disabled" => MetricsStatus::Disabled,
+            _ => MetricsStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for MetricsStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<MetricsStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MetricsStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for MetricsStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for MetricsStatus {
+    fn deserialize<D>(deserializer: D) -> Result<MetricsStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct MetricsStatusDeserializer;
 impl MetricsStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<MetricsStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -9018,7 +11684,7 @@ impl MetricsStatusSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &MetricsStatus,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -9069,7 +11735,7 @@ impl MinutesSerializer {
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct MultipartUpload {
     /// <p>Date and time at which the multipart upload was initiated.</p>
-    pub initiated: Option<String>,
+    pub initiated: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Identifies who initiated the multipart upload.</p>
     pub initiator: Option<Initiator>,
     /// <p>Key of the object for which the multipart upload was initiated.</p>
@@ -9077,7 +11743,7 @@ pub struct MultipartUpload {
     /// <p>Specifies the owner of the object that is part of the multipart upload. </p>
     pub owner: Option<Owner>,
     /// <p>The class of storage used to store the object.</p>
-    pub storage_class: Option<String>,
+    pub storage_class: Option<StorageClass>,
     /// <p>Upload ID that identifies the multipart upload.</p>
     pub upload_id: Option<String>,
 }
@@ -9310,7 +11976,7 @@ pub struct NoncurrentVersionTransition {
     /// <p>Specifies the number of days an object is noncurrent before Amazon S3 can perform the associated action. For information about the noncurrent days calculations, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/intro-lifecycle-rules.html#non-current-days-calculations">How Amazon S3 Calculates How Long an Object Has Been Noncurrent</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p>
     pub noncurrent_days: Option<i64>,
     /// <p>The class of storage used to store the object.</p>
-    pub storage_class: Option<String>,
+    pub storage_class: Option<TransitionStorageClass>,
 }
 
 struct NoncurrentVersionTransitionDeserializer;
@@ -9690,13 +12356,13 @@ pub struct Object {
     /// <p>The name that you assign to an object. You use the object key to retrieve the object.</p>
     pub key: Option<String>,
     /// <p>The date the Object was Last Modified</p>
-    pub last_modified: Option<String>,
+    pub last_modified: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The owner of the object</p>
     pub owner: Option<Owner>,
     /// <p>Size in bytes of the object</p>
     pub size: Option<i64>,
     /// <p>The class of storage used to store the object.</p>
-    pub storage_class: Option<String>,
+    pub storage_class: Option<ObjectStorageClass>,
 }
 
 struct ObjectDeserializer;
@@ -9735,24 +12401,144 @@ impl ObjectDeserializer {
         })
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ObjectCannedACL {
+    /// `private`
+    Private,
+    /// `public-read`
+    PublicRead,
+    /// `public-read-write`
+    PublicReadWrite,
+    /// `authenticated-read`
+    AuthenticatedRead,
+    /// `aws-exec-read`
+    AwsExecRead,
+    /// `bucket-owner-read`
+    BucketOwnerRead,
+    /// `bucket-owner-full-control`
+    BucketOwnerFullControl,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ObjectCannedACL {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ObjectCannedACL::Private => "private",
+            ObjectCannedACL::PublicRead => "public-read",
+            ObjectCannedACL::PublicReadWrite => "public-read-write",
+            ObjectCannedACL::AuthenticatedRead => "authenticated-read",
+            ObjectCannedACL::AwsExecRead => "aws-exec-read",
+            ObjectCannedACL::BucketOwnerRead => "bucket-owner-read",
+            ObjectCannedACL::BucketOwnerFullControl => "bucket-owner-full-control",
+            ObjectCannedACL::Unknown(ref value) => value,
+        }
+    }
+}
 
-pub struct ObjectCannedACLSerializer;
-impl ObjectCannedACLSerializer {
-    #[allow(unused_variables, warnings)]
-    pub fn serialize<W>(
-        mut writer: &mut EventWriter<W>,
-        name: &str,
-        obj: &String,
-    ) -> Result<(), xml::writer::Error>
-    where
-        W: Write,
-    {
-        writer.write(xml::writer::XmlEvent::start_element(name))?;
-        writer.write(xml::writer::XmlEvent::characters(&format!(
-            "{value}",
-            value = obj.to_string()
-        )))?;
-        writer.write(xml::writer::XmlEvent::end_element())
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ObjectCannedACL {
+    fn default() -> ObjectCannedACL {
+        ObjectCannedACL::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ObjectCannedACL {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ObjectCannedACL {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ObjectCannedACL {
+    fn from(value: &'a str) -> ObjectCannedACL {
+        match value {
+            "private" => ObjectCannedACL::Private,
+            "public-read" => ObjectCannedACL::PublicRead,
+            "public-read-write" => ObjectCannedACL::PublicReadWrite,
+            "authenticated-read" => ObjectCannedACL::AuthenticatedRead,
+            "aws-exec-read" => ObjectCannedACL::AwsExecRead,
+            "bucket-owner-read" => ObjectCannedACL::BucketOwnerRead,
+            "bucket-owner-full-control" => ObjectCannedACL::BucketOwnerFullControl,
+            _ => ObjectCannedACL::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ObjectCannedACL {
+    fn from(value: String) -> ObjectCannedACL {
+        match value.as_str() {
+            "private" => ObjectCannedACL::Private,
+            "public-read" => ObjectCannedACL::PublicRead,
+            "public-read-write" => ObjectCannedACL::PublicReadWrite,
+            "authenticated-read" => ObjectCannedACL::AuthenticatedRead,
+            "aws-exec-read" => ObjectCannedACL::AwsExecRead,
+            "bucket-owner-read" => ObjectCannedACL::BucketOwnerRead,
+            "bucket-owner-full-control" => ObjectCannedACL::BucketOwnerFullControl,
+            _ => ObjectCannedACL::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ObjectCannedACL {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ObjectCannedACL, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ObjectCannedACL {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ObjectCannedACL {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ObjectCannedACL {
+    fn deserialize<D>(deserializer: D) -> Result<ObjectCannedACL, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+
+pub struct ObjectCannedACLSerializer;
+impl ObjectCannedACLSerializer {
+    #[allow(unused_variables, warnings)]
+    pub fn serialize<W>(
+        mut writer: &mut EventWriter<W>,
+        name: &str,
+        obj: &ObjectCannedACL,
+    ) -> Result<(), xml::writer::Error>
+    where
+        W: Write,
+    {
+        writer.write(xml::writer::XmlEvent::start_element(name))?;
+        writer.write(xml::writer::XmlEvent::characters(&format!(
+            "{value}",
+            value = obj.to_string()
+        )))?;
+        writer.write(xml::writer::XmlEvent::end_element())
     }
 }
 
@@ -9781,7 +12567,7 @@ impl ObjectIdentifierSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Key"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.key
+            value = &obj.key
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         if let Some(ref value) = obj.version_id {
@@ -9879,7 +12665,7 @@ impl ObjectListDeserializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ObjectLockConfiguration {
     /// <p>Indicates whether this bucket has an Object Lock configuration enabled.</p>
-    pub object_lock_enabled: Option<String>,
+    pub object_lock_enabled: Option<ObjectLockEnabled>,
     /// <p>The Object Lock rule in place for the specified object.</p>
     pub rule: Option<ObjectLockRule>,
 }
@@ -9940,12 +12726,105 @@ impl ObjectLockConfigurationSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ObjectLockEnabled {
+    /// `Enabled`
+    Enabled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ObjectLockEnabled {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ObjectLockEnabled::Enabled => "Enabled",
+            ObjectLockEnabled::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ObjectLockEnabled {
+    fn default() -> ObjectLockEnabled {
+        ObjectLockEnabled::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ObjectLockEnabled {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ObjectLockEnabled {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ObjectLockEnabled {
+    fn from(value: &'a str) -> ObjectLockEnabled {
+        match value {
+            "Enabled" => ObjectLockEnabled::Enabled,
+            _ => ObjectLockEnabled::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ObjectLockEnabled {
+    fn from(value: String) -> ObjectLockEnabled {
+        match value.as_str() {
+            "Enabled" => ObjectLockEnabled::Enabled,
+            _ => ObjectLockEnabled::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ObjectLockEnabled {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ObjectLockEnabled, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ObjectLockEnabled {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ObjectLockEnabled {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ObjectLockEnabled {
+    fn deserialize<D>(deserializer: D) -> Result<ObjectLockEnabled, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct ObjectLockEnabledDeserializer;
 impl ObjectLockEnabledDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ObjectLockEnabled, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -9958,7 +12837,7 @@ impl ObjectLockEnabledSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &ObjectLockEnabled,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -9978,7 +12857,7 @@ impl ObjectLockEnabledSerializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ObjectLockLegalHold {
     /// <p>Indicates whether the specified object has a Legal Hold in place.</p>
-    pub status: Option<String>,
+    pub status: Option<ObjectLockLegalHoldStatus>,
 }
 
 struct ObjectLockLegalHoldDeserializer;
@@ -10026,12 +12905,110 @@ impl ObjectLockLegalHoldSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ObjectLockLegalHoldStatus {
+    /// `ON`
+    On,
+    /// `OFF`
+    Off,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ObjectLockLegalHoldStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ObjectLockLegalHoldStatus::On => "ON",
+            ObjectLockLegalHoldStatus::Off => "OFF",
+            ObjectLockLegalHoldStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ObjectLockLegalHoldStatus {
+    fn default() -> ObjectLockLegalHoldStatus {
+        ObjectLockLegalHoldStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ObjectLockLegalHoldStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ObjectLockLegalHoldStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ObjectLockLegalHoldStatus {
+    fn from(value: &'a str) -> ObjectLockLegalHoldStatus {
+        match value {
+            "ON" => ObjectLockLegalHoldStatus::On,
+            "OFF" => ObjectLockLegalHoldStatus::Off,
+            _ => ObjectLockLegalHoldStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ObjectLockLegalHoldStatus {
+    fn from(value: String) -> ObjectLockLegalHoldStatus {
+        match value.as_str() {
+            "ON" => ObjectLockLegalHoldStatus::On,
+            "OFF" => ObjectLockLegalHoldStatus::Off,
+            _ => ObjectLockLegalHoldStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ObjectLockLegalHoldStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ObjectLockLegalHoldStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ObjectLockLegalHoldStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ObjectLockLegalHoldStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ObjectLockLegalHoldStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ObjectLockLegalHoldStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct ObjectLockLegalHoldStatusDeserializer;
 impl ObjectLockLegalHoldStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ObjectLockLegalHoldStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -10044,7 +13021,7 @@ impl ObjectLockLegalHoldStatusSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &ObjectLockLegalHoldStatus,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -10058,15 +13035,110 @@ impl ObjectLockLegalHoldStatusSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ObjectLockMode {
+    /// `GOVERNANCE`
+    Governance,
+    /// `COMPLIANCE`
+    Compliance,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ObjectLockMode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ObjectLockMode::Governance => "GOVERNANCE",
+            ObjectLockMode::Compliance => "COMPLIANCE",
+            ObjectLockMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ObjectLockMode {
+    fn default() -> ObjectLockMode {
+        ObjectLockMode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ObjectLockMode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ObjectLockMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ObjectLockMode {
+    fn from(value: &'a str) -> ObjectLockMode {
+        match value {
+            "GOVERNANCE" => ObjectLockMode::Governance,
+            "COMPLIANCE" => ObjectLockMode::Compliance,
+            _ => ObjectLockMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ObjectLockMode {
+    fn from(value: String) -> ObjectLockMode {
+        match value.as_str() {
+            "GOVERNANCE" => ObjectLockMode::Governance,
+            "COMPLIANCE" => ObjectLockMode::Compliance,
+            _ => ObjectLockMode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ObjectLockMode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ObjectLockMode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ObjectLockMode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ObjectLockMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ObjectLockMode {
+    fn deserialize<D>(deserializer: D) -> Result<ObjectLockMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A Retention configuration for an object.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ObjectLockRetention {
     /// <p>Indicates the Retention mode for the specified object.</p>
-    pub mode: Option<String>,
+    pub mode: Option<ObjectLockRetentionMode>,
     /// <p>The date on which this Object Lock Retention will expire.</p>
-    pub retain_until_date: Option<String>,
+    pub retain_until_date: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 struct ObjectLockRetentionDeserializer;
@@ -10118,7 +13190,7 @@ impl ObjectLockRetentionSerializer {
             writer.write(xml::writer::XmlEvent::start_element("RetainUntilDate"))?;
             writer.write(xml::writer::XmlEvent::characters(&format!(
                 "{value}",
-                value = value
+                value = rusoto_core::serialization::TimestampFormat::Iso8601.format(value)
             )));
             writer.write(xml::writer::XmlEvent::end_element())?;
         }
@@ -10126,12 +13198,110 @@ impl ObjectLockRetentionSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ObjectLockRetentionMode {
+    /// `GOVERNANCE`
+    Governance,
+    /// `COMPLIANCE`
+    Compliance,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ObjectLockRetentionMode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ObjectLockRetentionMode::Governance => "GOVERNANCE",
+            ObjectLockRetentionMode::Compliance => "COMPLIANCE",
+            ObjectLockRetentionMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ObjectLockRetentionMode {
+    fn default() -> ObjectLockRetentionMode {
+        ObjectLockRetentionMode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ObjectLockRetentionMode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ObjectLockRetentionMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ObjectLockRetentionMode {
+    fn from(value: &'a str) -> ObjectLockRetentionMode {
+        match value {
+            "GOVERNANCE" => ObjectLockRetentionMode::Governance,
+            "COMPLIANCE" => ObjectLockRetentionMode::Compliance,
+            _ => ObjectLockRetentionMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ObjectLockRetentionMode {
+    fn from(value: String) -> ObjectLockRetentionMode {
+        match value.as_str() {
+            "GOVERNANCE" => ObjectLockRetentionMode::Governance,
+            "COMPLIANCE" => ObjectLockRetentionMode::Compliance,
+            _ => ObjectLockRetentionMode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ObjectLockRetentionMode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ObjectLockRetentionMode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ObjectLockRetentionMode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ObjectLockRetentionMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ObjectLockRetentionMode {
+    fn deserialize<D>(deserializer: D) -> Result<ObjectLockRetentionMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct ObjectLockRetentionModeDeserializer;
 impl ObjectLockRetentionModeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ObjectLockRetentionMode, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -10144,7 +13314,7 @@ impl ObjectLockRetentionModeSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &ObjectLockRetentionMode,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -10208,12 +13378,155 @@ impl ObjectLockRuleSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ObjectStorageClass {
+    /// `STANDARD`
+    Standard,
+    /// `REDUCED_REDUNDANCY`
+    ReducedRedundancy,
+    /// `GLACIER`
+    Glacier,
+    /// `STANDARD_IA`
+    StandardIa,
+    /// `ONEZONE_IA`
+    OnezoneIa,
+    /// `INTELLIGENT_TIERING`
+    IntelligentTiering,
+    /// `DEEP_ARCHIVE`
+    DeepArchive,
+    /// `OUTPOSTS`
+    Outposts,
+    /// `GLACIER_IR`
+    GlacierIr,
+    /// `SNOW`
+    Snow,
+    /// `EXPRESS_ONEZONE`
+    ExpressOnezone,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ObjectStorageClass {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ObjectStorageClass::Standard => "STANDARD",
+            ObjectStorageClass::ReducedRedundancy => "REDUCED_REDUNDANCY",
+            ObjectStorageClass::Glacier => "GLACIER",
+            ObjectStorageClass::StandardIa => "STANDARD_IA",
+            ObjectStorageClass::OnezoneIa => "ONEZONE_IA",
+            ObjectStorageClass::IntelligentTiering => "INTELLIGENT_TIERING",
+            ObjectStorageClass::DeepArchive => "DEEP_ARCHIVE",
+            ObjectStorageClass::Outposts => "OUTPOSTS",
+            ObjectStorageClass::GlacierIr => "GLACIER_IR",
+            ObjectStorageClass::Snow => "SNOW",
+            ObjectStorageClass::ExpressOnezone => "EXPRESS_ONEZONE",
+            ObjectStorageClass::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ObjectStorageClass {
+    fn default() -> ObjectStorageClass {
+        ObjectStorageClass::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ObjectStorageClass {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ObjectStorageClass {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ObjectStorageClass {
+    fn from(value: &'a str) -> ObjectStorageClass {
+        match value {
+            "STANDARD" => ObjectStorageClass::Standard,
+            "REDUCED_REDUNDANCY" => ObjectStorageClass::ReducedRedundancy,
+            "GLACIER" => ObjectStorageClass::Glacier,
+            "STANDARD_IA" => ObjectStorageClass::StandardIa,
+            "ONEZONE_IA" => ObjectStorageClass::OnezoneIa,
+            "INTELLIGENT_TIERING" => ObjectStorageClass::IntelligentTiering,
+            "DEEP_ARCHIVE" => ObjectStorageClass::DeepArchive,
+            "OUTPOSTS" => ObjectStorageClass::Outposts,
+            "GLACIER_IR" => ObjectStorageClass::GlacierIr,
+            "SNOW" => ObjectStorageClass::Snow,
+            "EXPRESS_ONEZONE" => ObjectStorageClass::ExpressOnezone,
+            _ => ObjectStorageClass::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ObjectStorageClass {
+    fn from(value: String) -> ObjectStorageClass {
+        match value.as_str() {
+            "STANDARD" => ObjectStorageClass::Standard,
+            "REDUCED_REDUNDANCY" => ObjectStorageClass::ReducedRedundancy,
+            "GLACIER" => ObjectStorageClass::Glacier,
+            "STANDARD_IA" => ObjectStorageClass::StandardIa,
+            "ONEZONE_IA" => ObjectStorageClass::OnezoneIa,
+            "INTELLIGENT_TIERING" => ObjectStorageClass::IntelligentTiering,
+            "DEEP_ARCHIVE" => ObjectStorageClass::DeepArchive,
+            "OUTPOSTS" => ObjectStorageClass::Outposts,
+            "GLACIER_IR" => ObjectStorageClass::GlacierIr,
+            "SNOW" => ObjectStorageClass::Snow,
+            "EXPRESS_ONEZONE" => ObjectStorageClass::ExpressOnezone,
+            _ => ObjectStorageClass::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ObjectStorageClass {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ObjectStorageClass, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ObjectStorageClass {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ObjectStorageClass {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ObjectStorageClass {
+    fn deserialize<D>(deserializer: D) -> Result<ObjectStorageClass, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct ObjectStorageClassDeserializer;
 impl ObjectStorageClassDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ObjectStorageClass, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -10230,13 +13543,13 @@ pub struct ObjectVersion {
     /// <p>The object key.</p>
     pub key: Option<String>,
     /// <p>Date and time the object was last modified.</p>
-    pub last_modified: Option<String>,
+    pub last_modified: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Specifies the owner of the object.</p>
     pub owner: Option<Owner>,
     /// <p>Size in bytes of the object.</p>
     pub size: Option<i64>,
     /// <p>The class of storage used to store the object.</p>
-    pub storage_class: Option<String>,
+    pub storage_class: Option<ObjectVersionStorageClass>,
     /// <p>Version ID of an object.</p>
     pub version_id: Option<String>,
 }
@@ -10345,15 +13658,108 @@ impl ObjectVersionListDeserializer {
             }
         }
 
-        Ok(obj)
+        Ok(obj)
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ObjectVersionStorageClass {
+    /// `STANDARD`
+    Standard,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ObjectVersionStorageClass {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ObjectVersionStorageClass::Standard => "STANDARD",
+            ObjectVersionStorageClass::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ObjectVersionStorageClass {
+    fn default() -> ObjectVersionStorageClass {
+        ObjectVersionStorageClass::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ObjectVersionStorageClass {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ObjectVersionStorageClass {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ObjectVersionStorageClass {
+    fn from(value: &'a str) -> ObjectVersionStorageClass {
+        match value {
+            "STANDARD" => ObjectVersionStorageClass::Standard,
+            _ => ObjectVersionStorageClass::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ObjectVersionStorageClass {
+    fn from(value: String) -> ObjectVersionStorageClass {
+        match value.as_str() {
+            "STANDARD" => ObjectVersionStorageClass::Standard,
+            _ => ObjectVersionStorageClass::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ObjectVersionStorageClass {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ObjectVersionStorageClass, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ObjectVersionStorageClass {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ObjectVersionStorageClass {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ObjectVersionStorageClass {
+    fn deserialize<D>(deserializer: D) -> Result<ObjectVersionStorageClass, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
     }
 }
 struct ObjectVersionStorageClassDeserializer;
 impl ObjectVersionStorageClassDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ObjectVersionStorageClass, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -10481,12 +13887,105 @@ impl OwnerSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OwnerOverride {
+    /// `Destination`
+    Destination,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl OwnerOverride {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OwnerOverride::Destination => "Destination",
+            OwnerOverride::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for OwnerOverride {
+    fn default() -> OwnerOverride {
+        OwnerOverride::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for OwnerOverride {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for OwnerOverride {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for OwnerOverride {
+    fn from(value: &'a str) -> OwnerOverride {
+        match value {
+            "Destination" => OwnerOverride::Destination,
+            _ => OwnerOverride::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OwnerOverride {
+    fn from(value: String) -> OwnerOverride {
+        match value.as_str() {
+            "Destination" => OwnerOverride::Destination,
+            _ => OwnerOverride::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for OwnerOverride {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<OwnerOverride, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OwnerOverride {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for OwnerOverride {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for OwnerOverride {
+    fn deserialize<D>(deserializer: D) -> Result<OwnerOverride, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct OwnerOverrideDeserializer;
 impl OwnerOverrideDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<OwnerOverride, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -10499,7 +13998,7 @@ impl OwnerOverrideSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &OwnerOverride,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -10541,7 +14040,7 @@ pub struct Part {
     /// <p>Entity tag returned when the part was uploaded.</p>
     pub e_tag: Option<String>,
     /// <p>Date and time at which the part was uploaded.</p>
-    pub last_modified: Option<String>,
+    pub last_modified: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Part number identifying the part. This is a positive integer between 1 and 10,000.</p>
     pub part_number: Option<i64>,
     /// <p>Size in bytes of the uploaded part data.</p>
@@ -10667,12 +14166,107 @@ impl PartsDeserializer {
         Ok(obj)
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Payer {
+    /// `Requester`
+    Requester,
+    /// `BucketOwner`
+    BucketOwner,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Payer {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Payer::Requester => "Requester",
+            Payer::BucketOwner => "BucketOwner",
+            Payer::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Payer {
+    fn default() -> Payer {
+        Payer::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Payer {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Payer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Payer {
+    fn from(value: &'a str) -> Payer {
+        match value {
+            "Requester" => Payer::Requester,
+            "BucketOwner" => Payer::BucketOwner,
+            _ => Payer::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Payer {
+    fn from(value: String) -> Payer {
+        match value.as_str() {
+            "Requester" => Payer::Requester,
+            "BucketOwner" => Payer::BucketOwner,
+            _ => Payer::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Payer {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Payer, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Payer {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for Payer {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for Payer {
+    fn deserialize<D>(deserializer: D) -> Result<Payer, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct PayerDeserializer;
 impl PayerDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<Payer, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -10685,7 +14279,7 @@ impl PayerSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &Payer,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -10699,12 +14293,125 @@ impl PayerSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Permission {
+    /// `FULL_CONTROL`
+    FullControl,
+    /// `WRITE`
+    Write,
+    /// `WRITE_ACP`
+    WriteAcp,
+    /// `READ`
+    Read,
+    /// `READ_ACP`
+    ReadAcp,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Permission {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Permission::FullControl => "FULL_CONTROL",
+            Permission::Write => "WRITE",
+            Permission::WriteAcp => "WRITE_ACP",
+            Permission::Read => "READ",
+            Permission::ReadAcp => "READ_ACP",
+            Permission::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Permission {
+    fn default() -> Permission {
+        Permission::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Permission {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Permission {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Permission {
+    fn from(value: &'a str) -> Permission {
+        match value {
+            "FULL_CONTROL" => Permission::FullControl,
+            "WRITE" => Permission::Write,
+            "WRITE_ACP" => Permission::WriteAcp,
+            "READ" => Permission::Read,
+            "READ_ACP" => Permission::ReadAcp,
+            _ => Permission::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Permission {
+    fn from(value: String) -> Permission {
+        match value.as_str() {
+            "FULL_CONTROL" => Permission::FullControl,
+            "WRITE" => Permission::Write,
+            "WRITE_ACP" => Permission::WriteAcp,
+            "READ" => Permission::Read,
+            "READ_ACP" => Permission::ReadAcp,
+            _ => Permission::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Permission {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Permission, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Permission {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for Permission {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for Permission {
+    fn deserialize<D>(deserializer: D) -> Result<Permission, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct PermissionDeserializer;
 impl PermissionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Permission, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -10717,7 +14424,7 @@ impl PermissionSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &Permission,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -10868,36 +14575,115 @@ impl ProgressDeserializer {
 }
 /// <p>This data type contains information about the progress event of an operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
-#[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct ProgressEvent {
     /// <p>The Progress event details.</p>
     pub details: Option<Progress>,
 }
 
-struct ProgressEventDeserializer;
-impl ProgressEventDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
-        tag_name: &str,
-        stack: &mut T,
-    ) -> Result<ProgressEvent, XmlParseError> {
-        deserialize_elements::<_, ProgressEvent, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "Details" => {
-                    obj.details = Some(ProgressDeserializer::deserialize("Details", stack)?);
-                }
-                _ => skip_tree(stack),
-            }
-            Ok(())
-        })
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Protocol {
+    /// `http`
+    Http,
+    /// `https`
+    Https,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Protocol {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Protocol::Http => "http",
+            Protocol::Https => "https",
+            Protocol::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Protocol {
+    fn default() -> Protocol {
+        Protocol::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Protocol {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Protocol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Protocol {
+    fn from(value: &'a str) -> Protocol {
+        match value {
+            "http" => Protocol::Http,
+            "https" => Protocol::Https,
+            _ => Protocol::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Protocol {
+    fn from(value: String) -> Protocol {
+        match value.as_str() {
+            "http" => Protocol::Http,
+            "https" => Protocol::Https,
+            _ => Protocol::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Protocol {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Protocol, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Protocol {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for Protocol {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for Protocol {
+    fn deserialize<D>(deserializer: D) -> Result<Protocol, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
     }
 }
 struct ProtocolDeserializer;
 impl ProtocolDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Protocol, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -10910,7 +14696,7 @@ impl ProtocolSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &Protocol,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -11042,7 +14828,7 @@ pub struct PutBucketAccelerateConfigurationRequest {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct PutBucketAclRequest {
     /// <p>The canned ACL to apply to the bucket.</p>
-    pub acl: Option<String>,
+    pub acl: Option<BucketCannedACL>,
     /// <p>Contains the elements that set the ACL permissions for an object per grantee.</p>
     pub access_control_policy: Option<AccessControlPolicy>,
     /// <p>The bucket to which to apply the ACL.</p>
@@ -11239,7 +15025,7 @@ pub struct PutBucketWebsiteRequest {
 #[derive(Default, Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct PutObjectAclOutput {
-    pub request_charged: Option<String>,
+    pub request_charged: Option<RequestCharged>,
 }
 
 struct PutObjectAclOutputDeserializer;
@@ -11262,7 +15048,7 @@ impl PutObjectAclOutputDeserializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct PutObjectAclRequest {
     /// <p>The canned ACL to apply to the object. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/acl-overview.html#CannedACL">Canned ACL</a>.</p>
-    pub acl: Option<String>,
+    pub acl: Option<ObjectCannedACL>,
     /// <p>Contains the elements that set the ACL permissions for an object per grantee.</p>
     pub access_control_policy: Option<AccessControlPolicy>,
     /// <p>The bucket name that contains the object to which you want to attach the ACL. </p> <p>When using this API with an access point, you must direct requests to the access point hostname. The access point hostname takes the form <i>AccessPointName</i>-<i>AccountId</i>.s3-accesspoint.<i>Region</i>.amazonaws.com. When using this operation using an access point through the AWS SDKs, you provide the access point ARN in place of the bucket name. For more information about access point ARNs, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-access-points.html">Using Access Points</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p>
@@ -11281,7 +15067,7 @@ pub struct PutObjectAclRequest {
     pub grant_write_acp: Option<String>,
     /// <p>Key for which the PUT operation was initiated.</p>
     pub key: String,
-    pub request_payer: Option<String>,
+    pub request_payer: Option<RequestPayer>,
     /// <p>VersionId used to reference a specific version of the object.</p>
     pub version_id: Option<String>,
 }
@@ -11289,7 +15075,7 @@ pub struct PutObjectAclRequest {
 #[derive(Default, Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct PutObjectLegalHoldOutput {
-    pub request_charged: Option<String>,
+    pub request_charged: Option<RequestCharged>,
 }
 
 struct PutObjectLegalHoldOutputDeserializer;
@@ -11319,7 +15105,7 @@ pub struct PutObjectLegalHoldRequest {
     pub key: String,
     /// <p>Container element for the Legal Hold configuration you want to apply to the specified object.</p>
     pub legal_hold: Option<ObjectLockLegalHold>,
-    pub request_payer: Option<String>,
+    pub request_payer: Option<RequestPayer>,
     /// <p>The version ID of the object that you want to place a Legal Hold on.</p>
     pub version_id: Option<String>,
 }
@@ -11327,7 +15113,7 @@ pub struct PutObjectLegalHoldRequest {
 #[derive(Default, Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct PutObjectLockConfigurationOutput {
-    pub request_charged: Option<String>,
+    pub request_charged: Option<RequestCharged>,
 }
 
 struct PutObjectLockConfigurationOutputDeserializer;
@@ -11355,7 +15141,7 @@ pub struct PutObjectLockConfigurationRequest {
     pub content_md5: Option<String>,
     /// <p>The Object Lock configuration that you want to apply to the specified bucket.</p>
     pub object_lock_configuration: Option<ObjectLockConfiguration>,
-    pub request_payer: Option<String>,
+    pub request_payer: Option<RequestPayer>,
     /// <p>A token to allow Object Lock to be enabled for an existing bucket.</p>
     pub token: Option<String>,
 }
@@ -11367,7 +15153,7 @@ pub struct PutObjectOutput {
     pub e_tag: Option<String>,
     /// <p> If the expiration is configured for the object (see <a>PutBucketLifecycleConfiguration</a>), the response includes this header. It includes the expiry-date and rule-id key-value pairs that provide information about object expiration. The value of the rule-id is URL encoded.</p>
     pub expiration: Option<String>,
-    pub request_charged: Option<String>,
+    pub request_charged: Option<RequestCharged>,
     /// <p>If server-side encryption with a customer-provided encryption key was requested, the response will include this header confirming the encryption algorithm used.</p>
     pub sse_customer_algorithm: Option<String>,
     /// <p>If server-side encryption with a customer-provided encryption key was requested, the response will include this header to provide round-trip message integrity verification of the customer-provided encryption key.</p>
@@ -11377,7 +15163,7 @@ pub struct PutObjectOutput {
     /// <p>If <code>x-amz-server-side-encryption</code> is present and has the value of <code>aws:kms</code>, this header specifies the ID of the AWS Key Management Service (AWS KMS) symmetric customer managed customer master key (CMK) that was used for the object. </p>
     pub ssekms_key_id: Option<String>,
     /// <p>If you specified server-side encryption either with an AWS KMS customer master key (CMK) or Amazon S3-managed encryption key in your PUT request, the response includes this header. It confirms the encryption algorithm that Amazon S3 used to encrypt the object.</p>
-    pub server_side_encryption: Option<String>,
+    pub server_side_encryption: Option<ServerSideEncryption>,
     /// <p>Version of the object.</p>
     pub version_id: Option<String>,
 }
@@ -11401,7 +15187,7 @@ impl PutObjectOutputDeserializer {
 #[derive(Default, Debug)]
 pub struct PutObjectRequest {
     /// <p>The canned ACL to apply to the object. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/acl-overview.html#CannedACL">Canned ACL</a>.</p>
-    pub acl: Option<String>,
+    pub acl: Option<ObjectCannedACL>,
     /// <p>Object data.</p>
     pub body: Option<StreamingBody>,
     /// <p>Bucket name to which the PUT operation was initiated. </p> <p>When using this API with an access point, you must direct requests to the access point hostname. The access point hostname takes the form <i>AccessPointName</i>-<i>AccountId</i>.s3-accesspoint.<i>Region</i>.amazonaws.com. When using this operation using an access point through the AWS SDKs, you provide the access point ARN in place of the bucket name. For more information about access point ARNs, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-access-points.html">Using Access Points</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p>
@@ -11421,7 +15207,7 @@ pub struct PutObjectRequest {
     /// <p>A standard MIME type describing the format of the contents. For more information, see <a href="http://www.w3.org/Protocols/rfc2616/rfc2616-sec14.html#sec14.17">http://www.w3.org/Protocols/rfc2616/rfc2616-sec14.html#sec14.17</a>.</p>
     pub content_type: Option<String>,
     /// <p>The date and time at which the object is no longer cacheable. For more information, see <a href="http://www.w3.org/Protocols/rfc2616/rfc2616-sec14.html#sec14.21">http://www.w3.org/Protocols/rfc2616/rfc2616-sec14.html#sec14.21</a>.</p>
-    pub expires: Option<String>,
+    pub expires: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Gives the grantee READ, READ_ACP, and WRITE_ACP permissions on the object.</p>
     pub grant_full_control: Option<String>,
     /// <p>Allows grantee to read the object data and its metadata.</p>
@@ -11435,12 +15221,12 @@ pub struct PutObjectRequest {
     /// <p>A map of metadata to store with the object in S3.</p>
     pub metadata: Option<::std::collections::HashMap<String, String>>,
     /// <p>Specifies whether a legal hold will be applied to this object. For more information about S3 Object Lock, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/object-lock.html">Object Lock</a>.</p>
-    pub object_lock_legal_hold_status: Option<String>,
+    pub object_lock_legal_hold_status: Option<ObjectLockLegalHoldStatus>,
     /// <p>The Object Lock mode that you want to apply to this object.</p>
-    pub object_lock_mode: Option<String>,
+    pub object_lock_mode: Option<ObjectLockMode>,
     /// <p>The date and time when you want this object's Object Lock to expire.</p>
-    pub object_lock_retain_until_date: Option<String>,
-    pub request_payer: Option<String>,
+    pub object_lock_retain_until_date: Option<::chrono::DateTime<::chrono::Utc>>,
+    pub request_payer: Option<RequestPayer>,
     /// <p>Specifies the algorithm to use to when encrypting the object (for example, AES256).</p>
     pub sse_customer_algorithm: Option<String>,
     /// <p>Specifies the customer-provided encryption key for Amazon S3 to use in encrypting data. This value is used to store the object and then it is discarded; Amazon S3 does not store the encryption key. The key must be appropriate for use with the algorithm specified in the <code>x-amz-server-side​-encryption​-customer-algorithm</code> header.</p>
@@ -11452,9 +15238,9 @@ pub struct PutObjectRequest {
     /// <p>If <code>x-amz-server-side-encryption</code> is present and has the value of <code>aws:kms</code>, this header specifies the ID of the AWS Key Management Service (AWS KMS) symmetrical customer managed customer master key (CMK) that was used for the object.</p> <p> If the value of <code>x-amz-server-side-encryption</code> is <code>aws:kms</code>, this header specifies the ID of the symmetric customer managed AWS KMS CMK that will be used for the object. If you specify <code>x-amz-server-side-encryption:aws:kms</code>, but do not provide<code> x-amz-server-side-encryption-aws-kms-key-id</code>, Amazon S3 uses the AWS managed CMK in AWS to protect the data.</p>
     pub ssekms_key_id: Option<String>,
     /// <p>The server-side encryption algorithm used when storing this object in Amazon S3 (for example, AES256, aws:kms).</p>
-    pub server_side_encryption: Option<String>,
+    pub server_side_encryption: Option<ServerSideEncryption>,
     /// <p>If you don't specify, Standard is the default storage class. Amazon S3 supports other storage classes.</p>
-    pub storage_class: Option<String>,
+    pub storage_class: Option<StorageClass>,
     /// <p>The tag-set for the object. The tag-set must be encoded as URL Query parameters. (For example, "Key1=Value1")</p>
     pub tagging: Option<String>,
     /// <p>If the bucket is configured as a website, redirects requests for this object to another object in the same bucket or to an external URL. Amazon S3 stores the value of this header in the object metadata. For information about object metadata, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/UsingMetadata.html">Object Key and Metadata</a>.</p> <p>In the following example, the request header sets the redirect to an object (anotherPage.html) in the same bucket:</p> <p> <code>x-amz-website-redirect-location: /anotherPage.html</code> </p> <p>In the following example, the request header sets the object redirect to another website:</p> <p> <code>x-amz-website-redirect-location: http://www.example.com/</code> </p> <p>For more information about website hosting in Amazon S3, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/WebsiteHosting.html">Hosting Websites on Amazon S3</a> and <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/how-to-page-redirect.html">How to Configure Website Page Redirects</a>. </p>
@@ -11464,7 +15250,7 @@ pub struct PutObjectRequest {
 #[derive(Default, Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct PutObjectRetentionOutput {
-    pub request_charged: Option<String>,
+    pub request_charged: Option<RequestCharged>,
 }
 
 struct PutObjectRetentionOutputDeserializer;
@@ -11494,7 +15280,7 @@ pub struct PutObjectRetentionRequest {
     pub content_md5: Option<String>,
     /// <p>The key name for the object that you want to apply this Object Retention configuration to.</p>
     pub key: String,
-    pub request_payer: Option<String>,
+    pub request_payer: Option<RequestPayer>,
     /// <p>The container element for the Object Retention configuration.</p>
     pub retention: Option<ObjectLockRetention>,
     /// <p>The version ID for the object that you want to apply this Object Retention configuration to.</p>
@@ -11588,7 +15374,7 @@ impl QueueArnSerializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct QueueConfiguration {
     /// <p>A collection of bucket events for which to send notifications</p>
-    pub events: Vec<String>,
+    pub events: Vec<Event>,
     pub filter: Option<NotificationConfigurationFilter>,
     pub id: Option<String>,
     /// <p>The Amazon Resource Name (ARN) of the Amazon SQS queue to which Amazon S3 publishes a message when it detects events of the specified type.</p>
@@ -11653,7 +15439,7 @@ impl QueueConfigurationSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Queue"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.queue_arn
+            value = &obj.queue_arn
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::end_element())
@@ -11666,7 +15452,7 @@ impl QueueConfigurationSerializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct QueueConfigurationDeprecated {
     /// <p>A collection of bucket events for which to send notifications</p>
-    pub events: Option<Vec<String>>,
+    pub events: Option<Vec<Event>>,
     pub id: Option<String>,
     /// <p>The Amazon Resource Name (ARN) of the Amazon SQS queue to which Amazon S3 publishes a message when it detects events of the specified type. </p>
     pub queue: Option<String>,
@@ -11846,13 +15632,109 @@ impl QuoteEscapeCharacterSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum QuoteFields {
+    /// `ALWAYS`
+    Always,
+    /// `ASNEEDED`
+    Asneeded,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl QuoteFields {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            QuoteFields::Always => "ALWAYS",
+            QuoteFields::Asneeded => "ASNEEDED",
+            QuoteFields::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for QuoteFields {
+    fn default() -> QuoteFields {
+        QuoteFields::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for QuoteFields {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for QuoteFields {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for QuoteFields {
+    fn from(value: &'a str) -> QuoteFields {
+        match value {
+            "ALWAYS" => QuoteFields::Always,
+            "ASNEEDED" => QuoteFields::Asneeded,
+            _ => QuoteFields::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for QuoteFields {
+    fn from(value: String) -> QuoteFields {
+        match value.as_str() {
+            "ALWAYS" => QuoteFields::Always,
+            "ASNEEDED" => QuoteFields::Asneeded,
+            _ => QuoteFields::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for QuoteFields {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<QuoteFields, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for QuoteFields {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for QuoteFields {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for QuoteFields {
+    fn deserialize<D>(deserializer: D) -> Result<QuoteFields, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+
 pub struct QuoteFieldsSerializer;
 impl QuoteFieldsSerializer {
     #[allow(unused_variables, warnings)]
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &QuoteFields,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -11888,30 +15770,11 @@ impl RecordDelimiterSerializer {
 
 /// <p>The container for the records event.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
-#[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct RecordsEvent {
     /// <p>The byte array of partial, one or more result records.</p>
     pub payload: Option<bytes::Bytes>,
 }
 
-struct RecordsEventDeserializer;
-impl RecordsEventDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
-        tag_name: &str,
-        stack: &mut T,
-    ) -> Result<RecordsEvent, XmlParseError> {
-        deserialize_elements::<_, RecordsEvent, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "Payload" => {
-                    obj.payload = Some(BodyDeserializer::deserialize("Payload", stack)?);
-                }
-                _ => skip_tree(stack),
-            }
-            Ok(())
-        })
-    }
-}
 /// <p>Specifies how requests are redirected. In the event of an error, you can specify a different error code to return.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
@@ -11922,7 +15785,7 @@ pub struct Redirect {
     /// <p>The HTTP redirect code to use on the response. Not required if one of the siblings is present.</p>
     pub http_redirect_code: Option<String>,
     /// <p>Protocol to use when redirecting requests. The default is the protocol that is used in the original request.</p>
-    pub protocol: Option<String>,
+    pub protocol: Option<Protocol>,
     /// <p>The object key prefix to use in the redirect request. For example, to redirect requests for all pages with prefix <code>docs/</code> (objects in the <code>docs/</code> folder) to <code>documents/</code>, you can set a condition block with <code>KeyPrefixEquals</code> set to <code>docs/</code> and in the Redirect set <code>ReplaceKeyPrefixWith</code> to <code>/documents</code>. Not required if one of the siblings is present. Can be present only if <code>ReplaceKeyWith</code> is not provided.</p>
     pub replace_key_prefix_with: Option<String>,
     /// <p>The specific object key to use in the redirect request. For example, redirect request to <code>error.html</code>. Not required if one of the siblings is present. Can be present only if <code>ReplaceKeyPrefixWith</code> is not provided.</p>
@@ -12034,7 +15897,7 @@ pub struct RedirectAllRequestsTo {
     /// <p>Name of the host where requests are redirected.</p>
     pub host_name: String,
     /// <p>Protocol to use when redirecting requests. The default is the protocol that is used in the original request.</p>
-    pub protocol: Option<String>,
+    pub protocol: Option<Protocol>,
 }
 
 struct RedirectAllRequestsToDeserializer;
@@ -12074,7 +15937,7 @@ impl RedirectAllRequestsToSerializer {
         writer.write(xml::writer::XmlEvent::start_element("HostName"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.host_name
+            value = &obj.host_name
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         if let Some(ref value) = obj.protocol {
@@ -12238,7 +16101,7 @@ impl ReplicationConfigurationSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Role"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.role
+            value = &obj.role
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         ReplicationRulesSerializer::serialize(&mut writer, "Rule", &obj.rules)?;
@@ -12264,7 +16127,7 @@ pub struct ReplicationRule {
     /// <p>A container that describes additional filters for identifying the source objects that you want to replicate. You can choose to enable or disable the replication of these objects. Currently, Amazon S3 supports only the filter that you can specify for objects created with server-side encryption using a customer master key (CMK) stored in AWS Key Management Service (SSE-KMS).</p>
     pub source_selection_criteria: Option<SourceSelectionCriteria>,
     /// <p>Specifies whether the rule is enabled.</p>
-    pub status: String,
+    pub status: ReplicationRuleStatus,
 }
 
 struct ReplicationRuleDeserializer;
@@ -12377,7 +16240,7 @@ impl ReplicationRuleSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Status"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.status
+            value = &obj.status
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::end_element())
@@ -12520,12 +16383,110 @@ impl ReplicationRuleFilterSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ReplicationRuleStatus {
+    /// `Enabled`
+    Enabled,
+    /// `Disabled`
+    Disabled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ReplicationRuleStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReplicationRuleStatus::Enabled => "Enabled",
+            ReplicationRuleStatus::Disabled => "Disabled",
+            ReplicationRuleStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ReplicationRuleStatus {
+    fn default() -> ReplicationRuleStatus {
+        ReplicationRuleStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ReplicationRuleStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ReplicationRuleStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ReplicationRuleStatus {
+    fn from(value: &'a str) -> ReplicationRuleStatus {
+        match value {
+            "Enabled" => ReplicationRuleStatus::Enabled,
+            "Disabled" => ReplicationRuleStatus::Disabled,
+            _ => ReplicationRuleStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReplicationRuleStatus {
+    fn from(value: String) -> ReplicationRuleStatus {
+        match value.as_str() {
+            "Enabled" => ReplicationRuleStatus::Enabled,
+            "Disabled" => ReplicationRuleStatus::Disabled,
+            _ => ReplicationRuleStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ReplicationRuleStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ReplicationRuleStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ReplicationRuleStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ReplicationRuleStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ReplicationRuleStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ReplicationRuleStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct ReplicationRuleStatusDeserializer;
 impl ReplicationRuleStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ReplicationRuleStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -12538,7 +16499,7 @@ impl ReplicationRuleStatusSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &ReplicationRuleStatus,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -12598,13 +16559,123 @@ impl ReplicationRulesSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ReplicationStatus {
+    /// `COMPLETE`
+    Complete,
+    /// `PENDING`
+    Pending,
+    /// `FAILED`
+    Failed,
+    /// `REPLICA`
+    Replica,
+    /// `COMPLETED`
+    Completed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ReplicationStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReplicationStatus::Complete => "COMPLETE",
+            ReplicationStatus::Pending => "PENDING",
+            ReplicationStatus::Failed => "FAILED",
+            ReplicationStatus::Replica => "REPLICA",
+            ReplicationStatus::Completed => "COMPLETED",
+            ReplicationStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ReplicationStatus {
+    fn default() -> ReplicationStatus {
+        ReplicationStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ReplicationStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ReplicationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ReplicationStatus {
+    fn from(value: &'a str) -> ReplicationStatus {
+        match value {
+            "COMPLETE" => ReplicationStatus::Complete,
+            "PENDING" => ReplicationStatus::Pending,
+            "FAILED" => ReplicationStatus::Failed,
+            "REPLICA" => ReplicationStatus::Replica,
+            "COMPLETED" => ReplicationStatus::Completed,
+            _ => ReplicationStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReplicationStatus {
+    fn from(value: String) -> ReplicationStatus {
+        match value.as_str() {
+            "COMPLETE" => ReplicationStatus::Complete,
+            "PENDING" => ReplicationStatus::Pending,
+            "FAILED" => ReplicationStatus::Failed,
+            "REPLICA" => ReplicationStatus::Replica,
+            "COMPLETED" => ReplicationStatus::Completed,
+            _ => ReplicationStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ReplicationStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ReplicationStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ReplicationStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ReplicationStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ReplicationStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ReplicationStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p> A container specifying S3 Replication Time Control (S3 RTC) related information, including whether S3 RTC is enabled and the time when all objects and operations on objects must be replicated. Must be specified together with a <code>Metrics</code> block. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ReplicationTime {
     /// <p> Specifies whether the replication time is enabled. </p>
-    pub status: String,
+    pub status: ReplicationTimeStatus,
     /// <p> A container specifying the time by which replication should be complete for all objects and operations on objects. </p>
     pub time: ReplicationTimeValue,
 }
@@ -12646,7 +16717,7 @@ impl ReplicationTimeSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Status"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.status
+            value = &obj.status
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         ReplicationTimeValueSerializer::serialize(&mut writer, "Time", &obj.time)?;
@@ -12654,12 +16725,110 @@ impl ReplicationTimeSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ReplicationTimeStatus {
+    /// `Enabled`
+    Enabled,
+    /// `Disabled`
+    Disabled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ReplicationTimeStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReplicationTimeStatus::Enabled => "Enabled",
+            ReplicationTimeStatus::Disabled => "Disabled",
+            ReplicationTimeStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ReplicationTimeStatus {
+    fn default() -> ReplicationTimeStatus {
+        ReplicationTimeStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ReplicationTimeStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ReplicationTimeStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ReplicationTimeStatus {
+    fn from(value: &'a str) -> ReplicationTimeStatus {
+        match value {
+            "Enabled" => ReplicationTimeStatus::Enabled,
+            "Disabled" => ReplicationTimeStatus::Disabled,
+            _ => ReplicationTimeStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReplicationTimeStatus {
+    fn from(value: String) -> ReplicationTimeStatus {
+        match value.as_str() {
+            "Enabled" => ReplicationTimeStatus::Enabled,
+            "Disabled" => ReplicationTimeStatus::Disabled,
+            _ => ReplicationTimeStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ReplicationTimeStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ReplicationTimeStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ReplicationTimeStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ReplicationTimeStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ReplicationTimeStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ReplicationTimeStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct ReplicationTimeStatusDeserializer;
 impl ReplicationTimeStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ReplicationTimeStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -12672,7 +16841,7 @@ impl ReplicationTimeStatusSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &ReplicationTimeStatus,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -12686,64 +16855,246 @@ impl ReplicationTimeStatusSerializer {
     }
 }
 
-/// <p> A container specifying the time value for S3 Replication Time Control (S3 RTC) and replication metrics <code>EventThreshold</code>. </p>
-#[derive(Default, Debug, Clone, PartialEq)]
-#[cfg_attr(feature = "serialize_structs", derive(Serialize))]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct ReplicationTimeValue {
-    /// <p> Contains an integer specifying time in minutes. </p> <p> Valid values: 15 minutes. </p>
-    pub minutes: Option<i64>,
+/// <p> A container specifying the time value for S3 Replication Time Control (S3 RTC) and replication metrics <code>EventThreshold</code>. </p>
+#[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize_structs", derive(Serialize))]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct ReplicationTimeValue {
+    /// <p> Contains an integer specifying time in minutes. </p> <p> Valid values: 15 minutes. </p>
+    pub minutes: Option<i64>,
+}
+
+struct ReplicationTimeValueDeserializer;
+impl ReplicationTimeValueDeserializer {
+    #[allow(unused_variables)]
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ReplicationTimeValue, XmlParseError> {
+        deserialize_elements::<_, ReplicationTimeValue, _>(tag_name, stack, |name, stack, obj| {
+            match name {
+                "Minutes" => {
+                    obj.minutes = Some(MinutesDeserializer::deserialize("Minutes", stack)?);
+                }
+                _ => skip_tree(stack),
+            }
+            Ok(())
+        })
+    }
+}
+
+pub struct ReplicationTimeValueSerializer;
+impl ReplicationTimeValueSerializer {
+    #[allow(unused_variables, warnings)]
+    pub fn serialize<W>(
+        mut writer: &mut EventWriter<W>,
+        name: &str,
+        obj: &ReplicationTimeValue,
+    ) -> Result<(), xml::writer::Error>
+    where
+        W: Write,
+    {
+        writer.write(xml::writer::XmlEvent::start_element(name))?;
+        if let Some(ref value) = obj.minutes {
+            writer.write(xml::writer::XmlEvent::start_element("Minutes"))?;
+            writer.write(xml::writer::XmlEvent::characters(&format!(
+                "{value}",
+                value = value
+            )));
+            writer.write(xml::writer::XmlEvent::end_element())?;
+        }
+        writer.write(xml::writer::XmlEvent::end_element())
+    }
+}
+
+/// <p>If present, indicates that the requester was successfully charged for the request.</p> <note> <p>This functionality is not supported for directory buckets.</p> </note>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RequestCharged {
+    /// `requester`
+    Requester,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RequestCharged {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RequestCharged::Requester => "requester",
+            RequestCharged::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RequestCharged {
+    fn default() -> RequestCharged {
+        RequestCharged::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RequestCharged {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RequestCharged {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RequestCharged {
+    fn from(value: &'a str) -> RequestCharged {
+        match value {
+            "requester" => RequestCharged::Requester,
+            _ => RequestCharged::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RequestCharged {
+    fn from(value: String) -> RequestCharged {
+        match value.as_str() {
+            "requester" => RequestCharged::Requester,
+            _ => RequestCharged::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RequestCharged {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RequestCharged, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RequestCharged {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for RequestCharged {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for RequestCharged {
+    fn deserialize<D>(deserializer: D) -> Result<RequestCharged, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Confirms that the requester knows that they will be charged for the request. Bucket owners need not specify this parameter in their requests. If either the source or destination S3 bucket has Requester Pays enabled, the requester will pay for corresponding charges to copy the object. For information about downloading objects from Requester Pays buckets, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/ObjectsinRequesterPaysBuckets.html">Downloading Objects in Requester Pays Buckets</a> in the <i>Amazon S3 User Guide</i>.</p> <note> <p>This functionality is not supported for directory buckets.</p> </note>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RequestPayer {
+    /// `requester`
+    Requester,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RequestPayer {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RequestPayer::Requester => "requester",
+            RequestPayer::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RequestPayer {
+    fn default() -> RequestPayer {
+        RequestPayer::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RequestPayer {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RequestPayer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
-struct ReplicationTimeValueDeserializer;
-impl ReplicationTimeValueDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
-        tag_name: &str,
-        stack: &mut T,
-    ) -> Result<ReplicationTimeValue, XmlParseError> {
-        deserialize_elements::<_, ReplicationTimeValue, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "Minutes" => {
-                    obj.minutes = Some(MinutesDeserializer::deserialize("Minutes", stack)?);
-                }
-                _ => skip_tree(stack),
-            }
-            Ok(())
-        })
+impl<'a> From<&'a str> for RequestPayer {
+    fn from(value: &'a str) -> RequestPayer {
+        match value {
+            "requester" => RequestPayer::Requester,
+            _ => RequestPayer::Unknown(value.to_owned()),
+        }
     }
 }
 
-pub struct ReplicationTimeValueSerializer;
-impl ReplicationTimeValueSerializer {
-    #[allow(unused_variables, warnings)]
-    pub fn serialize<W>(
-        mut writer: &mut EventWriter<W>,
-        name: &str,
-        obj: &ReplicationTimeValue,
-    ) -> Result<(), xml::writer::Error>
+impl From<String> for RequestPayer {
+    fn from(value: String) -> RequestPayer {
+        match value.as_str() {
+            "requester" => RequestPayer::Requester,
+            _ => RequestPayer::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RequestPayer {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RequestPayer, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RequestPayer {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for RequestPayer {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        W: Write,
+        S: ::serde::Serializer,
     {
-        writer.write(xml::writer::XmlEvent::start_element(name))?;
-        if let Some(ref value) = obj.minutes {
-            writer.write(xml::writer::XmlEvent::start_element("Minutes"))?;
-            writer.write(xml::writer::XmlEvent::characters(&format!(
-                "{value}",
-                value = value
-            )));
-            writer.write(xml::writer::XmlEvent::end_element())?;
-        }
-        writer.write(xml::writer::XmlEvent::end_element())
+        serializer.serialize_str(self.as_str())
     }
 }
 
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for RequestPayer {
+    fn deserialize<D>(deserializer: D) -> Result<RequestPayer, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Container for Payer.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct RequestPaymentConfiguration {
     /// <p>Specifies who pays for the download and request fees.</p>
-    pub payer: String,
+    pub payer: Payer,
 }
 
 pub struct RequestPaymentConfigurationSerializer;
@@ -12761,7 +17112,7 @@ impl RequestPaymentConfigurationSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Payer"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.payer
+            value = &obj.payer
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::end_element())
@@ -12906,7 +17257,7 @@ impl ResponseExpiresSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &::chrono::DateTime<::chrono::Utc>,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -12914,7 +17265,7 @@ impl ResponseExpiresSerializer {
         writer.write(xml::writer::XmlEvent::start_element(name))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.to_string()
+            value = rusoto_core::serialization::TimestampFormat::Rfc822.format(obj)
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())
     }
@@ -12923,7 +17274,7 @@ impl ResponseExpiresSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct RestoreObjectOutput {
-    pub request_charged: Option<String>,
+    pub request_charged: Option<RequestCharged>,
     /// <p>Indicates the path in the provided S3 output location where Select results will be restored to.</p>
     pub restore_output_path: Option<String>,
 }
@@ -12951,7 +17302,7 @@ pub struct RestoreObjectRequest {
     pub bucket: String,
     /// <p>Object key for which the operation was initiated.</p>
     pub key: String,
-    pub request_payer: Option<String>,
+    pub request_payer: Option<RequestPayer>,
     pub restore_request: Option<RestoreRequest>,
     /// <p>VersionId used to reference a specific version of the object.</p>
     pub version_id: Option<String>,
@@ -12972,9 +17323,9 @@ pub struct RestoreRequest {
     /// <p>Describes the parameters for Select job types.</p>
     pub select_parameters: Option<SelectParameters>,
     /// <p>Glacier retrieval tier at which the restore will be processed.</p>
-    pub tier: Option<String>,
+    pub tier: Option<Tier>,
     /// <p>Type of restore request.</p>
-    pub type_: Option<String>,
+    pub type_: Option<RestoreRequestType>,
 }
 
 pub struct RestoreRequestSerializer;
@@ -13034,13 +17385,104 @@ impl RestoreRequestSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RestoreRequestType {
+    /// `SELECT`
+    Select,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RestoreRequestType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RestoreRequestType::Select => "SELECT",
+            RestoreRequestType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RestoreRequestType {
+    fn default() -> RestoreRequestType {
+        RestoreRequestType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RestoreRequestType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RestoreRequestType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RestoreRequestType {
+    fn from(value: &'a str) -> RestoreRequestType {
+        match value {
+            "SELECT" => RestoreRequestType::Select,
+            _ => RestoreRequestType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RestoreRequestType {
+    fn from(value: String) -> RestoreRequestType {
+        match value.as_str() {
+            "SELECT" => RestoreRequestType::Select,
+            _ => RestoreRequestType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RestoreRequestType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RestoreRequestType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RestoreRequestType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for RestoreRequestType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for RestoreRequestType {
+    fn deserialize<D>(deserializer: D) -> Result<RestoreRequestType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+
 pub struct RestoreRequestTypeSerializer;
 impl RestoreRequestTypeSerializer {
     #[allow(unused_variables, warnings)]
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &RestoreRequestType,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -13192,7 +17634,7 @@ pub struct Rule {
     /// <p>Object key prefix that identifies one or more objects to which this rule applies.</p>
     pub prefix: String,
     /// <p>If <code>Enabled</code>, the rule is currently being applied. If <code>Disabled</code>, the rule is not currently being applied.</p>
-    pub status: String,
+    pub status: ExpirationStatus,
     /// <p>Specifies when an object transitions to a specified storage class.</p>
     pub transition: Option<Transition>,
 }
@@ -13297,13 +17739,13 @@ impl RuleSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Prefix"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.prefix
+            value = &obj.prefix
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::start_element("Status"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.status
+            value = &obj.status
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         if let Some(ref value) = obj.transition {
@@ -13416,12 +17858,12 @@ pub struct S3Location {
     /// <p>The name of the bucket where the restore results will be placed.</p>
     pub bucket_name: String,
     /// <p>The canned ACL to apply to the restore results.</p>
-    pub canned_acl: Option<String>,
+    pub canned_acl: Option<ObjectCannedACL>,
     pub encryption: Option<Encryption>,
     /// <p>The prefix that is prepended to the restore results for this request.</p>
     pub prefix: String,
     /// <p>The class of storage used to store the restore results.</p>
-    pub storage_class: Option<String>,
+    pub storage_class: Option<StorageClass>,
     /// <p>The tag-set that is applied to the restore results.</p>
     pub tagging: Option<Tagging>,
     /// <p>A list of metadata to store with the restore results in S3.</p>
@@ -13446,7 +17888,7 @@ impl S3LocationSerializer {
         writer.write(xml::writer::XmlEvent::start_element("BucketName"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.bucket_name
+            value = &obj.bucket_name
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         if let Some(ref value) = obj.canned_acl {
@@ -13463,7 +17905,7 @@ impl S3LocationSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Prefix"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.prefix
+            value = &obj.prefix
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         if let Some(ref value) = obj.storage_class {
@@ -13524,7 +17966,7 @@ impl SSEKMSSerializer {
         writer.write(xml::writer::XmlEvent::start_element("KeyId"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.key_id
+            value = &obj.key_id
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::end_element())
@@ -13643,7 +18085,6 @@ impl ScanRangeSerializer {
 
 /// <p>The container for selecting objects from a content event stream.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
-#[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct SelectObjectContentEventStream {
     /// <p>The Continuation Event.</p>
     pub cont: Option<ContinuationEvent>,
@@ -13657,64 +18098,12 @@ pub struct SelectObjectContentEventStream {
     pub stats: Option<StatsEvent>,
 }
 
-struct SelectObjectContentEventStreamDeserializer;
-impl SelectObjectContentEventStreamDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
-        tag_name: &str,
-        stack: &mut T,
-    ) -> Result<SelectObjectContentEventStream, XmlParseError> {
-        deserialize_elements::<_, SelectObjectContentEventStream, _>(
-            tag_name,
-            stack,
-            |name, stack, obj| {
-                match name {
-                    "Cont" => {
-                        obj.cont = Some(ContinuationEventDeserializer::deserialize("Cont", stack)?);
-                    }
-                    "End" => {
-                        obj.end = Some(EndEventDeserializer::deserialize("End", stack)?);
-                    }
-                    "Progress" => {
-                        obj.progress =
-                            Some(ProgressEventDeserializer::deserialize("Progress", stack)?);
-                    }
-                    "Records" => {
-                        obj.records =
-                            Some(RecordsEventDeserializer::deserialize("Records", stack)?);
-                    }
-                    "Stats" => {
-                        obj.stats = Some(StatsEventDeserializer::deserialize("Stats", stack)?);
-                    }
-                    _ => skip_tree(stack),
-                }
-                Ok(())
-            },
-        )
-    }
-}
-#[derive(Default, Debug, Clone, PartialEq)]
-#[cfg_attr(feature = "serialize_structs", derive(Serialize))]
+#[derive(Default, Debug)]
 pub struct SelectObjectContentOutput {
     /// <p>The array of results.</p>
-    pub payload: Option<SelectObjectContentEventStream>,
+    pub payload: Option<::rusoto_core::event_stream::EventStream<SelectObjectContentEventStream>>,
 }
 
-struct SelectObjectContentOutputDeserializer;
-impl SelectObjectContentOutputDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
-        tag_name: &str,
-        stack: &mut T,
-    ) -> Result<SelectObjectContentOutput, XmlParseError> {
-        Ok(SelectObjectContentOutput {
-            payload: Some(SelectObjectContentEventStreamDeserializer::deserialize(
-                "Payload", stack,
-            )?),
-            ..SelectObjectContentOutput::default()
-        })
-    }
-}
 /// <p>Request to filter the contents of an Amazon S3 object based on a simple Structured Query Language (SQL) statement. In the request, along with the SQL expression, you must specify a data serialization format (JSON or CSV) of the object. Amazon S3 uses this to parse object data into records. It returns only records that match the specified SQL expression. You must also specify the data serialization format for the response. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/API/RESTObjectSELECTContent.html">S3Select API Documentation</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -13724,7 +18113,7 @@ pub struct SelectObjectContentRequest {
     /// <p>The expression that is used to query the object.</p>
     pub expression: String,
     /// <p>The type of the provided expression (for example, SQL).</p>
-    pub expression_type: String,
+    pub expression_type: ExpressionType,
     /// <p>Describes the format of the data in the object that is being queried.</p>
     pub input_serialization: InputSerialization,
     /// <p>The object key.</p>
@@ -13784,7 +18173,7 @@ pub struct SelectParameters {
     /// <p>The expression that is used to query the object.</p>
     pub expression: String,
     /// <p>The type of the provided expression (for example, SQL).</p>
-    pub expression_type: String,
+    pub expression_type: ExpressionType,
     /// <p>Describes the serialization format of the object.</p>
     pub input_serialization: InputSerialization,
     /// <p>Describes how the results of the Select job are serialized.</p>
@@ -13806,13 +18195,13 @@ impl SelectParametersSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Expression"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.expression
+            value = &obj.expression
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::start_element("ExpressionType"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.expression_type
+            value = &obj.expression_type
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         InputSerializationSerializer::serialize(
@@ -13829,12 +18218,115 @@ impl SelectParametersSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ServerSideEncryption {
+    /// `AES256`
+    Aes256,
+    /// `aws:kms`
+    AwsKms,
+    /// `aws:kms:dsse`
+    AwsKmsDsse,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ServerSideEncryption {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ServerSideEncryption::Aes256 => "AES256",
+            ServerSideEncryption::AwsKms => "aws:kms",
+            ServerSideEncryption::AwsKmsDsse => "aws:kms:dsse",
+            ServerSideEncryption::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ServerSideEncryption {
+    fn default() -> ServerSideEncryption {
+        ServerSideEncryption::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ServerSideEncryption {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ServerSideEncryption {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ServerSideEncryption {
+    fn from(value: &'a str) -> ServerSideEncryption {
+        match value {
+            "AES256" => ServerSideEncryption::Aes256,
+            "aws:kms" => ServerSideEncryption::AwsKms,
+            "aws:kms:dsse" => ServerSideEncryption::AwsKmsDsse,
+            _ => ServerSideEncryption::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ServerSideEncryption {
+    fn from(value: String) -> ServerSideEncryption {
+        match value.as_str() {
+            "AES256" => ServerSideEncryption::Aes256,
+            "aws:kms" => ServerSideEncryption::AwsKms,
+            "aws:kms:dsse" => ServerSideEncryption::AwsKmsDsse,
+            _ => ServerSideEncryption::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ServerSideEncryption {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ServerSideEncryption, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ServerSideEncryption {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ServerSideEncryption {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ServerSideEncryption {
+    fn deserialize<D>(deserializer: D) -> Result<ServerSideEncryption, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct ServerSideEncryptionDeserializer;
 impl ServerSideEncryptionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ServerSideEncryption, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -13847,7 +18339,7 @@ impl ServerSideEncryptionSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &ServerSideEncryption,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -13869,7 +18361,7 @@ pub struct ServerSideEncryptionByDefault {
     /// <p>KMS master key ID to use for the default encryption. This parameter is allowed if and only if <code>SSEAlgorithm</code> is set to <code>aws:kms</code>.</p>
     pub kms_master_key_id: Option<String>,
     /// <p>Server-side encryption algorithm to use for the default encryption.</p>
-    pub sse_algorithm: String,
+    pub sse_algorithm: ServerSideEncryption,
 }
 
 struct ServerSideEncryptionByDefaultDeserializer;
@@ -13925,7 +18417,7 @@ impl ServerSideEncryptionByDefaultSerializer {
         writer.write(xml::writer::XmlEvent::start_element("SSEAlgorithm"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.sse_algorithm
+            value = &obj.sse_algorithm
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::end_element())
@@ -14199,7 +18691,7 @@ impl SourceSelectionCriteriaSerializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct SseKmsEncryptedObjects {
     /// <p>Specifies whether Amazon S3 replicates objects created with server-side encryption using a customer master key (CMK) stored in AWS Key Management Service.</p>
-    pub status: String,
+    pub status: SseKmsEncryptedObjectsStatus,
 }
 
 struct SseKmsEncryptedObjectsDeserializer;
@@ -14237,19 +18729,117 @@ impl SseKmsEncryptedObjectsSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Status"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.status
+            value = &obj.status
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::end_element())
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SseKmsEncryptedObjectsStatus {
+    /// `Enabled`
+    Enabled,
+    /// `Disabled`
+    Disabled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SseKmsEncryptedObjectsStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SseKmsEncryptedObjectsStatus::Enabled => "Enabled",
+            SseKmsEncryptedObjectsStatus::Disabled => "Disabled",
+            SseKmsEncryptedObjectsStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SseKmsEncryptedObjectsStatus {
+    fn default() -> SseKmsEncryptedObjectsStatus {
+        SseKmsEncryptedObjectsStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SseKmsEncryptedObjectsStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SseKmsEncryptedObjectsStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SseKmsEncryptedObjectsStatus {
+    fn from(value: &'a str) -> SseKmsEncryptedObjectsStatus {
+        match value {
+            "Enabled" => SseKmsEncryptedObjectsStatus::Enabled,
+            "Disabled" => SseKmsEncryptedObjectsStatus::Disabled,
+            _ => SseKmsEncryptedObjectsStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SseKmsEncryptedObjectsStatus {
+    fn from(value: String) -> SseKmsEncryptedObjectsStatus {
+        match value.as_str() {
+            "Enabled" => SseKmsEncryptedObjectsStatus::Enabled,
+            "Disabled" => SseKmsEncryptedObjectsStatus::Disabled,
+            _ => SseKmsEncryptedObjectsStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SseKmsEncryptedObjectsStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SseKmsEncryptedObjectsStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SseKmsEncryptedObjectsStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for SseKmsEncryptedObjectsStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for SseKmsEncryptedObjectsStatus {
+    fn deserialize<D>(deserializer: D) -> Result<SseKmsEncryptedObjectsStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct SseKmsEncryptedObjectsStatusDeserializer;
 impl SseKmsEncryptedObjectsStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<SseKmsEncryptedObjectsStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -14262,7 +18852,7 @@ impl SseKmsEncryptedObjectsStatusSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &SseKmsEncryptedObjectsStatus,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -14370,38 +18960,162 @@ impl StatsDeserializer {
         })
     }
 }
-/// <p>Container for the Stats Event.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
-#[cfg_attr(feature = "serialize_structs", derive(Serialize))]
-pub struct StatsEvent {
-    /// <p>The Stats event details.</p>
-    pub details: Option<Stats>,
+/// <p>Container for the Stats Event.</p>
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct StatsEvent {
+    /// <p>The Stats event details.</p>
+    pub details: Option<Stats>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StorageClass {
+    /// `STANDARD`
+    Standard,
+    /// `REDUCED_REDUNDANCY`
+    ReducedRedundancy,
+    /// `STANDARD_IA`
+    StandardIa,
+    /// `ONEZONE_IA`
+    OnezoneIa,
+    /// `INTELLIGENT_TIERING`
+    IntelligentTiering,
+    /// `GLACIER`
+    Glacier,
+    /// `DEEP_ARCHIVE`
+    DeepArchive,
+    /// `OUTPOSTS`
+    Outposts,
+    /// `GLACIER_IR`
+    GlacierIr,
+    /// `SNOW`
+    Snow,
+    /// `EXPRESS_ONEZONE`
+    ExpressOnezone,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl StorageClass {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StorageClass::Standard => "STANDARD",
+            StorageClass::ReducedRedundancy => "REDUCED_REDUNDANCY",
+            StorageClass::StandardIa => "STANDARD_IA",
+            StorageClass::OnezoneIa => "ONEZONE_IA",
+            StorageClass::IntelligentTiering => "INTELLIGENT_TIERING",
+            StorageClass::Glacier => "GLACIER",
+            StorageClass::DeepArchive => "DEEP_ARCHIVE",
+            StorageClass::Outposts => "OUTPOSTS",
+            StorageClass::GlacierIr => "GLACIER_IR",
+            StorageClass::Snow => "SNOW",
+            StorageClass::ExpressOnezone => "EXPRESS_ONEZONE",
+            StorageClass::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for StorageClass {
+    fn default() -> StorageClass {
+        StorageClass::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for StorageClass {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for StorageClass {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for StorageClass {
+    fn from(value: &'a str) -> StorageClass {
+        match value {
+            "STANDARD" => StorageClass::Standard,
+            "REDUCED_REDUNDANCY" => StorageClass::ReducedRedundancy,
+            "STANDARD_IA" => StorageClass::StandardIa,
+            "ONEZONE_IA" => StorageClass::OnezoneIa,
+            "INTELLIGENT_TIERING" => StorageClass::IntelligentTiering,
+            "GLACIER" => StorageClass::Glacier,
+            "DEEP_ARCHIVE" => StorageClass::DeepArchive,
+            "OUTPOSTS" => StorageClass::Outposts,
+            "GLACIER_IR" => StorageClass::GlacierIr,
+            "SNOW" => StorageClass::Snow,
+            "EXPRESS_ONEZONE" => StorageClass::ExpressOnezone,
+            _ => StorageClass::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StorageClass {
+    fn from(value: String) -> StorageClass {
+        match value.as_str() {
+            "STANDARD" => StorageClass::Standard,
+            "REDUCED_REDUNDANCY" => StorageClass::ReducedRedundancy,
+            "STANDARD_IA" => StorageClass::StandardIa,
+            "ONEZONE_IA" => StorageClass::OnezoneIa,
+            "INTELLIGENT_TIERING" => StorageClass::IntelligentTiering,
+            "GLACIER" => StorageClass::Glacier,
+            "DEEP_ARCHIVE" => StorageClass::DeepArchive,
+            "OUTPOSTS" => StorageClass::Outposts,
+            "GLACIER_IR" => StorageClass::GlacierIr,
+            "SNOW" => StorageClass::Snow,
+            "EXPRESS_ONEZONE" => StorageClass::ExpressOnezone,
+            _ => StorageClass::Unknown(value),
+        }
+    }
 }
 
-struct StatsEventDeserializer;
-impl StatsEventDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
-        tag_name: &str,
-        stack: &mut T,
-    ) -> Result<StatsEvent, XmlParseError> {
-        deserialize_elements::<_, StatsEvent, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "Details" => {
-                    obj.details = Some(StatsDeserializer::deserialize("Details", stack)?);
-                }
-                _ => skip_tree(stack),
-            }
-            Ok(())
-        })
+impl ::std::str::FromStr for StorageClass {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<StorageClass, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StorageClass {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for StorageClass {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for StorageClass {
+    fn deserialize<D>(deserializer: D) -> Result<StorageClass, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
     }
 }
 struct StorageClassDeserializer;
 impl StorageClassDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<StorageClass, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -14414,7 +19128,7 @@ impl StorageClassSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &StorageClass,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -14487,7 +19201,7 @@ pub struct StorageClassAnalysisDataExport {
     /// <p>The place to store the data for an analysis.</p>
     pub destination: AnalyticsExportDestination,
     /// <p>The version of the output schema to use when exporting data. Must be <code>V_1</code>.</p>
-    pub output_schema_version: String,
+    pub output_schema_version: StorageClassAnalysisSchemaVersion,
 }
 
 struct StorageClassAnalysisDataExportDeserializer;
@@ -14543,19 +19257,112 @@ impl StorageClassAnalysisDataExportSerializer {
         writer.write(xml::writer::XmlEvent::start_element("OutputSchemaVersion"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.output_schema_version
+            value = &obj.output_schema_version
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::end_element())
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StorageClassAnalysisSchemaVersion {
+    /// `V_1`
+    V1,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl StorageClassAnalysisSchemaVersion {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StorageClassAnalysisSchemaVersion::V1 => "V_1",
+            StorageClassAnalysisSchemaVersion::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for StorageClassAnalysisSchemaVersion {
+    fn default() -> StorageClassAnalysisSchemaVersion {
+        StorageClassAnalysisSchemaVersion::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for StorageClassAnalysisSchemaVersion {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for StorageClassAnalysisSchemaVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for StorageClassAnalysisSchemaVersion {
+    fn from(value: &'a str) -> StorageClassAnalysisSchemaVersion {
+        match value {
+            "V_1" => StorageClassAnalysisSchemaVersion::V1,
+            _ => StorageClassAnalysisSchemaVersion::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StorageClassAnalysisSchemaVersion {
+    fn from(value: String) -> StorageClassAnalysisSchemaVersion {
+        match value.as_str() {
+            "V_1" => StorageClassAnalysisSchemaVersion::V1,
+            _ => StorageClassAnalysisSchemaVersion::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for StorageClassAnalysisSchemaVersion {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<StorageClassAnalysisSchemaVersion, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StorageClassAnalysisSchemaVersion {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for StorageClassAnalysisSchemaVersion {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for StorageClassAnalysisSchemaVersion {
+    fn deserialize<D>(deserializer: D) -> Result<StorageClassAnalysisSchemaVersion, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct StorageClassAnalysisSchemaVersionDeserializer;
 impl StorageClassAnalysisSchemaVersionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<StorageClassAnalysisSchemaVersion, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -14568,7 +19375,7 @@ impl StorageClassAnalysisSchemaVersionSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &StorageClassAnalysisSchemaVersion,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -14659,13 +19466,13 @@ impl TagSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Key"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.key
+            value = &obj.key
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::start_element("Value"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.value
+            value = &obj.value
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::end_element())
@@ -14735,6 +19542,101 @@ impl TaggingSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TaggingDirective {
+    /// `COPY`
+    Copy,
+    /// `REPLACE`
+    Replace,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TaggingDirective {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TaggingDirective::Copy => "COPY",
+            TaggingDirective::Replace => "REPLACE",
+            TaggingDirective::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TaggingDirective {
+    fn default() -> TaggingDirective {
+        TaggingDirective::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TaggingDirective {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TaggingDirective {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TaggingDirective {
+    fn from(value: &'a str) -> TaggingDirective {
+        match value {
+            "COPY" => TaggingDirective::Copy,
+            "REPLACE" => TaggingDirective::Replace,
+            _ => TaggingDirective::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TaggingDirective {
+    fn from(value: String) -> TaggingDirective {
+        match value.as_str() {
+            "COPY" => TaggingDirective::Copy,
+            "REPLACE" => TaggingDirective::Replace,
+            _ => TaggingDirective::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TaggingDirective {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TaggingDirective, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TaggingDirective {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for TaggingDirective {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for TaggingDirective {
+    fn deserialize<D>(deserializer: D) -> Result<TaggingDirective, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct TargetBucketDeserializer;
 impl TargetBucketDeserializer {
     #[allow(unused_variables)]
@@ -14775,7 +19677,7 @@ pub struct TargetGrant {
     /// <p>Container for the person being granted permissions.</p>
     pub grantee: Option<Grantee>,
     /// <p>Logging permissions assigned to the Grantee for the bucket.</p>
-    pub permission: Option<String>,
+    pub permission: Option<BucketLogsPermission>,
 }
 
 struct TargetGrantDeserializer;
@@ -14900,13 +19802,114 @@ impl TargetPrefixSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Tier {
+    /// `Standard`
+    Standard,
+    /// `Bulk`
+    Bulk,
+    /// `Expedited`
+    Expedited,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Tier {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Tier::Standard => "Standard",
+            Tier::Bulk => "Bulk",
+            Tier::Expedited => "Expedited",
+            Tier::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Tier {
+    fn default() -> Tier {
+        Tier::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Tier {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Tier {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Tier {
+    fn from(value: &'a str) -> Tier {
+        match value {
+            "Standard" => Tier::Standard,
+            "Bulk" => Tier::Bulk,
+            "Expedited" => Tier::Expedited,
+            _ => Tier::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Tier {
+    fn from(value: String) -> Tier {
+        match value.as_str() {
+            "Standard" => Tier::Standard,
+            "Bulk" => Tier::Bulk,
+            "Expedited" => Tier::Expedited,
+            _ => Tier::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Tier {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Tier, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Tier {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for Tier {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for Tier {
+    fn deserialize<D>(deserializer: D) -> Result<Tier, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+
 pub struct TierSerializer;
 impl TierSerializer {
     #[allow(unused_variables, warnings)]
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &Tier,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -14990,7 +19993,7 @@ impl TopicArnSerializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct TopicConfiguration {
     /// <p>The Amazon S3 bucket event about which to send notifications. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/NotificationHowTo.html">Supported Event Types</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p>
-    pub events: Vec<String>,
+    pub events: Vec<Event>,
     pub filter: Option<NotificationConfigurationFilter>,
     pub id: Option<String>,
     /// <p>The Amazon Resource Name (ARN) of the Amazon SNS topic to which Amazon S3 publishes a message when it detects events of the specified type.</p>
@@ -15055,7 +20058,7 @@ impl TopicConfigurationSerializer {
         writer.write(xml::writer::XmlEvent::start_element("Topic"))?;
         writer.write(xml::writer::XmlEvent::characters(&format!(
             "{value}",
-            value = obj.topic_arn
+            value = &obj.topic_arn
         )))?;
         writer.write(xml::writer::XmlEvent::end_element())?;
         writer.write(xml::writer::XmlEvent::end_element())
@@ -15068,7 +20071,7 @@ impl TopicConfigurationSerializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct TopicConfigurationDeprecated {
     /// <p>A collection of events related to objects</p>
-    pub events: Option<Vec<String>>,
+    pub events: Option<Vec<Event>>,
     pub id: Option<String>,
     /// <p>Amazon SNS topic to which Amazon S3 will publish a message to report the specified events for the bucket.</p>
     pub topic: Option<String>,
@@ -15194,11 +20197,11 @@ impl TopicConfigurationListSerializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct Transition {
     /// <p>Indicates when objects are transitioned to the specified storage class. The date value must be in ISO 8601 format. The time is always midnight UTC.</p>
-    pub date: Option<String>,
+    pub date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Indicates the number of days after creation when objects are transitioned to the specified storage class. The value must be a positive integer.</p>
     pub days: Option<i64>,
     /// <p>The storage class to which you want the object to transition.</p>
-    pub storage_class: Option<String>,
+    pub storage_class: Option<TransitionStorageClass>,
 }
 
 struct TransitionDeserializer;
@@ -15245,7 +20248,7 @@ impl TransitionSerializer {
             writer.write(xml::writer::XmlEvent::start_element("Date"))?;
             writer.write(xml::writer::XmlEvent::characters(&format!(
                 "{value}",
-                value = value
+                value = rusoto_core::serialization::TimestampFormat::Iso8601.format(value)
             )));
             writer.write(xml::writer::XmlEvent::end_element())?;
         }
@@ -15315,12 +20318,130 @@ impl TransitionListSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TransitionStorageClass {
+    /// `GLACIER`
+    Glacier,
+    /// `STANDARD_IA`
+    StandardIa,
+    /// `ONEZONE_IA`
+    OnezoneIa,
+    /// `INTELLIGENT_TIERING`
+    IntelligentTiering,
+    /// `DEEP_ARCHIVE`
+    DeepArchive,
+    /// `GLACIER_IR`
+    GlacierIr,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TransitionStorageClass {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TransitionStorageClass::Glacier => "GLACIER",
+            TransitionStorageClass::StandardIa => "STANDARD_IA",
+            TransitionStorageClass::OnezoneIa => "ONEZONE_IA",
+            TransitionStorageClass::IntelligentTiering => "INTELLIGENT_TIERING",
+            TransitionStorageClass::DeepArchive => "DEEP_ARCHIVE",
+            TransitionStorageClass::GlacierIr => "GLACIER_IR",
+            TransitionStorageClass::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TransitionStorageClass {
+    fn default() -> TransitionStorageClass {
+        TransitionStorageClass::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TransitionStorageClass {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TransitionStorageClass {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TransitionStorageClass {
+    fn from(value: &'a str) -> TransitionStorageClass {
+        match value {
+            "GLACIER" => TransitionStorageClass::Glacier,
+            "STANDARD_IA" => TransitionStorageClass::StandardIa,
+            "ONEZONE_IA" => TransitionStorageClass::OnezoneIa,
+            "INTELLIGENT_TIERING" => TransitionStorageClass::IntelligentTiering,
+            "DEEP_ARCHIVE" => TransitionStorageClass::DeepArchive,
+            "GLACIER_IR" => TransitionStorageClass::GlacierIr,
+            _ => TransitionStorageClass::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TransitionStorageClass {
+    fn from(value: String) -> TransitionStorageClass {
+        match value.as_str() {
+            "GLACIER" => TransitionStorageClass::Glacier,
+            "STANDARD_IA" => TransitionStorageClass::StandardIa,
+            "ONEZONE_IA" => TransitionStorageClass::OnezoneIa,
+            "INTELLIGENT_TIERING" => TransitionStorageClass::IntelligentTiering,
+            "DEEP_ARCHIVE" => TransitionStorageClass::DeepArchive,
+            "GLACIER_IR" => TransitionStorageClass::GlacierIr,
+            _ => TransitionStorageClass::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TransitionStorageClass {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TransitionStorageClass, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TransitionStorageClass {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for TransitionStorageClass {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for TransitionStorageClass {
+    fn deserialize<D>(deserializer: D) -> Result<TransitionStorageClass, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct TransitionStorageClassDeserializer;
 impl TransitionStorageClassDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<TransitionStorageClass, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -15333,7 +20454,7 @@ impl TransitionStorageClassSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &TransitionStorageClass,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -15347,12 +20468,112 @@ impl TransitionStorageClassSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Type {
+    /// `CanonicalUser`
+    CanonicalUser,
+    /// `AmazonCustomerByEmail`
+    AmazonCustomerByEmail,
+    /// `Group`
+    Group,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Type {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Type::CanonicalUser => "CanonicalUser",
+            Type::AmazonCustomerByEmail => "AmazonCustomerByEmail",
+            Type::Group => "Group",
+            Type::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Type {
+    fn default() -> Type {
+        Type::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Type {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Type {
+    fn from(value: &'a str) -> Type {
+        match value {
+            "CanonicalUser" => Type::CanonicalUser,
+            "AmazonCustomerByEmail" => Type::AmazonCustomerByEmail,
+            "Group" => Type::Group,
+            _ => Type::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Type {
+    fn from(value: String) -> Type {
+        match value.as_str() {
+            "CanonicalUser" => Type::CanonicalUser,
+            "AmazonCustomerByEmail" => Type::AmazonCustomerByEmail,
+            "Group" => Type::Group,
+            _ => Type::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Type {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Type, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Type {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for Type {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for Type {
+    fn deserialize<D>(deserializer: D) -> Result<Type, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct TypeDeserializer;
 impl TypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<Type, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -15365,7 +20586,7 @@ impl TypeSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &Type,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -15450,7 +20671,7 @@ pub struct UploadPartCopyOutput {
     pub copy_part_result: Option<CopyPartResult>,
     /// <p>The version of the source object that was copied, if you have enabled versioning on the source bucket.</p>
     pub copy_source_version_id: Option<String>,
-    pub request_charged: Option<String>,
+    pub request_charged: Option<RequestCharged>,
     /// <p>If server-side encryption with a customer-provided encryption key was requested, the response will include this header confirming the encryption algorithm used.</p>
     pub sse_customer_algorithm: Option<String>,
     /// <p>If server-side encryption with a customer-provided encryption key was requested, the response will include this header to provide round-trip message integrity verification of the customer-provided encryption key.</p>
@@ -15458,7 +20679,7 @@ pub struct UploadPartCopyOutput {
     /// <p>If present, specifies the ID of the AWS Key Management Service (AWS KMS) symmetric customer managed customer master key (CMK) that was used for the object.</p>
     pub ssekms_key_id: Option<String>,
     /// <p>The server-side encryption algorithm used when storing this object in Amazon S3 (for example, AES256, aws:kms).</p>
-    pub server_side_encryption: Option<String>,
+    pub server_side_encryption: Option<ServerSideEncryption>,
 }
 
 struct UploadPartCopyOutputDeserializer;
@@ -15487,11 +20708,11 @@ pub struct UploadPartCopyRequest {
     /// <p>Copies the object if its entity tag (ETag) matches the specified tag.</p>
     pub copy_source_if_match: Option<String>,
     /// <p>Copies the object if it has been modified since the specified time.</p>
-    pub copy_source_if_modified_since: Option<String>,
+    pub copy_source_if_modified_since: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Copies the object if its entity tag (ETag) is different than the specified ETag.</p>
     pub copy_source_if_none_match: Option<String>,
     /// <p>Copies the object if it hasn't been modified since the specified time.</p>
-    pub copy_source_if_unmodified_since: Option<String>,
+    pub copy_source_if_unmodified_since: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The range of bytes to copy from the source object. The range value must use the form bytes=first-last, where the first and last are the zero-based byte offsets to copy. For example, bytes=0-9 indicates that you want to copy the first 10 bytes of the source. You can copy a range only if the source object is greater than 5 MB.</p>
     pub copy_source_range: Option<String>,
     /// <p>Specifies the algorithm to use when decrypting the source object (for example, AES256).</p>
@@ -15504,7 +20725,7 @@ pub struct UploadPartCopyRequest {
     pub key: String,
     /// <p>Part number of part being copied. This is a positive integer between 1 and 10,000.</p>
     pub part_number: i64,
-    pub request_payer: Option<String>,
+    pub request_payer: Option<RequestPayer>,
     /// <p>Specifies the algorithm to use to when encrypting the object (for example, AES256).</p>
     pub sse_customer_algorithm: Option<String>,
     /// <p>Specifies the customer-provided encryption key for Amazon S3 to use in encrypting data. This value is used to store the object and then it is discarded; Amazon S3 does not store the encryption key. The key must be appropriate for use with the algorithm specified in the <code>x-amz-server-side​-encryption​-customer-algorithm</code> header. This must be the same encryption key specified in the initiate multipart upload request.</p>
@@ -15520,7 +20741,7 @@ pub struct UploadPartCopyRequest {
 pub struct UploadPartOutput {
     /// <p>Entity tag for the uploaded object.</p>
     pub e_tag: Option<String>,
-    pub request_charged: Option<String>,
+    pub request_charged: Option<RequestCharged>,
     /// <p>If server-side encryption with a customer-provided encryption key was requested, the response will include this header confirming the encryption algorithm used.</p>
     pub sse_customer_algorithm: Option<String>,
     /// <p>If server-side encryption with a customer-provided encryption key was requested, the response will include this header to provide round-trip message integrity verification of the customer-provided encryption key.</p>
@@ -15528,7 +20749,7 @@ pub struct UploadPartOutput {
     /// <p>If present, specifies the ID of the AWS Key Management Service (AWS KMS) symmetric customer managed customer master key (CMK) was used for the object.</p>
     pub ssekms_key_id: Option<String>,
     /// <p>The server-side encryption algorithm used when storing this object in Amazon S3 (for example, AES256, aws:kms).</p>
-    pub server_side_encryption: Option<String>,
+    pub server_side_encryption: Option<ServerSideEncryption>,
 }
 
 struct UploadPartOutputDeserializer;
@@ -15561,7 +20782,7 @@ pub struct UploadPartRequest {
     pub key: String,
     /// <p>Part number of part being uploaded. This is a positive integer between 1 and 10,000.</p>
     pub part_number: i64,
-    pub request_payer: Option<String>,
+    pub request_payer: Option<RequestPayer>,
     /// <p>Specifies the algorithm to use to when encrypting the object (for example, AES256).</p>
     pub sse_customer_algorithm: Option<String>,
     /// <p>Specifies the customer-provided encryption key for Amazon S3 to use in encrypting data. This value is used to store the object and then it is discarded; Amazon S3 does not store the encryption key. The key must be appropriate for use with the algorithm specified in the <code>x-amz-server-side​-encryption​-customer-algorithm header</code>. This must be the same encryption key specified in the initiate multipart upload request.</p>
@@ -15661,9 +20882,9 @@ impl VersionIdMarkerSerializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct VersioningConfiguration {
     /// <p>Specifies whether MFA delete is enabled in the bucket versioning configuration. This element is only returned if the bucket has been configured with MFA delete. If the bucket has never been so configured, this element is not returned.</p>
-    pub mfa_delete: Option<String>,
+    pub mfa_delete: Option<MFADelete>,
     /// <p>The versioning state of the bucket.</p>
-    pub status: Option<String>,
+    pub status: Option<BucketVersioningStatus>,
 }
 
 pub struct VersioningConfigurationSerializer;
@@ -18840,6 +24061,66 @@ impl fmt::Display for UploadPartCopyError {
     }
 }
 impl Error for UploadPartCopyError {}
+impl rusoto_core::event_stream::DeserializeEvent for SelectObjectContentEventStream {
+    type Error = SelectObjectContentError;
+
+    #[allow(unused_variables)]
+    fn deserialize_event(
+        event_type: &str,
+        message: &rusoto_core::event_stream::Message,
+    ) -> Result<SelectObjectContentEventStream, rusoto_core::event_stream::EventStreamError> {
+        let mut event = SelectObjectContentEventStream::default();
+        match event_type {
+            "Cont" => {
+                event.cont = Some(ContinuationEvent::default());
+            }
+            "End" => {
+                event.end = Some(EndEvent::default());
+            }
+            "Progress" => {
+                event.progress = Some(ProgressEvent {
+                    details: Some({
+                        let reader = EventReader::new_with_config(
+                            message.payload.as_ref(),
+                            ParserConfig::new().trim_whitespace(false),
+                        );
+                        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                        let _start_document = stack.next();
+                        let actual_tag_name = peek_at_name(&mut stack)?;
+                        ProgressDeserializer::deserialize(&actual_tag_name, &mut stack)?
+                    }),
+                });
+            }
+            "Records" => {
+                event.records = Some(RecordsEvent {
+                    payload: Some(message.payload.clone()),
+                });
+            }
+            "Stats" => {
+                event.stats = Some(StatsEvent {
+                    details: Some({
+                        let reader = EventReader::new_with_config(
+                            message.payload.as_ref(),
+                            ParserConfig::new().trim_whitespace(false),
+                        );
+                        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                        let _start_document = stack.next();
+                        let actual_tag_name = peek_at_name(&mut stack)?;
+                        StatsDeserializer::deserialize(&actual_tag_name, &mut stack)?
+                    }),
+                });
+            }
+            _ => {}
+        }
+        Ok(event)
+    }
+
+    fn deserialize_error(
+        error: rusoto_core::event_stream::ErrorMessage,
+    ) -> RusotoError<SelectObjectContentError> {
+        SelectObjectContentError::from_response(error.into_xml_response())
+    }
+}
 /// Trait representing the capabilities of the Amazon S3 API. Amazon S3 clients implement this trait.
 #[async_trait]
 pub trait S3 {
@@ -19394,16 +24675,19 @@ pub trait S3 {
 pub struct S3Client {
     client: Client,
     region: region::Region,
+    config: S3Config,
 }
 
 impl S3Client {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> S3Client {
         S3Client {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "S3"),
+            config: S3Config::default(),
         }
     }
 
@@ -19419,11 +24703,22 @@ impl S3Client {
         S3Client {
             client: Client::new_with(credentials_provider, request_dispatcher),
             region,
+            config: S3Config::default(),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> S3Client {
-        S3Client { client, region }
+        S3Client {
+            client,
+            region,
+            config: S3Config::default(),
+        }
+    }
+
+    /// Sets the configuration of the client.
+    pub fn with_config(mut self, config: S3Config) -> S3Client {
+        self.config = config;
+        self
     }
 }
 
@@ -19438,6 +24733,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("AbortMultipartUpload");
 
         if let Some(ref request_payer) = input.request_payer {
             request.add_header("x-amz-request-payer", &request_payer.to_string());
@@ -19446,6 +24742,10 @@ impl S3 for S3Client {
         params.put("uploadId", &input.upload_id);
         request.set_params(params);
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -19474,7 +24774,7 @@ impl S3 for S3Client {
         }
         if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
             let value = request_charged.to_owned();
-            result.request_charged = Some(value)
+            result.request_charged = Some(value.into())
         }; // parse non-payload
         Ok(result)
     }
@@ -19488,6 +24788,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("POST", "s3", &self.region, &request_uri);
+        request.set_operation("CompleteMultipartUpload");
 
         if let Some(ref request_payer) = input.request_payer {
             request.add_header("x-amz-request-payer", &request_payer.to_string());
@@ -19507,15 +24808,20 @@ impl S3 for S3Client {
             request.set_payload(Some(Vec::new()));
         }
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
-            .sign_and_dispatch(request)
+            .sign_and_dispatch_checking_body(request)
             .await
             .map_err(RusotoError::from)?;
-        if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(CompleteMultipartUploadError::from_response(response));
+        let buffered = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        if !buffered.status.is_success() || buffered.is_xml_error() {
+            return Err(CompleteMultipartUploadError::from_response(buffered));
         }
+        let mut response = rusoto_core::request::HttpResponse::from(buffered);
 
         let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
         let mut result;
@@ -19541,7 +24847,7 @@ impl S3 for S3Client {
         };
         if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
             let value = request_charged.to_owned();
-            result.request_charged = Some(value)
+            result.request_charged = Some(value.into())
         };
         if let Some(ssekms_key_id) = response
             .headers
@@ -19552,7 +24858,7 @@ impl S3 for S3Client {
         };
         if let Some(server_side_encryption) = response.headers.get("x-amz-server-side-encryption") {
             let value = server_side_encryption.to_owned();
-            result.server_side_encryption = Some(value)
+            result.server_side_encryption = Some(value.into())
         };
         if let Some(version_id) = response.headers.get("x-amz-version-id") {
             let value = version_id.to_owned();
@@ -19570,6 +24876,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("CopyObject");
 
         if let Some(ref acl) = input.acl {
             request.add_header("x-amz-acl", &acl.to_string());
@@ -19606,7 +24913,8 @@ impl S3 for S3Client {
         if let Some(ref copy_source_if_modified_since) = input.copy_source_if_modified_since {
             request.add_header(
                 "x-amz-copy-source-if-modified-since",
-                &copy_source_if_modified_since.to_string(),
+                &rusoto_core::serialization::TimestampFormat::Rfc822
+                    .format(copy_source_if_modified_since),
             );
         }
 
@@ -19620,7 +24928,8 @@ impl S3 for S3Client {
         if let Some(ref copy_source_if_unmodified_since) = input.copy_source_if_unmodified_since {
             request.add_header(
                 "x-amz-copy-source-if-unmodified-since",
-                &copy_source_if_unmodified_since.to_string(),
+                &rusoto_core::serialization::TimestampFormat::Rfc822
+                    .format(copy_source_if_unmodified_since),
             );
         }
 
@@ -19648,7 +24957,10 @@ impl S3 for S3Client {
         }
 
         if let Some(ref expires) = input.expires {
-            request.add_header("Expires", &expires.to_string());
+            request.add_header(
+                "Expires",
+                &rusoto_core::serialization::TimestampFormat::Rfc822.format(expires),
+            );
         }
 
         if let Some(ref grant_full_control) = input.grant_full_control {
@@ -19692,7 +25004,8 @@ impl S3 for S3Client {
         if let Some(ref object_lock_retain_until_date) = input.object_lock_retain_until_date {
             request.add_header(
                 "x-amz-object-lock-retain-until-date",
-                &object_lock_retain_until_date.to_string(),
+                &rusoto_core::serialization::TimestampFormat::Iso8601
+                    .format(object_lock_retain_until_date),
             );
         }
 
@@ -19761,15 +25074,20 @@ impl S3 for S3Client {
             );
         }
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
-            .sign_and_dispatch(request)
+            .sign_and_dispatch_checking_body(request)
             .await
             .map_err(RusotoError::from)?;
-        if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(CopyObjectError::from_response(response));
+        let buffered = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        if !buffered.status.is_success() || buffered.is_xml_error() {
+            return Err(CopyObjectError::from_response(buffered));
         }
+        let mut response = rusoto_core::request::HttpResponse::from(buffered);
 
         let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
         let mut result;
@@ -19796,7 +25114,7 @@ impl S3 for S3Client {
         };
         if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
             let value = request_charged.to_owned();
-            result.request_charged = Some(value)
+            result.request_charged = Some(value.into())
         };
         if let Some(sse_customer_algorithm) = response
             .headers
@@ -19827,7 +25145,7 @@ impl S3 for S3Client {
         };
         if let Some(server_side_encryption) = response.headers.get("x-amz-server-side-encryption") {
             let value = server_side_encryption.to_owned();
-            result.server_side_encryption = Some(value)
+            result.server_side_encryption = Some(value.into())
         };
         if let Some(version_id) = response.headers.get("x-amz-version-id") {
             let value = version_id.to_owned();
@@ -19845,6 +25163,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("CreateBucket");
 
         if let Some(ref acl) = input.acl {
             request.add_header("x-amz-acl", &acl.to_string());
@@ -19889,6 +25208,10 @@ impl S3 for S3Client {
             request.set_payload(Some(Vec::new()));
         }
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -19930,6 +25253,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("POST", "s3", &self.region, &request_uri);
+        request.set_operation("CreateMultipartUpload");
 
         if let Some(ref acl) = input.acl {
             request.add_header("x-amz-acl", &acl.to_string());
@@ -19956,7 +25280,10 @@ impl S3 for S3Client {
         }
 
         if let Some(ref expires) = input.expires {
-            request.add_header("Expires", &expires.to_string());
+            request.add_header(
+                "Expires",
+                &rusoto_core::serialization::TimestampFormat::Rfc822.format(expires),
+            );
         }
 
         if let Some(ref grant_full_control) = input.grant_full_control {
@@ -19996,7 +25323,8 @@ impl S3 for S3Client {
         if let Some(ref object_lock_retain_until_date) = input.object_lock_retain_until_date {
             request.add_header(
                 "x-amz-object-lock-retain-until-date",
-                &object_lock_retain_until_date.to_string(),
+                &rusoto_core::serialization::TimestampFormat::Iso8601
+                    .format(object_lock_retain_until_date),
             );
         }
 
@@ -20064,6 +25392,10 @@ impl S3 for S3Client {
         params.put_key("uploads");
         request.set_params(params);
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -20092,7 +25424,7 @@ impl S3 for S3Client {
         }
         if let Some(abort_date) = response.headers.get("x-amz-abort-date") {
             let value = abort_date.to_owned();
-            result.abort_date = Some(value)
+            result.abort_date = Some(rusoto_core::serialization::parse_timestamp(&value)?)
         };
         if let Some(abort_rule_id) = response.headers.get("x-amz-abort-rule-id") {
             let value = abort_rule_id.to_owned();
@@ -20100,7 +25432,7 @@ impl S3 for S3Client {
         };
         if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
             let value = request_charged.to_owned();
-            result.request_charged = Some(value)
+            result.request_charged = Some(value.into())
         };
         if let Some(sse_customer_algorithm) = response
             .headers
@@ -20131,7 +25463,7 @@ impl S3 for S3Client {
         };
         if let Some(server_side_encryption) = response.headers.get("x-amz-server-side-encryption") {
             let value = server_side_encryption.to_owned();
-            result.server_side_encryption = Some(value)
+            result.server_side_encryption = Some(value.into())
         }; // parse non-payload
         Ok(result)
     }
@@ -20145,6 +25477,11 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteBucket");
+
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
 
         let mut response = self
             .client
@@ -20169,12 +25506,17 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteBucketAnalyticsConfiguration");
 
         let mut params = Params::new();
         params.put("id", &input.id);
         params.put_key("analytics");
         request.set_params(params);
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -20200,11 +25542,16 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteBucketCors");
 
         let mut params = Params::new();
         params.put_key("cors");
         request.set_params(params);
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -20228,11 +25575,16 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteBucketEncryption");
 
         let mut params = Params::new();
         params.put_key("encryption");
         request.set_params(params);
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -20256,12 +25608,17 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteBucketInventoryConfiguration");
 
         let mut params = Params::new();
         params.put("id", &input.id);
         params.put_key("inventory");
         request.set_params(params);
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -20287,11 +25644,16 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteBucketLifecycle");
 
         let mut params = Params::new();
         params.put_key("lifecycle");
         request.set_params(params);
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -20315,12 +25677,17 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteBucketMetricsConfiguration");
 
         let mut params = Params::new();
         params.put("id", &input.id);
         params.put_key("metrics");
         request.set_params(params);
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -20346,11 +25713,16 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteBucketPolicy");
 
         let mut params = Params::new();
         params.put_key("policy");
         request.set_params(params);
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -20374,11 +25746,16 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteBucketReplication");
 
         let mut params = Params::new();
         params.put_key("replication");
         request.set_params(params);
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -20402,11 +25779,16 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteBucketTagging");
 
         let mut params = Params::new();
         params.put_key("tagging");
         request.set_params(params);
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -20430,11 +25812,16 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteBucketWebsite");
 
         let mut params = Params::new();
         params.put_key("website");
         request.set_params(params);
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -20458,6 +25845,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteObject");
 
         if let Some(ref bypass_governance_retention) = input.bypass_governance_retention {
             request.add_header(
@@ -20479,6 +25867,10 @@ impl S3 for S3Client {
         }
         request.set_params(params);
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -20510,7 +25902,7 @@ impl S3 for S3Client {
         };
         if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
             let value = request_charged.to_owned();
-            result.request_charged = Some(value)
+            result.request_charged = Some(value.into())
         };
         if let Some(version_id) = response.headers.get("x-amz-version-id") {
             let value = version_id.to_owned();
@@ -20528,6 +25920,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteObjectTagging");
 
         let mut params = Params::new();
         if let Some(ref x) = input.version_id {
@@ -20536,6 +25929,10 @@ impl S3 for S3Client {
         params.put_key("tagging");
         request.set_params(params);
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -20578,6 +25975,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("POST", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteObjects");
 
         if let Some(ref bypass_governance_retention) = input.bypass_governance_retention {
             request.add_header(
@@ -20600,6 +25998,9 @@ impl S3 for S3Client {
         DeleteSerializer::serialize(&mut writer, "Delete", &input.delete);
         request.set_payload(Some(writer.into_inner()));
         request.set_content_md5_header();
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
 
         let mut response = self
             .client
@@ -20628,7 +26029,7 @@ impl S3 for S3Client {
         }
         if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
             let value = request_charged.to_owned();
-            result.request_charged = Some(value)
+            result.request_charged = Some(value.into())
         }; // parse non-payload
         Ok(result)
     }
@@ -20642,11 +26043,16 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeletePublicAccessBlock");
 
         let mut params = Params::new();
         params.put_key("publicAccessBlock");
         request.set_params(params);
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -20673,11 +26079,16 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketAccelerateConfiguration");
 
         let mut params = Params::new();
         params.put_key("accelerate");
         request.set_params(params);
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -20721,11 +26132,16 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketAcl");
 
         let mut params = Params::new();
         params.put_key("acl");
         request.set_params(params);
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -20767,12 +26183,17 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketAnalyticsConfiguration");
 
         let mut params = Params::new();
         params.put("id", &input.id);
         params.put_key("analytics");
         request.set_params(params);
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -20816,11 +26237,16 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketCors");
 
         let mut params = Params::new();
         params.put_key("cors");
         request.set_params(params);
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -20859,11 +26285,16 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketEncryption");
 
         let mut params = Params::new();
         params.put_key("encryption");
         request.set_params(params);
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -20906,12 +26337,17 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketInventoryConfiguration");
 
         let mut params = Params::new();
         params.put("id", &input.id);
         params.put_key("inventory");
         request.set_params(params);
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -20955,11 +26391,16 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketLifecycle");
 
         let mut params = Params::new();
         params.put_key("lifecycle");
         request.set_params(params);
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -21002,11 +26443,16 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketLifecycleConfiguration");
 
         let mut params = Params::new();
         params.put_key("lifecycle");
         request.set_params(params);
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -21050,11 +26496,16 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketLocation");
 
         let mut params = Params::new();
         params.put_key("location");
         request.set_params(params);
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -21094,11 +26545,16 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketLogging");
 
         let mut params = Params::new();
         params.put_key("logging");
         request.set_params(params);
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -21138,12 +26594,17 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketMetricsConfiguration");
 
         let mut params = Params::new();
         params.put("id", &input.id);
         params.put_key("metrics");
         request.set_params(params);
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -21185,11 +26646,16 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketNotification");
 
         let mut params = Params::new();
         params.put_key("notification");
         request.set_params(params);
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -21232,11 +26698,16 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketNotificationConfiguration");
 
         let mut params = Params::new();
         params.put_key("notification");
         request.set_params(params);
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -21278,11 +26749,16 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketPolicy");
 
         let mut params = Params::new();
         params.put_key("policy");
         request.set_params(params);
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -21309,11 +26785,16 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketPolicyStatus");
 
         let mut params = Params::new();
         params.put_key("policyStatus");
         request.set_params(params);
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -21353,11 +26834,16 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketReplication");
 
         let mut params = Params::new();
         params.put_key("replication");
         request.set_params(params);
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -21397,11 +26883,16 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketRequestPayment");
 
         let mut params = Params::new();
         params.put_key("requestPayment");
         request.set_params(params);
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -21443,11 +26934,16 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketTagging");
 
         let mut params = Params::new();
         params.put_key("tagging");
         request.set_params(params);
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -21486,11 +26982,16 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketVersioning");
 
         let mut params = Params::new();
         params.put_key("versioning");
         request.set_params(params);
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -21530,11 +27031,16 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketWebsite");
 
         let mut params = Params::new();
         params.put_key("website");
         request.set_params(params);
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -21573,13 +27079,17 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetObject");
 
         if let Some(ref if_match) = input.if_match {
             request.add_header("If-Match", &if_match.to_string());
         }
 
         if let Some(ref if_modified_since) = input.if_modified_since {
-            request.add_header("If-Modified-Since", &if_modified_since.to_string());
+            request.add_header(
+                "If-Modified-Since",
+                &rusoto_core::serialization::TimestampFormat::Rfc822.format(if_modified_since),
+            );
         }
 
         if let Some(ref if_none_match) = input.if_none_match {
@@ -21587,7 +27097,10 @@ impl S3 for S3Client {
         }
 
         if let Some(ref if_unmodified_since) = input.if_unmodified_since {
-            request.add_header("If-Unmodified-Since", &if_unmodified_since.to_string());
+            request.add_header(
+                "If-Unmodified-Since",
+                &rusoto_core::serialization::TimestampFormat::Rfc822.format(if_unmodified_since),
+            );
         }
 
         if let Some(ref range) = input.range {
@@ -21638,13 +27151,20 @@ impl S3 for S3Client {
             params.put("response-content-type", x);
         }
         if let Some(ref x) = input.response_expires {
-            params.put("response-expires", x);
+            params.put(
+                "response-expires",
+                &rusoto_core::serialization::TimestampFormat::Rfc822.format(x),
+            );
         }
         if let Some(ref x) = input.version_id {
             params.put("versionId", x);
         }
         request.set_params(params);
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -21703,11 +27223,11 @@ impl S3 for S3Client {
         };
         if let Some(expires) = response.headers.get("Expires") {
             let value = expires.to_owned();
-            result.expires = Some(value)
+            result.expires = Some(rusoto_core::serialization::parse_timestamp(&value)?)
         };
         if let Some(last_modified) = response.headers.get("Last-Modified") {
             let value = last_modified.to_owned();
-            result.last_modified = Some(value)
+            result.last_modified = Some(rusoto_core::serialization::parse_timestamp(&value)?)
         };
         let mut values = ::std::collections::HashMap::new();
         for (key, value) in response.headers.iter() {
@@ -21727,17 +27247,18 @@ impl S3 for S3Client {
             response.headers.get("x-amz-object-lock-legal-hold")
         {
             let value = object_lock_legal_hold_status.to_owned();
-            result.object_lock_legal_hold_status = Some(value)
+            result.object_lock_legal_hold_status = Some(value.into())
         };
         if let Some(object_lock_mode) = response.headers.get("x-amz-object-lock-mode") {
             let value = object_lock_mode.to_owned();
-            result.object_lock_mode = Some(value)
+            result.object_lock_mode = Some(value.into())
         };
         if let Some(object_lock_retain_until_date) =
             response.headers.get("x-amz-object-lock-retain-until-date")
         {
             let value = object_lock_retain_until_date.to_owned();
-            result.object_lock_retain_until_date = Some(value)
+            result.object_lock_retain_until_date =
+                Some(rusoto_core::serialization::parse_timestamp(&value)?)
         };
         if let Some(parts_count) = response.headers.get("x-amz-mp-parts-count") {
             let value = parts_count.to_owned();
@@ -21745,11 +27266,11 @@ impl S3 for S3Client {
         };
         if let Some(replication_status) = response.headers.get("x-amz-replication-status") {
             let value = replication_status.to_owned();
-            result.replication_status = Some(value)
+            result.replication_status = Some(value.into())
         };
         if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
             let value = request_charged.to_owned();
-            result.request_charged = Some(value)
+            result.request_charged = Some(value.into())
         };
         if let Some(restore) = response.headers.get("x-amz-restore") {
             let value = restore.to_owned();
@@ -21778,11 +27299,11 @@ impl S3 for S3Client {
         };
         if let Some(server_side_encryption) = response.headers.get("x-amz-server-side-encryption") {
             let value = server_side_encryption.to_owned();
-            result.server_side_encryption = Some(value)
+            result.server_side_encryption = Some(value.into())
         };
         if let Some(storage_class) = response.headers.get("x-amz-storage-class") {
             let value = storage_class.to_owned();
-            result.storage_class = Some(value)
+            result.storage_class = Some(value.into())
         };
         if let Some(tag_count) = response.headers.get("x-amz-tagging-count") {
             let value = tag_count.to_owned();
@@ -21810,6 +27331,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetObjectAcl");
 
         if let Some(ref request_payer) = input.request_payer {
             request.add_header("x-amz-request-payer", &request_payer.to_string());
@@ -21821,6 +27343,10 @@ impl S3 for S3Client {
         params.put_key("acl");
         request.set_params(params);
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -21848,7 +27374,7 @@ impl S3 for S3Client {
         }
         if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
             let value = request_charged.to_owned();
-            result.request_charged = Some(value)
+            result.request_charged = Some(value.into())
         }; // parse non-payload
         Ok(result)
     }
@@ -21862,6 +27388,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetObjectLegalHold");
 
         if let Some(ref request_payer) = input.request_payer {
             request.add_header("x-amz-request-payer", &request_payer.to_string());
@@ -21873,6 +27400,10 @@ impl S3 for S3Client {
         params.put_key("legal-hold");
         request.set_params(params);
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -21913,11 +27444,16 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetObjectLockConfiguration");
 
         let mut params = Params::new();
         params.put_key("object-lock");
         request.set_params(params);
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -21959,6 +27495,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetObjectRetention");
 
         if let Some(ref request_payer) = input.request_payer {
             request.add_header("x-amz-request-payer", &request_payer.to_string());
@@ -21970,6 +27507,10 @@ impl S3 for S3Client {
         params.put_key("retention");
         request.set_params(params);
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -22009,6 +27550,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetObjectTagging");
 
         let mut params = Params::new();
         if let Some(ref x) = input.version_id {
@@ -22017,6 +27559,10 @@ impl S3 for S3Client {
         params.put_key("tagging");
         request.set_params(params);
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -22058,6 +27604,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetObjectTorrent");
 
         if let Some(ref request_payer) = input.request_payer {
             request.add_header("x-amz-request-payer", &request_payer.to_string());
@@ -22066,6 +27613,10 @@ impl S3 for S3Client {
         params.put_key("torrent");
         request.set_params(params);
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -22080,7 +27631,7 @@ impl S3 for S3Client {
         result.body = Some(response.body);
         if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
             let value = request_charged.to_owned();
-            result.request_charged = Some(value)
+            result.request_charged = Some(value.into())
         };
         Ok(result)
     }
@@ -22094,11 +27645,16 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetPublicAccessBlock");
 
         let mut params = Params::new();
         params.put_key("publicAccessBlock");
         request.set_params(params);
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -22138,6 +27694,11 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("HEAD", "s3", &self.region, &request_uri);
+        request.set_operation("HeadBucket");
+
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
 
         let mut response = self
             .client
@@ -22162,13 +27723,17 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("HEAD", "s3", &self.region, &request_uri);
+        request.set_operation("HeadObject");
 
         if let Some(ref if_match) = input.if_match {
             request.add_header("If-Match", &if_match.to_string());
         }
 
         if let Some(ref if_modified_since) = input.if_modified_since {
-            request.add_header("If-Modified-Since", &if_modified_since.to_string());
+            request.add_header(
+                "If-Modified-Since",
+                &rusoto_core::serialization::TimestampFormat::Rfc822.format(if_modified_since),
+            );
         }
 
         if let Some(ref if_none_match) = input.if_none_match {
@@ -22176,7 +27741,10 @@ impl S3 for S3Client {
         }
 
         if let Some(ref if_unmodified_since) = input.if_unmodified_since {
-            request.add_header("If-Unmodified-Since", &if_unmodified_since.to_string());
+            request.add_header(
+                "If-Unmodified-Since",
+                &rusoto_core::serialization::TimestampFormat::Rfc822.format(if_unmodified_since),
+            );
         }
 
         if let Some(ref range) = input.range {
@@ -22216,6 +27784,10 @@ impl S3 for S3Client {
         }
         request.set_params(params);
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -22283,11 +27855,11 @@ impl S3 for S3Client {
         };
         if let Some(expires) = response.headers.get("Expires") {
             let value = expires.to_owned();
-            result.expires = Some(value)
+            result.expires = Some(rusoto_core::serialization::parse_timestamp(&value)?)
         };
         if let Some(last_modified) = response.headers.get("Last-Modified") {
             let value = last_modified.to_owned();
-            result.last_modified = Some(value)
+            result.last_modified = Some(rusoto_core::serialization::parse_timestamp(&value)?)
         };
         let mut values = ::std::collections::HashMap::new();
         for (key, value) in response.headers.iter() {
@@ -22307,17 +27879,18 @@ impl S3 for S3Client {
             response.headers.get("x-amz-object-lock-legal-hold")
         {
             let value = object_lock_legal_hold_status.to_owned();
-            result.object_lock_legal_hold_status = Some(value)
+            result.object_lock_legal_hold_status = Some(value.into())
         };
         if let Some(object_lock_mode) = response.headers.get("x-amz-object-lock-mode") {
             let value = object_lock_mode.to_owned();
-            result.object_lock_mode = Some(value)
+            result.object_lock_mode = Some(value.into())
         };
         if let Some(object_lock_retain_until_date) =
             response.headers.get("x-amz-object-lock-retain-until-date")
         {
             let value = object_lock_retain_until_date.to_owned();
-            result.object_lock_retain_until_date = Some(value)
+            result.object_lock_retain_until_date =
+                Some(rusoto_core::serialization::parse_timestamp(&value)?)
         };
         if let Some(parts_count) = response.headers.get("x-amz-mp-parts-count") {
             let value = parts_count.to_owned();
@@ -22325,11 +27898,11 @@ impl S3 for S3Client {
         };
         if let Some(replication_status) = response.headers.get("x-amz-replication-status") {
             let value = replication_status.to_owned();
-            result.replication_status = Some(value)
+            result.replication_status = Some(value.into())
         };
         if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
             let value = request_charged.to_owned();
-            result.request_charged = Some(value)
+            result.request_charged = Some(value.into())
         };
         if let Some(restore) = response.headers.get("x-amz-restore") {
             let value = restore.to_owned();
@@ -22358,11 +27931,11 @@ impl S3 for S3Client {
         };
         if let Some(server_side_encryption) = response.headers.get("x-amz-server-side-encryption") {
             let value = server_side_encryption.to_owned();
-            result.server_side_encryption = Some(value)
+            result.server_side_encryption = Some(value.into())
         };
         if let Some(storage_class) = response.headers.get("x-amz-storage-class") {
             let value = storage_class.to_owned();
-            result.storage_class = Some(value)
+            result.storage_class = Some(value.into())
         };
         if let Some(version_id) = response.headers.get("x-amz-version-id") {
             let value = version_id.to_owned();
@@ -22389,6 +27962,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("ListBucketAnalyticsConfigurations");
 
         let mut params = Params::new();
         if let Some(ref x) = input.continuation_token {
@@ -22397,6 +27971,10 @@ impl S3 for S3Client {
         params.put_key("analytics");
         request.set_params(params);
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -22443,6 +28021,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("ListBucketInventoryConfigurations");
 
         let mut params = Params::new();
         if let Some(ref x) = input.continuation_token {
@@ -22451,6 +28030,10 @@ impl S3 for S3Client {
         params.put_key("inventory");
         request.set_params(params);
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -22497,6 +28080,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("ListBucketMetricsConfigurations");
 
         let mut params = Params::new();
         if let Some(ref x) = input.continuation_token {
@@ -22505,6 +28089,10 @@ impl S3 for S3Client {
         params.put_key("metrics");
         request.set_params(params);
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -22545,6 +28133,11 @@ impl S3 for S3Client {
         let request_uri = "/";
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("ListBuckets");
+
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
 
         let mut response = self
             .client
@@ -22584,6 +28177,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("ListMultipartUploads");
 
         let mut params = Params::new();
         if let Some(ref x) = input.delimiter {
@@ -22607,6 +28201,10 @@ impl S3 for S3Client {
         params.put_key("uploads");
         request.set_params(params);
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -22646,6 +28244,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("ListObjectVersions");
 
         let mut params = Params::new();
         if let Some(ref x) = input.delimiter {
@@ -22669,6 +28268,10 @@ impl S3 for S3Client {
         params.put_key("versions");
         request.set_params(params);
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -22708,6 +28311,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("ListObjects");
 
         if let Some(ref request_payer) = input.request_payer {
             request.add_header("x-amz-request-payer", &request_payer.to_string());
@@ -22730,6 +28334,10 @@ impl S3 for S3Client {
         }
         request.set_params(params);
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -22768,6 +28376,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("ListObjectsV2");
 
         if let Some(ref request_payer) = input.request_payer {
             request.add_header("x-amz-request-payer", &request_payer.to_string());
@@ -22797,6 +28406,10 @@ impl S3 for S3Client {
         params.put("list-type", "2");
         request.set_params(params);
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -22835,6 +28448,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("ListParts");
 
         if let Some(ref request_payer) = input.request_payer {
             request.add_header("x-amz-request-payer", &request_payer.to_string());
@@ -22849,6 +28463,10 @@ impl S3 for S3Client {
         params.put("uploadId", &input.upload_id);
         request.set_params(params);
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -22876,7 +28494,7 @@ impl S3 for S3Client {
         }
         if let Some(abort_date) = response.headers.get("x-amz-abort-date") {
             let value = abort_date.to_owned();
-            result.abort_date = Some(value)
+            result.abort_date = Some(rusoto_core::serialization::parse_timestamp(&value)?)
         };
         if let Some(abort_rule_id) = response.headers.get("x-amz-abort-rule-id") {
             let value = abort_rule_id.to_owned();
@@ -22884,7 +28502,7 @@ impl S3 for S3Client {
         };
         if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
             let value = request_charged.to_owned();
-            result.request_charged = Some(value)
+            result.request_charged = Some(value.into())
         }; // parse non-payload
         Ok(result)
     }
@@ -22898,6 +28516,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketAccelerateConfiguration");
 
         let mut params = Params::new();
         params.put_key("accelerate");
@@ -22910,6 +28529,10 @@ impl S3 for S3Client {
         );
         request.set_payload(Some(writer.into_inner()));
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -22935,6 +28558,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketAcl");
 
         if let Some(ref acl) = input.acl {
             request.add_header("x-amz-acl", &acl.to_string());
@@ -22978,6 +28602,10 @@ impl S3 for S3Client {
             request.set_payload(Some(Vec::new()));
         }
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -23001,6 +28629,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketAnalyticsConfiguration");
 
         let mut params = Params::new();
         params.put("id", &input.id);
@@ -23014,6 +28643,10 @@ impl S3 for S3Client {
         );
         request.set_payload(Some(writer.into_inner()));
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -23039,6 +28672,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketCors");
 
         if let Some(ref content_md5) = input.content_md5 {
             request.add_header("Content-MD5", &content_md5.to_string());
@@ -23054,6 +28688,9 @@ impl S3 for S3Client {
         );
         request.set_payload(Some(writer.into_inner()));
         request.set_content_md5_header();
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
 
         let mut response = self
             .client
@@ -23078,6 +28715,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketEncryption");
 
         if let Some(ref content_md5) = input.content_md5 {
             request.add_header("Content-MD5", &content_md5.to_string());
@@ -23093,6 +28731,10 @@ impl S3 for S3Client {
         );
         request.set_payload(Some(writer.into_inner()));
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -23116,6 +28758,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketInventoryConfiguration");
 
         let mut params = Params::new();
         params.put("id", &input.id);
@@ -23129,6 +28772,10 @@ impl S3 for S3Client {
         );
         request.set_payload(Some(writer.into_inner()));
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -23154,6 +28801,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketLifecycle");
 
         if let Some(ref content_md5) = input.content_md5 {
             request.add_header("Content-MD5", &content_md5.to_string());
@@ -23173,6 +28821,9 @@ impl S3 for S3Client {
             request.set_payload(Some(Vec::new()));
         }
         request.set_content_md5_header();
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
 
         let mut response = self
             .client
@@ -23197,6 +28848,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketLifecycleConfiguration");
 
         let mut params = Params::new();
         params.put_key("lifecycle");
@@ -23213,6 +28865,9 @@ impl S3 for S3Client {
             request.set_payload(Some(Vec::new()));
         }
         request.set_content_md5_header();
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
 
         let mut response = self
             .client
@@ -23239,6 +28894,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketLogging");
 
         if let Some(ref content_md5) = input.content_md5 {
             request.add_header("Content-MD5", &content_md5.to_string());
@@ -23254,6 +28910,10 @@ impl S3 for S3Client {
         );
         request.set_payload(Some(writer.into_inner()));
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -23277,6 +28937,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketMetricsConfiguration");
 
         let mut params = Params::new();
         params.put("id", &input.id);
@@ -23290,6 +28951,10 @@ impl S3 for S3Client {
         );
         request.set_payload(Some(writer.into_inner()));
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -23313,6 +28978,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketNotification");
 
         if let Some(ref content_md5) = input.content_md5 {
             request.add_header("Content-MD5", &content_md5.to_string());
@@ -23328,6 +28994,10 @@ impl S3 for S3Client {
         );
         request.set_payload(Some(writer.into_inner()));
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -23351,6 +29021,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketNotificationConfiguration");
 
         let mut params = Params::new();
         params.put_key("notification");
@@ -23363,6 +29034,10 @@ impl S3 for S3Client {
         );
         request.set_payload(Some(writer.into_inner()));
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -23388,6 +29063,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketPolicy");
 
         if let Some(ref confirm_remove_self_bucket_access) = input.confirm_remove_self_bucket_access
         {
@@ -23405,6 +29081,10 @@ impl S3 for S3Client {
         request.set_params(params);
         request.set_payload(Some(input.policy.into_bytes()));
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -23428,6 +29108,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketReplication");
 
         if let Some(ref content_md5) = input.content_md5 {
             request.add_header("Content-MD5", &content_md5.to_string());
@@ -23447,6 +29128,9 @@ impl S3 for S3Client {
         );
         request.set_payload(Some(writer.into_inner()));
         request.set_content_md5_header();
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
 
         let mut response = self
             .client
@@ -23471,6 +29155,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketRequestPayment");
 
         if let Some(ref content_md5) = input.content_md5 {
             request.add_header("Content-MD5", &content_md5.to_string());
@@ -23486,6 +29171,10 @@ impl S3 for S3Client {
         );
         request.set_payload(Some(writer.into_inner()));
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -23509,6 +29198,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketTagging");
 
         if let Some(ref content_md5) = input.content_md5 {
             request.add_header("Content-MD5", &content_md5.to_string());
@@ -23520,6 +29210,9 @@ impl S3 for S3Client {
         TaggingSerializer::serialize(&mut writer, "Tagging", &input.tagging);
         request.set_payload(Some(writer.into_inner()));
         request.set_content_md5_header();
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
 
         let mut response = self
             .client
@@ -23544,6 +29237,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketVersioning");
 
         if let Some(ref content_md5) = input.content_md5 {
             request.add_header("Content-MD5", &content_md5.to_string());
@@ -23563,6 +29257,10 @@ impl S3 for S3Client {
         );
         request.set_payload(Some(writer.into_inner()));
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -23586,6 +29284,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketWebsite");
 
         if let Some(ref content_md5) = input.content_md5 {
             request.add_header("Content-MD5", &content_md5.to_string());
@@ -23601,6 +29300,10 @@ impl S3 for S3Client {
         );
         request.set_payload(Some(writer.into_inner()));
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -23624,6 +29327,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutObject");
 
         if let Some(ref acl) = input.acl {
             request.add_header("x-amz-acl", &acl.to_string());
@@ -23658,7 +29362,10 @@ impl S3 for S3Client {
         }
 
         if let Some(ref expires) = input.expires {
-            request.add_header("Expires", &expires.to_string());
+            request.add_header(
+                "Expires",
+                &rusoto_core::serialization::TimestampFormat::Rfc822.format(expires),
+            );
         }
 
         if let Some(ref grant_full_control) = input.grant_full_control {
@@ -23698,7 +29405,8 @@ impl S3 for S3Client {
         if let Some(ref object_lock_retain_until_date) = input.object_lock_retain_until_date {
             request.add_header(
                 "x-amz-object-lock-retain-until-date",
-                &object_lock_retain_until_date.to_string(),
+                &rusoto_core::serialization::TimestampFormat::Iso8601
+                    .format(object_lock_retain_until_date),
             );
         }
 
@@ -23767,6 +29475,10 @@ impl S3 for S3Client {
             request.set_payload_stream(__body);
         }
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -23802,7 +29514,7 @@ impl S3 for S3Client {
         };
         if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
             let value = request_charged.to_owned();
-            result.request_charged = Some(value)
+            result.request_charged = Some(value.into())
         };
         if let Some(sse_customer_algorithm) = response
             .headers
@@ -23833,7 +29545,7 @@ impl S3 for S3Client {
         };
         if let Some(server_side_encryption) = response.headers.get("x-amz-server-side-encryption") {
             let value = server_side_encryption.to_owned();
-            result.server_side_encryption = Some(value)
+            result.server_side_encryption = Some(value.into())
         };
         if let Some(version_id) = response.headers.get("x-amz-version-id") {
             let value = version_id.to_owned();
@@ -23851,6 +29563,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutObjectAcl");
 
         if let Some(ref acl) = input.acl {
             request.add_header("x-amz-acl", &acl.to_string());
@@ -23901,6 +29614,10 @@ impl S3 for S3Client {
             request.set_payload(Some(Vec::new()));
         }
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -23928,7 +29645,7 @@ impl S3 for S3Client {
         }
         if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
             let value = request_charged.to_owned();
-            result.request_charged = Some(value)
+            result.request_charged = Some(value.into())
         }; // parse non-payload
         Ok(result)
     }
@@ -23942,6 +29659,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutObjectLegalHold");
 
         if let Some(ref content_md5) = input.content_md5 {
             request.add_header("Content-MD5", &content_md5.to_string());
@@ -23968,6 +29686,10 @@ impl S3 for S3Client {
             request.set_payload(Some(Vec::new()));
         }
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -23996,7 +29718,7 @@ impl S3 for S3Client {
         }
         if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
             let value = request_charged.to_owned();
-            result.request_charged = Some(value)
+            result.request_charged = Some(value.into())
         }; // parse non-payload
         Ok(result)
     }
@@ -24011,6 +29733,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutObjectLockConfiguration");
 
         if let Some(ref content_md5) = input.content_md5 {
             request.add_header("Content-MD5", &content_md5.to_string());
@@ -24038,6 +29761,10 @@ impl S3 for S3Client {
             request.set_payload(Some(Vec::new()));
         }
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -24068,7 +29795,7 @@ impl S3 for S3Client {
         }
         if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
             let value = request_charged.to_owned();
-            result.request_charged = Some(value)
+            result.request_charged = Some(value.into())
         }; // parse non-payload
         Ok(result)
     }
@@ -24082,6 +29809,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutObjectRetention");
 
         if let Some(ref bypass_governance_retention) = input.bypass_governance_retention {
             request.add_header(
@@ -24115,6 +29843,10 @@ impl S3 for S3Client {
             request.set_payload(Some(Vec::new()));
         }
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -24143,7 +29875,7 @@ impl S3 for S3Client {
         }
         if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
             let value = request_charged.to_owned();
-            result.request_charged = Some(value)
+            result.request_charged = Some(value.into())
         }; // parse non-payload
         Ok(result)
     }
@@ -24157,6 +29889,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutObjectTagging");
 
         if let Some(ref content_md5) = input.content_md5 {
             request.add_header("Content-MD5", &content_md5.to_string());
@@ -24171,6 +29904,10 @@ impl S3 for S3Client {
         TaggingSerializer::serialize(&mut writer, "Tagging", &input.tagging);
         request.set_payload(Some(writer.into_inner()));
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -24212,6 +29949,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutPublicAccessBlock");
 
         if let Some(ref content_md5) = input.content_md5 {
             request.add_header("Content-MD5", &content_md5.to_string());
@@ -24227,6 +29965,10 @@ impl S3 for S3Client {
         );
         request.set_payload(Some(writer.into_inner()));
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -24250,6 +29992,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("POST", "s3", &self.region, &request_uri);
+        request.set_operation("RestoreObject");
 
         if let Some(ref request_payer) = input.request_payer {
             request.add_header("x-amz-request-payer", &request_payer.to_string());
@@ -24272,6 +30015,10 @@ impl S3 for S3Client {
             request.set_payload(Some(Vec::new()));
         }
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -24299,7 +30046,7 @@ impl S3 for S3Client {
         }
         if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
             let value = request_charged.to_owned();
-            result.request_charged = Some(value)
+            result.request_charged = Some(value.into())
         };
         if let Some(restore_output_path) = response.headers.get("x-amz-restore-output-path") {
             let value = restore_output_path.to_owned();
@@ -24317,6 +30064,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("POST", "s3", &self.region, &request_uri);
+        request.set_operation("SelectObjectContent");
 
         if let Some(ref sse_customer_algorithm) = input.sse_customer_algorithm {
             request.add_header(
@@ -24351,6 +30099,10 @@ impl S3 for S3Client {
         );
         request.set_payload(Some(writer.into_inner()));
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -24361,23 +30113,9 @@ impl S3 for S3Client {
             return Err(SelectObjectContentError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-        let mut result;
+        let mut result = SelectObjectContentOutput::default();
+        result.payload = Some(rusoto_core::event_stream::EventStream::new(response.body));
 
-        if xml_response.body.is_empty() {
-            result = SelectObjectContentOutput::default();
-        } else {
-            let reader = EventReader::new_with_config(
-                xml_response.body.as_ref(),
-                ParserConfig::new().trim_whitespace(false),
-            );
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            let _start_document = stack.next();
-            let actual_tag_name = peek_at_name(&mut stack)?;
-            result =
-                SelectObjectContentOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
-        }
-        // parse non-payload
         Ok(result)
     }
 
@@ -24390,6 +30128,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("UploadPart");
 
         if let Some(ref content_length) = input.content_length {
             request.add_header("Content-Length", &content_length.to_string());
@@ -24431,6 +30170,10 @@ impl S3 for S3Client {
             request.set_payload_stream(__body);
         }
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
@@ -24462,7 +30205,7 @@ impl S3 for S3Client {
         };
         if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
             let value = request_charged.to_owned();
-            result.request_charged = Some(value)
+            result.request_charged = Some(value.into())
         };
         if let Some(sse_customer_algorithm) = response
             .headers
@@ -24487,7 +30230,7 @@ impl S3 for S3Client {
         };
         if let Some(server_side_encryption) = response.headers.get("x-amz-server-side-encryption") {
             let value = server_side_encryption.to_owned();
-            result.server_side_encryption = Some(value)
+            result.server_side_encryption = Some(value.into())
         }; // parse non-payload
         Ok(result)
     }
@@ -24501,6 +30244,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("UploadPartCopy");
 
         request.add_header("x-amz-copy-source", &input.copy_source);
 
@@ -24514,7 +30258,8 @@ impl S3 for S3Client {
         if let Some(ref copy_source_if_modified_since) = input.copy_source_if_modified_since {
             request.add_header(
                 "x-amz-copy-source-if-modified-since",
-                &copy_source_if_modified_since.to_string(),
+                &rusoto_core::serialization::TimestampFormat::Rfc822
+                    .format(copy_source_if_modified_since),
             );
         }
 
@@ -24528,7 +30273,8 @@ impl S3 for S3Client {
         if let Some(ref copy_source_if_unmodified_since) = input.copy_source_if_unmodified_since {
             request.add_header(
                 "x-amz-copy-source-if-unmodified-since",
-                &copy_source_if_unmodified_since.to_string(),
+                &rusoto_core::serialization::TimestampFormat::Rfc822
+                    .format(copy_source_if_unmodified_since),
             );
         }
 
@@ -24588,15 +30334,20 @@ impl S3 for S3Client {
         params.put("uploadId", &input.upload_id);
         request.set_params(params);
 
+        self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+
         let mut response = self
             .client
-            .sign_and_dispatch(request)
+            .sign_and_dispatch_checking_body(request)
             .await
             .map_err(RusotoError::from)?;
-        if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(UploadPartCopyError::from_response(response));
+        let buffered = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        if !buffered.status.is_success() || buffered.is_xml_error() {
+            return Err(UploadPartCopyError::from_response(buffered));
         }
+        let mut response = rusoto_core::request::HttpResponse::from(buffered);
 
         let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
         let mut result;
@@ -24619,7 +30370,7 @@ impl S3 for S3Client {
         };
         if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
             let value = request_charged.to_owned();
-            result.request_charged = Some(value)
+            result.request_charged = Some(value.into())
         };
         if let Some(sse_customer_algorithm) = response
             .headers
@@ -24644,11 +30395,341 @@ impl S3 for S3Client {
         };
         if let Some(server_side_encryption) = response.headers.get("x-amz-server-side-encryption") {
             let value = server_side_encryption.to_owned();
-            result.server_side_encryption = Some(value)
+            result.server_side_encryption = Some(value.into())
         }; // parse non-payload
         Ok(result)
     }
 }
+impl S3Client {
+    /// Auto-paginating version of `list_multipart_uploads`, streaming every page of results.
+    pub fn list_multipart_uploads_pages(
+        &self,
+        input: ListMultipartUploadsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListMultipartUploadsOutput, ListMultipartUploadsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: S3Client, input: ListMultipartUploadsRequest| async move {
+                client.list_multipart_uploads(input).await
+            },
+            |input: &ListMultipartUploadsRequest, output: &ListMultipartUploadsOutput| {
+                if !output.is_truncated.unwrap_or(false) {
+                    return None;
+                }
+                let key_marker = output.next_key_marker.clone();
+                let upload_id_marker = output.next_upload_id_marker.clone();
+                if key_marker.is_none() && upload_id_marker.is_none() {
+                    return None;
+                }
+                Some(ListMultipartUploadsRequest {
+                    key_marker,
+                    upload_id_marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_multipart_uploads`, streaming the `Uploads` of every page of results.
+    pub fn list_multipart_uploads_items(
+        &self,
+        input: ListMultipartUploadsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<MultipartUpload, ListMultipartUploadsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_multipart_uploads_pages(input),
+            |output: ListMultipartUploadsOutput| output.uploads.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_object_versions`, streaming every page of results.
+    pub fn list_object_versions_pages(
+        &self,
+        input: ListObjectVersionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListObjectVersionsOutput, ListObjectVersionsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: S3Client, input: ListObjectVersionsRequest| async move {
+                client.list_object_versions(input).await
+            },
+            |input: &ListObjectVersionsRequest, output: &ListObjectVersionsOutput| {
+                if !output.is_truncated.unwrap_or(false) {
+                    return None;
+                }
+                let key_marker = output.next_key_marker.clone();
+                let version_id_marker = output.next_version_id_marker.clone();
+                if key_marker.is_none() && version_id_marker.is_none() {
+                    return None;
+                }
+                Some(ListObjectVersionsRequest {
+                    key_marker,
+                    version_id_marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_object_versions`, streaming the `Versions` of every page of results.
+    pub fn list_object_versions_items(
+        &self,
+        input: ListObjectVersionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ObjectVersion, ListObjectVersionsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_object_versions_pages(input),
+            |output: ListObjectVersionsOutput| output.versions.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_objects`, streaming every page of results.
+    pub fn list_objects_pages(
+        &self,
+        input: ListObjectsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListObjectsOutput, ListObjectsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: S3Client, input: ListObjectsRequest| async move {
+                client.list_objects(input).await
+            },
+            |input: &ListObjectsRequest, output: &ListObjectsOutput| {
+                if !output.is_truncated.unwrap_or(false) {
+                    return None;
+                }
+                Some(ListObjectsRequest {
+                    marker: Some(output.next_marker.clone().or_else(|| {
+                        output
+                            .contents
+                            .as_ref()
+                            .and_then(|v| v.last())
+                            .and_then(|v| v.key.clone())
+                    })?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_objects`, streaming the `Contents` of every page of results.
+    pub fn list_objects_items(
+        &self,
+        input: ListObjectsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Object, ListObjectsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_objects_pages(input),
+            |output: ListObjectsOutput| output.contents.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_objects_v2`, streaming every page of results.
+    pub fn list_objects_v2_pages(
+        &self,
+        input: ListObjectsV2Request,
+    ) -> rusoto_core::pagination::RusotoStream<ListObjectsV2Output, ListObjectsV2Error> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: S3Client, input: ListObjectsV2Request| async move {
+                client.list_objects_v2(input).await
+            },
+            |input: &ListObjectsV2Request, output: &ListObjectsV2Output| {
+                if !output.is_truncated.unwrap_or(false) {
+                    return None;
+                }
+                Some(ListObjectsV2Request {
+                    continuation_token: Some(output.next_continuation_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_objects_v2`, streaming the `Contents` of every page of results.
+    pub fn list_objects_v2_items(
+        &self,
+        input: ListObjectsV2Request,
+    ) -> rusoto_core::pagination::RusotoStream<Object, ListObjectsV2Error> {
+        rusoto_core::pagination::paginate_items(
+            self.list_objects_v2_pages(input),
+            |output: ListObjectsV2Output| output.contents.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_parts`, streaming every page of results.
+    pub fn list_parts_pages(
+        &self,
+        input: ListPartsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListPartsOutput, ListPartsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: S3Client, input: ListPartsRequest| async move { client.list_parts(input).await },
+            |input: &ListPartsRequest, output: &ListPartsOutput| {
+                if !output.is_truncated.unwrap_or(false) {
+                    return None;
+                }
+                Some(ListPartsRequest {
+                    part_number_marker: Some(output.next_part_number_marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_parts`, streaming the `Parts` of every page of results.
+    pub fn list_parts_items(
+        &self,
+        input: ListPartsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Part, ListPartsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_parts_pages(input),
+            |output: ListPartsOutput| output.parts.unwrap_or_default(),
+        )
+    }
+}
+impl S3Client {
+    /// Waits until `head_bucket` reports the `BucketExists` state, polling every 5 seconds up to 20 times.
+    pub async fn wait_until_bucket_exists(
+        &self,
+        input: HeadBucketRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<HeadBucketError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(5), 20);
+        self.wait_until_bucket_exists_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_bucket_exists`, polling as often and as many times as `config` says.
+    pub async fn wait_until_bucket_exists_with_config(
+        &self,
+        input: HeadBucketRequest,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<HeadBucketError>> {
+        rusoto_core::waiter::wait(
+            "BucketExists",
+            &config,
+            || self.head_bucket(input.clone()),
+            |result: &Result<(), RusotoError<HeadBucketError>>| match result {
+                Ok(_) => Some(rusoto_core::waiter::WaiterState::Success),
+                Err(RusotoError::Unknown(response)) if response.status.as_u16() == 301 => {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Err(RusotoError::Unknown(response)) if response.status.as_u16() == 403 => {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Err(RusotoError::Service(HeadBucketError::NoSuchBucket(_))) => {
+                    Some(rusoto_core::waiter::WaiterState::Retry)
+                }
+                Err(RusotoError::Unknown(response)) if response.status.as_u16() == 404 => {
+                    Some(rusoto_core::waiter::WaiterState::Retry)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+
+    /// Waits until `head_bucket` reports the `BucketNotExists` state, polling every 5 seconds up to 20 times.
+    pub async fn wait_until_bucket_not_exists(
+        &self,
+        input: HeadBucketRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<HeadBucketError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(5), 20);
+        self.wait_until_bucket_not_exists_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_bucket_not_exists`, polling as often and as many times as `config` says.
+    pub async fn wait_until_bucket_not_exists_with_config(
+        &self,
+        input: HeadBucketRequest,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<HeadBucketError>> {
+        rusoto_core::waiter::wait(
+            "BucketNotExists",
+            &config,
+            || self.head_bucket(input.clone()),
+            |result: &Result<(), RusotoError<HeadBucketError>>| match result {
+                Err(RusotoError::Service(HeadBucketError::NoSuchBucket(_))) => {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Err(RusotoError::Unknown(response)) if response.status.as_u16() == 404 => {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+
+    /// Waits until `head_object` reports the `ObjectExists` state, polling every 5 seconds up to 20 times.
+    pub async fn wait_until_object_exists(
+        &self,
+        input: HeadObjectRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<HeadObjectError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(5), 20);
+        self.wait_until_object_exists_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_object_exists`, polling as often and as many times as `config` says.
+    pub async fn wait_until_object_exists_with_config(
+        &self,
+        input: HeadObjectRequest,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<HeadObjectError>> {
+        rusoto_core::waiter::wait(
+            "ObjectExists",
+            &config,
+            || self.head_object(input.clone()),
+            |result: &Result<HeadObjectOutput, RusotoError<HeadObjectError>>| match result {
+                Ok(_) => Some(rusoto_core::waiter::WaiterState::Success),
+                Err(RusotoError::Service(HeadObjectError::NoSuchKey(_))) => {
+                    Some(rusoto_core::waiter::WaiterState::Retry)
+                }
+                Err(RusotoError::Unknown(response)) if response.status.as_u16() == 404 => {
+                    Some(rusoto_core::waiter::WaiterState::Retry)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+
+    /// Waits until `head_object` reports the `ObjectNotExists` state, polling every 5 seconds up to 20 times.
+    pub async fn wait_until_object_not_exists(
+        &self,
+        input: HeadObjectRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<HeadObjectError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(5), 20);
+        self.wait_until_object_not_exists_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_object_not_exists`, polling as often and as many times as `config` says.
+    pub async fn wait_until_object_not_exists_with_config(
+        &self,
+        input: HeadObjectRequest,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<HeadObjectError>> {
+        rusoto_core::waiter::wait(
+            "ObjectNotExists",
+            &config,
+            || self.head_object(input.clone()),
+            |result: &Result<HeadObjectOutput, RusotoError<HeadObjectError>>| match result {
+                Err(RusotoError::Service(HeadObjectError::NoSuchKey(_))) => {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Err(RusotoError::Unknown(response)) if response.status.as_u16() == 404 => {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+}
 
 #[cfg(test)]
 mod protocol_tests {
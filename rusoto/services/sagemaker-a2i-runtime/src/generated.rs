@@ -25,6 +25,105 @@ use rusoto_core::signature::SignedRequest;
 #[allow(unused_imports)]
 use serde::{Deserialize, Serialize};
 use serde_json;
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ContentClassifier {
+    /// `FreeOfPersonallyIdentifiableInformation`
+    FreeOfPersonallyIdentifiableInformation,
+    /// `FreeOfAdultContent`
+    FreeOfAdultContent,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ContentClassifier {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ContentClassifier::FreeOfPersonallyIdentifiableInformation => {
+                "FreeOfPersonallyIdentifiableInformation"
+            }
+            ContentClassifier::FreeOfAdultContent => "FreeOfAdultContent",
+            ContentClassifier::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ContentClassifier {
+    fn default() -> ContentClassifier {
+        ContentClassifier::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ContentClassifier {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ContentClassifier {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ContentClassifier {
+    fn from(value: &'a str) -> ContentClassifier {
+        match value {
+            "FreeOfPersonallyIdentifiableInformation" => {
+                ContentClassifier::FreeOfPersonallyIdentifiableInformation
+            }
+            "FreeOfAdultContent" => ContentClassifier::FreeOfAdultContent,
+            _ => ContentClassifier::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ContentClassifier {
+    fn from(value: String) -> ContentClassifier {
+        match value.as_str() {
+            "FreeOfPersonallyIdentifiableInformation" => {
+                ContentClassifier::FreeOfPersonallyIdentifiableInformation
+            }
+            "FreeOfAdultContent" => ContentClassifier::FreeOfAdultContent,
+            _ => ContentClassifier::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ContentClassifier {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ContentClassifier, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ContentClassifier {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ContentClassifier {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ContentClassifier {
+    fn deserialize<D>(deserializer: D) -> Result<ContentClassifier, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct DeleteHumanLoopRequest {
@@ -50,7 +149,12 @@ pub struct DescribeHumanLoopRequest {
 pub struct DescribeHumanLoopResponse {
     /// <p>The timestamp when Amazon Augmented AI created the human loop.</p>
     #[serde(rename = "CreationTimestamp")]
-    pub creation_timestamp: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub creation_timestamp: ::chrono::DateTime<::chrono::Utc>,
     /// <p>A failure code denoting a specific type of failure.</p>
     #[serde(rename = "FailureCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -77,7 +181,7 @@ pub struct DescribeHumanLoopResponse {
     pub human_loop_output: Option<HumanLoopOutputContent>,
     /// <p>The status of the human loop. Valid values:</p>
     #[serde(rename = "HumanLoopStatus")]
-    pub human_loop_status: String,
+    pub human_loop_status: HumanLoopStatus,
 }
 
 /// <p>Contains information about why a human loop was triggered. If at least one activation reason is evaluated to be true, the human loop is activated.</p>
@@ -121,14 +225,127 @@ pub struct HumanLoopOutputContent {
     pub output_s3_uri: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum HumanLoopStatus {
+    /// `InProgress`
+    InProgress,
+    /// `Failed`
+    Failed,
+    /// `Completed`
+    Completed,
+    /// `Stopped`
+    Stopped,
+    /// `Stopping`
+    Stopping,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl HumanLoopStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            HumanLoopStatus::InProgress => "InProgress",
+            HumanLoopStatus::Failed => "Failed",
+            HumanLoopStatus::Completed => "Completed",
+            HumanLoopStatus::Stopped => "Stopped",
+            HumanLoopStatus::Stopping => "Stopping",
+            HumanLoopStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for HumanLoopStatus {
+    fn default() -> HumanLoopStatus {
+        HumanLoopStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for HumanLoopStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for HumanLoopStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for HumanLoopStatus {
+    fn from(value: &'a str) -> HumanLoopStatus {
+        match value {
+            "InProgress" => HumanLoopStatus::InProgress,
+            "Failed" => HumanLoopStatus::Failed,
+            "Completed" => HumanLoopStatus::Completed,
+            "Stopped" => HumanLoopStatus::Stopped,
+            "Stopping" => HumanLoopStatus::Stopping,
+            _ => HumanLoopStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for HumanLoopStatus {
+    fn from(value: String) -> HumanLoopStatus {
+        match value.as_str() {
+            "InProgress" => HumanLoopStatus::InProgress,
+            "Failed" => HumanLoopStatus::Failed,
+            "Completed" => HumanLoopStatus::Completed,
+            "Stopped" => HumanLoopStatus::Stopped,
+            "Stopping" => HumanLoopStatus::Stopping,
+            _ => HumanLoopStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for HumanLoopStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<HumanLoopStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for HumanLoopStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for HumanLoopStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for HumanLoopStatus {
+    fn deserialize<D>(deserializer: D) -> Result<HumanLoopStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Summary information about the human loop.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct HumanLoopSummary {
     /// <p>When Amazon Augmented AI created the human loop.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The reason why the human loop failed. A failure reason is returned only when the status of the human loop is <code>Failed</code>.</p>
     #[serde(rename = "FailureReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -144,7 +361,7 @@ pub struct HumanLoopSummary {
     /// <p>The status of the human loop. Valid values:</p>
     #[serde(rename = "HumanLoopStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub human_loop_status: Option<String>,
+    pub human_loop_status: Option<HumanLoopStatus>,
 }
 
 /// <p>Attributes of the data specified by the customer. Use these to describe the data to be labeled.</p>
@@ -153,7 +370,7 @@ pub struct HumanLoopSummary {
 pub struct HumanReviewDataAttributes {
     /// <p>Declares that your content is free of personally identifiable information or adult content. Amazon SageMaker may restrict the Amazon Mechanical Turk workers that can view your task based on this information.</p>
     #[serde(rename = "ContentClassifiers")]
-    pub content_classifiers: Vec<String>,
+    pub content_classifiers: Vec<ContentClassifier>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -161,12 +378,22 @@ pub struct HumanReviewDataAttributes {
 pub struct ListHumanLoopsRequest {
     /// <p>(Optional) The timestamp of the date when you want the human loops to begin. For example, <code>1551000000</code>.</p>
     #[serde(rename = "CreationTimeAfter")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time_after: Option<f64>,
+    pub creation_time_after: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>(Optional) The timestamp of the date before which you want the human loops to begin. For example, <code>1550000000</code>.</p>
     #[serde(rename = "CreationTimeBefore")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time_before: Option<f64>,
+    pub creation_time_before: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The total number of items to return. If the total number of available items is more than the value specified in <code>MaxResults</code>, then a <code>NextToken</code> will be provided in the output that you can use to resume pagination.</p>
     #[serde(rename = "MaxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -178,7 +405,7 @@ pub struct ListHumanLoopsRequest {
     /// <p>An optional value that specifies whether you want the results sorted in <code>Ascending</code> or <code>Descending</code> order.</p>
     #[serde(rename = "SortOrder")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort_order: Option<String>,
+    pub sort_order: Option<SortOrder>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -193,6 +420,99 @@ pub struct ListHumanLoopsResponse {
     pub next_token: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SortOrder {
+    /// `Ascending`
+    Ascending,
+    /// `Descending`
+    Descending,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SortOrder {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SortOrder::Ascending => "Ascending",
+            SortOrder::Descending => "Descending",
+            SortOrder::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SortOrder {
+    fn default() -> SortOrder {
+        SortOrder::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SortOrder {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SortOrder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SortOrder {
+    fn from(value: &'a str) -> SortOrder {
+        match value {
+            "Ascending" => SortOrder::Ascending,
+            "Descending" => SortOrder::Descending,
+            _ => SortOrder::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SortOrder {
+    fn from(value: String) -> SortOrder {
+        match value.as_str() {
+            "Ascending" => SortOrder::Ascending,
+            "Descending" => SortOrder::Descending,
+            _ => SortOrder::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SortOrder {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SortOrder, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SortOrder {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SortOrder {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SortOrder {
+    fn deserialize<D>(deserializer: D) -> Result<SortOrder, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct StartHumanLoopRequest {
@@ -483,11 +803,15 @@ pub struct SagemakerA2iRuntimeClient {
 impl SagemakerA2iRuntimeClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> SagemakerA2iRuntimeClient {
         SagemakerA2iRuntimeClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(
+                region,
+                "SageMaker A2I Runtime",
+            ),
         }
     }
 
@@ -524,6 +848,7 @@ impl SagemakerA2iRuntime for SagemakerA2iRuntimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "sagemaker", &self.region, &request_uri);
+        request.set_operation("DeleteHumanLoop");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("a2i-runtime.sagemaker".to_string());
@@ -556,6 +881,7 @@ impl SagemakerA2iRuntime for SagemakerA2iRuntimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "sagemaker", &self.region, &request_uri);
+        request.set_operation("DescribeHumanLoop");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("a2i-runtime.sagemaker".to_string());
@@ -585,16 +911,23 @@ impl SagemakerA2iRuntime for SagemakerA2iRuntimeClient {
         let request_uri = "/human-loops";
 
         let mut request = SignedRequest::new("GET", "sagemaker", &self.region, &request_uri);
+        request.set_operation("ListHumanLoops");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("a2i-runtime.sagemaker".to_string());
 
         let mut params = Params::new();
         if let Some(ref x) = input.creation_time_after {
-            params.put("CreationTimeAfter", x);
+            params.put(
+                "CreationTimeAfter",
+                &rusoto_core::serialization::TimestampFormat::Iso8601.format(x),
+            );
         }
         if let Some(ref x) = input.creation_time_before {
-            params.put("CreationTimeBefore", x);
+            params.put(
+                "CreationTimeBefore",
+                &rusoto_core::serialization::TimestampFormat::Iso8601.format(x),
+            );
         }
         if let Some(ref x) = input.max_results {
             params.put("MaxResults", x);
@@ -632,6 +965,7 @@ impl SagemakerA2iRuntime for SagemakerA2iRuntimeClient {
         let request_uri = "/human-loops";
 
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, &request_uri);
+        request.set_operation("StartHumanLoop");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("a2i-runtime.sagemaker".to_string());
@@ -663,6 +997,7 @@ impl SagemakerA2iRuntime for SagemakerA2iRuntimeClient {
         let request_uri = "/human-loops/stop";
 
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, &request_uri);
+        request.set_operation("StopHumanLoop");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("a2i-runtime.sagemaker".to_string());
@@ -686,3 +1021,35 @@ impl SagemakerA2iRuntime for SagemakerA2iRuntimeClient {
         }
     }
 }
+impl SagemakerA2iRuntimeClient {
+    /// Auto-paginating version of `list_human_loops`, streaming every page of results.
+    pub fn list_human_loops_pages(
+        &self,
+        input: ListHumanLoopsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListHumanLoopsResponse, ListHumanLoopsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SagemakerA2iRuntimeClient, input: ListHumanLoopsRequest| async move {
+                client.list_human_loops(input).await
+            },
+            |input: &ListHumanLoopsRequest, output: &ListHumanLoopsResponse| {
+                Some(ListHumanLoopsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_human_loops`, streaming the `HumanLoopSummaries` of every page of results.
+    pub fn list_human_loops_items(
+        &self,
+        input: ListHumanLoopsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<HumanLoopSummary, ListHumanLoopsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_human_loops_pages(input),
+            |output: ListHumanLoopsResponse| output.human_loop_summaries,
+        )
+    }
+}
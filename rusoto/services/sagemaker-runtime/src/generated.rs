@@ -134,11 +134,12 @@ pub struct SageMakerRuntimeClient {
 impl SageMakerRuntimeClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> SageMakerRuntimeClient {
         SageMakerRuntimeClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "SageMaker Runtime"),
         }
     }
 
@@ -175,6 +176,7 @@ impl SageMakerRuntime for SageMakerRuntimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, &request_uri);
+        request.set_operation("InvokeEndpoint");
         if input.content_type.is_none() {
             request.set_content_type("application/x-amz-json-1.1".to_owned());
         }
@@ -56,6 +56,99 @@ pub struct CreateSavingsPlanResponse {
     pub savings_plan_id: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CurrencyCode {
+    /// `CNY`
+    Cny,
+    /// `USD`
+    Usd,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CurrencyCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CurrencyCode::Cny => "CNY",
+            CurrencyCode::Usd => "USD",
+            CurrencyCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CurrencyCode {
+    fn default() -> CurrencyCode {
+        CurrencyCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CurrencyCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CurrencyCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CurrencyCode {
+    fn from(value: &'a str) -> CurrencyCode {
+        match value {
+            "CNY" => CurrencyCode::Cny,
+            "USD" => CurrencyCode::Usd,
+            _ => CurrencyCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CurrencyCode {
+    fn from(value: String) -> CurrencyCode {
+        match value.as_str() {
+            "CNY" => CurrencyCode::Cny,
+            "USD" => CurrencyCode::Usd,
+            _ => CurrencyCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CurrencyCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CurrencyCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CurrencyCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CurrencyCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CurrencyCode {
+    fn deserialize<D>(deserializer: D) -> Result<CurrencyCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct DescribeSavingsPlanRatesRequest {
@@ -115,7 +208,7 @@ pub struct DescribeSavingsPlansOfferingRatesRequest {
     /// <p>The AWS products.</p>
     #[serde(rename = "products")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub products: Option<Vec<String>>,
+    pub products: Option<Vec<SavingsPlanProductType>>,
     /// <p>The IDs of the offerings.</p>
     #[serde(rename = "savingsPlanOfferingIds")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -123,15 +216,15 @@ pub struct DescribeSavingsPlansOfferingRatesRequest {
     /// <p>The payment options.</p>
     #[serde(rename = "savingsPlanPaymentOptions")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub savings_plan_payment_options: Option<Vec<String>>,
+    pub savings_plan_payment_options: Option<Vec<SavingsPlanPaymentOption>>,
     /// <p>The plan types.</p>
     #[serde(rename = "savingsPlanTypes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub savings_plan_types: Option<Vec<String>>,
+    pub savings_plan_types: Option<Vec<SavingsPlanType>>,
     /// <p>The services.</p>
     #[serde(rename = "serviceCodes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub service_codes: Option<Vec<String>>,
+    pub service_codes: Option<Vec<SavingsPlanRateServiceCode>>,
     /// <p>The usage details of the line item in the billing report.</p>
     #[serde(rename = "usageTypes")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -157,7 +250,7 @@ pub struct DescribeSavingsPlansOfferingsRequest {
     /// <p>The currencies.</p>
     #[serde(rename = "currencies")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub currencies: Option<Vec<String>>,
+    pub currencies: Option<Vec<CurrencyCode>>,
     /// <p>The descriptions.</p>
     #[serde(rename = "descriptions")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -189,15 +282,15 @@ pub struct DescribeSavingsPlansOfferingsRequest {
     /// <p>The payment options.</p>
     #[serde(rename = "paymentOptions")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub payment_options: Option<Vec<String>>,
+    pub payment_options: Option<Vec<SavingsPlanPaymentOption>>,
     /// <p>The plan type.</p>
     #[serde(rename = "planTypes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub plan_types: Option<Vec<String>>,
+    pub plan_types: Option<Vec<SavingsPlanType>>,
     /// <p>The product type.</p>
     #[serde(rename = "productType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub product_type: Option<String>,
+    pub product_type: Option<SavingsPlanProductType>,
     /// <p>The services.</p>
     #[serde(rename = "serviceCodes")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -247,7 +340,7 @@ pub struct DescribeSavingsPlansRequest {
     /// <p>The states.</p>
     #[serde(rename = "states")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub states: Option<Vec<String>>,
+    pub states: Option<Vec<SavingsPlanState>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -287,7 +380,7 @@ pub struct ParentSavingsPlanOffering {
     /// <p>The currency.</p>
     #[serde(rename = "currency")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub currency: Option<String>,
+    pub currency: Option<CurrencyCode>,
     /// <p>The duration, in seconds.</p>
     #[serde(rename = "durationSeconds")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -299,7 +392,7 @@ pub struct ParentSavingsPlanOffering {
     /// <p>The payment option.</p>
     #[serde(rename = "paymentOption")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub payment_option: Option<String>,
+    pub payment_option: Option<SavingsPlanPaymentOption>,
     /// <p>The description.</p>
     #[serde(rename = "planDescription")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -307,7 +400,7 @@ pub struct ParentSavingsPlanOffering {
     /// <p>The plan type.</p>
     #[serde(rename = "planType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub plan_type: Option<String>,
+    pub plan_type: Option<SavingsPlanType>,
 }
 
 /// <p>Information about a Savings Plan.</p>
@@ -321,7 +414,7 @@ pub struct SavingsPlan {
     /// <p>The currency.</p>
     #[serde(rename = "currency")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub currency: Option<String>,
+    pub currency: Option<CurrencyCode>,
     /// <p>The description.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -341,11 +434,11 @@ pub struct SavingsPlan {
     /// <p>The payment option.</p>
     #[serde(rename = "paymentOption")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub payment_option: Option<String>,
+    pub payment_option: Option<SavingsPlanPaymentOption>,
     /// <p>The product types.</p>
     #[serde(rename = "productTypes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub product_types: Option<Vec<String>>,
+    pub product_types: Option<Vec<SavingsPlanProductType>>,
     /// <p>The recurring payment amount.</p>
     #[serde(rename = "recurringPaymentAmount")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -365,7 +458,7 @@ pub struct SavingsPlan {
     /// <p>The plan type.</p>
     #[serde(rename = "savingsPlanType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub savings_plan_type: Option<String>,
+    pub savings_plan_type: Option<SavingsPlanType>,
     /// <p>The start time.</p>
     #[serde(rename = "start")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -373,7 +466,7 @@ pub struct SavingsPlan {
     /// <p>The state.</p>
     #[serde(rename = "state")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<SavingsPlanState>,
     /// <p>One or more tags.</p>
     #[serde(rename = "tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -395,7 +488,7 @@ pub struct SavingsPlanFilter {
     /// <p>The filter name.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
+    pub name: Option<SavingsPlansFilterName>,
     /// <p>The filter value.</p>
     #[serde(rename = "values")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -409,7 +502,7 @@ pub struct SavingsPlanOffering {
     /// <p>The currency.</p>
     #[serde(rename = "currency")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub currency: Option<String>,
+    pub currency: Option<CurrencyCode>,
     /// <p>The description.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -429,15 +522,15 @@ pub struct SavingsPlanOffering {
     /// <p>The payment option.</p>
     #[serde(rename = "paymentOption")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub payment_option: Option<String>,
+    pub payment_option: Option<SavingsPlanPaymentOption>,
     /// <p>The plan type.</p>
     #[serde(rename = "planType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub plan_type: Option<String>,
+    pub plan_type: Option<SavingsPlanType>,
     /// <p>The product type.</p>
     #[serde(rename = "productTypes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub product_types: Option<Vec<String>>,
+    pub product_types: Option<Vec<SavingsPlanProductType>>,
     /// <p>The properties.</p>
     #[serde(rename = "properties")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -452,6 +545,99 @@ pub struct SavingsPlanOffering {
     pub usage_type: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SavingsPlanOfferingFilterAttribute {
+    /// `region`
+    Region,
+    /// `instanceFamily`
+    InstanceFamily,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SavingsPlanOfferingFilterAttribute {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SavingsPlanOfferingFilterAttribute::Region => "region",
+            SavingsPlanOfferingFilterAttribute::InstanceFamily => "instanceFamily",
+            SavingsPlanOfferingFilterAttribute::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SavingsPlanOfferingFilterAttribute {
+    fn default() -> SavingsPlanOfferingFilterAttribute {
+        SavingsPlanOfferingFilterAttribute::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SavingsPlanOfferingFilterAttribute {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SavingsPlanOfferingFilterAttribute {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SavingsPlanOfferingFilterAttribute {
+    fn from(value: &'a str) -> SavingsPlanOfferingFilterAttribute {
+        match value {
+            "region" => SavingsPlanOfferingFilterAttribute::Region,
+            "instanceFamily" => SavingsPlanOfferingFilterAttribute::InstanceFamily,
+            _ => SavingsPlanOfferingFilterAttribute::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SavingsPlanOfferingFilterAttribute {
+    fn from(value: String) -> SavingsPlanOfferingFilterAttribute {
+        match value.as_str() {
+            "region" => SavingsPlanOfferingFilterAttribute::Region,
+            "instanceFamily" => SavingsPlanOfferingFilterAttribute::InstanceFamily,
+            _ => SavingsPlanOfferingFilterAttribute::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SavingsPlanOfferingFilterAttribute {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SavingsPlanOfferingFilterAttribute, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SavingsPlanOfferingFilterAttribute {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SavingsPlanOfferingFilterAttribute {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SavingsPlanOfferingFilterAttribute {
+    fn deserialize<D>(deserializer: D) -> Result<SavingsPlanOfferingFilterAttribute, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about a filter.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -459,7 +645,7 @@ pub struct SavingsPlanOfferingFilterElement {
     /// <p>The filter name.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
+    pub name: Option<SavingsPlanOfferingFilterAttribute>,
     /// <p>The filter values.</p>
     #[serde(rename = "values")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -473,13 +659,106 @@ pub struct SavingsPlanOfferingProperty {
     /// <p>The property name.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
+    pub name: Option<SavingsPlanOfferingPropertyKey>,
     /// <p>The property value.</p>
     #[serde(rename = "value")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SavingsPlanOfferingPropertyKey {
+    /// `region`
+    Region,
+    /// `instanceFamily`
+    InstanceFamily,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SavingsPlanOfferingPropertyKey {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SavingsPlanOfferingPropertyKey::Region => "region",
+            SavingsPlanOfferingPropertyKey::InstanceFamily => "instanceFamily",
+            SavingsPlanOfferingPropertyKey::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SavingsPlanOfferingPropertyKey {
+    fn default() -> SavingsPlanOfferingPropertyKey {
+        SavingsPlanOfferingPropertyKey::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SavingsPlanOfferingPropertyKey {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SavingsPlanOfferingPropertyKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SavingsPlanOfferingPropertyKey {
+    fn from(value: &'a str) -> SavingsPlanOfferingPropertyKey {
+        match value {
+            "region" => SavingsPlanOfferingPropertyKey::Region,
+            "instanceFamily" => SavingsPlanOfferingPropertyKey::InstanceFamily,
+            _ => SavingsPlanOfferingPropertyKey::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SavingsPlanOfferingPropertyKey {
+    fn from(value: String) -> SavingsPlanOfferingPropertyKey {
+        match value.as_str() {
+            "region" => SavingsPlanOfferingPropertyKey::Region,
+            "instanceFamily" => SavingsPlanOfferingPropertyKey::InstanceFamily,
+            _ => SavingsPlanOfferingPropertyKey::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SavingsPlanOfferingPropertyKey {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SavingsPlanOfferingPropertyKey, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SavingsPlanOfferingPropertyKey {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SavingsPlanOfferingPropertyKey {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SavingsPlanOfferingPropertyKey {
+    fn deserialize<D>(deserializer: D) -> Result<SavingsPlanOfferingPropertyKey, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about a Savings Plan offering rate.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -491,7 +770,7 @@ pub struct SavingsPlanOfferingRate {
     /// <p>The product type.</p>
     #[serde(rename = "productType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub product_type: Option<String>,
+    pub product_type: Option<SavingsPlanProductType>,
     /// <p>The properties.</p>
     #[serde(rename = "properties")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -507,11 +786,11 @@ pub struct SavingsPlanOfferingRate {
     /// <p>The service.</p>
     #[serde(rename = "serviceCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub service_code: Option<String>,
+    pub service_code: Option<SavingsPlanRateServiceCode>,
     /// <p>The unit.</p>
     #[serde(rename = "unit")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub unit: Option<String>,
+    pub unit: Option<SavingsPlanRateUnit>,
     /// <p>The usage details of the line item in the billing report.</p>
     #[serde(rename = "usageType")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -525,7 +804,7 @@ pub struct SavingsPlanOfferingRateFilterElement {
     /// <p>The filter name.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
+    pub name: Option<SavingsPlanRateFilterAttribute>,
     /// <p>The filter values.</p>
     #[serde(rename = "values")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -546,6 +825,207 @@ pub struct SavingsPlanOfferingRateProperty {
     pub value: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SavingsPlanPaymentOption {
+    /// `All Upfront`
+    AllUpfront,
+    /// `Partial Upfront`
+    PartialUpfront,
+    /// `No Upfront`
+    NoUpfront,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SavingsPlanPaymentOption {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SavingsPlanPaymentOption::AllUpfront => "All Upfront",
+            SavingsPlanPaymentOption::PartialUpfront => "Partial Upfront",
+            SavingsPlanPaymentOption::NoUpfront => "No Upfront",
+            SavingsPlanPaymentOption::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SavingsPlanPaymentOption {
+    fn default() -> SavingsPlanPaymentOption {
+        SavingsPlanPaymentOption::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SavingsPlanPaymentOption {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SavingsPlanPaymentOption {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SavingsPlanPaymentOption {
+    fn from(value: &'a str) -> SavingsPlanPaymentOption {
+        match value {
+            "All Upfront" => SavingsPlanPaymentOption::AllUpfront,
+            "Partial Upfront" => SavingsPlanPaymentOption::PartialUpfront,
+            "No Upfront" => SavingsPlanPaymentOption::NoUpfront,
+            _ => SavingsPlanPaymentOption::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SavingsPlanPaymentOption {
+    fn from(value: String) -> SavingsPlanPaymentOption {
+        match value.as_str() {
+            "All Upfront" => SavingsPlanPaymentOption::AllUpfront,
+            "Partial Upfront" => SavingsPlanPaymentOption::PartialUpfront,
+            "No Upfront" => SavingsPlanPaymentOption::NoUpfront,
+            _ => SavingsPlanPaymentOption::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SavingsPlanPaymentOption {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SavingsPlanPaymentOption, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SavingsPlanPaymentOption {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SavingsPlanPaymentOption {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SavingsPlanPaymentOption {
+    fn deserialize<D>(deserializer: D) -> Result<SavingsPlanPaymentOption, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SavingsPlanProductType {
+    /// `EC2`
+    Ec2,
+    /// `Fargate`
+    Fargate,
+    /// `Lambda`
+    Lambda,
+    /// `SageMaker`
+    SageMaker,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SavingsPlanProductType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SavingsPlanProductType::Ec2 => "EC2",
+            SavingsPlanProductType::Fargate => "Fargate",
+            SavingsPlanProductType::Lambda => "Lambda",
+            SavingsPlanProductType::SageMaker => "SageMaker",
+            SavingsPlanProductType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SavingsPlanProductType {
+    fn default() -> SavingsPlanProductType {
+        SavingsPlanProductType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SavingsPlanProductType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SavingsPlanProductType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SavingsPlanProductType {
+    fn from(value: &'a str) -> SavingsPlanProductType {
+        match value {
+            "EC2" => SavingsPlanProductType::Ec2,
+            "Fargate" => SavingsPlanProductType::Fargate,
+            "Lambda" => SavingsPlanProductType::Lambda,
+            "SageMaker" => SavingsPlanProductType::SageMaker,
+            _ => SavingsPlanProductType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SavingsPlanProductType {
+    fn from(value: String) -> SavingsPlanProductType {
+        match value.as_str() {
+            "EC2" => SavingsPlanProductType::Ec2,
+            "Fargate" => SavingsPlanProductType::Fargate,
+            "Lambda" => SavingsPlanProductType::Lambda,
+            "SageMaker" => SavingsPlanProductType::SageMaker,
+            _ => SavingsPlanProductType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SavingsPlanProductType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SavingsPlanProductType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SavingsPlanProductType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SavingsPlanProductType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SavingsPlanProductType {
+    fn deserialize<D>(deserializer: D) -> Result<SavingsPlanProductType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about a Savings Plan rate.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -553,7 +1033,7 @@ pub struct SavingsPlanRate {
     /// <p>The currency.</p>
     #[serde(rename = "currency")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub currency: Option<String>,
+    pub currency: Option<CurrencyCode>,
     /// <p>The specific AWS operation for the line item in the billing report.</p>
     #[serde(rename = "operation")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -561,7 +1041,7 @@ pub struct SavingsPlanRate {
     /// <p>The product type.</p>
     #[serde(rename = "productType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub product_type: Option<String>,
+    pub product_type: Option<SavingsPlanProductType>,
     /// <p>The properties.</p>
     #[serde(rename = "properties")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -573,11 +1053,11 @@ pub struct SavingsPlanRate {
     /// <p>The service.</p>
     #[serde(rename = "serviceCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub service_code: Option<String>,
+    pub service_code: Option<SavingsPlanRateServiceCode>,
     /// <p>The unit.</p>
     #[serde(rename = "unit")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub unit: Option<String>,
+    pub unit: Option<SavingsPlanRateUnit>,
     /// <p>The usage details of the line item in the billing report.</p>
     #[serde(rename = "usageType")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -591,56 +1071,945 @@ pub struct SavingsPlanRateFilter {
     /// <p>The filter name.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
+    pub name: Option<SavingsPlanRateFilterName>,
     /// <p>The filter values.</p>
     #[serde(rename = "values")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub values: Option<Vec<String>>,
 }
 
-/// <p>Information about a property.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct SavingsPlanRateProperty {
-    /// <p>The property name.</p>
-    #[serde(rename = "name")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
-    /// <p>The property value.</p>
-    #[serde(rename = "value")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub value: Option<String>,
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SavingsPlanRateFilterAttribute {
+    /// `region`
+    Region,
+    /// `instanceFamily`
+    InstanceFamily,
+    /// `instanceType`
+    InstanceType,
+    /// `productDescription`
+    ProductDescription,
+    /// `tenancy`
+    Tenancy,
+    /// `productId`
+    ProductId,
+    /// A value not known to this version of the crate
+    Unknown(String),
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct TagResourceRequest {
-    /// <p>The Amazon Resource Name (ARN) of the resource.</p>
-    #[serde(rename = "resourceArn")]
-    pub resource_arn: String,
-    /// <p>One or more tags. For example, { "tags": {"key1":"value1", "key2":"value2"} }.</p>
-    #[serde(rename = "tags")]
-    pub tags: ::std::collections::HashMap<String, String>,
+impl SavingsPlanRateFilterAttribute {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SavingsPlanRateFilterAttribute::Region => "region",
+            SavingsPlanRateFilterAttribute::InstanceFamily => "instanceFamily",
+            SavingsPlanRateFilterAttribute::InstanceType => "instanceType",
+            SavingsPlanRateFilterAttribute::ProductDescription => "productDescription",
+            SavingsPlanRateFilterAttribute::Tenancy => "tenancy",
+            SavingsPlanRateFilterAttribute::ProductId => "productId",
+            SavingsPlanRateFilterAttribute::Unknown(ref value) => value,
+        }
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct TagResourceResponse {}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct UntagResourceRequest {
-    /// <p>The Amazon Resource Name (ARN) of the resource.</p>
-    #[serde(rename = "resourceArn")]
-    pub resource_arn: String,
-    /// <p>The tag keys.</p>
-    #[serde(rename = "tagKeys")]
-    pub tag_keys: Vec<String>,
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SavingsPlanRateFilterAttribute {
+    fn default() -> SavingsPlanRateFilterAttribute {
+        SavingsPlanRateFilterAttribute::Unknown(String::new())
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct UntagResourceResponse {}
+impl AsRef<str> for SavingsPlanRateFilterAttribute {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SavingsPlanRateFilterAttribute {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SavingsPlanRateFilterAttribute {
+    fn from(value: &'a str) -> SavingsPlanRateFilterAttribute {
+        match value {
+            "region" => SavingsPlanRateFilterAttribute::Region,
+            "instanceFamily" => SavingsPlanRateFilterAttribute::InstanceFamily,
+            "instanceType" => SavingsPlanRateFilterAttribute::InstanceType,
+            "productDescription" => SavingsPlanRateFilterAttribute::ProductDescription,
+            "tenancy" => SavingsPlanRateFilterAttribute::Tenancy,
+            "productId" => SavingsPlanRateFilterAttribute::ProductId,
+            _ => SavingsPlanRateFilterAttribute::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SavingsPlanRateFilterAttribute {
+    fn from(value: String) -> SavingsPlanRateFilterAttribute {
+        match value.as_str() {
+            "region" => SavingsPlanRateFilterAttribute::Region,
+            "instanceFamily" => SavingsPlanRateFilterAttribute::InstanceFamily,
+            "instanceType" => SavingsPlanRateFilterAttribute::InstanceType,
+            "productDescription" => SavingsPlanRateFilterAttribute::ProductDescription,
+            "tenancy" => SavingsPlanRateFilterAttribute::Tenancy,
+            "productId" => SavingsPlanRateFilterAttribute::ProductId,
+            _ => SavingsPlanRateFilterAttribute::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SavingsPlanRateFilterAttribute {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SavingsPlanRateFilterAttribute, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SavingsPlanRateFilterAttribute {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SavingsPlanRateFilterAttribute {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SavingsPlanRateFilterAttribute {
+    fn deserialize<D>(deserializer: D) -> Result<SavingsPlanRateFilterAttribute, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SavingsPlanRateFilterName {
+    /// `region`
+    Region,
+    /// `instanceType`
+    InstanceType,
+    /// `productDescription`
+    ProductDescription,
+    /// `tenancy`
+    Tenancy,
+    /// `productType`
+    ProductType,
+    /// `serviceCode`
+    ServiceCode,
+    /// `usageType`
+    UsageType,
+    /// `operation`
+    Operation,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SavingsPlanRateFilterName {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SavingsPlanRateFilterName::Region => "region",
+            SavingsPlanRateFilterName::InstanceType => "instanceType",
+            SavingsPlanRateFilterName::ProductDescription => "productDescription",
+            SavingsPlanRateFilterName::Tenancy => "tenancy",
+            SavingsPlanRateFilterName::ProductType => "productType",
+            SavingsPlanRateFilterName::ServiceCode => "serviceCode",
+            SavingsPlanRateFilterName::UsageType => "usageType",
+            SavingsPlanRateFilterName::Operation => "operation",
+            SavingsPlanRateFilterName::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SavingsPlanRateFilterName {
+    fn default() -> SavingsPlanRateFilterName {
+        SavingsPlanRateFilterName::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SavingsPlanRateFilterName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SavingsPlanRateFilterName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SavingsPlanRateFilterName {
+    fn from(value: &'a str) -> SavingsPlanRateFilterName {
+        match value {
+            "region" => SavingsPlanRateFilterName::Region,
+            "instanceType" => SavingsPlanRateFilterName::InstanceType,
+            "productDescription" => SavingsPlanRateFilterName::ProductDescription,
+            "tenancy" => SavingsPlanRateFilterName::Tenancy,
+            "productType" => SavingsPlanRateFilterName::ProductType,
+            "serviceCode" => SavingsPlanRateFilterName::ServiceCode,
+            "usageType" => SavingsPlanRateFilterName::UsageType,
+            "operation" => SavingsPlanRateFilterName::Operation,
+            _ => SavingsPlanRateFilterName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SavingsPlanRateFilterName {
+    fn from(value: String) -> SavingsPlanRateFilterName {
+        match value.as_str() {
+            "region" => SavingsPlanRateFilterName::Region,
+            "instanceType" => SavingsPlanRateFilterName::InstanceType,
+            "productDescription" => SavingsPlanRateFilterName::ProductDescription,
+            "tenancy" => SavingsPlanRateFilterName::Tenancy,
+            "productType" => SavingsPlanRateFilterName::ProductType,
+            "serviceCode" => SavingsPlanRateFilterName::ServiceCode,
+            "usageType" => SavingsPlanRateFilterName::UsageType,
+            "operation" => SavingsPlanRateFilterName::Operation,
+            _ => SavingsPlanRateFilterName::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SavingsPlanRateFilterName {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SavingsPlanRateFilterName, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SavingsPlanRateFilterName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SavingsPlanRateFilterName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SavingsPlanRateFilterName {
+    fn deserialize<D>(deserializer: D) -> Result<SavingsPlanRateFilterName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Information about a property.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct SavingsPlanRateProperty {
+    /// <p>The property name.</p>
+    #[serde(rename = "name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<SavingsPlanRatePropertyKey>,
+    /// <p>The property value.</p>
+    #[serde(rename = "value")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SavingsPlanRatePropertyKey {
+    /// `region`
+    Region,
+    /// `instanceType`
+    InstanceType,
+    /// `instanceFamily`
+    InstanceFamily,
+    /// `productDescription`
+    ProductDescription,
+    /// `tenancy`
+    Tenancy,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SavingsPlanRatePropertyKey {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SavingsPlanRatePropertyKey::Region => "region",
+            SavingsPlanRatePropertyKey::InstanceType => "instanceType",
+            SavingsPlanRatePropertyKey::InstanceFamily => "instanceFamily",
+            SavingsPlanRatePropertyKey::ProductDescription => "productDescription",
+            SavingsPlanRatePropertyKey::Tenancy => "tenancy",
+            SavingsPlanRatePropertyKey::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SavingsPlanRatePropertyKey {
+    fn default() -> SavingsPlanRatePropertyKey {
+        SavingsPlanRatePropertyKey::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SavingsPlanRatePropertyKey {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SavingsPlanRatePropertyKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SavingsPlanRatePropertyKey {
+    fn from(value: &'a str) -> SavingsPlanRatePropertyKey {
+        match value {
+            "region" => SavingsPlanRatePropertyKey::Region,
+            "instanceType" => SavingsPlanRatePropertyKey::InstanceType,
+            "instanceFamily" => SavingsPlanRatePropertyKey::InstanceFamily,
+            "productDescription" => SavingsPlanRatePropertyKey::ProductDescription,
+            "tenancy" => SavingsPlanRatePropertyKey::Tenancy,
+            _ => SavingsPlanRatePropertyKey::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SavingsPlanRatePropertyKey {
+    fn from(value: String) -> SavingsPlanRatePropertyKey {
+        match value.as_str() {
+            "region" => SavingsPlanRatePropertyKey::Region,
+            "instanceType" => SavingsPlanRatePropertyKey::InstanceType,
+            "instanceFamily" => SavingsPlanRatePropertyKey::InstanceFamily,
+            "productDescription" => SavingsPlanRatePropertyKey::ProductDescription,
+            "tenancy" => SavingsPlanRatePropertyKey::Tenancy,
+            _ => SavingsPlanRatePropertyKey::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SavingsPlanRatePropertyKey {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SavingsPlanRatePropertyKey, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SavingsPlanRatePropertyKey {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SavingsPlanRatePropertyKey {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SavingsPlanRatePropertyKey {
+    fn deserialize<D>(deserializer: D) -> Result<SavingsPlanRatePropertyKey, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SavingsPlanRateServiceCode {
+    /// `AmazonEC2`
+    AmazonEC2,
+    /// `AmazonECS`
+    AmazonECS,
+    /// `AmazonEKS`
+    AmazonEKS,
+    /// `AWSLambda`
+    AWSLambda,
+    /// `AmazonSageMaker`
+    AmazonSageMaker,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SavingsPlanRateServiceCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SavingsPlanRateServiceCode::AmazonEC2 => "AmazonEC2",
+            SavingsPlanRateServiceCode::AmazonECS => "AmazonECS",
+            SavingsPlanRateServiceCode::AmazonEKS => "AmazonEKS",
+            SavingsPlanRateServiceCode::AWSLambda => "AWSLambda",
+            SavingsPlanRateServiceCode::AmazonSageMaker => "AmazonSageMaker",
+            SavingsPlanRateServiceCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SavingsPlanRateServiceCode {
+    fn default() -> SavingsPlanRateServiceCode {
+        SavingsPlanRateServiceCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SavingsPlanRateServiceCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SavingsPlanRateServiceCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SavingsPlanRateServiceCode {
+    fn from(value: &'a str) -> SavingsPlanRateServiceCode {
+        match value {
+            "AmazonEC2" => SavingsPlanRateServiceCode::AmazonEC2,
+            "AmazonECS" => SavingsPlanRateServiceCode::AmazonECS,
+            "AmazonEKS" => SavingsPlanRateServiceCode::AmazonEKS,
+            "AWSLambda" => SavingsPlanRateServiceCode::AWSLambda,
+            "AmazonSageMaker" => SavingsPlanRateServiceCode::AmazonSageMaker,
+            _ => SavingsPlanRateServiceCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SavingsPlanRateServiceCode {
+    fn from(value: String) -> SavingsPlanRateServiceCode {
+        match value.as_str() {
+            "AmazonEC2" => SavingsPlanRateServiceCode::AmazonEC2,
+            "AmazonECS" => SavingsPlanRateServiceCode::AmazonECS,
+            "AmazonEKS" => SavingsPlanRateServiceCode::AmazonEKS,
+            "AWSLambda" => SavingsPlanRateServiceCode::AWSLambda,
+            "AmazonSageMaker" => SavingsPlanRateServiceCode::AmazonSageMaker,
+            _ => SavingsPlanRateServiceCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SavingsPlanRateServiceCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SavingsPlanRateServiceCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SavingsPlanRateServiceCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SavingsPlanRateServiceCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SavingsPlanRateServiceCode {
+    fn deserialize<D>(deserializer: D) -> Result<SavingsPlanRateServiceCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SavingsPlanRateUnit {
+    /// `Hrs`
+    Hrs,
+    /// `Lambda-GB-Second`
+    LambdaGbSecond,
+    /// `Request`
+    Request,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SavingsPlanRateUnit {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SavingsPlanRateUnit::Hrs => "Hrs",
+            SavingsPlanRateUnit::LambdaGbSecond => "Lambda-GB-Second",
+            SavingsPlanRateUnit::Request => "Request",
+            SavingsPlanRateUnit::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SavingsPlanRateUnit {
+    fn default() -> SavingsPlanRateUnit {
+        SavingsPlanRateUnit::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SavingsPlanRateUnit {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SavingsPlanRateUnit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SavingsPlanRateUnit {
+    fn from(value: &'a str) -> SavingsPlanRateUnit {
+        match value {
+            "Hrs" => SavingsPlanRateUnit::Hrs,
+            "Lambda-GB-Second" => SavingsPlanRateUnit::LambdaGbSecond,
+            "Request" => SavingsPlanRateUnit::Request,
+            _ => SavingsPlanRateUnit::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SavingsPlanRateUnit {
+    fn from(value: String) -> SavingsPlanRateUnit {
+        match value.as_str() {
+            "Hrs" => SavingsPlanRateUnit::Hrs,
+            "Lambda-GB-Second" => SavingsPlanRateUnit::LambdaGbSecond,
+            "Request" => SavingsPlanRateUnit::Request,
+            _ => SavingsPlanRateUnit::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SavingsPlanRateUnit {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SavingsPlanRateUnit, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SavingsPlanRateUnit {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SavingsPlanRateUnit {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SavingsPlanRateUnit {
+    fn deserialize<D>(deserializer: D) -> Result<SavingsPlanRateUnit, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SavingsPlanState {
+    /// `payment-pending`
+    PaymentPending,
+    /// `payment-failed`
+    PaymentFailed,
+    /// `active`
+    Active,
+    /// `retired`
+    Retired,
+    /// `queued`
+    Queued,
+    /// `queued-deleted`
+    QueuedDeleted,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SavingsPlanState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SavingsPlanState::PaymentPending => "payment-pending",
+            SavingsPlanState::PaymentFailed => "payment-failed",
+            SavingsPlanState::Active => "active",
+            SavingsPlanState::Retired => "retired",
+            SavingsPlanState::Queued => "queued",
+            SavingsPlanState::QueuedDeleted => "queued-deleted",
+            SavingsPlanState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SavingsPlanState {
+    fn default() -> SavingsPlanState {
+        SavingsPlanState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SavingsPlanState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SavingsPlanState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SavingsPlanState {
+    fn from(value: &'a str) -> SavingsPlanState {
+        match value {
+            "payment-pending" => SavingsPlanState::PaymentPending,
+            "payment-failed" => SavingsPlanState::PaymentFailed,
+            "active" => SavingsPlanState::Active,
+            "retired" => SavingsPlanState::Retired,
+            "queued" => SavingsPlanState::Queued,
+            "queued-deleted" => SavingsPlanState::QueuedDeleted,
+            _ => SavingsPlanState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SavingsPlanState {
+    fn from(value: String) -> SavingsPlanState {
+        match value.as_str() {
+            "payment-pending" => SavingsPlanState::PaymentPending,
+            "payment-failed" => SavingsPlanState::PaymentFailed,
+            "active" => SavingsPlanState::Active,
+            "retired" => SavingsPlanState::Retired,
+            "queued" => SavingsPlanState::Queued,
+            "queued-deleted" => SavingsPlanState::QueuedDeleted,
+            _ => SavingsPlanState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SavingsPlanState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SavingsPlanState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SavingsPlanState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SavingsPlanState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SavingsPlanState {
+    fn deserialize<D>(deserializer: D) -> Result<SavingsPlanState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SavingsPlanType {
+    /// `Compute`
+    Compute,
+    /// `EC2Instance`
+    EC2Instance,
+    /// `SageMaker`
+    SageMaker,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SavingsPlanType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SavingsPlanType::Compute => "Compute",
+            SavingsPlanType::EC2Instance => "EC2Instance",
+            SavingsPlanType::SageMaker => "SageMaker",
+            SavingsPlanType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SavingsPlanType {
+    fn default() -> SavingsPlanType {
+        SavingsPlanType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SavingsPlanType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SavingsPlanType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SavingsPlanType {
+    fn from(value: &'a str) -> SavingsPlanType {
+        match value {
+            "Compute" => SavingsPlanType::Compute,
+            "EC2Instance" => SavingsPlanType::EC2Instance,
+            "SageMaker" => SavingsPlanType::SageMaker,
+            _ => SavingsPlanType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SavingsPlanType {
+    fn from(value: String) -> SavingsPlanType {
+        match value.as_str() {
+            "Compute" => SavingsPlanType::Compute,
+            "EC2Instance" => SavingsPlanType::EC2Instance,
+            "SageMaker" => SavingsPlanType::SageMaker,
+            _ => SavingsPlanType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SavingsPlanType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SavingsPlanType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SavingsPlanType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SavingsPlanType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SavingsPlanType {
+    fn deserialize<D>(deserializer: D) -> Result<SavingsPlanType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SavingsPlansFilterName {
+    /// `region`
+    Region,
+    /// `ec2-instance-family`
+    Ec2InstanceFamily,
+    /// `commitment`
+    Commitment,
+    /// `upfront`
+    Upfront,
+    /// `term`
+    Term,
+    /// `savings-plan-type`
+    SavingsPlanType,
+    /// `payment-option`
+    PaymentOption,
+    /// `start`
+    Start,
+    /// `end`
+    End,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SavingsPlansFilterName {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SavingsPlansFilterName::Region => "region",
+            SavingsPlansFilterName::Ec2InstanceFamily => "ec2-instance-family",
+            SavingsPlansFilterName::Commitment => "commitment",
+            SavingsPlansFilterName::Upfront => "upfront",
+            SavingsPlansFilterName::Term => "term",
+            SavingsPlansFilterName::SavingsPlanType => "savings-plan-type",
+            SavingsPlansFilterName::PaymentOption => "payment-option",
+            SavingsPlansFilterName::Start => "start",
+            SavingsPlansFilterName::End => "end",
+            SavingsPlansFilterName::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SavingsPlansFilterName {
+    fn default() -> SavingsPlansFilterName {
+        SavingsPlansFilterName::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SavingsPlansFilterName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SavingsPlansFilterName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SavingsPlansFilterName {
+    fn from(value: &'a str) -> SavingsPlansFilterName {
+        match value {
+            "region" => SavingsPlansFilterName::Region,
+            "ec2-instance-family" => SavingsPlansFilterName::Ec2InstanceFamily,
+            "commitment" => SavingsPlansFilterName::Commitment,
+            "upfront" => SavingsPlansFilterName::Upfront,
+            "term" => SavingsPlansFilterName::Term,
+            "savings-plan-type" => SavingsPlansFilterName::SavingsPlanType,
+            "payment-option" => SavingsPlansFilterName::PaymentOption,
+            "start" => SavingsPlansFilterName::Start,
+            "end" => SavingsPlansFilterName::End,
+            _ => SavingsPlansFilterName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SavingsPlansFilterName {
+    fn from(value: String) -> SavingsPlansFilterName {
+        match value.as_str() {
+            "region" => SavingsPlansFilterName::Region,
+            "ec2-instance-family" => SavingsPlansFilterName::Ec2InstanceFamily,
+            "commitment" => SavingsPlansFilterName::Commitment,
+            "upfront" => SavingsPlansFilterName::Upfront,
+            "term" => SavingsPlansFilterName::Term,
+            "savings-plan-type" => SavingsPlansFilterName::SavingsPlanType,
+            "payment-option" => SavingsPlansFilterName::PaymentOption,
+            "start" => SavingsPlansFilterName::Start,
+            "end" => SavingsPlansFilterName::End,
+            _ => SavingsPlansFilterName::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SavingsPlansFilterName {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SavingsPlansFilterName, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SavingsPlansFilterName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SavingsPlansFilterName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SavingsPlansFilterName {
+    fn deserialize<D>(deserializer: D) -> Result<SavingsPlansFilterName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct TagResourceRequest {
+    /// <p>The Amazon Resource Name (ARN) of the resource.</p>
+    #[serde(rename = "resourceArn")]
+    pub resource_arn: String,
+    /// <p>One or more tags. For example, { "tags": {"key1":"value1", "key2":"value2"} }.</p>
+    #[serde(rename = "tags")]
+    pub tags: ::std::collections::HashMap<String, String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct TagResourceResponse {}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct UntagResourceRequest {
+    /// <p>The Amazon Resource Name (ARN) of the resource.</p>
+    #[serde(rename = "resourceArn")]
+    pub resource_arn: String,
+    /// <p>The tag keys.</p>
+    #[serde(rename = "tagKeys")]
+    pub tag_keys: Vec<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct UntagResourceResponse {}
 
 /// Errors returned by CreateSavingsPlan
 #[derive(Debug, PartialEq)]
@@ -1001,11 +2370,12 @@ pub struct SavingsPlansClient {
 impl SavingsPlansClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> SavingsPlansClient {
         SavingsPlansClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "savingsplans"),
         }
     }
 
@@ -1039,6 +2409,7 @@ impl SavingsPlans for SavingsPlansClient {
         let request_uri = "/CreateSavingsPlan";
 
         let mut request = SignedRequest::new("POST", "savingsplans", &self.region, &request_uri);
+        request.set_operation("CreateSavingsPlan");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1069,6 +2440,7 @@ impl SavingsPlans for SavingsPlansClient {
         let request_uri = "/DescribeSavingsPlanRates";
 
         let mut request = SignedRequest::new("POST", "savingsplans", &self.region, &request_uri);
+        request.set_operation("DescribeSavingsPlanRates");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1099,6 +2471,7 @@ impl SavingsPlans for SavingsPlansClient {
         let request_uri = "/DescribeSavingsPlans";
 
         let mut request = SignedRequest::new("POST", "savingsplans", &self.region, &request_uri);
+        request.set_operation("DescribeSavingsPlans");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1132,6 +2505,7 @@ impl SavingsPlans for SavingsPlansClient {
         let request_uri = "/DescribeSavingsPlansOfferingRates";
 
         let mut request = SignedRequest::new("POST", "savingsplans", &self.region, &request_uri);
+        request.set_operation("DescribeSavingsPlansOfferingRates");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1167,6 +2541,7 @@ impl SavingsPlans for SavingsPlansClient {
         let request_uri = "/DescribeSavingsPlansOfferings";
 
         let mut request = SignedRequest::new("POST", "savingsplans", &self.region, &request_uri);
+        request.set_operation("DescribeSavingsPlansOfferings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1197,6 +2572,7 @@ impl SavingsPlans for SavingsPlansClient {
         let request_uri = "/ListTagsForResource";
 
         let mut request = SignedRequest::new("POST", "savingsplans", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1227,6 +2603,7 @@ impl SavingsPlans for SavingsPlansClient {
         let request_uri = "/TagResource";
 
         let mut request = SignedRequest::new("POST", "savingsplans", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1257,6 +2634,7 @@ impl SavingsPlans for SavingsPlansClient {
         let request_uri = "/UntagResource";
 
         let mut request = SignedRequest::new("POST", "savingsplans", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -25,6 +25,104 @@ use rusoto_core::signature::SignedRequest;
 #[allow(unused_imports)]
 use serde::{Deserialize, Serialize};
 use serde_json;
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CodeGenerationStatus {
+    /// `CREATE_IN_PROGRESS`
+    CreateInProgress,
+    /// `CREATE_COMPLETE`
+    CreateComplete,
+    /// `CREATE_FAILED`
+    CreateFailed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CodeGenerationStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CodeGenerationStatus::CreateInProgress => "CREATE_IN_PROGRESS",
+            CodeGenerationStatus::CreateComplete => "CREATE_COMPLETE",
+            CodeGenerationStatus::CreateFailed => "CREATE_FAILED",
+            CodeGenerationStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CodeGenerationStatus {
+    fn default() -> CodeGenerationStatus {
+        CodeGenerationStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CodeGenerationStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CodeGenerationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CodeGenerationStatus {
+    fn from(value: &'a str) -> CodeGenerationStatus {
+        match value {
+            "CREATE_IN_PROGRESS" => CodeGenerationStatus::CreateInProgress,
+            "CREATE_COMPLETE" => CodeGenerationStatus::CreateComplete,
+            "CREATE_FAILED" => CodeGenerationStatus::CreateFailed,
+            _ => CodeGenerationStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CodeGenerationStatus {
+    fn from(value: String) -> CodeGenerationStatus {
+        match value.as_str() {
+            "CREATE_IN_PROGRESS" => CodeGenerationStatus::CreateInProgress,
+            "CREATE_COMPLETE" => CodeGenerationStatus::CreateComplete,
+            "CREATE_FAILED" => CodeGenerationStatus::CreateFailed,
+            _ => CodeGenerationStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CodeGenerationStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CodeGenerationStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CodeGenerationStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CodeGenerationStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CodeGenerationStatus {
+    fn deserialize<D>(deserializer: D) -> Result<CodeGenerationStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateDiscovererRequest {
@@ -63,7 +161,7 @@ pub struct CreateDiscovererResponse {
     /// <p>The state of the discoverer.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<DiscovererState>,
     /// <p>Tags associated with the resource.</p>
     #[serde(rename = "Tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -124,7 +222,7 @@ pub struct CreateSchemaRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tags: Option<::std::collections::HashMap<String, String>>,
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: Type,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -136,8 +234,13 @@ pub struct CreateSchemaResponse {
     pub description: Option<String>,
     /// <p>The date and time that schema was modified.</p>
     #[serde(rename = "LastModified")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified: Option<f64>,
+    pub last_modified: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The ARN of the schema.</p>
     #[serde(rename = "SchemaArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -159,8 +262,13 @@ pub struct CreateSchemaResponse {
     pub type_: Option<String>,
     /// <p>The date the schema version was created.</p>
     #[serde(rename = "VersionCreatedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub version_created_date: Option<f64>,
+    pub version_created_date: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -216,12 +324,22 @@ pub struct DescribeCodeBindingRequest {
 pub struct DescribeCodeBindingResponse {
     /// <p>The time and date that the code binding was created.</p>
     #[serde(rename = "CreationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The date and time that code bindings were modified.</p>
     #[serde(rename = "LastModified")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified: Option<f64>,
+    pub last_modified: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The version number of the schema.</p>
     #[serde(rename = "SchemaVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -229,7 +347,7 @@ pub struct DescribeCodeBindingResponse {
     /// <p>The current status of code binding generation.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<CodeGenerationStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -261,7 +379,7 @@ pub struct DescribeDiscovererResponse {
     /// <p>The state of the discoverer.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<DiscovererState>,
     /// <p>Tags associated with the resource.</p>
     #[serde(rename = "Tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -320,8 +438,13 @@ pub struct DescribeSchemaResponse {
     pub description: Option<String>,
     /// <p>The date and time that schema was modified.</p>
     #[serde(rename = "LastModified")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified: Option<f64>,
+    pub last_modified: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The ARN of the schema.</p>
     #[serde(rename = "SchemaArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -344,10 +467,108 @@ pub struct DescribeSchemaResponse {
     pub type_: Option<String>,
     /// <p>The date the schema version was created.</p>
     #[serde(rename = "VersionCreatedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub version_created_date: Option<f64>,
+    pub version_created_date: Option<::chrono::DateTime<::chrono::Utc>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DiscovererState {
+    /// `STARTED`
+    Started,
+    /// `STOPPED`
+    Stopped,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DiscovererState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DiscovererState::Started => "STARTED",
+            DiscovererState::Stopped => "STOPPED",
+            DiscovererState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DiscovererState {
+    fn default() -> DiscovererState {
+        DiscovererState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DiscovererState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DiscovererState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DiscovererState {
+    fn from(value: &'a str) -> DiscovererState {
+        match value {
+            "STARTED" => DiscovererState::Started,
+            "STOPPED" => DiscovererState::Stopped,
+            _ => DiscovererState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DiscovererState {
+    fn from(value: String) -> DiscovererState {
+        match value.as_str() {
+            "STARTED" => DiscovererState::Started,
+            "STOPPED" => DiscovererState::Stopped,
+            _ => DiscovererState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DiscovererState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DiscovererState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DiscovererState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
 }
 
+impl ::serde::Serialize for DiscovererState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DiscovererState {
+    fn deserialize<D>(deserializer: D) -> Result<DiscovererState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DiscovererSummary {
@@ -365,7 +586,7 @@ pub struct DiscovererSummary {
     pub source_arn: Option<String>,
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<DiscovererState>,
     /// <p>Tags associated with the resource.</p>
     #[serde(rename = "Tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -399,7 +620,7 @@ pub struct GetDiscoveredSchemaRequest {
     pub events: Vec<String>,
     /// <p>The type of event.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: Type,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -583,12 +804,22 @@ pub struct PutCodeBindingRequest {
 pub struct PutCodeBindingResponse {
     /// <p>The time and date that the code binding was created.</p>
     #[serde(rename = "CreationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The date and time that code bindings were modified.</p>
     #[serde(rename = "LastModified")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified: Option<f64>,
+    pub last_modified: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The version number of the schema.</p>
     #[serde(rename = "SchemaVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -596,7 +827,7 @@ pub struct PutCodeBindingResponse {
     /// <p>The current status of code binding generation.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<CodeGenerationStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -622,8 +853,13 @@ pub struct RegistrySummary {
 pub struct SchemaSummary {
     /// <p>The date and time that schema was modified.</p>
     #[serde(rename = "LastModified")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified: Option<f64>,
+    pub last_modified: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The ARN of the schema.</p>
     #[serde(rename = "SchemaArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -684,8 +920,13 @@ pub struct SearchSchemaSummary {
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SearchSchemaVersionSummary {
     #[serde(rename = "CreatedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The version number of the schema</p>
     #[serde(rename = "SchemaVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -737,7 +978,7 @@ pub struct StartDiscovererResponse {
     /// <p>The state of the discoverer.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<DiscovererState>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -757,7 +998,7 @@ pub struct StopDiscovererResponse {
     /// <p>The state of the discoverer.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<DiscovererState>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -769,6 +1010,99 @@ pub struct TagResourceRequest {
     pub tags: ::std::collections::HashMap<String, String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Type {
+    /// `OpenApi3`
+    OpenApi3,
+    /// `JSONSchemaDraft4`
+    JSONSchemaDraft4,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Type {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Type::OpenApi3 => "OpenApi3",
+            Type::JSONSchemaDraft4 => "JSONSchemaDraft4",
+            Type::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Type {
+    fn default() -> Type {
+        Type::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Type {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Type {
+    fn from(value: &'a str) -> Type {
+        match value {
+            "OpenApi3" => Type::OpenApi3,
+            "JSONSchemaDraft4" => Type::JSONSchemaDraft4,
+            _ => Type::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Type {
+    fn from(value: String) -> Type {
+        match value.as_str() {
+            "OpenApi3" => Type::OpenApi3,
+            "JSONSchemaDraft4" => Type::JSONSchemaDraft4,
+            _ => Type::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Type {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Type, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Type {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Type {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Type {
+    fn deserialize<D>(deserializer: D) -> Result<Type, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct UnlockServiceLinkedRoleRequest {
@@ -822,7 +1156,7 @@ pub struct UpdateDiscovererResponse {
     /// <p>The state of the discoverer.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<DiscovererState>,
     /// <p>Tags associated with the resource.</p>
     #[serde(rename = "Tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -883,7 +1217,7 @@ pub struct UpdateSchemaRequest {
     /// <p>The schema type for the events schema.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<Type>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -895,8 +1229,13 @@ pub struct UpdateSchemaResponse {
     pub description: Option<String>,
     /// <p>The date and time that schema was modified.</p>
     #[serde(rename = "LastModified")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified: Option<f64>,
+    pub last_modified: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The ARN of the schema.</p>
     #[serde(rename = "SchemaArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -918,8 +1257,13 @@ pub struct UpdateSchemaResponse {
     pub type_: Option<String>,
     /// <p>The date the schema version was created.</p>
     #[serde(rename = "VersionCreatedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub version_created_date: Option<f64>,
+    pub version_created_date: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// Errors returned by CreateDiscoverer
@@ -2768,11 +3112,12 @@ pub struct SchemasClient {
 impl SchemasClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> SchemasClient {
         SchemasClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "schemas"),
         }
     }
 
@@ -2806,6 +3151,7 @@ impl Schemas for SchemasClient {
         let request_uri = "/v1/discoverers";
 
         let mut request = SignedRequest::new("POST", "schemas", &self.region, &request_uri);
+        request.set_operation("CreateDiscoverer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2839,6 +3185,7 @@ impl Schemas for SchemasClient {
         );
 
         let mut request = SignedRequest::new("POST", "schemas", &self.region, &request_uri);
+        request.set_operation("CreateRegistry");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2873,6 +3220,7 @@ impl Schemas for SchemasClient {
         );
 
         let mut request = SignedRequest::new("POST", "schemas", &self.region, &request_uri);
+        request.set_operation("CreateSchema");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2906,6 +3254,7 @@ impl Schemas for SchemasClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "schemas", &self.region, &request_uri);
+        request.set_operation("DeleteDiscoverer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2935,6 +3284,7 @@ impl Schemas for SchemasClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "schemas", &self.region, &request_uri);
+        request.set_operation("DeleteRegistry");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2965,6 +3315,7 @@ impl Schemas for SchemasClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "schemas", &self.region, &request_uri);
+        request.set_operation("DeleteSchema");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2991,6 +3342,7 @@ impl Schemas for SchemasClient {
         let request_uri = format!("/v1/registries/name/{registry_name}/schemas/name/{schema_name}/version/{schema_version}", registry_name = input.registry_name, schema_name = input.schema_name, schema_version = input.schema_version);
 
         let mut request = SignedRequest::new("DELETE", "schemas", &self.region, &request_uri);
+        request.set_operation("DeleteSchemaVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3022,6 +3374,7 @@ impl Schemas for SchemasClient {
         );
 
         let mut request = SignedRequest::new("GET", "schemas", &self.region, &request_uri);
+        request.set_operation("DescribeCodeBinding");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3058,6 +3411,7 @@ impl Schemas for SchemasClient {
         );
 
         let mut request = SignedRequest::new("GET", "schemas", &self.region, &request_uri);
+        request.set_operation("DescribeDiscoverer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3088,6 +3442,7 @@ impl Schemas for SchemasClient {
         );
 
         let mut request = SignedRequest::new("GET", "schemas", &self.region, &request_uri);
+        request.set_operation("DescribeRegistry");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3119,6 +3474,7 @@ impl Schemas for SchemasClient {
         );
 
         let mut request = SignedRequest::new("GET", "schemas", &self.region, &request_uri);
+        request.set_operation("DescribeSchema");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3152,6 +3508,7 @@ impl Schemas for SchemasClient {
         let request_uri = format!("/v1/registries/name/{registry_name}/schemas/name/{schema_name}/language/{language}/source", language = input.language, registry_name = input.registry_name, schema_name = input.schema_name);
 
         let mut request = SignedRequest::new("GET", "schemas", &self.region, &request_uri);
+        request.set_operation("GetCodeBindingSource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3186,6 +3543,7 @@ impl Schemas for SchemasClient {
         let request_uri = "/v1/discover";
 
         let mut request = SignedRequest::new("POST", "schemas", &self.region, &request_uri);
+        request.set_operation("GetDiscoveredSchema");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3216,6 +3574,7 @@ impl Schemas for SchemasClient {
         let request_uri = "/v1/discoverers";
 
         let mut request = SignedRequest::new("GET", "schemas", &self.region, &request_uri);
+        request.set_operation("ListDiscoverers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3258,6 +3617,7 @@ impl Schemas for SchemasClient {
         let request_uri = "/v1/registries";
 
         let mut request = SignedRequest::new("GET", "schemas", &self.region, &request_uri);
+        request.set_operation("ListRegistries");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3304,6 +3664,7 @@ impl Schemas for SchemasClient {
         );
 
         let mut request = SignedRequest::new("GET", "schemas", &self.region, &request_uri);
+        request.set_operation("ListSchemaVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3343,6 +3704,7 @@ impl Schemas for SchemasClient {
         );
 
         let mut request = SignedRequest::new("GET", "schemas", &self.region, &request_uri);
+        request.set_operation("ListSchemas");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3382,6 +3744,7 @@ impl Schemas for SchemasClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "schemas", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3408,6 +3771,7 @@ impl Schemas for SchemasClient {
         let request_uri = "/slr-deletion/lock";
 
         let mut request = SignedRequest::new("POST", "schemas", &self.region, &request_uri);
+        request.set_operation("LockServiceLinkedRole");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3443,6 +3807,7 @@ impl Schemas for SchemasClient {
         );
 
         let mut request = SignedRequest::new("POST", "schemas", &self.region, &request_uri);
+        request.set_operation("PutCodeBinding");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3479,6 +3844,7 @@ impl Schemas for SchemasClient {
         );
 
         let mut request = SignedRequest::new("GET", "schemas", &self.region, &request_uri);
+        request.set_operation("SearchSchemas");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3519,6 +3885,7 @@ impl Schemas for SchemasClient {
         );
 
         let mut request = SignedRequest::new("POST", "schemas", &self.region, &request_uri);
+        request.set_operation("StartDiscoverer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3549,6 +3916,7 @@ impl Schemas for SchemasClient {
         );
 
         let mut request = SignedRequest::new("POST", "schemas", &self.region, &request_uri);
+        request.set_operation("StopDiscoverer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3576,6 +3944,7 @@ impl Schemas for SchemasClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "schemas", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3604,6 +3973,7 @@ impl Schemas for SchemasClient {
         let request_uri = "/slr-deletion/unlock";
 
         let mut request = SignedRequest::new("POST", "schemas", &self.region, &request_uri);
+        request.set_operation("UnlockServiceLinkedRole");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3634,6 +4004,7 @@ impl Schemas for SchemasClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "schemas", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3669,6 +4040,7 @@ impl Schemas for SchemasClient {
         );
 
         let mut request = SignedRequest::new("PUT", "schemas", &self.region, &request_uri);
+        request.set_operation("UpdateDiscoverer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3702,6 +4074,7 @@ impl Schemas for SchemasClient {
         );
 
         let mut request = SignedRequest::new("PUT", "schemas", &self.region, &request_uri);
+        request.set_operation("UpdateRegistry");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3736,6 +4109,7 @@ impl Schemas for SchemasClient {
         );
 
         let mut request = SignedRequest::new("PUT", "schemas", &self.region, &request_uri);
+        request.set_operation("UpdateSchema");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3758,3 +4132,224 @@ impl Schemas for SchemasClient {
         }
     }
 }
+impl SchemasClient {
+    /// Auto-paginating version of `list_discoverers`, streaming every page of results.
+    pub fn list_discoverers_pages(
+        &self,
+        input: ListDiscoverersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListDiscoverersResponse, ListDiscoverersError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SchemasClient, input: ListDiscoverersRequest| async move {
+                client.list_discoverers(input).await
+            },
+            |input: &ListDiscoverersRequest, output: &ListDiscoverersResponse| {
+                Some(ListDiscoverersRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_discoverers`, streaming the `Discoverers` of every page of results.
+    pub fn list_discoverers_items(
+        &self,
+        input: ListDiscoverersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DiscovererSummary, ListDiscoverersError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_discoverers_pages(input),
+            |output: ListDiscoverersResponse| output.discoverers.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_registries`, streaming every page of results.
+    pub fn list_registries_pages(
+        &self,
+        input: ListRegistriesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListRegistriesResponse, ListRegistriesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SchemasClient, input: ListRegistriesRequest| async move {
+                client.list_registries(input).await
+            },
+            |input: &ListRegistriesRequest, output: &ListRegistriesResponse| {
+                Some(ListRegistriesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_registries`, streaming the `Registries` of every page of results.
+    pub fn list_registries_items(
+        &self,
+        input: ListRegistriesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<RegistrySummary, ListRegistriesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_registries_pages(input),
+            |output: ListRegistriesResponse| output.registries.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_schema_versions`, streaming every page of results.
+    pub fn list_schema_versions_pages(
+        &self,
+        input: ListSchemaVersionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListSchemaVersionsResponse, ListSchemaVersionsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SchemasClient, input: ListSchemaVersionsRequest| async move {
+                client.list_schema_versions(input).await
+            },
+            |input: &ListSchemaVersionsRequest, output: &ListSchemaVersionsResponse| {
+                Some(ListSchemaVersionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_schema_versions`, streaming the `SchemaVersions` of every page of results.
+    pub fn list_schema_versions_items(
+        &self,
+        input: ListSchemaVersionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<SchemaVersionSummary, ListSchemaVersionsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_schema_versions_pages(input),
+            |output: ListSchemaVersionsResponse| output.schema_versions.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_schemas`, streaming every page of results.
+    pub fn list_schemas_pages(
+        &self,
+        input: ListSchemasRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListSchemasResponse, ListSchemasError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SchemasClient, input: ListSchemasRequest| async move {
+                client.list_schemas(input).await
+            },
+            |input: &ListSchemasRequest, output: &ListSchemasResponse| {
+                Some(ListSchemasRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_schemas`, streaming the `Schemas` of every page of results.
+    pub fn list_schemas_items(
+        &self,
+        input: ListSchemasRequest,
+    ) -> rusoto_core::pagination::RusotoStream<SchemaSummary, ListSchemasError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_schemas_pages(input),
+            |output: ListSchemasResponse| output.schemas.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `search_schemas`, streaming every page of results.
+    pub fn search_schemas_pages(
+        &self,
+        input: SearchSchemasRequest,
+    ) -> rusoto_core::pagination::RusotoStream<SearchSchemasResponse, SearchSchemasError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SchemasClient, input: SearchSchemasRequest| async move {
+                client.search_schemas(input).await
+            },
+            |input: &SearchSchemasRequest, output: &SearchSchemasResponse| {
+                Some(SearchSchemasRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `search_schemas`, streaming the `Schemas` of every page of results.
+    pub fn search_schemas_items(
+        &self,
+        input: SearchSchemasRequest,
+    ) -> rusoto_core::pagination::RusotoStream<SearchSchemaSummary, SearchSchemasError> {
+        rusoto_core::pagination::paginate_items(
+            self.search_schemas_pages(input),
+            |output: SearchSchemasResponse| output.schemas.unwrap_or_default(),
+        )
+    }
+}
+impl SchemasClient {
+    /// Waits until `describe_code_binding` reports the `CodeBindingExists` state, polling every 2 seconds up to 30 times.
+    pub async fn wait_until_code_binding_exists(
+        &self,
+        input: DescribeCodeBindingRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeCodeBindingError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(2), 30);
+        self.wait_until_code_binding_exists_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_code_binding_exists`, polling as often and as many times as `config` says.
+    pub async fn wait_until_code_binding_exists_with_config(
+        &self,
+        input: DescribeCodeBindingRequest,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeCodeBindingError>> {
+        rusoto_core::waiter::wait(
+            "CodeBindingExists",
+            &config,
+            || self.describe_code_binding(input.clone()),
+            |result: &Result<
+                DescribeCodeBindingResponse,
+                RusotoError<DescribeCodeBindingError>,
+            >| match result {
+                Ok(output)
+                    if output
+                        .status
+                        .iter()
+                        .any(|v| *v == CodeGenerationStatus::CreateComplete) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if output
+                        .status
+                        .iter()
+                        .any(|v| *v == CodeGenerationStatus::CreateInProgress) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Retry)
+                }
+                Ok(output)
+                    if output
+                        .status
+                        .iter()
+                        .any(|v| *v == CodeGenerationStatus::CreateFailed) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Err(RusotoError::Service(DescribeCodeBindingError::NotFound(_))) => {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Err(RusotoError::Unknown(response))
+                    if rusoto_core::retry::error_code(response).as_deref()
+                        == Some("NotFoundException") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+}
@@ -1649,11 +1649,12 @@ pub struct SimpleDbClient {
 impl SimpleDbClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> SimpleDbClient {
         SimpleDbClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "SimpleDB"),
         }
     }
 
@@ -1685,6 +1686,7 @@ impl SimpleDb for SimpleDbClient {
         input: BatchDeleteAttributesRequest,
     ) -> Result<(), RusotoError<BatchDeleteAttributesError>> {
         let mut request = SignedRequest::new("POST", "sdb", &self.region, "/");
+        request.set_operation("BatchDeleteAttributes");
         let mut params = Params::new();
 
         params.put("Action", "BatchDeleteAttributes");
@@ -1713,6 +1715,7 @@ impl SimpleDb for SimpleDbClient {
         input: BatchPutAttributesRequest,
     ) -> Result<(), RusotoError<BatchPutAttributesError>> {
         let mut request = SignedRequest::new("POST", "sdb", &self.region, "/");
+        request.set_operation("BatchPutAttributes");
         let mut params = Params::new();
 
         params.put("Action", "BatchPutAttributes");
@@ -1741,6 +1744,7 @@ impl SimpleDb for SimpleDbClient {
         input: CreateDomainRequest,
     ) -> Result<(), RusotoError<CreateDomainError>> {
         let mut request = SignedRequest::new("POST", "sdb", &self.region, "/");
+        request.set_operation("CreateDomain");
         let mut params = Params::new();
 
         params.put("Action", "CreateDomain");
@@ -1769,6 +1773,7 @@ impl SimpleDb for SimpleDbClient {
         input: DeleteAttributesRequest,
     ) -> Result<(), RusotoError<DeleteAttributesError>> {
         let mut request = SignedRequest::new("POST", "sdb", &self.region, "/");
+        request.set_operation("DeleteAttributes");
         let mut params = Params::new();
 
         params.put("Action", "DeleteAttributes");
@@ -1797,6 +1802,7 @@ impl SimpleDb for SimpleDbClient {
         input: DeleteDomainRequest,
     ) -> Result<(), RusotoError<DeleteDomainError>> {
         let mut request = SignedRequest::new("POST", "sdb", &self.region, "/");
+        request.set_operation("DeleteDomain");
         let mut params = Params::new();
 
         params.put("Action", "DeleteDomain");
@@ -1825,6 +1831,7 @@ impl SimpleDb for SimpleDbClient {
         input: DomainMetadataRequest,
     ) -> Result<DomainMetadataResult, RusotoError<DomainMetadataError>> {
         let mut request = SignedRequest::new("POST", "sdb", &self.region, "/");
+        request.set_operation("DomainMetadata");
         let mut params = Params::new();
 
         params.put("Action", "DomainMetadata");
@@ -1872,6 +1879,7 @@ impl SimpleDb for SimpleDbClient {
         input: GetAttributesRequest,
     ) -> Result<GetAttributesResult, RusotoError<GetAttributesError>> {
         let mut request = SignedRequest::new("POST", "sdb", &self.region, "/");
+        request.set_operation("GetAttributes");
         let mut params = Params::new();
 
         params.put("Action", "GetAttributes");
@@ -1919,6 +1927,7 @@ impl SimpleDb for SimpleDbClient {
         input: ListDomainsRequest,
     ) -> Result<ListDomainsResult, RusotoError<ListDomainsError>> {
         let mut request = SignedRequest::new("POST", "sdb", &self.region, "/");
+        request.set_operation("ListDomains");
         let mut params = Params::new();
 
         params.put("Action", "ListDomains");
@@ -1965,6 +1974,7 @@ impl SimpleDb for SimpleDbClient {
         input: PutAttributesRequest,
     ) -> Result<(), RusotoError<PutAttributesError>> {
         let mut request = SignedRequest::new("POST", "sdb", &self.region, "/");
+        request.set_operation("PutAttributes");
         let mut params = Params::new();
 
         params.put("Action", "PutAttributes");
@@ -1990,6 +2000,7 @@ impl SimpleDb for SimpleDbClient {
     /// <p> The <code>Select</code> operation returns a set of attributes for <code>ItemNames</code> that match the select expression. <code>Select</code> is similar to the standard SQL SELECT statement. </p> <p> The total size of the response cannot exceed 1 MB in total size. Amazon SimpleDB automatically adjusts the number of items returned per page to enforce this limit. For example, if the client asks to retrieve 2500 items, but each individual item is 10 kB in size, the system returns 100 items and an appropriate <code>NextToken</code> so the client can access the next page of results. </p> <p> For information on how to construct select expressions, see Using Select to Create Amazon SimpleDB Queries in the Developer Guide. </p>
     async fn select(&self, input: SelectRequest) -> Result<SelectResult, RusotoError<SelectError>> {
         let mut request = SignedRequest::new("POST", "sdb", &self.region, "/");
+        request.set_operation("Select");
         let mut params = Params::new();
 
         params.put("Action", "Select");
@@ -2030,3 +2041,63 @@ impl SimpleDb for SimpleDbClient {
         Ok(result)
     }
 }
+impl SimpleDbClient {
+    /// Auto-paginating version of `list_domains`, streaming every page of results.
+    pub fn list_domains_pages(
+        &self,
+        input: ListDomainsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListDomainsResult, ListDomainsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SimpleDbClient, input: ListDomainsRequest| async move {
+                client.list_domains(input).await
+            },
+            |input: &ListDomainsRequest, output: &ListDomainsResult| {
+                Some(ListDomainsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_domains`, streaming the `DomainNames` of every page of results.
+    pub fn list_domains_items(
+        &self,
+        input: ListDomainsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListDomainsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_domains_pages(input),
+            |output: ListDomainsResult| output.domain_names.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `select`, streaming every page of results.
+    pub fn select_pages(
+        &self,
+        input: SelectRequest,
+    ) -> rusoto_core::pagination::RusotoStream<SelectResult, SelectError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SimpleDbClient, input: SelectRequest| async move { client.select(input).await },
+            |input: &SelectRequest, output: &SelectResult| {
+                Some(SelectRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `select`, streaming the `Items` of every page of results.
+    pub fn select_items(
+        &self,
+        input: SelectRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Item, SelectError> {
+        rusoto_core::pagination::paginate_items(self.select_pages(input), |output: SelectResult| {
+            output.items.unwrap_or_default()
+        })
+    }
+}
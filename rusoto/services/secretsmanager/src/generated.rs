@@ -149,8 +149,13 @@ pub struct DeleteSecretResponse {
     pub arn: Option<String>,
     /// <p>The date and time after which this secret can be deleted by Secrets Manager and can no longer be restored. This value is the date and time of the delete request plus the number of days specified in <code>RecoveryWindowInDays</code>.</p>
     #[serde(rename = "DeletionDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deletion_date: Option<f64>,
+    pub deletion_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The friendly name of the secret that is now scheduled for deletion.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -174,8 +179,13 @@ pub struct DescribeSecretResponse {
     pub arn: Option<String>,
     /// <p>This value exists if the secret is scheduled for deletion. Some time after the specified date and time, Secrets Manager deletes the secret and all of its versions.</p> <p>If a secret is scheduled for deletion, then its details, including the encrypted secret information, is not accessible. To cancel a scheduled deletion and restore access, use <a>RestoreSecret</a>.</p>
     #[serde(rename = "DeletedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deleted_date: Option<f64>,
+    pub deleted_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The user-provided description of the secret.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -186,16 +196,31 @@ pub struct DescribeSecretResponse {
     pub kms_key_id: Option<String>,
     /// <p>The last date that this secret was accessed. This value is truncated to midnight of the date and therefore shows only the date, not the time.</p>
     #[serde(rename = "LastAccessedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_accessed_date: Option<f64>,
+    pub last_accessed_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The last date and time that this secret was modified in any way.</p>
     #[serde(rename = "LastChangedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_changed_date: Option<f64>,
+    pub last_changed_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The most recent date and time that the Secrets Manager rotation process was successfully completed. This value is null if the secret has never rotated.</p>
     #[serde(rename = "LastRotatedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_rotated_date: Option<f64>,
+    pub last_rotated_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The user-provided friendly name of the secret.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -321,8 +346,13 @@ pub struct GetSecretValueResponse {
     pub arn: Option<String>,
     /// <p>The date and time that this version of the secret was created.</p>
     #[serde(rename = "CreatedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The friendly name of the secret.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -568,8 +598,13 @@ pub struct SecretListEntry {
     pub arn: Option<String>,
     /// <p>The date and time on which this secret was deleted. Not present on active secrets. The secret can be recovered until the number of days in the recovery window has passed, as specified in the <code>RecoveryWindowInDays</code> parameter of the <a>DeleteSecret</a> operation.</p>
     #[serde(rename = "DeletedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deleted_date: Option<f64>,
+    pub deleted_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The user-provided description of the secret.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -580,16 +615,31 @@ pub struct SecretListEntry {
     pub kms_key_id: Option<String>,
     /// <p>The last date that this secret was accessed. This value is truncated to midnight of the date and therefore shows only the date, not the time.</p>
     #[serde(rename = "LastAccessedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_accessed_date: Option<f64>,
+    pub last_accessed_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The last date and time that this secret was modified in any way.</p>
     #[serde(rename = "LastChangedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_changed_date: Option<f64>,
+    pub last_changed_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The last date and time that the rotation process for this secret was invoked.</p>
     #[serde(rename = "LastRotatedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_rotated_date: Option<f64>,
+    pub last_rotated_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The friendly name of the secret. You can use forward slashes in the name to represent a path hierarchy. For example, <code>/prod/databases/dbserver1</code> could represent the secret for a server named <code>dbserver1</code> in the folder <code>databases</code> in the folder <code>prod</code>. </p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -625,12 +675,22 @@ pub struct SecretListEntry {
 pub struct SecretVersionsListEntry {
     /// <p>The date and time this version of the secret was created.</p>
     #[serde(rename = "CreatedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The date that this version of the secret was last accessed. Note that the resolution of this field is at the date level and does not include the time.</p>
     #[serde(rename = "LastAccessedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_accessed_date: Option<f64>,
+    pub last_accessed_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The unique version identifier of this version of the secret.</p>
     #[serde(rename = "VersionId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1833,11 +1893,12 @@ pub struct SecretsManagerClient {
 impl SecretsManagerClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> SecretsManagerClient {
         SecretsManagerClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Secrets Manager"),
         }
     }
 
@@ -1869,6 +1930,7 @@ impl SecretsManager for SecretsManagerClient {
         input: CancelRotateSecretRequest,
     ) -> Result<CancelRotateSecretResponse, RusotoError<CancelRotateSecretError>> {
         let mut request = SignedRequest::new("POST", "secretsmanager", &self.region, "/");
+        request.set_operation("CancelRotateSecret");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "secretsmanager.CancelRotateSecret");
@@ -1897,6 +1959,7 @@ impl SecretsManager for SecretsManagerClient {
         input: CreateSecretRequest,
     ) -> Result<CreateSecretResponse, RusotoError<CreateSecretError>> {
         let mut request = SignedRequest::new("POST", "secretsmanager", &self.region, "/");
+        request.set_operation("CreateSecret");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "secretsmanager.CreateSecret");
@@ -1924,6 +1987,7 @@ impl SecretsManager for SecretsManagerClient {
         input: DeleteResourcePolicyRequest,
     ) -> Result<DeleteResourcePolicyResponse, RusotoError<DeleteResourcePolicyError>> {
         let mut request = SignedRequest::new("POST", "secretsmanager", &self.region, "/");
+        request.set_operation("DeleteResourcePolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "secretsmanager.DeleteResourcePolicy");
@@ -1952,6 +2016,7 @@ impl SecretsManager for SecretsManagerClient {
         input: DeleteSecretRequest,
     ) -> Result<DeleteSecretResponse, RusotoError<DeleteSecretError>> {
         let mut request = SignedRequest::new("POST", "secretsmanager", &self.region, "/");
+        request.set_operation("DeleteSecret");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "secretsmanager.DeleteSecret");
@@ -1979,6 +2044,7 @@ impl SecretsManager for SecretsManagerClient {
         input: DescribeSecretRequest,
     ) -> Result<DescribeSecretResponse, RusotoError<DescribeSecretError>> {
         let mut request = SignedRequest::new("POST", "secretsmanager", &self.region, "/");
+        request.set_operation("DescribeSecret");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "secretsmanager.DescribeSecret");
@@ -2006,6 +2072,7 @@ impl SecretsManager for SecretsManagerClient {
         input: GetRandomPasswordRequest,
     ) -> Result<GetRandomPasswordResponse, RusotoError<GetRandomPasswordError>> {
         let mut request = SignedRequest::new("POST", "secretsmanager", &self.region, "/");
+        request.set_operation("GetRandomPassword");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "secretsmanager.GetRandomPassword");
@@ -2034,6 +2101,7 @@ impl SecretsManager for SecretsManagerClient {
         input: GetResourcePolicyRequest,
     ) -> Result<GetResourcePolicyResponse, RusotoError<GetResourcePolicyError>> {
         let mut request = SignedRequest::new("POST", "secretsmanager", &self.region, "/");
+        request.set_operation("GetResourcePolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "secretsmanager.GetResourcePolicy");
@@ -2062,6 +2130,7 @@ impl SecretsManager for SecretsManagerClient {
         input: GetSecretValueRequest,
     ) -> Result<GetSecretValueResponse, RusotoError<GetSecretValueError>> {
         let mut request = SignedRequest::new("POST", "secretsmanager", &self.region, "/");
+        request.set_operation("GetSecretValue");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "secretsmanager.GetSecretValue");
@@ -2089,6 +2158,7 @@ impl SecretsManager for SecretsManagerClient {
         input: ListSecretVersionIdsRequest,
     ) -> Result<ListSecretVersionIdsResponse, RusotoError<ListSecretVersionIdsError>> {
         let mut request = SignedRequest::new("POST", "secretsmanager", &self.region, "/");
+        request.set_operation("ListSecretVersionIds");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "secretsmanager.ListSecretVersionIds");
@@ -2117,6 +2187,7 @@ impl SecretsManager for SecretsManagerClient {
         input: ListSecretsRequest,
     ) -> Result<ListSecretsResponse, RusotoError<ListSecretsError>> {
         let mut request = SignedRequest::new("POST", "secretsmanager", &self.region, "/");
+        request.set_operation("ListSecrets");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "secretsmanager.ListSecrets");
@@ -2144,6 +2215,7 @@ impl SecretsManager for SecretsManagerClient {
         input: PutResourcePolicyRequest,
     ) -> Result<PutResourcePolicyResponse, RusotoError<PutResourcePolicyError>> {
         let mut request = SignedRequest::new("POST", "secretsmanager", &self.region, "/");
+        request.set_operation("PutResourcePolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "secretsmanager.PutResourcePolicy");
@@ -2172,6 +2244,7 @@ impl SecretsManager for SecretsManagerClient {
         input: PutSecretValueRequest,
     ) -> Result<PutSecretValueResponse, RusotoError<PutSecretValueError>> {
         let mut request = SignedRequest::new("POST", "secretsmanager", &self.region, "/");
+        request.set_operation("PutSecretValue");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "secretsmanager.PutSecretValue");
@@ -2199,6 +2272,7 @@ impl SecretsManager for SecretsManagerClient {
         input: RestoreSecretRequest,
     ) -> Result<RestoreSecretResponse, RusotoError<RestoreSecretError>> {
         let mut request = SignedRequest::new("POST", "secretsmanager", &self.region, "/");
+        request.set_operation("RestoreSecret");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "secretsmanager.RestoreSecret");
@@ -2226,6 +2300,7 @@ impl SecretsManager for SecretsManagerClient {
         input: RotateSecretRequest,
     ) -> Result<RotateSecretResponse, RusotoError<RotateSecretError>> {
         let mut request = SignedRequest::new("POST", "secretsmanager", &self.region, "/");
+        request.set_operation("RotateSecret");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "secretsmanager.RotateSecret");
@@ -2253,6 +2328,7 @@ impl SecretsManager for SecretsManagerClient {
         input: TagResourceRequest,
     ) -> Result<(), RusotoError<TagResourceError>> {
         let mut request = SignedRequest::new("POST", "secretsmanager", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "secretsmanager.TagResource");
@@ -2280,6 +2356,7 @@ impl SecretsManager for SecretsManagerClient {
         input: UntagResourceRequest,
     ) -> Result<(), RusotoError<UntagResourceError>> {
         let mut request = SignedRequest::new("POST", "secretsmanager", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "secretsmanager.UntagResource");
@@ -2307,6 +2384,7 @@ impl SecretsManager for SecretsManagerClient {
         input: UpdateSecretRequest,
     ) -> Result<UpdateSecretResponse, RusotoError<UpdateSecretError>> {
         let mut request = SignedRequest::new("POST", "secretsmanager", &self.region, "/");
+        request.set_operation("UpdateSecret");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "secretsmanager.UpdateSecret");
@@ -2334,6 +2412,7 @@ impl SecretsManager for SecretsManagerClient {
         input: UpdateSecretVersionStageRequest,
     ) -> Result<UpdateSecretVersionStageResponse, RusotoError<UpdateSecretVersionStageError>> {
         let mut request = SignedRequest::new("POST", "secretsmanager", &self.region, "/");
+        request.set_operation("UpdateSecretVersionStage");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "secretsmanager.UpdateSecretVersionStage");
@@ -2356,3 +2435,35 @@ impl SecretsManager for SecretsManagerClient {
         }
     }
 }
+impl SecretsManagerClient {
+    /// Auto-paginating version of `list_secrets`, streaming every page of results.
+    pub fn list_secrets_pages(
+        &self,
+        input: ListSecretsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListSecretsResponse, ListSecretsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SecretsManagerClient, input: ListSecretsRequest| async move {
+                client.list_secrets(input).await
+            },
+            |input: &ListSecretsRequest, output: &ListSecretsResponse| {
+                Some(ListSecretsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_secrets`, streaming the `SecretList` of every page of results.
+    pub fn list_secrets_items(
+        &self,
+        input: ListSecretsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<SecretListEntry, ListSecretsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_secrets_pages(input),
+            |output: ListSecretsResponse| output.secret_list.unwrap_or_default(),
+        )
+    }
+}
@@ -270,9 +270,102 @@ pub struct AwsIamAccessKeyDetails {
     /// <p>The status of the IAM access key related to a finding.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<AwsIamAccessKeyStatus>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AwsIamAccessKeyStatus {
+    /// `Active`
+    Active,
+    /// `Inactive`
+    Inactive,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AwsIamAccessKeyStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AwsIamAccessKeyStatus::Active => "Active",
+            AwsIamAccessKeyStatus::Inactive => "Inactive",
+            AwsIamAccessKeyStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AwsIamAccessKeyStatus {
+    fn default() -> AwsIamAccessKeyStatus {
+        AwsIamAccessKeyStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AwsIamAccessKeyStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AwsIamAccessKeyStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AwsIamAccessKeyStatus {
+    fn from(value: &'a str) -> AwsIamAccessKeyStatus {
+        match value {
+            "Active" => AwsIamAccessKeyStatus::Active,
+            "Inactive" => AwsIamAccessKeyStatus::Inactive,
+            _ => AwsIamAccessKeyStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AwsIamAccessKeyStatus {
+    fn from(value: String) -> AwsIamAccessKeyStatus {
+        match value.as_str() {
+            "Active" => AwsIamAccessKeyStatus::Active,
+            "Inactive" => AwsIamAccessKeyStatus::Inactive,
+            _ => AwsIamAccessKeyStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AwsIamAccessKeyStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AwsIamAccessKeyStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AwsIamAccessKeyStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AwsIamAccessKeyStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for AwsIamAccessKeyStatus {
+    fn deserialize<D>(deserializer: D) -> Result<AwsIamAccessKeyStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Contains information about an IAM role, including all of the role's policies.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AwsIamRoleDetails {
@@ -580,7 +673,7 @@ pub struct AwsSecurityFinding {
     /// <p>The record state of a finding.</p>
     #[serde(rename = "RecordState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub record_state: Option<String>,
+    pub record_state: Option<RecordState>,
     /// <p>A list of related findings.</p>
     #[serde(rename = "RelatedFindings")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -622,11 +715,11 @@ pub struct AwsSecurityFinding {
     /// <p>Indicates the veracity of a finding. </p>
     #[serde(rename = "VerificationState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub verification_state: Option<String>,
+    pub verification_state: Option<VerificationState>,
     /// <p>The workflow state of a finding. </p>
     #[serde(rename = "WorkflowState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub workflow_state: Option<String>,
+    pub workflow_state: Option<WorkflowState>,
 }
 
 /// <p>A collection of attributes that are applied to all active Security Hub-aggregated findings and that result in a subset of findings that are included in this insight. </p>
@@ -1084,9 +1177,112 @@ pub struct Compliance {
     /// <p>The result of a compliance check.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ComplianceStatus>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ComplianceStatus {
+    /// `PASSED`
+    Passed,
+    /// `WARNING`
+    Warning,
+    /// `FAILED`
+    Failed,
+    /// `NOT_AVAILABLE`
+    NotAvailable,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ComplianceStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ComplianceStatus::Passed => "PASSED",
+            ComplianceStatus::Warning => "WARNING",
+            ComplianceStatus::Failed => "FAILED",
+            ComplianceStatus::NotAvailable => "NOT_AVAILABLE",
+            ComplianceStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ComplianceStatus {
+    fn default() -> ComplianceStatus {
+        ComplianceStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ComplianceStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ComplianceStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ComplianceStatus {
+    fn from(value: &'a str) -> ComplianceStatus {
+        match value {
+            "PASSED" => ComplianceStatus::Passed,
+            "WARNING" => ComplianceStatus::Warning,
+            "FAILED" => ComplianceStatus::Failed,
+            "NOT_AVAILABLE" => ComplianceStatus::NotAvailable,
+            _ => ComplianceStatus::Unknown(value.to_owned()),
+        }
+    }
 }
 
+impl From<String> for ComplianceStatus {
+    fn from(value: String) -> ComplianceStatus {
+        match value.as_str() {
+            "PASSED" => ComplianceStatus::Passed,
+            "WARNING" => ComplianceStatus::Warning,
+            "FAILED" => ComplianceStatus::Failed,
+            "NOT_AVAILABLE" => ComplianceStatus::NotAvailable,
+            _ => ComplianceStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ComplianceStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ComplianceStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ComplianceStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ComplianceStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ComplianceStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ComplianceStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Container details related to a finding.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ContainerDetails {
@@ -1108,6 +1304,99 @@ pub struct ContainerDetails {
     pub name: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ControlStatus {
+    /// `ENABLED`
+    Enabled,
+    /// `DISABLED`
+    Disabled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ControlStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ControlStatus::Enabled => "ENABLED",
+            ControlStatus::Disabled => "DISABLED",
+            ControlStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ControlStatus {
+    fn default() -> ControlStatus {
+        ControlStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ControlStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ControlStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ControlStatus {
+    fn from(value: &'a str) -> ControlStatus {
+        match value {
+            "ENABLED" => ControlStatus::Enabled,
+            "DISABLED" => ControlStatus::Disabled,
+            _ => ControlStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ControlStatus {
+    fn from(value: String) -> ControlStatus {
+        match value.as_str() {
+            "ENABLED" => ControlStatus::Enabled,
+            "DISABLED" => ControlStatus::Disabled,
+            _ => ControlStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ControlStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ControlStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ControlStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ControlStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ControlStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ControlStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateActionTargetRequest {
@@ -1193,13 +1482,101 @@ pub struct DateRange {
     /// <p>A date range unit for the date filter.</p>
     #[serde(rename = "Unit")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub unit: Option<String>,
+    pub unit: Option<DateRangeUnit>,
     /// <p>A date range value for the date filter.</p>
     #[serde(rename = "Value")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<i64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DateRangeUnit {
+    /// `DAYS`
+    Days,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DateRangeUnit {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DateRangeUnit::Days => "DAYS",
+            DateRangeUnit::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DateRangeUnit {
+    fn default() -> DateRangeUnit {
+        DateRangeUnit::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DateRangeUnit {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DateRangeUnit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DateRangeUnit {
+    fn from(value: &'a str) -> DateRangeUnit {
+        match value {
+            "DAYS" => DateRangeUnit::Days,
+            _ => DateRangeUnit::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DateRangeUnit {
+    fn from(value: String) -> DateRangeUnit {
+        match value.as_str() {
+            "DAYS" => DateRangeUnit::Days,
+            _ => DateRangeUnit::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DateRangeUnit {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DateRangeUnit, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DateRangeUnit {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DateRangeUnit {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DateRangeUnit {
+    fn deserialize<D>(deserializer: D) -> Result<DateRangeUnit, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct DeclineInvitationsRequest {
@@ -1689,8 +2066,13 @@ pub struct Invitation {
     pub invitation_id: Option<String>,
     /// <p>The timestamp of when the invitation was sent.</p>
     #[serde(rename = "InvitedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub invited_at: Option<f64>,
+    pub invited_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The current status of the association between member and master accounts.</p>
     #[serde(rename = "MemberStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1858,20 +2240,276 @@ pub struct Malware {
     /// <p>The state of the malware that was observed.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<MalwareState>,
     /// <p>The type of the malware that was observed.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<MalwareType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MalwareState {
+    /// `OBSERVED`
+    Observed,
+    /// `REMOVAL_FAILED`
+    RemovalFailed,
+    /// `REMOVED`
+    Removed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl MalwareState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MalwareState::Observed => "OBSERVED",
+            MalwareState::RemovalFailed => "REMOVAL_FAILED",
+            MalwareState::Removed => "REMOVED",
+            MalwareState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for MalwareState {
+    fn default() -> MalwareState {
+        MalwareState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for MalwareState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for MalwareState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for MalwareState {
+    fn from(value: &'a str) -> MalwareState {
+        match value {
+            "OBSERVED" => MalwareState::Observed,
+            "REMOVAL_FAILED" => MalwareState::RemovalFailed,
+            "REMOVED" => MalwareState::Removed,
+            _ => MalwareState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MalwareState {
+    fn from(value: String) -> MalwareState {
+        match value.as_str() {
+            "OBSERVED" => MalwareState::Observed,
+            "REMOVAL_FAILED" => MalwareState::RemovalFailed,
+            "REMOVED" => MalwareState::Removed,
+            _ => MalwareState::Unknown(value),
+        }
+    }
 }
 
+impl ::std::str::FromStr for MalwareState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<MalwareState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MalwareState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for MalwareState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MalwareState {
+    fn deserialize<D>(deserializer: D) -> Result<MalwareState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MalwareType {
+    /// `ADWARE`
+    Adware,
+    /// `BLENDED_THREAT`
+    BlendedThreat,
+    /// `BOTNET_AGENT`
+    BotnetAgent,
+    /// `COIN_MINER`
+    CoinMiner,
+    /// `EXPLOIT_KIT`
+    ExploitKit,
+    /// `KEYLOGGER`
+    Keylogger,
+    /// `MACRO`
+    Macro,
+    /// `POTENTIALLY_UNWANTED`
+    PotentiallyUnwanted,
+    /// `SPYWARE`
+    Spyware,
+    /// `RANSOMWARE`
+    Ransomware,
+    /// `REMOTE_ACCESS`
+    RemoteAccess,
+    /// `ROOTKIT`
+    Rootkit,
+    /// `TROJAN`
+    Trojan,
+    /// `VIRUS`
+    Virus,
+    /// `WORM`
+    Worm,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl MalwareType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MalwareType::Adware => "ADWARE",
+            MalwareType::BlendedThreat => "BLENDED_THREAT",
+            MalwareType::BotnetAgent => "BOTNET_AGENT",
+            MalwareType::CoinMiner => "COIN_MINER",
+            MalwareType::ExploitKit => "EXPLOIT_KIT",
+            MalwareType::Keylogger => "KEYLOGGER",
+            MalwareType::Macro => "MACRO",
+            MalwareType::PotentiallyUnwanted => "POTENTIALLY_UNWANTED",
+            MalwareType::Spyware => "SPYWARE",
+            MalwareType::Ransomware => "RANSOMWARE",
+            MalwareType::RemoteAccess => "REMOTE_ACCESS",
+            MalwareType::Rootkit => "ROOTKIT",
+            MalwareType::Trojan => "TROJAN",
+            MalwareType::Virus => "VIRUS",
+            MalwareType::Worm => "WORM",
+            MalwareType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for MalwareType {
+    fn default() -> MalwareType {
+        MalwareType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for MalwareType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for MalwareType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for MalwareType {
+    fn from(value: &'a str) -> MalwareType {
+        match value {
+            "ADWARE" => MalwareType::Adware,
+            "BLENDED_THREAT" => MalwareType::BlendedThreat,
+            "BOTNET_AGENT" => MalwareType::BotnetAgent,
+            "COIN_MINER" => MalwareType::CoinMiner,
+            "EXPLOIT_KIT" => MalwareType::ExploitKit,
+            "KEYLOGGER" => MalwareType::Keylogger,
+            "MACRO" => MalwareType::Macro,
+            "POTENTIALLY_UNWANTED" => MalwareType::PotentiallyUnwanted,
+            "SPYWARE" => MalwareType::Spyware,
+            "RANSOMWARE" => MalwareType::Ransomware,
+            "REMOTE_ACCESS" => MalwareType::RemoteAccess,
+            "ROOTKIT" => MalwareType::Rootkit,
+            "TROJAN" => MalwareType::Trojan,
+            "VIRUS" => MalwareType::Virus,
+            "WORM" => MalwareType::Worm,
+            _ => MalwareType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MalwareType {
+    fn from(value: String) -> MalwareType {
+        match value.as_str() {
+            "ADWARE" => MalwareType::Adware,
+            "BLENDED_THREAT" => MalwareType::BlendedThreat,
+            "BOTNET_AGENT" => MalwareType::BotnetAgent,
+            "COIN_MINER" => MalwareType::CoinMiner,
+            "EXPLOIT_KIT" => MalwareType::ExploitKit,
+            "KEYLOGGER" => MalwareType::Keylogger,
+            "MACRO" => MalwareType::Macro,
+            "POTENTIALLY_UNWANTED" => MalwareType::PotentiallyUnwanted,
+            "SPYWARE" => MalwareType::Spyware,
+            "RANSOMWARE" => MalwareType::Ransomware,
+            "REMOTE_ACCESS" => MalwareType::RemoteAccess,
+            "ROOTKIT" => MalwareType::Rootkit,
+            "TROJAN" => MalwareType::Trojan,
+            "VIRUS" => MalwareType::Virus,
+            "WORM" => MalwareType::Worm,
+            _ => MalwareType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for MalwareType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<MalwareType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MalwareType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for MalwareType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MalwareType {
+    fn deserialize<D>(deserializer: D) -> Result<MalwareType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The map filter for querying findings.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MapFilter {
     /// <p>The condition to apply to a key value when querying for findings with a map filter.</p>
     #[serde(rename = "Comparison")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub comparison: Option<String>,
+    pub comparison: Option<MapFilterComparison>,
     /// <p>The key of the map filter.</p>
     #[serde(rename = "Key")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1882,22 +2520,130 @@ pub struct MapFilter {
     pub value: Option<String>,
 }
 
-/// <p>The details about a member account.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct Member {
-    /// <p>The AWS account ID of the member account.</p>
-    #[serde(rename = "AccountId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub account_id: Option<String>,
-    /// <p>The email address of the member account.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MapFilterComparison {
+    /// `EQUALS`
+    Equals,
+    /// `NOT_EQUALS`
+    NotEquals,
+    /// `CONTAINS`
+    Contains,
+    /// `NOT_CONTAINS`
+    NotContains,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl MapFilterComparison {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MapFilterComparison::Equals => "EQUALS",
+            MapFilterComparison::NotEquals => "NOT_EQUALS",
+            MapFilterComparison::Contains => "CONTAINS",
+            MapFilterComparison::NotContains => "NOT_CONTAINS",
+            MapFilterComparison::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for MapFilterComparison {
+    fn default() -> MapFilterComparison {
+        MapFilterComparison::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for MapFilterComparison {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for MapFilterComparison {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for MapFilterComparison {
+    fn from(value: &'a str) -> MapFilterComparison {
+        match value {
+            "EQUALS" => MapFilterComparison::Equals,
+            "NOT_EQUALS" => MapFilterComparison::NotEquals,
+            "CONTAINS" => MapFilterComparison::Contains,
+            "NOT_CONTAINS" => MapFilterComparison::NotContains,
+            _ => MapFilterComparison::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MapFilterComparison {
+    fn from(value: String) -> MapFilterComparison {
+        match value.as_str() {
+            "EQUALS" => MapFilterComparison::Equals,
+            "NOT_EQUALS" => MapFilterComparison::NotEquals,
+            "CONTAINS" => MapFilterComparison::Contains,
+            "NOT_CONTAINS" => MapFilterComparison::NotContains,
+            _ => MapFilterComparison::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for MapFilterComparison {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<MapFilterComparison, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MapFilterComparison {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for MapFilterComparison {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MapFilterComparison {
+    fn deserialize<D>(deserializer: D) -> Result<MapFilterComparison, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>The details about a member account.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct Member {
+    /// <p>The AWS account ID of the member account.</p>
+    #[serde(rename = "AccountId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_id: Option<String>,
+    /// <p>The email address of the member account.</p>
     #[serde(rename = "Email")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub email: Option<String>,
     /// <p>A timestamp for the date and time when the invitation was sent to the member account.</p>
     #[serde(rename = "InvitedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub invited_at: Option<f64>,
+    pub invited_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The AWS account ID of the Security Hub master account associated with this member account.</p>
     #[serde(rename = "MasterId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1908,8 +2654,13 @@ pub struct Member {
     pub member_status: Option<String>,
     /// <p>The timestamp for the date and time when the member account was updated.</p>
     #[serde(rename = "UpdatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<f64>,
+    pub updated_at: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>The details of network-related information about a finding.</p>
@@ -1934,7 +2685,7 @@ pub struct Network {
     /// <p>The direction of network traffic associated with a finding.</p>
     #[serde(rename = "Direction")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub direction: Option<String>,
+    pub direction: Option<NetworkDirection>,
     /// <p>The protocol of network-related information about a finding.</p>
     #[serde(rename = "Protocol")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1961,6 +2712,99 @@ pub struct Network {
     pub source_port: Option<i64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum NetworkDirection {
+    /// `IN`
+    In,
+    /// `OUT`
+    Out,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl NetworkDirection {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            NetworkDirection::In => "IN",
+            NetworkDirection::Out => "OUT",
+            NetworkDirection::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for NetworkDirection {
+    fn default() -> NetworkDirection {
+        NetworkDirection::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for NetworkDirection {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for NetworkDirection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for NetworkDirection {
+    fn from(value: &'a str) -> NetworkDirection {
+        match value {
+            "IN" => NetworkDirection::In,
+            "OUT" => NetworkDirection::Out,
+            _ => NetworkDirection::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for NetworkDirection {
+    fn from(value: String) -> NetworkDirection {
+        match value.as_str() {
+            "IN" => NetworkDirection::In,
+            "OUT" => NetworkDirection::Out,
+            _ => NetworkDirection::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for NetworkDirection {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<NetworkDirection, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for NetworkDirection {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for NetworkDirection {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for NetworkDirection {
+    fn deserialize<D>(deserializer: D) -> Result<NetworkDirection, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A user-defined note added to a finding.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Note {
@@ -2004,6 +2848,104 @@ pub struct NumberFilter {
     pub lte: Option<f64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Partition {
+    /// `aws`
+    Aws,
+    /// `aws-cn`
+    AwsCn,
+    /// `aws-us-gov`
+    AwsUsGov,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Partition {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Partition::Aws => "aws",
+            Partition::AwsCn => "aws-cn",
+            Partition::AwsUsGov => "aws-us-gov",
+            Partition::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Partition {
+    fn default() -> Partition {
+        Partition::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Partition {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Partition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Partition {
+    fn from(value: &'a str) -> Partition {
+        match value {
+            "aws" => Partition::Aws,
+            "aws-cn" => Partition::AwsCn,
+            "aws-us-gov" => Partition::AwsUsGov,
+            _ => Partition::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Partition {
+    fn from(value: String) -> Partition {
+        match value.as_str() {
+            "aws" => Partition::Aws,
+            "aws-cn" => Partition::AwsCn,
+            "aws-us-gov" => Partition::AwsUsGov,
+            _ => Partition::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Partition {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Partition, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Partition {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Partition {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Partition {
+    fn deserialize<D>(deserializer: D) -> Result<Partition, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The details of process-related information about a finding.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ProcessDetails {
@@ -2083,6 +3025,99 @@ pub struct Recommendation {
     pub url: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RecordState {
+    /// `ACTIVE`
+    Active,
+    /// `ARCHIVED`
+    Archived,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RecordState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RecordState::Active => "ACTIVE",
+            RecordState::Archived => "ARCHIVED",
+            RecordState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RecordState {
+    fn default() -> RecordState {
+        RecordState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RecordState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RecordState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RecordState {
+    fn from(value: &'a str) -> RecordState {
+        match value {
+            "ACTIVE" => RecordState::Active,
+            "ARCHIVED" => RecordState::Archived,
+            _ => RecordState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RecordState {
+    fn from(value: String) -> RecordState {
+        match value.as_str() {
+            "ACTIVE" => RecordState::Active,
+            "ARCHIVED" => RecordState::Archived,
+            _ => RecordState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RecordState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RecordState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RecordState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RecordState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RecordState {
+    fn deserialize<D>(deserializer: D) -> Result<RecordState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Details about a related finding.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RelatedFinding {
@@ -2116,7 +3151,7 @@ pub struct Resource {
     /// <p>The canonical AWS partition name that the Region is assigned to.</p>
     #[serde(rename = "Partition")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub partition: Option<String>,
+    pub partition: Option<Partition>,
     /// <p>The canonical AWS external Region name where this resource is located.</p>
     #[serde(rename = "Region")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2209,6 +3244,109 @@ pub struct Severity {
     pub product: Option<f64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SeverityRating {
+    /// `LOW`
+    Low,
+    /// `MEDIUM`
+    Medium,
+    /// `HIGH`
+    High,
+    /// `CRITICAL`
+    Critical,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SeverityRating {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SeverityRating::Low => "LOW",
+            SeverityRating::Medium => "MEDIUM",
+            SeverityRating::High => "HIGH",
+            SeverityRating::Critical => "CRITICAL",
+            SeverityRating::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SeverityRating {
+    fn default() -> SeverityRating {
+        SeverityRating::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SeverityRating {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SeverityRating {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SeverityRating {
+    fn from(value: &'a str) -> SeverityRating {
+        match value {
+            "LOW" => SeverityRating::Low,
+            "MEDIUM" => SeverityRating::Medium,
+            "HIGH" => SeverityRating::High,
+            "CRITICAL" => SeverityRating::Critical,
+            _ => SeverityRating::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SeverityRating {
+    fn from(value: String) -> SeverityRating {
+        match value.as_str() {
+            "LOW" => SeverityRating::Low,
+            "MEDIUM" => SeverityRating::Medium,
+            "HIGH" => SeverityRating::High,
+            "CRITICAL" => SeverityRating::Critical,
+            _ => SeverityRating::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SeverityRating {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SeverityRating, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SeverityRating {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SeverityRating {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SeverityRating {
+    fn deserialize<D>(deserializer: D) -> Result<SeverityRating, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A collection of finding attributes used to sort findings.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -2220,9 +3358,102 @@ pub struct SortCriterion {
     /// <p>The order used to sort findings.</p>
     #[serde(rename = "SortOrder")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort_order: Option<String>,
+    pub sort_order: Option<SortOrder>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SortOrder {
+    /// `asc`
+    Asc,
+    /// `desc`
+    Desc,
+    /// A value not known to this version of the crate
+    Unknown(String),
 }
 
+impl SortOrder {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SortOrder::Asc => "asc",
+            SortOrder::Desc => "desc",
+            SortOrder::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SortOrder {
+    fn default() -> SortOrder {
+        SortOrder::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SortOrder {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SortOrder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SortOrder {
+    fn from(value: &'a str) -> SortOrder {
+        match value {
+            "asc" => SortOrder::Asc,
+            "desc" => SortOrder::Desc,
+            _ => SortOrder::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SortOrder {
+    fn from(value: String) -> SortOrder {
+        match value.as_str() {
+            "asc" => SortOrder::Asc,
+            "desc" => SortOrder::Desc,
+            _ => SortOrder::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SortOrder {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SortOrder, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SortOrder {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SortOrder {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SortOrder {
+    fn deserialize<D>(deserializer: D) -> Result<SortOrder, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Details for an individual compliance standard control.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -2234,11 +3465,16 @@ pub struct StandardsControl {
     /// <p>The current status of the compliance standard control. Indicates whether the control is enabled or disabled. Security Hub does not check against disabled controls.</p>
     #[serde(rename = "ControlStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub control_status: Option<String>,
+    pub control_status: Option<ControlStatus>,
     /// <p>The date and time that the status of the compliance standard control was most recently updated.</p>
     #[serde(rename = "ControlStatusUpdatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub control_status_updated_at: Option<f64>,
+    pub control_status_updated_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The longer description of the compliance standard control. Provides information about what the control is checking for.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2254,7 +3490,7 @@ pub struct StandardsControl {
     /// <p>The severity of findings generated from this compliance standard control.</p> <p>The finding severity is based on an assessment of how easy it would be to compromise AWS resources if the compliance issue is detected.</p>
     #[serde(rename = "SeverityRating")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub severity_rating: Option<String>,
+    pub severity_rating: Option<SeverityRating>,
     /// <p>The ARN of the compliance standard control.</p>
     #[serde(rename = "StandardsControlArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2265,6 +3501,114 @@ pub struct StandardsControl {
     pub title: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StandardsStatus {
+    /// `PENDING`
+    Pending,
+    /// `READY`
+    Ready,
+    /// `FAILED`
+    Failed,
+    /// `DELETING`
+    Deleting,
+    /// `INCOMPLETE`
+    Incomplete,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl StandardsStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StandardsStatus::Pending => "PENDING",
+            StandardsStatus::Ready => "READY",
+            StandardsStatus::Failed => "FAILED",
+            StandardsStatus::Deleting => "DELETING",
+            StandardsStatus::Incomplete => "INCOMPLETE",
+            StandardsStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for StandardsStatus {
+    fn default() -> StandardsStatus {
+        StandardsStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for StandardsStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for StandardsStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for StandardsStatus {
+    fn from(value: &'a str) -> StandardsStatus {
+        match value {
+            "PENDING" => StandardsStatus::Pending,
+            "READY" => StandardsStatus::Ready,
+            "FAILED" => StandardsStatus::Failed,
+            "DELETING" => StandardsStatus::Deleting,
+            "INCOMPLETE" => StandardsStatus::Incomplete,
+            _ => StandardsStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StandardsStatus {
+    fn from(value: String) -> StandardsStatus {
+        match value.as_str() {
+            "PENDING" => StandardsStatus::Pending,
+            "READY" => StandardsStatus::Ready,
+            "FAILED" => StandardsStatus::Failed,
+            "DELETING" => StandardsStatus::Deleting,
+            "INCOMPLETE" => StandardsStatus::Incomplete,
+            _ => StandardsStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for StandardsStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<StandardsStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StandardsStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for StandardsStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StandardsStatus {
+    fn deserialize<D>(deserializer: D) -> Result<StandardsStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A resource that represents your subscription to a supported standard.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -2277,7 +3621,7 @@ pub struct StandardsSubscription {
     pub standards_input: ::std::collections::HashMap<String, String>,
     /// <p>The status of the standards subscription.</p>
     #[serde(rename = "StandardsStatus")]
-    pub standards_status: String,
+    pub standards_status: StandardsStatus,
     /// <p>The ARN of a resource that represents your subscription to a supported standard.</p>
     #[serde(rename = "StandardsSubscriptionArn")]
     pub standards_subscription_arn: String,
@@ -2296,19 +3640,132 @@ pub struct StandardsSubscriptionRequest {
     pub standards_input: Option<::std::collections::HashMap<String, String>>,
 }
 
-/// <p>A string filter for querying findings.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct StringFilter {
-    /// <p>The condition to be applied to a string value when querying for findings. </p>
-    #[serde(rename = "Comparison")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub comparison: Option<String>,
-    /// <p>The string filter value.</p>
-    #[serde(rename = "Value")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub value: Option<String>,
+/// <p>A string filter for querying findings.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StringFilter {
+    /// <p>The condition to be applied to a string value when querying for findings. </p>
+    #[serde(rename = "Comparison")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comparison: Option<StringFilterComparison>,
+    /// <p>The string filter value.</p>
+    #[serde(rename = "Value")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StringFilterComparison {
+    /// `EQUALS`
+    Equals,
+    /// `PREFIX`
+    Prefix,
+    /// `NOT_EQUALS`
+    NotEquals,
+    /// `PREFIX_NOT_EQUALS`
+    PrefixNotEquals,
+    /// `CONTAINS`
+    Contains,
+    /// `NOT_CONTAINS`
+    NotContains,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl StringFilterComparison {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StringFilterComparison::Equals => "EQUALS",
+            StringFilterComparison::Prefix => "PREFIX",
+            StringFilterComparison::NotEquals => "NOT_EQUALS",
+            StringFilterComparison::PrefixNotEquals => "PREFIX_NOT_EQUALS",
+            StringFilterComparison::Contains => "CONTAINS",
+            StringFilterComparison::NotContains => "NOT_CONTAINS",
+            StringFilterComparison::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for StringFilterComparison {
+    fn default() -> StringFilterComparison {
+        StringFilterComparison::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for StringFilterComparison {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for StringFilterComparison {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for StringFilterComparison {
+    fn from(value: &'a str) -> StringFilterComparison {
+        match value {
+            "EQUALS" => StringFilterComparison::Equals,
+            "PREFIX" => StringFilterComparison::Prefix,
+            "NOT_EQUALS" => StringFilterComparison::NotEquals,
+            "PREFIX_NOT_EQUALS" => StringFilterComparison::PrefixNotEquals,
+            "CONTAINS" => StringFilterComparison::Contains,
+            "NOT_CONTAINS" => StringFilterComparison::NotContains,
+            _ => StringFilterComparison::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StringFilterComparison {
+    fn from(value: String) -> StringFilterComparison {
+        match value.as_str() {
+            "EQUALS" => StringFilterComparison::Equals,
+            "PREFIX" => StringFilterComparison::Prefix,
+            "NOT_EQUALS" => StringFilterComparison::NotEquals,
+            "PREFIX_NOT_EQUALS" => StringFilterComparison::PrefixNotEquals,
+            "CONTAINS" => StringFilterComparison::Contains,
+            "NOT_CONTAINS" => StringFilterComparison::NotContains,
+            _ => StringFilterComparison::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for StringFilterComparison {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<StringFilterComparison, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StringFilterComparison {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for StringFilterComparison {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for StringFilterComparison {
+    fn deserialize<D>(deserializer: D) -> Result<StringFilterComparison, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct TagResourceRequest {
@@ -2330,7 +3787,7 @@ pub struct ThreatIntelIndicator {
     /// <p>The category of a threat intel indicator.</p>
     #[serde(rename = "Category")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub category: Option<String>,
+    pub category: Option<ThreatIntelIndicatorCategory>,
     /// <p>The date and time when the most recent instance of a threat intel indicator was observed.</p>
     #[serde(rename = "LastObservedAt")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2346,13 +3803,264 @@ pub struct ThreatIntelIndicator {
     /// <p>The type of a threat intel indicator.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<ThreatIntelIndicatorType>,
     /// <p>The value of a threat intel indicator.</p>
     #[serde(rename = "Value")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ThreatIntelIndicatorCategory {
+    /// `BACKDOOR`
+    Backdoor,
+    /// `CARD_STEALER`
+    CardStealer,
+    /// `COMMAND_AND_CONTROL`
+    CommandAndControl,
+    /// `DROP_SITE`
+    DropSite,
+    /// `EXPLOIT_SITE`
+    ExploitSite,
+    /// `KEYLOGGER`
+    Keylogger,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ThreatIntelIndicatorCategory {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ThreatIntelIndicatorCategory::Backdoor => "BACKDOOR",
+            ThreatIntelIndicatorCategory::CardStealer => "CARD_STEALER",
+            ThreatIntelIndicatorCategory::CommandAndControl => "COMMAND_AND_CONTROL",
+            ThreatIntelIndicatorCategory::DropSite => "DROP_SITE",
+            ThreatIntelIndicatorCategory::ExploitSite => "EXPLOIT_SITE",
+            ThreatIntelIndicatorCategory::Keylogger => "KEYLOGGER",
+            ThreatIntelIndicatorCategory::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ThreatIntelIndicatorCategory {
+    fn default() -> ThreatIntelIndicatorCategory {
+        ThreatIntelIndicatorCategory::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ThreatIntelIndicatorCategory {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ThreatIntelIndicatorCategory {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ThreatIntelIndicatorCategory {
+    fn from(value: &'a str) -> ThreatIntelIndicatorCategory {
+        match value {
+            "BACKDOOR" => ThreatIntelIndicatorCategory::Backdoor,
+            "CARD_STEALER" => ThreatIntelIndicatorCategory::CardStealer,
+            "COMMAND_AND_CONTROL" => ThreatIntelIndicatorCategory::CommandAndControl,
+            "DROP_SITE" => ThreatIntelIndicatorCategory::DropSite,
+            "EXPLOIT_SITE" => ThreatIntelIndicatorCategory::ExploitSite,
+            "KEYLOGGER" => ThreatIntelIndicatorCategory::Keylogger,
+            _ => ThreatIntelIndicatorCategory::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ThreatIntelIndicatorCategory {
+    fn from(value: String) -> ThreatIntelIndicatorCategory {
+        match value.as_str() {
+            "BACKDOOR" => ThreatIntelIndicatorCategory::Backdoor,
+            "CARD_STEALER" => ThreatIntelIndicatorCategory::CardStealer,
+            "COMMAND_AND_CONTROL" => ThreatIntelIndicatorCategory::CommandAndControl,
+            "DROP_SITE" => ThreatIntelIndicatorCategory::DropSite,
+            "EXPLOIT_SITE" => ThreatIntelIndicatorCategory::ExploitSite,
+            "KEYLOGGER" => ThreatIntelIndicatorCategory::Keylogger,
+            _ => ThreatIntelIndicatorCategory::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ThreatIntelIndicatorCategory {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ThreatIntelIndicatorCategory, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ThreatIntelIndicatorCategory {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ThreatIntelIndicatorCategory {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ThreatIntelIndicatorCategory {
+    fn deserialize<D>(deserializer: D) -> Result<ThreatIntelIndicatorCategory, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ThreatIntelIndicatorType {
+    /// `DOMAIN`
+    Domain,
+    /// `EMAIL_ADDRESS`
+    EmailAddress,
+    /// `HASH_MD5`
+    HashMd5,
+    /// `HASH_SHA1`
+    HashSha1,
+    /// `HASH_SHA256`
+    HashSha256,
+    /// `HASH_SHA512`
+    HashSha512,
+    /// `IPV4_ADDRESS`
+    Ipv4Address,
+    /// `IPV6_ADDRESS`
+    Ipv6Address,
+    /// `MUTEX`
+    Mutex,
+    /// `PROCESS`
+    Process,
+    /// `URL`
+    Url,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ThreatIntelIndicatorType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ThreatIntelIndicatorType::Domain => "DOMAIN",
+            ThreatIntelIndicatorType::EmailAddress => "EMAIL_ADDRESS",
+            ThreatIntelIndicatorType::HashMd5 => "HASH_MD5",
+            ThreatIntelIndicatorType::HashSha1 => "HASH_SHA1",
+            ThreatIntelIndicatorType::HashSha256 => "HASH_SHA256",
+            ThreatIntelIndicatorType::HashSha512 => "HASH_SHA512",
+            ThreatIntelIndicatorType::Ipv4Address => "IPV4_ADDRESS",
+            ThreatIntelIndicatorType::Ipv6Address => "IPV6_ADDRESS",
+            ThreatIntelIndicatorType::Mutex => "MUTEX",
+            ThreatIntelIndicatorType::Process => "PROCESS",
+            ThreatIntelIndicatorType::Url => "URL",
+            ThreatIntelIndicatorType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ThreatIntelIndicatorType {
+    fn default() -> ThreatIntelIndicatorType {
+        ThreatIntelIndicatorType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ThreatIntelIndicatorType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ThreatIntelIndicatorType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ThreatIntelIndicatorType {
+    fn from(value: &'a str) -> ThreatIntelIndicatorType {
+        match value {
+            "DOMAIN" => ThreatIntelIndicatorType::Domain,
+            "EMAIL_ADDRESS" => ThreatIntelIndicatorType::EmailAddress,
+            "HASH_MD5" => ThreatIntelIndicatorType::HashMd5,
+            "HASH_SHA1" => ThreatIntelIndicatorType::HashSha1,
+            "HASH_SHA256" => ThreatIntelIndicatorType::HashSha256,
+            "HASH_SHA512" => ThreatIntelIndicatorType::HashSha512,
+            "IPV4_ADDRESS" => ThreatIntelIndicatorType::Ipv4Address,
+            "IPV6_ADDRESS" => ThreatIntelIndicatorType::Ipv6Address,
+            "MUTEX" => ThreatIntelIndicatorType::Mutex,
+            "PROCESS" => ThreatIntelIndicatorType::Process,
+            "URL" => ThreatIntelIndicatorType::Url,
+            _ => ThreatIntelIndicatorType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ThreatIntelIndicatorType {
+    fn from(value: String) -> ThreatIntelIndicatorType {
+        match value.as_str() {
+            "DOMAIN" => ThreatIntelIndicatorType::Domain,
+            "EMAIL_ADDRESS" => ThreatIntelIndicatorType::EmailAddress,
+            "HASH_MD5" => ThreatIntelIndicatorType::HashMd5,
+            "HASH_SHA1" => ThreatIntelIndicatorType::HashSha1,
+            "HASH_SHA256" => ThreatIntelIndicatorType::HashSha256,
+            "HASH_SHA512" => ThreatIntelIndicatorType::HashSha512,
+            "IPV4_ADDRESS" => ThreatIntelIndicatorType::Ipv4Address,
+            "IPV6_ADDRESS" => ThreatIntelIndicatorType::Ipv6Address,
+            "MUTEX" => ThreatIntelIndicatorType::Mutex,
+            "PROCESS" => ThreatIntelIndicatorType::Process,
+            "URL" => ThreatIntelIndicatorType::Url,
+            _ => ThreatIntelIndicatorType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ThreatIntelIndicatorType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ThreatIntelIndicatorType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ThreatIntelIndicatorType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ThreatIntelIndicatorType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ThreatIntelIndicatorType {
+    fn deserialize<D>(deserializer: D) -> Result<ThreatIntelIndicatorType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct UntagResourceRequest {
@@ -2401,7 +4109,7 @@ pub struct UpdateFindingsRequest {
     /// <p>The updated record state for the finding.</p>
     #[serde(rename = "RecordState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub record_state: Option<String>,
+    pub record_state: Option<RecordState>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -2438,7 +4146,7 @@ pub struct UpdateStandardsControlRequest {
     /// <p>The updated status of the compliance standard control.</p>
     #[serde(rename = "ControlStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub control_status: Option<String>,
+    pub control_status: Option<ControlStatus>,
     /// <p>A description of the reason why you are disabling a compliance standard control.</p>
     #[serde(rename = "DisabledReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2452,6 +4160,217 @@ pub struct UpdateStandardsControlRequest {
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateStandardsControlResponse {}
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum VerificationState {
+    /// `UNKNOWN`
+    UnknownValue,
+    /// `TRUE_POSITIVE`
+    TruePositive,
+    /// `FALSE_POSITIVE`
+    FalsePositive,
+    /// `BENIGN_POSITIVE`
+    BenignPositive,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl VerificationState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            VerificationState::UnknownValue => "UNKNOWN",
+            VerificationState::TruePositive => "TRUE_POSITIVE",
+            VerificationState::FalsePositive => "FALSE_POSITIVE",
+            VerificationState::BenignPositive => "BENIGN_POSITIVE",
+            VerificationState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for VerificationState {
+    fn default() -> VerificationState {
+        VerificationState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for VerificationState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for VerificationState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for VerificationState {
+    fn from(value: &'a str) -> VerificationState {
+        match value {
+            "UNKNOWN" => VerificationState::UnknownValue,
+            "TRUE_POSITIVE" => VerificationState::TruePositive,
+            "FALSE_POSITIVE" => VerificationState::FalsePositive,
+            "BENIGN_POSITIVE" => VerificationState::BenignPositive,
+            _ => VerificationState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for VerificationState {
+    fn from(value: String) -> VerificationState {
+        match value.as_str() {
+            "UNKNOWN" => VerificationState::UnknownValue,
+            "TRUE_POSITIVE" => VerificationState::TruePositive,
+            "FALSE_POSITIVE" => VerificationState::FalsePositive,
+            "BENIGN_POSITIVE" => VerificationState::BenignPositive,
+            _ => VerificationState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for VerificationState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<VerificationState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for VerificationState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for VerificationState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for VerificationState {
+    fn deserialize<D>(deserializer: D) -> Result<VerificationState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum WorkflowState {
+    /// `NEW`
+    New,
+    /// `ASSIGNED`
+    Assigned,
+    /// `IN_PROGRESS`
+    InProgress,
+    /// `DEFERRED`
+    Deferred,
+    /// `RESOLVED`
+    Resolved,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl WorkflowState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            WorkflowState::New => "NEW",
+            WorkflowState::Assigned => "ASSIGNED",
+            WorkflowState::InProgress => "IN_PROGRESS",
+            WorkflowState::Deferred => "DEFERRED",
+            WorkflowState::Resolved => "RESOLVED",
+            WorkflowState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for WorkflowState {
+    fn default() -> WorkflowState {
+        WorkflowState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for WorkflowState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for WorkflowState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for WorkflowState {
+    fn from(value: &'a str) -> WorkflowState {
+        match value {
+            "NEW" => WorkflowState::New,
+            "ASSIGNED" => WorkflowState::Assigned,
+            "IN_PROGRESS" => WorkflowState::InProgress,
+            "DEFERRED" => WorkflowState::Deferred,
+            "RESOLVED" => WorkflowState::Resolved,
+            _ => WorkflowState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for WorkflowState {
+    fn from(value: String) -> WorkflowState {
+        match value.as_str() {
+            "NEW" => WorkflowState::New,
+            "ASSIGNED" => WorkflowState::Assigned,
+            "IN_PROGRESS" => WorkflowState::InProgress,
+            "DEFERRED" => WorkflowState::Deferred,
+            "RESOLVED" => WorkflowState::Resolved,
+            _ => WorkflowState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for WorkflowState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<WorkflowState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for WorkflowState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for WorkflowState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for WorkflowState {
+    fn deserialize<D>(deserializer: D) -> Result<WorkflowState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// Errors returned by AcceptInvitation
 #[derive(Debug, PartialEq)]
 pub enum AcceptInvitationError {
@@ -4796,11 +6715,12 @@ pub struct SecurityHubClient {
 impl SecurityHubClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> SecurityHubClient {
         SecurityHubClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "SecurityHub"),
         }
     }
 
@@ -4834,6 +6754,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/master";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("AcceptInvitation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4864,6 +6785,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/standards/deregister";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("BatchDisableStandards");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4894,6 +6816,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/standards/register";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("BatchEnableStandards");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4924,6 +6847,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/findings/import";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("BatchImportFindings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4954,6 +6878,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/actionTargets";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("CreateActionTarget");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4984,6 +6909,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/insights";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("CreateInsight");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5014,6 +6940,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/members";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("CreateMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5044,6 +6971,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/invitations/decline";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("DeclineInvitations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5077,6 +7005,7 @@ impl SecurityHub for SecurityHubClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "securityhub", &self.region, &request_uri);
+        request.set_operation("DeleteActionTarget");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5104,6 +7033,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = format!("/insights/{insight_arn}", insight_arn = input.insight_arn);
 
         let mut request = SignedRequest::new("DELETE", "securityhub", &self.region, &request_uri);
+        request.set_operation("DeleteInsight");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5131,6 +7061,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/invitations/delete";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("DeleteInvitations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5161,6 +7092,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/members/delete";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("DeleteMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5191,6 +7123,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/actionTargets/get";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("DescribeActionTargets");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5221,6 +7154,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/accounts";
 
         let mut request = SignedRequest::new("GET", "securityhub", &self.region, &request_uri);
+        request.set_operation("DescribeHub");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5254,6 +7188,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/products";
 
         let mut request = SignedRequest::new("GET", "securityhub", &self.region, &request_uri);
+        request.set_operation("DescribeProducts");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5294,6 +7229,7 @@ impl SecurityHub for SecurityHubClient {
         );
 
         let mut request = SignedRequest::new("GET", "securityhub", &self.region, &request_uri);
+        request.set_operation("DescribeStandardsControls");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5336,6 +7272,7 @@ impl SecurityHub for SecurityHubClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "securityhub", &self.region, &request_uri);
+        request.set_operation("DisableImportFindingsForProduct");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5364,6 +7301,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/accounts";
 
         let mut request = SignedRequest::new("DELETE", "securityhub", &self.region, &request_uri);
+        request.set_operation("DisableSecurityHub");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5393,6 +7331,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/master/disassociate";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("DisassociateFromMasterAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5420,6 +7359,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/members/disassociate";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("DisassociateMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5453,6 +7393,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/productSubscriptions";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("EnableImportFindingsForProduct");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5483,6 +7424,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/accounts";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("EnableSecurityHub");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5513,6 +7455,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/standards/get";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("GetEnabledStandards");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5543,6 +7486,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/findings";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("GetFindings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5576,6 +7520,7 @@ impl SecurityHub for SecurityHubClient {
         );
 
         let mut request = SignedRequest::new("GET", "securityhub", &self.region, &request_uri);
+        request.set_operation("GetInsightResults");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5603,6 +7548,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/insights/get";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("GetInsights");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5632,6 +7578,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/invitations/count";
 
         let mut request = SignedRequest::new("GET", "securityhub", &self.region, &request_uri);
+        request.set_operation("GetInvitationsCount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5658,6 +7605,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/master";
 
         let mut request = SignedRequest::new("GET", "securityhub", &self.region, &request_uri);
+        request.set_operation("GetMasterAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5685,6 +7633,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/members/get";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("GetMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5715,6 +7664,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/members/invite";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("InviteMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5746,6 +7696,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/productSubscriptions";
 
         let mut request = SignedRequest::new("GET", "securityhub", &self.region, &request_uri);
+        request.set_operation("ListEnabledProductsForImport");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5782,6 +7733,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/invitations";
 
         let mut request = SignedRequest::new("GET", "securityhub", &self.region, &request_uri);
+        request.set_operation("ListInvitations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5818,6 +7770,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/members";
 
         let mut request = SignedRequest::new("GET", "securityhub", &self.region, &request_uri);
+        request.set_operation("ListMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5857,6 +7810,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "securityhub", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5884,6 +7838,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5914,6 +7869,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "securityhub", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5950,6 +7906,7 @@ impl SecurityHub for SecurityHubClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "securityhub", &self.region, &request_uri);
+        request.set_operation("UpdateActionTarget");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5980,6 +7937,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/findings";
 
         let mut request = SignedRequest::new("PATCH", "securityhub", &self.region, &request_uri);
+        request.set_operation("UpdateFindings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6010,6 +7968,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = format!("/insights/{insight_arn}", insight_arn = input.insight_arn);
 
         let mut request = SignedRequest::new("PATCH", "securityhub", &self.region, &request_uri);
+        request.set_operation("UpdateInsight");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6043,6 +8002,7 @@ impl SecurityHub for SecurityHubClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "securityhub", &self.region, &request_uri);
+        request.set_operation("UpdateStandardsControl");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6065,3 +8025,302 @@ impl SecurityHub for SecurityHubClient {
         }
     }
 }
+impl SecurityHubClient {
+    /// Auto-paginating version of `describe_action_targets`, streaming every page of results.
+    pub fn describe_action_targets_pages(
+        &self,
+        input: DescribeActionTargetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeActionTargetsResponse,
+        DescribeActionTargetsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SecurityHubClient, input: DescribeActionTargetsRequest| async move {
+                client.describe_action_targets(input).await
+            },
+            |input: &DescribeActionTargetsRequest, output: &DescribeActionTargetsResponse| {
+                Some(DescribeActionTargetsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_action_targets`, streaming the `ActionTargets` of every page of results.
+    pub fn describe_action_targets_items(
+        &self,
+        input: DescribeActionTargetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ActionTarget, DescribeActionTargetsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_action_targets_pages(input),
+            |output: DescribeActionTargetsResponse| output.action_targets,
+        )
+    }
+
+    /// Auto-paginating version of `describe_products`, streaming every page of results.
+    pub fn describe_products_pages(
+        &self,
+        input: DescribeProductsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeProductsResponse, DescribeProductsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SecurityHubClient, input: DescribeProductsRequest| async move {
+                client.describe_products(input).await
+            },
+            |input: &DescribeProductsRequest, output: &DescribeProductsResponse| {
+                Some(DescribeProductsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_products`, streaming the `Products` of every page of results.
+    pub fn describe_products_items(
+        &self,
+        input: DescribeProductsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Product, DescribeProductsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_products_pages(input),
+            |output: DescribeProductsResponse| output.products,
+        )
+    }
+
+    /// Auto-paginating version of `describe_standards_controls`, streaming every page of results.
+    pub fn describe_standards_controls_pages(
+        &self,
+        input: DescribeStandardsControlsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeStandardsControlsResponse,
+        DescribeStandardsControlsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SecurityHubClient, input: DescribeStandardsControlsRequest| async move {
+                client.describe_standards_controls(input).await
+            },
+            |input: &DescribeStandardsControlsRequest,
+             output: &DescribeStandardsControlsResponse| {
+                Some(DescribeStandardsControlsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_standards_controls`, streaming the `Controls` of every page of results.
+    pub fn describe_standards_controls_items(
+        &self,
+        input: DescribeStandardsControlsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<StandardsControl, DescribeStandardsControlsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_standards_controls_pages(input),
+            |output: DescribeStandardsControlsResponse| output.controls.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_enabled_standards`, streaming every page of results.
+    pub fn get_enabled_standards_pages(
+        &self,
+        input: GetEnabledStandardsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetEnabledStandardsResponse, GetEnabledStandardsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SecurityHubClient, input: GetEnabledStandardsRequest| async move {
+                client.get_enabled_standards(input).await
+            },
+            |input: &GetEnabledStandardsRequest, output: &GetEnabledStandardsResponse| {
+                Some(GetEnabledStandardsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_enabled_standards`, streaming the `StandardsSubscriptions` of every page of results.
+    pub fn get_enabled_standards_items(
+        &self,
+        input: GetEnabledStandardsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<StandardsSubscription, GetEnabledStandardsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.get_enabled_standards_pages(input),
+            |output: GetEnabledStandardsResponse| {
+                output.standards_subscriptions.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_findings`, streaming every page of results.
+    pub fn get_findings_pages(
+        &self,
+        input: GetFindingsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetFindingsResponse, GetFindingsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SecurityHubClient, input: GetFindingsRequest| async move {
+                client.get_findings(input).await
+            },
+            |input: &GetFindingsRequest, output: &GetFindingsResponse| {
+                Some(GetFindingsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_findings`, streaming the `Findings` of every page of results.
+    pub fn get_findings_items(
+        &self,
+        input: GetFindingsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<AwsSecurityFinding, GetFindingsError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_findings_pages(input),
+            |output: GetFindingsResponse| output.findings,
+        )
+    }
+
+    /// Auto-paginating version of `get_insights`, streaming every page of results.
+    pub fn get_insights_pages(
+        &self,
+        input: GetInsightsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetInsightsResponse, GetInsightsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SecurityHubClient, input: GetInsightsRequest| async move {
+                client.get_insights(input).await
+            },
+            |input: &GetInsightsRequest, output: &GetInsightsResponse| {
+                Some(GetInsightsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_insights`, streaming the `Insights` of every page of results.
+    pub fn get_insights_items(
+        &self,
+        input: GetInsightsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Insight, GetInsightsError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_insights_pages(input),
+            |output: GetInsightsResponse| output.insights,
+        )
+    }
+
+    /// Auto-paginating version of `list_enabled_products_for_import`, streaming every page of results.
+    pub fn list_enabled_products_for_import_pages(
+        &self,
+        input: ListEnabledProductsForImportRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListEnabledProductsForImportResponse,
+        ListEnabledProductsForImportError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SecurityHubClient, input: ListEnabledProductsForImportRequest| async move {
+                client.list_enabled_products_for_import(input).await
+            },
+            |input: &ListEnabledProductsForImportRequest,
+             output: &ListEnabledProductsForImportResponse| {
+                Some(ListEnabledProductsForImportRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_enabled_products_for_import`, streaming the `ProductSubscriptions` of every page of results.
+    pub fn list_enabled_products_for_import_items(
+        &self,
+        input: ListEnabledProductsForImportRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListEnabledProductsForImportError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_enabled_products_for_import_pages(input),
+            |output: ListEnabledProductsForImportResponse| {
+                output.product_subscriptions.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_invitations`, streaming every page of results.
+    pub fn list_invitations_pages(
+        &self,
+        input: ListInvitationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListInvitationsResponse, ListInvitationsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SecurityHubClient, input: ListInvitationsRequest| async move {
+                client.list_invitations(input).await
+            },
+            |input: &ListInvitationsRequest, output: &ListInvitationsResponse| {
+                Some(ListInvitationsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_invitations`, streaming the `Invitations` of every page of results.
+    pub fn list_invitations_items(
+        &self,
+        input: ListInvitationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Invitation, ListInvitationsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_invitations_pages(input),
+            |output: ListInvitationsResponse| output.invitations.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_members`, streaming every page of results.
+    pub fn list_members_pages(
+        &self,
+        input: ListMembersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListMembersResponse, ListMembersError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SecurityHubClient, input: ListMembersRequest| async move {
+                client.list_members(input).await
+            },
+            |input: &ListMembersRequest, output: &ListMembersResponse| {
+                Some(ListMembersRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_members`, streaming the `Members` of every page of results.
+    pub fn list_members_items(
+        &self,
+        input: ListMembersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Member, ListMembersError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_members_pages(input),
+            |output: ListMembersResponse| output.members.unwrap_or_default(),
+        )
+    }
+}
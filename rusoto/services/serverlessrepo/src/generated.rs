@@ -87,6 +87,110 @@ pub struct ApplicationSummary {
     pub spdx_license_id: Option<String>,
 }
 
+/// <p>Values that must be specified in order to deploy some applications.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Capability {
+    /// `CAPABILITY_IAM`
+    CapabilityIam,
+    /// `CAPABILITY_NAMED_IAM`
+    CapabilityNamedIam,
+    /// `CAPABILITY_AUTO_EXPAND`
+    CapabilityAutoExpand,
+    /// `CAPABILITY_RESOURCE_POLICY`
+    CapabilityResourcePolicy,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Capability {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Capability::CapabilityIam => "CAPABILITY_IAM",
+            Capability::CapabilityNamedIam => "CAPABILITY_NAMED_IAM",
+            Capability::CapabilityAutoExpand => "CAPABILITY_AUTO_EXPAND",
+            Capability::CapabilityResourcePolicy => "CAPABILITY_RESOURCE_POLICY",
+            Capability::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Capability {
+    fn default() -> Capability {
+        Capability::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Capability {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Capability {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Capability {
+    fn from(value: &'a str) -> Capability {
+        match value {
+            "CAPABILITY_IAM" => Capability::CapabilityIam,
+            "CAPABILITY_NAMED_IAM" => Capability::CapabilityNamedIam,
+            "CAPABILITY_AUTO_EXPAND" => Capability::CapabilityAutoExpand,
+            "CAPABILITY_RESOURCE_POLICY" => Capability::CapabilityResourcePolicy,
+            _ => Capability::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Capability {
+    fn from(value: String) -> Capability {
+        match value.as_str() {
+            "CAPABILITY_IAM" => Capability::CapabilityIam,
+            "CAPABILITY_NAMED_IAM" => Capability::CapabilityNamedIam,
+            "CAPABILITY_AUTO_EXPAND" => Capability::CapabilityAutoExpand,
+            "CAPABILITY_RESOURCE_POLICY" => Capability::CapabilityResourcePolicy,
+            _ => Capability::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Capability {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Capability, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Capability {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Capability {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Capability {
+    fn deserialize<D>(deserializer: D) -> Result<Capability, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateApplicationRequest {
@@ -278,7 +382,7 @@ pub struct CreateApplicationVersionResponse {
     /// this parameter for an application that requires capabilities, the call will fail.</p>
     #[serde(rename = "RequiredCapabilities")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub required_capabilities: Option<Vec<String>>,
+    pub required_capabilities: Option<Vec<Capability>>,
     /// <p>Whether all of the AWS resources contained in this application are supported in the region
     /// in which it is being retrieved.</p>
     #[serde(rename = "ResourcesSupported")]
@@ -454,7 +558,7 @@ pub struct CreateCloudFormationTemplateResponse {
     /// </p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<Status>,
     /// <p>The UUID returned by CreateCloudFormationTemplate.</p><p>Pattern: [0-9a-fA-F]{8}\-[0-9a-fA-F]{4}\-[0-9a-fA-F]{4}\-[0-9a-fA-F]{4}\-[0-9a-fA-F]{12}</p>
     #[serde(rename = "TemplateId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -597,7 +701,7 @@ pub struct GetCloudFormationTemplateResponse {
     /// </p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<Status>,
     /// <p>The UUID returned by CreateCloudFormationTemplate.</p><p>Pattern: [0-9a-fA-F]{8}\-[0-9a-fA-F]{4}\-[0-9a-fA-F]{4}\-[0-9a-fA-F]{4}\-[0-9a-fA-F]{12}</p>
     #[serde(rename = "TemplateId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -835,6 +939,104 @@ pub struct RollbackTrigger {
     pub type_: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Status {
+    /// `PREPARING`
+    Preparing,
+    /// `ACTIVE`
+    Active,
+    /// `EXPIRED`
+    Expired,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Status {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Status::Preparing => "PREPARING",
+            Status::Active => "ACTIVE",
+            Status::Expired => "EXPIRED",
+            Status::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Status {
+    fn default() -> Status {
+        Status::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Status {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Status {
+    fn from(value: &'a str) -> Status {
+        match value {
+            "PREPARING" => Status::Preparing,
+            "ACTIVE" => Status::Active,
+            "EXPIRED" => Status::Expired,
+            _ => Status::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Status {
+    fn from(value: String) -> Status {
+        match value.as_str() {
+            "PREPARING" => Status::Preparing,
+            "ACTIVE" => Status::Active,
+            "EXPIRED" => Status::Expired,
+            _ => Status::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Status {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Status, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Status {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Status {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Status {
+    fn deserialize<D>(deserializer: D) -> Result<Status, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>This property corresponds to the <i>AWS CloudFormation <a href="https://docs.aws.amazon.com/goto/WebAPI/cloudformation-2010-05-15/Tag">Tag</a>
 /// </i> Data Type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -978,7 +1180,7 @@ pub struct Version {
     /// all permissions associated with the application before deploying. If you don't specify
     /// this parameter for an application that requires capabilities, the call will fail.</p>
     #[serde(rename = "RequiredCapabilities")]
-    pub required_capabilities: Vec<String>,
+    pub required_capabilities: Vec<Capability>,
     /// <p>Whether all of the AWS resources contained in this application are supported in the region
     /// in which it is being retrieved.</p>
     #[serde(rename = "ResourcesSupported")]
@@ -1895,11 +2097,15 @@ pub struct ServerlessRepoClient {
 impl ServerlessRepoClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> ServerlessRepoClient {
         ServerlessRepoClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(
+                region,
+                "ServerlessApplicationRepository",
+            ),
         }
     }
 
@@ -1933,6 +2139,7 @@ impl ServerlessRepo for ServerlessRepoClient {
         let request_uri = "/applications";
 
         let mut request = SignedRequest::new("POST", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation("CreateApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1967,6 +2174,7 @@ impl ServerlessRepo for ServerlessRepoClient {
         );
 
         let mut request = SignedRequest::new("PUT", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation("CreateApplicationVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2003,6 +2211,7 @@ impl ServerlessRepo for ServerlessRepoClient {
         );
 
         let mut request = SignedRequest::new("POST", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation("CreateCloudFormationChangeSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2037,6 +2246,7 @@ impl ServerlessRepo for ServerlessRepoClient {
         );
 
         let mut request = SignedRequest::new("POST", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation("CreateCloudFormationTemplate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2071,6 +2281,7 @@ impl ServerlessRepo for ServerlessRepoClient {
 
         let mut request =
             SignedRequest::new("DELETE", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation("DeleteApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2100,6 +2311,7 @@ impl ServerlessRepo for ServerlessRepoClient {
         );
 
         let mut request = SignedRequest::new("GET", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation("GetApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2136,6 +2348,7 @@ impl ServerlessRepo for ServerlessRepoClient {
         );
 
         let mut request = SignedRequest::new("GET", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation("GetApplicationPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2168,6 +2381,7 @@ impl ServerlessRepo for ServerlessRepoClient {
         );
 
         let mut request = SignedRequest::new("GET", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation("GetCloudFormationTemplate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2199,6 +2413,7 @@ impl ServerlessRepo for ServerlessRepoClient {
         );
 
         let mut request = SignedRequest::new("GET", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation("ListApplicationDependencies");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2241,6 +2456,7 @@ impl ServerlessRepo for ServerlessRepoClient {
         );
 
         let mut request = SignedRequest::new("GET", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation("ListApplicationVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2277,6 +2493,7 @@ impl ServerlessRepo for ServerlessRepoClient {
         let request_uri = "/applications";
 
         let mut request = SignedRequest::new("GET", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation("ListApplications");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2319,6 +2536,7 @@ impl ServerlessRepo for ServerlessRepoClient {
         );
 
         let mut request = SignedRequest::new("PUT", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation("PutApplicationPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2352,6 +2570,7 @@ impl ServerlessRepo for ServerlessRepoClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation("UpdateApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2374,3 +2593,108 @@ impl ServerlessRepo for ServerlessRepoClient {
         }
     }
 }
+impl ServerlessRepoClient {
+    /// Auto-paginating version of `list_application_dependencies`, streaming every page of results.
+    pub fn list_application_dependencies_pages(
+        &self,
+        input: ListApplicationDependenciesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListApplicationDependenciesResponse,
+        ListApplicationDependenciesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ServerlessRepoClient, input: ListApplicationDependenciesRequest| async move {
+                client.list_application_dependencies(input).await
+            },
+            |input: &ListApplicationDependenciesRequest,
+             output: &ListApplicationDependenciesResponse| {
+                Some(ListApplicationDependenciesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_application_dependencies`, streaming the `Dependencies` of every page of results.
+    pub fn list_application_dependencies_items(
+        &self,
+        input: ListApplicationDependenciesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ApplicationDependencySummary,
+        ListApplicationDependenciesError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_application_dependencies_pages(input),
+            |output: ListApplicationDependenciesResponse| output.dependencies.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_application_versions`, streaming every page of results.
+    pub fn list_application_versions_pages(
+        &self,
+        input: ListApplicationVersionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListApplicationVersionsResponse,
+        ListApplicationVersionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ServerlessRepoClient, input: ListApplicationVersionsRequest| async move {
+                client.list_application_versions(input).await
+            },
+            |input: &ListApplicationVersionsRequest, output: &ListApplicationVersionsResponse| {
+                Some(ListApplicationVersionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_application_versions`, streaming the `Versions` of every page of results.
+    pub fn list_application_versions_items(
+        &self,
+        input: ListApplicationVersionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<VersionSummary, ListApplicationVersionsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_application_versions_pages(input),
+            |output: ListApplicationVersionsResponse| output.versions.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_applications`, streaming every page of results.
+    pub fn list_applications_pages(
+        &self,
+        input: ListApplicationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListApplicationsResponse, ListApplicationsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ServerlessRepoClient, input: ListApplicationsRequest| async move {
+                client.list_applications(input).await
+            },
+            |input: &ListApplicationsRequest, output: &ListApplicationsResponse| {
+                Some(ListApplicationsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_applications`, streaming the `Applications` of every page of results.
+    pub fn list_applications_items(
+        &self,
+        input: ListApplicationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ApplicationSummary, ListApplicationsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_applications_pages(input),
+            |output: ListApplicationsResponse| output.applications.unwrap_or_default(),
+        )
+    }
+}
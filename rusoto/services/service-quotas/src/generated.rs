@@ -58,6 +58,109 @@ pub struct DisassociateServiceQuotaTemplateRequest {}
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DisassociateServiceQuotaTemplateResponse {}
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorCode {
+    /// `DEPENDENCY_ACCESS_DENIED_ERROR`
+    DependencyAccessDeniedError,
+    /// `DEPENDENCY_THROTTLING_ERROR`
+    DependencyThrottlingError,
+    /// `DEPENDENCY_SERVICE_ERROR`
+    DependencyServiceError,
+    /// `SERVICE_QUOTA_NOT_AVAILABLE_ERROR`
+    ServiceQuotaNotAvailableError,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ErrorCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ErrorCode::DependencyAccessDeniedError => "DEPENDENCY_ACCESS_DENIED_ERROR",
+            ErrorCode::DependencyThrottlingError => "DEPENDENCY_THROTTLING_ERROR",
+            ErrorCode::DependencyServiceError => "DEPENDENCY_SERVICE_ERROR",
+            ErrorCode::ServiceQuotaNotAvailableError => "SERVICE_QUOTA_NOT_AVAILABLE_ERROR",
+            ErrorCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ErrorCode {
+    fn default() -> ErrorCode {
+        ErrorCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ErrorCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ErrorCode {
+    fn from(value: &'a str) -> ErrorCode {
+        match value {
+            "DEPENDENCY_ACCESS_DENIED_ERROR" => ErrorCode::DependencyAccessDeniedError,
+            "DEPENDENCY_THROTTLING_ERROR" => ErrorCode::DependencyThrottlingError,
+            "DEPENDENCY_SERVICE_ERROR" => ErrorCode::DependencyServiceError,
+            "SERVICE_QUOTA_NOT_AVAILABLE_ERROR" => ErrorCode::ServiceQuotaNotAvailableError,
+            _ => ErrorCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ErrorCode {
+    fn from(value: String) -> ErrorCode {
+        match value.as_str() {
+            "DEPENDENCY_ACCESS_DENIED_ERROR" => ErrorCode::DependencyAccessDeniedError,
+            "DEPENDENCY_THROTTLING_ERROR" => ErrorCode::DependencyThrottlingError,
+            "DEPENDENCY_SERVICE_ERROR" => ErrorCode::DependencyServiceError,
+            "SERVICE_QUOTA_NOT_AVAILABLE_ERROR" => ErrorCode::ServiceQuotaNotAvailableError,
+            _ => ErrorCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ErrorCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ErrorCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ErrorCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ErrorCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ErrorCode {
+    fn deserialize<D>(deserializer: D) -> Result<ErrorCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Returns an error that explains why the action did not succeed.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -65,7 +168,7 @@ pub struct ErrorReason {
     /// <p>Service Quotas returns the following error values. </p> <p> <code>DEPENDENCY_ACCESS_DENIED_ERROR</code> is returned when the caller does not have permission to call the service or service quota. To resolve the error, you need permission to access the service or service quota.</p> <p> <code>DEPENDENCY_THROTTLING_ERROR</code> is returned when the service being called is throttling Service Quotas.</p> <p> <code>DEPENDENCY_SERVICE_ERROR</code> is returned when the service being called has availability issues.</p> <p> <code>SERVICE_QUOTA_NOT_AVAILABLE_ERROR</code> is returned when there was an error in Service Quotas.</p>
     #[serde(rename = "ErrorCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub error_code: Option<String>,
+    pub error_code: Option<ErrorCode>,
     /// <p>The error message that provides more detail.</p>
     #[serde(rename = "ErrorMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -102,7 +205,7 @@ pub struct GetAssociationForServiceQuotaTemplateResponse {
     /// <p>Specifies whether the template is <code>ASSOCIATED</code> or <code>DISASSOCIATED</code>. If the template is <code>ASSOCIATED</code>, then it requests service quota increases for all new accounts created in your organization. </p>
     #[serde(rename = "ServiceQuotaTemplateAssociationStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub service_quota_template_association_status: Option<String>,
+    pub service_quota_template_association_status: Option<ServiceQuotaTemplateAssociationStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -214,7 +317,7 @@ pub struct ListRequestedServiceQuotaChangeHistoryByQuotaRequest {
     /// <p>Specifies the status value of the quota increase request.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<RequestStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -248,7 +351,7 @@ pub struct ListRequestedServiceQuotaChangeHistoryRequest {
     /// <p>Specifies the status value of the quota increase request.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<RequestStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -376,6 +479,124 @@ pub struct MetricInfo {
     pub metric_statistic_recommendation: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PeriodUnit {
+    /// `MICROSECOND`
+    Microsecond,
+    /// `MILLISECOND`
+    Millisecond,
+    /// `SECOND`
+    Second,
+    /// `MINUTE`
+    Minute,
+    /// `HOUR`
+    Hour,
+    /// `DAY`
+    Day,
+    /// `WEEK`
+    Week,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PeriodUnit {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PeriodUnit::Microsecond => "MICROSECOND",
+            PeriodUnit::Millisecond => "MILLISECOND",
+            PeriodUnit::Second => "SECOND",
+            PeriodUnit::Minute => "MINUTE",
+            PeriodUnit::Hour => "HOUR",
+            PeriodUnit::Day => "DAY",
+            PeriodUnit::Week => "WEEK",
+            PeriodUnit::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PeriodUnit {
+    fn default() -> PeriodUnit {
+        PeriodUnit::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PeriodUnit {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PeriodUnit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PeriodUnit {
+    fn from(value: &'a str) -> PeriodUnit {
+        match value {
+            "MICROSECOND" => PeriodUnit::Microsecond,
+            "MILLISECOND" => PeriodUnit::Millisecond,
+            "SECOND" => PeriodUnit::Second,
+            "MINUTE" => PeriodUnit::Minute,
+            "HOUR" => PeriodUnit::Hour,
+            "DAY" => PeriodUnit::Day,
+            "WEEK" => PeriodUnit::Week,
+            _ => PeriodUnit::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PeriodUnit {
+    fn from(value: String) -> PeriodUnit {
+        match value.as_str() {
+            "MICROSECOND" => PeriodUnit::Microsecond,
+            "MILLISECOND" => PeriodUnit::Millisecond,
+            "SECOND" => PeriodUnit::Second,
+            "MINUTE" => PeriodUnit::Minute,
+            "HOUR" => PeriodUnit::Hour,
+            "DAY" => PeriodUnit::Day,
+            "WEEK" => PeriodUnit::Week,
+            _ => PeriodUnit::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PeriodUnit {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PeriodUnit, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PeriodUnit {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PeriodUnit {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PeriodUnit {
+    fn deserialize<D>(deserializer: D) -> Result<PeriodUnit, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct PutServiceQuotaIncreaseRequestIntoTemplateRequest {
@@ -409,7 +630,7 @@ pub struct QuotaPeriod {
     /// <p>The time unit of a period.</p>
     #[serde(rename = "PeriodUnit")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub period_unit: Option<String>,
+    pub period_unit: Option<PeriodUnit>,
     /// <p>The value of a period.</p>
     #[serde(rename = "PeriodValue")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -439,6 +660,124 @@ pub struct RequestServiceQuotaIncreaseResponse {
     pub requested_quota: Option<RequestedServiceQuotaChange>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RequestStatus {
+    /// `PENDING`
+    Pending,
+    /// `CASE_OPENED`
+    CaseOpened,
+    /// `APPROVED`
+    Approved,
+    /// `DENIED`
+    Denied,
+    /// `CASE_CLOSED`
+    CaseClosed,
+    /// `NOT_APPROVED`
+    NotApproved,
+    /// `INVALID_REQUEST`
+    InvalidRequest,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RequestStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RequestStatus::Pending => "PENDING",
+            RequestStatus::CaseOpened => "CASE_OPENED",
+            RequestStatus::Approved => "APPROVED",
+            RequestStatus::Denied => "DENIED",
+            RequestStatus::CaseClosed => "CASE_CLOSED",
+            RequestStatus::NotApproved => "NOT_APPROVED",
+            RequestStatus::InvalidRequest => "INVALID_REQUEST",
+            RequestStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RequestStatus {
+    fn default() -> RequestStatus {
+        RequestStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RequestStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RequestStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RequestStatus {
+    fn from(value: &'a str) -> RequestStatus {
+        match value {
+            "PENDING" => RequestStatus::Pending,
+            "CASE_OPENED" => RequestStatus::CaseOpened,
+            "APPROVED" => RequestStatus::Approved,
+            "DENIED" => RequestStatus::Denied,
+            "CASE_CLOSED" => RequestStatus::CaseClosed,
+            "NOT_APPROVED" => RequestStatus::NotApproved,
+            "INVALID_REQUEST" => RequestStatus::InvalidRequest,
+            _ => RequestStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RequestStatus {
+    fn from(value: String) -> RequestStatus {
+        match value.as_str() {
+            "PENDING" => RequestStatus::Pending,
+            "CASE_OPENED" => RequestStatus::CaseOpened,
+            "APPROVED" => RequestStatus::Approved,
+            "DENIED" => RequestStatus::Denied,
+            "CASE_CLOSED" => RequestStatus::CaseClosed,
+            "NOT_APPROVED" => RequestStatus::NotApproved,
+            "INVALID_REQUEST" => RequestStatus::InvalidRequest,
+            _ => RequestStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RequestStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RequestStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RequestStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RequestStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RequestStatus {
+    fn deserialize<D>(deserializer: D) -> Result<RequestStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A structure that contains information about a requested change for a quota.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -449,8 +788,13 @@ pub struct RequestedServiceQuotaChange {
     pub case_id: Option<String>,
     /// <p>The date and time when the service quota increase request was received and the case Id was created. </p>
     #[serde(rename = "Created")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created: Option<f64>,
+    pub created: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>New increased value for the service quota.</p>
     #[serde(rename = "DesiredValue")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -465,8 +809,13 @@ pub struct RequestedServiceQuotaChange {
     pub id: Option<String>,
     /// <p>The date and time of the most recent change in the service quota increase request.</p>
     #[serde(rename = "LastUpdated")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated: Option<f64>,
+    pub last_updated: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The Amazon Resource Name (ARN) of the service quota.</p>
     #[serde(rename = "QuotaArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -494,7 +843,7 @@ pub struct RequestedServiceQuotaChange {
     /// <p>State of the service quota increase request.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<RequestStatus>,
     /// <p>Specifies the unit used for the quota.</p>
     #[serde(rename = "Unit")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -607,6 +956,99 @@ pub struct ServiceQuotaIncreaseRequestInTemplate {
     pub unit: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ServiceQuotaTemplateAssociationStatus {
+    /// `ASSOCIATED`
+    Associated,
+    /// `DISASSOCIATED`
+    Disassociated,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ServiceQuotaTemplateAssociationStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ServiceQuotaTemplateAssociationStatus::Associated => "ASSOCIATED",
+            ServiceQuotaTemplateAssociationStatus::Disassociated => "DISASSOCIATED",
+            ServiceQuotaTemplateAssociationStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ServiceQuotaTemplateAssociationStatus {
+    fn default() -> ServiceQuotaTemplateAssociationStatus {
+        ServiceQuotaTemplateAssociationStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ServiceQuotaTemplateAssociationStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ServiceQuotaTemplateAssociationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ServiceQuotaTemplateAssociationStatus {
+    fn from(value: &'a str) -> ServiceQuotaTemplateAssociationStatus {
+        match value {
+            "ASSOCIATED" => ServiceQuotaTemplateAssociationStatus::Associated,
+            "DISASSOCIATED" => ServiceQuotaTemplateAssociationStatus::Disassociated,
+            _ => ServiceQuotaTemplateAssociationStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ServiceQuotaTemplateAssociationStatus {
+    fn from(value: String) -> ServiceQuotaTemplateAssociationStatus {
+        match value.as_str() {
+            "ASSOCIATED" => ServiceQuotaTemplateAssociationStatus::Associated,
+            "DISASSOCIATED" => ServiceQuotaTemplateAssociationStatus::Disassociated,
+            _ => ServiceQuotaTemplateAssociationStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ServiceQuotaTemplateAssociationStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ServiceQuotaTemplateAssociationStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ServiceQuotaTemplateAssociationStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ServiceQuotaTemplateAssociationStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ServiceQuotaTemplateAssociationStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ServiceQuotaTemplateAssociationStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// Errors returned by AssociateServiceQuotaTemplate
 #[derive(Debug, PartialEq)]
 pub enum AssociateServiceQuotaTemplateError {
@@ -2175,11 +2617,12 @@ pub struct ServiceQuotasClient {
 impl ServiceQuotasClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> ServiceQuotasClient {
         ServiceQuotasClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Service Quotas"),
         }
     }
 
@@ -2213,6 +2656,7 @@ impl ServiceQuotas for ServiceQuotasClient {
         RusotoError<AssociateServiceQuotaTemplateError>,
     > {
         let mut request = SignedRequest::new("POST", "servicequotas", &self.region, "/");
+        request.set_operation("AssociateServiceQuotaTemplate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2246,6 +2690,7 @@ impl ServiceQuotas for ServiceQuotasClient {
         RusotoError<DeleteServiceQuotaIncreaseRequestFromTemplateError>,
     > {
         let mut request = SignedRequest::new("POST", "servicequotas", &self.region, "/");
+        request.set_operation("DeleteServiceQuotaIncreaseRequestFromTemplate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2279,6 +2724,7 @@ impl ServiceQuotas for ServiceQuotasClient {
         RusotoError<DisassociateServiceQuotaTemplateError>,
     > {
         let mut request = SignedRequest::new("POST", "servicequotas", &self.region, "/");
+        request.set_operation("DisassociateServiceQuotaTemplate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2312,6 +2758,7 @@ impl ServiceQuotas for ServiceQuotasClient {
     ) -> Result<GetAWSDefaultServiceQuotaResponse, RusotoError<GetAWSDefaultServiceQuotaError>>
     {
         let mut request = SignedRequest::new("POST", "servicequotas", &self.region, "/");
+        request.set_operation("GetAWSDefaultServiceQuota");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2345,6 +2792,7 @@ impl ServiceQuotas for ServiceQuotasClient {
         RusotoError<GetAssociationForServiceQuotaTemplateError>,
     > {
         let mut request = SignedRequest::new("POST", "servicequotas", &self.region, "/");
+        request.set_operation("GetAssociationForServiceQuotaTemplate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2380,6 +2828,7 @@ impl ServiceQuotas for ServiceQuotasClient {
         RusotoError<GetRequestedServiceQuotaChangeError>,
     > {
         let mut request = SignedRequest::new("POST", "servicequotas", &self.region, "/");
+        request.set_operation("GetRequestedServiceQuotaChange");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2411,6 +2860,7 @@ impl ServiceQuotas for ServiceQuotasClient {
         input: GetServiceQuotaRequest,
     ) -> Result<GetServiceQuotaResponse, RusotoError<GetServiceQuotaError>> {
         let mut request = SignedRequest::new("POST", "servicequotas", &self.region, "/");
+        request.set_operation("GetServiceQuota");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ServiceQuotasV20190624.GetServiceQuota");
@@ -2441,6 +2891,7 @@ impl ServiceQuotas for ServiceQuotasClient {
         RusotoError<GetServiceQuotaIncreaseRequestFromTemplateError>,
     > {
         let mut request = SignedRequest::new("POST", "servicequotas", &self.region, "/");
+        request.set_operation("GetServiceQuotaIncreaseRequestFromTemplate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2473,6 +2924,7 @@ impl ServiceQuotas for ServiceQuotasClient {
     ) -> Result<ListAWSDefaultServiceQuotasResponse, RusotoError<ListAWSDefaultServiceQuotasError>>
     {
         let mut request = SignedRequest::new("POST", "servicequotas", &self.region, "/");
+        request.set_operation("ListAWSDefaultServiceQuotas");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2507,6 +2959,7 @@ impl ServiceQuotas for ServiceQuotasClient {
         RusotoError<ListRequestedServiceQuotaChangeHistoryError>,
     > {
         let mut request = SignedRequest::new("POST", "servicequotas", &self.region, "/");
+        request.set_operation("ListRequestedServiceQuotaChangeHistory");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2543,6 +2996,7 @@ impl ServiceQuotas for ServiceQuotasClient {
         RusotoError<ListRequestedServiceQuotaChangeHistoryByQuotaError>,
     > {
         let mut request = SignedRequest::new("POST", "servicequotas", &self.region, "/");
+        request.set_operation("ListRequestedServiceQuotaChangeHistoryByQuota");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2577,6 +3031,7 @@ impl ServiceQuotas for ServiceQuotasClient {
         RusotoError<ListServiceQuotaIncreaseRequestsInTemplateError>,
     > {
         let mut request = SignedRequest::new("POST", "servicequotas", &self.region, "/");
+        request.set_operation("ListServiceQuotaIncreaseRequestsInTemplate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2608,6 +3063,7 @@ impl ServiceQuotas for ServiceQuotasClient {
         input: ListServiceQuotasRequest,
     ) -> Result<ListServiceQuotasResponse, RusotoError<ListServiceQuotasError>> {
         let mut request = SignedRequest::new("POST", "servicequotas", &self.region, "/");
+        request.set_operation("ListServiceQuotas");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ServiceQuotasV20190624.ListServiceQuotas");
@@ -2636,6 +3092,7 @@ impl ServiceQuotas for ServiceQuotasClient {
         input: ListServicesRequest,
     ) -> Result<ListServicesResponse, RusotoError<ListServicesError>> {
         let mut request = SignedRequest::new("POST", "servicequotas", &self.region, "/");
+        request.set_operation("ListServices");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ServiceQuotasV20190624.ListServices");
@@ -2666,6 +3123,7 @@ impl ServiceQuotas for ServiceQuotasClient {
         RusotoError<PutServiceQuotaIncreaseRequestIntoTemplateError>,
     > {
         let mut request = SignedRequest::new("POST", "servicequotas", &self.region, "/");
+        request.set_operation("PutServiceQuotaIncreaseRequestIntoTemplate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2698,6 +3156,7 @@ impl ServiceQuotas for ServiceQuotasClient {
     ) -> Result<RequestServiceQuotaIncreaseResponse, RusotoError<RequestServiceQuotaIncreaseError>>
     {
         let mut request = SignedRequest::new("POST", "servicequotas", &self.region, "/");
+        request.set_operation("RequestServiceQuotaIncrease");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2723,3 +3182,232 @@ impl ServiceQuotas for ServiceQuotasClient {
         }
     }
 }
+impl ServiceQuotasClient {
+    /// Auto-paginating version of `list_aws_default_service_quotas`, streaming every page of results.
+    pub fn list_aws_default_service_quotas_pages(
+        &self,
+        input: ListAWSDefaultServiceQuotasRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListAWSDefaultServiceQuotasResponse,
+        ListAWSDefaultServiceQuotasError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ServiceQuotasClient, input: ListAWSDefaultServiceQuotasRequest| async move {
+                client.list_aws_default_service_quotas(input).await
+            },
+            |input: &ListAWSDefaultServiceQuotasRequest,
+             output: &ListAWSDefaultServiceQuotasResponse| {
+                Some(ListAWSDefaultServiceQuotasRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_aws_default_service_quotas`, streaming the `Quotas` of every page of results.
+    pub fn list_aws_default_service_quotas_items(
+        &self,
+        input: ListAWSDefaultServiceQuotasRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ServiceQuota, ListAWSDefaultServiceQuotasError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_aws_default_service_quotas_pages(input),
+            |output: ListAWSDefaultServiceQuotasResponse| output.quotas.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_requested_service_quota_change_history`, streaming every page of results.
+    pub fn list_requested_service_quota_change_history_pages(
+        &self,
+        input: ListRequestedServiceQuotaChangeHistoryRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListRequestedServiceQuotaChangeHistoryResponse,
+        ListRequestedServiceQuotaChangeHistoryError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ServiceQuotasClient, input: ListRequestedServiceQuotaChangeHistoryRequest| async move {
+                client
+                    .list_requested_service_quota_change_history(input)
+                    .await
+            },
+            |input: &ListRequestedServiceQuotaChangeHistoryRequest,
+             output: &ListRequestedServiceQuotaChangeHistoryResponse| {
+                Some(ListRequestedServiceQuotaChangeHistoryRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_requested_service_quota_change_history`, streaming the `RequestedQuotas` of every page of results.
+    pub fn list_requested_service_quota_change_history_items(
+        &self,
+        input: ListRequestedServiceQuotaChangeHistoryRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        RequestedServiceQuotaChange,
+        ListRequestedServiceQuotaChangeHistoryError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_requested_service_quota_change_history_pages(input),
+            |output: ListRequestedServiceQuotaChangeHistoryResponse| {
+                output.requested_quotas.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_requested_service_quota_change_history_by_quota`, streaming every page of results.
+    pub fn list_requested_service_quota_change_history_by_quota_pages(
+        &self,
+        input: ListRequestedServiceQuotaChangeHistoryByQuotaRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListRequestedServiceQuotaChangeHistoryByQuotaResponse,
+        ListRequestedServiceQuotaChangeHistoryByQuotaError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ServiceQuotasClient,
+             input: ListRequestedServiceQuotaChangeHistoryByQuotaRequest| async move {
+                client
+                    .list_requested_service_quota_change_history_by_quota(input)
+                    .await
+            },
+            |input: &ListRequestedServiceQuotaChangeHistoryByQuotaRequest,
+             output: &ListRequestedServiceQuotaChangeHistoryByQuotaResponse| {
+                Some(ListRequestedServiceQuotaChangeHistoryByQuotaRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_requested_service_quota_change_history_by_quota`, streaming the `RequestedQuotas` of every page of results.
+    pub fn list_requested_service_quota_change_history_by_quota_items(
+        &self,
+        input: ListRequestedServiceQuotaChangeHistoryByQuotaRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        RequestedServiceQuotaChange,
+        ListRequestedServiceQuotaChangeHistoryByQuotaError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_requested_service_quota_change_history_by_quota_pages(input),
+            |output: ListRequestedServiceQuotaChangeHistoryByQuotaResponse| {
+                output.requested_quotas.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_service_quota_increase_requests_in_template`, streaming every page of results.
+    pub fn list_service_quota_increase_requests_in_template_pages(
+        &self,
+        input: ListServiceQuotaIncreaseRequestsInTemplateRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListServiceQuotaIncreaseRequestsInTemplateResponse,
+        ListServiceQuotaIncreaseRequestsInTemplateError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ServiceQuotasClient,
+             input: ListServiceQuotaIncreaseRequestsInTemplateRequest| async move {
+                client
+                    .list_service_quota_increase_requests_in_template(input)
+                    .await
+            },
+            |input: &ListServiceQuotaIncreaseRequestsInTemplateRequest,
+             output: &ListServiceQuotaIncreaseRequestsInTemplateResponse| {
+                Some(ListServiceQuotaIncreaseRequestsInTemplateRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_service_quota_increase_requests_in_template`, streaming the `ServiceQuotaIncreaseRequestInTemplateList` of every page of results.
+    pub fn list_service_quota_increase_requests_in_template_items(
+        &self,
+        input: ListServiceQuotaIncreaseRequestsInTemplateRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ServiceQuotaIncreaseRequestInTemplate,
+        ListServiceQuotaIncreaseRequestsInTemplateError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_service_quota_increase_requests_in_template_pages(input),
+            |output: ListServiceQuotaIncreaseRequestsInTemplateResponse| {
+                output
+                    .service_quota_increase_request_in_template_list
+                    .unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_service_quotas`, streaming every page of results.
+    pub fn list_service_quotas_pages(
+        &self,
+        input: ListServiceQuotasRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListServiceQuotasResponse, ListServiceQuotasError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ServiceQuotasClient, input: ListServiceQuotasRequest| async move {
+                client.list_service_quotas(input).await
+            },
+            |input: &ListServiceQuotasRequest, output: &ListServiceQuotasResponse| {
+                Some(ListServiceQuotasRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_service_quotas`, streaming the `Quotas` of every page of results.
+    pub fn list_service_quotas_items(
+        &self,
+        input: ListServiceQuotasRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ServiceQuota, ListServiceQuotasError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_service_quotas_pages(input),
+            |output: ListServiceQuotasResponse| output.quotas.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_services`, streaming every page of results.
+    pub fn list_services_pages(
+        &self,
+        input: ListServicesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListServicesResponse, ListServicesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ServiceQuotasClient, input: ListServicesRequest| async move {
+                client.list_services(input).await
+            },
+            |input: &ListServicesRequest, output: &ListServicesResponse| {
+                Some(ListServicesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_services`, streaming the `Services` of every page of results.
+    pub fn list_services_items(
+        &self,
+        input: ListServicesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ServiceInfo, ListServicesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_services_pages(input),
+            |output: ListServicesResponse| output.services.unwrap_or_default(),
+        )
+    }
+}
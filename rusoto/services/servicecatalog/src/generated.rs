@@ -37,7 +37,7 @@ pub struct AcceptPortfolioShareInput {
     /// <p>The type of shared portfolios to accept. The default is to accept imported portfolios.</p> <ul> <li> <p> <code>AWS_ORGANIZATIONS</code> - Accept portfolios shared by the master account of your organization.</p> </li> <li> <p> <code>IMPORTED</code> - Accept imported portfolios.</p> </li> <li> <p> <code>AWS_SERVICECATALOG</code> - Not supported. (Throws ResourceNotFoundException.)</p> </li> </ul> <p>For example, <code>aws servicecatalog accept-portfolio-share --portfolio-id "port-2qwzkwxt3y5fk" --portfolio-share-type AWS_ORGANIZATIONS</code> </p>
     #[serde(rename = "PortfolioShareType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub portfolio_share_type: Option<String>,
+    pub portfolio_share_type: Option<PortfolioShareType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -51,13 +51,209 @@ pub struct AccessLevelFilter {
     /// <p><p>The access level.</p> <ul> <li> <p> <code>Account</code> - Filter results based on the account.</p> </li> <li> <p> <code>Role</code> - Filter results based on the federated role of the specified user.</p> </li> <li> <p> <code>User</code> - Filter results based on the specified user.</p> </li> </ul></p>
     #[serde(rename = "Key")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub key: Option<String>,
+    pub key: Option<AccessLevelFilterKey>,
     /// <p>The user to which the access level applies. The only supported value is <code>Self</code>.</p>
     #[serde(rename = "Value")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AccessLevelFilterKey {
+    /// `Account`
+    Account,
+    /// `Role`
+    Role,
+    /// `User`
+    User,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AccessLevelFilterKey {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AccessLevelFilterKey::Account => "Account",
+            AccessLevelFilterKey::Role => "Role",
+            AccessLevelFilterKey::User => "User",
+            AccessLevelFilterKey::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AccessLevelFilterKey {
+    fn default() -> AccessLevelFilterKey {
+        AccessLevelFilterKey::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AccessLevelFilterKey {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AccessLevelFilterKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AccessLevelFilterKey {
+    fn from(value: &'a str) -> AccessLevelFilterKey {
+        match value {
+            "Account" => AccessLevelFilterKey::Account,
+            "Role" => AccessLevelFilterKey::Role,
+            "User" => AccessLevelFilterKey::User,
+            _ => AccessLevelFilterKey::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AccessLevelFilterKey {
+    fn from(value: String) -> AccessLevelFilterKey {
+        match value.as_str() {
+            "Account" => AccessLevelFilterKey::Account,
+            "Role" => AccessLevelFilterKey::Role,
+            "User" => AccessLevelFilterKey::User,
+            _ => AccessLevelFilterKey::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AccessLevelFilterKey {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AccessLevelFilterKey, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AccessLevelFilterKey {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AccessLevelFilterKey {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AccessLevelFilterKey {
+    fn deserialize<D>(deserializer: D) -> Result<AccessLevelFilterKey, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AccessStatus {
+    /// `ENABLED`
+    Enabled,
+    /// `UNDER_CHANGE`
+    UnderChange,
+    /// `DISABLED`
+    Disabled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AccessStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AccessStatus::Enabled => "ENABLED",
+            AccessStatus::UnderChange => "UNDER_CHANGE",
+            AccessStatus::Disabled => "DISABLED",
+            AccessStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AccessStatus {
+    fn default() -> AccessStatus {
+        AccessStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AccessStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AccessStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AccessStatus {
+    fn from(value: &'a str) -> AccessStatus {
+        match value {
+            "ENABLED" => AccessStatus::Enabled,
+            "UNDER_CHANGE" => AccessStatus::UnderChange,
+            "DISABLED" => AccessStatus::Disabled,
+            _ => AccessStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AccessStatus {
+    fn from(value: String) -> AccessStatus {
+        match value.as_str() {
+            "ENABLED" => AccessStatus::Enabled,
+            "UNDER_CHANGE" => AccessStatus::UnderChange,
+            "DISABLED" => AccessStatus::Disabled,
+            _ => AccessStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AccessStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AccessStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AccessStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AccessStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AccessStatus {
+    fn deserialize<D>(deserializer: D) -> Result<AccessStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct AssociateBudgetWithResourceInput {
@@ -88,7 +284,7 @@ pub struct AssociatePrincipalWithPortfolioInput {
     pub principal_arn: String,
     /// <p>The principal type. The supported value is <code>IAM</code>.</p>
     #[serde(rename = "PrincipalType")]
-    pub principal_type: String,
+    pub principal_type: PrincipalType,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -207,6 +403,104 @@ pub struct BudgetDetail {
     pub budget_name: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ChangeAction {
+    /// `ADD`
+    Add,
+    /// `MODIFY`
+    Modify,
+    /// `REMOVE`
+    Remove,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ChangeAction {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ChangeAction::Add => "ADD",
+            ChangeAction::Modify => "MODIFY",
+            ChangeAction::Remove => "REMOVE",
+            ChangeAction::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ChangeAction {
+    fn default() -> ChangeAction {
+        ChangeAction::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ChangeAction {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ChangeAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ChangeAction {
+    fn from(value: &'a str) -> ChangeAction {
+        match value {
+            "ADD" => ChangeAction::Add,
+            "MODIFY" => ChangeAction::Modify,
+            "REMOVE" => ChangeAction::Remove,
+            _ => ChangeAction::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ChangeAction {
+    fn from(value: String) -> ChangeAction {
+        match value.as_str() {
+            "ADD" => ChangeAction::Add,
+            "MODIFY" => ChangeAction::Modify,
+            "REMOVE" => ChangeAction::Remove,
+            _ => ChangeAction::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ChangeAction {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ChangeAction, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ChangeAction {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ChangeAction {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ChangeAction {
+    fn deserialize<D>(deserializer: D) -> Result<ChangeAction, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about a CloudWatch dashboard.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -253,6 +547,94 @@ pub struct ConstraintSummary {
     pub type_: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CopyOption {
+    /// `CopyTags`
+    CopyTags,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CopyOption {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CopyOption::CopyTags => "CopyTags",
+            CopyOption::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CopyOption {
+    fn default() -> CopyOption {
+        CopyOption::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CopyOption {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CopyOption {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CopyOption {
+    fn from(value: &'a str) -> CopyOption {
+        match value {
+            "CopyTags" => CopyOption::CopyTags,
+            _ => CopyOption::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CopyOption {
+    fn from(value: String) -> CopyOption {
+        match value.as_str() {
+            "CopyTags" => CopyOption::CopyTags,
+            _ => CopyOption::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CopyOption {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CopyOption, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CopyOption {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CopyOption {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CopyOption {
+    fn deserialize<D>(deserializer: D) -> Result<CopyOption, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CopyProductInput {
@@ -263,7 +645,7 @@ pub struct CopyProductInput {
     /// <p>The copy options. If the value is <code>CopyTags</code>, the tags from the source product are copied to the target product.</p>
     #[serde(rename = "CopyOptions")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub copy_options: Option<Vec<String>>,
+    pub copy_options: Option<Vec<CopyOption>>,
     /// <p> A unique identifier that you provide to ensure idempotency. If multiple requests differ only by the idempotency token, the same response is returned for each repeated request. </p>
     #[serde(rename = "IdempotencyToken")]
     pub idempotency_token: String,
@@ -274,7 +656,7 @@ pub struct CopyProductInput {
     #[serde(rename = "SourceProvisioningArtifactIdentifiers")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub source_provisioning_artifact_identifiers:
-        Option<Vec<::std::collections::HashMap<String, String>>>,
+        Option<Vec<::std::collections::HashMap<ProvisioningArtifactPropertyName, String>>>,
     /// <p>The identifier of the target product. By default, a new product is created.</p>
     #[serde(rename = "TargetProductId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -294,6 +676,104 @@ pub struct CopyProductOutput {
     pub copy_product_token: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CopyProductStatus {
+    /// `SUCCEEDED`
+    Succeeded,
+    /// `IN_PROGRESS`
+    InProgress,
+    /// `FAILED`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CopyProductStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CopyProductStatus::Succeeded => "SUCCEEDED",
+            CopyProductStatus::InProgress => "IN_PROGRESS",
+            CopyProductStatus::Failed => "FAILED",
+            CopyProductStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CopyProductStatus {
+    fn default() -> CopyProductStatus {
+        CopyProductStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CopyProductStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CopyProductStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CopyProductStatus {
+    fn from(value: &'a str) -> CopyProductStatus {
+        match value {
+            "SUCCEEDED" => CopyProductStatus::Succeeded,
+            "IN_PROGRESS" => CopyProductStatus::InProgress,
+            "FAILED" => CopyProductStatus::Failed,
+            _ => CopyProductStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CopyProductStatus {
+    fn from(value: String) -> CopyProductStatus {
+        match value.as_str() {
+            "SUCCEEDED" => CopyProductStatus::Succeeded,
+            "IN_PROGRESS" => CopyProductStatus::InProgress,
+            "FAILED" => CopyProductStatus::Failed,
+            _ => CopyProductStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CopyProductStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CopyProductStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CopyProductStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CopyProductStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CopyProductStatus {
+    fn deserialize<D>(deserializer: D) -> Result<CopyProductStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateConstraintInput {
@@ -336,7 +816,7 @@ pub struct CreateConstraintOutput {
     /// <p>The status of the current request.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<Status>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -433,7 +913,7 @@ pub struct CreateProductInput {
     pub owner: String,
     /// <p>The type of product.</p>
     #[serde(rename = "ProductType")]
-    pub product_type: String,
+    pub product_type: ProductType,
     /// <p>The configuration of the provisioning artifact.</p>
     #[serde(rename = "ProvisioningArtifactParameters")]
     pub provisioning_artifact_parameters: ProvisioningArtifactProperties,
@@ -495,7 +975,7 @@ pub struct CreateProvisionedProductPlanInput {
     pub plan_name: String,
     /// <p>The plan type.</p>
     #[serde(rename = "PlanType")]
-    pub plan_type: String,
+    pub plan_type: ProvisionedProductPlanType,
     /// <p>The product identifier.</p>
     #[serde(rename = "ProductId")]
     pub product_id: String,
@@ -572,7 +1052,7 @@ pub struct CreateProvisioningArtifactOutput {
     /// <p>The status of the current request.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<Status>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -584,10 +1064,10 @@ pub struct CreateServiceActionInput {
     pub accept_language: Option<String>,
     /// <p><p>The self-service action definition. Can be one of the following:</p> <dl> <dt>Name</dt> <dd> <p>The name of the AWS Systems Manager Document. For example, <code>AWS-RestartEC2Instance</code>.</p> </dd> <dt>Version</dt> <dd> <p>The AWS Systems Manager automation document version. For example, <code>&quot;Version&quot;: &quot;1&quot;</code> </p> </dd> <dt>AssumeRole</dt> <dd> <p>The Amazon Resource Name (ARN) of the role that performs the self-service actions on your behalf. For example, <code>&quot;AssumeRole&quot;: &quot;arn:aws:iam::12345678910:role/ActionRole&quot;</code>.</p> <p>To reuse the provisioned product launch role, set to <code>&quot;AssumeRole&quot;: &quot;LAUNCH_ROLE&quot;</code>.</p> </dd> <dt>Parameters</dt> <dd> <p>The list of parameters in JSON format.</p> <p>For example: <code>[{&quot;Name&quot;:&quot;InstanceId&quot;,&quot;Type&quot;:&quot;TARGET&quot;}]</code>.</p> </dd> </dl></p>
     #[serde(rename = "Definition")]
-    pub definition: ::std::collections::HashMap<String, String>,
+    pub definition: ::std::collections::HashMap<ServiceActionDefinitionKey, String>,
     /// <p>The service action definition type. For example, <code>SSM_AUTOMATION</code>.</p>
     #[serde(rename = "DefinitionType")]
-    pub definition_type: String,
+    pub definition_type: ServiceActionDefinitionType,
     /// <p>The self-service action description.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -799,7 +1279,7 @@ pub struct DescribeConstraintOutput {
     /// <p>The status of the current request.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<Status>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -820,7 +1300,7 @@ pub struct DescribeCopyProductStatusOutput {
     /// <p>The status of the copy product operation.</p>
     #[serde(rename = "CopyProductStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub copy_product_status: Option<String>,
+    pub copy_product_status: Option<CopyProductStatus>,
     /// <p>The status message.</p>
     #[serde(rename = "StatusDetail")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -894,7 +1374,7 @@ pub struct DescribePortfolioShareStatusOutput {
     /// <p>Status of the portfolio share operation.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ShareStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -1083,7 +1563,7 @@ pub struct DescribeProvisioningArtifactOutput {
     /// <p>The status of the current request.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<Status>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -1331,6 +1811,99 @@ pub struct EnableAWSOrganizationsAccessInput {}
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EnableAWSOrganizationsAccessOutput {}
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EvaluationType {
+    /// `STATIC`
+    Static,
+    /// `DYNAMIC`
+    Dynamic,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EvaluationType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EvaluationType::Static => "STATIC",
+            EvaluationType::Dynamic => "DYNAMIC",
+            EvaluationType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EvaluationType {
+    fn default() -> EvaluationType {
+        EvaluationType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EvaluationType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EvaluationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EvaluationType {
+    fn from(value: &'a str) -> EvaluationType {
+        match value {
+            "STATIC" => EvaluationType::Static,
+            "DYNAMIC" => EvaluationType::Dynamic,
+            _ => EvaluationType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EvaluationType {
+    fn from(value: String) -> EvaluationType {
+        match value.as_str() {
+            "STATIC" => EvaluationType::Static,
+            "DYNAMIC" => EvaluationType::Dynamic,
+            _ => EvaluationType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EvaluationType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EvaluationType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EvaluationType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EvaluationType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EvaluationType {
+    fn deserialize<D>(deserializer: D) -> Result<EvaluationType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ExecuteProvisionedProductPlanInput {
@@ -1406,7 +1979,7 @@ pub struct FailedServiceActionAssociation {
     /// <p>The error code. Valid values are listed below.</p>
     #[serde(rename = "ErrorCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub error_code: Option<String>,
+    pub error_code: Option<ServiceActionAssociationErrorCode>,
     /// <p>A text description of the error.</p>
     #[serde(rename = "ErrorMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1435,7 +2008,7 @@ pub struct GetAWSOrganizationsAccessStatusOutput {
     /// <p>The status of the portfolio share feature.</p>
     #[serde(rename = "AccessStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub access_status: Option<String>,
+    pub access_status: Option<AccessStatus>,
 }
 
 /// <p>Summary information about a product path for a user.</p>
@@ -1478,7 +2051,7 @@ pub struct ListAcceptedPortfolioSharesInput {
     /// <p><p>The type of shared portfolios to list. The default is to list imported portfolios.</p> <ul> <li> <p> <code>AWS<em>ORGANIZATIONS</code> - List portfolios shared by the master account of your organization</p> </li> <li> <p> <code>AWS</em>SERVICECATALOG</code> - List default portfolios</p> </li> <li> <p> <code>IMPORTED</code> - List imported portfolios</p> </li> </ul></p>
     #[serde(rename = "PortfolioShareType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub portfolio_share_type: Option<String>,
+    pub portfolio_share_type: Option<PortfolioShareType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1606,7 +2179,7 @@ pub struct ListOrganizationPortfolioAccessInput {
     pub accept_language: Option<String>,
     /// <p><p>The organization node type that will be returned in the output.</p> <ul> <li> <p> <code>ORGANIZATION</code> - Organization that has access to the portfolio. </p> </li> <li> <p> <code>ORGANIZATIONAL_UNIT</code> - Organizational unit that has access to the portfolio within your organization.</p> </li> <li> <p> <code>ACCOUNT</code> - Account that has access to the portfolio within your organization.</p> </li> </ul></p>
     #[serde(rename = "OrganizationNodeType")]
-    pub organization_node_type: String,
+    pub organization_node_type: OrganizationNodeType,
     /// <p>The maximum number of items to return with this call.</p>
     #[serde(rename = "PageSize")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2088,35 +2661,138 @@ pub struct OrganizationNode {
     /// <p>The organization node type.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<OrganizationNodeType>,
     /// <p>The identifier of the organization node.</p>
     #[serde(rename = "Value")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<String>,
 }
 
-/// <p>The constraints that the administrator has put on the parameter.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct ParameterConstraints {
-    /// <p>The values that the administrator has allowed for the parameter.</p>
-    #[serde(rename = "AllowedValues")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub allowed_values: Option<Vec<String>>,
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OrganizationNodeType {
+    /// `ORGANIZATION`
+    Organization,
+    /// `ORGANIZATIONAL_UNIT`
+    OrganizationalUnit,
+    /// `ACCOUNT`
+    Account,
+    /// A value not known to this version of the crate
+    Unknown(String),
 }
 
-/// <p>Information about a portfolio.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct PortfolioDetail {
+impl OrganizationNodeType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OrganizationNodeType::Organization => "ORGANIZATION",
+            OrganizationNodeType::OrganizationalUnit => "ORGANIZATIONAL_UNIT",
+            OrganizationNodeType::Account => "ACCOUNT",
+            OrganizationNodeType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for OrganizationNodeType {
+    fn default() -> OrganizationNodeType {
+        OrganizationNodeType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for OrganizationNodeType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for OrganizationNodeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for OrganizationNodeType {
+    fn from(value: &'a str) -> OrganizationNodeType {
+        match value {
+            "ORGANIZATION" => OrganizationNodeType::Organization,
+            "ORGANIZATIONAL_UNIT" => OrganizationNodeType::OrganizationalUnit,
+            "ACCOUNT" => OrganizationNodeType::Account,
+            _ => OrganizationNodeType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OrganizationNodeType {
+    fn from(value: String) -> OrganizationNodeType {
+        match value.as_str() {
+            "ORGANIZATION" => OrganizationNodeType::Organization,
+            "ORGANIZATIONAL_UNIT" => OrganizationNodeType::OrganizationalUnit,
+            "ACCOUNT" => OrganizationNodeType::Account,
+            _ => OrganizationNodeType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for OrganizationNodeType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<OrganizationNodeType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OrganizationNodeType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for OrganizationNodeType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OrganizationNodeType {
+    fn deserialize<D>(deserializer: D) -> Result<OrganizationNodeType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>The constraints that the administrator has put on the parameter.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct ParameterConstraints {
+    /// <p>The values that the administrator has allowed for the parameter.</p>
+    #[serde(rename = "AllowedValues")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_values: Option<Vec<String>>,
+}
+
+/// <p>Information about a portfolio.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct PortfolioDetail {
     /// <p>The ARN assigned to the portfolio.</p>
     #[serde(rename = "ARN")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub arn: Option<String>,
     /// <p>The UTC time stamp of the creation time.</p>
     #[serde(rename = "CreatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time: Option<f64>,
+    pub created_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The description of the portfolio.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2135,6 +2811,104 @@ pub struct PortfolioDetail {
     pub provider_name: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PortfolioShareType {
+    /// `IMPORTED`
+    Imported,
+    /// `AWS_SERVICECATALOG`
+    AwsServicecatalog,
+    /// `AWS_ORGANIZATIONS`
+    AwsOrganizations,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PortfolioShareType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PortfolioShareType::Imported => "IMPORTED",
+            PortfolioShareType::AwsServicecatalog => "AWS_SERVICECATALOG",
+            PortfolioShareType::AwsOrganizations => "AWS_ORGANIZATIONS",
+            PortfolioShareType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PortfolioShareType {
+    fn default() -> PortfolioShareType {
+        PortfolioShareType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PortfolioShareType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PortfolioShareType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PortfolioShareType {
+    fn from(value: &'a str) -> PortfolioShareType {
+        match value {
+            "IMPORTED" => PortfolioShareType::Imported,
+            "AWS_SERVICECATALOG" => PortfolioShareType::AwsServicecatalog,
+            "AWS_ORGANIZATIONS" => PortfolioShareType::AwsOrganizations,
+            _ => PortfolioShareType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PortfolioShareType {
+    fn from(value: String) -> PortfolioShareType {
+        match value.as_str() {
+            "IMPORTED" => PortfolioShareType::Imported,
+            "AWS_SERVICECATALOG" => PortfolioShareType::AwsServicecatalog,
+            "AWS_ORGANIZATIONS" => PortfolioShareType::AwsOrganizations,
+            _ => PortfolioShareType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PortfolioShareType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PortfolioShareType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PortfolioShareType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PortfolioShareType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PortfolioShareType {
+    fn deserialize<D>(deserializer: D) -> Result<PortfolioShareType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about a principal.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -2146,9 +2920,298 @@ pub struct Principal {
     /// <p>The principal type. The supported value is <code>IAM</code>.</p>
     #[serde(rename = "PrincipalType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub principal_type: Option<String>,
+    pub principal_type: Option<PrincipalType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PrincipalType {
+    /// `IAM`
+    Iam,
+    /// `IAM_PATTERN`
+    IamPattern,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PrincipalType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PrincipalType::Iam => "IAM",
+            PrincipalType::IamPattern => "IAM_PATTERN",
+            PrincipalType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PrincipalType {
+    fn default() -> PrincipalType {
+        PrincipalType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PrincipalType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PrincipalType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PrincipalType {
+    fn from(value: &'a str) -> PrincipalType {
+        match value {
+            "IAM" => PrincipalType::Iam,
+            "IAM_PATTERN" => PrincipalType::IamPattern,
+            _ => PrincipalType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PrincipalType {
+    fn from(value: String) -> PrincipalType {
+        match value.as_str() {
+            "IAM" => PrincipalType::Iam,
+            "IAM_PATTERN" => PrincipalType::IamPattern,
+            _ => PrincipalType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PrincipalType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PrincipalType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PrincipalType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PrincipalType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PrincipalType {
+    fn deserialize<D>(deserializer: D) -> Result<PrincipalType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ProductSource {
+    /// `ACCOUNT`
+    Account,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ProductSource {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ProductSource::Account => "ACCOUNT",
+            ProductSource::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ProductSource {
+    fn default() -> ProductSource {
+        ProductSource::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ProductSource {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ProductSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ProductSource {
+    fn from(value: &'a str) -> ProductSource {
+        match value {
+            "ACCOUNT" => ProductSource::Account,
+            _ => ProductSource::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ProductSource {
+    fn from(value: String) -> ProductSource {
+        match value.as_str() {
+            "ACCOUNT" => ProductSource::Account,
+            _ => ProductSource::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ProductSource {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ProductSource, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ProductSource {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ProductSource {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ProductSource {
+    fn deserialize<D>(deserializer: D) -> Result<ProductSource, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ProductType {
+    /// `CLOUD_FORMATION_TEMPLATE`
+    CloudFormationTemplate,
+    /// `MARKETPLACE`
+    Marketplace,
+    /// `TERRAFORM_OPEN_SOURCE`
+    TerraformOpenSource,
+    /// `TERRAFORM_CLOUD`
+    TerraformCloud,
+    /// `EXTERNAL`
+    External,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ProductType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ProductType::CloudFormationTemplate => "CLOUD_FORMATION_TEMPLATE",
+            ProductType::Marketplace => "MARKETPLACE",
+            ProductType::TerraformOpenSource => "TERRAFORM_OPEN_SOURCE",
+            ProductType::TerraformCloud => "TERRAFORM_CLOUD",
+            ProductType::External => "EXTERNAL",
+            ProductType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ProductType {
+    fn default() -> ProductType {
+        ProductType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ProductType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ProductType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
+impl<'a> From<&'a str> for ProductType {
+    fn from(value: &'a str) -> ProductType {
+        match value {
+            "CLOUD_FORMATION_TEMPLATE" => ProductType::CloudFormationTemplate,
+            "MARKETPLACE" => ProductType::Marketplace,
+            "TERRAFORM_OPEN_SOURCE" => ProductType::TerraformOpenSource,
+            "TERRAFORM_CLOUD" => ProductType::TerraformCloud,
+            "EXTERNAL" => ProductType::External,
+            _ => ProductType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ProductType {
+    fn from(value: String) -> ProductType {
+        match value.as_str() {
+            "CLOUD_FORMATION_TEMPLATE" => ProductType::CloudFormationTemplate,
+            "MARKETPLACE" => ProductType::Marketplace,
+            "TERRAFORM_OPEN_SOURCE" => ProductType::TerraformOpenSource,
+            "TERRAFORM_CLOUD" => ProductType::TerraformCloud,
+            "EXTERNAL" => ProductType::External,
+            _ => ProductType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ProductType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ProductType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ProductType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ProductType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ProductType {
+    fn deserialize<D>(deserializer: D) -> Result<ProductType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A single product view aggregation value/count pair, containing metadata about each product to which the calling user has access.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -2163,28 +3226,234 @@ pub struct ProductViewAggregationValue {
     pub value: Option<String>,
 }
 
-/// <p>Information about a product view.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct ProductViewDetail {
-    /// <p>The UTC time stamp of the creation time.</p>
-    #[serde(rename = "CreatedTime")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time: Option<f64>,
-    /// <p>The ARN of the product.</p>
-    #[serde(rename = "ProductARN")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub product_arn: Option<String>,
-    /// <p>Summary information about the product view.</p>
-    #[serde(rename = "ProductViewSummary")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub product_view_summary: Option<ProductViewSummary>,
-    /// <p><p>The status of the product.</p> <ul> <li> <p> <code>AVAILABLE</code> - The product is ready for use.</p> </li> <li> <p> <code>CREATING</code> - Product creation has started; the product is not ready for use.</p> </li> <li> <p> <code>FAILED</code> - An action failed.</p> </li> </ul></p>
-    #[serde(rename = "Status")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+/// <p>Information about a product view.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct ProductViewDetail {
+    /// <p>The UTC time stamp of the creation time.</p>
+    #[serde(rename = "CreatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_time: Option<::chrono::DateTime<::chrono::Utc>>,
+    /// <p>The ARN of the product.</p>
+    #[serde(rename = "ProductARN")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product_arn: Option<String>,
+    /// <p>Summary information about the product view.</p>
+    #[serde(rename = "ProductViewSummary")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product_view_summary: Option<ProductViewSummary>,
+    /// <p><p>The status of the product.</p> <ul> <li> <p> <code>AVAILABLE</code> - The product is ready for use.</p> </li> <li> <p> <code>CREATING</code> - Product creation has started; the product is not ready for use.</p> </li> <li> <p> <code>FAILED</code> - An action failed.</p> </li> </ul></p>
+    #[serde(rename = "Status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<Status>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ProductViewFilterBy {
+    /// `FullTextSearch`
+    FullTextSearch,
+    /// `Owner`
+    Owner,
+    /// `ProductType`
+    ProductType,
+    /// `SourceProductId`
+    SourceProductId,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ProductViewFilterBy {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ProductViewFilterBy::FullTextSearch => "FullTextSearch",
+            ProductViewFilterBy::Owner => "Owner",
+            ProductViewFilterBy::ProductType => "ProductType",
+            ProductViewFilterBy::SourceProductId => "SourceProductId",
+            ProductViewFilterBy::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ProductViewFilterBy {
+    fn default() -> ProductViewFilterBy {
+        ProductViewFilterBy::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ProductViewFilterBy {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ProductViewFilterBy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ProductViewFilterBy {
+    fn from(value: &'a str) -> ProductViewFilterBy {
+        match value {
+            "FullTextSearch" => ProductViewFilterBy::FullTextSearch,
+            "Owner" => ProductViewFilterBy::Owner,
+            "ProductType" => ProductViewFilterBy::ProductType,
+            "SourceProductId" => ProductViewFilterBy::SourceProductId,
+            _ => ProductViewFilterBy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ProductViewFilterBy {
+    fn from(value: String) -> ProductViewFilterBy {
+        match value.as_str() {
+            "FullTextSearch" => ProductViewFilterBy::FullTextSearch,
+            "Owner" => ProductViewFilterBy::Owner,
+            "ProductType" => ProductViewFilterBy::ProductType,
+            "SourceProductId" => ProductViewFilterBy::SourceProductId,
+            _ => ProductViewFilterBy::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ProductViewFilterBy {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ProductViewFilterBy, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ProductViewFilterBy {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ProductViewFilterBy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ProductViewFilterBy {
+    fn deserialize<D>(deserializer: D) -> Result<ProductViewFilterBy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ProductViewSortBy {
+    /// `Title`
+    Title,
+    /// `VersionCount`
+    VersionCount,
+    /// `CreationDate`
+    CreationDate,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ProductViewSortBy {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ProductViewSortBy::Title => "Title",
+            ProductViewSortBy::VersionCount => "VersionCount",
+            ProductViewSortBy::CreationDate => "CreationDate",
+            ProductViewSortBy::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ProductViewSortBy {
+    fn default() -> ProductViewSortBy {
+        ProductViewSortBy::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ProductViewSortBy {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ProductViewSortBy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ProductViewSortBy {
+    fn from(value: &'a str) -> ProductViewSortBy {
+        match value {
+            "Title" => ProductViewSortBy::Title,
+            "VersionCount" => ProductViewSortBy::VersionCount,
+            "CreationDate" => ProductViewSortBy::CreationDate,
+            _ => ProductViewSortBy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ProductViewSortBy {
+    fn from(value: String) -> ProductViewSortBy {
+        match value.as_str() {
+            "Title" => ProductViewSortBy::Title,
+            "VersionCount" => ProductViewSortBy::VersionCount,
+            "CreationDate" => ProductViewSortBy::CreationDate,
+            _ => ProductViewSortBy::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ProductViewSortBy {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ProductViewSortBy, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ProductViewSortBy {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ProductViewSortBy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for ProductViewSortBy {
+    fn deserialize<D>(deserializer: D) -> Result<ProductViewSortBy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Summary information about a product view.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -2232,9 +3501,102 @@ pub struct ProductViewSummary {
     /// <p>The product type. Contact the product administrator for the significance of this value. If this value is <code>MARKETPLACE</code>, the product was created by AWS Marketplace.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<ProductType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PropertyKey {
+    /// `OWNER`
+    Owner,
+    /// `LAUNCH_ROLE`
+    LaunchRole,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PropertyKey {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PropertyKey::Owner => "OWNER",
+            PropertyKey::LaunchRole => "LAUNCH_ROLE",
+            PropertyKey::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PropertyKey {
+    fn default() -> PropertyKey {
+        PropertyKey::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PropertyKey {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PropertyKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PropertyKey {
+    fn from(value: &'a str) -> PropertyKey {
+        match value {
+            "OWNER" => PropertyKey::Owner,
+            "LAUNCH_ROLE" => PropertyKey::LaunchRole,
+            _ => PropertyKey::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PropertyKey {
+    fn from(value: String) -> PropertyKey {
+        match value.as_str() {
+            "OWNER" => PropertyKey::Owner,
+            "LAUNCH_ROLE" => PropertyKey::LaunchRole,
+            _ => PropertyKey::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PropertyKey {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PropertyKey, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PropertyKey {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PropertyKey {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for PropertyKey {
+    fn deserialize<D>(deserializer: D) -> Result<PropertyKey, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ProvisionProductInput {
@@ -2295,8 +3657,13 @@ pub struct ProvisionedProductAttribute {
     pub arn: Option<String>,
     /// <p>The UTC time stamp of the creation time.</p>
     #[serde(rename = "CreatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time: Option<f64>,
+    pub created_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The identifier of the provisioned product.</p>
     #[serde(rename = "Id")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2328,7 +3695,7 @@ pub struct ProvisionedProductAttribute {
     /// <p><p>The current status of the provisioned product.</p> <ul> <li> <p> <code>AVAILABLE</code> - Stable state, ready to perform any operation. The most recent operation succeeded and completed.</p> </li> <li> <p> <code>UNDER<em>CHANGE</code> - Transitive state. Operations performed might not have valid results. Wait for an <code>AVAILABLE</code> status before performing operations.</p> </li> <li> <p> <code>TAINTED</code> - Stable state, ready to perform any operation. The stack has completed the requested operation but is not exactly what was requested. For example, a request to update to a new version failed and the stack rolled back to the current version.</p> </li> <li> <p> <code>ERROR</code> - An unexpected error occurred. The provisioned product exists but the stack is not running. For example, CloudFormation received a parameter value that was not valid and could not launch the stack.</p> </li> <li> <p> <code>PLAN</em>IN_PROGRESS</code> - Transitive state. The plan operations were performed to provision a new product, but resources have not yet been created. After reviewing the list of resources to be created, execute the plan. Wait for an <code>AVAILABLE</code> status before performing operations.</p> </li> </ul></p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ProvisionedProductStatus>,
     /// <p>The current status message of the provisioned product.</p>
     #[serde(rename = "StatusMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2361,8 +3728,13 @@ pub struct ProvisionedProductDetail {
     pub arn: Option<String>,
     /// <p>The UTC time stamp of the creation time.</p>
     #[serde(rename = "CreatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time: Option<f64>,
+    pub created_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The identifier of the provisioned product.</p>
     #[serde(rename = "Id")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2390,7 +3762,7 @@ pub struct ProvisionedProductDetail {
     /// <p><p>The current status of the provisioned product.</p> <ul> <li> <p> <code>AVAILABLE</code> - Stable state, ready to perform any operation. The most recent operation succeeded and completed.</p> </li> <li> <p> <code>UNDER<em>CHANGE</code> - Transitive state. Operations performed might not have valid results. Wait for an <code>AVAILABLE</code> status before performing operations.</p> </li> <li> <p> <code>TAINTED</code> - Stable state, ready to perform any operation. The stack has completed the requested operation but is not exactly what was requested. For example, a request to update to a new version failed and the stack rolled back to the current version.</p> </li> <li> <p> <code>ERROR</code> - An unexpected error occurred. The provisioned product exists but the stack is not running. For example, CloudFormation received a parameter value that was not valid and could not launch the stack.</p> </li> <li> <p> <code>PLAN</em>IN_PROGRESS</code> - Transitive state. The plan operations were performed to provision a new product, but resources have not yet been created. After reviewing the list of resources to be created, execute the plan. Wait for an <code>AVAILABLE</code> status before performing operations.</p> </li> </ul></p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ProvisionedProductStatus>,
     /// <p>The current status message of the provisioned product.</p>
     #[serde(rename = "StatusMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2407,8 +3779,13 @@ pub struct ProvisionedProductDetail {
 pub struct ProvisionedProductPlanDetails {
     /// <p>The UTC time stamp of the creation time.</p>
     #[serde(rename = "CreatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time: Option<f64>,
+    pub created_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Passed to CloudFormation. The SNS topic ARNs to which to publish stack-related events.</p>
     #[serde(rename = "NotificationArns")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2428,7 +3805,7 @@ pub struct ProvisionedProductPlanDetails {
     /// <p>The plan type.</p>
     #[serde(rename = "PlanType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub plan_type: Option<String>,
+    pub plan_type: Option<ProvisionedProductPlanType>,
     /// <p>The product identifier.</p>
     #[serde(rename = "ProductId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2452,7 +3829,7 @@ pub struct ProvisionedProductPlanDetails {
     /// <p>The status.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ProvisionedProductPlanStatus>,
     /// <p>The status message.</p>
     #[serde(rename = "StatusMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2463,10 +3840,128 @@ pub struct ProvisionedProductPlanDetails {
     pub tags: Option<Vec<Tag>>,
     /// <p>The time when the plan was last updated.</p>
     #[serde(rename = "UpdatedTime")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_time: Option<f64>,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_time: Option<::chrono::DateTime<::chrono::Utc>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ProvisionedProductPlanStatus {
+    /// `CREATE_IN_PROGRESS`
+    CreateInProgress,
+    /// `CREATE_SUCCESS`
+    CreateSuccess,
+    /// `CREATE_FAILED`
+    CreateFailed,
+    /// `EXECUTE_IN_PROGRESS`
+    ExecuteInProgress,
+    /// `EXECUTE_SUCCESS`
+    ExecuteSuccess,
+    /// `EXECUTE_FAILED`
+    ExecuteFailed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ProvisionedProductPlanStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ProvisionedProductPlanStatus::CreateInProgress => "CREATE_IN_PROGRESS",
+            ProvisionedProductPlanStatus::CreateSuccess => "CREATE_SUCCESS",
+            ProvisionedProductPlanStatus::CreateFailed => "CREATE_FAILED",
+            ProvisionedProductPlanStatus::ExecuteInProgress => "EXECUTE_IN_PROGRESS",
+            ProvisionedProductPlanStatus::ExecuteSuccess => "EXECUTE_SUCCESS",
+            ProvisionedProductPlanStatus::ExecuteFailed => "EXECUTE_FAILED",
+            ProvisionedProductPlanStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ProvisionedProductPlanStatus {
+    fn default() -> ProvisionedProductPlanStatus {
+        ProvisionedProductPlanStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ProvisionedProductPlanStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ProvisionedProductPlanStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ProvisionedProductPlanStatus {
+    fn from(value: &'a str) -> ProvisionedProductPlanStatus {
+        match value {
+            "CREATE_IN_PROGRESS" => ProvisionedProductPlanStatus::CreateInProgress,
+            "CREATE_SUCCESS" => ProvisionedProductPlanStatus::CreateSuccess,
+            "CREATE_FAILED" => ProvisionedProductPlanStatus::CreateFailed,
+            "EXECUTE_IN_PROGRESS" => ProvisionedProductPlanStatus::ExecuteInProgress,
+            "EXECUTE_SUCCESS" => ProvisionedProductPlanStatus::ExecuteSuccess,
+            "EXECUTE_FAILED" => ProvisionedProductPlanStatus::ExecuteFailed,
+            _ => ProvisionedProductPlanStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ProvisionedProductPlanStatus {
+    fn from(value: String) -> ProvisionedProductPlanStatus {
+        match value.as_str() {
+            "CREATE_IN_PROGRESS" => ProvisionedProductPlanStatus::CreateInProgress,
+            "CREATE_SUCCESS" => ProvisionedProductPlanStatus::CreateSuccess,
+            "CREATE_FAILED" => ProvisionedProductPlanStatus::CreateFailed,
+            "EXECUTE_IN_PROGRESS" => ProvisionedProductPlanStatus::ExecuteInProgress,
+            "EXECUTE_SUCCESS" => ProvisionedProductPlanStatus::ExecuteSuccess,
+            "EXECUTE_FAILED" => ProvisionedProductPlanStatus::ExecuteFailed,
+            _ => ProvisionedProductPlanStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ProvisionedProductPlanStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ProvisionedProductPlanStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ProvisionedProductPlanStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
 }
 
+impl ::serde::Serialize for ProvisionedProductPlanStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ProvisionedProductPlanStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ProvisionedProductPlanStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Summary information about a plan.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -2482,7 +3977,7 @@ pub struct ProvisionedProductPlanSummary {
     /// <p>The plan type.</p>
     #[serde(rename = "PlanType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub plan_type: Option<String>,
+    pub plan_type: Option<ProvisionedProductPlanType>,
     /// <p>The product identifier.</p>
     #[serde(rename = "ProvisionProductId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2497,14 +3992,303 @@ pub struct ProvisionedProductPlanSummary {
     pub provisioning_artifact_id: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ProvisionedProductPlanType {
+    /// `CLOUDFORMATION`
+    Cloudformation,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ProvisionedProductPlanType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ProvisionedProductPlanType::Cloudformation => "CLOUDFORMATION",
+            ProvisionedProductPlanType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ProvisionedProductPlanType {
+    fn default() -> ProvisionedProductPlanType {
+        ProvisionedProductPlanType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ProvisionedProductPlanType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ProvisionedProductPlanType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ProvisionedProductPlanType {
+    fn from(value: &'a str) -> ProvisionedProductPlanType {
+        match value {
+            "CLOUDFORMATION" => ProvisionedProductPlanType::Cloudformation,
+            _ => ProvisionedProductPlanType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ProvisionedProductPlanType {
+    fn from(value: String) -> ProvisionedProductPlanType {
+        match value.as_str() {
+            "CLOUDFORMATION" => ProvisionedProductPlanType::Cloudformation,
+            _ => ProvisionedProductPlanType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ProvisionedProductPlanType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ProvisionedProductPlanType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ProvisionedProductPlanType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ProvisionedProductPlanType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ProvisionedProductPlanType {
+    fn deserialize<D>(deserializer: D) -> Result<ProvisionedProductPlanType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ProvisionedProductStatus {
+    /// `AVAILABLE`
+    Available,
+    /// `UNDER_CHANGE`
+    UnderChange,
+    /// `TAINTED`
+    Tainted,
+    /// `ERROR`
+    Error,
+    /// `PLAN_IN_PROGRESS`
+    PlanInProgress,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ProvisionedProductStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ProvisionedProductStatus::Available => "AVAILABLE",
+            ProvisionedProductStatus::UnderChange => "UNDER_CHANGE",
+            ProvisionedProductStatus::Tainted => "TAINTED",
+            ProvisionedProductStatus::Error => "ERROR",
+            ProvisionedProductStatus::PlanInProgress => "PLAN_IN_PROGRESS",
+            ProvisionedProductStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ProvisionedProductStatus {
+    fn default() -> ProvisionedProductStatus {
+        ProvisionedProductStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ProvisionedProductStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ProvisionedProductStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ProvisionedProductStatus {
+    fn from(value: &'a str) -> ProvisionedProductStatus {
+        match value {
+            "AVAILABLE" => ProvisionedProductStatus::Available,
+            "UNDER_CHANGE" => ProvisionedProductStatus::UnderChange,
+            "TAINTED" => ProvisionedProductStatus::Tainted,
+            "ERROR" => ProvisionedProductStatus::Error,
+            "PLAN_IN_PROGRESS" => ProvisionedProductStatus::PlanInProgress,
+            _ => ProvisionedProductStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ProvisionedProductStatus {
+    fn from(value: String) -> ProvisionedProductStatus {
+        match value.as_str() {
+            "AVAILABLE" => ProvisionedProductStatus::Available,
+            "UNDER_CHANGE" => ProvisionedProductStatus::UnderChange,
+            "TAINTED" => ProvisionedProductStatus::Tainted,
+            "ERROR" => ProvisionedProductStatus::Error,
+            "PLAN_IN_PROGRESS" => ProvisionedProductStatus::PlanInProgress,
+            _ => ProvisionedProductStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ProvisionedProductStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ProvisionedProductStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ProvisionedProductStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ProvisionedProductStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ProvisionedProductStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ProvisionedProductStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ProvisionedProductViewFilterBy {
+    /// `SearchQuery`
+    SearchQuery,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ProvisionedProductViewFilterBy {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ProvisionedProductViewFilterBy::SearchQuery => "SearchQuery",
+            ProvisionedProductViewFilterBy::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ProvisionedProductViewFilterBy {
+    fn default() -> ProvisionedProductViewFilterBy {
+        ProvisionedProductViewFilterBy::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ProvisionedProductViewFilterBy {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ProvisionedProductViewFilterBy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ProvisionedProductViewFilterBy {
+    fn from(value: &'a str) -> ProvisionedProductViewFilterBy {
+        match value {
+            "SearchQuery" => ProvisionedProductViewFilterBy::SearchQuery,
+            _ => ProvisionedProductViewFilterBy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ProvisionedProductViewFilterBy {
+    fn from(value: String) -> ProvisionedProductViewFilterBy {
+        match value.as_str() {
+            "SearchQuery" => ProvisionedProductViewFilterBy::SearchQuery,
+            _ => ProvisionedProductViewFilterBy::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ProvisionedProductViewFilterBy {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ProvisionedProductViewFilterBy, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ProvisionedProductViewFilterBy {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ProvisionedProductViewFilterBy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ProvisionedProductViewFilterBy {
+    fn deserialize<D>(deserializer: D) -> Result<ProvisionedProductViewFilterBy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about a provisioning artifact. A provisioning artifact is also known as a product version.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ProvisioningArtifact {
     /// <p>The UTC time stamp of the creation time.</p>
     #[serde(rename = "CreatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time: Option<f64>,
+    pub created_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The description of the provisioning artifact.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2512,7 +4296,7 @@ pub struct ProvisioningArtifact {
     /// <p>Information set by the administrator to provide guidance to end users about which provisioning artifacts to use.</p>
     #[serde(rename = "Guidance")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub guidance: Option<String>,
+    pub guidance: Option<ProvisioningArtifactGuidance>,
     /// <p>The identifier of the provisioning artifact.</p>
     #[serde(rename = "Id")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2533,8 +4317,13 @@ pub struct ProvisioningArtifactDetail {
     pub active: Option<bool>,
     /// <p>The UTC time stamp of the creation time.</p>
     #[serde(rename = "CreatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time: Option<f64>,
+    pub created_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The description of the provisioning artifact.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2542,7 +4331,7 @@ pub struct ProvisioningArtifactDetail {
     /// <p>Information set by the administrator to provide guidance to end users about which provisioning artifacts to use.</p>
     #[serde(rename = "Guidance")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub guidance: Option<String>,
+    pub guidance: Option<ProvisioningArtifactGuidance>,
     /// <p>The identifier of the provisioning artifact.</p>
     #[serde(rename = "Id")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2554,9 +4343,102 @@ pub struct ProvisioningArtifactDetail {
     /// <p><p>The type of provisioning artifact.</p> <ul> <li> <p> <code>CLOUD<em>FORMATION</em>TEMPLATE</code> - AWS CloudFormation template</p> </li> <li> <p> <code>MARKETPLACE<em>AMI</code> - AWS Marketplace AMI</p> </li> <li> <p> <code>MARKETPLACE</em>CAR</code> - AWS Marketplace Clusters and AWS Resources</p> </li> </ul></p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<ProvisioningArtifactType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ProvisioningArtifactGuidance {
+    /// `DEFAULT`
+    Default,
+    /// `DEPRECATED`
+    Deprecated,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ProvisioningArtifactGuidance {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ProvisioningArtifactGuidance::Default => "DEFAULT",
+            ProvisioningArtifactGuidance::Deprecated => "DEPRECATED",
+            ProvisioningArtifactGuidance::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ProvisioningArtifactGuidance {
+    fn default() -> ProvisioningArtifactGuidance {
+        ProvisioningArtifactGuidance::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ProvisioningArtifactGuidance {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ProvisioningArtifactGuidance {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ProvisioningArtifactGuidance {
+    fn from(value: &'a str) -> ProvisioningArtifactGuidance {
+        match value {
+            "DEFAULT" => ProvisioningArtifactGuidance::Default,
+            "DEPRECATED" => ProvisioningArtifactGuidance::Deprecated,
+            _ => ProvisioningArtifactGuidance::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ProvisioningArtifactGuidance {
+    fn from(value: String) -> ProvisioningArtifactGuidance {
+        match value.as_str() {
+            "DEFAULT" => ProvisioningArtifactGuidance::Default,
+            "DEPRECATED" => ProvisioningArtifactGuidance::Deprecated,
+            _ => ProvisioningArtifactGuidance::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ProvisioningArtifactGuidance {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ProvisioningArtifactGuidance, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ProvisioningArtifactGuidance {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ProvisioningArtifactGuidance {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for ProvisioningArtifactGuidance {
+    fn deserialize<D>(deserializer: D) -> Result<ProvisioningArtifactGuidance, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about a parameter used to provision a product.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -2623,17 +4505,110 @@ pub struct ProvisioningArtifactProperties {
     /// <p><p>The type of provisioning artifact.</p> <ul> <li> <p> <code>CLOUD<em>FORMATION</em>TEMPLATE</code> - AWS CloudFormation template</p> </li> <li> <p> <code>MARKETPLACE<em>AMI</code> - AWS Marketplace AMI</p> </li> <li> <p> <code>MARKETPLACE</em>CAR</code> - AWS Marketplace Clusters and AWS Resources</p> </li> </ul></p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<ProvisioningArtifactType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ProvisioningArtifactPropertyName {
+    /// `Id`
+    Id,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ProvisioningArtifactPropertyName {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ProvisioningArtifactPropertyName::Id => "Id",
+            ProvisioningArtifactPropertyName::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ProvisioningArtifactPropertyName {
+    fn default() -> ProvisioningArtifactPropertyName {
+        ProvisioningArtifactPropertyName::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ProvisioningArtifactPropertyName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ProvisioningArtifactPropertyName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ProvisioningArtifactPropertyName {
+    fn from(value: &'a str) -> ProvisioningArtifactPropertyName {
+        match value {
+            "Id" => ProvisioningArtifactPropertyName::Id,
+            _ => ProvisioningArtifactPropertyName::Unknown(value.to_owned()),
+        }
+    }
 }
 
+impl From<String> for ProvisioningArtifactPropertyName {
+    fn from(value: String) -> ProvisioningArtifactPropertyName {
+        match value.as_str() {
+            "Id" => ProvisioningArtifactPropertyName::Id,
+            _ => ProvisioningArtifactPropertyName::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ProvisioningArtifactPropertyName {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ProvisioningArtifactPropertyName, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ProvisioningArtifactPropertyName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ProvisioningArtifactPropertyName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ProvisioningArtifactPropertyName {
+    fn deserialize<D>(deserializer: D) -> Result<ProvisioningArtifactPropertyName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Summary information about a provisioning artifact (also known as a version) for a product.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ProvisioningArtifactSummary {
     /// <p>The UTC time stamp of the creation time.</p>
     #[serde(rename = "CreatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time: Option<f64>,
+    pub created_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The description of the provisioning artifact.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2652,6 +4627,119 @@ pub struct ProvisioningArtifactSummary {
     pub provisioning_artifact_metadata: Option<::std::collections::HashMap<String, String>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ProvisioningArtifactType {
+    /// `CLOUD_FORMATION_TEMPLATE`
+    CloudFormationTemplate,
+    /// `MARKETPLACE_AMI`
+    MarketplaceAmi,
+    /// `MARKETPLACE_CAR`
+    MarketplaceCar,
+    /// `TERRAFORM_OPEN_SOURCE`
+    TerraformOpenSource,
+    /// `TERRAFORM_CLOUD`
+    TerraformCloud,
+    /// `EXTERNAL`
+    External,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ProvisioningArtifactType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ProvisioningArtifactType::CloudFormationTemplate => "CLOUD_FORMATION_TEMPLATE",
+            ProvisioningArtifactType::MarketplaceAmi => "MARKETPLACE_AMI",
+            ProvisioningArtifactType::MarketplaceCar => "MARKETPLACE_CAR",
+            ProvisioningArtifactType::TerraformOpenSource => "TERRAFORM_OPEN_SOURCE",
+            ProvisioningArtifactType::TerraformCloud => "TERRAFORM_CLOUD",
+            ProvisioningArtifactType::External => "EXTERNAL",
+            ProvisioningArtifactType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ProvisioningArtifactType {
+    fn default() -> ProvisioningArtifactType {
+        ProvisioningArtifactType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ProvisioningArtifactType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ProvisioningArtifactType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ProvisioningArtifactType {
+    fn from(value: &'a str) -> ProvisioningArtifactType {
+        match value {
+            "CLOUD_FORMATION_TEMPLATE" => ProvisioningArtifactType::CloudFormationTemplate,
+            "MARKETPLACE_AMI" => ProvisioningArtifactType::MarketplaceAmi,
+            "MARKETPLACE_CAR" => ProvisioningArtifactType::MarketplaceCar,
+            "TERRAFORM_OPEN_SOURCE" => ProvisioningArtifactType::TerraformOpenSource,
+            "TERRAFORM_CLOUD" => ProvisioningArtifactType::TerraformCloud,
+            "EXTERNAL" => ProvisioningArtifactType::External,
+            _ => ProvisioningArtifactType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ProvisioningArtifactType {
+    fn from(value: String) -> ProvisioningArtifactType {
+        match value.as_str() {
+            "CLOUD_FORMATION_TEMPLATE" => ProvisioningArtifactType::CloudFormationTemplate,
+            "MARKETPLACE_AMI" => ProvisioningArtifactType::MarketplaceAmi,
+            "MARKETPLACE_CAR" => ProvisioningArtifactType::MarketplaceCar,
+            "TERRAFORM_OPEN_SOURCE" => ProvisioningArtifactType::TerraformOpenSource,
+            "TERRAFORM_CLOUD" => ProvisioningArtifactType::TerraformCloud,
+            "EXTERNAL" => ProvisioningArtifactType::External,
+            _ => ProvisioningArtifactType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ProvisioningArtifactType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ProvisioningArtifactType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ProvisioningArtifactType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ProvisioningArtifactType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ProvisioningArtifactType {
+    fn deserialize<D>(deserializer: D) -> Result<ProvisioningArtifactType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An object that contains summary information about a product view and a provisioning artifact.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -2716,8 +4804,13 @@ pub struct ProvisioningPreferences {
 pub struct RecordDetail {
     /// <p>The UTC time stamp of the creation time.</p>
     #[serde(rename = "CreatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time: Option<f64>,
+    pub created_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The path identifier.</p>
     #[serde(rename = "PathId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2761,11 +4854,16 @@ pub struct RecordDetail {
     /// <p><p>The status of the provisioned product.</p> <ul> <li> <p> <code>CREATED</code> - The request was created but the operation has not started.</p> </li> <li> <p> <code>IN<em>PROGRESS</code> - The requested operation is in progress.</p> </li> <li> <p> <code>IN</em>PROGRESS<em>IN</em>ERROR</code> - The provisioned product is under change but the requested operation failed and some remediation is occurring. For example, a rollback.</p> </li> <li> <p> <code>SUCCEEDED</code> - The requested operation has successfully completed.</p> </li> <li> <p> <code>FAILED</code> - The requested operation has unsuccessfully completed. Investigate using the error messages returned.</p> </li> </ul></p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<RecordStatus>,
     /// <p>The time when the record was last updated.</p>
     #[serde(rename = "UpdatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_time: Option<f64>,
+    pub updated_time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>The error code and description resulting from an operation.</p>
@@ -2800,40 +4898,457 @@ pub struct RecordOutput {
     pub output_value: Option<String>,
 }
 
-/// <p>Information about a tag, which is a key-value pair.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct RecordTag {
-    /// <p>The key for this tag.</p>
-    #[serde(rename = "Key")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub key: Option<String>,
-    /// <p>The value for this tag.</p>
-    #[serde(rename = "Value")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub value: Option<String>,
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RecordStatus {
+    /// `CREATED`
+    Created,
+    /// `IN_PROGRESS`
+    InProgress,
+    /// `IN_PROGRESS_IN_ERROR`
+    InProgressInError,
+    /// `SUCCEEDED`
+    Succeeded,
+    /// `FAILED`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RecordStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RecordStatus::Created => "CREATED",
+            RecordStatus::InProgress => "IN_PROGRESS",
+            RecordStatus::InProgressInError => "IN_PROGRESS_IN_ERROR",
+            RecordStatus::Succeeded => "SUCCEEDED",
+            RecordStatus::Failed => "FAILED",
+            RecordStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RecordStatus {
+    fn default() -> RecordStatus {
+        RecordStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RecordStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RecordStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RecordStatus {
+    fn from(value: &'a str) -> RecordStatus {
+        match value {
+            "CREATED" => RecordStatus::Created,
+            "IN_PROGRESS" => RecordStatus::InProgress,
+            "IN_PROGRESS_IN_ERROR" => RecordStatus::InProgressInError,
+            "SUCCEEDED" => RecordStatus::Succeeded,
+            "FAILED" => RecordStatus::Failed,
+            _ => RecordStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RecordStatus {
+    fn from(value: String) -> RecordStatus {
+        match value.as_str() {
+            "CREATED" => RecordStatus::Created,
+            "IN_PROGRESS" => RecordStatus::InProgress,
+            "IN_PROGRESS_IN_ERROR" => RecordStatus::InProgressInError,
+            "SUCCEEDED" => RecordStatus::Succeeded,
+            "FAILED" => RecordStatus::Failed,
+            _ => RecordStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RecordStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RecordStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RecordStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RecordStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RecordStatus {
+    fn deserialize<D>(deserializer: D) -> Result<RecordStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Information about a tag, which is a key-value pair.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct RecordTag {
+    /// <p>The key for this tag.</p>
+    #[serde(rename = "Key")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+    /// <p>The value for this tag.</p>
+    #[serde(rename = "Value")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct RejectPortfolioShareInput {
+    /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
+    #[serde(rename = "AcceptLanguage")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accept_language: Option<String>,
+    /// <p>The portfolio identifier.</p>
+    #[serde(rename = "PortfolioId")]
+    pub portfolio_id: String,
+    /// <p>The type of shared portfolios to reject. The default is to reject imported portfolios.</p> <ul> <li> <p> <code>AWS_ORGANIZATIONS</code> - Reject portfolios shared by the master account of your organization.</p> </li> <li> <p> <code>IMPORTED</code> - Reject imported portfolios.</p> </li> <li> <p> <code>AWS_SERVICECATALOG</code> - Not supported. (Throws ResourceNotFoundException.)</p> </li> </ul> <p>For example, <code>aws servicecatalog reject-portfolio-share --portfolio-id "port-2qwzkwxt3y5fk" --portfolio-share-type AWS_ORGANIZATIONS</code> </p>
+    #[serde(rename = "PortfolioShareType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub portfolio_share_type: Option<PortfolioShareType>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct RejectPortfolioShareOutput {}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Replacement {
+    /// `TRUE`
+    True,
+    /// `FALSE`
+    False,
+    /// `CONDITIONAL`
+    Conditional,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Replacement {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Replacement::True => "TRUE",
+            Replacement::False => "FALSE",
+            Replacement::Conditional => "CONDITIONAL",
+            Replacement::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Replacement {
+    fn default() -> Replacement {
+        Replacement::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Replacement {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Replacement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Replacement {
+    fn from(value: &'a str) -> Replacement {
+        match value {
+            "TRUE" => Replacement::True,
+            "FALSE" => Replacement::False,
+            "CONDITIONAL" => Replacement::Conditional,
+            _ => Replacement::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Replacement {
+    fn from(value: String) -> Replacement {
+        match value.as_str() {
+            "TRUE" => Replacement::True,
+            "FALSE" => Replacement::False,
+            "CONDITIONAL" => Replacement::Conditional,
+            _ => Replacement::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Replacement {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Replacement, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Replacement {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Replacement {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Replacement {
+    fn deserialize<D>(deserializer: D) -> Result<Replacement, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RequiresRecreation {
+    /// `NEVER`
+    Never,
+    /// `CONDITIONALLY`
+    Conditionally,
+    /// `ALWAYS`
+    Always,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RequiresRecreation {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RequiresRecreation::Never => "NEVER",
+            RequiresRecreation::Conditionally => "CONDITIONALLY",
+            RequiresRecreation::Always => "ALWAYS",
+            RequiresRecreation::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RequiresRecreation {
+    fn default() -> RequiresRecreation {
+        RequiresRecreation::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RequiresRecreation {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RequiresRecreation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RequiresRecreation {
+    fn from(value: &'a str) -> RequiresRecreation {
+        match value {
+            "NEVER" => RequiresRecreation::Never,
+            "CONDITIONALLY" => RequiresRecreation::Conditionally,
+            "ALWAYS" => RequiresRecreation::Always,
+            _ => RequiresRecreation::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RequiresRecreation {
+    fn from(value: String) -> RequiresRecreation {
+        match value.as_str() {
+            "NEVER" => RequiresRecreation::Never,
+            "CONDITIONALLY" => RequiresRecreation::Conditionally,
+            "ALWAYS" => RequiresRecreation::Always,
+            _ => RequiresRecreation::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RequiresRecreation {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RequiresRecreation, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RequiresRecreation {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RequiresRecreation {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RequiresRecreation {
+    fn deserialize<D>(deserializer: D) -> Result<RequiresRecreation, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ResourceAttribute {
+    /// `PROPERTIES`
+    Properties,
+    /// `METADATA`
+    Metadata,
+    /// `CREATIONPOLICY`
+    Creationpolicy,
+    /// `UPDATEPOLICY`
+    Updatepolicy,
+    /// `DELETIONPOLICY`
+    Deletionpolicy,
+    /// `TAGS`
+    Tags,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ResourceAttribute {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceAttribute::Properties => "PROPERTIES",
+            ResourceAttribute::Metadata => "METADATA",
+            ResourceAttribute::Creationpolicy => "CREATIONPOLICY",
+            ResourceAttribute::Updatepolicy => "UPDATEPOLICY",
+            ResourceAttribute::Deletionpolicy => "DELETIONPOLICY",
+            ResourceAttribute::Tags => "TAGS",
+            ResourceAttribute::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ResourceAttribute {
+    fn default() -> ResourceAttribute {
+        ResourceAttribute::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ResourceAttribute {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ResourceAttribute {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ResourceAttribute {
+    fn from(value: &'a str) -> ResourceAttribute {
+        match value {
+            "PROPERTIES" => ResourceAttribute::Properties,
+            "METADATA" => ResourceAttribute::Metadata,
+            "CREATIONPOLICY" => ResourceAttribute::Creationpolicy,
+            "UPDATEPOLICY" => ResourceAttribute::Updatepolicy,
+            "DELETIONPOLICY" => ResourceAttribute::Deletionpolicy,
+            "TAGS" => ResourceAttribute::Tags,
+            _ => ResourceAttribute::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceAttribute {
+    fn from(value: String) -> ResourceAttribute {
+        match value.as_str() {
+            "PROPERTIES" => ResourceAttribute::Properties,
+            "METADATA" => ResourceAttribute::Metadata,
+            "CREATIONPOLICY" => ResourceAttribute::Creationpolicy,
+            "UPDATEPOLICY" => ResourceAttribute::Updatepolicy,
+            "DELETIONPOLICY" => ResourceAttribute::Deletionpolicy,
+            "TAGS" => ResourceAttribute::Tags,
+            _ => ResourceAttribute::Unknown(value),
+        }
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct RejectPortfolioShareInput {
-    /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
-    #[serde(rename = "AcceptLanguage")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub accept_language: Option<String>,
-    /// <p>The portfolio identifier.</p>
-    #[serde(rename = "PortfolioId")]
-    pub portfolio_id: String,
-    /// <p>The type of shared portfolios to reject. The default is to reject imported portfolios.</p> <ul> <li> <p> <code>AWS_ORGANIZATIONS</code> - Reject portfolios shared by the master account of your organization.</p> </li> <li> <p> <code>IMPORTED</code> - Reject imported portfolios.</p> </li> <li> <p> <code>AWS_SERVICECATALOG</code> - Not supported. (Throws ResourceNotFoundException.)</p> </li> </ul> <p>For example, <code>aws servicecatalog reject-portfolio-share --portfolio-id "port-2qwzkwxt3y5fk" --portfolio-share-type AWS_ORGANIZATIONS</code> </p>
-    #[serde(rename = "PortfolioShareType")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub portfolio_share_type: Option<String>,
+impl ::std::str::FromStr for ResourceAttribute {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ResourceAttribute, Self::Err> {
+        Ok(value.into())
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct RejectPortfolioShareOutput {}
+impl ::rusoto_core::param::ToParam for ResourceAttribute {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ResourceAttribute {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
 
+impl<'de> ::serde::Deserialize<'de> for ResourceAttribute {
+    fn deserialize<D>(deserializer: D) -> Result<ResourceAttribute, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about a resource change that will occur when a plan is executed.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -2841,7 +5356,7 @@ pub struct ResourceChange {
     /// <p>The change action.</p>
     #[serde(rename = "Action")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub action: Option<String>,
+    pub action: Option<ChangeAction>,
     /// <p>Information about the resource changes.</p>
     #[serde(rename = "Details")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2857,7 +5372,7 @@ pub struct ResourceChange {
     /// <p>If the change type is <code>Modify</code>, indicates whether the existing resource is deleted and replaced with a new one.</p>
     #[serde(rename = "Replacement")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub replacement: Option<String>,
+    pub replacement: Option<Replacement>,
     /// <p>The type of resource.</p>
     #[serde(rename = "ResourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2865,7 +5380,7 @@ pub struct ResourceChange {
     /// <p>The change scope.</p>
     #[serde(rename = "Scope")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scope: Option<Vec<String>>,
+    pub scope: Option<Vec<ResourceAttribute>>,
 }
 
 /// <p>Information about a change to a resource attribute.</p>
@@ -2879,7 +5394,7 @@ pub struct ResourceChangeDetail {
     /// <p>For static evaluations, the value of the resource attribute will change and the new value is known. For dynamic evaluations, the value might change, and any new value will be determined when the plan is updated.</p>
     #[serde(rename = "Evaluation")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub evaluation: Option<String>,
+    pub evaluation: Option<EvaluationType>,
     /// <p>Information about the resource attribute to be modified.</p>
     #[serde(rename = "Target")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2896,8 +5411,13 @@ pub struct ResourceDetail {
     pub arn: Option<String>,
     /// <p>The creation time of the resource.</p>
     #[serde(rename = "CreatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time: Option<f64>,
+    pub created_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The description of the resource.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2919,7 +5439,7 @@ pub struct ResourceTargetDefinition {
     /// <p>The attribute to be changed.</p>
     #[serde(rename = "Attribute")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub attribute: Option<String>,
+    pub attribute: Option<ResourceAttribute>,
     /// <p>If the attribute is <code>Properties</code>, the value is the name of the property. Otherwise, the value is null.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2927,7 +5447,7 @@ pub struct ResourceTargetDefinition {
     /// <p>If the attribute is <code>Properties</code>, indicates whether a change to this property causes the resource to be re-created.</p>
     #[serde(rename = "RequiresRecreation")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub requires_recreation: Option<String>,
+    pub requires_recreation: Option<RequiresRecreation>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -2974,7 +5494,7 @@ pub struct SearchProductsAsAdminInput {
     /// <p>The search filters. If no search filters are specified, the output includes all products to which the administrator has access.</p>
     #[serde(rename = "Filters")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub filters: Option<::std::collections::HashMap<String, Vec<String>>>,
+    pub filters: Option<::std::collections::HashMap<ProductViewFilterBy, Vec<String>>>,
     /// <p>The maximum number of items to return with this call.</p>
     #[serde(rename = "PageSize")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2990,15 +5510,15 @@ pub struct SearchProductsAsAdminInput {
     /// <p>Access level of the source of the product.</p>
     #[serde(rename = "ProductSource")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub product_source: Option<String>,
+    pub product_source: Option<ProductSource>,
     /// <p>The sort field. If no value is specified, the results are not sorted.</p>
     #[serde(rename = "SortBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort_by: Option<String>,
+    pub sort_by: Option<ProductViewSortBy>,
     /// <p>The sort order. If no value is specified, the results are not sorted.</p>
     #[serde(rename = "SortOrder")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort_order: Option<String>,
+    pub sort_order: Option<SortOrder>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -3024,7 +5544,7 @@ pub struct SearchProductsInput {
     /// <p>The search filters. If no search filters are specified, the output includes all products to which the caller has access.</p>
     #[serde(rename = "Filters")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub filters: Option<::std::collections::HashMap<String, Vec<String>>>,
+    pub filters: Option<::std::collections::HashMap<ProductViewFilterBy, Vec<String>>>,
     /// <p>The maximum number of items to return with this call.</p>
     #[serde(rename = "PageSize")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3036,11 +5556,11 @@ pub struct SearchProductsInput {
     /// <p>The sort field. If no value is specified, the results are not sorted.</p>
     #[serde(rename = "SortBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort_by: Option<String>,
+    pub sort_by: Option<ProductViewSortBy>,
     /// <p>The sort order. If no value is specified, the results are not sorted.</p>
     #[serde(rename = "SortOrder")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort_order: Option<String>,
+    pub sort_order: Option<SortOrder>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -3075,7 +5595,7 @@ pub struct SearchProvisionedProductsInput {
     /// <p>The search filters.</p> <p>When the key is <code>SearchQuery</code>, the searchable fields are <code>arn</code>, <code>createdTime</code>, <code>id</code>, <code>lastRecordId</code>, <code>idempotencyToken</code>, <code>name</code>, <code>physicalId</code>, <code>productId</code>, <code>provisioningArtifact</code>, <code>type</code>, <code>status</code>, <code>tags</code>, <code>userArn</code>, and <code>userArnSession</code>.</p> <p>Example: <code>"SearchQuery":["status:AVAILABLE"]</code> </p>
     #[serde(rename = "Filters")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub filters: Option<::std::collections::HashMap<String, Vec<String>>>,
+    pub filters: Option<::std::collections::HashMap<ProvisionedProductViewFilterBy, Vec<String>>>,
     /// <p>The maximum number of items to return with this call.</p>
     #[serde(rename = "PageSize")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3091,7 +5611,7 @@ pub struct SearchProvisionedProductsInput {
     /// <p>The sort order. If no value is specified, the results are not sorted.</p>
     #[serde(rename = "SortOrder")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort_order: Option<String>,
+    pub sort_order: Option<SortOrder>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -3126,6 +5646,310 @@ pub struct ServiceActionAssociation {
     pub service_action_id: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ServiceActionAssociationErrorCode {
+    /// `DUPLICATE_RESOURCE`
+    DuplicateResource,
+    /// `INTERNAL_FAILURE`
+    InternalFailure,
+    /// `LIMIT_EXCEEDED`
+    LimitExceeded,
+    /// `RESOURCE_NOT_FOUND`
+    ResourceNotFound,
+    /// `THROTTLING`
+    Throttling,
+    /// `INVALID_PARAMETER`
+    InvalidParameter,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ServiceActionAssociationErrorCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ServiceActionAssociationErrorCode::DuplicateResource => "DUPLICATE_RESOURCE",
+            ServiceActionAssociationErrorCode::InternalFailure => "INTERNAL_FAILURE",
+            ServiceActionAssociationErrorCode::LimitExceeded => "LIMIT_EXCEEDED",
+            ServiceActionAssociationErrorCode::ResourceNotFound => "RESOURCE_NOT_FOUND",
+            ServiceActionAssociationErrorCode::Throttling => "THROTTLING",
+            ServiceActionAssociationErrorCode::InvalidParameter => "INVALID_PARAMETER",
+            ServiceActionAssociationErrorCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ServiceActionAssociationErrorCode {
+    fn default() -> ServiceActionAssociationErrorCode {
+        ServiceActionAssociationErrorCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ServiceActionAssociationErrorCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ServiceActionAssociationErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ServiceActionAssociationErrorCode {
+    fn from(value: &'a str) -> ServiceActionAssociationErrorCode {
+        match value {
+            "DUPLICATE_RESOURCE" => ServiceActionAssociationErrorCode::DuplicateResource,
+            "INTERNAL_FAILURE" => ServiceActionAssociationErrorCode::InternalFailure,
+            "LIMIT_EXCEEDED" => ServiceActionAssociationErrorCode::LimitExceeded,
+            "RESOURCE_NOT_FOUND" => ServiceActionAssociationErrorCode::ResourceNotFound,
+            "THROTTLING" => ServiceActionAssociationErrorCode::Throttling,
+            "INVALID_PARAMETER" => ServiceActionAssociationErrorCode::InvalidParameter,
+            _ => ServiceActionAssociationErrorCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ServiceActionAssociationErrorCode {
+    fn from(value: String) -> ServiceActionAssociationErrorCode {
+        match value.as_str() {
+            "DUPLICATE_RESOURCE" => ServiceActionAssociationErrorCode::DuplicateResource,
+            "INTERNAL_FAILURE" => ServiceActionAssociationErrorCode::InternalFailure,
+            "LIMIT_EXCEEDED" => ServiceActionAssociationErrorCode::LimitExceeded,
+            "RESOURCE_NOT_FOUND" => ServiceActionAssociationErrorCode::ResourceNotFound,
+            "THROTTLING" => ServiceActionAssociationErrorCode::Throttling,
+            "INVALID_PARAMETER" => ServiceActionAssociationErrorCode::InvalidParameter,
+            _ => ServiceActionAssociationErrorCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ServiceActionAssociationErrorCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ServiceActionAssociationErrorCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ServiceActionAssociationErrorCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ServiceActionAssociationErrorCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ServiceActionAssociationErrorCode {
+    fn deserialize<D>(deserializer: D) -> Result<ServiceActionAssociationErrorCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ServiceActionDefinitionKey {
+    /// `Name`
+    Name,
+    /// `Version`
+    Version,
+    /// `AssumeRole`
+    AssumeRole,
+    /// `Parameters`
+    Parameters,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ServiceActionDefinitionKey {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ServiceActionDefinitionKey::Name => "Name",
+            ServiceActionDefinitionKey::Version => "Version",
+            ServiceActionDefinitionKey::AssumeRole => "AssumeRole",
+            ServiceActionDefinitionKey::Parameters => "Parameters",
+            ServiceActionDefinitionKey::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ServiceActionDefinitionKey {
+    fn default() -> ServiceActionDefinitionKey {
+        ServiceActionDefinitionKey::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ServiceActionDefinitionKey {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ServiceActionDefinitionKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ServiceActionDefinitionKey {
+    fn from(value: &'a str) -> ServiceActionDefinitionKey {
+        match value {
+            "Name" => ServiceActionDefinitionKey::Name,
+            "Version" => ServiceActionDefinitionKey::Version,
+            "AssumeRole" => ServiceActionDefinitionKey::AssumeRole,
+            "Parameters" => ServiceActionDefinitionKey::Parameters,
+            _ => ServiceActionDefinitionKey::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ServiceActionDefinitionKey {
+    fn from(value: String) -> ServiceActionDefinitionKey {
+        match value.as_str() {
+            "Name" => ServiceActionDefinitionKey::Name,
+            "Version" => ServiceActionDefinitionKey::Version,
+            "AssumeRole" => ServiceActionDefinitionKey::AssumeRole,
+            "Parameters" => ServiceActionDefinitionKey::Parameters,
+            _ => ServiceActionDefinitionKey::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ServiceActionDefinitionKey {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ServiceActionDefinitionKey, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ServiceActionDefinitionKey {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ServiceActionDefinitionKey {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ServiceActionDefinitionKey {
+    fn deserialize<D>(deserializer: D) -> Result<ServiceActionDefinitionKey, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ServiceActionDefinitionType {
+    /// `SSM_AUTOMATION`
+    SsmAutomation,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ServiceActionDefinitionType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ServiceActionDefinitionType::SsmAutomation => "SSM_AUTOMATION",
+            ServiceActionDefinitionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ServiceActionDefinitionType {
+    fn default() -> ServiceActionDefinitionType {
+        ServiceActionDefinitionType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ServiceActionDefinitionType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ServiceActionDefinitionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ServiceActionDefinitionType {
+    fn from(value: &'a str) -> ServiceActionDefinitionType {
+        match value {
+            "SSM_AUTOMATION" => ServiceActionDefinitionType::SsmAutomation,
+            _ => ServiceActionDefinitionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ServiceActionDefinitionType {
+    fn from(value: String) -> ServiceActionDefinitionType {
+        match value.as_str() {
+            "SSM_AUTOMATION" => ServiceActionDefinitionType::SsmAutomation,
+            _ => ServiceActionDefinitionType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ServiceActionDefinitionType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ServiceActionDefinitionType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ServiceActionDefinitionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ServiceActionDefinitionType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ServiceActionDefinitionType {
+    fn deserialize<D>(deserializer: D) -> Result<ServiceActionDefinitionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An object containing detailed information about the self-service action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -3133,7 +5957,7 @@ pub struct ServiceActionDetail {
     /// <p>A map that defines the self-service action.</p>
     #[serde(rename = "Definition")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub definition: Option<::std::collections::HashMap<String, String>>,
+    pub definition: Option<::std::collections::HashMap<ServiceActionDefinitionKey, String>>,
     /// <p>Summary information about the self-service action.</p>
     #[serde(rename = "ServiceActionSummary")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3147,7 +5971,7 @@ pub struct ServiceActionSummary {
     /// <p>The self-service action definition type. For example, <code>SSM_AUTOMATION</code>.</p>
     #[serde(rename = "DefinitionType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub definition_type: Option<String>,
+    pub definition_type: Option<ServiceActionDefinitionType>,
     /// <p>The self-service action description.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3176,24 +6000,225 @@ pub struct ShareDetails {
     pub successful_shares: Option<Vec<String>>,
 }
 
-/// <p>Errors that occurred during the portfolio share operation.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct ShareError {
-    /// <p>List of accounts impacted by the error.</p>
-    #[serde(rename = "Accounts")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub accounts: Option<Vec<String>>,
-    /// <p>Error type that happened when processing the operation.</p>
-    #[serde(rename = "Error")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub error: Option<String>,
-    /// <p>Information about the error.</p>
-    #[serde(rename = "Message")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub message: Option<String>,
+/// <p>Errors that occurred during the portfolio share operation.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct ShareError {
+    /// <p>List of accounts impacted by the error.</p>
+    #[serde(rename = "Accounts")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accounts: Option<Vec<String>>,
+    /// <p>Error type that happened when processing the operation.</p>
+    #[serde(rename = "Error")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// <p>Information about the error.</p>
+    #[serde(rename = "Message")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ShareStatus {
+    /// `NOT_STARTED`
+    NotStarted,
+    /// `IN_PROGRESS`
+    InProgress,
+    /// `COMPLETED`
+    Completed,
+    /// `COMPLETED_WITH_ERRORS`
+    CompletedWithErrors,
+    /// `ERROR`
+    Error,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ShareStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ShareStatus::NotStarted => "NOT_STARTED",
+            ShareStatus::InProgress => "IN_PROGRESS",
+            ShareStatus::Completed => "COMPLETED",
+            ShareStatus::CompletedWithErrors => "COMPLETED_WITH_ERRORS",
+            ShareStatus::Error => "ERROR",
+            ShareStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ShareStatus {
+    fn default() -> ShareStatus {
+        ShareStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ShareStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ShareStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ShareStatus {
+    fn from(value: &'a str) -> ShareStatus {
+        match value {
+            "NOT_STARTED" => ShareStatus::NotStarted,
+            "IN_PROGRESS" => ShareStatus::InProgress,
+            "COMPLETED" => ShareStatus::Completed,
+            "COMPLETED_WITH_ERRORS" => ShareStatus::CompletedWithErrors,
+            "ERROR" => ShareStatus::Error,
+            _ => ShareStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ShareStatus {
+    fn from(value: String) -> ShareStatus {
+        match value.as_str() {
+            "NOT_STARTED" => ShareStatus::NotStarted,
+            "IN_PROGRESS" => ShareStatus::InProgress,
+            "COMPLETED" => ShareStatus::Completed,
+            "COMPLETED_WITH_ERRORS" => ShareStatus::CompletedWithErrors,
+            "ERROR" => ShareStatus::Error,
+            _ => ShareStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ShareStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ShareStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ShareStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ShareStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ShareStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ShareStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SortOrder {
+    /// `ASCENDING`
+    Ascending,
+    /// `DESCENDING`
+    Descending,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SortOrder {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SortOrder::Ascending => "ASCENDING",
+            SortOrder::Descending => "DESCENDING",
+            SortOrder::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SortOrder {
+    fn default() -> SortOrder {
+        SortOrder::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SortOrder {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SortOrder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SortOrder {
+    fn from(value: &'a str) -> SortOrder {
+        match value {
+            "ASCENDING" => SortOrder::Ascending,
+            "DESCENDING" => SortOrder::Descending,
+            _ => SortOrder::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SortOrder {
+    fn from(value: String) -> SortOrder {
+        match value.as_str() {
+            "ASCENDING" => SortOrder::Ascending,
+            "DESCENDING" => SortOrder::Descending,
+            _ => SortOrder::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SortOrder {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SortOrder, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SortOrder {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SortOrder {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SortOrder {
+    fn deserialize<D>(deserializer: D) -> Result<SortOrder, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
 }
-
 /// <p>An AWS CloudFormation stack, in a specific account and region, that's part of a stack set operation. A stack instance is a reference to an attempted or actual stack in a given account within a given region. A stack instance can exist without a stack—for example, if the stack couldn't be created for some reason. A stack instance is associated with only one stack set. Each stack instance contains the ID of its associated stack set, as well as the ID of the actual stack and the stack status. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -3209,9 +6234,303 @@ pub struct StackInstance {
     /// <p><p>The status of the stack instance, in terms of its synchronization with its associated stack set. </p> <ul> <li> <p> <code>INOPERABLE</code>: A <code>DeleteStackInstances</code> operation has failed and left the stack in an unstable state. Stacks in this state are excluded from further <code>UpdateStackSet</code> operations. You might need to perform a <code>DeleteStackInstances</code> operation, with <code>RetainStacks</code> set to true, to delete the stack instance, and then delete the stack manually. </p> </li> <li> <p> <code>OUTDATED</code>: The stack isn&#39;t currently up to date with the stack set because either the associated stack failed during a <code>CreateStackSet</code> or <code>UpdateStackSet</code> operation, or the stack was part of a <code>CreateStackSet</code> or <code>UpdateStackSet</code> operation that failed or was stopped before the stack was created or updated.</p> </li> <li> <p> <code>CURRENT</code>: The stack is currently up to date with the stack set.</p> </li> </ul></p>
     #[serde(rename = "StackInstanceStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stack_instance_status: Option<String>,
+    pub stack_instance_status: Option<StackInstanceStatus>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StackInstanceStatus {
+    /// `CURRENT`
+    Current,
+    /// `OUTDATED`
+    Outdated,
+    /// `INOPERABLE`
+    Inoperable,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl StackInstanceStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StackInstanceStatus::Current => "CURRENT",
+            StackInstanceStatus::Outdated => "OUTDATED",
+            StackInstanceStatus::Inoperable => "INOPERABLE",
+            StackInstanceStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for StackInstanceStatus {
+    fn default() -> StackInstanceStatus {
+        StackInstanceStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for StackInstanceStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for StackInstanceStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for StackInstanceStatus {
+    fn from(value: &'a str) -> StackInstanceStatus {
+        match value {
+            "CURRENT" => StackInstanceStatus::Current,
+            "OUTDATED" => StackInstanceStatus::Outdated,
+            "INOPERABLE" => StackInstanceStatus::Inoperable,
+            _ => StackInstanceStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StackInstanceStatus {
+    fn from(value: String) -> StackInstanceStatus {
+        match value.as_str() {
+            "CURRENT" => StackInstanceStatus::Current,
+            "OUTDATED" => StackInstanceStatus::Outdated,
+            "INOPERABLE" => StackInstanceStatus::Inoperable,
+            _ => StackInstanceStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for StackInstanceStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<StackInstanceStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StackInstanceStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for StackInstanceStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StackInstanceStatus {
+    fn deserialize<D>(deserializer: D) -> Result<StackInstanceStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StackSetOperationType {
+    /// `CREATE`
+    Create,
+    /// `UPDATE`
+    Update,
+    /// `DELETE`
+    Delete,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl StackSetOperationType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StackSetOperationType::Create => "CREATE",
+            StackSetOperationType::Update => "UPDATE",
+            StackSetOperationType::Delete => "DELETE",
+            StackSetOperationType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for StackSetOperationType {
+    fn default() -> StackSetOperationType {
+        StackSetOperationType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for StackSetOperationType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for StackSetOperationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for StackSetOperationType {
+    fn from(value: &'a str) -> StackSetOperationType {
+        match value {
+            "CREATE" => StackSetOperationType::Create,
+            "UPDATE" => StackSetOperationType::Update,
+            "DELETE" => StackSetOperationType::Delete,
+            _ => StackSetOperationType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StackSetOperationType {
+    fn from(value: String) -> StackSetOperationType {
+        match value.as_str() {
+            "CREATE" => StackSetOperationType::Create,
+            "UPDATE" => StackSetOperationType::Update,
+            "DELETE" => StackSetOperationType::Delete,
+            _ => StackSetOperationType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for StackSetOperationType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<StackSetOperationType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StackSetOperationType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for StackSetOperationType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StackSetOperationType {
+    fn deserialize<D>(deserializer: D) -> Result<StackSetOperationType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Status {
+    /// `AVAILABLE`
+    Available,
+    /// `CREATING`
+    Creating,
+    /// `FAILED`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Status {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Status::Available => "AVAILABLE",
+            Status::Creating => "CREATING",
+            Status::Failed => "FAILED",
+            Status::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Status {
+    fn default() -> Status {
+        Status::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Status {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Status {
+    fn from(value: &'a str) -> Status {
+        match value {
+            "AVAILABLE" => Status::Available,
+            "CREATING" => Status::Creating,
+            "FAILED" => Status::Failed,
+            _ => Status::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Status {
+    fn from(value: String) -> Status {
+        match value.as_str() {
+            "AVAILABLE" => Status::Available,
+            "CREATING" => Status::Creating,
+            "FAILED" => Status::Failed,
+            _ => Status::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Status {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Status, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Status {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Status {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for Status {
+    fn deserialize<D>(deserializer: D) -> Result<Status, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about a tag. A tag is a key-value pair. Tags are propagated to the resources created when provisioning a product.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Tag {
@@ -3326,7 +6645,7 @@ pub struct UpdateConstraintOutput {
     /// <p>The status of the current request.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<Status>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -3503,7 +6822,7 @@ pub struct UpdateProvisionedProductPropertiesInput {
     pub provisioned_product_id: String,
     /// <p>A map that contains the provisioned product properties to be updated.</p> <p>The <code>OWNER</code> key only accepts user ARNs. The owner is the user that is allowed to see, update, terminate, and execute service actions in the provisioned product.</p> <p>The administrator can change the owner of a provisioned product to another IAM user within the same account. Both end user owners and administrators can see ownership history of the provisioned product using the <code>ListRecordHistory</code> API. The new owner can describe all past records for the provisioned product using the <code>DescribeRecord</code> API. The previous owner can no longer use <code>DescribeRecord</code>, but can still see the product's history from when he was an owner using <code>ListRecordHistory</code>.</p> <p>If a provisioned product ownership is assigned to an end user, they can see and perform any action through the API or Service Catalog console such as update, terminate, and execute service actions. If an end user provisions a product and the owner is updated to someone else, they will no longer be able to see or perform any actions through API or the Service Catalog console on that provisioned product.</p>
     #[serde(rename = "ProvisionedProductProperties")]
-    pub provisioned_product_properties: ::std::collections::HashMap<String, String>,
+    pub provisioned_product_properties: ::std::collections::HashMap<PropertyKey, String>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -3516,7 +6835,7 @@ pub struct UpdateProvisionedProductPropertiesOutput {
     /// <p>A map that contains the properties updated.</p>
     #[serde(rename = "ProvisionedProductProperties")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub provisioned_product_properties: Option<::std::collections::HashMap<String, String>>,
+    pub provisioned_product_properties: Option<::std::collections::HashMap<PropertyKey, String>>,
     /// <p>The identifier of the record.</p>
     #[serde(rename = "RecordId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3524,7 +6843,7 @@ pub struct UpdateProvisionedProductPropertiesOutput {
     /// <p>The status of the request.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<RecordStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -3545,7 +6864,7 @@ pub struct UpdateProvisioningArtifactInput {
     /// <p>Information set by the administrator to provide guidance to end users about which provisioning artifacts to use.</p> <p>The <code>DEFAULT</code> value indicates that the product version is active.</p> <p>The administrator can set the guidance to <code>DEPRECATED</code> to inform users that the product version is deprecated. Users are able to make updates to a provisioned product of a deprecated version but cannot launch new provisioned products using a deprecated version.</p>
     #[serde(rename = "Guidance")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub guidance: Option<String>,
+    pub guidance: Option<ProvisioningArtifactGuidance>,
     /// <p>The updated name of the provisioning artifact.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3572,7 +6891,7 @@ pub struct UpdateProvisioningArtifactOutput {
     /// <p>The status of the current request.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<Status>,
 }
 
 /// <p>The parameter key-value pair used to update a provisioned product.</p>
@@ -3619,7 +6938,7 @@ pub struct UpdateProvisioningPreferences {
     /// <p><p>Determines what action AWS Service Catalog performs to a stack set or a stack instance represented by the provisioned product. The default value is <code>UPDATE</code> if nothing is specified.</p> <p>Applicable only to a <code>CFN_STACKSET</code> provisioned product type.</p> <dl> <dt>CREATE</dt> <dd> <p>Creates a new stack instance in the stack set represented by the provisioned product. In this case, only new stack instances are created based on accounts and regions; if new ProductId or ProvisioningArtifactID are passed, they will be ignored.</p> </dd> <dt>UPDATE</dt> <dd> <p>Updates the stack set represented by the provisioned product and also its stack instances.</p> </dd> <dt>DELETE</dt> <dd> <p>Deletes a stack instance in the stack set represented by the provisioned product.</p> </dd> </dl></p>
     #[serde(rename = "StackSetOperationType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stack_set_operation_type: Option<String>,
+    pub stack_set_operation_type: Option<StackSetOperationType>,
     /// <p>One or more AWS Regions where the provisioned product will be available.</p> <p>Applicable only to a <code>CFN_STACKSET</code> provisioned product type.</p> <p>The specified regions should be within the list of regions from the <code>STACKSET</code> constraint. To get the list of regions in the <code>STACKSET</code> constraint, use the <code>DescribeProvisioningParameters</code> operation.</p> <p>If no values are specified, the default value is all regions from the <code>STACKSET</code> constraint.</p>
     #[serde(rename = "StackSetRegions")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3636,7 +6955,7 @@ pub struct UpdateServiceActionInput {
     /// <p>A map that defines the self-service action.</p>
     #[serde(rename = "Definition")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub definition: Option<::std::collections::HashMap<String, String>>,
+    pub definition: Option<::std::collections::HashMap<ServiceActionDefinitionKey, String>>,
     /// <p>The self-service action description.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -7865,11 +11184,12 @@ pub struct ServiceCatalogClient {
 impl ServiceCatalogClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> ServiceCatalogClient {
         ServiceCatalogClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Service Catalog"),
         }
     }
 
@@ -7901,6 +11221,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: AcceptPortfolioShareInput,
     ) -> Result<AcceptPortfolioShareOutput, RusotoError<AcceptPortfolioShareError>> {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("AcceptPortfolioShare");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7933,6 +11254,7 @@ impl ServiceCatalog for ServiceCatalogClient {
     ) -> Result<AssociateBudgetWithResourceOutput, RusotoError<AssociateBudgetWithResourceError>>
     {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("AssociateBudgetWithResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7967,6 +11289,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         RusotoError<AssociatePrincipalWithPortfolioError>,
     > {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("AssociatePrincipalWithPortfolio");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8001,6 +11324,7 @@ impl ServiceCatalog for ServiceCatalogClient {
     ) -> Result<AssociateProductWithPortfolioOutput, RusotoError<AssociateProductWithPortfolioError>>
     {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("AssociateProductWithPortfolio");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8035,6 +11359,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         RusotoError<AssociateServiceActionWithProvisioningArtifactError>,
     > {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("AssociateServiceActionWithProvisioningArtifact");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8069,6 +11394,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         RusotoError<AssociateTagOptionWithResourceError>,
     > {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("AssociateTagOptionWithResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8103,6 +11429,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         RusotoError<BatchAssociateServiceActionWithProvisioningArtifactError>,
     > {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("BatchAssociateServiceActionWithProvisioningArtifact");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8137,6 +11464,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         RusotoError<BatchDisassociateServiceActionFromProvisioningArtifactError>,
     > {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("BatchDisassociateServiceActionFromProvisioningArtifact");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8172,6 +11500,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: CopyProductInput,
     ) -> Result<CopyProductOutput, RusotoError<CopyProductError>> {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("CopyProduct");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWS242ServiceCatalogService.CopyProduct");
@@ -8199,6 +11528,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: CreateConstraintInput,
     ) -> Result<CreateConstraintOutput, RusotoError<CreateConstraintError>> {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("CreateConstraint");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8229,6 +11559,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: CreatePortfolioInput,
     ) -> Result<CreatePortfolioOutput, RusotoError<CreatePortfolioError>> {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("CreatePortfolio");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8259,6 +11590,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: CreatePortfolioShareInput,
     ) -> Result<CreatePortfolioShareOutput, RusotoError<CreatePortfolioShareError>> {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("CreatePortfolioShare");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8290,6 +11622,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: CreateProductInput,
     ) -> Result<CreateProductOutput, RusotoError<CreateProductError>> {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("CreateProduct");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWS242ServiceCatalogService.CreateProduct");
@@ -8318,6 +11651,7 @@ impl ServiceCatalog for ServiceCatalogClient {
     ) -> Result<CreateProvisionedProductPlanOutput, RusotoError<CreateProvisionedProductPlanError>>
     {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("CreateProvisionedProductPlan");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8350,6 +11684,7 @@ impl ServiceCatalog for ServiceCatalogClient {
     ) -> Result<CreateProvisioningArtifactOutput, RusotoError<CreateProvisioningArtifactError>>
     {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("CreateProvisioningArtifact");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8381,6 +11716,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: CreateServiceActionInput,
     ) -> Result<CreateServiceActionOutput, RusotoError<CreateServiceActionError>> {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("CreateServiceAction");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8412,6 +11748,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: CreateTagOptionInput,
     ) -> Result<CreateTagOptionOutput, RusotoError<CreateTagOptionError>> {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("CreateTagOption");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8442,6 +11779,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: DeleteConstraintInput,
     ) -> Result<DeleteConstraintOutput, RusotoError<DeleteConstraintError>> {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DeleteConstraint");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8472,6 +11810,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: DeletePortfolioInput,
     ) -> Result<DeletePortfolioOutput, RusotoError<DeletePortfolioError>> {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DeletePortfolio");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8502,6 +11841,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: DeletePortfolioShareInput,
     ) -> Result<DeletePortfolioShareOutput, RusotoError<DeletePortfolioShareError>> {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DeletePortfolioShare");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8533,6 +11873,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: DeleteProductInput,
     ) -> Result<DeleteProductOutput, RusotoError<DeleteProductError>> {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DeleteProduct");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWS242ServiceCatalogService.DeleteProduct");
@@ -8561,6 +11902,7 @@ impl ServiceCatalog for ServiceCatalogClient {
     ) -> Result<DeleteProvisionedProductPlanOutput, RusotoError<DeleteProvisionedProductPlanError>>
     {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DeleteProvisionedProductPlan");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8593,6 +11935,7 @@ impl ServiceCatalog for ServiceCatalogClient {
     ) -> Result<DeleteProvisioningArtifactOutput, RusotoError<DeleteProvisioningArtifactError>>
     {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DeleteProvisioningArtifact");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8624,6 +11967,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: DeleteServiceActionInput,
     ) -> Result<DeleteServiceActionOutput, RusotoError<DeleteServiceActionError>> {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DeleteServiceAction");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8655,6 +11999,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: DeleteTagOptionInput,
     ) -> Result<DeleteTagOptionOutput, RusotoError<DeleteTagOptionError>> {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DeleteTagOption");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8685,6 +12030,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: DescribeConstraintInput,
     ) -> Result<DescribeConstraintOutput, RusotoError<DescribeConstraintError>> {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DescribeConstraint");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8716,6 +12062,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: DescribeCopyProductStatusInput,
     ) -> Result<DescribeCopyProductStatusOutput, RusotoError<DescribeCopyProductStatusError>> {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DescribeCopyProductStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8747,6 +12094,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: DescribePortfolioInput,
     ) -> Result<DescribePortfolioOutput, RusotoError<DescribePortfolioError>> {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DescribePortfolio");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8778,6 +12126,7 @@ impl ServiceCatalog for ServiceCatalogClient {
     ) -> Result<DescribePortfolioShareStatusOutput, RusotoError<DescribePortfolioShareStatusError>>
     {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DescribePortfolioShareStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8809,6 +12158,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: DescribeProductInput,
     ) -> Result<DescribeProductOutput, RusotoError<DescribeProductError>> {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DescribeProduct");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8839,6 +12189,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: DescribeProductAsAdminInput,
     ) -> Result<DescribeProductAsAdminOutput, RusotoError<DescribeProductAsAdminError>> {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DescribeProductAsAdmin");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8870,6 +12221,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: DescribeProductViewInput,
     ) -> Result<DescribeProductViewOutput, RusotoError<DescribeProductViewError>> {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DescribeProductView");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8902,6 +12254,7 @@ impl ServiceCatalog for ServiceCatalogClient {
     ) -> Result<DescribeProvisionedProductOutput, RusotoError<DescribeProvisionedProductError>>
     {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DescribeProvisionedProduct");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8936,6 +12289,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         RusotoError<DescribeProvisionedProductPlanError>,
     > {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DescribeProvisionedProductPlan");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8968,6 +12322,7 @@ impl ServiceCatalog for ServiceCatalogClient {
     ) -> Result<DescribeProvisioningArtifactOutput, RusotoError<DescribeProvisioningArtifactError>>
     {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DescribeProvisioningArtifact");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9002,6 +12357,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         RusotoError<DescribeProvisioningParametersError>,
     > {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DescribeProvisioningParameters");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9033,6 +12389,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: DescribeRecordInput,
     ) -> Result<DescribeRecordOutput, RusotoError<DescribeRecordError>> {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DescribeRecord");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWS242ServiceCatalogService.DescribeRecord");
@@ -9060,6 +12417,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: DescribeServiceActionInput,
     ) -> Result<DescribeServiceActionOutput, RusotoError<DescribeServiceActionError>> {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DescribeServiceAction");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9093,6 +12451,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         RusotoError<DescribeServiceActionExecutionParametersError>,
     > {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DescribeServiceActionExecutionParameters");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9124,6 +12483,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: DescribeTagOptionInput,
     ) -> Result<DescribeTagOptionOutput, RusotoError<DescribeTagOptionError>> {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DescribeTagOption");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9154,6 +12514,7 @@ impl ServiceCatalog for ServiceCatalogClient {
     ) -> Result<DisableAWSOrganizationsAccessOutput, RusotoError<DisableAWSOrganizationsAccessError>>
     {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DisableAWSOrganizationsAccess");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9187,6 +12548,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         RusotoError<DisassociateBudgetFromResourceError>,
     > {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DisassociateBudgetFromResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9221,6 +12583,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         RusotoError<DisassociatePrincipalFromPortfolioError>,
     > {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DisassociatePrincipalFromPortfolio");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9257,6 +12620,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         RusotoError<DisassociateProductFromPortfolioError>,
     > {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DisassociateProductFromPortfolio");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9293,6 +12657,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         RusotoError<DisassociateServiceActionFromProvisioningArtifactError>,
     > {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DisassociateServiceActionFromProvisioningArtifact");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9327,6 +12692,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         RusotoError<DisassociateTagOptionFromResourceError>,
     > {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DisassociateTagOptionFromResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9360,6 +12726,7 @@ impl ServiceCatalog for ServiceCatalogClient {
     ) -> Result<EnableAWSOrganizationsAccessOutput, RusotoError<EnableAWSOrganizationsAccessError>>
     {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("EnableAWSOrganizationsAccess");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9391,6 +12758,7 @@ impl ServiceCatalog for ServiceCatalogClient {
     ) -> Result<ExecuteProvisionedProductPlanOutput, RusotoError<ExecuteProvisionedProductPlanError>>
     {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ExecuteProvisionedProductPlan");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9425,6 +12793,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         RusotoError<ExecuteProvisionedProductServiceActionError>,
     > {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ExecuteProvisionedProductServiceAction");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9460,6 +12829,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         RusotoError<GetAWSOrganizationsAccessStatusError>,
     > {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("GetAWSOrganizationsAccessStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9493,6 +12863,7 @@ impl ServiceCatalog for ServiceCatalogClient {
     ) -> Result<ListAcceptedPortfolioSharesOutput, RusotoError<ListAcceptedPortfolioSharesError>>
     {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ListAcceptedPortfolioShares");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9524,6 +12895,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: ListBudgetsForResourceInput,
     ) -> Result<ListBudgetsForResourceOutput, RusotoError<ListBudgetsForResourceError>> {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ListBudgetsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9556,6 +12928,7 @@ impl ServiceCatalog for ServiceCatalogClient {
     ) -> Result<ListConstraintsForPortfolioOutput, RusotoError<ListConstraintsForPortfolioError>>
     {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ListConstraintsForPortfolio");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9587,6 +12960,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: ListLaunchPathsInput,
     ) -> Result<ListLaunchPathsOutput, RusotoError<ListLaunchPathsError>> {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ListLaunchPaths");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9620,6 +12994,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         RusotoError<ListOrganizationPortfolioAccessError>,
     > {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ListOrganizationPortfolioAccess");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9653,6 +13028,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: ListPortfolioAccessInput,
     ) -> Result<ListPortfolioAccessOutput, RusotoError<ListPortfolioAccessError>> {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ListPortfolioAccess");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9684,6 +13060,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: ListPortfoliosInput,
     ) -> Result<ListPortfoliosOutput, RusotoError<ListPortfoliosError>> {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ListPortfolios");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWS242ServiceCatalogService.ListPortfolios");
@@ -9711,6 +13088,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: ListPortfoliosForProductInput,
     ) -> Result<ListPortfoliosForProductOutput, RusotoError<ListPortfoliosForProductError>> {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ListPortfoliosForProduct");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9743,6 +13121,7 @@ impl ServiceCatalog for ServiceCatalogClient {
     ) -> Result<ListPrincipalsForPortfolioOutput, RusotoError<ListPrincipalsForPortfolioError>>
     {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ListPrincipalsForPortfolio");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9775,6 +13154,7 @@ impl ServiceCatalog for ServiceCatalogClient {
     ) -> Result<ListProvisionedProductPlansOutput, RusotoError<ListProvisionedProductPlansError>>
     {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ListProvisionedProductPlans");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9806,6 +13186,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: ListProvisioningArtifactsInput,
     ) -> Result<ListProvisioningArtifactsOutput, RusotoError<ListProvisioningArtifactsError>> {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ListProvisioningArtifacts");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9840,6 +13221,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         RusotoError<ListProvisioningArtifactsForServiceActionError>,
     > {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ListProvisioningArtifactsForServiceAction");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9871,6 +13253,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: ListRecordHistoryInput,
     ) -> Result<ListRecordHistoryOutput, RusotoError<ListRecordHistoryError>> {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ListRecordHistory");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9901,6 +13284,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: ListResourcesForTagOptionInput,
     ) -> Result<ListResourcesForTagOptionOutput, RusotoError<ListResourcesForTagOptionError>> {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ListResourcesForTagOption");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9932,6 +13316,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: ListServiceActionsInput,
     ) -> Result<ListServiceActionsOutput, RusotoError<ListServiceActionsError>> {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ListServiceActions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9966,6 +13351,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         RusotoError<ListServiceActionsForProvisioningArtifactError>,
     > {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ListServiceActionsForProvisioningArtifact");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -10000,6 +13386,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         RusotoError<ListStackInstancesForProvisionedProductError>,
     > {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ListStackInstancesForProvisionedProduct");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -10033,6 +13420,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: ListTagOptionsInput,
     ) -> Result<ListTagOptionsOutput, RusotoError<ListTagOptionsError>> {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ListTagOptions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWS242ServiceCatalogService.ListTagOptions");
@@ -10060,6 +13448,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: ProvisionProductInput,
     ) -> Result<ProvisionProductOutput, RusotoError<ProvisionProductError>> {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ProvisionProduct");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -10090,6 +13479,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: RejectPortfolioShareInput,
     ) -> Result<RejectPortfolioShareOutput, RusotoError<RejectPortfolioShareError>> {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("RejectPortfolioShare");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -10121,6 +13511,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: ScanProvisionedProductsInput,
     ) -> Result<ScanProvisionedProductsOutput, RusotoError<ScanProvisionedProductsError>> {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ScanProvisionedProducts");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -10152,6 +13543,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: SearchProductsInput,
     ) -> Result<SearchProductsOutput, RusotoError<SearchProductsError>> {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("SearchProducts");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWS242ServiceCatalogService.SearchProducts");
@@ -10179,6 +13571,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: SearchProductsAsAdminInput,
     ) -> Result<SearchProductsAsAdminOutput, RusotoError<SearchProductsAsAdminError>> {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("SearchProductsAsAdmin");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -10210,6 +13603,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: SearchProvisionedProductsInput,
     ) -> Result<SearchProvisionedProductsOutput, RusotoError<SearchProvisionedProductsError>> {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("SearchProvisionedProducts");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -10242,6 +13636,7 @@ impl ServiceCatalog for ServiceCatalogClient {
     ) -> Result<TerminateProvisionedProductOutput, RusotoError<TerminateProvisionedProductError>>
     {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("TerminateProvisionedProduct");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -10273,6 +13668,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: UpdateConstraintInput,
     ) -> Result<UpdateConstraintOutput, RusotoError<UpdateConstraintError>> {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("UpdateConstraint");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -10303,6 +13699,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: UpdatePortfolioInput,
     ) -> Result<UpdatePortfolioOutput, RusotoError<UpdatePortfolioError>> {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("UpdatePortfolio");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -10333,6 +13730,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: UpdateProductInput,
     ) -> Result<UpdateProductOutput, RusotoError<UpdateProductError>> {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("UpdateProduct");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWS242ServiceCatalogService.UpdateProduct");
@@ -10360,6 +13758,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: UpdateProvisionedProductInput,
     ) -> Result<UpdateProvisionedProductOutput, RusotoError<UpdateProvisionedProductError>> {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("UpdateProvisionedProduct");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -10394,6 +13793,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         RusotoError<UpdateProvisionedProductPropertiesError>,
     > {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("UpdateProvisionedProductProperties");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -10428,6 +13828,7 @@ impl ServiceCatalog for ServiceCatalogClient {
     ) -> Result<UpdateProvisioningArtifactOutput, RusotoError<UpdateProvisioningArtifactError>>
     {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("UpdateProvisioningArtifact");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -10459,6 +13860,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: UpdateServiceActionInput,
     ) -> Result<UpdateServiceActionOutput, RusotoError<UpdateServiceActionError>> {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("UpdateServiceAction");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -10490,6 +13892,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: UpdateTagOptionInput,
     ) -> Result<UpdateTagOptionOutput, RusotoError<UpdateTagOptionError>> {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("UpdateTagOption");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -10514,3 +13917,572 @@ impl ServiceCatalog for ServiceCatalogClient {
         }
     }
 }
+impl ServiceCatalogClient {
+    /// Auto-paginating version of `list_accepted_portfolio_shares`, streaming every page of results.
+    pub fn list_accepted_portfolio_shares_pages(
+        &self,
+        input: ListAcceptedPortfolioSharesInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListAcceptedPortfolioSharesOutput,
+        ListAcceptedPortfolioSharesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ServiceCatalogClient, input: ListAcceptedPortfolioSharesInput| async move {
+                client.list_accepted_portfolio_shares(input).await
+            },
+            |input: &ListAcceptedPortfolioSharesInput,
+             output: &ListAcceptedPortfolioSharesOutput| {
+                Some(ListAcceptedPortfolioSharesInput {
+                    page_token: Some(output.next_page_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_accepted_portfolio_shares`, streaming the `PortfolioDetails` of every page of results.
+    pub fn list_accepted_portfolio_shares_items(
+        &self,
+        input: ListAcceptedPortfolioSharesInput,
+    ) -> rusoto_core::pagination::RusotoStream<PortfolioDetail, ListAcceptedPortfolioSharesError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_accepted_portfolio_shares_pages(input),
+            |output: ListAcceptedPortfolioSharesOutput| {
+                output.portfolio_details.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_constraints_for_portfolio`, streaming every page of results.
+    pub fn list_constraints_for_portfolio_pages(
+        &self,
+        input: ListConstraintsForPortfolioInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListConstraintsForPortfolioOutput,
+        ListConstraintsForPortfolioError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ServiceCatalogClient, input: ListConstraintsForPortfolioInput| async move {
+                client.list_constraints_for_portfolio(input).await
+            },
+            |input: &ListConstraintsForPortfolioInput,
+             output: &ListConstraintsForPortfolioOutput| {
+                Some(ListConstraintsForPortfolioInput {
+                    page_token: Some(output.next_page_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_constraints_for_portfolio`, streaming the `ConstraintDetails` of every page of results.
+    pub fn list_constraints_for_portfolio_items(
+        &self,
+        input: ListConstraintsForPortfolioInput,
+    ) -> rusoto_core::pagination::RusotoStream<ConstraintDetail, ListConstraintsForPortfolioError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_constraints_for_portfolio_pages(input),
+            |output: ListConstraintsForPortfolioOutput| {
+                output.constraint_details.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_launch_paths`, streaming every page of results.
+    pub fn list_launch_paths_pages(
+        &self,
+        input: ListLaunchPathsInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListLaunchPathsOutput, ListLaunchPathsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ServiceCatalogClient, input: ListLaunchPathsInput| async move {
+                client.list_launch_paths(input).await
+            },
+            |input: &ListLaunchPathsInput, output: &ListLaunchPathsOutput| {
+                Some(ListLaunchPathsInput {
+                    page_token: Some(output.next_page_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_launch_paths`, streaming the `LaunchPathSummaries` of every page of results.
+    pub fn list_launch_paths_items(
+        &self,
+        input: ListLaunchPathsInput,
+    ) -> rusoto_core::pagination::RusotoStream<LaunchPathSummary, ListLaunchPathsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_launch_paths_pages(input),
+            |output: ListLaunchPathsOutput| output.launch_path_summaries.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_organization_portfolio_access`, streaming every page of results.
+    pub fn list_organization_portfolio_access_pages(
+        &self,
+        input: ListOrganizationPortfolioAccessInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListOrganizationPortfolioAccessOutput,
+        ListOrganizationPortfolioAccessError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ServiceCatalogClient, input: ListOrganizationPortfolioAccessInput| async move {
+                client.list_organization_portfolio_access(input).await
+            },
+            |input: &ListOrganizationPortfolioAccessInput,
+             output: &ListOrganizationPortfolioAccessOutput| {
+                Some(ListOrganizationPortfolioAccessInput {
+                    page_token: Some(output.next_page_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_organization_portfolio_access`, streaming the `OrganizationNodes` of every page of results.
+    pub fn list_organization_portfolio_access_items(
+        &self,
+        input: ListOrganizationPortfolioAccessInput,
+    ) -> rusoto_core::pagination::RusotoStream<OrganizationNode, ListOrganizationPortfolioAccessError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_organization_portfolio_access_pages(input),
+            |output: ListOrganizationPortfolioAccessOutput| {
+                output.organization_nodes.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_portfolios`, streaming every page of results.
+    pub fn list_portfolios_pages(
+        &self,
+        input: ListPortfoliosInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListPortfoliosOutput, ListPortfoliosError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ServiceCatalogClient, input: ListPortfoliosInput| async move {
+                client.list_portfolios(input).await
+            },
+            |input: &ListPortfoliosInput, output: &ListPortfoliosOutput| {
+                Some(ListPortfoliosInput {
+                    page_token: Some(output.next_page_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_portfolios`, streaming the `PortfolioDetails` of every page of results.
+    pub fn list_portfolios_items(
+        &self,
+        input: ListPortfoliosInput,
+    ) -> rusoto_core::pagination::RusotoStream<PortfolioDetail, ListPortfoliosError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_portfolios_pages(input),
+            |output: ListPortfoliosOutput| output.portfolio_details.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_portfolios_for_product`, streaming every page of results.
+    pub fn list_portfolios_for_product_pages(
+        &self,
+        input: ListPortfoliosForProductInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListPortfoliosForProductOutput,
+        ListPortfoliosForProductError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ServiceCatalogClient, input: ListPortfoliosForProductInput| async move {
+                client.list_portfolios_for_product(input).await
+            },
+            |input: &ListPortfoliosForProductInput, output: &ListPortfoliosForProductOutput| {
+                Some(ListPortfoliosForProductInput {
+                    page_token: Some(output.next_page_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_portfolios_for_product`, streaming the `PortfolioDetails` of every page of results.
+    pub fn list_portfolios_for_product_items(
+        &self,
+        input: ListPortfoliosForProductInput,
+    ) -> rusoto_core::pagination::RusotoStream<PortfolioDetail, ListPortfoliosForProductError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_portfolios_for_product_pages(input),
+            |output: ListPortfoliosForProductOutput| output.portfolio_details.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_principals_for_portfolio`, streaming every page of results.
+    pub fn list_principals_for_portfolio_pages(
+        &self,
+        input: ListPrincipalsForPortfolioInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListPrincipalsForPortfolioOutput,
+        ListPrincipalsForPortfolioError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ServiceCatalogClient, input: ListPrincipalsForPortfolioInput| async move {
+                client.list_principals_for_portfolio(input).await
+            },
+            |input: &ListPrincipalsForPortfolioInput, output: &ListPrincipalsForPortfolioOutput| {
+                Some(ListPrincipalsForPortfolioInput {
+                    page_token: Some(output.next_page_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_principals_for_portfolio`, streaming the `Principals` of every page of results.
+    pub fn list_principals_for_portfolio_items(
+        &self,
+        input: ListPrincipalsForPortfolioInput,
+    ) -> rusoto_core::pagination::RusotoStream<Principal, ListPrincipalsForPortfolioError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_principals_for_portfolio_pages(input),
+            |output: ListPrincipalsForPortfolioOutput| output.principals.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_provisioned_product_plans`, streaming every page of results.
+    pub fn list_provisioned_product_plans_pages(
+        &self,
+        input: ListProvisionedProductPlansInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListProvisionedProductPlansOutput,
+        ListProvisionedProductPlansError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ServiceCatalogClient, input: ListProvisionedProductPlansInput| async move {
+                client.list_provisioned_product_plans(input).await
+            },
+            |input: &ListProvisionedProductPlansInput,
+             output: &ListProvisionedProductPlansOutput| {
+                Some(ListProvisionedProductPlansInput {
+                    page_token: Some(output.next_page_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_provisioned_product_plans`, streaming the `ProvisionedProductPlans` of every page of results.
+    pub fn list_provisioned_product_plans_items(
+        &self,
+        input: ListProvisionedProductPlansInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ProvisionedProductPlanSummary,
+        ListProvisionedProductPlansError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_provisioned_product_plans_pages(input),
+            |output: ListProvisionedProductPlansOutput| {
+                output.provisioned_product_plans.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_provisioning_artifacts_for_service_action`, streaming every page of results.
+    pub fn list_provisioning_artifacts_for_service_action_pages(
+        &self,
+        input: ListProvisioningArtifactsForServiceActionInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListProvisioningArtifactsForServiceActionOutput,
+        ListProvisioningArtifactsForServiceActionError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ServiceCatalogClient,
+             input: ListProvisioningArtifactsForServiceActionInput| async move {
+                client
+                    .list_provisioning_artifacts_for_service_action(input)
+                    .await
+            },
+            |input: &ListProvisioningArtifactsForServiceActionInput,
+             output: &ListProvisioningArtifactsForServiceActionOutput| {
+                Some(ListProvisioningArtifactsForServiceActionInput {
+                    page_token: Some(output.next_page_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_provisioning_artifacts_for_service_action`, streaming the `ProvisioningArtifactViews` of every page of results.
+    pub fn list_provisioning_artifacts_for_service_action_items(
+        &self,
+        input: ListProvisioningArtifactsForServiceActionInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ProvisioningArtifactView,
+        ListProvisioningArtifactsForServiceActionError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_provisioning_artifacts_for_service_action_pages(input),
+            |output: ListProvisioningArtifactsForServiceActionOutput| {
+                output.provisioning_artifact_views.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_record_history`, streaming every page of results.
+    pub fn list_record_history_pages(
+        &self,
+        input: ListRecordHistoryInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListRecordHistoryOutput, ListRecordHistoryError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ServiceCatalogClient, input: ListRecordHistoryInput| async move {
+                client.list_record_history(input).await
+            },
+            |input: &ListRecordHistoryInput, output: &ListRecordHistoryOutput| {
+                Some(ListRecordHistoryInput {
+                    page_token: Some(output.next_page_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_record_history`, streaming the `RecordDetails` of every page of results.
+    pub fn list_record_history_items(
+        &self,
+        input: ListRecordHistoryInput,
+    ) -> rusoto_core::pagination::RusotoStream<RecordDetail, ListRecordHistoryError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_record_history_pages(input),
+            |output: ListRecordHistoryOutput| output.record_details.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_resources_for_tag_option`, streaming every page of results.
+    pub fn list_resources_for_tag_option_pages(
+        &self,
+        input: ListResourcesForTagOptionInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListResourcesForTagOptionOutput,
+        ListResourcesForTagOptionError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ServiceCatalogClient, input: ListResourcesForTagOptionInput| async move {
+                client.list_resources_for_tag_option(input).await
+            },
+            |input: &ListResourcesForTagOptionInput, output: &ListResourcesForTagOptionOutput| {
+                Some(ListResourcesForTagOptionInput {
+                    page_token: Some(output.page_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_resources_for_tag_option`, streaming the `ResourceDetails` of every page of results.
+    pub fn list_resources_for_tag_option_items(
+        &self,
+        input: ListResourcesForTagOptionInput,
+    ) -> rusoto_core::pagination::RusotoStream<ResourceDetail, ListResourcesForTagOptionError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_resources_for_tag_option_pages(input),
+            |output: ListResourcesForTagOptionOutput| output.resource_details.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_service_actions`, streaming every page of results.
+    pub fn list_service_actions_pages(
+        &self,
+        input: ListServiceActionsInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListServiceActionsOutput, ListServiceActionsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ServiceCatalogClient, input: ListServiceActionsInput| async move {
+                client.list_service_actions(input).await
+            },
+            |input: &ListServiceActionsInput, output: &ListServiceActionsOutput| {
+                Some(ListServiceActionsInput {
+                    page_token: Some(output.next_page_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_service_actions`, streaming the `ServiceActionSummaries` of every page of results.
+    pub fn list_service_actions_items(
+        &self,
+        input: ListServiceActionsInput,
+    ) -> rusoto_core::pagination::RusotoStream<ServiceActionSummary, ListServiceActionsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_service_actions_pages(input),
+            |output: ListServiceActionsOutput| output.service_action_summaries.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_service_actions_for_provisioning_artifact`, streaming every page of results.
+    pub fn list_service_actions_for_provisioning_artifact_pages(
+        &self,
+        input: ListServiceActionsForProvisioningArtifactInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListServiceActionsForProvisioningArtifactOutput,
+        ListServiceActionsForProvisioningArtifactError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ServiceCatalogClient,
+             input: ListServiceActionsForProvisioningArtifactInput| async move {
+                client
+                    .list_service_actions_for_provisioning_artifact(input)
+                    .await
+            },
+            |input: &ListServiceActionsForProvisioningArtifactInput,
+             output: &ListServiceActionsForProvisioningArtifactOutput| {
+                Some(ListServiceActionsForProvisioningArtifactInput {
+                    page_token: Some(output.next_page_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_service_actions_for_provisioning_artifact`, streaming the `ServiceActionSummaries` of every page of results.
+    pub fn list_service_actions_for_provisioning_artifact_items(
+        &self,
+        input: ListServiceActionsForProvisioningArtifactInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ServiceActionSummary,
+        ListServiceActionsForProvisioningArtifactError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_service_actions_for_provisioning_artifact_pages(input),
+            |output: ListServiceActionsForProvisioningArtifactOutput| {
+                output.service_action_summaries.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_tag_options`, streaming every page of results.
+    pub fn list_tag_options_pages(
+        &self,
+        input: ListTagOptionsInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListTagOptionsOutput, ListTagOptionsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ServiceCatalogClient, input: ListTagOptionsInput| async move {
+                client.list_tag_options(input).await
+            },
+            |input: &ListTagOptionsInput, output: &ListTagOptionsOutput| {
+                Some(ListTagOptionsInput {
+                    page_token: Some(output.page_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_tag_options`, streaming the `TagOptionDetails` of every page of results.
+    pub fn list_tag_options_items(
+        &self,
+        input: ListTagOptionsInput,
+    ) -> rusoto_core::pagination::RusotoStream<TagOptionDetail, ListTagOptionsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_tag_options_pages(input),
+            |output: ListTagOptionsOutput| output.tag_option_details.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `scan_provisioned_products`, streaming every page of results.
+    pub fn scan_provisioned_products_pages(
+        &self,
+        input: ScanProvisionedProductsInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ScanProvisionedProductsOutput,
+        ScanProvisionedProductsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ServiceCatalogClient, input: ScanProvisionedProductsInput| async move {
+                client.scan_provisioned_products(input).await
+            },
+            |input: &ScanProvisionedProductsInput, output: &ScanProvisionedProductsOutput| {
+                Some(ScanProvisionedProductsInput {
+                    page_token: Some(output.next_page_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `scan_provisioned_products`, streaming the `ProvisionedProducts` of every page of results.
+    pub fn scan_provisioned_products_items(
+        &self,
+        input: ScanProvisionedProductsInput,
+    ) -> rusoto_core::pagination::RusotoStream<ProvisionedProductDetail, ScanProvisionedProductsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.scan_provisioned_products_pages(input),
+            |output: ScanProvisionedProductsOutput| output.provisioned_products.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `search_products_as_admin`, streaming every page of results.
+    pub fn search_products_as_admin_pages(
+        &self,
+        input: SearchProductsAsAdminInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        SearchProductsAsAdminOutput,
+        SearchProductsAsAdminError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ServiceCatalogClient, input: SearchProductsAsAdminInput| async move {
+                client.search_products_as_admin(input).await
+            },
+            |input: &SearchProductsAsAdminInput, output: &SearchProductsAsAdminOutput| {
+                Some(SearchProductsAsAdminInput {
+                    page_token: Some(output.next_page_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `search_products_as_admin`, streaming the `ProductViewDetails` of every page of results.
+    pub fn search_products_as_admin_items(
+        &self,
+        input: SearchProductsAsAdminInput,
+    ) -> rusoto_core::pagination::RusotoStream<ProductViewDetail, SearchProductsAsAdminError> {
+        rusoto_core::pagination::paginate_items(
+            self.search_products_as_admin_pages(input),
+            |output: SearchProductsAsAdminOutput| output.product_view_details.unwrap_or_default(),
+        )
+    }
+}
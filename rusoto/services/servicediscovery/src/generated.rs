@@ -143,6 +143,99 @@ pub struct CreateServiceResponse {
     pub service: Option<Service>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CustomHealthStatus {
+    /// `HEALTHY`
+    Healthy,
+    /// `UNHEALTHY`
+    Unhealthy,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CustomHealthStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CustomHealthStatus::Healthy => "HEALTHY",
+            CustomHealthStatus::Unhealthy => "UNHEALTHY",
+            CustomHealthStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CustomHealthStatus {
+    fn default() -> CustomHealthStatus {
+        CustomHealthStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CustomHealthStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CustomHealthStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CustomHealthStatus {
+    fn from(value: &'a str) -> CustomHealthStatus {
+        match value {
+            "HEALTHY" => CustomHealthStatus::Healthy,
+            "UNHEALTHY" => CustomHealthStatus::Unhealthy,
+            _ => CustomHealthStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CustomHealthStatus {
+    fn from(value: String) -> CustomHealthStatus {
+        match value.as_str() {
+            "HEALTHY" => CustomHealthStatus::Healthy,
+            "UNHEALTHY" => CustomHealthStatus::Unhealthy,
+            _ => CustomHealthStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CustomHealthStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CustomHealthStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CustomHealthStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CustomHealthStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CustomHealthStatus {
+    fn deserialize<D>(deserializer: D) -> Result<CustomHealthStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct DeleteNamespaceRequest {
@@ -198,7 +291,7 @@ pub struct DiscoverInstancesRequest {
     /// <p>The health status of the instances that you want to discover.</p>
     #[serde(rename = "HealthStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub health_status: Option<String>,
+    pub health_status: Option<HealthStatusFilter>,
     /// <p>The maximum number of instances that you want Cloud Map to return in the response to a <code>DiscoverInstances</code> request. If you don't specify a value for <code>MaxResults</code>, Cloud Map returns up to 100 instances.</p>
     #[serde(rename = "MaxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -233,7 +326,7 @@ pub struct DnsConfig {
     /// <p>The routing policy that you want to apply to all Route 53 DNS records that AWS Cloud Map creates when you register an instance and specify this service.</p> <note> <p>If you want to use this service to register instances that create alias records, specify <code>WEIGHTED</code> for the routing policy.</p> </note> <p>You can specify the following values:</p> <p> <b>MULTIVALUE</b> </p> <p>If you define a health check for the service and the health check is healthy, Route 53 returns the applicable value for up to eight instances.</p> <p>For example, suppose the service includes configurations for one A record and a health check, and you use the service to register 10 instances. Route 53 responds to DNS queries with IP addresses for up to eight healthy instances. If fewer than eight instances are healthy, Route 53 responds to every DNS query with the IP addresses for all of the healthy instances.</p> <p>If you don't define a health check for the service, Route 53 assumes that all instances are healthy and returns the values for up to eight instances.</p> <p>For more information about the multivalue routing policy, see <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/routing-policy.html#routing-policy-multivalue">Multivalue Answer Routing</a> in the <i>Route 53 Developer Guide</i>.</p> <p> <b>WEIGHTED</b> </p> <p>Route 53 returns the applicable value from one randomly selected instance from among the instances that you registered using the same service. Currently, all records have the same weight, so you can't route more or less traffic to any instances.</p> <p>For example, suppose the service includes configurations for one A record and a health check, and you use the service to register 10 instances. Route 53 responds to DNS queries with the IP address for one randomly selected instance from among the healthy instances. If no instances are healthy, Route 53 responds to DNS queries as if all of the instances were healthy.</p> <p>If you don't define a health check for the service, Route 53 assumes that all instances are healthy and returns the applicable value for one randomly selected instance.</p> <p>For more information about the weighted routing policy, see <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/routing-policy.html#routing-policy-weighted">Weighted Routing</a> in the <i>Route 53 Developer Guide</i>.</p>
     #[serde(rename = "RoutingPolicy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub routing_policy: Option<String>,
+    pub routing_policy: Option<RoutingPolicy>,
 }
 
 /// <p>A complex type that contains information about changes to the Route 53 DNS records that AWS Cloud Map creates when you register an instance.</p>
@@ -263,9 +356,112 @@ pub struct DnsRecord {
     pub ttl: i64,
     /// <p>The type of the resource, which indicates the type of value that Route 53 returns in response to DNS queries.</p> <p>Note the following:</p> <ul> <li> <p> <b>A, AAAA, and SRV records:</b> You can specify settings for a maximum of one A, one AAAA, and one SRV record. You can specify them in any combination.</p> </li> <li> <p> <b>CNAME records:</b> If you specify <code>CNAME</code> for <code>Type</code>, you can't define any other records. This is a limitation of DNS: you can't create a CNAME record and any other type of record that has the same name as a CNAME record.</p> </li> <li> <p> <b>Alias records:</b> If you want AWS Cloud Map to create a Route 53 alias record when you register an instance, specify <code>A</code> or <code>AAAA</code> for <code>Type</code>.</p> </li> <li> <p> <b>All records:</b> You specify settings other than <code>TTL</code> and <code>Type</code> when you register an instance.</p> </li> </ul> <p>The following values are supported:</p> <p> <b>A</b> </p> <p>Route 53 returns the IP address of the resource in IPv4 format, such as 192.0.2.44.</p> <p> <b>AAAA</b> </p> <p>Route 53 returns the IP address of the resource in IPv6 format, such as 2001:0db8:85a3:0000:0000:abcd:0001:2345.</p> <p> <b>CNAME</b> </p> <p>Route 53 returns the domain name of the resource, such as www.example.com. Note the following:</p> <ul> <li> <p>You specify the domain name that you want to route traffic to when you register an instance. For more information, see <a>RegisterInstanceRequest$Attributes</a>.</p> </li> <li> <p>You must specify <code>WEIGHTED</code> for the value of <code>RoutingPolicy</code>.</p> </li> <li> <p>You can't specify both <code>CNAME</code> for <code>Type</code> and settings for <code>HealthCheckConfig</code>. If you do, the request will fail with an <code>InvalidInput</code> error.</p> </li> </ul> <p> <b>SRV</b> </p> <p>Route 53 returns the value for an SRV record. The value for an SRV record uses the following values:</p> <p> <code>priority weight port service-hostname</code> </p> <p>Note the following about the values:</p> <ul> <li> <p>The values of <code>priority</code> and <code>weight</code> are both set to <code>1</code> and can't be changed. </p> </li> <li> <p>The value of <code>port</code> comes from the value that you specify for the <code>AWS_INSTANCE_PORT</code> attribute when you submit a <a>RegisterInstance</a> request. </p> </li> <li> <p>The value of <code>service-hostname</code> is a concatenation of the following values:</p> <ul> <li> <p>The value that you specify for <code>InstanceId</code> when you register an instance.</p> </li> <li> <p>The name of the service.</p> </li> <li> <p>The name of the namespace. </p> </li> </ul> <p>For example, if the value of <code>InstanceId</code> is <code>test</code>, the name of the service is <code>backend</code>, and the name of the namespace is <code>example.com</code>, the value of <code>service-hostname</code> is:</p> <p> <code>test.backend.example.com</code> </p> </li> </ul> <p>If you specify settings for an SRV record and if you specify values for <code>AWS_INSTANCE_IPV4</code>, <code>AWS_INSTANCE_IPV6</code>, or both in the <code>RegisterInstance</code> request, AWS Cloud Map automatically creates <code>A</code> and/or <code>AAAA</code> records that have the same name as the value of <code>service-hostname</code> in the SRV record. You can ignore these records.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: RecordType,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FilterCondition {
+    /// `EQ`
+    Eq,
+    /// `IN`
+    In,
+    /// `BETWEEN`
+    Between,
+    /// `BEGINS_WITH`
+    BeginsWith,
+    /// A value not known to this version of the crate
+    Unknown(String),
 }
 
+impl FilterCondition {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FilterCondition::Eq => "EQ",
+            FilterCondition::In => "IN",
+            FilterCondition::Between => "BETWEEN",
+            FilterCondition::BeginsWith => "BEGINS_WITH",
+            FilterCondition::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for FilterCondition {
+    fn default() -> FilterCondition {
+        FilterCondition::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for FilterCondition {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FilterCondition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FilterCondition {
+    fn from(value: &'a str) -> FilterCondition {
+        match value {
+            "EQ" => FilterCondition::Eq,
+            "IN" => FilterCondition::In,
+            "BETWEEN" => FilterCondition::Between,
+            "BEGINS_WITH" => FilterCondition::BeginsWith,
+            _ => FilterCondition::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FilterCondition {
+    fn from(value: String) -> FilterCondition {
+        match value.as_str() {
+            "EQ" => FilterCondition::Eq,
+            "IN" => FilterCondition::In,
+            "BETWEEN" => FilterCondition::Between,
+            "BEGINS_WITH" => FilterCondition::BeginsWith,
+            _ => FilterCondition::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for FilterCondition {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<FilterCondition, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FilterCondition {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FilterCondition {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FilterCondition {
+    fn deserialize<D>(deserializer: D) -> Result<FilterCondition, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct GetInstanceRequest {
@@ -316,7 +512,7 @@ pub struct GetInstancesHealthStatusResponse {
     /// <p>A complex type that contains the IDs and the health status of the instances that you specified in the <code>GetInstancesHealthStatus</code> request.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<::std::collections::HashMap<String, String>>,
+    pub status: Option<::std::collections::HashMap<String, HealthStatus>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -383,7 +579,7 @@ pub struct HealthCheckConfig {
     pub resource_path: Option<String>,
     /// <p>The type of health check that you want to create, which indicates how Route 53 determines whether an endpoint is healthy.</p> <important> <p>You can't change the value of <code>Type</code> after you create a health check.</p> </important> <p>You can create the following types of health checks:</p> <ul> <li> <p> <b>HTTP</b>: Route 53 tries to establish a TCP connection. If successful, Route 53 submits an HTTP request and waits for an HTTP status code of 200 or greater and less than 400.</p> </li> <li> <p> <b>HTTPS</b>: Route 53 tries to establish a TCP connection. If successful, Route 53 submits an HTTPS request and waits for an HTTP status code of 200 or greater and less than 400.</p> <important> <p>If you specify HTTPS for the value of <code>Type</code>, the endpoint must support TLS v1.0 or later.</p> </important> </li> <li> <p> <b>TCP</b>: Route 53 tries to establish a TCP connection.</p> <p>If you specify <code>TCP</code> for <code>Type</code>, don't specify a value for <code>ResourcePath</code>.</p> </li> </ul> <p>For more information, see <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/dns-failover-determining-health-of-endpoints.html">How Route 53 Determines Whether an Endpoint Is Healthy</a> in the <i>Route 53 Developer Guide</i>.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: HealthCheckType,
 }
 
 /// <p>A complex type that contains information about an optional custom health check. A custom health check, which requires that you use a third-party health checker to evaluate the health of your resources, is useful in the following circumstances:</p> <ul> <li> <p>You can't use a health check that is defined by <code>HealthCheckConfig</code> because the resource isn't available over the internet. For example, you can use a custom health check when the instance is in an Amazon VPC. (To check the health of resources in a VPC, the health checker must also be in the VPC.)</p> </li> <li> <p>You want to use a third-party health checker regardless of where your resources are.</p> </li> </ul> <important> <p>If you specify a health check configuration, you can specify either <code>HealthCheckCustomConfig</code> or <code>HealthCheckConfig</code> but not both.</p> </important> <p>To change the status of a custom health check, submit an <code>UpdateInstanceCustomHealthStatus</code> request. Cloud Map doesn't monitor the status of the resource, it just keeps a record of the status specified in the most recent <code>UpdateInstanceCustomHealthStatus</code> request.</p> <p>Here's how custom health checks work:</p> <ol> <li> <p>You create a service and specify a value for <code>FailureThreshold</code>. </p> <p>The failure threshold indicates the number of 30-second intervals you want AWS Cloud Map to wait between the time that your application sends an <a>UpdateInstanceCustomHealthStatus</a> request and the time that AWS Cloud Map stops routing internet traffic to the corresponding resource.</p> </li> <li> <p>You register an instance.</p> </li> <li> <p>You configure a third-party health checker to monitor the resource that is associated with the new instance. </p> <note> <p>AWS Cloud Map doesn't check the health of the resource directly. </p> </note> </li> <li> <p>The third-party health-checker determines that the resource is unhealthy and notifies your application.</p> </li> <li> <p>Your application submits an <code>UpdateInstanceCustomHealthStatus</code> request.</p> </li> <li> <p>AWS Cloud Map waits for (<code>FailureThreshold</code> x 30) seconds.</p> </li> <li> <p>If another <code>UpdateInstanceCustomHealthStatus</code> request doesn't arrive during that time to change the status back to healthy, AWS Cloud Map stops routing traffic to the resource.</p> </li> </ol> <p>Note the following about configuring custom health checks.</p>
@@ -395,6 +591,305 @@ pub struct HealthCheckCustomConfig {
     pub failure_threshold: Option<i64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum HealthCheckType {
+    /// `HTTP`
+    Http,
+    /// `HTTPS`
+    Https,
+    /// `TCP`
+    Tcp,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl HealthCheckType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            HealthCheckType::Http => "HTTP",
+            HealthCheckType::Https => "HTTPS",
+            HealthCheckType::Tcp => "TCP",
+            HealthCheckType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for HealthCheckType {
+    fn default() -> HealthCheckType {
+        HealthCheckType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for HealthCheckType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for HealthCheckType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for HealthCheckType {
+    fn from(value: &'a str) -> HealthCheckType {
+        match value {
+            "HTTP" => HealthCheckType::Http,
+            "HTTPS" => HealthCheckType::Https,
+            "TCP" => HealthCheckType::Tcp,
+            _ => HealthCheckType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for HealthCheckType {
+    fn from(value: String) -> HealthCheckType {
+        match value.as_str() {
+            "HTTP" => HealthCheckType::Http,
+            "HTTPS" => HealthCheckType::Https,
+            "TCP" => HealthCheckType::Tcp,
+            _ => HealthCheckType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for HealthCheckType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<HealthCheckType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for HealthCheckType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for HealthCheckType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for HealthCheckType {
+    fn deserialize<D>(deserializer: D) -> Result<HealthCheckType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum HealthStatus {
+    /// `HEALTHY`
+    Healthy,
+    /// `UNHEALTHY`
+    Unhealthy,
+    /// `UNKNOWN`
+    UnknownValue,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl HealthStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            HealthStatus::Healthy => "HEALTHY",
+            HealthStatus::Unhealthy => "UNHEALTHY",
+            HealthStatus::UnknownValue => "UNKNOWN",
+            HealthStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for HealthStatus {
+    fn default() -> HealthStatus {
+        HealthStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for HealthStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for HealthStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for HealthStatus {
+    fn from(value: &'a str) -> HealthStatus {
+        match value {
+            "HEALTHY" => HealthStatus::Healthy,
+            "UNHEALTHY" => HealthStatus::Unhealthy,
+            "UNKNOWN" => HealthStatus::UnknownValue,
+            _ => HealthStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for HealthStatus {
+    fn from(value: String) -> HealthStatus {
+        match value.as_str() {
+            "HEALTHY" => HealthStatus::Healthy,
+            "UNHEALTHY" => HealthStatus::Unhealthy,
+            "UNKNOWN" => HealthStatus::UnknownValue,
+            _ => HealthStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for HealthStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<HealthStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for HealthStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for HealthStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for HealthStatus {
+    fn deserialize<D>(deserializer: D) -> Result<HealthStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum HealthStatusFilter {
+    /// `HEALTHY`
+    Healthy,
+    /// `UNHEALTHY`
+    Unhealthy,
+    /// `ALL`
+    All,
+    /// `HEALTHY_OR_ELSE_ALL`
+    HealthyOrElseAll,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl HealthStatusFilter {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            HealthStatusFilter::Healthy => "HEALTHY",
+            HealthStatusFilter::Unhealthy => "UNHEALTHY",
+            HealthStatusFilter::All => "ALL",
+            HealthStatusFilter::HealthyOrElseAll => "HEALTHY_OR_ELSE_ALL",
+            HealthStatusFilter::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for HealthStatusFilter {
+    fn default() -> HealthStatusFilter {
+        HealthStatusFilter::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for HealthStatusFilter {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for HealthStatusFilter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for HealthStatusFilter {
+    fn from(value: &'a str) -> HealthStatusFilter {
+        match value {
+            "HEALTHY" => HealthStatusFilter::Healthy,
+            "UNHEALTHY" => HealthStatusFilter::Unhealthy,
+            "ALL" => HealthStatusFilter::All,
+            "HEALTHY_OR_ELSE_ALL" => HealthStatusFilter::HealthyOrElseAll,
+            _ => HealthStatusFilter::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for HealthStatusFilter {
+    fn from(value: String) -> HealthStatusFilter {
+        match value.as_str() {
+            "HEALTHY" => HealthStatusFilter::Healthy,
+            "UNHEALTHY" => HealthStatusFilter::Unhealthy,
+            "ALL" => HealthStatusFilter::All,
+            "HEALTHY_OR_ELSE_ALL" => HealthStatusFilter::HealthyOrElseAll,
+            _ => HealthStatusFilter::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for HealthStatusFilter {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<HealthStatusFilter, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for HealthStatusFilter {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for HealthStatusFilter {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for HealthStatusFilter {
+    fn deserialize<D>(deserializer: D) -> Result<HealthStatusFilter, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>In a response to a <a>DiscoverInstance</a> request, <code>HttpInstanceSummary</code> contains information about one instance that matches the values that you specified in the request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -406,7 +901,7 @@ pub struct HttpInstanceSummary {
     /// <p>If you configured health checking in the service, the current health status of the service instance.</p>
     #[serde(rename = "HealthStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub health_status: Option<String>,
+    pub health_status: Option<HealthStatus>,
     /// <p>The ID of an instance that matches the values that you specified in the request.</p>
     #[serde(rename = "InstanceId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -591,8 +1086,13 @@ pub struct Namespace {
     pub arn: Option<String>,
     /// <p>The date that the namespace was created, in Unix date/time format and Coordinated Universal Time (UTC). The value of <code>CreateDate</code> is accurate to milliseconds. For example, the value <code>1516925490.087</code> represents Friday, January 26, 2018 12:11:30.087 AM.</p>
     #[serde(rename = "CreateDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub create_date: Option<f64>,
+    pub create_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A unique string that identifies the request and that allows failed requests to be retried without the risk of executing an operation twice. </p>
     #[serde(rename = "CreatorRequestId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -620,7 +1120,7 @@ pub struct Namespace {
     /// <p>The type of the namespace. Valid values are <code>DNS_PUBLIC</code> and <code>DNS_PRIVATE</code>.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<NamespaceType>,
 }
 
 /// <p>A complex type that identifies the namespaces that you want to list. You can choose to list public or private namespaces.</p>
@@ -630,15 +1130,113 @@ pub struct NamespaceFilter {
     /// <p><p>The operator that you want to use to determine whether <code>ListNamespaces</code> returns a namespace. Valid values for <code>condition</code> include:</p> <ul> <li> <p> <code>EQ</code>: When you specify <code>EQ</code> for the condition, you can choose to list only public namespaces or private namespaces, but not both. <code>EQ</code> is the default condition and can be omitted.</p> </li> <li> <p> <code>IN</code>: When you specify <code>IN</code> for the condition, you can choose to list public namespaces, private namespaces, or both. </p> </li> <li> <p> <code>BETWEEN</code>: Not applicable</p> </li> </ul></p>
     #[serde(rename = "Condition")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub condition: Option<String>,
+    pub condition: Option<FilterCondition>,
     /// <p>Specify <code>TYPE</code>.</p>
     #[serde(rename = "Name")]
-    pub name: String,
+    pub name: NamespaceFilterName,
     /// <p>If you specify <code>EQ</code> for <code>Condition</code>, specify either <code>DNS_PUBLIC</code> or <code>DNS_PRIVATE</code>.</p> <p>If you specify <code>IN</code> for <code>Condition</code>, you can specify <code>DNS_PUBLIC</code>, <code>DNS_PRIVATE</code>, or both.</p>
     #[serde(rename = "Values")]
     pub values: Vec<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum NamespaceFilterName {
+    /// `TYPE`
+    Type,
+    /// `NAME`
+    Name,
+    /// `HTTP_NAME`
+    HttpName,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl NamespaceFilterName {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            NamespaceFilterName::Type => "TYPE",
+            NamespaceFilterName::Name => "NAME",
+            NamespaceFilterName::HttpName => "HTTP_NAME",
+            NamespaceFilterName::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for NamespaceFilterName {
+    fn default() -> NamespaceFilterName {
+        NamespaceFilterName::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for NamespaceFilterName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for NamespaceFilterName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for NamespaceFilterName {
+    fn from(value: &'a str) -> NamespaceFilterName {
+        match value {
+            "TYPE" => NamespaceFilterName::Type,
+            "NAME" => NamespaceFilterName::Name,
+            "HTTP_NAME" => NamespaceFilterName::HttpName,
+            _ => NamespaceFilterName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for NamespaceFilterName {
+    fn from(value: String) -> NamespaceFilterName {
+        match value.as_str() {
+            "TYPE" => NamespaceFilterName::Type,
+            "NAME" => NamespaceFilterName::Name,
+            "HTTP_NAME" => NamespaceFilterName::HttpName,
+            _ => NamespaceFilterName::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for NamespaceFilterName {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<NamespaceFilterName, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for NamespaceFilterName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for NamespaceFilterName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for NamespaceFilterName {
+    fn deserialize<D>(deserializer: D) -> Result<NamespaceFilterName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A complex type that contains information that is specific to the namespace type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -663,8 +1261,13 @@ pub struct NamespaceSummary {
     pub arn: Option<String>,
     /// <p>The date and time that the namespace was created.</p>
     #[serde(rename = "CreateDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub create_date: Option<f64>,
+    pub create_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A description for the namespace.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -687,17 +1290,120 @@ pub struct NamespaceSummary {
     /// <p>The type of the namespace, either public or private.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<NamespaceType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum NamespaceType {
+    /// `DNS_PUBLIC`
+    DnsPublic,
+    /// `DNS_PRIVATE`
+    DnsPrivate,
+    /// `HTTP`
+    Http,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl NamespaceType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            NamespaceType::DnsPublic => "DNS_PUBLIC",
+            NamespaceType::DnsPrivate => "DNS_PRIVATE",
+            NamespaceType::Http => "HTTP",
+            NamespaceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for NamespaceType {
+    fn default() -> NamespaceType {
+        NamespaceType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for NamespaceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
 }
 
+impl fmt::Display for NamespaceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for NamespaceType {
+    fn from(value: &'a str) -> NamespaceType {
+        match value {
+            "DNS_PUBLIC" => NamespaceType::DnsPublic,
+            "DNS_PRIVATE" => NamespaceType::DnsPrivate,
+            "HTTP" => NamespaceType::Http,
+            _ => NamespaceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for NamespaceType {
+    fn from(value: String) -> NamespaceType {
+        match value.as_str() {
+            "DNS_PUBLIC" => NamespaceType::DnsPublic,
+            "DNS_PRIVATE" => NamespaceType::DnsPrivate,
+            "HTTP" => NamespaceType::Http,
+            _ => NamespaceType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for NamespaceType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<NamespaceType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for NamespaceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for NamespaceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for NamespaceType {
+    fn deserialize<D>(deserializer: D) -> Result<NamespaceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A complex type that contains information about a specified operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Operation {
     /// <p>The date and time that the request was submitted, in Unix date/time format and Coordinated Universal Time (UTC). The value of <code>CreateDate</code> is accurate to milliseconds. For example, the value <code>1516925490.087</code> represents Friday, January 26, 2018 12:11:30.087 AM.</p>
     #[serde(rename = "CreateDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub create_date: Option<f64>,
+    pub create_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>The code associated with <code>ErrorMessage</code>. Values for <code>ErrorCode</code> include the following:</p> <ul> <li> <p> <code>ACCESS<em>DENIED</code> </p> </li> <li> <p> <code>CANNOT</em>CREATE<em>HOSTED</em>ZONE</code> </p> </li> <li> <p> <code>EXPIRED<em>TOKEN</code> </p> </li> <li> <p> <code>HOSTED</em>ZONE<em>NOT</em>FOUND</code> </p> </li> <li> <p> <code>INTERNAL<em>FAILURE</code> </p> </li> <li> <p> <code>INVALID</em>CHANGE<em>BATCH</code> </p> </li> <li> <p> <code>THROTTLED</em>REQUEST</code> </p> </li> </ul></p>
     #[serde(rename = "ErrorCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -713,19 +1419,24 @@ pub struct Operation {
     /// <p><p>The status of the operation. Values include the following:</p> <ul> <li> <p> <b>SUBMITTED</b>: This is the initial state immediately after you submit a request.</p> </li> <li> <p> <b>PENDING</b>: AWS Cloud Map is performing the operation.</p> </li> <li> <p> <b>SUCCESS</b>: The operation succeeded.</p> </li> <li> <p> <b>FAIL</b>: The operation failed. For the failure reason, see <code>ErrorMessage</code>.</p> </li> </ul></p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<OperationStatus>,
     /// <p><p>The name of the target entity that is associated with the operation:</p> <ul> <li> <p> <b>NAMESPACE</b>: The namespace ID is returned in the <code>ResourceId</code> property.</p> </li> <li> <p> <b>SERVICE</b>: The service ID is returned in the <code>ResourceId</code> property.</p> </li> <li> <p> <b>INSTANCE</b>: The instance ID is returned in the <code>ResourceId</code> property.</p> </li> </ul></p>
     #[serde(rename = "Targets")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub targets: Option<::std::collections::HashMap<String, String>>,
+    pub targets: Option<::std::collections::HashMap<OperationTargetType, String>>,
     /// <p>The name of the operation that is associated with the specified ID.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<OperationType>,
     /// <p>The date and time that the value of <code>Status</code> changed to the current value, in Unix date/time format and Coordinated Universal Time (UTC). The value of <code>UpdateDate</code> is accurate to milliseconds. For example, the value <code>1516925490.087</code> represents Friday, January 26, 2018 12:11:30.087 AM.</p>
     #[serde(rename = "UpdateDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub update_date: Option<f64>,
+    pub update_date: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>A complex type that lets you select the operations that you want to list.</p>
@@ -735,15 +1446,226 @@ pub struct OperationFilter {
     /// <p><p>The operator that you want to use to determine whether an operation matches the specified value. Valid values for condition include:</p> <ul> <li> <p> <code>EQ</code>: When you specify <code>EQ</code> for the condition, you can specify only one value. <code>EQ</code> is supported for <code>NAMESPACE<em>ID</code>, <code>SERVICE</em>ID</code>, <code>STATUS</code>, and <code>TYPE</code>. <code>EQ</code> is the default condition and can be omitted.</p> </li> <li> <p> <code>IN</code>: When you specify <code>IN</code> for the condition, you can specify a list of one or more values. <code>IN</code> is supported for <code>STATUS</code> and <code>TYPE</code>. An operation must match one of the specified values to be returned in the response.</p> </li> <li> <p> <code>BETWEEN</code>: Specify a start date and an end date in Unix date/time format and Coordinated Universal Time (UTC). The start date must be the first value. <code>BETWEEN</code> is supported for <code>UPDATE_DATE</code>. </p> </li> </ul></p>
     #[serde(rename = "Condition")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub condition: Option<String>,
+    pub condition: Option<FilterCondition>,
     /// <p><p>Specify the operations that you want to get:</p> <ul> <li> <p> <b>NAMESPACE<em>ID</b>: Gets operations related to specified namespaces.</p> </li> <li> <p> <b>SERVICE</em>ID</b>: Gets operations related to specified services.</p> </li> <li> <p> <b>STATUS</b>: Gets operations based on the status of the operations: <code>SUBMITTED</code>, <code>PENDING</code>, <code>SUCCEED</code>, or <code>FAIL</code>.</p> </li> <li> <p> <b>TYPE</b>: Gets specified types of operation.</p> </li> <li> <p> <b>UPDATE_DATE</b>: Gets operations that changed status during a specified date/time range. </p> </li> </ul></p>
     #[serde(rename = "Name")]
-    pub name: String,
+    pub name: OperationFilterName,
     /// <p><p>Specify values that are applicable to the value that you specify for <code>Name</code>: </p> <ul> <li> <p> <b>NAMESPACE<em>ID</b>: Specify one namespace ID.</p> </li> <li> <p> <b>SERVICE</em>ID</b>: Specify one service ID.</p> </li> <li> <p> <b>STATUS</b>: Specify one or more statuses: <code>SUBMITTED</code>, <code>PENDING</code>, <code>SUCCEED</code>, or <code>FAIL</code>.</p> </li> <li> <p> <b>TYPE</b>: Specify one or more of the following types: <code>CREATE<em>NAMESPACE</code>, <code>DELETE</em>NAMESPACE</code>, <code>UPDATE<em>SERVICE</code>, <code>REGISTER</em>INSTANCE</code>, or <code>DEREGISTER<em>INSTANCE</code>.</p> </li> <li> <p> <b>UPDATE</em>DATE</b>: Specify a start date and an end date in Unix date/time format and Coordinated Universal Time (UTC). The start date must be the first value.</p> </li> </ul></p>
     #[serde(rename = "Values")]
     pub values: Vec<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OperationFilterName {
+    /// `NAMESPACE_ID`
+    NamespaceId,
+    /// `SERVICE_ID`
+    ServiceId,
+    /// `STATUS`
+    Status,
+    /// `TYPE`
+    Type,
+    /// `UPDATE_DATE`
+    UpdateDate,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl OperationFilterName {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OperationFilterName::NamespaceId => "NAMESPACE_ID",
+            OperationFilterName::ServiceId => "SERVICE_ID",
+            OperationFilterName::Status => "STATUS",
+            OperationFilterName::Type => "TYPE",
+            OperationFilterName::UpdateDate => "UPDATE_DATE",
+            OperationFilterName::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for OperationFilterName {
+    fn default() -> OperationFilterName {
+        OperationFilterName::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for OperationFilterName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for OperationFilterName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for OperationFilterName {
+    fn from(value: &'a str) -> OperationFilterName {
+        match value {
+            "NAMESPACE_ID" => OperationFilterName::NamespaceId,
+            "SERVICE_ID" => OperationFilterName::ServiceId,
+            "STATUS" => OperationFilterName::Status,
+            "TYPE" => OperationFilterName::Type,
+            "UPDATE_DATE" => OperationFilterName::UpdateDate,
+            _ => OperationFilterName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OperationFilterName {
+    fn from(value: String) -> OperationFilterName {
+        match value.as_str() {
+            "NAMESPACE_ID" => OperationFilterName::NamespaceId,
+            "SERVICE_ID" => OperationFilterName::ServiceId,
+            "STATUS" => OperationFilterName::Status,
+            "TYPE" => OperationFilterName::Type,
+            "UPDATE_DATE" => OperationFilterName::UpdateDate,
+            _ => OperationFilterName::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for OperationFilterName {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<OperationFilterName, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OperationFilterName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for OperationFilterName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OperationFilterName {
+    fn deserialize<D>(deserializer: D) -> Result<OperationFilterName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OperationStatus {
+    /// `SUBMITTED`
+    Submitted,
+    /// `PENDING`
+    Pending,
+    /// `SUCCESS`
+    Success,
+    /// `FAIL`
+    Fail,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl OperationStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OperationStatus::Submitted => "SUBMITTED",
+            OperationStatus::Pending => "PENDING",
+            OperationStatus::Success => "SUCCESS",
+            OperationStatus::Fail => "FAIL",
+            OperationStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for OperationStatus {
+    fn default() -> OperationStatus {
+        OperationStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for OperationStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for OperationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for OperationStatus {
+    fn from(value: &'a str) -> OperationStatus {
+        match value {
+            "SUBMITTED" => OperationStatus::Submitted,
+            "PENDING" => OperationStatus::Pending,
+            "SUCCESS" => OperationStatus::Success,
+            "FAIL" => OperationStatus::Fail,
+            _ => OperationStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OperationStatus {
+    fn from(value: String) -> OperationStatus {
+        match value.as_str() {
+            "SUBMITTED" => OperationStatus::Submitted,
+            "PENDING" => OperationStatus::Pending,
+            "SUCCESS" => OperationStatus::Success,
+            "FAIL" => OperationStatus::Fail,
+            _ => OperationStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for OperationStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<OperationStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OperationStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for OperationStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OperationStatus {
+    fn deserialize<D>(deserializer: D) -> Result<OperationStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A complex type that contains information about an operation that matches the criteria that you specified in a <a>ListOperations</a> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -755,9 +1677,323 @@ pub struct OperationSummary {
     /// <p><p>The status of the operation. Values include the following:</p> <ul> <li> <p> <b>SUBMITTED</b>: This is the initial state immediately after you submit a request.</p> </li> <li> <p> <b>PENDING</b>: AWS Cloud Map is performing the operation.</p> </li> <li> <p> <b>SUCCESS</b>: The operation succeeded.</p> </li> <li> <p> <b>FAIL</b>: The operation failed. For the failure reason, see <code>ErrorMessage</code>.</p> </li> </ul></p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<OperationStatus>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OperationTargetType {
+    /// `NAMESPACE`
+    Namespace,
+    /// `SERVICE`
+    Service,
+    /// `INSTANCE`
+    Instance,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl OperationTargetType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OperationTargetType::Namespace => "NAMESPACE",
+            OperationTargetType::Service => "SERVICE",
+            OperationTargetType::Instance => "INSTANCE",
+            OperationTargetType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for OperationTargetType {
+    fn default() -> OperationTargetType {
+        OperationTargetType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for OperationTargetType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for OperationTargetType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for OperationTargetType {
+    fn from(value: &'a str) -> OperationTargetType {
+        match value {
+            "NAMESPACE" => OperationTargetType::Namespace,
+            "SERVICE" => OperationTargetType::Service,
+            "INSTANCE" => OperationTargetType::Instance,
+            _ => OperationTargetType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OperationTargetType {
+    fn from(value: String) -> OperationTargetType {
+        match value.as_str() {
+            "NAMESPACE" => OperationTargetType::Namespace,
+            "SERVICE" => OperationTargetType::Service,
+            "INSTANCE" => OperationTargetType::Instance,
+            _ => OperationTargetType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for OperationTargetType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<OperationTargetType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OperationTargetType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for OperationTargetType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OperationTargetType {
+    fn deserialize<D>(deserializer: D) -> Result<OperationTargetType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OperationType {
+    /// `CREATE_NAMESPACE`
+    CreateNamespace,
+    /// `DELETE_NAMESPACE`
+    DeleteNamespace,
+    /// `UPDATE_NAMESPACE`
+    UpdateNamespace,
+    /// `UPDATE_SERVICE`
+    UpdateService,
+    /// `REGISTER_INSTANCE`
+    RegisterInstance,
+    /// `DEREGISTER_INSTANCE`
+    DeregisterInstance,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl OperationType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OperationType::CreateNamespace => "CREATE_NAMESPACE",
+            OperationType::DeleteNamespace => "DELETE_NAMESPACE",
+            OperationType::UpdateNamespace => "UPDATE_NAMESPACE",
+            OperationType::UpdateService => "UPDATE_SERVICE",
+            OperationType::RegisterInstance => "REGISTER_INSTANCE",
+            OperationType::DeregisterInstance => "DEREGISTER_INSTANCE",
+            OperationType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for OperationType {
+    fn default() -> OperationType {
+        OperationType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for OperationType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for OperationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for OperationType {
+    fn from(value: &'a str) -> OperationType {
+        match value {
+            "CREATE_NAMESPACE" => OperationType::CreateNamespace,
+            "DELETE_NAMESPACE" => OperationType::DeleteNamespace,
+            "UPDATE_NAMESPACE" => OperationType::UpdateNamespace,
+            "UPDATE_SERVICE" => OperationType::UpdateService,
+            "REGISTER_INSTANCE" => OperationType::RegisterInstance,
+            "DEREGISTER_INSTANCE" => OperationType::DeregisterInstance,
+            _ => OperationType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OperationType {
+    fn from(value: String) -> OperationType {
+        match value.as_str() {
+            "CREATE_NAMESPACE" => OperationType::CreateNamespace,
+            "DELETE_NAMESPACE" => OperationType::DeleteNamespace,
+            "UPDATE_NAMESPACE" => OperationType::UpdateNamespace,
+            "UPDATE_SERVICE" => OperationType::UpdateService,
+            "REGISTER_INSTANCE" => OperationType::RegisterInstance,
+            "DEREGISTER_INSTANCE" => OperationType::DeregisterInstance,
+            _ => OperationType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for OperationType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<OperationType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OperationType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for OperationType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OperationType {
+    fn deserialize<D>(deserializer: D) -> Result<OperationType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RecordType {
+    /// `SRV`
+    Srv,
+    /// `A`
+    A,
+    /// `AAAA`
+    Aaaa,
+    /// `CNAME`
+    Cname,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RecordType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RecordType::Srv => "SRV",
+            RecordType::A => "A",
+            RecordType::Aaaa => "AAAA",
+            RecordType::Cname => "CNAME",
+            RecordType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RecordType {
+    fn default() -> RecordType {
+        RecordType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RecordType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RecordType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RecordType {
+    fn from(value: &'a str) -> RecordType {
+        match value {
+            "SRV" => RecordType::Srv,
+            "A" => RecordType::A,
+            "AAAA" => RecordType::Aaaa,
+            "CNAME" => RecordType::Cname,
+            _ => RecordType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RecordType {
+    fn from(value: String) -> RecordType {
+        match value.as_str() {
+            "SRV" => RecordType::Srv,
+            "A" => RecordType::A,
+            "AAAA" => RecordType::Aaaa,
+            "CNAME" => RecordType::Cname,
+            _ => RecordType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RecordType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RecordType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RecordType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RecordType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for RecordType {
+    fn deserialize<D>(deserializer: D) -> Result<RecordType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct RegisterInstanceRequest {
@@ -785,6 +2021,99 @@ pub struct RegisterInstanceResponse {
     pub operation_id: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RoutingPolicy {
+    /// `MULTIVALUE`
+    Multivalue,
+    /// `WEIGHTED`
+    Weighted,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RoutingPolicy {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RoutingPolicy::Multivalue => "MULTIVALUE",
+            RoutingPolicy::Weighted => "WEIGHTED",
+            RoutingPolicy::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RoutingPolicy {
+    fn default() -> RoutingPolicy {
+        RoutingPolicy::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RoutingPolicy {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RoutingPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RoutingPolicy {
+    fn from(value: &'a str) -> RoutingPolicy {
+        match value {
+            "MULTIVALUE" => RoutingPolicy::Multivalue,
+            "WEIGHTED" => RoutingPolicy::Weighted,
+            _ => RoutingPolicy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RoutingPolicy {
+    fn from(value: String) -> RoutingPolicy {
+        match value.as_str() {
+            "MULTIVALUE" => RoutingPolicy::Multivalue,
+            "WEIGHTED" => RoutingPolicy::Weighted,
+            _ => RoutingPolicy::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RoutingPolicy {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RoutingPolicy, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RoutingPolicy {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RoutingPolicy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RoutingPolicy {
+    fn deserialize<D>(deserializer: D) -> Result<RoutingPolicy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A complex type that contains information about the specified service.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -795,8 +2124,13 @@ pub struct Service {
     pub arn: Option<String>,
     /// <p>The date and time that the service was created, in Unix format and Coordinated Universal Time (UTC). The value of <code>CreateDate</code> is accurate to milliseconds. For example, the value <code>1516925490.087</code> represents Friday, January 26, 2018 12:11:30.087 AM.</p>
     #[serde(rename = "CreateDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub create_date: Option<f64>,
+    pub create_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A unique string that identifies the request and that allows failed requests to be retried without the risk of executing the operation twice. <code>CreatorRequestId</code> can be any unique string, for example, a date/time stamp.</p>
     #[serde(rename = "CreatorRequestId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -858,15 +2192,103 @@ pub struct ServiceFilter {
     /// <p><p>The operator that you want to use to determine whether a service is returned by <code>ListServices</code>. Valid values for <code>Condition</code> include the following:</p> <ul> <li> <p> <code>EQ</code>: When you specify <code>EQ</code>, specify one namespace ID for <code>Values</code>. <code>EQ</code> is the default condition and can be omitted.</p> </li> <li> <p> <code>IN</code>: When you specify <code>IN</code>, specify a list of the IDs for the namespaces that you want <code>ListServices</code> to return a list of services for.</p> </li> <li> <p> <code>BETWEEN</code>: Not applicable.</p> </li> </ul></p>
     #[serde(rename = "Condition")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub condition: Option<String>,
+    pub condition: Option<FilterCondition>,
     /// <p>Specify <code>NAMESPACE_ID</code>.</p>
     #[serde(rename = "Name")]
-    pub name: String,
+    pub name: ServiceFilterName,
     /// <p>The values that are applicable to the value that you specify for <code>Condition</code> to filter the list of services.</p>
     #[serde(rename = "Values")]
     pub values: Vec<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ServiceFilterName {
+    /// `NAMESPACE_ID`
+    NamespaceId,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ServiceFilterName {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ServiceFilterName::NamespaceId => "NAMESPACE_ID",
+            ServiceFilterName::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ServiceFilterName {
+    fn default() -> ServiceFilterName {
+        ServiceFilterName::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ServiceFilterName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ServiceFilterName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ServiceFilterName {
+    fn from(value: &'a str) -> ServiceFilterName {
+        match value {
+            "NAMESPACE_ID" => ServiceFilterName::NamespaceId,
+            _ => ServiceFilterName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ServiceFilterName {
+    fn from(value: String) -> ServiceFilterName {
+        match value.as_str() {
+            "NAMESPACE_ID" => ServiceFilterName::NamespaceId,
+            _ => ServiceFilterName::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ServiceFilterName {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ServiceFilterName, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ServiceFilterName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ServiceFilterName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ServiceFilterName {
+    fn deserialize<D>(deserializer: D) -> Result<ServiceFilterName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A complex type that contains information about a specified service.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -877,8 +2299,13 @@ pub struct ServiceSummary {
     pub arn: Option<String>,
     /// <p>The date and time that the service was created.</p>
     #[serde(rename = "CreateDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub create_date: Option<f64>,
+    pub create_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The description that you specify when you create the service.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -917,7 +2344,7 @@ pub struct UpdateInstanceCustomHealthStatusRequest {
     pub service_id: String,
     /// <p>The new status of the instance, <code>HEALTHY</code> or <code>UNHEALTHY</code>.</p>
     #[serde(rename = "Status")]
-    pub status: String,
+    pub status: CustomHealthStatus,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -1969,11 +3396,12 @@ pub struct ServiceDiscoveryClient {
 impl ServiceDiscoveryClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> ServiceDiscoveryClient {
         ServiceDiscoveryClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "ServiceDiscovery"),
         }
     }
 
@@ -2005,6 +3433,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: CreateHttpNamespaceRequest,
     ) -> Result<CreateHttpNamespaceResponse, RusotoError<CreateHttpNamespaceError>> {
         let mut request = SignedRequest::new("POST", "servicediscovery", &self.region, "/");
+        request.set_operation("CreateHttpNamespace");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2037,6 +3466,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
     ) -> Result<CreatePrivateDnsNamespaceResponse, RusotoError<CreatePrivateDnsNamespaceError>>
     {
         let mut request = SignedRequest::new("POST", "servicediscovery", &self.region, "/");
+        request.set_operation("CreatePrivateDnsNamespace");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2068,6 +3498,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: CreatePublicDnsNamespaceRequest,
     ) -> Result<CreatePublicDnsNamespaceResponse, RusotoError<CreatePublicDnsNamespaceError>> {
         let mut request = SignedRequest::new("POST", "servicediscovery", &self.region, "/");
+        request.set_operation("CreatePublicDnsNamespace");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2099,6 +3530,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: CreateServiceRequest,
     ) -> Result<CreateServiceResponse, RusotoError<CreateServiceError>> {
         let mut request = SignedRequest::new("POST", "servicediscovery", &self.region, "/");
+        request.set_operation("CreateService");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53AutoNaming_v20170314.CreateService");
@@ -2126,6 +3558,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: DeleteNamespaceRequest,
     ) -> Result<DeleteNamespaceResponse, RusotoError<DeleteNamespaceError>> {
         let mut request = SignedRequest::new("POST", "servicediscovery", &self.region, "/");
+        request.set_operation("DeleteNamespace");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2156,6 +3589,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: DeleteServiceRequest,
     ) -> Result<DeleteServiceResponse, RusotoError<DeleteServiceError>> {
         let mut request = SignedRequest::new("POST", "servicediscovery", &self.region, "/");
+        request.set_operation("DeleteService");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53AutoNaming_v20170314.DeleteService");
@@ -2183,6 +3617,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: DeregisterInstanceRequest,
     ) -> Result<DeregisterInstanceResponse, RusotoError<DeregisterInstanceError>> {
         let mut request = SignedRequest::new("POST", "servicediscovery", &self.region, "/");
+        request.set_operation("DeregisterInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2214,6 +3649,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: DiscoverInstancesRequest,
     ) -> Result<DiscoverInstancesResponse, RusotoError<DiscoverInstancesError>> {
         let mut request = SignedRequest::new("POST", "servicediscovery", &self.region, "/");
+        request.set_operation("DiscoverInstances");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2245,6 +3681,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: GetInstanceRequest,
     ) -> Result<GetInstanceResponse, RusotoError<GetInstanceError>> {
         let mut request = SignedRequest::new("POST", "servicediscovery", &self.region, "/");
+        request.set_operation("GetInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53AutoNaming_v20170314.GetInstance");
@@ -2272,6 +3709,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: GetInstancesHealthStatusRequest,
     ) -> Result<GetInstancesHealthStatusResponse, RusotoError<GetInstancesHealthStatusError>> {
         let mut request = SignedRequest::new("POST", "servicediscovery", &self.region, "/");
+        request.set_operation("GetInstancesHealthStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2303,6 +3741,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: GetNamespaceRequest,
     ) -> Result<GetNamespaceResponse, RusotoError<GetNamespaceError>> {
         let mut request = SignedRequest::new("POST", "servicediscovery", &self.region, "/");
+        request.set_operation("GetNamespace");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53AutoNaming_v20170314.GetNamespace");
@@ -2330,6 +3769,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: GetOperationRequest,
     ) -> Result<GetOperationResponse, RusotoError<GetOperationError>> {
         let mut request = SignedRequest::new("POST", "servicediscovery", &self.region, "/");
+        request.set_operation("GetOperation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53AutoNaming_v20170314.GetOperation");
@@ -2357,6 +3797,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: GetServiceRequest,
     ) -> Result<GetServiceResponse, RusotoError<GetServiceError>> {
         let mut request = SignedRequest::new("POST", "servicediscovery", &self.region, "/");
+        request.set_operation("GetService");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53AutoNaming_v20170314.GetService");
@@ -2384,6 +3825,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: ListInstancesRequest,
     ) -> Result<ListInstancesResponse, RusotoError<ListInstancesError>> {
         let mut request = SignedRequest::new("POST", "servicediscovery", &self.region, "/");
+        request.set_operation("ListInstances");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53AutoNaming_v20170314.ListInstances");
@@ -2411,6 +3853,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: ListNamespacesRequest,
     ) -> Result<ListNamespacesResponse, RusotoError<ListNamespacesError>> {
         let mut request = SignedRequest::new("POST", "servicediscovery", &self.region, "/");
+        request.set_operation("ListNamespaces");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53AutoNaming_v20170314.ListNamespaces");
@@ -2438,6 +3881,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: ListOperationsRequest,
     ) -> Result<ListOperationsResponse, RusotoError<ListOperationsError>> {
         let mut request = SignedRequest::new("POST", "servicediscovery", &self.region, "/");
+        request.set_operation("ListOperations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53AutoNaming_v20170314.ListOperations");
@@ -2465,6 +3909,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: ListServicesRequest,
     ) -> Result<ListServicesResponse, RusotoError<ListServicesError>> {
         let mut request = SignedRequest::new("POST", "servicediscovery", &self.region, "/");
+        request.set_operation("ListServices");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53AutoNaming_v20170314.ListServices");
@@ -2492,6 +3937,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: RegisterInstanceRequest,
     ) -> Result<RegisterInstanceResponse, RusotoError<RegisterInstanceError>> {
         let mut request = SignedRequest::new("POST", "servicediscovery", &self.region, "/");
+        request.set_operation("RegisterInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2523,6 +3969,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: UpdateInstanceCustomHealthStatusRequest,
     ) -> Result<(), RusotoError<UpdateInstanceCustomHealthStatusError>> {
         let mut request = SignedRequest::new("POST", "servicediscovery", &self.region, "/");
+        request.set_operation("UpdateInstanceCustomHealthStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2555,6 +4002,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: UpdateServiceRequest,
     ) -> Result<UpdateServiceResponse, RusotoError<UpdateServiceError>> {
         let mut request = SignedRequest::new("POST", "servicediscovery", &self.region, "/");
+        request.set_operation("UpdateService");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53AutoNaming_v20170314.UpdateService");
@@ -2576,3 +4024,128 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         }
     }
 }
+impl ServiceDiscoveryClient {
+    /// Auto-paginating version of `list_instances`, streaming every page of results.
+    pub fn list_instances_pages(
+        &self,
+        input: ListInstancesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListInstancesResponse, ListInstancesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ServiceDiscoveryClient, input: ListInstancesRequest| async move {
+                client.list_instances(input).await
+            },
+            |input: &ListInstancesRequest, output: &ListInstancesResponse| {
+                Some(ListInstancesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_instances`, streaming the `Instances` of every page of results.
+    pub fn list_instances_items(
+        &self,
+        input: ListInstancesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<InstanceSummary, ListInstancesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_instances_pages(input),
+            |output: ListInstancesResponse| output.instances.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_namespaces`, streaming every page of results.
+    pub fn list_namespaces_pages(
+        &self,
+        input: ListNamespacesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListNamespacesResponse, ListNamespacesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ServiceDiscoveryClient, input: ListNamespacesRequest| async move {
+                client.list_namespaces(input).await
+            },
+            |input: &ListNamespacesRequest, output: &ListNamespacesResponse| {
+                Some(ListNamespacesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_namespaces`, streaming the `Namespaces` of every page of results.
+    pub fn list_namespaces_items(
+        &self,
+        input: ListNamespacesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<NamespaceSummary, ListNamespacesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_namespaces_pages(input),
+            |output: ListNamespacesResponse| output.namespaces.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_operations`, streaming every page of results.
+    pub fn list_operations_pages(
+        &self,
+        input: ListOperationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListOperationsResponse, ListOperationsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ServiceDiscoveryClient, input: ListOperationsRequest| async move {
+                client.list_operations(input).await
+            },
+            |input: &ListOperationsRequest, output: &ListOperationsResponse| {
+                Some(ListOperationsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_operations`, streaming the `Operations` of every page of results.
+    pub fn list_operations_items(
+        &self,
+        input: ListOperationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<OperationSummary, ListOperationsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_operations_pages(input),
+            |output: ListOperationsResponse| output.operations.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_services`, streaming every page of results.
+    pub fn list_services_pages(
+        &self,
+        input: ListServicesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListServicesResponse, ListServicesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ServiceDiscoveryClient, input: ListServicesRequest| async move {
+                client.list_services(input).await
+            },
+            |input: &ListServicesRequest, output: &ListServicesResponse| {
+                Some(ListServicesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_services`, streaming the `Services` of every page of results.
+    pub fn list_services_items(
+        &self,
+        input: ListServicesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ServiceSummary, ListServicesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_services_pages(input),
+            |output: ListServicesResponse| output.services.unwrap_or_default(),
+        )
+    }
+}
@@ -130,12 +130,110 @@ impl AmazonResourceNameDeserializer {
         Ok(obj)
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BehaviorOnMXFailure {
+    /// `UseDefaultValue`
+    UseDefaultValue,
+    /// `RejectMessage`
+    RejectMessage,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl BehaviorOnMXFailure {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BehaviorOnMXFailure::UseDefaultValue => "UseDefaultValue",
+            BehaviorOnMXFailure::RejectMessage => "RejectMessage",
+            BehaviorOnMXFailure::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for BehaviorOnMXFailure {
+    fn default() -> BehaviorOnMXFailure {
+        BehaviorOnMXFailure::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for BehaviorOnMXFailure {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for BehaviorOnMXFailure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for BehaviorOnMXFailure {
+    fn from(value: &'a str) -> BehaviorOnMXFailure {
+        match value {
+            "UseDefaultValue" => BehaviorOnMXFailure::UseDefaultValue,
+            "RejectMessage" => BehaviorOnMXFailure::RejectMessage,
+            _ => BehaviorOnMXFailure::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BehaviorOnMXFailure {
+    fn from(value: String) -> BehaviorOnMXFailure {
+        match value.as_str() {
+            "UseDefaultValue" => BehaviorOnMXFailure::UseDefaultValue,
+            "RejectMessage" => BehaviorOnMXFailure::RejectMessage,
+            _ => BehaviorOnMXFailure::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for BehaviorOnMXFailure {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<BehaviorOnMXFailure, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for BehaviorOnMXFailure {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for BehaviorOnMXFailure {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for BehaviorOnMXFailure {
+    fn deserialize<D>(deserializer: D) -> Result<BehaviorOnMXFailure, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct BehaviorOnMXFailureDeserializer;
 impl BehaviorOnMXFailureDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<BehaviorOnMXFailure, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -280,12 +378,127 @@ impl BounceStatusCodeDeserializer {
         Ok(obj)
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BounceType {
+    /// `DoesNotExist`
+    DoesNotExist,
+    /// `MessageTooLarge`
+    MessageTooLarge,
+    /// `ExceededQuota`
+    ExceededQuota,
+    /// `ContentRejected`
+    ContentRejected,
+    /// `Undefined`
+    Undefined,
+    /// `TemporaryFailure`
+    TemporaryFailure,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl BounceType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BounceType::DoesNotExist => "DoesNotExist",
+            BounceType::MessageTooLarge => "MessageTooLarge",
+            BounceType::ExceededQuota => "ExceededQuota",
+            BounceType::ContentRejected => "ContentRejected",
+            BounceType::Undefined => "Undefined",
+            BounceType::TemporaryFailure => "TemporaryFailure",
+            BounceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for BounceType {
+    fn default() -> BounceType {
+        BounceType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for BounceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for BounceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for BounceType {
+    fn from(value: &'a str) -> BounceType {
+        match value {
+            "DoesNotExist" => BounceType::DoesNotExist,
+            "MessageTooLarge" => BounceType::MessageTooLarge,
+            "ExceededQuota" => BounceType::ExceededQuota,
+            "ContentRejected" => BounceType::ContentRejected,
+            "Undefined" => BounceType::Undefined,
+            "TemporaryFailure" => BounceType::TemporaryFailure,
+            _ => BounceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BounceType {
+    fn from(value: String) -> BounceType {
+        match value.as_str() {
+            "DoesNotExist" => BounceType::DoesNotExist,
+            "MessageTooLarge" => BounceType::MessageTooLarge,
+            "ExceededQuota" => BounceType::ExceededQuota,
+            "ContentRejected" => BounceType::ContentRejected,
+            "Undefined" => BounceType::Undefined,
+            "TemporaryFailure" => BounceType::TemporaryFailure,
+            _ => BounceType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for BounceType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<BounceType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for BounceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for BounceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for BounceType {
+    fn deserialize<D>(deserializer: D) -> Result<BounceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Recipient-related information to include in the Delivery Status Notification (DSN) when an email that Amazon SES receives on your behalf bounces.</p> <p>For information about receiving email through Amazon SES, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct BouncedRecipientInfo {
     /// <p>The reason for the bounce. You must provide either this parameter or <code>RecipientDsnFields</code>.</p>
-    pub bounce_type: Option<String>,
+    pub bounce_type: Option<BounceType>,
     /// <p>The email address of the recipient of the bounced email.</p>
     pub recipient: String,
     /// <p>This parameter is used only for sending authorization. It is the ARN of the identity that is associated with the sending authorization policy that permits you to receive email for the recipient of the bounced email. For more information about sending authorization, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/sending-authorization.html">Amazon SES Developer Guide</a>.</p>
@@ -392,7 +605,7 @@ pub struct BulkEmailDestinationStatus {
     /// <p>The unique message identifier returned from the <code>SendBulkTemplatedEmail</code> operation.</p>
     pub message_id: Option<String>,
     /// <p><p>The status of a message sent using the <code>SendBulkTemplatedEmail</code> operation.</p> <p>Possible values for this parameter include:</p> <ul> <li> <p> <code>Success</code>: Amazon SES accepted the message, and will attempt to deliver it to the recipients.</p> </li> <li> <p> <code>MessageRejected</code>: The message was rejected because it contained a virus.</p> </li> <li> <p> <code>MailFromDomainNotVerified</code>: The sender&#39;s email address or domain was not verified.</p> </li> <li> <p> <code>ConfigurationSetDoesNotExist</code>: The configuration set you specified does not exist.</p> </li> <li> <p> <code>TemplateDoesNotExist</code>: The template you specified does not exist.</p> </li> <li> <p> <code>AccountSuspended</code>: Your account has been shut down because of issues related to your email sending practices.</p> </li> <li> <p> <code>AccountThrottled</code>: The number of emails you can send has been reduced because your account has exceeded its allocated sending limit.</p> </li> <li> <p> <code>AccountDailyQuotaExceeded</code>: You have reached or exceeded the maximum number of emails you can send from your account in a 24-hour period.</p> </li> <li> <p> <code>InvalidSendingPoolName</code>: The configuration set you specified refers to an IP pool that does not exist.</p> </li> <li> <p> <code>AccountSendingPaused</code>: Email sending for the Amazon SES account was disabled using the <a>UpdateAccountSendingEnabled</a> operation.</p> </li> <li> <p> <code>ConfigurationSetSendingPaused</code>: Email sending for this configuration set was disabled using the <a>UpdateConfigurationSetSendingEnabled</a> operation.</p> </li> <li> <p> <code>InvalidParameterValue</code>: One or more of the parameters you specified when calling this operation was invalid. See the error message for additional information.</p> </li> <li> <p> <code>TransientFailure</code>: Amazon SES was unable to process your request because of a temporary issue.</p> </li> <li> <p> <code>Failed</code>: Amazon SES was unable to process your request. See the error message for additional information.</p> </li> </ul></p>
-    pub status: Option<String>,
+    pub status: Option<BulkEmailStatus>,
 }
 
 struct BulkEmailDestinationStatusDeserializer;
@@ -444,12 +657,170 @@ impl BulkEmailDestinationStatusListDeserializer {
         })
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BulkEmailStatus {
+    /// `Success`
+    Success,
+    /// `MessageRejected`
+    MessageRejected,
+    /// `MailFromDomainNotVerified`
+    MailFromDomainNotVerified,
+    /// `ConfigurationSetDoesNotExist`
+    ConfigurationSetDoesNotExist,
+    /// `TemplateDoesNotExist`
+    TemplateDoesNotExist,
+    /// `AccountSuspended`
+    AccountSuspended,
+    /// `AccountThrottled`
+    AccountThrottled,
+    /// `AccountDailyQuotaExceeded`
+    AccountDailyQuotaExceeded,
+    /// `InvalidSendingPoolName`
+    InvalidSendingPoolName,
+    /// `AccountSendingPaused`
+    AccountSendingPaused,
+    /// `ConfigurationSetSendingPaused`
+    ConfigurationSetSendingPaused,
+    /// `InvalidParameterValue`
+    InvalidParameterValue,
+    /// `TransientFailure`
+    TransientFailure,
+    /// `Failed`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl BulkEmailStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BulkEmailStatus::Success => "Success",
+            BulkEmailStatus::MessageRejected => "MessageRejected",
+            BulkEmailStatus::MailFromDomainNotVerified => "MailFromDomainNotVerified",
+            BulkEmailStatus::ConfigurationSetDoesNotExist => "ConfigurationSetDoesNotExist",
+            BulkEmailStatus::TemplateDoesNotExist => "TemplateDoesNotExist",
+            BulkEmailStatus::AccountSuspended => "AccountSuspended",
+            BulkEmailStatus::AccountThrottled => "AccountThrottled",
+            BulkEmailStatus::AccountDailyQuotaExceeded => "AccountDailyQuotaExceeded",
+            BulkEmailStatus::InvalidSendingPoolName => "InvalidSendingPoolName",
+            BulkEmailStatus::AccountSendingPaused => "AccountSendingPaused",
+            BulkEmailStatus::ConfigurationSetSendingPaused => "ConfigurationSetSendingPaused",
+            BulkEmailStatus::InvalidParameterValue => "InvalidParameterValue",
+            BulkEmailStatus::TransientFailure => "TransientFailure",
+            BulkEmailStatus::Failed => "Failed",
+            BulkEmailStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for BulkEmailStatus {
+    fn default() -> BulkEmailStatus {
+        BulkEmailStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for BulkEmailStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for BulkEmailStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for BulkEmailStatus {
+    fn from(value: &'a str) -> BulkEmailStatus {
+        match value {
+            "Success" => BulkEmailStatus::Success,
+            "MessageRejected" => BulkEmailStatus::MessageRejected,
+            "MailFromDomainNotVerified" => BulkEmailStatus::MailFromDomainNotVerified,
+            "ConfigurationSetDoesNotExist" => BulkEmailStatus::ConfigurationSetDoesNotExist,
+            "TemplateDoesNotExist" => BulkEmailStatus::TemplateDoesNotExist,
+            "AccountSuspended" => BulkEmailStatus::AccountSuspended,
+            "AccountThrottled" => BulkEmailStatus::AccountThrottled,
+            "AccountDailyQuotaExceeded" => BulkEmailStatus::AccountDailyQuotaExceeded,
+            "InvalidSendingPoolName" => BulkEmailStatus::InvalidSendingPoolName,
+            "AccountSendingPaused" => BulkEmailStatus::AccountSendingPaused,
+            "ConfigurationSetSendingPaused" => BulkEmailStatus::ConfigurationSetSendingPaused,
+            "InvalidParameterValue" => BulkEmailStatus::InvalidParameterValue,
+            "TransientFailure" => BulkEmailStatus::TransientFailure,
+            "Failed" => BulkEmailStatus::Failed,
+            _ => BulkEmailStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BulkEmailStatus {
+    fn from(value: String) -> BulkEmailStatus {
+        match value.as_str() {
+            "Success" => BulkEmailStatus::Success,
+            "MessageRejected" => BulkEmailStatus::MessageRejected,
+            "MailFromDomainNotVerified" => BulkEmailStatus::MailFromDomainNotVerified,
+            "ConfigurationSetDoesNotExist" => BulkEmailStatus::ConfigurationSetDoesNotExist,
+            "TemplateDoesNotExist" => BulkEmailStatus::TemplateDoesNotExist,
+            "AccountSuspended" => BulkEmailStatus::AccountSuspended,
+            "AccountThrottled" => BulkEmailStatus::AccountThrottled,
+            "AccountDailyQuotaExceeded" => BulkEmailStatus::AccountDailyQuotaExceeded,
+            "InvalidSendingPoolName" => BulkEmailStatus::InvalidSendingPoolName,
+            "AccountSendingPaused" => BulkEmailStatus::AccountSendingPaused,
+            "ConfigurationSetSendingPaused" => BulkEmailStatus::ConfigurationSetSendingPaused,
+            "InvalidParameterValue" => BulkEmailStatus::InvalidParameterValue,
+            "TransientFailure" => BulkEmailStatus::TransientFailure,
+            "Failed" => BulkEmailStatus::Failed,
+            _ => BulkEmailStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for BulkEmailStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<BulkEmailStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for BulkEmailStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for BulkEmailStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for BulkEmailStatus {
+    fn deserialize<D>(deserializer: D) -> Result<BulkEmailStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct BulkEmailStatusDeserializer;
 impl BulkEmailStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<BulkEmailStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -574,7 +945,7 @@ pub struct CloudWatchDimensionConfiguration {
     /// <p><p>The name of an Amazon CloudWatch dimension associated with an email sending metric. The name must:</p> <ul> <li> <p>This value can only contain ASCII letters (a-z, A-Z), numbers (0-9), underscores (_), or dashes (-).</p> </li> <li> <p>Contain less than 256 characters.</p> </li> </ul></p>
     pub dimension_name: String,
     /// <p>The place where Amazon SES finds the value of a dimension to publish to Amazon CloudWatch. If you want Amazon SES to use the message tags that you specify using an <code>X-SES-MESSAGE-TAGS</code> header or a parameter to the <code>SendEmail</code>/<code>SendRawEmail</code> API, choose <code>messageTag</code>. If you want Amazon SES to use your own email headers, choose <code>emailHeader</code>.</p>
-    pub dimension_value_source: String,
+    pub dimension_value_source: DimensionValueSource,
 }
 
 struct CloudWatchDimensionConfigurationDeserializer;
@@ -710,10 +1081,116 @@ impl ConfigurationSetSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ConfigurationSetAttribute {
+    /// `eventDestinations`
+    EventDestinations,
+    /// `trackingOptions`
+    TrackingOptions,
+    /// `deliveryOptions`
+    DeliveryOptions,
+    /// `reputationOptions`
+    ReputationOptions,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ConfigurationSetAttribute {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ConfigurationSetAttribute::EventDestinations => "eventDestinations",
+            ConfigurationSetAttribute::TrackingOptions => "trackingOptions",
+            ConfigurationSetAttribute::DeliveryOptions => "deliveryOptions",
+            ConfigurationSetAttribute::ReputationOptions => "reputationOptions",
+            ConfigurationSetAttribute::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ConfigurationSetAttribute {
+    fn default() -> ConfigurationSetAttribute {
+        ConfigurationSetAttribute::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ConfigurationSetAttribute {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ConfigurationSetAttribute {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ConfigurationSetAttribute {
+    fn from(value: &'a str) -> ConfigurationSetAttribute {
+        match value {
+            "eventDestinations" => ConfigurationSetAttribute::EventDestinations,
+            "trackingOptions" => ConfigurationSetAttribute::TrackingOptions,
+            "deliveryOptions" => ConfigurationSetAttribute::DeliveryOptions,
+            "reputationOptions" => ConfigurationSetAttribute::ReputationOptions,
+            _ => ConfigurationSetAttribute::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ConfigurationSetAttribute {
+    fn from(value: String) -> ConfigurationSetAttribute {
+        match value.as_str() {
+            "eventDestinations" => ConfigurationSetAttribute::EventDestinations,
+            "trackingOptions" => ConfigurationSetAttribute::TrackingOptions,
+            "deliveryOptions" => ConfigurationSetAttribute::DeliveryOptions,
+            "reputationOptions" => ConfigurationSetAttribute::ReputationOptions,
+            _ => ConfigurationSetAttribute::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ConfigurationSetAttribute {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ConfigurationSetAttribute, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ConfigurationSetAttribute {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ConfigurationSetAttribute {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ConfigurationSetAttribute {
+    fn deserialize<D>(deserializer: D) -> Result<ConfigurationSetAttribute, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+
 /// Serialize `ConfigurationSetAttributeList` contents to a `SignedRequest`.
 struct ConfigurationSetAttributeListSerializer;
 impl ConfigurationSetAttributeListSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &Vec<String>) {
+    fn serialize(params: &mut Params, name: &str, obj: &Vec<ConfigurationSetAttribute>) {
         for (index, obj) in obj.iter().enumerate() {
             let key = format!("{}.member.{}", name, index + 1);
             params.put(&key, &obj);
@@ -1173,12 +1650,120 @@ impl CreateTemplateResponseDeserializer {
         Ok(obj)
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CustomMailFromStatus {
+    /// `Pending`
+    Pending,
+    /// `Success`
+    Success,
+    /// `Failed`
+    Failed,
+    /// `TemporaryFailure`
+    TemporaryFailure,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CustomMailFromStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CustomMailFromStatus::Pending => "Pending",
+            CustomMailFromStatus::Success => "Success",
+            CustomMailFromStatus::Failed => "Failed",
+            CustomMailFromStatus::TemporaryFailure => "TemporaryFailure",
+            CustomMailFromStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CustomMailFromStatus {
+    fn default() -> CustomMailFromStatus {
+        CustomMailFromStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CustomMailFromStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CustomMailFromStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CustomMailFromStatus {
+    fn from(value: &'a str) -> CustomMailFromStatus {
+        match value {
+            "Pending" => CustomMailFromStatus::Pending,
+            "Success" => CustomMailFromStatus::Success,
+            "Failed" => CustomMailFromStatus::Failed,
+            "TemporaryFailure" => CustomMailFromStatus::TemporaryFailure,
+            _ => CustomMailFromStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CustomMailFromStatus {
+    fn from(value: String) -> CustomMailFromStatus {
+        match value.as_str() {
+            "Pending" => CustomMailFromStatus::Pending,
+            "Success" => CustomMailFromStatus::Success,
+            "Failed" => CustomMailFromStatus::Failed,
+            "TemporaryFailure" => CustomMailFromStatus::TemporaryFailure,
+            _ => CustomMailFromStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CustomMailFromStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CustomMailFromStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CustomMailFromStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for CustomMailFromStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for CustomMailFromStatus {
+    fn deserialize<D>(deserializer: D) -> Result<CustomMailFromStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct CustomMailFromStatusDeserializer;
 impl CustomMailFromStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<CustomMailFromStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -1748,7 +2333,7 @@ impl DeleteVerifiedEmailAddressRequestSerializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct DeliveryOptions {
     /// <p>Specifies whether messages that use the configuration set are required to use Transport Layer Security (TLS). If the value is <code>Require</code>, messages are only delivered if a TLS connection can be established. If the value is <code>Optional</code>, messages can be delivered in plain text if a TLS connection can't be established.</p>
-    pub tls_policy: Option<String>,
+    pub tls_policy: Option<TlsPolicy>,
 }
 
 struct DeliveryOptionsDeserializer;
@@ -1845,7 +2430,7 @@ impl DescribeActiveReceiptRuleSetResponseDeserializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct DescribeConfigurationSetRequest {
     /// <p>A list of configuration set attributes to return.</p>
-    pub configuration_set_attribute_names: Option<Vec<String>>,
+    pub configuration_set_attribute_names: Option<Vec<ConfigurationSetAttribute>>,
     /// <p>The name of the configuration set to describe.</p>
     pub configuration_set_name: String,
 }
@@ -2106,12 +2691,115 @@ impl DimensionNameDeserializer {
         Ok(obj)
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DimensionValueSource {
+    /// `messageTag`
+    MessageTag,
+    /// `emailHeader`
+    EmailHeader,
+    /// `linkTag`
+    LinkTag,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DimensionValueSource {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DimensionValueSource::MessageTag => "messageTag",
+            DimensionValueSource::EmailHeader => "emailHeader",
+            DimensionValueSource::LinkTag => "linkTag",
+            DimensionValueSource::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DimensionValueSource {
+    fn default() -> DimensionValueSource {
+        DimensionValueSource::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DimensionValueSource {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DimensionValueSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DimensionValueSource {
+    fn from(value: &'a str) -> DimensionValueSource {
+        match value {
+            "messageTag" => DimensionValueSource::MessageTag,
+            "emailHeader" => DimensionValueSource::EmailHeader,
+            "linkTag" => DimensionValueSource::LinkTag,
+            _ => DimensionValueSource::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DimensionValueSource {
+    fn from(value: String) -> DimensionValueSource {
+        match value.as_str() {
+            "messageTag" => DimensionValueSource::MessageTag,
+            "emailHeader" => DimensionValueSource::EmailHeader,
+            "linkTag" => DimensionValueSource::LinkTag,
+            _ => DimensionValueSource::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DimensionValueSource {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DimensionValueSource, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DimensionValueSource {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for DimensionValueSource {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for DimensionValueSource {
+    fn deserialize<D>(deserializer: D) -> Result<DimensionValueSource, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct DimensionValueSourceDeserializer;
 impl DimensionValueSourceDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<DimensionValueSource, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -2140,6 +2828,116 @@ impl DkimAttributesDeserializer {
         Ok(obj)
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DsnAction {
+    /// `failed`
+    Failed,
+    /// `delayed`
+    Delayed,
+    /// `delivered`
+    Delivered,
+    /// `relayed`
+    Relayed,
+    /// `expanded`
+    Expanded,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DsnAction {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DsnAction::Failed => "failed",
+            DsnAction::Delayed => "delayed",
+            DsnAction::Delivered => "delivered",
+            DsnAction::Relayed => "relayed",
+            DsnAction::Expanded => "expanded",
+            DsnAction::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DsnAction {
+    fn default() -> DsnAction {
+        DsnAction::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DsnAction {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DsnAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DsnAction {
+    fn from(value: &'a str) -> DsnAction {
+        match value {
+            "failed" => DsnAction::Failed,
+            "delayed" => DsnAction::Delayed,
+            "delivered" => DsnAction::Delivered,
+            "relayed" => DsnAction::Relayed,
+            "expanded" => DsnAction::Expanded,
+            _ => DsnAction::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DsnAction {
+    fn from(value: String) -> DsnAction {
+        match value.as_str() {
+            "failed" => DsnAction::Failed,
+            "delayed" => DsnAction::Delayed,
+            "delivered" => DsnAction::Delivered,
+            "relayed" => DsnAction::Relayed,
+            "expanded" => DsnAction::Expanded,
+            _ => DsnAction::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DsnAction {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DsnAction, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DsnAction {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for DsnAction {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for DsnAction {
+    fn deserialize<D>(deserializer: D) -> Result<DsnAction, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct EnabledDeserializer;
 impl EnabledDeserializer {
     #[allow(unused_variables)]
@@ -2174,7 +2972,7 @@ pub struct EventDestination {
     /// <p>An object that contains the delivery stream ARN and the IAM role ARN associated with an Amazon Kinesis Firehose event destination.</p>
     pub kinesis_firehose_destination: Option<KinesisFirehoseDestination>,
     /// <p>The type of email sending events to publish to the event destination.</p>
-    pub matching_event_types: Vec<String>,
+    pub matching_event_types: Vec<EventType>,
     /// <p><p>The name of the event destination. The name must:</p> <ul> <li> <p>This value can only contain ASCII letters (a-z, A-Z), numbers (0-9), underscores (_), or dashes (-).</p> </li> <li> <p>Contain less than 64 characters.</p> </li> </ul></p>
     pub name: String,
     /// <p>An object that contains the topic ARN associated with an Amazon Simple Notification Service (Amazon SNS) event destination.</p>
@@ -2272,40 +3070,168 @@ impl EventDestinationSerializer {
     }
 }
 
-struct EventDestinationNameDeserializer;
-impl EventDestinationNameDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
-        start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
-        end_element(tag_name, stack)?;
+struct EventDestinationNameDeserializer;
+impl EventDestinationNameDeserializer {
+    #[allow(unused_variables)]
+    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+        start_element(tag_name, stack)?;
+        let obj = characters(stack)?;
+        end_element(tag_name, stack)?;
+
+        Ok(obj)
+    }
+}
+struct EventDestinationsDeserializer;
+impl EventDestinationsDeserializer {
+    #[allow(unused_variables)]
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Vec<EventDestination>, XmlParseError> {
+        deserialize_elements::<_, Vec<_>, _>(tag_name, stack, |name, stack, obj| {
+            if name == "member" {
+                obj.push(EventDestinationDeserializer::deserialize("member", stack)?);
+            } else {
+                skip_tree(stack);
+            }
+            Ok(())
+        })
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EventType {
+    /// `send`
+    Send,
+    /// `reject`
+    Reject,
+    /// `bounce`
+    Bounce,
+    /// `complaint`
+    Complaint,
+    /// `delivery`
+    Delivery,
+    /// `open`
+    Open,
+    /// `click`
+    Click,
+    /// `renderingFailure`
+    RenderingFailure,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EventType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EventType::Send => "send",
+            EventType::Reject => "reject",
+            EventType::Bounce => "bounce",
+            EventType::Complaint => "complaint",
+            EventType::Delivery => "delivery",
+            EventType::Open => "open",
+            EventType::Click => "click",
+            EventType::RenderingFailure => "renderingFailure",
+            EventType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EventType {
+    fn default() -> EventType {
+        EventType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EventType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EventType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EventType {
+    fn from(value: &'a str) -> EventType {
+        match value {
+            "send" => EventType::Send,
+            "reject" => EventType::Reject,
+            "bounce" => EventType::Bounce,
+            "complaint" => EventType::Complaint,
+            "delivery" => EventType::Delivery,
+            "open" => EventType::Open,
+            "click" => EventType::Click,
+            "renderingFailure" => EventType::RenderingFailure,
+            _ => EventType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EventType {
+    fn from(value: String) -> EventType {
+        match value.as_str() {
+            "send" => EventType::Send,
+            "reject" => EventType::Reject,
+            "bounce" => EventType::Bounce,
+            "complaint" => EventType::Complaint,
+            "delivery" => EventType::Delivery,
+            "open" => EventType::Open,
+            "click" => EventType::Click,
+            "renderingFailure" => EventType::RenderingFailure,
+            _ => EventType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EventType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EventType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EventType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
 
-        Ok(obj)
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for EventType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
     }
 }
-struct EventDestinationsDeserializer;
-impl EventDestinationsDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
-        tag_name: &str,
-        stack: &mut T,
-    ) -> Result<Vec<EventDestination>, XmlParseError> {
-        deserialize_elements::<_, Vec<_>, _>(tag_name, stack, |name, stack, obj| {
-            if name == "member" {
-                obj.push(EventDestinationDeserializer::deserialize("member", stack)?);
-            } else {
-                skip_tree(stack);
-            }
-            Ok(())
-        })
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for EventType {
+    fn deserialize<D>(deserializer: D) -> Result<EventType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
     }
 }
 struct EventTypeDeserializer;
 impl EventTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<EventType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -2317,7 +3243,7 @@ impl EventTypesDeserializer {
     fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
-    ) -> Result<Vec<String>, XmlParseError> {
+    ) -> Result<Vec<EventType>, XmlParseError> {
         deserialize_elements::<_, Vec<_>, _>(tag_name, stack, |name, stack, obj| {
             if name == "member" {
                 obj.push(EventTypeDeserializer::deserialize("member", stack)?);
@@ -2332,7 +3258,7 @@ impl EventTypesDeserializer {
 /// Serialize `EventTypes` contents to a `SignedRequest`.
 struct EventTypesSerializer;
 impl EventTypesSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &Vec<String>) {
+    fn serialize(params: &mut Params, name: &str, obj: &Vec<EventType>) {
         for (index, obj) in obj.iter().enumerate() {
             let key = format!("{}.member.{}", name, index + 1);
             params.put(&key, &obj);
@@ -2991,7 +3917,7 @@ pub struct IdentityDkimAttributes {
     /// <p>A set of character strings that represent the domain's identity. Using these tokens, you need to create DNS CNAME records that point to DKIM public keys that are hosted by Amazon SES. Amazon Web Services eventually detects that you've updated your DNS records. This detection process might take up to 72 hours. After successful detection, Amazon SES is able to DKIM-sign email originating from that domain. (This only applies to domain identities, not email address identities.)</p> <p>For more information about creating DNS records using DKIM tokens, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/easy-dkim.html">Amazon SES Developer Guide</a>.</p>
     pub dkim_tokens: Option<Vec<String>>,
     /// <p>Describes whether Amazon SES has successfully verified the DKIM DNS records (tokens) published in the domain name's DNS. (This only applies to domain identities, not email address identities.)</p>
-    pub dkim_verification_status: String,
+    pub dkim_verification_status: VerificationStatus,
 }
 
 struct IdentityDkimAttributesDeserializer;
@@ -3057,11 +3983,11 @@ impl IdentityListSerializer {
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct IdentityMailFromDomainAttributes {
     /// <p>The action that Amazon SES takes if it cannot successfully read the required MX record when you send an email. A value of <code>UseDefaultValue</code> indicates that if Amazon SES cannot read the required MX record, it uses amazonses.com (or a subdomain of that) as the MAIL FROM domain. A value of <code>RejectMessage</code> indicates that if Amazon SES cannot read the required MX record, Amazon SES returns a <code>MailFromDomainNotVerified</code> error and does not send the email.</p> <p>The custom MAIL FROM setup states that result in this behavior are <code>Pending</code>, <code>Failed</code>, and <code>TemporaryFailure</code>.</p>
-    pub behavior_on_mx_failure: String,
+    pub behavior_on_mx_failure: BehaviorOnMXFailure,
     /// <p>The custom MAIL FROM domain that the identity is configured to use.</p>
     pub mail_from_domain: String,
     /// <p>The state that indicates whether Amazon SES has successfully read the MX record required for custom MAIL FROM domain setup. If the state is <code>Success</code>, Amazon SES uses the specified custom MAIL FROM domain when the verified identity sends an email. All other states indicate that Amazon SES takes the action described by <code>BehaviorOnMXFailure</code>.</p>
-    pub mail_from_domain_status: String,
+    pub mail_from_domain_status: CustomMailFromStatus,
 }
 
 struct IdentityMailFromDomainAttributesDeserializer;
@@ -3176,12 +4102,107 @@ impl IdentityNotificationAttributesDeserializer {
         )
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum IdentityType {
+    /// `EmailAddress`
+    EmailAddress,
+    /// `Domain`
+    Domain,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl IdentityType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            IdentityType::EmailAddress => "EmailAddress",
+            IdentityType::Domain => "Domain",
+            IdentityType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for IdentityType {
+    fn default() -> IdentityType {
+        IdentityType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for IdentityType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for IdentityType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for IdentityType {
+    fn from(value: &'a str) -> IdentityType {
+        match value {
+            "EmailAddress" => IdentityType::EmailAddress,
+            "Domain" => IdentityType::Domain,
+            _ => IdentityType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for IdentityType {
+    fn from(value: String) -> IdentityType {
+        match value.as_str() {
+            "EmailAddress" => IdentityType::EmailAddress,
+            "Domain" => IdentityType::Domain,
+            _ => IdentityType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for IdentityType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<IdentityType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for IdentityType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for IdentityType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for IdentityType {
+    fn deserialize<D>(deserializer: D) -> Result<IdentityType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents the verification attributes of a single identity.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct IdentityVerificationAttributes {
     /// <p>The verification status of the identity: "Pending", "Success", "Failed", or "TemporaryFailure".</p>
-    pub verification_status: String,
+    pub verification_status: VerificationStatus,
     /// <p>The verification token for a domain identity. Null for email address identities.</p>
     pub verification_token: Option<String>,
 }
@@ -3217,12 +4238,110 @@ impl IdentityVerificationAttributesDeserializer {
         )
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum InvocationType {
+    /// `Event`
+    Event,
+    /// `RequestResponse`
+    RequestResponse,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl InvocationType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InvocationType::Event => "Event",
+            InvocationType::RequestResponse => "RequestResponse",
+            InvocationType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for InvocationType {
+    fn default() -> InvocationType {
+        InvocationType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for InvocationType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for InvocationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for InvocationType {
+    fn from(value: &'a str) -> InvocationType {
+        match value {
+            "Event" => InvocationType::Event,
+            "RequestResponse" => InvocationType::RequestResponse,
+            _ => InvocationType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InvocationType {
+    fn from(value: String) -> InvocationType {
+        match value.as_str() {
+            "Event" => InvocationType::Event,
+            "RequestResponse" => InvocationType::RequestResponse,
+            _ => InvocationType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for InvocationType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<InvocationType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for InvocationType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for InvocationType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for InvocationType {
+    fn deserialize<D>(deserializer: D) -> Result<InvocationType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct InvocationTypeDeserializer;
 impl InvocationTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<InvocationType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -3294,7 +4413,7 @@ pub struct LambdaAction {
     /// <p>The Amazon Resource Name (ARN) of the AWS Lambda function. An example of an AWS Lambda function ARN is <code>arn:aws:lambda:us-west-2:account-id:function:MyFunction</code>. For more information about AWS Lambda, see the <a href="https://docs.aws.amazon.com/lambda/latest/dg/welcome.html">AWS Lambda Developer Guide</a>.</p>
     pub function_arn: String,
     /// <p><p>The invocation type of the AWS Lambda function. An invocation type of <code>RequestResponse</code> means that the execution of the function will immediately result in a response, and a value of <code>Event</code> means that the function will be invoked asynchronously. The default value is <code>Event</code>. For information about AWS Lambda invocation types, see the <a href="https://docs.aws.amazon.com/lambda/latest/dg/API_Invoke.html">AWS Lambda Developer Guide</a>.</p> <important> <p>There is a 30-second timeout on <code>RequestResponse</code> invocations. You should use <code>Event</code> invocation in most cases. Use <code>RequestResponse</code> only when you want to make a mail flow decision, such as whether to stop the receipt rule or the receipt rule set.</p> </important></p>
-    pub invocation_type: Option<String>,
+    pub invocation_type: Option<InvocationType>,
     /// <p>The Amazon Resource Name (ARN) of the Amazon SNS topic to notify when the Lambda action is taken. An example of an Amazon SNS topic ARN is <code>arn:aws:sns:us-west-2:123456789012:MyTopic</code>. For more information about Amazon SNS topics, see the <a href="https://docs.aws.amazon.com/sns/latest/dg/CreateTopic.html">Amazon SNS Developer Guide</a>.</p>
     pub topic_arn: Option<String>,
 }
@@ -3352,9 +4471,12 @@ impl LambdaActionSerializer {
 struct LastFreshStartDeserializer;
 impl LastFreshStartDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<::chrono::DateTime<::chrono::Utc>, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::proto::xml::util::timestamp_characters(stack)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -3504,7 +4626,7 @@ impl ListCustomVerificationEmailTemplatesResponseDeserializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ListIdentitiesRequest {
     /// <p>The type of the identities to list. Possible values are "EmailAddress" and "Domain". If this parameter is omitted, then all identities will be listed.</p>
-    pub identity_type: Option<String>,
+    pub identity_type: Option<IdentityType>,
     /// <p>The maximum number of identities per page. Possible values are 1-1000 inclusive.</p>
     pub max_items: Option<i64>,
     /// <p>The token to use for pagination.</p>
@@ -3905,7 +5027,7 @@ impl MessageSerializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct MessageDsn {
     /// <p>When the message was received by the reporting mail transfer agent (MTA), in <a href="https://www.ietf.org/rfc/rfc0822.txt">RFC 822</a> date-time format.</p>
-    pub arrival_date: Option<String>,
+    pub arrival_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Additional X-headers to include in the DSN.</p>
     pub extension_fields: Option<Vec<ExtensionField>>,
     /// <p>The reporting MTA that attempted to deliver the message, formatted as specified in <a href="https://tools.ietf.org/html/rfc3464">RFC 3464</a> (<code>mta-name-type; mta-name</code>). The default value is <code>dns; inbound-smtp.[region].amazonaws.com</code>.</p>
@@ -3922,7 +5044,10 @@ impl MessageDsnSerializer {
         }
 
         if let Some(ref field_value) = obj.arrival_date {
-            params.put(&format!("{}{}", prefix, "ArrivalDate"), &field_value);
+            params.put(
+                &format!("{}{}", prefix, "ArrivalDate"),
+                &rusoto_core::serialization::TimestampFormat::Iso8601.format(field_value),
+            );
         }
         if let Some(ref field_value) = obj.extension_fields {
             ExtensionFieldListSerializer::serialize(
@@ -4027,6 +5152,106 @@ impl NotificationTopicDeserializer {
         Ok(obj)
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum NotificationType {
+    /// `Bounce`
+    Bounce,
+    /// `Complaint`
+    Complaint,
+    /// `Delivery`
+    Delivery,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl NotificationType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            NotificationType::Bounce => "Bounce",
+            NotificationType::Complaint => "Complaint",
+            NotificationType::Delivery => "Delivery",
+            NotificationType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for NotificationType {
+    fn default() -> NotificationType {
+        NotificationType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for NotificationType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for NotificationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for NotificationType {
+    fn from(value: &'a str) -> NotificationType {
+        match value {
+            "Bounce" => NotificationType::Bounce,
+            "Complaint" => NotificationType::Complaint,
+            "Delivery" => NotificationType::Delivery,
+            _ => NotificationType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for NotificationType {
+    fn from(value: String) -> NotificationType {
+        match value.as_str() {
+            "Bounce" => NotificationType::Bounce,
+            "Complaint" => NotificationType::Complaint,
+            "Delivery" => NotificationType::Delivery,
+            _ => NotificationType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for NotificationType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<NotificationType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for NotificationType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for NotificationType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for NotificationType {
+    fn deserialize<D>(deserializer: D) -> Result<NotificationType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct PolicyDeserializer;
 impl PolicyDeserializer {
     #[allow(unused_variables)]
@@ -4467,12 +5692,110 @@ impl ReceiptFilterNameDeserializer {
         Ok(obj)
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ReceiptFilterPolicy {
+    /// `Block`
+    Block,
+    /// `Allow`
+    Allow,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ReceiptFilterPolicy {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReceiptFilterPolicy::Block => "Block",
+            ReceiptFilterPolicy::Allow => "Allow",
+            ReceiptFilterPolicy::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ReceiptFilterPolicy {
+    fn default() -> ReceiptFilterPolicy {
+        ReceiptFilterPolicy::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ReceiptFilterPolicy {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ReceiptFilterPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ReceiptFilterPolicy {
+    fn from(value: &'a str) -> ReceiptFilterPolicy {
+        match value {
+            "Block" => ReceiptFilterPolicy::Block,
+            "Allow" => ReceiptFilterPolicy::Allow,
+            _ => ReceiptFilterPolicy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReceiptFilterPolicy {
+    fn from(value: String) -> ReceiptFilterPolicy {
+        match value.as_str() {
+            "Block" => ReceiptFilterPolicy::Block,
+            "Allow" => ReceiptFilterPolicy::Allow,
+            _ => ReceiptFilterPolicy::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ReceiptFilterPolicy {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ReceiptFilterPolicy, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ReceiptFilterPolicy {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for ReceiptFilterPolicy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for ReceiptFilterPolicy {
+    fn deserialize<D>(deserializer: D) -> Result<ReceiptFilterPolicy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct ReceiptFilterPolicyDeserializer;
 impl ReceiptFilterPolicyDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ReceiptFilterPolicy, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -4486,7 +5809,7 @@ pub struct ReceiptIpFilter {
     /// <p>A single IP address or a range of IP addresses that you want to block or allow, specified in Classless Inter-Domain Routing (CIDR) notation. An example of a single email address is 10.0.0.1. An example of a range of IP addresses is 10.0.0.1/24. For more information about CIDR notation, see <a href="https://tools.ietf.org/html/rfc2317">RFC 2317</a>.</p>
     pub cidr: String,
     /// <p>Indicates whether to block or allow incoming mail from the specified IP addresses.</p>
-    pub policy: String,
+    pub policy: ReceiptFilterPolicy,
 }
 
 struct ReceiptIpFilterDeserializer;
@@ -4541,7 +5864,7 @@ pub struct ReceiptRule {
     /// <p>If <code>true</code>, then messages that this receipt rule applies to are scanned for spam and viruses. The default value is <code>false</code>.</p>
     pub scan_enabled: Option<bool>,
     /// <p>Specifies whether Amazon SES should require that incoming email is delivered over a connection encrypted with Transport Layer Security (TLS). If this parameter is set to <code>Require</code>, Amazon SES will bounce emails that are not received over TLS. The default is <code>Optional</code>.</p>
-    pub tls_policy: Option<String>,
+    pub tls_policy: Option<TlsPolicy>,
 }
 
 struct ReceiptRuleDeserializer;
@@ -4647,7 +5970,7 @@ impl ReceiptRuleNamesListSerializer {
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct ReceiptRuleSetMetadata {
     /// <p>The date and time the receipt rule set was created.</p>
-    pub created_timestamp: Option<String>,
+    pub created_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>The name of the receipt rule set. The name must:</p> <ul> <li> <p>This value can only contain ASCII letters (a-z, A-Z), numbers (0-9), underscores (_), or dashes (-).</p> </li> <li> <p>Start and end with a letter or number.</p> </li> <li> <p>Contain less than 64 characters.</p> </li> </ul></p>
     pub name: Option<String>,
 }
@@ -4739,7 +6062,7 @@ impl RecipientDeserializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct RecipientDsnFields {
     /// <p>The action performed by the reporting mail transfer agent (MTA) as a result of its attempt to deliver the message to the recipient address. This is required by <a href="https://tools.ietf.org/html/rfc3464">RFC 3464</a>.</p>
-    pub action: String,
+    pub action: DsnAction,
     /// <p>An extended explanation of what went wrong; this is usually an SMTP response. See <a href="https://tools.ietf.org/html/rfc3463">RFC 3463</a> for the correct formatting of this parameter.</p>
     pub diagnostic_code: Option<String>,
     /// <p>Additional X-headers to include in the DSN.</p>
@@ -4747,7 +6070,7 @@ pub struct RecipientDsnFields {
     /// <p><p>The email address that the message was ultimately delivered to. This corresponds to the <code>Final-Recipient</code> in the DSN. If not specified, <code>FinalRecipient</code> will be set to the <code>Recipient</code> specified in the <code>BouncedRecipientInfo</code> structure. Either <code>FinalRecipient</code> or the recipient in <code>BouncedRecipientInfo</code> must be a recipient of the original bounced message.</p> <note> <p>Do not prepend the <code>FinalRecipient</code> email address with <code>rfc 822;</code>, as described in <a href="https://tools.ietf.org/html/rfc3798">RFC 3798</a>.</p> </note></p>
     pub final_recipient: Option<String>,
     /// <p>The time the final delivery attempt was made, in <a href="https://www.ietf.org/rfc/rfc0822.txt">RFC 822</a> date-time format.</p>
-    pub last_attempt_date: Option<String>,
+    pub last_attempt_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The MTA to which the remote MTA attempted to deliver the message, formatted as specified in <a href="https://tools.ietf.org/html/rfc3464">RFC 3464</a> (<code>mta-name-type; mta-name</code>). This parameter typically applies only to propagating synchronous bounces.</p>
     pub remote_mta: Option<String>,
     /// <p>The status code that indicates what went wrong. This is required by <a href="https://tools.ietf.org/html/rfc3464">RFC 3464</a>.</p>
@@ -4778,7 +6101,10 @@ impl RecipientDsnFieldsSerializer {
             params.put(&format!("{}{}", prefix, "FinalRecipient"), &field_value);
         }
         if let Some(ref field_value) = obj.last_attempt_date {
-            params.put(&format!("{}{}", prefix, "LastAttemptDate"), &field_value);
+            params.put(
+                &format!("{}{}", prefix, "LastAttemptDate"),
+                &rusoto_core::serialization::TimestampFormat::Iso8601.format(field_value),
+            );
         }
         if let Some(ref field_value) = obj.remote_mta {
             params.put(&format!("{}{}", prefix, "RemoteMta"), &field_value);
@@ -4881,7 +6207,7 @@ impl ReorderReceiptRuleSetResponseDeserializer {
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct ReputationOptions {
     /// <p>The date and time at which the reputation metrics for the configuration set were last reset. Resetting these metrics is known as a <i>fresh start</i>.</p> <p>When you disable email sending for a configuration set using <a>UpdateConfigurationSetSendingEnabled</a> and later re-enable it, the reputation metrics for the configuration set (but not for the entire Amazon SES account) are reset.</p> <p>If email sending for the configuration set has never been disabled and later re-enabled, the value of this attribute is <code>null</code>.</p>
-    pub last_fresh_start: Option<String>,
+    pub last_fresh_start: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Describes whether or not Amazon SES publishes reputation metrics for the configuration set, such as bounce and complaint rates, to Amazon CloudWatch.</p> <p>If the value is <code>true</code>, reputation metrics are published. If the value is <code>false</code>, reputation metrics are not published. The default value is <code>false</code>.</p>
     pub reputation_metrics_enabled: Option<bool>,
     /// <p>Describes whether email sending is enabled or disabled for the configuration set. If the value is <code>true</code>, then Amazon SES will send emails that use the configuration set. If the value is <code>false</code>, Amazon SES will not send emails that use the configuration set. The default value is <code>true</code>. You can change this setting using <a>UpdateConfigurationSetSendingEnabled</a>.</p>
@@ -5020,7 +6346,7 @@ impl S3KeyPrefixDeserializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct SNSAction {
     /// <p>The encoding to use for the email within the Amazon SNS notification. UTF-8 is easier to use, but may not preserve all special characters when a message was encoded with a different encoding format. Base64 preserves all special characters. The default value is UTF-8.</p>
-    pub encoding: Option<String>,
+    pub encoding: Option<SNSActionEncoding>,
     /// <p>The Amazon Resource Name (ARN) of the Amazon SNS topic to notify. An example of an Amazon SNS topic ARN is <code>arn:aws:sns:us-west-2:123456789012:MyTopic</code>. For more information about Amazon SNS topics, see the <a href="https://docs.aws.amazon.com/sns/latest/dg/CreateTopic.html">Amazon SNS Developer Guide</a>.</p>
     pub topic_arn: String,
 }
@@ -5049,28 +6375,126 @@ impl SNSActionDeserializer {
     }
 }
 
-/// Serialize `SNSAction` contents to a `SignedRequest`.
-struct SNSActionSerializer;
-impl SNSActionSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &SNSAction) {
-        let mut prefix = name.to_string();
-        if prefix != "" {
-            prefix.push_str(".");
+/// Serialize `SNSAction` contents to a `SignedRequest`.
+struct SNSActionSerializer;
+impl SNSActionSerializer {
+    fn serialize(params: &mut Params, name: &str, obj: &SNSAction) {
+        let mut prefix = name.to_string();
+        if prefix != "" {
+            prefix.push_str(".");
+        }
+
+        if let Some(ref field_value) = obj.encoding {
+            params.put(&format!("{}{}", prefix, "Encoding"), &field_value);
+        }
+        params.put(&format!("{}{}", prefix, "TopicArn"), &obj.topic_arn);
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SNSActionEncoding {
+    /// `UTF-8`
+    Utf8,
+    /// `Base64`
+    Base64,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SNSActionEncoding {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SNSActionEncoding::Utf8 => "UTF-8",
+            SNSActionEncoding::Base64 => "Base64",
+            SNSActionEncoding::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SNSActionEncoding {
+    fn default() -> SNSActionEncoding {
+        SNSActionEncoding::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SNSActionEncoding {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SNSActionEncoding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SNSActionEncoding {
+    fn from(value: &'a str) -> SNSActionEncoding {
+        match value {
+            "UTF-8" => SNSActionEncoding::Utf8,
+            "Base64" => SNSActionEncoding::Base64,
+            _ => SNSActionEncoding::Unknown(value.to_owned()),
         }
+    }
+}
 
-        if let Some(ref field_value) = obj.encoding {
-            params.put(&format!("{}{}", prefix, "Encoding"), &field_value);
+impl From<String> for SNSActionEncoding {
+    fn from(value: String) -> SNSActionEncoding {
+        match value.as_str() {
+            "UTF-8" => SNSActionEncoding::Utf8,
+            "Base64" => SNSActionEncoding::Base64,
+            _ => SNSActionEncoding::Unknown(value),
         }
-        params.put(&format!("{}{}", prefix, "TopicArn"), &obj.topic_arn);
     }
 }
 
+impl ::std::str::FromStr for SNSActionEncoding {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SNSActionEncoding, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SNSActionEncoding {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for SNSActionEncoding {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for SNSActionEncoding {
+    fn deserialize<D>(deserializer: D) -> Result<SNSActionEncoding, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct SNSActionEncodingDeserializer;
 impl SNSActionEncodingDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<SNSActionEncoding, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -5389,7 +6813,7 @@ pub struct SendDataPoint {
     /// <p>Number of emails rejected by Amazon SES.</p>
     pub rejects: Option<i64>,
     /// <p>Time of the data point.</p>
-    pub timestamp: Option<String>,
+    pub timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 struct SendDataPointDeserializer;
@@ -5905,7 +7329,7 @@ pub struct SetIdentityHeadersInNotificationsEnabledRequest {
     /// <p>The identity for which to enable or disable headers in notifications. Examples: <code>user@example.com</code>, <code>example.com</code>.</p>
     pub identity: String,
     /// <p>The notification type for which to enable or disable headers in notifications. </p>
-    pub notification_type: String,
+    pub notification_type: NotificationType,
 }
 
 /// Serialize `SetIdentityHeadersInNotificationsEnabledRequest` contents to a `SignedRequest`.
@@ -5956,7 +7380,7 @@ impl SetIdentityHeadersInNotificationsEnabledResponseDeserializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct SetIdentityMailFromDomainRequest {
     /// <p>The action that you want Amazon SES to take if it cannot successfully read the required MX record when you send an email. If you choose <code>UseDefaultValue</code>, Amazon SES will use amazonses.com (or a subdomain of that) as the MAIL FROM domain. If you choose <code>RejectMessage</code>, Amazon SES will return a <code>MailFromDomainNotVerified</code> error and not send the email.</p> <p>The action specified in <code>BehaviorOnMXFailure</code> is taken when the custom MAIL FROM domain setup is in the <code>Pending</code>, <code>Failed</code>, and <code>TemporaryFailure</code> states.</p>
-    pub behavior_on_mx_failure: Option<String>,
+    pub behavior_on_mx_failure: Option<BehaviorOnMXFailure>,
     /// <p>The verified identity for which you want to enable or disable the specified custom MAIL FROM domain.</p>
     pub identity: String,
     /// <p>The custom MAIL FROM domain that you want the verified identity to use. The MAIL FROM domain must 1) be a subdomain of the verified identity, 2) not be used in a "From" address if the MAIL FROM domain is the destination of email feedback forwarding (for more information, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/mail-from.html">Amazon SES Developer Guide</a>), and 3) not be used to receive emails. A value of <code>null</code> disables the custom MAIL FROM setting for the identity.</p>
@@ -6013,7 +7437,7 @@ pub struct SetIdentityNotificationTopicRequest {
     /// <p>The identity (email address or domain) that you want to set the Amazon SNS topic for.</p> <important> <p>You can only specify a verified identity for this parameter.</p> </important> <p>You can specify an identity by using its name or by using its Amazon Resource Name (ARN). The following examples are all valid identities: <code>sender@example.com</code>, <code>example.com</code>, <code>arn:aws:ses:us-east-1:123456789012:identity/example.com</code>.</p>
     pub identity: String,
     /// <p>The type of notifications that will be published to the specified Amazon SNS topic.</p>
-    pub notification_type: String,
+    pub notification_type: NotificationType,
     /// <p>The Amazon Resource Name (ARN) of the Amazon SNS topic. If the parameter is omitted from the request or a null value is passed, <code>SnsTopic</code> is cleared and publishing is disabled.</p>
     pub sns_topic: Option<String>,
 }
@@ -6115,7 +7539,7 @@ impl SetReceiptRulePositionResponseDeserializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct StopAction {
     /// <p>The scope of the StopAction. The only acceptable value is <code>RuleSet</code>.</p>
-    pub scope: String,
+    pub scope: StopScope,
     /// <p>The Amazon Resource Name (ARN) of the Amazon SNS topic to notify when the stop action is taken. An example of an Amazon SNS topic ARN is <code>arn:aws:sns:us-west-2:123456789012:MyTopic</code>. For more information about Amazon SNS topics, see the <a href="https://docs.aws.amazon.com/sns/latest/dg/CreateTopic.html">Amazon SNS Developer Guide</a>.</p>
     pub topic_arn: Option<String>,
 }
@@ -6160,12 +7584,105 @@ impl StopActionSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StopScope {
+    /// `RuleSet`
+    RuleSet,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl StopScope {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StopScope::RuleSet => "RuleSet",
+            StopScope::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for StopScope {
+    fn default() -> StopScope {
+        StopScope::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for StopScope {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for StopScope {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for StopScope {
+    fn from(value: &'a str) -> StopScope {
+        match value {
+            "RuleSet" => StopScope::RuleSet,
+            _ => StopScope::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StopScope {
+    fn from(value: String) -> StopScope {
+        match value.as_str() {
+            "RuleSet" => StopScope::RuleSet,
+            _ => StopScope::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for StopScope {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<StopScope, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StopScope {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for StopScope {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for StopScope {
+    fn deserialize<D>(deserializer: D) -> Result<StopScope, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct StopScopeDeserializer;
 impl StopScopeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<StopScope, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -6287,7 +7804,7 @@ impl TemplateContentDeserializer {
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct TemplateMetadata {
     /// <p>The time and date the template was created.</p>
-    pub created_timestamp: Option<String>,
+    pub created_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the template.</p>
     pub name: Option<String>,
 }
@@ -6413,20 +7930,121 @@ impl TextPartDeserializer {
 struct TimestampDeserializer;
 impl TimestampDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<::chrono::DateTime<::chrono::Utc>, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::proto::xml::util::timestamp_characters(stack)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TlsPolicy {
+    /// `Require`
+    Require,
+    /// `Optional`
+    Optional,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TlsPolicy {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TlsPolicy::Require => "Require",
+            TlsPolicy::Optional => "Optional",
+            TlsPolicy::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TlsPolicy {
+    fn default() -> TlsPolicy {
+        TlsPolicy::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TlsPolicy {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TlsPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TlsPolicy {
+    fn from(value: &'a str) -> TlsPolicy {
+        match value {
+            "Require" => TlsPolicy::Require,
+            "Optional" => TlsPolicy::Optional,
+            _ => TlsPolicy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TlsPolicy {
+    fn from(value: String) -> TlsPolicy {
+        match value.as_str() {
+            "Require" => TlsPolicy::Require,
+            "Optional" => TlsPolicy::Optional,
+            _ => TlsPolicy::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TlsPolicy {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TlsPolicy, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TlsPolicy {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for TlsPolicy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for TlsPolicy {
+    fn deserialize<D>(deserializer: D) -> Result<TlsPolicy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct TlsPolicyDeserializer;
 impl TlsPolicyDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<TlsPolicy, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -6842,12 +8460,125 @@ impl VerificationAttributesDeserializer {
         Ok(obj)
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum VerificationStatus {
+    /// `Pending`
+    Pending,
+    /// `Success`
+    Success,
+    /// `Failed`
+    Failed,
+    /// `TemporaryFailure`
+    TemporaryFailure,
+    /// `NotStarted`
+    NotStarted,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl VerificationStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            VerificationStatus::Pending => "Pending",
+            VerificationStatus::Success => "Success",
+            VerificationStatus::Failed => "Failed",
+            VerificationStatus::TemporaryFailure => "TemporaryFailure",
+            VerificationStatus::NotStarted => "NotStarted",
+            VerificationStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for VerificationStatus {
+    fn default() -> VerificationStatus {
+        VerificationStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for VerificationStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for VerificationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for VerificationStatus {
+    fn from(value: &'a str) -> VerificationStatus {
+        match value {
+            "Pending" => VerificationStatus::Pending,
+            "Success" => VerificationStatus::Success,
+            "Failed" => VerificationStatus::Failed,
+            "TemporaryFailure" => VerificationStatus::TemporaryFailure,
+            "NotStarted" => VerificationStatus::NotStarted,
+            _ => VerificationStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for VerificationStatus {
+    fn from(value: String) -> VerificationStatus {
+        match value.as_str() {
+            "Pending" => VerificationStatus::Pending,
+            "Success" => VerificationStatus::Success,
+            "Failed" => VerificationStatus::Failed,
+            "TemporaryFailure" => VerificationStatus::TemporaryFailure,
+            "NotStarted" => VerificationStatus::NotStarted,
+            _ => VerificationStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for VerificationStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<VerificationStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for VerificationStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for VerificationStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for VerificationStatus {
+    fn deserialize<D>(deserializer: D) -> Result<VerificationStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct VerificationStatusDeserializer;
 impl VerificationStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<VerificationStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -11023,11 +12754,12 @@ pub struct SesClient {
 impl SesClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> SesClient {
         SesClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "SES"),
         }
     }
 
@@ -11059,6 +12791,7 @@ impl Ses for SesClient {
         input: CloneReceiptRuleSetRequest,
     ) -> Result<CloneReceiptRuleSetResponse, RusotoError<CloneReceiptRuleSetError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("CloneReceiptRuleSet");
         let mut params = Params::new();
 
         params.put("Action", "CloneReceiptRuleSet");
@@ -11108,6 +12841,7 @@ impl Ses for SesClient {
         input: CreateConfigurationSetRequest,
     ) -> Result<CreateConfigurationSetResponse, RusotoError<CreateConfigurationSetError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("CreateConfigurationSet");
         let mut params = Params::new();
 
         params.put("Action", "CreateConfigurationSet");
@@ -11160,6 +12894,7 @@ impl Ses for SesClient {
         RusotoError<CreateConfigurationSetEventDestinationError>,
     > {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("CreateConfigurationSetEventDestination");
         let mut params = Params::new();
 
         params.put("Action", "CreateConfigurationSetEventDestination");
@@ -11214,6 +12949,7 @@ impl Ses for SesClient {
         RusotoError<CreateConfigurationSetTrackingOptionsError>,
     > {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("CreateConfigurationSetTrackingOptions");
         let mut params = Params::new();
 
         params.put("Action", "CreateConfigurationSetTrackingOptions");
@@ -11265,6 +13001,7 @@ impl Ses for SesClient {
         input: CreateCustomVerificationEmailTemplateRequest,
     ) -> Result<(), RusotoError<CreateCustomVerificationEmailTemplateError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("CreateCustomVerificationEmailTemplate");
         let mut params = Params::new();
 
         params.put("Action", "CreateCustomVerificationEmailTemplate");
@@ -11295,6 +13032,7 @@ impl Ses for SesClient {
         input: CreateReceiptFilterRequest,
     ) -> Result<CreateReceiptFilterResponse, RusotoError<CreateReceiptFilterError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("CreateReceiptFilter");
         let mut params = Params::new();
 
         params.put("Action", "CreateReceiptFilter");
@@ -11344,6 +13082,7 @@ impl Ses for SesClient {
         input: CreateReceiptRuleRequest,
     ) -> Result<CreateReceiptRuleResponse, RusotoError<CreateReceiptRuleError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("CreateReceiptRule");
         let mut params = Params::new();
 
         params.put("Action", "CreateReceiptRule");
@@ -11393,6 +13132,7 @@ impl Ses for SesClient {
         input: CreateReceiptRuleSetRequest,
     ) -> Result<CreateReceiptRuleSetResponse, RusotoError<CreateReceiptRuleSetError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("CreateReceiptRuleSet");
         let mut params = Params::new();
 
         params.put("Action", "CreateReceiptRuleSet");
@@ -11442,6 +13182,7 @@ impl Ses for SesClient {
         input: CreateTemplateRequest,
     ) -> Result<CreateTemplateResponse, RusotoError<CreateTemplateError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("CreateTemplate");
         let mut params = Params::new();
 
         params.put("Action", "CreateTemplate");
@@ -11491,6 +13232,7 @@ impl Ses for SesClient {
         input: DeleteConfigurationSetRequest,
     ) -> Result<DeleteConfigurationSetResponse, RusotoError<DeleteConfigurationSetError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("DeleteConfigurationSet");
         let mut params = Params::new();
 
         params.put("Action", "DeleteConfigurationSet");
@@ -11543,6 +13285,7 @@ impl Ses for SesClient {
         RusotoError<DeleteConfigurationSetEventDestinationError>,
     > {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("DeleteConfigurationSetEventDestination");
         let mut params = Params::new();
 
         params.put("Action", "DeleteConfigurationSetEventDestination");
@@ -11597,6 +13340,7 @@ impl Ses for SesClient {
         RusotoError<DeleteConfigurationSetTrackingOptionsError>,
     > {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("DeleteConfigurationSetTrackingOptions");
         let mut params = Params::new();
 
         params.put("Action", "DeleteConfigurationSetTrackingOptions");
@@ -11648,6 +13392,7 @@ impl Ses for SesClient {
         input: DeleteCustomVerificationEmailTemplateRequest,
     ) -> Result<(), RusotoError<DeleteCustomVerificationEmailTemplateError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("DeleteCustomVerificationEmailTemplate");
         let mut params = Params::new();
 
         params.put("Action", "DeleteCustomVerificationEmailTemplate");
@@ -11678,6 +13423,7 @@ impl Ses for SesClient {
         input: DeleteIdentityRequest,
     ) -> Result<DeleteIdentityResponse, RusotoError<DeleteIdentityError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("DeleteIdentity");
         let mut params = Params::new();
 
         params.put("Action", "DeleteIdentity");
@@ -11727,6 +13473,7 @@ impl Ses for SesClient {
         input: DeleteIdentityPolicyRequest,
     ) -> Result<DeleteIdentityPolicyResponse, RusotoError<DeleteIdentityPolicyError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("DeleteIdentityPolicy");
         let mut params = Params::new();
 
         params.put("Action", "DeleteIdentityPolicy");
@@ -11776,6 +13523,7 @@ impl Ses for SesClient {
         input: DeleteReceiptFilterRequest,
     ) -> Result<DeleteReceiptFilterResponse, RusotoError<DeleteReceiptFilterError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("DeleteReceiptFilter");
         let mut params = Params::new();
 
         params.put("Action", "DeleteReceiptFilter");
@@ -11825,6 +13573,7 @@ impl Ses for SesClient {
         input: DeleteReceiptRuleRequest,
     ) -> Result<DeleteReceiptRuleResponse, RusotoError<DeleteReceiptRuleError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("DeleteReceiptRule");
         let mut params = Params::new();
 
         params.put("Action", "DeleteReceiptRule");
@@ -11874,6 +13623,7 @@ impl Ses for SesClient {
         input: DeleteReceiptRuleSetRequest,
     ) -> Result<DeleteReceiptRuleSetResponse, RusotoError<DeleteReceiptRuleSetError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("DeleteReceiptRuleSet");
         let mut params = Params::new();
 
         params.put("Action", "DeleteReceiptRuleSet");
@@ -11923,6 +13673,7 @@ impl Ses for SesClient {
         input: DeleteTemplateRequest,
     ) -> Result<DeleteTemplateResponse, RusotoError<DeleteTemplateError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("DeleteTemplate");
         let mut params = Params::new();
 
         params.put("Action", "DeleteTemplate");
@@ -11972,6 +13723,7 @@ impl Ses for SesClient {
         input: DeleteVerifiedEmailAddressRequest,
     ) -> Result<(), RusotoError<DeleteVerifiedEmailAddressError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("DeleteVerifiedEmailAddress");
         let mut params = Params::new();
 
         params.put("Action", "DeleteVerifiedEmailAddress");
@@ -12001,6 +13753,7 @@ impl Ses for SesClient {
     ) -> Result<DescribeActiveReceiptRuleSetResponse, RusotoError<DescribeActiveReceiptRuleSetError>>
     {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("DescribeActiveReceiptRuleSet");
         let mut params = Params::new();
 
         params.put("Action", "DescribeActiveReceiptRuleSet");
@@ -12050,6 +13803,7 @@ impl Ses for SesClient {
         input: DescribeConfigurationSetRequest,
     ) -> Result<DescribeConfigurationSetResponse, RusotoError<DescribeConfigurationSetError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("DescribeConfigurationSet");
         let mut params = Params::new();
 
         params.put("Action", "DescribeConfigurationSet");
@@ -12099,6 +13853,7 @@ impl Ses for SesClient {
         input: DescribeReceiptRuleRequest,
     ) -> Result<DescribeReceiptRuleResponse, RusotoError<DescribeReceiptRuleError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("DescribeReceiptRule");
         let mut params = Params::new();
 
         params.put("Action", "DescribeReceiptRule");
@@ -12148,6 +13903,7 @@ impl Ses for SesClient {
         input: DescribeReceiptRuleSetRequest,
     ) -> Result<DescribeReceiptRuleSetResponse, RusotoError<DescribeReceiptRuleSetError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("DescribeReceiptRuleSet");
         let mut params = Params::new();
 
         params.put("Action", "DescribeReceiptRuleSet");
@@ -12196,6 +13952,7 @@ impl Ses for SesClient {
         &self,
     ) -> Result<GetAccountSendingEnabledResponse, RusotoError<GetAccountSendingEnabledError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("GetAccountSendingEnabled");
         let mut params = Params::new();
 
         params.put("Action", "GetAccountSendingEnabled");
@@ -12248,6 +14005,7 @@ impl Ses for SesClient {
         RusotoError<GetCustomVerificationEmailTemplateError>,
     > {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("GetCustomVerificationEmailTemplate");
         let mut params = Params::new();
 
         params.put("Action", "GetCustomVerificationEmailTemplate");
@@ -12300,6 +14058,7 @@ impl Ses for SesClient {
     ) -> Result<GetIdentityDkimAttributesResponse, RusotoError<GetIdentityDkimAttributesError>>
     {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("GetIdentityDkimAttributes");
         let mut params = Params::new();
 
         params.put("Action", "GetIdentityDkimAttributes");
@@ -12352,6 +14111,7 @@ impl Ses for SesClient {
         RusotoError<GetIdentityMailFromDomainAttributesError>,
     > {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("GetIdentityMailFromDomainAttributes");
         let mut params = Params::new();
 
         params.put("Action", "GetIdentityMailFromDomainAttributes");
@@ -12406,6 +14166,7 @@ impl Ses for SesClient {
         RusotoError<GetIdentityNotificationAttributesError>,
     > {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("GetIdentityNotificationAttributes");
         let mut params = Params::new();
 
         params.put("Action", "GetIdentityNotificationAttributes");
@@ -12457,6 +14218,7 @@ impl Ses for SesClient {
         input: GetIdentityPoliciesRequest,
     ) -> Result<GetIdentityPoliciesResponse, RusotoError<GetIdentityPoliciesError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("GetIdentityPolicies");
         let mut params = Params::new();
 
         params.put("Action", "GetIdentityPolicies");
@@ -12509,6 +14271,7 @@ impl Ses for SesClient {
         RusotoError<GetIdentityVerificationAttributesError>,
     > {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("GetIdentityVerificationAttributes");
         let mut params = Params::new();
 
         params.put("Action", "GetIdentityVerificationAttributes");
@@ -12557,6 +14320,7 @@ impl Ses for SesClient {
     /// <p>Provides the sending limits for the Amazon SES account. </p> <p>You can execute this operation no more than once per second.</p>
     async fn get_send_quota(&self) -> Result<GetSendQuotaResponse, RusotoError<GetSendQuotaError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("GetSendQuota");
         let mut params = Params::new();
 
         params.put("Action", "GetSendQuota");
@@ -12603,6 +14367,7 @@ impl Ses for SesClient {
         &self,
     ) -> Result<GetSendStatisticsResponse, RusotoError<GetSendStatisticsError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("GetSendStatistics");
         let mut params = Params::new();
 
         params.put("Action", "GetSendStatistics");
@@ -12652,6 +14417,7 @@ impl Ses for SesClient {
         input: GetTemplateRequest,
     ) -> Result<GetTemplateResponse, RusotoError<GetTemplateError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("GetTemplate");
         let mut params = Params::new();
 
         params.put("Action", "GetTemplate");
@@ -12698,6 +14464,7 @@ impl Ses for SesClient {
         input: ListConfigurationSetsRequest,
     ) -> Result<ListConfigurationSetsResponse, RusotoError<ListConfigurationSetsError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("ListConfigurationSets");
         let mut params = Params::new();
 
         params.put("Action", "ListConfigurationSets");
@@ -12750,6 +14517,7 @@ impl Ses for SesClient {
         RusotoError<ListCustomVerificationEmailTemplatesError>,
     > {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("ListCustomVerificationEmailTemplates");
         let mut params = Params::new();
 
         params.put("Action", "ListCustomVerificationEmailTemplates");
@@ -12801,6 +14569,7 @@ impl Ses for SesClient {
         input: ListIdentitiesRequest,
     ) -> Result<ListIdentitiesResponse, RusotoError<ListIdentitiesError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("ListIdentities");
         let mut params = Params::new();
 
         params.put("Action", "ListIdentities");
@@ -12850,6 +14619,7 @@ impl Ses for SesClient {
         input: ListIdentityPoliciesRequest,
     ) -> Result<ListIdentityPoliciesResponse, RusotoError<ListIdentityPoliciesError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("ListIdentityPolicies");
         let mut params = Params::new();
 
         params.put("Action", "ListIdentityPolicies");
@@ -12899,6 +14669,7 @@ impl Ses for SesClient {
         input: ListReceiptFiltersRequest,
     ) -> Result<ListReceiptFiltersResponse, RusotoError<ListReceiptFiltersError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("ListReceiptFilters");
         let mut params = Params::new();
 
         params.put("Action", "ListReceiptFilters");
@@ -12948,6 +14719,7 @@ impl Ses for SesClient {
         input: ListReceiptRuleSetsRequest,
     ) -> Result<ListReceiptRuleSetsResponse, RusotoError<ListReceiptRuleSetsError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("ListReceiptRuleSets");
         let mut params = Params::new();
 
         params.put("Action", "ListReceiptRuleSets");
@@ -12997,6 +14769,7 @@ impl Ses for SesClient {
         input: ListTemplatesRequest,
     ) -> Result<ListTemplatesResponse, RusotoError<ListTemplatesError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("ListTemplates");
         let mut params = Params::new();
 
         params.put("Action", "ListTemplates");
@@ -13044,6 +14817,7 @@ impl Ses for SesClient {
     ) -> Result<ListVerifiedEmailAddressesResponse, RusotoError<ListVerifiedEmailAddressesError>>
     {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("ListVerifiedEmailAddresses");
         let mut params = Params::new();
 
         params.put("Action", "ListVerifiedEmailAddresses");
@@ -13096,6 +14870,7 @@ impl Ses for SesClient {
         RusotoError<PutConfigurationSetDeliveryOptionsError>,
     > {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("PutConfigurationSetDeliveryOptions");
         let mut params = Params::new();
 
         params.put("Action", "PutConfigurationSetDeliveryOptions");
@@ -13147,6 +14922,7 @@ impl Ses for SesClient {
         input: PutIdentityPolicyRequest,
     ) -> Result<PutIdentityPolicyResponse, RusotoError<PutIdentityPolicyError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("PutIdentityPolicy");
         let mut params = Params::new();
 
         params.put("Action", "PutIdentityPolicy");
@@ -13196,6 +14972,7 @@ impl Ses for SesClient {
         input: ReorderReceiptRuleSetRequest,
     ) -> Result<ReorderReceiptRuleSetResponse, RusotoError<ReorderReceiptRuleSetError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("ReorderReceiptRuleSet");
         let mut params = Params::new();
 
         params.put("Action", "ReorderReceiptRuleSet");
@@ -13245,6 +15022,7 @@ impl Ses for SesClient {
         input: SendBounceRequest,
     ) -> Result<SendBounceResponse, RusotoError<SendBounceError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("SendBounce");
         let mut params = Params::new();
 
         params.put("Action", "SendBounce");
@@ -13291,6 +15069,7 @@ impl Ses for SesClient {
         input: SendBulkTemplatedEmailRequest,
     ) -> Result<SendBulkTemplatedEmailResponse, RusotoError<SendBulkTemplatedEmailError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("SendBulkTemplatedEmail");
         let mut params = Params::new();
 
         params.put("Action", "SendBulkTemplatedEmail");
@@ -13341,6 +15120,7 @@ impl Ses for SesClient {
     ) -> Result<SendCustomVerificationEmailResponse, RusotoError<SendCustomVerificationEmailError>>
     {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("SendCustomVerificationEmail");
         let mut params = Params::new();
 
         params.put("Action", "SendCustomVerificationEmail");
@@ -13390,6 +15170,7 @@ impl Ses for SesClient {
         input: SendEmailRequest,
     ) -> Result<SendEmailResponse, RusotoError<SendEmailError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("SendEmail");
         let mut params = Params::new();
 
         params.put("Action", "SendEmail");
@@ -13436,6 +15217,7 @@ impl Ses for SesClient {
         input: SendRawEmailRequest,
     ) -> Result<SendRawEmailResponse, RusotoError<SendRawEmailError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("SendRawEmail");
         let mut params = Params::new();
 
         params.put("Action", "SendRawEmail");
@@ -13483,6 +15265,7 @@ impl Ses for SesClient {
         input: SendTemplatedEmailRequest,
     ) -> Result<SendTemplatedEmailResponse, RusotoError<SendTemplatedEmailError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("SendTemplatedEmail");
         let mut params = Params::new();
 
         params.put("Action", "SendTemplatedEmail");
@@ -13532,6 +15315,7 @@ impl Ses for SesClient {
         input: SetActiveReceiptRuleSetRequest,
     ) -> Result<SetActiveReceiptRuleSetResponse, RusotoError<SetActiveReceiptRuleSetError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("SetActiveReceiptRuleSet");
         let mut params = Params::new();
 
         params.put("Action", "SetActiveReceiptRuleSet");
@@ -13581,6 +15365,7 @@ impl Ses for SesClient {
         input: SetIdentityDkimEnabledRequest,
     ) -> Result<SetIdentityDkimEnabledResponse, RusotoError<SetIdentityDkimEnabledError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("SetIdentityDkimEnabled");
         let mut params = Params::new();
 
         params.put("Action", "SetIdentityDkimEnabled");
@@ -13633,6 +15418,7 @@ impl Ses for SesClient {
         RusotoError<SetIdentityFeedbackForwardingEnabledError>,
     > {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("SetIdentityFeedbackForwardingEnabled");
         let mut params = Params::new();
 
         params.put("Action", "SetIdentityFeedbackForwardingEnabled");
@@ -13687,6 +15473,7 @@ impl Ses for SesClient {
         RusotoError<SetIdentityHeadersInNotificationsEnabledError>,
     > {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("SetIdentityHeadersInNotificationsEnabled");
         let mut params = Params::new();
 
         params.put("Action", "SetIdentityHeadersInNotificationsEnabled");
@@ -13741,6 +15528,7 @@ impl Ses for SesClient {
     ) -> Result<SetIdentityMailFromDomainResponse, RusotoError<SetIdentityMailFromDomainError>>
     {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("SetIdentityMailFromDomain");
         let mut params = Params::new();
 
         params.put("Action", "SetIdentityMailFromDomain");
@@ -13791,6 +15579,7 @@ impl Ses for SesClient {
     ) -> Result<SetIdentityNotificationTopicResponse, RusotoError<SetIdentityNotificationTopicError>>
     {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("SetIdentityNotificationTopic");
         let mut params = Params::new();
 
         params.put("Action", "SetIdentityNotificationTopic");
@@ -13840,6 +15629,7 @@ impl Ses for SesClient {
         input: SetReceiptRulePositionRequest,
     ) -> Result<SetReceiptRulePositionResponse, RusotoError<SetReceiptRulePositionError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("SetReceiptRulePosition");
         let mut params = Params::new();
 
         params.put("Action", "SetReceiptRulePosition");
@@ -13889,6 +15679,7 @@ impl Ses for SesClient {
         input: TestRenderTemplateRequest,
     ) -> Result<TestRenderTemplateResponse, RusotoError<TestRenderTemplateError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("TestRenderTemplate");
         let mut params = Params::new();
 
         params.put("Action", "TestRenderTemplate");
@@ -13938,6 +15729,7 @@ impl Ses for SesClient {
         input: UpdateAccountSendingEnabledRequest,
     ) -> Result<(), RusotoError<UpdateAccountSendingEnabledError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("UpdateAccountSendingEnabled");
         let mut params = Params::new();
 
         params.put("Action", "UpdateAccountSendingEnabled");
@@ -13969,6 +15761,7 @@ impl Ses for SesClient {
         RusotoError<UpdateConfigurationSetEventDestinationError>,
     > {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("UpdateConfigurationSetEventDestination");
         let mut params = Params::new();
 
         params.put("Action", "UpdateConfigurationSetEventDestination");
@@ -14020,6 +15813,7 @@ impl Ses for SesClient {
         input: UpdateConfigurationSetReputationMetricsEnabledRequest,
     ) -> Result<(), RusotoError<UpdateConfigurationSetReputationMetricsEnabledError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("UpdateConfigurationSetReputationMetricsEnabled");
         let mut params = Params::new();
 
         params.put("Action", "UpdateConfigurationSetReputationMetricsEnabled");
@@ -14054,6 +15848,7 @@ impl Ses for SesClient {
         input: UpdateConfigurationSetSendingEnabledRequest,
     ) -> Result<(), RusotoError<UpdateConfigurationSetSendingEnabledError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("UpdateConfigurationSetSendingEnabled");
         let mut params = Params::new();
 
         params.put("Action", "UpdateConfigurationSetSendingEnabled");
@@ -14087,6 +15882,7 @@ impl Ses for SesClient {
         RusotoError<UpdateConfigurationSetTrackingOptionsError>,
     > {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("UpdateConfigurationSetTrackingOptions");
         let mut params = Params::new();
 
         params.put("Action", "UpdateConfigurationSetTrackingOptions");
@@ -14138,6 +15934,7 @@ impl Ses for SesClient {
         input: UpdateCustomVerificationEmailTemplateRequest,
     ) -> Result<(), RusotoError<UpdateCustomVerificationEmailTemplateError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("UpdateCustomVerificationEmailTemplate");
         let mut params = Params::new();
 
         params.put("Action", "UpdateCustomVerificationEmailTemplate");
@@ -14168,6 +15965,7 @@ impl Ses for SesClient {
         input: UpdateReceiptRuleRequest,
     ) -> Result<UpdateReceiptRuleResponse, RusotoError<UpdateReceiptRuleError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("UpdateReceiptRule");
         let mut params = Params::new();
 
         params.put("Action", "UpdateReceiptRule");
@@ -14217,6 +16015,7 @@ impl Ses for SesClient {
         input: UpdateTemplateRequest,
     ) -> Result<UpdateTemplateResponse, RusotoError<UpdateTemplateError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("UpdateTemplate");
         let mut params = Params::new();
 
         params.put("Action", "UpdateTemplate");
@@ -14266,6 +16065,7 @@ impl Ses for SesClient {
         input: VerifyDomainDkimRequest,
     ) -> Result<VerifyDomainDkimResponse, RusotoError<VerifyDomainDkimError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("VerifyDomainDkim");
         let mut params = Params::new();
 
         params.put("Action", "VerifyDomainDkim");
@@ -14315,6 +16115,7 @@ impl Ses for SesClient {
         input: VerifyDomainIdentityRequest,
     ) -> Result<VerifyDomainIdentityResponse, RusotoError<VerifyDomainIdentityError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("VerifyDomainIdentity");
         let mut params = Params::new();
 
         params.put("Action", "VerifyDomainIdentity");
@@ -14364,6 +16165,7 @@ impl Ses for SesClient {
         input: VerifyEmailAddressRequest,
     ) -> Result<(), RusotoError<VerifyEmailAddressError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("VerifyEmailAddress");
         let mut params = Params::new();
 
         params.put("Action", "VerifyEmailAddress");
@@ -14392,6 +16194,7 @@ impl Ses for SesClient {
         input: VerifyEmailIdentityRequest,
     ) -> Result<VerifyEmailIdentityResponse, RusotoError<VerifyEmailIdentityError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("VerifyEmailIdentity");
         let mut params = Params::new();
 
         params.put("Action", "VerifyEmailIdentity");
@@ -14435,6 +16238,218 @@ impl Ses for SesClient {
         Ok(result)
     }
 }
+impl SesClient {
+    /// Auto-paginating version of `list_configuration_sets`, streaming every page of results.
+    pub fn list_configuration_sets_pages(
+        &self,
+        input: ListConfigurationSetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListConfigurationSetsResponse,
+        ListConfigurationSetsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SesClient, input: ListConfigurationSetsRequest| async move {
+                client.list_configuration_sets(input).await
+            },
+            |input: &ListConfigurationSetsRequest, output: &ListConfigurationSetsResponse| {
+                Some(ListConfigurationSetsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_configuration_sets`, streaming the `ConfigurationSets` of every page of results.
+    pub fn list_configuration_sets_items(
+        &self,
+        input: ListConfigurationSetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ConfigurationSet, ListConfigurationSetsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_configuration_sets_pages(input),
+            |output: ListConfigurationSetsResponse| output.configuration_sets.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_custom_verification_email_templates`, streaming every page of results.
+    pub fn list_custom_verification_email_templates_pages(
+        &self,
+        input: ListCustomVerificationEmailTemplatesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListCustomVerificationEmailTemplatesResponse,
+        ListCustomVerificationEmailTemplatesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SesClient, input: ListCustomVerificationEmailTemplatesRequest| async move {
+                client.list_custom_verification_email_templates(input).await
+            },
+            |input: &ListCustomVerificationEmailTemplatesRequest,
+             output: &ListCustomVerificationEmailTemplatesResponse| {
+                Some(ListCustomVerificationEmailTemplatesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_custom_verification_email_templates`, streaming the `CustomVerificationEmailTemplates` of every page of results.
+    pub fn list_custom_verification_email_templates_items(
+        &self,
+        input: ListCustomVerificationEmailTemplatesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        CustomVerificationEmailTemplate,
+        ListCustomVerificationEmailTemplatesError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_custom_verification_email_templates_pages(input),
+            |output: ListCustomVerificationEmailTemplatesResponse| {
+                output
+                    .custom_verification_email_templates
+                    .unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_identities`, streaming every page of results.
+    pub fn list_identities_pages(
+        &self,
+        input: ListIdentitiesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListIdentitiesResponse, ListIdentitiesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SesClient, input: ListIdentitiesRequest| async move {
+                client.list_identities(input).await
+            },
+            |input: &ListIdentitiesRequest, output: &ListIdentitiesResponse| {
+                Some(ListIdentitiesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_identities`, streaming the `Identities` of every page of results.
+    pub fn list_identities_items(
+        &self,
+        input: ListIdentitiesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListIdentitiesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_identities_pages(input),
+            |output: ListIdentitiesResponse| output.identities,
+        )
+    }
+
+    /// Auto-paginating version of `list_receipt_rule_sets`, streaming every page of results.
+    pub fn list_receipt_rule_sets_pages(
+        &self,
+        input: ListReceiptRuleSetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListReceiptRuleSetsResponse, ListReceiptRuleSetsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SesClient, input: ListReceiptRuleSetsRequest| async move {
+                client.list_receipt_rule_sets(input).await
+            },
+            |_input: &ListReceiptRuleSetsRequest, output: &ListReceiptRuleSetsResponse| {
+                Some(ListReceiptRuleSetsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_receipt_rule_sets`, streaming the `RuleSets` of every page of results.
+    pub fn list_receipt_rule_sets_items(
+        &self,
+        input: ListReceiptRuleSetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ReceiptRuleSetMetadata, ListReceiptRuleSetsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_receipt_rule_sets_pages(input),
+            |output: ListReceiptRuleSetsResponse| output.rule_sets.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_templates`, streaming every page of results.
+    pub fn list_templates_pages(
+        &self,
+        input: ListTemplatesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListTemplatesResponse, ListTemplatesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SesClient, input: ListTemplatesRequest| async move {
+                client.list_templates(input).await
+            },
+            |input: &ListTemplatesRequest, output: &ListTemplatesResponse| {
+                Some(ListTemplatesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_templates`, streaming the `TemplatesMetadata` of every page of results.
+    pub fn list_templates_items(
+        &self,
+        input: ListTemplatesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<TemplateMetadata, ListTemplatesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_templates_pages(input),
+            |output: ListTemplatesResponse| output.templates_metadata.unwrap_or_default(),
+        )
+    }
+}
+impl SesClient {
+    /// Waits until `get_identity_verification_attributes` reports the `IdentityExists` state, polling every 3 seconds up to 20 times.
+    pub async fn wait_until_identity_exists(
+        &self,
+        input: GetIdentityVerificationAttributesRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetIdentityVerificationAttributesError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(3), 20);
+        self.wait_until_identity_exists_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_identity_exists`, polling as often and as many times as `config` says.
+    pub async fn wait_until_identity_exists_with_config(
+        &self,
+        input: GetIdentityVerificationAttributesRequest,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetIdentityVerificationAttributesError>> {
+        rusoto_core::waiter::wait(
+            "IdentityExists",
+            &config,
+            || self.get_identity_verification_attributes(input.clone()),
+            |result: &Result<
+                GetIdentityVerificationAttributesResponse,
+                RusotoError<GetIdentityVerificationAttributesError>,
+            >| match result {
+                Ok(output)
+                    if rusoto_core::waiter::all_match(
+                        std::iter::once(&output.verification_attributes)
+                            .flat_map(|v| v.values())
+                            .map(|v| &v.verification_status),
+                        |v| *v == VerificationStatus::Success,
+                    ) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+}
 
 #[cfg(test)]
 mod protocol_tests {
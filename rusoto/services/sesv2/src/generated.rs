@@ -25,6 +25,100 @@ use rusoto_core::signature::SignedRequest;
 #[allow(unused_imports)]
 use serde::{Deserialize, Serialize};
 use serde_json;
+/// <p>The action to take if the required MX record can't be found when you send an email. When you set this value to <code>UseDefaultValue</code>, the mail is sent using <i>amazonses.com</i> as the MAIL FROM domain. When you set this value to <code>RejectMessage</code>, the Amazon SES API v2 returns a <code>MailFromDomainNotVerified</code> error, and doesn't attempt to deliver the email.</p> <p>These behaviors are taken when the custom MAIL FROM domain configuration is in the <code>Pending</code>, <code>Failed</code>, and <code>TemporaryFailure</code> states.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BehaviorOnMxFailure {
+    /// `USE_DEFAULT_VALUE`
+    UseDefaultValue,
+    /// `REJECT_MESSAGE`
+    RejectMessage,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl BehaviorOnMxFailure {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BehaviorOnMxFailure::UseDefaultValue => "USE_DEFAULT_VALUE",
+            BehaviorOnMxFailure::RejectMessage => "REJECT_MESSAGE",
+            BehaviorOnMxFailure::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for BehaviorOnMxFailure {
+    fn default() -> BehaviorOnMxFailure {
+        BehaviorOnMxFailure::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for BehaviorOnMxFailure {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for BehaviorOnMxFailure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for BehaviorOnMxFailure {
+    fn from(value: &'a str) -> BehaviorOnMxFailure {
+        match value {
+            "USE_DEFAULT_VALUE" => BehaviorOnMxFailure::UseDefaultValue,
+            "REJECT_MESSAGE" => BehaviorOnMxFailure::RejectMessage,
+            _ => BehaviorOnMxFailure::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BehaviorOnMxFailure {
+    fn from(value: String) -> BehaviorOnMxFailure {
+        match value.as_str() {
+            "USE_DEFAULT_VALUE" => BehaviorOnMxFailure::UseDefaultValue,
+            "REJECT_MESSAGE" => BehaviorOnMxFailure::RejectMessage,
+            _ => BehaviorOnMxFailure::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for BehaviorOnMxFailure {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<BehaviorOnMxFailure, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for BehaviorOnMxFailure {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for BehaviorOnMxFailure {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BehaviorOnMxFailure {
+    fn deserialize<D>(deserializer: D) -> Result<BehaviorOnMxFailure, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An object that contains information about a blacklisting event that impacts one of the dedicated IP addresses that is associated with your account.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -35,8 +129,13 @@ pub struct BlacklistEntry {
     pub description: Option<String>,
     /// <p>The time when the blacklisting event occurred, shown in Unix time format.</p>
     #[serde(rename = "ListingTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub listing_time: Option<f64>,
+    pub listing_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the blacklist that the IP address appears on.</p>
     #[serde(rename = "RblName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -76,7 +175,7 @@ pub struct CloudWatchDimensionConfiguration {
     pub dimension_name: String,
     /// <p>The location where the Amazon SES API v2 finds the value of a dimension to publish to Amazon CloudWatch. If you want to use the message tags that you specify using an <code>X-SES-MESSAGE-TAGS</code> header or a parameter to the <code>SendEmail</code> or <code>SendRawEmail</code> API, choose <code>messageTag</code>. If you want to use your own email headers, choose <code>emailHeader</code>. If you want to use link tags, choose <code>linkTags</code>.</p>
     #[serde(rename = "DimensionValueSource")]
-    pub dimension_value_source: String,
+    pub dimension_value_source: DimensionValueSource,
 }
 
 /// <p>An object that represents the content of the email, and optionally a character set specification.</p>
@@ -193,7 +292,7 @@ pub struct CreateDeliverabilityTestReportRequest {
 pub struct CreateDeliverabilityTestReportResponse {
     /// <p>The status of the predictive inbox placement test. If the status is <code>IN_PROGRESS</code>, then the predictive inbox placement test is currently running. Predictive inbox placement tests are usually complete within 24 hours of creating the test. If the status is <code>COMPLETE</code>, then the test is finished, and you can use the <code>GetDeliverabilityTestReport</code> to view the results of the test.</p>
     #[serde(rename = "DeliverabilityTestStatus")]
-    pub deliverability_test_status: String,
+    pub deliverability_test_status: DeliverabilityTestStatus,
     /// <p>A unique string that identifies the predictive inbox placement test.</p>
     #[serde(rename = "ReportId")]
     pub report_id: String,
@@ -227,7 +326,7 @@ pub struct CreateEmailIdentityResponse {
     /// <p>The email identity type.</p>
     #[serde(rename = "IdentityType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub identity_type: Option<String>,
+    pub identity_type: Option<IdentityType>,
     /// <p>Specifies whether or not the identity is verified. You can only send email from verified email addresses or domains. For more information about verifying identities, see the <a href="https://docs.aws.amazon.com/pinpoint/latest/userguide/channels-email-manage-verify.html">Amazon Pinpoint User Guide</a>.</p>
     #[serde(rename = "VerifiedForSendingStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -244,8 +343,13 @@ pub struct DailyVolume {
     pub domain_isp_placements: Option<Vec<DomainIspPlacement>>,
     /// <p>The date that the DailyVolume metrics apply to, in Unix time.</p>
     #[serde(rename = "StartDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_date: Option<f64>,
+    pub start_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>An object that contains inbox placement metrics for a specific day in the analysis period.</p>
     #[serde(rename = "VolumeStatistics")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -268,7 +372,7 @@ pub struct DedicatedIp {
     pub warmup_percentage: i64,
     /// <p><p>The warm-up status of a dedicated IP address. The status can have one of the following values:</p> <ul> <li> <p> <code>IN_PROGRESS</code> – The IP address isn&#39;t ready to use because the dedicated IP warm-up process is ongoing.</p> </li> <li> <p> <code>DONE</code> – The dedicated IP warm-up process is complete, and the IP address is ready to use.</p> </li> </ul></p>
     #[serde(rename = "WarmupStatus")]
-    pub warmup_status: String,
+    pub warmup_status: WarmupStatus,
 }
 
 /// <p>A request to delete an event destination from a configuration set.</p>
@@ -344,18 +448,122 @@ pub struct DeleteSuppressedDestinationRequest {
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteSuppressedDestinationResponse {}
 
+/// <p>The current status of your Deliverability dashboard subscription. If this value is <code>PENDING_EXPIRATION</code>, your subscription is scheduled to expire at the end of the current calendar month.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DeliverabilityDashboardAccountStatus {
+    /// `ACTIVE`
+    Active,
+    /// `PENDING_EXPIRATION`
+    PendingExpiration,
+    /// `DISABLED`
+    Disabled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DeliverabilityDashboardAccountStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeliverabilityDashboardAccountStatus::Active => "ACTIVE",
+            DeliverabilityDashboardAccountStatus::PendingExpiration => "PENDING_EXPIRATION",
+            DeliverabilityDashboardAccountStatus::Disabled => "DISABLED",
+            DeliverabilityDashboardAccountStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DeliverabilityDashboardAccountStatus {
+    fn default() -> DeliverabilityDashboardAccountStatus {
+        DeliverabilityDashboardAccountStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DeliverabilityDashboardAccountStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DeliverabilityDashboardAccountStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DeliverabilityDashboardAccountStatus {
+    fn from(value: &'a str) -> DeliverabilityDashboardAccountStatus {
+        match value {
+            "ACTIVE" => DeliverabilityDashboardAccountStatus::Active,
+            "PENDING_EXPIRATION" => DeliverabilityDashboardAccountStatus::PendingExpiration,
+            "DISABLED" => DeliverabilityDashboardAccountStatus::Disabled,
+            _ => DeliverabilityDashboardAccountStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeliverabilityDashboardAccountStatus {
+    fn from(value: String) -> DeliverabilityDashboardAccountStatus {
+        match value.as_str() {
+            "ACTIVE" => DeliverabilityDashboardAccountStatus::Active,
+            "PENDING_EXPIRATION" => DeliverabilityDashboardAccountStatus::PendingExpiration,
+            "DISABLED" => DeliverabilityDashboardAccountStatus::Disabled,
+            _ => DeliverabilityDashboardAccountStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DeliverabilityDashboardAccountStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DeliverabilityDashboardAccountStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DeliverabilityDashboardAccountStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DeliverabilityDashboardAccountStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeliverabilityDashboardAccountStatus {
+    fn deserialize<D>(deserializer: D) -> Result<DeliverabilityDashboardAccountStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An object that contains metadata related to a predictive inbox placement test.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeliverabilityTestReport {
     /// <p>The date and time when the predictive inbox placement test was created, in Unix time format.</p>
     #[serde(rename = "CreateDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub create_date: Option<f64>,
+    pub create_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The status of the predictive inbox placement test. If the status is <code>IN_PROGRESS</code>, then the predictive inbox placement test is currently running. Predictive inbox placement tests are usually complete within 24 hours of creating the test. If the status is <code>COMPLETE</code>, then the test is finished, and you can use the <code>GetDeliverabilityTestReport</code> to view the results of the test.</p>
     #[serde(rename = "DeliverabilityTestStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deliverability_test_status: Option<String>,
+    pub deliverability_test_status: Option<DeliverabilityTestStatus>,
     /// <p>The sender address that you specified for the predictive inbox placement test.</p>
     #[serde(rename = "FromEmailAddress")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -374,6 +582,100 @@ pub struct DeliverabilityTestReport {
     pub subject: Option<String>,
 }
 
+/// <p>The status of a predictive inbox placement test. If the status is <code>IN_PROGRESS</code>, then the predictive inbox placement test is currently running. Predictive inbox placement tests are usually complete within 24 hours of creating the test. If the status is <code>COMPLETE</code>, then the test is finished, and you can use the <code>GetDeliverabilityTestReport</code> operation to view the results of the test.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DeliverabilityTestStatus {
+    /// `IN_PROGRESS`
+    InProgress,
+    /// `COMPLETED`
+    Completed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DeliverabilityTestStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeliverabilityTestStatus::InProgress => "IN_PROGRESS",
+            DeliverabilityTestStatus::Completed => "COMPLETED",
+            DeliverabilityTestStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DeliverabilityTestStatus {
+    fn default() -> DeliverabilityTestStatus {
+        DeliverabilityTestStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DeliverabilityTestStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DeliverabilityTestStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DeliverabilityTestStatus {
+    fn from(value: &'a str) -> DeliverabilityTestStatus {
+        match value {
+            "IN_PROGRESS" => DeliverabilityTestStatus::InProgress,
+            "COMPLETED" => DeliverabilityTestStatus::Completed,
+            _ => DeliverabilityTestStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeliverabilityTestStatus {
+    fn from(value: String) -> DeliverabilityTestStatus {
+        match value.as_str() {
+            "IN_PROGRESS" => DeliverabilityTestStatus::InProgress,
+            "COMPLETED" => DeliverabilityTestStatus::Completed,
+            _ => DeliverabilityTestStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DeliverabilityTestStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DeliverabilityTestStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DeliverabilityTestStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DeliverabilityTestStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeliverabilityTestStatus {
+    fn deserialize<D>(deserializer: D) -> Result<DeliverabilityTestStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Used to associate a configuration set with a dedicated IP pool.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DeliveryOptions {
@@ -384,7 +686,7 @@ pub struct DeliveryOptions {
     /// <p>Specifies whether messages that use the configuration set are required to use Transport Layer Security (TLS). If the value is <code>Require</code>, messages are only delivered if a TLS connection can be established. If the value is <code>Optional</code>, messages can be delivered in plain text if a TLS connection can't be established.</p>
     #[serde(rename = "TlsPolicy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub tls_policy: Option<String>,
+    pub tls_policy: Option<TlsPolicy>,
 }
 
 /// <p>An object that describes the recipients for an email.</p>
@@ -405,6 +707,105 @@ pub struct Destination {
     pub to_addresses: Option<Vec<String>>,
 }
 
+/// <p>The location where the Amazon SES API v2 finds the value of a dimension to publish to Amazon CloudWatch. To use the message tags that you specify using an <code>X-SES-MESSAGE-TAGS</code> header or a parameter to the <code>SendEmail</code> or <code>SendRawEmail</code> API, choose <code>messageTag</code>. To use your own email headers, choose <code>emailHeader</code>. To use link tags, choose <code>linkTags</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DimensionValueSource {
+    /// `MESSAGE_TAG`
+    MessageTag,
+    /// `EMAIL_HEADER`
+    EmailHeader,
+    /// `LINK_TAG`
+    LinkTag,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DimensionValueSource {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DimensionValueSource::MessageTag => "MESSAGE_TAG",
+            DimensionValueSource::EmailHeader => "EMAIL_HEADER",
+            DimensionValueSource::LinkTag => "LINK_TAG",
+            DimensionValueSource::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DimensionValueSource {
+    fn default() -> DimensionValueSource {
+        DimensionValueSource::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DimensionValueSource {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DimensionValueSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DimensionValueSource {
+    fn from(value: &'a str) -> DimensionValueSource {
+        match value {
+            "MESSAGE_TAG" => DimensionValueSource::MessageTag,
+            "EMAIL_HEADER" => DimensionValueSource::EmailHeader,
+            "LINK_TAG" => DimensionValueSource::LinkTag,
+            _ => DimensionValueSource::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DimensionValueSource {
+    fn from(value: String) -> DimensionValueSource {
+        match value.as_str() {
+            "MESSAGE_TAG" => DimensionValueSource::MessageTag,
+            "EMAIL_HEADER" => DimensionValueSource::EmailHeader,
+            "LINK_TAG" => DimensionValueSource::LinkTag,
+            _ => DimensionValueSource::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DimensionValueSource {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DimensionValueSource, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DimensionValueSource {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DimensionValueSource {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DimensionValueSource {
+    fn deserialize<D>(deserializer: D) -> Result<DimensionValueSource, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An object that contains information about the DKIM authentication status for an email identity.</p> <p>Amazon SES determines the authentication status by searching for specific records in the DNS configuration for the domain. If you used <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/easy-dkim.html">Easy DKIM</a> to set up DKIM authentication, Amazon SES tries to find three unique CNAME records in the DNS configuration for your domain. If you provided a public key to perform DKIM authentication, Amazon SES tries to find a TXT record that uses the selector that you specified. The value of the TXT record must be a public key that's paired with the private key that you specified in the process of creating the identity</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -412,7 +813,7 @@ pub struct DkimAttributes {
     /// <p><p>A string that indicates how DKIM was configured for the identity. There are two possible values:</p> <ul> <li> <p> <code>AWS_SES</code> – Indicates that DKIM was configured for the identity by using <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/easy-dkim.html">Easy DKIM</a>.</p> </li> <li> <p> <code>EXTERNAL</code> – Indicates that DKIM was configured for the identity by using Bring Your Own DKIM (BYODKIM).</p> </li> </ul></p>
     #[serde(rename = "SigningAttributesOrigin")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub signing_attributes_origin: Option<String>,
+    pub signing_attributes_origin: Option<DkimSigningAttributesOrigin>,
     /// <p>If the value is <code>true</code>, then the messages that you send from the identity are signed using DKIM. If the value is <code>false</code>, then the messages that you send from the identity aren't DKIM-signed.</p>
     #[serde(rename = "SigningEnabled")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -420,7 +821,7 @@ pub struct DkimAttributes {
     /// <p><p>Describes whether or not Amazon SES has successfully located the DKIM records in the DNS records for the domain. The status can be one of the following:</p> <ul> <li> <p> <code>PENDING</code> – The verification process was initiated, but Amazon SES hasn&#39;t yet detected the DKIM records in the DNS configuration for the domain.</p> </li> <li> <p> <code>SUCCESS</code> – The verification process completed successfully.</p> </li> <li> <p> <code>FAILED</code> – The verification process failed. This typically occurs when Amazon SES fails to find the DKIM records in the DNS configuration of the domain.</p> </li> <li> <p> <code>TEMPORARY<em>FAILURE</code> – A temporary issue is preventing Amazon SES from determining the DKIM authentication status of the domain.</p> </li> <li> <p> <code>NOT</em>STARTED</code> – The DKIM verification process hasn&#39;t been initiated for the domain.</p> </li> </ul></p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<DkimStatus>,
     /// <p>If you used <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/easy-dkim.html">Easy DKIM</a> to configure DKIM authentication for the domain, then this object contains a set of unique strings that you use to create a set of CNAME records that you add to the DNS configuration for your domain. When Amazon SES detects these records in the DNS configuration for your domain, the DKIM authentication process is complete.</p> <p>If you configured DKIM authentication for the domain by providing your own public-private key pair, then this object contains the selector for the public key.</p> <p>Regardless of the DKIM authentication method you use, Amazon SES searches for the appropriate records in the DNS configuration of the domain for up to 72 hours.</p>
     #[serde(rename = "Tokens")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -439,6 +840,208 @@ pub struct DkimSigningAttributes {
     pub domain_signing_selector: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DkimSigningAttributesOrigin {
+    /// `AWS_SES`
+    AwsSes,
+    /// `EXTERNAL`
+    External,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DkimSigningAttributesOrigin {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DkimSigningAttributesOrigin::AwsSes => "AWS_SES",
+            DkimSigningAttributesOrigin::External => "EXTERNAL",
+            DkimSigningAttributesOrigin::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DkimSigningAttributesOrigin {
+    fn default() -> DkimSigningAttributesOrigin {
+        DkimSigningAttributesOrigin::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DkimSigningAttributesOrigin {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DkimSigningAttributesOrigin {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DkimSigningAttributesOrigin {
+    fn from(value: &'a str) -> DkimSigningAttributesOrigin {
+        match value {
+            "AWS_SES" => DkimSigningAttributesOrigin::AwsSes,
+            "EXTERNAL" => DkimSigningAttributesOrigin::External,
+            _ => DkimSigningAttributesOrigin::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DkimSigningAttributesOrigin {
+    fn from(value: String) -> DkimSigningAttributesOrigin {
+        match value.as_str() {
+            "AWS_SES" => DkimSigningAttributesOrigin::AwsSes,
+            "EXTERNAL" => DkimSigningAttributesOrigin::External,
+            _ => DkimSigningAttributesOrigin::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DkimSigningAttributesOrigin {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DkimSigningAttributesOrigin, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DkimSigningAttributesOrigin {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DkimSigningAttributesOrigin {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DkimSigningAttributesOrigin {
+    fn deserialize<D>(deserializer: D) -> Result<DkimSigningAttributesOrigin, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>The DKIM authentication status of the identity. The status can be one of the following:</p> <ul> <li> <p> <code>PENDING</code> – The verification process was initiated, but Amazon SES hasn't yet detected the DKIM records in the DNS configuration for the domain.</p> </li> <li> <p> <code>SUCCESS</code> – The verification process completed successfully.</p> </li> <li> <p> <code>FAILED</code> – The verification process failed. This typically occurs when Amazon SES fails to find the DKIM records in the DNS configuration of the domain.</p> </li> <li> <p> <code>TEMPORARY_FAILURE</code> – A temporary issue is preventing Amazon SES from determining the DKIM authentication status of the domain.</p> </li> <li> <p> <code>NOT_STARTED</code> – The DKIM verification process hasn't been initiated for the domain.</p> </li> </ul>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DkimStatus {
+    /// `PENDING`
+    Pending,
+    /// `SUCCESS`
+    Success,
+    /// `FAILED`
+    Failed,
+    /// `TEMPORARY_FAILURE`
+    TemporaryFailure,
+    /// `NOT_STARTED`
+    NotStarted,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DkimStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DkimStatus::Pending => "PENDING",
+            DkimStatus::Success => "SUCCESS",
+            DkimStatus::Failed => "FAILED",
+            DkimStatus::TemporaryFailure => "TEMPORARY_FAILURE",
+            DkimStatus::NotStarted => "NOT_STARTED",
+            DkimStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DkimStatus {
+    fn default() -> DkimStatus {
+        DkimStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DkimStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DkimStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DkimStatus {
+    fn from(value: &'a str) -> DkimStatus {
+        match value {
+            "PENDING" => DkimStatus::Pending,
+            "SUCCESS" => DkimStatus::Success,
+            "FAILED" => DkimStatus::Failed,
+            "TEMPORARY_FAILURE" => DkimStatus::TemporaryFailure,
+            "NOT_STARTED" => DkimStatus::NotStarted,
+            _ => DkimStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DkimStatus {
+    fn from(value: String) -> DkimStatus {
+        match value.as_str() {
+            "PENDING" => DkimStatus::Pending,
+            "SUCCESS" => DkimStatus::Success,
+            "FAILED" => DkimStatus::Failed,
+            "TEMPORARY_FAILURE" => DkimStatus::TemporaryFailure,
+            "NOT_STARTED" => DkimStatus::NotStarted,
+            _ => DkimStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DkimStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DkimStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DkimStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DkimStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DkimStatus {
+    fn deserialize<D>(deserializer: D) -> Result<DkimStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An object that contains the deliverability data for a specific campaign. This data is available for a campaign only if the campaign sent email by using a domain that the Deliverability dashboard is enabled for (<code>PutDeliverabilityDashboardOption</code> operation).</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -457,8 +1060,13 @@ pub struct DomainDeliverabilityCampaign {
     pub esps: Option<Vec<String>>,
     /// <p>The first time, in Unix time format, when the email message was delivered to any recipient's inbox. This value can help you determine how long it took for a campaign to deliver an email message.</p>
     #[serde(rename = "FirstSeenDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub first_seen_date_time: Option<f64>,
+    pub first_seen_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The verified email address that the email message was sent from.</p>
     #[serde(rename = "FromAddress")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -473,8 +1081,13 @@ pub struct DomainDeliverabilityCampaign {
     pub inbox_count: Option<i64>,
     /// <p>The last time, in Unix time format, when the email message was delivered to any recipient's inbox. This value can help you determine how long it took for a campaign to deliver an email message.</p>
     #[serde(rename = "LastSeenDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_seen_date_time: Option<f64>,
+    pub last_seen_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The projected number of recipients that the email message was sent to.</p>
     #[serde(rename = "ProjectedVolume")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -514,8 +1127,13 @@ pub struct DomainDeliverabilityTrackingOption {
     pub inbox_placement_tracking_option: Option<InboxPlacementTrackingOption>,
     /// <p>The date, in Unix time format, when you enabled the Deliverability dashboard for the domain.</p>
     #[serde(rename = "SubscriptionStartDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub subscription_start_date: Option<f64>,
+    pub subscription_start_date: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>An object that contains inbox placement data for email sent from one of your email domains to a specific email provider.</p>
@@ -580,7 +1198,7 @@ pub struct EventDestination {
     pub kinesis_firehose_destination: Option<KinesisFirehoseDestination>,
     /// <p>The types of events that Amazon SES sends to the specified event destinations.</p>
     #[serde(rename = "MatchingEventTypes")]
-    pub matching_event_types: Vec<String>,
+    pub matching_event_types: Vec<EventType>,
     /// <p>A name that identifies the event destination.</p>
     #[serde(rename = "Name")]
     pub name: String,
@@ -613,7 +1231,7 @@ pub struct EventDestinationDefinition {
     /// <p>An array that specifies which events the Amazon SES API v2 should send to the destinations in this <code>EventDestinationDefinition</code>.</p>
     #[serde(rename = "MatchingEventTypes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub matching_event_types: Option<Vec<String>>,
+    pub matching_event_types: Option<Vec<EventType>>,
     /// <p>An object that defines an Amazon Pinpoint project destination for email events. You can send email event data to a Amazon Pinpoint project to view metrics using the Transactional Messaging dashboards that are built in to Amazon Pinpoint. For more information, see <a href="https://docs.aws.amazon.com/pinpoint/latest/userguide/analytics-transactional-messages.html">Transactional Messaging Charts</a> in the <i>Amazon Pinpoint User Guide</i>.</p>
     #[serde(rename = "PinpointDestination")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -624,6 +1242,140 @@ pub struct EventDestinationDefinition {
     pub sns_destination: Option<SnsDestination>,
 }
 
+/// <p>An email sending event type. For example, email sends, opens, and bounces are all email events.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EventType {
+    /// `SEND`
+    Send,
+    /// `REJECT`
+    Reject,
+    /// `BOUNCE`
+    Bounce,
+    /// `COMPLAINT`
+    Complaint,
+    /// `DELIVERY`
+    Delivery,
+    /// `OPEN`
+    Open,
+    /// `CLICK`
+    Click,
+    /// `RENDERING_FAILURE`
+    RenderingFailure,
+    /// `DELIVERY_DELAY`
+    DeliveryDelay,
+    /// `SUBSCRIPTION`
+    Subscription,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EventType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EventType::Send => "SEND",
+            EventType::Reject => "REJECT",
+            EventType::Bounce => "BOUNCE",
+            EventType::Complaint => "COMPLAINT",
+            EventType::Delivery => "DELIVERY",
+            EventType::Open => "OPEN",
+            EventType::Click => "CLICK",
+            EventType::RenderingFailure => "RENDERING_FAILURE",
+            EventType::DeliveryDelay => "DELIVERY_DELAY",
+            EventType::Subscription => "SUBSCRIPTION",
+            EventType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EventType {
+    fn default() -> EventType {
+        EventType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EventType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EventType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EventType {
+    fn from(value: &'a str) -> EventType {
+        match value {
+            "SEND" => EventType::Send,
+            "REJECT" => EventType::Reject,
+            "BOUNCE" => EventType::Bounce,
+            "COMPLAINT" => EventType::Complaint,
+            "DELIVERY" => EventType::Delivery,
+            "OPEN" => EventType::Open,
+            "CLICK" => EventType::Click,
+            "RENDERING_FAILURE" => EventType::RenderingFailure,
+            "DELIVERY_DELAY" => EventType::DeliveryDelay,
+            "SUBSCRIPTION" => EventType::Subscription,
+            _ => EventType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EventType {
+    fn from(value: String) -> EventType {
+        match value.as_str() {
+            "SEND" => EventType::Send,
+            "REJECT" => EventType::Reject,
+            "BOUNCE" => EventType::Bounce,
+            "COMPLAINT" => EventType::Complaint,
+            "DELIVERY" => EventType::Delivery,
+            "OPEN" => EventType::Open,
+            "CLICK" => EventType::Click,
+            "RENDERING_FAILURE" => EventType::RenderingFailure,
+            "DELIVERY_DELAY" => EventType::DeliveryDelay,
+            "SUBSCRIPTION" => EventType::Subscription,
+            _ => EventType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EventType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EventType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EventType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EventType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EventType {
+    fn deserialize<D>(deserializer: D) -> Result<EventType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A request to obtain information about the email-sending capabilities of your Amazon SES account.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -802,7 +1554,7 @@ pub struct GetDeliverabilityDashboardOptionsResponse {
     /// <p>The current status of your Deliverability dashboard subscription. If this value is <code>PENDING_EXPIRATION</code>, your subscription is scheduled to expire at the end of the current calendar month.</p>
     #[serde(rename = "AccountStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub account_status: Option<String>,
+    pub account_status: Option<DeliverabilityDashboardAccountStatus>,
     /// <p>An array of objects, one for each verified domain that you use to send email and currently has an active Deliverability dashboard subscription that isn’t scheduled to expire at the end of the current calendar month.</p>
     #[serde(rename = "ActiveSubscribedDomains")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -816,8 +1568,13 @@ pub struct GetDeliverabilityDashboardOptionsResponse {
     pub pending_expiration_subscribed_domains: Option<Vec<DomainDeliverabilityTrackingOption>>,
     /// <p>The date, in Unix time format, when your current subscription to the Deliverability dashboard is scheduled to expire, if your subscription is scheduled to expire at the end of the current calendar month. This value is null if you have an active subscription that isn’t due to expire at the end of the month.</p>
     #[serde(rename = "SubscriptionExpiryDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub subscription_expiry_date: Option<f64>,
+    pub subscription_expiry_date: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>A request to retrieve the results of a predictive inbox placement test.</p>
@@ -879,10 +1636,20 @@ pub struct GetDomainStatisticsReportRequest {
     pub domain: String,
     /// <p>The last day (in Unix time) that you want to obtain domain deliverability metrics for. The <code>EndDate</code> that you specify has to be less than or equal to 30 days after the <code>StartDate</code>.</p>
     #[serde(rename = "EndDate")]
-    pub end_date: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
+    pub end_date: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The first day (in Unix time) that you want to obtain domain deliverability metrics for.</p>
     #[serde(rename = "StartDate")]
-    pub start_date: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
+    pub start_date: ::chrono::DateTime<::chrono::Utc>,
 }
 
 /// <p>An object that includes statistics that are related to the domain that you specified.</p>
@@ -921,7 +1688,7 @@ pub struct GetEmailIdentityResponse {
     /// <p>The email identity type.</p>
     #[serde(rename = "IdentityType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub identity_type: Option<String>,
+    pub identity_type: Option<IdentityType>,
     /// <p>An object that contains information about the Mail-From attributes for the email identity.</p>
     #[serde(rename = "MailFromAttributes")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -965,13 +1732,111 @@ pub struct IdentityInfo {
     /// <p><p>The email identity type. The identity type can be one of the following:</p> <ul> <li> <p> <code>EMAIL<em>ADDRESS</code> – The identity is an email address.</p> </li> <li> <p> <code>DOMAIN</code> – The identity is a domain.</p> </li> <li> <p> <code>MANAGED</em>DOMAIN</code> – The identity is a domain that is managed by AWS.</p> </li> </ul></p>
     #[serde(rename = "IdentityType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub identity_type: Option<String>,
+    pub identity_type: Option<IdentityType>,
     /// <p>Indicates whether or not you can send email from the identity.</p> <p>An <i>identity</i> is an email address or domain that you send email from. Before you can send email from an identity, you have to demostrate that you own the identity, and that you authorize Amazon SES to send email from that identity.</p>
     #[serde(rename = "SendingEnabled")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sending_enabled: Option<bool>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum IdentityType {
+    /// `EMAIL_ADDRESS`
+    EmailAddress,
+    /// `DOMAIN`
+    Domain,
+    /// `MANAGED_DOMAIN`
+    ManagedDomain,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl IdentityType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            IdentityType::EmailAddress => "EMAIL_ADDRESS",
+            IdentityType::Domain => "DOMAIN",
+            IdentityType::ManagedDomain => "MANAGED_DOMAIN",
+            IdentityType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for IdentityType {
+    fn default() -> IdentityType {
+        IdentityType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for IdentityType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for IdentityType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for IdentityType {
+    fn from(value: &'a str) -> IdentityType {
+        match value {
+            "EMAIL_ADDRESS" => IdentityType::EmailAddress,
+            "DOMAIN" => IdentityType::Domain,
+            "MANAGED_DOMAIN" => IdentityType::ManagedDomain,
+            _ => IdentityType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for IdentityType {
+    fn from(value: String) -> IdentityType {
+        match value.as_str() {
+            "EMAIL_ADDRESS" => IdentityType::EmailAddress,
+            "DOMAIN" => IdentityType::Domain,
+            "MANAGED_DOMAIN" => IdentityType::ManagedDomain,
+            _ => IdentityType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for IdentityType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<IdentityType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for IdentityType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for IdentityType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for IdentityType {
+    fn deserialize<D>(deserializer: D) -> Result<IdentityType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An object that contains information about the inbox placement data settings for a verified domain that’s associated with your AWS account. This data is available only if you enabled the Deliverability dashboard for the domain.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct InboxPlacementTrackingOption {
@@ -1099,7 +1964,12 @@ pub struct ListDeliverabilityTestReportsResponse {
 pub struct ListDomainDeliverabilityCampaignsRequest {
     /// <p>The last day, in Unix time format, that you want to obtain deliverability data for. This value has to be less than or equal to 30 days after the value of the <code>StartDate</code> parameter.</p>
     #[serde(rename = "EndDate")]
-    pub end_date: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
+    pub end_date: ::chrono::DateTime<::chrono::Utc>,
     /// <p>A token that’s returned from a previous call to the <code>ListDomainDeliverabilityCampaigns</code> operation. This token indicates the position of a campaign in the list of campaigns.</p>
     #[serde(rename = "NextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1110,7 +1980,12 @@ pub struct ListDomainDeliverabilityCampaignsRequest {
     pub page_size: Option<i64>,
     /// <p>The first day, in Unix time format, that you want to obtain deliverability data for.</p>
     #[serde(rename = "StartDate")]
-    pub start_date: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
+    pub start_date: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The domain to obtain deliverability data for.</p>
     #[serde(rename = "SubscribedDomain")]
     pub subscribed_domain: String,
@@ -1163,8 +2038,13 @@ pub struct ListEmailIdentitiesResponse {
 pub struct ListSuppressedDestinationsRequest {
     /// <p>Used to filter the list of suppressed email destinations so that it only includes addresses that were added to the list before a specific date. The date that you specify should be in Unix time format.</p>
     #[serde(rename = "EndDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_date: Option<f64>,
+    pub end_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A token returned from a previous call to <code>ListSuppressedDestinations</code> to indicate the position in the list of suppressed email addresses.</p>
     #[serde(rename = "NextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1176,11 +2056,16 @@ pub struct ListSuppressedDestinationsRequest {
     /// <p>The factors that caused the email address to be added to .</p>
     #[serde(rename = "Reasons")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub reasons: Option<Vec<String>>,
+    pub reasons: Option<Vec<SuppressionListReason>>,
     /// <p>Used to filter the list of suppressed email destinations so that it only includes addresses that were added to the list after a specific date. The date that you specify should be in Unix time format.</p>
     #[serde(rename = "StartDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_date: Option<f64>,
+    pub start_date: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>A list of suppressed email addresses.</p>
@@ -1219,15 +2104,119 @@ pub struct ListTagsForResourceResponse {
 pub struct MailFromAttributes {
     /// <p>The action that you want to take if the required MX record can't be found when you send an email. When you set this value to <code>UseDefaultValue</code>, the mail is sent using <i>amazonses.com</i> as the MAIL FROM domain. When you set this value to <code>RejectMessage</code>, the Amazon SES API v2 returns a <code>MailFromDomainNotVerified</code> error, and doesn't attempt to deliver the email.</p> <p>These behaviors are taken when the custom MAIL FROM domain configuration is in the <code>Pending</code>, <code>Failed</code>, and <code>TemporaryFailure</code> states.</p>
     #[serde(rename = "BehaviorOnMxFailure")]
-    pub behavior_on_mx_failure: String,
+    pub behavior_on_mx_failure: BehaviorOnMxFailure,
     /// <p>The name of a domain that an email identity uses as a custom MAIL FROM domain.</p>
     #[serde(rename = "MailFromDomain")]
     pub mail_from_domain: String,
     /// <p><p>The status of the MAIL FROM domain. This status can have the following values:</p> <ul> <li> <p> <code>PENDING</code> – Amazon SES hasn&#39;t started searching for the MX record yet.</p> </li> <li> <p> <code>SUCCESS</code> – Amazon SES detected the required MX record for the MAIL FROM domain.</p> </li> <li> <p> <code>FAILED</code> – Amazon SES can&#39;t find the required MX record, or the record no longer exists.</p> </li> <li> <p> <code>TEMPORARY_FAILURE</code> – A temporary issue occurred, which prevented Amazon SES from determining the status of the MAIL FROM domain.</p> </li> </ul></p>
     #[serde(rename = "MailFromDomainStatus")]
-    pub mail_from_domain_status: String,
+    pub mail_from_domain_status: MailFromDomainStatus,
+}
+
+/// <p>The status of the MAIL FROM domain. This status can have the following values:</p> <ul> <li> <p> <code>PENDING</code> – Amazon SES hasn't started searching for the MX record yet.</p> </li> <li> <p> <code>SUCCESS</code> – Amazon SES detected the required MX record for the MAIL FROM domain.</p> </li> <li> <p> <code>FAILED</code> – Amazon SES can't find the required MX record, or the record no longer exists.</p> </li> <li> <p> <code>TEMPORARY_FAILURE</code> – A temporary issue occurred, which prevented Amazon SES from determining the status of the MAIL FROM domain.</p> </li> </ul>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MailFromDomainStatus {
+    /// `PENDING`
+    Pending,
+    /// `SUCCESS`
+    Success,
+    /// `FAILED`
+    Failed,
+    /// `TEMPORARY_FAILURE`
+    TemporaryFailure,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl MailFromDomainStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MailFromDomainStatus::Pending => "PENDING",
+            MailFromDomainStatus::Success => "SUCCESS",
+            MailFromDomainStatus::Failed => "FAILED",
+            MailFromDomainStatus::TemporaryFailure => "TEMPORARY_FAILURE",
+            MailFromDomainStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for MailFromDomainStatus {
+    fn default() -> MailFromDomainStatus {
+        MailFromDomainStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for MailFromDomainStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for MailFromDomainStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for MailFromDomainStatus {
+    fn from(value: &'a str) -> MailFromDomainStatus {
+        match value {
+            "PENDING" => MailFromDomainStatus::Pending,
+            "SUCCESS" => MailFromDomainStatus::Success,
+            "FAILED" => MailFromDomainStatus::Failed,
+            "TEMPORARY_FAILURE" => MailFromDomainStatus::TemporaryFailure,
+            _ => MailFromDomainStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MailFromDomainStatus {
+    fn from(value: String) -> MailFromDomainStatus {
+        match value.as_str() {
+            "PENDING" => MailFromDomainStatus::Pending,
+            "SUCCESS" => MailFromDomainStatus::Success,
+            "FAILED" => MailFromDomainStatus::Failed,
+            "TEMPORARY_FAILURE" => MailFromDomainStatus::TemporaryFailure,
+            _ => MailFromDomainStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for MailFromDomainStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<MailFromDomainStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MailFromDomainStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for MailFromDomainStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for MailFromDomainStatus {
+    fn deserialize<D>(deserializer: D) -> Result<MailFromDomainStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents the email message that you're sending. The <code>Message</code> object consists of a subject line and a message body.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -1342,7 +2331,7 @@ pub struct PutAccountSuppressionAttributesRequest {
     /// <p><p>A list that contains the reasons that email addresses will be automatically added to the suppression list for your account. This list can contain any or all of the following:</p> <ul> <li> <p> <code>COMPLAINT</code> – Amazon SES adds an email address to the suppression list for your account when a message sent to that address results in a complaint.</p> </li> <li> <p> <code>BOUNCE</code> – Amazon SES adds an email address to the suppression list for your account when a message sent to that address results in a hard bounce.</p> </li> </ul></p>
     #[serde(rename = "SuppressedReasons")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub suppressed_reasons: Option<Vec<String>>,
+    pub suppressed_reasons: Option<Vec<SuppressionListReason>>,
 }
 
 /// <p>An HTTP 200 response if the request succeeds, or an error message if the request fails.</p>
@@ -1364,7 +2353,7 @@ pub struct PutConfigurationSetDeliveryOptionsRequest {
     /// <p>Specifies whether messages that use the configuration set are required to use Transport Layer Security (TLS). If the value is <code>Require</code>, messages are only delivered if a TLS connection can be established. If the value is <code>Optional</code>, messages can be delivered in plain text if a TLS connection can't be established.</p>
     #[serde(rename = "TlsPolicy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub tls_policy: Option<String>,
+    pub tls_policy: Option<TlsPolicy>,
 }
 
 /// <p>An HTTP 200 response if the request succeeds, or an error message if the request fails.</p>
@@ -1418,7 +2407,7 @@ pub struct PutConfigurationSetSuppressionOptionsRequest {
     /// <p><p>A list that contains the reasons that email addresses are automatically added to the suppression list for your account. This list can contain any or all of the following:</p> <ul> <li> <p> <code>COMPLAINT</code> – Amazon SES adds an email address to the suppression list for your account when a message sent to that address results in a complaint.</p> </li> <li> <p> <code>BOUNCE</code> – Amazon SES adds an email address to the suppression list for your account when a message sent to that address results in a hard bounce.</p> </li> </ul></p>
     #[serde(rename = "SuppressedReasons")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub suppressed_reasons: Option<Vec<String>>,
+    pub suppressed_reasons: Option<Vec<SuppressionListReason>>,
 }
 
 /// <p>An HTTP 200 response if the request succeeds, or an error message if the request fails.</p>
@@ -1527,7 +2516,7 @@ pub struct PutEmailIdentityDkimSigningAttributesRequest {
     pub signing_attributes: Option<DkimSigningAttributes>,
     /// <p><p>The method that you want to use to configure DKIM for the identity. There are two possible values:</p> <ul> <li> <p> <code>AWS_SES</code> – Configure DKIM for the identity by using <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/easy-dkim.html">Easy DKIM</a>.</p> </li> <li> <p> <code>EXTERNAL</code> – Configure DKIM for the identity by using Bring Your Own DKIM (BYODKIM).</p> </li> </ul></p>
     #[serde(rename = "SigningAttributesOrigin")]
-    pub signing_attributes_origin: String,
+    pub signing_attributes_origin: DkimSigningAttributesOrigin,
 }
 
 /// <p>If the action is successful, the service sends back an HTTP 200 response.</p> <p>The following data is returned in JSON format by the service.</p>
@@ -1537,7 +2526,7 @@ pub struct PutEmailIdentityDkimSigningAttributesResponse {
     /// <p><p>The DKIM authentication status of the identity. Amazon SES determines the authentication status by searching for specific records in the DNS configuration for your domain. If you used <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/easy-dkim.html">Easy DKIM</a> to set up DKIM authentication, Amazon SES tries to find three unique CNAME records in the DNS configuration for your domain.</p> <p>If you provided a public key to perform DKIM authentication, Amazon SES tries to find a TXT record that uses the selector that you specified. The value of the TXT record must be a public key that&#39;s paired with the private key that you specified in the process of creating the identity.</p> <p>The status can be one of the following:</p> <ul> <li> <p> <code>PENDING</code> – The verification process was initiated, but Amazon SES hasn&#39;t yet detected the DKIM records in the DNS configuration for the domain.</p> </li> <li> <p> <code>SUCCESS</code> – The verification process completed successfully.</p> </li> <li> <p> <code>FAILED</code> – The verification process failed. This typically occurs when Amazon SES fails to find the DKIM records in the DNS configuration of the domain.</p> </li> <li> <p> <code>TEMPORARY<em>FAILURE</code> – A temporary issue is preventing Amazon SES from determining the DKIM authentication status of the domain.</p> </li> <li> <p> <code>NOT</em>STARTED</code> – The DKIM verification process hasn&#39;t been initiated for the domain.</p> </li> </ul></p>
     #[serde(rename = "DkimStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub dkim_status: Option<String>,
+    pub dkim_status: Option<DkimStatus>,
     /// <p>If you used <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/easy-dkim.html">Easy DKIM</a> to configure DKIM authentication for the domain, then this object contains a set of unique strings that you use to create a set of CNAME records that you add to the DNS configuration for your domain. When Amazon SES detects these records in the DNS configuration for your domain, the DKIM authentication process is complete.</p> <p>If you configured DKIM authentication for the domain by providing your own public-private key pair, then this object contains the selector that's associated with your public key.</p> <p>Regardless of the DKIM authentication method you use, Amazon SES searches for the appropriate records in the DNS configuration of the domain for up to 72 hours.</p>
     #[serde(rename = "DkimTokens")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1569,7 +2558,7 @@ pub struct PutEmailIdentityMailFromAttributesRequest {
     /// <p>The action that you want to take if the required MX record isn't found when you send an email. When you set this value to <code>UseDefaultValue</code>, the mail is sent using <i>amazonses.com</i> as the MAIL FROM domain. When you set this value to <code>RejectMessage</code>, the Amazon SES API v2 returns a <code>MailFromDomainNotVerified</code> error, and doesn't attempt to deliver the email.</p> <p>These behaviors are taken when the custom MAIL FROM domain configuration is in the <code>Pending</code>, <code>Failed</code>, and <code>TemporaryFailure</code> states.</p>
     #[serde(rename = "BehaviorOnMxFailure")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub behavior_on_mx_failure: Option<String>,
+    pub behavior_on_mx_failure: Option<BehaviorOnMxFailure>,
     /// <p>The verified email identity that you want to set up the custom MAIL FROM domain for.</p>
     #[serde(rename = "EmailIdentity")]
     pub email_identity: String,
@@ -1593,7 +2582,7 @@ pub struct PutSuppressedDestinationRequest {
     pub email_address: String,
     /// <p>The factors that should cause the email address to be added to the suppression list for your account.</p>
     #[serde(rename = "Reason")]
-    pub reason: String,
+    pub reason: SuppressionListReason,
 }
 
 /// <p>An HTTP 200 response if the request succeeds, or an error message if the request fails.</p>
@@ -1620,8 +2609,13 @@ pub struct RawMessage {
 pub struct ReputationOptions {
     /// <p>The date and time (in Unix time) when the reputation metrics were last given a fresh start. When your account is given a fresh start, your reputation metrics are calculated starting from the date of the fresh start.</p>
     #[serde(rename = "LastFreshStart")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_fresh_start: Option<f64>,
+    pub last_fresh_start: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>If <code>true</code>, tracking of reputation metrics is enabled for the configuration set. If <code>false</code>, tracking of reputation metrics is disabled for the configuration set.</p>
     #[serde(rename = "ReputationMetricsEnabled")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1718,10 +2712,15 @@ pub struct SuppressedDestination {
     pub email_address: String,
     /// <p>The date and time when the suppressed destination was last updated, shown in Unix time format.</p>
     #[serde(rename = "LastUpdateTime")]
-    pub last_update_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub last_update_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The reason that the address was added to the suppression list for your account.</p>
     #[serde(rename = "Reason")]
-    pub reason: String,
+    pub reason: SuppressionListReason,
 }
 
 /// <p>An object that contains additional attributes that are related an email address that is on the suppression list for your account.</p>
@@ -1747,10 +2746,15 @@ pub struct SuppressedDestinationSummary {
     pub email_address: String,
     /// <p>The date and time when the suppressed destination was last updated, shown in Unix time format.</p>
     #[serde(rename = "LastUpdateTime")]
-    pub last_update_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub last_update_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The reason that the address was added to the suppression list for your account.</p>
     #[serde(rename = "Reason")]
-    pub reason: String,
+    pub reason: SuppressionListReason,
 }
 
 /// <p>An object that contains information about the email address suppression preferences for your account in the current AWS Region.</p>
@@ -1760,16 +2764,110 @@ pub struct SuppressionAttributes {
     /// <p><p>A list that contains the reasons that email addresses will be automatically added to the suppression list for your account. This list can contain any or all of the following:</p> <ul> <li> <p> <code>COMPLAINT</code> – Amazon SES adds an email address to the suppression list for your account when a message sent to that address results in a complaint.</p> </li> <li> <p> <code>BOUNCE</code> – Amazon SES adds an email address to the suppression list for your account when a message sent to that address results in a hard bounce.</p> </li> </ul></p>
     #[serde(rename = "SuppressedReasons")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub suppressed_reasons: Option<Vec<String>>,
+    pub suppressed_reasons: Option<Vec<SuppressionListReason>>,
 }
 
+/// <p>The reason that the address was added to the suppression list for your account. The value can be one of the following:</p> <ul> <li> <p> <code>COMPLAINT</code> – Amazon SES added an email address to the suppression list for your account because a message sent to that address results in a complaint.</p> </li> <li> <p> <code>BOUNCE</code> – Amazon SES added an email address to the suppression list for your account because a message sent to that address results in a hard bounce.</p> </li> </ul>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SuppressionListReason {
+    /// `BOUNCE`
+    Bounce,
+    /// `COMPLAINT`
+    Complaint,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SuppressionListReason {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SuppressionListReason::Bounce => "BOUNCE",
+            SuppressionListReason::Complaint => "COMPLAINT",
+            SuppressionListReason::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SuppressionListReason {
+    fn default() -> SuppressionListReason {
+        SuppressionListReason::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SuppressionListReason {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SuppressionListReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SuppressionListReason {
+    fn from(value: &'a str) -> SuppressionListReason {
+        match value {
+            "BOUNCE" => SuppressionListReason::Bounce,
+            "COMPLAINT" => SuppressionListReason::Complaint,
+            _ => SuppressionListReason::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SuppressionListReason {
+    fn from(value: String) -> SuppressionListReason {
+        match value.as_str() {
+            "BOUNCE" => SuppressionListReason::Bounce,
+            "COMPLAINT" => SuppressionListReason::Complaint,
+            _ => SuppressionListReason::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SuppressionListReason {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SuppressionListReason, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SuppressionListReason {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SuppressionListReason {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SuppressionListReason {
+    fn deserialize<D>(deserializer: D) -> Result<SuppressionListReason, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An object that contains information about the suppression list preferences for your account.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SuppressionOptions {
     /// <p><p>A list that contains the reasons that email addresses are automatically added to the suppression list for your account. This list can contain any or all of the following:</p> <ul> <li> <p> <code>COMPLAINT</code> – Amazon SES adds an email address to the suppression list for your account when a message sent to that address results in a complaint.</p> </li> <li> <p> <code>BOUNCE</code> – Amazon SES adds an email address to the suppression list for your account when a message sent to that address results in a hard bounce.</p> </li> </ul></p>
     #[serde(rename = "SuppressedReasons")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub suppressed_reasons: Option<Vec<String>>,
+    pub suppressed_reasons: Option<Vec<SuppressionListReason>>,
 }
 
 /// <p><p>An object that defines the tags that are associated with a resource. A <i>tag</i> is a label that you optionally define and associate with a resource. Tags can help you categorize and manage resources in different ways, such as by purpose, owner, environment, or other criteria. A resource can have as many as 50 tags.</p> <p>Each tag consists of a required <i>tag key</i> and an associated <i>tag value</i>, both of which you define. A tag key is a general label that acts as a category for a more specific tag value. A tag value acts as a descriptor within a tag key. A tag key can contain as many as 128 characters. A tag value can contain as many as 256 characters. The characters can be Unicode letters, digits, white space, or one of the following symbols: _ . : / = + -. The following additional restrictions apply to tags:</p> <ul> <li> <p>Tag keys and values are case sensitive.</p> </li> <li> <p>For each associated resource, each tag key must be unique and it can have only one value.</p> </li> <li> <p>The <code>aws:</code> prefix is reserved for use by AWS; you can’t use it in any tag keys or values that you define. In addition, you can&#39;t edit or remove tag keys or values that use this prefix. Tags that use this prefix don’t count against the limit of 50 tags per resource.</p> </li> <li> <p>You can associate tags with public or shared resources, but the tags are available only for your AWS account, not any other accounts that share the resource. In addition, the tags are available only for resources that are located in the specified AWS Region for your AWS account.</p> </li> </ul></p>
@@ -1812,6 +2910,100 @@ pub struct Template {
     pub template_data: Option<String>,
 }
 
+/// <p>Specifies whether messages that use the configuration set are required to use Transport Layer Security (TLS). If the value is <code>Require</code>, messages are only delivered if a TLS connection can be established. If the value is <code>Optional</code>, messages can be delivered in plain text if a TLS connection can't be established.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TlsPolicy {
+    /// `REQUIRE`
+    Require,
+    /// `OPTIONAL`
+    Optional,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TlsPolicy {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TlsPolicy::Require => "REQUIRE",
+            TlsPolicy::Optional => "OPTIONAL",
+            TlsPolicy::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TlsPolicy {
+    fn default() -> TlsPolicy {
+        TlsPolicy::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TlsPolicy {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TlsPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TlsPolicy {
+    fn from(value: &'a str) -> TlsPolicy {
+        match value {
+            "REQUIRE" => TlsPolicy::Require,
+            "OPTIONAL" => TlsPolicy::Optional,
+            _ => TlsPolicy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TlsPolicy {
+    fn from(value: String) -> TlsPolicy {
+        match value.as_str() {
+            "REQUIRE" => TlsPolicy::Require,
+            "OPTIONAL" => TlsPolicy::Optional,
+            _ => TlsPolicy::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TlsPolicy {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TlsPolicy, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TlsPolicy {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TlsPolicy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TlsPolicy {
+    fn deserialize<D>(deserializer: D) -> Result<TlsPolicy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An object that defines the tracking options for a configuration set. When you use the Amazon SES API v2 to send an email, it contains an invisible image that's used to track when recipients open your email. If your email contains links, those links are changed slightly in order to track when recipients click them.</p> <p>These images and links include references to a domain operated by AWS. You can optionally configure the Amazon SES to use a domain that you operate for these images and links.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TrackingOptions {
@@ -1877,6 +3069,100 @@ pub struct VolumeStatistics {
     pub spam_raw_count: Option<i64>,
 }
 
+/// <p>The warmup status of a dedicated IP.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum WarmupStatus {
+    /// `IN_PROGRESS`
+    InProgress,
+    /// `DONE`
+    Done,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl WarmupStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            WarmupStatus::InProgress => "IN_PROGRESS",
+            WarmupStatus::Done => "DONE",
+            WarmupStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for WarmupStatus {
+    fn default() -> WarmupStatus {
+        WarmupStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for WarmupStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for WarmupStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for WarmupStatus {
+    fn from(value: &'a str) -> WarmupStatus {
+        match value {
+            "IN_PROGRESS" => WarmupStatus::InProgress,
+            "DONE" => WarmupStatus::Done,
+            _ => WarmupStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for WarmupStatus {
+    fn from(value: String) -> WarmupStatus {
+        match value.as_str() {
+            "IN_PROGRESS" => WarmupStatus::InProgress,
+            "DONE" => WarmupStatus::Done,
+            _ => WarmupStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for WarmupStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<WarmupStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for WarmupStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for WarmupStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for WarmupStatus {
+    fn deserialize<D>(deserializer: D) -> Result<WarmupStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// Errors returned by CreateConfigurationSet
 #[derive(Debug, PartialEq)]
 pub enum CreateConfigurationSetError {
@@ -4784,11 +6070,12 @@ pub struct SesV2Client {
 impl SesV2Client {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> SesV2Client {
         SesV2Client {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "SESv2"),
         }
     }
 
@@ -4822,6 +6109,7 @@ impl SesV2 for SesV2Client {
         let request_uri = "/v2/email/configuration-sets";
 
         let mut request = SignedRequest::new("POST", "ses", &self.region, &request_uri);
+        request.set_operation("CreateConfigurationSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4859,6 +6147,7 @@ impl SesV2 for SesV2Client {
         );
 
         let mut request = SignedRequest::new("POST", "ses", &self.region, &request_uri);
+        request.set_operation("CreateConfigurationSetEventDestination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4892,6 +6181,7 @@ impl SesV2 for SesV2Client {
         let request_uri = "/v2/email/dedicated-ip-pools";
 
         let mut request = SignedRequest::new("POST", "ses", &self.region, &request_uri);
+        request.set_operation("CreateDedicatedIpPool");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4926,6 +6216,7 @@ impl SesV2 for SesV2Client {
         let request_uri = "/v2/email/deliverability-dashboard/test";
 
         let mut request = SignedRequest::new("POST", "ses", &self.region, &request_uri);
+        request.set_operation("CreateDeliverabilityTestReport");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4957,6 +6248,7 @@ impl SesV2 for SesV2Client {
         let request_uri = "/v2/email/identities";
 
         let mut request = SignedRequest::new("POST", "ses", &self.region, &request_uri);
+        request.set_operation("CreateEmailIdentity");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4991,6 +6283,7 @@ impl SesV2 for SesV2Client {
         );
 
         let mut request = SignedRequest::new("DELETE", "ses", &self.region, &request_uri);
+        request.set_operation("DeleteConfigurationSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5023,6 +6316,7 @@ impl SesV2 for SesV2Client {
         let request_uri = format!("/v2/email/configuration-sets/{configuration_set_name}/event-destinations/{event_destination_name}", configuration_set_name = input.configuration_set_name, event_destination_name = input.event_destination_name);
 
         let mut request = SignedRequest::new("DELETE", "ses", &self.region, &request_uri);
+        request.set_operation("DeleteConfigurationSetEventDestination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5057,6 +6351,7 @@ impl SesV2 for SesV2Client {
         );
 
         let mut request = SignedRequest::new("DELETE", "ses", &self.region, &request_uri);
+        request.set_operation("DeleteDedicatedIpPool");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5089,6 +6384,7 @@ impl SesV2 for SesV2Client {
         );
 
         let mut request = SignedRequest::new("DELETE", "ses", &self.region, &request_uri);
+        request.set_operation("DeleteEmailIdentity");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5122,6 +6418,7 @@ impl SesV2 for SesV2Client {
         );
 
         let mut request = SignedRequest::new("DELETE", "ses", &self.region, &request_uri);
+        request.set_operation("DeleteSuppressedDestination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5148,6 +6445,7 @@ impl SesV2 for SesV2Client {
         let request_uri = "/v2/email/account";
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("GetAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5177,6 +6475,7 @@ impl SesV2 for SesV2Client {
         let request_uri = "/v2/email/deliverability-dashboard/blacklist-report";
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("GetBlacklistReports");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5215,6 +6514,7 @@ impl SesV2 for SesV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("GetConfigurationSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5250,6 +6550,7 @@ impl SesV2 for SesV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("GetConfigurationSetEventDestinations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5281,6 +6582,7 @@ impl SesV2 for SesV2Client {
         let request_uri = format!("/v2/email/dedicated-ips/{ip}", ip = input.ip);
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("GetDedicatedIp");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5310,6 +6612,7 @@ impl SesV2 for SesV2Client {
         let request_uri = "/v2/email/dedicated-ips";
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("GetDedicatedIps");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5353,6 +6656,7 @@ impl SesV2 for SesV2Client {
         let request_uri = "/v2/email/deliverability-dashboard";
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("GetDeliverabilityDashboardOptions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5388,6 +6692,7 @@ impl SesV2 for SesV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("GetDeliverabilityTestReport");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5423,6 +6728,7 @@ impl SesV2 for SesV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("GetDomainDeliverabilityCampaign");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5458,13 +6764,20 @@ impl SesV2 for SesV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("GetDomainStatisticsReport");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
 
         let mut params = Params::new();
-        params.put("EndDate", &input.end_date);
-        params.put("StartDate", &input.start_date);
+        params.put(
+            "EndDate",
+            &rusoto_core::serialization::TimestampFormat::Iso8601.format(&input.end_date),
+        );
+        params.put(
+            "StartDate",
+            &rusoto_core::serialization::TimestampFormat::Iso8601.format(&input.start_date),
+        );
         request.set_params(params);
 
         let mut response = self
@@ -5495,6 +6808,7 @@ impl SesV2 for SesV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("GetEmailIdentity");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5527,6 +6841,7 @@ impl SesV2 for SesV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("GetSuppressedDestination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5556,6 +6871,7 @@ impl SesV2 for SesV2Client {
         let request_uri = "/v2/email/configuration-sets";
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("ListConfigurationSets");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5594,6 +6910,7 @@ impl SesV2 for SesV2Client {
         let request_uri = "/v2/email/dedicated-ip-pools";
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("ListDedicatedIpPools");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5635,6 +6952,7 @@ impl SesV2 for SesV2Client {
         let request_uri = "/v2/email/deliverability-dashboard/test-reports";
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("ListDeliverabilityTestReports");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5679,19 +6997,26 @@ impl SesV2 for SesV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("ListDomainDeliverabilityCampaigns");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
 
         let mut params = Params::new();
-        params.put("EndDate", &input.end_date);
+        params.put(
+            "EndDate",
+            &rusoto_core::serialization::TimestampFormat::Iso8601.format(&input.end_date),
+        );
         if let Some(ref x) = input.next_token {
             params.put("NextToken", x);
         }
         if let Some(ref x) = input.page_size {
             params.put("PageSize", x);
         }
-        params.put("StartDate", &input.start_date);
+        params.put(
+            "StartDate",
+            &rusoto_core::serialization::TimestampFormat::Iso8601.format(&input.start_date),
+        );
         request.set_params(params);
 
         let mut response = self
@@ -5721,6 +7046,7 @@ impl SesV2 for SesV2Client {
         let request_uri = "/v2/email/identities";
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("ListEmailIdentities");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5760,13 +7086,17 @@ impl SesV2 for SesV2Client {
         let request_uri = "/v2/email/suppression/addresses";
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("ListSuppressedDestinations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
 
         let mut params = Params::new();
         if let Some(ref x) = input.end_date {
-            params.put("EndDate", x);
+            params.put(
+                "EndDate",
+                &rusoto_core::serialization::TimestampFormat::Iso8601.format(x),
+            );
         }
         if let Some(ref x) = input.next_token {
             params.put("NextToken", x);
@@ -5780,7 +7110,10 @@ impl SesV2 for SesV2Client {
             }
         }
         if let Some(ref x) = input.start_date {
-            params.put("StartDate", x);
+            params.put(
+                "StartDate",
+                &rusoto_core::serialization::TimestampFormat::Iso8601.format(x),
+            );
         }
         request.set_params(params);
 
@@ -5809,6 +7142,7 @@ impl SesV2 for SesV2Client {
         let request_uri = "/v2/email/tags";
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5845,6 +7179,7 @@ impl SesV2 for SesV2Client {
         let request_uri = "/v2/email/account/dedicated-ips/warmup";
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutAccountDedicatedIpWarmupAttributes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5879,6 +7214,7 @@ impl SesV2 for SesV2Client {
         let request_uri = "/v2/email/account/sending";
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutAccountSendingAttributes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5913,6 +7249,7 @@ impl SesV2 for SesV2Client {
         let request_uri = "/v2/email/account/suppression";
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutAccountSuppressionAttributes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5952,6 +7289,7 @@ impl SesV2 for SesV2Client {
         );
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutConfigurationSetDeliveryOptions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5991,6 +7329,7 @@ impl SesV2 for SesV2Client {
         );
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutConfigurationSetReputationOptions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -6030,6 +7369,7 @@ impl SesV2 for SesV2Client {
         );
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutConfigurationSetSendingOptions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -6069,6 +7409,7 @@ impl SesV2 for SesV2Client {
         );
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutConfigurationSetSuppressionOptions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -6108,6 +7449,7 @@ impl SesV2 for SesV2Client {
         );
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutConfigurationSetTrackingOptions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -6141,6 +7483,7 @@ impl SesV2 for SesV2Client {
         let request_uri = format!("/v2/email/dedicated-ips/{ip}/pool", ip = input.ip);
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutDedicatedIpInPool");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -6175,6 +7518,7 @@ impl SesV2 for SesV2Client {
         let request_uri = format!("/v2/email/dedicated-ips/{ip}/warmup", ip = input.ip);
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutDedicatedIpWarmupAttributes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -6209,6 +7553,7 @@ impl SesV2 for SesV2Client {
         let request_uri = "/v2/email/deliverability-dashboard";
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutDeliverabilityDashboardOption");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -6248,6 +7593,7 @@ impl SesV2 for SesV2Client {
         );
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutEmailIdentityDkimAttributes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -6285,6 +7631,7 @@ impl SesV2 for SesV2Client {
         );
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutEmailIdentityDkimSigningAttributes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -6324,6 +7671,7 @@ impl SesV2 for SesV2Client {
         );
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutEmailIdentityFeedbackAttributes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -6363,6 +7711,7 @@ impl SesV2 for SesV2Client {
         );
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutEmailIdentityMailFromAttributes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -6396,6 +7745,7 @@ impl SesV2 for SesV2Client {
         let request_uri = "/v2/email/suppression/addresses";
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutSuppressedDestination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -6427,6 +7777,7 @@ impl SesV2 for SesV2Client {
         let request_uri = "/v2/email/outbound-emails";
 
         let mut request = SignedRequest::new("POST", "ses", &self.region, &request_uri);
+        request.set_operation("SendEmail");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -6458,6 +7809,7 @@ impl SesV2 for SesV2Client {
         let request_uri = "/v2/email/tags";
 
         let mut request = SignedRequest::new("POST", "ses", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -6489,6 +7841,7 @@ impl SesV2 for SesV2Client {
         let request_uri = "/v2/email/tags";
 
         let mut request = SignedRequest::new("DELETE", "ses", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -6528,6 +7881,7 @@ impl SesV2 for SesV2Client {
         let request_uri = format!("/v2/email/configuration-sets/{configuration_set_name}/event-destinations/{event_destination_name}", configuration_set_name = input.configuration_set_name, event_destination_name = input.event_destination_name);
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("UpdateConfigurationSetEventDestination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -66,8 +66,13 @@ pub struct AttackDetail {
     pub attack_properties: Option<Vec<AttackProperty>>,
     /// <p>The time the attack ended, in Unix time in seconds. For more information see <a href="http://docs.aws.amazon.com/cli/latest/userguide/cli-using-param.html#parameter-types">timestamp</a>.</p>
     #[serde(rename = "EndTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>List of mitigation actions taken for the attack.</p>
     #[serde(rename = "Mitigations")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -78,14 +83,112 @@ pub struct AttackDetail {
     pub resource_arn: Option<String>,
     /// <p>The time the attack started, in Unix time in seconds. For more information see <a href="http://docs.aws.amazon.com/cli/latest/userguide/cli-using-param.html#parameter-types">timestamp</a>.</p>
     #[serde(rename = "StartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>If applicable, additional detail about the resource being attacked, for example, IP address or URL.</p>
     #[serde(rename = "SubResources")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sub_resources: Option<Vec<SubResourceSummary>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AttackLayer {
+    /// `NETWORK`
+    Network,
+    /// `APPLICATION`
+    Application,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AttackLayer {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AttackLayer::Network => "NETWORK",
+            AttackLayer::Application => "APPLICATION",
+            AttackLayer::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AttackLayer {
+    fn default() -> AttackLayer {
+        AttackLayer::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AttackLayer {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AttackLayer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AttackLayer {
+    fn from(value: &'a str) -> AttackLayer {
+        match value {
+            "NETWORK" => AttackLayer::Network,
+            "APPLICATION" => AttackLayer::Application,
+            _ => AttackLayer::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AttackLayer {
+    fn from(value: String) -> AttackLayer {
+        match value.as_str() {
+            "NETWORK" => AttackLayer::Network,
+            "APPLICATION" => AttackLayer::Application,
+            _ => AttackLayer::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AttackLayer {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AttackLayer, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AttackLayer {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AttackLayer {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AttackLayer {
+    fn deserialize<D>(deserializer: D) -> Result<AttackLayer, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Details of the described attack.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -93,11 +196,11 @@ pub struct AttackProperty {
     /// <p>The type of distributed denial of service (DDoS) event that was observed. <code>NETWORK</code> indicates layer 3 and layer 4 events and <code>APPLICATION</code> indicates layer 7 events.</p>
     #[serde(rename = "AttackLayer")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub attack_layer: Option<String>,
+    pub attack_layer: Option<AttackLayer>,
     /// <p>Defines the DDoS attack property information that is provided. The <code>WORDPRESS_PINGBACK_REFLECTOR</code> and <code>WORDPRESS_PINGBACK_SOURCE</code> values are valid only for WordPress reflective pingback DDoS attacks.</p>
     #[serde(rename = "AttackPropertyIdentifier")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub attack_property_identifier: Option<String>,
+    pub attack_property_identifier: Option<AttackPropertyIdentifier>,
     /// <p>The array of <a>Contributor</a> objects that includes the top five contributors to an attack. </p>
     #[serde(rename = "TopContributors")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -109,9 +212,132 @@ pub struct AttackProperty {
     /// <p>The unit of the <code>Value</code> of the contributions.</p>
     #[serde(rename = "Unit")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub unit: Option<String>,
+    pub unit: Option<Unit>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AttackPropertyIdentifier {
+    /// `DESTINATION_URL`
+    DestinationUrl,
+    /// `REFERRER`
+    Referrer,
+    /// `SOURCE_ASN`
+    SourceAsn,
+    /// `SOURCE_COUNTRY`
+    SourceCountry,
+    /// `SOURCE_IP_ADDRESS`
+    SourceIpAddress,
+    /// `SOURCE_USER_AGENT`
+    SourceUserAgent,
+    /// `WORDPRESS_PINGBACK_REFLECTOR`
+    WordpressPingbackReflector,
+    /// `WORDPRESS_PINGBACK_SOURCE`
+    WordpressPingbackSource,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AttackPropertyIdentifier {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AttackPropertyIdentifier::DestinationUrl => "DESTINATION_URL",
+            AttackPropertyIdentifier::Referrer => "REFERRER",
+            AttackPropertyIdentifier::SourceAsn => "SOURCE_ASN",
+            AttackPropertyIdentifier::SourceCountry => "SOURCE_COUNTRY",
+            AttackPropertyIdentifier::SourceIpAddress => "SOURCE_IP_ADDRESS",
+            AttackPropertyIdentifier::SourceUserAgent => "SOURCE_USER_AGENT",
+            AttackPropertyIdentifier::WordpressPingbackReflector => "WORDPRESS_PINGBACK_REFLECTOR",
+            AttackPropertyIdentifier::WordpressPingbackSource => "WORDPRESS_PINGBACK_SOURCE",
+            AttackPropertyIdentifier::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AttackPropertyIdentifier {
+    fn default() -> AttackPropertyIdentifier {
+        AttackPropertyIdentifier::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AttackPropertyIdentifier {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AttackPropertyIdentifier {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AttackPropertyIdentifier {
+    fn from(value: &'a str) -> AttackPropertyIdentifier {
+        match value {
+            "DESTINATION_URL" => AttackPropertyIdentifier::DestinationUrl,
+            "REFERRER" => AttackPropertyIdentifier::Referrer,
+            "SOURCE_ASN" => AttackPropertyIdentifier::SourceAsn,
+            "SOURCE_COUNTRY" => AttackPropertyIdentifier::SourceCountry,
+            "SOURCE_IP_ADDRESS" => AttackPropertyIdentifier::SourceIpAddress,
+            "SOURCE_USER_AGENT" => AttackPropertyIdentifier::SourceUserAgent,
+            "WORDPRESS_PINGBACK_REFLECTOR" => AttackPropertyIdentifier::WordpressPingbackReflector,
+            "WORDPRESS_PINGBACK_SOURCE" => AttackPropertyIdentifier::WordpressPingbackSource,
+            _ => AttackPropertyIdentifier::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AttackPropertyIdentifier {
+    fn from(value: String) -> AttackPropertyIdentifier {
+        match value.as_str() {
+            "DESTINATION_URL" => AttackPropertyIdentifier::DestinationUrl,
+            "REFERRER" => AttackPropertyIdentifier::Referrer,
+            "SOURCE_ASN" => AttackPropertyIdentifier::SourceAsn,
+            "SOURCE_COUNTRY" => AttackPropertyIdentifier::SourceCountry,
+            "SOURCE_IP_ADDRESS" => AttackPropertyIdentifier::SourceIpAddress,
+            "SOURCE_USER_AGENT" => AttackPropertyIdentifier::SourceUserAgent,
+            "WORDPRESS_PINGBACK_REFLECTOR" => AttackPropertyIdentifier::WordpressPingbackReflector,
+            "WORDPRESS_PINGBACK_SOURCE" => AttackPropertyIdentifier::WordpressPingbackSource,
+            _ => AttackPropertyIdentifier::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AttackPropertyIdentifier {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AttackPropertyIdentifier, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AttackPropertyIdentifier {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AttackPropertyIdentifier {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for AttackPropertyIdentifier {
+    fn deserialize<D>(deserializer: D) -> Result<AttackPropertyIdentifier, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Summarizes all DDoS attacks for a specified time period.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -126,16 +352,26 @@ pub struct AttackSummary {
     pub attack_vectors: Option<Vec<AttackVectorDescription>>,
     /// <p>The end time of the attack, in Unix time in seconds. For more information see <a href="http://docs.aws.amazon.com/cli/latest/userguide/cli-using-param.html#parameter-types">timestamp</a>.</p>
     #[serde(rename = "EndTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The ARN (Amazon Resource Name) of the resource that was attacked.</p>
     #[serde(rename = "ResourceArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub resource_arn: Option<String>,
     /// <p>The start time of the attack, in Unix time in seconds. For more information see <a href="http://docs.aws.amazon.com/cli/latest/userguide/cli-using-param.html#parameter-types">timestamp</a>.</p>
     #[serde(rename = "StartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>Describes the attack.</p>
@@ -147,6 +383,99 @@ pub struct AttackVectorDescription {
     pub vector_type: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AutoRenew {
+    /// `ENABLED`
+    Enabled,
+    /// `DISABLED`
+    Disabled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AutoRenew {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AutoRenew::Enabled => "ENABLED",
+            AutoRenew::Disabled => "DISABLED",
+            AutoRenew::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AutoRenew {
+    fn default() -> AutoRenew {
+        AutoRenew::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AutoRenew {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AutoRenew {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AutoRenew {
+    fn from(value: &'a str) -> AutoRenew {
+        match value {
+            "ENABLED" => AutoRenew::Enabled,
+            "DISABLED" => AutoRenew::Disabled,
+            _ => AutoRenew::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AutoRenew {
+    fn from(value: String) -> AutoRenew {
+        match value.as_str() {
+            "ENABLED" => AutoRenew::Enabled,
+            "DISABLED" => AutoRenew::Disabled,
+            _ => AutoRenew::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AutoRenew {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AutoRenew, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AutoRenew {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AutoRenew {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AutoRenew {
+    fn deserialize<D>(deserializer: D) -> Result<AutoRenew, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A contributor to the attack and their contribution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -328,7 +657,7 @@ pub struct GetSubscriptionStateRequest {}
 pub struct GetSubscriptionStateResponse {
     /// <p>The status of the subscription.</p>
     #[serde(rename = "SubscriptionState")]
-    pub subscription_state: String,
+    pub subscription_state: SubscriptionState,
 }
 
 /// <p>Specifies how many protections of a given type you can create.</p>
@@ -456,9 +785,102 @@ pub struct SubResourceSummary {
     /// <p>The <code>SubResource</code> type.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<SubResourceType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SubResourceType {
+    /// `IP`
+    Ip,
+    /// `URL`
+    Url,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SubResourceType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SubResourceType::Ip => "IP",
+            SubResourceType::Url => "URL",
+            SubResourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SubResourceType {
+    fn default() -> SubResourceType {
+        SubResourceType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SubResourceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SubResourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SubResourceType {
+    fn from(value: &'a str) -> SubResourceType {
+        match value {
+            "IP" => SubResourceType::Ip,
+            "URL" => SubResourceType::Url,
+            _ => SubResourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SubResourceType {
+    fn from(value: String) -> SubResourceType {
+        match value.as_str() {
+            "IP" => SubResourceType::Ip,
+            "URL" => SubResourceType::Url,
+            _ => SubResourceType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SubResourceType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SubResourceType, Self::Err> {
+        Ok(value.into())
+    }
 }
 
+impl ::rusoto_core::param::ToParam for SubResourceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SubResourceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SubResourceType {
+    fn deserialize<D>(deserializer: D) -> Result<SubResourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about the AWS Shield Advanced subscription for an account.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -466,25 +888,128 @@ pub struct Subscription {
     /// <p>If <code>ENABLED</code>, the subscription will be automatically renewed at the end of the existing subscription period.</p> <p>When you initally create a subscription, <code>AutoRenew</code> is set to <code>ENABLED</code>. You can change this by submitting an <code>UpdateSubscription</code> request. If the <code>UpdateSubscription</code> request does not included a value for <code>AutoRenew</code>, the existing value for <code>AutoRenew</code> remains unchanged.</p>
     #[serde(rename = "AutoRenew")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub auto_renew: Option<String>,
+    pub auto_renew: Option<AutoRenew>,
     /// <p>The date and time your subscription will end.</p>
     #[serde(rename = "EndTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Specifies how many protections of a given type you can create.</p>
     #[serde(rename = "Limits")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limits: Option<Vec<Limit>>,
     /// <p>The start time of the subscription, in Unix time in seconds. For more information see <a href="http://docs.aws.amazon.com/cli/latest/userguide/cli-using-param.html#parameter-types">timestamp</a>.</p>
     #[serde(rename = "StartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The length, in seconds, of the AWS Shield Advanced subscription for the account.</p>
     #[serde(rename = "TimeCommitmentInSeconds")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub time_commitment_in_seconds: Option<i64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SubscriptionState {
+    /// `ACTIVE`
+    Active,
+    /// `INACTIVE`
+    Inactive,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SubscriptionState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SubscriptionState::Active => "ACTIVE",
+            SubscriptionState::Inactive => "INACTIVE",
+            SubscriptionState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SubscriptionState {
+    fn default() -> SubscriptionState {
+        SubscriptionState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SubscriptionState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SubscriptionState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SubscriptionState {
+    fn from(value: &'a str) -> SubscriptionState {
+        match value {
+            "ACTIVE" => SubscriptionState::Active,
+            "INACTIVE" => SubscriptionState::Inactive,
+            _ => SubscriptionState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SubscriptionState {
+    fn from(value: String) -> SubscriptionState {
+        match value.as_str() {
+            "ACTIVE" => SubscriptionState::Active,
+            "INACTIVE" => SubscriptionState::Inactive,
+            _ => SubscriptionState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SubscriptionState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SubscriptionState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SubscriptionState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SubscriptionState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SubscriptionState {
+    fn deserialize<D>(deserializer: D) -> Result<SubscriptionState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A summary of information about the attack.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -534,14 +1059,127 @@ pub struct SummarizedCounter {
 pub struct TimeRange {
     /// <p>The start time, in Unix time in seconds. For more information see <a href="http://docs.aws.amazon.com/cli/latest/userguide/cli-using-param.html#parameter-types">timestamp</a>.</p>
     #[serde(rename = "FromInclusive")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub from_inclusive: Option<f64>,
+    pub from_inclusive: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The end time, in Unix time in seconds. For more information see <a href="http://docs.aws.amazon.com/cli/latest/userguide/cli-using-param.html#parameter-types">timestamp</a>.</p>
     #[serde(rename = "ToExclusive")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub to_exclusive: Option<f64>,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to_exclusive: Option<::chrono::DateTime<::chrono::Utc>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Unit {
+    /// `BITS`
+    Bits,
+    /// `BYTES`
+    Bytes,
+    /// `PACKETS`
+    Packets,
+    /// `REQUESTS`
+    Requests,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Unit {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Unit::Bits => "BITS",
+            Unit::Bytes => "BYTES",
+            Unit::Packets => "PACKETS",
+            Unit::Requests => "REQUESTS",
+            Unit::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Unit {
+    fn default() -> Unit {
+        Unit::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Unit {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Unit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Unit {
+    fn from(value: &'a str) -> Unit {
+        match value {
+            "BITS" => Unit::Bits,
+            "BYTES" => Unit::Bytes,
+            "PACKETS" => Unit::Packets,
+            "REQUESTS" => Unit::Requests,
+            _ => Unit::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Unit {
+    fn from(value: String) -> Unit {
+        match value.as_str() {
+            "BITS" => Unit::Bits,
+            "BYTES" => Unit::Bytes,
+            "PACKETS" => Unit::Packets,
+            "REQUESTS" => Unit::Requests,
+            _ => Unit::Unknown(value),
+        }
+    }
 }
 
+impl ::std::str::FromStr for Unit {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Unit, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Unit {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Unit {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Unit {
+    fn deserialize<D>(deserializer: D) -> Result<Unit, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct UpdateEmergencyContactSettingsRequest {
@@ -561,13 +1199,106 @@ pub struct UpdateSubscriptionRequest {
     /// <p>When you initally create a subscription, <code>AutoRenew</code> is set to <code>ENABLED</code>. If <code>ENABLED</code>, the subscription will be automatically renewed at the end of the existing subscription period. You can change this by submitting an <code>UpdateSubscription</code> request. If the <code>UpdateSubscription</code> request does not included a value for <code>AutoRenew</code>, the existing value for <code>AutoRenew</code> remains unchanged.</p>
     #[serde(rename = "AutoRenew")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub auto_renew: Option<String>,
+    pub auto_renew: Option<AutoRenew>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateSubscriptionResponse {}
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ValidationExceptionReason {
+    /// `FIELD_VALIDATION_FAILED`
+    FieldValidationFailed,
+    /// `OTHER`
+    Other,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ValidationExceptionReason {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ValidationExceptionReason::FieldValidationFailed => "FIELD_VALIDATION_FAILED",
+            ValidationExceptionReason::Other => "OTHER",
+            ValidationExceptionReason::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ValidationExceptionReason {
+    fn default() -> ValidationExceptionReason {
+        ValidationExceptionReason::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ValidationExceptionReason {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ValidationExceptionReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ValidationExceptionReason {
+    fn from(value: &'a str) -> ValidationExceptionReason {
+        match value {
+            "FIELD_VALIDATION_FAILED" => ValidationExceptionReason::FieldValidationFailed,
+            "OTHER" => ValidationExceptionReason::Other,
+            _ => ValidationExceptionReason::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ValidationExceptionReason {
+    fn from(value: String) -> ValidationExceptionReason {
+        match value.as_str() {
+            "FIELD_VALIDATION_FAILED" => ValidationExceptionReason::FieldValidationFailed,
+            "OTHER" => ValidationExceptionReason::Other,
+            _ => ValidationExceptionReason::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ValidationExceptionReason {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ValidationExceptionReason, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ValidationExceptionReason {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ValidationExceptionReason {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ValidationExceptionReason {
+    fn deserialize<D>(deserializer: D) -> Result<ValidationExceptionReason, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// Errors returned by AssociateDRTLogBucket
 #[derive(Debug, PartialEq)]
 pub enum AssociateDRTLogBucketError {
@@ -1590,11 +2321,12 @@ pub struct ShieldClient {
 impl ShieldClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> ShieldClient {
         ShieldClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Shield"),
         }
     }
 
@@ -1626,6 +2358,7 @@ impl Shield for ShieldClient {
         input: AssociateDRTLogBucketRequest,
     ) -> Result<AssociateDRTLogBucketResponse, RusotoError<AssociateDRTLogBucketError>> {
         let mut request = SignedRequest::new("POST", "shield", &self.region, "/");
+        request.set_operation("AssociateDRTLogBucket");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSShield_20160616.AssociateDRTLogBucket");
@@ -1654,6 +2387,7 @@ impl Shield for ShieldClient {
         input: AssociateDRTRoleRequest,
     ) -> Result<AssociateDRTRoleResponse, RusotoError<AssociateDRTRoleError>> {
         let mut request = SignedRequest::new("POST", "shield", &self.region, "/");
+        request.set_operation("AssociateDRTRole");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSShield_20160616.AssociateDRTRole");
@@ -1682,6 +2416,7 @@ impl Shield for ShieldClient {
         input: CreateProtectionRequest,
     ) -> Result<CreateProtectionResponse, RusotoError<CreateProtectionError>> {
         let mut request = SignedRequest::new("POST", "shield", &self.region, "/");
+        request.set_operation("CreateProtection");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSShield_20160616.CreateProtection");
@@ -1709,6 +2444,7 @@ impl Shield for ShieldClient {
         &self,
     ) -> Result<CreateSubscriptionResponse, RusotoError<CreateSubscriptionError>> {
         let mut request = SignedRequest::new("POST", "shield", &self.region, "/");
+        request.set_operation("CreateSubscription");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSShield_20160616.CreateSubscription");
@@ -1736,6 +2472,7 @@ impl Shield for ShieldClient {
         input: DeleteProtectionRequest,
     ) -> Result<DeleteProtectionResponse, RusotoError<DeleteProtectionError>> {
         let mut request = SignedRequest::new("POST", "shield", &self.region, "/");
+        request.set_operation("DeleteProtection");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSShield_20160616.DeleteProtection");
@@ -1763,6 +2500,7 @@ impl Shield for ShieldClient {
         &self,
     ) -> Result<DeleteSubscriptionResponse, RusotoError<DeleteSubscriptionError>> {
         let mut request = SignedRequest::new("POST", "shield", &self.region, "/");
+        request.set_operation("DeleteSubscription");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSShield_20160616.DeleteSubscription");
@@ -1790,6 +2528,7 @@ impl Shield for ShieldClient {
         input: DescribeAttackRequest,
     ) -> Result<DescribeAttackResponse, RusotoError<DescribeAttackError>> {
         let mut request = SignedRequest::new("POST", "shield", &self.region, "/");
+        request.set_operation("DescribeAttack");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSShield_20160616.DescribeAttack");
@@ -1816,6 +2555,7 @@ impl Shield for ShieldClient {
         &self,
     ) -> Result<DescribeDRTAccessResponse, RusotoError<DescribeDRTAccessError>> {
         let mut request = SignedRequest::new("POST", "shield", &self.region, "/");
+        request.set_operation("DescribeDRTAccess");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSShield_20160616.DescribeDRTAccess");
@@ -1845,6 +2585,7 @@ impl Shield for ShieldClient {
         RusotoError<DescribeEmergencyContactSettingsError>,
     > {
         let mut request = SignedRequest::new("POST", "shield", &self.region, "/");
+        request.set_operation("DescribeEmergencyContactSettings");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1877,6 +2618,7 @@ impl Shield for ShieldClient {
         input: DescribeProtectionRequest,
     ) -> Result<DescribeProtectionResponse, RusotoError<DescribeProtectionError>> {
         let mut request = SignedRequest::new("POST", "shield", &self.region, "/");
+        request.set_operation("DescribeProtection");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSShield_20160616.DescribeProtection");
@@ -1904,6 +2646,7 @@ impl Shield for ShieldClient {
         &self,
     ) -> Result<DescribeSubscriptionResponse, RusotoError<DescribeSubscriptionError>> {
         let mut request = SignedRequest::new("POST", "shield", &self.region, "/");
+        request.set_operation("DescribeSubscription");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSShield_20160616.DescribeSubscription");
@@ -1931,6 +2674,7 @@ impl Shield for ShieldClient {
         input: DisassociateDRTLogBucketRequest,
     ) -> Result<DisassociateDRTLogBucketResponse, RusotoError<DisassociateDRTLogBucketError>> {
         let mut request = SignedRequest::new("POST", "shield", &self.region, "/");
+        request.set_operation("DisassociateDRTLogBucket");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1961,6 +2705,7 @@ impl Shield for ShieldClient {
         &self,
     ) -> Result<DisassociateDRTRoleResponse, RusotoError<DisassociateDRTRoleError>> {
         let mut request = SignedRequest::new("POST", "shield", &self.region, "/");
+        request.set_operation("DisassociateDRTRole");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSShield_20160616.DisassociateDRTRole");
@@ -1987,6 +2732,7 @@ impl Shield for ShieldClient {
         &self,
     ) -> Result<GetSubscriptionStateResponse, RusotoError<GetSubscriptionStateError>> {
         let mut request = SignedRequest::new("POST", "shield", &self.region, "/");
+        request.set_operation("GetSubscriptionState");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSShield_20160616.GetSubscriptionState");
@@ -2014,6 +2760,7 @@ impl Shield for ShieldClient {
         input: ListAttacksRequest,
     ) -> Result<ListAttacksResponse, RusotoError<ListAttacksError>> {
         let mut request = SignedRequest::new("POST", "shield", &self.region, "/");
+        request.set_operation("ListAttacks");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSShield_20160616.ListAttacks");
@@ -2041,6 +2788,7 @@ impl Shield for ShieldClient {
         input: ListProtectionsRequest,
     ) -> Result<ListProtectionsResponse, RusotoError<ListProtectionsError>> {
         let mut request = SignedRequest::new("POST", "shield", &self.region, "/");
+        request.set_operation("ListProtections");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSShield_20160616.ListProtections");
@@ -2071,6 +2819,7 @@ impl Shield for ShieldClient {
         RusotoError<UpdateEmergencyContactSettingsError>,
     > {
         let mut request = SignedRequest::new("POST", "shield", &self.region, "/");
+        request.set_operation("UpdateEmergencyContactSettings");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2102,6 +2851,7 @@ impl Shield for ShieldClient {
         input: UpdateSubscriptionRequest,
     ) -> Result<UpdateSubscriptionResponse, RusotoError<UpdateSubscriptionError>> {
         let mut request = SignedRequest::new("POST", "shield", &self.region, "/");
+        request.set_operation("UpdateSubscription");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSShield_20160616.UpdateSubscription");
@@ -2124,3 +2874,66 @@ impl Shield for ShieldClient {
         }
     }
 }
+impl ShieldClient {
+    /// Auto-paginating version of `list_attacks`, streaming every page of results.
+    pub fn list_attacks_pages(
+        &self,
+        input: ListAttacksRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListAttacksResponse, ListAttacksError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ShieldClient, input: ListAttacksRequest| async move {
+                client.list_attacks(input).await
+            },
+            |input: &ListAttacksRequest, output: &ListAttacksResponse| {
+                Some(ListAttacksRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_attacks`, streaming the `AttackSummaries` of every page of results.
+    pub fn list_attacks_items(
+        &self,
+        input: ListAttacksRequest,
+    ) -> rusoto_core::pagination::RusotoStream<AttackSummary, ListAttacksError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_attacks_pages(input),
+            |output: ListAttacksResponse| output.attack_summaries.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_protections`, streaming every page of results.
+    pub fn list_protections_pages(
+        &self,
+        input: ListProtectionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListProtectionsResponse, ListProtectionsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ShieldClient, input: ListProtectionsRequest| async move {
+                client.list_protections(input).await
+            },
+            |input: &ListProtectionsRequest, output: &ListProtectionsResponse| {
+                Some(ListProtectionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_protections`, streaming the `Protections` of every page of results.
+    pub fn list_protections_items(
+        &self,
+        input: ListProtectionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Protection, ListProtectionsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_protections_pages(input),
+            |output: ListProtectionsResponse| output.protections.unwrap_or_default(),
+        )
+    }
+}
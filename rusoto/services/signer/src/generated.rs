@@ -33,6 +33,94 @@ pub struct CancelSigningProfileRequest {
     pub profile_name: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Category {
+    /// `AWSIoT`
+    AWSIoT,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Category {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Category::AWSIoT => "AWSIoT",
+            Category::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Category {
+    fn default() -> Category {
+        Category::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Category {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Category {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Category {
+    fn from(value: &'a str) -> Category {
+        match value {
+            "AWSIoT" => Category::AWSIoT,
+            _ => Category::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Category {
+    fn from(value: String) -> Category {
+        match value.as_str() {
+            "AWSIoT" => Category::AWSIoT,
+            _ => Category::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Category {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Category, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Category {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Category {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Category {
+    fn deserialize<D>(deserializer: D) -> Result<Category, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct DescribeSigningJobRequest {
@@ -46,12 +134,22 @@ pub struct DescribeSigningJobRequest {
 pub struct DescribeSigningJobResponse {
     /// <p>Date and time that the signing job was completed.</p>
     #[serde(rename = "completedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub completed_at: Option<f64>,
+    pub completed_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Date and time that the signing job was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The ID of the signing job on output.</p>
     #[serde(rename = "jobId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -91,7 +189,7 @@ pub struct DescribeSigningJobResponse {
     /// <p>Status of the signing job.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<SigningStatus>,
     /// <p>String value that contains the status reason.</p>
     #[serde(rename = "statusReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -108,16 +206,109 @@ pub struct Destination {
     pub s_3: Option<S3Destination>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EncryptionAlgorithm {
+    /// `RSA`
+    Rsa,
+    /// `ECDSA`
+    Ecdsa,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EncryptionAlgorithm {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EncryptionAlgorithm::Rsa => "RSA",
+            EncryptionAlgorithm::Ecdsa => "ECDSA",
+            EncryptionAlgorithm::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EncryptionAlgorithm {
+    fn default() -> EncryptionAlgorithm {
+        EncryptionAlgorithm::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EncryptionAlgorithm {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EncryptionAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EncryptionAlgorithm {
+    fn from(value: &'a str) -> EncryptionAlgorithm {
+        match value {
+            "RSA" => EncryptionAlgorithm::Rsa,
+            "ECDSA" => EncryptionAlgorithm::Ecdsa,
+            _ => EncryptionAlgorithm::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EncryptionAlgorithm {
+    fn from(value: String) -> EncryptionAlgorithm {
+        match value.as_str() {
+            "RSA" => EncryptionAlgorithm::Rsa,
+            "ECDSA" => EncryptionAlgorithm::Ecdsa,
+            _ => EncryptionAlgorithm::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EncryptionAlgorithm {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EncryptionAlgorithm, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EncryptionAlgorithm {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EncryptionAlgorithm {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EncryptionAlgorithm {
+    fn deserialize<D>(deserializer: D) -> Result<EncryptionAlgorithm, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The encryption algorithm options that are available to a code signing job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EncryptionAlgorithmOptions {
     /// <p>The set of accepted encryption algorithms that are allowed in a code signing job.</p>
     #[serde(rename = "allowedValues")]
-    pub allowed_values: Vec<String>,
+    pub allowed_values: Vec<EncryptionAlgorithm>,
     /// <p>The default encryption algorithm that is used by a code signing job.</p>
     #[serde(rename = "defaultValue")]
-    pub default_value: String,
+    pub default_value: EncryptionAlgorithm,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -134,7 +325,7 @@ pub struct GetSigningPlatformResponse {
     /// <p>The category type of the target signing platform.</p>
     #[serde(rename = "category")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub category: Option<String>,
+    pub category: Option<Category>,
     /// <p>The display name of the target signing platform.</p>
     #[serde(rename = "displayName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -203,25 +394,216 @@ pub struct GetSigningProfileResponse {
     /// <p>The status of the target signing profile.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<SigningProfileStatus>,
     /// <p>A list of tags associated with the signing profile.</p>
     #[serde(rename = "tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum HashAlgorithm {
+    /// `SHA1`
+    Sha1,
+    /// `SHA256`
+    Sha256,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl HashAlgorithm {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            HashAlgorithm::Sha1 => "SHA1",
+            HashAlgorithm::Sha256 => "SHA256",
+            HashAlgorithm::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for HashAlgorithm {
+    fn default() -> HashAlgorithm {
+        HashAlgorithm::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for HashAlgorithm {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for HashAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for HashAlgorithm {
+    fn from(value: &'a str) -> HashAlgorithm {
+        match value {
+            "SHA1" => HashAlgorithm::Sha1,
+            "SHA256" => HashAlgorithm::Sha256,
+            _ => HashAlgorithm::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for HashAlgorithm {
+    fn from(value: String) -> HashAlgorithm {
+        match value.as_str() {
+            "SHA1" => HashAlgorithm::Sha1,
+            "SHA256" => HashAlgorithm::Sha256,
+            _ => HashAlgorithm::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for HashAlgorithm {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<HashAlgorithm, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for HashAlgorithm {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for HashAlgorithm {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for HashAlgorithm {
+    fn deserialize<D>(deserializer: D) -> Result<HashAlgorithm, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The hash algorithms that are available to a code signing job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct HashAlgorithmOptions {
     /// <p>The set of accepted hash algorithms allowed in a code signing job.</p>
     #[serde(rename = "allowedValues")]
-    pub allowed_values: Vec<String>,
+    pub allowed_values: Vec<HashAlgorithm>,
     /// <p>The default hash algorithm that is used in a code signing job.</p>
     #[serde(rename = "defaultValue")]
-    pub default_value: String,
+    pub default_value: HashAlgorithm,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ImageFormat {
+    /// `JSON`
+    Json,
+    /// `JSONEmbedded`
+    JSONEmbedded,
+    /// `JSONDetached`
+    JSONDetached,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ImageFormat {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ImageFormat::Json => "JSON",
+            ImageFormat::JSONEmbedded => "JSONEmbedded",
+            ImageFormat::JSONDetached => "JSONDetached",
+            ImageFormat::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ImageFormat {
+    fn default() -> ImageFormat {
+        ImageFormat::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ImageFormat {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ImageFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ImageFormat {
+    fn from(value: &'a str) -> ImageFormat {
+        match value {
+            "JSON" => ImageFormat::Json,
+            "JSONEmbedded" => ImageFormat::JSONEmbedded,
+            "JSONDetached" => ImageFormat::JSONDetached,
+            _ => ImageFormat::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ImageFormat {
+    fn from(value: String) -> ImageFormat {
+        match value.as_str() {
+            "JSON" => ImageFormat::Json,
+            "JSONEmbedded" => ImageFormat::JSONEmbedded,
+            "JSONDetached" => ImageFormat::JSONDetached,
+            _ => ImageFormat::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ImageFormat {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ImageFormat, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ImageFormat {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ImageFormat {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for ImageFormat {
+    fn deserialize<D>(deserializer: D) -> Result<ImageFormat, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ListSigningJobsRequest {
@@ -244,7 +626,7 @@ pub struct ListSigningJobsRequest {
     /// <p>A status value with which to filter your results.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<SigningStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -450,11 +832,11 @@ pub struct SigningConfigurationOverrides {
     /// <p>A specified override of the default encryption algorithm that is used in a code signing job.</p>
     #[serde(rename = "encryptionAlgorithm")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub encryption_algorithm: Option<String>,
+    pub encryption_algorithm: Option<EncryptionAlgorithm>,
     /// <p>A specified override of the default hash algorithm that is used in a code signing job.</p>
     #[serde(rename = "hashAlgorithm")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub hash_algorithm: Option<String>,
+    pub hash_algorithm: Option<HashAlgorithm>,
 }
 
 /// <p>The image format of a code signing platform or profile.</p>
@@ -463,10 +845,10 @@ pub struct SigningConfigurationOverrides {
 pub struct SigningImageFormat {
     /// <p>The default format of a code signing signing image.</p>
     #[serde(rename = "defaultFormat")]
-    pub default_format: String,
+    pub default_format: ImageFormat,
     /// <p>The supported formats of a code signing signing image.</p>
     #[serde(rename = "supportedFormats")]
-    pub supported_formats: Vec<String>,
+    pub supported_formats: Vec<ImageFormat>,
 }
 
 /// <p>Contains information about a signing job.</p>
@@ -475,8 +857,13 @@ pub struct SigningImageFormat {
 pub struct SigningJob {
     /// <p>The date and time that the signing job was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The ID of the signing job.</p>
     #[serde(rename = "jobId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -496,7 +883,7 @@ pub struct SigningJob {
     /// <p>The status of the signing job.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<SigningStatus>,
 }
 
 /// <p>The ACM certificate that is used to sign your code.</p>
@@ -514,7 +901,7 @@ pub struct SigningPlatform {
     /// <p>The category of a code signing platform.</p>
     #[serde(rename = "category")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub category: Option<String>,
+    pub category: Option<Category>,
     /// <p>The display name of a code signing platform.</p>
     #[serde(rename = "displayName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -580,13 +967,209 @@ pub struct SigningProfile {
     /// <p>The status of a code signing profile.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<SigningProfileStatus>,
     /// <p>A list of tags associated with the signing profile.</p>
     #[serde(rename = "tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SigningProfileStatus {
+    /// `Active`
+    Active,
+    /// `Canceled`
+    Canceled,
+    /// `Revoked`
+    Revoked,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SigningProfileStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SigningProfileStatus::Active => "Active",
+            SigningProfileStatus::Canceled => "Canceled",
+            SigningProfileStatus::Revoked => "Revoked",
+            SigningProfileStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SigningProfileStatus {
+    fn default() -> SigningProfileStatus {
+        SigningProfileStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SigningProfileStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SigningProfileStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SigningProfileStatus {
+    fn from(value: &'a str) -> SigningProfileStatus {
+        match value {
+            "Active" => SigningProfileStatus::Active,
+            "Canceled" => SigningProfileStatus::Canceled,
+            "Revoked" => SigningProfileStatus::Revoked,
+            _ => SigningProfileStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SigningProfileStatus {
+    fn from(value: String) -> SigningProfileStatus {
+        match value.as_str() {
+            "Active" => SigningProfileStatus::Active,
+            "Canceled" => SigningProfileStatus::Canceled,
+            "Revoked" => SigningProfileStatus::Revoked,
+            _ => SigningProfileStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SigningProfileStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SigningProfileStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SigningProfileStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SigningProfileStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SigningProfileStatus {
+    fn deserialize<D>(deserializer: D) -> Result<SigningProfileStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SigningStatus {
+    /// `InProgress`
+    InProgress,
+    /// `Failed`
+    Failed,
+    /// `Succeeded`
+    Succeeded,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SigningStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SigningStatus::InProgress => "InProgress",
+            SigningStatus::Failed => "Failed",
+            SigningStatus::Succeeded => "Succeeded",
+            SigningStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SigningStatus {
+    fn default() -> SigningStatus {
+        SigningStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SigningStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SigningStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SigningStatus {
+    fn from(value: &'a str) -> SigningStatus {
+        match value {
+            "InProgress" => SigningStatus::InProgress,
+            "Failed" => SigningStatus::Failed,
+            "Succeeded" => SigningStatus::Succeeded,
+            _ => SigningStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SigningStatus {
+    fn from(value: String) -> SigningStatus {
+        match value.as_str() {
+            "InProgress" => SigningStatus::InProgress,
+            "Failed" => SigningStatus::Failed,
+            "Succeeded" => SigningStatus::Succeeded,
+            _ => SigningStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SigningStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SigningStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SigningStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SigningStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SigningStatus {
+    fn deserialize<D>(deserializer: D) -> Result<SigningStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An <code>S3Source</code> object that contains information about the S3 bucket where you saved your unsigned code.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Source {
@@ -1288,11 +1871,12 @@ pub struct SignerClient {
 impl SignerClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> SignerClient {
         SignerClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "signer"),
         }
     }
 
@@ -1329,6 +1913,7 @@ impl Signer for SignerClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "signer", &self.region, &request_uri);
+        request.set_operation("CancelSigningProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1355,6 +1940,7 @@ impl Signer for SignerClient {
         let request_uri = format!("/signing-jobs/{job_id}", job_id = input.job_id);
 
         let mut request = SignedRequest::new("GET", "signer", &self.region, &request_uri);
+        request.set_operation("DescribeSigningJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1385,6 +1971,7 @@ impl Signer for SignerClient {
         );
 
         let mut request = SignedRequest::new("GET", "signer", &self.region, &request_uri);
+        request.set_operation("GetSigningPlatform");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1415,6 +2002,7 @@ impl Signer for SignerClient {
         );
 
         let mut request = SignedRequest::new("GET", "signer", &self.region, &request_uri);
+        request.set_operation("GetSigningProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1442,6 +2030,7 @@ impl Signer for SignerClient {
         let request_uri = "/signing-jobs";
 
         let mut request = SignedRequest::new("GET", "signer", &self.region, &request_uri);
+        request.set_operation("ListSigningJobs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1487,6 +2076,7 @@ impl Signer for SignerClient {
         let request_uri = "/signing-platforms";
 
         let mut request = SignedRequest::new("GET", "signer", &self.region, &request_uri);
+        request.set_operation("ListSigningPlatforms");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1532,6 +2122,7 @@ impl Signer for SignerClient {
         let request_uri = "/signing-profiles";
 
         let mut request = SignedRequest::new("GET", "signer", &self.region, &request_uri);
+        request.set_operation("ListSigningProfiles");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1571,6 +2162,7 @@ impl Signer for SignerClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "signer", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1601,6 +2193,7 @@ impl Signer for SignerClient {
         );
 
         let mut request = SignedRequest::new("PUT", "signer", &self.region, &request_uri);
+        request.set_operation("PutSigningProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1631,6 +2224,7 @@ impl Signer for SignerClient {
         let request_uri = "/signing-jobs";
 
         let mut request = SignedRequest::new("POST", "signer", &self.region, &request_uri);
+        request.set_operation("StartSigningJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1661,6 +2255,7 @@ impl Signer for SignerClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "signer", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1691,6 +2286,7 @@ impl Signer for SignerClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "signer", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1716,3 +2312,146 @@ impl Signer for SignerClient {
         }
     }
 }
+impl SignerClient {
+    /// Auto-paginating version of `list_signing_jobs`, streaming every page of results.
+    pub fn list_signing_jobs_pages(
+        &self,
+        input: ListSigningJobsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListSigningJobsResponse, ListSigningJobsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SignerClient, input: ListSigningJobsRequest| async move {
+                client.list_signing_jobs(input).await
+            },
+            |input: &ListSigningJobsRequest, output: &ListSigningJobsResponse| {
+                Some(ListSigningJobsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_signing_jobs`, streaming the `jobs` of every page of results.
+    pub fn list_signing_jobs_items(
+        &self,
+        input: ListSigningJobsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<SigningJob, ListSigningJobsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_signing_jobs_pages(input),
+            |output: ListSigningJobsResponse| output.jobs.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_signing_platforms`, streaming every page of results.
+    pub fn list_signing_platforms_pages(
+        &self,
+        input: ListSigningPlatformsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListSigningPlatformsResponse,
+        ListSigningPlatformsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SignerClient, input: ListSigningPlatformsRequest| async move {
+                client.list_signing_platforms(input).await
+            },
+            |input: &ListSigningPlatformsRequest, output: &ListSigningPlatformsResponse| {
+                Some(ListSigningPlatformsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_signing_platforms`, streaming the `platforms` of every page of results.
+    pub fn list_signing_platforms_items(
+        &self,
+        input: ListSigningPlatformsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<SigningPlatform, ListSigningPlatformsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_signing_platforms_pages(input),
+            |output: ListSigningPlatformsResponse| output.platforms.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_signing_profiles`, streaming every page of results.
+    pub fn list_signing_profiles_pages(
+        &self,
+        input: ListSigningProfilesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListSigningProfilesResponse, ListSigningProfilesError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SignerClient, input: ListSigningProfilesRequest| async move {
+                client.list_signing_profiles(input).await
+            },
+            |input: &ListSigningProfilesRequest, output: &ListSigningProfilesResponse| {
+                Some(ListSigningProfilesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_signing_profiles`, streaming the `profiles` of every page of results.
+    pub fn list_signing_profiles_items(
+        &self,
+        input: ListSigningProfilesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<SigningProfile, ListSigningProfilesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_signing_profiles_pages(input),
+            |output: ListSigningProfilesResponse| output.profiles.unwrap_or_default(),
+        )
+    }
+}
+impl SignerClient {
+    /// Waits until `describe_signing_job` reports the `SuccessfulSigningJob` state, polling every 20 seconds up to 25 times.
+    pub async fn wait_until_successful_signing_job(
+        &self,
+        input: DescribeSigningJobRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeSigningJobError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(20), 25);
+        self.wait_until_successful_signing_job_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_successful_signing_job`, polling as often and as many times as `config` says.
+    pub async fn wait_until_successful_signing_job_with_config(
+        &self,
+        input: DescribeSigningJobRequest,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeSigningJobError>> {
+        rusoto_core::waiter::wait(
+            "SuccessfulSigningJob",
+            &config,
+            || self.describe_signing_job(input.clone()),
+            |result: &Result<DescribeSigningJobResponse, RusotoError<DescribeSigningJobError>>| {
+                match result {
+                    Ok(output) if output.status.iter().any(|v| *v == SigningStatus::Succeeded) => {
+                        Some(rusoto_core::waiter::WaiterState::Success)
+                    }
+                    Ok(output) if output.status.iter().any(|v| *v == SigningStatus::Failed) => {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    Err(RusotoError::Service(DescribeSigningJobError::ResourceNotFound(_))) => {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    Err(RusotoError::Unknown(response))
+                        if rusoto_core::retry::error_code(response).as_deref()
+                            == Some("ResourceNotFoundException") =>
+                    {
+                        Some(rusoto_core::waiter::WaiterState::Failure)
+                    }
+                    _ => None,
+                }
+            },
+        )
+        .await
+    }
+}
@@ -24,6 +24,440 @@ use rusoto_core::signature::SignedRequest;
 #[allow(unused_imports)]
 use serde::{Deserialize, Serialize};
 use serde_json;
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AppLaunchStatus {
+    /// `READY_FOR_CONFIGURATION`
+    ReadyForConfiguration,
+    /// `CONFIGURATION_IN_PROGRESS`
+    ConfigurationInProgress,
+    /// `CONFIGURATION_INVALID`
+    ConfigurationInvalid,
+    /// `READY_FOR_LAUNCH`
+    ReadyForLaunch,
+    /// `VALIDATION_IN_PROGRESS`
+    ValidationInProgress,
+    /// `LAUNCH_PENDING`
+    LaunchPending,
+    /// `LAUNCH_IN_PROGRESS`
+    LaunchInProgress,
+    /// `LAUNCHED`
+    Launched,
+    /// `PARTIALLY_LAUNCHED`
+    PartiallyLaunched,
+    /// `DELTA_LAUNCH_IN_PROGRESS`
+    DeltaLaunchInProgress,
+    /// `DELTA_LAUNCH_FAILED`
+    DeltaLaunchFailed,
+    /// `LAUNCH_FAILED`
+    LaunchFailed,
+    /// `TERMINATE_IN_PROGRESS`
+    TerminateInProgress,
+    /// `TERMINATE_FAILED`
+    TerminateFailed,
+    /// `TERMINATED`
+    Terminated,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AppLaunchStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AppLaunchStatus::ReadyForConfiguration => "READY_FOR_CONFIGURATION",
+            AppLaunchStatus::ConfigurationInProgress => "CONFIGURATION_IN_PROGRESS",
+            AppLaunchStatus::ConfigurationInvalid => "CONFIGURATION_INVALID",
+            AppLaunchStatus::ReadyForLaunch => "READY_FOR_LAUNCH",
+            AppLaunchStatus::ValidationInProgress => "VALIDATION_IN_PROGRESS",
+            AppLaunchStatus::LaunchPending => "LAUNCH_PENDING",
+            AppLaunchStatus::LaunchInProgress => "LAUNCH_IN_PROGRESS",
+            AppLaunchStatus::Launched => "LAUNCHED",
+            AppLaunchStatus::PartiallyLaunched => "PARTIALLY_LAUNCHED",
+            AppLaunchStatus::DeltaLaunchInProgress => "DELTA_LAUNCH_IN_PROGRESS",
+            AppLaunchStatus::DeltaLaunchFailed => "DELTA_LAUNCH_FAILED",
+            AppLaunchStatus::LaunchFailed => "LAUNCH_FAILED",
+            AppLaunchStatus::TerminateInProgress => "TERMINATE_IN_PROGRESS",
+            AppLaunchStatus::TerminateFailed => "TERMINATE_FAILED",
+            AppLaunchStatus::Terminated => "TERMINATED",
+            AppLaunchStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AppLaunchStatus {
+    fn default() -> AppLaunchStatus {
+        AppLaunchStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AppLaunchStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AppLaunchStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AppLaunchStatus {
+    fn from(value: &'a str) -> AppLaunchStatus {
+        match value {
+            "READY_FOR_CONFIGURATION" => AppLaunchStatus::ReadyForConfiguration,
+            "CONFIGURATION_IN_PROGRESS" => AppLaunchStatus::ConfigurationInProgress,
+            "CONFIGURATION_INVALID" => AppLaunchStatus::ConfigurationInvalid,
+            "READY_FOR_LAUNCH" => AppLaunchStatus::ReadyForLaunch,
+            "VALIDATION_IN_PROGRESS" => AppLaunchStatus::ValidationInProgress,
+            "LAUNCH_PENDING" => AppLaunchStatus::LaunchPending,
+            "LAUNCH_IN_PROGRESS" => AppLaunchStatus::LaunchInProgress,
+            "LAUNCHED" => AppLaunchStatus::Launched,
+            "PARTIALLY_LAUNCHED" => AppLaunchStatus::PartiallyLaunched,
+            "DELTA_LAUNCH_IN_PROGRESS" => AppLaunchStatus::DeltaLaunchInProgress,
+            "DELTA_LAUNCH_FAILED" => AppLaunchStatus::DeltaLaunchFailed,
+            "LAUNCH_FAILED" => AppLaunchStatus::LaunchFailed,
+            "TERMINATE_IN_PROGRESS" => AppLaunchStatus::TerminateInProgress,
+            "TERMINATE_FAILED" => AppLaunchStatus::TerminateFailed,
+            "TERMINATED" => AppLaunchStatus::Terminated,
+            _ => AppLaunchStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AppLaunchStatus {
+    fn from(value: String) -> AppLaunchStatus {
+        match value.as_str() {
+            "READY_FOR_CONFIGURATION" => AppLaunchStatus::ReadyForConfiguration,
+            "CONFIGURATION_IN_PROGRESS" => AppLaunchStatus::ConfigurationInProgress,
+            "CONFIGURATION_INVALID" => AppLaunchStatus::ConfigurationInvalid,
+            "READY_FOR_LAUNCH" => AppLaunchStatus::ReadyForLaunch,
+            "VALIDATION_IN_PROGRESS" => AppLaunchStatus::ValidationInProgress,
+            "LAUNCH_PENDING" => AppLaunchStatus::LaunchPending,
+            "LAUNCH_IN_PROGRESS" => AppLaunchStatus::LaunchInProgress,
+            "LAUNCHED" => AppLaunchStatus::Launched,
+            "PARTIALLY_LAUNCHED" => AppLaunchStatus::PartiallyLaunched,
+            "DELTA_LAUNCH_IN_PROGRESS" => AppLaunchStatus::DeltaLaunchInProgress,
+            "DELTA_LAUNCH_FAILED" => AppLaunchStatus::DeltaLaunchFailed,
+            "LAUNCH_FAILED" => AppLaunchStatus::LaunchFailed,
+            "TERMINATE_IN_PROGRESS" => AppLaunchStatus::TerminateInProgress,
+            "TERMINATE_FAILED" => AppLaunchStatus::TerminateFailed,
+            "TERMINATED" => AppLaunchStatus::Terminated,
+            _ => AppLaunchStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AppLaunchStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AppLaunchStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AppLaunchStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AppLaunchStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AppLaunchStatus {
+    fn deserialize<D>(deserializer: D) -> Result<AppLaunchStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AppReplicationStatus {
+    /// `READY_FOR_CONFIGURATION`
+    ReadyForConfiguration,
+    /// `CONFIGURATION_IN_PROGRESS`
+    ConfigurationInProgress,
+    /// `CONFIGURATION_INVALID`
+    ConfigurationInvalid,
+    /// `READY_FOR_REPLICATION`
+    ReadyForReplication,
+    /// `VALIDATION_IN_PROGRESS`
+    ValidationInProgress,
+    /// `REPLICATION_PENDING`
+    ReplicationPending,
+    /// `REPLICATION_IN_PROGRESS`
+    ReplicationInProgress,
+    /// `REPLICATED`
+    Replicated,
+    /// `PARTIALLY_REPLICATED`
+    PartiallyReplicated,
+    /// `DELTA_REPLICATION_IN_PROGRESS`
+    DeltaReplicationInProgress,
+    /// `DELTA_REPLICATED`
+    DeltaReplicated,
+    /// `DELTA_REPLICATION_FAILED`
+    DeltaReplicationFailed,
+    /// `REPLICATION_FAILED`
+    ReplicationFailed,
+    /// `REPLICATION_STOPPING`
+    ReplicationStopping,
+    /// `REPLICATION_STOP_FAILED`
+    ReplicationStopFailed,
+    /// `REPLICATION_STOPPED`
+    ReplicationStopped,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AppReplicationStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AppReplicationStatus::ReadyForConfiguration => "READY_FOR_CONFIGURATION",
+            AppReplicationStatus::ConfigurationInProgress => "CONFIGURATION_IN_PROGRESS",
+            AppReplicationStatus::ConfigurationInvalid => "CONFIGURATION_INVALID",
+            AppReplicationStatus::ReadyForReplication => "READY_FOR_REPLICATION",
+            AppReplicationStatus::ValidationInProgress => "VALIDATION_IN_PROGRESS",
+            AppReplicationStatus::ReplicationPending => "REPLICATION_PENDING",
+            AppReplicationStatus::ReplicationInProgress => "REPLICATION_IN_PROGRESS",
+            AppReplicationStatus::Replicated => "REPLICATED",
+            AppReplicationStatus::PartiallyReplicated => "PARTIALLY_REPLICATED",
+            AppReplicationStatus::DeltaReplicationInProgress => "DELTA_REPLICATION_IN_PROGRESS",
+            AppReplicationStatus::DeltaReplicated => "DELTA_REPLICATED",
+            AppReplicationStatus::DeltaReplicationFailed => "DELTA_REPLICATION_FAILED",
+            AppReplicationStatus::ReplicationFailed => "REPLICATION_FAILED",
+            AppReplicationStatus::ReplicationStopping => "REPLICATION_STOPPING",
+            AppReplicationStatus::ReplicationStopFailed => "REPLICATION_STOP_FAILED",
+            AppReplicationStatus::ReplicationStopped => "REPLICATION_STOPPED",
+            AppReplicationStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AppReplicationStatus {
+    fn default() -> AppReplicationStatus {
+        AppReplicationStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AppReplicationStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AppReplicationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AppReplicationStatus {
+    fn from(value: &'a str) -> AppReplicationStatus {
+        match value {
+            "READY_FOR_CONFIGURATION" => AppReplicationStatus::ReadyForConfiguration,
+            "CONFIGURATION_IN_PROGRESS" => AppReplicationStatus::ConfigurationInProgress,
+            "CONFIGURATION_INVALID" => AppReplicationStatus::ConfigurationInvalid,
+            "READY_FOR_REPLICATION" => AppReplicationStatus::ReadyForReplication,
+            "VALIDATION_IN_PROGRESS" => AppReplicationStatus::ValidationInProgress,
+            "REPLICATION_PENDING" => AppReplicationStatus::ReplicationPending,
+            "REPLICATION_IN_PROGRESS" => AppReplicationStatus::ReplicationInProgress,
+            "REPLICATED" => AppReplicationStatus::Replicated,
+            "PARTIALLY_REPLICATED" => AppReplicationStatus::PartiallyReplicated,
+            "DELTA_REPLICATION_IN_PROGRESS" => AppReplicationStatus::DeltaReplicationInProgress,
+            "DELTA_REPLICATED" => AppReplicationStatus::DeltaReplicated,
+            "DELTA_REPLICATION_FAILED" => AppReplicationStatus::DeltaReplicationFailed,
+            "REPLICATION_FAILED" => AppReplicationStatus::ReplicationFailed,
+            "REPLICATION_STOPPING" => AppReplicationStatus::ReplicationStopping,
+            "REPLICATION_STOP_FAILED" => AppReplicationStatus::ReplicationStopFailed,
+            "REPLICATION_STOPPED" => AppReplicationStatus::ReplicationStopped,
+            _ => AppReplicationStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AppReplicationStatus {
+    fn from(value: String) -> AppReplicationStatus {
+        match value.as_str() {
+            "READY_FOR_CONFIGURATION" => AppReplicationStatus::ReadyForConfiguration,
+            "CONFIGURATION_IN_PROGRESS" => AppReplicationStatus::ConfigurationInProgress,
+            "CONFIGURATION_INVALID" => AppReplicationStatus::ConfigurationInvalid,
+            "READY_FOR_REPLICATION" => AppReplicationStatus::ReadyForReplication,
+            "VALIDATION_IN_PROGRESS" => AppReplicationStatus::ValidationInProgress,
+            "REPLICATION_PENDING" => AppReplicationStatus::ReplicationPending,
+            "REPLICATION_IN_PROGRESS" => AppReplicationStatus::ReplicationInProgress,
+            "REPLICATED" => AppReplicationStatus::Replicated,
+            "PARTIALLY_REPLICATED" => AppReplicationStatus::PartiallyReplicated,
+            "DELTA_REPLICATION_IN_PROGRESS" => AppReplicationStatus::DeltaReplicationInProgress,
+            "DELTA_REPLICATED" => AppReplicationStatus::DeltaReplicated,
+            "DELTA_REPLICATION_FAILED" => AppReplicationStatus::DeltaReplicationFailed,
+            "REPLICATION_FAILED" => AppReplicationStatus::ReplicationFailed,
+            "REPLICATION_STOPPING" => AppReplicationStatus::ReplicationStopping,
+            "REPLICATION_STOP_FAILED" => AppReplicationStatus::ReplicationStopFailed,
+            "REPLICATION_STOPPED" => AppReplicationStatus::ReplicationStopped,
+            _ => AppReplicationStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AppReplicationStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AppReplicationStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AppReplicationStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AppReplicationStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AppReplicationStatus {
+    fn deserialize<D>(deserializer: D) -> Result<AppReplicationStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AppStatus {
+    /// `CREATING`
+    Creating,
+    /// `ACTIVE`
+    Active,
+    /// `UPDATING`
+    Updating,
+    /// `DELETING`
+    Deleting,
+    /// `DELETED`
+    Deleted,
+    /// `DELETE_FAILED`
+    DeleteFailed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AppStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AppStatus::Creating => "CREATING",
+            AppStatus::Active => "ACTIVE",
+            AppStatus::Updating => "UPDATING",
+            AppStatus::Deleting => "DELETING",
+            AppStatus::Deleted => "DELETED",
+            AppStatus::DeleteFailed => "DELETE_FAILED",
+            AppStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AppStatus {
+    fn default() -> AppStatus {
+        AppStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AppStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AppStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AppStatus {
+    fn from(value: &'a str) -> AppStatus {
+        match value {
+            "CREATING" => AppStatus::Creating,
+            "ACTIVE" => AppStatus::Active,
+            "UPDATING" => AppStatus::Updating,
+            "DELETING" => AppStatus::Deleting,
+            "DELETED" => AppStatus::Deleted,
+            "DELETE_FAILED" => AppStatus::DeleteFailed,
+            _ => AppStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AppStatus {
+    fn from(value: String) -> AppStatus {
+        match value.as_str() {
+            "CREATING" => AppStatus::Creating,
+            "ACTIVE" => AppStatus::Active,
+            "UPDATING" => AppStatus::Updating,
+            "DELETING" => AppStatus::Deleting,
+            "DELETED" => AppStatus::Deleted,
+            "DELETE_FAILED" => AppStatus::DeleteFailed,
+            _ => AppStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AppStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AppStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AppStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AppStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AppStatus {
+    fn deserialize<D>(deserializer: D) -> Result<AppStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about the application.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -34,20 +468,35 @@ pub struct AppSummary {
     pub app_id: Option<String>,
     /// <p>Time of creation of this application.</p>
     #[serde(rename = "creationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Description of the application.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     /// <p>Timestamp of the application's creation.</p>
     #[serde(rename = "lastModified")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified: Option<f64>,
+    pub last_modified: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Timestamp of the application's most recent successful replication.</p>
     #[serde(rename = "latestReplicationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub latest_replication_time: Option<f64>,
+    pub latest_replication_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Details about the latest launch of the application.</p>
     #[serde(rename = "launchDetails")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -55,7 +504,7 @@ pub struct AppSummary {
     /// <p>Launch status of the application.</p>
     #[serde(rename = "launchStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub launch_status: Option<String>,
+    pub launch_status: Option<AppLaunchStatus>,
     /// <p>A message related to the launch status of the application.</p>
     #[serde(rename = "launchStatusMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -67,7 +516,7 @@ pub struct AppSummary {
     /// <p>Replication status of the application.</p>
     #[serde(rename = "replicationStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub replication_status: Option<String>,
+    pub replication_status: Option<AppReplicationStatus>,
     /// <p>A message related to the replication status of the application.</p>
     #[serde(rename = "replicationStatusMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -79,7 +528,7 @@ pub struct AppSummary {
     /// <p>Status of the application.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<AppStatus>,
     /// <p>A message related to the status of the application</p>
     #[serde(rename = "statusMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -100,12 +549,17 @@ pub struct AppSummary {
 pub struct Connector {
     /// <p>The time the connector was associated.</p>
     #[serde(rename = "associatedOn")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub associated_on: Option<f64>,
+    pub associated_on: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The capabilities of the connector.</p>
     #[serde(rename = "capabilityList")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub capability_list: Option<Vec<String>>,
+    pub capability_list: Option<Vec<ConnectorCapability>>,
     /// <p>The identifier of the connector.</p>
     #[serde(rename = "connectorId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -121,7 +575,7 @@ pub struct Connector {
     /// <p>The status of the connector.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ConnectorStatus>,
     /// <p>The connector version.</p>
     #[serde(rename = "version")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -137,9 +591,210 @@ pub struct Connector {
     /// <p>The VM management product.</p>
     #[serde(rename = "vmManagerType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub vm_manager_type: Option<String>,
+    pub vm_manager_type: Option<VmManagerType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ConnectorCapability {
+    /// `VSPHERE`
+    Vsphere,
+    /// `SCVMM`
+    Scvmm,
+    /// `HYPERV-MANAGER`
+    HypervManager,
+    /// `SNAPSHOT_BATCHING`
+    SnapshotBatching,
+    /// `SMS_OPTIMIZED`
+    SmsOptimized,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ConnectorCapability {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ConnectorCapability::Vsphere => "VSPHERE",
+            ConnectorCapability::Scvmm => "SCVMM",
+            ConnectorCapability::HypervManager => "HYPERV-MANAGER",
+            ConnectorCapability::SnapshotBatching => "SNAPSHOT_BATCHING",
+            ConnectorCapability::SmsOptimized => "SMS_OPTIMIZED",
+            ConnectorCapability::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ConnectorCapability {
+    fn default() -> ConnectorCapability {
+        ConnectorCapability::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ConnectorCapability {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ConnectorCapability {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ConnectorCapability {
+    fn from(value: &'a str) -> ConnectorCapability {
+        match value {
+            "VSPHERE" => ConnectorCapability::Vsphere,
+            "SCVMM" => ConnectorCapability::Scvmm,
+            "HYPERV-MANAGER" => ConnectorCapability::HypervManager,
+            "SNAPSHOT_BATCHING" => ConnectorCapability::SnapshotBatching,
+            "SMS_OPTIMIZED" => ConnectorCapability::SmsOptimized,
+            _ => ConnectorCapability::Unknown(value.to_owned()),
+        }
+    }
 }
 
+impl From<String> for ConnectorCapability {
+    fn from(value: String) -> ConnectorCapability {
+        match value.as_str() {
+            "VSPHERE" => ConnectorCapability::Vsphere,
+            "SCVMM" => ConnectorCapability::Scvmm,
+            "HYPERV-MANAGER" => ConnectorCapability::HypervManager,
+            "SNAPSHOT_BATCHING" => ConnectorCapability::SnapshotBatching,
+            "SMS_OPTIMIZED" => ConnectorCapability::SmsOptimized,
+            _ => ConnectorCapability::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ConnectorCapability {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ConnectorCapability, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ConnectorCapability {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ConnectorCapability {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ConnectorCapability {
+    fn deserialize<D>(deserializer: D) -> Result<ConnectorCapability, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ConnectorStatus {
+    /// `HEALTHY`
+    Healthy,
+    /// `UNHEALTHY`
+    Unhealthy,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ConnectorStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ConnectorStatus::Healthy => "HEALTHY",
+            ConnectorStatus::Unhealthy => "UNHEALTHY",
+            ConnectorStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ConnectorStatus {
+    fn default() -> ConnectorStatus {
+        ConnectorStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ConnectorStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ConnectorStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ConnectorStatus {
+    fn from(value: &'a str) -> ConnectorStatus {
+        match value {
+            "HEALTHY" => ConnectorStatus::Healthy,
+            "UNHEALTHY" => ConnectorStatus::Unhealthy,
+            _ => ConnectorStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ConnectorStatus {
+    fn from(value: String) -> ConnectorStatus {
+        match value.as_str() {
+            "HEALTHY" => ConnectorStatus::Healthy,
+            "UNHEALTHY" => ConnectorStatus::Unhealthy,
+            _ => ConnectorStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ConnectorStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ConnectorStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ConnectorStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ConnectorStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ConnectorStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ConnectorStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateAppRequest {
@@ -208,7 +863,7 @@ pub struct CreateReplicationJobRequest {
     /// <p>The license type to be used for the AMI created by a successful replication run.</p>
     #[serde(rename = "licenseType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub license_type: Option<String>,
+    pub license_type: Option<LicenseType>,
     /// <p>The maximum number of SMS-created AMIs to retain. The oldest will be deleted once the maximum number is reached and a new AMI is created.</p>
     #[serde(rename = "numberOfRecentAmisToKeep")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -223,7 +878,12 @@ pub struct CreateReplicationJobRequest {
     pub run_once: Option<bool>,
     /// <p>The seed replication time.</p>
     #[serde(rename = "seedReplicationTime")]
-    pub seed_replication_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub seed_replication_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The identifier of the server.</p>
     #[serde(rename = "serverId")]
     pub server_id: String,
@@ -327,7 +987,7 @@ pub struct GenerateChangeSetRequest {
     /// <p>Format for the change set.</p>
     #[serde(rename = "changesetFormat")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub changeset_format: Option<String>,
+    pub changeset_format: Option<OutputFormat>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -349,7 +1009,7 @@ pub struct GenerateTemplateRequest {
     /// <p>Format for generating the Amazon CloudFormation template.</p>
     #[serde(rename = "templateFormat")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub template_format: Option<String>,
+    pub template_format: Option<OutputFormat>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -542,8 +1202,13 @@ pub struct GetServersRequest {
 pub struct GetServersResponse {
     /// <p>The time when the server was last modified.</p>
     #[serde(rename = "lastModifiedOn")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_on: Option<f64>,
+    pub last_modified_on: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The token required to retrieve the next set of results. This value is null when there are no more results to return.</p>
     #[serde(rename = "nextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -551,7 +1216,7 @@ pub struct GetServersResponse {
     /// <p>The status of the server catalog.</p>
     #[serde(rename = "serverCatalogStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub server_catalog_status: Option<String>,
+    pub server_catalog_status: Option<ServerCatalogStatus>,
     /// <p>Information about the servers.</p>
     #[serde(rename = "serverList")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -585,8 +1250,13 @@ pub struct LaunchAppResponse {}
 pub struct LaunchDetails {
     /// <p>Latest time this application was launched successfully.</p>
     #[serde(rename = "latestLaunchTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub latest_launch_time: Option<f64>,
+    pub latest_launch_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Identifier of the latest stack launched for this application.</p>
     #[serde(rename = "stackId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -597,6 +1267,99 @@ pub struct LaunchDetails {
     pub stack_name: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LicenseType {
+    /// `AWS`
+    Aws,
+    /// `BYOL`
+    Byol,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LicenseType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LicenseType::Aws => "AWS",
+            LicenseType::Byol => "BYOL",
+            LicenseType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LicenseType {
+    fn default() -> LicenseType {
+        LicenseType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LicenseType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LicenseType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LicenseType {
+    fn from(value: &'a str) -> LicenseType {
+        match value {
+            "AWS" => LicenseType::Aws,
+            "BYOL" => LicenseType::Byol,
+            _ => LicenseType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LicenseType {
+    fn from(value: String) -> LicenseType {
+        match value.as_str() {
+            "AWS" => LicenseType::Aws,
+            "BYOL" => LicenseType::Byol,
+            _ => LicenseType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LicenseType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LicenseType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LicenseType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LicenseType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LicenseType {
+    fn deserialize<D>(deserializer: D) -> Result<LicenseType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ListAppsRequest {
@@ -627,6 +1390,99 @@ pub struct ListAppsResponse {
     pub next_token: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OutputFormat {
+    /// `JSON`
+    Json,
+    /// `YAML`
+    Yaml,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl OutputFormat {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OutputFormat::Json => "JSON",
+            OutputFormat::Yaml => "YAML",
+            OutputFormat::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for OutputFormat {
+    fn default() -> OutputFormat {
+        OutputFormat::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for OutputFormat {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for OutputFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for OutputFormat {
+    fn from(value: &'a str) -> OutputFormat {
+        match value {
+            "JSON" => OutputFormat::Json,
+            "YAML" => OutputFormat::Yaml,
+            _ => OutputFormat::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OutputFormat {
+    fn from(value: String) -> OutputFormat {
+        match value.as_str() {
+            "JSON" => OutputFormat::Json,
+            "YAML" => OutputFormat::Yaml,
+            _ => OutputFormat::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for OutputFormat {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<OutputFormat, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OutputFormat {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for OutputFormat {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OutputFormat {
+    fn deserialize<D>(deserializer: D) -> Result<OutputFormat, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct PutAppLaunchConfigurationRequest {
@@ -692,11 +1548,16 @@ pub struct ReplicationJob {
     /// <p>The license type to be used for the AMI created by a successful replication run.</p>
     #[serde(rename = "licenseType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub license_type: Option<String>,
+    pub license_type: Option<LicenseType>,
     /// <p>The start time of the next replication run.</p>
     #[serde(rename = "nextReplicationRunStartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_replication_run_start_time: Option<f64>,
+    pub next_replication_run_start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Number of recent AMIs to keep in the customer's account for a replication job. By default the value is set to zero, meaning that all AMIs are kept.</p>
     #[serde(rename = "numberOfRecentAmisToKeep")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -719,8 +1580,13 @@ pub struct ReplicationJob {
     pub run_once: Option<bool>,
     /// <p>The seed replication time.</p>
     #[serde(rename = "seedReplicationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub seed_replication_time: Option<f64>,
+    pub seed_replication_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The identifier of the server.</p>
     #[serde(rename = "serverId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -728,11 +1594,11 @@ pub struct ReplicationJob {
     /// <p>The type of server.</p>
     #[serde(rename = "serverType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub server_type: Option<String>,
+    pub server_type: Option<ServerType>,
     /// <p>The state of the replication job.</p>
     #[serde(rename = "state")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<ReplicationJobState>,
     /// <p>The description of the current status of the replication job.</p>
     #[serde(rename = "statusMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -743,6 +1609,129 @@ pub struct ReplicationJob {
     pub vm_server: Option<VmServer>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ReplicationJobState {
+    /// `PENDING`
+    Pending,
+    /// `ACTIVE`
+    Active,
+    /// `FAILED`
+    Failed,
+    /// `DELETING`
+    Deleting,
+    /// `DELETED`
+    Deleted,
+    /// `COMPLETED`
+    Completed,
+    /// `PAUSED_ON_FAILURE`
+    PausedOnFailure,
+    /// `FAILING`
+    Failing,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ReplicationJobState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReplicationJobState::Pending => "PENDING",
+            ReplicationJobState::Active => "ACTIVE",
+            ReplicationJobState::Failed => "FAILED",
+            ReplicationJobState::Deleting => "DELETING",
+            ReplicationJobState::Deleted => "DELETED",
+            ReplicationJobState::Completed => "COMPLETED",
+            ReplicationJobState::PausedOnFailure => "PAUSED_ON_FAILURE",
+            ReplicationJobState::Failing => "FAILING",
+            ReplicationJobState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ReplicationJobState {
+    fn default() -> ReplicationJobState {
+        ReplicationJobState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ReplicationJobState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ReplicationJobState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ReplicationJobState {
+    fn from(value: &'a str) -> ReplicationJobState {
+        match value {
+            "PENDING" => ReplicationJobState::Pending,
+            "ACTIVE" => ReplicationJobState::Active,
+            "FAILED" => ReplicationJobState::Failed,
+            "DELETING" => ReplicationJobState::Deleting,
+            "DELETED" => ReplicationJobState::Deleted,
+            "COMPLETED" => ReplicationJobState::Completed,
+            "PAUSED_ON_FAILURE" => ReplicationJobState::PausedOnFailure,
+            "FAILING" => ReplicationJobState::Failing,
+            _ => ReplicationJobState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReplicationJobState {
+    fn from(value: String) -> ReplicationJobState {
+        match value.as_str() {
+            "PENDING" => ReplicationJobState::Pending,
+            "ACTIVE" => ReplicationJobState::Active,
+            "FAILED" => ReplicationJobState::Failed,
+            "DELETING" => ReplicationJobState::Deleting,
+            "DELETED" => ReplicationJobState::Deleted,
+            "COMPLETED" => ReplicationJobState::Completed,
+            "PAUSED_ON_FAILURE" => ReplicationJobState::PausedOnFailure,
+            "FAILING" => ReplicationJobState::Failing,
+            _ => ReplicationJobState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ReplicationJobState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ReplicationJobState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ReplicationJobState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ReplicationJobState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ReplicationJobState {
+    fn deserialize<D>(deserializer: D) -> Result<ReplicationJobState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents a replication run.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -753,8 +1742,13 @@ pub struct ReplicationRun {
     pub ami_id: Option<String>,
     /// <p>The completion time of the last replication run.</p>
     #[serde(rename = "completedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub completed_time: Option<f64>,
+    pub completed_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The description of the replication run.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -773,8 +1767,13 @@ pub struct ReplicationRun {
     pub replication_run_id: Option<String>,
     /// <p>The start time of the next replication run.</p>
     #[serde(rename = "scheduledStartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scheduled_start_time: Option<f64>,
+    pub scheduled_start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Details of the current stage of the replication run.</p>
     #[serde(rename = "stageDetails")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -782,7 +1781,7 @@ pub struct ReplicationRun {
     /// <p>The state of the replication run.</p>
     #[serde(rename = "state")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<ReplicationRunState>,
     /// <p>The description of the current status of the replication job.</p>
     #[serde(rename = "statusMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -790,7 +1789,7 @@ pub struct ReplicationRun {
     /// <p>The type of replication run.</p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<ReplicationRunType>,
 }
 
 /// <p>Details of the current stage of a replication run.</p>
@@ -807,6 +1806,217 @@ pub struct ReplicationRunStageDetails {
     pub stage_progress: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ReplicationRunState {
+    /// `PENDING`
+    Pending,
+    /// `MISSED`
+    Missed,
+    /// `ACTIVE`
+    Active,
+    /// `FAILED`
+    Failed,
+    /// `COMPLETED`
+    Completed,
+    /// `DELETING`
+    Deleting,
+    /// `DELETED`
+    Deleted,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ReplicationRunState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReplicationRunState::Pending => "PENDING",
+            ReplicationRunState::Missed => "MISSED",
+            ReplicationRunState::Active => "ACTIVE",
+            ReplicationRunState::Failed => "FAILED",
+            ReplicationRunState::Completed => "COMPLETED",
+            ReplicationRunState::Deleting => "DELETING",
+            ReplicationRunState::Deleted => "DELETED",
+            ReplicationRunState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ReplicationRunState {
+    fn default() -> ReplicationRunState {
+        ReplicationRunState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ReplicationRunState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ReplicationRunState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ReplicationRunState {
+    fn from(value: &'a str) -> ReplicationRunState {
+        match value {
+            "PENDING" => ReplicationRunState::Pending,
+            "MISSED" => ReplicationRunState::Missed,
+            "ACTIVE" => ReplicationRunState::Active,
+            "FAILED" => ReplicationRunState::Failed,
+            "COMPLETED" => ReplicationRunState::Completed,
+            "DELETING" => ReplicationRunState::Deleting,
+            "DELETED" => ReplicationRunState::Deleted,
+            _ => ReplicationRunState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReplicationRunState {
+    fn from(value: String) -> ReplicationRunState {
+        match value.as_str() {
+            "PENDING" => ReplicationRunState::Pending,
+            "MISSED" => ReplicationRunState::Missed,
+            "ACTIVE" => ReplicationRunState::Active,
+            "FAILED" => ReplicationRunState::Failed,
+            "COMPLETED" => ReplicationRunState::Completed,
+            "DELETING" => ReplicationRunState::Deleting,
+            "DELETED" => ReplicationRunState::Deleted,
+            _ => ReplicationRunState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ReplicationRunState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ReplicationRunState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ReplicationRunState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ReplicationRunState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ReplicationRunState {
+    fn deserialize<D>(deserializer: D) -> Result<ReplicationRunState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ReplicationRunType {
+    /// `ON_DEMAND`
+    OnDemand,
+    /// `AUTOMATIC`
+    Automatic,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ReplicationRunType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReplicationRunType::OnDemand => "ON_DEMAND",
+            ReplicationRunType::Automatic => "AUTOMATIC",
+            ReplicationRunType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ReplicationRunType {
+    fn default() -> ReplicationRunType {
+        ReplicationRunType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ReplicationRunType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ReplicationRunType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ReplicationRunType {
+    fn from(value: &'a str) -> ReplicationRunType {
+        match value {
+            "ON_DEMAND" => ReplicationRunType::OnDemand,
+            "AUTOMATIC" => ReplicationRunType::Automatic,
+            _ => ReplicationRunType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReplicationRunType {
+    fn from(value: String) -> ReplicationRunType {
+        match value.as_str() {
+            "ON_DEMAND" => ReplicationRunType::OnDemand,
+            "AUTOMATIC" => ReplicationRunType::Automatic,
+            _ => ReplicationRunType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ReplicationRunType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ReplicationRunType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ReplicationRunType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ReplicationRunType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ReplicationRunType {
+    fn deserialize<D>(deserializer: D) -> Result<ReplicationRunType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Location of the Amazon S3 object in the customer's account.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct S3Location {
@@ -838,13 +2048,121 @@ pub struct Server {
     /// <p>The type of server.</p>
     #[serde(rename = "serverType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub server_type: Option<String>,
+    pub server_type: Option<ServerType>,
     /// <p>Information about the VM server.</p>
     #[serde(rename = "vmServer")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub vm_server: Option<VmServer>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ServerCatalogStatus {
+    /// `NOT_IMPORTED`
+    NotImported,
+    /// `IMPORTING`
+    Importing,
+    /// `AVAILABLE`
+    Available,
+    /// `DELETED`
+    Deleted,
+    /// `EXPIRED`
+    Expired,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ServerCatalogStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ServerCatalogStatus::NotImported => "NOT_IMPORTED",
+            ServerCatalogStatus::Importing => "IMPORTING",
+            ServerCatalogStatus::Available => "AVAILABLE",
+            ServerCatalogStatus::Deleted => "DELETED",
+            ServerCatalogStatus::Expired => "EXPIRED",
+            ServerCatalogStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ServerCatalogStatus {
+    fn default() -> ServerCatalogStatus {
+        ServerCatalogStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ServerCatalogStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ServerCatalogStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ServerCatalogStatus {
+    fn from(value: &'a str) -> ServerCatalogStatus {
+        match value {
+            "NOT_IMPORTED" => ServerCatalogStatus::NotImported,
+            "IMPORTING" => ServerCatalogStatus::Importing,
+            "AVAILABLE" => ServerCatalogStatus::Available,
+            "DELETED" => ServerCatalogStatus::Deleted,
+            "EXPIRED" => ServerCatalogStatus::Expired,
+            _ => ServerCatalogStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ServerCatalogStatus {
+    fn from(value: String) -> ServerCatalogStatus {
+        match value.as_str() {
+            "NOT_IMPORTED" => ServerCatalogStatus::NotImported,
+            "IMPORTING" => ServerCatalogStatus::Importing,
+            "AVAILABLE" => ServerCatalogStatus::Available,
+            "DELETED" => ServerCatalogStatus::Deleted,
+            "EXPIRED" => ServerCatalogStatus::Expired,
+            _ => ServerCatalogStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ServerCatalogStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ServerCatalogStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ServerCatalogStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ServerCatalogStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ServerCatalogStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ServerCatalogStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A logical grouping of servers.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ServerGroup {
@@ -964,7 +2282,7 @@ pub struct ServerReplicationParameters {
     /// <p>License type for creating a replication job for the server.</p>
     #[serde(rename = "licenseType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub license_type: Option<String>,
+    pub license_type: Option<LicenseType>,
     /// <p>Number of recent AMIs to keep when creating a replication job for this server.</p>
     #[serde(rename = "numberOfRecentAmisToKeep")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -975,10 +2293,103 @@ pub struct ServerReplicationParameters {
     pub run_once: Option<bool>,
     /// <p>Seed time for creating a replication job for the server.</p>
     #[serde(rename = "seedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub seed_time: Option<f64>,
+    pub seed_time: Option<::chrono::DateTime<::chrono::Utc>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ServerType {
+    /// `VIRTUAL_MACHINE`
+    VirtualMachine,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ServerType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ServerType::VirtualMachine => "VIRTUAL_MACHINE",
+            ServerType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ServerType {
+    fn default() -> ServerType {
+        ServerType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ServerType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ServerType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ServerType {
+    fn from(value: &'a str) -> ServerType {
+        match value {
+            "VIRTUAL_MACHINE" => ServerType::VirtualMachine,
+            _ => ServerType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ServerType {
+    fn from(value: String) -> ServerType {
+        match value.as_str() {
+            "VIRTUAL_MACHINE" => ServerType::VirtualMachine,
+            _ => ServerType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ServerType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ServerType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ServerType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ServerType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for ServerType {
+    fn deserialize<D>(deserializer: D) -> Result<ServerType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct StartAppReplicationRequest {
@@ -1120,11 +2531,16 @@ pub struct UpdateReplicationJobRequest {
     /// <p>The license type to be used for the AMI created by a successful replication run.</p>
     #[serde(rename = "licenseType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub license_type: Option<String>,
+    pub license_type: Option<LicenseType>,
     /// <p>The start time of the next replication run.</p>
     #[serde(rename = "nextReplicationRunStartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_replication_run_start_time: Option<f64>,
+    pub next_replication_run_start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The maximum number of SMS-created AMIs to retain. The oldest will be deleted once the maximum number is reached and a new AMI is created.</p>
     #[serde(rename = "numberOfRecentAmisToKeep")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1151,6 +2567,104 @@ pub struct UserData {
     pub s_3_location: Option<S3Location>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum VmManagerType {
+    /// `VSPHERE`
+    Vsphere,
+    /// `SCVMM`
+    Scvmm,
+    /// `HYPERV-MANAGER`
+    HypervManager,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl VmManagerType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            VmManagerType::Vsphere => "VSPHERE",
+            VmManagerType::Scvmm => "SCVMM",
+            VmManagerType::HypervManager => "HYPERV-MANAGER",
+            VmManagerType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for VmManagerType {
+    fn default() -> VmManagerType {
+        VmManagerType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for VmManagerType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for VmManagerType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for VmManagerType {
+    fn from(value: &'a str) -> VmManagerType {
+        match value {
+            "VSPHERE" => VmManagerType::Vsphere,
+            "SCVMM" => VmManagerType::Scvmm,
+            "HYPERV-MANAGER" => VmManagerType::HypervManager,
+            _ => VmManagerType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for VmManagerType {
+    fn from(value: String) -> VmManagerType {
+        match value.as_str() {
+            "VSPHERE" => VmManagerType::Vsphere,
+            "SCVMM" => VmManagerType::Scvmm,
+            "HYPERV-MANAGER" => VmManagerType::HypervManager,
+            _ => VmManagerType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for VmManagerType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<VmManagerType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for VmManagerType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for VmManagerType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for VmManagerType {
+    fn deserialize<D>(deserializer: D) -> Result<VmManagerType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents a VM server.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct VmServer {
@@ -1161,7 +2675,7 @@ pub struct VmServer {
     /// <p>The type of VM management product.</p>
     #[serde(rename = "vmManagerType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub vm_manager_type: Option<String>,
+    pub vm_manager_type: Option<VmManagerType>,
     /// <p>The name of the VM.</p>
     #[serde(rename = "vmName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3097,11 +4611,12 @@ pub struct ServerMigrationServiceClient {
 impl ServerMigrationServiceClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> ServerMigrationServiceClient {
         ServerMigrationServiceClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "SMS"),
         }
     }
 
@@ -3133,6 +4648,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         input: CreateAppRequest,
     ) -> Result<CreateAppResponse, RusotoError<CreateAppError>> {
         let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("CreateApp");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3163,6 +4679,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         input: CreateReplicationJobRequest,
     ) -> Result<CreateReplicationJobResponse, RusotoError<CreateReplicationJobError>> {
         let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("CreateReplicationJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3194,6 +4711,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         input: DeleteAppRequest,
     ) -> Result<DeleteAppResponse, RusotoError<DeleteAppError>> {
         let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("DeleteApp");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3225,6 +4743,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
     ) -> Result<DeleteAppLaunchConfigurationResponse, RusotoError<DeleteAppLaunchConfigurationError>>
     {
         let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("DeleteAppLaunchConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3259,6 +4778,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         RusotoError<DeleteAppReplicationConfigurationError>,
     > {
         let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("DeleteAppReplicationConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3292,6 +4812,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         input: DeleteReplicationJobRequest,
     ) -> Result<DeleteReplicationJobResponse, RusotoError<DeleteReplicationJobError>> {
         let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("DeleteReplicationJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3322,6 +4843,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         &self,
     ) -> Result<DeleteServerCatalogResponse, RusotoError<DeleteServerCatalogError>> {
         let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("DeleteServerCatalog");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3352,6 +4874,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         input: DisassociateConnectorRequest,
     ) -> Result<DisassociateConnectorResponse, RusotoError<DisassociateConnectorError>> {
         let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("DisassociateConnector");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3383,6 +4906,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         input: GenerateChangeSetRequest,
     ) -> Result<GenerateChangeSetResponse, RusotoError<GenerateChangeSetError>> {
         let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("GenerateChangeSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3414,6 +4938,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         input: GenerateTemplateRequest,
     ) -> Result<GenerateTemplateResponse, RusotoError<GenerateTemplateError>> {
         let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("GenerateTemplate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3445,6 +4970,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         input: GetAppRequest,
     ) -> Result<GetAppResponse, RusotoError<GetAppError>> {
         let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("GetApp");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3476,6 +5002,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
     ) -> Result<GetAppLaunchConfigurationResponse, RusotoError<GetAppLaunchConfigurationError>>
     {
         let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("GetAppLaunchConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3510,6 +5037,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         RusotoError<GetAppReplicationConfigurationError>,
     > {
         let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("GetAppReplicationConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3541,6 +5069,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         input: GetConnectorsRequest,
     ) -> Result<GetConnectorsResponse, RusotoError<GetConnectorsError>> {
         let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("GetConnectors");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3571,6 +5100,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         input: GetReplicationJobsRequest,
     ) -> Result<GetReplicationJobsResponse, RusotoError<GetReplicationJobsError>> {
         let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("GetReplicationJobs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3602,6 +5132,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         input: GetReplicationRunsRequest,
     ) -> Result<GetReplicationRunsResponse, RusotoError<GetReplicationRunsError>> {
         let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("GetReplicationRuns");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3633,6 +5164,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         input: GetServersRequest,
     ) -> Result<GetServersResponse, RusotoError<GetServersError>> {
         let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("GetServers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3662,6 +5194,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         &self,
     ) -> Result<ImportServerCatalogResponse, RusotoError<ImportServerCatalogError>> {
         let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("ImportServerCatalog");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3692,6 +5225,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         input: LaunchAppRequest,
     ) -> Result<LaunchAppResponse, RusotoError<LaunchAppError>> {
         let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("LaunchApp");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3722,6 +5256,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         input: ListAppsRequest,
     ) -> Result<ListAppsResponse, RusotoError<ListAppsError>> {
         let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("ListApps");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3753,6 +5288,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
     ) -> Result<PutAppLaunchConfigurationResponse, RusotoError<PutAppLaunchConfigurationError>>
     {
         let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("PutAppLaunchConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3787,6 +5323,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         RusotoError<PutAppReplicationConfigurationError>,
     > {
         let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("PutAppReplicationConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3818,6 +5355,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         input: StartAppReplicationRequest,
     ) -> Result<StartAppReplicationResponse, RusotoError<StartAppReplicationError>> {
         let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("StartAppReplication");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3850,6 +5388,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
     ) -> Result<StartOnDemandReplicationRunResponse, RusotoError<StartOnDemandReplicationRunError>>
     {
         let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("StartOnDemandReplicationRun");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3881,6 +5420,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         input: StopAppReplicationRequest,
     ) -> Result<StopAppReplicationResponse, RusotoError<StopAppReplicationError>> {
         let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("StopAppReplication");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3912,6 +5452,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         input: TerminateAppRequest,
     ) -> Result<TerminateAppResponse, RusotoError<TerminateAppError>> {
         let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("TerminateApp");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3942,6 +5483,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         input: UpdateAppRequest,
     ) -> Result<UpdateAppResponse, RusotoError<UpdateAppError>> {
         let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("UpdateApp");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3972,6 +5514,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         input: UpdateReplicationJobRequest,
     ) -> Result<UpdateReplicationJobResponse, RusotoError<UpdateReplicationJobError>> {
         let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("UpdateReplicationJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3997,3 +5540,161 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         }
     }
 }
+impl ServerMigrationServiceClient {
+    /// Auto-paginating version of `get_connectors`, streaming every page of results.
+    pub fn get_connectors_pages(
+        &self,
+        input: GetConnectorsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetConnectorsResponse, GetConnectorsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ServerMigrationServiceClient, input: GetConnectorsRequest| async move {
+                client.get_connectors(input).await
+            },
+            |input: &GetConnectorsRequest, output: &GetConnectorsResponse| {
+                Some(GetConnectorsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_connectors`, streaming the `connectorList` of every page of results.
+    pub fn get_connectors_items(
+        &self,
+        input: GetConnectorsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Connector, GetConnectorsError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_connectors_pages(input),
+            |output: GetConnectorsResponse| output.connector_list.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_replication_jobs`, streaming every page of results.
+    pub fn get_replication_jobs_pages(
+        &self,
+        input: GetReplicationJobsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetReplicationJobsResponse, GetReplicationJobsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ServerMigrationServiceClient, input: GetReplicationJobsRequest| async move {
+                client.get_replication_jobs(input).await
+            },
+            |input: &GetReplicationJobsRequest, output: &GetReplicationJobsResponse| {
+                Some(GetReplicationJobsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_replication_jobs`, streaming the `replicationJobList` of every page of results.
+    pub fn get_replication_jobs_items(
+        &self,
+        input: GetReplicationJobsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ReplicationJob, GetReplicationJobsError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_replication_jobs_pages(input),
+            |output: GetReplicationJobsResponse| output.replication_job_list.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_replication_runs`, streaming every page of results.
+    pub fn get_replication_runs_pages(
+        &self,
+        input: GetReplicationRunsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetReplicationRunsResponse, GetReplicationRunsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ServerMigrationServiceClient, input: GetReplicationRunsRequest| async move {
+                client.get_replication_runs(input).await
+            },
+            |input: &GetReplicationRunsRequest, output: &GetReplicationRunsResponse| {
+                Some(GetReplicationRunsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_replication_runs`, streaming the `replicationRunList` of every page of results.
+    pub fn get_replication_runs_items(
+        &self,
+        input: GetReplicationRunsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ReplicationRun, GetReplicationRunsError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_replication_runs_pages(input),
+            |output: GetReplicationRunsResponse| output.replication_run_list.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_servers`, streaming every page of results.
+    pub fn get_servers_pages(
+        &self,
+        input: GetServersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetServersResponse, GetServersError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ServerMigrationServiceClient, input: GetServersRequest| async move {
+                client.get_servers(input).await
+            },
+            |input: &GetServersRequest, output: &GetServersResponse| {
+                Some(GetServersRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_servers`, streaming the `serverList` of every page of results.
+    pub fn get_servers_items(
+        &self,
+        input: GetServersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Server, GetServersError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_servers_pages(input),
+            |output: GetServersResponse| output.server_list.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_apps`, streaming every page of results.
+    pub fn list_apps_pages(
+        &self,
+        input: ListAppsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListAppsResponse, ListAppsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ServerMigrationServiceClient, input: ListAppsRequest| async move {
+                client.list_apps(input).await
+            },
+            |input: &ListAppsRequest, output: &ListAppsResponse| {
+                Some(ListAppsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_apps`, streaming the `apps` of every page of results.
+    pub fn list_apps_items(
+        &self,
+        input: ListAppsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<AppSummary, ListAppsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_apps_pages(input),
+            |output: ListAppsResponse| output.apps.unwrap_or_default(),
+        )
+    }
+}
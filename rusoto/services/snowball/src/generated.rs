@@ -120,11 +120,16 @@ pub struct ClusterListEntry {
     /// <p>The current state of this cluster. For information about the state of a specific node, see <a>JobListEntry$JobState</a>.</p>
     #[serde(rename = "ClusterState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub cluster_state: Option<String>,
+    pub cluster_state: Option<ClusterState>,
     /// <p>The creation date for this cluster.</p>
     #[serde(rename = "CreationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Defines an optional description of the cluster, for example <code>Environmental Data Cluster-01</code>.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -146,11 +151,16 @@ pub struct ClusterMetadata {
     /// <p>The current status of the cluster.</p>
     #[serde(rename = "ClusterState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub cluster_state: Option<String>,
+    pub cluster_state: Option<ClusterState>,
     /// <p>The creation date for this cluster.</p>
     #[serde(rename = "CreationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The optional description of the cluster.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -162,7 +172,7 @@ pub struct ClusterMetadata {
     /// <p>The type of job for this cluster. Currently, the only job type supported for clusters is <code>LOCAL_USE</code>.</p>
     #[serde(rename = "JobType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_type: Option<String>,
+    pub job_type: Option<JobType>,
     /// <p>The <code>KmsKeyARN</code> Amazon Resource Name (ARN) associated with this cluster. This ARN was created using the <a href="https://docs.aws.amazon.com/kms/latest/APIReference/API_CreateKey.html">CreateKey</a> API action in AWS Key Management Service (AWS KMS).</p>
     #[serde(rename = "KmsKeyARN")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -182,13 +192,121 @@ pub struct ClusterMetadata {
     /// <p><p>The shipping speed for each node in this cluster. This speed doesn&#39;t dictate how soon you&#39;ll get each Snowball Edge device, rather it represents how quickly each device moves to its destination while in transit. Regional shipping speeds are as follows:</p> <ul> <li> <p>In Australia, you have access to express shipping. Typically, devices shipped express are delivered in about a day.</p> </li> <li> <p>In the European Union (EU), you have access to express shipping. Typically, Snowball Edges shipped express are delivered in about a day. In addition, most countries in the EU have access to standard shipping, which typically takes less than a week, one way.</p> </li> <li> <p>In India, Snowball Edges are delivered in one to seven days.</p> </li> <li> <p>In the US, you have access to one-day shipping and two-day shipping.</p> </li> </ul></p>
     #[serde(rename = "ShippingOption")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub shipping_option: Option<String>,
+    pub shipping_option: Option<ShippingOption>,
     /// <p>The type of AWS Snowball device to use for this cluster. Currently, the only supported device type for cluster jobs is <code>EDGE</code>.</p>
     #[serde(rename = "SnowballType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub snowball_type: Option<String>,
+    pub snowball_type: Option<SnowballType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ClusterState {
+    /// `AwaitingQuorum`
+    AwaitingQuorum,
+    /// `Pending`
+    Pending,
+    /// `InUse`
+    InUse,
+    /// `Complete`
+    Complete,
+    /// `Cancelled`
+    Cancelled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ClusterState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ClusterState::AwaitingQuorum => "AwaitingQuorum",
+            ClusterState::Pending => "Pending",
+            ClusterState::InUse => "InUse",
+            ClusterState::Complete => "Complete",
+            ClusterState::Cancelled => "Cancelled",
+            ClusterState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ClusterState {
+    fn default() -> ClusterState {
+        ClusterState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ClusterState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
 }
 
+impl fmt::Display for ClusterState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ClusterState {
+    fn from(value: &'a str) -> ClusterState {
+        match value {
+            "AwaitingQuorum" => ClusterState::AwaitingQuorum,
+            "Pending" => ClusterState::Pending,
+            "InUse" => ClusterState::InUse,
+            "Complete" => ClusterState::Complete,
+            "Cancelled" => ClusterState::Cancelled,
+            _ => ClusterState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ClusterState {
+    fn from(value: String) -> ClusterState {
+        match value.as_str() {
+            "AwaitingQuorum" => ClusterState::AwaitingQuorum,
+            "Pending" => ClusterState::Pending,
+            "InUse" => ClusterState::InUse,
+            "Complete" => ClusterState::Complete,
+            "Cancelled" => ClusterState::Cancelled,
+            _ => ClusterState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ClusterState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ClusterState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ClusterState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ClusterState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ClusterState {
+    fn deserialize<D>(deserializer: D) -> Result<ClusterState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A JSON-formatted object that describes a compatible Amazon Machine Image (AMI), including the ID and name for a Snowball Edge AMI. This AMI is compatible with the device's physical hardware requirements, and it should be able to be run in an SBE1 instance on the device.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -236,7 +354,7 @@ pub struct CreateClusterRequest {
     pub forwarding_address_id: Option<String>,
     /// <p>The type of job for this cluster. Currently, the only job type supported for clusters is <code>LOCAL_USE</code>.</p>
     #[serde(rename = "JobType")]
-    pub job_type: String,
+    pub job_type: JobType,
     /// <p>The <code>KmsKeyARN</code> value that you want to associate with this cluster. <code>KmsKeyARN</code> values are created by using the <a href="https://docs.aws.amazon.com/kms/latest/APIReference/API_CreateKey.html">CreateKey</a> API action in AWS Key Management Service (AWS KMS). </p>
     #[serde(rename = "KmsKeyARN")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -253,11 +371,11 @@ pub struct CreateClusterRequest {
     pub role_arn: String,
     /// <p><p>The shipping speed for each node in this cluster. This speed doesn&#39;t dictate how soon you&#39;ll get each Snowball Edge device, rather it represents how quickly each device moves to its destination while in transit. Regional shipping speeds are as follows:</p> <ul> <li> <p>In Australia, you have access to express shipping. Typically, devices shipped express are delivered in about a day.</p> </li> <li> <p>In the European Union (EU), you have access to express shipping. Typically, Snowball Edges shipped express are delivered in about a day. In addition, most countries in the EU have access to standard shipping, which typically takes less than a week, one way.</p> </li> <li> <p>In India, Snowball Edges are delivered in one to seven days.</p> </li> <li> <p>In the US, you have access to one-day shipping and two-day shipping.</p> </li> </ul></p>
     #[serde(rename = "ShippingOption")]
-    pub shipping_option: String,
+    pub shipping_option: ShippingOption,
     /// <p>The type of AWS Snowball device to use for this cluster. Currently, the only supported device type for cluster jobs is <code>EDGE</code>.</p>
     #[serde(rename = "SnowballType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub snowball_type: Option<String>,
+    pub snowball_type: Option<SnowballType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -291,7 +409,7 @@ pub struct CreateJobRequest {
     /// <p>Defines the type of job that you're creating. </p>
     #[serde(rename = "JobType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_type: Option<String>,
+    pub job_type: Option<JobType>,
     /// <p>The <code>KmsKeyARN</code> that you want to associate with this job. <code>KmsKeyARN</code>s are created using the <a href="https://docs.aws.amazon.com/kms/latest/APIReference/API_CreateKey.html">CreateKey</a> AWS Key Management Service (KMS) API action.</p>
     #[serde(rename = "KmsKeyARN")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -311,15 +429,15 @@ pub struct CreateJobRequest {
     /// <p><p>The shipping speed for this job. This speed doesn&#39;t dictate how soon you&#39;ll get the Snowball, rather it represents how quickly the Snowball moves to its destination while in transit. Regional shipping speeds are as follows:</p> <ul> <li> <p>In Australia, you have access to express shipping. Typically, Snowballs shipped express are delivered in about a day.</p> </li> <li> <p>In the European Union (EU), you have access to express shipping. Typically, Snowballs shipped express are delivered in about a day. In addition, most countries in the EU have access to standard shipping, which typically takes less than a week, one way.</p> </li> <li> <p>In India, Snowballs are delivered in one to seven days.</p> </li> <li> <p>In the US, you have access to one-day shipping and two-day shipping.</p> </li> </ul></p>
     #[serde(rename = "ShippingOption")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub shipping_option: Option<String>,
+    pub shipping_option: Option<ShippingOption>,
     /// <p>If your job is being created in one of the US regions, you have the option of specifying what size Snowball you'd like for this job. In all other regions, Snowballs come with 80 TB in storage capacity.</p>
     #[serde(rename = "SnowballCapacityPreference")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub snowball_capacity_preference: Option<String>,
+    pub snowball_capacity_preference: Option<SnowballCapacity>,
     /// <p>The type of AWS Snowball device to use for this job. Currently, the only supported device type for cluster jobs is <code>EDGE</code>.</p>
     #[serde(rename = "SnowballType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub snowball_type: Option<String>,
+    pub snowball_type: Option<SnowballType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -529,8 +647,13 @@ pub struct GetSoftwareUpdatesResult {
 pub struct JobListEntry {
     /// <p>The creation date for this job.</p>
     #[serde(rename = "CreationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The optional description of this specific job, for example <code>Important Photos 2016-08-11</code>.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -546,15 +669,15 @@ pub struct JobListEntry {
     /// <p>The current state of this job.</p>
     #[serde(rename = "JobState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_state: Option<String>,
+    pub job_state: Option<JobState>,
     /// <p>The type of job.</p>
     #[serde(rename = "JobType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_type: Option<String>,
+    pub job_type: Option<JobType>,
     /// <p>The type of device used with this job.</p>
     #[serde(rename = "SnowballType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub snowball_type: Option<String>,
+    pub snowball_type: Option<SnowballType>,
 }
 
 /// <p>Contains job logs. Whenever Snowball is used to import data into or export data out of Amazon S3, you'll have the option of downloading a PDF job report. Job logs are returned as a part of the response syntax of the <code>DescribeJob</code> action in the <code>JobMetadata</code> data type. The job logs can be accessed for up to 60 minutes after this request has been made. To access any of the job logs after 60 minutes have passed, you'll have to make another call to the <code>DescribeJob</code> action.</p> <p>For import jobs, the PDF job report becomes available at the end of the import process. For export jobs, your job report typically becomes available while the Snowball for your job part is being delivered to you.</p> <p>The job report provides you insight into the state of your Amazon S3 data transfer. The report includes details about your job or job part for your records.</p> <p>For deeper visibility into the status of your transferred objects, you can look at the two associated logs: a success log and a failure log. The logs are saved in comma-separated value (CSV) format, and the name of each log includes the ID of the job or job part that the log describes.</p>
@@ -589,8 +712,13 @@ pub struct JobMetadata {
     pub cluster_id: Option<String>,
     /// <p>The creation date for this job.</p>
     #[serde(rename = "CreationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A value that defines the real-time status of a Snowball's data transfer while the device is at AWS. This data is only available while a job has a <code>JobState</code> value of <code>InProgress</code>, for both import and export jobs.</p>
     #[serde(rename = "DataTransferProgress")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -614,11 +742,11 @@ pub struct JobMetadata {
     /// <p>The current status of the jobs.</p>
     #[serde(rename = "JobState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_state: Option<String>,
+    pub job_state: Option<JobState>,
     /// <p>The type of job.</p>
     #[serde(rename = "JobType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_type: Option<String>,
+    pub job_type: Option<JobType>,
     /// <p>The Amazon Resource Name (ARN) for the AWS Key Management Service (AWS KMS) key associated with this job. This ARN was created using the <a href="https://docs.aws.amazon.com/kms/latest/APIReference/API_CreateKey.html">CreateKey</a> API action in AWS KMS.</p>
     #[serde(rename = "KmsKeyARN")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -642,11 +770,11 @@ pub struct JobMetadata {
     /// <p>The Snowball capacity preference for this job, specified at job creation. In US regions, you can choose between 50 TB and 80 TB Snowballs. All other regions use 80 TB capacity Snowballs.</p>
     #[serde(rename = "SnowballCapacityPreference")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub snowball_capacity_preference: Option<String>,
+    pub snowball_capacity_preference: Option<SnowballCapacity>,
     /// <p>The type of device used with this job.</p>
     #[serde(rename = "SnowballType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub snowball_type: Option<String>,
+    pub snowball_type: Option<SnowballType>,
 }
 
 /// <p>Contains an array of AWS resource objects. Each object represents an Amazon S3 bucket, an AWS Lambda function, or an Amazon Machine Image (AMI) based on Amazon EC2 that is associated with a particular job.</p>
@@ -666,6 +794,252 @@ pub struct JobResource {
     pub s3_resources: Option<Vec<S3Resource>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum JobState {
+    /// `New`
+    New,
+    /// `PreparingAppliance`
+    PreparingAppliance,
+    /// `PreparingShipment`
+    PreparingShipment,
+    /// `InTransitToCustomer`
+    InTransitToCustomer,
+    /// `WithCustomer`
+    WithCustomer,
+    /// `InTransitToAWS`
+    InTransitToAWS,
+    /// `WithAWSSortingFacility`
+    WithAWSSortingFacility,
+    /// `WithAWS`
+    WithAWS,
+    /// `InProgress`
+    InProgress,
+    /// `Complete`
+    Complete,
+    /// `Cancelled`
+    Cancelled,
+    /// `Listing`
+    Listing,
+    /// `Pending`
+    Pending,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl JobState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            JobState::New => "New",
+            JobState::PreparingAppliance => "PreparingAppliance",
+            JobState::PreparingShipment => "PreparingShipment",
+            JobState::InTransitToCustomer => "InTransitToCustomer",
+            JobState::WithCustomer => "WithCustomer",
+            JobState::InTransitToAWS => "InTransitToAWS",
+            JobState::WithAWSSortingFacility => "WithAWSSortingFacility",
+            JobState::WithAWS => "WithAWS",
+            JobState::InProgress => "InProgress",
+            JobState::Complete => "Complete",
+            JobState::Cancelled => "Cancelled",
+            JobState::Listing => "Listing",
+            JobState::Pending => "Pending",
+            JobState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for JobState {
+    fn default() -> JobState {
+        JobState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for JobState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for JobState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for JobState {
+    fn from(value: &'a str) -> JobState {
+        match value {
+            "New" => JobState::New,
+            "PreparingAppliance" => JobState::PreparingAppliance,
+            "PreparingShipment" => JobState::PreparingShipment,
+            "InTransitToCustomer" => JobState::InTransitToCustomer,
+            "WithCustomer" => JobState::WithCustomer,
+            "InTransitToAWS" => JobState::InTransitToAWS,
+            "WithAWSSortingFacility" => JobState::WithAWSSortingFacility,
+            "WithAWS" => JobState::WithAWS,
+            "InProgress" => JobState::InProgress,
+            "Complete" => JobState::Complete,
+            "Cancelled" => JobState::Cancelled,
+            "Listing" => JobState::Listing,
+            "Pending" => JobState::Pending,
+            _ => JobState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for JobState {
+    fn from(value: String) -> JobState {
+        match value.as_str() {
+            "New" => JobState::New,
+            "PreparingAppliance" => JobState::PreparingAppliance,
+            "PreparingShipment" => JobState::PreparingShipment,
+            "InTransitToCustomer" => JobState::InTransitToCustomer,
+            "WithCustomer" => JobState::WithCustomer,
+            "InTransitToAWS" => JobState::InTransitToAWS,
+            "WithAWSSortingFacility" => JobState::WithAWSSortingFacility,
+            "WithAWS" => JobState::WithAWS,
+            "InProgress" => JobState::InProgress,
+            "Complete" => JobState::Complete,
+            "Cancelled" => JobState::Cancelled,
+            "Listing" => JobState::Listing,
+            "Pending" => JobState::Pending,
+            _ => JobState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for JobState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<JobState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for JobState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for JobState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for JobState {
+    fn deserialize<D>(deserializer: D) -> Result<JobState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum JobType {
+    /// `IMPORT`
+    Import,
+    /// `EXPORT`
+    Export,
+    /// `LOCAL_USE`
+    LocalUse,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl JobType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            JobType::Import => "IMPORT",
+            JobType::Export => "EXPORT",
+            JobType::LocalUse => "LOCAL_USE",
+            JobType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for JobType {
+    fn default() -> JobType {
+        JobType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for JobType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for JobType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for JobType {
+    fn from(value: &'a str) -> JobType {
+        match value {
+            "IMPORT" => JobType::Import,
+            "EXPORT" => JobType::Export,
+            "LOCAL_USE" => JobType::LocalUse,
+            _ => JobType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for JobType {
+    fn from(value: String) -> JobType {
+        match value.as_str() {
+            "IMPORT" => JobType::Import,
+            "EXPORT" => JobType::Export,
+            "LOCAL_USE" => JobType::LocalUse,
+            _ => JobType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for JobType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<JobType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for JobType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for JobType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for JobType {
+    fn deserialize<D>(deserializer: D) -> Result<JobType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Contains a key range. For export jobs, a <code>S3Resource</code> object can have an optional <code>KeyRange</code> value. The length of the range is defined at job creation, and has either an inclusive <code>BeginMarker</code>, an inclusive <code>EndMarker</code>, or both. Ranges are UTF-8 binary sorted.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct KeyRange {
@@ -805,7 +1179,7 @@ pub struct Notification {
     /// <p>The list of job states that will trigger a notification for this job.</p>
     #[serde(rename = "JobStatesToNotify")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_states_to_notify: Option<Vec<String>>,
+    pub job_states_to_notify: Option<Vec<JobState>>,
     /// <p>Any change in job state will trigger a notification for this job.</p>
     #[serde(rename = "NotifyAll")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -858,9 +1232,383 @@ pub struct ShippingDetails {
     /// <p><p>The shipping speed for a particular job. This speed doesn&#39;t dictate how soon you&#39;ll get the Snowball from the job&#39;s creation date. This speed represents how quickly it moves to its destination while in transit. Regional shipping speeds are as follows:</p> <ul> <li> <p>In Australia, you have access to express shipping. Typically, Snowballs shipped express are delivered in about a day.</p> </li> <li> <p>In the European Union (EU), you have access to express shipping. Typically, Snowballs shipped express are delivered in about a day. In addition, most countries in the EU have access to standard shipping, which typically takes less than a week, one way.</p> </li> <li> <p>In India, Snowballs are delivered in one to seven days.</p> </li> <li> <p>In the United States of America (US), you have access to one-day shipping and two-day shipping.</p> </li> </ul></p>
     #[serde(rename = "ShippingOption")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub shipping_option: Option<String>,
+    pub shipping_option: Option<ShippingOption>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ShippingOption {
+    /// `SECOND_DAY`
+    SecondDay,
+    /// `NEXT_DAY`
+    NextDay,
+    /// `EXPRESS`
+    Express,
+    /// `STANDARD`
+    Standard,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ShippingOption {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ShippingOption::SecondDay => "SECOND_DAY",
+            ShippingOption::NextDay => "NEXT_DAY",
+            ShippingOption::Express => "EXPRESS",
+            ShippingOption::Standard => "STANDARD",
+            ShippingOption::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ShippingOption {
+    fn default() -> ShippingOption {
+        ShippingOption::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ShippingOption {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ShippingOption {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ShippingOption {
+    fn from(value: &'a str) -> ShippingOption {
+        match value {
+            "SECOND_DAY" => ShippingOption::SecondDay,
+            "NEXT_DAY" => ShippingOption::NextDay,
+            "EXPRESS" => ShippingOption::Express,
+            "STANDARD" => ShippingOption::Standard,
+            _ => ShippingOption::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ShippingOption {
+    fn from(value: String) -> ShippingOption {
+        match value.as_str() {
+            "SECOND_DAY" => ShippingOption::SecondDay,
+            "NEXT_DAY" => ShippingOption::NextDay,
+            "EXPRESS" => ShippingOption::Express,
+            "STANDARD" => ShippingOption::Standard,
+            _ => ShippingOption::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ShippingOption {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ShippingOption, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ShippingOption {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ShippingOption {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ShippingOption {
+    fn deserialize<D>(deserializer: D) -> Result<ShippingOption, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SnowballCapacity {
+    /// `T50`
+    T50,
+    /// `T80`
+    T80,
+    /// `T100`
+    T100,
+    /// `T42`
+    T42,
+    /// `T98`
+    T98,
+    /// `T8`
+    T8,
+    /// `T14`
+    T14,
+    /// `T32`
+    T32,
+    /// `NoPreference`
+    NoPreference,
+    /// `T240`
+    T240,
+    /// `T13`
+    T13,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SnowballCapacity {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SnowballCapacity::T50 => "T50",
+            SnowballCapacity::T80 => "T80",
+            SnowballCapacity::T100 => "T100",
+            SnowballCapacity::T42 => "T42",
+            SnowballCapacity::T98 => "T98",
+            SnowballCapacity::T8 => "T8",
+            SnowballCapacity::T14 => "T14",
+            SnowballCapacity::T32 => "T32",
+            SnowballCapacity::NoPreference => "NoPreference",
+            SnowballCapacity::T240 => "T240",
+            SnowballCapacity::T13 => "T13",
+            SnowballCapacity::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SnowballCapacity {
+    fn default() -> SnowballCapacity {
+        SnowballCapacity::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SnowballCapacity {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SnowballCapacity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SnowballCapacity {
+    fn from(value: &'a str) -> SnowballCapacity {
+        match value {
+            "T50" => SnowballCapacity::T50,
+            "T80" => SnowballCapacity::T80,
+            "T100" => SnowballCapacity::T100,
+            "T42" => SnowballCapacity::T42,
+            "T98" => SnowballCapacity::T98,
+            "T8" => SnowballCapacity::T8,
+            "T14" => SnowballCapacity::T14,
+            "T32" => SnowballCapacity::T32,
+            "NoPreference" => SnowballCapacity::NoPreference,
+            "T240" => SnowballCapacity::T240,
+            "T13" => SnowballCapacity::T13,
+            _ => SnowballCapacity::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SnowballCapacity {
+    fn from(value: String) -> SnowballCapacity {
+        match value.as_str() {
+            "T50" => SnowballCapacity::T50,
+            "T80" => SnowballCapacity::T80,
+            "T100" => SnowballCapacity::T100,
+            "T42" => SnowballCapacity::T42,
+            "T98" => SnowballCapacity::T98,
+            "T8" => SnowballCapacity::T8,
+            "T14" => SnowballCapacity::T14,
+            "T32" => SnowballCapacity::T32,
+            "NoPreference" => SnowballCapacity::NoPreference,
+            "T240" => SnowballCapacity::T240,
+            "T13" => SnowballCapacity::T13,
+            _ => SnowballCapacity::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SnowballCapacity {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SnowballCapacity, Self::Err> {
+        Ok(value.into())
+    }
 }
 
+impl ::rusoto_core::param::ToParam for SnowballCapacity {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SnowballCapacity {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SnowballCapacity {
+    fn deserialize<D>(deserializer: D) -> Result<SnowballCapacity, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SnowballType {
+    /// `STANDARD`
+    Standard,
+    /// `EDGE`
+    Edge,
+    /// `EDGE_C`
+    EdgeC,
+    /// `EDGE_CG`
+    EdgeCg,
+    /// `EDGE_S`
+    EdgeS,
+    /// `SNC1_HDD`
+    Snc1Hdd,
+    /// `SNC1_SSD`
+    Snc1Ssd,
+    /// `V3_5C`
+    V3_5c,
+    /// `V3_5S`
+    V3_5s,
+    /// `RACK_5U_C`
+    Rack5uC,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SnowballType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SnowballType::Standard => "STANDARD",
+            SnowballType::Edge => "EDGE",
+            SnowballType::EdgeC => "EDGE_C",
+            SnowballType::EdgeCg => "EDGE_CG",
+            SnowballType::EdgeS => "EDGE_S",
+            SnowballType::Snc1Hdd => "SNC1_HDD",
+            SnowballType::Snc1Ssd => "SNC1_SSD",
+            SnowballType::V3_5c => "V3_5C",
+            SnowballType::V3_5s => "V3_5S",
+            SnowballType::Rack5uC => "RACK_5U_C",
+            SnowballType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SnowballType {
+    fn default() -> SnowballType {
+        SnowballType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SnowballType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SnowballType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SnowballType {
+    fn from(value: &'a str) -> SnowballType {
+        match value {
+            "STANDARD" => SnowballType::Standard,
+            "EDGE" => SnowballType::Edge,
+            "EDGE_C" => SnowballType::EdgeC,
+            "EDGE_CG" => SnowballType::EdgeCg,
+            "EDGE_S" => SnowballType::EdgeS,
+            "SNC1_HDD" => SnowballType::Snc1Hdd,
+            "SNC1_SSD" => SnowballType::Snc1Ssd,
+            "V3_5C" => SnowballType::V3_5c,
+            "V3_5S" => SnowballType::V3_5s,
+            "RACK_5U_C" => SnowballType::Rack5uC,
+            _ => SnowballType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SnowballType {
+    fn from(value: String) -> SnowballType {
+        match value.as_str() {
+            "STANDARD" => SnowballType::Standard,
+            "EDGE" => SnowballType::Edge,
+            "EDGE_C" => SnowballType::EdgeC,
+            "EDGE_CG" => SnowballType::EdgeCg,
+            "EDGE_S" => SnowballType::EdgeS,
+            "SNC1_HDD" => SnowballType::Snc1Hdd,
+            "SNC1_SSD" => SnowballType::Snc1Ssd,
+            "V3_5C" => SnowballType::V3_5c,
+            "V3_5S" => SnowballType::V3_5s,
+            "RACK_5U_C" => SnowballType::Rack5uC,
+            _ => SnowballType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SnowballType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SnowballType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SnowballType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SnowballType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SnowballType {
+    fn deserialize<D>(deserializer: D) -> Result<SnowballType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct UpdateClusterRequest {
@@ -894,7 +1642,7 @@ pub struct UpdateClusterRequest {
     /// <p>The updated shipping option value of this cluster's <a>ShippingDetails</a> object.</p>
     #[serde(rename = "ShippingOption")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub shipping_option: Option<String>,
+    pub shipping_option: Option<ShippingOption>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -934,11 +1682,11 @@ pub struct UpdateJobRequest {
     /// <p>The updated shipping option value of this job's <a>ShippingDetails</a> object.</p>
     #[serde(rename = "ShippingOption")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub shipping_option: Option<String>,
+    pub shipping_option: Option<ShippingOption>,
     /// <p>The updated <code>SnowballCapacityPreference</code> of this job's <a>JobMetadata</a> object. The 50 TB Snowballs are only available in the US regions.</p>
     #[serde(rename = "SnowballCapacityPreference")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub snowball_capacity_preference: Option<String>,
+    pub snowball_capacity_preference: Option<SnowballCapacity>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1803,11 +2551,12 @@ pub struct SnowballClient {
 impl SnowballClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> SnowballClient {
         SnowballClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Snowball"),
         }
     }
 
@@ -1839,6 +2588,7 @@ impl Snowball for SnowballClient {
         input: CancelClusterRequest,
     ) -> Result<CancelClusterResult, RusotoError<CancelClusterError>> {
         let mut request = SignedRequest::new("POST", "snowball", &self.region, "/");
+        request.set_operation("CancelCluster");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1869,6 +2619,7 @@ impl Snowball for SnowballClient {
         input: CancelJobRequest,
     ) -> Result<CancelJobResult, RusotoError<CancelJobError>> {
         let mut request = SignedRequest::new("POST", "snowball", &self.region, "/");
+        request.set_operation("CancelJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1899,6 +2650,7 @@ impl Snowball for SnowballClient {
         input: CreateAddressRequest,
     ) -> Result<CreateAddressResult, RusotoError<CreateAddressError>> {
         let mut request = SignedRequest::new("POST", "snowball", &self.region, "/");
+        request.set_operation("CreateAddress");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1929,6 +2681,7 @@ impl Snowball for SnowballClient {
         input: CreateClusterRequest,
     ) -> Result<CreateClusterResult, RusotoError<CreateClusterError>> {
         let mut request = SignedRequest::new("POST", "snowball", &self.region, "/");
+        request.set_operation("CreateCluster");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1959,6 +2712,7 @@ impl Snowball for SnowballClient {
         input: CreateJobRequest,
     ) -> Result<CreateJobResult, RusotoError<CreateJobError>> {
         let mut request = SignedRequest::new("POST", "snowball", &self.region, "/");
+        request.set_operation("CreateJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1989,6 +2743,7 @@ impl Snowball for SnowballClient {
         input: DescribeAddressRequest,
     ) -> Result<DescribeAddressResult, RusotoError<DescribeAddressError>> {
         let mut request = SignedRequest::new("POST", "snowball", &self.region, "/");
+        request.set_operation("DescribeAddress");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2019,6 +2774,7 @@ impl Snowball for SnowballClient {
         input: DescribeAddressesRequest,
     ) -> Result<DescribeAddressesResult, RusotoError<DescribeAddressesError>> {
         let mut request = SignedRequest::new("POST", "snowball", &self.region, "/");
+        request.set_operation("DescribeAddresses");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2049,6 +2805,7 @@ impl Snowball for SnowballClient {
         input: DescribeClusterRequest,
     ) -> Result<DescribeClusterResult, RusotoError<DescribeClusterError>> {
         let mut request = SignedRequest::new("POST", "snowball", &self.region, "/");
+        request.set_operation("DescribeCluster");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2079,6 +2836,7 @@ impl Snowball for SnowballClient {
         input: DescribeJobRequest,
     ) -> Result<DescribeJobResult, RusotoError<DescribeJobError>> {
         let mut request = SignedRequest::new("POST", "snowball", &self.region, "/");
+        request.set_operation("DescribeJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2109,6 +2867,7 @@ impl Snowball for SnowballClient {
         input: GetJobManifestRequest,
     ) -> Result<GetJobManifestResult, RusotoError<GetJobManifestError>> {
         let mut request = SignedRequest::new("POST", "snowball", &self.region, "/");
+        request.set_operation("GetJobManifest");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2139,6 +2898,7 @@ impl Snowball for SnowballClient {
         input: GetJobUnlockCodeRequest,
     ) -> Result<GetJobUnlockCodeResult, RusotoError<GetJobUnlockCodeError>> {
         let mut request = SignedRequest::new("POST", "snowball", &self.region, "/");
+        request.set_operation("GetJobUnlockCode");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2168,6 +2928,7 @@ impl Snowball for SnowballClient {
         &self,
     ) -> Result<GetSnowballUsageResult, RusotoError<GetSnowballUsageError>> {
         let mut request = SignedRequest::new("POST", "snowball", &self.region, "/");
+        request.set_operation("GetSnowballUsage");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2197,6 +2958,7 @@ impl Snowball for SnowballClient {
         input: GetSoftwareUpdatesRequest,
     ) -> Result<GetSoftwareUpdatesResult, RusotoError<GetSoftwareUpdatesError>> {
         let mut request = SignedRequest::new("POST", "snowball", &self.region, "/");
+        request.set_operation("GetSoftwareUpdates");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2228,6 +2990,7 @@ impl Snowball for SnowballClient {
         input: ListClusterJobsRequest,
     ) -> Result<ListClusterJobsResult, RusotoError<ListClusterJobsError>> {
         let mut request = SignedRequest::new("POST", "snowball", &self.region, "/");
+        request.set_operation("ListClusterJobs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2258,6 +3021,7 @@ impl Snowball for SnowballClient {
         input: ListClustersRequest,
     ) -> Result<ListClustersResult, RusotoError<ListClustersError>> {
         let mut request = SignedRequest::new("POST", "snowball", &self.region, "/");
+        request.set_operation("ListClusters");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2288,6 +3052,7 @@ impl Snowball for SnowballClient {
         input: ListCompatibleImagesRequest,
     ) -> Result<ListCompatibleImagesResult, RusotoError<ListCompatibleImagesError>> {
         let mut request = SignedRequest::new("POST", "snowball", &self.region, "/");
+        request.set_operation("ListCompatibleImages");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2319,6 +3084,7 @@ impl Snowball for SnowballClient {
         input: ListJobsRequest,
     ) -> Result<ListJobsResult, RusotoError<ListJobsError>> {
         let mut request = SignedRequest::new("POST", "snowball", &self.region, "/");
+        request.set_operation("ListJobs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSIESnowballJobManagementService.ListJobs");
@@ -2346,6 +3112,7 @@ impl Snowball for SnowballClient {
         input: UpdateClusterRequest,
     ) -> Result<UpdateClusterResult, RusotoError<UpdateClusterError>> {
         let mut request = SignedRequest::new("POST", "snowball", &self.region, "/");
+        request.set_operation("UpdateCluster");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2376,6 +3143,7 @@ impl Snowball for SnowballClient {
         input: UpdateJobRequest,
     ) -> Result<UpdateJobResult, RusotoError<UpdateJobError>> {
         let mut request = SignedRequest::new("POST", "snowball", &self.region, "/");
+        request.set_operation("UpdateJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2400,3 +3168,161 @@ impl Snowball for SnowballClient {
         }
     }
 }
+impl SnowballClient {
+    /// Auto-paginating version of `describe_addresses`, streaming every page of results.
+    pub fn describe_addresses_pages(
+        &self,
+        input: DescribeAddressesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeAddressesResult, DescribeAddressesError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SnowballClient, input: DescribeAddressesRequest| async move {
+                client.describe_addresses(input).await
+            },
+            |input: &DescribeAddressesRequest, output: &DescribeAddressesResult| {
+                Some(DescribeAddressesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_addresses`, streaming the `Addresses` of every page of results.
+    pub fn describe_addresses_items(
+        &self,
+        input: DescribeAddressesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Address, DescribeAddressesError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_addresses_pages(input),
+            |output: DescribeAddressesResult| output.addresses.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_cluster_jobs`, streaming every page of results.
+    pub fn list_cluster_jobs_pages(
+        &self,
+        input: ListClusterJobsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListClusterJobsResult, ListClusterJobsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SnowballClient, input: ListClusterJobsRequest| async move {
+                client.list_cluster_jobs(input).await
+            },
+            |input: &ListClusterJobsRequest, output: &ListClusterJobsResult| {
+                Some(ListClusterJobsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_cluster_jobs`, streaming the `JobListEntries` of every page of results.
+    pub fn list_cluster_jobs_items(
+        &self,
+        input: ListClusterJobsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<JobListEntry, ListClusterJobsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_cluster_jobs_pages(input),
+            |output: ListClusterJobsResult| output.job_list_entries.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_clusters`, streaming every page of results.
+    pub fn list_clusters_pages(
+        &self,
+        input: ListClustersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListClustersResult, ListClustersError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SnowballClient, input: ListClustersRequest| async move {
+                client.list_clusters(input).await
+            },
+            |input: &ListClustersRequest, output: &ListClustersResult| {
+                Some(ListClustersRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_clusters`, streaming the `ClusterListEntries` of every page of results.
+    pub fn list_clusters_items(
+        &self,
+        input: ListClustersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ClusterListEntry, ListClustersError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_clusters_pages(input),
+            |output: ListClustersResult| output.cluster_list_entries.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_compatible_images`, streaming every page of results.
+    pub fn list_compatible_images_pages(
+        &self,
+        input: ListCompatibleImagesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListCompatibleImagesResult, ListCompatibleImagesError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SnowballClient, input: ListCompatibleImagesRequest| async move {
+                client.list_compatible_images(input).await
+            },
+            |input: &ListCompatibleImagesRequest, output: &ListCompatibleImagesResult| {
+                Some(ListCompatibleImagesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_compatible_images`, streaming the `CompatibleImages` of every page of results.
+    pub fn list_compatible_images_items(
+        &self,
+        input: ListCompatibleImagesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<CompatibleImage, ListCompatibleImagesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_compatible_images_pages(input),
+            |output: ListCompatibleImagesResult| output.compatible_images.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_jobs`, streaming every page of results.
+    pub fn list_jobs_pages(
+        &self,
+        input: ListJobsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListJobsResult, ListJobsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SnowballClient, input: ListJobsRequest| async move {
+                client.list_jobs(input).await
+            },
+            |input: &ListJobsRequest, output: &ListJobsResult| {
+                Some(ListJobsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_jobs`, streaming the `JobListEntries` of every page of results.
+    pub fn list_jobs_items(
+        &self,
+        input: ListJobsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<JobListEntry, ListJobsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_jobs_pages(input),
+            |output: ListJobsResult| output.job_list_entries.unwrap_or_default(),
+        )
+    }
+}
@@ -5047,11 +5047,12 @@ pub struct SnsClient {
 impl SnsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> SnsClient {
         SnsClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "SNS"),
         }
     }
 
@@ -5083,6 +5084,7 @@ impl Sns for SnsClient {
         input: AddPermissionInput,
     ) -> Result<(), RusotoError<AddPermissionError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("AddPermission");
         let mut params = Params::new();
 
         params.put("Action", "AddPermission");
@@ -5112,6 +5114,7 @@ impl Sns for SnsClient {
     ) -> Result<CheckIfPhoneNumberIsOptedOutResponse, RusotoError<CheckIfPhoneNumberIsOptedOutError>>
     {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("CheckIfPhoneNumberIsOptedOut");
         let mut params = Params::new();
 
         params.put("Action", "CheckIfPhoneNumberIsOptedOut");
@@ -5161,6 +5164,7 @@ impl Sns for SnsClient {
         input: ConfirmSubscriptionInput,
     ) -> Result<ConfirmSubscriptionResponse, RusotoError<ConfirmSubscriptionError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("ConfirmSubscription");
         let mut params = Params::new();
 
         params.put("Action", "ConfirmSubscription");
@@ -5211,6 +5215,7 @@ impl Sns for SnsClient {
     ) -> Result<CreatePlatformApplicationResponse, RusotoError<CreatePlatformApplicationError>>
     {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("CreatePlatformApplication");
         let mut params = Params::new();
 
         params.put("Action", "CreatePlatformApplication");
@@ -5260,6 +5265,7 @@ impl Sns for SnsClient {
         input: CreatePlatformEndpointInput,
     ) -> Result<CreateEndpointResponse, RusotoError<CreatePlatformEndpointError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("CreatePlatformEndpoint");
         let mut params = Params::new();
 
         params.put("Action", "CreatePlatformEndpoint");
@@ -5309,6 +5315,7 @@ impl Sns for SnsClient {
         input: CreateTopicInput,
     ) -> Result<CreateTopicResponse, RusotoError<CreateTopicError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("CreateTopic");
         let mut params = Params::new();
 
         params.put("Action", "CreateTopic");
@@ -5355,6 +5362,7 @@ impl Sns for SnsClient {
         input: DeleteEndpointInput,
     ) -> Result<(), RusotoError<DeleteEndpointError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("DeleteEndpoint");
         let mut params = Params::new();
 
         params.put("Action", "DeleteEndpoint");
@@ -5383,6 +5391,7 @@ impl Sns for SnsClient {
         input: DeletePlatformApplicationInput,
     ) -> Result<(), RusotoError<DeletePlatformApplicationError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("DeletePlatformApplication");
         let mut params = Params::new();
 
         params.put("Action", "DeletePlatformApplication");
@@ -5411,6 +5420,7 @@ impl Sns for SnsClient {
         input: DeleteTopicInput,
     ) -> Result<(), RusotoError<DeleteTopicError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("DeleteTopic");
         let mut params = Params::new();
 
         params.put("Action", "DeleteTopic");
@@ -5439,6 +5449,7 @@ impl Sns for SnsClient {
         input: GetEndpointAttributesInput,
     ) -> Result<GetEndpointAttributesResponse, RusotoError<GetEndpointAttributesError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("GetEndpointAttributes");
         let mut params = Params::new();
 
         params.put("Action", "GetEndpointAttributes");
@@ -5491,6 +5502,7 @@ impl Sns for SnsClient {
         RusotoError<GetPlatformApplicationAttributesError>,
     > {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("GetPlatformApplicationAttributes");
         let mut params = Params::new();
 
         params.put("Action", "GetPlatformApplicationAttributes");
@@ -5542,6 +5554,7 @@ impl Sns for SnsClient {
         input: GetSMSAttributesInput,
     ) -> Result<GetSMSAttributesResponse, RusotoError<GetSMSAttributesError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("GetSMSAttributes");
         let mut params = Params::new();
 
         params.put("Action", "GetSMSAttributes");
@@ -5592,6 +5605,7 @@ impl Sns for SnsClient {
     ) -> Result<GetSubscriptionAttributesResponse, RusotoError<GetSubscriptionAttributesError>>
     {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("GetSubscriptionAttributes");
         let mut params = Params::new();
 
         params.put("Action", "GetSubscriptionAttributes");
@@ -5641,6 +5655,7 @@ impl Sns for SnsClient {
         input: GetTopicAttributesInput,
     ) -> Result<GetTopicAttributesResponse, RusotoError<GetTopicAttributesError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("GetTopicAttributes");
         let mut params = Params::new();
 
         params.put("Action", "GetTopicAttributes");
@@ -5693,6 +5708,7 @@ impl Sns for SnsClient {
         RusotoError<ListEndpointsByPlatformApplicationError>,
     > {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("ListEndpointsByPlatformApplication");
         let mut params = Params::new();
 
         params.put("Action", "ListEndpointsByPlatformApplication");
@@ -5744,6 +5760,7 @@ impl Sns for SnsClient {
         input: ListPhoneNumbersOptedOutInput,
     ) -> Result<ListPhoneNumbersOptedOutResponse, RusotoError<ListPhoneNumbersOptedOutError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("ListPhoneNumbersOptedOut");
         let mut params = Params::new();
 
         params.put("Action", "ListPhoneNumbersOptedOut");
@@ -5793,6 +5810,7 @@ impl Sns for SnsClient {
         input: ListPlatformApplicationsInput,
     ) -> Result<ListPlatformApplicationsResponse, RusotoError<ListPlatformApplicationsError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("ListPlatformApplications");
         let mut params = Params::new();
 
         params.put("Action", "ListPlatformApplications");
@@ -5842,6 +5860,7 @@ impl Sns for SnsClient {
         input: ListSubscriptionsInput,
     ) -> Result<ListSubscriptionsResponse, RusotoError<ListSubscriptionsError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("ListSubscriptions");
         let mut params = Params::new();
 
         params.put("Action", "ListSubscriptions");
@@ -5891,6 +5910,7 @@ impl Sns for SnsClient {
         input: ListSubscriptionsByTopicInput,
     ) -> Result<ListSubscriptionsByTopicResponse, RusotoError<ListSubscriptionsByTopicError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("ListSubscriptionsByTopic");
         let mut params = Params::new();
 
         params.put("Action", "ListSubscriptionsByTopic");
@@ -5940,6 +5960,7 @@ impl Sns for SnsClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("ListTagsForResource");
         let mut params = Params::new();
 
         params.put("Action", "ListTagsForResource");
@@ -5989,6 +6010,7 @@ impl Sns for SnsClient {
         input: ListTopicsInput,
     ) -> Result<ListTopicsResponse, RusotoError<ListTopicsError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("ListTopics");
         let mut params = Params::new();
 
         params.put("Action", "ListTopics");
@@ -6035,6 +6057,7 @@ impl Sns for SnsClient {
         input: OptInPhoneNumberInput,
     ) -> Result<OptInPhoneNumberResponse, RusotoError<OptInPhoneNumberError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("OptInPhoneNumber");
         let mut params = Params::new();
 
         params.put("Action", "OptInPhoneNumber");
@@ -6084,6 +6107,7 @@ impl Sns for SnsClient {
         input: PublishInput,
     ) -> Result<PublishResponse, RusotoError<PublishError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("Publish");
         let mut params = Params::new();
 
         params.put("Action", "Publish");
@@ -6130,6 +6154,7 @@ impl Sns for SnsClient {
         input: RemovePermissionInput,
     ) -> Result<(), RusotoError<RemovePermissionError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("RemovePermission");
         let mut params = Params::new();
 
         params.put("Action", "RemovePermission");
@@ -6158,6 +6183,7 @@ impl Sns for SnsClient {
         input: SetEndpointAttributesInput,
     ) -> Result<(), RusotoError<SetEndpointAttributesError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("SetEndpointAttributes");
         let mut params = Params::new();
 
         params.put("Action", "SetEndpointAttributes");
@@ -6186,6 +6212,7 @@ impl Sns for SnsClient {
         input: SetPlatformApplicationAttributesInput,
     ) -> Result<(), RusotoError<SetPlatformApplicationAttributesError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("SetPlatformApplicationAttributes");
         let mut params = Params::new();
 
         params.put("Action", "SetPlatformApplicationAttributes");
@@ -6216,6 +6243,7 @@ impl Sns for SnsClient {
         input: SetSMSAttributesInput,
     ) -> Result<SetSMSAttributesResponse, RusotoError<SetSMSAttributesError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("SetSMSAttributes");
         let mut params = Params::new();
 
         params.put("Action", "SetSMSAttributes");
@@ -6265,6 +6293,7 @@ impl Sns for SnsClient {
         input: SetSubscriptionAttributesInput,
     ) -> Result<(), RusotoError<SetSubscriptionAttributesError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("SetSubscriptionAttributes");
         let mut params = Params::new();
 
         params.put("Action", "SetSubscriptionAttributes");
@@ -6293,6 +6322,7 @@ impl Sns for SnsClient {
         input: SetTopicAttributesInput,
     ) -> Result<(), RusotoError<SetTopicAttributesError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("SetTopicAttributes");
         let mut params = Params::new();
 
         params.put("Action", "SetTopicAttributes");
@@ -6321,6 +6351,7 @@ impl Sns for SnsClient {
         input: SubscribeInput,
     ) -> Result<SubscribeResponse, RusotoError<SubscribeError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("Subscribe");
         let mut params = Params::new();
 
         params.put("Action", "Subscribe");
@@ -6367,6 +6398,7 @@ impl Sns for SnsClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("TagResource");
         let mut params = Params::new();
 
         params.put("Action", "TagResource");
@@ -6413,6 +6445,7 @@ impl Sns for SnsClient {
         input: UnsubscribeInput,
     ) -> Result<(), RusotoError<UnsubscribeError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("Unsubscribe");
         let mut params = Params::new();
 
         params.put("Action", "Unsubscribe");
@@ -6441,6 +6474,7 @@ impl Sns for SnsClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("UntagResource");
         let mut params = Params::new();
 
         params.put("Action", "UntagResource");
@@ -6482,6 +6516,207 @@ impl Sns for SnsClient {
         Ok(result)
     }
 }
+impl SnsClient {
+    /// Auto-paginating version of `list_endpoints_by_platform_application`, streaming every page of results.
+    pub fn list_endpoints_by_platform_application_pages(
+        &self,
+        input: ListEndpointsByPlatformApplicationInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListEndpointsByPlatformApplicationResponse,
+        ListEndpointsByPlatformApplicationError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SnsClient, input: ListEndpointsByPlatformApplicationInput| async move {
+                client.list_endpoints_by_platform_application(input).await
+            },
+            |input: &ListEndpointsByPlatformApplicationInput,
+             output: &ListEndpointsByPlatformApplicationResponse| {
+                Some(ListEndpointsByPlatformApplicationInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_endpoints_by_platform_application`, streaming the `Endpoints` of every page of results.
+    pub fn list_endpoints_by_platform_application_items(
+        &self,
+        input: ListEndpointsByPlatformApplicationInput,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListEndpointsByPlatformApplicationError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_endpoints_by_platform_application_pages(input),
+            |output: ListEndpointsByPlatformApplicationResponse| {
+                output.endpoints.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_phone_numbers_opted_out`, streaming every page of results.
+    pub fn list_phone_numbers_opted_out_pages(
+        &self,
+        input: ListPhoneNumbersOptedOutInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListPhoneNumbersOptedOutResponse,
+        ListPhoneNumbersOptedOutError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SnsClient, input: ListPhoneNumbersOptedOutInput| async move {
+                client.list_phone_numbers_opted_out(input).await
+            },
+            |_input: &ListPhoneNumbersOptedOutInput, output: &ListPhoneNumbersOptedOutResponse| {
+                Some(ListPhoneNumbersOptedOutInput {
+                    next_token: Some(output.next_token.clone()?),
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_phone_numbers_opted_out`, streaming the `phoneNumbers` of every page of results.
+    pub fn list_phone_numbers_opted_out_items(
+        &self,
+        input: ListPhoneNumbersOptedOutInput,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListPhoneNumbersOptedOutError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_phone_numbers_opted_out_pages(input),
+            |output: ListPhoneNumbersOptedOutResponse| output.phone_numbers.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_platform_applications`, streaming every page of results.
+    pub fn list_platform_applications_pages(
+        &self,
+        input: ListPlatformApplicationsInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListPlatformApplicationsResponse,
+        ListPlatformApplicationsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SnsClient, input: ListPlatformApplicationsInput| async move {
+                client.list_platform_applications(input).await
+            },
+            |_input: &ListPlatformApplicationsInput, output: &ListPlatformApplicationsResponse| {
+                Some(ListPlatformApplicationsInput {
+                    next_token: Some(output.next_token.clone()?),
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_platform_applications`, streaming the `PlatformApplications` of every page of results.
+    pub fn list_platform_applications_items(
+        &self,
+        input: ListPlatformApplicationsInput,
+    ) -> rusoto_core::pagination::RusotoStream<PlatformApplication, ListPlatformApplicationsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_platform_applications_pages(input),
+            |output: ListPlatformApplicationsResponse| {
+                output.platform_applications.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_subscriptions`, streaming every page of results.
+    pub fn list_subscriptions_pages(
+        &self,
+        input: ListSubscriptionsInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListSubscriptionsResponse, ListSubscriptionsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SnsClient, input: ListSubscriptionsInput| async move {
+                client.list_subscriptions(input).await
+            },
+            |_input: &ListSubscriptionsInput, output: &ListSubscriptionsResponse| {
+                Some(ListSubscriptionsInput {
+                    next_token: Some(output.next_token.clone()?),
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_subscriptions`, streaming the `Subscriptions` of every page of results.
+    pub fn list_subscriptions_items(
+        &self,
+        input: ListSubscriptionsInput,
+    ) -> rusoto_core::pagination::RusotoStream<Subscription, ListSubscriptionsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_subscriptions_pages(input),
+            |output: ListSubscriptionsResponse| output.subscriptions.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_subscriptions_by_topic`, streaming every page of results.
+    pub fn list_subscriptions_by_topic_pages(
+        &self,
+        input: ListSubscriptionsByTopicInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListSubscriptionsByTopicResponse,
+        ListSubscriptionsByTopicError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SnsClient, input: ListSubscriptionsByTopicInput| async move {
+                client.list_subscriptions_by_topic(input).await
+            },
+            |input: &ListSubscriptionsByTopicInput, output: &ListSubscriptionsByTopicResponse| {
+                Some(ListSubscriptionsByTopicInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_subscriptions_by_topic`, streaming the `Subscriptions` of every page of results.
+    pub fn list_subscriptions_by_topic_items(
+        &self,
+        input: ListSubscriptionsByTopicInput,
+    ) -> rusoto_core::pagination::RusotoStream<Subscription, ListSubscriptionsByTopicError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_subscriptions_by_topic_pages(input),
+            |output: ListSubscriptionsByTopicResponse| output.subscriptions.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_topics`, streaming every page of results.
+    pub fn list_topics_pages(
+        &self,
+        input: ListTopicsInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListTopicsResponse, ListTopicsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SnsClient, input: ListTopicsInput| async move { client.list_topics(input).await },
+            |_input: &ListTopicsInput, output: &ListTopicsResponse| {
+                Some(ListTopicsInput {
+                    next_token: Some(output.next_token.clone()?),
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_topics`, streaming the `Topics` of every page of results.
+    pub fn list_topics_items(
+        &self,
+        input: ListTopicsInput,
+    ) -> rusoto_core::pagination::RusotoStream<Topic, ListTopicsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_topics_pages(input),
+            |output: ListTopicsResponse| output.topics.unwrap_or_default(),
+        )
+    }
+}
 
 #[cfg(test)]
 mod protocol_tests {
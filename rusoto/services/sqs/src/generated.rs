@@ -95,7 +95,7 @@ impl AddPermissionRequestSerializer {
 /// Serialize `AttributeNameList` contents to a `SignedRequest`.
 struct AttributeNameListSerializer;
 impl AttributeNameListSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &Vec<String>) {
+    fn serialize(params: &mut Params, name: &str, obj: &Vec<QueueAttributeName>) {
         for (index, obj) in obj.iter().enumerate() {
             let key = format!("{}.{}", name, index + 1);
             params.put(&key, &obj);
@@ -444,7 +444,7 @@ impl ChangeMessageVisibilityRequestSerializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateQueueRequest {
     /// <p><p>A map of attributes with their corresponding values.</p> <p>The following lists the names, descriptions, and values of the special request parameters that the <code>CreateQueue</code> action uses:</p> <ul> <li> <p> <code>DelaySeconds</code> - The length of time, in seconds, for which the delivery of all messages in the queue is delayed. Valid values: An integer from 0 to 900 seconds (15 minutes). Default: 0. </p> </li> <li> <p> <code>MaximumMessageSize</code> - The limit of how many bytes a message can contain before Amazon SQS rejects it. Valid values: An integer from 1,024 bytes (1 KiB) to 262,144 bytes (256 KiB). Default: 262,144 (256 KiB). </p> </li> <li> <p> <code>MessageRetentionPeriod</code> - The length of time, in seconds, for which Amazon SQS retains a message. Valid values: An integer from 60 seconds (1 minute) to 1,209,600 seconds (14 days). Default: 345,600 (4 days). </p> </li> <li> <p> <code>Policy</code> - The queue&#39;s policy. A valid AWS policy. For more information about policy structure, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/PoliciesOverview.html">Overview of AWS IAM Policies</a> in the <i>Amazon IAM User Guide</i>. </p> </li> <li> <p> <code>ReceiveMessageWaitTimeSeconds</code> - The length of time, in seconds, for which a <code> <a>ReceiveMessage</a> </code> action waits for a message to arrive. Valid values: An integer from 0 to 20 (seconds). Default: 0. </p> </li> <li> <p> <code>RedrivePolicy</code> - The string that includes the parameters for the dead-letter queue functionality of the source queue. For more information about the redrive policy and dead-letter queues, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-dead-letter-queues.html">Using Amazon SQS Dead-Letter Queues</a> in the <i>Amazon Simple Queue Service Developer Guide</i>. </p> <ul> <li> <p> <code>deadLetterTargetArn</code> - The Amazon Resource Name (ARN) of the dead-letter queue to which Amazon SQS moves messages after the value of <code>maxReceiveCount</code> is exceeded.</p> </li> <li> <p> <code>maxReceiveCount</code> - The number of times a message is delivered to the source queue before being moved to the dead-letter queue. When the <code>ReceiveCount</code> for a message exceeds the <code>maxReceiveCount</code> for a queue, Amazon SQS moves the message to the dead-letter-queue.</p> </li> </ul> <note> <p>The dead-letter queue of a FIFO queue must also be a FIFO queue. Similarly, the dead-letter queue of a standard queue must also be a standard queue.</p> </note> </li> <li> <p> <code>VisibilityTimeout</code> - The visibility timeout for the queue, in seconds. Valid values: An integer from 0 to 43,200 (12 hours). Default: 30. For more information about the visibility timeout, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-visibility-timeout.html">Visibility Timeout</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> </li> </ul> <p>The following attributes apply only to <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-server-side-encryption.html">server-side-encryption</a>:</p> <ul> <li> <p> <code>KmsMasterKeyId</code> - The ID of an AWS-managed customer master key (CMK) for Amazon SQS or a custom CMK. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-server-side-encryption.html#sqs-sse-key-terms">Key Terms</a>. While the alias of the AWS-managed CMK for Amazon SQS is always <code>alias/aws/sqs</code>, the alias of a custom CMK can, for example, be <code>alias/<i>MyAlias</i> </code>. For more examples, see <a href="https://docs.aws.amazon.com/kms/latest/APIReference/API_DescribeKey.html#API_DescribeKey_RequestParameters">KeyId</a> in the <i>AWS Key Management Service API Reference</i>. </p> </li> <li> <p> <code>KmsDataKeyReusePeriodSeconds</code> - The length of time, in seconds, for which Amazon SQS can reuse a <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#data-keys">data key</a> to encrypt or decrypt messages before calling AWS KMS again. An integer representing seconds, between 60 seconds (1 minute) and 86,400 seconds (24 hours). Default: 300 (5 minutes). A shorter time period provides better security but results in more calls to KMS which might incur charges after Free Tier. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-server-side-encryption.html#sqs-how-does-the-data-key-reuse-period-work">How Does the Data Key Reuse Period Work?</a>. </p> </li> </ul> <p>The following attributes apply only to <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/FIFO-queues.html">FIFO (first-in-first-out) queues</a>:</p> <ul> <li> <p> <code>FifoQueue</code> - Designates a queue as FIFO. Valid values: <code>true</code>, <code>false</code>. If you don&#39;t specify the <code>FifoQueue</code> attribute, Amazon SQS creates a standard queue. You can provide this attribute only during queue creation. You can&#39;t change it for an existing queue. When you set this attribute, you must also provide the <code>MessageGroupId</code> for your messages explicitly.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/FIFO-queues.html#FIFO-queues-understanding-logic">FIFO Queue Logic</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> </li> <li> <p> <code>ContentBasedDeduplication</code> - Enables content-based deduplication. Valid values: <code>true</code>, <code>false</code>. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/FIFO-queues.html#FIFO-queues-exactly-once-processing">Exactly-Once Processing</a> in the <i>Amazon Simple Queue Service Developer Guide</i>. </p> <ul> <li> <p>Every message must have a unique <code>MessageDeduplicationId</code>,</p> <ul> <li> <p>You may provide a <code>MessageDeduplicationId</code> explicitly.</p> </li> <li> <p>If you aren&#39;t able to provide a <code>MessageDeduplicationId</code> and you enable <code>ContentBasedDeduplication</code> for your queue, Amazon SQS uses a SHA-256 hash to generate the <code>MessageDeduplicationId</code> using the body of the message (but not the attributes of the message). </p> </li> <li> <p>If you don&#39;t provide a <code>MessageDeduplicationId</code> and the queue doesn&#39;t have <code>ContentBasedDeduplication</code> set, the action fails with an error.</p> </li> <li> <p>If the queue has <code>ContentBasedDeduplication</code> set, your <code>MessageDeduplicationId</code> overrides the generated one.</p> </li> </ul> </li> <li> <p>When <code>ContentBasedDeduplication</code> is in effect, messages with identical content sent within the deduplication interval are treated as duplicates and only one copy of the message is delivered.</p> </li> <li> <p>If you send one message with <code>ContentBasedDeduplication</code> enabled and then another message with a <code>MessageDeduplicationId</code> that is the same as the one generated for the first <code>MessageDeduplicationId</code>, the two messages are treated as duplicates and only one copy of the message is delivered. </p> </li> </ul> </li> </ul></p>
-    pub attributes: Option<::std::collections::HashMap<String, String>>,
+    pub attributes: Option<::std::collections::HashMap<QueueAttributeName, String>>,
     /// <p>The name of the new queue. The following limits apply to this name:</p> <ul> <li> <p>A queue name can have up to 80 characters.</p> </li> <li> <p>Valid values: alphanumeric characters, hyphens (<code>-</code>), and underscores (<code>_</code>).</p> </li> <li> <p>A FIFO queue name must end with the <code>.fifo</code> suffix.</p> </li> </ul> <p>Queue URLs and names are case-sensitive.</p>
     pub queue_name: String,
     /// <p><p>Add cost allocation tags to the specified Amazon SQS queue. For an overview, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-queue-tags.html">Tagging Your Amazon SQS Queues</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> <p>When you use queue tags, keep the following guidelines in mind:</p> <ul> <li> <p>Adding more than 50 tags to a queue isn&#39;t recommended.</p> </li> <li> <p>Tags don&#39;t have any semantic meaning. Amazon SQS interprets tags as character strings.</p> </li> <li> <p>Tags are case-sensitive.</p> </li> <li> <p>A new tag with a key identical to that of an existing tag overwrites the existing tag.</p> </li> </ul> <p>For a full list of tag restrictions, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-limits.html#limits-queues">Limits Related to Queues</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> <note> <p>To be able to tag a queue on creation, you must have the <code>sqs:CreateQueue</code> and <code>sqs:TagQueue</code> permissions.</p> <p>Cross-account permissions don&#39;t apply to this action. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-customer-managed-policy-examples.html#grant-cross-account-permissions-to-role-and-user-name">Grant Cross-Account Permissions to a Role and a User Name</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> </note></p>
@@ -722,7 +722,7 @@ impl DeleteQueueRequestSerializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct GetQueueAttributesRequest {
     /// <p><p>A list of attributes for which to retrieve information.</p> <note> <p>In the future, new attributes might be added. If you write code that calls this action, we recommend that you structure your code so that it can handle new attributes gracefully.</p> </note> <p>The following attributes are supported:</p> <ul> <li> <p> <code>All</code> - Returns all values. </p> </li> <li> <p> <code>ApproximateNumberOfMessages</code> - Returns the approximate number of messages available for retrieval from the queue.</p> </li> <li> <p> <code>ApproximateNumberOfMessagesDelayed</code> - Returns the approximate number of messages in the queue that are delayed and not available for reading immediately. This can happen when the queue is configured as a delay queue or when a message has been sent with a delay parameter.</p> </li> <li> <p> <code>ApproximateNumberOfMessagesNotVisible</code> - Returns the approximate number of messages that are in flight. Messages are considered to be <i>in flight</i> if they have been sent to a client but have not yet been deleted or have not yet reached the end of their visibility window. </p> </li> <li> <p> <code>CreatedTimestamp</code> - Returns the time when the queue was created in seconds (<a href="http://en.wikipedia.org/wiki/Unix_time">epoch time</a>).</p> </li> <li> <p> <code>DelaySeconds</code> - Returns the default delay on the queue in seconds.</p> </li> <li> <p> <code>LastModifiedTimestamp</code> - Returns the time when the queue was last changed in seconds (<a href="http://en.wikipedia.org/wiki/Unix_time">epoch time</a>).</p> </li> <li> <p> <code>MaximumMessageSize</code> - Returns the limit of how many bytes a message can contain before Amazon SQS rejects it.</p> </li> <li> <p> <code>MessageRetentionPeriod</code> - Returns the length of time, in seconds, for which Amazon SQS retains a message.</p> </li> <li> <p> <code>Policy</code> - Returns the policy of the queue.</p> </li> <li> <p> <code>QueueArn</code> - Returns the Amazon resource name (ARN) of the queue.</p> </li> <li> <p> <code>ReceiveMessageWaitTimeSeconds</code> - Returns the length of time, in seconds, for which the <code>ReceiveMessage</code> action waits for a message to arrive. </p> </li> <li> <p> <code>RedrivePolicy</code> - Returns the string that includes the parameters for dead-letter queue functionality of the source queue. For more information about the redrive policy and dead-letter queues, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-dead-letter-queues.html">Using Amazon SQS Dead-Letter Queues</a> in the <i>Amazon Simple Queue Service Developer Guide</i>. </p> <ul> <li> <p> <code>deadLetterTargetArn</code> - The Amazon Resource Name (ARN) of the dead-letter queue to which Amazon SQS moves messages after the value of <code>maxReceiveCount</code> is exceeded.</p> </li> <li> <p> <code>maxReceiveCount</code> - The number of times a message is delivered to the source queue before being moved to the dead-letter queue. When the <code>ReceiveCount</code> for a message exceeds the <code>maxReceiveCount</code> for a queue, Amazon SQS moves the message to the dead-letter-queue.</p> </li> </ul> </li> <li> <p> <code>VisibilityTimeout</code> - Returns the visibility timeout for the queue. For more information about the visibility timeout, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-visibility-timeout.html">Visibility Timeout</a> in the <i>Amazon Simple Queue Service Developer Guide</i>. </p> </li> </ul> <p>The following attributes apply only to <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-server-side-encryption.html">server-side-encryption</a>:</p> <ul> <li> <p> <code>KmsMasterKeyId</code> - Returns the ID of an AWS-managed customer master key (CMK) for Amazon SQS or a custom CMK. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-server-side-encryption.html#sqs-sse-key-terms">Key Terms</a>. </p> </li> <li> <p> <code>KmsDataKeyReusePeriodSeconds</code> - Returns the length of time, in seconds, for which Amazon SQS can reuse a data key to encrypt or decrypt messages before calling AWS KMS again. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-server-side-encryption.html#sqs-how-does-the-data-key-reuse-period-work">How Does the Data Key Reuse Period Work?</a>. </p> </li> </ul> <p>The following attributes apply only to <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/FIFO-queues.html">FIFO (first-in-first-out) queues</a>:</p> <ul> <li> <p> <code>FifoQueue</code> - Returns whether the queue is FIFO. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/FIFO-queues.html#FIFO-queues-understanding-logic">FIFO Queue Logic</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> <note> <p>To determine whether a queue is <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/FIFO-queues.html">FIFO</a>, you can check whether <code>QueueName</code> ends with the <code>.fifo</code> suffix.</p> </note> </li> <li> <p> <code>ContentBasedDeduplication</code> - Returns whether content-based deduplication is enabled for the queue. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/FIFO-queues.html#FIFO-queues-exactly-once-processing">Exactly-Once Processing</a> in the <i>Amazon Simple Queue Service Developer Guide</i>. </p> </li> </ul></p>
-    pub attribute_names: Option<Vec<String>>,
+    pub attribute_names: Option<Vec<QueueAttributeName>>,
     /// <p>The URL of the Amazon SQS queue whose attribute information is retrieved.</p> <p>Queue URLs and names are case-sensitive.</p>
     pub queue_url: String,
 }
@@ -752,7 +752,7 @@ impl GetQueueAttributesRequestSerializer {
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct GetQueueAttributesResult {
     /// <p>A map of attributes to their respective values.</p>
-    pub attributes: Option<::std::collections::HashMap<String, String>>,
+    pub attributes: Option<::std::collections::HashMap<QueueAttributeName, String>>,
 }
 
 struct GetQueueAttributesResultDeserializer;
@@ -988,7 +988,7 @@ impl ListQueuesResultDeserializer {
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct Message {
     /// <p>A map of the attributes requested in <code> <a>ReceiveMessage</a> </code> to their respective values. Supported attributes:</p> <ul> <li> <p> <code>ApproximateReceiveCount</code> </p> </li> <li> <p> <code>ApproximateFirstReceiveTimestamp</code> </p> </li> <li> <p> <code>MessageDeduplicationId</code> </p> </li> <li> <p> <code>MessageGroupId</code> </p> </li> <li> <p> <code>SenderId</code> </p> </li> <li> <p> <code>SentTimestamp</code> </p> </li> <li> <p> <code>SequenceNumber</code> </p> </li> </ul> <p> <code>ApproximateFirstReceiveTimestamp</code> and <code>SentTimestamp</code> are each returned as an integer representing the <a href="http://en.wikipedia.org/wiki/Unix_time">epoch time</a> in milliseconds.</p>
-    pub attributes: Option<::std::collections::HashMap<String, String>>,
+    pub attributes: Option<::std::collections::HashMap<MessageSystemAttributeName, String>>,
     /// <p>The message's contents (not URL-encoded).</p>
     pub body: Option<String>,
     /// <p>An MD5 digest of the non-URL-encoded message body string.</p>
@@ -1197,7 +1197,10 @@ impl MessageBodySystemAttributeMapSerializer {
     fn serialize(
         params: &mut Params,
         name: &str,
-        obj: &::std::collections::HashMap<String, MessageSystemAttributeValue>,
+        obj: &::std::collections::HashMap<
+            MessageSystemAttributeNameForSends,
+            MessageSystemAttributeValue,
+        >,
     ) {
         for (index, (key, value)) in obj.iter().enumerate() {
             let prefix = format!("{}.{}", name, index + 1);
@@ -1244,7 +1247,8 @@ impl MessageSystemAttributeMapDeserializer {
     fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
-    ) -> Result<::std::collections::HashMap<String, String>, XmlParseError> {
+    ) -> Result<::std::collections::HashMap<MessageSystemAttributeName, String>, XmlParseError>
+    {
         let mut obj = ::std::collections::HashMap::new();
 
         while peek_at_name(stack)? == "Attribute" {
@@ -1258,17 +1262,246 @@ impl MessageSystemAttributeMapDeserializer {
         Ok(obj)
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MessageSystemAttributeName {
+    /// `SenderId`
+    SenderId,
+    /// `SentTimestamp`
+    SentTimestamp,
+    /// `ApproximateReceiveCount`
+    ApproximateReceiveCount,
+    /// `ApproximateFirstReceiveTimestamp`
+    ApproximateFirstReceiveTimestamp,
+    /// `SequenceNumber`
+    SequenceNumber,
+    /// `MessageDeduplicationId`
+    MessageDeduplicationId,
+    /// `MessageGroupId`
+    MessageGroupId,
+    /// `AWSTraceHeader`
+    AWSTraceHeader,
+    /// `DeadLetterQueueSourceArn`
+    DeadLetterQueueSourceArn,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl MessageSystemAttributeName {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MessageSystemAttributeName::SenderId => "SenderId",
+            MessageSystemAttributeName::SentTimestamp => "SentTimestamp",
+            MessageSystemAttributeName::ApproximateReceiveCount => "ApproximateReceiveCount",
+            MessageSystemAttributeName::ApproximateFirstReceiveTimestamp => {
+                "ApproximateFirstReceiveTimestamp"
+            }
+            MessageSystemAttributeName::SequenceNumber => "SequenceNumber",
+            MessageSystemAttributeName::MessageDeduplicationId => "MessageDeduplicationId",
+            MessageSystemAttributeName::MessageGroupId => "MessageGroupId",
+            MessageSystemAttributeName::AWSTraceHeader => "AWSTraceHeader",
+            MessageSystemAttributeName::DeadLetterQueueSourceArn => "DeadLetterQueueSourceArn",
+            MessageSystemAttributeName::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for MessageSystemAttributeName {
+    fn default() -> MessageSystemAttributeName {
+        MessageSystemAttributeName::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for MessageSystemAttributeName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for MessageSystemAttributeName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for MessageSystemAttributeName {
+    fn from(value: &'a str) -> MessageSystemAttributeName {
+        match value {
+            "SenderId" => MessageSystemAttributeName::SenderId,
+            "SentTimestamp" => MessageSystemAttributeName::SentTimestamp,
+            "ApproximateReceiveCount" => MessageSystemAttributeName::ApproximateReceiveCount,
+            "ApproximateFirstReceiveTimestamp" => {
+                MessageSystemAttributeName::ApproximateFirstReceiveTimestamp
+            }
+            "SequenceNumber" => MessageSystemAttributeName::SequenceNumber,
+            "MessageDeduplicationId" => MessageSystemAttributeName::MessageDeduplicationId,
+            "MessageGroupId" => MessageSystemAttributeName::MessageGroupId,
+            "AWSTraceHeader" => MessageSystemAttributeName::AWSTraceHeader,
+            "DeadLetterQueueSourceArn" => MessageSystemAttributeName::DeadLetterQueueSourceArn,
+            _ => MessageSystemAttributeName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MessageSystemAttributeName {
+    fn from(value: String) -> MessageSystemAttributeName {
+        match value.as_str() {
+            "SenderId" => MessageSystemAttributeName::SenderId,
+            "SentTimestamp" => MessageSystemAttributeName::SentTimestamp,
+            "ApproximateReceiveCount" => MessageSystemAttributeName::ApproximateReceiveCount,
+            "ApproximateFirstReceiveTimestamp" => {
+                MessageSystemAttributeName::ApproximateFirstReceiveTimestamp
+            }
+            "SequenceNumber" => MessageSystemAttributeName::SequenceNumber,
+            "MessageDeduplicationId" => MessageSystemAttributeName::MessageDeduplicationId,
+            "MessageGroupId" => MessageSystemAttributeName::MessageGroupId,
+            "AWSTraceHeader" => MessageSystemAttributeName::AWSTraceHeader,
+            "DeadLetterQueueSourceArn" => MessageSystemAttributeName::DeadLetterQueueSourceArn,
+            _ => MessageSystemAttributeName::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for MessageSystemAttributeName {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<MessageSystemAttributeName, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MessageSystemAttributeName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for MessageSystemAttributeName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for MessageSystemAttributeName {
+    fn deserialize<D>(deserializer: D) -> Result<MessageSystemAttributeName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct MessageSystemAttributeNameDeserializer;
 impl MessageSystemAttributeNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<MessageSystemAttributeName, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MessageSystemAttributeNameForSends {
+    /// `AWSTraceHeader`
+    AWSTraceHeader,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl MessageSystemAttributeNameForSends {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MessageSystemAttributeNameForSends::AWSTraceHeader => "AWSTraceHeader",
+            MessageSystemAttributeNameForSends::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for MessageSystemAttributeNameForSends {
+    fn default() -> MessageSystemAttributeNameForSends {
+        MessageSystemAttributeNameForSends::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for MessageSystemAttributeNameForSends {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for MessageSystemAttributeNameForSends {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for MessageSystemAttributeNameForSends {
+    fn from(value: &'a str) -> MessageSystemAttributeNameForSends {
+        match value {
+            "AWSTraceHeader" => MessageSystemAttributeNameForSends::AWSTraceHeader,
+            _ => MessageSystemAttributeNameForSends::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MessageSystemAttributeNameForSends {
+    fn from(value: String) -> MessageSystemAttributeNameForSends {
+        match value.as_str() {
+            "AWSTraceHeader" => MessageSystemAttributeNameForSends::AWSTraceHeader,
+            _ => MessageSystemAttributeNameForSends::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for MessageSystemAttributeNameForSends {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<MessageSystemAttributeNameForSends, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MessageSystemAttributeNameForSends {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for MessageSystemAttributeNameForSends {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for MessageSystemAttributeNameForSends {
+    fn deserialize<D>(deserializer: D) -> Result<MessageSystemAttributeNameForSends, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The user-specified message system attribute value. For string data types, the <code>Value</code> attribute has the same restrictions on the content as the message body. For more information, see <code> <a>SendMessage</a>.</code> </p> <p> <code>Name</code>, <code>type</code>, <code>value</code> and the message body must not be empty or null.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -1348,7 +1581,7 @@ impl QueueAttributeMapDeserializer {
     fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
-    ) -> Result<::std::collections::HashMap<String, String>, XmlParseError> {
+    ) -> Result<::std::collections::HashMap<QueueAttributeName, String>, XmlParseError> {
         let mut obj = ::std::collections::HashMap::new();
 
         while peek_at_name(stack)? == "Attribute" {
@@ -1369,7 +1602,7 @@ impl QueueAttributeMapSerializer {
     fn serialize(
         params: &mut Params,
         name: &str,
-        obj: &::std::collections::HashMap<String, String>,
+        obj: &::std::collections::HashMap<QueueAttributeName, String>,
     ) {
         for (index, (key, value)) in obj.iter().enumerate() {
             let prefix = format!("{}.{}", name, index + 1);
@@ -1379,12 +1612,222 @@ impl QueueAttributeMapSerializer {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum QueueAttributeName {
+    /// `All`
+    All,
+    /// `Policy`
+    Policy,
+    /// `VisibilityTimeout`
+    VisibilityTimeout,
+    /// `MaximumMessageSize`
+    MaximumMessageSize,
+    /// `MessageRetentionPeriod`
+    MessageRetentionPeriod,
+    /// `ApproximateNumberOfMessages`
+    ApproximateNumberOfMessages,
+    /// `ApproximateNumberOfMessagesNotVisible`
+    ApproximateNumberOfMessagesNotVisible,
+    /// `CreatedTimestamp`
+    CreatedTimestamp,
+    /// `LastModifiedTimestamp`
+    LastModifiedTimestamp,
+    /// `QueueArn`
+    QueueArn,
+    /// `ApproximateNumberOfMessagesDelayed`
+    ApproximateNumberOfMessagesDelayed,
+    /// `DelaySeconds`
+    DelaySeconds,
+    /// `ReceiveMessageWaitTimeSeconds`
+    ReceiveMessageWaitTimeSeconds,
+    /// `RedrivePolicy`
+    RedrivePolicy,
+    /// `FifoQueue`
+    FifoQueue,
+    /// `ContentBasedDeduplication`
+    ContentBasedDeduplication,
+    /// `KmsMasterKeyId`
+    KmsMasterKeyId,
+    /// `KmsDataKeyReusePeriodSeconds`
+    KmsDataKeyReusePeriodSeconds,
+    /// `DeduplicationScope`
+    DeduplicationScope,
+    /// `FifoThroughputLimit`
+    FifoThroughputLimit,
+    /// `RedriveAllowPolicy`
+    RedriveAllowPolicy,
+    /// `SqsManagedSseEnabled`
+    SqsManagedSseEnabled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl QueueAttributeName {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            QueueAttributeName::All => "All",
+            QueueAttributeName::Policy => "Policy",
+            QueueAttributeName::VisibilityTimeout => "VisibilityTimeout",
+            QueueAttributeName::MaximumMessageSize => "MaximumMessageSize",
+            QueueAttributeName::MessageRetentionPeriod => "MessageRetentionPeriod",
+            QueueAttributeName::ApproximateNumberOfMessages => "ApproximateNumberOfMessages",
+            QueueAttributeName::ApproximateNumberOfMessagesNotVisible => {
+                "ApproximateNumberOfMessagesNotVisible"
+            }
+            QueueAttributeName::CreatedTimestamp => "CreatedTimestamp",
+            QueueAttributeName::LastModifiedTimestamp => "LastModifiedTimestamp",
+            QueueAttributeName::QueueArn => "QueueArn",
+            QueueAttributeName::ApproximateNumberOfMessagesDelayed => {
+                "ApproximateNumberOfMessagesDelayed"
+            }
+            QueueAttributeName::DelaySeconds => "DelaySeconds",
+            QueueAttributeName::ReceiveMessageWaitTimeSeconds => "ReceiveMessageWaitTimeSeconds",
+            QueueAttributeName::RedrivePolicy => "RedrivePolicy",
+            QueueAttributeName::FifoQueue => "FifoQueue",
+            QueueAttributeName::ContentBasedDeduplication => "ContentBasedDeduplication",
+            QueueAttributeName::KmsMasterKeyId => "KmsMasterKeyId",
+            QueueAttributeName::KmsDataKeyReusePeriodSeconds => "KmsDataKeyReusePeriodSeconds",
+            QueueAttributeName::DeduplicationScope => "DeduplicationScope",
+            QueueAttributeName::FifoThroughputLimit => "FifoThroughputLimit",
+            QueueAttributeName::RedriveAllowPolicy => "RedriveAllowPolicy",
+            QueueAttributeName::SqsManagedSseEnabled => "SqsManagedSseEnabled",
+            QueueAttributeName::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for QueueAttributeName {
+    fn default() -> QueueAttributeName {
+        QueueAttributeName::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for QueueAttributeName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for QueueAttributeName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for QueueAttributeName {
+    fn from(value: &'a str) -> QueueAttributeName {
+        match value {
+            "All" => QueueAttributeName::All,
+            "Policy" => QueueAttributeName::Policy,
+            "VisibilityTimeout" => QueueAttributeName::VisibilityTimeout,
+            "MaximumMessageSize" => QueueAttributeName::MaximumMessageSize,
+            "MessageRetentionPeriod" => QueueAttributeName::MessageRetentionPeriod,
+            "ApproximateNumberOfMessages" => QueueAttributeName::ApproximateNumberOfMessages,
+            "ApproximateNumberOfMessagesNotVisible" => {
+                QueueAttributeName::ApproximateNumberOfMessagesNotVisible
+            }
+            "CreatedTimestamp" => QueueAttributeName::CreatedTimestamp,
+            "LastModifiedTimestamp" => QueueAttributeName::LastModifiedTimestamp,
+            "QueueArn" => QueueAttributeName::QueueArn,
+            "ApproximateNumberOfMessagesDelayed" => {
+                QueueAttributeName::ApproximateNumberOfMessagesDelayed
+            }
+            "DelaySeconds" => QueueAttributeName::DelaySeconds,
+            "ReceiveMessageWaitTimeSeconds" => QueueAttributeName::ReceiveMessageWaitTimeSeconds,
+            "RedrivePolicy" => QueueAttributeName::RedrivePolicy,
+            "FifoQueue" => QueueAttributeName::FifoQueue,
+            "ContentBasedDeduplication" => QueueAttributeName::ContentBasedDeduplication,
+            "KmsMasterKeyId" => QueueAttributeName::KmsMasterKeyId,
+            "KmsDataKeyReusePeriodSeconds" => QueueAttributeName::KmsDataKeyReusePeriodSeconds,
+            "DeduplicationScope" => QueueAttributeName::DeduplicationScope,
+            "FifoThroughputLimit" => QueueAttributeName::FifoThroughputLimit,
+            "RedriveAllowPolicy" => QueueAttributeName::RedriveAllowPolicy,
+            "SqsManagedSseEnabled" => QueueAttributeName::SqsManagedSseEnabled,
+            _ => QueueAttributeName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for QueueAttributeName {
+    fn from(value: String) -> QueueAttributeName {
+        match value.as_str() {
+            "All" => QueueAttributeName::All,
+            "Policy" => QueueAttributeName::Policy,
+            "VisibilityTimeout" => QueueAttributeName::VisibilityTimeout,
+            "MaximumMessageSize" => QueueAttributeName::MaximumMessageSize,
+            "MessageRetentionPeriod" => QueueAttributeName::MessageRetentionPeriod,
+            "ApproximateNumberOfMessages" => QueueAttributeName::ApproximateNumberOfMessages,
+            "ApproximateNumberOfMessagesNotVisible" => {
+                QueueAttributeName::ApproximateNumberOfMessagesNotVisible
+            }
+            "CreatedTimestamp" => QueueAttributeName::CreatedTimestamp,
+            "LastModifiedTimestamp" => QueueAttributeName::LastModifiedTimestamp,
+            "QueueArn" => QueueAttributeName::QueueArn,
+            "ApproximateNumberOfMessagesDelayed" => {
+                QueueAttributeName::ApproximateNumberOfMessagesDelayed
+            }
+            "DelaySeconds" => QueueAttributeName::DelaySeconds,
+            "ReceiveMessageWaitTimeSeconds" => QueueAttributeName::ReceiveMessageWaitTimeSeconds,
+            "RedrivePolicy" => QueueAttributeName::RedrivePolicy,
+            "FifoQueue" => QueueAttributeName::FifoQueue,
+            "ContentBasedDeduplication" => QueueAttributeName::ContentBasedDeduplication,
+            "KmsMasterKeyId" => QueueAttributeName::KmsMasterKeyId,
+            "KmsDataKeyReusePeriodSeconds" => QueueAttributeName::KmsDataKeyReusePeriodSeconds,
+            "DeduplicationScope" => QueueAttributeName::DeduplicationScope,
+            "FifoThroughputLimit" => QueueAttributeName::FifoThroughputLimit,
+            "RedriveAllowPolicy" => QueueAttributeName::RedriveAllowPolicy,
+            "SqsManagedSseEnabled" => QueueAttributeName::SqsManagedSseEnabled,
+            _ => QueueAttributeName::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for QueueAttributeName {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<QueueAttributeName, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for QueueAttributeName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[cfg(feature = "serialize_structs")]
+impl ::serde::Serialize for QueueAttributeName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "deserialize_structs")]
+impl<'de> ::serde::Deserialize<'de> for QueueAttributeName {
+    fn deserialize<D>(deserializer: D) -> Result<QueueAttributeName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 struct QueueAttributeNameDeserializer;
 impl QueueAttributeNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<QueueAttributeName, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -1422,7 +1865,7 @@ impl QueueUrlListDeserializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ReceiveMessageRequest {
     /// <p><p>A list of attributes that need to be returned along with each message. These attributes include:</p> <ul> <li> <p> <code>All</code> - Returns all values.</p> </li> <li> <p> <code>ApproximateFirstReceiveTimestamp</code> - Returns the time the message was first received from the queue (<a href="http://en.wikipedia.org/wiki/Unix_time">epoch time</a> in milliseconds).</p> </li> <li> <p> <code>ApproximateReceiveCount</code> - Returns the number of times a message has been received from the queue but not deleted.</p> </li> <li> <p> <code>AWSTraceHeader</code> - Returns the AWS X-Ray trace header string. </p> </li> <li> <p> <code>SenderId</code> </p> <ul> <li> <p>For an IAM user, returns the IAM user ID, for example <code>ABCDEFGHI1JKLMNOPQ23R</code>.</p> </li> <li> <p>For an IAM role, returns the IAM role ID, for example <code>ABCDE1F2GH3I4JK5LMNOP:i-a123b456</code>.</p> </li> </ul> </li> <li> <p> <code>SentTimestamp</code> - Returns the time the message was sent to the queue (<a href="http://en.wikipedia.org/wiki/Unix_time">epoch time</a> in milliseconds).</p> </li> <li> <p> <code>MessageDeduplicationId</code> - Returns the value provided by the producer that calls the <code> <a>SendMessage</a> </code> action.</p> </li> <li> <p> <code>MessageGroupId</code> - Returns the value provided by the producer that calls the <code> <a>SendMessage</a> </code> action. Messages with the same <code>MessageGroupId</code> are returned in sequence.</p> </li> <li> <p> <code>SequenceNumber</code> - Returns the value provided by Amazon SQS.</p> </li> </ul></p>
-    pub attribute_names: Option<Vec<String>>,
+    pub attribute_names: Option<Vec<QueueAttributeName>>,
     /// <p>The maximum number of messages to return. Amazon SQS never returns more messages than this value (however, fewer messages might be returned). Valid values: 1 to 10. Default: 1.</p>
     pub max_number_of_messages: Option<i64>,
     /// <p>The name of the message attribute, where <i>N</i> is the index.</p> <ul> <li> <p>The name can contain alphanumeric characters and the underscore (<code>_</code>), hyphen (<code>-</code>), and period (<code>.</code>).</p> </li> <li> <p>The name is case-sensitive and must be unique among all attribute names for the message.</p> </li> <li> <p>The name must not start with AWS-reserved prefixes such as <code>AWS.</code> or <code>Amazon.</code> (or any casing variants).</p> </li> <li> <p>The name must not start or end with a period (<code>.</code>), and it should not have periods in succession (<code>..</code>).</p> </li> <li> <p>The name can be up to 256 characters long.</p> </li> </ul> <p>When using <code>ReceiveMessage</code>, you can send a list of attribute names to receive, or you can return all of the attributes by specifying <code>All</code> or <code>.*</code> in your request. You can also use all message attributes starting with a prefix, for example <code>bar.*</code>.</p>
@@ -1579,8 +2022,12 @@ pub struct SendMessageBatchRequestEntry {
     /// <p><p>This parameter applies only to FIFO (first-in-first-out) queues.</p> <p>The tag that specifies that a message belongs to a specific message group. Messages that belong to the same message group are processed in a FIFO manner (however, messages in different message groups might be processed out of order). To interleave multiple ordered streams within a single queue, use <code>MessageGroupId</code> values (for example, session data for multiple users). In this scenario, multiple consumers can process the queue, but the session data of each user is processed in a FIFO fashion.</p> <ul> <li> <p>You must associate a non-empty <code>MessageGroupId</code> with a message. If you don&#39;t provide a <code>MessageGroupId</code>, the action fails.</p> </li> <li> <p> <code>ReceiveMessage</code> might return messages with multiple <code>MessageGroupId</code> values. For each <code>MessageGroupId</code>, the messages are sorted by time sent. The caller can&#39;t specify a <code>MessageGroupId</code>.</p> </li> </ul> <p>The length of <code>MessageGroupId</code> is 128 characters. Valid values: alphanumeric characters and punctuation <code>(!&quot;#$%&amp;&#39;()*+,-./:;&lt;=&gt;?@[]^_`{|}~)</code>.</p> <p>For best practices of using <code>MessageGroupId</code>, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/using-messagegroupid-property.html">Using the MessageGroupId Property</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> <important> <p> <code>MessageGroupId</code> is required for FIFO queues. You can&#39;t use it for Standard queues.</p> </important></p>
     pub message_group_id: Option<String>,
     /// <p><p>The message system attribute to send Each message system attribute consists of a <code>Name</code>, <code>Type</code>, and <code>Value</code>.</p> <important> <ul> <li> <p>Currently, the only supported message system attribute is <code>AWSTraceHeader</code>. Its type must be <code>String</code> and its value must be a correctly formatted AWS X-Ray trace string.</p> </li> <li> <p>The size of a message system attribute doesn&#39;t count towards the total size of a message.</p> </li> </ul> </important></p>
-    pub message_system_attributes:
-        Option<::std::collections::HashMap<String, MessageSystemAttributeValue>>,
+    pub message_system_attributes: Option<
+        ::std::collections::HashMap<
+            MessageSystemAttributeNameForSends,
+            MessageSystemAttributeValue,
+        >,
+    >,
 }
 
 /// Serialize `SendMessageBatchRequestEntry` contents to a `SignedRequest`.
@@ -1780,8 +2227,12 @@ pub struct SendMessageRequest {
     /// <p><p>This parameter applies only to FIFO (first-in-first-out) queues.</p> <p>The tag that specifies that a message belongs to a specific message group. Messages that belong to the same message group are processed in a FIFO manner (however, messages in different message groups might be processed out of order). To interleave multiple ordered streams within a single queue, use <code>MessageGroupId</code> values (for example, session data for multiple users). In this scenario, multiple consumers can process the queue, but the session data of each user is processed in a FIFO fashion.</p> <ul> <li> <p>You must associate a non-empty <code>MessageGroupId</code> with a message. If you don&#39;t provide a <code>MessageGroupId</code>, the action fails.</p> </li> <li> <p> <code>ReceiveMessage</code> might return messages with multiple <code>MessageGroupId</code> values. For each <code>MessageGroupId</code>, the messages are sorted by time sent. The caller can&#39;t specify a <code>MessageGroupId</code>.</p> </li> </ul> <p>The length of <code>MessageGroupId</code> is 128 characters. Valid values: alphanumeric characters and punctuation <code>(!&quot;#$%&amp;&#39;()*+,-./:;&lt;=&gt;?@[]^_`{|}~)</code>.</p> <p>For best practices of using <code>MessageGroupId</code>, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/using-messagegroupid-property.html">Using the MessageGroupId Property</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> <important> <p> <code>MessageGroupId</code> is required for FIFO queues. You can&#39;t use it for Standard queues.</p> </important></p>
     pub message_group_id: Option<String>,
     /// <p><p>The message system attribute to send. Each message system attribute consists of a <code>Name</code>, <code>Type</code>, and <code>Value</code>.</p> <important> <ul> <li> <p>Currently, the only supported message system attribute is <code>AWSTraceHeader</code>. Its type must be <code>String</code> and its value must be a correctly formatted AWS X-Ray trace string.</p> </li> <li> <p>The size of a message system attribute doesn&#39;t count towards the total size of a message.</p> </li> </ul> </important></p>
-    pub message_system_attributes:
-        Option<::std::collections::HashMap<String, MessageSystemAttributeValue>>,
+    pub message_system_attributes: Option<
+        ::std::collections::HashMap<
+            MessageSystemAttributeNameForSends,
+            MessageSystemAttributeValue,
+        >,
+    >,
     /// <p>The URL of the Amazon SQS queue to which a message is sent.</p> <p>Queue URLs and names are case-sensitive.</p>
     pub queue_url: String,
 }
@@ -1885,7 +2336,7 @@ impl SendMessageResultDeserializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct SetQueueAttributesRequest {
     /// <p><p>A map of attributes to set.</p> <p>The following lists the names, descriptions, and values of the special request parameters that the <code>SetQueueAttributes</code> action uses:</p> <ul> <li> <p> <code>DelaySeconds</code> - The length of time, in seconds, for which the delivery of all messages in the queue is delayed. Valid values: An integer from 0 to 900 (15 minutes). Default: 0. </p> </li> <li> <p> <code>MaximumMessageSize</code> - The limit of how many bytes a message can contain before Amazon SQS rejects it. Valid values: An integer from 1,024 bytes (1 KiB) up to 262,144 bytes (256 KiB). Default: 262,144 (256 KiB). </p> </li> <li> <p> <code>MessageRetentionPeriod</code> - The length of time, in seconds, for which Amazon SQS retains a message. Valid values: An integer representing seconds, from 60 (1 minute) to 1,209,600 (14 days). Default: 345,600 (4 days). </p> </li> <li> <p> <code>Policy</code> - The queue&#39;s policy. A valid AWS policy. For more information about policy structure, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/PoliciesOverview.html">Overview of AWS IAM Policies</a> in the <i>Amazon IAM User Guide</i>. </p> </li> <li> <p> <code>ReceiveMessageWaitTimeSeconds</code> - The length of time, in seconds, for which a <code> <a>ReceiveMessage</a> </code> action waits for a message to arrive. Valid values: an integer from 0 to 20 (seconds). Default: 0. </p> </li> <li> <p> <code>RedrivePolicy</code> - The string that includes the parameters for the dead-letter queue functionality of the source queue. For more information about the redrive policy and dead-letter queues, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-dead-letter-queues.html">Using Amazon SQS Dead-Letter Queues</a> in the <i>Amazon Simple Queue Service Developer Guide</i>. </p> <ul> <li> <p> <code>deadLetterTargetArn</code> - The Amazon Resource Name (ARN) of the dead-letter queue to which Amazon SQS moves messages after the value of <code>maxReceiveCount</code> is exceeded.</p> </li> <li> <p> <code>maxReceiveCount</code> - The number of times a message is delivered to the source queue before being moved to the dead-letter queue. When the <code>ReceiveCount</code> for a message exceeds the <code>maxReceiveCount</code> for a queue, Amazon SQS moves the message to the dead-letter-queue.</p> </li> </ul> <note> <p>The dead-letter queue of a FIFO queue must also be a FIFO queue. Similarly, the dead-letter queue of a standard queue must also be a standard queue.</p> </note> </li> <li> <p> <code>VisibilityTimeout</code> - The visibility timeout for the queue, in seconds. Valid values: an integer from 0 to 43,200 (12 hours). Default: 30. For more information about the visibility timeout, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-visibility-timeout.html">Visibility Timeout</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> </li> </ul> <p>The following attributes apply only to <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-server-side-encryption.html">server-side-encryption</a>:</p> <ul> <li> <p> <code>KmsMasterKeyId</code> - The ID of an AWS-managed customer master key (CMK) for Amazon SQS or a custom CMK. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-server-side-encryption.html#sqs-sse-key-terms">Key Terms</a>. While the alias of the AWS-managed CMK for Amazon SQS is always <code>alias/aws/sqs</code>, the alias of a custom CMK can, for example, be <code>alias/<i>MyAlias</i> </code>. For more examples, see <a href="https://docs.aws.amazon.com/kms/latest/APIReference/API_DescribeKey.html#API_DescribeKey_RequestParameters">KeyId</a> in the <i>AWS Key Management Service API Reference</i>. </p> </li> <li> <p> <code>KmsDataKeyReusePeriodSeconds</code> - The length of time, in seconds, for which Amazon SQS can reuse a <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#data-keys">data key</a> to encrypt or decrypt messages before calling AWS KMS again. An integer representing seconds, between 60 seconds (1 minute) and 86,400 seconds (24 hours). Default: 300 (5 minutes). A shorter time period provides better security but results in more calls to KMS which might incur charges after Free Tier. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-server-side-encryption.html#sqs-how-does-the-data-key-reuse-period-work">How Does the Data Key Reuse Period Work?</a>. </p> </li> </ul> <p>The following attribute applies only to <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/FIFO-queues.html">FIFO (first-in-first-out) queues</a>:</p> <ul> <li> <p> <code>ContentBasedDeduplication</code> - Enables content-based deduplication. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/FIFO-queues.html#FIFO-queues-exactly-once-processing">Exactly-Once Processing</a> in the <i>Amazon Simple Queue Service Developer Guide</i>. </p> <ul> <li> <p>Every message must have a unique <code>MessageDeduplicationId</code>,</p> <ul> <li> <p>You may provide a <code>MessageDeduplicationId</code> explicitly.</p> </li> <li> <p>If you aren&#39;t able to provide a <code>MessageDeduplicationId</code> and you enable <code>ContentBasedDeduplication</code> for your queue, Amazon SQS uses a SHA-256 hash to generate the <code>MessageDeduplicationId</code> using the body of the message (but not the attributes of the message). </p> </li> <li> <p>If you don&#39;t provide a <code>MessageDeduplicationId</code> and the queue doesn&#39;t have <code>ContentBasedDeduplication</code> set, the action fails with an error.</p> </li> <li> <p>If the queue has <code>ContentBasedDeduplication</code> set, your <code>MessageDeduplicationId</code> overrides the generated one.</p> </li> </ul> </li> <li> <p>When <code>ContentBasedDeduplication</code> is in effect, messages with identical content sent within the deduplication interval are treated as duplicates and only one copy of the message is delivered.</p> </li> <li> <p>If you send one message with <code>ContentBasedDeduplication</code> enabled and then another message with a <code>MessageDeduplicationId</code> that is the same as the one generated for the first <code>MessageDeduplicationId</code>, the two messages are treated as duplicates and only one copy of the message is delivered. </p> </li> </ul> </li> </ul></p>
-    pub attributes: ::std::collections::HashMap<String, String>,
+    pub attributes: ::std::collections::HashMap<QueueAttributeName, String>,
     /// <p>The URL of the Amazon SQS queue whose attributes are set.</p> <p>Queue URLs and names are case-sensitive.</p>
     pub queue_url: String,
 }
@@ -3177,11 +3628,12 @@ pub struct SqsClient {
 impl SqsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> SqsClient {
         SqsClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "SQS"),
         }
     }
 
@@ -3213,6 +3665,7 @@ impl Sqs for SqsClient {
         input: AddPermissionRequest,
     ) -> Result<(), RusotoError<AddPermissionError>> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("AddPermission");
         let mut params = Params::new();
 
         params.put("Action", "AddPermission");
@@ -3241,6 +3694,7 @@ impl Sqs for SqsClient {
         input: ChangeMessageVisibilityRequest,
     ) -> Result<(), RusotoError<ChangeMessageVisibilityError>> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("ChangeMessageVisibility");
         let mut params = Params::new();
 
         params.put("Action", "ChangeMessageVisibility");
@@ -3270,6 +3724,7 @@ impl Sqs for SqsClient {
     ) -> Result<ChangeMessageVisibilityBatchResult, RusotoError<ChangeMessageVisibilityBatchError>>
     {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("ChangeMessageVisibilityBatch");
         let mut params = Params::new();
 
         params.put("Action", "ChangeMessageVisibilityBatch");
@@ -3319,6 +3774,7 @@ impl Sqs for SqsClient {
         input: CreateQueueRequest,
     ) -> Result<CreateQueueResult, RusotoError<CreateQueueError>> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("CreateQueue");
         let mut params = Params::new();
 
         params.put("Action", "CreateQueue");
@@ -3365,6 +3821,7 @@ impl Sqs for SqsClient {
         input: DeleteMessageRequest,
     ) -> Result<(), RusotoError<DeleteMessageError>> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("DeleteMessage");
         let mut params = Params::new();
 
         params.put("Action", "DeleteMessage");
@@ -3393,6 +3850,7 @@ impl Sqs for SqsClient {
         input: DeleteMessageBatchRequest,
     ) -> Result<DeleteMessageBatchResult, RusotoError<DeleteMessageBatchError>> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("DeleteMessageBatch");
         let mut params = Params::new();
 
         params.put("Action", "DeleteMessageBatch");
@@ -3442,6 +3900,7 @@ impl Sqs for SqsClient {
         input: DeleteQueueRequest,
     ) -> Result<(), RusotoError<DeleteQueueError>> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("DeleteQueue");
         let mut params = Params::new();
 
         params.put("Action", "DeleteQueue");
@@ -3470,6 +3929,7 @@ impl Sqs for SqsClient {
         input: GetQueueAttributesRequest,
     ) -> Result<GetQueueAttributesResult, RusotoError<GetQueueAttributesError>> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("GetQueueAttributes");
         let mut params = Params::new();
 
         params.put("Action", "GetQueueAttributes");
@@ -3519,6 +3979,7 @@ impl Sqs for SqsClient {
         input: GetQueueUrlRequest,
     ) -> Result<GetQueueUrlResult, RusotoError<GetQueueUrlError>> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("GetQueueUrl");
         let mut params = Params::new();
 
         params.put("Action", "GetQueueUrl");
@@ -3566,6 +4027,7 @@ impl Sqs for SqsClient {
     ) -> Result<ListDeadLetterSourceQueuesResult, RusotoError<ListDeadLetterSourceQueuesError>>
     {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("ListDeadLetterSourceQueues");
         let mut params = Params::new();
 
         params.put("Action", "ListDeadLetterSourceQueues");
@@ -3615,6 +4077,7 @@ impl Sqs for SqsClient {
         input: ListQueueTagsRequest,
     ) -> Result<ListQueueTagsResult, RusotoError<ListQueueTagsError>> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("ListQueueTags");
         let mut params = Params::new();
 
         params.put("Action", "ListQueueTags");
@@ -3662,6 +4125,7 @@ impl Sqs for SqsClient {
         input: ListQueuesRequest,
     ) -> Result<ListQueuesResult, RusotoError<ListQueuesError>> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("ListQueues");
         let mut params = Params::new();
 
         params.put("Action", "ListQueues");
@@ -3708,6 +4172,7 @@ impl Sqs for SqsClient {
         input: PurgeQueueRequest,
     ) -> Result<(), RusotoError<PurgeQueueError>> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("PurgeQueue");
         let mut params = Params::new();
 
         params.put("Action", "PurgeQueue");
@@ -3736,6 +4201,7 @@ impl Sqs for SqsClient {
         input: ReceiveMessageRequest,
     ) -> Result<ReceiveMessageResult, RusotoError<ReceiveMessageError>> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("ReceiveMessage");
         let mut params = Params::new();
 
         params.put("Action", "ReceiveMessage");
@@ -3783,6 +4249,7 @@ impl Sqs for SqsClient {
         input: RemovePermissionRequest,
     ) -> Result<(), RusotoError<RemovePermissionError>> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("RemovePermission");
         let mut params = Params::new();
 
         params.put("Action", "RemovePermission");
@@ -3811,6 +4278,7 @@ impl Sqs for SqsClient {
         input: SendMessageRequest,
     ) -> Result<SendMessageResult, RusotoError<SendMessageError>> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("SendMessage");
         let mut params = Params::new();
 
         params.put("Action", "SendMessage");
@@ -3857,6 +4325,7 @@ impl Sqs for SqsClient {
         input: SendMessageBatchRequest,
     ) -> Result<SendMessageBatchResult, RusotoError<SendMessageBatchError>> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("SendMessageBatch");
         let mut params = Params::new();
 
         params.put("Action", "SendMessageBatch");
@@ -3906,6 +4375,7 @@ impl Sqs for SqsClient {
         input: SetQueueAttributesRequest,
     ) -> Result<(), RusotoError<SetQueueAttributesError>> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("SetQueueAttributes");
         let mut params = Params::new();
 
         params.put("Action", "SetQueueAttributes");
@@ -3931,6 +4401,7 @@ impl Sqs for SqsClient {
     /// <p><p>Add cost allocation tags to the specified Amazon SQS queue. For an overview, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-queue-tags.html">Tagging Your Amazon SQS Queues</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> <p>When you use queue tags, keep the following guidelines in mind:</p> <ul> <li> <p>Adding more than 50 tags to a queue isn&#39;t recommended.</p> </li> <li> <p>Tags don&#39;t have any semantic meaning. Amazon SQS interprets tags as character strings.</p> </li> <li> <p>Tags are case-sensitive.</p> </li> <li> <p>A new tag with a key identical to that of an existing tag overwrites the existing tag.</p> </li> </ul> <p>For a full list of tag restrictions, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-limits.html#limits-queues">Limits Related to Queues</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> <note> <p>Cross-account permissions don&#39;t apply to this action. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-customer-managed-policy-examples.html#grant-cross-account-permissions-to-role-and-user-name">Grant Cross-Account Permissions to a Role and a User Name</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> </note></p>
     async fn tag_queue(&self, input: TagQueueRequest) -> Result<(), RusotoError<TagQueueError>> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("TagQueue");
         let mut params = Params::new();
 
         params.put("Action", "TagQueue");
@@ -3959,6 +4430,7 @@ impl Sqs for SqsClient {
         input: UntagQueueRequest,
     ) -> Result<(), RusotoError<UntagQueueError>> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("UntagQueue");
         let mut params = Params::new();
 
         params.put("Action", "UntagQueue");
@@ -48,8 +48,13 @@ pub struct Activation {
     pub activation_id: Option<String>,
     /// <p>The date the activation was created.</p>
     #[serde(rename = "CreatedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A name for the managed instance when it is created.</p>
     #[serde(rename = "DefaultInstanceName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -60,8 +65,13 @@ pub struct Activation {
     pub description: Option<String>,
     /// <p>The date when this activation can no longer be used to register managed instances.</p>
     #[serde(rename = "ExpirationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expiration_date: Option<f64>,
+    pub expiration_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Whether or not the activation is expired.</p>
     #[serde(rename = "Expired")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -92,7 +102,7 @@ pub struct AddTagsToResourceRequest {
     pub resource_id: String,
     /// <p><p>Specifies the type of resource you are tagging.</p> <note> <p>The ManagedInstance type for this API action is for on-premises managed instances. You must specify the name of the managed instance in the following format: mi-ID_number. For example, mi-1a2b3c4d5e6f.</p> </note></p>
     #[serde(rename = "ResourceType")]
-    pub resource_type: String,
+    pub resource_type: ResourceTypeForTagging,
     /// <p><p> One or more tags. The value parameter is required, but if you don&#39;t want the tag to have a value, specify the parameter with no value, and we set the value to an empty string. </p> <important> <p>Do not enter personally identifiable information in this field.</p> </important></p>
     #[serde(rename = "Tags")]
     pub tags: Vec<Tag>,
@@ -128,8 +138,13 @@ pub struct Association {
     pub instance_id: Option<String>,
     /// <p>The date on which the association was last run.</p>
     #[serde(rename = "LastExecutionDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_execution_date: Option<f64>,
+    pub last_execution_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the Systems Manager document.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -148,6 +163,114 @@ pub struct Association {
     pub targets: Option<Vec<Target>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AssociationComplianceSeverity {
+    /// `CRITICAL`
+    Critical,
+    /// `HIGH`
+    High,
+    /// `MEDIUM`
+    Medium,
+    /// `LOW`
+    Low,
+    /// `UNSPECIFIED`
+    Unspecified,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AssociationComplianceSeverity {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AssociationComplianceSeverity::Critical => "CRITICAL",
+            AssociationComplianceSeverity::High => "HIGH",
+            AssociationComplianceSeverity::Medium => "MEDIUM",
+            AssociationComplianceSeverity::Low => "LOW",
+            AssociationComplianceSeverity::Unspecified => "UNSPECIFIED",
+            AssociationComplianceSeverity::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AssociationComplianceSeverity {
+    fn default() -> AssociationComplianceSeverity {
+        AssociationComplianceSeverity::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AssociationComplianceSeverity {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AssociationComplianceSeverity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AssociationComplianceSeverity {
+    fn from(value: &'a str) -> AssociationComplianceSeverity {
+        match value {
+            "CRITICAL" => AssociationComplianceSeverity::Critical,
+            "HIGH" => AssociationComplianceSeverity::High,
+            "MEDIUM" => AssociationComplianceSeverity::Medium,
+            "LOW" => AssociationComplianceSeverity::Low,
+            "UNSPECIFIED" => AssociationComplianceSeverity::Unspecified,
+            _ => AssociationComplianceSeverity::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AssociationComplianceSeverity {
+    fn from(value: String) -> AssociationComplianceSeverity {
+        match value.as_str() {
+            "CRITICAL" => AssociationComplianceSeverity::Critical,
+            "HIGH" => AssociationComplianceSeverity::High,
+            "MEDIUM" => AssociationComplianceSeverity::Medium,
+            "LOW" => AssociationComplianceSeverity::Low,
+            "UNSPECIFIED" => AssociationComplianceSeverity::Unspecified,
+            _ => AssociationComplianceSeverity::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AssociationComplianceSeverity {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AssociationComplianceSeverity, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AssociationComplianceSeverity {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AssociationComplianceSeverity {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AssociationComplianceSeverity {
+    fn deserialize<D>(deserializer: D) -> Result<AssociationComplianceSeverity, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes the parameters for a document.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -171,11 +294,16 @@ pub struct AssociationDescription {
     /// <p>The severity level that is assigned to the association.</p>
     #[serde(rename = "ComplianceSeverity")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compliance_severity: Option<String>,
+    pub compliance_severity: Option<AssociationComplianceSeverity>,
     /// <p>The date when the association was made.</p>
     #[serde(rename = "Date")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub date: Option<f64>,
+    pub date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The document version.</p>
     #[serde(rename = "DocumentVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -186,16 +314,31 @@ pub struct AssociationDescription {
     pub instance_id: Option<String>,
     /// <p>The date on which the association was last run.</p>
     #[serde(rename = "LastExecutionDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_execution_date: Option<f64>,
+    pub last_execution_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The last date on which the association was successfully run.</p>
     #[serde(rename = "LastSuccessfulExecutionDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_successful_execution_date: Option<f64>,
+    pub last_successful_execution_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The date when the association was last updated.</p>
     #[serde(rename = "LastUpdateAssociationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_update_association_date: Option<f64>,
+    pub last_update_association_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The maximum number of targets allowed to run the association at the same time. You can specify a number, for example 10, or a percentage of the target set, for example 10%. The default value is 100%, which means all targets run the association at the same time.</p> <p>If a new instance starts and attempts to run an association while Systems Manager is running MaxConcurrency associations, the association is allowed to run. During the next association interval, the new instance will process its association within the limit specified for MaxConcurrency.</p>
     #[serde(rename = "MaxConcurrency")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -248,8 +391,13 @@ pub struct AssociationExecution {
     pub association_version: Option<String>,
     /// <p>The time the execution started.</p>
     #[serde(rename = "CreatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time: Option<f64>,
+    pub created_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Detailed status information about the execution.</p>
     #[serde(rename = "DetailedStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -260,8 +408,13 @@ pub struct AssociationExecution {
     pub execution_id: Option<String>,
     /// <p>The date of the last execution.</p>
     #[serde(rename = "LastExecutionDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_execution_date: Option<f64>,
+    pub last_execution_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>An aggregate status of the resources in the execution based on the status type.</p>
     #[serde(rename = "ResourceCountByStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -278,15 +431,113 @@ pub struct AssociationExecution {
 pub struct AssociationExecutionFilter {
     /// <p>The key value used in the request.</p>
     #[serde(rename = "Key")]
-    pub key: String,
+    pub key: AssociationExecutionFilterKey,
     /// <p>The filter type specified in the request.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: AssociationFilterOperatorType,
     /// <p>The value specified for the key.</p>
     #[serde(rename = "Value")]
     pub value: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AssociationExecutionFilterKey {
+    /// `ExecutionId`
+    ExecutionId,
+    /// `Status`
+    Status,
+    /// `CreatedTime`
+    CreatedTime,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AssociationExecutionFilterKey {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AssociationExecutionFilterKey::ExecutionId => "ExecutionId",
+            AssociationExecutionFilterKey::Status => "Status",
+            AssociationExecutionFilterKey::CreatedTime => "CreatedTime",
+            AssociationExecutionFilterKey::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AssociationExecutionFilterKey {
+    fn default() -> AssociationExecutionFilterKey {
+        AssociationExecutionFilterKey::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AssociationExecutionFilterKey {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AssociationExecutionFilterKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AssociationExecutionFilterKey {
+    fn from(value: &'a str) -> AssociationExecutionFilterKey {
+        match value {
+            "ExecutionId" => AssociationExecutionFilterKey::ExecutionId,
+            "Status" => AssociationExecutionFilterKey::Status,
+            "CreatedTime" => AssociationExecutionFilterKey::CreatedTime,
+            _ => AssociationExecutionFilterKey::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AssociationExecutionFilterKey {
+    fn from(value: String) -> AssociationExecutionFilterKey {
+        match value.as_str() {
+            "ExecutionId" => AssociationExecutionFilterKey::ExecutionId,
+            "Status" => AssociationExecutionFilterKey::Status,
+            "CreatedTime" => AssociationExecutionFilterKey::CreatedTime,
+            _ => AssociationExecutionFilterKey::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AssociationExecutionFilterKey {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AssociationExecutionFilterKey, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AssociationExecutionFilterKey {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AssociationExecutionFilterKey {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AssociationExecutionFilterKey {
+    fn deserialize<D>(deserializer: D) -> Result<AssociationExecutionFilterKey, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Includes information about the specified association execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -309,8 +560,13 @@ pub struct AssociationExecutionTarget {
     pub execution_id: Option<String>,
     /// <p>The date of the last execution.</p>
     #[serde(rename = "LastExecutionDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_execution_date: Option<f64>,
+    pub last_execution_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The location where the association details are saved.</p>
     #[serde(rename = "OutputSource")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -335,24 +591,343 @@ pub struct AssociationExecutionTarget {
 pub struct AssociationExecutionTargetsFilter {
     /// <p>The key value used in the request.</p>
     #[serde(rename = "Key")]
-    pub key: String,
+    pub key: AssociationExecutionTargetsFilterKey,
     /// <p>The value specified for the key.</p>
     #[serde(rename = "Value")]
     pub value: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AssociationExecutionTargetsFilterKey {
+    /// `Status`
+    Status,
+    /// `ResourceId`
+    ResourceId,
+    /// `ResourceType`
+    ResourceType,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AssociationExecutionTargetsFilterKey {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AssociationExecutionTargetsFilterKey::Status => "Status",
+            AssociationExecutionTargetsFilterKey::ResourceId => "ResourceId",
+            AssociationExecutionTargetsFilterKey::ResourceType => "ResourceType",
+            AssociationExecutionTargetsFilterKey::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AssociationExecutionTargetsFilterKey {
+    fn default() -> AssociationExecutionTargetsFilterKey {
+        AssociationExecutionTargetsFilterKey::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AssociationExecutionTargetsFilterKey {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AssociationExecutionTargetsFilterKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AssociationExecutionTargetsFilterKey {
+    fn from(value: &'a str) -> AssociationExecutionTargetsFilterKey {
+        match value {
+            "Status" => AssociationExecutionTargetsFilterKey::Status,
+            "ResourceId" => AssociationExecutionTargetsFilterKey::ResourceId,
+            "ResourceType" => AssociationExecutionTargetsFilterKey::ResourceType,
+            _ => AssociationExecutionTargetsFilterKey::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AssociationExecutionTargetsFilterKey {
+    fn from(value: String) -> AssociationExecutionTargetsFilterKey {
+        match value.as_str() {
+            "Status" => AssociationExecutionTargetsFilterKey::Status,
+            "ResourceId" => AssociationExecutionTargetsFilterKey::ResourceId,
+            "ResourceType" => AssociationExecutionTargetsFilterKey::ResourceType,
+            _ => AssociationExecutionTargetsFilterKey::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AssociationExecutionTargetsFilterKey {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AssociationExecutionTargetsFilterKey, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AssociationExecutionTargetsFilterKey {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AssociationExecutionTargetsFilterKey {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AssociationExecutionTargetsFilterKey {
+    fn deserialize<D>(deserializer: D) -> Result<AssociationExecutionTargetsFilterKey, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes a filter.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct AssociationFilter {
     /// <p>The name of the filter.</p>
     #[serde(rename = "key")]
-    pub key: String,
+    pub key: AssociationFilterKey,
     /// <p>The filter value.</p>
     #[serde(rename = "value")]
     pub value: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AssociationFilterKey {
+    /// `InstanceId`
+    InstanceId,
+    /// `Name`
+    Name,
+    /// `AssociationId`
+    AssociationId,
+    /// `AssociationStatusName`
+    AssociationStatusName,
+    /// `LastExecutedBefore`
+    LastExecutedBefore,
+    /// `LastExecutedAfter`
+    LastExecutedAfter,
+    /// `AssociationName`
+    AssociationName,
+    /// `ResourceGroupName`
+    ResourceGroupName,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AssociationFilterKey {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AssociationFilterKey::InstanceId => "InstanceId",
+            AssociationFilterKey::Name => "Name",
+            AssociationFilterKey::AssociationId => "AssociationId",
+            AssociationFilterKey::AssociationStatusName => "AssociationStatusName",
+            AssociationFilterKey::LastExecutedBefore => "LastExecutedBefore",
+            AssociationFilterKey::LastExecutedAfter => "LastExecutedAfter",
+            AssociationFilterKey::AssociationName => "AssociationName",
+            AssociationFilterKey::ResourceGroupName => "ResourceGroupName",
+            AssociationFilterKey::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AssociationFilterKey {
+    fn default() -> AssociationFilterKey {
+        AssociationFilterKey::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AssociationFilterKey {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AssociationFilterKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AssociationFilterKey {
+    fn from(value: &'a str) -> AssociationFilterKey {
+        match value {
+            "InstanceId" => AssociationFilterKey::InstanceId,
+            "Name" => AssociationFilterKey::Name,
+            "AssociationId" => AssociationFilterKey::AssociationId,
+            "AssociationStatusName" => AssociationFilterKey::AssociationStatusName,
+            "LastExecutedBefore" => AssociationFilterKey::LastExecutedBefore,
+            "LastExecutedAfter" => AssociationFilterKey::LastExecutedAfter,
+            "AssociationName" => AssociationFilterKey::AssociationName,
+            "ResourceGroupName" => AssociationFilterKey::ResourceGroupName,
+            _ => AssociationFilterKey::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AssociationFilterKey {
+    fn from(value: String) -> AssociationFilterKey {
+        match value.as_str() {
+            "InstanceId" => AssociationFilterKey::InstanceId,
+            "Name" => AssociationFilterKey::Name,
+            "AssociationId" => AssociationFilterKey::AssociationId,
+            "AssociationStatusName" => AssociationFilterKey::AssociationStatusName,
+            "LastExecutedBefore" => AssociationFilterKey::LastExecutedBefore,
+            "LastExecutedAfter" => AssociationFilterKey::LastExecutedAfter,
+            "AssociationName" => AssociationFilterKey::AssociationName,
+            "ResourceGroupName" => AssociationFilterKey::ResourceGroupName,
+            _ => AssociationFilterKey::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AssociationFilterKey {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AssociationFilterKey, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AssociationFilterKey {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AssociationFilterKey {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AssociationFilterKey {
+    fn deserialize<D>(deserializer: D) -> Result<AssociationFilterKey, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AssociationFilterOperatorType {
+    /// `EQUAL`
+    Equal,
+    /// `LESS_THAN`
+    LessThan,
+    /// `GREATER_THAN`
+    GreaterThan,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AssociationFilterOperatorType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AssociationFilterOperatorType::Equal => "EQUAL",
+            AssociationFilterOperatorType::LessThan => "LESS_THAN",
+            AssociationFilterOperatorType::GreaterThan => "GREATER_THAN",
+            AssociationFilterOperatorType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AssociationFilterOperatorType {
+    fn default() -> AssociationFilterOperatorType {
+        AssociationFilterOperatorType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AssociationFilterOperatorType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AssociationFilterOperatorType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AssociationFilterOperatorType {
+    fn from(value: &'a str) -> AssociationFilterOperatorType {
+        match value {
+            "EQUAL" => AssociationFilterOperatorType::Equal,
+            "LESS_THAN" => AssociationFilterOperatorType::LessThan,
+            "GREATER_THAN" => AssociationFilterOperatorType::GreaterThan,
+            _ => AssociationFilterOperatorType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AssociationFilterOperatorType {
+    fn from(value: String) -> AssociationFilterOperatorType {
+        match value.as_str() {
+            "EQUAL" => AssociationFilterOperatorType::Equal,
+            "LESS_THAN" => AssociationFilterOperatorType::LessThan,
+            "GREATER_THAN" => AssociationFilterOperatorType::GreaterThan,
+            _ => AssociationFilterOperatorType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AssociationFilterOperatorType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AssociationFilterOperatorType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AssociationFilterOperatorType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AssociationFilterOperatorType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AssociationFilterOperatorType {
+    fn deserialize<D>(deserializer: D) -> Result<AssociationFilterOperatorType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about the association.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -380,39 +955,147 @@ pub struct AssociationStatus {
     pub additional_info: Option<String>,
     /// <p>The date when the status changed.</p>
     #[serde(rename = "Date")]
-    pub date: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub date: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The reason for the status.</p>
     #[serde(rename = "Message")]
     pub message: String,
     /// <p>The status.</p>
     #[serde(rename = "Name")]
-    pub name: String,
+    pub name: AssociationStatusName,
 }
 
-/// <p>Information about the association version.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct AssociationVersionInfo {
-    /// <p>The ID created by the system when the association was created.</p>
-    #[serde(rename = "AssociationId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub association_id: Option<String>,
-    /// <p>The name specified for the association version when the association version was created.</p>
-    #[serde(rename = "AssociationName")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub association_name: Option<String>,
-    /// <p>The association version.</p>
-    #[serde(rename = "AssociationVersion")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub association_version: Option<String>,
-    /// <p>The severity level that is assigned to the association.</p>
-    #[serde(rename = "ComplianceSeverity")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub compliance_severity: Option<String>,
-    /// <p>The date the association version was created.</p>
-    #[serde(rename = "CreatedDate")]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AssociationStatusName {
+    /// `Pending`
+    Pending,
+    /// `Success`
+    Success,
+    /// `Failed`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AssociationStatusName {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AssociationStatusName::Pending => "Pending",
+            AssociationStatusName::Success => "Success",
+            AssociationStatusName::Failed => "Failed",
+            AssociationStatusName::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AssociationStatusName {
+    fn default() -> AssociationStatusName {
+        AssociationStatusName::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AssociationStatusName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AssociationStatusName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AssociationStatusName {
+    fn from(value: &'a str) -> AssociationStatusName {
+        match value {
+            "Pending" => AssociationStatusName::Pending,
+            "Success" => AssociationStatusName::Success,
+            "Failed" => AssociationStatusName::Failed,
+            _ => AssociationStatusName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AssociationStatusName {
+    fn from(value: String) -> AssociationStatusName {
+        match value.as_str() {
+            "Pending" => AssociationStatusName::Pending,
+            "Success" => AssociationStatusName::Success,
+            "Failed" => AssociationStatusName::Failed,
+            _ => AssociationStatusName::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AssociationStatusName {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AssociationStatusName, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AssociationStatusName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AssociationStatusName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AssociationStatusName {
+    fn deserialize<D>(deserializer: D) -> Result<AssociationStatusName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Information about the association version.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct AssociationVersionInfo {
+    /// <p>The ID created by the system when the association was created.</p>
+    #[serde(rename = "AssociationId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub association_id: Option<String>,
+    /// <p>The name specified for the association version when the association version was created.</p>
+    #[serde(rename = "AssociationName")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub association_name: Option<String>,
+    /// <p>The association version.</p>
+    #[serde(rename = "AssociationVersion")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub association_version: Option<String>,
+    /// <p>The severity level that is assigned to the association.</p>
+    #[serde(rename = "ComplianceSeverity")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compliance_severity: Option<AssociationComplianceSeverity>,
+    /// <p>The date the association version was created.</p>
+    #[serde(rename = "CreatedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The version of a Systems Manager document used when the association version was created.</p>
     #[serde(rename = "DocumentVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -458,7 +1141,7 @@ pub struct AttachmentContent {
     /// <p>The hash algorithm used to calculate the hash value.</p>
     #[serde(rename = "HashType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub hash_type: Option<String>,
+    pub hash_type: Option<AttachmentHashType>,
     /// <p>The name of an attachment.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -473,6 +1156,94 @@ pub struct AttachmentContent {
     pub url: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AttachmentHashType {
+    /// `Sha256`
+    Sha256,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AttachmentHashType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AttachmentHashType::Sha256 => "Sha256",
+            AttachmentHashType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AttachmentHashType {
+    fn default() -> AttachmentHashType {
+        AttachmentHashType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AttachmentHashType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AttachmentHashType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AttachmentHashType {
+    fn from(value: &'a str) -> AttachmentHashType {
+        match value {
+            "Sha256" => AttachmentHashType::Sha256,
+            _ => AttachmentHashType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AttachmentHashType {
+    fn from(value: String) -> AttachmentHashType {
+        match value.as_str() {
+            "Sha256" => AttachmentHashType::Sha256,
+            _ => AttachmentHashType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AttachmentHashType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AttachmentHashType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AttachmentHashType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AttachmentHashType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AttachmentHashType {
+    fn deserialize<D>(deserializer: D) -> Result<AttachmentHashType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An attribute of an attachment, such as the attachment name.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -490,7 +1261,7 @@ pub struct AttachmentsSource {
     /// <p>The key of a key-value pair that identifies the location of an attachment to a document.</p>
     #[serde(rename = "Key")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub key: Option<String>,
+    pub key: Option<AttachmentsSourceKey>,
     /// <p>The name of the document attachment file.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -501,6 +1272,104 @@ pub struct AttachmentsSource {
     pub values: Option<Vec<String>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AttachmentsSourceKey {
+    /// `SourceUrl`
+    SourceUrl,
+    /// `S3FileUrl`
+    S3FileUrl,
+    /// `AttachmentReference`
+    AttachmentReference,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AttachmentsSourceKey {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AttachmentsSourceKey::SourceUrl => "SourceUrl",
+            AttachmentsSourceKey::S3FileUrl => "S3FileUrl",
+            AttachmentsSourceKey::AttachmentReference => "AttachmentReference",
+            AttachmentsSourceKey::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AttachmentsSourceKey {
+    fn default() -> AttachmentsSourceKey {
+        AttachmentsSourceKey::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AttachmentsSourceKey {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AttachmentsSourceKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AttachmentsSourceKey {
+    fn from(value: &'a str) -> AttachmentsSourceKey {
+        match value {
+            "SourceUrl" => AttachmentsSourceKey::SourceUrl,
+            "S3FileUrl" => AttachmentsSourceKey::S3FileUrl,
+            "AttachmentReference" => AttachmentsSourceKey::AttachmentReference,
+            _ => AttachmentsSourceKey::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AttachmentsSourceKey {
+    fn from(value: String) -> AttachmentsSourceKey {
+        match value.as_str() {
+            "SourceUrl" => AttachmentsSourceKey::SourceUrl,
+            "S3FileUrl" => AttachmentsSourceKey::S3FileUrl,
+            "AttachmentReference" => AttachmentsSourceKey::AttachmentReference,
+            _ => AttachmentsSourceKey::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AttachmentsSourceKey {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AttachmentsSourceKey, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AttachmentsSourceKey {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AttachmentsSourceKey {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AttachmentsSourceKey {
+    fn deserialize<D>(deserializer: D) -> Result<AttachmentsSourceKey, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Detailed information about the current state of an individual Automation execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -512,7 +1381,7 @@ pub struct AutomationExecution {
     /// <p>The execution status of the Automation.</p>
     #[serde(rename = "AutomationExecutionStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub automation_execution_status: Option<String>,
+    pub automation_execution_status: Option<AutomationExecutionStatus>,
     /// <p>The action of the step that is currently running.</p>
     #[serde(rename = "CurrentAction")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -535,12 +1404,22 @@ pub struct AutomationExecution {
     pub executed_by: Option<String>,
     /// <p>The time the execution finished.</p>
     #[serde(rename = "ExecutionEndTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub execution_end_time: Option<f64>,
+    pub execution_end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The time the execution started.</p>
     #[serde(rename = "ExecutionStartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub execution_start_time: Option<f64>,
+    pub execution_start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A message describing why an execution has failed, if the status is set to Failed.</p>
     #[serde(rename = "FailureMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -556,7 +1435,7 @@ pub struct AutomationExecution {
     /// <p>The automation execution mode.</p>
     #[serde(rename = "Mode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub mode: Option<String>,
+    pub mode: Option<ExecutionMode>,
     /// <p>The list of execution outputs as defined in the automation document.</p>
     #[serde(rename = "Outputs")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -613,12 +1492,155 @@ pub struct AutomationExecution {
 pub struct AutomationExecutionFilter {
     /// <p>One or more keys to limit the results. Valid filter keys include the following: DocumentNamePrefix, ExecutionStatus, ExecutionId, ParentExecutionId, CurrentAction, StartTimeBefore, StartTimeAfter.</p>
     #[serde(rename = "Key")]
-    pub key: String,
+    pub key: AutomationExecutionFilterKey,
     /// <p>The values used to limit the execution information associated with the filter's key.</p>
     #[serde(rename = "Values")]
     pub values: Vec<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AutomationExecutionFilterKey {
+    /// `DocumentNamePrefix`
+    DocumentNamePrefix,
+    /// `ExecutionStatus`
+    ExecutionStatus,
+    /// `ExecutionId`
+    ExecutionId,
+    /// `ParentExecutionId`
+    ParentExecutionId,
+    /// `CurrentAction`
+    CurrentAction,
+    /// `StartTimeBefore`
+    StartTimeBefore,
+    /// `StartTimeAfter`
+    StartTimeAfter,
+    /// `AutomationType`
+    AutomationType,
+    /// `TagKey`
+    TagKey,
+    /// `TargetResourceGroup`
+    TargetResourceGroup,
+    /// `AutomationSubtype`
+    AutomationSubtype,
+    /// `OpsItemId`
+    OpsItemId,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AutomationExecutionFilterKey {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AutomationExecutionFilterKey::DocumentNamePrefix => "DocumentNamePrefix",
+            AutomationExecutionFilterKey::ExecutionStatus => "ExecutionStatus",
+            AutomationExecutionFilterKey::ExecutionId => "ExecutionId",
+            AutomationExecutionFilterKey::ParentExecutionId => "ParentExecutionId",
+            AutomationExecutionFilterKey::CurrentAction => "CurrentAction",
+            AutomationExecutionFilterKey::StartTimeBefore => "StartTimeBefore",
+            AutomationExecutionFilterKey::StartTimeAfter => "StartTimeAfter",
+            AutomationExecutionFilterKey::AutomationType => "AutomationType",
+            AutomationExecutionFilterKey::TagKey => "TagKey",
+            AutomationExecutionFilterKey::TargetResourceGroup => "TargetResourceGroup",
+            AutomationExecutionFilterKey::AutomationSubtype => "AutomationSubtype",
+            AutomationExecutionFilterKey::OpsItemId => "OpsItemId",
+            AutomationExecutionFilterKey::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AutomationExecutionFilterKey {
+    fn default() -> AutomationExecutionFilterKey {
+        AutomationExecutionFilterKey::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AutomationExecutionFilterKey {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AutomationExecutionFilterKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AutomationExecutionFilterKey {
+    fn from(value: &'a str) -> AutomationExecutionFilterKey {
+        match value {
+            "DocumentNamePrefix" => AutomationExecutionFilterKey::DocumentNamePrefix,
+            "ExecutionStatus" => AutomationExecutionFilterKey::ExecutionStatus,
+            "ExecutionId" => AutomationExecutionFilterKey::ExecutionId,
+            "ParentExecutionId" => AutomationExecutionFilterKey::ParentExecutionId,
+            "CurrentAction" => AutomationExecutionFilterKey::CurrentAction,
+            "StartTimeBefore" => AutomationExecutionFilterKey::StartTimeBefore,
+            "StartTimeAfter" => AutomationExecutionFilterKey::StartTimeAfter,
+            "AutomationType" => AutomationExecutionFilterKey::AutomationType,
+            "TagKey" => AutomationExecutionFilterKey::TagKey,
+            "TargetResourceGroup" => AutomationExecutionFilterKey::TargetResourceGroup,
+            "AutomationSubtype" => AutomationExecutionFilterKey::AutomationSubtype,
+            "OpsItemId" => AutomationExecutionFilterKey::OpsItemId,
+            _ => AutomationExecutionFilterKey::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AutomationExecutionFilterKey {
+    fn from(value: String) -> AutomationExecutionFilterKey {
+        match value.as_str() {
+            "DocumentNamePrefix" => AutomationExecutionFilterKey::DocumentNamePrefix,
+            "ExecutionStatus" => AutomationExecutionFilterKey::ExecutionStatus,
+            "ExecutionId" => AutomationExecutionFilterKey::ExecutionId,
+            "ParentExecutionId" => AutomationExecutionFilterKey::ParentExecutionId,
+            "CurrentAction" => AutomationExecutionFilterKey::CurrentAction,
+            "StartTimeBefore" => AutomationExecutionFilterKey::StartTimeBefore,
+            "StartTimeAfter" => AutomationExecutionFilterKey::StartTimeAfter,
+            "AutomationType" => AutomationExecutionFilterKey::AutomationType,
+            "TagKey" => AutomationExecutionFilterKey::TagKey,
+            "TargetResourceGroup" => AutomationExecutionFilterKey::TargetResourceGroup,
+            "AutomationSubtype" => AutomationExecutionFilterKey::AutomationSubtype,
+            "OpsItemId" => AutomationExecutionFilterKey::OpsItemId,
+            _ => AutomationExecutionFilterKey::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AutomationExecutionFilterKey {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AutomationExecutionFilterKey, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AutomationExecutionFilterKey {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AutomationExecutionFilterKey {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AutomationExecutionFilterKey {
+    fn deserialize<D>(deserializer: D) -> Result<AutomationExecutionFilterKey, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Details about a specific Automation execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -630,11 +1652,11 @@ pub struct AutomationExecutionMetadata {
     /// <p>The status of the execution.</p>
     #[serde(rename = "AutomationExecutionStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub automation_execution_status: Option<String>,
+    pub automation_execution_status: Option<AutomationExecutionStatus>,
     /// <p>Use this filter with <a>DescribeAutomationExecutions</a>. Specify either Local or CrossAccount. CrossAccount is an Automation that runs in multiple AWS Regions and accounts. For more information, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/systems-manager-automation-multiple-accounts-and-regions.html">Executing Automations in Multiple AWS Regions and Accounts</a> in the <i>AWS Systems Manager User Guide</i>. </p>
     #[serde(rename = "AutomationType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub automation_type: Option<String>,
+    pub automation_type: Option<AutomationType>,
     /// <p>The action of the step that is currently running.</p>
     #[serde(rename = "CurrentAction")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -657,12 +1679,22 @@ pub struct AutomationExecutionMetadata {
     pub executed_by: Option<String>,
     /// <p>The time the execution finished. This is not populated if the execution is still in progress.</p>
     #[serde(rename = "ExecutionEndTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub execution_end_time: Option<f64>,
+    pub execution_end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The time the execution started.</p>
     #[serde(rename = "ExecutionStartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub execution_start_time: Option<f64>,
+    pub execution_start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The list of execution outputs as defined in the Automation document.</p>
     #[serde(rename = "FailureMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -682,7 +1714,7 @@ pub struct AutomationExecutionMetadata {
     /// <p>The Automation execution mode.</p>
     #[serde(rename = "Mode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub mode: Option<String>,
+    pub mode: Option<ExecutionMode>,
     /// <p>The list of execution outputs as defined in the Automation document.</p>
     #[serde(rename = "Outputs")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -713,51 +1745,433 @@ pub struct AutomationExecutionMetadata {
     pub targets: Option<Vec<Target>>,
 }
 
-/// <p><p/></p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct CancelCommandRequest {
-    /// <p>The ID of the command you want to cancel.</p>
-    #[serde(rename = "CommandId")]
-    pub command_id: String,
-    /// <p>(Optional) A list of instance IDs on which you want to cancel the command. If not provided, the command is canceled on every instance on which it was requested.</p>
-    #[serde(rename = "InstanceIds")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub instance_ids: Option<Vec<String>>,
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AutomationExecutionStatus {
+    /// `Pending`
+    Pending,
+    /// `InProgress`
+    InProgress,
+    /// `Waiting`
+    Waiting,
+    /// `Success`
+    Success,
+    /// `TimedOut`
+    TimedOut,
+    /// `Cancelling`
+    Cancelling,
+    /// `Cancelled`
+    Cancelled,
+    /// `Failed`
+    Failed,
+    /// `PendingApproval`
+    PendingApproval,
+    /// `Approved`
+    Approved,
+    /// `Rejected`
+    Rejected,
+    /// `Scheduled`
+    Scheduled,
+    /// `RunbookInProgress`
+    RunbookInProgress,
+    /// `PendingChangeCalendarOverride`
+    PendingChangeCalendarOverride,
+    /// `ChangeCalendarOverrideApproved`
+    ChangeCalendarOverrideApproved,
+    /// `ChangeCalendarOverrideRejected`
+    ChangeCalendarOverrideRejected,
+    /// `CompletedWithSuccess`
+    CompletedWithSuccess,
+    /// `CompletedWithFailure`
+    CompletedWithFailure,
+    /// `Exited`
+    Exited,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AutomationExecutionStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AutomationExecutionStatus::Pending => "Pending",
+            AutomationExecutionStatus::InProgress => "InProgress",
+            AutomationExecutionStatus::Waiting => "Waiting",
+            AutomationExecutionStatus::Success => "Success",
+            AutomationExecutionStatus::TimedOut => "TimedOut",
+            AutomationExecutionStatus::Cancelling => "Cancelling",
+            AutomationExecutionStatus::Cancelled => "Cancelled",
+            AutomationExecutionStatus::Failed => "Failed",
+            AutomationExecutionStatus::PendingApproval => "PendingApproval",
+            AutomationExecutionStatus::Approved => "Approved",
+            AutomationExecutionStatus::Rejected => "Rejected",
+            AutomationExecutionStatus::Scheduled => "Scheduled",
+            AutomationExecutionStatus::RunbookInProgress => "RunbookInProgress",
+            AutomationExecutionStatus::PendingChangeCalendarOverride => {
+                "PendingChangeCalendarOverride"
+            }
+            AutomationExecutionStatus::ChangeCalendarOverrideApproved => {
+                "ChangeCalendarOverrideApproved"
+            }
+            AutomationExecutionStatus::ChangeCalendarOverrideRejected => {
+                "ChangeCalendarOverrideRejected"
+            }
+            AutomationExecutionStatus::CompletedWithSuccess => "CompletedWithSuccess",
+            AutomationExecutionStatus::CompletedWithFailure => "CompletedWithFailure",
+            AutomationExecutionStatus::Exited => "Exited",
+            AutomationExecutionStatus::Unknown(ref value) => value,
+        }
+    }
 }
 
-/// <p>Whether or not the command was successfully canceled. There is no guarantee that a request can be canceled.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct CancelCommandResult {}
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AutomationExecutionStatus {
+    fn default() -> AutomationExecutionStatus {
+        AutomationExecutionStatus::Unknown(String::new())
+    }
+}
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct CancelMaintenanceWindowExecutionRequest {
-    /// <p>The ID of the maintenance window execution to stop.</p>
-    #[serde(rename = "WindowExecutionId")]
-    pub window_execution_id: String,
+impl AsRef<str> for AutomationExecutionStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct CancelMaintenanceWindowExecutionResult {
-    /// <p>The ID of the maintenance window execution that has been stopped.</p>
-    #[serde(rename = "WindowExecutionId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub window_execution_id: Option<String>,
+impl fmt::Display for AutomationExecutionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AutomationExecutionStatus {
+    fn from(value: &'a str) -> AutomationExecutionStatus {
+        match value {
+            "Pending" => AutomationExecutionStatus::Pending,
+            "InProgress" => AutomationExecutionStatus::InProgress,
+            "Waiting" => AutomationExecutionStatus::Waiting,
+            "Success" => AutomationExecutionStatus::Success,
+            "TimedOut" => AutomationExecutionStatus::TimedOut,
+            "Cancelling" => AutomationExecutionStatus::Cancelling,
+            "Cancelled" => AutomationExecutionStatus::Cancelled,
+            "Failed" => AutomationExecutionStatus::Failed,
+            "PendingApproval" => AutomationExecutionStatus::PendingApproval,
+            "Approved" => AutomationExecutionStatus::Approved,
+            "Rejected" => AutomationExecutionStatus::Rejected,
+            "Scheduled" => AutomationExecutionStatus::Scheduled,
+            "RunbookInProgress" => AutomationExecutionStatus::RunbookInProgress,
+            "PendingChangeCalendarOverride" => {
+                AutomationExecutionStatus::PendingChangeCalendarOverride
+            }
+            "ChangeCalendarOverrideApproved" => {
+                AutomationExecutionStatus::ChangeCalendarOverrideApproved
+            }
+            "ChangeCalendarOverrideRejected" => {
+                AutomationExecutionStatus::ChangeCalendarOverrideRejected
+            }
+            "CompletedWithSuccess" => AutomationExecutionStatus::CompletedWithSuccess,
+            "CompletedWithFailure" => AutomationExecutionStatus::CompletedWithFailure,
+            "Exited" => AutomationExecutionStatus::Exited,
+            _ => AutomationExecutionStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AutomationExecutionStatus {
+    fn from(value: String) -> AutomationExecutionStatus {
+        match value.as_str() {
+            "Pending" => AutomationExecutionStatus::Pending,
+            "InProgress" => AutomationExecutionStatus::InProgress,
+            "Waiting" => AutomationExecutionStatus::Waiting,
+            "Success" => AutomationExecutionStatus::Success,
+            "TimedOut" => AutomationExecutionStatus::TimedOut,
+            "Cancelling" => AutomationExecutionStatus::Cancelling,
+            "Cancelled" => AutomationExecutionStatus::Cancelled,
+            "Failed" => AutomationExecutionStatus::Failed,
+            "PendingApproval" => AutomationExecutionStatus::PendingApproval,
+            "Approved" => AutomationExecutionStatus::Approved,
+            "Rejected" => AutomationExecutionStatus::Rejected,
+            "Scheduled" => AutomationExecutionStatus::Scheduled,
+            "RunbookInProgress" => AutomationExecutionStatus::RunbookInProgress,
+            "PendingChangeCalendarOverride" => {
+                AutomationExecutionStatus::PendingChangeCalendarOverride
+            }
+            "ChangeCalendarOverrideApproved" => {
+                AutomationExecutionStatus::ChangeCalendarOverrideApproved
+            }
+            "ChangeCalendarOverrideRejected" => {
+                AutomationExecutionStatus::ChangeCalendarOverrideRejected
+            }
+            "CompletedWithSuccess" => AutomationExecutionStatus::CompletedWithSuccess,
+            "CompletedWithFailure" => AutomationExecutionStatus::CompletedWithFailure,
+            "Exited" => AutomationExecutionStatus::Exited,
+            _ => AutomationExecutionStatus::Unknown(value),
+        }
+    }
 }
 
-/// <p>Configuration options for sending command output to CloudWatch Logs.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct CloudWatchOutputConfig {
-    /// <p>The name of the CloudWatch log group where you want to send command output. If you don't specify a group name, Systems Manager automatically creates a log group for you. The log group uses the following naming format: aws/ssm/<i>SystemsManagerDocumentName</i>.</p>
-    #[serde(rename = "CloudWatchLogGroupName")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub cloud_watch_log_group_name: Option<String>,
-    /// <p>Enables Systems Manager to send command output to CloudWatch Logs.</p>
-    #[serde(rename = "CloudWatchOutputEnabled")]
-    #[serde(skip_serializing_if = "Option::is_none")]
+impl ::std::str::FromStr for AutomationExecutionStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AutomationExecutionStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AutomationExecutionStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AutomationExecutionStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AutomationExecutionStatus {
+    fn deserialize<D>(deserializer: D) -> Result<AutomationExecutionStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AutomationType {
+    /// `CrossAccount`
+    CrossAccount,
+    /// `Local`
+    Local,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AutomationType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AutomationType::CrossAccount => "CrossAccount",
+            AutomationType::Local => "Local",
+            AutomationType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AutomationType {
+    fn default() -> AutomationType {
+        AutomationType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AutomationType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AutomationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AutomationType {
+    fn from(value: &'a str) -> AutomationType {
+        match value {
+            "CrossAccount" => AutomationType::CrossAccount,
+            "Local" => AutomationType::Local,
+            _ => AutomationType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AutomationType {
+    fn from(value: String) -> AutomationType {
+        match value.as_str() {
+            "CrossAccount" => AutomationType::CrossAccount,
+            "Local" => AutomationType::Local,
+            _ => AutomationType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AutomationType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AutomationType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AutomationType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AutomationType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AutomationType {
+    fn deserialize<D>(deserializer: D) -> Result<AutomationType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CalendarState {
+    /// `OPEN`
+    Open,
+    /// `CLOSED`
+    Closed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CalendarState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CalendarState::Open => "OPEN",
+            CalendarState::Closed => "CLOSED",
+            CalendarState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CalendarState {
+    fn default() -> CalendarState {
+        CalendarState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CalendarState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CalendarState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CalendarState {
+    fn from(value: &'a str) -> CalendarState {
+        match value {
+            "OPEN" => CalendarState::Open,
+            "CLOSED" => CalendarState::Closed,
+            _ => CalendarState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CalendarState {
+    fn from(value: String) -> CalendarState {
+        match value.as_str() {
+            "OPEN" => CalendarState::Open,
+            "CLOSED" => CalendarState::Closed,
+            _ => CalendarState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CalendarState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CalendarState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CalendarState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CalendarState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CalendarState {
+    fn deserialize<D>(deserializer: D) -> Result<CalendarState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p><p/></p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct CancelCommandRequest {
+    /// <p>The ID of the command you want to cancel.</p>
+    #[serde(rename = "CommandId")]
+    pub command_id: String,
+    /// <p>(Optional) A list of instance IDs on which you want to cancel the command. If not provided, the command is canceled on every instance on which it was requested.</p>
+    #[serde(rename = "InstanceIds")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instance_ids: Option<Vec<String>>,
+}
+
+/// <p>Whether or not the command was successfully canceled. There is no guarantee that a request can be canceled.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct CancelCommandResult {}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct CancelMaintenanceWindowExecutionRequest {
+    /// <p>The ID of the maintenance window execution to stop.</p>
+    #[serde(rename = "WindowExecutionId")]
+    pub window_execution_id: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct CancelMaintenanceWindowExecutionResult {
+    /// <p>The ID of the maintenance window execution that has been stopped.</p>
+    #[serde(rename = "WindowExecutionId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub window_execution_id: Option<String>,
+}
+
+/// <p>Configuration options for sending command output to CloudWatch Logs.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CloudWatchOutputConfig {
+    /// <p>The name of the CloudWatch log group where you want to send command output. If you don't specify a group name, Systems Manager automatically creates a log group for you. The log group uses the following naming format: aws/ssm/<i>SystemsManagerDocumentName</i>.</p>
+    #[serde(rename = "CloudWatchLogGroupName")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cloud_watch_log_group_name: Option<String>,
+    /// <p>Enables Systems Manager to send command output to CloudWatch Logs.</p>
+    #[serde(rename = "CloudWatchOutputEnabled")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub cloud_watch_output_enabled: Option<bool>,
 }
 
@@ -799,8 +2213,13 @@ pub struct Command {
     pub error_count: Option<i64>,
     /// <p>If this time is reached and the command has not already started running, it will not run. Calculated based on the ExpiresAfter user input provided as part of the SendCommand API.</p>
     #[serde(rename = "ExpiresAfter")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expires_after: Option<f64>,
+    pub expires_after: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The instance IDs against which this command was requested.</p>
     #[serde(rename = "InstanceIds")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -835,8 +2254,13 @@ pub struct Command {
     pub parameters: Option<::std::collections::HashMap<String, Vec<String>>>,
     /// <p>The date and time the command was requested.</p>
     #[serde(rename = "RequestedDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub requested_date_time: Option<f64>,
+    pub requested_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The IAM service role that Run Command uses to act on your behalf when sending notifications about command status changes. </p>
     #[serde(rename = "ServiceRole")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -844,7 +2268,7 @@ pub struct Command {
     /// <p>The status of the command.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<CommandStatus>,
     /// <p><p>A detailed status of the command execution. StatusDetails includes more information than Status because it includes states resulting from error and concurrency control parameters. StatusDetails can show different results than Status. For more information about these statuses, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/monitor-commands.html">Understanding Command Statuses</a> in the <i>AWS Systems Manager User Guide</i>. StatusDetails can be one of the following values:</p> <ul> <li> <p>Pending: The command has not been sent to any instances.</p> </li> <li> <p>In Progress: The command has been sent to at least one instance but has not reached a final state on all instances.</p> </li> <li> <p>Success: The command successfully ran on all invocations. This is a terminal state.</p> </li> <li> <p>Delivery Timed Out: The value of MaxErrors or more command invocations shows a status of Delivery Timed Out. This is a terminal state.</p> </li> <li> <p>Execution Timed Out: The value of MaxErrors or more command invocations shows a status of Execution Timed Out. This is a terminal state.</p> </li> <li> <p>Failed: The value of MaxErrors or more command invocations shows a status of Failed. This is a terminal state.</p> </li> <li> <p>Incomplete: The command was attempted on all instances and one or more invocations does not have a value of Success but not enough invocations failed for the status to be Failed. This is a terminal state.</p> </li> <li> <p>Canceled: The command was terminated before it was completed. This is a terminal state.</p> </li> <li> <p>Rate Exceeded: The number of instances targeted by the command exceeded the account limit for pending invocations. The system has canceled the command before running it on any instance. This is a terminal state.</p> </li> </ul></p>
     #[serde(rename = "StatusDetails")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -865,12 +2289,120 @@ pub struct Command {
 pub struct CommandFilter {
     /// <p>The name of the filter.</p>
     #[serde(rename = "key")]
-    pub key: String,
+    pub key: CommandFilterKey,
     /// <p><p>The filter value. Valid values for each filter key are as follows:</p> <ul> <li> <p> <b>InvokedAfter</b>: Specify a timestamp to limit your results. For example, specify <code>2018-07-07T00:00:00Z</code> to see a list of command executions occurring July 7, 2018, and later.</p> </li> <li> <p> <b>InvokedBefore</b>: Specify a timestamp to limit your results. For example, specify <code>2018-07-07T00:00:00Z</code> to see a list of command executions from before July 7, 2018.</p> </li> <li> <p> <b>Status</b>: Specify a valid command status to see a list of all command executions with that status. Status values you can specify include:</p> <ul> <li> <p> <code>Pending</code> </p> </li> <li> <p> <code>InProgress</code> </p> </li> <li> <p> <code>Success</code> </p> </li> <li> <p> <code>Cancelled</code> </p> </li> <li> <p> <code>Failed</code> </p> </li> <li> <p> <code>TimedOut</code> </p> </li> <li> <p> <code>Cancelling</code> </p> </li> </ul> </li> <li> <p> <b>DocumentName</b>: Specify name of the SSM document for which you want to see command execution results. For example, specify <code>AWS-RunPatchBaseline</code> to see command executions that used this SSM document to perform security patching operations on instances. </p> </li> <li> <p> <b>ExecutionStage</b>: Specify one of the following values:</p> <ul> <li> <p> <code>Executing</code>: Returns a list of command executions that are currently still running.</p> </li> <li> <p> <code>Complete</code>: Returns a list of command executions that have already completed. </p> </li> </ul> </li> </ul></p>
     #[serde(rename = "value")]
     pub value: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CommandFilterKey {
+    /// `InvokedAfter`
+    InvokedAfter,
+    /// `InvokedBefore`
+    InvokedBefore,
+    /// `Status`
+    Status,
+    /// `ExecutionStage`
+    ExecutionStage,
+    /// `DocumentName`
+    DocumentName,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CommandFilterKey {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CommandFilterKey::InvokedAfter => "InvokedAfter",
+            CommandFilterKey::InvokedBefore => "InvokedBefore",
+            CommandFilterKey::Status => "Status",
+            CommandFilterKey::ExecutionStage => "ExecutionStage",
+            CommandFilterKey::DocumentName => "DocumentName",
+            CommandFilterKey::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CommandFilterKey {
+    fn default() -> CommandFilterKey {
+        CommandFilterKey::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CommandFilterKey {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CommandFilterKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CommandFilterKey {
+    fn from(value: &'a str) -> CommandFilterKey {
+        match value {
+            "InvokedAfter" => CommandFilterKey::InvokedAfter,
+            "InvokedBefore" => CommandFilterKey::InvokedBefore,
+            "Status" => CommandFilterKey::Status,
+            "ExecutionStage" => CommandFilterKey::ExecutionStage,
+            "DocumentName" => CommandFilterKey::DocumentName,
+            _ => CommandFilterKey::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CommandFilterKey {
+    fn from(value: String) -> CommandFilterKey {
+        match value.as_str() {
+            "InvokedAfter" => CommandFilterKey::InvokedAfter,
+            "InvokedBefore" => CommandFilterKey::InvokedBefore,
+            "Status" => CommandFilterKey::Status,
+            "ExecutionStage" => CommandFilterKey::ExecutionStage,
+            "DocumentName" => CommandFilterKey::DocumentName,
+            _ => CommandFilterKey::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CommandFilterKey {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CommandFilterKey, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CommandFilterKey {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CommandFilterKey {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CommandFilterKey {
+    fn deserialize<D>(deserializer: D) -> Result<CommandFilterKey, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An invocation is copy of a command sent to a specific instance. A command can apply to one or more instances. A command invocation applies to one instance. For example, if a user runs SendCommand against three instances, then a command invocation is created for each requested instance ID. A command invocation returns status and detail information about a command you ran. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -912,8 +2444,13 @@ pub struct CommandInvocation {
     pub notification_config: Option<NotificationConfig>,
     /// <p>The time and date the request was sent to this instance.</p>
     #[serde(rename = "RequestedDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub requested_date_time: Option<f64>,
+    pub requested_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The IAM service role that Run Command uses to act on your behalf when sending notifications about command status changes on a per instance basis.</p>
     #[serde(rename = "ServiceRole")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -929,7 +2466,7 @@ pub struct CommandInvocation {
     /// <p>Whether or not the invocation succeeded, failed, or is pending.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<CommandInvocationStatus>,
     /// <p><p>A detailed status of the command execution for each invocation (each instance targeted by the command). StatusDetails includes more information than Status because it includes states resulting from error and concurrency control parameters. StatusDetails can show different results than Status. For more information about these statuses, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/monitor-commands.html">Understanding Command Statuses</a> in the <i>AWS Systems Manager User Guide</i>. StatusDetails can be one of the following values:</p> <ul> <li> <p>Pending: The command has not been sent to the instance.</p> </li> <li> <p>In Progress: The command has been sent to the instance but has not reached a terminal state.</p> </li> <li> <p>Success: The execution of the command or plugin was successfully completed. This is a terminal state.</p> </li> <li> <p>Delivery Timed Out: The command was not delivered to the instance before the delivery timeout expired. Delivery timeouts do not count against the parent command&#39;s MaxErrors limit, but they do contribute to whether the parent command status is Success or Incomplete. This is a terminal state.</p> </li> <li> <p>Execution Timed Out: Command execution started on the instance, but the execution was not complete before the execution timeout expired. Execution timeouts count against the MaxErrors limit of the parent command. This is a terminal state.</p> </li> <li> <p>Failed: The command was not successful on the instance. For a plugin, this indicates that the result code was not zero. For a command invocation, this indicates that the result code for one or more plugins was not zero. Invocation failures count against the MaxErrors limit of the parent command. This is a terminal state.</p> </li> <li> <p>Canceled: The command was terminated before it was completed. This is a terminal state.</p> </li> <li> <p>Undeliverable: The command can&#39;t be delivered to the instance. The instance might not exist or might not be responding. Undeliverable invocations don&#39;t count against the parent command&#39;s MaxErrors limit and don&#39;t contribute to whether the parent command status is Success or Incomplete. This is a terminal state.</p> </li> <li> <p>Terminated: The parent command exceeded its MaxErrors limit and subsequent command invocations were canceled by the system. This is a terminal state.</p> </li> </ul></p>
     #[serde(rename = "StatusDetails")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -940,6 +2477,129 @@ pub struct CommandInvocation {
     pub trace_output: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CommandInvocationStatus {
+    /// `Pending`
+    Pending,
+    /// `InProgress`
+    InProgress,
+    /// `Delayed`
+    Delayed,
+    /// `Success`
+    Success,
+    /// `Cancelled`
+    Cancelled,
+    /// `TimedOut`
+    TimedOut,
+    /// `Failed`
+    Failed,
+    /// `Cancelling`
+    Cancelling,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CommandInvocationStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CommandInvocationStatus::Pending => "Pending",
+            CommandInvocationStatus::InProgress => "InProgress",
+            CommandInvocationStatus::Delayed => "Delayed",
+            CommandInvocationStatus::Success => "Success",
+            CommandInvocationStatus::Cancelled => "Cancelled",
+            CommandInvocationStatus::TimedOut => "TimedOut",
+            CommandInvocationStatus::Failed => "Failed",
+            CommandInvocationStatus::Cancelling => "Cancelling",
+            CommandInvocationStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CommandInvocationStatus {
+    fn default() -> CommandInvocationStatus {
+        CommandInvocationStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CommandInvocationStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CommandInvocationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CommandInvocationStatus {
+    fn from(value: &'a str) -> CommandInvocationStatus {
+        match value {
+            "Pending" => CommandInvocationStatus::Pending,
+            "InProgress" => CommandInvocationStatus::InProgress,
+            "Delayed" => CommandInvocationStatus::Delayed,
+            "Success" => CommandInvocationStatus::Success,
+            "Cancelled" => CommandInvocationStatus::Cancelled,
+            "TimedOut" => CommandInvocationStatus::TimedOut,
+            "Failed" => CommandInvocationStatus::Failed,
+            "Cancelling" => CommandInvocationStatus::Cancelling,
+            _ => CommandInvocationStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CommandInvocationStatus {
+    fn from(value: String) -> CommandInvocationStatus {
+        match value.as_str() {
+            "Pending" => CommandInvocationStatus::Pending,
+            "InProgress" => CommandInvocationStatus::InProgress,
+            "Delayed" => CommandInvocationStatus::Delayed,
+            "Success" => CommandInvocationStatus::Success,
+            "Cancelled" => CommandInvocationStatus::Cancelled,
+            "TimedOut" => CommandInvocationStatus::TimedOut,
+            "Failed" => CommandInvocationStatus::Failed,
+            "Cancelling" => CommandInvocationStatus::Cancelling,
+            _ => CommandInvocationStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CommandInvocationStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CommandInvocationStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CommandInvocationStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CommandInvocationStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CommandInvocationStatus {
+    fn deserialize<D>(deserializer: D) -> Result<CommandInvocationStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes plugin details.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -970,12 +2630,22 @@ pub struct CommandPlugin {
     pub response_code: Option<i64>,
     /// <p>The time the plugin stopped running. Could stop prematurely if, for example, a cancel command was sent. </p>
     #[serde(rename = "ResponseFinishDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub response_finish_date_time: Option<f64>,
+    pub response_finish_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The time the plugin started running. </p>
     #[serde(rename = "ResponseStartDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub response_start_date_time: Option<f64>,
+    pub response_start_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The URL for the complete text written by the plugin to stderr. If execution is not yet complete, then this string is empty.</p>
     #[serde(rename = "StandardErrorUrl")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -987,27 +2657,263 @@ pub struct CommandPlugin {
     /// <p>The status of this plugin. You can run a document with multiple plugins.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<CommandPluginStatus>,
     /// <p><p>A detailed status of the plugin execution. StatusDetails includes more information than Status because it includes states resulting from error and concurrency control parameters. StatusDetails can show different results than Status. For more information about these statuses, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/monitor-commands.html">Understanding Command Statuses</a> in the <i>AWS Systems Manager User Guide</i>. StatusDetails can be one of the following values:</p> <ul> <li> <p>Pending: The command has not been sent to the instance.</p> </li> <li> <p>In Progress: The command has been sent to the instance but has not reached a terminal state.</p> </li> <li> <p>Success: The execution of the command or plugin was successfully completed. This is a terminal state.</p> </li> <li> <p>Delivery Timed Out: The command was not delivered to the instance before the delivery timeout expired. Delivery timeouts do not count against the parent command&#39;s MaxErrors limit, but they do contribute to whether the parent command status is Success or Incomplete. This is a terminal state.</p> </li> <li> <p>Execution Timed Out: Command execution started on the instance, but the execution was not complete before the execution timeout expired. Execution timeouts count against the MaxErrors limit of the parent command. This is a terminal state.</p> </li> <li> <p>Failed: The command was not successful on the instance. For a plugin, this indicates that the result code was not zero. For a command invocation, this indicates that the result code for one or more plugins was not zero. Invocation failures count against the MaxErrors limit of the parent command. This is a terminal state.</p> </li> <li> <p>Canceled: The command was terminated before it was completed. This is a terminal state.</p> </li> <li> <p>Undeliverable: The command can&#39;t be delivered to the instance. The instance might not exist, or it might not be responding. Undeliverable invocations don&#39;t count against the parent command&#39;s MaxErrors limit, and they don&#39;t contribute to whether the parent command status is Success or Incomplete. This is a terminal state.</p> </li> <li> <p>Terminated: The parent command exceeded its MaxErrors limit and subsequent command invocations were canceled by the system. This is a terminal state.</p> </li> </ul></p>
     #[serde(rename = "StatusDetails")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status_details: Option<String>,
 }
 
-/// <p>A summary of the call execution that includes an execution ID, the type of execution (for example, <code>Command</code>), and the date/time of the execution using a datetime object that is saved in the following format: yyyy-MM-dd'T'HH:mm:ss'Z'.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct ComplianceExecutionSummary {
-    /// <p>An ID created by the system when <code>PutComplianceItems</code> was called. For example, <code>CommandID</code> is a valid execution ID. You can use this ID in subsequent calls.</p>
-    #[serde(rename = "ExecutionId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub execution_id: Option<String>,
-    /// <p>The time the execution ran as a datetime object that is saved in the following format: yyyy-MM-dd'T'HH:mm:ss'Z'.</p>
-    #[serde(rename = "ExecutionTime")]
-    pub execution_time: f64,
-    /// <p>The type of execution. For example, <code>Command</code> is a valid execution type.</p>
-    #[serde(rename = "ExecutionType")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub execution_type: Option<String>,
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CommandPluginStatus {
+    /// `Pending`
+    Pending,
+    /// `InProgress`
+    InProgress,
+    /// `Success`
+    Success,
+    /// `TimedOut`
+    TimedOut,
+    /// `Cancelled`
+    Cancelled,
+    /// `Failed`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CommandPluginStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CommandPluginStatus::Pending => "Pending",
+            CommandPluginStatus::InProgress => "InProgress",
+            CommandPluginStatus::Success => "Success",
+            CommandPluginStatus::TimedOut => "TimedOut",
+            CommandPluginStatus::Cancelled => "Cancelled",
+            CommandPluginStatus::Failed => "Failed",
+            CommandPluginStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CommandPluginStatus {
+    fn default() -> CommandPluginStatus {
+        CommandPluginStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CommandPluginStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CommandPluginStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CommandPluginStatus {
+    fn from(value: &'a str) -> CommandPluginStatus {
+        match value {
+            "Pending" => CommandPluginStatus::Pending,
+            "InProgress" => CommandPluginStatus::InProgress,
+            "Success" => CommandPluginStatus::Success,
+            "TimedOut" => CommandPluginStatus::TimedOut,
+            "Cancelled" => CommandPluginStatus::Cancelled,
+            "Failed" => CommandPluginStatus::Failed,
+            _ => CommandPluginStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CommandPluginStatus {
+    fn from(value: String) -> CommandPluginStatus {
+        match value.as_str() {
+            "Pending" => CommandPluginStatus::Pending,
+            "InProgress" => CommandPluginStatus::InProgress,
+            "Success" => CommandPluginStatus::Success,
+            "TimedOut" => CommandPluginStatus::TimedOut,
+            "Cancelled" => CommandPluginStatus::Cancelled,
+            "Failed" => CommandPluginStatus::Failed,
+            _ => CommandPluginStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CommandPluginStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CommandPluginStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CommandPluginStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CommandPluginStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CommandPluginStatus {
+    fn deserialize<D>(deserializer: D) -> Result<CommandPluginStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CommandStatus {
+    /// `Pending`
+    Pending,
+    /// `InProgress`
+    InProgress,
+    /// `Success`
+    Success,
+    /// `Cancelled`
+    Cancelled,
+    /// `Failed`
+    Failed,
+    /// `TimedOut`
+    TimedOut,
+    /// `Cancelling`
+    Cancelling,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CommandStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CommandStatus::Pending => "Pending",
+            CommandStatus::InProgress => "InProgress",
+            CommandStatus::Success => "Success",
+            CommandStatus::Cancelled => "Cancelled",
+            CommandStatus::Failed => "Failed",
+            CommandStatus::TimedOut => "TimedOut",
+            CommandStatus::Cancelling => "Cancelling",
+            CommandStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CommandStatus {
+    fn default() -> CommandStatus {
+        CommandStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CommandStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CommandStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CommandStatus {
+    fn from(value: &'a str) -> CommandStatus {
+        match value {
+            "Pending" => CommandStatus::Pending,
+            "InProgress" => CommandStatus::InProgress,
+            "Success" => CommandStatus::Success,
+            "Cancelled" => CommandStatus::Cancelled,
+            "Failed" => CommandStatus::Failed,
+            "TimedOut" => CommandStatus::TimedOut,
+            "Cancelling" => CommandStatus::Cancelling,
+            _ => CommandStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CommandStatus {
+    fn from(value: String) -> CommandStatus {
+        match value.as_str() {
+            "Pending" => CommandStatus::Pending,
+            "InProgress" => CommandStatus::InProgress,
+            "Success" => CommandStatus::Success,
+            "Cancelled" => CommandStatus::Cancelled,
+            "Failed" => CommandStatus::Failed,
+            "TimedOut" => CommandStatus::TimedOut,
+            "Cancelling" => CommandStatus::Cancelling,
+            _ => CommandStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CommandStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CommandStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CommandStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CommandStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CommandStatus {
+    fn deserialize<D>(deserializer: D) -> Result<CommandStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>A summary of the call execution that includes an execution ID, the type of execution (for example, <code>Command</code>), and the date/time of the execution using a datetime object that is saved in the following format: yyyy-MM-dd'T'HH:mm:ss'Z'.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ComplianceExecutionSummary {
+    /// <p>An ID created by the system when <code>PutComplianceItems</code> was called. For example, <code>CommandID</code> is a valid execution ID. You can use this ID in subsequent calls.</p>
+    #[serde(rename = "ExecutionId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub execution_id: Option<String>,
+    /// <p>The time the execution ran as a datetime object that is saved in the following format: yyyy-MM-dd'T'HH:mm:ss'Z'.</p>
+    #[serde(rename = "ExecutionTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub execution_time: ::chrono::DateTime<::chrono::Utc>,
+    /// <p>The type of execution. For example, <code>Command</code> is a valid execution type.</p>
+    #[serde(rename = "ExecutionType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub execution_type: Option<String>,
 }
 
 /// <p>Information about the compliance as defined by the resource type. For example, for a patch resource type, <code>Items</code> includes information about the PatchSeverity, Classification, etc.</p>
@@ -1041,11 +2947,11 @@ pub struct ComplianceItem {
     /// <p>The severity of the compliance status. Severity can be one of the following: Critical, High, Medium, Low, Informational, Unspecified.</p>
     #[serde(rename = "Severity")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub severity: Option<String>,
+    pub severity: Option<ComplianceSeverity>,
     /// <p>The status of the compliance item. An item is either COMPLIANT or NON_COMPLIANT.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ComplianceStatus>,
     /// <p>A title for the compliance item. For example, if the compliance item is a Windows patch, the title could be the title of the KB article for the patch; for example: Security Update for Active Directory Federation Services.</p>
     #[serde(rename = "Title")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1066,16 +2972,330 @@ pub struct ComplianceItemEntry {
     pub id: Option<String>,
     /// <p>The severity of the compliance status. Severity can be one of the following: Critical, High, Medium, Low, Informational, Unspecified.</p>
     #[serde(rename = "Severity")]
-    pub severity: String,
+    pub severity: ComplianceSeverity,
     /// <p>The status of the compliance item. An item is either COMPLIANT or NON_COMPLIANT.</p>
     #[serde(rename = "Status")]
-    pub status: String,
+    pub status: ComplianceStatus,
     /// <p>The title of the compliance item. For example, if the compliance item is a Windows patch, the title could be the title of the KB article for the patch; for example: Security Update for Active Directory Federation Services. </p>
     #[serde(rename = "Title")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ComplianceQueryOperatorType {
+    /// `EQUAL`
+    Equal,
+    /// `NOT_EQUAL`
+    NotEqual,
+    /// `BEGIN_WITH`
+    BeginWith,
+    /// `LESS_THAN`
+    LessThan,
+    /// `GREATER_THAN`
+    GreaterThan,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ComplianceQueryOperatorType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ComplianceQueryOperatorType::Equal => "EQUAL",
+            ComplianceQueryOperatorType::NotEqual => "NOT_EQUAL",
+            ComplianceQueryOperatorType::BeginWith => "BEGIN_WITH",
+            ComplianceQueryOperatorType::LessThan => "LESS_THAN",
+            ComplianceQueryOperatorType::GreaterThan => "GREATER_THAN",
+            ComplianceQueryOperatorType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ComplianceQueryOperatorType {
+    fn default() -> ComplianceQueryOperatorType {
+        ComplianceQueryOperatorType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ComplianceQueryOperatorType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ComplianceQueryOperatorType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ComplianceQueryOperatorType {
+    fn from(value: &'a str) -> ComplianceQueryOperatorType {
+        match value {
+            "EQUAL" => ComplianceQueryOperatorType::Equal,
+            "NOT_EQUAL" => ComplianceQueryOperatorType::NotEqual,
+            "BEGIN_WITH" => ComplianceQueryOperatorType::BeginWith,
+            "LESS_THAN" => ComplianceQueryOperatorType::LessThan,
+            "GREATER_THAN" => ComplianceQueryOperatorType::GreaterThan,
+            _ => ComplianceQueryOperatorType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ComplianceQueryOperatorType {
+    fn from(value: String) -> ComplianceQueryOperatorType {
+        match value.as_str() {
+            "EQUAL" => ComplianceQueryOperatorType::Equal,
+            "NOT_EQUAL" => ComplianceQueryOperatorType::NotEqual,
+            "BEGIN_WITH" => ComplianceQueryOperatorType::BeginWith,
+            "LESS_THAN" => ComplianceQueryOperatorType::LessThan,
+            "GREATER_THAN" => ComplianceQueryOperatorType::GreaterThan,
+            _ => ComplianceQueryOperatorType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ComplianceQueryOperatorType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ComplianceQueryOperatorType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ComplianceQueryOperatorType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ComplianceQueryOperatorType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ComplianceQueryOperatorType {
+    fn deserialize<D>(deserializer: D) -> Result<ComplianceQueryOperatorType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ComplianceSeverity {
+    /// `CRITICAL`
+    Critical,
+    /// `HIGH`
+    High,
+    /// `MEDIUM`
+    Medium,
+    /// `LOW`
+    Low,
+    /// `INFORMATIONAL`
+    Informational,
+    /// `UNSPECIFIED`
+    Unspecified,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ComplianceSeverity {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ComplianceSeverity::Critical => "CRITICAL",
+            ComplianceSeverity::High => "HIGH",
+            ComplianceSeverity::Medium => "MEDIUM",
+            ComplianceSeverity::Low => "LOW",
+            ComplianceSeverity::Informational => "INFORMATIONAL",
+            ComplianceSeverity::Unspecified => "UNSPECIFIED",
+            ComplianceSeverity::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ComplianceSeverity {
+    fn default() -> ComplianceSeverity {
+        ComplianceSeverity::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ComplianceSeverity {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ComplianceSeverity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ComplianceSeverity {
+    fn from(value: &'a str) -> ComplianceSeverity {
+        match value {
+            "CRITICAL" => ComplianceSeverity::Critical,
+            "HIGH" => ComplianceSeverity::High,
+            "MEDIUM" => ComplianceSeverity::Medium,
+            "LOW" => ComplianceSeverity::Low,
+            "INFORMATIONAL" => ComplianceSeverity::Informational,
+            "UNSPECIFIED" => ComplianceSeverity::Unspecified,
+            _ => ComplianceSeverity::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ComplianceSeverity {
+    fn from(value: String) -> ComplianceSeverity {
+        match value.as_str() {
+            "CRITICAL" => ComplianceSeverity::Critical,
+            "HIGH" => ComplianceSeverity::High,
+            "MEDIUM" => ComplianceSeverity::Medium,
+            "LOW" => ComplianceSeverity::Low,
+            "INFORMATIONAL" => ComplianceSeverity::Informational,
+            "UNSPECIFIED" => ComplianceSeverity::Unspecified,
+            _ => ComplianceSeverity::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ComplianceSeverity {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ComplianceSeverity, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ComplianceSeverity {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ComplianceSeverity {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ComplianceSeverity {
+    fn deserialize<D>(deserializer: D) -> Result<ComplianceSeverity, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ComplianceStatus {
+    /// `COMPLIANT`
+    Compliant,
+    /// `NON_COMPLIANT`
+    NonCompliant,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ComplianceStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ComplianceStatus::Compliant => "COMPLIANT",
+            ComplianceStatus::NonCompliant => "NON_COMPLIANT",
+            ComplianceStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ComplianceStatus {
+    fn default() -> ComplianceStatus {
+        ComplianceStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ComplianceStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ComplianceStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ComplianceStatus {
+    fn from(value: &'a str) -> ComplianceStatus {
+        match value {
+            "COMPLIANT" => ComplianceStatus::Compliant,
+            "NON_COMPLIANT" => ComplianceStatus::NonCompliant,
+            _ => ComplianceStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ComplianceStatus {
+    fn from(value: String) -> ComplianceStatus {
+        match value.as_str() {
+            "COMPLIANT" => ComplianceStatus::Compliant,
+            "NON_COMPLIANT" => ComplianceStatus::NonCompliant,
+            _ => ComplianceStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ComplianceStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ComplianceStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ComplianceStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ComplianceStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ComplianceStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ComplianceStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>One or more filters. Use a filter to return a more specific list of results.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -1087,7 +3307,7 @@ pub struct ComplianceStringFilter {
     /// <p>The type of comparison that should be performed for the value: Equal, NotEqual, BeginWith, LessThan, or GreaterThan.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<ComplianceQueryOperatorType>,
     /// <p>The value for which to search.</p>
     #[serde(rename = "Values")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1126,6 +3346,99 @@ pub struct CompliantSummary {
     pub severity_summary: Option<SeveritySummary>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ConnectionStatus {
+    /// `connected`
+    Connected,
+    /// `notconnected`
+    Notconnected,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ConnectionStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ConnectionStatus::Connected => "connected",
+            ConnectionStatus::Notconnected => "notconnected",
+            ConnectionStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ConnectionStatus {
+    fn default() -> ConnectionStatus {
+        ConnectionStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ConnectionStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ConnectionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ConnectionStatus {
+    fn from(value: &'a str) -> ConnectionStatus {
+        match value {
+            "connected" => ConnectionStatus::Connected,
+            "notconnected" => ConnectionStatus::Notconnected,
+            _ => ConnectionStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ConnectionStatus {
+    fn from(value: String) -> ConnectionStatus {
+        match value.as_str() {
+            "connected" => ConnectionStatus::Connected,
+            "notconnected" => ConnectionStatus::Notconnected,
+            _ => ConnectionStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ConnectionStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ConnectionStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ConnectionStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ConnectionStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ConnectionStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ConnectionStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateActivationRequest {
@@ -1139,8 +3452,13 @@ pub struct CreateActivationRequest {
     pub description: Option<String>,
     /// <p>The date by which this activation request should expire. The default value is 24 hours.</p>
     #[serde(rename = "ExpirationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expiration_date: Option<f64>,
+    pub expiration_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The Amazon Identity and Access Management (IAM) role that you want to assign to the managed instance. This IAM role must provide AssumeRole permissions for the Systems Manager service principal <code>ssm.amazonaws.com</code>. For more information, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/sysman-service-role.html">Create an IAM Service Role for a Hybrid Environment</a> in the <i>AWS Systems Manager User Guide</i>.</p>
     #[serde(rename = "IamRole")]
     pub iam_role: String,
@@ -1189,7 +3507,7 @@ pub struct CreateAssociationBatchRequestEntry {
     /// <p>The severity level to assign to the association.</p>
     #[serde(rename = "ComplianceSeverity")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compliance_severity: Option<String>,
+    pub compliance_severity: Option<AssociationComplianceSeverity>,
     /// <p>The document version.</p>
     #[serde(rename = "DocumentVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1254,7 +3572,7 @@ pub struct CreateAssociationRequest {
     /// <p>The severity level to assign to the association.</p>
     #[serde(rename = "ComplianceSeverity")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compliance_severity: Option<String>,
+    pub compliance_severity: Option<AssociationComplianceSeverity>,
     /// <p>The document version you want to associate with the target(s). Can be a specific version or the default version.</p>
     #[serde(rename = "DocumentVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1314,11 +3632,11 @@ pub struct CreateDocumentRequest {
     /// <p>Specify the document format for the request. The document format can be JSON, YAML, or TEXT. JSON is the default format.</p>
     #[serde(rename = "DocumentFormat")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub document_format: Option<String>,
+    pub document_format: Option<DocumentFormat>,
     /// <p>The type of document to create.</p>
     #[serde(rename = "DocumentType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub document_type: Option<String>,
+    pub document_type: Option<DocumentType>,
     /// <p><p>A name for the Systems Manager document.</p> <important> <p>Do not use the following to begin the names of documents you create. They are reserved by AWS for use as document prefixes:</p> <ul> <li> <p> <code>aws</code> </p> </li> <li> <p> <code>amazon</code> </p> </li> <li> <p> <code>amzn</code> </p> </li> </ul> </important></p>
     #[serde(rename = "Name")]
     pub name: String,
@@ -1467,7 +3785,7 @@ pub struct CreatePatchBaselineRequest {
     /// <p>Defines the compliance level for approved patches. This means that if an approved patch is reported as missing, this is the severity of the compliance violation. The default value is UNSPECIFIED.</p>
     #[serde(rename = "ApprovedPatchesComplianceLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub approved_patches_compliance_level: Option<String>,
+    pub approved_patches_compliance_level: Option<PatchComplianceLevel>,
     /// <p>Indicates whether the list of approved patches includes non-security updates that should be applied to the instances. The default value is 'false'. Applies to Linux instances only.</p>
     #[serde(rename = "ApprovedPatchesEnableNonSecurity")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1490,7 +3808,7 @@ pub struct CreatePatchBaselineRequest {
     /// <p>Defines the operating system the patch baseline applies to. The Default value is WINDOWS.</p>
     #[serde(rename = "OperatingSystem")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub operating_system: Option<String>,
+    pub operating_system: Option<OperatingSystem>,
     /// <p>A list of explicitly rejected patches for the baseline.</p> <p>For information about accepted formats for lists of approved patches and rejected patches, see <a href="https://docs.aws.amazon.com/systems-manager/latest/userguide/patch-manager-approved-rejected-package-name-formats.html">Package Name Formats for Approved and Rejected Patch Lists</a> in the <i>AWS Systems Manager User Guide</i>.</p>
     #[serde(rename = "RejectedPatches")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1498,7 +3816,7 @@ pub struct CreatePatchBaselineRequest {
     /// <p><p>The action for Patch Manager to take on patches included in the RejectedPackages list.</p> <ul> <li> <p> <b>ALLOW<em>AS</em>DEPENDENCY</b>: A package in the Rejected patches list is installed only if it is a dependency of another package. It is considered compliant with the patch baseline, and its status is reported as <i>InstalledOther</i>. This is the default action if no option is specified.</p> </li> <li> <p> <b>BLOCK</b>: Packages in the RejectedPatches list, and packages that include them as dependencies, are not installed under any circumstances. If a package was installed before it was added to the Rejected patches list, it is considered non-compliant with the patch baseline, and its status is reported as <i>InstalledRejected</i>.</p> </li> </ul></p>
     #[serde(rename = "RejectedPatchesAction")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub rejected_patches_action: Option<String>,
+    pub rejected_patches_action: Option<PatchAction>,
     /// <p>Information about the patches to use to update the instances, including target operating systems and source repositories. Applies to Linux instances only.</p>
     #[serde(rename = "Sources")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1613,7 +3931,7 @@ pub struct DeleteInventoryRequest {
     /// <p>Use the <code>SchemaDeleteOption</code> to delete a custom inventory type (schema). If you don't choose this option, the system only deletes existing inventory data associated with the custom inventory type. Choose one of the following options:</p> <p>DisableSchema: If you choose this option, the system ignores all inventory data for the specified version, and any earlier versions. To enable this schema again, you must call the <code>PutInventory</code> action for a version greater than the disabled version.</p> <p>DeleteSchema: This option deletes the specified custom type from the Inventory service. You can recreate the schema later, if you want.</p>
     #[serde(rename = "SchemaDeleteOption")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub schema_delete_option: Option<String>,
+    pub schema_delete_option: Option<InventorySchemaDeleteOption>,
     /// <p>The name of the custom inventory type for which you want to delete either all previously collected data, or the inventory type itself. </p>
     #[serde(rename = "TypeName")]
     pub type_name: String,
@@ -1814,16 +4132,114 @@ pub struct DescribeActivationsFilter {
     /// <p>The name of the filter.</p>
     #[serde(rename = "FilterKey")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub filter_key: Option<String>,
+    pub filter_key: Option<DescribeActivationsFilterKeys>,
     /// <p>The filter values.</p>
     #[serde(rename = "FilterValues")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub filter_values: Option<Vec<String>>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct DescribeActivationsRequest {
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DescribeActivationsFilterKeys {
+    /// `ActivationIds`
+    ActivationIds,
+    /// `DefaultInstanceName`
+    DefaultInstanceName,
+    /// `IamRole`
+    IamRole,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DescribeActivationsFilterKeys {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DescribeActivationsFilterKeys::ActivationIds => "ActivationIds",
+            DescribeActivationsFilterKeys::DefaultInstanceName => "DefaultInstanceName",
+            DescribeActivationsFilterKeys::IamRole => "IamRole",
+            DescribeActivationsFilterKeys::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DescribeActivationsFilterKeys {
+    fn default() -> DescribeActivationsFilterKeys {
+        DescribeActivationsFilterKeys::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DescribeActivationsFilterKeys {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DescribeActivationsFilterKeys {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DescribeActivationsFilterKeys {
+    fn from(value: &'a str) -> DescribeActivationsFilterKeys {
+        match value {
+            "ActivationIds" => DescribeActivationsFilterKeys::ActivationIds,
+            "DefaultInstanceName" => DescribeActivationsFilterKeys::DefaultInstanceName,
+            "IamRole" => DescribeActivationsFilterKeys::IamRole,
+            _ => DescribeActivationsFilterKeys::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DescribeActivationsFilterKeys {
+    fn from(value: String) -> DescribeActivationsFilterKeys {
+        match value.as_str() {
+            "ActivationIds" => DescribeActivationsFilterKeys::ActivationIds,
+            "DefaultInstanceName" => DescribeActivationsFilterKeys::DefaultInstanceName,
+            "IamRole" => DescribeActivationsFilterKeys::IamRole,
+            _ => DescribeActivationsFilterKeys::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DescribeActivationsFilterKeys {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DescribeActivationsFilterKeys, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DescribeActivationsFilterKeys {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DescribeActivationsFilterKeys {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DescribeActivationsFilterKeys {
+    fn deserialize<D>(deserializer: D) -> Result<DescribeActivationsFilterKeys, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct DescribeActivationsRequest {
     /// <p>A filter to view information about your activations.</p>
     #[serde(rename = "Filters")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2055,7 +4471,7 @@ pub struct DescribeDocumentPermissionRequest {
     pub name: String,
     /// <p>The permission type for the document. The permission type can be <i>Share</i>.</p>
     #[serde(rename = "PermissionType")]
-    pub permission_type: String,
+    pub permission_type: DocumentPermissionType,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -2463,7 +4879,7 @@ pub struct DescribeMaintenanceWindowScheduleRequest {
     /// <p>The type of resource you want to retrieve information about. For example, "INSTANCE".</p>
     #[serde(rename = "ResourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<MaintenanceWindowResourceType>,
     /// <p>The instance ID or key/value pair to retrieve information about.</p>
     #[serde(rename = "Targets")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2566,7 +4982,7 @@ pub struct DescribeMaintenanceWindowsForTargetRequest {
     pub next_token: Option<String>,
     /// <p>The type of resource you want to retrieve information about. For example, "INSTANCE".</p>
     #[serde(rename = "ResourceType")]
-    pub resource_type: String,
+    pub resource_type: MaintenanceWindowResourceType,
     /// <p>The instance ID or key/value pair to retrieve information about.</p>
     #[serde(rename = "Targets")]
     pub targets: Vec<Target>,
@@ -2801,14 +5217,14 @@ pub struct DescribePatchPropertiesRequest {
     pub next_token: Option<String>,
     /// <p>The operating system type for which to list patches.</p>
     #[serde(rename = "OperatingSystem")]
-    pub operating_system: String,
+    pub operating_system: OperatingSystem,
     /// <p>Indicates whether to list patches for the Windows operating system or for Microsoft applications. Not applicable for Linux operating systems.</p>
     #[serde(rename = "PatchSet")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub patch_set: Option<String>,
+    pub patch_set: Option<PatchSet>,
     /// <p>The patch property for which you want to view patch details. </p>
     #[serde(rename = "Property")]
-    pub property: String,
+    pub property: PatchProperty,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -2841,7 +5257,7 @@ pub struct DescribeSessionsRequest {
     pub next_token: Option<String>,
     /// <p>The session status to retrieve a list of sessions for. For example, "Active".</p>
     #[serde(rename = "State")]
-    pub state: String,
+    pub state: SessionState,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -2885,8 +5301,13 @@ pub struct DocumentDescription {
     pub attachments_information: Option<Vec<AttachmentInformation>>,
     /// <p>The date when the document was created.</p>
     #[serde(rename = "CreatedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The default version.</p>
     #[serde(rename = "DefaultVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2898,11 +5319,11 @@ pub struct DocumentDescription {
     /// <p>The document format, either JSON or YAML.</p>
     #[serde(rename = "DocumentFormat")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub document_format: Option<String>,
+    pub document_format: Option<DocumentFormat>,
     /// <p>The type of document.</p>
     #[serde(rename = "DocumentType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub document_type: Option<String>,
+    pub document_type: Option<DocumentType>,
     /// <p>The document version.</p>
     #[serde(rename = "DocumentVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2914,7 +5335,7 @@ pub struct DocumentDescription {
     /// <p><p>The hash type of the document. Valid values include <code>Sha256</code> or <code>Sha1</code>.</p> <note> <p>Sha1 hashes have been deprecated.</p> </note></p>
     #[serde(rename = "HashType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub hash_type: Option<String>,
+    pub hash_type: Option<DocumentHashType>,
     /// <p>The latest version of the document.</p>
     #[serde(rename = "LatestVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2934,7 +5355,7 @@ pub struct DocumentDescription {
     /// <p>The list of OS platforms compatible with this Systems Manager document. </p>
     #[serde(rename = "PlatformTypes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub platform_types: Option<Vec<String>>,
+    pub platform_types: Option<Vec<PlatformType>>,
     /// <p>A list of SSM documents required by a document. For example, an <code>ApplicationConfiguration</code> document requires an <code>ApplicationConfigurationSchema</code> document.</p>
     #[serde(rename = "Requires")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2950,7 +5371,7 @@ pub struct DocumentDescription {
     /// <p>The status of the Systems Manager document.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<DocumentStatus>,
     /// <p>A message returned by AWS Systems Manager that explains the <code>Status</code> value. For example, a <code>Failed</code> status might be explained by the <code>StatusInformation</code> message, "The specified S3 bucket does not exist. Verify that the URL of the S3 bucket is correct."</p>
     #[serde(rename = "StatusInformation")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2975,12 +5396,306 @@ pub struct DocumentDescription {
 pub struct DocumentFilter {
     /// <p>The name of the filter.</p>
     #[serde(rename = "key")]
-    pub key: String,
+    pub key: DocumentFilterKey,
     /// <p>The value of the filter.</p>
     #[serde(rename = "value")]
     pub value: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DocumentFilterKey {
+    /// `Name`
+    Name,
+    /// `Owner`
+    Owner,
+    /// `PlatformTypes`
+    PlatformTypes,
+    /// `DocumentType`
+    DocumentType,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DocumentFilterKey {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DocumentFilterKey::Name => "Name",
+            DocumentFilterKey::Owner => "Owner",
+            DocumentFilterKey::PlatformTypes => "PlatformTypes",
+            DocumentFilterKey::DocumentType => "DocumentType",
+            DocumentFilterKey::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DocumentFilterKey {
+    fn default() -> DocumentFilterKey {
+        DocumentFilterKey::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DocumentFilterKey {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DocumentFilterKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DocumentFilterKey {
+    fn from(value: &'a str) -> DocumentFilterKey {
+        match value {
+            "Name" => DocumentFilterKey::Name,
+            "Owner" => DocumentFilterKey::Owner,
+            "PlatformTypes" => DocumentFilterKey::PlatformTypes,
+            "DocumentType" => DocumentFilterKey::DocumentType,
+            _ => DocumentFilterKey::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DocumentFilterKey {
+    fn from(value: String) -> DocumentFilterKey {
+        match value.as_str() {
+            "Name" => DocumentFilterKey::Name,
+            "Owner" => DocumentFilterKey::Owner,
+            "PlatformTypes" => DocumentFilterKey::PlatformTypes,
+            "DocumentType" => DocumentFilterKey::DocumentType,
+            _ => DocumentFilterKey::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DocumentFilterKey {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DocumentFilterKey, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DocumentFilterKey {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DocumentFilterKey {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DocumentFilterKey {
+    fn deserialize<D>(deserializer: D) -> Result<DocumentFilterKey, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DocumentFormat {
+    /// `YAML`
+    Yaml,
+    /// `JSON`
+    Json,
+    /// `TEXT`
+    Text,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DocumentFormat {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DocumentFormat::Yaml => "YAML",
+            DocumentFormat::Json => "JSON",
+            DocumentFormat::Text => "TEXT",
+            DocumentFormat::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DocumentFormat {
+    fn default() -> DocumentFormat {
+        DocumentFormat::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DocumentFormat {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DocumentFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DocumentFormat {
+    fn from(value: &'a str) -> DocumentFormat {
+        match value {
+            "YAML" => DocumentFormat::Yaml,
+            "JSON" => DocumentFormat::Json,
+            "TEXT" => DocumentFormat::Text,
+            _ => DocumentFormat::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DocumentFormat {
+    fn from(value: String) -> DocumentFormat {
+        match value.as_str() {
+            "YAML" => DocumentFormat::Yaml,
+            "JSON" => DocumentFormat::Json,
+            "TEXT" => DocumentFormat::Text,
+            _ => DocumentFormat::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DocumentFormat {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DocumentFormat, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DocumentFormat {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DocumentFormat {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DocumentFormat {
+    fn deserialize<D>(deserializer: D) -> Result<DocumentFormat, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DocumentHashType {
+    /// `Sha256`
+    Sha256,
+    /// `Sha1`
+    Sha1,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DocumentHashType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DocumentHashType::Sha256 => "Sha256",
+            DocumentHashType::Sha1 => "Sha1",
+            DocumentHashType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DocumentHashType {
+    fn default() -> DocumentHashType {
+        DocumentHashType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DocumentHashType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DocumentHashType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DocumentHashType {
+    fn from(value: &'a str) -> DocumentHashType {
+        match value {
+            "Sha256" => DocumentHashType::Sha256,
+            "Sha1" => DocumentHashType::Sha1,
+            _ => DocumentHashType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DocumentHashType {
+    fn from(value: String) -> DocumentHashType {
+        match value.as_str() {
+            "Sha256" => DocumentHashType::Sha256,
+            "Sha1" => DocumentHashType::Sha1,
+            _ => DocumentHashType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DocumentHashType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DocumentHashType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DocumentHashType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DocumentHashType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DocumentHashType {
+    fn deserialize<D>(deserializer: D) -> Result<DocumentHashType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes the name of a Systems Manager document.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -2988,11 +5703,11 @@ pub struct DocumentIdentifier {
     /// <p>The document format, either JSON or YAML.</p>
     #[serde(rename = "DocumentFormat")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub document_format: Option<String>,
+    pub document_format: Option<DocumentFormat>,
     /// <p>The document type.</p>
     #[serde(rename = "DocumentType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub document_type: Option<String>,
+    pub document_type: Option<DocumentType>,
     /// <p>The document version.</p>
     #[serde(rename = "DocumentVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3008,7 +5723,7 @@ pub struct DocumentIdentifier {
     /// <p>The operating system platform. </p>
     #[serde(rename = "PlatformTypes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub platform_types: Option<Vec<String>>,
+    pub platform_types: Option<Vec<PlatformType>>,
     /// <p>A list of SSM documents required by a document. For example, an <code>ApplicationConfiguration</code> document requires an <code>ApplicationConfigurationSchema</code> document.</p>
     #[serde(rename = "Requires")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3064,9 +5779,190 @@ pub struct DocumentParameter {
     /// <p>The type of parameter. The type can be either String or StringList.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<DocumentParameterType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DocumentParameterType {
+    /// `String`
+    String,
+    /// `StringList`
+    StringList,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DocumentParameterType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DocumentParameterType::String => "String",
+            DocumentParameterType::StringList => "StringList",
+            DocumentParameterType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DocumentParameterType {
+    fn default() -> DocumentParameterType {
+        DocumentParameterType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DocumentParameterType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DocumentParameterType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DocumentParameterType {
+    fn from(value: &'a str) -> DocumentParameterType {
+        match value {
+            "String" => DocumentParameterType::String,
+            "StringList" => DocumentParameterType::StringList,
+            _ => DocumentParameterType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DocumentParameterType {
+    fn from(value: String) -> DocumentParameterType {
+        match value.as_str() {
+            "String" => DocumentParameterType::String,
+            "StringList" => DocumentParameterType::StringList,
+            _ => DocumentParameterType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DocumentParameterType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DocumentParameterType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DocumentParameterType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DocumentParameterType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DocumentParameterType {
+    fn deserialize<D>(deserializer: D) -> Result<DocumentParameterType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DocumentPermissionType {
+    /// `Share`
+    Share,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DocumentPermissionType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DocumentPermissionType::Share => "Share",
+            DocumentPermissionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DocumentPermissionType {
+    fn default() -> DocumentPermissionType {
+        DocumentPermissionType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DocumentPermissionType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DocumentPermissionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DocumentPermissionType {
+    fn from(value: &'a str) -> DocumentPermissionType {
+        match value {
+            "Share" => DocumentPermissionType::Share,
+            _ => DocumentPermissionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DocumentPermissionType {
+    fn from(value: String) -> DocumentPermissionType {
+        match value.as_str() {
+            "Share" => DocumentPermissionType::Share,
+            _ => DocumentPermissionType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DocumentPermissionType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DocumentPermissionType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DocumentPermissionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DocumentPermissionType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for DocumentPermissionType {
+    fn deserialize<D>(deserializer: D) -> Result<DocumentPermissionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An SSM document required by the current document.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DocumentRequires {
@@ -3079,18 +5975,290 @@ pub struct DocumentRequires {
     pub version: Option<String>,
 }
 
-/// <p>Version information about the document.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct DocumentVersionInfo {
-    /// <p>The date the document was created.</p>
-    #[serde(rename = "CreatedDate")]
+/// <p>The status of a document.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DocumentStatus {
+    /// `Creating`
+    Creating,
+    /// `Active`
+    Active,
+    /// `Updating`
+    Updating,
+    /// `Deleting`
+    Deleting,
+    /// `Failed`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DocumentStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DocumentStatus::Creating => "Creating",
+            DocumentStatus::Active => "Active",
+            DocumentStatus::Updating => "Updating",
+            DocumentStatus::Deleting => "Deleting",
+            DocumentStatus::Failed => "Failed",
+            DocumentStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DocumentStatus {
+    fn default() -> DocumentStatus {
+        DocumentStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DocumentStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DocumentStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DocumentStatus {
+    fn from(value: &'a str) -> DocumentStatus {
+        match value {
+            "Creating" => DocumentStatus::Creating,
+            "Active" => DocumentStatus::Active,
+            "Updating" => DocumentStatus::Updating,
+            "Deleting" => DocumentStatus::Deleting,
+            "Failed" => DocumentStatus::Failed,
+            _ => DocumentStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DocumentStatus {
+    fn from(value: String) -> DocumentStatus {
+        match value.as_str() {
+            "Creating" => DocumentStatus::Creating,
+            "Active" => DocumentStatus::Active,
+            "Updating" => DocumentStatus::Updating,
+            "Deleting" => DocumentStatus::Deleting,
+            "Failed" => DocumentStatus::Failed,
+            _ => DocumentStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DocumentStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DocumentStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DocumentStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DocumentStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DocumentStatus {
+    fn deserialize<D>(deserializer: D) -> Result<DocumentStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DocumentType {
+    /// `Command`
+    Command,
+    /// `Policy`
+    Policy,
+    /// `Automation`
+    Automation,
+    /// `Session`
+    Session,
+    /// `Package`
+    Package,
+    /// `ApplicationConfiguration`
+    ApplicationConfiguration,
+    /// `ApplicationConfigurationSchema`
+    ApplicationConfigurationSchema,
+    /// `DeploymentStrategy`
+    DeploymentStrategy,
+    /// `ChangeCalendar`
+    ChangeCalendar,
+    /// `Automation.ChangeTemplate`
+    AutomationChangeTemplate,
+    /// `ProblemAnalysis`
+    ProblemAnalysis,
+    /// `ProblemAnalysisTemplate`
+    ProblemAnalysisTemplate,
+    /// `CloudFormation`
+    CloudFormation,
+    /// `ConformancePackTemplate`
+    ConformancePackTemplate,
+    /// `QuickSetup`
+    QuickSetup,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DocumentType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DocumentType::Command => "Command",
+            DocumentType::Policy => "Policy",
+            DocumentType::Automation => "Automation",
+            DocumentType::Session => "Session",
+            DocumentType::Package => "Package",
+            DocumentType::ApplicationConfiguration => "ApplicationConfiguration",
+            DocumentType::ApplicationConfigurationSchema => "ApplicationConfigurationSchema",
+            DocumentType::DeploymentStrategy => "DeploymentStrategy",
+            DocumentType::ChangeCalendar => "ChangeCalendar",
+            DocumentType::AutomationChangeTemplate => "Automation.ChangeTemplate",
+            DocumentType::ProblemAnalysis => "ProblemAnalysis",
+            DocumentType::ProblemAnalysisTemplate => "ProblemAnalysisTemplate",
+            DocumentType::CloudFormation => "CloudFormation",
+            DocumentType::ConformancePackTemplate => "ConformancePackTemplate",
+            DocumentType::QuickSetup => "QuickSetup",
+            DocumentType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DocumentType {
+    fn default() -> DocumentType {
+        DocumentType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DocumentType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DocumentType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DocumentType {
+    fn from(value: &'a str) -> DocumentType {
+        match value {
+            "Command" => DocumentType::Command,
+            "Policy" => DocumentType::Policy,
+            "Automation" => DocumentType::Automation,
+            "Session" => DocumentType::Session,
+            "Package" => DocumentType::Package,
+            "ApplicationConfiguration" => DocumentType::ApplicationConfiguration,
+            "ApplicationConfigurationSchema" => DocumentType::ApplicationConfigurationSchema,
+            "DeploymentStrategy" => DocumentType::DeploymentStrategy,
+            "ChangeCalendar" => DocumentType::ChangeCalendar,
+            "Automation.ChangeTemplate" => DocumentType::AutomationChangeTemplate,
+            "ProblemAnalysis" => DocumentType::ProblemAnalysis,
+            "ProblemAnalysisTemplate" => DocumentType::ProblemAnalysisTemplate,
+            "CloudFormation" => DocumentType::CloudFormation,
+            "ConformancePackTemplate" => DocumentType::ConformancePackTemplate,
+            "QuickSetup" => DocumentType::QuickSetup,
+            _ => DocumentType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DocumentType {
+    fn from(value: String) -> DocumentType {
+        match value.as_str() {
+            "Command" => DocumentType::Command,
+            "Policy" => DocumentType::Policy,
+            "Automation" => DocumentType::Automation,
+            "Session" => DocumentType::Session,
+            "Package" => DocumentType::Package,
+            "ApplicationConfiguration" => DocumentType::ApplicationConfiguration,
+            "ApplicationConfigurationSchema" => DocumentType::ApplicationConfigurationSchema,
+            "DeploymentStrategy" => DocumentType::DeploymentStrategy,
+            "ChangeCalendar" => DocumentType::ChangeCalendar,
+            "Automation.ChangeTemplate" => DocumentType::AutomationChangeTemplate,
+            "ProblemAnalysis" => DocumentType::ProblemAnalysis,
+            "ProblemAnalysisTemplate" => DocumentType::ProblemAnalysisTemplate,
+            "CloudFormation" => DocumentType::CloudFormation,
+            "ConformancePackTemplate" => DocumentType::ConformancePackTemplate,
+            "QuickSetup" => DocumentType::QuickSetup,
+            _ => DocumentType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DocumentType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DocumentType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DocumentType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DocumentType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DocumentType {
+    fn deserialize<D>(deserializer: D) -> Result<DocumentType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Version information about the document.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct DocumentVersionInfo {
+    /// <p>The date the document was created.</p>
+    #[serde(rename = "CreatedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The document format, either JSON or YAML.</p>
     #[serde(rename = "DocumentFormat")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub document_format: Option<String>,
+    pub document_format: Option<DocumentFormat>,
     /// <p>The document version.</p>
     #[serde(rename = "DocumentVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3106,7 +6274,7 @@ pub struct DocumentVersionInfo {
     /// <p>The status of the Systems Manager document, such as <code>Creating</code>, <code>Active</code>, <code>Failed</code>, and <code>Deleting</code>.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<DocumentStatus>,
     /// <p>A message returned by AWS Systems Manager that explains the <code>Status</code> value. For example, a <code>Failed</code> status might be explained by the <code>StatusInformation</code> message, "The specified S3 bucket does not exist. Verify that the URL of the S3 bucket is correct."</p>
     #[serde(rename = "StatusInformation")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3131,6 +6299,99 @@ pub struct EffectivePatch {
     pub patch_status: Option<PatchStatus>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ExecutionMode {
+    /// `Auto`
+    Auto,
+    /// `Interactive`
+    Interactive,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ExecutionMode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ExecutionMode::Auto => "Auto",
+            ExecutionMode::Interactive => "Interactive",
+            ExecutionMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ExecutionMode {
+    fn default() -> ExecutionMode {
+        ExecutionMode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ExecutionMode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ExecutionMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ExecutionMode {
+    fn from(value: &'a str) -> ExecutionMode {
+        match value {
+            "Auto" => ExecutionMode::Auto,
+            "Interactive" => ExecutionMode::Interactive,
+            _ => ExecutionMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ExecutionMode {
+    fn from(value: String) -> ExecutionMode {
+        match value.as_str() {
+            "Auto" => ExecutionMode::Auto,
+            "Interactive" => ExecutionMode::Interactive,
+            _ => ExecutionMode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ExecutionMode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ExecutionMode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ExecutionMode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ExecutionMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ExecutionMode {
+    fn deserialize<D>(deserializer: D) -> Result<ExecutionMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes a failed association.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -3142,7 +6403,7 @@ pub struct FailedCreateAssociation {
     /// <p>The source of the failure.</p>
     #[serde(rename = "Fault")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub fault: Option<String>,
+    pub fault: Option<Fault>,
     /// <p>A description of the failure.</p>
     #[serde(rename = "Message")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3167,6 +6428,104 @@ pub struct FailureDetails {
     pub failure_type: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Fault {
+    /// `Client`
+    Client,
+    /// `Server`
+    Server,
+    /// `Unknown`
+    UnknownValue,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Fault {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Fault::Client => "Client",
+            Fault::Server => "Server",
+            Fault::UnknownValue => "Unknown",
+            Fault::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Fault {
+    fn default() -> Fault {
+        Fault::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Fault {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Fault {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Fault {
+    fn from(value: &'a str) -> Fault {
+        match value {
+            "Client" => Fault::Client,
+            "Server" => Fault::Server,
+            "Unknown" => Fault::UnknownValue,
+            _ => Fault::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Fault {
+    fn from(value: String) -> Fault {
+        match value.as_str() {
+            "Client" => Fault::Client,
+            "Server" => Fault::Server,
+            "Unknown" => Fault::UnknownValue,
+            _ => Fault::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Fault {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Fault, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Fault {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Fault {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Fault {
+    fn deserialize<D>(deserializer: D) -> Result<Fault, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct GetAutomationExecutionRequest {
@@ -3210,7 +6569,7 @@ pub struct GetCalendarStateResponse {
     /// <p>The state of the calendar. An <code>OPEN</code> calendar indicates that actions are allowed to proceed, and a <code>CLOSED</code> calendar indicates that actions are not allowed to proceed.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<CalendarState>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -3294,7 +6653,7 @@ pub struct GetCommandInvocationResult {
     /// <p>The status of this invocation plugin. This status can be different than StatusDetails.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<CommandInvocationStatus>,
     /// <p><p>A detailed status of the command execution for an invocation. StatusDetails includes more information than Status because it includes states resulting from error and concurrency control parameters. StatusDetails can show different results than Status. For more information about these statuses, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/monitor-commands.html">Understanding Command Statuses</a> in the <i>AWS Systems Manager User Guide</i>. StatusDetails can be one of the following values:</p> <ul> <li> <p>Pending: The command has not been sent to the instance.</p> </li> <li> <p>In Progress: The command has been sent to the instance but has not reached a terminal state.</p> </li> <li> <p>Delayed: The system attempted to send the command to the target, but the target was not available. The instance might not be available because of network issues, the instance was stopped, etc. The system will try to deliver the command again.</p> </li> <li> <p>Success: The command or plugin was run successfully. This is a terminal state.</p> </li> <li> <p>Delivery Timed Out: The command was not delivered to the instance before the delivery timeout expired. Delivery timeouts do not count against the parent command&#39;s MaxErrors limit, but they do contribute to whether the parent command status is Success or Incomplete. This is a terminal state.</p> </li> <li> <p>Execution Timed Out: The command started to run on the instance, but the execution was not complete before the timeout expired. Execution timeouts count against the MaxErrors limit of the parent command. This is a terminal state.</p> </li> <li> <p>Failed: The command wasn&#39;t run successfully on the instance. For a plugin, this indicates that the result code was not zero. For a command invocation, this indicates that the result code for one or more plugins was not zero. Invocation failures count against the MaxErrors limit of the parent command. This is a terminal state.</p> </li> <li> <p>Canceled: The command was terminated before it was completed. This is a terminal state.</p> </li> <li> <p>Undeliverable: The command can&#39;t be delivered to the instance. The instance might not exist or might not be responding. Undeliverable invocations don&#39;t count against the parent command&#39;s MaxErrors limit and don&#39;t contribute to whether the parent command status is Success or Incomplete. This is a terminal state.</p> </li> <li> <p>Terminated: The parent command exceeded its MaxErrors limit and subsequent command invocations were canceled by the system. This is a terminal state.</p> </li> </ul></p>
     #[serde(rename = "StatusDetails")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3315,7 +6674,7 @@ pub struct GetConnectionStatusResponse {
     /// <p>The status of the connection to the instance. For example, 'Connected' or 'Not Connected'.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ConnectionStatus>,
     /// <p>The ID of the instance to check connection status. </p>
     #[serde(rename = "Target")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3328,7 +6687,7 @@ pub struct GetDefaultPatchBaselineRequest {
     /// <p>Returns the default patch baseline for the specified operating system.</p>
     #[serde(rename = "OperatingSystem")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub operating_system: Option<String>,
+    pub operating_system: Option<OperatingSystem>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -3341,7 +6700,7 @@ pub struct GetDefaultPatchBaselineResult {
     /// <p>The operating system for the returned patch baseline. </p>
     #[serde(rename = "OperatingSystem")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub operating_system: Option<String>,
+    pub operating_system: Option<OperatingSystem>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -3382,7 +6741,7 @@ pub struct GetDocumentRequest {
     /// <p>Returns the document in the specified format. The document format can be either JSON or YAML. JSON is the default format.</p>
     #[serde(rename = "DocumentFormat")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub document_format: Option<String>,
+    pub document_format: Option<DocumentFormat>,
     /// <p>The document version for which you want information.</p>
     #[serde(rename = "DocumentVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3410,11 +6769,11 @@ pub struct GetDocumentResult {
     /// <p>The document format, either JSON or YAML.</p>
     #[serde(rename = "DocumentFormat")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub document_format: Option<String>,
+    pub document_format: Option<DocumentFormat>,
     /// <p>The document type.</p>
     #[serde(rename = "DocumentType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub document_type: Option<String>,
+    pub document_type: Option<DocumentType>,
     /// <p>The document version.</p>
     #[serde(rename = "DocumentVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3430,7 +6789,7 @@ pub struct GetDocumentResult {
     /// <p>The status of the Systems Manager document, such as <code>Creating</code>, <code>Active</code>, <code>Updating</code>, <code>Failed</code>, and <code>Deleting</code>.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<DocumentStatus>,
     /// <p>A message returned by AWS Systems Manager that explains the <code>Status</code> value. For example, a <code>Failed</code> status might be explained by the <code>StatusInformation</code> message, "The specified S3 bucket does not exist. Verify that the URL of the S3 bucket is correct."</p>
     #[serde(rename = "StatusInformation")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3530,16 +6889,26 @@ pub struct GetMaintenanceWindowExecutionRequest {
 pub struct GetMaintenanceWindowExecutionResult {
     /// <p>The time the maintenance window finished running.</p>
     #[serde(rename = "EndTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The time the maintenance window started running.</p>
     #[serde(rename = "StartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The status of the maintenance window execution.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<MaintenanceWindowExecutionStatus>,
     /// <p>The details explaining the Status. Only available for certain status values.</p>
     #[serde(rename = "StatusDetails")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3573,8 +6942,13 @@ pub struct GetMaintenanceWindowExecutionTaskInvocationRequest {
 pub struct GetMaintenanceWindowExecutionTaskInvocationResult {
     /// <p>The time that the task finished running on the target.</p>
     #[serde(rename = "EndTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The execution ID.</p>
     #[serde(rename = "ExecutionId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3593,12 +6967,17 @@ pub struct GetMaintenanceWindowExecutionTaskInvocationResult {
     pub parameters: Option<String>,
     /// <p>The time that the task started running on the target.</p>
     #[serde(rename = "StartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The task status for an invocation.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<MaintenanceWindowExecutionStatus>,
     /// <p>The details explaining the status. Details are only available for certain status values.</p>
     #[serde(rename = "StatusDetails")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3610,7 +6989,7 @@ pub struct GetMaintenanceWindowExecutionTaskInvocationResult {
     /// <p>Retrieves the task type for a maintenance window. Task types include the following: LAMBDA, STEP_FUNCTIONS, AUTOMATION, RUN_COMMAND.</p>
     #[serde(rename = "TaskType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub task_type: Option<String>,
+    pub task_type: Option<MaintenanceWindowTaskType>,
     /// <p>The maintenance window execution ID.</p>
     #[serde(rename = "WindowExecutionId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3637,8 +7016,13 @@ pub struct GetMaintenanceWindowExecutionTaskRequest {
 pub struct GetMaintenanceWindowExecutionTaskResult {
     /// <p>The time the task execution completed.</p>
     #[serde(rename = "EndTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The defined maximum number of task executions that could be run in parallel.</p>
     #[serde(rename = "MaxConcurrency")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3657,12 +7041,17 @@ pub struct GetMaintenanceWindowExecutionTaskResult {
     pub service_role: Option<String>,
     /// <p>The time the task execution started.</p>
     #[serde(rename = "StartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The status of the task.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<MaintenanceWindowExecutionStatus>,
     /// <p>The details explaining the Status. Only available for certain status values.</p>
     #[serde(rename = "StatusDetails")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3684,7 +7073,7 @@ pub struct GetMaintenanceWindowExecutionTaskResult {
     /// <p>The type of task that was run.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<MaintenanceWindowTaskType>,
     /// <p>The ID of the maintenance window execution that includes the task.</p>
     #[serde(rename = "WindowExecutionId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3708,8 +7097,13 @@ pub struct GetMaintenanceWindowResult {
     pub allow_unassociated_targets: Option<bool>,
     /// <p>The date the maintenance window was created.</p>
     #[serde(rename = "CreatedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The number of hours before the end of the maintenance window that Systems Manager stops scheduling new tasks for execution.</p>
     #[serde(rename = "Cutoff")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3732,8 +7126,13 @@ pub struct GetMaintenanceWindowResult {
     pub end_date: Option<String>,
     /// <p>The date the maintenance window was last modified.</p>
     #[serde(rename = "ModifiedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub modified_date: Option<f64>,
+    pub modified_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the maintenance window.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3822,7 +7221,7 @@ pub struct GetMaintenanceWindowTaskResult {
     /// <p>The type of task to run.</p>
     #[serde(rename = "TaskType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub task_type: Option<String>,
+    pub task_type: Option<MaintenanceWindowTaskType>,
     /// <p>The retrieved maintenance window ID.</p>
     #[serde(rename = "WindowId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4018,7 +7417,7 @@ pub struct GetPatchBaselineForPatchGroupRequest {
     /// <p>Returns he operating system rule specified for patch groups using the patch baseline.</p>
     #[serde(rename = "OperatingSystem")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub operating_system: Option<String>,
+    pub operating_system: Option<OperatingSystem>,
     /// <p>The name of the patch group whose patch baseline should be retrieved.</p>
     #[serde(rename = "PatchGroup")]
     pub patch_group: String,
@@ -4034,7 +7433,7 @@ pub struct GetPatchBaselineForPatchGroupResult {
     /// <p>The operating system rule specified for patch groups using the patch baseline.</p>
     #[serde(rename = "OperatingSystem")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub operating_system: Option<String>,
+    pub operating_system: Option<OperatingSystem>,
     /// <p>The name of the patch group.</p>
     #[serde(rename = "PatchGroup")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4063,7 +7462,7 @@ pub struct GetPatchBaselineResult {
     /// <p>Returns the specified compliance severity level for approved patches in the patch baseline.</p>
     #[serde(rename = "ApprovedPatchesComplianceLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub approved_patches_compliance_level: Option<String>,
+    pub approved_patches_compliance_level: Option<PatchComplianceLevel>,
     /// <p>Indicates whether the list of approved patches includes non-security updates that should be applied to the instances. The default value is 'false'. Applies to Linux instances only.</p>
     #[serde(rename = "ApprovedPatchesEnableNonSecurity")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4074,8 +7473,13 @@ pub struct GetPatchBaselineResult {
     pub baseline_id: Option<String>,
     /// <p>The date the patch baseline was created.</p>
     #[serde(rename = "CreatedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A description of the patch baseline.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4086,8 +7490,13 @@ pub struct GetPatchBaselineResult {
     pub global_filters: Option<PatchFilterGroup>,
     /// <p>The date the patch baseline was last modified.</p>
     #[serde(rename = "ModifiedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub modified_date: Option<f64>,
+    pub modified_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the patch baseline.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4095,7 +7504,7 @@ pub struct GetPatchBaselineResult {
     /// <p>Returns the operating system specified for the patch baseline.</p>
     #[serde(rename = "OperatingSystem")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub operating_system: Option<String>,
+    pub operating_system: Option<OperatingSystem>,
     /// <p>Patch groups included in the patch baseline.</p>
     #[serde(rename = "PatchGroups")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4107,7 +7516,7 @@ pub struct GetPatchBaselineResult {
     /// <p>The action specified to take on patches included in the RejectedPatches list. A patch can be allowed only if it is a dependency of another package, or blocked entirely along with packages that include it as a dependency.</p>
     #[serde(rename = "RejectedPatchesAction")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub rejected_patches_action: Option<String>,
+    pub rejected_patches_action: Option<PatchAction>,
     /// <p>Information about the patches to use to update the instances, including target operating systems and source repositories. Applies to Linux instances only.</p>
     #[serde(rename = "Sources")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4219,8 +7628,13 @@ pub struct InstanceAssociationStatusInfo {
     pub error_code: Option<String>,
     /// <p>The date the instance association ran. </p>
     #[serde(rename = "ExecutionDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub execution_date: Option<f64>,
+    pub execution_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Summary information about association execution.</p>
     #[serde(rename = "ExecutionSummary")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4285,16 +7699,31 @@ pub struct InstanceInformation {
     pub is_latest_version: Option<bool>,
     /// <p>The date the association was last run.</p>
     #[serde(rename = "LastAssociationExecutionDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_association_execution_date: Option<f64>,
+    pub last_association_execution_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The date and time when agent last pinged Systems Manager service. </p>
     #[serde(rename = "LastPingDateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_ping_date_time: Option<f64>,
+    pub last_ping_date_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The last date the association was successfully run.</p>
     #[serde(rename = "LastSuccessfulAssociationExecutionDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_successful_association_execution_date: Option<f64>,
+    pub last_successful_association_execution_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the managed instance.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4302,7 +7731,7 @@ pub struct InstanceInformation {
     /// <p>Connection status of SSM Agent. </p>
     #[serde(rename = "PingStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ping_status: Option<String>,
+    pub ping_status: Option<PingStatus>,
     /// <p>The name of the operating system platform running on your instance. </p>
     #[serde(rename = "PlatformName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4310,19 +7739,24 @@ pub struct InstanceInformation {
     /// <p>The operating system platform type. </p>
     #[serde(rename = "PlatformType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub platform_type: Option<String>,
+    pub platform_type: Option<PlatformType>,
     /// <p>The version of the OS platform running on your instance. </p>
     #[serde(rename = "PlatformVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub platform_version: Option<String>,
     /// <p>The date the server or VM was registered with AWS as a managed instance.</p>
     #[serde(rename = "RegistrationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub registration_date: Option<f64>,
+    pub registration_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The type of instance. Instances are either EC2 instances or managed instances. </p>
     #[serde(rename = "ResourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ResourceType>,
 }
 
 /// <p>Describes a filter for a specific list of instances. You can filter instances information by using tags. You specify tags by using a key-value mapping.</p> <p>Use this action instead of the <a>DescribeInstanceInformationRequest$InstanceInformationFilterList</a> method. The <code>InstanceInformationFilterList</code> method is a legacy method and does not support tags. </p>
@@ -4331,45 +7765,168 @@ pub struct InstanceInformation {
 pub struct InstanceInformationFilter {
     /// <p>The name of the filter. </p>
     #[serde(rename = "key")]
-    pub key: String,
+    pub key: InstanceInformationFilterKey,
     /// <p>The filter values.</p>
     #[serde(rename = "valueSet")]
     pub value_set: Vec<String>,
 }
 
-/// <p>The filters to describe or get information about your managed instances.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct InstanceInformationStringFilter {
-    /// <p>The filter key name to describe your instances. For example:</p> <p>"InstanceIds"|"AgentVersion"|"PingStatus"|"PlatformTypes"|"ActivationIds"|"IamRole"|"ResourceType"|"AssociationStatus"|"Tag Key"</p>
-    #[serde(rename = "Key")]
-    pub key: String,
-    /// <p>The filter values.</p>
-    #[serde(rename = "Values")]
-    pub values: Vec<String>,
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum InstanceInformationFilterKey {
+    /// `InstanceIds`
+    InstanceIds,
+    /// `AgentVersion`
+    AgentVersion,
+    /// `PingStatus`
+    PingStatus,
+    /// `PlatformTypes`
+    PlatformTypes,
+    /// `ActivationIds`
+    ActivationIds,
+    /// `IamRole`
+    IamRole,
+    /// `ResourceType`
+    ResourceType,
+    /// `AssociationStatus`
+    AssociationStatus,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl InstanceInformationFilterKey {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InstanceInformationFilterKey::InstanceIds => "InstanceIds",
+            InstanceInformationFilterKey::AgentVersion => "AgentVersion",
+            InstanceInformationFilterKey::PingStatus => "PingStatus",
+            InstanceInformationFilterKey::PlatformTypes => "PlatformTypes",
+            InstanceInformationFilterKey::ActivationIds => "ActivationIds",
+            InstanceInformationFilterKey::IamRole => "IamRole",
+            InstanceInformationFilterKey::ResourceType => "ResourceType",
+            InstanceInformationFilterKey::AssociationStatus => "AssociationStatus",
+            InstanceInformationFilterKey::Unknown(ref value) => value,
+        }
+    }
 }
 
-/// <p>Defines the high-level patch compliance state for a managed instance, providing information about the number of installed, missing, not applicable, and failed patches along with metadata about the operation when this information was gathered for the instance.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct InstancePatchState {
-    /// <p>The ID of the patch baseline used to patch the instance.</p>
-    #[serde(rename = "BaselineId")]
-    pub baseline_id: String,
-    /// <p>The number of patches from the patch baseline that were attempted to be installed during the last patching operation, but failed to install.</p>
-    #[serde(rename = "FailedCount")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub failed_count: Option<i64>,
-    /// <p>An https URL or an Amazon S3 path-style URL to a list of patches to be installed. This patch installation list, which you maintain in an Amazon S3 bucket in YAML format and specify in the SSM document <code>AWS-RunPatchBaseline</code>, overrides the patches specified by the default patch baseline.</p> <p>For more information about the <code>InstallOverrideList</code> parameter, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/patch-manager-about-aws-runpatchbaseline.html">About the SSM Document AWS-RunPatchBaseline</a> in the <i>AWS Systems Manager User Guide</i>.</p>
-    #[serde(rename = "InstallOverrideList")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub install_override_list: Option<String>,
-    /// <p>The number of patches from the patch baseline that are installed on the instance.</p>
-    #[serde(rename = "InstalledCount")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub installed_count: Option<i64>,
-    /// <p>The number of patches not specified in the patch baseline that are installed on the instance.</p>
-    #[serde(rename = "InstalledOtherCount")]
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for InstanceInformationFilterKey {
+    fn default() -> InstanceInformationFilterKey {
+        InstanceInformationFilterKey::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for InstanceInformationFilterKey {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for InstanceInformationFilterKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for InstanceInformationFilterKey {
+    fn from(value: &'a str) -> InstanceInformationFilterKey {
+        match value {
+            "InstanceIds" => InstanceInformationFilterKey::InstanceIds,
+            "AgentVersion" => InstanceInformationFilterKey::AgentVersion,
+            "PingStatus" => InstanceInformationFilterKey::PingStatus,
+            "PlatformTypes" => InstanceInformationFilterKey::PlatformTypes,
+            "ActivationIds" => InstanceInformationFilterKey::ActivationIds,
+            "IamRole" => InstanceInformationFilterKey::IamRole,
+            "ResourceType" => InstanceInformationFilterKey::ResourceType,
+            "AssociationStatus" => InstanceInformationFilterKey::AssociationStatus,
+            _ => InstanceInformationFilterKey::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InstanceInformationFilterKey {
+    fn from(value: String) -> InstanceInformationFilterKey {
+        match value.as_str() {
+            "InstanceIds" => InstanceInformationFilterKey::InstanceIds,
+            "AgentVersion" => InstanceInformationFilterKey::AgentVersion,
+            "PingStatus" => InstanceInformationFilterKey::PingStatus,
+            "PlatformTypes" => InstanceInformationFilterKey::PlatformTypes,
+            "ActivationIds" => InstanceInformationFilterKey::ActivationIds,
+            "IamRole" => InstanceInformationFilterKey::IamRole,
+            "ResourceType" => InstanceInformationFilterKey::ResourceType,
+            "AssociationStatus" => InstanceInformationFilterKey::AssociationStatus,
+            _ => InstanceInformationFilterKey::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for InstanceInformationFilterKey {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<InstanceInformationFilterKey, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for InstanceInformationFilterKey {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for InstanceInformationFilterKey {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InstanceInformationFilterKey {
+    fn deserialize<D>(deserializer: D) -> Result<InstanceInformationFilterKey, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>The filters to describe or get information about your managed instances.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct InstanceInformationStringFilter {
+    /// <p>The filter key name to describe your instances. For example:</p> <p>"InstanceIds"|"AgentVersion"|"PingStatus"|"PlatformTypes"|"ActivationIds"|"IamRole"|"ResourceType"|"AssociationStatus"|"Tag Key"</p>
+    #[serde(rename = "Key")]
+    pub key: String,
+    /// <p>The filter values.</p>
+    #[serde(rename = "Values")]
+    pub values: Vec<String>,
+}
+
+/// <p>Defines the high-level patch compliance state for a managed instance, providing information about the number of installed, missing, not applicable, and failed patches along with metadata about the operation when this information was gathered for the instance.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct InstancePatchState {
+    /// <p>The ID of the patch baseline used to patch the instance.</p>
+    #[serde(rename = "BaselineId")]
+    pub baseline_id: String,
+    /// <p>The number of patches from the patch baseline that were attempted to be installed during the last patching operation, but failed to install.</p>
+    #[serde(rename = "FailedCount")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub failed_count: Option<i64>,
+    /// <p>An https URL or an Amazon S3 path-style URL to a list of patches to be installed. This patch installation list, which you maintain in an Amazon S3 bucket in YAML format and specify in the SSM document <code>AWS-RunPatchBaseline</code>, overrides the patches specified by the default patch baseline.</p> <p>For more information about the <code>InstallOverrideList</code> parameter, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/patch-manager-about-aws-runpatchbaseline.html">About the SSM Document AWS-RunPatchBaseline</a> in the <i>AWS Systems Manager User Guide</i>.</p>
+    #[serde(rename = "InstallOverrideList")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub install_override_list: Option<String>,
+    /// <p>The number of patches from the patch baseline that are installed on the instance.</p>
+    #[serde(rename = "InstalledCount")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub installed_count: Option<i64>,
+    /// <p>The number of patches not specified in the patch baseline that are installed on the instance.</p>
+    #[serde(rename = "InstalledOtherCount")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub installed_other_count: Option<i64>,
     /// <p>The number of patches installed by Patch Manager since the last time the instance was rebooted.</p>
@@ -4385,8 +7942,13 @@ pub struct InstancePatchState {
     pub instance_id: String,
     /// <p>The time of the last attempt to patch the instance with <code>NoReboot</code> specified as the reboot option.</p>
     #[serde(rename = "LastNoRebootInstallOperationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_no_reboot_install_operation_time: Option<f64>,
+    pub last_no_reboot_install_operation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The number of patches from the patch baseline that are applicable for the instance but aren't currently installed.</p>
     #[serde(rename = "MissingCount")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4397,13 +7959,23 @@ pub struct InstancePatchState {
     pub not_applicable_count: Option<i64>,
     /// <p>The type of patching operation that was performed: SCAN (assess patch compliance state) or INSTALL (install missing patches).</p>
     #[serde(rename = "Operation")]
-    pub operation: String,
+    pub operation: PatchOperationType,
     /// <p>The time the most recent patching operation completed on the instance.</p>
     #[serde(rename = "OperationEndTime")]
-    pub operation_end_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub operation_end_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The time the most recent patching operation was started on the instance.</p>
     #[serde(rename = "OperationStartTime")]
-    pub operation_start_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub operation_start_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>Placeholder information. This field will always be empty in the current release of the service.</p>
     #[serde(rename = "OwnerInformation")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4414,7 +7986,7 @@ pub struct InstancePatchState {
     /// <p><p>Indicates the reboot option specified in the patch baseline.</p> <note> <p>Reboot options apply to <code>Install</code> operations only. Reboots are not attempted for Patch Manager <code>Scan</code> operations.</p> </note> <ul> <li> <p> <b>RebootIfNeeded</b>: Patch Manager tries to reboot the instance if it installed any patches, or if any patches are detected with a status of <code>InstalledPendingReboot</code>.</p> </li> <li> <p> <b>NoReboot</b>: Patch Manager attempts to install missing packages without trying to reboot the system. Patches installed with this option are assigned a status of <code>InstalledPendingReboot</code>. These patches might not be in effect until a reboot is performed.</p> </li> </ul></p>
     #[serde(rename = "RebootOption")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub reboot_option: Option<String>,
+    pub reboot_option: Option<RebootOption>,
     /// <p>The ID of the patch baseline snapshot used during the patching operation when this compliance data was collected.</p>
     #[serde(rename = "SnapshotId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4434,12 +8006,115 @@ pub struct InstancePatchStateFilter {
     pub key: String,
     /// <p>The type of comparison that should be performed for the value: Equal, NotEqual, LessThan or GreaterThan.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: InstancePatchStateOperatorType,
     /// <p>The value for the filter, must be an integer greater than or equal to 0.</p>
     #[serde(rename = "Values")]
     pub values: Vec<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum InstancePatchStateOperatorType {
+    /// `Equal`
+    Equal,
+    /// `NotEqual`
+    NotEqual,
+    /// `LessThan`
+    LessThan,
+    /// `GreaterThan`
+    GreaterThan,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl InstancePatchStateOperatorType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InstancePatchStateOperatorType::Equal => "Equal",
+            InstancePatchStateOperatorType::NotEqual => "NotEqual",
+            InstancePatchStateOperatorType::LessThan => "LessThan",
+            InstancePatchStateOperatorType::GreaterThan => "GreaterThan",
+            InstancePatchStateOperatorType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for InstancePatchStateOperatorType {
+    fn default() -> InstancePatchStateOperatorType {
+        InstancePatchStateOperatorType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for InstancePatchStateOperatorType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for InstancePatchStateOperatorType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for InstancePatchStateOperatorType {
+    fn from(value: &'a str) -> InstancePatchStateOperatorType {
+        match value {
+            "Equal" => InstancePatchStateOperatorType::Equal,
+            "NotEqual" => InstancePatchStateOperatorType::NotEqual,
+            "LessThan" => InstancePatchStateOperatorType::LessThan,
+            "GreaterThan" => InstancePatchStateOperatorType::GreaterThan,
+            _ => InstancePatchStateOperatorType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InstancePatchStateOperatorType {
+    fn from(value: String) -> InstancePatchStateOperatorType {
+        match value.as_str() {
+            "Equal" => InstancePatchStateOperatorType::Equal,
+            "NotEqual" => InstancePatchStateOperatorType::NotEqual,
+            "LessThan" => InstancePatchStateOperatorType::LessThan,
+            "GreaterThan" => InstancePatchStateOperatorType::GreaterThan,
+            _ => InstancePatchStateOperatorType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for InstancePatchStateOperatorType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<InstancePatchStateOperatorType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for InstancePatchStateOperatorType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for InstancePatchStateOperatorType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InstancePatchStateOperatorType {
+    fn deserialize<D>(deserializer: D) -> Result<InstancePatchStateOperatorType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Specifies the inventory type and attribute for the aggregation execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -4458,6 +8133,192 @@ pub struct InventoryAggregator {
     pub groups: Option<Vec<InventoryGroup>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum InventoryAttributeDataType {
+    /// `string`
+    String,
+    /// `number`
+    Number,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl InventoryAttributeDataType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InventoryAttributeDataType::String => "string",
+            InventoryAttributeDataType::Number => "number",
+            InventoryAttributeDataType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for InventoryAttributeDataType {
+    fn default() -> InventoryAttributeDataType {
+        InventoryAttributeDataType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for InventoryAttributeDataType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for InventoryAttributeDataType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for InventoryAttributeDataType {
+    fn from(value: &'a str) -> InventoryAttributeDataType {
+        match value {
+            "string" => InventoryAttributeDataType::String,
+            "number" => InventoryAttributeDataType::Number,
+            _ => InventoryAttributeDataType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InventoryAttributeDataType {
+    fn from(value: String) -> InventoryAttributeDataType {
+        match value.as_str() {
+            "string" => InventoryAttributeDataType::String,
+            "number" => InventoryAttributeDataType::Number,
+            _ => InventoryAttributeDataType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for InventoryAttributeDataType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<InventoryAttributeDataType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for InventoryAttributeDataType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for InventoryAttributeDataType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InventoryAttributeDataType {
+    fn deserialize<D>(deserializer: D) -> Result<InventoryAttributeDataType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum InventoryDeletionStatus {
+    /// `InProgress`
+    InProgress,
+    /// `Complete`
+    Complete,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl InventoryDeletionStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InventoryDeletionStatus::InProgress => "InProgress",
+            InventoryDeletionStatus::Complete => "Complete",
+            InventoryDeletionStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for InventoryDeletionStatus {
+    fn default() -> InventoryDeletionStatus {
+        InventoryDeletionStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for InventoryDeletionStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for InventoryDeletionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for InventoryDeletionStatus {
+    fn from(value: &'a str) -> InventoryDeletionStatus {
+        match value {
+            "InProgress" => InventoryDeletionStatus::InProgress,
+            "Complete" => InventoryDeletionStatus::Complete,
+            _ => InventoryDeletionStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InventoryDeletionStatus {
+    fn from(value: String) -> InventoryDeletionStatus {
+        match value.as_str() {
+            "InProgress" => InventoryDeletionStatus::InProgress,
+            "Complete" => InventoryDeletionStatus::Complete,
+            _ => InventoryDeletionStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for InventoryDeletionStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<InventoryDeletionStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for InventoryDeletionStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for InventoryDeletionStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InventoryDeletionStatus {
+    fn deserialize<D>(deserializer: D) -> Result<InventoryDeletionStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Status information returned by the <code>DeleteInventory</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -4468,8 +8329,13 @@ pub struct InventoryDeletionStatusItem {
     pub deletion_id: Option<String>,
     /// <p>The UTC timestamp when the delete operation started.</p>
     #[serde(rename = "DeletionStartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deletion_start_time: Option<f64>,
+    pub deletion_start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Information about the delete operation. For more information about this summary, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/sysman-inventory-custom.html#sysman-inventory-delete">Understanding the Delete Inventory Summary</a> in the <i>AWS Systems Manager User Guide</i>.</p>
     #[serde(rename = "DeletionSummary")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4477,15 +8343,20 @@ pub struct InventoryDeletionStatusItem {
     /// <p>The status of the operation. Possible values are InProgress and Complete.</p>
     #[serde(rename = "LastStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_status: Option<String>,
+    pub last_status: Option<InventoryDeletionStatus>,
     /// <p>Information about the status.</p>
     #[serde(rename = "LastStatusMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub last_status_message: Option<String>,
     /// <p>The UTC timestamp of when the last status report.</p>
     #[serde(rename = "LastStatusUpdateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_status_update_time: Option<f64>,
+    pub last_status_update_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the inventory data type.</p>
     #[serde(rename = "TypeName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4538,7 +8409,7 @@ pub struct InventoryFilter {
     /// <p>The type of filter.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<InventoryQueryOperatorType>,
     /// <p>Inventory filter values. Example: inventory filter where instance IDs are specified as values Key=AWS:InstanceInformation.InstanceId,Values= i-a12b3c4d5e6g, i-1a2b3c4d5e6,Type=Equal </p>
     #[serde(rename = "Values")]
     pub values: Vec<String>,
@@ -4589,7 +8460,7 @@ pub struct InventoryItem {
 pub struct InventoryItemAttribute {
     /// <p>The data type of the inventory item attribute. </p>
     #[serde(rename = "DataType")]
-    pub data_type: String,
+    pub data_type: InventoryAttributeDataType,
     /// <p>Name of the inventory item attribute.</p>
     #[serde(rename = "Name")]
     pub name: String,
@@ -4615,6 +8486,119 @@ pub struct InventoryItemSchema {
     pub version: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum InventoryQueryOperatorType {
+    /// `Equal`
+    Equal,
+    /// `NotEqual`
+    NotEqual,
+    /// `BeginWith`
+    BeginWith,
+    /// `LessThan`
+    LessThan,
+    /// `GreaterThan`
+    GreaterThan,
+    /// `Exists`
+    Exists,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl InventoryQueryOperatorType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InventoryQueryOperatorType::Equal => "Equal",
+            InventoryQueryOperatorType::NotEqual => "NotEqual",
+            InventoryQueryOperatorType::BeginWith => "BeginWith",
+            InventoryQueryOperatorType::LessThan => "LessThan",
+            InventoryQueryOperatorType::GreaterThan => "GreaterThan",
+            InventoryQueryOperatorType::Exists => "Exists",
+            InventoryQueryOperatorType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for InventoryQueryOperatorType {
+    fn default() -> InventoryQueryOperatorType {
+        InventoryQueryOperatorType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for InventoryQueryOperatorType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for InventoryQueryOperatorType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for InventoryQueryOperatorType {
+    fn from(value: &'a str) -> InventoryQueryOperatorType {
+        match value {
+            "Equal" => InventoryQueryOperatorType::Equal,
+            "NotEqual" => InventoryQueryOperatorType::NotEqual,
+            "BeginWith" => InventoryQueryOperatorType::BeginWith,
+            "LessThan" => InventoryQueryOperatorType::LessThan,
+            "GreaterThan" => InventoryQueryOperatorType::GreaterThan,
+            "Exists" => InventoryQueryOperatorType::Exists,
+            _ => InventoryQueryOperatorType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InventoryQueryOperatorType {
+    fn from(value: String) -> InventoryQueryOperatorType {
+        match value.as_str() {
+            "Equal" => InventoryQueryOperatorType::Equal,
+            "NotEqual" => InventoryQueryOperatorType::NotEqual,
+            "BeginWith" => InventoryQueryOperatorType::BeginWith,
+            "LessThan" => InventoryQueryOperatorType::LessThan,
+            "GreaterThan" => InventoryQueryOperatorType::GreaterThan,
+            "Exists" => InventoryQueryOperatorType::Exists,
+            _ => InventoryQueryOperatorType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for InventoryQueryOperatorType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<InventoryQueryOperatorType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for InventoryQueryOperatorType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for InventoryQueryOperatorType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InventoryQueryOperatorType {
+    fn deserialize<D>(deserializer: D) -> Result<InventoryQueryOperatorType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Inventory query results.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -4652,22 +8636,115 @@ pub struct InventoryResultItem {
     pub type_name: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct LabelParameterVersionRequest {
-    /// <p>One or more labels to attach to the specified parameter version.</p>
-    #[serde(rename = "Labels")]
-    pub labels: Vec<String>,
-    /// <p>The parameter name on which you want to attach one or more labels.</p>
-    #[serde(rename = "Name")]
-    pub name: String,
-    /// <p>The specific version of the parameter on which you want to attach one or more labels. If no version is specified, the system attaches the label to the latest version.</p>
-    #[serde(rename = "ParameterVersion")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub parameter_version: Option<i64>,
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum InventorySchemaDeleteOption {
+    /// `DisableSchema`
+    DisableSchema,
+    /// `DeleteSchema`
+    DeleteSchema,
+    /// A value not known to this version of the crate
+    Unknown(String),
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+impl InventorySchemaDeleteOption {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InventorySchemaDeleteOption::DisableSchema => "DisableSchema",
+            InventorySchemaDeleteOption::DeleteSchema => "DeleteSchema",
+            InventorySchemaDeleteOption::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for InventorySchemaDeleteOption {
+    fn default() -> InventorySchemaDeleteOption {
+        InventorySchemaDeleteOption::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for InventorySchemaDeleteOption {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for InventorySchemaDeleteOption {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for InventorySchemaDeleteOption {
+    fn from(value: &'a str) -> InventorySchemaDeleteOption {
+        match value {
+            "DisableSchema" => InventorySchemaDeleteOption::DisableSchema,
+            "DeleteSchema" => InventorySchemaDeleteOption::DeleteSchema,
+            _ => InventorySchemaDeleteOption::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InventorySchemaDeleteOption {
+    fn from(value: String) -> InventorySchemaDeleteOption {
+        match value.as_str() {
+            "DisableSchema" => InventorySchemaDeleteOption::DisableSchema,
+            "DeleteSchema" => InventorySchemaDeleteOption::DeleteSchema,
+            _ => InventorySchemaDeleteOption::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for InventorySchemaDeleteOption {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<InventorySchemaDeleteOption, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for InventorySchemaDeleteOption {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for InventorySchemaDeleteOption {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InventorySchemaDeleteOption {
+    fn deserialize<D>(deserializer: D) -> Result<InventorySchemaDeleteOption, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct LabelParameterVersionRequest {
+    /// <p>One or more labels to attach to the specified parameter version.</p>
+    #[serde(rename = "Labels")]
+    pub labels: Vec<String>,
+    /// <p>The parameter name on which you want to attach one or more labels.</p>
+    #[serde(rename = "Name")]
+    pub name: String,
+    /// <p>The specific version of the parameter on which you want to attach one or more labels. If no version is specified, the system attaches the label to the latest version.</p>
+    #[serde(rename = "ParameterVersion")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parameter_version: Option<i64>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LabelParameterVersionResult {
     /// <p>The label does not meet the requirements. For information about parameter label requirements, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/sysman-paramstore-labels.html">Labeling Parameters</a> in the <i>AWS Systems Manager User Guide</i>.</p>
@@ -4680,6 +8757,104 @@ pub struct LabelParameterVersionResult {
     pub parameter_version: Option<i64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LastResourceDataSyncStatus {
+    /// `Successful`
+    Successful,
+    /// `Failed`
+    Failed,
+    /// `InProgress`
+    InProgress,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LastResourceDataSyncStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LastResourceDataSyncStatus::Successful => "Successful",
+            LastResourceDataSyncStatus::Failed => "Failed",
+            LastResourceDataSyncStatus::InProgress => "InProgress",
+            LastResourceDataSyncStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LastResourceDataSyncStatus {
+    fn default() -> LastResourceDataSyncStatus {
+        LastResourceDataSyncStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LastResourceDataSyncStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LastResourceDataSyncStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LastResourceDataSyncStatus {
+    fn from(value: &'a str) -> LastResourceDataSyncStatus {
+        match value {
+            "Successful" => LastResourceDataSyncStatus::Successful,
+            "Failed" => LastResourceDataSyncStatus::Failed,
+            "InProgress" => LastResourceDataSyncStatus::InProgress,
+            _ => LastResourceDataSyncStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LastResourceDataSyncStatus {
+    fn from(value: String) -> LastResourceDataSyncStatus {
+        match value.as_str() {
+            "Successful" => LastResourceDataSyncStatus::Successful,
+            "Failed" => LastResourceDataSyncStatus::Failed,
+            "InProgress" => LastResourceDataSyncStatus::InProgress,
+            _ => LastResourceDataSyncStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LastResourceDataSyncStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LastResourceDataSyncStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LastResourceDataSyncStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LastResourceDataSyncStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LastResourceDataSyncStatus {
+    fn deserialize<D>(deserializer: D) -> Result<LastResourceDataSyncStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ListAssociationVersionsRequest {
@@ -5070,7 +9245,7 @@ pub struct ListTagsForResourceRequest {
     pub resource_id: String,
     /// <p>Returns a list of tags for a specific resource type.</p>
     #[serde(rename = "ResourceType")]
-    pub resource_type: String,
+    pub resource_type: ResourceTypeForTagging,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -5116,16 +9291,26 @@ pub struct MaintenanceWindowAutomationParameters {
 pub struct MaintenanceWindowExecution {
     /// <p>The time the execution finished.</p>
     #[serde(rename = "EndTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The time the execution started.</p>
     #[serde(rename = "StartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The status of the execution.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<MaintenanceWindowExecutionStatus>,
     /// <p>The details explaining the Status. Only available for certain status values.</p>
     #[serde(rename = "StatusDetails")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -5140,22 +9325,155 @@ pub struct MaintenanceWindowExecution {
     pub window_id: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MaintenanceWindowExecutionStatus {
+    /// `PENDING`
+    Pending,
+    /// `IN_PROGRESS`
+    InProgress,
+    /// `SUCCESS`
+    Success,
+    /// `FAILED`
+    Failed,
+    /// `TIMED_OUT`
+    TimedOut,
+    /// `CANCELLING`
+    Cancelling,
+    /// `CANCELLED`
+    Cancelled,
+    /// `SKIPPED_OVERLAPPING`
+    SkippedOverlapping,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl MaintenanceWindowExecutionStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MaintenanceWindowExecutionStatus::Pending => "PENDING",
+            MaintenanceWindowExecutionStatus::InProgress => "IN_PROGRESS",
+            MaintenanceWindowExecutionStatus::Success => "SUCCESS",
+            MaintenanceWindowExecutionStatus::Failed => "FAILED",
+            MaintenanceWindowExecutionStatus::TimedOut => "TIMED_OUT",
+            MaintenanceWindowExecutionStatus::Cancelling => "CANCELLING",
+            MaintenanceWindowExecutionStatus::Cancelled => "CANCELLED",
+            MaintenanceWindowExecutionStatus::SkippedOverlapping => "SKIPPED_OVERLAPPING",
+            MaintenanceWindowExecutionStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for MaintenanceWindowExecutionStatus {
+    fn default() -> MaintenanceWindowExecutionStatus {
+        MaintenanceWindowExecutionStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for MaintenanceWindowExecutionStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for MaintenanceWindowExecutionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for MaintenanceWindowExecutionStatus {
+    fn from(value: &'a str) -> MaintenanceWindowExecutionStatus {
+        match value {
+            "PENDING" => MaintenanceWindowExecutionStatus::Pending,
+            "IN_PROGRESS" => MaintenanceWindowExecutionStatus::InProgress,
+            "SUCCESS" => MaintenanceWindowExecutionStatus::Success,
+            "FAILED" => MaintenanceWindowExecutionStatus::Failed,
+            "TIMED_OUT" => MaintenanceWindowExecutionStatus::TimedOut,
+            "CANCELLING" => MaintenanceWindowExecutionStatus::Cancelling,
+            "CANCELLED" => MaintenanceWindowExecutionStatus::Cancelled,
+            "SKIPPED_OVERLAPPING" => MaintenanceWindowExecutionStatus::SkippedOverlapping,
+            _ => MaintenanceWindowExecutionStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MaintenanceWindowExecutionStatus {
+    fn from(value: String) -> MaintenanceWindowExecutionStatus {
+        match value.as_str() {
+            "PENDING" => MaintenanceWindowExecutionStatus::Pending,
+            "IN_PROGRESS" => MaintenanceWindowExecutionStatus::InProgress,
+            "SUCCESS" => MaintenanceWindowExecutionStatus::Success,
+            "FAILED" => MaintenanceWindowExecutionStatus::Failed,
+            "TIMED_OUT" => MaintenanceWindowExecutionStatus::TimedOut,
+            "CANCELLING" => MaintenanceWindowExecutionStatus::Cancelling,
+            "CANCELLED" => MaintenanceWindowExecutionStatus::Cancelled,
+            "SKIPPED_OVERLAPPING" => MaintenanceWindowExecutionStatus::SkippedOverlapping,
+            _ => MaintenanceWindowExecutionStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for MaintenanceWindowExecutionStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<MaintenanceWindowExecutionStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MaintenanceWindowExecutionStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for MaintenanceWindowExecutionStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MaintenanceWindowExecutionStatus {
+    fn deserialize<D>(deserializer: D) -> Result<MaintenanceWindowExecutionStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about a task execution performed as part of a maintenance window execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct MaintenanceWindowExecutionTaskIdentity {
     /// <p>The time the task execution finished.</p>
     #[serde(rename = "EndTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The time the task execution started.</p>
     #[serde(rename = "StartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The status of the task execution.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<MaintenanceWindowExecutionStatus>,
     /// <p>The details explaining the status of the task execution. Only available for certain status values.</p>
     #[serde(rename = "StatusDetails")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -5171,7 +9489,7 @@ pub struct MaintenanceWindowExecutionTaskIdentity {
     /// <p>The type of task that ran.</p>
     #[serde(rename = "TaskType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub task_type: Option<String>,
+    pub task_type: Option<MaintenanceWindowTaskType>,
     /// <p>The ID of the maintenance window execution that ran the task.</p>
     #[serde(rename = "WindowExecutionId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -5184,8 +9502,13 @@ pub struct MaintenanceWindowExecutionTaskIdentity {
 pub struct MaintenanceWindowExecutionTaskInvocationIdentity {
     /// <p>The time the invocation finished.</p>
     #[serde(rename = "EndTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The ID of the action performed in the service that actually handled the task invocation. If the task type is RUN_COMMAND, this value is the command ID.</p>
     #[serde(rename = "ExecutionId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -5204,12 +9527,17 @@ pub struct MaintenanceWindowExecutionTaskInvocationIdentity {
     pub parameters: Option<String>,
     /// <p>The time the invocation started.</p>
     #[serde(rename = "StartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The status of the task invocation.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<MaintenanceWindowExecutionStatus>,
     /// <p>The details explaining the status of the task invocation. Only available for certain Status values. </p>
     #[serde(rename = "StatusDetails")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -5221,7 +9549,7 @@ pub struct MaintenanceWindowExecutionTaskInvocationIdentity {
     /// <p>The task type.</p>
     #[serde(rename = "TaskType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub task_type: Option<String>,
+    pub task_type: Option<MaintenanceWindowTaskType>,
     /// <p>The ID of the maintenance window execution that ran the task.</p>
     #[serde(rename = "WindowExecutionId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -5332,6 +9660,99 @@ pub struct MaintenanceWindowLambdaParameters {
     pub qualifier: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MaintenanceWindowResourceType {
+    /// `INSTANCE`
+    Instance,
+    /// `RESOURCE_GROUP`
+    ResourceGroup,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl MaintenanceWindowResourceType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MaintenanceWindowResourceType::Instance => "INSTANCE",
+            MaintenanceWindowResourceType::ResourceGroup => "RESOURCE_GROUP",
+            MaintenanceWindowResourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for MaintenanceWindowResourceType {
+    fn default() -> MaintenanceWindowResourceType {
+        MaintenanceWindowResourceType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for MaintenanceWindowResourceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for MaintenanceWindowResourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for MaintenanceWindowResourceType {
+    fn from(value: &'a str) -> MaintenanceWindowResourceType {
+        match value {
+            "INSTANCE" => MaintenanceWindowResourceType::Instance,
+            "RESOURCE_GROUP" => MaintenanceWindowResourceType::ResourceGroup,
+            _ => MaintenanceWindowResourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MaintenanceWindowResourceType {
+    fn from(value: String) -> MaintenanceWindowResourceType {
+        match value.as_str() {
+            "INSTANCE" => MaintenanceWindowResourceType::Instance,
+            "RESOURCE_GROUP" => MaintenanceWindowResourceType::ResourceGroup,
+            _ => MaintenanceWindowResourceType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for MaintenanceWindowResourceType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<MaintenanceWindowResourceType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MaintenanceWindowResourceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for MaintenanceWindowResourceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MaintenanceWindowResourceType {
+    fn deserialize<D>(deserializer: D) -> Result<MaintenanceWindowResourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p><p>The parameters for a RUN_COMMAND task type.</p> <p>For information about specifying and updating task parameters, see <a>RegisterTaskWithMaintenanceWindow</a> and <a>UpdateMaintenanceWindowTask</a>.</p> <note> <p> <code>LoggingInfo</code> has been deprecated. To specify an S3 bucket to contain logs, instead use the <code>OutputS3BucketName</code> and <code>OutputS3KeyPrefix</code> options in the <code>TaskInvocationParameters</code> structure. For information about how Systems Manager handles these options for the supported maintenance window task types, see <a>MaintenanceWindowTaskInvocationParameters</a>.</p> <p> <code>TaskParameters</code> has been deprecated. To specify parameters to pass to a task when it runs, instead use the <code>Parameters</code> option in the <code>TaskInvocationParameters</code> structure. For information about how Systems Manager handles these options for the supported maintenance window task types, see <a>MaintenanceWindowTaskInvocationParameters</a>.</p> <p>For Run Command tasks, Systems Manager uses specified values for <code>TaskParameters</code> and <code>LoggingInfo</code> only if no values are specified for <code>TaskInvocationParameters</code>. </p> </note></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MaintenanceWindowRunCommandParameters {
@@ -5349,7 +9770,7 @@ pub struct MaintenanceWindowRunCommandParameters {
     /// <p>SHA-256 or SHA-1. SHA-1 hashes have been deprecated.</p>
     #[serde(rename = "DocumentHashType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub document_hash_type: Option<String>,
+    pub document_hash_type: Option<DocumentHashType>,
     /// <p>The SSM document version to use in the request. You can specify $DEFAULT, $LATEST, or a specific version number. If you run commands by using the AWS CLI, then you must escape the first two options by using a backslash. If you specify a version number, then you don't need to use the backslash. For example:</p> <p>--document-version "\$DEFAULT"</p> <p>--document-version "\$LATEST"</p> <p>--document-version "3"</p>
     #[serde(rename = "DocumentVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -5412,7 +9833,7 @@ pub struct MaintenanceWindowTarget {
     /// <p>The type of target that is being registered with the maintenance window.</p>
     #[serde(rename = "ResourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<MaintenanceWindowResourceType>,
     /// <p>The targets, either instances or tags.</p> <p>Specify instances using the following format:</p> <p> <code>Key=instanceids,Values=&lt;instanceid1&gt;,&lt;instanceid2&gt;</code> </p> <p>Tags are specified using the following format:</p> <p> <code>Key=&lt;tag name&gt;,Values=&lt;tag value&gt;</code>.</p>
     #[serde(rename = "Targets")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -5475,7 +9896,7 @@ pub struct MaintenanceWindowTask {
     /// <p>The type of task. The type can be one of the following: RUN_COMMAND, AUTOMATION, LAMBDA, or STEP_FUNCTIONS.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<MaintenanceWindowTaskType>,
     /// <p>The ID of the maintenance window where the task is registered.</p>
     #[serde(rename = "WindowId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -5516,6 +9937,109 @@ pub struct MaintenanceWindowTaskParameterValueExpression {
     pub values: Option<Vec<String>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MaintenanceWindowTaskType {
+    /// `RUN_COMMAND`
+    RunCommand,
+    /// `AUTOMATION`
+    Automation,
+    /// `STEP_FUNCTIONS`
+    StepFunctions,
+    /// `LAMBDA`
+    Lambda,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl MaintenanceWindowTaskType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MaintenanceWindowTaskType::RunCommand => "RUN_COMMAND",
+            MaintenanceWindowTaskType::Automation => "AUTOMATION",
+            MaintenanceWindowTaskType::StepFunctions => "STEP_FUNCTIONS",
+            MaintenanceWindowTaskType::Lambda => "LAMBDA",
+            MaintenanceWindowTaskType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for MaintenanceWindowTaskType {
+    fn default() -> MaintenanceWindowTaskType {
+        MaintenanceWindowTaskType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for MaintenanceWindowTaskType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for MaintenanceWindowTaskType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for MaintenanceWindowTaskType {
+    fn from(value: &'a str) -> MaintenanceWindowTaskType {
+        match value {
+            "RUN_COMMAND" => MaintenanceWindowTaskType::RunCommand,
+            "AUTOMATION" => MaintenanceWindowTaskType::Automation,
+            "STEP_FUNCTIONS" => MaintenanceWindowTaskType::StepFunctions,
+            "LAMBDA" => MaintenanceWindowTaskType::Lambda,
+            _ => MaintenanceWindowTaskType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MaintenanceWindowTaskType {
+    fn from(value: String) -> MaintenanceWindowTaskType {
+        match value.as_str() {
+            "RUN_COMMAND" => MaintenanceWindowTaskType::RunCommand,
+            "AUTOMATION" => MaintenanceWindowTaskType::Automation,
+            "STEP_FUNCTIONS" => MaintenanceWindowTaskType::StepFunctions,
+            "LAMBDA" => MaintenanceWindowTaskType::Lambda,
+            _ => MaintenanceWindowTaskType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for MaintenanceWindowTaskType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<MaintenanceWindowTaskType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MaintenanceWindowTaskType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for MaintenanceWindowTaskType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MaintenanceWindowTaskType {
+    fn deserialize<D>(deserializer: D) -> Result<MaintenanceWindowTaskType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ModifyDocumentPermissionRequest {
@@ -5532,7 +10056,7 @@ pub struct ModifyDocumentPermissionRequest {
     pub name: String,
     /// <p>The permission type for the document. The permission type can be <i>Share</i>.</p>
     #[serde(rename = "PermissionType")]
-    pub permission_type: String,
+    pub permission_type: DocumentPermissionType,
     /// <p>(Optional) The version of the document to share. If it's not specified, the system choose the <code>Default</code> version to share.</p>
     #[serde(rename = "SharedDocumentVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -5567,28 +10091,392 @@ pub struct NotificationConfig {
     /// <p>The different events for which you can receive notifications. These events include the following: All (events), InProgress, Success, TimedOut, Cancelled, Failed. To learn more about these events, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/monitoring-sns-notifications.html">Configuring Amazon SNS Notifications for AWS Systems Manager</a> in the <i>AWS Systems Manager User Guide</i>.</p>
     #[serde(rename = "NotificationEvents")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub notification_events: Option<Vec<String>>,
+    pub notification_events: Option<Vec<NotificationEvent>>,
     /// <p>Command: Receive notification when the status of a command changes. Invocation: For commands sent to multiple instances, receive notification on a per-instance basis when the status of a command changes. </p>
     #[serde(rename = "NotificationType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub notification_type: Option<String>,
+    pub notification_type: Option<NotificationType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum NotificationEvent {
+    /// `All`
+    All,
+    /// `InProgress`
+    InProgress,
+    /// `Success`
+    Success,
+    /// `TimedOut`
+    TimedOut,
+    /// `Cancelled`
+    Cancelled,
+    /// `Failed`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl NotificationEvent {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            NotificationEvent::All => "All",
+            NotificationEvent::InProgress => "InProgress",
+            NotificationEvent::Success => "Success",
+            NotificationEvent::TimedOut => "TimedOut",
+            NotificationEvent::Cancelled => "Cancelled",
+            NotificationEvent::Failed => "Failed",
+            NotificationEvent::Unknown(ref value) => value,
+        }
+    }
 }
 
-/// <p>One or more aggregators for viewing counts of OpsItems using different dimensions such as <code>Source</code>, <code>CreatedTime</code>, or <code>Source and CreatedTime</code>, to name a few.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct OpsAggregator {
-    /// <p>Either a Range or Count aggregator for limiting an OpsItem summary.</p>
-    #[serde(rename = "AggregatorType")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub aggregator_type: Option<String>,
-    /// <p>A nested aggregator for viewing counts of OpsItems.</p>
-    #[serde(rename = "Aggregators")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub aggregators: Option<Vec<OpsAggregator>>,
-    /// <p>The name of an OpsItem attribute on which to limit the count of OpsItems.</p>
-    #[serde(rename = "AttributeName")]
-    #[serde(skip_serializing_if = "Option::is_none")]
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for NotificationEvent {
+    fn default() -> NotificationEvent {
+        NotificationEvent::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for NotificationEvent {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for NotificationEvent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for NotificationEvent {
+    fn from(value: &'a str) -> NotificationEvent {
+        match value {
+            "All" => NotificationEvent::All,
+            "InProgress" => NotificationEvent::InProgress,
+            "Success" => NotificationEvent::Success,
+            "TimedOut" => NotificationEvent::TimedOut,
+            "Cancelled" => NotificationEvent::Cancelled,
+            "Failed" => NotificationEvent::Failed,
+            _ => NotificationEvent::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for NotificationEvent {
+    fn from(value: String) -> NotificationEvent {
+        match value.as_str() {
+            "All" => NotificationEvent::All,
+            "InProgress" => NotificationEvent::InProgress,
+            "Success" => NotificationEvent::Success,
+            "TimedOut" => NotificationEvent::TimedOut,
+            "Cancelled" => NotificationEvent::Cancelled,
+            "Failed" => NotificationEvent::Failed,
+            _ => NotificationEvent::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for NotificationEvent {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<NotificationEvent, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for NotificationEvent {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for NotificationEvent {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for NotificationEvent {
+    fn deserialize<D>(deserializer: D) -> Result<NotificationEvent, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum NotificationType {
+    /// `Command`
+    Command,
+    /// `Invocation`
+    Invocation,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl NotificationType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            NotificationType::Command => "Command",
+            NotificationType::Invocation => "Invocation",
+            NotificationType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for NotificationType {
+    fn default() -> NotificationType {
+        NotificationType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for NotificationType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for NotificationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for NotificationType {
+    fn from(value: &'a str) -> NotificationType {
+        match value {
+            "Command" => NotificationType::Command,
+            "Invocation" => NotificationType::Invocation,
+            _ => NotificationType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for NotificationType {
+    fn from(value: String) -> NotificationType {
+        match value.as_str() {
+            "Command" => NotificationType::Command,
+            "Invocation" => NotificationType::Invocation,
+            _ => NotificationType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for NotificationType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<NotificationType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for NotificationType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for NotificationType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for NotificationType {
+    fn deserialize<D>(deserializer: D) -> Result<NotificationType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OperatingSystem {
+    /// `WINDOWS`
+    Windows,
+    /// `AMAZON_LINUX`
+    AmazonLinux,
+    /// `AMAZON_LINUX_2`
+    AmazonLinux2,
+    /// `AMAZON_LINUX_2022`
+    AmazonLinux2022,
+    /// `UBUNTU`
+    Ubuntu,
+    /// `REDHAT_ENTERPRISE_LINUX`
+    RedhatEnterpriseLinux,
+    /// `SUSE`
+    Suse,
+    /// `CENTOS`
+    Centos,
+    /// `ORACLE_LINUX`
+    OracleLinux,
+    /// `DEBIAN`
+    Debian,
+    /// `MACOS`
+    Macos,
+    /// `RASPBIAN`
+    Raspbian,
+    /// `ROCKY_LINUX`
+    RockyLinux,
+    /// `ALMA_LINUX`
+    AlmaLinux,
+    /// `AMAZON_LINUX_2023`
+    AmazonLinux2023,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl OperatingSystem {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OperatingSystem::Windows => "WINDOWS",
+            OperatingSystem::AmazonLinux => "AMAZON_LINUX",
+            OperatingSystem::AmazonLinux2 => "AMAZON_LINUX_2",
+            OperatingSystem::AmazonLinux2022 => "AMAZON_LINUX_2022",
+            OperatingSystem::Ubuntu => "UBUNTU",
+            OperatingSystem::RedhatEnterpriseLinux => "REDHAT_ENTERPRISE_LINUX",
+            OperatingSystem::Suse => "SUSE",
+            OperatingSystem::Centos => "CENTOS",
+            OperatingSystem::OracleLinux => "ORACLE_LINUX",
+            OperatingSystem::Debian => "DEBIAN",
+            OperatingSystem::Macos => "MACOS",
+            OperatingSystem::Raspbian => "RASPBIAN",
+            OperatingSystem::RockyLinux => "ROCKY_LINUX",
+            OperatingSystem::AlmaLinux => "ALMA_LINUX",
+            OperatingSystem::AmazonLinux2023 => "AMAZON_LINUX_2023",
+            OperatingSystem::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for OperatingSystem {
+    fn default() -> OperatingSystem {
+        OperatingSystem::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for OperatingSystem {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for OperatingSystem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for OperatingSystem {
+    fn from(value: &'a str) -> OperatingSystem {
+        match value {
+            "WINDOWS" => OperatingSystem::Windows,
+            "AMAZON_LINUX" => OperatingSystem::AmazonLinux,
+            "AMAZON_LINUX_2" => OperatingSystem::AmazonLinux2,
+            "AMAZON_LINUX_2022" => OperatingSystem::AmazonLinux2022,
+            "UBUNTU" => OperatingSystem::Ubuntu,
+            "REDHAT_ENTERPRISE_LINUX" => OperatingSystem::RedhatEnterpriseLinux,
+            "SUSE" => OperatingSystem::Suse,
+            "CENTOS" => OperatingSystem::Centos,
+            "ORACLE_LINUX" => OperatingSystem::OracleLinux,
+            "DEBIAN" => OperatingSystem::Debian,
+            "MACOS" => OperatingSystem::Macos,
+            "RASPBIAN" => OperatingSystem::Raspbian,
+            "ROCKY_LINUX" => OperatingSystem::RockyLinux,
+            "ALMA_LINUX" => OperatingSystem::AlmaLinux,
+            "AMAZON_LINUX_2023" => OperatingSystem::AmazonLinux2023,
+            _ => OperatingSystem::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OperatingSystem {
+    fn from(value: String) -> OperatingSystem {
+        match value.as_str() {
+            "WINDOWS" => OperatingSystem::Windows,
+            "AMAZON_LINUX" => OperatingSystem::AmazonLinux,
+            "AMAZON_LINUX_2" => OperatingSystem::AmazonLinux2,
+            "AMAZON_LINUX_2022" => OperatingSystem::AmazonLinux2022,
+            "UBUNTU" => OperatingSystem::Ubuntu,
+            "REDHAT_ENTERPRISE_LINUX" => OperatingSystem::RedhatEnterpriseLinux,
+            "SUSE" => OperatingSystem::Suse,
+            "CENTOS" => OperatingSystem::Centos,
+            "ORACLE_LINUX" => OperatingSystem::OracleLinux,
+            "DEBIAN" => OperatingSystem::Debian,
+            "MACOS" => OperatingSystem::Macos,
+            "RASPBIAN" => OperatingSystem::Raspbian,
+            "ROCKY_LINUX" => OperatingSystem::RockyLinux,
+            "ALMA_LINUX" => OperatingSystem::AlmaLinux,
+            "AMAZON_LINUX_2023" => OperatingSystem::AmazonLinux2023,
+            _ => OperatingSystem::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for OperatingSystem {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<OperatingSystem, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OperatingSystem {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for OperatingSystem {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OperatingSystem {
+    fn deserialize<D>(deserializer: D) -> Result<OperatingSystem, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>One or more aggregators for viewing counts of OpsItems using different dimensions such as <code>Source</code>, <code>CreatedTime</code>, or <code>Source and CreatedTime</code>, to name a few.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct OpsAggregator {
+    /// <p>Either a Range or Count aggregator for limiting an OpsItem summary.</p>
+    #[serde(rename = "AggregatorType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aggregator_type: Option<String>,
+    /// <p>A nested aggregator for viewing counts of OpsItems.</p>
+    #[serde(rename = "Aggregators")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aggregators: Option<Vec<OpsAggregator>>,
+    /// <p>The name of an OpsItem attribute on which to limit the count of OpsItems.</p>
+    #[serde(rename = "AttributeName")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub attribute_name: Option<String>,
     /// <p>The aggregator filters.</p>
     #[serde(rename = "Filters")]
@@ -5642,12 +10530,125 @@ pub struct OpsFilter {
     /// <p>The type of filter.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<OpsFilterOperatorType>,
     /// <p>The filter value.</p>
     #[serde(rename = "Values")]
     pub values: Vec<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OpsFilterOperatorType {
+    /// `Equal`
+    Equal,
+    /// `NotEqual`
+    NotEqual,
+    /// `BeginWith`
+    BeginWith,
+    /// `LessThan`
+    LessThan,
+    /// `GreaterThan`
+    GreaterThan,
+    /// `Exists`
+    Exists,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl OpsFilterOperatorType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OpsFilterOperatorType::Equal => "Equal",
+            OpsFilterOperatorType::NotEqual => "NotEqual",
+            OpsFilterOperatorType::BeginWith => "BeginWith",
+            OpsFilterOperatorType::LessThan => "LessThan",
+            OpsFilterOperatorType::GreaterThan => "GreaterThan",
+            OpsFilterOperatorType::Exists => "Exists",
+            OpsFilterOperatorType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for OpsFilterOperatorType {
+    fn default() -> OpsFilterOperatorType {
+        OpsFilterOperatorType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for OpsFilterOperatorType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for OpsFilterOperatorType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for OpsFilterOperatorType {
+    fn from(value: &'a str) -> OpsFilterOperatorType {
+        match value {
+            "Equal" => OpsFilterOperatorType::Equal,
+            "NotEqual" => OpsFilterOperatorType::NotEqual,
+            "BeginWith" => OpsFilterOperatorType::BeginWith,
+            "LessThan" => OpsFilterOperatorType::LessThan,
+            "GreaterThan" => OpsFilterOperatorType::GreaterThan,
+            "Exists" => OpsFilterOperatorType::Exists,
+            _ => OpsFilterOperatorType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OpsFilterOperatorType {
+    fn from(value: String) -> OpsFilterOperatorType {
+        match value.as_str() {
+            "Equal" => OpsFilterOperatorType::Equal,
+            "NotEqual" => OpsFilterOperatorType::NotEqual,
+            "BeginWith" => OpsFilterOperatorType::BeginWith,
+            "LessThan" => OpsFilterOperatorType::LessThan,
+            "GreaterThan" => OpsFilterOperatorType::GreaterThan,
+            "Exists" => OpsFilterOperatorType::Exists,
+            _ => OpsFilterOperatorType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for OpsFilterOperatorType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<OpsFilterOperatorType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OpsFilterOperatorType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for OpsFilterOperatorType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OpsFilterOperatorType {
+    fn deserialize<D>(deserializer: D) -> Result<OpsFilterOperatorType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Operations engineers and IT professionals use OpsCenter to view, investigate, and remediate operational issues impacting the performance and health of their AWS resources. For more information, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/OpsCenter.html">AWS Systems Manager OpsCenter</a> in the <i>AWS Systems Manager User Guide</i>. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -5662,8 +10663,13 @@ pub struct OpsItem {
     pub created_by: Option<String>,
     /// <p>The date and time the OpsItem was created.</p>
     #[serde(rename = "CreatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time: Option<f64>,
+    pub created_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The OpsItem description.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -5674,8 +10680,13 @@ pub struct OpsItem {
     pub last_modified_by: Option<String>,
     /// <p>The date and time the OpsItem was last updated.</p>
     #[serde(rename = "LastModifiedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_time: Option<f64>,
+    pub last_modified_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The Amazon Resource Name (ARN) of an SNS topic where notifications are sent when this OpsItem is edited or changed.</p>
     #[serde(rename = "Notifications")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -5707,7 +10718,7 @@ pub struct OpsItem {
     /// <p>The OpsItem status. Status can be <code>Open</code>, <code>In Progress</code>, or <code>Resolved</code>. For more information, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/OpsCenter-working-with-OpsItems-editing-details.html">Editing OpsItem Details</a> in the <i>AWS Systems Manager User Guide</i>.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<OpsItemStatus>,
     /// <p>A short heading that describes the nature of the OpsItem and the impacted resource.</p>
     #[serde(rename = "Title")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -5718,13 +10729,106 @@ pub struct OpsItem {
     pub version: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OpsItemDataType {
+    /// `SearchableString`
+    SearchableString,
+    /// `String`
+    String,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl OpsItemDataType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OpsItemDataType::SearchableString => "SearchableString",
+            OpsItemDataType::String => "String",
+            OpsItemDataType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for OpsItemDataType {
+    fn default() -> OpsItemDataType {
+        OpsItemDataType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for OpsItemDataType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for OpsItemDataType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for OpsItemDataType {
+    fn from(value: &'a str) -> OpsItemDataType {
+        match value {
+            "SearchableString" => OpsItemDataType::SearchableString,
+            "String" => OpsItemDataType::String,
+            _ => OpsItemDataType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OpsItemDataType {
+    fn from(value: String) -> OpsItemDataType {
+        match value.as_str() {
+            "SearchableString" => OpsItemDataType::SearchableString,
+            "String" => OpsItemDataType::String,
+            _ => OpsItemDataType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for OpsItemDataType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<OpsItemDataType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OpsItemDataType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for OpsItemDataType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OpsItemDataType {
+    fn deserialize<D>(deserializer: D) -> Result<OpsItemDataType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>An object that defines the value of the key and its type in the OperationalData map.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct OpsItemDataValue {
     /// <p>The type of key-value pair. Valid types include <code>SearchableString</code> and <code>String</code>.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<OpsItemDataType>,
     /// <p>The value of the OperationalData key.</p>
     #[serde(rename = "Value")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -5737,56 +10841,576 @@ pub struct OpsItemDataValue {
 pub struct OpsItemFilter {
     /// <p>The name of the filter.</p>
     #[serde(rename = "Key")]
-    pub key: String,
+    pub key: OpsItemFilterKey,
     /// <p>The operator used by the filter call.</p>
     #[serde(rename = "Operator")]
-    pub operator: String,
+    pub operator: OpsItemFilterOperator,
     /// <p>The filter value.</p>
     #[serde(rename = "Values")]
     pub values: Vec<String>,
 }
 
-/// <p>A notification about the OpsItem.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct OpsItemNotification {
-    /// <p>The Amazon Resource Name (ARN) of an SNS topic where notifications are sent when this OpsItem is edited or changed.</p>
-    #[serde(rename = "Arn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub arn: Option<String>,
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OpsItemFilterKey {
+    /// `Status`
+    Status,
+    /// `CreatedBy`
+    CreatedBy,
+    /// `Source`
+    Source,
+    /// `Priority`
+    Priority,
+    /// `Title`
+    Title,
+    /// `OpsItemId`
+    OpsItemId,
+    /// `CreatedTime`
+    CreatedTime,
+    /// `LastModifiedTime`
+    LastModifiedTime,
+    /// `ActualStartTime`
+    ActualStartTime,
+    /// `ActualEndTime`
+    ActualEndTime,
+    /// `PlannedStartTime`
+    PlannedStartTime,
+    /// `PlannedEndTime`
+    PlannedEndTime,
+    /// `OperationalData`
+    OperationalData,
+    /// `OperationalDataKey`
+    OperationalDataKey,
+    /// `OperationalDataValue`
+    OperationalDataValue,
+    /// `ResourceId`
+    ResourceId,
+    /// `AutomationId`
+    AutomationId,
+    /// `Category`
+    Category,
+    /// `Severity`
+    Severity,
+    /// `OpsItemType`
+    OpsItemType,
+    /// `ChangeRequestByRequesterArn`
+    ChangeRequestByRequesterArn,
+    /// `ChangeRequestByRequesterName`
+    ChangeRequestByRequesterName,
+    /// `ChangeRequestByApproverArn`
+    ChangeRequestByApproverArn,
+    /// `ChangeRequestByApproverName`
+    ChangeRequestByApproverName,
+    /// `ChangeRequestByTemplate`
+    ChangeRequestByTemplate,
+    /// `ChangeRequestByTargetsResourceGroup`
+    ChangeRequestByTargetsResourceGroup,
+    /// `InsightByType`
+    InsightByType,
+    /// `AccountId`
+    AccountId,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl OpsItemFilterKey {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OpsItemFilterKey::Status => "Status",
+            OpsItemFilterKey::CreatedBy => "CreatedBy",
+            OpsItemFilterKey::Source => "Source",
+            OpsItemFilterKey::Priority => "Priority",
+            OpsItemFilterKey::Title => "Title",
+            OpsItemFilterKey::OpsItemId => "OpsItemId",
+            OpsItemFilterKey::CreatedTime => "CreatedTime",
+            OpsItemFilterKey::LastModifiedTime => "LastModifiedTime",
+            OpsItemFilterKey::ActualStartTime => "ActualStartTime",
+            OpsItemFilterKey::ActualEndTime => "ActualEndTime",
+            OpsItemFilterKey::PlannedStartTime => "PlannedStartTime",
+            OpsItemFilterKey::PlannedEndTime => "PlannedEndTime",
+            OpsItemFilterKey::OperationalData => "OperationalData",
+            OpsItemFilterKey::OperationalDataKey => "OperationalDataKey",
+            OpsItemFilterKey::OperationalDataValue => "OperationalDataValue",
+            OpsItemFilterKey::ResourceId => "ResourceId",
+            OpsItemFilterKey::AutomationId => "AutomationId",
+            OpsItemFilterKey::Category => "Category",
+            OpsItemFilterKey::Severity => "Severity",
+            OpsItemFilterKey::OpsItemType => "OpsItemType",
+            OpsItemFilterKey::ChangeRequestByRequesterArn => "ChangeRequestByRequesterArn",
+            OpsItemFilterKey::ChangeRequestByRequesterName => "ChangeRequestByRequesterName",
+            OpsItemFilterKey::ChangeRequestByApproverArn => "ChangeRequestByApproverArn",
+            OpsItemFilterKey::ChangeRequestByApproverName => "ChangeRequestByApproverName",
+            OpsItemFilterKey::ChangeRequestByTemplate => "ChangeRequestByTemplate",
+            OpsItemFilterKey::ChangeRequestByTargetsResourceGroup => {
+                "ChangeRequestByTargetsResourceGroup"
+            }
+            OpsItemFilterKey::InsightByType => "InsightByType",
+            OpsItemFilterKey::AccountId => "AccountId",
+            OpsItemFilterKey::Unknown(ref value) => value,
+        }
+    }
 }
 
-/// <p>A count of OpsItems.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct OpsItemSummary {
-    /// <p>A list of OpsItems by category.</p>
-    #[serde(rename = "Category")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub category: Option<String>,
-    /// <p>The Amazon Resource Name (ARN) of the IAM entity that created the OpsItem.</p>
-    #[serde(rename = "CreatedBy")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_by: Option<String>,
-    /// <p>The date and time the OpsItem was created.</p>
-    #[serde(rename = "CreatedTime")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time: Option<f64>,
-    /// <p>The Amazon Resource Name (ARN) of the IAM entity that created the OpsItem.</p>
-    #[serde(rename = "LastModifiedBy")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_by: Option<String>,
-    /// <p>The date and time the OpsItem was last updated.</p>
-    #[serde(rename = "LastModifiedTime")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_time: Option<f64>,
-    /// <p>Operational data is custom data that provides useful reference details about the OpsItem. </p>
-    #[serde(rename = "OperationalData")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub operational_data: Option<::std::collections::HashMap<String, OpsItemDataValue>>,
-    /// <p>The ID of the OpsItem.</p>
-    #[serde(rename = "OpsItemId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub ops_item_id: Option<String>,
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for OpsItemFilterKey {
+    fn default() -> OpsItemFilterKey {
+        OpsItemFilterKey::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for OpsItemFilterKey {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for OpsItemFilterKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for OpsItemFilterKey {
+    fn from(value: &'a str) -> OpsItemFilterKey {
+        match value {
+            "Status" => OpsItemFilterKey::Status,
+            "CreatedBy" => OpsItemFilterKey::CreatedBy,
+            "Source" => OpsItemFilterKey::Source,
+            "Priority" => OpsItemFilterKey::Priority,
+            "Title" => OpsItemFilterKey::Title,
+            "OpsItemId" => OpsItemFilterKey::OpsItemId,
+            "CreatedTime" => OpsItemFilterKey::CreatedTime,
+            "LastModifiedTime" => OpsItemFilterKey::LastModifiedTime,
+            "ActualStartTime" => OpsItemFilterKey::ActualStartTime,
+            "ActualEndTime" => OpsItemFilterKey::ActualEndTime,
+            "PlannedStartTime" => OpsItemFilterKey::PlannedStartTime,
+            "PlannedEndTime" => OpsItemFilterKey::PlannedEndTime,
+            "OperationalData" => OpsItemFilterKey::OperationalData,
+            "OperationalDataKey" => OpsItemFilterKey::OperationalDataKey,
+            "OperationalDataValue" => OpsItemFilterKey::OperationalDataValue,
+            "ResourceId" => OpsItemFilterKey::ResourceId,
+            "AutomationId" => OpsItemFilterKey::AutomationId,
+            "Category" => OpsItemFilterKey::Category,
+            "Severity" => OpsItemFilterKey::Severity,
+            "OpsItemType" => OpsItemFilterKey::OpsItemType,
+            "ChangeRequestByRequesterArn" => OpsItemFilterKey::ChangeRequestByRequesterArn,
+            "ChangeRequestByRequesterName" => OpsItemFilterKey::ChangeRequestByRequesterName,
+            "ChangeRequestByApproverArn" => OpsItemFilterKey::ChangeRequestByApproverArn,
+            "ChangeRequestByApproverName" => OpsItemFilterKey::ChangeRequestByApproverName,
+            "ChangeRequestByTemplate" => OpsItemFilterKey::ChangeRequestByTemplate,
+            "ChangeRequestByTargetsResourceGroup" => {
+                OpsItemFilterKey::ChangeRequestByTargetsResourceGroup
+            }
+            "InsightByType" => OpsItemFilterKey::InsightByType,
+            "AccountId" => OpsItemFilterKey::AccountId,
+            _ => OpsItemFilterKey::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OpsItemFilterKey {
+    fn from(value: String) -> OpsItemFilterKey {
+        match value.as_str() {
+            "Status" => OpsItemFilterKey::Status,
+            "CreatedBy" => OpsItemFilterKey::CreatedBy,
+            "Source" => OpsItemFilterKey::Source,
+            "Priority" => OpsItemFilterKey::Priority,
+            "Title" => OpsItemFilterKey::Title,
+            "OpsItemId" => OpsItemFilterKey::OpsItemId,
+            "CreatedTime" => OpsItemFilterKey::CreatedTime,
+            "LastModifiedTime" => OpsItemFilterKey::LastModifiedTime,
+            "ActualStartTime" => OpsItemFilterKey::ActualStartTime,
+            "ActualEndTime" => OpsItemFilterKey::ActualEndTime,
+            "PlannedStartTime" => OpsItemFilterKey::PlannedStartTime,
+            "PlannedEndTime" => OpsItemFilterKey::PlannedEndTime,
+            "OperationalData" => OpsItemFilterKey::OperationalData,
+            "OperationalDataKey" => OpsItemFilterKey::OperationalDataKey,
+            "OperationalDataValue" => OpsItemFilterKey::OperationalDataValue,
+            "ResourceId" => OpsItemFilterKey::ResourceId,
+            "AutomationId" => OpsItemFilterKey::AutomationId,
+            "Category" => OpsItemFilterKey::Category,
+            "Severity" => OpsItemFilterKey::Severity,
+            "OpsItemType" => OpsItemFilterKey::OpsItemType,
+            "ChangeRequestByRequesterArn" => OpsItemFilterKey::ChangeRequestByRequesterArn,
+            "ChangeRequestByRequesterName" => OpsItemFilterKey::ChangeRequestByRequesterName,
+            "ChangeRequestByApproverArn" => OpsItemFilterKey::ChangeRequestByApproverArn,
+            "ChangeRequestByApproverName" => OpsItemFilterKey::ChangeRequestByApproverName,
+            "ChangeRequestByTemplate" => OpsItemFilterKey::ChangeRequestByTemplate,
+            "ChangeRequestByTargetsResourceGroup" => {
+                OpsItemFilterKey::ChangeRequestByTargetsResourceGroup
+            }
+            "InsightByType" => OpsItemFilterKey::InsightByType,
+            "AccountId" => OpsItemFilterKey::AccountId,
+            _ => OpsItemFilterKey::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for OpsItemFilterKey {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<OpsItemFilterKey, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OpsItemFilterKey {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for OpsItemFilterKey {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OpsItemFilterKey {
+    fn deserialize<D>(deserializer: D) -> Result<OpsItemFilterKey, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OpsItemFilterOperator {
+    /// `Equal`
+    Equal,
+    /// `Contains`
+    Contains,
+    /// `GreaterThan`
+    GreaterThan,
+    /// `LessThan`
+    LessThan,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl OpsItemFilterOperator {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OpsItemFilterOperator::Equal => "Equal",
+            OpsItemFilterOperator::Contains => "Contains",
+            OpsItemFilterOperator::GreaterThan => "GreaterThan",
+            OpsItemFilterOperator::LessThan => "LessThan",
+            OpsItemFilterOperator::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for OpsItemFilterOperator {
+    fn default() -> OpsItemFilterOperator {
+        OpsItemFilterOperator::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for OpsItemFilterOperator {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for OpsItemFilterOperator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for OpsItemFilterOperator {
+    fn from(value: &'a str) -> OpsItemFilterOperator {
+        match value {
+            "Equal" => OpsItemFilterOperator::Equal,
+            "Contains" => OpsItemFilterOperator::Contains,
+            "GreaterThan" => OpsItemFilterOperator::GreaterThan,
+            "LessThan" => OpsItemFilterOperator::LessThan,
+            _ => OpsItemFilterOperator::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OpsItemFilterOperator {
+    fn from(value: String) -> OpsItemFilterOperator {
+        match value.as_str() {
+            "Equal" => OpsItemFilterOperator::Equal,
+            "Contains" => OpsItemFilterOperator::Contains,
+            "GreaterThan" => OpsItemFilterOperator::GreaterThan,
+            "LessThan" => OpsItemFilterOperator::LessThan,
+            _ => OpsItemFilterOperator::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for OpsItemFilterOperator {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<OpsItemFilterOperator, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OpsItemFilterOperator {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for OpsItemFilterOperator {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OpsItemFilterOperator {
+    fn deserialize<D>(deserializer: D) -> Result<OpsItemFilterOperator, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>A notification about the OpsItem.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OpsItemNotification {
+    /// <p>The Amazon Resource Name (ARN) of an SNS topic where notifications are sent when this OpsItem is edited or changed.</p>
+    #[serde(rename = "Arn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub arn: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OpsItemStatus {
+    /// `Open`
+    Open,
+    /// `InProgress`
+    InProgress,
+    /// `Resolved`
+    Resolved,
+    /// `Pending`
+    Pending,
+    /// `TimedOut`
+    TimedOut,
+    /// `Cancelling`
+    Cancelling,
+    /// `Cancelled`
+    Cancelled,
+    /// `Failed`
+    Failed,
+    /// `CompletedWithSuccess`
+    CompletedWithSuccess,
+    /// `CompletedWithFailure`
+    CompletedWithFailure,
+    /// `Scheduled`
+    Scheduled,
+    /// `RunbookInProgress`
+    RunbookInProgress,
+    /// `PendingChangeCalendarOverride`
+    PendingChangeCalendarOverride,
+    /// `ChangeCalendarOverrideApproved`
+    ChangeCalendarOverrideApproved,
+    /// `ChangeCalendarOverrideRejected`
+    ChangeCalendarOverrideRejected,
+    /// `PendingApproval`
+    PendingApproval,
+    /// `Approved`
+    Approved,
+    /// `Rejected`
+    Rejected,
+    /// `Closed`
+    Closed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl OpsItemStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OpsItemStatus::Open => "Open",
+            OpsItemStatus::InProgress => "InProgress",
+            OpsItemStatus::Resolved => "Resolved",
+            OpsItemStatus::Pending => "Pending",
+            OpsItemStatus::TimedOut => "TimedOut",
+            OpsItemStatus::Cancelling => "Cancelling",
+            OpsItemStatus::Cancelled => "Cancelled",
+            OpsItemStatus::Failed => "Failed",
+            OpsItemStatus::CompletedWithSuccess => "CompletedWithSuccess",
+            OpsItemStatus::CompletedWithFailure => "CompletedWithFailure",
+            OpsItemStatus::Scheduled => "Scheduled",
+            OpsItemStatus::RunbookInProgress => "RunbookInProgress",
+            OpsItemStatus::PendingChangeCalendarOverride => "PendingChangeCalendarOverride",
+            OpsItemStatus::ChangeCalendarOverrideApproved => "ChangeCalendarOverrideApproved",
+            OpsItemStatus::ChangeCalendarOverrideRejected => "ChangeCalendarOverrideRejected",
+            OpsItemStatus::PendingApproval => "PendingApproval",
+            OpsItemStatus::Approved => "Approved",
+            OpsItemStatus::Rejected => "Rejected",
+            OpsItemStatus::Closed => "Closed",
+            OpsItemStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for OpsItemStatus {
+    fn default() -> OpsItemStatus {
+        OpsItemStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for OpsItemStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for OpsItemStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for OpsItemStatus {
+    fn from(value: &'a str) -> OpsItemStatus {
+        match value {
+            "Open" => OpsItemStatus::Open,
+            "InProgress" => OpsItemStatus::InProgress,
+            "Resolved" => OpsItemStatus::Resolved,
+            "Pending" => OpsItemStatus::Pending,
+            "TimedOut" => OpsItemStatus::TimedOut,
+            "Cancelling" => OpsItemStatus::Cancelling,
+            "Cancelled" => OpsItemStatus::Cancelled,
+            "Failed" => OpsItemStatus::Failed,
+            "CompletedWithSuccess" => OpsItemStatus::CompletedWithSuccess,
+            "CompletedWithFailure" => OpsItemStatus::CompletedWithFailure,
+            "Scheduled" => OpsItemStatus::Scheduled,
+            "RunbookInProgress" => OpsItemStatus::RunbookInProgress,
+            "PendingChangeCalendarOverride" => OpsItemStatus::PendingChangeCalendarOverride,
+            "ChangeCalendarOverrideApproved" => OpsItemStatus::ChangeCalendarOverrideApproved,
+            "ChangeCalendarOverrideRejected" => OpsItemStatus::ChangeCalendarOverrideRejected,
+            "PendingApproval" => OpsItemStatus::PendingApproval,
+            "Approved" => OpsItemStatus::Approved,
+            "Rejected" => OpsItemStatus::Rejected,
+            "Closed" => OpsItemStatus::Closed,
+            _ => OpsItemStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OpsItemStatus {
+    fn from(value: String) -> OpsItemStatus {
+        match value.as_str() {
+            "Open" => OpsItemStatus::Open,
+            "InProgress" => OpsItemStatus::InProgress,
+            "Resolved" => OpsItemStatus::Resolved,
+            "Pending" => OpsItemStatus::Pending,
+            "TimedOut" => OpsItemStatus::TimedOut,
+            "Cancelling" => OpsItemStatus::Cancelling,
+            "Cancelled" => OpsItemStatus::Cancelled,
+            "Failed" => OpsItemStatus::Failed,
+            "CompletedWithSuccess" => OpsItemStatus::CompletedWithSuccess,
+            "CompletedWithFailure" => OpsItemStatus::CompletedWithFailure,
+            "Scheduled" => OpsItemStatus::Scheduled,
+            "RunbookInProgress" => OpsItemStatus::RunbookInProgress,
+            "PendingChangeCalendarOverride" => OpsItemStatus::PendingChangeCalendarOverride,
+            "ChangeCalendarOverrideApproved" => OpsItemStatus::ChangeCalendarOverrideApproved,
+            "ChangeCalendarOverrideRejected" => OpsItemStatus::ChangeCalendarOverrideRejected,
+            "PendingApproval" => OpsItemStatus::PendingApproval,
+            "Approved" => OpsItemStatus::Approved,
+            "Rejected" => OpsItemStatus::Rejected,
+            "Closed" => OpsItemStatus::Closed,
+            _ => OpsItemStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for OpsItemStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<OpsItemStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OpsItemStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for OpsItemStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OpsItemStatus {
+    fn deserialize<D>(deserializer: D) -> Result<OpsItemStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>A count of OpsItems.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct OpsItemSummary {
+    /// <p>A list of OpsItems by category.</p>
+    #[serde(rename = "Category")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+    /// <p>The Amazon Resource Name (ARN) of the IAM entity that created the OpsItem.</p>
+    #[serde(rename = "CreatedBy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_by: Option<String>,
+    /// <p>The date and time the OpsItem was created.</p>
+    #[serde(rename = "CreatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_time: Option<::chrono::DateTime<::chrono::Utc>>,
+    /// <p>The Amazon Resource Name (ARN) of the IAM entity that created the OpsItem.</p>
+    #[serde(rename = "LastModifiedBy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_modified_by: Option<String>,
+    /// <p>The date and time the OpsItem was last updated.</p>
+    #[serde(rename = "LastModifiedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_modified_time: Option<::chrono::DateTime<::chrono::Utc>>,
+    /// <p>Operational data is custom data that provides useful reference details about the OpsItem. </p>
+    #[serde(rename = "OperationalData")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub operational_data: Option<::std::collections::HashMap<String, OpsItemDataValue>>,
+    /// <p>The ID of the OpsItem.</p>
+    #[serde(rename = "OpsItemId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ops_item_id: Option<String>,
     /// <p>The importance of this OpsItem in relation to other OpsItems in the system.</p>
     #[serde(rename = "Priority")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -5802,7 +11426,7 @@ pub struct OpsItemSummary {
     /// <p>The OpsItem status. Status can be <code>Open</code>, <code>In Progress</code>, or <code>Resolved</code>.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<OpsItemStatus>,
     /// <p>A short heading that describes the nature of the OpsItem and the impacted resource.</p>
     #[serde(rename = "Title")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -5842,8 +11466,13 @@ pub struct Parameter {
     pub arn: Option<String>,
     /// <p>Date the parameter was last changed or updated and the parameter version was created.</p>
     #[serde(rename = "LastModifiedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_date: Option<f64>,
+    pub last_modified_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the parameter.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -5859,7 +11488,7 @@ pub struct Parameter {
     /// <p>The type of parameter. Valid values include the following: String, String list, Secure string.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<ParameterType>,
     /// <p>The parameter value.</p>
     #[serde(rename = "Value")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -5892,8 +11521,13 @@ pub struct ParameterHistory {
     pub labels: Option<Vec<String>>,
     /// <p>Date the parameter was last changed or updated.</p>
     #[serde(rename = "LastModifiedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_date: Option<f64>,
+    pub last_modified_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Amazon Resource Name (ARN) of the AWS user who last changed the parameter.</p>
     #[serde(rename = "LastModifiedUser")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -5909,11 +11543,11 @@ pub struct ParameterHistory {
     /// <p>The parameter tier.</p>
     #[serde(rename = "Tier")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub tier: Option<String>,
+    pub tier: Option<ParameterTier>,
     /// <p>The type of parameter used.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<ParameterType>,
     /// <p>The parameter value.</p>
     #[serde(rename = "Value")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -5960,8 +11594,13 @@ pub struct ParameterMetadata {
     pub key_id: Option<String>,
     /// <p>Date the parameter was last changed or updated.</p>
     #[serde(rename = "LastModifiedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_date: Option<f64>,
+    pub last_modified_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Amazon Resource Name (ARN) of the AWS user who last changed the parameter.</p>
     #[serde(rename = "LastModifiedUser")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -5977,11 +11616,11 @@ pub struct ParameterMetadata {
     /// <p>The parameter tier.</p>
     #[serde(rename = "Tier")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub tier: Option<String>,
+    pub tier: Option<ParameterTier>,
     /// <p>The type of parameter. Valid parameter types include the following: String, String list, Secure string.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<ParameterType>,
     /// <p>The parameter version.</p>
     #[serde(rename = "Version")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -6005,35 +11644,329 @@ pub struct ParameterStringFilter {
     pub values: Option<Vec<String>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ParameterTier {
+    /// `Standard`
+    Standard,
+    /// `Advanced`
+    Advanced,
+    /// `Intelligent-Tiering`
+    IntelligentTiering,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ParameterTier {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ParameterTier::Standard => "Standard",
+            ParameterTier::Advanced => "Advanced",
+            ParameterTier::IntelligentTiering => "Intelligent-Tiering",
+            ParameterTier::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ParameterTier {
+    fn default() -> ParameterTier {
+        ParameterTier::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ParameterTier {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ParameterTier {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ParameterTier {
+    fn from(value: &'a str) -> ParameterTier {
+        match value {
+            "Standard" => ParameterTier::Standard,
+            "Advanced" => ParameterTier::Advanced,
+            "Intelligent-Tiering" => ParameterTier::IntelligentTiering,
+            _ => ParameterTier::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ParameterTier {
+    fn from(value: String) -> ParameterTier {
+        match value.as_str() {
+            "Standard" => ParameterTier::Standard,
+            "Advanced" => ParameterTier::Advanced,
+            "Intelligent-Tiering" => ParameterTier::IntelligentTiering,
+            _ => ParameterTier::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ParameterTier {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ParameterTier, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ParameterTier {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ParameterTier {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ParameterTier {
+    fn deserialize<D>(deserializer: D) -> Result<ParameterTier, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ParameterType {
+    /// `String`
+    String,
+    /// `StringList`
+    StringList,
+    /// `SecureString`
+    SecureString,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ParameterType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ParameterType::String => "String",
+            ParameterType::StringList => "StringList",
+            ParameterType::SecureString => "SecureString",
+            ParameterType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ParameterType {
+    fn default() -> ParameterType {
+        ParameterType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ParameterType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ParameterType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ParameterType {
+    fn from(value: &'a str) -> ParameterType {
+        match value {
+            "String" => ParameterType::String,
+            "StringList" => ParameterType::StringList,
+            "SecureString" => ParameterType::SecureString,
+            _ => ParameterType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ParameterType {
+    fn from(value: String) -> ParameterType {
+        match value.as_str() {
+            "String" => ParameterType::String,
+            "StringList" => ParameterType::StringList,
+            "SecureString" => ParameterType::SecureString,
+            _ => ParameterType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ParameterType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ParameterType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ParameterType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ParameterType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ParameterType {
+    fn deserialize<D>(deserializer: D) -> Result<ParameterType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>This data type is deprecated. Instead, use <a>ParameterStringFilter</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ParametersFilter {
     /// <p>The name of the filter.</p>
     #[serde(rename = "Key")]
-    pub key: String,
+    pub key: ParametersFilterKey,
     /// <p>The filter values.</p>
     #[serde(rename = "Values")]
     pub values: Vec<String>,
 }
 
-/// <p>Represents metadata about a patch.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct Patch {
-    /// <p>The classification of the patch (for example, SecurityUpdates, Updates, CriticalUpdates).</p>
-    #[serde(rename = "Classification")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub classification: Option<String>,
-    /// <p>The URL where more information can be obtained about the patch.</p>
-    #[serde(rename = "ContentUrl")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub content_url: Option<String>,
-    /// <p>The description of the patch.</p>
-    #[serde(rename = "Description")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub description: Option<String>,
-    /// <p>The ID of the patch (this is different than the Microsoft Knowledge Base ID).</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ParametersFilterKey {
+    /// `Name`
+    Name,
+    /// `Type`
+    Type,
+    /// `KeyId`
+    KeyId,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ParametersFilterKey {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ParametersFilterKey::Name => "Name",
+            ParametersFilterKey::Type => "Type",
+            ParametersFilterKey::KeyId => "KeyId",
+            ParametersFilterKey::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ParametersFilterKey {
+    fn default() -> ParametersFilterKey {
+        ParametersFilterKey::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ParametersFilterKey {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ParametersFilterKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ParametersFilterKey {
+    fn from(value: &'a str) -> ParametersFilterKey {
+        match value {
+            "Name" => ParametersFilterKey::Name,
+            "Type" => ParametersFilterKey::Type,
+            "KeyId" => ParametersFilterKey::KeyId,
+            _ => ParametersFilterKey::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ParametersFilterKey {
+    fn from(value: String) -> ParametersFilterKey {
+        match value.as_str() {
+            "Name" => ParametersFilterKey::Name,
+            "Type" => ParametersFilterKey::Type,
+            "KeyId" => ParametersFilterKey::KeyId,
+            _ => ParametersFilterKey::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ParametersFilterKey {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ParametersFilterKey, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ParametersFilterKey {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ParametersFilterKey {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ParametersFilterKey {
+    fn deserialize<D>(deserializer: D) -> Result<ParametersFilterKey, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Represents metadata about a patch.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct Patch {
+    /// <p>The classification of the patch (for example, SecurityUpdates, Updates, CriticalUpdates).</p>
+    #[serde(rename = "Classification")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub classification: Option<String>,
+    /// <p>The URL where more information can be obtained about the patch.</p>
+    #[serde(rename = "ContentUrl")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_url: Option<String>,
+    /// <p>The description of the patch.</p>
+    #[serde(rename = "Description")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// <p>The ID of the patch (this is different than the Microsoft Knowledge Base ID).</p>
     #[serde(rename = "Id")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
@@ -6063,8 +11996,13 @@ pub struct Patch {
     pub product_family: Option<String>,
     /// <p>The date the patch was released.</p>
     #[serde(rename = "ReleaseDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub release_date: Option<f64>,
+    pub release_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The title of the patch.</p>
     #[serde(rename = "Title")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -6075,6 +12013,99 @@ pub struct Patch {
     pub vendor: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PatchAction {
+    /// `ALLOW_AS_DEPENDENCY`
+    AllowAsDependency,
+    /// `BLOCK`
+    Block,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PatchAction {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PatchAction::AllowAsDependency => "ALLOW_AS_DEPENDENCY",
+            PatchAction::Block => "BLOCK",
+            PatchAction::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PatchAction {
+    fn default() -> PatchAction {
+        PatchAction::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PatchAction {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PatchAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PatchAction {
+    fn from(value: &'a str) -> PatchAction {
+        match value {
+            "ALLOW_AS_DEPENDENCY" => PatchAction::AllowAsDependency,
+            "BLOCK" => PatchAction::Block,
+            _ => PatchAction::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PatchAction {
+    fn from(value: String) -> PatchAction {
+        match value.as_str() {
+            "ALLOW_AS_DEPENDENCY" => PatchAction::AllowAsDependency,
+            "BLOCK" => PatchAction::Block,
+            _ => PatchAction::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PatchAction {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PatchAction, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PatchAction {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PatchAction {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PatchAction {
+    fn deserialize<D>(deserializer: D) -> Result<PatchAction, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Defines the basic information about a patch baseline.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -6098,7 +12129,7 @@ pub struct PatchBaselineIdentity {
     /// <p>Defines the operating system the patch baseline applies to. The Default value is WINDOWS. </p>
     #[serde(rename = "OperatingSystem")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub operating_system: Option<String>,
+    pub operating_system: Option<OperatingSystem>,
 }
 
 /// <p>Information about the state of a patch on a particular instance as it relates to the patch baseline used to patch the instance.</p>
@@ -6110,7 +12141,12 @@ pub struct PatchComplianceData {
     pub classification: String,
     /// <p>The date/time the patch was installed on the instance. Note that not all operating systems provide this level of information.</p>
     #[serde(rename = "InstalledTime")]
-    pub installed_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub installed_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The operating system-specific ID of the patch.</p>
     #[serde(rename = "KBId")]
     pub kb_id: String,
@@ -6119,18 +12155,352 @@ pub struct PatchComplianceData {
     pub severity: String,
     /// <p>The state of the patch on the instance, such as INSTALLED or FAILED.</p> <p>For descriptions of each patch state, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/sysman-compliance-about.html#sysman-compliance-monitor-patch">About Patch Compliance</a> in the <i>AWS Systems Manager User Guide</i>.</p>
     #[serde(rename = "State")]
-    pub state: String,
+    pub state: PatchComplianceDataState,
     /// <p>The title of the patch.</p>
     #[serde(rename = "Title")]
     pub title: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PatchComplianceDataState {
+    /// `INSTALLED`
+    Installed,
+    /// `INSTALLED_OTHER`
+    InstalledOther,
+    /// `INSTALLED_PENDING_REBOOT`
+    InstalledPendingReboot,
+    /// `INSTALLED_REJECTED`
+    InstalledRejected,
+    /// `MISSING`
+    Missing,
+    /// `NOT_APPLICABLE`
+    NotApplicable,
+    /// `FAILED`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PatchComplianceDataState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PatchComplianceDataState::Installed => "INSTALLED",
+            PatchComplianceDataState::InstalledOther => "INSTALLED_OTHER",
+            PatchComplianceDataState::InstalledPendingReboot => "INSTALLED_PENDING_REBOOT",
+            PatchComplianceDataState::InstalledRejected => "INSTALLED_REJECTED",
+            PatchComplianceDataState::Missing => "MISSING",
+            PatchComplianceDataState::NotApplicable => "NOT_APPLICABLE",
+            PatchComplianceDataState::Failed => "FAILED",
+            PatchComplianceDataState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PatchComplianceDataState {
+    fn default() -> PatchComplianceDataState {
+        PatchComplianceDataState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PatchComplianceDataState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PatchComplianceDataState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PatchComplianceDataState {
+    fn from(value: &'a str) -> PatchComplianceDataState {
+        match value {
+            "INSTALLED" => PatchComplianceDataState::Installed,
+            "INSTALLED_OTHER" => PatchComplianceDataState::InstalledOther,
+            "INSTALLED_PENDING_REBOOT" => PatchComplianceDataState::InstalledPendingReboot,
+            "INSTALLED_REJECTED" => PatchComplianceDataState::InstalledRejected,
+            "MISSING" => PatchComplianceDataState::Missing,
+            "NOT_APPLICABLE" => PatchComplianceDataState::NotApplicable,
+            "FAILED" => PatchComplianceDataState::Failed,
+            _ => PatchComplianceDataState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PatchComplianceDataState {
+    fn from(value: String) -> PatchComplianceDataState {
+        match value.as_str() {
+            "INSTALLED" => PatchComplianceDataState::Installed,
+            "INSTALLED_OTHER" => PatchComplianceDataState::InstalledOther,
+            "INSTALLED_PENDING_REBOOT" => PatchComplianceDataState::InstalledPendingReboot,
+            "INSTALLED_REJECTED" => PatchComplianceDataState::InstalledRejected,
+            "MISSING" => PatchComplianceDataState::Missing,
+            "NOT_APPLICABLE" => PatchComplianceDataState::NotApplicable,
+            "FAILED" => PatchComplianceDataState::Failed,
+            _ => PatchComplianceDataState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PatchComplianceDataState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PatchComplianceDataState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PatchComplianceDataState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PatchComplianceDataState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PatchComplianceDataState {
+    fn deserialize<D>(deserializer: D) -> Result<PatchComplianceDataState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PatchComplianceLevel {
+    /// `CRITICAL`
+    Critical,
+    /// `HIGH`
+    High,
+    /// `MEDIUM`
+    Medium,
+    /// `LOW`
+    Low,
+    /// `INFORMATIONAL`
+    Informational,
+    /// `UNSPECIFIED`
+    Unspecified,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PatchComplianceLevel {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PatchComplianceLevel::Critical => "CRITICAL",
+            PatchComplianceLevel::High => "HIGH",
+            PatchComplianceLevel::Medium => "MEDIUM",
+            PatchComplianceLevel::Low => "LOW",
+            PatchComplianceLevel::Informational => "INFORMATIONAL",
+            PatchComplianceLevel::Unspecified => "UNSPECIFIED",
+            PatchComplianceLevel::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PatchComplianceLevel {
+    fn default() -> PatchComplianceLevel {
+        PatchComplianceLevel::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PatchComplianceLevel {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PatchComplianceLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PatchComplianceLevel {
+    fn from(value: &'a str) -> PatchComplianceLevel {
+        match value {
+            "CRITICAL" => PatchComplianceLevel::Critical,
+            "HIGH" => PatchComplianceLevel::High,
+            "MEDIUM" => PatchComplianceLevel::Medium,
+            "LOW" => PatchComplianceLevel::Low,
+            "INFORMATIONAL" => PatchComplianceLevel::Informational,
+            "UNSPECIFIED" => PatchComplianceLevel::Unspecified,
+            _ => PatchComplianceLevel::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PatchComplianceLevel {
+    fn from(value: String) -> PatchComplianceLevel {
+        match value.as_str() {
+            "CRITICAL" => PatchComplianceLevel::Critical,
+            "HIGH" => PatchComplianceLevel::High,
+            "MEDIUM" => PatchComplianceLevel::Medium,
+            "LOW" => PatchComplianceLevel::Low,
+            "INFORMATIONAL" => PatchComplianceLevel::Informational,
+            "UNSPECIFIED" => PatchComplianceLevel::Unspecified,
+            _ => PatchComplianceLevel::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PatchComplianceLevel {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PatchComplianceLevel, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PatchComplianceLevel {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PatchComplianceLevel {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PatchComplianceLevel {
+    fn deserialize<D>(deserializer: D) -> Result<PatchComplianceLevel, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PatchDeploymentStatus {
+    /// `APPROVED`
+    Approved,
+    /// `PENDING_APPROVAL`
+    PendingApproval,
+    /// `EXPLICIT_APPROVED`
+    ExplicitApproved,
+    /// `EXPLICIT_REJECTED`
+    ExplicitRejected,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PatchDeploymentStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PatchDeploymentStatus::Approved => "APPROVED",
+            PatchDeploymentStatus::PendingApproval => "PENDING_APPROVAL",
+            PatchDeploymentStatus::ExplicitApproved => "EXPLICIT_APPROVED",
+            PatchDeploymentStatus::ExplicitRejected => "EXPLICIT_REJECTED",
+            PatchDeploymentStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PatchDeploymentStatus {
+    fn default() -> PatchDeploymentStatus {
+        PatchDeploymentStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PatchDeploymentStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PatchDeploymentStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PatchDeploymentStatus {
+    fn from(value: &'a str) -> PatchDeploymentStatus {
+        match value {
+            "APPROVED" => PatchDeploymentStatus::Approved,
+            "PENDING_APPROVAL" => PatchDeploymentStatus::PendingApproval,
+            "EXPLICIT_APPROVED" => PatchDeploymentStatus::ExplicitApproved,
+            "EXPLICIT_REJECTED" => PatchDeploymentStatus::ExplicitRejected,
+            _ => PatchDeploymentStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PatchDeploymentStatus {
+    fn from(value: String) -> PatchDeploymentStatus {
+        match value.as_str() {
+            "APPROVED" => PatchDeploymentStatus::Approved,
+            "PENDING_APPROVAL" => PatchDeploymentStatus::PendingApproval,
+            "EXPLICIT_APPROVED" => PatchDeploymentStatus::ExplicitApproved,
+            "EXPLICIT_REJECTED" => PatchDeploymentStatus::ExplicitRejected,
+            _ => PatchDeploymentStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PatchDeploymentStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PatchDeploymentStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PatchDeploymentStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PatchDeploymentStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PatchDeploymentStatus {
+    fn deserialize<D>(deserializer: D) -> Result<PatchDeploymentStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p> Defines which patches should be included in a patch baseline.</p> <p>A patch filter consists of a key and a set of values. The filter key is a patch property. For example, the available filter keys for WINDOWS are PATCH_SET, PRODUCT, PRODUCT_FAMILY, CLASSIFICATION, and MSRC_SEVERITY. The filter values define a matching criterion for the patch property indicated by the key. For example, if the filter key is PRODUCT and the filter values are ["Office 2013", "Office 2016"], then the filter accepts all patches where product name is either "Office 2013" or "Office 2016". The filter values can be exact values for the patch property given as a key, or a wildcard (*), which matches all values.</p> <p>You can view lists of valid values for the patch properties by running the <code>DescribePatchProperties</code> command. For information about which patch properties can be used with each major operating system, see <a>DescribePatchProperties</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PatchFilter {
     /// <p>The key for the filter.</p> <p>Run the <a>DescribePatchProperties</a> command to view lists of valid keys for each operating system type.</p>
     #[serde(rename = "Key")]
-    pub key: String,
+    pub key: PatchFilterKey,
     /// <p>The value for the filter key.</p> <p>Run the <a>DescribePatchProperties</a> command to view lists of valid values for each key based on operating system type.</p>
     #[serde(rename = "Values")]
     pub values: Vec<String>,
@@ -6144,44 +12514,428 @@ pub struct PatchFilterGroup {
     pub patch_filters: Vec<PatchFilter>,
 }
 
-/// <p>The mapping between a patch group and the patch baseline the patch group is registered with.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct PatchGroupPatchBaselineMapping {
-    /// <p>The patch baseline the patch group is registered with.</p>
-    #[serde(rename = "BaselineIdentity")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub baseline_identity: Option<PatchBaselineIdentity>,
-    /// <p>The name of the patch group registered with the patch baseline.</p>
-    #[serde(rename = "PatchGroup")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub patch_group: Option<String>,
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PatchFilterKey {
+    /// `ARCH`
+    Arch,
+    /// `ADVISORY_ID`
+    AdvisoryId,
+    /// `BUGZILLA_ID`
+    BugzillaId,
+    /// `PATCH_SET`
+    PatchSet,
+    /// `PRODUCT`
+    Product,
+    /// `PRODUCT_FAMILY`
+    ProductFamily,
+    /// `CLASSIFICATION`
+    Classification,
+    /// `CVE_ID`
+    CveId,
+    /// `EPOCH`
+    Epoch,
+    /// `MSRC_SEVERITY`
+    MsrcSeverity,
+    /// `NAME`
+    Name,
+    /// `PATCH_ID`
+    PatchId,
+    /// `SECTION`
+    Section,
+    /// `PRIORITY`
+    Priority,
+    /// `REPOSITORY`
+    Repository,
+    /// `RELEASE`
+    Release,
+    /// `SEVERITY`
+    Severity,
+    /// `SECURITY`
+    Security,
+    /// `VERSION`
+    Version,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PatchFilterKey {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PatchFilterKey::Arch => "ARCH",
+            PatchFilterKey::AdvisoryId => "ADVISORY_ID",
+            PatchFilterKey::BugzillaId => "BUGZILLA_ID",
+            PatchFilterKey::PatchSet => "PATCH_SET",
+            PatchFilterKey::Product => "PRODUCT",
+            PatchFilterKey::ProductFamily => "PRODUCT_FAMILY",
+            PatchFilterKey::Classification => "CLASSIFICATION",
+            PatchFilterKey::CveId => "CVE_ID",
+            PatchFilterKey::Epoch => "EPOCH",
+            PatchFilterKey::MsrcSeverity => "MSRC_SEVERITY",
+            PatchFilterKey::Name => "NAME",
+            PatchFilterKey::PatchId => "PATCH_ID",
+            PatchFilterKey::Section => "SECTION",
+            PatchFilterKey::Priority => "PRIORITY",
+            PatchFilterKey::Repository => "REPOSITORY",
+            PatchFilterKey::Release => "RELEASE",
+            PatchFilterKey::Severity => "SEVERITY",
+            PatchFilterKey::Security => "SECURITY",
+            PatchFilterKey::Version => "VERSION",
+            PatchFilterKey::Unknown(ref value) => value,
+        }
+    }
 }
 
-/// <p>Defines a filter used in Patch Manager APIs.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct PatchOrchestratorFilter {
-    /// <p>The key for the filter.</p>
-    #[serde(rename = "Key")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub key: Option<String>,
-    /// <p>The value for the filter.</p>
-    #[serde(rename = "Values")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub values: Option<Vec<String>>,
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PatchFilterKey {
+    fn default() -> PatchFilterKey {
+        PatchFilterKey::Unknown(String::new())
+    }
 }
 
-/// <p>Defines an approval rule for a patch baseline.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct PatchRule {
-    /// <p>The number of days after the release date of each patch matched by the rule that the patch is marked as approved in the patch baseline. For example, a value of <code>7</code> means that patches are approved seven days after they are released. </p>
-    #[serde(rename = "ApproveAfterDays")]
-    pub approve_after_days: i64,
-    /// <p>A compliance severity level for all approved patches in a patch baseline. Valid compliance severity levels include the following: Unspecified, Critical, High, Medium, Low, and Informational.</p>
-    #[serde(rename = "ComplianceLevel")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub compliance_level: Option<String>,
+impl AsRef<str> for PatchFilterKey {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PatchFilterKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PatchFilterKey {
+    fn from(value: &'a str) -> PatchFilterKey {
+        match value {
+            "ARCH" => PatchFilterKey::Arch,
+            "ADVISORY_ID" => PatchFilterKey::AdvisoryId,
+            "BUGZILLA_ID" => PatchFilterKey::BugzillaId,
+            "PATCH_SET" => PatchFilterKey::PatchSet,
+            "PRODUCT" => PatchFilterKey::Product,
+            "PRODUCT_FAMILY" => PatchFilterKey::ProductFamily,
+            "CLASSIFICATION" => PatchFilterKey::Classification,
+            "CVE_ID" => PatchFilterKey::CveId,
+            "EPOCH" => PatchFilterKey::Epoch,
+            "MSRC_SEVERITY" => PatchFilterKey::MsrcSeverity,
+            "NAME" => PatchFilterKey::Name,
+            "PATCH_ID" => PatchFilterKey::PatchId,
+            "SECTION" => PatchFilterKey::Section,
+            "PRIORITY" => PatchFilterKey::Priority,
+            "REPOSITORY" => PatchFilterKey::Repository,
+            "RELEASE" => PatchFilterKey::Release,
+            "SEVERITY" => PatchFilterKey::Severity,
+            "SECURITY" => PatchFilterKey::Security,
+            "VERSION" => PatchFilterKey::Version,
+            _ => PatchFilterKey::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PatchFilterKey {
+    fn from(value: String) -> PatchFilterKey {
+        match value.as_str() {
+            "ARCH" => PatchFilterKey::Arch,
+            "ADVISORY_ID" => PatchFilterKey::AdvisoryId,
+            "BUGZILLA_ID" => PatchFilterKey::BugzillaId,
+            "PATCH_SET" => PatchFilterKey::PatchSet,
+            "PRODUCT" => PatchFilterKey::Product,
+            "PRODUCT_FAMILY" => PatchFilterKey::ProductFamily,
+            "CLASSIFICATION" => PatchFilterKey::Classification,
+            "CVE_ID" => PatchFilterKey::CveId,
+            "EPOCH" => PatchFilterKey::Epoch,
+            "MSRC_SEVERITY" => PatchFilterKey::MsrcSeverity,
+            "NAME" => PatchFilterKey::Name,
+            "PATCH_ID" => PatchFilterKey::PatchId,
+            "SECTION" => PatchFilterKey::Section,
+            "PRIORITY" => PatchFilterKey::Priority,
+            "REPOSITORY" => PatchFilterKey::Repository,
+            "RELEASE" => PatchFilterKey::Release,
+            "SEVERITY" => PatchFilterKey::Severity,
+            "SECURITY" => PatchFilterKey::Security,
+            "VERSION" => PatchFilterKey::Version,
+            _ => PatchFilterKey::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PatchFilterKey {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PatchFilterKey, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PatchFilterKey {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PatchFilterKey {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PatchFilterKey {
+    fn deserialize<D>(deserializer: D) -> Result<PatchFilterKey, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>The mapping between a patch group and the patch baseline the patch group is registered with.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct PatchGroupPatchBaselineMapping {
+    /// <p>The patch baseline the patch group is registered with.</p>
+    #[serde(rename = "BaselineIdentity")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub baseline_identity: Option<PatchBaselineIdentity>,
+    /// <p>The name of the patch group registered with the patch baseline.</p>
+    #[serde(rename = "PatchGroup")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub patch_group: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PatchOperationType {
+    /// `Scan`
+    Scan,
+    /// `Install`
+    Install,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PatchOperationType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PatchOperationType::Scan => "Scan",
+            PatchOperationType::Install => "Install",
+            PatchOperationType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PatchOperationType {
+    fn default() -> PatchOperationType {
+        PatchOperationType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PatchOperationType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PatchOperationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PatchOperationType {
+    fn from(value: &'a str) -> PatchOperationType {
+        match value {
+            "Scan" => PatchOperationType::Scan,
+            "Install" => PatchOperationType::Install,
+            _ => PatchOperationType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PatchOperationType {
+    fn from(value: String) -> PatchOperationType {
+        match value.as_str() {
+            "Scan" => PatchOperationType::Scan,
+            "Install" => PatchOperationType::Install,
+            _ => PatchOperationType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PatchOperationType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PatchOperationType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PatchOperationType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PatchOperationType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PatchOperationType {
+    fn deserialize<D>(deserializer: D) -> Result<PatchOperationType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Defines a filter used in Patch Manager APIs.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct PatchOrchestratorFilter {
+    /// <p>The key for the filter.</p>
+    #[serde(rename = "Key")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+    /// <p>The value for the filter.</p>
+    #[serde(rename = "Values")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub values: Option<Vec<String>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PatchProperty {
+    /// `PRODUCT`
+    Product,
+    /// `PRODUCT_FAMILY`
+    ProductFamily,
+    /// `CLASSIFICATION`
+    Classification,
+    /// `MSRC_SEVERITY`
+    MsrcSeverity,
+    /// `PRIORITY`
+    Priority,
+    /// `SEVERITY`
+    Severity,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PatchProperty {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PatchProperty::Product => "PRODUCT",
+            PatchProperty::ProductFamily => "PRODUCT_FAMILY",
+            PatchProperty::Classification => "CLASSIFICATION",
+            PatchProperty::MsrcSeverity => "MSRC_SEVERITY",
+            PatchProperty::Priority => "PRIORITY",
+            PatchProperty::Severity => "SEVERITY",
+            PatchProperty::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PatchProperty {
+    fn default() -> PatchProperty {
+        PatchProperty::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PatchProperty {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PatchProperty {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PatchProperty {
+    fn from(value: &'a str) -> PatchProperty {
+        match value {
+            "PRODUCT" => PatchProperty::Product,
+            "PRODUCT_FAMILY" => PatchProperty::ProductFamily,
+            "CLASSIFICATION" => PatchProperty::Classification,
+            "MSRC_SEVERITY" => PatchProperty::MsrcSeverity,
+            "PRIORITY" => PatchProperty::Priority,
+            "SEVERITY" => PatchProperty::Severity,
+            _ => PatchProperty::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PatchProperty {
+    fn from(value: String) -> PatchProperty {
+        match value.as_str() {
+            "PRODUCT" => PatchProperty::Product,
+            "PRODUCT_FAMILY" => PatchProperty::ProductFamily,
+            "CLASSIFICATION" => PatchProperty::Classification,
+            "MSRC_SEVERITY" => PatchProperty::MsrcSeverity,
+            "PRIORITY" => PatchProperty::Priority,
+            "SEVERITY" => PatchProperty::Severity,
+            _ => PatchProperty::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PatchProperty {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PatchProperty, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PatchProperty {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PatchProperty {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PatchProperty {
+    fn deserialize<D>(deserializer: D) -> Result<PatchProperty, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Defines an approval rule for a patch baseline.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PatchRule {
+    /// <p>The number of days after the release date of each patch matched by the rule that the patch is marked as approved in the patch baseline. For example, a value of <code>7</code> means that patches are approved seven days after they are released. </p>
+    #[serde(rename = "ApproveAfterDays")]
+    pub approve_after_days: i64,
+    /// <p>A compliance severity level for all approved patches in a patch baseline. Valid compliance severity levels include the following: Unspecified, Critical, High, Medium, Low, and Informational.</p>
+    #[serde(rename = "ComplianceLevel")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compliance_level: Option<PatchComplianceLevel>,
     /// <p>For instances identified by the approval rule filters, enables a patch baseline to apply non-security updates available in the specified repository. The default value is 'false'. Applies to Linux instances only.</p>
     #[serde(rename = "EnableNonSecurity")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -6199,6 +12953,99 @@ pub struct PatchRuleGroup {
     pub patch_rules: Vec<PatchRule>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PatchSet {
+    /// `OS`
+    Os,
+    /// `APPLICATION`
+    Application,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PatchSet {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PatchSet::Os => "OS",
+            PatchSet::Application => "APPLICATION",
+            PatchSet::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PatchSet {
+    fn default() -> PatchSet {
+        PatchSet::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PatchSet {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PatchSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PatchSet {
+    fn from(value: &'a str) -> PatchSet {
+        match value {
+            "OS" => PatchSet::Os,
+            "APPLICATION" => PatchSet::Application,
+            _ => PatchSet::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PatchSet {
+    fn from(value: String) -> PatchSet {
+        match value.as_str() {
+            "OS" => PatchSet::Os,
+            "APPLICATION" => PatchSet::Application,
+            _ => PatchSet::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PatchSet {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PatchSet, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PatchSet {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PatchSet {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PatchSet {
+    fn deserialize<D>(deserializer: D) -> Result<PatchSet, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about the patches to use to update the instances, including target operating systems and source repository. Applies to Linux instances only.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PatchSource {
@@ -6219,81 +13066,282 @@ pub struct PatchSource {
 pub struct PatchStatus {
     /// <p>The date the patch was approved (or will be approved if the status is PENDING_APPROVAL).</p>
     #[serde(rename = "ApprovalDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub approval_date: Option<f64>,
+    pub approval_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The compliance severity level for a patch.</p>
     #[serde(rename = "ComplianceLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compliance_level: Option<String>,
+    pub compliance_level: Option<PatchComplianceLevel>,
     /// <p>The approval status of a patch (APPROVED, PENDING_APPROVAL, EXPLICIT_APPROVED, EXPLICIT_REJECTED).</p>
     #[serde(rename = "DeploymentStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deployment_status: Option<String>,
+    pub deployment_status: Option<PatchDeploymentStatus>,
 }
 
-/// <p>An aggregate of step execution statuses displayed in the AWS Console for a multi-Region and multi-account Automation execution.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct ProgressCounters {
-    /// <p>The total number of steps that the system cancelled in all specified AWS Regions and accounts for the current Automation execution.</p>
-    #[serde(rename = "CancelledSteps")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub cancelled_steps: Option<i64>,
-    /// <p>The total number of steps that failed to run in all specified AWS Regions and accounts for the current Automation execution.</p>
-    #[serde(rename = "FailedSteps")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub failed_steps: Option<i64>,
-    /// <p>The total number of steps that successfully completed in all specified AWS Regions and accounts for the current Automation execution.</p>
-    #[serde(rename = "SuccessSteps")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub success_steps: Option<i64>,
-    /// <p>The total number of steps that timed out in all specified AWS Regions and accounts for the current Automation execution.</p>
-    #[serde(rename = "TimedOutSteps")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub timed_out_steps: Option<i64>,
-    /// <p>The total number of steps run in all specified AWS Regions and accounts for the current Automation execution.</p>
-    #[serde(rename = "TotalSteps")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub total_steps: Option<i64>,
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PingStatus {
+    /// `Online`
+    Online,
+    /// `ConnectionLost`
+    ConnectionLost,
+    /// `Inactive`
+    Inactive,
+    /// A value not known to this version of the crate
+    Unknown(String),
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct PutComplianceItemsRequest {
-    /// <p>Specify the compliance type. For example, specify Association (for a State Manager association), Patch, or Custom:<code>string</code>.</p>
-    #[serde(rename = "ComplianceType")]
-    pub compliance_type: String,
-    /// <p>A summary of the call execution that includes an execution ID, the type of execution (for example, <code>Command</code>), and the date/time of the execution using a datetime object that is saved in the following format: yyyy-MM-dd'T'HH:mm:ss'Z'.</p>
-    #[serde(rename = "ExecutionSummary")]
-    pub execution_summary: ComplianceExecutionSummary,
-    /// <p>MD5 or SHA-256 content hash. The content hash is used to determine if existing information should be overwritten or ignored. If the content hashes match, the request to put compliance information is ignored.</p>
-    #[serde(rename = "ItemContentHash")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub item_content_hash: Option<String>,
-    /// <p>Information about the compliance as defined by the resource type. For example, for a patch compliance type, <code>Items</code> includes information about the PatchSeverity, Classification, etc.</p>
-    #[serde(rename = "Items")]
-    pub items: Vec<ComplianceItemEntry>,
-    /// <p>Specify an ID for this resource. For a managed instance, this is the instance ID.</p>
-    #[serde(rename = "ResourceId")]
-    pub resource_id: String,
-    /// <p>Specify the type of resource. <code>ManagedInstance</code> is currently the only supported resource type.</p>
-    #[serde(rename = "ResourceType")]
-    pub resource_type: String,
+impl PingStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PingStatus::Online => "Online",
+            PingStatus::ConnectionLost => "ConnectionLost",
+            PingStatus::Inactive => "Inactive",
+            PingStatus::Unknown(ref value) => value,
+        }
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct PutComplianceItemsResult {}
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PingStatus {
+    fn default() -> PingStatus {
+        PingStatus::Unknown(String::new())
+    }
+}
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct PutInventoryRequest {
-    /// <p>An instance ID where you want to add or update inventory items.</p>
-    #[serde(rename = "InstanceId")]
-    pub instance_id: String,
-    /// <p>The inventory items that you want to add or update on instances.</p>
-    #[serde(rename = "Items")]
-    pub items: Vec<InventoryItem>,
+impl AsRef<str> for PingStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PingStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PingStatus {
+    fn from(value: &'a str) -> PingStatus {
+        match value {
+            "Online" => PingStatus::Online,
+            "ConnectionLost" => PingStatus::ConnectionLost,
+            "Inactive" => PingStatus::Inactive,
+            _ => PingStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PingStatus {
+    fn from(value: String) -> PingStatus {
+        match value.as_str() {
+            "Online" => PingStatus::Online,
+            "ConnectionLost" => PingStatus::ConnectionLost,
+            "Inactive" => PingStatus::Inactive,
+            _ => PingStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PingStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PingStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PingStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PingStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PingStatus {
+    fn deserialize<D>(deserializer: D) -> Result<PingStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PlatformType {
+    /// `Windows`
+    Windows,
+    /// `Linux`
+    Linux,
+    /// `MacOS`
+    MacOS,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PlatformType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PlatformType::Windows => "Windows",
+            PlatformType::Linux => "Linux",
+            PlatformType::MacOS => "MacOS",
+            PlatformType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PlatformType {
+    fn default() -> PlatformType {
+        PlatformType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PlatformType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PlatformType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PlatformType {
+    fn from(value: &'a str) -> PlatformType {
+        match value {
+            "Windows" => PlatformType::Windows,
+            "Linux" => PlatformType::Linux,
+            "MacOS" => PlatformType::MacOS,
+            _ => PlatformType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PlatformType {
+    fn from(value: String) -> PlatformType {
+        match value.as_str() {
+            "Windows" => PlatformType::Windows,
+            "Linux" => PlatformType::Linux,
+            "MacOS" => PlatformType::MacOS,
+            _ => PlatformType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PlatformType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PlatformType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PlatformType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PlatformType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PlatformType {
+    fn deserialize<D>(deserializer: D) -> Result<PlatformType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>An aggregate of step execution statuses displayed in the AWS Console for a multi-Region and multi-account Automation execution.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct ProgressCounters {
+    /// <p>The total number of steps that the system cancelled in all specified AWS Regions and accounts for the current Automation execution.</p>
+    #[serde(rename = "CancelledSteps")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancelled_steps: Option<i64>,
+    /// <p>The total number of steps that failed to run in all specified AWS Regions and accounts for the current Automation execution.</p>
+    #[serde(rename = "FailedSteps")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub failed_steps: Option<i64>,
+    /// <p>The total number of steps that successfully completed in all specified AWS Regions and accounts for the current Automation execution.</p>
+    #[serde(rename = "SuccessSteps")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub success_steps: Option<i64>,
+    /// <p>The total number of steps that timed out in all specified AWS Regions and accounts for the current Automation execution.</p>
+    #[serde(rename = "TimedOutSteps")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timed_out_steps: Option<i64>,
+    /// <p>The total number of steps run in all specified AWS Regions and accounts for the current Automation execution.</p>
+    #[serde(rename = "TotalSteps")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_steps: Option<i64>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct PutComplianceItemsRequest {
+    /// <p>Specify the compliance type. For example, specify Association (for a State Manager association), Patch, or Custom:<code>string</code>.</p>
+    #[serde(rename = "ComplianceType")]
+    pub compliance_type: String,
+    /// <p>A summary of the call execution that includes an execution ID, the type of execution (for example, <code>Command</code>), and the date/time of the execution using a datetime object that is saved in the following format: yyyy-MM-dd'T'HH:mm:ss'Z'.</p>
+    #[serde(rename = "ExecutionSummary")]
+    pub execution_summary: ComplianceExecutionSummary,
+    /// <p>MD5 or SHA-256 content hash. The content hash is used to determine if existing information should be overwritten or ignored. If the content hashes match, the request to put compliance information is ignored.</p>
+    #[serde(rename = "ItemContentHash")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub item_content_hash: Option<String>,
+    /// <p>Information about the compliance as defined by the resource type. For example, for a patch compliance type, <code>Items</code> includes information about the PatchSeverity, Classification, etc.</p>
+    #[serde(rename = "Items")]
+    pub items: Vec<ComplianceItemEntry>,
+    /// <p>Specify an ID for this resource. For a managed instance, this is the instance ID.</p>
+    #[serde(rename = "ResourceId")]
+    pub resource_id: String,
+    /// <p>Specify the type of resource. <code>ManagedInstance</code> is currently the only supported resource type.</p>
+    #[serde(rename = "ResourceType")]
+    pub resource_type: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct PutComplianceItemsResult {}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct PutInventoryRequest {
+    /// <p>An instance ID where you want to add or update inventory items.</p>
+    #[serde(rename = "InstanceId")]
+    pub instance_id: String,
+    /// <p>The inventory items that you want to add or update on instances.</p>
+    #[serde(rename = "Items")]
+    pub items: Vec<InventoryItem>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -6338,10 +13386,10 @@ pub struct PutParameterRequest {
     /// <p>The parameter tier to assign to a parameter.</p> <p>Parameter Store offers a standard tier and an advanced tier for parameters. Standard parameters have a content size limit of 4 KB and can't be configured to use parameter policies. You can create a maximum of 10,000 standard parameters for each Region in an AWS account. Standard parameters are offered at no additional cost. </p> <p>Advanced parameters have a content size limit of 8 KB and can be configured to use parameter policies. You can create a maximum of 100,000 advanced parameters for each Region in an AWS account. Advanced parameters incur a charge. For more information, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/parameter-store-advanced-parameters.html">About Advanced Parameters</a> in the <i>AWS Systems Manager User Guide</i>.</p> <p>You can change a standard parameter to an advanced parameter any time. But you can't revert an advanced parameter to a standard parameter. Reverting an advanced parameter to a standard parameter would result in data loss because the system would truncate the size of the parameter from 8 KB to 4 KB. Reverting would also remove any policies attached to the parameter. Lastly, advanced parameters use a different form of encryption than standard parameters. </p> <p>If you no longer need an advanced parameter, or if you no longer want to incur charges for an advanced parameter, you must delete it and recreate it as a new standard parameter. </p> <p> <b>Using the Default Tier Configuration</b> </p> <p>In <code>PutParameter</code> requests, you can specify the tier to create the parameter in. Whenever you specify a tier in the request, Parameter Store creates or updates the parameter according to that request. However, if you do not specify a tier in a request, Parameter Store assigns the tier based on the current Parameter Store default tier configuration.</p> <p>The default tier when you begin using Parameter Store is the standard-parameter tier. If you use the advanced-parameter tier, you can specify one of the following as the default:</p> <ul> <li> <p> <b>Advanced</b>: With this option, Parameter Store evaluates all requests as advanced parameters. </p> </li> <li> <p> <b>Intelligent-Tiering</b>: With this option, Parameter Store evaluates each request to determine if the parameter is standard or advanced. </p> <p>If the request doesn't include any options that require an advanced parameter, the parameter is created in the standard-parameter tier. If one or more options requiring an advanced parameter are included in the request, Parameter Store create a parameter in the advanced-parameter tier.</p> <p>This approach helps control your parameter-related costs by always creating standard parameters unless an advanced parameter is necessary. </p> </li> </ul> <p>Options that require an advanced parameter include the following:</p> <ul> <li> <p>The content size of the parameter is more than 4 KB.</p> </li> <li> <p>The parameter uses a parameter policy.</p> </li> <li> <p>More than 10,000 parameters already exist in your AWS account in the current Region.</p> </li> </ul> <p>For more information about configuring the default tier option, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/ps-default-tier.html">Specifying a Default Parameter Tier</a> in the <i>AWS Systems Manager User Guide</i>.</p>
     #[serde(rename = "Tier")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub tier: Option<String>,
+    pub tier: Option<ParameterTier>,
     /// <p><p>The type of parameter that you want to add to the system.</p> <p>Items in a <code>StringList</code> must be separated by a comma (,). You can&#39;t use other punctuation or special character to escape items in the list. If you have a parameter value that requires a comma, then use the <code>String</code> data type.</p> <note> <p> <code>SecureString</code> is not currently supported for AWS CloudFormation templates or in the China Regions.</p> </note></p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: ParameterType,
     /// <p>The parameter value that you want to add to the system. Standard parameters have a value limit of 4 KB. Advanced parameters have a value limit of 8 KB.</p>
     #[serde(rename = "Value")]
     pub value: String,
@@ -6353,13 +13401,106 @@ pub struct PutParameterResult {
     /// <p>The tier assigned to the parameter.</p>
     #[serde(rename = "Tier")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub tier: Option<String>,
+    pub tier: Option<ParameterTier>,
     /// <p>The new version number of a parameter. If you edit a parameter value, Parameter Store automatically creates a new version and assigns this new version a unique ID. You can reference a parameter version ID in API actions or in Systems Manager documents (SSM documents). By default, if you don't specify a specific version, the system returns the latest parameter value when a parameter is called.</p>
     #[serde(rename = "Version")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub version: Option<i64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RebootOption {
+    /// `RebootIfNeeded`
+    RebootIfNeeded,
+    /// `NoReboot`
+    NoReboot,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RebootOption {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RebootOption::RebootIfNeeded => "RebootIfNeeded",
+            RebootOption::NoReboot => "NoReboot",
+            RebootOption::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RebootOption {
+    fn default() -> RebootOption {
+        RebootOption::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RebootOption {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RebootOption {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RebootOption {
+    fn from(value: &'a str) -> RebootOption {
+        match value {
+            "RebootIfNeeded" => RebootOption::RebootIfNeeded,
+            "NoReboot" => RebootOption::NoReboot,
+            _ => RebootOption::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RebootOption {
+    fn from(value: String) -> RebootOption {
+        match value.as_str() {
+            "RebootIfNeeded" => RebootOption::RebootIfNeeded,
+            "NoReboot" => RebootOption::NoReboot,
+            _ => RebootOption::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RebootOption {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RebootOption, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RebootOption {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RebootOption {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RebootOption {
+    fn deserialize<D>(deserializer: D) -> Result<RebootOption, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct RegisterDefaultPatchBaselineRequest {
@@ -6422,7 +13563,7 @@ pub struct RegisterTargetWithMaintenanceWindowRequest {
     pub owner_information: Option<String>,
     /// <p>The type of target being registered with the maintenance window.</p>
     #[serde(rename = "ResourceType")]
-    pub resource_type: String,
+    pub resource_type: MaintenanceWindowResourceType,
     /// <p>The targets to register with the maintenance window. In other words, the instances to run commands on when the maintenance window runs.</p> <p>You can specify targets using instance IDs, resource group names, or tags that have been applied to instances.</p> <p> <b>Example 1</b>: Specify instance IDs</p> <p> <code>Key=InstanceIds,Values=<i>instance-id-1</i>,<i>instance-id-2</i>,<i>instance-id-3</i> </code> </p> <p> <b>Example 2</b>: Use tag key-pairs applied to instances</p> <p> <code>Key=tag:<i>my-tag-key</i>,Values=<i>my-tag-value-1</i>,<i>my-tag-value-2</i> </code> </p> <p> <b>Example 3</b>: Use tag-keys applied to instances</p> <p> <code>Key=tag-key,Values=<i>my-tag-key-1</i>,<i>my-tag-key-2</i> </code> </p> <p> <b>Example 4</b>: Use resource group names</p> <p> <code>Key=resource-groups:Name,Values=<i>resource-group-name</i> </code> </p> <p> <b>Example 5</b>: Use filters for resource group types</p> <p> <code>Key=resource-groups:ResourceTypeFilters,Values=<i>resource-type-1</i>,<i>resource-type-2</i> </code> </p> <note> <p>For <code>Key=resource-groups:ResourceTypeFilters</code>, specify resource types in the following format</p> <p> <code>Key=resource-groups:ResourceTypeFilters,Values=<i>AWS::EC2::INSTANCE</i>,<i>AWS::EC2::VPC</i> </code> </p> </note> <p>For more information about these examples formats, including the best use case for each one, see <a href="https://docs.aws.amazon.com/systems-manager/latest/userguide/mw-cli-tutorial-targets-examples.html">Examples: Register Targets with a Maintenance Window</a> in the <i>AWS Systems Manager User Guide</i>.</p>
     #[serde(rename = "Targets")]
     pub targets: Vec<Target>,
@@ -6490,7 +13631,7 @@ pub struct RegisterTaskWithMaintenanceWindowRequest {
         Option<::std::collections::HashMap<String, MaintenanceWindowTaskParameterValueExpression>>,
     /// <p>The type of task being registered.</p>
     #[serde(rename = "TaskType")]
-    pub task_type: String,
+    pub task_type: MaintenanceWindowTaskType,
     /// <p>The ID of the maintenance window the task should be added to.</p>
     #[serde(rename = "WindowId")]
     pub window_id: String,
@@ -6521,7 +13662,7 @@ pub struct RemoveTagsFromResourceRequest {
     pub resource_id: String,
     /// <p><p>The type of resource from which you want to remove a tag.</p> <note> <p>The ManagedInstance type for this API action is only for on-premises managed instances. Specify the name of the managed instance in the following format: mi-ID_number. For example, mi-1a2b3c4d5e6f.</p> </note></p>
     #[serde(rename = "ResourceType")]
-    pub resource_type: String,
+    pub resource_type: ResourceTypeForTagging,
     /// <p>Tag keys that you want to remove from the specified resource.</p>
     #[serde(rename = "TagKeys")]
     pub tag_keys: Vec<String>,
@@ -6587,7 +13728,7 @@ pub struct ResourceComplianceSummaryItem {
     /// <p>The highest severity item found for the resource. The resource is compliant for this item.</p>
     #[serde(rename = "OverallSeverity")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub overall_severity: Option<String>,
+    pub overall_severity: Option<ComplianceSeverity>,
     /// <p>The resource ID.</p>
     #[serde(rename = "ResourceId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -6599,7 +13740,7 @@ pub struct ResourceComplianceSummaryItem {
     /// <p>The compliance status for the resource.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ComplianceStatus>,
 }
 
 /// <p>Information about the AwsOrganizationsSource resource data sync source. A sync source of this type can synchronize data from AWS Organizations or, if an AWS Organization is not present, from multiple AWS Regions.</p>
@@ -6621,31 +13762,51 @@ pub struct ResourceDataSyncItem {
     /// <p>The status reported by the last sync.</p>
     #[serde(rename = "LastStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_status: Option<String>,
+    pub last_status: Option<LastResourceDataSyncStatus>,
     /// <p>The last time the sync operations returned a status of <code>SUCCESSFUL</code> (UTC).</p>
     #[serde(rename = "LastSuccessfulSyncTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_successful_sync_time: Option<f64>,
+    pub last_successful_sync_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The status message details reported by the last sync.</p>
     #[serde(rename = "LastSyncStatusMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub last_sync_status_message: Option<String>,
     /// <p>The last time the configuration attempted to sync (UTC).</p>
     #[serde(rename = "LastSyncTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_sync_time: Option<f64>,
+    pub last_sync_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Configuration information for the target Amazon S3 bucket.</p>
     #[serde(rename = "S3Destination")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub s3_destination: Option<ResourceDataSyncS3Destination>,
     /// <p>The date and time the configuration was created (UTC).</p>
     #[serde(rename = "SyncCreatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sync_created_time: Option<f64>,
+    pub sync_created_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The date and time the resource data sync was changed. </p>
     #[serde(rename = "SyncLastModifiedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sync_last_modified_time: Option<f64>,
+    pub sync_last_modified_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the Resource Data Sync.</p>
     #[serde(rename = "SyncName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -6688,9 +13849,97 @@ pub struct ResourceDataSyncS3Destination {
     pub region: String,
     /// <p>A supported sync format. The following format is currently supported: JsonSerDe</p>
     #[serde(rename = "SyncFormat")]
-    pub sync_format: String,
+    pub sync_format: ResourceDataSyncS3Format,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ResourceDataSyncS3Format {
+    /// `JsonSerDe`
+    JsonSerDe,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ResourceDataSyncS3Format {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceDataSyncS3Format::JsonSerDe => "JsonSerDe",
+            ResourceDataSyncS3Format::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ResourceDataSyncS3Format {
+    fn default() -> ResourceDataSyncS3Format {
+        ResourceDataSyncS3Format::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ResourceDataSyncS3Format {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ResourceDataSyncS3Format {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ResourceDataSyncS3Format {
+    fn from(value: &'a str) -> ResourceDataSyncS3Format {
+        match value {
+            "JsonSerDe" => ResourceDataSyncS3Format::JsonSerDe,
+            _ => ResourceDataSyncS3Format::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceDataSyncS3Format {
+    fn from(value: String) -> ResourceDataSyncS3Format {
+        match value.as_str() {
+            "JsonSerDe" => ResourceDataSyncS3Format::JsonSerDe,
+            _ => ResourceDataSyncS3Format::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ResourceDataSyncS3Format {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ResourceDataSyncS3Format, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ResourceDataSyncS3Format {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ResourceDataSyncS3Format {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for ResourceDataSyncS3Format {
+    fn deserialize<D>(deserializer: D) -> Result<ResourceDataSyncS3Format, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about the source of the data included in the resource data sync.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -6737,36 +13986,257 @@ pub struct ResourceDataSyncSourceWithState {
     pub state: Option<String>,
 }
 
-/// <p>The inventory item result attribute.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct ResultAttribute {
-    /// <p>Name of the inventory item type. Valid value: AWS:InstanceInformation. Default Value: AWS:InstanceInformation.</p>
-    #[serde(rename = "TypeName")]
-    pub type_name: String,
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ResourceType {
+    /// `ManagedInstance`
+    ManagedInstance,
+    /// `EC2Instance`
+    EC2Instance,
+    /// A value not known to this version of the crate
+    Unknown(String),
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct ResumeSessionRequest {
-    /// <p>The ID of the disconnected session to resume.</p>
-    #[serde(rename = "SessionId")]
-    pub session_id: String,
+impl ResourceType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceType::ManagedInstance => "ManagedInstance",
+            ResourceType::EC2Instance => "EC2Instance",
+            ResourceType::Unknown(ref value) => value,
+        }
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct ResumeSessionResponse {
-    /// <p>The ID of the session.</p>
-    #[serde(rename = "SessionId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub session_id: Option<String>,
-    /// <p>A URL back to SSM Agent on the instance that the Session Manager client uses to send commands and receive output from the instance. Format: <code>wss://ssmmessages.<b>region</b>.amazonaws.com/v1/data-channel/<b>session-id</b>?stream=(input|output)</code>.</p> <p> <b>region</b> represents the Region identifier for an AWS Region supported by AWS Systems Manager, such as <code>us-east-2</code> for the US East (Ohio) Region. For a list of supported <b>region</b> values, see the <b>Region</b> column in <a href="http://docs.aws.amazon.com/general/latest/gr/ssm.html#ssm_region">Systems Manager Service Endpoints</a> in the <i>AWS General Reference</i>.</p> <p> <b>session-id</b> represents the ID of a Session Manager session, such as <code>1a2b3c4dEXAMPLE</code>.</p>
-    #[serde(rename = "StreamUrl")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub stream_url: Option<String>,
-    /// <p>An encrypted token value containing session and caller information. Used to authenticate the connection to the instance.</p>
-    #[serde(rename = "TokenValue")]
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ResourceType {
+    fn default() -> ResourceType {
+        ResourceType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ResourceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ResourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ResourceType {
+    fn from(value: &'a str) -> ResourceType {
+        match value {
+            "ManagedInstance" => ResourceType::ManagedInstance,
+            "EC2Instance" => ResourceType::EC2Instance,
+            _ => ResourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceType {
+    fn from(value: String) -> ResourceType {
+        match value.as_str() {
+            "ManagedInstance" => ResourceType::ManagedInstance,
+            "EC2Instance" => ResourceType::EC2Instance,
+            _ => ResourceType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ResourceType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ResourceType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ResourceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ResourceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ResourceType {
+    fn deserialize<D>(deserializer: D) -> Result<ResourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ResourceTypeForTagging {
+    /// `Document`
+    Document,
+    /// `ManagedInstance`
+    ManagedInstance,
+    /// `MaintenanceWindow`
+    MaintenanceWindow,
+    /// `Parameter`
+    Parameter,
+    /// `PatchBaseline`
+    PatchBaseline,
+    /// `OpsItem`
+    OpsItem,
+    /// `OpsMetadata`
+    OpsMetadata,
+    /// `Automation`
+    Automation,
+    /// `Association`
+    Association,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ResourceTypeForTagging {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceTypeForTagging::Document => "Document",
+            ResourceTypeForTagging::ManagedInstance => "ManagedInstance",
+            ResourceTypeForTagging::MaintenanceWindow => "MaintenanceWindow",
+            ResourceTypeForTagging::Parameter => "Parameter",
+            ResourceTypeForTagging::PatchBaseline => "PatchBaseline",
+            ResourceTypeForTagging::OpsItem => "OpsItem",
+            ResourceTypeForTagging::OpsMetadata => "OpsMetadata",
+            ResourceTypeForTagging::Automation => "Automation",
+            ResourceTypeForTagging::Association => "Association",
+            ResourceTypeForTagging::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ResourceTypeForTagging {
+    fn default() -> ResourceTypeForTagging {
+        ResourceTypeForTagging::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ResourceTypeForTagging {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ResourceTypeForTagging {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ResourceTypeForTagging {
+    fn from(value: &'a str) -> ResourceTypeForTagging {
+        match value {
+            "Document" => ResourceTypeForTagging::Document,
+            "ManagedInstance" => ResourceTypeForTagging::ManagedInstance,
+            "MaintenanceWindow" => ResourceTypeForTagging::MaintenanceWindow,
+            "Parameter" => ResourceTypeForTagging::Parameter,
+            "PatchBaseline" => ResourceTypeForTagging::PatchBaseline,
+            "OpsItem" => ResourceTypeForTagging::OpsItem,
+            "OpsMetadata" => ResourceTypeForTagging::OpsMetadata,
+            "Automation" => ResourceTypeForTagging::Automation,
+            "Association" => ResourceTypeForTagging::Association,
+            _ => ResourceTypeForTagging::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceTypeForTagging {
+    fn from(value: String) -> ResourceTypeForTagging {
+        match value.as_str() {
+            "Document" => ResourceTypeForTagging::Document,
+            "ManagedInstance" => ResourceTypeForTagging::ManagedInstance,
+            "MaintenanceWindow" => ResourceTypeForTagging::MaintenanceWindow,
+            "Parameter" => ResourceTypeForTagging::Parameter,
+            "PatchBaseline" => ResourceTypeForTagging::PatchBaseline,
+            "OpsItem" => ResourceTypeForTagging::OpsItem,
+            "OpsMetadata" => ResourceTypeForTagging::OpsMetadata,
+            "Automation" => ResourceTypeForTagging::Automation,
+            "Association" => ResourceTypeForTagging::Association,
+            _ => ResourceTypeForTagging::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ResourceTypeForTagging {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ResourceTypeForTagging, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ResourceTypeForTagging {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ResourceTypeForTagging {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ResourceTypeForTagging {
+    fn deserialize<D>(deserializer: D) -> Result<ResourceTypeForTagging, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>The inventory item result attribute.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct ResultAttribute {
+    /// <p>Name of the inventory item type. Valid value: AWS:InstanceInformation. Default Value: AWS:InstanceInformation.</p>
+    #[serde(rename = "TypeName")]
+    pub type_name: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct ResumeSessionRequest {
+    /// <p>The ID of the disconnected session to resume.</p>
+    #[serde(rename = "SessionId")]
+    pub session_id: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct ResumeSessionResponse {
+    /// <p>The ID of the session.</p>
+    #[serde(rename = "SessionId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session_id: Option<String>,
+    /// <p>A URL back to SSM Agent on the instance that the Session Manager client uses to send commands and receive output from the instance. Format: <code>wss://ssmmessages.<b>region</b>.amazonaws.com/v1/data-channel/<b>session-id</b>?stream=(input|output)</code>.</p> <p> <b>region</b> represents the Region identifier for an AWS Region supported by AWS Systems Manager, such as <code>us-east-2</code> for the US East (Ohio) Region. For a list of supported <b>region</b> values, see the <b>Region</b> column in <a href="http://docs.aws.amazon.com/general/latest/gr/ssm.html#ssm_region">Systems Manager Service Endpoints</a> in the <i>AWS General Reference</i>.</p> <p> <b>session-id</b> represents the ID of a Session Manager session, such as <code>1a2b3c4dEXAMPLE</code>.</p>
+    #[serde(rename = "StreamUrl")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stream_url: Option<String>,
+    /// <p>An encrypted token value containing session and caller information. Used to authenticate the connection to the instance.</p>
+    #[serde(rename = "TokenValue")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub token_value: Option<String>,
 }
@@ -6828,7 +14298,7 @@ pub struct SendAutomationSignalRequest {
     pub payload: Option<::std::collections::HashMap<String, Vec<String>>>,
     /// <p>The type of signal to send to an Automation execution. </p>
     #[serde(rename = "SignalType")]
-    pub signal_type: String,
+    pub signal_type: SignalType,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -6853,7 +14323,7 @@ pub struct SendCommandRequest {
     /// <p><p>Sha256 or Sha1.</p> <note> <p>Sha1 hashes have been deprecated.</p> </note></p>
     #[serde(rename = "DocumentHashType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub document_hash_type: Option<String>,
+    pub document_hash_type: Option<DocumentHashType>,
     /// <p>Required. The name of the Systems Manager document to run. This can be a public document or a custom document.</p>
     #[serde(rename = "DocumentName")]
     pub document_name: String,
@@ -6926,8 +14396,13 @@ pub struct ServiceSetting {
     pub arn: Option<String>,
     /// <p>The last time the service setting was modified.</p>
     #[serde(rename = "LastModifiedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_date: Option<f64>,
+    pub last_modified_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The ARN of the last modified user. This field is populated only if the setting value was overwritten.</p>
     #[serde(rename = "LastModifiedUser")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -6960,8 +14435,13 @@ pub struct Session {
     pub document_name: Option<String>,
     /// <p>The date and time, in ISO-8601 Extended format, when the session was terminated.</p>
     #[serde(rename = "EndDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_date: Option<f64>,
+    pub end_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Reserved for future use.</p>
     #[serde(rename = "OutputUrl")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -6976,12 +14456,17 @@ pub struct Session {
     pub session_id: Option<String>,
     /// <p>The date and time, in ISO-8601 Extended format, when the session began.</p>
     #[serde(rename = "StartDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_date: Option<f64>,
+    pub start_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The status of the session. For example, "Connected" or "Terminated".</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<SessionStatus>,
     /// <p>The instance that the Session Manager session connected to.</p>
     #[serde(rename = "Target")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -6994,12 +14479,125 @@ pub struct Session {
 pub struct SessionFilter {
     /// <p>The name of the filter.</p>
     #[serde(rename = "key")]
-    pub key: String,
+    pub key: SessionFilterKey,
     /// <p><p>The filter value. Valid values for each filter key are as follows:</p> <ul> <li> <p>InvokedAfter: Specify a timestamp to limit your results. For example, specify 2018-08-29T00:00:00Z to see sessions that started August 29, 2018, and later.</p> </li> <li> <p>InvokedBefore: Specify a timestamp to limit your results. For example, specify 2018-08-29T00:00:00Z to see sessions that started before August 29, 2018.</p> </li> <li> <p>Target: Specify an instance to which session connections have been made.</p> </li> <li> <p>Owner: Specify an AWS user account to see a list of sessions started by that user.</p> </li> <li> <p>Status: Specify a valid session status to see a list of all sessions with that status. Status values you can specify include:</p> <ul> <li> <p>Connected</p> </li> <li> <p>Connecting</p> </li> <li> <p>Disconnected</p> </li> <li> <p>Terminated</p> </li> <li> <p>Terminating</p> </li> <li> <p>Failed</p> </li> </ul> </li> </ul></p>
     #[serde(rename = "value")]
     pub value: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SessionFilterKey {
+    /// `InvokedAfter`
+    InvokedAfter,
+    /// `InvokedBefore`
+    InvokedBefore,
+    /// `Target`
+    Target,
+    /// `Owner`
+    Owner,
+    /// `Status`
+    Status,
+    /// `SessionId`
+    SessionId,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SessionFilterKey {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SessionFilterKey::InvokedAfter => "InvokedAfter",
+            SessionFilterKey::InvokedBefore => "InvokedBefore",
+            SessionFilterKey::Target => "Target",
+            SessionFilterKey::Owner => "Owner",
+            SessionFilterKey::Status => "Status",
+            SessionFilterKey::SessionId => "SessionId",
+            SessionFilterKey::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SessionFilterKey {
+    fn default() -> SessionFilterKey {
+        SessionFilterKey::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SessionFilterKey {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SessionFilterKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SessionFilterKey {
+    fn from(value: &'a str) -> SessionFilterKey {
+        match value {
+            "InvokedAfter" => SessionFilterKey::InvokedAfter,
+            "InvokedBefore" => SessionFilterKey::InvokedBefore,
+            "Target" => SessionFilterKey::Target,
+            "Owner" => SessionFilterKey::Owner,
+            "Status" => SessionFilterKey::Status,
+            "SessionId" => SessionFilterKey::SessionId,
+            _ => SessionFilterKey::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SessionFilterKey {
+    fn from(value: String) -> SessionFilterKey {
+        match value.as_str() {
+            "InvokedAfter" => SessionFilterKey::InvokedAfter,
+            "InvokedBefore" => SessionFilterKey::InvokedBefore,
+            "Target" => SessionFilterKey::Target,
+            "Owner" => SessionFilterKey::Owner,
+            "Status" => SessionFilterKey::Status,
+            "SessionId" => SessionFilterKey::SessionId,
+            _ => SessionFilterKey::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SessionFilterKey {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SessionFilterKey, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SessionFilterKey {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SessionFilterKey {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SessionFilterKey {
+    fn deserialize<D>(deserializer: D) -> Result<SessionFilterKey, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Reserved for future use.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -7014,6 +14612,212 @@ pub struct SessionManagerOutputUrl {
     pub s3_output_url: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SessionState {
+    /// `Active`
+    Active,
+    /// `History`
+    History,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SessionState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SessionState::Active => "Active",
+            SessionState::History => "History",
+            SessionState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SessionState {
+    fn default() -> SessionState {
+        SessionState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SessionState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SessionState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SessionState {
+    fn from(value: &'a str) -> SessionState {
+        match value {
+            "Active" => SessionState::Active,
+            "History" => SessionState::History,
+            _ => SessionState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SessionState {
+    fn from(value: String) -> SessionState {
+        match value.as_str() {
+            "Active" => SessionState::Active,
+            "History" => SessionState::History,
+            _ => SessionState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SessionState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SessionState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SessionState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SessionState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SessionState {
+    fn deserialize<D>(deserializer: D) -> Result<SessionState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SessionStatus {
+    /// `Connected`
+    Connected,
+    /// `Connecting`
+    Connecting,
+    /// `Disconnected`
+    Disconnected,
+    /// `Terminated`
+    Terminated,
+    /// `Terminating`
+    Terminating,
+    /// `Failed`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SessionStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SessionStatus::Connected => "Connected",
+            SessionStatus::Connecting => "Connecting",
+            SessionStatus::Disconnected => "Disconnected",
+            SessionStatus::Terminated => "Terminated",
+            SessionStatus::Terminating => "Terminating",
+            SessionStatus::Failed => "Failed",
+            SessionStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SessionStatus {
+    fn default() -> SessionStatus {
+        SessionStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SessionStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SessionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SessionStatus {
+    fn from(value: &'a str) -> SessionStatus {
+        match value {
+            "Connected" => SessionStatus::Connected,
+            "Connecting" => SessionStatus::Connecting,
+            "Disconnected" => SessionStatus::Disconnected,
+            "Terminated" => SessionStatus::Terminated,
+            "Terminating" => SessionStatus::Terminating,
+            "Failed" => SessionStatus::Failed,
+            _ => SessionStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SessionStatus {
+    fn from(value: String) -> SessionStatus {
+        match value.as_str() {
+            "Connected" => SessionStatus::Connected,
+            "Connecting" => SessionStatus::Connecting,
+            "Disconnected" => SessionStatus::Disconnected,
+            "Terminated" => SessionStatus::Terminated,
+            "Terminating" => SessionStatus::Terminating,
+            "Failed" => SessionStatus::Failed,
+            _ => SessionStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SessionStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SessionStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SessionStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SessionStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SessionStatus {
+    fn deserialize<D>(deserializer: D) -> Result<SessionStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The number of managed instances found for each patch severity level defined in the request filter.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -7044,31 +14848,139 @@ pub struct SeveritySummary {
     pub unspecified_count: Option<i64>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct StartAssociationsOnceRequest {
-    /// <p>The association IDs that you want to run immediately and only one time.</p>
-    #[serde(rename = "AssociationIds")]
-    pub association_ids: Vec<String>,
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SignalType {
+    /// `Approve`
+    Approve,
+    /// `Reject`
+    Reject,
+    /// `StartStep`
+    StartStep,
+    /// `StopStep`
+    StopStep,
+    /// `Resume`
+    Resume,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SignalType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SignalType::Approve => "Approve",
+            SignalType::Reject => "Reject",
+            SignalType::StartStep => "StartStep",
+            SignalType::StopStep => "StopStep",
+            SignalType::Resume => "Resume",
+            SignalType::Unknown(ref value) => value,
+        }
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct StartAssociationsOnceResult {}
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SignalType {
+    fn default() -> SignalType {
+        SignalType::Unknown(String::new())
+    }
+}
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct StartAutomationExecutionRequest {
-    /// <p>User-provided idempotency token. The token must be unique, is case insensitive, enforces the UUID format, and can't be reused.</p>
-    #[serde(rename = "ClientToken")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub client_token: Option<String>,
-    /// <p>The name of the Automation document to use for this execution.</p>
-    #[serde(rename = "DocumentName")]
-    pub document_name: String,
-    /// <p>The version of the Automation document to use for this execution.</p>
-    #[serde(rename = "DocumentVersion")]
-    #[serde(skip_serializing_if = "Option::is_none")]
+impl AsRef<str> for SignalType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SignalType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SignalType {
+    fn from(value: &'a str) -> SignalType {
+        match value {
+            "Approve" => SignalType::Approve,
+            "Reject" => SignalType::Reject,
+            "StartStep" => SignalType::StartStep,
+            "StopStep" => SignalType::StopStep,
+            "Resume" => SignalType::Resume,
+            _ => SignalType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SignalType {
+    fn from(value: String) -> SignalType {
+        match value.as_str() {
+            "Approve" => SignalType::Approve,
+            "Reject" => SignalType::Reject,
+            "StartStep" => SignalType::StartStep,
+            "StopStep" => SignalType::StopStep,
+            "Resume" => SignalType::Resume,
+            _ => SignalType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SignalType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SignalType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SignalType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SignalType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SignalType {
+    fn deserialize<D>(deserializer: D) -> Result<SignalType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct StartAssociationsOnceRequest {
+    /// <p>The association IDs that you want to run immediately and only one time.</p>
+    #[serde(rename = "AssociationIds")]
+    pub association_ids: Vec<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct StartAssociationsOnceResult {}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct StartAutomationExecutionRequest {
+    /// <p>User-provided idempotency token. The token must be unique, is case insensitive, enforces the UUID format, and can't be reused.</p>
+    #[serde(rename = "ClientToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_token: Option<String>,
+    /// <p>The name of the Automation document to use for this execution.</p>
+    #[serde(rename = "DocumentName")]
+    pub document_name: String,
+    /// <p>The version of the Automation document to use for this execution.</p>
+    #[serde(rename = "DocumentVersion")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub document_version: Option<String>,
     /// <p>The maximum number of targets allowed to run this task in parallel. You can specify a number, such as 10, or a percentage, such as 10%. The default value is 10.</p>
     #[serde(rename = "MaxConcurrency")]
@@ -7081,7 +14993,7 @@ pub struct StartAutomationExecutionRequest {
     /// <p>The execution mode of the automation. Valid modes include the following: Auto and Interactive. The default mode is Auto.</p>
     #[serde(rename = "Mode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub mode: Option<String>,
+    pub mode: Option<ExecutionMode>,
     /// <p>A key-value map of execution parameters, which match the declared parameters in the Automation document.</p>
     #[serde(rename = "Parameters")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -7160,12 +15072,22 @@ pub struct StepExecution {
     pub action: Option<String>,
     /// <p>If a step has finished execution, this contains the time the execution ended. If the step has not yet concluded, this field is not populated.</p>
     #[serde(rename = "ExecutionEndTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub execution_end_time: Option<f64>,
+    pub execution_end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>If a step has begun execution, this contains the time the step started. If the step is in Pending status, this field is not populated.</p>
     #[serde(rename = "ExecutionStartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub execution_start_time: Option<f64>,
+    pub execution_start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Information about the Automation failure.</p>
     #[serde(rename = "FailureDetails")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -7225,7 +15147,7 @@ pub struct StepExecution {
     /// <p>The execution status for this step.</p>
     #[serde(rename = "StepStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub step_status: Option<String>,
+    pub step_status: Option<AutomationExecutionStatus>,
     /// <p>The combination of AWS Regions and accounts targeted by the current Automation execution.</p>
     #[serde(rename = "TargetLocation")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -7250,12 +15172,140 @@ pub struct StepExecution {
 pub struct StepExecutionFilter {
     /// <p>One or more keys to limit the results. Valid filter keys include the following: StepName, Action, StepExecutionId, StepExecutionStatus, StartTimeBefore, StartTimeAfter.</p>
     #[serde(rename = "Key")]
-    pub key: String,
+    pub key: StepExecutionFilterKey,
     /// <p>The values of the filter key.</p>
     #[serde(rename = "Values")]
     pub values: Vec<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StepExecutionFilterKey {
+    /// `StartTimeBefore`
+    StartTimeBefore,
+    /// `StartTimeAfter`
+    StartTimeAfter,
+    /// `StepExecutionStatus`
+    StepExecutionStatus,
+    /// `StepExecutionId`
+    StepExecutionId,
+    /// `StepName`
+    StepName,
+    /// `Action`
+    Action,
+    /// `ParentStepExecutionId`
+    ParentStepExecutionId,
+    /// `ParentStepIteration`
+    ParentStepIteration,
+    /// `ParentStepIteratorValue`
+    ParentStepIteratorValue,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl StepExecutionFilterKey {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StepExecutionFilterKey::StartTimeBefore => "StartTimeBefore",
+            StepExecutionFilterKey::StartTimeAfter => "StartTimeAfter",
+            StepExecutionFilterKey::StepExecutionStatus => "StepExecutionStatus",
+            StepExecutionFilterKey::StepExecutionId => "StepExecutionId",
+            StepExecutionFilterKey::StepName => "StepName",
+            StepExecutionFilterKey::Action => "Action",
+            StepExecutionFilterKey::ParentStepExecutionId => "ParentStepExecutionId",
+            StepExecutionFilterKey::ParentStepIteration => "ParentStepIteration",
+            StepExecutionFilterKey::ParentStepIteratorValue => "ParentStepIteratorValue",
+            StepExecutionFilterKey::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for StepExecutionFilterKey {
+    fn default() -> StepExecutionFilterKey {
+        StepExecutionFilterKey::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for StepExecutionFilterKey {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for StepExecutionFilterKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for StepExecutionFilterKey {
+    fn from(value: &'a str) -> StepExecutionFilterKey {
+        match value {
+            "StartTimeBefore" => StepExecutionFilterKey::StartTimeBefore,
+            "StartTimeAfter" => StepExecutionFilterKey::StartTimeAfter,
+            "StepExecutionStatus" => StepExecutionFilterKey::StepExecutionStatus,
+            "StepExecutionId" => StepExecutionFilterKey::StepExecutionId,
+            "StepName" => StepExecutionFilterKey::StepName,
+            "Action" => StepExecutionFilterKey::Action,
+            "ParentStepExecutionId" => StepExecutionFilterKey::ParentStepExecutionId,
+            "ParentStepIteration" => StepExecutionFilterKey::ParentStepIteration,
+            "ParentStepIteratorValue" => StepExecutionFilterKey::ParentStepIteratorValue,
+            _ => StepExecutionFilterKey::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StepExecutionFilterKey {
+    fn from(value: String) -> StepExecutionFilterKey {
+        match value.as_str() {
+            "StartTimeBefore" => StepExecutionFilterKey::StartTimeBefore,
+            "StartTimeAfter" => StepExecutionFilterKey::StartTimeAfter,
+            "StepExecutionStatus" => StepExecutionFilterKey::StepExecutionStatus,
+            "StepExecutionId" => StepExecutionFilterKey::StepExecutionId,
+            "StepName" => StepExecutionFilterKey::StepName,
+            "Action" => StepExecutionFilterKey::Action,
+            "ParentStepExecutionId" => StepExecutionFilterKey::ParentStepExecutionId,
+            "ParentStepIteration" => StepExecutionFilterKey::ParentStepIteration,
+            "ParentStepIteratorValue" => StepExecutionFilterKey::ParentStepIteratorValue,
+            _ => StepExecutionFilterKey::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for StepExecutionFilterKey {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<StepExecutionFilterKey, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StepExecutionFilterKey {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for StepExecutionFilterKey {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StepExecutionFilterKey {
+    fn deserialize<D>(deserializer: D) -> Result<StepExecutionFilterKey, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct StopAutomationExecutionRequest {
@@ -7265,13 +15315,106 @@ pub struct StopAutomationExecutionRequest {
     /// <p>The stop request type. Valid types include the following: Cancel and Complete. The default type is Cancel.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<StopType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StopAutomationExecutionResult {}
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StopType {
+    /// `Complete`
+    Complete,
+    /// `Cancel`
+    Cancel,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl StopType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StopType::Complete => "Complete",
+            StopType::Cancel => "Cancel",
+            StopType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for StopType {
+    fn default() -> StopType {
+        StopType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for StopType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for StopType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for StopType {
+    fn from(value: &'a str) -> StopType {
+        match value {
+            "Complete" => StopType::Complete,
+            "Cancel" => StopType::Cancel,
+            _ => StopType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StopType {
+    fn from(value: String) -> StopType {
+        match value.as_str() {
+            "Complete" => StopType::Complete,
+            "Cancel" => StopType::Cancel,
+            _ => StopType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for StopType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<StopType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StopType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for StopType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StopType {
+    fn deserialize<D>(deserializer: D) -> Result<StopType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Metadata that you assign to your AWS resources. Tags enable you to categorize your resources in different ways, for example, by purpose, owner, or environment. In Systems Manager, you can apply tags to documents, managed instances, maintenance windows, Parameter Store parameters, and patch baselines.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Tag {
@@ -7359,7 +15502,7 @@ pub struct UpdateAssociationRequest {
     /// <p>The severity level to assign to the association.</p>
     #[serde(rename = "ComplianceSeverity")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compliance_severity: Option<String>,
+    pub compliance_severity: Option<AssociationComplianceSeverity>,
     /// <p>The document version you want update for the association. </p>
     #[serde(rename = "DocumentVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -7459,7 +15602,7 @@ pub struct UpdateDocumentRequest {
     /// <p>Specify the document format for the new document version. Systems Manager supports JSON and YAML documents. JSON is the default format.</p>
     #[serde(rename = "DocumentFormat")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub document_format: Option<String>,
+    pub document_format: Option<DocumentFormat>,
     /// <p>(Required) The version of the document that you want to update. </p>
     #[serde(rename = "DocumentVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -7821,7 +15964,7 @@ pub struct UpdateOpsItemRequest {
     /// <p>The OpsItem status. Status can be <code>Open</code>, <code>In Progress</code>, or <code>Resolved</code>. For more information, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/OpsCenter-working-with-OpsItems-editing-details.html">Editing OpsItem Details</a> in the <i>AWS Systems Manager User Guide</i>.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<OpsItemStatus>,
     /// <p>A short heading that describes the nature of the OpsItem and the impacted resource.</p>
     #[serde(rename = "Title")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -7846,7 +15989,7 @@ pub struct UpdatePatchBaselineRequest {
     /// <p>Assigns a new compliance severity level to an existing patch baseline.</p>
     #[serde(rename = "ApprovedPatchesComplianceLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub approved_patches_compliance_level: Option<String>,
+    pub approved_patches_compliance_level: Option<PatchComplianceLevel>,
     /// <p>Indicates whether the list of approved patches includes non-security updates that should be applied to the instances. The default value is 'false'. Applies to Linux instances only.</p>
     #[serde(rename = "ApprovedPatchesEnableNonSecurity")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -7873,7 +16016,7 @@ pub struct UpdatePatchBaselineRequest {
     /// <p><p>The action for Patch Manager to take on patches included in the RejectedPackages list.</p> <ul> <li> <p> <b>ALLOW<em>AS</em>DEPENDENCY</b>: A package in the Rejected patches list is installed only if it is a dependency of another package. It is considered compliant with the patch baseline, and its status is reported as <i>InstalledOther</i>. This is the default action if no option is specified.</p> </li> <li> <p> <b>BLOCK</b>: Packages in the RejectedPatches list, and packages that include them as dependencies, are not installed under any circumstances. If a package was installed before it was added to the Rejected patches list, it is considered non-compliant with the patch baseline, and its status is reported as <i>InstalledRejected</i>.</p> </li> </ul></p>
     #[serde(rename = "RejectedPatchesAction")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub rejected_patches_action: Option<String>,
+    pub rejected_patches_action: Option<PatchAction>,
     /// <p>If True, then all fields that are required by the CreatePatchBaseline action are also required for this API request. Optional fields that are not specified are set to null.</p>
     #[serde(rename = "Replace")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -7898,7 +16041,7 @@ pub struct UpdatePatchBaselineResult {
     /// <p>The compliance severity level assigned to the patch baseline after the update completed.</p>
     #[serde(rename = "ApprovedPatchesComplianceLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub approved_patches_compliance_level: Option<String>,
+    pub approved_patches_compliance_level: Option<PatchComplianceLevel>,
     /// <p>Indicates whether the list of approved patches includes non-security updates that should be applied to the instances. The default value is 'false'. Applies to Linux instances only.</p>
     #[serde(rename = "ApprovedPatchesEnableNonSecurity")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -7909,8 +16052,13 @@ pub struct UpdatePatchBaselineResult {
     pub baseline_id: Option<String>,
     /// <p>The date when the patch baseline was created.</p>
     #[serde(rename = "CreatedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A description of the Patch Baseline.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -7921,8 +16069,13 @@ pub struct UpdatePatchBaselineResult {
     pub global_filters: Option<PatchFilterGroup>,
     /// <p>The date when the patch baseline was last modified.</p>
     #[serde(rename = "ModifiedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub modified_date: Option<f64>,
+    pub modified_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the patch baseline.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -7930,7 +16083,7 @@ pub struct UpdatePatchBaselineResult {
     /// <p>The operating system rule used by the updated patch baseline.</p>
     #[serde(rename = "OperatingSystem")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub operating_system: Option<String>,
+    pub operating_system: Option<OperatingSystem>,
     /// <p>A list of explicitly rejected patches for the baseline.</p>
     #[serde(rename = "RejectedPatches")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -7938,7 +16091,7 @@ pub struct UpdatePatchBaselineResult {
     /// <p>The action specified to take on patches included in the RejectedPatches list. A patch can be allowed only if it is a dependency of another package, or blocked entirely along with packages that include it as a dependency.</p>
     #[serde(rename = "RejectedPatchesAction")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub rejected_patches_action: Option<String>,
+    pub rejected_patches_action: Option<PatchAction>,
     /// <p>Information about the patches to use to update the instances, including target operating systems and source repositories. Applies to Linux instances only.</p>
     #[serde(rename = "Sources")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -15371,11 +23524,12 @@ pub struct SsmClient {
 impl SsmClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> SsmClient {
         SsmClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "SSM"),
         }
     }
 
@@ -15407,6 +23561,7 @@ impl Ssm for SsmClient {
         input: AddTagsToResourceRequest,
     ) -> Result<AddTagsToResourceResult, RusotoError<AddTagsToResourceError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("AddTagsToResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.AddTagsToResource");
@@ -15434,6 +23589,7 @@ impl Ssm for SsmClient {
         input: CancelCommandRequest,
     ) -> Result<CancelCommandResult, RusotoError<CancelCommandError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("CancelCommand");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.CancelCommand");
@@ -15464,6 +23620,7 @@ impl Ssm for SsmClient {
         RusotoError<CancelMaintenanceWindowExecutionError>,
     > {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("CancelMaintenanceWindowExecution");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.CancelMaintenanceWindowExecution");
@@ -15494,6 +23651,7 @@ impl Ssm for SsmClient {
         input: CreateActivationRequest,
     ) -> Result<CreateActivationResult, RusotoError<CreateActivationError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("CreateActivation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.CreateActivation");
@@ -15521,6 +23679,7 @@ impl Ssm for SsmClient {
         input: CreateAssociationRequest,
     ) -> Result<CreateAssociationResult, RusotoError<CreateAssociationError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("CreateAssociation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.CreateAssociation");
@@ -15548,6 +23707,7 @@ impl Ssm for SsmClient {
         input: CreateAssociationBatchRequest,
     ) -> Result<CreateAssociationBatchResult, RusotoError<CreateAssociationBatchError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("CreateAssociationBatch");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.CreateAssociationBatch");
@@ -15576,6 +23736,7 @@ impl Ssm for SsmClient {
         input: CreateDocumentRequest,
     ) -> Result<CreateDocumentResult, RusotoError<CreateDocumentError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("CreateDocument");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.CreateDocument");
@@ -15603,6 +23764,7 @@ impl Ssm for SsmClient {
         input: CreateMaintenanceWindowRequest,
     ) -> Result<CreateMaintenanceWindowResult, RusotoError<CreateMaintenanceWindowError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("CreateMaintenanceWindow");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.CreateMaintenanceWindow");
@@ -15631,6 +23793,7 @@ impl Ssm for SsmClient {
         input: CreateOpsItemRequest,
     ) -> Result<CreateOpsItemResponse, RusotoError<CreateOpsItemError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("CreateOpsItem");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.CreateOpsItem");
@@ -15658,6 +23821,7 @@ impl Ssm for SsmClient {
         input: CreatePatchBaselineRequest,
     ) -> Result<CreatePatchBaselineResult, RusotoError<CreatePatchBaselineError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("CreatePatchBaseline");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.CreatePatchBaseline");
@@ -15686,6 +23850,7 @@ impl Ssm for SsmClient {
         input: CreateResourceDataSyncRequest,
     ) -> Result<CreateResourceDataSyncResult, RusotoError<CreateResourceDataSyncError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("CreateResourceDataSync");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.CreateResourceDataSync");
@@ -15714,6 +23879,7 @@ impl Ssm for SsmClient {
         input: DeleteActivationRequest,
     ) -> Result<DeleteActivationResult, RusotoError<DeleteActivationError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DeleteActivation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DeleteActivation");
@@ -15741,6 +23907,7 @@ impl Ssm for SsmClient {
         input: DeleteAssociationRequest,
     ) -> Result<DeleteAssociationResult, RusotoError<DeleteAssociationError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DeleteAssociation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DeleteAssociation");
@@ -15768,6 +23935,7 @@ impl Ssm for SsmClient {
         input: DeleteDocumentRequest,
     ) -> Result<DeleteDocumentResult, RusotoError<DeleteDocumentError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DeleteDocument");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DeleteDocument");
@@ -15795,6 +23963,7 @@ impl Ssm for SsmClient {
         input: DeleteInventoryRequest,
     ) -> Result<DeleteInventoryResult, RusotoError<DeleteInventoryError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DeleteInventory");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DeleteInventory");
@@ -15822,6 +23991,7 @@ impl Ssm for SsmClient {
         input: DeleteMaintenanceWindowRequest,
     ) -> Result<DeleteMaintenanceWindowResult, RusotoError<DeleteMaintenanceWindowError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DeleteMaintenanceWindow");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DeleteMaintenanceWindow");
@@ -15850,6 +24020,7 @@ impl Ssm for SsmClient {
         input: DeleteParameterRequest,
     ) -> Result<DeleteParameterResult, RusotoError<DeleteParameterError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DeleteParameter");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DeleteParameter");
@@ -15877,6 +24048,7 @@ impl Ssm for SsmClient {
         input: DeleteParametersRequest,
     ) -> Result<DeleteParametersResult, RusotoError<DeleteParametersError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DeleteParameters");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DeleteParameters");
@@ -15904,6 +24076,7 @@ impl Ssm for SsmClient {
         input: DeletePatchBaselineRequest,
     ) -> Result<DeletePatchBaselineResult, RusotoError<DeletePatchBaselineError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DeletePatchBaseline");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DeletePatchBaseline");
@@ -15932,6 +24105,7 @@ impl Ssm for SsmClient {
         input: DeleteResourceDataSyncRequest,
     ) -> Result<DeleteResourceDataSyncResult, RusotoError<DeleteResourceDataSyncError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DeleteResourceDataSync");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DeleteResourceDataSync");
@@ -15960,6 +24134,7 @@ impl Ssm for SsmClient {
         input: DeregisterManagedInstanceRequest,
     ) -> Result<DeregisterManagedInstanceResult, RusotoError<DeregisterManagedInstanceError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DeregisterManagedInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DeregisterManagedInstance");
@@ -15991,6 +24166,7 @@ impl Ssm for SsmClient {
         RusotoError<DeregisterPatchBaselineForPatchGroupError>,
     > {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DeregisterPatchBaselineForPatchGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16027,6 +24203,7 @@ impl Ssm for SsmClient {
         RusotoError<DeregisterTargetFromMaintenanceWindowError>,
     > {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DeregisterTargetFromMaintenanceWindow");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16063,6 +24240,7 @@ impl Ssm for SsmClient {
         RusotoError<DeregisterTaskFromMaintenanceWindowError>,
     > {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DeregisterTaskFromMaintenanceWindow");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16096,6 +24274,7 @@ impl Ssm for SsmClient {
         input: DescribeActivationsRequest,
     ) -> Result<DescribeActivationsResult, RusotoError<DescribeActivationsError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeActivations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DescribeActivations");
@@ -16124,6 +24303,7 @@ impl Ssm for SsmClient {
         input: DescribeAssociationRequest,
     ) -> Result<DescribeAssociationResult, RusotoError<DescribeAssociationError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeAssociation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DescribeAssociation");
@@ -16155,6 +24335,7 @@ impl Ssm for SsmClient {
         RusotoError<DescribeAssociationExecutionTargetsError>,
     > {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeAssociationExecutionTargets");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16189,6 +24370,7 @@ impl Ssm for SsmClient {
     ) -> Result<DescribeAssociationExecutionsResult, RusotoError<DescribeAssociationExecutionsError>>
     {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeAssociationExecutions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DescribeAssociationExecutions");
@@ -16218,6 +24400,7 @@ impl Ssm for SsmClient {
     ) -> Result<DescribeAutomationExecutionsResult, RusotoError<DescribeAutomationExecutionsError>>
     {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeAutomationExecutions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DescribeAutomationExecutions");
@@ -16249,6 +24432,7 @@ impl Ssm for SsmClient {
         RusotoError<DescribeAutomationStepExecutionsError>,
     > {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeAutomationStepExecutions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DescribeAutomationStepExecutions");
@@ -16279,6 +24463,7 @@ impl Ssm for SsmClient {
         input: DescribeAvailablePatchesRequest,
     ) -> Result<DescribeAvailablePatchesResult, RusotoError<DescribeAvailablePatchesError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeAvailablePatches");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DescribeAvailablePatches");
@@ -16307,6 +24492,7 @@ impl Ssm for SsmClient {
         input: DescribeDocumentRequest,
     ) -> Result<DescribeDocumentResult, RusotoError<DescribeDocumentError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeDocument");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DescribeDocument");
@@ -16335,6 +24521,7 @@ impl Ssm for SsmClient {
     ) -> Result<DescribeDocumentPermissionResponse, RusotoError<DescribeDocumentPermissionError>>
     {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeDocumentPermission");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DescribeDocumentPermission");
@@ -16366,6 +24553,7 @@ impl Ssm for SsmClient {
         RusotoError<DescribeEffectiveInstanceAssociationsError>,
     > {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeEffectiveInstanceAssociations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16402,6 +24590,7 @@ impl Ssm for SsmClient {
         RusotoError<DescribeEffectivePatchesForPatchBaselineError>,
     > {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeEffectivePatchesForPatchBaseline");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16436,6 +24625,7 @@ impl Ssm for SsmClient {
         RusotoError<DescribeInstanceAssociationsStatusError>,
     > {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeInstanceAssociationsStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16470,6 +24660,7 @@ impl Ssm for SsmClient {
     ) -> Result<DescribeInstanceInformationResult, RusotoError<DescribeInstanceInformationError>>
     {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeInstanceInformation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DescribeInstanceInformation");
@@ -16499,6 +24690,7 @@ impl Ssm for SsmClient {
     ) -> Result<DescribeInstancePatchStatesResult, RusotoError<DescribeInstancePatchStatesError>>
     {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeInstancePatchStates");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DescribeInstancePatchStates");
@@ -16530,6 +24722,7 @@ impl Ssm for SsmClient {
         RusotoError<DescribeInstancePatchStatesForPatchGroupError>,
     > {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeInstancePatchStatesForPatchGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16561,6 +24754,7 @@ impl Ssm for SsmClient {
         input: DescribeInstancePatchesRequest,
     ) -> Result<DescribeInstancePatchesResult, RusotoError<DescribeInstancePatchesError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeInstancePatches");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DescribeInstancePatches");
@@ -16590,6 +24784,7 @@ impl Ssm for SsmClient {
     ) -> Result<DescribeInventoryDeletionsResult, RusotoError<DescribeInventoryDeletionsError>>
     {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeInventoryDeletions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DescribeInventoryDeletions");
@@ -16621,6 +24816,7 @@ impl Ssm for SsmClient {
         RusotoError<DescribeMaintenanceWindowExecutionTaskInvocationsError>,
     > {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeMaintenanceWindowExecutionTaskInvocations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16655,6 +24851,7 @@ impl Ssm for SsmClient {
         RusotoError<DescribeMaintenanceWindowExecutionTasksError>,
     > {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeMaintenanceWindowExecutionTasks");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16691,6 +24888,7 @@ impl Ssm for SsmClient {
         RusotoError<DescribeMaintenanceWindowExecutionsError>,
     > {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeMaintenanceWindowExecutions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16727,6 +24925,7 @@ impl Ssm for SsmClient {
         RusotoError<DescribeMaintenanceWindowScheduleError>,
     > {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeMaintenanceWindowSchedule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16763,6 +24962,7 @@ impl Ssm for SsmClient {
         RusotoError<DescribeMaintenanceWindowTargetsError>,
     > {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeMaintenanceWindowTargets");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DescribeMaintenanceWindowTargets");
@@ -16796,6 +24996,7 @@ impl Ssm for SsmClient {
         RusotoError<DescribeMaintenanceWindowTasksError>,
     > {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeMaintenanceWindowTasks");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DescribeMaintenanceWindowTasks");
@@ -16825,6 +25026,7 @@ impl Ssm for SsmClient {
     ) -> Result<DescribeMaintenanceWindowsResult, RusotoError<DescribeMaintenanceWindowsError>>
     {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeMaintenanceWindows");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DescribeMaintenanceWindows");
@@ -16856,6 +25058,7 @@ impl Ssm for SsmClient {
         RusotoError<DescribeMaintenanceWindowsForTargetError>,
     > {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeMaintenanceWindowsForTarget");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16889,6 +25092,7 @@ impl Ssm for SsmClient {
         input: DescribeOpsItemsRequest,
     ) -> Result<DescribeOpsItemsResponse, RusotoError<DescribeOpsItemsError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeOpsItems");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DescribeOpsItems");
@@ -16917,6 +25121,7 @@ impl Ssm for SsmClient {
         input: DescribeParametersRequest,
     ) -> Result<DescribeParametersResult, RusotoError<DescribeParametersError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeParameters");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DescribeParameters");
@@ -16945,6 +25150,7 @@ impl Ssm for SsmClient {
         input: DescribePatchBaselinesRequest,
     ) -> Result<DescribePatchBaselinesResult, RusotoError<DescribePatchBaselinesError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribePatchBaselines");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DescribePatchBaselines");
@@ -16973,6 +25179,7 @@ impl Ssm for SsmClient {
         input: DescribePatchGroupStateRequest,
     ) -> Result<DescribePatchGroupStateResult, RusotoError<DescribePatchGroupStateError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribePatchGroupState");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DescribePatchGroupState");
@@ -17001,6 +25208,7 @@ impl Ssm for SsmClient {
         input: DescribePatchGroupsRequest,
     ) -> Result<DescribePatchGroupsResult, RusotoError<DescribePatchGroupsError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribePatchGroups");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DescribePatchGroups");
@@ -17029,6 +25237,7 @@ impl Ssm for SsmClient {
         input: DescribePatchPropertiesRequest,
     ) -> Result<DescribePatchPropertiesResult, RusotoError<DescribePatchPropertiesError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribePatchProperties");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DescribePatchProperties");
@@ -17057,6 +25266,7 @@ impl Ssm for SsmClient {
         input: DescribeSessionsRequest,
     ) -> Result<DescribeSessionsResponse, RusotoError<DescribeSessionsError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeSessions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DescribeSessions");
@@ -17085,6 +25295,7 @@ impl Ssm for SsmClient {
         input: GetAutomationExecutionRequest,
     ) -> Result<GetAutomationExecutionResult, RusotoError<GetAutomationExecutionError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetAutomationExecution");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.GetAutomationExecution");
@@ -17113,6 +25324,7 @@ impl Ssm for SsmClient {
         input: GetCalendarStateRequest,
     ) -> Result<GetCalendarStateResponse, RusotoError<GetCalendarStateError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetCalendarState");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.GetCalendarState");
@@ -17141,6 +25353,7 @@ impl Ssm for SsmClient {
         input: GetCommandInvocationRequest,
     ) -> Result<GetCommandInvocationResult, RusotoError<GetCommandInvocationError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetCommandInvocation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.GetCommandInvocation");
@@ -17169,6 +25382,7 @@ impl Ssm for SsmClient {
         input: GetConnectionStatusRequest,
     ) -> Result<GetConnectionStatusResponse, RusotoError<GetConnectionStatusError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetConnectionStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.GetConnectionStatus");
@@ -17197,6 +25411,7 @@ impl Ssm for SsmClient {
         input: GetDefaultPatchBaselineRequest,
     ) -> Result<GetDefaultPatchBaselineResult, RusotoError<GetDefaultPatchBaselineError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetDefaultPatchBaseline");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.GetDefaultPatchBaseline");
@@ -17228,6 +25443,7 @@ impl Ssm for SsmClient {
         RusotoError<GetDeployablePatchSnapshotForInstanceError>,
     > {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetDeployablePatchSnapshotForInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -17261,6 +25477,7 @@ impl Ssm for SsmClient {
         input: GetDocumentRequest,
     ) -> Result<GetDocumentResult, RusotoError<GetDocumentError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetDocument");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.GetDocument");
@@ -17288,6 +25505,7 @@ impl Ssm for SsmClient {
         input: GetInventoryRequest,
     ) -> Result<GetInventoryResult, RusotoError<GetInventoryError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetInventory");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.GetInventory");
@@ -17315,6 +25533,7 @@ impl Ssm for SsmClient {
         input: GetInventorySchemaRequest,
     ) -> Result<GetInventorySchemaResult, RusotoError<GetInventorySchemaError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetInventorySchema");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.GetInventorySchema");
@@ -17343,6 +25562,7 @@ impl Ssm for SsmClient {
         input: GetMaintenanceWindowRequest,
     ) -> Result<GetMaintenanceWindowResult, RusotoError<GetMaintenanceWindowError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetMaintenanceWindow");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.GetMaintenanceWindow");
@@ -17372,6 +25592,7 @@ impl Ssm for SsmClient {
     ) -> Result<GetMaintenanceWindowExecutionResult, RusotoError<GetMaintenanceWindowExecutionError>>
     {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetMaintenanceWindowExecution");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.GetMaintenanceWindowExecution");
@@ -17403,6 +25624,7 @@ impl Ssm for SsmClient {
         RusotoError<GetMaintenanceWindowExecutionTaskError>,
     > {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetMaintenanceWindowExecutionTask");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -17439,6 +25661,7 @@ impl Ssm for SsmClient {
         RusotoError<GetMaintenanceWindowExecutionTaskInvocationError>,
     > {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetMaintenanceWindowExecutionTaskInvocation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -17470,6 +25693,7 @@ impl Ssm for SsmClient {
         input: GetMaintenanceWindowTaskRequest,
     ) -> Result<GetMaintenanceWindowTaskResult, RusotoError<GetMaintenanceWindowTaskError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetMaintenanceWindowTask");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.GetMaintenanceWindowTask");
@@ -17498,6 +25722,7 @@ impl Ssm for SsmClient {
         input: GetOpsItemRequest,
     ) -> Result<GetOpsItemResponse, RusotoError<GetOpsItemError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetOpsItem");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.GetOpsItem");
@@ -17525,6 +25750,7 @@ impl Ssm for SsmClient {
         input: GetOpsSummaryRequest,
     ) -> Result<GetOpsSummaryResult, RusotoError<GetOpsSummaryError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetOpsSummary");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.GetOpsSummary");
@@ -17552,6 +25778,7 @@ impl Ssm for SsmClient {
         input: GetParameterRequest,
     ) -> Result<GetParameterResult, RusotoError<GetParameterError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetParameter");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.GetParameter");
@@ -17579,6 +25806,7 @@ impl Ssm for SsmClient {
         input: GetParameterHistoryRequest,
     ) -> Result<GetParameterHistoryResult, RusotoError<GetParameterHistoryError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetParameterHistory");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.GetParameterHistory");
@@ -17607,6 +25835,7 @@ impl Ssm for SsmClient {
         input: GetParametersRequest,
     ) -> Result<GetParametersResult, RusotoError<GetParametersError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetParameters");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.GetParameters");
@@ -17634,6 +25863,7 @@ impl Ssm for SsmClient {
         input: GetParametersByPathRequest,
     ) -> Result<GetParametersByPathResult, RusotoError<GetParametersByPathError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetParametersByPath");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.GetParametersByPath");
@@ -17662,6 +25892,7 @@ impl Ssm for SsmClient {
         input: GetPatchBaselineRequest,
     ) -> Result<GetPatchBaselineResult, RusotoError<GetPatchBaselineError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetPatchBaseline");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.GetPatchBaseline");
@@ -17690,6 +25921,7 @@ impl Ssm for SsmClient {
     ) -> Result<GetPatchBaselineForPatchGroupResult, RusotoError<GetPatchBaselineForPatchGroupError>>
     {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetPatchBaselineForPatchGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.GetPatchBaselineForPatchGroup");
@@ -17718,6 +25950,7 @@ impl Ssm for SsmClient {
         input: GetServiceSettingRequest,
     ) -> Result<GetServiceSettingResult, RusotoError<GetServiceSettingError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetServiceSetting");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.GetServiceSetting");
@@ -17745,6 +25978,7 @@ impl Ssm for SsmClient {
         input: LabelParameterVersionRequest,
     ) -> Result<LabelParameterVersionResult, RusotoError<LabelParameterVersionError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("LabelParameterVersion");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.LabelParameterVersion");
@@ -17773,6 +26007,7 @@ impl Ssm for SsmClient {
         input: ListAssociationVersionsRequest,
     ) -> Result<ListAssociationVersionsResult, RusotoError<ListAssociationVersionsError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("ListAssociationVersions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.ListAssociationVersions");
@@ -17801,6 +26036,7 @@ impl Ssm for SsmClient {
         input: ListAssociationsRequest,
     ) -> Result<ListAssociationsResult, RusotoError<ListAssociationsError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("ListAssociations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.ListAssociations");
@@ -17828,6 +26064,7 @@ impl Ssm for SsmClient {
         input: ListCommandInvocationsRequest,
     ) -> Result<ListCommandInvocationsResult, RusotoError<ListCommandInvocationsError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("ListCommandInvocations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.ListCommandInvocations");
@@ -17856,6 +26093,7 @@ impl Ssm for SsmClient {
         input: ListCommandsRequest,
     ) -> Result<ListCommandsResult, RusotoError<ListCommandsError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("ListCommands");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.ListCommands");
@@ -17883,6 +26121,7 @@ impl Ssm for SsmClient {
         input: ListComplianceItemsRequest,
     ) -> Result<ListComplianceItemsResult, RusotoError<ListComplianceItemsError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("ListComplianceItems");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.ListComplianceItems");
@@ -17911,6 +26150,7 @@ impl Ssm for SsmClient {
         input: ListComplianceSummariesRequest,
     ) -> Result<ListComplianceSummariesResult, RusotoError<ListComplianceSummariesError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("ListComplianceSummaries");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.ListComplianceSummaries");
@@ -17939,6 +26179,7 @@ impl Ssm for SsmClient {
         input: ListDocumentVersionsRequest,
     ) -> Result<ListDocumentVersionsResult, RusotoError<ListDocumentVersionsError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("ListDocumentVersions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.ListDocumentVersions");
@@ -17967,6 +26208,7 @@ impl Ssm for SsmClient {
         input: ListDocumentsRequest,
     ) -> Result<ListDocumentsResult, RusotoError<ListDocumentsError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("ListDocuments");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.ListDocuments");
@@ -17994,6 +26236,7 @@ impl Ssm for SsmClient {
         input: ListInventoryEntriesRequest,
     ) -> Result<ListInventoryEntriesResult, RusotoError<ListInventoryEntriesError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("ListInventoryEntries");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.ListInventoryEntries");
@@ -18025,6 +26268,7 @@ impl Ssm for SsmClient {
         RusotoError<ListResourceComplianceSummariesError>,
     > {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("ListResourceComplianceSummaries");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.ListResourceComplianceSummaries");
@@ -18055,6 +26299,7 @@ impl Ssm for SsmClient {
         input: ListResourceDataSyncRequest,
     ) -> Result<ListResourceDataSyncResult, RusotoError<ListResourceDataSyncError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("ListResourceDataSync");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.ListResourceDataSync");
@@ -18083,6 +26328,7 @@ impl Ssm for SsmClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResult, RusotoError<ListTagsForResourceError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.ListTagsForResource");
@@ -18111,6 +26357,7 @@ impl Ssm for SsmClient {
         input: ModifyDocumentPermissionRequest,
     ) -> Result<ModifyDocumentPermissionResponse, RusotoError<ModifyDocumentPermissionError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("ModifyDocumentPermission");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.ModifyDocumentPermission");
@@ -18139,6 +26386,7 @@ impl Ssm for SsmClient {
         input: PutComplianceItemsRequest,
     ) -> Result<PutComplianceItemsResult, RusotoError<PutComplianceItemsError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("PutComplianceItems");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.PutComplianceItems");
@@ -18167,6 +26415,7 @@ impl Ssm for SsmClient {
         input: PutInventoryRequest,
     ) -> Result<PutInventoryResult, RusotoError<PutInventoryError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("PutInventory");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.PutInventory");
@@ -18194,6 +26443,7 @@ impl Ssm for SsmClient {
         input: PutParameterRequest,
     ) -> Result<PutParameterResult, RusotoError<PutParameterError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("PutParameter");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.PutParameter");
@@ -18222,6 +26472,7 @@ impl Ssm for SsmClient {
     ) -> Result<RegisterDefaultPatchBaselineResult, RusotoError<RegisterDefaultPatchBaselineError>>
     {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("RegisterDefaultPatchBaseline");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.RegisterDefaultPatchBaseline");
@@ -18253,6 +26504,7 @@ impl Ssm for SsmClient {
         RusotoError<RegisterPatchBaselineForPatchGroupError>,
     > {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("RegisterPatchBaselineForPatchGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -18289,6 +26541,7 @@ impl Ssm for SsmClient {
         RusotoError<RegisterTargetWithMaintenanceWindowError>,
     > {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("RegisterTargetWithMaintenanceWindow");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -18325,6 +26578,7 @@ impl Ssm for SsmClient {
         RusotoError<RegisterTaskWithMaintenanceWindowError>,
     > {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("RegisterTaskWithMaintenanceWindow");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -18358,6 +26612,7 @@ impl Ssm for SsmClient {
         input: RemoveTagsFromResourceRequest,
     ) -> Result<RemoveTagsFromResourceResult, RusotoError<RemoveTagsFromResourceError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("RemoveTagsFromResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.RemoveTagsFromResource");
@@ -18386,6 +26641,7 @@ impl Ssm for SsmClient {
         input: ResetServiceSettingRequest,
     ) -> Result<ResetServiceSettingResult, RusotoError<ResetServiceSettingError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("ResetServiceSetting");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.ResetServiceSetting");
@@ -18414,6 +26670,7 @@ impl Ssm for SsmClient {
         input: ResumeSessionRequest,
     ) -> Result<ResumeSessionResponse, RusotoError<ResumeSessionError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("ResumeSession");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.ResumeSession");
@@ -18441,6 +26698,7 @@ impl Ssm for SsmClient {
         input: SendAutomationSignalRequest,
     ) -> Result<SendAutomationSignalResult, RusotoError<SendAutomationSignalError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("SendAutomationSignal");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.SendAutomationSignal");
@@ -18469,6 +26727,7 @@ impl Ssm for SsmClient {
         input: SendCommandRequest,
     ) -> Result<SendCommandResult, RusotoError<SendCommandError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("SendCommand");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.SendCommand");
@@ -18496,6 +26755,7 @@ impl Ssm for SsmClient {
         input: StartAssociationsOnceRequest,
     ) -> Result<StartAssociationsOnceResult, RusotoError<StartAssociationsOnceError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("StartAssociationsOnce");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.StartAssociationsOnce");
@@ -18524,6 +26784,7 @@ impl Ssm for SsmClient {
         input: StartAutomationExecutionRequest,
     ) -> Result<StartAutomationExecutionResult, RusotoError<StartAutomationExecutionError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("StartAutomationExecution");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.StartAutomationExecution");
@@ -18552,6 +26813,7 @@ impl Ssm for SsmClient {
         input: StartSessionRequest,
     ) -> Result<StartSessionResponse, RusotoError<StartSessionError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("StartSession");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.StartSession");
@@ -18579,6 +26841,7 @@ impl Ssm for SsmClient {
         input: StopAutomationExecutionRequest,
     ) -> Result<StopAutomationExecutionResult, RusotoError<StopAutomationExecutionError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("StopAutomationExecution");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.StopAutomationExecution");
@@ -18607,6 +26870,7 @@ impl Ssm for SsmClient {
         input: TerminateSessionRequest,
     ) -> Result<TerminateSessionResponse, RusotoError<TerminateSessionError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("TerminateSession");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.TerminateSession");
@@ -18635,6 +26899,7 @@ impl Ssm for SsmClient {
         input: UpdateAssociationRequest,
     ) -> Result<UpdateAssociationResult, RusotoError<UpdateAssociationError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("UpdateAssociation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.UpdateAssociation");
@@ -18662,6 +26927,7 @@ impl Ssm for SsmClient {
         input: UpdateAssociationStatusRequest,
     ) -> Result<UpdateAssociationStatusResult, RusotoError<UpdateAssociationStatusError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("UpdateAssociationStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.UpdateAssociationStatus");
@@ -18690,6 +26956,7 @@ impl Ssm for SsmClient {
         input: UpdateDocumentRequest,
     ) -> Result<UpdateDocumentResult, RusotoError<UpdateDocumentError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("UpdateDocument");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.UpdateDocument");
@@ -18718,6 +26985,7 @@ impl Ssm for SsmClient {
     ) -> Result<UpdateDocumentDefaultVersionResult, RusotoError<UpdateDocumentDefaultVersionError>>
     {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("UpdateDocumentDefaultVersion");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.UpdateDocumentDefaultVersion");
@@ -18746,6 +27014,7 @@ impl Ssm for SsmClient {
         input: UpdateMaintenanceWindowRequest,
     ) -> Result<UpdateMaintenanceWindowResult, RusotoError<UpdateMaintenanceWindowError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("UpdateMaintenanceWindow");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.UpdateMaintenanceWindow");
@@ -18775,6 +27044,7 @@ impl Ssm for SsmClient {
     ) -> Result<UpdateMaintenanceWindowTargetResult, RusotoError<UpdateMaintenanceWindowTargetError>>
     {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("UpdateMaintenanceWindowTarget");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.UpdateMaintenanceWindowTarget");
@@ -18804,6 +27074,7 @@ impl Ssm for SsmClient {
     ) -> Result<UpdateMaintenanceWindowTaskResult, RusotoError<UpdateMaintenanceWindowTaskError>>
     {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("UpdateMaintenanceWindowTask");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.UpdateMaintenanceWindowTask");
@@ -18832,6 +27103,7 @@ impl Ssm for SsmClient {
         input: UpdateManagedInstanceRoleRequest,
     ) -> Result<UpdateManagedInstanceRoleResult, RusotoError<UpdateManagedInstanceRoleError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("UpdateManagedInstanceRole");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.UpdateManagedInstanceRole");
@@ -18860,6 +27132,7 @@ impl Ssm for SsmClient {
         input: UpdateOpsItemRequest,
     ) -> Result<UpdateOpsItemResponse, RusotoError<UpdateOpsItemError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("UpdateOpsItem");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.UpdateOpsItem");
@@ -18887,6 +27160,7 @@ impl Ssm for SsmClient {
         input: UpdatePatchBaselineRequest,
     ) -> Result<UpdatePatchBaselineResult, RusotoError<UpdatePatchBaselineError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("UpdatePatchBaseline");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.UpdatePatchBaseline");
@@ -18915,6 +27189,7 @@ impl Ssm for SsmClient {
         input: UpdateResourceDataSyncRequest,
     ) -> Result<UpdateResourceDataSyncResult, RusotoError<UpdateResourceDataSyncError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("UpdateResourceDataSync");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.UpdateResourceDataSync");
@@ -18943,6 +27218,7 @@ impl Ssm for SsmClient {
         input: UpdateServiceSettingRequest,
     ) -> Result<UpdateServiceSettingResult, RusotoError<UpdateServiceSettingError>> {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("UpdateServiceSetting");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.UpdateServiceSetting");
@@ -18965,3 +27241,1668 @@ impl Ssm for SsmClient {
         }
     }
 }
+impl SsmClient {
+    /// Auto-paginating version of `describe_activations`, streaming every page of results.
+    pub fn describe_activations_pages(
+        &self,
+        input: DescribeActivationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeActivationsResult, DescribeActivationsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input: DescribeActivationsRequest| async move {
+                client.describe_activations(input).await
+            },
+            |input: &DescribeActivationsRequest, output: &DescribeActivationsResult| {
+                Some(DescribeActivationsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_activations`, streaming the `ActivationList` of every page of results.
+    pub fn describe_activations_items(
+        &self,
+        input: DescribeActivationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Activation, DescribeActivationsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_activations_pages(input),
+            |output: DescribeActivationsResult| output.activation_list.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_association_execution_targets`, streaming every page of results.
+    pub fn describe_association_execution_targets_pages(
+        &self,
+        input: DescribeAssociationExecutionTargetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeAssociationExecutionTargetsResult,
+        DescribeAssociationExecutionTargetsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input: DescribeAssociationExecutionTargetsRequest| async move {
+                client.describe_association_execution_targets(input).await
+            },
+            |input: &DescribeAssociationExecutionTargetsRequest,
+             output: &DescribeAssociationExecutionTargetsResult| {
+                Some(DescribeAssociationExecutionTargetsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_association_execution_targets`, streaming the `AssociationExecutionTargets` of every page of results.
+    pub fn describe_association_execution_targets_items(
+        &self,
+        input: DescribeAssociationExecutionTargetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        AssociationExecutionTarget,
+        DescribeAssociationExecutionTargetsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_association_execution_targets_pages(input),
+            |output: DescribeAssociationExecutionTargetsResult| {
+                output.association_execution_targets.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_association_executions`, streaming every page of results.
+    pub fn describe_association_executions_pages(
+        &self,
+        input: DescribeAssociationExecutionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeAssociationExecutionsResult,
+        DescribeAssociationExecutionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input: DescribeAssociationExecutionsRequest| async move {
+                client.describe_association_executions(input).await
+            },
+            |input: &DescribeAssociationExecutionsRequest,
+             output: &DescribeAssociationExecutionsResult| {
+                Some(DescribeAssociationExecutionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_association_executions`, streaming the `AssociationExecutions` of every page of results.
+    pub fn describe_association_executions_items(
+        &self,
+        input: DescribeAssociationExecutionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        AssociationExecution,
+        DescribeAssociationExecutionsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_association_executions_pages(input),
+            |output: DescribeAssociationExecutionsResult| {
+                output.association_executions.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_automation_executions`, streaming every page of results.
+    pub fn describe_automation_executions_pages(
+        &self,
+        input: DescribeAutomationExecutionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeAutomationExecutionsResult,
+        DescribeAutomationExecutionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input: DescribeAutomationExecutionsRequest| async move {
+                client.describe_automation_executions(input).await
+            },
+            |input: &DescribeAutomationExecutionsRequest,
+             output: &DescribeAutomationExecutionsResult| {
+                Some(DescribeAutomationExecutionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_automation_executions`, streaming the `AutomationExecutionMetadataList` of every page of results.
+    pub fn describe_automation_executions_items(
+        &self,
+        input: DescribeAutomationExecutionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        AutomationExecutionMetadata,
+        DescribeAutomationExecutionsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_automation_executions_pages(input),
+            |output: DescribeAutomationExecutionsResult| {
+                output
+                    .automation_execution_metadata_list
+                    .unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_automation_step_executions`, streaming every page of results.
+    pub fn describe_automation_step_executions_pages(
+        &self,
+        input: DescribeAutomationStepExecutionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeAutomationStepExecutionsResult,
+        DescribeAutomationStepExecutionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input: DescribeAutomationStepExecutionsRequest| async move {
+                client.describe_automation_step_executions(input).await
+            },
+            |input: &DescribeAutomationStepExecutionsRequest,
+             output: &DescribeAutomationStepExecutionsResult| {
+                Some(DescribeAutomationStepExecutionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_automation_step_executions`, streaming the `StepExecutions` of every page of results.
+    pub fn describe_automation_step_executions_items(
+        &self,
+        input: DescribeAutomationStepExecutionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<StepExecution, DescribeAutomationStepExecutionsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_automation_step_executions_pages(input),
+            |output: DescribeAutomationStepExecutionsResult| {
+                output.step_executions.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_available_patches`, streaming every page of results.
+    pub fn describe_available_patches_pages(
+        &self,
+        input: DescribeAvailablePatchesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeAvailablePatchesResult,
+        DescribeAvailablePatchesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input: DescribeAvailablePatchesRequest| async move {
+                client.describe_available_patches(input).await
+            },
+            |input: &DescribeAvailablePatchesRequest, output: &DescribeAvailablePatchesResult| {
+                Some(DescribeAvailablePatchesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_available_patches`, streaming the `Patches` of every page of results.
+    pub fn describe_available_patches_items(
+        &self,
+        input: DescribeAvailablePatchesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Patch, DescribeAvailablePatchesError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_available_patches_pages(input),
+            |output: DescribeAvailablePatchesResult| output.patches.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_effective_instance_associations`, streaming every page of results.
+    pub fn describe_effective_instance_associations_pages(
+        &self,
+        input: DescribeEffectiveInstanceAssociationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeEffectiveInstanceAssociationsResult,
+        DescribeEffectiveInstanceAssociationsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input: DescribeEffectiveInstanceAssociationsRequest| async move {
+                client.describe_effective_instance_associations(input).await
+            },
+            |input: &DescribeEffectiveInstanceAssociationsRequest,
+             output: &DescribeEffectiveInstanceAssociationsResult| {
+                Some(DescribeEffectiveInstanceAssociationsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_effective_instance_associations`, streaming the `Associations` of every page of results.
+    pub fn describe_effective_instance_associations_items(
+        &self,
+        input: DescribeEffectiveInstanceAssociationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        InstanceAssociation,
+        DescribeEffectiveInstanceAssociationsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_effective_instance_associations_pages(input),
+            |output: DescribeEffectiveInstanceAssociationsResult| {
+                output.associations.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_effective_patches_for_patch_baseline`, streaming every page of results.
+    pub fn describe_effective_patches_for_patch_baseline_pages(
+        &self,
+        input: DescribeEffectivePatchesForPatchBaselineRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeEffectivePatchesForPatchBaselineResult,
+        DescribeEffectivePatchesForPatchBaselineError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input: DescribeEffectivePatchesForPatchBaselineRequest| async move {
+                client
+                    .describe_effective_patches_for_patch_baseline(input)
+                    .await
+            },
+            |input: &DescribeEffectivePatchesForPatchBaselineRequest,
+             output: &DescribeEffectivePatchesForPatchBaselineResult| {
+                Some(DescribeEffectivePatchesForPatchBaselineRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_effective_patches_for_patch_baseline`, streaming the `EffectivePatches` of every page of results.
+    pub fn describe_effective_patches_for_patch_baseline_items(
+        &self,
+        input: DescribeEffectivePatchesForPatchBaselineRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        EffectivePatch,
+        DescribeEffectivePatchesForPatchBaselineError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_effective_patches_for_patch_baseline_pages(input),
+            |output: DescribeEffectivePatchesForPatchBaselineResult| {
+                output.effective_patches.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_instance_associations_status`, streaming every page of results.
+    pub fn describe_instance_associations_status_pages(
+        &self,
+        input: DescribeInstanceAssociationsStatusRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeInstanceAssociationsStatusResult,
+        DescribeInstanceAssociationsStatusError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input: DescribeInstanceAssociationsStatusRequest| async move {
+                client.describe_instance_associations_status(input).await
+            },
+            |input: &DescribeInstanceAssociationsStatusRequest,
+             output: &DescribeInstanceAssociationsStatusResult| {
+                Some(DescribeInstanceAssociationsStatusRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_instance_associations_status`, streaming the `InstanceAssociationStatusInfos` of every page of results.
+    pub fn describe_instance_associations_status_items(
+        &self,
+        input: DescribeInstanceAssociationsStatusRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        InstanceAssociationStatusInfo,
+        DescribeInstanceAssociationsStatusError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_instance_associations_status_pages(input),
+            |output: DescribeInstanceAssociationsStatusResult| {
+                output.instance_association_status_infos.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_instance_information`, streaming every page of results.
+    pub fn describe_instance_information_pages(
+        &self,
+        input: DescribeInstanceInformationRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeInstanceInformationResult,
+        DescribeInstanceInformationError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input: DescribeInstanceInformationRequest| async move {
+                client.describe_instance_information(input).await
+            },
+            |input: &DescribeInstanceInformationRequest,
+             output: &DescribeInstanceInformationResult| {
+                Some(DescribeInstanceInformationRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_instance_information`, streaming the `InstanceInformationList` of every page of results.
+    pub fn describe_instance_information_items(
+        &self,
+        input: DescribeInstanceInformationRequest,
+    ) -> rusoto_core::pagination::RusotoStream<InstanceInformation, DescribeInstanceInformationError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_instance_information_pages(input),
+            |output: DescribeInstanceInformationResult| {
+                output.instance_information_list.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_instance_patch_states`, streaming every page of results.
+    pub fn describe_instance_patch_states_pages(
+        &self,
+        input: DescribeInstancePatchStatesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeInstancePatchStatesResult,
+        DescribeInstancePatchStatesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input: DescribeInstancePatchStatesRequest| async move {
+                client.describe_instance_patch_states(input).await
+            },
+            |input: &DescribeInstancePatchStatesRequest,
+             output: &DescribeInstancePatchStatesResult| {
+                Some(DescribeInstancePatchStatesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_instance_patch_states`, streaming the `InstancePatchStates` of every page of results.
+    pub fn describe_instance_patch_states_items(
+        &self,
+        input: DescribeInstancePatchStatesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<InstancePatchState, DescribeInstancePatchStatesError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_instance_patch_states_pages(input),
+            |output: DescribeInstancePatchStatesResult| {
+                output.instance_patch_states.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_instance_patch_states_for_patch_group`, streaming every page of results.
+    pub fn describe_instance_patch_states_for_patch_group_pages(
+        &self,
+        input: DescribeInstancePatchStatesForPatchGroupRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeInstancePatchStatesForPatchGroupResult,
+        DescribeInstancePatchStatesForPatchGroupError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input: DescribeInstancePatchStatesForPatchGroupRequest| async move {
+                client
+                    .describe_instance_patch_states_for_patch_group(input)
+                    .await
+            },
+            |input: &DescribeInstancePatchStatesForPatchGroupRequest,
+             output: &DescribeInstancePatchStatesForPatchGroupResult| {
+                Some(DescribeInstancePatchStatesForPatchGroupRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_instance_patch_states_for_patch_group`, streaming the `InstancePatchStates` of every page of results.
+    pub fn describe_instance_patch_states_for_patch_group_items(
+        &self,
+        input: DescribeInstancePatchStatesForPatchGroupRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        InstancePatchState,
+        DescribeInstancePatchStatesForPatchGroupError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_instance_patch_states_for_patch_group_pages(input),
+            |output: DescribeInstancePatchStatesForPatchGroupResult| {
+                output.instance_patch_states.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_instance_patches`, streaming every page of results.
+    pub fn describe_instance_patches_pages(
+        &self,
+        input: DescribeInstancePatchesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeInstancePatchesResult,
+        DescribeInstancePatchesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input: DescribeInstancePatchesRequest| async move {
+                client.describe_instance_patches(input).await
+            },
+            |input: &DescribeInstancePatchesRequest, output: &DescribeInstancePatchesResult| {
+                Some(DescribeInstancePatchesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_instance_patches`, streaming the `Patches` of every page of results.
+    pub fn describe_instance_patches_items(
+        &self,
+        input: DescribeInstancePatchesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<PatchComplianceData, DescribeInstancePatchesError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_instance_patches_pages(input),
+            |output: DescribeInstancePatchesResult| output.patches.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_inventory_deletions`, streaming every page of results.
+    pub fn describe_inventory_deletions_pages(
+        &self,
+        input: DescribeInventoryDeletionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeInventoryDeletionsResult,
+        DescribeInventoryDeletionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input: DescribeInventoryDeletionsRequest| async move {
+                client.describe_inventory_deletions(input).await
+            },
+            |input: &DescribeInventoryDeletionsRequest,
+             output: &DescribeInventoryDeletionsResult| {
+                Some(DescribeInventoryDeletionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_inventory_deletions`, streaming the `InventoryDeletions` of every page of results.
+    pub fn describe_inventory_deletions_items(
+        &self,
+        input: DescribeInventoryDeletionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        InventoryDeletionStatusItem,
+        DescribeInventoryDeletionsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_inventory_deletions_pages(input),
+            |output: DescribeInventoryDeletionsResult| {
+                output.inventory_deletions.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_maintenance_window_execution_task_invocations`, streaming every page of results.
+    pub fn describe_maintenance_window_execution_task_invocations_pages(
+        &self,
+        input: DescribeMaintenanceWindowExecutionTaskInvocationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeMaintenanceWindowExecutionTaskInvocationsResult,
+        DescribeMaintenanceWindowExecutionTaskInvocationsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input: DescribeMaintenanceWindowExecutionTaskInvocationsRequest| async move {
+                client
+                    .describe_maintenance_window_execution_task_invocations(input)
+                    .await
+            },
+            |input: &DescribeMaintenanceWindowExecutionTaskInvocationsRequest,
+             output: &DescribeMaintenanceWindowExecutionTaskInvocationsResult| {
+                Some(DescribeMaintenanceWindowExecutionTaskInvocationsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_maintenance_window_execution_task_invocations`, streaming the `WindowExecutionTaskInvocationIdentities` of every page of results.
+    pub fn describe_maintenance_window_execution_task_invocations_items(
+        &self,
+        input: DescribeMaintenanceWindowExecutionTaskInvocationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        MaintenanceWindowExecutionTaskInvocationIdentity,
+        DescribeMaintenanceWindowExecutionTaskInvocationsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_maintenance_window_execution_task_invocations_pages(input),
+            |output: DescribeMaintenanceWindowExecutionTaskInvocationsResult| {
+                output
+                    .window_execution_task_invocation_identities
+                    .unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_maintenance_window_execution_tasks`, streaming every page of results.
+    pub fn describe_maintenance_window_execution_tasks_pages(
+        &self,
+        input: DescribeMaintenanceWindowExecutionTasksRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeMaintenanceWindowExecutionTasksResult,
+        DescribeMaintenanceWindowExecutionTasksError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input: DescribeMaintenanceWindowExecutionTasksRequest| async move {
+                client
+                    .describe_maintenance_window_execution_tasks(input)
+                    .await
+            },
+            |input: &DescribeMaintenanceWindowExecutionTasksRequest,
+             output: &DescribeMaintenanceWindowExecutionTasksResult| {
+                Some(DescribeMaintenanceWindowExecutionTasksRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_maintenance_window_execution_tasks`, streaming the `WindowExecutionTaskIdentities` of every page of results.
+    pub fn describe_maintenance_window_execution_tasks_items(
+        &self,
+        input: DescribeMaintenanceWindowExecutionTasksRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        MaintenanceWindowExecutionTaskIdentity,
+        DescribeMaintenanceWindowExecutionTasksError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_maintenance_window_execution_tasks_pages(input),
+            |output: DescribeMaintenanceWindowExecutionTasksResult| {
+                output.window_execution_task_identities.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_maintenance_window_executions`, streaming every page of results.
+    pub fn describe_maintenance_window_executions_pages(
+        &self,
+        input: DescribeMaintenanceWindowExecutionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeMaintenanceWindowExecutionsResult,
+        DescribeMaintenanceWindowExecutionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input: DescribeMaintenanceWindowExecutionsRequest| async move {
+                client.describe_maintenance_window_executions(input).await
+            },
+            |input: &DescribeMaintenanceWindowExecutionsRequest,
+             output: &DescribeMaintenanceWindowExecutionsResult| {
+                Some(DescribeMaintenanceWindowExecutionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_maintenance_window_executions`, streaming the `WindowExecutions` of every page of results.
+    pub fn describe_maintenance_window_executions_items(
+        &self,
+        input: DescribeMaintenanceWindowExecutionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        MaintenanceWindowExecution,
+        DescribeMaintenanceWindowExecutionsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_maintenance_window_executions_pages(input),
+            |output: DescribeMaintenanceWindowExecutionsResult| {
+                output.window_executions.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_maintenance_window_schedule`, streaming every page of results.
+    pub fn describe_maintenance_window_schedule_pages(
+        &self,
+        input: DescribeMaintenanceWindowScheduleRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeMaintenanceWindowScheduleResult,
+        DescribeMaintenanceWindowScheduleError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input: DescribeMaintenanceWindowScheduleRequest| async move {
+                client.describe_maintenance_window_schedule(input).await
+            },
+            |input: &DescribeMaintenanceWindowScheduleRequest,
+             output: &DescribeMaintenanceWindowScheduleResult| {
+                Some(DescribeMaintenanceWindowScheduleRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_maintenance_window_schedule`, streaming the `ScheduledWindowExecutions` of every page of results.
+    pub fn describe_maintenance_window_schedule_items(
+        &self,
+        input: DescribeMaintenanceWindowScheduleRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ScheduledWindowExecution,
+        DescribeMaintenanceWindowScheduleError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_maintenance_window_schedule_pages(input),
+            |output: DescribeMaintenanceWindowScheduleResult| {
+                output.scheduled_window_executions.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_maintenance_window_targets`, streaming every page of results.
+    pub fn describe_maintenance_window_targets_pages(
+        &self,
+        input: DescribeMaintenanceWindowTargetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeMaintenanceWindowTargetsResult,
+        DescribeMaintenanceWindowTargetsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input: DescribeMaintenanceWindowTargetsRequest| async move {
+                client.describe_maintenance_window_targets(input).await
+            },
+            |input: &DescribeMaintenanceWindowTargetsRequest,
+             output: &DescribeMaintenanceWindowTargetsResult| {
+                Some(DescribeMaintenanceWindowTargetsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_maintenance_window_targets`, streaming the `Targets` of every page of results.
+    pub fn describe_maintenance_window_targets_items(
+        &self,
+        input: DescribeMaintenanceWindowTargetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        MaintenanceWindowTarget,
+        DescribeMaintenanceWindowTargetsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_maintenance_window_targets_pages(input),
+            |output: DescribeMaintenanceWindowTargetsResult| output.targets.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_maintenance_window_tasks`, streaming every page of results.
+    pub fn describe_maintenance_window_tasks_pages(
+        &self,
+        input: DescribeMaintenanceWindowTasksRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeMaintenanceWindowTasksResult,
+        DescribeMaintenanceWindowTasksError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input: DescribeMaintenanceWindowTasksRequest| async move {
+                client.describe_maintenance_window_tasks(input).await
+            },
+            |input: &DescribeMaintenanceWindowTasksRequest,
+             output: &DescribeMaintenanceWindowTasksResult| {
+                Some(DescribeMaintenanceWindowTasksRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_maintenance_window_tasks`, streaming the `Tasks` of every page of results.
+    pub fn describe_maintenance_window_tasks_items(
+        &self,
+        input: DescribeMaintenanceWindowTasksRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        MaintenanceWindowTask,
+        DescribeMaintenanceWindowTasksError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_maintenance_window_tasks_pages(input),
+            |output: DescribeMaintenanceWindowTasksResult| output.tasks.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_maintenance_windows`, streaming every page of results.
+    pub fn describe_maintenance_windows_pages(
+        &self,
+        input: DescribeMaintenanceWindowsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeMaintenanceWindowsResult,
+        DescribeMaintenanceWindowsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input: DescribeMaintenanceWindowsRequest| async move {
+                client.describe_maintenance_windows(input).await
+            },
+            |input: &DescribeMaintenanceWindowsRequest,
+             output: &DescribeMaintenanceWindowsResult| {
+                Some(DescribeMaintenanceWindowsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_maintenance_windows`, streaming the `WindowIdentities` of every page of results.
+    pub fn describe_maintenance_windows_items(
+        &self,
+        input: DescribeMaintenanceWindowsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        MaintenanceWindowIdentity,
+        DescribeMaintenanceWindowsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_maintenance_windows_pages(input),
+            |output: DescribeMaintenanceWindowsResult| output.window_identities.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_maintenance_windows_for_target`, streaming every page of results.
+    pub fn describe_maintenance_windows_for_target_pages(
+        &self,
+        input: DescribeMaintenanceWindowsForTargetRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeMaintenanceWindowsForTargetResult,
+        DescribeMaintenanceWindowsForTargetError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input: DescribeMaintenanceWindowsForTargetRequest| async move {
+                client.describe_maintenance_windows_for_target(input).await
+            },
+            |input: &DescribeMaintenanceWindowsForTargetRequest,
+             output: &DescribeMaintenanceWindowsForTargetResult| {
+                Some(DescribeMaintenanceWindowsForTargetRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_maintenance_windows_for_target`, streaming the `WindowIdentities` of every page of results.
+    pub fn describe_maintenance_windows_for_target_items(
+        &self,
+        input: DescribeMaintenanceWindowsForTargetRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        MaintenanceWindowIdentityForTarget,
+        DescribeMaintenanceWindowsForTargetError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_maintenance_windows_for_target_pages(input),
+            |output: DescribeMaintenanceWindowsForTargetResult| {
+                output.window_identities.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_ops_items`, streaming every page of results.
+    pub fn describe_ops_items_pages(
+        &self,
+        input: DescribeOpsItemsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeOpsItemsResponse, DescribeOpsItemsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input: DescribeOpsItemsRequest| async move {
+                client.describe_ops_items(input).await
+            },
+            |input: &DescribeOpsItemsRequest, output: &DescribeOpsItemsResponse| {
+                Some(DescribeOpsItemsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_ops_items`, streaming the `OpsItemSummaries` of every page of results.
+    pub fn describe_ops_items_items(
+        &self,
+        input: DescribeOpsItemsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<OpsItemSummary, DescribeOpsItemsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_ops_items_pages(input),
+            |output: DescribeOpsItemsResponse| output.ops_item_summaries.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_parameters`, streaming every page of results.
+    pub fn describe_parameters_pages(
+        &self,
+        input: DescribeParametersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeParametersResult, DescribeParametersError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input: DescribeParametersRequest| async move {
+                client.describe_parameters(input).await
+            },
+            |input: &DescribeParametersRequest, output: &DescribeParametersResult| {
+                Some(DescribeParametersRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_parameters`, streaming the `Parameters` of every page of results.
+    pub fn describe_parameters_items(
+        &self,
+        input: DescribeParametersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ParameterMetadata, DescribeParametersError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_parameters_pages(input),
+            |output: DescribeParametersResult| output.parameters.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_patch_baselines`, streaming every page of results.
+    pub fn describe_patch_baselines_pages(
+        &self,
+        input: DescribePatchBaselinesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribePatchBaselinesResult,
+        DescribePatchBaselinesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input: DescribePatchBaselinesRequest| async move {
+                client.describe_patch_baselines(input).await
+            },
+            |input: &DescribePatchBaselinesRequest, output: &DescribePatchBaselinesResult| {
+                Some(DescribePatchBaselinesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_patch_baselines`, streaming the `BaselineIdentities` of every page of results.
+    pub fn describe_patch_baselines_items(
+        &self,
+        input: DescribePatchBaselinesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<PatchBaselineIdentity, DescribePatchBaselinesError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_patch_baselines_pages(input),
+            |output: DescribePatchBaselinesResult| output.baseline_identities.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_patch_groups`, streaming every page of results.
+    pub fn describe_patch_groups_pages(
+        &self,
+        input: DescribePatchGroupsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DescribePatchGroupsResult, DescribePatchGroupsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input: DescribePatchGroupsRequest| async move {
+                client.describe_patch_groups(input).await
+            },
+            |input: &DescribePatchGroupsRequest, output: &DescribePatchGroupsResult| {
+                Some(DescribePatchGroupsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_patch_groups`, streaming the `Mappings` of every page of results.
+    pub fn describe_patch_groups_items(
+        &self,
+        input: DescribePatchGroupsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        PatchGroupPatchBaselineMapping,
+        DescribePatchGroupsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_patch_groups_pages(input),
+            |output: DescribePatchGroupsResult| output.mappings.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_patch_properties`, streaming every page of results.
+    pub fn describe_patch_properties_pages(
+        &self,
+        input: DescribePatchPropertiesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribePatchPropertiesResult,
+        DescribePatchPropertiesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input: DescribePatchPropertiesRequest| async move {
+                client.describe_patch_properties(input).await
+            },
+            |input: &DescribePatchPropertiesRequest, output: &DescribePatchPropertiesResult| {
+                Some(DescribePatchPropertiesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_patch_properties`, streaming the `Properties` of every page of results.
+    pub fn describe_patch_properties_items(
+        &self,
+        input: DescribePatchPropertiesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ::std::collections::HashMap<String, String>,
+        DescribePatchPropertiesError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_patch_properties_pages(input),
+            |output: DescribePatchPropertiesResult| output.properties.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_sessions`, streaming every page of results.
+    pub fn describe_sessions_pages(
+        &self,
+        input: DescribeSessionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeSessionsResponse, DescribeSessionsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input: DescribeSessionsRequest| async move {
+                client.describe_sessions(input).await
+            },
+            |input: &DescribeSessionsRequest, output: &DescribeSessionsResponse| {
+                Some(DescribeSessionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_sessions`, streaming the `Sessions` of every page of results.
+    pub fn describe_sessions_items(
+        &self,
+        input: DescribeSessionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Session, DescribeSessionsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_sessions_pages(input),
+            |output: DescribeSessionsResponse| output.sessions.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_inventory`, streaming every page of results.
+    pub fn get_inventory_pages(
+        &self,
+        input: GetInventoryRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetInventoryResult, GetInventoryError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input: GetInventoryRequest| async move {
+                client.get_inventory(input).await
+            },
+            |input: &GetInventoryRequest, output: &GetInventoryResult| {
+                Some(GetInventoryRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_inventory`, streaming the `Entities` of every page of results.
+    pub fn get_inventory_items(
+        &self,
+        input: GetInventoryRequest,
+    ) -> rusoto_core::pagination::RusotoStream<InventoryResultEntity, GetInventoryError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_inventory_pages(input),
+            |output: GetInventoryResult| output.entities.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_inventory_schema`, streaming every page of results.
+    pub fn get_inventory_schema_pages(
+        &self,
+        input: GetInventorySchemaRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetInventorySchemaResult, GetInventorySchemaError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input: GetInventorySchemaRequest| async move {
+                client.get_inventory_schema(input).await
+            },
+            |input: &GetInventorySchemaRequest, output: &GetInventorySchemaResult| {
+                Some(GetInventorySchemaRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_inventory_schema`, streaming the `Schemas` of every page of results.
+    pub fn get_inventory_schema_items(
+        &self,
+        input: GetInventorySchemaRequest,
+    ) -> rusoto_core::pagination::RusotoStream<InventoryItemSchema, GetInventorySchemaError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_inventory_schema_pages(input),
+            |output: GetInventorySchemaResult| output.schemas.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_ops_summary`, streaming every page of results.
+    pub fn get_ops_summary_pages(
+        &self,
+        input: GetOpsSummaryRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetOpsSummaryResult, GetOpsSummaryError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input: GetOpsSummaryRequest| async move {
+                client.get_ops_summary(input).await
+            },
+            |input: &GetOpsSummaryRequest, output: &GetOpsSummaryResult| {
+                Some(GetOpsSummaryRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_ops_summary`, streaming the `Entities` of every page of results.
+    pub fn get_ops_summary_items(
+        &self,
+        input: GetOpsSummaryRequest,
+    ) -> rusoto_core::pagination::RusotoStream<OpsEntity, GetOpsSummaryError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_ops_summary_pages(input),
+            |output: GetOpsSummaryResult| output.entities.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_parameter_history`, streaming every page of results.
+    pub fn get_parameter_history_pages(
+        &self,
+        input: GetParameterHistoryRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetParameterHistoryResult, GetParameterHistoryError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input: GetParameterHistoryRequest| async move {
+                client.get_parameter_history(input).await
+            },
+            |input: &GetParameterHistoryRequest, output: &GetParameterHistoryResult| {
+                Some(GetParameterHistoryRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_parameter_history`, streaming the `Parameters` of every page of results.
+    pub fn get_parameter_history_items(
+        &self,
+        input: GetParameterHistoryRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ParameterHistory, GetParameterHistoryError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_parameter_history_pages(input),
+            |output: GetParameterHistoryResult| output.parameters.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_parameters_by_path`, streaming every page of results.
+    pub fn get_parameters_by_path_pages(
+        &self,
+        input: GetParametersByPathRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetParametersByPathResult, GetParametersByPathError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input: GetParametersByPathRequest| async move {
+                client.get_parameters_by_path(input).await
+            },
+            |input: &GetParametersByPathRequest, output: &GetParametersByPathResult| {
+                Some(GetParametersByPathRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_parameters_by_path`, streaming the `Parameters` of every page of results.
+    pub fn get_parameters_by_path_items(
+        &self,
+        input: GetParametersByPathRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Parameter, GetParametersByPathError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_parameters_by_path_pages(input),
+            |output: GetParametersByPathResult| output.parameters.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_association_versions`, streaming every page of results.
+    pub fn list_association_versions_pages(
+        &self,
+        input: ListAssociationVersionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListAssociationVersionsResult,
+        ListAssociationVersionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input: ListAssociationVersionsRequest| async move {
+                client.list_association_versions(input).await
+            },
+            |input: &ListAssociationVersionsRequest, output: &ListAssociationVersionsResult| {
+                Some(ListAssociationVersionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_association_versions`, streaming the `AssociationVersions` of every page of results.
+    pub fn list_association_versions_items(
+        &self,
+        input: ListAssociationVersionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<AssociationVersionInfo, ListAssociationVersionsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_association_versions_pages(input),
+            |output: ListAssociationVersionsResult| output.association_versions.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_associations`, streaming every page of results.
+    pub fn list_associations_pages(
+        &self,
+        input: ListAssociationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListAssociationsResult, ListAssociationsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input: ListAssociationsRequest| async move {
+                client.list_associations(input).await
+            },
+            |input: &ListAssociationsRequest, output: &ListAssociationsResult| {
+                Some(ListAssociationsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_associations`, streaming the `Associations` of every page of results.
+    pub fn list_associations_items(
+        &self,
+        input: ListAssociationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Association, ListAssociationsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_associations_pages(input),
+            |output: ListAssociationsResult| output.associations.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_command_invocations`, streaming every page of results.
+    pub fn list_command_invocations_pages(
+        &self,
+        input: ListCommandInvocationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListCommandInvocationsResult,
+        ListCommandInvocationsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input: ListCommandInvocationsRequest| async move {
+                client.list_command_invocations(input).await
+            },
+            |input: &ListCommandInvocationsRequest, output: &ListCommandInvocationsResult| {
+                Some(ListCommandInvocationsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_command_invocations`, streaming the `CommandInvocations` of every page of results.
+    pub fn list_command_invocations_items(
+        &self,
+        input: ListCommandInvocationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<CommandInvocation, ListCommandInvocationsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_command_invocations_pages(input),
+            |output: ListCommandInvocationsResult| output.command_invocations.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_commands`, streaming every page of results.
+    pub fn list_commands_pages(
+        &self,
+        input: ListCommandsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListCommandsResult, ListCommandsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input: ListCommandsRequest| async move {
+                client.list_commands(input).await
+            },
+            |input: &ListCommandsRequest, output: &ListCommandsResult| {
+                Some(ListCommandsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_commands`, streaming the `Commands` of every page of results.
+    pub fn list_commands_items(
+        &self,
+        input: ListCommandsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Command, ListCommandsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_commands_pages(input),
+            |output: ListCommandsResult| output.commands.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_compliance_items`, streaming every page of results.
+    pub fn list_compliance_items_pages(
+        &self,
+        input: ListComplianceItemsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListComplianceItemsResult, ListComplianceItemsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input: ListComplianceItemsRequest| async move {
+                client.list_compliance_items(input).await
+            },
+            |input: &ListComplianceItemsRequest, output: &ListComplianceItemsResult| {
+                Some(ListComplianceItemsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_compliance_items`, streaming the `ComplianceItems` of every page of results.
+    pub fn list_compliance_items_items(
+        &self,
+        input: ListComplianceItemsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ComplianceItem, ListComplianceItemsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_compliance_items_pages(input),
+            |output: ListComplianceItemsResult| output.compliance_items.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_compliance_summaries`, streaming every page of results.
+    pub fn list_compliance_summaries_pages(
+        &self,
+        input: ListComplianceSummariesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListComplianceSummariesResult,
+        ListComplianceSummariesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input: ListComplianceSummariesRequest| async move {
+                client.list_compliance_summaries(input).await
+            },
+            |input: &ListComplianceSummariesRequest, output: &ListComplianceSummariesResult| {
+                Some(ListComplianceSummariesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_compliance_summaries`, streaming the `ComplianceSummaryItems` of every page of results.
+    pub fn list_compliance_summaries_items(
+        &self,
+        input: ListComplianceSummariesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ComplianceSummaryItem, ListComplianceSummariesError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_compliance_summaries_pages(input),
+            |output: ListComplianceSummariesResult| {
+                output.compliance_summary_items.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_document_versions`, streaming every page of results.
+    pub fn list_document_versions_pages(
+        &self,
+        input: ListDocumentVersionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListDocumentVersionsResult, ListDocumentVersionsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input: ListDocumentVersionsRequest| async move {
+                client.list_document_versions(input).await
+            },
+            |input: &ListDocumentVersionsRequest, output: &ListDocumentVersionsResult| {
+                Some(ListDocumentVersionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_document_versions`, streaming the `DocumentVersions` of every page of results.
+    pub fn list_document_versions_items(
+        &self,
+        input: ListDocumentVersionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DocumentVersionInfo, ListDocumentVersionsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_document_versions_pages(input),
+            |output: ListDocumentVersionsResult| output.document_versions.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_documents`, streaming every page of results.
+    pub fn list_documents_pages(
+        &self,
+        input: ListDocumentsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListDocumentsResult, ListDocumentsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input: ListDocumentsRequest| async move {
+                client.list_documents(input).await
+            },
+            |input: &ListDocumentsRequest, output: &ListDocumentsResult| {
+                Some(ListDocumentsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_documents`, streaming the `DocumentIdentifiers` of every page of results.
+    pub fn list_documents_items(
+        &self,
+        input: ListDocumentsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DocumentIdentifier, ListDocumentsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_documents_pages(input),
+            |output: ListDocumentsResult| output.document_identifiers.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_resource_compliance_summaries`, streaming every page of results.
+    pub fn list_resource_compliance_summaries_pages(
+        &self,
+        input: ListResourceComplianceSummariesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListResourceComplianceSummariesResult,
+        ListResourceComplianceSummariesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input: ListResourceComplianceSummariesRequest| async move {
+                client.list_resource_compliance_summaries(input).await
+            },
+            |input: &ListResourceComplianceSummariesRequest,
+             output: &ListResourceComplianceSummariesResult| {
+                Some(ListResourceComplianceSummariesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_resource_compliance_summaries`, streaming the `ResourceComplianceSummaryItems` of every page of results.
+    pub fn list_resource_compliance_summaries_items(
+        &self,
+        input: ListResourceComplianceSummariesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ResourceComplianceSummaryItem,
+        ListResourceComplianceSummariesError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_resource_compliance_summaries_pages(input),
+            |output: ListResourceComplianceSummariesResult| {
+                output.resource_compliance_summary_items.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_resource_data_sync`, streaming every page of results.
+    pub fn list_resource_data_sync_pages(
+        &self,
+        input: ListResourceDataSyncRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListResourceDataSyncResult, ListResourceDataSyncError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input: ListResourceDataSyncRequest| async move {
+                client.list_resource_data_sync(input).await
+            },
+            |input: &ListResourceDataSyncRequest, output: &ListResourceDataSyncResult| {
+                Some(ListResourceDataSyncRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_resource_data_sync`, streaming the `ResourceDataSyncItems` of every page of results.
+    pub fn list_resource_data_sync_items(
+        &self,
+        input: ListResourceDataSyncRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ResourceDataSyncItem, ListResourceDataSyncError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_resource_data_sync_pages(input),
+            |output: ListResourceDataSyncResult| {
+                output.resource_data_sync_items.unwrap_or_default()
+            },
+        )
+    }
+}
+impl SsmClient {
+    /// Waits until `get_command_invocation` reports the `CommandExecuted` state, polling every 5 seconds up to 20 times.
+    pub async fn wait_until_command_executed(
+        &self,
+        input: GetCommandInvocationRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetCommandInvocationError>> {
+        let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(5), 20);
+        self.wait_until_command_executed_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_command_executed`, polling as often and as many times as `config` says.
+    pub async fn wait_until_command_executed_with_config(
+        &self,
+        input: GetCommandInvocationRequest,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetCommandInvocationError>> {
+        rusoto_core::waiter::wait(
+            "CommandExecuted",
+            &config,
+            || self.get_command_invocation(input.clone()),
+            |result: &Result<
+                GetCommandInvocationResult,
+                RusotoError<GetCommandInvocationError>,
+            >| match result {
+                Ok(output)
+                    if output
+                        .status
+                        .iter()
+                        .any(|v| *v == CommandInvocationStatus::Pending) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Retry)
+                }
+                Ok(output)
+                    if output
+                        .status
+                        .iter()
+                        .any(|v| *v == CommandInvocationStatus::InProgress) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Retry)
+                }
+                Ok(output)
+                    if output
+                        .status
+                        .iter()
+                        .any(|v| *v == CommandInvocationStatus::Delayed) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Retry)
+                }
+                Ok(output)
+                    if output
+                        .status
+                        .iter()
+                        .any(|v| *v == CommandInvocationStatus::Success) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if output
+                        .status
+                        .iter()
+                        .any(|v| *v == CommandInvocationStatus::Cancelled) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .status
+                        .iter()
+                        .any(|v| *v == CommandInvocationStatus::TimedOut) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .status
+                        .iter()
+                        .any(|v| *v == CommandInvocationStatus::Failed) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Ok(output)
+                    if output
+                        .status
+                        .iter()
+                        .any(|v| *v == CommandInvocationStatus::Cancelling) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                Err(RusotoError::Service(GetCommandInvocationError::InvocationDoesNotExist(_))) => {
+                    Some(rusoto_core::waiter::WaiterState::Retry)
+                }
+                Err(RusotoError::Unknown(response))
+                    if rusoto_core::retry::error_code(response).as_deref()
+                        == Some("InvocationDoesNotExist") =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Retry)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+}
@@ -402,11 +402,12 @@ pub struct SsoOidcClient {
 impl SsoOidcClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> SsoOidcClient {
         SsoOidcClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "SSO OIDC"),
         }
     }
 
@@ -440,6 +441,7 @@ impl SsoOidc for SsoOidcClient {
         let request_uri = "/token";
 
         let mut request = SignedRequest::new("POST", "awsssooidc", &self.region, &request_uri);
+        request.set_operation("CreateToken");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("oidc".to_string());
@@ -471,6 +473,7 @@ impl SsoOidc for SsoOidcClient {
         let request_uri = "/client/register";
 
         let mut request = SignedRequest::new("POST", "awsssooidc", &self.region, &request_uri);
+        request.set_operation("RegisterClient");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("oidc".to_string());
@@ -502,6 +505,7 @@ impl SsoOidc for SsoOidcClient {
         let request_uri = "/device_authorization";
 
         let mut request = SignedRequest::new("POST", "awsssooidc", &self.region, &request_uri);
+        request.set_operation("StartDeviceAuthorization");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("oidc".to_string());
@@ -0,0 +1,213 @@
+use std::fmt;
+
+use async_trait::async_trait;
+use chrono::{TimeZone, Utc};
+
+use crate::{GetRoleCredentialsRequest, RoleCredentials, Sso, SsoClient};
+use rusoto_core::credential::{AwsCredentials, CredentialsError, ExchangeSsoToken, SsoProfile};
+use rusoto_core::request::HttpClient;
+use rusoto_core::{Client, Region};
+
+/// Exchanges the SSO sessions cached by `aws sso login` for the credentials of the role of
+/// SSO profiles, for a [ProfileProvider](../rusoto_credential/struct.ProfileProvider.html), by
+/// calling `GetRoleCredentials` in the profile's `sso_region`.
+///
+/// The cached session is exchanged every time the profile provider is asked for credentials, so
+/// it should be wrapped in an `AutoRefreshingProvider`, as
+/// `DefaultCredentialsProvider::with_profile_provider` does, reusing the credentials until they
+/// expire, when AWS SSO says so.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use rusoto_core::credential::{DefaultCredentialsProvider, ProfileProvider};
+/// use rusoto_sso::SsoTokenExchanger;
+///
+/// let mut profile_provider = ProfileProvider::new().unwrap();
+/// profile_provider.set_sso_token_exchanger(SsoTokenExchanger::new());
+/// let provider = DefaultCredentialsProvider::with_profile_provider(profile_provider).unwrap();
+/// ```
+#[derive(Clone)]
+pub struct SsoTokenExchanger {
+    client: Client,
+}
+
+impl SsoTokenExchanger {
+    /// Creates a new `SsoTokenExchanger`, sending its requests with a new `HttpClient`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `HttpClient` can't be created, as when the TLS backend can't be initialized.
+    pub fn new() -> SsoTokenExchanger {
+        let dispatcher = HttpClient::new().expect("failed to create request dispatcher");
+        SsoTokenExchanger::new_with_client(Client::new_not_signing(dispatcher))
+    }
+
+    /// Creates a new `SsoTokenExchanger` sending its requests with `client`.
+    ///
+    /// `GetRoleCredentials` authenticates with the SSO access token rather than a signature,
+    /// so the client doesn't need any credentials: see `Client::new_not_signing`.
+    pub fn new_with_client(client: Client) -> SsoTokenExchanger {
+        SsoTokenExchanger { client }
+    }
+}
+
+impl Default for SsoTokenExchanger {
+    fn default() -> SsoTokenExchanger {
+        SsoTokenExchanger::new()
+    }
+}
+
+impl fmt::Debug for SsoTokenExchanger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SsoTokenExchanger")
+            .field("client", &"Client")
+            .finish()
+    }
+}
+
+#[async_trait]
+impl ExchangeSsoToken for SsoTokenExchanger {
+    async fn exchange_sso_token(
+        &self,
+        profile: &SsoProfile,
+        access_token: &str,
+    ) -> Result<AwsCredentials, CredentialsError> {
+        let region = profile
+            .region
+            .parse::<Region>()
+            .map_err(CredentialsError::new)?;
+        let sso = SsoClient::new_with_client(self.client.clone(), region);
+        let request = GetRoleCredentialsRequest {
+            access_token: access_token.to_owned(),
+            account_id: profile.account_id.clone(),
+            role_name: profile.role_name.clone(),
+        };
+        let response = sso.get_role_credentials(request).await.map_err(|err| {
+            CredentialsError::new(format!(
+                "Could not get the role credentials of profile {}: {}",
+                profile.profile, err
+            ))
+        })?;
+        let credentials = response
+            .role_credentials
+            .ok_or_else(|| CredentialsError::new("no role credentials in response"))?;
+
+        aws_credentials(credentials)
+    }
+}
+
+fn aws_credentials(credentials: RoleCredentials) -> Result<AwsCredentials, CredentialsError> {
+    let (access_key_id, secret_access_key) =
+        match (credentials.access_key_id, credentials.secret_access_key) {
+            (Some(access_key_id), Some(secret_access_key)) => (access_key_id, secret_access_key),
+            _ => return Err(CredentialsError::new("no access keys in role credentials")),
+        };
+    // the expiration is in milliseconds since the epoch
+    let expires_at = match credentials.expiration {
+        Some(expiration) => Some(
+            Utc.timestamp_millis_opt(expiration)
+                .single()
+                .ok_or_else(|| CredentialsError::new("invalid role credentials expiration"))?,
+        ),
+        None => None,
+    };
+
+    Ok(AwsCredentials::new(
+        access_key_id,
+        secret_access_key,
+        credentials.session_token,
+        expires_at,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rusoto_mock::MockRequestDispatcher;
+
+    fn sso_profile() -> SsoProfile {
+        SsoProfile {
+            profile: "sso".to_owned(),
+            start_url: "https://my-sso-portal.awsapps.com/start".to_owned(),
+            region: "us-east-1".to_owned(),
+            account_id: "123456789012".to_owned(),
+            role_name: "ReadOnly".to_owned(),
+        }
+    }
+
+    #[tokio::test]
+    async fn exchanges_access_token_for_role_credentials() {
+        let dispatcher = MockRequestDispatcher::default()
+            .with_body(
+                r#"{
+                    "roleCredentials": {
+                        "accessKeyId": "ASIAEXAMPLE",
+                        "secretAccessKey": "secret",
+                        "sessionToken": "session",
+                        "expiration": 1893456000000
+                    }
+                }"#,
+            )
+            .with_request_checker(|request| {
+                assert_eq!(request.method, "GET");
+                assert_eq!(request.path, "/federation/credentials");
+                assert_eq!(
+                    request.headers.get("x-amz-sso_bearer_token"),
+                    Some(&vec![b"access-token".to_vec()])
+                );
+                assert_eq!(
+                    request.params.get("account_id"),
+                    Some(&Some("123456789012".to_owned()))
+                );
+                assert_eq!(
+                    request.params.get("role_name"),
+                    Some(&Some("ReadOnly".to_owned()))
+                );
+                assert!(!request.headers.contains_key("authorization"));
+            });
+        let exchanger = SsoTokenExchanger::new_with_client(Client::new_not_signing(dispatcher));
+
+        let credentials = exchanger
+            .exchange_sso_token(&sso_profile(), "access-token")
+            .await
+            .expect("role credentials");
+        assert_eq!(credentials.aws_access_key_id(), "ASIAEXAMPLE");
+        assert_eq!(credentials.aws_secret_access_key(), "secret");
+        assert_eq!(credentials.token().as_deref(), Some("session"));
+        assert_eq!(
+            credentials
+                .expires_at()
+                .map(|expires_at| expires_at.timestamp()),
+            Some(1_893_456_000)
+        );
+    }
+
+    #[tokio::test]
+    async fn reports_rejected_access_tokens() {
+        let dispatcher = MockRequestDispatcher::with_status(401).with_body(
+            r#"{"__type": "UnauthorizedException", "message": "Session token not found or invalid"}"#,
+        );
+        let exchanger = SsoTokenExchanger::new_with_client(Client::new_not_signing(dispatcher));
+
+        let err = exchanger
+            .exchange_sso_token(&sso_profile(), "access-token")
+            .await
+            .expect_err("rejected access token");
+        assert!(err
+            .message
+            .starts_with("Could not get the role credentials of profile sso"));
+    }
+
+    #[tokio::test]
+    async fn rejects_credentials_without_keys() {
+        let dispatcher =
+            MockRequestDispatcher::default().with_body(r#"{"roleCredentials": {"expiration": 0}}"#);
+        let exchanger = SsoTokenExchanger::new_with_client(Client::new_not_signing(dispatcher));
+
+        assert!(exchanger
+            .exchange_sso_token(&sso_profile(), "access-token")
+            .await
+            .is_err());
+    }
+}
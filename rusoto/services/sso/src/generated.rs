@@ -391,11 +391,12 @@ pub struct SsoClient {
 impl SsoClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> SsoClient {
         SsoClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "SSO"),
         }
     }
 
@@ -429,6 +430,7 @@ impl Sso for SsoClient {
         let request_uri = "/federation/credentials";
 
         let mut request = SignedRequest::new("GET", "awsssoportal", &self.region, &request_uri);
+        request.set_operation("GetRoleCredentials");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("portal.sso".to_string());
@@ -464,6 +466,7 @@ impl Sso for SsoClient {
         let request_uri = "/assignment/roles";
 
         let mut request = SignedRequest::new("GET", "awsssoportal", &self.region, &request_uri);
+        request.set_operation("ListAccountRoles");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("portal.sso".to_string());
@@ -504,6 +507,7 @@ impl Sso for SsoClient {
         let request_uri = "/assignment/accounts";
 
         let mut request = SignedRequest::new("GET", "awsssoportal", &self.region, &request_uri);
+        request.set_operation("ListAccounts");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("portal.sso".to_string());
@@ -540,6 +544,7 @@ impl Sso for SsoClient {
         let request_uri = "/logout";
 
         let mut request = SignedRequest::new("POST", "awsssoportal", &self.region, &request_uri);
+        request.set_operation("Logout");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("portal.sso".to_string());
@@ -562,3 +567,67 @@ impl Sso for SsoClient {
         }
     }
 }
+impl SsoClient {
+    /// Auto-paginating version of `list_account_roles`, streaming every page of results.
+    pub fn list_account_roles_pages(
+        &self,
+        input: ListAccountRolesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListAccountRolesResponse, ListAccountRolesError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsoClient, input: ListAccountRolesRequest| async move {
+                client.list_account_roles(input).await
+            },
+            |input: &ListAccountRolesRequest, output: &ListAccountRolesResponse| {
+                Some(ListAccountRolesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_account_roles`, streaming the `roleList` of every page of results.
+    pub fn list_account_roles_items(
+        &self,
+        input: ListAccountRolesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<RoleInfo, ListAccountRolesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_account_roles_pages(input),
+            |output: ListAccountRolesResponse| output.role_list.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_accounts`, streaming every page of results.
+    pub fn list_accounts_pages(
+        &self,
+        input: ListAccountsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListAccountsResponse, ListAccountsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsoClient, input: ListAccountsRequest| async move {
+                client.list_accounts(input).await
+            },
+            |input: &ListAccountsRequest, output: &ListAccountsResponse| {
+                Some(ListAccountsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_accounts`, streaming the `accountList` of every page of results.
+    pub fn list_accounts_items(
+        &self,
+        input: ListAccountsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<AccountInfo, ListAccountsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_accounts_pages(input),
+            |output: ListAccountsResponse| output.account_list.unwrap_or_default(),
+        )
+    }
+}
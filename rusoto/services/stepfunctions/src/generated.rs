@@ -47,7 +47,12 @@ pub struct ActivityListItem {
     pub activity_arn: String,
     /// <p>The date the activity is created.</p>
     #[serde(rename = "creationDate")]
-    pub creation_date: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub creation_date: ::chrono::DateTime<::chrono::Utc>,
     /// <p><p>The name of the activity.</p> <p>A name must <i>not</i> contain:</p> <ul> <li> <p>white space</p> </li> <li> <p>brackets <code>&lt; &gt; { } [ ]</code> </p> </li> <li> <p>wildcard characters <code>? *</code> </p> </li> <li> <p>special characters <code>&quot; # % \ ^ | ~ ` $ &amp; , ; : /</code> </p> </li> <li> <p>control characters (<code>U+0000-001F</code>, <code>U+007F-009F</code>)</p> </li> </ul></p>
     #[serde(rename = "name")]
     pub name: String,
@@ -151,7 +156,12 @@ pub struct CreateActivityOutput {
     pub activity_arn: String,
     /// <p>The date the activity is created.</p>
     #[serde(rename = "creationDate")]
-    pub creation_date: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub creation_date: ::chrono::DateTime<::chrono::Utc>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -177,7 +187,7 @@ pub struct CreateStateMachineInput {
     /// <p>Determines whether a Standard or Express state machine is created. If not set, Standard is created.</p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<StateMachineType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -185,7 +195,12 @@ pub struct CreateStateMachineInput {
 pub struct CreateStateMachineOutput {
     /// <p>The date the state machine is created.</p>
     #[serde(rename = "creationDate")]
-    pub creation_date: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub creation_date: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The Amazon Resource Name (ARN) that identifies the created state machine.</p>
     #[serde(rename = "stateMachineArn")]
     pub state_machine_arn: String,
@@ -231,7 +246,12 @@ pub struct DescribeActivityOutput {
     pub activity_arn: String,
     /// <p>The date the activity is created.</p>
     #[serde(rename = "creationDate")]
-    pub creation_date: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub creation_date: ::chrono::DateTime<::chrono::Utc>,
     /// <p><p>The name of the activity.</p> <p>A name must <i>not</i> contain:</p> <ul> <li> <p>white space</p> </li> <li> <p>brackets <code>&lt; &gt; { } [ ]</code> </p> </li> <li> <p>wildcard characters <code>? *</code> </p> </li> <li> <p>special characters <code>&quot; # % \ ^ | ~ ` $ &amp; , ; : /</code> </p> </li> <li> <p>control characters (<code>U+0000-001F</code>, <code>U+007F-009F</code>)</p> </li> </ul></p>
     #[serde(rename = "name")]
     pub name: String,
@@ -264,17 +284,27 @@ pub struct DescribeExecutionOutput {
     pub output: Option<String>,
     /// <p>The date the execution is started.</p>
     #[serde(rename = "startDate")]
-    pub start_date: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub start_date: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The Amazon Resource Name (ARN) of the executed stated machine.</p>
     #[serde(rename = "stateMachineArn")]
     pub state_machine_arn: String,
     /// <p>The current status of the execution.</p>
     #[serde(rename = "status")]
-    pub status: String,
+    pub status: ExecutionStatus,
     /// <p>If the execution has already ended, the date the execution stopped.</p>
     #[serde(rename = "stopDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stop_date: Option<f64>,
+    pub stop_date: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -302,7 +332,12 @@ pub struct DescribeStateMachineForExecutionOutput {
     pub state_machine_arn: String,
     /// <p>The date and time the state machine associated with an execution was updated. For a newly created state machine, this is the creation date.</p>
     #[serde(rename = "updateDate")]
-    pub update_date: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub update_date: ::chrono::DateTime<::chrono::Utc>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -318,7 +353,12 @@ pub struct DescribeStateMachineInput {
 pub struct DescribeStateMachineOutput {
     /// <p>The date the state machine is created.</p>
     #[serde(rename = "creationDate")]
-    pub creation_date: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub creation_date: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The Amazon States Language definition of the state machine. See <a href="https://docs.aws.amazon.com/step-functions/latest/dg/concepts-amazon-states-language.html">Amazon States Language</a>.</p>
     #[serde(rename = "definition")]
     pub definition: String,
@@ -338,10 +378,10 @@ pub struct DescribeStateMachineOutput {
     /// <p>The current status of the state machine.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<StateMachineStatus>,
     /// <p><p/></p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: StateMachineType,
 }
 
 /// <p>Contains details about an abort of an execution.</p>
@@ -384,17 +424,27 @@ pub struct ExecutionListItem {
     pub name: String,
     /// <p>The date the execution started.</p>
     #[serde(rename = "startDate")]
-    pub start_date: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub start_date: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The Amazon Resource Name (ARN) of the executed state machine.</p>
     #[serde(rename = "stateMachineArn")]
     pub state_machine_arn: String,
     /// <p>The current status of the execution.</p>
     #[serde(rename = "status")]
-    pub status: String,
+    pub status: ExecutionStatus,
     /// <p>If the execution already ended, the date the execution stopped.</p>
     #[serde(rename = "stopDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stop_date: Option<f64>,
+    pub stop_date: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>Contains details about the start of the execution.</p>
@@ -411,6 +461,119 @@ pub struct ExecutionStartedEventDetails {
     pub role_arn: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ExecutionStatus {
+    /// `RUNNING`
+    Running,
+    /// `SUCCEEDED`
+    Succeeded,
+    /// `FAILED`
+    Failed,
+    /// `TIMED_OUT`
+    TimedOut,
+    /// `ABORTED`
+    Aborted,
+    /// `PENDING_REDRIVE`
+    PendingRedrive,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ExecutionStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ExecutionStatus::Running => "RUNNING",
+            ExecutionStatus::Succeeded => "SUCCEEDED",
+            ExecutionStatus::Failed => "FAILED",
+            ExecutionStatus::TimedOut => "TIMED_OUT",
+            ExecutionStatus::Aborted => "ABORTED",
+            ExecutionStatus::PendingRedrive => "PENDING_REDRIVE",
+            ExecutionStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ExecutionStatus {
+    fn default() -> ExecutionStatus {
+        ExecutionStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ExecutionStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ExecutionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ExecutionStatus {
+    fn from(value: &'a str) -> ExecutionStatus {
+        match value {
+            "RUNNING" => ExecutionStatus::Running,
+            "SUCCEEDED" => ExecutionStatus::Succeeded,
+            "FAILED" => ExecutionStatus::Failed,
+            "TIMED_OUT" => ExecutionStatus::TimedOut,
+            "ABORTED" => ExecutionStatus::Aborted,
+            "PENDING_REDRIVE" => ExecutionStatus::PendingRedrive,
+            _ => ExecutionStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ExecutionStatus {
+    fn from(value: String) -> ExecutionStatus {
+        match value.as_str() {
+            "RUNNING" => ExecutionStatus::Running,
+            "SUCCEEDED" => ExecutionStatus::Succeeded,
+            "FAILED" => ExecutionStatus::Failed,
+            "TIMED_OUT" => ExecutionStatus::TimedOut,
+            "ABORTED" => ExecutionStatus::Aborted,
+            "PENDING_REDRIVE" => ExecutionStatus::PendingRedrive,
+            _ => ExecutionStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ExecutionStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ExecutionStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ExecutionStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ExecutionStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ExecutionStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ExecutionStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Contains details about the successful termination of the execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -618,12 +781,405 @@ pub struct HistoryEvent {
     pub task_timed_out_event_details: Option<TaskTimedOutEventDetails>,
     /// <p>The date and time the event occurred.</p>
     #[serde(rename = "timestamp")]
-    pub timestamp: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub timestamp: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The type of the event.</p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: HistoryEventType,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum HistoryEventType {
+    /// `ActivityFailed`
+    ActivityFailed,
+    /// `ActivityScheduled`
+    ActivityScheduled,
+    /// `ActivityScheduleFailed`
+    ActivityScheduleFailed,
+    /// `ActivityStarted`
+    ActivityStarted,
+    /// `ActivitySucceeded`
+    ActivitySucceeded,
+    /// `ActivityTimedOut`
+    ActivityTimedOut,
+    /// `ChoiceStateEntered`
+    ChoiceStateEntered,
+    /// `ChoiceStateExited`
+    ChoiceStateExited,
+    /// `ExecutionAborted`
+    ExecutionAborted,
+    /// `ExecutionFailed`
+    ExecutionFailed,
+    /// `ExecutionStarted`
+    ExecutionStarted,
+    /// `ExecutionSucceeded`
+    ExecutionSucceeded,
+    /// `ExecutionTimedOut`
+    ExecutionTimedOut,
+    /// `FailStateEntered`
+    FailStateEntered,
+    /// `LambdaFunctionFailed`
+    LambdaFunctionFailed,
+    /// `LambdaFunctionScheduled`
+    LambdaFunctionScheduled,
+    /// `LambdaFunctionScheduleFailed`
+    LambdaFunctionScheduleFailed,
+    /// `LambdaFunctionStarted`
+    LambdaFunctionStarted,
+    /// `LambdaFunctionStartFailed`
+    LambdaFunctionStartFailed,
+    /// `LambdaFunctionSucceeded`
+    LambdaFunctionSucceeded,
+    /// `LambdaFunctionTimedOut`
+    LambdaFunctionTimedOut,
+    /// `MapIterationAborted`
+    MapIterationAborted,
+    /// `MapIterationFailed`
+    MapIterationFailed,
+    /// `MapIterationStarted`
+    MapIterationStarted,
+    /// `MapIterationSucceeded`
+    MapIterationSucceeded,
+    /// `MapStateAborted`
+    MapStateAborted,
+    /// `MapStateEntered`
+    MapStateEntered,
+    /// `MapStateExited`
+    MapStateExited,
+    /// `MapStateFailed`
+    MapStateFailed,
+    /// `MapStateStarted`
+    MapStateStarted,
+    /// `MapStateSucceeded`
+    MapStateSucceeded,
+    /// `ParallelStateAborted`
+    ParallelStateAborted,
+    /// `ParallelStateEntered`
+    ParallelStateEntered,
+    /// `ParallelStateExited`
+    ParallelStateExited,
+    /// `ParallelStateFailed`
+    ParallelStateFailed,
+    /// `ParallelStateStarted`
+    ParallelStateStarted,
+    /// `ParallelStateSucceeded`
+    ParallelStateSucceeded,
+    /// `PassStateEntered`
+    PassStateEntered,
+    /// `PassStateExited`
+    PassStateExited,
+    /// `SucceedStateEntered`
+    SucceedStateEntered,
+    /// `SucceedStateExited`
+    SucceedStateExited,
+    /// `TaskFailed`
+    TaskFailed,
+    /// `TaskScheduled`
+    TaskScheduled,
+    /// `TaskStarted`
+    TaskStarted,
+    /// `TaskStartFailed`
+    TaskStartFailed,
+    /// `TaskStateAborted`
+    TaskStateAborted,
+    /// `TaskStateEntered`
+    TaskStateEntered,
+    /// `TaskStateExited`
+    TaskStateExited,
+    /// `TaskSubmitFailed`
+    TaskSubmitFailed,
+    /// `TaskSubmitted`
+    TaskSubmitted,
+    /// `TaskSucceeded`
+    TaskSucceeded,
+    /// `TaskTimedOut`
+    TaskTimedOut,
+    /// `WaitStateAborted`
+    WaitStateAborted,
+    /// `WaitStateEntered`
+    WaitStateEntered,
+    /// `WaitStateExited`
+    WaitStateExited,
+    /// `MapRunAborted`
+    MapRunAborted,
+    /// `MapRunFailed`
+    MapRunFailed,
+    /// `MapRunStarted`
+    MapRunStarted,
+    /// `MapRunSucceeded`
+    MapRunSucceeded,
+    /// `ExecutionRedriven`
+    ExecutionRedriven,
+    /// `MapRunRedriven`
+    MapRunRedriven,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl HistoryEventType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            HistoryEventType::ActivityFailed => "ActivityFailed",
+            HistoryEventType::ActivityScheduled => "ActivityScheduled",
+            HistoryEventType::ActivityScheduleFailed => "ActivityScheduleFailed",
+            HistoryEventType::ActivityStarted => "ActivityStarted",
+            HistoryEventType::ActivitySucceeded => "ActivitySucceeded",
+            HistoryEventType::ActivityTimedOut => "ActivityTimedOut",
+            HistoryEventType::ChoiceStateEntered => "ChoiceStateEntered",
+            HistoryEventType::ChoiceStateExited => "ChoiceStateExited",
+            HistoryEventType::ExecutionAborted => "ExecutionAborted",
+            HistoryEventType::ExecutionFailed => "ExecutionFailed",
+            HistoryEventType::ExecutionStarted => "ExecutionStarted",
+            HistoryEventType::ExecutionSucceeded => "ExecutionSucceeded",
+            HistoryEventType::ExecutionTimedOut => "ExecutionTimedOut",
+            HistoryEventType::FailStateEntered => "FailStateEntered",
+            HistoryEventType::LambdaFunctionFailed => "LambdaFunctionFailed",
+            HistoryEventType::LambdaFunctionScheduled => "LambdaFunctionScheduled",
+            HistoryEventType::LambdaFunctionScheduleFailed => "LambdaFunctionScheduleFailed",
+            HistoryEventType::LambdaFunctionStarted => "LambdaFunctionStarted",
+            HistoryEventType::LambdaFunctionStartFailed => "LambdaFunctionStartFailed",
+            HistoryEventType::LambdaFunctionSucceeded => "LambdaFunctionSucceeded",
+            HistoryEventType::LambdaFunctionTimedOut => "LambdaFunctionTimedOut",
+            HistoryEventType::MapIterationAborted => "MapIterationAborted",
+            HistoryEventType::MapIterationFailed => "MapIterationFailed",
+            HistoryEventType::MapIterationStarted => "MapIterationStarted",
+            HistoryEventType::MapIterationSucceeded => "MapIterationSucceeded",
+            HistoryEventType::MapStateAborted => "MapStateAborted",
+            HistoryEventType::MapStateEntered => "MapStateEntered",
+            HistoryEventType::MapStateExited => "MapStateExited",
+            HistoryEventType::MapStateFailed => "MapStateFailed",
+            HistoryEventType::MapStateStarted => "MapStateStarted",
+            HistoryEventType::MapStateSucceeded => "MapStateSucceeded",
+            HistoryEventType::ParallelStateAborted => "ParallelStateAborted",
+            HistoryEventType::ParallelStateEntered => "ParallelStateEntered",
+            HistoryEventType::ParallelStateExited => "ParallelStateExited",
+            HistoryEventType::ParallelStateFailed => "ParallelStateFailed",
+            HistoryEventType::ParallelStateStarted => "ParallelStateStarted",
+            HistoryEventType::ParallelStateSucceeded => "ParallelStateSucceeded",
+            HistoryEventType::PassStateEntered => "PassStateEntered",
+            HistoryEventType::PassStateExited => "PassStateExited",
+            HistoryEventType::SucceedStateEntered => "SucceedStateEntered",
+            HistoryEventType::SucceedStateExited => "SucceedStateExited",
+            HistoryEventType::TaskFailed => "TaskFailed",
+            HistoryEventType::TaskScheduled => "TaskScheduled",
+            HistoryEventType::TaskStarted => "TaskStarted",
+            HistoryEventType::TaskStartFailed => "TaskStartFailed",
+            HistoryEventType::TaskStateAborted => "TaskStateAborted",
+            HistoryEventType::TaskStateEntered => "TaskStateEntered",
+            HistoryEventType::TaskStateExited => "TaskStateExited",
+            HistoryEventType::TaskSubmitFailed => "TaskSubmitFailed",
+            HistoryEventType::TaskSubmitted => "TaskSubmitted",
+            HistoryEventType::TaskSucceeded => "TaskSucceeded",
+            HistoryEventType::TaskTimedOut => "TaskTimedOut",
+            HistoryEventType::WaitStateAborted => "WaitStateAborted",
+            HistoryEventType::WaitStateEntered => "WaitStateEntered",
+            HistoryEventType::WaitStateExited => "WaitStateExited",
+            HistoryEventType::MapRunAborted => "MapRunAborted",
+            HistoryEventType::MapRunFailed => "MapRunFailed",
+            HistoryEventType::MapRunStarted => "MapRunStarted",
+            HistoryEventType::MapRunSucceeded => "MapRunSucceeded",
+            HistoryEventType::ExecutionRedriven => "ExecutionRedriven",
+            HistoryEventType::MapRunRedriven => "MapRunRedriven",
+            HistoryEventType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for HistoryEventType {
+    fn default() -> HistoryEventType {
+        HistoryEventType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for HistoryEventType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for HistoryEventType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for HistoryEventType {
+    fn from(value: &'a str) -> HistoryEventType {
+        match value {
+            "ActivityFailed" => HistoryEventType::ActivityFailed,
+            "ActivityScheduled" => HistoryEventType::ActivityScheduled,
+            "ActivityScheduleFailed" => HistoryEventType::ActivityScheduleFailed,
+            "ActivityStarted" => HistoryEventType::ActivityStarted,
+            "ActivitySucceeded" => HistoryEventType::ActivitySucceeded,
+            "ActivityTimedOut" => HistoryEventType::ActivityTimedOut,
+            "ChoiceStateEntered" => HistoryEventType::ChoiceStateEntered,
+            "ChoiceStateExited" => HistoryEventType::ChoiceStateExited,
+            "ExecutionAborted" => HistoryEventType::ExecutionAborted,
+            "ExecutionFailed" => HistoryEventType::ExecutionFailed,
+            "ExecutionStarted" => HistoryEventType::ExecutionStarted,
+            "ExecutionSucceeded" => HistoryEventType::ExecutionSucceeded,
+            "ExecutionTimedOut" => HistoryEventType::ExecutionTimedOut,
+            "FailStateEntered" => HistoryEventType::FailStateEntered,
+            "LambdaFunctionFailed" => HistoryEventType::LambdaFunctionFailed,
+            "LambdaFunctionScheduled" => HistoryEventType::LambdaFunctionScheduled,
+            "LambdaFunctionScheduleFailed" => HistoryEventType::LambdaFunctionScheduleFailed,
+            "LambdaFunctionStarted" => HistoryEventType::LambdaFunctionStarted,
+            "LambdaFunctionStartFailed" => HistoryEventType::LambdaFunctionStartFailed,
+            "LambdaFunctionSucceeded" => HistoryEventType::LambdaFunctionSucceeded,
+            "LambdaFunctionTimedOut" => HistoryEventType::LambdaFunctionTimedOut,
+            "MapIterationAborted" => HistoryEventType::MapIterationAborted,
+            "MapIterationFailed" => HistoryEventType::MapIterationFailed,
+            "MapIterationStarted" => HistoryEventType::MapIterationStarted,
+            "MapIterationSucceeded" => HistoryEventType::MapIterationSucceeded,
+            "MapStateAborted" => HistoryEventType::MapStateAborted,
+            "MapStateEntered" => HistoryEventType::MapStateEntered,
+            "MapStateExited" => HistoryEventType::MapStateExited,
+            "MapStateFailed" => HistoryEventType::MapStateFailed,
+            "MapStateStarted" => HistoryEventType::MapStateStarted,
+            "MapStateSucceeded" => HistoryEventType::MapStateSucceeded,
+            "ParallelStateAborted" => HistoryEventType::ParallelStateAborted,
+            "ParallelStateEntered" => HistoryEventType::ParallelStateEntered,
+            "ParallelStateExited" => HistoryEventType::ParallelStateExited,
+            "ParallelStateFailed" => HistoryEventType::ParallelStateFailed,
+            "ParallelStateStarted" => HistoryEventType::ParallelStateStarted,
+            "ParallelStateSucceeded" => HistoryEventType::ParallelStateSucceeded,
+            "PassStateEntered" => HistoryEventType::PassStateEntered,
+            "PassStateExited" => HistoryEventType::PassStateExited,
+            "SucceedStateEntered" => HistoryEventType::SucceedStateEntered,
+            "SucceedStateExited" => HistoryEventType::SucceedStateExited,
+            "TaskFailed" => HistoryEventType::TaskFailed,
+            "TaskScheduled" => HistoryEventType::TaskScheduled,
+            "TaskStarted" => HistoryEventType::TaskStarted,
+            "TaskStartFailed" => HistoryEventType::TaskStartFailed,
+            "TaskStateAborted" => HistoryEventType::TaskStateAborted,
+            "TaskStateEntered" => HistoryEventType::TaskStateEntered,
+            "TaskStateExited" => HistoryEventType::TaskStateExited,
+            "TaskSubmitFailed" => HistoryEventType::TaskSubmitFailed,
+            "TaskSubmitted" => HistoryEventType::TaskSubmitted,
+            "TaskSucceeded" => HistoryEventType::TaskSucceeded,
+            "TaskTimedOut" => HistoryEventType::TaskTimedOut,
+            "WaitStateAborted" => HistoryEventType::WaitStateAborted,
+            "WaitStateEntered" => HistoryEventType::WaitStateEntered,
+            "WaitStateExited" => HistoryEventType::WaitStateExited,
+            "MapRunAborted" => HistoryEventType::MapRunAborted,
+            "MapRunFailed" => HistoryEventType::MapRunFailed,
+            "MapRunStarted" => HistoryEventType::MapRunStarted,
+            "MapRunSucceeded" => HistoryEventType::MapRunSucceeded,
+            "ExecutionRedriven" => HistoryEventType::ExecutionRedriven,
+            "MapRunRedriven" => HistoryEventType::MapRunRedriven,
+            _ => HistoryEventType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for HistoryEventType {
+    fn from(value: String) -> HistoryEventType {
+        match value.as_str() {
+            "ActivityFailed" => HistoryEventType::ActivityFailed,
+            "ActivityScheduled" => HistoryEventType::ActivityScheduled,
+            "ActivityScheduleFailed" => HistoryEventType::ActivityScheduleFailed,
+            "ActivityStarted" => HistoryEventType::ActivityStarted,
+            "ActivitySucceeded" => HistoryEventType::ActivitySucceeded,
+            "ActivityTimedOut" => HistoryEventType::ActivityTimedOut,
+            "ChoiceStateEntered" => HistoryEventType::ChoiceStateEntered,
+            "ChoiceStateExited" => HistoryEventType::ChoiceStateExited,
+            "ExecutionAborted" => HistoryEventType::ExecutionAborted,
+            "ExecutionFailed" => HistoryEventType::ExecutionFailed,
+            "ExecutionStarted" => HistoryEventType::ExecutionStarted,
+            "ExecutionSucceeded" => HistoryEventType::ExecutionSucceeded,
+            "ExecutionTimedOut" => HistoryEventType::ExecutionTimedOut,
+            "FailStateEntered" => HistoryEventType::FailStateEntered,
+            "LambdaFunctionFailed" => HistoryEventType::LambdaFunctionFailed,
+            "LambdaFunctionScheduled" => HistoryEventType::LambdaFunctionScheduled,
+            "LambdaFunctionScheduleFailed" => HistoryEventType::LambdaFunctionScheduleFailed,
+            "LambdaFunctionStarted" => HistoryEventType::LambdaFunctionStarted,
+            "LambdaFunctionStartFailed" => HistoryEventType::LambdaFunctionStartFailed,
+            "LambdaFunctionSucceeded" => HistoryEventType::LambdaFunctionSucceeded,
+            "LambdaFunctionTimedOut" => HistoryEventType::LambdaFunctionTimedOut,
+            "MapIterationAborted" => HistoryEventType::MapIterationAborted,
+            "MapIterationFailed" => HistoryEventType::MapIterationFailed,
+            "MapIterationStarted" => HistoryEventType::MapIterationStarted,
+            "MapIterationSucceeded" => HistoryEventType::MapIterationSucceeded,
+            "MapStateAborted" => HistoryEventType::MapStateAborted,
+            "MapStateEntered" => HistoryEventType::MapStateEntered,
+            "MapStateExited" => HistoryEventType::MapStateExited,
+            "MapStateFailed" => HistoryEventType::MapStateFailed,
+            "MapStateStarted" => HistoryEventType::MapStateStarted,
+            "MapStateSucceeded" => HistoryEventType::MapStateSucceeded,
+            "ParallelStateAborted" => HistoryEventType::ParallelStateAborted,
+            "ParallelStateEntered" => HistoryEventType::ParallelStateEntered,
+            "ParallelStateExited" => HistoryEventType::ParallelStateExited,
+            "ParallelStateFailed" => HistoryEventType::ParallelStateFailed,
+            "ParallelStateStarted" => HistoryEventType::ParallelStateStarted,
+            "ParallelStateSucceeded" => HistoryEventType::ParallelStateSucceeded,
+            "PassStateEntered" => HistoryEventType::PassStateEntered,
+            "PassStateExited" => HistoryEventType::PassStateExited,
+            "SucceedStateEntered" => HistoryEventType::SucceedStateEntered,
+            "SucceedStateExited" => HistoryEventType::SucceedStateExited,
+            "TaskFailed" => HistoryEventType::TaskFailed,
+            "TaskScheduled" => HistoryEventType::TaskScheduled,
+            "TaskStarted" => HistoryEventType::TaskStarted,
+            "TaskStartFailed" => HistoryEventType::TaskStartFailed,
+            "TaskStateAborted" => HistoryEventType::TaskStateAborted,
+            "TaskStateEntered" => HistoryEventType::TaskStateEntered,
+            "TaskStateExited" => HistoryEventType::TaskStateExited,
+            "TaskSubmitFailed" => HistoryEventType::TaskSubmitFailed,
+            "TaskSubmitted" => HistoryEventType::TaskSubmitted,
+            "TaskSucceeded" => HistoryEventType::TaskSucceeded,
+            "TaskTimedOut" => HistoryEventType::TaskTimedOut,
+            "WaitStateAborted" => HistoryEventType::WaitStateAborted,
+            "WaitStateEntered" => HistoryEventType::WaitStateEntered,
+            "WaitStateExited" => HistoryEventType::WaitStateExited,
+            "MapRunAborted" => HistoryEventType::MapRunAborted,
+            "MapRunFailed" => HistoryEventType::MapRunFailed,
+            "MapRunStarted" => HistoryEventType::MapRunStarted,
+            "MapRunSucceeded" => HistoryEventType::MapRunSucceeded,
+            "ExecutionRedriven" => HistoryEventType::ExecutionRedriven,
+            "MapRunRedriven" => HistoryEventType::MapRunRedriven,
+            _ => HistoryEventType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for HistoryEventType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<HistoryEventType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for HistoryEventType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for HistoryEventType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for HistoryEventType {
+    fn deserialize<D>(deserializer: D) -> Result<HistoryEventType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Contains details about a lambda function that failed during an execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -749,7 +1305,7 @@ pub struct ListExecutionsInput {
     /// <p>If specified, only list the executions whose current execution status matches the given filter.</p>
     #[serde(rename = "statusFilter")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status_filter: Option<String>,
+    pub status_filter: Option<ExecutionStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -814,6 +1370,109 @@ pub struct LogDestination {
     pub cloud_watch_logs_log_group: Option<CloudWatchLogsLogGroup>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LogLevel {
+    /// `ALL`
+    All,
+    /// `ERROR`
+    Error,
+    /// `FATAL`
+    Fatal,
+    /// `OFF`
+    Off,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LogLevel {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LogLevel::All => "ALL",
+            LogLevel::Error => "ERROR",
+            LogLevel::Fatal => "FATAL",
+            LogLevel::Off => "OFF",
+            LogLevel::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LogLevel {
+    fn default() -> LogLevel {
+        LogLevel::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LogLevel {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LogLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LogLevel {
+    fn from(value: &'a str) -> LogLevel {
+        match value {
+            "ALL" => LogLevel::All,
+            "ERROR" => LogLevel::Error,
+            "FATAL" => LogLevel::Fatal,
+            "OFF" => LogLevel::Off,
+            _ => LogLevel::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LogLevel {
+    fn from(value: String) -> LogLevel {
+        match value.as_str() {
+            "ALL" => LogLevel::All,
+            "ERROR" => LogLevel::Error,
+            "FATAL" => LogLevel::Fatal,
+            "OFF" => LogLevel::Off,
+            _ => LogLevel::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LogLevel {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LogLevel, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LogLevel {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LogLevel {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LogLevel {
+    fn deserialize<D>(deserializer: D) -> Result<LogLevel, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct LoggingConfiguration {
@@ -828,7 +1487,7 @@ pub struct LoggingConfiguration {
     /// <p>Defines which category of execution history events are logged.</p>
     #[serde(rename = "level")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub level: Option<String>,
+    pub level: Option<LogLevel>,
 }
 
 /// <p>Contains details about an iteration of a Map state.</p>
@@ -926,7 +1585,12 @@ pub struct StartExecutionOutput {
     pub execution_arn: String,
     /// <p>The date the execution is started.</p>
     #[serde(rename = "startDate")]
-    pub start_date: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub start_date: ::chrono::DateTime<::chrono::Utc>,
 }
 
 /// <p>Contains details about a state entered during an execution.</p>
@@ -961,7 +1625,12 @@ pub struct StateExitedEventDetails {
 pub struct StateMachineListItem {
     /// <p>The date the state machine is created.</p>
     #[serde(rename = "creationDate")]
-    pub creation_date: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub creation_date: ::chrono::DateTime<::chrono::Utc>,
     /// <p><p>The name of the state machine.</p> <p>A name must <i>not</i> contain:</p> <ul> <li> <p>white space</p> </li> <li> <p>brackets <code>&lt; &gt; { } [ ]</code> </p> </li> <li> <p>wildcard characters <code>? *</code> </p> </li> <li> <p>special characters <code>&quot; # % \ ^ | ~ ` $ &amp; , ; : /</code> </p> </li> <li> <p>control characters (<code>U+0000-001F</code>, <code>U+007F-009F</code>)</p> </li> </ul></p>
     #[serde(rename = "name")]
     pub name: String,
@@ -970,9 +1639,195 @@ pub struct StateMachineListItem {
     pub state_machine_arn: String,
     /// <p><p/></p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: StateMachineType,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StateMachineStatus {
+    /// `ACTIVE`
+    Active,
+    /// `DELETING`
+    Deleting,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl StateMachineStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StateMachineStatus::Active => "ACTIVE",
+            StateMachineStatus::Deleting => "DELETING",
+            StateMachineStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for StateMachineStatus {
+    fn default() -> StateMachineStatus {
+        StateMachineStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for StateMachineStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for StateMachineStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for StateMachineStatus {
+    fn from(value: &'a str) -> StateMachineStatus {
+        match value {
+            "ACTIVE" => StateMachineStatus::Active,
+            "DELETING" => StateMachineStatus::Deleting,
+            _ => StateMachineStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StateMachineStatus {
+    fn from(value: String) -> StateMachineStatus {
+        match value.as_str() {
+            "ACTIVE" => StateMachineStatus::Active,
+            "DELETING" => StateMachineStatus::Deleting,
+            _ => StateMachineStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for StateMachineStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<StateMachineStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StateMachineStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for StateMachineStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StateMachineStatus {
+    fn deserialize<D>(deserializer: D) -> Result<StateMachineStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StateMachineType {
+    /// `STANDARD`
+    Standard,
+    /// `EXPRESS`
+    Express,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl StateMachineType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StateMachineType::Standard => "STANDARD",
+            StateMachineType::Express => "EXPRESS",
+            StateMachineType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for StateMachineType {
+    fn default() -> StateMachineType {
+        StateMachineType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for StateMachineType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for StateMachineType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for StateMachineType {
+    fn from(value: &'a str) -> StateMachineType {
+        match value {
+            "STANDARD" => StateMachineType::Standard,
+            "EXPRESS" => StateMachineType::Express,
+            _ => StateMachineType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StateMachineType {
+    fn from(value: String) -> StateMachineType {
+        match value.as_str() {
+            "STANDARD" => StateMachineType::Standard,
+            "EXPRESS" => StateMachineType::Express,
+            _ => StateMachineType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for StateMachineType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<StateMachineType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StateMachineType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for StateMachineType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StateMachineType {
+    fn deserialize<D>(deserializer: D) -> Result<StateMachineType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct StopExecutionInput {
@@ -994,7 +1849,12 @@ pub struct StopExecutionInput {
 pub struct StopExecutionOutput {
     /// <p>The date the execution is stopped.</p>
     #[serde(rename = "stopDate")]
-    pub stop_date: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub stop_date: ::chrono::DateTime<::chrono::Utc>,
 }
 
 /// <p>Tags are key-value pairs that can be associated with Step Functions state machines and activities.</p> <p>An array of key-value pairs. For more information, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/cost-alloc-tags.html">Using Cost Allocation Tags</a> in the <i>AWS Billing and Cost Management User Guide</i>, and <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/access_iam-tags.html">Controlling Access Using IAM Tags</a>.</p> <p>Tags may only contain Unicode letters, digits, white space, or these symbols: <code>_ . : / = + - @</code>.</p>
@@ -1211,7 +2071,12 @@ pub struct UpdateStateMachineInput {
 pub struct UpdateStateMachineOutput {
     /// <p>The date and time the state machine was updated.</p>
     #[serde(rename = "updateDate")]
-    pub update_date: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub update_date: ::chrono::DateTime<::chrono::Utc>,
 }
 
 /// Errors returned by CreateActivity
@@ -2350,11 +3215,12 @@ pub struct StepFunctionsClient {
 impl StepFunctionsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> StepFunctionsClient {
         StepFunctionsClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "SFN"),
         }
     }
 
@@ -2386,6 +3252,7 @@ impl StepFunctions for StepFunctionsClient {
         input: CreateActivityInput,
     ) -> Result<CreateActivityOutput, RusotoError<CreateActivityError>> {
         let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("CreateActivity");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "AWSStepFunctions.CreateActivity");
@@ -2413,6 +3280,7 @@ impl StepFunctions for StepFunctionsClient {
         input: CreateStateMachineInput,
     ) -> Result<CreateStateMachineOutput, RusotoError<CreateStateMachineError>> {
         let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("CreateStateMachine");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "AWSStepFunctions.CreateStateMachine");
@@ -2441,6 +3309,7 @@ impl StepFunctions for StepFunctionsClient {
         input: DeleteActivityInput,
     ) -> Result<DeleteActivityOutput, RusotoError<DeleteActivityError>> {
         let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("DeleteActivity");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "AWSStepFunctions.DeleteActivity");
@@ -2468,6 +3337,7 @@ impl StepFunctions for StepFunctionsClient {
         input: DeleteStateMachineInput,
     ) -> Result<DeleteStateMachineOutput, RusotoError<DeleteStateMachineError>> {
         let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("DeleteStateMachine");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "AWSStepFunctions.DeleteStateMachine");
@@ -2496,6 +3366,7 @@ impl StepFunctions for StepFunctionsClient {
         input: DescribeActivityInput,
     ) -> Result<DescribeActivityOutput, RusotoError<DescribeActivityError>> {
         let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("DescribeActivity");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "AWSStepFunctions.DescribeActivity");
@@ -2523,6 +3394,7 @@ impl StepFunctions for StepFunctionsClient {
         input: DescribeExecutionInput,
     ) -> Result<DescribeExecutionOutput, RusotoError<DescribeExecutionError>> {
         let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("DescribeExecution");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "AWSStepFunctions.DescribeExecution");
@@ -2550,6 +3422,7 @@ impl StepFunctions for StepFunctionsClient {
         input: DescribeStateMachineInput,
     ) -> Result<DescribeStateMachineOutput, RusotoError<DescribeStateMachineError>> {
         let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("DescribeStateMachine");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "AWSStepFunctions.DescribeStateMachine");
@@ -2581,6 +3454,7 @@ impl StepFunctions for StepFunctionsClient {
         RusotoError<DescribeStateMachineForExecutionError>,
     > {
         let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("DescribeStateMachineForExecution");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -2614,6 +3488,7 @@ impl StepFunctions for StepFunctionsClient {
         input: GetActivityTaskInput,
     ) -> Result<GetActivityTaskOutput, RusotoError<GetActivityTaskError>> {
         let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("GetActivityTask");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "AWSStepFunctions.GetActivityTask");
@@ -2641,6 +3516,7 @@ impl StepFunctions for StepFunctionsClient {
         input: GetExecutionHistoryInput,
     ) -> Result<GetExecutionHistoryOutput, RusotoError<GetExecutionHistoryError>> {
         let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("GetExecutionHistory");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "AWSStepFunctions.GetExecutionHistory");
@@ -2669,6 +3545,7 @@ impl StepFunctions for StepFunctionsClient {
         input: ListActivitiesInput,
     ) -> Result<ListActivitiesOutput, RusotoError<ListActivitiesError>> {
         let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("ListActivities");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "AWSStepFunctions.ListActivities");
@@ -2696,6 +3573,7 @@ impl StepFunctions for StepFunctionsClient {
         input: ListExecutionsInput,
     ) -> Result<ListExecutionsOutput, RusotoError<ListExecutionsError>> {
         let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("ListExecutions");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "AWSStepFunctions.ListExecutions");
@@ -2723,6 +3601,7 @@ impl StepFunctions for StepFunctionsClient {
         input: ListStateMachinesInput,
     ) -> Result<ListStateMachinesOutput, RusotoError<ListStateMachinesError>> {
         let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("ListStateMachines");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "AWSStepFunctions.ListStateMachines");
@@ -2750,6 +3629,7 @@ impl StepFunctions for StepFunctionsClient {
         input: ListTagsForResourceInput,
     ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
         let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "AWSStepFunctions.ListTagsForResource");
@@ -2778,6 +3658,7 @@ impl StepFunctions for StepFunctionsClient {
         input: SendTaskFailureInput,
     ) -> Result<SendTaskFailureOutput, RusotoError<SendTaskFailureError>> {
         let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("SendTaskFailure");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "AWSStepFunctions.SendTaskFailure");
@@ -2805,6 +3686,7 @@ impl StepFunctions for StepFunctionsClient {
         input: SendTaskHeartbeatInput,
     ) -> Result<SendTaskHeartbeatOutput, RusotoError<SendTaskHeartbeatError>> {
         let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("SendTaskHeartbeat");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "AWSStepFunctions.SendTaskHeartbeat");
@@ -2832,6 +3714,7 @@ impl StepFunctions for StepFunctionsClient {
         input: SendTaskSuccessInput,
     ) -> Result<SendTaskSuccessOutput, RusotoError<SendTaskSuccessError>> {
         let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("SendTaskSuccess");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "AWSStepFunctions.SendTaskSuccess");
@@ -2859,6 +3742,7 @@ impl StepFunctions for StepFunctionsClient {
         input: StartExecutionInput,
     ) -> Result<StartExecutionOutput, RusotoError<StartExecutionError>> {
         let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("StartExecution");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "AWSStepFunctions.StartExecution");
@@ -2886,6 +3770,7 @@ impl StepFunctions for StepFunctionsClient {
         input: StopExecutionInput,
     ) -> Result<StopExecutionOutput, RusotoError<StopExecutionError>> {
         let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("StopExecution");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "AWSStepFunctions.StopExecution");
@@ -2913,6 +3798,7 @@ impl StepFunctions for StepFunctionsClient {
         input: TagResourceInput,
     ) -> Result<TagResourceOutput, RusotoError<TagResourceError>> {
         let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "AWSStepFunctions.TagResource");
@@ -2940,6 +3826,7 @@ impl StepFunctions for StepFunctionsClient {
         input: UntagResourceInput,
     ) -> Result<UntagResourceOutput, RusotoError<UntagResourceError>> {
         let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "AWSStepFunctions.UntagResource");
@@ -2967,6 +3854,7 @@ impl StepFunctions for StepFunctionsClient {
         input: UpdateStateMachineInput,
     ) -> Result<UpdateStateMachineOutput, RusotoError<UpdateStateMachineError>> {
         let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("UpdateStateMachine");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "AWSStepFunctions.UpdateStateMachine");
@@ -2989,3 +3877,130 @@ impl StepFunctions for StepFunctionsClient {
         }
     }
 }
+impl StepFunctionsClient {
+    /// Auto-paginating version of `get_execution_history`, streaming every page of results.
+    pub fn get_execution_history_pages(
+        &self,
+        input: GetExecutionHistoryInput,
+    ) -> rusoto_core::pagination::RusotoStream<GetExecutionHistoryOutput, GetExecutionHistoryError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: StepFunctionsClient, input: GetExecutionHistoryInput| async move {
+                client.get_execution_history(input).await
+            },
+            |input: &GetExecutionHistoryInput, output: &GetExecutionHistoryOutput| {
+                Some(GetExecutionHistoryInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_execution_history`, streaming the `events` of every page of results.
+    pub fn get_execution_history_items(
+        &self,
+        input: GetExecutionHistoryInput,
+    ) -> rusoto_core::pagination::RusotoStream<HistoryEvent, GetExecutionHistoryError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_execution_history_pages(input),
+            |output: GetExecutionHistoryOutput| output.events,
+        )
+    }
+
+    /// Auto-paginating version of `list_activities`, streaming every page of results.
+    pub fn list_activities_pages(
+        &self,
+        input: ListActivitiesInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListActivitiesOutput, ListActivitiesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: StepFunctionsClient, input: ListActivitiesInput| async move {
+                client.list_activities(input).await
+            },
+            |input: &ListActivitiesInput, output: &ListActivitiesOutput| {
+                Some(ListActivitiesInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_activities`, streaming the `activities` of every page of results.
+    pub fn list_activities_items(
+        &self,
+        input: ListActivitiesInput,
+    ) -> rusoto_core::pagination::RusotoStream<ActivityListItem, ListActivitiesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_activities_pages(input),
+            |output: ListActivitiesOutput| output.activities,
+        )
+    }
+
+    /// Auto-paginating version of `list_executions`, streaming every page of results.
+    pub fn list_executions_pages(
+        &self,
+        input: ListExecutionsInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListExecutionsOutput, ListExecutionsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: StepFunctionsClient, input: ListExecutionsInput| async move {
+                client.list_executions(input).await
+            },
+            |input: &ListExecutionsInput, output: &ListExecutionsOutput| {
+                Some(ListExecutionsInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_executions`, streaming the `executions` of every page of results.
+    pub fn list_executions_items(
+        &self,
+        input: ListExecutionsInput,
+    ) -> rusoto_core::pagination::RusotoStream<ExecutionListItem, ListExecutionsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_executions_pages(input),
+            |output: ListExecutionsOutput| output.executions,
+        )
+    }
+
+    /// Auto-paginating version of `list_state_machines`, streaming every page of results.
+    pub fn list_state_machines_pages(
+        &self,
+        input: ListStateMachinesInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListStateMachinesOutput, ListStateMachinesError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: StepFunctionsClient, input: ListStateMachinesInput| async move {
+                client.list_state_machines(input).await
+            },
+            |input: &ListStateMachinesInput, output: &ListStateMachinesOutput| {
+                Some(ListStateMachinesInput {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_state_machines`, streaming the `stateMachines` of every page of results.
+    pub fn list_state_machines_items(
+        &self,
+        input: ListStateMachinesInput,
+    ) -> rusoto_core::pagination::RusotoStream<StateMachineListItem, ListStateMachinesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_state_machines_pages(input),
+            |output: ListStateMachinesOutput| output.state_machines,
+        )
+    }
+}
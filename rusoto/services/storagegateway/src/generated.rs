@@ -67,6 +67,124 @@ pub struct ActivateGatewayOutput {
     pub gateway_arn: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ActiveDirectoryStatus {
+    /// `ACCESS_DENIED`
+    AccessDenied,
+    /// `DETACHED`
+    Detached,
+    /// `JOINED`
+    Joined,
+    /// `JOINING`
+    Joining,
+    /// `NETWORK_ERROR`
+    NetworkError,
+    /// `TIMEOUT`
+    Timeout,
+    /// `UNKNOWN_ERROR`
+    UnknownError,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ActiveDirectoryStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ActiveDirectoryStatus::AccessDenied => "ACCESS_DENIED",
+            ActiveDirectoryStatus::Detached => "DETACHED",
+            ActiveDirectoryStatus::Joined => "JOINED",
+            ActiveDirectoryStatus::Joining => "JOINING",
+            ActiveDirectoryStatus::NetworkError => "NETWORK_ERROR",
+            ActiveDirectoryStatus::Timeout => "TIMEOUT",
+            ActiveDirectoryStatus::UnknownError => "UNKNOWN_ERROR",
+            ActiveDirectoryStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ActiveDirectoryStatus {
+    fn default() -> ActiveDirectoryStatus {
+        ActiveDirectoryStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ActiveDirectoryStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ActiveDirectoryStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ActiveDirectoryStatus {
+    fn from(value: &'a str) -> ActiveDirectoryStatus {
+        match value {
+            "ACCESS_DENIED" => ActiveDirectoryStatus::AccessDenied,
+            "DETACHED" => ActiveDirectoryStatus::Detached,
+            "JOINED" => ActiveDirectoryStatus::Joined,
+            "JOINING" => ActiveDirectoryStatus::Joining,
+            "NETWORK_ERROR" => ActiveDirectoryStatus::NetworkError,
+            "TIMEOUT" => ActiveDirectoryStatus::Timeout,
+            "UNKNOWN_ERROR" => ActiveDirectoryStatus::UnknownError,
+            _ => ActiveDirectoryStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ActiveDirectoryStatus {
+    fn from(value: String) -> ActiveDirectoryStatus {
+        match value.as_str() {
+            "ACCESS_DENIED" => ActiveDirectoryStatus::AccessDenied,
+            "DETACHED" => ActiveDirectoryStatus::Detached,
+            "JOINED" => ActiveDirectoryStatus::Joined,
+            "JOINING" => ActiveDirectoryStatus::Joining,
+            "NETWORK_ERROR" => ActiveDirectoryStatus::NetworkError,
+            "TIMEOUT" => ActiveDirectoryStatus::Timeout,
+            "UNKNOWN_ERROR" => ActiveDirectoryStatus::UnknownError,
+            _ => ActiveDirectoryStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ActiveDirectoryStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ActiveDirectoryStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ActiveDirectoryStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ActiveDirectoryStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ActiveDirectoryStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ActiveDirectoryStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct AddCacheInput {
@@ -202,14 +320,117 @@ pub struct AttachVolumeOutput {
     pub volume_arn: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AvailabilityMonitorTestStatus {
+    /// `COMPLETE`
+    Complete,
+    /// `FAILED`
+    Failed,
+    /// `PENDING`
+    Pending,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AvailabilityMonitorTestStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AvailabilityMonitorTestStatus::Complete => "COMPLETE",
+            AvailabilityMonitorTestStatus::Failed => "FAILED",
+            AvailabilityMonitorTestStatus::Pending => "PENDING",
+            AvailabilityMonitorTestStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AvailabilityMonitorTestStatus {
+    fn default() -> AvailabilityMonitorTestStatus {
+        AvailabilityMonitorTestStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AvailabilityMonitorTestStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AvailabilityMonitorTestStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AvailabilityMonitorTestStatus {
+    fn from(value: &'a str) -> AvailabilityMonitorTestStatus {
+        match value {
+            "COMPLETE" => AvailabilityMonitorTestStatus::Complete,
+            "FAILED" => AvailabilityMonitorTestStatus::Failed,
+            "PENDING" => AvailabilityMonitorTestStatus::Pending,
+            _ => AvailabilityMonitorTestStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AvailabilityMonitorTestStatus {
+    fn from(value: String) -> AvailabilityMonitorTestStatus {
+        match value.as_str() {
+            "COMPLETE" => AvailabilityMonitorTestStatus::Complete,
+            "FAILED" => AvailabilityMonitorTestStatus::Failed,
+            "PENDING" => AvailabilityMonitorTestStatus::Pending,
+            _ => AvailabilityMonitorTestStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AvailabilityMonitorTestStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AvailabilityMonitorTestStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AvailabilityMonitorTestStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AvailabilityMonitorTestStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AvailabilityMonitorTestStatus {
+    fn deserialize<D>(deserializer: D) -> Result<AvailabilityMonitorTestStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes an iSCSI cached volume.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CachediSCSIVolume {
     /// <p>The date the volume was created. Volumes created prior to March 28, 2017 don’t have this time stamp.</p>
     #[serde(rename = "CreatedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<::chrono::DateTime<::chrono::Utc>>,
     #[serde(rename = "KMSKey")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub kms_key: Option<String>,
@@ -415,7 +636,7 @@ pub struct CreateNFSFileShareInput {
     /// <p>A value that sets the access control list permission for objects in the S3 bucket that a file gateway puts objects into. The default value is "private".</p>
     #[serde(rename = "ObjectACL")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub object_acl: Option<String>,
+    pub object_acl: Option<ObjectACL>,
     /// <p>A value that sets the write status of a file share. This value is true if the write status is read-only, and otherwise false.</p>
     #[serde(rename = "ReadOnly")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -491,7 +712,7 @@ pub struct CreateSMBFileShareInput {
     /// <p>A value that sets the access control list permission for objects in the S3 bucket that a file gateway puts objects into. The default value is "private".</p>
     #[serde(rename = "ObjectACL")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub object_acl: Option<String>,
+    pub object_acl: Option<ObjectACL>,
     /// <p>A value that sets the write status of a file share. This value is true if the write status is read-only, and otherwise false.</p>
     #[serde(rename = "ReadOnly")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -909,12 +1130,17 @@ pub struct DescribeAvailabilityMonitorTestOutput {
     pub gateway_arn: Option<String>,
     /// <p>The time the High Availability monitoring test was started. If a test hasn't been performed, the value of this field is null.</p>
     #[serde(rename = "StartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The status of the High Availability monitoring test. If a test hasn't been performed, the value of this field is null.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<AvailabilityMonitorTestStatus>,
 }
 
 /// <p>A JSON object containing the of the gateway.</p>
@@ -1072,7 +1298,7 @@ pub struct DescribeGatewayInformationOutput {
     /// <p>The type of hypervisor environment used by the host.</p>
     #[serde(rename = "HostEnvironment")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub host_environment: Option<String>,
+    pub host_environment: Option<HostEnvironment>,
     /// <p>The date on which the last software update was applied to the gateway. If the gateway has never been updated, this field does not return a value in the response.</p>
     #[serde(rename = "LastSoftwareUpdate")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1179,7 +1405,7 @@ pub struct DescribeSMBSettingsOutput {
     /// <p><p>Indicates the status of a gateway that is a member of the Active Directory domain.</p> <ul> <li> <p>ACCESS<em>DENIED: Indicates that the <code>JoinDomain</code> operation failed due to an authentication error.</p> </li> <li> <p>DETACHED: Indicates that gateway is not joined to a domain.</p> </li> <li> <p>JOINED: Indicates that the gateway has successfully joined a domain.</p> </li> <li> <p>JOINING: Indicates that a <code>JoinDomain</code> operation is in progress.</p> </li> <li> <p>NETWORK</em>ERROR: Indicates that <code>JoinDomain</code> operation failed due to a network or connectivity error.</p> </li> <li> <p>TIMEOUT: Indicates that the <code>JoinDomain</code> operation failed because the operation didn&#39;t complete within the allotted time.</p> </li> <li> <p>UNKNOWN_ERROR: Indicates that the <code>JoinDomain</code> operation failed due to another type of error.</p> </li> </ul></p>
     #[serde(rename = "ActiveDirectoryStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub active_directory_status: Option<String>,
+    pub active_directory_status: Option<ActiveDirectoryStatus>,
     /// <p>The name of the domain that the gateway is joined to.</p>
     #[serde(rename = "DomainName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1194,7 +1420,7 @@ pub struct DescribeSMBSettingsOutput {
     /// <p>The type of security strategy that was specified for file gateway.</p> <p>ClientSpecified: if you use this option, requests are established based on what is negotiated by the client. This option is recommended when you want to maximize compatibility across different clients in your environment. </p> <p>MandatorySigning: if you use this option, file gateway only allows connections from SMBv2 or SMBv3 clients that have signing enabled. This option works with SMB clients on Microsoft Windows Vista, Windows Server 2008 or newer. </p> <p>MandatoryEncryption: if you use this option, file gateway only allows connections from SMBv3 clients that have encryption enabled. This option is highly recommended for environments that handle sensitive data. This option works with SMB clients on Microsoft Windows 8, Windows Server 2012 or newer. </p>
     #[serde(rename = "SMBSecurityStrategy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub smb_security_strategy: Option<String>,
+    pub smb_security_strategy: Option<SMBSecurityStrategy>,
 }
 
 /// <p>A JSON object containing the <a>DescribeSnapshotScheduleInput$VolumeARN</a> of the volume.</p>
@@ -1544,6 +1770,399 @@ pub struct Disk {
     pub disk_status: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorCode {
+    /// `ActivationKeyExpired`
+    ActivationKeyExpired,
+    /// `ActivationKeyInvalid`
+    ActivationKeyInvalid,
+    /// `ActivationKeyNotFound`
+    ActivationKeyNotFound,
+    /// `GatewayInternalError`
+    GatewayInternalError,
+    /// `GatewayNotConnected`
+    GatewayNotConnected,
+    /// `GatewayNotFound`
+    GatewayNotFound,
+    /// `GatewayProxyNetworkConnectionBusy`
+    GatewayProxyNetworkConnectionBusy,
+    /// `AuthenticationFailure`
+    AuthenticationFailure,
+    /// `BandwidthThrottleScheduleNotFound`
+    BandwidthThrottleScheduleNotFound,
+    /// `Blocked`
+    Blocked,
+    /// `CannotExportSnapshot`
+    CannotExportSnapshot,
+    /// `ChapCredentialNotFound`
+    ChapCredentialNotFound,
+    /// `DiskAlreadyAllocated`
+    DiskAlreadyAllocated,
+    /// `DiskDoesNotExist`
+    DiskDoesNotExist,
+    /// `DiskSizeGreaterThanVolumeMaxSize`
+    DiskSizeGreaterThanVolumeMaxSize,
+    /// `DiskSizeLessThanVolumeSize`
+    DiskSizeLessThanVolumeSize,
+    /// `DiskSizeNotGigAligned`
+    DiskSizeNotGigAligned,
+    /// `DuplicateCertificateInfo`
+    DuplicateCertificateInfo,
+    /// `DuplicateSchedule`
+    DuplicateSchedule,
+    /// `EndpointNotFound`
+    EndpointNotFound,
+    /// `IAMNotSupported`
+    IAMNotSupported,
+    /// `InitiatorInvalid`
+    InitiatorInvalid,
+    /// `InitiatorNotFound`
+    InitiatorNotFound,
+    /// `InternalError`
+    InternalError,
+    /// `InvalidGateway`
+    InvalidGateway,
+    /// `InvalidEndpoint`
+    InvalidEndpoint,
+    /// `InvalidParameters`
+    InvalidParameters,
+    /// `InvalidSchedule`
+    InvalidSchedule,
+    /// `LocalStorageLimitExceeded`
+    LocalStorageLimitExceeded,
+    /// `LunAlreadyAllocated `
+    LunAlreadyAllocated,
+    /// `LunInvalid`
+    LunInvalid,
+    /// `JoinDomainInProgress`
+    JoinDomainInProgress,
+    /// `MaximumContentLengthExceeded`
+    MaximumContentLengthExceeded,
+    /// `MaximumTapeCartridgeCountExceeded`
+    MaximumTapeCartridgeCountExceeded,
+    /// `MaximumVolumeCountExceeded`
+    MaximumVolumeCountExceeded,
+    /// `NetworkConfigurationChanged`
+    NetworkConfigurationChanged,
+    /// `NoDisksAvailable`
+    NoDisksAvailable,
+    /// `NotImplemented`
+    NotImplemented,
+    /// `NotSupported`
+    NotSupported,
+    /// `OperationAborted`
+    OperationAborted,
+    /// `OutdatedGateway`
+    OutdatedGateway,
+    /// `ParametersNotImplemented`
+    ParametersNotImplemented,
+    /// `RegionInvalid`
+    RegionInvalid,
+    /// `RequestTimeout`
+    RequestTimeout,
+    /// `ServiceUnavailable`
+    ServiceUnavailable,
+    /// `SnapshotDeleted`
+    SnapshotDeleted,
+    /// `SnapshotIdInvalid`
+    SnapshotIdInvalid,
+    /// `SnapshotInProgress`
+    SnapshotInProgress,
+    /// `SnapshotNotFound`
+    SnapshotNotFound,
+    /// `SnapshotScheduleNotFound`
+    SnapshotScheduleNotFound,
+    /// `StagingAreaFull`
+    StagingAreaFull,
+    /// `StorageFailure`
+    StorageFailure,
+    /// `TapeCartridgeNotFound`
+    TapeCartridgeNotFound,
+    /// `TargetAlreadyExists`
+    TargetAlreadyExists,
+    /// `TargetInvalid`
+    TargetInvalid,
+    /// `TargetNotFound`
+    TargetNotFound,
+    /// `UnauthorizedOperation`
+    UnauthorizedOperation,
+    /// `VolumeAlreadyExists`
+    VolumeAlreadyExists,
+    /// `VolumeIdInvalid`
+    VolumeIdInvalid,
+    /// `VolumeInUse`
+    VolumeInUse,
+    /// `VolumeNotFound`
+    VolumeNotFound,
+    /// `VolumeNotReady`
+    VolumeNotReady,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ErrorCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ErrorCode::ActivationKeyExpired => "ActivationKeyExpired",
+            ErrorCode::ActivationKeyInvalid => "ActivationKeyInvalid",
+            ErrorCode::ActivationKeyNotFound => "ActivationKeyNotFound",
+            ErrorCode::GatewayInternalError => "GatewayInternalError",
+            ErrorCode::GatewayNotConnected => "GatewayNotConnected",
+            ErrorCode::GatewayNotFound => "GatewayNotFound",
+            ErrorCode::GatewayProxyNetworkConnectionBusy => "GatewayProxyNetworkConnectionBusy",
+            ErrorCode::AuthenticationFailure => "AuthenticationFailure",
+            ErrorCode::BandwidthThrottleScheduleNotFound => "BandwidthThrottleScheduleNotFound",
+            ErrorCode::Blocked => "Blocked",
+            ErrorCode::CannotExportSnapshot => "CannotExportSnapshot",
+            ErrorCode::ChapCredentialNotFound => "ChapCredentialNotFound",
+            ErrorCode::DiskAlreadyAllocated => "DiskAlreadyAllocated",
+            ErrorCode::DiskDoesNotExist => "DiskDoesNotExist",
+            ErrorCode::DiskSizeGreaterThanVolumeMaxSize => "DiskSizeGreaterThanVolumeMaxSize",
+            ErrorCode::DiskSizeLessThanVolumeSize => "DiskSizeLessThanVolumeSize",
+            ErrorCode::DiskSizeNotGigAligned => "DiskSizeNotGigAligned",
+            ErrorCode::DuplicateCertificateInfo => "DuplicateCertificateInfo",
+            ErrorCode::DuplicateSchedule => "DuplicateSchedule",
+            ErrorCode::EndpointNotFound => "EndpointNotFound",
+            ErrorCode::IAMNotSupported => "IAMNotSupported",
+            ErrorCode::InitiatorInvalid => "InitiatorInvalid",
+            ErrorCode::InitiatorNotFound => "InitiatorNotFound",
+            ErrorCode::InternalError => "InternalError",
+            ErrorCode::InvalidGateway => "InvalidGateway",
+            ErrorCode::InvalidEndpoint => "InvalidEndpoint",
+            ErrorCode::InvalidParameters => "InvalidParameters",
+            ErrorCode::InvalidSchedule => "InvalidSchedule",
+            ErrorCode::LocalStorageLimitExceeded => "LocalStorageLimitExceeded",
+            ErrorCode::LunAlreadyAllocated => "LunAlreadyAllocated ",
+            ErrorCode::LunInvalid => "LunInvalid",
+            ErrorCode::JoinDomainInProgress => "JoinDomainInProgress",
+            ErrorCode::MaximumContentLengthExceeded => "MaximumContentLengthExceeded",
+            ErrorCode::MaximumTapeCartridgeCountExceeded => "MaximumTapeCartridgeCountExceeded",
+            ErrorCode::MaximumVolumeCountExceeded => "MaximumVolumeCountExceeded",
+            ErrorCode::NetworkConfigurationChanged => "NetworkConfigurationChanged",
+            ErrorCode::NoDisksAvailable => "NoDisksAvailable",
+            ErrorCode::NotImplemented => "NotImplemented",
+            ErrorCode::NotSupported => "NotSupported",
+            ErrorCode::OperationAborted => "OperationAborted",
+            ErrorCode::OutdatedGateway => "OutdatedGateway",
+            ErrorCode::ParametersNotImplemented => "ParametersNotImplemented",
+            ErrorCode::RegionInvalid => "RegionInvalid",
+            ErrorCode::RequestTimeout => "RequestTimeout",
+            ErrorCode::ServiceUnavailable => "ServiceUnavailable",
+            ErrorCode::SnapshotDeleted => "SnapshotDeleted",
+            ErrorCode::SnapshotIdInvalid => "SnapshotIdInvalid",
+            ErrorCode::SnapshotInProgress => "SnapshotInProgress",
+            ErrorCode::SnapshotNotFound => "SnapshotNotFound",
+            ErrorCode::SnapshotScheduleNotFound => "SnapshotScheduleNotFound",
+            ErrorCode::StagingAreaFull => "StagingAreaFull",
+            ErrorCode::StorageFailure => "StorageFailure",
+            ErrorCode::TapeCartridgeNotFound => "TapeCartridgeNotFound",
+            ErrorCode::TargetAlreadyExists => "TargetAlreadyExists",
+            ErrorCode::TargetInvalid => "TargetInvalid",
+            ErrorCode::TargetNotFound => "TargetNotFound",
+            ErrorCode::UnauthorizedOperation => "UnauthorizedOperation",
+            ErrorCode::VolumeAlreadyExists => "VolumeAlreadyExists",
+            ErrorCode::VolumeIdInvalid => "VolumeIdInvalid",
+            ErrorCode::VolumeInUse => "VolumeInUse",
+            ErrorCode::VolumeNotFound => "VolumeNotFound",
+            ErrorCode::VolumeNotReady => "VolumeNotReady",
+            ErrorCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ErrorCode {
+    fn default() -> ErrorCode {
+        ErrorCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ErrorCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ErrorCode {
+    fn from(value: &'a str) -> ErrorCode {
+        match value {
+            "ActivationKeyExpired" => ErrorCode::ActivationKeyExpired,
+            "ActivationKeyInvalid" => ErrorCode::ActivationKeyInvalid,
+            "ActivationKeyNotFound" => ErrorCode::ActivationKeyNotFound,
+            "GatewayInternalError" => ErrorCode::GatewayInternalError,
+            "GatewayNotConnected" => ErrorCode::GatewayNotConnected,
+            "GatewayNotFound" => ErrorCode::GatewayNotFound,
+            "GatewayProxyNetworkConnectionBusy" => ErrorCode::GatewayProxyNetworkConnectionBusy,
+            "AuthenticationFailure" => ErrorCode::AuthenticationFailure,
+            "BandwidthThrottleScheduleNotFound" => ErrorCode::BandwidthThrottleScheduleNotFound,
+            "Blocked" => ErrorCode::Blocked,
+            "CannotExportSnapshot" => ErrorCode::CannotExportSnapshot,
+            "ChapCredentialNotFound" => ErrorCode::ChapCredentialNotFound,
+            "DiskAlreadyAllocated" => ErrorCode::DiskAlreadyAllocated,
+            "DiskDoesNotExist" => ErrorCode::DiskDoesNotExist,
+            "DiskSizeGreaterThanVolumeMaxSize" => ErrorCode::DiskSizeGreaterThanVolumeMaxSize,
+            "DiskSizeLessThanVolumeSize" => ErrorCode::DiskSizeLessThanVolumeSize,
+            "DiskSizeNotGigAligned" => ErrorCode::DiskSizeNotGigAligned,
+            "DuplicateCertificateInfo" => ErrorCode::DuplicateCertificateInfo,
+            "DuplicateSchedule" => ErrorCode::DuplicateSchedule,
+            "EndpointNotFound" => ErrorCode::EndpointNotFound,
+            "IAMNotSupported" => ErrorCode::IAMNotSupported,
+            "InitiatorInvalid" => ErrorCode::InitiatorInvalid,
+            "InitiatorNotFound" => ErrorCode::InitiatorNotFound,
+            "InternalError" => ErrorCode::InternalError,
+            "InvalidGateway" => ErrorCode::InvalidGateway,
+            "InvalidEndpoint" => ErrorCode::InvalidEndpoint,
+            "InvalidParameters" => ErrorCode::InvalidParameters,
+            "InvalidSchedule" => ErrorCode::InvalidSchedule,
+            "LocalStorageLimitExceeded" => ErrorCode::LocalStorageLimitExceeded,
+            "LunAlreadyAllocated " => ErrorCode::LunAlreadyAllocated,
+            "LunInvalid" => ErrorCode::LunInvalid,
+            "JoinDomainInProgress" => ErrorCode::JoinDomainInProgress,
+            "MaximumContentLengthExceeded" => ErrorCode::MaximumContentLengthExceeded,
+            "MaximumTapeCartridgeCountExceeded" => ErrorCode::MaximumTapeCartridgeCountExceeded,
+            "MaximumVolumeCountExceeded" => ErrorCode::MaximumVolumeCountExceeded,
+            "NetworkConfigurationChanged" => ErrorCode::NetworkConfigurationChanged,
+            "NoDisksAvailable" => ErrorCode::NoDisksAvailable,
+            "NotImplemented" => ErrorCode::NotImplemented,
+            "NotSupported" => ErrorCode::NotSupported,
+            "OperationAborted" => ErrorCode::OperationAborted,
+            "OutdatedGateway" => ErrorCode::OutdatedGateway,
+            "ParametersNotImplemented" => ErrorCode::ParametersNotImplemented,
+            "RegionInvalid" => ErrorCode::RegionInvalid,
+            "RequestTimeout" => ErrorCode::RequestTimeout,
+            "ServiceUnavailable" => ErrorCode::ServiceUnavailable,
+            "SnapshotDeleted" => ErrorCode::SnapshotDeleted,
+            "SnapshotIdInvalid" => ErrorCode::SnapshotIdInvalid,
+            "SnapshotInProgress" => ErrorCode::SnapshotInProgress,
+            "SnapshotNotFound" => ErrorCode::SnapshotNotFound,
+            "SnapshotScheduleNotFound" => ErrorCode::SnapshotScheduleNotFound,
+            "StagingAreaFull" => ErrorCode::StagingAreaFull,
+            "StorageFailure" => ErrorCode::StorageFailure,
+            "TapeCartridgeNotFound" => ErrorCode::TapeCartridgeNotFound,
+            "TargetAlreadyExists" => ErrorCode::TargetAlreadyExists,
+            "TargetInvalid" => ErrorCode::TargetInvalid,
+            "TargetNotFound" => ErrorCode::TargetNotFound,
+            "UnauthorizedOperation" => ErrorCode::UnauthorizedOperation,
+            "VolumeAlreadyExists" => ErrorCode::VolumeAlreadyExists,
+            "VolumeIdInvalid" => ErrorCode::VolumeIdInvalid,
+            "VolumeInUse" => ErrorCode::VolumeInUse,
+            "VolumeNotFound" => ErrorCode::VolumeNotFound,
+            "VolumeNotReady" => ErrorCode::VolumeNotReady,
+            _ => ErrorCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ErrorCode {
+    fn from(value: String) -> ErrorCode {
+        match value.as_str() {
+            "ActivationKeyExpired" => ErrorCode::ActivationKeyExpired,
+            "ActivationKeyInvalid" => ErrorCode::ActivationKeyInvalid,
+            "ActivationKeyNotFound" => ErrorCode::ActivationKeyNotFound,
+            "GatewayInternalError" => ErrorCode::GatewayInternalError,
+            "GatewayNotConnected" => ErrorCode::GatewayNotConnected,
+            "GatewayNotFound" => ErrorCode::GatewayNotFound,
+            "GatewayProxyNetworkConnectionBusy" => ErrorCode::GatewayProxyNetworkConnectionBusy,
+            "AuthenticationFailure" => ErrorCode::AuthenticationFailure,
+            "BandwidthThrottleScheduleNotFound" => ErrorCode::BandwidthThrottleScheduleNotFound,
+            "Blocked" => ErrorCode::Blocked,
+            "CannotExportSnapshot" => ErrorCode::CannotExportSnapshot,
+            "ChapCredentialNotFound" => ErrorCode::ChapCredentialNotFound,
+            "DiskAlreadyAllocated" => ErrorCode::DiskAlreadyAllocated,
+            "DiskDoesNotExist" => ErrorCode::DiskDoesNotExist,
+            "DiskSizeGreaterThanVolumeMaxSize" => ErrorCode::DiskSizeGreaterThanVolumeMaxSize,
+            "DiskSizeLessThanVolumeSize" => ErrorCode::DiskSizeLessThanVolumeSize,
+            "DiskSizeNotGigAligned" => ErrorCode::DiskSizeNotGigAligned,
+            "DuplicateCertificateInfo" => ErrorCode::DuplicateCertificateInfo,
+            "DuplicateSchedule" => ErrorCode::DuplicateSchedule,
+            "EndpointNotFound" => ErrorCode::EndpointNotFound,
+            "IAMNotSupported" => ErrorCode::IAMNotSupported,
+            "InitiatorInvalid" => ErrorCode::InitiatorInvalid,
+            "InitiatorNotFound" => ErrorCode::InitiatorNotFound,
+            "InternalError" => ErrorCode::InternalError,
+            "InvalidGateway" => ErrorCode::InvalidGateway,
+            "InvalidEndpoint" => ErrorCode::InvalidEndpoint,
+            "InvalidParameters" => ErrorCode::InvalidParameters,
+            "InvalidSchedule" => ErrorCode::InvalidSchedule,
+            "LocalStorageLimitExceeded" => ErrorCode::LocalStorageLimitExceeded,
+            "LunAlreadyAllocated " => ErrorCode::LunAlreadyAllocated,
+            "LunInvalid" => ErrorCode::LunInvalid,
+            "JoinDomainInProgress" => ErrorCode::JoinDomainInProgress,
+            "MaximumContentLengthExceeded" => ErrorCode::MaximumContentLengthExceeded,
+            "MaximumTapeCartridgeCountExceeded" => ErrorCode::MaximumTapeCartridgeCountExceeded,
+            "MaximumVolumeCountExceeded" => ErrorCode::MaximumVolumeCountExceeded,
+            "NetworkConfigurationChanged" => ErrorCode::NetworkConfigurationChanged,
+            "NoDisksAvailable" => ErrorCode::NoDisksAvailable,
+            "NotImplemented" => ErrorCode::NotImplemented,
+            "NotSupported" => ErrorCode::NotSupported,
+            "OperationAborted" => ErrorCode::OperationAborted,
+            "OutdatedGateway" => ErrorCode::OutdatedGateway,
+            "ParametersNotImplemented" => ErrorCode::ParametersNotImplemented,
+            "RegionInvalid" => ErrorCode::RegionInvalid,
+            "RequestTimeout" => ErrorCode::RequestTimeout,
+            "ServiceUnavailable" => ErrorCode::ServiceUnavailable,
+            "SnapshotDeleted" => ErrorCode::SnapshotDeleted,
+            "SnapshotIdInvalid" => ErrorCode::SnapshotIdInvalid,
+            "SnapshotInProgress" => ErrorCode::SnapshotInProgress,
+            "SnapshotNotFound" => ErrorCode::SnapshotNotFound,
+            "SnapshotScheduleNotFound" => ErrorCode::SnapshotScheduleNotFound,
+            "StagingAreaFull" => ErrorCode::StagingAreaFull,
+            "StorageFailure" => ErrorCode::StorageFailure,
+            "TapeCartridgeNotFound" => ErrorCode::TapeCartridgeNotFound,
+            "TargetAlreadyExists" => ErrorCode::TargetAlreadyExists,
+            "TargetInvalid" => ErrorCode::TargetInvalid,
+            "TargetNotFound" => ErrorCode::TargetNotFound,
+            "UnauthorizedOperation" => ErrorCode::UnauthorizedOperation,
+            "VolumeAlreadyExists" => ErrorCode::VolumeAlreadyExists,
+            "VolumeIdInvalid" => ErrorCode::VolumeIdInvalid,
+            "VolumeInUse" => ErrorCode::VolumeInUse,
+            "VolumeNotFound" => ErrorCode::VolumeNotFound,
+            "VolumeNotReady" => ErrorCode::VolumeNotReady,
+            _ => ErrorCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ErrorCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ErrorCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ErrorCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ErrorCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ErrorCode {
+    fn deserialize<D>(deserializer: D) -> Result<ErrorCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes a file share.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1559,12 +2178,106 @@ pub struct FileShareInfo {
     pub file_share_status: Option<String>,
     #[serde(rename = "FileShareType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub file_share_type: Option<String>,
+    pub file_share_type: Option<FileShareType>,
     #[serde(rename = "GatewayARN")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub gateway_arn: Option<String>,
 }
 
+/// <p>The type of the file share.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FileShareType {
+    /// `NFS`
+    Nfs,
+    /// `SMB`
+    Smb,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl FileShareType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FileShareType::Nfs => "NFS",
+            FileShareType::Smb => "SMB",
+            FileShareType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for FileShareType {
+    fn default() -> FileShareType {
+        FileShareType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for FileShareType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FileShareType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FileShareType {
+    fn from(value: &'a str) -> FileShareType {
+        match value {
+            "NFS" => FileShareType::Nfs,
+            "SMB" => FileShareType::Smb,
+            _ => FileShareType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FileShareType {
+    fn from(value: String) -> FileShareType {
+        match value.as_str() {
+            "NFS" => FileShareType::Nfs,
+            "SMB" => FileShareType::Smb,
+            _ => FileShareType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for FileShareType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<FileShareType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FileShareType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FileShareType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FileShareType {
+    fn deserialize<D>(deserializer: D) -> Result<FileShareType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes a gateway object.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1599,6 +2312,119 @@ pub struct GatewayInfo {
     pub gateway_type: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum HostEnvironment {
+    /// `VMWARE`
+    Vmware,
+    /// `HYPER-V`
+    HyperV,
+    /// `EC2`
+    Ec2,
+    /// `KVM`
+    Kvm,
+    /// `OTHER`
+    Other,
+    /// `SNOWBALL`
+    Snowball,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl HostEnvironment {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            HostEnvironment::Vmware => "VMWARE",
+            HostEnvironment::HyperV => "HYPER-V",
+            HostEnvironment::Ec2 => "EC2",
+            HostEnvironment::Kvm => "KVM",
+            HostEnvironment::Other => "OTHER",
+            HostEnvironment::Snowball => "SNOWBALL",
+            HostEnvironment::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for HostEnvironment {
+    fn default() -> HostEnvironment {
+        HostEnvironment::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for HostEnvironment {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for HostEnvironment {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for HostEnvironment {
+    fn from(value: &'a str) -> HostEnvironment {
+        match value {
+            "VMWARE" => HostEnvironment::Vmware,
+            "HYPER-V" => HostEnvironment::HyperV,
+            "EC2" => HostEnvironment::Ec2,
+            "KVM" => HostEnvironment::Kvm,
+            "OTHER" => HostEnvironment::Other,
+            "SNOWBALL" => HostEnvironment::Snowball,
+            _ => HostEnvironment::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for HostEnvironment {
+    fn from(value: String) -> HostEnvironment {
+        match value.as_str() {
+            "VMWARE" => HostEnvironment::Vmware,
+            "HYPER-V" => HostEnvironment::HyperV,
+            "EC2" => HostEnvironment::Ec2,
+            "KVM" => HostEnvironment::Kvm,
+            "OTHER" => HostEnvironment::Other,
+            "SNOWBALL" => HostEnvironment::Snowball,
+            _ => HostEnvironment::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for HostEnvironment {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<HostEnvironment, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for HostEnvironment {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for HostEnvironment {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for HostEnvironment {
+    fn deserialize<D>(deserializer: D) -> Result<HostEnvironment, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>JoinDomainInput</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -1636,7 +2462,7 @@ pub struct JoinDomainOutput {
     /// <p><p>Indicates the status of the gateway as a member of the Active Directory domain.</p> <ul> <li> <p>ACCESS<em>DENIED: Indicates that the <code>JoinDomain</code> operation failed due to an authentication error.</p> </li> <li> <p>DETACHED: Indicates that gateway is not joined to a domain.</p> </li> <li> <p>JOINED: Indicates that the gateway has successfully joined a domain.</p> </li> <li> <p>JOINING: Indicates that a <code>JoinDomain</code> operation is in progress.</p> </li> <li> <p>NETWORK</em>ERROR: Indicates that <code>JoinDomain</code> operation failed due to a network or connectivity error.</p> </li> <li> <p>TIMEOUT: Indicates that the <code>JoinDomain</code> operation failed because the operation didn&#39;t complete within the allotted time.</p> </li> <li> <p>UNKNOWN_ERROR: Indicates that the <code>JoinDomain</code> operation failed due to another type of error.</p> </li> </ul></p>
     #[serde(rename = "ActiveDirectoryStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub active_directory_status: Option<String>,
+    pub active_directory_status: Option<ActiveDirectoryStatus>,
     /// <p>The unique Amazon Resource Name (ARN) of the gateway that joined the domain.</p>
     #[serde(rename = "GatewayARN")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1926,7 +2752,7 @@ pub struct NFSFileShareInfo {
     pub nfs_file_share_defaults: Option<NFSFileShareDefaults>,
     #[serde(rename = "ObjectACL")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub object_acl: Option<String>,
+    pub object_acl: Option<ObjectACL>,
     #[serde(rename = "Path")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub path: Option<String>,
@@ -1986,6 +2812,125 @@ pub struct NotifyWhenUploadedOutput {
     pub notification_id: Option<String>,
 }
 
+/// <p>A value that sets the access control list (ACL) permission for objects in the S3 bucket that an S3 File Gateway puts objects into. The default value is <code>private</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ObjectACL {
+    /// `private`
+    Private,
+    /// `public-read`
+    PublicRead,
+    /// `public-read-write`
+    PublicReadWrite,
+    /// `authenticated-read`
+    AuthenticatedRead,
+    /// `bucket-owner-read`
+    BucketOwnerRead,
+    /// `bucket-owner-full-control`
+    BucketOwnerFullControl,
+    /// `aws-exec-read`
+    AwsExecRead,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ObjectACL {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ObjectACL::Private => "private",
+            ObjectACL::PublicRead => "public-read",
+            ObjectACL::PublicReadWrite => "public-read-write",
+            ObjectACL::AuthenticatedRead => "authenticated-read",
+            ObjectACL::BucketOwnerRead => "bucket-owner-read",
+            ObjectACL::BucketOwnerFullControl => "bucket-owner-full-control",
+            ObjectACL::AwsExecRead => "aws-exec-read",
+            ObjectACL::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ObjectACL {
+    fn default() -> ObjectACL {
+        ObjectACL::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ObjectACL {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ObjectACL {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ObjectACL {
+    fn from(value: &'a str) -> ObjectACL {
+        match value {
+            "private" => ObjectACL::Private,
+            "public-read" => ObjectACL::PublicRead,
+            "public-read-write" => ObjectACL::PublicReadWrite,
+            "authenticated-read" => ObjectACL::AuthenticatedRead,
+            "bucket-owner-read" => ObjectACL::BucketOwnerRead,
+            "bucket-owner-full-control" => ObjectACL::BucketOwnerFullControl,
+            "aws-exec-read" => ObjectACL::AwsExecRead,
+            _ => ObjectACL::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ObjectACL {
+    fn from(value: String) -> ObjectACL {
+        match value.as_str() {
+            "private" => ObjectACL::Private,
+            "public-read" => ObjectACL::PublicRead,
+            "public-read-write" => ObjectACL::PublicReadWrite,
+            "authenticated-read" => ObjectACL::AuthenticatedRead,
+            "bucket-owner-read" => ObjectACL::BucketOwnerRead,
+            "bucket-owner-full-control" => ObjectACL::BucketOwnerFullControl,
+            "aws-exec-read" => ObjectACL::AwsExecRead,
+            _ => ObjectACL::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ObjectACL {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ObjectACL, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ObjectACL {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ObjectACL {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ObjectACL {
+    fn deserialize<D>(deserializer: D) -> Result<ObjectACL, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>RefreshCacheInput</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -2142,7 +3087,7 @@ pub struct SMBFileShareInfo {
     pub location_arn: Option<String>,
     #[serde(rename = "ObjectACL")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub object_acl: Option<String>,
+    pub object_acl: Option<ObjectACL>,
     /// <p>The file share path used by the SMB client to identify the mount point.</p>
     #[serde(rename = "Path")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2172,6 +3117,104 @@ pub struct SMBFileShareInfo {
     pub valid_user_list: Option<Vec<String>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SMBSecurityStrategy {
+    /// `ClientSpecified`
+    ClientSpecified,
+    /// `MandatorySigning`
+    MandatorySigning,
+    /// `MandatoryEncryption`
+    MandatoryEncryption,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SMBSecurityStrategy {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SMBSecurityStrategy::ClientSpecified => "ClientSpecified",
+            SMBSecurityStrategy::MandatorySigning => "MandatorySigning",
+            SMBSecurityStrategy::MandatoryEncryption => "MandatoryEncryption",
+            SMBSecurityStrategy::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SMBSecurityStrategy {
+    fn default() -> SMBSecurityStrategy {
+        SMBSecurityStrategy::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SMBSecurityStrategy {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SMBSecurityStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SMBSecurityStrategy {
+    fn from(value: &'a str) -> SMBSecurityStrategy {
+        match value {
+            "ClientSpecified" => SMBSecurityStrategy::ClientSpecified,
+            "MandatorySigning" => SMBSecurityStrategy::MandatorySigning,
+            "MandatoryEncryption" => SMBSecurityStrategy::MandatoryEncryption,
+            _ => SMBSecurityStrategy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SMBSecurityStrategy {
+    fn from(value: String) -> SMBSecurityStrategy {
+        match value.as_str() {
+            "ClientSpecified" => SMBSecurityStrategy::ClientSpecified,
+            "MandatorySigning" => SMBSecurityStrategy::MandatorySigning,
+            "MandatoryEncryption" => SMBSecurityStrategy::MandatoryEncryption,
+            _ => SMBSecurityStrategy::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SMBSecurityStrategy {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SMBSecurityStrategy, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SMBSecurityStrategy {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SMBSecurityStrategy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SMBSecurityStrategy {
+    fn deserialize<D>(deserializer: D) -> Result<SMBSecurityStrategy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>SetLocalConsolePasswordInput</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -2264,7 +3307,7 @@ pub struct StartGatewayOutput {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct StorageGatewayError {
     /// <p>Additional information about the error.</p>
-    pub error_code: Option<String>,
+    pub error_code: Option<ErrorCode>,
     /// <p>Human-readable text that provides detail about the error that occurred.</p>
     pub error_details: Option<::std::collections::HashMap<String, String>>,
 }
@@ -2275,8 +3318,13 @@ pub struct StorageGatewayError {
 pub struct StorediSCSIVolume {
     /// <p>The date the volume was created. Volumes created prior to March 28, 2017 don’t have this time stamp.</p>
     #[serde(rename = "CreatedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<::chrono::DateTime<::chrono::Utc>>,
     #[serde(rename = "KMSKey")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub kms_key: Option<String>,
@@ -2370,8 +3418,13 @@ pub struct Tape {
     pub tape_barcode: Option<String>,
     /// <p>The date the virtual tape was created.</p>
     #[serde(rename = "TapeCreatedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub tape_created_date: Option<f64>,
+    pub tape_created_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The size, in bytes, of the virtual tape capacity.</p>
     #[serde(rename = "TapeSizeInBytes")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2396,8 +3449,13 @@ pub struct Tape {
 pub struct TapeArchive {
     /// <p>The time that the archiving of the virtual tape was completed.</p> <p>The default time stamp format is in the ISO8601 extended YYYY-MM-DD'T'HH:MM:SS'Z' format.</p>
     #[serde(rename = "CompletionTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub completion_time: Option<f64>,
+    pub completion_time: Option<::chrono::DateTime<::chrono::Utc>>,
     #[serde(rename = "KMSKey")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub kms_key: Option<String>,
@@ -2419,8 +3477,13 @@ pub struct TapeArchive {
     pub tape_barcode: Option<String>,
     /// <p>The date the virtual tape was created.</p>
     #[serde(rename = "TapeCreatedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub tape_created_date: Option<f64>,
+    pub tape_created_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The size, in bytes, of the archived virtual tape.</p>
     #[serde(rename = "TapeSizeInBytes")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2475,8 +3538,13 @@ pub struct TapeRecoveryPointInfo {
     pub tape_arn: Option<String>,
     /// <p>The time when the point-in-time view of the virtual tape was replicated for later recovery.</p> <p>The default time stamp format of the tape recovery point time is in the ISO8601 extended YYYY-MM-DD'T'HH:MM:SS'Z' format.</p>
     #[serde(rename = "TapeRecoveryPointTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub tape_recovery_point_time: Option<f64>,
+    pub tape_recovery_point_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The size, in bytes, of the virtual tapes to recover.</p>
     #[serde(rename = "TapeSizeInBytes")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2658,7 +3726,7 @@ pub struct UpdateNFSFileShareInput {
     /// <p>A value that sets the access control list permission for objects in the S3 bucket that a file gateway puts objects into. The default value is "private".</p>
     #[serde(rename = "ObjectACL")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub object_acl: Option<String>,
+    pub object_acl: Option<ObjectACL>,
     /// <p>A value that sets the write status of a file share. This value is true if the write status is read-only, and otherwise false.</p>
     #[serde(rename = "ReadOnly")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2717,7 +3785,7 @@ pub struct UpdateSMBFileShareInput {
     /// <p>A value that sets the access control list permission for objects in the S3 bucket that a file gateway puts objects into. The default value is "private".</p>
     #[serde(rename = "ObjectACL")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub object_acl: Option<String>,
+    pub object_acl: Option<ObjectACL>,
     /// <p>A value that sets the write status of a file share. This value is true if the write status is read-only, and otherwise false.</p>
     #[serde(rename = "ReadOnly")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2753,7 +3821,7 @@ pub struct UpdateSMBSecurityStrategyInput {
     pub gateway_arn: String,
     /// <p>Specifies the type of security strategy.</p> <p>ClientSpecified: if you use this option, requests are established based on what is negotiated by the client. This option is recommended when you want to maximize compatibility across different clients in your environment. </p> <p>MandatorySigning: if you use this option, file gateway only allows connections from SMBv2 or SMBv3 clients that have signing enabled. This option works with SMB clients on Microsoft Windows Vista, Windows Server 2008 or newer. </p> <p>MandatoryEncryption: if you use this option, file gateway only allows connections from SMBv3 clients that have encryption enabled. This option is highly recommended for environments that handle sensitive data. This option works with SMB clients on Microsoft Windows 8, Windows Server 2012 or newer. </p>
     #[serde(rename = "SMBSecurityStrategy")]
-    pub smb_security_strategy: String,
+    pub smb_security_strategy: SMBSecurityStrategy,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -6416,11 +7484,12 @@ pub struct StorageGatewayClient {
 impl StorageGatewayClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> StorageGatewayClient {
         StorageGatewayClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Storage Gateway"),
         }
     }
 
@@ -6452,6 +7521,7 @@ impl StorageGateway for StorageGatewayClient {
         input: ActivateGatewayInput,
     ) -> Result<ActivateGatewayOutput, RusotoError<ActivateGatewayError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("ActivateGateway");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.ActivateGateway");
@@ -6479,6 +7549,7 @@ impl StorageGateway for StorageGatewayClient {
         input: AddCacheInput,
     ) -> Result<AddCacheOutput, RusotoError<AddCacheError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("AddCache");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.AddCache");
@@ -6506,6 +7577,7 @@ impl StorageGateway for StorageGatewayClient {
         input: AddTagsToResourceInput,
     ) -> Result<AddTagsToResourceOutput, RusotoError<AddTagsToResourceError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("AddTagsToResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.AddTagsToResource");
@@ -6533,6 +7605,7 @@ impl StorageGateway for StorageGatewayClient {
         input: AddUploadBufferInput,
     ) -> Result<AddUploadBufferOutput, RusotoError<AddUploadBufferError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("AddUploadBuffer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.AddUploadBuffer");
@@ -6560,6 +7633,7 @@ impl StorageGateway for StorageGatewayClient {
         input: AddWorkingStorageInput,
     ) -> Result<AddWorkingStorageOutput, RusotoError<AddWorkingStorageError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("AddWorkingStorage");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.AddWorkingStorage");
@@ -6587,6 +7661,7 @@ impl StorageGateway for StorageGatewayClient {
         input: AssignTapePoolInput,
     ) -> Result<AssignTapePoolOutput, RusotoError<AssignTapePoolError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("AssignTapePool");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.AssignTapePool");
@@ -6614,6 +7689,7 @@ impl StorageGateway for StorageGatewayClient {
         input: AttachVolumeInput,
     ) -> Result<AttachVolumeOutput, RusotoError<AttachVolumeError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("AttachVolume");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.AttachVolume");
@@ -6641,6 +7717,7 @@ impl StorageGateway for StorageGatewayClient {
         input: CancelArchivalInput,
     ) -> Result<CancelArchivalOutput, RusotoError<CancelArchivalError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("CancelArchival");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.CancelArchival");
@@ -6668,6 +7745,7 @@ impl StorageGateway for StorageGatewayClient {
         input: CancelRetrievalInput,
     ) -> Result<CancelRetrievalOutput, RusotoError<CancelRetrievalError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("CancelRetrieval");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.CancelRetrieval");
@@ -6695,6 +7773,7 @@ impl StorageGateway for StorageGatewayClient {
         input: CreateCachediSCSIVolumeInput,
     ) -> Result<CreateCachediSCSIVolumeOutput, RusotoError<CreateCachediSCSIVolumeError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("CreateCachediSCSIVolume");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6726,6 +7805,7 @@ impl StorageGateway for StorageGatewayClient {
         input: CreateNFSFileShareInput,
     ) -> Result<CreateNFSFileShareOutput, RusotoError<CreateNFSFileShareError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("CreateNFSFileShare");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.CreateNFSFileShare");
@@ -6754,6 +7834,7 @@ impl StorageGateway for StorageGatewayClient {
         input: CreateSMBFileShareInput,
     ) -> Result<CreateSMBFileShareOutput, RusotoError<CreateSMBFileShareError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("CreateSMBFileShare");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.CreateSMBFileShare");
@@ -6782,6 +7863,7 @@ impl StorageGateway for StorageGatewayClient {
         input: CreateSnapshotInput,
     ) -> Result<CreateSnapshotOutput, RusotoError<CreateSnapshotError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("CreateSnapshot");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.CreateSnapshot");
@@ -6812,6 +7894,7 @@ impl StorageGateway for StorageGatewayClient {
         RusotoError<CreateSnapshotFromVolumeRecoveryPointError>,
     > {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("CreateSnapshotFromVolumeRecoveryPoint");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6845,6 +7928,7 @@ impl StorageGateway for StorageGatewayClient {
         input: CreateStorediSCSIVolumeInput,
     ) -> Result<CreateStorediSCSIVolumeOutput, RusotoError<CreateStorediSCSIVolumeError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("CreateStorediSCSIVolume");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6876,6 +7960,7 @@ impl StorageGateway for StorageGatewayClient {
         input: CreateTapeWithBarcodeInput,
     ) -> Result<CreateTapeWithBarcodeOutput, RusotoError<CreateTapeWithBarcodeError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("CreateTapeWithBarcode");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6907,6 +7992,7 @@ impl StorageGateway for StorageGatewayClient {
         input: CreateTapesInput,
     ) -> Result<CreateTapesOutput, RusotoError<CreateTapesError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("CreateTapes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.CreateTapes");
@@ -6934,6 +8020,7 @@ impl StorageGateway for StorageGatewayClient {
         input: DeleteBandwidthRateLimitInput,
     ) -> Result<DeleteBandwidthRateLimitOutput, RusotoError<DeleteBandwidthRateLimitError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DeleteBandwidthRateLimit");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6965,6 +8052,7 @@ impl StorageGateway for StorageGatewayClient {
         input: DeleteChapCredentialsInput,
     ) -> Result<DeleteChapCredentialsOutput, RusotoError<DeleteChapCredentialsError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DeleteChapCredentials");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6996,6 +8084,7 @@ impl StorageGateway for StorageGatewayClient {
         input: DeleteFileShareInput,
     ) -> Result<DeleteFileShareOutput, RusotoError<DeleteFileShareError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DeleteFileShare");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.DeleteFileShare");
@@ -7023,6 +8112,7 @@ impl StorageGateway for StorageGatewayClient {
         input: DeleteGatewayInput,
     ) -> Result<DeleteGatewayOutput, RusotoError<DeleteGatewayError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DeleteGateway");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.DeleteGateway");
@@ -7050,6 +8140,7 @@ impl StorageGateway for StorageGatewayClient {
         input: DeleteSnapshotScheduleInput,
     ) -> Result<DeleteSnapshotScheduleOutput, RusotoError<DeleteSnapshotScheduleError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DeleteSnapshotSchedule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7081,6 +8172,7 @@ impl StorageGateway for StorageGatewayClient {
         input: DeleteTapeInput,
     ) -> Result<DeleteTapeOutput, RusotoError<DeleteTapeError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DeleteTape");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.DeleteTape");
@@ -7108,6 +8200,7 @@ impl StorageGateway for StorageGatewayClient {
         input: DeleteTapeArchiveInput,
     ) -> Result<DeleteTapeArchiveOutput, RusotoError<DeleteTapeArchiveError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DeleteTapeArchive");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.DeleteTapeArchive");
@@ -7135,6 +8228,7 @@ impl StorageGateway for StorageGatewayClient {
         input: DeleteVolumeInput,
     ) -> Result<DeleteVolumeOutput, RusotoError<DeleteVolumeError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DeleteVolume");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.DeleteVolume");
@@ -7165,6 +8259,7 @@ impl StorageGateway for StorageGatewayClient {
         RusotoError<DescribeAvailabilityMonitorTestError>,
     > {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DescribeAvailabilityMonitorTest");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7199,6 +8294,7 @@ impl StorageGateway for StorageGatewayClient {
     ) -> Result<DescribeBandwidthRateLimitOutput, RusotoError<DescribeBandwidthRateLimitError>>
     {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DescribeBandwidthRateLimit");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7230,6 +8326,7 @@ impl StorageGateway for StorageGatewayClient {
         input: DescribeCacheInput,
     ) -> Result<DescribeCacheOutput, RusotoError<DescribeCacheError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DescribeCache");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.DescribeCache");
@@ -7258,6 +8355,7 @@ impl StorageGateway for StorageGatewayClient {
     ) -> Result<DescribeCachediSCSIVolumesOutput, RusotoError<DescribeCachediSCSIVolumesError>>
     {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DescribeCachediSCSIVolumes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7289,6 +8387,7 @@ impl StorageGateway for StorageGatewayClient {
         input: DescribeChapCredentialsInput,
     ) -> Result<DescribeChapCredentialsOutput, RusotoError<DescribeChapCredentialsError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DescribeChapCredentials");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7321,6 +8420,7 @@ impl StorageGateway for StorageGatewayClient {
     ) -> Result<DescribeGatewayInformationOutput, RusotoError<DescribeGatewayInformationError>>
     {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DescribeGatewayInformation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7353,6 +8453,7 @@ impl StorageGateway for StorageGatewayClient {
     ) -> Result<DescribeMaintenanceStartTimeOutput, RusotoError<DescribeMaintenanceStartTimeError>>
     {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DescribeMaintenanceStartTime");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7384,6 +8485,7 @@ impl StorageGateway for StorageGatewayClient {
         input: DescribeNFSFileSharesInput,
     ) -> Result<DescribeNFSFileSharesOutput, RusotoError<DescribeNFSFileSharesError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DescribeNFSFileShares");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7415,6 +8517,7 @@ impl StorageGateway for StorageGatewayClient {
         input: DescribeSMBFileSharesInput,
     ) -> Result<DescribeSMBFileSharesOutput, RusotoError<DescribeSMBFileSharesError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DescribeSMBFileShares");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7446,6 +8549,7 @@ impl StorageGateway for StorageGatewayClient {
         input: DescribeSMBSettingsInput,
     ) -> Result<DescribeSMBSettingsOutput, RusotoError<DescribeSMBSettingsError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DescribeSMBSettings");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7477,6 +8581,7 @@ impl StorageGateway for StorageGatewayClient {
         input: DescribeSnapshotScheduleInput,
     ) -> Result<DescribeSnapshotScheduleOutput, RusotoError<DescribeSnapshotScheduleError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DescribeSnapshotSchedule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7509,6 +8614,7 @@ impl StorageGateway for StorageGatewayClient {
     ) -> Result<DescribeStorediSCSIVolumesOutput, RusotoError<DescribeStorediSCSIVolumesError>>
     {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DescribeStorediSCSIVolumes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7540,6 +8646,7 @@ impl StorageGateway for StorageGatewayClient {
         input: DescribeTapeArchivesInput,
     ) -> Result<DescribeTapeArchivesOutput, RusotoError<DescribeTapeArchivesError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DescribeTapeArchives");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7572,6 +8679,7 @@ impl StorageGateway for StorageGatewayClient {
     ) -> Result<DescribeTapeRecoveryPointsOutput, RusotoError<DescribeTapeRecoveryPointsError>>
     {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DescribeTapeRecoveryPoints");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7603,6 +8711,7 @@ impl StorageGateway for StorageGatewayClient {
         input: DescribeTapesInput,
     ) -> Result<DescribeTapesOutput, RusotoError<DescribeTapesError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DescribeTapes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.DescribeTapes");
@@ -7630,6 +8739,7 @@ impl StorageGateway for StorageGatewayClient {
         input: DescribeUploadBufferInput,
     ) -> Result<DescribeUploadBufferOutput, RusotoError<DescribeUploadBufferError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DescribeUploadBuffer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7661,6 +8771,7 @@ impl StorageGateway for StorageGatewayClient {
         input: DescribeVTLDevicesInput,
     ) -> Result<DescribeVTLDevicesOutput, RusotoError<DescribeVTLDevicesError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DescribeVTLDevices");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.DescribeVTLDevices");
@@ -7689,6 +8800,7 @@ impl StorageGateway for StorageGatewayClient {
         input: DescribeWorkingStorageInput,
     ) -> Result<DescribeWorkingStorageOutput, RusotoError<DescribeWorkingStorageError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DescribeWorkingStorage");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7720,6 +8832,7 @@ impl StorageGateway for StorageGatewayClient {
         input: DetachVolumeInput,
     ) -> Result<DetachVolumeOutput, RusotoError<DetachVolumeError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DetachVolume");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.DetachVolume");
@@ -7747,6 +8860,7 @@ impl StorageGateway for StorageGatewayClient {
         input: DisableGatewayInput,
     ) -> Result<DisableGatewayOutput, RusotoError<DisableGatewayError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DisableGateway");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.DisableGateway");
@@ -7774,6 +8888,7 @@ impl StorageGateway for StorageGatewayClient {
         input: JoinDomainInput,
     ) -> Result<JoinDomainOutput, RusotoError<JoinDomainError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("JoinDomain");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.JoinDomain");
@@ -7801,6 +8916,7 @@ impl StorageGateway for StorageGatewayClient {
         input: ListFileSharesInput,
     ) -> Result<ListFileSharesOutput, RusotoError<ListFileSharesError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("ListFileShares");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.ListFileShares");
@@ -7828,6 +8944,7 @@ impl StorageGateway for StorageGatewayClient {
         input: ListGatewaysInput,
     ) -> Result<ListGatewaysOutput, RusotoError<ListGatewaysError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("ListGateways");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.ListGateways");
@@ -7855,6 +8972,7 @@ impl StorageGateway for StorageGatewayClient {
         input: ListLocalDisksInput,
     ) -> Result<ListLocalDisksOutput, RusotoError<ListLocalDisksError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("ListLocalDisks");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.ListLocalDisks");
@@ -7882,6 +9000,7 @@ impl StorageGateway for StorageGatewayClient {
         input: ListTagsForResourceInput,
     ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7913,6 +9032,7 @@ impl StorageGateway for StorageGatewayClient {
         input: ListTapesInput,
     ) -> Result<ListTapesOutput, RusotoError<ListTapesError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("ListTapes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.ListTapes");
@@ -7940,6 +9060,7 @@ impl StorageGateway for StorageGatewayClient {
         input: ListVolumeInitiatorsInput,
     ) -> Result<ListVolumeInitiatorsOutput, RusotoError<ListVolumeInitiatorsError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("ListVolumeInitiators");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7971,6 +9092,7 @@ impl StorageGateway for StorageGatewayClient {
         input: ListVolumeRecoveryPointsInput,
     ) -> Result<ListVolumeRecoveryPointsOutput, RusotoError<ListVolumeRecoveryPointsError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("ListVolumeRecoveryPoints");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8002,6 +9124,7 @@ impl StorageGateway for StorageGatewayClient {
         input: ListVolumesInput,
     ) -> Result<ListVolumesOutput, RusotoError<ListVolumesError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("ListVolumes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.ListVolumes");
@@ -8029,6 +9152,7 @@ impl StorageGateway for StorageGatewayClient {
         input: NotifyWhenUploadedInput,
     ) -> Result<NotifyWhenUploadedOutput, RusotoError<NotifyWhenUploadedError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("NotifyWhenUploaded");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.NotifyWhenUploaded");
@@ -8057,6 +9181,7 @@ impl StorageGateway for StorageGatewayClient {
         input: RefreshCacheInput,
     ) -> Result<RefreshCacheOutput, RusotoError<RefreshCacheError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("RefreshCache");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.RefreshCache");
@@ -8084,6 +9209,7 @@ impl StorageGateway for StorageGatewayClient {
         input: RemoveTagsFromResourceInput,
     ) -> Result<RemoveTagsFromResourceOutput, RusotoError<RemoveTagsFromResourceError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("RemoveTagsFromResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8115,6 +9241,7 @@ impl StorageGateway for StorageGatewayClient {
         input: ResetCacheInput,
     ) -> Result<ResetCacheOutput, RusotoError<ResetCacheError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("ResetCache");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.ResetCache");
@@ -8142,6 +9269,7 @@ impl StorageGateway for StorageGatewayClient {
         input: RetrieveTapeArchiveInput,
     ) -> Result<RetrieveTapeArchiveOutput, RusotoError<RetrieveTapeArchiveError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("RetrieveTapeArchive");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8173,6 +9301,7 @@ impl StorageGateway for StorageGatewayClient {
         input: RetrieveTapeRecoveryPointInput,
     ) -> Result<RetrieveTapeRecoveryPointOutput, RusotoError<RetrieveTapeRecoveryPointError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("RetrieveTapeRecoveryPoint");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8204,6 +9333,7 @@ impl StorageGateway for StorageGatewayClient {
         input: SetLocalConsolePasswordInput,
     ) -> Result<SetLocalConsolePasswordOutput, RusotoError<SetLocalConsolePasswordError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("SetLocalConsolePassword");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8235,6 +9365,7 @@ impl StorageGateway for StorageGatewayClient {
         input: SetSMBGuestPasswordInput,
     ) -> Result<SetSMBGuestPasswordOutput, RusotoError<SetSMBGuestPasswordError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("SetSMBGuestPassword");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8266,6 +9397,7 @@ impl StorageGateway for StorageGatewayClient {
         input: ShutdownGatewayInput,
     ) -> Result<ShutdownGatewayOutput, RusotoError<ShutdownGatewayError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("ShutdownGateway");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.ShutdownGateway");
@@ -8294,6 +9426,7 @@ impl StorageGateway for StorageGatewayClient {
     ) -> Result<StartAvailabilityMonitorTestOutput, RusotoError<StartAvailabilityMonitorTestError>>
     {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("StartAvailabilityMonitorTest");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8325,6 +9458,7 @@ impl StorageGateway for StorageGatewayClient {
         input: StartGatewayInput,
     ) -> Result<StartGatewayOutput, RusotoError<StartGatewayError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("StartGateway");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.StartGateway");
@@ -8352,6 +9486,7 @@ impl StorageGateway for StorageGatewayClient {
         input: UpdateBandwidthRateLimitInput,
     ) -> Result<UpdateBandwidthRateLimitOutput, RusotoError<UpdateBandwidthRateLimitError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("UpdateBandwidthRateLimit");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8383,6 +9518,7 @@ impl StorageGateway for StorageGatewayClient {
         input: UpdateChapCredentialsInput,
     ) -> Result<UpdateChapCredentialsOutput, RusotoError<UpdateChapCredentialsError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("UpdateChapCredentials");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8414,6 +9550,7 @@ impl StorageGateway for StorageGatewayClient {
         input: UpdateGatewayInformationInput,
     ) -> Result<UpdateGatewayInformationOutput, RusotoError<UpdateGatewayInformationError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("UpdateGatewayInformation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8445,6 +9582,7 @@ impl StorageGateway for StorageGatewayClient {
         input: UpdateGatewaySoftwareNowInput,
     ) -> Result<UpdateGatewaySoftwareNowOutput, RusotoError<UpdateGatewaySoftwareNowError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("UpdateGatewaySoftwareNow");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8477,6 +9615,7 @@ impl StorageGateway for StorageGatewayClient {
     ) -> Result<UpdateMaintenanceStartTimeOutput, RusotoError<UpdateMaintenanceStartTimeError>>
     {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("UpdateMaintenanceStartTime");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8508,6 +9647,7 @@ impl StorageGateway for StorageGatewayClient {
         input: UpdateNFSFileShareInput,
     ) -> Result<UpdateNFSFileShareOutput, RusotoError<UpdateNFSFileShareError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("UpdateNFSFileShare");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.UpdateNFSFileShare");
@@ -8536,6 +9676,7 @@ impl StorageGateway for StorageGatewayClient {
         input: UpdateSMBFileShareInput,
     ) -> Result<UpdateSMBFileShareOutput, RusotoError<UpdateSMBFileShareError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("UpdateSMBFileShare");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.UpdateSMBFileShare");
@@ -8564,6 +9705,7 @@ impl StorageGateway for StorageGatewayClient {
         input: UpdateSMBSecurityStrategyInput,
     ) -> Result<UpdateSMBSecurityStrategyOutput, RusotoError<UpdateSMBSecurityStrategyError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("UpdateSMBSecurityStrategy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8595,6 +9737,7 @@ impl StorageGateway for StorageGatewayClient {
         input: UpdateSnapshotScheduleInput,
     ) -> Result<UpdateSnapshotScheduleOutput, RusotoError<UpdateSnapshotScheduleError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("UpdateSnapshotSchedule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8626,6 +9769,7 @@ impl StorageGateway for StorageGatewayClient {
         input: UpdateVTLDeviceTypeInput,
     ) -> Result<UpdateVTLDeviceTypeOutput, RusotoError<UpdateVTLDeviceTypeError>> {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("UpdateVTLDeviceType");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8651,3 +9795,292 @@ impl StorageGateway for StorageGatewayClient {
         }
     }
 }
+impl StorageGatewayClient {
+    /// Auto-paginating version of `describe_tape_archives`, streaming every page of results.
+    pub fn describe_tape_archives_pages(
+        &self,
+        input: DescribeTapeArchivesInput,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeTapeArchivesOutput, DescribeTapeArchivesError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: StorageGatewayClient, input: DescribeTapeArchivesInput| async move {
+                client.describe_tape_archives(input).await
+            },
+            |input: &DescribeTapeArchivesInput, output: &DescribeTapeArchivesOutput| {
+                Some(DescribeTapeArchivesInput {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_tape_archives`, streaming the `TapeArchives` of every page of results.
+    pub fn describe_tape_archives_items(
+        &self,
+        input: DescribeTapeArchivesInput,
+    ) -> rusoto_core::pagination::RusotoStream<TapeArchive, DescribeTapeArchivesError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_tape_archives_pages(input),
+            |output: DescribeTapeArchivesOutput| output.tape_archives.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_tape_recovery_points`, streaming every page of results.
+    pub fn describe_tape_recovery_points_pages(
+        &self,
+        input: DescribeTapeRecoveryPointsInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeTapeRecoveryPointsOutput,
+        DescribeTapeRecoveryPointsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: StorageGatewayClient, input: DescribeTapeRecoveryPointsInput| async move {
+                client.describe_tape_recovery_points(input).await
+            },
+            |input: &DescribeTapeRecoveryPointsInput, output: &DescribeTapeRecoveryPointsOutput| {
+                Some(DescribeTapeRecoveryPointsInput {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_tape_recovery_points`, streaming the `TapeRecoveryPointInfos` of every page of results.
+    pub fn describe_tape_recovery_points_items(
+        &self,
+        input: DescribeTapeRecoveryPointsInput,
+    ) -> rusoto_core::pagination::RusotoStream<TapeRecoveryPointInfo, DescribeTapeRecoveryPointsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_tape_recovery_points_pages(input),
+            |output: DescribeTapeRecoveryPointsOutput| {
+                output.tape_recovery_point_infos.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_tapes`, streaming every page of results.
+    pub fn describe_tapes_pages(
+        &self,
+        input: DescribeTapesInput,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeTapesOutput, DescribeTapesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: StorageGatewayClient, input: DescribeTapesInput| async move {
+                client.describe_tapes(input).await
+            },
+            |input: &DescribeTapesInput, output: &DescribeTapesOutput| {
+                Some(DescribeTapesInput {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_tapes`, streaming the `Tapes` of every page of results.
+    pub fn describe_tapes_items(
+        &self,
+        input: DescribeTapesInput,
+    ) -> rusoto_core::pagination::RusotoStream<Tape, DescribeTapesError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_tapes_pages(input),
+            |output: DescribeTapesOutput| output.tapes.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_vtl_devices`, streaming every page of results.
+    pub fn describe_vtl_devices_pages(
+        &self,
+        input: DescribeVTLDevicesInput,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeVTLDevicesOutput, DescribeVTLDevicesError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: StorageGatewayClient, input: DescribeVTLDevicesInput| async move {
+                client.describe_vtl_devices(input).await
+            },
+            |input: &DescribeVTLDevicesInput, output: &DescribeVTLDevicesOutput| {
+                Some(DescribeVTLDevicesInput {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_vtl_devices`, streaming the `VTLDevices` of every page of results.
+    pub fn describe_vtl_devices_items(
+        &self,
+        input: DescribeVTLDevicesInput,
+    ) -> rusoto_core::pagination::RusotoStream<VTLDevice, DescribeVTLDevicesError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_vtl_devices_pages(input),
+            |output: DescribeVTLDevicesOutput| output.vtl_devices.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_file_shares`, streaming every page of results.
+    pub fn list_file_shares_pages(
+        &self,
+        input: ListFileSharesInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListFileSharesOutput, ListFileSharesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: StorageGatewayClient, input: ListFileSharesInput| async move {
+                client.list_file_shares(input).await
+            },
+            |input: &ListFileSharesInput, output: &ListFileSharesOutput| {
+                Some(ListFileSharesInput {
+                    marker: Some(output.next_marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_file_shares`, streaming the `FileShareInfoList` of every page of results.
+    pub fn list_file_shares_items(
+        &self,
+        input: ListFileSharesInput,
+    ) -> rusoto_core::pagination::RusotoStream<FileShareInfo, ListFileSharesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_file_shares_pages(input),
+            |output: ListFileSharesOutput| output.file_share_info_list.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_gateways`, streaming every page of results.
+    pub fn list_gateways_pages(
+        &self,
+        input: ListGatewaysInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListGatewaysOutput, ListGatewaysError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: StorageGatewayClient, input: ListGatewaysInput| async move {
+                client.list_gateways(input).await
+            },
+            |input: &ListGatewaysInput, output: &ListGatewaysOutput| {
+                Some(ListGatewaysInput {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_gateways`, streaming the `Gateways` of every page of results.
+    pub fn list_gateways_items(
+        &self,
+        input: ListGatewaysInput,
+    ) -> rusoto_core::pagination::RusotoStream<GatewayInfo, ListGatewaysError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_gateways_pages(input),
+            |output: ListGatewaysOutput| output.gateways.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_tags_for_resource`, streaming every page of results.
+    pub fn list_tags_for_resource_pages(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListTagsForResourceOutput, ListTagsForResourceError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: StorageGatewayClient, input: ListTagsForResourceInput| async move {
+                client.list_tags_for_resource(input).await
+            },
+            |input: &ListTagsForResourceInput, output: &ListTagsForResourceOutput| {
+                Some(ListTagsForResourceInput {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_tags_for_resource`, streaming the `Tags` of every page of results.
+    pub fn list_tags_for_resource_items(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> rusoto_core::pagination::RusotoStream<Tag, ListTagsForResourceError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_tags_for_resource_pages(input),
+            |output: ListTagsForResourceOutput| output.tags.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_tapes`, streaming every page of results.
+    pub fn list_tapes_pages(
+        &self,
+        input: ListTapesInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListTapesOutput, ListTapesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: StorageGatewayClient, input: ListTapesInput| async move {
+                client.list_tapes(input).await
+            },
+            |input: &ListTapesInput, output: &ListTapesOutput| {
+                Some(ListTapesInput {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_tapes`, streaming the `TapeInfos` of every page of results.
+    pub fn list_tapes_items(
+        &self,
+        input: ListTapesInput,
+    ) -> rusoto_core::pagination::RusotoStream<TapeInfo, ListTapesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_tapes_pages(input),
+            |output: ListTapesOutput| output.tape_infos.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_volumes`, streaming every page of results.
+    pub fn list_volumes_pages(
+        &self,
+        input: ListVolumesInput,
+    ) -> rusoto_core::pagination::RusotoStream<ListVolumesOutput, ListVolumesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: StorageGatewayClient, input: ListVolumesInput| async move {
+                client.list_volumes(input).await
+            },
+            |input: &ListVolumesInput, output: &ListVolumesOutput| {
+                Some(ListVolumesInput {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_volumes`, streaming the `VolumeInfos` of every page of results.
+    pub fn list_volumes_items(
+        &self,
+        input: ListVolumesInput,
+    ) -> rusoto_core::pagination::RusotoStream<VolumeInfo, ListVolumesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_volumes_pages(input),
+            |output: ListVolumesOutput| output.volume_infos.unwrap_or_default(),
+        )
+    }
+}
@@ -1,5 +1,4 @@
 use async_trait::async_trait;
-use chrono::prelude::*;
 use chrono::Duration;
 
 use rusoto_core;
@@ -33,18 +32,11 @@ impl NewAwsCredsForStsCreds for AwsCredentials {
     fn new_for_credentials(
         sts_creds: crate::generated::Credentials,
     ) -> Result<AwsCredentials, CredentialsError> {
-        let expires_at = Some(
-            sts_creds
-                .expiration
-                .parse::<DateTime<Utc>>()
-                .map_err(CredentialsError::from)?,
-        );
-
         Ok(AwsCredentials::new(
             sts_creds.access_key_id,
             sts_creds.secret_access_key,
             Some(sts_creds.session_token),
-            expires_at,
+            Some(sts_creds.expiration),
         ))
     }
 }
@@ -5,5 +5,8 @@ pub use self::credential::{
     StsWebIdentityFederationSessionCredentialsProvider,
 };
 
+mod profile;
+pub use self::profile::StsRoleAssumer;
+
 mod web_identity;
 pub use self::web_identity::*;
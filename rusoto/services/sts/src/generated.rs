@@ -502,7 +502,7 @@ pub struct Credentials {
     /// <p>The access key ID that identifies the temporary security credentials.</p>
     pub access_key_id: String,
     /// <p>The date on which the current credentials expire.</p>
-    pub expiration: String,
+    pub expiration: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The secret access key that can be used to sign requests.</p>
     pub secret_access_key: String,
     /// <p>The token that users must pass to the service API to use the temporary credentials.</p>
@@ -541,9 +541,12 @@ impl CredentialsDeserializer {
 struct DateTypeDeserializer;
 impl DateTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<::chrono::DateTime<::chrono::Utc>, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::proto::xml::util::timestamp_characters(stack)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -1622,11 +1625,12 @@ pub struct StsClient {
 impl StsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> StsClient {
         StsClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "STS"),
         }
     }
 
@@ -1658,6 +1662,7 @@ impl Sts for StsClient {
         input: AssumeRoleRequest,
     ) -> Result<AssumeRoleResponse, RusotoError<AssumeRoleError>> {
         let mut request = SignedRequest::new("POST", "sts", &self.region, "/");
+        request.set_operation("AssumeRole");
         let mut params = Params::new();
 
         params.put("Action", "AssumeRole");
@@ -1704,6 +1709,7 @@ impl Sts for StsClient {
         input: AssumeRoleWithSAMLRequest,
     ) -> Result<AssumeRoleWithSAMLResponse, RusotoError<AssumeRoleWithSAMLError>> {
         let mut request = SignedRequest::new("POST", "sts", &self.region, "/");
+        request.set_operation("AssumeRoleWithSAML");
         let mut params = Params::new();
 
         params.put("Action", "AssumeRoleWithSAML");
@@ -1754,6 +1760,7 @@ impl Sts for StsClient {
     ) -> Result<AssumeRoleWithWebIdentityResponse, RusotoError<AssumeRoleWithWebIdentityError>>
     {
         let mut request = SignedRequest::new("POST", "sts", &self.region, "/");
+        request.set_operation("AssumeRoleWithWebIdentity");
         let mut params = Params::new();
 
         params.put("Action", "AssumeRoleWithWebIdentity");
@@ -1804,6 +1811,7 @@ impl Sts for StsClient {
     ) -> Result<DecodeAuthorizationMessageResponse, RusotoError<DecodeAuthorizationMessageError>>
     {
         let mut request = SignedRequest::new("POST", "sts", &self.region, "/");
+        request.set_operation("DecodeAuthorizationMessage");
         let mut params = Params::new();
 
         params.put("Action", "DecodeAuthorizationMessage");
@@ -1853,6 +1861,7 @@ impl Sts for StsClient {
         input: GetAccessKeyInfoRequest,
     ) -> Result<GetAccessKeyInfoResponse, RusotoError<GetAccessKeyInfoError>> {
         let mut request = SignedRequest::new("POST", "sts", &self.region, "/");
+        request.set_operation("GetAccessKeyInfo");
         let mut params = Params::new();
 
         params.put("Action", "GetAccessKeyInfo");
@@ -1902,6 +1911,7 @@ impl Sts for StsClient {
         input: GetCallerIdentityRequest,
     ) -> Result<GetCallerIdentityResponse, RusotoError<GetCallerIdentityError>> {
         let mut request = SignedRequest::new("POST", "sts", &self.region, "/");
+        request.set_operation("GetCallerIdentity");
         let mut params = Params::new();
 
         params.put("Action", "GetCallerIdentity");
@@ -1951,6 +1961,7 @@ impl Sts for StsClient {
         input: GetFederationTokenRequest,
     ) -> Result<GetFederationTokenResponse, RusotoError<GetFederationTokenError>> {
         let mut request = SignedRequest::new("POST", "sts", &self.region, "/");
+        request.set_operation("GetFederationToken");
         let mut params = Params::new();
 
         params.put("Action", "GetFederationToken");
@@ -2000,6 +2011,7 @@ impl Sts for StsClient {
         input: GetSessionTokenRequest,
     ) -> Result<GetSessionTokenResponse, RusotoError<GetSessionTokenError>> {
         let mut request = SignedRequest::new("POST", "sts", &self.region, "/");
+        request.set_operation("GetSessionToken");
         let mut params = Params::new();
 
         params.put("Action", "GetSessionToken");
@@ -1407,11 +1407,12 @@ pub struct AWSSupportClient {
 impl AWSSupportClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> AWSSupportClient {
         AWSSupportClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Support"),
         }
     }
 
@@ -1443,6 +1444,7 @@ impl AWSSupport for AWSSupportClient {
         input: AddAttachmentsToSetRequest,
     ) -> Result<AddAttachmentsToSetResponse, RusotoError<AddAttachmentsToSetError>> {
         let mut request = SignedRequest::new("POST", "support", &self.region, "/");
+        request.set_operation("AddAttachmentsToSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSSupport_20130415.AddAttachmentsToSet");
@@ -1471,6 +1473,7 @@ impl AWSSupport for AWSSupportClient {
         input: AddCommunicationToCaseRequest,
     ) -> Result<AddCommunicationToCaseResponse, RusotoError<AddCommunicationToCaseError>> {
         let mut request = SignedRequest::new("POST", "support", &self.region, "/");
+        request.set_operation("AddCommunicationToCase");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSSupport_20130415.AddCommunicationToCase");
@@ -1499,6 +1502,7 @@ impl AWSSupport for AWSSupportClient {
         input: CreateCaseRequest,
     ) -> Result<CreateCaseResponse, RusotoError<CreateCaseError>> {
         let mut request = SignedRequest::new("POST", "support", &self.region, "/");
+        request.set_operation("CreateCase");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSSupport_20130415.CreateCase");
@@ -1526,6 +1530,7 @@ impl AWSSupport for AWSSupportClient {
         input: DescribeAttachmentRequest,
     ) -> Result<DescribeAttachmentResponse, RusotoError<DescribeAttachmentError>> {
         let mut request = SignedRequest::new("POST", "support", &self.region, "/");
+        request.set_operation("DescribeAttachment");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSSupport_20130415.DescribeAttachment");
@@ -1554,6 +1559,7 @@ impl AWSSupport for AWSSupportClient {
         input: DescribeCasesRequest,
     ) -> Result<DescribeCasesResponse, RusotoError<DescribeCasesError>> {
         let mut request = SignedRequest::new("POST", "support", &self.region, "/");
+        request.set_operation("DescribeCases");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSSupport_20130415.DescribeCases");
@@ -1581,6 +1587,7 @@ impl AWSSupport for AWSSupportClient {
         input: DescribeCommunicationsRequest,
     ) -> Result<DescribeCommunicationsResponse, RusotoError<DescribeCommunicationsError>> {
         let mut request = SignedRequest::new("POST", "support", &self.region, "/");
+        request.set_operation("DescribeCommunications");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSSupport_20130415.DescribeCommunications");
@@ -1609,6 +1616,7 @@ impl AWSSupport for AWSSupportClient {
         input: DescribeServicesRequest,
     ) -> Result<DescribeServicesResponse, RusotoError<DescribeServicesError>> {
         let mut request = SignedRequest::new("POST", "support", &self.region, "/");
+        request.set_operation("DescribeServices");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSSupport_20130415.DescribeServices");
@@ -1637,6 +1645,7 @@ impl AWSSupport for AWSSupportClient {
         input: DescribeSeverityLevelsRequest,
     ) -> Result<DescribeSeverityLevelsResponse, RusotoError<DescribeSeverityLevelsError>> {
         let mut request = SignedRequest::new("POST", "support", &self.region, "/");
+        request.set_operation("DescribeSeverityLevels");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSSupport_20130415.DescribeSeverityLevels");
@@ -1668,6 +1677,7 @@ impl AWSSupport for AWSSupportClient {
         RusotoError<DescribeTrustedAdvisorCheckRefreshStatusesError>,
     > {
         let mut request = SignedRequest::new("POST", "support", &self.region, "/");
+        request.set_operation("DescribeTrustedAdvisorCheckRefreshStatuses");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1702,6 +1712,7 @@ impl AWSSupport for AWSSupportClient {
         RusotoError<DescribeTrustedAdvisorCheckResultError>,
     > {
         let mut request = SignedRequest::new("POST", "support", &self.region, "/");
+        request.set_operation("DescribeTrustedAdvisorCheckResult");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1738,6 +1749,7 @@ impl AWSSupport for AWSSupportClient {
         RusotoError<DescribeTrustedAdvisorCheckSummariesError>,
     > {
         let mut request = SignedRequest::new("POST", "support", &self.region, "/");
+        request.set_operation("DescribeTrustedAdvisorCheckSummaries");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1772,6 +1784,7 @@ impl AWSSupport for AWSSupportClient {
     ) -> Result<DescribeTrustedAdvisorChecksResponse, RusotoError<DescribeTrustedAdvisorChecksError>>
     {
         let mut request = SignedRequest::new("POST", "support", &self.region, "/");
+        request.set_operation("DescribeTrustedAdvisorChecks");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1804,6 +1817,7 @@ impl AWSSupport for AWSSupportClient {
     ) -> Result<RefreshTrustedAdvisorCheckResponse, RusotoError<RefreshTrustedAdvisorCheckError>>
     {
         let mut request = SignedRequest::new("POST", "support", &self.region, "/");
+        request.set_operation("RefreshTrustedAdvisorCheck");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1835,6 +1849,7 @@ impl AWSSupport for AWSSupportClient {
         input: ResolveCaseRequest,
     ) -> Result<ResolveCaseResponse, RusotoError<ResolveCaseError>> {
         let mut request = SignedRequest::new("POST", "support", &self.region, "/");
+        request.set_operation("ResolveCase");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSSupport_20130415.ResolveCase");
@@ -1856,3 +1871,69 @@ impl AWSSupport for AWSSupportClient {
         }
     }
 }
+impl AWSSupportClient {
+    /// Auto-paginating version of `describe_cases`, streaming every page of results.
+    pub fn describe_cases_pages(
+        &self,
+        input: DescribeCasesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeCasesResponse, DescribeCasesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AWSSupportClient, input: DescribeCasesRequest| async move {
+                client.describe_cases(input).await
+            },
+            |input: &DescribeCasesRequest, output: &DescribeCasesResponse| {
+                Some(DescribeCasesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_cases`, streaming the `cases` of every page of results.
+    pub fn describe_cases_items(
+        &self,
+        input: DescribeCasesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<CaseDetails, DescribeCasesError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_cases_pages(input),
+            |output: DescribeCasesResponse| output.cases.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_communications`, streaming every page of results.
+    pub fn describe_communications_pages(
+        &self,
+        input: DescribeCommunicationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeCommunicationsResponse,
+        DescribeCommunicationsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AWSSupportClient, input: DescribeCommunicationsRequest| async move {
+                client.describe_communications(input).await
+            },
+            |input: &DescribeCommunicationsRequest, output: &DescribeCommunicationsResponse| {
+                Some(DescribeCommunicationsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_communications`, streaming the `communications` of every page of results.
+    pub fn describe_communications_items(
+        &self,
+        input: DescribeCommunicationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Communication, DescribeCommunicationsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_communications_pages(input),
+            |output: DescribeCommunicationsResponse| output.communications.unwrap_or_default(),
+        )
+    }
+}
@@ -201,9 +201,112 @@ pub struct ActivityTaskTimedOutEventAttributes {
     pub started_event_id: i64,
     /// <p>The type of the timeout that caused this event.</p>
     #[serde(rename = "timeoutType")]
-    pub timeout_type: String,
+    pub timeout_type: ActivityTaskTimeoutType,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ActivityTaskTimeoutType {
+    /// `START_TO_CLOSE`
+    StartToClose,
+    /// `SCHEDULE_TO_START`
+    ScheduleToStart,
+    /// `SCHEDULE_TO_CLOSE`
+    ScheduleToClose,
+    /// `HEARTBEAT`
+    Heartbeat,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ActivityTaskTimeoutType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ActivityTaskTimeoutType::StartToClose => "START_TO_CLOSE",
+            ActivityTaskTimeoutType::ScheduleToStart => "SCHEDULE_TO_START",
+            ActivityTaskTimeoutType::ScheduleToClose => "SCHEDULE_TO_CLOSE",
+            ActivityTaskTimeoutType::Heartbeat => "HEARTBEAT",
+            ActivityTaskTimeoutType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ActivityTaskTimeoutType {
+    fn default() -> ActivityTaskTimeoutType {
+        ActivityTaskTimeoutType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ActivityTaskTimeoutType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ActivityTaskTimeoutType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ActivityTaskTimeoutType {
+    fn from(value: &'a str) -> ActivityTaskTimeoutType {
+        match value {
+            "START_TO_CLOSE" => ActivityTaskTimeoutType::StartToClose,
+            "SCHEDULE_TO_START" => ActivityTaskTimeoutType::ScheduleToStart,
+            "SCHEDULE_TO_CLOSE" => ActivityTaskTimeoutType::ScheduleToClose,
+            "HEARTBEAT" => ActivityTaskTimeoutType::Heartbeat,
+            _ => ActivityTaskTimeoutType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ActivityTaskTimeoutType {
+    fn from(value: String) -> ActivityTaskTimeoutType {
+        match value.as_str() {
+            "START_TO_CLOSE" => ActivityTaskTimeoutType::StartToClose,
+            "SCHEDULE_TO_START" => ActivityTaskTimeoutType::ScheduleToStart,
+            "SCHEDULE_TO_CLOSE" => ActivityTaskTimeoutType::ScheduleToClose,
+            "HEARTBEAT" => ActivityTaskTimeoutType::Heartbeat,
+            _ => ActivityTaskTimeoutType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ActivityTaskTimeoutType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ActivityTaskTimeoutType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ActivityTaskTimeoutType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ActivityTaskTimeoutType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for ActivityTaskTimeoutType {
+    fn deserialize<D>(deserializer: D) -> Result<ActivityTaskTimeoutType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents an activity type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ActivityType {
@@ -266,18 +369,28 @@ pub struct ActivityTypeInfo {
     pub activity_type: ActivityType,
     /// <p>The date and time this activity type was created through <a>RegisterActivityType</a>.</p>
     #[serde(rename = "creationDate")]
-    pub creation_date: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub creation_date: ::chrono::DateTime<::chrono::Utc>,
     /// <p>If DEPRECATED, the date and time <a>DeprecateActivityType</a> was called.</p>
     #[serde(rename = "deprecationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deprecation_date: Option<f64>,
+    pub deprecation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The description of the activity type provided in <a>RegisterActivityType</a>.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     /// <p>The current status of the activity type.</p>
     #[serde(rename = "status")]
-    pub status: String,
+    pub status: RegistrationStatus,
 }
 
 /// <p>Contains a paginated list of activity type information structures.</p>
@@ -302,13 +415,106 @@ pub struct CancelTimerDecisionAttributes {
     pub timer_id: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CancelTimerFailedCause {
+    /// `TIMER_ID_UNKNOWN`
+    TimerIdUnknown,
+    /// `OPERATION_NOT_PERMITTED`
+    OperationNotPermitted,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CancelTimerFailedCause {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CancelTimerFailedCause::TimerIdUnknown => "TIMER_ID_UNKNOWN",
+            CancelTimerFailedCause::OperationNotPermitted => "OPERATION_NOT_PERMITTED",
+            CancelTimerFailedCause::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CancelTimerFailedCause {
+    fn default() -> CancelTimerFailedCause {
+        CancelTimerFailedCause::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CancelTimerFailedCause {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CancelTimerFailedCause {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CancelTimerFailedCause {
+    fn from(value: &'a str) -> CancelTimerFailedCause {
+        match value {
+            "TIMER_ID_UNKNOWN" => CancelTimerFailedCause::TimerIdUnknown,
+            "OPERATION_NOT_PERMITTED" => CancelTimerFailedCause::OperationNotPermitted,
+            _ => CancelTimerFailedCause::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CancelTimerFailedCause {
+    fn from(value: String) -> CancelTimerFailedCause {
+        match value.as_str() {
+            "TIMER_ID_UNKNOWN" => CancelTimerFailedCause::TimerIdUnknown,
+            "OPERATION_NOT_PERMITTED" => CancelTimerFailedCause::OperationNotPermitted,
+            _ => CancelTimerFailedCause::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CancelTimerFailedCause {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CancelTimerFailedCause, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CancelTimerFailedCause {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CancelTimerFailedCause {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CancelTimerFailedCause {
+    fn deserialize<D>(deserializer: D) -> Result<CancelTimerFailedCause, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Provides the details of the <code>CancelTimerFailed</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CancelTimerFailedEventAttributes {
     /// <p><p>The cause of the failure. This information is generated by the system and can be useful for diagnostic purposes.</p> <note> <p>If <code>cause</code> is set to <code>OPERATION<em>NOT</em>PERMITTED</code>, the decision failed because it lacked sufficient permissions. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p> </note></p>
     #[serde(rename = "cause")]
-    pub cause: String,
+    pub cause: CancelTimerFailedCause,
     /// <p>The ID of the <code>DecisionTaskCompleted</code> event corresponding to the decision task that resulted in the <code>CancelTimer</code> decision to cancel this timer. This information can be useful for diagnosing problems by tracing back the chain of events leading up to this event.</p>
     #[serde(rename = "decisionTaskCompletedEventId")]
     pub decision_task_completed_event_id: i64,
@@ -327,18 +533,209 @@ pub struct CancelWorkflowExecutionDecisionAttributes {
     pub details: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CancelWorkflowExecutionFailedCause {
+    /// `UNHANDLED_DECISION`
+    UnhandledDecision,
+    /// `OPERATION_NOT_PERMITTED`
+    OperationNotPermitted,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CancelWorkflowExecutionFailedCause {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CancelWorkflowExecutionFailedCause::UnhandledDecision => "UNHANDLED_DECISION",
+            CancelWorkflowExecutionFailedCause::OperationNotPermitted => "OPERATION_NOT_PERMITTED",
+            CancelWorkflowExecutionFailedCause::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CancelWorkflowExecutionFailedCause {
+    fn default() -> CancelWorkflowExecutionFailedCause {
+        CancelWorkflowExecutionFailedCause::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CancelWorkflowExecutionFailedCause {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CancelWorkflowExecutionFailedCause {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CancelWorkflowExecutionFailedCause {
+    fn from(value: &'a str) -> CancelWorkflowExecutionFailedCause {
+        match value {
+            "UNHANDLED_DECISION" => CancelWorkflowExecutionFailedCause::UnhandledDecision,
+            "OPERATION_NOT_PERMITTED" => CancelWorkflowExecutionFailedCause::OperationNotPermitted,
+            _ => CancelWorkflowExecutionFailedCause::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CancelWorkflowExecutionFailedCause {
+    fn from(value: String) -> CancelWorkflowExecutionFailedCause {
+        match value.as_str() {
+            "UNHANDLED_DECISION" => CancelWorkflowExecutionFailedCause::UnhandledDecision,
+            "OPERATION_NOT_PERMITTED" => CancelWorkflowExecutionFailedCause::OperationNotPermitted,
+            _ => CancelWorkflowExecutionFailedCause::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CancelWorkflowExecutionFailedCause {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CancelWorkflowExecutionFailedCause, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CancelWorkflowExecutionFailedCause {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CancelWorkflowExecutionFailedCause {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CancelWorkflowExecutionFailedCause {
+    fn deserialize<D>(deserializer: D) -> Result<CancelWorkflowExecutionFailedCause, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Provides the details of the <code>CancelWorkflowExecutionFailed</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CancelWorkflowExecutionFailedEventAttributes {
     /// <p><p>The cause of the failure. This information is generated by the system and can be useful for diagnostic purposes.</p> <note> <p>If <code>cause</code> is set to <code>OPERATION<em>NOT</em>PERMITTED</code>, the decision failed because it lacked sufficient permissions. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p> </note></p>
     #[serde(rename = "cause")]
-    pub cause: String,
+    pub cause: CancelWorkflowExecutionFailedCause,
     /// <p>The ID of the <code>DecisionTaskCompleted</code> event corresponding to the decision task that resulted in the <code>CancelWorkflowExecution</code> decision for this cancellation request. This information can be useful for diagnosing problems by tracing back the chain of events leading up to this event.</p>
     #[serde(rename = "decisionTaskCompletedEventId")]
     pub decision_task_completed_event_id: i64,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ChildPolicy {
+    /// `TERMINATE`
+    Terminate,
+    /// `REQUEST_CANCEL`
+    RequestCancel,
+    /// `ABANDON`
+    Abandon,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ChildPolicy {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ChildPolicy::Terminate => "TERMINATE",
+            ChildPolicy::RequestCancel => "REQUEST_CANCEL",
+            ChildPolicy::Abandon => "ABANDON",
+            ChildPolicy::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ChildPolicy {
+    fn default() -> ChildPolicy {
+        ChildPolicy::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ChildPolicy {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ChildPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ChildPolicy {
+    fn from(value: &'a str) -> ChildPolicy {
+        match value {
+            "TERMINATE" => ChildPolicy::Terminate,
+            "REQUEST_CANCEL" => ChildPolicy::RequestCancel,
+            "ABANDON" => ChildPolicy::Abandon,
+            _ => ChildPolicy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ChildPolicy {
+    fn from(value: String) -> ChildPolicy {
+        match value.as_str() {
+            "TERMINATE" => ChildPolicy::Terminate,
+            "REQUEST_CANCEL" => ChildPolicy::RequestCancel,
+            "ABANDON" => ChildPolicy::Abandon,
+            _ => ChildPolicy::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ChildPolicy {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ChildPolicy, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ChildPolicy {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ChildPolicy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ChildPolicy {
+    fn deserialize<D>(deserializer: D) -> Result<ChildPolicy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Provide details of the <code>ChildWorkflowExecutionCanceled</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -454,7 +851,7 @@ pub struct ChildWorkflowExecutionTimedOutEventAttributes {
     pub started_event_id: i64,
     /// <p>The type of the timeout that caused the child workflow execution to time out.</p>
     #[serde(rename = "timeoutType")]
-    pub timeout_type: String,
+    pub timeout_type: WorkflowExecutionTimeoutType,
     /// <p>The child workflow execution that timed out.</p>
     #[serde(rename = "workflowExecution")]
     pub workflow_execution: WorkflowExecution,
@@ -463,13 +860,126 @@ pub struct ChildWorkflowExecutionTimedOutEventAttributes {
     pub workflow_type: WorkflowType,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CloseStatus {
+    /// `COMPLETED`
+    Completed,
+    /// `FAILED`
+    Failed,
+    /// `CANCELED`
+    Canceled,
+    /// `TERMINATED`
+    Terminated,
+    /// `CONTINUED_AS_NEW`
+    ContinuedAsNew,
+    /// `TIMED_OUT`
+    TimedOut,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CloseStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CloseStatus::Completed => "COMPLETED",
+            CloseStatus::Failed => "FAILED",
+            CloseStatus::Canceled => "CANCELED",
+            CloseStatus::Terminated => "TERMINATED",
+            CloseStatus::ContinuedAsNew => "CONTINUED_AS_NEW",
+            CloseStatus::TimedOut => "TIMED_OUT",
+            CloseStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CloseStatus {
+    fn default() -> CloseStatus {
+        CloseStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CloseStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CloseStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CloseStatus {
+    fn from(value: &'a str) -> CloseStatus {
+        match value {
+            "COMPLETED" => CloseStatus::Completed,
+            "FAILED" => CloseStatus::Failed,
+            "CANCELED" => CloseStatus::Canceled,
+            "TERMINATED" => CloseStatus::Terminated,
+            "CONTINUED_AS_NEW" => CloseStatus::ContinuedAsNew,
+            "TIMED_OUT" => CloseStatus::TimedOut,
+            _ => CloseStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CloseStatus {
+    fn from(value: String) -> CloseStatus {
+        match value.as_str() {
+            "COMPLETED" => CloseStatus::Completed,
+            "FAILED" => CloseStatus::Failed,
+            "CANCELED" => CloseStatus::Canceled,
+            "TERMINATED" => CloseStatus::Terminated,
+            "CONTINUED_AS_NEW" => CloseStatus::ContinuedAsNew,
+            "TIMED_OUT" => CloseStatus::TimedOut,
+            _ => CloseStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CloseStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CloseStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CloseStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CloseStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CloseStatus {
+    fn deserialize<D>(deserializer: D) -> Result<CloseStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Used to filter the closed workflow executions in visibility APIs by their close status.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CloseStatusFilter {
     /// <p> The close status that must match the close status of an execution for it to meet the criteria of this filter.</p>
     #[serde(rename = "status")]
-    pub status: String,
+    pub status: CloseStatus,
 }
 
 /// <p>Provides the details of the <code>CompleteWorkflowExecution</code> decision.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this decision's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
@@ -482,13 +992,112 @@ pub struct CompleteWorkflowExecutionDecisionAttributes {
     pub result: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CompleteWorkflowExecutionFailedCause {
+    /// `UNHANDLED_DECISION`
+    UnhandledDecision,
+    /// `OPERATION_NOT_PERMITTED`
+    OperationNotPermitted,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CompleteWorkflowExecutionFailedCause {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CompleteWorkflowExecutionFailedCause::UnhandledDecision => "UNHANDLED_DECISION",
+            CompleteWorkflowExecutionFailedCause::OperationNotPermitted => {
+                "OPERATION_NOT_PERMITTED"
+            }
+            CompleteWorkflowExecutionFailedCause::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CompleteWorkflowExecutionFailedCause {
+    fn default() -> CompleteWorkflowExecutionFailedCause {
+        CompleteWorkflowExecutionFailedCause::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CompleteWorkflowExecutionFailedCause {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CompleteWorkflowExecutionFailedCause {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CompleteWorkflowExecutionFailedCause {
+    fn from(value: &'a str) -> CompleteWorkflowExecutionFailedCause {
+        match value {
+            "UNHANDLED_DECISION" => CompleteWorkflowExecutionFailedCause::UnhandledDecision,
+            "OPERATION_NOT_PERMITTED" => {
+                CompleteWorkflowExecutionFailedCause::OperationNotPermitted
+            }
+            _ => CompleteWorkflowExecutionFailedCause::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CompleteWorkflowExecutionFailedCause {
+    fn from(value: String) -> CompleteWorkflowExecutionFailedCause {
+        match value.as_str() {
+            "UNHANDLED_DECISION" => CompleteWorkflowExecutionFailedCause::UnhandledDecision,
+            "OPERATION_NOT_PERMITTED" => {
+                CompleteWorkflowExecutionFailedCause::OperationNotPermitted
+            }
+            _ => CompleteWorkflowExecutionFailedCause::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CompleteWorkflowExecutionFailedCause {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CompleteWorkflowExecutionFailedCause, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CompleteWorkflowExecutionFailedCause {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CompleteWorkflowExecutionFailedCause {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CompleteWorkflowExecutionFailedCause {
+    fn deserialize<D>(deserializer: D) -> Result<CompleteWorkflowExecutionFailedCause, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Provides the details of the <code>CompleteWorkflowExecutionFailed</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CompleteWorkflowExecutionFailedEventAttributes {
     /// <p><p>The cause of the failure. This information is generated by the system and can be useful for diagnostic purposes.</p> <note> <p>If <code>cause</code> is set to <code>OPERATION<em>NOT</em>PERMITTED</code>, the decision failed because it lacked sufficient permissions. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p> </note></p>
     #[serde(rename = "cause")]
-    pub cause: String,
+    pub cause: CompleteWorkflowExecutionFailedCause,
     /// <p>The ID of the <code>DecisionTaskCompleted</code> event corresponding to the decision task that resulted in the <code>CompleteWorkflowExecution</code> decision to complete this execution. This information can be useful for diagnosing problems by tracing back the chain of events leading up to this event.</p>
     #[serde(rename = "decisionTaskCompletedEventId")]
     pub decision_task_completed_event_id: i64,
@@ -501,7 +1110,7 @@ pub struct ContinueAsNewWorkflowExecutionDecisionAttributes {
     /// <p><p>If set, specifies the policy to use for the child workflow executions of the new execution if it is terminated by calling the <a>TerminateWorkflowExecution</a> action explicitly or due to an expired timeout. This policy overrides the default child policy specified when registering the workflow type using <a>RegisterWorkflowType</a>.</p> <p>The supported child policies are:</p> <ul> <li> <p> <code>TERMINATE</code> – The child executions are terminated.</p> </li> <li> <p> <code>REQUEST_CANCEL</code> – A request to cancel is attempted for each child execution by recording a <code>WorkflowExecutionCancelRequested</code> event in its history. It is up to the decider to take appropriate actions when it receives an execution history with this event.</p> </li> <li> <p> <code>ABANDON</code> – No action is taken. The child executions continue to run.</p> </li> </ul> <note> <p>A child policy for this workflow execution must be specified either as a default for the workflow type or through this parameter. If neither this parameter is set nor a default child policy was specified at registration time then a fault is returned.</p> </note></p>
     #[serde(rename = "childPolicy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub child_policy: Option<String>,
+    pub child_policy: Option<ChildPolicy>,
     /// <p><p>If set, specifies the total duration for this workflow execution. This overrides the <code>defaultExecutionStartToCloseTimeout</code> specified when registering the workflow type.</p> <p>The duration is specified in seconds, an integer greater than or equal to <code>0</code>. You can use <code>NONE</code> to specify unlimited duration.</p> <note> <p>An execution start-to-close timeout for this workflow execution must be specified either as a default for the workflow type or through this field. If neither this field is set nor a default execution start-to-close timeout was specified at registration time then a fault is returned.</p> </note></p>
     #[serde(rename = "executionStartToCloseTimeout")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -536,13 +1145,143 @@ pub struct ContinueAsNewWorkflowExecutionDecisionAttributes {
     pub workflow_type_version: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ContinueAsNewWorkflowExecutionFailedCause {
+    /// `UNHANDLED_DECISION`
+    UnhandledDecision,
+    /// `WORKFLOW_TYPE_DEPRECATED`
+    WorkflowTypeDeprecated,
+    /// `WORKFLOW_TYPE_DOES_NOT_EXIST`
+    WorkflowTypeDoesNotExist,
+    /// `DEFAULT_EXECUTION_START_TO_CLOSE_TIMEOUT_UNDEFINED`
+    DefaultExecutionStartToCloseTimeoutUndefined,
+    /// `DEFAULT_TASK_START_TO_CLOSE_TIMEOUT_UNDEFINED`
+    DefaultTaskStartToCloseTimeoutUndefined,
+    /// `DEFAULT_TASK_LIST_UNDEFINED`
+    DefaultTaskListUndefined,
+    /// `DEFAULT_CHILD_POLICY_UNDEFINED`
+    DefaultChildPolicyUndefined,
+    /// `CONTINUE_AS_NEW_WORKFLOW_EXECUTION_RATE_EXCEEDED`
+    ContinueAsNewWorkflowExecutionRateExceeded,
+    /// `OPERATION_NOT_PERMITTED`
+    OperationNotPermitted,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ContinueAsNewWorkflowExecutionFailedCause {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+                    ContinueAsNewWorkflowExecutionFailedCause::UnhandledDecision => "UNHANDLED_DECISION",
+ContinueAsNewWorkflowExecutionFailedCause::WorkflowTypeDeprecated => "WORKFLOW_TYPE_DEPRECATED",
+ContinueAsNewWorkflowExecutionFailedCause::WorkflowTypeDoesNotExist => "WORKFLOW_TYPE_DOES_NOT_EXIST",
+ContinueAsNewWorkflowExecutionFailedCause::DefaultExecutionStartToCloseTimeoutUndefined => "DEFAULT_EXECUTION_START_TO_CLOSE_TIMEOUT_UNDEFINED",
+ContinueAsNewWorkflowExecutionFailedCause::DefaultTaskStartToCloseTimeoutUndefined => "DEFAULT_TASK_START_TO_CLOSE_TIMEOUT_UNDEFINED",
+ContinueAsNewWorkflowExecutionFailedCause::DefaultTaskListUndefined => "DEFAULT_TASK_LIST_UNDEFINED",
+ContinueAsNewWorkflowExecutionFailedCause::DefaultChildPolicyUndefined => "DEFAULT_CHILD_POLICY_UNDEFINED",
+ContinueAsNewWorkflowExecutionFailedCause::ContinueAsNewWorkflowExecutionRateExceeded => "CONTINUE_AS_NEW_WORKFLOW_EXECUTION_RATE_EXCEEDED",
+ContinueAsNewWorkflowExecutionFailedCause::OperationNotPermitted => "OPERATION_NOT_PERMITTED",
+                    ContinueAsNewWorkflowExecutionFailedCause::Unknown(ref value) => value,
+                }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ContinueAsNewWorkflowExecutionFailedCause {
+    fn default() -> ContinueAsNewWorkflowExecutionFailedCause {
+        ContinueAsNewWorkflowExecutionFailedCause::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ContinueAsNewWorkflowExecutionFailedCause {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ContinueAsNewWorkflowExecutionFailedCause {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ContinueAsNewWorkflowExecutionFailedCause {
+    fn from(value: &'a str) -> ContinueAsNewWorkflowExecutionFailedCause {
+        match value {
+                    "UNHANDLED_DECISION" => ContinueAsNewWorkflowExecutionFailedCause::UnhandledDecision,
+"WORKFLOW_TYPE_DEPRECATED" => ContinueAsNewWorkflowExecutionFailedCause::WorkflowTypeDeprecated,
+"WORKFLOW_TYPE_DOES_NOT_EXIST" => ContinueAsNewWorkflowExecutionFailedCause::WorkflowTypeDoesNotExist,
+"DEFAULT_EXECUTION_START_TO_CLOSE_TIMEOUT_UNDEFINED" => ContinueAsNewWorkflowExecutionFailedCause::DefaultExecutionStartToCloseTimeoutUndefined,
+"DEFAULT_TASK_START_TO_CLOSE_TIMEOUT_UNDEFINED" => ContinueAsNewWorkflowExecutionFailedCause::DefaultTaskStartToCloseTimeoutUndefined,
+"DEFAULT_TASK_LIST_UNDEFINED" => ContinueAsNewWorkflowExecutionFailedCause::DefaultTaskListUndefined,
+"DEFAULT_CHILD_POLICY_UNDEFINED" => ContinueAsNewWorkflowExecutionFailedCause::DefaultChildPolicyUndefined,
+"CONTINUE_AS_NEW_WORKFLOW_EXECUTION_RATE_EXCEEDED" => ContinueAsNewWorkflowExecutionFailedCause::ContinueAsNewWorkflowExecutionRateExceeded,
+"OPERATION_NOT_PERMITTED" => ContinueAsNewWorkflowExecutionFailedCause::OperationNotPermitted,
+                    _ => ContinueAsNewWorkflowExecutionFailedCause::Unknown(value.to_owned()),
+                }
+    }
+}
+
+impl From<String> for ContinueAsNewWorkflowExecutionFailedCause {
+    fn from(value: String) -> ContinueAsNewWorkflowExecutionFailedCause {
+        match value.as_str() {
+                    "UNHANDLED_DECISION" => ContinueAsNewWorkflowExecutionFailedCause::UnhandledDecision,
+"WORKFLOW_TYPE_DEPRECATED" => ContinueAsNewWorkflowExecutionFailedCause::WorkflowTypeDeprecated,
+"WORKFLOW_TYPE_DOES_NOT_EXIST" => ContinueAsNewWorkflowExecutionFailedCause::WorkflowTypeDoesNotExist,
+"DEFAULT_EXECUTION_START_TO_CLOSE_TIMEOUT_UNDEFINED" => ContinueAsNewWorkflowExecutionFailedCause::DefaultExecutionStartToCloseTimeoutUndefined,
+"DEFAULT_TASK_START_TO_CLOSE_TIMEOUT_UNDEFINED" => ContinueAsNewWorkflowExecutionFailedCause::DefaultTaskStartToCloseTimeoutUndefined,
+"DEFAULT_TASK_LIST_UNDEFINED" => ContinueAsNewWorkflowExecutionFailedCause::DefaultTaskListUndefined,
+"DEFAULT_CHILD_POLICY_UNDEFINED" => ContinueAsNewWorkflowExecutionFailedCause::DefaultChildPolicyUndefined,
+"CONTINUE_AS_NEW_WORKFLOW_EXECUTION_RATE_EXCEEDED" => ContinueAsNewWorkflowExecutionFailedCause::ContinueAsNewWorkflowExecutionRateExceeded,
+"OPERATION_NOT_PERMITTED" => ContinueAsNewWorkflowExecutionFailedCause::OperationNotPermitted,
+                    _ => ContinueAsNewWorkflowExecutionFailedCause::Unknown(value),
+                }
+    }
+}
+
+impl ::std::str::FromStr for ContinueAsNewWorkflowExecutionFailedCause {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ContinueAsNewWorkflowExecutionFailedCause, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ContinueAsNewWorkflowExecutionFailedCause {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ContinueAsNewWorkflowExecutionFailedCause {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ContinueAsNewWorkflowExecutionFailedCause {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> Result<ContinueAsNewWorkflowExecutionFailedCause, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Provides the details of the <code>ContinueAsNewWorkflowExecutionFailed</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ContinueAsNewWorkflowExecutionFailedEventAttributes {
     /// <p><p>The cause of the failure. This information is generated by the system and can be useful for diagnostic purposes.</p> <note> <p>If <code>cause</code> is set to <code>OPERATION<em>NOT</em>PERMITTED</code>, the decision failed because it lacked sufficient permissions. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p> </note></p>
     #[serde(rename = "cause")]
-    pub cause: String,
+    pub cause: ContinueAsNewWorkflowExecutionFailedCause,
     /// <p>The ID of the <code>DecisionTaskCompleted</code> event corresponding to the decision task that resulted in the <code>ContinueAsNewWorkflowExecution</code> decision that started this execution. This information can be useful for diagnosing problems by tracing back the chain of events leading up to this event.</p>
     #[serde(rename = "decisionTaskCompletedEventId")]
     pub decision_task_completed_event_id: i64,
@@ -650,7 +1389,7 @@ pub struct Decision {
         Option<ContinueAsNewWorkflowExecutionDecisionAttributes>,
     /// <p>Specifies the type of the decision.</p>
     #[serde(rename = "decisionType")]
-    pub decision_type: String,
+    pub decision_type: DecisionType,
     /// <p>Provides the details of the <code>FailWorkflowExecution</code> decision. It isn't set for other decision types.</p>
     #[serde(rename = "failWorkflowExecutionDecisionAttributes")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -782,9 +1521,256 @@ pub struct DecisionTaskTimedOutEventAttributes {
     pub started_event_id: i64,
     /// <p>The type of timeout that expired before the decision task could be completed.</p>
     #[serde(rename = "timeoutType")]
-    pub timeout_type: String,
+    pub timeout_type: DecisionTaskTimeoutType,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DecisionTaskTimeoutType {
+    /// `START_TO_CLOSE`
+    StartToClose,
+    /// `SCHEDULE_TO_START`
+    ScheduleToStart,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DecisionTaskTimeoutType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DecisionTaskTimeoutType::StartToClose => "START_TO_CLOSE",
+            DecisionTaskTimeoutType::ScheduleToStart => "SCHEDULE_TO_START",
+            DecisionTaskTimeoutType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DecisionTaskTimeoutType {
+    fn default() -> DecisionTaskTimeoutType {
+        DecisionTaskTimeoutType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DecisionTaskTimeoutType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DecisionTaskTimeoutType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DecisionTaskTimeoutType {
+    fn from(value: &'a str) -> DecisionTaskTimeoutType {
+        match value {
+            "START_TO_CLOSE" => DecisionTaskTimeoutType::StartToClose,
+            "SCHEDULE_TO_START" => DecisionTaskTimeoutType::ScheduleToStart,
+            _ => DecisionTaskTimeoutType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DecisionTaskTimeoutType {
+    fn from(value: String) -> DecisionTaskTimeoutType {
+        match value.as_str() {
+            "START_TO_CLOSE" => DecisionTaskTimeoutType::StartToClose,
+            "SCHEDULE_TO_START" => DecisionTaskTimeoutType::ScheduleToStart,
+            _ => DecisionTaskTimeoutType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DecisionTaskTimeoutType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DecisionTaskTimeoutType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DecisionTaskTimeoutType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DecisionTaskTimeoutType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DecisionTaskTimeoutType {
+    fn deserialize<D>(deserializer: D) -> Result<DecisionTaskTimeoutType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DecisionType {
+    /// `ScheduleActivityTask`
+    ScheduleActivityTask,
+    /// `RequestCancelActivityTask`
+    RequestCancelActivityTask,
+    /// `CompleteWorkflowExecution`
+    CompleteWorkflowExecution,
+    /// `FailWorkflowExecution`
+    FailWorkflowExecution,
+    /// `CancelWorkflowExecution`
+    CancelWorkflowExecution,
+    /// `ContinueAsNewWorkflowExecution`
+    ContinueAsNewWorkflowExecution,
+    /// `RecordMarker`
+    RecordMarker,
+    /// `StartTimer`
+    StartTimer,
+    /// `CancelTimer`
+    CancelTimer,
+    /// `SignalExternalWorkflowExecution`
+    SignalExternalWorkflowExecution,
+    /// `RequestCancelExternalWorkflowExecution`
+    RequestCancelExternalWorkflowExecution,
+    /// `StartChildWorkflowExecution`
+    StartChildWorkflowExecution,
+    /// `ScheduleLambdaFunction`
+    ScheduleLambdaFunction,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DecisionType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DecisionType::ScheduleActivityTask => "ScheduleActivityTask",
+            DecisionType::RequestCancelActivityTask => "RequestCancelActivityTask",
+            DecisionType::CompleteWorkflowExecution => "CompleteWorkflowExecution",
+            DecisionType::FailWorkflowExecution => "FailWorkflowExecution",
+            DecisionType::CancelWorkflowExecution => "CancelWorkflowExecution",
+            DecisionType::ContinueAsNewWorkflowExecution => "ContinueAsNewWorkflowExecution",
+            DecisionType::RecordMarker => "RecordMarker",
+            DecisionType::StartTimer => "StartTimer",
+            DecisionType::CancelTimer => "CancelTimer",
+            DecisionType::SignalExternalWorkflowExecution => "SignalExternalWorkflowExecution",
+            DecisionType::RequestCancelExternalWorkflowExecution => {
+                "RequestCancelExternalWorkflowExecution"
+            }
+            DecisionType::StartChildWorkflowExecution => "StartChildWorkflowExecution",
+            DecisionType::ScheduleLambdaFunction => "ScheduleLambdaFunction",
+            DecisionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DecisionType {
+    fn default() -> DecisionType {
+        DecisionType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DecisionType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DecisionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DecisionType {
+    fn from(value: &'a str) -> DecisionType {
+        match value {
+            "ScheduleActivityTask" => DecisionType::ScheduleActivityTask,
+            "RequestCancelActivityTask" => DecisionType::RequestCancelActivityTask,
+            "CompleteWorkflowExecution" => DecisionType::CompleteWorkflowExecution,
+            "FailWorkflowExecution" => DecisionType::FailWorkflowExecution,
+            "CancelWorkflowExecution" => DecisionType::CancelWorkflowExecution,
+            "ContinueAsNewWorkflowExecution" => DecisionType::ContinueAsNewWorkflowExecution,
+            "RecordMarker" => DecisionType::RecordMarker,
+            "StartTimer" => DecisionType::StartTimer,
+            "CancelTimer" => DecisionType::CancelTimer,
+            "SignalExternalWorkflowExecution" => DecisionType::SignalExternalWorkflowExecution,
+            "RequestCancelExternalWorkflowExecution" => {
+                DecisionType::RequestCancelExternalWorkflowExecution
+            }
+            "StartChildWorkflowExecution" => DecisionType::StartChildWorkflowExecution,
+            "ScheduleLambdaFunction" => DecisionType::ScheduleLambdaFunction,
+            _ => DecisionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DecisionType {
+    fn from(value: String) -> DecisionType {
+        match value.as_str() {
+            "ScheduleActivityTask" => DecisionType::ScheduleActivityTask,
+            "RequestCancelActivityTask" => DecisionType::RequestCancelActivityTask,
+            "CompleteWorkflowExecution" => DecisionType::CompleteWorkflowExecution,
+            "FailWorkflowExecution" => DecisionType::FailWorkflowExecution,
+            "CancelWorkflowExecution" => DecisionType::CancelWorkflowExecution,
+            "ContinueAsNewWorkflowExecution" => DecisionType::ContinueAsNewWorkflowExecution,
+            "RecordMarker" => DecisionType::RecordMarker,
+            "StartTimer" => DecisionType::StartTimer,
+            "CancelTimer" => DecisionType::CancelTimer,
+            "SignalExternalWorkflowExecution" => DecisionType::SignalExternalWorkflowExecution,
+            "RequestCancelExternalWorkflowExecution" => {
+                DecisionType::RequestCancelExternalWorkflowExecution
+            }
+            "StartChildWorkflowExecution" => DecisionType::StartChildWorkflowExecution,
+            "ScheduleLambdaFunction" => DecisionType::ScheduleLambdaFunction,
+            _ => DecisionType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DecisionType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DecisionType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DecisionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DecisionType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for DecisionType {
+    fn deserialize<D>(deserializer: D) -> Result<DecisionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct DeprecateActivityTypeInput {
@@ -894,7 +1880,7 @@ pub struct DomainInfo {
     pub name: String,
     /// <p><p>The status of the domain:</p> <ul> <li> <p> <code>REGISTERED</code> – The domain is properly registered and available. You can use this domain for registering types and creating new workflow executions. </p> </li> <li> <p> <code>DEPRECATED</code> – The domain was deprecated using <a>DeprecateDomain</a>, but is still in use. You should not create new workflow executions in this domain. </p> </li> </ul></p>
     #[serde(rename = "status")]
-    pub status: String,
+    pub status: RegistrationStatus,
 }
 
 /// <p>Contains a paginated collection of DomainInfo structures.</p>
@@ -910,17 +1896,515 @@ pub struct DomainInfos {
     pub next_page_token: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EventType {
+    /// `WorkflowExecutionStarted`
+    WorkflowExecutionStarted,
+    /// `WorkflowExecutionCancelRequested`
+    WorkflowExecutionCancelRequested,
+    /// `WorkflowExecutionCompleted`
+    WorkflowExecutionCompleted,
+    /// `CompleteWorkflowExecutionFailed`
+    CompleteWorkflowExecutionFailed,
+    /// `WorkflowExecutionFailed`
+    WorkflowExecutionFailed,
+    /// `FailWorkflowExecutionFailed`
+    FailWorkflowExecutionFailed,
+    /// `WorkflowExecutionTimedOut`
+    WorkflowExecutionTimedOut,
+    /// `WorkflowExecutionCanceled`
+    WorkflowExecutionCanceled,
+    /// `CancelWorkflowExecutionFailed`
+    CancelWorkflowExecutionFailed,
+    /// `WorkflowExecutionContinuedAsNew`
+    WorkflowExecutionContinuedAsNew,
+    /// `ContinueAsNewWorkflowExecutionFailed`
+    ContinueAsNewWorkflowExecutionFailed,
+    /// `WorkflowExecutionTerminated`
+    WorkflowExecutionTerminated,
+    /// `DecisionTaskScheduled`
+    DecisionTaskScheduled,
+    /// `DecisionTaskStarted`
+    DecisionTaskStarted,
+    /// `DecisionTaskCompleted`
+    DecisionTaskCompleted,
+    /// `DecisionTaskTimedOut`
+    DecisionTaskTimedOut,
+    /// `ActivityTaskScheduled`
+    ActivityTaskScheduled,
+    /// `ScheduleActivityTaskFailed`
+    ScheduleActivityTaskFailed,
+    /// `ActivityTaskStarted`
+    ActivityTaskStarted,
+    /// `ActivityTaskCompleted`
+    ActivityTaskCompleted,
+    /// `ActivityTaskFailed`
+    ActivityTaskFailed,
+    /// `ActivityTaskTimedOut`
+    ActivityTaskTimedOut,
+    /// `ActivityTaskCanceled`
+    ActivityTaskCanceled,
+    /// `ActivityTaskCancelRequested`
+    ActivityTaskCancelRequested,
+    /// `RequestCancelActivityTaskFailed`
+    RequestCancelActivityTaskFailed,
+    /// `WorkflowExecutionSignaled`
+    WorkflowExecutionSignaled,
+    /// `MarkerRecorded`
+    MarkerRecorded,
+    /// `RecordMarkerFailed`
+    RecordMarkerFailed,
+    /// `TimerStarted`
+    TimerStarted,
+    /// `StartTimerFailed`
+    StartTimerFailed,
+    /// `TimerFired`
+    TimerFired,
+    /// `TimerCanceled`
+    TimerCanceled,
+    /// `CancelTimerFailed`
+    CancelTimerFailed,
+    /// `StartChildWorkflowExecutionInitiated`
+    StartChildWorkflowExecutionInitiated,
+    /// `StartChildWorkflowExecutionFailed`
+    StartChildWorkflowExecutionFailed,
+    /// `ChildWorkflowExecutionStarted`
+    ChildWorkflowExecutionStarted,
+    /// `ChildWorkflowExecutionCompleted`
+    ChildWorkflowExecutionCompleted,
+    /// `ChildWorkflowExecutionFailed`
+    ChildWorkflowExecutionFailed,
+    /// `ChildWorkflowExecutionTimedOut`
+    ChildWorkflowExecutionTimedOut,
+    /// `ChildWorkflowExecutionCanceled`
+    ChildWorkflowExecutionCanceled,
+    /// `ChildWorkflowExecutionTerminated`
+    ChildWorkflowExecutionTerminated,
+    /// `SignalExternalWorkflowExecutionInitiated`
+    SignalExternalWorkflowExecutionInitiated,
+    /// `SignalExternalWorkflowExecutionFailed`
+    SignalExternalWorkflowExecutionFailed,
+    /// `ExternalWorkflowExecutionSignaled`
+    ExternalWorkflowExecutionSignaled,
+    /// `RequestCancelExternalWorkflowExecutionInitiated`
+    RequestCancelExternalWorkflowExecutionInitiated,
+    /// `RequestCancelExternalWorkflowExecutionFailed`
+    RequestCancelExternalWorkflowExecutionFailed,
+    /// `ExternalWorkflowExecutionCancelRequested`
+    ExternalWorkflowExecutionCancelRequested,
+    /// `LambdaFunctionScheduled`
+    LambdaFunctionScheduled,
+    /// `LambdaFunctionStarted`
+    LambdaFunctionStarted,
+    /// `LambdaFunctionCompleted`
+    LambdaFunctionCompleted,
+    /// `LambdaFunctionFailed`
+    LambdaFunctionFailed,
+    /// `LambdaFunctionTimedOut`
+    LambdaFunctionTimedOut,
+    /// `ScheduleLambdaFunctionFailed`
+    ScheduleLambdaFunctionFailed,
+    /// `StartLambdaFunctionFailed`
+    StartLambdaFunctionFailed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EventType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EventType::WorkflowExecutionStarted => "WorkflowExecutionStarted",
+            EventType::WorkflowExecutionCancelRequested => "WorkflowExecutionCancelRequested",
+            EventType::WorkflowExecutionCompleted => "WorkflowExecutionCompleted",
+            EventType::CompleteWorkflowExecutionFailed => "CompleteWorkflowExecutionFailed",
+            EventType::WorkflowExecutionFailed => "WorkflowExecutionFailed",
+            EventType::FailWorkflowExecutionFailed => "FailWorkflowExecutionFailed",
+            EventType::WorkflowExecutionTimedOut => "WorkflowExecutionTimedOut",
+            EventType::WorkflowExecutionCanceled => "WorkflowExecutionCanceled",
+            EventType::CancelWorkflowExecutionFailed => "CancelWorkflowExecutionFailed",
+            EventType::WorkflowExecutionContinuedAsNew => "WorkflowExecutionContinuedAsNew",
+            EventType::ContinueAsNewWorkflowExecutionFailed => {
+                "ContinueAsNewWorkflowExecutionFailed"
+            }
+            EventType::WorkflowExecutionTerminated => "WorkflowExecutionTerminated",
+            EventType::DecisionTaskScheduled => "DecisionTaskScheduled",
+            EventType::DecisionTaskStarted => "DecisionTaskStarted",
+            EventType::DecisionTaskCompleted => "DecisionTaskCompleted",
+            EventType::DecisionTaskTimedOut => "DecisionTaskTimedOut",
+            EventType::ActivityTaskScheduled => "ActivityTaskScheduled",
+            EventType::ScheduleActivityTaskFailed => "ScheduleActivityTaskFailed",
+            EventType::ActivityTaskStarted => "ActivityTaskStarted",
+            EventType::ActivityTaskCompleted => "ActivityTaskCompleted",
+            EventType::ActivityTaskFailed => "ActivityTaskFailed",
+            EventType::ActivityTaskTimedOut => "ActivityTaskTimedOut",
+            EventType::ActivityTaskCanceled => "ActivityTaskCanceled",
+            EventType::ActivityTaskCancelRequested => "ActivityTaskCancelRequested",
+            EventType::RequestCancelActivityTaskFailed => "RequestCancelActivityTaskFailed",
+            EventType::WorkflowExecutionSignaled => "WorkflowExecutionSignaled",
+            EventType::MarkerRecorded => "MarkerRecorded",
+            EventType::RecordMarkerFailed => "RecordMarkerFailed",
+            EventType::TimerStarted => "TimerStarted",
+            EventType::StartTimerFailed => "StartTimerFailed",
+            EventType::TimerFired => "TimerFired",
+            EventType::TimerCanceled => "TimerCanceled",
+            EventType::CancelTimerFailed => "CancelTimerFailed",
+            EventType::StartChildWorkflowExecutionInitiated => {
+                "StartChildWorkflowExecutionInitiated"
+            }
+            EventType::StartChildWorkflowExecutionFailed => "StartChildWorkflowExecutionFailed",
+            EventType::ChildWorkflowExecutionStarted => "ChildWorkflowExecutionStarted",
+            EventType::ChildWorkflowExecutionCompleted => "ChildWorkflowExecutionCompleted",
+            EventType::ChildWorkflowExecutionFailed => "ChildWorkflowExecutionFailed",
+            EventType::ChildWorkflowExecutionTimedOut => "ChildWorkflowExecutionTimedOut",
+            EventType::ChildWorkflowExecutionCanceled => "ChildWorkflowExecutionCanceled",
+            EventType::ChildWorkflowExecutionTerminated => "ChildWorkflowExecutionTerminated",
+            EventType::SignalExternalWorkflowExecutionInitiated => {
+                "SignalExternalWorkflowExecutionInitiated"
+            }
+            EventType::SignalExternalWorkflowExecutionFailed => {
+                "SignalExternalWorkflowExecutionFailed"
+            }
+            EventType::ExternalWorkflowExecutionSignaled => "ExternalWorkflowExecutionSignaled",
+            EventType::RequestCancelExternalWorkflowExecutionInitiated => {
+                "RequestCancelExternalWorkflowExecutionInitiated"
+            }
+            EventType::RequestCancelExternalWorkflowExecutionFailed => {
+                "RequestCancelExternalWorkflowExecutionFailed"
+            }
+            EventType::ExternalWorkflowExecutionCancelRequested => {
+                "ExternalWorkflowExecutionCancelRequested"
+            }
+            EventType::LambdaFunctionScheduled => "LambdaFunctionScheduled",
+            EventType::LambdaFunctionStarted => "LambdaFunctionStarted",
+            EventType::LambdaFunctionCompleted => "LambdaFunctionCompleted",
+            EventType::LambdaFunctionFailed => "LambdaFunctionFailed",
+            EventType::LambdaFunctionTimedOut => "LambdaFunctionTimedOut",
+            EventType::ScheduleLambdaFunctionFailed => "ScheduleLambdaFunctionFailed",
+            EventType::StartLambdaFunctionFailed => "StartLambdaFunctionFailed",
+            EventType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EventType {
+    fn default() -> EventType {
+        EventType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EventType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EventType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EventType {
+    fn from(value: &'a str) -> EventType {
+        match value {
+            "WorkflowExecutionStarted" => EventType::WorkflowExecutionStarted,
+            "WorkflowExecutionCancelRequested" => EventType::WorkflowExecutionCancelRequested,
+            "WorkflowExecutionCompleted" => EventType::WorkflowExecutionCompleted,
+            "CompleteWorkflowExecutionFailed" => EventType::CompleteWorkflowExecutionFailed,
+            "WorkflowExecutionFailed" => EventType::WorkflowExecutionFailed,
+            "FailWorkflowExecutionFailed" => EventType::FailWorkflowExecutionFailed,
+            "WorkflowExecutionTimedOut" => EventType::WorkflowExecutionTimedOut,
+            "WorkflowExecutionCanceled" => EventType::WorkflowExecutionCanceled,
+            "CancelWorkflowExecutionFailed" => EventType::CancelWorkflowExecutionFailed,
+            "WorkflowExecutionContinuedAsNew" => EventType::WorkflowExecutionContinuedAsNew,
+            "ContinueAsNewWorkflowExecutionFailed" => {
+                EventType::ContinueAsNewWorkflowExecutionFailed
+            }
+            "WorkflowExecutionTerminated" => EventType::WorkflowExecutionTerminated,
+            "DecisionTaskScheduled" => EventType::DecisionTaskScheduled,
+            "DecisionTaskStarted" => EventType::DecisionTaskStarted,
+            "DecisionTaskCompleted" => EventType::DecisionTaskCompleted,
+            "DecisionTaskTimedOut" => EventType::DecisionTaskTimedOut,
+            "ActivityTaskScheduled" => EventType::ActivityTaskScheduled,
+            "ScheduleActivityTaskFailed" => EventType::ScheduleActivityTaskFailed,
+            "ActivityTaskStarted" => EventType::ActivityTaskStarted,
+            "ActivityTaskCompleted" => EventType::ActivityTaskCompleted,
+            "ActivityTaskFailed" => EventType::ActivityTaskFailed,
+            "ActivityTaskTimedOut" => EventType::ActivityTaskTimedOut,
+            "ActivityTaskCanceled" => EventType::ActivityTaskCanceled,
+            "ActivityTaskCancelRequested" => EventType::ActivityTaskCancelRequested,
+            "RequestCancelActivityTaskFailed" => EventType::RequestCancelActivityTaskFailed,
+            "WorkflowExecutionSignaled" => EventType::WorkflowExecutionSignaled,
+            "MarkerRecorded" => EventType::MarkerRecorded,
+            "RecordMarkerFailed" => EventType::RecordMarkerFailed,
+            "TimerStarted" => EventType::TimerStarted,
+            "StartTimerFailed" => EventType::StartTimerFailed,
+            "TimerFired" => EventType::TimerFired,
+            "TimerCanceled" => EventType::TimerCanceled,
+            "CancelTimerFailed" => EventType::CancelTimerFailed,
+            "StartChildWorkflowExecutionInitiated" => {
+                EventType::StartChildWorkflowExecutionInitiated
+            }
+            "StartChildWorkflowExecutionFailed" => EventType::StartChildWorkflowExecutionFailed,
+            "ChildWorkflowExecutionStarted" => EventType::ChildWorkflowExecutionStarted,
+            "ChildWorkflowExecutionCompleted" => EventType::ChildWorkflowExecutionCompleted,
+            "ChildWorkflowExecutionFailed" => EventType::ChildWorkflowExecutionFailed,
+            "ChildWorkflowExecutionTimedOut" => EventType::ChildWorkflowExecutionTimedOut,
+            "ChildWorkflowExecutionCanceled" => EventType::ChildWorkflowExecutionCanceled,
+            "ChildWorkflowExecutionTerminated" => EventType::ChildWorkflowExecutionTerminated,
+            "SignalExternalWorkflowExecutionInitiated" => {
+                EventType::SignalExternalWorkflowExecutionInitiated
+            }
+            "SignalExternalWorkflowExecutionFailed" => {
+                EventType::SignalExternalWorkflowExecutionFailed
+            }
+            "ExternalWorkflowExecutionSignaled" => EventType::ExternalWorkflowExecutionSignaled,
+            "RequestCancelExternalWorkflowExecutionInitiated" => {
+                EventType::RequestCancelExternalWorkflowExecutionInitiated
+            }
+            "RequestCancelExternalWorkflowExecutionFailed" => {
+                EventType::RequestCancelExternalWorkflowExecutionFailed
+            }
+            "ExternalWorkflowExecutionCancelRequested" => {
+                EventType::ExternalWorkflowExecutionCancelRequested
+            }
+            "LambdaFunctionScheduled" => EventType::LambdaFunctionScheduled,
+            "LambdaFunctionStarted" => EventType::LambdaFunctionStarted,
+            "LambdaFunctionCompleted" => EventType::LambdaFunctionCompleted,
+            "LambdaFunctionFailed" => EventType::LambdaFunctionFailed,
+            "LambdaFunctionTimedOut" => EventType::LambdaFunctionTimedOut,
+            "ScheduleLambdaFunctionFailed" => EventType::ScheduleLambdaFunctionFailed,
+            "StartLambdaFunctionFailed" => EventType::StartLambdaFunctionFailed,
+            _ => EventType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EventType {
+    fn from(value: String) -> EventType {
+        match value.as_str() {
+            "WorkflowExecutionStarted" => EventType::WorkflowExecutionStarted,
+            "WorkflowExecutionCancelRequested" => EventType::WorkflowExecutionCancelRequested,
+            "WorkflowExecutionCompleted" => EventType::WorkflowExecutionCompleted,
+            "CompleteWorkflowExecutionFailed" => EventType::CompleteWorkflowExecutionFailed,
+            "WorkflowExecutionFailed" => EventType::WorkflowExecutionFailed,
+            "FailWorkflowExecutionFailed" => EventType::FailWorkflowExecutionFailed,
+            "WorkflowExecutionTimedOut" => EventType::WorkflowExecutionTimedOut,
+            "WorkflowExecutionCanceled" => EventType::WorkflowExecutionCanceled,
+            "CancelWorkflowExecutionFailed" => EventType::CancelWorkflowExecutionFailed,
+            "WorkflowExecutionContinuedAsNew" => EventType::WorkflowExecutionContinuedAsNew,
+            "ContinueAsNewWorkflowExecutionFailed" => {
+                EventType::ContinueAsNewWorkflowExecutionFailed
+            }
+            "WorkflowExecutionTerminated" => EventType::WorkflowExecutionTerminated,
+            "DecisionTaskScheduled" => EventType::DecisionTaskScheduled,
+            "DecisionTaskStarted" => EventType::DecisionTaskStarted,
+            "DecisionTaskCompleted" => EventType::DecisionTaskCompleted,
+            "DecisionTaskTimedOut" => EventType::DecisionTaskTimedOut,
+            "ActivityTaskScheduled" => EventType::ActivityTaskScheduled,
+            "ScheduleActivityTaskFailed" => EventType::ScheduleActivityTaskFailed,
+            "ActivityTaskStarted" => EventType::ActivityTaskStarted,
+            "ActivityTaskCompleted" => EventType::ActivityTaskCompleted,
+            "ActivityTaskFailed" => EventType::ActivityTaskFailed,
+            "ActivityTaskTimedOut" => EventType::ActivityTaskTimedOut,
+            "ActivityTaskCanceled" => EventType::ActivityTaskCanceled,
+            "ActivityTaskCancelRequested" => EventType::ActivityTaskCancelRequested,
+            "RequestCancelActivityTaskFailed" => EventType::RequestCancelActivityTaskFailed,
+            "WorkflowExecutionSignaled" => EventType::WorkflowExecutionSignaled,
+            "MarkerRecorded" => EventType::MarkerRecorded,
+            "RecordMarkerFailed" => EventType::RecordMarkerFailed,
+            "TimerStarted" => EventType::TimerStarted,
+            "StartTimerFailed" => EventType::StartTimerFailed,
+            "TimerFired" => EventType::TimerFired,
+            "TimerCanceled" => EventType::TimerCanceled,
+            "CancelTimerFailed" => EventType::CancelTimerFailed,
+            "StartChildWorkflowExecutionInitiated" => {
+                EventType::StartChildWorkflowExecutionInitiated
+            }
+            "StartChildWorkflowExecutionFailed" => EventType::StartChildWorkflowExecutionFailed,
+            "ChildWorkflowExecutionStarted" => EventType::ChildWorkflowExecutionStarted,
+            "ChildWorkflowExecutionCompleted" => EventType::ChildWorkflowExecutionCompleted,
+            "ChildWorkflowExecutionFailed" => EventType::ChildWorkflowExecutionFailed,
+            "ChildWorkflowExecutionTimedOut" => EventType::ChildWorkflowExecutionTimedOut,
+            "ChildWorkflowExecutionCanceled" => EventType::ChildWorkflowExecutionCanceled,
+            "ChildWorkflowExecutionTerminated" => EventType::ChildWorkflowExecutionTerminated,
+            "SignalExternalWorkflowExecutionInitiated" => {
+                EventType::SignalExternalWorkflowExecutionInitiated
+            }
+            "SignalExternalWorkflowExecutionFailed" => {
+                EventType::SignalExternalWorkflowExecutionFailed
+            }
+            "ExternalWorkflowExecutionSignaled" => EventType::ExternalWorkflowExecutionSignaled,
+            "RequestCancelExternalWorkflowExecutionInitiated" => {
+                EventType::RequestCancelExternalWorkflowExecutionInitiated
+            }
+            "RequestCancelExternalWorkflowExecutionFailed" => {
+                EventType::RequestCancelExternalWorkflowExecutionFailed
+            }
+            "ExternalWorkflowExecutionCancelRequested" => {
+                EventType::ExternalWorkflowExecutionCancelRequested
+            }
+            "LambdaFunctionScheduled" => EventType::LambdaFunctionScheduled,
+            "LambdaFunctionStarted" => EventType::LambdaFunctionStarted,
+            "LambdaFunctionCompleted" => EventType::LambdaFunctionCompleted,
+            "LambdaFunctionFailed" => EventType::LambdaFunctionFailed,
+            "LambdaFunctionTimedOut" => EventType::LambdaFunctionTimedOut,
+            "ScheduleLambdaFunctionFailed" => EventType::ScheduleLambdaFunctionFailed,
+            "StartLambdaFunctionFailed" => EventType::StartLambdaFunctionFailed,
+            _ => EventType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EventType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EventType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EventType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EventType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EventType {
+    fn deserialize<D>(deserializer: D) -> Result<EventType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ExecutionStatus {
+    /// `OPEN`
+    Open,
+    /// `CLOSED`
+    Closed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ExecutionStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ExecutionStatus::Open => "OPEN",
+            ExecutionStatus::Closed => "CLOSED",
+            ExecutionStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ExecutionStatus {
+    fn default() -> ExecutionStatus {
+        ExecutionStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ExecutionStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ExecutionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ExecutionStatus {
+    fn from(value: &'a str) -> ExecutionStatus {
+        match value {
+            "OPEN" => ExecutionStatus::Open,
+            "CLOSED" => ExecutionStatus::Closed,
+            _ => ExecutionStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ExecutionStatus {
+    fn from(value: String) -> ExecutionStatus {
+        match value.as_str() {
+            "OPEN" => ExecutionStatus::Open,
+            "CLOSED" => ExecutionStatus::Closed,
+            _ => ExecutionStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ExecutionStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ExecutionStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ExecutionStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ExecutionStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ExecutionStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ExecutionStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Used to filter the workflow executions in visibility APIs by various time-based rules. Each parameter, if specified, defines a rule that must be satisfied by each returned query result. The parameter values are in the <a href="https://en.wikipedia.org/wiki/Unix_time">Unix Time format</a>. For example: <code>"oldestDate": 1325376070.</code> </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ExecutionTimeFilter {
     /// <p>Specifies the latest start or close date and time to return.</p>
     #[serde(rename = "latestDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub latest_date: Option<f64>,
+    pub latest_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Specifies the oldest start or close date and time to return.</p>
     #[serde(rename = "oldestDate")]
-    pub oldest_date: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub oldest_date: ::chrono::DateTime<::chrono::Utc>,
 }
 
 /// <p>Provides the details of the <code>ExternalWorkflowExecutionCancelRequested</code> event.</p>
@@ -935,39 +2419,132 @@ pub struct ExternalWorkflowExecutionCancelRequestedEventAttributes {
     pub workflow_execution: WorkflowExecution,
 }
 
-/// <p>Provides the details of the <code>ExternalWorkflowExecutionSignaled</code> event.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct ExternalWorkflowExecutionSignaledEventAttributes {
-    /// <p>The ID of the <code>SignalExternalWorkflowExecutionInitiated</code> event corresponding to the <code>SignalExternalWorkflowExecution</code> decision to request this signal. This information can be useful for diagnosing problems by tracing back the chain of events leading up to this event.</p>
-    #[serde(rename = "initiatedEventId")]
-    pub initiated_event_id: i64,
-    /// <p>The external workflow execution that the signal was delivered to.</p>
-    #[serde(rename = "workflowExecution")]
-    pub workflow_execution: WorkflowExecution,
+/// <p>Provides the details of the <code>ExternalWorkflowExecutionSignaled</code> event.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct ExternalWorkflowExecutionSignaledEventAttributes {
+    /// <p>The ID of the <code>SignalExternalWorkflowExecutionInitiated</code> event corresponding to the <code>SignalExternalWorkflowExecution</code> decision to request this signal. This information can be useful for diagnosing problems by tracing back the chain of events leading up to this event.</p>
+    #[serde(rename = "initiatedEventId")]
+    pub initiated_event_id: i64,
+    /// <p>The external workflow execution that the signal was delivered to.</p>
+    #[serde(rename = "workflowExecution")]
+    pub workflow_execution: WorkflowExecution,
+}
+
+/// <p>Provides the details of the <code>FailWorkflowExecution</code> decision.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this decision's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct FailWorkflowExecutionDecisionAttributes {
+    /// <p> Details of the failure.</p>
+    #[serde(rename = "details")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details: Option<String>,
+    /// <p>A descriptive reason for the failure that may help in diagnostics.</p>
+    #[serde(rename = "reason")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FailWorkflowExecutionFailedCause {
+    /// `UNHANDLED_DECISION`
+    UnhandledDecision,
+    /// `OPERATION_NOT_PERMITTED`
+    OperationNotPermitted,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl FailWorkflowExecutionFailedCause {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FailWorkflowExecutionFailedCause::UnhandledDecision => "UNHANDLED_DECISION",
+            FailWorkflowExecutionFailedCause::OperationNotPermitted => "OPERATION_NOT_PERMITTED",
+            FailWorkflowExecutionFailedCause::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for FailWorkflowExecutionFailedCause {
+    fn default() -> FailWorkflowExecutionFailedCause {
+        FailWorkflowExecutionFailedCause::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for FailWorkflowExecutionFailedCause {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FailWorkflowExecutionFailedCause {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FailWorkflowExecutionFailedCause {
+    fn from(value: &'a str) -> FailWorkflowExecutionFailedCause {
+        match value {
+            "UNHANDLED_DECISION" => FailWorkflowExecutionFailedCause::UnhandledDecision,
+            "OPERATION_NOT_PERMITTED" => FailWorkflowExecutionFailedCause::OperationNotPermitted,
+            _ => FailWorkflowExecutionFailedCause::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FailWorkflowExecutionFailedCause {
+    fn from(value: String) -> FailWorkflowExecutionFailedCause {
+        match value.as_str() {
+            "UNHANDLED_DECISION" => FailWorkflowExecutionFailedCause::UnhandledDecision,
+            "OPERATION_NOT_PERMITTED" => FailWorkflowExecutionFailedCause::OperationNotPermitted,
+            _ => FailWorkflowExecutionFailedCause::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for FailWorkflowExecutionFailedCause {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<FailWorkflowExecutionFailedCause, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FailWorkflowExecutionFailedCause {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
 }
 
-/// <p>Provides the details of the <code>FailWorkflowExecution</code> decision.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this decision's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct FailWorkflowExecutionDecisionAttributes {
-    /// <p> Details of the failure.</p>
-    #[serde(rename = "details")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub details: Option<String>,
-    /// <p>A descriptive reason for the failure that may help in diagnostics.</p>
-    #[serde(rename = "reason")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub reason: Option<String>,
+impl ::serde::Serialize for FailWorkflowExecutionFailedCause {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for FailWorkflowExecutionFailedCause {
+    fn deserialize<D>(deserializer: D) -> Result<FailWorkflowExecutionFailedCause, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Provides the details of the <code>FailWorkflowExecutionFailed</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct FailWorkflowExecutionFailedEventAttributes {
     /// <p><p>The cause of the failure. This information is generated by the system and can be useful for diagnostic purposes.</p> <note> <p>If <code>cause</code> is set to <code>OPERATION<em>NOT</em>PERMITTED</code>, the decision failed because it lacked sufficient permissions. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p> </note></p>
     #[serde(rename = "cause")]
-    pub cause: String,
+    pub cause: FailWorkflowExecutionFailedCause,
     /// <p>The ID of the <code>DecisionTaskCompleted</code> event corresponding to the decision task that resulted in the <code>FailWorkflowExecution</code> decision to fail this execution. This information can be useful for diagnosing problems by tracing back the chain of events leading up to this event.</p>
     #[serde(rename = "decisionTaskCompletedEventId")]
     pub decision_task_completed_event_id: i64,
@@ -1112,10 +2689,15 @@ pub struct HistoryEvent {
     pub event_id: i64,
     /// <p>The date and time when the event occurred.</p>
     #[serde(rename = "eventTimestamp")]
-    pub event_timestamp: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub event_timestamp: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The type of the history event.</p>
     #[serde(rename = "eventType")]
-    pub event_type: String,
+    pub event_type: EventType,
     /// <p>If the event is of type <code>ExternalWorkflowExecutionCancelRequested</code> then this member is set and provides detailed information about the event. It isn't set for other event types. </p>
     #[serde(rename = "externalWorkflowExecutionCancelRequestedEventAttributes")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1356,9 +2938,97 @@ pub struct LambdaFunctionTimedOutEventAttributes {
     /// <p>The type of the timeout that caused this event.</p>
     #[serde(rename = "timeoutType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub timeout_type: Option<String>,
+    pub timeout_type: Option<LambdaFunctionTimeoutType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LambdaFunctionTimeoutType {
+    /// `START_TO_CLOSE`
+    StartToClose,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LambdaFunctionTimeoutType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LambdaFunctionTimeoutType::StartToClose => "START_TO_CLOSE",
+            LambdaFunctionTimeoutType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LambdaFunctionTimeoutType {
+    fn default() -> LambdaFunctionTimeoutType {
+        LambdaFunctionTimeoutType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LambdaFunctionTimeoutType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LambdaFunctionTimeoutType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LambdaFunctionTimeoutType {
+    fn from(value: &'a str) -> LambdaFunctionTimeoutType {
+        match value {
+            "START_TO_CLOSE" => LambdaFunctionTimeoutType::StartToClose,
+            _ => LambdaFunctionTimeoutType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LambdaFunctionTimeoutType {
+    fn from(value: String) -> LambdaFunctionTimeoutType {
+        match value.as_str() {
+            "START_TO_CLOSE" => LambdaFunctionTimeoutType::StartToClose,
+            _ => LambdaFunctionTimeoutType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LambdaFunctionTimeoutType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LambdaFunctionTimeoutType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LambdaFunctionTimeoutType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LambdaFunctionTimeoutType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for LambdaFunctionTimeoutType {
+    fn deserialize<D>(deserializer: D) -> Result<LambdaFunctionTimeoutType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ListActivityTypesInput {
@@ -1379,7 +3049,7 @@ pub struct ListActivityTypesInput {
     pub next_page_token: Option<String>,
     /// <p>Specifies the registration status of the activity types to list.</p>
     #[serde(rename = "registrationStatus")]
-    pub registration_status: String,
+    pub registration_status: RegistrationStatus,
     /// <p>When set to <code>true</code>, returns the results in reverse order. By default, the results are returned in ascending alphabetical order by <code>name</code> of the activity types.</p>
     #[serde(rename = "reverseOrder")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1443,7 +3113,7 @@ pub struct ListDomainsInput {
     pub next_page_token: Option<String>,
     /// <p>Specifies the registration status of the domains to list.</p>
     #[serde(rename = "registrationStatus")]
-    pub registration_status: String,
+    pub registration_status: RegistrationStatus,
     /// <p>When set to <code>true</code>, returns the results in reverse order. By default, the results are returned in ascending alphabetical order by <code>name</code> of the domains.</p>
     #[serde(rename = "reverseOrder")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1522,7 +3192,7 @@ pub struct ListWorkflowTypesInput {
     pub next_page_token: Option<String>,
     /// <p>Specifies the registration status of the workflow types to list.</p>
     #[serde(rename = "registrationStatus")]
-    pub registration_status: String,
+    pub registration_status: RegistrationStatus,
     /// <p>When set to <code>true</code>, returns the results in reverse order. By default the results are returned in ascending alphabetical order of the <code>name</code> of the workflow types.</p>
     #[serde(rename = "reverseOrder")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1625,13 +3295,101 @@ pub struct RecordMarkerDecisionAttributes {
     pub marker_name: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RecordMarkerFailedCause {
+    /// `OPERATION_NOT_PERMITTED`
+    OperationNotPermitted,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RecordMarkerFailedCause {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RecordMarkerFailedCause::OperationNotPermitted => "OPERATION_NOT_PERMITTED",
+            RecordMarkerFailedCause::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RecordMarkerFailedCause {
+    fn default() -> RecordMarkerFailedCause {
+        RecordMarkerFailedCause::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RecordMarkerFailedCause {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RecordMarkerFailedCause {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RecordMarkerFailedCause {
+    fn from(value: &'a str) -> RecordMarkerFailedCause {
+        match value {
+            "OPERATION_NOT_PERMITTED" => RecordMarkerFailedCause::OperationNotPermitted,
+            _ => RecordMarkerFailedCause::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RecordMarkerFailedCause {
+    fn from(value: String) -> RecordMarkerFailedCause {
+        match value.as_str() {
+            "OPERATION_NOT_PERMITTED" => RecordMarkerFailedCause::OperationNotPermitted,
+            _ => RecordMarkerFailedCause::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RecordMarkerFailedCause {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RecordMarkerFailedCause, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RecordMarkerFailedCause {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RecordMarkerFailedCause {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RecordMarkerFailedCause {
+    fn deserialize<D>(deserializer: D) -> Result<RecordMarkerFailedCause, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Provides the details of the <code>RecordMarkerFailed</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RecordMarkerFailedEventAttributes {
     /// <p><p>The cause of the failure. This information is generated by the system and can be useful for diagnostic purposes.</p> <note> <p>If <code>cause</code> is set to <code>OPERATION<em>NOT</em>PERMITTED</code>, the decision failed because it lacked sufficient permissions. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p> </note></p>
     #[serde(rename = "cause")]
-    pub cause: String,
+    pub cause: RecordMarkerFailedCause,
     /// <p>The ID of the <code>DecisionTaskCompleted</code> event corresponding to the decision task that resulted in the <code>RecordMarkerFailed</code> decision for this cancellation request. This information can be useful for diagnosing problems by tracing back the chain of events leading up to this event.</p>
     #[serde(rename = "decisionTaskCompletedEventId")]
     pub decision_task_completed_event_id: i64,
@@ -1707,7 +3465,7 @@ pub struct RegisterWorkflowTypeInput {
     /// <p><p>If set, specifies the default policy to use for the child workflow executions when a workflow execution of this type is terminated, by calling the <a>TerminateWorkflowExecution</a> action explicitly or due to an expired timeout. This default can be overridden when starting a workflow execution using the <a>StartWorkflowExecution</a> action or the <code>StartChildWorkflowExecution</code> <a>Decision</a>.</p> <p>The supported child policies are:</p> <ul> <li> <p> <code>TERMINATE</code> – The child executions are terminated.</p> </li> <li> <p> <code>REQUEST_CANCEL</code> – A request to cancel is attempted for each child execution by recording a <code>WorkflowExecutionCancelRequested</code> event in its history. It is up to the decider to take appropriate actions when it receives an execution history with this event.</p> </li> <li> <p> <code>ABANDON</code> – No action is taken. The child executions continue to run.</p> </li> </ul></p>
     #[serde(rename = "defaultChildPolicy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub default_child_policy: Option<String>,
+    pub default_child_policy: Option<ChildPolicy>,
     /// <p>If set, specifies the default maximum duration for executions of this workflow type. You can override this default when starting an execution through the <a>StartWorkflowExecution</a> Action or <code>StartChildWorkflowExecution</code> <a>Decision</a>.</p> <p>The duration is specified in seconds; an integer greater than or equal to 0. Unlike some of the other timeout parameters in Amazon SWF, you cannot specify a value of "NONE" for <code>defaultExecutionStartToCloseTimeout</code>; there is a one-year max limit on the time that a workflow execution can run. Exceeding this limit always causes the workflow execution to time out.</p>
     #[serde(rename = "defaultExecutionStartToCloseTimeout")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1743,6 +3501,99 @@ pub struct RegisterWorkflowTypeInput {
     pub version: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RegistrationStatus {
+    /// `REGISTERED`
+    Registered,
+    /// `DEPRECATED`
+    Deprecated,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RegistrationStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RegistrationStatus::Registered => "REGISTERED",
+            RegistrationStatus::Deprecated => "DEPRECATED",
+            RegistrationStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RegistrationStatus {
+    fn default() -> RegistrationStatus {
+        RegistrationStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RegistrationStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RegistrationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RegistrationStatus {
+    fn from(value: &'a str) -> RegistrationStatus {
+        match value {
+            "REGISTERED" => RegistrationStatus::Registered,
+            "DEPRECATED" => RegistrationStatus::Deprecated,
+            _ => RegistrationStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RegistrationStatus {
+    fn from(value: String) -> RegistrationStatus {
+        match value.as_str() {
+            "REGISTERED" => RegistrationStatus::Registered,
+            "DEPRECATED" => RegistrationStatus::Deprecated,
+            _ => RegistrationStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RegistrationStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RegistrationStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RegistrationStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RegistrationStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RegistrationStatus {
+    fn deserialize<D>(deserializer: D) -> Result<RegistrationStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Provides the details of the <code>RequestCancelActivityTask</code> decision.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this decision's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -1752,45 +3603,246 @@ pub struct RequestCancelActivityTaskDecisionAttributes {
     pub activity_id: String,
 }
 
-/// <p>Provides the details of the <code>RequestCancelActivityTaskFailed</code> event.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct RequestCancelActivityTaskFailedEventAttributes {
-    /// <p>The activityId provided in the <code>RequestCancelActivityTask</code> decision that failed.</p>
-    #[serde(rename = "activityId")]
-    pub activity_id: String,
-    /// <p><p>The cause of the failure. This information is generated by the system and can be useful for diagnostic purposes.</p> <note> <p>If <code>cause</code> is set to <code>OPERATION<em>NOT</em>PERMITTED</code>, the decision failed because it lacked sufficient permissions. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p> </note></p>
-    #[serde(rename = "cause")]
-    pub cause: String,
-    /// <p>The ID of the <code>DecisionTaskCompleted</code> event corresponding to the decision task that resulted in the <code>RequestCancelActivityTask</code> decision for this cancellation request. This information can be useful for diagnosing problems by tracing back the chain of events leading up to this event.</p>
-    #[serde(rename = "decisionTaskCompletedEventId")]
-    pub decision_task_completed_event_id: i64,
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RequestCancelActivityTaskFailedCause {
+    /// `ACTIVITY_ID_UNKNOWN`
+    ActivityIdUnknown,
+    /// `OPERATION_NOT_PERMITTED`
+    OperationNotPermitted,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RequestCancelActivityTaskFailedCause {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RequestCancelActivityTaskFailedCause::ActivityIdUnknown => "ACTIVITY_ID_UNKNOWN",
+            RequestCancelActivityTaskFailedCause::OperationNotPermitted => {
+                "OPERATION_NOT_PERMITTED"
+            }
+            RequestCancelActivityTaskFailedCause::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RequestCancelActivityTaskFailedCause {
+    fn default() -> RequestCancelActivityTaskFailedCause {
+        RequestCancelActivityTaskFailedCause::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RequestCancelActivityTaskFailedCause {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RequestCancelActivityTaskFailedCause {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RequestCancelActivityTaskFailedCause {
+    fn from(value: &'a str) -> RequestCancelActivityTaskFailedCause {
+        match value {
+            "ACTIVITY_ID_UNKNOWN" => RequestCancelActivityTaskFailedCause::ActivityIdUnknown,
+            "OPERATION_NOT_PERMITTED" => {
+                RequestCancelActivityTaskFailedCause::OperationNotPermitted
+            }
+            _ => RequestCancelActivityTaskFailedCause::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RequestCancelActivityTaskFailedCause {
+    fn from(value: String) -> RequestCancelActivityTaskFailedCause {
+        match value.as_str() {
+            "ACTIVITY_ID_UNKNOWN" => RequestCancelActivityTaskFailedCause::ActivityIdUnknown,
+            "OPERATION_NOT_PERMITTED" => {
+                RequestCancelActivityTaskFailedCause::OperationNotPermitted
+            }
+            _ => RequestCancelActivityTaskFailedCause::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RequestCancelActivityTaskFailedCause {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RequestCancelActivityTaskFailedCause, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RequestCancelActivityTaskFailedCause {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RequestCancelActivityTaskFailedCause {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RequestCancelActivityTaskFailedCause {
+    fn deserialize<D>(deserializer: D) -> Result<RequestCancelActivityTaskFailedCause, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Provides the details of the <code>RequestCancelActivityTaskFailed</code> event.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct RequestCancelActivityTaskFailedEventAttributes {
+    /// <p>The activityId provided in the <code>RequestCancelActivityTask</code> decision that failed.</p>
+    #[serde(rename = "activityId")]
+    pub activity_id: String,
+    /// <p><p>The cause of the failure. This information is generated by the system and can be useful for diagnostic purposes.</p> <note> <p>If <code>cause</code> is set to <code>OPERATION<em>NOT</em>PERMITTED</code>, the decision failed because it lacked sufficient permissions. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p> </note></p>
+    #[serde(rename = "cause")]
+    pub cause: RequestCancelActivityTaskFailedCause,
+    /// <p>The ID of the <code>DecisionTaskCompleted</code> event corresponding to the decision task that resulted in the <code>RequestCancelActivityTask</code> decision for this cancellation request. This information can be useful for diagnosing problems by tracing back the chain of events leading up to this event.</p>
+    #[serde(rename = "decisionTaskCompletedEventId")]
+    pub decision_task_completed_event_id: i64,
+}
+
+/// <p>Provides the details of the <code>RequestCancelExternalWorkflowExecution</code> decision.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this decision's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct RequestCancelExternalWorkflowExecutionDecisionAttributes {
+    /// <p>The data attached to the event that can be used by the decider in subsequent workflow tasks.</p>
+    #[serde(rename = "control")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub control: Option<String>,
+    /// <p>The <code>runId</code> of the external workflow execution to cancel.</p>
+    #[serde(rename = "runId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub run_id: Option<String>,
+    /// <p> The <code>workflowId</code> of the external workflow execution to cancel.</p>
+    #[serde(rename = "workflowId")]
+    pub workflow_id: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RequestCancelExternalWorkflowExecutionFailedCause {
+    /// `UNKNOWN_EXTERNAL_WORKFLOW_EXECUTION`
+    UnknownExternalWorkflowExecution,
+    /// `REQUEST_CANCEL_EXTERNAL_WORKFLOW_EXECUTION_RATE_EXCEEDED`
+    RequestCancelExternalWorkflowExecutionRateExceeded,
+    /// `OPERATION_NOT_PERMITTED`
+    OperationNotPermitted,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RequestCancelExternalWorkflowExecutionFailedCause {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+                    RequestCancelExternalWorkflowExecutionFailedCause::UnknownExternalWorkflowExecution => "UNKNOWN_EXTERNAL_WORKFLOW_EXECUTION",
+RequestCancelExternalWorkflowExecutionFailedCause::RequestCancelExternalWorkflowExecutionRateExceeded => "REQUEST_CANCEL_EXTERNAL_WORKFLOW_EXECUTION_RATE_EXCEEDED",
+RequestCancelExternalWorkflowExecutionFailedCause::OperationNotPermitted => "OPERATION_NOT_PERMITTED",
+                    RequestCancelExternalWorkflowExecutionFailedCause::Unknown(ref value) => value,
+                }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RequestCancelExternalWorkflowExecutionFailedCause {
+    fn default() -> RequestCancelExternalWorkflowExecutionFailedCause {
+        RequestCancelExternalWorkflowExecutionFailedCause::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RequestCancelExternalWorkflowExecutionFailedCause {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RequestCancelExternalWorkflowExecutionFailedCause {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RequestCancelExternalWorkflowExecutionFailedCause {
+    fn from(value: &'a str) -> RequestCancelExternalWorkflowExecutionFailedCause {
+        match value {
+                    "UNKNOWN_EXTERNAL_WORKFLOW_EXECUTION" => RequestCancelExternalWorkflowExecutionFailedCause::UnknownExternalWorkflowExecution,
+"REQUEST_CANCEL_EXTERNAL_WORKFLOW_EXECUTION_RATE_EXCEEDED" => RequestCancelExternalWorkflowExecutionFailedCause::RequestCancelExternalWorkflowExecutionRateExceeded,
+"OPERATION_NOT_PERMITTED" => RequestCancelExternalWorkflowExecutionFailedCause::OperationNotPermitted,
+                    _ => RequestCancelExternalWorkflowExecutionFailedCause::Unknown(value.to_owned()),
+                }
+    }
+}
+
+impl From<String> for RequestCancelExternalWorkflowExecutionFailedCause {
+    fn from(value: String) -> RequestCancelExternalWorkflowExecutionFailedCause {
+        match value.as_str() {
+                    "UNKNOWN_EXTERNAL_WORKFLOW_EXECUTION" => RequestCancelExternalWorkflowExecutionFailedCause::UnknownExternalWorkflowExecution,
+"REQUEST_CANCEL_EXTERNAL_WORKFLOW_EXECUTION_RATE_EXCEEDED" => RequestCancelExternalWorkflowExecutionFailedCause::RequestCancelExternalWorkflowExecutionRateExceeded,
+"OPERATION_NOT_PERMITTED" => RequestCancelExternalWorkflowExecutionFailedCause::OperationNotPermitted,
+                    _ => RequestCancelExternalWorkflowExecutionFailedCause::Unknown(value),
+                }
+    }
+}
+
+impl ::std::str::FromStr for RequestCancelExternalWorkflowExecutionFailedCause {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(
+        value: &str,
+    ) -> Result<RequestCancelExternalWorkflowExecutionFailedCause, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RequestCancelExternalWorkflowExecutionFailedCause {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
 }
 
-/// <p>Provides the details of the <code>RequestCancelExternalWorkflowExecution</code> decision.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this decision's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct RequestCancelExternalWorkflowExecutionDecisionAttributes {
-    /// <p>The data attached to the event that can be used by the decider in subsequent workflow tasks.</p>
-    #[serde(rename = "control")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub control: Option<String>,
-    /// <p>The <code>runId</code> of the external workflow execution to cancel.</p>
-    #[serde(rename = "runId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub run_id: Option<String>,
-    /// <p> The <code>workflowId</code> of the external workflow execution to cancel.</p>
-    #[serde(rename = "workflowId")]
-    pub workflow_id: String,
+impl ::serde::Serialize for RequestCancelExternalWorkflowExecutionFailedCause {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for RequestCancelExternalWorkflowExecutionFailedCause {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> Result<RequestCancelExternalWorkflowExecutionFailedCause, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Provides the details of the <code>RequestCancelExternalWorkflowExecutionFailed</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RequestCancelExternalWorkflowExecutionFailedEventAttributes {
     /// <p><p>The cause of the failure. This information is generated by the system and can be useful for diagnostic purposes.</p> <note> <p>If <code>cause</code> is set to <code>OPERATION<em>NOT</em>PERMITTED</code>, the decision failed because it lacked sufficient permissions. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p> </note></p>
     #[serde(rename = "cause")]
-    pub cause: String,
+    pub cause: RequestCancelExternalWorkflowExecutionFailedCause,
     /// <p>The data attached to the event that the decider can use in subsequent workflow tasks. This data isn't sent to the workflow execution.</p>
     #[serde(rename = "control")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1968,6 +4020,192 @@ pub struct ScheduleActivityTaskDecisionAttributes {
     pub task_priority: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ScheduleActivityTaskFailedCause {
+    /// `ACTIVITY_TYPE_DEPRECATED`
+    ActivityTypeDeprecated,
+    /// `ACTIVITY_TYPE_DOES_NOT_EXIST`
+    ActivityTypeDoesNotExist,
+    /// `ACTIVITY_ID_ALREADY_IN_USE`
+    ActivityIdAlreadyInUse,
+    /// `OPEN_ACTIVITIES_LIMIT_EXCEEDED`
+    OpenActivitiesLimitExceeded,
+    /// `ACTIVITY_CREATION_RATE_EXCEEDED`
+    ActivityCreationRateExceeded,
+    /// `DEFAULT_SCHEDULE_TO_CLOSE_TIMEOUT_UNDEFINED`
+    DefaultScheduleToCloseTimeoutUndefined,
+    /// `DEFAULT_TASK_LIST_UNDEFINED`
+    DefaultTaskListUndefined,
+    /// `DEFAULT_SCHEDULE_TO_START_TIMEOUT_UNDEFINED`
+    DefaultScheduleToStartTimeoutUndefined,
+    /// `DEFAULT_START_TO_CLOSE_TIMEOUT_UNDEFINED`
+    DefaultStartToCloseTimeoutUndefined,
+    /// `DEFAULT_HEARTBEAT_TIMEOUT_UNDEFINED`
+    DefaultHeartbeatTimeoutUndefined,
+    /// `OPERATION_NOT_PERMITTED`
+    OperationNotPermitted,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ScheduleActivityTaskFailedCause {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ScheduleActivityTaskFailedCause::ActivityTypeDeprecated => "ACTIVITY_TYPE_DEPRECATED",
+            ScheduleActivityTaskFailedCause::ActivityTypeDoesNotExist => {
+                "ACTIVITY_TYPE_DOES_NOT_EXIST"
+            }
+            ScheduleActivityTaskFailedCause::ActivityIdAlreadyInUse => "ACTIVITY_ID_ALREADY_IN_USE",
+            ScheduleActivityTaskFailedCause::OpenActivitiesLimitExceeded => {
+                "OPEN_ACTIVITIES_LIMIT_EXCEEDED"
+            }
+            ScheduleActivityTaskFailedCause::ActivityCreationRateExceeded => {
+                "ACTIVITY_CREATION_RATE_EXCEEDED"
+            }
+            ScheduleActivityTaskFailedCause::DefaultScheduleToCloseTimeoutUndefined => {
+                "DEFAULT_SCHEDULE_TO_CLOSE_TIMEOUT_UNDEFINED"
+            }
+            ScheduleActivityTaskFailedCause::DefaultTaskListUndefined => {
+                "DEFAULT_TASK_LIST_UNDEFINED"
+            }
+            ScheduleActivityTaskFailedCause::DefaultScheduleToStartTimeoutUndefined => {
+                "DEFAULT_SCHEDULE_TO_START_TIMEOUT_UNDEFINED"
+            }
+            ScheduleActivityTaskFailedCause::DefaultStartToCloseTimeoutUndefined => {
+                "DEFAULT_START_TO_CLOSE_TIMEOUT_UNDEFINED"
+            }
+            ScheduleActivityTaskFailedCause::DefaultHeartbeatTimeoutUndefined => {
+                "DEFAULT_HEARTBEAT_TIMEOUT_UNDEFINED"
+            }
+            ScheduleActivityTaskFailedCause::OperationNotPermitted => "OPERATION_NOT_PERMITTED",
+            ScheduleActivityTaskFailedCause::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ScheduleActivityTaskFailedCause {
+    fn default() -> ScheduleActivityTaskFailedCause {
+        ScheduleActivityTaskFailedCause::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ScheduleActivityTaskFailedCause {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ScheduleActivityTaskFailedCause {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ScheduleActivityTaskFailedCause {
+    fn from(value: &'a str) -> ScheduleActivityTaskFailedCause {
+        match value {
+            "ACTIVITY_TYPE_DEPRECATED" => ScheduleActivityTaskFailedCause::ActivityTypeDeprecated,
+            "ACTIVITY_TYPE_DOES_NOT_EXIST" => {
+                ScheduleActivityTaskFailedCause::ActivityTypeDoesNotExist
+            }
+            "ACTIVITY_ID_ALREADY_IN_USE" => ScheduleActivityTaskFailedCause::ActivityIdAlreadyInUse,
+            "OPEN_ACTIVITIES_LIMIT_EXCEEDED" => {
+                ScheduleActivityTaskFailedCause::OpenActivitiesLimitExceeded
+            }
+            "ACTIVITY_CREATION_RATE_EXCEEDED" => {
+                ScheduleActivityTaskFailedCause::ActivityCreationRateExceeded
+            }
+            "DEFAULT_SCHEDULE_TO_CLOSE_TIMEOUT_UNDEFINED" => {
+                ScheduleActivityTaskFailedCause::DefaultScheduleToCloseTimeoutUndefined
+            }
+            "DEFAULT_TASK_LIST_UNDEFINED" => {
+                ScheduleActivityTaskFailedCause::DefaultTaskListUndefined
+            }
+            "DEFAULT_SCHEDULE_TO_START_TIMEOUT_UNDEFINED" => {
+                ScheduleActivityTaskFailedCause::DefaultScheduleToStartTimeoutUndefined
+            }
+            "DEFAULT_START_TO_CLOSE_TIMEOUT_UNDEFINED" => {
+                ScheduleActivityTaskFailedCause::DefaultStartToCloseTimeoutUndefined
+            }
+            "DEFAULT_HEARTBEAT_TIMEOUT_UNDEFINED" => {
+                ScheduleActivityTaskFailedCause::DefaultHeartbeatTimeoutUndefined
+            }
+            "OPERATION_NOT_PERMITTED" => ScheduleActivityTaskFailedCause::OperationNotPermitted,
+            _ => ScheduleActivityTaskFailedCause::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ScheduleActivityTaskFailedCause {
+    fn from(value: String) -> ScheduleActivityTaskFailedCause {
+        match value.as_str() {
+            "ACTIVITY_TYPE_DEPRECATED" => ScheduleActivityTaskFailedCause::ActivityTypeDeprecated,
+            "ACTIVITY_TYPE_DOES_NOT_EXIST" => {
+                ScheduleActivityTaskFailedCause::ActivityTypeDoesNotExist
+            }
+            "ACTIVITY_ID_ALREADY_IN_USE" => ScheduleActivityTaskFailedCause::ActivityIdAlreadyInUse,
+            "OPEN_ACTIVITIES_LIMIT_EXCEEDED" => {
+                ScheduleActivityTaskFailedCause::OpenActivitiesLimitExceeded
+            }
+            "ACTIVITY_CREATION_RATE_EXCEEDED" => {
+                ScheduleActivityTaskFailedCause::ActivityCreationRateExceeded
+            }
+            "DEFAULT_SCHEDULE_TO_CLOSE_TIMEOUT_UNDEFINED" => {
+                ScheduleActivityTaskFailedCause::DefaultScheduleToCloseTimeoutUndefined
+            }
+            "DEFAULT_TASK_LIST_UNDEFINED" => {
+                ScheduleActivityTaskFailedCause::DefaultTaskListUndefined
+            }
+            "DEFAULT_SCHEDULE_TO_START_TIMEOUT_UNDEFINED" => {
+                ScheduleActivityTaskFailedCause::DefaultScheduleToStartTimeoutUndefined
+            }
+            "DEFAULT_START_TO_CLOSE_TIMEOUT_UNDEFINED" => {
+                ScheduleActivityTaskFailedCause::DefaultStartToCloseTimeoutUndefined
+            }
+            "DEFAULT_HEARTBEAT_TIMEOUT_UNDEFINED" => {
+                ScheduleActivityTaskFailedCause::DefaultHeartbeatTimeoutUndefined
+            }
+            "OPERATION_NOT_PERMITTED" => ScheduleActivityTaskFailedCause::OperationNotPermitted,
+            _ => ScheduleActivityTaskFailedCause::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ScheduleActivityTaskFailedCause {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ScheduleActivityTaskFailedCause, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ScheduleActivityTaskFailedCause {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ScheduleActivityTaskFailedCause {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ScheduleActivityTaskFailedCause {
+    fn deserialize<D>(deserializer: D) -> Result<ScheduleActivityTaskFailedCause, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Provides the details of the <code>ScheduleActivityTaskFailed</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1980,7 +4218,7 @@ pub struct ScheduleActivityTaskFailedEventAttributes {
     pub activity_type: ActivityType,
     /// <p><p>The cause of the failure. This information is generated by the system and can be useful for diagnostic purposes.</p> <note> <p>If <code>cause</code> is set to <code>OPERATION<em>NOT</em>PERMITTED</code>, the decision failed because it lacked sufficient permissions. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p> </note></p>
     #[serde(rename = "cause")]
-    pub cause: String,
+    pub cause: ScheduleActivityTaskFailedCause,
     /// <p>The ID of the <code>DecisionTaskCompleted</code> event corresponding to the decision that resulted in the scheduling of this activity task. This information can be useful for diagnosing problems by tracing back the chain of events leading up to this event.</p>
     #[serde(rename = "decisionTaskCompletedEventId")]
     pub decision_task_completed_event_id: i64,
@@ -2010,13 +4248,134 @@ pub struct ScheduleLambdaFunctionDecisionAttributes {
     pub start_to_close_timeout: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ScheduleLambdaFunctionFailedCause {
+    /// `ID_ALREADY_IN_USE`
+    IdAlreadyInUse,
+    /// `OPEN_LAMBDA_FUNCTIONS_LIMIT_EXCEEDED`
+    OpenLambdaFunctionsLimitExceeded,
+    /// `LAMBDA_FUNCTION_CREATION_RATE_EXCEEDED`
+    LambdaFunctionCreationRateExceeded,
+    /// `LAMBDA_SERVICE_NOT_AVAILABLE_IN_REGION`
+    LambdaServiceNotAvailableInRegion,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ScheduleLambdaFunctionFailedCause {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ScheduleLambdaFunctionFailedCause::IdAlreadyInUse => "ID_ALREADY_IN_USE",
+            ScheduleLambdaFunctionFailedCause::OpenLambdaFunctionsLimitExceeded => {
+                "OPEN_LAMBDA_FUNCTIONS_LIMIT_EXCEEDED"
+            }
+            ScheduleLambdaFunctionFailedCause::LambdaFunctionCreationRateExceeded => {
+                "LAMBDA_FUNCTION_CREATION_RATE_EXCEEDED"
+            }
+            ScheduleLambdaFunctionFailedCause::LambdaServiceNotAvailableInRegion => {
+                "LAMBDA_SERVICE_NOT_AVAILABLE_IN_REGION"
+            }
+            ScheduleLambdaFunctionFailedCause::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ScheduleLambdaFunctionFailedCause {
+    fn default() -> ScheduleLambdaFunctionFailedCause {
+        ScheduleLambdaFunctionFailedCause::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ScheduleLambdaFunctionFailedCause {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ScheduleLambdaFunctionFailedCause {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ScheduleLambdaFunctionFailedCause {
+    fn from(value: &'a str) -> ScheduleLambdaFunctionFailedCause {
+        match value {
+            "ID_ALREADY_IN_USE" => ScheduleLambdaFunctionFailedCause::IdAlreadyInUse,
+            "OPEN_LAMBDA_FUNCTIONS_LIMIT_EXCEEDED" => {
+                ScheduleLambdaFunctionFailedCause::OpenLambdaFunctionsLimitExceeded
+            }
+            "LAMBDA_FUNCTION_CREATION_RATE_EXCEEDED" => {
+                ScheduleLambdaFunctionFailedCause::LambdaFunctionCreationRateExceeded
+            }
+            "LAMBDA_SERVICE_NOT_AVAILABLE_IN_REGION" => {
+                ScheduleLambdaFunctionFailedCause::LambdaServiceNotAvailableInRegion
+            }
+            _ => ScheduleLambdaFunctionFailedCause::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ScheduleLambdaFunctionFailedCause {
+    fn from(value: String) -> ScheduleLambdaFunctionFailedCause {
+        match value.as_str() {
+            "ID_ALREADY_IN_USE" => ScheduleLambdaFunctionFailedCause::IdAlreadyInUse,
+            "OPEN_LAMBDA_FUNCTIONS_LIMIT_EXCEEDED" => {
+                ScheduleLambdaFunctionFailedCause::OpenLambdaFunctionsLimitExceeded
+            }
+            "LAMBDA_FUNCTION_CREATION_RATE_EXCEEDED" => {
+                ScheduleLambdaFunctionFailedCause::LambdaFunctionCreationRateExceeded
+            }
+            "LAMBDA_SERVICE_NOT_AVAILABLE_IN_REGION" => {
+                ScheduleLambdaFunctionFailedCause::LambdaServiceNotAvailableInRegion
+            }
+            _ => ScheduleLambdaFunctionFailedCause::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ScheduleLambdaFunctionFailedCause {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ScheduleLambdaFunctionFailedCause, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ScheduleLambdaFunctionFailedCause {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ScheduleLambdaFunctionFailedCause {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ScheduleLambdaFunctionFailedCause {
+    fn deserialize<D>(deserializer: D) -> Result<ScheduleLambdaFunctionFailedCause, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Provides the details of the <code>ScheduleLambdaFunctionFailed</code> event. It isn't set for other event types.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ScheduleLambdaFunctionFailedEventAttributes {
     /// <p><p>The cause of the failure. To help diagnose issues, use this information to trace back the chain of events leading up to this event.</p> <note> <p>If <code>cause</code> is set to <code>OPERATION<em>NOT</em>PERMITTED</code>, the decision failed because it lacked sufficient permissions. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p> </note></p>
     #[serde(rename = "cause")]
-    pub cause: String,
+    pub cause: ScheduleLambdaFunctionFailedCause,
     /// <p>The ID of the <code>LambdaFunctionCompleted</code> event corresponding to the decision that resulted in scheduling this Lambda task. To help diagnose issues, use this information to trace back the chain of events leading up to this event.</p>
     #[serde(rename = "decisionTaskCompletedEventId")]
     pub decision_task_completed_event_id: i64,
@@ -2052,13 +4411,113 @@ pub struct SignalExternalWorkflowExecutionDecisionAttributes {
     pub workflow_id: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SignalExternalWorkflowExecutionFailedCause {
+    /// `UNKNOWN_EXTERNAL_WORKFLOW_EXECUTION`
+    UnknownExternalWorkflowExecution,
+    /// `SIGNAL_EXTERNAL_WORKFLOW_EXECUTION_RATE_EXCEEDED`
+    SignalExternalWorkflowExecutionRateExceeded,
+    /// `OPERATION_NOT_PERMITTED`
+    OperationNotPermitted,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SignalExternalWorkflowExecutionFailedCause {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+                    SignalExternalWorkflowExecutionFailedCause::UnknownExternalWorkflowExecution => "UNKNOWN_EXTERNAL_WORKFLOW_EXECUTION",
+SignalExternalWorkflowExecutionFailedCause::SignalExternalWorkflowExecutionRateExceeded => "SIGNAL_EXTERNAL_WORKFLOW_EXECUTION_RATE_EXCEEDED",
+SignalExternalWorkflowExecutionFailedCause::OperationNotPermitted => "OPERATION_NOT_PERMITTED",
+                    SignalExternalWorkflowExecutionFailedCause::Unknown(ref value) => value,
+                }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SignalExternalWorkflowExecutionFailedCause {
+    fn default() -> SignalExternalWorkflowExecutionFailedCause {
+        SignalExternalWorkflowExecutionFailedCause::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SignalExternalWorkflowExecutionFailedCause {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SignalExternalWorkflowExecutionFailedCause {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SignalExternalWorkflowExecutionFailedCause {
+    fn from(value: &'a str) -> SignalExternalWorkflowExecutionFailedCause {
+        match value {
+                    "UNKNOWN_EXTERNAL_WORKFLOW_EXECUTION" => SignalExternalWorkflowExecutionFailedCause::UnknownExternalWorkflowExecution,
+"SIGNAL_EXTERNAL_WORKFLOW_EXECUTION_RATE_EXCEEDED" => SignalExternalWorkflowExecutionFailedCause::SignalExternalWorkflowExecutionRateExceeded,
+"OPERATION_NOT_PERMITTED" => SignalExternalWorkflowExecutionFailedCause::OperationNotPermitted,
+                    _ => SignalExternalWorkflowExecutionFailedCause::Unknown(value.to_owned()),
+                }
+    }
+}
+
+impl From<String> for SignalExternalWorkflowExecutionFailedCause {
+    fn from(value: String) -> SignalExternalWorkflowExecutionFailedCause {
+        match value.as_str() {
+                    "UNKNOWN_EXTERNAL_WORKFLOW_EXECUTION" => SignalExternalWorkflowExecutionFailedCause::UnknownExternalWorkflowExecution,
+"SIGNAL_EXTERNAL_WORKFLOW_EXECUTION_RATE_EXCEEDED" => SignalExternalWorkflowExecutionFailedCause::SignalExternalWorkflowExecutionRateExceeded,
+"OPERATION_NOT_PERMITTED" => SignalExternalWorkflowExecutionFailedCause::OperationNotPermitted,
+                    _ => SignalExternalWorkflowExecutionFailedCause::Unknown(value),
+                }
+    }
+}
+
+impl ::std::str::FromStr for SignalExternalWorkflowExecutionFailedCause {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SignalExternalWorkflowExecutionFailedCause, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SignalExternalWorkflowExecutionFailedCause {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SignalExternalWorkflowExecutionFailedCause {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SignalExternalWorkflowExecutionFailedCause {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> Result<SignalExternalWorkflowExecutionFailedCause, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Provides the details of the <code>SignalExternalWorkflowExecutionFailed</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SignalExternalWorkflowExecutionFailedEventAttributes {
     /// <p><p>The cause of the failure. This information is generated by the system and can be useful for diagnostic purposes.</p> <note> <p>If <code>cause</code> is set to <code>OPERATION<em>NOT</em>PERMITTED</code>, the decision failed because it lacked sufficient permissions. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p> </note></p>
     #[serde(rename = "cause")]
-    pub cause: String,
+    pub cause: SignalExternalWorkflowExecutionFailedCause,
     /// <p>The data attached to the event that the decider can use in subsequent workflow tasks. This data isn't sent to the workflow execution.</p>
     #[serde(rename = "control")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2134,7 +4593,7 @@ pub struct StartChildWorkflowExecutionDecisionAttributes {
     /// <p><p> If set, specifies the policy to use for the child workflow executions if the workflow execution being started is terminated by calling the <a>TerminateWorkflowExecution</a> action explicitly or due to an expired timeout. This policy overrides the default child policy specified when registering the workflow type using <a>RegisterWorkflowType</a>.</p> <p>The supported child policies are:</p> <ul> <li> <p> <code>TERMINATE</code> – The child executions are terminated.</p> </li> <li> <p> <code>REQUEST_CANCEL</code> – A request to cancel is attempted for each child execution by recording a <code>WorkflowExecutionCancelRequested</code> event in its history. It is up to the decider to take appropriate actions when it receives an execution history with this event.</p> </li> <li> <p> <code>ABANDON</code> – No action is taken. The child executions continue to run.</p> </li> </ul> <note> <p>A child policy for this workflow execution must be specified either as a default for the workflow type or through this parameter. If neither this parameter is set nor a default child policy was specified at registration time then a fault is returned.</p> </note></p>
     #[serde(rename = "childPolicy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub child_policy: Option<String>,
+    pub child_policy: Option<ChildPolicy>,
     /// <p>The data attached to the event that can be used by the decider in subsequent workflow tasks. This data isn't sent to the child workflow execution.</p>
     #[serde(rename = "control")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2175,13 +4634,195 @@ pub struct StartChildWorkflowExecutionDecisionAttributes {
     pub workflow_type: WorkflowType,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StartChildWorkflowExecutionFailedCause {
+    /// `WORKFLOW_TYPE_DOES_NOT_EXIST`
+    WorkflowTypeDoesNotExist,
+    /// `WORKFLOW_TYPE_DEPRECATED`
+    WorkflowTypeDeprecated,
+    /// `OPEN_CHILDREN_LIMIT_EXCEEDED`
+    OpenChildrenLimitExceeded,
+    /// `OPEN_WORKFLOWS_LIMIT_EXCEEDED`
+    OpenWorkflowsLimitExceeded,
+    /// `CHILD_CREATION_RATE_EXCEEDED`
+    ChildCreationRateExceeded,
+    /// `WORKFLOW_ALREADY_RUNNING`
+    WorkflowAlreadyRunning,
+    /// `DEFAULT_EXECUTION_START_TO_CLOSE_TIMEOUT_UNDEFINED`
+    DefaultExecutionStartToCloseTimeoutUndefined,
+    /// `DEFAULT_TASK_LIST_UNDEFINED`
+    DefaultTaskListUndefined,
+    /// `DEFAULT_TASK_START_TO_CLOSE_TIMEOUT_UNDEFINED`
+    DefaultTaskStartToCloseTimeoutUndefined,
+    /// `DEFAULT_CHILD_POLICY_UNDEFINED`
+    DefaultChildPolicyUndefined,
+    /// `OPERATION_NOT_PERMITTED`
+    OperationNotPermitted,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl StartChildWorkflowExecutionFailedCause {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+                    StartChildWorkflowExecutionFailedCause::WorkflowTypeDoesNotExist => "WORKFLOW_TYPE_DOES_NOT_EXIST",
+StartChildWorkflowExecutionFailedCause::WorkflowTypeDeprecated => "WORKFLOW_TYPE_DEPRECATED",
+StartChildWorkflowExecutionFailedCause::OpenChildrenLimitExceeded => "OPEN_CHILDREN_LIMIT_EXCEEDED",
+StartChildWorkflowExecutionFailedCause::OpenWorkflowsLimitExceeded => "OPEN_WORKFLOWS_LIMIT_EXCEEDED",
+StartChildWorkflowExecutionFailedCause::ChildCreationRateExceeded => "CHILD_CREATION_RATE_EXCEEDED",
+StartChildWorkflowExecutionFailedCause::WorkflowAlreadyRunning => "WORKFLOW_ALREADY_RUNNING",
+StartChildWorkflowExecutionFailedCause::DefaultExecutionStartToCloseTimeoutUndefined => "DEFAULT_EXECUTION_START_TO_CLOSE_TIMEOUT_UNDEFINED",
+StartChildWorkflowExecutionFailedCause::DefaultTaskListUndefined => "DEFAULT_TASK_LIST_UNDEFINED",
+StartChildWorkflowExecutionFailedCause::DefaultTaskStartToCloseTimeoutUndefined => "DEFAULT_TASK_START_TO_CLOSE_TIMEOUT_UNDEFINED",
+StartChildWorkflowExecutionFailedCause::DefaultChildPolicyUndefined => "DEFAULT_CHILD_POLICY_UNDEFINED",
+StartChildWorkflowExecutionFailedCause::OperationNotPermitted => "OPERATION_NOT_PERMITTED",
+                    StartChildWorkflowExecutionFailedCause::Unknown(ref value) => value,
+                }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for StartChildWorkflowExecutionFailedCause {
+    fn default() -> StartChildWorkflowExecutionFailedCause {
+        StartChildWorkflowExecutionFailedCause::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for StartChildWorkflowExecutionFailedCause {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for StartChildWorkflowExecutionFailedCause {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for StartChildWorkflowExecutionFailedCause {
+    fn from(value: &'a str) -> StartChildWorkflowExecutionFailedCause {
+        match value {
+            "WORKFLOW_TYPE_DOES_NOT_EXIST" => {
+                StartChildWorkflowExecutionFailedCause::WorkflowTypeDoesNotExist
+            }
+            "WORKFLOW_TYPE_DEPRECATED" => {
+                StartChildWorkflowExecutionFailedCause::WorkflowTypeDeprecated
+            }
+            "OPEN_CHILDREN_LIMIT_EXCEEDED" => {
+                StartChildWorkflowExecutionFailedCause::OpenChildrenLimitExceeded
+            }
+            "OPEN_WORKFLOWS_LIMIT_EXCEEDED" => {
+                StartChildWorkflowExecutionFailedCause::OpenWorkflowsLimitExceeded
+            }
+            "CHILD_CREATION_RATE_EXCEEDED" => {
+                StartChildWorkflowExecutionFailedCause::ChildCreationRateExceeded
+            }
+            "WORKFLOW_ALREADY_RUNNING" => {
+                StartChildWorkflowExecutionFailedCause::WorkflowAlreadyRunning
+            }
+            "DEFAULT_EXECUTION_START_TO_CLOSE_TIMEOUT_UNDEFINED" => {
+                StartChildWorkflowExecutionFailedCause::DefaultExecutionStartToCloseTimeoutUndefined
+            }
+            "DEFAULT_TASK_LIST_UNDEFINED" => {
+                StartChildWorkflowExecutionFailedCause::DefaultTaskListUndefined
+            }
+            "DEFAULT_TASK_START_TO_CLOSE_TIMEOUT_UNDEFINED" => {
+                StartChildWorkflowExecutionFailedCause::DefaultTaskStartToCloseTimeoutUndefined
+            }
+            "DEFAULT_CHILD_POLICY_UNDEFINED" => {
+                StartChildWorkflowExecutionFailedCause::DefaultChildPolicyUndefined
+            }
+            "OPERATION_NOT_PERMITTED" => {
+                StartChildWorkflowExecutionFailedCause::OperationNotPermitted
+            }
+            _ => StartChildWorkflowExecutionFailedCause::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StartChildWorkflowExecutionFailedCause {
+    fn from(value: String) -> StartChildWorkflowExecutionFailedCause {
+        match value.as_str() {
+            "WORKFLOW_TYPE_DOES_NOT_EXIST" => {
+                StartChildWorkflowExecutionFailedCause::WorkflowTypeDoesNotExist
+            }
+            "WORKFLOW_TYPE_DEPRECATED" => {
+                StartChildWorkflowExecutionFailedCause::WorkflowTypeDeprecated
+            }
+            "OPEN_CHILDREN_LIMIT_EXCEEDED" => {
+                StartChildWorkflowExecutionFailedCause::OpenChildrenLimitExceeded
+            }
+            "OPEN_WORKFLOWS_LIMIT_EXCEEDED" => {
+                StartChildWorkflowExecutionFailedCause::OpenWorkflowsLimitExceeded
+            }
+            "CHILD_CREATION_RATE_EXCEEDED" => {
+                StartChildWorkflowExecutionFailedCause::ChildCreationRateExceeded
+            }
+            "WORKFLOW_ALREADY_RUNNING" => {
+                StartChildWorkflowExecutionFailedCause::WorkflowAlreadyRunning
+            }
+            "DEFAULT_EXECUTION_START_TO_CLOSE_TIMEOUT_UNDEFINED" => {
+                StartChildWorkflowExecutionFailedCause::DefaultExecutionStartToCloseTimeoutUndefined
+            }
+            "DEFAULT_TASK_LIST_UNDEFINED" => {
+                StartChildWorkflowExecutionFailedCause::DefaultTaskListUndefined
+            }
+            "DEFAULT_TASK_START_TO_CLOSE_TIMEOUT_UNDEFINED" => {
+                StartChildWorkflowExecutionFailedCause::DefaultTaskStartToCloseTimeoutUndefined
+            }
+            "DEFAULT_CHILD_POLICY_UNDEFINED" => {
+                StartChildWorkflowExecutionFailedCause::DefaultChildPolicyUndefined
+            }
+            "OPERATION_NOT_PERMITTED" => {
+                StartChildWorkflowExecutionFailedCause::OperationNotPermitted
+            }
+            _ => StartChildWorkflowExecutionFailedCause::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for StartChildWorkflowExecutionFailedCause {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<StartChildWorkflowExecutionFailedCause, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StartChildWorkflowExecutionFailedCause {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for StartChildWorkflowExecutionFailedCause {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StartChildWorkflowExecutionFailedCause {
+    fn deserialize<D>(deserializer: D) -> Result<StartChildWorkflowExecutionFailedCause, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Provides the details of the <code>StartChildWorkflowExecutionFailed</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartChildWorkflowExecutionFailedEventAttributes {
     /// <p><p>The cause of the failure. This information is generated by the system and can be useful for diagnostic purposes.</p> <note> <p>When <code>cause</code> is set to <code>OPERATION<em>NOT</em>PERMITTED</code>, the decision fails because it lacks sufficient permissions. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html"> Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p> </note></p>
     #[serde(rename = "cause")]
-    pub cause: String,
+    pub cause: StartChildWorkflowExecutionFailedCause,
     /// <p>The data attached to the event that the decider can use in subsequent workflow tasks. This data isn't sent to the child workflow execution.</p>
     #[serde(rename = "control")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2206,7 +4847,7 @@ pub struct StartChildWorkflowExecutionFailedEventAttributes {
 pub struct StartChildWorkflowExecutionInitiatedEventAttributes {
     /// <p><p>The policy to use for the child workflow executions if this execution gets terminated by explicitly calling the <a>TerminateWorkflowExecution</a> action or due to an expired timeout.</p> <p>The supported child policies are:</p> <ul> <li> <p> <code>TERMINATE</code> – The child executions are terminated.</p> </li> <li> <p> <code>REQUEST_CANCEL</code> – A request to cancel is attempted for each child execution by recording a <code>WorkflowExecutionCancelRequested</code> event in its history. It is up to the decider to take appropriate actions when it receives an execution history with this event.</p> </li> <li> <p> <code>ABANDON</code> – No action is taken. The child executions continue to run.</p> </li> </ul></p>
     #[serde(rename = "childPolicy")]
-    pub child_policy: String,
+    pub child_policy: ChildPolicy,
     /// <p>Data attached to the event that can be used by the decider in subsequent decision tasks. This data isn't sent to the activity.</p>
     #[serde(rename = "control")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2249,6 +4890,94 @@ pub struct StartChildWorkflowExecutionInitiatedEventAttributes {
     pub workflow_type: WorkflowType,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StartLambdaFunctionFailedCause {
+    /// `ASSUME_ROLE_FAILED`
+    AssumeRoleFailed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl StartLambdaFunctionFailedCause {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StartLambdaFunctionFailedCause::AssumeRoleFailed => "ASSUME_ROLE_FAILED",
+            StartLambdaFunctionFailedCause::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for StartLambdaFunctionFailedCause {
+    fn default() -> StartLambdaFunctionFailedCause {
+        StartLambdaFunctionFailedCause::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for StartLambdaFunctionFailedCause {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for StartLambdaFunctionFailedCause {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for StartLambdaFunctionFailedCause {
+    fn from(value: &'a str) -> StartLambdaFunctionFailedCause {
+        match value {
+            "ASSUME_ROLE_FAILED" => StartLambdaFunctionFailedCause::AssumeRoleFailed,
+            _ => StartLambdaFunctionFailedCause::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StartLambdaFunctionFailedCause {
+    fn from(value: String) -> StartLambdaFunctionFailedCause {
+        match value.as_str() {
+            "ASSUME_ROLE_FAILED" => StartLambdaFunctionFailedCause::AssumeRoleFailed,
+            _ => StartLambdaFunctionFailedCause::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for StartLambdaFunctionFailedCause {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<StartLambdaFunctionFailedCause, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StartLambdaFunctionFailedCause {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for StartLambdaFunctionFailedCause {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StartLambdaFunctionFailedCause {
+    fn deserialize<D>(deserializer: D) -> Result<StartLambdaFunctionFailedCause, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Provides the details of the <code>StartLambdaFunctionFailed</code> event. It isn't set for other event types.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -2256,7 +4985,7 @@ pub struct StartLambdaFunctionFailedEventAttributes {
     /// <p><p>The cause of the failure. To help diagnose issues, use this information to trace back the chain of events leading up to this event.</p> <note> <p>If <code>cause</code> is set to <code>OPERATION<em>NOT</em>PERMITTED</code>, the decision failed because the IAM role attached to the execution lacked sufficient permissions. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/lambda-task.html">Lambda Tasks</a> in the <i>Amazon SWF Developer Guide</i>.</p> </note></p>
     #[serde(rename = "cause")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub cause: Option<String>,
+    pub cause: Option<StartLambdaFunctionFailedCause>,
     /// <p>A description that can help diagnose the cause of the fault.</p>
     #[serde(rename = "message")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2283,13 +5012,116 @@ pub struct StartTimerDecisionAttributes {
     pub timer_id: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StartTimerFailedCause {
+    /// `TIMER_ID_ALREADY_IN_USE`
+    TimerIdAlreadyInUse,
+    /// `OPEN_TIMERS_LIMIT_EXCEEDED`
+    OpenTimersLimitExceeded,
+    /// `TIMER_CREATION_RATE_EXCEEDED`
+    TimerCreationRateExceeded,
+    /// `OPERATION_NOT_PERMITTED`
+    OperationNotPermitted,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl StartTimerFailedCause {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StartTimerFailedCause::TimerIdAlreadyInUse => "TIMER_ID_ALREADY_IN_USE",
+            StartTimerFailedCause::OpenTimersLimitExceeded => "OPEN_TIMERS_LIMIT_EXCEEDED",
+            StartTimerFailedCause::TimerCreationRateExceeded => "TIMER_CREATION_RATE_EXCEEDED",
+            StartTimerFailedCause::OperationNotPermitted => "OPERATION_NOT_PERMITTED",
+            StartTimerFailedCause::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for StartTimerFailedCause {
+    fn default() -> StartTimerFailedCause {
+        StartTimerFailedCause::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for StartTimerFailedCause {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for StartTimerFailedCause {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for StartTimerFailedCause {
+    fn from(value: &'a str) -> StartTimerFailedCause {
+        match value {
+            "TIMER_ID_ALREADY_IN_USE" => StartTimerFailedCause::TimerIdAlreadyInUse,
+            "OPEN_TIMERS_LIMIT_EXCEEDED" => StartTimerFailedCause::OpenTimersLimitExceeded,
+            "TIMER_CREATION_RATE_EXCEEDED" => StartTimerFailedCause::TimerCreationRateExceeded,
+            "OPERATION_NOT_PERMITTED" => StartTimerFailedCause::OperationNotPermitted,
+            _ => StartTimerFailedCause::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StartTimerFailedCause {
+    fn from(value: String) -> StartTimerFailedCause {
+        match value.as_str() {
+            "TIMER_ID_ALREADY_IN_USE" => StartTimerFailedCause::TimerIdAlreadyInUse,
+            "OPEN_TIMERS_LIMIT_EXCEEDED" => StartTimerFailedCause::OpenTimersLimitExceeded,
+            "TIMER_CREATION_RATE_EXCEEDED" => StartTimerFailedCause::TimerCreationRateExceeded,
+            "OPERATION_NOT_PERMITTED" => StartTimerFailedCause::OperationNotPermitted,
+            _ => StartTimerFailedCause::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for StartTimerFailedCause {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<StartTimerFailedCause, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StartTimerFailedCause {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for StartTimerFailedCause {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StartTimerFailedCause {
+    fn deserialize<D>(deserializer: D) -> Result<StartTimerFailedCause, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Provides the details of the <code>StartTimerFailed</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartTimerFailedEventAttributes {
     /// <p><p>The cause of the failure. This information is generated by the system and can be useful for diagnostic purposes.</p> <note> <p>If <code>cause</code> is set to <code>OPERATION<em>NOT</em>PERMITTED</code>, the decision failed because it lacked sufficient permissions. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p> </note></p>
     #[serde(rename = "cause")]
-    pub cause: String,
+    pub cause: StartTimerFailedCause,
     /// <p>The ID of the <code>DecisionTaskCompleted</code> event corresponding to the decision task that resulted in the <code>StartTimer</code> decision for this activity task. This information can be useful for diagnosing problems by tracing back the chain of events leading up to this event.</p>
     #[serde(rename = "decisionTaskCompletedEventId")]
     pub decision_task_completed_event_id: i64,
@@ -2304,7 +5136,7 @@ pub struct StartWorkflowExecutionInput {
     /// <p><p>If set, specifies the policy to use for the child workflow executions of this workflow execution if it is terminated, by calling the <a>TerminateWorkflowExecution</a> action explicitly or due to an expired timeout. This policy overrides the default child policy specified when registering the workflow type using <a>RegisterWorkflowType</a>.</p> <p>The supported child policies are:</p> <ul> <li> <p> <code>TERMINATE</code> – The child executions are terminated.</p> </li> <li> <p> <code>REQUEST_CANCEL</code> – A request to cancel is attempted for each child execution by recording a <code>WorkflowExecutionCancelRequested</code> event in its history. It is up to the decider to take appropriate actions when it receives an execution history with this event.</p> </li> <li> <p> <code>ABANDON</code> – No action is taken. The child executions continue to run.</p> </li> </ul> <note> <p>A child policy for this workflow execution must be specified either as a default for the workflow type or through this parameter. If neither this parameter is set nor a default child policy was specified at registration time then a fault is returned.</p> </note></p>
     #[serde(rename = "childPolicy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub child_policy: Option<String>,
+    pub child_policy: Option<ChildPolicy>,
     /// <p>The name of the domain in which the workflow execution is created.</p>
     #[serde(rename = "domain")]
     pub domain: String,
@@ -2378,7 +5210,7 @@ pub struct TerminateWorkflowExecutionInput {
     /// <p><p>If set, specifies the policy to use for the child workflow executions of the workflow execution being terminated. This policy overrides the child policy specified for the workflow execution at registration time or when starting the execution.</p> <p>The supported child policies are:</p> <ul> <li> <p> <code>TERMINATE</code> – The child executions are terminated.</p> </li> <li> <p> <code>REQUEST_CANCEL</code> – A request to cancel is attempted for each child execution by recording a <code>WorkflowExecutionCancelRequested</code> event in its history. It is up to the decider to take appropriate actions when it receives an execution history with this event.</p> </li> <li> <p> <code>ABANDON</code> – No action is taken. The child executions continue to run.</p> </li> </ul> <note> <p>A child policy for this workflow execution must be specified either as a default for the workflow type or through this parameter. If neither this parameter is set nor a default child policy was specified at registration time then a fault is returned.</p> </note></p>
     #[serde(rename = "childPolicy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub child_policy: Option<String>,
+    pub child_policy: Option<ChildPolicy>,
     /// <p> Details for terminating the workflow execution.</p>
     #[serde(rename = "details")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2497,6 +5329,94 @@ pub struct WorkflowExecution {
     pub workflow_id: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum WorkflowExecutionCancelRequestedCause {
+    /// `CHILD_POLICY_APPLIED`
+    ChildPolicyApplied,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl WorkflowExecutionCancelRequestedCause {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            WorkflowExecutionCancelRequestedCause::ChildPolicyApplied => "CHILD_POLICY_APPLIED",
+            WorkflowExecutionCancelRequestedCause::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for WorkflowExecutionCancelRequestedCause {
+    fn default() -> WorkflowExecutionCancelRequestedCause {
+        WorkflowExecutionCancelRequestedCause::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for WorkflowExecutionCancelRequestedCause {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for WorkflowExecutionCancelRequestedCause {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for WorkflowExecutionCancelRequestedCause {
+    fn from(value: &'a str) -> WorkflowExecutionCancelRequestedCause {
+        match value {
+            "CHILD_POLICY_APPLIED" => WorkflowExecutionCancelRequestedCause::ChildPolicyApplied,
+            _ => WorkflowExecutionCancelRequestedCause::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for WorkflowExecutionCancelRequestedCause {
+    fn from(value: String) -> WorkflowExecutionCancelRequestedCause {
+        match value.as_str() {
+            "CHILD_POLICY_APPLIED" => WorkflowExecutionCancelRequestedCause::ChildPolicyApplied,
+            _ => WorkflowExecutionCancelRequestedCause::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for WorkflowExecutionCancelRequestedCause {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<WorkflowExecutionCancelRequestedCause, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for WorkflowExecutionCancelRequestedCause {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for WorkflowExecutionCancelRequestedCause {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for WorkflowExecutionCancelRequestedCause {
+    fn deserialize<D>(deserializer: D) -> Result<WorkflowExecutionCancelRequestedCause, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Provides the details of the <code>WorkflowExecutionCancelRequested</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -2504,7 +5424,7 @@ pub struct WorkflowExecutionCancelRequestedEventAttributes {
     /// <p>If set, indicates that the request to cancel the workflow execution was automatically generated, and specifies the cause. This happens if the parent workflow execution times out or is terminated, and the child policy is set to cancel child executions.</p>
     #[serde(rename = "cause")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub cause: Option<String>,
+    pub cause: Option<WorkflowExecutionCancelRequestedCause>,
     /// <p>The ID of the <code>RequestCancelExternalWorkflowExecutionInitiated</code> event corresponding to the <code>RequestCancelExternalWorkflowExecution</code> decision to cancel this workflow execution.The source event with this ID can be found in the history of the source workflow execution. This information can be useful for diagnosing problems by tracing back the chain of events leading up to this event.</p>
     #[serde(rename = "externalInitiatedEventId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2547,7 +5467,7 @@ pub struct WorkflowExecutionCompletedEventAttributes {
 pub struct WorkflowExecutionConfiguration {
     /// <p><p>The policy to use for the child workflow executions if this workflow execution is terminated, by calling the <a>TerminateWorkflowExecution</a> action explicitly or due to an expired timeout.</p> <p>The supported child policies are:</p> <ul> <li> <p> <code>TERMINATE</code> – The child executions are terminated.</p> </li> <li> <p> <code>REQUEST_CANCEL</code> – A request to cancel is attempted for each child execution by recording a <code>WorkflowExecutionCancelRequested</code> event in its history. It is up to the decider to take appropriate actions when it receives an execution history with this event.</p> </li> <li> <p> <code>ABANDON</code> – No action is taken. The child executions continue to run.</p> </li> </ul></p>
     #[serde(rename = "childPolicy")]
-    pub child_policy: String,
+    pub child_policy: ChildPolicy,
     /// <p>The total duration for this workflow execution.</p> <p>The duration is specified in seconds, an integer greater than or equal to <code>0</code>. You can use <code>NONE</code> to specify unlimited duration.</p>
     #[serde(rename = "executionStartToCloseTimeout")]
     pub execution_start_to_close_timeout: String,
@@ -2573,7 +5493,7 @@ pub struct WorkflowExecutionConfiguration {
 pub struct WorkflowExecutionContinuedAsNewEventAttributes {
     /// <p><p>The policy to use for the child workflow executions of the new execution if it is terminated by calling the <a>TerminateWorkflowExecution</a> action explicitly or due to an expired timeout.</p> <p>The supported child policies are:</p> <ul> <li> <p> <code>TERMINATE</code> – The child executions are terminated.</p> </li> <li> <p> <code>REQUEST_CANCEL</code> – A request to cancel is attempted for each child execution by recording a <code>WorkflowExecutionCancelRequested</code> event in its history. It is up to the decider to take appropriate actions when it receives an execution history with this event.</p> </li> <li> <p> <code>ABANDON</code> – No action is taken. The child executions continue to run.</p> </li> </ul></p>
     #[serde(rename = "childPolicy")]
-    pub child_policy: String,
+    pub child_policy: ChildPolicy,
     /// <p>The ID of the <code>DecisionTaskCompleted</code> event corresponding to the decision task that resulted in the <code>ContinueAsNewWorkflowExecution</code> decision that started this execution. This information can be useful for diagnosing problems by tracing back the chain of events leading up to this event.</p>
     #[serde(rename = "decisionTaskCompletedEventId")]
     pub decision_task_completed_event_id: i64,
@@ -2637,8 +5557,13 @@ pub struct WorkflowExecutionDetail {
     pub execution_info: WorkflowExecutionInfo,
     /// <p>The time when the last activity task was scheduled for this workflow execution. You can use this information to determine if the workflow has not made progress for an unusually long period of time and might require a corrective action.</p>
     #[serde(rename = "latestActivityTaskTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub latest_activity_task_timestamp: Option<f64>,
+    pub latest_activity_task_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The latest executionContext provided by the decider for this workflow execution. A decider can provide an executionContext (a free-form string) when closing a decision task using <a>RespondDecisionTaskCompleted</a>.</p>
     #[serde(rename = "latestExecutionContext")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2685,24 +5610,34 @@ pub struct WorkflowExecutionInfo {
     /// <p><p>If the execution status is closed then this specifies how the execution was closed:</p> <ul> <li> <p> <code>COMPLETED</code> – the execution was successfully completed.</p> </li> <li> <p> <code>CANCELED</code> – the execution was canceled.Cancellation allows the implementation to gracefully clean up before the execution is closed.</p> </li> <li> <p> <code>TERMINATED</code> – the execution was force terminated.</p> </li> <li> <p> <code>FAILED</code> – the execution failed to complete.</p> </li> <li> <p> <code>TIMED<em>OUT</code> – the execution did not complete in the alloted time and was automatically timed out.</p> </li> <li> <p> <code>CONTINUED</em>AS_NEW</code> – the execution is logically continued. This means the current execution was completed and a new execution was started to carry on the workflow.</p> </li> </ul></p>
     #[serde(rename = "closeStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub close_status: Option<String>,
+    pub close_status: Option<CloseStatus>,
     /// <p>The time when the workflow execution was closed. Set only if the execution status is CLOSED.</p>
     #[serde(rename = "closeTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub close_timestamp: Option<f64>,
+    pub close_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The workflow execution this information is about.</p>
     #[serde(rename = "execution")]
     pub execution: WorkflowExecution,
     /// <p>The current status of the execution.</p>
     #[serde(rename = "executionStatus")]
-    pub execution_status: String,
+    pub execution_status: ExecutionStatus,
     /// <p>If this workflow execution is a child of another execution then contains the workflow execution that started this execution.</p>
     #[serde(rename = "parent")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parent: Option<WorkflowExecution>,
     /// <p>The time when the execution was started.</p>
     #[serde(rename = "startTimestamp")]
-    pub start_timestamp: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub start_timestamp: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The list of tags associated with the workflow execution. Tags can be used to identify and list workflow executions of interest through the visibility APIs. A workflow execution can have a maximum of 5 tags.</p>
     #[serde(rename = "tagList")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2774,7 +5709,7 @@ pub struct WorkflowExecutionSignaledEventAttributes {
 pub struct WorkflowExecutionStartedEventAttributes {
     /// <p><p>The policy to use for the child workflow executions if this workflow execution is terminated, by calling the <a>TerminateWorkflowExecution</a> action explicitly or due to an expired timeout.</p> <p>The supported child policies are:</p> <ul> <li> <p> <code>TERMINATE</code> – The child executions are terminated.</p> </li> <li> <p> <code>REQUEST_CANCEL</code> – A request to cancel is attempted for each child execution by recording a <code>WorkflowExecutionCancelRequested</code> event in its history. It is up to the decider to take appropriate actions when it receives an execution history with this event.</p> </li> <li> <p> <code>ABANDON</code> – No action is taken. The child executions continue to run.</p> </li> </ul></p>
     #[serde(rename = "childPolicy")]
-    pub child_policy: String,
+    pub child_policy: ChildPolicy,
     /// <p>If this workflow execution was started due to a <code>ContinueAsNewWorkflowExecution</code> decision, then it contains the <code>runId</code> of the previous workflow execution that was closed and continued as this execution.</p>
     #[serde(rename = "continuedExecutionRunId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2819,6 +5754,104 @@ pub struct WorkflowExecutionStartedEventAttributes {
     pub workflow_type: WorkflowType,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum WorkflowExecutionTerminatedCause {
+    /// `CHILD_POLICY_APPLIED`
+    ChildPolicyApplied,
+    /// `EVENT_LIMIT_EXCEEDED`
+    EventLimitExceeded,
+    /// `OPERATOR_INITIATED`
+    OperatorInitiated,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl WorkflowExecutionTerminatedCause {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            WorkflowExecutionTerminatedCause::ChildPolicyApplied => "CHILD_POLICY_APPLIED",
+            WorkflowExecutionTerminatedCause::EventLimitExceeded => "EVENT_LIMIT_EXCEEDED",
+            WorkflowExecutionTerminatedCause::OperatorInitiated => "OPERATOR_INITIATED",
+            WorkflowExecutionTerminatedCause::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for WorkflowExecutionTerminatedCause {
+    fn default() -> WorkflowExecutionTerminatedCause {
+        WorkflowExecutionTerminatedCause::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for WorkflowExecutionTerminatedCause {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for WorkflowExecutionTerminatedCause {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for WorkflowExecutionTerminatedCause {
+    fn from(value: &'a str) -> WorkflowExecutionTerminatedCause {
+        match value {
+            "CHILD_POLICY_APPLIED" => WorkflowExecutionTerminatedCause::ChildPolicyApplied,
+            "EVENT_LIMIT_EXCEEDED" => WorkflowExecutionTerminatedCause::EventLimitExceeded,
+            "OPERATOR_INITIATED" => WorkflowExecutionTerminatedCause::OperatorInitiated,
+            _ => WorkflowExecutionTerminatedCause::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for WorkflowExecutionTerminatedCause {
+    fn from(value: String) -> WorkflowExecutionTerminatedCause {
+        match value.as_str() {
+            "CHILD_POLICY_APPLIED" => WorkflowExecutionTerminatedCause::ChildPolicyApplied,
+            "EVENT_LIMIT_EXCEEDED" => WorkflowExecutionTerminatedCause::EventLimitExceeded,
+            "OPERATOR_INITIATED" => WorkflowExecutionTerminatedCause::OperatorInitiated,
+            _ => WorkflowExecutionTerminatedCause::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for WorkflowExecutionTerminatedCause {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<WorkflowExecutionTerminatedCause, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for WorkflowExecutionTerminatedCause {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for WorkflowExecutionTerminatedCause {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for WorkflowExecutionTerminatedCause {
+    fn deserialize<D>(deserializer: D) -> Result<WorkflowExecutionTerminatedCause, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Provides the details of the <code>WorkflowExecutionTerminated</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -2826,10 +5859,10 @@ pub struct WorkflowExecutionTerminatedEventAttributes {
     /// <p>If set, indicates that the workflow execution was automatically terminated, and specifies the cause. This happens if the parent workflow execution times out or is terminated and the child policy is set to terminate child executions.</p>
     #[serde(rename = "cause")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub cause: Option<String>,
+    pub cause: Option<WorkflowExecutionTerminatedCause>,
     /// <p><p>The policy used for the child workflow executions of this workflow execution.</p> <p>The supported child policies are:</p> <ul> <li> <p> <code>TERMINATE</code> – The child executions are terminated.</p> </li> <li> <p> <code>REQUEST_CANCEL</code> – A request to cancel is attempted for each child execution by recording a <code>WorkflowExecutionCancelRequested</code> event in its history. It is up to the decider to take appropriate actions when it receives an execution history with this event.</p> </li> <li> <p> <code>ABANDON</code> – No action is taken. The child executions continue to run.</p> </li> </ul></p>
     #[serde(rename = "childPolicy")]
-    pub child_policy: String,
+    pub child_policy: ChildPolicy,
     /// <p>The details provided for the termination.</p>
     #[serde(rename = "details")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2846,12 +5879,100 @@ pub struct WorkflowExecutionTerminatedEventAttributes {
 pub struct WorkflowExecutionTimedOutEventAttributes {
     /// <p><p>The policy used for the child workflow executions of this workflow execution.</p> <p>The supported child policies are:</p> <ul> <li> <p> <code>TERMINATE</code> – The child executions are terminated.</p> </li> <li> <p> <code>REQUEST_CANCEL</code> – A request to cancel is attempted for each child execution by recording a <code>WorkflowExecutionCancelRequested</code> event in its history. It is up to the decider to take appropriate actions when it receives an execution history with this event.</p> </li> <li> <p> <code>ABANDON</code> – No action is taken. The child executions continue to run.</p> </li> </ul></p>
     #[serde(rename = "childPolicy")]
-    pub child_policy: String,
+    pub child_policy: ChildPolicy,
     /// <p>The type of timeout that caused this event.</p>
     #[serde(rename = "timeoutType")]
-    pub timeout_type: String,
+    pub timeout_type: WorkflowExecutionTimeoutType,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum WorkflowExecutionTimeoutType {
+    /// `START_TO_CLOSE`
+    StartToClose,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl WorkflowExecutionTimeoutType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            WorkflowExecutionTimeoutType::StartToClose => "START_TO_CLOSE",
+            WorkflowExecutionTimeoutType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for WorkflowExecutionTimeoutType {
+    fn default() -> WorkflowExecutionTimeoutType {
+        WorkflowExecutionTimeoutType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for WorkflowExecutionTimeoutType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for WorkflowExecutionTimeoutType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for WorkflowExecutionTimeoutType {
+    fn from(value: &'a str) -> WorkflowExecutionTimeoutType {
+        match value {
+            "START_TO_CLOSE" => WorkflowExecutionTimeoutType::StartToClose,
+            _ => WorkflowExecutionTimeoutType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for WorkflowExecutionTimeoutType {
+    fn from(value: String) -> WorkflowExecutionTimeoutType {
+        match value.as_str() {
+            "START_TO_CLOSE" => WorkflowExecutionTimeoutType::StartToClose,
+            _ => WorkflowExecutionTimeoutType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for WorkflowExecutionTimeoutType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<WorkflowExecutionTimeoutType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for WorkflowExecutionTimeoutType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for WorkflowExecutionTimeoutType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for WorkflowExecutionTimeoutType {
+    fn deserialize<D>(deserializer: D) -> Result<WorkflowExecutionTimeoutType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents a workflow type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct WorkflowType {
@@ -2870,7 +5991,7 @@ pub struct WorkflowTypeConfiguration {
     /// <p><p> The default policy to use for the child workflow executions when a workflow execution of this type is terminated, by calling the <a>TerminateWorkflowExecution</a> action explicitly or due to an expired timeout. This default can be overridden when starting a workflow execution using the <a>StartWorkflowExecution</a> action or the <code>StartChildWorkflowExecution</code> <a>Decision</a>.</p> <p>The supported child policies are:</p> <ul> <li> <p> <code>TERMINATE</code> – The child executions are terminated.</p> </li> <li> <p> <code>REQUEST_CANCEL</code> – A request to cancel is attempted for each child execution by recording a <code>WorkflowExecutionCancelRequested</code> event in its history. It is up to the decider to take appropriate actions when it receives an execution history with this event.</p> </li> <li> <p> <code>ABANDON</code> – No action is taken. The child executions continue to run.</p> </li> </ul></p>
     #[serde(rename = "defaultChildPolicy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub default_child_policy: Option<String>,
+    pub default_child_policy: Option<ChildPolicy>,
     /// <p> The default maximum duration, specified when registering the workflow type, for executions of this workflow type. This default can be overridden when starting a workflow execution using the <a>StartWorkflowExecution</a> action or the <code>StartChildWorkflowExecution</code> <a>Decision</a>.</p> <p>The duration is specified in seconds, an integer greater than or equal to <code>0</code>. You can use <code>NONE</code> to specify unlimited duration.</p>
     #[serde(rename = "defaultExecutionStartToCloseTimeout")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2924,18 +6045,28 @@ pub struct WorkflowTypeFilter {
 pub struct WorkflowTypeInfo {
     /// <p>The date when this type was registered.</p>
     #[serde(rename = "creationDate")]
-    pub creation_date: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub creation_date: ::chrono::DateTime<::chrono::Utc>,
     /// <p>If the type is in deprecated state, then it is set to the date when the type was deprecated.</p>
     #[serde(rename = "deprecationDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deprecation_date: Option<f64>,
+    pub deprecation_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The description of the type registered through <a>RegisterWorkflowType</a>.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     /// <p>The current status of the workflow type.</p>
     #[serde(rename = "status")]
-    pub status: String,
+    pub status: RegistrationStatus,
     /// <p>The workflow type this information is about.</p>
     #[serde(rename = "workflowType")]
     pub workflow_type: WorkflowType,
@@ -4935,11 +8066,12 @@ pub struct SwfClient {
 impl SwfClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> SwfClient {
         SwfClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "SWF"),
         }
     }
 
@@ -4971,6 +8103,7 @@ impl Swf for SwfClient {
         input: CountClosedWorkflowExecutionsInput,
     ) -> Result<WorkflowExecutionCount, RusotoError<CountClosedWorkflowExecutionsError>> {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("CountClosedWorkflowExecutions");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -5001,6 +8134,7 @@ impl Swf for SwfClient {
         input: CountOpenWorkflowExecutionsInput,
     ) -> Result<WorkflowExecutionCount, RusotoError<CountOpenWorkflowExecutionsError>> {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("CountOpenWorkflowExecutions");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -5031,6 +8165,7 @@ impl Swf for SwfClient {
         input: CountPendingActivityTasksInput,
     ) -> Result<PendingTaskCount, RusotoError<CountPendingActivityTasksError>> {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("CountPendingActivityTasks");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -5061,6 +8196,7 @@ impl Swf for SwfClient {
         input: CountPendingDecisionTasksInput,
     ) -> Result<PendingTaskCount, RusotoError<CountPendingDecisionTasksError>> {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("CountPendingDecisionTasks");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -5091,6 +8227,7 @@ impl Swf for SwfClient {
         input: DeprecateActivityTypeInput,
     ) -> Result<(), RusotoError<DeprecateActivityTypeError>> {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("DeprecateActivityType");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -5121,6 +8258,7 @@ impl Swf for SwfClient {
         input: DeprecateDomainInput,
     ) -> Result<(), RusotoError<DeprecateDomainError>> {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("DeprecateDomain");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "SimpleWorkflowService.DeprecateDomain");
@@ -5148,6 +8286,7 @@ impl Swf for SwfClient {
         input: DeprecateWorkflowTypeInput,
     ) -> Result<(), RusotoError<DeprecateWorkflowTypeError>> {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("DeprecateWorkflowType");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -5178,6 +8317,7 @@ impl Swf for SwfClient {
         input: DescribeActivityTypeInput,
     ) -> Result<ActivityTypeDetail, RusotoError<DescribeActivityTypeError>> {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("DescribeActivityType");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "SimpleWorkflowService.DescribeActivityType");
@@ -5205,6 +8345,7 @@ impl Swf for SwfClient {
         input: DescribeDomainInput,
     ) -> Result<DomainDetail, RusotoError<DescribeDomainError>> {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("DescribeDomain");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "SimpleWorkflowService.DescribeDomain");
@@ -5232,6 +8373,7 @@ impl Swf for SwfClient {
         input: DescribeWorkflowExecutionInput,
     ) -> Result<WorkflowExecutionDetail, RusotoError<DescribeWorkflowExecutionError>> {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("DescribeWorkflowExecution");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -5262,6 +8404,7 @@ impl Swf for SwfClient {
         input: DescribeWorkflowTypeInput,
     ) -> Result<WorkflowTypeDetail, RusotoError<DescribeWorkflowTypeError>> {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("DescribeWorkflowType");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "SimpleWorkflowService.DescribeWorkflowType");
@@ -5289,6 +8432,7 @@ impl Swf for SwfClient {
         input: GetWorkflowExecutionHistoryInput,
     ) -> Result<History, RusotoError<GetWorkflowExecutionHistoryError>> {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("GetWorkflowExecutionHistory");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -5319,6 +8463,7 @@ impl Swf for SwfClient {
         input: ListActivityTypesInput,
     ) -> Result<ActivityTypeInfos, RusotoError<ListActivityTypesError>> {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("ListActivityTypes");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "SimpleWorkflowService.ListActivityTypes");
@@ -5346,6 +8491,7 @@ impl Swf for SwfClient {
         input: ListClosedWorkflowExecutionsInput,
     ) -> Result<WorkflowExecutionInfos, RusotoError<ListClosedWorkflowExecutionsError>> {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("ListClosedWorkflowExecutions");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -5376,6 +8522,7 @@ impl Swf for SwfClient {
         input: ListDomainsInput,
     ) -> Result<DomainInfos, RusotoError<ListDomainsError>> {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("ListDomains");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "SimpleWorkflowService.ListDomains");
@@ -5403,6 +8550,7 @@ impl Swf for SwfClient {
         input: ListOpenWorkflowExecutionsInput,
     ) -> Result<WorkflowExecutionInfos, RusotoError<ListOpenWorkflowExecutionsError>> {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("ListOpenWorkflowExecutions");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -5433,6 +8581,7 @@ impl Swf for SwfClient {
         input: ListTagsForResourceInput,
     ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "SimpleWorkflowService.ListTagsForResource");
@@ -5461,6 +8610,7 @@ impl Swf for SwfClient {
         input: ListWorkflowTypesInput,
     ) -> Result<WorkflowTypeInfos, RusotoError<ListWorkflowTypesError>> {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("ListWorkflowTypes");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "SimpleWorkflowService.ListWorkflowTypes");
@@ -5488,6 +8638,7 @@ impl Swf for SwfClient {
         input: PollForActivityTaskInput,
     ) -> Result<ActivityTask, RusotoError<PollForActivityTaskError>> {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("PollForActivityTask");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "SimpleWorkflowService.PollForActivityTask");
@@ -5515,6 +8666,7 @@ impl Swf for SwfClient {
         input: PollForDecisionTaskInput,
     ) -> Result<DecisionTask, RusotoError<PollForDecisionTaskError>> {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("PollForDecisionTask");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "SimpleWorkflowService.PollForDecisionTask");
@@ -5542,6 +8694,7 @@ impl Swf for SwfClient {
         input: RecordActivityTaskHeartbeatInput,
     ) -> Result<ActivityTaskStatus, RusotoError<RecordActivityTaskHeartbeatError>> {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("RecordActivityTaskHeartbeat");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -5572,6 +8725,7 @@ impl Swf for SwfClient {
         input: RegisterActivityTypeInput,
     ) -> Result<(), RusotoError<RegisterActivityTypeError>> {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("RegisterActivityType");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "SimpleWorkflowService.RegisterActivityType");
@@ -5599,6 +8753,7 @@ impl Swf for SwfClient {
         input: RegisterDomainInput,
     ) -> Result<(), RusotoError<RegisterDomainError>> {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("RegisterDomain");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "SimpleWorkflowService.RegisterDomain");
@@ -5626,6 +8781,7 @@ impl Swf for SwfClient {
         input: RegisterWorkflowTypeInput,
     ) -> Result<(), RusotoError<RegisterWorkflowTypeError>> {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("RegisterWorkflowType");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "SimpleWorkflowService.RegisterWorkflowType");
@@ -5653,6 +8809,7 @@ impl Swf for SwfClient {
         input: RequestCancelWorkflowExecutionInput,
     ) -> Result<(), RusotoError<RequestCancelWorkflowExecutionError>> {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("RequestCancelWorkflowExecution");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -5683,6 +8840,7 @@ impl Swf for SwfClient {
         input: RespondActivityTaskCanceledInput,
     ) -> Result<(), RusotoError<RespondActivityTaskCanceledError>> {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("RespondActivityTaskCanceled");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -5713,6 +8871,7 @@ impl Swf for SwfClient {
         input: RespondActivityTaskCompletedInput,
     ) -> Result<(), RusotoError<RespondActivityTaskCompletedError>> {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("RespondActivityTaskCompleted");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -5743,6 +8902,7 @@ impl Swf for SwfClient {
         input: RespondActivityTaskFailedInput,
     ) -> Result<(), RusotoError<RespondActivityTaskFailedError>> {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("RespondActivityTaskFailed");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -5773,6 +8933,7 @@ impl Swf for SwfClient {
         input: RespondDecisionTaskCompletedInput,
     ) -> Result<(), RusotoError<RespondDecisionTaskCompletedError>> {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("RespondDecisionTaskCompleted");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -5803,6 +8964,7 @@ impl Swf for SwfClient {
         input: SignalWorkflowExecutionInput,
     ) -> Result<(), RusotoError<SignalWorkflowExecutionError>> {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("SignalWorkflowExecution");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -5833,6 +8995,7 @@ impl Swf for SwfClient {
         input: StartWorkflowExecutionInput,
     ) -> Result<Run, RusotoError<StartWorkflowExecutionError>> {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("StartWorkflowExecution");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -5863,6 +9026,7 @@ impl Swf for SwfClient {
         input: TagResourceInput,
     ) -> Result<(), RusotoError<TagResourceError>> {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "SimpleWorkflowService.TagResource");
@@ -5890,6 +9054,7 @@ impl Swf for SwfClient {
         input: TerminateWorkflowExecutionInput,
     ) -> Result<(), RusotoError<TerminateWorkflowExecutionError>> {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("TerminateWorkflowExecution");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -5920,6 +9085,7 @@ impl Swf for SwfClient {
         input: UndeprecateActivityTypeInput,
     ) -> Result<(), RusotoError<UndeprecateActivityTypeError>> {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("UndeprecateActivityType");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -5950,6 +9116,7 @@ impl Swf for SwfClient {
         input: UndeprecateDomainInput,
     ) -> Result<(), RusotoError<UndeprecateDomainError>> {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("UndeprecateDomain");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "SimpleWorkflowService.UndeprecateDomain");
@@ -5977,6 +9144,7 @@ impl Swf for SwfClient {
         input: UndeprecateWorkflowTypeInput,
     ) -> Result<(), RusotoError<UndeprecateWorkflowTypeError>> {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("UndeprecateWorkflowType");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -6007,6 +9175,7 @@ impl Swf for SwfClient {
         input: UntagResourceInput,
     ) -> Result<(), RusotoError<UntagResourceError>> {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "SimpleWorkflowService.UntagResource");
@@ -6028,3 +9197,229 @@ impl Swf for SwfClient {
         }
     }
 }
+impl SwfClient {
+    /// Auto-paginating version of `get_workflow_execution_history`, streaming every page of results.
+    pub fn get_workflow_execution_history_pages(
+        &self,
+        input: GetWorkflowExecutionHistoryInput,
+    ) -> rusoto_core::pagination::RusotoStream<History, GetWorkflowExecutionHistoryError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SwfClient, input: GetWorkflowExecutionHistoryInput| async move {
+                client.get_workflow_execution_history(input).await
+            },
+            |input: &GetWorkflowExecutionHistoryInput, output: &History| {
+                Some(GetWorkflowExecutionHistoryInput {
+                    next_page_token: Some(output.next_page_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_workflow_execution_history`, streaming the `events` of every page of results.
+    pub fn get_workflow_execution_history_items(
+        &self,
+        input: GetWorkflowExecutionHistoryInput,
+    ) -> rusoto_core::pagination::RusotoStream<HistoryEvent, GetWorkflowExecutionHistoryError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_workflow_execution_history_pages(input),
+            |output: History| output.events,
+        )
+    }
+
+    /// Auto-paginating version of `list_activity_types`, streaming every page of results.
+    pub fn list_activity_types_pages(
+        &self,
+        input: ListActivityTypesInput,
+    ) -> rusoto_core::pagination::RusotoStream<ActivityTypeInfos, ListActivityTypesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SwfClient, input: ListActivityTypesInput| async move {
+                client.list_activity_types(input).await
+            },
+            |input: &ListActivityTypesInput, output: &ActivityTypeInfos| {
+                Some(ListActivityTypesInput {
+                    next_page_token: Some(output.next_page_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_activity_types`, streaming the `typeInfos` of every page of results.
+    pub fn list_activity_types_items(
+        &self,
+        input: ListActivityTypesInput,
+    ) -> rusoto_core::pagination::RusotoStream<ActivityTypeInfo, ListActivityTypesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_activity_types_pages(input),
+            |output: ActivityTypeInfos| output.type_infos,
+        )
+    }
+
+    /// Auto-paginating version of `list_closed_workflow_executions`, streaming every page of results.
+    pub fn list_closed_workflow_executions_pages(
+        &self,
+        input: ListClosedWorkflowExecutionsInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        WorkflowExecutionInfos,
+        ListClosedWorkflowExecutionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SwfClient, input: ListClosedWorkflowExecutionsInput| async move {
+                client.list_closed_workflow_executions(input).await
+            },
+            |input: &ListClosedWorkflowExecutionsInput, output: &WorkflowExecutionInfos| {
+                Some(ListClosedWorkflowExecutionsInput {
+                    next_page_token: Some(output.next_page_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_closed_workflow_executions`, streaming the `executionInfos` of every page of results.
+    pub fn list_closed_workflow_executions_items(
+        &self,
+        input: ListClosedWorkflowExecutionsInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        WorkflowExecutionInfo,
+        ListClosedWorkflowExecutionsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_closed_workflow_executions_pages(input),
+            |output: WorkflowExecutionInfos| output.execution_infos,
+        )
+    }
+
+    /// Auto-paginating version of `list_domains`, streaming every page of results.
+    pub fn list_domains_pages(
+        &self,
+        input: ListDomainsInput,
+    ) -> rusoto_core::pagination::RusotoStream<DomainInfos, ListDomainsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SwfClient, input: ListDomainsInput| async move { client.list_domains(input).await },
+            |input: &ListDomainsInput, output: &DomainInfos| {
+                Some(ListDomainsInput {
+                    next_page_token: Some(output.next_page_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_domains`, streaming the `domainInfos` of every page of results.
+    pub fn list_domains_items(
+        &self,
+        input: ListDomainsInput,
+    ) -> rusoto_core::pagination::RusotoStream<DomainInfo, ListDomainsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_domains_pages(input),
+            |output: DomainInfos| output.domain_infos,
+        )
+    }
+
+    /// Auto-paginating version of `list_open_workflow_executions`, streaming every page of results.
+    pub fn list_open_workflow_executions_pages(
+        &self,
+        input: ListOpenWorkflowExecutionsInput,
+    ) -> rusoto_core::pagination::RusotoStream<
+        WorkflowExecutionInfos,
+        ListOpenWorkflowExecutionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SwfClient, input: ListOpenWorkflowExecutionsInput| async move {
+                client.list_open_workflow_executions(input).await
+            },
+            |input: &ListOpenWorkflowExecutionsInput, output: &WorkflowExecutionInfos| {
+                Some(ListOpenWorkflowExecutionsInput {
+                    next_page_token: Some(output.next_page_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_open_workflow_executions`, streaming the `executionInfos` of every page of results.
+    pub fn list_open_workflow_executions_items(
+        &self,
+        input: ListOpenWorkflowExecutionsInput,
+    ) -> rusoto_core::pagination::RusotoStream<WorkflowExecutionInfo, ListOpenWorkflowExecutionsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_open_workflow_executions_pages(input),
+            |output: WorkflowExecutionInfos| output.execution_infos,
+        )
+    }
+
+    /// Auto-paginating version of `list_workflow_types`, streaming every page of results.
+    pub fn list_workflow_types_pages(
+        &self,
+        input: ListWorkflowTypesInput,
+    ) -> rusoto_core::pagination::RusotoStream<WorkflowTypeInfos, ListWorkflowTypesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SwfClient, input: ListWorkflowTypesInput| async move {
+                client.list_workflow_types(input).await
+            },
+            |input: &ListWorkflowTypesInput, output: &WorkflowTypeInfos| {
+                Some(ListWorkflowTypesInput {
+                    next_page_token: Some(output.next_page_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_workflow_types`, streaming the `typeInfos` of every page of results.
+    pub fn list_workflow_types_items(
+        &self,
+        input: ListWorkflowTypesInput,
+    ) -> rusoto_core::pagination::RusotoStream<WorkflowTypeInfo, ListWorkflowTypesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_workflow_types_pages(input),
+            |output: WorkflowTypeInfos| output.type_infos,
+        )
+    }
+
+    /// Auto-paginating version of `poll_for_decision_task`, streaming every page of results.
+    pub fn poll_for_decision_task_pages(
+        &self,
+        input: PollForDecisionTaskInput,
+    ) -> rusoto_core::pagination::RusotoStream<DecisionTask, PollForDecisionTaskError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SwfClient, input: PollForDecisionTaskInput| async move {
+                client.poll_for_decision_task(input).await
+            },
+            |input: &PollForDecisionTaskInput, output: &DecisionTask| {
+                Some(PollForDecisionTaskInput {
+                    next_page_token: Some(output.next_page_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `poll_for_decision_task`, streaming the `events` of every page of results.
+    pub fn poll_for_decision_task_items(
+        &self,
+        input: PollForDecisionTaskInput,
+    ) -> rusoto_core::pagination::RusotoStream<HistoryEvent, PollForDecisionTaskError> {
+        rusoto_core::pagination::paginate_items(
+            self.poll_for_decision_task_pages(input),
+            |output: DecisionTask| output.events,
+        )
+    }
+}
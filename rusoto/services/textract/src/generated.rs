@@ -32,7 +32,7 @@ pub struct AnalyzeDocumentRequest {
     pub document: Document,
     /// <p>A list of the types of analysis to perform. Add TABLES to the list to return information about the tables that are detected in the input document. Add FORMS to return detected form data. To perform both types of analysis, add TABLES and FORMS to <code>FeatureTypes</code>. All lines and words detected in the document are included in the response (including text that isn't related to the value of <code>FeatureTypes</code>). </p>
     #[serde(rename = "FeatureTypes")]
-    pub feature_types: Vec<String>,
+    pub feature_types: Vec<FeatureType>,
     /// <p>Sets the configuration for the human in the loop workflow for analyzing documents.</p>
     #[serde(rename = "HumanLoopConfig")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -67,7 +67,7 @@ pub struct Block {
     /// <p><p>The type of text item that&#39;s recognized. In operations for text detection, the following types are returned:</p> <ul> <li> <p> <i>PAGE</i> - Contains a list of the LINE <code>Block</code> objects that are detected on a document page.</p> </li> <li> <p> <i>WORD</i> - A word detected on a document page. A word is one or more ISO basic Latin script characters that aren&#39;t separated by spaces.</p> </li> <li> <p> <i>LINE</i> - A string of tab-delimited, contiguous words that are detected on a document page.</p> </li> </ul> <p>In text analysis operations, the following types are returned:</p> <ul> <li> <p> <i>PAGE</i> - Contains a list of child <code>Block</code> objects that are detected on a document page.</p> </li> <li> <p> <i>KEY<em>VALUE</em>SET</i> - Stores the KEY and VALUE <code>Block</code> objects for linked text that&#39;s detected on a document page. Use the <code>EntityType</code> field to determine if a KEY<em>VALUE</em>SET object is a KEY <code>Block</code> object or a VALUE <code>Block</code> object. </p> </li> <li> <p> <i>WORD</i> - A word that&#39;s detected on a document page. A word is one or more ISO basic Latin script characters that aren&#39;t separated by spaces.</p> </li> <li> <p> <i>LINE</i> - A string of tab-delimited, contiguous words that are detected on a document page.</p> </li> <li> <p> <i>TABLE</i> - A table that&#39;s detected on a document page. A table is grid-based information with two or more rows or columns, with a cell span of one row and one column each. </p> </li> <li> <p> <i>CELL</i> - A cell within a detected table. The cell is the parent of the block that contains the text in the cell.</p> </li> <li> <p> <i>SELECTION_ELEMENT</i> - A selection element such as an option button (radio button) or a check box that&#39;s detected on a document page. Use the value of <code>SelectionStatus</code> to determine the status of the selection element.</p> </li> </ul></p>
     #[serde(rename = "BlockType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub block_type: Option<String>,
+    pub block_type: Option<BlockType>,
     /// <p>The column in which a table cell appears. The first column position is 1. <code>ColumnIndex</code> isn't returned by <code>DetectDocumentText</code> and <code>GetDocumentTextDetection</code>.</p>
     #[serde(rename = "ColumnIndex")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -83,7 +83,7 @@ pub struct Block {
     /// <p>The type of entity. The following can be returned:</p> <ul> <li> <p> <i>KEY</i> - An identifier for a field on the document.</p> </li> <li> <p> <i>VALUE</i> - The field text.</p> </li> </ul> <p> <code>EntityTypes</code> isn't returned by <code>DetectDocumentText</code> and <code>GetDocumentTextDetection</code>.</p>
     #[serde(rename = "EntityTypes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub entity_types: Option<Vec<String>>,
+    pub entity_types: Option<Vec<EntityType>>,
     /// <p>The location of the recognized text on the image. It includes an axis-aligned, coarse bounding box that surrounds the text, and a finer-grain polygon for more accurate spatial information. </p>
     #[serde(rename = "Geometry")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -111,13 +111,216 @@ pub struct Block {
     /// <p>The selection status of a selection element, such as an option button or check box. </p>
     #[serde(rename = "SelectionStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub selection_status: Option<String>,
+    pub selection_status: Option<SelectionStatus>,
     /// <p>The word or line of text that's recognized by Amazon Textract. </p>
     #[serde(rename = "Text")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BlockType {
+    /// `KEY_VALUE_SET`
+    KeyValueSet,
+    /// `PAGE`
+    Page,
+    /// `LINE`
+    Line,
+    /// `WORD`
+    Word,
+    /// `TABLE`
+    Table,
+    /// `CELL`
+    Cell,
+    /// `SELECTION_ELEMENT`
+    SelectionElement,
+    /// `MERGED_CELL`
+    MergedCell,
+    /// `TITLE`
+    Title,
+    /// `QUERY`
+    Query,
+    /// `QUERY_RESULT`
+    QueryResult,
+    /// `SIGNATURE`
+    Signature,
+    /// `TABLE_TITLE`
+    TableTitle,
+    /// `TABLE_FOOTER`
+    TableFooter,
+    /// `LAYOUT_TEXT`
+    LayoutText,
+    /// `LAYOUT_TITLE`
+    LayoutTitle,
+    /// `LAYOUT_HEADER`
+    LayoutHeader,
+    /// `LAYOUT_FOOTER`
+    LayoutFooter,
+    /// `LAYOUT_SECTION_HEADER`
+    LayoutSectionHeader,
+    /// `LAYOUT_PAGE_NUMBER`
+    LayoutPageNumber,
+    /// `LAYOUT_LIST`
+    LayoutList,
+    /// `LAYOUT_FIGURE`
+    LayoutFigure,
+    /// `LAYOUT_TABLE`
+    LayoutTable,
+    /// `LAYOUT_KEY_VALUE`
+    LayoutKeyValue,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl BlockType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BlockType::KeyValueSet => "KEY_VALUE_SET",
+            BlockType::Page => "PAGE",
+            BlockType::Line => "LINE",
+            BlockType::Word => "WORD",
+            BlockType::Table => "TABLE",
+            BlockType::Cell => "CELL",
+            BlockType::SelectionElement => "SELECTION_ELEMENT",
+            BlockType::MergedCell => "MERGED_CELL",
+            BlockType::Title => "TITLE",
+            BlockType::Query => "QUERY",
+            BlockType::QueryResult => "QUERY_RESULT",
+            BlockType::Signature => "SIGNATURE",
+            BlockType::TableTitle => "TABLE_TITLE",
+            BlockType::TableFooter => "TABLE_FOOTER",
+            BlockType::LayoutText => "LAYOUT_TEXT",
+            BlockType::LayoutTitle => "LAYOUT_TITLE",
+            BlockType::LayoutHeader => "LAYOUT_HEADER",
+            BlockType::LayoutFooter => "LAYOUT_FOOTER",
+            BlockType::LayoutSectionHeader => "LAYOUT_SECTION_HEADER",
+            BlockType::LayoutPageNumber => "LAYOUT_PAGE_NUMBER",
+            BlockType::LayoutList => "LAYOUT_LIST",
+            BlockType::LayoutFigure => "LAYOUT_FIGURE",
+            BlockType::LayoutTable => "LAYOUT_TABLE",
+            BlockType::LayoutKeyValue => "LAYOUT_KEY_VALUE",
+            BlockType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for BlockType {
+    fn default() -> BlockType {
+        BlockType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for BlockType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for BlockType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for BlockType {
+    fn from(value: &'a str) -> BlockType {
+        match value {
+            "KEY_VALUE_SET" => BlockType::KeyValueSet,
+            "PAGE" => BlockType::Page,
+            "LINE" => BlockType::Line,
+            "WORD" => BlockType::Word,
+            "TABLE" => BlockType::Table,
+            "CELL" => BlockType::Cell,
+            "SELECTION_ELEMENT" => BlockType::SelectionElement,
+            "MERGED_CELL" => BlockType::MergedCell,
+            "TITLE" => BlockType::Title,
+            "QUERY" => BlockType::Query,
+            "QUERY_RESULT" => BlockType::QueryResult,
+            "SIGNATURE" => BlockType::Signature,
+            "TABLE_TITLE" => BlockType::TableTitle,
+            "TABLE_FOOTER" => BlockType::TableFooter,
+            "LAYOUT_TEXT" => BlockType::LayoutText,
+            "LAYOUT_TITLE" => BlockType::LayoutTitle,
+            "LAYOUT_HEADER" => BlockType::LayoutHeader,
+            "LAYOUT_FOOTER" => BlockType::LayoutFooter,
+            "LAYOUT_SECTION_HEADER" => BlockType::LayoutSectionHeader,
+            "LAYOUT_PAGE_NUMBER" => BlockType::LayoutPageNumber,
+            "LAYOUT_LIST" => BlockType::LayoutList,
+            "LAYOUT_FIGURE" => BlockType::LayoutFigure,
+            "LAYOUT_TABLE" => BlockType::LayoutTable,
+            "LAYOUT_KEY_VALUE" => BlockType::LayoutKeyValue,
+            _ => BlockType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BlockType {
+    fn from(value: String) -> BlockType {
+        match value.as_str() {
+            "KEY_VALUE_SET" => BlockType::KeyValueSet,
+            "PAGE" => BlockType::Page,
+            "LINE" => BlockType::Line,
+            "WORD" => BlockType::Word,
+            "TABLE" => BlockType::Table,
+            "CELL" => BlockType::Cell,
+            "SELECTION_ELEMENT" => BlockType::SelectionElement,
+            "MERGED_CELL" => BlockType::MergedCell,
+            "TITLE" => BlockType::Title,
+            "QUERY" => BlockType::Query,
+            "QUERY_RESULT" => BlockType::QueryResult,
+            "SIGNATURE" => BlockType::Signature,
+            "TABLE_TITLE" => BlockType::TableTitle,
+            "TABLE_FOOTER" => BlockType::TableFooter,
+            "LAYOUT_TEXT" => BlockType::LayoutText,
+            "LAYOUT_TITLE" => BlockType::LayoutTitle,
+            "LAYOUT_HEADER" => BlockType::LayoutHeader,
+            "LAYOUT_FOOTER" => BlockType::LayoutFooter,
+            "LAYOUT_SECTION_HEADER" => BlockType::LayoutSectionHeader,
+            "LAYOUT_PAGE_NUMBER" => BlockType::LayoutPageNumber,
+            "LAYOUT_LIST" => BlockType::LayoutList,
+            "LAYOUT_FIGURE" => BlockType::LayoutFigure,
+            "LAYOUT_TABLE" => BlockType::LayoutTable,
+            "LAYOUT_KEY_VALUE" => BlockType::LayoutKeyValue,
+            _ => BlockType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for BlockType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<BlockType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for BlockType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for BlockType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BlockType {
+    fn deserialize<D>(deserializer: D) -> Result<BlockType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The bounding box around the detected page, text, key-value pair, table, table cell, or selection element on a document page. The <code>left</code> (x-coordinate) and <code>top</code> (y-coordinate) are coordinates that represent the top and left sides of the bounding box. Note that the upper-left corner of the image is the origin (0,0). </p> <p>The <code>top</code> and <code>left</code> values returned are ratios of the overall document page size. For example, if the input image is 700 x 200 pixels, and the top-left coordinate of the bounding box is 350 x 50 pixels, the API returns a <code>left</code> value of 0.5 (350/700) and a <code>top</code> value of 0.25 (50/200).</p> <p>The <code>width</code> and <code>height</code> values represent the dimensions of the bounding box as a ratio of the overall document page dimension. For example, if the document page size is 700 x 200 pixels, and the bounding box width is 70 pixels, the width returned is 0.1. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -140,6 +343,105 @@ pub struct BoundingBox {
     pub width: Option<f32>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ContentClassifier {
+    /// `FreeOfPersonallyIdentifiableInformation`
+    FreeOfPersonallyIdentifiableInformation,
+    /// `FreeOfAdultContent`
+    FreeOfAdultContent,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ContentClassifier {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ContentClassifier::FreeOfPersonallyIdentifiableInformation => {
+                "FreeOfPersonallyIdentifiableInformation"
+            }
+            ContentClassifier::FreeOfAdultContent => "FreeOfAdultContent",
+            ContentClassifier::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ContentClassifier {
+    fn default() -> ContentClassifier {
+        ContentClassifier::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ContentClassifier {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ContentClassifier {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ContentClassifier {
+    fn from(value: &'a str) -> ContentClassifier {
+        match value {
+            "FreeOfPersonallyIdentifiableInformation" => {
+                ContentClassifier::FreeOfPersonallyIdentifiableInformation
+            }
+            "FreeOfAdultContent" => ContentClassifier::FreeOfAdultContent,
+            _ => ContentClassifier::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ContentClassifier {
+    fn from(value: String) -> ContentClassifier {
+        match value.as_str() {
+            "FreeOfPersonallyIdentifiableInformation" => {
+                ContentClassifier::FreeOfPersonallyIdentifiableInformation
+            }
+            "FreeOfAdultContent" => ContentClassifier::FreeOfAdultContent,
+            _ => ContentClassifier::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ContentClassifier {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ContentClassifier, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ContentClassifier {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ContentClassifier {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ContentClassifier {
+    fn deserialize<D>(deserializer: D) -> Result<ContentClassifier, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct DetectDocumentTextRequest {
@@ -204,6 +506,242 @@ pub struct DocumentMetadata {
     pub pages: Option<i64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EntityType {
+    /// `KEY`
+    Key,
+    /// `VALUE`
+    Value,
+    /// `COLUMN_HEADER`
+    ColumnHeader,
+    /// `TABLE_TITLE`
+    TableTitle,
+    /// `TABLE_FOOTER`
+    TableFooter,
+    /// `TABLE_SECTION_TITLE`
+    TableSectionTitle,
+    /// `TABLE_SUMMARY`
+    TableSummary,
+    /// `STRUCTURED_TABLE`
+    StructuredTable,
+    /// `SEMI_STRUCTURED_TABLE`
+    SemiStructuredTable,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EntityType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EntityType::Key => "KEY",
+            EntityType::Value => "VALUE",
+            EntityType::ColumnHeader => "COLUMN_HEADER",
+            EntityType::TableTitle => "TABLE_TITLE",
+            EntityType::TableFooter => "TABLE_FOOTER",
+            EntityType::TableSectionTitle => "TABLE_SECTION_TITLE",
+            EntityType::TableSummary => "TABLE_SUMMARY",
+            EntityType::StructuredTable => "STRUCTURED_TABLE",
+            EntityType::SemiStructuredTable => "SEMI_STRUCTURED_TABLE",
+            EntityType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EntityType {
+    fn default() -> EntityType {
+        EntityType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EntityType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EntityType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EntityType {
+    fn from(value: &'a str) -> EntityType {
+        match value {
+            "KEY" => EntityType::Key,
+            "VALUE" => EntityType::Value,
+            "COLUMN_HEADER" => EntityType::ColumnHeader,
+            "TABLE_TITLE" => EntityType::TableTitle,
+            "TABLE_FOOTER" => EntityType::TableFooter,
+            "TABLE_SECTION_TITLE" => EntityType::TableSectionTitle,
+            "TABLE_SUMMARY" => EntityType::TableSummary,
+            "STRUCTURED_TABLE" => EntityType::StructuredTable,
+            "SEMI_STRUCTURED_TABLE" => EntityType::SemiStructuredTable,
+            _ => EntityType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EntityType {
+    fn from(value: String) -> EntityType {
+        match value.as_str() {
+            "KEY" => EntityType::Key,
+            "VALUE" => EntityType::Value,
+            "COLUMN_HEADER" => EntityType::ColumnHeader,
+            "TABLE_TITLE" => EntityType::TableTitle,
+            "TABLE_FOOTER" => EntityType::TableFooter,
+            "TABLE_SECTION_TITLE" => EntityType::TableSectionTitle,
+            "TABLE_SUMMARY" => EntityType::TableSummary,
+            "STRUCTURED_TABLE" => EntityType::StructuredTable,
+            "SEMI_STRUCTURED_TABLE" => EntityType::SemiStructuredTable,
+            _ => EntityType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EntityType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EntityType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EntityType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EntityType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EntityType {
+    fn deserialize<D>(deserializer: D) -> Result<EntityType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FeatureType {
+    /// `TABLES`
+    Tables,
+    /// `FORMS`
+    Forms,
+    /// `QUERIES`
+    Queries,
+    /// `SIGNATURES`
+    Signatures,
+    /// `LAYOUT`
+    Layout,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl FeatureType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FeatureType::Tables => "TABLES",
+            FeatureType::Forms => "FORMS",
+            FeatureType::Queries => "QUERIES",
+            FeatureType::Signatures => "SIGNATURES",
+            FeatureType::Layout => "LAYOUT",
+            FeatureType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for FeatureType {
+    fn default() -> FeatureType {
+        FeatureType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for FeatureType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FeatureType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FeatureType {
+    fn from(value: &'a str) -> FeatureType {
+        match value {
+            "TABLES" => FeatureType::Tables,
+            "FORMS" => FeatureType::Forms,
+            "QUERIES" => FeatureType::Queries,
+            "SIGNATURES" => FeatureType::Signatures,
+            "LAYOUT" => FeatureType::Layout,
+            _ => FeatureType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FeatureType {
+    fn from(value: String) -> FeatureType {
+        match value.as_str() {
+            "TABLES" => FeatureType::Tables,
+            "FORMS" => FeatureType::Forms,
+            "QUERIES" => FeatureType::Queries,
+            "SIGNATURES" => FeatureType::Signatures,
+            "LAYOUT" => FeatureType::Layout,
+            _ => FeatureType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for FeatureType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<FeatureType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FeatureType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FeatureType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FeatureType {
+    fn deserialize<D>(deserializer: D) -> Result<FeatureType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Information about where the following items are located on a document page: detected page, text, key-value pairs, tables, table cells, and selection elements.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -252,7 +790,7 @@ pub struct GetDocumentAnalysisResponse {
     /// <p>The current status of the text detection job.</p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<JobStatus>,
     /// <p>If the response is truncated, Amazon Textract returns this token. You can use this token in the subsequent request to retrieve the next set of text detection results.</p>
     #[serde(rename = "NextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -301,7 +839,7 @@ pub struct GetDocumentTextDetectionResponse {
     /// <p>The current status of the text detection job.</p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<JobStatus>,
     /// <p>If the response is truncated, Amazon Textract returns this token. You can use this token in the subsequent request to retrieve the next set of text-detection results.</p>
     #[serde(rename = "NextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -357,9 +895,112 @@ pub struct HumanLoopDataAttributes {
     /// <p>Sets whether the input image is free of personally identifiable information or adult content.</p>
     #[serde(rename = "ContentClassifiers")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub content_classifiers: Option<Vec<String>>,
+    pub content_classifiers: Option<Vec<ContentClassifier>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum JobStatus {
+    /// `IN_PROGRESS`
+    InProgress,
+    /// `SUCCEEDED`
+    Succeeded,
+    /// `FAILED`
+    Failed,
+    /// `PARTIAL_SUCCESS`
+    PartialSuccess,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl JobStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            JobStatus::InProgress => "IN_PROGRESS",
+            JobStatus::Succeeded => "SUCCEEDED",
+            JobStatus::Failed => "FAILED",
+            JobStatus::PartialSuccess => "PARTIAL_SUCCESS",
+            JobStatus::Unknown(ref value) => value,
+        }
+    }
 }
 
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for JobStatus {
+    fn default() -> JobStatus {
+        JobStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for JobStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for JobStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for JobStatus {
+    fn from(value: &'a str) -> JobStatus {
+        match value {
+            "IN_PROGRESS" => JobStatus::InProgress,
+            "SUCCEEDED" => JobStatus::Succeeded,
+            "FAILED" => JobStatus::Failed,
+            "PARTIAL_SUCCESS" => JobStatus::PartialSuccess,
+            _ => JobStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for JobStatus {
+    fn from(value: String) -> JobStatus {
+        match value.as_str() {
+            "IN_PROGRESS" => JobStatus::InProgress,
+            "SUCCEEDED" => JobStatus::Succeeded,
+            "FAILED" => JobStatus::Failed,
+            "PARTIAL_SUCCESS" => JobStatus::PartialSuccess,
+            _ => JobStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for JobStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<JobStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for JobStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for JobStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for JobStatus {
+    fn deserialize<D>(deserializer: D) -> Result<JobStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The Amazon Simple Notification Service (Amazon SNS) topic to which Amazon Textract publishes the completion status of an asynchronous document operation, such as <a>StartDocumentTextDetection</a>. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -397,9 +1038,137 @@ pub struct Relationship {
     /// <p>The type of relationship that the blocks in the IDs array have with the current block. The relationship can be <code>VALUE</code> or <code>CHILD</code>. A relationship of type VALUE is a list that contains the ID of the VALUE block that's associated with the KEY of a key-value pair. A relationship of type CHILD is a list of IDs that identify WORD blocks.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<RelationshipType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RelationshipType {
+    /// `VALUE`
+    Value,
+    /// `CHILD`
+    Child,
+    /// `COMPLEX_FEATURES`
+    ComplexFeatures,
+    /// `MERGED_CELL`
+    MergedCell,
+    /// `TITLE`
+    Title,
+    /// `ANSWER`
+    Answer,
+    /// `TABLE`
+    Table,
+    /// `TABLE_TITLE`
+    TableTitle,
+    /// `TABLE_FOOTER`
+    TableFooter,
+    /// A value not known to this version of the crate
+    Unknown(String),
 }
 
+impl RelationshipType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RelationshipType::Value => "VALUE",
+            RelationshipType::Child => "CHILD",
+            RelationshipType::ComplexFeatures => "COMPLEX_FEATURES",
+            RelationshipType::MergedCell => "MERGED_CELL",
+            RelationshipType::Title => "TITLE",
+            RelationshipType::Answer => "ANSWER",
+            RelationshipType::Table => "TABLE",
+            RelationshipType::TableTitle => "TABLE_TITLE",
+            RelationshipType::TableFooter => "TABLE_FOOTER",
+            RelationshipType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RelationshipType {
+    fn default() -> RelationshipType {
+        RelationshipType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RelationshipType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RelationshipType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RelationshipType {
+    fn from(value: &'a str) -> RelationshipType {
+        match value {
+            "VALUE" => RelationshipType::Value,
+            "CHILD" => RelationshipType::Child,
+            "COMPLEX_FEATURES" => RelationshipType::ComplexFeatures,
+            "MERGED_CELL" => RelationshipType::MergedCell,
+            "TITLE" => RelationshipType::Title,
+            "ANSWER" => RelationshipType::Answer,
+            "TABLE" => RelationshipType::Table,
+            "TABLE_TITLE" => RelationshipType::TableTitle,
+            "TABLE_FOOTER" => RelationshipType::TableFooter,
+            _ => RelationshipType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RelationshipType {
+    fn from(value: String) -> RelationshipType {
+        match value.as_str() {
+            "VALUE" => RelationshipType::Value,
+            "CHILD" => RelationshipType::Child,
+            "COMPLEX_FEATURES" => RelationshipType::ComplexFeatures,
+            "MERGED_CELL" => RelationshipType::MergedCell,
+            "TITLE" => RelationshipType::Title,
+            "ANSWER" => RelationshipType::Answer,
+            "TABLE" => RelationshipType::Table,
+            "TABLE_TITLE" => RelationshipType::TableTitle,
+            "TABLE_FOOTER" => RelationshipType::TableFooter,
+            _ => RelationshipType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RelationshipType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RelationshipType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RelationshipType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RelationshipType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RelationshipType {
+    fn deserialize<D>(deserializer: D) -> Result<RelationshipType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The S3 bucket name and file name that identifies the document.</p> <p>The AWS Region for the S3 bucket that contains the document must match the Region that you use for Amazon Textract operations.</p> <p>For Amazon Textract to process a file in an S3 bucket, the user must have permission to access the S3 bucket and file. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -418,6 +1187,99 @@ pub struct S3Object {
     pub version: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SelectionStatus {
+    /// `SELECTED`
+    Selected,
+    /// `NOT_SELECTED`
+    NotSelected,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SelectionStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SelectionStatus::Selected => "SELECTED",
+            SelectionStatus::NotSelected => "NOT_SELECTED",
+            SelectionStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SelectionStatus {
+    fn default() -> SelectionStatus {
+        SelectionStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SelectionStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SelectionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SelectionStatus {
+    fn from(value: &'a str) -> SelectionStatus {
+        match value {
+            "SELECTED" => SelectionStatus::Selected,
+            "NOT_SELECTED" => SelectionStatus::NotSelected,
+            _ => SelectionStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SelectionStatus {
+    fn from(value: String) -> SelectionStatus {
+        match value.as_str() {
+            "SELECTED" => SelectionStatus::Selected,
+            "NOT_SELECTED" => SelectionStatus::NotSelected,
+            _ => SelectionStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SelectionStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SelectionStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SelectionStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SelectionStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SelectionStatus {
+    fn deserialize<D>(deserializer: D) -> Result<SelectionStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct StartDocumentAnalysisRequest {
@@ -430,7 +1292,7 @@ pub struct StartDocumentAnalysisRequest {
     pub document_location: DocumentLocation,
     /// <p>A list of the types of analysis to perform. Add TABLES to the list to return information about the tables that are detected in the input document. Add FORMS to return detected form data. To perform both types of analysis, add TABLES and FORMS to <code>FeatureTypes</code>. All lines and words detected in the document are included in the response (including text that isn't related to the value of <code>FeatureTypes</code>). </p>
     #[serde(rename = "FeatureTypes")]
-    pub feature_types: Vec<String>,
+    pub feature_types: Vec<FeatureType>,
     /// <p>An identifier that you specify that's included in the completion notification published to the Amazon SNS topic. For example, you can use <code>JobTag</code> to identify the type of document that the completion notification corresponds to (such as a tax form or a receipt).</p>
     #[serde(rename = "JobTag")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1088,11 +1950,12 @@ pub struct TextractClient {
 impl TextractClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> TextractClient {
         TextractClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Textract"),
         }
     }
 
@@ -1124,6 +1987,7 @@ impl Textract for TextractClient {
         input: AnalyzeDocumentRequest,
     ) -> Result<AnalyzeDocumentResponse, RusotoError<AnalyzeDocumentError>> {
         let mut request = SignedRequest::new("POST", "textract", &self.region, "/");
+        request.set_operation("AnalyzeDocument");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Textract.AnalyzeDocument");
@@ -1151,6 +2015,7 @@ impl Textract for TextractClient {
         input: DetectDocumentTextRequest,
     ) -> Result<DetectDocumentTextResponse, RusotoError<DetectDocumentTextError>> {
         let mut request = SignedRequest::new("POST", "textract", &self.region, "/");
+        request.set_operation("DetectDocumentText");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Textract.DetectDocumentText");
@@ -1179,6 +2044,7 @@ impl Textract for TextractClient {
         input: GetDocumentAnalysisRequest,
     ) -> Result<GetDocumentAnalysisResponse, RusotoError<GetDocumentAnalysisError>> {
         let mut request = SignedRequest::new("POST", "textract", &self.region, "/");
+        request.set_operation("GetDocumentAnalysis");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Textract.GetDocumentAnalysis");
@@ -1207,6 +2073,7 @@ impl Textract for TextractClient {
         input: GetDocumentTextDetectionRequest,
     ) -> Result<GetDocumentTextDetectionResponse, RusotoError<GetDocumentTextDetectionError>> {
         let mut request = SignedRequest::new("POST", "textract", &self.region, "/");
+        request.set_operation("GetDocumentTextDetection");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Textract.GetDocumentTextDetection");
@@ -1235,6 +2102,7 @@ impl Textract for TextractClient {
         input: StartDocumentAnalysisRequest,
     ) -> Result<StartDocumentAnalysisResponse, RusotoError<StartDocumentAnalysisError>> {
         let mut request = SignedRequest::new("POST", "textract", &self.region, "/");
+        request.set_operation("StartDocumentAnalysis");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Textract.StartDocumentAnalysis");
@@ -1264,6 +2132,7 @@ impl Textract for TextractClient {
     ) -> Result<StartDocumentTextDetectionResponse, RusotoError<StartDocumentTextDetectionError>>
     {
         let mut request = SignedRequest::new("POST", "textract", &self.region, "/");
+        request.set_operation("StartDocumentTextDetection");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Textract.StartDocumentTextDetection");
@@ -29,7 +29,7 @@ use serde_json;
 pub struct CreateVocabularyFilterRequest {
     /// <p>The language code of the words in the vocabulary filter. All words in the filter must be in the same language. The vocabulary filter can only be used with transcription jobs in the specified language.</p>
     #[serde(rename = "LanguageCode")]
-    pub language_code: String,
+    pub language_code: LanguageCode,
     /// <p>The Amazon S3 location of a text file used as input to create the vocabulary filter. Only use characters from the character set defined for custom vocabularies. For a list of character sets, see <a href="https://docs.aws.amazon.com/transcribe/latest/dg/how-vocabulary.html#charsets">Character Sets for Custom Vocabularies</a>.</p> <p>The specified file must be less than 50 KB of UTF-8 characters.</p> <p>If you provide the location of a list of words in the <code>VocabularyFilterFileUri</code> parameter, you can't use the <code>Words</code> parameter.</p>
     #[serde(rename = "VocabularyFilterFileUri")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -49,11 +49,16 @@ pub struct CreateVocabularyFilterResponse {
     /// <p>The language code of the words in the collection.</p>
     #[serde(rename = "LanguageCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub language_code: Option<String>,
+    pub language_code: Option<LanguageCode>,
     /// <p>The date and time that the vocabulary filter was modified.</p>
     #[serde(rename = "LastModifiedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_time: Option<f64>,
+    pub last_modified_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the vocabulary filter.</p>
     #[serde(rename = "VocabularyFilterName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -65,7 +70,7 @@ pub struct CreateVocabularyFilterResponse {
 pub struct CreateVocabularyRequest {
     /// <p>The language code of the vocabulary entries.</p>
     #[serde(rename = "LanguageCode")]
-    pub language_code: String,
+    pub language_code: LanguageCode,
     /// <p>An array of strings that contains the vocabulary entries. </p>
     #[serde(rename = "Phrases")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -89,11 +94,16 @@ pub struct CreateVocabularyResponse {
     /// <p>The language code of the vocabulary entries.</p>
     #[serde(rename = "LanguageCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub language_code: Option<String>,
+    pub language_code: Option<LanguageCode>,
     /// <p>The date and time that the vocabulary was created.</p>
     #[serde(rename = "LastModifiedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_time: Option<f64>,
+    pub last_modified_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the vocabulary.</p>
     #[serde(rename = "VocabularyName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -101,7 +111,7 @@ pub struct CreateVocabularyResponse {
     /// <p>The processing state of the vocabulary. When the <code>VocabularyState</code> field contains <code>READY</code> the vocabulary is ready to be used in a <code>StartTranscriptionJob</code> request.</p>
     #[serde(rename = "VocabularyState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub vocabulary_state: Option<String>,
+    pub vocabulary_state: Option<VocabularyState>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -163,11 +173,16 @@ pub struct GetVocabularyFilterResponse {
     /// <p>The language code of the words in the vocabulary filter.</p>
     #[serde(rename = "LanguageCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub language_code: Option<String>,
+    pub language_code: Option<LanguageCode>,
     /// <p>The date and time that the contents of the vocabulary filter were updated.</p>
     #[serde(rename = "LastModifiedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_time: Option<f64>,
+    pub last_modified_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the vocabulary filter.</p>
     #[serde(rename = "VocabularyFilterName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -196,11 +211,16 @@ pub struct GetVocabularyResponse {
     /// <p>The language code of the vocabulary entries.</p>
     #[serde(rename = "LanguageCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub language_code: Option<String>,
+    pub language_code: Option<LanguageCode>,
     /// <p>The date and time that the vocabulary was last modified.</p>
     #[serde(rename = "LastModifiedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_time: Option<f64>,
+    pub last_modified_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the vocabulary to return.</p>
     #[serde(rename = "VocabularyName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -208,7 +228,7 @@ pub struct GetVocabularyResponse {
     /// <p>The processing state of the vocabulary.</p>
     #[serde(rename = "VocabularyState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub vocabulary_state: Option<String>,
+    pub vocabulary_state: Option<VocabularyState>,
 }
 
 /// <p>Provides information about when a transcription job should be executed.</p>
@@ -224,6 +244,604 @@ pub struct JobExecutionSettings {
     pub data_access_role_arn: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LanguageCode {
+    /// `af-ZA`
+    AfZa,
+    /// `ar-AE`
+    ArAe,
+    /// `ar-SA`
+    ArSa,
+    /// `da-DK`
+    DaDk,
+    /// `de-CH`
+    DeCh,
+    /// `de-DE`
+    DeDe,
+    /// `en-AB`
+    EnAb,
+    /// `en-AU`
+    EnAu,
+    /// `en-GB`
+    EnGb,
+    /// `en-IE`
+    EnIe,
+    /// `en-IN`
+    EnIn,
+    /// `en-US`
+    EnUs,
+    /// `en-WL`
+    EnWl,
+    /// `es-ES`
+    EsEs,
+    /// `es-US`
+    EsUs,
+    /// `fa-IR`
+    FaIr,
+    /// `fr-CA`
+    FrCa,
+    /// `fr-FR`
+    FrFr,
+    /// `he-IL`
+    HeIl,
+    /// `hi-IN`
+    HiIn,
+    /// `id-ID`
+    IdId,
+    /// `it-IT`
+    ItIt,
+    /// `ja-JP`
+    JaJp,
+    /// `ko-KR`
+    KoKr,
+    /// `ms-MY`
+    MsMy,
+    /// `nl-NL`
+    NlNl,
+    /// `pt-BR`
+    PtBr,
+    /// `pt-PT`
+    PtPt,
+    /// `ru-RU`
+    RuRu,
+    /// `ta-IN`
+    TaIn,
+    /// `te-IN`
+    TeIn,
+    /// `tr-TR`
+    TrTr,
+    /// `zh-CN`
+    ZhCn,
+    /// `zh-TW`
+    ZhTw,
+    /// `th-TH`
+    ThTh,
+    /// `en-ZA`
+    EnZa,
+    /// `en-NZ`
+    EnNz,
+    /// `vi-VN`
+    ViVn,
+    /// `sv-SE`
+    SvSe,
+    /// `ab-GE`
+    AbGe,
+    /// `ast-ES`
+    AstEs,
+    /// `az-AZ`
+    AzAz,
+    /// `ba-RU`
+    BaRu,
+    /// `be-BY`
+    BeBy,
+    /// `bg-BG`
+    BgBg,
+    /// `bn-IN`
+    BnIn,
+    /// `bs-BA`
+    BsBa,
+    /// `ca-ES`
+    CaEs,
+    /// `ckb-IQ`
+    CkbIq,
+    /// `ckb-IR`
+    CkbIr,
+    /// `cs-CZ`
+    CsCz,
+    /// `cy-WL`
+    CyWl,
+    /// `el-GR`
+    ElGr,
+    /// `et-ET`
+    EtEt,
+    /// `eu-ES`
+    EuEs,
+    /// `fi-FI`
+    FiFi,
+    /// `gl-ES`
+    GlEs,
+    /// `gu-IN`
+    GuIn,
+    /// `ha-NG`
+    HaNg,
+    /// `hr-HR`
+    HrHr,
+    /// `hu-HU`
+    HuHu,
+    /// `hy-AM`
+    HyAm,
+    /// `is-IS`
+    IsIs,
+    /// `ka-GE`
+    KaGe,
+    /// `kab-DZ`
+    KabDz,
+    /// `kk-KZ`
+    KkKz,
+    /// `kn-IN`
+    KnIn,
+    /// `ky-KG`
+    KyKg,
+    /// `lg-IN`
+    LgIn,
+    /// `lt-LT`
+    LtLt,
+    /// `lv-LV`
+    LvLv,
+    /// `mhr-RU`
+    MhrRu,
+    /// `mi-NZ`
+    MiNz,
+    /// `mk-MK`
+    MkMk,
+    /// `ml-IN`
+    MlIn,
+    /// `mn-MN`
+    MnMn,
+    /// `mr-IN`
+    MrIn,
+    /// `mt-MT`
+    MtMt,
+    /// `no-NO`
+    NoNo,
+    /// `or-IN`
+    OrIn,
+    /// `pa-IN`
+    PaIn,
+    /// `pl-PL`
+    PlPl,
+    /// `ps-AF`
+    PsAf,
+    /// `ro-RO`
+    RoRo,
+    /// `rw-RW`
+    RwRw,
+    /// `si-LK`
+    SiLk,
+    /// `sk-SK`
+    SkSk,
+    /// `sl-SI`
+    SlSi,
+    /// `so-SO`
+    SoSo,
+    /// `sr-RS`
+    SrRs,
+    /// `su-ID`
+    SuId,
+    /// `sw-BI`
+    SwBi,
+    /// `sw-KE`
+    SwKe,
+    /// `sw-RW`
+    SwRw,
+    /// `sw-TZ`
+    SwTz,
+    /// `sw-UG`
+    SwUg,
+    /// `tl-PH`
+    TlPh,
+    /// `tt-RU`
+    TtRu,
+    /// `ug-CN`
+    UgCn,
+    /// `uk-UA`
+    UkUa,
+    /// `uz-UZ`
+    UzUz,
+    /// `wo-SN`
+    WoSn,
+    /// `zu-ZA`
+    ZuZa,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LanguageCode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LanguageCode::AfZa => "af-ZA",
+            LanguageCode::ArAe => "ar-AE",
+            LanguageCode::ArSa => "ar-SA",
+            LanguageCode::DaDk => "da-DK",
+            LanguageCode::DeCh => "de-CH",
+            LanguageCode::DeDe => "de-DE",
+            LanguageCode::EnAb => "en-AB",
+            LanguageCode::EnAu => "en-AU",
+            LanguageCode::EnGb => "en-GB",
+            LanguageCode::EnIe => "en-IE",
+            LanguageCode::EnIn => "en-IN",
+            LanguageCode::EnUs => "en-US",
+            LanguageCode::EnWl => "en-WL",
+            LanguageCode::EsEs => "es-ES",
+            LanguageCode::EsUs => "es-US",
+            LanguageCode::FaIr => "fa-IR",
+            LanguageCode::FrCa => "fr-CA",
+            LanguageCode::FrFr => "fr-FR",
+            LanguageCode::HeIl => "he-IL",
+            LanguageCode::HiIn => "hi-IN",
+            LanguageCode::IdId => "id-ID",
+            LanguageCode::ItIt => "it-IT",
+            LanguageCode::JaJp => "ja-JP",
+            LanguageCode::KoKr => "ko-KR",
+            LanguageCode::MsMy => "ms-MY",
+            LanguageCode::NlNl => "nl-NL",
+            LanguageCode::PtBr => "pt-BR",
+            LanguageCode::PtPt => "pt-PT",
+            LanguageCode::RuRu => "ru-RU",
+            LanguageCode::TaIn => "ta-IN",
+            LanguageCode::TeIn => "te-IN",
+            LanguageCode::TrTr => "tr-TR",
+            LanguageCode::ZhCn => "zh-CN",
+            LanguageCode::ZhTw => "zh-TW",
+            LanguageCode::ThTh => "th-TH",
+            LanguageCode::EnZa => "en-ZA",
+            LanguageCode::EnNz => "en-NZ",
+            LanguageCode::ViVn => "vi-VN",
+            LanguageCode::SvSe => "sv-SE",
+            LanguageCode::AbGe => "ab-GE",
+            LanguageCode::AstEs => "ast-ES",
+            LanguageCode::AzAz => "az-AZ",
+            LanguageCode::BaRu => "ba-RU",
+            LanguageCode::BeBy => "be-BY",
+            LanguageCode::BgBg => "bg-BG",
+            LanguageCode::BnIn => "bn-IN",
+            LanguageCode::BsBa => "bs-BA",
+            LanguageCode::CaEs => "ca-ES",
+            LanguageCode::CkbIq => "ckb-IQ",
+            LanguageCode::CkbIr => "ckb-IR",
+            LanguageCode::CsCz => "cs-CZ",
+            LanguageCode::CyWl => "cy-WL",
+            LanguageCode::ElGr => "el-GR",
+            LanguageCode::EtEt => "et-ET",
+            LanguageCode::EuEs => "eu-ES",
+            LanguageCode::FiFi => "fi-FI",
+            LanguageCode::GlEs => "gl-ES",
+            LanguageCode::GuIn => "gu-IN",
+            LanguageCode::HaNg => "ha-NG",
+            LanguageCode::HrHr => "hr-HR",
+            LanguageCode::HuHu => "hu-HU",
+            LanguageCode::HyAm => "hy-AM",
+            LanguageCode::IsIs => "is-IS",
+            LanguageCode::KaGe => "ka-GE",
+            LanguageCode::KabDz => "kab-DZ",
+            LanguageCode::KkKz => "kk-KZ",
+            LanguageCode::KnIn => "kn-IN",
+            LanguageCode::KyKg => "ky-KG",
+            LanguageCode::LgIn => "lg-IN",
+            LanguageCode::LtLt => "lt-LT",
+            LanguageCode::LvLv => "lv-LV",
+            LanguageCode::MhrRu => "mhr-RU",
+            LanguageCode::MiNz => "mi-NZ",
+            LanguageCode::MkMk => "mk-MK",
+            LanguageCode::MlIn => "ml-IN",
+            LanguageCode::MnMn => "mn-MN",
+            LanguageCode::MrIn => "mr-IN",
+            LanguageCode::MtMt => "mt-MT",
+            LanguageCode::NoNo => "no-NO",
+            LanguageCode::OrIn => "or-IN",
+            LanguageCode::PaIn => "pa-IN",
+            LanguageCode::PlPl => "pl-PL",
+            LanguageCode::PsAf => "ps-AF",
+            LanguageCode::RoRo => "ro-RO",
+            LanguageCode::RwRw => "rw-RW",
+            LanguageCode::SiLk => "si-LK",
+            LanguageCode::SkSk => "sk-SK",
+            LanguageCode::SlSi => "sl-SI",
+            LanguageCode::SoSo => "so-SO",
+            LanguageCode::SrRs => "sr-RS",
+            LanguageCode::SuId => "su-ID",
+            LanguageCode::SwBi => "sw-BI",
+            LanguageCode::SwKe => "sw-KE",
+            LanguageCode::SwRw => "sw-RW",
+            LanguageCode::SwTz => "sw-TZ",
+            LanguageCode::SwUg => "sw-UG",
+            LanguageCode::TlPh => "tl-PH",
+            LanguageCode::TtRu => "tt-RU",
+            LanguageCode::UgCn => "ug-CN",
+            LanguageCode::UkUa => "uk-UA",
+            LanguageCode::UzUz => "uz-UZ",
+            LanguageCode::WoSn => "wo-SN",
+            LanguageCode::ZuZa => "zu-ZA",
+            LanguageCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LanguageCode {
+    fn default() -> LanguageCode {
+        LanguageCode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LanguageCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LanguageCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LanguageCode {
+    fn from(value: &'a str) -> LanguageCode {
+        match value {
+            "af-ZA" => LanguageCode::AfZa,
+            "ar-AE" => LanguageCode::ArAe,
+            "ar-SA" => LanguageCode::ArSa,
+            "da-DK" => LanguageCode::DaDk,
+            "de-CH" => LanguageCode::DeCh,
+            "de-DE" => LanguageCode::DeDe,
+            "en-AB" => LanguageCode::EnAb,
+            "en-AU" => LanguageCode::EnAu,
+            "en-GB" => LanguageCode::EnGb,
+            "en-IE" => LanguageCode::EnIe,
+            "en-IN" => LanguageCode::EnIn,
+            "en-US" => LanguageCode::EnUs,
+            "en-WL" => LanguageCode::EnWl,
+            "es-ES" => LanguageCode::EsEs,
+            "es-US" => LanguageCode::EsUs,
+            "fa-IR" => LanguageCode::FaIr,
+            "fr-CA" => LanguageCode::FrCa,
+            "fr-FR" => LanguageCode::FrFr,
+            "he-IL" => LanguageCode::HeIl,
+            "hi-IN" => LanguageCode::HiIn,
+            "id-ID" => LanguageCode::IdId,
+            "it-IT" => LanguageCode::ItIt,
+            "ja-JP" => LanguageCode::JaJp,
+            "ko-KR" => LanguageCode::KoKr,
+            "ms-MY" => LanguageCode::MsMy,
+            "nl-NL" => LanguageCode::NlNl,
+            "pt-BR" => LanguageCode::PtBr,
+            "pt-PT" => LanguageCode::PtPt,
+            "ru-RU" => LanguageCode::RuRu,
+            "ta-IN" => LanguageCode::TaIn,
+            "te-IN" => LanguageCode::TeIn,
+            "tr-TR" => LanguageCode::TrTr,
+            "zh-CN" => LanguageCode::ZhCn,
+            "zh-TW" => LanguageCode::ZhTw,
+            "th-TH" => LanguageCode::ThTh,
+            "en-ZA" => LanguageCode::EnZa,
+            "en-NZ" => LanguageCode::EnNz,
+            "vi-VN" => LanguageCode::ViVn,
+            "sv-SE" => LanguageCode::SvSe,
+            "ab-GE" => LanguageCode::AbGe,
+            "ast-ES" => LanguageCode::AstEs,
+            "az-AZ" => LanguageCode::AzAz,
+            "ba-RU" => LanguageCode::BaRu,
+            "be-BY" => LanguageCode::BeBy,
+            "bg-BG" => LanguageCode::BgBg,
+            "bn-IN" => LanguageCode::BnIn,
+            "bs-BA" => LanguageCode::BsBa,
+            "ca-ES" => LanguageCode::CaEs,
+            "ckb-IQ" => LanguageCode::CkbIq,
+            "ckb-IR" => LanguageCode::CkbIr,
+            "cs-CZ" => LanguageCode::CsCz,
+            "cy-WL" => LanguageCode::CyWl,
+            "el-GR" => LanguageCode::ElGr,
+            "et-ET" => LanguageCode::EtEt,
+            "eu-ES" => LanguageCode::EuEs,
+            "fi-FI" => LanguageCode::FiFi,
+            "gl-ES" => LanguageCode::GlEs,
+            "gu-IN" => LanguageCode::GuIn,
+            "ha-NG" => LanguageCode::HaNg,
+            "hr-HR" => LanguageCode::HrHr,
+            "hu-HU" => LanguageCode::HuHu,
+            "hy-AM" => LanguageCode::HyAm,
+            "is-IS" => LanguageCode::IsIs,
+            "ka-GE" => LanguageCode::KaGe,
+            "kab-DZ" => LanguageCode::KabDz,
+            "kk-KZ" => LanguageCode::KkKz,
+            "kn-IN" => LanguageCode::KnIn,
+            "ky-KG" => LanguageCode::KyKg,
+            "lg-IN" => LanguageCode::LgIn,
+            "lt-LT" => LanguageCode::LtLt,
+            "lv-LV" => LanguageCode::LvLv,
+            "mhr-RU" => LanguageCode::MhrRu,
+            "mi-NZ" => LanguageCode::MiNz,
+            "mk-MK" => LanguageCode::MkMk,
+            "ml-IN" => LanguageCode::MlIn,
+            "mn-MN" => LanguageCode::MnMn,
+            "mr-IN" => LanguageCode::MrIn,
+            "mt-MT" => LanguageCode::MtMt,
+            "no-NO" => LanguageCode::NoNo,
+            "or-IN" => LanguageCode::OrIn,
+            "pa-IN" => LanguageCode::PaIn,
+            "pl-PL" => LanguageCode::PlPl,
+            "ps-AF" => LanguageCode::PsAf,
+            "ro-RO" => LanguageCode::RoRo,
+            "rw-RW" => LanguageCode::RwRw,
+            "si-LK" => LanguageCode::SiLk,
+            "sk-SK" => LanguageCode::SkSk,
+            "sl-SI" => LanguageCode::SlSi,
+            "so-SO" => LanguageCode::SoSo,
+            "sr-RS" => LanguageCode::SrRs,
+            "su-ID" => LanguageCode::SuId,
+            "sw-BI" => LanguageCode::SwBi,
+            "sw-KE" => LanguageCode::SwKe,
+            "sw-RW" => LanguageCode::SwRw,
+            "sw-TZ" => LanguageCode::SwTz,
+            "sw-UG" => LanguageCode::SwUg,
+            "tl-PH" => LanguageCode::TlPh,
+            "tt-RU" => LanguageCode::TtRu,
+            "ug-CN" => LanguageCode::UgCn,
+            "uk-UA" => LanguageCode::UkUa,
+            "uz-UZ" => LanguageCode::UzUz,
+            "wo-SN" => LanguageCode::WoSn,
+            "zu-ZA" => LanguageCode::ZuZa,
+            _ => LanguageCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LanguageCode {
+    fn from(value: String) -> LanguageCode {
+        match value.as_str() {
+            "af-ZA" => LanguageCode::AfZa,
+            "ar-AE" => LanguageCode::ArAe,
+            "ar-SA" => LanguageCode::ArSa,
+            "da-DK" => LanguageCode::DaDk,
+            "de-CH" => LanguageCode::DeCh,
+            "de-DE" => LanguageCode::DeDe,
+            "en-AB" => LanguageCode::EnAb,
+            "en-AU" => LanguageCode::EnAu,
+            "en-GB" => LanguageCode::EnGb,
+            "en-IE" => LanguageCode::EnIe,
+            "en-IN" => LanguageCode::EnIn,
+            "en-US" => LanguageCode::EnUs,
+            "en-WL" => LanguageCode::EnWl,
+            "es-ES" => LanguageCode::EsEs,
+            "es-US" => LanguageCode::EsUs,
+            "fa-IR" => LanguageCode::FaIr,
+            "fr-CA" => LanguageCode::FrCa,
+            "fr-FR" => LanguageCode::FrFr,
+            "he-IL" => LanguageCode::HeIl,
+            "hi-IN" => LanguageCode::HiIn,
+            "id-ID" => LanguageCode::IdId,
+            "it-IT" => LanguageCode::ItIt,
+            "ja-JP" => LanguageCode::JaJp,
+            "ko-KR" => LanguageCode::KoKr,
+            "ms-MY" => LanguageCode::MsMy,
+            "nl-NL" => LanguageCode::NlNl,
+            "pt-BR" => LanguageCode::PtBr,
+            "pt-PT" => LanguageCode::PtPt,
+            "ru-RU" => LanguageCode::RuRu,
+            "ta-IN" => LanguageCode::TaIn,
+            "te-IN" => LanguageCode::TeIn,
+            "tr-TR" => LanguageCode::TrTr,
+            "zh-CN" => LanguageCode::ZhCn,
+            "zh-TW" => LanguageCode::ZhTw,
+            "th-TH" => LanguageCode::ThTh,
+            "en-ZA" => LanguageCode::EnZa,
+            "en-NZ" => LanguageCode::EnNz,
+            "vi-VN" => LanguageCode::ViVn,
+            "sv-SE" => LanguageCode::SvSe,
+            "ab-GE" => LanguageCode::AbGe,
+            "ast-ES" => LanguageCode::AstEs,
+            "az-AZ" => LanguageCode::AzAz,
+            "ba-RU" => LanguageCode::BaRu,
+            "be-BY" => LanguageCode::BeBy,
+            "bg-BG" => LanguageCode::BgBg,
+            "bn-IN" => LanguageCode::BnIn,
+            "bs-BA" => LanguageCode::BsBa,
+            "ca-ES" => LanguageCode::CaEs,
+            "ckb-IQ" => LanguageCode::CkbIq,
+            "ckb-IR" => LanguageCode::CkbIr,
+            "cs-CZ" => LanguageCode::CsCz,
+            "cy-WL" => LanguageCode::CyWl,
+            "el-GR" => LanguageCode::ElGr,
+            "et-ET" => LanguageCode::EtEt,
+            "eu-ES" => LanguageCode::EuEs,
+            "fi-FI" => LanguageCode::FiFi,
+            "gl-ES" => LanguageCode::GlEs,
+            "gu-IN" => LanguageCode::GuIn,
+            "ha-NG" => LanguageCode::HaNg,
+            "hr-HR" => LanguageCode::HrHr,
+            "hu-HU" => LanguageCode::HuHu,
+            "hy-AM" => LanguageCode::HyAm,
+            "is-IS" => LanguageCode::IsIs,
+            "ka-GE" => LanguageCode::KaGe,
+            "kab-DZ" => LanguageCode::KabDz,
+            "kk-KZ" => LanguageCode::KkKz,
+            "kn-IN" => LanguageCode::KnIn,
+            "ky-KG" => LanguageCode::KyKg,
+            "lg-IN" => LanguageCode::LgIn,
+            "lt-LT" => LanguageCode::LtLt,
+            "lv-LV" => LanguageCode::LvLv,
+            "mhr-RU" => LanguageCode::MhrRu,
+            "mi-NZ" => LanguageCode::MiNz,
+            "mk-MK" => LanguageCode::MkMk,
+            "ml-IN" => LanguageCode::MlIn,
+            "mn-MN" => LanguageCode::MnMn,
+            "mr-IN" => LanguageCode::MrIn,
+            "mt-MT" => LanguageCode::MtMt,
+            "no-NO" => LanguageCode::NoNo,
+            "or-IN" => LanguageCode::OrIn,
+            "pa-IN" => LanguageCode::PaIn,
+            "pl-PL" => LanguageCode::PlPl,
+            "ps-AF" => LanguageCode::PsAf,
+            "ro-RO" => LanguageCode::RoRo,
+            "rw-RW" => LanguageCode::RwRw,
+            "si-LK" => LanguageCode::SiLk,
+            "sk-SK" => LanguageCode::SkSk,
+            "sl-SI" => LanguageCode::SlSi,
+            "so-SO" => LanguageCode::SoSo,
+            "sr-RS" => LanguageCode::SrRs,
+            "su-ID" => LanguageCode::SuId,
+            "sw-BI" => LanguageCode::SwBi,
+            "sw-KE" => LanguageCode::SwKe,
+            "sw-RW" => LanguageCode::SwRw,
+            "sw-TZ" => LanguageCode::SwTz,
+            "sw-UG" => LanguageCode::SwUg,
+            "tl-PH" => LanguageCode::TlPh,
+            "tt-RU" => LanguageCode::TtRu,
+            "ug-CN" => LanguageCode::UgCn,
+            "uk-UA" => LanguageCode::UkUa,
+            "uz-UZ" => LanguageCode::UzUz,
+            "wo-SN" => LanguageCode::WoSn,
+            "zu-ZA" => LanguageCode::ZuZa,
+            _ => LanguageCode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LanguageCode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LanguageCode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LanguageCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LanguageCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LanguageCode {
+    fn deserialize<D>(deserializer: D) -> Result<LanguageCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ListTranscriptionJobsRequest {
@@ -242,7 +860,7 @@ pub struct ListTranscriptionJobsRequest {
     /// <p>When specified, returns only transcription jobs with the specified status. Jobs are ordered by creation date, with the newest jobs returned first. If you don’t specify a status, Amazon Transcribe returns all transcription jobs ordered by creation date. </p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<TranscriptionJobStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -255,7 +873,7 @@ pub struct ListTranscriptionJobsResponse {
     /// <p>The requested status of the jobs returned.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<TranscriptionJobStatus>,
     /// <p>A list of objects containing summary information for a transcription job.</p>
     #[serde(rename = "TranscriptionJobSummaries")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -280,7 +898,7 @@ pub struct ListVocabulariesRequest {
     /// <p>When specified, only returns vocabularies with the <code>VocabularyState</code> field equal to the specified state.</p>
     #[serde(rename = "StateEquals")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state_equals: Option<String>,
+    pub state_equals: Option<VocabularyState>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -293,7 +911,7 @@ pub struct ListVocabulariesResponse {
     /// <p>The requested vocabulary state.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<VocabularyState>,
     /// <p>A list of objects that describe the vocabularies that match the search criteria in the request.</p>
     #[serde(rename = "Vocabularies")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -339,6 +957,222 @@ pub struct Media {
     pub media_file_uri: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MediaFormat {
+    /// `mp3`
+    Mp3,
+    /// `mp4`
+    Mp4,
+    /// `wav`
+    Wav,
+    /// `flac`
+    Flac,
+    /// `ogg`
+    Ogg,
+    /// `amr`
+    Amr,
+    /// `webm`
+    Webm,
+    /// `m4a`
+    M4a,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl MediaFormat {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MediaFormat::Mp3 => "mp3",
+            MediaFormat::Mp4 => "mp4",
+            MediaFormat::Wav => "wav",
+            MediaFormat::Flac => "flac",
+            MediaFormat::Ogg => "ogg",
+            MediaFormat::Amr => "amr",
+            MediaFormat::Webm => "webm",
+            MediaFormat::M4a => "m4a",
+            MediaFormat::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for MediaFormat {
+    fn default() -> MediaFormat {
+        MediaFormat::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for MediaFormat {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for MediaFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for MediaFormat {
+    fn from(value: &'a str) -> MediaFormat {
+        match value {
+            "mp3" => MediaFormat::Mp3,
+            "mp4" => MediaFormat::Mp4,
+            "wav" => MediaFormat::Wav,
+            "flac" => MediaFormat::Flac,
+            "ogg" => MediaFormat::Ogg,
+            "amr" => MediaFormat::Amr,
+            "webm" => MediaFormat::Webm,
+            "m4a" => MediaFormat::M4a,
+            _ => MediaFormat::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MediaFormat {
+    fn from(value: String) -> MediaFormat {
+        match value.as_str() {
+            "mp3" => MediaFormat::Mp3,
+            "mp4" => MediaFormat::Mp4,
+            "wav" => MediaFormat::Wav,
+            "flac" => MediaFormat::Flac,
+            "ogg" => MediaFormat::Ogg,
+            "amr" => MediaFormat::Amr,
+            "webm" => MediaFormat::Webm,
+            "m4a" => MediaFormat::M4a,
+            _ => MediaFormat::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for MediaFormat {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<MediaFormat, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MediaFormat {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for MediaFormat {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MediaFormat {
+    fn deserialize<D>(deserializer: D) -> Result<MediaFormat, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OutputLocationType {
+    /// `CUSTOMER_BUCKET`
+    CustomerBucket,
+    /// `SERVICE_BUCKET`
+    ServiceBucket,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl OutputLocationType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OutputLocationType::CustomerBucket => "CUSTOMER_BUCKET",
+            OutputLocationType::ServiceBucket => "SERVICE_BUCKET",
+            OutputLocationType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for OutputLocationType {
+    fn default() -> OutputLocationType {
+        OutputLocationType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for OutputLocationType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for OutputLocationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for OutputLocationType {
+    fn from(value: &'a str) -> OutputLocationType {
+        match value {
+            "CUSTOMER_BUCKET" => OutputLocationType::CustomerBucket,
+            "SERVICE_BUCKET" => OutputLocationType::ServiceBucket,
+            _ => OutputLocationType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OutputLocationType {
+    fn from(value: String) -> OutputLocationType {
+        match value.as_str() {
+            "CUSTOMER_BUCKET" => OutputLocationType::CustomerBucket,
+            "SERVICE_BUCKET" => OutputLocationType::ServiceBucket,
+            _ => OutputLocationType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for OutputLocationType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<OutputLocationType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OutputLocationType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for OutputLocationType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OutputLocationType {
+    fn deserialize<D>(deserializer: D) -> Result<OutputLocationType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Provides optional settings for the <code>StartTranscriptionJob</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Settings {
@@ -365,7 +1199,7 @@ pub struct Settings {
     /// <p>Set to <code>mask</code> to remove filtered text from the transcript and replace it with three asterisks ("***") as placeholder text. Set to <code>remove</code> to remove filtered text from the transcript without using placeholder text.</p>
     #[serde(rename = "VocabularyFilterMethod")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub vocabulary_filter_method: Option<String>,
+    pub vocabulary_filter_method: Option<VocabularyFilterMethod>,
     /// <p>The name of the vocabulary filter to use when transcribing the audio. The filter that you specify must have the same language code as the transcription job.</p>
     #[serde(rename = "VocabularyFilterName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -385,14 +1219,14 @@ pub struct StartTranscriptionJobRequest {
     pub job_execution_settings: Option<JobExecutionSettings>,
     /// <p>The language code for the language used in the input media file.</p>
     #[serde(rename = "LanguageCode")]
-    pub language_code: String,
+    pub language_code: LanguageCode,
     /// <p>An object that describes the input media for a transcription job.</p>
     #[serde(rename = "Media")]
     pub media: Media,
     /// <p>The format of the input media file.</p>
     #[serde(rename = "MediaFormat")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub media_format: Option<String>,
+    pub media_format: Option<MediaFormat>,
     /// <p>The sample rate, in Hertz, of the audio track in the input media file. </p> <p>If you do not specify the media sample rate, Amazon Transcribe determines the sample rate. If you specify the sample rate, it must match the sample rate detected by Amazon Transcribe. In most cases, you should leave the <code>MediaSampleRateHertz</code> field blank and let Amazon Transcribe determine the sample rate.</p>
     #[serde(rename = "MediaSampleRateHertz")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -439,12 +1273,22 @@ pub struct Transcript {
 pub struct TranscriptionJob {
     /// <p>A timestamp that shows when the job was completed.</p>
     #[serde(rename = "CompletionTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub completion_time: Option<f64>,
+    pub completion_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A timestamp that shows when the job was created.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p><p>If the <code>TranscriptionJobStatus</code> field is <code>FAILED</code>, this field contains information about why the job failed.</p> <p>The <code>FailureReason</code> field can contain one of the following values:</p> <ul> <li> <p> <code>Unsupported media format</code> - The media format specified in the <code>MediaFormat</code> field of the request isn&#39;t valid. See the description of the <code>MediaFormat</code> field for a list of valid values.</p> </li> <li> <p> <code>The media format provided does not match the detected media format</code> - The media format of the audio file doesn&#39;t match the format specified in the <code>MediaFormat</code> field in the request. Check the media format of your media file and make sure that the two values match.</p> </li> <li> <p> <code>Invalid sample rate for audio file</code> - The sample rate specified in the <code>MediaSampleRateHertz</code> of the request isn&#39;t valid. The sample rate must be between 8000 and 48000 Hertz.</p> </li> <li> <p> <code>The sample rate provided does not match the detected sample rate</code> - The sample rate in the audio file doesn&#39;t match the sample rate specified in the <code>MediaSampleRateHertz</code> field in the request. Check the sample rate of your media file and make sure that the two values match.</p> </li> <li> <p> <code>Invalid file size: file size too large</code> - The size of your audio file is larger than Amazon Transcribe can process. For more information, see <a href="https://docs.aws.amazon.com/transcribe/latest/dg/limits-guidelines.html#limits">Limits</a> in the <i>Amazon Transcribe Developer Guide</i>.</p> </li> <li> <p> <code>Invalid number of channels: number of channels too large</code> - Your audio contains more channels than Amazon Transcribe is configured to process. To request additional channels, see <a href="https://docs.aws.amazon.com/general/latest/gr/aws_service_limits.html#limits-amazon-transcribe">Amazon Transcribe Limits</a> in the <i>Amazon Web Services General Reference</i>.</p> </li> </ul></p>
     #[serde(rename = "FailureReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -456,7 +1300,7 @@ pub struct TranscriptionJob {
     /// <p>The language code for the input speech.</p>
     #[serde(rename = "LanguageCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub language_code: Option<String>,
+    pub language_code: Option<LanguageCode>,
     /// <p>An object that describes the input media for the transcription job.</p>
     #[serde(rename = "Media")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -464,7 +1308,7 @@ pub struct TranscriptionJob {
     /// <p>The format of the input media file.</p>
     #[serde(rename = "MediaFormat")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub media_format: Option<String>,
+    pub media_format: Option<MediaFormat>,
     /// <p>The sample rate, in Hertz, of the audio track in the input media file. </p>
     #[serde(rename = "MediaSampleRateHertz")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -475,8 +1319,13 @@ pub struct TranscriptionJob {
     pub settings: Option<Settings>,
     /// <p>A timestamp that shows with the job was started processing.</p>
     #[serde(rename = "StartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>An object that describes the output of the transcription job.</p>
     #[serde(rename = "Transcript")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -488,21 +1337,134 @@ pub struct TranscriptionJob {
     /// <p>The status of the transcription job.</p>
     #[serde(rename = "TranscriptionJobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub transcription_job_status: Option<String>,
+    pub transcription_job_status: Option<TranscriptionJobStatus>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TranscriptionJobStatus {
+    /// `QUEUED`
+    Queued,
+    /// `IN_PROGRESS`
+    InProgress,
+    /// `FAILED`
+    Failed,
+    /// `COMPLETED`
+    Completed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TranscriptionJobStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TranscriptionJobStatus::Queued => "QUEUED",
+            TranscriptionJobStatus::InProgress => "IN_PROGRESS",
+            TranscriptionJobStatus::Failed => "FAILED",
+            TranscriptionJobStatus::Completed => "COMPLETED",
+            TranscriptionJobStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TranscriptionJobStatus {
+    fn default() -> TranscriptionJobStatus {
+        TranscriptionJobStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TranscriptionJobStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TranscriptionJobStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TranscriptionJobStatus {
+    fn from(value: &'a str) -> TranscriptionJobStatus {
+        match value {
+            "QUEUED" => TranscriptionJobStatus::Queued,
+            "IN_PROGRESS" => TranscriptionJobStatus::InProgress,
+            "FAILED" => TranscriptionJobStatus::Failed,
+            "COMPLETED" => TranscriptionJobStatus::Completed,
+            _ => TranscriptionJobStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TranscriptionJobStatus {
+    fn from(value: String) -> TranscriptionJobStatus {
+        match value.as_str() {
+            "QUEUED" => TranscriptionJobStatus::Queued,
+            "IN_PROGRESS" => TranscriptionJobStatus::InProgress,
+            "FAILED" => TranscriptionJobStatus::Failed,
+            "COMPLETED" => TranscriptionJobStatus::Completed,
+            _ => TranscriptionJobStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TranscriptionJobStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TranscriptionJobStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TranscriptionJobStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TranscriptionJobStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TranscriptionJobStatus {
+    fn deserialize<D>(deserializer: D) -> Result<TranscriptionJobStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Provides a summary of information about a transcription job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TranscriptionJobSummary {
     /// <p>A timestamp that shows when the job was completed.</p>
     #[serde(rename = "CompletionTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub completion_time: Option<f64>,
+    pub completion_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A timestamp that shows when the job was created.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>If the <code>TranscriptionJobStatus</code> field is <code>FAILED</code>, a description of the error.</p>
     #[serde(rename = "FailureReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -510,15 +1472,20 @@ pub struct TranscriptionJobSummary {
     /// <p>The language code for the input speech.</p>
     #[serde(rename = "LanguageCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub language_code: Option<String>,
+    pub language_code: Option<LanguageCode>,
     /// <p>Indicates the location of the output of the transcription job.</p> <p>If the value is <code>CUSTOMER_BUCKET</code> then the location is the S3 bucket specified in the <code>outputBucketName</code> field when the transcription job was started with the <code>StartTranscriptionJob</code> operation.</p> <p>If the value is <code>SERVICE_BUCKET</code> then the output is stored by Amazon Transcribe and can be retrieved using the URI in the <code>GetTranscriptionJob</code> response's <code>TranscriptFileUri</code> field.</p>
     #[serde(rename = "OutputLocationType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub output_location_type: Option<String>,
+    pub output_location_type: Option<OutputLocationType>,
     /// <p>A timestamp that shows when the job started processing.</p>
     #[serde(rename = "StartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the transcription job.</p>
     #[serde(rename = "TranscriptionJobName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -526,7 +1493,7 @@ pub struct TranscriptionJobSummary {
     /// <p>The status of the transcription job. When the status is <code>COMPLETED</code>, use the <code>GetTranscriptionJob</code> operation to get the results of the transcription.</p>
     #[serde(rename = "TranscriptionJobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub transcription_job_status: Option<String>,
+    pub transcription_job_status: Option<TranscriptionJobStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -551,11 +1518,16 @@ pub struct UpdateVocabularyFilterResponse {
     /// <p>The language code of the words in the vocabulary filter.</p>
     #[serde(rename = "LanguageCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub language_code: Option<String>,
+    pub language_code: Option<LanguageCode>,
     /// <p>The date and time that the vocabulary filter was updated.</p>
     #[serde(rename = "LastModifiedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_time: Option<f64>,
+    pub last_modified_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the updated vocabulary filter.</p>
     #[serde(rename = "VocabularyFilterName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -567,7 +1539,7 @@ pub struct UpdateVocabularyFilterResponse {
 pub struct UpdateVocabularyRequest {
     /// <p>The language code of the vocabulary entries.</p>
     #[serde(rename = "LanguageCode")]
-    pub language_code: String,
+    pub language_code: LanguageCode,
     /// <p>An array of strings containing the vocabulary entries.</p>
     #[serde(rename = "Phrases")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -587,11 +1559,16 @@ pub struct UpdateVocabularyResponse {
     /// <p>The language code of the vocabulary entries.</p>
     #[serde(rename = "LanguageCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub language_code: Option<String>,
+    pub language_code: Option<LanguageCode>,
     /// <p>The date and time that the vocabulary was updated.</p>
     #[serde(rename = "LastModifiedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_time: Option<f64>,
+    pub last_modified_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the vocabulary that was updated.</p>
     #[serde(rename = "VocabularyName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -599,7 +1576,7 @@ pub struct UpdateVocabularyResponse {
     /// <p>The processing state of the vocabulary. When the <code>VocabularyState</code> field contains <code>READY</code> the vocabulary is ready to be used in a <code>StartTranscriptionJob</code> request.</p>
     #[serde(rename = "VocabularyState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub vocabulary_state: Option<String>,
+    pub vocabulary_state: Option<VocabularyState>,
 }
 
 /// <p>Provides information about a vocabulary filter.</p>
@@ -609,17 +1586,120 @@ pub struct VocabularyFilterInfo {
     /// <p>The language code of the words in the vocabulary filter.</p>
     #[serde(rename = "LanguageCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub language_code: Option<String>,
+    pub language_code: Option<LanguageCode>,
     /// <p>The date and time that the vocabulary was last updated.</p>
     #[serde(rename = "LastModifiedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_time: Option<f64>,
+    pub last_modified_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the vocabulary filter. The name must be unique in the account that holds the filter.</p>
     #[serde(rename = "VocabularyFilterName")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub vocabulary_filter_name: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum VocabularyFilterMethod {
+    /// `remove`
+    Remove,
+    /// `mask`
+    Mask,
+    /// `tag`
+    Tag,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl VocabularyFilterMethod {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            VocabularyFilterMethod::Remove => "remove",
+            VocabularyFilterMethod::Mask => "mask",
+            VocabularyFilterMethod::Tag => "tag",
+            VocabularyFilterMethod::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for VocabularyFilterMethod {
+    fn default() -> VocabularyFilterMethod {
+        VocabularyFilterMethod::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for VocabularyFilterMethod {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for VocabularyFilterMethod {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for VocabularyFilterMethod {
+    fn from(value: &'a str) -> VocabularyFilterMethod {
+        match value {
+            "remove" => VocabularyFilterMethod::Remove,
+            "mask" => VocabularyFilterMethod::Mask,
+            "tag" => VocabularyFilterMethod::Tag,
+            _ => VocabularyFilterMethod::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for VocabularyFilterMethod {
+    fn from(value: String) -> VocabularyFilterMethod {
+        match value.as_str() {
+            "remove" => VocabularyFilterMethod::Remove,
+            "mask" => VocabularyFilterMethod::Mask,
+            "tag" => VocabularyFilterMethod::Tag,
+            _ => VocabularyFilterMethod::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for VocabularyFilterMethod {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<VocabularyFilterMethod, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for VocabularyFilterMethod {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for VocabularyFilterMethod {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for VocabularyFilterMethod {
+    fn deserialize<D>(deserializer: D) -> Result<VocabularyFilterMethod, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Provides information about a custom vocabulary. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -627,11 +1707,16 @@ pub struct VocabularyInfo {
     /// <p>The language code of the vocabulary entries.</p>
     #[serde(rename = "LanguageCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub language_code: Option<String>,
+    pub language_code: Option<LanguageCode>,
     /// <p>The date and time that the vocabulary was last modified.</p>
     #[serde(rename = "LastModifiedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_time: Option<f64>,
+    pub last_modified_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the vocabulary.</p>
     #[serde(rename = "VocabularyName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -639,9 +1724,107 @@ pub struct VocabularyInfo {
     /// <p>The processing state of the vocabulary. If the state is <code>READY</code> you can use the vocabulary in a <code>StartTranscriptionJob</code> request.</p>
     #[serde(rename = "VocabularyState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub vocabulary_state: Option<String>,
+    pub vocabulary_state: Option<VocabularyState>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum VocabularyState {
+    /// `PENDING`
+    Pending,
+    /// `READY`
+    Ready,
+    /// `FAILED`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl VocabularyState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            VocabularyState::Pending => "PENDING",
+            VocabularyState::Ready => "READY",
+            VocabularyState::Failed => "FAILED",
+            VocabularyState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for VocabularyState {
+    fn default() -> VocabularyState {
+        VocabularyState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for VocabularyState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for VocabularyState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for VocabularyState {
+    fn from(value: &'a str) -> VocabularyState {
+        match value {
+            "PENDING" => VocabularyState::Pending,
+            "READY" => VocabularyState::Ready,
+            "FAILED" => VocabularyState::Failed,
+            _ => VocabularyState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for VocabularyState {
+    fn from(value: String) -> VocabularyState {
+        match value.as_str() {
+            "PENDING" => VocabularyState::Pending,
+            "READY" => VocabularyState::Ready,
+            "FAILED" => VocabularyState::Failed,
+            _ => VocabularyState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for VocabularyState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<VocabularyState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for VocabularyState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
 }
 
+impl ::serde::Serialize for VocabularyState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for VocabularyState {
+    fn deserialize<D>(deserializer: D) -> Result<VocabularyState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// Errors returned by CreateVocabulary
 #[derive(Debug, PartialEq)]
 pub enum CreateVocabularyError {
@@ -1415,11 +2598,12 @@ pub struct TranscribeClient {
 impl TranscribeClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> TranscribeClient {
         TranscribeClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Transcribe"),
         }
     }
 
@@ -1451,6 +2635,7 @@ impl Transcribe for TranscribeClient {
         input: CreateVocabularyRequest,
     ) -> Result<CreateVocabularyResponse, RusotoError<CreateVocabularyError>> {
         let mut request = SignedRequest::new("POST", "transcribe", &self.region, "/");
+        request.set_operation("CreateVocabulary");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Transcribe.CreateVocabulary");
@@ -1479,6 +2664,7 @@ impl Transcribe for TranscribeClient {
         input: CreateVocabularyFilterRequest,
     ) -> Result<CreateVocabularyFilterResponse, RusotoError<CreateVocabularyFilterError>> {
         let mut request = SignedRequest::new("POST", "transcribe", &self.region, "/");
+        request.set_operation("CreateVocabularyFilter");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Transcribe.CreateVocabularyFilter");
@@ -1507,6 +2693,7 @@ impl Transcribe for TranscribeClient {
         input: DeleteTranscriptionJobRequest,
     ) -> Result<(), RusotoError<DeleteTranscriptionJobError>> {
         let mut request = SignedRequest::new("POST", "transcribe", &self.region, "/");
+        request.set_operation("DeleteTranscriptionJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Transcribe.DeleteTranscriptionJob");
@@ -1534,6 +2721,7 @@ impl Transcribe for TranscribeClient {
         input: DeleteVocabularyRequest,
     ) -> Result<(), RusotoError<DeleteVocabularyError>> {
         let mut request = SignedRequest::new("POST", "transcribe", &self.region, "/");
+        request.set_operation("DeleteVocabulary");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Transcribe.DeleteVocabulary");
@@ -1561,6 +2749,7 @@ impl Transcribe for TranscribeClient {
         input: DeleteVocabularyFilterRequest,
     ) -> Result<(), RusotoError<DeleteVocabularyFilterError>> {
         let mut request = SignedRequest::new("POST", "transcribe", &self.region, "/");
+        request.set_operation("DeleteVocabularyFilter");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Transcribe.DeleteVocabularyFilter");
@@ -1588,6 +2777,7 @@ impl Transcribe for TranscribeClient {
         input: GetTranscriptionJobRequest,
     ) -> Result<GetTranscriptionJobResponse, RusotoError<GetTranscriptionJobError>> {
         let mut request = SignedRequest::new("POST", "transcribe", &self.region, "/");
+        request.set_operation("GetTranscriptionJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Transcribe.GetTranscriptionJob");
@@ -1616,6 +2806,7 @@ impl Transcribe for TranscribeClient {
         input: GetVocabularyRequest,
     ) -> Result<GetVocabularyResponse, RusotoError<GetVocabularyError>> {
         let mut request = SignedRequest::new("POST", "transcribe", &self.region, "/");
+        request.set_operation("GetVocabulary");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Transcribe.GetVocabulary");
@@ -1643,6 +2834,7 @@ impl Transcribe for TranscribeClient {
         input: GetVocabularyFilterRequest,
     ) -> Result<GetVocabularyFilterResponse, RusotoError<GetVocabularyFilterError>> {
         let mut request = SignedRequest::new("POST", "transcribe", &self.region, "/");
+        request.set_operation("GetVocabularyFilter");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Transcribe.GetVocabularyFilter");
@@ -1671,6 +2863,7 @@ impl Transcribe for TranscribeClient {
         input: ListTranscriptionJobsRequest,
     ) -> Result<ListTranscriptionJobsResponse, RusotoError<ListTranscriptionJobsError>> {
         let mut request = SignedRequest::new("POST", "transcribe", &self.region, "/");
+        request.set_operation("ListTranscriptionJobs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Transcribe.ListTranscriptionJobs");
@@ -1699,6 +2892,7 @@ impl Transcribe for TranscribeClient {
         input: ListVocabulariesRequest,
     ) -> Result<ListVocabulariesResponse, RusotoError<ListVocabulariesError>> {
         let mut request = SignedRequest::new("POST", "transcribe", &self.region, "/");
+        request.set_operation("ListVocabularies");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Transcribe.ListVocabularies");
@@ -1727,6 +2921,7 @@ impl Transcribe for TranscribeClient {
         input: ListVocabularyFiltersRequest,
     ) -> Result<ListVocabularyFiltersResponse, RusotoError<ListVocabularyFiltersError>> {
         let mut request = SignedRequest::new("POST", "transcribe", &self.region, "/");
+        request.set_operation("ListVocabularyFilters");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Transcribe.ListVocabularyFilters");
@@ -1755,6 +2950,7 @@ impl Transcribe for TranscribeClient {
         input: StartTranscriptionJobRequest,
     ) -> Result<StartTranscriptionJobResponse, RusotoError<StartTranscriptionJobError>> {
         let mut request = SignedRequest::new("POST", "transcribe", &self.region, "/");
+        request.set_operation("StartTranscriptionJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Transcribe.StartTranscriptionJob");
@@ -1783,6 +2979,7 @@ impl Transcribe for TranscribeClient {
         input: UpdateVocabularyRequest,
     ) -> Result<UpdateVocabularyResponse, RusotoError<UpdateVocabularyError>> {
         let mut request = SignedRequest::new("POST", "transcribe", &self.region, "/");
+        request.set_operation("UpdateVocabulary");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Transcribe.UpdateVocabulary");
@@ -1811,6 +3008,7 @@ impl Transcribe for TranscribeClient {
         input: UpdateVocabularyFilterRequest,
     ) -> Result<UpdateVocabularyFilterResponse, RusotoError<UpdateVocabularyFilterError>> {
         let mut request = SignedRequest::new("POST", "transcribe", &self.region, "/");
+        request.set_operation("UpdateVocabularyFilter");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Transcribe.UpdateVocabularyFilter");
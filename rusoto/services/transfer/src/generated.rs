@@ -34,7 +34,7 @@ pub struct CreateServerRequest {
     /// <p>The type of VPC endpoint that you want your SFTP server to connect to. You can choose to connect to the public internet or a virtual private cloud (VPC) endpoint. With a VPC endpoint, you can restrict access to your SFTP server and resources only within your VPC.</p>
     #[serde(rename = "EndpointType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub endpoint_type: Option<String>,
+    pub endpoint_type: Option<EndpointType>,
     /// <p>The RSA private key as generated by the <code>ssh-keygen -N "" -f my-new-server-key</code> command.</p> <important> <p>If you aren't planning to migrate existing users from an existing SFTP server to a new AWS SFTP server, don't update the host key. Accidentally changing a server's host key can be disruptive.</p> </important> <p> For more information, see "https://alpha-docs-aws.amazon.com/transfer/latest/userguide/configuring-servers.html#change-host-key" in the <i>AWS SFTP User Guide.</i> </p>
     #[serde(rename = "HostKey")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -46,7 +46,7 @@ pub struct CreateServerRequest {
     /// <p>Specifies the mode of authentication for the SFTP server. The default value is <code>SERVICE_MANAGED</code>, which allows you to store and access SFTP user credentials within the AWS Transfer for SFTP service. Use the <code>API_GATEWAY</code> value to integrate with an identity provider of your choosing. The <code>API_GATEWAY</code> setting requires you to provide an API Gateway endpoint URL to call for authentication using the <code>IdentityProviderDetails</code> parameter.</p>
     #[serde(rename = "IdentityProviderType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub identity_provider_type: Option<String>,
+    pub identity_provider_type: Option<IdentityProviderType>,
     /// <p>A value that allows the service to write your SFTP users' activity to your Amazon CloudWatch logs for monitoring and auditing purposes.</p>
     #[serde(rename = "LoggingRole")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -79,7 +79,7 @@ pub struct CreateUserRequest {
     /// <p>The type of landing directory (folder) you want your users' home directory to be when they log into the SFTP server. If you set it to <code>PATH</code>, the user will see the absolute Amazon S3 bucket paths as is in their SFTP clients. If you set it <code>LOGICAL</code>, you will need to provide mappings in the <code>HomeDirectoryMappings</code> for how you want to make S3 paths visible to your user.</p>
     #[serde(rename = "HomeDirectoryType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub home_directory_type: Option<String>,
+    pub home_directory_type: Option<HomeDirectoryType>,
     /// <p><p>A scope-down policy for your user so you can use the same IAM role across multiple users. This policy scopes down user access to portions of their Amazon S3 bucket. Variables that you can use inside this policy include <code>${Transfer:UserName}</code>, <code>${Transfer:HomeDirectory}</code>, and <code>${Transfer:HomeBucket}</code>.</p> <note> <p>For scope-down policies, AWS Transfer for SFTP stores the policy as a JSON blob, instead of the Amazon Resource Name (ARN) of the policy. You save the policy as a JSON blob and pass it in the <code>Policy</code> argument.</p> <p>For an example of a scope-down policy, see &quot;https://docs.aws.amazon.com/transfer/latest/userguide/users.html#users-policies-scope-down&quot;&gt;Creating a Scope-Down Policy.</p> <p>For more information, see &quot;https://docs.aws.amazon.com/STS/latest/APIReference/API_AssumeRole.html&quot; in the <i>AWS Security Token Service API Reference</i>.</p> </note></p>
     #[serde(rename = "Policy")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -199,7 +199,7 @@ pub struct DescribedServer {
     /// <p>The type of endpoint that your SFTP server is connected to. If your SFTP server is connected to a VPC endpoint, your server isn't accessible over the public internet.</p>
     #[serde(rename = "EndpointType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub endpoint_type: Option<String>,
+    pub endpoint_type: Option<EndpointType>,
     /// <p>This value contains the message-digest algorithm (MD5) hash of the server's host key. This value is equivalent to the output of the <code>ssh-keygen -l -E md5 -f my-new-server-key</code> command.</p>
     #[serde(rename = "HostKeyFingerprint")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -211,7 +211,7 @@ pub struct DescribedServer {
     /// <p>This property defines the mode of authentication method enabled for this service. A value of <code>SERVICE_MANAGED</code> means that you are using this server to store and access SFTP user credentials within the service. A value of <code>API_GATEWAY</code> indicates that you have integrated an API Gateway endpoint that will be invoked for authenticating your user into the service.</p>
     #[serde(rename = "IdentityProviderType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub identity_provider_type: Option<String>,
+    pub identity_provider_type: Option<IdentityProviderType>,
     /// <p>This property is an AWS Identity and Access Management (IAM) entity that allows the server to turn on Amazon CloudWatch logging for Amazon S3 events. When set, user activity can be viewed in your CloudWatch logs.</p>
     #[serde(rename = "LoggingRole")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -223,7 +223,7 @@ pub struct DescribedServer {
     /// <p>The condition of the SFTP server for the server that was described. A value of <code>ONLINE</code> indicates that the server can accept jobs and transfer files. A <code>State</code> value of <code>OFFLINE</code> means that the server cannot perform file transfer operations.</p> <p>The states of <code>STARTING</code> and <code>STOPPING</code> indicate that the server is in an intermediate state, either not fully able to respond, or not fully offline. The values of <code>START_FAILED</code> or <code>STOP_FAILED</code> can indicate an error condition.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<State>,
     /// <p>This property contains the key-value pairs that you can use to search for and group servers that were assigned to the server that was described.</p>
     #[serde(rename = "Tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -252,7 +252,7 @@ pub struct DescribedUser {
     /// <p>The type of landing directory (folder) you mapped for your users' to see when they log into the SFTP server. If you set it to <code>PATH</code>, the user will see the absolute Amazon S3 bucket paths as is in their SFTP clients. If you set it <code>LOGICAL</code>, you will need to provide mappings in the <code>HomeDirectoryMappings</code> for how you want to make S3 paths visible to your user.</p>
     #[serde(rename = "HomeDirectoryType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub home_directory_type: Option<String>,
+    pub home_directory_type: Option<HomeDirectoryType>,
     /// <p>Specifies the name of the policy in use for the described user.</p>
     #[serde(rename = "Policy")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -296,6 +296,104 @@ pub struct EndpointDetails {
     pub vpc_id: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EndpointType {
+    /// `PUBLIC`
+    Public,
+    /// `VPC`
+    Vpc,
+    /// `VPC_ENDPOINT`
+    VpcEndpoint,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EndpointType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EndpointType::Public => "PUBLIC",
+            EndpointType::Vpc => "VPC",
+            EndpointType::VpcEndpoint => "VPC_ENDPOINT",
+            EndpointType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EndpointType {
+    fn default() -> EndpointType {
+        EndpointType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EndpointType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EndpointType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EndpointType {
+    fn from(value: &'a str) -> EndpointType {
+        match value {
+            "PUBLIC" => EndpointType::Public,
+            "VPC" => EndpointType::Vpc,
+            "VPC_ENDPOINT" => EndpointType::VpcEndpoint,
+            _ => EndpointType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EndpointType {
+    fn from(value: String) -> EndpointType {
+        match value.as_str() {
+            "PUBLIC" => EndpointType::Public,
+            "VPC" => EndpointType::Vpc,
+            "VPC_ENDPOINT" => EndpointType::VpcEndpoint,
+            _ => EndpointType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EndpointType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EndpointType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EndpointType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EndpointType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EndpointType {
+    fn deserialize<D>(deserializer: D) -> Result<EndpointType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Represents an object that contains entries and a targets for <code>HomeDirectoryMappings</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct HomeDirectoryMapEntry {
@@ -307,6 +405,99 @@ pub struct HomeDirectoryMapEntry {
     pub target: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum HomeDirectoryType {
+    /// `PATH`
+    Path,
+    /// `LOGICAL`
+    Logical,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl HomeDirectoryType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            HomeDirectoryType::Path => "PATH",
+            HomeDirectoryType::Logical => "LOGICAL",
+            HomeDirectoryType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for HomeDirectoryType {
+    fn default() -> HomeDirectoryType {
+        HomeDirectoryType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for HomeDirectoryType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for HomeDirectoryType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for HomeDirectoryType {
+    fn from(value: &'a str) -> HomeDirectoryType {
+        match value {
+            "PATH" => HomeDirectoryType::Path,
+            "LOGICAL" => HomeDirectoryType::Logical,
+            _ => HomeDirectoryType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for HomeDirectoryType {
+    fn from(value: String) -> HomeDirectoryType {
+        match value.as_str() {
+            "PATH" => HomeDirectoryType::Path,
+            "LOGICAL" => HomeDirectoryType::Logical,
+            _ => HomeDirectoryType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for HomeDirectoryType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<HomeDirectoryType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for HomeDirectoryType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for HomeDirectoryType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for HomeDirectoryType {
+    fn deserialize<D>(deserializer: D) -> Result<HomeDirectoryType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Returns information related to the type of user authentication that is in use for a server's users. A server can have only one method of authentication.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct IdentityProviderDetails {
@@ -320,6 +511,110 @@ pub struct IdentityProviderDetails {
     pub url: Option<String>,
 }
 
+/// <p>The mode of authentication for a server. The default value is <code>SERVICE_MANAGED</code>, which allows you to store and access user credentials within the Transfer Family service.</p> <p>Use <code>AWS_DIRECTORY_SERVICE</code> to provide access to Active Directory groups in Directory Service for Microsoft Active Directory or Microsoft Active Directory in your on-premises environment or in Amazon Web Services using AD Connector. This option also requires you to provide a Directory ID by using the <code>IdentityProviderDetails</code> parameter.</p> <p>Use the <code>API_GATEWAY</code> value to integrate with an identity provider of your choosing. The <code>API_GATEWAY</code> setting requires you to provide an Amazon API Gateway endpoint URL to call for authentication by using the <code>IdentityProviderDetails</code> parameter.</p> <p>Use the <code>AWS_LAMBDA</code> value to directly use an Lambda function as your identity provider. If you choose this value, you must specify the ARN for the Lambda function in the <code>Function</code> parameter for the <code>IdentityProviderDetails</code> data type.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum IdentityProviderType {
+    /// `SERVICE_MANAGED`
+    ServiceManaged,
+    /// `API_GATEWAY`
+    ApiGateway,
+    /// `AWS_DIRECTORY_SERVICE`
+    AwsDirectoryService,
+    /// `AWS_LAMBDA`
+    AwsLambda,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl IdentityProviderType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            IdentityProviderType::ServiceManaged => "SERVICE_MANAGED",
+            IdentityProviderType::ApiGateway => "API_GATEWAY",
+            IdentityProviderType::AwsDirectoryService => "AWS_DIRECTORY_SERVICE",
+            IdentityProviderType::AwsLambda => "AWS_LAMBDA",
+            IdentityProviderType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for IdentityProviderType {
+    fn default() -> IdentityProviderType {
+        IdentityProviderType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for IdentityProviderType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for IdentityProviderType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for IdentityProviderType {
+    fn from(value: &'a str) -> IdentityProviderType {
+        match value {
+            "SERVICE_MANAGED" => IdentityProviderType::ServiceManaged,
+            "API_GATEWAY" => IdentityProviderType::ApiGateway,
+            "AWS_DIRECTORY_SERVICE" => IdentityProviderType::AwsDirectoryService,
+            "AWS_LAMBDA" => IdentityProviderType::AwsLambda,
+            _ => IdentityProviderType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for IdentityProviderType {
+    fn from(value: String) -> IdentityProviderType {
+        match value.as_str() {
+            "SERVICE_MANAGED" => IdentityProviderType::ServiceManaged,
+            "API_GATEWAY" => IdentityProviderType::ApiGateway,
+            "AWS_DIRECTORY_SERVICE" => IdentityProviderType::AwsDirectoryService,
+            "AWS_LAMBDA" => IdentityProviderType::AwsLambda,
+            _ => IdentityProviderType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for IdentityProviderType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<IdentityProviderType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for IdentityProviderType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for IdentityProviderType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for IdentityProviderType {
+    fn deserialize<D>(deserializer: D) -> Result<IdentityProviderType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ImportSshPublicKeyRequest {
@@ -448,11 +743,11 @@ pub struct ListedServer {
     /// <p>The type of VPC endpoint that your SFTP server is connected to. If your SFTP server is connected to a VPC endpoint, your server isn't accessible over the public internet.</p>
     #[serde(rename = "EndpointType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub endpoint_type: Option<String>,
+    pub endpoint_type: Option<EndpointType>,
     /// <p>The authentication method used to validate a user for the server that was specified. This can include Secure Shell (SSH), user name and password combinations, or your own custom authentication method. Valid values include <code>SERVICE_MANAGED</code> or <code>API_GATEWAY</code>.</p>
     #[serde(rename = "IdentityProviderType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub identity_provider_type: Option<String>,
+    pub identity_provider_type: Option<IdentityProviderType>,
     /// <p>The AWS Identity and Access Management entity that allows the server to turn on Amazon CloudWatch logging.</p>
     #[serde(rename = "LoggingRole")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -464,7 +759,7 @@ pub struct ListedServer {
     /// <p>This property describes the condition of the SFTP server for the server that was described. A value of <code>ONLINE</code>&gt; indicates that the server can accept jobs and transfer files. A <code>State</code> value of <code>OFFLINE</code> means that the server cannot perform file transfer operations.</p> <p>The states of <code>STARTING</code> and <code>STOPPING</code> indicate that the server is in an intermediate state, either not fully able to respond, or not fully offline. The values of <code>START_FAILED</code> or <code>STOP_FAILED</code> can indicate an error condition.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<State>,
     /// <p>This property is a numeric value that indicates the number of users that are assigned to the SFTP server you specified with the <code>ServerId</code>.</p>
     #[serde(rename = "UserCount")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -485,7 +780,7 @@ pub struct ListedUser {
     /// <p>The type of landing directory (folder) you mapped for your users' home directory. If you set it to <code>PATH</code>, the user will see the absolute Amazon S3 bucket paths as is in their SFTP clients. If you set it <code>LOGICAL</code>, you will need to provide mappings in the <code>HomeDirectoryMappings</code> for how you want to make S3 paths visible to your user.</p>
     #[serde(rename = "HomeDirectoryType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub home_directory_type: Option<String>,
+    pub home_directory_type: Option<HomeDirectoryType>,
     /// <p>The role in use by this user. A <i>role</i> is an AWS Identity and Access Management (IAM) entity that, in this case, allows the SFTP server to act on a user's behalf. It allows the server to inherit the trust relationship that enables that user to perform file operations to their Amazon S3 bucket.</p>
     #[serde(rename = "Role")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -506,7 +801,12 @@ pub struct ListedUser {
 pub struct SshPublicKey {
     /// <p>The date that the public key was added to the user account.</p>
     #[serde(rename = "DateImported")]
-    pub date_imported: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub date_imported: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The content of the SSH public key as specified by the <code>PublicKeyId</code>.</p>
     #[serde(rename = "SshPublicKeyBody")]
     pub ssh_public_key_body: String,
@@ -523,6 +823,120 @@ pub struct StartServerRequest {
     pub server_id: String,
 }
 
+/// <p>Describes the condition of a file transfer protocol-enabled server with respect to its ability to perform file operations. There are six possible states: <code>OFFLINE</code>, <code>ONLINE</code>, <code>STARTING</code>, <code>STOPPING</code>, <code>START_FAILED</code>, and <code>STOP_FAILED</code>.</p> <p> <code>OFFLINE</code> indicates that the server exists, but that it is not available for file operations. <code>ONLINE</code> indicates that the server is available to perform file operations. <code>STARTING</code> indicates that the server's was instantiated, but the server is not yet available to perform file operations. Under normal conditions, it can take a couple of minutes for the server to be completely operational. Both <code>START_FAILED</code> and <code>STOP_FAILED</code> are error conditions.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum State {
+    /// `OFFLINE`
+    Offline,
+    /// `ONLINE`
+    Online,
+    /// `STARTING`
+    Starting,
+    /// `STOPPING`
+    Stopping,
+    /// `START_FAILED`
+    StartFailed,
+    /// `STOP_FAILED`
+    StopFailed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl State {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            State::Offline => "OFFLINE",
+            State::Online => "ONLINE",
+            State::Starting => "STARTING",
+            State::Stopping => "STOPPING",
+            State::StartFailed => "START_FAILED",
+            State::StopFailed => "STOP_FAILED",
+            State::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for State {
+    fn default() -> State {
+        State::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for State {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for State {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for State {
+    fn from(value: &'a str) -> State {
+        match value {
+            "OFFLINE" => State::Offline,
+            "ONLINE" => State::Online,
+            "STARTING" => State::Starting,
+            "STOPPING" => State::Stopping,
+            "START_FAILED" => State::StartFailed,
+            "STOP_FAILED" => State::StopFailed,
+            _ => State::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for State {
+    fn from(value: String) -> State {
+        match value.as_str() {
+            "OFFLINE" => State::Offline,
+            "ONLINE" => State::Online,
+            "STARTING" => State::Starting,
+            "STOPPING" => State::Stopping,
+            "START_FAILED" => State::StartFailed,
+            "STOP_FAILED" => State::StopFailed,
+            _ => State::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for State {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<State, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for State {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for State {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for State {
+    fn deserialize<D>(deserializer: D) -> Result<State, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct StopServerRequest {
@@ -608,7 +1022,7 @@ pub struct UpdateServerRequest {
     /// <p>The type of endpoint that you want your SFTP server to connect to. You can choose to connect to the public internet or a virtual private cloud (VPC) endpoint. With a VPC endpoint, your SFTP server isn't accessible over the public internet. </p>
     #[serde(rename = "EndpointType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub endpoint_type: Option<String>,
+    pub endpoint_type: Option<EndpointType>,
     /// <p>The RSA private key as generated by <code>ssh-keygen -N "" -f my-new-server-key</code>.</p> <important> <p>If you aren't planning to migrate existing users from an existing SFTP server to a new AWS SFTP server, don't update the host key. Accidentally changing a server's host key can be disruptive.</p> </important> <p> For more information, see "https://docs.aws.amazon.com/transfer/latest/userguide/configuring-servers.html#change-host-key" in the <i>AWS SFTP User Guide.</i> </p>
     #[serde(rename = "HostKey")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -648,7 +1062,7 @@ pub struct UpdateUserRequest {
     /// <p>The type of landing directory (folder) you want your users' home directory to be when they log into the SFTP serve. If you set it to <code>PATH</code>, the user will see the absolute Amazon S3 bucket paths as is in their SFTP clients. If you set it <code>LOGICAL</code>, you will need to provide mappings in the <code>HomeDirectoryMappings</code> for how you want to make S3 paths visible to your user.</p>
     #[serde(rename = "HomeDirectoryType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub home_directory_type: Option<String>,
+    pub home_directory_type: Option<HomeDirectoryType>,
     /// <p><p>Allows you to supply a scope-down policy for your user so you can use the same AWS Identity and Access Management (IAM) role across multiple users. The policy scopes down user access to portions of your Amazon S3 bucket. Variables you can use inside this policy include <code>${Transfer:UserName}</code>, <code>${Transfer:HomeDirectory}</code>, and <code>${Transfer:HomeBucket}</code>.</p> <note> <p>For scope-down policies, AWS Transfer for SFTP stores the policy as a JSON blob, instead of the Amazon Resource Name (ARN) of the policy. You save the policy as a JSON blob and pass it in the <code>Policy</code> argument.</p> <p>For an example of a scope-down policy, see &quot;https://docs.aws.amazon.com/transfer/latest/userguide/users.html#users-policies-scope-down&quot;&gt;Creating a Scope-Down Policy.</p> <p>For more information, see &quot;https://docs.aws.amazon.com/STS/latest/APIReference/API_AssumeRole.html&quot; in the <i>AWS Security Token Service API Reference</i>.</p> </note></p>
     #[serde(rename = "Policy")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1748,11 +2162,12 @@ pub struct TransferClient {
 impl TransferClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> TransferClient {
         TransferClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Transfer"),
         }
     }
 
@@ -1784,6 +2199,7 @@ impl Transfer for TransferClient {
         input: CreateServerRequest,
     ) -> Result<CreateServerResponse, RusotoError<CreateServerError>> {
         let mut request = SignedRequest::new("POST", "transfer", &self.region, "/");
+        request.set_operation("CreateServer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TransferService.CreateServer");
@@ -1811,6 +2227,7 @@ impl Transfer for TransferClient {
         input: CreateUserRequest,
     ) -> Result<CreateUserResponse, RusotoError<CreateUserError>> {
         let mut request = SignedRequest::new("POST", "transfer", &self.region, "/");
+        request.set_operation("CreateUser");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TransferService.CreateUser");
@@ -1838,6 +2255,7 @@ impl Transfer for TransferClient {
         input: DeleteServerRequest,
     ) -> Result<(), RusotoError<DeleteServerError>> {
         let mut request = SignedRequest::new("POST", "transfer", &self.region, "/");
+        request.set_operation("DeleteServer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TransferService.DeleteServer");
@@ -1865,6 +2283,7 @@ impl Transfer for TransferClient {
         input: DeleteSshPublicKeyRequest,
     ) -> Result<(), RusotoError<DeleteSshPublicKeyError>> {
         let mut request = SignedRequest::new("POST", "transfer", &self.region, "/");
+        request.set_operation("DeleteSshPublicKey");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TransferService.DeleteSshPublicKey");
@@ -1892,6 +2311,7 @@ impl Transfer for TransferClient {
         input: DeleteUserRequest,
     ) -> Result<(), RusotoError<DeleteUserError>> {
         let mut request = SignedRequest::new("POST", "transfer", &self.region, "/");
+        request.set_operation("DeleteUser");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TransferService.DeleteUser");
@@ -1919,6 +2339,7 @@ impl Transfer for TransferClient {
         input: DescribeServerRequest,
     ) -> Result<DescribeServerResponse, RusotoError<DescribeServerError>> {
         let mut request = SignedRequest::new("POST", "transfer", &self.region, "/");
+        request.set_operation("DescribeServer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TransferService.DescribeServer");
@@ -1946,6 +2367,7 @@ impl Transfer for TransferClient {
         input: DescribeUserRequest,
     ) -> Result<DescribeUserResponse, RusotoError<DescribeUserError>> {
         let mut request = SignedRequest::new("POST", "transfer", &self.region, "/");
+        request.set_operation("DescribeUser");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TransferService.DescribeUser");
@@ -1973,6 +2395,7 @@ impl Transfer for TransferClient {
         input: ImportSshPublicKeyRequest,
     ) -> Result<ImportSshPublicKeyResponse, RusotoError<ImportSshPublicKeyError>> {
         let mut request = SignedRequest::new("POST", "transfer", &self.region, "/");
+        request.set_operation("ImportSshPublicKey");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TransferService.ImportSshPublicKey");
@@ -2001,6 +2424,7 @@ impl Transfer for TransferClient {
         input: ListServersRequest,
     ) -> Result<ListServersResponse, RusotoError<ListServersError>> {
         let mut request = SignedRequest::new("POST", "transfer", &self.region, "/");
+        request.set_operation("ListServers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TransferService.ListServers");
@@ -2028,6 +2452,7 @@ impl Transfer for TransferClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = SignedRequest::new("POST", "transfer", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TransferService.ListTagsForResource");
@@ -2056,6 +2481,7 @@ impl Transfer for TransferClient {
         input: ListUsersRequest,
     ) -> Result<ListUsersResponse, RusotoError<ListUsersError>> {
         let mut request = SignedRequest::new("POST", "transfer", &self.region, "/");
+        request.set_operation("ListUsers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TransferService.ListUsers");
@@ -2083,6 +2509,7 @@ impl Transfer for TransferClient {
         input: StartServerRequest,
     ) -> Result<(), RusotoError<StartServerError>> {
         let mut request = SignedRequest::new("POST", "transfer", &self.region, "/");
+        request.set_operation("StartServer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TransferService.StartServer");
@@ -2110,6 +2537,7 @@ impl Transfer for TransferClient {
         input: StopServerRequest,
     ) -> Result<(), RusotoError<StopServerError>> {
         let mut request = SignedRequest::new("POST", "transfer", &self.region, "/");
+        request.set_operation("StopServer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TransferService.StopServer");
@@ -2137,6 +2565,7 @@ impl Transfer for TransferClient {
         input: TagResourceRequest,
     ) -> Result<(), RusotoError<TagResourceError>> {
         let mut request = SignedRequest::new("POST", "transfer", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TransferService.TagResource");
@@ -2164,6 +2593,7 @@ impl Transfer for TransferClient {
         input: TestIdentityProviderRequest,
     ) -> Result<TestIdentityProviderResponse, RusotoError<TestIdentityProviderError>> {
         let mut request = SignedRequest::new("POST", "transfer", &self.region, "/");
+        request.set_operation("TestIdentityProvider");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TransferService.TestIdentityProvider");
@@ -2192,6 +2622,7 @@ impl Transfer for TransferClient {
         input: UntagResourceRequest,
     ) -> Result<(), RusotoError<UntagResourceError>> {
         let mut request = SignedRequest::new("POST", "transfer", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TransferService.UntagResource");
@@ -2219,6 +2650,7 @@ impl Transfer for TransferClient {
         input: UpdateServerRequest,
     ) -> Result<UpdateServerResponse, RusotoError<UpdateServerError>> {
         let mut request = SignedRequest::new("POST", "transfer", &self.region, "/");
+        request.set_operation("UpdateServer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TransferService.UpdateServer");
@@ -2246,6 +2678,7 @@ impl Transfer for TransferClient {
         input: UpdateUserRequest,
     ) -> Result<UpdateUserResponse, RusotoError<UpdateUserError>> {
         let mut request = SignedRequest::new("POST", "transfer", &self.region, "/");
+        request.set_operation("UpdateUser");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TransferService.UpdateUser");
@@ -2267,3 +2700,185 @@ impl Transfer for TransferClient {
         }
     }
 }
+impl TransferClient {
+    /// Auto-paginating version of `list_servers`, streaming every page of results.
+    pub fn list_servers_pages(
+        &self,
+        input: ListServersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListServersResponse, ListServersError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: TransferClient, input: ListServersRequest| async move {
+                client.list_servers(input).await
+            },
+            |input: &ListServersRequest, output: &ListServersResponse| {
+                Some(ListServersRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_servers`, streaming the `Servers` of every page of results.
+    pub fn list_servers_items(
+        &self,
+        input: ListServersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListedServer, ListServersError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_servers_pages(input),
+            |output: ListServersResponse| output.servers,
+        )
+    }
+
+    /// Auto-paginating version of `list_tags_for_resource`, streaming every page of results.
+    pub fn list_tags_for_resource_pages(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListTagsForResourceResponse, ListTagsForResourceError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: TransferClient, input: ListTagsForResourceRequest| async move {
+                client.list_tags_for_resource(input).await
+            },
+            |input: &ListTagsForResourceRequest, output: &ListTagsForResourceResponse| {
+                Some(ListTagsForResourceRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_tags_for_resource`, streaming the `Tags` of every page of results.
+    pub fn list_tags_for_resource_items(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Tag, ListTagsForResourceError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_tags_for_resource_pages(input),
+            |output: ListTagsForResourceResponse| output.tags.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_users`, streaming every page of results.
+    pub fn list_users_pages(
+        &self,
+        input: ListUsersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListUsersResponse, ListUsersError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: TransferClient, input: ListUsersRequest| async move {
+                client.list_users(input).await
+            },
+            |input: &ListUsersRequest, output: &ListUsersResponse| {
+                Some(ListUsersRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_users`, streaming the `Users` of every page of results.
+    pub fn list_users_items(
+        &self,
+        input: ListUsersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListedUser, ListUsersError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_users_pages(input),
+            |output: ListUsersResponse| output.users,
+        )
+    }
+}
+impl TransferClient {
+    /// Waits until `describe_server` reports the `ServerOffline` state, polling every 30 seconds up to 120 times.
+    pub async fn wait_until_server_offline(
+        &self,
+        input: DescribeServerRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeServerError>> {
+        let config =
+            rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(30), 120);
+        self.wait_until_server_offline_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_server_offline`, polling as often and as many times as `config` says.
+    pub async fn wait_until_server_offline_with_config(
+        &self,
+        input: DescribeServerRequest,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeServerError>> {
+        rusoto_core::waiter::wait(
+            "ServerOffline",
+            &config,
+            || self.describe_server(input.clone()),
+            |result: &Result<DescribeServerResponse, RusotoError<DescribeServerError>>| match result
+            {
+                Ok(output)
+                    if std::iter::once(&output.server)
+                        .flat_map(|v| v.state.iter())
+                        .any(|v| *v == State::Offline) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if std::iter::once(&output.server)
+                        .flat_map(|v| v.state.iter())
+                        .any(|v| *v == State::StopFailed) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+
+    /// Waits until `describe_server` reports the `ServerOnline` state, polling every 30 seconds up to 120 times.
+    pub async fn wait_until_server_online(
+        &self,
+        input: DescribeServerRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeServerError>> {
+        let config =
+            rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs(30), 120);
+        self.wait_until_server_online_with_config(input, config)
+            .await
+    }
+
+    /// Same as `wait_until_server_online`, polling as often and as many times as `config` says.
+    pub async fn wait_until_server_online_with_config(
+        &self,
+        input: DescribeServerRequest,
+        config: rusoto_core::waiter::WaiterConfig,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeServerError>> {
+        rusoto_core::waiter::wait(
+            "ServerOnline",
+            &config,
+            || self.describe_server(input.clone()),
+            |result: &Result<DescribeServerResponse, RusotoError<DescribeServerError>>| match result
+            {
+                Ok(output)
+                    if std::iter::once(&output.server)
+                        .flat_map(|v| v.state.iter())
+                        .any(|v| *v == State::Online) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Success)
+                }
+                Ok(output)
+                    if std::iter::once(&output.server)
+                        .flat_map(|v| v.state.iter())
+                        .any(|v| *v == State::StartFailed) =>
+                {
+                    Some(rusoto_core::waiter::WaiterState::Failure)
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+}
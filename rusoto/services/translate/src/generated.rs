@@ -71,9 +71,97 @@ pub struct EncryptionKey {
     pub id: String,
     /// <p>The type of encryption key used by Amazon Translate to encrypt custom terminologies.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: EncryptionKeyType,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EncryptionKeyType {
+    /// `KMS`
+    Kms,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EncryptionKeyType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EncryptionKeyType::Kms => "KMS",
+            EncryptionKeyType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EncryptionKeyType {
+    fn default() -> EncryptionKeyType {
+        EncryptionKeyType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EncryptionKeyType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EncryptionKeyType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EncryptionKeyType {
+    fn from(value: &'a str) -> EncryptionKeyType {
+        match value {
+            "KMS" => EncryptionKeyType::Kms,
+            _ => EncryptionKeyType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EncryptionKeyType {
+    fn from(value: String) -> EncryptionKeyType {
+        match value.as_str() {
+            "KMS" => EncryptionKeyType::Kms,
+            _ => EncryptionKeyType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EncryptionKeyType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EncryptionKeyType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EncryptionKeyType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EncryptionKeyType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EncryptionKeyType {
+    fn deserialize<D>(deserializer: D) -> Result<EncryptionKeyType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct GetTerminologyRequest {
@@ -82,7 +170,7 @@ pub struct GetTerminologyRequest {
     pub name: String,
     /// <p>The data format of the custom terminology being retrieved, either CSV or TMX.</p>
     #[serde(rename = "TerminologyDataFormat")]
-    pub terminology_data_format: String,
+    pub terminology_data_format: TerminologyDataFormat,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -111,7 +199,7 @@ pub struct ImportTerminologyRequest {
     pub encryption_key: Option<EncryptionKey>,
     /// <p>The merge strategy of the custom terminology being imported. Currently, only the OVERWRITE merge strategy is supported. In this case, the imported terminology will overwrite an existing terminology of the same name.</p>
     #[serde(rename = "MergeStrategy")]
-    pub merge_strategy: String,
+    pub merge_strategy: MergeStrategy,
     /// <p>The name of the custom terminology being imported.</p>
     #[serde(rename = "Name")]
     pub name: String,
@@ -158,6 +246,124 @@ pub struct JobDetails {
     pub translated_documents_count: Option<i64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum JobStatus {
+    /// `SUBMITTED`
+    Submitted,
+    /// `IN_PROGRESS`
+    InProgress,
+    /// `COMPLETED`
+    Completed,
+    /// `COMPLETED_WITH_ERROR`
+    CompletedWithError,
+    /// `FAILED`
+    Failed,
+    /// `STOP_REQUESTED`
+    StopRequested,
+    /// `STOPPED`
+    Stopped,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl JobStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            JobStatus::Submitted => "SUBMITTED",
+            JobStatus::InProgress => "IN_PROGRESS",
+            JobStatus::Completed => "COMPLETED",
+            JobStatus::CompletedWithError => "COMPLETED_WITH_ERROR",
+            JobStatus::Failed => "FAILED",
+            JobStatus::StopRequested => "STOP_REQUESTED",
+            JobStatus::Stopped => "STOPPED",
+            JobStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for JobStatus {
+    fn default() -> JobStatus {
+        JobStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for JobStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for JobStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for JobStatus {
+    fn from(value: &'a str) -> JobStatus {
+        match value {
+            "SUBMITTED" => JobStatus::Submitted,
+            "IN_PROGRESS" => JobStatus::InProgress,
+            "COMPLETED" => JobStatus::Completed,
+            "COMPLETED_WITH_ERROR" => JobStatus::CompletedWithError,
+            "FAILED" => JobStatus::Failed,
+            "STOP_REQUESTED" => JobStatus::StopRequested,
+            "STOPPED" => JobStatus::Stopped,
+            _ => JobStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for JobStatus {
+    fn from(value: String) -> JobStatus {
+        match value.as_str() {
+            "SUBMITTED" => JobStatus::Submitted,
+            "IN_PROGRESS" => JobStatus::InProgress,
+            "COMPLETED" => JobStatus::Completed,
+            "COMPLETED_WITH_ERROR" => JobStatus::CompletedWithError,
+            "FAILED" => JobStatus::Failed,
+            "STOP_REQUESTED" => JobStatus::StopRequested,
+            "STOPPED" => JobStatus::Stopped,
+            _ => JobStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for JobStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<JobStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for JobStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for JobStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for JobStatus {
+    fn deserialize<D>(deserializer: D) -> Result<JobStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ListTerminologiesRequest {
@@ -214,6 +420,94 @@ pub struct ListTextTranslationJobsResponse {
     pub text_translation_job_properties_list: Option<Vec<TextTranslationJobProperties>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MergeStrategy {
+    /// `OVERWRITE`
+    Overwrite,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl MergeStrategy {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MergeStrategy::Overwrite => "OVERWRITE",
+            MergeStrategy::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for MergeStrategy {
+    fn default() -> MergeStrategy {
+        MergeStrategy::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for MergeStrategy {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for MergeStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for MergeStrategy {
+    fn from(value: &'a str) -> MergeStrategy {
+        match value {
+            "OVERWRITE" => MergeStrategy::Overwrite,
+            _ => MergeStrategy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MergeStrategy {
+    fn from(value: String) -> MergeStrategy {
+        match value.as_str() {
+            "OVERWRITE" => MergeStrategy::Overwrite,
+            _ => MergeStrategy::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for MergeStrategy {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<MergeStrategy, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MergeStrategy {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for MergeStrategy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MergeStrategy {
+    fn deserialize<D>(deserializer: D) -> Result<MergeStrategy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The output configuration properties for a batch translation job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct OutputDataConfig {
@@ -263,7 +557,7 @@ pub struct StartTextTranslationJobResponse {
     /// <p><p>The status of the job. Possible values include:</p> <ul> <li> <p> <code>SUBMITTED</code> - The job has been received and is queued for processing.</p> </li> <li> <p> <code>IN<em>PROGRESS</code> - Amazon Translate is processing the job.</p> </li> <li> <p> <code>COMPLETED</code> - The job was successfully completed and the output is available.</p> </li> <li> <p> <code>COMPLETED</em>WITH<em>ERRORS</code> - The job was completed with errors. The errors can be analyzed in the job&#39;s output.</p> </li> <li> <p> <code>FAILED</code> - The job did not complete. To get details, use the <a>DescribeTextTranslationJob</a> operation.</p> </li> <li> <p> <code>STOP</em>REQUESTED</code> - The user who started the job has requested that it be stopped.</p> </li> <li> <p> <code>STOPPED</code> - The job has been stopped.</p> </li> </ul></p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<JobStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -284,7 +578,7 @@ pub struct StopTextTranslationJobResponse {
     /// <p>The status of the designated job. Upon successful completion, the job's status will be <code>STOPPED</code>.</p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<JobStatus>,
 }
 
 /// <p>The term being translated by the custom terminology.</p>
@@ -315,9 +609,107 @@ pub struct TerminologyData {
     pub file: bytes::Bytes,
     /// <p>The data format of the custom terminology. Either CSV or TMX.</p>
     #[serde(rename = "Format")]
-    pub format: String,
+    pub format: TerminologyDataFormat,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TerminologyDataFormat {
+    /// `CSV`
+    Csv,
+    /// `TMX`
+    Tmx,
+    /// `TSV`
+    Tsv,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TerminologyDataFormat {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TerminologyDataFormat::Csv => "CSV",
+            TerminologyDataFormat::Tmx => "TMX",
+            TerminologyDataFormat::Tsv => "TSV",
+            TerminologyDataFormat::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TerminologyDataFormat {
+    fn default() -> TerminologyDataFormat {
+        TerminologyDataFormat::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TerminologyDataFormat {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TerminologyDataFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TerminologyDataFormat {
+    fn from(value: &'a str) -> TerminologyDataFormat {
+        match value {
+            "CSV" => TerminologyDataFormat::Csv,
+            "TMX" => TerminologyDataFormat::Tmx,
+            "TSV" => TerminologyDataFormat::Tsv,
+            _ => TerminologyDataFormat::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TerminologyDataFormat {
+    fn from(value: String) -> TerminologyDataFormat {
+        match value.as_str() {
+            "CSV" => TerminologyDataFormat::Csv,
+            "TMX" => TerminologyDataFormat::Tmx,
+            "TSV" => TerminologyDataFormat::Tsv,
+            _ => TerminologyDataFormat::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TerminologyDataFormat {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TerminologyDataFormat, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TerminologyDataFormat {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TerminologyDataFormat {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for TerminologyDataFormat {
+    fn deserialize<D>(deserializer: D) -> Result<TerminologyDataFormat, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The location of the custom terminology data.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -340,8 +732,13 @@ pub struct TerminologyProperties {
     pub arn: Option<String>,
     /// <p>The time at which the custom terminology was created, based on the timestamp.</p>
     #[serde(rename = "CreatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The description of the custom terminology properties.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -352,8 +749,13 @@ pub struct TerminologyProperties {
     pub encryption_key: Option<EncryptionKey>,
     /// <p>The time at which the custom terminology was last update, based on the timestamp.</p>
     #[serde(rename = "LastUpdatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_at: Option<f64>,
+    pub last_updated_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the custom terminology.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -387,15 +789,25 @@ pub struct TextTranslationJobFilter {
     /// <p>Filters the list of jobs based by job status.</p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<JobStatus>,
     /// <p>Filters the list of jobs based on the time that the job was submitted for processing and returns only the jobs submitted after the specified time. Jobs are returned in descending order, newest to oldest.</p>
     #[serde(rename = "SubmittedAfterTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submitted_after_time: Option<f64>,
+    pub submitted_after_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Filters the list of jobs based on the time that the job was submitted for processing and returns only the jobs submitted before the specified time. Jobs are returned in ascending order, oldest to newest.</p>
     #[serde(rename = "SubmittedBeforeTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submitted_before_time: Option<f64>,
+    pub submitted_before_time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>Provides information about a translation job.</p>
@@ -408,8 +820,13 @@ pub struct TextTranslationJobProperties {
     pub data_access_role_arn: Option<String>,
     /// <p>The time at which the translation job ended.</p>
     #[serde(rename = "EndTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The input configuration properties that were specified when the job was requested.</p>
     #[serde(rename = "InputDataConfig")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -429,7 +846,7 @@ pub struct TextTranslationJobProperties {
     /// <p>The status of the translation job.</p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<JobStatus>,
     /// <p>An explanation of any errors that may have occured during the translation job.</p>
     #[serde(rename = "Message")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -444,8 +861,13 @@ pub struct TextTranslationJobProperties {
     pub source_language_code: Option<String>,
     /// <p>The time at which the translation job was submitted.</p>
     #[serde(rename = "SubmittedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submitted_time: Option<f64>,
+    pub submitted_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The language code of the language of the target text. The language must be a language supported by Amazon Translate.</p>
     #[serde(rename = "TargetLanguageCodes")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1041,11 +1463,12 @@ pub struct TranslateClient {
 impl TranslateClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> TranslateClient {
         TranslateClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "Translate"),
         }
     }
 
@@ -1077,6 +1500,7 @@ impl Translate for TranslateClient {
         input: DeleteTerminologyRequest,
     ) -> Result<(), RusotoError<DeleteTerminologyError>> {
         let mut request = SignedRequest::new("POST", "translate", &self.region, "/");
+        request.set_operation("DeleteTerminology");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1108,6 +1532,7 @@ impl Translate for TranslateClient {
     ) -> Result<DescribeTextTranslationJobResponse, RusotoError<DescribeTextTranslationJobError>>
     {
         let mut request = SignedRequest::new("POST", "translate", &self.region, "/");
+        request.set_operation("DescribeTextTranslationJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1139,6 +1564,7 @@ impl Translate for TranslateClient {
         input: GetTerminologyRequest,
     ) -> Result<GetTerminologyResponse, RusotoError<GetTerminologyError>> {
         let mut request = SignedRequest::new("POST", "translate", &self.region, "/");
+        request.set_operation("GetTerminology");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1169,6 +1595,7 @@ impl Translate for TranslateClient {
         input: ImportTerminologyRequest,
     ) -> Result<ImportTerminologyResponse, RusotoError<ImportTerminologyError>> {
         let mut request = SignedRequest::new("POST", "translate", &self.region, "/");
+        request.set_operation("ImportTerminology");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1200,6 +1627,7 @@ impl Translate for TranslateClient {
         input: ListTerminologiesRequest,
     ) -> Result<ListTerminologiesResponse, RusotoError<ListTerminologiesError>> {
         let mut request = SignedRequest::new("POST", "translate", &self.region, "/");
+        request.set_operation("ListTerminologies");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1231,6 +1659,7 @@ impl Translate for TranslateClient {
         input: ListTextTranslationJobsRequest,
     ) -> Result<ListTextTranslationJobsResponse, RusotoError<ListTextTranslationJobsError>> {
         let mut request = SignedRequest::new("POST", "translate", &self.region, "/");
+        request.set_operation("ListTextTranslationJobs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1262,6 +1691,7 @@ impl Translate for TranslateClient {
         input: StartTextTranslationJobRequest,
     ) -> Result<StartTextTranslationJobResponse, RusotoError<StartTextTranslationJobError>> {
         let mut request = SignedRequest::new("POST", "translate", &self.region, "/");
+        request.set_operation("StartTextTranslationJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1293,6 +1723,7 @@ impl Translate for TranslateClient {
         input: StopTextTranslationJobRequest,
     ) -> Result<StopTextTranslationJobResponse, RusotoError<StopTextTranslationJobError>> {
         let mut request = SignedRequest::new("POST", "translate", &self.region, "/");
+        request.set_operation("StopTextTranslationJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1324,6 +1755,7 @@ impl Translate for TranslateClient {
         input: TranslateTextRequest,
     ) -> Result<TranslateTextResponse, RusotoError<TranslateTextError>> {
         let mut request = SignedRequest::new("POST", "translate", &self.region, "/");
+        request.set_operation("TranslateText");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1348,3 +1780,38 @@ impl Translate for TranslateClient {
         }
     }
 }
+impl TranslateClient {
+    /// Auto-paginating version of `list_terminologies`, streaming every page of results.
+    pub fn list_terminologies_pages(
+        &self,
+        input: ListTerminologiesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListTerminologiesResponse, ListTerminologiesError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: TranslateClient, input: ListTerminologiesRequest| async move {
+                client.list_terminologies(input).await
+            },
+            |input: &ListTerminologiesRequest, output: &ListTerminologiesResponse| {
+                Some(ListTerminologiesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_terminologies`, streaming the `TerminologyPropertiesList` of every page of results.
+    pub fn list_terminologies_items(
+        &self,
+        input: ListTerminologiesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<TerminologyProperties, ListTerminologiesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_terminologies_pages(input),
+            |output: ListTerminologiesResponse| {
+                output.terminology_properties_list.unwrap_or_default()
+            },
+        )
+    }
+}
@@ -48,7 +48,7 @@ pub struct ActivatedRule {
     /// <p>The rule type, either <code>REGULAR</code>, as defined by <a>Rule</a>, <code>RATE_BASED</code>, as defined by <a>RateBasedRule</a>, or <code>GROUP</code>, as defined by <a>RuleGroup</a>. The default is REGULAR. Although this field is optional, be aware that if you try to add a RATE_BASED rule to a web ACL without setting the type, the <a>UpdateWebACL</a> request will fail because the request tries to add a REGULAR rule with the specified ID, which does not exist. </p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<WafRuleType>,
 }
 
 /// <p>In a <a>GetByteMatchSet</a> request, <code>ByteMatchSet</code> is a complex type that contains the <code>ByteMatchSetId</code> and <code>Name</code> of a <code>ByteMatchSet</code>, and the values that you specified when you updated the <code>ByteMatchSet</code>. </p> <p>A complex type that contains <code>ByteMatchTuple</code> objects, which specify the parts of web requests that you want AWS WAF to inspect and the values that you want AWS WAF to search for. If a <code>ByteMatchSet</code> contains more than one <code>ByteMatchTuple</code> object, a request needs to match the settings in only one <code>ByteMatchTuple</code> to be considered a match.</p>
@@ -85,7 +85,7 @@ pub struct ByteMatchSetSummary {
 pub struct ByteMatchSetUpdate {
     /// <p>Specifies whether to insert or delete a <a>ByteMatchTuple</a>.</p>
     #[serde(rename = "Action")]
-    pub action: String,
+    pub action: ChangeAction,
     /// <p>Information about the part of a web request that you want AWS WAF to inspect and the value that you want AWS WAF to search for. If you specify <code>DELETE</code> for the value of <code>Action</code>, the <code>ByteMatchTuple</code> values must exactly match the values in the <code>ByteMatchTuple</code> that you want to delete from the <code>ByteMatchSet</code>.</p>
     #[serde(rename = "ByteMatchTuple")]
     pub byte_match_tuple: ByteMatchTuple,
@@ -99,7 +99,7 @@ pub struct ByteMatchTuple {
     pub field_to_match: FieldToMatch,
     /// <p>Within the portion of a web request that you want to search (for example, in the query string, if any), specify where you want AWS WAF to search. Valid values include the following:</p> <p> <b>CONTAINS</b> </p> <p>The specified part of the web request must include the value of <code>TargetString</code>, but the location doesn't matter.</p> <p> <b>CONTAINS_WORD</b> </p> <p>The specified part of the web request must include the value of <code>TargetString</code>, and <code>TargetString</code> must contain only alphanumeric characters or underscore (A-Z, a-z, 0-9, or _). In addition, <code>TargetString</code> must be a word, which means one of the following:</p> <ul> <li> <p> <code>TargetString</code> exactly matches the value of the specified part of the web request, such as the value of a header.</p> </li> <li> <p> <code>TargetString</code> is at the beginning of the specified part of the web request and is followed by a character other than an alphanumeric character or underscore (_), for example, <code>BadBot;</code>.</p> </li> <li> <p> <code>TargetString</code> is at the end of the specified part of the web request and is preceded by a character other than an alphanumeric character or underscore (_), for example, <code>;BadBot</code>.</p> </li> <li> <p> <code>TargetString</code> is in the middle of the specified part of the web request and is preceded and followed by characters other than alphanumeric characters or underscore (_), for example, <code>-BadBot;</code>.</p> </li> </ul> <p> <b>EXACTLY</b> </p> <p>The value of the specified part of the web request must exactly match the value of <code>TargetString</code>.</p> <p> <b>STARTS_WITH</b> </p> <p>The value of <code>TargetString</code> must appear at the beginning of the specified part of the web request.</p> <p> <b>ENDS_WITH</b> </p> <p>The value of <code>TargetString</code> must appear at the end of the specified part of the web request.</p>
     #[serde(rename = "PositionalConstraint")]
-    pub positional_constraint: String,
+    pub positional_constraint: PositionalConstraint,
     /// <p>The value that you want AWS WAF to search for. AWS WAF searches for the specified string in the part of web requests that you specified in <code>FieldToMatch</code>. The maximum length of the value is 50 bytes.</p> <p>Valid values depend on the values that you specified for <code>FieldToMatch</code>:</p> <ul> <li> <p> <code>HEADER</code>: The value that you want AWS WAF to search for in the request header that you specified in <a>FieldToMatch</a>, for example, the value of the <code>User-Agent</code> or <code>Referer</code> header.</p> </li> <li> <p> <code>METHOD</code>: The HTTP method, which indicates the type of operation specified in the request. CloudFront supports the following methods: <code>DELETE</code>, <code>GET</code>, <code>HEAD</code>, <code>OPTIONS</code>, <code>PATCH</code>, <code>POST</code>, and <code>PUT</code>.</p> </li> <li> <p> <code>QUERY_STRING</code>: The value that you want AWS WAF to search for in the query string, which is the part of a URL that appears after a <code>?</code> character.</p> </li> <li> <p> <code>URI</code>: The value that you want AWS WAF to search for in the part of a URL that identifies a resource, for example, <code>/images/daily-ad.jpg</code>.</p> </li> <li> <p> <code>BODY</code>: The part of a request that contains any additional data that you want to send to your web server as the HTTP request body, such as data from a form. The request body immediately follows the request headers. Note that only the first <code>8192</code> bytes of the request body are forwarded to AWS WAF for inspection. To allow or block requests based on the length of the body, you can create a size constraint set. For more information, see <a>CreateSizeConstraintSet</a>. </p> </li> <li> <p> <code>SINGLE_QUERY_ARG</code>: The parameter in the query string that you will inspect, such as <i>UserName</i> or <i>SalesRegion</i>. The maximum length for <code>SINGLE_QUERY_ARG</code> is 30 characters.</p> </li> <li> <p> <code>ALL_QUERY_ARGS</code>: Similar to <code>SINGLE_QUERY_ARG</code>, but instead of inspecting a single parameter, AWS WAF inspects all parameters within the query string for the value or regex pattern that you specify in <code>TargetString</code>.</p> </li> </ul> <p>If <code>TargetString</code> includes alphabetic characters A-Z and a-z, note that the value is case sensitive.</p> <p> <b>If you're using the AWS WAF API</b> </p> <p>Specify a base64-encoded version of the value. The maximum length of the value before you base64-encode it is 50 bytes.</p> <p>For example, suppose the value of <code>Type</code> is <code>HEADER</code> and the value of <code>Data</code> is <code>User-Agent</code>. If you want to search the <code>User-Agent</code> header for the value <code>BadBot</code>, you base64-encode <code>BadBot</code> using MIME base64-encoding and include the resulting value, <code>QmFkQm90</code>, in the value of <code>TargetString</code>.</p> <p> <b>If you're using the AWS CLI or one of the AWS SDKs</b> </p> <p>The value that you want AWS WAF to search for. The SDK automatically base64 encodes the value.</p>
     #[serde(rename = "TargetString")]
     #[serde(
@@ -110,9 +110,313 @@ pub struct ByteMatchTuple {
     pub target_string: bytes::Bytes,
     /// <p>Text transformations eliminate some of the unusual formatting that attackers use in web requests in an effort to bypass AWS WAF. If you specify a transformation, AWS WAF performs the transformation on <code>TargetString</code> before inspecting a request for a match.</p> <p>You can only specify a single type of TextTransformation.</p> <p> <b>CMD_LINE</b> </p> <p>When you're concerned that attackers are injecting an operating system command line command and using unusual formatting to disguise some or all of the command, use this option to perform the following transformations:</p> <ul> <li> <p>Delete the following characters: \ " ' ^</p> </li> <li> <p>Delete spaces before the following characters: / (</p> </li> <li> <p>Replace the following characters with a space: , ;</p> </li> <li> <p>Replace multiple spaces with one space</p> </li> <li> <p>Convert uppercase letters (A-Z) to lowercase (a-z)</p> </li> </ul> <p> <b>COMPRESS_WHITE_SPACE</b> </p> <p>Use this option to replace the following characters with a space character (decimal 32):</p> <ul> <li> <p>\f, formfeed, decimal 12</p> </li> <li> <p>\t, tab, decimal 9</p> </li> <li> <p>\n, newline, decimal 10</p> </li> <li> <p>\r, carriage return, decimal 13</p> </li> <li> <p>\v, vertical tab, decimal 11</p> </li> <li> <p>non-breaking space, decimal 160</p> </li> </ul> <p> <code>COMPRESS_WHITE_SPACE</code> also replaces multiple spaces with one space.</p> <p> <b>HTML_ENTITY_DECODE</b> </p> <p>Use this option to replace HTML-encoded characters with unencoded characters. <code>HTML_ENTITY_DECODE</code> performs the following operations:</p> <ul> <li> <p>Replaces <code>(ampersand)quot;</code> with <code>"</code> </p> </li> <li> <p>Replaces <code>(ampersand)nbsp;</code> with a non-breaking space, decimal 160</p> </li> <li> <p>Replaces <code>(ampersand)lt;</code> with a "less than" symbol</p> </li> <li> <p>Replaces <code>(ampersand)gt;</code> with <code>&gt;</code> </p> </li> <li> <p>Replaces characters that are represented in hexadecimal format, <code>(ampersand)#xhhhh;</code>, with the corresponding characters</p> </li> <li> <p>Replaces characters that are represented in decimal format, <code>(ampersand)#nnnn;</code>, with the corresponding characters</p> </li> </ul> <p> <b>LOWERCASE</b> </p> <p>Use this option to convert uppercase letters (A-Z) to lowercase (a-z).</p> <p> <b>URL_DECODE</b> </p> <p>Use this option to decode a URL-encoded value.</p> <p> <b>NONE</b> </p> <p>Specify <code>NONE</code> if you don't want to perform any text transformations.</p>
     #[serde(rename = "TextTransformation")]
-    pub text_transformation: String,
+    pub text_transformation: TextTransformation,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ChangeAction {
+    /// `INSERT`
+    Insert,
+    /// `DELETE`
+    Delete,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ChangeAction {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ChangeAction::Insert => "INSERT",
+            ChangeAction::Delete => "DELETE",
+            ChangeAction::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ChangeAction {
+    fn default() -> ChangeAction {
+        ChangeAction::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ChangeAction {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ChangeAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ChangeAction {
+    fn from(value: &'a str) -> ChangeAction {
+        match value {
+            "INSERT" => ChangeAction::Insert,
+            "DELETE" => ChangeAction::Delete,
+            _ => ChangeAction::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ChangeAction {
+    fn from(value: String) -> ChangeAction {
+        match value.as_str() {
+            "INSERT" => ChangeAction::Insert,
+            "DELETE" => ChangeAction::Delete,
+            _ => ChangeAction::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ChangeAction {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ChangeAction, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ChangeAction {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ChangeAction {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ChangeAction {
+    fn deserialize<D>(deserializer: D) -> Result<ChangeAction, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ChangeTokenStatus {
+    /// `PROVISIONED`
+    Provisioned,
+    /// `PENDING`
+    Pending,
+    /// `INSYNC`
+    Insync,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ChangeTokenStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ChangeTokenStatus::Provisioned => "PROVISIONED",
+            ChangeTokenStatus::Pending => "PENDING",
+            ChangeTokenStatus::Insync => "INSYNC",
+            ChangeTokenStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ChangeTokenStatus {
+    fn default() -> ChangeTokenStatus {
+        ChangeTokenStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ChangeTokenStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ChangeTokenStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ChangeTokenStatus {
+    fn from(value: &'a str) -> ChangeTokenStatus {
+        match value {
+            "PROVISIONED" => ChangeTokenStatus::Provisioned,
+            "PENDING" => ChangeTokenStatus::Pending,
+            "INSYNC" => ChangeTokenStatus::Insync,
+            _ => ChangeTokenStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ChangeTokenStatus {
+    fn from(value: String) -> ChangeTokenStatus {
+        match value.as_str() {
+            "PROVISIONED" => ChangeTokenStatus::Provisioned,
+            "PENDING" => ChangeTokenStatus::Pending,
+            "INSYNC" => ChangeTokenStatus::Insync,
+            _ => ChangeTokenStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ChangeTokenStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ChangeTokenStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ChangeTokenStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ChangeTokenStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ChangeTokenStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ChangeTokenStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ComparisonOperator {
+    /// `EQ`
+    Eq,
+    /// `NE`
+    Ne,
+    /// `LE`
+    Le,
+    /// `LT`
+    Lt,
+    /// `GE`
+    Ge,
+    /// `GT`
+    Gt,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ComparisonOperator {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ComparisonOperator::Eq => "EQ",
+            ComparisonOperator::Ne => "NE",
+            ComparisonOperator::Le => "LE",
+            ComparisonOperator::Lt => "LT",
+            ComparisonOperator::Ge => "GE",
+            ComparisonOperator::Gt => "GT",
+            ComparisonOperator::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ComparisonOperator {
+    fn default() -> ComparisonOperator {
+        ComparisonOperator::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ComparisonOperator {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ComparisonOperator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ComparisonOperator {
+    fn from(value: &'a str) -> ComparisonOperator {
+        match value {
+            "EQ" => ComparisonOperator::Eq,
+            "NE" => ComparisonOperator::Ne,
+            "LE" => ComparisonOperator::Le,
+            "LT" => ComparisonOperator::Lt,
+            "GE" => ComparisonOperator::Ge,
+            "GT" => ComparisonOperator::Gt,
+            _ => ComparisonOperator::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ComparisonOperator {
+    fn from(value: String) -> ComparisonOperator {
+        match value.as_str() {
+            "EQ" => ComparisonOperator::Eq,
+            "NE" => ComparisonOperator::Ne,
+            "LE" => ComparisonOperator::Le,
+            "LT" => ComparisonOperator::Lt,
+            "GE" => ComparisonOperator::Ge,
+            "GT" => ComparisonOperator::Gt,
+            _ => ComparisonOperator::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ComparisonOperator {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ComparisonOperator, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ComparisonOperator {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ComparisonOperator {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ComparisonOperator {
+    fn deserialize<D>(deserializer: D) -> Result<ComparisonOperator, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateByteMatchSetRequest {
@@ -199,7 +503,7 @@ pub struct CreateRateBasedRuleRequest {
     pub name: String,
     /// <p>The field that AWS WAF uses to determine if requests are likely arriving from a single source and thus subject to rate monitoring. The only valid value for <code>RateKey</code> is <code>IP</code>. <code>IP</code> indicates that requests that arrive from the same IP address are subject to the <code>RateLimit</code> that is specified in the <code>RateBasedRule</code>.</p>
     #[serde(rename = "RateKey")]
-    pub rate_key: String,
+    pub rate_key: RateKey,
     /// <p>The maximum number of requests, which have an identical value in the field that is specified by <code>RateKey</code>, allowed in a five-minute period. If the number of requests exceeds the <code>RateLimit</code> and the other predicates specified in the rule are also met, AWS WAF triggers the action that is specified for this rule.</p>
     #[serde(rename = "RateLimit")]
     pub rate_limit: i64,
@@ -723,7 +1027,7 @@ pub struct FieldToMatch {
     pub data: Option<String>,
     /// <p><p>The part of the web request that you want AWS WAF to search for a specified string. Parts of a request that you can search include the following:</p> <ul> <li> <p> <code>HEADER</code>: A specified request header, for example, the value of the <code>User-Agent</code> or <code>Referer</code> header. If you choose <code>HEADER</code> for the type, specify the name of the header in <code>Data</code>.</p> </li> <li> <p> <code>METHOD</code>: The HTTP method, which indicated the type of operation that the request is asking the origin to perform. Amazon CloudFront supports the following methods: <code>DELETE</code>, <code>GET</code>, <code>HEAD</code>, <code>OPTIONS</code>, <code>PATCH</code>, <code>POST</code>, and <code>PUT</code>.</p> </li> <li> <p> <code>QUERY<em>STRING</code>: A query string, which is the part of a URL that appears after a <code>?</code> character, if any.</p> </li> <li> <p> <code>URI</code>: The part of a web request that identifies a resource, for example, <code>/images/daily-ad.jpg</code>.</p> </li> <li> <p> <code>BODY</code>: The part of a request that contains any additional data that you want to send to your web server as the HTTP request body, such as data from a form. The request body immediately follows the request headers. Note that only the first <code>8192</code> bytes of the request body are forwarded to AWS WAF for inspection. To allow or block requests based on the length of the body, you can create a size constraint set. For more information, see <a>CreateSizeConstraintSet</a>. </p> </li> <li> <p> <code>SINGLE</em>QUERY<em>ARG</code>: The parameter in the query string that you will inspect, such as <i>UserName</i> or <i>SalesRegion</i>. The maximum length for <code>SINGLE</em>QUERY<em>ARG</code> is 30 characters.</p> </li> <li> <p> <code>ALL</em>QUERY<em>ARGS</code>: Similar to <code>SINGLE</em>QUERY_ARG</code>, but rather than inspecting a single parameter, AWS WAF will inspect all parameters within the query for the value or regex pattern that you specify in <code>TargetString</code>.</p> </li> </ul></p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: MatchFieldType,
 }
 
 /// <p>The country from which web requests originate that you want AWS WAF to search for.</p>
@@ -731,12 +1035,1428 @@ pub struct FieldToMatch {
 pub struct GeoMatchConstraint {
     /// <p>The type of geographical area you want AWS WAF to search for. Currently <code>Country</code> is the only valid value.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: GeoMatchConstraintType,
     /// <p>The country that you want AWS WAF to search for.</p>
     #[serde(rename = "Value")]
-    pub value: String,
+    pub value: GeoMatchConstraintValue,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum GeoMatchConstraintType {
+    /// `Country`
+    Country,
+    /// A value not known to this version of the crate
+    Unknown(String),
 }
 
+impl GeoMatchConstraintType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            GeoMatchConstraintType::Country => "Country",
+            GeoMatchConstraintType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for GeoMatchConstraintType {
+    fn default() -> GeoMatchConstraintType {
+        GeoMatchConstraintType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for GeoMatchConstraintType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for GeoMatchConstraintType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for GeoMatchConstraintType {
+    fn from(value: &'a str) -> GeoMatchConstraintType {
+        match value {
+            "Country" => GeoMatchConstraintType::Country,
+            _ => GeoMatchConstraintType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for GeoMatchConstraintType {
+    fn from(value: String) -> GeoMatchConstraintType {
+        match value.as_str() {
+            "Country" => GeoMatchConstraintType::Country,
+            _ => GeoMatchConstraintType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for GeoMatchConstraintType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<GeoMatchConstraintType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for GeoMatchConstraintType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for GeoMatchConstraintType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for GeoMatchConstraintType {
+    fn deserialize<D>(deserializer: D) -> Result<GeoMatchConstraintType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum GeoMatchConstraintValue {
+    /// `AF`
+    Af,
+    /// `AX`
+    Ax,
+    /// `AL`
+    Al,
+    /// `DZ`
+    Dz,
+    /// `AS`
+    As,
+    /// `AD`
+    Ad,
+    /// `AO`
+    Ao,
+    /// `AI`
+    Ai,
+    /// `AQ`
+    Aq,
+    /// `AG`
+    Ag,
+    /// `AR`
+    Ar,
+    /// `AM`
+    Am,
+    /// `AW`
+    Aw,
+    /// `AU`
+    Au,
+    /// `AT`
+    At,
+    /// `AZ`
+    Az,
+    /// `BS`
+    Bs,
+    /// `BH`
+    Bh,
+    /// `BD`
+    Bd,
+    /// `BB`
+    Bb,
+    /// `BY`
+    By,
+    /// `BE`
+    Be,
+    /// `BZ`
+    Bz,
+    /// `BJ`
+    Bj,
+    /// `BM`
+    Bm,
+    /// `BT`
+    Bt,
+    /// `BO`
+    Bo,
+    /// `BQ`
+    Bq,
+    /// `BA`
+    Ba,
+    /// `BW`
+    Bw,
+    /// `BV`
+    Bv,
+    /// `BR`
+    Br,
+    /// `IO`
+    Io,
+    /// `BN`
+    Bn,
+    /// `BG`
+    Bg,
+    /// `BF`
+    Bf,
+    /// `BI`
+    Bi,
+    /// `KH`
+    Kh,
+    /// `CM`
+    Cm,
+    /// `CA`
+    Ca,
+    /// `CV`
+    Cv,
+    /// `KY`
+    Ky,
+    /// `CF`
+    Cf,
+    /// `TD`
+    Td,
+    /// `CL`
+    Cl,
+    /// `CN`
+    Cn,
+    /// `CX`
+    Cx,
+    /// `CC`
+    Cc,
+    /// `CO`
+    Co,
+    /// `KM`
+    Km,
+    /// `CG`
+    Cg,
+    /// `CD`
+    Cd,
+    /// `CK`
+    Ck,
+    /// `CR`
+    Cr,
+    /// `CI`
+    Ci,
+    /// `HR`
+    Hr,
+    /// `CU`
+    Cu,
+    /// `CW`
+    Cw,
+    /// `CY`
+    Cy,
+    /// `CZ`
+    Cz,
+    /// `DK`
+    Dk,
+    /// `DJ`
+    Dj,
+    /// `DM`
+    Dm,
+    /// `DO`
+    Do,
+    /// `EC`
+    Ec,
+    /// `EG`
+    Eg,
+    /// `SV`
+    Sv,
+    /// `GQ`
+    Gq,
+    /// `ER`
+    Er,
+    /// `EE`
+    Ee,
+    /// `ET`
+    Et,
+    /// `FK`
+    Fk,
+    /// `FO`
+    Fo,
+    /// `FJ`
+    Fj,
+    /// `FI`
+    Fi,
+    /// `FR`
+    Fr,
+    /// `GF`
+    Gf,
+    /// `PF`
+    Pf,
+    /// `TF`
+    Tf,
+    /// `GA`
+    Ga,
+    /// `GM`
+    Gm,
+    /// `GE`
+    Ge,
+    /// `DE`
+    De,
+    /// `GH`
+    Gh,
+    /// `GI`
+    Gi,
+    /// `GR`
+    Gr,
+    /// `GL`
+    Gl,
+    /// `GD`
+    Gd,
+    /// `GP`
+    Gp,
+    /// `GU`
+    Gu,
+    /// `GT`
+    Gt,
+    /// `GG`
+    Gg,
+    /// `GN`
+    Gn,
+    /// `GW`
+    Gw,
+    /// `GY`
+    Gy,
+    /// `HT`
+    Ht,
+    /// `HM`
+    Hm,
+    /// `VA`
+    Va,
+    /// `HN`
+    Hn,
+    /// `HK`
+    Hk,
+    /// `HU`
+    Hu,
+    /// `IS`
+    Is,
+    /// `IN`
+    In,
+    /// `ID`
+    Id,
+    /// `IR`
+    Ir,
+    /// `IQ`
+    Iq,
+    /// `IE`
+    Ie,
+    /// `IM`
+    Im,
+    /// `IL`
+    Il,
+    /// `IT`
+    It,
+    /// `JM`
+    Jm,
+    /// `JP`
+    Jp,
+    /// `JE`
+    Je,
+    /// `JO`
+    Jo,
+    /// `KZ`
+    Kz,
+    /// `KE`
+    Ke,
+    /// `KI`
+    Ki,
+    /// `KP`
+    Kp,
+    /// `KR`
+    Kr,
+    /// `KW`
+    Kw,
+    /// `KG`
+    Kg,
+    /// `LA`
+    La,
+    /// `LV`
+    Lv,
+    /// `LB`
+    Lb,
+    /// `LS`
+    Ls,
+    /// `LR`
+    Lr,
+    /// `LY`
+    Ly,
+    /// `LI`
+    Li,
+    /// `LT`
+    Lt,
+    /// `LU`
+    Lu,
+    /// `MO`
+    Mo,
+    /// `MK`
+    Mk,
+    /// `MG`
+    Mg,
+    /// `MW`
+    Mw,
+    /// `MY`
+    My,
+    /// `MV`
+    Mv,
+    /// `ML`
+    Ml,
+    /// `MT`
+    Mt,
+    /// `MH`
+    Mh,
+    /// `MQ`
+    Mq,
+    /// `MR`
+    Mr,
+    /// `MU`
+    Mu,
+    /// `YT`
+    Yt,
+    /// `MX`
+    Mx,
+    /// `FM`
+    Fm,
+    /// `MD`
+    Md,
+    /// `MC`
+    Mc,
+    /// `MN`
+    Mn,
+    /// `ME`
+    Me,
+    /// `MS`
+    Ms,
+    /// `MA`
+    Ma,
+    /// `MZ`
+    Mz,
+    /// `MM`
+    Mm,
+    /// `NA`
+    Na,
+    /// `NR`
+    Nr,
+    /// `NP`
+    Np,
+    /// `NL`
+    Nl,
+    /// `NC`
+    Nc,
+    /// `NZ`
+    Nz,
+    /// `NI`
+    Ni,
+    /// `NE`
+    Ne,
+    /// `NG`
+    Ng,
+    /// `NU`
+    Nu,
+    /// `NF`
+    Nf,
+    /// `MP`
+    Mp,
+    /// `NO`
+    No,
+    /// `OM`
+    Om,
+    /// `PK`
+    Pk,
+    /// `PW`
+    Pw,
+    /// `PS`
+    Ps,
+    /// `PA`
+    Pa,
+    /// `PG`
+    Pg,
+    /// `PY`
+    Py,
+    /// `PE`
+    Pe,
+    /// `PH`
+    Ph,
+    /// `PN`
+    Pn,
+    /// `PL`
+    Pl,
+    /// `PT`
+    Pt,
+    /// `PR`
+    Pr,
+    /// `QA`
+    Qa,
+    /// `RE`
+    Re,
+    /// `RO`
+    Ro,
+    /// `RU`
+    Ru,
+    /// `RW`
+    Rw,
+    /// `BL`
+    Bl,
+    /// `SH`
+    Sh,
+    /// `KN`
+    Kn,
+    /// `LC`
+    Lc,
+    /// `MF`
+    Mf,
+    /// `PM`
+    Pm,
+    /// `VC`
+    Vc,
+    /// `WS`
+    Ws,
+    /// `SM`
+    Sm,
+    /// `ST`
+    St,
+    /// `SA`
+    Sa,
+    /// `SN`
+    Sn,
+    /// `RS`
+    Rs,
+    /// `SC`
+    Sc,
+    /// `SL`
+    Sl,
+    /// `SG`
+    Sg,
+    /// `SX`
+    Sx,
+    /// `SK`
+    Sk,
+    /// `SI`
+    Si,
+    /// `SB`
+    Sb,
+    /// `SO`
+    So,
+    /// `ZA`
+    Za,
+    /// `GS`
+    Gs,
+    /// `SS`
+    Ss,
+    /// `ES`
+    Es,
+    /// `LK`
+    Lk,
+    /// `SD`
+    Sd,
+    /// `SR`
+    Sr,
+    /// `SJ`
+    Sj,
+    /// `SZ`
+    Sz,
+    /// `SE`
+    Se,
+    /// `CH`
+    Ch,
+    /// `SY`
+    Sy,
+    /// `TW`
+    Tw,
+    /// `TJ`
+    Tj,
+    /// `TZ`
+    Tz,
+    /// `TH`
+    Th,
+    /// `TL`
+    Tl,
+    /// `TG`
+    Tg,
+    /// `TK`
+    Tk,
+    /// `TO`
+    To,
+    /// `TT`
+    Tt,
+    /// `TN`
+    Tn,
+    /// `TR`
+    Tr,
+    /// `TM`
+    Tm,
+    /// `TC`
+    Tc,
+    /// `TV`
+    Tv,
+    /// `UG`
+    Ug,
+    /// `UA`
+    Ua,
+    /// `AE`
+    Ae,
+    /// `GB`
+    Gb,
+    /// `US`
+    Us,
+    /// `UM`
+    Um,
+    /// `UY`
+    Uy,
+    /// `UZ`
+    Uz,
+    /// `VU`
+    Vu,
+    /// `VE`
+    Ve,
+    /// `VN`
+    Vn,
+    /// `VG`
+    Vg,
+    /// `VI`
+    Vi,
+    /// `WF`
+    Wf,
+    /// `EH`
+    Eh,
+    /// `YE`
+    Ye,
+    /// `ZM`
+    Zm,
+    /// `ZW`
+    Zw,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl GeoMatchConstraintValue {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            GeoMatchConstraintValue::Af => "AF",
+            GeoMatchConstraintValue::Ax => "AX",
+            GeoMatchConstraintValue::Al => "AL",
+            GeoMatchConstraintValue::Dz => "DZ",
+            GeoMatchConstraintValue::As => "AS",
+            GeoMatchConstraintValue::Ad => "AD",
+            GeoMatchConstraintValue::Ao => "AO",
+            GeoMatchConstraintValue::Ai => "AI",
+            GeoMatchConstraintValue::Aq => "AQ",
+            GeoMatchConstraintValue::Ag => "AG",
+            GeoMatchConstraintValue::Ar => "AR",
+            GeoMatchConstraintValue::Am => "AM",
+            GeoMatchConstraintValue::Aw => "AW",
+            GeoMatchConstraintValue::Au => "AU",
+            GeoMatchConstraintValue::At => "AT",
+            GeoMatchConstraintValue::Az => "AZ",
+            GeoMatchConstraintValue::Bs => "BS",
+            GeoMatchConstraintValue::Bh => "BH",
+            GeoMatchConstraintValue::Bd => "BD",
+            GeoMatchConstraintValue::Bb => "BB",
+            GeoMatchConstraintValue::By => "BY",
+            GeoMatchConstraintValue::Be => "BE",
+            GeoMatchConstraintValue::Bz => "BZ",
+            GeoMatchConstraintValue::Bj => "BJ",
+            GeoMatchConstraintValue::Bm => "BM",
+            GeoMatchConstraintValue::Bt => "BT",
+            GeoMatchConstraintValue::Bo => "BO",
+            GeoMatchConstraintValue::Bq => "BQ",
+            GeoMatchConstraintValue::Ba => "BA",
+            GeoMatchConstraintValue::Bw => "BW",
+            GeoMatchConstraintValue::Bv => "BV",
+            GeoMatchConstraintValue::Br => "BR",
+            GeoMatchConstraintValue::Io => "IO",
+            GeoMatchConstraintValue::Bn => "BN",
+            GeoMatchConstraintValue::Bg => "BG",
+            GeoMatchConstraintValue::Bf => "BF",
+            GeoMatchConstraintValue::Bi => "BI",
+            GeoMatchConstraintValue::Kh => "KH",
+            GeoMatchConstraintValue::Cm => "CM",
+            GeoMatchConstraintValue::Ca => "CA",
+            GeoMatchConstraintValue::Cv => "CV",
+            GeoMatchConstraintValue::Ky => "KY",
+            GeoMatchConstraintValue::Cf => "CF",
+            GeoMatchConstraintValue::Td => "TD",
+            GeoMatchConstraintValue::Cl => "CL",
+            GeoMatchConstraintValue::Cn => "CN",
+            GeoMatchConstraintValue::Cx => "CX",
+            GeoMatchConstraintValue::Cc => "CC",
+            GeoMatchConstraintValue::Co => "CO",
+            GeoMatchConstraintValue::Km => "KM",
+            GeoMatchConstraintValue::Cg => "CG",
+            GeoMatchConstraintValue::Cd => "CD",
+            GeoMatchConstraintValue::Ck => "CK",
+            GeoMatchConstraintValue::Cr => "CR",
+            GeoMatchConstraintValue::Ci => "CI",
+            GeoMatchConstraintValue::Hr => "HR",
+            GeoMatchConstraintValue::Cu => "CU",
+            GeoMatchConstraintValue::Cw => "CW",
+            GeoMatchConstraintValue::Cy => "CY",
+            GeoMatchConstraintValue::Cz => "CZ",
+            GeoMatchConstraintValue::Dk => "DK",
+            GeoMatchConstraintValue::Dj => "DJ",
+            GeoMatchConstraintValue::Dm => "DM",
+            GeoMatchConstraintValue::Do => "DO",
+            GeoMatchConstraintValue::Ec => "EC",
+            GeoMatchConstraintValue::Eg => "EG",
+            GeoMatchConstraintValue::Sv => "SV",
+            GeoMatchConstraintValue::Gq => "GQ",
+            GeoMatchConstraintValue::Er => "ER",
+            GeoMatchConstraintValue::Ee => "EE",
+            GeoMatchConstraintValue::Et => "ET",
+            GeoMatchConstraintValue::Fk => "FK",
+            GeoMatchConstraintValue::Fo => "FO",
+            GeoMatchConstraintValue::Fj => "FJ",
+            GeoMatchConstraintValue::Fi => "FI",
+            GeoMatchConstraintValue::Fr => "FR",
+            GeoMatchConstraintValue::Gf => "GF",
+            GeoMatchConstraintValue::Pf => "PF",
+            GeoMatchConstraintValue::Tf => "TF",
+            GeoMatchConstraintValue::Ga => "GA",
+            GeoMatchConstraintValue::Gm => "GM",
+            GeoMatchConstraintValue::Ge => "GE",
+            GeoMatchConstraintValue::De => "DE",
+            GeoMatchConstraintValue::Gh => "GH",
+            GeoMatchConstraintValue::Gi => "GI",
+            GeoMatchConstraintValue::Gr => "GR",
+            GeoMatchConstraintValue::Gl => "GL",
+            GeoMatchConstraintValue::Gd => "GD",
+            GeoMatchConstraintValue::Gp => "GP",
+            GeoMatchConstraintValue::Gu => "GU",
+            GeoMatchConstraintValue::Gt => "GT",
+            GeoMatchConstraintValue::Gg => "GG",
+            GeoMatchConstraintValue::Gn => "GN",
+            GeoMatchConstraintValue::Gw => "GW",
+            GeoMatchConstraintValue::Gy => "GY",
+            GeoMatchConstraintValue::Ht => "HT",
+            GeoMatchConstraintValue::Hm => "HM",
+            GeoMatchConstraintValue::Va => "VA",
+            GeoMatchConstraintValue::Hn => "HN",
+            GeoMatchConstraintValue::Hk => "HK",
+            GeoMatchConstraintValue::Hu => "HU",
+            GeoMatchConstraintValue::Is => "IS",
+            GeoMatchConstraintValue::In => "IN",
+            GeoMatchConstraintValue::Id => "ID",
+            GeoMatchConstraintValue::Ir => "IR",
+            GeoMatchConstraintValue::Iq => "IQ",
+            GeoMatchConstraintValue::Ie => "IE",
+            GeoMatchConstraintValue::Im => "IM",
+            GeoMatchConstraintValue::Il => "IL",
+            GeoMatchConstraintValue::It => "IT",
+            GeoMatchConstraintValue::Jm => "JM",
+            GeoMatchConstraintValue::Jp => "JP",
+            GeoMatchConstraintValue::Je => "JE",
+            GeoMatchConstraintValue::Jo => "JO",
+            GeoMatchConstraintValue::Kz => "KZ",
+            GeoMatchConstraintValue::Ke => "KE",
+            GeoMatchConstraintValue::Ki => "KI",
+            GeoMatchConstraintValue::Kp => "KP",
+            GeoMatchConstraintValue::Kr => "KR",
+            GeoMatchConstraintValue::Kw => "KW",
+            GeoMatchConstraintValue::Kg => "KG",
+            GeoMatchConstraintValue::La => "LA",
+            GeoMatchConstraintValue::Lv => "LV",
+            GeoMatchConstraintValue::Lb => "LB",
+            GeoMatchConstraintValue::Ls => "LS",
+            GeoMatchConstraintValue::Lr => "LR",
+            GeoMatchConstraintValue::Ly => "LY",
+            GeoMatchConstraintValue::Li => "LI",
+            GeoMatchConstraintValue::Lt => "LT",
+            GeoMatchConstraintValue::Lu => "LU",
+            GeoMatchConstraintValue::Mo => "MO",
+            GeoMatchConstraintValue::Mk => "MK",
+            GeoMatchConstraintValue::Mg => "MG",
+            GeoMatchConstraintValue::Mw => "MW",
+            GeoMatchConstraintValue::My => "MY",
+            GeoMatchConstraintValue::Mv => "MV",
+            GeoMatchConstraintValue::Ml => "ML",
+            GeoMatchConstraintValue::Mt => "MT",
+            GeoMatchConstraintValue::Mh => "MH",
+            GeoMatchConstraintValue::Mq => "MQ",
+            GeoMatchConstraintValue::Mr => "MR",
+            GeoMatchConstraintValue::Mu => "MU",
+            GeoMatchConstraintValue::Yt => "YT",
+            GeoMatchConstraintValue::Mx => "MX",
+            GeoMatchConstraintValue::Fm => "FM",
+            GeoMatchConstraintValue::Md => "MD",
+            GeoMatchConstraintValue::Mc => "MC",
+            GeoMatchConstraintValue::Mn => "MN",
+            GeoMatchConstraintValue::Me => "ME",
+            GeoMatchConstraintValue::Ms => "MS",
+            GeoMatchConstraintValue::Ma => "MA",
+            GeoMatchConstraintValue::Mz => "MZ",
+            GeoMatchConstraintValue::Mm => "MM",
+            GeoMatchConstraintValue::Na => "NA",
+            GeoMatchConstraintValue::Nr => "NR",
+            GeoMatchConstraintValue::Np => "NP",
+            GeoMatchConstraintValue::Nl => "NL",
+            GeoMatchConstraintValue::Nc => "NC",
+            GeoMatchConstraintValue::Nz => "NZ",
+            GeoMatchConstraintValue::Ni => "NI",
+            GeoMatchConstraintValue::Ne => "NE",
+            GeoMatchConstraintValue::Ng => "NG",
+            GeoMatchConstraintValue::Nu => "NU",
+            GeoMatchConstraintValue::Nf => "NF",
+            GeoMatchConstraintValue::Mp => "MP",
+            GeoMatchConstraintValue::No => "NO",
+            GeoMatchConstraintValue::Om => "OM",
+            GeoMatchConstraintValue::Pk => "PK",
+            GeoMatchConstraintValue::Pw => "PW",
+            GeoMatchConstraintValue::Ps => "PS",
+            GeoMatchConstraintValue::Pa => "PA",
+            GeoMatchConstraintValue::Pg => "PG",
+            GeoMatchConstraintValue::Py => "PY",
+            GeoMatchConstraintValue::Pe => "PE",
+            GeoMatchConstraintValue::Ph => "PH",
+            GeoMatchConstraintValue::Pn => "PN",
+            GeoMatchConstraintValue::Pl => "PL",
+            GeoMatchConstraintValue::Pt => "PT",
+            GeoMatchConstraintValue::Pr => "PR",
+            GeoMatchConstraintValue::Qa => "QA",
+            GeoMatchConstraintValue::Re => "RE",
+            GeoMatchConstraintValue::Ro => "RO",
+            GeoMatchConstraintValue::Ru => "RU",
+            GeoMatchConstraintValue::Rw => "RW",
+            GeoMatchConstraintValue::Bl => "BL",
+            GeoMatchConstraintValue::Sh => "SH",
+            GeoMatchConstraintValue::Kn => "KN",
+            GeoMatchConstraintValue::Lc => "LC",
+            GeoMatchConstraintValue::Mf => "MF",
+            GeoMatchConstraintValue::Pm => "PM",
+            GeoMatchConstraintValue::Vc => "VC",
+            GeoMatchConstraintValue::Ws => "WS",
+            GeoMatchConstraintValue::Sm => "SM",
+            GeoMatchConstraintValue::St => "ST",
+            GeoMatchConstraintValue::Sa => "SA",
+            GeoMatchConstraintValue::Sn => "SN",
+            GeoMatchConstraintValue::Rs => "RS",
+            GeoMatchConstraintValue::Sc => "SC",
+            GeoMatchConstraintValue::Sl => "SL",
+            GeoMatchConstraintValue::Sg => "SG",
+            GeoMatchConstraintValue::Sx => "SX",
+            GeoMatchConstraintValue::Sk => "SK",
+            GeoMatchConstraintValue::Si => "SI",
+            GeoMatchConstraintValue::Sb => "SB",
+            GeoMatchConstraintValue::So => "SO",
+            GeoMatchConstraintValue::Za => "ZA",
+            GeoMatchConstraintValue::Gs => "GS",
+            GeoMatchConstraintValue::Ss => "SS",
+            GeoMatchConstraintValue::Es => "ES",
+            GeoMatchConstraintValue::Lk => "LK",
+            GeoMatchConstraintValue::Sd => "SD",
+            GeoMatchConstraintValue::Sr => "SR",
+            GeoMatchConstraintValue::Sj => "SJ",
+            GeoMatchConstraintValue::Sz => "SZ",
+            GeoMatchConstraintValue::Se => "SE",
+            GeoMatchConstraintValue::Ch => "CH",
+            GeoMatchConstraintValue::Sy => "SY",
+            GeoMatchConstraintValue::Tw => "TW",
+            GeoMatchConstraintValue::Tj => "TJ",
+            GeoMatchConstraintValue::Tz => "TZ",
+            GeoMatchConstraintValue::Th => "TH",
+            GeoMatchConstraintValue::Tl => "TL",
+            GeoMatchConstraintValue::Tg => "TG",
+            GeoMatchConstraintValue::Tk => "TK",
+            GeoMatchConstraintValue::To => "TO",
+            GeoMatchConstraintValue::Tt => "TT",
+            GeoMatchConstraintValue::Tn => "TN",
+            GeoMatchConstraintValue::Tr => "TR",
+            GeoMatchConstraintValue::Tm => "TM",
+            GeoMatchConstraintValue::Tc => "TC",
+            GeoMatchConstraintValue::Tv => "TV",
+            GeoMatchConstraintValue::Ug => "UG",
+            GeoMatchConstraintValue::Ua => "UA",
+            GeoMatchConstraintValue::Ae => "AE",
+            GeoMatchConstraintValue::Gb => "GB",
+            GeoMatchConstraintValue::Us => "US",
+            GeoMatchConstraintValue::Um => "UM",
+            GeoMatchConstraintValue::Uy => "UY",
+            GeoMatchConstraintValue::Uz => "UZ",
+            GeoMatchConstraintValue::Vu => "VU",
+            GeoMatchConstraintValue::Ve => "VE",
+            GeoMatchConstraintValue::Vn => "VN",
+            GeoMatchConstraintValue::Vg => "VG",
+            GeoMatchConstraintValue::Vi => "VI",
+            GeoMatchConstraintValue::Wf => "WF",
+            GeoMatchConstraintValue::Eh => "EH",
+            GeoMatchConstraintValue::Ye => "YE",
+            GeoMatchConstraintValue::Zm => "ZM",
+            GeoMatchConstraintValue::Zw => "ZW",
+            GeoMatchConstraintValue::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for GeoMatchConstraintValue {
+    fn default() -> GeoMatchConstraintValue {
+        GeoMatchConstraintValue::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for GeoMatchConstraintValue {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for GeoMatchConstraintValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for GeoMatchConstraintValue {
+    fn from(value: &'a str) -> GeoMatchConstraintValue {
+        match value {
+            "AF" => GeoMatchConstraintValue::Af,
+            "AX" => GeoMatchConstraintValue::Ax,
+            "AL" => GeoMatchConstraintValue::Al,
+            "DZ" => GeoMatchConstraintValue::Dz,
+            "AS" => GeoMatchConstraintValue::As,
+            "AD" => GeoMatchConstraintValue::Ad,
+            "AO" => GeoMatchConstraintValue::Ao,
+            "AI" => GeoMatchConstraintValue::Ai,
+            "AQ" => GeoMatchConstraintValue::Aq,
+            "AG" => GeoMatchConstraintValue::Ag,
+            "AR" => GeoMatchConstraintValue::Ar,
+            "AM" => GeoMatchConstraintValue::Am,
+            "AW" => GeoMatchConstraintValue::Aw,
+            "AU" => GeoMatchConstraintValue::Au,
+            "AT" => GeoMatchConstraintValue::At,
+            "AZ" => GeoMatchConstraintValue::Az,
+            "BS" => GeoMatchConstraintValue::Bs,
+            "BH" => GeoMatchConstraintValue::Bh,
+            "BD" => GeoMatchConstraintValue::Bd,
+            "BB" => GeoMatchConstraintValue::Bb,
+            "BY" => GeoMatchConstraintValue::By,
+            "BE" => GeoMatchConstraintValue::Be,
+            "BZ" => GeoMatchConstraintValue::Bz,
+            "BJ" => GeoMatchConstraintValue::Bj,
+            "BM" => GeoMatchConstraintValue::Bm,
+            "BT" => GeoMatchConstraintValue::Bt,
+            "BO" => GeoMatchConstraintValue::Bo,
+            "BQ" => GeoMatchConstraintValue::Bq,
+            "BA" => GeoMatchConstraintValue::Ba,
+            "BW" => GeoMatchConstraintValue::Bw,
+            "BV" => GeoMatchConstraintValue::Bv,
+            "BR" => GeoMatchConstraintValue::Br,
+            "IO" => GeoMatchConstraintValue::Io,
+            "BN" => GeoMatchConstraintValue::Bn,
+            "BG" => GeoMatchConstraintValue::Bg,
+            "BF" => GeoMatchConstraintValue::Bf,
+            "BI" => GeoMatchConstraintValue::Bi,
+            "KH" => GeoMatchConstraintValue::Kh,
+            "CM" => GeoMatchConstraintValue::Cm,
+            "CA" => GeoMatchConstraintValue::Ca,
+            "CV" => GeoMatchConstraintValue::Cv,
+            "KY" => GeoMatchConstraintValue::Ky,
+            "CF" => GeoMatchConstraintValue::Cf,
+            "TD" => GeoMatchConstraintValue::Td,
+            "CL" => GeoMatchConstraintValue::Cl,
+            "CN" => GeoMatchConstraintValue::Cn,
+            "CX" => GeoMatchConstraintValue::Cx,
+            "CC" => GeoMatchConstraintValue::Cc,
+            "CO" => GeoMatchConstraintValue::Co,
+            "KM" => GeoMatchConstraintValue::Km,
+            "CG" => GeoMatchConstraintValue::Cg,
+            "CD" => GeoMatchConstraintValue::Cd,
+            "CK" => GeoMatchConstraintValue::Ck,
+            "CR" => GeoMatchConstraintValue::Cr,
+            "CI" => GeoMatchConstraintValue::Ci,
+            "HR" => GeoMatchConstraintValue::Hr,
+            "CU" => GeoMatchConstraintValue::Cu,
+            "CW" => GeoMatchConstraintValue::Cw,
+            "CY" => GeoMatchConstraintValue::Cy,
+            "CZ" => GeoMatchConstraintValue::Cz,
+            "DK" => GeoMatchConstraintValue::Dk,
+            "DJ" => GeoMatchConstraintValue::Dj,
+            "DM" => GeoMatchConstraintValue::Dm,
+            "DO" => GeoMatchConstraintValue::Do,
+            "EC" => GeoMatchConstraintValue::Ec,
+            "EG" => GeoMatchConstraintValue::Eg,
+            "SV" => GeoMatchConstraintValue::Sv,
+            "GQ" => GeoMatchConstraintValue::Gq,
+            "ER" => GeoMatchConstraintValue::Er,
+            "EE" => GeoMatchConstraintValue::Ee,
+            "ET" => GeoMatchConstraintValue::Et,
+            "FK" => GeoMatchConstraintValue::Fk,
+            "FO" => GeoMatchConstraintValue::Fo,
+            "FJ" => GeoMatchConstraintValue::Fj,
+            "FI" => GeoMatchConstraintValue::Fi,
+            "FR" => GeoMatchConstraintValue::Fr,
+            "GF" => GeoMatchConstraintValue::Gf,
+            "PF" => GeoMatchConstraintValue::Pf,
+            "TF" => GeoMatchConstraintValue::Tf,
+            "GA" => GeoMatchConstraintValue::Ga,
+            "GM" => GeoMatchConstraintValue::Gm,
+            "GE" => GeoMatchConstraintValue::Ge,
+            "DE" => GeoMatchConstraintValue::De,
+            "GH" => GeoMatchConstraintValue::Gh,
+            "GI" => GeoMatchConstraintValue::Gi,
+            "GR" => GeoMatchConstraintValue::Gr,
+            "GL" => GeoMatchConstraintValue::Gl,
+            "GD" => GeoMatchConstraintValue::Gd,
+            "GP" => GeoMatchConstraintValue::Gp,
+            "GU" => GeoMatchConstraintValue::Gu,
+            "GT" => GeoMatchConstraintValue::Gt,
+            "GG" => GeoMatchConstraintValue::Gg,
+            "GN" => GeoMatchConstraintValue::Gn,
+            "GW" => GeoMatchConstraintValue::Gw,
+            "GY" => GeoMatchConstraintValue::Gy,
+            "HT" => GeoMatchConstraintValue::Ht,
+            "HM" => GeoMatchConstraintValue::Hm,
+            "VA" => GeoMatchConstraintValue::Va,
+            "HN" => GeoMatchConstraintValue::Hn,
+            "HK" => GeoMatchConstraintValue::Hk,
+            "HU" => GeoMatchConstraintValue::Hu,
+            "IS" => GeoMatchConstraintValue::Is,
+            "IN" => GeoMatchConstraintValue::In,
+            "ID" => GeoMatchConstraintValue::Id,
+            "IR" => GeoMatchConstraintValue::Ir,
+            "IQ" => GeoMatchConstraintValue::Iq,
+            "IE" => GeoMatchConstraintValue::Ie,
+            "IM" => GeoMatchConstraintValue::Im,
+            "IL" => GeoMatchConstraintValue::Il,
+            "IT" => GeoMatchConstraintValue::It,
+            "JM" => GeoMatchConstraintValue::Jm,
+            "JP" => GeoMatchConstraintValue::Jp,
+            "JE" => GeoMatchConstraintValue::Je,
+            "JO" => GeoMatchConstraintValue::Jo,
+            "KZ" => GeoMatchConstraintValue::Kz,
+            "KE" => GeoMatchConstraintValue::Ke,
+            "KI" => GeoMatchConstraintValue::Ki,
+            "KP" => GeoMatchConstraintValue::Kp,
+            "KR" => GeoMatchConstraintValue::Kr,
+            "KW" => GeoMatchConstraintValue::Kw,
+            "KG" => GeoMatchConstraintValue::Kg,
+            "LA" => GeoMatchConstraintValue::La,
+            "LV" => GeoMatchConstraintValue::Lv,
+            "LB" => GeoMatchConstraintValue::Lb,
+            "LS" => GeoMatchConstraintValue::Ls,
+            "LR" => GeoMatchConstraintValue::Lr,
+            "LY" => GeoMatchConstraintValue::Ly,
+            "LI" => GeoMatchConstraintValue::Li,
+            "LT" => GeoMatchConstraintValue::Lt,
+            "LU" => GeoMatchConstraintValue::Lu,
+            "MO" => GeoMatchConstraintValue::Mo,
+            "MK" => GeoMatchConstraintValue::Mk,
+            "MG" => GeoMatchConstraintValue::Mg,
+            "MW" => GeoMatchConstraintValue::Mw,
+            "MY" => GeoMatchConstraintValue::My,
+            "MV" => GeoMatchConstraintValue::Mv,
+            "ML" => GeoMatchConstraintValue::Ml,
+            "MT" => GeoMatchConstraintValue::Mt,
+            "MH" => GeoMatchConstraintValue::Mh,
+            "MQ" => GeoMatchConstraintValue::Mq,
+            "MR" => GeoMatchConstraintValue::Mr,
+            "MU" => GeoMatchConstraintValue::Mu,
+            "YT" => GeoMatchConstraintValue::Yt,
+            "MX" => GeoMatchConstraintValue::Mx,
+            "FM" => GeoMatchConstraintValue::Fm,
+            "MD" => GeoMatchConstraintValue::Md,
+            "MC" => GeoMatchConstraintValue::Mc,
+            "MN" => GeoMatchConstraintValue::Mn,
+            "ME" => GeoMatchConstraintValue::Me,
+            "MS" => GeoMatchConstraintValue::Ms,
+            "MA" => GeoMatchConstraintValue::Ma,
+            "MZ" => GeoMatchConstraintValue::Mz,
+            "MM" => GeoMatchConstraintValue::Mm,
+            "NA" => GeoMatchConstraintValue::Na,
+            "NR" => GeoMatchConstraintValue::Nr,
+            "NP" => GeoMatchConstraintValue::Np,
+            "NL" => GeoMatchConstraintValue::Nl,
+            "NC" => GeoMatchConstraintValue::Nc,
+            "NZ" => GeoMatchConstraintValue::Nz,
+            "NI" => GeoMatchConstraintValue::Ni,
+            "NE" => GeoMatchConstraintValue::Ne,
+            "NG" => GeoMatchConstraintValue::Ng,
+            "NU" => GeoMatchConstraintValue::Nu,
+            "NF" => GeoMatchConstraintValue::Nf,
+            "MP" => GeoMatchConstraintValue::Mp,
+            "NO" => GeoMatchConstraintValue::No,
+            "OM" => GeoMatchConstraintValue::Om,
+            "PK" => GeoMatchConstraintValue::Pk,
+            "PW" => GeoMatchConstraintValue::Pw,
+            "PS" => GeoMatchConstraintValue::Ps,
+            "PA" => GeoMatchConstraintValue::Pa,
+            "PG" => GeoMatchConstraintValue::Pg,
+            "PY" => GeoMatchConstraintValue::Py,
+            "PE" => GeoMatchConstraintValue::Pe,
+            "PH" => GeoMatchConstraintValue::Ph,
+            "PN" => GeoMatchConstraintValue::Pn,
+            "PL" => GeoMatchConstraintValue::Pl,
+            "PT" => GeoMatchConstraintValue::Pt,
+            "PR" => GeoMatchConstraintValue::Pr,
+            "QA" => GeoMatchConstraintValue::Qa,
+            "RE" => GeoMatchConstraintValue::Re,
+            "RO" => GeoMatchConstraintValue::Ro,
+            "RU" => GeoMatchConstraintValue::Ru,
+            "RW" => GeoMatchConstraintValue::Rw,
+            "BL" => GeoMatchConstraintValue::Bl,
+            "SH" => GeoMatchConstraintValue::Sh,
+            "KN" => GeoMatchConstraintValue::Kn,
+            "LC" => GeoMatchConstraintValue::Lc,
+            "MF" => GeoMatchConstraintValue::Mf,
+            "PM" => GeoMatchConstraintValue::Pm,
+            "VC" => GeoMatchConstraintValue::Vc,
+            "WS" => GeoMatchConstraintValue::Ws,
+            "SM" => GeoMatchConstraintValue::Sm,
+            "ST" => GeoMatchConstraintValue::St,
+            "SA" => GeoMatchConstraintValue::Sa,
+            "SN" => GeoMatchConstraintValue::Sn,
+            "RS" => GeoMatchConstraintValue::Rs,
+            "SC" => GeoMatchConstraintValue::Sc,
+            "SL" => GeoMatchConstraintValue::Sl,
+            "SG" => GeoMatchConstraintValue::Sg,
+            "SX" => GeoMatchConstraintValue::Sx,
+            "SK" => GeoMatchConstraintValue::Sk,
+            "SI" => GeoMatchConstraintValue::Si,
+            "SB" => GeoMatchConstraintValue::Sb,
+            "SO" => GeoMatchConstraintValue::So,
+            "ZA" => GeoMatchConstraintValue::Za,
+            "GS" => GeoMatchConstraintValue::Gs,
+            "SS" => GeoMatchConstraintValue::Ss,
+            "ES" => GeoMatchConstraintValue::Es,
+            "LK" => GeoMatchConstraintValue::Lk,
+            "SD" => GeoMatchConstraintValue::Sd,
+            "SR" => GeoMatchConstraintValue::Sr,
+            "SJ" => GeoMatchConstraintValue::Sj,
+            "SZ" => GeoMatchConstraintValue::Sz,
+            "SE" => GeoMatchConstraintValue::Se,
+            "CH" => GeoMatchConstraintValue::Ch,
+            "SY" => GeoMatchConstraintValue::Sy,
+            "TW" => GeoMatchConstraintValue::Tw,
+            "TJ" => GeoMatchConstraintValue::Tj,
+            "TZ" => GeoMatchConstraintValue::Tz,
+            "TH" => GeoMatchConstraintValue::Th,
+            "TL" => GeoMatchConstraintValue::Tl,
+            "TG" => GeoMatchConstraintValue::Tg,
+            "TK" => GeoMatchConstraintValue::Tk,
+            "TO" => GeoMatchConstraintValue::To,
+            "TT" => GeoMatchConstraintValue::Tt,
+            "TN" => GeoMatchConstraintValue::Tn,
+            "TR" => GeoMatchConstraintValue::Tr,
+            "TM" => GeoMatchConstraintValue::Tm,
+            "TC" => GeoMatchConstraintValue::Tc,
+            "TV" => GeoMatchConstraintValue::Tv,
+            "UG" => GeoMatchConstraintValue::Ug,
+            "UA" => GeoMatchConstraintValue::Ua,
+            "AE" => GeoMatchConstraintValue::Ae,
+            "GB" => GeoMatchConstraintValue::Gb,
+            "US" => GeoMatchConstraintValue::Us,
+            "UM" => GeoMatchConstraintValue::Um,
+            "UY" => GeoMatchConstraintValue::Uy,
+            "UZ" => GeoMatchConstraintValue::Uz,
+            "VU" => GeoMatchConstraintValue::Vu,
+            "VE" => GeoMatchConstraintValue::Ve,
+            "VN" => GeoMatchConstraintValue::Vn,
+            "VG" => GeoMatchConstraintValue::Vg,
+            "VI" => GeoMatchConstraintValue::Vi,
+            "WF" => GeoMatchConstraintValue::Wf,
+            "EH" => GeoMatchConstraintValue::Eh,
+            "YE" => GeoMatchConstraintValue::Ye,
+            "ZM" => GeoMatchConstraintValue::Zm,
+            "ZW" => GeoMatchConstraintValue::Zw,
+            _ => GeoMatchConstraintValue::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for GeoMatchConstraintValue {
+    fn from(value: String) -> GeoMatchConstraintValue {
+        match value.as_str() {
+            "AF" => GeoMatchConstraintValue::Af,
+            "AX" => GeoMatchConstraintValue::Ax,
+            "AL" => GeoMatchConstraintValue::Al,
+            "DZ" => GeoMatchConstraintValue::Dz,
+            "AS" => GeoMatchConstraintValue::As,
+            "AD" => GeoMatchConstraintValue::Ad,
+            "AO" => GeoMatchConstraintValue::Ao,
+            "AI" => GeoMatchConstraintValue::Ai,
+            "AQ" => GeoMatchConstraintValue::Aq,
+            "AG" => GeoMatchConstraintValue::Ag,
+            "AR" => GeoMatchConstraintValue::Ar,
+            "AM" => GeoMatchConstraintValue::Am,
+            "AW" => GeoMatchConstraintValue::Aw,
+            "AU" => GeoMatchConstraintValue::Au,
+            "AT" => GeoMatchConstraintValue::At,
+            "AZ" => GeoMatchConstraintValue::Az,
+            "BS" => GeoMatchConstraintValue::Bs,
+            "BH" => GeoMatchConstraintValue::Bh,
+            "BD" => GeoMatchConstraintValue::Bd,
+            "BB" => GeoMatchConstraintValue::Bb,
+            "BY" => GeoMatchConstraintValue::By,
+            "BE" => GeoMatchConstraintValue::Be,
+            "BZ" => GeoMatchConstraintValue::Bz,
+            "BJ" => GeoMatchConstraintValue::Bj,
+            "BM" => GeoMatchConstraintValue::Bm,
+            "BT" => GeoMatchConstraintValue::Bt,
+            "BO" => GeoMatchConstraintValue::Bo,
+            "BQ" => GeoMatchConstraintValue::Bq,
+            "BA" => GeoMatchConstraintValue::Ba,
+            "BW" => GeoMatchConstraintValue::Bw,
+            "BV" => GeoMatchConstraintValue::Bv,
+            "BR" => GeoMatchConstraintValue::Br,
+            "IO" => GeoMatchConstraintValue::Io,
+            "BN" => GeoMatchConstraintValue::Bn,
+            "BG" => GeoMatchConstraintValue::Bg,
+            "BF" => GeoMatchConstraintValue::Bf,
+            "BI" => GeoMatchConstraintValue::Bi,
+            "KH" => GeoMatchConstraintValue::Kh,
+            "CM" => GeoMatchConstraintValue::Cm,
+            "CA" => GeoMatchConstraintValue::Ca,
+            "CV" => GeoMatchConstraintValue::Cv,
+            "KY" => GeoMatchConstraintValue::Ky,
+            "CF" => GeoMatchConstraintValue::Cf,
+            "TD" => GeoMatchConstraintValue::Td,
+            "CL" => GeoMatchConstraintValue::Cl,
+            "CN" => GeoMatchConstraintValue::Cn,
+            "CX" => GeoMatchConstraintValue::Cx,
+            "CC" => GeoMatchConstraintValue::Cc,
+            "CO" => GeoMatchConstraintValue::Co,
+            "KM" => GeoMatchConstraintValue::Km,
+            "CG" => GeoMatchConstraintValue::Cg,
+            "CD" => GeoMatchConstraintValue::Cd,
+            "CK" => GeoMatchConstraintValue::Ck,
+            "CR" => GeoMatchConstraintValue::Cr,
+            "CI" => GeoMatchConstraintValue::Ci,
+            "HR" => GeoMatchConstraintValue::Hr,
+            "CU" => GeoMatchConstraintValue::Cu,
+            "CW" => GeoMatchConstraintValue::Cw,
+            "CY" => GeoMatchConstraintValue::Cy,
+            "CZ" => GeoMatchConstraintValue::Cz,
+            "DK" => GeoMatchConstraintValue::Dk,
+            "DJ" => GeoMatchConstraintValue::Dj,
+            "DM" => GeoMatchConstraintValue::Dm,
+            "DO" => GeoMatchConstraintValue::Do,
+            "EC" => GeoMatchConstraintValue::Ec,
+            "EG" => GeoMatchConstraintValue::Eg,
+            "SV" => GeoMatchConstraintValue::Sv,
+            "GQ" => GeoMatchConstraintValue::Gq,
+            "ER" => GeoMatchConstraintValue::Er,
+            "EE" => GeoMatchConstraintValue::Ee,
+            "ET" => GeoMatchConstraintValue::Et,
+            "FK" => GeoMatchConstraintValue::Fk,
+            "FO" => GeoMatchConstraintValue::Fo,
+            "FJ" => GeoMatchConstraintValue::Fj,
+            "FI" => GeoMatchConstraintValue::Fi,
+            "FR" => GeoMatchConstraintValue::Fr,
+            "GF" => GeoMatchConstraintValue::Gf,
+            "PF" => GeoMatchConstraintValue::Pf,
+            "TF" => GeoMatchConstraintValue::Tf,
+            "GA" => GeoMatchConstraintValue::Ga,
+            "GM" => GeoMatchConstraintValue::Gm,
+            "GE" => GeoMatchConstraintValue::Ge,
+            "DE" => GeoMatchConstraintValue::De,
+            "GH" => GeoMatchConstraintValue::Gh,
+            "GI" => GeoMatchConstraintValue::Gi,
+            "GR" => GeoMatchConstraintValue::Gr,
+            "GL" => GeoMatchConstraintValue::Gl,
+            "GD" => GeoMatchConstraintValue::Gd,
+            "GP" => GeoMatchConstraintValue::Gp,
+            "GU" => GeoMatchConstraintValue::Gu,
+            "GT" => GeoMatchConstraintValue::Gt,
+            "GG" => GeoMatchConstraintValue::Gg,
+            "GN" => GeoMatchConstraintValue::Gn,
+            "GW" => GeoMatchConstraintValue::Gw,
+            "GY" => GeoMatchConstraintValue::Gy,
+            "HT" => GeoMatchConstraintValue::Ht,
+            "HM" => GeoMatchConstraintValue::Hm,
+            "VA" => GeoMatchConstraintValue::Va,
+            "HN" => GeoMatchConstraintValue::Hn,
+            "HK" => GeoMatchConstraintValue::Hk,
+            "HU" => GeoMatchConstraintValue::Hu,
+            "IS" => GeoMatchConstraintValue::Is,
+            "IN" => GeoMatchConstraintValue::In,
+            "ID" => GeoMatchConstraintValue::Id,
+            "IR" => GeoMatchConstraintValue::Ir,
+            "IQ" => GeoMatchConstraintValue::Iq,
+            "IE" => GeoMatchConstraintValue::Ie,
+            "IM" => GeoMatchConstraintValue::Im,
+            "IL" => GeoMatchConstraintValue::Il,
+            "IT" => GeoMatchConstraintValue::It,
+            "JM" => GeoMatchConstraintValue::Jm,
+            "JP" => GeoMatchConstraintValue::Jp,
+            "JE" => GeoMatchConstraintValue::Je,
+            "JO" => GeoMatchConstraintValue::Jo,
+            "KZ" => GeoMatchConstraintValue::Kz,
+            "KE" => GeoMatchConstraintValue::Ke,
+            "KI" => GeoMatchConstraintValue::Ki,
+            "KP" => GeoMatchConstraintValue::Kp,
+            "KR" => GeoMatchConstraintValue::Kr,
+            "KW" => GeoMatchConstraintValue::Kw,
+            "KG" => GeoMatchConstraintValue::Kg,
+            "LA" => GeoMatchConstraintValue::La,
+            "LV" => GeoMatchConstraintValue::Lv,
+            "LB" => GeoMatchConstraintValue::Lb,
+            "LS" => GeoMatchConstraintValue::Ls,
+            "LR" => GeoMatchConstraintValue::Lr,
+            "LY" => GeoMatchConstraintValue::Ly,
+            "LI" => GeoMatchConstraintValue::Li,
+            "LT" => GeoMatchConstraintValue::Lt,
+            "LU" => GeoMatchConstraintValue::Lu,
+            "MO" => GeoMatchConstraintValue::Mo,
+            "MK" => GeoMatchConstraintValue::Mk,
+            "MG" => GeoMatchConstraintValue::Mg,
+            "MW" => GeoMatchConstraintValue::Mw,
+            "MY" => GeoMatchConstraintValue::My,
+            "MV" => GeoMatchConstraintValue::Mv,
+            "ML" => GeoMatchConstraintValue::Ml,
+            "MT" => GeoMatchConstraintValue::Mt,
+            "MH" => GeoMatchConstraintValue::Mh,
+            "MQ" => GeoMatchConstraintValue::Mq,
+            "MR" => GeoMatchConstraintValue::Mr,
+            "MU" => GeoMatchConstraintValue::Mu,
+            "YT" => GeoMatchConstraintValue::Yt,
+            "MX" => GeoMatchConstraintValue::Mx,
+            "FM" => GeoMatchConstraintValue::Fm,
+            "MD" => GeoMatchConstraintValue::Md,
+            "MC" => GeoMatchConstraintValue::Mc,
+            "MN" => GeoMatchConstraintValue::Mn,
+            "ME" => GeoMatchConstraintValue::Me,
+            "MS" => GeoMatchConstraintValue::Ms,
+            "MA" => GeoMatchConstraintValue::Ma,
+            "MZ" => GeoMatchConstraintValue::Mz,
+            "MM" => GeoMatchConstraintValue::Mm,
+            "NA" => GeoMatchConstraintValue::Na,
+            "NR" => GeoMatchConstraintValue::Nr,
+            "NP" => GeoMatchConstraintValue::Np,
+            "NL" => GeoMatchConstraintValue::Nl,
+            "NC" => GeoMatchConstraintValue::Nc,
+            "NZ" => GeoMatchConstraintValue::Nz,
+            "NI" => GeoMatchConstraintValue::Ni,
+            "NE" => GeoMatchConstraintValue::Ne,
+            "NG" => GeoMatchConstraintValue::Ng,
+            "NU" => GeoMatchConstraintValue::Nu,
+            "NF" => GeoMatchConstraintValue::Nf,
+            "MP" => GeoMatchConstraintValue::Mp,
+            "NO" => GeoMatchConstraintValue::No,
+            "OM" => GeoMatchConstraintValue::Om,
+            "PK" => GeoMatchConstraintValue::Pk,
+            "PW" => GeoMatchConstraintValue::Pw,
+            "PS" => GeoMatchConstraintValue::Ps,
+            "PA" => GeoMatchConstraintValue::Pa,
+            "PG" => GeoMatchConstraintValue::Pg,
+            "PY" => GeoMatchConstraintValue::Py,
+            "PE" => GeoMatchConstraintValue::Pe,
+            "PH" => GeoMatchConstraintValue::Ph,
+            "PN" => GeoMatchConstraintValue::Pn,
+            "PL" => GeoMatchConstraintValue::Pl,
+            "PT" => GeoMatchConstraintValue::Pt,
+            "PR" => GeoMatchConstraintValue::Pr,
+            "QA" => GeoMatchConstraintValue::Qa,
+            "RE" => GeoMatchConstraintValue::Re,
+            "RO" => GeoMatchConstraintValue::Ro,
+            "RU" => GeoMatchConstraintValue::Ru,
+            "RW" => GeoMatchConstraintValue::Rw,
+            "BL" => GeoMatchConstraintValue::Bl,
+            "SH" => GeoMatchConstraintValue::Sh,
+            "KN" => GeoMatchConstraintValue::Kn,
+            "LC" => GeoMatchConstraintValue::Lc,
+            "MF" => GeoMatchConstraintValue::Mf,
+            "PM" => GeoMatchConstraintValue::Pm,
+            "VC" => GeoMatchConstraintValue::Vc,
+            "WS" => GeoMatchConstraintValue::Ws,
+            "SM" => GeoMatchConstraintValue::Sm,
+            "ST" => GeoMatchConstraintValue::St,
+            "SA" => GeoMatchConstraintValue::Sa,
+            "SN" => GeoMatchConstraintValue::Sn,
+            "RS" => GeoMatchConstraintValue::Rs,
+            "SC" => GeoMatchConstraintValue::Sc,
+            "SL" => GeoMatchConstraintValue::Sl,
+            "SG" => GeoMatchConstraintValue::Sg,
+            "SX" => GeoMatchConstraintValue::Sx,
+            "SK" => GeoMatchConstraintValue::Sk,
+            "SI" => GeoMatchConstraintValue::Si,
+            "SB" => GeoMatchConstraintValue::Sb,
+            "SO" => GeoMatchConstraintValue::So,
+            "ZA" => GeoMatchConstraintValue::Za,
+            "GS" => GeoMatchConstraintValue::Gs,
+            "SS" => GeoMatchConstraintValue::Ss,
+            "ES" => GeoMatchConstraintValue::Es,
+            "LK" => GeoMatchConstraintValue::Lk,
+            "SD" => GeoMatchConstraintValue::Sd,
+            "SR" => GeoMatchConstraintValue::Sr,
+            "SJ" => GeoMatchConstraintValue::Sj,
+            "SZ" => GeoMatchConstraintValue::Sz,
+            "SE" => GeoMatchConstraintValue::Se,
+            "CH" => GeoMatchConstraintValue::Ch,
+            "SY" => GeoMatchConstraintValue::Sy,
+            "TW" => GeoMatchConstraintValue::Tw,
+            "TJ" => GeoMatchConstraintValue::Tj,
+            "TZ" => GeoMatchConstraintValue::Tz,
+            "TH" => GeoMatchConstraintValue::Th,
+            "TL" => GeoMatchConstraintValue::Tl,
+            "TG" => GeoMatchConstraintValue::Tg,
+            "TK" => GeoMatchConstraintValue::Tk,
+            "TO" => GeoMatchConstraintValue::To,
+            "TT" => GeoMatchConstraintValue::Tt,
+            "TN" => GeoMatchConstraintValue::Tn,
+            "TR" => GeoMatchConstraintValue::Tr,
+            "TM" => GeoMatchConstraintValue::Tm,
+            "TC" => GeoMatchConstraintValue::Tc,
+            "TV" => GeoMatchConstraintValue::Tv,
+            "UG" => GeoMatchConstraintValue::Ug,
+            "UA" => GeoMatchConstraintValue::Ua,
+            "AE" => GeoMatchConstraintValue::Ae,
+            "GB" => GeoMatchConstraintValue::Gb,
+            "US" => GeoMatchConstraintValue::Us,
+            "UM" => GeoMatchConstraintValue::Um,
+            "UY" => GeoMatchConstraintValue::Uy,
+            "UZ" => GeoMatchConstraintValue::Uz,
+            "VU" => GeoMatchConstraintValue::Vu,
+            "VE" => GeoMatchConstraintValue::Ve,
+            "VN" => GeoMatchConstraintValue::Vn,
+            "VG" => GeoMatchConstraintValue::Vg,
+            "VI" => GeoMatchConstraintValue::Vi,
+            "WF" => GeoMatchConstraintValue::Wf,
+            "EH" => GeoMatchConstraintValue::Eh,
+            "YE" => GeoMatchConstraintValue::Ye,
+            "ZM" => GeoMatchConstraintValue::Zm,
+            "ZW" => GeoMatchConstraintValue::Zw,
+            _ => GeoMatchConstraintValue::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for GeoMatchConstraintValue {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<GeoMatchConstraintValue, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for GeoMatchConstraintValue {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for GeoMatchConstraintValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for GeoMatchConstraintValue {
+    fn deserialize<D>(deserializer: D) -> Result<GeoMatchConstraintValue, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Contains one or more countries that AWS WAF will search for.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -771,7 +2491,7 @@ pub struct GeoMatchSetSummary {
 pub struct GeoMatchSetUpdate {
     /// <p>Specifies whether to insert or delete a country with <a>UpdateGeoMatchSet</a>.</p>
     #[serde(rename = "Action")]
-    pub action: String,
+    pub action: ChangeAction,
     /// <p>The country from which web requests originate that you want AWS WAF to search for.</p>
     #[serde(rename = "GeoMatchConstraint")]
     pub geo_match_constraint: GeoMatchConstraint,
@@ -821,7 +2541,7 @@ pub struct GetChangeTokenStatusResponse {
     /// <p>The status of the change token.</p>
     #[serde(rename = "ChangeTokenStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub change_token_status: Option<String>,
+    pub change_token_status: Option<ChangeTokenStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -1173,12 +2893,105 @@ pub struct IPSet {
 pub struct IPSetDescriptor {
     /// <p>Specify <code>IPV4</code> or <code>IPV6</code>.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: IPSetDescriptorType,
     /// <p><p>Specify an IPv4 address by using CIDR notation. For example:</p> <ul> <li> <p>To configure AWS WAF to allow, block, or count requests that originated from the IP address 192.0.2.44, specify <code>192.0.2.44/32</code>.</p> </li> <li> <p>To configure AWS WAF to allow, block, or count requests that originated from IP addresses from 192.0.2.0 to 192.0.2.255, specify <code>192.0.2.0/24</code>.</p> </li> </ul> <p>For more information about CIDR notation, see the Wikipedia entry <a href="https://en.wikipedia.org/wiki/Classless_Inter-Domain_Routing">Classless Inter-Domain Routing</a>.</p> <p>Specify an IPv6 address by using CIDR notation. For example:</p> <ul> <li> <p>To configure AWS WAF to allow, block, or count requests that originated from the IP address 1111:0000:0000:0000:0000:0000:0000:0111, specify <code>1111:0000:0000:0000:0000:0000:0000:0111/128</code>.</p> </li> <li> <p>To configure AWS WAF to allow, block, or count requests that originated from IP addresses 1111:0000:0000:0000:0000:0000:0000:0000 to 1111:0000:0000:0000:ffff:ffff:ffff:ffff, specify <code>1111:0000:0000:0000:0000:0000:0000:0000/64</code>.</p> </li> </ul></p>
     #[serde(rename = "Value")]
     pub value: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum IPSetDescriptorType {
+    /// `IPV4`
+    Ipv4,
+    /// `IPV6`
+    Ipv6,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl IPSetDescriptorType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            IPSetDescriptorType::Ipv4 => "IPV4",
+            IPSetDescriptorType::Ipv6 => "IPV6",
+            IPSetDescriptorType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for IPSetDescriptorType {
+    fn default() -> IPSetDescriptorType {
+        IPSetDescriptorType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for IPSetDescriptorType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for IPSetDescriptorType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for IPSetDescriptorType {
+    fn from(value: &'a str) -> IPSetDescriptorType {
+        match value {
+            "IPV4" => IPSetDescriptorType::Ipv4,
+            "IPV6" => IPSetDescriptorType::Ipv6,
+            _ => IPSetDescriptorType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for IPSetDescriptorType {
+    fn from(value: String) -> IPSetDescriptorType {
+        match value.as_str() {
+            "IPV4" => IPSetDescriptorType::Ipv4,
+            "IPV6" => IPSetDescriptorType::Ipv6,
+            _ => IPSetDescriptorType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for IPSetDescriptorType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<IPSetDescriptorType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for IPSetDescriptorType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for IPSetDescriptorType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for IPSetDescriptorType {
+    fn deserialize<D>(deserializer: D) -> Result<IPSetDescriptorType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Contains the identifier and the name of the <code>IPSet</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1197,7 +3010,7 @@ pub struct IPSetSummary {
 pub struct IPSetUpdate {
     /// <p>Specifies whether to insert or delete an IP address with <a>UpdateIPSet</a>.</p>
     #[serde(rename = "Action")]
-    pub action: String,
+    pub action: ChangeAction,
     /// <p>The IP address type (<code>IPV4</code> or <code>IPV6</code>) and the IP address range (in CIDR notation) that web requests originate from.</p>
     #[serde(rename = "IPSetDescriptor")]
     pub ip_set_descriptor: IPSetDescriptor,
@@ -1640,6 +3453,526 @@ pub struct LoggingConfiguration {
     pub resource_arn: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MatchFieldType {
+    /// `URI`
+    Uri,
+    /// `QUERY_STRING`
+    QueryString,
+    /// `HEADER`
+    Header,
+    /// `METHOD`
+    Method,
+    /// `BODY`
+    Body,
+    /// `SINGLE_QUERY_ARG`
+    SingleQueryArg,
+    /// `ALL_QUERY_ARGS`
+    AllQueryArgs,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl MatchFieldType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MatchFieldType::Uri => "URI",
+            MatchFieldType::QueryString => "QUERY_STRING",
+            MatchFieldType::Header => "HEADER",
+            MatchFieldType::Method => "METHOD",
+            MatchFieldType::Body => "BODY",
+            MatchFieldType::SingleQueryArg => "SINGLE_QUERY_ARG",
+            MatchFieldType::AllQueryArgs => "ALL_QUERY_ARGS",
+            MatchFieldType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for MatchFieldType {
+    fn default() -> MatchFieldType {
+        MatchFieldType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for MatchFieldType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for MatchFieldType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for MatchFieldType {
+    fn from(value: &'a str) -> MatchFieldType {
+        match value {
+            "URI" => MatchFieldType::Uri,
+            "QUERY_STRING" => MatchFieldType::QueryString,
+            "HEADER" => MatchFieldType::Header,
+            "METHOD" => MatchFieldType::Method,
+            "BODY" => MatchFieldType::Body,
+            "SINGLE_QUERY_ARG" => MatchFieldType::SingleQueryArg,
+            "ALL_QUERY_ARGS" => MatchFieldType::AllQueryArgs,
+            _ => MatchFieldType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MatchFieldType {
+    fn from(value: String) -> MatchFieldType {
+        match value.as_str() {
+            "URI" => MatchFieldType::Uri,
+            "QUERY_STRING" => MatchFieldType::QueryString,
+            "HEADER" => MatchFieldType::Header,
+            "METHOD" => MatchFieldType::Method,
+            "BODY" => MatchFieldType::Body,
+            "SINGLE_QUERY_ARG" => MatchFieldType::SingleQueryArg,
+            "ALL_QUERY_ARGS" => MatchFieldType::AllQueryArgs,
+            _ => MatchFieldType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for MatchFieldType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<MatchFieldType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MatchFieldType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for MatchFieldType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MatchFieldType {
+    fn deserialize<D>(deserializer: D) -> Result<MatchFieldType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ParameterExceptionField {
+    /// `CHANGE_ACTION`
+    ChangeAction,
+    /// `WAF_ACTION`
+    WafAction,
+    /// `WAF_OVERRIDE_ACTION`
+    WafOverrideAction,
+    /// `PREDICATE_TYPE`
+    PredicateType,
+    /// `IPSET_TYPE`
+    IpsetType,
+    /// `BYTE_MATCH_FIELD_TYPE`
+    ByteMatchFieldType,
+    /// `SQL_INJECTION_MATCH_FIELD_TYPE`
+    SqlInjectionMatchFieldType,
+    /// `BYTE_MATCH_TEXT_TRANSFORMATION`
+    ByteMatchTextTransformation,
+    /// `BYTE_MATCH_POSITIONAL_CONSTRAINT`
+    ByteMatchPositionalConstraint,
+    /// `SIZE_CONSTRAINT_COMPARISON_OPERATOR`
+    SizeConstraintComparisonOperator,
+    /// `GEO_MATCH_LOCATION_TYPE`
+    GeoMatchLocationType,
+    /// `GEO_MATCH_LOCATION_VALUE`
+    GeoMatchLocationValue,
+    /// `RATE_KEY`
+    RateKey,
+    /// `RULE_TYPE`
+    RuleType,
+    /// `NEXT_MARKER`
+    NextMarker,
+    /// `RESOURCE_ARN`
+    ResourceArn,
+    /// `TAGS`
+    Tags,
+    /// `TAG_KEYS`
+    TagKeys,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ParameterExceptionField {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ParameterExceptionField::ChangeAction => "CHANGE_ACTION",
+            ParameterExceptionField::WafAction => "WAF_ACTION",
+            ParameterExceptionField::WafOverrideAction => "WAF_OVERRIDE_ACTION",
+            ParameterExceptionField::PredicateType => "PREDICATE_TYPE",
+            ParameterExceptionField::IpsetType => "IPSET_TYPE",
+            ParameterExceptionField::ByteMatchFieldType => "BYTE_MATCH_FIELD_TYPE",
+            ParameterExceptionField::SqlInjectionMatchFieldType => "SQL_INJECTION_MATCH_FIELD_TYPE",
+            ParameterExceptionField::ByteMatchTextTransformation => {
+                "BYTE_MATCH_TEXT_TRANSFORMATION"
+            }
+            ParameterExceptionField::ByteMatchPositionalConstraint => {
+                "BYTE_MATCH_POSITIONAL_CONSTRAINT"
+            }
+            ParameterExceptionField::SizeConstraintComparisonOperator => {
+                "SIZE_CONSTRAINT_COMPARISON_OPERATOR"
+            }
+            ParameterExceptionField::GeoMatchLocationType => "GEO_MATCH_LOCATION_TYPE",
+            ParameterExceptionField::GeoMatchLocationValue => "GEO_MATCH_LOCATION_VALUE",
+            ParameterExceptionField::RateKey => "RATE_KEY",
+            ParameterExceptionField::RuleType => "RULE_TYPE",
+            ParameterExceptionField::NextMarker => "NEXT_MARKER",
+            ParameterExceptionField::ResourceArn => "RESOURCE_ARN",
+            ParameterExceptionField::Tags => "TAGS",
+            ParameterExceptionField::TagKeys => "TAG_KEYS",
+            ParameterExceptionField::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ParameterExceptionField {
+    fn default() -> ParameterExceptionField {
+        ParameterExceptionField::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ParameterExceptionField {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ParameterExceptionField {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ParameterExceptionField {
+    fn from(value: &'a str) -> ParameterExceptionField {
+        match value {
+            "CHANGE_ACTION" => ParameterExceptionField::ChangeAction,
+            "WAF_ACTION" => ParameterExceptionField::WafAction,
+            "WAF_OVERRIDE_ACTION" => ParameterExceptionField::WafOverrideAction,
+            "PREDICATE_TYPE" => ParameterExceptionField::PredicateType,
+            "IPSET_TYPE" => ParameterExceptionField::IpsetType,
+            "BYTE_MATCH_FIELD_TYPE" => ParameterExceptionField::ByteMatchFieldType,
+            "SQL_INJECTION_MATCH_FIELD_TYPE" => ParameterExceptionField::SqlInjectionMatchFieldType,
+            "BYTE_MATCH_TEXT_TRANSFORMATION" => {
+                ParameterExceptionField::ByteMatchTextTransformation
+            }
+            "BYTE_MATCH_POSITIONAL_CONSTRAINT" => {
+                ParameterExceptionField::ByteMatchPositionalConstraint
+            }
+            "SIZE_CONSTRAINT_COMPARISON_OPERATOR" => {
+                ParameterExceptionField::SizeConstraintComparisonOperator
+            }
+            "GEO_MATCH_LOCATION_TYPE" => ParameterExceptionField::GeoMatchLocationType,
+            "GEO_MATCH_LOCATION_VALUE" => ParameterExceptionField::GeoMatchLocationValue,
+            "RATE_KEY" => ParameterExceptionField::RateKey,
+            "RULE_TYPE" => ParameterExceptionField::RuleType,
+            "NEXT_MARKER" => ParameterExceptionField::NextMarker,
+            "RESOURCE_ARN" => ParameterExceptionField::ResourceArn,
+            "TAGS" => ParameterExceptionField::Tags,
+            "TAG_KEYS" => ParameterExceptionField::TagKeys,
+            _ => ParameterExceptionField::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ParameterExceptionField {
+    fn from(value: String) -> ParameterExceptionField {
+        match value.as_str() {
+            "CHANGE_ACTION" => ParameterExceptionField::ChangeAction,
+            "WAF_ACTION" => ParameterExceptionField::WafAction,
+            "WAF_OVERRIDE_ACTION" => ParameterExceptionField::WafOverrideAction,
+            "PREDICATE_TYPE" => ParameterExceptionField::PredicateType,
+            "IPSET_TYPE" => ParameterExceptionField::IpsetType,
+            "BYTE_MATCH_FIELD_TYPE" => ParameterExceptionField::ByteMatchFieldType,
+            "SQL_INJECTION_MATCH_FIELD_TYPE" => ParameterExceptionField::SqlInjectionMatchFieldType,
+            "BYTE_MATCH_TEXT_TRANSFORMATION" => {
+                ParameterExceptionField::ByteMatchTextTransformation
+            }
+            "BYTE_MATCH_POSITIONAL_CONSTRAINT" => {
+                ParameterExceptionField::ByteMatchPositionalConstraint
+            }
+            "SIZE_CONSTRAINT_COMPARISON_OPERATOR" => {
+                ParameterExceptionField::SizeConstraintComparisonOperator
+            }
+            "GEO_MATCH_LOCATION_TYPE" => ParameterExceptionField::GeoMatchLocationType,
+            "GEO_MATCH_LOCATION_VALUE" => ParameterExceptionField::GeoMatchLocationValue,
+            "RATE_KEY" => ParameterExceptionField::RateKey,
+            "RULE_TYPE" => ParameterExceptionField::RuleType,
+            "NEXT_MARKER" => ParameterExceptionField::NextMarker,
+            "RESOURCE_ARN" => ParameterExceptionField::ResourceArn,
+            "TAGS" => ParameterExceptionField::Tags,
+            "TAG_KEYS" => ParameterExceptionField::TagKeys,
+            _ => ParameterExceptionField::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ParameterExceptionField {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ParameterExceptionField, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ParameterExceptionField {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ParameterExceptionField {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ParameterExceptionField {
+    fn deserialize<D>(deserializer: D) -> Result<ParameterExceptionField, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ParameterExceptionReason {
+    /// `INVALID_OPTION`
+    InvalidOption,
+    /// `ILLEGAL_COMBINATION`
+    IllegalCombination,
+    /// `ILLEGAL_ARGUMENT`
+    IllegalArgument,
+    /// `INVALID_TAG_KEY`
+    InvalidTagKey,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ParameterExceptionReason {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ParameterExceptionReason::InvalidOption => "INVALID_OPTION",
+            ParameterExceptionReason::IllegalCombination => "ILLEGAL_COMBINATION",
+            ParameterExceptionReason::IllegalArgument => "ILLEGAL_ARGUMENT",
+            ParameterExceptionReason::InvalidTagKey => "INVALID_TAG_KEY",
+            ParameterExceptionReason::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ParameterExceptionReason {
+    fn default() -> ParameterExceptionReason {
+        ParameterExceptionReason::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ParameterExceptionReason {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ParameterExceptionReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ParameterExceptionReason {
+    fn from(value: &'a str) -> ParameterExceptionReason {
+        match value {
+            "INVALID_OPTION" => ParameterExceptionReason::InvalidOption,
+            "ILLEGAL_COMBINATION" => ParameterExceptionReason::IllegalCombination,
+            "ILLEGAL_ARGUMENT" => ParameterExceptionReason::IllegalArgument,
+            "INVALID_TAG_KEY" => ParameterExceptionReason::InvalidTagKey,
+            _ => ParameterExceptionReason::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ParameterExceptionReason {
+    fn from(value: String) -> ParameterExceptionReason {
+        match value.as_str() {
+            "INVALID_OPTION" => ParameterExceptionReason::InvalidOption,
+            "ILLEGAL_COMBINATION" => ParameterExceptionReason::IllegalCombination,
+            "ILLEGAL_ARGUMENT" => ParameterExceptionReason::IllegalArgument,
+            "INVALID_TAG_KEY" => ParameterExceptionReason::InvalidTagKey,
+            _ => ParameterExceptionReason::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ParameterExceptionReason {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ParameterExceptionReason, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ParameterExceptionReason {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ParameterExceptionReason {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ParameterExceptionReason {
+    fn deserialize<D>(deserializer: D) -> Result<ParameterExceptionReason, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PositionalConstraint {
+    /// `EXACTLY`
+    Exactly,
+    /// `STARTS_WITH`
+    StartsWith,
+    /// `ENDS_WITH`
+    EndsWith,
+    /// `CONTAINS`
+    Contains,
+    /// `CONTAINS_WORD`
+    ContainsWord,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PositionalConstraint {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PositionalConstraint::Exactly => "EXACTLY",
+            PositionalConstraint::StartsWith => "STARTS_WITH",
+            PositionalConstraint::EndsWith => "ENDS_WITH",
+            PositionalConstraint::Contains => "CONTAINS",
+            PositionalConstraint::ContainsWord => "CONTAINS_WORD",
+            PositionalConstraint::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PositionalConstraint {
+    fn default() -> PositionalConstraint {
+        PositionalConstraint::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PositionalConstraint {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PositionalConstraint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PositionalConstraint {
+    fn from(value: &'a str) -> PositionalConstraint {
+        match value {
+            "EXACTLY" => PositionalConstraint::Exactly,
+            "STARTS_WITH" => PositionalConstraint::StartsWith,
+            "ENDS_WITH" => PositionalConstraint::EndsWith,
+            "CONTAINS" => PositionalConstraint::Contains,
+            "CONTAINS_WORD" => PositionalConstraint::ContainsWord,
+            _ => PositionalConstraint::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PositionalConstraint {
+    fn from(value: String) -> PositionalConstraint {
+        match value.as_str() {
+            "EXACTLY" => PositionalConstraint::Exactly,
+            "STARTS_WITH" => PositionalConstraint::StartsWith,
+            "ENDS_WITH" => PositionalConstraint::EndsWith,
+            "CONTAINS" => PositionalConstraint::Contains,
+            "CONTAINS_WORD" => PositionalConstraint::ContainsWord,
+            _ => PositionalConstraint::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PositionalConstraint {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PositionalConstraint, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PositionalConstraint {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PositionalConstraint {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PositionalConstraint {
+    fn deserialize<D>(deserializer: D) -> Result<PositionalConstraint, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Specifies the <a>ByteMatchSet</a>, <a>IPSet</a>, <a>SqlInjectionMatchSet</a>, <a>XssMatchSet</a>, <a>RegexMatchSet</a>, <a>GeoMatchSet</a>, and <a>SizeConstraintSet</a> objects that you want to add to a <code>Rule</code> and, for each object, indicates whether you want to negate the settings, for example, requests that do NOT originate from the IP address 192.0.2.44. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Predicate {
@@ -1651,9 +3984,127 @@ pub struct Predicate {
     pub negated: bool,
     /// <p>The type of predicate in a <code>Rule</code>, such as <code>ByteMatch</code> or <code>IPSet</code>.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: PredicateType,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PredicateType {
+    /// `IPMatch`
+    IPMatch,
+    /// `ByteMatch`
+    ByteMatch,
+    /// `SqlInjectionMatch`
+    SqlInjectionMatch,
+    /// `GeoMatch`
+    GeoMatch,
+    /// `SizeConstraint`
+    SizeConstraint,
+    /// `XssMatch`
+    XssMatch,
+    /// `RegexMatch`
+    RegexMatch,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PredicateType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PredicateType::IPMatch => "IPMatch",
+            PredicateType::ByteMatch => "ByteMatch",
+            PredicateType::SqlInjectionMatch => "SqlInjectionMatch",
+            PredicateType::GeoMatch => "GeoMatch",
+            PredicateType::SizeConstraint => "SizeConstraint",
+            PredicateType::XssMatch => "XssMatch",
+            PredicateType::RegexMatch => "RegexMatch",
+            PredicateType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PredicateType {
+    fn default() -> PredicateType {
+        PredicateType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PredicateType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PredicateType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PredicateType {
+    fn from(value: &'a str) -> PredicateType {
+        match value {
+            "IPMatch" => PredicateType::IPMatch,
+            "ByteMatch" => PredicateType::ByteMatch,
+            "SqlInjectionMatch" => PredicateType::SqlInjectionMatch,
+            "GeoMatch" => PredicateType::GeoMatch,
+            "SizeConstraint" => PredicateType::SizeConstraint,
+            "XssMatch" => PredicateType::XssMatch,
+            "RegexMatch" => PredicateType::RegexMatch,
+            _ => PredicateType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PredicateType {
+    fn from(value: String) -> PredicateType {
+        match value.as_str() {
+            "IPMatch" => PredicateType::IPMatch,
+            "ByteMatch" => PredicateType::ByteMatch,
+            "SqlInjectionMatch" => PredicateType::SqlInjectionMatch,
+            "GeoMatch" => PredicateType::GeoMatch,
+            "SizeConstraint" => PredicateType::SizeConstraint,
+            "XssMatch" => PredicateType::XssMatch,
+            "RegexMatch" => PredicateType::RegexMatch,
+            _ => PredicateType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PredicateType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PredicateType, Self::Err> {
+        Ok(value.into())
+    }
 }
 
+impl ::rusoto_core::param::ToParam for PredicateType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PredicateType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PredicateType {
+    fn deserialize<D>(deserializer: D) -> Result<PredicateType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct PutLoggingConfigurationRequest {
@@ -1703,7 +4154,7 @@ pub struct RateBasedRule {
     pub name: Option<String>,
     /// <p>The field that AWS WAF uses to determine if requests are likely arriving from single source and thus subject to rate monitoring. The only valid value for <code>RateKey</code> is <code>IP</code>. <code>IP</code> indicates that requests arriving from the same IP address are subject to the <code>RateLimit</code> that is specified in the <code>RateBasedRule</code>.</p>
     #[serde(rename = "RateKey")]
-    pub rate_key: String,
+    pub rate_key: RateKey,
     /// <p>The maximum number of requests, which have an identical value in the field specified by the <code>RateKey</code>, allowed in a five-minute period. If the number of requests exceeds the <code>RateLimit</code> and the other predicates specified in the rule are also met, AWS WAF triggers the action that is specified for this rule.</p>
     #[serde(rename = "RateLimit")]
     pub rate_limit: i64,
@@ -1712,6 +4163,94 @@ pub struct RateBasedRule {
     pub rule_id: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RateKey {
+    /// `IP`
+    Ip,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RateKey {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RateKey::Ip => "IP",
+            RateKey::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RateKey {
+    fn default() -> RateKey {
+        RateKey::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RateKey {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RateKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RateKey {
+    fn from(value: &'a str) -> RateKey {
+        match value {
+            "IP" => RateKey::Ip,
+            _ => RateKey::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RateKey {
+    fn from(value: String) -> RateKey {
+        match value.as_str() {
+            "IP" => RateKey::Ip,
+            _ => RateKey::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RateKey {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RateKey, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RateKey {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RateKey {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RateKey {
+    fn deserialize<D>(deserializer: D) -> Result<RateKey, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>In a <a>GetRegexMatchSet</a> request, <code>RegexMatchSet</code> is a complex type that contains the <code>RegexMatchSetId</code> and <code>Name</code> of a <code>RegexMatchSet</code>, and the values that you specified when you updated the <code>RegexMatchSet</code>.</p> <p> The values are contained in a <code>RegexMatchTuple</code> object, which specify the parts of web requests that you want AWS WAF to inspect and the values that you want AWS WAF to search for. If a <code>RegexMatchSet</code> contains more than one <code>RegexMatchTuple</code> object, a request needs to match the settings in only one <code>ByteMatchTuple</code> to be considered a match.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1748,7 +4287,7 @@ pub struct RegexMatchSetSummary {
 pub struct RegexMatchSetUpdate {
     /// <p>Specifies whether to insert or delete a <a>RegexMatchTuple</a>.</p>
     #[serde(rename = "Action")]
-    pub action: String,
+    pub action: ChangeAction,
     /// <p>Information about the part of a web request that you want AWS WAF to inspect and the identifier of the regular expression (regex) pattern that you want AWS WAF to search for. If you specify <code>DELETE</code> for the value of <code>Action</code>, the <code>RegexMatchTuple</code> values must exactly match the values in the <code>RegexMatchTuple</code> that you want to delete from the <code>RegexMatchSet</code>.</p>
     #[serde(rename = "RegexMatchTuple")]
     pub regex_match_tuple: RegexMatchTuple,
@@ -1765,7 +4304,7 @@ pub struct RegexMatchTuple {
     pub regex_pattern_set_id: String,
     /// <p>Text transformations eliminate some of the unusual formatting that attackers use in web requests in an effort to bypass AWS WAF. If you specify a transformation, AWS WAF performs the transformation on <code>RegexPatternSet</code> before inspecting a request for a match.</p> <p>You can only specify a single type of TextTransformation.</p> <p> <b>CMD_LINE</b> </p> <p>When you're concerned that attackers are injecting an operating system commandline command and using unusual formatting to disguise some or all of the command, use this option to perform the following transformations:</p> <ul> <li> <p>Delete the following characters: \ " ' ^</p> </li> <li> <p>Delete spaces before the following characters: / (</p> </li> <li> <p>Replace the following characters with a space: , ;</p> </li> <li> <p>Replace multiple spaces with one space</p> </li> <li> <p>Convert uppercase letters (A-Z) to lowercase (a-z)</p> </li> </ul> <p> <b>COMPRESS_WHITE_SPACE</b> </p> <p>Use this option to replace the following characters with a space character (decimal 32):</p> <ul> <li> <p>\f, formfeed, decimal 12</p> </li> <li> <p>\t, tab, decimal 9</p> </li> <li> <p>\n, newline, decimal 10</p> </li> <li> <p>\r, carriage return, decimal 13</p> </li> <li> <p>\v, vertical tab, decimal 11</p> </li> <li> <p>non-breaking space, decimal 160</p> </li> </ul> <p> <code>COMPRESS_WHITE_SPACE</code> also replaces multiple spaces with one space.</p> <p> <b>HTML_ENTITY_DECODE</b> </p> <p>Use this option to replace HTML-encoded characters with unencoded characters. <code>HTML_ENTITY_DECODE</code> performs the following operations:</p> <ul> <li> <p>Replaces <code>(ampersand)quot;</code> with <code>"</code> </p> </li> <li> <p>Replaces <code>(ampersand)nbsp;</code> with a non-breaking space, decimal 160</p> </li> <li> <p>Replaces <code>(ampersand)lt;</code> with a "less than" symbol</p> </li> <li> <p>Replaces <code>(ampersand)gt;</code> with <code>&gt;</code> </p> </li> <li> <p>Replaces characters that are represented in hexadecimal format, <code>(ampersand)#xhhhh;</code>, with the corresponding characters</p> </li> <li> <p>Replaces characters that are represented in decimal format, <code>(ampersand)#nnnn;</code>, with the corresponding characters</p> </li> </ul> <p> <b>LOWERCASE</b> </p> <p>Use this option to convert uppercase letters (A-Z) to lowercase (a-z).</p> <p> <b>URL_DECODE</b> </p> <p>Use this option to decode a URL-encoded value.</p> <p> <b>NONE</b> </p> <p>Specify <code>NONE</code> if you don't want to perform any text transformations.</p>
     #[serde(rename = "TextTransformation")]
-    pub text_transformation: String,
+    pub text_transformation: TextTransformation,
 }
 
 /// <p>The <code>RegexPatternSet</code> specifies the regular expression (regex) pattern that you want AWS WAF to search for, such as <code>B[a@]dB[o0]t</code>. You can then configure AWS WAF to reject those requests.</p>
@@ -1802,7 +4341,7 @@ pub struct RegexPatternSetSummary {
 pub struct RegexPatternSetUpdate {
     /// <p>Specifies whether to insert or delete a <code>RegexPatternString</code>.</p>
     #[serde(rename = "Action")]
-    pub action: String,
+    pub action: ChangeAction,
     /// <p>Specifies the regular expression (regex) pattern that you want AWS WAF to search for, such as <code>B[a@]dB[o0]t</code>.</p>
     #[serde(rename = "RegexPatternString")]
     pub regex_pattern_string: String,
@@ -1863,7 +4402,7 @@ pub struct RuleGroupSummary {
 pub struct RuleGroupUpdate {
     /// <p>Specify <code>INSERT</code> to add an <code>ActivatedRule</code> to a <code>RuleGroup</code>. Use <code>DELETE</code> to remove an <code>ActivatedRule</code> from a <code>RuleGroup</code>.</p>
     #[serde(rename = "Action")]
-    pub action: String,
+    pub action: ChangeAction,
     /// <p>The <code>ActivatedRule</code> object specifies a <code>Rule</code> that you want to insert or delete, the priority of the <code>Rule</code> in the <code>WebACL</code>, and the action that you want AWS WAF to take when a web request matches the <code>Rule</code> (<code>ALLOW</code>, <code>BLOCK</code>, or <code>COUNT</code>).</p>
     #[serde(rename = "ActivatedRule")]
     pub activated_rule: ActivatedRule,
@@ -1887,7 +4426,7 @@ pub struct RuleSummary {
 pub struct RuleUpdate {
     /// <p>Specify <code>INSERT</code> to add a <code>Predicate</code> to a <code>Rule</code>. Use <code>DELETE</code> to remove a <code>Predicate</code> from a <code>Rule</code>.</p>
     #[serde(rename = "Action")]
-    pub action: String,
+    pub action: ChangeAction,
     /// <p>The ID of the <code>Predicate</code> (such as an <code>IPSet</code>) that you want to add to a <code>Rule</code>.</p>
     #[serde(rename = "Predicate")]
     pub predicate: Predicate,
@@ -1910,8 +4449,13 @@ pub struct SampledHTTPRequest {
     pub rule_within_rule_group: Option<String>,
     /// <p>The time at which AWS WAF received the request from your AWS resource, in Unix time format (in seconds).</p>
     #[serde(rename = "Timestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub timestamp: Option<f64>,
+    pub timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A value that indicates how one result in the response relates proportionally to other results in the response. A result that has a weight of <code>2</code> represents roughly twice as many CloudFront web requests as a result that has a weight of <code>1</code>.</p>
     #[serde(rename = "Weight")]
     pub weight: i64,
@@ -1922,7 +4466,7 @@ pub struct SampledHTTPRequest {
 pub struct SizeConstraint {
     /// <p>The type of comparison you want AWS WAF to perform. AWS WAF uses this in combination with the provided <code>Size</code> and <code>FieldToMatch</code> to build an expression in the form of "<code>Size</code> <code>ComparisonOperator</code> size in bytes of <code>FieldToMatch</code>". If that expression is true, the <code>SizeConstraint</code> is considered to match.</p> <p> <b>EQ</b>: Used to test if the <code>Size</code> is equal to the size of the <code>FieldToMatch</code> </p> <p> <b>NE</b>: Used to test if the <code>Size</code> is not equal to the size of the <code>FieldToMatch</code> </p> <p> <b>LE</b>: Used to test if the <code>Size</code> is less than or equal to the size of the <code>FieldToMatch</code> </p> <p> <b>LT</b>: Used to test if the <code>Size</code> is strictly less than the size of the <code>FieldToMatch</code> </p> <p> <b>GE</b>: Used to test if the <code>Size</code> is greater than or equal to the size of the <code>FieldToMatch</code> </p> <p> <b>GT</b>: Used to test if the <code>Size</code> is strictly greater than the size of the <code>FieldToMatch</code> </p>
     #[serde(rename = "ComparisonOperator")]
-    pub comparison_operator: String,
+    pub comparison_operator: ComparisonOperator,
     /// <p>Specifies where in a web request to look for the size constraint.</p>
     #[serde(rename = "FieldToMatch")]
     pub field_to_match: FieldToMatch,
@@ -1931,7 +4475,7 @@ pub struct SizeConstraint {
     pub size: i64,
     /// <p>Text transformations eliminate some of the unusual formatting that attackers use in web requests in an effort to bypass AWS WAF. If you specify a transformation, AWS WAF performs the transformation on <code>FieldToMatch</code> before inspecting a request for a match.</p> <p>You can only specify a single type of TextTransformation.</p> <p>Note that if you choose <code>BODY</code> for the value of <code>Type</code>, you must choose <code>NONE</code> for <code>TextTransformation</code> because CloudFront forwards only the first 8192 bytes for inspection. </p> <p> <b>NONE</b> </p> <p>Specify <code>NONE</code> if you don't want to perform any text transformations.</p> <p> <b>CMD_LINE</b> </p> <p>When you're concerned that attackers are injecting an operating system command line command and using unusual formatting to disguise some or all of the command, use this option to perform the following transformations:</p> <ul> <li> <p>Delete the following characters: \ " ' ^</p> </li> <li> <p>Delete spaces before the following characters: / (</p> </li> <li> <p>Replace the following characters with a space: , ;</p> </li> <li> <p>Replace multiple spaces with one space</p> </li> <li> <p>Convert uppercase letters (A-Z) to lowercase (a-z)</p> </li> </ul> <p> <b>COMPRESS_WHITE_SPACE</b> </p> <p>Use this option to replace the following characters with a space character (decimal 32):</p> <ul> <li> <p>\f, formfeed, decimal 12</p> </li> <li> <p>\t, tab, decimal 9</p> </li> <li> <p>\n, newline, decimal 10</p> </li> <li> <p>\r, carriage return, decimal 13</p> </li> <li> <p>\v, vertical tab, decimal 11</p> </li> <li> <p>non-breaking space, decimal 160</p> </li> </ul> <p> <code>COMPRESS_WHITE_SPACE</code> also replaces multiple spaces with one space.</p> <p> <b>HTML_ENTITY_DECODE</b> </p> <p>Use this option to replace HTML-encoded characters with unencoded characters. <code>HTML_ENTITY_DECODE</code> performs the following operations:</p> <ul> <li> <p>Replaces <code>(ampersand)quot;</code> with <code>"</code> </p> </li> <li> <p>Replaces <code>(ampersand)nbsp;</code> with a non-breaking space, decimal 160</p> </li> <li> <p>Replaces <code>(ampersand)lt;</code> with a "less than" symbol</p> </li> <li> <p>Replaces <code>(ampersand)gt;</code> with <code>&gt;</code> </p> </li> <li> <p>Replaces characters that are represented in hexadecimal format, <code>(ampersand)#xhhhh;</code>, with the corresponding characters</p> </li> <li> <p>Replaces characters that are represented in decimal format, <code>(ampersand)#nnnn;</code>, with the corresponding characters</p> </li> </ul> <p> <b>LOWERCASE</b> </p> <p>Use this option to convert uppercase letters (A-Z) to lowercase (a-z).</p> <p> <b>URL_DECODE</b> </p> <p>Use this option to decode a URL-encoded value.</p>
     #[serde(rename = "TextTransformation")]
-    pub text_transformation: String,
+    pub text_transformation: TextTransformation,
 }
 
 /// <p>A complex type that contains <code>SizeConstraint</code> objects, which specify the parts of web requests that you want AWS WAF to inspect the size of. If a <code>SizeConstraintSet</code> contains more than one <code>SizeConstraint</code> object, a request only needs to match one constraint to be considered a match.</p>
@@ -1968,7 +4512,7 @@ pub struct SizeConstraintSetSummary {
 pub struct SizeConstraintSetUpdate {
     /// <p>Specify <code>INSERT</code> to add a <a>SizeConstraintSetUpdate</a> to a <a>SizeConstraintSet</a>. Use <code>DELETE</code> to remove a <code>SizeConstraintSetUpdate</code> from a <code>SizeConstraintSet</code>.</p>
     #[serde(rename = "Action")]
-    pub action: String,
+    pub action: ChangeAction,
     /// <p>Specifies a constraint on the size of a part of the web request. AWS WAF uses the <code>Size</code>, <code>ComparisonOperator</code>, and <code>FieldToMatch</code> to build an expression in the form of "<code>Size</code> <code>ComparisonOperator</code> size in bytes of <code>FieldToMatch</code>". If that expression is true, the <code>SizeConstraint</code> is considered to match.</p>
     #[serde(rename = "SizeConstraint")]
     pub size_constraint: SizeConstraint,
@@ -2008,7 +4552,7 @@ pub struct SqlInjectionMatchSetSummary {
 pub struct SqlInjectionMatchSetUpdate {
     /// <p>Specify <code>INSERT</code> to add a <a>SqlInjectionMatchSetUpdate</a> to a <a>SqlInjectionMatchSet</a>. Use <code>DELETE</code> to remove a <code>SqlInjectionMatchSetUpdate</code> from a <code>SqlInjectionMatchSet</code>.</p>
     #[serde(rename = "Action")]
-    pub action: String,
+    pub action: ChangeAction,
     /// <p>Specifies the part of a web request that you want AWS WAF to inspect for snippets of malicious SQL code and, if you want AWS WAF to inspect a header, the name of the header.</p>
     #[serde(rename = "SqlInjectionMatchTuple")]
     pub sql_injection_match_tuple: SqlInjectionMatchTuple,
@@ -2022,7 +4566,7 @@ pub struct SqlInjectionMatchTuple {
     pub field_to_match: FieldToMatch,
     /// <p>Text transformations eliminate some of the unusual formatting that attackers use in web requests in an effort to bypass AWS WAF. If you specify a transformation, AWS WAF performs the transformation on <code>FieldToMatch</code> before inspecting a request for a match.</p> <p>You can only specify a single type of TextTransformation.</p> <p> <b>CMD_LINE</b> </p> <p>When you're concerned that attackers are injecting an operating system command line command and using unusual formatting to disguise some or all of the command, use this option to perform the following transformations:</p> <ul> <li> <p>Delete the following characters: \ " ' ^</p> </li> <li> <p>Delete spaces before the following characters: / (</p> </li> <li> <p>Replace the following characters with a space: , ;</p> </li> <li> <p>Replace multiple spaces with one space</p> </li> <li> <p>Convert uppercase letters (A-Z) to lowercase (a-z)</p> </li> </ul> <p> <b>COMPRESS_WHITE_SPACE</b> </p> <p>Use this option to replace the following characters with a space character (decimal 32):</p> <ul> <li> <p>\f, formfeed, decimal 12</p> </li> <li> <p>\t, tab, decimal 9</p> </li> <li> <p>\n, newline, decimal 10</p> </li> <li> <p>\r, carriage return, decimal 13</p> </li> <li> <p>\v, vertical tab, decimal 11</p> </li> <li> <p>non-breaking space, decimal 160</p> </li> </ul> <p> <code>COMPRESS_WHITE_SPACE</code> also replaces multiple spaces with one space.</p> <p> <b>HTML_ENTITY_DECODE</b> </p> <p>Use this option to replace HTML-encoded characters with unencoded characters. <code>HTML_ENTITY_DECODE</code> performs the following operations:</p> <ul> <li> <p>Replaces <code>(ampersand)quot;</code> with <code>"</code> </p> </li> <li> <p>Replaces <code>(ampersand)nbsp;</code> with a non-breaking space, decimal 160</p> </li> <li> <p>Replaces <code>(ampersand)lt;</code> with a "less than" symbol</p> </li> <li> <p>Replaces <code>(ampersand)gt;</code> with <code>&gt;</code> </p> </li> <li> <p>Replaces characters that are represented in hexadecimal format, <code>(ampersand)#xhhhh;</code>, with the corresponding characters</p> </li> <li> <p>Replaces characters that are represented in decimal format, <code>(ampersand)#nnnn;</code>, with the corresponding characters</p> </li> </ul> <p> <b>LOWERCASE</b> </p> <p>Use this option to convert uppercase letters (A-Z) to lowercase (a-z).</p> <p> <b>URL_DECODE</b> </p> <p>Use this option to decode a URL-encoded value.</p> <p> <b>NONE</b> </p> <p>Specify <code>NONE</code> if you don't want to perform any text transformations.</p>
     #[serde(rename = "TextTransformation")]
-    pub text_transformation: String,
+    pub text_transformation: TextTransformation,
 }
 
 /// <p>A summary of the rule groups you are subscribed to.</p>
@@ -2070,19 +4614,142 @@ pub struct TagResourceRequest {
     pub tags: Vec<Tag>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct TagResourceResponse {}
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct TagResourceResponse {}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TextTransformation {
+    /// `NONE`
+    None,
+    /// `COMPRESS_WHITE_SPACE`
+    CompressWhiteSpace,
+    /// `HTML_ENTITY_DECODE`
+    HtmlEntityDecode,
+    /// `LOWERCASE`
+    Lowercase,
+    /// `CMD_LINE`
+    CmdLine,
+    /// `URL_DECODE`
+    UrlDecode,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TextTransformation {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TextTransformation::None => "NONE",
+            TextTransformation::CompressWhiteSpace => "COMPRESS_WHITE_SPACE",
+            TextTransformation::HtmlEntityDecode => "HTML_ENTITY_DECODE",
+            TextTransformation::Lowercase => "LOWERCASE",
+            TextTransformation::CmdLine => "CMD_LINE",
+            TextTransformation::UrlDecode => "URL_DECODE",
+            TextTransformation::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TextTransformation {
+    fn default() -> TextTransformation {
+        TextTransformation::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TextTransformation {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TextTransformation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TextTransformation {
+    fn from(value: &'a str) -> TextTransformation {
+        match value {
+            "NONE" => TextTransformation::None,
+            "COMPRESS_WHITE_SPACE" => TextTransformation::CompressWhiteSpace,
+            "HTML_ENTITY_DECODE" => TextTransformation::HtmlEntityDecode,
+            "LOWERCASE" => TextTransformation::Lowercase,
+            "CMD_LINE" => TextTransformation::CmdLine,
+            "URL_DECODE" => TextTransformation::UrlDecode,
+            _ => TextTransformation::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TextTransformation {
+    fn from(value: String) -> TextTransformation {
+        match value.as_str() {
+            "NONE" => TextTransformation::None,
+            "COMPRESS_WHITE_SPACE" => TextTransformation::CompressWhiteSpace,
+            "HTML_ENTITY_DECODE" => TextTransformation::HtmlEntityDecode,
+            "LOWERCASE" => TextTransformation::Lowercase,
+            "CMD_LINE" => TextTransformation::CmdLine,
+            "URL_DECODE" => TextTransformation::UrlDecode,
+            _ => TextTransformation::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TextTransformation {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TextTransformation, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TextTransformation {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TextTransformation {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
 
+impl<'de> ::serde::Deserialize<'de> for TextTransformation {
+    fn deserialize<D>(deserializer: D) -> Result<TextTransformation, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>In a <a>GetSampledRequests</a> request, the <code>StartTime</code> and <code>EndTime</code> objects specify the time range for which you want AWS WAF to return a sample of web requests.</p> <p>In a <a>GetSampledRequests</a> response, the <code>StartTime</code> and <code>EndTime</code> objects specify the time range for which AWS WAF actually returned a sample of web requests. AWS WAF gets the specified number of requests from among the first 5,000 requests that your AWS resource receives during the specified time period. If your resource receives more than 5,000 requests during that period, AWS WAF stops sampling after the 5,000th request. In that case, <code>EndTime</code> is the time that AWS WAF received the 5,000th request. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TimeWindow {
     /// <p>The end of the time range from which you want <code>GetSampledRequests</code> to return a sample of the requests that your AWS resource received. Specify the date and time in the following format: <code>"2016-09-27T14:50Z"</code>. You can specify any time range in the previous three hours.</p>
     #[serde(rename = "EndTime")]
-    pub end_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub end_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The beginning of the time range from which you want <code>GetSampledRequests</code> to return a sample of the requests that your AWS resource received. Specify the date and time in the following format: <code>"2016-09-27T14:50Z"</code>. You can specify any time range in the previous three hours.</p>
     #[serde(rename = "StartTime")]
-    pub start_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub start_time: ::chrono::DateTime<::chrono::Utc>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -2391,17 +5058,306 @@ pub struct UpdateXssMatchSetResponse {
 pub struct WafAction {
     /// <p><p>Specifies how you want AWS WAF to respond to requests that match the settings in a <code>Rule</code>. Valid settings include the following:</p> <ul> <li> <p> <code>ALLOW</code>: AWS WAF allows requests</p> </li> <li> <p> <code>BLOCK</code>: AWS WAF blocks requests</p> </li> <li> <p> <code>COUNT</code>: AWS WAF increments a counter of the requests that match all of the conditions in the rule. AWS WAF then continues to inspect the web request based on the remaining rules in the web ACL. You can&#39;t specify <code>COUNT</code> for the default action for a <code>WebACL</code>.</p> </li> </ul></p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: WafActionType,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum WafActionType {
+    /// `BLOCK`
+    Block,
+    /// `ALLOW`
+    Allow,
+    /// `COUNT`
+    Count,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl WafActionType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            WafActionType::Block => "BLOCK",
+            WafActionType::Allow => "ALLOW",
+            WafActionType::Count => "COUNT",
+            WafActionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for WafActionType {
+    fn default() -> WafActionType {
+        WafActionType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for WafActionType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for WafActionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for WafActionType {
+    fn from(value: &'a str) -> WafActionType {
+        match value {
+            "BLOCK" => WafActionType::Block,
+            "ALLOW" => WafActionType::Allow,
+            "COUNT" => WafActionType::Count,
+            _ => WafActionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for WafActionType {
+    fn from(value: String) -> WafActionType {
+        match value.as_str() {
+            "BLOCK" => WafActionType::Block,
+            "ALLOW" => WafActionType::Allow,
+            "COUNT" => WafActionType::Count,
+            _ => WafActionType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for WafActionType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<WafActionType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for WafActionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for WafActionType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for WafActionType {
+    fn deserialize<D>(deserializer: D) -> Result<WafActionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The action to take if any rule within the <code>RuleGroup</code> matches a request. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct WafOverrideAction {
     /// <p> <code>COUNT</code> overrides the action specified by the individual rule within a <code>RuleGroup</code> . If set to <code>NONE</code>, the rule's action will take place.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: WafOverrideActionType,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum WafOverrideActionType {
+    /// `NONE`
+    None,
+    /// `COUNT`
+    Count,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl WafOverrideActionType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            WafOverrideActionType::None => "NONE",
+            WafOverrideActionType::Count => "COUNT",
+            WafOverrideActionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for WafOverrideActionType {
+    fn default() -> WafOverrideActionType {
+        WafOverrideActionType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for WafOverrideActionType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for WafOverrideActionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for WafOverrideActionType {
+    fn from(value: &'a str) -> WafOverrideActionType {
+        match value {
+            "NONE" => WafOverrideActionType::None,
+            "COUNT" => WafOverrideActionType::Count,
+            _ => WafOverrideActionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for WafOverrideActionType {
+    fn from(value: String) -> WafOverrideActionType {
+        match value.as_str() {
+            "NONE" => WafOverrideActionType::None,
+            "COUNT" => WafOverrideActionType::Count,
+            _ => WafOverrideActionType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for WafOverrideActionType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<WafOverrideActionType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for WafOverrideActionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for WafOverrideActionType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for WafOverrideActionType {
+    fn deserialize<D>(deserializer: D) -> Result<WafOverrideActionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum WafRuleType {
+    /// `REGULAR`
+    Regular,
+    /// `RATE_BASED`
+    RateBased,
+    /// `GROUP`
+    Group,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl WafRuleType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            WafRuleType::Regular => "REGULAR",
+            WafRuleType::RateBased => "RATE_BASED",
+            WafRuleType::Group => "GROUP",
+            WafRuleType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for WafRuleType {
+    fn default() -> WafRuleType {
+        WafRuleType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for WafRuleType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for WafRuleType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for WafRuleType {
+    fn from(value: &'a str) -> WafRuleType {
+        match value {
+            "REGULAR" => WafRuleType::Regular,
+            "RATE_BASED" => WafRuleType::RateBased,
+            "GROUP" => WafRuleType::Group,
+            _ => WafRuleType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for WafRuleType {
+    fn from(value: String) -> WafRuleType {
+        match value.as_str() {
+            "REGULAR" => WafRuleType::Regular,
+            "RATE_BASED" => WafRuleType::RateBased,
+            "GROUP" => WafRuleType::Group,
+            _ => WafRuleType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for WafRuleType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<WafRuleType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for WafRuleType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for WafRuleType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for WafRuleType {
+    fn deserialize<D>(deserializer: D) -> Result<WafRuleType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Contains the <code>Rules</code> that identify the requests that you want to allow, block, or count. In a <code>WebACL</code>, you also specify a default action (<code>ALLOW</code> or <code>BLOCK</code>), and the action for each <code>Rule</code> that you add to a <code>WebACL</code>, for example, block requests from specified IP addresses or block requests from specified referrers. You also associate the <code>WebACL</code> with a CloudFront distribution to identify the requests that you want AWS WAF to filter. If you add more than one <code>Rule</code> to a <code>WebACL</code>, a request needs to match only one of the specifications to be allowed, blocked, or counted. For more information, see <a>UpdateWebACL</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -2447,7 +5403,7 @@ pub struct WebACLSummary {
 pub struct WebACLUpdate {
     /// <p>Specifies whether to insert a <code>Rule</code> into or delete a <code>Rule</code> from a <code>WebACL</code>.</p>
     #[serde(rename = "Action")]
-    pub action: String,
+    pub action: ChangeAction,
     /// <p>The <code>ActivatedRule</code> object in an <a>UpdateWebACL</a> request specifies a <code>Rule</code> that you want to insert or delete, the priority of the <code>Rule</code> in the <code>WebACL</code>, and the action that you want AWS WAF to take when a web request matches the <code>Rule</code> (<code>ALLOW</code>, <code>BLOCK</code>, or <code>COUNT</code>).</p>
     #[serde(rename = "ActivatedRule")]
     pub activated_rule: ActivatedRule,
@@ -2487,7 +5443,7 @@ pub struct XssMatchSetSummary {
 pub struct XssMatchSetUpdate {
     /// <p>Specify <code>INSERT</code> to add an <a>XssMatchSetUpdate</a> to an <a>XssMatchSet</a>. Use <code>DELETE</code> to remove an <code>XssMatchSetUpdate</code> from an <code>XssMatchSet</code>.</p>
     #[serde(rename = "Action")]
-    pub action: String,
+    pub action: ChangeAction,
     /// <p>Specifies the part of a web request that you want AWS WAF to inspect for cross-site scripting attacks and, if you want AWS WAF to inspect a header, the name of the header.</p>
     #[serde(rename = "XssMatchTuple")]
     pub xss_match_tuple: XssMatchTuple,
@@ -2501,7 +5457,7 @@ pub struct XssMatchTuple {
     pub field_to_match: FieldToMatch,
     /// <p>Text transformations eliminate some of the unusual formatting that attackers use in web requests in an effort to bypass AWS WAF. If you specify a transformation, AWS WAF performs the transformation on <code>FieldToMatch</code> before inspecting a request for a match.</p> <p>You can only specify a single type of TextTransformation.</p> <p> <b>CMD_LINE</b> </p> <p>When you're concerned that attackers are injecting an operating system command line command and using unusual formatting to disguise some or all of the command, use this option to perform the following transformations:</p> <ul> <li> <p>Delete the following characters: \ " ' ^</p> </li> <li> <p>Delete spaces before the following characters: / (</p> </li> <li> <p>Replace the following characters with a space: , ;</p> </li> <li> <p>Replace multiple spaces with one space</p> </li> <li> <p>Convert uppercase letters (A-Z) to lowercase (a-z)</p> </li> </ul> <p> <b>COMPRESS_WHITE_SPACE</b> </p> <p>Use this option to replace the following characters with a space character (decimal 32):</p> <ul> <li> <p>\f, formfeed, decimal 12</p> </li> <li> <p>\t, tab, decimal 9</p> </li> <li> <p>\n, newline, decimal 10</p> </li> <li> <p>\r, carriage return, decimal 13</p> </li> <li> <p>\v, vertical tab, decimal 11</p> </li> <li> <p>non-breaking space, decimal 160</p> </li> </ul> <p> <code>COMPRESS_WHITE_SPACE</code> also replaces multiple spaces with one space.</p> <p> <b>HTML_ENTITY_DECODE</b> </p> <p>Use this option to replace HTML-encoded characters with unencoded characters. <code>HTML_ENTITY_DECODE</code> performs the following operations:</p> <ul> <li> <p>Replaces <code>(ampersand)quot;</code> with <code>"</code> </p> </li> <li> <p>Replaces <code>(ampersand)nbsp;</code> with a non-breaking space, decimal 160</p> </li> <li> <p>Replaces <code>(ampersand)lt;</code> with a "less than" symbol</p> </li> <li> <p>Replaces <code>(ampersand)gt;</code> with <code>&gt;</code> </p> </li> <li> <p>Replaces characters that are represented in hexadecimal format, <code>(ampersand)#xhhhh;</code>, with the corresponding characters</p> </li> <li> <p>Replaces characters that are represented in decimal format, <code>(ampersand)#nnnn;</code>, with the corresponding characters</p> </li> </ul> <p> <b>LOWERCASE</b> </p> <p>Use this option to convert uppercase letters (A-Z) to lowercase (a-z).</p> <p> <b>URL_DECODE</b> </p> <p>Use this option to decode a URL-encoded value.</p> <p> <b>NONE</b> </p> <p>Specify <code>NONE</code> if you don't want to perform any text transformations.</p>
     #[serde(rename = "TextTransformation")]
-    pub text_transformation: String,
+    pub text_transformation: TextTransformation,
 }
 
 /// Errors returned by CreateByteMatchSet
@@ -7419,11 +10375,12 @@ pub struct WafClient {
 impl WafClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> WafClient {
         WafClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "WAF"),
         }
     }
 
@@ -7455,6 +10412,7 @@ impl Waf for WafClient {
         input: CreateByteMatchSetRequest,
     ) -> Result<CreateByteMatchSetResponse, RusotoError<CreateByteMatchSetError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("CreateByteMatchSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.CreateByteMatchSet");
@@ -7483,6 +10441,7 @@ impl Waf for WafClient {
         input: CreateGeoMatchSetRequest,
     ) -> Result<CreateGeoMatchSetResponse, RusotoError<CreateGeoMatchSetError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("CreateGeoMatchSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.CreateGeoMatchSet");
@@ -7511,6 +10470,7 @@ impl Waf for WafClient {
         input: CreateIPSetRequest,
     ) -> Result<CreateIPSetResponse, RusotoError<CreateIPSetError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("CreateIPSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.CreateIPSet");
@@ -7538,6 +10498,7 @@ impl Waf for WafClient {
         input: CreateRateBasedRuleRequest,
     ) -> Result<CreateRateBasedRuleResponse, RusotoError<CreateRateBasedRuleError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("CreateRateBasedRule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.CreateRateBasedRule");
@@ -7566,6 +10527,7 @@ impl Waf for WafClient {
         input: CreateRegexMatchSetRequest,
     ) -> Result<CreateRegexMatchSetResponse, RusotoError<CreateRegexMatchSetError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("CreateRegexMatchSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.CreateRegexMatchSet");
@@ -7594,6 +10556,7 @@ impl Waf for WafClient {
         input: CreateRegexPatternSetRequest,
     ) -> Result<CreateRegexPatternSetResponse, RusotoError<CreateRegexPatternSetError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("CreateRegexPatternSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.CreateRegexPatternSet");
@@ -7622,6 +10585,7 @@ impl Waf for WafClient {
         input: CreateRuleRequest,
     ) -> Result<CreateRuleResponse, RusotoError<CreateRuleError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("CreateRule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.CreateRule");
@@ -7649,6 +10613,7 @@ impl Waf for WafClient {
         input: CreateRuleGroupRequest,
     ) -> Result<CreateRuleGroupResponse, RusotoError<CreateRuleGroupError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("CreateRuleGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.CreateRuleGroup");
@@ -7676,6 +10641,7 @@ impl Waf for WafClient {
         input: CreateSizeConstraintSetRequest,
     ) -> Result<CreateSizeConstraintSetResponse, RusotoError<CreateSizeConstraintSetError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("CreateSizeConstraintSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.CreateSizeConstraintSet");
@@ -7705,6 +10671,7 @@ impl Waf for WafClient {
     ) -> Result<CreateSqlInjectionMatchSetResponse, RusotoError<CreateSqlInjectionMatchSetError>>
     {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("CreateSqlInjectionMatchSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.CreateSqlInjectionMatchSet");
@@ -7733,6 +10700,7 @@ impl Waf for WafClient {
         input: CreateWebACLRequest,
     ) -> Result<CreateWebACLResponse, RusotoError<CreateWebACLError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("CreateWebACL");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.CreateWebACL");
@@ -7760,6 +10728,7 @@ impl Waf for WafClient {
         input: CreateXssMatchSetRequest,
     ) -> Result<CreateXssMatchSetResponse, RusotoError<CreateXssMatchSetError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("CreateXssMatchSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.CreateXssMatchSet");
@@ -7788,6 +10757,7 @@ impl Waf for WafClient {
         input: DeleteByteMatchSetRequest,
     ) -> Result<DeleteByteMatchSetResponse, RusotoError<DeleteByteMatchSetError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("DeleteByteMatchSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.DeleteByteMatchSet");
@@ -7816,6 +10786,7 @@ impl Waf for WafClient {
         input: DeleteGeoMatchSetRequest,
     ) -> Result<DeleteGeoMatchSetResponse, RusotoError<DeleteGeoMatchSetError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("DeleteGeoMatchSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.DeleteGeoMatchSet");
@@ -7844,6 +10815,7 @@ impl Waf for WafClient {
         input: DeleteIPSetRequest,
     ) -> Result<DeleteIPSetResponse, RusotoError<DeleteIPSetError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("DeleteIPSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.DeleteIPSet");
@@ -7872,6 +10844,7 @@ impl Waf for WafClient {
     ) -> Result<DeleteLoggingConfigurationResponse, RusotoError<DeleteLoggingConfigurationError>>
     {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("DeleteLoggingConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.DeleteLoggingConfiguration");
@@ -7900,6 +10873,7 @@ impl Waf for WafClient {
         input: DeletePermissionPolicyRequest,
     ) -> Result<DeletePermissionPolicyResponse, RusotoError<DeletePermissionPolicyError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("DeletePermissionPolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.DeletePermissionPolicy");
@@ -7928,6 +10902,7 @@ impl Waf for WafClient {
         input: DeleteRateBasedRuleRequest,
     ) -> Result<DeleteRateBasedRuleResponse, RusotoError<DeleteRateBasedRuleError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("DeleteRateBasedRule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.DeleteRateBasedRule");
@@ -7956,6 +10931,7 @@ impl Waf for WafClient {
         input: DeleteRegexMatchSetRequest,
     ) -> Result<DeleteRegexMatchSetResponse, RusotoError<DeleteRegexMatchSetError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("DeleteRegexMatchSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.DeleteRegexMatchSet");
@@ -7984,6 +10960,7 @@ impl Waf for WafClient {
         input: DeleteRegexPatternSetRequest,
     ) -> Result<DeleteRegexPatternSetResponse, RusotoError<DeleteRegexPatternSetError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("DeleteRegexPatternSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.DeleteRegexPatternSet");
@@ -8012,6 +10989,7 @@ impl Waf for WafClient {
         input: DeleteRuleRequest,
     ) -> Result<DeleteRuleResponse, RusotoError<DeleteRuleError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("DeleteRule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.DeleteRule");
@@ -8039,6 +11017,7 @@ impl Waf for WafClient {
         input: DeleteRuleGroupRequest,
     ) -> Result<DeleteRuleGroupResponse, RusotoError<DeleteRuleGroupError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("DeleteRuleGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.DeleteRuleGroup");
@@ -8066,6 +11045,7 @@ impl Waf for WafClient {
         input: DeleteSizeConstraintSetRequest,
     ) -> Result<DeleteSizeConstraintSetResponse, RusotoError<DeleteSizeConstraintSetError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("DeleteSizeConstraintSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.DeleteSizeConstraintSet");
@@ -8095,6 +11075,7 @@ impl Waf for WafClient {
     ) -> Result<DeleteSqlInjectionMatchSetResponse, RusotoError<DeleteSqlInjectionMatchSetError>>
     {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("DeleteSqlInjectionMatchSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.DeleteSqlInjectionMatchSet");
@@ -8123,6 +11104,7 @@ impl Waf for WafClient {
         input: DeleteWebACLRequest,
     ) -> Result<DeleteWebACLResponse, RusotoError<DeleteWebACLError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("DeleteWebACL");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.DeleteWebACL");
@@ -8150,6 +11132,7 @@ impl Waf for WafClient {
         input: DeleteXssMatchSetRequest,
     ) -> Result<DeleteXssMatchSetResponse, RusotoError<DeleteXssMatchSetError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("DeleteXssMatchSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.DeleteXssMatchSet");
@@ -8178,6 +11161,7 @@ impl Waf for WafClient {
         input: GetByteMatchSetRequest,
     ) -> Result<GetByteMatchSetResponse, RusotoError<GetByteMatchSetError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("GetByteMatchSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.GetByteMatchSet");
@@ -8204,6 +11188,7 @@ impl Waf for WafClient {
         &self,
     ) -> Result<GetChangeTokenResponse, RusotoError<GetChangeTokenError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("GetChangeToken");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.GetChangeToken");
@@ -8230,6 +11215,7 @@ impl Waf for WafClient {
         input: GetChangeTokenStatusRequest,
     ) -> Result<GetChangeTokenStatusResponse, RusotoError<GetChangeTokenStatusError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("GetChangeTokenStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.GetChangeTokenStatus");
@@ -8258,6 +11244,7 @@ impl Waf for WafClient {
         input: GetGeoMatchSetRequest,
     ) -> Result<GetGeoMatchSetResponse, RusotoError<GetGeoMatchSetError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("GetGeoMatchSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.GetGeoMatchSet");
@@ -8285,6 +11272,7 @@ impl Waf for WafClient {
         input: GetIPSetRequest,
     ) -> Result<GetIPSetResponse, RusotoError<GetIPSetError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("GetIPSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.GetIPSet");
@@ -8312,6 +11300,7 @@ impl Waf for WafClient {
         input: GetLoggingConfigurationRequest,
     ) -> Result<GetLoggingConfigurationResponse, RusotoError<GetLoggingConfigurationError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("GetLoggingConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.GetLoggingConfiguration");
@@ -8340,6 +11329,7 @@ impl Waf for WafClient {
         input: GetPermissionPolicyRequest,
     ) -> Result<GetPermissionPolicyResponse, RusotoError<GetPermissionPolicyError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("GetPermissionPolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.GetPermissionPolicy");
@@ -8368,6 +11358,7 @@ impl Waf for WafClient {
         input: GetRateBasedRuleRequest,
     ) -> Result<GetRateBasedRuleResponse, RusotoError<GetRateBasedRuleError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("GetRateBasedRule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.GetRateBasedRule");
@@ -8397,6 +11388,7 @@ impl Waf for WafClient {
     ) -> Result<GetRateBasedRuleManagedKeysResponse, RusotoError<GetRateBasedRuleManagedKeysError>>
     {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("GetRateBasedRuleManagedKeys");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8428,6 +11420,7 @@ impl Waf for WafClient {
         input: GetRegexMatchSetRequest,
     ) -> Result<GetRegexMatchSetResponse, RusotoError<GetRegexMatchSetError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("GetRegexMatchSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.GetRegexMatchSet");
@@ -8456,6 +11449,7 @@ impl Waf for WafClient {
         input: GetRegexPatternSetRequest,
     ) -> Result<GetRegexPatternSetResponse, RusotoError<GetRegexPatternSetError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("GetRegexPatternSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.GetRegexPatternSet");
@@ -8484,6 +11478,7 @@ impl Waf for WafClient {
         input: GetRuleRequest,
     ) -> Result<GetRuleResponse, RusotoError<GetRuleError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("GetRule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.GetRule");
@@ -8511,6 +11506,7 @@ impl Waf for WafClient {
         input: GetRuleGroupRequest,
     ) -> Result<GetRuleGroupResponse, RusotoError<GetRuleGroupError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("GetRuleGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.GetRuleGroup");
@@ -8538,6 +11534,7 @@ impl Waf for WafClient {
         input: GetSampledRequestsRequest,
     ) -> Result<GetSampledRequestsResponse, RusotoError<GetSampledRequestsError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("GetSampledRequests");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.GetSampledRequests");
@@ -8566,6 +11563,7 @@ impl Waf for WafClient {
         input: GetSizeConstraintSetRequest,
     ) -> Result<GetSizeConstraintSetResponse, RusotoError<GetSizeConstraintSetError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("GetSizeConstraintSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.GetSizeConstraintSet");
@@ -8594,6 +11592,7 @@ impl Waf for WafClient {
         input: GetSqlInjectionMatchSetRequest,
     ) -> Result<GetSqlInjectionMatchSetResponse, RusotoError<GetSqlInjectionMatchSetError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("GetSqlInjectionMatchSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.GetSqlInjectionMatchSet");
@@ -8622,6 +11621,7 @@ impl Waf for WafClient {
         input: GetWebACLRequest,
     ) -> Result<GetWebACLResponse, RusotoError<GetWebACLError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("GetWebACL");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.GetWebACL");
@@ -8649,6 +11649,7 @@ impl Waf for WafClient {
         input: GetXssMatchSetRequest,
     ) -> Result<GetXssMatchSetResponse, RusotoError<GetXssMatchSetError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("GetXssMatchSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.GetXssMatchSet");
@@ -8679,6 +11680,7 @@ impl Waf for WafClient {
         RusotoError<ListActivatedRulesInRuleGroupError>,
     > {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("ListActivatedRulesInRuleGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8710,6 +11712,7 @@ impl Waf for WafClient {
         input: ListByteMatchSetsRequest,
     ) -> Result<ListByteMatchSetsResponse, RusotoError<ListByteMatchSetsError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("ListByteMatchSets");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.ListByteMatchSets");
@@ -8738,6 +11741,7 @@ impl Waf for WafClient {
         input: ListGeoMatchSetsRequest,
     ) -> Result<ListGeoMatchSetsResponse, RusotoError<ListGeoMatchSetsError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("ListGeoMatchSets");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.ListGeoMatchSets");
@@ -8766,6 +11770,7 @@ impl Waf for WafClient {
         input: ListIPSetsRequest,
     ) -> Result<ListIPSetsResponse, RusotoError<ListIPSetsError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("ListIPSets");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.ListIPSets");
@@ -8794,6 +11799,7 @@ impl Waf for WafClient {
     ) -> Result<ListLoggingConfigurationsResponse, RusotoError<ListLoggingConfigurationsError>>
     {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("ListLoggingConfigurations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.ListLoggingConfigurations");
@@ -8822,6 +11828,7 @@ impl Waf for WafClient {
         input: ListRateBasedRulesRequest,
     ) -> Result<ListRateBasedRulesResponse, RusotoError<ListRateBasedRulesError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("ListRateBasedRules");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.ListRateBasedRules");
@@ -8850,6 +11857,7 @@ impl Waf for WafClient {
         input: ListRegexMatchSetsRequest,
     ) -> Result<ListRegexMatchSetsResponse, RusotoError<ListRegexMatchSetsError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("ListRegexMatchSets");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.ListRegexMatchSets");
@@ -8878,6 +11886,7 @@ impl Waf for WafClient {
         input: ListRegexPatternSetsRequest,
     ) -> Result<ListRegexPatternSetsResponse, RusotoError<ListRegexPatternSetsError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("ListRegexPatternSets");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.ListRegexPatternSets");
@@ -8906,6 +11915,7 @@ impl Waf for WafClient {
         input: ListRuleGroupsRequest,
     ) -> Result<ListRuleGroupsResponse, RusotoError<ListRuleGroupsError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("ListRuleGroups");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.ListRuleGroups");
@@ -8933,6 +11943,7 @@ impl Waf for WafClient {
         input: ListRulesRequest,
     ) -> Result<ListRulesResponse, RusotoError<ListRulesError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("ListRules");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.ListRules");
@@ -8960,6 +11971,7 @@ impl Waf for WafClient {
         input: ListSizeConstraintSetsRequest,
     ) -> Result<ListSizeConstraintSetsResponse, RusotoError<ListSizeConstraintSetsError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("ListSizeConstraintSets");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.ListSizeConstraintSets");
@@ -8989,6 +12001,7 @@ impl Waf for WafClient {
     ) -> Result<ListSqlInjectionMatchSetsResponse, RusotoError<ListSqlInjectionMatchSetsError>>
     {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("ListSqlInjectionMatchSets");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.ListSqlInjectionMatchSets");
@@ -9017,6 +12030,7 @@ impl Waf for WafClient {
         input: ListSubscribedRuleGroupsRequest,
     ) -> Result<ListSubscribedRuleGroupsResponse, RusotoError<ListSubscribedRuleGroupsError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("ListSubscribedRuleGroups");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.ListSubscribedRuleGroups");
@@ -9044,6 +12058,7 @@ impl Waf for WafClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.ListTagsForResource");
@@ -9072,6 +12087,7 @@ impl Waf for WafClient {
         input: ListWebACLsRequest,
     ) -> Result<ListWebACLsResponse, RusotoError<ListWebACLsError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("ListWebACLs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.ListWebACLs");
@@ -9099,6 +12115,7 @@ impl Waf for WafClient {
         input: ListXssMatchSetsRequest,
     ) -> Result<ListXssMatchSetsResponse, RusotoError<ListXssMatchSetsError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("ListXssMatchSets");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.ListXssMatchSets");
@@ -9127,6 +12144,7 @@ impl Waf for WafClient {
         input: PutLoggingConfigurationRequest,
     ) -> Result<PutLoggingConfigurationResponse, RusotoError<PutLoggingConfigurationError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("PutLoggingConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.PutLoggingConfiguration");
@@ -9155,6 +12173,7 @@ impl Waf for WafClient {
         input: PutPermissionPolicyRequest,
     ) -> Result<PutPermissionPolicyResponse, RusotoError<PutPermissionPolicyError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("PutPermissionPolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.PutPermissionPolicy");
@@ -9182,6 +12201,7 @@ impl Waf for WafClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.TagResource");
@@ -9208,6 +12228,7 @@ impl Waf for WafClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.UntagResource");
@@ -9235,6 +12256,7 @@ impl Waf for WafClient {
         input: UpdateByteMatchSetRequest,
     ) -> Result<UpdateByteMatchSetResponse, RusotoError<UpdateByteMatchSetError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("UpdateByteMatchSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.UpdateByteMatchSet");
@@ -9263,6 +12285,7 @@ impl Waf for WafClient {
         input: UpdateGeoMatchSetRequest,
     ) -> Result<UpdateGeoMatchSetResponse, RusotoError<UpdateGeoMatchSetError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("UpdateGeoMatchSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.UpdateGeoMatchSet");
@@ -9291,6 +12314,7 @@ impl Waf for WafClient {
         input: UpdateIPSetRequest,
     ) -> Result<UpdateIPSetResponse, RusotoError<UpdateIPSetError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("UpdateIPSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.UpdateIPSet");
@@ -9318,6 +12342,7 @@ impl Waf for WafClient {
         input: UpdateRateBasedRuleRequest,
     ) -> Result<UpdateRateBasedRuleResponse, RusotoError<UpdateRateBasedRuleError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("UpdateRateBasedRule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.UpdateRateBasedRule");
@@ -9346,6 +12371,7 @@ impl Waf for WafClient {
         input: UpdateRegexMatchSetRequest,
     ) -> Result<UpdateRegexMatchSetResponse, RusotoError<UpdateRegexMatchSetError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("UpdateRegexMatchSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.UpdateRegexMatchSet");
@@ -9374,6 +12400,7 @@ impl Waf for WafClient {
         input: UpdateRegexPatternSetRequest,
     ) -> Result<UpdateRegexPatternSetResponse, RusotoError<UpdateRegexPatternSetError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("UpdateRegexPatternSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.UpdateRegexPatternSet");
@@ -9402,6 +12429,7 @@ impl Waf for WafClient {
         input: UpdateRuleRequest,
     ) -> Result<UpdateRuleResponse, RusotoError<UpdateRuleError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("UpdateRule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.UpdateRule");
@@ -9429,6 +12457,7 @@ impl Waf for WafClient {
         input: UpdateRuleGroupRequest,
     ) -> Result<UpdateRuleGroupResponse, RusotoError<UpdateRuleGroupError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("UpdateRuleGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.UpdateRuleGroup");
@@ -9456,6 +12485,7 @@ impl Waf for WafClient {
         input: UpdateSizeConstraintSetRequest,
     ) -> Result<UpdateSizeConstraintSetResponse, RusotoError<UpdateSizeConstraintSetError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("UpdateSizeConstraintSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.UpdateSizeConstraintSet");
@@ -9485,6 +12515,7 @@ impl Waf for WafClient {
     ) -> Result<UpdateSqlInjectionMatchSetResponse, RusotoError<UpdateSqlInjectionMatchSetError>>
     {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("UpdateSqlInjectionMatchSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.UpdateSqlInjectionMatchSet");
@@ -9513,6 +12544,7 @@ impl Waf for WafClient {
         input: UpdateWebACLRequest,
     ) -> Result<UpdateWebACLResponse, RusotoError<UpdateWebACLError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("UpdateWebACL");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.UpdateWebACL");
@@ -9540,6 +12572,7 @@ impl Waf for WafClient {
         input: UpdateXssMatchSetRequest,
     ) -> Result<UpdateXssMatchSetResponse, RusotoError<UpdateXssMatchSetError>> {
         let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("UpdateXssMatchSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_20150824.UpdateXssMatchSet");
@@ -9562,3 +12595,546 @@ impl Waf for WafClient {
         }
     }
 }
+impl WafClient {
+    /// Auto-paginating version of `get_rate_based_rule_managed_keys`, streaming every page of results.
+    pub fn get_rate_based_rule_managed_keys_pages(
+        &self,
+        input: GetRateBasedRuleManagedKeysRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        GetRateBasedRuleManagedKeysResponse,
+        GetRateBasedRuleManagedKeysError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: WafClient, input: GetRateBasedRuleManagedKeysRequest| async move {
+                client.get_rate_based_rule_managed_keys(input).await
+            },
+            |input: &GetRateBasedRuleManagedKeysRequest,
+             output: &GetRateBasedRuleManagedKeysResponse| {
+                Some(GetRateBasedRuleManagedKeysRequest {
+                    next_marker: Some(output.next_marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_rate_based_rule_managed_keys`, streaming the `ManagedKeys` of every page of results.
+    pub fn get_rate_based_rule_managed_keys_items(
+        &self,
+        input: GetRateBasedRuleManagedKeysRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, GetRateBasedRuleManagedKeysError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_rate_based_rule_managed_keys_pages(input),
+            |output: GetRateBasedRuleManagedKeysResponse| output.managed_keys.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_activated_rules_in_rule_group`, streaming every page of results.
+    pub fn list_activated_rules_in_rule_group_pages(
+        &self,
+        input: ListActivatedRulesInRuleGroupRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListActivatedRulesInRuleGroupResponse,
+        ListActivatedRulesInRuleGroupError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: WafClient, input: ListActivatedRulesInRuleGroupRequest| async move {
+                client.list_activated_rules_in_rule_group(input).await
+            },
+            |input: &ListActivatedRulesInRuleGroupRequest,
+             output: &ListActivatedRulesInRuleGroupResponse| {
+                Some(ListActivatedRulesInRuleGroupRequest {
+                    next_marker: Some(output.next_marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_activated_rules_in_rule_group`, streaming the `ActivatedRules` of every page of results.
+    pub fn list_activated_rules_in_rule_group_items(
+        &self,
+        input: ListActivatedRulesInRuleGroupRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ActivatedRule, ListActivatedRulesInRuleGroupError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_activated_rules_in_rule_group_pages(input),
+            |output: ListActivatedRulesInRuleGroupResponse| {
+                output.activated_rules.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_byte_match_sets`, streaming every page of results.
+    pub fn list_byte_match_sets_pages(
+        &self,
+        input: ListByteMatchSetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListByteMatchSetsResponse, ListByteMatchSetsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: WafClient, input: ListByteMatchSetsRequest| async move {
+                client.list_byte_match_sets(input).await
+            },
+            |input: &ListByteMatchSetsRequest, output: &ListByteMatchSetsResponse| {
+                Some(ListByteMatchSetsRequest {
+                    next_marker: Some(output.next_marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_byte_match_sets`, streaming the `ByteMatchSets` of every page of results.
+    pub fn list_byte_match_sets_items(
+        &self,
+        input: ListByteMatchSetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ByteMatchSetSummary, ListByteMatchSetsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_byte_match_sets_pages(input),
+            |output: ListByteMatchSetsResponse| output.byte_match_sets.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_geo_match_sets`, streaming every page of results.
+    pub fn list_geo_match_sets_pages(
+        &self,
+        input: ListGeoMatchSetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListGeoMatchSetsResponse, ListGeoMatchSetsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: WafClient, input: ListGeoMatchSetsRequest| async move {
+                client.list_geo_match_sets(input).await
+            },
+            |input: &ListGeoMatchSetsRequest, output: &ListGeoMatchSetsResponse| {
+                Some(ListGeoMatchSetsRequest {
+                    next_marker: Some(output.next_marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_geo_match_sets`, streaming the `GeoMatchSets` of every page of results.
+    pub fn list_geo_match_sets_items(
+        &self,
+        input: ListGeoMatchSetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GeoMatchSetSummary, ListGeoMatchSetsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_geo_match_sets_pages(input),
+            |output: ListGeoMatchSetsResponse| output.geo_match_sets.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_ip_sets`, streaming every page of results.
+    pub fn list_ip_sets_pages(
+        &self,
+        input: ListIPSetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListIPSetsResponse, ListIPSetsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: WafClient, input: ListIPSetsRequest| async move {
+                client.list_ip_sets(input).await
+            },
+            |input: &ListIPSetsRequest, output: &ListIPSetsResponse| {
+                Some(ListIPSetsRequest {
+                    next_marker: Some(output.next_marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_ip_sets`, streaming the `IPSets` of every page of results.
+    pub fn list_ip_sets_items(
+        &self,
+        input: ListIPSetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<IPSetSummary, ListIPSetsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_ip_sets_pages(input),
+            |output: ListIPSetsResponse| output.ip_sets.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_logging_configurations`, streaming every page of results.
+    pub fn list_logging_configurations_pages(
+        &self,
+        input: ListLoggingConfigurationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListLoggingConfigurationsResponse,
+        ListLoggingConfigurationsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: WafClient, input: ListLoggingConfigurationsRequest| async move {
+                client.list_logging_configurations(input).await
+            },
+            |input: &ListLoggingConfigurationsRequest,
+             output: &ListLoggingConfigurationsResponse| {
+                Some(ListLoggingConfigurationsRequest {
+                    next_marker: Some(output.next_marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_logging_configurations`, streaming the `LoggingConfigurations` of every page of results.
+    pub fn list_logging_configurations_items(
+        &self,
+        input: ListLoggingConfigurationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<LoggingConfiguration, ListLoggingConfigurationsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_logging_configurations_pages(input),
+            |output: ListLoggingConfigurationsResponse| {
+                output.logging_configurations.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_rate_based_rules`, streaming every page of results.
+    pub fn list_rate_based_rules_pages(
+        &self,
+        input: ListRateBasedRulesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListRateBasedRulesResponse, ListRateBasedRulesError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: WafClient, input: ListRateBasedRulesRequest| async move {
+                client.list_rate_based_rules(input).await
+            },
+            |input: &ListRateBasedRulesRequest, output: &ListRateBasedRulesResponse| {
+                Some(ListRateBasedRulesRequest {
+                    next_marker: Some(output.next_marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_rate_based_rules`, streaming the `Rules` of every page of results.
+    pub fn list_rate_based_rules_items(
+        &self,
+        input: ListRateBasedRulesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<RuleSummary, ListRateBasedRulesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_rate_based_rules_pages(input),
+            |output: ListRateBasedRulesResponse| output.rules.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_regex_match_sets`, streaming every page of results.
+    pub fn list_regex_match_sets_pages(
+        &self,
+        input: ListRegexMatchSetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListRegexMatchSetsResponse, ListRegexMatchSetsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: WafClient, input: ListRegexMatchSetsRequest| async move {
+                client.list_regex_match_sets(input).await
+            },
+            |input: &ListRegexMatchSetsRequest, output: &ListRegexMatchSetsResponse| {
+                Some(ListRegexMatchSetsRequest {
+                    next_marker: Some(output.next_marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_regex_match_sets`, streaming the `RegexMatchSets` of every page of results.
+    pub fn list_regex_match_sets_items(
+        &self,
+        input: ListRegexMatchSetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<RegexMatchSetSummary, ListRegexMatchSetsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_regex_match_sets_pages(input),
+            |output: ListRegexMatchSetsResponse| output.regex_match_sets.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_regex_pattern_sets`, streaming every page of results.
+    pub fn list_regex_pattern_sets_pages(
+        &self,
+        input: ListRegexPatternSetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListRegexPatternSetsResponse,
+        ListRegexPatternSetsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: WafClient, input: ListRegexPatternSetsRequest| async move {
+                client.list_regex_pattern_sets(input).await
+            },
+            |input: &ListRegexPatternSetsRequest, output: &ListRegexPatternSetsResponse| {
+                Some(ListRegexPatternSetsRequest {
+                    next_marker: Some(output.next_marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_regex_pattern_sets`, streaming the `RegexPatternSets` of every page of results.
+    pub fn list_regex_pattern_sets_items(
+        &self,
+        input: ListRegexPatternSetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<RegexPatternSetSummary, ListRegexPatternSetsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_regex_pattern_sets_pages(input),
+            |output: ListRegexPatternSetsResponse| output.regex_pattern_sets.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_rule_groups`, streaming every page of results.
+    pub fn list_rule_groups_pages(
+        &self,
+        input: ListRuleGroupsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListRuleGroupsResponse, ListRuleGroupsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: WafClient, input: ListRuleGroupsRequest| async move {
+                client.list_rule_groups(input).await
+            },
+            |input: &ListRuleGroupsRequest, output: &ListRuleGroupsResponse| {
+                Some(ListRuleGroupsRequest {
+                    next_marker: Some(output.next_marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_rule_groups`, streaming the `RuleGroups` of every page of results.
+    pub fn list_rule_groups_items(
+        &self,
+        input: ListRuleGroupsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<RuleGroupSummary, ListRuleGroupsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_rule_groups_pages(input),
+            |output: ListRuleGroupsResponse| output.rule_groups.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_rules`, streaming every page of results.
+    pub fn list_rules_pages(
+        &self,
+        input: ListRulesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListRulesResponse, ListRulesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: WafClient, input: ListRulesRequest| async move { client.list_rules(input).await },
+            |input: &ListRulesRequest, output: &ListRulesResponse| {
+                Some(ListRulesRequest {
+                    next_marker: Some(output.next_marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_rules`, streaming the `Rules` of every page of results.
+    pub fn list_rules_items(
+        &self,
+        input: ListRulesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<RuleSummary, ListRulesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_rules_pages(input),
+            |output: ListRulesResponse| output.rules.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_size_constraint_sets`, streaming every page of results.
+    pub fn list_size_constraint_sets_pages(
+        &self,
+        input: ListSizeConstraintSetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListSizeConstraintSetsResponse,
+        ListSizeConstraintSetsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: WafClient, input: ListSizeConstraintSetsRequest| async move {
+                client.list_size_constraint_sets(input).await
+            },
+            |input: &ListSizeConstraintSetsRequest, output: &ListSizeConstraintSetsResponse| {
+                Some(ListSizeConstraintSetsRequest {
+                    next_marker: Some(output.next_marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_size_constraint_sets`, streaming the `SizeConstraintSets` of every page of results.
+    pub fn list_size_constraint_sets_items(
+        &self,
+        input: ListSizeConstraintSetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<SizeConstraintSetSummary, ListSizeConstraintSetsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.list_size_constraint_sets_pages(input),
+            |output: ListSizeConstraintSetsResponse| {
+                output.size_constraint_sets.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_sql_injection_match_sets`, streaming every page of results.
+    pub fn list_sql_injection_match_sets_pages(
+        &self,
+        input: ListSqlInjectionMatchSetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListSqlInjectionMatchSetsResponse,
+        ListSqlInjectionMatchSetsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: WafClient, input: ListSqlInjectionMatchSetsRequest| async move {
+                client.list_sql_injection_match_sets(input).await
+            },
+            |input: &ListSqlInjectionMatchSetsRequest,
+             output: &ListSqlInjectionMatchSetsResponse| {
+                Some(ListSqlInjectionMatchSetsRequest {
+                    next_marker: Some(output.next_marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_sql_injection_match_sets`, streaming the `SqlInjectionMatchSets` of every page of results.
+    pub fn list_sql_injection_match_sets_items(
+        &self,
+        input: ListSqlInjectionMatchSetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        SqlInjectionMatchSetSummary,
+        ListSqlInjectionMatchSetsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_sql_injection_match_sets_pages(input),
+            |output: ListSqlInjectionMatchSetsResponse| {
+                output.sql_injection_match_sets.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_subscribed_rule_groups`, streaming every page of results.
+    pub fn list_subscribed_rule_groups_pages(
+        &self,
+        input: ListSubscribedRuleGroupsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListSubscribedRuleGroupsResponse,
+        ListSubscribedRuleGroupsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: WafClient, input: ListSubscribedRuleGroupsRequest| async move {
+                client.list_subscribed_rule_groups(input).await
+            },
+            |input: &ListSubscribedRuleGroupsRequest, output: &ListSubscribedRuleGroupsResponse| {
+                Some(ListSubscribedRuleGroupsRequest {
+                    next_marker: Some(output.next_marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_subscribed_rule_groups`, streaming the `RuleGroups` of every page of results.
+    pub fn list_subscribed_rule_groups_items(
+        &self,
+        input: ListSubscribedRuleGroupsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        SubscribedRuleGroupSummary,
+        ListSubscribedRuleGroupsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.list_subscribed_rule_groups_pages(input),
+            |output: ListSubscribedRuleGroupsResponse| output.rule_groups.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_web_ac_ls`, streaming every page of results.
+    pub fn list_web_ac_ls_pages(
+        &self,
+        input: ListWebACLsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListWebACLsResponse, ListWebACLsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: WafClient, input: ListWebACLsRequest| async move {
+                client.list_web_ac_ls(input).await
+            },
+            |input: &ListWebACLsRequest, output: &ListWebACLsResponse| {
+                Some(ListWebACLsRequest {
+                    next_marker: Some(output.next_marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_web_ac_ls`, streaming the `WebACLs` of every page of results.
+    pub fn list_web_ac_ls_items(
+        &self,
+        input: ListWebACLsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<WebACLSummary, ListWebACLsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_web_ac_ls_pages(input),
+            |output: ListWebACLsResponse| output.web_ac_ls.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_xss_match_sets`, streaming every page of results.
+    pub fn list_xss_match_sets_pages(
+        &self,
+        input: ListXssMatchSetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListXssMatchSetsResponse, ListXssMatchSetsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: WafClient, input: ListXssMatchSetsRequest| async move {
+                client.list_xss_match_sets(input).await
+            },
+            |input: &ListXssMatchSetsRequest, output: &ListXssMatchSetsResponse| {
+                Some(ListXssMatchSetsRequest {
+                    next_marker: Some(output.next_marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_xss_match_sets`, streaming the `XssMatchSets` of every page of results.
+    pub fn list_xss_match_sets_items(
+        &self,
+        input: ListXssMatchSetsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<XssMatchSetSummary, ListXssMatchSetsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_xss_match_sets_pages(input),
+            |output: ListXssMatchSetsResponse| output.xss_match_sets.unwrap_or_default(),
+        )
+    }
+}
@@ -95,14 +95,279 @@ pub struct Activity {
     pub resource_metadata: Option<ResourceMetadata>,
     /// <p>The timestamp when the action was performed.</p>
     #[serde(rename = "TimeStamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub time_stamp: Option<f64>,
+    pub time_stamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The activity type.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<ActivityType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ActivityType {
+    /// `DOCUMENT_CHECKED_IN`
+    DocumentCheckedIn,
+    /// `DOCUMENT_CHECKED_OUT`
+    DocumentCheckedOut,
+    /// `DOCUMENT_RENAMED`
+    DocumentRenamed,
+    /// `DOCUMENT_VERSION_UPLOADED`
+    DocumentVersionUploaded,
+    /// `DOCUMENT_VERSION_DELETED`
+    DocumentVersionDeleted,
+    /// `DOCUMENT_VERSION_VIEWED`
+    DocumentVersionViewed,
+    /// `DOCUMENT_VERSION_DOWNLOADED`
+    DocumentVersionDownloaded,
+    /// `DOCUMENT_RECYCLED`
+    DocumentRecycled,
+    /// `DOCUMENT_RESTORED`
+    DocumentRestored,
+    /// `DOCUMENT_REVERTED`
+    DocumentReverted,
+    /// `DOCUMENT_SHARED`
+    DocumentShared,
+    /// `DOCUMENT_UNSHARED`
+    DocumentUnshared,
+    /// `DOCUMENT_SHARE_PERMISSION_CHANGED`
+    DocumentSharePermissionChanged,
+    /// `DOCUMENT_SHAREABLE_LINK_CREATED`
+    DocumentShareableLinkCreated,
+    /// `DOCUMENT_SHAREABLE_LINK_REMOVED`
+    DocumentShareableLinkRemoved,
+    /// `DOCUMENT_SHAREABLE_LINK_PERMISSION_CHANGED`
+    DocumentShareableLinkPermissionChanged,
+    /// `DOCUMENT_MOVED`
+    DocumentMoved,
+    /// `DOCUMENT_COMMENT_ADDED`
+    DocumentCommentAdded,
+    /// `DOCUMENT_COMMENT_DELETED`
+    DocumentCommentDeleted,
+    /// `DOCUMENT_ANNOTATION_ADDED`
+    DocumentAnnotationAdded,
+    /// `DOCUMENT_ANNOTATION_DELETED`
+    DocumentAnnotationDeleted,
+    /// `FOLDER_CREATED`
+    FolderCreated,
+    /// `FOLDER_DELETED`
+    FolderDeleted,
+    /// `FOLDER_RENAMED`
+    FolderRenamed,
+    /// `FOLDER_RECYCLED`
+    FolderRecycled,
+    /// `FOLDER_RESTORED`
+    FolderRestored,
+    /// `FOLDER_SHARED`
+    FolderShared,
+    /// `FOLDER_UNSHARED`
+    FolderUnshared,
+    /// `FOLDER_SHARE_PERMISSION_CHANGED`
+    FolderSharePermissionChanged,
+    /// `FOLDER_SHAREABLE_LINK_CREATED`
+    FolderShareableLinkCreated,
+    /// `FOLDER_SHAREABLE_LINK_REMOVED`
+    FolderShareableLinkRemoved,
+    /// `FOLDER_SHAREABLE_LINK_PERMISSION_CHANGED`
+    FolderShareableLinkPermissionChanged,
+    /// `FOLDER_MOVED`
+    FolderMoved,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ActivityType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ActivityType::DocumentCheckedIn => "DOCUMENT_CHECKED_IN",
+            ActivityType::DocumentCheckedOut => "DOCUMENT_CHECKED_OUT",
+            ActivityType::DocumentRenamed => "DOCUMENT_RENAMED",
+            ActivityType::DocumentVersionUploaded => "DOCUMENT_VERSION_UPLOADED",
+            ActivityType::DocumentVersionDeleted => "DOCUMENT_VERSION_DELETED",
+            ActivityType::DocumentVersionViewed => "DOCUMENT_VERSION_VIEWED",
+            ActivityType::DocumentVersionDownloaded => "DOCUMENT_VERSION_DOWNLOADED",
+            ActivityType::DocumentRecycled => "DOCUMENT_RECYCLED",
+            ActivityType::DocumentRestored => "DOCUMENT_RESTORED",
+            ActivityType::DocumentReverted => "DOCUMENT_REVERTED",
+            ActivityType::DocumentShared => "DOCUMENT_SHARED",
+            ActivityType::DocumentUnshared => "DOCUMENT_UNSHARED",
+            ActivityType::DocumentSharePermissionChanged => "DOCUMENT_SHARE_PERMISSION_CHANGED",
+            ActivityType::DocumentShareableLinkCreated => "DOCUMENT_SHAREABLE_LINK_CREATED",
+            ActivityType::DocumentShareableLinkRemoved => "DOCUMENT_SHAREABLE_LINK_REMOVED",
+            ActivityType::DocumentShareableLinkPermissionChanged => {
+                "DOCUMENT_SHAREABLE_LINK_PERMISSION_CHANGED"
+            }
+            ActivityType::DocumentMoved => "DOCUMENT_MOVED",
+            ActivityType::DocumentCommentAdded => "DOCUMENT_COMMENT_ADDED",
+            ActivityType::DocumentCommentDeleted => "DOCUMENT_COMMENT_DELETED",
+            ActivityType::DocumentAnnotationAdded => "DOCUMENT_ANNOTATION_ADDED",
+            ActivityType::DocumentAnnotationDeleted => "DOCUMENT_ANNOTATION_DELETED",
+            ActivityType::FolderCreated => "FOLDER_CREATED",
+            ActivityType::FolderDeleted => "FOLDER_DELETED",
+            ActivityType::FolderRenamed => "FOLDER_RENAMED",
+            ActivityType::FolderRecycled => "FOLDER_RECYCLED",
+            ActivityType::FolderRestored => "FOLDER_RESTORED",
+            ActivityType::FolderShared => "FOLDER_SHARED",
+            ActivityType::FolderUnshared => "FOLDER_UNSHARED",
+            ActivityType::FolderSharePermissionChanged => "FOLDER_SHARE_PERMISSION_CHANGED",
+            ActivityType::FolderShareableLinkCreated => "FOLDER_SHAREABLE_LINK_CREATED",
+            ActivityType::FolderShareableLinkRemoved => "FOLDER_SHAREABLE_LINK_REMOVED",
+            ActivityType::FolderShareableLinkPermissionChanged => {
+                "FOLDER_SHAREABLE_LINK_PERMISSION_CHANGED"
+            }
+            ActivityType::FolderMoved => "FOLDER_MOVED",
+            ActivityType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ActivityType {
+    fn default() -> ActivityType {
+        ActivityType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ActivityType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ActivityType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ActivityType {
+    fn from(value: &'a str) -> ActivityType {
+        match value {
+            "DOCUMENT_CHECKED_IN" => ActivityType::DocumentCheckedIn,
+            "DOCUMENT_CHECKED_OUT" => ActivityType::DocumentCheckedOut,
+            "DOCUMENT_RENAMED" => ActivityType::DocumentRenamed,
+            "DOCUMENT_VERSION_UPLOADED" => ActivityType::DocumentVersionUploaded,
+            "DOCUMENT_VERSION_DELETED" => ActivityType::DocumentVersionDeleted,
+            "DOCUMENT_VERSION_VIEWED" => ActivityType::DocumentVersionViewed,
+            "DOCUMENT_VERSION_DOWNLOADED" => ActivityType::DocumentVersionDownloaded,
+            "DOCUMENT_RECYCLED" => ActivityType::DocumentRecycled,
+            "DOCUMENT_RESTORED" => ActivityType::DocumentRestored,
+            "DOCUMENT_REVERTED" => ActivityType::DocumentReverted,
+            "DOCUMENT_SHARED" => ActivityType::DocumentShared,
+            "DOCUMENT_UNSHARED" => ActivityType::DocumentUnshared,
+            "DOCUMENT_SHARE_PERMISSION_CHANGED" => ActivityType::DocumentSharePermissionChanged,
+            "DOCUMENT_SHAREABLE_LINK_CREATED" => ActivityType::DocumentShareableLinkCreated,
+            "DOCUMENT_SHAREABLE_LINK_REMOVED" => ActivityType::DocumentShareableLinkRemoved,
+            "DOCUMENT_SHAREABLE_LINK_PERMISSION_CHANGED" => {
+                ActivityType::DocumentShareableLinkPermissionChanged
+            }
+            "DOCUMENT_MOVED" => ActivityType::DocumentMoved,
+            "DOCUMENT_COMMENT_ADDED" => ActivityType::DocumentCommentAdded,
+            "DOCUMENT_COMMENT_DELETED" => ActivityType::DocumentCommentDeleted,
+            "DOCUMENT_ANNOTATION_ADDED" => ActivityType::DocumentAnnotationAdded,
+            "DOCUMENT_ANNOTATION_DELETED" => ActivityType::DocumentAnnotationDeleted,
+            "FOLDER_CREATED" => ActivityType::FolderCreated,
+            "FOLDER_DELETED" => ActivityType::FolderDeleted,
+            "FOLDER_RENAMED" => ActivityType::FolderRenamed,
+            "FOLDER_RECYCLED" => ActivityType::FolderRecycled,
+            "FOLDER_RESTORED" => ActivityType::FolderRestored,
+            "FOLDER_SHARED" => ActivityType::FolderShared,
+            "FOLDER_UNSHARED" => ActivityType::FolderUnshared,
+            "FOLDER_SHARE_PERMISSION_CHANGED" => ActivityType::FolderSharePermissionChanged,
+            "FOLDER_SHAREABLE_LINK_CREATED" => ActivityType::FolderShareableLinkCreated,
+            "FOLDER_SHAREABLE_LINK_REMOVED" => ActivityType::FolderShareableLinkRemoved,
+            "FOLDER_SHAREABLE_LINK_PERMISSION_CHANGED" => {
+                ActivityType::FolderShareableLinkPermissionChanged
+            }
+            "FOLDER_MOVED" => ActivityType::FolderMoved,
+            _ => ActivityType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ActivityType {
+    fn from(value: String) -> ActivityType {
+        match value.as_str() {
+            "DOCUMENT_CHECKED_IN" => ActivityType::DocumentCheckedIn,
+            "DOCUMENT_CHECKED_OUT" => ActivityType::DocumentCheckedOut,
+            "DOCUMENT_RENAMED" => ActivityType::DocumentRenamed,
+            "DOCUMENT_VERSION_UPLOADED" => ActivityType::DocumentVersionUploaded,
+            "DOCUMENT_VERSION_DELETED" => ActivityType::DocumentVersionDeleted,
+            "DOCUMENT_VERSION_VIEWED" => ActivityType::DocumentVersionViewed,
+            "DOCUMENT_VERSION_DOWNLOADED" => ActivityType::DocumentVersionDownloaded,
+            "DOCUMENT_RECYCLED" => ActivityType::DocumentRecycled,
+            "DOCUMENT_RESTORED" => ActivityType::DocumentRestored,
+            "DOCUMENT_REVERTED" => ActivityType::DocumentReverted,
+            "DOCUMENT_SHARED" => ActivityType::DocumentShared,
+            "DOCUMENT_UNSHARED" => ActivityType::DocumentUnshared,
+            "DOCUMENT_SHARE_PERMISSION_CHANGED" => ActivityType::DocumentSharePermissionChanged,
+            "DOCUMENT_SHAREABLE_LINK_CREATED" => ActivityType::DocumentShareableLinkCreated,
+            "DOCUMENT_SHAREABLE_LINK_REMOVED" => ActivityType::DocumentShareableLinkRemoved,
+            "DOCUMENT_SHAREABLE_LINK_PERMISSION_CHANGED" => {
+                ActivityType::DocumentShareableLinkPermissionChanged
+            }
+            "DOCUMENT_MOVED" => ActivityType::DocumentMoved,
+            "DOCUMENT_COMMENT_ADDED" => ActivityType::DocumentCommentAdded,
+            "DOCUMENT_COMMENT_DELETED" => ActivityType::DocumentCommentDeleted,
+            "DOCUMENT_ANNOTATION_ADDED" => ActivityType::DocumentAnnotationAdded,
+            "DOCUMENT_ANNOTATION_DELETED" => ActivityType::DocumentAnnotationDeleted,
+            "FOLDER_CREATED" => ActivityType::FolderCreated,
+            "FOLDER_DELETED" => ActivityType::FolderDeleted,
+            "FOLDER_RENAMED" => ActivityType::FolderRenamed,
+            "FOLDER_RECYCLED" => ActivityType::FolderRecycled,
+            "FOLDER_RESTORED" => ActivityType::FolderRestored,
+            "FOLDER_SHARED" => ActivityType::FolderShared,
+            "FOLDER_UNSHARED" => ActivityType::FolderUnshared,
+            "FOLDER_SHARE_PERMISSION_CHANGED" => ActivityType::FolderSharePermissionChanged,
+            "FOLDER_SHAREABLE_LINK_CREATED" => ActivityType::FolderShareableLinkCreated,
+            "FOLDER_SHAREABLE_LINK_REMOVED" => ActivityType::FolderShareableLinkRemoved,
+            "FOLDER_SHAREABLE_LINK_PERMISSION_CHANGED" => {
+                ActivityType::FolderShareableLinkPermissionChanged
+            }
+            "FOLDER_MOVED" => ActivityType::FolderMoved,
+            _ => ActivityType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ActivityType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ActivityType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ActivityType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ActivityType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for ActivityType {
+    fn deserialize<D>(deserializer: D) -> Result<ActivityType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct AddResourcePermissionsRequest {
@@ -131,6 +396,99 @@ pub struct AddResourcePermissionsResponse {
     pub share_results: Option<Vec<ShareResult>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BooleanEnumType {
+    /// `TRUE`
+    True,
+    /// `FALSE`
+    False,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl BooleanEnumType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BooleanEnumType::True => "TRUE",
+            BooleanEnumType::False => "FALSE",
+            BooleanEnumType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for BooleanEnumType {
+    fn default() -> BooleanEnumType {
+        BooleanEnumType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for BooleanEnumType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for BooleanEnumType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for BooleanEnumType {
+    fn from(value: &'a str) -> BooleanEnumType {
+        match value {
+            "TRUE" => BooleanEnumType::True,
+            "FALSE" => BooleanEnumType::False,
+            _ => BooleanEnumType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BooleanEnumType {
+    fn from(value: String) -> BooleanEnumType {
+        match value.as_str() {
+            "TRUE" => BooleanEnumType::True,
+            "FALSE" => BooleanEnumType::False,
+            _ => BooleanEnumType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for BooleanEnumType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<BooleanEnumType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for BooleanEnumType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for BooleanEnumType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BooleanEnumType {
+    fn deserialize<D>(deserializer: D) -> Result<BooleanEnumType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes a comment.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -144,8 +502,13 @@ pub struct Comment {
     pub contributor: Option<User>,
     /// <p>The time that the comment was created.</p>
     #[serde(rename = "CreatedTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_timestamp: Option<f64>,
+    pub created_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The ID of the parent comment.</p>
     #[serde(rename = "ParentId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -157,7 +520,7 @@ pub struct Comment {
     /// <p>The status of the comment.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<CommentStatusType>,
     /// <p>The text of the comment.</p>
     #[serde(rename = "Text")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -169,7 +532,7 @@ pub struct Comment {
     /// <p>The visibility of the comment. Options are either PRIVATE, where the comment is visible only to the comment author and document owner and co-owners, or PUBLIC, where the comment is visible to document owners, co-owners, and contributors.</p>
     #[serde(rename = "Visibility")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub visibility: Option<String>,
+    pub visibility: Option<CommentVisibilityType>,
 }
 
 /// <p>Describes the metadata of a comment.</p>
@@ -183,21 +546,217 @@ pub struct CommentMetadata {
     /// <p>The status of the comment.</p>
     #[serde(rename = "CommentStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub comment_status: Option<String>,
+    pub comment_status: Option<CommentStatusType>,
     /// <p>The user who made the comment.</p>
     #[serde(rename = "Contributor")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub contributor: Option<User>,
     /// <p>The timestamp that the comment was created.</p>
     #[serde(rename = "CreatedTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_timestamp: Option<f64>,
+    pub created_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The ID of the user being replied to.</p>
     #[serde(rename = "RecipientId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub recipient_id: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CommentStatusType {
+    /// `DRAFT`
+    Draft,
+    /// `PUBLISHED`
+    Published,
+    /// `DELETED`
+    Deleted,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CommentStatusType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CommentStatusType::Draft => "DRAFT",
+            CommentStatusType::Published => "PUBLISHED",
+            CommentStatusType::Deleted => "DELETED",
+            CommentStatusType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CommentStatusType {
+    fn default() -> CommentStatusType {
+        CommentStatusType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CommentStatusType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CommentStatusType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CommentStatusType {
+    fn from(value: &'a str) -> CommentStatusType {
+        match value {
+            "DRAFT" => CommentStatusType::Draft,
+            "PUBLISHED" => CommentStatusType::Published,
+            "DELETED" => CommentStatusType::Deleted,
+            _ => CommentStatusType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CommentStatusType {
+    fn from(value: String) -> CommentStatusType {
+        match value.as_str() {
+            "DRAFT" => CommentStatusType::Draft,
+            "PUBLISHED" => CommentStatusType::Published,
+            "DELETED" => CommentStatusType::Deleted,
+            _ => CommentStatusType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CommentStatusType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CommentStatusType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CommentStatusType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CommentStatusType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CommentStatusType {
+    fn deserialize<D>(deserializer: D) -> Result<CommentStatusType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CommentVisibilityType {
+    /// `PUBLIC`
+    Public,
+    /// `PRIVATE`
+    Private,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl CommentVisibilityType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CommentVisibilityType::Public => "PUBLIC",
+            CommentVisibilityType::Private => "PRIVATE",
+            CommentVisibilityType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for CommentVisibilityType {
+    fn default() -> CommentVisibilityType {
+        CommentVisibilityType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for CommentVisibilityType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CommentVisibilityType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CommentVisibilityType {
+    fn from(value: &'a str) -> CommentVisibilityType {
+        match value {
+            "PUBLIC" => CommentVisibilityType::Public,
+            "PRIVATE" => CommentVisibilityType::Private,
+            _ => CommentVisibilityType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CommentVisibilityType {
+    fn from(value: String) -> CommentVisibilityType {
+        match value.as_str() {
+            "PUBLIC" => CommentVisibilityType::Public,
+            "PRIVATE" => CommentVisibilityType::Private,
+            _ => CommentVisibilityType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CommentVisibilityType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<CommentVisibilityType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CommentVisibilityType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CommentVisibilityType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CommentVisibilityType {
+    fn deserialize<D>(deserializer: D) -> Result<CommentVisibilityType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateCommentRequest {
@@ -229,7 +788,7 @@ pub struct CreateCommentRequest {
     /// <p>The visibility of the comment. Options are either PRIVATE, where the comment is visible only to the comment author and document owner and co-owners, or PUBLIC, where the comment is visible to document owners, co-owners, and contributors.</p>
     #[serde(rename = "Visibility")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub visibility: Option<String>,
+    pub visibility: Option<CommentVisibilityType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -319,10 +878,10 @@ pub struct CreateNotificationSubscriptionRequest {
     pub organization_id: String,
     /// <p>The protocol to use. The supported value is https, which delivers JSON-encoded messages using HTTPS POST.</p>
     #[serde(rename = "Protocol")]
-    pub protocol: String,
+    pub protocol: SubscriptionProtocolType,
     /// <p>The notification type.</p>
     #[serde(rename = "SubscriptionType")]
-    pub subscription_type: String,
+    pub subscription_type: SubscriptionType,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -534,8 +1093,13 @@ pub struct DescribeActivitiesRequest {
     pub authentication_token: Option<String>,
     /// <p>The timestamp that determines the end time of the activities. The response includes the activities performed before the specified timestamp.</p>
     #[serde(rename = "EndTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Includes indirect activities. An indirect activity results from a direct activity performed on a parent resource. For example, sharing a parent folder (the direct activity) shares all of the subfolders and documents within the parent folder (the indirect activity).</p>
     #[serde(rename = "IncludeIndirectActivities")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -558,8 +1122,13 @@ pub struct DescribeActivitiesRequest {
     pub resource_id: Option<String>,
     /// <p>The timestamp that determines the starting time of the activities. The response includes the activities performed after the specified timestamp.</p>
     #[serde(rename = "StartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_iso8601",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The ID of the user who performed the action. The response includes activities pertaining to this user. This is an optional parameter and is only applicable for administrative API (SigV4) requests.</p>
     #[serde(rename = "UserId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -681,15 +1250,15 @@ pub struct DescribeFolderContentsRequest {
     /// <p>The order for the contents of the folder.</p>
     #[serde(rename = "Order")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub order: Option<String>,
+    pub order: Option<OrderType>,
     /// <p>The sorting criteria.</p>
     #[serde(rename = "Sort")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort: Option<String>,
+    pub sort: Option<ResourceSortType>,
     /// <p>The type of items.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<FolderContentType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -855,7 +1424,7 @@ pub struct DescribeUsersRequest {
     /// <p>The state of the users. Specify "ALL" to include inactive users.</p>
     #[serde(rename = "Include")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub include: Option<String>,
+    pub include: Option<UserFilterType>,
     /// <p>The maximum number of items to return.</p>
     #[serde(rename = "Limit")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -867,7 +1436,7 @@ pub struct DescribeUsersRequest {
     /// <p>The order for the results.</p>
     #[serde(rename = "Order")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub order: Option<String>,
+    pub order: Option<OrderType>,
     /// <p>The ID of the organization.</p>
     #[serde(rename = "OrganizationId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -879,7 +1448,7 @@ pub struct DescribeUsersRequest {
     /// <p>The sorting criteria.</p>
     #[serde(rename = "Sort")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort: Option<String>,
+    pub sort: Option<UserSortType>,
     /// <p>The IDs of the users.</p>
     #[serde(rename = "UserIds")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -905,8 +1474,13 @@ pub struct DescribeUsersResponse {
 pub struct DocumentMetadata {
     /// <p>The time when the document was created.</p>
     #[serde(rename = "CreatedTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_timestamp: Option<f64>,
+    pub created_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The ID of the creator.</p>
     #[serde(rename = "CreatorId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -925,8 +1499,13 @@ pub struct DocumentMetadata {
     pub latest_version_metadata: Option<DocumentVersionMetadata>,
     /// <p>The time when the document was updated.</p>
     #[serde(rename = "ModifiedTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub modified_timestamp: Option<f64>,
+    pub modified_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The ID of the parent folder.</p>
     #[serde(rename = "ParentFolderId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -934,548 +1513,1970 @@ pub struct DocumentMetadata {
     /// <p>The resource state.</p>
     #[serde(rename = "ResourceState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_state: Option<String>,
+    pub resource_state: Option<ResourceStateType>,
 }
 
-/// <p>Describes a version of a document.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct DocumentVersionMetadata {
-    /// <p>The timestamp when the content of the document was originally created.</p>
-    #[serde(rename = "ContentCreatedTimestamp")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub content_created_timestamp: Option<f64>,
-    /// <p>The timestamp when the content of the document was modified.</p>
-    #[serde(rename = "ContentModifiedTimestamp")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub content_modified_timestamp: Option<f64>,
-    /// <p>The content type of the document.</p>
-    #[serde(rename = "ContentType")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub content_type: Option<String>,
-    /// <p>The timestamp when the document was first uploaded.</p>
-    #[serde(rename = "CreatedTimestamp")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_timestamp: Option<f64>,
-    /// <p>The ID of the creator.</p>
-    #[serde(rename = "CreatorId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub creator_id: Option<String>,
-    /// <p>The ID of the version.</p>
-    #[serde(rename = "Id")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
-    /// <p>The timestamp when the document was last uploaded.</p>
-    #[serde(rename = "ModifiedTimestamp")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub modified_timestamp: Option<f64>,
-    /// <p>The name of the version.</p>
-    #[serde(rename = "Name")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
-    /// <p>The signature of the document.</p>
-    #[serde(rename = "Signature")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub signature: Option<String>,
-    /// <p>The size of the document, in bytes.</p>
-    #[serde(rename = "Size")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub size: Option<i64>,
-    /// <p>The source of the document.</p>
-    #[serde(rename = "Source")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub source: Option<::std::collections::HashMap<String, String>>,
-    /// <p>The status of the document.</p>
-    #[serde(rename = "Status")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
-    /// <p>The thumbnail of the document.</p>
-    #[serde(rename = "Thumbnail")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub thumbnail: Option<::std::collections::HashMap<String, String>>,
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DocumentSourceType {
+    /// `ORIGINAL`
+    Original,
+    /// `WITH_COMMENTS`
+    WithComments,
+    /// A value not known to this version of the crate
+    Unknown(String),
 }
 
-/// <p>Describes a folder.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct FolderMetadata {
-    /// <p>The time when the folder was created.</p>
-    #[serde(rename = "CreatedTimestamp")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_timestamp: Option<f64>,
-    /// <p>The ID of the creator.</p>
-    #[serde(rename = "CreatorId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub creator_id: Option<String>,
-    /// <p>The ID of the folder.</p>
-    #[serde(rename = "Id")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
-    /// <p>List of labels on the folder.</p>
-    #[serde(rename = "Labels")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub labels: Option<Vec<String>>,
-    /// <p>The size of the latest version of the folder metadata.</p>
-    #[serde(rename = "LatestVersionSize")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub latest_version_size: Option<i64>,
-    /// <p>The time when the folder was updated.</p>
-    #[serde(rename = "ModifiedTimestamp")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub modified_timestamp: Option<f64>,
-    /// <p>The name of the folder.</p>
-    #[serde(rename = "Name")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
-    /// <p>The ID of the parent folder.</p>
-    #[serde(rename = "ParentFolderId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub parent_folder_id: Option<String>,
-    /// <p>The resource state of the folder.</p>
-    #[serde(rename = "ResourceState")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_state: Option<String>,
-    /// <p>The unique identifier created from the subfolders and documents of the folder.</p>
-    #[serde(rename = "Signature")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub signature: Option<String>,
-    /// <p>The size of the folder metadata.</p>
-    #[serde(rename = "Size")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub size: Option<i64>,
+impl DocumentSourceType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DocumentSourceType::Original => "ORIGINAL",
+            DocumentSourceType::WithComments => "WITH_COMMENTS",
+            DocumentSourceType::Unknown(ref value) => value,
+        }
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct GetCurrentUserRequest {
-    /// <p>Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API using AWS credentials.</p>
-    #[serde(rename = "AuthenticationToken")]
-    pub authentication_token: String,
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DocumentSourceType {
+    fn default() -> DocumentSourceType {
+        DocumentSourceType::Unknown(String::new())
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct GetCurrentUserResponse {
-    /// <p>Metadata of the user.</p>
-    #[serde(rename = "User")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub user: Option<User>,
+impl AsRef<str> for DocumentSourceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct GetDocumentPathRequest {
-    /// <p>Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API using AWS credentials.</p>
-    #[serde(rename = "AuthenticationToken")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub authentication_token: Option<String>,
-    /// <p>The ID of the document.</p>
-    #[serde(rename = "DocumentId")]
-    pub document_id: String,
-    /// <p>A comma-separated list of values. Specify <code>NAME</code> to include the names of the parent folders.</p>
-    #[serde(rename = "Fields")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub fields: Option<String>,
-    /// <p>The maximum number of levels in the hierarchy to return.</p>
-    #[serde(rename = "Limit")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub limit: Option<i64>,
-    /// <p>This value is not supported.</p>
-    #[serde(rename = "Marker")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub marker: Option<String>,
+impl fmt::Display for DocumentSourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct GetDocumentPathResponse {
-    /// <p>The path information.</p>
-    #[serde(rename = "Path")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub path: Option<ResourcePath>,
+impl<'a> From<&'a str> for DocumentSourceType {
+    fn from(value: &'a str) -> DocumentSourceType {
+        match value {
+            "ORIGINAL" => DocumentSourceType::Original,
+            "WITH_COMMENTS" => DocumentSourceType::WithComments,
+            _ => DocumentSourceType::Unknown(value.to_owned()),
+        }
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct GetDocumentRequest {
-    /// <p>Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API using AWS credentials.</p>
-    #[serde(rename = "AuthenticationToken")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub authentication_token: Option<String>,
-    /// <p>The ID of the document.</p>
-    #[serde(rename = "DocumentId")]
-    pub document_id: String,
-    /// <p>Set this to <code>TRUE</code> to include custom metadata in the response.</p>
-    #[serde(rename = "IncludeCustomMetadata")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub include_custom_metadata: Option<bool>,
+impl From<String> for DocumentSourceType {
+    fn from(value: String) -> DocumentSourceType {
+        match value.as_str() {
+            "ORIGINAL" => DocumentSourceType::Original,
+            "WITH_COMMENTS" => DocumentSourceType::WithComments,
+            _ => DocumentSourceType::Unknown(value),
+        }
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct GetDocumentResponse {
-    /// <p>The custom metadata on the document.</p>
-    #[serde(rename = "CustomMetadata")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub custom_metadata: Option<::std::collections::HashMap<String, String>>,
-    /// <p>The metadata details of the document.</p>
-    #[serde(rename = "Metadata")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub metadata: Option<DocumentMetadata>,
+impl ::std::str::FromStr for DocumentSourceType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DocumentSourceType, Self::Err> {
+        Ok(value.into())
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct GetDocumentVersionRequest {
-    /// <p>Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API using AWS credentials.</p>
-    #[serde(rename = "AuthenticationToken")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub authentication_token: Option<String>,
-    /// <p>The ID of the document.</p>
-    #[serde(rename = "DocumentId")]
-    pub document_id: String,
-    /// <p>A comma-separated list of values. Specify "SOURCE" to include a URL for the source document.</p>
-    #[serde(rename = "Fields")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub fields: Option<String>,
-    /// <p>Set this to TRUE to include custom metadata in the response.</p>
-    #[serde(rename = "IncludeCustomMetadata")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub include_custom_metadata: Option<bool>,
-    /// <p>The version ID of the document.</p>
-    #[serde(rename = "VersionId")]
-    pub version_id: String,
+impl ::rusoto_core::param::ToParam for DocumentSourceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct GetDocumentVersionResponse {
-    /// <p>The custom metadata on the document version.</p>
-    #[serde(rename = "CustomMetadata")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub custom_metadata: Option<::std::collections::HashMap<String, String>>,
-    /// <p>The version metadata.</p>
-    #[serde(rename = "Metadata")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub metadata: Option<DocumentVersionMetadata>,
+impl ::serde::Serialize for DocumentSourceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct GetFolderPathRequest {
-    /// <p>Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API using AWS credentials.</p>
-    #[serde(rename = "AuthenticationToken")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub authentication_token: Option<String>,
-    /// <p>A comma-separated list of values. Specify "NAME" to include the names of the parent folders.</p>
-    #[serde(rename = "Fields")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub fields: Option<String>,
-    /// <p>The ID of the folder.</p>
-    #[serde(rename = "FolderId")]
-    pub folder_id: String,
-    /// <p>The maximum number of levels in the hierarchy to return.</p>
-    #[serde(rename = "Limit")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub limit: Option<i64>,
-    /// <p>This value is not supported.</p>
-    #[serde(rename = "Marker")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub marker: Option<String>,
+impl<'de> ::serde::Deserialize<'de> for DocumentSourceType {
+    fn deserialize<D>(deserializer: D) -> Result<DocumentSourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DocumentStatusType {
+    /// `INITIALIZED`
+    Initialized,
+    /// `ACTIVE`
+    Active,
+    /// A value not known to this version of the crate
+    Unknown(String),
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct GetFolderPathResponse {
-    /// <p>The path information.</p>
-    #[serde(rename = "Path")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub path: Option<ResourcePath>,
+impl DocumentStatusType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DocumentStatusType::Initialized => "INITIALIZED",
+            DocumentStatusType::Active => "ACTIVE",
+            DocumentStatusType::Unknown(ref value) => value,
+        }
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct GetFolderRequest {
-    /// <p>Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API using AWS credentials.</p>
-    #[serde(rename = "AuthenticationToken")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub authentication_token: Option<String>,
-    /// <p>The ID of the folder.</p>
-    #[serde(rename = "FolderId")]
-    pub folder_id: String,
-    /// <p>Set to TRUE to include custom metadata in the response.</p>
-    #[serde(rename = "IncludeCustomMetadata")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub include_custom_metadata: Option<bool>,
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DocumentStatusType {
+    fn default() -> DocumentStatusType {
+        DocumentStatusType::Unknown(String::new())
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct GetFolderResponse {
-    /// <p>The custom metadata on the folder.</p>
-    #[serde(rename = "CustomMetadata")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub custom_metadata: Option<::std::collections::HashMap<String, String>>,
-    /// <p>The metadata of the folder.</p>
-    #[serde(rename = "Metadata")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub metadata: Option<FolderMetadata>,
+impl AsRef<str> for DocumentStatusType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct GetResourcesRequest {
-    /// <p>The Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API operation using AWS credentials.</p>
-    #[serde(rename = "AuthenticationToken")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub authentication_token: Option<String>,
-    /// <p>The collection type.</p>
-    #[serde(rename = "CollectionType")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub collection_type: Option<String>,
-    /// <p>The maximum number of resources to return.</p>
-    #[serde(rename = "Limit")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub limit: Option<i64>,
-    /// <p>The marker for the next set of results. This marker was received from a previous call.</p>
-    #[serde(rename = "Marker")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub marker: Option<String>,
-    /// <p>The user ID for the resource collection. This is a required field for accessing the API operation using IAM credentials.</p>
-    #[serde(rename = "UserId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub user_id: Option<String>,
+impl fmt::Display for DocumentStatusType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct GetResourcesResponse {
-    /// <p>The documents in the specified collection.</p>
-    #[serde(rename = "Documents")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub documents: Option<Vec<DocumentMetadata>>,
-    /// <p>The folders in the specified folder.</p>
-    #[serde(rename = "Folders")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub folders: Option<Vec<FolderMetadata>>,
-    /// <p>The marker to use when requesting the next set of results. If there are no additional results, the string is empty.</p>
-    #[serde(rename = "Marker")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub marker: Option<String>,
+impl<'a> From<&'a str> for DocumentStatusType {
+    fn from(value: &'a str) -> DocumentStatusType {
+        match value {
+            "INITIALIZED" => DocumentStatusType::Initialized,
+            "ACTIVE" => DocumentStatusType::Active,
+            _ => DocumentStatusType::Unknown(value.to_owned()),
+        }
+    }
 }
 
-/// <p>Describes the metadata of a user group.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct GroupMetadata {
-    /// <p>The ID of the user group.</p>
-    #[serde(rename = "Id")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
-    /// <p>The name of the group.</p>
-    #[serde(rename = "Name")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
+impl From<String> for DocumentStatusType {
+    fn from(value: String) -> DocumentStatusType {
+        match value.as_str() {
+            "INITIALIZED" => DocumentStatusType::Initialized,
+            "ACTIVE" => DocumentStatusType::Active,
+            _ => DocumentStatusType::Unknown(value),
+        }
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct InitiateDocumentVersionUploadRequest {
-    /// <p>Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API using AWS credentials.</p>
-    #[serde(rename = "AuthenticationToken")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub authentication_token: Option<String>,
-    /// <p>The timestamp when the content of the document was originally created.</p>
-    #[serde(rename = "ContentCreatedTimestamp")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub content_created_timestamp: Option<f64>,
-    /// <p>The timestamp when the content of the document was modified.</p>
-    #[serde(rename = "ContentModifiedTimestamp")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub content_modified_timestamp: Option<f64>,
-    /// <p>The content type of the document.</p>
-    #[serde(rename = "ContentType")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub content_type: Option<String>,
-    /// <p>The size of the document, in bytes.</p>
-    #[serde(rename = "DocumentSizeInBytes")]
+impl ::std::str::FromStr for DocumentStatusType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DocumentStatusType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DocumentStatusType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DocumentStatusType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DocumentStatusType {
+    fn deserialize<D>(deserializer: D) -> Result<DocumentStatusType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DocumentThumbnailType {
+    /// `SMALL`
+    Small,
+    /// `SMALL_HQ`
+    SmallHq,
+    /// `LARGE`
+    Large,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DocumentThumbnailType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DocumentThumbnailType::Small => "SMALL",
+            DocumentThumbnailType::SmallHq => "SMALL_HQ",
+            DocumentThumbnailType::Large => "LARGE",
+            DocumentThumbnailType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DocumentThumbnailType {
+    fn default() -> DocumentThumbnailType {
+        DocumentThumbnailType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DocumentThumbnailType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DocumentThumbnailType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DocumentThumbnailType {
+    fn from(value: &'a str) -> DocumentThumbnailType {
+        match value {
+            "SMALL" => DocumentThumbnailType::Small,
+            "SMALL_HQ" => DocumentThumbnailType::SmallHq,
+            "LARGE" => DocumentThumbnailType::Large,
+            _ => DocumentThumbnailType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DocumentThumbnailType {
+    fn from(value: String) -> DocumentThumbnailType {
+        match value.as_str() {
+            "SMALL" => DocumentThumbnailType::Small,
+            "SMALL_HQ" => DocumentThumbnailType::SmallHq,
+            "LARGE" => DocumentThumbnailType::Large,
+            _ => DocumentThumbnailType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DocumentThumbnailType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DocumentThumbnailType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DocumentThumbnailType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DocumentThumbnailType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DocumentThumbnailType {
+    fn deserialize<D>(deserializer: D) -> Result<DocumentThumbnailType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Describes a version of a document.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct DocumentVersionMetadata {
+    /// <p>The timestamp when the content of the document was originally created.</p>
+    #[serde(rename = "ContentCreatedTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub document_size_in_bytes: Option<i64>,
-    /// <p>The ID of the document.</p>
+    pub content_created_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
+    /// <p>The timestamp when the content of the document was modified.</p>
+    #[serde(rename = "ContentModifiedTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_modified_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
+    /// <p>The content type of the document.</p>
+    #[serde(rename = "ContentType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+    /// <p>The timestamp when the document was first uploaded.</p>
+    #[serde(rename = "CreatedTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
+    /// <p>The ID of the creator.</p>
+    #[serde(rename = "CreatorId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub creator_id: Option<String>,
+    /// <p>The ID of the version.</p>
     #[serde(rename = "Id")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
-    /// <p>The name of the document.</p>
+    /// <p>The timestamp when the document was last uploaded.</p>
+    #[serde(rename = "ModifiedTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub modified_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
+    /// <p>The name of the version.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
-    /// <p>The ID of the parent folder.</p>
-    #[serde(rename = "ParentFolderId")]
-    pub parent_folder_id: String,
+    /// <p>The signature of the document.</p>
+    #[serde(rename = "Signature")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature: Option<String>,
+    /// <p>The size of the document, in bytes.</p>
+    #[serde(rename = "Size")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<i64>,
+    /// <p>The source of the document.</p>
+    #[serde(rename = "Source")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<::std::collections::HashMap<DocumentSourceType, String>>,
+    /// <p>The status of the document.</p>
+    #[serde(rename = "Status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<DocumentStatusType>,
+    /// <p>The thumbnail of the document.</p>
+    #[serde(rename = "Thumbnail")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail: Option<::std::collections::HashMap<DocumentThumbnailType, String>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DocumentVersionStatus {
+    /// `ACTIVE`
+    Active,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DocumentVersionStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DocumentVersionStatus::Active => "ACTIVE",
+            DocumentVersionStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DocumentVersionStatus {
+    fn default() -> DocumentVersionStatus {
+        DocumentVersionStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DocumentVersionStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DocumentVersionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DocumentVersionStatus {
+    fn from(value: &'a str) -> DocumentVersionStatus {
+        match value {
+            "ACTIVE" => DocumentVersionStatus::Active,
+            _ => DocumentVersionStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DocumentVersionStatus {
+    fn from(value: String) -> DocumentVersionStatus {
+        match value.as_str() {
+            "ACTIVE" => DocumentVersionStatus::Active,
+            _ => DocumentVersionStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DocumentVersionStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DocumentVersionStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DocumentVersionStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DocumentVersionStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DocumentVersionStatus {
+    fn deserialize<D>(deserializer: D) -> Result<DocumentVersionStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FolderContentType {
+    /// `ALL`
+    All,
+    /// `DOCUMENT`
+    Document,
+    /// `FOLDER`
+    Folder,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl FolderContentType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FolderContentType::All => "ALL",
+            FolderContentType::Document => "DOCUMENT",
+            FolderContentType::Folder => "FOLDER",
+            FolderContentType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for FolderContentType {
+    fn default() -> FolderContentType {
+        FolderContentType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for FolderContentType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FolderContentType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FolderContentType {
+    fn from(value: &'a str) -> FolderContentType {
+        match value {
+            "ALL" => FolderContentType::All,
+            "DOCUMENT" => FolderContentType::Document,
+            "FOLDER" => FolderContentType::Folder,
+            _ => FolderContentType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FolderContentType {
+    fn from(value: String) -> FolderContentType {
+        match value.as_str() {
+            "ALL" => FolderContentType::All,
+            "DOCUMENT" => FolderContentType::Document,
+            "FOLDER" => FolderContentType::Folder,
+            _ => FolderContentType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for FolderContentType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<FolderContentType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FolderContentType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
 }
 
+impl ::serde::Serialize for FolderContentType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FolderContentType {
+    fn deserialize<D>(deserializer: D) -> Result<FolderContentType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Describes a folder.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct InitiateDocumentVersionUploadResponse {
-    /// <p>The document metadata.</p>
-    #[serde(rename = "Metadata")]
+pub struct FolderMetadata {
+    /// <p>The time when the folder was created.</p>
+    #[serde(rename = "CreatedTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub metadata: Option<DocumentMetadata>,
-    /// <p>The upload metadata.</p>
-    #[serde(rename = "UploadMetadata")]
+    pub created_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
+    /// <p>The ID of the creator.</p>
+    #[serde(rename = "CreatorId")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub upload_metadata: Option<UploadMetadata>,
+    pub creator_id: Option<String>,
+    /// <p>The ID of the folder.</p>
+    #[serde(rename = "Id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// <p>List of labels on the folder.</p>
+    #[serde(rename = "Labels")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Vec<String>>,
+    /// <p>The size of the latest version of the folder metadata.</p>
+    #[serde(rename = "LatestVersionSize")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latest_version_size: Option<i64>,
+    /// <p>The time when the folder was updated.</p>
+    #[serde(rename = "ModifiedTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub modified_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
+    /// <p>The name of the folder.</p>
+    #[serde(rename = "Name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// <p>The ID of the parent folder.</p>
+    #[serde(rename = "ParentFolderId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_folder_id: Option<String>,
+    /// <p>The resource state of the folder.</p>
+    #[serde(rename = "ResourceState")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_state: Option<ResourceStateType>,
+    /// <p>The unique identifier created from the subfolders and documents of the folder.</p>
+    #[serde(rename = "Signature")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature: Option<String>,
+    /// <p>The size of the folder metadata.</p>
+    #[serde(rename = "Size")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<i64>,
 }
 
-/// <p>Set of options which defines notification preferences of given action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct NotificationOptions {
-    /// <p>Text value to be included in the email body.</p>
-    #[serde(rename = "EmailMessage")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub email_message: Option<String>,
-    /// <p>Boolean value to indicate an email notification should be sent to the receipients.</p>
-    #[serde(rename = "SendEmail")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub send_email: Option<bool>,
+pub struct GetCurrentUserRequest {
+    /// <p>Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API using AWS credentials.</p>
+    #[serde(rename = "AuthenticationToken")]
+    pub authentication_token: String,
 }
 
-/// <p>Describes the users or user groups.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct Participants {
-    /// <p>The list of user groups.</p>
-    #[serde(rename = "Groups")]
+pub struct GetCurrentUserResponse {
+    /// <p>Metadata of the user.</p>
+    #[serde(rename = "User")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub groups: Option<Vec<GroupMetadata>>,
-    /// <p>The list of users.</p>
+    pub user: Option<User>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct GetDocumentPathRequest {
+    /// <p>Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API using AWS credentials.</p>
+    #[serde(rename = "AuthenticationToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authentication_token: Option<String>,
+    /// <p>The ID of the document.</p>
+    #[serde(rename = "DocumentId")]
+    pub document_id: String,
+    /// <p>A comma-separated list of values. Specify <code>NAME</code> to include the names of the parent folders.</p>
+    #[serde(rename = "Fields")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<String>,
+    /// <p>The maximum number of levels in the hierarchy to return.</p>
+    #[serde(rename = "Limit")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    /// <p>This value is not supported.</p>
+    #[serde(rename = "Marker")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub marker: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct GetDocumentPathResponse {
+    /// <p>The path information.</p>
+    #[serde(rename = "Path")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<ResourcePath>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct GetDocumentRequest {
+    /// <p>Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API using AWS credentials.</p>
+    #[serde(rename = "AuthenticationToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authentication_token: Option<String>,
+    /// <p>The ID of the document.</p>
+    #[serde(rename = "DocumentId")]
+    pub document_id: String,
+    /// <p>Set this to <code>TRUE</code> to include custom metadata in the response.</p>
+    #[serde(rename = "IncludeCustomMetadata")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_custom_metadata: Option<bool>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct GetDocumentResponse {
+    /// <p>The custom metadata on the document.</p>
+    #[serde(rename = "CustomMetadata")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_metadata: Option<::std::collections::HashMap<String, String>>,
+    /// <p>The metadata details of the document.</p>
+    #[serde(rename = "Metadata")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<DocumentMetadata>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct GetDocumentVersionRequest {
+    /// <p>Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API using AWS credentials.</p>
+    #[serde(rename = "AuthenticationToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authentication_token: Option<String>,
+    /// <p>The ID of the document.</p>
+    #[serde(rename = "DocumentId")]
+    pub document_id: String,
+    /// <p>A comma-separated list of values. Specify "SOURCE" to include a URL for the source document.</p>
+    #[serde(rename = "Fields")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<String>,
+    /// <p>Set this to TRUE to include custom metadata in the response.</p>
+    #[serde(rename = "IncludeCustomMetadata")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_custom_metadata: Option<bool>,
+    /// <p>The version ID of the document.</p>
+    #[serde(rename = "VersionId")]
+    pub version_id: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct GetDocumentVersionResponse {
+    /// <p>The custom metadata on the document version.</p>
+    #[serde(rename = "CustomMetadata")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_metadata: Option<::std::collections::HashMap<String, String>>,
+    /// <p>The version metadata.</p>
+    #[serde(rename = "Metadata")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<DocumentVersionMetadata>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct GetFolderPathRequest {
+    /// <p>Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API using AWS credentials.</p>
+    #[serde(rename = "AuthenticationToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authentication_token: Option<String>,
+    /// <p>A comma-separated list of values. Specify "NAME" to include the names of the parent folders.</p>
+    #[serde(rename = "Fields")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<String>,
+    /// <p>The ID of the folder.</p>
+    #[serde(rename = "FolderId")]
+    pub folder_id: String,
+    /// <p>The maximum number of levels in the hierarchy to return.</p>
+    #[serde(rename = "Limit")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    /// <p>This value is not supported.</p>
+    #[serde(rename = "Marker")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub marker: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct GetFolderPathResponse {
+    /// <p>The path information.</p>
+    #[serde(rename = "Path")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<ResourcePath>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct GetFolderRequest {
+    /// <p>Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API using AWS credentials.</p>
+    #[serde(rename = "AuthenticationToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authentication_token: Option<String>,
+    /// <p>The ID of the folder.</p>
+    #[serde(rename = "FolderId")]
+    pub folder_id: String,
+    /// <p>Set to TRUE to include custom metadata in the response.</p>
+    #[serde(rename = "IncludeCustomMetadata")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_custom_metadata: Option<bool>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct GetFolderResponse {
+    /// <p>The custom metadata on the folder.</p>
+    #[serde(rename = "CustomMetadata")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_metadata: Option<::std::collections::HashMap<String, String>>,
+    /// <p>The metadata of the folder.</p>
+    #[serde(rename = "Metadata")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<FolderMetadata>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct GetResourcesRequest {
+    /// <p>The Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API operation using AWS credentials.</p>
+    #[serde(rename = "AuthenticationToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authentication_token: Option<String>,
+    /// <p>The collection type.</p>
+    #[serde(rename = "CollectionType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collection_type: Option<ResourceCollectionType>,
+    /// <p>The maximum number of resources to return.</p>
+    #[serde(rename = "Limit")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    /// <p>The marker for the next set of results. This marker was received from a previous call.</p>
+    #[serde(rename = "Marker")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub marker: Option<String>,
+    /// <p>The user ID for the resource collection. This is a required field for accessing the API operation using IAM credentials.</p>
+    #[serde(rename = "UserId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct GetResourcesResponse {
+    /// <p>The documents in the specified collection.</p>
+    #[serde(rename = "Documents")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub documents: Option<Vec<DocumentMetadata>>,
+    /// <p>The folders in the specified folder.</p>
+    #[serde(rename = "Folders")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub folders: Option<Vec<FolderMetadata>>,
+    /// <p>The marker to use when requesting the next set of results. If there are no additional results, the string is empty.</p>
+    #[serde(rename = "Marker")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub marker: Option<String>,
+}
+
+/// <p>Describes the metadata of a user group.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct GroupMetadata {
+    /// <p>The ID of the user group.</p>
+    #[serde(rename = "Id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// <p>The name of the group.</p>
+    #[serde(rename = "Name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct InitiateDocumentVersionUploadRequest {
+    /// <p>Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API using AWS credentials.</p>
+    #[serde(rename = "AuthenticationToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authentication_token: Option<String>,
+    /// <p>The timestamp when the content of the document was originally created.</p>
+    #[serde(rename = "ContentCreatedTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_created_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
+    /// <p>The timestamp when the content of the document was modified.</p>
+    #[serde(rename = "ContentModifiedTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_modified_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
+    /// <p>The content type of the document.</p>
+    #[serde(rename = "ContentType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+    /// <p>The size of the document, in bytes.</p>
+    #[serde(rename = "DocumentSizeInBytes")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub document_size_in_bytes: Option<i64>,
+    /// <p>The ID of the document.</p>
+    #[serde(rename = "Id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// <p>The name of the document.</p>
+    #[serde(rename = "Name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// <p>The ID of the parent folder.</p>
+    #[serde(rename = "ParentFolderId")]
+    pub parent_folder_id: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct InitiateDocumentVersionUploadResponse {
+    /// <p>The document metadata.</p>
+    #[serde(rename = "Metadata")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<DocumentMetadata>,
+    /// <p>The upload metadata.</p>
+    #[serde(rename = "UploadMetadata")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub upload_metadata: Option<UploadMetadata>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LocaleType {
+    /// `en`
+    En,
+    /// `fr`
+    Fr,
+    /// `ko`
+    Ko,
+    /// `de`
+    De,
+    /// `es`
+    Es,
+    /// `ja`
+    Ja,
+    /// `ru`
+    Ru,
+    /// `zh_CN`
+    ZhCn,
+    /// `zh_TW`
+    ZhTw,
+    /// `pt_BR`
+    PtBr,
+    /// `default`
+    Default,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl LocaleType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LocaleType::En => "en",
+            LocaleType::Fr => "fr",
+            LocaleType::Ko => "ko",
+            LocaleType::De => "de",
+            LocaleType::Es => "es",
+            LocaleType::Ja => "ja",
+            LocaleType::Ru => "ru",
+            LocaleType::ZhCn => "zh_CN",
+            LocaleType::ZhTw => "zh_TW",
+            LocaleType::PtBr => "pt_BR",
+            LocaleType::Default => "default",
+            LocaleType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for LocaleType {
+    fn default() -> LocaleType {
+        LocaleType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for LocaleType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LocaleType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for LocaleType {
+    fn from(value: &'a str) -> LocaleType {
+        match value {
+            "en" => LocaleType::En,
+            "fr" => LocaleType::Fr,
+            "ko" => LocaleType::Ko,
+            "de" => LocaleType::De,
+            "es" => LocaleType::Es,
+            "ja" => LocaleType::Ja,
+            "ru" => LocaleType::Ru,
+            "zh_CN" => LocaleType::ZhCn,
+            "zh_TW" => LocaleType::ZhTw,
+            "pt_BR" => LocaleType::PtBr,
+            "default" => LocaleType::Default,
+            _ => LocaleType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LocaleType {
+    fn from(value: String) -> LocaleType {
+        match value.as_str() {
+            "en" => LocaleType::En,
+            "fr" => LocaleType::Fr,
+            "ko" => LocaleType::Ko,
+            "de" => LocaleType::De,
+            "es" => LocaleType::Es,
+            "ja" => LocaleType::Ja,
+            "ru" => LocaleType::Ru,
+            "zh_CN" => LocaleType::ZhCn,
+            "zh_TW" => LocaleType::ZhTw,
+            "pt_BR" => LocaleType::PtBr,
+            "default" => LocaleType::Default,
+            _ => LocaleType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for LocaleType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<LocaleType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LocaleType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LocaleType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LocaleType {
+    fn deserialize<D>(deserializer: D) -> Result<LocaleType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Set of options which defines notification preferences of given action.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct NotificationOptions {
+    /// <p>Text value to be included in the email body.</p>
+    #[serde(rename = "EmailMessage")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_message: Option<String>,
+    /// <p>Boolean value to indicate an email notification should be sent to the receipients.</p>
+    #[serde(rename = "SendEmail")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub send_email: Option<bool>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OrderType {
+    /// `ASCENDING`
+    Ascending,
+    /// `DESCENDING`
+    Descending,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl OrderType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OrderType::Ascending => "ASCENDING",
+            OrderType::Descending => "DESCENDING",
+            OrderType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for OrderType {
+    fn default() -> OrderType {
+        OrderType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for OrderType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for OrderType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for OrderType {
+    fn from(value: &'a str) -> OrderType {
+        match value {
+            "ASCENDING" => OrderType::Ascending,
+            "DESCENDING" => OrderType::Descending,
+            _ => OrderType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OrderType {
+    fn from(value: String) -> OrderType {
+        match value.as_str() {
+            "ASCENDING" => OrderType::Ascending,
+            "DESCENDING" => OrderType::Descending,
+            _ => OrderType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for OrderType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<OrderType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OrderType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for OrderType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OrderType {
+    fn deserialize<D>(deserializer: D) -> Result<OrderType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Describes the users or user groups.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct Participants {
+    /// <p>The list of user groups.</p>
+    #[serde(rename = "Groups")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub groups: Option<Vec<GroupMetadata>>,
+    /// <p>The list of users.</p>
     #[serde(rename = "Users")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub users: Option<Vec<UserMetadata>>,
 }
 
-/// <p>Describes the permissions.</p>
+/// <p>Describes the permissions.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct PermissionInfo {
+    /// <p>The role of the user.</p>
+    #[serde(rename = "Role")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub role: Option<RoleType>,
+    /// <p>The type of permissions.</p>
+    #[serde(rename = "Type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_: Option<RolePermissionType>,
+}
+
+/// <p>Describes a resource.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct Principal {
+    /// <p>The ID of the resource.</p>
+    #[serde(rename = "Id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// <p>The permission information for the resource.</p>
+    #[serde(rename = "Roles")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub roles: Option<Vec<PermissionInfo>>,
+    /// <p>The type of resource.</p>
+    #[serde(rename = "Type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_: Option<PrincipalType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PrincipalType {
+    /// `USER`
+    User,
+    /// `GROUP`
+    Group,
+    /// `INVITE`
+    Invite,
+    /// `ANONYMOUS`
+    Anonymous,
+    /// `ORGANIZATION`
+    Organization,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl PrincipalType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PrincipalType::User => "USER",
+            PrincipalType::Group => "GROUP",
+            PrincipalType::Invite => "INVITE",
+            PrincipalType::Anonymous => "ANONYMOUS",
+            PrincipalType::Organization => "ORGANIZATION",
+            PrincipalType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PrincipalType {
+    fn default() -> PrincipalType {
+        PrincipalType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PrincipalType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PrincipalType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PrincipalType {
+    fn from(value: &'a str) -> PrincipalType {
+        match value {
+            "USER" => PrincipalType::User,
+            "GROUP" => PrincipalType::Group,
+            "INVITE" => PrincipalType::Invite,
+            "ANONYMOUS" => PrincipalType::Anonymous,
+            "ORGANIZATION" => PrincipalType::Organization,
+            _ => PrincipalType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PrincipalType {
+    fn from(value: String) -> PrincipalType {
+        match value.as_str() {
+            "USER" => PrincipalType::User,
+            "GROUP" => PrincipalType::Group,
+            "INVITE" => PrincipalType::Invite,
+            "ANONYMOUS" => PrincipalType::Anonymous,
+            "ORGANIZATION" => PrincipalType::Organization,
+            _ => PrincipalType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PrincipalType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PrincipalType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PrincipalType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PrincipalType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PrincipalType {
+    fn deserialize<D>(deserializer: D) -> Result<PrincipalType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct RemoveAllResourcePermissionsRequest {
+    /// <p>Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API using AWS credentials.</p>
+    #[serde(rename = "AuthenticationToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authentication_token: Option<String>,
+    /// <p>The ID of the resource.</p>
+    #[serde(rename = "ResourceId")]
+    pub resource_id: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct RemoveResourcePermissionRequest {
+    /// <p>Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API using AWS credentials.</p>
+    #[serde(rename = "AuthenticationToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authentication_token: Option<String>,
+    /// <p>The principal ID of the resource.</p>
+    #[serde(rename = "PrincipalId")]
+    pub principal_id: String,
+    /// <p>The principal type of the resource.</p>
+    #[serde(rename = "PrincipalType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub principal_type: Option<PrincipalType>,
+    /// <p>The ID of the resource.</p>
+    #[serde(rename = "ResourceId")]
+    pub resource_id: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ResourceCollectionType {
+    /// `SHARED_WITH_ME`
+    SharedWithMe,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ResourceCollectionType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceCollectionType::SharedWithMe => "SHARED_WITH_ME",
+            ResourceCollectionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ResourceCollectionType {
+    fn default() -> ResourceCollectionType {
+        ResourceCollectionType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ResourceCollectionType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ResourceCollectionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ResourceCollectionType {
+    fn from(value: &'a str) -> ResourceCollectionType {
+        match value {
+            "SHARED_WITH_ME" => ResourceCollectionType::SharedWithMe,
+            _ => ResourceCollectionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceCollectionType {
+    fn from(value: String) -> ResourceCollectionType {
+        match value.as_str() {
+            "SHARED_WITH_ME" => ResourceCollectionType::SharedWithMe,
+            _ => ResourceCollectionType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ResourceCollectionType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ResourceCollectionType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ResourceCollectionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ResourceCollectionType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ResourceCollectionType {
+    fn deserialize<D>(deserializer: D) -> Result<ResourceCollectionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Describes the metadata of a resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct PermissionInfo {
-    /// <p>The role of the user.</p>
-    #[serde(rename = "Role")]
+pub struct ResourceMetadata {
+    /// <p>The ID of the resource.</p>
+    #[serde(rename = "Id")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub role: Option<String>,
-    /// <p>The type of permissions.</p>
+    pub id: Option<String>,
+    /// <p>The name of the resource.</p>
+    #[serde(rename = "Name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// <p>The original name of the resource before a rename operation.</p>
+    #[serde(rename = "OriginalName")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub original_name: Option<String>,
+    /// <p>The owner of the resource.</p>
+    #[serde(rename = "Owner")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner: Option<UserMetadata>,
+    /// <p>The parent ID of the resource before a rename operation.</p>
+    #[serde(rename = "ParentId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<String>,
+    /// <p>The type of resource.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<ResourceType>,
+    /// <p>The version ID of the resource. This is an optional field and is filled for action on document version.</p>
+    #[serde(rename = "VersionId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version_id: Option<String>,
+}
+
+/// <p>Describes the path information of a resource.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct ResourcePath {
+    /// <p>The components of the resource path.</p>
+    #[serde(rename = "Components")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub components: Option<Vec<ResourcePathComponent>>,
+}
+
+/// <p>Describes the resource path.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct ResourcePathComponent {
+    /// <p>The ID of the resource path.</p>
+    #[serde(rename = "Id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// <p>The name of the resource path.</p>
+    #[serde(rename = "Name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ResourceSortType {
+    /// `DATE`
+    Date,
+    /// `NAME`
+    Name,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ResourceSortType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceSortType::Date => "DATE",
+            ResourceSortType::Name => "NAME",
+            ResourceSortType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ResourceSortType {
+    fn default() -> ResourceSortType {
+        ResourceSortType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ResourceSortType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ResourceSortType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ResourceSortType {
+    fn from(value: &'a str) -> ResourceSortType {
+        match value {
+            "DATE" => ResourceSortType::Date,
+            "NAME" => ResourceSortType::Name,
+            _ => ResourceSortType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceSortType {
+    fn from(value: String) -> ResourceSortType {
+        match value.as_str() {
+            "DATE" => ResourceSortType::Date,
+            "NAME" => ResourceSortType::Name,
+            _ => ResourceSortType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ResourceSortType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ResourceSortType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ResourceSortType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ResourceSortType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ResourceSortType {
+    fn deserialize<D>(deserializer: D) -> Result<ResourceSortType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ResourceStateType {
+    /// `ACTIVE`
+    Active,
+    /// `RESTORING`
+    Restoring,
+    /// `RECYCLING`
+    Recycling,
+    /// `RECYCLED`
+    Recycled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ResourceStateType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceStateType::Active => "ACTIVE",
+            ResourceStateType::Restoring => "RESTORING",
+            ResourceStateType::Recycling => "RECYCLING",
+            ResourceStateType::Recycled => "RECYCLED",
+            ResourceStateType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ResourceStateType {
+    fn default() -> ResourceStateType {
+        ResourceStateType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ResourceStateType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ResourceStateType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ResourceStateType {
+    fn from(value: &'a str) -> ResourceStateType {
+        match value {
+            "ACTIVE" => ResourceStateType::Active,
+            "RESTORING" => ResourceStateType::Restoring,
+            "RECYCLING" => ResourceStateType::Recycling,
+            "RECYCLED" => ResourceStateType::Recycled,
+            _ => ResourceStateType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceStateType {
+    fn from(value: String) -> ResourceStateType {
+        match value.as_str() {
+            "ACTIVE" => ResourceStateType::Active,
+            "RESTORING" => ResourceStateType::Restoring,
+            "RECYCLING" => ResourceStateType::Recycling,
+            "RECYCLED" => ResourceStateType::Recycled,
+            _ => ResourceStateType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ResourceStateType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ResourceStateType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ResourceStateType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ResourceStateType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ResourceStateType {
+    fn deserialize<D>(deserializer: D) -> Result<ResourceStateType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ResourceType {
+    /// `FOLDER`
+    Folder,
+    /// `DOCUMENT`
+    Document,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ResourceType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceType::Folder => "FOLDER",
+            ResourceType::Document => "DOCUMENT",
+            ResourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ResourceType {
+    fn default() -> ResourceType {
+        ResourceType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ResourceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ResourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ResourceType {
+    fn from(value: &'a str) -> ResourceType {
+        match value {
+            "FOLDER" => ResourceType::Folder,
+            "DOCUMENT" => ResourceType::Document,
+            _ => ResourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceType {
+    fn from(value: String) -> ResourceType {
+        match value.as_str() {
+            "FOLDER" => ResourceType::Folder,
+            "DOCUMENT" => ResourceType::Document,
+            _ => ResourceType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ResourceType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ResourceType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ResourceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ResourceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ResourceType {
+    fn deserialize<D>(deserializer: D) -> Result<ResourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RolePermissionType {
+    /// `DIRECT`
+    Direct,
+    /// `INHERITED`
+    Inherited,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RolePermissionType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RolePermissionType::Direct => "DIRECT",
+            RolePermissionType::Inherited => "INHERITED",
+            RolePermissionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RolePermissionType {
+    fn default() -> RolePermissionType {
+        RolePermissionType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RolePermissionType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RolePermissionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RolePermissionType {
+    fn from(value: &'a str) -> RolePermissionType {
+        match value {
+            "DIRECT" => RolePermissionType::Direct,
+            "INHERITED" => RolePermissionType::Inherited,
+            _ => RolePermissionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RolePermissionType {
+    fn from(value: String) -> RolePermissionType {
+        match value.as_str() {
+            "DIRECT" => RolePermissionType::Direct,
+            "INHERITED" => RolePermissionType::Inherited,
+            _ => RolePermissionType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RolePermissionType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RolePermissionType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RolePermissionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RolePermissionType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RolePermissionType {
+    fn deserialize<D>(deserializer: D) -> Result<RolePermissionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RoleType {
+    /// `VIEWER`
+    Viewer,
+    /// `CONTRIBUTOR`
+    Contributor,
+    /// `OWNER`
+    Owner,
+    /// `COOWNER`
+    Coowner,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RoleType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RoleType::Viewer => "VIEWER",
+            RoleType::Contributor => "CONTRIBUTOR",
+            RoleType::Owner => "OWNER",
+            RoleType::Coowner => "COOWNER",
+            RoleType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RoleType {
+    fn default() -> RoleType {
+        RoleType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RoleType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RoleType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
-/// <p>Describes a resource.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct Principal {
-    /// <p>The ID of the resource.</p>
-    #[serde(rename = "Id")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
-    /// <p>The permission information for the resource.</p>
-    #[serde(rename = "Roles")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub roles: Option<Vec<PermissionInfo>>,
-    /// <p>The type of resource.</p>
-    #[serde(rename = "Type")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+impl<'a> From<&'a str> for RoleType {
+    fn from(value: &'a str) -> RoleType {
+        match value {
+            "VIEWER" => RoleType::Viewer,
+            "CONTRIBUTOR" => RoleType::Contributor,
+            "OWNER" => RoleType::Owner,
+            "COOWNER" => RoleType::Coowner,
+            _ => RoleType::Unknown(value.to_owned()),
+        }
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct RemoveAllResourcePermissionsRequest {
-    /// <p>Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API using AWS credentials.</p>
-    #[serde(rename = "AuthenticationToken")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub authentication_token: Option<String>,
-    /// <p>The ID of the resource.</p>
-    #[serde(rename = "ResourceId")]
-    pub resource_id: String,
+impl From<String> for RoleType {
+    fn from(value: String) -> RoleType {
+        match value.as_str() {
+            "VIEWER" => RoleType::Viewer,
+            "CONTRIBUTOR" => RoleType::Contributor,
+            "OWNER" => RoleType::Owner,
+            "COOWNER" => RoleType::Coowner,
+            _ => RoleType::Unknown(value),
+        }
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct RemoveResourcePermissionRequest {
-    /// <p>Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API using AWS credentials.</p>
-    #[serde(rename = "AuthenticationToken")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub authentication_token: Option<String>,
-    /// <p>The principal ID of the resource.</p>
-    #[serde(rename = "PrincipalId")]
-    pub principal_id: String,
-    /// <p>The principal type of the resource.</p>
-    #[serde(rename = "PrincipalType")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub principal_type: Option<String>,
-    /// <p>The ID of the resource.</p>
-    #[serde(rename = "ResourceId")]
-    pub resource_id: String,
-}
+impl ::std::str::FromStr for RoleType {
+    type Err = ::std::convert::Infallible;
 
-/// <p>Describes the metadata of a resource.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct ResourceMetadata {
-    /// <p>The ID of the resource.</p>
-    #[serde(rename = "Id")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
-    /// <p>The name of the resource.</p>
-    #[serde(rename = "Name")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
-    /// <p>The original name of the resource before a rename operation.</p>
-    #[serde(rename = "OriginalName")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub original_name: Option<String>,
-    /// <p>The owner of the resource.</p>
-    #[serde(rename = "Owner")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub owner: Option<UserMetadata>,
-    /// <p>The parent ID of the resource before a rename operation.</p>
-    #[serde(rename = "ParentId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub parent_id: Option<String>,
-    /// <p>The type of resource.</p>
-    #[serde(rename = "Type")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
-    /// <p>The version ID of the resource. This is an optional field and is filled for action on document version.</p>
-    #[serde(rename = "VersionId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub version_id: Option<String>,
+    fn from_str(value: &str) -> Result<RoleType, Self::Err> {
+        Ok(value.into())
+    }
 }
 
-/// <p>Describes the path information of a resource.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct ResourcePath {
-    /// <p>The components of the resource path.</p>
-    #[serde(rename = "Components")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub components: Option<Vec<ResourcePathComponent>>,
+impl ::rusoto_core::param::ToParam for RoleType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
 }
 
-/// <p>Describes the resource path.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct ResourcePathComponent {
-    /// <p>The ID of the resource path.</p>
-    #[serde(rename = "Id")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
-    /// <p>The name of the resource path.</p>
-    #[serde(rename = "Name")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
+impl ::serde::Serialize for RoleType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for RoleType {
+    fn deserialize<D>(deserializer: D) -> Result<RoleType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes the recipient type and ID, if available.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -1485,10 +3486,10 @@ pub struct SharePrincipal {
     pub id: String,
     /// <p>The role of the recipient.</p>
     #[serde(rename = "Role")]
-    pub role: String,
+    pub role: RoleType,
     /// <p>The type of the recipient.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: PrincipalType,
 }
 
 /// <p>Describes the share results of a resource.</p>
@@ -1506,7 +3507,7 @@ pub struct ShareResult {
     /// <p>The role.</p>
     #[serde(rename = "Role")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub role: Option<String>,
+    pub role: Option<RoleType>,
     /// <p>The ID of the resource that was shared.</p>
     #[serde(rename = "ShareId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1514,13 +3515,106 @@ pub struct ShareResult {
     /// <p>The status.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ShareStatusType>,
     /// <p>The status message.</p>
     #[serde(rename = "StatusMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status_message: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ShareStatusType {
+    /// `SUCCESS`
+    Success,
+    /// `FAILURE`
+    Failure,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ShareStatusType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ShareStatusType::Success => "SUCCESS",
+            ShareStatusType::Failure => "FAILURE",
+            ShareStatusType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ShareStatusType {
+    fn default() -> ShareStatusType {
+        ShareStatusType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ShareStatusType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ShareStatusType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ShareStatusType {
+    fn from(value: &'a str) -> ShareStatusType {
+        match value {
+            "SUCCESS" => ShareStatusType::Success,
+            "FAILURE" => ShareStatusType::Failure,
+            _ => ShareStatusType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ShareStatusType {
+    fn from(value: String) -> ShareStatusType {
+        match value.as_str() {
+            "SUCCESS" => ShareStatusType::Success,
+            "FAILURE" => ShareStatusType::Failure,
+            _ => ShareStatusType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ShareStatusType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ShareStatusType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ShareStatusType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ShareStatusType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ShareStatusType {
+    fn deserialize<D>(deserializer: D) -> Result<ShareStatusType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes the storage for a user.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct StorageRuleType {
@@ -1531,9 +3625,102 @@ pub struct StorageRuleType {
     /// <p>The type of storage.</p>
     #[serde(rename = "StorageType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub storage_type: Option<String>,
+    pub storage_type: Option<StorageType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StorageType {
+    /// `UNLIMITED`
+    Unlimited,
+    /// `QUOTA`
+    Quota,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl StorageType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StorageType::Unlimited => "UNLIMITED",
+            StorageType::Quota => "QUOTA",
+            StorageType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for StorageType {
+    fn default() -> StorageType {
+        StorageType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for StorageType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
 }
 
+impl fmt::Display for StorageType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for StorageType {
+    fn from(value: &'a str) -> StorageType {
+        match value {
+            "UNLIMITED" => StorageType::Unlimited,
+            "QUOTA" => StorageType::Quota,
+            _ => StorageType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StorageType {
+    fn from(value: String) -> StorageType {
+        match value.as_str() {
+            "UNLIMITED" => StorageType::Unlimited,
+            "QUOTA" => StorageType::Quota,
+            _ => StorageType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for StorageType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<StorageType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StorageType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for StorageType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StorageType {
+    fn deserialize<D>(deserializer: D) -> Result<StorageType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes a subscription.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1545,13 +3732,194 @@ pub struct Subscription {
     /// <p>The protocol of the subscription.</p>
     #[serde(rename = "Protocol")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub protocol: Option<String>,
+    pub protocol: Option<SubscriptionProtocolType>,
     /// <p>The ID of the subscription.</p>
     #[serde(rename = "SubscriptionId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub subscription_id: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SubscriptionProtocolType {
+    /// `HTTPS`
+    Https,
+    /// `SQS`
+    Sqs,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SubscriptionProtocolType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SubscriptionProtocolType::Https => "HTTPS",
+            SubscriptionProtocolType::Sqs => "SQS",
+            SubscriptionProtocolType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SubscriptionProtocolType {
+    fn default() -> SubscriptionProtocolType {
+        SubscriptionProtocolType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SubscriptionProtocolType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SubscriptionProtocolType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SubscriptionProtocolType {
+    fn from(value: &'a str) -> SubscriptionProtocolType {
+        match value {
+            "HTTPS" => SubscriptionProtocolType::Https,
+            "SQS" => SubscriptionProtocolType::Sqs,
+            _ => SubscriptionProtocolType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SubscriptionProtocolType {
+    fn from(value: String) -> SubscriptionProtocolType {
+        match value.as_str() {
+            "HTTPS" => SubscriptionProtocolType::Https,
+            "SQS" => SubscriptionProtocolType::Sqs,
+            _ => SubscriptionProtocolType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SubscriptionProtocolType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SubscriptionProtocolType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SubscriptionProtocolType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SubscriptionProtocolType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SubscriptionProtocolType {
+    fn deserialize<D>(deserializer: D) -> Result<SubscriptionProtocolType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SubscriptionType {
+    /// `ALL`
+    All,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SubscriptionType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SubscriptionType::All => "ALL",
+            SubscriptionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SubscriptionType {
+    fn default() -> SubscriptionType {
+        SubscriptionType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SubscriptionType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SubscriptionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SubscriptionType {
+    fn from(value: &'a str) -> SubscriptionType {
+        match value {
+            "ALL" => SubscriptionType::All,
+            _ => SubscriptionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SubscriptionType {
+    fn from(value: String) -> SubscriptionType {
+        match value.as_str() {
+            "ALL" => SubscriptionType::All,
+            _ => SubscriptionType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SubscriptionType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SubscriptionType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SubscriptionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SubscriptionType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SubscriptionType {
+    fn deserialize<D>(deserializer: D) -> Result<SubscriptionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct UpdateDocumentRequest {
@@ -1573,7 +3941,7 @@ pub struct UpdateDocumentRequest {
     /// <p>The resource state of the document. Only ACTIVE and RECYCLED are supported.</p>
     #[serde(rename = "ResourceState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_state: Option<String>,
+    pub resource_state: Option<ResourceStateType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -1592,7 +3960,7 @@ pub struct UpdateDocumentVersionRequest {
     /// <p>The status of the version.</p>
     #[serde(rename = "VersionStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub version_status: Option<String>,
+    pub version_status: Option<DocumentVersionStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -1616,7 +3984,7 @@ pub struct UpdateFolderRequest {
     /// <p>The resource state of the folder. Only ACTIVE and RECYCLED are accepted values from the API.</p>
     #[serde(rename = "ResourceState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_state: Option<String>,
+    pub resource_state: Option<ResourceStateType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -1633,11 +4001,11 @@ pub struct UpdateUserRequest {
     /// <p>Boolean value to determine whether the user is granted Poweruser privileges.</p>
     #[serde(rename = "GrantPoweruserPrivileges")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub grant_poweruser_privileges: Option<String>,
+    pub grant_poweruser_privileges: Option<BooleanEnumType>,
     /// <p>The locale of the user.</p>
     #[serde(rename = "Locale")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub locale: Option<String>,
+    pub locale: Option<LocaleType>,
     /// <p>The amount of storage for the user.</p>
     #[serde(rename = "StorageRule")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1653,7 +4021,7 @@ pub struct UpdateUserRequest {
     /// <p>The type of the user.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<UserType>,
     /// <p>The ID of the user.</p>
     #[serde(rename = "UserId")]
     pub user_id: String,
@@ -1688,8 +4056,13 @@ pub struct UploadMetadata {
 pub struct User {
     /// <p>The time when the user was created.</p>
     #[serde(rename = "CreatedTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_timestamp: Option<f64>,
+    pub created_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The email address of the user.</p>
     #[serde(rename = "EmailAddress")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1705,11 +4078,16 @@ pub struct User {
     /// <p>The locale of the user.</p>
     #[serde(rename = "Locale")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub locale: Option<String>,
+    pub locale: Option<LocaleType>,
     /// <p>The time when the user was modified.</p>
     #[serde(rename = "ModifiedTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub modified_timestamp: Option<f64>,
+    pub modified_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The ID of the organization.</p>
     #[serde(rename = "OrganizationId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1725,7 +4103,7 @@ pub struct User {
     /// <p>The status of the user.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<UserStatusType>,
     /// <p>The storage for the user.</p>
     #[serde(rename = "Storage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1741,13 +4119,106 @@ pub struct User {
     /// <p>The type of user.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<UserType>,
     /// <p>The login name of the user.</p>
     #[serde(rename = "Username")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub username: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum UserFilterType {
+    /// `ALL`
+    All,
+    /// `ACTIVE_PENDING`
+    ActivePending,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl UserFilterType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            UserFilterType::All => "ALL",
+            UserFilterType::ActivePending => "ACTIVE_PENDING",
+            UserFilterType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for UserFilterType {
+    fn default() -> UserFilterType {
+        UserFilterType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for UserFilterType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for UserFilterType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for UserFilterType {
+    fn from(value: &'a str) -> UserFilterType {
+        match value {
+            "ALL" => UserFilterType::All,
+            "ACTIVE_PENDING" => UserFilterType::ActivePending,
+            _ => UserFilterType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for UserFilterType {
+    fn from(value: String) -> UserFilterType {
+        match value.as_str() {
+            "ALL" => UserFilterType::All,
+            "ACTIVE_PENDING" => UserFilterType::ActivePending,
+            _ => UserFilterType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for UserFilterType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<UserFilterType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for UserFilterType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for UserFilterType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for UserFilterType {
+    fn deserialize<D>(deserializer: D) -> Result<UserFilterType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes the metadata of the user.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1774,6 +4245,212 @@ pub struct UserMetadata {
     pub username: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum UserSortType {
+    /// `USER_NAME`
+    UserName,
+    /// `FULL_NAME`
+    FullName,
+    /// `STORAGE_LIMIT`
+    StorageLimit,
+    /// `USER_STATUS`
+    UserStatus,
+    /// `STORAGE_USED`
+    StorageUsed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl UserSortType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            UserSortType::UserName => "USER_NAME",
+            UserSortType::FullName => "FULL_NAME",
+            UserSortType::StorageLimit => "STORAGE_LIMIT",
+            UserSortType::UserStatus => "USER_STATUS",
+            UserSortType::StorageUsed => "STORAGE_USED",
+            UserSortType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for UserSortType {
+    fn default() -> UserSortType {
+        UserSortType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for UserSortType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for UserSortType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for UserSortType {
+    fn from(value: &'a str) -> UserSortType {
+        match value {
+            "USER_NAME" => UserSortType::UserName,
+            "FULL_NAME" => UserSortType::FullName,
+            "STORAGE_LIMIT" => UserSortType::StorageLimit,
+            "USER_STATUS" => UserSortType::UserStatus,
+            "STORAGE_USED" => UserSortType::StorageUsed,
+            _ => UserSortType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for UserSortType {
+    fn from(value: String) -> UserSortType {
+        match value.as_str() {
+            "USER_NAME" => UserSortType::UserName,
+            "FULL_NAME" => UserSortType::FullName,
+            "STORAGE_LIMIT" => UserSortType::StorageLimit,
+            "USER_STATUS" => UserSortType::UserStatus,
+            "STORAGE_USED" => UserSortType::StorageUsed,
+            _ => UserSortType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for UserSortType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<UserSortType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for UserSortType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for UserSortType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for UserSortType {
+    fn deserialize<D>(deserializer: D) -> Result<UserSortType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum UserStatusType {
+    /// `ACTIVE`
+    Active,
+    /// `INACTIVE`
+    Inactive,
+    /// `PENDING`
+    Pending,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl UserStatusType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            UserStatusType::Active => "ACTIVE",
+            UserStatusType::Inactive => "INACTIVE",
+            UserStatusType::Pending => "PENDING",
+            UserStatusType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for UserStatusType {
+    fn default() -> UserStatusType {
+        UserStatusType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for UserStatusType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for UserStatusType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for UserStatusType {
+    fn from(value: &'a str) -> UserStatusType {
+        match value {
+            "ACTIVE" => UserStatusType::Active,
+            "INACTIVE" => UserStatusType::Inactive,
+            "PENDING" => UserStatusType::Pending,
+            _ => UserStatusType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for UserStatusType {
+    fn from(value: String) -> UserStatusType {
+        match value.as_str() {
+            "ACTIVE" => UserStatusType::Active,
+            "INACTIVE" => UserStatusType::Inactive,
+            "PENDING" => UserStatusType::Pending,
+            _ => UserStatusType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for UserStatusType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<UserStatusType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for UserStatusType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for UserStatusType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for UserStatusType {
+    fn deserialize<D>(deserializer: D) -> Result<UserStatusType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes the storage for a user.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1788,6 +4465,114 @@ pub struct UserStorageMetadata {
     pub storage_utilized_in_bytes: Option<i64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum UserType {
+    /// `USER`
+    User,
+    /// `ADMIN`
+    Admin,
+    /// `POWERUSER`
+    Poweruser,
+    /// `MINIMALUSER`
+    Minimaluser,
+    /// `WORKSPACESUSER`
+    Workspacesuser,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl UserType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            UserType::User => "USER",
+            UserType::Admin => "ADMIN",
+            UserType::Poweruser => "POWERUSER",
+            UserType::Minimaluser => "MINIMALUSER",
+            UserType::Workspacesuser => "WORKSPACESUSER",
+            UserType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for UserType {
+    fn default() -> UserType {
+        UserType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for UserType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for UserType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for UserType {
+    fn from(value: &'a str) -> UserType {
+        match value {
+            "USER" => UserType::User,
+            "ADMIN" => UserType::Admin,
+            "POWERUSER" => UserType::Poweruser,
+            "MINIMALUSER" => UserType::Minimaluser,
+            "WORKSPACESUSER" => UserType::Workspacesuser,
+            _ => UserType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for UserType {
+    fn from(value: String) -> UserType {
+        match value.as_str() {
+            "USER" => UserType::User,
+            "ADMIN" => UserType::Admin,
+            "POWERUSER" => UserType::Poweruser,
+            "MINIMALUSER" => UserType::Minimaluser,
+            "WORKSPACESUSER" => UserType::Workspacesuser,
+            _ => UserType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for UserType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<UserType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for UserType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for UserType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for UserType {
+    fn deserialize<D>(deserializer: D) -> Result<UserType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// Errors returned by AbortDocumentVersionUpload
 #[derive(Debug, PartialEq)]
 pub enum AbortDocumentVersionUploadError {
@@ -4912,11 +7697,12 @@ pub struct WorkdocsClient {
 impl WorkdocsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> WorkdocsClient {
         WorkdocsClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "WorkDocs"),
         }
     }
 
@@ -4954,6 +7740,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
+        request.set_operation("AbortDocumentVersionUpload");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         if let Some(ref authentication_token) = input.authentication_token {
@@ -4987,6 +7774,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("POST", "workdocs", &self.region, &request_uri);
+        request.set_operation("ActivateUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         if let Some(ref authentication_token) = input.authentication_token {
@@ -5021,6 +7809,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("POST", "workdocs", &self.region, &request_uri);
+        request.set_operation("AddResourcePermissions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5059,6 +7848,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("POST", "workdocs", &self.region, &request_uri);
+        request.set_operation("CreateComment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5096,6 +7886,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("PUT", "workdocs", &self.region, &request_uri);
+        request.set_operation("CreateCustomMetadata");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5135,6 +7926,7 @@ impl Workdocs for WorkdocsClient {
         let request_uri = "/api/v1/folders";
 
         let mut request = SignedRequest::new("POST", "workdocs", &self.region, &request_uri);
+        request.set_operation("CreateFolder");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5172,6 +7964,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("PUT", "workdocs", &self.region, &request_uri);
+        request.set_operation("CreateLabels");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5212,6 +8005,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("POST", "workdocs", &self.region, &request_uri);
+        request.set_operation("CreateNotificationSubscription");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5242,6 +8036,7 @@ impl Workdocs for WorkdocsClient {
         let request_uri = "/api/v1/users";
 
         let mut request = SignedRequest::new("POST", "workdocs", &self.region, &request_uri);
+        request.set_operation("CreateUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5279,6 +8074,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
+        request.set_operation("DeactivateUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         if let Some(ref authentication_token) = input.authentication_token {
@@ -5314,6 +8110,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
+        request.set_operation("DeleteComment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         if let Some(ref authentication_token) = input.authentication_token {
@@ -5347,6 +8144,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
+        request.set_operation("DeleteCustomMetadata");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         if let Some(ref authentication_token) = input.authentication_token {
@@ -5394,6 +8192,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
+        request.set_operation("DeleteDocument");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         if let Some(ref authentication_token) = input.authentication_token {
@@ -5424,6 +8223,7 @@ impl Workdocs for WorkdocsClient {
         let request_uri = format!("/api/v1/folders/{folder_id}", folder_id = input.folder_id);
 
         let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
+        request.set_operation("DeleteFolder");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         if let Some(ref authentication_token) = input.authentication_token {
@@ -5457,6 +8257,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
+        request.set_operation("DeleteFolderContents");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         if let Some(ref authentication_token) = input.authentication_token {
@@ -5490,6 +8291,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
+        request.set_operation("DeleteLabels");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         if let Some(ref authentication_token) = input.authentication_token {
@@ -5535,6 +8337,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
+        request.set_operation("DeleteNotificationSubscription");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5561,6 +8364,7 @@ impl Workdocs for WorkdocsClient {
         let request_uri = format!("/api/v1/users/{user_id}", user_id = input.user_id);
 
         let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
+        request.set_operation("DeleteUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         if let Some(ref authentication_token) = input.authentication_token {
@@ -5591,6 +8395,7 @@ impl Workdocs for WorkdocsClient {
         let request_uri = "/api/v1/activities";
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("DescribeActivities");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         if let Some(ref authentication_token) = input.authentication_token {
@@ -5601,7 +8406,10 @@ impl Workdocs for WorkdocsClient {
             params.put("activityTypes", x);
         }
         if let Some(ref x) = input.end_time {
-            params.put("endTime", x);
+            params.put(
+                "endTime",
+                &rusoto_core::serialization::TimestampFormat::Iso8601.format(x),
+            );
         }
         if let Some(ref x) = input.include_indirect_activities {
             params.put("includeIndirectActivities", x);
@@ -5619,7 +8427,10 @@ impl Workdocs for WorkdocsClient {
             params.put("resourceId", x);
         }
         if let Some(ref x) = input.start_time {
-            params.put("startTime", x);
+            params.put(
+                "startTime",
+                &rusoto_core::serialization::TimestampFormat::Iso8601.format(x),
+            );
         }
         if let Some(ref x) = input.user_id {
             params.put("userId", x);
@@ -5655,6 +8466,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("DescribeComments");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         if let Some(ref authentication_token) = input.authentication_token {
@@ -5697,6 +8509,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("DescribeDocumentVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         if let Some(ref authentication_token) = input.authentication_token {
@@ -5745,6 +8558,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("DescribeFolderContents");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         if let Some(ref authentication_token) = input.authentication_token {
@@ -5796,6 +8610,7 @@ impl Workdocs for WorkdocsClient {
         let request_uri = "/api/v1/groups";
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("DescribeGroups");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         if let Some(ref authentication_token) = input.authentication_token {
@@ -5845,6 +8660,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("DescribeNotificationSubscriptions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5887,6 +8703,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("DescribeResourcePermissions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         if let Some(ref authentication_token) = input.authentication_token {
@@ -5929,6 +8746,7 @@ impl Workdocs for WorkdocsClient {
         let request_uri = "/api/v1/me/root";
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("DescribeRootFolders");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_header("Authentication", &input.authentication_token);
@@ -5966,6 +8784,7 @@ impl Workdocs for WorkdocsClient {
         let request_uri = "/api/v1/users";
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("DescribeUsers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         if let Some(ref authentication_token) = input.authentication_token {
@@ -6026,6 +8845,7 @@ impl Workdocs for WorkdocsClient {
         let request_uri = "/api/v1/me";
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("GetCurrentUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_header("Authentication", &input.authentication_token);
@@ -6058,6 +8878,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("GetDocument");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         if let Some(ref authentication_token) = input.authentication_token {
@@ -6097,6 +8918,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("GetDocumentPath");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         if let Some(ref authentication_token) = input.authentication_token {
@@ -6143,6 +8965,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("GetDocumentVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         if let Some(ref authentication_token) = input.authentication_token {
@@ -6182,6 +9005,7 @@ impl Workdocs for WorkdocsClient {
         let request_uri = format!("/api/v1/folders/{folder_id}", folder_id = input.folder_id);
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("GetFolder");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         if let Some(ref authentication_token) = input.authentication_token {
@@ -6221,6 +9045,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("GetFolderPath");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         if let Some(ref authentication_token) = input.authentication_token {
@@ -6263,6 +9088,7 @@ impl Workdocs for WorkdocsClient {
         let request_uri = "/api/v1/resources";
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("GetResources");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         if let Some(ref authentication_token) = input.authentication_token {
@@ -6311,6 +9137,7 @@ impl Workdocs for WorkdocsClient {
         let request_uri = "/api/v1/documents";
 
         let mut request = SignedRequest::new("POST", "workdocs", &self.region, &request_uri);
+        request.set_operation("InitiateDocumentVersionUpload");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6348,6 +9175,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
+        request.set_operation("RemoveAllResourcePermissions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         if let Some(ref authentication_token) = input.authentication_token {
@@ -6382,6 +9210,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
+        request.set_operation("RemoveResourcePermission");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         if let Some(ref authentication_token) = input.authentication_token {
@@ -6420,6 +9249,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "workdocs", &self.region, &request_uri);
+        request.set_operation("UpdateDocument");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6457,6 +9287,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "workdocs", &self.region, &request_uri);
+        request.set_operation("UpdateDocumentVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6490,6 +9321,7 @@ impl Workdocs for WorkdocsClient {
         let request_uri = format!("/api/v1/folders/{folder_id}", folder_id = input.folder_id);
 
         let mut request = SignedRequest::new("PATCH", "workdocs", &self.region, &request_uri);
+        request.set_operation("UpdateFolder");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6523,6 +9355,7 @@ impl Workdocs for WorkdocsClient {
         let request_uri = format!("/api/v1/users/{user_id}", user_id = input.user_id);
 
         let mut request = SignedRequest::new("PATCH", "workdocs", &self.region, &request_uri);
+        request.set_operation("UpdateUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6549,3 +9382,304 @@ impl Workdocs for WorkdocsClient {
         }
     }
 }
+impl WorkdocsClient {
+    /// Auto-paginating version of `describe_activities`, streaming every page of results.
+    pub fn describe_activities_pages(
+        &self,
+        input: DescribeActivitiesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeActivitiesResponse, DescribeActivitiesError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: WorkdocsClient, input: DescribeActivitiesRequest| async move {
+                client.describe_activities(input).await
+            },
+            |input: &DescribeActivitiesRequest, output: &DescribeActivitiesResponse| {
+                Some(DescribeActivitiesRequest {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_activities`, streaming the `UserActivities` of every page of results.
+    pub fn describe_activities_items(
+        &self,
+        input: DescribeActivitiesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Activity, DescribeActivitiesError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_activities_pages(input),
+            |output: DescribeActivitiesResponse| output.user_activities.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_comments`, streaming every page of results.
+    pub fn describe_comments_pages(
+        &self,
+        input: DescribeCommentsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeCommentsResponse, DescribeCommentsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: WorkdocsClient, input: DescribeCommentsRequest| async move {
+                client.describe_comments(input).await
+            },
+            |input: &DescribeCommentsRequest, output: &DescribeCommentsResponse| {
+                Some(DescribeCommentsRequest {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_comments`, streaming the `Comments` of every page of results.
+    pub fn describe_comments_items(
+        &self,
+        input: DescribeCommentsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Comment, DescribeCommentsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_comments_pages(input),
+            |output: DescribeCommentsResponse| output.comments.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_document_versions`, streaming every page of results.
+    pub fn describe_document_versions_pages(
+        &self,
+        input: DescribeDocumentVersionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeDocumentVersionsResponse,
+        DescribeDocumentVersionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: WorkdocsClient, input: DescribeDocumentVersionsRequest| async move {
+                client.describe_document_versions(input).await
+            },
+            |input: &DescribeDocumentVersionsRequest, output: &DescribeDocumentVersionsResponse| {
+                Some(DescribeDocumentVersionsRequest {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_document_versions`, streaming the `DocumentVersions` of every page of results.
+    pub fn describe_document_versions_items(
+        &self,
+        input: DescribeDocumentVersionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DocumentVersionMetadata, DescribeDocumentVersionsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_document_versions_pages(input),
+            |output: DescribeDocumentVersionsResponse| output.document_versions.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_folder_contents`, streaming every page of results.
+    pub fn describe_folder_contents_pages(
+        &self,
+        input: DescribeFolderContentsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeFolderContentsResponse,
+        DescribeFolderContentsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: WorkdocsClient, input: DescribeFolderContentsRequest| async move {
+                client.describe_folder_contents(input).await
+            },
+            |input: &DescribeFolderContentsRequest, output: &DescribeFolderContentsResponse| {
+                Some(DescribeFolderContentsRequest {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_folder_contents`, streaming the `Folders` of every page of results.
+    pub fn describe_folder_contents_items(
+        &self,
+        input: DescribeFolderContentsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<FolderMetadata, DescribeFolderContentsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_folder_contents_pages(input),
+            |output: DescribeFolderContentsResponse| output.folders.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_groups`, streaming every page of results.
+    pub fn describe_groups_pages(
+        &self,
+        input: DescribeGroupsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeGroupsResponse, DescribeGroupsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: WorkdocsClient, input: DescribeGroupsRequest| async move {
+                client.describe_groups(input).await
+            },
+            |input: &DescribeGroupsRequest, output: &DescribeGroupsResponse| {
+                Some(DescribeGroupsRequest {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_groups`, streaming the `Groups` of every page of results.
+    pub fn describe_groups_items(
+        &self,
+        input: DescribeGroupsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GroupMetadata, DescribeGroupsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_groups_pages(input),
+            |output: DescribeGroupsResponse| output.groups.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_notification_subscriptions`, streaming every page of results.
+    pub fn describe_notification_subscriptions_pages(
+        &self,
+        input: DescribeNotificationSubscriptionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeNotificationSubscriptionsResponse,
+        DescribeNotificationSubscriptionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: WorkdocsClient, input: DescribeNotificationSubscriptionsRequest| async move {
+                client.describe_notification_subscriptions(input).await
+            },
+            |input: &DescribeNotificationSubscriptionsRequest,
+             output: &DescribeNotificationSubscriptionsResponse| {
+                Some(DescribeNotificationSubscriptionsRequest {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_notification_subscriptions`, streaming the `Subscriptions` of every page of results.
+    pub fn describe_notification_subscriptions_items(
+        &self,
+        input: DescribeNotificationSubscriptionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Subscription, DescribeNotificationSubscriptionsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_notification_subscriptions_pages(input),
+            |output: DescribeNotificationSubscriptionsResponse| {
+                output.subscriptions.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_resource_permissions`, streaming every page of results.
+    pub fn describe_resource_permissions_pages(
+        &self,
+        input: DescribeResourcePermissionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeResourcePermissionsResponse,
+        DescribeResourcePermissionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: WorkdocsClient, input: DescribeResourcePermissionsRequest| async move {
+                client.describe_resource_permissions(input).await
+            },
+            |input: &DescribeResourcePermissionsRequest,
+             output: &DescribeResourcePermissionsResponse| {
+                Some(DescribeResourcePermissionsRequest {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_resource_permissions`, streaming the `Principals` of every page of results.
+    pub fn describe_resource_permissions_items(
+        &self,
+        input: DescribeResourcePermissionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Principal, DescribeResourcePermissionsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_resource_permissions_pages(input),
+            |output: DescribeResourcePermissionsResponse| output.principals.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_root_folders`, streaming every page of results.
+    pub fn describe_root_folders_pages(
+        &self,
+        input: DescribeRootFoldersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeRootFoldersResponse, DescribeRootFoldersError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: WorkdocsClient, input: DescribeRootFoldersRequest| async move {
+                client.describe_root_folders(input).await
+            },
+            |input: &DescribeRootFoldersRequest, output: &DescribeRootFoldersResponse| {
+                Some(DescribeRootFoldersRequest {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_root_folders`, streaming the `Folders` of every page of results.
+    pub fn describe_root_folders_items(
+        &self,
+        input: DescribeRootFoldersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<FolderMetadata, DescribeRootFoldersError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_root_folders_pages(input),
+            |output: DescribeRootFoldersResponse| output.folders.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_users`, streaming every page of results.
+    pub fn describe_users_pages(
+        &self,
+        input: DescribeUsersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeUsersResponse, DescribeUsersError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: WorkdocsClient, input: DescribeUsersRequest| async move {
+                client.describe_users(input).await
+            },
+            |input: &DescribeUsersRequest, output: &DescribeUsersResponse| {
+                Some(DescribeUsersRequest {
+                    marker: Some(output.marker.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_users`, streaming the `Users` of every page of results.
+    pub fn describe_users_items(
+        &self,
+        input: DescribeUsersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<User, DescribeUsersError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_users_pages(input),
+            |output: DescribeUsersResponse| output.users.unwrap_or_default(),
+        )
+    }
+}
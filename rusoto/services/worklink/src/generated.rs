@@ -51,7 +51,7 @@ pub struct AssociateDomainResponse {}
 pub struct AssociateWebsiteAuthorizationProviderRequest {
     /// <p>The authorization provider type.</p>
     #[serde(rename = "AuthorizationProviderType")]
-    pub authorization_provider_type: String,
+    pub authorization_provider_type: AuthorizationProviderType,
     /// <p>The domain name of the authorization provider. This applies only to SAML-based authorization providers.</p>
     #[serde(rename = "DomainName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -94,6 +94,94 @@ pub struct AssociateWebsiteCertificateAuthorityResponse {
     pub website_ca_id: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AuthorizationProviderType {
+    /// `SAML`
+    Saml,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AuthorizationProviderType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AuthorizationProviderType::Saml => "SAML",
+            AuthorizationProviderType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AuthorizationProviderType {
+    fn default() -> AuthorizationProviderType {
+        AuthorizationProviderType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AuthorizationProviderType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AuthorizationProviderType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AuthorizationProviderType {
+    fn from(value: &'a str) -> AuthorizationProviderType {
+        match value {
+            "SAML" => AuthorizationProviderType::Saml,
+            _ => AuthorizationProviderType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AuthorizationProviderType {
+    fn from(value: String) -> AuthorizationProviderType {
+        match value.as_str() {
+            "SAML" => AuthorizationProviderType::Saml,
+            _ => AuthorizationProviderType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AuthorizationProviderType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AuthorizationProviderType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AuthorizationProviderType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AuthorizationProviderType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AuthorizationProviderType {
+    fn deserialize<D>(deserializer: D) -> Result<AuthorizationProviderType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateFleetRequest {
@@ -206,12 +294,22 @@ pub struct DescribeDeviceRequest {
 pub struct DescribeDeviceResponse {
     /// <p>The date that the device first signed in to Amazon WorkLink.</p>
     #[serde(rename = "FirstAccessedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub first_accessed_time: Option<f64>,
+    pub first_accessed_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The date that the device last accessed Amazon WorkLink.</p>
     #[serde(rename = "LastAccessedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_accessed_time: Option<f64>,
+    pub last_accessed_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The manufacturer of the device.</p>
     #[serde(rename = "Manufacturer")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -235,7 +333,7 @@ pub struct DescribeDeviceResponse {
     /// <p>The current state of the device.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<DeviceStatus>,
     /// <p>The user name associated with the device.</p>
     #[serde(rename = "Username")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -262,8 +360,13 @@ pub struct DescribeDomainResponse {
     pub acm_certificate_arn: Option<String>,
     /// <p>The time that the domain was added.</p>
     #[serde(rename = "CreatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time: Option<f64>,
+    pub created_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name to display.</p>
     #[serde(rename = "DisplayName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -275,7 +378,7 @@ pub struct DescribeDomainResponse {
     /// <p>The current state for the domain.</p>
     #[serde(rename = "DomainStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub domain_status: Option<String>,
+    pub domain_status: Option<DomainStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -295,8 +398,13 @@ pub struct DescribeFleetMetadataResponse {
     pub company_code: Option<String>,
     /// <p>The time that the fleet was created.</p>
     #[serde(rename = "CreatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time: Option<f64>,
+    pub created_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name to display.</p>
     #[serde(rename = "DisplayName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -308,11 +416,16 @@ pub struct DescribeFleetMetadataResponse {
     /// <p>The current state of the fleet.</p>
     #[serde(rename = "FleetStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub fleet_status: Option<String>,
+    pub fleet_status: Option<FleetStatus>,
     /// <p>The time that the fleet was last updated.</p>
     #[serde(rename = "LastUpdatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_time: Option<f64>,
+    pub last_updated_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The option to optimize for better performance by routing traffic through the closest AWS Region to users, which may be outside of your home Region.</p>
     #[serde(rename = "OptimizeForEndUserLocation")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -337,7 +450,7 @@ pub struct DescribeIdentityProviderConfigurationResponse {
     /// <p>The type of identity provider.</p>
     #[serde(rename = "IdentityProviderType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub identity_provider_type: Option<String>,
+    pub identity_provider_type: Option<IdentityProviderType>,
     /// <p>The SAML metadata document uploaded to the user’s identity provider.</p>
     #[serde(rename = "ServiceProviderSamlMetadata")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -364,14 +477,112 @@ pub struct DescribeWebsiteCertificateAuthorityResponse {
     pub certificate: Option<String>,
     /// <p>The time that the certificate authority was added.</p>
     #[serde(rename = "CreatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time: Option<f64>,
+    pub created_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The certificate name to display.</p>
     #[serde(rename = "DisplayName")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub display_name: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DeviceStatus {
+    /// `ACTIVE`
+    Active,
+    /// `SIGNED_OUT`
+    SignedOut,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DeviceStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeviceStatus::Active => "ACTIVE",
+            DeviceStatus::SignedOut => "SIGNED_OUT",
+            DeviceStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DeviceStatus {
+    fn default() -> DeviceStatus {
+        DeviceStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DeviceStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DeviceStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DeviceStatus {
+    fn from(value: &'a str) -> DeviceStatus {
+        match value {
+            "ACTIVE" => DeviceStatus::Active,
+            "SIGNED_OUT" => DeviceStatus::SignedOut,
+            _ => DeviceStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeviceStatus {
+    fn from(value: String) -> DeviceStatus {
+        match value.as_str() {
+            "ACTIVE" => DeviceStatus::Active,
+            "SIGNED_OUT" => DeviceStatus::SignedOut,
+            _ => DeviceStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DeviceStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DeviceStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DeviceStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DeviceStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeviceStatus {
+    fn deserialize<D>(deserializer: D) -> Result<DeviceStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The summary of devices.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -383,7 +594,7 @@ pub struct DeviceSummary {
     /// <p>The status of the device.</p>
     #[serde(rename = "DeviceStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub device_status: Option<String>,
+    pub device_status: Option<DeviceStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -431,13 +642,141 @@ pub struct DisassociateWebsiteCertificateAuthorityRequest {
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DisassociateWebsiteCertificateAuthorityResponse {}
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DomainStatus {
+    /// `PENDING_VALIDATION`
+    PendingValidation,
+    /// `ASSOCIATING`
+    Associating,
+    /// `ACTIVE`
+    Active,
+    /// `INACTIVE`
+    Inactive,
+    /// `DISASSOCIATING`
+    Disassociating,
+    /// `DISASSOCIATED`
+    Disassociated,
+    /// `FAILED_TO_ASSOCIATE`
+    FailedToAssociate,
+    /// `FAILED_TO_DISASSOCIATE`
+    FailedToDisassociate,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DomainStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DomainStatus::PendingValidation => "PENDING_VALIDATION",
+            DomainStatus::Associating => "ASSOCIATING",
+            DomainStatus::Active => "ACTIVE",
+            DomainStatus::Inactive => "INACTIVE",
+            DomainStatus::Disassociating => "DISASSOCIATING",
+            DomainStatus::Disassociated => "DISASSOCIATED",
+            DomainStatus::FailedToAssociate => "FAILED_TO_ASSOCIATE",
+            DomainStatus::FailedToDisassociate => "FAILED_TO_DISASSOCIATE",
+            DomainStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DomainStatus {
+    fn default() -> DomainStatus {
+        DomainStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DomainStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DomainStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DomainStatus {
+    fn from(value: &'a str) -> DomainStatus {
+        match value {
+            "PENDING_VALIDATION" => DomainStatus::PendingValidation,
+            "ASSOCIATING" => DomainStatus::Associating,
+            "ACTIVE" => DomainStatus::Active,
+            "INACTIVE" => DomainStatus::Inactive,
+            "DISASSOCIATING" => DomainStatus::Disassociating,
+            "DISASSOCIATED" => DomainStatus::Disassociated,
+            "FAILED_TO_ASSOCIATE" => DomainStatus::FailedToAssociate,
+            "FAILED_TO_DISASSOCIATE" => DomainStatus::FailedToDisassociate,
+            _ => DomainStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DomainStatus {
+    fn from(value: String) -> DomainStatus {
+        match value.as_str() {
+            "PENDING_VALIDATION" => DomainStatus::PendingValidation,
+            "ASSOCIATING" => DomainStatus::Associating,
+            "ACTIVE" => DomainStatus::Active,
+            "INACTIVE" => DomainStatus::Inactive,
+            "DISASSOCIATING" => DomainStatus::Disassociating,
+            "DISASSOCIATED" => DomainStatus::Disassociated,
+            "FAILED_TO_ASSOCIATE" => DomainStatus::FailedToAssociate,
+            "FAILED_TO_DISASSOCIATE" => DomainStatus::FailedToDisassociate,
+            _ => DomainStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DomainStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DomainStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DomainStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DomainStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DomainStatus {
+    fn deserialize<D>(deserializer: D) -> Result<DomainStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The summary of the domain.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DomainSummary {
     /// <p>The time that the domain was created.</p>
     #[serde(rename = "CreatedTime")]
-    pub created_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub created_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The name to display.</p>
     #[serde(rename = "DisplayName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -447,9 +786,122 @@ pub struct DomainSummary {
     pub domain_name: String,
     /// <p>The status of the domain.</p>
     #[serde(rename = "DomainStatus")]
-    pub domain_status: String,
+    pub domain_status: DomainStatus,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FleetStatus {
+    /// `CREATING`
+    Creating,
+    /// `ACTIVE`
+    Active,
+    /// `DELETING`
+    Deleting,
+    /// `DELETED`
+    Deleted,
+    /// `FAILED_TO_CREATE`
+    FailedToCreate,
+    /// `FAILED_TO_DELETE`
+    FailedToDelete,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl FleetStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FleetStatus::Creating => "CREATING",
+            FleetStatus::Active => "ACTIVE",
+            FleetStatus::Deleting => "DELETING",
+            FleetStatus::Deleted => "DELETED",
+            FleetStatus::FailedToCreate => "FAILED_TO_CREATE",
+            FleetStatus::FailedToDelete => "FAILED_TO_DELETE",
+            FleetStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for FleetStatus {
+    fn default() -> FleetStatus {
+        FleetStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for FleetStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FleetStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FleetStatus {
+    fn from(value: &'a str) -> FleetStatus {
+        match value {
+            "CREATING" => FleetStatus::Creating,
+            "ACTIVE" => FleetStatus::Active,
+            "DELETING" => FleetStatus::Deleting,
+            "DELETED" => FleetStatus::Deleted,
+            "FAILED_TO_CREATE" => FleetStatus::FailedToCreate,
+            "FAILED_TO_DELETE" => FleetStatus::FailedToDelete,
+            _ => FleetStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FleetStatus {
+    fn from(value: String) -> FleetStatus {
+        match value.as_str() {
+            "CREATING" => FleetStatus::Creating,
+            "ACTIVE" => FleetStatus::Active,
+            "DELETING" => FleetStatus::Deleting,
+            "DELETED" => FleetStatus::Deleted,
+            "FAILED_TO_CREATE" => FleetStatus::FailedToCreate,
+            "FAILED_TO_DELETE" => FleetStatus::FailedToDelete,
+            _ => FleetStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for FleetStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<FleetStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FleetStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FleetStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for FleetStatus {
+    fn deserialize<D>(deserializer: D) -> Result<FleetStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The summary of the fleet.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -460,8 +912,13 @@ pub struct FleetSummary {
     pub company_code: Option<String>,
     /// <p>The time when the fleet was created.</p>
     #[serde(rename = "CreatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time: Option<f64>,
+    pub created_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name to display.</p>
     #[serde(rename = "DisplayName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -477,13 +934,106 @@ pub struct FleetSummary {
     /// <p>The status of the fleet.</p>
     #[serde(rename = "FleetStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub fleet_status: Option<String>,
+    pub fleet_status: Option<FleetStatus>,
     /// <p>The time when the fleet was last updated.</p>
     #[serde(rename = "LastUpdatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_time: Option<f64>,
+    pub last_updated_time: Option<::chrono::DateTime<::chrono::Utc>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum IdentityProviderType {
+    /// `SAML`
+    Saml,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl IdentityProviderType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            IdentityProviderType::Saml => "SAML",
+            IdentityProviderType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for IdentityProviderType {
+    fn default() -> IdentityProviderType {
+        IdentityProviderType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for IdentityProviderType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for IdentityProviderType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
+impl<'a> From<&'a str> for IdentityProviderType {
+    fn from(value: &'a str) -> IdentityProviderType {
+        match value {
+            "SAML" => IdentityProviderType::Saml,
+            _ => IdentityProviderType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for IdentityProviderType {
+    fn from(value: String) -> IdentityProviderType {
+        match value.as_str() {
+            "SAML" => IdentityProviderType::Saml,
+            _ => IdentityProviderType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for IdentityProviderType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<IdentityProviderType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for IdentityProviderType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for IdentityProviderType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for IdentityProviderType {
+    fn deserialize<D>(deserializer: D) -> Result<IdentityProviderType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ListDevicesRequest {
@@ -775,7 +1325,7 @@ pub struct UpdateIdentityProviderConfigurationRequest {
     pub identity_provider_saml_metadata: Option<String>,
     /// <p>The type of identity provider.</p>
     #[serde(rename = "IdentityProviderType")]
-    pub identity_provider_type: String,
+    pub identity_provider_type: IdentityProviderType,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -792,11 +1342,16 @@ pub struct WebsiteAuthorizationProviderSummary {
     pub authorization_provider_id: Option<String>,
     /// <p>The authorization provider type.</p>
     #[serde(rename = "AuthorizationProviderType")]
-    pub authorization_provider_type: String,
+    pub authorization_provider_type: AuthorizationProviderType,
     /// <p>The time of creation.</p>
     #[serde(rename = "CreatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time: Option<f64>,
+    pub created_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The domain name of the authorization provider. This applies only to SAML-based authorization providers.</p>
     #[serde(rename = "DomainName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -809,8 +1364,13 @@ pub struct WebsiteAuthorizationProviderSummary {
 pub struct WebsiteCaSummary {
     /// <p>The time when the CA was added.</p>
     #[serde(rename = "CreatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time: Option<f64>,
+    pub created_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name to display.</p>
     #[serde(rename = "DisplayName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3055,11 +3615,12 @@ pub struct WorklinkClient {
 impl WorklinkClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> WorklinkClient {
         WorklinkClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "WorkLink"),
         }
     }
 
@@ -3093,6 +3654,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/associateDomain";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("AssociateDomain");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3126,6 +3688,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/associateWebsiteAuthorizationProvider";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("AssociateWebsiteAuthorizationProvider");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3161,6 +3724,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/associateWebsiteCertificateAuthority";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("AssociateWebsiteCertificateAuthority");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3193,6 +3757,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/createFleet";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("CreateFleet");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3223,6 +3788,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/deleteFleet";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("DeleteFleet");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3256,6 +3822,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/describeAuditStreamConfiguration";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("DescribeAuditStreamConfiguration");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3291,6 +3858,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/describeCompanyNetworkConfiguration";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("DescribeCompanyNetworkConfiguration");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3323,6 +3891,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/describeDevice";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("DescribeDevice");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3356,6 +3925,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/describeDevicePolicyConfiguration";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("DescribeDevicePolicyConfiguration");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3388,6 +3958,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/describeDomain";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("DescribeDomain");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3418,6 +3989,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/describeFleetMetadata";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("DescribeFleetMetadata");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3451,6 +4023,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/describeIdentityProviderConfiguration";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("DescribeIdentityProviderConfiguration");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3486,6 +4059,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/describeWebsiteCertificateAuthority";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("DescribeWebsiteCertificateAuthority");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3518,6 +4092,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/disassociateDomain";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("DisassociateDomain");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3551,6 +4126,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/disassociateWebsiteAuthorizationProvider";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("DisassociateWebsiteAuthorizationProvider");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3584,6 +4160,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/disassociateWebsiteCertificateAuthority";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("DisassociateWebsiteCertificateAuthority");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3616,6 +4193,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/listDevices";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("ListDevices");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3646,6 +4224,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/listDomains";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("ListDomains");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3676,6 +4255,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/listFleets";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("ListFleets");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3709,6 +4289,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/listWebsiteAuthorizationProviders";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("ListWebsiteAuthorizationProviders");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3744,6 +4325,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/listWebsiteCertificateAuthorities";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("ListWebsiteCertificateAuthorities");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3776,6 +4358,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/restoreDomainAccess";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("RestoreDomainAccess");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3806,6 +4389,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/revokeDomainAccess";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("RevokeDomainAccess");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3836,6 +4420,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/signOutUser";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("SignOutUser");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3869,6 +4454,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/updateAuditStreamConfiguration";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("UpdateAuditStreamConfiguration");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3902,6 +4488,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/updateCompanyNetworkConfiguration";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("UpdateCompanyNetworkConfiguration");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3937,6 +4524,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/updateDevicePolicyConfiguration";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("UpdateDevicePolicyConfiguration");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3969,6 +4557,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/updateDomainMetadata";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("UpdateDomainMetadata");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3999,6 +4588,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/UpdateFleetMetadata";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("UpdateFleetMetadata");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4032,6 +4622,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/updateIdentityProviderConfiguration";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("UpdateIdentityProviderConfiguration");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
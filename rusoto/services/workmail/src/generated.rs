@@ -126,7 +126,7 @@ pub struct CreateResourceRequest {
     pub organization_id: String,
     /// <p>The type of the new resource. The available types are <code>equipment</code> and <code>room</code>.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: ResourceType,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -173,7 +173,7 @@ pub struct Delegate {
     pub id: String,
     /// <p>The type of the delegate: user or group.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: MemberType,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -288,16 +288,26 @@ pub struct DescribeGroupRequest {
 pub struct DescribeGroupResponse {
     /// <p>The date and time when a user was deregistered from WorkMail, in UNIX epoch time format.</p>
     #[serde(rename = "DisabledDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub disabled_date: Option<f64>,
+    pub disabled_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The email of the described group.</p>
     #[serde(rename = "Email")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub email: Option<String>,
     /// <p>The date and time when a user was registered to WorkMail, in UNIX epoch time format.</p>
     #[serde(rename = "EnabledDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub enabled_date: Option<f64>,
+    pub enabled_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The identifier of the described group.</p>
     #[serde(rename = "GroupId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -309,7 +319,7 @@ pub struct DescribeGroupResponse {
     /// <p>The state of the user: enabled (registered to Amazon WorkMail) or disabled (deregistered or never registered to WorkMail).</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<EntityState>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -329,8 +339,13 @@ pub struct DescribeOrganizationResponse {
     pub alias: Option<String>,
     /// <p>The date at which the organization became usable in the WorkMail context, in UNIX epoch time format.</p>
     #[serde(rename = "CompletedDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub completed_date: Option<f64>,
+    pub completed_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The default mail domain associated with the organization.</p>
     #[serde(rename = "DefaultMailDomain")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -377,16 +392,26 @@ pub struct DescribeResourceResponse {
     pub booking_options: Option<BookingOptions>,
     /// <p>The date and time when a resource was disabled from WorkMail, in UNIX epoch time format.</p>
     #[serde(rename = "DisabledDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub disabled_date: Option<f64>,
+    pub disabled_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The email of the described resource.</p>
     #[serde(rename = "Email")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub email: Option<String>,
     /// <p>The date and time when a resource was enabled for WorkMail, in UNIX epoch time format.</p>
     #[serde(rename = "EnabledDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub enabled_date: Option<f64>,
+    pub enabled_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the described resource.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -398,11 +423,11 @@ pub struct DescribeResourceResponse {
     /// <p>The state of the resource: enabled (registered to Amazon WorkMail) or disabled (deregistered or never registered to WorkMail).</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<EntityState>,
     /// <p>The type of the described resource.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<ResourceType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -421,8 +446,13 @@ pub struct DescribeUserRequest {
 pub struct DescribeUserResponse {
     /// <p>The date and time at which the user was disabled for Amazon WorkMail usage, in UNIX epoch time format.</p>
     #[serde(rename = "DisabledDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub disabled_date: Option<f64>,
+    pub disabled_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The display name of the user.</p>
     #[serde(rename = "DisplayName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -433,8 +463,13 @@ pub struct DescribeUserResponse {
     pub email: Option<String>,
     /// <p>The date and time at which the user was enabled for Amazon WorkMail usage, in UNIX epoch time format.</p>
     #[serde(rename = "EnabledDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub enabled_date: Option<f64>,
+    pub enabled_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name for the user.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -442,7 +477,7 @@ pub struct DescribeUserResponse {
     /// <p>The state of a user: enabled (registered to Amazon WorkMail) or disabled (deregistered or never registered to WorkMail).</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<EntityState>,
     /// <p>The identifier for the described user.</p>
     #[serde(rename = "UserId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -450,7 +485,7 @@ pub struct DescribeUserResponse {
     /// <p>In certain cases, other entities are modeled as users. If interoperability is enabled, resources are imported into Amazon WorkMail as users. Because different WorkMail organizations rely on different directory types, administrators can distinguish between an unregistered user (account is disabled and has a user role) and the directory administrators. The values are USER, RESOURCE, and SYSTEM_USER.</p>
     #[serde(rename = "UserRole")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub user_role: Option<String>,
+    pub user_role: Option<UserRole>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -489,6 +524,104 @@ pub struct DisassociateMemberFromGroupRequest {
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DisassociateMemberFromGroupResponse {}
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EntityState {
+    /// `ENABLED`
+    Enabled,
+    /// `DISABLED`
+    Disabled,
+    /// `DELETED`
+    Deleted,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EntityState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EntityState::Enabled => "ENABLED",
+            EntityState::Disabled => "DISABLED",
+            EntityState::Deleted => "DELETED",
+            EntityState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EntityState {
+    fn default() -> EntityState {
+        EntityState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EntityState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EntityState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EntityState {
+    fn from(value: &'a str) -> EntityState {
+        match value {
+            "ENABLED" => EntityState::Enabled,
+            "DISABLED" => EntityState::Disabled,
+            "DELETED" => EntityState::Deleted,
+            _ => EntityState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EntityState {
+    fn from(value: String) -> EntityState {
+        match value.as_str() {
+            "ENABLED" => EntityState::Enabled,
+            "DISABLED" => EntityState::Disabled,
+            "DELETED" => EntityState::Deleted,
+            _ => EntityState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EntityState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EntityState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EntityState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EntityState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EntityState {
+    fn deserialize<D>(deserializer: D) -> Result<EntityState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct GetMailboxDetailsRequest {
@@ -519,16 +652,26 @@ pub struct GetMailboxDetailsResponse {
 pub struct Group {
     /// <p>The date indicating when the group was disabled from Amazon WorkMail use.</p>
     #[serde(rename = "DisabledDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub disabled_date: Option<f64>,
+    pub disabled_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The email of the group.</p>
     #[serde(rename = "Email")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub email: Option<String>,
     /// <p>The date indicating when the group was enabled for Amazon WorkMail use.</p>
     #[serde(rename = "EnabledDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub enabled_date: Option<f64>,
+    pub enabled_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The identifier of the group.</p>
     #[serde(rename = "Id")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -540,7 +683,7 @@ pub struct Group {
     /// <p>The state of the group, which can be ENABLED, DISABLED, or DELETED.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<EntityState>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -790,12 +933,22 @@ pub struct ListUsersResponse {
 pub struct Member {
     /// <p>The date indicating when the member was disabled from Amazon WorkMail use.</p>
     #[serde(rename = "DisabledDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub disabled_date: Option<f64>,
+    pub disabled_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The date indicating when the member was enabled for Amazon WorkMail use.</p>
     #[serde(rename = "EnabledDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub enabled_date: Option<f64>,
+    pub enabled_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The identifier of the member.</p>
     #[serde(rename = "Id")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -807,13 +960,106 @@ pub struct Member {
     /// <p>The state of the member, which can be ENABLED, DISABLED, or DELETED.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<EntityState>,
     /// <p>A member can be a user or group.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<MemberType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MemberType {
+    /// `GROUP`
+    Group,
+    /// `USER`
+    User,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl MemberType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MemberType::Group => "GROUP",
+            MemberType::User => "USER",
+            MemberType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for MemberType {
+    fn default() -> MemberType {
+        MemberType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for MemberType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for MemberType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for MemberType {
+    fn from(value: &'a str) -> MemberType {
+        match value {
+            "GROUP" => MemberType::Group,
+            "USER" => MemberType::User,
+            _ => MemberType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MemberType {
+    fn from(value: String) -> MemberType {
+        match value.as_str() {
+            "GROUP" => MemberType::Group,
+            "USER" => MemberType::User,
+            _ => MemberType::Unknown(value),
+        }
+    }
 }
 
+impl ::std::str::FromStr for MemberType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<MemberType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MemberType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for MemberType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MemberType {
+    fn deserialize<D>(deserializer: D) -> Result<MemberType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The representation of an organization.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -845,12 +1091,110 @@ pub struct Permission {
     pub grantee_id: String,
     /// <p>The type of user, group, or resource referred to in GranteeId.</p>
     #[serde(rename = "GranteeType")]
-    pub grantee_type: String,
+    pub grantee_type: MemberType,
     /// <p>The permissions granted to the grantee. SEND_AS allows the grantee to send email as the owner of the mailbox (the grantee is not mentioned on these emails). SEND_ON_BEHALF allows the grantee to send email on behalf of the owner of the mailbox (the grantee is not mentioned as the physical sender of these emails). FULL_ACCESS allows the grantee full access to the mailbox, irrespective of other folder-level permissions set on the mailbox.</p>
     #[serde(rename = "PermissionValues")]
-    pub permission_values: Vec<String>,
+    pub permission_values: Vec<PermissionType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PermissionType {
+    /// `FULL_ACCESS`
+    FullAccess,
+    /// `SEND_AS`
+    SendAs,
+    /// `SEND_ON_BEHALF`
+    SendOnBehalf,
+    /// A value not known to this version of the crate
+    Unknown(String),
 }
 
+impl PermissionType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PermissionType::FullAccess => "FULL_ACCESS",
+            PermissionType::SendAs => "SEND_AS",
+            PermissionType::SendOnBehalf => "SEND_ON_BEHALF",
+            PermissionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for PermissionType {
+    fn default() -> PermissionType {
+        PermissionType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for PermissionType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PermissionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for PermissionType {
+    fn from(value: &'a str) -> PermissionType {
+        match value {
+            "FULL_ACCESS" => PermissionType::FullAccess,
+            "SEND_AS" => PermissionType::SendAs,
+            "SEND_ON_BEHALF" => PermissionType::SendOnBehalf,
+            _ => PermissionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PermissionType {
+    fn from(value: String) -> PermissionType {
+        match value.as_str() {
+            "FULL_ACCESS" => PermissionType::FullAccess,
+            "SEND_AS" => PermissionType::SendAs,
+            "SEND_ON_BEHALF" => PermissionType::SendOnBehalf,
+            _ => PermissionType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for PermissionType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<PermissionType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PermissionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PermissionType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PermissionType {
+    fn deserialize<D>(deserializer: D) -> Result<PermissionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct PutMailboxPermissionsRequest {
@@ -865,7 +1209,7 @@ pub struct PutMailboxPermissionsRequest {
     pub organization_id: String,
     /// <p>The permissions granted to the grantee. SEND_AS allows the grantee to send email as the owner of the mailbox (the grantee is not mentioned on these emails). SEND_ON_BEHALF allows the grantee to send email on behalf of the owner of the mailbox (the grantee is not mentioned as the physical sender of these emails). FULL_ACCESS allows the grantee full access to the mailbox, irrespective of other folder-level permissions set on the mailbox.</p>
     #[serde(rename = "PermissionValues")]
-    pub permission_values: Vec<String>,
+    pub permission_values: Vec<PermissionType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -914,16 +1258,26 @@ pub struct ResetPasswordResponse {}
 pub struct Resource {
     /// <p>The date indicating when the resource was disabled from Amazon WorkMail use.</p>
     #[serde(rename = "DisabledDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub disabled_date: Option<f64>,
+    pub disabled_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The email of the resource.</p>
     #[serde(rename = "Email")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub email: Option<String>,
     /// <p>The date indicating when the resource was enabled for Amazon WorkMail use.</p>
     #[serde(rename = "EnabledDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub enabled_date: Option<f64>,
+    pub enabled_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The identifier of the resource.</p>
     #[serde(rename = "Id")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -935,13 +1289,106 @@ pub struct Resource {
     /// <p>The state of the resource, which can be ENABLED, DISABLED, or DELETED.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<EntityState>,
     /// <p>The type of the resource: equipment or room.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<ResourceType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ResourceType {
+    /// `ROOM`
+    Room,
+    /// `EQUIPMENT`
+    Equipment,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ResourceType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceType::Room => "ROOM",
+            ResourceType::Equipment => "EQUIPMENT",
+            ResourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ResourceType {
+    fn default() -> ResourceType {
+        ResourceType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ResourceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ResourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ResourceType {
+    fn from(value: &'a str) -> ResourceType {
+        match value {
+            "ROOM" => ResourceType::Room,
+            "EQUIPMENT" => ResourceType::Equipment,
+            _ => ResourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceType {
+    fn from(value: String) -> ResourceType {
+        match value.as_str() {
+            "ROOM" => ResourceType::Room,
+            "EQUIPMENT" => ResourceType::Equipment,
+            _ => ResourceType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ResourceType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ResourceType, Self::Err> {
+        Ok(value.into())
+    }
 }
 
+impl ::rusoto_core::param::ToParam for ResourceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ResourceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ResourceType {
+    fn deserialize<D>(deserializer: D) -> Result<ResourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct UpdateMailboxQuotaRequest {
@@ -1007,8 +1454,13 @@ pub struct UpdateResourceResponse {}
 pub struct User {
     /// <p>The date indicating when the user was disabled from Amazon WorkMail use.</p>
     #[serde(rename = "DisabledDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub disabled_date: Option<f64>,
+    pub disabled_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The display name of the user.</p>
     #[serde(rename = "DisplayName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1019,8 +1471,13 @@ pub struct User {
     pub email: Option<String>,
     /// <p>The date indicating when the user was enabled for Amazon WorkMail use.</p>
     #[serde(rename = "EnabledDate")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub enabled_date: Option<f64>,
+    pub enabled_date: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The identifier of the user.</p>
     #[serde(rename = "Id")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1032,13 +1489,116 @@ pub struct User {
     /// <p>The state of the user, which can be ENABLED, DISABLED, or DELETED.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<EntityState>,
     /// <p>The role of the user.</p>
     #[serde(rename = "UserRole")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub user_role: Option<String>,
+    pub user_role: Option<UserRole>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum UserRole {
+    /// `USER`
+    User,
+    /// `RESOURCE`
+    Resource,
+    /// `SYSTEM_USER`
+    SystemUser,
+    /// `REMOTE_USER`
+    RemoteUser,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl UserRole {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            UserRole::User => "USER",
+            UserRole::Resource => "RESOURCE",
+            UserRole::SystemUser => "SYSTEM_USER",
+            UserRole::RemoteUser => "REMOTE_USER",
+            UserRole::Unknown(ref value) => value,
+        }
+    }
 }
 
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for UserRole {
+    fn default() -> UserRole {
+        UserRole::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for UserRole {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for UserRole {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for UserRole {
+    fn from(value: &'a str) -> UserRole {
+        match value {
+            "USER" => UserRole::User,
+            "RESOURCE" => UserRole::Resource,
+            "SYSTEM_USER" => UserRole::SystemUser,
+            "REMOTE_USER" => UserRole::RemoteUser,
+            _ => UserRole::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for UserRole {
+    fn from(value: String) -> UserRole {
+        match value.as_str() {
+            "USER" => UserRole::User,
+            "RESOURCE" => UserRole::Resource,
+            "SYSTEM_USER" => UserRole::SystemUser,
+            "REMOTE_USER" => UserRole::RemoteUser,
+            _ => UserRole::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for UserRole {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<UserRole, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for UserRole {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for UserRole {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for UserRole {
+    fn deserialize<D>(deserializer: D) -> Result<UserRole, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// Errors returned by AssociateDelegateToResource
 #[derive(Debug, PartialEq)]
 pub enum AssociateDelegateToResourceError {
@@ -3391,11 +3951,12 @@ pub struct WorkmailClient {
 impl WorkmailClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> WorkmailClient {
         WorkmailClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "WorkMail"),
         }
     }
 
@@ -3428,6 +3989,7 @@ impl Workmail for WorkmailClient {
     ) -> Result<AssociateDelegateToResourceResponse, RusotoError<AssociateDelegateToResourceError>>
     {
         let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("AssociateDelegateToResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3459,6 +4021,7 @@ impl Workmail for WorkmailClient {
         input: AssociateMemberToGroupRequest,
     ) -> Result<AssociateMemberToGroupResponse, RusotoError<AssociateMemberToGroupError>> {
         let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("AssociateMemberToGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "WorkMailService.AssociateMemberToGroup");
@@ -3487,6 +4050,7 @@ impl Workmail for WorkmailClient {
         input: CreateAliasRequest,
     ) -> Result<CreateAliasResponse, RusotoError<CreateAliasError>> {
         let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("CreateAlias");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "WorkMailService.CreateAlias");
@@ -3514,6 +4078,7 @@ impl Workmail for WorkmailClient {
         input: CreateGroupRequest,
     ) -> Result<CreateGroupResponse, RusotoError<CreateGroupError>> {
         let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("CreateGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "WorkMailService.CreateGroup");
@@ -3541,6 +4106,7 @@ impl Workmail for WorkmailClient {
         input: CreateResourceRequest,
     ) -> Result<CreateResourceResponse, RusotoError<CreateResourceError>> {
         let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("CreateResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "WorkMailService.CreateResource");
@@ -3568,6 +4134,7 @@ impl Workmail for WorkmailClient {
         input: CreateUserRequest,
     ) -> Result<CreateUserResponse, RusotoError<CreateUserError>> {
         let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("CreateUser");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "WorkMailService.CreateUser");
@@ -3595,6 +4162,7 @@ impl Workmail for WorkmailClient {
         input: DeleteAliasRequest,
     ) -> Result<DeleteAliasResponse, RusotoError<DeleteAliasError>> {
         let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("DeleteAlias");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "WorkMailService.DeleteAlias");
@@ -3622,6 +4190,7 @@ impl Workmail for WorkmailClient {
         input: DeleteGroupRequest,
     ) -> Result<DeleteGroupResponse, RusotoError<DeleteGroupError>> {
         let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("DeleteGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "WorkMailService.DeleteGroup");
@@ -3649,6 +4218,7 @@ impl Workmail for WorkmailClient {
         input: DeleteMailboxPermissionsRequest,
     ) -> Result<DeleteMailboxPermissionsResponse, RusotoError<DeleteMailboxPermissionsError>> {
         let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("DeleteMailboxPermissions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "WorkMailService.DeleteMailboxPermissions");
@@ -3677,6 +4247,7 @@ impl Workmail for WorkmailClient {
         input: DeleteResourceRequest,
     ) -> Result<DeleteResourceResponse, RusotoError<DeleteResourceError>> {
         let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("DeleteResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "WorkMailService.DeleteResource");
@@ -3704,6 +4275,7 @@ impl Workmail for WorkmailClient {
         input: DeleteUserRequest,
     ) -> Result<DeleteUserResponse, RusotoError<DeleteUserError>> {
         let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("DeleteUser");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "WorkMailService.DeleteUser");
@@ -3731,6 +4303,7 @@ impl Workmail for WorkmailClient {
         input: DeregisterFromWorkMailRequest,
     ) -> Result<DeregisterFromWorkMailResponse, RusotoError<DeregisterFromWorkMailError>> {
         let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("DeregisterFromWorkMail");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "WorkMailService.DeregisterFromWorkMail");
@@ -3759,6 +4332,7 @@ impl Workmail for WorkmailClient {
         input: DescribeGroupRequest,
     ) -> Result<DescribeGroupResponse, RusotoError<DescribeGroupError>> {
         let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("DescribeGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "WorkMailService.DescribeGroup");
@@ -3786,6 +4360,7 @@ impl Workmail for WorkmailClient {
         input: DescribeOrganizationRequest,
     ) -> Result<DescribeOrganizationResponse, RusotoError<DescribeOrganizationError>> {
         let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("DescribeOrganization");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "WorkMailService.DescribeOrganization");
@@ -3814,6 +4389,7 @@ impl Workmail for WorkmailClient {
         input: DescribeResourceRequest,
     ) -> Result<DescribeResourceResponse, RusotoError<DescribeResourceError>> {
         let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("DescribeResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "WorkMailService.DescribeResource");
@@ -3842,6 +4418,7 @@ impl Workmail for WorkmailClient {
         input: DescribeUserRequest,
     ) -> Result<DescribeUserResponse, RusotoError<DescribeUserError>> {
         let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("DescribeUser");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "WorkMailService.DescribeUser");
@@ -3872,6 +4449,7 @@ impl Workmail for WorkmailClient {
         RusotoError<DisassociateDelegateFromResourceError>,
     > {
         let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("DisassociateDelegateFromResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3906,6 +4484,7 @@ impl Workmail for WorkmailClient {
     ) -> Result<DisassociateMemberFromGroupResponse, RusotoError<DisassociateMemberFromGroupError>>
     {
         let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("DisassociateMemberFromGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3937,6 +4516,7 @@ impl Workmail for WorkmailClient {
         input: GetMailboxDetailsRequest,
     ) -> Result<GetMailboxDetailsResponse, RusotoError<GetMailboxDetailsError>> {
         let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("GetMailboxDetails");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "WorkMailService.GetMailboxDetails");
@@ -3965,6 +4545,7 @@ impl Workmail for WorkmailClient {
         input: ListAliasesRequest,
     ) -> Result<ListAliasesResponse, RusotoError<ListAliasesError>> {
         let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("ListAliases");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "WorkMailService.ListAliases");
@@ -3992,6 +4573,7 @@ impl Workmail for WorkmailClient {
         input: ListGroupMembersRequest,
     ) -> Result<ListGroupMembersResponse, RusotoError<ListGroupMembersError>> {
         let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("ListGroupMembers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "WorkMailService.ListGroupMembers");
@@ -4020,6 +4602,7 @@ impl Workmail for WorkmailClient {
         input: ListGroupsRequest,
     ) -> Result<ListGroupsResponse, RusotoError<ListGroupsError>> {
         let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("ListGroups");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "WorkMailService.ListGroups");
@@ -4047,6 +4630,7 @@ impl Workmail for WorkmailClient {
         input: ListMailboxPermissionsRequest,
     ) -> Result<ListMailboxPermissionsResponse, RusotoError<ListMailboxPermissionsError>> {
         let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("ListMailboxPermissions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "WorkMailService.ListMailboxPermissions");
@@ -4075,6 +4659,7 @@ impl Workmail for WorkmailClient {
         input: ListOrganizationsRequest,
     ) -> Result<ListOrganizationsResponse, RusotoError<ListOrganizationsError>> {
         let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("ListOrganizations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "WorkMailService.ListOrganizations");
@@ -4103,6 +4688,7 @@ impl Workmail for WorkmailClient {
         input: ListResourceDelegatesRequest,
     ) -> Result<ListResourceDelegatesResponse, RusotoError<ListResourceDelegatesError>> {
         let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("ListResourceDelegates");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "WorkMailService.ListResourceDelegates");
@@ -4131,6 +4717,7 @@ impl Workmail for WorkmailClient {
         input: ListResourcesRequest,
     ) -> Result<ListResourcesResponse, RusotoError<ListResourcesError>> {
         let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("ListResources");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "WorkMailService.ListResources");
@@ -4158,6 +4745,7 @@ impl Workmail for WorkmailClient {
         input: ListUsersRequest,
     ) -> Result<ListUsersResponse, RusotoError<ListUsersError>> {
         let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("ListUsers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "WorkMailService.ListUsers");
@@ -4185,6 +4773,7 @@ impl Workmail for WorkmailClient {
         input: PutMailboxPermissionsRequest,
     ) -> Result<PutMailboxPermissionsResponse, RusotoError<PutMailboxPermissionsError>> {
         let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("PutMailboxPermissions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "WorkMailService.PutMailboxPermissions");
@@ -4213,6 +4802,7 @@ impl Workmail for WorkmailClient {
         input: RegisterToWorkMailRequest,
     ) -> Result<RegisterToWorkMailResponse, RusotoError<RegisterToWorkMailError>> {
         let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("RegisterToWorkMail");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "WorkMailService.RegisterToWorkMail");
@@ -4241,6 +4831,7 @@ impl Workmail for WorkmailClient {
         input: ResetPasswordRequest,
     ) -> Result<ResetPasswordResponse, RusotoError<ResetPasswordError>> {
         let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("ResetPassword");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "WorkMailService.ResetPassword");
@@ -4268,6 +4859,7 @@ impl Workmail for WorkmailClient {
         input: UpdateMailboxQuotaRequest,
     ) -> Result<UpdateMailboxQuotaResponse, RusotoError<UpdateMailboxQuotaError>> {
         let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("UpdateMailboxQuota");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "WorkMailService.UpdateMailboxQuota");
@@ -4297,6 +4889,7 @@ impl Workmail for WorkmailClient {
     ) -> Result<UpdatePrimaryEmailAddressResponse, RusotoError<UpdatePrimaryEmailAddressError>>
     {
         let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("UpdatePrimaryEmailAddress");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "WorkMailService.UpdatePrimaryEmailAddress");
@@ -4325,6 +4918,7 @@ impl Workmail for WorkmailClient {
         input: UpdateResourceRequest,
     ) -> Result<UpdateResourceResponse, RusotoError<UpdateResourceError>> {
         let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("UpdateResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "WorkMailService.UpdateResource");
@@ -4346,3 +4940,260 @@ impl Workmail for WorkmailClient {
         }
     }
 }
+impl WorkmailClient {
+    /// Auto-paginating version of `list_aliases`, streaming every page of results.
+    pub fn list_aliases_pages(
+        &self,
+        input: ListAliasesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListAliasesResponse, ListAliasesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: WorkmailClient, input: ListAliasesRequest| async move {
+                client.list_aliases(input).await
+            },
+            |input: &ListAliasesRequest, output: &ListAliasesResponse| {
+                Some(ListAliasesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_aliases`, streaming the `Aliases` of every page of results.
+    pub fn list_aliases_items(
+        &self,
+        input: ListAliasesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListAliasesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_aliases_pages(input),
+            |output: ListAliasesResponse| output.aliases.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_group_members`, streaming every page of results.
+    pub fn list_group_members_pages(
+        &self,
+        input: ListGroupMembersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListGroupMembersResponse, ListGroupMembersError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: WorkmailClient, input: ListGroupMembersRequest| async move {
+                client.list_group_members(input).await
+            },
+            |input: &ListGroupMembersRequest, output: &ListGroupMembersResponse| {
+                Some(ListGroupMembersRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_group_members`, streaming the `Members` of every page of results.
+    pub fn list_group_members_items(
+        &self,
+        input: ListGroupMembersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Member, ListGroupMembersError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_group_members_pages(input),
+            |output: ListGroupMembersResponse| output.members.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_groups`, streaming every page of results.
+    pub fn list_groups_pages(
+        &self,
+        input: ListGroupsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListGroupsResponse, ListGroupsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: WorkmailClient, input: ListGroupsRequest| async move {
+                client.list_groups(input).await
+            },
+            |input: &ListGroupsRequest, output: &ListGroupsResponse| {
+                Some(ListGroupsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_groups`, streaming the `Groups` of every page of results.
+    pub fn list_groups_items(
+        &self,
+        input: ListGroupsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Group, ListGroupsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_groups_pages(input),
+            |output: ListGroupsResponse| output.groups.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_mailbox_permissions`, streaming every page of results.
+    pub fn list_mailbox_permissions_pages(
+        &self,
+        input: ListMailboxPermissionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListMailboxPermissionsResponse,
+        ListMailboxPermissionsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: WorkmailClient, input: ListMailboxPermissionsRequest| async move {
+                client.list_mailbox_permissions(input).await
+            },
+            |input: &ListMailboxPermissionsRequest, output: &ListMailboxPermissionsResponse| {
+                Some(ListMailboxPermissionsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_mailbox_permissions`, streaming the `Permissions` of every page of results.
+    pub fn list_mailbox_permissions_items(
+        &self,
+        input: ListMailboxPermissionsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Permission, ListMailboxPermissionsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_mailbox_permissions_pages(input),
+            |output: ListMailboxPermissionsResponse| output.permissions.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_organizations`, streaming every page of results.
+    pub fn list_organizations_pages(
+        &self,
+        input: ListOrganizationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListOrganizationsResponse, ListOrganizationsError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: WorkmailClient, input: ListOrganizationsRequest| async move {
+                client.list_organizations(input).await
+            },
+            |input: &ListOrganizationsRequest, output: &ListOrganizationsResponse| {
+                Some(ListOrganizationsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_organizations`, streaming the `OrganizationSummaries` of every page of results.
+    pub fn list_organizations_items(
+        &self,
+        input: ListOrganizationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<OrganizationSummary, ListOrganizationsError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_organizations_pages(input),
+            |output: ListOrganizationsResponse| output.organization_summaries.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_resource_delegates`, streaming every page of results.
+    pub fn list_resource_delegates_pages(
+        &self,
+        input: ListResourceDelegatesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListResourceDelegatesResponse,
+        ListResourceDelegatesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: WorkmailClient, input: ListResourceDelegatesRequest| async move {
+                client.list_resource_delegates(input).await
+            },
+            |input: &ListResourceDelegatesRequest, output: &ListResourceDelegatesResponse| {
+                Some(ListResourceDelegatesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_resource_delegates`, streaming the `Delegates` of every page of results.
+    pub fn list_resource_delegates_items(
+        &self,
+        input: ListResourceDelegatesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Delegate, ListResourceDelegatesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_resource_delegates_pages(input),
+            |output: ListResourceDelegatesResponse| output.delegates.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_resources`, streaming every page of results.
+    pub fn list_resources_pages(
+        &self,
+        input: ListResourcesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListResourcesResponse, ListResourcesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: WorkmailClient, input: ListResourcesRequest| async move {
+                client.list_resources(input).await
+            },
+            |input: &ListResourcesRequest, output: &ListResourcesResponse| {
+                Some(ListResourcesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_resources`, streaming the `Resources` of every page of results.
+    pub fn list_resources_items(
+        &self,
+        input: ListResourcesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Resource, ListResourcesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_resources_pages(input),
+            |output: ListResourcesResponse| output.resources.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `list_users`, streaming every page of results.
+    pub fn list_users_pages(
+        &self,
+        input: ListUsersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<ListUsersResponse, ListUsersError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: WorkmailClient, input: ListUsersRequest| async move {
+                client.list_users(input).await
+            },
+            |input: &ListUsersRequest, output: &ListUsersResponse| {
+                Some(ListUsersRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_users`, streaming the `Users` of every page of results.
+    pub fn list_users_items(
+        &self,
+        input: ListUsersRequest,
+    ) -> rusoto_core::pagination::RusotoStream<User, ListUsersError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_users_pages(input),
+            |output: ListUsersResponse| output.users.unwrap_or_default(),
+        )
+    }
+}
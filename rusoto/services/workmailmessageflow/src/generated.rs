@@ -89,11 +89,12 @@ pub struct WorkmailMessageFlowClient {
 impl WorkmailMessageFlowClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> WorkmailMessageFlowClient {
         WorkmailMessageFlowClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "WorkMailMessageFlow"),
         }
     }
 
@@ -128,6 +129,7 @@ impl WorkmailMessageFlow for WorkmailMessageFlowClient {
 
         let mut request =
             SignedRequest::new("GET", "workmailmessageflow", &self.region, &request_uri);
+        request.set_operation("GetRawMessageContent");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
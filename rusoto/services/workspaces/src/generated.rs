@@ -24,6 +24,99 @@ use rusoto_core::signature::SignedRequest;
 #[allow(unused_imports)]
 use serde::{Deserialize, Serialize};
 use serde_json;
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AccessPropertyValue {
+    /// `ALLOW`
+    Allow,
+    /// `DENY`
+    Deny,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl AccessPropertyValue {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AccessPropertyValue::Allow => "ALLOW",
+            AccessPropertyValue::Deny => "DENY",
+            AccessPropertyValue::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for AccessPropertyValue {
+    fn default() -> AccessPropertyValue {
+        AccessPropertyValue::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for AccessPropertyValue {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AccessPropertyValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for AccessPropertyValue {
+    fn from(value: &'a str) -> AccessPropertyValue {
+        match value {
+            "ALLOW" => AccessPropertyValue::Allow,
+            "DENY" => AccessPropertyValue::Deny,
+            _ => AccessPropertyValue::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AccessPropertyValue {
+    fn from(value: String) -> AccessPropertyValue {
+        match value.as_str() {
+            "ALLOW" => AccessPropertyValue::Allow,
+            "DENY" => AccessPropertyValue::Deny,
+            _ => AccessPropertyValue::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for AccessPropertyValue {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<AccessPropertyValue, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AccessPropertyValue {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AccessPropertyValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AccessPropertyValue {
+    fn deserialize<D>(deserializer: D) -> Result<AccessPropertyValue, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes a modification to the configuration of Bring Your Own License (BYOL) for the specified account. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -35,7 +128,7 @@ pub struct AccountModification {
     /// <p>The status of BYOL (whether BYOL is being enabled or disabled).</p>
     #[serde(rename = "DedicatedTenancySupport")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub dedicated_tenancy_support: Option<String>,
+    pub dedicated_tenancy_support: Option<DedicatedTenancySupportResultEnum>,
     /// <p>The error code that is returned if the configuration of BYOL cannot be modified.</p>
     #[serde(rename = "ErrorCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -47,11 +140,16 @@ pub struct AccountModification {
     /// <p>The state of the modification to the configuration of BYOL.</p>
     #[serde(rename = "ModificationState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub modification_state: Option<String>,
+    pub modification_state: Option<DedicatedTenancyModificationStateEnum>,
     /// <p>The timestamp when the modification of the BYOL configuration was started.</p>
     #[serde(rename = "StartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -90,7 +188,7 @@ pub struct ClientProperties {
     /// <p>Specifies whether users can cache their credentials on the Amazon WorkSpaces client. When enabled, users can choose to reconnect to their WorkSpaces without re-entering their credentials. </p>
     #[serde(rename = "ReconnectEnabled")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub reconnect_enabled: Option<String>,
+    pub reconnect_enabled: Option<ReconnectEnum>,
 }
 
 /// <p>Information about the Amazon WorkSpaces client.</p>
@@ -107,6 +205,134 @@ pub struct ClientPropertiesResult {
     pub resource_id: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Compute {
+    /// `VALUE`
+    Value,
+    /// `STANDARD`
+    Standard,
+    /// `PERFORMANCE`
+    Performance,
+    /// `POWER`
+    Power,
+    /// `GRAPHICS`
+    Graphics,
+    /// `POWERPRO`
+    Powerpro,
+    /// `GRAPHICSPRO`
+    Graphicspro,
+    /// `GRAPHICS_G4DN`
+    GraphicsG4dn,
+    /// `GRAPHICSPRO_G4DN`
+    GraphicsproG4dn,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Compute {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Compute::Value => "VALUE",
+            Compute::Standard => "STANDARD",
+            Compute::Performance => "PERFORMANCE",
+            Compute::Power => "POWER",
+            Compute::Graphics => "GRAPHICS",
+            Compute::Powerpro => "POWERPRO",
+            Compute::Graphicspro => "GRAPHICSPRO",
+            Compute::GraphicsG4dn => "GRAPHICS_G4DN",
+            Compute::GraphicsproG4dn => "GRAPHICSPRO_G4DN",
+            Compute::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Compute {
+    fn default() -> Compute {
+        Compute::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Compute {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Compute {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Compute {
+    fn from(value: &'a str) -> Compute {
+        match value {
+            "VALUE" => Compute::Value,
+            "STANDARD" => Compute::Standard,
+            "PERFORMANCE" => Compute::Performance,
+            "POWER" => Compute::Power,
+            "GRAPHICS" => Compute::Graphics,
+            "POWERPRO" => Compute::Powerpro,
+            "GRAPHICSPRO" => Compute::Graphicspro,
+            "GRAPHICS_G4DN" => Compute::GraphicsG4dn,
+            "GRAPHICSPRO_G4DN" => Compute::GraphicsproG4dn,
+            _ => Compute::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Compute {
+    fn from(value: String) -> Compute {
+        match value.as_str() {
+            "VALUE" => Compute::Value,
+            "STANDARD" => Compute::Standard,
+            "PERFORMANCE" => Compute::Performance,
+            "POWER" => Compute::Power,
+            "GRAPHICS" => Compute::Graphics,
+            "POWERPRO" => Compute::Powerpro,
+            "GRAPHICSPRO" => Compute::Graphicspro,
+            "GRAPHICS_G4DN" => Compute::GraphicsG4dn,
+            "GRAPHICSPRO_G4DN" => Compute::GraphicsproG4dn,
+            _ => Compute::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Compute {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Compute, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Compute {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Compute {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Compute {
+    fn deserialize<D>(deserializer: D) -> Result<Compute, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes the compute type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -114,9 +340,107 @@ pub struct ComputeType {
     /// <p>The compute type.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
+    pub name: Option<Compute>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ConnectionState {
+    /// `CONNECTED`
+    Connected,
+    /// `DISCONNECTED`
+    Disconnected,
+    /// `UNKNOWN`
+    UnknownValue,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ConnectionState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ConnectionState::Connected => "CONNECTED",
+            ConnectionState::Disconnected => "DISCONNECTED",
+            ConnectionState::UnknownValue => "UNKNOWN",
+            ConnectionState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ConnectionState {
+    fn default() -> ConnectionState {
+        ConnectionState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ConnectionState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ConnectionState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
+impl<'a> From<&'a str> for ConnectionState {
+    fn from(value: &'a str) -> ConnectionState {
+        match value {
+            "CONNECTED" => ConnectionState::Connected,
+            "DISCONNECTED" => ConnectionState::Disconnected,
+            "UNKNOWN" => ConnectionState::UnknownValue,
+            _ => ConnectionState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ConnectionState {
+    fn from(value: String) -> ConnectionState {
+        match value.as_str() {
+            "CONNECTED" => ConnectionState::Connected,
+            "DISCONNECTED" => ConnectionState::Disconnected,
+            "UNKNOWN" => ConnectionState::UnknownValue,
+            _ => ConnectionState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ConnectionState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ConnectionState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ConnectionState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ConnectionState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ConnectionState {
+    fn deserialize<D>(deserializer: D) -> Result<ConnectionState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CopyWorkspaceImageRequest {
@@ -213,6 +537,285 @@ pub struct CreateWorkspacesResult {
     pub pending_requests: Option<Vec<Workspace>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DedicatedTenancyModificationStateEnum {
+    /// `PENDING`
+    Pending,
+    /// `COMPLETED`
+    Completed,
+    /// `FAILED`
+    Failed,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DedicatedTenancyModificationStateEnum {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DedicatedTenancyModificationStateEnum::Pending => "PENDING",
+            DedicatedTenancyModificationStateEnum::Completed => "COMPLETED",
+            DedicatedTenancyModificationStateEnum::Failed => "FAILED",
+            DedicatedTenancyModificationStateEnum::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DedicatedTenancyModificationStateEnum {
+    fn default() -> DedicatedTenancyModificationStateEnum {
+        DedicatedTenancyModificationStateEnum::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DedicatedTenancyModificationStateEnum {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DedicatedTenancyModificationStateEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DedicatedTenancyModificationStateEnum {
+    fn from(value: &'a str) -> DedicatedTenancyModificationStateEnum {
+        match value {
+            "PENDING" => DedicatedTenancyModificationStateEnum::Pending,
+            "COMPLETED" => DedicatedTenancyModificationStateEnum::Completed,
+            "FAILED" => DedicatedTenancyModificationStateEnum::Failed,
+            _ => DedicatedTenancyModificationStateEnum::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DedicatedTenancyModificationStateEnum {
+    fn from(value: String) -> DedicatedTenancyModificationStateEnum {
+        match value.as_str() {
+            "PENDING" => DedicatedTenancyModificationStateEnum::Pending,
+            "COMPLETED" => DedicatedTenancyModificationStateEnum::Completed,
+            "FAILED" => DedicatedTenancyModificationStateEnum::Failed,
+            _ => DedicatedTenancyModificationStateEnum::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DedicatedTenancyModificationStateEnum {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DedicatedTenancyModificationStateEnum, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DedicatedTenancyModificationStateEnum {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DedicatedTenancyModificationStateEnum {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DedicatedTenancyModificationStateEnum {
+    fn deserialize<D>(deserializer: D) -> Result<DedicatedTenancyModificationStateEnum, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DedicatedTenancySupportEnum {
+    /// `ENABLED`
+    Enabled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DedicatedTenancySupportEnum {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DedicatedTenancySupportEnum::Enabled => "ENABLED",
+            DedicatedTenancySupportEnum::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DedicatedTenancySupportEnum {
+    fn default() -> DedicatedTenancySupportEnum {
+        DedicatedTenancySupportEnum::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DedicatedTenancySupportEnum {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DedicatedTenancySupportEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DedicatedTenancySupportEnum {
+    fn from(value: &'a str) -> DedicatedTenancySupportEnum {
+        match value {
+            "ENABLED" => DedicatedTenancySupportEnum::Enabled,
+            _ => DedicatedTenancySupportEnum::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DedicatedTenancySupportEnum {
+    fn from(value: String) -> DedicatedTenancySupportEnum {
+        match value.as_str() {
+            "ENABLED" => DedicatedTenancySupportEnum::Enabled,
+            _ => DedicatedTenancySupportEnum::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DedicatedTenancySupportEnum {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DedicatedTenancySupportEnum, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DedicatedTenancySupportEnum {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DedicatedTenancySupportEnum {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DedicatedTenancySupportEnum {
+    fn deserialize<D>(deserializer: D) -> Result<DedicatedTenancySupportEnum, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DedicatedTenancySupportResultEnum {
+    /// `ENABLED`
+    Enabled,
+    /// `DISABLED`
+    Disabled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl DedicatedTenancySupportResultEnum {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DedicatedTenancySupportResultEnum::Enabled => "ENABLED",
+            DedicatedTenancySupportResultEnum::Disabled => "DISABLED",
+            DedicatedTenancySupportResultEnum::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for DedicatedTenancySupportResultEnum {
+    fn default() -> DedicatedTenancySupportResultEnum {
+        DedicatedTenancySupportResultEnum::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for DedicatedTenancySupportResultEnum {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DedicatedTenancySupportResultEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for DedicatedTenancySupportResultEnum {
+    fn from(value: &'a str) -> DedicatedTenancySupportResultEnum {
+        match value {
+            "ENABLED" => DedicatedTenancySupportResultEnum::Enabled,
+            "DISABLED" => DedicatedTenancySupportResultEnum::Disabled,
+            _ => DedicatedTenancySupportResultEnum::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DedicatedTenancySupportResultEnum {
+    fn from(value: String) -> DedicatedTenancySupportResultEnum {
+        match value.as_str() {
+            "ENABLED" => DedicatedTenancySupportResultEnum::Enabled,
+            "DISABLED" => DedicatedTenancySupportResultEnum::Disabled,
+            _ => DedicatedTenancySupportResultEnum::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for DedicatedTenancySupportResultEnum {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<DedicatedTenancySupportResultEnum, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DedicatedTenancySupportResultEnum {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DedicatedTenancySupportResultEnum {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DedicatedTenancySupportResultEnum {
+    fn deserialize<D>(deserializer: D) -> Result<DedicatedTenancySupportResultEnum, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes the default values that are used to create WorkSpaces. For more information, see <a href="https://docs.aws.amazon.com/workspaces/latest/adminguide/update-directory-details.html">Update Directory Details for Your WorkSpaces</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -330,7 +933,7 @@ pub struct DescribeAccountResult {
     /// <p>The status of BYOL (whether BYOL is enabled or disabled).</p>
     #[serde(rename = "DedicatedTenancySupport")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub dedicated_tenancy_support: Option<String>,
+    pub dedicated_tenancy_support: Option<DedicatedTenancySupportResultEnum>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -641,7 +1244,7 @@ pub struct ImportWorkspaceImageRequest {
     pub image_name: String,
     /// <p>The ingestion process to be used when importing the image.</p>
     #[serde(rename = "IngestionProcess")]
-    pub ingestion_process: String,
+    pub ingestion_process: WorkspaceImageIngestionProcess,
     /// <p>The tags. Each WorkSpaces resource can have a maximum of 50 tags.</p>
     #[serde(rename = "Tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -723,23 +1326,214 @@ pub struct MigrateWorkspaceResult {
     pub target_workspace_id: Option<String>,
 }
 
-/// <p>Describes a WorkSpace modification.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
-pub struct ModificationState {
-    /// <p>The resource.</p>
-    #[serde(rename = "Resource")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource: Option<String>,
-    /// <p>The modification state.</p>
-    #[serde(rename = "State")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ModificationResourceEnum {
+    /// `ROOT_VOLUME`
+    RootVolume,
+    /// `USER_VOLUME`
+    UserVolume,
+    /// `COMPUTE_TYPE`
+    ComputeType,
+    /// A value not known to this version of the crate
+    Unknown(String),
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
-pub struct ModifyAccountRequest {
+impl ModificationResourceEnum {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ModificationResourceEnum::RootVolume => "ROOT_VOLUME",
+            ModificationResourceEnum::UserVolume => "USER_VOLUME",
+            ModificationResourceEnum::ComputeType => "COMPUTE_TYPE",
+            ModificationResourceEnum::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ModificationResourceEnum {
+    fn default() -> ModificationResourceEnum {
+        ModificationResourceEnum::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ModificationResourceEnum {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ModificationResourceEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ModificationResourceEnum {
+    fn from(value: &'a str) -> ModificationResourceEnum {
+        match value {
+            "ROOT_VOLUME" => ModificationResourceEnum::RootVolume,
+            "USER_VOLUME" => ModificationResourceEnum::UserVolume,
+            "COMPUTE_TYPE" => ModificationResourceEnum::ComputeType,
+            _ => ModificationResourceEnum::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ModificationResourceEnum {
+    fn from(value: String) -> ModificationResourceEnum {
+        match value.as_str() {
+            "ROOT_VOLUME" => ModificationResourceEnum::RootVolume,
+            "USER_VOLUME" => ModificationResourceEnum::UserVolume,
+            "COMPUTE_TYPE" => ModificationResourceEnum::ComputeType,
+            _ => ModificationResourceEnum::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ModificationResourceEnum {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ModificationResourceEnum, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ModificationResourceEnum {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ModificationResourceEnum {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ModificationResourceEnum {
+    fn deserialize<D>(deserializer: D) -> Result<ModificationResourceEnum, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+/// <p>Describes a WorkSpace modification.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct ModificationState {
+    /// <p>The resource.</p>
+    #[serde(rename = "Resource")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource: Option<ModificationResourceEnum>,
+    /// <p>The modification state.</p>
+    #[serde(rename = "State")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<ModificationStateEnum>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ModificationStateEnum {
+    /// `UPDATE_INITIATED`
+    UpdateInitiated,
+    /// `UPDATE_IN_PROGRESS`
+    UpdateInProgress,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ModificationStateEnum {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ModificationStateEnum::UpdateInitiated => "UPDATE_INITIATED",
+            ModificationStateEnum::UpdateInProgress => "UPDATE_IN_PROGRESS",
+            ModificationStateEnum::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ModificationStateEnum {
+    fn default() -> ModificationStateEnum {
+        ModificationStateEnum::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ModificationStateEnum {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ModificationStateEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ModificationStateEnum {
+    fn from(value: &'a str) -> ModificationStateEnum {
+        match value {
+            "UPDATE_INITIATED" => ModificationStateEnum::UpdateInitiated,
+            "UPDATE_IN_PROGRESS" => ModificationStateEnum::UpdateInProgress,
+            _ => ModificationStateEnum::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ModificationStateEnum {
+    fn from(value: String) -> ModificationStateEnum {
+        match value.as_str() {
+            "UPDATE_INITIATED" => ModificationStateEnum::UpdateInitiated,
+            "UPDATE_IN_PROGRESS" => ModificationStateEnum::UpdateInProgress,
+            _ => ModificationStateEnum::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ModificationStateEnum {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ModificationStateEnum, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ModificationStateEnum {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ModificationStateEnum {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ModificationStateEnum {
+    fn deserialize<D>(deserializer: D) -> Result<ModificationStateEnum, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
+pub struct ModifyAccountRequest {
     /// <p>The IP address range, specified as an IPv4 CIDR block, for the management network interface. Specify an IP address range that is compatible with your network and in CIDR notation (that is, specify the range as an IPv4 CIDR block). The CIDR block size must be /16 (for example, 203.0.113.25/16). It must also be specified as available by the <code>ListAvailableManagementCidrRanges</code> operation.</p>
     #[serde(rename = "DedicatedTenancyManagementCidrRange")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -747,7 +1541,7 @@ pub struct ModifyAccountRequest {
     /// <p>The status of BYOL.</p>
     #[serde(rename = "DedicatedTenancySupport")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub dedicated_tenancy_support: Option<String>,
+    pub dedicated_tenancy_support: Option<DedicatedTenancySupportEnum>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -837,7 +1631,7 @@ pub struct ModifyWorkspaceStateRequest {
     pub workspace_id: String,
     /// <p>The WorkSpace state.</p>
     #[serde(rename = "WorkspaceState")]
-    pub workspace_state: String,
+    pub workspace_state: TargetWorkspaceState,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -851,9 +1645,102 @@ pub struct OperatingSystem {
     /// <p>The operating system.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<OperatingSystemType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OperatingSystemType {
+    /// `WINDOWS`
+    Windows,
+    /// `LINUX`
+    Linux,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl OperatingSystemType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OperatingSystemType::Windows => "WINDOWS",
+            OperatingSystemType::Linux => "LINUX",
+            OperatingSystemType::Unknown(ref value) => value,
+        }
+    }
 }
 
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for OperatingSystemType {
+    fn default() -> OperatingSystemType {
+        OperatingSystemType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for OperatingSystemType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for OperatingSystemType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for OperatingSystemType {
+    fn from(value: &'a str) -> OperatingSystemType {
+        match value {
+            "WINDOWS" => OperatingSystemType::Windows,
+            "LINUX" => OperatingSystemType::Linux,
+            _ => OperatingSystemType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OperatingSystemType {
+    fn from(value: String) -> OperatingSystemType {
+        match value.as_str() {
+            "WINDOWS" => OperatingSystemType::Windows,
+            "LINUX" => OperatingSystemType::Linux,
+            _ => OperatingSystemType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for OperatingSystemType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<OperatingSystemType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OperatingSystemType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for OperatingSystemType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OperatingSystemType {
+    fn deserialize<D>(deserializer: D) -> Result<OperatingSystemType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes the information used to reboot a WorkSpace.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -906,6 +1793,99 @@ pub struct RebuildWorkspacesResult {
     pub failed_requests: Option<Vec<FailedWorkspaceChangeRequest>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ReconnectEnum {
+    /// `ENABLED`
+    Enabled,
+    /// `DISABLED`
+    Disabled,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl ReconnectEnum {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReconnectEnum::Enabled => "ENABLED",
+            ReconnectEnum::Disabled => "DISABLED",
+            ReconnectEnum::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for ReconnectEnum {
+    fn default() -> ReconnectEnum {
+        ReconnectEnum::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for ReconnectEnum {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ReconnectEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ReconnectEnum {
+    fn from(value: &'a str) -> ReconnectEnum {
+        match value {
+            "ENABLED" => ReconnectEnum::Enabled,
+            "DISABLED" => ReconnectEnum::Disabled,
+            _ => ReconnectEnum::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReconnectEnum {
+    fn from(value: String) -> ReconnectEnum {
+        match value.as_str() {
+            "ENABLED" => ReconnectEnum::Enabled,
+            "DISABLED" => ReconnectEnum::Disabled,
+            _ => ReconnectEnum::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ReconnectEnum {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<ReconnectEnum, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ReconnectEnum {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ReconnectEnum {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ReconnectEnum {
+    fn deserialize<D>(deserializer: D) -> Result<ReconnectEnum, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct RegisterWorkspaceDirectoryRequest {
@@ -930,7 +1910,7 @@ pub struct RegisterWorkspaceDirectoryRequest {
     /// <p>Indicates whether your WorkSpace directory is dedicated or shared. To use Bring Your Own License (BYOL) images, this value must be set to <code>DEDICATED</code> and your AWS account must be enabled for BYOL. If your account has not been enabled for BYOL, you will receive an InvalidParameterValuesException error. For more information about BYOL images, see <a href="https://docs.aws.amazon.com/workspaces/latest/adminguide/byol-windows-images.html">Bring Your Own Windows Desktop Images</a>.</p>
     #[serde(rename = "Tenancy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub tenancy: Option<String>,
+    pub tenancy: Option<Tenancy>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -974,29 +1954,127 @@ pub struct RootStorage {
     pub capacity: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RunningMode {
+    /// `AUTO_STOP`
+    AutoStop,
+    /// `ALWAYS_ON`
+    AlwaysOn,
+    /// `MANUAL`
+    Manual,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl RunningMode {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RunningMode::AutoStop => "AUTO_STOP",
+            RunningMode::AlwaysOn => "ALWAYS_ON",
+            RunningMode::Manual => "MANUAL",
+            RunningMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for RunningMode {
+    fn default() -> RunningMode {
+        RunningMode::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for RunningMode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RunningMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RunningMode {
+    fn from(value: &'a str) -> RunningMode {
+        match value {
+            "AUTO_STOP" => RunningMode::AutoStop,
+            "ALWAYS_ON" => RunningMode::AlwaysOn,
+            "MANUAL" => RunningMode::Manual,
+            _ => RunningMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RunningMode {
+    fn from(value: String) -> RunningMode {
+        match value.as_str() {
+            "AUTO_STOP" => RunningMode::AutoStop,
+            "ALWAYS_ON" => RunningMode::AlwaysOn,
+            "MANUAL" => RunningMode::Manual,
+            _ => RunningMode::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for RunningMode {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<RunningMode, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RunningMode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RunningMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RunningMode {
+    fn deserialize<D>(deserializer: D) -> Result<RunningMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes the self-service permissions for a directory. For more information, see <a href="https://docs.aws.amazon.com/workspaces/latest/adminguide/enable-user-self-service-workspace-management.html">Enable Self-Service WorkSpace Management Capabilities for Your Users</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SelfservicePermissions {
     /// <p>Specifies whether users can change the compute type (bundle) for their WorkSpace.</p>
     #[serde(rename = "ChangeComputeType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub change_compute_type: Option<String>,
+    pub change_compute_type: Option<ReconnectEnum>,
     /// <p>Specifies whether users can increase the volume size of the drives on their WorkSpace.</p>
     #[serde(rename = "IncreaseVolumeSize")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub increase_volume_size: Option<String>,
+    pub increase_volume_size: Option<ReconnectEnum>,
     /// <p>Specifies whether users can rebuild the operating system of a WorkSpace to its original state.</p>
     #[serde(rename = "RebuildWorkspace")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub rebuild_workspace: Option<String>,
+    pub rebuild_workspace: Option<ReconnectEnum>,
     /// <p>Specifies whether users can restart their WorkSpace.</p>
     #[serde(rename = "RestartWorkspace")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub restart_workspace: Option<String>,
+    pub restart_workspace: Option<ReconnectEnum>,
     /// <p>Specifies whether users can switch the running mode of their WorkSpace.</p>
     #[serde(rename = "SwitchRunningMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub switch_running_mode: Option<String>,
+    pub switch_running_mode: Option<ReconnectEnum>,
 }
 
 /// <p>Describes a snapshot.</p>
@@ -1005,8 +2083,13 @@ pub struct SelfservicePermissions {
 pub struct Snapshot {
     /// <p>The time when the snapshot was created.</p>
     #[serde(rename = "SnapshotTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub snapshot_time: Option<f64>,
+    pub snapshot_time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>Information used to start a WorkSpace.</p>
@@ -1075,6 +2158,192 @@ pub struct Tag {
     pub value: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TargetWorkspaceState {
+    /// `AVAILABLE`
+    Available,
+    /// `ADMIN_MAINTENANCE`
+    AdminMaintenance,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TargetWorkspaceState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TargetWorkspaceState::Available => "AVAILABLE",
+            TargetWorkspaceState::AdminMaintenance => "ADMIN_MAINTENANCE",
+            TargetWorkspaceState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TargetWorkspaceState {
+    fn default() -> TargetWorkspaceState {
+        TargetWorkspaceState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TargetWorkspaceState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TargetWorkspaceState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for TargetWorkspaceState {
+    fn from(value: &'a str) -> TargetWorkspaceState {
+        match value {
+            "AVAILABLE" => TargetWorkspaceState::Available,
+            "ADMIN_MAINTENANCE" => TargetWorkspaceState::AdminMaintenance,
+            _ => TargetWorkspaceState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TargetWorkspaceState {
+    fn from(value: String) -> TargetWorkspaceState {
+        match value.as_str() {
+            "AVAILABLE" => TargetWorkspaceState::Available,
+            "ADMIN_MAINTENANCE" => TargetWorkspaceState::AdminMaintenance,
+            _ => TargetWorkspaceState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TargetWorkspaceState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TargetWorkspaceState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TargetWorkspaceState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TargetWorkspaceState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TargetWorkspaceState {
+    fn deserialize<D>(deserializer: D) -> Result<TargetWorkspaceState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Tenancy {
+    /// `DEDICATED`
+    Dedicated,
+    /// `SHARED`
+    Shared,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl Tenancy {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Tenancy::Dedicated => "DEDICATED",
+            Tenancy::Shared => "SHARED",
+            Tenancy::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for Tenancy {
+    fn default() -> Tenancy {
+        Tenancy::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for Tenancy {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Tenancy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Tenancy {
+    fn from(value: &'a str) -> Tenancy {
+        match value {
+            "DEDICATED" => Tenancy::Dedicated,
+            "SHARED" => Tenancy::Shared,
+            _ => Tenancy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Tenancy {
+    fn from(value: String) -> Tenancy {
+        match value.as_str() {
+            "DEDICATED" => Tenancy::Dedicated,
+            "SHARED" => Tenancy::Shared,
+            _ => Tenancy::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Tenancy {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Tenancy, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Tenancy {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Tenancy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Tenancy {
+    fn deserialize<D>(deserializer: D) -> Result<Tenancy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes the information used to terminate a WorkSpace.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
@@ -1165,7 +2434,7 @@ pub struct Workspace {
     /// <p>The operational state of the WorkSpace.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<WorkspaceState>,
     /// <p>The identifier of the subnet for the WorkSpace.</p>
     #[serde(rename = "SubnetId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1198,31 +2467,31 @@ pub struct WorkspaceAccessProperties {
     /// <p>Indicates whether users can use Android devices to access their WorkSpaces.</p>
     #[serde(rename = "DeviceTypeAndroid")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub device_type_android: Option<String>,
+    pub device_type_android: Option<AccessPropertyValue>,
     /// <p>Indicates whether users can use Chromebooks to access their WorkSpaces.</p>
     #[serde(rename = "DeviceTypeChromeOs")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub device_type_chrome_os: Option<String>,
+    pub device_type_chrome_os: Option<AccessPropertyValue>,
     /// <p>Indicates whether users can use iOS devices to access their WorkSpaces.</p>
     #[serde(rename = "DeviceTypeIos")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub device_type_ios: Option<String>,
+    pub device_type_ios: Option<AccessPropertyValue>,
     /// <p>Indicates whether users can use macOS clients to access their WorkSpaces. To restrict WorkSpaces access to trusted devices (also known as managed devices) with valid certificates, specify a value of <code>TRUST</code>. For more information, see <a href="https://docs.aws.amazon.com/workspaces/latest/adminguide/trusted-devices.html">Restrict WorkSpaces Access to Trusted Devices</a>. </p>
     #[serde(rename = "DeviceTypeOsx")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub device_type_osx: Option<String>,
+    pub device_type_osx: Option<AccessPropertyValue>,
     /// <p>Indicates whether users can access their WorkSpaces through a web browser.</p>
     #[serde(rename = "DeviceTypeWeb")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub device_type_web: Option<String>,
+    pub device_type_web: Option<AccessPropertyValue>,
     /// <p>Indicates whether users can use Windows clients to access their WorkSpaces. To restrict WorkSpaces access to trusted devices (also known as managed devices) with valid certificates, specify a value of <code>TRUST</code>. For more information, see <a href="https://docs.aws.amazon.com/workspaces/latest/adminguide/trusted-devices.html">Restrict WorkSpaces Access to Trusted Devices</a>. </p>
     #[serde(rename = "DeviceTypeWindows")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub device_type_windows: Option<String>,
+    pub device_type_windows: Option<AccessPropertyValue>,
     /// <p>Indicates whether users can use zero client devices to access their WorkSpaces.</p>
     #[serde(rename = "DeviceTypeZeroClient")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub device_type_zero_client: Option<String>,
+    pub device_type_zero_client: Option<AccessPropertyValue>,
 }
 
 /// <p>Describes a WorkSpace bundle.</p>
@@ -1247,8 +2516,13 @@ pub struct WorkspaceBundle {
     pub image_id: Option<String>,
     /// <p>The last time that the bundle was updated.</p>
     #[serde(rename = "LastUpdatedTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_time: Option<f64>,
+    pub last_updated_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the bundle.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1274,15 +2548,25 @@ pub struct WorkspaceConnectionStatus {
     /// <p>The connection state of the WorkSpace. The connection state is unknown if the WorkSpace is stopped.</p>
     #[serde(rename = "ConnectionState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub connection_state: Option<String>,
+    pub connection_state: Option<ConnectionState>,
     /// <p>The timestamp of the connection status check.</p>
     #[serde(rename = "ConnectionStateCheckTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub connection_state_check_timestamp: Option<f64>,
+    pub connection_state_check_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The timestamp of the last known user connection.</p>
     #[serde(rename = "LastKnownUserConnectionTimestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_known_user_connection_timestamp: Option<f64>,
+    pub last_known_user_connection_timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The identifier of the WorkSpace.</p>
     #[serde(rename = "WorkspaceId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1338,7 +2622,7 @@ pub struct WorkspaceDirectory {
     /// <p>The directory type.</p>
     #[serde(rename = "DirectoryType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub directory_type: Option<String>,
+    pub directory_type: Option<WorkspaceDirectoryType>,
     /// <p>The IP addresses of the DNS servers for the directory.</p>
     #[serde(rename = "DnsIpAddresses")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1358,7 +2642,7 @@ pub struct WorkspaceDirectory {
     /// <p>The state of the directory's registration with Amazon WorkSpaces.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<WorkspaceDirectoryState>,
     /// <p>The identifiers of the subnets used with the directory.</p>
     #[serde(rename = "SubnetIds")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1366,7 +2650,7 @@ pub struct WorkspaceDirectory {
     /// <p>Specifies whether the directory is dedicated or shared. To use Bring Your Own License (BYOL), this value must be set to <code>DEDICATED</code>. For more information, see <a href="https://docs.aws.amazon.com/workspaces/latest/adminguide/byol-windows-images.html">Bring Your Own Windows Desktop Images</a>.</p>
     #[serde(rename = "Tenancy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub tenancy: Option<String>,
+    pub tenancy: Option<Tenancy>,
     /// <p>The devices and operating systems that users can use to access WorkSpaces.</p>
     #[serde(rename = "WorkspaceAccessProperties")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1385,6 +2669,207 @@ pub struct WorkspaceDirectory {
     pub ip_group_ids: Option<Vec<String>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum WorkspaceDirectoryState {
+    /// `REGISTERING`
+    Registering,
+    /// `REGISTERED`
+    Registered,
+    /// `DEREGISTERING`
+    Deregistering,
+    /// `DEREGISTERED`
+    Deregistered,
+    /// `ERROR`
+    Error,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl WorkspaceDirectoryState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            WorkspaceDirectoryState::Registering => "REGISTERING",
+            WorkspaceDirectoryState::Registered => "REGISTERED",
+            WorkspaceDirectoryState::Deregistering => "DEREGISTERING",
+            WorkspaceDirectoryState::Deregistered => "DEREGISTERED",
+            WorkspaceDirectoryState::Error => "ERROR",
+            WorkspaceDirectoryState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for WorkspaceDirectoryState {
+    fn default() -> WorkspaceDirectoryState {
+        WorkspaceDirectoryState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for WorkspaceDirectoryState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for WorkspaceDirectoryState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for WorkspaceDirectoryState {
+    fn from(value: &'a str) -> WorkspaceDirectoryState {
+        match value {
+            "REGISTERING" => WorkspaceDirectoryState::Registering,
+            "REGISTERED" => WorkspaceDirectoryState::Registered,
+            "DEREGISTERING" => WorkspaceDirectoryState::Deregistering,
+            "DEREGISTERED" => WorkspaceDirectoryState::Deregistered,
+            "ERROR" => WorkspaceDirectoryState::Error,
+            _ => WorkspaceDirectoryState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for WorkspaceDirectoryState {
+    fn from(value: String) -> WorkspaceDirectoryState {
+        match value.as_str() {
+            "REGISTERING" => WorkspaceDirectoryState::Registering,
+            "REGISTERED" => WorkspaceDirectoryState::Registered,
+            "DEREGISTERING" => WorkspaceDirectoryState::Deregistering,
+            "DEREGISTERED" => WorkspaceDirectoryState::Deregistered,
+            "ERROR" => WorkspaceDirectoryState::Error,
+            _ => WorkspaceDirectoryState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for WorkspaceDirectoryState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<WorkspaceDirectoryState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for WorkspaceDirectoryState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for WorkspaceDirectoryState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for WorkspaceDirectoryState {
+    fn deserialize<D>(deserializer: D) -> Result<WorkspaceDirectoryState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum WorkspaceDirectoryType {
+    /// `SIMPLE_AD`
+    SimpleAd,
+    /// `AD_CONNECTOR`
+    AdConnector,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl WorkspaceDirectoryType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            WorkspaceDirectoryType::SimpleAd => "SIMPLE_AD",
+            WorkspaceDirectoryType::AdConnector => "AD_CONNECTOR",
+            WorkspaceDirectoryType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for WorkspaceDirectoryType {
+    fn default() -> WorkspaceDirectoryType {
+        WorkspaceDirectoryType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for WorkspaceDirectoryType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for WorkspaceDirectoryType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for WorkspaceDirectoryType {
+    fn from(value: &'a str) -> WorkspaceDirectoryType {
+        match value {
+            "SIMPLE_AD" => WorkspaceDirectoryType::SimpleAd,
+            "AD_CONNECTOR" => WorkspaceDirectoryType::AdConnector,
+            _ => WorkspaceDirectoryType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for WorkspaceDirectoryType {
+    fn from(value: String) -> WorkspaceDirectoryType {
+        match value.as_str() {
+            "SIMPLE_AD" => WorkspaceDirectoryType::SimpleAd,
+            "AD_CONNECTOR" => WorkspaceDirectoryType::AdConnector,
+            _ => WorkspaceDirectoryType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for WorkspaceDirectoryType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<WorkspaceDirectoryType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for WorkspaceDirectoryType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for WorkspaceDirectoryType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for WorkspaceDirectoryType {
+    fn deserialize<D>(deserializer: D) -> Result<WorkspaceDirectoryType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes a WorkSpace image.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1416,20 +2901,329 @@ pub struct WorkspaceImage {
     /// <p>Specifies whether the image is running on dedicated hardware. When Bring Your Own License (BYOL) is enabled, this value is set to <code>DEDICATED</code>. For more information, see <a href="https://docs.aws.amazon.com/workspaces/latest/adminguide/byol-windows-images.html">Bring Your Own Windows Desktop Images</a>.</p>
     #[serde(rename = "RequiredTenancy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub required_tenancy: Option<String>,
+    pub required_tenancy: Option<WorkspaceImageRequiredTenancy>,
     /// <p>The status of the image.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<WorkspaceImageState>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum WorkspaceImageIngestionProcess {
+    /// `BYOL_REGULAR`
+    ByolRegular,
+    /// `BYOL_GRAPHICS`
+    ByolGraphics,
+    /// `BYOL_GRAPHICSPRO`
+    ByolGraphicspro,
+    /// `BYOL_GRAPHICS_G4DN`
+    ByolGraphicsG4dn,
+    /// `BYOL_REGULAR_WSP`
+    ByolRegularWsp,
+    /// `BYOL_REGULAR_BYOP`
+    ByolRegularByop,
+    /// `BYOL_GRAPHICS_G4DN_BYOP`
+    ByolGraphicsG4dnByop,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl WorkspaceImageIngestionProcess {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            WorkspaceImageIngestionProcess::ByolRegular => "BYOL_REGULAR",
+            WorkspaceImageIngestionProcess::ByolGraphics => "BYOL_GRAPHICS",
+            WorkspaceImageIngestionProcess::ByolGraphicspro => "BYOL_GRAPHICSPRO",
+            WorkspaceImageIngestionProcess::ByolGraphicsG4dn => "BYOL_GRAPHICS_G4DN",
+            WorkspaceImageIngestionProcess::ByolRegularWsp => "BYOL_REGULAR_WSP",
+            WorkspaceImageIngestionProcess::ByolRegularByop => "BYOL_REGULAR_BYOP",
+            WorkspaceImageIngestionProcess::ByolGraphicsG4dnByop => "BYOL_GRAPHICS_G4DN_BYOP",
+            WorkspaceImageIngestionProcess::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for WorkspaceImageIngestionProcess {
+    fn default() -> WorkspaceImageIngestionProcess {
+        WorkspaceImageIngestionProcess::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for WorkspaceImageIngestionProcess {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for WorkspaceImageIngestionProcess {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for WorkspaceImageIngestionProcess {
+    fn from(value: &'a str) -> WorkspaceImageIngestionProcess {
+        match value {
+            "BYOL_REGULAR" => WorkspaceImageIngestionProcess::ByolRegular,
+            "BYOL_GRAPHICS" => WorkspaceImageIngestionProcess::ByolGraphics,
+            "BYOL_GRAPHICSPRO" => WorkspaceImageIngestionProcess::ByolGraphicspro,
+            "BYOL_GRAPHICS_G4DN" => WorkspaceImageIngestionProcess::ByolGraphicsG4dn,
+            "BYOL_REGULAR_WSP" => WorkspaceImageIngestionProcess::ByolRegularWsp,
+            "BYOL_REGULAR_BYOP" => WorkspaceImageIngestionProcess::ByolRegularByop,
+            "BYOL_GRAPHICS_G4DN_BYOP" => WorkspaceImageIngestionProcess::ByolGraphicsG4dnByop,
+            _ => WorkspaceImageIngestionProcess::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for WorkspaceImageIngestionProcess {
+    fn from(value: String) -> WorkspaceImageIngestionProcess {
+        match value.as_str() {
+            "BYOL_REGULAR" => WorkspaceImageIngestionProcess::ByolRegular,
+            "BYOL_GRAPHICS" => WorkspaceImageIngestionProcess::ByolGraphics,
+            "BYOL_GRAPHICSPRO" => WorkspaceImageIngestionProcess::ByolGraphicspro,
+            "BYOL_GRAPHICS_G4DN" => WorkspaceImageIngestionProcess::ByolGraphicsG4dn,
+            "BYOL_REGULAR_WSP" => WorkspaceImageIngestionProcess::ByolRegularWsp,
+            "BYOL_REGULAR_BYOP" => WorkspaceImageIngestionProcess::ByolRegularByop,
+            "BYOL_GRAPHICS_G4DN_BYOP" => WorkspaceImageIngestionProcess::ByolGraphicsG4dnByop,
+            _ => WorkspaceImageIngestionProcess::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for WorkspaceImageIngestionProcess {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<WorkspaceImageIngestionProcess, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for WorkspaceImageIngestionProcess {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for WorkspaceImageIngestionProcess {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for WorkspaceImageIngestionProcess {
+    fn deserialize<D>(deserializer: D) -> Result<WorkspaceImageIngestionProcess, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum WorkspaceImageRequiredTenancy {
+    /// `DEFAULT`
+    Default,
+    /// `DEDICATED`
+    Dedicated,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl WorkspaceImageRequiredTenancy {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            WorkspaceImageRequiredTenancy::Default => "DEFAULT",
+            WorkspaceImageRequiredTenancy::Dedicated => "DEDICATED",
+            WorkspaceImageRequiredTenancy::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for WorkspaceImageRequiredTenancy {
+    fn default() -> WorkspaceImageRequiredTenancy {
+        WorkspaceImageRequiredTenancy::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for WorkspaceImageRequiredTenancy {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for WorkspaceImageRequiredTenancy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for WorkspaceImageRequiredTenancy {
+    fn from(value: &'a str) -> WorkspaceImageRequiredTenancy {
+        match value {
+            "DEFAULT" => WorkspaceImageRequiredTenancy::Default,
+            "DEDICATED" => WorkspaceImageRequiredTenancy::Dedicated,
+            _ => WorkspaceImageRequiredTenancy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for WorkspaceImageRequiredTenancy {
+    fn from(value: String) -> WorkspaceImageRequiredTenancy {
+        match value.as_str() {
+            "DEFAULT" => WorkspaceImageRequiredTenancy::Default,
+            "DEDICATED" => WorkspaceImageRequiredTenancy::Dedicated,
+            _ => WorkspaceImageRequiredTenancy::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for WorkspaceImageRequiredTenancy {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<WorkspaceImageRequiredTenancy, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for WorkspaceImageRequiredTenancy {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for WorkspaceImageRequiredTenancy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for WorkspaceImageRequiredTenancy {
+    fn deserialize<D>(deserializer: D) -> Result<WorkspaceImageRequiredTenancy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum WorkspaceImageState {
+    /// `AVAILABLE`
+    Available,
+    /// `PENDING`
+    Pending,
+    /// `ERROR`
+    Error,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl WorkspaceImageState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            WorkspaceImageState::Available => "AVAILABLE",
+            WorkspaceImageState::Pending => "PENDING",
+            WorkspaceImageState::Error => "ERROR",
+            WorkspaceImageState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for WorkspaceImageState {
+    fn default() -> WorkspaceImageState {
+        WorkspaceImageState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for WorkspaceImageState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for WorkspaceImageState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for WorkspaceImageState {
+    fn from(value: &'a str) -> WorkspaceImageState {
+        match value {
+            "AVAILABLE" => WorkspaceImageState::Available,
+            "PENDING" => WorkspaceImageState::Pending,
+            "ERROR" => WorkspaceImageState::Error,
+            _ => WorkspaceImageState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for WorkspaceImageState {
+    fn from(value: String) -> WorkspaceImageState {
+        match value.as_str() {
+            "AVAILABLE" => WorkspaceImageState::Available,
+            "PENDING" => WorkspaceImageState::Pending,
+            "ERROR" => WorkspaceImageState::Error,
+            _ => WorkspaceImageState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for WorkspaceImageState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<WorkspaceImageState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for WorkspaceImageState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for WorkspaceImageState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
+impl<'de> ::serde::Deserialize<'de> for WorkspaceImageState {
+    fn deserialize<D>(deserializer: D) -> Result<WorkspaceImageState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes a WorkSpace.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct WorkspaceProperties {
     /// <p>The compute type. For more information, see <a href="http://aws.amazon.com/workspaces/details/#Amazon_WorkSpaces_Bundles">Amazon WorkSpaces Bundles</a>.</p>
     #[serde(rename = "ComputeTypeName")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compute_type_name: Option<String>,
+    pub compute_type_name: Option<Compute>,
     /// <p>The size of the root volume.</p>
     #[serde(rename = "RootVolumeSizeGib")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1437,7 +3231,7 @@ pub struct WorkspaceProperties {
     /// <p>The running mode. For more information, see <a href="https://docs.aws.amazon.com/workspaces/latest/adminguide/running-mode.html">Manage the WorkSpace Running Mode</a>.</p>
     #[serde(rename = "RunningMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub running_mode: Option<String>,
+    pub running_mode: Option<RunningMode>,
     /// <p>The time after a user logs off when WorkSpaces are automatically stopped. Configured in 60-minute intervals.</p>
     #[serde(rename = "RunningModeAutoStopTimeoutInMinutes")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1482,6 +3276,174 @@ pub struct WorkspaceRequest {
     pub workspace_properties: Option<WorkspaceProperties>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum WorkspaceState {
+    /// `PENDING`
+    Pending,
+    /// `AVAILABLE`
+    Available,
+    /// `IMPAIRED`
+    Impaired,
+    /// `UNHEALTHY`
+    Unhealthy,
+    /// `REBOOTING`
+    Rebooting,
+    /// `STARTING`
+    Starting,
+    /// `REBUILDING`
+    Rebuilding,
+    /// `RESTORING`
+    Restoring,
+    /// `MAINTENANCE`
+    Maintenance,
+    /// `ADMIN_MAINTENANCE`
+    AdminMaintenance,
+    /// `TERMINATING`
+    Terminating,
+    /// `TERMINATED`
+    Terminated,
+    /// `SUSPENDED`
+    Suspended,
+    /// `UPDATING`
+    Updating,
+    /// `STOPPING`
+    Stopping,
+    /// `STOPPED`
+    Stopped,
+    /// `ERROR`
+    Error,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl WorkspaceState {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            WorkspaceState::Pending => "PENDING",
+            WorkspaceState::Available => "AVAILABLE",
+            WorkspaceState::Impaired => "IMPAIRED",
+            WorkspaceState::Unhealthy => "UNHEALTHY",
+            WorkspaceState::Rebooting => "REBOOTING",
+            WorkspaceState::Starting => "STARTING",
+            WorkspaceState::Rebuilding => "REBUILDING",
+            WorkspaceState::Restoring => "RESTORING",
+            WorkspaceState::Maintenance => "MAINTENANCE",
+            WorkspaceState::AdminMaintenance => "ADMIN_MAINTENANCE",
+            WorkspaceState::Terminating => "TERMINATING",
+            WorkspaceState::Terminated => "TERMINATED",
+            WorkspaceState::Suspended => "SUSPENDED",
+            WorkspaceState::Updating => "UPDATING",
+            WorkspaceState::Stopping => "STOPPING",
+            WorkspaceState::Stopped => "STOPPED",
+            WorkspaceState::Error => "ERROR",
+            WorkspaceState::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for WorkspaceState {
+    fn default() -> WorkspaceState {
+        WorkspaceState::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for WorkspaceState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for WorkspaceState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for WorkspaceState {
+    fn from(value: &'a str) -> WorkspaceState {
+        match value {
+            "PENDING" => WorkspaceState::Pending,
+            "AVAILABLE" => WorkspaceState::Available,
+            "IMPAIRED" => WorkspaceState::Impaired,
+            "UNHEALTHY" => WorkspaceState::Unhealthy,
+            "REBOOTING" => WorkspaceState::Rebooting,
+            "STARTING" => WorkspaceState::Starting,
+            "REBUILDING" => WorkspaceState::Rebuilding,
+            "RESTORING" => WorkspaceState::Restoring,
+            "MAINTENANCE" => WorkspaceState::Maintenance,
+            "ADMIN_MAINTENANCE" => WorkspaceState::AdminMaintenance,
+            "TERMINATING" => WorkspaceState::Terminating,
+            "TERMINATED" => WorkspaceState::Terminated,
+            "SUSPENDED" => WorkspaceState::Suspended,
+            "UPDATING" => WorkspaceState::Updating,
+            "STOPPING" => WorkspaceState::Stopping,
+            "STOPPED" => WorkspaceState::Stopped,
+            "ERROR" => WorkspaceState::Error,
+            _ => WorkspaceState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for WorkspaceState {
+    fn from(value: String) -> WorkspaceState {
+        match value.as_str() {
+            "PENDING" => WorkspaceState::Pending,
+            "AVAILABLE" => WorkspaceState::Available,
+            "IMPAIRED" => WorkspaceState::Impaired,
+            "UNHEALTHY" => WorkspaceState::Unhealthy,
+            "REBOOTING" => WorkspaceState::Rebooting,
+            "STARTING" => WorkspaceState::Starting,
+            "REBUILDING" => WorkspaceState::Rebuilding,
+            "RESTORING" => WorkspaceState::Restoring,
+            "MAINTENANCE" => WorkspaceState::Maintenance,
+            "ADMIN_MAINTENANCE" => WorkspaceState::AdminMaintenance,
+            "TERMINATING" => WorkspaceState::Terminating,
+            "TERMINATED" => WorkspaceState::Terminated,
+            "SUSPENDED" => WorkspaceState::Suspended,
+            "UPDATING" => WorkspaceState::Updating,
+            "STOPPING" => WorkspaceState::Stopping,
+            "STOPPED" => WorkspaceState::Stopped,
+            "ERROR" => WorkspaceState::Error,
+            _ => WorkspaceState::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for WorkspaceState {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<WorkspaceState, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for WorkspaceState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for WorkspaceState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for WorkspaceState {
+    fn deserialize<D>(deserializer: D) -> Result<WorkspaceState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Describes an IP access control group.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -3738,11 +5700,12 @@ pub struct WorkspacesClient {
 impl WorkspacesClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> WorkspacesClient {
         WorkspacesClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "WorkSpaces"),
         }
     }
 
@@ -3774,6 +5737,7 @@ impl Workspaces for WorkspacesClient {
         input: AssociateIpGroupsRequest,
     ) -> Result<AssociateIpGroupsResult, RusotoError<AssociateIpGroupsError>> {
         let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("AssociateIpGroups");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "WorkspacesService.AssociateIpGroups");
@@ -3801,6 +5765,7 @@ impl Workspaces for WorkspacesClient {
         input: AuthorizeIpRulesRequest,
     ) -> Result<AuthorizeIpRulesResult, RusotoError<AuthorizeIpRulesError>> {
         let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("AuthorizeIpRules");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "WorkspacesService.AuthorizeIpRules");
@@ -3828,6 +5793,7 @@ impl Workspaces for WorkspacesClient {
         input: CopyWorkspaceImageRequest,
     ) -> Result<CopyWorkspaceImageResult, RusotoError<CopyWorkspaceImageError>> {
         let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("CopyWorkspaceImage");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "WorkspacesService.CopyWorkspaceImage");
@@ -3856,6 +5822,7 @@ impl Workspaces for WorkspacesClient {
         input: CreateIpGroupRequest,
     ) -> Result<CreateIpGroupResult, RusotoError<CreateIpGroupError>> {
         let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("CreateIpGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "WorkspacesService.CreateIpGroup");
@@ -3883,6 +5850,7 @@ impl Workspaces for WorkspacesClient {
         input: CreateTagsRequest,
     ) -> Result<CreateTagsResult, RusotoError<CreateTagsError>> {
         let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("CreateTags");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "WorkspacesService.CreateTags");
@@ -3910,6 +5878,7 @@ impl Workspaces for WorkspacesClient {
         input: CreateWorkspacesRequest,
     ) -> Result<CreateWorkspacesResult, RusotoError<CreateWorkspacesError>> {
         let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("CreateWorkspaces");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "WorkspacesService.CreateWorkspaces");
@@ -3937,6 +5906,7 @@ impl Workspaces for WorkspacesClient {
         input: DeleteIpGroupRequest,
     ) -> Result<DeleteIpGroupResult, RusotoError<DeleteIpGroupError>> {
         let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("DeleteIpGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "WorkspacesService.DeleteIpGroup");
@@ -3964,6 +5934,7 @@ impl Workspaces for WorkspacesClient {
         input: DeleteTagsRequest,
     ) -> Result<DeleteTagsResult, RusotoError<DeleteTagsError>> {
         let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("DeleteTags");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "WorkspacesService.DeleteTags");
@@ -3991,6 +5962,7 @@ impl Workspaces for WorkspacesClient {
         input: DeleteWorkspaceImageRequest,
     ) -> Result<DeleteWorkspaceImageResult, RusotoError<DeleteWorkspaceImageError>> {
         let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("DeleteWorkspaceImage");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "WorkspacesService.DeleteWorkspaceImage");
@@ -4020,6 +5992,7 @@ impl Workspaces for WorkspacesClient {
     ) -> Result<DeregisterWorkspaceDirectoryResult, RusotoError<DeregisterWorkspaceDirectoryError>>
     {
         let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("DeregisterWorkspaceDirectory");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4050,6 +6023,7 @@ impl Workspaces for WorkspacesClient {
         &self,
     ) -> Result<DescribeAccountResult, RusotoError<DescribeAccountError>> {
         let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("DescribeAccount");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "WorkspacesService.DescribeAccount");
@@ -4077,6 +6051,7 @@ impl Workspaces for WorkspacesClient {
     ) -> Result<DescribeAccountModificationsResult, RusotoError<DescribeAccountModificationsError>>
     {
         let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("DescribeAccountModifications");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4108,6 +6083,7 @@ impl Workspaces for WorkspacesClient {
         input: DescribeClientPropertiesRequest,
     ) -> Result<DescribeClientPropertiesResult, RusotoError<DescribeClientPropertiesError>> {
         let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("DescribeClientProperties");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "WorkspacesService.DescribeClientProperties");
@@ -4136,6 +6112,7 @@ impl Workspaces for WorkspacesClient {
         input: DescribeIpGroupsRequest,
     ) -> Result<DescribeIpGroupsResult, RusotoError<DescribeIpGroupsError>> {
         let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("DescribeIpGroups");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "WorkspacesService.DescribeIpGroups");
@@ -4163,6 +6140,7 @@ impl Workspaces for WorkspacesClient {
         input: DescribeTagsRequest,
     ) -> Result<DescribeTagsResult, RusotoError<DescribeTagsError>> {
         let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("DescribeTags");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "WorkspacesService.DescribeTags");
@@ -4190,6 +6168,7 @@ impl Workspaces for WorkspacesClient {
         input: DescribeWorkspaceBundlesRequest,
     ) -> Result<DescribeWorkspaceBundlesResult, RusotoError<DescribeWorkspaceBundlesError>> {
         let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("DescribeWorkspaceBundles");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "WorkspacesService.DescribeWorkspaceBundles");
@@ -4219,6 +6198,7 @@ impl Workspaces for WorkspacesClient {
     ) -> Result<DescribeWorkspaceDirectoriesResult, RusotoError<DescribeWorkspaceDirectoriesError>>
     {
         let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("DescribeWorkspaceDirectories");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4250,6 +6230,7 @@ impl Workspaces for WorkspacesClient {
         input: DescribeWorkspaceImagesRequest,
     ) -> Result<DescribeWorkspaceImagesResult, RusotoError<DescribeWorkspaceImagesError>> {
         let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("DescribeWorkspaceImages");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "WorkspacesService.DescribeWorkspaceImages");
@@ -4279,6 +6260,7 @@ impl Workspaces for WorkspacesClient {
     ) -> Result<DescribeWorkspaceSnapshotsResult, RusotoError<DescribeWorkspaceSnapshotsError>>
     {
         let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("DescribeWorkspaceSnapshots");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4310,6 +6292,7 @@ impl Workspaces for WorkspacesClient {
         input: DescribeWorkspacesRequest,
     ) -> Result<DescribeWorkspacesResult, RusotoError<DescribeWorkspacesError>> {
         let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("DescribeWorkspaces");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "WorkspacesService.DescribeWorkspaces");
@@ -4341,6 +6324,7 @@ impl Workspaces for WorkspacesClient {
         RusotoError<DescribeWorkspacesConnectionStatusError>,
     > {
         let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("DescribeWorkspacesConnectionStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4374,6 +6358,7 @@ impl Workspaces for WorkspacesClient {
         input: DisassociateIpGroupsRequest,
     ) -> Result<DisassociateIpGroupsResult, RusotoError<DisassociateIpGroupsError>> {
         let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("DisassociateIpGroups");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "WorkspacesService.DisassociateIpGroups");
@@ -4402,6 +6387,7 @@ impl Workspaces for WorkspacesClient {
         input: ImportWorkspaceImageRequest,
     ) -> Result<ImportWorkspaceImageResult, RusotoError<ImportWorkspaceImageError>> {
         let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("ImportWorkspaceImage");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "WorkspacesService.ImportWorkspaceImage");
@@ -4433,6 +6419,7 @@ impl Workspaces for WorkspacesClient {
         RusotoError<ListAvailableManagementCidrRangesError>,
     > {
         let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("ListAvailableManagementCidrRanges");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4466,6 +6453,7 @@ impl Workspaces for WorkspacesClient {
         input: MigrateWorkspaceRequest,
     ) -> Result<MigrateWorkspaceResult, RusotoError<MigrateWorkspaceError>> {
         let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("MigrateWorkspace");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "WorkspacesService.MigrateWorkspace");
@@ -4493,6 +6481,7 @@ impl Workspaces for WorkspacesClient {
         input: ModifyAccountRequest,
     ) -> Result<ModifyAccountResult, RusotoError<ModifyAccountError>> {
         let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("ModifyAccount");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "WorkspacesService.ModifyAccount");
@@ -4520,6 +6509,7 @@ impl Workspaces for WorkspacesClient {
         input: ModifyClientPropertiesRequest,
     ) -> Result<ModifyClientPropertiesResult, RusotoError<ModifyClientPropertiesError>> {
         let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("ModifyClientProperties");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "WorkspacesService.ModifyClientProperties");
@@ -4549,6 +6539,7 @@ impl Workspaces for WorkspacesClient {
     ) -> Result<ModifySelfservicePermissionsResult, RusotoError<ModifySelfservicePermissionsError>>
     {
         let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("ModifySelfservicePermissions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4583,6 +6574,7 @@ impl Workspaces for WorkspacesClient {
         RusotoError<ModifyWorkspaceAccessPropertiesError>,
     > {
         let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("ModifyWorkspaceAccessProperties");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4619,6 +6611,7 @@ impl Workspaces for WorkspacesClient {
         RusotoError<ModifyWorkspaceCreationPropertiesError>,
     > {
         let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("ModifyWorkspaceCreationProperties");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4652,6 +6645,7 @@ impl Workspaces for WorkspacesClient {
         input: ModifyWorkspacePropertiesRequest,
     ) -> Result<ModifyWorkspacePropertiesResult, RusotoError<ModifyWorkspacePropertiesError>> {
         let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("ModifyWorkspaceProperties");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4683,6 +6677,7 @@ impl Workspaces for WorkspacesClient {
         input: ModifyWorkspaceStateRequest,
     ) -> Result<ModifyWorkspaceStateResult, RusotoError<ModifyWorkspaceStateError>> {
         let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("ModifyWorkspaceState");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "WorkspacesService.ModifyWorkspaceState");
@@ -4711,6 +6706,7 @@ impl Workspaces for WorkspacesClient {
         input: RebootWorkspacesRequest,
     ) -> Result<RebootWorkspacesResult, RusotoError<RebootWorkspacesError>> {
         let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("RebootWorkspaces");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "WorkspacesService.RebootWorkspaces");
@@ -4738,6 +6734,7 @@ impl Workspaces for WorkspacesClient {
         input: RebuildWorkspacesRequest,
     ) -> Result<RebuildWorkspacesResult, RusotoError<RebuildWorkspacesError>> {
         let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("RebuildWorkspaces");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "WorkspacesService.RebuildWorkspaces");
@@ -4766,6 +6763,7 @@ impl Workspaces for WorkspacesClient {
     ) -> Result<RegisterWorkspaceDirectoryResult, RusotoError<RegisterWorkspaceDirectoryError>>
     {
         let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("RegisterWorkspaceDirectory");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4797,6 +6795,7 @@ impl Workspaces for WorkspacesClient {
         input: RestoreWorkspaceRequest,
     ) -> Result<RestoreWorkspaceResult, RusotoError<RestoreWorkspaceError>> {
         let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("RestoreWorkspace");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "WorkspacesService.RestoreWorkspace");
@@ -4824,6 +6823,7 @@ impl Workspaces for WorkspacesClient {
         input: RevokeIpRulesRequest,
     ) -> Result<RevokeIpRulesResult, RusotoError<RevokeIpRulesError>> {
         let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("RevokeIpRules");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "WorkspacesService.RevokeIpRules");
@@ -4851,6 +6851,7 @@ impl Workspaces for WorkspacesClient {
         input: StartWorkspacesRequest,
     ) -> Result<StartWorkspacesResult, RusotoError<StartWorkspacesError>> {
         let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("StartWorkspaces");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "WorkspacesService.StartWorkspaces");
@@ -4878,6 +6879,7 @@ impl Workspaces for WorkspacesClient {
         input: StopWorkspacesRequest,
     ) -> Result<StopWorkspacesResult, RusotoError<StopWorkspacesError>> {
         let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("StopWorkspaces");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "WorkspacesService.StopWorkspaces");
@@ -4905,6 +6907,7 @@ impl Workspaces for WorkspacesClient {
         input: TerminateWorkspacesRequest,
     ) -> Result<TerminateWorkspacesResult, RusotoError<TerminateWorkspacesError>> {
         let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("TerminateWorkspaces");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "WorkspacesService.TerminateWorkspaces");
@@ -4933,6 +6936,7 @@ impl Workspaces for WorkspacesClient {
         input: UpdateRulesOfIpGroupRequest,
     ) -> Result<UpdateRulesOfIpGroupResult, RusotoError<UpdateRulesOfIpGroupError>> {
         let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("UpdateRulesOfIpGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "WorkspacesService.UpdateRulesOfIpGroup");
@@ -4955,3 +6959,285 @@ impl Workspaces for WorkspacesClient {
         }
     }
 }
+impl WorkspacesClient {
+    /// Auto-paginating version of `describe_account_modifications`, streaming every page of results.
+    pub fn describe_account_modifications_pages(
+        &self,
+        input: DescribeAccountModificationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeAccountModificationsResult,
+        DescribeAccountModificationsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: WorkspacesClient, input: DescribeAccountModificationsRequest| async move {
+                client.describe_account_modifications(input).await
+            },
+            |_input: &DescribeAccountModificationsRequest,
+             output: &DescribeAccountModificationsResult| {
+                Some(DescribeAccountModificationsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_account_modifications`, streaming the `AccountModifications` of every page of results.
+    pub fn describe_account_modifications_items(
+        &self,
+        input: DescribeAccountModificationsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<AccountModification, DescribeAccountModificationsError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_account_modifications_pages(input),
+            |output: DescribeAccountModificationsResult| {
+                output.account_modifications.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_ip_groups`, streaming every page of results.
+    pub fn describe_ip_groups_pages(
+        &self,
+        input: DescribeIpGroupsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeIpGroupsResult, DescribeIpGroupsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: WorkspacesClient, input: DescribeIpGroupsRequest| async move {
+                client.describe_ip_groups(input).await
+            },
+            |input: &DescribeIpGroupsRequest, output: &DescribeIpGroupsResult| {
+                Some(DescribeIpGroupsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_ip_groups`, streaming the `Result` of every page of results.
+    pub fn describe_ip_groups_items(
+        &self,
+        input: DescribeIpGroupsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<WorkspacesIpGroup, DescribeIpGroupsError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_ip_groups_pages(input),
+            |output: DescribeIpGroupsResult| output.result.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_workspace_bundles`, streaming every page of results.
+    pub fn describe_workspace_bundles_pages(
+        &self,
+        input: DescribeWorkspaceBundlesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeWorkspaceBundlesResult,
+        DescribeWorkspaceBundlesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: WorkspacesClient, input: DescribeWorkspaceBundlesRequest| async move {
+                client.describe_workspace_bundles(input).await
+            },
+            |input: &DescribeWorkspaceBundlesRequest, output: &DescribeWorkspaceBundlesResult| {
+                Some(DescribeWorkspaceBundlesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_workspace_bundles`, streaming the `Bundles` of every page of results.
+    pub fn describe_workspace_bundles_items(
+        &self,
+        input: DescribeWorkspaceBundlesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<WorkspaceBundle, DescribeWorkspaceBundlesError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_workspace_bundles_pages(input),
+            |output: DescribeWorkspaceBundlesResult| output.bundles.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_workspace_directories`, streaming every page of results.
+    pub fn describe_workspace_directories_pages(
+        &self,
+        input: DescribeWorkspaceDirectoriesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeWorkspaceDirectoriesResult,
+        DescribeWorkspaceDirectoriesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: WorkspacesClient, input: DescribeWorkspaceDirectoriesRequest| async move {
+                client.describe_workspace_directories(input).await
+            },
+            |input: &DescribeWorkspaceDirectoriesRequest,
+             output: &DescribeWorkspaceDirectoriesResult| {
+                Some(DescribeWorkspaceDirectoriesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_workspace_directories`, streaming the `Directories` of every page of results.
+    pub fn describe_workspace_directories_items(
+        &self,
+        input: DescribeWorkspaceDirectoriesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<WorkspaceDirectory, DescribeWorkspaceDirectoriesError>
+    {
+        rusoto_core::pagination::paginate_items(
+            self.describe_workspace_directories_pages(input),
+            |output: DescribeWorkspaceDirectoriesResult| output.directories.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_workspace_images`, streaming every page of results.
+    pub fn describe_workspace_images_pages(
+        &self,
+        input: DescribeWorkspaceImagesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeWorkspaceImagesResult,
+        DescribeWorkspaceImagesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: WorkspacesClient, input: DescribeWorkspaceImagesRequest| async move {
+                client.describe_workspace_images(input).await
+            },
+            |input: &DescribeWorkspaceImagesRequest, output: &DescribeWorkspaceImagesResult| {
+                Some(DescribeWorkspaceImagesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_workspace_images`, streaming the `Images` of every page of results.
+    pub fn describe_workspace_images_items(
+        &self,
+        input: DescribeWorkspaceImagesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<WorkspaceImage, DescribeWorkspaceImagesError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_workspace_images_pages(input),
+            |output: DescribeWorkspaceImagesResult| output.images.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_workspaces`, streaming every page of results.
+    pub fn describe_workspaces_pages(
+        &self,
+        input: DescribeWorkspacesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<DescribeWorkspacesResult, DescribeWorkspacesError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: WorkspacesClient, input: DescribeWorkspacesRequest| async move {
+                client.describe_workspaces(input).await
+            },
+            |input: &DescribeWorkspacesRequest, output: &DescribeWorkspacesResult| {
+                Some(DescribeWorkspacesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_workspaces`, streaming the `Workspaces` of every page of results.
+    pub fn describe_workspaces_items(
+        &self,
+        input: DescribeWorkspacesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Workspace, DescribeWorkspacesError> {
+        rusoto_core::pagination::paginate_items(
+            self.describe_workspaces_pages(input),
+            |output: DescribeWorkspacesResult| output.workspaces.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `describe_workspaces_connection_status`, streaming every page of results.
+    pub fn describe_workspaces_connection_status_pages(
+        &self,
+        input: DescribeWorkspacesConnectionStatusRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        DescribeWorkspacesConnectionStatusResult,
+        DescribeWorkspacesConnectionStatusError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: WorkspacesClient, input: DescribeWorkspacesConnectionStatusRequest| async move {
+                client.describe_workspaces_connection_status(input).await
+            },
+            |input: &DescribeWorkspacesConnectionStatusRequest,
+             output: &DescribeWorkspacesConnectionStatusResult| {
+                Some(DescribeWorkspacesConnectionStatusRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `describe_workspaces_connection_status`, streaming the `WorkspacesConnectionStatus` of every page of results.
+    pub fn describe_workspaces_connection_status_items(
+        &self,
+        input: DescribeWorkspacesConnectionStatusRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        WorkspaceConnectionStatus,
+        DescribeWorkspacesConnectionStatusError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.describe_workspaces_connection_status_pages(input),
+            |output: DescribeWorkspacesConnectionStatusResult| {
+                output.workspaces_connection_status.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_available_management_cidr_ranges`, streaming every page of results.
+    pub fn list_available_management_cidr_ranges_pages(
+        &self,
+        input: ListAvailableManagementCidrRangesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        ListAvailableManagementCidrRangesResult,
+        ListAvailableManagementCidrRangesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: WorkspacesClient, input: ListAvailableManagementCidrRangesRequest| async move {
+                client.list_available_management_cidr_ranges(input).await
+            },
+            |input: &ListAvailableManagementCidrRangesRequest,
+             output: &ListAvailableManagementCidrRangesResult| {
+                Some(ListAvailableManagementCidrRangesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `list_available_management_cidr_ranges`, streaming the `ManagementCidrRanges` of every page of results.
+    pub fn list_available_management_cidr_ranges_items(
+        &self,
+        input: ListAvailableManagementCidrRangesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<String, ListAvailableManagementCidrRangesError> {
+        rusoto_core::pagination::paginate_items(
+            self.list_available_management_cidr_ranges_pages(input),
+            |output: ListAvailableManagementCidrRangesResult| {
+                output.management_cidr_ranges.unwrap_or_default()
+            },
+        )
+    }
+}
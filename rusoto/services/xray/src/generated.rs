@@ -216,8 +216,13 @@ pub struct Edge {
     pub aliases: Option<Vec<Alias>>,
     /// <p>The end time of the last segment on the edge.</p>
     #[serde(rename = "EndTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Identifier of the edge. Unique within a service map.</p>
     #[serde(rename = "ReferenceId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -228,8 +233,13 @@ pub struct Edge {
     pub response_time_histogram: Option<Vec<HistogramEntry>>,
     /// <p>The start time of the first segment on the edge.</p>
     #[serde(rename = "StartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Response statistics for segments on the edge.</p>
     #[serde(rename = "SummaryStatistics")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -273,13 +283,199 @@ pub struct EncryptionConfig {
     /// <p>The encryption status. While the status is <code>UPDATING</code>, X-Ray may encrypt data with a combination of the new and old settings.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<EncryptionStatus>,
     /// <p>The type of encryption. Set to <code>KMS</code> for encryption with CMKs. Set to <code>NONE</code> for default encryption.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<EncryptionType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EncryptionStatus {
+    /// `UPDATING`
+    Updating,
+    /// `ACTIVE`
+    Active,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EncryptionStatus {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EncryptionStatus::Updating => "UPDATING",
+            EncryptionStatus::Active => "ACTIVE",
+            EncryptionStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EncryptionStatus {
+    fn default() -> EncryptionStatus {
+        EncryptionStatus::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EncryptionStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EncryptionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for EncryptionStatus {
+    fn from(value: &'a str) -> EncryptionStatus {
+        match value {
+            "UPDATING" => EncryptionStatus::Updating,
+            "ACTIVE" => EncryptionStatus::Active,
+            _ => EncryptionStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EncryptionStatus {
+    fn from(value: String) -> EncryptionStatus {
+        match value.as_str() {
+            "UPDATING" => EncryptionStatus::Updating,
+            "ACTIVE" => EncryptionStatus::Active,
+            _ => EncryptionStatus::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EncryptionStatus {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EncryptionStatus, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EncryptionStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EncryptionStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EncryptionStatus {
+    fn deserialize<D>(deserializer: D) -> Result<EncryptionStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EncryptionType {
+    /// `NONE`
+    None,
+    /// `KMS`
+    Kms,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl EncryptionType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EncryptionType::None => "NONE",
+            EncryptionType::Kms => "KMS",
+            EncryptionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for EncryptionType {
+    fn default() -> EncryptionType {
+        EncryptionType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for EncryptionType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for EncryptionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
+impl<'a> From<&'a str> for EncryptionType {
+    fn from(value: &'a str) -> EncryptionType {
+        match value {
+            "NONE" => EncryptionType::None,
+            "KMS" => EncryptionType::Kms,
+            _ => EncryptionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EncryptionType {
+    fn from(value: String) -> EncryptionType {
+        match value.as_str() {
+            "NONE" => EncryptionType::None,
+            "KMS" => EncryptionType::Kms,
+            _ => EncryptionType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for EncryptionType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<EncryptionType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EncryptionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EncryptionType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EncryptionType {
+    fn deserialize<D>(deserializer: D) -> Result<EncryptionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>The root cause of a trace summary error.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -542,8 +738,13 @@ pub struct GetSamplingTargetsRequest {
 pub struct GetSamplingTargetsResult {
     /// <p>The last time a user changed the sampling rule configuration. If the sampling rule configuration changed since the service last retrieved it, the service should call <a>GetSamplingRules</a> to get the latest version.</p>
     #[serde(rename = "LastRuleModification")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_rule_modification: Option<f64>,
+    pub last_rule_modification: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Updated rules that the service should use to sample requests.</p>
     #[serde(rename = "SamplingTargetDocuments")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -559,7 +760,12 @@ pub struct GetSamplingTargetsResult {
 pub struct GetServiceGraphRequest {
     /// <p>The end of the timeframe for which to generate a graph.</p>
     #[serde(rename = "EndTime")]
-    pub end_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub end_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>The ARN of a group to generate a graph based on.</p>
     #[serde(rename = "GroupARN")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -574,7 +780,12 @@ pub struct GetServiceGraphRequest {
     pub next_token: Option<String>,
     /// <p>The start of the time frame for which to generate a graph.</p>
     #[serde(rename = "StartTime")]
-    pub start_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub start_time: ::chrono::DateTime<::chrono::Utc>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -586,8 +797,13 @@ pub struct GetServiceGraphResult {
     pub contains_old_group_versions: Option<bool>,
     /// <p>The end of the time frame for which the graph was generated.</p>
     #[serde(rename = "EndTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>Pagination token.</p>
     #[serde(rename = "NextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -598,8 +814,13 @@ pub struct GetServiceGraphResult {
     pub services: Option<Vec<Service>>,
     /// <p>The start of the time frame for which the graph was generated.</p>
     #[serde(rename = "StartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -607,7 +828,12 @@ pub struct GetServiceGraphResult {
 pub struct GetTimeSeriesServiceStatisticsRequest {
     /// <p>The end of the time frame for which to aggregate statistics.</p>
     #[serde(rename = "EndTime")]
-    pub end_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub end_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>A filter expression defining entities that will be aggregated for statistics. Supports ID, service, and edge functions. If no selector expression is specified, edge statistics are returned. </p>
     #[serde(rename = "EntitySelectorExpression")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -630,7 +856,12 @@ pub struct GetTimeSeriesServiceStatisticsRequest {
     pub period: Option<i64>,
     /// <p>The start of the time frame for which to aggregate statistics.</p>
     #[serde(rename = "StartTime")]
-    pub start_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub start_time: ::chrono::DateTime<::chrono::Utc>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -680,7 +911,12 @@ pub struct GetTraceGraphResult {
 pub struct GetTraceSummariesRequest {
     /// <p>The end of the time frame for which to retrieve traces.</p>
     #[serde(rename = "EndTime")]
-    pub end_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub end_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>Specify a filter expression to retrieve trace summaries for services or requests that meet certain requirements.</p>
     #[serde(rename = "FilterExpression")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -699,11 +935,16 @@ pub struct GetTraceSummariesRequest {
     pub sampling_strategy: Option<SamplingStrategy>,
     /// <p>The start of the time frame for which to retrieve traces.</p>
     #[serde(rename = "StartTime")]
-    pub start_time: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub start_time: ::chrono::DateTime<::chrono::Utc>,
     /// <p>A parameter to indicate whether to query trace summaries by TraceId or Event time.</p>
     #[serde(rename = "TimeRangeType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub time_range_type: Option<String>,
+    pub time_range_type: Option<TimeRangeType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -711,8 +952,13 @@ pub struct GetTraceSummariesRequest {
 pub struct GetTraceSummariesResult {
     /// <p>The start time of this page of results.</p>
     #[serde(rename = "ApproximateTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub approximate_time: Option<f64>,
+    pub approximate_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>If the requested time frame contained more than one page of results, you can use this token to retrieve the next page. The first page contains the most most recent results, closest to the end of the time frame.</p>
     #[serde(rename = "NextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -822,7 +1068,7 @@ pub struct PutEncryptionConfigRequest {
     pub key_id: Option<String>,
     /// <p>The type of encryption. Set to <code>KMS</code> to use your own key for encryption. Set to <code>NONE</code> for default encryption.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: EncryptionType,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1010,12 +1256,22 @@ pub struct SamplingRule {
 pub struct SamplingRuleRecord {
     /// <p>When the rule was created.</p>
     #[serde(rename = "CreatedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>When the rule was last modified.</p>
     #[serde(rename = "ModifiedAt")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub modified_at: Option<f64>,
+    pub modified_at: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The sampling rule.</p>
     #[serde(rename = "SamplingRule")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1098,8 +1354,13 @@ pub struct SamplingStatisticSummary {
     pub sampled_count: Option<i64>,
     /// <p>The start time of the reporting window.</p>
     #[serde(rename = "Timestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub timestamp: Option<f64>,
+    pub timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>Request sampling results for a single rule from a service. Results are for the last 10 seconds unless the service has been assigned a longer reporting interval after a previous call to <a>GetSamplingTargets</a>.</p>
@@ -1124,7 +1385,12 @@ pub struct SamplingStatisticsDocument {
     pub sampled_count: i64,
     /// <p>The current time.</p>
     #[serde(rename = "Timestamp")]
-    pub timestamp: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub timestamp: ::chrono::DateTime<::chrono::Utc>,
 }
 
 /// <p>The name and value of a sampling rule to apply to a trace summary.</p>
@@ -1134,13 +1400,106 @@ pub struct SamplingStrategy {
     /// <p>The name of a sampling rule.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
+    pub name: Option<SamplingStrategyName>,
     /// <p>The value of a sampling rule.</p>
     #[serde(rename = "Value")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<f64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SamplingStrategyName {
+    /// `PartialScan`
+    PartialScan,
+    /// `FixedRate`
+    FixedRate,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl SamplingStrategyName {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SamplingStrategyName::PartialScan => "PartialScan",
+            SamplingStrategyName::FixedRate => "FixedRate",
+            SamplingStrategyName::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for SamplingStrategyName {
+    fn default() -> SamplingStrategyName {
+        SamplingStrategyName::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for SamplingStrategyName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SamplingStrategyName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for SamplingStrategyName {
+    fn from(value: &'a str) -> SamplingStrategyName {
+        match value {
+            "PartialScan" => SamplingStrategyName::PartialScan,
+            "FixedRate" => SamplingStrategyName::FixedRate,
+            _ => SamplingStrategyName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SamplingStrategyName {
+    fn from(value: String) -> SamplingStrategyName {
+        match value.as_str() {
+            "PartialScan" => SamplingStrategyName::PartialScan,
+            "FixedRate" => SamplingStrategyName::FixedRate,
+            _ => SamplingStrategyName::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for SamplingStrategyName {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<SamplingStrategyName, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SamplingStrategyName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SamplingStrategyName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SamplingStrategyName {
+    fn deserialize<D>(deserializer: D) -> Result<SamplingStrategyName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>Temporary changes to a sampling rule configuration. To meet the global sampling target for a rule, X-Ray calculates a new reservoir for each service based on the recent sampling results of all services that called <a>GetSamplingTargets</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1159,8 +1518,13 @@ pub struct SamplingTargetDocument {
     pub reservoir_quota: Option<i64>,
     /// <p>When the reservoir quota expires.</p>
     #[serde(rename = "ReservoirQuotaTTL")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub reservoir_quota_ttl: Option<f64>,
+    pub reservoir_quota_ttl: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The name of the sampling rule.</p>
     #[serde(rename = "RuleName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1199,8 +1563,13 @@ pub struct Service {
     pub edges: Option<Vec<Edge>>,
     /// <p>The end time of the last segment that the service generated.</p>
     #[serde(rename = "EndTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The canonical name of the service.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1223,8 +1592,13 @@ pub struct Service {
     pub root: Option<bool>,
     /// <p>The start time of the first segment that the service generated.</p>
     #[serde(rename = "StartTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>The service's state.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1313,9 +1687,112 @@ pub struct TelemetryRecord {
     pub segments_spillover_count: Option<i64>,
     /// <p><p/></p>
     #[serde(rename = "Timestamp")]
-    pub timestamp: f64,
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
+    pub timestamp: ::chrono::DateTime<::chrono::Utc>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TimeRangeType {
+    /// `TraceId`
+    TraceId,
+    /// `Event`
+    Event,
+    /// `Service`
+    Service,
+    /// A value not known to this version of the crate
+    Unknown(String),
+}
+
+impl TimeRangeType {
+    /// The value sent over the wire
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TimeRangeType::TraceId => "TraceId",
+            TimeRangeType::Event => "Event",
+            TimeRangeType::Service => "Service",
+            TimeRangeType::Unknown(ref value) => value,
+        }
+    }
+}
+
+// required fields of defaulted structs are left empty, as they would be for strings
+impl Default for TimeRangeType {
+    fn default() -> TimeRangeType {
+        TimeRangeType::Unknown(String::new())
+    }
+}
+
+impl AsRef<str> for TimeRangeType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TimeRangeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
+impl<'a> From<&'a str> for TimeRangeType {
+    fn from(value: &'a str) -> TimeRangeType {
+        match value {
+            "TraceId" => TimeRangeType::TraceId,
+            "Event" => TimeRangeType::Event,
+            "Service" => TimeRangeType::Service,
+            _ => TimeRangeType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TimeRangeType {
+    fn from(value: String) -> TimeRangeType {
+        match value.as_str() {
+            "TraceId" => TimeRangeType::TraceId,
+            "Event" => TimeRangeType::Event,
+            "Service" => TimeRangeType::Service,
+            _ => TimeRangeType::Unknown(value),
+        }
+    }
+}
+
+impl ::std::str::FromStr for TimeRangeType {
+    type Err = ::std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<TimeRangeType, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TimeRangeType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TimeRangeType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TimeRangeType {
+    fn deserialize<D>(deserializer: D) -> Result<TimeRangeType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(value.into())
+    }
+}
 /// <p>A list of TimeSeriesStatistic structures.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
@@ -1332,8 +1809,13 @@ pub struct TimeSeriesServiceStatistics {
     pub service_summary_statistics: Option<ServiceStatistics>,
     /// <p>Timestamp of the window for which statistics are aggregated.</p>
     #[serde(rename = "Timestamp")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub timestamp: Option<f64>,
+    pub timestamp: Option<::chrono::DateTime<::chrono::Utc>>,
 }
 
 /// <p>A collection of segment documents with matching trace IDs.</p>
@@ -1412,8 +1894,13 @@ pub struct TraceSummary {
     pub is_partial: Option<bool>,
     /// <p>The matched time stamp of a defined event.</p>
     #[serde(rename = "MatchedEventTime")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp",
+        serialize_with = "::rusoto_core::serialization::SerdeTimestamp::serialize_unix_timestamp",
+        default
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub matched_event_time: Option<f64>,
+    pub matched_event_time: Option<::chrono::DateTime<::chrono::Utc>>,
     /// <p>A list of resource ARNs for any resource corresponding to the trace segments.</p>
     #[serde(rename = "ResourceARNs")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2424,11 +2911,12 @@ pub struct XRayClient {
 impl XRayClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
     pub fn new(region: region::Region) -> XRayClient {
         XRayClient {
             client: Client::shared(),
-            region,
+            region: rusoto_core::endpoint::with_configured_endpoint(region, "XRay"),
         }
     }
 
@@ -2462,6 +2950,7 @@ impl XRay for XRayClient {
         let request_uri = "/Traces";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("BatchGetTraces");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2492,6 +2981,7 @@ impl XRay for XRayClient {
         let request_uri = "/CreateGroup";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("CreateGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2522,6 +3012,7 @@ impl XRay for XRayClient {
         let request_uri = "/CreateSamplingRule";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("CreateSamplingRule");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2552,6 +3043,7 @@ impl XRay for XRayClient {
         let request_uri = "/DeleteGroup";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("DeleteGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2582,6 +3074,7 @@ impl XRay for XRayClient {
         let request_uri = "/DeleteSamplingRule";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("DeleteSamplingRule");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2611,6 +3104,7 @@ impl XRay for XRayClient {
         let request_uri = "/EncryptionConfig";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("GetEncryptionConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2638,6 +3132,7 @@ impl XRay for XRayClient {
         let request_uri = "/GetGroup";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("GetGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2668,6 +3163,7 @@ impl XRay for XRayClient {
         let request_uri = "/Groups";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("GetGroups");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2698,6 +3194,7 @@ impl XRay for XRayClient {
         let request_uri = "/GetSamplingRules";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("GetSamplingRules");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2729,6 +3226,7 @@ impl XRay for XRayClient {
         let request_uri = "/SamplingStatisticSummaries";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("GetSamplingStatisticSummaries");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2759,6 +3257,7 @@ impl XRay for XRayClient {
         let request_uri = "/SamplingTargets";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("GetSamplingTargets");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2789,6 +3288,7 @@ impl XRay for XRayClient {
         let request_uri = "/ServiceGraph";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("GetServiceGraph");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2822,6 +3322,7 @@ impl XRay for XRayClient {
         let request_uri = "/TimeSeriesServiceStatistics";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("GetTimeSeriesServiceStatistics");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2852,6 +3353,7 @@ impl XRay for XRayClient {
         let request_uri = "/TraceGraph";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("GetTraceGraph");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2882,6 +3384,7 @@ impl XRay for XRayClient {
         let request_uri = "/TraceSummaries";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("GetTraceSummaries");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2912,6 +3415,7 @@ impl XRay for XRayClient {
         let request_uri = "/PutEncryptionConfig";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("PutEncryptionConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2942,6 +3446,7 @@ impl XRay for XRayClient {
         let request_uri = "/TelemetryRecords";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("PutTelemetryRecords");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2972,6 +3477,7 @@ impl XRay for XRayClient {
         let request_uri = "/TraceSegments";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("PutTraceSegments");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3002,6 +3508,7 @@ impl XRay for XRayClient {
         let request_uri = "/UpdateGroup";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("UpdateGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3032,6 +3539,7 @@ impl XRay for XRayClient {
         let request_uri = "/UpdateSamplingRule";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("UpdateSamplingRule");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3054,3 +3562,266 @@ impl XRay for XRayClient {
         }
     }
 }
+impl XRayClient {
+    /// Auto-paginating version of `batch_get_traces`, streaming every page of results.
+    pub fn batch_get_traces_pages(
+        &self,
+        input: BatchGetTracesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<BatchGetTracesResult, BatchGetTracesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: XRayClient, input: BatchGetTracesRequest| async move {
+                client.batch_get_traces(input).await
+            },
+            |input: &BatchGetTracesRequest, output: &BatchGetTracesResult| {
+                Some(BatchGetTracesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `batch_get_traces`, streaming the `Traces` of every page of results.
+    pub fn batch_get_traces_items(
+        &self,
+        input: BatchGetTracesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Trace, BatchGetTracesError> {
+        rusoto_core::pagination::paginate_items(
+            self.batch_get_traces_pages(input),
+            |output: BatchGetTracesResult| output.traces.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_groups`, streaming every page of results.
+    pub fn get_groups_pages(
+        &self,
+        input: GetGroupsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetGroupsResult, GetGroupsError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: XRayClient, input: GetGroupsRequest| async move { client.get_groups(input).await },
+            |_input: &GetGroupsRequest, output: &GetGroupsResult| {
+                Some(GetGroupsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_groups`, streaming the `Groups` of every page of results.
+    pub fn get_groups_items(
+        &self,
+        input: GetGroupsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GroupSummary, GetGroupsError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_groups_pages(input),
+            |output: GetGroupsResult| output.groups.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_sampling_rules`, streaming every page of results.
+    pub fn get_sampling_rules_pages(
+        &self,
+        input: GetSamplingRulesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetSamplingRulesResult, GetSamplingRulesError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: XRayClient, input: GetSamplingRulesRequest| async move {
+                client.get_sampling_rules(input).await
+            },
+            |_input: &GetSamplingRulesRequest, output: &GetSamplingRulesResult| {
+                Some(GetSamplingRulesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_sampling_rules`, streaming the `SamplingRuleRecords` of every page of results.
+    pub fn get_sampling_rules_items(
+        &self,
+        input: GetSamplingRulesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<SamplingRuleRecord, GetSamplingRulesError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_sampling_rules_pages(input),
+            |output: GetSamplingRulesResult| output.sampling_rule_records.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_sampling_statistic_summaries`, streaming every page of results.
+    pub fn get_sampling_statistic_summaries_pages(
+        &self,
+        input: GetSamplingStatisticSummariesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        GetSamplingStatisticSummariesResult,
+        GetSamplingStatisticSummariesError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: XRayClient, input: GetSamplingStatisticSummariesRequest| async move {
+                client.get_sampling_statistic_summaries(input).await
+            },
+            |_input: &GetSamplingStatisticSummariesRequest,
+             output: &GetSamplingStatisticSummariesResult| {
+                Some(GetSamplingStatisticSummariesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_sampling_statistic_summaries`, streaming the `SamplingStatisticSummaries` of every page of results.
+    pub fn get_sampling_statistic_summaries_items(
+        &self,
+        input: GetSamplingStatisticSummariesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        SamplingStatisticSummary,
+        GetSamplingStatisticSummariesError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.get_sampling_statistic_summaries_pages(input),
+            |output: GetSamplingStatisticSummariesResult| {
+                output.sampling_statistic_summaries.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_service_graph`, streaming every page of results.
+    pub fn get_service_graph_pages(
+        &self,
+        input: GetServiceGraphRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetServiceGraphResult, GetServiceGraphError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: XRayClient, input: GetServiceGraphRequest| async move {
+                client.get_service_graph(input).await
+            },
+            |input: &GetServiceGraphRequest, output: &GetServiceGraphResult| {
+                Some(GetServiceGraphRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_service_graph`, streaming the `Services` of every page of results.
+    pub fn get_service_graph_items(
+        &self,
+        input: GetServiceGraphRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Service, GetServiceGraphError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_service_graph_pages(input),
+            |output: GetServiceGraphResult| output.services.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_time_series_service_statistics`, streaming every page of results.
+    pub fn get_time_series_service_statistics_pages(
+        &self,
+        input: GetTimeSeriesServiceStatisticsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        GetTimeSeriesServiceStatisticsResult,
+        GetTimeSeriesServiceStatisticsError,
+    > {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: XRayClient, input: GetTimeSeriesServiceStatisticsRequest| async move {
+                client.get_time_series_service_statistics(input).await
+            },
+            |input: &GetTimeSeriesServiceStatisticsRequest,
+             output: &GetTimeSeriesServiceStatisticsResult| {
+                Some(GetTimeSeriesServiceStatisticsRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_time_series_service_statistics`, streaming the `TimeSeriesServiceStatistics` of every page of results.
+    pub fn get_time_series_service_statistics_items(
+        &self,
+        input: GetTimeSeriesServiceStatisticsRequest,
+    ) -> rusoto_core::pagination::RusotoStream<
+        TimeSeriesServiceStatistics,
+        GetTimeSeriesServiceStatisticsError,
+    > {
+        rusoto_core::pagination::paginate_items(
+            self.get_time_series_service_statistics_pages(input),
+            |output: GetTimeSeriesServiceStatisticsResult| {
+                output.time_series_service_statistics.unwrap_or_default()
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_trace_graph`, streaming every page of results.
+    pub fn get_trace_graph_pages(
+        &self,
+        input: GetTraceGraphRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetTraceGraphResult, GetTraceGraphError> {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: XRayClient, input: GetTraceGraphRequest| async move {
+                client.get_trace_graph(input).await
+            },
+            |input: &GetTraceGraphRequest, output: &GetTraceGraphResult| {
+                Some(GetTraceGraphRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_trace_graph`, streaming the `Services` of every page of results.
+    pub fn get_trace_graph_items(
+        &self,
+        input: GetTraceGraphRequest,
+    ) -> rusoto_core::pagination::RusotoStream<Service, GetTraceGraphError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_trace_graph_pages(input),
+            |output: GetTraceGraphResult| output.services.unwrap_or_default(),
+        )
+    }
+
+    /// Auto-paginating version of `get_trace_summaries`, streaming every page of results.
+    pub fn get_trace_summaries_pages(
+        &self,
+        input: GetTraceSummariesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<GetTraceSummariesResult, GetTraceSummariesError>
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: XRayClient, input: GetTraceSummariesRequest| async move {
+                client.get_trace_summaries(input).await
+            },
+            |input: &GetTraceSummariesRequest, output: &GetTraceSummariesResult| {
+                Some(GetTraceSummariesRequest {
+                    next_token: Some(output.next_token.clone()?),
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Auto-paginating version of `get_trace_summaries`, streaming the `TraceSummaries` of every page of results.
+    pub fn get_trace_summaries_items(
+        &self,
+        input: GetTraceSummariesRequest,
+    ) -> rusoto_core::pagination::RusotoStream<TraceSummary, GetTraceSummariesError> {
+        rusoto_core::pagination::paginate_items(
+            self.get_trace_summaries_pages(input),
+            |output: GetTraceSummariesResult| output.trace_summaries.unwrap_or_default(),
+        )
+    }
+}
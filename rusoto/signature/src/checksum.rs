@@ -0,0 +1,180 @@
+//! Checksums of payloads, sent in the `x-amz-checksum-*` headers.
+//!
+//! S3 verifies them on uploads, the headers being trailers of payloads signed chunk by chunk.
+
+use crc32fast::Hasher as Crc32;
+use sha1::Sha1;
+use sha2::{Digest, Sha256};
+
+/// Algorithms computing the checksums of payloads.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChecksumAlgorithm {
+    /// CRC-32, as used by zlib
+    Crc32,
+    /// CRC-32C, with the Castagnoli polynomial
+    Crc32c,
+    /// SHA-1
+    Sha1,
+    /// SHA-256
+    Sha256,
+}
+
+impl ChecksumAlgorithm {
+    /// Name of the header holding checksums computed with this algorithm.
+    pub fn header_name(self) -> &'static str {
+        match self {
+            ChecksumAlgorithm::Crc32 => "x-amz-checksum-crc32",
+            ChecksumAlgorithm::Crc32c => "x-amz-checksum-crc32c",
+            ChecksumAlgorithm::Sha1 => "x-amz-checksum-sha1",
+            ChecksumAlgorithm::Sha256 => "x-amz-checksum-sha256",
+        }
+    }
+
+    // length of the checksums, once base64 encoded
+    pub(crate) fn encoded_len(self) -> usize {
+        match self {
+            ChecksumAlgorithm::Crc32 | ChecksumAlgorithm::Crc32c => 8,
+            ChecksumAlgorithm::Sha1 => 28,
+            ChecksumAlgorithm::Sha256 => 44,
+        }
+    }
+}
+
+/// Computes the checksum of a payload, fed a chunk at a time.
+#[derive(Clone)]
+pub struct Checksum {
+    state: State,
+}
+
+#[derive(Clone)]
+enum State {
+    Crc32(Crc32),
+    Crc32c(u32),
+    Sha1(Sha1),
+    Sha256(Sha256),
+}
+
+impl Checksum {
+    /// Creates the checksum of an empty payload.
+    pub fn new(algorithm: ChecksumAlgorithm) -> Checksum {
+        let state = match algorithm {
+            ChecksumAlgorithm::Crc32 => State::Crc32(Crc32::new()),
+            ChecksumAlgorithm::Crc32c => State::Crc32c(0),
+            ChecksumAlgorithm::Sha1 => State::Sha1(Sha1::new()),
+            ChecksumAlgorithm::Sha256 => State::Sha256(Sha256::new()),
+        };
+        Checksum { state }
+    }
+
+    /// Returns the algorithm computing the checksum.
+    pub fn algorithm(&self) -> ChecksumAlgorithm {
+        match self.state {
+            State::Crc32(_) => ChecksumAlgorithm::Crc32,
+            State::Crc32c(_) => ChecksumAlgorithm::Crc32c,
+            State::Sha1(_) => ChecksumAlgorithm::Sha1,
+            State::Sha256(_) => ChecksumAlgorithm::Sha256,
+        }
+    }
+
+    /// Feeds the next chunk of the payload.
+    pub fn update(&mut self, data: &[u8]) {
+        match self.state {
+            State::Crc32(ref mut crc) => crc.update(data),
+            State::Crc32c(ref mut crc) => *crc = crc32c::crc32c_append(*crc, data),
+            State::Sha1(ref mut sha1) => sha1.input(data),
+            State::Sha256(ref mut sha256) => sha256.input(data),
+        }
+    }
+
+    /// Returns the checksum of the payload fed, CRCs being big endian.
+    pub fn finalize(self) -> Vec<u8> {
+        match self.state {
+            State::Crc32(crc) => crc.finalize().to_be_bytes().to_vec(),
+            State::Crc32c(crc) => crc.to_be_bytes().to_vec(),
+            State::Sha1(sha1) => sha1.result().to_vec(),
+            State::Sha256(sha256) => sha256.result().to_vec(),
+        }
+    }
+
+    /// Returns the checksum of the payload fed, base64 encoded as in the `x-amz-checksum-*`
+    /// headers.
+    pub fn finalize_base64(self) -> String {
+        base64::encode(&self.finalize())
+    }
+}
+
+/// Computes the checksum of `data`, base64 encoded.
+pub fn checksum(algorithm: ChecksumAlgorithm, data: &[u8]) -> String {
+    let mut checksum = Checksum::new(algorithm);
+    checksum.update(data);
+    checksum.finalize_base64()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex_checksum(algorithm: ChecksumAlgorithm, data: &[u8]) -> String {
+        let mut checksum = Checksum::new(algorithm);
+        checksum.update(data);
+        hex::encode(checksum.finalize())
+    }
+
+    #[test]
+    fn computes_crcs() {
+        assert_eq!(
+            hex_checksum(ChecksumAlgorithm::Crc32, b"123456789"),
+            "cbf43926"
+        );
+        assert_eq!(
+            hex_checksum(ChecksumAlgorithm::Crc32c, b"123456789"),
+            "e3069283"
+        );
+        // the payload of the S3 documentation's examples of trailing checksums
+        assert_eq!(
+            checksum(ChecksumAlgorithm::Crc32c, &[b'a'; 66560]),
+            "sOO8/Q=="
+        );
+    }
+
+    #[test]
+    fn computes_sha1() {
+        assert_eq!(
+            hex_checksum(ChecksumAlgorithm::Sha1, b"abc"),
+            "a9993e364706816aba3e25717850c26c9cd0d89d"
+        );
+        assert_eq!(
+            hex_checksum(ChecksumAlgorithm::Sha1, b""),
+            "da39a3ee5e6b4b0d3255bfef95601890afd80709"
+        );
+        // spans several blocks, fed in chunks not lining up with them
+        let mut sha1 = Checksum::new(ChecksumAlgorithm::Sha1);
+        for _ in 0..1000 {
+            sha1.update(&[b'a'; 1000]);
+        }
+        assert_eq!(
+            hex::encode(sha1.finalize()),
+            "34aa973cd4c4daa4f61eeb2bdbad27316534016f"
+        );
+    }
+
+    #[test]
+    fn computes_sha256() {
+        assert_eq!(
+            hex_checksum(ChecksumAlgorithm::Sha256, b"abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn encodes_checksums() {
+        for &algorithm in &[
+            ChecksumAlgorithm::Crc32,
+            ChecksumAlgorithm::Crc32c,
+            ChecksumAlgorithm::Sha1,
+            ChecksumAlgorithm::Sha256,
+        ] {
+            assert_eq!(checksum(algorithm, b"abc").len(), algorithm.encoded_len());
+        }
+    }
+}
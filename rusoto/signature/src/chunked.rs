@@ -0,0 +1,321 @@
+//! Streaming payloads signed chunk by chunk.
+//!
+//! Follows [Transferring Payload in Multiple Chunks](https://docs.aws.amazon.com/AmazonS3/latest/API/sigv4-streaming.html):
+//! the payload is sent with the `aws-chunked` content encoding, each chunk carrying a signature
+//! chained from the signature of the previous one, the first chained from the seed signature of
+//! the request itself.
+//!
+//! The payload may be followed by the [trailing checksum](https://docs.aws.amazon.com/AmazonS3/latest/API/sigv4-streaming-trailers.html)
+//! of its bytes, signed after the empty chunk ending it.
+
+use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use bytes::{BufMut, Bytes, BytesMut};
+use futures::Stream;
+use hmac::Mac;
+use pin_project::pin_project;
+
+use crate::checksum::{Checksum, ChecksumAlgorithm};
+use crate::signature::{hmac, to_hexdigest, EMPTY_SHA256_HASH};
+use crate::stream::ByteStream;
+
+/// Payload string to use for payloads signed chunk by chunk
+pub static STREAMING_PAYLOAD: &str = "STREAMING-AWS4-HMAC-SHA256-PAYLOAD";
+
+/// Payload string to use for payloads signed chunk by chunk, followed by a trailing checksum
+pub static STREAMING_PAYLOAD_TRAILER: &str = "STREAMING-AWS4-HMAC-SHA256-PAYLOAD-TRAILER";
+
+/// Size of the chunks payloads are split in, all but the last one
+pub(crate) const CHUNK_SIZE: usize = 64 * 1024;
+
+// length of the signature of a chunk, hex encoded
+const SIGNATURE_LEN: usize = 64;
+
+/// Signs the chunks of a payload, each one with the signature of the previous one.
+#[derive(Clone)]
+pub(crate) struct ChunkSigner {
+    signing_key: Vec<u8>,
+    timestamp: String,
+    scope: String,
+    previous_signature: String,
+}
+
+impl ChunkSigner {
+    /// Create a signer for the chunks following the seed signature of a request, signed with
+    /// `signing_key` at `timestamp` for `scope`.
+    pub fn new(
+        signing_key: Vec<u8>,
+        timestamp: String,
+        scope: String,
+        seed_signature: String,
+    ) -> ChunkSigner {
+        ChunkSigner {
+            signing_key,
+            timestamp,
+            scope,
+            previous_signature: seed_signature,
+        }
+    }
+
+    /// Sign the next chunk, an empty one ending the payload.
+    pub fn sign(&mut self, chunk: &[u8]) -> String {
+        let string_to_sign = format!(
+            "AWS4-HMAC-SHA256-PAYLOAD\n{}\n{}\n{}\n{}\n{}",
+            self.timestamp,
+            self.scope,
+            self.previous_signature,
+            EMPTY_SHA256_HASH,
+            to_hexdigest(chunk)
+        );
+        let signature = hex::encode(
+            hmac(&self.signing_key, string_to_sign.as_bytes())
+                .result()
+                .code()
+                .as_ref(),
+        );
+        self.previous_signature = signature.clone();
+        signature
+    }
+
+    /// Sign the next chunk and frame it as sent.
+    pub fn encode(&mut self, chunk: &[u8]) -> Bytes {
+        let signature = self.sign(chunk);
+        let mut encoded = BytesMut::with_capacity(encoded_chunk_len(chunk.len()));
+        encoded.put(format!("{:x};chunk-signature={}\r\n", chunk.len(), signature).as_bytes());
+        encoded.put(chunk);
+        encoded.put(&b"\r\n"[..]);
+        encoded.freeze()
+    }
+
+    /// Sign the trailing headers following the empty chunk, as sent but for the `\r`s.
+    pub fn sign_trailer(&mut self, trailer: &str) -> String {
+        let string_to_sign = format!(
+            "AWS4-HMAC-SHA256-TRAILER\n{}\n{}\n{}\n{}",
+            self.timestamp,
+            self.scope,
+            self.previous_signature,
+            to_hexdigest(trailer)
+        );
+        let signature = hex::encode(
+            hmac(&self.signing_key, string_to_sign.as_bytes())
+                .result()
+                .code()
+                .as_ref(),
+        );
+        self.previous_signature = signature.clone();
+        signature
+    }
+
+    /// Sign the empty chunk ending the payload and the trailing `checksum` of the payload, and
+    /// frame them as sent.
+    pub fn encode_trailer(&mut self, checksum: Checksum) -> Bytes {
+        let header = checksum.algorithm().header_name();
+        let trailer = format!("{}:{}\n", header, checksum.finalize_base64());
+        let chunk_signature = self.sign(&[]);
+        let trailer_signature = self.sign_trailer(&trailer);
+        Bytes::from(format!(
+            "0;chunk-signature={}\r\n{}\r\nx-amz-trailer-signature:{}\r\n\r\n",
+            chunk_signature,
+            trailer.trim_end(),
+            trailer_signature
+        ))
+    }
+}
+
+/// Length of a chunk holding `len` bytes of the payload once framed.
+fn encoded_chunk_len(len: usize) -> usize {
+    format!("{:x}", len).len() + ";chunk-signature=".len() + SIGNATURE_LEN + len + 4
+}
+
+/// Length of a payload of `len` bytes once split in chunks of `chunk_size` bytes and framed.
+pub(crate) fn encoded_len(len: usize, chunk_size: usize) -> usize {
+    let remainder = len % chunk_size;
+    let mut encoded = (len / chunk_size) * encoded_chunk_len(chunk_size) + encoded_chunk_len(0);
+    if remainder > 0 {
+        encoded += encoded_chunk_len(remainder);
+    }
+    encoded
+}
+
+/// Length of the checksum computed with `algorithm` and of its signature, once framed after the
+/// payload.
+pub(crate) fn encoded_trailer_len(algorithm: ChecksumAlgorithm) -> usize {
+    algorithm.header_name().len()
+        + 1
+        + algorithm.encoded_len()
+        + "\r\nx-amz-trailer-signature:".len()
+        + SIGNATURE_LEN
+        + 2
+}
+
+/// Stream splitting a payload in chunks of the same size, but for the last one, and emitting them
+/// signed and framed, followed by the empty chunk ending the payload and, if any, the trailing
+/// checksum of the payload.
+#[pin_project]
+pub(crate) struct AwsChunkedStream {
+    #[pin]
+    inner: ByteStream,
+    signer: ChunkSigner,
+    chunk_size: usize,
+    checksum: Option<Checksum>,
+    buffer: BytesMut,
+    inner_done: bool,
+    done: bool,
+}
+
+impl AwsChunkedStream {
+    pub fn new(inner: ByteStream, signer: ChunkSigner, chunk_size: usize) -> AwsChunkedStream {
+        AwsChunkedStream {
+            inner,
+            signer,
+            chunk_size,
+            checksum: None,
+            buffer: BytesMut::new(),
+            inner_done: false,
+            done: false,
+        }
+    }
+
+    /// Follows the payload with its checksum, computed with `algorithm`.
+    pub fn with_checksum(mut self, algorithm: ChecksumAlgorithm) -> AwsChunkedStream {
+        self.checksum = Some(Checksum::new(algorithm));
+        self
+    }
+}
+
+impl Stream for AwsChunkedStream {
+    type Item = Result<Bytes, io::Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        loop {
+            if *this.done {
+                return Poll::Ready(None);
+            }
+            if this.buffer.len() >= *this.chunk_size {
+                let chunk = this.buffer.split_to(*this.chunk_size);
+                return Poll::Ready(Some(Ok(this.signer.encode(&chunk))));
+            }
+            if *this.inner_done {
+                if this.buffer.is_empty() {
+                    *this.done = true;
+                    let last = match this.checksum.take() {
+                        Some(checksum) => this.signer.encode_trailer(checksum),
+                        None => this.signer.encode(&[]),
+                    };
+                    return Poll::Ready(Some(Ok(last)));
+                }
+                let chunk = this.buffer.split();
+                return Poll::Ready(Some(Ok(this.signer.encode(&chunk))));
+            }
+            match futures::ready!(this.inner.as_mut().poll_next(cx)) {
+                Some(Ok(bytes)) => {
+                    if let Some(checksum) = this.checksum.as_mut() {
+                        checksum.update(&bytes);
+                    }
+                    this.buffer.put(bytes)
+                }
+                Some(Err(e)) => return Poll::Ready(Some(Err(e))),
+                None => *this.inner_done = true,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::{stream, TryStreamExt};
+
+    // https://docs.aws.amazon.com/AmazonS3/latest/API/sigv4-streaming.html
+    const SEED_SIGNATURE: &str = "4f232c4386841ef735655705268965c44a0e4690baa4adea153f7db9fa80a0a9";
+    const CHUNK_SIGNATURES: [&str; 3] = [
+        "ad80c730a21e5b8d04586a2213dd63b9a0e99e0e2307b0ade35a65485a288648",
+        "0055627c9e194cb4542bae2aa5492e3c1575bbb81b612b7d234b86a503ef5497",
+        "b6c6ea8a5354eaf15b3cb7646744f4275b71ea724fed81ceb9323e279d449df9",
+    ];
+
+    fn example_signer() -> ChunkSigner {
+        let date = time::Date::try_from_ymd(2013, 5, 24).unwrap();
+        ChunkSigner::new(
+            crate::signature::signing_key(
+                "wJalrXUtnFEMI/K7MDENG/bPxRfiCYEXAMPLEKEY",
+                date,
+                "us-east-1",
+                "s3",
+            ),
+            "20130524T000000Z".to_owned(),
+            "20130524/us-east-1/s3/aws4_request".to_owned(),
+            SEED_SIGNATURE.to_owned(),
+        )
+    }
+
+    #[test]
+    fn signs_chunks_from_seed_signature() {
+        let mut signer = example_signer();
+        assert_eq!(signer.sign(&[b'a'; 65536]), CHUNK_SIGNATURES[0]);
+        assert_eq!(signer.sign(&[b'a'; 1024]), CHUNK_SIGNATURES[1]);
+        assert_eq!(signer.sign(&[]), CHUNK_SIGNATURES[2]);
+    }
+
+    #[test]
+    fn signs_trailing_checksum() {
+        // https://docs.aws.amazon.com/AmazonS3/latest/API/sigv4-streaming-trailers.html
+        let mut signer = example_signer();
+        signer.previous_signature =
+            "106e2a8a18243abcf37539882f36619c00e2dfc72633413f02d3b74544bfeb8e".to_owned();
+        assert_eq!(
+            signer.sign(&[b'a'; 65536]),
+            "b474d8862b1487a5145d686f57f013e54db672cee1c953b3010fb58501ef5aa2"
+        );
+        assert_eq!(
+            signer.sign(&[b'a'; 1024]),
+            "1c1344b170168f8e65b41376b44b20fe354e373826ccbbe2c1d40a8cae51e5c7"
+        );
+        assert_eq!(
+            signer.sign(&[]),
+            "2ca2aba2005185cf7159c6277faf83795951dd77a3a99e6e65d5c9f85863f992"
+        );
+        assert_eq!(
+            signer.sign_trailer("x-amz-checksum-crc32c:sOO8/Q==\n"),
+            "d81f82fc3505edab99d459891051a732e8730629a2e4a59689829ca17fe2e435"
+        );
+    }
+
+    #[test]
+    fn computes_encoded_len() {
+        assert_eq!(encoded_len(66560, 65536), 66824);
+        assert_eq!(encoded_len(65536, 65536), 88 + 65536 + 2 + 86);
+        assert_eq!(encoded_len(0, 65536), 86);
+    }
+
+    #[tokio::test]
+    async fn emits_signed_chunks() {
+        // chunks of the payload not lining up with the chunks sent
+        let payload = vec![
+            Ok(Bytes::from(vec![b'a'; 40000])),
+            Ok(Bytes::from(vec![b'a'; 26560])),
+        ];
+        let stream = AwsChunkedStream::new(
+            ByteStream::new(stream::iter(payload)),
+            example_signer(),
+            65536,
+        );
+        let chunks: Vec<Bytes> = stream.try_collect().await.unwrap();
+
+        assert_eq!(chunks.len(), 3);
+        let lengths = [65536, 1024, 0];
+        for ((chunk, signature), len) in chunks.iter().zip(&CHUNK_SIGNATURES).zip(&lengths) {
+            let header = format!("{:x};chunk-signature={}\r\n", len, signature);
+            assert!(chunk.starts_with(header.as_bytes()));
+            assert!(chunk[header.len()..header.len() + len]
+                .iter()
+                .all(|b| *b == b'a'));
+            assert_eq!(&chunk[header.len() + len..], b"\r\n");
+        }
+        let total: usize = chunks.iter().map(Bytes::len).sum();
+        assert_eq!(total, encoded_len(66560, 65536));
+    }
+}
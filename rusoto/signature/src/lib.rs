@@ -11,9 +11,13 @@
 #![cfg_attr(not(feature = "unstable"), deny(warnings))]
 #![cfg_attr(not(feature = "unstable"), allow(clippy::type_complexity))]
 pub extern crate rusoto_credential as credential;
+pub mod checksum;
+mod chunked;
 pub mod region;
 pub mod signature;
+mod sigv4a;
 pub mod stream;
+pub use checksum::ChecksumAlgorithm;
 pub use region::Region;
-pub use signature::{SignedRequest, SignedRequestPayload};
+pub use signature::{SignedRequest, SignedRequestPayload, SigningAlgorithm};
 pub use stream::ByteStream;
@@ -33,6 +33,20 @@ use std::str::FromStr;
 ///     };
 /// ```
 ///
+/// # Other AWS regions
+///
+/// `Region::Named` names regions launched after this version of Rusoto, whose endpoints are
+/// resolved by clients from their names as those of the other regions are. Parsing the name of
+/// such a region makes one.
+///
+/// ```
+///     # use rusoto_signature::Region;
+///     assert_eq!(
+///         "ap-southeast-3".parse(),
+///         Ok(Region::Named("ap-southeast-3".to_owned()))
+///     );
+/// ```
+///
 /// # Caveats
 ///
 /// `CnNorth1` is currently untested due to Rusoto maintainers not having access to AWS China.
@@ -107,6 +121,10 @@ pub enum Region {
     /// Region that covers North-Western  part of China
     CnNorthwest1,
 
+    /// Region known by its name only (e.g. `"ap-southeast-3"`), its endpoints being resolved
+    /// from it
+    Named(String),
+
     /// Specifies a custom region, such as a local Ceph target
     Custom {
         /// Name of the endpoint (e.g. `"eu-east-2"`).
@@ -154,11 +172,25 @@ impl Region {
             Region::UsGovWest1 => "us-gov-west-1",
             Region::CnNorth1 => "cn-north-1",
             Region::CnNorthwest1 => "cn-northwest-1",
+            Region::Named(ref name) => name,
             Region::Custom { ref name, .. } => name,
         }
     }
 }
 
+/// Whether `name` is shaped like the names of AWS regions, such as `ap-southeast-3` or
+/// `us-gov-east-1`.
+fn is_region_name(name: &str) -> bool {
+    let parts: Vec<&str> = name.split('-').collect();
+    parts.len() >= 3
+        && parts[0].len() == 2
+        && parts[..parts.len() - 1]
+            .iter()
+            .all(|part| !part.is_empty() && part.bytes().all(|b| b.is_ascii_lowercase()))
+        && parts[parts.len() - 1].bytes().all(|b| b.is_ascii_digit())
+        && !parts[parts.len() - 1].is_empty()
+}
+
 /// An error produced when attempting to convert a `str` into a `Region` fails.
 #[derive(Debug, PartialEq)]
 pub struct ParseRegionError {
@@ -252,6 +284,7 @@ impl FromStr for Region {
             "us-gov-west-1" | "usgovwest1" => Ok(Region::UsGovWest1),
             "cn-north-1" | "cnnorth1" => Ok(Region::CnNorth1),
             "cn-northwest-1" | "cnnorthwest1" => Ok(Region::CnNorthwest1),
+            s if is_region_name(s) => Ok(Region::Named(s.to_owned())),
             s => Err(ParseRegionError::new(s)),
         }
     }
@@ -325,6 +358,17 @@ mod tests {
         assert_eq!("us-gov-west-1".parse(), Ok(Region::UsGovWest1));
         assert_eq!("cn-north-1".parse(), Ok(Region::CnNorth1));
         assert_eq!("cn-northwest-1".parse(), Ok(Region::CnNorthwest1));
+        assert_eq!(
+            "AP-SOUTHEAST-3".parse(),
+            Ok(Region::Named("ap-southeast-3".to_owned()))
+        );
+        assert_eq!(
+            "us-isob-east-1".parse(),
+            Ok(Region::Named("us-isob-east-1".to_owned()))
+        );
+        assert!("eu-west".parse::<Region>().is_err());
+        assert!("eu-west-x".parse::<Region>().is_err());
+        assert!("europe-west-1".parse::<Region>().is_err());
     }
 
     #[test]
@@ -399,6 +443,16 @@ mod tests {
         assert_eq!(r, from_json);
     }
 
+    #[test]
+    fn region_serialize_deserialize_named() {
+        let r = Region::Named("ap-southeast-3".to_owned());
+        let region_serialized = serde_json::to_string(&r).unwrap();
+        assert_eq!(region_serialized, "[\"ap-southeast-3\",null]");
+
+        let from_json = serde_json::de::from_str(&region_serialized).unwrap();
+        assert_eq!(r, from_json);
+    }
+
     #[test]
     fn region_serialize_deserialize_standard_only_region_name() {
         let r = Region::UsWest2;
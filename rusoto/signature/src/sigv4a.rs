@@ -0,0 +1,108 @@
+//! AWS Signature Version 4A.
+//!
+//! Requests are signed with ECDSA over P-256, the key being derived from the AWS credentials, so
+//! the same signature is valid in all the regions of the `x-amz-region-set` header rather than
+//! just one.
+
+use hmac::Mac;
+use p256::ecdsa::signature::Signer;
+use p256::ecdsa::{Signature, SigningKey};
+
+use crate::credential::AwsCredentials;
+use crate::signature::hmac;
+
+/// Name of the algorithm, used in the authorization header and the derivation of keys
+pub static ALGORITHM: &str = "AWS4-ECDSA-P256-SHA256";
+
+// order of the P-256 group, minus 2
+const N_MINUS_2: [u8; 32] = [
+    0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+    0xbc, 0xe6, 0xfa, 0xad, 0xa7, 0x17, 0x9e, 0x84, 0xf3, 0xb9, 0xca, 0xc2, 0xfc, 0x63, 0x25, 0x4f,
+];
+
+/// Derives the private key signing requests for `creds`.
+///
+/// Candidates are computed with HMAC-SHA256 in counter mode (NIST SP 800-108) until one is lower
+/// than the order of the group minus one, the key being the candidate plus one.
+pub(crate) fn signing_key(creds: &AwsCredentials) -> SigningKey {
+    let secret = format!("AWS4A{}", creds.aws_secret_access_key());
+    for counter in 1..=254u8 {
+        let mut fixed_input = Vec::new();
+        fixed_input.extend_from_slice(&1u32.to_be_bytes());
+        fixed_input.extend_from_slice(ALGORITHM.as_bytes());
+        fixed_input.push(0);
+        fixed_input.extend_from_slice(creds.aws_access_key_id().as_bytes());
+        fixed_input.push(counter);
+        fixed_input.extend_from_slice(&256u32.to_be_bytes());
+
+        let mut candidate = [0u8; 32];
+        candidate.copy_from_slice(
+            hmac(secret.as_bytes(), &fixed_input)
+                .result()
+                .code()
+                .as_ref(),
+        );
+        // big endian, so comparing the bytes compares the numbers
+        if candidate > N_MINUS_2 {
+            continue;
+        }
+        // can't overflow, the candidate being lower than the order of the group
+        for byte in candidate.iter_mut().rev() {
+            let (sum, carry) = byte.overflowing_add(1);
+            *byte = sum;
+            if !carry {
+                break;
+            }
+        }
+        return SigningKey::from_bytes(&candidate)
+            .expect("keys lower than the order of the group are valid");
+    }
+    // each candidate has a chance of about 2^-32 to be rejected
+    unreachable!("failed to derive a signing key")
+}
+
+/// Signs `string_to_sign`, returning the DER encoded signature as hex.
+pub(crate) fn sign_string(string_to_sign: &str, key: &SigningKey) -> String {
+    let signature: Signature = key.sign(string_to_sign.as_bytes());
+    hex::encode(signature.to_der().as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use p256::ecdsa::signature::Verifier;
+    use p256::ecdsa::VerifyingKey;
+
+    #[test]
+    fn derives_signing_keys() {
+        // aws-c-auth's signing test suite
+        let creds = AwsCredentials::new(
+            "AKIDEXAMPLE",
+            "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY",
+            None,
+            None,
+        );
+        let public_key = VerifyingKey::from(&signing_key(&creds)).to_encoded_point(false);
+
+        assert_eq!(
+            hex::encode(public_key.x().unwrap()),
+            "b6618f6a65740a99e650b33b6b4b5bd0d43b176d721a3edfea7e7d2d56d936b1"
+        );
+        assert_eq!(
+            hex::encode(public_key.y().unwrap()),
+            "865ed22a7eadc9c5cb9d2cbaca1b3699139fedc5043dc6661864218330c8e518"
+        );
+    }
+
+    #[test]
+    fn signs_strings() {
+        let creds = AwsCredentials::new("AKIDEXAMPLE", "secret", None, None);
+        let key = signing_key(&creds);
+        let signature = sign_string("string to sign", &key);
+
+        let signature = Signature::from_der(&hex::decode(signature).unwrap()).unwrap();
+        VerifyingKey::from(&key)
+            .verify(b"string to sign", &signature)
+            .expect("valid signature");
+    }
+}
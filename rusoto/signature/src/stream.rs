@@ -12,6 +12,8 @@ use tokio::io::AsyncRead;
 #[pin_project]
 pub struct ByteStream {
     size_hint: Option<usize>,
+    // the content of streams made of a buffer
+    buffer: Option<Bytes>,
     #[pin]
     inner: Pin<Box<dyn Stream<Item = Result<Bytes, io::Error>> + Send + Sync + 'static>>,
 }
@@ -24,6 +26,21 @@ impl ByteStream {
     {
         ByteStream {
             size_hint: None,
+            buffer: None,
+            inner: Box::pin(stream),
+        }
+    }
+
+    /// Create a new `ByteStream` by wrapping a `futures` stream of `size_hint` bytes.
+    ///
+    /// Knowing the size of the stream lets requests send it with a content-length.
+    pub fn new_with_size<S>(stream: S, size_hint: usize) -> ByteStream
+    where
+        S: Stream<Item = Result<Bytes, io::Error>> + Send + Sync + 'static,
+    {
+        ByteStream {
+            size_hint: Some(size_hint),
+            buffer: None,
             inner: Box::pin(stream),
         }
     }
@@ -32,6 +49,11 @@ impl ByteStream {
         self.size_hint
     }
 
+    /// The content of the stream, if it's made of a buffer.
+    pub(crate) fn buffer(&self) -> Option<&Bytes> {
+        self.buffer.as_ref()
+    }
+
     /// Return an implementation of `AsyncRead` that uses async i/o to consume the stream.
     pub fn into_async_read(self) -> impl AsyncRead + Send {
         ImplAsyncRead::new(self.inner)
@@ -45,9 +67,11 @@ impl ByteStream {
 
 impl From<Vec<u8>> for ByteStream {
     fn from(buf: Vec<u8>) -> ByteStream {
+        let buf = Bytes::from(buf);
         ByteStream {
             size_hint: Some(buf.len()),
-            inner: Box::pin(stream::once(async move { Ok(Bytes::from(buf)) })),
+            buffer: Some(buf.clone()),
+            inner: Box::pin(stream::once(future::ready(Ok(buf)))),
         }
     }
 }
@@ -23,6 +23,10 @@ pub struct ServiceDefinition {
     #[serde(deserialize_with = "ShapesMap::deserialize_shapes_map")]
     pub shapes: BTreeMap<String, Shape>,
     pub version: Option<String>,
+    #[serde(skip)]
+    pub paginators: BTreeMap<String, Paginator>,
+    #[serde(skip)]
+    pub waiters: BTreeMap<String, Waiter>,
 }
 
 impl ServiceDefinition {
@@ -32,7 +36,24 @@ impl ServiceDefinition {
 
         let input_file = BufReader::new(File::open(&input_path)?);
 
-        let service: ServiceDefinition = serde_json::from_reader(input_file)?;
+        let mut service: ServiceDefinition = serde_json::from_reader(input_file)?;
+
+        // not every service has paginated operations
+        let paginators_path = Path::new(BOTOCORE_DIR)
+            .join(format!("{}/{}/paginators-1.json", name, protocol_version));
+        if paginators_path.exists() {
+            let paginators_file = BufReader::new(File::open(&paginators_path)?);
+            let paginators: Paginators = serde_json::from_reader(paginators_file)?;
+            service.paginators = paginators.pagination;
+        }
+
+        let waiters_path =
+            Path::new(BOTOCORE_DIR).join(format!("{}/{}/waiters-2.json", name, protocol_version));
+        if waiters_path.exists() {
+            let waiters_file = BufReader::new(File::open(&waiters_path)?);
+            let waiters: Waiters = serde_json::from_reader(waiters_file)?;
+            service.waiters = waiters.waiters;
+        }
 
         Ok(service)
     }
@@ -77,6 +98,12 @@ impl ServiceDefinition {
     }
 }
 
+/// Loads `endpoints.json`, the endpoints of services in the regions of each partition.
+pub fn load_endpoints() -> Result<serde_json::Value, Box<dyn error::Error>> {
+    let input_file = BufReader::new(File::open(Path::new(BOTOCORE_DIR).join("endpoints.json"))?);
+    Ok(serde_json::from_reader(input_file)?)
+}
+
 #[derive(Debug, Deserialize)]
 pub struct HttpRequest {
     pub method: String,
@@ -128,6 +155,8 @@ pub struct HttpError {
 pub struct Member {
     pub deprecated: Option<bool>,
     pub documentation: Option<String>,
+    pub eventheader: Option<bool>,
+    pub eventpayload: Option<bool>,
     pub flattened: Option<bool>,
     pub location: Option<String>,
     #[serde(rename = "locationName")]
@@ -135,6 +164,8 @@ pub struct Member {
     #[serde(deserialize_with = "ShapeName::deserialize_shape_name")]
     pub shape: String,
     pub streaming: Option<bool>,
+    #[serde(rename = "timestampFormat")]
+    pub timestamp_format: Option<String>,
     #[serde(rename = "xmlAttribute")]
     pub xml_attribute: Option<bool>,
     #[serde(rename = "xmlNamespace")]
@@ -149,6 +180,14 @@ impl Member {
     pub fn streaming(&self) -> bool {
         self.streaming.unwrap_or(false)
     }
+
+    pub fn eventheader(&self) -> bool {
+        self.eventheader.unwrap_or(false)
+    }
+
+    pub fn eventpayload(&self) -> bool {
+        self.eventpayload.unwrap_or(false)
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -202,6 +241,7 @@ pub struct Shape {
     pub aws_box: Option<bool>,
     pub documentation: Option<String>,
     pub error: Option<HttpError>,
+    pub eventstream: Option<bool>,
     pub exception: Option<bool>,
     pub fault: Option<bool>,
     pub flattened: Option<bool>,
@@ -271,6 +311,14 @@ impl<'a> Shape {
     pub fn exception(&self) -> bool {
         self.exception.unwrap_or(false)
     }
+
+    pub fn eventstream(&self) -> bool {
+        self.eventstream.unwrap_or(false)
+    }
+
+    pub fn is_enum(&self) -> bool {
+        self.shape_type == ShapeType::String && self.shape_enum.is_some()
+    }
 }
 
 #[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
@@ -344,6 +392,85 @@ impl<'a> Operation {
     }
 }
 
+#[derive(Debug, Deserialize)]
+pub struct Paginators {
+    pub pagination: BTreeMap<String, Paginator>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Paginator {
+    pub input_token: PaginatorKeys,
+    pub output_token: PaginatorKeys,
+    pub limit_key: Option<String>,
+    pub more_results: Option<String>,
+    pub result_key: Option<PaginatorKeys>,
+}
+
+// botocore uses a plain string for paginators with a single token or result key
+// and a list for the others
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum PaginatorKeys {
+    Single(String),
+    Multiple(Vec<String>),
+}
+
+impl PaginatorKeys {
+    pub fn keys(&self) -> Vec<&str> {
+        match *self {
+            PaginatorKeys::Single(ref key) => vec![key.as_str()],
+            PaginatorKeys::Multiple(ref keys) => keys.iter().map(String::as_str).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Waiters {
+    pub waiters: BTreeMap<String, Waiter>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Waiter {
+    pub acceptors: Vec<Acceptor>,
+    pub delay: u64,
+    pub description: Option<String>,
+    #[serde(rename = "maxAttempts")]
+    pub max_attempts: u32,
+    pub operation: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Acceptor {
+    pub argument: Option<String>,
+    pub expected: serde_json::Value,
+    pub matcher: AcceptorMatcher,
+    pub state: AcceptorState,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
+pub enum AcceptorMatcher {
+    #[serde(rename = "error")]
+    Error,
+    #[serde(rename = "path")]
+    Path,
+    #[serde(rename = "pathAll")]
+    PathAll,
+    #[serde(rename = "pathAny")]
+    PathAny,
+    #[serde(rename = "status")]
+    Status,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
+pub enum AcceptorState {
+    #[serde(rename = "failure")]
+    Failure,
+    #[serde(rename = "retry")]
+    Retry,
+    #[serde(rename = "success")]
+    Success,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct Metadata {
     #[serde(rename = "apiVersion")]
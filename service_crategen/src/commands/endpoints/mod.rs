@@ -0,0 +1,47 @@
+use std::fs;
+use std::path::Path;
+
+use serde_json::Value;
+
+use crate::botocore;
+
+// what rusoto_core resolves endpoints without
+const UNUSED_KEYS: &[&str] = &[
+    "description",
+    "partitionName",
+    "protocols",
+    "signatureVersions",
+    "sslCommonName",
+];
+
+/// Writes the endpoints of services botocore knows of to `out_file`, embedded by rusoto_core to
+/// resolve the endpoints of requests.
+pub fn generate_endpoints(out_file: &Path) {
+    let mut endpoints = botocore::load_endpoints()
+        .expect("Failed to load endpoints. Make sure the botocore submodule has been initialized!");
+    prune(&mut endpoints);
+
+    let mut contents = serde_json::to_string(&endpoints).expect("Unable to serialize endpoints");
+    contents.push('\n');
+    fs::write(out_file, contents)
+        .unwrap_or_else(|_| panic!("Unable to write {}", out_file.display()));
+}
+
+/// Leaves out what isn't needed to resolve endpoints, and the deprecated pseudo-regions of
+/// endpoints, such as `fips-us-gov-west-1`, superseded by their variants.
+fn prune(value: &mut Value) {
+    match *value {
+        Value::Object(ref mut map) => {
+            for key in UNUSED_KEYS {
+                map.remove(*key);
+            }
+            if let Some(Value::Object(endpoints)) = map.get_mut("endpoints") {
+                endpoints
+                    .retain(|_, endpoint| endpoint.get("deprecated") != Some(&Value::Bool(true)));
+            }
+            map.values_mut().for_each(prune);
+        }
+        Value::Array(ref mut values) => values.iter_mut().for_each(prune),
+        _ => (),
+    }
+}
@@ -0,0 +1,208 @@
+use std::collections::BTreeSet;
+
+use crate::botocore::Shape;
+
+/// Generate an enum for a string shape botocore restricts to a set of values.
+///
+/// Services add values over time, so the enum is non-exhaustive and keeps values it doesn't
+/// know about in an `Unknown` variant rather than failing to parse them. Serde support is
+/// unconditional for protocols deriving it on structs, and behind the
+/// `serialize_structs`/`deserialize_structs` features otherwise.
+pub fn generate_enum(name: &str, shape: &Shape, serde_derived: bool) -> String {
+    let variants = enum_variants(shape);
+    let (serialize_cfg, deserialize_cfg) = if serde_derived {
+        ("", "")
+    } else {
+        (
+            "#[cfg(feature = \"serialize_structs\")]",
+            "#[cfg(feature = \"deserialize_structs\")]",
+        )
+    };
+
+    format!(
+        "#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+        #[non_exhaustive]
+        pub enum {name} {{
+            {variants}
+            /// A value not known to this version of the crate
+            Unknown(String),
+        }}
+
+        impl {name} {{
+            /// The value sent over the wire
+            pub fn as_str(&self) -> &str {{
+                match *self {{
+                    {as_str_arms}
+                    {name}::Unknown(ref value) => value,
+                }}
+            }}
+        }}
+
+        // required fields of defaulted structs are left empty, as they would be for strings
+        impl Default for {name} {{
+            fn default() -> {name} {{
+                {name}::Unknown(String::new())
+            }}
+        }}
+
+        impl AsRef<str> for {name} {{
+            fn as_ref(&self) -> &str {{
+                self.as_str()
+            }}
+        }}
+
+        impl fmt::Display for {name} {{
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {{
+                f.write_str(self.as_str())
+            }}
+        }}
+
+        impl<'a> From<&'a str> for {name} {{
+            fn from(value: &'a str) -> {name} {{
+                match value {{
+                    {from_arms}
+                    _ => {name}::Unknown(value.to_owned()),
+                }}
+            }}
+        }}
+
+        impl From<String> for {name} {{
+            fn from(value: String) -> {name} {{
+                match value.as_str() {{
+                    {from_arms}
+                    _ => {name}::Unknown(value),
+                }}
+            }}
+        }}
+
+        impl ::std::str::FromStr for {name} {{
+            type Err = ::std::convert::Infallible;
+
+            fn from_str(value: &str) -> Result<{name}, Self::Err> {{
+                Ok(value.into())
+            }}
+        }}
+
+        impl ::rusoto_core::param::ToParam for {name} {{
+            fn to_param(&self) -> String {{
+                self.as_str().to_owned()
+            }}
+        }}
+
+        {serialize_cfg}
+        impl ::serde::Serialize for {name} {{
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: ::serde::Serializer,
+            {{
+                serializer.serialize_str(self.as_str())
+            }}
+        }}
+
+        {deserialize_cfg}
+        impl<'de> ::serde::Deserialize<'de> for {name} {{
+            fn deserialize<D>(deserializer: D) -> Result<{name}, D::Error>
+            where
+                D: ::serde::Deserializer<'de>,
+            {{
+                let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+                Ok(value.into())
+            }}
+        }}",
+        name = name,
+        variants = variants
+            .iter()
+            .map(|(variant, value)| format!("/// `{}`\n{},", value, variant))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        as_str_arms = variants
+            .iter()
+            .map(|(variant, value)| format!("{}::{} => {:?},", name, variant, value))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        from_arms = variants
+            .iter()
+            .map(|(variant, value)| format!("{:?} => {}::{},", value, name, variant))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        serialize_cfg = serialize_cfg,
+        deserialize_cfg = deserialize_cfg,
+    )
+}
+
+/// The variants of the enum generated for `shape`, along with the value each stands for
+pub fn enum_variants(shape: &Shape) -> Vec<(String, &str)> {
+    let mut names = BTreeSet::new();
+    let mut values = BTreeSet::new();
+    let mut variants = Vec::new();
+    for value in shape.shape_enum.iter().flatten() {
+        if !values.insert(value) {
+            continue;
+        }
+        let name = variant_name(value);
+        // values only differing in punctuation or case would end up with the same name
+        let mut unique = name.clone();
+        let mut suffix = 2;
+        while !names.insert(unique.clone()) {
+            unique = format!("{}{}", name, suffix);
+            suffix += 1;
+        }
+        variants.push((unique, value.as_str()));
+    }
+    variants
+}
+
+/// Translate a value such as `REDUCED_REDUNDANCY`, `t2.micro` or `aws:kms` to a variant name
+fn variant_name(value: &str) -> String {
+    let mut name = String::new();
+    for word in value.split(|c: char| !c.is_ascii_alphanumeric()) {
+        let mut chars = word.chars();
+        let first = match chars.next() {
+            Some(first) => first,
+            None => continue,
+        };
+        // keep digits apart, `1.0` and `10` being different values
+        if first.is_ascii_digit() && name.ends_with(|c: char| c.is_ascii_digit()) {
+            name.push('_');
+        }
+        name.push(first.to_ascii_uppercase());
+        // SHOUTING_CASE words are lowercased, camelCase ones kept as they are
+        if word.chars().any(|c| c.is_ascii_lowercase()) {
+            name.extend(chars);
+        } else {
+            name.extend(chars.map(|c| c.to_ascii_lowercase()));
+        }
+    }
+
+    if name.is_empty() {
+        "Empty".to_owned()
+    } else if name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("V{}", name)
+    } else if name == "Unknown" || name == "Self" {
+        format!("{}Value", name)
+    } else {
+        name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::variant_name;
+
+    #[test]
+    fn names_variants() {
+        assert_eq!(variant_name("REDUCED_REDUNDANCY"), "ReducedRedundancy");
+        assert_eq!(variant_name("t2.micro"), "T2Micro");
+        assert_eq!(variant_name("aws:kms"), "AwsKms");
+        assert_eq!(variant_name("GetObject"), "GetObject");
+        assert_eq!(variant_name("s3:ObjectCreated:*"), "S3ObjectCreated");
+        assert_eq!(
+            variant_name("Linux/UNIX (Amazon VPC)"),
+            "LinuxUnixAmazonVpc"
+        );
+        assert_eq!(variant_name("python3.8"), "Python3_8");
+        assert_eq!(variant_name("1.0"), "V1_0");
+        assert_eq!(variant_name("UNKNOWN"), "UnknownValue");
+        assert_eq!(variant_name("*"), "Empty");
+    }
+}
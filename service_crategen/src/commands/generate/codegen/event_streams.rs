@@ -0,0 +1,214 @@
+use std::collections::BTreeSet;
+use std::io::Write;
+
+use super::{
+    error_type_name, event_stream_member, mutate_type_name, struct_field, FileWriter, IoResult,
+};
+use crate::botocore::{Shape, ShapeType};
+use crate::Service;
+
+/// Implement `rusoto_core::event_stream::DeserializeEvent` for the event streams operations
+/// respond with, so their responses can be handed out as a typed `EventStream`.
+///
+/// Every message of the stream sets the one member of the event stream struct named by its
+/// `:event-type`. Exceptions sent in place of events are turned into the operation's error.
+pub fn generate_event_streams(writer: &mut FileWriter, service: &Service<'_>) -> IoResult {
+    let mut generated = BTreeSet::new();
+    for (operation_name, operation) in service.operations().iter() {
+        let output_shape = match operation.output {
+            Some(ref output) => service.get_shape(&output.shape).unwrap(),
+            None => continue,
+        };
+        let member = match event_stream_member(service, output_shape) {
+            Some((_, member)) => member,
+            None => continue,
+        };
+        // the error type of the first operation wins, should several share an event stream
+        if generated.insert(member.shape.clone()) {
+            generate_event_stream(
+                writer,
+                service,
+                &member.shape,
+                &error_type_name(service, operation_name),
+            )?;
+        }
+    }
+    Ok(())
+}
+
+fn is_xml(service: &Service<'_>) -> bool {
+    match service.protocol() {
+        "query" | "ec2" | "rest-xml" => true,
+        _ => false,
+    }
+}
+
+fn generate_event_stream(
+    writer: &mut FileWriter,
+    service: &Service<'_>,
+    shape_name: &str,
+    error_type: &str,
+) -> IoResult {
+    let shape = service.get_shape(shape_name).unwrap();
+    let arms: Vec<String> = shape
+        .members
+        .iter()
+        .flat_map(|members| members.keys())
+        .filter_map(|member_name| {
+            let field = struct_field(service, shape_name, shape, member_name)?;
+            Some(format!(
+                "\"{event_type}\" => {{
+                    event.{field} = Some({value});
+                }}",
+                event_type = member_name,
+                field = field.name,
+                value = event_value(service, field.shape_name, field.shape),
+            ))
+        })
+        .collect();
+
+    writeln!(
+        writer,
+        "impl rusoto_core::event_stream::DeserializeEvent for {name} {{
+            type Error = {error_type};
+
+            #[allow(unused_variables)]
+            fn deserialize_event(
+                event_type: &str,
+                message: &rusoto_core::event_stream::Message,
+            ) -> Result<{name}, rusoto_core::event_stream::EventStreamError> {{
+                let mut event = {name}::default();
+                match event_type {{
+                    {arms}
+                    _ => {{}}
+                }}
+                Ok(event)
+            }}
+
+            fn deserialize_error(
+                error: rusoto_core::event_stream::ErrorMessage,
+            ) -> RusotoError<{error_type}> {{
+                {error_type}::from_response(error.into_{format}_response())
+            }}
+        }}",
+        name = mutate_type_name(service, shape_name),
+        error_type = error_type,
+        arms = arms.join("\n"),
+        format = if is_xml(service) { "xml" } else { "json" },
+    )
+}
+
+/// Expression evaluating to an event read from `message`.
+///
+/// Members flagged `eventpayload` take the whole payload and `eventheader` ones the header
+/// of the same name. Without an `eventpayload` member, the payload is the event itself.
+fn event_value(service: &Service<'_>, shape_name: &str, shape: &Shape) -> String {
+    let type_name = mutate_type_name(service, shape_name);
+    let members = match shape.members {
+        Some(ref members) if !members.is_empty() => members,
+        _ => return format!("{}::default()", type_name),
+    };
+
+    let mut fields = Vec::new();
+    let mut covered = true;
+    let mut has_payload_member = false;
+    for (member_name, member) in members {
+        let field = match struct_field(service, shape_name, shape, member_name) {
+            Some(field) => field,
+            None => continue,
+        };
+        let value = if member.eventpayload() {
+            has_payload_member = true;
+            payload_value(service, field.shape_name, field.shape)
+        } else if member.eventheader() {
+            header_value(member_name, field.shape)
+        } else {
+            None
+        };
+        match value {
+            Some(value) if field.optional => fields.push(format!("{}: {},", field.name, value)),
+            Some(value) => fields.push(format!("{}: {}.unwrap_or_default(),", field.name, value)),
+            None => covered = false,
+        }
+    }
+
+    if !has_payload_member {
+        let payload = deserialize_payload(service, &type_name);
+        if fields.is_empty() {
+            return payload;
+        }
+        return format!(
+            "{type_name} {{ {fields} ..{payload} }}",
+            type_name = type_name,
+            fields = fields.join(" "),
+            payload = payload,
+        );
+    }
+    format!(
+        "{type_name} {{ {fields} {default} }}",
+        type_name = type_name,
+        fields = fields.join(" "),
+        default = if covered {
+            "".to_owned()
+        } else {
+            format!("..{}::default()", type_name)
+        },
+    )
+}
+
+/// Expression evaluating to an `Option` of the payload, as the type of an `eventpayload` member.
+fn payload_value(service: &Service<'_>, shape_name: &str, shape: &Shape) -> Option<String> {
+    match shape.shape_type {
+        ShapeType::Blob => Some("Some(message.payload.clone())".to_owned()),
+        ShapeType::String if shape.is_enum() => {
+            Some("Some(String::from_utf8_lossy(&message.payload).into_owned().into())".to_owned())
+        }
+        ShapeType::String => {
+            Some("Some(String::from_utf8_lossy(&message.payload).into_owned())".to_owned())
+        }
+        ShapeType::Structure => Some(format!(
+            "Some({})",
+            deserialize_payload(service, &mutate_type_name(service, shape_name))
+        )),
+        _ => None,
+    }
+}
+
+/// Expression deserializing the payload as a `type_name`, returning early on errors.
+fn deserialize_payload(service: &Service<'_>, type_name: &str) -> String {
+    if is_xml(service) {
+        format!(
+            "{{
+                let reader = EventReader::new_with_config(
+                    message.payload.as_ref(),
+                    ParserConfig::new().trim_whitespace(false),
+                );
+                let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                let _start_document = stack.next();
+                let actual_tag_name = peek_at_name(&mut stack)?;
+                {type_name}Deserializer::deserialize(&actual_tag_name, &mut stack)?
+            }}",
+            type_name = type_name,
+        )
+    } else {
+        "serde_json::from_slice(&message.payload)?".to_owned()
+    }
+}
+
+/// Expression evaluating to an `Option` of the value of header `name`.
+fn header_value(name: &str, shape: &Shape) -> Option<String> {
+    let conversion = match shape.shape_type {
+        ShapeType::Blob => "as_bytes().cloned()",
+        ShapeType::Boolean => "as_bool()",
+        ShapeType::Integer | ShapeType::Long => "as_i64()",
+        ShapeType::String if shape.is_enum() => "as_str().map(Into::into)",
+        ShapeType::String => "as_str().map(ToOwned::to_owned)",
+        ShapeType::Timestamp => "as_timestamp()",
+        _ => return None,
+    };
+    Some(format!(
+        "message.header(\"{name}\").and_then(|value| value.{conversion})",
+        name = name,
+        conversion = conversion,
+    ))
+}
@@ -1,7 +1,10 @@
 use inflector::Inflector;
 use std::io::Write;
 
-use super::{error_type_name, FileWriter, GenerateProtocol, IoResult};
+use super::{
+    configure_request, error_type_name, event_stream_member, generate_field_name, FileWriter,
+    GenerateProtocol, IoResult,
+};
 use crate::botocore::Operation;
 use crate::Service;
 
@@ -40,10 +43,12 @@ impl GenerateProtocol for JsonGenerator {
                 {documentation}
                 {method_signature} -> Result<{output_type}, RusotoError<{error_type}>> {{
                     let mut request = SignedRequest::new(\"{http_method}\", \"{signing_name}\", &self.region, \"{request_uri}\");
+                    request.set_operation(\"{name}\");
                     {modify_endpoint_prefix}
                     request.set_content_type(\"application/x-amz-json-{json_version}\".to_owned());
                     request.add_header(\"x-amz-target\", \"{target_prefix}.{name}\");
                     {payload}
+                    {configure_request}
 
                     let mut response = self.client.sign_and_dispatch(request).await.map_err(RusotoError::from)?;
                     if response.status.is_success() {{
@@ -57,13 +62,14 @@ impl GenerateProtocol for JsonGenerator {
                 ",
                      documentation = generate_documentation(operation).unwrap_or_else(|| "".to_owned()),
                      method_signature = generate_method_signature(service, operation),
+                     configure_request = configure_request(service),
                      payload = generate_payload(service, operation),
                      signing_name = service.signing_name(),
                      modify_endpoint_prefix = generate_endpoint_modification(service)
                          .unwrap_or_else(|| "".to_owned()),
                      http_method = operation.http.method,
                      name = operation.name,
-                     ok_response = generate_ok_response(operation, output_type),
+                     ok_response = generate_ok_response(service, operation, output_type),
                      request_uri = operation.http.request_uri,
                      target_prefix = service.target_prefix().unwrap(),
                      json_version = service.json_version().unwrap(),
@@ -94,10 +100,6 @@ impl GenerateProtocol for JsonGenerator {
     fn deserialize_trait(&self) -> Option<&'static str> {
         Some("Deserialize")
     }
-
-    fn timestamp_type(&self) -> &'static str {
-        "f64"
-    }
 }
 
 fn generate_endpoint_modification(service: &Service<'_>) -> Option<String> {
@@ -160,8 +162,21 @@ fn generate_documentation(operation: &Operation) -> Option<String> {
         .map(|docs| crate::doco::Item(docs).to_string())
 }
 
-fn generate_ok_response(operation: &Operation, output_type: &str) -> String {
-    if operation.output.is_some() {
+fn generate_ok_response(service: &Service<'_>, operation: &Operation, output_type: &str) -> String {
+    let event_stream = operation
+        .output
+        .as_ref()
+        .and_then(|output| service.get_shape(&output.shape))
+        .and_then(|output_shape| event_stream_member(service, output_shape));
+    if let Some((member_name, _)) = event_stream {
+        format!(
+            "let mut result = {output_type}::default();
+            result.{field} = Some(rusoto_core::event_stream::EventStream::new(response.body));
+            Ok(result)",
+            output_type = output_type,
+            field = generate_field_name(member_name),
+        )
+    } else if operation.output.is_some() {
         format!(
             "let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
             proto::json::ResponsePayload::new(&response).deserialize::<{}, _>()",
@@ -4,19 +4,26 @@ use std::io::{BufWriter, Write};
 
 use inflector::Inflector;
 
+use self::enums::generate_enum;
 use self::error_types::{GenerateErrorTypes, JsonErrorTypes, RestJsonErrorTypes, XmlErrorTypes};
+use self::event_streams::generate_event_streams;
 use self::json::JsonGenerator;
+use self::paginators::generate_paginators;
 use self::query::QueryGenerator;
 use self::rest_json::RestJsonGenerator;
 use self::rest_xml::RestXmlGenerator;
 use self::tests::generate_tests;
 use self::type_filter::filter_types;
+use self::waiters::generate_waiters;
 use crate::botocore::{Member, Shape, ShapeType};
 use crate::util;
 use crate::Service;
 
+mod enums;
 mod error_types;
+mod event_streams;
 mod json;
+mod paginators;
 mod query;
 mod rest_json;
 mod rest_request_generator;
@@ -24,6 +31,7 @@ mod rest_response_parser;
 mod rest_xml;
 pub mod tests;
 mod type_filter;
+mod waiters;
 mod xml_payload_parser;
 
 type FileWriter = BufWriter<File>;
@@ -78,7 +86,9 @@ pub trait GenerateProtocol {
     }
 
     /// Return the type used by this protocol for timestamps
-    fn timestamp_type(&self) -> &'static str;
+    fn timestamp_type(&self) -> &'static str {
+        "::chrono::DateTime<::chrono::Utc>"
+    }
 }
 
 pub fn generate_source(service: &Service<'_>, writer: &mut FileWriter) -> IoResult {
@@ -142,10 +152,16 @@ where
     "
     )?;
 
+    if let Some(config_type) = service.client_config() {
+        writeln!(writer, "use crate::custom::{};", config_type)?;
+    }
     protocol_generator.generate_prelude(writer, service)?;
     generate_types(writer, service, &protocol_generator)?;
     error_type_generator.generate_error_types(writer, service)?;
+    generate_event_streams(writer, service)?;
     generate_client(writer, service, &protocol_generator)?;
+    generate_paginators(writer, service, protocol_generator.timestamp_type())?;
+    generate_waiters(writer, service)?;
     generate_tests(writer, service)?;
 
     Ok(())
@@ -173,22 +189,42 @@ where
 
     writeln!(writer, "}}")?;
 
+    // clients of services with a configuration of their own default it
+    let (config_field, config_default, config_setter) = match service.client_config() {
+        Some(config_type) => (
+            format!("config: {},", config_type),
+            format!("config: {}::default(),", config_type),
+            format!(
+                "/// Sets the configuration of the client.
+                pub fn with_config(mut self, config: {config_type}) -> {type_name} {{
+                    self.config = config;
+                    self
+                }}",
+                config_type = config_type,
+                type_name = service.client_type_name(),
+            ),
+        ),
+        None => (String::new(), String::new(), String::new()),
+    };
     writeln!(writer,
         "/// A client for the {service_name} API.
         #[derive(Clone)]
         pub struct {type_name} {{
             client: Client,
             region: region::Region,
+            {config_field}
         }}
 
         impl {type_name} {{
             /// Creates a client backed by the default tokio event loop.
             ///
-            /// The client will use the default credentials provider and tls client.
+            /// The client will use the default credentials provider and tls client, and the
+            /// endpoint URL configured for the service in the environment or `~/.aws/config`, if any.
             pub fn new(region: region::Region) -> {type_name} {{
                 {type_name} {{
                     client: Client::shared(),
-                    region
+                    region: rusoto_core::endpoint::with_configured_endpoint(region, \"{service_id}\"),
+                    {config_default}
                 }}
             }}
 
@@ -198,7 +234,8 @@ where
             {{
                 {type_name} {{
                     client: Client::new_with(credentials_provider, request_dispatcher),
-                    region
+                    region,
+                    {config_default}
                 }}
             }}
 
@@ -206,22 +243,41 @@ where
             {{
                 {type_name} {{
                     client,
-                    region
+                    region,
+                    {config_default}
                 }}
             }}
+
+            {config_setter}
         }}
 
         #[async_trait]
         impl {trait_name} for {type_name} {{
         ",
         service_name = service.name(),
+        service_id = service.service_id().unwrap_or_else(|| service.name()),
         type_name = service.client_type_name(),
         trait_name = service.service_type_name(),
+        config_field = config_field,
+        config_default = config_default,
+        config_setter = config_setter,
     )?;
     protocol_generator.generate_method_impls(writer, service)?;
     writeln!(writer, "}}")
 }
 
+/// Statement letting the configuration of the client, if the service has one, adjust `request`
+/// before it's signed and sent, or reject it as invalid.
+pub fn configure_request(service: &Service<'_>) -> &'static str {
+    if service.client_config().is_some() {
+        "self.config
+            .configure_request(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;"
+    } else {
+        ""
+    }
+}
+
 pub fn get_rust_type(
     service: &Service<'_>,
     shape_name: &str,
@@ -236,6 +292,7 @@ pub fn get_rust_type(
             ShapeType::Double => "f64".into(),
             ShapeType::Float => "f32".into(),
             ShapeType::Integer | ShapeType::Long => "i64".into(),
+            ShapeType::String if shape.is_enum() => mutate_type_name(service, shape_name),
             ShapeType::String => "String".into(),
             ShapeType::Timestamp => for_timestamps.into(),
             ShapeType::List => format!(
@@ -265,6 +322,10 @@ pub fn get_rust_type(
                     for_timestamps
                 ),
             ),
+            ShapeType::Structure if shape.eventstream() => format!(
+                "::rusoto_core::event_stream::EventStream<{}>",
+                mutate_type_name(service, shape_name)
+            ),
             ShapeType::Structure => mutate_type_name(service, shape_name),
         }
     } else {
@@ -282,6 +343,26 @@ fn streaming_members<'a>(shape: &'a Shape) -> Box<dyn Iterator<Item = &'a Member
     Box::new(it)
 }
 
+/// The member of an output shape holding the events of an event stream response
+fn event_stream_member<'a>(
+    service: &Service<'_>,
+    shape: &'a Shape,
+) -> Option<(&'a String, &'a Member)> {
+    shape.members.as_ref()?.iter().find(|(_, member)| {
+        service
+            .get_shape(&member.shape)
+            .map_or(false, Shape::eventstream)
+    })
+}
+
+/// Exceptions sent through event streams are returned as errors, rather than as events
+fn is_event_stream_exception(service: &Service<'_>, shape: &Shape, member: &Member) -> bool {
+    shape.eventstream()
+        && service
+            .shape_for_member(member)
+            .map_or(false, Shape::exception)
+}
+
 fn is_streaming_shape(service: &Service<'_>, name: &str) -> bool {
     service
         .shapes()
@@ -409,12 +490,23 @@ where
         let deserialized = deserialized_types.contains(&type_name);
         let serialized = serialized_types.contains(&type_name);
 
-        if shape.shape_type == ShapeType::Structure {
+        if shape.shape_type == ShapeType::Structure || shape.is_enum() {
             // If botocore includes documentation, clean it up a bit and use it
             if let Some(ref docs) = shape.documentation {
                 writeln!(writer, "{}", crate::doco::Item(docs))?;
             }
+        }
+
+        if shape.is_enum() {
+            let serde_derived = protocol_generator.serialize_trait().is_some();
+            writeln!(
+                writer,
+                "{}",
+                generate_enum(&type_name, shape, serde_derived)
+            )?;
+        }
 
+        if shape.shape_type == ShapeType::Structure {
             // generate a rust type for the shape
             if type_name != "String" {
                 let generated = generate_struct(
@@ -477,7 +569,10 @@ where
     let mut not_streaming = false;
     // Streaming is implemented with Box<Stream<...>>, so we can't derive Clone nor PartialEq.
     // This affects both the streaming struct itself, and structs which contain it.
-    if !streaming && streaming_members(shape).next().is_none() {
+    if !streaming
+        && streaming_members(shape).next().is_none()
+        && event_stream_member(service, shape).is_none()
+    {
         not_streaming = true;
         derived.push("Clone");
         derived.push("PartialEq");
@@ -547,7 +642,7 @@ fn generate_struct_fields<P: GenerateProtocol>(
     protocol_generator: &P,
 ) -> String {
     shape.members.as_ref().unwrap().iter().filter_map(|(member_name, member)| {
-        if member.deprecated == Some(true) {
+        if member.deprecated == Some(true) || is_event_stream_exception(service, shape, member) {
             return None;
         }
 
@@ -569,10 +664,19 @@ fn generate_struct_fields<P: GenerateProtocol>(
                             default,
                         )]".to_owned()
                     );
+                } else if member_shape.shape_type == ShapeType::Timestamp {
+                    lines.push(format!(
+                        "#[serde(
+                            deserialize_with=\"::rusoto_core::serialization::SerdeTimestamp::deserialize_timestamp\",
+                            serialize_with=\"::rusoto_core::serialization::SerdeTimestamp::serialize_{}\",
+                            default,
+                        )]",
+                        serde_timestamp_format(timestamp_format(service, member, member_shape))
+                    ));
                 } else if member_shape.shape_type == ShapeType::List {
                     if let Some(ref list_element_member) = member_shape.member {
-                        if let Some(list_element_shape_type) = service.shape_type_for_member(list_element_member) {
-                            if list_element_shape_type == ShapeType::Blob {
+                        if let Some(list_element_shape) = service.shape_for_member(list_element_member) {
+                            if list_element_shape.shape_type == ShapeType::Blob {
                                 lines.push(
                                     "#[serde(
                                         deserialize_with=\"::rusoto_core::serialization::SerdeBlobList::deserialize_blob_list\",
@@ -580,6 +684,15 @@ fn generate_struct_fields<P: GenerateProtocol>(
                                         default,
                                     )]".to_owned()
                                 );
+                            } else if list_element_shape.shape_type == ShapeType::Timestamp {
+                                lines.push(format!(
+                                    "#[serde(
+                                        deserialize_with=\"::rusoto_core::serialization::SerdeTimestampList::deserialize_timestamp_list\",
+                                        serialize_with=\"::rusoto_core::serialization::SerdeTimestampList::serialize_{}_list\",
+                                        default,
+                                    )]",
+                                    serde_timestamp_format(timestamp_format(service, list_element_member, list_element_shape))
+                                ));
                             }
                         }
                     }
@@ -614,6 +727,10 @@ fn generate_struct_fields<P: GenerateProtocol>(
             // See https://github.com/rusoto/rusoto/issues/1419 for more information
             if service.name() == "CodePipeline" && shape_name == "ActionRevision" && name == "revision_change_id" || name == "created" {
                 lines.push(format!("pub {}: Option<{}>,", name, rs_type))
+            // Every message of an event stream carries a single event, and the stream itself
+            // is only attached to the output once the response starts coming in.
+            } else if shape.eventstream() || member_shape.eventstream() {
+                lines.push(format!("pub {}: Option<{}>,", name, rs_type))
             // In pratice, Lex can return null values for slots that are not filled. The documentation
             // does not mention that the slot values themselves can be null.
             } else if service.name() == "Amazon Lex Runtime Service"  && shape_name == "PostTextResponse" && name == "slots"{
@@ -633,6 +750,125 @@ fn generate_struct_fields<P: GenerateProtocol>(
     }).collect::<Vec<String>>().join("\n")
 }
 
+/// A field of a generated struct, named and wrapped the way `generate_struct_fields` does,
+/// for generated code reaching into it
+struct Field<'a> {
+    name: String,
+    optional: bool,
+    shape_name: &'a str,
+    shape: &'a Shape,
+}
+
+fn struct_field<'a>(
+    service: &'a Service<'_>,
+    shape_name: &str,
+    shape: &'a Shape,
+    member_name: &str,
+) -> Option<Field<'a>> {
+    let member = shape.members.as_ref()?.get(member_name)?;
+    if member.deprecated()
+        || member.streaming()
+        || member.shape == shape_name
+        || is_event_stream_exception(service, shape, member)
+    {
+        // not generated, or boxed
+        return None;
+    }
+    let member_shape = service.shape_for_member(member)?;
+    let name = generate_field_name(member_name);
+
+    let (name, optional) = if name == "match" {
+        ("route_match".to_owned(), true)
+    } else if name == "created"
+        || shape.eventstream()
+        || member_shape.eventstream()
+        || (service.name() == "CodePipeline"
+            && shape_name == "ActionRevision"
+            && name == "revision_change_id")
+    {
+        (name, true)
+    } else {
+        let optional = !shape.required(member_name);
+        (name, optional)
+    };
+
+    Some(Field {
+        name,
+        optional,
+        shape_name: &member.shape,
+        shape: member_shape,
+    })
+}
+
+/// The `rusoto_core::serialization::TimestampFormat` a timestamp member is sent in: the one
+/// botocore gives the member or its shape, or the protocol's default for the member's location
+fn timestamp_format(service: &Service<'_>, member: &Member, shape: &Shape) -> &'static str {
+    let format = member
+        .timestamp_format
+        .as_ref()
+        .or_else(|| shape.timestamp_format.as_ref());
+    match member.location.as_ref().map(String::as_str) {
+        Some("header") | Some("headers") => botocore_timestamp_format(format).unwrap_or("Rfc822"),
+        Some("querystring") | Some("uri") => botocore_timestamp_format(format).unwrap_or("Iso8601"),
+        _ => botocore_timestamp_format(format)
+            .unwrap_or_else(|| shape_timestamp_format(service, shape)),
+    }
+}
+
+/// The `rusoto_core::serialization::TimestampFormat` a timestamp shape is sent in within a
+/// body: the one botocore gives the shape, or the protocol's default
+fn shape_timestamp_format(service: &Service<'_>, shape: &Shape) -> &'static str {
+    botocore_timestamp_format(shape.timestamp_format.as_ref()).unwrap_or_else(|| {
+        match service.protocol() {
+            "json" | "rest-json" => "UnixTimestamp",
+            _ => "Iso8601",
+        }
+    })
+}
+
+/// The `rusoto_core::serialization::TimestampFormat` of a botocore `timestampFormat`
+fn botocore_timestamp_format(format: Option<&String>) -> Option<&'static str> {
+    match format.map(String::as_str) {
+        Some("iso8601") => Some("Iso8601"),
+        Some("rfc822") => Some("Rfc822"),
+        Some("unixTimestamp") => Some("UnixTimestamp"),
+        _ => None,
+    }
+}
+
+/// The name `SerdeTimestamp` methods use for a `TimestampFormat`
+fn serde_timestamp_format(format: &str) -> &'static str {
+    match format {
+        "Iso8601" => "iso8601",
+        "Rfc822" => "rfc822",
+        _ => "unix_timestamp",
+    }
+}
+
+/// An expression formatting `timestamp`, a reference to a timestamp of `shape`, sent outside of
+/// any member
+fn format_shape_timestamp(service: &Service<'_>, shape: &Shape, timestamp: &str) -> String {
+    format!(
+        "rusoto_core::serialization::TimestampFormat::{}.format({})",
+        shape_timestamp_format(service, shape),
+        timestamp
+    )
+}
+
+/// An expression formatting `timestamp`, a reference to a timestamp, as sent for `member`
+fn format_timestamp(
+    service: &Service<'_>,
+    member: &Member,
+    shape: &Shape,
+    timestamp: &str,
+) -> String {
+    format!(
+        "rusoto_core::serialization::TimestampFormat::{}.format({})",
+        timestamp_format(service, member, shape),
+        timestamp
+    )
+}
+
 fn error_type_name(service: &Service<'_>, name: &str) -> String {
     let type_name = mutate_type_name(service, name);
     format!("{}Error", type_name)
@@ -0,0 +1,551 @@
+use inflector::Inflector;
+use std::io::Write;
+
+use super::{error_type_name, get_rust_type, struct_field, FileWriter, IoResult};
+use crate::botocore::{Paginator, Shape, ShapeType};
+use crate::Service;
+
+/// Generate `<operation>_pages` and `<operation>_items` streams on the client for every
+/// operation botocore describes as paginated.
+///
+/// Paginators using token or result key expressions we can't translate are skipped, the
+/// operation itself is still generated as usual.
+pub fn generate_paginators(
+    writer: &mut FileWriter,
+    service: &Service<'_>,
+    timestamp_type: &str,
+) -> IoResult {
+    let methods: Vec<String> = service
+        .paginators()
+        .iter()
+        .filter_map(|(operation_name, paginator)| {
+            generate_paginator(service, operation_name, paginator, timestamp_type)
+        })
+        .collect();
+
+    if methods.is_empty() {
+        return Ok(());
+    }
+
+    writeln!(
+        writer,
+        "impl {type_name} {{
+            {methods}
+        }}",
+        type_name = service.client_type_name(),
+        methods = methods.join("\n\n"),
+    )
+}
+
+fn generate_paginator(
+    service: &Service<'_>,
+    operation_name: &str,
+    paginator: &Paginator,
+    timestamp_type: &str,
+) -> Option<String> {
+    let operation = service.operations().get(operation_name)?;
+    let input_type = &operation.input.as_ref()?.shape;
+    let output_type = &operation.output.as_ref()?.shape;
+    let input_shape = service.get_shape(input_type)?;
+    let method_name = operation_name.to_snake_case();
+
+    let pages_method = format!("{}_pages", method_name);
+    let items_method = format!("{}_items", method_name);
+    // an inherent method would shadow the trait method of an operation with the same name
+    if service
+        .operations()
+        .keys()
+        .any(|name| name.to_snake_case() == pages_method || name.to_snake_case() == items_method)
+    {
+        return None;
+    }
+
+    let input_tokens = paginator.input_token.keys();
+    let output_tokens = paginator.output_token.keys();
+    if input_tokens.len() != output_tokens.len() {
+        return None;
+    }
+
+    let mut bindings = Vec::new();
+    for (input_token, output_token) in input_tokens.iter().zip(output_tokens.iter()) {
+        let field = struct_field(service, input_type, input_shape, input_token)?;
+        if !field.optional || ["client", "input", "output"].contains(&field.name.as_str()) {
+            return None;
+        }
+        let field_type = get_rust_type(
+            service,
+            field.shape_name,
+            field.shape,
+            false,
+            timestamp_type,
+        );
+        let (expression, token_type) =
+            token_expression(service, output_type, output_token, timestamp_type)?;
+        if token_type != field_type {
+            return None;
+        }
+        bindings.push((field.name, expression));
+    }
+
+    let more_results = match paginator.more_results {
+        Some(ref path) => format!(
+            "if !{} {{
+                return None;
+            }}",
+            bool_expression(service, output_type, path)?
+        ),
+        None => "".to_owned(),
+    };
+
+    let remaining_fields = if input_shape
+        .members
+        .as_ref()
+        .map_or(0, |members| members.len())
+        > bindings.len()
+    {
+        "..input.clone()"
+    } else {
+        ""
+    };
+    let input_binding = if remaining_fields.is_empty() {
+        "_input"
+    } else {
+        "input"
+    };
+
+    let next_input = if bindings.len() == 1 {
+        let (field, expression) = &bindings[0];
+        format!(
+            "Some({input_type} {{ {field}: Some({expression}?), {remaining_fields} }})",
+            input_type = input_type,
+            field = field,
+            expression = expression,
+            remaining_fields = remaining_fields,
+        )
+    } else {
+        format!(
+            "{lets}
+            if {all_none} {{
+                return None;
+            }}
+            Some({input_type} {{ {fields}, {remaining_fields} }})",
+            lets = bindings
+                .iter()
+                .map(|(field, expression)| format!("let {} = {};", field, expression))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            all_none = bindings
+                .iter()
+                .map(|(field, _)| format!("{}.is_none()", field))
+                .collect::<Vec<_>>()
+                .join(" && "),
+            input_type = input_type,
+            fields = bindings
+                .iter()
+                .map(|(field, _)| field.as_str())
+                .collect::<Vec<_>>()
+                .join(", "),
+            remaining_fields = remaining_fields,
+        )
+    };
+
+    let error_type = error_type_name(service, operation_name);
+    let mut methods = format!(
+        "/// Auto-paginating version of `{method_name}`, streaming every page of results.
+        pub fn {pages_method}(&self, input: {input_type}) -> rusoto_core::pagination::RusotoStream<{output_type}, {error_type}> {{
+            rusoto_core::pagination::paginate(
+                self.clone(),
+                input,
+                |client: {client_type}, input: {input_type}| async move {{ client.{method_name}(input).await }},
+                |{input_binding}: &{input_type}, output: &{output_type}| {{
+                    {more_results}
+                    {next_input}
+                }},
+            )
+        }}
+        ",
+        method_name = method_name,
+        pages_method = pages_method,
+        client_type = service.client_type_name(),
+        input_type = input_type,
+        output_type = output_type,
+        error_type = error_type,
+        input_binding = input_binding,
+        more_results = more_results,
+        next_input = next_input,
+    );
+
+    // when there are several result keys the first one holds the actual results,
+    // e.g. `Contents` rather than `CommonPrefixes` for S3's `ListObjectsV2`
+    let result_key = paginator
+        .result_key
+        .as_ref()
+        .and_then(|keys| keys.keys().first().cloned());
+    if let Some((expression, item_type)) =
+        result_key.and_then(|key| items_expression(service, output_type, key, timestamp_type))
+    {
+        methods.push_str(&format!(
+            "
+            /// Auto-paginating version of `{method_name}`, streaming the `{result_key}` of every page of results.
+            pub fn {items_method}(&self, input: {input_type}) -> rusoto_core::pagination::RusotoStream<{item_type}, {error_type}> {{
+                rusoto_core::pagination::paginate_items(self.{pages_method}(input), |output: {output_type}| {expression})
+            }}
+            ",
+            method_name = method_name,
+            result_key = result_key.unwrap(),
+            items_method = items_method,
+            pages_method = pages_method,
+            input_type = input_type,
+            output_type = output_type,
+            item_type = item_type,
+            error_type = error_type,
+            expression = expression,
+        ));
+    }
+
+    Some(methods)
+}
+
+/// A single step of a botocore paginator path such as `StreamDescription.Shards[-1].ShardId`
+#[derive(Debug, PartialEq)]
+enum Step<'a> {
+    Field(&'a str),
+    Last,
+}
+
+fn parse_path(path: &str) -> Option<Vec<Step<'_>>> {
+    let mut steps = Vec::new();
+    for segment in path.trim().split('.') {
+        let (name, last) = match segment.strip_suffix("[-1]") {
+            Some(name) => (name, true),
+            None => (segment, false),
+        };
+        if name.is_empty() || !name.chars().all(char::is_alphanumeric) {
+            return None;
+        }
+        steps.push(Step::Field(name));
+        if last {
+            steps.push(Step::Last);
+        }
+    }
+    Some(steps)
+}
+
+/// An expression for the value at a path in `output`
+enum Expression {
+    /// The value itself, when all fields on the path are required
+    Place(String),
+    /// An `Option` of the value, once an optional field or the last item of a list is involved
+    Chain(String),
+}
+
+/// How the value at the end of a path is taken out of `output`
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Access {
+    /// Borrowed from `output`
+    Borrow,
+    /// Cloned out of `output`, which is borrowed
+    Clone,
+    /// Copied out of `output`, which is borrowed
+    Copy,
+    /// Moved out of `output`
+    Move,
+}
+
+/// Builds an expression for the value at `path` in `output`, taken out of it as `access` says,
+/// along with the name and shape of the value.
+///
+/// A `Place` is left as is, whatever the access: it names the value.
+fn path_expression<'a>(
+    service: &'a Service<'_>,
+    output_type: &'a str,
+    path: &str,
+    access: Access,
+) -> Option<(Expression, &'a str, &'a Shape)> {
+    let mut shape_name = output_type;
+    let mut shape = service.get_shape(output_type)?;
+    let mut expression = Expression::Place("output".to_owned());
+
+    let steps = parse_path(path)?;
+    let last_step = steps.len() - 1;
+    for (index, step) in steps.into_iter().enumerate() {
+        // values on the way to the last one are only borrowed, unless it's moved
+        let access = match access {
+            Access::Clone | Access::Copy if index < last_step => Access::Borrow,
+            access => access,
+        };
+        expression = match step {
+            Step::Field(member_name) => {
+                if shape.shape_type != ShapeType::Structure {
+                    return None;
+                }
+                let field = struct_field(service, shape_name, shape, member_name)?;
+                shape_name = field.shape_name;
+                shape = field.shape;
+                let taken = match access {
+                    Access::Borrow => ".as_ref()",
+                    Access::Clone => ".clone()",
+                    Access::Copy | Access::Move => "",
+                };
+                match (expression, field.optional) {
+                    (Expression::Place(place), false) => {
+                        Expression::Place(format!("{}.{}", place, field.name))
+                    }
+                    (Expression::Place(place), true) => {
+                        Expression::Chain(format!("{}.{}{}", place, field.name, taken))
+                    }
+                    (Expression::Chain(chain), false) => {
+                        let (borrow, taken) = match access {
+                            Access::Borrow => ("&", ""),
+                            _ => ("", taken),
+                        };
+                        Expression::Chain(format!(
+                            "{}.map(|v| {}v.{}{})",
+                            chain, borrow, field.name, taken
+                        ))
+                    }
+                    (Expression::Chain(chain), true) => Expression::Chain(format!(
+                        "{}.and_then(|v| v.{}{})",
+                        chain, field.name, taken
+                    )),
+                }
+            }
+            Step::Last => {
+                if shape.shape_type != ShapeType::List {
+                    return None;
+                }
+                shape_name = shape.member_type();
+                shape = service.get_shape(shape_name)?;
+                let last = match access {
+                    Access::Move => "into_iter().last()",
+                    _ => "last()",
+                };
+                let taken = match access {
+                    Access::Clone => ".cloned()",
+                    Access::Copy => ".copied()",
+                    Access::Borrow | Access::Move => "",
+                };
+                match expression {
+                    Expression::Place(place) => {
+                        Expression::Chain(format!("{}.{}{}", place, last, taken))
+                    }
+                    Expression::Chain(chain) => {
+                        Expression::Chain(format!("{}.and_then(|v| v.{}){}", chain, last, taken))
+                    }
+                }
+            }
+        };
+    }
+
+    Some((expression, shape_name, shape))
+}
+
+/// An expression cloning the next value of an output token out of `output`, along with its type
+fn token_expression(
+    service: &Service<'_>,
+    output_type: &str,
+    token: &str,
+    timestamp_type: &str,
+) -> Option<(String, String)> {
+    let mut expressions = Vec::new();
+    let mut token_type = None;
+
+    // `NextMarker || Contents[-1].Key` falls back to the key of the last object
+    for path in token.split("||") {
+        let (expression, shape_name, shape) =
+            path_expression(service, output_type, path, Access::Clone)?;
+        let rust_type = get_rust_type(service, shape_name, shape, false, timestamp_type);
+        if token_type.get_or_insert_with(|| rust_type.clone()) != &rust_type {
+            return None;
+        }
+        expressions.push(match expression {
+            Expression::Place(place) => format!("Some({}.clone())", place),
+            Expression::Chain(chain) => chain,
+        });
+    }
+
+    let expression = expressions
+        .iter()
+        .skip(1)
+        .fold(expressions[0].clone(), |expression, alternative| {
+            format!("{}.or_else(|| {})", expression, alternative)
+        });
+    Some((expression, token_type?))
+}
+
+/// An expression telling whether `output` says more results are available
+fn bool_expression(service: &Service<'_>, output_type: &str, path: &str) -> Option<String> {
+    let (expression, _, shape) = path_expression(service, output_type, path, Access::Copy)?;
+    if shape.shape_type != ShapeType::Boolean {
+        return None;
+    }
+    Some(match expression {
+        Expression::Place(place) => place,
+        Expression::Chain(chain) => format!("{}.unwrap_or(false)", chain),
+    })
+}
+
+/// An expression moving the list of results at `path` out of `output`, along with the type of its items
+fn items_expression(
+    service: &Service<'_>,
+    output_type: &str,
+    path: &str,
+    timestamp_type: &str,
+) -> Option<(String, String)> {
+    let (expression, _, shape) = path_expression(service, output_type, path, Access::Move)?;
+    if shape.shape_type != ShapeType::List {
+        return None;
+    }
+    let member_type = shape.member_type();
+    let item_type = get_rust_type(
+        service,
+        member_type,
+        service.get_shape(member_type)?,
+        false,
+        timestamp_type,
+    );
+    let expression = match expression {
+        Expression::Place(place) => place,
+        Expression::Chain(chain) => format!("{}.unwrap_or_default()", chain),
+    };
+    Some((expression, item_type))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::botocore::ServiceDefinition;
+    use crate::config::ServiceConfig;
+
+    #[test]
+    fn parses_single_and_multiple_tokens() {
+        let paginator: Paginator = serde_json::from_str(
+            "{
+                \"input_token\": [\"KeyMarker\", \"VersionIdMarker\"],
+                \"output_token\": [\"NextKeyMarker\", \"NextVersionIdMarker\"],
+                \"more_results\": \"IsTruncated\",
+                \"result_key\": \"Versions\"
+            }",
+        )
+        .expect("failed to parse paginator json");
+
+        assert_eq!(
+            paginator.input_token.keys(),
+            vec!["KeyMarker", "VersionIdMarker"]
+        );
+        assert_eq!(paginator.result_key.unwrap().keys(), vec!["Versions"]);
+        assert_eq!(paginator.limit_key, None);
+    }
+
+    #[test]
+    fn parses_paths() {
+        assert_eq!(
+            parse_path("StreamDescription.Shards[-1].ShardId"),
+            Some(vec![
+                Step::Field("StreamDescription"),
+                Step::Field("Shards"),
+                Step::Last,
+                Step::Field("ShardId"),
+            ])
+        );
+        assert_eq!(parse_path("Reservations[].Instances[]"), None);
+    }
+
+    fn test_service_definition() -> ServiceDefinition {
+        serde_json::from_str(
+            "{
+                \"metadata\": {
+                    \"apiVersion\": \"2020-01-01\",
+                    \"endpointPrefix\": \"test\",
+                    \"protocol\": \"json\",
+                    \"serviceFullName\": \"Test Service\",
+                    \"signatureVersion\": \"v4\"
+                },
+                \"operations\": {},
+                \"shapes\": {
+                    \"Boolean\": { \"type\": \"boolean\" },
+                    \"String\": { \"type\": \"string\" },
+                    \"Output\": {
+                        \"type\": \"structure\",
+                        \"required\": [\"StreamDescription\"],
+                        \"members\": {
+                            \"NextToken\": { \"shape\": \"String\" },
+                            \"Result\": { \"shape\": \"Result\" },
+                            \"StreamDescription\": { \"shape\": \"StreamDescription\" },
+                            \"Contents\": { \"shape\": \"Objects\" }
+                        }
+                    },
+                    \"Result\": {
+                        \"type\": \"structure\",
+                        \"members\": { \"IsTruncated\": { \"shape\": \"Boolean\" } }
+                    },
+                    \"StreamDescription\": {
+                        \"type\": \"structure\",
+                        \"required\": [\"Shards\"],
+                        \"members\": { \"Shards\": { \"shape\": \"Shards\" } }
+                    },
+                    \"Shards\": { \"type\": \"list\", \"member\": { \"shape\": \"Shard\" } },
+                    \"Shard\": {
+                        \"type\": \"structure\",
+                        \"required\": [\"ShardId\"],
+                        \"members\": { \"ShardId\": { \"shape\": \"String\" } }
+                    },
+                    \"Objects\": { \"type\": \"list\", \"member\": { \"shape\": \"Object\" } },
+                    \"Object\": {
+                        \"type\": \"structure\",
+                        \"members\": { \"Key\": { \"shape\": \"String\" } }
+                    }
+                }
+            }",
+        )
+        .expect("failed to parse service json")
+    }
+
+    fn test_service_config() -> ServiceConfig {
+        serde_json::from_str(
+            "{
+                \"version\": \"0.1.0\",
+                \"coreVersion\": \"0.1.0\",
+                \"protocolVersion\": \"2020-01-01\",
+                \"baseTypeName\": \"Test\"
+            }",
+        )
+        .expect("failed to parse service config json")
+    }
+
+    #[test]
+    fn builds_token_expressions_from_shapes() {
+        let config = test_service_config();
+        let service = Service::new(&config, test_service_definition());
+        let token = |path| {
+            token_expression(&service, "Output", path, "f64")
+                .expect("failed to build token expression")
+        };
+
+        assert_eq!(
+            token("NextToken"),
+            ("output.next_token.clone()".to_owned(), "String".to_owned())
+        );
+        assert_eq!(
+            token("StreamDescription.Shards[-1].ShardId").0,
+            "output.stream_description.shards.last().map(|v| v.shard_id.clone())"
+        );
+        assert_eq!(
+            token("NextToken || Contents[-1].Key").0,
+            "output.next_token.clone().or_else(|| \
+             output.contents.as_ref().and_then(|v| v.last()).and_then(|v| v.key.clone()))"
+        );
+        assert_eq!(
+            bool_expression(&service, "Output", "Result.IsTruncated").unwrap(),
+            "output.result.as_ref().and_then(|v| v.is_truncated).unwrap_or(false)"
+        );
+        assert_eq!(
+            items_expression(&service, "Output", "Contents", "f64").unwrap(),
+            (
+                "output.contents.unwrap_or_default()".to_owned(),
+                "Object".to_owned()
+            )
+        );
+        assert!(token_expression(&service, "Output", "NextToken || Result", "f64").is_none());
+    }
+}
@@ -7,7 +7,8 @@ use crate::Service;
 
 use super::xml_payload_parser;
 use super::{
-    error_type_name, generate_field_name, get_rust_type, FileWriter, GenerateProtocol, IoResult,
+    configure_request, error_type_name, format_timestamp, generate_field_name, get_rust_type,
+    FileWriter, GenerateProtocol, IoResult,
 };
 
 pub struct QueryGenerator;
@@ -39,12 +40,14 @@ impl GenerateProtocol for QueryGenerator {
                 {documentation}
                 {method_signature} {{
                     let mut request = SignedRequest::new(\"{http_method}\", \"{endpoint_prefix}\", &self.region, \"{request_uri}\");
+                    request.set_operation(\"{operation_name}\");
                     let mut params = Params::new();
 
                     params.put(\"Action\", \"{operation_name}\");
                     params.put(\"Version\", \"{api_version}\");
                     {serialize_input}
                     {set_input_params}
+                    {configure_request}
 
                     let mut response = self.client.sign_and_dispatch(request).await.map_err(RusotoError::from)?;
                     if !response.status.is_success() {{
@@ -63,6 +66,7 @@ impl GenerateProtocol for QueryGenerator {
                      parse_payload =
                          xml_payload_parser::generate_response_parser(service, operation, false, ""),
                      method_signature = generate_method_signature(operation_name, operation, service),
+                     configure_request = configure_request(service),
                      operation_name = &operation.name,
                      request_uri = &operation.http.request_uri,
                      serialize_input = generate_method_input_serialization(operation),
@@ -123,10 +127,6 @@ impl GenerateProtocol for QueryGenerator {
             name, &ty, shape, service,
         ))
     }
-
-    fn timestamp_type(&self) -> &'static str {
-        "String"
-    }
 }
 
 pub fn generate_method_input_serialization(operation: &Operation) -> String {
@@ -191,7 +191,12 @@ fn generate_list_serializer(service: &Service<'_>, shape: &Shape) -> String {
         list_member_format = lmf
     ));
 
-    if primitive {
+    if member_shape.shape_type == ShapeType::Timestamp {
+        parts.push(format!(
+            "params.put(&key, &{});",
+            format_timestamp(service, shape.member.as_ref().unwrap(), member_shape, "obj")
+        ));
+    } else if primitive {
         parts.push(format!(
             "params.put(&key, {});",
             serialize_primitive_expression(member_shape.shape_type, "obj")
@@ -365,7 +370,14 @@ fn optional_primitive_field_serializer(
         return "".to_owned();
     }
     let member_shape = service.shape_for_member(member).unwrap();
-    let expression = serialize_primitive_expression(member_shape.shape_type, "field_value");
+    let expression = if member_shape.shape_type == ShapeType::Timestamp {
+        format!(
+            "&{}",
+            format_timestamp(service, member, member_shape, "field_value")
+        )
+    } else {
+        serialize_primitive_expression(member_shape.shape_type, "field_value")
+    };
 
     format!(
         "if let Some(ref field_value) = obj.{field_name} {{
@@ -383,10 +395,15 @@ fn required_primitive_field_serializer(
     member: &Member,
 ) -> String {
     let member_shape = service.shape_for_member(member).unwrap();
-    let expression = serialize_primitive_expression(
-        member_shape.shape_type,
-        &format!("obj.{}", generate_field_name(member_name)),
-    );
+    let field = format!("obj.{}", generate_field_name(member_name));
+    let expression = if member_shape.shape_type == ShapeType::Timestamp {
+        format!(
+            "&{}",
+            format_timestamp(service, member, member_shape, &format!("&{}", field))
+        )
+    } else {
+        serialize_primitive_expression(member_shape.shape_type, &field)
+    };
 
     format!(
         "params.put(&format!(\"{{}}{{}}\", prefix, \"{tag_name}\"), {expression});",
@@ -398,7 +415,6 @@ fn required_primitive_field_serializer(
 fn serialize_primitive_expression(shape_type: ShapeType, var_name: &str) -> String {
     match shape_type {
         ShapeType::String
-        | ShapeType::Timestamp
         | ShapeType::Integer
         | ShapeType::Double
         | ShapeType::Long
@@ -3,8 +3,8 @@ use std::io::Write;
 use inflector::Inflector;
 
 use super::{
-    error_type_name, generate_field_name, rest_request_generator, rest_response_parser, FileWriter,
-    GenerateProtocol, IoResult,
+    configure_request, error_type_name, event_stream_member, generate_field_name,
+    rest_request_generator, rest_response_parser, FileWriter, GenerateProtocol, IoResult,
 };
 use crate::botocore::{Operation, Shape, ShapeType};
 use crate::Service;
@@ -60,16 +60,17 @@ impl GenerateProtocol for RestJsonGenerator {
                     {request_uri_formatter}
 
                     let mut request = SignedRequest::new(\"{http_method}\", \"{endpoint_prefix}\", &self.region, &request_uri);
+                    request.set_operation(\"{operation_name}\");
                     {default_headers}
                     {set_headers}
                     {modify_endpoint_prefix}
                     {load_payload}
                     {load_headers}
                     {load_params}
+                    {configure_request}
 
                     let mut response = self.client.sign_and_dispatch(request).await.map_err(RusotoError::from)?;
                     if {status_check} {{
-                        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
                         {parse_body}
                         {parse_headers}
                         {parse_status_code}
@@ -82,9 +83,11 @@ impl GenerateProtocol for RestJsonGenerator {
                 ",
                 documentation = generate_documentation(operation).unwrap_or_else(|| "".to_owned()),
                 method_signature = generate_method_signature(operation, *input_shape),
+                configure_request = configure_request(service),
                 endpoint_prefix = service.signing_name(),
                 modify_endpoint_prefix = generate_endpoint_modification(service).unwrap_or_else(|| "".to_owned()),
                 http_method = operation.http.method,
+                operation_name = operation.name,
                 error_type = error_type_name(service, operation_name),
                 status_check = http_code_expected(operation.http.response_code),
                 parse_body = generate_body_parser(operation, service),
@@ -133,10 +136,6 @@ impl GenerateProtocol for RestJsonGenerator {
     fn deserialize_trait(&self) -> Option<&'static str> {
         Some("Deserialize")
     }
-
-    fn timestamp_type(&self) -> &'static str {
-        "f64"
-    }
 }
 
 fn http_code_expected(code: Option<i32>) -> String {
@@ -333,8 +332,9 @@ fn generate_status_code_parser(operation: &Operation, service: &Service<'_>) ->
 /// will be set later (e.g. from headers), so the compiler won't spit out
 /// warnings about unnecessary mutability
 fn generate_body_parser(operation: &Operation, service: &Service<'_>) -> String {
+    let buffer = "let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;";
     if operation.output.is_none() {
-        return "let result = ::std::mem::drop(response);".to_string();
+        return format!("{}\nlet result = ::std::mem::drop(response);", buffer);
     }
 
     let shape_name = &operation.output.as_ref().unwrap().shape;
@@ -342,6 +342,16 @@ fn generate_body_parser(operation: &Operation, service: &Service<'_>) -> String
         .get_shape(shape_name)
         .expect("Shape missing from service definition");
 
+    // event streams are read as they come in, rather than buffered
+    if let Some((member_name, _)) = event_stream_member(service, output_shape) {
+        return format!(
+            "let mut result = {output_shape}::default();
+            result.{field} = Some(rusoto_core::event_stream::EventStream::new(response.body));",
+            output_shape = shape_name,
+            field = generate_field_name(member_name),
+        );
+    }
+
     let mutable_result = output_shape
         .members
         .as_ref()
@@ -350,7 +360,7 @@ fn generate_body_parser(operation: &Operation, service: &Service<'_>) -> String
         .any(|(_, member)| member.location.is_some())
         || output_shape.payload.is_some();
 
-    match output_shape.payload {
+    let parser = match output_shape.payload {
         None => json_body_parser(shape_name, mutable_result),
         Some(ref payload_member_name) => {
             let payload_member_shape =
@@ -378,7 +388,8 @@ fn generate_body_parser(operation: &Operation, service: &Service<'_>) -> String
                 _ => json_body_parser(shape_name, false),
             }
         }
-    }
+    };
+    format!("{}\n{}", buffer, parser)
 }
 
 /// Take the raw http response body and assign it to the payload field
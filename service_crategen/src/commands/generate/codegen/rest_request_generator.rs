@@ -1,4 +1,4 @@
-use super::generate_field_name;
+use super::{format_timestamp, generate_field_name};
 use crate::botocore::{Member, Operation, Shape, ShapeType};
 use crate::Service;
 use inflector::Inflector;
@@ -21,20 +21,33 @@ pub fn generate_headers(service: &Service<'_>, operation: &Operation) -> Option<
             member.location.as_ref()?;
             match &member.location.as_ref().unwrap()[..] {
                 "header" => {
+                    let member_shape = service.shape_for_member(member).unwrap();
+                    let field_name = generate_field_name(member_name);
                     if shape.required(member_name) {
-                        Some(format!("request.add_header(\"{location_name}\", 
-                                      &input.{field_name});",
+                        Some(format!("request.add_header(\"{location_name}\",
+                                      {value});",
                                      location_name = member.location_name.as_ref().unwrap(),
-                                     field_name = generate_field_name(member_name)))
+                                     value = if member_shape.is_enum() {
+                                         format!("input.{}.as_str()", field_name)
+                                     } else if member_shape.shape_type == ShapeType::Timestamp {
+                                         format!("&{}", format_timestamp(service, member, member_shape, &format!("&input.{}", field_name)))
+                                     } else {
+                                         format!("&input.{}", field_name)
+                                     }))
                     } else {
                         Some(format!("
                         if let Some(ref {field_name}) = 
                                       input.{field_name} {{
                                       request.add_header(\"{location_name}\", 
-                                      &{field_name}.to_string());
+                                      &{value});
                         }}",
                                      location_name = member.location_name.as_ref().unwrap(),
-                                     field_name = generate_field_name(member_name)))
+                                     value = if member_shape.shape_type == ShapeType::Timestamp {
+                                         format_timestamp(service, member, member_shape, &field_name)
+                                     } else {
+                                         format!("{}.to_string()", field_name)
+                                     },
+                                     field_name = field_name))
                     }
                 },
                 "headers" => {
@@ -227,6 +240,7 @@ fn generate_shape_member_param_strings(service: &Service<'_>, shape: &Shape) ->
                 if !member.deprecated() && loc == "querystring" {
                     let member_shape = service.shape_for_member(member).unwrap();
                     Some(generate_param_load_string(
+                        service,
                         member_name,
                         member_shape,
                         member,
@@ -241,6 +255,7 @@ fn generate_shape_member_param_strings(service: &Service<'_>, shape: &Shape) ->
 }
 
 fn generate_param_load_string(
+    service: &Service<'_>,
     member_name: &str,
     member_shape: &Shape,
     member: &Member,
@@ -282,6 +297,19 @@ fn generate_param_load_string(
                 }}",
             field_name = field_name,
         ),
+        (ShapeType::Timestamp, true) => format!(
+            "params.put(\"{param_name}\", &{value});",
+            param_name = param_name,
+            value = format_timestamp(service, member, member_shape, &format!("&input.{}", field_name)),
+        ),
+        (ShapeType::Timestamp, false) => format!(
+            "if let Some(ref x) = input.{field_name} {{
+                    params.put(\"{param_name}\", &{value});
+                }}",
+            param_name = param_name,
+            field_name = field_name,
+            value = format_timestamp(service, member, member_shape, "x"),
+        ),
         (_, true) => format!(
             "params.put(\"{param_name}\", &input.{field_name});",
             param_name = param_name,
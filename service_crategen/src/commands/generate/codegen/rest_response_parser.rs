@@ -127,7 +127,9 @@ fn parse_single_header(
 
 fn generate_header_primitive_parser(shape: &Shape) -> String {
     let statement = match shape.shape_type {
-        ShapeType::String | ShapeType::Timestamp => "value",
+        ShapeType::String if shape.is_enum() => "value.into()",
+        ShapeType::String => "value",
+        ShapeType::Timestamp => "rusoto_core::serialization::parse_timestamp(&value)?",
         ShapeType::Double => "value.parse::<f64>().unwrap()",
         ShapeType::Integer | ShapeType::Long => "value.parse::<i64>().unwrap()",
         ShapeType::Float => "value.parse::<f32>().unwrap()",
@@ -1,7 +1,10 @@
 use inflector::Inflector;
 use std::io::Write;
 
-use super::{error_type_name, generate_field_name, GenerateProtocol};
+use super::{
+    configure_request, error_type_name, format_shape_timestamp, format_timestamp,
+    generate_field_name, GenerateProtocol,
+};
 use super::{
     get_rust_type, mutate_type_name, rest_request_generator, rest_response_parser,
     xml_payload_parser,
@@ -46,25 +49,25 @@ impl GenerateProtocol for RestXmlGenerator {
                         {modify_uri}
 
                         let mut request = SignedRequest::new(\"{http_method}\", \"{endpoint_prefix}\", &self.region, &request_uri);
+                        request.set_operation(\"{operation_name}\");
 
                         {set_headers}
                         {set_parameters}
                         {build_payload}
+                        {configure_request}
 
-                        let mut response = self.client.sign_and_dispatch(request).await.map_err(RusotoError::from)?;
-                        if !response.status.is_success() {{
-                            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-                            return Err({error_type}::from_response(response));
-                        }}
+                        {dispatch_request}
 
                         {parse_response_body}
                     }}
                     ",
                      documentation = generate_documentation(operation, service),
                      http_method = &operation.http.method,
+                     operation_name = &operation.name,
                      endpoint_prefix = service.endpoint_prefix(),
                      method_signature = generate_method_signature(operation_name, operation, service),
-                     error_type = error_type_name(service, operation_name),
+                     configure_request = configure_request(service),
+                     dispatch_request = generate_dispatch(service, operation_name),
                      build_payload = generate_payload_serialization(service, operation)
                          .unwrap_or_else(|| "".to_string()),
                      modify_uri = rest_request_generator::generate_uri_formatter(&request_uri,
@@ -149,9 +152,30 @@ impl GenerateProtocol for RestXmlGenerator {
             name, &ty, shape, service,
         ))
     }
+}
 
-    fn timestamp_type(&self) -> &'static str {
-        "String"
+fn generate_dispatch(service: &Service<'_>, operation_name: &str) -> String {
+    let error_type = error_type_name(service, operation_name);
+    if service.reports_errors_in_body(operation_name) {
+        // the body of successful responses is checked, S3 reporting some errors there
+        format!(
+            "let mut response = self.client.sign_and_dispatch_checking_body(request).await.map_err(RusotoError::from)?;
+            let buffered = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            if !buffered.status.is_success() || buffered.is_xml_error() {{
+                return Err({error_type}::from_response(buffered));
+            }}
+            let mut response = rusoto_core::request::HttpResponse::from(buffered);",
+            error_type = error_type
+        )
+    } else {
+        format!(
+            "let mut response = self.client.sign_and_dispatch(request).await.map_err(RusotoError::from)?;
+            if !response.status.is_success() {{
+                let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+                return Err({error_type}::from_response(response));
+            }}",
+            error_type = error_type
+        )
     }
 }
 
@@ -294,7 +318,7 @@ fn generate_serializer_body(shape: &Shape, service: &Service<'_>) -> String {
         ShapeType::List => generate_list_serializer(shape, service),
         ShapeType::Map => generate_map_serializer(shape),
         ShapeType::Structure => generate_struct_serializer(shape, service),
-        _ => generate_primitive_serializer(shape),
+        _ => generate_primitive_serializer(shape, service),
     }
 }
 
@@ -305,10 +329,13 @@ fn generate_serializer_signature(name: &str) -> String {
             name)
 }
 
-fn generate_primitive_serializer(shape: &Shape) -> String {
+fn generate_primitive_serializer(shape: &Shape, service: &Service<'_>) -> String {
     let value_str = match shape.shape_type {
-        ShapeType::Blob => "String::from_utf8(obj.to_vec()).expect(\"Not a UTF-8 string\")",
-        _ => "obj.to_string()",
+        ShapeType::Blob => {
+            "String::from_utf8(obj.to_vec()).expect(\"Not a UTF-8 string\")".to_owned()
+        }
+        ShapeType::Timestamp => format_shape_timestamp(service, shape, "obj"),
+        _ => "obj.to_string()".to_owned(),
     };
     format!("
         writer.write(xml::writer::XmlEvent::start_element(name))?;
@@ -326,26 +353,47 @@ fn generate_list_serializer(shape: &Shape, service: &Service<'_>) -> String {
     };
 
     let member = shape.member.as_ref().expect("Member shape undefined");
+    let member_shape = service.shape_for_member(member).unwrap();
     let element_type = &mutate_type_name(service, &member.shape);
     let mut serializer = "".to_owned();
 
+    // timestamps are written in the format of the list's member, which
+    // the serializer of their shape doesn't know
+    let serialize_element = |element_name: &str| {
+        if member_shape.shape_type == ShapeType::Timestamp {
+            format!(
+                "writer.write(xml::writer::XmlEvent::start_element({element_name}))?;
+                writer.write(xml::writer::XmlEvent::characters(&{value}))?;
+                writer.write(xml::writer::XmlEvent::end_element())?;",
+                element_name = element_name,
+                value = format_timestamp(service, member, member_shape, "element"),
+            )
+        } else {
+            format!(
+                "{element_type}Serializer::serialize(writer, {element_name}, element)?;",
+                element_type = element_type,
+                element_name = element_name,
+            )
+        }
+    };
+
     if flattened {
         serializer += &format!(
             "
             for element in obj {{
-                {element_type}Serializer::serialize(writer, name, element)?;
+                {serialize_element}
             }}",
-            element_type = element_type
+            serialize_element = serialize_element("name")
         );
     } else {
         serializer += "writer.write(xml::writer::XmlEvent::start_element(name))?;";
         serializer += &format!(
             "
             for element in obj {{
-                {element_type}Serializer::serialize(writer, \"{location_name}\", element)?;
+                {serialize_element}
             }}",
-            element_type = element_type,
-            location_name = member.location_name.as_ref().unwrap()
+            serialize_element =
+                serialize_element(&format!("\"{}\"", member.location_name.as_ref().unwrap()))
         );
         serializer += "writer.write(xml::writer::XmlEvent::end_element())?;";
     }
@@ -382,8 +430,13 @@ fn generate_struct_serializer(shape: &Shape, service: &Service<'_>) -> String {
                 );
             }
             _ => {
-                serializer +=
-                    &generate_primitive_struct_field_serializer(shape, location_name, member_name);
+                serializer += &generate_primitive_struct_field_serializer(
+                    service,
+                    shape,
+                    member,
+                    location_name,
+                    member_name,
+                );
             }
         }
     }
@@ -393,26 +446,38 @@ fn generate_struct_serializer(shape: &Shape, service: &Service<'_>) -> String {
 }
 
 fn generate_primitive_struct_field_serializer(
+    service: &Service<'_>,
     shape: &Shape,
+    member: &Member,
     location_name: &str,
     member_name: &str,
 ) -> String {
+    let field_name = generate_field_name(member_name);
+    let member_shape = service.shape_for_member(member).unwrap();
+    let value = |var: &str| {
+        if member_shape.shape_type == ShapeType::Timestamp {
+            format_timestamp(service, member, member_shape, var)
+        } else {
+            var.to_owned()
+        }
+    };
     if shape.required(member_name) {
         format!(
-        "writer.write(xml::writer::XmlEvent::start_element(\"{location_name}\"))?;
-        writer.write(xml::writer::XmlEvent::characters(&format!(\"{{value}}\", value=obj.{field_name})))?;
+            "writer.write(xml::writer::XmlEvent::start_element(\"{location_name}\"))?;
+        writer.write(xml::writer::XmlEvent::characters(&format!(\"{{value}}\", value={value})))?;
         writer.write(xml::writer::XmlEvent::end_element())?;",
-        field_name = generate_field_name(member_name),
-        location_name = location_name,
-    )
+            value = value(&format!("&obj.{}", field_name)),
+            location_name = location_name,
+        )
     } else {
         format!(
             "if let Some(ref value) = obj.{field_name} {{
                 writer.write(xml::writer::XmlEvent::start_element(\"{location_name}\"))?;
-                writer.write(xml::writer::XmlEvent::characters(&format!(\"{{value}}\", value=value)));
+                writer.write(xml::writer::XmlEvent::characters(&format!(\"{{value}}\", value={value})));
                 writer.write(xml::writer::XmlEvent::end_element())?;
             }}",
-            field_name = generate_field_name(member_name),
+            field_name = field_name,
+            value = value("value"),
             location_name = location_name,
         )
     }
@@ -1,6 +1,6 @@
 use std::collections::BTreeSet;
 
-use super::mutate_type_name;
+use super::{event_stream_member, mutate_type_name};
 use crate::botocore::{Shape, ShapeType};
 use crate::Service;
 
@@ -13,7 +13,18 @@ pub fn filter_types(service: &Service<'_>) -> (BTreeSet<String>, BTreeSet<String
                 .get_shape(&output.shape)
                 .expect("Shape type missing from service definition");
 
-            if !can_skip_deserializer(service, output_shape) {
+            if let Some((_, member)) = event_stream_member(service, output_shape) {
+                // the output and the event stream are put together by hand, only the events
+                // themselves need deserializing
+                let event_stream = service.get_shape(&member.shape).unwrap();
+                for event in event_stream
+                    .members
+                    .iter()
+                    .flat_map(|members| members.values())
+                {
+                    find_event_shapes(service, &mut deserialized_types, &event.shape);
+                }
+            } else if !can_skip_deserializer(service, output_shape) {
                 recurse_find_shapes(service, &mut deserialized_types, &output.shape);
             }
         }
@@ -25,6 +36,25 @@ pub fn filter_types(service: &Service<'_>) -> (BTreeSet<String>, BTreeSet<String
     (serialized_types, deserialized_types)
 }
 
+/// Events are deserialized from their payload, unless they have no members or a member
+/// taking the whole payload, which is only deserialized when it is a structure.
+fn find_event_shapes(service: &Service<'_>, types: &mut BTreeSet<String>, shape_name: &str) {
+    let shape = service.get_shape(shape_name).unwrap();
+    let members = match shape.members {
+        Some(ref members) if !members.is_empty() => members,
+        _ => return,
+    };
+    match members.values().find(|member| member.eventpayload()) {
+        Some(payload) => {
+            let payload_shape = service.get_shape(&payload.shape).unwrap();
+            if payload_shape.shape_type == ShapeType::Structure {
+                recurse_find_shapes(service, types, &payload.shape);
+            }
+        }
+        None => recurse_find_shapes(service, types, shape_name),
+    }
+}
+
 fn recurse_find_shapes(service: &Service<'_>, types: &mut BTreeSet<String>, shape_name: &str) {
     types.insert(mutate_type_name(service, shape_name).to_owned());
     let shape = service
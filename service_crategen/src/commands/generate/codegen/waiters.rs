@@ -0,0 +1,547 @@
+use inflector::Inflector;
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde_json::Value;
+use std::fmt;
+use std::io::Write;
+
+use super::enums::enum_variants;
+use super::{error_type_name, mutate_type_name, struct_field, FileWriter, IoResult};
+use crate::botocore::{
+    Acceptor, AcceptorMatcher, AcceptorState, Error, Operation, Shape, ShapeType, Waiter,
+};
+use crate::Service;
+
+/// Generate `wait_until_<state>` methods on the client for every waiter botocore defines
+/// for the service.
+///
+/// Waiters with acceptors whose arguments we can't translate are skipped entirely, as
+/// leaving out one of their acceptors would change what they wait for.
+pub fn generate_waiters(writer: &mut FileWriter, service: &Service<'_>) -> IoResult {
+    let methods: Vec<String> = service
+        .waiters()
+        .iter()
+        .filter_map(|(waiter_name, waiter)| generate_waiter(service, waiter_name, waiter))
+        .collect();
+
+    if methods.is_empty() {
+        return Ok(());
+    }
+
+    writeln!(
+        writer,
+        "impl {type_name} {{
+            {methods}
+        }}",
+        type_name = service.client_type_name(),
+        methods = methods.join("\n\n"),
+    )
+}
+
+fn generate_waiter(service: &Service<'_>, waiter_name: &str, waiter: &Waiter) -> Option<String> {
+    let operation = service.operations().get(&waiter.operation)?;
+    let method_name = format!("wait_until_{}", waiter_name.to_snake_case());
+    let operation_method = waiter.operation.to_snake_case();
+    let output_type = operation.output_shape_or("()");
+    let error_type = error_type_name(service, &waiter.operation);
+
+    let mut arms: Vec<Arm> = Vec::new();
+    for acceptor in &waiter.acceptors {
+        for arm in acceptor_arms(service, operation, &error_type, acceptor)? {
+            // an earlier catch-all `Ok(_)` or error variant makes later ones unreachable
+            let unreachable = arms.iter().any(|earlier| {
+                earlier.guard.is_none()
+                    && (earlier.pattern == arm.pattern
+                        || earlier.pattern == "Ok(_)" && arm.pattern.starts_with("Ok("))
+            });
+            if !unreachable {
+                arms.push(arm);
+            }
+        }
+    }
+
+    let (input_parameter, input_argument, poll) = if takes_input(service, operation) {
+        (
+            format!(", input: {}", operation.input_shape()),
+            "input, ",
+            format!("self.{}(input.clone())", operation_method),
+        )
+    } else {
+        ("".to_owned(), "", format!("self.{}()", operation_method))
+    };
+
+    Some(format!(
+        "/// Waits until `{operation_method}` reports the `{waiter_name}` state, polling every {delay} seconds up to {max_attempts} times.
+        pub async fn {method_name}(&self{input_parameter}) -> Result<(), rusoto_core::waiter::WaiterError<{error_type}>> {{
+            let config = rusoto_core::waiter::WaiterConfig::new(std::time::Duration::from_secs({delay}), {max_attempts});
+            self.{method_name}_with_config({input_argument}config).await
+        }}
+
+        /// Same as `{method_name}`, polling as often and as many times as `config` says.
+        pub async fn {method_name}_with_config(&self{input_parameter}, config: rusoto_core::waiter::WaiterConfig) -> Result<(), rusoto_core::waiter::WaiterError<{error_type}>> {{
+            rusoto_core::waiter::wait(
+                \"{waiter_name}\",
+                &config,
+                || {poll},
+                |result: &Result<{output_type}, RusotoError<{error_type}>>| match result {{
+                    {arms}
+                    _ => None,
+                }},
+            )
+            .await
+        }}
+        ",
+        operation_method = operation_method,
+        waiter_name = waiter_name,
+        delay = waiter.delay,
+        max_attempts = waiter.max_attempts,
+        method_name = method_name,
+        input_parameter = input_parameter,
+        input_argument = input_argument,
+        error_type = error_type,
+        poll = poll,
+        output_type = output_type,
+        arms = arms
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join("\n"),
+    ))
+}
+
+/// Whether the operation's method takes an input, see `generate_method_signature` for
+/// each protocol
+fn takes_input(service: &Service<'_>, operation: &Operation) -> bool {
+    match operation.input {
+        None => false,
+        Some(ref input) => match service.protocol() {
+            "query" | "ec2" | "rest-xml" => true,
+            _ => service
+                .get_shape(&input.shape)
+                .and_then(|shape| shape.members.as_ref())
+                .map_or(false, |members| !members.is_empty()),
+        },
+    }
+}
+
+/// An arm of the `match` on the result of the operation, in the acceptors' order
+struct Arm {
+    pattern: String,
+    guard: Option<String>,
+    state: &'static str,
+}
+
+impl Arm {
+    fn new(pattern: String, guard: Option<String>, state: &'static str) -> Arm {
+        Arm {
+            pattern,
+            guard,
+            state,
+        }
+    }
+}
+
+impl fmt::Display for Arm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.guard {
+            Some(ref guard) => write!(f, "{} if {} => {},", self.pattern, guard, self.state),
+            None => write!(f, "{} => {},", self.pattern, self.state),
+        }
+    }
+}
+
+fn acceptor_arms(
+    service: &Service<'_>,
+    operation: &Operation,
+    error_type: &str,
+    acceptor: &Acceptor,
+) -> Option<Vec<Arm>> {
+    let state = match acceptor.state {
+        AcceptorState::Success => "Some(rusoto_core::waiter::WaiterState::Success)",
+        AcceptorState::Failure => "Some(rusoto_core::waiter::WaiterState::Failure)",
+        AcceptorState::Retry => "Some(rusoto_core::waiter::WaiterState::Retry)",
+    };
+    let service_error = |error: &Error| {
+        Arm::new(
+            format!(
+                "Err(RusotoError::Service({}::{}(_)))",
+                error_type,
+                error.idiomatic_error_name()
+            ),
+            None,
+            state,
+        )
+    };
+    let errors = operation.errors.iter().flatten();
+
+    let arms = match acceptor.matcher {
+        AcceptorMatcher::Path | AcceptorMatcher::PathAll | AcceptorMatcher::PathAny => {
+            let output_type = operation.output.as_ref()?.shape.as_str();
+            let argument = acceptor.argument.as_ref()?;
+            vec![Arm::new(
+                "Ok(output)".to_owned(),
+                Some(path_condition(service, output_type, argument, acceptor)?),
+                state,
+            )]
+        }
+        AcceptorMatcher::Status => {
+            let status = acceptor.expected.as_u64()?;
+            if (200..300).contains(&status) {
+                vec![Arm::new("Ok(_)".to_owned(), None, state)]
+            } else {
+                let mut arms: Vec<Arm> = errors
+                    .filter(|error| {
+                        service
+                            .get_shape(&error.shape)
+                            .and_then(|shape| shape.error.as_ref())
+                            .map_or(false, |http_error| {
+                                http_error.http_status_code as u64 == status
+                            })
+                    })
+                    .map(service_error)
+                    .collect();
+                arms.push(Arm::new(
+                    "Err(RusotoError::Unknown(response))".to_owned(),
+                    Some(format!("response.status.as_u16() == {}", status)),
+                    state,
+                ));
+                arms
+            }
+        }
+        AcceptorMatcher::Error => {
+            let code = acceptor.expected.as_str()?;
+            let json = service.protocol() == "json" || service.protocol() == "rest-json";
+            let mut arms: Vec<Arm> = if json && code == "ValidationException" {
+                vec![Arm::new(
+                    "Err(RusotoError::Validation(_))".to_owned(),
+                    None,
+                    state,
+                )]
+            } else {
+                errors
+                    .filter(|error| error_code(service, error, json) == code)
+                    .map(service_error)
+                    .collect()
+            };
+            arms.push(Arm::new(
+                "Err(RusotoError::Unknown(response))".to_owned(),
+                Some(format!(
+                    "rusoto_core::retry::error_code(response).as_deref() == Some({:?})",
+                    code
+                )),
+                state,
+            ));
+            arms
+        }
+    };
+
+    Some(arms)
+}
+
+/// The code an operation's error is parsed from, see `GenerateErrorTypes`
+fn error_code<'a>(service: &'a Service<'_>, error: &'a Error, json: bool) -> &'a str {
+    if json {
+        return &error.shape;
+    }
+    service
+        .get_shape(&error.shape)
+        .and_then(|shape| shape.error.as_ref())
+        .and_then(|http_error| http_error.code.as_ref())
+        .unwrap_or(&error.shape)
+}
+
+/// A single step of the JMESPath subset used by waiter acceptors
+#[derive(Debug, PartialEq)]
+enum Step<'a> {
+    /// `Name`
+    Field(&'a str),
+    /// `[]`, projecting the items of a list
+    Flatten,
+    /// `*`, projecting the values of a map
+    Values,
+}
+
+/// A waiter acceptor argument, such as `Table.TableStatus`, `Reservations[].Instances[].State.Name`
+/// or ``length(DBInstances) == `0` ``
+#[derive(Debug, PartialEq)]
+enum Argument<'a> {
+    Path(Vec<Step<'a>>),
+    Length(Vec<Step<'a>>, &'a str, usize),
+}
+
+fn parse_argument(argument: &str) -> Option<Argument<'_>> {
+    lazy_static! {
+        static ref LENGTH_REGEX: Regex =
+            Regex::new(r"^length\((.+)\)\s*(==|!=|>=|<=|>|<)\s*`(\d+)`$").unwrap();
+    }
+
+    if let Some(captures) = LENGTH_REGEX.captures(argument.trim()) {
+        return Some(Argument::Length(
+            parse_path(captures.get(1).unwrap().as_str())?,
+            captures.get(2).unwrap().as_str(),
+            captures.get(3).unwrap().as_str().parse().ok()?,
+        ));
+    }
+    parse_path(argument).map(Argument::Path)
+}
+
+fn parse_path(path: &str) -> Option<Vec<Step<'_>>> {
+    let mut steps = Vec::new();
+    for segment in path.trim().split('.') {
+        if segment == "*" {
+            steps.push(Step::Values);
+            continue;
+        }
+        let (name, flatten) = match segment.strip_suffix("[]") {
+            Some(name) => (name, true),
+            None => (segment, false),
+        };
+        if name.is_empty() || !name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+            return None;
+        }
+        steps.push(Step::Field(name));
+        if flatten {
+            steps.push(Step::Flatten);
+        }
+    }
+    Some(steps)
+}
+
+/// An iterator over references to the values at `steps` in `output`, along with the
+/// name and shape of those values and whether the path is a projection
+fn path_iterator<'a>(
+    service: &'a Service<'_>,
+    output_type: &'a str,
+    steps: &[Step<'_>],
+) -> Option<(String, &'a str, &'a Shape, bool)> {
+    let mut shape_name = output_type;
+    let mut shape = service.get_shape(output_type)?;
+    let mut iterator = String::new();
+    let mut projection = false;
+
+    for step in steps {
+        match *step {
+            Step::Field(member_name) => {
+                if shape.shape_type != ShapeType::Structure {
+                    return None;
+                }
+                let field = struct_field(service, shape_name, shape, member_name)?;
+                iterator = match (iterator.is_empty(), field.optional) {
+                    (true, true) => format!("output.{}.iter()", field.name),
+                    (true, false) => format!("std::iter::once(&output.{})", field.name),
+                    (false, true) => format!("{}.flat_map(|v| v.{}.iter())", iterator, field.name),
+                    (false, false) => format!("{}.map(|v| &v.{})", iterator, field.name),
+                };
+                shape_name = field.shape_name;
+                shape = field.shape;
+            }
+            Step::Flatten => {
+                if shape.shape_type != ShapeType::List || iterator.is_empty() {
+                    return None;
+                }
+                iterator.push_str(".flat_map(|v| v.iter())");
+                shape_name = shape.member_type();
+                shape = service.get_shape(shape_name)?;
+                projection = true;
+            }
+            Step::Values => {
+                if shape.shape_type != ShapeType::Map || iterator.is_empty() {
+                    return None;
+                }
+                iterator.push_str(".flat_map(|v| v.values())");
+                shape_name = shape.value_type();
+                shape = service.get_shape(shape_name)?;
+                projection = true;
+            }
+        }
+    }
+
+    if iterator.is_empty() {
+        return None;
+    }
+    Some((iterator, shape_name, shape, projection))
+}
+
+/// A closure body comparing `v`, a reference to a value of `shape`, with `expected`
+fn comparison(
+    service: &Service<'_>,
+    shape_name: &str,
+    shape: &Shape,
+    expected: &Value,
+) -> Option<String> {
+    match (shape.shape_type, expected) {
+        (ShapeType::String, Value::String(expected)) if shape.is_enum() => {
+            let variant = enum_variants(shape)
+                .into_iter()
+                .find(|&(_, value)| value == expected);
+            match variant {
+                Some((variant, _)) => Some(format!(
+                    "*v == {}::{}",
+                    mutate_type_name(service, shape_name),
+                    variant
+                )),
+                // the service answers with values its model doesn't list at times
+                None => Some(format!("v.as_str() == {:?}", expected)),
+            }
+        }
+        (ShapeType::String, Value::String(expected)) => Some(format!("v == {:?}", expected)),
+        (ShapeType::Boolean, Value::Bool(true)) => Some("*v".to_owned()),
+        (ShapeType::Boolean, Value::Bool(false)) => Some("!*v".to_owned()),
+        (ShapeType::Integer, Value::Number(expected))
+        | (ShapeType::Long, Value::Number(expected))
+            if expected.is_i64() =>
+        {
+            Some(format!("*v == {}", expected))
+        }
+        _ => None,
+    }
+}
+
+/// The guard of a `path`, `pathAll` or `pathAny` acceptor arm
+fn path_condition(
+    service: &Service<'_>,
+    output_type: &str,
+    argument: &str,
+    acceptor: &Acceptor,
+) -> Option<String> {
+    match (parse_argument(argument)?, acceptor.matcher) {
+        (Argument::Length(steps, operator, count), AcceptorMatcher::Path) => {
+            let (iterator, _, shape, projection) = path_iterator(service, output_type, &steps)?;
+            let length = if projection {
+                format!("{}.count()", iterator)
+            } else {
+                match shape.shape_type {
+                    ShapeType::String if shape.is_enum() => {
+                        format!("{}.next().map_or(0, |v| v.as_str().len())", iterator)
+                    }
+                    ShapeType::List | ShapeType::Map | ShapeType::String => {
+                        format!("{}.next().map_or(0, |v| v.len())", iterator)
+                    }
+                    _ => return None,
+                }
+            };
+            // `length(...) > 0` expected to be `false` is `length(...) <= 0`
+            let operator = match (acceptor.expected.as_bool()?, operator) {
+                (true, operator) => operator,
+                (false, "==") => "!=",
+                (false, "!=") => "==",
+                (false, ">") => "<=",
+                (false, "<=") => ">",
+                (false, "<") => ">=",
+                (false, ">=") => "<",
+                _ => return None,
+            };
+            Some(format!("{} {} {}", length, operator, count))
+        }
+        (Argument::Path(steps), AcceptorMatcher::Path) => {
+            let (iterator, shape_name, shape, projection) =
+                path_iterator(service, output_type, &steps)?;
+            if projection {
+                return None;
+            }
+            Some(format!(
+                "{}.any(|v| {})",
+                iterator,
+                comparison(service, shape_name, shape, &acceptor.expected)?
+            ))
+        }
+        (Argument::Path(steps), matcher) => {
+            let (mut iterator, mut shape_name, mut shape, projection) =
+                path_iterator(service, output_type, &steps)?;
+            // a list is compared item by item
+            if !projection {
+                if shape.shape_type != ShapeType::List {
+                    return None;
+                }
+                iterator.push_str(".flat_map(|v| v.iter())");
+                shape_name = shape.member_type();
+                shape = service.get_shape(shape_name)?;
+            }
+            let comparison = comparison(service, shape_name, shape, &acceptor.expected)?;
+            if matcher == AcceptorMatcher::PathAll {
+                Some(format!(
+                    "rusoto_core::waiter::all_match({}, |v| {})",
+                    iterator, comparison
+                ))
+            } else {
+                Some(format!("{}.any(|v| {})", iterator, comparison))
+            }
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_waiters() {
+        let waiter: Waiter = serde_json::from_str(
+            "{
+                \"delay\": 20,
+                \"operation\": \"DescribeTable\",
+                \"maxAttempts\": 25,
+                \"acceptors\": [
+                    {
+                        \"expected\": \"ACTIVE\",
+                        \"matcher\": \"path\",
+                        \"state\": \"success\",
+                        \"argument\": \"Table.TableStatus\"
+                    },
+                    {
+                        \"expected\": \"ResourceNotFoundException\",
+                        \"matcher\": \"error\",
+                        \"state\": \"retry\"
+                    }
+                ]
+            }",
+        )
+        .expect("failed to parse waiter json");
+
+        assert_eq!(waiter.max_attempts, 25);
+        assert_eq!(waiter.acceptors[0].matcher, AcceptorMatcher::Path);
+        assert_eq!(waiter.acceptors[1].state, AcceptorState::Retry);
+        assert_eq!(
+            waiter.acceptors[1].expected,
+            Value::String("ResourceNotFoundException".to_owned())
+        );
+    }
+
+    #[test]
+    fn parses_arguments() {
+        assert_eq!(
+            parse_argument("Reservations[].Instances[].State.Name"),
+            Some(Argument::Path(vec![
+                Step::Field("Reservations"),
+                Step::Flatten,
+                Step::Field("Instances"),
+                Step::Flatten,
+                Step::Field("State"),
+                Step::Field("Name"),
+            ]))
+        );
+        assert_eq!(
+            parse_argument("VerificationAttributes.*.VerificationStatus"),
+            Some(Argument::Path(vec![
+                Step::Field("VerificationAttributes"),
+                Step::Values,
+                Step::Field("VerificationStatus"),
+            ]))
+        );
+        assert_eq!(
+            parse_argument("length(KeyPairs[].KeyName) > `0`"),
+            Some(Argument::Length(
+                vec![
+                    Step::Field("KeyPairs"),
+                    Step::Flatten,
+                    Step::Field("KeyName")
+                ],
+                ">",
+                0
+            ))
+        );
+        assert_eq!(parse_argument("status != 'DELETING'"), None);
+        assert_eq!(parse_argument("length(Output || '') > `0`"), None);
+    }
+}
@@ -1,6 +1,6 @@
 use inflector::Inflector;
 
-use super::{generate_field_name, mutate_type_name};
+use super::{event_stream_member, generate_field_name, mutate_type_name};
 use crate::botocore::{Member, Operation, Shape, ShapeType};
 use crate::Service;
 
@@ -61,6 +61,19 @@ pub fn generate_response_parser(
         .expect("failed to get output shape");
     let mutated_shape_name = mutate_type_name(service, shape_name);
 
+    // event streams are read as they come in, rather than buffered
+    if let Some((member_name, _)) = event_stream_member(service, output_shape) {
+        return format!(
+            "let mut result = {output_shape}::default();
+            result.{field} = Some(rusoto_core::event_stream::EventStream::new(response.body));
+            {parse_non_payload}
+            Ok(result)",
+            output_shape = mutated_shape_name,
+            field = generate_field_name(member_name),
+            parse_non_payload = parse_non_payload,
+        );
+    }
+
     // if the 'payload' field on the output shape is a blob or string, it indicates that
     // the entire payload is set as one of the struct members, and not parsed
     match output_shape.payload {
@@ -358,7 +371,9 @@ fn generate_primitive_deserializer(shape: &Shape, percent_decode: bool) -> Strin
         ShapeType::String if percent_decode => {
             "rusoto_core::signature::decode_uri(&characters(stack)?)"
         }
-        ShapeType::String | ShapeType::Timestamp => "characters(stack)?",
+        ShapeType::String if shape.is_enum() => "characters(stack)?.into()",
+        ShapeType::String => "characters(stack)?",
+        ShapeType::Timestamp => "rusoto_core::proto::xml::util::timestamp_characters(stack)?",
         ShapeType::Integer | ShapeType::Long => {
             "i64::from_str(characters(stack)?.as_ref()).unwrap()"
         }
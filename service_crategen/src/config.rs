@@ -23,6 +23,12 @@ pub struct ServiceConfig {
     pub custom_dev_dependencies: Option<BTreeMap<String, cargo::Dependency>>,
     #[serde(rename = "baseTypeName")]
     pub base_type_name: String,
+    /// Type of the configuration of the client, defined by the custom code of the crate
+    #[serde(rename = "clientConfig")]
+    pub client_config: Option<String>,
+    /// Operations whose successful responses may be errors, reported in an XML `Error` body
+    #[serde(rename = "errorBodyOperations")]
+    pub error_body_operations: Option<Vec<String>>,
 }
 
 impl ServiceConfig {
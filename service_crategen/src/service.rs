@@ -1,6 +1,8 @@
 use std::collections::BTreeMap;
 
-use crate::botocore::{Member, Operation, ServiceDefinition, Shape, ShapeType, Value};
+use crate::botocore::{
+    Member, Operation, Paginator, ServiceDefinition, Shape, ShapeType, Value, Waiter,
+};
 use crate::cargo;
 use crate::config::ServiceConfig;
 
@@ -50,6 +52,17 @@ impl<'b> Service<'b> {
         &self.config.base_type_name
     }
 
+    pub fn client_config(&self) -> Option<&str> {
+        self.config.client_config.as_deref()
+    }
+
+    pub fn reports_errors_in_body(&self, operation_name: &str) -> bool {
+        self.config
+            .error_body_operations
+            .as_ref()
+            .map_or(false, |operations| operations.iter().any(|name| name == operation_name))
+    }
+
     pub fn endpoint_prefix(&self) -> &str {
         &self.definition.metadata.endpoint_prefix
     }
@@ -78,6 +91,14 @@ impl<'b> Service<'b> {
         &self.definition.operations
     }
 
+    pub fn paginators(&self) -> &BTreeMap<String, Paginator> {
+        &self.definition.paginators
+    }
+
+    pub fn waiters(&self) -> &BTreeMap<String, Waiter> {
+        &self.definition.waiters
+    }
+
     pub fn shape_for_value<'a>(&'a self, value: &Value) -> Option<&'a Shape> {
         self.definition.shapes.get(&value.shape)
     }
@@ -119,6 +140,10 @@ impl<'b> Service<'b> {
             cargo::Dependency::Simple("0.1".into()),
         );
         dependencies.insert("bytes".to_owned(), cargo::Dependency::Simple("0.5".into()));
+        dependencies.insert(
+            "chrono".to_owned(),
+            cargo::Dependency::Simple("0.4.20".into()),
+        );
         dependencies.insert(
             "futures".to_owned(),
             cargo::Dependency::Extended {